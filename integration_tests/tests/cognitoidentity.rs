@@ -54,7 +54,7 @@ async fn should_handle_validation_errors_gracefully() {
     request.identity_pool_id = "invalid".to_string();
 
     match client.list_identities(request).await {
-        Err(RusotoError::Validation(msg)) => assert!(msg.contains("identityPoolId")),
+        Err(RusotoError::Validation(msg, _)) => assert!(msg.contains("identityPoolId")),
         err @ _ => panic!("Expected Validation error - got {:#?}", err),
     };
 }
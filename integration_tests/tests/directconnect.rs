@@ -25,7 +25,7 @@ async fn should_fail_gracefully() {
     };
 
     match client.describe_connections(request).await {
-        Err(RusotoError::Service(DescribeConnectionsError::DirectConnectClient(msg))) => {
+        Err(RusotoError::Service(DescribeConnectionsError::DirectConnectClient(msg), _)) => {
             assert!(msg.contains("Connection ID"))
         }
         err @ _ => panic!("Expected DirectConnectClient error, got {:#?}", err),
@@ -18,7 +18,7 @@ async fn should_parse_error_type() {
 
     let response = client.list_tables(request).await;
     match response {
-        Err(RusotoError::Validation(msg)) => {
+        Err(RusotoError::Validation(msg, _)) => {
             // local dynamodb gives a different error, this matches both:
             assert!(msg.contains("greater than or equal to 1"))
         }
@@ -20,7 +20,7 @@ async fn should_handle_deprecation_gracefully() {
     let request = DescribeJobFlowsInput::default();
 
     match client.describe_job_flows(request).await {
-        Err(RusotoError::Validation(msg)) => {
+        Err(RusotoError::Validation(msg, _)) => {
             assert!(msg.contains("DescribeJobFlows API is deprecated."))
         }
         err @ _ => panic!("Expected OK response, got {:#?}", err),
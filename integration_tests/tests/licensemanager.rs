@@ -16,7 +16,7 @@ async fn should_list_domains() {
         Ok(_) => (),
         Err(e) => {
             match e {
-                RusotoError::Service(err) => {
+                RusotoError::Service(err, _) => {
                     assert!(format!("{:?}", err).contains("Denied"));
                 }
                 _ => (),
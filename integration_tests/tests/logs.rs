@@ -30,7 +30,7 @@ async fn rusoto_logs_test_executor(client: CloudWatchLogsClient, log_group: &str
         .await
         .unwrap_or_else(|e| {
             match e {
-                RusotoError::Service(CreateLogGroupError::ResourceAlreadyExists(err)) => {
+                RusotoError::Service(CreateLogGroupError::ResourceAlreadyExists(err), _) => {
                     warn!("CreateLogGroupError::ResourceAlreadyExists: {}", err);
                     // It's fine, continue
                 }
@@ -50,7 +50,7 @@ async fn rusoto_logs_test_executor(client: CloudWatchLogsClient, log_group: &str
         .await
         .unwrap_or_else(|e| {
             match e {
-                RusotoError::Service(CreateLogStreamError::ResourceAlreadyExists(err)) => {
+                RusotoError::Service(CreateLogStreamError::ResourceAlreadyExists(err), _) => {
                     warn!("CreateLogStreamError::ResourceAlreadyExists: {}", err);
                     // It's fine, continue
                 }
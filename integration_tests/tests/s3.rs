@@ -947,7 +947,7 @@ async fn test_get_object_no_such_object(client: &S3Client, bucket: &str, filenam
     };
 
     match client.get_object(get_req).await {
-        Err(RusotoError::Service(GetObjectError::NoSuchKey(_))) => (),
+        Err(RusotoError::Service(GetObjectError::NoSuchKey(_), _)) => (),
         r => panic!("unexpected response {:?}", r),
     };
 }
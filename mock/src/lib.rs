@@ -39,7 +39,7 @@ use std::time::Duration;
 
 use async_trait::async_trait;
 use futures::FutureExt;
-use http::{header::HeaderName, HeaderMap, StatusCode};
+use http::{header::HeaderName, HeaderMap, Method, StatusCode};
 use rusoto_core::credential::{AwsCredentials, ProvideAwsCredentials};
 use rusoto_core::request::HttpResponse;
 use rusoto_core::signature::SignedRequest;
@@ -148,6 +148,10 @@ impl DispatchSignedRequest for MockRequestDispatcher {
         if self.request_checker.is_some() {
             self.request_checker.as_ref().unwrap()(&request);
         }
+        if Method::from_bytes(request.method().as_bytes()).is_err() {
+            let message = format!("Invalid HTTP method {}", request.method());
+            return futures::future::ready(Err(HttpDispatchError::new(message))).boxed();
+        }
         match self.outcome {
             RequestOutcome::Performed(ref status) => futures::future::ready(Ok(HttpResponse {
                 status: *status,
@@ -0,0 +1,103 @@
+//! The runtime driving the blocking clients of the service crates.
+
+use std::future::Future;
+use std::io;
+use std::sync::Arc;
+
+use lazy_static::lazy_static;
+use tokio::runtime::{Builder, Runtime};
+
+lazy_static! {
+    static ref SHARED_RUNTIME: BlockingRuntime =
+        BlockingRuntime::new().expect("Couldn't create the shared blocking runtime");
+}
+
+/// A tokio runtime on which blocking clients run their calls.
+///
+/// Every service crate built with the `blocking` feature has a blocking client, for
+/// instance `SqsBlockingClient`, with the same methods as the async client but waiting
+/// for their results. Connections are driven by the worker thread of the runtime, so
+/// streaming bodies can be read with `ByteStream::into_blocking_read` after the call
+/// returned.
+///
+/// Blocking clients must not be called from within an async runtime.
+#[derive(Clone)]
+pub struct BlockingRuntime {
+    runtime: Arc<Runtime>,
+}
+
+impl BlockingRuntime {
+    /// Returns the runtime shared by the blocking clients created without one.
+    pub fn shared() -> BlockingRuntime {
+        SHARED_RUNTIME.clone()
+    }
+
+    /// Creates a new runtime with a single worker thread.
+    pub fn new() -> Result<BlockingRuntime, io::Error> {
+        let runtime = Builder::new_multi_thread()
+            .worker_threads(1)
+            .thread_name("rusoto-blocking")
+            .enable_all()
+            .build()?;
+        Ok(BlockingRuntime {
+            runtime: Arc::new(runtime),
+        })
+    }
+
+    /// Runs a future to completion, blocking the current thread.
+    pub fn block_on<F: Future>(&self, future: F) -> F::Output {
+        self.runtime.block_on(future)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Read;
+
+    use super::*;
+    use crate::signature::SignedRequest;
+    use crate::{Client, HttpClient, HttpConfig, ProxyConfig, Region};
+    use rusoto_credential::StaticProvider;
+
+    #[test]
+    fn runs_calls_and_streams_bodies_after_returning() {
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = std::thread::spawn(move || {
+            use std::io::Write;
+
+            let (mut socket, _) = listener.accept().unwrap();
+            let mut buffer = [0; 1024];
+            let _ = socket.read(&mut buffer).unwrap();
+            socket
+                .write_all(b"HTTP/1.1 200 OK\r\ncontent-length: 11\r\n\r\nhello")
+                .unwrap();
+            std::thread::sleep(std::time::Duration::from_millis(50));
+            socket.write_all(b" world").unwrap();
+        });
+
+        let mut config = HttpConfig::new();
+        config.proxy(ProxyConfig::new());
+        let client = Client::new_with(
+            StaticProvider::new_minimal("access_key".to_owned(), "secret_key".to_owned()),
+            HttpClient::new_with_config(config).unwrap(),
+        );
+        let region = Region::Custom {
+            name: "us-east-1".to_owned(),
+            endpoint: format!("http://{}", address),
+        };
+        let runtime = BlockingRuntime::new().unwrap();
+        let response = runtime
+            .block_on(client.sign_and_dispatch(SignedRequest::new("GET", "s3", &region, "/")))
+            .unwrap();
+
+        let mut body = String::new();
+        response
+            .body
+            .into_blocking_read()
+            .read_to_string(&mut body)
+            .unwrap();
+        assert_eq!(body, "hello world");
+        server.join().unwrap();
+    }
+}
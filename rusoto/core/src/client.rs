@@ -1,15 +1,31 @@
+use std::error::Error;
+use std::io;
 use std::sync::{Arc, Mutex, Weak};
 use std::time::Duration;
 
+use crate::clock_skew::ClockSkew;
+use crate::config::AwsConfig;
 use crate::credential::{
     Anonymous, CredentialsError, DefaultCredentialsProvider, ProvideAwsCredentials, StaticProvider,
 };
+#[cfg(feature = "encoding")]
+use crate::encoding::decode_response;
 use crate::encoding::ContentEncoding;
+use crate::interceptor::Interceptor;
+use crate::metrics::{ByteCounts, CallTimer, MetricsSink};
+use crate::options::CallOptions;
+use crate::rate_limit::RateLimiter;
 use crate::request::{DispatchSignedRequest, HttpClient, HttpDispatchError, HttpResponse};
-use crate::signature::SignedRequest;
+use crate::retry::{self, RetryKind, RetryPolicy};
+use crate::signature::{PayloadSigning, SignedRequest, SigningAlgorithm};
+use crate::stream::ByteStream;
+use crate::trace;
 
 use async_trait::async_trait;
 use lazy_static::lazy_static;
+use log::{debug, warn};
+use rusoto_signature::EndpointVariant;
+use tokio::sync::Semaphore;
 use tokio::time;
 
 lazy_static! {
@@ -21,6 +37,16 @@ lazy_static! {
 #[derive(Clone)]
 pub struct Client {
     inner: Arc<dyn SignAndDispatch + Send + Sync>,
+    retry_policy: RetryPolicy,
+    rate_limiter: Option<RateLimiter>,
+    in_flight: Option<Arc<Semaphore>>,
+    interceptors: Vec<Arc<dyn Interceptor>>,
+    metrics_sink: Option<Arc<dyn MetricsSink>>,
+    decompress_responses: bool,
+    endpoint_variant: EndpointVariant,
+    payload_signing: PayloadSigning,
+    signing_algorithm: Option<SigningAlgorithm>,
+    options: CallOptions,
 }
 
 impl Client {
@@ -28,7 +54,7 @@ impl Client {
     pub fn shared() -> Self {
         let mut lock = SHARED_CLIENT.lock().unwrap();
         if let Some(inner) = lock.upgrade() {
-            return Client { inner };
+            return Client::from_inner(inner);
         }
         let credentials_provider =
             DefaultCredentialsProvider::new().expect("failed to create credentials provider");
@@ -37,9 +63,10 @@ impl Client {
             credentials_provider: Some(Arc::new(credentials_provider)),
             dispatcher: Arc::new(dispatcher),
             content_encoding: Default::default(),
+            clock_skew: Default::default(),
         });
         *lock = Arc::downgrade(&inner);
-        Client { inner }
+        Client::from_inner(inner)
     }
 
     /// Create a client from a credentials provider and request dispatcher.
@@ -52,10 +79,9 @@ impl Client {
             credentials_provider: Some(Arc::new(credentials_provider)),
             dispatcher: Arc::new(dispatcher),
             content_encoding: Default::default(),
+            clock_skew: Default::default(),
         };
-        Client {
-            inner: Arc::new(inner),
-        }
+        Client::from_inner(Arc::new(inner))
     }
 
     /// Create a client from a request dispatcher without a credentials provider. The client will
@@ -71,9 +97,27 @@ impl Client {
             credentials_provider: None,
             dispatcher: Arc::new(dispatcher),
             content_encoding: Default::default(),
+            clock_skew: Default::default(),
         };
-        Client {
-            inner: Arc::new(inner),
+        Client::from_inner(Arc::new(inner))
+    }
+
+    /// Create a client from the credentials provider, HTTP client, retry policy and rate
+    /// limiter of a configuration.
+    pub fn new_with_config(config: &AwsConfig) -> Self {
+        let inner = ClientInner {
+            credentials_provider: Some(config.get_credentials_provider()),
+            dispatcher: config.get_http_client(),
+            content_encoding: Default::default(),
+            clock_skew: Default::default(),
+        };
+        let client = Client::from_inner(Arc::new(inner))
+            .with_retry_policy(config.get_retry_policy().clone())
+            .with_fips_endpoint(config.get_use_fips_endpoint())
+            .with_dualstack_endpoint(config.get_use_dualstack_endpoint());
+        match config.get_rate_limiter() {
+            Some(rate_limiter) => client.with_rate_limiter(rate_limiter.clone()),
+            None => client,
         }
     }
 
@@ -92,18 +136,346 @@ impl Client {
             credentials_provider: Some(Arc::new(credentials_provider)),
             dispatcher: Arc::new(dispatcher),
             content_encoding,
+            clock_skew: Default::default(),
         };
+        Client::from_inner(Arc::new(inner))
+    }
+
+    fn from_inner(inner: Arc<dyn SignAndDispatch + Send + Sync>) -> Self {
         Client {
-            inner: Arc::new(inner),
+            inner,
+            retry_policy: RetryPolicy::default(),
+            rate_limiter: None,
+            in_flight: None,
+            interceptors: Vec::new(),
+            metrics_sink: None,
+            decompress_responses: false,
+            endpoint_variant: EndpointVariant::default(),
+            payload_signing: PayloadSigning::default(),
+            signing_algorithm: None,
+            options: CallOptions::default(),
+        }
+    }
+
+    /// Use the given policy to retry requests which failed for a transient reason.
+    ///
+    /// By default clients use `RetryPolicy::default()`.
+    pub fn with_retry_policy(mut self, retry_policy: RetryPolicy) -> Self {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Limit the rate at which requests are sent once the service starts throttling them.
+    ///
+    /// The rate limiter is shared by all clones of the returned client.
+    pub fn with_rate_limiter(mut self, rate_limiter: RateLimiter) -> Self {
+        self.rate_limiter = Some(rate_limiter);
+        self
+    }
+
+    /// Limit the number of requests in flight at the same time. Further requests wait until
+    /// a response was received for a previous one.
+    ///
+    /// The limit is shared by all clones of the returned client.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `max_in_flight` is 0, since no request could ever be sent.
+    pub fn with_max_in_flight(mut self, max_in_flight: usize) -> Self {
+        assert!(max_in_flight > 0, "max_in_flight must be at least 1");
+        self.in_flight = Some(Arc::new(Semaphore::new(max_in_flight)));
+        self
+    }
+
+    /// Register an interceptor called for every request sent by the client, after the
+    /// interceptors registered before it.
+    pub fn with_interceptor<I>(mut self, interceptor: I) -> Self
+    where
+        I: Interceptor + 'static,
+    {
+        self.interceptors.push(Arc::new(interceptor));
+        self
+    }
+
+    /// Report the metrics of every call made by the client to the given sink.
+    pub fn with_metrics_sink<M>(mut self, metrics_sink: M) -> Self
+    where
+        M: MetricsSink + 'static,
+    {
+        self.metrics_sink = Some(Arc::new(metrics_sink));
+        self
+    }
+
+    /// Decompress the bodies of responses with a `Content-Encoding` of `gzip` or `deflate`
+    /// while they are read, removing the `Content-Encoding` and `Content-Length` headers, and
+    /// ask for them with an `Accept-Encoding` of `gzip`.
+    ///
+    /// The bodies of operations returning user data, like S3 `GetObject`, are returned as they
+    /// were stored. Use `CallOptions::decompress_response` to override this for some calls.
+    #[cfg(feature = "encoding")]
+    pub fn with_response_decompression(mut self) -> Self {
+        self.decompress_responses = true;
+        self
+    }
+
+    /// Send requests to the FIPS endpoints of the services, signing them for the region of
+    /// the client.
+    pub fn with_fips_endpoint(mut self, use_fips: bool) -> Self {
+        self.endpoint_variant.fips = use_fips;
+        self
+    }
+
+    /// Send requests to the dual-stack endpoints of the services, reachable over IPv4 and
+    /// IPv6, signing them for the region of the client.
+    pub fn with_dualstack_endpoint(mut self, use_dualstack: bool) -> Self {
+        self.endpoint_variant.dualstack = use_dualstack;
+        self
+    }
+
+    /// Sign the streaming payloads of known length, like the bodies of S3 `PutObject` and
+    /// `UploadPart`, as configured instead of leaving them unsigned.
+    ///
+    /// Use `CallOptions::payload_signing` to sign the payloads of some calls only.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the payloads can't be signed this way with the signing algorithm of the
+    /// client, see `PayloadSigning::validate`.
+    pub fn with_payload_signing(mut self, payload_signing: PayloadSigning) -> Self {
+        self.payload_signing = payload_signing;
+        self.validate_payload_signing();
+        self
+    }
+
+    /// Sign every request with the given algorithm instead of the one of its service, for
+    /// instance `SigningAlgorithm::S3Legacy` to call S3 compatible services which only
+    /// accept the legacy S3 authentication.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the payload signing of the client can't be used with this algorithm, see
+    /// `PayloadSigning::validate`.
+    pub fn with_signing_algorithm(mut self, signing_algorithm: SigningAlgorithm) -> Self {
+        self.signing_algorithm = Some(signing_algorithm);
+        self.validate_payload_signing();
+        self
+    }
+
+    /// Returns a copy of the client applying the given options to every request.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the payload signing of the options can't be used with the signing
+    /// algorithm of the client, see `PayloadSigning::validate`.
+    pub fn with_options(&self, options: CallOptions) -> Self {
+        let client = Client {
+            options,
+            ..self.clone()
+        };
+        client.validate_payload_signing();
+        client
+    }
+
+    fn validate_payload_signing(&self) {
+        let signing_algorithm = self
+            .signing_algorithm
+            .clone()
+            .unwrap_or(SigningAlgorithm::SigV4);
+        let payload_signing = self
+            .options
+            .get_payload_signing()
+            .unwrap_or(&self.payload_signing);
+        if let Err(err) = payload_signing.validate(&signing_algorithm) {
+            panic!("invalid payload signing: {}", err);
         }
     }
 
     /// Fetch credentials, sign the request and dispatch it.
+    ///
+    /// Attempts failing for a transient reason are signed and dispatched again according to
+    /// the client's `RetryPolicy`.
     pub async fn sign_and_dispatch(
         &self,
         request: SignedRequest,
     ) -> Result<HttpResponse, SignAndDispatchError> {
-        self.inner.sign_and_dispatch(request, None).await
+        let span = trace::operation_span(&request);
+        let timer = self
+            .metrics_sink
+            .as_ref()
+            .map(|_| CallTimer::start(&request));
+        let counts = timer.as_ref().map(CallTimer::counts);
+        let (result, attempts) =
+            trace::instrument(self.sign_and_dispatch_with_retries(request, counts), &span).await;
+        trace::record_result(&span, &result);
+        match (&self.metrics_sink, timer) {
+            (Some(metrics_sink), Some(timer)) => {
+                timer.finish(result, attempts, metrics_sink.clone())
+            }
+            _ => result,
+        }
+    }
+
+    /// Returns the result of the last attempt and the number of attempts made.
+    async fn sign_and_dispatch_with_retries(
+        &self,
+        request: SignedRequest,
+        counts: Option<&ByteCounts>,
+    ) -> (Result<HttpResponse, SignAndDispatchError>, u32) {
+        let mut request = request;
+        if self.endpoint_variant != EndpointVariant::default() {
+            request.set_endpoint_variant(self.endpoint_variant);
+        }
+        if let Some(ref signing_algorithm) = self.signing_algorithm {
+            request.set_signing_algorithm(signing_algorithm.clone());
+        }
+        if self.payload_signing != PayloadSigning::default() {
+            if let Err(err) = request.set_payload_signing(self.payload_signing.clone()) {
+                warn!("Leaving the payload unsigned: {}", err);
+            }
+        }
+        self.options.apply(&mut request);
+        self.options
+            .get_content_encoding()
+            .unwrap_or_else(|| self.inner.content_encoding())
+            .encode(&mut request);
+        let retry_policy = self
+            .options
+            .get_retry_policy()
+            .unwrap_or(&self.retry_policy);
+        let timeout = self.options.get_timeout();
+        #[cfg(feature = "encoding")]
+        let decompress = self
+            .options
+            .get_decompress_response()
+            .unwrap_or(self.decompress_responses && !request.raw_response);
+        #[cfg(feature = "encoding")]
+        if decompress && !request.headers.contains_key("accept-encoding") {
+            // `deflate` is left out as servers disagree on whether it is wrapped in zlib
+            request.add_header("Accept-Encoding", "gzip");
+        }
+        let mut attempt = 1;
+        loop {
+            let next_request = if attempt < retry_policy.get_max_attempts() {
+                request.try_clone()
+            } else {
+                None
+            };
+            if let Some(ref rate_limiter) = self.rate_limiter {
+                rate_limiter.acquire().await;
+            }
+            let span = trace::attempt_span(attempt);
+            let result = match self.in_flight {
+                Some(ref in_flight) => {
+                    let _permit = in_flight
+                        .acquire()
+                        .await
+                        .expect("in-flight semaphore is never closed");
+                    let attempt =
+                        self.inner
+                            .sign_and_dispatch(request, timeout, &self.interceptors, counts);
+                    trace::instrument(attempt, &span).await
+                }
+                None => {
+                    let attempt =
+                        self.inner
+                            .sign_and_dispatch(request, timeout, &self.interceptors, counts);
+                    trace::instrument(attempt, &span).await
+                }
+            };
+            #[cfg(feature = "encoding")]
+            let result = match result {
+                Ok(response) if decompress => Ok(decode_response(response)),
+                result => result,
+            };
+            trace::record_result(&span, &result);
+            // Nothing to retry nor to report to the rate limiter, the response is left as is
+            // unless the clock skew is to be corrected for the next calls
+            if next_request.is_none() && self.rate_limiter.is_none() {
+                return (
+                    update_clock_skew(result, self.inner.clock_skew()).await,
+                    attempt,
+                );
+            }
+            let (result, retry_kind) = classify_result(result, self.inner.clock_skew()).await;
+            if let Some(ref rate_limiter) = self.rate_limiter {
+                rate_limiter.record_response(retry_kind == Some(RetryKind::Throttling));
+            }
+            match (retry_kind, next_request) {
+                (Some(kind), Some(next_request)) => {
+                    debug!("Attempt {} failed ({:?}), retrying", attempt, kind);
+                    if kind != RetryKind::ClockSkew {
+                        time::sleep(retry_policy.backoff(attempt)).await;
+                    }
+                    request = next_request;
+                    attempt += 1;
+                }
+                _ => return (result, attempt),
+            }
+        }
+    }
+}
+
+/// Determines whether the result of an attempt is worth retrying. Error responses are
+/// buffered to look for the error code, and to correct the clock skew when the request was
+/// rejected because of it.
+async fn classify_result(
+    result: Result<HttpResponse, SignAndDispatchError>,
+    clock_skew: &ClockSkew,
+) -> (
+    Result<HttpResponse, SignAndDispatchError>,
+    Option<RetryKind>,
+) {
+    match result {
+        Ok(mut response) if retry::may_be_retryable(response.status) => {
+            match response.buffer().await {
+                Ok(buffered) => {
+                    let retry_kind = if clock_skew.update(&buffered) {
+                        Some(RetryKind::ClockSkew)
+                    } else {
+                        retry::classify_response(&buffered)
+                    };
+                    (Ok(buffered.into_http_response()), retry_kind)
+                }
+                Err(err) => (
+                    Err(SignAndDispatchError::Dispatch(err)),
+                    Some(RetryKind::Transient),
+                ),
+            }
+        }
+        Err(SignAndDispatchError::Dispatch(err)) => {
+            let retry_kind = retry::classify_dispatch_error(&err);
+            (Err(SignAndDispatchError::Dispatch(err)), retry_kind)
+        }
+        result => (result, None),
+    }
+}
+
+/// Corrects the clock skew from the client error response of an attempt which is not
+/// retried. The body of the response is buffered, and fails to be read again if it couldn't
+/// be buffered.
+async fn update_clock_skew(
+    result: Result<HttpResponse, SignAndDispatchError>,
+    clock_skew: &ClockSkew,
+) -> Result<HttpResponse, SignAndDispatchError> {
+    match result {
+        Ok(mut response) if response.status.is_client_error() => {
+            let body = match response.buffer().await {
+                Ok(buffered) => {
+                    clock_skew.update(&buffered);
+                    ByteStream::from(buffered.body.to_vec())
+                }
+                Err(err) => {
+                    let kind = err
+                        .source()
+                        .and_then(|source| source.downcast_ref::<io::Error>())
+                        .map_or(io::ErrorKind::Other, io::Error::kind);
+                    let err = io::Error::new(kind, err.to_string());
+                    ByteStream::new(futures::stream::once(async { Err(err) }))
+                }
+            };
+            Ok(HttpResponse { body, ..response })
+        }
+        result => result,
     }
 }
 
@@ -122,21 +494,27 @@ trait SignAndDispatch {
         &self,
         request: SignedRequest,
         timeout: Option<Duration>,
+        interceptors: &[Arc<dyn Interceptor>],
+        counts: Option<&ByteCounts>,
     ) -> Result<HttpResponse, SignAndDispatchError>;
+    fn content_encoding(&self) -> &ContentEncoding;
+    fn clock_skew(&self) -> &ClockSkew;
 }
 
-struct ClientInner<P, D> {
+struct ClientInner<P: ?Sized, D> {
     credentials_provider: Option<Arc<P>>,
     dispatcher: Arc<D>,
     content_encoding: ContentEncoding,
+    clock_skew: Arc<ClockSkew>,
 }
 
-impl<P, D> Clone for ClientInner<P, D> {
+impl<P: ?Sized, D> Clone for ClientInner<P, D> {
     fn clone(&self) -> Self {
         ClientInner {
             credentials_provider: self.credentials_provider.clone(),
             dispatcher: self.dispatcher.clone(),
             content_encoding: self.content_encoding.clone(),
+            clock_skew: self.clock_skew.clone(),
         }
     }
 }
@@ -145,51 +523,84 @@ async fn sign_and_dispatch<P, D>(
     client: ClientInner<P, D>,
     mut request: SignedRequest,
     timeout: Option<Duration>,
+    interceptors: &[Arc<dyn Interceptor>],
+    counts: Option<&ByteCounts>,
 ) -> Result<HttpResponse, SignAndDispatchError>
 where
-    P: ProvideAwsCredentials + Send + Sync + 'static,
+    P: ProvideAwsCredentials + Send + Sync + ?Sized + 'static,
     D: DispatchSignedRequest + Send + Sync + 'static,
 {
-    client.content_encoding.encode(&mut request);
+    for interceptor in interceptors {
+        interceptor.before_sign(&mut request);
+    }
     if let Some(provider) = client.credentials_provider {
+        let credentials = trace::instrument(provider.credentials(), &trace::credentials_span());
         let credentials = if let Some(to) = timeout {
-            time::timeout(to, provider.credentials())
+            time::timeout(to, credentials)
                 .await
                 .map_err(|_| CredentialsError {
                     message: "Timeout getting credentials".to_owned(),
                 })
                 .and_then(std::convert::identity)
         } else {
-            provider.credentials().await
+            credentials.await
         }
         .map_err(SignAndDispatchError::Credentials)?;
-        if credentials.is_anonymous() {
-            request.complement();
-        } else {
-            request.sign(&credentials);
-        }
+        let clock_skew = &client.clock_skew;
+        trace::sign_span().in_scope(|| {
+            if credentials.is_anonymous() {
+                request.complement();
+            } else {
+                request.sign_with_time(&credentials, clock_skew.now());
+            }
+        });
     } else {
         request.complement();
     }
-    client
-        .dispatcher
-        .dispatch(request, timeout)
+    for interceptor in interceptors {
+        interceptor.after_sign(&request);
+    }
+    for interceptor in interceptors {
+        interceptor.before_dispatch(&mut request);
+    }
+    if let Some(counts) = counts {
+        counts.count_request(&mut request);
+    }
+    let dispatch = client.dispatcher.dispatch(request, timeout);
+    let mut response = trace::instrument(dispatch, &trace::dispatch_span())
         .await
-        .map_err(SignAndDispatchError::Dispatch)
+        .map_err(SignAndDispatchError::Dispatch)?;
+    if let Some(counts) = counts {
+        counts.count_response(&mut response);
+    }
+    for interceptor in interceptors {
+        interceptor.after_response(&response);
+    }
+    Ok(response)
 }
 
 #[async_trait]
 impl<P, D> SignAndDispatch for ClientInner<P, D>
 where
-    P: ProvideAwsCredentials + Send + Sync + 'static,
+    P: ProvideAwsCredentials + Send + Sync + ?Sized + 'static,
     D: DispatchSignedRequest + Send + Sync + 'static,
 {
     async fn sign_and_dispatch(
         &self,
         request: SignedRequest,
         timeout: Option<Duration>,
+        interceptors: &[Arc<dyn Interceptor>],
+        counts: Option<&ByteCounts>,
     ) -> Result<HttpResponse, SignAndDispatchError> {
-        sign_and_dispatch(self.clone(), request, timeout).await
+        sign_and_dispatch(self.clone(), request, timeout, interceptors, counts).await
+    }
+
+    fn content_encoding(&self) -> &ContentEncoding {
+        &self.content_encoding
+    }
+
+    fn clock_skew(&self) -> &ClockSkew {
+        &self.clock_skew
     }
 }
 
@@ -199,3 +610,679 @@ fn client_is_send_and_sync() {
 
     is_send_and_sync::<Client>();
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metrics::CallMetrics;
+    use crate::request::DispatchSignedRequestFuture;
+    use crate::Region;
+    use futures::FutureExt;
+    use http::StatusCode;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    type RequestChecker = Box<dyn Fn(&SignedRequest) + Send + Sync>;
+
+    /// Returns the given responses in order and counts the signed requests it received.
+    struct SequenceDispatcher {
+        responses: Mutex<Vec<(u16, &'static str)>>,
+        signed: Arc<AtomicUsize>,
+        checker: Option<RequestChecker>,
+        timeouts: Arc<Mutex<Vec<Option<Duration>>>>,
+    }
+
+    impl SequenceDispatcher {
+        fn new(mut responses: Vec<(u16, &'static str)>) -> (Self, Arc<AtomicUsize>) {
+            responses.reverse();
+            let signed = Arc::new(AtomicUsize::new(0));
+            let dispatcher = SequenceDispatcher {
+                responses: Mutex::new(responses),
+                signed: signed.clone(),
+                checker: None,
+                timeouts: Default::default(),
+            };
+            (dispatcher, signed)
+        }
+
+        fn with_checker<F>(mut self, checker: F) -> Self
+        where
+            F: Fn(&SignedRequest) + Send + Sync + 'static,
+        {
+            self.checker = Some(Box::new(checker));
+            self
+        }
+    }
+
+    impl DispatchSignedRequest for SequenceDispatcher {
+        fn dispatch(
+            &self,
+            request: SignedRequest,
+            timeout: Option<Duration>,
+        ) -> DispatchSignedRequestFuture {
+            self.timeouts.lock().unwrap().push(timeout);
+            if let Some(ref checker) = self.checker {
+                checker(&request);
+            }
+            if request.headers().contains_key("authorization") {
+                self.signed.fetch_add(1, Ordering::SeqCst);
+            }
+            let (status, body) = self.responses.lock().unwrap().pop().unwrap();
+            futures::future::ready(Ok(HttpResponse {
+                status: StatusCode::from_u16(status).unwrap(),
+                body: ByteStream::from(body.as_bytes().to_vec()),
+                headers: Default::default(),
+            }))
+            .boxed()
+        }
+    }
+
+    fn fast_retry_policy(max_attempts: u32) -> RetryPolicy {
+        let mut policy = RetryPolicy::new();
+        policy.max_attempts(max_attempts);
+        policy.base_delay(Duration::from_millis(0));
+        policy
+    }
+
+    fn credentials() -> StaticProvider {
+        StaticProvider::new_minimal("access_key".to_owned(), "secret_key".to_owned())
+    }
+
+    #[tokio::test]
+    async fn retries_transient_errors_and_signs_every_attempt() {
+        let throttled = r#"{"__type":"ThrottlingException","message":"Rate exceeded"}"#;
+        let (dispatcher, signed) =
+            SequenceDispatcher::new(vec![(503, ""), (400, throttled), (200, "ok")]);
+        let client =
+            Client::new_with(credentials(), dispatcher).with_retry_policy(fast_retry_policy(3));
+
+        let request = SignedRequest::new("POST", "sqs", &Region::UsEast1, "/");
+        let mut response = client.sign_and_dispatch(request).await.unwrap();
+
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(response.buffer().await.unwrap().body_as_str(), "ok");
+        assert_eq!(signed.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test]
+    async fn gives_up_after_max_attempts() {
+        let (dispatcher, signed) = SequenceDispatcher::new(vec![(500, "first"), (500, "second")]);
+        let client =
+            Client::new_with(credentials(), dispatcher).with_retry_policy(fast_retry_policy(2));
+
+        let request = SignedRequest::new("POST", "sqs", &Region::UsEast1, "/");
+        let mut response = client.sign_and_dispatch(request).await.unwrap();
+
+        assert_eq!(response.status, StatusCode::INTERNAL_SERVER_ERROR);
+        assert_eq!(response.buffer().await.unwrap().body_as_str(), "second");
+        assert_eq!(signed.load(Ordering::SeqCst), 2);
+    }
+
+    #[tokio::test]
+    async fn reports_call_metrics() {
+        let (dispatcher, _) = SequenceDispatcher::new(vec![(503, ""), (200, "ok")]);
+        let recorded = Arc::new(Mutex::new(Vec::new()));
+        let sink = recorded.clone();
+        let client = Client::new_with(credentials(), dispatcher)
+            .with_retry_policy(fast_retry_policy(3))
+            .with_metrics_sink(move |metrics: &CallMetrics| {
+                sink.lock().unwrap().push(metrics.clone());
+            });
+
+        let mut request = SignedRequest::new("POST", "sqs", &Region::UsEast1, "/");
+        request.set_operation("SendMessage");
+        request.set_payload(Some("payload"));
+        let mut response = client.sign_and_dispatch(request).await.unwrap();
+        assert!(recorded.lock().unwrap().is_empty());
+        response.buffer().await.unwrap();
+
+        let recorded = recorded.lock().unwrap();
+        assert_eq!(recorded.len(), 1);
+        assert_eq!(recorded[0].operation.as_deref(), Some("SendMessage"));
+        assert_eq!(recorded[0].status, Some(StatusCode::OK));
+        assert_eq!(recorded[0].retries, 1);
+        assert_eq!(recorded[0].request_bytes, 14);
+        assert_eq!(recorded[0].response_bytes, 2);
+    }
+
+    /// Returns responses of the given status whose body can't be read.
+    struct UnreadableDispatcher(StatusCode);
+
+    impl DispatchSignedRequest for UnreadableDispatcher {
+        fn dispatch(
+            &self,
+            _request: SignedRequest,
+            _timeout: Option<Duration>,
+        ) -> DispatchSignedRequestFuture {
+            let body = futures::stream::iter(vec![Err(std::io::Error::new(
+                std::io::ErrorKind::ConnectionReset,
+                "connection reset",
+            ))]);
+            futures::future::ready(Ok(HttpResponse {
+                status: self.0,
+                body: ByteStream::new(body),
+                headers: Default::default(),
+            }))
+            .boxed()
+        }
+    }
+
+    #[tokio::test]
+    async fn leaves_the_body_of_the_last_attempt_unread() {
+        for status in &[StatusCode::INTERNAL_SERVER_ERROR, StatusCode::FORBIDDEN] {
+            let client = Client::new_with(credentials(), UnreadableDispatcher(*status))
+                .with_retry_policy(RetryPolicy::no_retry());
+
+            let request = SignedRequest::new("POST", "sqs", &Region::UsEast1, "/");
+            let mut response = client.sign_and_dispatch(request).await.unwrap();
+
+            assert_eq!(response.status, *status);
+            assert!(response.buffer().await.is_err());
+        }
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_client_errors() {
+        let invalid = r#"{"__type":"ValidationException","message":"invalid"}"#;
+        let (dispatcher, signed) = SequenceDispatcher::new(vec![(400, invalid)]);
+        let client =
+            Client::new_with(credentials(), dispatcher).with_retry_policy(fast_retry_policy(3));
+
+        let request = SignedRequest::new("POST", "sqs", &Region::UsEast1, "/");
+        let response = client.sign_and_dispatch(request).await.unwrap();
+
+        assert_eq!(response.status, StatusCode::BAD_REQUEST);
+        assert_eq!(signed.load(Ordering::SeqCst), 1);
+    }
+
+    /// Rejects the requests dated more than five minutes away from its clock, which is an
+    /// hour ahead of the local one.
+    struct SkewedDispatcher {
+        dates: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl DispatchSignedRequest for SkewedDispatcher {
+        fn dispatch(
+            &self,
+            request: SignedRequest,
+            _timeout: Option<Duration>,
+        ) -> DispatchSignedRequestFuture {
+            let server_time = chrono::Utc::now() + chrono::Duration::hours(1);
+            let date = String::from_utf8(request.headers()["x-amz-date"][0].clone()).unwrap();
+            self.dates.lock().unwrap().push(date.clone());
+            let request_time = chrono::NaiveDateTime::parse_from_str(&date, "%Y%m%dT%H%M%SZ")
+                .unwrap()
+                .timestamp();
+            let mut headers = http::HeaderMap::<String>::default();
+            headers.insert(http::header::DATE, server_time.to_rfc2822());
+            let (status, body) = if (server_time.timestamp() - request_time).abs() > 300 {
+                (403, "<Error><Code>RequestTimeTooSkewed</Code></Error>")
+            } else {
+                (200, "ok")
+            };
+            futures::future::ready(Ok(HttpResponse {
+                status: StatusCode::from_u16(status).unwrap(),
+                body: ByteStream::from(body.as_bytes().to_vec()),
+                headers,
+            }))
+            .boxed()
+        }
+    }
+
+    #[tokio::test]
+    async fn corrects_clock_skew_and_keeps_it() {
+        let dates = Arc::new(Mutex::new(Vec::new()));
+        let dispatcher = SkewedDispatcher {
+            dates: dates.clone(),
+        };
+        let client =
+            Client::new_with(credentials(), dispatcher).with_retry_policy(fast_retry_policy(3));
+
+        let request = SignedRequest::new("POST", "sqs", &Region::UsEast1, "/");
+        let response = client.sign_and_dispatch(request).await.unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(dates.lock().unwrap().len(), 2);
+
+        let request = SignedRequest::new("POST", "sqs", &Region::UsEast1, "/");
+        let client = client.with_retry_policy(RetryPolicy::no_retry());
+        let response = client.sign_and_dispatch(request).await.unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+        let dates = dates.lock().unwrap();
+        assert_eq!(dates.len(), 3);
+        assert_ne!(dates[0], dates[2]);
+    }
+
+    #[tokio::test]
+    async fn corrects_clock_skew_without_retrying() {
+        let dates = Arc::new(Mutex::new(Vec::new()));
+        let dispatcher = SkewedDispatcher {
+            dates: dates.clone(),
+        };
+        let client =
+            Client::new_with(credentials(), dispatcher).with_retry_policy(RetryPolicy::no_retry());
+
+        let request = SignedRequest::new("POST", "sqs", &Region::UsEast1, "/");
+        let response = client.sign_and_dispatch(request).await.unwrap();
+        assert_eq!(response.status, StatusCode::FORBIDDEN);
+        assert_eq!(dates.lock().unwrap().len(), 1);
+
+        let request = SignedRequest::new("POST", "sqs", &Region::UsEast1, "/");
+        let response = client.sign_and_dispatch(request).await.unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(dates.lock().unwrap().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn does_not_retry_streaming_requests() {
+        let (dispatcher, signed) = SequenceDispatcher::new(vec![(503, "")]);
+        let client =
+            Client::new_with(credentials(), dispatcher).with_retry_policy(fast_retry_policy(3));
+
+        let mut request = SignedRequest::new("PUT", "s3", &Region::UsEast1, "/bucket/key");
+        request.set_payload_stream(ByteStream::from(b"body".to_vec()));
+        let response = client.sign_and_dispatch(request).await.unwrap();
+
+        assert_eq!(response.status, StatusCode::SERVICE_UNAVAILABLE);
+        assert_eq!(signed.load(Ordering::SeqCst), 1);
+    }
+
+    /// Keeps every request in flight for a little while and records the highest number of
+    /// concurrent requests.
+    #[derive(Default)]
+    struct ConcurrencyDispatcher {
+        current: Arc<AtomicUsize>,
+        max: Arc<AtomicUsize>,
+    }
+
+    impl DispatchSignedRequest for ConcurrencyDispatcher {
+        fn dispatch(
+            &self,
+            _request: SignedRequest,
+            _timeout: Option<Duration>,
+        ) -> DispatchSignedRequestFuture {
+            let current = self.current.clone();
+            let max = self.max.clone();
+            async move {
+                let in_flight = current.fetch_add(1, Ordering::SeqCst) + 1;
+                max.fetch_max(in_flight, Ordering::SeqCst);
+                time::sleep(Duration::from_millis(10)).await;
+                current.fetch_sub(1, Ordering::SeqCst);
+                Ok(HttpResponse {
+                    status: StatusCode::OK,
+                    body: ByteStream::from(Vec::new()),
+                    headers: Default::default(),
+                })
+            }
+            .boxed()
+        }
+    }
+
+    #[tokio::test]
+    async fn limits_requests_in_flight() {
+        let dispatcher = ConcurrencyDispatcher::default();
+        let max = dispatcher.max.clone();
+        let client = Client::new_with(credentials(), dispatcher).with_max_in_flight(2);
+
+        let requests = (0..6).map(|_| {
+            let client = client.clone();
+            async move {
+                let request = SignedRequest::new("POST", "sqs", &Region::UsEast1, "/");
+                client.sign_and_dispatch(request).await.unwrap()
+            }
+        });
+        futures::future::join_all(requests).await;
+
+        assert_eq!(max.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn does_not_limit_the_other_shared_clients() {
+        let limited = Client::shared().with_max_in_flight(1);
+        let other = Client::shared();
+
+        assert!(Arc::ptr_eq(&limited.inner, &other.inner));
+        assert!(limited.in_flight.is_some());
+        assert!(other.in_flight.is_none());
+        assert!(Client::shared().in_flight.is_none());
+    }
+
+    #[tokio::test]
+    async fn shares_the_in_flight_limit_with_clones() {
+        let dispatcher = ConcurrencyDispatcher::default();
+        let max = dispatcher.max.clone();
+        let client = Client::new_with(credentials(), dispatcher).with_max_in_flight(2);
+        let other = client.clone();
+
+        let requests = (0..6).map(|i| {
+            let client = if i % 2 == 0 { &client } else { &other };
+            async move {
+                let request = SignedRequest::new("POST", "sqs", &Region::UsEast1, "/");
+                client.sign_and_dispatch(request).await.unwrap()
+            }
+        });
+        futures::future::join_all(requests).await;
+
+        assert_eq!(max.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    #[should_panic(expected = "max_in_flight must be at least 1")]
+    fn rejects_no_requests_in_flight() {
+        Client::new_with(credentials(), ConcurrencyDispatcher::default()).with_max_in_flight(0);
+    }
+
+    #[tokio::test]
+    async fn throttling_enables_rate_limiting() {
+        let throttled = "<ErrorResponse><Error><Code>Throttling</Code></Error></ErrorResponse>";
+        let (dispatcher, _) = SequenceDispatcher::new(vec![(400, throttled)]);
+        let rate_limiter = RateLimiter::new();
+        let client = Client::new_with(credentials(), dispatcher)
+            .with_retry_policy(RetryPolicy::no_retry())
+            .with_rate_limiter(rate_limiter.clone());
+
+        let request = SignedRequest::new("POST", "sqs", &Region::UsEast1, "/");
+        let response = client.sign_and_dispatch(request).await.unwrap();
+
+        assert_eq!(response.status, StatusCode::BAD_REQUEST);
+        assert!(rate_limiter.send_rate().is_some());
+    }
+
+    /// Records the hooks it is called with and adds a header before signing and before
+    /// dispatching.
+    #[derive(Default)]
+    struct RecordingInterceptor {
+        calls: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl Interceptor for RecordingInterceptor {
+        fn before_sign(&self, request: &mut SignedRequest) {
+            self.calls.lock().unwrap().push("before_sign".to_owned());
+            request.add_header("x-signed-tenant", "acme");
+        }
+
+        fn after_sign(&self, request: &SignedRequest) {
+            let authorization = &request.headers()["authorization"][0];
+            let authorization = String::from_utf8_lossy(authorization);
+            assert!(authorization.contains("x-signed-tenant"));
+            self.calls.lock().unwrap().push("after_sign".to_owned());
+        }
+
+        fn before_dispatch(&self, request: &mut SignedRequest) {
+            self.calls
+                .lock()
+                .unwrap()
+                .push("before_dispatch".to_owned());
+            request.add_header("x-trace-id", "1234");
+        }
+
+        fn after_response(&self, response: &HttpResponse) {
+            self.calls
+                .lock()
+                .unwrap()
+                .push(format!("after_response {}", response.status.as_u16()));
+        }
+    }
+
+    #[tokio::test]
+    async fn calls_interceptors_for_every_attempt() {
+        let (dispatcher, _) = SequenceDispatcher::new(vec![(503, ""), (200, "")]);
+        let dispatcher = dispatcher.with_checker(|request| {
+            assert!(request.headers().contains_key("x-trace-id"));
+            let authorization = &request.headers()["authorization"][0];
+            assert!(!String::from_utf8_lossy(authorization).contains("x-trace-id"));
+        });
+        let interceptor = RecordingInterceptor::default();
+        let calls = interceptor.calls.clone();
+        let client = Client::new_with(credentials(), dispatcher)
+            .with_retry_policy(fast_retry_policy(2))
+            .with_interceptor(interceptor);
+
+        let request = SignedRequest::new("POST", "sqs", &Region::UsEast1, "/");
+        client.sign_and_dispatch(request).await.unwrap();
+
+        let attempt = |status: u16| {
+            vec![
+                "before_sign".to_owned(),
+                "after_sign".to_owned(),
+                "before_dispatch".to_owned(),
+                format!("after_response {}", status),
+            ]
+        };
+        assert_eq!(
+            *calls.lock().unwrap(),
+            [attempt(503), attempt(200)].concat()
+        );
+    }
+
+    #[cfg(feature = "encoding")]
+    fn gzip(data: &[u8]) -> Vec<u8> {
+        use flate2::{write::GzEncoder, Compression};
+        use std::io::Write;
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(data).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    #[cfg(feature = "encoding")]
+    struct GzipDispatcher;
+
+    #[cfg(feature = "encoding")]
+    impl DispatchSignedRequest for GzipDispatcher {
+        fn dispatch(
+            &self,
+            request: SignedRequest,
+            _timeout: Option<Duration>,
+        ) -> DispatchSignedRequestFuture {
+            let body = gzip(b"decompressed");
+            let mut headers = http::HeaderMap::<String>::default();
+            headers.insert("content-encoding", "gzip".to_owned());
+            // Echoes the encodings asked for, which the tests can't see otherwise
+            if let Some(accept_encoding) = request.headers().get("accept-encoding") {
+                let accept_encoding = String::from_utf8(accept_encoding[0].clone()).unwrap();
+                headers.insert("x-accept-encoding", accept_encoding);
+            }
+            headers.insert("content-length", body.len().to_string());
+            futures::future::ready(Ok(HttpResponse {
+                status: StatusCode::OK,
+                body: ByteStream::from(body),
+                headers,
+            }))
+            .boxed()
+        }
+    }
+
+    #[cfg(feature = "encoding")]
+    #[tokio::test]
+    async fn decompresses_responses_unless_opted_out() {
+        let client = Client::new_with(credentials(), GzipDispatcher).with_response_decompression();
+        let request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket/key");
+        let mut response = client.sign_and_dispatch(request).await.unwrap();
+        assert_eq!(response.headers["x-accept-encoding"], "gzip");
+        assert!(!response.headers.contains_key("content-encoding"));
+        assert!(!response.headers.contains_key("content-length"));
+        assert_eq!(
+            response.buffer().await.unwrap().body_as_str(),
+            "decompressed"
+        );
+
+        let mut options = CallOptions::new();
+        options.decompress_response(false);
+        let request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket/key");
+        let mut response = client
+            .with_options(options)
+            .sign_and_dispatch(request)
+            .await
+            .unwrap();
+        assert!(!response.headers.contains_key("x-accept-encoding"));
+        assert_eq!(response.headers["content-encoding"], "gzip");
+        let buffered = response.buffer().await.unwrap();
+        assert_eq!(&buffered.body[..], &gzip(b"decompressed")[..]);
+    }
+
+    #[cfg(feature = "encoding")]
+    #[tokio::test]
+    async fn returns_raw_responses_as_they_were_stored() {
+        let client = Client::new_with(credentials(), GzipDispatcher).with_response_decompression();
+        let mut request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket/key");
+        request.set_raw_response(true);
+        let mut response = client.sign_and_dispatch(request).await.unwrap();
+        assert!(!response.headers.contains_key("x-accept-encoding"));
+        assert_eq!(response.headers["content-encoding"], "gzip");
+        let buffered = response.buffer().await.unwrap();
+        assert_eq!(&buffered.body[..], &gzip(b"decompressed")[..]);
+
+        let mut options = CallOptions::new();
+        options.decompress_response(true);
+        let mut request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket/key");
+        request.set_raw_response(true);
+        let mut response = client
+            .with_options(options)
+            .sign_and_dispatch(request)
+            .await
+            .unwrap();
+        assert_eq!(
+            response.buffer().await.unwrap().body_as_str(),
+            "decompressed"
+        );
+    }
+
+    #[cfg(feature = "encoding")]
+    #[tokio::test]
+    async fn overrides_the_content_encoding_per_call() {
+        let (dispatcher, _) = SequenceDispatcher::new(vec![(200, ""), (200, "")]);
+        let dispatcher = dispatcher.with_checker(|request| {
+            let payload = match request.payload {
+                Some(crate::signature::SignedRequestPayload::Buffer(ref payload)) => payload,
+                _ => panic!("Unexpected payload: {:?}", request.payload),
+            };
+            if request.path == "/compressed" {
+                assert_eq!(
+                    request.headers()["content-encoding"],
+                    vec![b"gzip".to_vec()]
+                );
+                assert_eq!(&payload[..], &gzip("a".repeat(1000).as_bytes())[..]);
+            } else {
+                assert!(!request.headers().contains_key("content-encoding"));
+                assert_eq!(&payload[..], "a".repeat(1000).as_bytes());
+            }
+        });
+        let client =
+            Client::new_with_encoding(credentials(), dispatcher, ContentEncoding::Gzip(None, 6));
+
+        let mut request = SignedRequest::new("PUT", "s3", &Region::UsEast1, "/compressed");
+        request.set_payload(Some("a".repeat(1000)));
+        client.sign_and_dispatch(request).await.unwrap();
+
+        let mut options = CallOptions::new();
+        options.content_encoding(ContentEncoding::Identity);
+        let mut request = SignedRequest::new("PUT", "s3", &Region::UsEast1, "/identity");
+        request.set_payload(Some("a".repeat(1000)));
+        client
+            .with_options(options)
+            .sign_and_dispatch(request)
+            .await
+            .unwrap();
+    }
+
+    #[cfg(feature = "encoding")]
+    #[tokio::test]
+    async fn reports_the_compressed_sizes() {
+        let recorded = Arc::new(Mutex::new(Vec::new()));
+        let sink = recorded.clone();
+        let client = Client::new_with_encoding(
+            credentials(),
+            GzipDispatcher,
+            ContentEncoding::Gzip(None, 6),
+        )
+        .with_response_decompression()
+        .with_metrics_sink(move |metrics: &CallMetrics| {
+            sink.lock().unwrap().push(metrics.clone());
+        });
+
+        let payload = "a".repeat(1000);
+        let mut request = SignedRequest::new("PUT", "s3", &Region::UsEast1, "/bucket/key");
+        request.set_payload(Some(payload.clone()));
+        let mut response = client.sign_and_dispatch(request).await.unwrap();
+        response.buffer().await.unwrap();
+
+        let recorded = recorded.lock().unwrap();
+        assert_eq!(
+            recorded[0].request_bytes,
+            gzip(payload.as_bytes()).len() as u64
+        );
+        assert_eq!(
+            recorded[0].response_bytes,
+            gzip(b"decompressed").len() as u64
+        );
+    }
+
+    #[tokio::test]
+    async fn sends_requests_to_endpoint_variants() {
+        let (dispatcher, signed) = SequenceDispatcher::new(vec![(200, "")]);
+        let dispatcher = dispatcher.with_checker(|request| {
+            assert_eq!(request.hostname(), "sqs-fips.us-east-2.api.aws");
+            let authorization = String::from_utf8(request.headers()["authorization"][0].clone());
+            assert!(authorization
+                .unwrap()
+                .contains("/us-east-2/sqs/aws4_request"));
+        });
+        let client = Client::new_with(credentials(), dispatcher)
+            .with_fips_endpoint(true)
+            .with_dualstack_endpoint(true);
+
+        let request = SignedRequest::new("POST", "sqs", &Region::UsEast2, "/");
+        let response = client.sign_and_dispatch(request).await.unwrap();
+
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(signed.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn overrides_the_signing_algorithm() {
+        let (dispatcher, signed) = SequenceDispatcher::new(vec![(200, "")]);
+        let dispatcher = dispatcher.with_checker(|request| {
+            let authorization = String::from_utf8(request.headers()["authorization"][0].clone());
+            assert!(authorization.unwrap().starts_with("AWS "));
+            assert!(request.headers().contains_key("date"));
+        });
+        let client = Client::new_with(credentials(), dispatcher)
+            .with_signing_algorithm(SigningAlgorithm::S3Legacy);
+
+        let mut request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/bucket/key");
+        request.set_signing_algorithm(SigningAlgorithm::SigV4);
+        let response = client.sign_and_dispatch(request).await.unwrap();
+
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(signed.load(Ordering::SeqCst), 1);
+    }
+
+    #[tokio::test]
+    async fn applies_call_options() {
+        let (dispatcher, _) = SequenceDispatcher::new(vec![(500, ""), (200, "")]);
+        let dispatcher = dispatcher.with_checker(|request| {
+            assert_eq!(request.headers()["x-custom"], vec![b"value".to_vec()]);
+            assert_eq!(request.hostname(), "localhost:4566");
+        });
+        let timeouts = dispatcher.timeouts.clone();
+        let client =
+            Client::new_with(credentials(), dispatcher).with_retry_policy(fast_retry_policy(1));
+
+        let mut options = CallOptions::new();
+        options.timeout(Duration::from_secs(3));
+        options.retry_policy(fast_retry_policy(2));
+        options.header("x-custom", "value");
+        options.endpoint("http://localhost:4566");
+        let request = SignedRequest::new("POST", "sqs", &Region::UsEast1, "/");
+        let response = client
+            .with_options(options)
+            .sign_and_dispatch(request)
+            .await
+            .unwrap();
+
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(
+            *timeouts.lock().unwrap(),
+            vec![Some(Duration::from_secs(3)); 2]
+        );
+    }
+}
@@ -0,0 +1,105 @@
+//! Correction of the skew between the local clock and the clock of AWS.
+
+use std::sync::atomic::{AtomicI64, Ordering};
+
+use chrono::{DateTime, Duration, Utc};
+use http::header::DATE;
+use log::warn;
+
+use crate::request::BufferedHttpResponse;
+use crate::retry;
+
+/// Error codes returned when the date of a request is too far from the time of the service.
+const CLOCK_SKEW_ERROR_CODES: &[&str] = &[
+    "RequestTimeTooSkewed",
+    "RequestExpired",
+    "RequestInTheFuture",
+    "InvalidSignatureException",
+    "SignatureDoesNotMatch",
+    "AuthFailure",
+];
+
+/// Skews smaller than this are accepted by AWS, so the errors above are not caused by
+/// the clock.
+const MAX_SKEW_SECONDS: i64 = 4 * 60;
+
+/// The offset to add to the local time when dating requests, shared by the clones of a
+/// `Client`.
+#[derive(Debug, Default)]
+pub(crate) struct ClockSkew {
+    offset_millis: AtomicI64,
+}
+
+impl ClockSkew {
+    /// Returns the current time corrected by the offset.
+    pub(crate) fn now(&self) -> DateTime<Utc> {
+        Utc::now() + Duration::milliseconds(self.offset_millis.load(Ordering::Relaxed))
+    }
+
+    /// Updates the offset from the `Date` header of a response rejected because of the skew
+    /// of the local clock. Returns whether the offset changed, in which case the request
+    /// should be signed and sent again.
+    pub(crate) fn update(&self, response: &BufferedHttpResponse) -> bool {
+        if !response.status.is_client_error() {
+            return false;
+        }
+        let server_time = match response
+            .headers
+            .get(DATE)
+            .and_then(|date| DateTime::parse_from_rfc2822(date).ok())
+        {
+            Some(server_time) => server_time.with_timezone(&Utc),
+            None => return false,
+        };
+        let skew = server_time - self.now();
+        if skew.num_seconds().abs() < MAX_SKEW_SECONDS {
+            return false;
+        }
+        match retry::error_code(response) {
+            Some(ref code) if CLOCK_SKEW_ERROR_CODES.contains(&code.as_str()) => {}
+            _ => return false,
+        }
+        let offset = self.offset_millis.load(Ordering::Relaxed) + skew.num_milliseconds();
+        warn!(
+            "Correcting a clock skew of {}s with the time of AWS",
+            offset / 1000
+        );
+        self.offset_millis.store(offset, Ordering::Relaxed);
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn response(status: u16, date: DateTime<Utc>, body: &str) -> BufferedHttpResponse {
+        BufferedHttpResponse::for_test(status, &[("date", &date.to_rfc2822())], body)
+    }
+
+    #[test]
+    fn corrects_skewed_clocks() {
+        let skew = ClockSkew::default();
+        let server_time = Utc::now() + Duration::minutes(20);
+        let skewed = response(
+            403,
+            server_time,
+            "<Error><Code>RequestTimeTooSkewed</Code></Error>",
+        );
+        assert!(skew.update(&skewed));
+        assert!((skew.now() - server_time).num_seconds().abs() < 5);
+        // The offset is kept, so the next requests are not rejected again.
+        assert!(!skew.update(&skewed));
+    }
+
+    #[test]
+    fn ignores_other_errors() {
+        let skew = ClockSkew::default();
+        let body = r#"{"__type":"InvalidSignatureException","message":"Signature expired"}"#;
+        assert!(!skew.update(&response(400, Utc::now(), body)));
+        let body = r#"{"__type":"ResourceNotFoundException","message":"not found"}"#;
+        assert!(!skew.update(&response(400, Utc::now() - Duration::hours(1), body)));
+        assert!(!skew.update(&response(500, Utc::now() - Duration::hours(1), body)));
+        assert!((skew.now() - Utc::now()).num_seconds().abs() < 5);
+    }
+}
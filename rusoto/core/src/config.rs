@@ -0,0 +1,911 @@
+//! Configuration shared by the clients of every service.
+//!
+//! An `AwsConfig` resolves the region, credentials provider, retry settings and HTTP settings
+//! from the environment and a profile of `~/.aws/config`, following the precedence of the
+//! AWS CLI: environment variables first, then the profile, then the defaults.
+//!
+//! ```no_run
+//! use rusoto_core::config::AwsConfig;
+//!
+//! let config = AwsConfig::load().expect("invalid configuration");
+//! println!("Using {:?} from profile {}", config.get_region(), config.get_profile());
+//! ```
+//!
+//! Every generated client has a `new_with_config` constructor taking an `AwsConfig`. The
+//! clients created from the same configuration share its HTTP client, credentials provider
+//! and rate limiter.
+//!
+//! The endpoint of a service can be overridden, to send its requests to a local emulator for
+//! instance, without changing the region used to sign them:
+//!
+//! * by the `AWS_ENDPOINT_URL_<SERVICE>` environment variable, where `<SERVICE>` is the id of
+//!   the service in upper case with its spaces replaced by underscores, like
+//!   `AWS_ENDPOINT_URL_DYNAMODB` or `AWS_ENDPOINT_URL_ELASTIC_BEANSTALK`
+//! * by the `endpoint_url` setting of the section named after the service, in lower case, in
+//!   the `[services <name>]` section of `~/.aws/config` the profile refers to
+//! * for all the services, by the `AWS_ENDPOINT_URL` environment variable
+//! * for all the services, by the `endpoint_url` setting of the profile
+//!
+//! ```ini
+//! [profile local]
+//! region = eu-west-1
+//! endpoint_url = http://localhost:4566
+//! services = local-services
+//!
+//! [services local-services]
+//! dynamodb =
+//!   endpoint_url = http://localhost:8000
+//! ```
+//!
+//! The generated clients apply these overrides when they are created, unless their region is
+//! a `Region::Custom` or `AWS_IGNORE_CONFIGURED_ENDPOINT_URLS` or the
+//! `ignore_configured_endpoint_urls` setting is `true`. The clients created with an explicit
+//! region read the overrides once, when the first of them is created.
+
+use std::collections::HashMap;
+use std::env;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::Arc;
+use std::time::Duration;
+
+use lazy_static::lazy_static;
+use rusoto_signature::endpoints;
+
+use crate::credential::{
+    AutoRefreshingProvider, ChainProvider, ProfileProvider, ProvideAwsCredentials,
+};
+use crate::rate_limit::RateLimiter;
+use crate::region::Region;
+use crate::request::{HttpClient, HttpConfig};
+use crate::retry::RetryPolicy;
+
+/// The resolved configuration of the clients, see the [module documentation](index.html).
+#[derive(Clone)]
+pub struct AwsConfig {
+    profile: String,
+    region: Region,
+    credentials_provider: Arc<dyn ProvideAwsCredentials + Send + Sync>,
+    http_client: Arc<HttpClient>,
+    retry_policy: RetryPolicy,
+    rate_limiter: Option<RateLimiter>,
+    use_fips_endpoint: bool,
+    use_dualstack_endpoint: bool,
+    ignore_configured_endpoint_urls: bool,
+    endpoint_urls: HashMap<String, String>,
+    sts_regional_endpoints: StsRegionalEndpoints,
+    s3_addressing_style: S3AddressingStyle,
+    role_session_duration: Option<Duration>,
+    settings: HashMap<String, String>,
+}
+
+impl AwsConfig {
+    /// Loads the configuration of the profile named by the `AWS_PROFILE` environment
+    /// variable, or of the `default` profile.
+    pub fn load() -> Result<AwsConfig, ConfigError> {
+        AwsConfig::load_profile(ProfileProvider::default_profile_name())
+    }
+
+    /// Loads the configuration of the given profile.
+    ///
+    /// The following settings are read from the environment, or from the profile in the file
+    /// named by `AWS_CONFIG_FILE`, `~/.aws/config` by default:
+    ///
+    /// * `AWS_REGION`, `AWS_DEFAULT_REGION` or `region`, `us-east-1` by default
+    /// * `AWS_MAX_ATTEMPTS` or `max_attempts`, the number of attempts made for every call
+    /// * `AWS_RETRY_MODE` or `retry_mode`, either `legacy`, `standard` or `adaptive`, the
+    ///   latter limiting the rate of requests once the services throttle them
+    /// * `AWS_CA_BUNDLE` or `ca_bundle`, the path of a PEM bundle of certificates to trust
+    /// * `AWS_USE_FIPS_ENDPOINT` or `use_fips_endpoint`, `true` to send requests to the FIPS
+    ///   endpoints of the services
+    /// * `AWS_USE_DUALSTACK_ENDPOINT` or `use_dualstack_endpoint`, `true` to send requests to
+    ///   the dual-stack endpoints of the services, reachable over IPv6
+    /// * `AWS_ENDPOINT_URL`, `AWS_ENDPOINT_URL_<SERVICE>` or `endpoint_url`, the endpoint of
+    ///   the services, see the [module documentation](index.html)
+    /// * `AWS_IGNORE_CONFIGURED_ENDPOINT_URLS` or `ignore_configured_endpoint_urls`, `true` to
+    ///   ignore the endpoints above
+    /// * `AWS_STS_REGIONAL_ENDPOINTS` or `sts_regional_endpoints`, `legacy` to send the
+    ///   requests of the STS clients of some regions to the global endpoint, see
+    ///   `StsRegionalEndpoints`
+    /// * `s3.addressing_style`, either `auto`, `virtual` or `path`, see `S3AddressingStyle`
+    /// * `duration_seconds`, the duration of the sessions of the role assumed by
+    ///   `StsAssumeRoleSessionCredentialsProvider::from_config` of `rusoto_sts`
+    ///
+    /// Credentials are resolved by a `ChainProvider` using the profile. The proxies are read
+    /// from the environment, see `ProxyConfig::from_env`.
+    ///
+    /// The other settings of the profile are not applied to the clients. They are only
+    /// returned by `get_setting`.
+    pub fn load_profile<P: Into<String>>(profile: P) -> Result<AwsConfig, ConfigError> {
+        let profile = profile.into();
+        let settings = match ProfileProvider::default_config_location() {
+            Ok(location) => read_profile(&location, &profile)?,
+            Err(_) => HashMap::new(),
+        };
+        AwsConfig::from_sources(profile, &env_vars(), settings, HttpConfig::new())
+    }
+
+    fn from_sources(
+        profile: String,
+        env: &HashMap<String, String>,
+        settings: HashMap<String, String>,
+        mut http_config: HttpConfig,
+    ) -> Result<AwsConfig, ConfigError> {
+        let env_var = |name: &str| {
+            env.get(name)
+                .filter(|value| !value.trim().is_empty())
+                .cloned()
+        };
+        let setting = |names: &[&str], key: &str| {
+            names
+                .iter()
+                .find_map(|name| env_var(name))
+                .or_else(|| settings.get(key).cloned())
+        };
+
+        let region = match setting(&["AWS_REGION", "AWS_DEFAULT_REGION"], "region") {
+            Some(name) => Region::from_str(name.trim()).map_err(|err| ConfigError {
+                message: err.to_string(),
+            })?,
+            None => Region::UsEast1,
+        };
+
+        let mut retry_policy = RetryPolicy::default();
+        if let Some(max_attempts) = setting(&["AWS_MAX_ATTEMPTS"], "max_attempts") {
+            let max_attempts = max_attempts.trim().parse().map_err(|_| ConfigError {
+                message: format!("Invalid max_attempts: {}", max_attempts),
+            })?;
+            retry_policy.max_attempts(max_attempts);
+        }
+        let rate_limiter = match setting(&["AWS_RETRY_MODE"], "retry_mode").as_deref() {
+            None | Some("legacy") | Some("standard") => None,
+            Some("adaptive") => Some(RateLimiter::new()),
+            Some(retry_mode) => {
+                return Err(ConfigError {
+                    message: format!("Invalid retry_mode: {}", retry_mode),
+                })
+            }
+        };
+
+        let flag = |name: &str, key: &str| match setting(&[name], key) {
+            None => Ok(false),
+            Some(value) => match value.trim().to_lowercase().as_str() {
+                "true" => Ok(true),
+                "false" => Ok(false),
+                _ => Err(ConfigError {
+                    message: format!("Invalid {}: {}", key, value),
+                }),
+            },
+        };
+        let use_fips_endpoint = flag("AWS_USE_FIPS_ENDPOINT", "use_fips_endpoint")?;
+        let use_dualstack_endpoint = flag("AWS_USE_DUALSTACK_ENDPOINT", "use_dualstack_endpoint")?;
+        let ignore_configured_endpoint_urls = flag(
+            "AWS_IGNORE_CONFIGURED_ENDPOINT_URLS",
+            "ignore_configured_endpoint_urls",
+        )?;
+        let endpoint_urls = endpoint_urls(env);
+
+        let sts_regional_endpoints =
+            match setting(&["AWS_STS_REGIONAL_ENDPOINTS"], "sts_regional_endpoints").as_deref() {
+                None | Some("regional") => StsRegionalEndpoints::Regional,
+                Some("legacy") => StsRegionalEndpoints::Legacy,
+                Some(value) => {
+                    return Err(ConfigError {
+                        message: format!("Invalid sts_regional_endpoints: {}", value),
+                    })
+                }
+            };
+        let s3_addressing_style = match setting(&[], "s3.addressing_style").as_deref() {
+            None | Some("path") => S3AddressingStyle::Path,
+            Some("auto") => S3AddressingStyle::Auto,
+            Some("virtual") => S3AddressingStyle::Virtual,
+            Some(value) => {
+                return Err(ConfigError {
+                    message: format!("Invalid s3.addressing_style: {}", value),
+                })
+            }
+        };
+        let role_session_duration = match setting(&[], "duration_seconds") {
+            Some(seconds) => Some(Duration::from_secs(seconds.trim().parse().map_err(
+                |_| ConfigError {
+                    message: format!("Invalid duration_seconds: {}", seconds),
+                },
+            )?)),
+            None => None,
+        };
+
+        if let Some(ca_bundle) = setting(&["AWS_CA_BUNDLE"], "ca_bundle") {
+            let pem = fs::read(&ca_bundle).map_err(|err| ConfigError {
+                message: format!("Couldn't read the CA bundle {}: {}", ca_bundle, err),
+            })?;
+            http_config.ca_bundle(pem);
+        }
+        let http_client = HttpClient::new_with_config(http_config).map_err(|err| ConfigError {
+            message: err.to_string(),
+        })?;
+
+        let profile_provider = ProfileProvider::with_default_credentials(profile.as_str())
+            .map_err(|err| ConfigError {
+                message: err.to_string(),
+            })?;
+        let credentials_provider =
+            AutoRefreshingProvider::new(ChainProvider::with_profile_provider(profile_provider))
+                .map_err(|err| ConfigError {
+                    message: err.to_string(),
+                })?;
+
+        Ok(AwsConfig {
+            profile,
+            region,
+            credentials_provider: Arc::new(credentials_provider),
+            http_client: Arc::new(http_client),
+            retry_policy,
+            rate_limiter,
+            use_fips_endpoint,
+            use_dualstack_endpoint,
+            ignore_configured_endpoint_urls,
+            endpoint_urls,
+            sts_regional_endpoints,
+            s3_addressing_style,
+            role_session_duration,
+            settings,
+        })
+    }
+
+    /// Returns the name of the profile the configuration was loaded from.
+    pub fn get_profile(&self) -> &str {
+        &self.profile
+    }
+
+    /// Returns the region of the clients.
+    pub fn get_region(&self) -> &Region {
+        &self.region
+    }
+
+    /// Returns the retry policy of the clients.
+    pub fn get_retry_policy(&self) -> &RetryPolicy {
+        &self.retry_policy
+    }
+
+    /// Returns whether the clients send their requests to FIPS endpoints.
+    pub fn get_use_fips_endpoint(&self) -> bool {
+        self.use_fips_endpoint
+    }
+
+    /// Returns whether the clients send their requests to dual-stack endpoints.
+    pub fn get_use_dualstack_endpoint(&self) -> bool {
+        self.use_dualstack_endpoint
+    }
+
+    /// Returns the endpoint URL configured for the service with the given id, like
+    /// `"DynamoDB"` or `"S3"`, see the [module documentation](index.html).
+    pub fn get_endpoint_url(&self, service_id: &str) -> Option<&str> {
+        if self.ignore_configured_endpoint_urls {
+            return None;
+        }
+        endpoint_url(
+            service_id,
+            |name| self.endpoint_urls.get(name).map(String::as_str),
+            &self.settings,
+        )
+    }
+
+    /// Returns the region of the clients of the service with the given id, like `"DynamoDB"`
+    /// or `"S3"`: a `Region::Custom` sending the requests to the endpoint URL configured for
+    /// the service, if any, and signing them for the region of the configuration.
+    ///
+    /// The STS clients of the legacy global regions send their requests to the global
+    /// endpoint when `get_sts_regional_endpoints` is `Legacy`, unless they use FIPS or
+    /// dual-stack endpoints.
+    pub fn get_region_for_service(&self, service_id: &str) -> Region {
+        let endpoint_url = self.get_endpoint_url(service_id);
+        if service_id == "STS"
+            && endpoint_url.is_none()
+            && self.sts_regional_endpoints == StsRegionalEndpoints::Legacy
+            && !self.use_fips_endpoint
+            && !self.use_dualstack_endpoint
+            && LEGACY_GLOBAL_STS_REGIONS.contains(&self.region.name())
+        {
+            let global = endpoints::resolve("sts", "aws-global");
+            return Region::Custom {
+                name: global.signing_region().to_owned(),
+                endpoint: format!("https://{}", global.hostname()),
+            };
+        }
+        with_endpoint_url(self.region.clone(), endpoint_url)
+    }
+
+    /// Returns whether the STS clients of the legacy global regions send their requests to
+    /// the global endpoint.
+    pub fn get_sts_regional_endpoints(&self) -> StsRegionalEndpoints {
+        self.sts_regional_endpoints
+    }
+
+    /// Returns how the S3 clients address the buckets.
+    pub fn get_s3_addressing_style(&self) -> S3AddressingStyle {
+        self.s3_addressing_style
+    }
+
+    /// Returns the duration of the sessions of the assumed role, set by `duration_seconds`.
+    pub fn get_role_session_duration(&self) -> Option<Duration> {
+        self.role_session_duration
+    }
+
+    /// Returns a setting of the profile, for instance `"sts_regional_endpoints"` or
+    /// `"duration_seconds"`. Settings nested in a section like `s3` are prefixed with its
+    /// name, for instance `"s3.addressing_style"`.
+    ///
+    /// The settings listed by `load_profile` are applied to the clients and have typed
+    /// accessors, the others are left to the application.
+    pub fn get_setting(&self, name: &str) -> Option<&str> {
+        self.settings.get(name).map(String::as_str)
+    }
+
+    /// Overrides the region of the clients.
+    pub fn region(&mut self, region: Region) {
+        self.region = region;
+    }
+
+    /// Overrides the credentials provider of the clients.
+    pub fn credentials_provider<P>(&mut self, credentials_provider: P)
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+    {
+        self.credentials_provider = Arc::new(credentials_provider);
+    }
+
+    /// Overrides the HTTP client of the clients.
+    pub fn http_client(&mut self, http_client: HttpClient) {
+        self.http_client = Arc::new(http_client);
+    }
+
+    /// Overrides the retry policy of the clients.
+    pub fn retry_policy(&mut self, retry_policy: RetryPolicy) {
+        self.retry_policy = retry_policy;
+    }
+
+    /// Overrides whether the clients send their requests to FIPS endpoints.
+    pub fn use_fips_endpoint(&mut self, use_fips_endpoint: bool) {
+        self.use_fips_endpoint = use_fips_endpoint;
+    }
+
+    /// Overrides whether the clients send their requests to dual-stack endpoints.
+    pub fn use_dualstack_endpoint(&mut self, use_dualstack_endpoint: bool) {
+        self.use_dualstack_endpoint = use_dualstack_endpoint;
+    }
+
+    /// Overrides whether the STS clients of the legacy global regions send their requests to
+    /// the global endpoint.
+    pub fn sts_regional_endpoints(&mut self, sts_regional_endpoints: StsRegionalEndpoints) {
+        self.sts_regional_endpoints = sts_regional_endpoints;
+    }
+
+    /// Overrides how the S3 clients address the buckets.
+    pub fn s3_addressing_style(&mut self, s3_addressing_style: S3AddressingStyle) {
+        self.s3_addressing_style = s3_addressing_style;
+    }
+
+    /// Overrides the duration of the sessions of the assumed role.
+    pub fn role_session_duration(&mut self, role_session_duration: Duration) {
+        self.role_session_duration = Some(role_session_duration);
+    }
+
+    pub(crate) fn get_credentials_provider(&self) -> Arc<dyn ProvideAwsCredentials + Send + Sync> {
+        self.credentials_provider.clone()
+    }
+
+    pub(crate) fn get_http_client(&self) -> Arc<HttpClient> {
+        self.http_client.clone()
+    }
+
+    pub(crate) fn get_rate_limiter(&self) -> Option<&RateLimiter> {
+        self.rate_limiter.as_ref()
+    }
+}
+
+impl fmt::Debug for AwsConfig {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AwsConfig")
+            .field("profile", &self.profile)
+            .field("region", &self.region)
+            .field("retry_policy", &self.retry_policy)
+            .field("adaptive_retries", &self.rate_limiter.is_some())
+            .field("use_fips_endpoint", &self.use_fips_endpoint)
+            .field("use_dualstack_endpoint", &self.use_dualstack_endpoint)
+            .field(
+                "ignore_configured_endpoint_urls",
+                &self.ignore_configured_endpoint_urls,
+            )
+            .field("endpoint_urls", &self.endpoint_urls)
+            .field("sts_regional_endpoints", &self.sts_regional_endpoints)
+            .field("s3_addressing_style", &self.s3_addressing_style)
+            .field("role_session_duration", &self.role_session_duration)
+            .field("settings", &self.settings)
+            .finish()
+    }
+}
+
+/// Which endpoint the STS clients of the legacy global regions send their requests to, set
+/// by `AWS_STS_REGIONAL_ENDPOINTS` or the `sts_regional_endpoints` setting.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum StsRegionalEndpoints {
+    /// The global endpoint, `sts.amazonaws.com`, signing the requests for `us-east-1`
+    Legacy,
+    /// The endpoint of the region of the client, the default
+    Regional,
+}
+
+/// How the S3 clients address the buckets, set by the `addressing_style` setting of the `s3`
+/// section of the profile.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum S3AddressingStyle {
+    /// Virtual hosted-style for the buckets whose names are valid hostnames without dots,
+    /// unless an endpoint URL is configured, path-style otherwise
+    Auto,
+    /// Virtual hosted-style, like `https://bucket.s3.eu-west-1.amazonaws.com/key`, for the
+    /// buckets whose names are valid hostnames without dots, path-style otherwise
+    Virtual,
+    /// Path-style, like `https://s3.eu-west-1.amazonaws.com/bucket/key`, the default
+    Path,
+}
+
+/// The regions whose STS clients send their requests to the global endpoint when
+/// `sts_regional_endpoints` is `legacy`, as listed by botocore.
+const LEGACY_GLOBAL_STS_REGIONS: &[&str] = &[
+    "ap-northeast-1",
+    "ap-south-1",
+    "ap-southeast-1",
+    "ap-southeast-2",
+    "ca-central-1",
+    "eu-central-1",
+    "eu-north-1",
+    "eu-west-1",
+    "eu-west-2",
+    "eu-west-3",
+    "sa-east-1",
+    "us-east-1",
+    "us-east-2",
+    "us-west-1",
+    "us-west-2",
+];
+
+const ENDPOINT_URL_VAR: &str = "AWS_ENDPOINT_URL";
+
+lazy_static! {
+    static ref CONFIGURED_ENDPOINTS: ConfiguredEndpoints = ConfiguredEndpoints::load();
+}
+
+/// The endpoint URLs configured in the environment and in the profile named by `AWS_PROFILE`.
+struct ConfiguredEndpoints {
+    ignore: bool,
+    endpoint_urls: HashMap<String, String>,
+    settings: HashMap<String, String>,
+}
+
+impl ConfiguredEndpoints {
+    fn load() -> ConfiguredEndpoints {
+        let env = env_vars();
+        let settings = ProfileProvider::default_config_location()
+            .ok()
+            .and_then(|location| {
+                read_profile(&location, &ProfileProvider::default_profile_name()).ok()
+            })
+            .unwrap_or_default();
+        let ignore = env
+            .get("AWS_IGNORE_CONFIGURED_ENDPOINT_URLS")
+            .filter(|value| !value.trim().is_empty())
+            .or_else(|| settings.get("ignore_configured_endpoint_urls"))
+            .filter(|ignore| ignore.trim().eq_ignore_ascii_case("true"))
+            .is_some();
+        ConfiguredEndpoints {
+            ignore,
+            endpoint_urls: endpoint_urls(&env),
+            settings,
+        }
+    }
+}
+
+/// Applies the endpoint URL configured for the service with the given id, like `"DynamoDB"` or
+/// `"S3"`, to a region, see the [module documentation](index.html).
+///
+/// The endpoint URL is read from the environment and from the profile named by `AWS_PROFILE`
+/// by the first call, later changes to them are ignored. The region is returned unchanged if
+/// it is a `Region::Custom` or if no endpoint URL is configured, otherwise the requests are
+/// sent to the endpoint URL and signed for the region.
+///
+/// ```
+/// use rusoto_core::config::region_for_service;
+/// use rusoto_core::Region;
+///
+/// std::env::set_var("AWS_ENDPOINT_URL_DYNAMODB", "http://localhost:8000");
+/// let region = region_for_service(Region::EuWest1, "DynamoDB");
+/// assert_eq!(
+///     region,
+///     Region::Custom {
+///         name: "eu-west-1".to_owned(),
+///         endpoint: "http://localhost:8000".to_owned(),
+///     }
+/// );
+/// ```
+pub fn region_for_service(region: Region, service_id: &str) -> Region {
+    if let Region::Custom { .. } = region {
+        return region;
+    }
+    let configured = &*CONFIGURED_ENDPOINTS;
+    if configured.ignore {
+        return region;
+    }
+    let endpoint_url = endpoint_url(
+        service_id,
+        |name| configured.endpoint_urls.get(name).map(String::as_str),
+        &configured.settings,
+    );
+    with_endpoint_url(region, endpoint_url)
+}
+
+/// Returns the endpoint URL of a service, from the most specific setting to the least.
+fn endpoint_url<'a, F>(
+    service_id: &str,
+    env_var: F,
+    settings: &'a HashMap<String, String>,
+) -> Option<&'a str>
+where
+    F: Fn(&str) -> Option<&'a str>,
+{
+    let service_key = service_id.trim().replace(' ', "_");
+    let setting = |key: &str| {
+        settings
+            .get(key)
+            .map(|value| value.trim())
+            .filter(|value| !value.is_empty())
+    };
+    env_var(&format!(
+        "{}_{}",
+        ENDPOINT_URL_VAR,
+        service_key.to_uppercase()
+    ))
+    .or_else(|| setting(&format!("{}.endpoint_url", service_key.to_lowercase())))
+    .or_else(|| env_var(ENDPOINT_URL_VAR))
+    .or_else(|| setting("endpoint_url"))
+}
+
+fn with_endpoint_url(region: Region, endpoint_url: Option<&str>) -> Region {
+    match (region, endpoint_url) {
+        (Region::Custom { name, endpoint }, _) => Region::Custom { name, endpoint },
+        (region, Some(endpoint)) => Region::Custom {
+            name: region.name().to_owned(),
+            endpoint: endpoint.to_owned(),
+        },
+        (region, None) => region,
+    }
+}
+
+/// Returns the endpoint URLs set by the `AWS_ENDPOINT_URL` environment variables.
+fn endpoint_urls(env: &HashMap<String, String>) -> HashMap<String, String> {
+    env.iter()
+        .filter(|(name, value)| name.starts_with(ENDPOINT_URL_VAR) && !value.trim().is_empty())
+        .map(|(name, value)| (name.clone(), value.trim().to_owned()))
+        .collect()
+}
+
+/// Returns the environment variables, leaving out the ones which aren't valid unicode.
+fn env_vars() -> HashMap<String, String> {
+    env::vars_os()
+        .filter_map(|(name, value)| Some((name.into_string().ok()?, value.into_string().ok()?)))
+        .collect()
+}
+
+/// An error loading an `AwsConfig`.
+#[derive(Debug, PartialEq)]
+pub struct ConfigError {
+    message: String,
+}
+
+impl Error for ConfigError {}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.message)
+    }
+}
+
+/// Reads the settings of a profile from a config file, returning no settings if the file
+/// does not exist.
+fn read_profile(path: &Path, profile: &str) -> Result<HashMap<String, String>, ConfigError> {
+    if !path.is_file() {
+        return Ok(HashMap::new());
+    }
+    let contents = fs::read_to_string(path).map_err(|err| ConfigError {
+        message: format!("Couldn't read {}: {}", path.display(), err),
+    })?;
+    Ok(parse_profile(&contents, profile))
+}
+
+/// Parses the settings of a profile, named `[profile <name>]` or `[<name>]`. The settings
+/// indented below a setting without a value, like `s3 =`, are prefixed with its name.
+///
+/// The settings of the `[services <name>]` section named by the `services` setting of the
+/// profile are added to them, unless the profile sets them itself.
+fn parse_profile(contents: &str, profile: &str) -> HashMap<String, String> {
+    let mut settings = parse_section(contents, |name| {
+        name.strip_prefix("profile ").unwrap_or(name).trim() == profile
+    });
+    if let Some(services) = settings.get("services").cloned() {
+        let services_settings = parse_section(contents, |name| {
+            name.strip_prefix("services ").map(str::trim) == Some(services.as_str())
+        });
+        for (key, value) in services_settings {
+            settings.entry(key).or_insert(value);
+        }
+    }
+    settings
+}
+
+/// Parses the settings of the sections whose name matches.
+fn parse_section<F>(contents: &str, matches: F) -> HashMap<String, String>
+where
+    F: Fn(&str) -> bool,
+{
+    let mut settings = HashMap::new();
+    let mut in_section = false;
+    let mut section: Option<String> = None;
+    for line in contents.lines() {
+        let trimmed = line.trim();
+        if trimmed.is_empty() || trimmed.starts_with('#') || trimmed.starts_with(';') {
+            continue;
+        }
+        if trimmed.starts_with('[') && trimmed.ends_with(']') {
+            in_section = matches(trimmed[1..trimmed.len() - 1].trim());
+            section = None;
+            continue;
+        }
+        if !in_section {
+            continue;
+        }
+        let (key, value) = match trimmed.find('=') {
+            Some(index) => (trimmed[..index].trim(), trimmed[index + 1..].trim()),
+            None => continue,
+        };
+        let nested = line.starts_with(char::is_whitespace);
+        match section {
+            Some(ref name) if nested => {
+                settings.insert(format!("{}.{}", name, key), value.to_owned());
+            }
+            _ if value.is_empty() => section = Some(key.to_owned()),
+            _ => {
+                section = None;
+                settings.insert(key.to_owned(), value.to_owned());
+            }
+        }
+    }
+    settings
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::proxy::ProxyConfig;
+
+    const CONFIG: &str = "
+[default]
+region = us-west-2
+
+[profile dev]
+# Comments are ignored
+region = eu-west-1
+max_attempts = 5
+retry_mode = adaptive
+use_fips_endpoint = true
+sts_regional_endpoints = regional
+s3 =
+  addressing_style = path
+  max_concurrent_requests = 20
+duration_seconds = 3600
+endpoint_url = http://localhost:4566
+services = local
+
+[services local]
+dynamodb =
+  endpoint_url = http://localhost:8000
+s3 =
+  addressing_style = virtual
+";
+
+    fn http_config() -> HttpConfig {
+        let mut http_config = HttpConfig::new();
+        http_config.proxy(ProxyConfig::new());
+        http_config
+    }
+
+    fn load(vars: &[(&str, &str)], profile: &str) -> Result<AwsConfig, ConfigError> {
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(name, value)| (name.to_string(), value.to_string()))
+            .collect();
+        AwsConfig::from_sources(
+            profile.to_owned(),
+            &vars,
+            parse_profile(CONFIG, profile),
+            http_config(),
+        )
+    }
+
+    #[test]
+    fn parses_profiles() {
+        let settings = parse_profile(CONFIG, "dev");
+        assert_eq!(settings["region"], "eu-west-1");
+        assert_eq!(settings["s3.addressing_style"], "path");
+        assert_eq!(settings["s3.max_concurrent_requests"], "20");
+        assert_eq!(settings["duration_seconds"], "3600");
+        assert_eq!(settings["dynamodb.endpoint_url"], "http://localhost:8000");
+        assert!(!settings.contains_key("s3"));
+
+        let settings = parse_profile(CONFIG, "default");
+        assert_eq!(settings.len(), 1);
+        assert_eq!(settings["region"], "us-west-2");
+        assert!(parse_profile(CONFIG, "missing").is_empty());
+    }
+
+    #[test]
+    fn resolves_settings_from_the_profile() {
+        let config = load(&[], "dev").unwrap();
+        assert_eq!(config.get_profile(), "dev");
+        assert_eq!(config.get_region(), &Region::EuWest1);
+        assert_eq!(config.get_retry_policy().get_max_attempts(), 5);
+        assert!(config.get_rate_limiter().is_some());
+        assert!(config.get_use_fips_endpoint());
+        assert!(!config.get_use_dualstack_endpoint());
+        assert_eq!(config.get_setting("s3.addressing_style"), Some("path"));
+        assert_eq!(config.get_s3_addressing_style(), S3AddressingStyle::Path);
+        assert_eq!(
+            config.get_sts_regional_endpoints(),
+            StsRegionalEndpoints::Regional
+        );
+        assert_eq!(
+            config.get_role_session_duration(),
+            Some(Duration::from_secs(3600))
+        );
+        assert_eq!(config.get_setting("missing"), None);
+    }
+
+    #[test]
+    fn prefers_environment_variables() {
+        let config = load(
+            &[
+                ("AWS_REGION", "ap-southeast-2"),
+                ("AWS_DEFAULT_REGION", "us-east-2"),
+                ("AWS_MAX_ATTEMPTS", "2"),
+                ("AWS_RETRY_MODE", "standard"),
+                ("AWS_USE_FIPS_ENDPOINT", "false"),
+                ("AWS_USE_DUALSTACK_ENDPOINT", "TRUE"),
+            ],
+            "dev",
+        )
+        .unwrap();
+        assert_eq!(config.get_region(), &Region::ApSoutheast2);
+        assert_eq!(config.get_retry_policy().get_max_attempts(), 2);
+        assert!(config.get_rate_limiter().is_none());
+        assert!(!config.get_use_fips_endpoint());
+        assert!(config.get_use_dualstack_endpoint());
+
+        let config = load(&[("AWS_DEFAULT_REGION", "us-east-2")], "default").unwrap();
+        assert_eq!(config.get_region(), &Region::UsEast2);
+        let config = load(&[], "missing").unwrap();
+        assert_eq!(config.get_region(), &Region::UsEast1);
+    }
+
+    #[test]
+    fn resolves_endpoint_urls() {
+        let config = load(&[], "dev").unwrap();
+        assert_eq!(
+            config.get_endpoint_url("DynamoDB"),
+            Some("http://localhost:8000")
+        );
+        assert_eq!(config.get_endpoint_url("S3"), Some("http://localhost:4566"));
+        assert_eq!(
+            config.get_region_for_service("DynamoDB"),
+            Region::Custom {
+                name: "eu-west-1".to_owned(),
+                endpoint: "http://localhost:8000".to_owned(),
+            }
+        );
+
+        let config = load(
+            &[
+                ("AWS_ENDPOINT_URL", "http://localhost:9000"),
+                (
+                    "AWS_ENDPOINT_URL_ELASTIC_BEANSTALK",
+                    "http://localhost:9001",
+                ),
+            ],
+            "dev",
+        )
+        .unwrap();
+        assert_eq!(
+            config.get_endpoint_url("DynamoDB"),
+            Some("http://localhost:8000")
+        );
+        assert_eq!(config.get_endpoint_url("S3"), Some("http://localhost:9000"));
+        assert_eq!(
+            config.get_endpoint_url("Elastic Beanstalk"),
+            Some("http://localhost:9001")
+        );
+
+        let config = load(&[("AWS_IGNORE_CONFIGURED_ENDPOINT_URLS", "true")], "dev").unwrap();
+        assert_eq!(config.get_endpoint_url("DynamoDB"), None);
+        assert_eq!(config.get_region_for_service("DynamoDB"), Region::EuWest1);
+
+        let mut config = load(&[], "default").unwrap();
+        assert_eq!(config.get_endpoint_url("S3"), None);
+        let region = Region::Custom {
+            name: "us-west-2".to_owned(),
+            endpoint: "http://localhost:9000".to_owned(),
+        };
+        config.region(region.clone());
+        let config = AwsConfig {
+            endpoint_urls: vec![(
+                "AWS_ENDPOINT_URL".to_owned(),
+                "http://localhost:4566".to_owned(),
+            )]
+            .into_iter()
+            .collect(),
+            ..config
+        };
+        assert_eq!(config.get_region_for_service("S3"), region);
+    }
+
+    #[test]
+    fn sends_legacy_sts_requests_to_the_global_endpoint() {
+        let config = load(&[("AWS_STS_REGIONAL_ENDPOINTS", "legacy")], "default").unwrap();
+        assert_eq!(
+            config.get_sts_regional_endpoints(),
+            StsRegionalEndpoints::Legacy
+        );
+        assert_eq!(
+            config.get_region_for_service("STS"),
+            Region::Custom {
+                name: "us-east-1".to_owned(),
+                endpoint: "https://sts.amazonaws.com".to_owned(),
+            }
+        );
+        assert_eq!(config.get_region_for_service("S3"), Region::UsWest2);
+
+        let mut config = config;
+        config.region(Region::ApEast1);
+        assert_eq!(config.get_region_for_service("STS"), Region::ApEast1);
+        config.region(Region::UsWest2);
+        config.use_fips_endpoint(true);
+        assert_eq!(config.get_region_for_service("STS"), Region::UsWest2);
+
+        let config = load(&[], "default").unwrap();
+        assert_eq!(config.get_region_for_service("STS"), Region::UsWest2);
+    }
+
+    #[test]
+    fn rejects_invalid_settings() {
+        assert!(load(&[("AWS_REGION", "nowhere")], "dev").is_err());
+        assert!(load(&[("AWS_MAX_ATTEMPTS", "many")], "dev").is_err());
+        assert!(load(&[("AWS_RETRY_MODE", "eager")], "dev").is_err());
+        assert!(load(&[("AWS_USE_FIPS_ENDPOINT", "yes")], "dev").is_err());
+        assert!(load(&[("AWS_IGNORE_CONFIGURED_ENDPOINT_URLS", "1")], "dev").is_err());
+        assert!(load(&[("AWS_STS_REGIONAL_ENDPOINTS", "global")], "dev").is_err());
+        for (key, value) in &[("s3.addressing_style", "dns"), ("duration_seconds", "1h")] {
+            let settings = vec![(key.to_string(), value.to_string())]
+                .into_iter()
+                .collect();
+            let err = AwsConfig::from_sources(
+                "test".to_owned(),
+                &HashMap::new(),
+                settings,
+                http_config(),
+            )
+            .unwrap_err();
+            assert_eq!(err.to_string(), format!("Invalid {}: {}", key, value));
+        }
+        let err = load(&[("AWS_CA_BUNDLE", "/nonexistent/bundle.pem")], "dev").unwrap_err();
+        assert!(err.to_string().starts_with("Couldn't read the CA bundle"));
+    }
+}
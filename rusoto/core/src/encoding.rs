@@ -2,23 +2,38 @@ use crate::signature::SignedRequest;
 #[cfg(feature = "encoding")]
 use crate::signature::SignedRequestPayload;
 #[cfg(feature = "encoding")]
-use flate2::{write::GzEncoder, Compression};
+use crate::{request::HttpResponse, stream::ByteStream};
 #[cfg(feature = "encoding")]
-use std::io::Write;
+use bytes::Bytes;
+#[cfg(feature = "encoding")]
+use flate2::write::{GzDecoder, GzEncoder, ZlibDecoder};
+#[cfg(feature = "encoding")]
+use flate2::Compression;
+#[cfg(feature = "encoding")]
+use futures::{ready, Stream};
+#[cfg(feature = "encoding")]
+use http::header::{CONTENT_ENCODING, CONTENT_LENGTH};
+#[cfg(feature = "encoding")]
+use std::io::{self, Write};
+#[cfg(feature = "encoding")]
+use std::pin::Pin;
+#[cfg(feature = "encoding")]
+use std::task::{Context, Poll};
 
 // Default compression level for gzip defined same as flate2
 pub const DEFAULT_GZIP_COMPRESSION_LEVEL: u32 = 6;
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, PartialEq)]
 pub enum ContentEncoding {
     /// Indicates the identity function (i.e., no compression or modification)
     Identity,
 
     /// Gzip encoding uses flate2 library's GzEncoder internally to compress request payloads.
-    /// Streaming requests are not supported yet.
+    /// Streaming payloads are compressed while they are sent, without a `Content-Length`,
+    /// so they are sent with chunked transfer encoding.
     ///
     /// First parameter is for minimum payload. If request payload length is lesser than it
-    /// no compression will be performed.
+    /// no compression will be performed. Streams without a size hint are always compressed.
     ///
     /// Second parameter is the compression level for gzip on a scale of 0-9 where 0 means
     /// "no compression" and 9 means "take as long as you'd like".
@@ -42,6 +57,10 @@ impl ContentEncoding {
             }
             #[cfg(feature = "encoding")]
             ContentEncoding::Gzip(min_payload_size, level) => {
+                // Already encoded, for instance an S3 object uploaded with its `Content-Encoding`
+                if request.headers().contains_key("content-encoding") {
+                    return;
+                }
                 match request.payload {
                     None => return,
                     Some(SignedRequestPayload::Buffer(ref payload)) => {
@@ -66,8 +85,23 @@ impl ContentEncoding {
                         request.payload = Some(SignedRequestPayload::Buffer(payload_compressed));
                     }
                     Some(SignedRequestPayload::Stream(ref stream)) => {
-                        // Stream compression is not supported yet
-                        return;
+                        if let (Some(min_payload_size), Some(size)) =
+                            (min_payload_size, stream.size_hint())
+                        {
+                            if size < *min_payload_size {
+                                return;
+                            }
+                        }
+                        let stream = match request.payload.take() {
+                            Some(SignedRequestPayload::Stream(stream)) => stream,
+                            _ => unreachable!(),
+                        };
+                        let encoder = GzEncoder::new(Vec::new(), Compression::new(*level));
+                        request.set_payload_stream(ByteStream::new(CodedStream {
+                            body: stream,
+                            coder: Some(encoder),
+                        }));
+                        request.remove_header("content-length");
                     }
                 };
                 request.add_header("Content-Encoding", "gzip");
@@ -75,3 +109,216 @@ impl ContentEncoding {
         }
     }
 }
+
+/// Decompresses the body of a response with a `Content-Encoding` of `gzip` or `deflate`
+/// while it is read, removing the `Content-Encoding` and `Content-Length` headers.
+/// Responses with any other encoding are returned untouched.
+#[cfg(feature = "encoding")]
+pub(crate) fn decode_response(mut response: HttpResponse) -> HttpResponse {
+    let decoder = match response.headers.get(CONTENT_ENCODING) {
+        Some(encoding) => match encoding.trim().to_ascii_lowercase().as_str() {
+            "gzip" | "x-gzip" => Decoder::Gzip(GzDecoder::new(Vec::new())),
+            "deflate" => Decoder::Deflate(ZlibDecoder::new(Vec::new())),
+            _ => return response,
+        },
+        None => return response,
+    };
+    response.headers.remove(CONTENT_ENCODING);
+    response.headers.remove(CONTENT_LENGTH);
+    let body = std::mem::replace(&mut response.body, ByteStream::from(Vec::new()));
+    response.body = ByteStream::new(CodedStream {
+        body,
+        coder: Some(decoder),
+    });
+    response
+}
+
+#[cfg(feature = "encoding")]
+enum Decoder {
+    Gzip(GzDecoder<Vec<u8>>),
+    Deflate(ZlibDecoder<Vec<u8>>),
+}
+
+/// Compresses or decompresses a body chunk by chunk.
+#[cfg(feature = "encoding")]
+trait Coder {
+    /// Processes a chunk, returning the bytes output so far.
+    fn code(&mut self, chunk: &[u8]) -> io::Result<Bytes>;
+
+    /// Checks the body is complete, returning the remaining bytes.
+    fn finish(self) -> io::Result<Bytes>;
+}
+
+#[cfg(feature = "encoding")]
+impl Coder for GzEncoder<Vec<u8>> {
+    fn code(&mut self, chunk: &[u8]) -> io::Result<Bytes> {
+        self.write_all(chunk)?;
+        Ok(Bytes::from(std::mem::take(self.get_mut())))
+    }
+
+    fn finish(self) -> io::Result<Bytes> {
+        GzEncoder::finish(self).map(Bytes::from)
+    }
+}
+
+#[cfg(feature = "encoding")]
+impl Coder for Decoder {
+    fn code(&mut self, chunk: &[u8]) -> io::Result<Bytes> {
+        let decoded = match self {
+            Decoder::Gzip(decoder) => {
+                decoder.write_all(chunk)?;
+                decoder.get_mut()
+            }
+            Decoder::Deflate(decoder) => {
+                decoder.write_all(chunk)?;
+                decoder.get_mut()
+            }
+        };
+        Ok(Bytes::from(std::mem::take(decoded)))
+    }
+
+    fn finish(self) -> io::Result<Bytes> {
+        let decoded = match self {
+            Decoder::Gzip(decoder) => decoder.finish()?,
+            Decoder::Deflate(decoder) => decoder.finish()?,
+        };
+        Ok(Bytes::from(decoded))
+    }
+}
+
+#[cfg(feature = "encoding")]
+struct CodedStream<C> {
+    body: ByteStream,
+    coder: Option<C>,
+}
+
+#[cfg(feature = "encoding")]
+impl<C: Coder + Unpin> Stream for CodedStream<C> {
+    type Item = Result<Bytes, io::Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = &mut *self;
+        loop {
+            let coder = match this.coder.as_mut() {
+                Some(coder) => coder,
+                None => return Poll::Ready(None),
+            };
+            match ready!(Pin::new(&mut this.body).poll_next(cx)) {
+                Some(Ok(chunk)) => match coder.code(&chunk) {
+                    Ok(output) if output.is_empty() => continue,
+                    result => return Poll::Ready(Some(result)),
+                },
+                Some(Err(err)) => return Poll::Ready(Some(Err(err))),
+                None => {
+                    let coder = this.coder.take().expect("coder is present");
+                    match coder.finish() {
+                        Ok(output) if output.is_empty() => return Poll::Ready(None),
+                        result => return Poll::Ready(Some(result)),
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "encoding"))]
+mod tests {
+    use super::*;
+    use crate::request::BufferedHttpResponse;
+    use flate2::write::ZlibEncoder;
+
+    fn response(encoding: &str, chunks: Vec<Vec<u8>>) -> HttpResponse {
+        let headers = [("content-encoding", encoding), ("content-length", "42")];
+        let chunks = chunks.into_iter().map(|chunk| Ok(Bytes::from(chunk)));
+        HttpResponse {
+            body: ByteStream::new(futures::stream::iter(chunks)),
+            ..BufferedHttpResponse::for_test(200, &headers, "").into_http_response()
+        }
+    }
+
+    fn gzip(data: &[u8]) -> Vec<u8> {
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(data).unwrap();
+        encoder.finish().unwrap()
+    }
+
+    #[tokio::test]
+    async fn encodes_streaming_payloads() {
+        let chunks = vec![Ok(Bytes::from("streamed ")), Ok(Bytes::from("payload"))];
+        let mut request = SignedRequest::new("POST", "logs", &crate::Region::UsEast1, "/");
+        request.add_header("Content-Length", "16");
+        request.set_payload_stream(ByteStream::new(futures::stream::iter(chunks)));
+        ContentEncoding::Gzip(None, DEFAULT_GZIP_COMPRESSION_LEVEL).encode(&mut request);
+
+        assert_eq!(
+            request.headers()["content-encoding"],
+            vec![b"gzip".to_vec()]
+        );
+        assert!(!request.headers().contains_key("content-length"));
+        let body = match request.payload.take() {
+            Some(SignedRequestPayload::Stream(stream)) => stream,
+            _ => panic!("payload is not a stream"),
+        };
+        let mut compressed = response("gzip", Vec::new());
+        compressed.body = body;
+        let mut decompressed = decode_response(compressed);
+        assert_eq!(
+            decompressed.buffer().await.unwrap().body_as_str(),
+            "streamed payload"
+        );
+    }
+
+    #[test]
+    fn skips_small_and_encoded_payloads() {
+        let encoding = ContentEncoding::Gzip(Some(1024), DEFAULT_GZIP_COMPRESSION_LEVEL);
+        let stream = futures::stream::iter(vec![Ok(Bytes::from("small"))]);
+        let mut request = SignedRequest::new("POST", "logs", &crate::Region::UsEast1, "/");
+        request.set_payload_stream(ByteStream::new_with_size(stream, 5));
+        encoding.encode(&mut request);
+        assert!(!request.headers().contains_key("content-encoding"));
+
+        let encoding = ContentEncoding::Gzip(None, DEFAULT_GZIP_COMPRESSION_LEVEL);
+        let mut request = SignedRequest::new("POST", "logs", &crate::Region::UsEast1, "/");
+        request.set_payload(Some(vec![b'a'; 100]));
+        encoding.encode(&mut request);
+        encoding.encode(&mut request);
+        assert_eq!(request.headers()["content-encoding"].len(), 1);
+    }
+
+    #[tokio::test]
+    async fn decodes_gzip_responses_in_chunks() {
+        let compressed = gzip(b"hello, compressed world");
+        let (first, second) = compressed.split_at(7);
+        let mut response = decode_response(response("gzip", vec![first.to_vec(), second.to_vec()]));
+
+        assert!(response.headers.get(CONTENT_ENCODING).is_none());
+        assert!(response.headers.get(CONTENT_LENGTH).is_none());
+        let buffered = response.buffer().await.unwrap();
+        assert_eq!(buffered.body_as_str(), "hello, compressed world");
+    }
+
+    #[tokio::test]
+    async fn decodes_deflate_responses() {
+        let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(b"deflated").unwrap();
+        let compressed = encoder.finish().unwrap();
+        let mut response = decode_response(response("Deflate", vec![compressed]));
+        assert_eq!(response.buffer().await.unwrap().body_as_str(), "deflated");
+    }
+
+    #[tokio::test]
+    async fn leaves_other_responses_untouched() {
+        let mut response = decode_response(response("br", vec![b"raw".to_vec()]));
+        assert_eq!(response.headers.get(CONTENT_ENCODING).unwrap(), "br");
+        assert_eq!(response.headers.get(CONTENT_LENGTH).unwrap(), "42");
+        assert_eq!(response.buffer().await.unwrap().body_as_str(), "raw");
+    }
+
+    #[tokio::test]
+    async fn fails_on_truncated_bodies() {
+        let compressed = gzip(b"truncated");
+        let truncated = compressed[..compressed.len() - 4].to_vec();
+        let mut response = decode_response(response("gzip", vec![truncated]));
+        assert!(response.buffer().await.is_err());
+    }
+}
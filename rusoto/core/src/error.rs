@@ -2,23 +2,26 @@ use std::error::Error;
 use std::fmt;
 use std::io;
 
+use http::{HeaderMap, StatusCode};
+
 use crate::credential::CredentialsError;
 
-use super::proto::xml::util::XmlParseError;
+use super::proto::xml::util::{find_element_text, XmlParseError};
 use super::request::{BufferedHttpResponse, HttpDispatchError};
 use crate::client::SignAndDispatchError;
 
 /// Generic error type returned by all rusoto requests.
 #[derive(Debug, PartialEq)]
 pub enum RusotoError<E> {
-    /// A service-specific error occurred.
-    Service(E),
+    /// A service-specific error occurred.  The metadata of the error response is provided.
+    Service(E, ErrorMetadata),
     /// An error occurred dispatching the HTTP request
     HttpDispatch(HttpDispatchError),
     /// An error was encountered with AWS credentials.
     Credentials(CredentialsError),
-    /// A validation error occurred.  Details from AWS are provided.
-    Validation(String),
+    /// A validation error occurred.  Details from AWS and the metadata of the error response
+    /// are provided.
+    Validation(String, ErrorMetadata),
     /// An error occurred parsing the response payload.
     ParseError(String),
     /// An unknown error occurred.  The raw HTTP response is provided.
@@ -33,6 +36,95 @@ pub type RusotoResult<T, E> = Result<T, RusotoError<E>>;
 /// Header used by AWS on responses to identify the request
 pub const AWS_REQUEST_ID_HEADER: &str = "x-amzn-requestid";
 
+/// Header used by S3 on responses to identify the request
+const S3_REQUEST_ID_HEADER: &str = "x-amz-request-id";
+
+/// Header used by S3 on responses to identify the host that handled the request
+const EXTENDED_REQUEST_ID_HEADER: &str = "x-amz-id-2";
+
+/// Details of an error response, to be given to AWS Support along with the request id.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ErrorMetadata {
+    /// The error code, for instance `"NoSuchKey"`, if the response could be parsed
+    pub code: Option<String>,
+    /// The error message, if the response could be parsed
+    pub message: Option<String>,
+    /// The id AWS assigned to the request
+    pub request_id: Option<String>,
+    /// The extended request id sent by S3 in the `x-amz-id-2` header
+    pub extended_request_id: Option<String>,
+    /// The status code of the response
+    pub status: StatusCode,
+    /// The headers of the response
+    pub headers: HeaderMap<String>,
+}
+
+impl ErrorMetadata {
+    /// Collects the metadata of an error response, leaving the code and message unset.
+    ///
+    /// The request id is read from the `x-amzn-requestid` or `x-amz-request-id` headers, or
+    /// from the `RequestId` element of XML bodies when neither is present.
+    pub fn from_response(response: &BufferedHttpResponse) -> ErrorMetadata {
+        let header = |name| response.headers.get(name).cloned();
+        ErrorMetadata {
+            code: None,
+            message: None,
+            request_id: header(AWS_REQUEST_ID_HEADER)
+                .or_else(|| header(S3_REQUEST_ID_HEADER))
+                .or_else(|| find_element_text(&response.body, &["RequestId", "RequestID"])),
+            extended_request_id: header(EXTENDED_REQUEST_ID_HEADER),
+            status: response.status,
+            headers: response.headers.clone(),
+        }
+    }
+}
+
+impl<E> RusotoError<E> {
+    /// Creates a `Service` error from an error response, used by the generated
+    /// `from_response` functions.
+    pub fn service_error<F>(
+        response: &BufferedHttpResponse,
+        code: &str,
+        message: String,
+        variant: F,
+    ) -> RusotoError<E>
+    where
+        F: FnOnce(String) -> E,
+    {
+        let mut metadata = ErrorMetadata::from_response(response);
+        metadata.code = Some(code.to_owned());
+        metadata.message = Some(message.clone());
+        RusotoError::Service(variant(message), metadata)
+    }
+
+    /// Creates a `Validation` error from a `ValidationException` response, used by the
+    /// generated `from_response` functions.
+    pub fn validation_error(response: &BufferedHttpResponse, message: String) -> RusotoError<E> {
+        let mut metadata = ErrorMetadata::from_response(response);
+        metadata.code = Some("ValidationException".to_owned());
+        metadata.message = Some(message.clone());
+        RusotoError::Validation(message, metadata)
+    }
+
+    /// Returns the metadata of the error response, for `Service`, `Validation` and `Unknown`
+    /// errors.
+    pub fn metadata(&self) -> Option<ErrorMetadata> {
+        match *self {
+            RusotoError::Service(_, ref metadata) | RusotoError::Validation(_, ref metadata) => {
+                Some(metadata.clone())
+            }
+            RusotoError::Unknown(ref response) => Some(ErrorMetadata::from_response(response)),
+            _ => None,
+        }
+    }
+
+    /// Returns the id AWS assigned to the request, for `Service`, `Validation` and `Unknown`
+    /// errors.
+    pub fn request_id(&self) -> Option<String> {
+        self.metadata().and_then(|metadata| metadata.request_id)
+    }
+}
+
 impl<E> From<XmlParseError> for RusotoError<E> {
     fn from(err: XmlParseError) -> Self {
         let XmlParseError(message) = err;
@@ -76,15 +168,15 @@ impl<E> From<io::Error> for RusotoError<E> {
 impl<E: Error + 'static> fmt::Display for RusotoError<E> {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         match *self {
-            RusotoError::Service(ref err) => write!(f, "{}", err),
-            RusotoError::Validation(ref cause) => write!(f, "{}", cause),
+            RusotoError::Service(ref err, _) => write!(f, "{}", err),
+            RusotoError::Validation(ref cause, _) => write!(f, "{}", cause),
             RusotoError::Credentials(ref err) => write!(f, "{}", err),
             RusotoError::HttpDispatch(ref dispatch_error) => write!(f, "{}", dispatch_error),
             RusotoError::ParseError(ref cause) => write!(f, "{}", cause),
             RusotoError::Unknown(ref cause) => write!(
                 f,
                 "Request ID: {:?} Body: {}",
-                cause.headers.get(AWS_REQUEST_ID_HEADER),
+                self.request_id(),
                 cause.body_as_str()
             ),
             RusotoError::Blocking => write!(f, "Failed to run blocking future"),
@@ -95,10 +187,84 @@ impl<E: Error + 'static> fmt::Display for RusotoError<E> {
 impl<E: Error + 'static> Error for RusotoError<E> {
     fn source(&self) -> Option<&(dyn Error + 'static)> {
         match *self {
-            RusotoError::Service(ref err) => Some(err),
+            RusotoError::Service(ref err, _) => Some(err),
             RusotoError::Credentials(ref err) => Some(err),
             RusotoError::HttpDispatch(ref err) => Some(err),
             _ => None,
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn collects_service_error_metadata() {
+        let response = BufferedHttpResponse::for_test(
+            404,
+            &[
+                ("x-amz-request-id", "4442587FB7D0A2F9"),
+                ("x-amz-id-2", "host-id"),
+            ],
+            "<Error><Code>NoSuchKey</Code></Error>",
+        );
+        let error = RusotoError::service_error(
+            &response,
+            "NoSuchKey",
+            "The specified key does not exist.".to_owned(),
+            |message| message,
+        );
+        let metadata = error.metadata().unwrap();
+        assert_eq!(metadata.code.as_deref(), Some("NoSuchKey"));
+        assert_eq!(
+            metadata.message.as_deref(),
+            Some("The specified key does not exist.")
+        );
+        assert_eq!(metadata.request_id.as_deref(), Some("4442587FB7D0A2F9"));
+        assert_eq!(metadata.extended_request_id.as_deref(), Some("host-id"));
+        assert_eq!(metadata.status, StatusCode::NOT_FOUND);
+        assert_eq!(metadata.headers.len(), 2);
+        match error {
+            RusotoError::Service(ref message, _) => {
+                assert_eq!(message, "The specified key does not exist.")
+            }
+            _ => panic!("Expected a service error"),
+        }
+    }
+
+    #[test]
+    fn reads_request_ids() {
+        let unknown: RusotoError<String> = RusotoError::Unknown(BufferedHttpResponse::for_test(
+            404,
+            &[("x-amzn-requestid", "c0b6d8a4")],
+            "{\"message\": \"Unknown\"}",
+        ));
+        assert_eq!(unknown.request_id().as_deref(), Some("c0b6d8a4"));
+        assert_eq!(unknown.metadata().unwrap().code, None);
+
+        let query = BufferedHttpResponse::for_test(
+            404,
+            &[],
+            "<ErrorResponse><Error><Code>Throttling</Code></Error>\
+             <RequestId>42d59b56-7407-4c4a-be0f-4c88daeea257</RequestId></ErrorResponse>",
+        );
+        assert_eq!(
+            ErrorMetadata::from_response(&query).request_id.as_deref(),
+            Some("42d59b56-7407-4c4a-be0f-4c88daeea257")
+        );
+        let ec2 = BufferedHttpResponse::for_test(
+            404,
+            &[],
+            "<Response><RequestID>ea966190</RequestID></Response>",
+        );
+        assert_eq!(
+            ErrorMetadata::from_response(&ec2).request_id.as_deref(),
+            Some("ea966190")
+        );
+
+        let dispatch: RusotoError<String> =
+            RusotoError::HttpDispatch(HttpDispatchError::new("boom".to_owned()));
+        assert_eq!(dispatch.metadata(), None);
+    }
+}
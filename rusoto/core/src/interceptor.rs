@@ -0,0 +1,38 @@
+//! Hooks into the requests sent by a `Client`.
+
+use crate::request::HttpResponse;
+use crate::signature::SignedRequest;
+
+/// Hooks called by a `Client` at each step of sending a request.
+///
+/// Interceptors are registered with `Client::with_interceptor` and called in registration
+/// order for every attempt, including retries. All hooks do nothing by default.
+///
+/// ```
+/// use rusoto_core::signature::SignedRequest;
+/// use rusoto_core::{Client, Interceptor};
+///
+/// struct TenantHeader(String);
+///
+/// impl Interceptor for TenantHeader {
+///     fn before_sign(&self, request: &mut SignedRequest) {
+///         request.add_header("x-tenant-id", &self.0);
+///     }
+/// }
+///
+/// let client = Client::shared().with_interceptor(TenantHeader("acme".to_owned()));
+/// ```
+pub trait Interceptor: Send + Sync {
+    /// Called before the request is signed. Headers and parameters added here are signed.
+    fn before_sign(&self, _request: &mut SignedRequest) {}
+
+    /// Called once the request is signed, or complemented if the client is not signing.
+    fn after_sign(&self, _request: &SignedRequest) {}
+
+    /// Called right before the request is dispatched. Changes made here are not covered by
+    /// the signature.
+    fn before_dispatch(&self, _request: &mut SignedRequest) {}
+
+    /// Called when a response was received, before its body is read.
+    fn after_response(&self, _response: &HttpResponse) {}
+}
@@ -19,15 +19,22 @@
 //! Rusoto is an [AWS](https://aws.amazon.com/) SDK for Rust.
 //! A high level overview is available in `README.md` at <https://github.com/rusoto/rusoto>.
 
-#[cfg(feature = "rustls")]
-use hyper_rustls as tls;
-#[cfg(feature = "native-tls")]
-use hyper_tls as tls;
-
+#[cfg(feature = "blocking")]
+mod blocking;
 mod client;
+mod clock_skew;
 mod error;
+mod interceptor;
+mod metrics;
+mod options;
+mod proxy;
+mod rate_limit;
+mod retry;
 mod stream;
+mod tls;
+mod trace;
 
+pub mod config;
 pub mod event_stream;
 pub mod param;
 #[doc(hidden)]
@@ -36,7 +43,10 @@ pub mod request;
 #[doc(hidden)]
 pub mod signature;
 
+#[cfg(feature = "blocking")]
+pub use crate::blocking::BlockingRuntime;
 pub use crate::client::Client;
+pub use crate::config::AwsConfig;
 #[doc(hidden)]
 pub mod encoding;
 #[doc(hidden)]
@@ -44,8 +54,16 @@ pub mod proto;
 #[doc(hidden)]
 pub mod serialization;
 
-pub use crate::error::{RusotoError, RusotoResult};
+pub use crate::error::{ErrorMetadata, RusotoError, RusotoResult};
+pub use crate::interceptor::Interceptor;
+pub use crate::metrics::{CallErrorKind, CallMetrics, MetricsSink};
+pub use crate::options::CallOptions;
+pub use crate::proxy::ProxyConfig;
+pub use crate::rate_limit::RateLimiter;
 pub use crate::region::Region;
-pub use crate::request::{DispatchSignedRequest, HttpClient, HttpConfig, HttpDispatchError};
+pub use crate::request::{
+    DispatchSignedRequest, HttpClient, HttpConfig, HttpDispatchError, HttpDispatchErrorKind,
+};
+pub use crate::retry::RetryPolicy;
 pub use crate::stream::ByteStream;
 pub use rusoto_credential as credential;
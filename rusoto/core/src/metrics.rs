@@ -0,0 +1,337 @@
+//! Metrics describing the calls made by a `Client`.
+
+use std::io;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::{Duration, Instant};
+
+use bytes::Bytes;
+use futures::{Stream, StreamExt};
+use http::StatusCode;
+
+use crate::client::SignAndDispatchError;
+use crate::request::HttpResponse;
+use crate::signature::{SignedRequest, SignedRequestPayload};
+use crate::stream::ByteStream;
+
+/// Receives the metrics of every call made by a `Client`.
+///
+/// Sinks are registered with `Client::with_metrics_sink`. Clients without a sink do not
+/// collect any metrics. Closures taking a `&CallMetrics` are sinks too:
+///
+/// ```
+/// use rusoto_core::{CallMetrics, Client};
+///
+/// let client = Client::shared().with_metrics_sink(|metrics: &CallMetrics| {
+///     println!(
+///         "{}.{}: {:?} in {:?}",
+///         metrics.service,
+///         metrics.operation.as_deref().unwrap_or("?"),
+///         metrics.status,
+///         metrics.latency
+///     );
+/// });
+/// ```
+pub trait MetricsSink: Send + Sync {
+    /// Called once for every call, once the body of the response was read or dropped, or
+    /// after the last attempt failed.
+    fn record(&self, metrics: &CallMetrics);
+}
+
+impl<F> MetricsSink for F
+where
+    F: Fn(&CallMetrics) + Send + Sync,
+{
+    fn record(&self, metrics: &CallMetrics) {
+        self(metrics)
+    }
+}
+
+/// The metrics of a call, covering all of its attempts.
+#[derive(Clone, Debug, PartialEq)]
+pub struct CallMetrics {
+    /// The signing name of the service, for instance `"sqs"`
+    pub service: String,
+    /// The name of the operation, as in the API reference
+    pub operation: Option<String>,
+    /// Time elapsed between the start of the call and the response headers of the last attempt
+    pub latency: Duration,
+    /// The status code of the last response, if any
+    pub status: Option<StatusCode>,
+    /// Why the call failed, if it did
+    pub error: Option<CallErrorKind>,
+    /// The number of attempts retried
+    pub retries: u32,
+    /// The number of payload bytes sent by all attempts, after compression
+    pub request_bytes: u64,
+    /// The number of body bytes received by all attempts, before decompression
+    pub response_bytes: u64,
+}
+
+/// The reason a call failed.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum CallErrorKind {
+    /// No credentials could be obtained
+    Credentials,
+    /// The request could not be dispatched or no response was received
+    Dispatch,
+    /// The service responded with an unsuccessful status code
+    Service,
+}
+
+/// Measures a call until its result is known.
+pub(crate) struct CallTimer {
+    started: Instant,
+    service: String,
+    operation: Option<String>,
+    counts: ByteCounts,
+}
+
+impl CallTimer {
+    pub(crate) fn start(request: &SignedRequest) -> CallTimer {
+        CallTimer {
+            started: Instant::now(),
+            service: request.service.clone(),
+            operation: request.operation.clone(),
+            counts: ByteCounts::default(),
+        }
+    }
+
+    /// The counts of the bytes sent and received by the attempts of the call.
+    pub(crate) fn counts(&self) -> &ByteCounts {
+        &self.counts
+    }
+
+    /// Reports the metrics of the call to the sink, once the body of the response was read
+    /// or dropped.
+    pub(crate) fn finish(
+        self,
+        result: Result<HttpResponse, SignAndDispatchError>,
+        attempts: u32,
+        sink: Arc<dyn MetricsSink>,
+    ) -> Result<HttpResponse, SignAndDispatchError> {
+        let metrics = self.metrics(&result, attempts);
+        match result {
+            Ok(mut response) => {
+                let body = std::mem::replace(&mut response.body, ByteStream::from(Vec::new()));
+                let size_hint = body.size_hint();
+                let body = ReportingBody {
+                    body,
+                    report: Some((metrics, self.counts, sink)),
+                };
+                response.body = match size_hint {
+                    Some(size) => ByteStream::new_with_size(body, size),
+                    None => ByteStream::new(body),
+                };
+                Ok(response)
+            }
+            Err(err) => {
+                sink.record(&self.counts.complete(metrics));
+                Err(err)
+            }
+        }
+    }
+
+    fn metrics(
+        &self,
+        result: &Result<HttpResponse, SignAndDispatchError>,
+        attempts: u32,
+    ) -> CallMetrics {
+        let (status, error) = match result {
+            Ok(response) => {
+                let error = if response.status.is_success() {
+                    None
+                } else {
+                    Some(CallErrorKind::Service)
+                };
+                (Some(response.status), error)
+            }
+            Err(SignAndDispatchError::Credentials(_)) => (None, Some(CallErrorKind::Credentials)),
+            Err(SignAndDispatchError::Dispatch(_)) => (None, Some(CallErrorKind::Dispatch)),
+        };
+        CallMetrics {
+            service: self.service.clone(),
+            operation: self.operation.clone(),
+            latency: self.started.elapsed(),
+            status,
+            error,
+            retries: attempts - 1,
+            request_bytes: 0,
+            response_bytes: 0,
+        }
+    }
+}
+
+/// Counts the bytes of the payloads sent and of the bodies received by the attempts of a
+/// call.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct ByteCounts {
+    sent: Arc<AtomicU64>,
+    received: Arc<AtomicU64>,
+}
+
+impl ByteCounts {
+    /// Counts the payload of a request about to be dispatched, streams as they are read.
+    pub(crate) fn count_request(&self, request: &mut SignedRequest) {
+        request.payload = match request.payload.take() {
+            Some(SignedRequestPayload::Buffer(payload)) => {
+                self.sent.fetch_add(payload.len() as u64, Ordering::Relaxed);
+                Some(SignedRequestPayload::Buffer(payload))
+            }
+            Some(SignedRequestPayload::Stream(stream)) => Some(SignedRequestPayload::Stream(
+                counted(stream, self.sent.clone()),
+            )),
+            None => None,
+        };
+    }
+
+    /// Counts the body of a response as it is read.
+    pub(crate) fn count_response(&self, response: &mut HttpResponse) {
+        let body = std::mem::replace(&mut response.body, ByteStream::from(Vec::new()));
+        response.body = counted(body, self.received.clone());
+    }
+
+    fn complete(&self, metrics: CallMetrics) -> CallMetrics {
+        CallMetrics {
+            request_bytes: self.sent.load(Ordering::Relaxed),
+            response_bytes: self.received.load(Ordering::Relaxed),
+            ..metrics
+        }
+    }
+}
+
+fn counted(stream: ByteStream, counter: Arc<AtomicU64>) -> ByteStream {
+    let size_hint = stream.size_hint();
+    let stream = stream.inspect(move |chunk| {
+        if let Ok(chunk) = chunk {
+            counter.fetch_add(chunk.len() as u64, Ordering::Relaxed);
+        }
+    });
+    match size_hint {
+        Some(size) => ByteStream::new_with_size(stream, size),
+        None => ByteStream::new(stream),
+    }
+}
+
+/// A response body reporting the metrics of its call when it ends or is dropped.
+struct ReportingBody {
+    body: ByteStream,
+    report: Option<(CallMetrics, ByteCounts, Arc<dyn MetricsSink>)>,
+}
+
+impl ReportingBody {
+    fn report(&mut self) {
+        if let Some((metrics, counts, sink)) = self.report.take() {
+            sink.record(&counts.complete(metrics));
+        }
+    }
+}
+
+impl Stream for ReportingBody {
+    type Item = Result<Bytes, io::Error>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let poll = self.body.poll_next_unpin(cx);
+        if let Poll::Ready(None) | Poll::Ready(Some(Err(_))) = poll {
+            self.report();
+        }
+        poll
+    }
+}
+
+impl Drop for ReportingBody {
+    fn drop(&mut self) {
+        self.report();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+    use crate::request::{BufferedHttpResponse, HttpDispatchError};
+    use crate::signature::SignedRequest;
+    use crate::Region;
+
+    fn recorder() -> (Arc<dyn MetricsSink>, Arc<Mutex<Vec<CallMetrics>>>) {
+        let recorded = Arc::new(Mutex::new(Vec::new()));
+        let sink = recorded.clone();
+        let sink = move |metrics: &CallMetrics| sink.lock().unwrap().push(metrics.clone());
+        (Arc::new(sink), recorded)
+    }
+
+    #[tokio::test]
+    async fn measures_successful_calls_once_the_body_is_read() {
+        let mut request = SignedRequest::new("POST", "sqs", &Region::UsEast1, "/");
+        request.set_operation("SendMessage");
+        request.set_payload(Some(b"Action=SendMessage".to_vec()));
+        let (sink, recorded) = recorder();
+
+        let timer = CallTimer::start(&request);
+        timer.counts().count_request(&mut request);
+        let mut response =
+            BufferedHttpResponse::for_test(200, &[], "<SendMessageResponse/>").into_http_response();
+        timer.counts().count_response(&mut response);
+        let mut response = timer.finish(Ok(response), 1, sink).unwrap();
+        assert!(recorded.lock().unwrap().is_empty());
+        response.buffer().await.unwrap();
+
+        let recorded = recorded.lock().unwrap();
+        assert_eq!(recorded.len(), 1);
+        assert_eq!(recorded[0].service, "sqs");
+        assert_eq!(recorded[0].operation.as_deref(), Some("SendMessage"));
+        assert_eq!(recorded[0].status, Some(StatusCode::OK));
+        assert_eq!(recorded[0].error, None);
+        assert_eq!(recorded[0].retries, 0);
+        assert_eq!(recorded[0].request_bytes, 18);
+        assert_eq!(recorded[0].response_bytes, 22);
+    }
+
+    #[tokio::test]
+    async fn counts_the_bytes_of_streams_as_they_are_read() {
+        let mut request = SignedRequest::new("PUT", "s3", &Region::UsEast1, "/bucket/key");
+        let chunks = vec![
+            Ok(Bytes::from_static(b"abc")),
+            Ok(Bytes::from_static(b"de")),
+        ];
+        request.set_payload_stream(ByteStream::new(futures::stream::iter(chunks)));
+        let (sink, recorded) = recorder();
+
+        let timer = CallTimer::start(&request);
+        timer.counts().count_request(&mut request);
+        match request.payload.take() {
+            Some(SignedRequestPayload::Stream(stream)) => {
+                stream.map(|chunk| chunk.unwrap()).collect::<Vec<_>>().await;
+            }
+            _ => panic!("payload is not a stream"),
+        }
+        let mut response =
+            BufferedHttpResponse::for_test(503, &[], "unavailable").into_http_response();
+        timer.counts().count_response(&mut response);
+        drop(timer.finish(Ok(response), 3, sink));
+
+        let recorded = recorded.lock().unwrap();
+        assert_eq!(recorded[0].error, Some(CallErrorKind::Service));
+        assert_eq!(recorded[0].retries, 2);
+        assert_eq!(recorded[0].request_bytes, 5);
+        assert_eq!(recorded[0].response_bytes, 0);
+    }
+
+    #[test]
+    fn measures_failed_calls() {
+        let request = SignedRequest::new("GET", "s3", &Region::UsEast1, "/");
+        let (sink, recorded) = recorder();
+        let error = SignAndDispatchError::Dispatch(HttpDispatchError::new("boom".to_owned()));
+
+        let result = CallTimer::start(&request).finish(Err(error), 1, sink);
+        assert!(result.is_err());
+
+        let recorded = recorded.lock().unwrap();
+        assert_eq!(recorded[0].status, None);
+        assert_eq!(recorded[0].error, Some(CallErrorKind::Dispatch));
+        assert_eq!(recorded[0].request_bytes, 0);
+    }
+}
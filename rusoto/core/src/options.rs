@@ -0,0 +1,197 @@
+//! Options overriding the client configuration for individual calls.
+
+use std::time::Duration;
+
+use crate::encoding::ContentEncoding;
+use crate::region::Region;
+use crate::retry::RetryPolicy;
+use crate::signature::{PayloadSigning, SignedRequest, SigningAlgorithm, SigningError};
+
+use log::warn;
+
+/// Options applied to every call made through a client returned by `with_options`.
+///
+/// Every generated client has a `with_options` method returning a copy of the client using
+/// these options, leaving the original client untouched:
+///
+/// ```
+/// use std::time::Duration;
+/// use rusoto_core::{CallOptions, Client};
+///
+/// let mut options = CallOptions::new();
+/// options.timeout(Duration::from_secs(2));
+/// options.header("x-amz-request-payer", "requester");
+///
+/// let client = Client::shared().with_options(options);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CallOptions {
+    timeout: Option<Duration>,
+    retry_policy: Option<RetryPolicy>,
+    headers: Vec<(String, String)>,
+    endpoint: Option<String>,
+    decompress_response: Option<bool>,
+    content_encoding: Option<ContentEncoding>,
+    payload_signing: Option<PayloadSigning>,
+}
+
+impl CallOptions {
+    /// Create a new CallOptions not overriding anything.
+    pub fn new() -> CallOptions {
+        CallOptions::default()
+    }
+
+    /// Sets a timeout for each attempt, covering fetching credentials and waiting for the
+    /// response headers.
+    pub fn timeout(&mut self, timeout: Duration) {
+        self.timeout = Some(timeout);
+    }
+
+    /// Overrides the retry policy of the client.
+    pub fn retry_policy(&mut self, retry_policy: RetryPolicy) {
+        self.retry_policy = Some(retry_policy);
+    }
+
+    /// Adds a header to every request, replacing any value set by the client.
+    pub fn header<K, V>(&mut self, key: K, value: V)
+    where
+        K: Into<String>,
+        V: Into<String>,
+    {
+        self.headers.push((key.into(), value.into()));
+    }
+
+    /// Sends requests to the given endpoint, for instance `"http://localhost:4566"`.
+    /// Requests are still signed for the region of the client.
+    pub fn endpoint<E: Into<String>>(&mut self, endpoint: E) {
+        self.endpoint = Some(endpoint.into());
+    }
+
+    /// Compresses the payload of the requests with the given encoding, instead of the
+    /// encoding of the client. Use it to only compress the payloads of the operations
+    /// accepting a `Content-Encoding` of `gzip`, like CloudWatch `PutMetricData`.
+    pub fn content_encoding(&mut self, content_encoding: ContentEncoding) {
+        self.content_encoding = Some(content_encoding);
+    }
+
+    /// Overrides how streaming payloads are signed, for instance to sign the body of an S3
+    /// `PutObject` chunk by chunk. See `Client::with_payload_signing`.
+    ///
+    /// Fails if the payload can't be signed this way, see `PayloadSigning::validate`.
+    pub fn payload_signing(&mut self, payload_signing: PayloadSigning) -> Result<(), SigningError> {
+        payload_signing.validate(&SigningAlgorithm::SigV4)?;
+        self.payload_signing = Some(payload_signing);
+        Ok(())
+    }
+
+    /// Overrides whether compressed response bodies are decompressed, for instance to
+    /// decompress an S3 object stored with a `Content-Encoding` of `gzip`, which is returned
+    /// as it was stored otherwise. See `Client::with_response_decompression`.
+    #[cfg(feature = "encoding")]
+    pub fn decompress_response(&mut self, decompress: bool) {
+        self.decompress_response = Some(decompress);
+    }
+
+    pub(crate) fn get_decompress_response(&self) -> Option<bool> {
+        self.decompress_response
+    }
+
+    pub(crate) fn get_timeout(&self) -> Option<Duration> {
+        self.timeout
+    }
+
+    pub(crate) fn get_retry_policy(&self) -> Option<&RetryPolicy> {
+        self.retry_policy.as_ref()
+    }
+
+    pub(crate) fn get_content_encoding(&self) -> Option<&ContentEncoding> {
+        self.content_encoding.as_ref()
+    }
+
+    pub(crate) fn get_payload_signing(&self) -> Option<&PayloadSigning> {
+        self.payload_signing.as_ref()
+    }
+
+    /// Applies the headers, endpoint and payload signing overrides to a request.
+    pub(crate) fn apply(&self, request: &mut SignedRequest) {
+        for (key, _) in &self.headers {
+            request.remove_header(key);
+        }
+        for (key, value) in &self.headers {
+            request.add_header(key, value);
+        }
+        if let Some(ref payload_signing) = self.payload_signing {
+            if let Err(err) = request.set_payload_signing(payload_signing.clone()) {
+                warn!("Not overriding the payload signing: {}", err);
+            }
+        }
+        if let Some(ref endpoint) = self.endpoint {
+            request.region = Region::Custom {
+                name: request.region.name().to_owned(),
+                endpoint: endpoint.to_owned(),
+            };
+            request.hostname = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn overrides_headers() {
+        let mut options = CallOptions::new();
+        options.header("X-Custom", "first");
+        options.header("X-Custom", "second");
+        options.header("Content-Type", "text/plain");
+
+        let mut request = SignedRequest::new("POST", "sqs", &Region::UsEast1, "/");
+        request.set_content_type("application/json".to_owned());
+        options.apply(&mut request);
+
+        assert_eq!(
+            request.headers()["x-custom"],
+            vec![b"first".to_vec(), b"second".to_vec()]
+        );
+        assert_eq!(
+            request.headers()["content-type"],
+            vec![b"text/plain".to_vec()]
+        );
+    }
+
+    #[test]
+    fn overrides_payload_signing() {
+        let payload_signing = PayloadSigning::chunked(8192, None).unwrap();
+        let mut options = CallOptions::new();
+        options.payload_signing(payload_signing.clone()).unwrap();
+        let mut request = SignedRequest::new("PUT", "s3", &Region::UsEast1, "/bucket/key");
+        options.apply(&mut request);
+        assert_eq!(request.payload_signing, payload_signing);
+    }
+
+    #[test]
+    fn rejects_small_chunks() {
+        let mut options = CallOptions::new();
+        let payload_signing = PayloadSigning::Chunked {
+            chunk_size: 1024,
+            checksum: None,
+        };
+        assert!(options.payload_signing(payload_signing).is_err());
+        assert_eq!(options, CallOptions::new());
+    }
+
+    #[test]
+    fn overrides_endpoint_but_not_signing_region() {
+        let mut options = CallOptions::new();
+        options.endpoint("http://localhost:4566");
+
+        let mut request = SignedRequest::new("POST", "sqs", &Region::EuWest1, "/");
+        request.set_endpoint_prefix("data.sqs".to_owned());
+        options.apply(&mut request);
+
+        assert_eq!(request.scheme(), "http");
+        assert_eq!(request.hostname(), "localhost:4566");
+        assert_eq!(request.region.name(), "eu-west-1");
+    }
+}
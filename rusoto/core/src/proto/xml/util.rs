@@ -76,6 +76,31 @@ impl From<ParseIntError> for XmlParseError {
     }
 }
 
+/// Returns the text of the first element named after one of the given names, at any depth,
+/// or `None` if there is none or the document is not valid XML.
+pub(crate) fn find_element_text(body: &[u8], names: &[&str]) -> Option<String> {
+    // The XML declaration must come first, after leading whitespace
+    let start = body
+        .iter()
+        .position(|b| !b.is_ascii_whitespace())
+        .unwrap_or(body.len());
+    let mut in_element = false;
+    for event in EventReader::new(&body[start..]) {
+        match event {
+            Ok(XmlEvent::StartElement { ref name, .. }) => {
+                in_element = names.contains(&name.local_name.as_str())
+            }
+            Ok(XmlEvent::Characters(ref text)) if in_element => {
+                return Some(text.trim().to_owned())
+            }
+            Ok(XmlEvent::EndElement { .. }) => in_element = false,
+            Err(_) => return None,
+            _ => {}
+        }
+    }
+    None
+}
+
 /// return a string field with the right name or throw a parse error
 pub fn string_field<T: Peek + Next>(name: &str, stack: &mut T) -> Result<String, XmlParseError> {
     start_element(name, stack)?;
@@ -286,6 +311,25 @@ mod tests {
     use std::fs::File;
     use std::io::Read;
 
+    #[test]
+    fn finds_element_text() {
+        let body = b"\n<?xml version=\"1.0\"?><Response><Errors><Error><Code> Throttling </Code>\
+            </Error></Errors><RequestID>ea966190</RequestID></Response>";
+        assert_eq!(
+            find_element_text(body, &["Code"]).as_deref(),
+            Some("Throttling")
+        );
+        assert_eq!(
+            find_element_text(body, &["RequestId", "RequestID"]).as_deref(),
+            Some("ea966190")
+        );
+        assert_eq!(find_element_text(body, &["Message"]), None);
+        assert_eq!(
+            find_element_text(b"{\"RequestId\": \"1\"}", &["RequestId"]),
+            None
+        );
+    }
+
     #[test]
     fn peek_at_name_happy_path() {
         let mut file = File::open("test_resources/list_queues_with_queue.xml").unwrap();
@@ -0,0 +1,232 @@
+//! Client side rate limiting reacting to throttling errors.
+
+use std::fmt;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use tokio::time;
+
+/// Factor applied to the send rate when a request is throttled.
+const THROTTLE_BETA: f64 = 0.7;
+/// Fraction of a token per second added to the send rate for every successful response.
+const SUCCESS_INCREMENT: f64 = 0.05;
+/// Smoothing applied to the measured send rate.
+const MEASUREMENT_SMOOTHING: f64 = 0.8;
+/// Interval over which the send rate is measured.
+const MEASUREMENT_INTERVAL: Duration = Duration::from_millis(500);
+
+/// An adaptive token bucket limiting the rate at which a `Client` sends requests.
+///
+/// The bucket does not limit anything until the first throttling error is received. From then
+/// on every attempt takes a token from the bucket and waits when it is empty. Throttling
+/// responses drain the bucket and lower the rate at which it is refilled, while successful
+/// responses slowly raise it again, similar to the "adaptive" retry mode of other AWS SDKs.
+///
+/// Cloning a `RateLimiter` or a `Client` using it shares the bucket, so that every user of
+/// the client backs off together.
+///
+/// ```
+/// use rusoto_core::{Client, RateLimiter};
+///
+/// let client = Client::shared().with_rate_limiter(RateLimiter::new());
+/// ```
+#[derive(Clone)]
+pub struct RateLimiter {
+    bucket: Arc<Mutex<TokenBucket>>,
+}
+
+struct TokenBucket {
+    enabled: bool,
+    tokens: f64,
+    fill_rate: f64,
+    min_fill_rate: f64,
+    last_refill: Instant,
+    measured_rate: f64,
+    measured_requests: u32,
+    last_measurement: Instant,
+}
+
+impl RateLimiter {
+    /// Create a new RateLimiter which never goes below half a request per second.
+    pub fn new() -> RateLimiter {
+        let now = Instant::now();
+        RateLimiter {
+            bucket: Arc::new(Mutex::new(TokenBucket {
+                enabled: false,
+                tokens: 0.0,
+                fill_rate: 0.0,
+                min_fill_rate: 0.5,
+                last_refill: now,
+                measured_rate: 0.0,
+                measured_requests: 0,
+                last_measurement: now,
+            })),
+        }
+    }
+
+    /// Returns the current maximum send rate in requests per second, or `None` if no
+    /// request has been throttled yet.
+    pub fn send_rate(&self) -> Option<f64> {
+        let bucket = self.bucket.lock().unwrap();
+        if bucket.enabled {
+            Some(bucket.fill_rate)
+        } else {
+            None
+        }
+    }
+
+    /// Waits until a request may be sent.
+    pub(crate) async fn acquire(&self) {
+        loop {
+            let wait = {
+                let mut bucket = self.bucket.lock().unwrap();
+                let now = Instant::now();
+                bucket.measure(now);
+                if !bucket.enabled {
+                    return;
+                }
+                bucket.refill(now);
+                if bucket.tokens >= 1.0 {
+                    bucket.tokens -= 1.0;
+                    return;
+                }
+                Duration::from_secs_f64((1.0 - bucket.tokens) / bucket.fill_rate)
+            };
+            time::sleep(wait).await;
+        }
+    }
+
+    /// Adjusts the send rate after a response was received.
+    pub(crate) fn record_response(&self, throttled: bool) {
+        let mut bucket = self.bucket.lock().unwrap();
+        let now = Instant::now();
+        bucket.refill(now);
+        if throttled {
+            let measured_rate = bucket.current_rate(now);
+            let rate = if bucket.enabled {
+                bucket.fill_rate.min(measured_rate)
+            } else {
+                measured_rate
+            };
+            bucket.enabled = true;
+            bucket.fill_rate = (rate * THROTTLE_BETA).max(bucket.min_fill_rate);
+            bucket.tokens = 0.0;
+        } else if bucket.enabled {
+            let ceiling = (2.0 * bucket.current_rate(now)).max(bucket.fill_rate);
+            bucket.fill_rate = (bucket.fill_rate + SUCCESS_INCREMENT).min(ceiling);
+        }
+    }
+}
+
+impl TokenBucket {
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.duration_since(self.last_refill).as_secs_f64();
+        let capacity = self.fill_rate.max(1.0);
+        self.tokens = (self.tokens + elapsed * self.fill_rate).min(capacity);
+        self.last_refill = now;
+    }
+
+    /// The measured send rate, falling back to the rate since the start of the current
+    /// measurement interval before a full interval has elapsed.
+    fn current_rate(&self, now: Instant) -> f64 {
+        if self.measured_rate > 0.0 {
+            return self.measured_rate;
+        }
+        let elapsed = now.duration_since(self.last_measurement).as_secs_f64();
+        if elapsed > 0.0 {
+            f64::from(self.measured_requests) / elapsed
+        } else {
+            0.0
+        }
+    }
+
+    fn measure(&mut self, now: Instant) {
+        self.measured_requests += 1;
+        let elapsed = now.duration_since(self.last_measurement);
+        if elapsed >= MEASUREMENT_INTERVAL {
+            let rate = f64::from(self.measured_requests) / elapsed.as_secs_f64();
+            self.measured_rate =
+                rate * MEASUREMENT_SMOOTHING + self.measured_rate * (1.0 - MEASUREMENT_SMOOTHING);
+            self.measured_requests = 0;
+            self.last_measurement = now;
+        }
+    }
+}
+
+impl Default for RateLimiter {
+    /// Create a new RateLimiter. Same as RateLimiter::new().
+    fn default() -> RateLimiter {
+        RateLimiter::new()
+    }
+}
+
+impl fmt::Debug for RateLimiter {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "RateLimiter {{ send_rate: {:?} }}", self.send_rate())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_rate(limiter: &RateLimiter, expected: f64) {
+        let rate = limiter.send_rate().expect("rate limiting is not enabled");
+        assert!((rate - expected).abs() < 1e-9, "{} != {}", rate, expected);
+    }
+
+    #[tokio::test]
+    async fn does_not_limit_until_throttled() {
+        let limiter = RateLimiter::new();
+        for _ in 0..100 {
+            limiter.acquire().await;
+            limiter.record_response(false);
+        }
+        assert_eq!(limiter.send_rate(), None);
+    }
+
+    #[test]
+    fn throttling_lowers_the_send_rate() {
+        let limiter = RateLimiter::new();
+        {
+            let mut bucket = limiter.bucket.lock().unwrap();
+            bucket.measured_rate = 100.0;
+            bucket.tokens = 10.0;
+        }
+        limiter.record_response(true);
+        assert_rate(&limiter, 70.0);
+        assert_eq!(limiter.bucket.lock().unwrap().tokens, 0.0);
+
+        limiter.record_response(true);
+        assert_rate(&limiter, 49.0);
+    }
+
+    #[test]
+    fn successes_raise_the_send_rate() {
+        let limiter = RateLimiter::new();
+        limiter.bucket.lock().unwrap().measured_rate = 10.0;
+        limiter.record_response(true);
+        assert_rate(&limiter, 7.0);
+        for _ in 0..20 {
+            limiter.record_response(false);
+        }
+        assert_rate(&limiter, 8.0);
+    }
+
+    #[test]
+    fn send_rate_has_a_floor() {
+        let limiter = RateLimiter::new();
+        for _ in 0..10 {
+            limiter.record_response(true);
+        }
+        assert_eq!(limiter.send_rate(), Some(0.5));
+    }
+
+    #[test]
+    fn clones_share_the_bucket() {
+        let limiter = RateLimiter::new();
+        let clone = limiter.clone();
+        clone.record_response(true);
+        assert!(limiter.send_rate().is_some());
+    }
+}
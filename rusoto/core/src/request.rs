@@ -15,7 +15,7 @@ use std::time::Duration;
 
 use bytes::{Bytes, BytesMut};
 use futures::{FutureExt, StreamExt};
-use http::header::{HeaderName, HeaderValue};
+use http::header::{HeaderName, HeaderValue, PROXY_AUTHORIZATION};
 use http::{HeaderMap, Method, StatusCode};
 use hyper::client::connect::Connect;
 use hyper::client::Builder as HyperBuilder;
@@ -28,9 +28,11 @@ use tokio::time;
 use log::Level::Debug;
 use log::*;
 
+use crate::proxy::ProxyConfig;
 use crate::signature::SignedRequest;
 use crate::stream::ByteStream;
-use crate::tls::HttpsConnector;
+use crate::tls::{DnsError, Resolver};
+pub use crate::tls::{HttpsConnector, MaybeHttpsStream};
 
 // Pulls in the statically generated rustc version.
 include!(concat!(env!("OUT_DIR"), "/user_agent_vars.rs"));
@@ -75,6 +77,33 @@ impl BufferedHttpResponse {
             _ => "unknown error",
         }
     }
+
+    /// Turns the response back into an `HttpResponse` streaming the buffered body.
+    pub(crate) fn into_http_response(self) -> HttpResponse {
+        HttpResponse {
+            status: self.status,
+            headers: self.headers,
+            body: ByteStream::from(self.body.to_vec()),
+        }
+    }
+
+    /// Creates a response of the given status, headers and body for the tests.
+    #[cfg(test)]
+    pub(crate) fn for_test(
+        status: u16,
+        headers: &[(&'static str, &str)],
+        body: &str,
+    ) -> BufferedHttpResponse {
+        let mut header_map = HeaderMap::<String>::default();
+        for (name, value) in headers {
+            header_map.insert(*name, (*value).to_owned());
+        }
+        BufferedHttpResponse {
+            status: StatusCode::from_u16(status).unwrap(),
+            body: body.to_owned().into(),
+            headers: header_map,
+        }
+    }
 }
 
 /// Best effort based Debug implementation to make generic error's body more readable.
@@ -100,8 +129,12 @@ impl HttpResponse {
     pub async fn buffer(&mut self) -> Result<BufferedHttpResponse, HttpDispatchError> {
         let mut bytes = BytesMut::new();
         while let Some(try_chunk) = self.body.next().await {
-            let chunk = try_chunk.map_err(|e| HttpDispatchError {
-                message: format!("Error obtaining body: {}", e),
+            let chunk = try_chunk.map_err(|e| {
+                HttpDispatchError::caused_by(
+                    HttpDispatchErrorKind::Body,
+                    format!("Error obtaining body: {}", e),
+                    e,
+                )
             })?;
             bytes.extend(chunk);
         }
@@ -139,20 +172,80 @@ impl HttpResponse {
     }
 }
 
-#[derive(Clone, Debug, PartialEq)]
+/// The kind of an `HttpDispatchError`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum HttpDispatchErrorKind {
+    /// No response was received before the timeout, or the TLS handshake timed out
+    Timeout,
+    /// The connection was refused or could not be established
+    Connect,
+    /// The hostname of the endpoint could not be resolved
+    Dns,
+    /// The TLS handshake failed
+    Tls,
+    /// The connection was closed or reset while the request or response body was transferred
+    Body,
+    /// Any other IO error on the connection
+    Io,
+    /// The request could not be built, or the error was created without a kind
+    Other,
+}
+
+#[derive(Clone, Debug)]
 /// An error produced when sending the request, such as a timeout error.
 pub struct HttpDispatchError {
+    kind: HttpDispatchErrorKind,
     message: String,
+    source: Option<Arc<dyn Error + Send + Sync>>,
 }
 
 impl HttpDispatchError {
     /// Construct a new HttpDispatchError for testing purposes
     pub fn new(message: String) -> HttpDispatchError {
-        HttpDispatchError { message }
+        HttpDispatchError::new_with_kind(HttpDispatchErrorKind::Other, message)
+    }
+
+    /// Construct a new HttpDispatchError of the given kind, for instance to simulate
+    /// timeouts with a mock dispatcher
+    pub fn new_with_kind(kind: HttpDispatchErrorKind, message: String) -> HttpDispatchError {
+        HttpDispatchError {
+            kind,
+            message,
+            source: None,
+        }
+    }
+
+    fn caused_by<E>(kind: HttpDispatchErrorKind, message: String, source: E) -> HttpDispatchError
+    where
+        E: Error + Send + Sync + 'static,
+    {
+        HttpDispatchError {
+            kind,
+            message,
+            source: Some(Arc::new(source)),
+        }
+    }
+
+    /// What caused the error, for instance to only alert on connection failures.
+    pub fn kind(&self) -> HttpDispatchErrorKind {
+        self.kind
     }
 }
 
-impl Error for HttpDispatchError {}
+impl PartialEq for HttpDispatchError {
+    fn eq(&self, other: &HttpDispatchError) -> bool {
+        self.kind == other.kind && self.message == other.message
+    }
+}
+
+impl Error for HttpDispatchError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self.source {
+            Some(ref source) => Some(source.as_ref()),
+            None => None,
+        }
+    }
+}
 
 impl fmt::Display for HttpDispatchError {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
@@ -162,18 +255,72 @@ impl fmt::Display for HttpDispatchError {
 
 impl From<HyperError> for HttpDispatchError {
     fn from(err: HyperError) -> HttpDispatchError {
-        HttpDispatchError {
-            message: err.to_string(),
-        }
+        HttpDispatchError::caused_by(classify_hyper_error(&err), err.to_string(), err)
     }
 }
 
 impl From<IoError> for HttpDispatchError {
     fn from(err: IoError) -> HttpDispatchError {
-        HttpDispatchError {
-            message: err.to_string(),
+        let kind = classify_error_chain(&err).unwrap_or(HttpDispatchErrorKind::Io);
+        HttpDispatchError::caused_by(kind, err.to_string(), err)
+    }
+}
+
+fn classify_hyper_error(err: &HyperError) -> HttpDispatchErrorKind {
+    if err.is_timeout() {
+        return HttpDispatchErrorKind::Timeout;
+    }
+    if let Some(kind) = classify_error_chain(err) {
+        return kind;
+    }
+    if err.is_connect() {
+        HttpDispatchErrorKind::Connect
+    } else if err.is_incomplete_message() || err.is_body_write_aborted() {
+        HttpDispatchErrorKind::Body
+    } else {
+        HttpDispatchErrorKind::Io
+    }
+}
+
+/// Looks for TLS errors, DNS errors, timeouts and refused connections among the causes
+/// of an error, including the errors wrapped by `io::Error`s.
+fn classify_error_chain(err: &(dyn Error + 'static)) -> Option<HttpDispatchErrorKind> {
+    let mut next = Some(err);
+    while let Some(err) = next {
+        if is_tls_error(err) {
+            return Some(HttpDispatchErrorKind::Tls);
+        }
+        if err.is::<DnsError>() {
+            return Some(HttpDispatchErrorKind::Dns);
         }
+        next = match err.downcast_ref::<IoError>() {
+            Some(io_err) => {
+                match io_err.kind() {
+                    io::ErrorKind::TimedOut => return Some(HttpDispatchErrorKind::Timeout),
+                    io::ErrorKind::ConnectionRefused => {
+                        return Some(HttpDispatchErrorKind::Connect)
+                    }
+                    _ => {}
+                }
+                match io_err.get_ref() {
+                    Some(inner) => Some(inner),
+                    None => None,
+                }
+            }
+            None => err.source(),
+        };
     }
+    None
+}
+
+#[cfg(feature = "native-tls")]
+fn is_tls_error(err: &(dyn Error + 'static)) -> bool {
+    err.is::<tokio_native_tls::native_tls::Error>()
+}
+
+#[cfg(feature = "rustls")]
+fn is_tls_error(err: &(dyn Error + 'static)) -> bool {
+    err.is::<tokio_rustls::rustls::TLSError>()
 }
 
 /// Type returned from `dispatch` for a `DispatchSignedRequest` implementor
@@ -211,33 +358,37 @@ impl<D: DispatchSignedRequest> DispatchSignedRequest for Arc<D> {
 }
 
 /// Http client for use with AWS services.
-pub struct HttpClient<C = HttpsConnector<HttpConnector>> {
+pub struct HttpClient<C = HttpsConnector> {
     inner: HyperClient<C, Body>,
     local_agent_prepend: Option<String>,
     local_agent_append: Option<String>,
+    proxy: ProxyConfig,
 }
 
 impl HttpClient {
-    /// Create a tls-enabled http client.
+    /// Create a tls-enabled http client, using the proxies set in the environment.
     pub fn new() -> Result<Self, TlsError> {
-        #[cfg(feature = "native-tls")]
-        let connector = HttpsConnector::new();
-
-        #[cfg(feature = "rustls")]
-        let connector = HttpsConnector::with_native_roots();
-
-        Ok(Self::from_connector(connector))
+        Self::new_with_config(HttpConfig::new())
     }
 
     /// Create a tls-enabled http client.
     pub fn new_with_config(config: HttpConfig) -> Result<Self, TlsError> {
-        #[cfg(feature = "native-tls")]
-        let connector = HttpsConnector::new();
-
-        #[cfg(feature = "rustls")]
-        let connector = HttpsConnector::with_native_roots();
-
-        Ok(Self::from_connector_with_config(connector, config))
+        let mut http = HttpConnector::new_with_resolver(Resolver::new());
+        http.set_connect_timeout(config.connect_timeout);
+        http.set_keepalive(config.tcp_keepalive);
+        http.set_nodelay(config.tcp_nodelay);
+        let connector = HttpsConnector::new(
+            http,
+            &config.proxy,
+            config.http2,
+            config.tls_handshake_timeout,
+            config.ca_bundle.as_deref(),
+        )?;
+
+        let proxy = config.proxy.clone();
+        let mut client = Self::from_connector_with_config(connector, config);
+        client.proxy = proxy;
+        Ok(client)
     }
 
     /// An alias for [`local_agent_prepend`] for backwards compatibility
@@ -269,11 +420,13 @@ where
             inner,
             local_agent_prepend: None,
             local_agent_append: None,
+            proxy: ProxyConfig::new(),
         }
     }
 
     /// Allows for a custom connector to be used with the HttpClient
-    /// with extra configuration options
+    /// with extra configuration options. The proxy configuration is not
+    /// used, connecting to a proxy is up to the connector.
     pub fn from_connector_with_config(connector: C, config: HttpConfig) -> Self {
         let mut builder = HyperClient::builder();
         config
@@ -282,12 +435,16 @@ where
         config
             .pool_idle_timeout
             .map(|t| builder.pool_idle_timeout(t));
+        config
+            .pool_max_idle_per_host
+            .map(|max| builder.pool_max_idle_per_host(max));
         let inner = builder.build(connector);
 
         HttpClient {
             inner,
             local_agent_prepend: None,
             local_agent_append: None,
+            proxy: ProxyConfig::new(),
         }
     }
 
@@ -298,6 +455,7 @@ where
             inner,
             local_agent_prepend: None,
             local_agent_append: None,
+            proxy: ProxyConfig::new(),
         }
     }
 }
@@ -306,14 +464,31 @@ where
 pub struct HttpConfig {
     read_buf_size: Option<usize>,
     pool_idle_timeout: Option<Duration>,
+    pool_max_idle_per_host: Option<usize>,
+    http2: bool,
+    tcp_keepalive: Option<Duration>,
+    tcp_nodelay: bool,
+    connect_timeout: Option<Duration>,
+    tls_handshake_timeout: Option<Duration>,
+    ca_bundle: Option<Vec<u8>>,
+    proxy: ProxyConfig,
 }
 
 impl HttpConfig {
-    /// Create a new HttpConfig
+    /// Create a new HttpConfig using the proxies set in the environment,
+    /// see `ProxyConfig::from_env`.
     pub fn new() -> HttpConfig {
         HttpConfig {
             read_buf_size: None,
             pool_idle_timeout: None,
+            pool_max_idle_per_host: None,
+            http2: false,
+            tcp_keepalive: None,
+            tcp_nodelay: false,
+            connect_timeout: None,
+            tls_handshake_timeout: None,
+            ca_bundle: None,
+            proxy: ProxyConfig::from_env(),
         }
     }
     /// Sets the size of the read buffer for inbound data
@@ -333,6 +508,64 @@ impl HttpConfig {
     {
         self.pool_idle_timeout = timeout.into();
     }
+
+    /// Sets the maximum number of idle connections kept open to each host.
+    /// Unlimited by default.
+    pub fn pool_max_idle_per_host(&mut self, max: usize) {
+        self.pool_max_idle_per_host = Some(max);
+    }
+
+    /// Offers HTTP/2 to `https` endpoints with ALPN, falling back to HTTP/1.1
+    /// for servers not supporting it. Disabled by default. Requests sent
+    /// over HTTP/2 share a single connection per host.
+    pub fn http2(&mut self, enabled: bool) {
+        self.http2 = enabled;
+    }
+
+    /// Sets the interval of the TCP keepalive probes sent on idle connections.
+    /// Keepalive is disabled by default.
+    pub fn tcp_keepalive<D>(&mut self, interval: D)
+    where
+        D: Into<Option<Duration>>,
+    {
+        self.tcp_keepalive = interval.into();
+    }
+
+    /// Sets `TCP_NODELAY` on new connections, disabling Nagle's algorithm.
+    pub fn tcp_nodelay(&mut self, nodelay: bool) {
+        self.tcp_nodelay = nodelay;
+    }
+
+    /// Sets a timeout for establishing TCP connections, once the hostname was resolved.
+    /// The DNS lookup is not covered. When the hostname resolves to several addresses,
+    /// the timeout is split between the connection attempts.
+    pub fn connect_timeout<D>(&mut self, timeout: D)
+    where
+        D: Into<Option<Duration>>,
+    {
+        self.connect_timeout = timeout.into();
+    }
+
+    /// Sets a timeout for the TLS handshake of new connections, once the TCP
+    /// connection is established, or the tunnel opened through a proxy.
+    pub fn tls_handshake_timeout<D>(&mut self, timeout: D)
+    where
+        D: Into<Option<Duration>>,
+    {
+        self.tls_handshake_timeout = timeout.into();
+    }
+
+    /// Trusts the certificates of the given PEM bundle in addition to the native
+    /// root certificates, for instance those of a TLS intercepting proxy.
+    pub fn ca_bundle(&mut self, pem: Vec<u8>) {
+        self.ca_bundle = Some(pem);
+    }
+
+    /// Sets the proxies to send requests through. Use `ProxyConfig::new()`
+    /// to ignore the proxies set in the environment.
+    pub fn proxy(&mut self, proxy: ProxyConfig) {
+        self.proxy = proxy;
+    }
 }
 
 impl Default for HttpConfig {
@@ -347,22 +580,13 @@ async fn http_client_dispatch<'a, C>(
     request: SignedRequest,
     timeout: Option<Duration>,
     user_agent: HeaderValue,
+    proxy_authorization: Option<HeaderValue>,
 ) -> Result<HttpResponse, HttpDispatchError>
 where
     C: Connect + Send + Sync + Clone + 'static,
 {
-    let hyper_method = match request.method().as_ref() {
-        "POST" => Method::POST,
-        "PUT" => Method::PUT,
-        "DELETE" => Method::DELETE,
-        "GET" => Method::GET,
-        "HEAD" => Method::HEAD,
-        v => {
-            return Err(HttpDispatchError {
-                message: format!("Unsupported HTTP verb {}", v),
-            });
-        }
-    };
+    let hyper_method = Method::from_bytes(request.method().as_bytes())
+        .map_err(|_| HttpDispatchError::new(format!("Invalid HTTP method {}", request.method())))?;
 
     // translate the headers map to a format Hyper likes
     let mut hyper_headers = HeaderMap::new();
@@ -370,18 +594,20 @@ where
         let header_name = match h.0.parse::<HeaderName>() {
             Ok(name) => name,
             Err(err) => {
-                return Err(HttpDispatchError {
-                    message: format!("error parsing header name: {}", err),
-                });
+                return Err(HttpDispatchError::new(format!(
+                    "error parsing header name: {}",
+                    err
+                )));
             }
         };
         for v in h.1.iter() {
             let header_value = match HeaderValue::from_bytes(v) {
                 Ok(value) => value,
                 Err(err) => {
-                    return Err(HttpDispatchError {
-                        message: format!("error parsing header value: {}", err),
-                    });
+                    return Err(HttpDispatchError::new(format!(
+                        "error parsing header value: {}",
+                        err
+                    )));
                 }
             };
             hyper_headers.append(&header_name, header_value);
@@ -393,6 +619,10 @@ where
         hyper_headers.insert("user-agent", user_agent);
     }
 
+    if let Some(proxy_authorization) = proxy_authorization {
+        hyper_headers.insert(PROXY_AUTHORIZATION, proxy_authorization);
+    }
+
     let mut final_uri = format!(
         "{}://{}{}",
         request.scheme(),
@@ -421,9 +651,8 @@ where
         http_request_builder.body(Body::empty())
     };
 
-    let mut http_request = try_http_request.map_err(|err| HttpDispatchError {
-        message: format!("error building request: {}", err),
-    })?;
+    let mut http_request = try_http_request
+        .map_err(|err| HttpDispatchError::new(format!("error building request: {}", err)))?;
 
     *http_request.headers_mut() = hyper_headers;
 
@@ -433,15 +662,20 @@ where
         None => f.await,
         Some(duration) => match time::timeout(duration, f).await {
             Err(_e) => {
-                return Err(HttpDispatchError {
-                    message: "Timeout while dispatching request".to_owned(),
-                })
+                return Err(HttpDispatchError::new_with_kind(
+                    HttpDispatchErrorKind::Timeout,
+                    "Timeout while dispatching request".to_owned(),
+                ))
             }
             Ok(try_req) => try_req,
         },
     };
-    let resp = try_resp.map_err(|e| HttpDispatchError {
-        message: format!("Error during dispatch: {}", e),
+    let resp = try_resp.map_err(|e| {
+        HttpDispatchError::caused_by(
+            classify_hyper_error(&e),
+            format!("Error during dispatch: {}", e),
+            e,
+        )
     })?;
     Ok(HttpResponse::from_hyper(resp).await)
 }
@@ -456,7 +690,17 @@ where
         timeout: Option<Duration>,
     ) -> DispatchSignedRequestFuture {
         let user_agent = build_user_agent(&self.local_agent_prepend, &self.local_agent_append);
-        http_client_dispatch::<C>(self.inner.clone(), request, timeout, user_agent).boxed()
+        let proxy_authorization = self
+            .proxy
+            .authorization(&request.scheme(), &request.hostname());
+        http_client_dispatch::<C>(
+            self.inner.clone(),
+            request,
+            timeout,
+            user_agent,
+            proxy_authorization,
+        )
+        .boxed()
     }
 }
 
@@ -477,7 +721,7 @@ fn build_user_agent(prepend: &Option<String>, append: &Option<String>) -> Header
 #[derive(Debug, PartialEq)]
 /// An error produced when the user has an invalid TLS client
 pub struct TlsError {
-    message: String,
+    pub(crate) message: String,
 }
 
 impl Error for TlsError {}
@@ -568,4 +812,255 @@ mod tests {
             build_user_agent(&None, &Some("after".to_string()))
         );
     }
+
+    /// Accepts a single connection, answers it with `response` and returns what was received
+    /// up to the end of the request headers.
+    async fn local_proxy(response: &'static [u8]) -> (String, tokio::task::JoinHandle<String>) {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let handle = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut received = Vec::new();
+            let mut buffer = [0; 1024];
+            while !received.ends_with(b"\r\n\r\n") {
+                let read = socket.read(&mut buffer).await.unwrap();
+                if read == 0 {
+                    break;
+                }
+                received.extend_from_slice(&buffer[..read]);
+            }
+            socket.write_all(response).await.unwrap();
+            String::from_utf8(received).unwrap()
+        });
+        (format!("http://user:p%40ss@{}", address), handle)
+    }
+
+    fn proxied_client(proxy: ProxyConfig) -> HttpClient {
+        let mut config = HttpConfig::new();
+        config.proxy(proxy);
+        HttpClient::new_with_config(config).unwrap()
+    }
+
+    #[tokio::test]
+    async fn sends_http_requests_to_the_proxy() {
+        let (proxy_url, proxy) =
+            local_proxy(b"HTTP/1.1 200 OK\r\ncontent-length: 2\r\n\r\nok").await;
+        let mut proxy_config = ProxyConfig::new();
+        proxy_config.http_proxy(proxy_url.parse().unwrap());
+        let client = proxied_client(proxy_config);
+
+        let region = Region::Custom {
+            name: "us-east-1".to_owned(),
+            endpoint: "http://sqs.example.com".to_owned(),
+        };
+        let request = SignedRequest::new("GET", "sqs", &region, "/");
+        let mut response = client.dispatch(request, None).await.unwrap();
+        assert_eq!(response.status, StatusCode::OK);
+        assert_eq!(response.buffer().await.unwrap().body_as_str(), "ok");
+
+        let received = proxy.await.unwrap();
+        assert!(received.starts_with("GET http://sqs.example.com/ HTTP/1.1\r\n"));
+        assert!(received.contains("proxy-authorization: Basic dXNlcjpwQHNz\r\n"));
+    }
+
+    #[tokio::test]
+    async fn tunnels_https_requests_through_the_proxy() {
+        let (proxy_url, proxy) = local_proxy(b"HTTP/1.1 403 Forbidden\r\n\r\n").await;
+        let mut proxy_config = ProxyConfig::new();
+        proxy_config.https_proxy(proxy_url.parse().unwrap());
+        let client = proxied_client(proxy_config);
+
+        let request = SignedRequest::new("GET", "sqs", &Region::UsEast1, "/");
+        assert!(client.dispatch(request, None).await.is_err());
+
+        let received = proxy.await.unwrap();
+        assert!(received.starts_with("CONNECT sqs.us-east-1.amazonaws.com:443 HTTP/1.1\r\n"));
+        assert!(received.contains("proxy-authorization: Basic dXNlcjpwQHNz\r\n"));
+    }
+
+    #[tokio::test]
+    async fn sends_requests_with_any_method() {
+        let (endpoint, server) = local_proxy(b"HTTP/1.1 204 No Content\r\n\r\n").await;
+        let client = proxied_client(ProxyConfig::new());
+        let region = Region::Custom {
+            name: "us-east-1".to_owned(),
+            endpoint: endpoint.replace("user:p%40ss@", ""),
+        };
+
+        let request = SignedRequest::new("PATCH", "apigateway", &region, "/restapis/a1b2c3");
+        let response = client.dispatch(request, None).await.unwrap();
+        assert_eq!(response.status, StatusCode::NO_CONTENT);
+        assert!(server
+            .await
+            .unwrap()
+            .starts_with("PATCH /restapis/a1b2c3 HTTP/1.1\r\n"));
+
+        let request = SignedRequest::new("NOT A METHOD", "apigateway", &region, "/");
+        let err = match client.dispatch(request, None).await {
+            Ok(_) => panic!("the method should have been rejected"),
+            Err(err) => err,
+        };
+        assert_eq!(err.to_string(), "Invalid HTTP method NOT A METHOD");
+        assert_eq!(err.kind(), HttpDispatchErrorKind::Other);
+    }
+
+    #[tokio::test]
+    async fn sends_requests_with_tuned_connections() {
+        let (endpoint, server) =
+            local_proxy(b"HTTP/1.1 200 OK\r\ncontent-length: 2\r\n\r\nok").await;
+        let mut config = HttpConfig::new();
+        config.proxy(ProxyConfig::new());
+        config.http2(true);
+        config.pool_max_idle_per_host(4);
+        config.tcp_keepalive(Duration::from_secs(30));
+        config.tcp_nodelay(true);
+        config.connect_timeout(Duration::from_secs(1));
+        config.tls_handshake_timeout(Duration::from_secs(1));
+        let client = HttpClient::new_with_config(config).unwrap();
+
+        let region = Region::Custom {
+            name: "us-east-1".to_owned(),
+            endpoint: endpoint.replace("user:p%40ss@", ""),
+        };
+        let request = SignedRequest::new("GET", "sqs", &region, "/");
+        let mut response = client.dispatch(request, None).await.unwrap();
+        assert_eq!(response.buffer().await.unwrap().body_as_str(), "ok");
+        assert!(server.await.unwrap().starts_with("GET / HTTP/1.1\r\n"));
+    }
+
+    #[test]
+    fn rejects_invalid_ca_bundles() {
+        let mut config = HttpConfig::new();
+        config.proxy(ProxyConfig::new());
+        config.ca_bundle(b"not a certificate".to_vec());
+        assert!(HttpClient::new_with_config(config).is_err());
+    }
+
+    #[tokio::test]
+    async fn times_out_tls_handshakes() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            // Accepts the connection but never answers the TLS client hello.
+            let (socket, _) = listener.accept().await.unwrap();
+            time::sleep(Duration::from_secs(5)).await;
+            drop(socket);
+        });
+
+        let mut config = HttpConfig::new();
+        config.proxy(ProxyConfig::new());
+        config.tls_handshake_timeout(Duration::from_millis(100));
+        let client = HttpClient::new_with_config(config).unwrap();
+
+        let region = Region::Custom {
+            name: "us-east-1".to_owned(),
+            endpoint: format!("https://localhost:{}", address.port()),
+        };
+        let request = SignedRequest::new("GET", "sqs", &region, "/");
+        let started = std::time::Instant::now();
+        let err = match client.dispatch(request, None).await {
+            Ok(_) => panic!("the handshake should have timed out"),
+            Err(err) => err,
+        };
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert!(
+            err.to_string().contains("TLS handshake timed out"),
+            "{}",
+            err
+        );
+        assert_eq!(err.kind(), HttpDispatchErrorKind::Timeout);
+        server.abort();
+    }
+
+    #[tokio::test]
+    async fn times_out_tls_handshakes_through_proxies() {
+        use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let proxy = tokio::spawn(async move {
+            // Opens the tunnel but never answers the TLS client hello sent through it.
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let mut buffer = [0; 1024];
+            let read = socket.read(&mut buffer).await.unwrap();
+            assert!(buffer[..read].starts_with(b"CONNECT sqs.us-east-1.amazonaws.com:443"));
+            socket
+                .write_all(b"HTTP/1.1 200 Connection established\r\n\r\n")
+                .await
+                .unwrap();
+            time::sleep(Duration::from_secs(5)).await;
+            drop(socket);
+        });
+
+        let mut proxy_config = ProxyConfig::new();
+        proxy_config.https_proxy(format!("http://{}", address).parse().unwrap());
+        let mut config = HttpConfig::new();
+        config.proxy(proxy_config);
+        config.tls_handshake_timeout(Duration::from_millis(100));
+        let client = HttpClient::new_with_config(config).unwrap();
+
+        let request = SignedRequest::new("GET", "sqs", &Region::UsEast1, "/");
+        let started = std::time::Instant::now();
+        let err = match client.dispatch(request, None).await {
+            Ok(_) => panic!("the handshake should have timed out"),
+            Err(err) => err,
+        };
+        assert!(started.elapsed() < Duration::from_secs(5));
+        assert!(
+            err.to_string().contains("TLS handshake timed out"),
+            "{}",
+            err
+        );
+        assert_eq!(err.kind(), HttpDispatchErrorKind::Timeout);
+        proxy.abort();
+    }
+
+    #[tokio::test]
+    async fn classifies_dispatch_errors() {
+        use tokio::io::AsyncWriteExt;
+
+        async fn dispatch_error(endpoint: String) -> HttpDispatchError {
+            let client = proxied_client(ProxyConfig::new());
+            let region = Region::Custom {
+                name: "us-east-1".to_owned(),
+                endpoint,
+            };
+            let request = SignedRequest::new("GET", "sqs", &region, "/");
+            match client.dispatch(request, None).await {
+                Ok(mut response) => match response.buffer().await {
+                    Ok(_) => panic!("the request should have failed"),
+                    Err(err) => err,
+                },
+                Err(err) => err,
+            }
+        }
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        drop(listener);
+        let err = dispatch_error(format!("http://{}", address)).await;
+        assert_eq!(err.kind(), HttpDispatchErrorKind::Connect, "{}", err);
+        assert!(err.source().is_some());
+
+        let err = dispatch_error("http://sqs.rusoto.invalid".to_owned()).await;
+        assert_eq!(err.kind(), HttpDispatchErrorKind::Dns, "{}", err);
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let server = tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            socket.write_all(b"HTTP/1.1 200 OK\r\n\r\n").await.unwrap();
+        });
+        let err = dispatch_error(format!("https://localhost:{}", address.port())).await;
+        assert_eq!(err.kind(), HttpDispatchErrorKind::Tls, "{}", err);
+        server.await.unwrap();
+
+        let (endpoint, server) =
+            local_proxy(b"HTTP/1.1 200 OK\r\ncontent-length: 10\r\n\r\nok").await;
+        let err = dispatch_error(endpoint.replace("user:p%40ss@", "")).await;
+        assert_eq!(err.kind(), HttpDispatchErrorKind::Body, "{}", err);
+        server.await.unwrap();
+    }
 }
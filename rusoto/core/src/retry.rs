@@ -0,0 +1,283 @@
+//! Retrying of requests which failed for transient reasons.
+
+use std::time::Duration;
+
+use http::StatusCode;
+use rand::Rng;
+
+use crate::proto::json;
+use crate::proto::xml::util::find_element_text;
+use crate::request::{BufferedHttpResponse, HttpDispatchError, HttpDispatchErrorKind};
+
+/// Error codes used by AWS services to signal that a request was throttled.
+const THROTTLING_ERROR_CODES: &[&str] = &[
+    "Throttling",
+    "ThrottlingException",
+    "ThrottledException",
+    "RequestThrottledException",
+    "RequestLimitExceeded",
+    "TooManyRequestsException",
+    "ProvisionedThroughputExceededException",
+    "SlowDown",
+];
+
+/// HTTP status codes which indicate a transient server side failure.
+const TRANSIENT_STATUS_CODES: &[StatusCode] = &[
+    StatusCode::INTERNAL_SERVER_ERROR,
+    StatusCode::BAD_GATEWAY,
+    StatusCode::SERVICE_UNAVAILABLE,
+    StatusCode::GATEWAY_TIMEOUT,
+];
+
+/// Controls how a `Client` retries requests which failed for a transient reason.
+///
+/// Connection errors, timeouts, `500`, `502`, `503` and `504` responses and throttling
+/// errors such as `ThrottlingException` are retried. The delay between two attempts grows
+/// exponentially from `base_delay` up to `max_delay` and is randomized using "full jitter".
+///
+/// Requests with a streaming payload are never retried as their body can not be replayed.
+///
+/// ```
+/// use std::time::Duration;
+/// use rusoto_core::RetryPolicy;
+///
+/// let mut policy = RetryPolicy::new();
+/// policy.max_attempts(5);
+/// policy.max_delay(Duration::from_secs(5));
+/// ```
+#[derive(Clone, Debug, PartialEq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    base_delay: Duration,
+    max_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// Create a new RetryPolicy making up to 3 attempts.
+    pub fn new() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 3,
+            base_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(20),
+        }
+    }
+
+    /// Create a RetryPolicy which never retries a request.
+    pub fn no_retry() -> RetryPolicy {
+        RetryPolicy {
+            max_attempts: 1,
+            ..RetryPolicy::new()
+        }
+    }
+
+    /// Sets the maximum number of attempts made for a request, including the first one.
+    /// A value of `0` is treated as `1`.
+    ///
+    /// A request rejected because of the skew of the local clock is sent again right away
+    /// once the clock is corrected, which counts as an attempt. The clock is only corrected
+    /// from the responses of requests which may be retried.
+    pub fn max_attempts(&mut self, max_attempts: u32) {
+        self.max_attempts = max_attempts.max(1);
+    }
+
+    /// Sets the delay the exponential backoff starts from.
+    pub fn base_delay(&mut self, delay: Duration) {
+        self.base_delay = delay;
+    }
+
+    /// Sets the upper bound of the delay between two attempts.
+    pub fn max_delay(&mut self, delay: Duration) {
+        self.max_delay = delay;
+    }
+
+    /// Returns the maximum number of attempts made for a request.
+    pub fn get_max_attempts(&self) -> u32 {
+        self.max_attempts
+    }
+
+    /// Computes the delay to wait before the given retry, starting at `1` for the first retry.
+    pub(crate) fn backoff(&self, retry: u32) -> Duration {
+        let exponent = retry.saturating_sub(1).min(31);
+        let ceiling = self
+            .base_delay
+            .checked_mul(1 << exponent)
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay);
+        if ceiling == Duration::from_secs(0) {
+            return ceiling;
+        }
+        ceiling.mul_f64(rand::thread_rng().gen::<f64>())
+    }
+}
+
+impl Default for RetryPolicy {
+    /// Create a new RetryPolicy. Same as RetryPolicy::new().
+    fn default() -> RetryPolicy {
+        RetryPolicy::new()
+    }
+}
+
+/// The reason a failed attempt may be retried.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(crate) enum RetryKind {
+    /// The service throttled the request.
+    Throttling,
+    /// The request failed for another transient reason.
+    Transient,
+    /// The request was dated with a skewed clock, which was corrected since.
+    ClockSkew,
+}
+
+/// Classifies an error response, returning `None` if it is not worth retrying.
+pub(crate) fn classify_response(response: &BufferedHttpResponse) -> Option<RetryKind> {
+    if response.status == StatusCode::TOO_MANY_REQUESTS {
+        return Some(RetryKind::Throttling);
+    }
+    if let Some(code) = error_code(response) {
+        if THROTTLING_ERROR_CODES.contains(&code.as_str()) {
+            return Some(RetryKind::Throttling);
+        }
+    }
+    if TRANSIENT_STATUS_CODES.contains(&response.status) {
+        return Some(RetryKind::Transient);
+    }
+    None
+}
+
+/// Classifies a dispatch error, returning `None` if it is not worth retrying.
+///
+/// Errors caused by the connection or a timeout are retried, while errors caused by an
+/// invalid request or a failed TLS handshake, like an untrusted certificate, are not.
+pub(crate) fn classify_dispatch_error(error: &HttpDispatchError) -> Option<RetryKind> {
+    match error.kind() {
+        HttpDispatchErrorKind::Tls | HttpDispatchErrorKind::Other => None,
+        _ => Some(RetryKind::Transient),
+    }
+}
+
+/// Returns whether a response status may be caused by a retryable error and the
+/// response body should be inspected.
+pub(crate) fn may_be_retryable(status: StatusCode) -> bool {
+    status.is_client_error() || status.is_server_error()
+}
+
+/// Best effort extraction of the error code of an error response, regardless of the
+/// protocol used by the service.
+pub(crate) fn error_code(response: &BufferedHttpResponse) -> Option<String> {
+    if let Some(error_type) = response.headers.get("x-amzn-errortype") {
+        if let Some(code) = error_type.split(':').next() {
+            return Some(code.to_owned());
+        }
+    }
+    match response.body.iter().find(|b| !b.is_ascii_whitespace()) {
+        Some(b'{') => json::Error::parse(response)
+            .filter(|err| err.typ != "Unknown")
+            .or_else(|| json::Error::parse_rest(response))
+            .map(|err| err.typ),
+        Some(b'<') => find_element_text(&response.body, &["Code"]),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn backoff_is_bounded() {
+        let mut policy = RetryPolicy::new();
+        policy.base_delay(Duration::from_millis(100));
+        policy.max_delay(Duration::from_millis(1000));
+        for retry in 1..40 {
+            let ceiling = Duration::from_millis(100 << (retry - 1).min(4)).min(policy.max_delay);
+            assert!(policy.backoff(retry) <= ceiling);
+        }
+    }
+
+    #[test]
+    fn max_attempts_is_at_least_one() {
+        let mut policy = RetryPolicy::new();
+        policy.max_attempts(0);
+        assert_eq!(policy.get_max_attempts(), 1);
+        assert_eq!(RetryPolicy::no_retry().get_max_attempts(), 1);
+    }
+
+    #[test]
+    fn classifies_server_errors() {
+        assert_eq!(
+            classify_response(&BufferedHttpResponse::for_test(503, &[], "")),
+            Some(RetryKind::Transient)
+        );
+        assert_eq!(
+            classify_response(&BufferedHttpResponse::for_test(501, &[], "")),
+            None
+        );
+        assert_eq!(
+            classify_response(&BufferedHttpResponse::for_test(404, &[], "")),
+            None
+        );
+    }
+
+    #[test]
+    fn classifies_json_throttling_errors() {
+        let body = r#"{"__type":"com.amazonaws.dynamodb.v20120810#ProvisionedThroughputExceededException","message":"slow down"}"#;
+        assert_eq!(
+            classify_response(&BufferedHttpResponse::for_test(400, &[], body)),
+            Some(RetryKind::Throttling)
+        );
+        let body = r#"{"__type":"ResourceNotFoundException","message":"not found"}"#;
+        assert_eq!(
+            classify_response(&BufferedHttpResponse::for_test(400, &[], body)),
+            None
+        );
+    }
+
+    #[test]
+    fn classifies_xml_throttling_errors() {
+        let body = "<ErrorResponse><Error><Type>Sender</Type><Code>Throttling</Code>\
+                    <Message>Rate exceeded</Message></Error></ErrorResponse>";
+        assert_eq!(
+            classify_response(&BufferedHttpResponse::for_test(400, &[], body)),
+            Some(RetryKind::Throttling)
+        );
+        let body = "<Response><Errors><Error><Code>RequestLimitExceeded</Code></Error></Errors></Response>";
+        assert_eq!(
+            classify_response(&BufferedHttpResponse::for_test(503, &[], body)),
+            Some(RetryKind::Throttling)
+        );
+    }
+
+    #[test]
+    fn classifies_error_type_header() {
+        let mut res = BufferedHttpResponse::for_test(400, &[], "{}");
+        res.headers.insert(
+            "x-amzn-errortype",
+            "ThrottlingException:http://internal".to_owned(),
+        );
+        assert_eq!(classify_response(&res), Some(RetryKind::Throttling));
+    }
+
+    #[test]
+    fn classifies_dispatch_errors() {
+        let timeout = HttpDispatchError::new_with_kind(
+            HttpDispatchErrorKind::Timeout,
+            "Timeout while dispatching request".to_owned(),
+        );
+        assert_eq!(
+            classify_dispatch_error(&timeout),
+            Some(RetryKind::Transient)
+        );
+        let reset = HttpDispatchError::new_with_kind(
+            HttpDispatchErrorKind::Body,
+            "connection reset".to_owned(),
+        );
+        assert_eq!(classify_dispatch_error(&reset), Some(RetryKind::Transient));
+        let untrusted = HttpDispatchError::new_with_kind(
+            HttpDispatchErrorKind::Tls,
+            "certificate verify failed".to_owned(),
+        );
+        assert_eq!(classify_dispatch_error(&untrusted), None);
+        let invalid = HttpDispatchError::new("Invalid HTTP method".to_owned());
+        assert_eq!(classify_dispatch_error(&invalid), None);
+    }
+}
@@ -0,0 +1,454 @@
+//! The connector used by `HttpClient` to open plain and TLS connections.
+
+use std::error::Error;
+use std::fmt;
+use std::future::Future;
+use std::io::{self, IoSlice};
+use std::pin::Pin;
+use std::task::{Context, Poll};
+use std::time::Duration;
+
+use futures::future::{MapErr, TryFutureExt};
+use http::Uri;
+use hyper::client::connect::dns::{GaiAddrs, GaiFuture, GaiResolver, Name};
+use hyper::client::connect::{Connected, Connection};
+use hyper::client::HttpConnector;
+use hyper::service::Service;
+use hyper_proxy::{ProxyConnector, ProxyStream};
+use tokio::io::{AsyncRead, AsyncWrite, ReadBuf};
+use tokio::net::TcpStream;
+use tokio::time;
+
+#[cfg(feature = "native-tls")]
+use tokio_native_tls::{native_tls, TlsConnector, TlsStream};
+#[cfg(feature = "rustls")]
+use tokio_rustls::{client::TlsStream, rustls, webpki, TlsConnector};
+
+use crate::proxy::ProxyConfig;
+use crate::request::TlsError;
+
+type BoxError = Box<dyn Error + Send + Sync>;
+
+/// The TCP connection of an `HttpsConnector`, to the endpoint or through a proxy.
+type Transport = ProxyStream<TcpStream>;
+
+/// Resolves hostnames with `getaddrinfo`, like the default resolver of hyper, returning its
+/// failures as `DnsError`s so that `HttpDispatchError::kind` can tell them apart.
+#[derive(Clone, Debug)]
+pub(crate) struct Resolver(GaiResolver);
+
+impl Resolver {
+    pub(crate) fn new() -> Resolver {
+        Resolver(GaiResolver::new())
+    }
+}
+
+impl Service<Name> for Resolver {
+    type Response = GaiAddrs;
+    type Error = DnsError;
+    type Future = MapErr<GaiFuture, fn(io::Error) -> DnsError>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), DnsError>> {
+        self.0.poll_ready(cx).map_err(DnsError)
+    }
+
+    fn call(&mut self, name: Name) -> Self::Future {
+        self.0.call(name).map_err(DnsError)
+    }
+}
+
+/// The hostname of an endpoint or proxy could not be resolved.
+#[derive(Debug)]
+pub(crate) struct DnsError(io::Error);
+
+impl fmt::Display for DnsError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl Error for DnsError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        Some(&self.0)
+    }
+}
+
+/// Connects to `http` endpoints over TCP and to `https` endpoints over TLS, directly or
+/// through the configured proxies.
+///
+/// The TLS handshake of `https` endpoints reached through a proxy happens in the tunnel
+/// opened with `CONNECT`, with the same timeout and certificates as direct connections.
+/// When HTTP/2 is enabled it is offered to the server with ALPN, and connections where the
+/// server picked it are used for HTTP/2.
+#[derive(Clone)]
+pub struct HttpsConnector {
+    proxy: ProxyConnector<HttpConnector<Resolver>>,
+    tls: TlsConnector,
+    handshake_timeout: Option<Duration>,
+}
+
+impl HttpsConnector {
+    pub(crate) fn new(
+        mut http: HttpConnector<Resolver>,
+        proxy: &ProxyConfig,
+        http2: bool,
+        handshake_timeout: Option<Duration>,
+        ca_bundle: Option<&[u8]>,
+    ) -> Result<HttpsConnector, TlsError> {
+        http.enforce_http(false);
+        let proxy = proxy.connector(http).map_err(|err| TlsError {
+            message: format!("Couldn't create proxy connector: {}", err),
+        })?;
+        Ok(HttpsConnector {
+            proxy,
+            tls: tls_connector(http2, ca_bundle)?,
+            handshake_timeout,
+        })
+    }
+}
+
+impl fmt::Debug for HttpsConnector {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("HttpsConnector")
+            .field("proxies", &self.proxy.proxies().len())
+            .field("handshake_timeout", &self.handshake_timeout)
+            .finish()
+    }
+}
+
+#[cfg(feature = "native-tls")]
+fn tls_connector(http2: bool, ca_bundle: Option<&[u8]>) -> Result<TlsConnector, TlsError> {
+    let mut builder = native_tls::TlsConnector::builder();
+    if http2 {
+        builder.request_alpns(&["h2", "http/1.1"]);
+    }
+    if let Some(ca_bundle) = ca_bundle {
+        for certificate in pem_certificates(ca_bundle)? {
+            let certificate = native_tls::Certificate::from_pem(certificate.as_bytes())
+                .map_err(|err| TlsError {
+                    message: format!("Invalid certificate in the CA bundle: {}", err),
+                })?;
+            builder.add_root_certificate(certificate);
+        }
+    }
+    let connector = builder.build().map_err(|err| TlsError {
+        message: format!("Couldn't create TLS connector: {}", err),
+    })?;
+    Ok(TlsConnector::from(connector))
+}
+
+/// Splits a PEM bundle into its certificates, as `native-tls` only reads the first one.
+#[cfg(feature = "native-tls")]
+fn pem_certificates(ca_bundle: &[u8]) -> Result<Vec<String>, TlsError> {
+    const END: &str = "-----END CERTIFICATE-----";
+
+    let invalid = || TlsError {
+        message: "The CA bundle contains no PEM certificate".to_owned(),
+    };
+    let bundle = std::str::from_utf8(ca_bundle).map_err(|_| invalid())?;
+    let certificates: Vec<String> = bundle
+        .split_inclusive(END)
+        .filter(|block| block.contains(END))
+        .map(|block| block.trim().to_owned())
+        .collect();
+    if certificates.is_empty() {
+        return Err(invalid());
+    }
+    Ok(certificates)
+}
+
+#[cfg(feature = "rustls")]
+fn tls_connector(http2: bool, ca_bundle: Option<&[u8]>) -> Result<TlsConnector, TlsError> {
+    let mut config = rustls::ClientConfig::new();
+    config.root_store = match rustls_native_certs::load_native_certs() {
+        Ok(store) => store,
+        Err((Some(store), err)) => {
+            log::warn!("Could not load all certificates: {}", err);
+            store
+        }
+        Err((None, err)) => {
+            return Err(TlsError {
+                message: format!("Couldn't load the native certificates: {}", err),
+            })
+        }
+    };
+    if let Some(mut ca_bundle) = ca_bundle {
+        match config.root_store.add_pem_file(&mut ca_bundle) {
+            Ok((valid, _)) if valid > 0 => {}
+            _ => {
+                return Err(TlsError {
+                    message: "The CA bundle contains no valid PEM certificate".to_owned(),
+                })
+            }
+        }
+    }
+    if config.root_store.is_empty() {
+        return Err(TlsError {
+            message: "No native certificates found".to_owned(),
+        });
+    }
+    config.alpn_protocols = if http2 {
+        vec![b"h2".to_vec(), b"http/1.1".to_vec()]
+    } else {
+        vec![b"http/1.1".to_vec()]
+    };
+    config.ct_logs = Some(&ct_logs::LOGS);
+    Ok(TlsConnector::from(std::sync::Arc::new(config)))
+}
+
+impl Service<Uri> for HttpsConnector {
+    type Response = MaybeHttpsStream;
+    type Error = BoxError;
+    #[allow(clippy::type_complexity)]
+    type Future = Pin<Box<dyn Future<Output = Result<MaybeHttpsStream, BoxError>> + Send>>;
+
+    fn poll_ready(&mut self, cx: &mut Context<'_>) -> Poll<Result<(), BoxError>> {
+        self.proxy.poll_ready(cx).map_err(Into::into)
+    }
+
+    fn call(&mut self, uri: Uri) -> Self::Future {
+        let is_https = uri.scheme_str() == Some("https");
+        let host = uri.host().unwrap_or_default().to_owned();
+        let connecting = self.proxy.call(uri);
+        let tls = self.tls.clone();
+        let handshake_timeout = self.handshake_timeout;
+
+        Box::pin(async move {
+            let transport = connecting.await?;
+            if !is_https {
+                return Ok(MaybeHttpsStream(Stream::Http(transport)));
+            }
+            let handshake = handshake(&tls, &host, transport);
+            let stream = match handshake_timeout {
+                Some(timeout) => time::timeout(timeout, handshake).await.map_err(|_| {
+                    io::Error::new(io::ErrorKind::TimedOut, "TLS handshake timed out")
+                })??,
+                None => handshake.await?,
+            };
+            Ok(MaybeHttpsStream(Stream::Https(stream)))
+        })
+    }
+}
+
+#[cfg(feature = "native-tls")]
+async fn handshake(
+    tls: &TlsConnector,
+    host: &str,
+    transport: Transport,
+) -> Result<TlsStream<Transport>, BoxError> {
+    Ok(tls.connect(host, transport).await?)
+}
+
+#[cfg(feature = "rustls")]
+async fn handshake(
+    tls: &TlsConnector,
+    host: &str,
+    transport: Transport,
+) -> Result<TlsStream<Transport>, BoxError> {
+    let dns_name = webpki::DNSNameRef::try_from_ascii_str(host)
+        .map_err(|_| io::Error::new(io::ErrorKind::InvalidInput, "invalid DNS name"))?;
+    Ok(tls.connect(dns_name, transport).await?)
+}
+
+/// A connection opened by an `HttpsConnector`, over TCP or TLS.
+pub struct MaybeHttpsStream(Stream);
+
+enum Stream {
+    Http(Transport),
+    Https(TlsStream<Transport>),
+}
+
+impl MaybeHttpsStream {
+    #[cfg(feature = "native-tls")]
+    fn negotiated_h2(stream: &TlsStream<Transport>) -> bool {
+        let alpn = stream.get_ref().negotiated_alpn().ok().flatten();
+        alpn.as_deref() == Some(b"h2")
+    }
+
+    #[cfg(feature = "rustls")]
+    fn negotiated_h2(stream: &TlsStream<Transport>) -> bool {
+        use rustls::Session;
+        stream.get_ref().1.get_alpn_protocol() == Some(b"h2")
+    }
+
+    #[cfg(feature = "native-tls")]
+    fn transport(stream: &TlsStream<Transport>) -> &Transport {
+        stream.get_ref().get_ref().get_ref()
+    }
+
+    #[cfg(feature = "rustls")]
+    fn transport(stream: &TlsStream<Transport>) -> &Transport {
+        stream.get_ref().0
+    }
+}
+
+impl fmt::Debug for MaybeHttpsStream {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0 {
+            Stream::Http(_) => f.write_str("Http(..)"),
+            Stream::Https(_) => f.write_str("Https(..)"),
+        }
+    }
+}
+
+impl Connection for MaybeHttpsStream {
+    fn connected(&self) -> Connected {
+        match &self.0 {
+            Stream::Http(transport) => transport.connected(),
+            Stream::Https(stream) => {
+                let connected = MaybeHttpsStream::transport(stream).connected();
+                if MaybeHttpsStream::negotiated_h2(stream) {
+                    connected.negotiated_h2()
+                } else {
+                    connected
+                }
+            }
+        }
+    }
+}
+
+impl AsyncRead for MaybeHttpsStream {
+    fn poll_read(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut ReadBuf<'_>,
+    ) -> Poll<io::Result<()>> {
+        match &mut self.get_mut().0 {
+            Stream::Http(transport) => Pin::new(transport).poll_read(cx, buf),
+            Stream::Https(stream) => Pin::new(stream).poll_read(cx, buf),
+        }
+    }
+}
+
+impl AsyncWrite for MaybeHttpsStream {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        match &mut self.get_mut().0 {
+            Stream::Http(transport) => Pin::new(transport).poll_write(cx, buf),
+            Stream::Https(stream) => Pin::new(stream).poll_write(cx, buf),
+        }
+    }
+
+    fn poll_write_vectored(
+        self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        bufs: &[IoSlice<'_>],
+    ) -> Poll<io::Result<usize>> {
+        match &mut self.get_mut().0 {
+            Stream::Http(transport) => Pin::new(transport).poll_write_vectored(cx, bufs),
+            Stream::Https(stream) => Pin::new(stream).poll_write_vectored(cx, bufs),
+        }
+    }
+
+    fn is_write_vectored(&self) -> bool {
+        match &self.0 {
+            Stream::Http(transport) => transport.is_write_vectored(),
+            Stream::Https(stream) => stream.is_write_vectored(),
+        }
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match &mut self.get_mut().0 {
+            Stream::Http(transport) => Pin::new(transport).poll_flush(cx),
+            Stream::Https(stream) => Pin::new(stream).poll_flush(cx),
+        }
+    }
+
+    fn poll_shutdown(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        match &mut self.get_mut().0 {
+            Stream::Http(transport) => Pin::new(transport).poll_shutdown(cx),
+            Stream::Https(stream) => Pin::new(stream).poll_shutdown(cx),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "native-tls"))]
+mod tests {
+    use std::net::TcpListener;
+    use std::thread;
+
+    use openssl::asn1::Asn1Time;
+    use openssl::bn::BigNum;
+    use openssl::ec::{EcGroup, EcKey};
+    use openssl::hash::MessageDigest;
+    use openssl::nid::Nid;
+    use openssl::pkey::{PKey, Private};
+    use openssl::ssl::{select_next_proto, AlpnError, SslAcceptor, SslMethod};
+    use openssl::x509::extension::{BasicConstraints, SubjectAlternativeName};
+    use openssl::x509::{X509NameBuilder, X509};
+
+    use super::*;
+
+    /// A certificate for `localhost`, signed by its own key.
+    fn self_signed_certificate() -> (X509, PKey<Private>) {
+        let group = EcGroup::from_curve_name(Nid::X9_62_PRIME256V1).unwrap();
+        let key = PKey::from_ec_key(EcKey::generate(&group).unwrap()).unwrap();
+        let mut name = X509NameBuilder::new().unwrap();
+        name.append_entry_by_text("CN", "localhost").unwrap();
+        let name = name.build();
+
+        let mut builder = X509::builder().unwrap();
+        builder.set_version(2).unwrap();
+        let serial_number = BigNum::from_u32(1).unwrap().to_asn1_integer().unwrap();
+        builder.set_serial_number(&serial_number).unwrap();
+        builder.set_subject_name(&name).unwrap();
+        builder.set_issuer_name(&name).unwrap();
+        builder.set_pubkey(&key).unwrap();
+        builder
+            .set_not_before(&Asn1Time::days_from_now(0).unwrap())
+            .unwrap();
+        builder
+            .set_not_after(&Asn1Time::days_from_now(1).unwrap())
+            .unwrap();
+        let basic_constraints = BasicConstraints::new().critical().ca().build().unwrap();
+        builder.append_extension(basic_constraints).unwrap();
+        let subject_alternative_name = SubjectAlternativeName::new()
+            .dns("localhost")
+            .build(&builder.x509v3_context(None, None))
+            .unwrap();
+        builder.append_extension(subject_alternative_name).unwrap();
+        builder.sign(&key, MessageDigest::sha256()).unwrap();
+        (builder.build(), key)
+    }
+
+    #[tokio::test]
+    async fn negotiates_http2_with_alpn() {
+        let (certificate, key) = self_signed_certificate();
+        let mut acceptor = SslAcceptor::mozilla_intermediate_v5(SslMethod::tls()).unwrap();
+        acceptor.set_certificate(&certificate).unwrap();
+        acceptor.set_private_key(&key).unwrap();
+        acceptor.set_alpn_select_callback(|_, client| {
+            select_next_proto(b"\x02h2\x08http/1.1", client).ok_or(AlpnError::NOACK)
+        });
+        let acceptor = acceptor.build();
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let uri: Uri = format!("https://localhost:{}", port).parse().unwrap();
+        let server = thread::spawn(move || {
+            let mut selected = Vec::new();
+            for tcp in listener.incoming().take(2) {
+                let stream = acceptor.accept(tcp.unwrap()).unwrap();
+                selected.push(stream.ssl().selected_alpn_protocol().map(<[u8]>::to_vec));
+            }
+            selected
+        });
+
+        let ca_bundle = certificate.to_pem().unwrap();
+        for &http2 in &[true, false] {
+            let mut connector = HttpsConnector::new(
+                HttpConnector::new_with_resolver(Resolver::new()),
+                &ProxyConfig::new(),
+                http2,
+                None,
+                Some(&ca_bundle),
+            )
+            .unwrap();
+            let stream = connector.call(uri.clone()).await.unwrap();
+            assert_eq!(stream.connected().is_negotiated_h2(), http2);
+        }
+        assert_eq!(server.join().unwrap(), [Some(b"h2".to_vec()), None]);
+    }
+}
@@ -0,0 +1,276 @@
+//! Spans describing the requests sent by a `Client`.
+//!
+//! With the `tracing` feature every call opens an `operation` span carrying the service,
+//! operation, region, HTTP status and request id, with an `attempt` span per attempt and
+//! `credentials`, `sign` and `dispatch` spans below it. Without the feature the functions of
+//! this module do nothing.
+
+#[cfg(not(feature = "tracing"))]
+pub(crate) use self::disabled::*;
+#[cfg(feature = "tracing")]
+pub(crate) use self::enabled::*;
+
+#[cfg(feature = "tracing")]
+mod enabled {
+    use std::future::Future;
+
+    pub(crate) use tracing::Span;
+    use tracing::{field, Instrument};
+
+    use crate::client::SignAndDispatchError;
+    use crate::request::HttpResponse;
+    use crate::signature::SignedRequest;
+
+    /// Headers carrying the id AWS assigned to a request.
+    const REQUEST_ID_HEADERS: &[&str] = &["x-amzn-requestid", "x-amz-request-id"];
+
+    pub(crate) fn operation_span(request: &SignedRequest) -> Span {
+        let operation = request.operation.as_deref().unwrap_or("");
+        tracing::info_span!(
+            "operation",
+            otel.name = %format_args!("{}.{}", request.service, operation),
+            otel.kind = "client",
+            rpc.system = "aws-api",
+            rpc.service = %request.service,
+            rpc.method = operation,
+            aws.region = request.region.name(),
+            aws.request_id = field::Empty,
+            aws.attempts = field::Empty,
+            http.status_code = field::Empty,
+            error = field::Empty,
+        )
+    }
+
+    /// Opens the span of an attempt and records the attempt number on the current
+    /// operation span.
+    pub(crate) fn attempt_span(attempt: u32) -> Span {
+        Span::current().record("aws.attempts", attempt);
+        tracing::debug_span!(
+            "attempt",
+            attempt,
+            aws.request_id = field::Empty,
+            http.status_code = field::Empty,
+            error = field::Empty,
+        )
+    }
+
+    pub(crate) fn credentials_span() -> Span {
+        tracing::debug_span!("credentials")
+    }
+
+    pub(crate) fn sign_span() -> Span {
+        tracing::debug_span!("sign")
+    }
+
+    pub(crate) fn dispatch_span() -> Span {
+        tracing::debug_span!("dispatch")
+    }
+
+    /// Records the status and request id of a response, or the error, on an operation or
+    /// attempt span.
+    pub(crate) fn record_result(span: &Span, result: &Result<HttpResponse, SignAndDispatchError>) {
+        match result {
+            Ok(response) => {
+                span.record("http.status_code", response.status.as_u16());
+                let request_id = REQUEST_ID_HEADERS
+                    .iter()
+                    .find_map(|name| response.headers.get(*name));
+                if let Some(request_id) = request_id {
+                    span.record("aws.request_id", request_id.as_str());
+                }
+            }
+            Err(SignAndDispatchError::Credentials(err)) => {
+                span.record("error", field::display(err));
+            }
+            Err(SignAndDispatchError::Dispatch(err)) => {
+                span.record("error", field::display(err));
+            }
+        }
+    }
+
+    pub(crate) fn instrument<F: Future>(future: F, span: &Span) -> impl Future<Output = F::Output> {
+        future.instrument(span.clone())
+    }
+}
+
+#[cfg(not(feature = "tracing"))]
+mod disabled {
+    use std::future::Future;
+
+    use crate::client::SignAndDispatchError;
+    use crate::request::HttpResponse;
+    use crate::signature::SignedRequest;
+
+    pub(crate) struct Span;
+
+    impl Span {
+        pub(crate) fn in_scope<F: FnOnce() -> T, T>(&self, f: F) -> T {
+            f()
+        }
+    }
+
+    pub(crate) fn operation_span(_request: &SignedRequest) -> Span {
+        Span
+    }
+
+    pub(crate) fn attempt_span(_attempt: u32) -> Span {
+        Span
+    }
+
+    pub(crate) fn credentials_span() -> Span {
+        Span
+    }
+
+    pub(crate) fn sign_span() -> Span {
+        Span
+    }
+
+    pub(crate) fn dispatch_span() -> Span {
+        Span
+    }
+
+    pub(crate) fn record_result(
+        _span: &Span,
+        _result: &Result<HttpResponse, SignAndDispatchError>,
+    ) {
+    }
+
+    pub(crate) fn instrument<F: Future>(future: F, _span: &Span) -> F {
+        future
+    }
+}
+
+#[cfg(all(test, feature = "tracing"))]
+mod tests {
+    use std::collections::HashMap;
+    use std::fmt;
+    use std::sync::atomic::{AtomicU64, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    use http::StatusCode;
+    use tracing::field::{Field, Visit};
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+    use tracing_core::span::Current;
+
+    use super::*;
+    use crate::request::HttpResponse;
+    use crate::signature::SignedRequest;
+    use crate::stream::ByteStream;
+    use crate::Region;
+
+    type Fields = HashMap<String, String>;
+
+    /// Captures the fields recorded on every span, by span name.
+    #[derive(Default)]
+    struct CapturingSubscriber {
+        next_id: AtomicU64,
+        spans: Mutex<HashMap<u64, &'static Metadata<'static>>>,
+        stack: Mutex<Vec<Id>>,
+        fields: Arc<Mutex<HashMap<&'static str, Fields>>>,
+    }
+
+    struct FieldVisitor<'a>(&'a mut Fields);
+
+    impl Visit for FieldVisitor<'_> {
+        fn record_str(&mut self, field: &Field, value: &str) {
+            self.0.insert(field.name().to_owned(), value.to_owned());
+        }
+
+        fn record_debug(&mut self, field: &Field, value: &dyn fmt::Debug) {
+            self.0
+                .insert(field.name().to_owned(), format!("{:?}", value));
+        }
+    }
+
+    impl CapturingSubscriber {
+        fn name(&self, span: &Id) -> &'static str {
+            self.spans.lock().unwrap()[&span.into_u64()].name()
+        }
+    }
+
+    impl Subscriber for CapturingSubscriber {
+        fn enabled(&self, _metadata: &Metadata<'_>) -> bool {
+            true
+        }
+
+        fn new_span(&self, attributes: &Attributes<'_>) -> Id {
+            let id = self.next_id.fetch_add(1, Ordering::SeqCst) + 1;
+            let metadata = attributes.metadata();
+            self.spans.lock().unwrap().insert(id, metadata);
+            let mut fields = self.fields.lock().unwrap();
+            attributes.record(&mut FieldVisitor(
+                fields.entry(metadata.name()).or_default(),
+            ));
+            Id::from_u64(id)
+        }
+
+        fn record(&self, span: &Id, values: &Record<'_>) {
+            let name = self.name(span);
+            let mut fields = self.fields.lock().unwrap();
+            values.record(&mut FieldVisitor(fields.entry(name).or_default()));
+        }
+
+        fn record_follows_from(&self, _span: &Id, _follows: &Id) {}
+
+        fn event(&self, _event: &Event<'_>) {}
+
+        fn enter(&self, span: &Id) {
+            self.stack.lock().unwrap().push(span.clone());
+        }
+
+        fn exit(&self, _span: &Id) {
+            self.stack.lock().unwrap().pop();
+        }
+
+        fn current_span(&self) -> Current {
+            match self.stack.lock().unwrap().last() {
+                Some(span) => {
+                    let metadata = self.spans.lock().unwrap()[&span.into_u64()];
+                    Current::new(span.clone(), metadata)
+                }
+                None => Current::none(),
+            }
+        }
+    }
+
+    #[test]
+    fn records_the_fields_of_the_operation_and_attempt_spans() {
+        let subscriber = CapturingSubscriber::default();
+        let fields = subscriber.fields.clone();
+
+        tracing::subscriber::with_default(subscriber, || {
+            let mut request = SignedRequest::new("POST", "sqs", &Region::UsEast1, "/");
+            request.set_operation("SendMessage");
+            let span = operation_span(&request);
+            let mut response = HttpResponse {
+                status: StatusCode::OK,
+                body: ByteStream::from(Vec::new()),
+                headers: Default::default(),
+            };
+            response
+                .headers
+                .insert("x-amzn-requestid", "request-id".to_owned());
+            let result = Ok(response);
+            span.in_scope(|| {
+                let attempt = attempt_span(2);
+                record_result(&attempt, &result);
+            });
+            record_result(&span, &result);
+        });
+
+        let fields = fields.lock().unwrap();
+        let operation = &fields["operation"];
+        assert_eq!(operation["otel.name"], "sqs.SendMessage");
+        assert_eq!(operation["rpc.service"], "sqs");
+        assert_eq!(operation["rpc.method"], "SendMessage");
+        assert_eq!(operation["aws.region"], "us-east-1");
+        assert_eq!(operation["aws.attempts"], "2");
+        assert_eq!(operation["http.status_code"], "200");
+        assert_eq!(operation["aws.request_id"], "request-id");
+        let attempt = &fields["attempt"];
+        assert_eq!(attempt["attempt"], "2");
+        assert_eq!(attempt["http.status_code"], "200");
+        assert_eq!(attempt["aws.request_id"], "request-id");
+    }
+}
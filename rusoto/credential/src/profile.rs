@@ -116,7 +116,7 @@ impl ProfileProvider {
     /// Default config file location:
     /// 1: if set and not empty, use the value from environment variable ```AWS_CONFIG_FILE```
     /// 2. otherwise return `~/.aws/config` (Linux/Mac) resp. `%USERPROFILE%\.aws\config` (Windows)
-    fn default_config_location() -> Result<PathBuf, CredentialsError> {
+    pub fn default_config_location() -> Result<PathBuf, CredentialsError> {
         let env = non_empty_env_var(AWS_CONFIG_FILE);
         match env {
             Some(path) => Ok(PathBuf::from(path)),
@@ -161,7 +161,7 @@ impl ProfileProvider {
     /// 1. if set and not empty, use value from environment variable ```AWS_PROFILE```
     /// 2. otherwise return ```"default"```
     /// see https://docs.aws.amazon.com/sdk-for-java/v1/developer-guide/credentials.html.
-    fn default_profile_name() -> String {
+    pub fn default_profile_name() -> String {
         non_empty_env_var(AWS_PROFILE).unwrap_or_else(|| DEFAULT.to_owned())
     }
 
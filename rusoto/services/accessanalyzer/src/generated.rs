@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -773,18 +774,38 @@ impl ApplyArchiveRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ApplyArchiveRuleError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ApplyArchiveRuleError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ApplyArchiveRuleError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ApplyArchiveRuleError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ApplyArchiveRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ApplyArchiveRuleError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ApplyArchiveRuleError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ApplyArchiveRuleError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -823,21 +844,46 @@ impl CreateAnalyzerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateAnalyzerError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAnalyzerError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateAnalyzerError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAnalyzerError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateAnalyzerError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAnalyzerError::InternalServer,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateAnalyzerError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAnalyzerError::ServiceQuotaExceeded,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateAnalyzerError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAnalyzerError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -879,26 +925,54 @@ impl CreateArchiveRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateArchiveRuleError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateArchiveRuleError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateArchiveRuleError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateArchiveRuleError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateArchiveRuleError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateArchiveRuleError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateArchiveRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateArchiveRuleError::ResourceNotFound,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateArchiveRuleError::ServiceQuotaExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateArchiveRuleError::ServiceQuotaExceeded,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateArchiveRuleError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateArchiveRuleError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -937,18 +1011,38 @@ impl DeleteAnalyzerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteAnalyzerError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAnalyzerError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteAnalyzerError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAnalyzerError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteAnalyzerError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAnalyzerError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteAnalyzerError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAnalyzerError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -985,18 +1079,38 @@ impl DeleteArchiveRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteArchiveRuleError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteArchiveRuleError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteArchiveRuleError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteArchiveRuleError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteArchiveRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteArchiveRuleError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteArchiveRuleError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteArchiveRuleError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1033,20 +1147,38 @@ impl GetAnalyzedResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetAnalyzedResourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAnalyzedResourceError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetAnalyzedResourceError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAnalyzedResourceError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetAnalyzedResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetAnalyzedResourceError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetAnalyzedResourceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAnalyzedResourceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1083,18 +1215,38 @@ impl GetAnalyzerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetAnalyzerError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAnalyzerError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetAnalyzerError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAnalyzerError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetAnalyzerError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAnalyzerError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetAnalyzerError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAnalyzerError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1131,18 +1283,38 @@ impl GetArchiveRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetArchiveRuleError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetArchiveRuleError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetArchiveRuleError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetArchiveRuleError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetArchiveRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetArchiveRuleError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetArchiveRuleError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetArchiveRuleError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1179,18 +1351,38 @@ impl GetFindingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetFindingError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFindingError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetFindingError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFindingError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetFindingError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFindingError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetFindingError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFindingError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1227,22 +1419,38 @@ impl ListAnalyzedResourcesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListAnalyzedResourcesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAnalyzedResourcesError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListAnalyzedResourcesError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAnalyzedResourcesError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListAnalyzedResourcesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAnalyzedResourcesError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListAnalyzedResourcesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAnalyzedResourcesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1277,15 +1485,30 @@ impl ListAnalyzersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListAnalyzersError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAnalyzersError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListAnalyzersError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAnalyzersError::InternalServer,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListAnalyzersError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAnalyzersError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1319,15 +1542,30 @@ impl ListArchiveRulesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListArchiveRulesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListArchiveRulesError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListArchiveRulesError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListArchiveRulesError::InternalServer,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListArchiveRulesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListArchiveRulesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1363,18 +1601,38 @@ impl ListFindingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListFindingsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFindingsError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListFindingsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFindingsError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListFindingsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFindingsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListFindingsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFindingsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1411,20 +1669,38 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1461,18 +1737,38 @@ impl StartResourceScanError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartResourceScanError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartResourceScanError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(StartResourceScanError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartResourceScanError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartResourceScanError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartResourceScanError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartResourceScanError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartResourceScanError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1509,18 +1805,38 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(TagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(TagResourceError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(TagResourceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1557,18 +1873,38 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UntagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UntagResourceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1605,18 +1941,38 @@ impl UpdateArchiveRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateArchiveRuleError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateArchiveRuleError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateArchiveRuleError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateArchiveRuleError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateArchiveRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateArchiveRuleError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateArchiveRuleError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateArchiveRuleError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1653,18 +2009,38 @@ impl UpdateFindingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateFindingsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFindingsError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateFindingsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFindingsError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateFindingsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFindingsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateFindingsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFindingsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1810,11 +2186,12 @@ pub struct AccessAnalyzerClient {
 impl AccessAnalyzerClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> AccessAnalyzerClient {
         AccessAnalyzerClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "AccessAnalyzer"),
         }
     }
 
@@ -1829,12 +2206,32 @@ impl AccessAnalyzerClient {
     {
         AccessAnalyzerClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "AccessAnalyzer"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> AccessAnalyzerClient {
-        AccessAnalyzerClient { client, region }
+        AccessAnalyzerClient {
+            client,
+            region: region_for_service(region, "AccessAnalyzer"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> AccessAnalyzerClient {
+        AccessAnalyzerClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("AccessAnalyzer"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> AccessAnalyzerClient {
+        AccessAnalyzerClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -1849,6 +2246,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = "/archive-rule";
 
         let mut request = SignedRequest::new("PUT", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("ApplyArchiveRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1879,6 +2277,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = "/analyzer";
 
         let mut request = SignedRequest::new("PUT", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("CreateAnalyzer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1913,6 +2312,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         );
 
         let mut request = SignedRequest::new("PUT", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("CreateArchiveRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1947,6 +2347,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("DeleteAnalyzer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1985,6 +2386,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("DeleteArchiveRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2018,6 +2420,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = "/analyzed-resource";
 
         let mut request = SignedRequest::new("GET", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("GetAnalyzedResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2054,6 +2457,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         );
 
         let mut request = SignedRequest::new("GET", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("GetAnalyzer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2086,6 +2490,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         );
 
         let mut request = SignedRequest::new("GET", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("GetArchiveRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2114,6 +2519,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = format!("/finding/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("GetFinding");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2146,6 +2552,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = "/analyzed-resource";
 
         let mut request = SignedRequest::new("POST", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("ListAnalyzedResources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2177,6 +2584,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = "/analyzer";
 
         let mut request = SignedRequest::new("GET", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("ListAnalyzers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2220,6 +2628,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         );
 
         let mut request = SignedRequest::new("GET", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("ListArchiveRules");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2257,6 +2666,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = "/finding";
 
         let mut request = SignedRequest::new("POST", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("ListFindings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2288,6 +2698,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2316,6 +2727,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = "/resource/scan";
 
         let mut request = SignedRequest::new("POST", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("StartResourceScan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2346,6 +2758,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2378,6 +2791,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2416,6 +2830,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         );
 
         let mut request = SignedRequest::new("PUT", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("UpdateArchiveRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2446,6 +2861,7 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         let request_uri = "/finding";
 
         let mut request = SignedRequest::new("PUT", "access-analyzer", &self.region, &request_uri);
+        request.set_operation("UpdateFindings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2467,3 +2883,242 @@ impl AccessAnalyzer for AccessAnalyzerClient {
         }
     }
 }
+
+/// A blocking client for the Access Analyzer API, running the calls of a `AccessAnalyzerClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `AccessAnalyzerClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct AccessAnalyzerBlockingClient {
+    client: AccessAnalyzerClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl AccessAnalyzerBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> AccessAnalyzerBlockingClient {
+        AccessAnalyzerBlockingClient::from_client(AccessAnalyzerClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> AccessAnalyzerBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        AccessAnalyzerBlockingClient::from_client(AccessAnalyzerClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> AccessAnalyzerBlockingClient {
+        AccessAnalyzerBlockingClient::from_client(AccessAnalyzerClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> AccessAnalyzerBlockingClient {
+        AccessAnalyzerBlockingClient::from_client(AccessAnalyzerClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: AccessAnalyzerClient) -> AccessAnalyzerBlockingClient {
+        AccessAnalyzerBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> AccessAnalyzerBlockingClient {
+        AccessAnalyzerBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> AccessAnalyzerBlockingClient {
+        AccessAnalyzerBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`AccessAnalyzer::apply_archive_rule`].
+    pub fn apply_archive_rule(
+        &self,
+        input: ApplyArchiveRuleRequest,
+    ) -> Result<(), RusotoError<ApplyArchiveRuleError>> {
+        self.runtime.block_on(self.client.apply_archive_rule(input))
+    }
+
+    /// Blocking version of [`AccessAnalyzer::create_analyzer`].
+    pub fn create_analyzer(
+        &self,
+        input: CreateAnalyzerRequest,
+    ) -> Result<CreateAnalyzerResponse, RusotoError<CreateAnalyzerError>> {
+        self.runtime.block_on(self.client.create_analyzer(input))
+    }
+
+    /// Blocking version of [`AccessAnalyzer::create_archive_rule`].
+    pub fn create_archive_rule(
+        &self,
+        input: CreateArchiveRuleRequest,
+    ) -> Result<(), RusotoError<CreateArchiveRuleError>> {
+        self.runtime
+            .block_on(self.client.create_archive_rule(input))
+    }
+
+    /// Blocking version of [`AccessAnalyzer::delete_analyzer`].
+    pub fn delete_analyzer(
+        &self,
+        input: DeleteAnalyzerRequest,
+    ) -> Result<(), RusotoError<DeleteAnalyzerError>> {
+        self.runtime.block_on(self.client.delete_analyzer(input))
+    }
+
+    /// Blocking version of [`AccessAnalyzer::delete_archive_rule`].
+    pub fn delete_archive_rule(
+        &self,
+        input: DeleteArchiveRuleRequest,
+    ) -> Result<(), RusotoError<DeleteArchiveRuleError>> {
+        self.runtime
+            .block_on(self.client.delete_archive_rule(input))
+    }
+
+    /// Blocking version of [`AccessAnalyzer::get_analyzed_resource`].
+    pub fn get_analyzed_resource(
+        &self,
+        input: GetAnalyzedResourceRequest,
+    ) -> Result<GetAnalyzedResourceResponse, RusotoError<GetAnalyzedResourceError>> {
+        self.runtime
+            .block_on(self.client.get_analyzed_resource(input))
+    }
+
+    /// Blocking version of [`AccessAnalyzer::get_analyzer`].
+    pub fn get_analyzer(
+        &self,
+        input: GetAnalyzerRequest,
+    ) -> Result<GetAnalyzerResponse, RusotoError<GetAnalyzerError>> {
+        self.runtime.block_on(self.client.get_analyzer(input))
+    }
+
+    /// Blocking version of [`AccessAnalyzer::get_archive_rule`].
+    pub fn get_archive_rule(
+        &self,
+        input: GetArchiveRuleRequest,
+    ) -> Result<GetArchiveRuleResponse, RusotoError<GetArchiveRuleError>> {
+        self.runtime.block_on(self.client.get_archive_rule(input))
+    }
+
+    /// Blocking version of [`AccessAnalyzer::get_finding`].
+    pub fn get_finding(
+        &self,
+        input: GetFindingRequest,
+    ) -> Result<GetFindingResponse, RusotoError<GetFindingError>> {
+        self.runtime.block_on(self.client.get_finding(input))
+    }
+
+    /// Blocking version of [`AccessAnalyzer::list_analyzed_resources`].
+    pub fn list_analyzed_resources(
+        &self,
+        input: ListAnalyzedResourcesRequest,
+    ) -> Result<ListAnalyzedResourcesResponse, RusotoError<ListAnalyzedResourcesError>> {
+        self.runtime
+            .block_on(self.client.list_analyzed_resources(input))
+    }
+
+    /// Blocking version of [`AccessAnalyzer::list_analyzers`].
+    pub fn list_analyzers(
+        &self,
+        input: ListAnalyzersRequest,
+    ) -> Result<ListAnalyzersResponse, RusotoError<ListAnalyzersError>> {
+        self.runtime.block_on(self.client.list_analyzers(input))
+    }
+
+    /// Blocking version of [`AccessAnalyzer::list_archive_rules`].
+    pub fn list_archive_rules(
+        &self,
+        input: ListArchiveRulesRequest,
+    ) -> Result<ListArchiveRulesResponse, RusotoError<ListArchiveRulesError>> {
+        self.runtime.block_on(self.client.list_archive_rules(input))
+    }
+
+    /// Blocking version of [`AccessAnalyzer::list_findings`].
+    pub fn list_findings(
+        &self,
+        input: ListFindingsRequest,
+    ) -> Result<ListFindingsResponse, RusotoError<ListFindingsError>> {
+        self.runtime.block_on(self.client.list_findings(input))
+    }
+
+    /// Blocking version of [`AccessAnalyzer::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`AccessAnalyzer::start_resource_scan`].
+    pub fn start_resource_scan(
+        &self,
+        input: StartResourceScanRequest,
+    ) -> Result<(), RusotoError<StartResourceScanError>> {
+        self.runtime
+            .block_on(self.client.start_resource_scan(input))
+    }
+
+    /// Blocking version of [`AccessAnalyzer::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`AccessAnalyzer::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`AccessAnalyzer::update_archive_rule`].
+    pub fn update_archive_rule(
+        &self,
+        input: UpdateArchiveRuleRequest,
+    ) -> Result<(), RusotoError<UpdateArchiveRuleError>> {
+        self.runtime
+            .block_on(self.client.update_archive_rule(input))
+    }
+
+    /// Blocking version of [`AccessAnalyzer::update_findings`].
+    pub fn update_findings(
+        &self,
+        input: UpdateFindingsRequest,
+    ) -> Result<(), RusotoError<UpdateFindingsError>> {
+        self.runtime.block_on(self.client.update_findings(input))
+    }
+}
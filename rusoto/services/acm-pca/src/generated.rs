@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -749,26 +750,38 @@ impl CreateCertificateAuthorityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgsException" => {
-                    return RusotoError::Service(CreateCertificateAuthorityError::InvalidArgs(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCertificateAuthorityError::InvalidArgs,
+                    )
                 }
                 "InvalidPolicyException" => {
-                    return RusotoError::Service(CreateCertificateAuthorityError::InvalidPolicy(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCertificateAuthorityError::InvalidPolicy,
+                    )
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(CreateCertificateAuthorityError::InvalidTag(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCertificateAuthorityError::InvalidTag,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateCertificateAuthorityError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCertificateAuthorityError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -811,36 +824,54 @@ impl CreateCertificateAuthorityAuditReportError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgsException" => {
-                    return RusotoError::Service(
-                        CreateCertificateAuthorityAuditReportError::InvalidArgs(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCertificateAuthorityAuditReportError::InvalidArgs,
                     )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(
-                        CreateCertificateAuthorityAuditReportError::InvalidArn(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCertificateAuthorityAuditReportError::InvalidArn,
                     )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(
-                        CreateCertificateAuthorityAuditReportError::InvalidState(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCertificateAuthorityAuditReportError::InvalidState,
                     )
                 }
                 "RequestFailedException" => {
-                    return RusotoError::Service(
-                        CreateCertificateAuthorityAuditReportError::RequestFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCertificateAuthorityAuditReportError::RequestFailed,
                     )
                 }
                 "RequestInProgressException" => {
-                    return RusotoError::Service(
-                        CreateCertificateAuthorityAuditReportError::RequestInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCertificateAuthorityAuditReportError::RequestInProgress,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        CreateCertificateAuthorityAuditReportError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCertificateAuthorityAuditReportError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -895,26 +926,54 @@ impl CreatePermissionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(CreatePermissionError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePermissionError::InvalidArn,
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(CreatePermissionError::InvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePermissionError::InvalidState,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreatePermissionError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePermissionError::LimitExceeded,
+                    )
                 }
                 "PermissionAlreadyExistsException" => {
-                    return RusotoError::Service(CreatePermissionError::PermissionAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePermissionError::PermissionAlreadyExists,
+                    )
                 }
                 "RequestFailedException" => {
-                    return RusotoError::Service(CreatePermissionError::RequestFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePermissionError::RequestFailed,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreatePermissionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePermissionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -955,26 +1014,38 @@ impl DeleteCertificateAuthorityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        DeleteCertificateAuthorityError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCertificateAuthorityError::ConcurrentModification,
                     )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DeleteCertificateAuthorityError::InvalidArn(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteCertificateAuthorityError::InvalidArn,
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(DeleteCertificateAuthorityError::InvalidState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteCertificateAuthorityError::InvalidState,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteCertificateAuthorityError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteCertificateAuthorityError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1013,18 +1084,38 @@ impl DeletePermissionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(DeletePermissionError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePermissionError::InvalidArn,
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(DeletePermissionError::InvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePermissionError::InvalidState,
+                    )
                 }
                 "RequestFailedException" => {
-                    return RusotoError::Service(DeletePermissionError::RequestFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePermissionError::RequestFailed,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeletePermissionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePermissionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1065,24 +1156,54 @@ impl DeletePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeletePolicyError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePolicyError::ConcurrentModification,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DeletePolicyError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePolicyError::InvalidArn,
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(DeletePolicyError::InvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePolicyError::InvalidState,
+                    )
                 }
                 "LockoutPreventedException" => {
-                    return RusotoError::Service(DeletePolicyError::LockoutPrevented(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePolicyError::LockoutPrevented,
+                    )
                 }
                 "RequestFailedException" => {
-                    return RusotoError::Service(DeletePolicyError::RequestFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePolicyError::RequestFailed,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeletePolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePolicyError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1119,16 +1240,22 @@ impl DescribeCertificateAuthorityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(DescribeCertificateAuthorityError::InvalidArn(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeCertificateAuthorityError::InvalidArn,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeCertificateAuthorityError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCertificateAuthorityError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1165,21 +1292,30 @@ impl DescribeCertificateAuthorityAuditReportError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgsException" => {
-                    return RusotoError::Service(
-                        DescribeCertificateAuthorityAuditReportError::InvalidArgs(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCertificateAuthorityAuditReportError::InvalidArgs,
                     )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(
-                        DescribeCertificateAuthorityAuditReportError::InvalidArn(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCertificateAuthorityAuditReportError::InvalidArn,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeCertificateAuthorityAuditReportError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCertificateAuthorityAuditReportError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1223,21 +1359,46 @@ impl GetCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetCertificateError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCertificateError::InvalidArn,
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(GetCertificateError::InvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCertificateError::InvalidState,
+                    )
                 }
                 "RequestFailedException" => {
-                    return RusotoError::Service(GetCertificateError::RequestFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCertificateError::RequestFailed,
+                    )
                 }
                 "RequestInProgressException" => {
-                    return RusotoError::Service(GetCertificateError::RequestInProgress(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCertificateError::RequestInProgress,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetCertificateError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCertificateError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1275,21 +1436,30 @@ impl GetCertificateAuthorityCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(
-                        GetCertificateAuthorityCertificateError::InvalidArn(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCertificateAuthorityCertificateError::InvalidArn,
                     )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(
-                        GetCertificateAuthorityCertificateError::InvalidState(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCertificateAuthorityCertificateError::InvalidState,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        GetCertificateAuthorityCertificateError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCertificateAuthorityCertificateError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1335,31 +1505,46 @@ impl GetCertificateAuthorityCsrError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetCertificateAuthorityCsrError::InvalidArn(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCertificateAuthorityCsrError::InvalidArn,
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(GetCertificateAuthorityCsrError::InvalidState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCertificateAuthorityCsrError::InvalidState,
+                    )
                 }
                 "RequestFailedException" => {
-                    return RusotoError::Service(GetCertificateAuthorityCsrError::RequestFailed(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCertificateAuthorityCsrError::RequestFailed,
+                    )
                 }
                 "RequestInProgressException" => {
-                    return RusotoError::Service(
-                        GetCertificateAuthorityCsrError::RequestInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCertificateAuthorityCsrError::RequestInProgress,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetCertificateAuthorityCsrError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCertificateAuthorityCsrError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1397,18 +1582,38 @@ impl GetPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetPolicyError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPolicyError::InvalidArn,
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(GetPolicyError::InvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPolicyError::InvalidState,
+                    )
                 }
                 "RequestFailedException" => {
-                    return RusotoError::Service(GetPolicyError::RequestFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPolicyError::RequestFailed,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetPolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPolicyError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1457,51 +1662,78 @@ impl ImportCertificateAuthorityCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CertificateMismatchException" => {
-                    return RusotoError::Service(
-                        ImportCertificateAuthorityCertificateError::CertificateMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportCertificateAuthorityCertificateError::CertificateMismatch,
                     )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        ImportCertificateAuthorityCertificateError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportCertificateAuthorityCertificateError::ConcurrentModification,
                     )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(
-                        ImportCertificateAuthorityCertificateError::InvalidArn(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportCertificateAuthorityCertificateError::InvalidArn,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        ImportCertificateAuthorityCertificateError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportCertificateAuthorityCertificateError::InvalidRequest,
                     )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(
-                        ImportCertificateAuthorityCertificateError::InvalidState(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportCertificateAuthorityCertificateError::InvalidState,
                     )
                 }
                 "MalformedCertificateException" => {
-                    return RusotoError::Service(
-                        ImportCertificateAuthorityCertificateError::MalformedCertificate(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportCertificateAuthorityCertificateError::MalformedCertificate,
                     )
                 }
                 "RequestFailedException" => {
-                    return RusotoError::Service(
-                        ImportCertificateAuthorityCertificateError::RequestFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportCertificateAuthorityCertificateError::RequestFailed,
                     )
                 }
                 "RequestInProgressException" => {
-                    return RusotoError::Service(
-                        ImportCertificateAuthorityCertificateError::RequestInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportCertificateAuthorityCertificateError::RequestInProgress,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        ImportCertificateAuthorityCertificateError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportCertificateAuthorityCertificateError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1565,24 +1797,54 @@ impl IssueCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgsException" => {
-                    return RusotoError::Service(IssueCertificateError::InvalidArgs(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        IssueCertificateError::InvalidArgs,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(IssueCertificateError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        IssueCertificateError::InvalidArn,
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(IssueCertificateError::InvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        IssueCertificateError::InvalidState,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(IssueCertificateError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        IssueCertificateError::LimitExceeded,
+                    )
                 }
                 "MalformedCSRException" => {
-                    return RusotoError::Service(IssueCertificateError::MalformedCSR(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        IssueCertificateError::MalformedCSR,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(IssueCertificateError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        IssueCertificateError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1617,11 +1879,14 @@ impl ListCertificateAuthoritiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListCertificateAuthoritiesError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListCertificateAuthoritiesError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1657,21 +1922,46 @@ impl ListPermissionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListPermissionsError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPermissionsError::InvalidArn,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListPermissionsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPermissionsError::InvalidNextToken,
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(ListPermissionsError::InvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPermissionsError::InvalidState,
+                    )
                 }
                 "RequestFailedException" => {
-                    return RusotoError::Service(ListPermissionsError::RequestFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPermissionsError::RequestFailed,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListPermissionsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPermissionsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1707,15 +1997,30 @@ impl ListTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListTagsError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsError::InvalidArn,
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(ListTagsError::InvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsError::InvalidState,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1757,27 +2062,62 @@ impl PutPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(PutPolicyError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutPolicyError::ConcurrentModification,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(PutPolicyError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutPolicyError::InvalidArn,
+                    )
                 }
                 "InvalidPolicyException" => {
-                    return RusotoError::Service(PutPolicyError::InvalidPolicy(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutPolicyError::InvalidPolicy,
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(PutPolicyError::InvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutPolicyError::InvalidState,
+                    )
                 }
                 "LockoutPreventedException" => {
-                    return RusotoError::Service(PutPolicyError::LockoutPrevented(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutPolicyError::LockoutPrevented,
+                    )
                 }
                 "RequestFailedException" => {
-                    return RusotoError::Service(PutPolicyError::RequestFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutPolicyError::RequestFailed,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutPolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutPolicyError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1817,21 +2157,30 @@ impl RestoreCertificateAuthorityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(RestoreCertificateAuthorityError::InvalidArn(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RestoreCertificateAuthorityError::InvalidArn,
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(RestoreCertificateAuthorityError::InvalidState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RestoreCertificateAuthorityError::InvalidState,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        RestoreCertificateAuthorityError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RestoreCertificateAuthorityError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1877,37 +2226,78 @@ impl RevokeCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(RevokeCertificateError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RevokeCertificateError::ConcurrentModification,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(RevokeCertificateError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RevokeCertificateError::InvalidArn,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RevokeCertificateError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RevokeCertificateError::InvalidRequest,
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(RevokeCertificateError::InvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RevokeCertificateError::InvalidState,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RevokeCertificateError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RevokeCertificateError::LimitExceeded,
+                    )
                 }
                 "RequestAlreadyProcessedException" => {
-                    return RusotoError::Service(RevokeCertificateError::RequestAlreadyProcessed(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RevokeCertificateError::RequestAlreadyProcessed,
+                    )
                 }
                 "RequestFailedException" => {
-                    return RusotoError::Service(RevokeCertificateError::RequestFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RevokeCertificateError::RequestFailed,
+                    )
                 }
                 "RequestInProgressException" => {
-                    return RusotoError::Service(RevokeCertificateError::RequestInProgress(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RevokeCertificateError::RequestInProgress,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RevokeCertificateError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RevokeCertificateError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1951,25 +2341,46 @@ impl TagCertificateAuthorityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(TagCertificateAuthorityError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagCertificateAuthorityError::InvalidArn,
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(TagCertificateAuthorityError::InvalidState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TagCertificateAuthorityError::InvalidState,
+                    )
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(TagCertificateAuthorityError::InvalidTag(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagCertificateAuthorityError::InvalidTag,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagCertificateAuthorityError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TagCertificateAuthorityError::ResourceNotFound,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagCertificateAuthorityError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagCertificateAuthorityError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2007,26 +2418,38 @@ impl UntagCertificateAuthorityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(UntagCertificateAuthorityError::InvalidArn(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UntagCertificateAuthorityError::InvalidArn,
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(UntagCertificateAuthorityError::InvalidState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UntagCertificateAuthorityError::InvalidState,
+                    )
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(UntagCertificateAuthorityError::InvalidTag(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UntagCertificateAuthorityError::InvalidTag,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagCertificateAuthorityError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UntagCertificateAuthorityError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2069,36 +2492,54 @@ impl UpdateCertificateAuthorityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        UpdateCertificateAuthorityError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCertificateAuthorityError::ConcurrentModification,
                     )
                 }
                 "InvalidArgsException" => {
-                    return RusotoError::Service(UpdateCertificateAuthorityError::InvalidArgs(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateCertificateAuthorityError::InvalidArgs,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(UpdateCertificateAuthorityError::InvalidArn(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateCertificateAuthorityError::InvalidArn,
+                    )
                 }
                 "InvalidPolicyException" => {
-                    return RusotoError::Service(UpdateCertificateAuthorityError::InvalidPolicy(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateCertificateAuthorityError::InvalidPolicy,
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(UpdateCertificateAuthorityError::InvalidState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateCertificateAuthorityError::InvalidState,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateCertificateAuthorityError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateCertificateAuthorityError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2278,11 +2719,12 @@ pub struct AcmPcaClient {
 impl AcmPcaClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> AcmPcaClient {
         AcmPcaClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "ACM PCA"),
         }
     }
 
@@ -2297,12 +2739,32 @@ impl AcmPcaClient {
     {
         AcmPcaClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "ACM PCA"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> AcmPcaClient {
-        AcmPcaClient { client, region }
+        AcmPcaClient {
+            client,
+            region: region_for_service(region, "ACM PCA"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> AcmPcaClient {
+        AcmPcaClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("ACM PCA"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> AcmPcaClient {
+        AcmPcaClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -2315,6 +2777,7 @@ impl AcmPca for AcmPcaClient {
     ) -> Result<CreateCertificateAuthorityResponse, RusotoError<CreateCertificateAuthorityError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateCertificateAuthority");
         request.add_header("x-amz-target", "ACMPrivateCA.CreateCertificateAuthority");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2337,6 +2800,7 @@ impl AcmPca for AcmPcaClient {
         RusotoError<CreateCertificateAuthorityAuditReportError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateCertificateAuthorityAuditReport");
         request.add_header(
             "x-amz-target",
             "ACMPrivateCA.CreateCertificateAuthorityAuditReport",
@@ -2362,6 +2826,7 @@ impl AcmPca for AcmPcaClient {
         input: CreatePermissionRequest,
     ) -> Result<(), RusotoError<CreatePermissionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreatePermission");
         request.add_header("x-amz-target", "ACMPrivateCA.CreatePermission");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2379,6 +2844,7 @@ impl AcmPca for AcmPcaClient {
         input: DeleteCertificateAuthorityRequest,
     ) -> Result<(), RusotoError<DeleteCertificateAuthorityError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteCertificateAuthority");
         request.add_header("x-amz-target", "ACMPrivateCA.DeleteCertificateAuthority");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2396,6 +2862,7 @@ impl AcmPca for AcmPcaClient {
         input: DeletePermissionRequest,
     ) -> Result<(), RusotoError<DeletePermissionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeletePermission");
         request.add_header("x-amz-target", "ACMPrivateCA.DeletePermission");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2413,6 +2880,7 @@ impl AcmPca for AcmPcaClient {
         input: DeletePolicyRequest,
     ) -> Result<(), RusotoError<DeletePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeletePolicy");
         request.add_header("x-amz-target", "ACMPrivateCA.DeletePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2431,6 +2899,7 @@ impl AcmPca for AcmPcaClient {
     ) -> Result<DescribeCertificateAuthorityResponse, RusotoError<DescribeCertificateAuthorityError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeCertificateAuthority");
         request.add_header("x-amz-target", "ACMPrivateCA.DescribeCertificateAuthority");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2453,6 +2922,7 @@ impl AcmPca for AcmPcaClient {
         RusotoError<DescribeCertificateAuthorityAuditReportError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeCertificateAuthorityAuditReport");
         request.add_header(
             "x-amz-target",
             "ACMPrivateCA.DescribeCertificateAuthorityAuditReport",
@@ -2478,6 +2948,7 @@ impl AcmPca for AcmPcaClient {
         input: GetCertificateRequest,
     ) -> Result<GetCertificateResponse, RusotoError<GetCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetCertificate");
         request.add_header("x-amz-target", "ACMPrivateCA.GetCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2499,6 +2970,7 @@ impl AcmPca for AcmPcaClient {
         RusotoError<GetCertificateAuthorityCertificateError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetCertificateAuthorityCertificate");
         request.add_header(
             "x-amz-target",
             "ACMPrivateCA.GetCertificateAuthorityCertificate",
@@ -2525,6 +2997,7 @@ impl AcmPca for AcmPcaClient {
     ) -> Result<GetCertificateAuthorityCsrResponse, RusotoError<GetCertificateAuthorityCsrError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetCertificateAuthorityCsr");
         request.add_header("x-amz-target", "ACMPrivateCA.GetCertificateAuthorityCsr");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2544,6 +3017,7 @@ impl AcmPca for AcmPcaClient {
         input: GetPolicyRequest,
     ) -> Result<GetPolicyResponse, RusotoError<GetPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetPolicy");
         request.add_header("x-amz-target", "ACMPrivateCA.GetPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2562,6 +3036,7 @@ impl AcmPca for AcmPcaClient {
         input: ImportCertificateAuthorityCertificateRequest,
     ) -> Result<(), RusotoError<ImportCertificateAuthorityCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ImportCertificateAuthorityCertificate");
         request.add_header(
             "x-amz-target",
             "ACMPrivateCA.ImportCertificateAuthorityCertificate",
@@ -2585,6 +3060,7 @@ impl AcmPca for AcmPcaClient {
         input: IssueCertificateRequest,
     ) -> Result<IssueCertificateResponse, RusotoError<IssueCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("IssueCertificate");
         request.add_header("x-amz-target", "ACMPrivateCA.IssueCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2604,6 +3080,7 @@ impl AcmPca for AcmPcaClient {
     ) -> Result<ListCertificateAuthoritiesResponse, RusotoError<ListCertificateAuthoritiesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListCertificateAuthorities");
         request.add_header("x-amz-target", "ACMPrivateCA.ListCertificateAuthorities");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2623,6 +3100,7 @@ impl AcmPca for AcmPcaClient {
         input: ListPermissionsRequest,
     ) -> Result<ListPermissionsResponse, RusotoError<ListPermissionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListPermissions");
         request.add_header("x-amz-target", "ACMPrivateCA.ListPermissions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2641,6 +3119,7 @@ impl AcmPca for AcmPcaClient {
         input: ListTagsRequest,
     ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTags");
         request.add_header("x-amz-target", "ACMPrivateCA.ListTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2656,6 +3135,7 @@ impl AcmPca for AcmPcaClient {
     /// <p><p>Attaches a resource-based policy to a private CA. </p> <p>A policy can also be applied by <a href="https://docs.aws.amazon.com/acm-pca/latest/userguide/pca-ram.html">sharing</a> a private CA through AWS Resource Access Manager (RAM).</p> <p>The policy can be displayed with <a href="https://docs.aws.amazon.com/acm-pca/latest/APIReference/API_GetPolicy.html">GetPolicy</a> and removed with <a href="https://docs.aws.amazon.com/acm-pca/latest/APIReference/API_DeletePolicy.html">DeletePolicy</a>.</p> <p class="title"> <b>About Policies</b> </p> <ul> <li> <p>A policy grants access on a private CA to an AWS customer account, to AWS Organizations, or to an AWS Organizations unit. Policies are under the control of a CA administrator. For more information, see <a href="acm-pca/latest/userguide/pca-rbp.html">Using a Resource Based Policy with ACM Private CA</a>.</p> </li> <li> <p>A policy permits a user of AWS Certificate Manager (ACM) to issue ACM certificates signed by a CA in another account.</p> </li> <li> <p>For ACM to manage automatic renewal of these certificates, the ACM user must configure a Service Linked Role (SLR). The SLR allows the ACM service to assume the identity of the user, subject to confirmation against the ACM Private CA policy. For more information, see <a href="https://docs.aws.amazon.com/acm/latest/userguide/acm-slr.html">Using a Service Linked Role with ACM</a>.</p> </li> <li> <p>Updates made in AWS Resource Manager (RAM) are reflected in policies. For more information, see <a href="acm-pca/latest/userguide/pca-ram.html">Using AWS Resource Access Manager (RAM) with ACM Private CA</a>.</p> </li> </ul></p>
     async fn put_policy(&self, input: PutPolicyRequest) -> Result<(), RusotoError<PutPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutPolicy");
         request.add_header("x-amz-target", "ACMPrivateCA.PutPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2673,6 +3153,7 @@ impl AcmPca for AcmPcaClient {
         input: RestoreCertificateAuthorityRequest,
     ) -> Result<(), RusotoError<RestoreCertificateAuthorityError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RestoreCertificateAuthority");
         request.add_header("x-amz-target", "ACMPrivateCA.RestoreCertificateAuthority");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2690,6 +3171,7 @@ impl AcmPca for AcmPcaClient {
         input: RevokeCertificateRequest,
     ) -> Result<(), RusotoError<RevokeCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RevokeCertificate");
         request.add_header("x-amz-target", "ACMPrivateCA.RevokeCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2707,6 +3189,7 @@ impl AcmPca for AcmPcaClient {
         input: TagCertificateAuthorityRequest,
     ) -> Result<(), RusotoError<TagCertificateAuthorityError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagCertificateAuthority");
         request.add_header("x-amz-target", "ACMPrivateCA.TagCertificateAuthority");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2724,6 +3207,7 @@ impl AcmPca for AcmPcaClient {
         input: UntagCertificateAuthorityRequest,
     ) -> Result<(), RusotoError<UntagCertificateAuthorityError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagCertificateAuthority");
         request.add_header("x-amz-target", "ACMPrivateCA.UntagCertificateAuthority");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2741,6 +3225,7 @@ impl AcmPca for AcmPcaClient {
         input: UpdateCertificateAuthorityRequest,
     ) -> Result<(), RusotoError<UpdateCertificateAuthorityError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateCertificateAuthority");
         request.add_header("x-amz-target", "ACMPrivateCA.UpdateCertificateAuthority");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2752,3 +3237,287 @@ impl AcmPca for AcmPcaClient {
         Ok(())
     }
 }
+
+/// A blocking client for the ACM-PCA API, running the calls of a `AcmPcaClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `AcmPcaClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct AcmPcaBlockingClient {
+    client: AcmPcaClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl AcmPcaBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> AcmPcaBlockingClient {
+        AcmPcaBlockingClient::from_client(AcmPcaClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> AcmPcaBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        AcmPcaBlockingClient::from_client(AcmPcaClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> AcmPcaBlockingClient {
+        AcmPcaBlockingClient::from_client(AcmPcaClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> AcmPcaBlockingClient {
+        AcmPcaBlockingClient::from_client(AcmPcaClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: AcmPcaClient) -> AcmPcaBlockingClient {
+        AcmPcaBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> AcmPcaBlockingClient {
+        AcmPcaBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> AcmPcaBlockingClient {
+        AcmPcaBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`AcmPca::create_certificate_authority`].
+    pub fn create_certificate_authority(
+        &self,
+        input: CreateCertificateAuthorityRequest,
+    ) -> Result<CreateCertificateAuthorityResponse, RusotoError<CreateCertificateAuthorityError>>
+    {
+        self.runtime
+            .block_on(self.client.create_certificate_authority(input))
+    }
+
+    /// Blocking version of [`AcmPca::create_certificate_authority_audit_report`].
+    pub fn create_certificate_authority_audit_report(
+        &self,
+        input: CreateCertificateAuthorityAuditReportRequest,
+    ) -> Result<
+        CreateCertificateAuthorityAuditReportResponse,
+        RusotoError<CreateCertificateAuthorityAuditReportError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_certificate_authority_audit_report(input))
+    }
+
+    /// Blocking version of [`AcmPca::create_permission`].
+    pub fn create_permission(
+        &self,
+        input: CreatePermissionRequest,
+    ) -> Result<(), RusotoError<CreatePermissionError>> {
+        self.runtime.block_on(self.client.create_permission(input))
+    }
+
+    /// Blocking version of [`AcmPca::delete_certificate_authority`].
+    pub fn delete_certificate_authority(
+        &self,
+        input: DeleteCertificateAuthorityRequest,
+    ) -> Result<(), RusotoError<DeleteCertificateAuthorityError>> {
+        self.runtime
+            .block_on(self.client.delete_certificate_authority(input))
+    }
+
+    /// Blocking version of [`AcmPca::delete_permission`].
+    pub fn delete_permission(
+        &self,
+        input: DeletePermissionRequest,
+    ) -> Result<(), RusotoError<DeletePermissionError>> {
+        self.runtime.block_on(self.client.delete_permission(input))
+    }
+
+    /// Blocking version of [`AcmPca::delete_policy`].
+    pub fn delete_policy(
+        &self,
+        input: DeletePolicyRequest,
+    ) -> Result<(), RusotoError<DeletePolicyError>> {
+        self.runtime.block_on(self.client.delete_policy(input))
+    }
+
+    /// Blocking version of [`AcmPca::describe_certificate_authority`].
+    pub fn describe_certificate_authority(
+        &self,
+        input: DescribeCertificateAuthorityRequest,
+    ) -> Result<DescribeCertificateAuthorityResponse, RusotoError<DescribeCertificateAuthorityError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_certificate_authority(input))
+    }
+
+    /// Blocking version of [`AcmPca::describe_certificate_authority_audit_report`].
+    pub fn describe_certificate_authority_audit_report(
+        &self,
+        input: DescribeCertificateAuthorityAuditReportRequest,
+    ) -> Result<
+        DescribeCertificateAuthorityAuditReportResponse,
+        RusotoError<DescribeCertificateAuthorityAuditReportError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_certificate_authority_audit_report(input),
+        )
+    }
+
+    /// Blocking version of [`AcmPca::get_certificate`].
+    pub fn get_certificate(
+        &self,
+        input: GetCertificateRequest,
+    ) -> Result<GetCertificateResponse, RusotoError<GetCertificateError>> {
+        self.runtime.block_on(self.client.get_certificate(input))
+    }
+
+    /// Blocking version of [`AcmPca::get_certificate_authority_certificate`].
+    pub fn get_certificate_authority_certificate(
+        &self,
+        input: GetCertificateAuthorityCertificateRequest,
+    ) -> Result<
+        GetCertificateAuthorityCertificateResponse,
+        RusotoError<GetCertificateAuthorityCertificateError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_certificate_authority_certificate(input))
+    }
+
+    /// Blocking version of [`AcmPca::get_certificate_authority_csr`].
+    pub fn get_certificate_authority_csr(
+        &self,
+        input: GetCertificateAuthorityCsrRequest,
+    ) -> Result<GetCertificateAuthorityCsrResponse, RusotoError<GetCertificateAuthorityCsrError>>
+    {
+        self.runtime
+            .block_on(self.client.get_certificate_authority_csr(input))
+    }
+
+    /// Blocking version of [`AcmPca::get_policy`].
+    pub fn get_policy(
+        &self,
+        input: GetPolicyRequest,
+    ) -> Result<GetPolicyResponse, RusotoError<GetPolicyError>> {
+        self.runtime.block_on(self.client.get_policy(input))
+    }
+
+    /// Blocking version of [`AcmPca::import_certificate_authority_certificate`].
+    pub fn import_certificate_authority_certificate(
+        &self,
+        input: ImportCertificateAuthorityCertificateRequest,
+    ) -> Result<(), RusotoError<ImportCertificateAuthorityCertificateError>> {
+        self.runtime
+            .block_on(self.client.import_certificate_authority_certificate(input))
+    }
+
+    /// Blocking version of [`AcmPca::issue_certificate`].
+    pub fn issue_certificate(
+        &self,
+        input: IssueCertificateRequest,
+    ) -> Result<IssueCertificateResponse, RusotoError<IssueCertificateError>> {
+        self.runtime.block_on(self.client.issue_certificate(input))
+    }
+
+    /// Blocking version of [`AcmPca::list_certificate_authorities`].
+    pub fn list_certificate_authorities(
+        &self,
+        input: ListCertificateAuthoritiesRequest,
+    ) -> Result<ListCertificateAuthoritiesResponse, RusotoError<ListCertificateAuthoritiesError>>
+    {
+        self.runtime
+            .block_on(self.client.list_certificate_authorities(input))
+    }
+
+    /// Blocking version of [`AcmPca::list_permissions`].
+    pub fn list_permissions(
+        &self,
+        input: ListPermissionsRequest,
+    ) -> Result<ListPermissionsResponse, RusotoError<ListPermissionsError>> {
+        self.runtime.block_on(self.client.list_permissions(input))
+    }
+
+    /// Blocking version of [`AcmPca::list_tags`].
+    pub fn list_tags(
+        &self,
+        input: ListTagsRequest,
+    ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
+        self.runtime.block_on(self.client.list_tags(input))
+    }
+
+    /// Blocking version of [`AcmPca::put_policy`].
+    pub fn put_policy(&self, input: PutPolicyRequest) -> Result<(), RusotoError<PutPolicyError>> {
+        self.runtime.block_on(self.client.put_policy(input))
+    }
+
+    /// Blocking version of [`AcmPca::restore_certificate_authority`].
+    pub fn restore_certificate_authority(
+        &self,
+        input: RestoreCertificateAuthorityRequest,
+    ) -> Result<(), RusotoError<RestoreCertificateAuthorityError>> {
+        self.runtime
+            .block_on(self.client.restore_certificate_authority(input))
+    }
+
+    /// Blocking version of [`AcmPca::revoke_certificate`].
+    pub fn revoke_certificate(
+        &self,
+        input: RevokeCertificateRequest,
+    ) -> Result<(), RusotoError<RevokeCertificateError>> {
+        self.runtime.block_on(self.client.revoke_certificate(input))
+    }
+
+    /// Blocking version of [`AcmPca::tag_certificate_authority`].
+    pub fn tag_certificate_authority(
+        &self,
+        input: TagCertificateAuthorityRequest,
+    ) -> Result<(), RusotoError<TagCertificateAuthorityError>> {
+        self.runtime
+            .block_on(self.client.tag_certificate_authority(input))
+    }
+
+    /// Blocking version of [`AcmPca::untag_certificate_authority`].
+    pub fn untag_certificate_authority(
+        &self,
+        input: UntagCertificateAuthorityRequest,
+    ) -> Result<(), RusotoError<UntagCertificateAuthorityError>> {
+        self.runtime
+            .block_on(self.client.untag_certificate_authority(input))
+    }
+
+    /// Blocking version of [`AcmPca::update_certificate_authority`].
+    pub fn update_certificate_authority(
+        &self,
+        input: UpdateCertificateAuthorityRequest,
+    ) -> Result<(), RusotoError<UpdateCertificateAuthorityError>> {
+        self.runtime
+            .block_on(self.client.update_certificate_authority(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -611,28 +612,54 @@ impl AddTagsToCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(AddTagsToCertificateError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsToCertificateError::InvalidArn,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AddTagsToCertificateError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddTagsToCertificateError::InvalidParameter,
+                    )
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(AddTagsToCertificateError::InvalidTag(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsToCertificateError::InvalidTag,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddTagsToCertificateError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddTagsToCertificateError::ResourceNotFound,
+                    )
                 }
                 "TagPolicyException" => {
-                    return RusotoError::Service(AddTagsToCertificateError::TagPolicy(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsToCertificateError::TagPolicy,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(AddTagsToCertificateError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsToCertificateError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -669,15 +696,30 @@ impl DeleteCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(DeleteCertificateError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCertificateError::InvalidArn,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteCertificateError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCertificateError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteCertificateError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCertificateError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -709,14 +751,22 @@ impl DescribeCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(DescribeCertificateError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCertificateError::InvalidArn,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeCertificateError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeCertificateError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -749,15 +799,30 @@ impl ExportCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(ExportCertificateError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExportCertificateError::InvalidArn,
+                    )
                 }
                 "RequestInProgressException" => {
-                    return RusotoError::Service(ExportCertificateError::RequestInProgress(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExportCertificateError::RequestInProgress,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ExportCertificateError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExportCertificateError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -791,15 +856,30 @@ impl GetCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetCertificateError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCertificateError::InvalidArn,
+                    )
                 }
                 "RequestInProgressException" => {
-                    return RusotoError::Service(GetCertificateError::RequestInProgress(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCertificateError::RequestInProgress,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetCertificateError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCertificateError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -839,24 +919,54 @@ impl ImportCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ImportCertificateError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportCertificateError::InvalidParameter,
+                    )
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(ImportCertificateError::InvalidTag(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportCertificateError::InvalidTag,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ImportCertificateError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportCertificateError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ImportCertificateError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportCertificateError::ResourceNotFound,
+                    )
                 }
                 "TagPolicyException" => {
-                    return RusotoError::Service(ImportCertificateError::TagPolicy(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportCertificateError::TagPolicy,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(ImportCertificateError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportCertificateError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -889,9 +999,14 @@ impl ListCertificatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgsException" => {
-                    return RusotoError::Service(ListCertificatesError::InvalidArgs(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCertificatesError::InvalidArgs,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -921,14 +1036,22 @@ impl ListTagsForCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListTagsForCertificateError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForCertificateError::InvalidArn,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForCertificateError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForCertificateError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -965,29 +1088,46 @@ impl RemoveTagsFromCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(RemoveTagsFromCertificateError::InvalidArn(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveTagsFromCertificateError::InvalidArn,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RemoveTagsFromCertificateError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveTagsFromCertificateError::InvalidParameter,
+                    )
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(RemoveTagsFromCertificateError::InvalidTag(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveTagsFromCertificateError::InvalidTag,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RemoveTagsFromCertificateError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveTagsFromCertificateError::ResourceNotFound,
+                    )
                 }
                 "TagPolicyException" => {
-                    return RusotoError::Service(RemoveTagsFromCertificateError::TagPolicy(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveTagsFromCertificateError::TagPolicy,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1021,12 +1161,22 @@ impl RenewCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(RenewCertificateError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RenewCertificateError::InvalidArn,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RenewCertificateError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RenewCertificateError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1067,29 +1217,62 @@ impl RequestCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(RequestCertificateError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RequestCertificateError::InvalidArn,
+                    )
                 }
                 "InvalidDomainValidationOptionsException" => {
-                    return RusotoError::Service(
-                        RequestCertificateError::InvalidDomainValidationOptions(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RequestCertificateError::InvalidDomainValidationOptions,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RequestCertificateError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RequestCertificateError::InvalidParameter,
+                    )
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(RequestCertificateError::InvalidTag(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RequestCertificateError::InvalidTag,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RequestCertificateError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RequestCertificateError::LimitExceeded,
+                    )
                 }
                 "TagPolicyException" => {
-                    return RusotoError::Service(RequestCertificateError::TagPolicy(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RequestCertificateError::TagPolicy,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(RequestCertificateError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RequestCertificateError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1131,22 +1314,38 @@ impl ResendValidationEmailError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(ResendValidationEmailError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResendValidationEmailError::InvalidArn,
+                    )
                 }
                 "InvalidDomainValidationOptionsException" => {
-                    return RusotoError::Service(
-                        ResendValidationEmailError::InvalidDomainValidationOptions(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResendValidationEmailError::InvalidDomainValidationOptions,
                     )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(ResendValidationEmailError::InvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResendValidationEmailError::InvalidState,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ResendValidationEmailError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ResendValidationEmailError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1185,24 +1384,38 @@ impl UpdateCertificateOptionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(UpdateCertificateOptionsError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCertificateOptionsError::InvalidArn,
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(UpdateCertificateOptionsError::InvalidState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateCertificateOptionsError::InvalidState,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateCertificateOptionsError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateCertificateOptionsError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateCertificateOptionsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateCertificateOptionsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1312,11 +1525,12 @@ pub struct AcmClient {
 impl AcmClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> AcmClient {
         AcmClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "ACM"),
         }
     }
 
@@ -1331,12 +1545,32 @@ impl AcmClient {
     {
         AcmClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "ACM"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> AcmClient {
-        AcmClient { client, region }
+        AcmClient {
+            client,
+            region: region_for_service(region, "ACM"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> AcmClient {
+        AcmClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("ACM"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> AcmClient {
+        AcmClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -1348,6 +1582,7 @@ impl Acm for AcmClient {
         input: AddTagsToCertificateRequest,
     ) -> Result<(), RusotoError<AddTagsToCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AddTagsToCertificate");
         request.add_header("x-amz-target", "CertificateManager.AddTagsToCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1365,6 +1600,7 @@ impl Acm for AcmClient {
         input: DeleteCertificateRequest,
     ) -> Result<(), RusotoError<DeleteCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteCertificate");
         request.add_header("x-amz-target", "CertificateManager.DeleteCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1382,6 +1618,7 @@ impl Acm for AcmClient {
         input: DescribeCertificateRequest,
     ) -> Result<DescribeCertificateResponse, RusotoError<DescribeCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeCertificate");
         request.add_header("x-amz-target", "CertificateManager.DescribeCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1400,6 +1637,7 @@ impl Acm for AcmClient {
         input: ExportCertificateRequest,
     ) -> Result<ExportCertificateResponse, RusotoError<ExportCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ExportCertificate");
         request.add_header("x-amz-target", "CertificateManager.ExportCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1418,6 +1656,7 @@ impl Acm for AcmClient {
         input: GetCertificateRequest,
     ) -> Result<GetCertificateResponse, RusotoError<GetCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetCertificate");
         request.add_header("x-amz-target", "CertificateManager.GetCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1436,6 +1675,7 @@ impl Acm for AcmClient {
         input: ImportCertificateRequest,
     ) -> Result<ImportCertificateResponse, RusotoError<ImportCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ImportCertificate");
         request.add_header("x-amz-target", "CertificateManager.ImportCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1454,6 +1694,7 @@ impl Acm for AcmClient {
         input: ListCertificatesRequest,
     ) -> Result<ListCertificatesResponse, RusotoError<ListCertificatesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListCertificates");
         request.add_header("x-amz-target", "CertificateManager.ListCertificates");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1472,6 +1713,7 @@ impl Acm for AcmClient {
         input: ListTagsForCertificateRequest,
     ) -> Result<ListTagsForCertificateResponse, RusotoError<ListTagsForCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForCertificate");
         request.add_header("x-amz-target", "CertificateManager.ListTagsForCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1491,6 +1733,7 @@ impl Acm for AcmClient {
         input: RemoveTagsFromCertificateRequest,
     ) -> Result<(), RusotoError<RemoveTagsFromCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RemoveTagsFromCertificate");
         request.add_header(
             "x-amz-target",
             "CertificateManager.RemoveTagsFromCertificate",
@@ -1511,6 +1754,7 @@ impl Acm for AcmClient {
         input: RenewCertificateRequest,
     ) -> Result<(), RusotoError<RenewCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RenewCertificate");
         request.add_header("x-amz-target", "CertificateManager.RenewCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1528,6 +1772,7 @@ impl Acm for AcmClient {
         input: RequestCertificateRequest,
     ) -> Result<RequestCertificateResponse, RusotoError<RequestCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RequestCertificate");
         request.add_header("x-amz-target", "CertificateManager.RequestCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1546,6 +1791,7 @@ impl Acm for AcmClient {
         input: ResendValidationEmailRequest,
     ) -> Result<(), RusotoError<ResendValidationEmailError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ResendValidationEmail");
         request.add_header("x-amz-target", "CertificateManager.ResendValidationEmail");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1563,6 +1809,7 @@ impl Acm for AcmClient {
         input: UpdateCertificateOptionsRequest,
     ) -> Result<(), RusotoError<UpdateCertificateOptionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateCertificateOptions");
         request.add_header(
             "x-amz-target",
             "CertificateManager.UpdateCertificateOptions",
@@ -1577,3 +1824,189 @@ impl Acm for AcmClient {
         Ok(())
     }
 }
+
+/// A blocking client for the ACM API, running the calls of a `AcmClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `AcmClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct AcmBlockingClient {
+    client: AcmClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl AcmBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> AcmBlockingClient {
+        AcmBlockingClient::from_client(AcmClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> AcmBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        AcmBlockingClient::from_client(AcmClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> AcmBlockingClient {
+        AcmBlockingClient::from_client(AcmClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> AcmBlockingClient {
+        AcmBlockingClient::from_client(AcmClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: AcmClient) -> AcmBlockingClient {
+        AcmBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> AcmBlockingClient {
+        AcmBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> AcmBlockingClient {
+        AcmBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Acm::add_tags_to_certificate`].
+    pub fn add_tags_to_certificate(
+        &self,
+        input: AddTagsToCertificateRequest,
+    ) -> Result<(), RusotoError<AddTagsToCertificateError>> {
+        self.runtime
+            .block_on(self.client.add_tags_to_certificate(input))
+    }
+
+    /// Blocking version of [`Acm::delete_certificate`].
+    pub fn delete_certificate(
+        &self,
+        input: DeleteCertificateRequest,
+    ) -> Result<(), RusotoError<DeleteCertificateError>> {
+        self.runtime.block_on(self.client.delete_certificate(input))
+    }
+
+    /// Blocking version of [`Acm::describe_certificate`].
+    pub fn describe_certificate(
+        &self,
+        input: DescribeCertificateRequest,
+    ) -> Result<DescribeCertificateResponse, RusotoError<DescribeCertificateError>> {
+        self.runtime
+            .block_on(self.client.describe_certificate(input))
+    }
+
+    /// Blocking version of [`Acm::export_certificate`].
+    pub fn export_certificate(
+        &self,
+        input: ExportCertificateRequest,
+    ) -> Result<ExportCertificateResponse, RusotoError<ExportCertificateError>> {
+        self.runtime.block_on(self.client.export_certificate(input))
+    }
+
+    /// Blocking version of [`Acm::get_certificate`].
+    pub fn get_certificate(
+        &self,
+        input: GetCertificateRequest,
+    ) -> Result<GetCertificateResponse, RusotoError<GetCertificateError>> {
+        self.runtime.block_on(self.client.get_certificate(input))
+    }
+
+    /// Blocking version of [`Acm::import_certificate`].
+    pub fn import_certificate(
+        &self,
+        input: ImportCertificateRequest,
+    ) -> Result<ImportCertificateResponse, RusotoError<ImportCertificateError>> {
+        self.runtime.block_on(self.client.import_certificate(input))
+    }
+
+    /// Blocking version of [`Acm::list_certificates`].
+    pub fn list_certificates(
+        &self,
+        input: ListCertificatesRequest,
+    ) -> Result<ListCertificatesResponse, RusotoError<ListCertificatesError>> {
+        self.runtime.block_on(self.client.list_certificates(input))
+    }
+
+    /// Blocking version of [`Acm::list_tags_for_certificate`].
+    pub fn list_tags_for_certificate(
+        &self,
+        input: ListTagsForCertificateRequest,
+    ) -> Result<ListTagsForCertificateResponse, RusotoError<ListTagsForCertificateError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_certificate(input))
+    }
+
+    /// Blocking version of [`Acm::remove_tags_from_certificate`].
+    pub fn remove_tags_from_certificate(
+        &self,
+        input: RemoveTagsFromCertificateRequest,
+    ) -> Result<(), RusotoError<RemoveTagsFromCertificateError>> {
+        self.runtime
+            .block_on(self.client.remove_tags_from_certificate(input))
+    }
+
+    /// Blocking version of [`Acm::renew_certificate`].
+    pub fn renew_certificate(
+        &self,
+        input: RenewCertificateRequest,
+    ) -> Result<(), RusotoError<RenewCertificateError>> {
+        self.runtime.block_on(self.client.renew_certificate(input))
+    }
+
+    /// Blocking version of [`Acm::request_certificate`].
+    pub fn request_certificate(
+        &self,
+        input: RequestCertificateRequest,
+    ) -> Result<RequestCertificateResponse, RusotoError<RequestCertificateError>> {
+        self.runtime
+            .block_on(self.client.request_certificate(input))
+    }
+
+    /// Blocking version of [`Acm::resend_validation_email`].
+    pub fn resend_validation_email(
+        &self,
+        input: ResendValidationEmailRequest,
+    ) -> Result<(), RusotoError<ResendValidationEmailError>> {
+        self.runtime
+            .block_on(self.client.resend_validation_email(input))
+    }
+
+    /// Blocking version of [`Acm::update_certificate_options`].
+    pub fn update_certificate_options(
+        &self,
+        input: UpdateCertificateOptionsRequest,
+    ) -> Result<(), RusotoError<UpdateCertificateOptionsError>> {
+        self.runtime
+            .block_on(self.client.update_certificate_options(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -3555,15 +3556,30 @@ impl ApproveSkillError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(ApproveSkillError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ApproveSkillError::ConcurrentModification,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ApproveSkillError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ApproveSkillError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ApproveSkillError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ApproveSkillError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3595,11 +3611,14 @@ impl AssociateContactWithAddressBookError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(
-                        AssociateContactWithAddressBookError::LimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateContactWithAddressBookError::LimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3635,21 +3654,30 @@ impl AssociateDeviceWithNetworkProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        AssociateDeviceWithNetworkProfileError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateDeviceWithNetworkProfileError::ConcurrentModification,
                     )
                 }
                 "DeviceNotRegisteredException" => {
-                    return RusotoError::Service(
-                        AssociateDeviceWithNetworkProfileError::DeviceNotRegistered(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateDeviceWithNetworkProfileError::DeviceNotRegistered,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AssociateDeviceWithNetworkProfileError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateDeviceWithNetworkProfileError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3687,21 +3715,30 @@ impl AssociateDeviceWithRoomError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        AssociateDeviceWithRoomError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateDeviceWithRoomError::ConcurrentModification,
                     )
                 }
                 "DeviceNotRegisteredException" => {
-                    return RusotoError::Service(AssociateDeviceWithRoomError::DeviceNotRegistered(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateDeviceWithRoomError::DeviceNotRegistered,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AssociateDeviceWithRoomError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateDeviceWithRoomError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3735,11 +3772,14 @@ impl AssociateSkillGroupWithRoomError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        AssociateSkillGroupWithRoomError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateSkillGroupWithRoomError::ConcurrentModification,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3775,21 +3815,30 @@ impl AssociateSkillWithSkillGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        AssociateSkillWithSkillGroupError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateSkillWithSkillGroupError::ConcurrentModification,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AssociateSkillWithSkillGroupError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateSkillWithSkillGroupError::NotFound,
+                    )
                 }
                 "SkillNotLinkedException" => {
-                    return RusotoError::Service(AssociateSkillWithSkillGroupError::SkillNotLinked(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateSkillWithSkillGroupError::SkillNotLinked,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3823,14 +3872,22 @@ impl AssociateSkillWithUsersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        AssociateSkillWithUsersError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateSkillWithUsersError::ConcurrentModification,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AssociateSkillWithUsersError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateSkillWithUsersError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3863,12 +3920,22 @@ impl CreateAddressBookError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateAddressBookError::AlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAddressBookError::AlreadyExists,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateAddressBookError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAddressBookError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3899,11 +3966,14 @@ impl CreateBusinessReportScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateBusinessReportScheduleError::AlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateBusinessReportScheduleError::AlreadyExists,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3931,11 +4001,14 @@ impl CreateConferenceProviderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateConferenceProviderError::AlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateConferenceProviderError::AlreadyExists,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3965,12 +4038,22 @@ impl CreateContactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateContactError::AlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateContactError::AlreadyExists,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateContactError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateContactError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4001,12 +4084,22 @@ impl CreateGatewayGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateGatewayGroupError::AlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGatewayGroupError::AlreadyExists,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateGatewayGroupError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGatewayGroupError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4043,27 +4136,46 @@ impl CreateNetworkProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateNetworkProfileError::AlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNetworkProfileError::AlreadyExists,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateNetworkProfileError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateNetworkProfileError::ConcurrentModification,
+                    )
                 }
                 "InvalidCertificateAuthorityException" => {
-                    return RusotoError::Service(
-                        CreateNetworkProfileError::InvalidCertificateAuthority(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNetworkProfileError::InvalidCertificateAuthority,
                     )
                 }
                 "InvalidServiceLinkedRoleStateException" => {
-                    return RusotoError::Service(
-                        CreateNetworkProfileError::InvalidServiceLinkedRoleState(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNetworkProfileError::InvalidServiceLinkedRoleState,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateNetworkProfileError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNetworkProfileError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4103,17 +4215,30 @@ impl CreateProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateProfileError::AlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProfileError::AlreadyExists,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateProfileError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateProfileError::ConcurrentModification,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateProfileError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProfileError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4145,12 +4270,22 @@ impl CreateRoomError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateRoomError::AlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRoomError::AlreadyExists,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateRoomError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRoomError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4183,17 +4318,30 @@ impl CreateSkillGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateSkillGroupError::AlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSkillGroupError::AlreadyExists,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateSkillGroupError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSkillGroupError::ConcurrentModification,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateSkillGroupError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSkillGroupError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4227,15 +4375,30 @@ impl CreateUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateUserError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserError::ConcurrentModification,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateUserError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserError::LimitExceeded,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateUserError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserError::ResourceInUse,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4267,14 +4430,22 @@ impl DeleteAddressBookError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteAddressBookError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteAddressBookError::ConcurrentModification,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteAddressBookError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAddressBookError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4307,16 +4478,22 @@ impl DeleteBusinessReportScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        DeleteBusinessReportScheduleError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBusinessReportScheduleError::ConcurrentModification,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteBusinessReportScheduleError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteBusinessReportScheduleError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4347,9 +4524,14 @@ impl DeleteConferenceProviderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteConferenceProviderError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteConferenceProviderError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4379,14 +4561,22 @@ impl DeleteContactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteContactError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteContactError::ConcurrentModification,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteContactError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteContactError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4419,17 +4609,30 @@ impl DeleteDeviceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteDeviceError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDeviceError::ConcurrentModification,
+                    )
                 }
                 "InvalidCertificateAuthorityException" => {
-                    return RusotoError::Service(DeleteDeviceError::InvalidCertificateAuthority(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDeviceError::InvalidCertificateAuthority,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDeviceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDeviceError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4463,17 +4666,30 @@ impl DeleteDeviceUsageDataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeviceNotRegisteredException" => {
-                    return RusotoError::Service(DeleteDeviceUsageDataError::DeviceNotRegistered(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDeviceUsageDataError::DeviceNotRegistered,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteDeviceUsageDataError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDeviceUsageDataError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDeviceUsageDataError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDeviceUsageDataError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4503,11 +4719,14 @@ impl DeleteGatewayGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceAssociatedException" => {
-                    return RusotoError::Service(DeleteGatewayGroupError::ResourceAssociated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteGatewayGroupError::ResourceAssociated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4539,17 +4758,30 @@ impl DeleteNetworkProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteNetworkProfileError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteNetworkProfileError::ConcurrentModification,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteNetworkProfileError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNetworkProfileError::NotFound,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteNetworkProfileError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNetworkProfileError::ResourceInUse,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4581,14 +4813,22 @@ impl DeleteProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteProfileError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteProfileError::ConcurrentModification,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteProfileError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProfileError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4619,12 +4859,22 @@ impl DeleteRoomError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteRoomError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRoomError::ConcurrentModification,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRoomError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRoomError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4653,11 +4903,14 @@ impl DeleteRoomSkillParameterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        DeleteRoomSkillParameterError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRoomSkillParameterError::ConcurrentModification,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4689,14 +4942,22 @@ impl DeleteSkillAuthorizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        DeleteSkillAuthorizationError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSkillAuthorizationError::ConcurrentModification,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteSkillAuthorizationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSkillAuthorizationError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4729,14 +4990,22 @@ impl DeleteSkillGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteSkillGroupError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSkillGroupError::ConcurrentModification,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteSkillGroupError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSkillGroupError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4767,12 +5036,22 @@ impl DeleteUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteUserError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserError::ConcurrentModification,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteUserError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4799,7 +5078,7 @@ impl DisassociateContactFromAddressBookError {
     ) -> RusotoError<DisassociateContactFromAddressBookError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4829,16 +5108,22 @@ impl DisassociateDeviceFromRoomError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        DisassociateDeviceFromRoomError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateDeviceFromRoomError::ConcurrentModification,
                     )
                 }
                 "DeviceNotRegisteredException" => {
-                    return RusotoError::Service(
-                        DisassociateDeviceFromRoomError::DeviceNotRegistered(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateDeviceFromRoomError::DeviceNotRegistered,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4875,16 +5160,22 @@ impl DisassociateSkillFromSkillGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        DisassociateSkillFromSkillGroupError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateSkillFromSkillGroupError::ConcurrentModification,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DisassociateSkillFromSkillGroupError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateSkillFromSkillGroupError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4919,14 +5210,22 @@ impl DisassociateSkillFromUsersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        DisassociateSkillFromUsersError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateSkillFromUsersError::ConcurrentModification,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DisassociateSkillFromUsersError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateSkillFromUsersError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4959,11 +5258,14 @@ impl DisassociateSkillGroupFromRoomError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        DisassociateSkillGroupFromRoomError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateSkillGroupFromRoomError::ConcurrentModification,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4993,9 +5295,14 @@ impl ForgetSmartHomeAppliancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(ForgetSmartHomeAppliancesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ForgetSmartHomeAppliancesError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5023,9 +5330,14 @@ impl GetAddressBookError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetAddressBookError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAddressBookError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5053,9 +5365,14 @@ impl GetConferencePreferenceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetConferencePreferenceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConferencePreferenceError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5083,9 +5400,14 @@ impl GetConferenceProviderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetConferenceProviderError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConferenceProviderError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5113,9 +5435,14 @@ impl GetContactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetContactError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetContactError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5143,9 +5470,14 @@ impl GetDeviceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDeviceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeviceError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5173,9 +5505,14 @@ impl GetGatewayError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetGatewayError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGatewayError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5203,9 +5540,14 @@ impl GetGatewayGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetGatewayGroupError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGatewayGroupError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5235,9 +5577,14 @@ impl GetInvitationConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetInvitationConfigurationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInvitationConfigurationError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5267,14 +5614,22 @@ impl GetNetworkProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidSecretsManagerResourceException" => {
-                    return RusotoError::Service(
-                        GetNetworkProfileError::InvalidSecretsManagerResource(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetNetworkProfileError::InvalidSecretsManagerResource,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetNetworkProfileError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetNetworkProfileError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5305,9 +5660,14 @@ impl GetProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetProfileError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetProfileError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5335,9 +5695,14 @@ impl GetRoomError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRoomError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRoomError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5365,9 +5730,14 @@ impl GetRoomSkillParameterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRoomSkillParameterError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRoomSkillParameterError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5395,9 +5765,14 @@ impl GetSkillGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetSkillGroupError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSkillGroupError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5423,7 +5798,7 @@ impl ListBusinessReportSchedulesError {
     ) -> RusotoError<ListBusinessReportSchedulesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5445,7 +5820,7 @@ impl ListConferenceProvidersError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListConferenceProvidersError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5471,9 +5846,14 @@ impl ListDeviceEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(ListDeviceEventsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDeviceEventsError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5497,7 +5877,7 @@ impl ListGatewayGroupsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListGatewayGroupsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5519,7 +5899,7 @@ impl ListGatewaysError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListGatewaysError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5541,7 +5921,7 @@ impl ListSkillsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListSkillsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5563,7 +5943,7 @@ impl ListSkillsStoreCategoriesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListSkillsStoreCategoriesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5587,7 +5967,7 @@ impl ListSkillsStoreSkillsByCategoryError {
     ) -> RusotoError<ListSkillsStoreSkillsByCategoryError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5613,9 +5993,14 @@ impl ListSmartHomeAppliancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(ListSmartHomeAppliancesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSmartHomeAppliancesError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5643,9 +6028,14 @@ impl ListTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5673,9 +6063,14 @@ impl PutConferencePreferenceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(PutConferencePreferenceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutConferencePreferenceError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5707,14 +6102,22 @@ impl PutInvitationConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        PutInvitationConfigurationError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutInvitationConfigurationError::ConcurrentModification,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutInvitationConfigurationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutInvitationConfigurationError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5745,11 +6148,14 @@ impl PutRoomSkillParameterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        PutRoomSkillParameterError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRoomSkillParameterError::ConcurrentModification,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5779,14 +6185,22 @@ impl PutSkillAuthorizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        PutSkillAuthorizationError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSkillAuthorizationError::ConcurrentModification,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(PutSkillAuthorizationError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSkillAuthorizationError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5821,20 +6235,38 @@ impl RegisterAVSDeviceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(RegisterAVSDeviceError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterAVSDeviceError::ConcurrentModification,
+                    )
                 }
                 "InvalidDeviceException" => {
-                    return RusotoError::Service(RegisterAVSDeviceError::InvalidDevice(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterAVSDeviceError::InvalidDevice,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RegisterAVSDeviceError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterAVSDeviceError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RegisterAVSDeviceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterAVSDeviceError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5867,12 +6299,22 @@ impl RejectSkillError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(RejectSkillError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RejectSkillError::ConcurrentModification,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RejectSkillError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RejectSkillError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5901,9 +6343,14 @@ impl ResolveRoomError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(ResolveRoomError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResolveRoomError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5933,14 +6380,22 @@ impl RevokeInvitationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(RevokeInvitationError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RevokeInvitationError::ConcurrentModification,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RevokeInvitationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RevokeInvitationError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5965,7 +6420,7 @@ impl SearchAddressBooksError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SearchAddressBooksError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5987,7 +6442,7 @@ impl SearchContactsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SearchContactsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6009,7 +6464,7 @@ impl SearchDevicesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SearchDevicesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6031,7 +6486,7 @@ impl SearchNetworkProfilesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SearchNetworkProfilesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6053,7 +6508,7 @@ impl SearchProfilesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SearchProfilesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6075,7 +6530,7 @@ impl SearchRoomsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SearchRoomsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6097,7 +6552,7 @@ impl SearchSkillGroupsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SearchSkillGroupsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6119,7 +6574,7 @@ impl SearchUsersError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SearchUsersError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6147,12 +6602,22 @@ impl SendAnnouncementError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(SendAnnouncementError::AlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendAnnouncementError::AlreadyExists,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(SendAnnouncementError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendAnnouncementError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6185,17 +6650,30 @@ impl SendInvitationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(SendInvitationError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SendInvitationError::ConcurrentModification,
+                    )
                 }
                 "InvalidUserStatusException" => {
-                    return RusotoError::Service(SendInvitationError::InvalidUserStatus(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendInvitationError::InvalidUserStatus,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(SendInvitationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendInvitationError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6225,9 +6703,14 @@ impl StartDeviceSyncError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeviceNotRegisteredException" => {
-                    return RusotoError::Service(StartDeviceSyncError::DeviceNotRegistered(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDeviceSyncError::DeviceNotRegistered,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6257,11 +6740,14 @@ impl StartSmartHomeApplianceDiscoveryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(StartSmartHomeApplianceDiscoveryError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartSmartHomeApplianceDiscoveryError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6289,9 +6775,14 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6319,9 +6810,14 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6353,17 +6849,30 @@ impl UpdateAddressBookError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateAddressBookError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAddressBookError::ConcurrentModification,
+                    )
                 }
                 "NameInUseException" => {
-                    return RusotoError::Service(UpdateAddressBookError::NameInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAddressBookError::NameInUse,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateAddressBookError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAddressBookError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6397,16 +6906,22 @@ impl UpdateBusinessReportScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        UpdateBusinessReportScheduleError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBusinessReportScheduleError::ConcurrentModification,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateBusinessReportScheduleError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateBusinessReportScheduleError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6437,9 +6952,14 @@ impl UpdateConferenceProviderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateConferenceProviderError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateConferenceProviderError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6469,14 +6989,22 @@ impl UpdateContactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateContactError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateContactError::ConcurrentModification,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateContactError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateContactError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6509,15 +7037,30 @@ impl UpdateDeviceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateDeviceError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeviceError::ConcurrentModification,
+                    )
                 }
                 "DeviceNotRegisteredException" => {
-                    return RusotoError::Service(UpdateDeviceError::DeviceNotRegistered(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeviceError::DeviceNotRegistered,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateDeviceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeviceError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6549,12 +7092,22 @@ impl UpdateGatewayError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NameInUseException" => {
-                    return RusotoError::Service(UpdateGatewayError::NameInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGatewayError::NameInUse,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateGatewayError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGatewayError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6585,12 +7138,22 @@ impl UpdateGatewayGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NameInUseException" => {
-                    return RusotoError::Service(UpdateGatewayGroupError::NameInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGatewayGroupError::NameInUse,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateGatewayGroupError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGatewayGroupError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6627,27 +7190,46 @@ impl UpdateNetworkProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateNetworkProfileError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateNetworkProfileError::ConcurrentModification,
+                    )
                 }
                 "InvalidCertificateAuthorityException" => {
-                    return RusotoError::Service(
-                        UpdateNetworkProfileError::InvalidCertificateAuthority(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateNetworkProfileError::InvalidCertificateAuthority,
                     )
                 }
                 "InvalidSecretsManagerResourceException" => {
-                    return RusotoError::Service(
-                        UpdateNetworkProfileError::InvalidSecretsManagerResource(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateNetworkProfileError::InvalidSecretsManagerResource,
                     )
                 }
                 "NameInUseException" => {
-                    return RusotoError::Service(UpdateNetworkProfileError::NameInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateNetworkProfileError::NameInUse,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateNetworkProfileError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateNetworkProfileError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6687,17 +7269,30 @@ impl UpdateProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateProfileError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateProfileError::ConcurrentModification,
+                    )
                 }
                 "NameInUseException" => {
-                    return RusotoError::Service(UpdateProfileError::NameInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateProfileError::NameInUse,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateProfileError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateProfileError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6729,12 +7324,22 @@ impl UpdateRoomError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NameInUseException" => {
-                    return RusotoError::Service(UpdateRoomError::NameInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRoomError::NameInUse,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateRoomError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRoomError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6767,17 +7372,30 @@ impl UpdateSkillGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateSkillGroupError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSkillGroupError::ConcurrentModification,
+                    )
                 }
                 "NameInUseException" => {
-                    return RusotoError::Service(UpdateSkillGroupError::NameInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSkillGroupError::NameInUse,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateSkillGroupError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSkillGroupError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7385,11 +8003,12 @@ pub struct AlexaForBusinessClient {
 impl AlexaForBusinessClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> AlexaForBusinessClient {
         AlexaForBusinessClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Alexa For Business"),
         }
     }
 
@@ -7404,12 +8023,32 @@ impl AlexaForBusinessClient {
     {
         AlexaForBusinessClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Alexa For Business"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> AlexaForBusinessClient {
-        AlexaForBusinessClient { client, region }
+        AlexaForBusinessClient {
+            client,
+            region: region_for_service(region, "Alexa For Business"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> AlexaForBusinessClient {
+        AlexaForBusinessClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Alexa For Business"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> AlexaForBusinessClient {
+        AlexaForBusinessClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -7421,6 +8060,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: ApproveSkillRequest,
     ) -> Result<ApproveSkillResponse, RusotoError<ApproveSkillError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ApproveSkill");
         request.add_header("x-amz-target", "AlexaForBusiness.ApproveSkill");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7442,6 +8082,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         RusotoError<AssociateContactWithAddressBookError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateContactWithAddressBook");
         request.add_header(
             "x-amz-target",
             "AlexaForBusiness.AssociateContactWithAddressBook",
@@ -7467,6 +8108,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         RusotoError<AssociateDeviceWithNetworkProfileError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateDeviceWithNetworkProfile");
         request.add_header(
             "x-amz-target",
             "AlexaForBusiness.AssociateDeviceWithNetworkProfile",
@@ -7492,6 +8134,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: AssociateDeviceWithRoomRequest,
     ) -> Result<AssociateDeviceWithRoomResponse, RusotoError<AssociateDeviceWithRoomError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateDeviceWithRoom");
         request.add_header("x-amz-target", "AlexaForBusiness.AssociateDeviceWithRoom");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7512,6 +8155,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
     ) -> Result<AssociateSkillGroupWithRoomResponse, RusotoError<AssociateSkillGroupWithRoomError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateSkillGroupWithRoom");
         request.add_header(
             "x-amz-target",
             "AlexaForBusiness.AssociateSkillGroupWithRoom",
@@ -7535,6 +8179,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
     ) -> Result<AssociateSkillWithSkillGroupResponse, RusotoError<AssociateSkillWithSkillGroupError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateSkillWithSkillGroup");
         request.add_header(
             "x-amz-target",
             "AlexaForBusiness.AssociateSkillWithSkillGroup",
@@ -7557,6 +8202,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: AssociateSkillWithUsersRequest,
     ) -> Result<AssociateSkillWithUsersResponse, RusotoError<AssociateSkillWithUsersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateSkillWithUsers");
         request.add_header("x-amz-target", "AlexaForBusiness.AssociateSkillWithUsers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7576,6 +8222,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: CreateAddressBookRequest,
     ) -> Result<CreateAddressBookResponse, RusotoError<CreateAddressBookError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateAddressBook");
         request.add_header("x-amz-target", "AlexaForBusiness.CreateAddressBook");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7595,6 +8242,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
     ) -> Result<CreateBusinessReportScheduleResponse, RusotoError<CreateBusinessReportScheduleError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateBusinessReportSchedule");
         request.add_header(
             "x-amz-target",
             "AlexaForBusiness.CreateBusinessReportSchedule",
@@ -7617,6 +8265,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: CreateConferenceProviderRequest,
     ) -> Result<CreateConferenceProviderResponse, RusotoError<CreateConferenceProviderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateConferenceProvider");
         request.add_header("x-amz-target", "AlexaForBusiness.CreateConferenceProvider");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7636,6 +8285,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: CreateContactRequest,
     ) -> Result<CreateContactResponse, RusotoError<CreateContactError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateContact");
         request.add_header("x-amz-target", "AlexaForBusiness.CreateContact");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7654,6 +8304,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: CreateGatewayGroupRequest,
     ) -> Result<CreateGatewayGroupResponse, RusotoError<CreateGatewayGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateGatewayGroup");
         request.add_header("x-amz-target", "AlexaForBusiness.CreateGatewayGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7672,6 +8323,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: CreateNetworkProfileRequest,
     ) -> Result<CreateNetworkProfileResponse, RusotoError<CreateNetworkProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateNetworkProfile");
         request.add_header("x-amz-target", "AlexaForBusiness.CreateNetworkProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7691,6 +8343,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: CreateProfileRequest,
     ) -> Result<CreateProfileResponse, RusotoError<CreateProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateProfile");
         request.add_header("x-amz-target", "AlexaForBusiness.CreateProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7709,6 +8362,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: CreateRoomRequest,
     ) -> Result<CreateRoomResponse, RusotoError<CreateRoomError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateRoom");
         request.add_header("x-amz-target", "AlexaForBusiness.CreateRoom");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7727,6 +8381,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: CreateSkillGroupRequest,
     ) -> Result<CreateSkillGroupResponse, RusotoError<CreateSkillGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateSkillGroup");
         request.add_header("x-amz-target", "AlexaForBusiness.CreateSkillGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7745,6 +8400,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: CreateUserRequest,
     ) -> Result<CreateUserResponse, RusotoError<CreateUserError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateUser");
         request.add_header("x-amz-target", "AlexaForBusiness.CreateUser");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7763,6 +8419,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: DeleteAddressBookRequest,
     ) -> Result<DeleteAddressBookResponse, RusotoError<DeleteAddressBookError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteAddressBook");
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteAddressBook");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7782,6 +8439,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
     ) -> Result<DeleteBusinessReportScheduleResponse, RusotoError<DeleteBusinessReportScheduleError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteBusinessReportSchedule");
         request.add_header(
             "x-amz-target",
             "AlexaForBusiness.DeleteBusinessReportSchedule",
@@ -7804,6 +8462,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: DeleteConferenceProviderRequest,
     ) -> Result<DeleteConferenceProviderResponse, RusotoError<DeleteConferenceProviderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteConferenceProvider");
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteConferenceProvider");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7823,6 +8482,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: DeleteContactRequest,
     ) -> Result<DeleteContactResponse, RusotoError<DeleteContactError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteContact");
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteContact");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7841,6 +8501,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: DeleteDeviceRequest,
     ) -> Result<DeleteDeviceResponse, RusotoError<DeleteDeviceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteDevice");
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteDevice");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7859,6 +8520,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: DeleteDeviceUsageDataRequest,
     ) -> Result<DeleteDeviceUsageDataResponse, RusotoError<DeleteDeviceUsageDataError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteDeviceUsageData");
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteDeviceUsageData");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7878,6 +8540,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: DeleteGatewayGroupRequest,
     ) -> Result<DeleteGatewayGroupResponse, RusotoError<DeleteGatewayGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteGatewayGroup");
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteGatewayGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7896,6 +8559,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: DeleteNetworkProfileRequest,
     ) -> Result<DeleteNetworkProfileResponse, RusotoError<DeleteNetworkProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteNetworkProfile");
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteNetworkProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7915,6 +8579,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: DeleteProfileRequest,
     ) -> Result<DeleteProfileResponse, RusotoError<DeleteProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteProfile");
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7933,6 +8598,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: DeleteRoomRequest,
     ) -> Result<DeleteRoomResponse, RusotoError<DeleteRoomError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteRoom");
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteRoom");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7951,6 +8617,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: DeleteRoomSkillParameterRequest,
     ) -> Result<DeleteRoomSkillParameterResponse, RusotoError<DeleteRoomSkillParameterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteRoomSkillParameter");
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteRoomSkillParameter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7970,6 +8637,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: DeleteSkillAuthorizationRequest,
     ) -> Result<DeleteSkillAuthorizationResponse, RusotoError<DeleteSkillAuthorizationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteSkillAuthorization");
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteSkillAuthorization");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7989,6 +8657,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: DeleteSkillGroupRequest,
     ) -> Result<DeleteSkillGroupResponse, RusotoError<DeleteSkillGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteSkillGroup");
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteSkillGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8007,6 +8676,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: DeleteUserRequest,
     ) -> Result<DeleteUserResponse, RusotoError<DeleteUserError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteUser");
         request.add_header("x-amz-target", "AlexaForBusiness.DeleteUser");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8028,6 +8698,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         RusotoError<DisassociateContactFromAddressBookError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisassociateContactFromAddressBook");
         request.add_header(
             "x-amz-target",
             "AlexaForBusiness.DisassociateContactFromAddressBook",
@@ -8054,6 +8725,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
     ) -> Result<DisassociateDeviceFromRoomResponse, RusotoError<DisassociateDeviceFromRoomError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisassociateDeviceFromRoom");
         request.add_header(
             "x-amz-target",
             "AlexaForBusiness.DisassociateDeviceFromRoom",
@@ -8079,6 +8751,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         RusotoError<DisassociateSkillFromSkillGroupError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisassociateSkillFromSkillGroup");
         request.add_header(
             "x-amz-target",
             "AlexaForBusiness.DisassociateSkillFromSkillGroup",
@@ -8102,6 +8775,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
     ) -> Result<DisassociateSkillFromUsersResponse, RusotoError<DisassociateSkillFromUsersError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisassociateSkillFromUsers");
         request.add_header(
             "x-amz-target",
             "AlexaForBusiness.DisassociateSkillFromUsers",
@@ -8127,6 +8801,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         RusotoError<DisassociateSkillGroupFromRoomError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisassociateSkillGroupFromRoom");
         request.add_header(
             "x-amz-target",
             "AlexaForBusiness.DisassociateSkillGroupFromRoom",
@@ -8150,6 +8825,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
     ) -> Result<ForgetSmartHomeAppliancesResponse, RusotoError<ForgetSmartHomeAppliancesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ForgetSmartHomeAppliances");
         request.add_header("x-amz-target", "AlexaForBusiness.ForgetSmartHomeAppliances");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8169,6 +8845,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: GetAddressBookRequest,
     ) -> Result<GetAddressBookResponse, RusotoError<GetAddressBookError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetAddressBook");
         request.add_header("x-amz-target", "AlexaForBusiness.GetAddressBook");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8186,6 +8863,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         &self,
     ) -> Result<GetConferencePreferenceResponse, RusotoError<GetConferencePreferenceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetConferencePreference");
         request.add_header("x-amz-target", "AlexaForBusiness.GetConferencePreference");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -8204,6 +8882,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: GetConferenceProviderRequest,
     ) -> Result<GetConferenceProviderResponse, RusotoError<GetConferenceProviderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetConferenceProvider");
         request.add_header("x-amz-target", "AlexaForBusiness.GetConferenceProvider");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8223,6 +8902,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: GetContactRequest,
     ) -> Result<GetContactResponse, RusotoError<GetContactError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetContact");
         request.add_header("x-amz-target", "AlexaForBusiness.GetContact");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8241,6 +8921,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: GetDeviceRequest,
     ) -> Result<GetDeviceResponse, RusotoError<GetDeviceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDevice");
         request.add_header("x-amz-target", "AlexaForBusiness.GetDevice");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8259,6 +8940,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: GetGatewayRequest,
     ) -> Result<GetGatewayResponse, RusotoError<GetGatewayError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetGateway");
         request.add_header("x-amz-target", "AlexaForBusiness.GetGateway");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8277,6 +8959,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: GetGatewayGroupRequest,
     ) -> Result<GetGatewayGroupResponse, RusotoError<GetGatewayGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetGatewayGroup");
         request.add_header("x-amz-target", "AlexaForBusiness.GetGatewayGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8295,6 +8978,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
     ) -> Result<GetInvitationConfigurationResponse, RusotoError<GetInvitationConfigurationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetInvitationConfiguration");
         request.add_header(
             "x-amz-target",
             "AlexaForBusiness.GetInvitationConfiguration",
@@ -8316,6 +9000,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: GetNetworkProfileRequest,
     ) -> Result<GetNetworkProfileResponse, RusotoError<GetNetworkProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetNetworkProfile");
         request.add_header("x-amz-target", "AlexaForBusiness.GetNetworkProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8334,6 +9019,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: GetProfileRequest,
     ) -> Result<GetProfileResponse, RusotoError<GetProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetProfile");
         request.add_header("x-amz-target", "AlexaForBusiness.GetProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8352,6 +9038,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: GetRoomRequest,
     ) -> Result<GetRoomResponse, RusotoError<GetRoomError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRoom");
         request.add_header("x-amz-target", "AlexaForBusiness.GetRoom");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8370,6 +9057,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: GetRoomSkillParameterRequest,
     ) -> Result<GetRoomSkillParameterResponse, RusotoError<GetRoomSkillParameterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRoomSkillParameter");
         request.add_header("x-amz-target", "AlexaForBusiness.GetRoomSkillParameter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8389,6 +9077,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: GetSkillGroupRequest,
     ) -> Result<GetSkillGroupResponse, RusotoError<GetSkillGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetSkillGroup");
         request.add_header("x-amz-target", "AlexaForBusiness.GetSkillGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8408,6 +9097,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
     ) -> Result<ListBusinessReportSchedulesResponse, RusotoError<ListBusinessReportSchedulesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListBusinessReportSchedules");
         request.add_header(
             "x-amz-target",
             "AlexaForBusiness.ListBusinessReportSchedules",
@@ -8430,6 +9120,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: ListConferenceProvidersRequest,
     ) -> Result<ListConferenceProvidersResponse, RusotoError<ListConferenceProvidersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListConferenceProviders");
         request.add_header("x-amz-target", "AlexaForBusiness.ListConferenceProviders");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8449,6 +9140,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: ListDeviceEventsRequest,
     ) -> Result<ListDeviceEventsResponse, RusotoError<ListDeviceEventsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListDeviceEvents");
         request.add_header("x-amz-target", "AlexaForBusiness.ListDeviceEvents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8467,6 +9159,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: ListGatewayGroupsRequest,
     ) -> Result<ListGatewayGroupsResponse, RusotoError<ListGatewayGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListGatewayGroups");
         request.add_header("x-amz-target", "AlexaForBusiness.ListGatewayGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8485,6 +9178,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: ListGatewaysRequest,
     ) -> Result<ListGatewaysResponse, RusotoError<ListGatewaysError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListGateways");
         request.add_header("x-amz-target", "AlexaForBusiness.ListGateways");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8503,6 +9197,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: ListSkillsRequest,
     ) -> Result<ListSkillsResponse, RusotoError<ListSkillsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListSkills");
         request.add_header("x-amz-target", "AlexaForBusiness.ListSkills");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8522,6 +9217,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
     ) -> Result<ListSkillsStoreCategoriesResponse, RusotoError<ListSkillsStoreCategoriesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListSkillsStoreCategories");
         request.add_header("x-amz-target", "AlexaForBusiness.ListSkillsStoreCategories");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8544,6 +9240,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         RusotoError<ListSkillsStoreSkillsByCategoryError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListSkillsStoreSkillsByCategory");
         request.add_header(
             "x-amz-target",
             "AlexaForBusiness.ListSkillsStoreSkillsByCategory",
@@ -8566,6 +9263,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: ListSmartHomeAppliancesRequest,
     ) -> Result<ListSmartHomeAppliancesResponse, RusotoError<ListSmartHomeAppliancesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListSmartHomeAppliances");
         request.add_header("x-amz-target", "AlexaForBusiness.ListSmartHomeAppliances");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8585,6 +9283,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: ListTagsRequest,
     ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTags");
         request.add_header("x-amz-target", "AlexaForBusiness.ListTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8603,6 +9302,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: PutConferencePreferenceRequest,
     ) -> Result<PutConferencePreferenceResponse, RusotoError<PutConferencePreferenceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutConferencePreference");
         request.add_header("x-amz-target", "AlexaForBusiness.PutConferencePreference");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8623,6 +9323,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
     ) -> Result<PutInvitationConfigurationResponse, RusotoError<PutInvitationConfigurationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutInvitationConfiguration");
         request.add_header(
             "x-amz-target",
             "AlexaForBusiness.PutInvitationConfiguration",
@@ -8645,6 +9346,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: PutRoomSkillParameterRequest,
     ) -> Result<PutRoomSkillParameterResponse, RusotoError<PutRoomSkillParameterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutRoomSkillParameter");
         request.add_header("x-amz-target", "AlexaForBusiness.PutRoomSkillParameter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8664,6 +9366,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: PutSkillAuthorizationRequest,
     ) -> Result<PutSkillAuthorizationResponse, RusotoError<PutSkillAuthorizationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutSkillAuthorization");
         request.add_header("x-amz-target", "AlexaForBusiness.PutSkillAuthorization");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8683,6 +9386,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: RegisterAVSDeviceRequest,
     ) -> Result<RegisterAVSDeviceResponse, RusotoError<RegisterAVSDeviceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RegisterAVSDevice");
         request.add_header("x-amz-target", "AlexaForBusiness.RegisterAVSDevice");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8701,6 +9405,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: RejectSkillRequest,
     ) -> Result<RejectSkillResponse, RusotoError<RejectSkillError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RejectSkill");
         request.add_header("x-amz-target", "AlexaForBusiness.RejectSkill");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8719,6 +9424,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: ResolveRoomRequest,
     ) -> Result<ResolveRoomResponse, RusotoError<ResolveRoomError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ResolveRoom");
         request.add_header("x-amz-target", "AlexaForBusiness.ResolveRoom");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8737,6 +9443,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: RevokeInvitationRequest,
     ) -> Result<RevokeInvitationResponse, RusotoError<RevokeInvitationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RevokeInvitation");
         request.add_header("x-amz-target", "AlexaForBusiness.RevokeInvitation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8755,6 +9462,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: SearchAddressBooksRequest,
     ) -> Result<SearchAddressBooksResponse, RusotoError<SearchAddressBooksError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SearchAddressBooks");
         request.add_header("x-amz-target", "AlexaForBusiness.SearchAddressBooks");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8773,6 +9481,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: SearchContactsRequest,
     ) -> Result<SearchContactsResponse, RusotoError<SearchContactsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SearchContacts");
         request.add_header("x-amz-target", "AlexaForBusiness.SearchContacts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8791,6 +9500,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: SearchDevicesRequest,
     ) -> Result<SearchDevicesResponse, RusotoError<SearchDevicesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SearchDevices");
         request.add_header("x-amz-target", "AlexaForBusiness.SearchDevices");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8809,6 +9519,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: SearchNetworkProfilesRequest,
     ) -> Result<SearchNetworkProfilesResponse, RusotoError<SearchNetworkProfilesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SearchNetworkProfiles");
         request.add_header("x-amz-target", "AlexaForBusiness.SearchNetworkProfiles");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8828,6 +9539,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: SearchProfilesRequest,
     ) -> Result<SearchProfilesResponse, RusotoError<SearchProfilesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SearchProfiles");
         request.add_header("x-amz-target", "AlexaForBusiness.SearchProfiles");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8846,6 +9558,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: SearchRoomsRequest,
     ) -> Result<SearchRoomsResponse, RusotoError<SearchRoomsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SearchRooms");
         request.add_header("x-amz-target", "AlexaForBusiness.SearchRooms");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8864,6 +9577,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: SearchSkillGroupsRequest,
     ) -> Result<SearchSkillGroupsResponse, RusotoError<SearchSkillGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SearchSkillGroups");
         request.add_header("x-amz-target", "AlexaForBusiness.SearchSkillGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8882,6 +9596,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: SearchUsersRequest,
     ) -> Result<SearchUsersResponse, RusotoError<SearchUsersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SearchUsers");
         request.add_header("x-amz-target", "AlexaForBusiness.SearchUsers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8900,6 +9615,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: SendAnnouncementRequest,
     ) -> Result<SendAnnouncementResponse, RusotoError<SendAnnouncementError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SendAnnouncement");
         request.add_header("x-amz-target", "AlexaForBusiness.SendAnnouncement");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8918,6 +9634,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: SendInvitationRequest,
     ) -> Result<SendInvitationResponse, RusotoError<SendInvitationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SendInvitation");
         request.add_header("x-amz-target", "AlexaForBusiness.SendInvitation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8936,6 +9653,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: StartDeviceSyncRequest,
     ) -> Result<StartDeviceSyncResponse, RusotoError<StartDeviceSyncError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartDeviceSync");
         request.add_header("x-amz-target", "AlexaForBusiness.StartDeviceSync");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8957,6 +9675,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         RusotoError<StartSmartHomeApplianceDiscoveryError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartSmartHomeApplianceDiscovery");
         request.add_header(
             "x-amz-target",
             "AlexaForBusiness.StartSmartHomeApplianceDiscovery",
@@ -8982,6 +9701,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "AlexaForBusiness.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9000,6 +9720,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "AlexaForBusiness.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9018,6 +9739,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: UpdateAddressBookRequest,
     ) -> Result<UpdateAddressBookResponse, RusotoError<UpdateAddressBookError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateAddressBook");
         request.add_header("x-amz-target", "AlexaForBusiness.UpdateAddressBook");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9037,6 +9759,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
     ) -> Result<UpdateBusinessReportScheduleResponse, RusotoError<UpdateBusinessReportScheduleError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateBusinessReportSchedule");
         request.add_header(
             "x-amz-target",
             "AlexaForBusiness.UpdateBusinessReportSchedule",
@@ -9059,6 +9782,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: UpdateConferenceProviderRequest,
     ) -> Result<UpdateConferenceProviderResponse, RusotoError<UpdateConferenceProviderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateConferenceProvider");
         request.add_header("x-amz-target", "AlexaForBusiness.UpdateConferenceProvider");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9078,6 +9802,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: UpdateContactRequest,
     ) -> Result<UpdateContactResponse, RusotoError<UpdateContactError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateContact");
         request.add_header("x-amz-target", "AlexaForBusiness.UpdateContact");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9096,6 +9821,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: UpdateDeviceRequest,
     ) -> Result<UpdateDeviceResponse, RusotoError<UpdateDeviceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateDevice");
         request.add_header("x-amz-target", "AlexaForBusiness.UpdateDevice");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9114,6 +9840,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: UpdateGatewayRequest,
     ) -> Result<UpdateGatewayResponse, RusotoError<UpdateGatewayError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateGateway");
         request.add_header("x-amz-target", "AlexaForBusiness.UpdateGateway");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9132,6 +9859,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: UpdateGatewayGroupRequest,
     ) -> Result<UpdateGatewayGroupResponse, RusotoError<UpdateGatewayGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateGatewayGroup");
         request.add_header("x-amz-target", "AlexaForBusiness.UpdateGatewayGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9150,6 +9878,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: UpdateNetworkProfileRequest,
     ) -> Result<UpdateNetworkProfileResponse, RusotoError<UpdateNetworkProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateNetworkProfile");
         request.add_header("x-amz-target", "AlexaForBusiness.UpdateNetworkProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9169,6 +9898,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: UpdateProfileRequest,
     ) -> Result<UpdateProfileResponse, RusotoError<UpdateProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateProfile");
         request.add_header("x-amz-target", "AlexaForBusiness.UpdateProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9187,6 +9917,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: UpdateRoomRequest,
     ) -> Result<UpdateRoomResponse, RusotoError<UpdateRoomError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateRoom");
         request.add_header("x-amz-target", "AlexaForBusiness.UpdateRoom");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9205,6 +9936,7 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         input: UpdateSkillGroupRequest,
     ) -> Result<UpdateSkillGroupResponse, RusotoError<UpdateSkillGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateSkillGroup");
         request.add_header("x-amz-target", "AlexaForBusiness.UpdateSkillGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9217,3 +9949,911 @@ impl AlexaForBusiness for AlexaForBusinessClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateSkillGroupResponse, _>()
     }
 }
+
+/// A blocking client for the Alexa For Business API, running the calls of a `AlexaForBusinessClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `AlexaForBusinessClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct AlexaForBusinessBlockingClient {
+    client: AlexaForBusinessClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl AlexaForBusinessBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> AlexaForBusinessBlockingClient {
+        AlexaForBusinessBlockingClient::from_client(AlexaForBusinessClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> AlexaForBusinessBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        AlexaForBusinessBlockingClient::from_client(AlexaForBusinessClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> AlexaForBusinessBlockingClient {
+        AlexaForBusinessBlockingClient::from_client(AlexaForBusinessClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> AlexaForBusinessBlockingClient {
+        AlexaForBusinessBlockingClient::from_client(AlexaForBusinessClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: AlexaForBusinessClient) -> AlexaForBusinessBlockingClient {
+        AlexaForBusinessBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> AlexaForBusinessBlockingClient {
+        AlexaForBusinessBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> AlexaForBusinessBlockingClient {
+        AlexaForBusinessBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`AlexaForBusiness::approve_skill`].
+    pub fn approve_skill(
+        &self,
+        input: ApproveSkillRequest,
+    ) -> Result<ApproveSkillResponse, RusotoError<ApproveSkillError>> {
+        self.runtime.block_on(self.client.approve_skill(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::associate_contact_with_address_book`].
+    pub fn associate_contact_with_address_book(
+        &self,
+        input: AssociateContactWithAddressBookRequest,
+    ) -> Result<
+        AssociateContactWithAddressBookResponse,
+        RusotoError<AssociateContactWithAddressBookError>,
+    > {
+        self.runtime
+            .block_on(self.client.associate_contact_with_address_book(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::associate_device_with_network_profile`].
+    pub fn associate_device_with_network_profile(
+        &self,
+        input: AssociateDeviceWithNetworkProfileRequest,
+    ) -> Result<
+        AssociateDeviceWithNetworkProfileResponse,
+        RusotoError<AssociateDeviceWithNetworkProfileError>,
+    > {
+        self.runtime
+            .block_on(self.client.associate_device_with_network_profile(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::associate_device_with_room`].
+    pub fn associate_device_with_room(
+        &self,
+        input: AssociateDeviceWithRoomRequest,
+    ) -> Result<AssociateDeviceWithRoomResponse, RusotoError<AssociateDeviceWithRoomError>> {
+        self.runtime
+            .block_on(self.client.associate_device_with_room(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::associate_skill_group_with_room`].
+    pub fn associate_skill_group_with_room(
+        &self,
+        input: AssociateSkillGroupWithRoomRequest,
+    ) -> Result<AssociateSkillGroupWithRoomResponse, RusotoError<AssociateSkillGroupWithRoomError>>
+    {
+        self.runtime
+            .block_on(self.client.associate_skill_group_with_room(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::associate_skill_with_skill_group`].
+    pub fn associate_skill_with_skill_group(
+        &self,
+        input: AssociateSkillWithSkillGroupRequest,
+    ) -> Result<AssociateSkillWithSkillGroupResponse, RusotoError<AssociateSkillWithSkillGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.associate_skill_with_skill_group(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::associate_skill_with_users`].
+    pub fn associate_skill_with_users(
+        &self,
+        input: AssociateSkillWithUsersRequest,
+    ) -> Result<AssociateSkillWithUsersResponse, RusotoError<AssociateSkillWithUsersError>> {
+        self.runtime
+            .block_on(self.client.associate_skill_with_users(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::create_address_book`].
+    pub fn create_address_book(
+        &self,
+        input: CreateAddressBookRequest,
+    ) -> Result<CreateAddressBookResponse, RusotoError<CreateAddressBookError>> {
+        self.runtime
+            .block_on(self.client.create_address_book(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::create_business_report_schedule`].
+    pub fn create_business_report_schedule(
+        &self,
+        input: CreateBusinessReportScheduleRequest,
+    ) -> Result<CreateBusinessReportScheduleResponse, RusotoError<CreateBusinessReportScheduleError>>
+    {
+        self.runtime
+            .block_on(self.client.create_business_report_schedule(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::create_conference_provider`].
+    pub fn create_conference_provider(
+        &self,
+        input: CreateConferenceProviderRequest,
+    ) -> Result<CreateConferenceProviderResponse, RusotoError<CreateConferenceProviderError>> {
+        self.runtime
+            .block_on(self.client.create_conference_provider(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::create_contact`].
+    pub fn create_contact(
+        &self,
+        input: CreateContactRequest,
+    ) -> Result<CreateContactResponse, RusotoError<CreateContactError>> {
+        self.runtime.block_on(self.client.create_contact(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::create_gateway_group`].
+    pub fn create_gateway_group(
+        &self,
+        input: CreateGatewayGroupRequest,
+    ) -> Result<CreateGatewayGroupResponse, RusotoError<CreateGatewayGroupError>> {
+        self.runtime
+            .block_on(self.client.create_gateway_group(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::create_network_profile`].
+    pub fn create_network_profile(
+        &self,
+        input: CreateNetworkProfileRequest,
+    ) -> Result<CreateNetworkProfileResponse, RusotoError<CreateNetworkProfileError>> {
+        self.runtime
+            .block_on(self.client.create_network_profile(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::create_profile`].
+    pub fn create_profile(
+        &self,
+        input: CreateProfileRequest,
+    ) -> Result<CreateProfileResponse, RusotoError<CreateProfileError>> {
+        self.runtime.block_on(self.client.create_profile(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::create_room`].
+    pub fn create_room(
+        &self,
+        input: CreateRoomRequest,
+    ) -> Result<CreateRoomResponse, RusotoError<CreateRoomError>> {
+        self.runtime.block_on(self.client.create_room(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::create_skill_group`].
+    pub fn create_skill_group(
+        &self,
+        input: CreateSkillGroupRequest,
+    ) -> Result<CreateSkillGroupResponse, RusotoError<CreateSkillGroupError>> {
+        self.runtime.block_on(self.client.create_skill_group(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::create_user`].
+    pub fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> Result<CreateUserResponse, RusotoError<CreateUserError>> {
+        self.runtime.block_on(self.client.create_user(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::delete_address_book`].
+    pub fn delete_address_book(
+        &self,
+        input: DeleteAddressBookRequest,
+    ) -> Result<DeleteAddressBookResponse, RusotoError<DeleteAddressBookError>> {
+        self.runtime
+            .block_on(self.client.delete_address_book(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::delete_business_report_schedule`].
+    pub fn delete_business_report_schedule(
+        &self,
+        input: DeleteBusinessReportScheduleRequest,
+    ) -> Result<DeleteBusinessReportScheduleResponse, RusotoError<DeleteBusinessReportScheduleError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_business_report_schedule(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::delete_conference_provider`].
+    pub fn delete_conference_provider(
+        &self,
+        input: DeleteConferenceProviderRequest,
+    ) -> Result<DeleteConferenceProviderResponse, RusotoError<DeleteConferenceProviderError>> {
+        self.runtime
+            .block_on(self.client.delete_conference_provider(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::delete_contact`].
+    pub fn delete_contact(
+        &self,
+        input: DeleteContactRequest,
+    ) -> Result<DeleteContactResponse, RusotoError<DeleteContactError>> {
+        self.runtime.block_on(self.client.delete_contact(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::delete_device`].
+    pub fn delete_device(
+        &self,
+        input: DeleteDeviceRequest,
+    ) -> Result<DeleteDeviceResponse, RusotoError<DeleteDeviceError>> {
+        self.runtime.block_on(self.client.delete_device(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::delete_device_usage_data`].
+    pub fn delete_device_usage_data(
+        &self,
+        input: DeleteDeviceUsageDataRequest,
+    ) -> Result<DeleteDeviceUsageDataResponse, RusotoError<DeleteDeviceUsageDataError>> {
+        self.runtime
+            .block_on(self.client.delete_device_usage_data(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::delete_gateway_group`].
+    pub fn delete_gateway_group(
+        &self,
+        input: DeleteGatewayGroupRequest,
+    ) -> Result<DeleteGatewayGroupResponse, RusotoError<DeleteGatewayGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_gateway_group(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::delete_network_profile`].
+    pub fn delete_network_profile(
+        &self,
+        input: DeleteNetworkProfileRequest,
+    ) -> Result<DeleteNetworkProfileResponse, RusotoError<DeleteNetworkProfileError>> {
+        self.runtime
+            .block_on(self.client.delete_network_profile(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::delete_profile`].
+    pub fn delete_profile(
+        &self,
+        input: DeleteProfileRequest,
+    ) -> Result<DeleteProfileResponse, RusotoError<DeleteProfileError>> {
+        self.runtime.block_on(self.client.delete_profile(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::delete_room`].
+    pub fn delete_room(
+        &self,
+        input: DeleteRoomRequest,
+    ) -> Result<DeleteRoomResponse, RusotoError<DeleteRoomError>> {
+        self.runtime.block_on(self.client.delete_room(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::delete_room_skill_parameter`].
+    pub fn delete_room_skill_parameter(
+        &self,
+        input: DeleteRoomSkillParameterRequest,
+    ) -> Result<DeleteRoomSkillParameterResponse, RusotoError<DeleteRoomSkillParameterError>> {
+        self.runtime
+            .block_on(self.client.delete_room_skill_parameter(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::delete_skill_authorization`].
+    pub fn delete_skill_authorization(
+        &self,
+        input: DeleteSkillAuthorizationRequest,
+    ) -> Result<DeleteSkillAuthorizationResponse, RusotoError<DeleteSkillAuthorizationError>> {
+        self.runtime
+            .block_on(self.client.delete_skill_authorization(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::delete_skill_group`].
+    pub fn delete_skill_group(
+        &self,
+        input: DeleteSkillGroupRequest,
+    ) -> Result<DeleteSkillGroupResponse, RusotoError<DeleteSkillGroupError>> {
+        self.runtime.block_on(self.client.delete_skill_group(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::delete_user`].
+    pub fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> Result<DeleteUserResponse, RusotoError<DeleteUserError>> {
+        self.runtime.block_on(self.client.delete_user(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::disassociate_contact_from_address_book`].
+    pub fn disassociate_contact_from_address_book(
+        &self,
+        input: DisassociateContactFromAddressBookRequest,
+    ) -> Result<
+        DisassociateContactFromAddressBookResponse,
+        RusotoError<DisassociateContactFromAddressBookError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_contact_from_address_book(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::disassociate_device_from_room`].
+    pub fn disassociate_device_from_room(
+        &self,
+        input: DisassociateDeviceFromRoomRequest,
+    ) -> Result<DisassociateDeviceFromRoomResponse, RusotoError<DisassociateDeviceFromRoomError>>
+    {
+        self.runtime
+            .block_on(self.client.disassociate_device_from_room(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::disassociate_skill_from_skill_group`].
+    pub fn disassociate_skill_from_skill_group(
+        &self,
+        input: DisassociateSkillFromSkillGroupRequest,
+    ) -> Result<
+        DisassociateSkillFromSkillGroupResponse,
+        RusotoError<DisassociateSkillFromSkillGroupError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_skill_from_skill_group(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::disassociate_skill_from_users`].
+    pub fn disassociate_skill_from_users(
+        &self,
+        input: DisassociateSkillFromUsersRequest,
+    ) -> Result<DisassociateSkillFromUsersResponse, RusotoError<DisassociateSkillFromUsersError>>
+    {
+        self.runtime
+            .block_on(self.client.disassociate_skill_from_users(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::disassociate_skill_group_from_room`].
+    pub fn disassociate_skill_group_from_room(
+        &self,
+        input: DisassociateSkillGroupFromRoomRequest,
+    ) -> Result<
+        DisassociateSkillGroupFromRoomResponse,
+        RusotoError<DisassociateSkillGroupFromRoomError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_skill_group_from_room(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::forget_smart_home_appliances`].
+    pub fn forget_smart_home_appliances(
+        &self,
+        input: ForgetSmartHomeAppliancesRequest,
+    ) -> Result<ForgetSmartHomeAppliancesResponse, RusotoError<ForgetSmartHomeAppliancesError>>
+    {
+        self.runtime
+            .block_on(self.client.forget_smart_home_appliances(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::get_address_book`].
+    pub fn get_address_book(
+        &self,
+        input: GetAddressBookRequest,
+    ) -> Result<GetAddressBookResponse, RusotoError<GetAddressBookError>> {
+        self.runtime.block_on(self.client.get_address_book(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::get_conference_preference`].
+    pub fn get_conference_preference(
+        &self,
+    ) -> Result<GetConferencePreferenceResponse, RusotoError<GetConferencePreferenceError>> {
+        self.runtime
+            .block_on(self.client.get_conference_preference())
+    }
+
+    /// Blocking version of [`AlexaForBusiness::get_conference_provider`].
+    pub fn get_conference_provider(
+        &self,
+        input: GetConferenceProviderRequest,
+    ) -> Result<GetConferenceProviderResponse, RusotoError<GetConferenceProviderError>> {
+        self.runtime
+            .block_on(self.client.get_conference_provider(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::get_contact`].
+    pub fn get_contact(
+        &self,
+        input: GetContactRequest,
+    ) -> Result<GetContactResponse, RusotoError<GetContactError>> {
+        self.runtime.block_on(self.client.get_contact(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::get_device`].
+    pub fn get_device(
+        &self,
+        input: GetDeviceRequest,
+    ) -> Result<GetDeviceResponse, RusotoError<GetDeviceError>> {
+        self.runtime.block_on(self.client.get_device(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::get_gateway`].
+    pub fn get_gateway(
+        &self,
+        input: GetGatewayRequest,
+    ) -> Result<GetGatewayResponse, RusotoError<GetGatewayError>> {
+        self.runtime.block_on(self.client.get_gateway(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::get_gateway_group`].
+    pub fn get_gateway_group(
+        &self,
+        input: GetGatewayGroupRequest,
+    ) -> Result<GetGatewayGroupResponse, RusotoError<GetGatewayGroupError>> {
+        self.runtime.block_on(self.client.get_gateway_group(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::get_invitation_configuration`].
+    pub fn get_invitation_configuration(
+        &self,
+    ) -> Result<GetInvitationConfigurationResponse, RusotoError<GetInvitationConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.get_invitation_configuration())
+    }
+
+    /// Blocking version of [`AlexaForBusiness::get_network_profile`].
+    pub fn get_network_profile(
+        &self,
+        input: GetNetworkProfileRequest,
+    ) -> Result<GetNetworkProfileResponse, RusotoError<GetNetworkProfileError>> {
+        self.runtime
+            .block_on(self.client.get_network_profile(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::get_profile`].
+    pub fn get_profile(
+        &self,
+        input: GetProfileRequest,
+    ) -> Result<GetProfileResponse, RusotoError<GetProfileError>> {
+        self.runtime.block_on(self.client.get_profile(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::get_room`].
+    pub fn get_room(
+        &self,
+        input: GetRoomRequest,
+    ) -> Result<GetRoomResponse, RusotoError<GetRoomError>> {
+        self.runtime.block_on(self.client.get_room(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::get_room_skill_parameter`].
+    pub fn get_room_skill_parameter(
+        &self,
+        input: GetRoomSkillParameterRequest,
+    ) -> Result<GetRoomSkillParameterResponse, RusotoError<GetRoomSkillParameterError>> {
+        self.runtime
+            .block_on(self.client.get_room_skill_parameter(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::get_skill_group`].
+    pub fn get_skill_group(
+        &self,
+        input: GetSkillGroupRequest,
+    ) -> Result<GetSkillGroupResponse, RusotoError<GetSkillGroupError>> {
+        self.runtime.block_on(self.client.get_skill_group(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::list_business_report_schedules`].
+    pub fn list_business_report_schedules(
+        &self,
+        input: ListBusinessReportSchedulesRequest,
+    ) -> Result<ListBusinessReportSchedulesResponse, RusotoError<ListBusinessReportSchedulesError>>
+    {
+        self.runtime
+            .block_on(self.client.list_business_report_schedules(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::list_conference_providers`].
+    pub fn list_conference_providers(
+        &self,
+        input: ListConferenceProvidersRequest,
+    ) -> Result<ListConferenceProvidersResponse, RusotoError<ListConferenceProvidersError>> {
+        self.runtime
+            .block_on(self.client.list_conference_providers(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::list_device_events`].
+    pub fn list_device_events(
+        &self,
+        input: ListDeviceEventsRequest,
+    ) -> Result<ListDeviceEventsResponse, RusotoError<ListDeviceEventsError>> {
+        self.runtime.block_on(self.client.list_device_events(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::list_gateway_groups`].
+    pub fn list_gateway_groups(
+        &self,
+        input: ListGatewayGroupsRequest,
+    ) -> Result<ListGatewayGroupsResponse, RusotoError<ListGatewayGroupsError>> {
+        self.runtime
+            .block_on(self.client.list_gateway_groups(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::list_gateways`].
+    pub fn list_gateways(
+        &self,
+        input: ListGatewaysRequest,
+    ) -> Result<ListGatewaysResponse, RusotoError<ListGatewaysError>> {
+        self.runtime.block_on(self.client.list_gateways(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::list_skills`].
+    pub fn list_skills(
+        &self,
+        input: ListSkillsRequest,
+    ) -> Result<ListSkillsResponse, RusotoError<ListSkillsError>> {
+        self.runtime.block_on(self.client.list_skills(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::list_skills_store_categories`].
+    pub fn list_skills_store_categories(
+        &self,
+        input: ListSkillsStoreCategoriesRequest,
+    ) -> Result<ListSkillsStoreCategoriesResponse, RusotoError<ListSkillsStoreCategoriesError>>
+    {
+        self.runtime
+            .block_on(self.client.list_skills_store_categories(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::list_skills_store_skills_by_category`].
+    pub fn list_skills_store_skills_by_category(
+        &self,
+        input: ListSkillsStoreSkillsByCategoryRequest,
+    ) -> Result<
+        ListSkillsStoreSkillsByCategoryResponse,
+        RusotoError<ListSkillsStoreSkillsByCategoryError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_skills_store_skills_by_category(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::list_smart_home_appliances`].
+    pub fn list_smart_home_appliances(
+        &self,
+        input: ListSmartHomeAppliancesRequest,
+    ) -> Result<ListSmartHomeAppliancesResponse, RusotoError<ListSmartHomeAppliancesError>> {
+        self.runtime
+            .block_on(self.client.list_smart_home_appliances(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::list_tags`].
+    pub fn list_tags(
+        &self,
+        input: ListTagsRequest,
+    ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
+        self.runtime.block_on(self.client.list_tags(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::put_conference_preference`].
+    pub fn put_conference_preference(
+        &self,
+        input: PutConferencePreferenceRequest,
+    ) -> Result<PutConferencePreferenceResponse, RusotoError<PutConferencePreferenceError>> {
+        self.runtime
+            .block_on(self.client.put_conference_preference(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::put_invitation_configuration`].
+    pub fn put_invitation_configuration(
+        &self,
+        input: PutInvitationConfigurationRequest,
+    ) -> Result<PutInvitationConfigurationResponse, RusotoError<PutInvitationConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.put_invitation_configuration(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::put_room_skill_parameter`].
+    pub fn put_room_skill_parameter(
+        &self,
+        input: PutRoomSkillParameterRequest,
+    ) -> Result<PutRoomSkillParameterResponse, RusotoError<PutRoomSkillParameterError>> {
+        self.runtime
+            .block_on(self.client.put_room_skill_parameter(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::put_skill_authorization`].
+    pub fn put_skill_authorization(
+        &self,
+        input: PutSkillAuthorizationRequest,
+    ) -> Result<PutSkillAuthorizationResponse, RusotoError<PutSkillAuthorizationError>> {
+        self.runtime
+            .block_on(self.client.put_skill_authorization(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::register_avs_device`].
+    pub fn register_avs_device(
+        &self,
+        input: RegisterAVSDeviceRequest,
+    ) -> Result<RegisterAVSDeviceResponse, RusotoError<RegisterAVSDeviceError>> {
+        self.runtime
+            .block_on(self.client.register_avs_device(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::reject_skill`].
+    pub fn reject_skill(
+        &self,
+        input: RejectSkillRequest,
+    ) -> Result<RejectSkillResponse, RusotoError<RejectSkillError>> {
+        self.runtime.block_on(self.client.reject_skill(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::resolve_room`].
+    pub fn resolve_room(
+        &self,
+        input: ResolveRoomRequest,
+    ) -> Result<ResolveRoomResponse, RusotoError<ResolveRoomError>> {
+        self.runtime.block_on(self.client.resolve_room(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::revoke_invitation`].
+    pub fn revoke_invitation(
+        &self,
+        input: RevokeInvitationRequest,
+    ) -> Result<RevokeInvitationResponse, RusotoError<RevokeInvitationError>> {
+        self.runtime.block_on(self.client.revoke_invitation(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::search_address_books`].
+    pub fn search_address_books(
+        &self,
+        input: SearchAddressBooksRequest,
+    ) -> Result<SearchAddressBooksResponse, RusotoError<SearchAddressBooksError>> {
+        self.runtime
+            .block_on(self.client.search_address_books(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::search_contacts`].
+    pub fn search_contacts(
+        &self,
+        input: SearchContactsRequest,
+    ) -> Result<SearchContactsResponse, RusotoError<SearchContactsError>> {
+        self.runtime.block_on(self.client.search_contacts(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::search_devices`].
+    pub fn search_devices(
+        &self,
+        input: SearchDevicesRequest,
+    ) -> Result<SearchDevicesResponse, RusotoError<SearchDevicesError>> {
+        self.runtime.block_on(self.client.search_devices(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::search_network_profiles`].
+    pub fn search_network_profiles(
+        &self,
+        input: SearchNetworkProfilesRequest,
+    ) -> Result<SearchNetworkProfilesResponse, RusotoError<SearchNetworkProfilesError>> {
+        self.runtime
+            .block_on(self.client.search_network_profiles(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::search_profiles`].
+    pub fn search_profiles(
+        &self,
+        input: SearchProfilesRequest,
+    ) -> Result<SearchProfilesResponse, RusotoError<SearchProfilesError>> {
+        self.runtime.block_on(self.client.search_profiles(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::search_rooms`].
+    pub fn search_rooms(
+        &self,
+        input: SearchRoomsRequest,
+    ) -> Result<SearchRoomsResponse, RusotoError<SearchRoomsError>> {
+        self.runtime.block_on(self.client.search_rooms(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::search_skill_groups`].
+    pub fn search_skill_groups(
+        &self,
+        input: SearchSkillGroupsRequest,
+    ) -> Result<SearchSkillGroupsResponse, RusotoError<SearchSkillGroupsError>> {
+        self.runtime
+            .block_on(self.client.search_skill_groups(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::search_users`].
+    pub fn search_users(
+        &self,
+        input: SearchUsersRequest,
+    ) -> Result<SearchUsersResponse, RusotoError<SearchUsersError>> {
+        self.runtime.block_on(self.client.search_users(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::send_announcement`].
+    pub fn send_announcement(
+        &self,
+        input: SendAnnouncementRequest,
+    ) -> Result<SendAnnouncementResponse, RusotoError<SendAnnouncementError>> {
+        self.runtime.block_on(self.client.send_announcement(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::send_invitation`].
+    pub fn send_invitation(
+        &self,
+        input: SendInvitationRequest,
+    ) -> Result<SendInvitationResponse, RusotoError<SendInvitationError>> {
+        self.runtime.block_on(self.client.send_invitation(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::start_device_sync`].
+    pub fn start_device_sync(
+        &self,
+        input: StartDeviceSyncRequest,
+    ) -> Result<StartDeviceSyncResponse, RusotoError<StartDeviceSyncError>> {
+        self.runtime.block_on(self.client.start_device_sync(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::start_smart_home_appliance_discovery`].
+    pub fn start_smart_home_appliance_discovery(
+        &self,
+        input: StartSmartHomeApplianceDiscoveryRequest,
+    ) -> Result<
+        StartSmartHomeApplianceDiscoveryResponse,
+        RusotoError<StartSmartHomeApplianceDiscoveryError>,
+    > {
+        self.runtime
+            .block_on(self.client.start_smart_home_appliance_discovery(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::update_address_book`].
+    pub fn update_address_book(
+        &self,
+        input: UpdateAddressBookRequest,
+    ) -> Result<UpdateAddressBookResponse, RusotoError<UpdateAddressBookError>> {
+        self.runtime
+            .block_on(self.client.update_address_book(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::update_business_report_schedule`].
+    pub fn update_business_report_schedule(
+        &self,
+        input: UpdateBusinessReportScheduleRequest,
+    ) -> Result<UpdateBusinessReportScheduleResponse, RusotoError<UpdateBusinessReportScheduleError>>
+    {
+        self.runtime
+            .block_on(self.client.update_business_report_schedule(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::update_conference_provider`].
+    pub fn update_conference_provider(
+        &self,
+        input: UpdateConferenceProviderRequest,
+    ) -> Result<UpdateConferenceProviderResponse, RusotoError<UpdateConferenceProviderError>> {
+        self.runtime
+            .block_on(self.client.update_conference_provider(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::update_contact`].
+    pub fn update_contact(
+        &self,
+        input: UpdateContactRequest,
+    ) -> Result<UpdateContactResponse, RusotoError<UpdateContactError>> {
+        self.runtime.block_on(self.client.update_contact(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::update_device`].
+    pub fn update_device(
+        &self,
+        input: UpdateDeviceRequest,
+    ) -> Result<UpdateDeviceResponse, RusotoError<UpdateDeviceError>> {
+        self.runtime.block_on(self.client.update_device(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::update_gateway`].
+    pub fn update_gateway(
+        &self,
+        input: UpdateGatewayRequest,
+    ) -> Result<UpdateGatewayResponse, RusotoError<UpdateGatewayError>> {
+        self.runtime.block_on(self.client.update_gateway(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::update_gateway_group`].
+    pub fn update_gateway_group(
+        &self,
+        input: UpdateGatewayGroupRequest,
+    ) -> Result<UpdateGatewayGroupResponse, RusotoError<UpdateGatewayGroupError>> {
+        self.runtime
+            .block_on(self.client.update_gateway_group(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::update_network_profile`].
+    pub fn update_network_profile(
+        &self,
+        input: UpdateNetworkProfileRequest,
+    ) -> Result<UpdateNetworkProfileResponse, RusotoError<UpdateNetworkProfileError>> {
+        self.runtime
+            .block_on(self.client.update_network_profile(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::update_profile`].
+    pub fn update_profile(
+        &self,
+        input: UpdateProfileRequest,
+    ) -> Result<UpdateProfileResponse, RusotoError<UpdateProfileError>> {
+        self.runtime.block_on(self.client.update_profile(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::update_room`].
+    pub fn update_room(
+        &self,
+        input: UpdateRoomRequest,
+    ) -> Result<UpdateRoomResponse, RusotoError<UpdateRoomError>> {
+        self.runtime.block_on(self.client.update_room(input))
+    }
+
+    /// Blocking version of [`AlexaForBusiness::update_skill_group`].
+    pub fn update_skill_group(
+        &self,
+        input: UpdateSkillGroupRequest,
+    ) -> Result<UpdateSkillGroupResponse, RusotoError<UpdateSkillGroupError>> {
+        self.runtime.block_on(self.client.update_skill_group(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1718,21 +1719,46 @@ impl CreateAppError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateAppError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAppError::BadRequest,
+                    )
                 }
                 "DependentServiceFailureException" => {
-                    return RusotoError::Service(CreateAppError::DependentServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAppError::DependentServiceFailure,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateAppError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAppError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateAppError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAppError::LimitExceeded,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateAppError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAppError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1772,27 +1798,46 @@ impl CreateBackendEnvironmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateBackendEnvironmentError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBackendEnvironmentError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateBackendEnvironmentError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateBackendEnvironmentError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateBackendEnvironmentError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateBackendEnvironmentError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateBackendEnvironmentError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBackendEnvironmentError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateBackendEnvironmentError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateBackendEnvironmentError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1834,26 +1879,54 @@ impl CreateBranchError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateBranchError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBranchError::BadRequest,
+                    )
                 }
                 "DependentServiceFailureException" => {
-                    return RusotoError::Service(CreateBranchError::DependentServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateBranchError::DependentServiceFailure,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateBranchError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBranchError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateBranchError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBranchError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateBranchError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBranchError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateBranchError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBranchError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1892,18 +1965,38 @@ impl CreateDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDeploymentError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateDeploymentError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDeploymentError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentError::LimitExceeded,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateDeploymentError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1944,32 +2037,54 @@ impl CreateDomainAssociationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDomainAssociationError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDomainAssociationError::BadRequest,
+                    )
                 }
                 "DependentServiceFailureException" => {
-                    return RusotoError::Service(
-                        CreateDomainAssociationError::DependentServiceFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDomainAssociationError::DependentServiceFailure,
                     )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateDomainAssociationError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDomainAssociationError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDomainAssociationError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDomainAssociationError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDomainAssociationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDomainAssociationError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateDomainAssociationError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDomainAssociationError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2014,26 +2129,54 @@ impl CreateWebhookError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateWebhookError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWebhookError::BadRequest,
+                    )
                 }
                 "DependentServiceFailureException" => {
-                    return RusotoError::Service(CreateWebhookError::DependentServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateWebhookError::DependentServiceFailure,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateWebhookError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWebhookError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateWebhookError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWebhookError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateWebhookError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWebhookError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateWebhookError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWebhookError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2074,21 +2217,46 @@ impl DeleteAppError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteAppError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppError::BadRequest,
+                    )
                 }
                 "DependentServiceFailureException" => {
-                    return RusotoError::Service(DeleteAppError::DependentServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppError::DependentServiceFailure,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteAppError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteAppError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteAppError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2128,27 +2296,46 @@ impl DeleteBackendEnvironmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteBackendEnvironmentError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBackendEnvironmentError::BadRequest,
+                    )
                 }
                 "DependentServiceFailureException" => {
-                    return RusotoError::Service(
-                        DeleteBackendEnvironmentError::DependentServiceFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBackendEnvironmentError::DependentServiceFailure,
                     )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteBackendEnvironmentError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteBackendEnvironmentError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteBackendEnvironmentError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBackendEnvironmentError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteBackendEnvironmentError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteBackendEnvironmentError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2190,23 +2377,46 @@ impl DeleteBranchError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteBranchError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBranchError::BadRequest,
+                    )
                 }
                 "DependentServiceFailureException" => {
-                    return RusotoError::Service(DeleteBranchError::DependentServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteBranchError::DependentServiceFailure,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteBranchError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBranchError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteBranchError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBranchError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteBranchError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBranchError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2246,27 +2456,46 @@ impl DeleteDomainAssociationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteDomainAssociationError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDomainAssociationError::BadRequest,
+                    )
                 }
                 "DependentServiceFailureException" => {
-                    return RusotoError::Service(
-                        DeleteDomainAssociationError::DependentServiceFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDomainAssociationError::DependentServiceFailure,
                     )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteDomainAssociationError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDomainAssociationError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDomainAssociationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDomainAssociationError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteDomainAssociationError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDomainAssociationError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2308,21 +2537,46 @@ impl DeleteJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteJobError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteJobError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteJobError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteJobError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteJobError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteJobError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteJobError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteJobError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteJobError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteJobError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2362,21 +2616,46 @@ impl DeleteWebhookError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteWebhookError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteWebhookError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteWebhookError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteWebhookError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteWebhookError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteWebhookError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteWebhookError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteWebhookError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteWebhookError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteWebhookError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2414,18 +2693,38 @@ impl GenerateAccessLogsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GenerateAccessLogsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GenerateAccessLogsError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GenerateAccessLogsError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GenerateAccessLogsError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GenerateAccessLogsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GenerateAccessLogsError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GenerateAccessLogsError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GenerateAccessLogsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2462,16 +2761,38 @@ impl GetAppError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetAppError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAppError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetAppError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAppError::InternalFailure,
+                    )
+                }
+                "NotFoundException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAppError::NotFound,
+                    )
                 }
-                "NotFoundException" => return RusotoError::Service(GetAppError::NotFound(err.msg)),
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetAppError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAppError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2510,21 +2831,46 @@ impl GetArtifactUrlError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetArtifactUrlError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetArtifactUrlError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetArtifactUrlError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetArtifactUrlError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetArtifactUrlError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetArtifactUrlError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetArtifactUrlError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetArtifactUrlError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetArtifactUrlError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetArtifactUrlError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2562,20 +2908,38 @@ impl GetBackendEnvironmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBackendEnvironmentError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBackendEnvironmentError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetBackendEnvironmentError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetBackendEnvironmentError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetBackendEnvironmentError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBackendEnvironmentError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetBackendEnvironmentError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBackendEnvironmentError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2612,18 +2976,38 @@ impl GetBranchError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBranchError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBranchError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetBranchError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBranchError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetBranchError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBranchError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetBranchError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBranchError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2660,20 +3044,38 @@ impl GetDomainAssociationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDomainAssociationError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDomainAssociationError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetDomainAssociationError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDomainAssociationError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDomainAssociationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDomainAssociationError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetDomainAssociationError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDomainAssociationError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2712,19 +3114,46 @@ impl GetJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetJobError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetJobError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetJobError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobError::LimitExceeded,
+                    )
+                }
+                "NotFoundException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobError::NotFound,
+                    )
                 }
-                "NotFoundException" => return RusotoError::Service(GetJobError::NotFound(err.msg)),
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetJobError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2764,21 +3193,46 @@ impl GetWebhookError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetWebhookError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetWebhookError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetWebhookError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetWebhookError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetWebhookError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetWebhookError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetWebhookError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetWebhookError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetWebhookError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetWebhookError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2814,15 +3268,30 @@ impl ListAppsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListAppsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAppsError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListAppsError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAppsError::InternalFailure,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListAppsError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAppsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2858,18 +3327,38 @@ impl ListArtifactsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListArtifactsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListArtifactsError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListArtifactsError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListArtifactsError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListArtifactsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListArtifactsError::LimitExceeded,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListArtifactsError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListArtifactsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2904,19 +3393,30 @@ impl ListBackendEnvironmentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListBackendEnvironmentsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBackendEnvironmentsError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListBackendEnvironmentsError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListBackendEnvironmentsError::InternalFailure,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListBackendEnvironmentsError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListBackendEnvironmentsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2950,15 +3450,30 @@ impl ListBranchesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListBranchesError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBranchesError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListBranchesError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBranchesError::InternalFailure,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListBranchesError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBranchesError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2992,17 +3507,30 @@ impl ListDomainAssociationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListDomainAssociationsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDomainAssociationsError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListDomainAssociationsError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDomainAssociationsError::InternalFailure,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListDomainAssociationsError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDomainAssociationsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3038,18 +3566,38 @@ impl ListJobsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListJobsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListJobsError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListJobsError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListJobsError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListJobsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListJobsError::LimitExceeded,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListJobsError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListJobsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3084,17 +3632,30 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InternalFailure,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3130,18 +3691,38 @@ impl ListWebhooksError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListWebhooksError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListWebhooksError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListWebhooksError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListWebhooksError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListWebhooksError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListWebhooksError::LimitExceeded,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListWebhooksError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListWebhooksError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3180,21 +3761,46 @@ impl StartDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StartDeploymentError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDeploymentError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(StartDeploymentError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDeploymentError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartDeploymentError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDeploymentError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StartDeploymentError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDeploymentError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(StartDeploymentError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDeploymentError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3234,21 +3840,46 @@ impl StartJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StartJobError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartJobError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(StartJobError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartJobError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartJobError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartJobError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StartJobError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartJobError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(StartJobError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartJobError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3288,21 +3919,46 @@ impl StopJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StopJobError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopJobError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(StopJobError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopJobError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StopJobError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopJobError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StopJobError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopJobError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(StopJobError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopJobError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3338,15 +3994,30 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(TagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InternalFailure,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3380,15 +4051,30 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UntagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InternalFailure,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3424,18 +4110,38 @@ impl UpdateAppError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateAppError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAppError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateAppError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAppError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateAppError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAppError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateAppError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAppError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3474,23 +4180,46 @@ impl UpdateBranchError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateBranchError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBranchError::BadRequest,
+                    )
                 }
                 "DependentServiceFailureException" => {
-                    return RusotoError::Service(UpdateBranchError::DependentServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateBranchError::DependentServiceFailure,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateBranchError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBranchError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateBranchError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBranchError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateBranchError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBranchError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3530,27 +4259,46 @@ impl UpdateDomainAssociationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateDomainAssociationError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDomainAssociationError::BadRequest,
+                    )
                 }
                 "DependentServiceFailureException" => {
-                    return RusotoError::Service(
-                        UpdateDomainAssociationError::DependentServiceFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDomainAssociationError::DependentServiceFailure,
                     )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateDomainAssociationError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDomainAssociationError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateDomainAssociationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDomainAssociationError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateDomainAssociationError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDomainAssociationError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3592,23 +4340,46 @@ impl UpdateWebhookError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateWebhookError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateWebhookError::BadRequest,
+                    )
                 }
                 "DependentServiceFailureException" => {
-                    return RusotoError::Service(UpdateWebhookError::DependentServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateWebhookError::DependentServiceFailure,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateWebhookError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateWebhookError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateWebhookError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateWebhookError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateWebhookError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateWebhookError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3859,11 +4630,12 @@ pub struct AmplifyClient {
 impl AmplifyClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> AmplifyClient {
         AmplifyClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Amplify"),
         }
     }
 
@@ -3878,12 +4650,32 @@ impl AmplifyClient {
     {
         AmplifyClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Amplify"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> AmplifyClient {
-        AmplifyClient { client, region }
+        AmplifyClient {
+            client,
+            region: region_for_service(region, "Amplify"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> AmplifyClient {
+        AmplifyClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Amplify"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> AmplifyClient {
+        AmplifyClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -3898,6 +4690,7 @@ impl Amplify for AmplifyClient {
         let request_uri = "/apps";
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("CreateApp");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3929,6 +4722,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}/backendenvironments", app_id = input.app_id);
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("CreateBackendEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3960,6 +4754,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}/branches", app_id = input.app_id);
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("CreateBranch");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3995,6 +4790,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("CreateDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4026,6 +4822,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}/domains", app_id = input.app_id);
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("CreateDomainAssociation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4057,6 +4854,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}/webhooks", app_id = input.app_id);
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("CreateWebhook");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4088,6 +4886,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}", app_id = input.app_id);
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation("DeleteApp");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4120,6 +4919,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation("DeleteBackendEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4152,6 +4952,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation("DeleteBranch");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4184,6 +4985,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation("DeleteDomainAssociation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4217,6 +5019,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation("DeleteJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4245,6 +5048,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/webhooks/{webhook_id}", webhook_id = input.webhook_id);
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation("DeleteWebhook");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4273,6 +5077,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}/accesslogs", app_id = input.app_id);
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("GenerateAccessLogs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4304,6 +5109,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}", app_id = input.app_id);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("GetApp");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4332,6 +5138,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/artifacts/{artifact_id}", artifact_id = input.artifact_id);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("GetArtifactUrl");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4364,6 +5171,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("GetBackendEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4396,6 +5204,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("GetBranch");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4428,6 +5237,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("GetDomainAssociation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4461,6 +5271,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("GetJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4489,6 +5300,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/webhooks/{webhook_id}", webhook_id = input.webhook_id);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("GetWebhook");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4517,6 +5329,7 @@ impl Amplify for AmplifyClient {
         let request_uri = "/apps";
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("ListApps");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4559,6 +5372,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("ListArtifacts");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4596,6 +5410,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}/backendenvironments", app_id = input.app_id);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("ListBackendEnvironments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4636,6 +5451,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}/branches", app_id = input.app_id);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("ListBranches");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4673,6 +5489,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}/domains", app_id = input.app_id);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("ListDomainAssociations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4714,6 +5531,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("ListJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4751,6 +5569,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4779,6 +5598,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}/webhooks", app_id = input.app_id);
 
         let mut request = SignedRequest::new("GET", "amplify", &self.region, &request_uri);
+        request.set_operation("ListWebhooks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4820,6 +5640,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("StartDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4855,6 +5676,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("StartJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4891,6 +5713,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation("StopJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4919,6 +5742,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4950,6 +5774,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "amplify", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4984,6 +5809,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/apps/{app_id}", app_id = input.app_id);
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("UpdateApp");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5019,6 +5845,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("UpdateBranch");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5054,6 +5881,7 @@ impl Amplify for AmplifyClient {
         );
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("UpdateDomainAssociation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5085,6 +5913,7 @@ impl Amplify for AmplifyClient {
         let request_uri = format!("/webhooks/{webhook_id}", webhook_id = input.webhook_id);
 
         let mut request = SignedRequest::new("POST", "amplify", &self.region, &request_uri);
+        request.set_operation("UpdateWebhook");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5107,3 +5936,379 @@ impl Amplify for AmplifyClient {
         }
     }
 }
+
+/// A blocking client for the Amplify API, running the calls of a `AmplifyClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `AmplifyClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct AmplifyBlockingClient {
+    client: AmplifyClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl AmplifyBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> AmplifyBlockingClient {
+        AmplifyBlockingClient::from_client(AmplifyClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> AmplifyBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        AmplifyBlockingClient::from_client(AmplifyClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> AmplifyBlockingClient {
+        AmplifyBlockingClient::from_client(AmplifyClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> AmplifyBlockingClient {
+        AmplifyBlockingClient::from_client(AmplifyClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: AmplifyClient) -> AmplifyBlockingClient {
+        AmplifyBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> AmplifyBlockingClient {
+        AmplifyBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> AmplifyBlockingClient {
+        AmplifyBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Amplify::create_app`].
+    pub fn create_app(
+        &self,
+        input: CreateAppRequest,
+    ) -> Result<CreateAppResult, RusotoError<CreateAppError>> {
+        self.runtime.block_on(self.client.create_app(input))
+    }
+
+    /// Blocking version of [`Amplify::create_backend_environment`].
+    pub fn create_backend_environment(
+        &self,
+        input: CreateBackendEnvironmentRequest,
+    ) -> Result<CreateBackendEnvironmentResult, RusotoError<CreateBackendEnvironmentError>> {
+        self.runtime
+            .block_on(self.client.create_backend_environment(input))
+    }
+
+    /// Blocking version of [`Amplify::create_branch`].
+    pub fn create_branch(
+        &self,
+        input: CreateBranchRequest,
+    ) -> Result<CreateBranchResult, RusotoError<CreateBranchError>> {
+        self.runtime.block_on(self.client.create_branch(input))
+    }
+
+    /// Blocking version of [`Amplify::create_deployment`].
+    pub fn create_deployment(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> Result<CreateDeploymentResult, RusotoError<CreateDeploymentError>> {
+        self.runtime.block_on(self.client.create_deployment(input))
+    }
+
+    /// Blocking version of [`Amplify::create_domain_association`].
+    pub fn create_domain_association(
+        &self,
+        input: CreateDomainAssociationRequest,
+    ) -> Result<CreateDomainAssociationResult, RusotoError<CreateDomainAssociationError>> {
+        self.runtime
+            .block_on(self.client.create_domain_association(input))
+    }
+
+    /// Blocking version of [`Amplify::create_webhook`].
+    pub fn create_webhook(
+        &self,
+        input: CreateWebhookRequest,
+    ) -> Result<CreateWebhookResult, RusotoError<CreateWebhookError>> {
+        self.runtime.block_on(self.client.create_webhook(input))
+    }
+
+    /// Blocking version of [`Amplify::delete_app`].
+    pub fn delete_app(
+        &self,
+        input: DeleteAppRequest,
+    ) -> Result<DeleteAppResult, RusotoError<DeleteAppError>> {
+        self.runtime.block_on(self.client.delete_app(input))
+    }
+
+    /// Blocking version of [`Amplify::delete_backend_environment`].
+    pub fn delete_backend_environment(
+        &self,
+        input: DeleteBackendEnvironmentRequest,
+    ) -> Result<DeleteBackendEnvironmentResult, RusotoError<DeleteBackendEnvironmentError>> {
+        self.runtime
+            .block_on(self.client.delete_backend_environment(input))
+    }
+
+    /// Blocking version of [`Amplify::delete_branch`].
+    pub fn delete_branch(
+        &self,
+        input: DeleteBranchRequest,
+    ) -> Result<DeleteBranchResult, RusotoError<DeleteBranchError>> {
+        self.runtime.block_on(self.client.delete_branch(input))
+    }
+
+    /// Blocking version of [`Amplify::delete_domain_association`].
+    pub fn delete_domain_association(
+        &self,
+        input: DeleteDomainAssociationRequest,
+    ) -> Result<DeleteDomainAssociationResult, RusotoError<DeleteDomainAssociationError>> {
+        self.runtime
+            .block_on(self.client.delete_domain_association(input))
+    }
+
+    /// Blocking version of [`Amplify::delete_job`].
+    pub fn delete_job(
+        &self,
+        input: DeleteJobRequest,
+    ) -> Result<DeleteJobResult, RusotoError<DeleteJobError>> {
+        self.runtime.block_on(self.client.delete_job(input))
+    }
+
+    /// Blocking version of [`Amplify::delete_webhook`].
+    pub fn delete_webhook(
+        &self,
+        input: DeleteWebhookRequest,
+    ) -> Result<DeleteWebhookResult, RusotoError<DeleteWebhookError>> {
+        self.runtime.block_on(self.client.delete_webhook(input))
+    }
+
+    /// Blocking version of [`Amplify::generate_access_logs`].
+    pub fn generate_access_logs(
+        &self,
+        input: GenerateAccessLogsRequest,
+    ) -> Result<GenerateAccessLogsResult, RusotoError<GenerateAccessLogsError>> {
+        self.runtime
+            .block_on(self.client.generate_access_logs(input))
+    }
+
+    /// Blocking version of [`Amplify::get_app`].
+    pub fn get_app(&self, input: GetAppRequest) -> Result<GetAppResult, RusotoError<GetAppError>> {
+        self.runtime.block_on(self.client.get_app(input))
+    }
+
+    /// Blocking version of [`Amplify::get_artifact_url`].
+    pub fn get_artifact_url(
+        &self,
+        input: GetArtifactUrlRequest,
+    ) -> Result<GetArtifactUrlResult, RusotoError<GetArtifactUrlError>> {
+        self.runtime.block_on(self.client.get_artifact_url(input))
+    }
+
+    /// Blocking version of [`Amplify::get_backend_environment`].
+    pub fn get_backend_environment(
+        &self,
+        input: GetBackendEnvironmentRequest,
+    ) -> Result<GetBackendEnvironmentResult, RusotoError<GetBackendEnvironmentError>> {
+        self.runtime
+            .block_on(self.client.get_backend_environment(input))
+    }
+
+    /// Blocking version of [`Amplify::get_branch`].
+    pub fn get_branch(
+        &self,
+        input: GetBranchRequest,
+    ) -> Result<GetBranchResult, RusotoError<GetBranchError>> {
+        self.runtime.block_on(self.client.get_branch(input))
+    }
+
+    /// Blocking version of [`Amplify::get_domain_association`].
+    pub fn get_domain_association(
+        &self,
+        input: GetDomainAssociationRequest,
+    ) -> Result<GetDomainAssociationResult, RusotoError<GetDomainAssociationError>> {
+        self.runtime
+            .block_on(self.client.get_domain_association(input))
+    }
+
+    /// Blocking version of [`Amplify::get_job`].
+    pub fn get_job(&self, input: GetJobRequest) -> Result<GetJobResult, RusotoError<GetJobError>> {
+        self.runtime.block_on(self.client.get_job(input))
+    }
+
+    /// Blocking version of [`Amplify::get_webhook`].
+    pub fn get_webhook(
+        &self,
+        input: GetWebhookRequest,
+    ) -> Result<GetWebhookResult, RusotoError<GetWebhookError>> {
+        self.runtime.block_on(self.client.get_webhook(input))
+    }
+
+    /// Blocking version of [`Amplify::list_apps`].
+    pub fn list_apps(
+        &self,
+        input: ListAppsRequest,
+    ) -> Result<ListAppsResult, RusotoError<ListAppsError>> {
+        self.runtime.block_on(self.client.list_apps(input))
+    }
+
+    /// Blocking version of [`Amplify::list_artifacts`].
+    pub fn list_artifacts(
+        &self,
+        input: ListArtifactsRequest,
+    ) -> Result<ListArtifactsResult, RusotoError<ListArtifactsError>> {
+        self.runtime.block_on(self.client.list_artifacts(input))
+    }
+
+    /// Blocking version of [`Amplify::list_backend_environments`].
+    pub fn list_backend_environments(
+        &self,
+        input: ListBackendEnvironmentsRequest,
+    ) -> Result<ListBackendEnvironmentsResult, RusotoError<ListBackendEnvironmentsError>> {
+        self.runtime
+            .block_on(self.client.list_backend_environments(input))
+    }
+
+    /// Blocking version of [`Amplify::list_branches`].
+    pub fn list_branches(
+        &self,
+        input: ListBranchesRequest,
+    ) -> Result<ListBranchesResult, RusotoError<ListBranchesError>> {
+        self.runtime.block_on(self.client.list_branches(input))
+    }
+
+    /// Blocking version of [`Amplify::list_domain_associations`].
+    pub fn list_domain_associations(
+        &self,
+        input: ListDomainAssociationsRequest,
+    ) -> Result<ListDomainAssociationsResult, RusotoError<ListDomainAssociationsError>> {
+        self.runtime
+            .block_on(self.client.list_domain_associations(input))
+    }
+
+    /// Blocking version of [`Amplify::list_jobs`].
+    pub fn list_jobs(
+        &self,
+        input: ListJobsRequest,
+    ) -> Result<ListJobsResult, RusotoError<ListJobsError>> {
+        self.runtime.block_on(self.client.list_jobs(input))
+    }
+
+    /// Blocking version of [`Amplify::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`Amplify::list_webhooks`].
+    pub fn list_webhooks(
+        &self,
+        input: ListWebhooksRequest,
+    ) -> Result<ListWebhooksResult, RusotoError<ListWebhooksError>> {
+        self.runtime.block_on(self.client.list_webhooks(input))
+    }
+
+    /// Blocking version of [`Amplify::start_deployment`].
+    pub fn start_deployment(
+        &self,
+        input: StartDeploymentRequest,
+    ) -> Result<StartDeploymentResult, RusotoError<StartDeploymentError>> {
+        self.runtime.block_on(self.client.start_deployment(input))
+    }
+
+    /// Blocking version of [`Amplify::start_job`].
+    pub fn start_job(
+        &self,
+        input: StartJobRequest,
+    ) -> Result<StartJobResult, RusotoError<StartJobError>> {
+        self.runtime.block_on(self.client.start_job(input))
+    }
+
+    /// Blocking version of [`Amplify::stop_job`].
+    pub fn stop_job(
+        &self,
+        input: StopJobRequest,
+    ) -> Result<StopJobResult, RusotoError<StopJobError>> {
+        self.runtime.block_on(self.client.stop_job(input))
+    }
+
+    /// Blocking version of [`Amplify::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`Amplify::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`Amplify::update_app`].
+    pub fn update_app(
+        &self,
+        input: UpdateAppRequest,
+    ) -> Result<UpdateAppResult, RusotoError<UpdateAppError>> {
+        self.runtime.block_on(self.client.update_app(input))
+    }
+
+    /// Blocking version of [`Amplify::update_branch`].
+    pub fn update_branch(
+        &self,
+        input: UpdateBranchRequest,
+    ) -> Result<UpdateBranchResult, RusotoError<UpdateBranchError>> {
+        self.runtime.block_on(self.client.update_branch(input))
+    }
+
+    /// Blocking version of [`Amplify::update_domain_association`].
+    pub fn update_domain_association(
+        &self,
+        input: UpdateDomainAssociationRequest,
+    ) -> Result<UpdateDomainAssociationResult, RusotoError<UpdateDomainAssociationError>> {
+        self.runtime
+            .block_on(self.client.update_domain_association(input))
+    }
+
+    /// Blocking version of [`Amplify::update_webhook`].
+    pub fn update_webhook(
+        &self,
+        input: UpdateWebhookRequest,
+    ) -> Result<UpdateWebhookResult, RusotoError<UpdateWebhookError>> {
+        self.runtime.block_on(self.client.update_webhook(input))
+    }
+}
@@ -0,0 +1,54 @@
+extern crate rusoto_mock;
+
+use crate::generated::{
+    ApiGateway, ApiGatewayClient, PatchOperation, RestApi, UpdateRestApiRequest,
+};
+
+use self::rusoto_mock::*;
+use rusoto_core::signature::{SignedRequest, SignedRequestPayload};
+use rusoto_core::Region;
+
+#[tokio::test]
+async fn should_send_update_rest_api_as_patch() {
+    let rest_api = RestApi {
+        id: Some("a1b2c3".to_owned()),
+        name: Some("renamed".to_owned()),
+        ..RestApi::default()
+    };
+    let mock = MockRequestDispatcher::with_status(200)
+        .with_json_body(rest_api.clone())
+        .with_request_checker(|request: &SignedRequest| {
+            assert_eq!("PATCH", request.method);
+            assert_eq!("/restapis/a1b2c3", request.path);
+            if let Some(SignedRequestPayload::Buffer(ref buffer)) = request.payload {
+                let payload = String::from_utf8(buffer.to_vec()).unwrap();
+                assert!(payload.contains(r#""path":"/name""#));
+            } else {
+                panic!("request payload is not a buffer");
+            }
+        });
+
+    let client = ApiGatewayClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    let request = UpdateRestApiRequest {
+        rest_api_id: "a1b2c3".to_owned(),
+        patch_operations: Some(vec![PatchOperation {
+            op: Some("replace".to_owned()),
+            path: Some("/name".to_owned()),
+            value: Some("renamed".to_owned()),
+            ..PatchOperation::default()
+        }]),
+    };
+    let result = client.update_rest_api(request).await.unwrap();
+    assert_eq!(result, rest_api);
+}
+
+#[tokio::test]
+async fn should_reject_invalid_methods() {
+    let mock = MockRequestDispatcher::with_status(200);
+    let request = SignedRequest::new("NOT A METHOD", "apigateway", &Region::UsEast1, "/");
+    let err = match rusoto_core::DispatchSignedRequest::dispatch(&mock, request, None).await {
+        Ok(_) => panic!("the method should have been rejected"),
+        Err(err) => err,
+    };
+    assert_eq!(err.to_string(), "Invalid HTTP method NOT A METHOD");
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -3719,24 +3720,54 @@ impl CreateApiKeyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateApiKeyError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApiKeyError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateApiKeyError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApiKeyError::Conflict,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateApiKeyError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApiKeyError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateApiKeyError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApiKeyError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateApiKeyError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApiKeyError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateApiKeyError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApiKeyError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3777,21 +3808,46 @@ impl CreateAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateAuthorizerError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAuthorizerError::BadRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateAuthorizerError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAuthorizerError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateAuthorizerError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAuthorizerError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateAuthorizerError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAuthorizerError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateAuthorizerError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAuthorizerError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3831,23 +3887,46 @@ impl CreateBasePathMappingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateBasePathMappingError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBasePathMappingError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateBasePathMappingError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBasePathMappingError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateBasePathMappingError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBasePathMappingError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateBasePathMappingError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateBasePathMappingError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateBasePathMappingError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBasePathMappingError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3891,27 +3970,62 @@ impl CreateDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDeploymentError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateDeploymentError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentError::Conflict,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDeploymentError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDeploymentError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateDeploymentError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateDeploymentError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateDeploymentError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3955,30 +4069,54 @@ impl CreateDocumentationPartError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDocumentationPartError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDocumentationPartError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateDocumentationPartError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDocumentationPartError::Conflict,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDocumentationPartError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDocumentationPartError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDocumentationPartError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDocumentationPartError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateDocumentationPartError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDocumentationPartError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateDocumentationPartError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDocumentationPartError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4023,32 +4161,54 @@ impl CreateDocumentationVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDocumentationVersionError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDocumentationVersionError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateDocumentationVersionError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDocumentationVersionError::Conflict,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDocumentationVersionError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDocumentationVersionError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDocumentationVersionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDocumentationVersionError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateDocumentationVersionError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDocumentationVersionError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateDocumentationVersionError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDocumentationVersionError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4087,18 +4247,38 @@ impl CreateDomainNameError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDomainNameError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDomainNameError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateDomainNameError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDomainNameError::Conflict,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateDomainNameError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDomainNameError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateDomainNameError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDomainNameError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4139,24 +4319,54 @@ impl CreateModelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateModelError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateModelError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateModelError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateModelError::Conflict,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateModelError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateModelError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateModelError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateModelError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateModelError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateModelError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateModelError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateModelError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4197,25 +4407,46 @@ impl CreateRequestValidatorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateRequestValidatorError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRequestValidatorError::BadRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateRequestValidatorError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRequestValidatorError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateRequestValidatorError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRequestValidatorError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateRequestValidatorError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRequestValidatorError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateRequestValidatorError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRequestValidatorError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4257,24 +4488,54 @@ impl CreateResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResourceError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateResourceError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResourceError::Conflict,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResourceError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResourceError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResourceError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateResourceError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResourceError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4313,18 +4574,38 @@ impl CreateRestApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateRestApiError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRestApiError::BadRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateRestApiError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRestApiError::LimitExceeded,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateRestApiError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRestApiError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateRestApiError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRestApiError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4365,24 +4646,54 @@ impl CreateStageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateStageError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStageError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateStageError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStageError::Conflict,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateStageError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStageError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateStageError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStageError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateStageError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStageError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateStageError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStageError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4425,24 +4736,54 @@ impl CreateUsagePlanError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateUsagePlanError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUsagePlanError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateUsagePlanError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUsagePlanError::Conflict,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateUsagePlanError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUsagePlanError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateUsagePlanError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUsagePlanError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateUsagePlanError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUsagePlanError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateUsagePlanError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUsagePlanError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4483,21 +4824,46 @@ impl CreateUsagePlanKeyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateUsagePlanKeyError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUsagePlanKeyError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateUsagePlanKeyError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUsagePlanKeyError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateUsagePlanKeyError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUsagePlanKeyError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateUsagePlanKeyError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUsagePlanKeyError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateUsagePlanKeyError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUsagePlanKeyError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4533,15 +4899,30 @@ impl CreateVpcLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateVpcLinkError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVpcLinkError::BadRequest,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateVpcLinkError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVpcLinkError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateVpcLinkError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVpcLinkError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4575,15 +4956,30 @@ impl DeleteApiKeyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteApiKeyError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApiKeyError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteApiKeyError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApiKeyError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteApiKeyError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApiKeyError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4621,21 +5017,46 @@ impl DeleteAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteAuthorizerError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAuthorizerError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteAuthorizerError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAuthorizerError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteAuthorizerError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAuthorizerError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteAuthorizerError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAuthorizerError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteAuthorizerError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAuthorizerError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4675,23 +5096,46 @@ impl DeleteBasePathMappingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteBasePathMappingError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBasePathMappingError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteBasePathMappingError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBasePathMappingError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteBasePathMappingError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBasePathMappingError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteBasePathMappingError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteBasePathMappingError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteBasePathMappingError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBasePathMappingError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4729,22 +5173,38 @@ impl DeleteClientCertificateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteClientCertificateError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteClientCertificateError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteClientCertificateError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteClientCertificateError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteClientCertificateError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteClientCertificateError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteClientCertificateError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteClientCertificateError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4781,18 +5241,38 @@ impl DeleteDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteDeploymentError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDeploymentError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDeploymentError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDeploymentError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteDeploymentError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDeploymentError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteDeploymentError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDeploymentError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4831,25 +5311,46 @@ impl DeleteDocumentationPartError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteDocumentationPartError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDocumentationPartError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteDocumentationPartError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDocumentationPartError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDocumentationPartError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDocumentationPartError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteDocumentationPartError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDocumentationPartError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteDocumentationPartError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDocumentationPartError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4891,27 +5392,46 @@ impl DeleteDocumentationVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteDocumentationVersionError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDocumentationVersionError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteDocumentationVersionError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDocumentationVersionError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDocumentationVersionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDocumentationVersionError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteDocumentationVersionError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDocumentationVersionError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteDocumentationVersionError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDocumentationVersionError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4949,18 +5469,38 @@ impl DeleteDomainNameError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteDomainNameError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDomainNameError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDomainNameError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDomainNameError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteDomainNameError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDomainNameError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteDomainNameError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDomainNameError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4999,23 +5539,46 @@ impl DeleteGatewayResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteGatewayResponseError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGatewayResponseError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteGatewayResponseError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGatewayResponseError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteGatewayResponseError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGatewayResponseError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteGatewayResponseError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteGatewayResponseError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteGatewayResponseError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGatewayResponseError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5053,18 +5616,38 @@ impl DeleteIntegrationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteIntegrationError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIntegrationError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteIntegrationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIntegrationError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteIntegrationError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIntegrationError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteIntegrationError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIntegrationError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5103,27 +5686,46 @@ impl DeleteIntegrationResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteIntegrationResponseError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteIntegrationResponseError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteIntegrationResponseError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIntegrationResponseError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteIntegrationResponseError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIntegrationResponseError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteIntegrationResponseError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteIntegrationResponseError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteIntegrationResponseError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteIntegrationResponseError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5161,18 +5763,38 @@ impl DeleteMethodError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteMethodError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMethodError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteMethodError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMethodError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteMethodError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMethodError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteMethodError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMethodError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5211,23 +5833,46 @@ impl DeleteMethodResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteMethodResponseError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMethodResponseError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteMethodResponseError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMethodResponseError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteMethodResponseError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMethodResponseError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteMethodResponseError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteMethodResponseError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteMethodResponseError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMethodResponseError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5267,21 +5912,46 @@ impl DeleteModelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteModelError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteModelError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteModelError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteModelError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteModelError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteModelError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteModelError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteModelError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteModelError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteModelError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5321,23 +5991,46 @@ impl DeleteRequestValidatorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteRequestValidatorError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRequestValidatorError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteRequestValidatorError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRequestValidatorError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRequestValidatorError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRequestValidatorError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteRequestValidatorError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRequestValidatorError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteRequestValidatorError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRequestValidatorError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5377,21 +6070,46 @@ impl DeleteResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteResourceError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteResourceError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteResourceError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteResourceError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteResourceError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteResourceError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteResourceError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5429,18 +6147,38 @@ impl DeleteRestApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteRestApiError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRestApiError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRestApiError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRestApiError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteRestApiError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRestApiError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteRestApiError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRestApiError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5477,18 +6215,38 @@ impl DeleteStageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteStageError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteStageError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteStageError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteStageError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteStageError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteStageError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteStageError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteStageError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5525,18 +6283,38 @@ impl DeleteUsagePlanError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteUsagePlanError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUsagePlanError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteUsagePlanError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUsagePlanError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteUsagePlanError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUsagePlanError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteUsagePlanError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUsagePlanError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5575,21 +6353,46 @@ impl DeleteUsagePlanKeyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteUsagePlanKeyError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUsagePlanKeyError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteUsagePlanKeyError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUsagePlanKeyError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteUsagePlanKeyError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUsagePlanKeyError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteUsagePlanKeyError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUsagePlanKeyError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteUsagePlanKeyError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUsagePlanKeyError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5627,18 +6430,38 @@ impl DeleteVpcLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteVpcLinkError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVpcLinkError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVpcLinkError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVpcLinkError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteVpcLinkError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVpcLinkError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteVpcLinkError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVpcLinkError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5677,24 +6500,38 @@ impl FlushStageAuthorizersCacheError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(FlushStageAuthorizersCacheError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        FlushStageAuthorizersCacheError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(FlushStageAuthorizersCacheError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        FlushStageAuthorizersCacheError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(FlushStageAuthorizersCacheError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        FlushStageAuthorizersCacheError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(FlushStageAuthorizersCacheError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        FlushStageAuthorizersCacheError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5731,18 +6568,38 @@ impl FlushStageCacheError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(FlushStageCacheError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        FlushStageCacheError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(FlushStageCacheError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        FlushStageCacheError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(FlushStageCacheError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        FlushStageCacheError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(FlushStageCacheError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        FlushStageCacheError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5777,21 +6634,30 @@ impl GenerateClientCertificateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(GenerateClientCertificateError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GenerateClientCertificateError::LimitExceeded,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GenerateClientCertificateError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GenerateClientCertificateError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GenerateClientCertificateError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GenerateClientCertificateError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5825,15 +6691,30 @@ impl GetAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetAccountError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAccountError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetAccountError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAccountError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetAccountError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAccountError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5867,15 +6748,30 @@ impl GetApiKeyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetApiKeyError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetApiKeyError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetApiKeyError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetApiKeyError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetApiKeyError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetApiKeyError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5909,15 +6805,30 @@ impl GetApiKeysError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetApiKeysError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetApiKeysError::BadRequest,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetApiKeysError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetApiKeysError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetApiKeysError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetApiKeysError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5951,15 +6862,30 @@ impl GetAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetAuthorizerError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAuthorizerError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetAuthorizerError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAuthorizerError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetAuthorizerError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAuthorizerError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5995,18 +6921,38 @@ impl GetAuthorizersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetAuthorizersError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAuthorizersError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetAuthorizersError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAuthorizersError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetAuthorizersError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAuthorizersError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetAuthorizersError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAuthorizersError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6041,15 +6987,30 @@ impl GetBasePathMappingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetBasePathMappingError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBasePathMappingError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetBasePathMappingError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBasePathMappingError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetBasePathMappingError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBasePathMappingError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6083,15 +7044,30 @@ impl GetBasePathMappingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetBasePathMappingsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBasePathMappingsError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetBasePathMappingsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBasePathMappingsError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetBasePathMappingsError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBasePathMappingsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6125,17 +7101,30 @@ impl GetClientCertificateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetClientCertificateError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetClientCertificateError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetClientCertificateError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetClientCertificateError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetClientCertificateError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetClientCertificateError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6169,17 +7158,30 @@ impl GetClientCertificatesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetClientCertificatesError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetClientCertificatesError::BadRequest,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetClientCertificatesError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetClientCertificatesError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetClientCertificatesError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetClientCertificatesError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6215,18 +7217,38 @@ impl GetDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDeploymentError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeploymentError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetDeploymentError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeploymentError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDeploymentError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeploymentError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetDeploymentError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeploymentError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6265,21 +7287,46 @@ impl GetDeploymentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDeploymentsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeploymentsError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDeploymentsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeploymentsError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetDeploymentsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeploymentsError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDeploymentsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeploymentsError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetDeploymentsError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeploymentsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6315,17 +7362,30 @@ impl GetDocumentationPartError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDocumentationPartError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDocumentationPartError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDocumentationPartError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDocumentationPartError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetDocumentationPartError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDocumentationPartError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6361,20 +7421,38 @@ impl GetDocumentationPartsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDocumentationPartsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDocumentationPartsError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDocumentationPartsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDocumentationPartsError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDocumentationPartsError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDocumentationPartsError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetDocumentationPartsError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDocumentationPartsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6409,19 +7487,30 @@ impl GetDocumentationVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDocumentationVersionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDocumentationVersionError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDocumentationVersionError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDocumentationVersionError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetDocumentationVersionError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDocumentationVersionError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6457,22 +7546,38 @@ impl GetDocumentationVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDocumentationVersionsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDocumentationVersionsError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDocumentationVersionsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDocumentationVersionsError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDocumentationVersionsError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDocumentationVersionsError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetDocumentationVersionsError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDocumentationVersionsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6509,18 +7614,38 @@ impl GetDomainNameError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDomainNameError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDomainNameError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetDomainNameError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDomainNameError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDomainNameError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDomainNameError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetDomainNameError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDomainNameError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6555,15 +7680,30 @@ impl GetDomainNamesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDomainNamesError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDomainNamesError::BadRequest,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDomainNamesError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDomainNamesError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetDomainNamesError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDomainNamesError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6601,21 +7741,46 @@ impl GetExportError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetExportError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetExportError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(GetExportError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetExportError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetExportError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetExportError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetExportError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetExportError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetExportError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetExportError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6651,15 +7816,30 @@ impl GetGatewayResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetGatewayResponseError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGatewayResponseError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetGatewayResponseError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGatewayResponseError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetGatewayResponseError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGatewayResponseError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6695,18 +7875,38 @@ impl GetGatewayResponsesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetGatewayResponsesError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGatewayResponsesError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetGatewayResponsesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGatewayResponsesError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetGatewayResponsesError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGatewayResponsesError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetGatewayResponsesError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGatewayResponsesError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6741,15 +7941,30 @@ impl GetIntegrationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetIntegrationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIntegrationError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetIntegrationError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIntegrationError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetIntegrationError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIntegrationError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6783,17 +7998,30 @@ impl GetIntegrationResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetIntegrationResponseError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIntegrationResponseError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetIntegrationResponseError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetIntegrationResponseError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetIntegrationResponseError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIntegrationResponseError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6827,15 +8055,30 @@ impl GetMethodError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetMethodError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMethodError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetMethodError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMethodError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetMethodError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMethodError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6869,15 +8112,30 @@ impl GetMethodResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetMethodResponseError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMethodResponseError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetMethodResponseError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMethodResponseError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetMethodResponseError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMethodResponseError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6911,15 +8169,30 @@ impl GetModelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetModelError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetModelError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetModelError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetModelError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetModelError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetModelError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6955,18 +8228,38 @@ impl GetModelTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetModelTemplateError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetModelTemplateError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetModelTemplateError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetModelTemplateError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetModelTemplateError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetModelTemplateError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetModelTemplateError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetModelTemplateError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7003,18 +8296,38 @@ impl GetModelsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetModelsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetModelsError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetModelsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetModelsError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetModelsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetModelsError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetModelsError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetModelsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7049,15 +8362,30 @@ impl GetRequestValidatorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRequestValidatorError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRequestValidatorError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetRequestValidatorError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRequestValidatorError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetRequestValidatorError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRequestValidatorError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7093,20 +8421,38 @@ impl GetRequestValidatorsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetRequestValidatorsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRequestValidatorsError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRequestValidatorsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRequestValidatorsError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetRequestValidatorsError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRequestValidatorsError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetRequestValidatorsError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRequestValidatorsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7141,15 +8487,30 @@ impl GetResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourceError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourceError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetResourceError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourceError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7185,18 +8546,38 @@ impl GetResourcesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetResourcesError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourcesError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetResourcesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourcesError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetResourcesError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourcesError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetResourcesError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourcesError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7231,15 +8612,30 @@ impl GetRestApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRestApiError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRestApiError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetRestApiError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRestApiError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetRestApiError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRestApiError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7273,15 +8669,30 @@ impl GetRestApisError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetRestApisError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRestApisError::BadRequest,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetRestApisError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRestApisError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetRestApisError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRestApisError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7319,17 +8730,46 @@ impl GetSdkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetSdkError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSdkError::BadRequest,
+                    )
+                }
+                "ConflictException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSdkError::Conflict,
+                    )
+                }
+                "NotFoundException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSdkError::NotFound,
+                    )
                 }
-                "ConflictException" => return RusotoError::Service(GetSdkError::Conflict(err.msg)),
-                "NotFoundException" => return RusotoError::Service(GetSdkError::NotFound(err.msg)),
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetSdkError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSdkError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetSdkError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSdkError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7365,15 +8805,30 @@ impl GetSdkTypeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetSdkTypeError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSdkTypeError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetSdkTypeError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSdkTypeError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetSdkTypeError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSdkTypeError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7405,12 +8860,22 @@ impl GetSdkTypesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetSdkTypesError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSdkTypesError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetSdkTypesError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSdkTypesError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7443,15 +8908,30 @@ impl GetStageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetStageError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetStageError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetStageError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetStageError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetStageError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetStageError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7485,15 +8965,30 @@ impl GetStagesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetStagesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetStagesError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetStagesError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetStagesError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetStagesError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetStagesError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7531,21 +9026,46 @@ impl GetTagsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetTagsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTagsError::BadRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetTagsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTagsError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetTagsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTagsError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetTagsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTagsError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetTagsError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTagsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7583,18 +9103,38 @@ impl GetUsageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetUsageError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUsageError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetUsageError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUsageError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetUsageError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUsageError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetUsageError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUsageError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7631,18 +9171,38 @@ impl GetUsagePlanError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetUsagePlanError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUsagePlanError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetUsagePlanError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUsagePlanError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetUsagePlanError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUsagePlanError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetUsagePlanError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUsagePlanError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7679,18 +9239,38 @@ impl GetUsagePlanKeyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetUsagePlanKeyError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUsagePlanKeyError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetUsagePlanKeyError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUsagePlanKeyError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetUsagePlanKeyError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUsagePlanKeyError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetUsagePlanKeyError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUsagePlanKeyError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7727,18 +9307,38 @@ impl GetUsagePlanKeysError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetUsagePlanKeysError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUsagePlanKeysError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetUsagePlanKeysError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUsagePlanKeysError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetUsagePlanKeysError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUsagePlanKeysError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetUsagePlanKeysError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUsagePlanKeysError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7777,21 +9377,46 @@ impl GetUsagePlansError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetUsagePlansError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUsagePlansError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(GetUsagePlansError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUsagePlansError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetUsagePlansError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUsagePlansError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetUsagePlansError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUsagePlansError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetUsagePlansError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUsagePlansError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7827,15 +9452,30 @@ impl GetVpcLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetVpcLinkError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVpcLinkError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetVpcLinkError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVpcLinkError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetVpcLinkError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVpcLinkError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7869,15 +9509,30 @@ impl GetVpcLinksError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetVpcLinksError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVpcLinksError::BadRequest,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetVpcLinksError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVpcLinksError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetVpcLinksError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVpcLinksError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7917,24 +9572,54 @@ impl ImportApiKeysError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ImportApiKeysError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportApiKeysError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(ImportApiKeysError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportApiKeysError::Conflict,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ImportApiKeysError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportApiKeysError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ImportApiKeysError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportApiKeysError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ImportApiKeysError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportApiKeysError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ImportApiKeysError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportApiKeysError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7975,27 +9660,46 @@ impl ImportDocumentationPartsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ImportDocumentationPartsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportDocumentationPartsError::BadRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ImportDocumentationPartsError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ImportDocumentationPartsError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ImportDocumentationPartsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportDocumentationPartsError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ImportDocumentationPartsError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ImportDocumentationPartsError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ImportDocumentationPartsError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ImportDocumentationPartsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8035,21 +9739,46 @@ impl ImportRestApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ImportRestApiError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportRestApiError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(ImportRestApiError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportRestApiError::Conflict,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ImportRestApiError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportRestApiError::LimitExceeded,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ImportRestApiError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportRestApiError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ImportRestApiError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportRestApiError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8089,21 +9818,46 @@ impl PutGatewayResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutGatewayResponseError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutGatewayResponseError::BadRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutGatewayResponseError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutGatewayResponseError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutGatewayResponseError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutGatewayResponseError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PutGatewayResponseError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutGatewayResponseError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(PutGatewayResponseError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutGatewayResponseError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8143,21 +9897,46 @@ impl PutIntegrationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutIntegrationError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutIntegrationError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(PutIntegrationError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutIntegrationError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutIntegrationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutIntegrationError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PutIntegrationError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutIntegrationError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(PutIntegrationError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutIntegrationError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8199,28 +9978,54 @@ impl PutIntegrationResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutIntegrationResponseError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutIntegrationResponseError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(PutIntegrationResponseError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutIntegrationResponseError::Conflict,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutIntegrationResponseError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutIntegrationResponseError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutIntegrationResponseError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutIntegrationResponseError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PutIntegrationResponseError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutIntegrationResponseError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(PutIntegrationResponseError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutIntegrationResponseError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8263,24 +10068,54 @@ impl PutMethodError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutMethodError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutMethodError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(PutMethodError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutMethodError::Conflict,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutMethodError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutMethodError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutMethodError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutMethodError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PutMethodError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutMethodError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(PutMethodError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutMethodError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8323,24 +10158,54 @@ impl PutMethodResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutMethodResponseError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutMethodResponseError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(PutMethodResponseError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutMethodResponseError::Conflict,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutMethodResponseError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutMethodResponseError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutMethodResponseError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutMethodResponseError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PutMethodResponseError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutMethodResponseError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(PutMethodResponseError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutMethodResponseError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8383,24 +10248,54 @@ impl PutRestApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutRestApiError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRestApiError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(PutRestApiError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRestApiError::Conflict,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutRestApiError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRestApiError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutRestApiError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRestApiError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PutRestApiError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRestApiError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(PutRestApiError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRestApiError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8443,24 +10338,54 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(TagResourceError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::Conflict,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(TagResourceError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8499,20 +10424,38 @@ impl TestInvokeAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TestInvokeAuthorizerError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestInvokeAuthorizerError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TestInvokeAuthorizerError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestInvokeAuthorizerError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TestInvokeAuthorizerError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TestInvokeAuthorizerError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(TestInvokeAuthorizerError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestInvokeAuthorizerError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8549,18 +10492,38 @@ impl TestInvokeMethodError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TestInvokeMethodError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestInvokeMethodError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TestInvokeMethodError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestInvokeMethodError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TestInvokeMethodError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestInvokeMethodError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(TestInvokeMethodError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestInvokeMethodError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8599,21 +10562,46 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UntagResourceError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UntagResourceError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8651,18 +10639,38 @@ impl UpdateAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateAccountError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAccountError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateAccountError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAccountError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateAccountError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAccountError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateAccountError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAccountError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8701,21 +10709,46 @@ impl UpdateApiKeyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateApiKeyError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApiKeyError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateApiKeyError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApiKeyError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateApiKeyError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApiKeyError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateApiKeyError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApiKeyError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateApiKeyError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApiKeyError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8753,18 +10786,38 @@ impl UpdateAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateAuthorizerError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAuthorizerError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateAuthorizerError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAuthorizerError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateAuthorizerError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAuthorizerError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateAuthorizerError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAuthorizerError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8803,23 +10856,46 @@ impl UpdateBasePathMappingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateBasePathMappingError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBasePathMappingError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateBasePathMappingError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBasePathMappingError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateBasePathMappingError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBasePathMappingError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateBasePathMappingError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateBasePathMappingError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateBasePathMappingError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBasePathMappingError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8857,22 +10933,38 @@ impl UpdateClientCertificateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateClientCertificateError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateClientCertificateError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateClientCertificateError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateClientCertificateError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateClientCertificateError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateClientCertificateError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateClientCertificateError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateClientCertificateError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8911,21 +11003,46 @@ impl UpdateDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateDeploymentError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeploymentError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateDeploymentError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeploymentError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateDeploymentError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeploymentError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateDeploymentError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeploymentError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateDeploymentError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeploymentError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8967,30 +11084,54 @@ impl UpdateDocumentationPartError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateDocumentationPartError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDocumentationPartError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDocumentationPartError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDocumentationPartError::Conflict,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateDocumentationPartError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDocumentationPartError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateDocumentationPartError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDocumentationPartError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateDocumentationPartError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDocumentationPartError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateDocumentationPartError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDocumentationPartError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9033,27 +11174,46 @@ impl UpdateDocumentationVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateDocumentationVersionError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDocumentationVersionError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDocumentationVersionError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDocumentationVersionError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateDocumentationVersionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDocumentationVersionError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateDocumentationVersionError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDocumentationVersionError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateDocumentationVersionError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDocumentationVersionError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9093,21 +11253,46 @@ impl UpdateDomainNameError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateDomainNameError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDomainNameError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDomainNameError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDomainNameError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateDomainNameError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDomainNameError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateDomainNameError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDomainNameError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateDomainNameError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDomainNameError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9145,20 +11330,38 @@ impl UpdateGatewayResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateGatewayResponseError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGatewayResponseError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateGatewayResponseError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGatewayResponseError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateGatewayResponseError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateGatewayResponseError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateGatewayResponseError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGatewayResponseError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9197,21 +11400,46 @@ impl UpdateIntegrationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateIntegrationError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIntegrationError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateIntegrationError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIntegrationError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateIntegrationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIntegrationError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateIntegrationError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIntegrationError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateIntegrationError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIntegrationError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9251,27 +11479,46 @@ impl UpdateIntegrationResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateIntegrationResponseError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateIntegrationResponseError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateIntegrationResponseError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIntegrationResponseError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateIntegrationResponseError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIntegrationResponseError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateIntegrationResponseError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateIntegrationResponseError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateIntegrationResponseError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateIntegrationResponseError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9311,21 +11558,46 @@ impl UpdateMethodError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateMethodError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMethodError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateMethodError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMethodError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateMethodError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMethodError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateMethodError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMethodError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateMethodError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMethodError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9367,26 +11639,54 @@ impl UpdateMethodResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateMethodResponseError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMethodResponseError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateMethodResponseError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMethodResponseError::Conflict,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateMethodResponseError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMethodResponseError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateMethodResponseError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMethodResponseError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateMethodResponseError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateMethodResponseError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateMethodResponseError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMethodResponseError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9427,21 +11727,46 @@ impl UpdateModelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateModelError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateModelError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateModelError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateModelError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateModelError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateModelError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateModelError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateModelError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateModelError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateModelError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9479,20 +11804,38 @@ impl UpdateRequestValidatorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateRequestValidatorError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRequestValidatorError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateRequestValidatorError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRequestValidatorError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateRequestValidatorError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRequestValidatorError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateRequestValidatorError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRequestValidatorError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9531,21 +11874,46 @@ impl UpdateResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateResourceError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateResourceError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateResourceError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateResourceError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateResourceError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateResourceError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateResourceError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9585,21 +11953,46 @@ impl UpdateRestApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateRestApiError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRestApiError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateRestApiError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRestApiError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateRestApiError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRestApiError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateRestApiError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRestApiError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateRestApiError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRestApiError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9639,21 +12032,46 @@ impl UpdateStageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateStageError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateStageError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateStageError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateStageError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateStageError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateStageError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateStageError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateStageError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateStageError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateStageError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9691,18 +12109,38 @@ impl UpdateUsageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateUsageError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUsageError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateUsageError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUsageError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateUsageError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUsageError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateUsageError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUsageError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9741,21 +12179,46 @@ impl UpdateUsagePlanError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateUsagePlanError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUsagePlanError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateUsagePlanError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUsagePlanError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateUsagePlanError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUsagePlanError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateUsagePlanError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUsagePlanError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateUsagePlanError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUsagePlanError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9795,21 +12258,46 @@ impl UpdateVpcLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateVpcLinkError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVpcLinkError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateVpcLinkError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVpcLinkError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateVpcLinkError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVpcLinkError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateVpcLinkError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVpcLinkError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateVpcLinkError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVpcLinkError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10531,11 +13019,12 @@ pub struct ApiGatewayClient {
 impl ApiGatewayClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> ApiGatewayClient {
         ApiGatewayClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "API Gateway"),
         }
     }
 
@@ -10550,12 +13039,32 @@ impl ApiGatewayClient {
     {
         ApiGatewayClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "API Gateway"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ApiGatewayClient {
-        ApiGatewayClient { client, region }
+        ApiGatewayClient {
+            client,
+            region: region_for_service(region, "API Gateway"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> ApiGatewayClient {
+        ApiGatewayClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("API Gateway"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ApiGatewayClient {
+        ApiGatewayClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -10570,6 +13079,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/apikeys";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateApiKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10603,6 +13113,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10637,6 +13148,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateBasePathMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10671,6 +13183,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10704,6 +13217,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateDocumentationPart");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10737,6 +13251,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateDocumentationVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10768,6 +13283,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/domainnames";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateDomainName");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10802,6 +13318,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10835,6 +13352,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateRequestValidator");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10870,6 +13388,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10901,6 +13420,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/restapis";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateRestApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10935,6 +13455,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateStage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10965,6 +13486,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/usageplans";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateUsagePlan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10999,6 +13521,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateUsagePlanKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11030,6 +13553,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/vpclinks";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateVpcLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11061,6 +13585,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/apikeys/{api_key}", api_key = input.api_key);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteApiKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11092,6 +13617,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11123,6 +13649,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteBasePathMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11153,6 +13680,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteClientCertificate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11184,6 +13712,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11214,6 +13743,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteDocumentationPart");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11244,6 +13774,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteDocumentationVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11274,6 +13805,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteDomainName");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11305,6 +13837,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteGatewayResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11337,6 +13870,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteIntegration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11364,6 +13898,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/integration/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteIntegrationResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11396,6 +13931,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteMethod");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11423,6 +13959,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteMethodResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11454,6 +13991,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11485,6 +14023,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteRequestValidator");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11516,6 +14055,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11543,6 +14083,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}", restapi_id = input.rest_api_id);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteRestApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11574,6 +14115,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteStage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11604,6 +14146,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteUsagePlan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11635,6 +14178,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteUsagePlanKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11662,6 +14206,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/vpclinks/{vpclink_id}", vpclink_id = input.vpc_link_id);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteVpcLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11693,6 +14238,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("FlushStageAuthorizersCache");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11724,6 +14270,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("FlushStageCache");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11751,6 +14298,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/clientcertificates";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("GenerateClientCertificate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11779,6 +14327,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/account";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11807,6 +14356,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/apikeys/{api_key}", api_key = input.api_key);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetApiKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11840,6 +14390,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/apikeys";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetApiKeys");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11890,6 +14441,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11921,6 +14473,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetAuthorizers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11962,6 +14515,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetBasePathMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11993,6 +14547,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetBasePathMappings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12033,6 +14588,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetClientCertificate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12061,6 +14617,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/clientcertificates";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetClientCertificates");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12102,6 +14659,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12141,6 +14699,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDeployments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12181,6 +14740,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDocumentationPart");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12211,6 +14771,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDocumentationParts");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12263,6 +14824,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDocumentationVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12293,6 +14855,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDocumentationVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12333,6 +14896,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDomainName");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12361,6 +14925,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/domainnames";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDomainNames");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12403,6 +14968,8 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetExport");
+        request.set_raw_response(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Accept", input.accepts.as_ref());
@@ -12448,6 +15015,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetGatewayResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12479,6 +15047,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetGatewayResponses");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12521,6 +15090,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetIntegration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12549,6 +15119,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/integration/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetIntegrationResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12582,6 +15153,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetMethod");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12609,6 +15181,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetMethodResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12638,6 +15211,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12675,6 +15249,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetModelTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12706,6 +15281,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetModels");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12746,6 +15322,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetRequestValidator");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12777,6 +15354,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetRequestValidators");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12818,6 +15396,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12857,6 +15436,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetResources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12899,6 +15479,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}", restapi_id = input.rest_api_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetRestApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12927,6 +15508,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/restapis";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetRestApis");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12966,6 +15548,8 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetSdk");
+        request.set_raw_response(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13005,6 +15589,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/sdktypes/{sdktype_id}", sdktype_id = input.id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetSdkType");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -13032,6 +15617,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/sdktypes";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetSdkTypes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13070,6 +15656,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetStage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -13100,6 +15687,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetStages");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13130,6 +15718,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13166,6 +15755,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetUsage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13210,6 +15800,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetUsagePlan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -13242,6 +15833,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetUsagePlanKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -13273,6 +15865,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetUsagePlanKeys");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13313,6 +15906,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/usageplans";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetUsagePlans");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13353,6 +15947,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/vpclinks/{vpclink_id}", vpclink_id = input.vpc_link_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetVpcLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -13381,6 +15976,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/vpclinks";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetVpcLinks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13418,6 +16014,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/apikeys";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("ImportApiKeys");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.body.to_owned());
@@ -13459,6 +16056,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("ImportDocumentationParts");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.body.to_owned());
@@ -13499,6 +16097,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/restapis";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("ImportRestApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.body.to_owned());
@@ -13546,6 +16145,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("PutGatewayResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13582,6 +16182,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("PutIntegration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13613,6 +16214,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/integration/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("PutIntegrationResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13649,6 +16251,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("PutMethod");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13679,6 +16282,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("PutMethodResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13710,6 +16314,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}", restapi_id = input.rest_api_id);
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("PutRestApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.body.to_owned());
@@ -13755,6 +16360,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13789,6 +16395,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("TestInvokeAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13825,6 +16432,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("TestInvokeMethod");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13856,6 +16464,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -13889,6 +16498,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = "/account";
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13920,6 +16530,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/apikeys/{api_key}", api_key = input.api_key);
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateApiKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13954,6 +16565,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13989,6 +16601,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateBasePathMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14023,6 +16636,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateClientCertificate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14058,6 +16672,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14092,6 +16707,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateDocumentationPart");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14126,6 +16742,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateDocumentationVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14160,6 +16777,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateDomainName");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14195,6 +16813,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateGatewayResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14231,6 +16850,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateIntegration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14262,6 +16882,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/integration/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateIntegrationResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14298,6 +16919,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateMethod");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14328,6 +16950,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}/resources/{resource_id}/methods/{http_method}/responses/{status_code}", http_method = input.http_method, resource_id = input.resource_id, restapi_id = input.rest_api_id, status_code = input.status_code);
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateMethodResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14363,6 +16986,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14397,6 +17021,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateRequestValidator");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14432,6 +17057,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14463,6 +17089,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/restapis/{restapi_id}", restapi_id = input.rest_api_id);
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateRestApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14498,6 +17125,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateStage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14532,6 +17160,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateUsage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14565,6 +17194,7 @@ impl ApiGateway for ApiGatewayClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateUsagePlan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14596,6 +17226,7 @@ impl ApiGateway for ApiGatewayClient {
         let request_uri = format!("/vpclinks/{vpclink_id}", vpclink_id = input.vpc_link_id);
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateVpcLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14618,3 +17249,1064 @@ impl ApiGateway for ApiGatewayClient {
         }
     }
 }
+
+/// A blocking client for the Amazon API Gateway API, running the calls of a `ApiGatewayClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `ApiGatewayClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ApiGatewayBlockingClient {
+    client: ApiGatewayClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ApiGatewayBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> ApiGatewayBlockingClient {
+        ApiGatewayBlockingClient::from_client(ApiGatewayClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ApiGatewayBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ApiGatewayBlockingClient::from_client(ApiGatewayClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> ApiGatewayBlockingClient {
+        ApiGatewayBlockingClient::from_client(ApiGatewayClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> ApiGatewayBlockingClient {
+        ApiGatewayBlockingClient::from_client(ApiGatewayClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: ApiGatewayClient) -> ApiGatewayBlockingClient {
+        ApiGatewayBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> ApiGatewayBlockingClient {
+        ApiGatewayBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ApiGatewayBlockingClient {
+        ApiGatewayBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`ApiGateway::create_api_key`].
+    pub fn create_api_key(
+        &self,
+        input: CreateApiKeyRequest,
+    ) -> Result<ApiKey, RusotoError<CreateApiKeyError>> {
+        self.runtime.block_on(self.client.create_api_key(input))
+    }
+
+    /// Blocking version of [`ApiGateway::create_authorizer`].
+    pub fn create_authorizer(
+        &self,
+        input: CreateAuthorizerRequest,
+    ) -> Result<Authorizer, RusotoError<CreateAuthorizerError>> {
+        self.runtime.block_on(self.client.create_authorizer(input))
+    }
+
+    /// Blocking version of [`ApiGateway::create_base_path_mapping`].
+    pub fn create_base_path_mapping(
+        &self,
+        input: CreateBasePathMappingRequest,
+    ) -> Result<BasePathMapping, RusotoError<CreateBasePathMappingError>> {
+        self.runtime
+            .block_on(self.client.create_base_path_mapping(input))
+    }
+
+    /// Blocking version of [`ApiGateway::create_deployment`].
+    pub fn create_deployment(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> Result<Deployment, RusotoError<CreateDeploymentError>> {
+        self.runtime.block_on(self.client.create_deployment(input))
+    }
+
+    /// Blocking version of [`ApiGateway::create_documentation_part`].
+    pub fn create_documentation_part(
+        &self,
+        input: CreateDocumentationPartRequest,
+    ) -> Result<DocumentationPart, RusotoError<CreateDocumentationPartError>> {
+        self.runtime
+            .block_on(self.client.create_documentation_part(input))
+    }
+
+    /// Blocking version of [`ApiGateway::create_documentation_version`].
+    pub fn create_documentation_version(
+        &self,
+        input: CreateDocumentationVersionRequest,
+    ) -> Result<DocumentationVersion, RusotoError<CreateDocumentationVersionError>> {
+        self.runtime
+            .block_on(self.client.create_documentation_version(input))
+    }
+
+    /// Blocking version of [`ApiGateway::create_domain_name`].
+    pub fn create_domain_name(
+        &self,
+        input: CreateDomainNameRequest,
+    ) -> Result<DomainName, RusotoError<CreateDomainNameError>> {
+        self.runtime.block_on(self.client.create_domain_name(input))
+    }
+
+    /// Blocking version of [`ApiGateway::create_model`].
+    pub fn create_model(
+        &self,
+        input: CreateModelRequest,
+    ) -> Result<Model, RusotoError<CreateModelError>> {
+        self.runtime.block_on(self.client.create_model(input))
+    }
+
+    /// Blocking version of [`ApiGateway::create_request_validator`].
+    pub fn create_request_validator(
+        &self,
+        input: CreateRequestValidatorRequest,
+    ) -> Result<RequestValidator, RusotoError<CreateRequestValidatorError>> {
+        self.runtime
+            .block_on(self.client.create_request_validator(input))
+    }
+
+    /// Blocking version of [`ApiGateway::create_resource`].
+    pub fn create_resource(
+        &self,
+        input: CreateResourceRequest,
+    ) -> Result<Resource, RusotoError<CreateResourceError>> {
+        self.runtime.block_on(self.client.create_resource(input))
+    }
+
+    /// Blocking version of [`ApiGateway::create_rest_api`].
+    pub fn create_rest_api(
+        &self,
+        input: CreateRestApiRequest,
+    ) -> Result<RestApi, RusotoError<CreateRestApiError>> {
+        self.runtime.block_on(self.client.create_rest_api(input))
+    }
+
+    /// Blocking version of [`ApiGateway::create_stage`].
+    pub fn create_stage(
+        &self,
+        input: CreateStageRequest,
+    ) -> Result<Stage, RusotoError<CreateStageError>> {
+        self.runtime.block_on(self.client.create_stage(input))
+    }
+
+    /// Blocking version of [`ApiGateway::create_usage_plan`].
+    pub fn create_usage_plan(
+        &self,
+        input: CreateUsagePlanRequest,
+    ) -> Result<UsagePlan, RusotoError<CreateUsagePlanError>> {
+        self.runtime.block_on(self.client.create_usage_plan(input))
+    }
+
+    /// Blocking version of [`ApiGateway::create_usage_plan_key`].
+    pub fn create_usage_plan_key(
+        &self,
+        input: CreateUsagePlanKeyRequest,
+    ) -> Result<UsagePlanKey, RusotoError<CreateUsagePlanKeyError>> {
+        self.runtime
+            .block_on(self.client.create_usage_plan_key(input))
+    }
+
+    /// Blocking version of [`ApiGateway::create_vpc_link`].
+    pub fn create_vpc_link(
+        &self,
+        input: CreateVpcLinkRequest,
+    ) -> Result<VpcLink, RusotoError<CreateVpcLinkError>> {
+        self.runtime.block_on(self.client.create_vpc_link(input))
+    }
+
+    /// Blocking version of [`ApiGateway::delete_api_key`].
+    pub fn delete_api_key(
+        &self,
+        input: DeleteApiKeyRequest,
+    ) -> Result<(), RusotoError<DeleteApiKeyError>> {
+        self.runtime.block_on(self.client.delete_api_key(input))
+    }
+
+    /// Blocking version of [`ApiGateway::delete_authorizer`].
+    pub fn delete_authorizer(
+        &self,
+        input: DeleteAuthorizerRequest,
+    ) -> Result<(), RusotoError<DeleteAuthorizerError>> {
+        self.runtime.block_on(self.client.delete_authorizer(input))
+    }
+
+    /// Blocking version of [`ApiGateway::delete_base_path_mapping`].
+    pub fn delete_base_path_mapping(
+        &self,
+        input: DeleteBasePathMappingRequest,
+    ) -> Result<(), RusotoError<DeleteBasePathMappingError>> {
+        self.runtime
+            .block_on(self.client.delete_base_path_mapping(input))
+    }
+
+    /// Blocking version of [`ApiGateway::delete_client_certificate`].
+    pub fn delete_client_certificate(
+        &self,
+        input: DeleteClientCertificateRequest,
+    ) -> Result<(), RusotoError<DeleteClientCertificateError>> {
+        self.runtime
+            .block_on(self.client.delete_client_certificate(input))
+    }
+
+    /// Blocking version of [`ApiGateway::delete_deployment`].
+    pub fn delete_deployment(
+        &self,
+        input: DeleteDeploymentRequest,
+    ) -> Result<(), RusotoError<DeleteDeploymentError>> {
+        self.runtime.block_on(self.client.delete_deployment(input))
+    }
+
+    /// Blocking version of [`ApiGateway::delete_documentation_part`].
+    pub fn delete_documentation_part(
+        &self,
+        input: DeleteDocumentationPartRequest,
+    ) -> Result<(), RusotoError<DeleteDocumentationPartError>> {
+        self.runtime
+            .block_on(self.client.delete_documentation_part(input))
+    }
+
+    /// Blocking version of [`ApiGateway::delete_documentation_version`].
+    pub fn delete_documentation_version(
+        &self,
+        input: DeleteDocumentationVersionRequest,
+    ) -> Result<(), RusotoError<DeleteDocumentationVersionError>> {
+        self.runtime
+            .block_on(self.client.delete_documentation_version(input))
+    }
+
+    /// Blocking version of [`ApiGateway::delete_domain_name`].
+    pub fn delete_domain_name(
+        &self,
+        input: DeleteDomainNameRequest,
+    ) -> Result<(), RusotoError<DeleteDomainNameError>> {
+        self.runtime.block_on(self.client.delete_domain_name(input))
+    }
+
+    /// Blocking version of [`ApiGateway::delete_gateway_response`].
+    pub fn delete_gateway_response(
+        &self,
+        input: DeleteGatewayResponseRequest,
+    ) -> Result<(), RusotoError<DeleteGatewayResponseError>> {
+        self.runtime
+            .block_on(self.client.delete_gateway_response(input))
+    }
+
+    /// Blocking version of [`ApiGateway::delete_integration`].
+    pub fn delete_integration(
+        &self,
+        input: DeleteIntegrationRequest,
+    ) -> Result<(), RusotoError<DeleteIntegrationError>> {
+        self.runtime.block_on(self.client.delete_integration(input))
+    }
+
+    /// Blocking version of [`ApiGateway::delete_integration_response`].
+    pub fn delete_integration_response(
+        &self,
+        input: DeleteIntegrationResponseRequest,
+    ) -> Result<(), RusotoError<DeleteIntegrationResponseError>> {
+        self.runtime
+            .block_on(self.client.delete_integration_response(input))
+    }
+
+    /// Blocking version of [`ApiGateway::delete_method`].
+    pub fn delete_method(
+        &self,
+        input: DeleteMethodRequest,
+    ) -> Result<(), RusotoError<DeleteMethodError>> {
+        self.runtime.block_on(self.client.delete_method(input))
+    }
+
+    /// Blocking version of [`ApiGateway::delete_method_response`].
+    pub fn delete_method_response(
+        &self,
+        input: DeleteMethodResponseRequest,
+    ) -> Result<(), RusotoError<DeleteMethodResponseError>> {
+        self.runtime
+            .block_on(self.client.delete_method_response(input))
+    }
+
+    /// Blocking version of [`ApiGateway::delete_model`].
+    pub fn delete_model(
+        &self,
+        input: DeleteModelRequest,
+    ) -> Result<(), RusotoError<DeleteModelError>> {
+        self.runtime.block_on(self.client.delete_model(input))
+    }
+
+    /// Blocking version of [`ApiGateway::delete_request_validator`].
+    pub fn delete_request_validator(
+        &self,
+        input: DeleteRequestValidatorRequest,
+    ) -> Result<(), RusotoError<DeleteRequestValidatorError>> {
+        self.runtime
+            .block_on(self.client.delete_request_validator(input))
+    }
+
+    /// Blocking version of [`ApiGateway::delete_resource`].
+    pub fn delete_resource(
+        &self,
+        input: DeleteResourceRequest,
+    ) -> Result<(), RusotoError<DeleteResourceError>> {
+        self.runtime.block_on(self.client.delete_resource(input))
+    }
+
+    /// Blocking version of [`ApiGateway::delete_rest_api`].
+    pub fn delete_rest_api(
+        &self,
+        input: DeleteRestApiRequest,
+    ) -> Result<(), RusotoError<DeleteRestApiError>> {
+        self.runtime.block_on(self.client.delete_rest_api(input))
+    }
+
+    /// Blocking version of [`ApiGateway::delete_stage`].
+    pub fn delete_stage(
+        &self,
+        input: DeleteStageRequest,
+    ) -> Result<(), RusotoError<DeleteStageError>> {
+        self.runtime.block_on(self.client.delete_stage(input))
+    }
+
+    /// Blocking version of [`ApiGateway::delete_usage_plan`].
+    pub fn delete_usage_plan(
+        &self,
+        input: DeleteUsagePlanRequest,
+    ) -> Result<(), RusotoError<DeleteUsagePlanError>> {
+        self.runtime.block_on(self.client.delete_usage_plan(input))
+    }
+
+    /// Blocking version of [`ApiGateway::delete_usage_plan_key`].
+    pub fn delete_usage_plan_key(
+        &self,
+        input: DeleteUsagePlanKeyRequest,
+    ) -> Result<(), RusotoError<DeleteUsagePlanKeyError>> {
+        self.runtime
+            .block_on(self.client.delete_usage_plan_key(input))
+    }
+
+    /// Blocking version of [`ApiGateway::delete_vpc_link`].
+    pub fn delete_vpc_link(
+        &self,
+        input: DeleteVpcLinkRequest,
+    ) -> Result<(), RusotoError<DeleteVpcLinkError>> {
+        self.runtime.block_on(self.client.delete_vpc_link(input))
+    }
+
+    /// Blocking version of [`ApiGateway::flush_stage_authorizers_cache`].
+    pub fn flush_stage_authorizers_cache(
+        &self,
+        input: FlushStageAuthorizersCacheRequest,
+    ) -> Result<(), RusotoError<FlushStageAuthorizersCacheError>> {
+        self.runtime
+            .block_on(self.client.flush_stage_authorizers_cache(input))
+    }
+
+    /// Blocking version of [`ApiGateway::flush_stage_cache`].
+    pub fn flush_stage_cache(
+        &self,
+        input: FlushStageCacheRequest,
+    ) -> Result<(), RusotoError<FlushStageCacheError>> {
+        self.runtime.block_on(self.client.flush_stage_cache(input))
+    }
+
+    /// Blocking version of [`ApiGateway::generate_client_certificate`].
+    pub fn generate_client_certificate(
+        &self,
+        input: GenerateClientCertificateRequest,
+    ) -> Result<ClientCertificate, RusotoError<GenerateClientCertificateError>> {
+        self.runtime
+            .block_on(self.client.generate_client_certificate(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_account`].
+    pub fn get_account(&self) -> Result<Account, RusotoError<GetAccountError>> {
+        self.runtime.block_on(self.client.get_account())
+    }
+
+    /// Blocking version of [`ApiGateway::get_api_key`].
+    pub fn get_api_key(
+        &self,
+        input: GetApiKeyRequest,
+    ) -> Result<ApiKey, RusotoError<GetApiKeyError>> {
+        self.runtime.block_on(self.client.get_api_key(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_api_keys`].
+    pub fn get_api_keys(
+        &self,
+        input: GetApiKeysRequest,
+    ) -> Result<ApiKeys, RusotoError<GetApiKeysError>> {
+        self.runtime.block_on(self.client.get_api_keys(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_authorizer`].
+    pub fn get_authorizer(
+        &self,
+        input: GetAuthorizerRequest,
+    ) -> Result<Authorizer, RusotoError<GetAuthorizerError>> {
+        self.runtime.block_on(self.client.get_authorizer(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_authorizers`].
+    pub fn get_authorizers(
+        &self,
+        input: GetAuthorizersRequest,
+    ) -> Result<Authorizers, RusotoError<GetAuthorizersError>> {
+        self.runtime.block_on(self.client.get_authorizers(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_base_path_mapping`].
+    pub fn get_base_path_mapping(
+        &self,
+        input: GetBasePathMappingRequest,
+    ) -> Result<BasePathMapping, RusotoError<GetBasePathMappingError>> {
+        self.runtime
+            .block_on(self.client.get_base_path_mapping(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_base_path_mappings`].
+    pub fn get_base_path_mappings(
+        &self,
+        input: GetBasePathMappingsRequest,
+    ) -> Result<BasePathMappings, RusotoError<GetBasePathMappingsError>> {
+        self.runtime
+            .block_on(self.client.get_base_path_mappings(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_client_certificate`].
+    pub fn get_client_certificate(
+        &self,
+        input: GetClientCertificateRequest,
+    ) -> Result<ClientCertificate, RusotoError<GetClientCertificateError>> {
+        self.runtime
+            .block_on(self.client.get_client_certificate(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_client_certificates`].
+    pub fn get_client_certificates(
+        &self,
+        input: GetClientCertificatesRequest,
+    ) -> Result<ClientCertificates, RusotoError<GetClientCertificatesError>> {
+        self.runtime
+            .block_on(self.client.get_client_certificates(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_deployment`].
+    pub fn get_deployment(
+        &self,
+        input: GetDeploymentRequest,
+    ) -> Result<Deployment, RusotoError<GetDeploymentError>> {
+        self.runtime.block_on(self.client.get_deployment(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_deployments`].
+    pub fn get_deployments(
+        &self,
+        input: GetDeploymentsRequest,
+    ) -> Result<Deployments, RusotoError<GetDeploymentsError>> {
+        self.runtime.block_on(self.client.get_deployments(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_documentation_part`].
+    pub fn get_documentation_part(
+        &self,
+        input: GetDocumentationPartRequest,
+    ) -> Result<DocumentationPart, RusotoError<GetDocumentationPartError>> {
+        self.runtime
+            .block_on(self.client.get_documentation_part(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_documentation_parts`].
+    pub fn get_documentation_parts(
+        &self,
+        input: GetDocumentationPartsRequest,
+    ) -> Result<DocumentationParts, RusotoError<GetDocumentationPartsError>> {
+        self.runtime
+            .block_on(self.client.get_documentation_parts(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_documentation_version`].
+    pub fn get_documentation_version(
+        &self,
+        input: GetDocumentationVersionRequest,
+    ) -> Result<DocumentationVersion, RusotoError<GetDocumentationVersionError>> {
+        self.runtime
+            .block_on(self.client.get_documentation_version(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_documentation_versions`].
+    pub fn get_documentation_versions(
+        &self,
+        input: GetDocumentationVersionsRequest,
+    ) -> Result<DocumentationVersions, RusotoError<GetDocumentationVersionsError>> {
+        self.runtime
+            .block_on(self.client.get_documentation_versions(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_domain_name`].
+    pub fn get_domain_name(
+        &self,
+        input: GetDomainNameRequest,
+    ) -> Result<DomainName, RusotoError<GetDomainNameError>> {
+        self.runtime.block_on(self.client.get_domain_name(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_domain_names`].
+    pub fn get_domain_names(
+        &self,
+        input: GetDomainNamesRequest,
+    ) -> Result<DomainNames, RusotoError<GetDomainNamesError>> {
+        self.runtime.block_on(self.client.get_domain_names(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_export`].
+    pub fn get_export(
+        &self,
+        input: GetExportRequest,
+    ) -> Result<ExportResponse, RusotoError<GetExportError>> {
+        self.runtime.block_on(self.client.get_export(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_gateway_response`].
+    pub fn get_gateway_response(
+        &self,
+        input: GetGatewayResponseRequest,
+    ) -> Result<GatewayResponse, RusotoError<GetGatewayResponseError>> {
+        self.runtime
+            .block_on(self.client.get_gateway_response(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_gateway_responses`].
+    pub fn get_gateway_responses(
+        &self,
+        input: GetGatewayResponsesRequest,
+    ) -> Result<GatewayResponses, RusotoError<GetGatewayResponsesError>> {
+        self.runtime
+            .block_on(self.client.get_gateway_responses(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_integration`].
+    pub fn get_integration(
+        &self,
+        input: GetIntegrationRequest,
+    ) -> Result<Integration, RusotoError<GetIntegrationError>> {
+        self.runtime.block_on(self.client.get_integration(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_integration_response`].
+    pub fn get_integration_response(
+        &self,
+        input: GetIntegrationResponseRequest,
+    ) -> Result<IntegrationResponse, RusotoError<GetIntegrationResponseError>> {
+        self.runtime
+            .block_on(self.client.get_integration_response(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_method`].
+    pub fn get_method(
+        &self,
+        input: GetMethodRequest,
+    ) -> Result<Method, RusotoError<GetMethodError>> {
+        self.runtime.block_on(self.client.get_method(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_method_response`].
+    pub fn get_method_response(
+        &self,
+        input: GetMethodResponseRequest,
+    ) -> Result<MethodResponse, RusotoError<GetMethodResponseError>> {
+        self.runtime
+            .block_on(self.client.get_method_response(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_model`].
+    pub fn get_model(&self, input: GetModelRequest) -> Result<Model, RusotoError<GetModelError>> {
+        self.runtime.block_on(self.client.get_model(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_model_template`].
+    pub fn get_model_template(
+        &self,
+        input: GetModelTemplateRequest,
+    ) -> Result<Template, RusotoError<GetModelTemplateError>> {
+        self.runtime.block_on(self.client.get_model_template(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_models`].
+    pub fn get_models(
+        &self,
+        input: GetModelsRequest,
+    ) -> Result<Models, RusotoError<GetModelsError>> {
+        self.runtime.block_on(self.client.get_models(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_request_validator`].
+    pub fn get_request_validator(
+        &self,
+        input: GetRequestValidatorRequest,
+    ) -> Result<RequestValidator, RusotoError<GetRequestValidatorError>> {
+        self.runtime
+            .block_on(self.client.get_request_validator(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_request_validators`].
+    pub fn get_request_validators(
+        &self,
+        input: GetRequestValidatorsRequest,
+    ) -> Result<RequestValidators, RusotoError<GetRequestValidatorsError>> {
+        self.runtime
+            .block_on(self.client.get_request_validators(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_resource`].
+    pub fn get_resource(
+        &self,
+        input: GetResourceRequest,
+    ) -> Result<Resource, RusotoError<GetResourceError>> {
+        self.runtime.block_on(self.client.get_resource(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_resources`].
+    pub fn get_resources(
+        &self,
+        input: GetResourcesRequest,
+    ) -> Result<Resources, RusotoError<GetResourcesError>> {
+        self.runtime.block_on(self.client.get_resources(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_rest_api`].
+    pub fn get_rest_api(
+        &self,
+        input: GetRestApiRequest,
+    ) -> Result<RestApi, RusotoError<GetRestApiError>> {
+        self.runtime.block_on(self.client.get_rest_api(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_rest_apis`].
+    pub fn get_rest_apis(
+        &self,
+        input: GetRestApisRequest,
+    ) -> Result<RestApis, RusotoError<GetRestApisError>> {
+        self.runtime.block_on(self.client.get_rest_apis(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_sdk`].
+    pub fn get_sdk(&self, input: GetSdkRequest) -> Result<SdkResponse, RusotoError<GetSdkError>> {
+        self.runtime.block_on(self.client.get_sdk(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_sdk_type`].
+    pub fn get_sdk_type(
+        &self,
+        input: GetSdkTypeRequest,
+    ) -> Result<SdkType, RusotoError<GetSdkTypeError>> {
+        self.runtime.block_on(self.client.get_sdk_type(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_sdk_types`].
+    pub fn get_sdk_types(
+        &self,
+        input: GetSdkTypesRequest,
+    ) -> Result<SdkTypes, RusotoError<GetSdkTypesError>> {
+        self.runtime.block_on(self.client.get_sdk_types(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_stage`].
+    pub fn get_stage(&self, input: GetStageRequest) -> Result<Stage, RusotoError<GetStageError>> {
+        self.runtime.block_on(self.client.get_stage(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_stages`].
+    pub fn get_stages(
+        &self,
+        input: GetStagesRequest,
+    ) -> Result<Stages, RusotoError<GetStagesError>> {
+        self.runtime.block_on(self.client.get_stages(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_tags`].
+    pub fn get_tags(&self, input: GetTagsRequest) -> Result<Tags, RusotoError<GetTagsError>> {
+        self.runtime.block_on(self.client.get_tags(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_usage`].
+    pub fn get_usage(&self, input: GetUsageRequest) -> Result<Usage, RusotoError<GetUsageError>> {
+        self.runtime.block_on(self.client.get_usage(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_usage_plan`].
+    pub fn get_usage_plan(
+        &self,
+        input: GetUsagePlanRequest,
+    ) -> Result<UsagePlan, RusotoError<GetUsagePlanError>> {
+        self.runtime.block_on(self.client.get_usage_plan(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_usage_plan_key`].
+    pub fn get_usage_plan_key(
+        &self,
+        input: GetUsagePlanKeyRequest,
+    ) -> Result<UsagePlanKey, RusotoError<GetUsagePlanKeyError>> {
+        self.runtime.block_on(self.client.get_usage_plan_key(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_usage_plan_keys`].
+    pub fn get_usage_plan_keys(
+        &self,
+        input: GetUsagePlanKeysRequest,
+    ) -> Result<UsagePlanKeys, RusotoError<GetUsagePlanKeysError>> {
+        self.runtime
+            .block_on(self.client.get_usage_plan_keys(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_usage_plans`].
+    pub fn get_usage_plans(
+        &self,
+        input: GetUsagePlansRequest,
+    ) -> Result<UsagePlans, RusotoError<GetUsagePlansError>> {
+        self.runtime.block_on(self.client.get_usage_plans(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_vpc_link`].
+    pub fn get_vpc_link(
+        &self,
+        input: GetVpcLinkRequest,
+    ) -> Result<VpcLink, RusotoError<GetVpcLinkError>> {
+        self.runtime.block_on(self.client.get_vpc_link(input))
+    }
+
+    /// Blocking version of [`ApiGateway::get_vpc_links`].
+    pub fn get_vpc_links(
+        &self,
+        input: GetVpcLinksRequest,
+    ) -> Result<VpcLinks, RusotoError<GetVpcLinksError>> {
+        self.runtime.block_on(self.client.get_vpc_links(input))
+    }
+
+    /// Blocking version of [`ApiGateway::import_api_keys`].
+    pub fn import_api_keys(
+        &self,
+        input: ImportApiKeysRequest,
+    ) -> Result<ApiKeyIds, RusotoError<ImportApiKeysError>> {
+        self.runtime.block_on(self.client.import_api_keys(input))
+    }
+
+    /// Blocking version of [`ApiGateway::import_documentation_parts`].
+    pub fn import_documentation_parts(
+        &self,
+        input: ImportDocumentationPartsRequest,
+    ) -> Result<DocumentationPartIds, RusotoError<ImportDocumentationPartsError>> {
+        self.runtime
+            .block_on(self.client.import_documentation_parts(input))
+    }
+
+    /// Blocking version of [`ApiGateway::import_rest_api`].
+    pub fn import_rest_api(
+        &self,
+        input: ImportRestApiRequest,
+    ) -> Result<RestApi, RusotoError<ImportRestApiError>> {
+        self.runtime.block_on(self.client.import_rest_api(input))
+    }
+
+    /// Blocking version of [`ApiGateway::put_gateway_response`].
+    pub fn put_gateway_response(
+        &self,
+        input: PutGatewayResponseRequest,
+    ) -> Result<GatewayResponse, RusotoError<PutGatewayResponseError>> {
+        self.runtime
+            .block_on(self.client.put_gateway_response(input))
+    }
+
+    /// Blocking version of [`ApiGateway::put_integration`].
+    pub fn put_integration(
+        &self,
+        input: PutIntegrationRequest,
+    ) -> Result<Integration, RusotoError<PutIntegrationError>> {
+        self.runtime.block_on(self.client.put_integration(input))
+    }
+
+    /// Blocking version of [`ApiGateway::put_integration_response`].
+    pub fn put_integration_response(
+        &self,
+        input: PutIntegrationResponseRequest,
+    ) -> Result<IntegrationResponse, RusotoError<PutIntegrationResponseError>> {
+        self.runtime
+            .block_on(self.client.put_integration_response(input))
+    }
+
+    /// Blocking version of [`ApiGateway::put_method`].
+    pub fn put_method(
+        &self,
+        input: PutMethodRequest,
+    ) -> Result<Method, RusotoError<PutMethodError>> {
+        self.runtime.block_on(self.client.put_method(input))
+    }
+
+    /// Blocking version of [`ApiGateway::put_method_response`].
+    pub fn put_method_response(
+        &self,
+        input: PutMethodResponseRequest,
+    ) -> Result<MethodResponse, RusotoError<PutMethodResponseError>> {
+        self.runtime
+            .block_on(self.client.put_method_response(input))
+    }
+
+    /// Blocking version of [`ApiGateway::put_rest_api`].
+    pub fn put_rest_api(
+        &self,
+        input: PutRestApiRequest,
+    ) -> Result<RestApi, RusotoError<PutRestApiError>> {
+        self.runtime.block_on(self.client.put_rest_api(input))
+    }
+
+    /// Blocking version of [`ApiGateway::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`ApiGateway::test_invoke_authorizer`].
+    pub fn test_invoke_authorizer(
+        &self,
+        input: TestInvokeAuthorizerRequest,
+    ) -> Result<TestInvokeAuthorizerResponse, RusotoError<TestInvokeAuthorizerError>> {
+        self.runtime
+            .block_on(self.client.test_invoke_authorizer(input))
+    }
+
+    /// Blocking version of [`ApiGateway::test_invoke_method`].
+    pub fn test_invoke_method(
+        &self,
+        input: TestInvokeMethodRequest,
+    ) -> Result<TestInvokeMethodResponse, RusotoError<TestInvokeMethodError>> {
+        self.runtime.block_on(self.client.test_invoke_method(input))
+    }
+
+    /// Blocking version of [`ApiGateway::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`ApiGateway::update_account`].
+    pub fn update_account(
+        &self,
+        input: UpdateAccountRequest,
+    ) -> Result<Account, RusotoError<UpdateAccountError>> {
+        self.runtime.block_on(self.client.update_account(input))
+    }
+
+    /// Blocking version of [`ApiGateway::update_api_key`].
+    pub fn update_api_key(
+        &self,
+        input: UpdateApiKeyRequest,
+    ) -> Result<ApiKey, RusotoError<UpdateApiKeyError>> {
+        self.runtime.block_on(self.client.update_api_key(input))
+    }
+
+    /// Blocking version of [`ApiGateway::update_authorizer`].
+    pub fn update_authorizer(
+        &self,
+        input: UpdateAuthorizerRequest,
+    ) -> Result<Authorizer, RusotoError<UpdateAuthorizerError>> {
+        self.runtime.block_on(self.client.update_authorizer(input))
+    }
+
+    /// Blocking version of [`ApiGateway::update_base_path_mapping`].
+    pub fn update_base_path_mapping(
+        &self,
+        input: UpdateBasePathMappingRequest,
+    ) -> Result<BasePathMapping, RusotoError<UpdateBasePathMappingError>> {
+        self.runtime
+            .block_on(self.client.update_base_path_mapping(input))
+    }
+
+    /// Blocking version of [`ApiGateway::update_client_certificate`].
+    pub fn update_client_certificate(
+        &self,
+        input: UpdateClientCertificateRequest,
+    ) -> Result<ClientCertificate, RusotoError<UpdateClientCertificateError>> {
+        self.runtime
+            .block_on(self.client.update_client_certificate(input))
+    }
+
+    /// Blocking version of [`ApiGateway::update_deployment`].
+    pub fn update_deployment(
+        &self,
+        input: UpdateDeploymentRequest,
+    ) -> Result<Deployment, RusotoError<UpdateDeploymentError>> {
+        self.runtime.block_on(self.client.update_deployment(input))
+    }
+
+    /// Blocking version of [`ApiGateway::update_documentation_part`].
+    pub fn update_documentation_part(
+        &self,
+        input: UpdateDocumentationPartRequest,
+    ) -> Result<DocumentationPart, RusotoError<UpdateDocumentationPartError>> {
+        self.runtime
+            .block_on(self.client.update_documentation_part(input))
+    }
+
+    /// Blocking version of [`ApiGateway::update_documentation_version`].
+    pub fn update_documentation_version(
+        &self,
+        input: UpdateDocumentationVersionRequest,
+    ) -> Result<DocumentationVersion, RusotoError<UpdateDocumentationVersionError>> {
+        self.runtime
+            .block_on(self.client.update_documentation_version(input))
+    }
+
+    /// Blocking version of [`ApiGateway::update_domain_name`].
+    pub fn update_domain_name(
+        &self,
+        input: UpdateDomainNameRequest,
+    ) -> Result<DomainName, RusotoError<UpdateDomainNameError>> {
+        self.runtime.block_on(self.client.update_domain_name(input))
+    }
+
+    /// Blocking version of [`ApiGateway::update_gateway_response`].
+    pub fn update_gateway_response(
+        &self,
+        input: UpdateGatewayResponseRequest,
+    ) -> Result<GatewayResponse, RusotoError<UpdateGatewayResponseError>> {
+        self.runtime
+            .block_on(self.client.update_gateway_response(input))
+    }
+
+    /// Blocking version of [`ApiGateway::update_integration`].
+    pub fn update_integration(
+        &self,
+        input: UpdateIntegrationRequest,
+    ) -> Result<Integration, RusotoError<UpdateIntegrationError>> {
+        self.runtime.block_on(self.client.update_integration(input))
+    }
+
+    /// Blocking version of [`ApiGateway::update_integration_response`].
+    pub fn update_integration_response(
+        &self,
+        input: UpdateIntegrationResponseRequest,
+    ) -> Result<IntegrationResponse, RusotoError<UpdateIntegrationResponseError>> {
+        self.runtime
+            .block_on(self.client.update_integration_response(input))
+    }
+
+    /// Blocking version of [`ApiGateway::update_method`].
+    pub fn update_method(
+        &self,
+        input: UpdateMethodRequest,
+    ) -> Result<Method, RusotoError<UpdateMethodError>> {
+        self.runtime.block_on(self.client.update_method(input))
+    }
+
+    /// Blocking version of [`ApiGateway::update_method_response`].
+    pub fn update_method_response(
+        &self,
+        input: UpdateMethodResponseRequest,
+    ) -> Result<MethodResponse, RusotoError<UpdateMethodResponseError>> {
+        self.runtime
+            .block_on(self.client.update_method_response(input))
+    }
+
+    /// Blocking version of [`ApiGateway::update_model`].
+    pub fn update_model(
+        &self,
+        input: UpdateModelRequest,
+    ) -> Result<Model, RusotoError<UpdateModelError>> {
+        self.runtime.block_on(self.client.update_model(input))
+    }
+
+    /// Blocking version of [`ApiGateway::update_request_validator`].
+    pub fn update_request_validator(
+        &self,
+        input: UpdateRequestValidatorRequest,
+    ) -> Result<RequestValidator, RusotoError<UpdateRequestValidatorError>> {
+        self.runtime
+            .block_on(self.client.update_request_validator(input))
+    }
+
+    /// Blocking version of [`ApiGateway::update_resource`].
+    pub fn update_resource(
+        &self,
+        input: UpdateResourceRequest,
+    ) -> Result<Resource, RusotoError<UpdateResourceError>> {
+        self.runtime.block_on(self.client.update_resource(input))
+    }
+
+    /// Blocking version of [`ApiGateway::update_rest_api`].
+    pub fn update_rest_api(
+        &self,
+        input: UpdateRestApiRequest,
+    ) -> Result<RestApi, RusotoError<UpdateRestApiError>> {
+        self.runtime.block_on(self.client.update_rest_api(input))
+    }
+
+    /// Blocking version of [`ApiGateway::update_stage`].
+    pub fn update_stage(
+        &self,
+        input: UpdateStageRequest,
+    ) -> Result<Stage, RusotoError<UpdateStageError>> {
+        self.runtime.block_on(self.client.update_stage(input))
+    }
+
+    /// Blocking version of [`ApiGateway::update_usage`].
+    pub fn update_usage(
+        &self,
+        input: UpdateUsageRequest,
+    ) -> Result<Usage, RusotoError<UpdateUsageError>> {
+        self.runtime.block_on(self.client.update_usage(input))
+    }
+
+    /// Blocking version of [`ApiGateway::update_usage_plan`].
+    pub fn update_usage_plan(
+        &self,
+        input: UpdateUsagePlanRequest,
+    ) -> Result<UsagePlan, RusotoError<UpdateUsagePlanError>> {
+        self.runtime.block_on(self.client.update_usage_plan(input))
+    }
+
+    /// Blocking version of [`ApiGateway::update_vpc_link`].
+    pub fn update_vpc_link(
+        &self,
+        input: UpdateVpcLinkRequest,
+    ) -> Result<VpcLink, RusotoError<UpdateVpcLinkError>> {
+        self.runtime.block_on(self.client.update_vpc_link(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -96,15 +97,30 @@ impl DeleteConnectionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteConnectionError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteConnectionError::Forbidden,
+                    )
                 }
                 "GoneException" => {
-                    return RusotoError::Service(DeleteConnectionError::Gone(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteConnectionError::Gone,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteConnectionError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteConnectionError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -138,13 +154,30 @@ impl GetConnectionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetConnectionError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConnectionError::Forbidden,
+                    )
+                }
+                "GoneException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConnectionError::Gone,
+                    )
                 }
-                "GoneException" => return RusotoError::Service(GetConnectionError::Gone(err.msg)),
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetConnectionError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConnectionError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -180,18 +213,38 @@ impl PostToConnectionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(PostToConnectionError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostToConnectionError::Forbidden,
+                    )
                 }
                 "GoneException" => {
-                    return RusotoError::Service(PostToConnectionError::Gone(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostToConnectionError::Gone,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PostToConnectionError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostToConnectionError::LimitExceeded,
+                    )
                 }
                 "PayloadTooLargeException" => {
-                    return RusotoError::Service(PostToConnectionError::PayloadTooLarge(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostToConnectionError::PayloadTooLarge,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -241,11 +294,12 @@ pub struct ApiGatewayManagementApiClient {
 impl ApiGatewayManagementApiClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> ApiGatewayManagementApiClient {
         ApiGatewayManagementApiClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "ApiGatewayManagementApi"),
         }
     }
 
@@ -260,7 +314,7 @@ impl ApiGatewayManagementApiClient {
     {
         ApiGatewayManagementApiClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "ApiGatewayManagementApi"),
         }
     }
 
@@ -268,7 +322,27 @@ impl ApiGatewayManagementApiClient {
         client: Client,
         region: region::Region,
     ) -> ApiGatewayManagementApiClient {
-        ApiGatewayManagementApiClient { client, region }
+        ApiGatewayManagementApiClient {
+            client,
+            region: region_for_service(region, "ApiGatewayManagementApi"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> ApiGatewayManagementApiClient {
+        ApiGatewayManagementApiClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("ApiGatewayManagementApi"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ApiGatewayManagementApiClient {
+        ApiGatewayManagementApiClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -286,6 +360,7 @@ impl ApiGatewayManagementApi for ApiGatewayManagementApiClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteConnection");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -316,6 +391,7 @@ impl ApiGatewayManagementApi for ApiGatewayManagementApiClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("GetConnection");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -347,6 +423,7 @@ impl ApiGatewayManagementApi for ApiGatewayManagementApiClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("PostToConnection");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.data.to_owned());
@@ -368,3 +445,116 @@ impl ApiGatewayManagementApi for ApiGatewayManagementApiClient {
         }
     }
 }
+
+/// A blocking client for the AmazonApiGatewayManagementApi API, running the calls of a `ApiGatewayManagementApiClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `ApiGatewayManagementApiClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ApiGatewayManagementApiBlockingClient {
+    client: ApiGatewayManagementApiClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ApiGatewayManagementApiBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> ApiGatewayManagementApiBlockingClient {
+        ApiGatewayManagementApiBlockingClient::from_client(ApiGatewayManagementApiClient::new(
+            region,
+        ))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ApiGatewayManagementApiBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ApiGatewayManagementApiBlockingClient::from_client(ApiGatewayManagementApiClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> ApiGatewayManagementApiBlockingClient {
+        ApiGatewayManagementApiBlockingClient::from_client(
+            ApiGatewayManagementApiClient::new_with_client(client, region),
+        )
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> ApiGatewayManagementApiBlockingClient {
+        ApiGatewayManagementApiBlockingClient::from_client(
+            ApiGatewayManagementApiClient::new_with_config(config),
+        )
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(
+        client: ApiGatewayManagementApiClient,
+    ) -> ApiGatewayManagementApiBlockingClient {
+        ApiGatewayManagementApiBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> ApiGatewayManagementApiBlockingClient {
+        ApiGatewayManagementApiBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ApiGatewayManagementApiBlockingClient {
+        ApiGatewayManagementApiBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`ApiGatewayManagementApi::delete_connection`].
+    pub fn delete_connection(
+        &self,
+        input: DeleteConnectionRequest,
+    ) -> Result<(), RusotoError<DeleteConnectionError>> {
+        self.runtime.block_on(self.client.delete_connection(input))
+    }
+
+    /// Blocking version of [`ApiGatewayManagementApi::get_connection`].
+    pub fn get_connection(
+        &self,
+        input: GetConnectionRequest,
+    ) -> Result<GetConnectionResponse, RusotoError<GetConnectionError>> {
+        self.runtime.block_on(self.client.get_connection(input))
+    }
+
+    /// Blocking version of [`ApiGatewayManagementApi::post_to_connection`].
+    pub fn post_to_connection(
+        &self,
+        input: PostToConnectionRequest,
+    ) -> Result<(), RusotoError<PostToConnectionError>> {
+        self.runtime.block_on(self.client.post_to_connection(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -4098,18 +4099,38 @@ impl CreateApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateApiError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApiError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateApiError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApiError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateApiError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApiError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateApiError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApiError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4146,18 +4167,38 @@ impl CreateApiMappingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateApiMappingError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApiMappingError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateApiMappingError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApiMappingError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateApiMappingError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApiMappingError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateApiMappingError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApiMappingError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4194,18 +4235,38 @@ impl CreateAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateAuthorizerError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAuthorizerError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateAuthorizerError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAuthorizerError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateAuthorizerError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAuthorizerError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateAuthorizerError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAuthorizerError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4242,18 +4303,38 @@ impl CreateDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDeploymentError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateDeploymentError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDeploymentError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateDeploymentError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4291,21 +4372,46 @@ impl CreateDomainNameError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDomainNameError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDomainNameError::AccessDenied,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDomainNameError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDomainNameError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateDomainNameError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDomainNameError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDomainNameError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDomainNameError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateDomainNameError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDomainNameError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4343,18 +4449,38 @@ impl CreateIntegrationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateIntegrationError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIntegrationError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateIntegrationError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIntegrationError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateIntegrationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIntegrationError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateIntegrationError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIntegrationError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4391,22 +4517,38 @@ impl CreateIntegrationResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateIntegrationResponseError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateIntegrationResponseError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateIntegrationResponseError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIntegrationResponseError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateIntegrationResponseError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIntegrationResponseError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateIntegrationResponseError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateIntegrationResponseError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4443,18 +4585,38 @@ impl CreateModelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateModelError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateModelError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateModelError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateModelError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateModelError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateModelError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateModelError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateModelError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4491,18 +4653,38 @@ impl CreateRouteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateRouteError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRouteError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateRouteError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRouteError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateRouteError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRouteError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateRouteError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRouteError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4539,18 +4721,38 @@ impl CreateRouteResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateRouteResponseError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRouteResponseError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateRouteResponseError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRouteResponseError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateRouteResponseError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRouteResponseError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateRouteResponseError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRouteResponseError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4587,18 +4789,38 @@ impl CreateStageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateStageError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStageError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateStageError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStageError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateStageError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStageError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateStageError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStageError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4631,12 +4853,22 @@ impl CreateVpcLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateVpcLinkError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVpcLinkError::BadRequest,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateVpcLinkError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVpcLinkError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4667,14 +4899,22 @@ impl DeleteAccessLogSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteAccessLogSettingsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAccessLogSettingsError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteAccessLogSettingsError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteAccessLogSettingsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4705,12 +4945,22 @@ impl DeleteApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteApiError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApiError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteApiError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApiError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4743,15 +4993,30 @@ impl DeleteApiMappingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteApiMappingError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApiMappingError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteApiMappingError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApiMappingError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteApiMappingError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApiMappingError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4783,12 +5048,22 @@ impl DeleteAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteAuthorizerError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAuthorizerError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteAuthorizerError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAuthorizerError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4819,14 +5094,22 @@ impl DeleteCorsConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteCorsConfigurationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCorsConfigurationError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteCorsConfigurationError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteCorsConfigurationError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4857,12 +5140,22 @@ impl DeleteDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDeploymentError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDeploymentError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteDeploymentError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDeploymentError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4893,12 +5186,22 @@ impl DeleteDomainNameError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDomainNameError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDomainNameError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteDomainNameError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDomainNameError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4929,12 +5232,22 @@ impl DeleteIntegrationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteIntegrationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIntegrationError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteIntegrationError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIntegrationError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4965,14 +5278,22 @@ impl DeleteIntegrationResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteIntegrationResponseError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIntegrationResponseError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteIntegrationResponseError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteIntegrationResponseError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5003,12 +5324,22 @@ impl DeleteModelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteModelError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteModelError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteModelError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteModelError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5039,12 +5370,22 @@ impl DeleteRouteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRouteError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRouteError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteRouteError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRouteError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5077,16 +5418,22 @@ impl DeleteRouteRequestParameterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRouteRequestParameterError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRouteRequestParameterError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteRouteRequestParameterError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRouteRequestParameterError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5117,12 +5464,22 @@ impl DeleteRouteResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRouteResponseError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRouteResponseError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteRouteResponseError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRouteResponseError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5153,12 +5510,22 @@ impl DeleteRouteSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRouteSettingsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRouteSettingsError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteRouteSettingsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRouteSettingsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5189,12 +5556,22 @@ impl DeleteStageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteStageError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteStageError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteStageError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteStageError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5225,12 +5602,22 @@ impl DeleteVpcLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVpcLinkError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVpcLinkError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteVpcLinkError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVpcLinkError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5263,15 +5650,30 @@ impl ExportApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ExportApiError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExportApiError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ExportApiError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExportApiError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ExportApiError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExportApiError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5302,11 +5704,23 @@ impl GetApiError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetApiError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "NotFoundException" => return RusotoError::Service(GetApiError::NotFound(err.msg)),
+                "NotFoundException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetApiError::NotFound,
+                    )
+                }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetApiError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetApiError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5339,15 +5753,30 @@ impl GetApiMappingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetApiMappingError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetApiMappingError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetApiMappingError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetApiMappingError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetApiMappingError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetApiMappingError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5381,15 +5810,30 @@ impl GetApiMappingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetApiMappingsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetApiMappingsError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetApiMappingsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetApiMappingsError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetApiMappingsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetApiMappingsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5423,15 +5867,30 @@ impl GetApisError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetApisError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetApisError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetApisError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetApisError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetApisError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetApisError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5463,12 +5922,22 @@ impl GetAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetAuthorizerError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAuthorizerError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetAuthorizerError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAuthorizerError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5501,15 +5970,30 @@ impl GetAuthorizersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetAuthorizersError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAuthorizersError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetAuthorizersError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAuthorizersError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetAuthorizersError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAuthorizersError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5541,12 +6025,22 @@ impl GetDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDeploymentError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeploymentError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDeploymentError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeploymentError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5579,15 +6073,30 @@ impl GetDeploymentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDeploymentsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeploymentsError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDeploymentsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeploymentsError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDeploymentsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeploymentsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5619,12 +6128,22 @@ impl GetDomainNameError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDomainNameError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDomainNameError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDomainNameError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDomainNameError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5657,15 +6176,30 @@ impl GetDomainNamesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDomainNamesError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDomainNamesError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDomainNamesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDomainNamesError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDomainNamesError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDomainNamesError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5697,12 +6231,22 @@ impl GetIntegrationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetIntegrationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIntegrationError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetIntegrationError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIntegrationError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5733,14 +6277,22 @@ impl GetIntegrationResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetIntegrationResponseError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIntegrationResponseError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetIntegrationResponseError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetIntegrationResponseError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5773,17 +6325,30 @@ impl GetIntegrationResponsesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetIntegrationResponsesError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIntegrationResponsesError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetIntegrationResponsesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIntegrationResponsesError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetIntegrationResponsesError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetIntegrationResponsesError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5817,15 +6382,30 @@ impl GetIntegrationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetIntegrationsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIntegrationsError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetIntegrationsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIntegrationsError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetIntegrationsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIntegrationsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5857,12 +6437,22 @@ impl GetModelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetModelError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetModelError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetModelError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetModelError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5893,12 +6483,22 @@ impl GetModelTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetModelTemplateError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetModelTemplateError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetModelTemplateError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetModelTemplateError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5931,15 +6531,30 @@ impl GetModelsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetModelsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetModelsError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetModelsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetModelsError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetModelsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetModelsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5971,12 +6586,22 @@ impl GetRouteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRouteError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRouteError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetRouteError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRouteError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6007,12 +6632,22 @@ impl GetRouteResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRouteResponseError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRouteResponseError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetRouteResponseError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRouteResponseError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6045,15 +6680,30 @@ impl GetRouteResponsesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetRouteResponsesError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRouteResponsesError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRouteResponsesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRouteResponsesError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetRouteResponsesError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRouteResponsesError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6087,15 +6737,30 @@ impl GetRoutesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetRoutesError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRoutesError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRoutesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRoutesError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetRoutesError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRoutesError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6127,12 +6792,22 @@ impl GetStageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetStageError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetStageError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetStageError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetStageError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6165,15 +6840,30 @@ impl GetStagesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetStagesError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetStagesError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetStagesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetStagesError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetStagesError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetStagesError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6209,18 +6899,38 @@ impl GetTagsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetTagsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTagsError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(GetTagsError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTagsError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetTagsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTagsError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetTagsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTagsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6253,12 +6963,22 @@ impl GetVpcLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(GetVpcLinkError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVpcLinkError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetVpcLinkError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVpcLinkError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6289,12 +7009,22 @@ impl GetVpcLinksError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetVpcLinksError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVpcLinksError::BadRequest,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetVpcLinksError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVpcLinksError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6329,18 +7059,38 @@ impl ImportApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ImportApiError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportApiError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(ImportApiError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportApiError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ImportApiError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportApiError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ImportApiError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportApiError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6377,18 +7127,38 @@ impl ReimportApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ReimportApiError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReimportApiError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(ReimportApiError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReimportApiError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ReimportApiError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReimportApiError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ReimportApiError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReimportApiError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6421,14 +7191,22 @@ impl ResetAuthorizersCacheError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "NotFoundException" => {
-                    return RusotoError::Service(ResetAuthorizersCacheError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResetAuthorizersCacheError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ResetAuthorizersCacheError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ResetAuthorizersCacheError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6463,18 +7241,38 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(TagResourceError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6511,18 +7309,38 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UntagResourceError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6559,18 +7377,38 @@ impl UpdateApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateApiError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApiError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateApiError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApiError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateApiError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApiError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateApiError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApiError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6607,18 +7445,38 @@ impl UpdateApiMappingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateApiMappingError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApiMappingError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateApiMappingError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApiMappingError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateApiMappingError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApiMappingError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateApiMappingError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApiMappingError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6655,18 +7513,38 @@ impl UpdateAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateAuthorizerError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAuthorizerError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateAuthorizerError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAuthorizerError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateAuthorizerError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAuthorizerError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateAuthorizerError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAuthorizerError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6703,18 +7581,38 @@ impl UpdateDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateDeploymentError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeploymentError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDeploymentError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeploymentError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateDeploymentError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeploymentError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateDeploymentError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeploymentError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6751,18 +7649,38 @@ impl UpdateDomainNameError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateDomainNameError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDomainNameError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDomainNameError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDomainNameError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateDomainNameError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDomainNameError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateDomainNameError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDomainNameError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6799,18 +7717,38 @@ impl UpdateIntegrationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateIntegrationError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIntegrationError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateIntegrationError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIntegrationError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateIntegrationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIntegrationError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateIntegrationError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIntegrationError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6847,22 +7785,38 @@ impl UpdateIntegrationResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateIntegrationResponseError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateIntegrationResponseError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateIntegrationResponseError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIntegrationResponseError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateIntegrationResponseError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIntegrationResponseError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateIntegrationResponseError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateIntegrationResponseError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6899,18 +7853,38 @@ impl UpdateModelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateModelError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateModelError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateModelError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateModelError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateModelError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateModelError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateModelError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateModelError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6947,18 +7921,38 @@ impl UpdateRouteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateRouteError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRouteError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateRouteError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRouteError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateRouteError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRouteError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateRouteError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRouteError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6995,18 +7989,38 @@ impl UpdateRouteResponseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateRouteResponseError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRouteResponseError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateRouteResponseError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRouteResponseError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateRouteResponseError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRouteResponseError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateRouteResponseError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRouteResponseError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7043,18 +8057,38 @@ impl UpdateStageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateStageError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateStageError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateStageError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateStageError::Conflict,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateStageError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateStageError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateStageError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateStageError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7089,15 +8123,30 @@ impl UpdateVpcLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateVpcLinkError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVpcLinkError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateVpcLinkError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVpcLinkError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateVpcLinkError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVpcLinkError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7556,11 +8605,12 @@ pub struct ApiGatewayV2Client {
 impl ApiGatewayV2Client {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> ApiGatewayV2Client {
         ApiGatewayV2Client {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "ApiGatewayV2"),
         }
     }
 
@@ -7575,12 +8625,32 @@ impl ApiGatewayV2Client {
     {
         ApiGatewayV2Client {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "ApiGatewayV2"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ApiGatewayV2Client {
-        ApiGatewayV2Client { client, region }
+        ApiGatewayV2Client {
+            client,
+            region: region_for_service(region, "ApiGatewayV2"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> ApiGatewayV2Client {
+        ApiGatewayV2Client {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("ApiGatewayV2"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ApiGatewayV2Client {
+        ApiGatewayV2Client {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -7595,6 +8665,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = "/v2/apis";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7629,6 +8700,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateApiMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7660,6 +8732,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/authorizers", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7691,6 +8764,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/deployments", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7722,6 +8796,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = "/v2/domainnames";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateDomainName");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7753,6 +8828,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/integrations", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateIntegration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7789,6 +8865,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateIntegrationResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7820,6 +8897,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/models", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7851,6 +8929,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/routes", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateRoute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7886,6 +8965,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateRouteResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7917,6 +8997,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/stages", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateStage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7948,6 +9029,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = "/v2/vpclinks";
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("CreateVpcLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7983,6 +9065,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteAccessLogSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8007,6 +9090,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}", api_id = input.api_id);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8038,6 +9122,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteApiMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8069,6 +9154,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8096,6 +9182,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/cors", api_id = input.api_id);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteCorsConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8127,6 +9214,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8157,6 +9245,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteDomainName");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8188,6 +9277,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteIntegration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8215,6 +9305,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/integrations/{integration_id}/integrationresponses/{integration_response_id}", api_id = input.api_id, integration_id = input.integration_id, integration_response_id = input.integration_response_id);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteIntegrationResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8246,6 +9337,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8277,6 +9369,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteRoute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8309,6 +9402,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteRouteRequestParameter");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8341,6 +9435,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteRouteResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8373,6 +9468,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteRouteSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8404,6 +9500,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteStage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8434,6 +9531,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("DeleteVpcLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8465,6 +9563,8 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("ExportApi");
+        request.set_raw_response(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8507,6 +9607,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8539,6 +9640,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetApiMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8570,6 +9672,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetApiMappings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8607,6 +9710,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = "/v2/apis";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetApis");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8648,6 +9752,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8676,6 +9781,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/authorizers", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetAuthorizers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8717,6 +9823,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8745,6 +9852,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/deployments", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDeployments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8785,6 +9893,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDomainName");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8813,6 +9922,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = "/v2/domainnames";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetDomainNames");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8854,6 +9964,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetIntegration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8882,6 +9993,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/integrations/{integration_id}/integrationresponses/{integration_response_id}", api_id = input.api_id, integration_id = input.integration_id, integration_response_id = input.integration_response_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetIntegrationResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8914,6 +10026,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetIntegrationResponses");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8951,6 +10064,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/integrations", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetIntegrations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8992,6 +10106,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9024,6 +10139,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetModelTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9052,6 +10168,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/models", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetModels");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9093,6 +10210,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetRoute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9126,6 +10244,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetRouteResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9158,6 +10277,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetRouteResponses");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9195,6 +10315,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/routes", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetRoutes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9236,6 +10357,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetStage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9264,6 +10386,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/stages", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetStages");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9301,6 +10424,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9332,6 +10456,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetVpcLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9360,6 +10485,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = "/v2/vpclinks";
 
         let mut request = SignedRequest::new("GET", "apigateway", &self.region, &request_uri);
+        request.set_operation("GetVpcLinks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9397,6 +10523,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = "/v2/apis";
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("ImportApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9437,6 +10564,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}", api_id = input.api_id);
 
         let mut request = SignedRequest::new("PUT", "apigateway", &self.region, &request_uri);
+        request.set_operation("ReimportApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9481,6 +10609,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("ResetAuthorizersCache");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9508,6 +10637,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "apigateway", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9539,6 +10669,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "apigateway", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9572,6 +10703,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}", api_id = input.api_id);
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9607,6 +10739,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateApiMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9642,6 +10775,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9677,6 +10811,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9711,6 +10846,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateDomainName");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9746,6 +10882,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateIntegration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9778,6 +10915,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         let request_uri = format!("/v2/apis/{api_id}/integrations/{integration_id}/integrationresponses/{integration_response_id}", api_id = input.api_id, integration_id = input.integration_id, integration_response_id = input.integration_response_id);
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateIntegrationResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9813,6 +10951,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateModel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9848,6 +10987,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateRoute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9884,6 +11024,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateRouteResponse");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9919,6 +11060,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateStage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9953,6 +11095,7 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         );
 
         let mut request = SignedRequest::new("PATCH", "apigateway", &self.region, &request_uri);
+        request.set_operation("UpdateVpcLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9975,3 +11118,670 @@ impl ApiGatewayV2 for ApiGatewayV2Client {
         }
     }
 }
+
+/// A blocking client for the AmazonApiGatewayV2 API, running the calls of a `ApiGatewayV2Client`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `ApiGatewayV2Client`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ApiGatewayV2BlockingClient {
+    client: ApiGatewayV2Client,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ApiGatewayV2BlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> ApiGatewayV2BlockingClient {
+        ApiGatewayV2BlockingClient::from_client(ApiGatewayV2Client::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ApiGatewayV2BlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ApiGatewayV2BlockingClient::from_client(ApiGatewayV2Client::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> ApiGatewayV2BlockingClient {
+        ApiGatewayV2BlockingClient::from_client(ApiGatewayV2Client::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> ApiGatewayV2BlockingClient {
+        ApiGatewayV2BlockingClient::from_client(ApiGatewayV2Client::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: ApiGatewayV2Client) -> ApiGatewayV2BlockingClient {
+        ApiGatewayV2BlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> ApiGatewayV2BlockingClient {
+        ApiGatewayV2BlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ApiGatewayV2BlockingClient {
+        ApiGatewayV2BlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`ApiGatewayV2::create_api`].
+    pub fn create_api(
+        &self,
+        input: CreateApiRequest,
+    ) -> Result<CreateApiResponse, RusotoError<CreateApiError>> {
+        self.runtime.block_on(self.client.create_api(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::create_api_mapping`].
+    pub fn create_api_mapping(
+        &self,
+        input: CreateApiMappingRequest,
+    ) -> Result<CreateApiMappingResponse, RusotoError<CreateApiMappingError>> {
+        self.runtime.block_on(self.client.create_api_mapping(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::create_authorizer`].
+    pub fn create_authorizer(
+        &self,
+        input: CreateAuthorizerRequest,
+    ) -> Result<CreateAuthorizerResponse, RusotoError<CreateAuthorizerError>> {
+        self.runtime.block_on(self.client.create_authorizer(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::create_deployment`].
+    pub fn create_deployment(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> Result<CreateDeploymentResponse, RusotoError<CreateDeploymentError>> {
+        self.runtime.block_on(self.client.create_deployment(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::create_domain_name`].
+    pub fn create_domain_name(
+        &self,
+        input: CreateDomainNameRequest,
+    ) -> Result<CreateDomainNameResponse, RusotoError<CreateDomainNameError>> {
+        self.runtime.block_on(self.client.create_domain_name(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::create_integration`].
+    pub fn create_integration(
+        &self,
+        input: CreateIntegrationRequest,
+    ) -> Result<CreateIntegrationResult, RusotoError<CreateIntegrationError>> {
+        self.runtime.block_on(self.client.create_integration(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::create_integration_response`].
+    pub fn create_integration_response(
+        &self,
+        input: CreateIntegrationResponseRequest,
+    ) -> Result<CreateIntegrationResponseResponse, RusotoError<CreateIntegrationResponseError>>
+    {
+        self.runtime
+            .block_on(self.client.create_integration_response(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::create_model`].
+    pub fn create_model(
+        &self,
+        input: CreateModelRequest,
+    ) -> Result<CreateModelResponse, RusotoError<CreateModelError>> {
+        self.runtime.block_on(self.client.create_model(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::create_route`].
+    pub fn create_route(
+        &self,
+        input: CreateRouteRequest,
+    ) -> Result<CreateRouteResult, RusotoError<CreateRouteError>> {
+        self.runtime.block_on(self.client.create_route(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::create_route_response`].
+    pub fn create_route_response(
+        &self,
+        input: CreateRouteResponseRequest,
+    ) -> Result<CreateRouteResponseResponse, RusotoError<CreateRouteResponseError>> {
+        self.runtime
+            .block_on(self.client.create_route_response(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::create_stage`].
+    pub fn create_stage(
+        &self,
+        input: CreateStageRequest,
+    ) -> Result<CreateStageResponse, RusotoError<CreateStageError>> {
+        self.runtime.block_on(self.client.create_stage(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::create_vpc_link`].
+    pub fn create_vpc_link(
+        &self,
+        input: CreateVpcLinkRequest,
+    ) -> Result<CreateVpcLinkResponse, RusotoError<CreateVpcLinkError>> {
+        self.runtime.block_on(self.client.create_vpc_link(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::delete_access_log_settings`].
+    pub fn delete_access_log_settings(
+        &self,
+        input: DeleteAccessLogSettingsRequest,
+    ) -> Result<(), RusotoError<DeleteAccessLogSettingsError>> {
+        self.runtime
+            .block_on(self.client.delete_access_log_settings(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::delete_api`].
+    pub fn delete_api(&self, input: DeleteApiRequest) -> Result<(), RusotoError<DeleteApiError>> {
+        self.runtime.block_on(self.client.delete_api(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::delete_api_mapping`].
+    pub fn delete_api_mapping(
+        &self,
+        input: DeleteApiMappingRequest,
+    ) -> Result<(), RusotoError<DeleteApiMappingError>> {
+        self.runtime.block_on(self.client.delete_api_mapping(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::delete_authorizer`].
+    pub fn delete_authorizer(
+        &self,
+        input: DeleteAuthorizerRequest,
+    ) -> Result<(), RusotoError<DeleteAuthorizerError>> {
+        self.runtime.block_on(self.client.delete_authorizer(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::delete_cors_configuration`].
+    pub fn delete_cors_configuration(
+        &self,
+        input: DeleteCorsConfigurationRequest,
+    ) -> Result<(), RusotoError<DeleteCorsConfigurationError>> {
+        self.runtime
+            .block_on(self.client.delete_cors_configuration(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::delete_deployment`].
+    pub fn delete_deployment(
+        &self,
+        input: DeleteDeploymentRequest,
+    ) -> Result<(), RusotoError<DeleteDeploymentError>> {
+        self.runtime.block_on(self.client.delete_deployment(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::delete_domain_name`].
+    pub fn delete_domain_name(
+        &self,
+        input: DeleteDomainNameRequest,
+    ) -> Result<(), RusotoError<DeleteDomainNameError>> {
+        self.runtime.block_on(self.client.delete_domain_name(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::delete_integration`].
+    pub fn delete_integration(
+        &self,
+        input: DeleteIntegrationRequest,
+    ) -> Result<(), RusotoError<DeleteIntegrationError>> {
+        self.runtime.block_on(self.client.delete_integration(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::delete_integration_response`].
+    pub fn delete_integration_response(
+        &self,
+        input: DeleteIntegrationResponseRequest,
+    ) -> Result<(), RusotoError<DeleteIntegrationResponseError>> {
+        self.runtime
+            .block_on(self.client.delete_integration_response(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::delete_model`].
+    pub fn delete_model(
+        &self,
+        input: DeleteModelRequest,
+    ) -> Result<(), RusotoError<DeleteModelError>> {
+        self.runtime.block_on(self.client.delete_model(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::delete_route`].
+    pub fn delete_route(
+        &self,
+        input: DeleteRouteRequest,
+    ) -> Result<(), RusotoError<DeleteRouteError>> {
+        self.runtime.block_on(self.client.delete_route(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::delete_route_request_parameter`].
+    pub fn delete_route_request_parameter(
+        &self,
+        input: DeleteRouteRequestParameterRequest,
+    ) -> Result<(), RusotoError<DeleteRouteRequestParameterError>> {
+        self.runtime
+            .block_on(self.client.delete_route_request_parameter(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::delete_route_response`].
+    pub fn delete_route_response(
+        &self,
+        input: DeleteRouteResponseRequest,
+    ) -> Result<(), RusotoError<DeleteRouteResponseError>> {
+        self.runtime
+            .block_on(self.client.delete_route_response(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::delete_route_settings`].
+    pub fn delete_route_settings(
+        &self,
+        input: DeleteRouteSettingsRequest,
+    ) -> Result<(), RusotoError<DeleteRouteSettingsError>> {
+        self.runtime
+            .block_on(self.client.delete_route_settings(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::delete_stage`].
+    pub fn delete_stage(
+        &self,
+        input: DeleteStageRequest,
+    ) -> Result<(), RusotoError<DeleteStageError>> {
+        self.runtime.block_on(self.client.delete_stage(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::delete_vpc_link`].
+    pub fn delete_vpc_link(
+        &self,
+        input: DeleteVpcLinkRequest,
+    ) -> Result<DeleteVpcLinkResponse, RusotoError<DeleteVpcLinkError>> {
+        self.runtime.block_on(self.client.delete_vpc_link(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::export_api`].
+    pub fn export_api(
+        &self,
+        input: ExportApiRequest,
+    ) -> Result<ExportApiResponse, RusotoError<ExportApiError>> {
+        self.runtime.block_on(self.client.export_api(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::get_api`].
+    pub fn get_api(
+        &self,
+        input: GetApiRequest,
+    ) -> Result<GetApiResponse, RusotoError<GetApiError>> {
+        self.runtime.block_on(self.client.get_api(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::get_api_mapping`].
+    pub fn get_api_mapping(
+        &self,
+        input: GetApiMappingRequest,
+    ) -> Result<GetApiMappingResponse, RusotoError<GetApiMappingError>> {
+        self.runtime.block_on(self.client.get_api_mapping(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::get_api_mappings`].
+    pub fn get_api_mappings(
+        &self,
+        input: GetApiMappingsRequest,
+    ) -> Result<GetApiMappingsResponse, RusotoError<GetApiMappingsError>> {
+        self.runtime.block_on(self.client.get_api_mappings(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::get_apis`].
+    pub fn get_apis(
+        &self,
+        input: GetApisRequest,
+    ) -> Result<GetApisResponse, RusotoError<GetApisError>> {
+        self.runtime.block_on(self.client.get_apis(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::get_authorizer`].
+    pub fn get_authorizer(
+        &self,
+        input: GetAuthorizerRequest,
+    ) -> Result<GetAuthorizerResponse, RusotoError<GetAuthorizerError>> {
+        self.runtime.block_on(self.client.get_authorizer(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::get_authorizers`].
+    pub fn get_authorizers(
+        &self,
+        input: GetAuthorizersRequest,
+    ) -> Result<GetAuthorizersResponse, RusotoError<GetAuthorizersError>> {
+        self.runtime.block_on(self.client.get_authorizers(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::get_deployment`].
+    pub fn get_deployment(
+        &self,
+        input: GetDeploymentRequest,
+    ) -> Result<GetDeploymentResponse, RusotoError<GetDeploymentError>> {
+        self.runtime.block_on(self.client.get_deployment(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::get_deployments`].
+    pub fn get_deployments(
+        &self,
+        input: GetDeploymentsRequest,
+    ) -> Result<GetDeploymentsResponse, RusotoError<GetDeploymentsError>> {
+        self.runtime.block_on(self.client.get_deployments(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::get_domain_name`].
+    pub fn get_domain_name(
+        &self,
+        input: GetDomainNameRequest,
+    ) -> Result<GetDomainNameResponse, RusotoError<GetDomainNameError>> {
+        self.runtime.block_on(self.client.get_domain_name(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::get_domain_names`].
+    pub fn get_domain_names(
+        &self,
+        input: GetDomainNamesRequest,
+    ) -> Result<GetDomainNamesResponse, RusotoError<GetDomainNamesError>> {
+        self.runtime.block_on(self.client.get_domain_names(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::get_integration`].
+    pub fn get_integration(
+        &self,
+        input: GetIntegrationRequest,
+    ) -> Result<GetIntegrationResult, RusotoError<GetIntegrationError>> {
+        self.runtime.block_on(self.client.get_integration(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::get_integration_response`].
+    pub fn get_integration_response(
+        &self,
+        input: GetIntegrationResponseRequest,
+    ) -> Result<GetIntegrationResponseResponse, RusotoError<GetIntegrationResponseError>> {
+        self.runtime
+            .block_on(self.client.get_integration_response(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::get_integration_responses`].
+    pub fn get_integration_responses(
+        &self,
+        input: GetIntegrationResponsesRequest,
+    ) -> Result<GetIntegrationResponsesResponse, RusotoError<GetIntegrationResponsesError>> {
+        self.runtime
+            .block_on(self.client.get_integration_responses(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::get_integrations`].
+    pub fn get_integrations(
+        &self,
+        input: GetIntegrationsRequest,
+    ) -> Result<GetIntegrationsResponse, RusotoError<GetIntegrationsError>> {
+        self.runtime.block_on(self.client.get_integrations(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::get_model`].
+    pub fn get_model(
+        &self,
+        input: GetModelRequest,
+    ) -> Result<GetModelResponse, RusotoError<GetModelError>> {
+        self.runtime.block_on(self.client.get_model(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::get_model_template`].
+    pub fn get_model_template(
+        &self,
+        input: GetModelTemplateRequest,
+    ) -> Result<GetModelTemplateResponse, RusotoError<GetModelTemplateError>> {
+        self.runtime.block_on(self.client.get_model_template(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::get_models`].
+    pub fn get_models(
+        &self,
+        input: GetModelsRequest,
+    ) -> Result<GetModelsResponse, RusotoError<GetModelsError>> {
+        self.runtime.block_on(self.client.get_models(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::get_route`].
+    pub fn get_route(
+        &self,
+        input: GetRouteRequest,
+    ) -> Result<GetRouteResult, RusotoError<GetRouteError>> {
+        self.runtime.block_on(self.client.get_route(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::get_route_response`].
+    pub fn get_route_response(
+        &self,
+        input: GetRouteResponseRequest,
+    ) -> Result<GetRouteResponseResponse, RusotoError<GetRouteResponseError>> {
+        self.runtime.block_on(self.client.get_route_response(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::get_route_responses`].
+    pub fn get_route_responses(
+        &self,
+        input: GetRouteResponsesRequest,
+    ) -> Result<GetRouteResponsesResponse, RusotoError<GetRouteResponsesError>> {
+        self.runtime
+            .block_on(self.client.get_route_responses(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::get_routes`].
+    pub fn get_routes(
+        &self,
+        input: GetRoutesRequest,
+    ) -> Result<GetRoutesResponse, RusotoError<GetRoutesError>> {
+        self.runtime.block_on(self.client.get_routes(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::get_stage`].
+    pub fn get_stage(
+        &self,
+        input: GetStageRequest,
+    ) -> Result<GetStageResponse, RusotoError<GetStageError>> {
+        self.runtime.block_on(self.client.get_stage(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::get_stages`].
+    pub fn get_stages(
+        &self,
+        input: GetStagesRequest,
+    ) -> Result<GetStagesResponse, RusotoError<GetStagesError>> {
+        self.runtime.block_on(self.client.get_stages(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::get_tags`].
+    pub fn get_tags(
+        &self,
+        input: GetTagsRequest,
+    ) -> Result<GetTagsResponse, RusotoError<GetTagsError>> {
+        self.runtime.block_on(self.client.get_tags(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::get_vpc_link`].
+    pub fn get_vpc_link(
+        &self,
+        input: GetVpcLinkRequest,
+    ) -> Result<GetVpcLinkResponse, RusotoError<GetVpcLinkError>> {
+        self.runtime.block_on(self.client.get_vpc_link(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::get_vpc_links`].
+    pub fn get_vpc_links(
+        &self,
+        input: GetVpcLinksRequest,
+    ) -> Result<GetVpcLinksResponse, RusotoError<GetVpcLinksError>> {
+        self.runtime.block_on(self.client.get_vpc_links(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::import_api`].
+    pub fn import_api(
+        &self,
+        input: ImportApiRequest,
+    ) -> Result<ImportApiResponse, RusotoError<ImportApiError>> {
+        self.runtime.block_on(self.client.import_api(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::reimport_api`].
+    pub fn reimport_api(
+        &self,
+        input: ReimportApiRequest,
+    ) -> Result<ReimportApiResponse, RusotoError<ReimportApiError>> {
+        self.runtime.block_on(self.client.reimport_api(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::reset_authorizers_cache`].
+    pub fn reset_authorizers_cache(
+        &self,
+        input: ResetAuthorizersCacheRequest,
+    ) -> Result<(), RusotoError<ResetAuthorizersCacheError>> {
+        self.runtime
+            .block_on(self.client.reset_authorizers_cache(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::update_api`].
+    pub fn update_api(
+        &self,
+        input: UpdateApiRequest,
+    ) -> Result<UpdateApiResponse, RusotoError<UpdateApiError>> {
+        self.runtime.block_on(self.client.update_api(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::update_api_mapping`].
+    pub fn update_api_mapping(
+        &self,
+        input: UpdateApiMappingRequest,
+    ) -> Result<UpdateApiMappingResponse, RusotoError<UpdateApiMappingError>> {
+        self.runtime.block_on(self.client.update_api_mapping(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::update_authorizer`].
+    pub fn update_authorizer(
+        &self,
+        input: UpdateAuthorizerRequest,
+    ) -> Result<UpdateAuthorizerResponse, RusotoError<UpdateAuthorizerError>> {
+        self.runtime.block_on(self.client.update_authorizer(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::update_deployment`].
+    pub fn update_deployment(
+        &self,
+        input: UpdateDeploymentRequest,
+    ) -> Result<UpdateDeploymentResponse, RusotoError<UpdateDeploymentError>> {
+        self.runtime.block_on(self.client.update_deployment(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::update_domain_name`].
+    pub fn update_domain_name(
+        &self,
+        input: UpdateDomainNameRequest,
+    ) -> Result<UpdateDomainNameResponse, RusotoError<UpdateDomainNameError>> {
+        self.runtime.block_on(self.client.update_domain_name(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::update_integration`].
+    pub fn update_integration(
+        &self,
+        input: UpdateIntegrationRequest,
+    ) -> Result<UpdateIntegrationResult, RusotoError<UpdateIntegrationError>> {
+        self.runtime.block_on(self.client.update_integration(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::update_integration_response`].
+    pub fn update_integration_response(
+        &self,
+        input: UpdateIntegrationResponseRequest,
+    ) -> Result<UpdateIntegrationResponseResponse, RusotoError<UpdateIntegrationResponseError>>
+    {
+        self.runtime
+            .block_on(self.client.update_integration_response(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::update_model`].
+    pub fn update_model(
+        &self,
+        input: UpdateModelRequest,
+    ) -> Result<UpdateModelResponse, RusotoError<UpdateModelError>> {
+        self.runtime.block_on(self.client.update_model(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::update_route`].
+    pub fn update_route(
+        &self,
+        input: UpdateRouteRequest,
+    ) -> Result<UpdateRouteResult, RusotoError<UpdateRouteError>> {
+        self.runtime.block_on(self.client.update_route(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::update_route_response`].
+    pub fn update_route_response(
+        &self,
+        input: UpdateRouteResponseRequest,
+    ) -> Result<UpdateRouteResponseResponse, RusotoError<UpdateRouteResponseError>> {
+        self.runtime
+            .block_on(self.client.update_route_response(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::update_stage`].
+    pub fn update_stage(
+        &self,
+        input: UpdateStageRequest,
+    ) -> Result<UpdateStageResponse, RusotoError<UpdateStageError>> {
+        self.runtime.block_on(self.client.update_stage(input))
+    }
+
+    /// Blocking version of [`ApiGatewayV2::update_vpc_link`].
+    pub fn update_vpc_link(
+        &self,
+        input: UpdateVpcLinkRequest,
+    ) -> Result<UpdateVpcLinkResponse, RusotoError<UpdateVpcLinkError>> {
+        self.runtime.block_on(self.client.update_vpc_link(input))
+    }
+}
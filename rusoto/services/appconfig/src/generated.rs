@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1040,12 +1041,22 @@ impl CreateApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateApplicationError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApplicationError::BadRequest,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateApplicationError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApplicationError::InternalServer,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1080,21 +1091,30 @@ impl CreateConfigurationProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateConfigurationProfileError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateConfigurationProfileError::BadRequest,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateConfigurationProfileError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateConfigurationProfileError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateConfigurationProfileError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateConfigurationProfileError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1126,14 +1146,22 @@ impl CreateDeploymentStrategyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDeploymentStrategyError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentStrategyError::BadRequest,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateDeploymentStrategyError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDeploymentStrategyError::InternalServer,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1166,15 +1194,30 @@ impl CreateEnvironmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateEnvironmentError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEnvironmentError::BadRequest,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateEnvironmentError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEnvironmentError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateEnvironmentError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEnvironmentError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1216,36 +1259,54 @@ impl CreateHostedConfigurationVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateHostedConfigurationVersionError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateHostedConfigurationVersionError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateHostedConfigurationVersionError::Conflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateHostedConfigurationVersionError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
-                        CreateHostedConfigurationVersionError::InternalServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateHostedConfigurationVersionError::InternalServer,
                     )
                 }
                 "PayloadTooLargeException" => {
-                    return RusotoError::Service(
-                        CreateHostedConfigurationVersionError::PayloadTooLarge(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateHostedConfigurationVersionError::PayloadTooLarge,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        CreateHostedConfigurationVersionError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateHostedConfigurationVersionError::ResourceNotFound,
                     )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(
-                        CreateHostedConfigurationVersionError::ServiceQuotaExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateHostedConfigurationVersionError::ServiceQuotaExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1290,15 +1351,30 @@ impl DeleteApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteApplicationError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationError::BadRequest,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteApplicationError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1336,24 +1412,38 @@ impl DeleteConfigurationProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteConfigurationProfileError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteConfigurationProfileError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteConfigurationProfileError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteConfigurationProfileError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteConfigurationProfileError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteConfigurationProfileError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteConfigurationProfileError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteConfigurationProfileError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1388,19 +1478,30 @@ impl DeleteDeploymentStrategyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteDeploymentStrategyError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDeploymentStrategyError::BadRequest,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteDeploymentStrategyError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDeploymentStrategyError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDeploymentStrategyError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDeploymentStrategyError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1436,18 +1537,38 @@ impl DeleteEnvironmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteEnvironmentError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEnvironmentError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteEnvironmentError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEnvironmentError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteEnvironmentError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEnvironmentError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteEnvironmentError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEnvironmentError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1484,21 +1605,30 @@ impl DeleteHostedConfigurationVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteHostedConfigurationVersionError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteHostedConfigurationVersionError::BadRequest,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
-                        DeleteHostedConfigurationVersionError::InternalServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteHostedConfigurationVersionError::InternalServer,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteHostedConfigurationVersionError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteHostedConfigurationVersionError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1536,15 +1666,30 @@ impl GetApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetApplicationError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetApplicationError::BadRequest,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetApplicationError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetApplicationError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetApplicationError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1578,15 +1723,30 @@ impl GetConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetConfigurationError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConfigurationError::BadRequest,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetConfigurationError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConfigurationError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetConfigurationError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConfigurationError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1620,19 +1780,30 @@ impl GetConfigurationProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetConfigurationProfileError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConfigurationProfileError::BadRequest,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetConfigurationProfileError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetConfigurationProfileError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetConfigurationProfileError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetConfigurationProfileError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1666,15 +1837,30 @@ impl GetDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDeploymentError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeploymentError::BadRequest,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetDeploymentError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeploymentError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDeploymentError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeploymentError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1708,19 +1894,30 @@ impl GetDeploymentStrategyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDeploymentStrategyError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeploymentStrategyError::BadRequest,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetDeploymentStrategyError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDeploymentStrategyError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDeploymentStrategyError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDeploymentStrategyError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1754,15 +1951,30 @@ impl GetEnvironmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetEnvironmentError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEnvironmentError::BadRequest,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetEnvironmentError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEnvironmentError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetEnvironmentError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEnvironmentError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1798,21 +2010,30 @@ impl GetHostedConfigurationVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetHostedConfigurationVersionError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetHostedConfigurationVersionError::BadRequest,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
-                        GetHostedConfigurationVersionError::InternalServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetHostedConfigurationVersionError::InternalServer,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        GetHostedConfigurationVersionError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetHostedConfigurationVersionError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1846,12 +2067,22 @@ impl ListApplicationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListApplicationsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListApplicationsError::BadRequest,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListApplicationsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListApplicationsError::InternalServer,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1884,21 +2115,30 @@ impl ListConfigurationProfilesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListConfigurationProfilesError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListConfigurationProfilesError::BadRequest,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListConfigurationProfilesError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListConfigurationProfilesError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListConfigurationProfilesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListConfigurationProfilesError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1930,14 +2170,22 @@ impl ListDeploymentStrategiesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListDeploymentStrategiesError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDeploymentStrategiesError::BadRequest,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListDeploymentStrategiesError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDeploymentStrategiesError::InternalServer,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1970,15 +2218,30 @@ impl ListDeploymentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListDeploymentsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDeploymentsError::BadRequest,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListDeploymentsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDeploymentsError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDeploymentsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDeploymentsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2012,15 +2275,30 @@ impl ListEnvironmentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListEnvironmentsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListEnvironmentsError::BadRequest,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListEnvironmentsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListEnvironmentsError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListEnvironmentsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListEnvironmentsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2056,21 +2334,30 @@ impl ListHostedConfigurationVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListHostedConfigurationVersionsError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListHostedConfigurationVersionsError::BadRequest,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
-                        ListHostedConfigurationVersionsError::InternalServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListHostedConfigurationVersionsError::InternalServer,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        ListHostedConfigurationVersionsError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListHostedConfigurationVersionsError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2108,17 +2395,30 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::BadRequest,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2154,18 +2454,38 @@ impl StartDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StartDeploymentError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDeploymentError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(StartDeploymentError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDeploymentError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(StartDeploymentError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDeploymentError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartDeploymentError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDeploymentError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2200,15 +2520,30 @@ impl StopDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StopDeploymentError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopDeploymentError::BadRequest,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(StopDeploymentError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopDeploymentError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopDeploymentError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopDeploymentError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2242,15 +2577,30 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::BadRequest,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(TagResourceError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2284,15 +2634,30 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::BadRequest,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2326,15 +2691,30 @@ impl UpdateApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateApplicationError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApplicationError::BadRequest,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateApplicationError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApplicationError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApplicationError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2370,21 +2750,30 @@ impl UpdateConfigurationProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateConfigurationProfileError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateConfigurationProfileError::BadRequest,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateConfigurationProfileError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateConfigurationProfileError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateConfigurationProfileError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateConfigurationProfileError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2418,19 +2807,30 @@ impl UpdateDeploymentStrategyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateDeploymentStrategyError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeploymentStrategyError::BadRequest,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateDeploymentStrategyError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDeploymentStrategyError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDeploymentStrategyError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDeploymentStrategyError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2464,15 +2864,30 @@ impl UpdateEnvironmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateEnvironmentError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateEnvironmentError::BadRequest,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateEnvironmentError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateEnvironmentError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateEnvironmentError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateEnvironmentError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2506,19 +2921,30 @@ impl ValidateConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ValidateConfigurationError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ValidateConfigurationError::BadRequest,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ValidateConfigurationError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ValidateConfigurationError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ValidateConfigurationError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ValidateConfigurationError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2747,11 +3173,12 @@ pub struct AppConfigClient {
 impl AppConfigClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> AppConfigClient {
         AppConfigClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "AppConfig"),
         }
     }
 
@@ -2766,12 +3193,32 @@ impl AppConfigClient {
     {
         AppConfigClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "AppConfig"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> AppConfigClient {
-        AppConfigClient { client, region }
+        AppConfigClient {
+            client,
+            region: region_for_service(region, "AppConfig"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> AppConfigClient {
+        AppConfigClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("AppConfig"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> AppConfigClient {
+        AppConfigClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -2786,6 +3233,7 @@ impl AppConfig for AppConfigClient {
         let request_uri = "/applications";
 
         let mut request = SignedRequest::new("POST", "appconfig", &self.region, &request_uri);
+        request.set_operation("CreateApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2820,6 +3268,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("POST", "appconfig", &self.region, &request_uri);
+        request.set_operation("CreateConfigurationProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2851,6 +3300,7 @@ impl AppConfig for AppConfigClient {
         let request_uri = "/deploymentstrategies";
 
         let mut request = SignedRequest::new("POST", "appconfig", &self.region, &request_uri);
+        request.set_operation("CreateDeploymentStrategy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2885,6 +3335,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("POST", "appconfig", &self.region, &request_uri);
+        request.set_operation("CreateEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2917,6 +3368,8 @@ impl AppConfig for AppConfigClient {
         let request_uri = format!("/applications/{application_id}/configurationprofiles/{configuration_profile_id}/hostedconfigurationversions", application_id = input.application_id, configuration_profile_id = input.configuration_profile_id);
 
         let mut request = SignedRequest::new("POST", "appconfig", &self.region, &request_uri);
+        request.set_operation("CreateHostedConfigurationVersion");
+        request.set_raw_response(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.content.to_owned());
@@ -2969,6 +3422,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appconfig", &self.region, &request_uri);
+        request.set_operation("DeleteApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3000,6 +3454,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appconfig", &self.region, &request_uri);
+        request.set_operation("DeleteConfigurationProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3030,6 +3485,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appconfig", &self.region, &request_uri);
+        request.set_operation("DeleteDeploymentStrategy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3061,6 +3517,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appconfig", &self.region, &request_uri);
+        request.set_operation("DeleteEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3088,6 +3545,7 @@ impl AppConfig for AppConfigClient {
         let request_uri = format!("/applications/{application_id}/configurationprofiles/{configuration_profile_id}/hostedconfigurationversions/{version_number}", application_id = input.application_id, configuration_profile_id = input.configuration_profile_id, version_number = input.version_number);
 
         let mut request = SignedRequest::new("DELETE", "appconfig", &self.region, &request_uri);
+        request.set_operation("DeleteHostedConfigurationVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3120,6 +3578,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("GetApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3153,6 +3612,8 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("GetConfiguration");
+        request.set_raw_response(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3196,6 +3657,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("GetConfigurationProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3224,6 +3686,7 @@ impl AppConfig for AppConfigClient {
         let request_uri = format!("/applications/{application_id}/environments/{environment_id}/deployments/{deployment_number}", application_id = input.application_id, deployment_number = input.deployment_number, environment_id = input.environment_id);
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("GetDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3255,6 +3718,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("GetDeploymentStrategy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3287,6 +3751,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("GetEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3315,6 +3780,8 @@ impl AppConfig for AppConfigClient {
         let request_uri = format!("/applications/{application_id}/configurationprofiles/{configuration_profile_id}/hostedconfigurationversions/{version_number}", application_id = input.application_id, configuration_profile_id = input.configuration_profile_id, version_number = input.version_number);
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("GetHostedConfigurationVersion");
+        request.set_raw_response(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3353,6 +3820,7 @@ impl AppConfig for AppConfigClient {
         let request_uri = "/applications";
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("ListApplications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3393,6 +3861,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("ListConfigurationProfiles");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3430,6 +3899,7 @@ impl AppConfig for AppConfigClient {
         let request_uri = "/deploymentstrategies";
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("ListDeploymentStrategies");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3471,6 +3941,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("ListDeployments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3511,6 +3982,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("ListEnvironments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3549,6 +4021,7 @@ impl AppConfig for AppConfigClient {
         let request_uri = format!("/applications/{application_id}/configurationprofiles/{configuration_profile_id}/hostedconfigurationversions", application_id = input.application_id, configuration_profile_id = input.configuration_profile_id);
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("ListHostedConfigurationVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3588,6 +4061,7 @@ impl AppConfig for AppConfigClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "appconfig", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3620,6 +4094,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("POST", "appconfig", &self.region, &request_uri);
+        request.set_operation("StartDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3651,6 +4126,7 @@ impl AppConfig for AppConfigClient {
         let request_uri = format!("/applications/{application_id}/environments/{environment_id}/deployments/{deployment_number}", application_id = input.application_id, deployment_number = input.deployment_number, environment_id = input.environment_id);
 
         let mut request = SignedRequest::new("DELETE", "appconfig", &self.region, &request_uri);
+        request.set_operation("StopDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3679,6 +4155,7 @@ impl AppConfig for AppConfigClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "appconfig", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3709,6 +4186,7 @@ impl AppConfig for AppConfigClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "appconfig", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3745,6 +4223,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "appconfig", &self.region, &request_uri);
+        request.set_operation("UpdateApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3780,6 +4259,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "appconfig", &self.region, &request_uri);
+        request.set_operation("UpdateConfigurationProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3814,6 +4294,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "appconfig", &self.region, &request_uri);
+        request.set_operation("UpdateDeploymentStrategy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3849,6 +4330,7 @@ impl AppConfig for AppConfigClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "appconfig", &self.region, &request_uri);
+        request.set_operation("UpdateEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3880,6 +4362,7 @@ impl AppConfig for AppConfigClient {
         let request_uri = format!("/applications/{application_id}/configurationprofiles/{configuration_profile_id}/validators", application_id = input.application_id, configuration_profile_id = input.configuration_profile_id);
 
         let mut request = SignedRequest::new("POST", "appconfig", &self.region, &request_uri);
+        request.set_operation("ValidateConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3902,3 +4385,360 @@ impl AppConfig for AppConfigClient {
         }
     }
 }
+
+/// A blocking client for the AppConfig API, running the calls of a `AppConfigClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `AppConfigClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct AppConfigBlockingClient {
+    client: AppConfigClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl AppConfigBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> AppConfigBlockingClient {
+        AppConfigBlockingClient::from_client(AppConfigClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> AppConfigBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        AppConfigBlockingClient::from_client(AppConfigClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> AppConfigBlockingClient {
+        AppConfigBlockingClient::from_client(AppConfigClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> AppConfigBlockingClient {
+        AppConfigBlockingClient::from_client(AppConfigClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: AppConfigClient) -> AppConfigBlockingClient {
+        AppConfigBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> AppConfigBlockingClient {
+        AppConfigBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> AppConfigBlockingClient {
+        AppConfigBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`AppConfig::create_application`].
+    pub fn create_application(
+        &self,
+        input: CreateApplicationRequest,
+    ) -> Result<Application, RusotoError<CreateApplicationError>> {
+        self.runtime.block_on(self.client.create_application(input))
+    }
+
+    /// Blocking version of [`AppConfig::create_configuration_profile`].
+    pub fn create_configuration_profile(
+        &self,
+        input: CreateConfigurationProfileRequest,
+    ) -> Result<ConfigurationProfile, RusotoError<CreateConfigurationProfileError>> {
+        self.runtime
+            .block_on(self.client.create_configuration_profile(input))
+    }
+
+    /// Blocking version of [`AppConfig::create_deployment_strategy`].
+    pub fn create_deployment_strategy(
+        &self,
+        input: CreateDeploymentStrategyRequest,
+    ) -> Result<DeploymentStrategy, RusotoError<CreateDeploymentStrategyError>> {
+        self.runtime
+            .block_on(self.client.create_deployment_strategy(input))
+    }
+
+    /// Blocking version of [`AppConfig::create_environment`].
+    pub fn create_environment(
+        &self,
+        input: CreateEnvironmentRequest,
+    ) -> Result<Environment, RusotoError<CreateEnvironmentError>> {
+        self.runtime.block_on(self.client.create_environment(input))
+    }
+
+    /// Blocking version of [`AppConfig::create_hosted_configuration_version`].
+    pub fn create_hosted_configuration_version(
+        &self,
+        input: CreateHostedConfigurationVersionRequest,
+    ) -> Result<HostedConfigurationVersion, RusotoError<CreateHostedConfigurationVersionError>>
+    {
+        self.runtime
+            .block_on(self.client.create_hosted_configuration_version(input))
+    }
+
+    /// Blocking version of [`AppConfig::delete_application`].
+    pub fn delete_application(
+        &self,
+        input: DeleteApplicationRequest,
+    ) -> Result<(), RusotoError<DeleteApplicationError>> {
+        self.runtime.block_on(self.client.delete_application(input))
+    }
+
+    /// Blocking version of [`AppConfig::delete_configuration_profile`].
+    pub fn delete_configuration_profile(
+        &self,
+        input: DeleteConfigurationProfileRequest,
+    ) -> Result<(), RusotoError<DeleteConfigurationProfileError>> {
+        self.runtime
+            .block_on(self.client.delete_configuration_profile(input))
+    }
+
+    /// Blocking version of [`AppConfig::delete_deployment_strategy`].
+    pub fn delete_deployment_strategy(
+        &self,
+        input: DeleteDeploymentStrategyRequest,
+    ) -> Result<(), RusotoError<DeleteDeploymentStrategyError>> {
+        self.runtime
+            .block_on(self.client.delete_deployment_strategy(input))
+    }
+
+    /// Blocking version of [`AppConfig::delete_environment`].
+    pub fn delete_environment(
+        &self,
+        input: DeleteEnvironmentRequest,
+    ) -> Result<(), RusotoError<DeleteEnvironmentError>> {
+        self.runtime.block_on(self.client.delete_environment(input))
+    }
+
+    /// Blocking version of [`AppConfig::delete_hosted_configuration_version`].
+    pub fn delete_hosted_configuration_version(
+        &self,
+        input: DeleteHostedConfigurationVersionRequest,
+    ) -> Result<(), RusotoError<DeleteHostedConfigurationVersionError>> {
+        self.runtime
+            .block_on(self.client.delete_hosted_configuration_version(input))
+    }
+
+    /// Blocking version of [`AppConfig::get_application`].
+    pub fn get_application(
+        &self,
+        input: GetApplicationRequest,
+    ) -> Result<Application, RusotoError<GetApplicationError>> {
+        self.runtime.block_on(self.client.get_application(input))
+    }
+
+    /// Blocking version of [`AppConfig::get_configuration`].
+    pub fn get_configuration(
+        &self,
+        input: GetConfigurationRequest,
+    ) -> Result<Configuration, RusotoError<GetConfigurationError>> {
+        self.runtime.block_on(self.client.get_configuration(input))
+    }
+
+    /// Blocking version of [`AppConfig::get_configuration_profile`].
+    pub fn get_configuration_profile(
+        &self,
+        input: GetConfigurationProfileRequest,
+    ) -> Result<ConfigurationProfile, RusotoError<GetConfigurationProfileError>> {
+        self.runtime
+            .block_on(self.client.get_configuration_profile(input))
+    }
+
+    /// Blocking version of [`AppConfig::get_deployment`].
+    pub fn get_deployment(
+        &self,
+        input: GetDeploymentRequest,
+    ) -> Result<Deployment, RusotoError<GetDeploymentError>> {
+        self.runtime.block_on(self.client.get_deployment(input))
+    }
+
+    /// Blocking version of [`AppConfig::get_deployment_strategy`].
+    pub fn get_deployment_strategy(
+        &self,
+        input: GetDeploymentStrategyRequest,
+    ) -> Result<DeploymentStrategy, RusotoError<GetDeploymentStrategyError>> {
+        self.runtime
+            .block_on(self.client.get_deployment_strategy(input))
+    }
+
+    /// Blocking version of [`AppConfig::get_environment`].
+    pub fn get_environment(
+        &self,
+        input: GetEnvironmentRequest,
+    ) -> Result<Environment, RusotoError<GetEnvironmentError>> {
+        self.runtime.block_on(self.client.get_environment(input))
+    }
+
+    /// Blocking version of [`AppConfig::get_hosted_configuration_version`].
+    pub fn get_hosted_configuration_version(
+        &self,
+        input: GetHostedConfigurationVersionRequest,
+    ) -> Result<HostedConfigurationVersion, RusotoError<GetHostedConfigurationVersionError>> {
+        self.runtime
+            .block_on(self.client.get_hosted_configuration_version(input))
+    }
+
+    /// Blocking version of [`AppConfig::list_applications`].
+    pub fn list_applications(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> Result<Applications, RusotoError<ListApplicationsError>> {
+        self.runtime.block_on(self.client.list_applications(input))
+    }
+
+    /// Blocking version of [`AppConfig::list_configuration_profiles`].
+    pub fn list_configuration_profiles(
+        &self,
+        input: ListConfigurationProfilesRequest,
+    ) -> Result<ConfigurationProfiles, RusotoError<ListConfigurationProfilesError>> {
+        self.runtime
+            .block_on(self.client.list_configuration_profiles(input))
+    }
+
+    /// Blocking version of [`AppConfig::list_deployment_strategies`].
+    pub fn list_deployment_strategies(
+        &self,
+        input: ListDeploymentStrategiesRequest,
+    ) -> Result<DeploymentStrategies, RusotoError<ListDeploymentStrategiesError>> {
+        self.runtime
+            .block_on(self.client.list_deployment_strategies(input))
+    }
+
+    /// Blocking version of [`AppConfig::list_deployments`].
+    pub fn list_deployments(
+        &self,
+        input: ListDeploymentsRequest,
+    ) -> Result<Deployments, RusotoError<ListDeploymentsError>> {
+        self.runtime.block_on(self.client.list_deployments(input))
+    }
+
+    /// Blocking version of [`AppConfig::list_environments`].
+    pub fn list_environments(
+        &self,
+        input: ListEnvironmentsRequest,
+    ) -> Result<Environments, RusotoError<ListEnvironmentsError>> {
+        self.runtime.block_on(self.client.list_environments(input))
+    }
+
+    /// Blocking version of [`AppConfig::list_hosted_configuration_versions`].
+    pub fn list_hosted_configuration_versions(
+        &self,
+        input: ListHostedConfigurationVersionsRequest,
+    ) -> Result<HostedConfigurationVersions, RusotoError<ListHostedConfigurationVersionsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_hosted_configuration_versions(input))
+    }
+
+    /// Blocking version of [`AppConfig::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ResourceTags, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`AppConfig::start_deployment`].
+    pub fn start_deployment(
+        &self,
+        input: StartDeploymentRequest,
+    ) -> Result<Deployment, RusotoError<StartDeploymentError>> {
+        self.runtime.block_on(self.client.start_deployment(input))
+    }
+
+    /// Blocking version of [`AppConfig::stop_deployment`].
+    pub fn stop_deployment(
+        &self,
+        input: StopDeploymentRequest,
+    ) -> Result<Deployment, RusotoError<StopDeploymentError>> {
+        self.runtime.block_on(self.client.stop_deployment(input))
+    }
+
+    /// Blocking version of [`AppConfig::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`AppConfig::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`AppConfig::update_application`].
+    pub fn update_application(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> Result<Application, RusotoError<UpdateApplicationError>> {
+        self.runtime.block_on(self.client.update_application(input))
+    }
+
+    /// Blocking version of [`AppConfig::update_configuration_profile`].
+    pub fn update_configuration_profile(
+        &self,
+        input: UpdateConfigurationProfileRequest,
+    ) -> Result<ConfigurationProfile, RusotoError<UpdateConfigurationProfileError>> {
+        self.runtime
+            .block_on(self.client.update_configuration_profile(input))
+    }
+
+    /// Blocking version of [`AppConfig::update_deployment_strategy`].
+    pub fn update_deployment_strategy(
+        &self,
+        input: UpdateDeploymentStrategyRequest,
+    ) -> Result<DeploymentStrategy, RusotoError<UpdateDeploymentStrategyError>> {
+        self.runtime
+            .block_on(self.client.update_deployment_strategy(input))
+    }
+
+    /// Blocking version of [`AppConfig::update_environment`].
+    pub fn update_environment(
+        &self,
+        input: UpdateEnvironmentRequest,
+    ) -> Result<Environment, RusotoError<UpdateEnvironmentError>> {
+        self.runtime.block_on(self.client.update_environment(input))
+    }
+
+    /// Blocking version of [`AppConfig::validate_configuration`].
+    pub fn validate_configuration(
+        &self,
+        input: ValidateConfigurationRequest,
+    ) -> Result<(), RusotoError<ValidateConfigurationError>> {
+        self.runtime
+            .block_on(self.client.validate_configuration(input))
+    }
+}
@@ -3,7 +3,7 @@ extern crate rusoto_mock;
 use crate::generated::*;
 
 use self::rusoto_mock::*;
-use rusoto_core::Region;
+use rusoto_core::{Region, RusotoError};
 
 #[tokio::test]
 // regression test for #1002
@@ -30,3 +30,58 @@ async fn register_scalable_target_returs_empty_body() {
 
     result.expect("Couldn't parse register_scalable_target");
 }
+
+#[tokio::test]
+async fn should_parse_error_metadata() {
+    let body = r#"{"__type":"ConcurrentUpdateException","message":"Update in progress"}"#;
+    let mock = MockRequestDispatcher::with_status(400)
+        .with_body(body)
+        .with_header("x-amzn-RequestId", "0b7a8f0e-1a52-4bd2-8f2c-4e4a3b3cb6d1");
+
+    let client =
+        ApplicationAutoScalingClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    let err = client
+        .register_scalable_target(Default::default())
+        .await
+        .unwrap_err();
+
+    match err {
+        RusotoError::Service(RegisterScalableTargetError::ConcurrentUpdate(ref message), _) => {
+            assert_eq!(message, "Update in progress")
+        }
+        _ => panic!("Expected a service error, got {:?}", err),
+    }
+    let metadata = err.metadata().unwrap();
+    assert_eq!(metadata.code.as_deref(), Some("ConcurrentUpdateException"));
+    assert_eq!(
+        metadata.request_id.as_deref(),
+        Some("0b7a8f0e-1a52-4bd2-8f2c-4e4a3b3cb6d1")
+    );
+}
+
+#[tokio::test]
+async fn should_parse_validation_error_metadata() {
+    let body = r#"{"__type":"ValidationException","message":"Invalid resource id"}"#;
+    let mock = MockRequestDispatcher::with_status(400)
+        .with_body(body)
+        .with_header("x-amzn-RequestId", "5d9ab1c5-8b9f-4cdc-a0b1-0e6ab4d83b0e");
+
+    let client =
+        ApplicationAutoScalingClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    let err = client
+        .register_scalable_target(Default::default())
+        .await
+        .unwrap_err();
+
+    match err {
+        RusotoError::Validation(ref message, ref metadata) => {
+            assert_eq!(message, "Invalid resource id");
+            assert_eq!(metadata.code.as_deref(), Some("ValidationException"));
+            assert_eq!(
+                metadata.request_id.as_deref(),
+                Some("5d9ab1c5-8b9f-4cdc-a0b1-0e6ab4d83b0e")
+            );
+        }
+        _ => panic!("Expected a validation error, got {:?}", err),
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -708,17 +709,30 @@ impl DeleteScalingPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(DeleteScalingPolicyError::ConcurrentUpdate(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteScalingPolicyError::ConcurrentUpdate,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteScalingPolicyError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteScalingPolicyError::InternalService,
+                    )
                 }
                 "ObjectNotFoundException" => {
-                    return RusotoError::Service(DeleteScalingPolicyError::ObjectNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteScalingPolicyError::ObjectNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -752,21 +766,30 @@ impl DeleteScheduledActionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(DeleteScheduledActionError::ConcurrentUpdate(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteScheduledActionError::ConcurrentUpdate,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteScheduledActionError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteScheduledActionError::InternalService,
+                    )
                 }
                 "ObjectNotFoundException" => {
-                    return RusotoError::Service(DeleteScheduledActionError::ObjectNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteScheduledActionError::ObjectNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -800,21 +823,30 @@ impl DeregisterScalableTargetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(DeregisterScalableTargetError::ConcurrentUpdate(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeregisterScalableTargetError::ConcurrentUpdate,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeregisterScalableTargetError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeregisterScalableTargetError::InternalService,
+                    )
                 }
                 "ObjectNotFoundException" => {
-                    return RusotoError::Service(DeregisterScalableTargetError::ObjectNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeregisterScalableTargetError::ObjectNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -848,21 +880,30 @@ impl DescribeScalableTargetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(DescribeScalableTargetsError::ConcurrentUpdate(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeScalableTargetsError::ConcurrentUpdate,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeScalableTargetsError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeScalableTargetsError::InternalService,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeScalableTargetsError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeScalableTargetsError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -896,21 +937,30 @@ impl DescribeScalingActivitiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(DescribeScalingActivitiesError::ConcurrentUpdate(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeScalingActivitiesError::ConcurrentUpdate,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeScalingActivitiesError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeScalingActivitiesError::InternalService,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeScalingActivitiesError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeScalingActivitiesError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -946,26 +996,38 @@ impl DescribeScalingPoliciesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(DescribeScalingPoliciesError::ConcurrentUpdate(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeScalingPoliciesError::ConcurrentUpdate,
+                    )
                 }
                 "FailedResourceAccessException" => {
-                    return RusotoError::Service(
-                        DescribeScalingPoliciesError::FailedResourceAccess(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeScalingPoliciesError::FailedResourceAccess,
                     )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeScalingPoliciesError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeScalingPoliciesError::InternalService,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeScalingPoliciesError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeScalingPoliciesError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1000,21 +1062,30 @@ impl DescribeScheduledActionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(DescribeScheduledActionsError::ConcurrentUpdate(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeScheduledActionsError::ConcurrentUpdate,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeScheduledActionsError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeScheduledActionsError::InternalService,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeScheduledActionsError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeScheduledActionsError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1052,23 +1123,46 @@ impl PutScalingPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(PutScalingPolicyError::ConcurrentUpdate(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutScalingPolicyError::ConcurrentUpdate,
+                    )
                 }
                 "FailedResourceAccessException" => {
-                    return RusotoError::Service(PutScalingPolicyError::FailedResourceAccess(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutScalingPolicyError::FailedResourceAccess,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(PutScalingPolicyError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutScalingPolicyError::InternalService,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutScalingPolicyError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutScalingPolicyError::LimitExceeded,
+                    )
                 }
                 "ObjectNotFoundException" => {
-                    return RusotoError::Service(PutScalingPolicyError::ObjectNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutScalingPolicyError::ObjectNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1106,18 +1200,38 @@ impl PutScheduledActionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(PutScheduledActionError::ConcurrentUpdate(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutScheduledActionError::ConcurrentUpdate,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(PutScheduledActionError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutScheduledActionError::InternalService,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutScheduledActionError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutScheduledActionError::LimitExceeded,
+                    )
                 }
                 "ObjectNotFoundException" => {
-                    return RusotoError::Service(PutScheduledActionError::ObjectNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutScheduledActionError::ObjectNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1152,21 +1266,30 @@ impl RegisterScalableTargetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(RegisterScalableTargetError::ConcurrentUpdate(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterScalableTargetError::ConcurrentUpdate,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(RegisterScalableTargetError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterScalableTargetError::InternalService,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RegisterScalableTargetError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterScalableTargetError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1257,11 +1380,12 @@ pub struct ApplicationAutoScalingClient {
 impl ApplicationAutoScalingClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> ApplicationAutoScalingClient {
         ApplicationAutoScalingClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Application Auto Scaling"),
         }
     }
 
@@ -1276,12 +1400,32 @@ impl ApplicationAutoScalingClient {
     {
         ApplicationAutoScalingClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Application Auto Scaling"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ApplicationAutoScalingClient {
-        ApplicationAutoScalingClient { client, region }
+        ApplicationAutoScalingClient {
+            client,
+            region: region_for_service(region, "Application Auto Scaling"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> ApplicationAutoScalingClient {
+        ApplicationAutoScalingClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Application Auto Scaling"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ApplicationAutoScalingClient {
+        ApplicationAutoScalingClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -1293,6 +1437,7 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         input: DeleteScalingPolicyRequest,
     ) -> Result<DeleteScalingPolicyResponse, RusotoError<DeleteScalingPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteScalingPolicy");
         request.add_header(
             "x-amz-target",
             "AnyScaleFrontendService.DeleteScalingPolicy",
@@ -1314,6 +1459,7 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         input: DeleteScheduledActionRequest,
     ) -> Result<DeleteScheduledActionResponse, RusotoError<DeleteScheduledActionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteScheduledAction");
         request.add_header(
             "x-amz-target",
             "AnyScaleFrontendService.DeleteScheduledAction",
@@ -1336,6 +1482,7 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         input: DeregisterScalableTargetRequest,
     ) -> Result<DeregisterScalableTargetResponse, RusotoError<DeregisterScalableTargetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeregisterScalableTarget");
         request.add_header(
             "x-amz-target",
             "AnyScaleFrontendService.DeregisterScalableTarget",
@@ -1358,6 +1505,7 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         input: DescribeScalableTargetsRequest,
     ) -> Result<DescribeScalableTargetsResponse, RusotoError<DescribeScalableTargetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeScalableTargets");
         request.add_header(
             "x-amz-target",
             "AnyScaleFrontendService.DescribeScalableTargets",
@@ -1381,6 +1529,7 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
     ) -> Result<DescribeScalingActivitiesResponse, RusotoError<DescribeScalingActivitiesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeScalingActivities");
         request.add_header(
             "x-amz-target",
             "AnyScaleFrontendService.DescribeScalingActivities",
@@ -1403,6 +1552,7 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         input: DescribeScalingPoliciesRequest,
     ) -> Result<DescribeScalingPoliciesResponse, RusotoError<DescribeScalingPoliciesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeScalingPolicies");
         request.add_header(
             "x-amz-target",
             "AnyScaleFrontendService.DescribeScalingPolicies",
@@ -1425,6 +1575,7 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         input: DescribeScheduledActionsRequest,
     ) -> Result<DescribeScheduledActionsResponse, RusotoError<DescribeScheduledActionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeScheduledActions");
         request.add_header(
             "x-amz-target",
             "AnyScaleFrontendService.DescribeScheduledActions",
@@ -1447,6 +1598,7 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         input: PutScalingPolicyRequest,
     ) -> Result<PutScalingPolicyResponse, RusotoError<PutScalingPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutScalingPolicy");
         request.add_header("x-amz-target", "AnyScaleFrontendService.PutScalingPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1465,6 +1617,7 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         input: PutScheduledActionRequest,
     ) -> Result<PutScheduledActionResponse, RusotoError<PutScheduledActionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutScheduledAction");
         request.add_header("x-amz-target", "AnyScaleFrontendService.PutScheduledAction");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1483,6 +1636,7 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
         input: RegisterScalableTargetRequest,
     ) -> Result<RegisterScalableTargetResponse, RusotoError<RegisterScalableTargetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RegisterScalableTarget");
         request.add_header(
             "x-amz-target",
             "AnyScaleFrontendService.RegisterScalableTarget",
@@ -1499,3 +1653,180 @@ impl ApplicationAutoScaling for ApplicationAutoScalingClient {
             .deserialize::<RegisterScalableTargetResponse, _>()
     }
 }
+
+/// A blocking client for the Application Auto Scaling API, running the calls of a `ApplicationAutoScalingClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `ApplicationAutoScalingClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ApplicationAutoScalingBlockingClient {
+    client: ApplicationAutoScalingClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ApplicationAutoScalingBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> ApplicationAutoScalingBlockingClient {
+        ApplicationAutoScalingBlockingClient::from_client(ApplicationAutoScalingClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ApplicationAutoScalingBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ApplicationAutoScalingBlockingClient::from_client(ApplicationAutoScalingClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> ApplicationAutoScalingBlockingClient {
+        ApplicationAutoScalingBlockingClient::from_client(
+            ApplicationAutoScalingClient::new_with_client(client, region),
+        )
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> ApplicationAutoScalingBlockingClient {
+        ApplicationAutoScalingBlockingClient::from_client(
+            ApplicationAutoScalingClient::new_with_config(config),
+        )
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(
+        client: ApplicationAutoScalingClient,
+    ) -> ApplicationAutoScalingBlockingClient {
+        ApplicationAutoScalingBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> ApplicationAutoScalingBlockingClient {
+        ApplicationAutoScalingBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ApplicationAutoScalingBlockingClient {
+        ApplicationAutoScalingBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`ApplicationAutoScaling::delete_scaling_policy`].
+    pub fn delete_scaling_policy(
+        &self,
+        input: DeleteScalingPolicyRequest,
+    ) -> Result<DeleteScalingPolicyResponse, RusotoError<DeleteScalingPolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_scaling_policy(input))
+    }
+
+    /// Blocking version of [`ApplicationAutoScaling::delete_scheduled_action`].
+    pub fn delete_scheduled_action(
+        &self,
+        input: DeleteScheduledActionRequest,
+    ) -> Result<DeleteScheduledActionResponse, RusotoError<DeleteScheduledActionError>> {
+        self.runtime
+            .block_on(self.client.delete_scheduled_action(input))
+    }
+
+    /// Blocking version of [`ApplicationAutoScaling::deregister_scalable_target`].
+    pub fn deregister_scalable_target(
+        &self,
+        input: DeregisterScalableTargetRequest,
+    ) -> Result<DeregisterScalableTargetResponse, RusotoError<DeregisterScalableTargetError>> {
+        self.runtime
+            .block_on(self.client.deregister_scalable_target(input))
+    }
+
+    /// Blocking version of [`ApplicationAutoScaling::describe_scalable_targets`].
+    pub fn describe_scalable_targets(
+        &self,
+        input: DescribeScalableTargetsRequest,
+    ) -> Result<DescribeScalableTargetsResponse, RusotoError<DescribeScalableTargetsError>> {
+        self.runtime
+            .block_on(self.client.describe_scalable_targets(input))
+    }
+
+    /// Blocking version of [`ApplicationAutoScaling::describe_scaling_activities`].
+    pub fn describe_scaling_activities(
+        &self,
+        input: DescribeScalingActivitiesRequest,
+    ) -> Result<DescribeScalingActivitiesResponse, RusotoError<DescribeScalingActivitiesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_scaling_activities(input))
+    }
+
+    /// Blocking version of [`ApplicationAutoScaling::describe_scaling_policies`].
+    pub fn describe_scaling_policies(
+        &self,
+        input: DescribeScalingPoliciesRequest,
+    ) -> Result<DescribeScalingPoliciesResponse, RusotoError<DescribeScalingPoliciesError>> {
+        self.runtime
+            .block_on(self.client.describe_scaling_policies(input))
+    }
+
+    /// Blocking version of [`ApplicationAutoScaling::describe_scheduled_actions`].
+    pub fn describe_scheduled_actions(
+        &self,
+        input: DescribeScheduledActionsRequest,
+    ) -> Result<DescribeScheduledActionsResponse, RusotoError<DescribeScheduledActionsError>> {
+        self.runtime
+            .block_on(self.client.describe_scheduled_actions(input))
+    }
+
+    /// Blocking version of [`ApplicationAutoScaling::put_scaling_policy`].
+    pub fn put_scaling_policy(
+        &self,
+        input: PutScalingPolicyRequest,
+    ) -> Result<PutScalingPolicyResponse, RusotoError<PutScalingPolicyError>> {
+        self.runtime.block_on(self.client.put_scaling_policy(input))
+    }
+
+    /// Blocking version of [`ApplicationAutoScaling::put_scheduled_action`].
+    pub fn put_scheduled_action(
+        &self,
+        input: PutScheduledActionRequest,
+    ) -> Result<PutScheduledActionResponse, RusotoError<PutScheduledActionError>> {
+        self.runtime
+            .block_on(self.client.put_scheduled_action(input))
+    }
+
+    /// Blocking version of [`ApplicationAutoScaling::register_scalable_target`].
+    pub fn register_scalable_target(
+        &self,
+        input: RegisterScalableTargetRequest,
+    ) -> Result<RegisterScalableTargetResponse, RusotoError<RegisterScalableTargetError>> {
+        self.runtime
+            .block_on(self.client.register_scalable_target(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1113,21 +1114,46 @@ impl CreateApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateApplicationError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApplicationError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateApplicationError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApplicationError::InternalServer,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateApplicationError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApplicationError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApplicationError::ResourceNotFound,
+                    )
                 }
                 "TagsAlreadyExistException" => {
-                    return RusotoError::Service(CreateApplicationError::TagsAlreadyExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApplicationError::TagsAlreadyExist,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1163,15 +1189,30 @@ impl CreateComponentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateComponentError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateComponentError::InternalServer,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateComponentError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateComponentError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateComponentError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateComponentError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1205,15 +1246,30 @@ impl CreateLogPatternError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateLogPatternError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLogPatternError::InternalServer,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateLogPatternError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLogPatternError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateLogPatternError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLogPatternError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1247,15 +1303,30 @@ impl DeleteApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteApplicationError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationError::BadRequest,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteApplicationError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1287,12 +1358,22 @@ impl DeleteComponentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteComponentError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteComponentError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteComponentError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteComponentError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1325,15 +1406,30 @@ impl DeleteLogPatternError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteLogPatternError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLogPatternError::BadRequest,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteLogPatternError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLogPatternError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteLogPatternError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLogPatternError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1365,14 +1461,22 @@ impl DescribeApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeApplicationError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeApplicationError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeApplicationError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeApplicationError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1403,12 +1507,22 @@ impl DescribeComponentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeComponentError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeComponentError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeComponentError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeComponentError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1441,16 +1555,22 @@ impl DescribeComponentConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
-                        DescribeComponentConfigurationError::InternalServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeComponentConfigurationError::InternalServer,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeComponentConfigurationError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeComponentConfigurationError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1487,18 +1607,22 @@ impl DescribeComponentConfigurationRecommendationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
-                        DescribeComponentConfigurationRecommendationError::InternalServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeComponentConfigurationRecommendationError::InternalServer,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeComponentConfigurationRecommendationError::ResourceNotFound(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeComponentConfigurationRecommendationError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1533,12 +1657,22 @@ impl DescribeLogPatternError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeLogPatternError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeLogPatternError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeLogPatternError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeLogPatternError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1569,14 +1703,22 @@ impl DescribeObservationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeObservationError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeObservationError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeObservationError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeObservationError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1607,12 +1749,22 @@ impl DescribeProblemError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeProblemError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeProblemError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeProblemError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeProblemError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1645,16 +1797,22 @@ impl DescribeProblemObservationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeProblemObservationsError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeProblemObservationsError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeProblemObservationsError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeProblemObservationsError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1683,9 +1841,14 @@ impl ListApplicationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListApplicationsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListApplicationsError::InternalServer,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1715,12 +1878,22 @@ impl ListComponentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListComponentsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListComponentsError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListComponentsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListComponentsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1751,16 +1924,22 @@ impl ListConfigurationHistoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListConfigurationHistoryError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListConfigurationHistoryError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListConfigurationHistoryError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListConfigurationHistoryError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1791,12 +1970,22 @@ impl ListLogPatternSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListLogPatternSetsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLogPatternSetsError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListLogPatternSetsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLogPatternSetsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1827,12 +2016,22 @@ impl ListLogPatternsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListLogPatternsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLogPatternsError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListLogPatternsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLogPatternsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1863,12 +2062,22 @@ impl ListProblemsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListProblemsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProblemsError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListProblemsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProblemsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1897,11 +2106,14 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1931,12 +2143,22 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1965,9 +2187,14 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1997,12 +2224,22 @@ impl UpdateApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateApplicationError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApplicationError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApplicationError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2035,15 +2272,30 @@ impl UpdateComponentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateComponentError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateComponentError::InternalServer,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateComponentError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateComponentError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateComponentError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateComponentError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2077,16 +2329,22 @@ impl UpdateComponentConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateComponentConfigurationError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateComponentConfigurationError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateComponentConfigurationError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateComponentConfigurationError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2121,15 +2379,30 @@ impl UpdateLogPatternError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateLogPatternError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateLogPatternError::InternalServer,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateLogPatternError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateLogPatternError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateLogPatternError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateLogPatternError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2328,11 +2601,12 @@ pub struct ApplicationInsightsClient {
 impl ApplicationInsightsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> ApplicationInsightsClient {
         ApplicationInsightsClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Application Insights"),
         }
     }
 
@@ -2347,12 +2621,32 @@ impl ApplicationInsightsClient {
     {
         ApplicationInsightsClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Application Insights"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ApplicationInsightsClient {
-        ApplicationInsightsClient { client, region }
+        ApplicationInsightsClient {
+            client,
+            region: region_for_service(region, "Application Insights"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> ApplicationInsightsClient {
+        ApplicationInsightsClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Application Insights"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ApplicationInsightsClient {
+        ApplicationInsightsClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -2364,6 +2658,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: CreateApplicationRequest,
     ) -> Result<CreateApplicationResponse, RusotoError<CreateApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateApplication");
         request.add_header("x-amz-target", "EC2WindowsBarleyService.CreateApplication");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2382,6 +2677,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: CreateComponentRequest,
     ) -> Result<CreateComponentResponse, RusotoError<CreateComponentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateComponent");
         request.add_header("x-amz-target", "EC2WindowsBarleyService.CreateComponent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2400,6 +2696,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: CreateLogPatternRequest,
     ) -> Result<CreateLogPatternResponse, RusotoError<CreateLogPatternError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateLogPattern");
         request.add_header("x-amz-target", "EC2WindowsBarleyService.CreateLogPattern");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2418,6 +2715,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: DeleteApplicationRequest,
     ) -> Result<DeleteApplicationResponse, RusotoError<DeleteApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteApplication");
         request.add_header("x-amz-target", "EC2WindowsBarleyService.DeleteApplication");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2436,6 +2734,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: DeleteComponentRequest,
     ) -> Result<DeleteComponentResponse, RusotoError<DeleteComponentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteComponent");
         request.add_header("x-amz-target", "EC2WindowsBarleyService.DeleteComponent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2454,6 +2753,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: DeleteLogPatternRequest,
     ) -> Result<DeleteLogPatternResponse, RusotoError<DeleteLogPatternError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteLogPattern");
         request.add_header("x-amz-target", "EC2WindowsBarleyService.DeleteLogPattern");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2472,6 +2772,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: DescribeApplicationRequest,
     ) -> Result<DescribeApplicationResponse, RusotoError<DescribeApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeApplication");
         request.add_header(
             "x-amz-target",
             "EC2WindowsBarleyService.DescribeApplication",
@@ -2493,6 +2794,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: DescribeComponentRequest,
     ) -> Result<DescribeComponentResponse, RusotoError<DescribeComponentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeComponent");
         request.add_header("x-amz-target", "EC2WindowsBarleyService.DescribeComponent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2514,6 +2816,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         RusotoError<DescribeComponentConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeComponentConfiguration");
         request.add_header(
             "x-amz-target",
             "EC2WindowsBarleyService.DescribeComponentConfiguration",
@@ -2539,6 +2842,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         RusotoError<DescribeComponentConfigurationRecommendationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeComponentConfigurationRecommendation");
         request.add_header(
             "x-amz-target",
             "EC2WindowsBarleyService.DescribeComponentConfigurationRecommendation",
@@ -2564,6 +2868,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: DescribeLogPatternRequest,
     ) -> Result<DescribeLogPatternResponse, RusotoError<DescribeLogPatternError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeLogPattern");
         request.add_header("x-amz-target", "EC2WindowsBarleyService.DescribeLogPattern");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2582,6 +2887,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: DescribeObservationRequest,
     ) -> Result<DescribeObservationResponse, RusotoError<DescribeObservationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeObservation");
         request.add_header(
             "x-amz-target",
             "EC2WindowsBarleyService.DescribeObservation",
@@ -2603,6 +2909,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: DescribeProblemRequest,
     ) -> Result<DescribeProblemResponse, RusotoError<DescribeProblemError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeProblem");
         request.add_header("x-amz-target", "EC2WindowsBarleyService.DescribeProblem");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2622,6 +2929,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
     ) -> Result<DescribeProblemObservationsResponse, RusotoError<DescribeProblemObservationsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeProblemObservations");
         request.add_header(
             "x-amz-target",
             "EC2WindowsBarleyService.DescribeProblemObservations",
@@ -2644,6 +2952,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: ListApplicationsRequest,
     ) -> Result<ListApplicationsResponse, RusotoError<ListApplicationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListApplications");
         request.add_header("x-amz-target", "EC2WindowsBarleyService.ListApplications");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2662,6 +2971,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: ListComponentsRequest,
     ) -> Result<ListComponentsResponse, RusotoError<ListComponentsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListComponents");
         request.add_header("x-amz-target", "EC2WindowsBarleyService.ListComponents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2680,6 +2990,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: ListConfigurationHistoryRequest,
     ) -> Result<ListConfigurationHistoryResponse, RusotoError<ListConfigurationHistoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListConfigurationHistory");
         request.add_header(
             "x-amz-target",
             "EC2WindowsBarleyService.ListConfigurationHistory",
@@ -2702,6 +3013,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: ListLogPatternSetsRequest,
     ) -> Result<ListLogPatternSetsResponse, RusotoError<ListLogPatternSetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListLogPatternSets");
         request.add_header("x-amz-target", "EC2WindowsBarleyService.ListLogPatternSets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2720,6 +3032,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: ListLogPatternsRequest,
     ) -> Result<ListLogPatternsResponse, RusotoError<ListLogPatternsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListLogPatterns");
         request.add_header("x-amz-target", "EC2WindowsBarleyService.ListLogPatterns");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2738,6 +3051,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: ListProblemsRequest,
     ) -> Result<ListProblemsResponse, RusotoError<ListProblemsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListProblems");
         request.add_header("x-amz-target", "EC2WindowsBarleyService.ListProblems");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2756,6 +3070,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header(
             "x-amz-target",
             "EC2WindowsBarleyService.ListTagsForResource",
@@ -2777,6 +3092,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "EC2WindowsBarleyService.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2795,6 +3111,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "EC2WindowsBarleyService.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2813,6 +3130,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: UpdateApplicationRequest,
     ) -> Result<UpdateApplicationResponse, RusotoError<UpdateApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateApplication");
         request.add_header("x-amz-target", "EC2WindowsBarleyService.UpdateApplication");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2831,6 +3149,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: UpdateComponentRequest,
     ) -> Result<UpdateComponentResponse, RusotoError<UpdateComponentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateComponent");
         request.add_header("x-amz-target", "EC2WindowsBarleyService.UpdateComponent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2850,6 +3169,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
     ) -> Result<UpdateComponentConfigurationResponse, RusotoError<UpdateComponentConfigurationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateComponentConfiguration");
         request.add_header(
             "x-amz-target",
             "EC2WindowsBarleyService.UpdateComponentConfiguration",
@@ -2872,6 +3192,7 @@ impl ApplicationInsights for ApplicationInsightsClient {
         input: UpdateLogPatternRequest,
     ) -> Result<UpdateLogPatternResponse, RusotoError<UpdateLogPatternError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateLogPattern");
         request.add_header("x-amz-target", "EC2WindowsBarleyService.UpdateLogPattern");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2884,3 +3205,324 @@ impl ApplicationInsights for ApplicationInsightsClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateLogPatternResponse, _>()
     }
 }
+
+/// A blocking client for the Application Insights API, running the calls of a `ApplicationInsightsClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `ApplicationInsightsClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ApplicationInsightsBlockingClient {
+    client: ApplicationInsightsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ApplicationInsightsBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> ApplicationInsightsBlockingClient {
+        ApplicationInsightsBlockingClient::from_client(ApplicationInsightsClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ApplicationInsightsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ApplicationInsightsBlockingClient::from_client(ApplicationInsightsClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> ApplicationInsightsBlockingClient {
+        ApplicationInsightsBlockingClient::from_client(ApplicationInsightsClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> ApplicationInsightsBlockingClient {
+        ApplicationInsightsBlockingClient::from_client(ApplicationInsightsClient::new_with_config(
+            config,
+        ))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: ApplicationInsightsClient) -> ApplicationInsightsBlockingClient {
+        ApplicationInsightsBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> ApplicationInsightsBlockingClient {
+        ApplicationInsightsBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ApplicationInsightsBlockingClient {
+        ApplicationInsightsBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`ApplicationInsights::create_application`].
+    pub fn create_application(
+        &self,
+        input: CreateApplicationRequest,
+    ) -> Result<CreateApplicationResponse, RusotoError<CreateApplicationError>> {
+        self.runtime.block_on(self.client.create_application(input))
+    }
+
+    /// Blocking version of [`ApplicationInsights::create_component`].
+    pub fn create_component(
+        &self,
+        input: CreateComponentRequest,
+    ) -> Result<CreateComponentResponse, RusotoError<CreateComponentError>> {
+        self.runtime.block_on(self.client.create_component(input))
+    }
+
+    /// Blocking version of [`ApplicationInsights::create_log_pattern`].
+    pub fn create_log_pattern(
+        &self,
+        input: CreateLogPatternRequest,
+    ) -> Result<CreateLogPatternResponse, RusotoError<CreateLogPatternError>> {
+        self.runtime.block_on(self.client.create_log_pattern(input))
+    }
+
+    /// Blocking version of [`ApplicationInsights::delete_application`].
+    pub fn delete_application(
+        &self,
+        input: DeleteApplicationRequest,
+    ) -> Result<DeleteApplicationResponse, RusotoError<DeleteApplicationError>> {
+        self.runtime.block_on(self.client.delete_application(input))
+    }
+
+    /// Blocking version of [`ApplicationInsights::delete_component`].
+    pub fn delete_component(
+        &self,
+        input: DeleteComponentRequest,
+    ) -> Result<DeleteComponentResponse, RusotoError<DeleteComponentError>> {
+        self.runtime.block_on(self.client.delete_component(input))
+    }
+
+    /// Blocking version of [`ApplicationInsights::delete_log_pattern`].
+    pub fn delete_log_pattern(
+        &self,
+        input: DeleteLogPatternRequest,
+    ) -> Result<DeleteLogPatternResponse, RusotoError<DeleteLogPatternError>> {
+        self.runtime.block_on(self.client.delete_log_pattern(input))
+    }
+
+    /// Blocking version of [`ApplicationInsights::describe_application`].
+    pub fn describe_application(
+        &self,
+        input: DescribeApplicationRequest,
+    ) -> Result<DescribeApplicationResponse, RusotoError<DescribeApplicationError>> {
+        self.runtime
+            .block_on(self.client.describe_application(input))
+    }
+
+    /// Blocking version of [`ApplicationInsights::describe_component`].
+    pub fn describe_component(
+        &self,
+        input: DescribeComponentRequest,
+    ) -> Result<DescribeComponentResponse, RusotoError<DescribeComponentError>> {
+        self.runtime.block_on(self.client.describe_component(input))
+    }
+
+    /// Blocking version of [`ApplicationInsights::describe_component_configuration`].
+    pub fn describe_component_configuration(
+        &self,
+        input: DescribeComponentConfigurationRequest,
+    ) -> Result<
+        DescribeComponentConfigurationResponse,
+        RusotoError<DescribeComponentConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_component_configuration(input))
+    }
+
+    /// Blocking version of [`ApplicationInsights::describe_component_configuration_recommendation`].
+    pub fn describe_component_configuration_recommendation(
+        &self,
+        input: DescribeComponentConfigurationRecommendationRequest,
+    ) -> Result<
+        DescribeComponentConfigurationRecommendationResponse,
+        RusotoError<DescribeComponentConfigurationRecommendationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_component_configuration_recommendation(input),
+        )
+    }
+
+    /// Blocking version of [`ApplicationInsights::describe_log_pattern`].
+    pub fn describe_log_pattern(
+        &self,
+        input: DescribeLogPatternRequest,
+    ) -> Result<DescribeLogPatternResponse, RusotoError<DescribeLogPatternError>> {
+        self.runtime
+            .block_on(self.client.describe_log_pattern(input))
+    }
+
+    /// Blocking version of [`ApplicationInsights::describe_observation`].
+    pub fn describe_observation(
+        &self,
+        input: DescribeObservationRequest,
+    ) -> Result<DescribeObservationResponse, RusotoError<DescribeObservationError>> {
+        self.runtime
+            .block_on(self.client.describe_observation(input))
+    }
+
+    /// Blocking version of [`ApplicationInsights::describe_problem`].
+    pub fn describe_problem(
+        &self,
+        input: DescribeProblemRequest,
+    ) -> Result<DescribeProblemResponse, RusotoError<DescribeProblemError>> {
+        self.runtime.block_on(self.client.describe_problem(input))
+    }
+
+    /// Blocking version of [`ApplicationInsights::describe_problem_observations`].
+    pub fn describe_problem_observations(
+        &self,
+        input: DescribeProblemObservationsRequest,
+    ) -> Result<DescribeProblemObservationsResponse, RusotoError<DescribeProblemObservationsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_problem_observations(input))
+    }
+
+    /// Blocking version of [`ApplicationInsights::list_applications`].
+    pub fn list_applications(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> Result<ListApplicationsResponse, RusotoError<ListApplicationsError>> {
+        self.runtime.block_on(self.client.list_applications(input))
+    }
+
+    /// Blocking version of [`ApplicationInsights::list_components`].
+    pub fn list_components(
+        &self,
+        input: ListComponentsRequest,
+    ) -> Result<ListComponentsResponse, RusotoError<ListComponentsError>> {
+        self.runtime.block_on(self.client.list_components(input))
+    }
+
+    /// Blocking version of [`ApplicationInsights::list_configuration_history`].
+    pub fn list_configuration_history(
+        &self,
+        input: ListConfigurationHistoryRequest,
+    ) -> Result<ListConfigurationHistoryResponse, RusotoError<ListConfigurationHistoryError>> {
+        self.runtime
+            .block_on(self.client.list_configuration_history(input))
+    }
+
+    /// Blocking version of [`ApplicationInsights::list_log_pattern_sets`].
+    pub fn list_log_pattern_sets(
+        &self,
+        input: ListLogPatternSetsRequest,
+    ) -> Result<ListLogPatternSetsResponse, RusotoError<ListLogPatternSetsError>> {
+        self.runtime
+            .block_on(self.client.list_log_pattern_sets(input))
+    }
+
+    /// Blocking version of [`ApplicationInsights::list_log_patterns`].
+    pub fn list_log_patterns(
+        &self,
+        input: ListLogPatternsRequest,
+    ) -> Result<ListLogPatternsResponse, RusotoError<ListLogPatternsError>> {
+        self.runtime.block_on(self.client.list_log_patterns(input))
+    }
+
+    /// Blocking version of [`ApplicationInsights::list_problems`].
+    pub fn list_problems(
+        &self,
+        input: ListProblemsRequest,
+    ) -> Result<ListProblemsResponse, RusotoError<ListProblemsError>> {
+        self.runtime.block_on(self.client.list_problems(input))
+    }
+
+    /// Blocking version of [`ApplicationInsights::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`ApplicationInsights::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`ApplicationInsights::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`ApplicationInsights::update_application`].
+    pub fn update_application(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> Result<UpdateApplicationResponse, RusotoError<UpdateApplicationError>> {
+        self.runtime.block_on(self.client.update_application(input))
+    }
+
+    /// Blocking version of [`ApplicationInsights::update_component`].
+    pub fn update_component(
+        &self,
+        input: UpdateComponentRequest,
+    ) -> Result<UpdateComponentResponse, RusotoError<UpdateComponentError>> {
+        self.runtime.block_on(self.client.update_component(input))
+    }
+
+    /// Blocking version of [`ApplicationInsights::update_component_configuration`].
+    pub fn update_component_configuration(
+        &self,
+        input: UpdateComponentConfigurationRequest,
+    ) -> Result<UpdateComponentConfigurationResponse, RusotoError<UpdateComponentConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.update_component_configuration(input))
+    }
+
+    /// Blocking version of [`ApplicationInsights::update_log_pattern`].
+    pub fn update_log_pattern(
+        &self,
+        input: UpdateLogPatternRequest,
+    ) -> Result<UpdateLogPatternResponse, RusotoError<UpdateLogPatternError>> {
+        self.runtime.block_on(self.client.update_log_pattern(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -2763,34 +2764,70 @@ impl CreateGatewayRouteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateGatewayRouteError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGatewayRouteError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateGatewayRouteError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGatewayRouteError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateGatewayRouteError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGatewayRouteError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateGatewayRouteError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateGatewayRouteError::InternalServerError,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateGatewayRouteError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGatewayRouteError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateGatewayRouteError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGatewayRouteError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateGatewayRouteError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateGatewayRouteError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateGatewayRouteError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGatewayRouteError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2839,30 +2876,70 @@ impl CreateMeshError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateMeshError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMeshError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateMeshError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMeshError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateMeshError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMeshError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateMeshError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMeshError::InternalServerError,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateMeshError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMeshError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateMeshError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMeshError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateMeshError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMeshError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateMeshError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMeshError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2911,30 +2988,70 @@ impl CreateRouteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateRouteError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRouteError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateRouteError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRouteError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateRouteError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRouteError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateRouteError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRouteError::InternalServerError,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateRouteError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRouteError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateRouteError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRouteError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateRouteError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRouteError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateRouteError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRouteError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2983,36 +3100,70 @@ impl CreateVirtualGatewayError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateVirtualGatewayError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVirtualGatewayError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateVirtualGatewayError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVirtualGatewayError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateVirtualGatewayError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVirtualGatewayError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateVirtualGatewayError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateVirtualGatewayError::InternalServerError,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateVirtualGatewayError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVirtualGatewayError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateVirtualGatewayError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVirtualGatewayError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateVirtualGatewayError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateVirtualGatewayError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateVirtualGatewayError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateVirtualGatewayError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3061,34 +3212,70 @@ impl CreateVirtualNodeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateVirtualNodeError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVirtualNodeError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateVirtualNodeError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVirtualNodeError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateVirtualNodeError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVirtualNodeError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateVirtualNodeError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateVirtualNodeError::InternalServerError,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateVirtualNodeError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVirtualNodeError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateVirtualNodeError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVirtualNodeError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateVirtualNodeError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateVirtualNodeError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateVirtualNodeError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVirtualNodeError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3137,34 +3324,70 @@ impl CreateVirtualRouterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateVirtualRouterError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVirtualRouterError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateVirtualRouterError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVirtualRouterError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateVirtualRouterError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVirtualRouterError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateVirtualRouterError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateVirtualRouterError::InternalServerError,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateVirtualRouterError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVirtualRouterError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateVirtualRouterError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVirtualRouterError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateVirtualRouterError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateVirtualRouterError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateVirtualRouterError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVirtualRouterError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3213,36 +3436,70 @@ impl CreateVirtualServiceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateVirtualServiceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVirtualServiceError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateVirtualServiceError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVirtualServiceError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateVirtualServiceError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVirtualServiceError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateVirtualServiceError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateVirtualServiceError::InternalServerError,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateVirtualServiceError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVirtualServiceError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateVirtualServiceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVirtualServiceError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateVirtualServiceError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateVirtualServiceError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateVirtualServiceError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateVirtualServiceError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3289,31 +3546,62 @@ impl DeleteGatewayRouteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteGatewayRouteError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGatewayRouteError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteGatewayRouteError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGatewayRouteError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteGatewayRouteError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteGatewayRouteError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteGatewayRouteError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGatewayRouteError::NotFound,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteGatewayRouteError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGatewayRouteError::ResourceInUse,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteGatewayRouteError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteGatewayRouteError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteGatewayRouteError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGatewayRouteError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3359,27 +3647,62 @@ impl DeleteMeshError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteMeshError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMeshError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteMeshError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMeshError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteMeshError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMeshError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteMeshError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMeshError::NotFound,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteMeshError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMeshError::ResourceInUse,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteMeshError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMeshError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteMeshError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMeshError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3425,27 +3748,62 @@ impl DeleteRouteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteRouteError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRouteError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteRouteError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRouteError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteRouteError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRouteError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRouteError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRouteError::NotFound,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteRouteError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRouteError::ResourceInUse,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteRouteError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRouteError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteRouteError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRouteError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3491,33 +3849,62 @@ impl DeleteVirtualGatewayError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteVirtualGatewayError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVirtualGatewayError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteVirtualGatewayError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVirtualGatewayError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteVirtualGatewayError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteVirtualGatewayError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVirtualGatewayError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVirtualGatewayError::NotFound,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteVirtualGatewayError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVirtualGatewayError::ResourceInUse,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteVirtualGatewayError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteVirtualGatewayError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteVirtualGatewayError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteVirtualGatewayError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3563,31 +3950,62 @@ impl DeleteVirtualNodeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteVirtualNodeError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVirtualNodeError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteVirtualNodeError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVirtualNodeError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteVirtualNodeError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteVirtualNodeError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVirtualNodeError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVirtualNodeError::NotFound,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteVirtualNodeError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVirtualNodeError::ResourceInUse,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteVirtualNodeError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteVirtualNodeError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteVirtualNodeError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVirtualNodeError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3633,31 +4051,62 @@ impl DeleteVirtualRouterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteVirtualRouterError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVirtualRouterError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteVirtualRouterError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVirtualRouterError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteVirtualRouterError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteVirtualRouterError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVirtualRouterError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVirtualRouterError::NotFound,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteVirtualRouterError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVirtualRouterError::ResourceInUse,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteVirtualRouterError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteVirtualRouterError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteVirtualRouterError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVirtualRouterError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3703,33 +4152,62 @@ impl DeleteVirtualServiceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteVirtualServiceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVirtualServiceError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteVirtualServiceError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVirtualServiceError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteVirtualServiceError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteVirtualServiceError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVirtualServiceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVirtualServiceError::NotFound,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteVirtualServiceError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVirtualServiceError::ResourceInUse,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteVirtualServiceError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteVirtualServiceError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteVirtualServiceError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteVirtualServiceError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3773,30 +4251,54 @@ impl DescribeGatewayRouteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeGatewayRouteError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeGatewayRouteError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeGatewayRouteError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeGatewayRouteError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeGatewayRouteError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeGatewayRouteError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeGatewayRouteError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeGatewayRouteError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeGatewayRouteError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeGatewayRouteError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeGatewayRouteError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeGatewayRouteError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3839,24 +4341,54 @@ impl DescribeMeshError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeMeshError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMeshError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeMeshError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMeshError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeMeshError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMeshError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeMeshError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMeshError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeMeshError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMeshError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeMeshError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMeshError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3899,24 +4431,54 @@ impl DescribeRouteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeRouteError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRouteError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeRouteError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRouteError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeRouteError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRouteError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeRouteError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRouteError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeRouteError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRouteError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeRouteError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRouteError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3959,30 +4521,54 @@ impl DescribeVirtualGatewayError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeVirtualGatewayError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeVirtualGatewayError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeVirtualGatewayError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeVirtualGatewayError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeVirtualGatewayError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeVirtualGatewayError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeVirtualGatewayError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeVirtualGatewayError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeVirtualGatewayError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeVirtualGatewayError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeVirtualGatewayError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeVirtualGatewayError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4025,28 +4611,54 @@ impl DescribeVirtualNodeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeVirtualNodeError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeVirtualNodeError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeVirtualNodeError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeVirtualNodeError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeVirtualNodeError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeVirtualNodeError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeVirtualNodeError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeVirtualNodeError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeVirtualNodeError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeVirtualNodeError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeVirtualNodeError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeVirtualNodeError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4089,30 +4701,54 @@ impl DescribeVirtualRouterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeVirtualRouterError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeVirtualRouterError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeVirtualRouterError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeVirtualRouterError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeVirtualRouterError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeVirtualRouterError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeVirtualRouterError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeVirtualRouterError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeVirtualRouterError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeVirtualRouterError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeVirtualRouterError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeVirtualRouterError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4155,30 +4791,54 @@ impl DescribeVirtualServiceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeVirtualServiceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeVirtualServiceError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeVirtualServiceError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeVirtualServiceError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeVirtualServiceError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeVirtualServiceError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeVirtualServiceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeVirtualServiceError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeVirtualServiceError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeVirtualServiceError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeVirtualServiceError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeVirtualServiceError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4221,28 +4881,54 @@ impl ListGatewayRoutesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListGatewayRoutesError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGatewayRoutesError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListGatewayRoutesError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGatewayRoutesError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListGatewayRoutesError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListGatewayRoutesError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListGatewayRoutesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGatewayRoutesError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListGatewayRoutesError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListGatewayRoutesError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListGatewayRoutesError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGatewayRoutesError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4285,24 +4971,54 @@ impl ListMeshesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListMeshesError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMeshesError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListMeshesError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMeshesError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListMeshesError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMeshesError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListMeshesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMeshesError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListMeshesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMeshesError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListMeshesError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMeshesError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4345,24 +5061,54 @@ impl ListRoutesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListRoutesError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRoutesError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListRoutesError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRoutesError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListRoutesError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRoutesError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListRoutesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRoutesError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListRoutesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRoutesError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListRoutesError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRoutesError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4405,28 +5151,54 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListTagsForResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4469,28 +5241,54 @@ impl ListVirtualGatewaysError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListVirtualGatewaysError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVirtualGatewaysError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListVirtualGatewaysError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVirtualGatewaysError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListVirtualGatewaysError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListVirtualGatewaysError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListVirtualGatewaysError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVirtualGatewaysError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListVirtualGatewaysError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListVirtualGatewaysError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListVirtualGatewaysError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVirtualGatewaysError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4533,26 +5331,54 @@ impl ListVirtualNodesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListVirtualNodesError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVirtualNodesError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListVirtualNodesError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVirtualNodesError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListVirtualNodesError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListVirtualNodesError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListVirtualNodesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVirtualNodesError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListVirtualNodesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVirtualNodesError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListVirtualNodesError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVirtualNodesError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4595,28 +5421,54 @@ impl ListVirtualRoutersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListVirtualRoutersError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVirtualRoutersError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListVirtualRoutersError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVirtualRoutersError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListVirtualRoutersError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListVirtualRoutersError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListVirtualRoutersError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVirtualRoutersError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListVirtualRoutersError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListVirtualRoutersError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListVirtualRoutersError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVirtualRoutersError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4659,28 +5511,54 @@ impl ListVirtualServicesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListVirtualServicesError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVirtualServicesError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListVirtualServicesError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVirtualServicesError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListVirtualServicesError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListVirtualServicesError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListVirtualServicesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVirtualServicesError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListVirtualServicesError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListVirtualServicesError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListVirtualServicesError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVirtualServicesError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4725,27 +5603,62 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(TagResourceError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(TagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(TagResourceError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::TooManyRequests,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4789,24 +5702,54 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UntagResourceError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UntagResourceError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4853,34 +5796,70 @@ impl UpdateGatewayRouteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateGatewayRouteError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGatewayRouteError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateGatewayRouteError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGatewayRouteError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateGatewayRouteError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGatewayRouteError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateGatewayRouteError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateGatewayRouteError::InternalServerError,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateGatewayRouteError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGatewayRouteError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateGatewayRouteError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGatewayRouteError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateGatewayRouteError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateGatewayRouteError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateGatewayRouteError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGatewayRouteError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4927,27 +5906,62 @@ impl UpdateMeshError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateMeshError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMeshError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateMeshError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMeshError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateMeshError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMeshError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateMeshError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMeshError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateMeshError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMeshError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateMeshError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMeshError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateMeshError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMeshError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4995,30 +6009,70 @@ impl UpdateRouteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateRouteError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRouteError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateRouteError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRouteError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateRouteError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRouteError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateRouteError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRouteError::InternalServerError,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateRouteError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRouteError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateRouteError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRouteError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateRouteError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRouteError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateRouteError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRouteError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5067,36 +6121,70 @@ impl UpdateVirtualGatewayError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateVirtualGatewayError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVirtualGatewayError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateVirtualGatewayError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVirtualGatewayError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateVirtualGatewayError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVirtualGatewayError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateVirtualGatewayError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateVirtualGatewayError::InternalServerError,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateVirtualGatewayError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVirtualGatewayError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateVirtualGatewayError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVirtualGatewayError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateVirtualGatewayError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateVirtualGatewayError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateVirtualGatewayError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateVirtualGatewayError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5145,34 +6233,70 @@ impl UpdateVirtualNodeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateVirtualNodeError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVirtualNodeError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateVirtualNodeError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVirtualNodeError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateVirtualNodeError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVirtualNodeError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateVirtualNodeError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateVirtualNodeError::InternalServerError,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateVirtualNodeError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVirtualNodeError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateVirtualNodeError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVirtualNodeError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateVirtualNodeError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateVirtualNodeError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateVirtualNodeError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVirtualNodeError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5221,34 +6345,70 @@ impl UpdateVirtualRouterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateVirtualRouterError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVirtualRouterError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateVirtualRouterError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVirtualRouterError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateVirtualRouterError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVirtualRouterError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateVirtualRouterError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateVirtualRouterError::InternalServerError,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateVirtualRouterError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVirtualRouterError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateVirtualRouterError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVirtualRouterError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateVirtualRouterError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateVirtualRouterError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateVirtualRouterError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVirtualRouterError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5297,36 +6457,70 @@ impl UpdateVirtualServiceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateVirtualServiceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVirtualServiceError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateVirtualServiceError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVirtualServiceError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateVirtualServiceError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVirtualServiceError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateVirtualServiceError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateVirtualServiceError::InternalServerError,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateVirtualServiceError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVirtualServiceError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateVirtualServiceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVirtualServiceError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateVirtualServiceError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateVirtualServiceError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateVirtualServiceError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateVirtualServiceError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5590,11 +6784,12 @@ pub struct AppMeshClient {
 impl AppMeshClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> AppMeshClient {
         AppMeshClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "App Mesh"),
         }
     }
 
@@ -5609,12 +6804,32 @@ impl AppMeshClient {
     {
         AppMeshClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "App Mesh"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> AppMeshClient {
-        AppMeshClient { client, region }
+        AppMeshClient {
+            client,
+            region: region_for_service(region, "App Mesh"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> AppMeshClient {
+        AppMeshClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("App Mesh"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> AppMeshClient {
+        AppMeshClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -5633,6 +6848,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("CreateGatewayRoute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5670,6 +6886,7 @@ impl AppMesh for AppMeshClient {
         let request_uri = "/v20190125/meshes";
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("CreateMesh");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5705,6 +6922,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("CreateRoute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5745,6 +6963,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("CreateVirtualGateway");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5785,6 +7004,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("CreateVirtualNode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5825,6 +7045,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("CreateVirtualRouter");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5865,6 +7086,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("CreateVirtualService");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5902,6 +7124,7 @@ impl AppMesh for AppMeshClient {
         let request_uri = format!("/v20190125/meshes/{mesh_name}/virtualGateway/{virtual_gateway_name}/gatewayRoutes/{gateway_route_name}", gateway_route_name = input.gateway_route_name, mesh_name = input.mesh_name, virtual_gateway_name = input.virtual_gateway_name);
 
         let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
+        request.set_operation("DeleteGatewayRoute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5936,6 +7159,7 @@ impl AppMesh for AppMeshClient {
         let request_uri = format!("/v20190125/meshes/{mesh_name}", mesh_name = input.mesh_name);
 
         let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
+        request.set_operation("DeleteMesh");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5969,6 +7193,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
+        request.set_operation("DeleteRoute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6007,6 +7232,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
+        request.set_operation("DeleteVirtualGateway");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6045,6 +7271,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
+        request.set_operation("DeleteVirtualNode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6083,6 +7310,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
+        request.set_operation("DeleteVirtualRouter");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6121,6 +7349,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appmesh", &self.region, &request_uri);
+        request.set_operation("DeleteVirtualService");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6155,6 +7384,7 @@ impl AppMesh for AppMeshClient {
         let request_uri = format!("/v20190125/meshes/{mesh_name}/virtualGateway/{virtual_gateway_name}/gatewayRoutes/{gateway_route_name}", gateway_route_name = input.gateway_route_name, mesh_name = input.mesh_name, virtual_gateway_name = input.virtual_gateway_name);
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("DescribeGatewayRoute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6189,6 +7419,7 @@ impl AppMesh for AppMeshClient {
         let request_uri = format!("/v20190125/meshes/{mesh_name}", mesh_name = input.mesh_name);
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("DescribeMesh");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6228,6 +7459,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("DescribeRoute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6266,6 +7498,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("DescribeVirtualGateway");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6304,6 +7537,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("DescribeVirtualNode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6342,6 +7576,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("DescribeVirtualRouter");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6380,6 +7615,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("DescribeVirtualService");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6418,6 +7654,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("ListGatewayRoutes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6458,6 +7695,7 @@ impl AppMesh for AppMeshClient {
         let request_uri = "/v20190125/meshes";
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("ListMeshes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6499,6 +7737,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("ListRoutes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6539,6 +7778,7 @@ impl AppMesh for AppMeshClient {
         let request_uri = "/v20190125/tags";
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6580,6 +7820,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("ListVirtualGateways");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6623,6 +7864,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("ListVirtualNodes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6666,6 +7908,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("ListVirtualRouters");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6709,6 +7952,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("GET", "appmesh", &self.region, &request_uri);
+        request.set_operation("ListVirtualServices");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6749,6 +7993,7 @@ impl AppMesh for AppMeshClient {
         let request_uri = "/v20190125/tag";
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6784,6 +8029,7 @@ impl AppMesh for AppMeshClient {
         let request_uri = "/v20190125/untag";
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6819,6 +8065,7 @@ impl AppMesh for AppMeshClient {
         let request_uri = format!("/v20190125/meshes/{mesh_name}/virtualGateway/{virtual_gateway_name}/gatewayRoutes/{gateway_route_name}", gateway_route_name = input.gateway_route_name, mesh_name = input.mesh_name, virtual_gateway_name = input.virtual_gateway_name);
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("UpdateGatewayRoute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6856,6 +8103,7 @@ impl AppMesh for AppMeshClient {
         let request_uri = format!("/v20190125/meshes/{mesh_name}", mesh_name = input.mesh_name);
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("UpdateMesh");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6892,6 +8140,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("UpdateRoute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6933,6 +8182,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("UpdateVirtualGateway");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6974,6 +8224,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("UpdateVirtualNode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7015,6 +8266,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("UpdateVirtualRouter");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7056,6 +8308,7 @@ impl AppMesh for AppMeshClient {
         );
 
         let mut request = SignedRequest::new("PUT", "appmesh", &self.region, &request_uri);
+        request.set_operation("UpdateVirtualService");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7084,3 +8337,407 @@ impl AppMesh for AppMeshClient {
         }
     }
 }
+
+/// A blocking client for the AWS App Mesh API, running the calls of a `AppMeshClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `AppMeshClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct AppMeshBlockingClient {
+    client: AppMeshClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl AppMeshBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> AppMeshBlockingClient {
+        AppMeshBlockingClient::from_client(AppMeshClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> AppMeshBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        AppMeshBlockingClient::from_client(AppMeshClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> AppMeshBlockingClient {
+        AppMeshBlockingClient::from_client(AppMeshClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> AppMeshBlockingClient {
+        AppMeshBlockingClient::from_client(AppMeshClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: AppMeshClient) -> AppMeshBlockingClient {
+        AppMeshBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> AppMeshBlockingClient {
+        AppMeshBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> AppMeshBlockingClient {
+        AppMeshBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`AppMesh::create_gateway_route`].
+    pub fn create_gateway_route(
+        &self,
+        input: CreateGatewayRouteInput,
+    ) -> Result<CreateGatewayRouteOutput, RusotoError<CreateGatewayRouteError>> {
+        self.runtime
+            .block_on(self.client.create_gateway_route(input))
+    }
+
+    /// Blocking version of [`AppMesh::create_mesh`].
+    pub fn create_mesh(
+        &self,
+        input: CreateMeshInput,
+    ) -> Result<CreateMeshOutput, RusotoError<CreateMeshError>> {
+        self.runtime.block_on(self.client.create_mesh(input))
+    }
+
+    /// Blocking version of [`AppMesh::create_route`].
+    pub fn create_route(
+        &self,
+        input: CreateRouteInput,
+    ) -> Result<CreateRouteOutput, RusotoError<CreateRouteError>> {
+        self.runtime.block_on(self.client.create_route(input))
+    }
+
+    /// Blocking version of [`AppMesh::create_virtual_gateway`].
+    pub fn create_virtual_gateway(
+        &self,
+        input: CreateVirtualGatewayInput,
+    ) -> Result<CreateVirtualGatewayOutput, RusotoError<CreateVirtualGatewayError>> {
+        self.runtime
+            .block_on(self.client.create_virtual_gateway(input))
+    }
+
+    /// Blocking version of [`AppMesh::create_virtual_node`].
+    pub fn create_virtual_node(
+        &self,
+        input: CreateVirtualNodeInput,
+    ) -> Result<CreateVirtualNodeOutput, RusotoError<CreateVirtualNodeError>> {
+        self.runtime
+            .block_on(self.client.create_virtual_node(input))
+    }
+
+    /// Blocking version of [`AppMesh::create_virtual_router`].
+    pub fn create_virtual_router(
+        &self,
+        input: CreateVirtualRouterInput,
+    ) -> Result<CreateVirtualRouterOutput, RusotoError<CreateVirtualRouterError>> {
+        self.runtime
+            .block_on(self.client.create_virtual_router(input))
+    }
+
+    /// Blocking version of [`AppMesh::create_virtual_service`].
+    pub fn create_virtual_service(
+        &self,
+        input: CreateVirtualServiceInput,
+    ) -> Result<CreateVirtualServiceOutput, RusotoError<CreateVirtualServiceError>> {
+        self.runtime
+            .block_on(self.client.create_virtual_service(input))
+    }
+
+    /// Blocking version of [`AppMesh::delete_gateway_route`].
+    pub fn delete_gateway_route(
+        &self,
+        input: DeleteGatewayRouteInput,
+    ) -> Result<DeleteGatewayRouteOutput, RusotoError<DeleteGatewayRouteError>> {
+        self.runtime
+            .block_on(self.client.delete_gateway_route(input))
+    }
+
+    /// Blocking version of [`AppMesh::delete_mesh`].
+    pub fn delete_mesh(
+        &self,
+        input: DeleteMeshInput,
+    ) -> Result<DeleteMeshOutput, RusotoError<DeleteMeshError>> {
+        self.runtime.block_on(self.client.delete_mesh(input))
+    }
+
+    /// Blocking version of [`AppMesh::delete_route`].
+    pub fn delete_route(
+        &self,
+        input: DeleteRouteInput,
+    ) -> Result<DeleteRouteOutput, RusotoError<DeleteRouteError>> {
+        self.runtime.block_on(self.client.delete_route(input))
+    }
+
+    /// Blocking version of [`AppMesh::delete_virtual_gateway`].
+    pub fn delete_virtual_gateway(
+        &self,
+        input: DeleteVirtualGatewayInput,
+    ) -> Result<DeleteVirtualGatewayOutput, RusotoError<DeleteVirtualGatewayError>> {
+        self.runtime
+            .block_on(self.client.delete_virtual_gateway(input))
+    }
+
+    /// Blocking version of [`AppMesh::delete_virtual_node`].
+    pub fn delete_virtual_node(
+        &self,
+        input: DeleteVirtualNodeInput,
+    ) -> Result<DeleteVirtualNodeOutput, RusotoError<DeleteVirtualNodeError>> {
+        self.runtime
+            .block_on(self.client.delete_virtual_node(input))
+    }
+
+    /// Blocking version of [`AppMesh::delete_virtual_router`].
+    pub fn delete_virtual_router(
+        &self,
+        input: DeleteVirtualRouterInput,
+    ) -> Result<DeleteVirtualRouterOutput, RusotoError<DeleteVirtualRouterError>> {
+        self.runtime
+            .block_on(self.client.delete_virtual_router(input))
+    }
+
+    /// Blocking version of [`AppMesh::delete_virtual_service`].
+    pub fn delete_virtual_service(
+        &self,
+        input: DeleteVirtualServiceInput,
+    ) -> Result<DeleteVirtualServiceOutput, RusotoError<DeleteVirtualServiceError>> {
+        self.runtime
+            .block_on(self.client.delete_virtual_service(input))
+    }
+
+    /// Blocking version of [`AppMesh::describe_gateway_route`].
+    pub fn describe_gateway_route(
+        &self,
+        input: DescribeGatewayRouteInput,
+    ) -> Result<DescribeGatewayRouteOutput, RusotoError<DescribeGatewayRouteError>> {
+        self.runtime
+            .block_on(self.client.describe_gateway_route(input))
+    }
+
+    /// Blocking version of [`AppMesh::describe_mesh`].
+    pub fn describe_mesh(
+        &self,
+        input: DescribeMeshInput,
+    ) -> Result<DescribeMeshOutput, RusotoError<DescribeMeshError>> {
+        self.runtime.block_on(self.client.describe_mesh(input))
+    }
+
+    /// Blocking version of [`AppMesh::describe_route`].
+    pub fn describe_route(
+        &self,
+        input: DescribeRouteInput,
+    ) -> Result<DescribeRouteOutput, RusotoError<DescribeRouteError>> {
+        self.runtime.block_on(self.client.describe_route(input))
+    }
+
+    /// Blocking version of [`AppMesh::describe_virtual_gateway`].
+    pub fn describe_virtual_gateway(
+        &self,
+        input: DescribeVirtualGatewayInput,
+    ) -> Result<DescribeVirtualGatewayOutput, RusotoError<DescribeVirtualGatewayError>> {
+        self.runtime
+            .block_on(self.client.describe_virtual_gateway(input))
+    }
+
+    /// Blocking version of [`AppMesh::describe_virtual_node`].
+    pub fn describe_virtual_node(
+        &self,
+        input: DescribeVirtualNodeInput,
+    ) -> Result<DescribeVirtualNodeOutput, RusotoError<DescribeVirtualNodeError>> {
+        self.runtime
+            .block_on(self.client.describe_virtual_node(input))
+    }
+
+    /// Blocking version of [`AppMesh::describe_virtual_router`].
+    pub fn describe_virtual_router(
+        &self,
+        input: DescribeVirtualRouterInput,
+    ) -> Result<DescribeVirtualRouterOutput, RusotoError<DescribeVirtualRouterError>> {
+        self.runtime
+            .block_on(self.client.describe_virtual_router(input))
+    }
+
+    /// Blocking version of [`AppMesh::describe_virtual_service`].
+    pub fn describe_virtual_service(
+        &self,
+        input: DescribeVirtualServiceInput,
+    ) -> Result<DescribeVirtualServiceOutput, RusotoError<DescribeVirtualServiceError>> {
+        self.runtime
+            .block_on(self.client.describe_virtual_service(input))
+    }
+
+    /// Blocking version of [`AppMesh::list_gateway_routes`].
+    pub fn list_gateway_routes(
+        &self,
+        input: ListGatewayRoutesInput,
+    ) -> Result<ListGatewayRoutesOutput, RusotoError<ListGatewayRoutesError>> {
+        self.runtime
+            .block_on(self.client.list_gateway_routes(input))
+    }
+
+    /// Blocking version of [`AppMesh::list_meshes`].
+    pub fn list_meshes(
+        &self,
+        input: ListMeshesInput,
+    ) -> Result<ListMeshesOutput, RusotoError<ListMeshesError>> {
+        self.runtime.block_on(self.client.list_meshes(input))
+    }
+
+    /// Blocking version of [`AppMesh::list_routes`].
+    pub fn list_routes(
+        &self,
+        input: ListRoutesInput,
+    ) -> Result<ListRoutesOutput, RusotoError<ListRoutesError>> {
+        self.runtime.block_on(self.client.list_routes(input))
+    }
+
+    /// Blocking version of [`AppMesh::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`AppMesh::list_virtual_gateways`].
+    pub fn list_virtual_gateways(
+        &self,
+        input: ListVirtualGatewaysInput,
+    ) -> Result<ListVirtualGatewaysOutput, RusotoError<ListVirtualGatewaysError>> {
+        self.runtime
+            .block_on(self.client.list_virtual_gateways(input))
+    }
+
+    /// Blocking version of [`AppMesh::list_virtual_nodes`].
+    pub fn list_virtual_nodes(
+        &self,
+        input: ListVirtualNodesInput,
+    ) -> Result<ListVirtualNodesOutput, RusotoError<ListVirtualNodesError>> {
+        self.runtime.block_on(self.client.list_virtual_nodes(input))
+    }
+
+    /// Blocking version of [`AppMesh::list_virtual_routers`].
+    pub fn list_virtual_routers(
+        &self,
+        input: ListVirtualRoutersInput,
+    ) -> Result<ListVirtualRoutersOutput, RusotoError<ListVirtualRoutersError>> {
+        self.runtime
+            .block_on(self.client.list_virtual_routers(input))
+    }
+
+    /// Blocking version of [`AppMesh::list_virtual_services`].
+    pub fn list_virtual_services(
+        &self,
+        input: ListVirtualServicesInput,
+    ) -> Result<ListVirtualServicesOutput, RusotoError<ListVirtualServicesError>> {
+        self.runtime
+            .block_on(self.client.list_virtual_services(input))
+    }
+
+    /// Blocking version of [`AppMesh::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`AppMesh::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`AppMesh::update_gateway_route`].
+    pub fn update_gateway_route(
+        &self,
+        input: UpdateGatewayRouteInput,
+    ) -> Result<UpdateGatewayRouteOutput, RusotoError<UpdateGatewayRouteError>> {
+        self.runtime
+            .block_on(self.client.update_gateway_route(input))
+    }
+
+    /// Blocking version of [`AppMesh::update_mesh`].
+    pub fn update_mesh(
+        &self,
+        input: UpdateMeshInput,
+    ) -> Result<UpdateMeshOutput, RusotoError<UpdateMeshError>> {
+        self.runtime.block_on(self.client.update_mesh(input))
+    }
+
+    /// Blocking version of [`AppMesh::update_route`].
+    pub fn update_route(
+        &self,
+        input: UpdateRouteInput,
+    ) -> Result<UpdateRouteOutput, RusotoError<UpdateRouteError>> {
+        self.runtime.block_on(self.client.update_route(input))
+    }
+
+    /// Blocking version of [`AppMesh::update_virtual_gateway`].
+    pub fn update_virtual_gateway(
+        &self,
+        input: UpdateVirtualGatewayInput,
+    ) -> Result<UpdateVirtualGatewayOutput, RusotoError<UpdateVirtualGatewayError>> {
+        self.runtime
+            .block_on(self.client.update_virtual_gateway(input))
+    }
+
+    /// Blocking version of [`AppMesh::update_virtual_node`].
+    pub fn update_virtual_node(
+        &self,
+        input: UpdateVirtualNodeInput,
+    ) -> Result<UpdateVirtualNodeOutput, RusotoError<UpdateVirtualNodeError>> {
+        self.runtime
+            .block_on(self.client.update_virtual_node(input))
+    }
+
+    /// Blocking version of [`AppMesh::update_virtual_router`].
+    pub fn update_virtual_router(
+        &self,
+        input: UpdateVirtualRouterInput,
+    ) -> Result<UpdateVirtualRouterOutput, RusotoError<UpdateVirtualRouterError>> {
+        self.runtime
+            .block_on(self.client.update_virtual_router(input))
+    }
+
+    /// Blocking version of [`AppMesh::update_virtual_service`].
+    pub fn update_virtual_service(
+        &self,
+        input: UpdateVirtualServiceInput,
+    ) -> Result<UpdateVirtualServiceOutput, RusotoError<UpdateVirtualServiceError>> {
+        self.runtime
+            .block_on(self.client.update_virtual_service(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -2041,28 +2042,54 @@ impl AssociateFleetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(AssociateFleetError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateFleetError::ConcurrentModification,
+                    )
                 }
                 "IncompatibleImageException" => {
-                    return RusotoError::Service(AssociateFleetError::IncompatibleImage(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateFleetError::IncompatibleImage,
+                    )
                 }
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(AssociateFleetError::InvalidAccountStatus(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateFleetError::InvalidAccountStatus,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AssociateFleetError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateFleetError::LimitExceeded,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(AssociateFleetError::OperationNotPermitted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateFleetError::OperationNotPermitted,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateFleetError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateFleetError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2097,16 +2124,22 @@ impl BatchAssociateUserStackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
-                        BatchAssociateUserStackError::InvalidParameterCombination(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchAssociateUserStackError::InvalidParameterCombination,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
-                        BatchAssociateUserStackError::OperationNotPermitted(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchAssociateUserStackError::OperationNotPermitted,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2143,16 +2176,22 @@ impl BatchDisassociateUserStackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
-                        BatchDisassociateUserStackError::InvalidParameterCombination(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDisassociateUserStackError::InvalidParameterCombination,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
-                        BatchDisassociateUserStackError::OperationNotPermitted(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDisassociateUserStackError::OperationNotPermitted,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2195,24 +2234,54 @@ impl CopyImageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "IncompatibleImageException" => {
-                    return RusotoError::Service(CopyImageError::IncompatibleImage(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CopyImageError::IncompatibleImage,
+                    )
                 }
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(CopyImageError::InvalidAccountStatus(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CopyImageError::InvalidAccountStatus,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CopyImageError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CopyImageError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CopyImageError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CopyImageError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceNotAvailableException" => {
-                    return RusotoError::Service(CopyImageError::ResourceNotAvailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CopyImageError::ResourceNotAvailable,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CopyImageError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CopyImageError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2255,32 +2324,54 @@ impl CreateDirectoryConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(CreateDirectoryConfigError::InvalidAccountStatus(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDirectoryConfigError::InvalidAccountStatus,
+                    )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(CreateDirectoryConfigError::InvalidRole(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDirectoryConfigError::InvalidRole,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDirectoryConfigError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDirectoryConfigError::LimitExceeded,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(CreateDirectoryConfigError::OperationNotPermitted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDirectoryConfigError::OperationNotPermitted,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateDirectoryConfigError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDirectoryConfigError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDirectoryConfigError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDirectoryConfigError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2333,41 +2424,94 @@ impl CreateFleetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateFleetError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFleetError::ConcurrentModification,
+                    )
                 }
                 "IncompatibleImageException" => {
-                    return RusotoError::Service(CreateFleetError::IncompatibleImage(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFleetError::IncompatibleImage,
+                    )
                 }
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(CreateFleetError::InvalidAccountStatus(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFleetError::InvalidAccountStatus,
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(CreateFleetError::InvalidParameterCombination(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateFleetError::InvalidParameterCombination,
+                    )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(CreateFleetError::InvalidRole(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFleetError::InvalidRole,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateFleetError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFleetError::LimitExceeded,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(CreateFleetError::OperationNotPermitted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFleetError::OperationNotPermitted,
+                    )
                 }
                 "RequestLimitExceededException" => {
-                    return RusotoError::Service(CreateFleetError::RequestLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFleetError::RequestLimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateFleetError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFleetError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceNotAvailableException" => {
-                    return RusotoError::Service(CreateFleetError::ResourceNotAvailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFleetError::ResourceNotAvailable,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateFleetError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFleetError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2425,55 +2569,94 @@ impl CreateImageBuilderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateImageBuilderError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateImageBuilderError::ConcurrentModification,
+                    )
                 }
                 "IncompatibleImageException" => {
-                    return RusotoError::Service(CreateImageBuilderError::IncompatibleImage(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateImageBuilderError::IncompatibleImage,
+                    )
                 }
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(CreateImageBuilderError::InvalidAccountStatus(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateImageBuilderError::InvalidAccountStatus,
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
-                        CreateImageBuilderError::InvalidParameterCombination(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateImageBuilderError::InvalidParameterCombination,
                     )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(CreateImageBuilderError::InvalidRole(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateImageBuilderError::InvalidRole,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateImageBuilderError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateImageBuilderError::LimitExceeded,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(CreateImageBuilderError::OperationNotPermitted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateImageBuilderError::OperationNotPermitted,
+                    )
                 }
                 "RequestLimitExceededException" => {
-                    return RusotoError::Service(CreateImageBuilderError::RequestLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateImageBuilderError::RequestLimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateImageBuilderError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateImageBuilderError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceNotAvailableException" => {
-                    return RusotoError::Service(CreateImageBuilderError::ResourceNotAvailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateImageBuilderError::ResourceNotAvailable,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateImageBuilderError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateImageBuilderError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2517,16 +2700,22 @@ impl CreateImageBuilderStreamingURLError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
-                        CreateImageBuilderStreamingURLError::OperationNotPermitted(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateImageBuilderStreamingURLError::OperationNotPermitted,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        CreateImageBuilderStreamingURLError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateImageBuilderStreamingURLError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2571,29 +2760,62 @@ impl CreateStackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateStackError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStackError::ConcurrentModification,
+                    )
                 }
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(CreateStackError::InvalidAccountStatus(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStackError::InvalidAccountStatus,
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(CreateStackError::InvalidParameterCombination(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateStackError::InvalidParameterCombination,
+                    )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(CreateStackError::InvalidRole(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStackError::InvalidRole,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateStackError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStackError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateStackError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStackError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateStackError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStackError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2633,24 +2855,38 @@ impl CreateStreamingURLError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
-                        CreateStreamingURLError::InvalidParameterCombination(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStreamingURLError::InvalidParameterCombination,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(CreateStreamingURLError::OperationNotPermitted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateStreamingURLError::OperationNotPermitted,
+                    )
                 }
                 "ResourceNotAvailableException" => {
-                    return RusotoError::Service(CreateStreamingURLError::ResourceNotAvailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateStreamingURLError::ResourceNotAvailable,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateStreamingURLError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStreamingURLError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2689,21 +2925,30 @@ impl CreateUsageReportSubscriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(
-                        CreateUsageReportSubscriptionError::InvalidAccountStatus(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUsageReportSubscriptionError::InvalidAccountStatus,
                     )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(CreateUsageReportSubscriptionError::InvalidRole(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateUsageReportSubscriptionError::InvalidRole,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateUsageReportSubscriptionError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateUsageReportSubscriptionError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2743,23 +2988,46 @@ impl CreateUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(CreateUserError::InvalidAccountStatus(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserError::InvalidAccountStatus,
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(CreateUserError::InvalidParameterCombination(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateUserError::InvalidParameterCombination,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateUserError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserError::LimitExceeded,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(CreateUserError::OperationNotPermitted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserError::OperationNotPermitted,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateUserError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserError::ResourceAlreadyExists,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2793,14 +3061,22 @@ impl DeleteDirectoryConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteDirectoryConfigError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDirectoryConfigError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDirectoryConfigError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDirectoryConfigError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2833,15 +3109,30 @@ impl DeleteFleetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteFleetError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFleetError::ConcurrentModification,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteFleetError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFleetError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteFleetError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFleetError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2877,18 +3168,38 @@ impl DeleteImageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteImageError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteImageError::ConcurrentModification,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(DeleteImageError::OperationNotPermitted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteImageError::OperationNotPermitted,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteImageError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteImageError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteImageError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteImageError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2923,19 +3234,30 @@ impl DeleteImageBuilderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteImageBuilderError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteImageBuilderError::ConcurrentModification,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(DeleteImageBuilderError::OperationNotPermitted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteImageBuilderError::OperationNotPermitted,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteImageBuilderError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteImageBuilderError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2967,16 +3289,22 @@ impl DeleteImagePermissionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotAvailableException" => {
-                    return RusotoError::Service(DeleteImagePermissionsError::ResourceNotAvailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteImagePermissionsError::ResourceNotAvailable,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteImagePermissionsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteImagePermissionsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3009,15 +3337,30 @@ impl DeleteStackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteStackError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteStackError::ConcurrentModification,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteStackError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteStackError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteStackError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteStackError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3051,16 +3394,22 @@ impl DeleteUsageReportSubscriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(
-                        DeleteUsageReportSubscriptionError::InvalidAccountStatus(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUsageReportSubscriptionError::InvalidAccountStatus,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteUsageReportSubscriptionError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUsageReportSubscriptionError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3093,9 +3442,14 @@ impl DeleteUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3123,11 +3477,14 @@ impl DescribeDirectoryConfigsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDirectoryConfigsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDirectoryConfigsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3155,9 +3512,14 @@ impl DescribeFleetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeFleetsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeFleetsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3185,11 +3547,14 @@ impl DescribeImageBuildersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeImageBuildersError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeImageBuildersError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3217,11 +3582,14 @@ impl DescribeImagePermissionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeImagePermissionsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeImagePermissionsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3251,14 +3619,22 @@ impl DescribeImagesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(DescribeImagesError::InvalidParameterCombination(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeImagesError::InvalidParameterCombination,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeImagesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeImagesError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3287,11 +3663,14 @@ impl DescribeSessionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
-                        DescribeSessionsError::InvalidParameterCombination(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSessionsError::InvalidParameterCombination,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3319,9 +3698,14 @@ impl DescribeStacksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeStacksError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeStacksError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3353,16 +3737,22 @@ impl DescribeUsageReportSubscriptionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(
-                        DescribeUsageReportSubscriptionsError::InvalidAccountStatus(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUsageReportSubscriptionsError::InvalidAccountStatus,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeUsageReportSubscriptionsError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUsageReportSubscriptionsError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3399,16 +3789,22 @@ impl DescribeUserStackAssociationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
-                        DescribeUserStackAssociationsError::InvalidParameterCombination(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUserStackAssociationsError::InvalidParameterCombination,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
-                        DescribeUserStackAssociationsError::OperationNotPermitted(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUserStackAssociationsError::OperationNotPermitted,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3443,14 +3839,22 @@ impl DescribeUsersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(DescribeUsersError::InvalidParameterCombination(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeUsersError::InvalidParameterCombination,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeUsersError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUsersError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3479,9 +3883,14 @@ impl DisableUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisableUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableUserError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3515,22 +3924,38 @@ impl DisassociateFleetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DisassociateFleetError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateFleetError::ConcurrentModification,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(DisassociateFleetError::OperationNotPermitted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateFleetError::OperationNotPermitted,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DisassociateFleetError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateFleetError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateFleetError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateFleetError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3563,12 +3988,22 @@ impl EnableUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(EnableUserError::InvalidAccountStatus(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableUserError::InvalidAccountStatus,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(EnableUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableUserError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3593,7 +4028,7 @@ impl ExpireSessionError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ExpireSessionError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3615,7 +4050,7 @@ impl ListAssociatedFleetsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListAssociatedFleetsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3637,7 +4072,7 @@ impl ListAssociatedStacksError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListAssociatedStacksError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3663,11 +4098,14 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3709,30 +4147,70 @@ impl StartFleetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(StartFleetError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartFleetError::ConcurrentModification,
+                    )
                 }
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(StartFleetError::InvalidAccountStatus(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartFleetError::InvalidAccountStatus,
+                    )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(StartFleetError::InvalidRole(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartFleetError::InvalidRole,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartFleetError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartFleetError::LimitExceeded,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(StartFleetError::OperationNotPermitted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartFleetError::OperationNotPermitted,
+                    )
                 }
                 "RequestLimitExceededException" => {
-                    return RusotoError::Service(StartFleetError::RequestLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartFleetError::RequestLimitExceeded,
+                    )
                 }
                 "ResourceNotAvailableException" => {
-                    return RusotoError::Service(StartFleetError::ResourceNotAvailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartFleetError::ResourceNotAvailable,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartFleetError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartFleetError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3775,27 +4253,46 @@ impl StartImageBuilderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(StartImageBuilderError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartImageBuilderError::ConcurrentModification,
+                    )
                 }
                 "IncompatibleImageException" => {
-                    return RusotoError::Service(StartImageBuilderError::IncompatibleImage(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartImageBuilderError::IncompatibleImage,
+                    )
                 }
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(StartImageBuilderError::InvalidAccountStatus(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartImageBuilderError::InvalidAccountStatus,
+                    )
                 }
                 "ResourceNotAvailableException" => {
-                    return RusotoError::Service(StartImageBuilderError::ResourceNotAvailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartImageBuilderError::ResourceNotAvailable,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartImageBuilderError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartImageBuilderError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3829,12 +4326,22 @@ impl StopFleetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(StopFleetError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopFleetError::ConcurrentModification,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopFleetError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopFleetError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3867,19 +4374,30 @@ impl StopImageBuilderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(StopImageBuilderError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopImageBuilderError::ConcurrentModification,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(StopImageBuilderError::OperationNotPermitted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopImageBuilderError::OperationNotPermitted,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopImageBuilderError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopImageBuilderError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3913,15 +4431,30 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(TagResourceError::InvalidAccountStatus(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidAccountStatus,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3951,9 +4484,14 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3989,27 +4527,46 @@ impl UpdateDirectoryConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        UpdateDirectoryConfigError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDirectoryConfigError::ConcurrentModification,
                     )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(UpdateDirectoryConfigError::InvalidRole(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDirectoryConfigError::InvalidRole,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(UpdateDirectoryConfigError::OperationNotPermitted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDirectoryConfigError::OperationNotPermitted,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateDirectoryConfigError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDirectoryConfigError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDirectoryConfigError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDirectoryConfigError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4061,41 +4618,94 @@ impl UpdateFleetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateFleetError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFleetError::ConcurrentModification,
+                    )
                 }
                 "IncompatibleImageException" => {
-                    return RusotoError::Service(UpdateFleetError::IncompatibleImage(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFleetError::IncompatibleImage,
+                    )
                 }
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(UpdateFleetError::InvalidAccountStatus(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFleetError::InvalidAccountStatus,
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(UpdateFleetError::InvalidParameterCombination(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateFleetError::InvalidParameterCombination,
+                    )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(UpdateFleetError::InvalidRole(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFleetError::InvalidRole,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateFleetError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFleetError::LimitExceeded,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(UpdateFleetError::OperationNotPermitted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFleetError::OperationNotPermitted,
+                    )
                 }
                 "RequestLimitExceededException" => {
-                    return RusotoError::Service(UpdateFleetError::RequestLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFleetError::RequestLimitExceeded,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateFleetError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFleetError::ResourceInUse,
+                    )
                 }
                 "ResourceNotAvailableException" => {
-                    return RusotoError::Service(UpdateFleetError::ResourceNotAvailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFleetError::ResourceNotAvailable,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateFleetError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFleetError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4137,21 +4747,30 @@ impl UpdateImagePermissionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateImagePermissionsError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateImagePermissionsError::LimitExceeded,
+                    )
                 }
                 "ResourceNotAvailableException" => {
-                    return RusotoError::Service(UpdateImagePermissionsError::ResourceNotAvailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateImagePermissionsError::ResourceNotAvailable,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateImagePermissionsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateImagePermissionsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4197,35 +4816,78 @@ impl UpdateStackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateStackError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateStackError::ConcurrentModification,
+                    )
                 }
                 "IncompatibleImageException" => {
-                    return RusotoError::Service(UpdateStackError::IncompatibleImage(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateStackError::IncompatibleImage,
+                    )
                 }
                 "InvalidAccountStatusException" => {
-                    return RusotoError::Service(UpdateStackError::InvalidAccountStatus(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateStackError::InvalidAccountStatus,
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(UpdateStackError::InvalidParameterCombination(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateStackError::InvalidParameterCombination,
+                    )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(UpdateStackError::InvalidRole(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateStackError::InvalidRole,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateStackError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateStackError::LimitExceeded,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(UpdateStackError::OperationNotPermitted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateStackError::OperationNotPermitted,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateStackError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateStackError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateStackError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateStackError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4548,11 +5210,12 @@ pub struct AppStreamClient {
 impl AppStreamClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> AppStreamClient {
         AppStreamClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "AppStream"),
         }
     }
 
@@ -4567,12 +5230,32 @@ impl AppStreamClient {
     {
         AppStreamClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "AppStream"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> AppStreamClient {
-        AppStreamClient { client, region }
+        AppStreamClient {
+            client,
+            region: region_for_service(region, "AppStream"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> AppStreamClient {
+        AppStreamClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("AppStream"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> AppStreamClient {
+        AppStreamClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -4584,6 +5267,7 @@ impl AppStream for AppStreamClient {
         input: AssociateFleetRequest,
     ) -> Result<AssociateFleetResult, RusotoError<AssociateFleetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateFleet");
         request.add_header("x-amz-target", "PhotonAdminProxyService.AssociateFleet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4602,6 +5286,7 @@ impl AppStream for AppStreamClient {
         input: BatchAssociateUserStackRequest,
     ) -> Result<BatchAssociateUserStackResult, RusotoError<BatchAssociateUserStackError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchAssociateUserStack");
         request.add_header(
             "x-amz-target",
             "PhotonAdminProxyService.BatchAssociateUserStack",
@@ -4625,6 +5310,7 @@ impl AppStream for AppStreamClient {
     ) -> Result<BatchDisassociateUserStackResult, RusotoError<BatchDisassociateUserStackError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchDisassociateUserStack");
         request.add_header(
             "x-amz-target",
             "PhotonAdminProxyService.BatchDisassociateUserStack",
@@ -4647,6 +5333,7 @@ impl AppStream for AppStreamClient {
         input: CopyImageRequest,
     ) -> Result<CopyImageResponse, RusotoError<CopyImageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CopyImage");
         request.add_header("x-amz-target", "PhotonAdminProxyService.CopyImage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4665,6 +5352,7 @@ impl AppStream for AppStreamClient {
         input: CreateDirectoryConfigRequest,
     ) -> Result<CreateDirectoryConfigResult, RusotoError<CreateDirectoryConfigError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateDirectoryConfig");
         request.add_header(
             "x-amz-target",
             "PhotonAdminProxyService.CreateDirectoryConfig",
@@ -4686,6 +5374,7 @@ impl AppStream for AppStreamClient {
         input: CreateFleetRequest,
     ) -> Result<CreateFleetResult, RusotoError<CreateFleetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateFleet");
         request.add_header("x-amz-target", "PhotonAdminProxyService.CreateFleet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4704,6 +5393,7 @@ impl AppStream for AppStreamClient {
         input: CreateImageBuilderRequest,
     ) -> Result<CreateImageBuilderResult, RusotoError<CreateImageBuilderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateImageBuilder");
         request.add_header("x-amz-target", "PhotonAdminProxyService.CreateImageBuilder");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4725,6 +5415,7 @@ impl AppStream for AppStreamClient {
         RusotoError<CreateImageBuilderStreamingURLError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateImageBuilderStreamingURL");
         request.add_header(
             "x-amz-target",
             "PhotonAdminProxyService.CreateImageBuilderStreamingURL",
@@ -4747,6 +5438,7 @@ impl AppStream for AppStreamClient {
         input: CreateStackRequest,
     ) -> Result<CreateStackResult, RusotoError<CreateStackError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateStack");
         request.add_header("x-amz-target", "PhotonAdminProxyService.CreateStack");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4765,6 +5457,7 @@ impl AppStream for AppStreamClient {
         input: CreateStreamingURLRequest,
     ) -> Result<CreateStreamingURLResult, RusotoError<CreateStreamingURLError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateStreamingURL");
         request.add_header("x-amz-target", "PhotonAdminProxyService.CreateStreamingURL");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4783,6 +5476,7 @@ impl AppStream for AppStreamClient {
     ) -> Result<CreateUsageReportSubscriptionResult, RusotoError<CreateUsageReportSubscriptionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateUsageReportSubscription");
         request.add_header(
             "x-amz-target",
             "PhotonAdminProxyService.CreateUsageReportSubscription",
@@ -4804,6 +5498,7 @@ impl AppStream for AppStreamClient {
         input: CreateUserRequest,
     ) -> Result<CreateUserResult, RusotoError<CreateUserError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateUser");
         request.add_header("x-amz-target", "PhotonAdminProxyService.CreateUser");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4822,6 +5517,7 @@ impl AppStream for AppStreamClient {
         input: DeleteDirectoryConfigRequest,
     ) -> Result<DeleteDirectoryConfigResult, RusotoError<DeleteDirectoryConfigError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteDirectoryConfig");
         request.add_header(
             "x-amz-target",
             "PhotonAdminProxyService.DeleteDirectoryConfig",
@@ -4843,6 +5539,7 @@ impl AppStream for AppStreamClient {
         input: DeleteFleetRequest,
     ) -> Result<DeleteFleetResult, RusotoError<DeleteFleetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteFleet");
         request.add_header("x-amz-target", "PhotonAdminProxyService.DeleteFleet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4861,6 +5558,7 @@ impl AppStream for AppStreamClient {
         input: DeleteImageRequest,
     ) -> Result<DeleteImageResult, RusotoError<DeleteImageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteImage");
         request.add_header("x-amz-target", "PhotonAdminProxyService.DeleteImage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4879,6 +5577,7 @@ impl AppStream for AppStreamClient {
         input: DeleteImageBuilderRequest,
     ) -> Result<DeleteImageBuilderResult, RusotoError<DeleteImageBuilderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteImageBuilder");
         request.add_header("x-amz-target", "PhotonAdminProxyService.DeleteImageBuilder");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4897,6 +5596,7 @@ impl AppStream for AppStreamClient {
         input: DeleteImagePermissionsRequest,
     ) -> Result<DeleteImagePermissionsResult, RusotoError<DeleteImagePermissionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteImagePermissions");
         request.add_header(
             "x-amz-target",
             "PhotonAdminProxyService.DeleteImagePermissions",
@@ -4919,6 +5619,7 @@ impl AppStream for AppStreamClient {
         input: DeleteStackRequest,
     ) -> Result<DeleteStackResult, RusotoError<DeleteStackError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteStack");
         request.add_header("x-amz-target", "PhotonAdminProxyService.DeleteStack");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4937,6 +5638,7 @@ impl AppStream for AppStreamClient {
     ) -> Result<DeleteUsageReportSubscriptionResult, RusotoError<DeleteUsageReportSubscriptionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteUsageReportSubscription");
         request.add_header(
             "x-amz-target",
             "PhotonAdminProxyService.DeleteUsageReportSubscription",
@@ -4958,6 +5660,7 @@ impl AppStream for AppStreamClient {
         input: DeleteUserRequest,
     ) -> Result<DeleteUserResult, RusotoError<DeleteUserError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteUser");
         request.add_header("x-amz-target", "PhotonAdminProxyService.DeleteUser");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4976,6 +5679,7 @@ impl AppStream for AppStreamClient {
         input: DescribeDirectoryConfigsRequest,
     ) -> Result<DescribeDirectoryConfigsResult, RusotoError<DescribeDirectoryConfigsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeDirectoryConfigs");
         request.add_header(
             "x-amz-target",
             "PhotonAdminProxyService.DescribeDirectoryConfigs",
@@ -4998,6 +5702,7 @@ impl AppStream for AppStreamClient {
         input: DescribeFleetsRequest,
     ) -> Result<DescribeFleetsResult, RusotoError<DescribeFleetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeFleets");
         request.add_header("x-amz-target", "PhotonAdminProxyService.DescribeFleets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5016,6 +5721,7 @@ impl AppStream for AppStreamClient {
         input: DescribeImageBuildersRequest,
     ) -> Result<DescribeImageBuildersResult, RusotoError<DescribeImageBuildersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeImageBuilders");
         request.add_header(
             "x-amz-target",
             "PhotonAdminProxyService.DescribeImageBuilders",
@@ -5037,6 +5743,7 @@ impl AppStream for AppStreamClient {
         input: DescribeImagePermissionsRequest,
     ) -> Result<DescribeImagePermissionsResult, RusotoError<DescribeImagePermissionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeImagePermissions");
         request.add_header(
             "x-amz-target",
             "PhotonAdminProxyService.DescribeImagePermissions",
@@ -5059,6 +5766,7 @@ impl AppStream for AppStreamClient {
         input: DescribeImagesRequest,
     ) -> Result<DescribeImagesResult, RusotoError<DescribeImagesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeImages");
         request.add_header("x-amz-target", "PhotonAdminProxyService.DescribeImages");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5077,6 +5785,7 @@ impl AppStream for AppStreamClient {
         input: DescribeSessionsRequest,
     ) -> Result<DescribeSessionsResult, RusotoError<DescribeSessionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeSessions");
         request.add_header("x-amz-target", "PhotonAdminProxyService.DescribeSessions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5095,6 +5804,7 @@ impl AppStream for AppStreamClient {
         input: DescribeStacksRequest,
     ) -> Result<DescribeStacksResult, RusotoError<DescribeStacksError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeStacks");
         request.add_header("x-amz-target", "PhotonAdminProxyService.DescribeStacks");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5116,6 +5826,7 @@ impl AppStream for AppStreamClient {
         RusotoError<DescribeUsageReportSubscriptionsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeUsageReportSubscriptions");
         request.add_header(
             "x-amz-target",
             "PhotonAdminProxyService.DescribeUsageReportSubscriptions",
@@ -5142,6 +5853,7 @@ impl AppStream for AppStreamClient {
     ) -> Result<DescribeUserStackAssociationsResult, RusotoError<DescribeUserStackAssociationsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeUserStackAssociations");
         request.add_header(
             "x-amz-target",
             "PhotonAdminProxyService.DescribeUserStackAssociations",
@@ -5164,6 +5876,7 @@ impl AppStream for AppStreamClient {
         input: DescribeUsersRequest,
     ) -> Result<DescribeUsersResult, RusotoError<DescribeUsersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeUsers");
         request.add_header("x-amz-target", "PhotonAdminProxyService.DescribeUsers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5182,6 +5895,7 @@ impl AppStream for AppStreamClient {
         input: DisableUserRequest,
     ) -> Result<DisableUserResult, RusotoError<DisableUserError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisableUser");
         request.add_header("x-amz-target", "PhotonAdminProxyService.DisableUser");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5200,6 +5914,7 @@ impl AppStream for AppStreamClient {
         input: DisassociateFleetRequest,
     ) -> Result<DisassociateFleetResult, RusotoError<DisassociateFleetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisassociateFleet");
         request.add_header("x-amz-target", "PhotonAdminProxyService.DisassociateFleet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5218,6 +5933,7 @@ impl AppStream for AppStreamClient {
         input: EnableUserRequest,
     ) -> Result<EnableUserResult, RusotoError<EnableUserError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("EnableUser");
         request.add_header("x-amz-target", "PhotonAdminProxyService.EnableUser");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5236,6 +5952,7 @@ impl AppStream for AppStreamClient {
         input: ExpireSessionRequest,
     ) -> Result<ExpireSessionResult, RusotoError<ExpireSessionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ExpireSession");
         request.add_header("x-amz-target", "PhotonAdminProxyService.ExpireSession");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5254,6 +5971,7 @@ impl AppStream for AppStreamClient {
         input: ListAssociatedFleetsRequest,
     ) -> Result<ListAssociatedFleetsResult, RusotoError<ListAssociatedFleetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListAssociatedFleets");
         request.add_header(
             "x-amz-target",
             "PhotonAdminProxyService.ListAssociatedFleets",
@@ -5275,6 +5993,7 @@ impl AppStream for AppStreamClient {
         input: ListAssociatedStacksRequest,
     ) -> Result<ListAssociatedStacksResult, RusotoError<ListAssociatedStacksError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListAssociatedStacks");
         request.add_header(
             "x-amz-target",
             "PhotonAdminProxyService.ListAssociatedStacks",
@@ -5296,6 +6015,7 @@ impl AppStream for AppStreamClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header(
             "x-amz-target",
             "PhotonAdminProxyService.ListTagsForResource",
@@ -5317,6 +6037,7 @@ impl AppStream for AppStreamClient {
         input: StartFleetRequest,
     ) -> Result<StartFleetResult, RusotoError<StartFleetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartFleet");
         request.add_header("x-amz-target", "PhotonAdminProxyService.StartFleet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5335,6 +6056,7 @@ impl AppStream for AppStreamClient {
         input: StartImageBuilderRequest,
     ) -> Result<StartImageBuilderResult, RusotoError<StartImageBuilderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartImageBuilder");
         request.add_header("x-amz-target", "PhotonAdminProxyService.StartImageBuilder");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5353,6 +6075,7 @@ impl AppStream for AppStreamClient {
         input: StopFleetRequest,
     ) -> Result<StopFleetResult, RusotoError<StopFleetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopFleet");
         request.add_header("x-amz-target", "PhotonAdminProxyService.StopFleet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5371,6 +6094,7 @@ impl AppStream for AppStreamClient {
         input: StopImageBuilderRequest,
     ) -> Result<StopImageBuilderResult, RusotoError<StopImageBuilderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopImageBuilder");
         request.add_header("x-amz-target", "PhotonAdminProxyService.StopImageBuilder");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5389,6 +6113,7 @@ impl AppStream for AppStreamClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "PhotonAdminProxyService.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5407,6 +6132,7 @@ impl AppStream for AppStreamClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "PhotonAdminProxyService.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5425,6 +6151,7 @@ impl AppStream for AppStreamClient {
         input: UpdateDirectoryConfigRequest,
     ) -> Result<UpdateDirectoryConfigResult, RusotoError<UpdateDirectoryConfigError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateDirectoryConfig");
         request.add_header(
             "x-amz-target",
             "PhotonAdminProxyService.UpdateDirectoryConfig",
@@ -5446,6 +6173,7 @@ impl AppStream for AppStreamClient {
         input: UpdateFleetRequest,
     ) -> Result<UpdateFleetResult, RusotoError<UpdateFleetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateFleet");
         request.add_header("x-amz-target", "PhotonAdminProxyService.UpdateFleet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5464,6 +6192,7 @@ impl AppStream for AppStreamClient {
         input: UpdateImagePermissionsRequest,
     ) -> Result<UpdateImagePermissionsResult, RusotoError<UpdateImagePermissionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateImagePermissions");
         request.add_header(
             "x-amz-target",
             "PhotonAdminProxyService.UpdateImagePermissions",
@@ -5486,6 +6215,7 @@ impl AppStream for AppStreamClient {
         input: UpdateStackRequest,
     ) -> Result<UpdateStackResult, RusotoError<UpdateStackError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateStack");
         request.add_header("x-amz-target", "PhotonAdminProxyService.UpdateStack");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5498,3 +6228,484 @@ impl AppStream for AppStreamClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateStackResult, _>()
     }
 }
+
+/// A blocking client for the Amazon AppStream API, running the calls of a `AppStreamClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `AppStreamClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct AppStreamBlockingClient {
+    client: AppStreamClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl AppStreamBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> AppStreamBlockingClient {
+        AppStreamBlockingClient::from_client(AppStreamClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> AppStreamBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        AppStreamBlockingClient::from_client(AppStreamClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> AppStreamBlockingClient {
+        AppStreamBlockingClient::from_client(AppStreamClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> AppStreamBlockingClient {
+        AppStreamBlockingClient::from_client(AppStreamClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: AppStreamClient) -> AppStreamBlockingClient {
+        AppStreamBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> AppStreamBlockingClient {
+        AppStreamBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> AppStreamBlockingClient {
+        AppStreamBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`AppStream::associate_fleet`].
+    pub fn associate_fleet(
+        &self,
+        input: AssociateFleetRequest,
+    ) -> Result<AssociateFleetResult, RusotoError<AssociateFleetError>> {
+        self.runtime.block_on(self.client.associate_fleet(input))
+    }
+
+    /// Blocking version of [`AppStream::batch_associate_user_stack`].
+    pub fn batch_associate_user_stack(
+        &self,
+        input: BatchAssociateUserStackRequest,
+    ) -> Result<BatchAssociateUserStackResult, RusotoError<BatchAssociateUserStackError>> {
+        self.runtime
+            .block_on(self.client.batch_associate_user_stack(input))
+    }
+
+    /// Blocking version of [`AppStream::batch_disassociate_user_stack`].
+    pub fn batch_disassociate_user_stack(
+        &self,
+        input: BatchDisassociateUserStackRequest,
+    ) -> Result<BatchDisassociateUserStackResult, RusotoError<BatchDisassociateUserStackError>>
+    {
+        self.runtime
+            .block_on(self.client.batch_disassociate_user_stack(input))
+    }
+
+    /// Blocking version of [`AppStream::copy_image`].
+    pub fn copy_image(
+        &self,
+        input: CopyImageRequest,
+    ) -> Result<CopyImageResponse, RusotoError<CopyImageError>> {
+        self.runtime.block_on(self.client.copy_image(input))
+    }
+
+    /// Blocking version of [`AppStream::create_directory_config`].
+    pub fn create_directory_config(
+        &self,
+        input: CreateDirectoryConfigRequest,
+    ) -> Result<CreateDirectoryConfigResult, RusotoError<CreateDirectoryConfigError>> {
+        self.runtime
+            .block_on(self.client.create_directory_config(input))
+    }
+
+    /// Blocking version of [`AppStream::create_fleet`].
+    pub fn create_fleet(
+        &self,
+        input: CreateFleetRequest,
+    ) -> Result<CreateFleetResult, RusotoError<CreateFleetError>> {
+        self.runtime.block_on(self.client.create_fleet(input))
+    }
+
+    /// Blocking version of [`AppStream::create_image_builder`].
+    pub fn create_image_builder(
+        &self,
+        input: CreateImageBuilderRequest,
+    ) -> Result<CreateImageBuilderResult, RusotoError<CreateImageBuilderError>> {
+        self.runtime
+            .block_on(self.client.create_image_builder(input))
+    }
+
+    /// Blocking version of [`AppStream::create_image_builder_streaming_url`].
+    pub fn create_image_builder_streaming_url(
+        &self,
+        input: CreateImageBuilderStreamingURLRequest,
+    ) -> Result<
+        CreateImageBuilderStreamingURLResult,
+        RusotoError<CreateImageBuilderStreamingURLError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_image_builder_streaming_url(input))
+    }
+
+    /// Blocking version of [`AppStream::create_stack`].
+    pub fn create_stack(
+        &self,
+        input: CreateStackRequest,
+    ) -> Result<CreateStackResult, RusotoError<CreateStackError>> {
+        self.runtime.block_on(self.client.create_stack(input))
+    }
+
+    /// Blocking version of [`AppStream::create_streaming_url`].
+    pub fn create_streaming_url(
+        &self,
+        input: CreateStreamingURLRequest,
+    ) -> Result<CreateStreamingURLResult, RusotoError<CreateStreamingURLError>> {
+        self.runtime
+            .block_on(self.client.create_streaming_url(input))
+    }
+
+    /// Blocking version of [`AppStream::create_usage_report_subscription`].
+    pub fn create_usage_report_subscription(
+        &self,
+    ) -> Result<CreateUsageReportSubscriptionResult, RusotoError<CreateUsageReportSubscriptionError>>
+    {
+        self.runtime
+            .block_on(self.client.create_usage_report_subscription())
+    }
+
+    /// Blocking version of [`AppStream::create_user`].
+    pub fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> Result<CreateUserResult, RusotoError<CreateUserError>> {
+        self.runtime.block_on(self.client.create_user(input))
+    }
+
+    /// Blocking version of [`AppStream::delete_directory_config`].
+    pub fn delete_directory_config(
+        &self,
+        input: DeleteDirectoryConfigRequest,
+    ) -> Result<DeleteDirectoryConfigResult, RusotoError<DeleteDirectoryConfigError>> {
+        self.runtime
+            .block_on(self.client.delete_directory_config(input))
+    }
+
+    /// Blocking version of [`AppStream::delete_fleet`].
+    pub fn delete_fleet(
+        &self,
+        input: DeleteFleetRequest,
+    ) -> Result<DeleteFleetResult, RusotoError<DeleteFleetError>> {
+        self.runtime.block_on(self.client.delete_fleet(input))
+    }
+
+    /// Blocking version of [`AppStream::delete_image`].
+    pub fn delete_image(
+        &self,
+        input: DeleteImageRequest,
+    ) -> Result<DeleteImageResult, RusotoError<DeleteImageError>> {
+        self.runtime.block_on(self.client.delete_image(input))
+    }
+
+    /// Blocking version of [`AppStream::delete_image_builder`].
+    pub fn delete_image_builder(
+        &self,
+        input: DeleteImageBuilderRequest,
+    ) -> Result<DeleteImageBuilderResult, RusotoError<DeleteImageBuilderError>> {
+        self.runtime
+            .block_on(self.client.delete_image_builder(input))
+    }
+
+    /// Blocking version of [`AppStream::delete_image_permissions`].
+    pub fn delete_image_permissions(
+        &self,
+        input: DeleteImagePermissionsRequest,
+    ) -> Result<DeleteImagePermissionsResult, RusotoError<DeleteImagePermissionsError>> {
+        self.runtime
+            .block_on(self.client.delete_image_permissions(input))
+    }
+
+    /// Blocking version of [`AppStream::delete_stack`].
+    pub fn delete_stack(
+        &self,
+        input: DeleteStackRequest,
+    ) -> Result<DeleteStackResult, RusotoError<DeleteStackError>> {
+        self.runtime.block_on(self.client.delete_stack(input))
+    }
+
+    /// Blocking version of [`AppStream::delete_usage_report_subscription`].
+    pub fn delete_usage_report_subscription(
+        &self,
+    ) -> Result<DeleteUsageReportSubscriptionResult, RusotoError<DeleteUsageReportSubscriptionError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_usage_report_subscription())
+    }
+
+    /// Blocking version of [`AppStream::delete_user`].
+    pub fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> Result<DeleteUserResult, RusotoError<DeleteUserError>> {
+        self.runtime.block_on(self.client.delete_user(input))
+    }
+
+    /// Blocking version of [`AppStream::describe_directory_configs`].
+    pub fn describe_directory_configs(
+        &self,
+        input: DescribeDirectoryConfigsRequest,
+    ) -> Result<DescribeDirectoryConfigsResult, RusotoError<DescribeDirectoryConfigsError>> {
+        self.runtime
+            .block_on(self.client.describe_directory_configs(input))
+    }
+
+    /// Blocking version of [`AppStream::describe_fleets`].
+    pub fn describe_fleets(
+        &self,
+        input: DescribeFleetsRequest,
+    ) -> Result<DescribeFleetsResult, RusotoError<DescribeFleetsError>> {
+        self.runtime.block_on(self.client.describe_fleets(input))
+    }
+
+    /// Blocking version of [`AppStream::describe_image_builders`].
+    pub fn describe_image_builders(
+        &self,
+        input: DescribeImageBuildersRequest,
+    ) -> Result<DescribeImageBuildersResult, RusotoError<DescribeImageBuildersError>> {
+        self.runtime
+            .block_on(self.client.describe_image_builders(input))
+    }
+
+    /// Blocking version of [`AppStream::describe_image_permissions`].
+    pub fn describe_image_permissions(
+        &self,
+        input: DescribeImagePermissionsRequest,
+    ) -> Result<DescribeImagePermissionsResult, RusotoError<DescribeImagePermissionsError>> {
+        self.runtime
+            .block_on(self.client.describe_image_permissions(input))
+    }
+
+    /// Blocking version of [`AppStream::describe_images`].
+    pub fn describe_images(
+        &self,
+        input: DescribeImagesRequest,
+    ) -> Result<DescribeImagesResult, RusotoError<DescribeImagesError>> {
+        self.runtime.block_on(self.client.describe_images(input))
+    }
+
+    /// Blocking version of [`AppStream::describe_sessions`].
+    pub fn describe_sessions(
+        &self,
+        input: DescribeSessionsRequest,
+    ) -> Result<DescribeSessionsResult, RusotoError<DescribeSessionsError>> {
+        self.runtime.block_on(self.client.describe_sessions(input))
+    }
+
+    /// Blocking version of [`AppStream::describe_stacks`].
+    pub fn describe_stacks(
+        &self,
+        input: DescribeStacksRequest,
+    ) -> Result<DescribeStacksResult, RusotoError<DescribeStacksError>> {
+        self.runtime.block_on(self.client.describe_stacks(input))
+    }
+
+    /// Blocking version of [`AppStream::describe_usage_report_subscriptions`].
+    pub fn describe_usage_report_subscriptions(
+        &self,
+        input: DescribeUsageReportSubscriptionsRequest,
+    ) -> Result<
+        DescribeUsageReportSubscriptionsResult,
+        RusotoError<DescribeUsageReportSubscriptionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_usage_report_subscriptions(input))
+    }
+
+    /// Blocking version of [`AppStream::describe_user_stack_associations`].
+    pub fn describe_user_stack_associations(
+        &self,
+        input: DescribeUserStackAssociationsRequest,
+    ) -> Result<DescribeUserStackAssociationsResult, RusotoError<DescribeUserStackAssociationsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_user_stack_associations(input))
+    }
+
+    /// Blocking version of [`AppStream::describe_users`].
+    pub fn describe_users(
+        &self,
+        input: DescribeUsersRequest,
+    ) -> Result<DescribeUsersResult, RusotoError<DescribeUsersError>> {
+        self.runtime.block_on(self.client.describe_users(input))
+    }
+
+    /// Blocking version of [`AppStream::disable_user`].
+    pub fn disable_user(
+        &self,
+        input: DisableUserRequest,
+    ) -> Result<DisableUserResult, RusotoError<DisableUserError>> {
+        self.runtime.block_on(self.client.disable_user(input))
+    }
+
+    /// Blocking version of [`AppStream::disassociate_fleet`].
+    pub fn disassociate_fleet(
+        &self,
+        input: DisassociateFleetRequest,
+    ) -> Result<DisassociateFleetResult, RusotoError<DisassociateFleetError>> {
+        self.runtime.block_on(self.client.disassociate_fleet(input))
+    }
+
+    /// Blocking version of [`AppStream::enable_user`].
+    pub fn enable_user(
+        &self,
+        input: EnableUserRequest,
+    ) -> Result<EnableUserResult, RusotoError<EnableUserError>> {
+        self.runtime.block_on(self.client.enable_user(input))
+    }
+
+    /// Blocking version of [`AppStream::expire_session`].
+    pub fn expire_session(
+        &self,
+        input: ExpireSessionRequest,
+    ) -> Result<ExpireSessionResult, RusotoError<ExpireSessionError>> {
+        self.runtime.block_on(self.client.expire_session(input))
+    }
+
+    /// Blocking version of [`AppStream::list_associated_fleets`].
+    pub fn list_associated_fleets(
+        &self,
+        input: ListAssociatedFleetsRequest,
+    ) -> Result<ListAssociatedFleetsResult, RusotoError<ListAssociatedFleetsError>> {
+        self.runtime
+            .block_on(self.client.list_associated_fleets(input))
+    }
+
+    /// Blocking version of [`AppStream::list_associated_stacks`].
+    pub fn list_associated_stacks(
+        &self,
+        input: ListAssociatedStacksRequest,
+    ) -> Result<ListAssociatedStacksResult, RusotoError<ListAssociatedStacksError>> {
+        self.runtime
+            .block_on(self.client.list_associated_stacks(input))
+    }
+
+    /// Blocking version of [`AppStream::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`AppStream::start_fleet`].
+    pub fn start_fleet(
+        &self,
+        input: StartFleetRequest,
+    ) -> Result<StartFleetResult, RusotoError<StartFleetError>> {
+        self.runtime.block_on(self.client.start_fleet(input))
+    }
+
+    /// Blocking version of [`AppStream::start_image_builder`].
+    pub fn start_image_builder(
+        &self,
+        input: StartImageBuilderRequest,
+    ) -> Result<StartImageBuilderResult, RusotoError<StartImageBuilderError>> {
+        self.runtime
+            .block_on(self.client.start_image_builder(input))
+    }
+
+    /// Blocking version of [`AppStream::stop_fleet`].
+    pub fn stop_fleet(
+        &self,
+        input: StopFleetRequest,
+    ) -> Result<StopFleetResult, RusotoError<StopFleetError>> {
+        self.runtime.block_on(self.client.stop_fleet(input))
+    }
+
+    /// Blocking version of [`AppStream::stop_image_builder`].
+    pub fn stop_image_builder(
+        &self,
+        input: StopImageBuilderRequest,
+    ) -> Result<StopImageBuilderResult, RusotoError<StopImageBuilderError>> {
+        self.runtime.block_on(self.client.stop_image_builder(input))
+    }
+
+    /// Blocking version of [`AppStream::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`AppStream::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`AppStream::update_directory_config`].
+    pub fn update_directory_config(
+        &self,
+        input: UpdateDirectoryConfigRequest,
+    ) -> Result<UpdateDirectoryConfigResult, RusotoError<UpdateDirectoryConfigError>> {
+        self.runtime
+            .block_on(self.client.update_directory_config(input))
+    }
+
+    /// Blocking version of [`AppStream::update_fleet`].
+    pub fn update_fleet(
+        &self,
+        input: UpdateFleetRequest,
+    ) -> Result<UpdateFleetResult, RusotoError<UpdateFleetError>> {
+        self.runtime.block_on(self.client.update_fleet(input))
+    }
+
+    /// Blocking version of [`AppStream::update_image_permissions`].
+    pub fn update_image_permissions(
+        &self,
+        input: UpdateImagePermissionsRequest,
+    ) -> Result<UpdateImagePermissionsResult, RusotoError<UpdateImagePermissionsError>> {
+        self.runtime
+            .block_on(self.client.update_image_permissions(input))
+    }
+
+    /// Blocking version of [`AppStream::update_stack`].
+    pub fn update_stack(
+        &self,
+        input: UpdateStackRequest,
+    ) -> Result<UpdateStackResult, RusotoError<UpdateStackError>> {
+        self.runtime.block_on(self.client.update_stack(input))
+    }
+}
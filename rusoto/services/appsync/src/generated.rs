@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1676,23 +1677,46 @@ impl CreateApiCacheError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateApiCacheError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApiCacheError::BadRequest,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateApiCacheError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateApiCacheError::ConcurrentModification,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateApiCacheError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApiCacheError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateApiCacheError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApiCacheError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateApiCacheError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApiCacheError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1736,29 +1760,62 @@ impl CreateApiKeyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ApiKeyLimitExceededException" => {
-                    return RusotoError::Service(CreateApiKeyError::ApiKeyLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApiKeyError::ApiKeyLimitExceeded,
+                    )
                 }
                 "ApiKeyValidityOutOfBoundsException" => {
-                    return RusotoError::Service(CreateApiKeyError::ApiKeyValidityOutOfBounds(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateApiKeyError::ApiKeyValidityOutOfBounds,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateApiKeyError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApiKeyError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateApiKeyError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApiKeyError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateApiKeyError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApiKeyError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateApiKeyError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApiKeyError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateApiKeyError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApiKeyError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1800,23 +1857,46 @@ impl CreateDataSourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDataSourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataSourceError::BadRequest,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateDataSourceError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDataSourceError::ConcurrentModification,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateDataSourceError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataSourceError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDataSourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataSourceError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateDataSourceError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataSourceError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1854,20 +1934,38 @@ impl CreateFunctionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateFunctionError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateFunctionError::ConcurrentModification,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateFunctionError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFunctionError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateFunctionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFunctionError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateFunctionError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFunctionError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1908,26 +2006,54 @@ impl CreateGraphqlApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ApiLimitExceededException" => {
-                    return RusotoError::Service(CreateGraphqlApiError::ApiLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGraphqlApiError::ApiLimitExceeded,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateGraphqlApiError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGraphqlApiError::BadRequest,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateGraphqlApiError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateGraphqlApiError::ConcurrentModification,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateGraphqlApiError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGraphqlApiError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateGraphqlApiError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGraphqlApiError::LimitExceeded,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateGraphqlApiError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGraphqlApiError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1966,20 +2092,38 @@ impl CreateResolverError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateResolverError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateResolverError::ConcurrentModification,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateResolverError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResolverError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateResolverError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResolverError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateResolverError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResolverError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2018,21 +2162,46 @@ impl CreateTypeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateTypeError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTypeError::BadRequest,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateTypeError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTypeError::ConcurrentModification,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateTypeError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTypeError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateTypeError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTypeError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateTypeError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTypeError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2072,23 +2241,46 @@ impl DeleteApiCacheError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteApiCacheError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApiCacheError::BadRequest,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteApiCacheError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteApiCacheError::ConcurrentModification,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteApiCacheError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApiCacheError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteApiCacheError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApiCacheError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteApiCacheError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApiCacheError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2126,18 +2318,38 @@ impl DeleteApiKeyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteApiKeyError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApiKeyError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteApiKeyError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApiKeyError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteApiKeyError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApiKeyError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteApiKeyError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApiKeyError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2176,23 +2388,46 @@ impl DeleteDataSourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteDataSourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDataSourceError::BadRequest,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteDataSourceError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDataSourceError::ConcurrentModification,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteDataSourceError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDataSourceError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDataSourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDataSourceError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteDataSourceError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDataSourceError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2230,20 +2465,38 @@ impl DeleteFunctionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteFunctionError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteFunctionError::ConcurrentModification,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteFunctionError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFunctionError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteFunctionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFunctionError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteFunctionError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFunctionError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2284,26 +2537,54 @@ impl DeleteGraphqlApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteGraphqlApiError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGraphqlApiError::AccessDenied,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteGraphqlApiError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGraphqlApiError::BadRequest,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteGraphqlApiError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteGraphqlApiError::ConcurrentModification,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteGraphqlApiError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGraphqlApiError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteGraphqlApiError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGraphqlApiError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteGraphqlApiError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGraphqlApiError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2342,20 +2623,38 @@ impl DeleteResolverError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteResolverError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteResolverError::ConcurrentModification,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteResolverError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteResolverError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteResolverError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteResolverError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteResolverError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteResolverError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2394,21 +2693,46 @@ impl DeleteTypeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteTypeError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTypeError::BadRequest,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteTypeError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTypeError::ConcurrentModification,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteTypeError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTypeError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteTypeError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTypeError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteTypeError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTypeError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2448,23 +2772,46 @@ impl FlushApiCacheError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(FlushApiCacheError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        FlushApiCacheError::BadRequest,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(FlushApiCacheError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        FlushApiCacheError::ConcurrentModification,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(FlushApiCacheError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        FlushApiCacheError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(FlushApiCacheError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        FlushApiCacheError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(FlushApiCacheError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        FlushApiCacheError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2504,21 +2851,46 @@ impl GetApiCacheError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetApiCacheError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetApiCacheError::BadRequest,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(GetApiCacheError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetApiCacheError::ConcurrentModification,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetApiCacheError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetApiCacheError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetApiCacheError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetApiCacheError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetApiCacheError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetApiCacheError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2558,23 +2930,46 @@ impl GetDataSourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDataSourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDataSourceError::BadRequest,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(GetDataSourceError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDataSourceError::ConcurrentModification,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetDataSourceError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDataSourceError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDataSourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDataSourceError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetDataSourceError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDataSourceError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2610,15 +3005,30 @@ impl GetFunctionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(GetFunctionError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFunctionError::ConcurrentModification,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetFunctionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFunctionError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetFunctionError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFunctionError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2656,21 +3066,46 @@ impl GetGraphqlApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetGraphqlApiError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGraphqlApiError::AccessDenied,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(GetGraphqlApiError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGraphqlApiError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetGraphqlApiError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGraphqlApiError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetGraphqlApiError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGraphqlApiError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetGraphqlApiError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGraphqlApiError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2708,22 +3143,38 @@ impl GetIntrospectionSchemaError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "GraphQLSchemaException" => {
-                    return RusotoError::Service(GetIntrospectionSchemaError::GraphQLSchema(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetIntrospectionSchemaError::GraphQLSchema,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetIntrospectionSchemaError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetIntrospectionSchemaError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetIntrospectionSchemaError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIntrospectionSchemaError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetIntrospectionSchemaError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIntrospectionSchemaError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2758,15 +3209,30 @@ impl GetResolverError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(GetResolverError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResolverError::ConcurrentModification,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetResolverError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResolverError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetResolverError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResolverError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2802,22 +3268,38 @@ impl GetSchemaCreationStatusError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetSchemaCreationStatusError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSchemaCreationStatusError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetSchemaCreationStatusError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSchemaCreationStatusError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetSchemaCreationStatusError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSchemaCreationStatusError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetSchemaCreationStatusError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSchemaCreationStatusError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2856,21 +3338,46 @@ impl GetTypeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetTypeError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTypeError::BadRequest,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(GetTypeError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTypeError::ConcurrentModification,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetTypeError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTypeError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetTypeError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTypeError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetTypeError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTypeError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2908,18 +3415,38 @@ impl ListApiKeysError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListApiKeysError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListApiKeysError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListApiKeysError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListApiKeysError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListApiKeysError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListApiKeysError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListApiKeysError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListApiKeysError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2956,18 +3483,38 @@ impl ListDataSourcesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListDataSourcesError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDataSourcesError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListDataSourcesError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDataSourcesError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListDataSourcesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDataSourcesError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListDataSourcesError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDataSourcesError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3004,18 +3551,38 @@ impl ListFunctionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListFunctionsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFunctionsError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListFunctionsError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFunctionsError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListFunctionsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFunctionsError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListFunctionsError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFunctionsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3050,15 +3617,30 @@ impl ListGraphqlApisError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListGraphqlApisError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGraphqlApisError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListGraphqlApisError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGraphqlApisError::InternalFailure,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListGraphqlApisError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGraphqlApisError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3094,18 +3676,38 @@ impl ListResolversError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListResolversError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResolversError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListResolversError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResolversError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListResolversError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResolversError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListResolversError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResolversError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3142,22 +3744,38 @@ impl ListResolversByFunctionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListResolversByFunctionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResolversByFunctionError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListResolversByFunctionError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResolversByFunctionError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListResolversByFunctionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResolversByFunctionError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListResolversByFunctionError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResolversByFunctionError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3198,24 +3816,54 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::AccessDenied,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListTagsForResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3256,21 +3904,46 @@ impl ListTypesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTypesError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTypesError::BadRequest,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(ListTypesError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTypesError::ConcurrentModification,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTypesError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTypesError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTypesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTypesError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListTypesError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTypesError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3310,23 +3983,46 @@ impl StartSchemaCreationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StartSchemaCreationError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartSchemaCreationError::BadRequest,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(StartSchemaCreationError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartSchemaCreationError::ConcurrentModification,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(StartSchemaCreationError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartSchemaCreationError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StartSchemaCreationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartSchemaCreationError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(StartSchemaCreationError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartSchemaCreationError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3368,24 +4064,54 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(TagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::AccessDenied,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(TagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(TagResourceError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3428,24 +4154,54 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UntagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::AccessDenied,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UntagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UntagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UntagResourceError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3486,23 +4242,46 @@ impl UpdateApiCacheError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateApiCacheError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApiCacheError::BadRequest,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateApiCacheError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateApiCacheError::ConcurrentModification,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateApiCacheError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApiCacheError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateApiCacheError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApiCacheError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateApiCacheError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApiCacheError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3544,26 +4323,54 @@ impl UpdateApiKeyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ApiKeyValidityOutOfBoundsException" => {
-                    return RusotoError::Service(UpdateApiKeyError::ApiKeyValidityOutOfBounds(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateApiKeyError::ApiKeyValidityOutOfBounds,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateApiKeyError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApiKeyError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateApiKeyError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApiKeyError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateApiKeyError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApiKeyError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateApiKeyError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApiKeyError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateApiKeyError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApiKeyError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3604,23 +4411,46 @@ impl UpdateDataSourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateDataSourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDataSourceError::BadRequest,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateDataSourceError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDataSourceError::ConcurrentModification,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateDataSourceError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDataSourceError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateDataSourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDataSourceError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateDataSourceError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDataSourceError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3658,20 +4488,38 @@ impl UpdateFunctionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateFunctionError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateFunctionError::ConcurrentModification,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateFunctionError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFunctionError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateFunctionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFunctionError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateFunctionError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFunctionError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3712,26 +4560,54 @@ impl UpdateGraphqlApiError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateGraphqlApiError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGraphqlApiError::AccessDenied,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateGraphqlApiError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGraphqlApiError::BadRequest,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateGraphqlApiError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateGraphqlApiError::ConcurrentModification,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateGraphqlApiError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGraphqlApiError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateGraphqlApiError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGraphqlApiError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateGraphqlApiError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGraphqlApiError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3770,20 +4646,38 @@ impl UpdateResolverError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateResolverError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateResolverError::ConcurrentModification,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateResolverError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateResolverError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateResolverError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateResolverError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateResolverError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateResolverError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3822,21 +4716,46 @@ impl UpdateTypeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateTypeError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTypeError::BadRequest,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateTypeError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTypeError::ConcurrentModification,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateTypeError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTypeError::InternalFailure,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateTypeError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTypeError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateTypeError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTypeError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4115,11 +5034,12 @@ pub struct AppSyncClient {
 impl AppSyncClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> AppSyncClient {
         AppSyncClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "AppSync"),
         }
     }
 
@@ -4134,12 +5054,32 @@ impl AppSyncClient {
     {
         AppSyncClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "AppSync"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> AppSyncClient {
-        AppSyncClient { client, region }
+        AppSyncClient {
+            client,
+            region: region_for_service(region, "AppSync"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> AppSyncClient {
+        AppSyncClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("AppSync"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> AppSyncClient {
+        AppSyncClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -4154,6 +5094,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/ApiCaches", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("CreateApiCache");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4185,6 +5126,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/apikeys", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("CreateApiKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4216,6 +5158,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/datasources", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("CreateDataSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4247,6 +5190,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/functions", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("CreateFunction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4278,6 +5222,7 @@ impl AppSync for AppSyncClient {
         let request_uri = "/v1/apis";
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("CreateGraphqlApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4313,6 +5258,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("CreateResolver");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4344,6 +5290,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/types", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("CreateType");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4375,6 +5322,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/ApiCaches", api_id = input.api_id);
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("DeleteApiCache");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4407,6 +5355,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("DeleteApiKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4439,6 +5388,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("DeleteDataSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4471,6 +5421,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("DeleteFunction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4499,6 +5450,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}", api_id = input.api_id);
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("DeleteGraphqlApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4532,6 +5484,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("DeleteResolver");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4564,6 +5517,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("DeleteType");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4592,6 +5546,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/FlushCache", api_id = input.api_id);
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("FlushApiCache");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4620,6 +5575,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/ApiCaches", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("GetApiCache");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4652,6 +5608,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("GetDataSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4684,6 +5641,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("GetFunction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4712,6 +5670,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("GetGraphqlApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4740,6 +5699,8 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/schema", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("GetIntrospectionSchema");
+        request.set_raw_response(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4781,6 +5742,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("GetResolver");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4809,6 +5771,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/schemacreation", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("GetSchemaCreationStatus");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4841,6 +5804,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("GetType");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4873,6 +5837,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/apikeys", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("ListApiKeys");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4910,6 +5875,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/datasources", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("ListDataSources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4947,6 +5913,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/functions", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("ListFunctions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4984,6 +5951,7 @@ impl AppSync for AppSyncClient {
         let request_uri = "/v1/apis";
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("ListGraphqlApis");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5025,6 +5993,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("ListResolvers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5066,6 +6035,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("ListResolversByFunction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5103,6 +6073,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5131,6 +6102,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/types", api_id = input.api_id);
 
         let mut request = SignedRequest::new("GET", "appsync", &self.region, &request_uri);
+        request.set_operation("ListTypes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5169,6 +6141,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/schemacreation", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("StartSchemaCreation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5200,6 +6173,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5231,6 +6205,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "appsync", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5265,6 +6240,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}/ApiCaches/update", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("UpdateApiCache");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5300,6 +6276,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("UpdateApiKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5335,6 +6312,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("UpdateDataSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5370,6 +6348,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("UpdateFunction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5401,6 +6380,7 @@ impl AppSync for AppSyncClient {
         let request_uri = format!("/v1/apis/{api_id}", api_id = input.api_id);
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("UpdateGraphqlApi");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5437,6 +6417,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("UpdateResolver");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5472,6 +6453,7 @@ impl AppSync for AppSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "appsync", &self.region, &request_uri);
+        request.set_operation("UpdateType");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5494,3 +6476,411 @@ impl AppSync for AppSyncClient {
         }
     }
 }
+
+/// A blocking client for the AWSAppSync API, running the calls of a `AppSyncClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `AppSyncClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct AppSyncBlockingClient {
+    client: AppSyncClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl AppSyncBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> AppSyncBlockingClient {
+        AppSyncBlockingClient::from_client(AppSyncClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> AppSyncBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        AppSyncBlockingClient::from_client(AppSyncClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> AppSyncBlockingClient {
+        AppSyncBlockingClient::from_client(AppSyncClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> AppSyncBlockingClient {
+        AppSyncBlockingClient::from_client(AppSyncClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: AppSyncClient) -> AppSyncBlockingClient {
+        AppSyncBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> AppSyncBlockingClient {
+        AppSyncBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> AppSyncBlockingClient {
+        AppSyncBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`AppSync::create_api_cache`].
+    pub fn create_api_cache(
+        &self,
+        input: CreateApiCacheRequest,
+    ) -> Result<CreateApiCacheResponse, RusotoError<CreateApiCacheError>> {
+        self.runtime.block_on(self.client.create_api_cache(input))
+    }
+
+    /// Blocking version of [`AppSync::create_api_key`].
+    pub fn create_api_key(
+        &self,
+        input: CreateApiKeyRequest,
+    ) -> Result<CreateApiKeyResponse, RusotoError<CreateApiKeyError>> {
+        self.runtime.block_on(self.client.create_api_key(input))
+    }
+
+    /// Blocking version of [`AppSync::create_data_source`].
+    pub fn create_data_source(
+        &self,
+        input: CreateDataSourceRequest,
+    ) -> Result<CreateDataSourceResponse, RusotoError<CreateDataSourceError>> {
+        self.runtime.block_on(self.client.create_data_source(input))
+    }
+
+    /// Blocking version of [`AppSync::create_function`].
+    pub fn create_function(
+        &self,
+        input: CreateFunctionRequest,
+    ) -> Result<CreateFunctionResponse, RusotoError<CreateFunctionError>> {
+        self.runtime.block_on(self.client.create_function(input))
+    }
+
+    /// Blocking version of [`AppSync::create_graphql_api`].
+    pub fn create_graphql_api(
+        &self,
+        input: CreateGraphqlApiRequest,
+    ) -> Result<CreateGraphqlApiResponse, RusotoError<CreateGraphqlApiError>> {
+        self.runtime.block_on(self.client.create_graphql_api(input))
+    }
+
+    /// Blocking version of [`AppSync::create_resolver`].
+    pub fn create_resolver(
+        &self,
+        input: CreateResolverRequest,
+    ) -> Result<CreateResolverResponse, RusotoError<CreateResolverError>> {
+        self.runtime.block_on(self.client.create_resolver(input))
+    }
+
+    /// Blocking version of [`AppSync::create_type`].
+    pub fn create_type(
+        &self,
+        input: CreateTypeRequest,
+    ) -> Result<CreateTypeResponse, RusotoError<CreateTypeError>> {
+        self.runtime.block_on(self.client.create_type(input))
+    }
+
+    /// Blocking version of [`AppSync::delete_api_cache`].
+    pub fn delete_api_cache(
+        &self,
+        input: DeleteApiCacheRequest,
+    ) -> Result<DeleteApiCacheResponse, RusotoError<DeleteApiCacheError>> {
+        self.runtime.block_on(self.client.delete_api_cache(input))
+    }
+
+    /// Blocking version of [`AppSync::delete_api_key`].
+    pub fn delete_api_key(
+        &self,
+        input: DeleteApiKeyRequest,
+    ) -> Result<DeleteApiKeyResponse, RusotoError<DeleteApiKeyError>> {
+        self.runtime.block_on(self.client.delete_api_key(input))
+    }
+
+    /// Blocking version of [`AppSync::delete_data_source`].
+    pub fn delete_data_source(
+        &self,
+        input: DeleteDataSourceRequest,
+    ) -> Result<DeleteDataSourceResponse, RusotoError<DeleteDataSourceError>> {
+        self.runtime.block_on(self.client.delete_data_source(input))
+    }
+
+    /// Blocking version of [`AppSync::delete_function`].
+    pub fn delete_function(
+        &self,
+        input: DeleteFunctionRequest,
+    ) -> Result<DeleteFunctionResponse, RusotoError<DeleteFunctionError>> {
+        self.runtime.block_on(self.client.delete_function(input))
+    }
+
+    /// Blocking version of [`AppSync::delete_graphql_api`].
+    pub fn delete_graphql_api(
+        &self,
+        input: DeleteGraphqlApiRequest,
+    ) -> Result<DeleteGraphqlApiResponse, RusotoError<DeleteGraphqlApiError>> {
+        self.runtime.block_on(self.client.delete_graphql_api(input))
+    }
+
+    /// Blocking version of [`AppSync::delete_resolver`].
+    pub fn delete_resolver(
+        &self,
+        input: DeleteResolverRequest,
+    ) -> Result<DeleteResolverResponse, RusotoError<DeleteResolverError>> {
+        self.runtime.block_on(self.client.delete_resolver(input))
+    }
+
+    /// Blocking version of [`AppSync::delete_type`].
+    pub fn delete_type(
+        &self,
+        input: DeleteTypeRequest,
+    ) -> Result<DeleteTypeResponse, RusotoError<DeleteTypeError>> {
+        self.runtime.block_on(self.client.delete_type(input))
+    }
+
+    /// Blocking version of [`AppSync::flush_api_cache`].
+    pub fn flush_api_cache(
+        &self,
+        input: FlushApiCacheRequest,
+    ) -> Result<FlushApiCacheResponse, RusotoError<FlushApiCacheError>> {
+        self.runtime.block_on(self.client.flush_api_cache(input))
+    }
+
+    /// Blocking version of [`AppSync::get_api_cache`].
+    pub fn get_api_cache(
+        &self,
+        input: GetApiCacheRequest,
+    ) -> Result<GetApiCacheResponse, RusotoError<GetApiCacheError>> {
+        self.runtime.block_on(self.client.get_api_cache(input))
+    }
+
+    /// Blocking version of [`AppSync::get_data_source`].
+    pub fn get_data_source(
+        &self,
+        input: GetDataSourceRequest,
+    ) -> Result<GetDataSourceResponse, RusotoError<GetDataSourceError>> {
+        self.runtime.block_on(self.client.get_data_source(input))
+    }
+
+    /// Blocking version of [`AppSync::get_function`].
+    pub fn get_function(
+        &self,
+        input: GetFunctionRequest,
+    ) -> Result<GetFunctionResponse, RusotoError<GetFunctionError>> {
+        self.runtime.block_on(self.client.get_function(input))
+    }
+
+    /// Blocking version of [`AppSync::get_graphql_api`].
+    pub fn get_graphql_api(
+        &self,
+        input: GetGraphqlApiRequest,
+    ) -> Result<GetGraphqlApiResponse, RusotoError<GetGraphqlApiError>> {
+        self.runtime.block_on(self.client.get_graphql_api(input))
+    }
+
+    /// Blocking version of [`AppSync::get_introspection_schema`].
+    pub fn get_introspection_schema(
+        &self,
+        input: GetIntrospectionSchemaRequest,
+    ) -> Result<GetIntrospectionSchemaResponse, RusotoError<GetIntrospectionSchemaError>> {
+        self.runtime
+            .block_on(self.client.get_introspection_schema(input))
+    }
+
+    /// Blocking version of [`AppSync::get_resolver`].
+    pub fn get_resolver(
+        &self,
+        input: GetResolverRequest,
+    ) -> Result<GetResolverResponse, RusotoError<GetResolverError>> {
+        self.runtime.block_on(self.client.get_resolver(input))
+    }
+
+    /// Blocking version of [`AppSync::get_schema_creation_status`].
+    pub fn get_schema_creation_status(
+        &self,
+        input: GetSchemaCreationStatusRequest,
+    ) -> Result<GetSchemaCreationStatusResponse, RusotoError<GetSchemaCreationStatusError>> {
+        self.runtime
+            .block_on(self.client.get_schema_creation_status(input))
+    }
+
+    /// Blocking version of [`AppSync::get_type`].
+    pub fn get_type(
+        &self,
+        input: GetTypeRequest,
+    ) -> Result<GetTypeResponse, RusotoError<GetTypeError>> {
+        self.runtime.block_on(self.client.get_type(input))
+    }
+
+    /// Blocking version of [`AppSync::list_api_keys`].
+    pub fn list_api_keys(
+        &self,
+        input: ListApiKeysRequest,
+    ) -> Result<ListApiKeysResponse, RusotoError<ListApiKeysError>> {
+        self.runtime.block_on(self.client.list_api_keys(input))
+    }
+
+    /// Blocking version of [`AppSync::list_data_sources`].
+    pub fn list_data_sources(
+        &self,
+        input: ListDataSourcesRequest,
+    ) -> Result<ListDataSourcesResponse, RusotoError<ListDataSourcesError>> {
+        self.runtime.block_on(self.client.list_data_sources(input))
+    }
+
+    /// Blocking version of [`AppSync::list_functions`].
+    pub fn list_functions(
+        &self,
+        input: ListFunctionsRequest,
+    ) -> Result<ListFunctionsResponse, RusotoError<ListFunctionsError>> {
+        self.runtime.block_on(self.client.list_functions(input))
+    }
+
+    /// Blocking version of [`AppSync::list_graphql_apis`].
+    pub fn list_graphql_apis(
+        &self,
+        input: ListGraphqlApisRequest,
+    ) -> Result<ListGraphqlApisResponse, RusotoError<ListGraphqlApisError>> {
+        self.runtime.block_on(self.client.list_graphql_apis(input))
+    }
+
+    /// Blocking version of [`AppSync::list_resolvers`].
+    pub fn list_resolvers(
+        &self,
+        input: ListResolversRequest,
+    ) -> Result<ListResolversResponse, RusotoError<ListResolversError>> {
+        self.runtime.block_on(self.client.list_resolvers(input))
+    }
+
+    /// Blocking version of [`AppSync::list_resolvers_by_function`].
+    pub fn list_resolvers_by_function(
+        &self,
+        input: ListResolversByFunctionRequest,
+    ) -> Result<ListResolversByFunctionResponse, RusotoError<ListResolversByFunctionError>> {
+        self.runtime
+            .block_on(self.client.list_resolvers_by_function(input))
+    }
+
+    /// Blocking version of [`AppSync::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`AppSync::list_types`].
+    pub fn list_types(
+        &self,
+        input: ListTypesRequest,
+    ) -> Result<ListTypesResponse, RusotoError<ListTypesError>> {
+        self.runtime.block_on(self.client.list_types(input))
+    }
+
+    /// Blocking version of [`AppSync::start_schema_creation`].
+    pub fn start_schema_creation(
+        &self,
+        input: StartSchemaCreationRequest,
+    ) -> Result<StartSchemaCreationResponse, RusotoError<StartSchemaCreationError>> {
+        self.runtime
+            .block_on(self.client.start_schema_creation(input))
+    }
+
+    /// Blocking version of [`AppSync::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`AppSync::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`AppSync::update_api_cache`].
+    pub fn update_api_cache(
+        &self,
+        input: UpdateApiCacheRequest,
+    ) -> Result<UpdateApiCacheResponse, RusotoError<UpdateApiCacheError>> {
+        self.runtime.block_on(self.client.update_api_cache(input))
+    }
+
+    /// Blocking version of [`AppSync::update_api_key`].
+    pub fn update_api_key(
+        &self,
+        input: UpdateApiKeyRequest,
+    ) -> Result<UpdateApiKeyResponse, RusotoError<UpdateApiKeyError>> {
+        self.runtime.block_on(self.client.update_api_key(input))
+    }
+
+    /// Blocking version of [`AppSync::update_data_source`].
+    pub fn update_data_source(
+        &self,
+        input: UpdateDataSourceRequest,
+    ) -> Result<UpdateDataSourceResponse, RusotoError<UpdateDataSourceError>> {
+        self.runtime.block_on(self.client.update_data_source(input))
+    }
+
+    /// Blocking version of [`AppSync::update_function`].
+    pub fn update_function(
+        &self,
+        input: UpdateFunctionRequest,
+    ) -> Result<UpdateFunctionResponse, RusotoError<UpdateFunctionError>> {
+        self.runtime.block_on(self.client.update_function(input))
+    }
+
+    /// Blocking version of [`AppSync::update_graphql_api`].
+    pub fn update_graphql_api(
+        &self,
+        input: UpdateGraphqlApiRequest,
+    ) -> Result<UpdateGraphqlApiResponse, RusotoError<UpdateGraphqlApiError>> {
+        self.runtime.block_on(self.client.update_graphql_api(input))
+    }
+
+    /// Blocking version of [`AppSync::update_resolver`].
+    pub fn update_resolver(
+        &self,
+        input: UpdateResolverRequest,
+    ) -> Result<UpdateResolverResponse, RusotoError<UpdateResolverError>> {
+        self.runtime.block_on(self.client.update_resolver(input))
+    }
+
+    /// Blocking version of [`AppSync::update_type`].
+    pub fn update_type(
+        &self,
+        input: UpdateTypeRequest,
+    ) -> Result<UpdateTypeResponse, RusotoError<UpdateTypeError>> {
+        self.runtime.block_on(self.client.update_type(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1225,12 +1226,22 @@ impl BatchGetNamedQueryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(BatchGetNamedQueryError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetNamedQueryError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(BatchGetNamedQueryError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetNamedQueryError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1261,16 +1272,22 @@ impl BatchGetQueryExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(BatchGetQueryExecutionError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchGetQueryExecutionError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(BatchGetQueryExecutionError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchGetQueryExecutionError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1301,12 +1318,22 @@ impl CreateDataCatalogError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateDataCatalogError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataCatalogError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateDataCatalogError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataCatalogError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1337,12 +1364,22 @@ impl CreateNamedQueryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateNamedQueryError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNamedQueryError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateNamedQueryError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNamedQueryError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1373,12 +1410,22 @@ impl CreateWorkGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateWorkGroupError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWorkGroupError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateWorkGroupError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWorkGroupError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1409,12 +1456,22 @@ impl DeleteDataCatalogError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteDataCatalogError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDataCatalogError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteDataCatalogError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDataCatalogError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1445,12 +1502,22 @@ impl DeleteNamedQueryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteNamedQueryError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNamedQueryError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteNamedQueryError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNamedQueryError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1481,12 +1548,22 @@ impl DeleteWorkGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteWorkGroupError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteWorkGroupError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteWorkGroupError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteWorkGroupError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1517,12 +1594,22 @@ impl GetDataCatalogError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetDataCatalogError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDataCatalogError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetDataCatalogError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDataCatalogError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1555,15 +1642,30 @@ impl GetDatabaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetDatabaseError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDatabaseError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetDatabaseError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDatabaseError::InvalidRequest,
+                    )
                 }
                 "MetadataException" => {
-                    return RusotoError::Service(GetDatabaseError::Metadata(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDatabaseError::Metadata,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1595,12 +1697,22 @@ impl GetNamedQueryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetNamedQueryError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetNamedQueryError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetNamedQueryError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetNamedQueryError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1631,12 +1743,22 @@ impl GetQueryExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetQueryExecutionError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetQueryExecutionError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetQueryExecutionError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetQueryExecutionError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1667,12 +1789,22 @@ impl GetQueryResultsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetQueryResultsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetQueryResultsError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetQueryResultsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetQueryResultsError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1705,15 +1837,30 @@ impl GetTableMetadataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetTableMetadataError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTableMetadataError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetTableMetadataError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTableMetadataError::InvalidRequest,
+                    )
                 }
                 "MetadataException" => {
-                    return RusotoError::Service(GetTableMetadataError::Metadata(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTableMetadataError::Metadata,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1745,12 +1892,22 @@ impl GetWorkGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetWorkGroupError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetWorkGroupError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetWorkGroupError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetWorkGroupError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1781,12 +1938,22 @@ impl ListDataCatalogsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListDataCatalogsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDataCatalogsError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListDataCatalogsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDataCatalogsError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1819,15 +1986,30 @@ impl ListDatabasesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListDatabasesError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDatabasesError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListDatabasesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDatabasesError::InvalidRequest,
+                    )
                 }
                 "MetadataException" => {
-                    return RusotoError::Service(ListDatabasesError::Metadata(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDatabasesError::Metadata,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1859,12 +2041,22 @@ impl ListNamedQueriesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListNamedQueriesError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListNamedQueriesError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListNamedQueriesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListNamedQueriesError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1895,12 +2087,22 @@ impl ListQueryExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListQueryExecutionsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListQueryExecutionsError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListQueryExecutionsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListQueryExecutionsError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1933,15 +2135,30 @@ impl ListTableMetadataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListTableMetadataError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTableMetadataError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTableMetadataError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTableMetadataError::InvalidRequest,
+                    )
                 }
                 "MetadataException" => {
-                    return RusotoError::Service(ListTableMetadataError::Metadata(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTableMetadataError::Metadata,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1975,17 +2192,30 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2017,12 +2247,22 @@ impl ListWorkGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListWorkGroupsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListWorkGroupsError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListWorkGroupsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListWorkGroupsError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2055,15 +2295,30 @@ impl StartQueryExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StartQueryExecutionError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartQueryExecutionError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartQueryExecutionError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartQueryExecutionError::InvalidRequest,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartQueryExecutionError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartQueryExecutionError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2095,12 +2350,22 @@ impl StopQueryExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StopQueryExecutionError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopQueryExecutionError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopQueryExecutionError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopQueryExecutionError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2133,15 +2398,30 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(TagResourceError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2175,15 +2455,30 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2215,12 +2510,22 @@ impl UpdateDataCatalogError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateDataCatalogError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDataCatalogError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateDataCatalogError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDataCatalogError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2251,12 +2556,22 @@ impl UpdateWorkGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateWorkGroupError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateWorkGroupError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateWorkGroupError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateWorkGroupError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2454,11 +2769,12 @@ pub struct AthenaClient {
 impl AthenaClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> AthenaClient {
         AthenaClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Athena"),
         }
     }
 
@@ -2473,12 +2789,32 @@ impl AthenaClient {
     {
         AthenaClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Athena"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> AthenaClient {
-        AthenaClient { client, region }
+        AthenaClient {
+            client,
+            region: region_for_service(region, "Athena"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> AthenaClient {
+        AthenaClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Athena"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> AthenaClient {
+        AthenaClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -2490,6 +2826,7 @@ impl Athena for AthenaClient {
         input: BatchGetNamedQueryInput,
     ) -> Result<BatchGetNamedQueryOutput, RusotoError<BatchGetNamedQueryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchGetNamedQuery");
         request.add_header("x-amz-target", "AmazonAthena.BatchGetNamedQuery");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2508,6 +2845,7 @@ impl Athena for AthenaClient {
         input: BatchGetQueryExecutionInput,
     ) -> Result<BatchGetQueryExecutionOutput, RusotoError<BatchGetQueryExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchGetQueryExecution");
         request.add_header("x-amz-target", "AmazonAthena.BatchGetQueryExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2527,6 +2865,7 @@ impl Athena for AthenaClient {
         input: CreateDataCatalogInput,
     ) -> Result<CreateDataCatalogOutput, RusotoError<CreateDataCatalogError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateDataCatalog");
         request.add_header("x-amz-target", "AmazonAthena.CreateDataCatalog");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2545,6 +2884,7 @@ impl Athena for AthenaClient {
         input: CreateNamedQueryInput,
     ) -> Result<CreateNamedQueryOutput, RusotoError<CreateNamedQueryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateNamedQuery");
         request.add_header("x-amz-target", "AmazonAthena.CreateNamedQuery");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2563,6 +2903,7 @@ impl Athena for AthenaClient {
         input: CreateWorkGroupInput,
     ) -> Result<CreateWorkGroupOutput, RusotoError<CreateWorkGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateWorkGroup");
         request.add_header("x-amz-target", "AmazonAthena.CreateWorkGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2581,6 +2922,7 @@ impl Athena for AthenaClient {
         input: DeleteDataCatalogInput,
     ) -> Result<DeleteDataCatalogOutput, RusotoError<DeleteDataCatalogError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteDataCatalog");
         request.add_header("x-amz-target", "AmazonAthena.DeleteDataCatalog");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2599,6 +2941,7 @@ impl Athena for AthenaClient {
         input: DeleteNamedQueryInput,
     ) -> Result<DeleteNamedQueryOutput, RusotoError<DeleteNamedQueryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteNamedQuery");
         request.add_header("x-amz-target", "AmazonAthena.DeleteNamedQuery");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2617,6 +2960,7 @@ impl Athena for AthenaClient {
         input: DeleteWorkGroupInput,
     ) -> Result<DeleteWorkGroupOutput, RusotoError<DeleteWorkGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteWorkGroup");
         request.add_header("x-amz-target", "AmazonAthena.DeleteWorkGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2635,6 +2979,7 @@ impl Athena for AthenaClient {
         input: GetDataCatalogInput,
     ) -> Result<GetDataCatalogOutput, RusotoError<GetDataCatalogError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDataCatalog");
         request.add_header("x-amz-target", "AmazonAthena.GetDataCatalog");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2653,6 +2998,7 @@ impl Athena for AthenaClient {
         input: GetDatabaseInput,
     ) -> Result<GetDatabaseOutput, RusotoError<GetDatabaseError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDatabase");
         request.add_header("x-amz-target", "AmazonAthena.GetDatabase");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2671,6 +3017,7 @@ impl Athena for AthenaClient {
         input: GetNamedQueryInput,
     ) -> Result<GetNamedQueryOutput, RusotoError<GetNamedQueryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetNamedQuery");
         request.add_header("x-amz-target", "AmazonAthena.GetNamedQuery");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2689,6 +3036,7 @@ impl Athena for AthenaClient {
         input: GetQueryExecutionInput,
     ) -> Result<GetQueryExecutionOutput, RusotoError<GetQueryExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetQueryExecution");
         request.add_header("x-amz-target", "AmazonAthena.GetQueryExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2707,6 +3055,7 @@ impl Athena for AthenaClient {
         input: GetQueryResultsInput,
     ) -> Result<GetQueryResultsOutput, RusotoError<GetQueryResultsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetQueryResults");
         request.add_header("x-amz-target", "AmazonAthena.GetQueryResults");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2725,6 +3074,7 @@ impl Athena for AthenaClient {
         input: GetTableMetadataInput,
     ) -> Result<GetTableMetadataOutput, RusotoError<GetTableMetadataError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetTableMetadata");
         request.add_header("x-amz-target", "AmazonAthena.GetTableMetadata");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2743,6 +3093,7 @@ impl Athena for AthenaClient {
         input: GetWorkGroupInput,
     ) -> Result<GetWorkGroupOutput, RusotoError<GetWorkGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetWorkGroup");
         request.add_header("x-amz-target", "AmazonAthena.GetWorkGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2761,6 +3112,7 @@ impl Athena for AthenaClient {
         input: ListDataCatalogsInput,
     ) -> Result<ListDataCatalogsOutput, RusotoError<ListDataCatalogsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListDataCatalogs");
         request.add_header("x-amz-target", "AmazonAthena.ListDataCatalogs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2779,6 +3131,7 @@ impl Athena for AthenaClient {
         input: ListDatabasesInput,
     ) -> Result<ListDatabasesOutput, RusotoError<ListDatabasesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListDatabases");
         request.add_header("x-amz-target", "AmazonAthena.ListDatabases");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2797,6 +3150,7 @@ impl Athena for AthenaClient {
         input: ListNamedQueriesInput,
     ) -> Result<ListNamedQueriesOutput, RusotoError<ListNamedQueriesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListNamedQueries");
         request.add_header("x-amz-target", "AmazonAthena.ListNamedQueries");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2815,6 +3169,7 @@ impl Athena for AthenaClient {
         input: ListQueryExecutionsInput,
     ) -> Result<ListQueryExecutionsOutput, RusotoError<ListQueryExecutionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListQueryExecutions");
         request.add_header("x-amz-target", "AmazonAthena.ListQueryExecutions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2833,6 +3188,7 @@ impl Athena for AthenaClient {
         input: ListTableMetadataInput,
     ) -> Result<ListTableMetadataOutput, RusotoError<ListTableMetadataError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTableMetadata");
         request.add_header("x-amz-target", "AmazonAthena.ListTableMetadata");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2851,6 +3207,7 @@ impl Athena for AthenaClient {
         input: ListTagsForResourceInput,
     ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header("x-amz-target", "AmazonAthena.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2869,6 +3226,7 @@ impl Athena for AthenaClient {
         input: ListWorkGroupsInput,
     ) -> Result<ListWorkGroupsOutput, RusotoError<ListWorkGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListWorkGroups");
         request.add_header("x-amz-target", "AmazonAthena.ListWorkGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2887,6 +3245,7 @@ impl Athena for AthenaClient {
         input: StartQueryExecutionInput,
     ) -> Result<StartQueryExecutionOutput, RusotoError<StartQueryExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartQueryExecution");
         request.add_header("x-amz-target", "AmazonAthena.StartQueryExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2905,6 +3264,7 @@ impl Athena for AthenaClient {
         input: StopQueryExecutionInput,
     ) -> Result<StopQueryExecutionOutput, RusotoError<StopQueryExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopQueryExecution");
         request.add_header("x-amz-target", "AmazonAthena.StopQueryExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2923,6 +3283,7 @@ impl Athena for AthenaClient {
         input: TagResourceInput,
     ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "AmazonAthena.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2941,6 +3302,7 @@ impl Athena for AthenaClient {
         input: UntagResourceInput,
     ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "AmazonAthena.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2959,6 +3321,7 @@ impl Athena for AthenaClient {
         input: UpdateDataCatalogInput,
     ) -> Result<UpdateDataCatalogOutput, RusotoError<UpdateDataCatalogError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateDataCatalog");
         request.add_header("x-amz-target", "AmazonAthena.UpdateDataCatalog");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2977,6 +3340,7 @@ impl Athena for AthenaClient {
         input: UpdateWorkGroupInput,
     ) -> Result<UpdateWorkGroupOutput, RusotoError<UpdateWorkGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateWorkGroup");
         request.add_header("x-amz-target", "AmazonAthena.UpdateWorkGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2989,3 +3353,313 @@ impl Athena for AthenaClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateWorkGroupOutput, _>()
     }
 }
+
+/// A blocking client for the Amazon Athena API, running the calls of a `AthenaClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `AthenaClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct AthenaBlockingClient {
+    client: AthenaClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl AthenaBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> AthenaBlockingClient {
+        AthenaBlockingClient::from_client(AthenaClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> AthenaBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        AthenaBlockingClient::from_client(AthenaClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> AthenaBlockingClient {
+        AthenaBlockingClient::from_client(AthenaClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> AthenaBlockingClient {
+        AthenaBlockingClient::from_client(AthenaClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: AthenaClient) -> AthenaBlockingClient {
+        AthenaBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> AthenaBlockingClient {
+        AthenaBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> AthenaBlockingClient {
+        AthenaBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Athena::batch_get_named_query`].
+    pub fn batch_get_named_query(
+        &self,
+        input: BatchGetNamedQueryInput,
+    ) -> Result<BatchGetNamedQueryOutput, RusotoError<BatchGetNamedQueryError>> {
+        self.runtime
+            .block_on(self.client.batch_get_named_query(input))
+    }
+
+    /// Blocking version of [`Athena::batch_get_query_execution`].
+    pub fn batch_get_query_execution(
+        &self,
+        input: BatchGetQueryExecutionInput,
+    ) -> Result<BatchGetQueryExecutionOutput, RusotoError<BatchGetQueryExecutionError>> {
+        self.runtime
+            .block_on(self.client.batch_get_query_execution(input))
+    }
+
+    /// Blocking version of [`Athena::create_data_catalog`].
+    pub fn create_data_catalog(
+        &self,
+        input: CreateDataCatalogInput,
+    ) -> Result<CreateDataCatalogOutput, RusotoError<CreateDataCatalogError>> {
+        self.runtime
+            .block_on(self.client.create_data_catalog(input))
+    }
+
+    /// Blocking version of [`Athena::create_named_query`].
+    pub fn create_named_query(
+        &self,
+        input: CreateNamedQueryInput,
+    ) -> Result<CreateNamedQueryOutput, RusotoError<CreateNamedQueryError>> {
+        self.runtime.block_on(self.client.create_named_query(input))
+    }
+
+    /// Blocking version of [`Athena::create_work_group`].
+    pub fn create_work_group(
+        &self,
+        input: CreateWorkGroupInput,
+    ) -> Result<CreateWorkGroupOutput, RusotoError<CreateWorkGroupError>> {
+        self.runtime.block_on(self.client.create_work_group(input))
+    }
+
+    /// Blocking version of [`Athena::delete_data_catalog`].
+    pub fn delete_data_catalog(
+        &self,
+        input: DeleteDataCatalogInput,
+    ) -> Result<DeleteDataCatalogOutput, RusotoError<DeleteDataCatalogError>> {
+        self.runtime
+            .block_on(self.client.delete_data_catalog(input))
+    }
+
+    /// Blocking version of [`Athena::delete_named_query`].
+    pub fn delete_named_query(
+        &self,
+        input: DeleteNamedQueryInput,
+    ) -> Result<DeleteNamedQueryOutput, RusotoError<DeleteNamedQueryError>> {
+        self.runtime.block_on(self.client.delete_named_query(input))
+    }
+
+    /// Blocking version of [`Athena::delete_work_group`].
+    pub fn delete_work_group(
+        &self,
+        input: DeleteWorkGroupInput,
+    ) -> Result<DeleteWorkGroupOutput, RusotoError<DeleteWorkGroupError>> {
+        self.runtime.block_on(self.client.delete_work_group(input))
+    }
+
+    /// Blocking version of [`Athena::get_data_catalog`].
+    pub fn get_data_catalog(
+        &self,
+        input: GetDataCatalogInput,
+    ) -> Result<GetDataCatalogOutput, RusotoError<GetDataCatalogError>> {
+        self.runtime.block_on(self.client.get_data_catalog(input))
+    }
+
+    /// Blocking version of [`Athena::get_database`].
+    pub fn get_database(
+        &self,
+        input: GetDatabaseInput,
+    ) -> Result<GetDatabaseOutput, RusotoError<GetDatabaseError>> {
+        self.runtime.block_on(self.client.get_database(input))
+    }
+
+    /// Blocking version of [`Athena::get_named_query`].
+    pub fn get_named_query(
+        &self,
+        input: GetNamedQueryInput,
+    ) -> Result<GetNamedQueryOutput, RusotoError<GetNamedQueryError>> {
+        self.runtime.block_on(self.client.get_named_query(input))
+    }
+
+    /// Blocking version of [`Athena::get_query_execution`].
+    pub fn get_query_execution(
+        &self,
+        input: GetQueryExecutionInput,
+    ) -> Result<GetQueryExecutionOutput, RusotoError<GetQueryExecutionError>> {
+        self.runtime
+            .block_on(self.client.get_query_execution(input))
+    }
+
+    /// Blocking version of [`Athena::get_query_results`].
+    pub fn get_query_results(
+        &self,
+        input: GetQueryResultsInput,
+    ) -> Result<GetQueryResultsOutput, RusotoError<GetQueryResultsError>> {
+        self.runtime.block_on(self.client.get_query_results(input))
+    }
+
+    /// Blocking version of [`Athena::get_table_metadata`].
+    pub fn get_table_metadata(
+        &self,
+        input: GetTableMetadataInput,
+    ) -> Result<GetTableMetadataOutput, RusotoError<GetTableMetadataError>> {
+        self.runtime.block_on(self.client.get_table_metadata(input))
+    }
+
+    /// Blocking version of [`Athena::get_work_group`].
+    pub fn get_work_group(
+        &self,
+        input: GetWorkGroupInput,
+    ) -> Result<GetWorkGroupOutput, RusotoError<GetWorkGroupError>> {
+        self.runtime.block_on(self.client.get_work_group(input))
+    }
+
+    /// Blocking version of [`Athena::list_data_catalogs`].
+    pub fn list_data_catalogs(
+        &self,
+        input: ListDataCatalogsInput,
+    ) -> Result<ListDataCatalogsOutput, RusotoError<ListDataCatalogsError>> {
+        self.runtime.block_on(self.client.list_data_catalogs(input))
+    }
+
+    /// Blocking version of [`Athena::list_databases`].
+    pub fn list_databases(
+        &self,
+        input: ListDatabasesInput,
+    ) -> Result<ListDatabasesOutput, RusotoError<ListDatabasesError>> {
+        self.runtime.block_on(self.client.list_databases(input))
+    }
+
+    /// Blocking version of [`Athena::list_named_queries`].
+    pub fn list_named_queries(
+        &self,
+        input: ListNamedQueriesInput,
+    ) -> Result<ListNamedQueriesOutput, RusotoError<ListNamedQueriesError>> {
+        self.runtime.block_on(self.client.list_named_queries(input))
+    }
+
+    /// Blocking version of [`Athena::list_query_executions`].
+    pub fn list_query_executions(
+        &self,
+        input: ListQueryExecutionsInput,
+    ) -> Result<ListQueryExecutionsOutput, RusotoError<ListQueryExecutionsError>> {
+        self.runtime
+            .block_on(self.client.list_query_executions(input))
+    }
+
+    /// Blocking version of [`Athena::list_table_metadata`].
+    pub fn list_table_metadata(
+        &self,
+        input: ListTableMetadataInput,
+    ) -> Result<ListTableMetadataOutput, RusotoError<ListTableMetadataError>> {
+        self.runtime
+            .block_on(self.client.list_table_metadata(input))
+    }
+
+    /// Blocking version of [`Athena::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`Athena::list_work_groups`].
+    pub fn list_work_groups(
+        &self,
+        input: ListWorkGroupsInput,
+    ) -> Result<ListWorkGroupsOutput, RusotoError<ListWorkGroupsError>> {
+        self.runtime.block_on(self.client.list_work_groups(input))
+    }
+
+    /// Blocking version of [`Athena::start_query_execution`].
+    pub fn start_query_execution(
+        &self,
+        input: StartQueryExecutionInput,
+    ) -> Result<StartQueryExecutionOutput, RusotoError<StartQueryExecutionError>> {
+        self.runtime
+            .block_on(self.client.start_query_execution(input))
+    }
+
+    /// Blocking version of [`Athena::stop_query_execution`].
+    pub fn stop_query_execution(
+        &self,
+        input: StopQueryExecutionInput,
+    ) -> Result<StopQueryExecutionOutput, RusotoError<StopQueryExecutionError>> {
+        self.runtime
+            .block_on(self.client.stop_query_execution(input))
+    }
+
+    /// Blocking version of [`Athena::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`Athena::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`Athena::update_data_catalog`].
+    pub fn update_data_catalog(
+        &self,
+        input: UpdateDataCatalogInput,
+    ) -> Result<UpdateDataCatalogOutput, RusotoError<UpdateDataCatalogError>> {
+        self.runtime
+            .block_on(self.client.update_data_catalog(input))
+    }
+
+    /// Blocking version of [`Athena::update_work_group`].
+    pub fn update_work_group(
+        &self,
+        input: UpdateWorkGroupInput,
+    ) -> Result<UpdateWorkGroupOutput, RusotoError<UpdateWorkGroupError>> {
+        self.runtime.block_on(self.client.update_work_group(input))
+    }
+}
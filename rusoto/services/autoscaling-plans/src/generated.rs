@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -521,15 +522,30 @@ impl CreateScalingPlanError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(CreateScalingPlanError::ConcurrentUpdate(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateScalingPlanError::ConcurrentUpdate,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateScalingPlanError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateScalingPlanError::InternalService,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateScalingPlanError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateScalingPlanError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -563,15 +579,30 @@ impl DeleteScalingPlanError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(DeleteScalingPlanError::ConcurrentUpdate(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteScalingPlanError::ConcurrentUpdate,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteScalingPlanError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteScalingPlanError::InternalService,
+                    )
                 }
                 "ObjectNotFoundException" => {
-                    return RusotoError::Service(DeleteScalingPlanError::ObjectNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteScalingPlanError::ObjectNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -607,21 +638,30 @@ impl DescribeScalingPlanResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(
-                        DescribeScalingPlanResourcesError::ConcurrentUpdate(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeScalingPlanResourcesError::ConcurrentUpdate,
                     )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(
-                        DescribeScalingPlanResourcesError::InternalService(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeScalingPlanResourcesError::InternalService,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        DescribeScalingPlanResourcesError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeScalingPlanResourcesError::InvalidNextToken,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -659,21 +699,30 @@ impl DescribeScalingPlansError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(DescribeScalingPlansError::ConcurrentUpdate(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeScalingPlansError::ConcurrentUpdate,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeScalingPlansError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeScalingPlansError::InternalService,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeScalingPlansError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeScalingPlansError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -705,11 +754,14 @@ impl GetScalingPlanResourceForecastDataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
-                        GetScalingPlanResourceForecastDataError::InternalService(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetScalingPlanResourceForecastDataError::InternalService,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -743,15 +795,30 @@ impl UpdateScalingPlanError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentUpdateException" => {
-                    return RusotoError::Service(UpdateScalingPlanError::ConcurrentUpdate(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateScalingPlanError::ConcurrentUpdate,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateScalingPlanError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateScalingPlanError::InternalService,
+                    )
                 }
                 "ObjectNotFoundException" => {
-                    return RusotoError::Service(UpdateScalingPlanError::ObjectNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateScalingPlanError::ObjectNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -821,11 +888,12 @@ pub struct AutoscalingPlansClient {
 impl AutoscalingPlansClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> AutoscalingPlansClient {
         AutoscalingPlansClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Auto Scaling Plans"),
         }
     }
 
@@ -840,12 +908,32 @@ impl AutoscalingPlansClient {
     {
         AutoscalingPlansClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Auto Scaling Plans"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> AutoscalingPlansClient {
-        AutoscalingPlansClient { client, region }
+        AutoscalingPlansClient {
+            client,
+            region: region_for_service(region, "Auto Scaling Plans"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> AutoscalingPlansClient {
+        AutoscalingPlansClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Auto Scaling Plans"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> AutoscalingPlansClient {
+        AutoscalingPlansClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -857,6 +945,7 @@ impl AutoscalingPlans for AutoscalingPlansClient {
         input: CreateScalingPlanRequest,
     ) -> Result<CreateScalingPlanResponse, RusotoError<CreateScalingPlanError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateScalingPlan");
         request.add_header(
             "x-amz-target",
             "AnyScaleScalingPlannerFrontendService.CreateScalingPlan",
@@ -878,6 +967,7 @@ impl AutoscalingPlans for AutoscalingPlansClient {
         input: DeleteScalingPlanRequest,
     ) -> Result<DeleteScalingPlanResponse, RusotoError<DeleteScalingPlanError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteScalingPlan");
         request.add_header(
             "x-amz-target",
             "AnyScaleScalingPlannerFrontendService.DeleteScalingPlan",
@@ -900,6 +990,7 @@ impl AutoscalingPlans for AutoscalingPlansClient {
     ) -> Result<DescribeScalingPlanResourcesResponse, RusotoError<DescribeScalingPlanResourcesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeScalingPlanResources");
         request.add_header(
             "x-amz-target",
             "AnyScaleScalingPlannerFrontendService.DescribeScalingPlanResources",
@@ -922,6 +1013,7 @@ impl AutoscalingPlans for AutoscalingPlansClient {
         input: DescribeScalingPlansRequest,
     ) -> Result<DescribeScalingPlansResponse, RusotoError<DescribeScalingPlansError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeScalingPlans");
         request.add_header(
             "x-amz-target",
             "AnyScaleScalingPlannerFrontendService.DescribeScalingPlans",
@@ -947,6 +1039,7 @@ impl AutoscalingPlans for AutoscalingPlansClient {
         RusotoError<GetScalingPlanResourceForecastDataError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetScalingPlanResourceForecastData");
         request.add_header(
             "x-amz-target",
             "AnyScaleScalingPlannerFrontendService.GetScalingPlanResourceForecastData",
@@ -972,6 +1065,7 @@ impl AutoscalingPlans for AutoscalingPlansClient {
         input: UpdateScalingPlanRequest,
     ) -> Result<UpdateScalingPlanResponse, RusotoError<UpdateScalingPlanError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateScalingPlan");
         request.add_header(
             "x-amz-target",
             "AnyScaleScalingPlannerFrontendService.UpdateScalingPlan",
@@ -987,3 +1081,144 @@ impl AutoscalingPlans for AutoscalingPlansClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateScalingPlanResponse, _>()
     }
 }
+
+/// A blocking client for the AWS Auto Scaling Plans API, running the calls of a `AutoscalingPlansClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `AutoscalingPlansClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct AutoscalingPlansBlockingClient {
+    client: AutoscalingPlansClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl AutoscalingPlansBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> AutoscalingPlansBlockingClient {
+        AutoscalingPlansBlockingClient::from_client(AutoscalingPlansClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> AutoscalingPlansBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        AutoscalingPlansBlockingClient::from_client(AutoscalingPlansClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> AutoscalingPlansBlockingClient {
+        AutoscalingPlansBlockingClient::from_client(AutoscalingPlansClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> AutoscalingPlansBlockingClient {
+        AutoscalingPlansBlockingClient::from_client(AutoscalingPlansClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: AutoscalingPlansClient) -> AutoscalingPlansBlockingClient {
+        AutoscalingPlansBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> AutoscalingPlansBlockingClient {
+        AutoscalingPlansBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> AutoscalingPlansBlockingClient {
+        AutoscalingPlansBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`AutoscalingPlans::create_scaling_plan`].
+    pub fn create_scaling_plan(
+        &self,
+        input: CreateScalingPlanRequest,
+    ) -> Result<CreateScalingPlanResponse, RusotoError<CreateScalingPlanError>> {
+        self.runtime
+            .block_on(self.client.create_scaling_plan(input))
+    }
+
+    /// Blocking version of [`AutoscalingPlans::delete_scaling_plan`].
+    pub fn delete_scaling_plan(
+        &self,
+        input: DeleteScalingPlanRequest,
+    ) -> Result<DeleteScalingPlanResponse, RusotoError<DeleteScalingPlanError>> {
+        self.runtime
+            .block_on(self.client.delete_scaling_plan(input))
+    }
+
+    /// Blocking version of [`AutoscalingPlans::describe_scaling_plan_resources`].
+    pub fn describe_scaling_plan_resources(
+        &self,
+        input: DescribeScalingPlanResourcesRequest,
+    ) -> Result<DescribeScalingPlanResourcesResponse, RusotoError<DescribeScalingPlanResourcesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_scaling_plan_resources(input))
+    }
+
+    /// Blocking version of [`AutoscalingPlans::describe_scaling_plans`].
+    pub fn describe_scaling_plans(
+        &self,
+        input: DescribeScalingPlansRequest,
+    ) -> Result<DescribeScalingPlansResponse, RusotoError<DescribeScalingPlansError>> {
+        self.runtime
+            .block_on(self.client.describe_scaling_plans(input))
+    }
+
+    /// Blocking version of [`AutoscalingPlans::get_scaling_plan_resource_forecast_data`].
+    pub fn get_scaling_plan_resource_forecast_data(
+        &self,
+        input: GetScalingPlanResourceForecastDataRequest,
+    ) -> Result<
+        GetScalingPlanResourceForecastDataResponse,
+        RusotoError<GetScalingPlanResourceForecastDataError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_scaling_plan_resource_forecast_data(input))
+    }
+
+    /// Blocking version of [`AutoscalingPlans::update_scaling_plan`].
+    pub fn update_scaling_plan(
+        &self,
+        input: UpdateScalingPlanRequest,
+    ) -> Result<UpdateScalingPlanResponse, RusotoError<UpdateScalingPlanError>> {
+        self.runtime
+            .block_on(self.client.update_scaling_plan(input))
+    }
+}
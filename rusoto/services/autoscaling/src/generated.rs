@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -7651,13 +7652,19 @@ impl AttachInstancesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(AttachInstancesError::ResourceContentionFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AttachInstancesError::ResourceContentionFault,
+                        )
                     }
                     "ServiceLinkedRoleFailure" => {
-                        return RusotoError::Service(
-                            AttachInstancesError::ServiceLinkedRoleFailure(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AttachInstancesError::ServiceLinkedRoleFailure,
                         )
                     }
                     _ => {}
@@ -7705,17 +7712,19 @@ impl AttachLoadBalancerTargetGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            AttachLoadBalancerTargetGroupsError::ResourceContentionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AttachLoadBalancerTargetGroupsError::ResourceContentionFault,
                         )
                     }
                     "ServiceLinkedRoleFailure" => {
-                        return RusotoError::Service(
-                            AttachLoadBalancerTargetGroupsError::ServiceLinkedRoleFailure(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AttachLoadBalancerTargetGroupsError::ServiceLinkedRoleFailure,
                         )
                     }
                     _ => {}
@@ -7765,15 +7774,19 @@ impl AttachLoadBalancersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            AttachLoadBalancersError::ResourceContentionFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AttachLoadBalancersError::ResourceContentionFault,
                         )
                     }
                     "ServiceLinkedRoleFailure" => {
-                        return RusotoError::Service(
-                            AttachLoadBalancersError::ServiceLinkedRoleFailure(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AttachLoadBalancersError::ServiceLinkedRoleFailure,
                         )
                     }
                     _ => {}
@@ -7819,10 +7832,11 @@ impl BatchDeleteScheduledActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            BatchDeleteScheduledActionError::ResourceContentionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            BatchDeleteScheduledActionError::ResourceContentionFault,
                         )
                     }
                     _ => {}
@@ -7873,24 +7887,27 @@ impl BatchPutScheduledUpdateGroupActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AlreadyExists" => {
-                        return RusotoError::Service(
-                            BatchPutScheduledUpdateGroupActionError::AlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            BatchPutScheduledUpdateGroupActionError::AlreadyExistsFault,
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(
-                            BatchPutScheduledUpdateGroupActionError::LimitExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            BatchPutScheduledUpdateGroupActionError::LimitExceededFault,
                         )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            BatchPutScheduledUpdateGroupActionError::ResourceContentionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            BatchPutScheduledUpdateGroupActionError::ResourceContentionFault,
                         )
                     }
                     _ => {}
@@ -7945,22 +7962,27 @@ impl CancelInstanceRefreshError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ActiveInstanceRefreshNotFound" => {
-                        return RusotoError::Service(
-                            CancelInstanceRefreshError::ActiveInstanceRefreshNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CancelInstanceRefreshError::ActiveInstanceRefreshNotFoundFault,
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(
-                            CancelInstanceRefreshError::LimitExceededFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CancelInstanceRefreshError::LimitExceededFault,
                         )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            CancelInstanceRefreshError::ResourceContentionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CancelInstanceRefreshError::ResourceContentionFault,
                         )
                     }
                     _ => {}
@@ -8009,10 +8031,11 @@ impl CompleteLifecycleActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            CompleteLifecycleActionError::ResourceContentionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CompleteLifecycleActionError::ResourceContentionFault,
                         )
                     }
                     _ => {}
@@ -8063,27 +8086,35 @@ impl CreateAutoScalingGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateAutoScalingGroupError::AlreadyExistsFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateAutoScalingGroupError::AlreadyExistsFault,
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(
-                            CreateAutoScalingGroupError::LimitExceededFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateAutoScalingGroupError::LimitExceededFault,
                         )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            CreateAutoScalingGroupError::ResourceContentionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateAutoScalingGroupError::ResourceContentionFault,
                         )
                     }
                     "ServiceLinkedRoleFailure" => {
-                        return RusotoError::Service(
-                            CreateAutoScalingGroupError::ServiceLinkedRoleFailure(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateAutoScalingGroupError::ServiceLinkedRoleFailure,
                         )
                     }
                     _ => {}
@@ -8137,24 +8168,27 @@ impl CreateLaunchConfigurationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateLaunchConfigurationError::AlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateLaunchConfigurationError::AlreadyExistsFault,
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(
-                            CreateLaunchConfigurationError::LimitExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateLaunchConfigurationError::LimitExceededFault,
                         )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            CreateLaunchConfigurationError::ResourceContentionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateLaunchConfigurationError::ResourceContentionFault,
                         )
                     }
                     _ => {}
@@ -8207,24 +8241,36 @@ impl CreateOrUpdateTagsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AlreadyExists" => {
-                        return RusotoError::Service(CreateOrUpdateTagsError::AlreadyExistsFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateOrUpdateTagsError::AlreadyExistsFault,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateOrUpdateTagsError::LimitExceededFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateOrUpdateTagsError::LimitExceededFault,
+                        )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            CreateOrUpdateTagsError::ResourceContentionFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateOrUpdateTagsError::ResourceContentionFault,
                         )
                     }
                     "ResourceInUse" => {
-                        return RusotoError::Service(CreateOrUpdateTagsError::ResourceInUseFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateOrUpdateTagsError::ResourceInUseFault,
+                        )
                     }
                     _ => {}
                 }
@@ -8273,22 +8319,27 @@ impl DeleteAutoScalingGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            DeleteAutoScalingGroupError::ResourceContentionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteAutoScalingGroupError::ResourceContentionFault,
                         )
                     }
                     "ResourceInUse" => {
-                        return RusotoError::Service(
-                            DeleteAutoScalingGroupError::ResourceInUseFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteAutoScalingGroupError::ResourceInUseFault,
                         )
                     }
                     "ScalingActivityInProgress" => {
-                        return RusotoError::Service(
-                            DeleteAutoScalingGroupError::ScalingActivityInProgressFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteAutoScalingGroupError::ScalingActivityInProgressFault,
                         )
                     }
                     _ => {}
@@ -8339,17 +8390,19 @@ impl DeleteLaunchConfigurationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            DeleteLaunchConfigurationError::ResourceContentionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteLaunchConfigurationError::ResourceContentionFault,
                         )
                     }
                     "ResourceInUse" => {
-                        return RusotoError::Service(
-                            DeleteLaunchConfigurationError::ResourceInUseFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteLaunchConfigurationError::ResourceInUseFault,
                         )
                     }
                     _ => {}
@@ -8395,8 +8448,11 @@ impl DeleteLifecycleHookError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            DeleteLifecycleHookError::ResourceContentionFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteLifecycleHookError::ResourceContentionFault,
                         )
                     }
                     _ => {}
@@ -8441,10 +8497,11 @@ impl DeleteNotificationConfigurationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            DeleteNotificationConfigurationError::ResourceContentionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteNotificationConfigurationError::ResourceContentionFault,
                         )
                     }
                     _ => {}
@@ -8491,14 +8548,20 @@ impl DeletePolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(DeletePolicyError::ResourceContentionFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeletePolicyError::ResourceContentionFault,
+                        )
                     }
                     "ServiceLinkedRoleFailure" => {
-                        return RusotoError::Service(DeletePolicyError::ServiceLinkedRoleFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeletePolicyError::ServiceLinkedRoleFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -8541,10 +8604,11 @@ impl DeleteScheduledActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            DeleteScheduledActionError::ResourceContentionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteScheduledActionError::ResourceContentionFault,
                         )
                     }
                     _ => {}
@@ -8591,14 +8655,20 @@ impl DeleteTagsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(DeleteTagsError::ResourceContentionFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteTagsError::ResourceContentionFault,
+                        )
                     }
                     "ResourceInUse" => {
-                        return RusotoError::Service(DeleteTagsError::ResourceInUseFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteTagsError::ResourceInUseFault,
+                        )
                     }
                     _ => {}
                 }
@@ -8641,10 +8711,11 @@ impl DescribeAccountLimitsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            DescribeAccountLimitsError::ResourceContentionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeAccountLimitsError::ResourceContentionFault,
                         )
                     }
                     _ => {}
@@ -8689,10 +8760,11 @@ impl DescribeAdjustmentTypesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            DescribeAdjustmentTypesError::ResourceContentionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeAdjustmentTypesError::ResourceContentionFault,
                         )
                     }
                     _ => {}
@@ -8739,15 +8811,19 @@ impl DescribeAutoScalingGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(
-                            DescribeAutoScalingGroupsError::InvalidNextToken(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeAutoScalingGroupsError::InvalidNextToken,
                         )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            DescribeAutoScalingGroupsError::ResourceContentionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeAutoScalingGroupsError::ResourceContentionFault,
                         )
                     }
                     _ => {}
@@ -8797,17 +8873,19 @@ impl DescribeAutoScalingInstancesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(
-                            DescribeAutoScalingInstancesError::InvalidNextToken(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeAutoScalingInstancesError::InvalidNextToken,
                         )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            DescribeAutoScalingInstancesError::ResourceContentionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeAutoScalingInstancesError::ResourceContentionFault,
                         )
                     }
                     _ => {}
@@ -8857,10 +8935,11 @@ impl DescribeAutoScalingNotificationTypesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            DescribeAutoScalingNotificationTypesError::ResourceContentionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeAutoScalingNotificationTypesError::ResourceContentionFault,
                         )
                     }
                     _ => {}
@@ -8907,15 +8986,19 @@ impl DescribeInstanceRefreshesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(
-                            DescribeInstanceRefreshesError::InvalidNextToken(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeInstanceRefreshesError::InvalidNextToken,
                         )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            DescribeInstanceRefreshesError::ResourceContentionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeInstanceRefreshesError::ResourceContentionFault,
                         )
                     }
                     _ => {}
@@ -8965,17 +9048,19 @@ impl DescribeLaunchConfigurationsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(
-                            DescribeLaunchConfigurationsError::InvalidNextToken(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeLaunchConfigurationsError::InvalidNextToken,
                         )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            DescribeLaunchConfigurationsError::ResourceContentionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeLaunchConfigurationsError::ResourceContentionFault,
                         )
                     }
                     _ => {}
@@ -9025,10 +9110,11 @@ impl DescribeLifecycleHookTypesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            DescribeLifecycleHookTypesError::ResourceContentionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeLifecycleHookTypesError::ResourceContentionFault,
                         )
                     }
                     _ => {}
@@ -9073,10 +9159,11 @@ impl DescribeLifecycleHooksError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            DescribeLifecycleHooksError::ResourceContentionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeLifecycleHooksError::ResourceContentionFault,
                         )
                     }
                     _ => {}
@@ -9123,10 +9210,11 @@ impl DescribeLoadBalancerTargetGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            DescribeLoadBalancerTargetGroupsError::ResourceContentionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeLoadBalancerTargetGroupsError::ResourceContentionFault,
                         )
                     }
                     _ => {}
@@ -9171,10 +9259,11 @@ impl DescribeLoadBalancersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            DescribeLoadBalancersError::ResourceContentionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeLoadBalancersError::ResourceContentionFault,
                         )
                     }
                     _ => {}
@@ -9221,10 +9310,11 @@ impl DescribeMetricCollectionTypesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            DescribeMetricCollectionTypesError::ResourceContentionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeMetricCollectionTypesError::ResourceContentionFault,
                         )
                     }
                     _ => {}
@@ -9273,17 +9363,19 @@ impl DescribeNotificationConfigurationsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(
-                            DescribeNotificationConfigurationsError::InvalidNextToken(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeNotificationConfigurationsError::InvalidNextToken,
                         )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            DescribeNotificationConfigurationsError::ResourceContentionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeNotificationConfigurationsError::ResourceContentionFault,
                         )
                     }
                     _ => {}
@@ -9335,18 +9427,27 @@ impl DescribePoliciesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(DescribePoliciesError::InvalidNextToken(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribePoliciesError::InvalidNextToken,
+                        )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            DescribePoliciesError::ResourceContentionFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribePoliciesError::ResourceContentionFault,
                         )
                     }
                     "ServiceLinkedRoleFailure" => {
-                        return RusotoError::Service(
-                            DescribePoliciesError::ServiceLinkedRoleFailure(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribePoliciesError::ServiceLinkedRoleFailure,
                         )
                     }
                     _ => {}
@@ -9393,15 +9494,19 @@ impl DescribeScalingActivitiesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(
-                            DescribeScalingActivitiesError::InvalidNextToken(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeScalingActivitiesError::InvalidNextToken,
                         )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            DescribeScalingActivitiesError::ResourceContentionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeScalingActivitiesError::ResourceContentionFault,
                         )
                     }
                     _ => {}
@@ -9449,10 +9554,11 @@ impl DescribeScalingProcessTypesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            DescribeScalingProcessTypesError::ResourceContentionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeScalingProcessTypesError::ResourceContentionFault,
                         )
                     }
                     _ => {}
@@ -9499,15 +9605,19 @@ impl DescribeScheduledActionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(
-                            DescribeScheduledActionsError::InvalidNextToken(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeScheduledActionsError::InvalidNextToken,
                         )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            DescribeScheduledActionsError::ResourceContentionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeScheduledActionsError::ResourceContentionFault,
                         )
                     }
                     _ => {}
@@ -9555,14 +9665,20 @@ impl DescribeTagsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(DescribeTagsError::InvalidNextToken(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeTagsError::InvalidNextToken,
+                        )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(DescribeTagsError::ResourceContentionFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeTagsError::ResourceContentionFault,
+                        )
                     }
                     _ => {}
                 }
@@ -9607,10 +9723,11 @@ impl DescribeTerminationPolicyTypesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            DescribeTerminationPolicyTypesError::ResourceContentionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeTerminationPolicyTypesError::ResourceContentionFault,
                         )
                     }
                     _ => {}
@@ -9655,9 +9772,12 @@ impl DetachInstancesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(DetachInstancesError::ResourceContentionFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DetachInstancesError::ResourceContentionFault,
+                        )
                     }
                     _ => {}
                 }
@@ -9701,10 +9821,11 @@ impl DetachLoadBalancerTargetGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            DetachLoadBalancerTargetGroupsError::ResourceContentionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DetachLoadBalancerTargetGroupsError::ResourceContentionFault,
                         )
                     }
                     _ => {}
@@ -9749,8 +9870,11 @@ impl DetachLoadBalancersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            DetachLoadBalancersError::ResourceContentionFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DetachLoadBalancersError::ResourceContentionFault,
                         )
                     }
                     _ => {}
@@ -9793,10 +9917,11 @@ impl DisableMetricsCollectionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            DisableMetricsCollectionError::ResourceContentionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DisableMetricsCollectionError::ResourceContentionFault,
                         )
                     }
                     _ => {}
@@ -9841,10 +9966,11 @@ impl EnableMetricsCollectionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            EnableMetricsCollectionError::ResourceContentionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            EnableMetricsCollectionError::ResourceContentionFault,
                         )
                     }
                     _ => {}
@@ -9889,9 +10015,12 @@ impl EnterStandbyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(EnterStandbyError::ResourceContentionFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            EnterStandbyError::ResourceContentionFault,
+                        )
                     }
                     _ => {}
                 }
@@ -9935,15 +10064,19 @@ impl ExecutePolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(ExecutePolicyError::ResourceContentionFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ExecutePolicyError::ResourceContentionFault,
+                        )
                     }
                     "ScalingActivityInProgress" => {
-                        return RusotoError::Service(
-                            ExecutePolicyError::ScalingActivityInProgressFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ExecutePolicyError::ScalingActivityInProgressFault,
                         )
                     }
                     _ => {}
@@ -9987,9 +10120,12 @@ impl ExitStandbyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(ExitStandbyError::ResourceContentionFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ExitStandbyError::ResourceContentionFault,
+                        )
                     }
                     _ => {}
                 }
@@ -10033,13 +10169,19 @@ impl PutLifecycleHookError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(PutLifecycleHookError::LimitExceededFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PutLifecycleHookError::LimitExceededFault,
+                        )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            PutLifecycleHookError::ResourceContentionFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            PutLifecycleHookError::ResourceContentionFault,
                         )
                     }
                     _ => {}
@@ -10089,24 +10231,27 @@ impl PutNotificationConfigurationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(
-                            PutNotificationConfigurationError::LimitExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            PutNotificationConfigurationError::LimitExceededFault,
                         )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            PutNotificationConfigurationError::ResourceContentionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            PutNotificationConfigurationError::ResourceContentionFault,
                         )
                     }
                     "ServiceLinkedRoleFailure" => {
-                        return RusotoError::Service(
-                            PutNotificationConfigurationError::ServiceLinkedRoleFailure(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            PutNotificationConfigurationError::ServiceLinkedRoleFailure,
                         )
                     }
                     _ => {}
@@ -10161,18 +10306,27 @@ impl PutScalingPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(PutScalingPolicyError::LimitExceededFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PutScalingPolicyError::LimitExceededFault,
+                        )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            PutScalingPolicyError::ResourceContentionFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            PutScalingPolicyError::ResourceContentionFault,
                         )
                     }
                     "ServiceLinkedRoleFailure" => {
-                        return RusotoError::Service(
-                            PutScalingPolicyError::ServiceLinkedRoleFailure(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            PutScalingPolicyError::ServiceLinkedRoleFailure,
                         )
                     }
                     _ => {}
@@ -10223,24 +10377,27 @@ impl PutScheduledUpdateGroupActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AlreadyExists" => {
-                        return RusotoError::Service(
-                            PutScheduledUpdateGroupActionError::AlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            PutScheduledUpdateGroupActionError::AlreadyExistsFault,
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(
-                            PutScheduledUpdateGroupActionError::LimitExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            PutScheduledUpdateGroupActionError::LimitExceededFault,
                         )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            PutScheduledUpdateGroupActionError::ResourceContentionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            PutScheduledUpdateGroupActionError::ResourceContentionFault,
                         )
                     }
                     _ => {}
@@ -10293,10 +10450,11 @@ impl RecordLifecycleActionHeartbeatError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            RecordLifecycleActionHeartbeatError::ResourceContentionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RecordLifecycleActionHeartbeatError::ResourceContentionFault,
                         )
                     }
                     _ => {}
@@ -10343,14 +10501,20 @@ impl ResumeProcessesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(ResumeProcessesError::ResourceContentionFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ResumeProcessesError::ResourceContentionFault,
+                        )
                     }
                     "ResourceInUse" => {
-                        return RusotoError::Service(ResumeProcessesError::ResourceInUseFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ResumeProcessesError::ResourceInUseFault,
+                        )
                     }
                     _ => {}
                 }
@@ -10395,15 +10559,19 @@ impl SetDesiredCapacityError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            SetDesiredCapacityError::ResourceContentionFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SetDesiredCapacityError::ResourceContentionFault,
                         )
                     }
                     "ScalingActivityInProgress" => {
-                        return RusotoError::Service(
-                            SetDesiredCapacityError::ScalingActivityInProgressFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SetDesiredCapacityError::ScalingActivityInProgressFault,
                         )
                     }
                     _ => {}
@@ -10449,8 +10617,11 @@ impl SetInstanceHealthError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            SetInstanceHealthError::ResourceContentionFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SetInstanceHealthError::ResourceContentionFault,
                         )
                     }
                     _ => {}
@@ -10495,15 +10666,19 @@ impl SetInstanceProtectionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(
-                            SetInstanceProtectionError::LimitExceededFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SetInstanceProtectionError::LimitExceededFault,
                         )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            SetInstanceProtectionError::ResourceContentionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SetInstanceProtectionError::ResourceContentionFault,
                         )
                     }
                     _ => {}
@@ -10553,22 +10728,27 @@ impl StartInstanceRefreshError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InstanceRefreshInProgress" => {
-                        return RusotoError::Service(
-                            StartInstanceRefreshError::InstanceRefreshInProgressFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StartInstanceRefreshError::InstanceRefreshInProgressFault,
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(StartInstanceRefreshError::LimitExceededFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            StartInstanceRefreshError::LimitExceededFault,
+                        )
                     }
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            StartInstanceRefreshError::ResourceContentionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StartInstanceRefreshError::ResourceContentionFault,
                         )
                     }
                     _ => {}
@@ -10617,14 +10797,20 @@ impl SuspendProcessesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            SuspendProcessesError::ResourceContentionFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SuspendProcessesError::ResourceContentionFault,
                         )
                     }
                     "ResourceInUse" => {
-                        return RusotoError::Service(SuspendProcessesError::ResourceInUseFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SuspendProcessesError::ResourceInUseFault,
+                        )
                     }
                     _ => {}
                 }
@@ -10671,16 +10857,18 @@ impl TerminateInstanceInAutoScalingGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            TerminateInstanceInAutoScalingGroupError::ResourceContentionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            TerminateInstanceInAutoScalingGroupError::ResourceContentionFault,
                         )
                     }
-                    "ScalingActivityInProgress" => return RusotoError::Service(
-                        TerminateInstanceInAutoScalingGroupError::ScalingActivityInProgressFault(
-                            parsed_error.message,
-                        ),
+                    "ScalingActivityInProgress" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        TerminateInstanceInAutoScalingGroupError::ScalingActivityInProgressFault,
                     ),
                     _ => {}
                 }
@@ -10731,24 +10919,27 @@ impl UpdateAutoScalingGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceContention" => {
-                        return RusotoError::Service(
-                            UpdateAutoScalingGroupError::ResourceContentionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateAutoScalingGroupError::ResourceContentionFault,
                         )
                     }
                     "ScalingActivityInProgress" => {
-                        return RusotoError::Service(
-                            UpdateAutoScalingGroupError::ScalingActivityInProgressFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateAutoScalingGroupError::ScalingActivityInProgressFault,
                         )
                     }
                     "ServiceLinkedRoleFailure" => {
-                        return RusotoError::Service(
-                            UpdateAutoScalingGroupError::ServiceLinkedRoleFailure(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateAutoScalingGroupError::ServiceLinkedRoleFailure,
                         )
                     }
                     _ => {}
@@ -11152,11 +11343,12 @@ pub struct AutoscalingClient {
 impl AutoscalingClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> AutoscalingClient {
         AutoscalingClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Auto Scaling"),
         }
     }
 
@@ -11171,12 +11363,32 @@ impl AutoscalingClient {
     {
         AutoscalingClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Auto Scaling"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> AutoscalingClient {
-        AutoscalingClient { client, region }
+        AutoscalingClient {
+            client,
+            region: region_for_service(region, "Auto Scaling"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> AutoscalingClient {
+        AutoscalingClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Auto Scaling"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> AutoscalingClient {
+        AutoscalingClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -11188,6 +11400,7 @@ impl Autoscaling for AutoscalingClient {
         input: AttachInstancesQuery,
     ) -> Result<(), RusotoError<AttachInstancesError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("AttachInstances");
         let params = self.new_params("AttachInstances");
         let mut params = params;
         AttachInstancesQuerySerializer::serialize(&mut params, "", &input);
@@ -11211,6 +11424,7 @@ impl Autoscaling for AutoscalingClient {
         RusotoError<AttachLoadBalancerTargetGroupsError>,
     > {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("AttachLoadBalancerTargetGroups");
         let params = self.new_params("AttachLoadBalancerTargetGroups");
         let mut params = params;
         AttachLoadBalancerTargetGroupsTypeSerializer::serialize(&mut params, "", &input);
@@ -11233,6 +11447,7 @@ impl Autoscaling for AutoscalingClient {
         input: AttachLoadBalancersType,
     ) -> Result<AttachLoadBalancersResultType, RusotoError<AttachLoadBalancersError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("AttachLoadBalancers");
         let params = self.new_params("AttachLoadBalancers");
         let mut params = params;
         AttachLoadBalancersTypeSerializer::serialize(&mut params, "", &input);
@@ -11256,6 +11471,7 @@ impl Autoscaling for AutoscalingClient {
     ) -> Result<BatchDeleteScheduledActionAnswer, RusotoError<BatchDeleteScheduledActionError>>
     {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("BatchDeleteScheduledAction");
         let params = self.new_params("BatchDeleteScheduledAction");
         let mut params = params;
         BatchDeleteScheduledActionTypeSerializer::serialize(&mut params, "", &input);
@@ -11292,6 +11508,7 @@ impl Autoscaling for AutoscalingClient {
         RusotoError<BatchPutScheduledUpdateGroupActionError>,
     > {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("BatchPutScheduledUpdateGroupAction");
         let params = self.new_params("BatchPutScheduledUpdateGroupAction");
         let mut params = params;
         BatchPutScheduledUpdateGroupActionTypeSerializer::serialize(&mut params, "", &input);
@@ -11328,6 +11545,7 @@ impl Autoscaling for AutoscalingClient {
         input: CancelInstanceRefreshType,
     ) -> Result<CancelInstanceRefreshAnswer, RusotoError<CancelInstanceRefreshError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("CancelInstanceRefresh");
         let params = self.new_params("CancelInstanceRefresh");
         let mut params = params;
         CancelInstanceRefreshTypeSerializer::serialize(&mut params, "", &input);
@@ -11361,6 +11579,7 @@ impl Autoscaling for AutoscalingClient {
         input: CompleteLifecycleActionType,
     ) -> Result<CompleteLifecycleActionAnswer, RusotoError<CompleteLifecycleActionError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("CompleteLifecycleAction");
         let params = self.new_params("CompleteLifecycleAction");
         let mut params = params;
         CompleteLifecycleActionTypeSerializer::serialize(&mut params, "", &input);
@@ -11383,6 +11602,7 @@ impl Autoscaling for AutoscalingClient {
         input: CreateAutoScalingGroupType,
     ) -> Result<(), RusotoError<CreateAutoScalingGroupError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("CreateAutoScalingGroup");
         let params = self.new_params("CreateAutoScalingGroup");
         let mut params = params;
         CreateAutoScalingGroupTypeSerializer::serialize(&mut params, "", &input);
@@ -11403,6 +11623,7 @@ impl Autoscaling for AutoscalingClient {
         input: CreateLaunchConfigurationType,
     ) -> Result<(), RusotoError<CreateLaunchConfigurationError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("CreateLaunchConfiguration");
         let params = self.new_params("CreateLaunchConfiguration");
         let mut params = params;
         CreateLaunchConfigurationTypeSerializer::serialize(&mut params, "", &input);
@@ -11423,6 +11644,7 @@ impl Autoscaling for AutoscalingClient {
         input: CreateOrUpdateTagsType,
     ) -> Result<(), RusotoError<CreateOrUpdateTagsError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("CreateOrUpdateTags");
         let params = self.new_params("CreateOrUpdateTags");
         let mut params = params;
         CreateOrUpdateTagsTypeSerializer::serialize(&mut params, "", &input);
@@ -11443,6 +11665,7 @@ impl Autoscaling for AutoscalingClient {
         input: DeleteAutoScalingGroupType,
     ) -> Result<(), RusotoError<DeleteAutoScalingGroupError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DeleteAutoScalingGroup");
         let params = self.new_params("DeleteAutoScalingGroup");
         let mut params = params;
         DeleteAutoScalingGroupTypeSerializer::serialize(&mut params, "", &input);
@@ -11463,6 +11686,7 @@ impl Autoscaling for AutoscalingClient {
         input: LaunchConfigurationNameType,
     ) -> Result<(), RusotoError<DeleteLaunchConfigurationError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DeleteLaunchConfiguration");
         let params = self.new_params("DeleteLaunchConfiguration");
         let mut params = params;
         LaunchConfigurationNameTypeSerializer::serialize(&mut params, "", &input);
@@ -11483,6 +11707,7 @@ impl Autoscaling for AutoscalingClient {
         input: DeleteLifecycleHookType,
     ) -> Result<DeleteLifecycleHookAnswer, RusotoError<DeleteLifecycleHookError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DeleteLifecycleHook");
         let params = self.new_params("DeleteLifecycleHook");
         let mut params = params;
         DeleteLifecycleHookTypeSerializer::serialize(&mut params, "", &input);
@@ -11505,6 +11730,7 @@ impl Autoscaling for AutoscalingClient {
         input: DeleteNotificationConfigurationType,
     ) -> Result<(), RusotoError<DeleteNotificationConfigurationError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DeleteNotificationConfiguration");
         let params = self.new_params("DeleteNotificationConfiguration");
         let mut params = params;
         DeleteNotificationConfigurationTypeSerializer::serialize(&mut params, "", &input);
@@ -11525,6 +11751,7 @@ impl Autoscaling for AutoscalingClient {
         input: DeletePolicyType,
     ) -> Result<(), RusotoError<DeletePolicyError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DeletePolicy");
         let params = self.new_params("DeletePolicy");
         let mut params = params;
         DeletePolicyTypeSerializer::serialize(&mut params, "", &input);
@@ -11545,6 +11772,7 @@ impl Autoscaling for AutoscalingClient {
         input: DeleteScheduledActionType,
     ) -> Result<(), RusotoError<DeleteScheduledActionError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DeleteScheduledAction");
         let params = self.new_params("DeleteScheduledAction");
         let mut params = params;
         DeleteScheduledActionTypeSerializer::serialize(&mut params, "", &input);
@@ -11562,6 +11790,7 @@ impl Autoscaling for AutoscalingClient {
     /// <p>Deletes the specified tags.</p>
     async fn delete_tags(&self, input: DeleteTagsType) -> Result<(), RusotoError<DeleteTagsError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DeleteTags");
         let params = self.new_params("DeleteTags");
         let mut params = params;
         DeleteTagsTypeSerializer::serialize(&mut params, "", &input);
@@ -11581,6 +11810,7 @@ impl Autoscaling for AutoscalingClient {
         &self,
     ) -> Result<DescribeAccountLimitsAnswer, RusotoError<DescribeAccountLimitsError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeAccountLimits");
         let params = self.new_params("DescribeAccountLimits");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -11612,6 +11842,7 @@ impl Autoscaling for AutoscalingClient {
         &self,
     ) -> Result<DescribeAdjustmentTypesAnswer, RusotoError<DescribeAdjustmentTypesError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeAdjustmentTypes");
         let params = self.new_params("DescribeAdjustmentTypes");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -11644,6 +11875,7 @@ impl Autoscaling for AutoscalingClient {
         input: AutoScalingGroupNamesType,
     ) -> Result<AutoScalingGroupsType, RusotoError<DescribeAutoScalingGroupsError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeAutoScalingGroups");
         let params = self.new_params("DescribeAutoScalingGroups");
         let mut params = params;
         AutoScalingGroupNamesTypeSerializer::serialize(&mut params, "", &input);
@@ -11677,6 +11909,7 @@ impl Autoscaling for AutoscalingClient {
         input: DescribeAutoScalingInstancesType,
     ) -> Result<AutoScalingInstancesType, RusotoError<DescribeAutoScalingInstancesError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeAutoScalingInstances");
         let params = self.new_params("DescribeAutoScalingInstances");
         let mut params = params;
         DescribeAutoScalingInstancesTypeSerializer::serialize(&mut params, "", &input);
@@ -11712,6 +11945,7 @@ impl Autoscaling for AutoscalingClient {
         RusotoError<DescribeAutoScalingNotificationTypesError>,
     > {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeAutoScalingNotificationTypes");
         let params = self.new_params("DescribeAutoScalingNotificationTypes");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -11747,6 +11981,7 @@ impl Autoscaling for AutoscalingClient {
         input: DescribeInstanceRefreshesType,
     ) -> Result<DescribeInstanceRefreshesAnswer, RusotoError<DescribeInstanceRefreshesError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeInstanceRefreshes");
         let params = self.new_params("DescribeInstanceRefreshes");
         let mut params = params;
         DescribeInstanceRefreshesTypeSerializer::serialize(&mut params, "", &input);
@@ -11780,6 +12015,7 @@ impl Autoscaling for AutoscalingClient {
         input: LaunchConfigurationNamesType,
     ) -> Result<LaunchConfigurationsType, RusotoError<DescribeLaunchConfigurationsError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeLaunchConfigurations");
         let params = self.new_params("DescribeLaunchConfigurations");
         let mut params = params;
         LaunchConfigurationNamesTypeSerializer::serialize(&mut params, "", &input);
@@ -11813,6 +12049,7 @@ impl Autoscaling for AutoscalingClient {
     ) -> Result<DescribeLifecycleHookTypesAnswer, RusotoError<DescribeLifecycleHookTypesError>>
     {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeLifecycleHookTypes");
         let params = self.new_params("DescribeLifecycleHookTypes");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -11845,6 +12082,7 @@ impl Autoscaling for AutoscalingClient {
         input: DescribeLifecycleHooksType,
     ) -> Result<DescribeLifecycleHooksAnswer, RusotoError<DescribeLifecycleHooksError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeLifecycleHooks");
         let params = self.new_params("DescribeLifecycleHooks");
         let mut params = params;
         DescribeLifecycleHooksTypeSerializer::serialize(&mut params, "", &input);
@@ -11881,6 +12119,7 @@ impl Autoscaling for AutoscalingClient {
         RusotoError<DescribeLoadBalancerTargetGroupsError>,
     > {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeLoadBalancerTargetGroups");
         let params = self.new_params("DescribeLoadBalancerTargetGroups");
         let mut params = params;
         DescribeLoadBalancerTargetGroupsRequestSerializer::serialize(&mut params, "", &input);
@@ -11917,6 +12156,7 @@ impl Autoscaling for AutoscalingClient {
         input: DescribeLoadBalancersRequest,
     ) -> Result<DescribeLoadBalancersResponse, RusotoError<DescribeLoadBalancersError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeLoadBalancers");
         let params = self.new_params("DescribeLoadBalancers");
         let mut params = params;
         DescribeLoadBalancersRequestSerializer::serialize(&mut params, "", &input);
@@ -11950,6 +12190,7 @@ impl Autoscaling for AutoscalingClient {
     ) -> Result<DescribeMetricCollectionTypesAnswer, RusotoError<DescribeMetricCollectionTypesError>>
     {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeMetricCollectionTypes");
         let params = self.new_params("DescribeMetricCollectionTypes");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -11985,6 +12226,7 @@ impl Autoscaling for AutoscalingClient {
         RusotoError<DescribeNotificationConfigurationsError>,
     > {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeNotificationConfigurations");
         let params = self.new_params("DescribeNotificationConfigurations");
         let mut params = params;
         DescribeNotificationConfigurationsTypeSerializer::serialize(&mut params, "", &input);
@@ -12021,6 +12263,7 @@ impl Autoscaling for AutoscalingClient {
         input: DescribePoliciesType,
     ) -> Result<PoliciesType, RusotoError<DescribePoliciesError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribePolicies");
         let params = self.new_params("DescribePolicies");
         let mut params = params;
         DescribePoliciesTypeSerializer::serialize(&mut params, "", &input);
@@ -12051,6 +12294,7 @@ impl Autoscaling for AutoscalingClient {
         input: DescribeScalingActivitiesType,
     ) -> Result<ActivitiesType, RusotoError<DescribeScalingActivitiesError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeScalingActivities");
         let params = self.new_params("DescribeScalingActivities");
         let mut params = params;
         DescribeScalingActivitiesTypeSerializer::serialize(&mut params, "", &input);
@@ -12081,6 +12325,7 @@ impl Autoscaling for AutoscalingClient {
         &self,
     ) -> Result<ProcessesType, RusotoError<DescribeScalingProcessTypesError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeScalingProcessTypes");
         let params = self.new_params("DescribeScalingProcessTypes");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -12111,6 +12356,7 @@ impl Autoscaling for AutoscalingClient {
         input: DescribeScheduledActionsType,
     ) -> Result<ScheduledActionsType, RusotoError<DescribeScheduledActionsError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeScheduledActions");
         let params = self.new_params("DescribeScheduledActions");
         let mut params = params;
         DescribeScheduledActionsTypeSerializer::serialize(&mut params, "", &input);
@@ -12144,6 +12390,7 @@ impl Autoscaling for AutoscalingClient {
         input: DescribeTagsType,
     ) -> Result<TagsType, RusotoError<DescribeTagsError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeTags");
         let params = self.new_params("DescribeTags");
         let mut params = params;
         DescribeTagsTypeSerializer::serialize(&mut params, "", &input);
@@ -12176,6 +12423,7 @@ impl Autoscaling for AutoscalingClient {
         RusotoError<DescribeTerminationPolicyTypesError>,
     > {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DescribeTerminationPolicyTypes");
         let params = self.new_params("DescribeTerminationPolicyTypes");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -12208,6 +12456,7 @@ impl Autoscaling for AutoscalingClient {
         input: DetachInstancesQuery,
     ) -> Result<DetachInstancesAnswer, RusotoError<DetachInstancesError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DetachInstances");
         let params = self.new_params("DetachInstances");
         let mut params = params;
         DetachInstancesQuerySerializer::serialize(&mut params, "", &input);
@@ -12242,6 +12491,7 @@ impl Autoscaling for AutoscalingClient {
         RusotoError<DetachLoadBalancerTargetGroupsError>,
     > {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DetachLoadBalancerTargetGroups");
         let params = self.new_params("DetachLoadBalancerTargetGroups");
         let mut params = params;
         DetachLoadBalancerTargetGroupsTypeSerializer::serialize(&mut params, "", &input);
@@ -12264,6 +12514,7 @@ impl Autoscaling for AutoscalingClient {
         input: DetachLoadBalancersType,
     ) -> Result<DetachLoadBalancersResultType, RusotoError<DetachLoadBalancersError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DetachLoadBalancers");
         let params = self.new_params("DetachLoadBalancers");
         let mut params = params;
         DetachLoadBalancersTypeSerializer::serialize(&mut params, "", &input);
@@ -12286,6 +12537,7 @@ impl Autoscaling for AutoscalingClient {
         input: DisableMetricsCollectionQuery,
     ) -> Result<(), RusotoError<DisableMetricsCollectionError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("DisableMetricsCollection");
         let params = self.new_params("DisableMetricsCollection");
         let mut params = params;
         DisableMetricsCollectionQuerySerializer::serialize(&mut params, "", &input);
@@ -12306,6 +12558,7 @@ impl Autoscaling for AutoscalingClient {
         input: EnableMetricsCollectionQuery,
     ) -> Result<(), RusotoError<EnableMetricsCollectionError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("EnableMetricsCollection");
         let params = self.new_params("EnableMetricsCollection");
         let mut params = params;
         EnableMetricsCollectionQuerySerializer::serialize(&mut params, "", &input);
@@ -12326,6 +12579,7 @@ impl Autoscaling for AutoscalingClient {
         input: EnterStandbyQuery,
     ) -> Result<EnterStandbyAnswer, RusotoError<EnterStandbyError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("EnterStandby");
         let params = self.new_params("EnterStandby");
         let mut params = params;
         EnterStandbyQuerySerializer::serialize(&mut params, "", &input);
@@ -12356,6 +12610,7 @@ impl Autoscaling for AutoscalingClient {
         input: ExecutePolicyType,
     ) -> Result<(), RusotoError<ExecutePolicyError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("ExecutePolicy");
         let params = self.new_params("ExecutePolicy");
         let mut params = params;
         ExecutePolicyTypeSerializer::serialize(&mut params, "", &input);
@@ -12376,6 +12631,7 @@ impl Autoscaling for AutoscalingClient {
         input: ExitStandbyQuery,
     ) -> Result<ExitStandbyAnswer, RusotoError<ExitStandbyError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("ExitStandby");
         let params = self.new_params("ExitStandby");
         let mut params = params;
         ExitStandbyQuerySerializer::serialize(&mut params, "", &input);
@@ -12406,6 +12662,7 @@ impl Autoscaling for AutoscalingClient {
         input: PutLifecycleHookType,
     ) -> Result<PutLifecycleHookAnswer, RusotoError<PutLifecycleHookError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("PutLifecycleHook");
         let params = self.new_params("PutLifecycleHook");
         let mut params = params;
         PutLifecycleHookTypeSerializer::serialize(&mut params, "", &input);
@@ -12428,6 +12685,7 @@ impl Autoscaling for AutoscalingClient {
         input: PutNotificationConfigurationType,
     ) -> Result<(), RusotoError<PutNotificationConfigurationError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("PutNotificationConfiguration");
         let params = self.new_params("PutNotificationConfiguration");
         let mut params = params;
         PutNotificationConfigurationTypeSerializer::serialize(&mut params, "", &input);
@@ -12448,6 +12706,7 @@ impl Autoscaling for AutoscalingClient {
         input: PutScalingPolicyType,
     ) -> Result<PolicyARNType, RusotoError<PutScalingPolicyError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("PutScalingPolicy");
         let params = self.new_params("PutScalingPolicy");
         let mut params = params;
         PutScalingPolicyTypeSerializer::serialize(&mut params, "", &input);
@@ -12478,6 +12737,7 @@ impl Autoscaling for AutoscalingClient {
         input: PutScheduledUpdateGroupActionType,
     ) -> Result<(), RusotoError<PutScheduledUpdateGroupActionError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("PutScheduledUpdateGroupAction");
         let params = self.new_params("PutScheduledUpdateGroupAction");
         let mut params = params;
         PutScheduledUpdateGroupActionTypeSerializer::serialize(&mut params, "", &input);
@@ -12501,6 +12761,7 @@ impl Autoscaling for AutoscalingClient {
         RusotoError<RecordLifecycleActionHeartbeatError>,
     > {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("RecordLifecycleActionHeartbeat");
         let params = self.new_params("RecordLifecycleActionHeartbeat");
         let mut params = params;
         RecordLifecycleActionHeartbeatTypeSerializer::serialize(&mut params, "", &input);
@@ -12523,6 +12784,7 @@ impl Autoscaling for AutoscalingClient {
         input: ScalingProcessQuery,
     ) -> Result<(), RusotoError<ResumeProcessesError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("ResumeProcesses");
         let params = self.new_params("ResumeProcesses");
         let mut params = params;
         ScalingProcessQuerySerializer::serialize(&mut params, "", &input);
@@ -12543,6 +12805,7 @@ impl Autoscaling for AutoscalingClient {
         input: SetDesiredCapacityType,
     ) -> Result<(), RusotoError<SetDesiredCapacityError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("SetDesiredCapacity");
         let params = self.new_params("SetDesiredCapacity");
         let mut params = params;
         SetDesiredCapacityTypeSerializer::serialize(&mut params, "", &input);
@@ -12563,6 +12826,7 @@ impl Autoscaling for AutoscalingClient {
         input: SetInstanceHealthQuery,
     ) -> Result<(), RusotoError<SetInstanceHealthError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("SetInstanceHealth");
         let params = self.new_params("SetInstanceHealth");
         let mut params = params;
         SetInstanceHealthQuerySerializer::serialize(&mut params, "", &input);
@@ -12583,6 +12847,7 @@ impl Autoscaling for AutoscalingClient {
         input: SetInstanceProtectionQuery,
     ) -> Result<SetInstanceProtectionAnswer, RusotoError<SetInstanceProtectionError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("SetInstanceProtection");
         let params = self.new_params("SetInstanceProtection");
         let mut params = params;
         SetInstanceProtectionQuerySerializer::serialize(&mut params, "", &input);
@@ -12605,6 +12870,7 @@ impl Autoscaling for AutoscalingClient {
         input: StartInstanceRefreshType,
     ) -> Result<StartInstanceRefreshAnswer, RusotoError<StartInstanceRefreshError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("StartInstanceRefresh");
         let params = self.new_params("StartInstanceRefresh");
         let mut params = params;
         StartInstanceRefreshTypeSerializer::serialize(&mut params, "", &input);
@@ -12638,6 +12904,7 @@ impl Autoscaling for AutoscalingClient {
         input: ScalingProcessQuery,
     ) -> Result<(), RusotoError<SuspendProcessesError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("SuspendProcesses");
         let params = self.new_params("SuspendProcesses");
         let mut params = params;
         ScalingProcessQuerySerializer::serialize(&mut params, "", &input);
@@ -12658,6 +12925,7 @@ impl Autoscaling for AutoscalingClient {
         input: TerminateInstanceInAutoScalingGroupType,
     ) -> Result<ActivityType, RusotoError<TerminateInstanceInAutoScalingGroupError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("TerminateInstanceInAutoScalingGroup");
         let params = self.new_params("TerminateInstanceInAutoScalingGroup");
         let mut params = params;
         TerminateInstanceInAutoScalingGroupTypeSerializer::serialize(&mut params, "", &input);
@@ -12694,6 +12962,7 @@ impl Autoscaling for AutoscalingClient {
         input: UpdateAutoScalingGroupType,
     ) -> Result<(), RusotoError<UpdateAutoScalingGroupError>> {
         let mut request = SignedRequest::new("POST", "autoscaling", &self.region, "/");
+        request.set_operation("UpdateAutoScalingGroup");
         let params = self.new_params("UpdateAutoScalingGroup");
         let mut params = params;
         UpdateAutoScalingGroupTypeSerializer::serialize(&mut params, "", &input);
@@ -12709,6 +12978,597 @@ impl Autoscaling for AutoscalingClient {
     }
 }
 
+/// A blocking client for the Auto Scaling API, running the calls of a `AutoscalingClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `AutoscalingClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct AutoscalingBlockingClient {
+    client: AutoscalingClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl AutoscalingBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> AutoscalingBlockingClient {
+        AutoscalingBlockingClient::from_client(AutoscalingClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> AutoscalingBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        AutoscalingBlockingClient::from_client(AutoscalingClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> AutoscalingBlockingClient {
+        AutoscalingBlockingClient::from_client(AutoscalingClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> AutoscalingBlockingClient {
+        AutoscalingBlockingClient::from_client(AutoscalingClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: AutoscalingClient) -> AutoscalingBlockingClient {
+        AutoscalingBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> AutoscalingBlockingClient {
+        AutoscalingBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> AutoscalingBlockingClient {
+        AutoscalingBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Autoscaling::attach_instances`].
+    pub fn attach_instances(
+        &self,
+        input: AttachInstancesQuery,
+    ) -> Result<(), RusotoError<AttachInstancesError>> {
+        self.runtime.block_on(self.client.attach_instances(input))
+    }
+
+    /// Blocking version of [`Autoscaling::attach_load_balancer_target_groups`].
+    pub fn attach_load_balancer_target_groups(
+        &self,
+        input: AttachLoadBalancerTargetGroupsType,
+    ) -> Result<
+        AttachLoadBalancerTargetGroupsResultType,
+        RusotoError<AttachLoadBalancerTargetGroupsError>,
+    > {
+        self.runtime
+            .block_on(self.client.attach_load_balancer_target_groups(input))
+    }
+
+    /// Blocking version of [`Autoscaling::attach_load_balancers`].
+    pub fn attach_load_balancers(
+        &self,
+        input: AttachLoadBalancersType,
+    ) -> Result<AttachLoadBalancersResultType, RusotoError<AttachLoadBalancersError>> {
+        self.runtime
+            .block_on(self.client.attach_load_balancers(input))
+    }
+
+    /// Blocking version of [`Autoscaling::batch_delete_scheduled_action`].
+    pub fn batch_delete_scheduled_action(
+        &self,
+        input: BatchDeleteScheduledActionType,
+    ) -> Result<BatchDeleteScheduledActionAnswer, RusotoError<BatchDeleteScheduledActionError>>
+    {
+        self.runtime
+            .block_on(self.client.batch_delete_scheduled_action(input))
+    }
+
+    /// Blocking version of [`Autoscaling::batch_put_scheduled_update_group_action`].
+    pub fn batch_put_scheduled_update_group_action(
+        &self,
+        input: BatchPutScheduledUpdateGroupActionType,
+    ) -> Result<
+        BatchPutScheduledUpdateGroupActionAnswer,
+        RusotoError<BatchPutScheduledUpdateGroupActionError>,
+    > {
+        self.runtime
+            .block_on(self.client.batch_put_scheduled_update_group_action(input))
+    }
+
+    /// Blocking version of [`Autoscaling::cancel_instance_refresh`].
+    pub fn cancel_instance_refresh(
+        &self,
+        input: CancelInstanceRefreshType,
+    ) -> Result<CancelInstanceRefreshAnswer, RusotoError<CancelInstanceRefreshError>> {
+        self.runtime
+            .block_on(self.client.cancel_instance_refresh(input))
+    }
+
+    /// Blocking version of [`Autoscaling::complete_lifecycle_action`].
+    pub fn complete_lifecycle_action(
+        &self,
+        input: CompleteLifecycleActionType,
+    ) -> Result<CompleteLifecycleActionAnswer, RusotoError<CompleteLifecycleActionError>> {
+        self.runtime
+            .block_on(self.client.complete_lifecycle_action(input))
+    }
+
+    /// Blocking version of [`Autoscaling::create_auto_scaling_group`].
+    pub fn create_auto_scaling_group(
+        &self,
+        input: CreateAutoScalingGroupType,
+    ) -> Result<(), RusotoError<CreateAutoScalingGroupError>> {
+        self.runtime
+            .block_on(self.client.create_auto_scaling_group(input))
+    }
+
+    /// Blocking version of [`Autoscaling::create_launch_configuration`].
+    pub fn create_launch_configuration(
+        &self,
+        input: CreateLaunchConfigurationType,
+    ) -> Result<(), RusotoError<CreateLaunchConfigurationError>> {
+        self.runtime
+            .block_on(self.client.create_launch_configuration(input))
+    }
+
+    /// Blocking version of [`Autoscaling::create_or_update_tags`].
+    pub fn create_or_update_tags(
+        &self,
+        input: CreateOrUpdateTagsType,
+    ) -> Result<(), RusotoError<CreateOrUpdateTagsError>> {
+        self.runtime
+            .block_on(self.client.create_or_update_tags(input))
+    }
+
+    /// Blocking version of [`Autoscaling::delete_auto_scaling_group`].
+    pub fn delete_auto_scaling_group(
+        &self,
+        input: DeleteAutoScalingGroupType,
+    ) -> Result<(), RusotoError<DeleteAutoScalingGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_auto_scaling_group(input))
+    }
+
+    /// Blocking version of [`Autoscaling::delete_launch_configuration`].
+    pub fn delete_launch_configuration(
+        &self,
+        input: LaunchConfigurationNameType,
+    ) -> Result<(), RusotoError<DeleteLaunchConfigurationError>> {
+        self.runtime
+            .block_on(self.client.delete_launch_configuration(input))
+    }
+
+    /// Blocking version of [`Autoscaling::delete_lifecycle_hook`].
+    pub fn delete_lifecycle_hook(
+        &self,
+        input: DeleteLifecycleHookType,
+    ) -> Result<DeleteLifecycleHookAnswer, RusotoError<DeleteLifecycleHookError>> {
+        self.runtime
+            .block_on(self.client.delete_lifecycle_hook(input))
+    }
+
+    /// Blocking version of [`Autoscaling::delete_notification_configuration`].
+    pub fn delete_notification_configuration(
+        &self,
+        input: DeleteNotificationConfigurationType,
+    ) -> Result<(), RusotoError<DeleteNotificationConfigurationError>> {
+        self.runtime
+            .block_on(self.client.delete_notification_configuration(input))
+    }
+
+    /// Blocking version of [`Autoscaling::delete_policy`].
+    pub fn delete_policy(
+        &self,
+        input: DeletePolicyType,
+    ) -> Result<(), RusotoError<DeletePolicyError>> {
+        self.runtime.block_on(self.client.delete_policy(input))
+    }
+
+    /// Blocking version of [`Autoscaling::delete_scheduled_action`].
+    pub fn delete_scheduled_action(
+        &self,
+        input: DeleteScheduledActionType,
+    ) -> Result<(), RusotoError<DeleteScheduledActionError>> {
+        self.runtime
+            .block_on(self.client.delete_scheduled_action(input))
+    }
+
+    /// Blocking version of [`Autoscaling::delete_tags`].
+    pub fn delete_tags(&self, input: DeleteTagsType) -> Result<(), RusotoError<DeleteTagsError>> {
+        self.runtime.block_on(self.client.delete_tags(input))
+    }
+
+    /// Blocking version of [`Autoscaling::describe_account_limits`].
+    pub fn describe_account_limits(
+        &self,
+    ) -> Result<DescribeAccountLimitsAnswer, RusotoError<DescribeAccountLimitsError>> {
+        self.runtime.block_on(self.client.describe_account_limits())
+    }
+
+    /// Blocking version of [`Autoscaling::describe_adjustment_types`].
+    pub fn describe_adjustment_types(
+        &self,
+    ) -> Result<DescribeAdjustmentTypesAnswer, RusotoError<DescribeAdjustmentTypesError>> {
+        self.runtime
+            .block_on(self.client.describe_adjustment_types())
+    }
+
+    /// Blocking version of [`Autoscaling::describe_auto_scaling_groups`].
+    pub fn describe_auto_scaling_groups(
+        &self,
+        input: AutoScalingGroupNamesType,
+    ) -> Result<AutoScalingGroupsType, RusotoError<DescribeAutoScalingGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_auto_scaling_groups(input))
+    }
+
+    /// Blocking version of [`Autoscaling::describe_auto_scaling_instances`].
+    pub fn describe_auto_scaling_instances(
+        &self,
+        input: DescribeAutoScalingInstancesType,
+    ) -> Result<AutoScalingInstancesType, RusotoError<DescribeAutoScalingInstancesError>> {
+        self.runtime
+            .block_on(self.client.describe_auto_scaling_instances(input))
+    }
+
+    /// Blocking version of [`Autoscaling::describe_auto_scaling_notification_types`].
+    pub fn describe_auto_scaling_notification_types(
+        &self,
+    ) -> Result<
+        DescribeAutoScalingNotificationTypesAnswer,
+        RusotoError<DescribeAutoScalingNotificationTypesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_auto_scaling_notification_types())
+    }
+
+    /// Blocking version of [`Autoscaling::describe_instance_refreshes`].
+    pub fn describe_instance_refreshes(
+        &self,
+        input: DescribeInstanceRefreshesType,
+    ) -> Result<DescribeInstanceRefreshesAnswer, RusotoError<DescribeInstanceRefreshesError>> {
+        self.runtime
+            .block_on(self.client.describe_instance_refreshes(input))
+    }
+
+    /// Blocking version of [`Autoscaling::describe_launch_configurations`].
+    pub fn describe_launch_configurations(
+        &self,
+        input: LaunchConfigurationNamesType,
+    ) -> Result<LaunchConfigurationsType, RusotoError<DescribeLaunchConfigurationsError>> {
+        self.runtime
+            .block_on(self.client.describe_launch_configurations(input))
+    }
+
+    /// Blocking version of [`Autoscaling::describe_lifecycle_hook_types`].
+    pub fn describe_lifecycle_hook_types(
+        &self,
+    ) -> Result<DescribeLifecycleHookTypesAnswer, RusotoError<DescribeLifecycleHookTypesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_lifecycle_hook_types())
+    }
+
+    /// Blocking version of [`Autoscaling::describe_lifecycle_hooks`].
+    pub fn describe_lifecycle_hooks(
+        &self,
+        input: DescribeLifecycleHooksType,
+    ) -> Result<DescribeLifecycleHooksAnswer, RusotoError<DescribeLifecycleHooksError>> {
+        self.runtime
+            .block_on(self.client.describe_lifecycle_hooks(input))
+    }
+
+    /// Blocking version of [`Autoscaling::describe_load_balancer_target_groups`].
+    pub fn describe_load_balancer_target_groups(
+        &self,
+        input: DescribeLoadBalancerTargetGroupsRequest,
+    ) -> Result<
+        DescribeLoadBalancerTargetGroupsResponse,
+        RusotoError<DescribeLoadBalancerTargetGroupsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_load_balancer_target_groups(input))
+    }
+
+    /// Blocking version of [`Autoscaling::describe_load_balancers`].
+    pub fn describe_load_balancers(
+        &self,
+        input: DescribeLoadBalancersRequest,
+    ) -> Result<DescribeLoadBalancersResponse, RusotoError<DescribeLoadBalancersError>> {
+        self.runtime
+            .block_on(self.client.describe_load_balancers(input))
+    }
+
+    /// Blocking version of [`Autoscaling::describe_metric_collection_types`].
+    pub fn describe_metric_collection_types(
+        &self,
+    ) -> Result<DescribeMetricCollectionTypesAnswer, RusotoError<DescribeMetricCollectionTypesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_metric_collection_types())
+    }
+
+    /// Blocking version of [`Autoscaling::describe_notification_configurations`].
+    pub fn describe_notification_configurations(
+        &self,
+        input: DescribeNotificationConfigurationsType,
+    ) -> Result<
+        DescribeNotificationConfigurationsAnswer,
+        RusotoError<DescribeNotificationConfigurationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_notification_configurations(input))
+    }
+
+    /// Blocking version of [`Autoscaling::describe_policies`].
+    pub fn describe_policies(
+        &self,
+        input: DescribePoliciesType,
+    ) -> Result<PoliciesType, RusotoError<DescribePoliciesError>> {
+        self.runtime.block_on(self.client.describe_policies(input))
+    }
+
+    /// Blocking version of [`Autoscaling::describe_scaling_activities`].
+    pub fn describe_scaling_activities(
+        &self,
+        input: DescribeScalingActivitiesType,
+    ) -> Result<ActivitiesType, RusotoError<DescribeScalingActivitiesError>> {
+        self.runtime
+            .block_on(self.client.describe_scaling_activities(input))
+    }
+
+    /// Blocking version of [`Autoscaling::describe_scaling_process_types`].
+    pub fn describe_scaling_process_types(
+        &self,
+    ) -> Result<ProcessesType, RusotoError<DescribeScalingProcessTypesError>> {
+        self.runtime
+            .block_on(self.client.describe_scaling_process_types())
+    }
+
+    /// Blocking version of [`Autoscaling::describe_scheduled_actions`].
+    pub fn describe_scheduled_actions(
+        &self,
+        input: DescribeScheduledActionsType,
+    ) -> Result<ScheduledActionsType, RusotoError<DescribeScheduledActionsError>> {
+        self.runtime
+            .block_on(self.client.describe_scheduled_actions(input))
+    }
+
+    /// Blocking version of [`Autoscaling::describe_tags`].
+    pub fn describe_tags(
+        &self,
+        input: DescribeTagsType,
+    ) -> Result<TagsType, RusotoError<DescribeTagsError>> {
+        self.runtime.block_on(self.client.describe_tags(input))
+    }
+
+    /// Blocking version of [`Autoscaling::describe_termination_policy_types`].
+    pub fn describe_termination_policy_types(
+        &self,
+    ) -> Result<
+        DescribeTerminationPolicyTypesAnswer,
+        RusotoError<DescribeTerminationPolicyTypesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_termination_policy_types())
+    }
+
+    /// Blocking version of [`Autoscaling::detach_instances`].
+    pub fn detach_instances(
+        &self,
+        input: DetachInstancesQuery,
+    ) -> Result<DetachInstancesAnswer, RusotoError<DetachInstancesError>> {
+        self.runtime.block_on(self.client.detach_instances(input))
+    }
+
+    /// Blocking version of [`Autoscaling::detach_load_balancer_target_groups`].
+    pub fn detach_load_balancer_target_groups(
+        &self,
+        input: DetachLoadBalancerTargetGroupsType,
+    ) -> Result<
+        DetachLoadBalancerTargetGroupsResultType,
+        RusotoError<DetachLoadBalancerTargetGroupsError>,
+    > {
+        self.runtime
+            .block_on(self.client.detach_load_balancer_target_groups(input))
+    }
+
+    /// Blocking version of [`Autoscaling::detach_load_balancers`].
+    pub fn detach_load_balancers(
+        &self,
+        input: DetachLoadBalancersType,
+    ) -> Result<DetachLoadBalancersResultType, RusotoError<DetachLoadBalancersError>> {
+        self.runtime
+            .block_on(self.client.detach_load_balancers(input))
+    }
+
+    /// Blocking version of [`Autoscaling::disable_metrics_collection`].
+    pub fn disable_metrics_collection(
+        &self,
+        input: DisableMetricsCollectionQuery,
+    ) -> Result<(), RusotoError<DisableMetricsCollectionError>> {
+        self.runtime
+            .block_on(self.client.disable_metrics_collection(input))
+    }
+
+    /// Blocking version of [`Autoscaling::enable_metrics_collection`].
+    pub fn enable_metrics_collection(
+        &self,
+        input: EnableMetricsCollectionQuery,
+    ) -> Result<(), RusotoError<EnableMetricsCollectionError>> {
+        self.runtime
+            .block_on(self.client.enable_metrics_collection(input))
+    }
+
+    /// Blocking version of [`Autoscaling::enter_standby`].
+    pub fn enter_standby(
+        &self,
+        input: EnterStandbyQuery,
+    ) -> Result<EnterStandbyAnswer, RusotoError<EnterStandbyError>> {
+        self.runtime.block_on(self.client.enter_standby(input))
+    }
+
+    /// Blocking version of [`Autoscaling::execute_policy`].
+    pub fn execute_policy(
+        &self,
+        input: ExecutePolicyType,
+    ) -> Result<(), RusotoError<ExecutePolicyError>> {
+        self.runtime.block_on(self.client.execute_policy(input))
+    }
+
+    /// Blocking version of [`Autoscaling::exit_standby`].
+    pub fn exit_standby(
+        &self,
+        input: ExitStandbyQuery,
+    ) -> Result<ExitStandbyAnswer, RusotoError<ExitStandbyError>> {
+        self.runtime.block_on(self.client.exit_standby(input))
+    }
+
+    /// Blocking version of [`Autoscaling::put_lifecycle_hook`].
+    pub fn put_lifecycle_hook(
+        &self,
+        input: PutLifecycleHookType,
+    ) -> Result<PutLifecycleHookAnswer, RusotoError<PutLifecycleHookError>> {
+        self.runtime.block_on(self.client.put_lifecycle_hook(input))
+    }
+
+    /// Blocking version of [`Autoscaling::put_notification_configuration`].
+    pub fn put_notification_configuration(
+        &self,
+        input: PutNotificationConfigurationType,
+    ) -> Result<(), RusotoError<PutNotificationConfigurationError>> {
+        self.runtime
+            .block_on(self.client.put_notification_configuration(input))
+    }
+
+    /// Blocking version of [`Autoscaling::put_scaling_policy`].
+    pub fn put_scaling_policy(
+        &self,
+        input: PutScalingPolicyType,
+    ) -> Result<PolicyARNType, RusotoError<PutScalingPolicyError>> {
+        self.runtime.block_on(self.client.put_scaling_policy(input))
+    }
+
+    /// Blocking version of [`Autoscaling::put_scheduled_update_group_action`].
+    pub fn put_scheduled_update_group_action(
+        &self,
+        input: PutScheduledUpdateGroupActionType,
+    ) -> Result<(), RusotoError<PutScheduledUpdateGroupActionError>> {
+        self.runtime
+            .block_on(self.client.put_scheduled_update_group_action(input))
+    }
+
+    /// Blocking version of [`Autoscaling::record_lifecycle_action_heartbeat`].
+    pub fn record_lifecycle_action_heartbeat(
+        &self,
+        input: RecordLifecycleActionHeartbeatType,
+    ) -> Result<
+        RecordLifecycleActionHeartbeatAnswer,
+        RusotoError<RecordLifecycleActionHeartbeatError>,
+    > {
+        self.runtime
+            .block_on(self.client.record_lifecycle_action_heartbeat(input))
+    }
+
+    /// Blocking version of [`Autoscaling::resume_processes`].
+    pub fn resume_processes(
+        &self,
+        input: ScalingProcessQuery,
+    ) -> Result<(), RusotoError<ResumeProcessesError>> {
+        self.runtime.block_on(self.client.resume_processes(input))
+    }
+
+    /// Blocking version of [`Autoscaling::set_desired_capacity`].
+    pub fn set_desired_capacity(
+        &self,
+        input: SetDesiredCapacityType,
+    ) -> Result<(), RusotoError<SetDesiredCapacityError>> {
+        self.runtime
+            .block_on(self.client.set_desired_capacity(input))
+    }
+
+    /// Blocking version of [`Autoscaling::set_instance_health`].
+    pub fn set_instance_health(
+        &self,
+        input: SetInstanceHealthQuery,
+    ) -> Result<(), RusotoError<SetInstanceHealthError>> {
+        self.runtime
+            .block_on(self.client.set_instance_health(input))
+    }
+
+    /// Blocking version of [`Autoscaling::set_instance_protection`].
+    pub fn set_instance_protection(
+        &self,
+        input: SetInstanceProtectionQuery,
+    ) -> Result<SetInstanceProtectionAnswer, RusotoError<SetInstanceProtectionError>> {
+        self.runtime
+            .block_on(self.client.set_instance_protection(input))
+    }
+
+    /// Blocking version of [`Autoscaling::start_instance_refresh`].
+    pub fn start_instance_refresh(
+        &self,
+        input: StartInstanceRefreshType,
+    ) -> Result<StartInstanceRefreshAnswer, RusotoError<StartInstanceRefreshError>> {
+        self.runtime
+            .block_on(self.client.start_instance_refresh(input))
+    }
+
+    /// Blocking version of [`Autoscaling::suspend_processes`].
+    pub fn suspend_processes(
+        &self,
+        input: ScalingProcessQuery,
+    ) -> Result<(), RusotoError<SuspendProcessesError>> {
+        self.runtime.block_on(self.client.suspend_processes(input))
+    }
+
+    /// Blocking version of [`Autoscaling::terminate_instance_in_auto_scaling_group`].
+    pub fn terminate_instance_in_auto_scaling_group(
+        &self,
+        input: TerminateInstanceInAutoScalingGroupType,
+    ) -> Result<ActivityType, RusotoError<TerminateInstanceInAutoScalingGroupError>> {
+        self.runtime
+            .block_on(self.client.terminate_instance_in_auto_scaling_group(input))
+    }
+
+    /// Blocking version of [`Autoscaling::update_auto_scaling_group`].
+    pub fn update_auto_scaling_group(
+        &self,
+        input: UpdateAutoScalingGroupType,
+    ) -> Result<(), RusotoError<UpdateAutoScalingGroupError>> {
+        self.runtime
+            .block_on(self.client.update_auto_scaling_group(input))
+    }
+}
+
 #[cfg(test)]
 mod protocol_tests {
 
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -2169,25 +2170,46 @@ impl CreateBackupPlanError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateBackupPlanError::AlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBackupPlanError::AlreadyExists,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateBackupPlanError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateBackupPlanError::InvalidParameterValue,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateBackupPlanError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBackupPlanError::LimitExceeded,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(CreateBackupPlanError::MissingParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateBackupPlanError::MissingParameterValue,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateBackupPlanError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBackupPlanError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2227,27 +2249,46 @@ impl CreateBackupSelectionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateBackupSelectionError::AlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBackupSelectionError::AlreadyExists,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateBackupSelectionError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateBackupSelectionError::InvalidParameterValue,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateBackupSelectionError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBackupSelectionError::LimitExceeded,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(CreateBackupSelectionError::MissingParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateBackupSelectionError::MissingParameterValue,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateBackupSelectionError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateBackupSelectionError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2287,27 +2328,46 @@ impl CreateBackupVaultError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateBackupVaultError::AlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBackupVaultError::AlreadyExists,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateBackupVaultError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateBackupVaultError::InvalidParameterValue,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateBackupVaultError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBackupVaultError::LimitExceeded,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(CreateBackupVaultError::MissingParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateBackupVaultError::MissingParameterValue,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateBackupVaultError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateBackupVaultError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2347,25 +2407,46 @@ impl DeleteBackupPlanError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteBackupPlanError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteBackupPlanError::InvalidParameterValue,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteBackupPlanError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBackupPlanError::InvalidRequest,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(DeleteBackupPlanError::MissingParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteBackupPlanError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteBackupPlanError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBackupPlanError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteBackupPlanError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBackupPlanError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2403,26 +2484,38 @@ impl DeleteBackupSelectionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteBackupSelectionError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteBackupSelectionError::InvalidParameterValue,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(DeleteBackupSelectionError::MissingParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteBackupSelectionError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteBackupSelectionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteBackupSelectionError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteBackupSelectionError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteBackupSelectionError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2461,27 +2554,46 @@ impl DeleteBackupVaultError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteBackupVaultError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteBackupVaultError::InvalidParameterValue,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteBackupVaultError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBackupVaultError::InvalidRequest,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(DeleteBackupVaultError::MissingParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteBackupVaultError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteBackupVaultError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBackupVaultError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteBackupVaultError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteBackupVaultError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2521,26 +2633,38 @@ impl DeleteBackupVaultAccessPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DeleteBackupVaultAccessPolicyError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBackupVaultAccessPolicyError::InvalidParameterValue,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
-                        DeleteBackupVaultAccessPolicyError::MissingParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBackupVaultAccessPolicyError::MissingParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteBackupVaultAccessPolicyError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBackupVaultAccessPolicyError::ResourceNotFound,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DeleteBackupVaultAccessPolicyError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBackupVaultAccessPolicyError::ServiceUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2587,26 +2711,38 @@ impl DeleteBackupVaultNotificationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DeleteBackupVaultNotificationsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBackupVaultNotificationsError::InvalidParameterValue,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
-                        DeleteBackupVaultNotificationsError::MissingParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBackupVaultNotificationsError::MissingParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteBackupVaultNotificationsError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBackupVaultNotificationsError::ResourceNotFound,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DeleteBackupVaultNotificationsError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBackupVaultNotificationsError::ServiceUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2653,29 +2789,46 @@ impl DeleteRecoveryPointError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteRecoveryPointError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRecoveryPointError::InvalidParameterValue,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteRecoveryPointError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRecoveryPointError::InvalidRequest,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(DeleteRecoveryPointError::MissingParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRecoveryPointError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteRecoveryPointError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRecoveryPointError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteRecoveryPointError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRecoveryPointError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2715,27 +2868,46 @@ impl DescribeBackupJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyFailureException" => {
-                    return RusotoError::Service(DescribeBackupJobError::DependencyFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBackupJobError::DependencyFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeBackupJobError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeBackupJobError::InvalidParameterValue,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(DescribeBackupJobError::MissingParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeBackupJobError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeBackupJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBackupJobError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeBackupJobError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeBackupJobError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2773,26 +2945,38 @@ impl DescribeBackupVaultError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeBackupVaultError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeBackupVaultError::InvalidParameterValue,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(DescribeBackupVaultError::MissingParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeBackupVaultError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeBackupVaultError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeBackupVaultError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeBackupVaultError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeBackupVaultError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2829,22 +3013,38 @@ impl DescribeCopyJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeCopyJobError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeCopyJobError::InvalidParameterValue,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(DescribeCopyJobError::MissingParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeCopyJobError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeCopyJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCopyJobError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeCopyJobError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCopyJobError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2875,11 +3075,14 @@ impl DescribeGlobalSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeGlobalSettingsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeGlobalSettingsError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2913,26 +3116,38 @@ impl DescribeProtectedResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DescribeProtectedResourceError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeProtectedResourceError::InvalidParameterValue,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
-                        DescribeProtectedResourceError::MissingParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeProtectedResourceError::MissingParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeProtectedResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeProtectedResourceError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DescribeProtectedResourceError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeProtectedResourceError::ServiceUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2973,26 +3188,38 @@ impl DescribeRecoveryPointError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeRecoveryPointError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRecoveryPointError::InvalidParameterValue,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(DescribeRecoveryPointError::MissingParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRecoveryPointError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeRecoveryPointError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRecoveryPointError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeRecoveryPointError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRecoveryPointError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3023,11 +3250,14 @@ impl DescribeRegionSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeRegionSettingsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRegionSettingsError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3063,29 +3293,46 @@ impl DescribeRestoreJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyFailureException" => {
-                    return RusotoError::Service(DescribeRestoreJobError::DependencyFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRestoreJobError::DependencyFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeRestoreJobError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRestoreJobError::InvalidParameterValue,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(DescribeRestoreJobError::MissingParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRestoreJobError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeRestoreJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRestoreJobError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeRestoreJobError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRestoreJobError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3123,26 +3370,38 @@ impl ExportBackupPlanTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        ExportBackupPlanTemplateError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExportBackupPlanTemplateError::InvalidParameterValue,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
-                        ExportBackupPlanTemplateError::MissingParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExportBackupPlanTemplateError::MissingParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ExportBackupPlanTemplateError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ExportBackupPlanTemplateError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ExportBackupPlanTemplateError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ExportBackupPlanTemplateError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3183,18 +3442,38 @@ impl GetBackupPlanError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetBackupPlanError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBackupPlanError::InvalidParameterValue,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(GetBackupPlanError::MissingParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBackupPlanError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetBackupPlanError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBackupPlanError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetBackupPlanError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBackupPlanError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3233,29 +3512,46 @@ impl GetBackupPlanFromJSONError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetBackupPlanFromJSONError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetBackupPlanFromJSONError::InvalidParameterValue,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetBackupPlanFromJSONError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetBackupPlanFromJSONError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetBackupPlanFromJSONError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBackupPlanFromJSONError::LimitExceeded,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(GetBackupPlanFromJSONError::MissingParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetBackupPlanFromJSONError::MissingParameterValue,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetBackupPlanFromJSONError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetBackupPlanFromJSONError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3293,26 +3589,38 @@ impl GetBackupPlanFromTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        GetBackupPlanFromTemplateError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBackupPlanFromTemplateError::InvalidParameterValue,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
-                        GetBackupPlanFromTemplateError::MissingParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBackupPlanFromTemplateError::MissingParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetBackupPlanFromTemplateError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetBackupPlanFromTemplateError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        GetBackupPlanFromTemplateError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBackupPlanFromTemplateError::ServiceUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3353,24 +3661,38 @@ impl GetBackupSelectionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetBackupSelectionError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetBackupSelectionError::InvalidParameterValue,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(GetBackupSelectionError::MissingParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetBackupSelectionError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetBackupSelectionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBackupSelectionError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetBackupSelectionError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetBackupSelectionError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3409,26 +3731,38 @@ impl GetBackupVaultAccessPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        GetBackupVaultAccessPolicyError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBackupVaultAccessPolicyError::InvalidParameterValue,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
-                        GetBackupVaultAccessPolicyError::MissingParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBackupVaultAccessPolicyError::MissingParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetBackupVaultAccessPolicyError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetBackupVaultAccessPolicyError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        GetBackupVaultAccessPolicyError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBackupVaultAccessPolicyError::ServiceUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3473,26 +3807,38 @@ impl GetBackupVaultNotificationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        GetBackupVaultNotificationsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBackupVaultNotificationsError::InvalidParameterValue,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
-                        GetBackupVaultNotificationsError::MissingParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBackupVaultNotificationsError::MissingParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        GetBackupVaultNotificationsError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBackupVaultNotificationsError::ResourceNotFound,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        GetBackupVaultNotificationsError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBackupVaultNotificationsError::ServiceUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3537,26 +3883,38 @@ impl GetRecoveryPointRestoreMetadataError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        GetRecoveryPointRestoreMetadataError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRecoveryPointRestoreMetadataError::InvalidParameterValue,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
-                        GetRecoveryPointRestoreMetadataError::MissingParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRecoveryPointRestoreMetadataError::MissingParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        GetRecoveryPointRestoreMetadataError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRecoveryPointRestoreMetadataError::ResourceNotFound,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        GetRecoveryPointRestoreMetadataError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRecoveryPointRestoreMetadataError::ServiceUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3595,11 +3953,14 @@ impl GetSupportedResourceTypesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        GetSupportedResourceTypesError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSupportedResourceTypesError::ServiceUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3629,14 +3990,22 @@ impl ListBackupJobsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListBackupJobsError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListBackupJobsError::InvalidParameterValue,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListBackupJobsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBackupJobsError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3671,26 +4040,38 @@ impl ListBackupPlanTemplatesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        ListBackupPlanTemplatesError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBackupPlanTemplatesError::InvalidParameterValue,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
-                        ListBackupPlanTemplatesError::MissingParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBackupPlanTemplatesError::MissingParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListBackupPlanTemplatesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListBackupPlanTemplatesError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListBackupPlanTemplatesError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListBackupPlanTemplatesError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3731,26 +4112,38 @@ impl ListBackupPlanVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        ListBackupPlanVersionsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBackupPlanVersionsError::InvalidParameterValue,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
-                        ListBackupPlanVersionsError::MissingParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBackupPlanVersionsError::MissingParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListBackupPlanVersionsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListBackupPlanVersionsError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListBackupPlanVersionsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListBackupPlanVersionsError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3787,22 +4180,38 @@ impl ListBackupPlansError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListBackupPlansError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListBackupPlansError::InvalidParameterValue,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(ListBackupPlansError::MissingParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListBackupPlansError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListBackupPlansError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBackupPlansError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListBackupPlansError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBackupPlansError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3839,26 +4248,38 @@ impl ListBackupSelectionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListBackupSelectionsError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListBackupSelectionsError::InvalidParameterValue,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(ListBackupSelectionsError::MissingParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListBackupSelectionsError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListBackupSelectionsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListBackupSelectionsError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListBackupSelectionsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListBackupSelectionsError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3895,22 +4316,38 @@ impl ListBackupVaultsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListBackupVaultsError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListBackupVaultsError::InvalidParameterValue,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(ListBackupVaultsError::MissingParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListBackupVaultsError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListBackupVaultsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBackupVaultsError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListBackupVaultsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBackupVaultsError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3943,12 +4380,22 @@ impl ListCopyJobsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListCopyJobsError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCopyJobsError::InvalidParameterValue,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListCopyJobsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCopyJobsError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3979,16 +4426,22 @@ impl ListProtectedResourcesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        ListProtectedResourcesError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProtectedResourcesError::InvalidParameterValue,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListProtectedResourcesError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListProtectedResourcesError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4025,26 +4478,38 @@ impl ListRecoveryPointsByBackupVaultError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        ListRecoveryPointsByBackupVaultError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRecoveryPointsByBackupVaultError::InvalidParameterValue,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
-                        ListRecoveryPointsByBackupVaultError::MissingParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRecoveryPointsByBackupVaultError::MissingParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        ListRecoveryPointsByBackupVaultError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRecoveryPointsByBackupVaultError::ResourceNotFound,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        ListRecoveryPointsByBackupVaultError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRecoveryPointsByBackupVaultError::ServiceUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4091,26 +4556,38 @@ impl ListRecoveryPointsByResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        ListRecoveryPointsByResourceError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRecoveryPointsByResourceError::InvalidParameterValue,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
-                        ListRecoveryPointsByResourceError::MissingParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRecoveryPointsByResourceError::MissingParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        ListRecoveryPointsByResourceError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRecoveryPointsByResourceError::ResourceNotFound,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        ListRecoveryPointsByResourceError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRecoveryPointsByResourceError::ServiceUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4155,22 +4632,38 @@ impl ListRestoreJobsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListRestoreJobsError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListRestoreJobsError::InvalidParameterValue,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(ListRestoreJobsError::MissingParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListRestoreJobsError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListRestoreJobsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRestoreJobsError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListRestoreJobsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRestoreJobsError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4207,18 +4700,38 @@ impl ListTagsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListTagsError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsError::InvalidParameterValue,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(ListTagsError::MissingParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListTagsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4257,26 +4770,38 @@ impl PutBackupVaultAccessPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        PutBackupVaultAccessPolicyError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutBackupVaultAccessPolicyError::InvalidParameterValue,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
-                        PutBackupVaultAccessPolicyError::MissingParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutBackupVaultAccessPolicyError::MissingParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutBackupVaultAccessPolicyError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutBackupVaultAccessPolicyError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        PutBackupVaultAccessPolicyError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutBackupVaultAccessPolicyError::ServiceUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4321,26 +4846,38 @@ impl PutBackupVaultNotificationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        PutBackupVaultNotificationsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutBackupVaultNotificationsError::InvalidParameterValue,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
-                        PutBackupVaultNotificationsError::MissingParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutBackupVaultNotificationsError::MissingParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        PutBackupVaultNotificationsError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutBackupVaultNotificationsError::ResourceNotFound,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        PutBackupVaultNotificationsError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutBackupVaultNotificationsError::ServiceUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4387,28 +4924,54 @@ impl StartBackupJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(StartBackupJobError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartBackupJobError::InvalidParameterValue,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartBackupJobError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartBackupJobError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartBackupJobError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartBackupJobError::LimitExceeded,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(StartBackupJobError::MissingParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartBackupJobError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartBackupJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartBackupJobError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(StartBackupJobError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartBackupJobError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4449,21 +5012,46 @@ impl StartCopyJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(StartCopyJobError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartCopyJobError::InvalidParameterValue,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartCopyJobError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartCopyJobError::LimitExceeded,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(StartCopyJobError::MissingParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartCopyJobError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartCopyJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartCopyJobError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(StartCopyJobError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartCopyJobError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4501,22 +5089,38 @@ impl StartRestoreJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(StartRestoreJobError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartRestoreJobError::InvalidParameterValue,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(StartRestoreJobError::MissingParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartRestoreJobError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartRestoreJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartRestoreJobError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(StartRestoreJobError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartRestoreJobError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4555,21 +5159,46 @@ impl StopBackupJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(StopBackupJobError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopBackupJobError::InvalidParameterValue,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopBackupJobError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopBackupJobError::InvalidRequest,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(StopBackupJobError::MissingParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopBackupJobError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopBackupJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopBackupJobError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(StopBackupJobError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopBackupJobError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4609,21 +5238,46 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidParameterValue,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::LimitExceeded,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(TagResourceError::MissingParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(TagResourceError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4661,18 +5315,38 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidParameterValue,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(UntagResourceError::MissingParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UntagResourceError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4709,22 +5383,38 @@ impl UpdateBackupPlanError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateBackupPlanError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateBackupPlanError::InvalidParameterValue,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(UpdateBackupPlanError::MissingParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateBackupPlanError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateBackupPlanError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBackupPlanError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateBackupPlanError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBackupPlanError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4761,24 +5451,38 @@ impl UpdateGlobalSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateGlobalSettingsError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateGlobalSettingsError::InvalidParameterValue,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateGlobalSettingsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGlobalSettingsError::InvalidRequest,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(UpdateGlobalSettingsError::MissingParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateGlobalSettingsError::MissingParameterValue,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateGlobalSettingsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateGlobalSettingsError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4817,26 +5521,38 @@ impl UpdateRecoveryPointLifecycleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        UpdateRecoveryPointLifecycleError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRecoveryPointLifecycleError::InvalidParameterValue,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
-                        UpdateRecoveryPointLifecycleError::MissingParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRecoveryPointLifecycleError::MissingParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateRecoveryPointLifecycleError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRecoveryPointLifecycleError::ResourceNotFound,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        UpdateRecoveryPointLifecycleError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRecoveryPointLifecycleError::ServiceUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4879,21 +5595,30 @@ impl UpdateRegionSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateRegionSettingsError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRegionSettingsError::InvalidParameterValue,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(UpdateRegionSettingsError::MissingParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRegionSettingsError::MissingParameterValue,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateRegionSettingsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRegionSettingsError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5227,11 +5952,12 @@ pub struct BackupClient {
 impl BackupClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> BackupClient {
         BackupClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Backup"),
         }
     }
 
@@ -5246,12 +5972,32 @@ impl BackupClient {
     {
         BackupClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Backup"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> BackupClient {
-        BackupClient { client, region }
+        BackupClient {
+            client,
+            region: region_for_service(region, "Backup"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> BackupClient {
+        BackupClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Backup"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> BackupClient {
+        BackupClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -5266,6 +6012,7 @@ impl Backup for BackupClient {
         let request_uri = "/backup/plans/";
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation("CreateBackupPlan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5300,6 +6047,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation("CreateBackupSelection");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5334,6 +6082,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation("CreateBackupVault");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5368,6 +6117,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "backup", &self.region, &request_uri);
+        request.set_operation("DeleteBackupPlan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5400,6 +6150,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "backup", &self.region, &request_uri);
+        request.set_operation("DeleteBackupSelection");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5430,6 +6181,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "backup", &self.region, &request_uri);
+        request.set_operation("DeleteBackupVault");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5460,6 +6212,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "backup", &self.region, &request_uri);
+        request.set_operation("DeleteBackupVaultAccessPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5490,6 +6243,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "backup", &self.region, &request_uri);
+        request.set_operation("DeleteBackupVaultNotifications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5521,6 +6275,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "backup", &self.region, &request_uri);
+        request.set_operation("DeleteRecoveryPoint");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5551,6 +6306,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("DescribeBackupJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5582,6 +6338,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("DescribeBackupVault");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5610,6 +6367,7 @@ impl Backup for BackupClient {
         let request_uri = format!("/copy-jobs/{copy_job_id}", copy_job_id = input.copy_job_id);
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("DescribeCopyJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5637,6 +6395,7 @@ impl Backup for BackupClient {
         let request_uri = "/global-settings";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("DescribeGlobalSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5668,6 +6427,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("DescribeProtectedResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5700,6 +6460,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("DescribeRecoveryPoint");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5727,6 +6488,7 @@ impl Backup for BackupClient {
         let request_uri = "/account-settings";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("DescribeRegionSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5758,6 +6520,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("DescribeRestoreJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5789,6 +6552,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ExportBackupPlanTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5820,6 +6584,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("GetBackupPlan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5854,6 +6619,7 @@ impl Backup for BackupClient {
         let request_uri = "/backup/template/json/toPlan";
 
         let mut request = SignedRequest::new("POST", "backup", &self.region, &request_uri);
+        request.set_operation("GetBackupPlanFromJSON");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5888,6 +6654,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("GetBackupPlanFromTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5920,6 +6687,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("GetBackupSelection");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5952,6 +6720,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("GetBackupVaultAccessPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5984,6 +6753,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("GetBackupVaultNotifications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6015,6 +6785,7 @@ impl Backup for BackupClient {
         let request_uri = format!("/backup-vaults/{backup_vault_name}/recovery-points/{recovery_point_arn}/restore-metadata", backup_vault_name = input.backup_vault_name, recovery_point_arn = input.recovery_point_arn);
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("GetRecoveryPointRestoreMetadata");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6044,6 +6815,7 @@ impl Backup for BackupClient {
         let request_uri = "/supported-resource-types";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("GetSupportedResourceTypes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6072,6 +6844,7 @@ impl Backup for BackupClient {
         let request_uri = "/backup-jobs/";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListBackupJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6130,6 +6903,7 @@ impl Backup for BackupClient {
         let request_uri = "/backup/template/plans";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListBackupPlanTemplates");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6170,6 +6944,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListBackupPlanVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6207,6 +6982,7 @@ impl Backup for BackupClient {
         let request_uri = "/backup/plans/";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListBackupPlans");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6250,6 +7026,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListBackupSelections");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6287,6 +7064,7 @@ impl Backup for BackupClient {
         let request_uri = "/backup-vaults/";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListBackupVaults");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6324,6 +7102,7 @@ impl Backup for BackupClient {
         let request_uri = "/copy-jobs/";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListCopyJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6382,6 +7161,7 @@ impl Backup for BackupClient {
         let request_uri = "/resources/";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListProtectedResources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6425,6 +7205,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListRecoveryPointsByBackupVault");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6483,6 +7264,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListRecoveryPointsByResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6520,6 +7302,7 @@ impl Backup for BackupClient {
         let request_uri = "/restore-jobs/";
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListRestoreJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6569,6 +7352,7 @@ impl Backup for BackupClient {
         let request_uri = format!("/tags/{resource_arn}/", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "backup", &self.region, &request_uri);
+        request.set_operation("ListTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6609,6 +7393,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation("PutBackupVaultAccessPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6642,6 +7427,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation("PutBackupVaultNotifications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6672,6 +7458,7 @@ impl Backup for BackupClient {
         let request_uri = "/backup-jobs";
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation("StartBackupJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6703,6 +7490,7 @@ impl Backup for BackupClient {
         let request_uri = "/copy-jobs";
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation("StartCopyJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6734,6 +7522,7 @@ impl Backup for BackupClient {
         let request_uri = "/restore-jobs";
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation("StartRestoreJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6768,6 +7557,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("POST", "backup", &self.region, &request_uri);
+        request.set_operation("StopBackupJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6795,6 +7585,7 @@ impl Backup for BackupClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "backup", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6825,6 +7616,7 @@ impl Backup for BackupClient {
         let request_uri = format!("/untag/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "backup", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6858,6 +7650,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("POST", "backup", &self.region, &request_uri);
+        request.set_operation("UpdateBackupPlan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6889,6 +7682,7 @@ impl Backup for BackupClient {
         let request_uri = "/global-settings";
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation("UpdateGlobalSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6924,6 +7718,7 @@ impl Backup for BackupClient {
         );
 
         let mut request = SignedRequest::new("POST", "backup", &self.region, &request_uri);
+        request.set_operation("UpdateRecoveryPointLifecycle");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6955,6 +7750,7 @@ impl Backup for BackupClient {
         let request_uri = "/account-settings";
 
         let mut request = SignedRequest::new("PUT", "backup", &self.region, &request_uri);
+        request.set_operation("UpdateRegionSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6976,3 +7772,518 @@ impl Backup for BackupClient {
         }
     }
 }
+
+/// A blocking client for the AWS Backup API, running the calls of a `BackupClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `BackupClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct BackupBlockingClient {
+    client: BackupClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl BackupBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> BackupBlockingClient {
+        BackupBlockingClient::from_client(BackupClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> BackupBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        BackupBlockingClient::from_client(BackupClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> BackupBlockingClient {
+        BackupBlockingClient::from_client(BackupClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> BackupBlockingClient {
+        BackupBlockingClient::from_client(BackupClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: BackupClient) -> BackupBlockingClient {
+        BackupBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> BackupBlockingClient {
+        BackupBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> BackupBlockingClient {
+        BackupBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Backup::create_backup_plan`].
+    pub fn create_backup_plan(
+        &self,
+        input: CreateBackupPlanInput,
+    ) -> Result<CreateBackupPlanOutput, RusotoError<CreateBackupPlanError>> {
+        self.runtime.block_on(self.client.create_backup_plan(input))
+    }
+
+    /// Blocking version of [`Backup::create_backup_selection`].
+    pub fn create_backup_selection(
+        &self,
+        input: CreateBackupSelectionInput,
+    ) -> Result<CreateBackupSelectionOutput, RusotoError<CreateBackupSelectionError>> {
+        self.runtime
+            .block_on(self.client.create_backup_selection(input))
+    }
+
+    /// Blocking version of [`Backup::create_backup_vault`].
+    pub fn create_backup_vault(
+        &self,
+        input: CreateBackupVaultInput,
+    ) -> Result<CreateBackupVaultOutput, RusotoError<CreateBackupVaultError>> {
+        self.runtime
+            .block_on(self.client.create_backup_vault(input))
+    }
+
+    /// Blocking version of [`Backup::delete_backup_plan`].
+    pub fn delete_backup_plan(
+        &self,
+        input: DeleteBackupPlanInput,
+    ) -> Result<DeleteBackupPlanOutput, RusotoError<DeleteBackupPlanError>> {
+        self.runtime.block_on(self.client.delete_backup_plan(input))
+    }
+
+    /// Blocking version of [`Backup::delete_backup_selection`].
+    pub fn delete_backup_selection(
+        &self,
+        input: DeleteBackupSelectionInput,
+    ) -> Result<(), RusotoError<DeleteBackupSelectionError>> {
+        self.runtime
+            .block_on(self.client.delete_backup_selection(input))
+    }
+
+    /// Blocking version of [`Backup::delete_backup_vault`].
+    pub fn delete_backup_vault(
+        &self,
+        input: DeleteBackupVaultInput,
+    ) -> Result<(), RusotoError<DeleteBackupVaultError>> {
+        self.runtime
+            .block_on(self.client.delete_backup_vault(input))
+    }
+
+    /// Blocking version of [`Backup::delete_backup_vault_access_policy`].
+    pub fn delete_backup_vault_access_policy(
+        &self,
+        input: DeleteBackupVaultAccessPolicyInput,
+    ) -> Result<(), RusotoError<DeleteBackupVaultAccessPolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_backup_vault_access_policy(input))
+    }
+
+    /// Blocking version of [`Backup::delete_backup_vault_notifications`].
+    pub fn delete_backup_vault_notifications(
+        &self,
+        input: DeleteBackupVaultNotificationsInput,
+    ) -> Result<(), RusotoError<DeleteBackupVaultNotificationsError>> {
+        self.runtime
+            .block_on(self.client.delete_backup_vault_notifications(input))
+    }
+
+    /// Blocking version of [`Backup::delete_recovery_point`].
+    pub fn delete_recovery_point(
+        &self,
+        input: DeleteRecoveryPointInput,
+    ) -> Result<(), RusotoError<DeleteRecoveryPointError>> {
+        self.runtime
+            .block_on(self.client.delete_recovery_point(input))
+    }
+
+    /// Blocking version of [`Backup::describe_backup_job`].
+    pub fn describe_backup_job(
+        &self,
+        input: DescribeBackupJobInput,
+    ) -> Result<DescribeBackupJobOutput, RusotoError<DescribeBackupJobError>> {
+        self.runtime
+            .block_on(self.client.describe_backup_job(input))
+    }
+
+    /// Blocking version of [`Backup::describe_backup_vault`].
+    pub fn describe_backup_vault(
+        &self,
+        input: DescribeBackupVaultInput,
+    ) -> Result<DescribeBackupVaultOutput, RusotoError<DescribeBackupVaultError>> {
+        self.runtime
+            .block_on(self.client.describe_backup_vault(input))
+    }
+
+    /// Blocking version of [`Backup::describe_copy_job`].
+    pub fn describe_copy_job(
+        &self,
+        input: DescribeCopyJobInput,
+    ) -> Result<DescribeCopyJobOutput, RusotoError<DescribeCopyJobError>> {
+        self.runtime.block_on(self.client.describe_copy_job(input))
+    }
+
+    /// Blocking version of [`Backup::describe_global_settings`].
+    pub fn describe_global_settings(
+        &self,
+    ) -> Result<DescribeGlobalSettingsOutput, RusotoError<DescribeGlobalSettingsError>> {
+        self.runtime
+            .block_on(self.client.describe_global_settings())
+    }
+
+    /// Blocking version of [`Backup::describe_protected_resource`].
+    pub fn describe_protected_resource(
+        &self,
+        input: DescribeProtectedResourceInput,
+    ) -> Result<DescribeProtectedResourceOutput, RusotoError<DescribeProtectedResourceError>> {
+        self.runtime
+            .block_on(self.client.describe_protected_resource(input))
+    }
+
+    /// Blocking version of [`Backup::describe_recovery_point`].
+    pub fn describe_recovery_point(
+        &self,
+        input: DescribeRecoveryPointInput,
+    ) -> Result<DescribeRecoveryPointOutput, RusotoError<DescribeRecoveryPointError>> {
+        self.runtime
+            .block_on(self.client.describe_recovery_point(input))
+    }
+
+    /// Blocking version of [`Backup::describe_region_settings`].
+    pub fn describe_region_settings(
+        &self,
+    ) -> Result<DescribeRegionSettingsOutput, RusotoError<DescribeRegionSettingsError>> {
+        self.runtime
+            .block_on(self.client.describe_region_settings())
+    }
+
+    /// Blocking version of [`Backup::describe_restore_job`].
+    pub fn describe_restore_job(
+        &self,
+        input: DescribeRestoreJobInput,
+    ) -> Result<DescribeRestoreJobOutput, RusotoError<DescribeRestoreJobError>> {
+        self.runtime
+            .block_on(self.client.describe_restore_job(input))
+    }
+
+    /// Blocking version of [`Backup::export_backup_plan_template`].
+    pub fn export_backup_plan_template(
+        &self,
+        input: ExportBackupPlanTemplateInput,
+    ) -> Result<ExportBackupPlanTemplateOutput, RusotoError<ExportBackupPlanTemplateError>> {
+        self.runtime
+            .block_on(self.client.export_backup_plan_template(input))
+    }
+
+    /// Blocking version of [`Backup::get_backup_plan`].
+    pub fn get_backup_plan(
+        &self,
+        input: GetBackupPlanInput,
+    ) -> Result<GetBackupPlanOutput, RusotoError<GetBackupPlanError>> {
+        self.runtime.block_on(self.client.get_backup_plan(input))
+    }
+
+    /// Blocking version of [`Backup::get_backup_plan_from_json`].
+    pub fn get_backup_plan_from_json(
+        &self,
+        input: GetBackupPlanFromJSONInput,
+    ) -> Result<GetBackupPlanFromJSONOutput, RusotoError<GetBackupPlanFromJSONError>> {
+        self.runtime
+            .block_on(self.client.get_backup_plan_from_json(input))
+    }
+
+    /// Blocking version of [`Backup::get_backup_plan_from_template`].
+    pub fn get_backup_plan_from_template(
+        &self,
+        input: GetBackupPlanFromTemplateInput,
+    ) -> Result<GetBackupPlanFromTemplateOutput, RusotoError<GetBackupPlanFromTemplateError>> {
+        self.runtime
+            .block_on(self.client.get_backup_plan_from_template(input))
+    }
+
+    /// Blocking version of [`Backup::get_backup_selection`].
+    pub fn get_backup_selection(
+        &self,
+        input: GetBackupSelectionInput,
+    ) -> Result<GetBackupSelectionOutput, RusotoError<GetBackupSelectionError>> {
+        self.runtime
+            .block_on(self.client.get_backup_selection(input))
+    }
+
+    /// Blocking version of [`Backup::get_backup_vault_access_policy`].
+    pub fn get_backup_vault_access_policy(
+        &self,
+        input: GetBackupVaultAccessPolicyInput,
+    ) -> Result<GetBackupVaultAccessPolicyOutput, RusotoError<GetBackupVaultAccessPolicyError>>
+    {
+        self.runtime
+            .block_on(self.client.get_backup_vault_access_policy(input))
+    }
+
+    /// Blocking version of [`Backup::get_backup_vault_notifications`].
+    pub fn get_backup_vault_notifications(
+        &self,
+        input: GetBackupVaultNotificationsInput,
+    ) -> Result<GetBackupVaultNotificationsOutput, RusotoError<GetBackupVaultNotificationsError>>
+    {
+        self.runtime
+            .block_on(self.client.get_backup_vault_notifications(input))
+    }
+
+    /// Blocking version of [`Backup::get_recovery_point_restore_metadata`].
+    pub fn get_recovery_point_restore_metadata(
+        &self,
+        input: GetRecoveryPointRestoreMetadataInput,
+    ) -> Result<
+        GetRecoveryPointRestoreMetadataOutput,
+        RusotoError<GetRecoveryPointRestoreMetadataError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_recovery_point_restore_metadata(input))
+    }
+
+    /// Blocking version of [`Backup::get_supported_resource_types`].
+    pub fn get_supported_resource_types(
+        &self,
+    ) -> Result<GetSupportedResourceTypesOutput, RusotoError<GetSupportedResourceTypesError>> {
+        self.runtime
+            .block_on(self.client.get_supported_resource_types())
+    }
+
+    /// Blocking version of [`Backup::list_backup_jobs`].
+    pub fn list_backup_jobs(
+        &self,
+        input: ListBackupJobsInput,
+    ) -> Result<ListBackupJobsOutput, RusotoError<ListBackupJobsError>> {
+        self.runtime.block_on(self.client.list_backup_jobs(input))
+    }
+
+    /// Blocking version of [`Backup::list_backup_plan_templates`].
+    pub fn list_backup_plan_templates(
+        &self,
+        input: ListBackupPlanTemplatesInput,
+    ) -> Result<ListBackupPlanTemplatesOutput, RusotoError<ListBackupPlanTemplatesError>> {
+        self.runtime
+            .block_on(self.client.list_backup_plan_templates(input))
+    }
+
+    /// Blocking version of [`Backup::list_backup_plan_versions`].
+    pub fn list_backup_plan_versions(
+        &self,
+        input: ListBackupPlanVersionsInput,
+    ) -> Result<ListBackupPlanVersionsOutput, RusotoError<ListBackupPlanVersionsError>> {
+        self.runtime
+            .block_on(self.client.list_backup_plan_versions(input))
+    }
+
+    /// Blocking version of [`Backup::list_backup_plans`].
+    pub fn list_backup_plans(
+        &self,
+        input: ListBackupPlansInput,
+    ) -> Result<ListBackupPlansOutput, RusotoError<ListBackupPlansError>> {
+        self.runtime.block_on(self.client.list_backup_plans(input))
+    }
+
+    /// Blocking version of [`Backup::list_backup_selections`].
+    pub fn list_backup_selections(
+        &self,
+        input: ListBackupSelectionsInput,
+    ) -> Result<ListBackupSelectionsOutput, RusotoError<ListBackupSelectionsError>> {
+        self.runtime
+            .block_on(self.client.list_backup_selections(input))
+    }
+
+    /// Blocking version of [`Backup::list_backup_vaults`].
+    pub fn list_backup_vaults(
+        &self,
+        input: ListBackupVaultsInput,
+    ) -> Result<ListBackupVaultsOutput, RusotoError<ListBackupVaultsError>> {
+        self.runtime.block_on(self.client.list_backup_vaults(input))
+    }
+
+    /// Blocking version of [`Backup::list_copy_jobs`].
+    pub fn list_copy_jobs(
+        &self,
+        input: ListCopyJobsInput,
+    ) -> Result<ListCopyJobsOutput, RusotoError<ListCopyJobsError>> {
+        self.runtime.block_on(self.client.list_copy_jobs(input))
+    }
+
+    /// Blocking version of [`Backup::list_protected_resources`].
+    pub fn list_protected_resources(
+        &self,
+        input: ListProtectedResourcesInput,
+    ) -> Result<ListProtectedResourcesOutput, RusotoError<ListProtectedResourcesError>> {
+        self.runtime
+            .block_on(self.client.list_protected_resources(input))
+    }
+
+    /// Blocking version of [`Backup::list_recovery_points_by_backup_vault`].
+    pub fn list_recovery_points_by_backup_vault(
+        &self,
+        input: ListRecoveryPointsByBackupVaultInput,
+    ) -> Result<
+        ListRecoveryPointsByBackupVaultOutput,
+        RusotoError<ListRecoveryPointsByBackupVaultError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_recovery_points_by_backup_vault(input))
+    }
+
+    /// Blocking version of [`Backup::list_recovery_points_by_resource`].
+    pub fn list_recovery_points_by_resource(
+        &self,
+        input: ListRecoveryPointsByResourceInput,
+    ) -> Result<ListRecoveryPointsByResourceOutput, RusotoError<ListRecoveryPointsByResourceError>>
+    {
+        self.runtime
+            .block_on(self.client.list_recovery_points_by_resource(input))
+    }
+
+    /// Blocking version of [`Backup::list_restore_jobs`].
+    pub fn list_restore_jobs(
+        &self,
+        input: ListRestoreJobsInput,
+    ) -> Result<ListRestoreJobsOutput, RusotoError<ListRestoreJobsError>> {
+        self.runtime.block_on(self.client.list_restore_jobs(input))
+    }
+
+    /// Blocking version of [`Backup::list_tags`].
+    pub fn list_tags(
+        &self,
+        input: ListTagsInput,
+    ) -> Result<ListTagsOutput, RusotoError<ListTagsError>> {
+        self.runtime.block_on(self.client.list_tags(input))
+    }
+
+    /// Blocking version of [`Backup::put_backup_vault_access_policy`].
+    pub fn put_backup_vault_access_policy(
+        &self,
+        input: PutBackupVaultAccessPolicyInput,
+    ) -> Result<(), RusotoError<PutBackupVaultAccessPolicyError>> {
+        self.runtime
+            .block_on(self.client.put_backup_vault_access_policy(input))
+    }
+
+    /// Blocking version of [`Backup::put_backup_vault_notifications`].
+    pub fn put_backup_vault_notifications(
+        &self,
+        input: PutBackupVaultNotificationsInput,
+    ) -> Result<(), RusotoError<PutBackupVaultNotificationsError>> {
+        self.runtime
+            .block_on(self.client.put_backup_vault_notifications(input))
+    }
+
+    /// Blocking version of [`Backup::start_backup_job`].
+    pub fn start_backup_job(
+        &self,
+        input: StartBackupJobInput,
+    ) -> Result<StartBackupJobOutput, RusotoError<StartBackupJobError>> {
+        self.runtime.block_on(self.client.start_backup_job(input))
+    }
+
+    /// Blocking version of [`Backup::start_copy_job`].
+    pub fn start_copy_job(
+        &self,
+        input: StartCopyJobInput,
+    ) -> Result<StartCopyJobOutput, RusotoError<StartCopyJobError>> {
+        self.runtime.block_on(self.client.start_copy_job(input))
+    }
+
+    /// Blocking version of [`Backup::start_restore_job`].
+    pub fn start_restore_job(
+        &self,
+        input: StartRestoreJobInput,
+    ) -> Result<StartRestoreJobOutput, RusotoError<StartRestoreJobError>> {
+        self.runtime.block_on(self.client.start_restore_job(input))
+    }
+
+    /// Blocking version of [`Backup::stop_backup_job`].
+    pub fn stop_backup_job(
+        &self,
+        input: StopBackupJobInput,
+    ) -> Result<(), RusotoError<StopBackupJobError>> {
+        self.runtime.block_on(self.client.stop_backup_job(input))
+    }
+
+    /// Blocking version of [`Backup::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`Backup::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`Backup::update_backup_plan`].
+    pub fn update_backup_plan(
+        &self,
+        input: UpdateBackupPlanInput,
+    ) -> Result<UpdateBackupPlanOutput, RusotoError<UpdateBackupPlanError>> {
+        self.runtime.block_on(self.client.update_backup_plan(input))
+    }
+
+    /// Blocking version of [`Backup::update_global_settings`].
+    pub fn update_global_settings(
+        &self,
+        input: UpdateGlobalSettingsInput,
+    ) -> Result<(), RusotoError<UpdateGlobalSettingsError>> {
+        self.runtime
+            .block_on(self.client.update_global_settings(input))
+    }
+
+    /// Blocking version of [`Backup::update_recovery_point_lifecycle`].
+    pub fn update_recovery_point_lifecycle(
+        &self,
+        input: UpdateRecoveryPointLifecycleInput,
+    ) -> Result<UpdateRecoveryPointLifecycleOutput, RusotoError<UpdateRecoveryPointLifecycleError>>
+    {
+        self.runtime
+            .block_on(self.client.update_recovery_point_lifecycle(input))
+    }
+
+    /// Blocking version of [`Backup::update_region_settings`].
+    pub fn update_region_settings(
+        &self,
+        input: UpdateRegionSettingsInput,
+    ) -> Result<(), RusotoError<UpdateRegionSettingsError>> {
+        self.runtime
+            .block_on(self.client.update_region_settings(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1637,9 +1638,23 @@ impl CancelJobError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CancelJobError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ClientException" => return RusotoError::Service(CancelJobError::Client(err.msg)),
-                "ServerException" => return RusotoError::Service(CancelJobError::Server(err.msg)),
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ClientException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelJobError::Client,
+                    )
+                }
+                "ServerException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelJobError::Server,
+                    )
+                }
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1670,12 +1685,22 @@ impl CreateComputeEnvironmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateComputeEnvironmentError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateComputeEnvironmentError::Client,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(CreateComputeEnvironmentError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateComputeEnvironmentError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1706,12 +1731,22 @@ impl CreateJobQueueError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateJobQueueError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateJobQueueError::Client,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(CreateJobQueueError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateJobQueueError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1742,12 +1777,22 @@ impl DeleteComputeEnvironmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteComputeEnvironmentError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteComputeEnvironmentError::Client,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeleteComputeEnvironmentError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteComputeEnvironmentError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1778,12 +1823,22 @@ impl DeleteJobQueueError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteJobQueueError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteJobQueueError::Client,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeleteJobQueueError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteJobQueueError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1814,12 +1869,22 @@ impl DeregisterJobDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeregisterJobDefinitionError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterJobDefinitionError::Client,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeregisterJobDefinitionError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterJobDefinitionError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1852,12 +1917,22 @@ impl DescribeComputeEnvironmentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeComputeEnvironmentsError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeComputeEnvironmentsError::Client,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeComputeEnvironmentsError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeComputeEnvironmentsError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1888,12 +1963,22 @@ impl DescribeJobDefinitionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeJobDefinitionsError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeJobDefinitionsError::Client,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeJobDefinitionsError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeJobDefinitionsError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1924,12 +2009,22 @@ impl DescribeJobQueuesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeJobQueuesError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeJobQueuesError::Client,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeJobQueuesError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeJobQueuesError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1960,12 +2055,22 @@ impl DescribeJobsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeJobsError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeJobsError::Client,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeJobsError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeJobsError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1995,9 +2100,23 @@ impl ListJobsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListJobsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ClientException" => return RusotoError::Service(ListJobsError::Client(err.msg)),
-                "ServerException" => return RusotoError::Service(ListJobsError::Server(err.msg)),
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ClientException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListJobsError::Client,
+                    )
+                }
+                "ServerException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListJobsError::Server,
+                    )
+                }
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2028,12 +2147,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::Client,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2064,12 +2193,22 @@ impl RegisterJobDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(RegisterJobDefinitionError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterJobDefinitionError::Client,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(RegisterJobDefinitionError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterJobDefinitionError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2099,9 +2238,23 @@ impl SubmitJobError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SubmitJobError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ClientException" => return RusotoError::Service(SubmitJobError::Client(err.msg)),
-                "ServerException" => return RusotoError::Service(SubmitJobError::Server(err.msg)),
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ClientException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SubmitJobError::Client,
+                    )
+                }
+                "ServerException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SubmitJobError::Server,
+                    )
+                }
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2132,12 +2285,22 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(TagResourceError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::Client,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(TagResourceError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2168,12 +2331,22 @@ impl TerminateJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(TerminateJobError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TerminateJobError::Client,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(TerminateJobError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TerminateJobError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2204,12 +2377,22 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UntagResourceError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::Client,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UntagResourceError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2240,12 +2423,22 @@ impl UpdateComputeEnvironmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateComputeEnvironmentError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateComputeEnvironmentError::Client,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UpdateComputeEnvironmentError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateComputeEnvironmentError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2276,12 +2469,22 @@ impl UpdateJobQueueError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateJobQueueError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateJobQueueError::Client,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UpdateJobQueueError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateJobQueueError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2425,11 +2628,12 @@ pub struct BatchClient {
 impl BatchClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> BatchClient {
         BatchClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Batch"),
         }
     }
 
@@ -2444,12 +2648,32 @@ impl BatchClient {
     {
         BatchClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Batch"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> BatchClient {
-        BatchClient { client, region }
+        BatchClient {
+            client,
+            region: region_for_service(region, "Batch"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> BatchClient {
+        BatchClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Batch"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> BatchClient {
+        BatchClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -2464,6 +2688,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/canceljob";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("CancelJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2495,6 +2720,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/createcomputeenvironment";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("CreateComputeEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2526,6 +2752,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/createjobqueue";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("CreateJobQueue");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2557,6 +2784,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/deletecomputeenvironment";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("DeleteComputeEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2588,6 +2816,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/deletejobqueue";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("DeleteJobQueue");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2619,6 +2848,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/deregisterjobdefinition";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("DeregisterJobDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2651,6 +2881,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/describecomputeenvironments";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("DescribeComputeEnvironments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2682,6 +2913,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/describejobdefinitions";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("DescribeJobDefinitions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2713,6 +2945,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/describejobqueues";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("DescribeJobQueues");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2744,6 +2977,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/describejobs";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("DescribeJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2775,6 +3009,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/listjobs";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("ListJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2806,6 +3041,7 @@ impl Batch for BatchClient {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "batch", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2834,6 +3070,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/registerjobdefinition";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("RegisterJobDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2865,6 +3102,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/submitjob";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("SubmitJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2896,6 +3134,7 @@ impl Batch for BatchClient {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2927,6 +3166,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/terminatejob";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("TerminateJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2958,6 +3198,7 @@ impl Batch for BatchClient {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "batch", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2992,6 +3233,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/updatecomputeenvironment";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("UpdateComputeEnvironment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3023,6 +3265,7 @@ impl Batch for BatchClient {
         let request_uri = "/v1/updatejobqueue";
 
         let mut request = SignedRequest::new("POST", "batch", &self.region, &request_uri);
+        request.set_operation("UpdateJobQueue");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3045,3 +3288,240 @@ impl Batch for BatchClient {
         }
     }
 }
+
+/// A blocking client for the AWS Batch API, running the calls of a `BatchClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `BatchClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct BatchBlockingClient {
+    client: BatchClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl BatchBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> BatchBlockingClient {
+        BatchBlockingClient::from_client(BatchClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> BatchBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        BatchBlockingClient::from_client(BatchClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> BatchBlockingClient {
+        BatchBlockingClient::from_client(BatchClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> BatchBlockingClient {
+        BatchBlockingClient::from_client(BatchClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: BatchClient) -> BatchBlockingClient {
+        BatchBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> BatchBlockingClient {
+        BatchBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> BatchBlockingClient {
+        BatchBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Batch::cancel_job`].
+    pub fn cancel_job(
+        &self,
+        input: CancelJobRequest,
+    ) -> Result<CancelJobResponse, RusotoError<CancelJobError>> {
+        self.runtime.block_on(self.client.cancel_job(input))
+    }
+
+    /// Blocking version of [`Batch::create_compute_environment`].
+    pub fn create_compute_environment(
+        &self,
+        input: CreateComputeEnvironmentRequest,
+    ) -> Result<CreateComputeEnvironmentResponse, RusotoError<CreateComputeEnvironmentError>> {
+        self.runtime
+            .block_on(self.client.create_compute_environment(input))
+    }
+
+    /// Blocking version of [`Batch::create_job_queue`].
+    pub fn create_job_queue(
+        &self,
+        input: CreateJobQueueRequest,
+    ) -> Result<CreateJobQueueResponse, RusotoError<CreateJobQueueError>> {
+        self.runtime.block_on(self.client.create_job_queue(input))
+    }
+
+    /// Blocking version of [`Batch::delete_compute_environment`].
+    pub fn delete_compute_environment(
+        &self,
+        input: DeleteComputeEnvironmentRequest,
+    ) -> Result<DeleteComputeEnvironmentResponse, RusotoError<DeleteComputeEnvironmentError>> {
+        self.runtime
+            .block_on(self.client.delete_compute_environment(input))
+    }
+
+    /// Blocking version of [`Batch::delete_job_queue`].
+    pub fn delete_job_queue(
+        &self,
+        input: DeleteJobQueueRequest,
+    ) -> Result<DeleteJobQueueResponse, RusotoError<DeleteJobQueueError>> {
+        self.runtime.block_on(self.client.delete_job_queue(input))
+    }
+
+    /// Blocking version of [`Batch::deregister_job_definition`].
+    pub fn deregister_job_definition(
+        &self,
+        input: DeregisterJobDefinitionRequest,
+    ) -> Result<DeregisterJobDefinitionResponse, RusotoError<DeregisterJobDefinitionError>> {
+        self.runtime
+            .block_on(self.client.deregister_job_definition(input))
+    }
+
+    /// Blocking version of [`Batch::describe_compute_environments`].
+    pub fn describe_compute_environments(
+        &self,
+        input: DescribeComputeEnvironmentsRequest,
+    ) -> Result<DescribeComputeEnvironmentsResponse, RusotoError<DescribeComputeEnvironmentsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_compute_environments(input))
+    }
+
+    /// Blocking version of [`Batch::describe_job_definitions`].
+    pub fn describe_job_definitions(
+        &self,
+        input: DescribeJobDefinitionsRequest,
+    ) -> Result<DescribeJobDefinitionsResponse, RusotoError<DescribeJobDefinitionsError>> {
+        self.runtime
+            .block_on(self.client.describe_job_definitions(input))
+    }
+
+    /// Blocking version of [`Batch::describe_job_queues`].
+    pub fn describe_job_queues(
+        &self,
+        input: DescribeJobQueuesRequest,
+    ) -> Result<DescribeJobQueuesResponse, RusotoError<DescribeJobQueuesError>> {
+        self.runtime
+            .block_on(self.client.describe_job_queues(input))
+    }
+
+    /// Blocking version of [`Batch::describe_jobs`].
+    pub fn describe_jobs(
+        &self,
+        input: DescribeJobsRequest,
+    ) -> Result<DescribeJobsResponse, RusotoError<DescribeJobsError>> {
+        self.runtime.block_on(self.client.describe_jobs(input))
+    }
+
+    /// Blocking version of [`Batch::list_jobs`].
+    pub fn list_jobs(
+        &self,
+        input: ListJobsRequest,
+    ) -> Result<ListJobsResponse, RusotoError<ListJobsError>> {
+        self.runtime.block_on(self.client.list_jobs(input))
+    }
+
+    /// Blocking version of [`Batch::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`Batch::register_job_definition`].
+    pub fn register_job_definition(
+        &self,
+        input: RegisterJobDefinitionRequest,
+    ) -> Result<RegisterJobDefinitionResponse, RusotoError<RegisterJobDefinitionError>> {
+        self.runtime
+            .block_on(self.client.register_job_definition(input))
+    }
+
+    /// Blocking version of [`Batch::submit_job`].
+    pub fn submit_job(
+        &self,
+        input: SubmitJobRequest,
+    ) -> Result<SubmitJobResponse, RusotoError<SubmitJobError>> {
+        self.runtime.block_on(self.client.submit_job(input))
+    }
+
+    /// Blocking version of [`Batch::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`Batch::terminate_job`].
+    pub fn terminate_job(
+        &self,
+        input: TerminateJobRequest,
+    ) -> Result<TerminateJobResponse, RusotoError<TerminateJobError>> {
+        self.runtime.block_on(self.client.terminate_job(input))
+    }
+
+    /// Blocking version of [`Batch::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`Batch::update_compute_environment`].
+    pub fn update_compute_environment(
+        &self,
+        input: UpdateComputeEnvironmentRequest,
+    ) -> Result<UpdateComputeEnvironmentResponse, RusotoError<UpdateComputeEnvironmentError>> {
+        self.runtime
+            .block_on(self.client.update_compute_environment(input))
+    }
+
+    /// Blocking version of [`Batch::update_job_queue`].
+    pub fn update_job_queue(
+        &self,
+        input: UpdateJobQueueRequest,
+    ) -> Result<UpdateJobQueueResponse, RusotoError<UpdateJobQueueError>> {
+        self.runtime.block_on(self.client.update_job_queue(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1011,21 +1012,46 @@ impl CreateBudgetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateBudgetError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBudgetError::AccessDenied,
+                    )
                 }
                 "CreationLimitExceededException" => {
-                    return RusotoError::Service(CreateBudgetError::CreationLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBudgetError::CreationLimitExceeded,
+                    )
                 }
                 "DuplicateRecordException" => {
-                    return RusotoError::Service(CreateBudgetError::DuplicateRecord(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBudgetError::DuplicateRecord,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateBudgetError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBudgetError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateBudgetError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBudgetError::InvalidParameter,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1067,26 +1093,54 @@ impl CreateBudgetActionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateBudgetActionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBudgetActionError::AccessDenied,
+                    )
                 }
                 "CreationLimitExceededException" => {
-                    return RusotoError::Service(CreateBudgetActionError::CreationLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateBudgetActionError::CreationLimitExceeded,
+                    )
                 }
                 "DuplicateRecordException" => {
-                    return RusotoError::Service(CreateBudgetActionError::DuplicateRecord(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBudgetActionError::DuplicateRecord,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateBudgetActionError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBudgetActionError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateBudgetActionError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBudgetActionError::InvalidParameter,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateBudgetActionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBudgetActionError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1129,26 +1183,54 @@ impl CreateNotificationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateNotificationError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNotificationError::AccessDenied,
+                    )
                 }
                 "CreationLimitExceededException" => {
-                    return RusotoError::Service(CreateNotificationError::CreationLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateNotificationError::CreationLimitExceeded,
+                    )
                 }
                 "DuplicateRecordException" => {
-                    return RusotoError::Service(CreateNotificationError::DuplicateRecord(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNotificationError::DuplicateRecord,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateNotificationError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNotificationError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateNotificationError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNotificationError::InvalidParameter,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateNotificationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNotificationError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1191,26 +1273,54 @@ impl CreateSubscriberError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateSubscriberError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSubscriberError::AccessDenied,
+                    )
                 }
                 "CreationLimitExceededException" => {
-                    return RusotoError::Service(CreateSubscriberError::CreationLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSubscriberError::CreationLimitExceeded,
+                    )
                 }
                 "DuplicateRecordException" => {
-                    return RusotoError::Service(CreateSubscriberError::DuplicateRecord(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSubscriberError::DuplicateRecord,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateSubscriberError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSubscriberError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateSubscriberError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSubscriberError::InvalidParameter,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateSubscriberError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSubscriberError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1249,18 +1359,38 @@ impl DeleteBudgetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteBudgetError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBudgetError::AccessDenied,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteBudgetError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBudgetError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteBudgetError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBudgetError::InvalidParameter,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteBudgetError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBudgetError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1299,21 +1429,46 @@ impl DeleteBudgetActionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteBudgetActionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBudgetActionError::AccessDenied,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteBudgetActionError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBudgetActionError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteBudgetActionError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBudgetActionError::InvalidParameter,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteBudgetActionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBudgetActionError::NotFound,
+                    )
                 }
                 "ResourceLockedException" => {
-                    return RusotoError::Service(DeleteBudgetActionError::ResourceLocked(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBudgetActionError::ResourceLocked,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1351,18 +1506,38 @@ impl DeleteNotificationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteNotificationError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNotificationError::AccessDenied,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteNotificationError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNotificationError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteNotificationError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNotificationError::InvalidParameter,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteNotificationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNotificationError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1399,18 +1574,38 @@ impl DeleteSubscriberError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteSubscriberError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSubscriberError::AccessDenied,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteSubscriberError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSubscriberError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteSubscriberError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSubscriberError::InvalidParameter,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteSubscriberError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSubscriberError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1447,18 +1642,38 @@ impl DescribeBudgetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeBudgetError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBudgetError::AccessDenied,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeBudgetError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBudgetError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeBudgetError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBudgetError::InvalidParameter,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeBudgetError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBudgetError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1495,20 +1710,38 @@ impl DescribeBudgetActionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeBudgetActionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBudgetActionError::AccessDenied,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeBudgetActionError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBudgetActionError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeBudgetActionError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeBudgetActionError::InvalidParameter,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeBudgetActionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBudgetActionError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1549,31 +1782,46 @@ impl DescribeBudgetActionHistoriesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeBudgetActionHistoriesError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeBudgetActionHistoriesError::AccessDenied,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeBudgetActionHistoriesError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeBudgetActionHistoriesError::InternalError,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        DescribeBudgetActionHistoriesError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBudgetActionHistoriesError::InvalidNextToken,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        DescribeBudgetActionHistoriesError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBudgetActionHistoriesError::InvalidParameter,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeBudgetActionHistoriesError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeBudgetActionHistoriesError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1617,26 +1865,38 @@ impl DescribeBudgetActionsForAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        DescribeBudgetActionsForAccountError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBudgetActionsForAccountError::AccessDenied,
                     )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(
-                        DescribeBudgetActionsForAccountError::InternalError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBudgetActionsForAccountError::InternalError,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        DescribeBudgetActionsForAccountError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBudgetActionsForAccountError::InvalidNextToken,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        DescribeBudgetActionsForAccountError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBudgetActionsForAccountError::InvalidParameter,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1683,31 +1943,46 @@ impl DescribeBudgetActionsForBudgetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeBudgetActionsForBudgetError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeBudgetActionsForBudgetError::AccessDenied,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(
-                        DescribeBudgetActionsForBudgetError::InternalError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBudgetActionsForBudgetError::InternalError,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        DescribeBudgetActionsForBudgetError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBudgetActionsForBudgetError::InvalidNextToken,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        DescribeBudgetActionsForBudgetError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBudgetActionsForBudgetError::InvalidParameter,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeBudgetActionsForBudgetError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeBudgetActionsForBudgetError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1755,36 +2030,54 @@ impl DescribeBudgetPerformanceHistoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        DescribeBudgetPerformanceHistoryError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBudgetPerformanceHistoryError::AccessDenied,
                     )
                 }
                 "ExpiredNextTokenException" => {
-                    return RusotoError::Service(
-                        DescribeBudgetPerformanceHistoryError::ExpiredNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBudgetPerformanceHistoryError::ExpiredNextToken,
                     )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(
-                        DescribeBudgetPerformanceHistoryError::InternalError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBudgetPerformanceHistoryError::InternalError,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        DescribeBudgetPerformanceHistoryError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBudgetPerformanceHistoryError::InvalidNextToken,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        DescribeBudgetPerformanceHistoryError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBudgetPerformanceHistoryError::InvalidParameter,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeBudgetPerformanceHistoryError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeBudgetPerformanceHistoryError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1837,24 +2130,54 @@ impl DescribeBudgetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeBudgetsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBudgetsError::AccessDenied,
+                    )
                 }
                 "ExpiredNextTokenException" => {
-                    return RusotoError::Service(DescribeBudgetsError::ExpiredNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBudgetsError::ExpiredNextToken,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeBudgetsError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBudgetsError::InternalError,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeBudgetsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBudgetsError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeBudgetsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBudgetsError::InvalidParameter,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeBudgetsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBudgetsError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1899,36 +2222,54 @@ impl DescribeNotificationsForBudgetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeNotificationsForBudgetError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeNotificationsForBudgetError::AccessDenied,
+                    )
                 }
                 "ExpiredNextTokenException" => {
-                    return RusotoError::Service(
-                        DescribeNotificationsForBudgetError::ExpiredNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeNotificationsForBudgetError::ExpiredNextToken,
                     )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(
-                        DescribeNotificationsForBudgetError::InternalError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeNotificationsForBudgetError::InternalError,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        DescribeNotificationsForBudgetError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeNotificationsForBudgetError::InvalidNextToken,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        DescribeNotificationsForBudgetError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeNotificationsForBudgetError::InvalidParameter,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeNotificationsForBudgetError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeNotificationsForBudgetError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1979,36 +2320,54 @@ impl DescribeSubscribersForNotificationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        DescribeSubscribersForNotificationError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSubscribersForNotificationError::AccessDenied,
                     )
                 }
                 "ExpiredNextTokenException" => {
-                    return RusotoError::Service(
-                        DescribeSubscribersForNotificationError::ExpiredNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSubscribersForNotificationError::ExpiredNextToken,
                     )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(
-                        DescribeSubscribersForNotificationError::InternalError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSubscribersForNotificationError::InternalError,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        DescribeSubscribersForNotificationError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSubscribersForNotificationError::InvalidNextToken,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        DescribeSubscribersForNotificationError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSubscribersForNotificationError::InvalidParameter,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeSubscribersForNotificationError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeSubscribersForNotificationError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2059,23 +2418,46 @@ impl ExecuteBudgetActionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ExecuteBudgetActionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExecuteBudgetActionError::AccessDenied,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(ExecuteBudgetActionError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExecuteBudgetActionError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ExecuteBudgetActionError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ExecuteBudgetActionError::InvalidParameter,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ExecuteBudgetActionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExecuteBudgetActionError::NotFound,
+                    )
                 }
                 "ResourceLockedException" => {
-                    return RusotoError::Service(ExecuteBudgetActionError::ResourceLocked(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExecuteBudgetActionError::ResourceLocked,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2113,18 +2495,38 @@ impl UpdateBudgetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateBudgetError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBudgetError::AccessDenied,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateBudgetError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBudgetError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateBudgetError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBudgetError::InvalidParameter,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateBudgetError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBudgetError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2163,21 +2565,46 @@ impl UpdateBudgetActionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateBudgetActionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBudgetActionError::AccessDenied,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateBudgetActionError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBudgetActionError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateBudgetActionError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBudgetActionError::InvalidParameter,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateBudgetActionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBudgetActionError::NotFound,
+                    )
                 }
                 "ResourceLockedException" => {
-                    return RusotoError::Service(UpdateBudgetActionError::ResourceLocked(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBudgetActionError::ResourceLocked,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2217,21 +2644,46 @@ impl UpdateNotificationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateNotificationError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateNotificationError::AccessDenied,
+                    )
                 }
                 "DuplicateRecordException" => {
-                    return RusotoError::Service(UpdateNotificationError::DuplicateRecord(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateNotificationError::DuplicateRecord,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateNotificationError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateNotificationError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateNotificationError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateNotificationError::InvalidParameter,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateNotificationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateNotificationError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2271,21 +2723,46 @@ impl UpdateSubscriberError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateSubscriberError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSubscriberError::AccessDenied,
+                    )
                 }
                 "DuplicateRecordException" => {
-                    return RusotoError::Service(UpdateSubscriberError::DuplicateRecord(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSubscriberError::DuplicateRecord,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateSubscriberError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSubscriberError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateSubscriberError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSubscriberError::InvalidParameter,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateSubscriberError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSubscriberError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2468,11 +2945,12 @@ pub struct BudgetsClient {
 impl BudgetsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> BudgetsClient {
         BudgetsClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Budgets"),
         }
     }
 
@@ -2487,12 +2965,32 @@ impl BudgetsClient {
     {
         BudgetsClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Budgets"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> BudgetsClient {
-        BudgetsClient { client, region }
+        BudgetsClient {
+            client,
+            region: region_for_service(region, "Budgets"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> BudgetsClient {
+        BudgetsClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Budgets"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> BudgetsClient {
+        BudgetsClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -2504,6 +3002,7 @@ impl Budgets for BudgetsClient {
         input: CreateBudgetRequest,
     ) -> Result<CreateBudgetResponse, RusotoError<CreateBudgetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateBudget");
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.CreateBudget");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2522,6 +3021,7 @@ impl Budgets for BudgetsClient {
         input: CreateBudgetActionRequest,
     ) -> Result<CreateBudgetActionResponse, RusotoError<CreateBudgetActionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateBudgetAction");
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.CreateBudgetAction");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2540,6 +3040,7 @@ impl Budgets for BudgetsClient {
         input: CreateNotificationRequest,
     ) -> Result<CreateNotificationResponse, RusotoError<CreateNotificationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateNotification");
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.CreateNotification");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2558,6 +3059,7 @@ impl Budgets for BudgetsClient {
         input: CreateSubscriberRequest,
     ) -> Result<CreateSubscriberResponse, RusotoError<CreateSubscriberError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateSubscriber");
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.CreateSubscriber");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2576,6 +3078,7 @@ impl Budgets for BudgetsClient {
         input: DeleteBudgetRequest,
     ) -> Result<DeleteBudgetResponse, RusotoError<DeleteBudgetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteBudget");
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.DeleteBudget");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2594,6 +3097,7 @@ impl Budgets for BudgetsClient {
         input: DeleteBudgetActionRequest,
     ) -> Result<DeleteBudgetActionResponse, RusotoError<DeleteBudgetActionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteBudgetAction");
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.DeleteBudgetAction");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2612,6 +3116,7 @@ impl Budgets for BudgetsClient {
         input: DeleteNotificationRequest,
     ) -> Result<DeleteNotificationResponse, RusotoError<DeleteNotificationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteNotification");
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.DeleteNotification");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2630,6 +3135,7 @@ impl Budgets for BudgetsClient {
         input: DeleteSubscriberRequest,
     ) -> Result<DeleteSubscriberResponse, RusotoError<DeleteSubscriberError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteSubscriber");
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.DeleteSubscriber");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2648,6 +3154,7 @@ impl Budgets for BudgetsClient {
         input: DescribeBudgetRequest,
     ) -> Result<DescribeBudgetResponse, RusotoError<DescribeBudgetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeBudget");
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.DescribeBudget");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2666,6 +3173,7 @@ impl Budgets for BudgetsClient {
         input: DescribeBudgetActionRequest,
     ) -> Result<DescribeBudgetActionResponse, RusotoError<DescribeBudgetActionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeBudgetAction");
         request.add_header(
             "x-amz-target",
             "AWSBudgetServiceGateway.DescribeBudgetAction",
@@ -2691,6 +3199,7 @@ impl Budgets for BudgetsClient {
         RusotoError<DescribeBudgetActionHistoriesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeBudgetActionHistories");
         request.add_header(
             "x-amz-target",
             "AWSBudgetServiceGateway.DescribeBudgetActionHistories",
@@ -2716,6 +3225,7 @@ impl Budgets for BudgetsClient {
         RusotoError<DescribeBudgetActionsForAccountError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeBudgetActionsForAccount");
         request.add_header(
             "x-amz-target",
             "AWSBudgetServiceGateway.DescribeBudgetActionsForAccount",
@@ -2741,6 +3251,7 @@ impl Budgets for BudgetsClient {
         RusotoError<DescribeBudgetActionsForBudgetError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeBudgetActionsForBudget");
         request.add_header(
             "x-amz-target",
             "AWSBudgetServiceGateway.DescribeBudgetActionsForBudget",
@@ -2766,6 +3277,7 @@ impl Budgets for BudgetsClient {
         RusotoError<DescribeBudgetPerformanceHistoryError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeBudgetPerformanceHistory");
         request.add_header(
             "x-amz-target",
             "AWSBudgetServiceGateway.DescribeBudgetPerformanceHistory",
@@ -2791,6 +3303,7 @@ impl Budgets for BudgetsClient {
         input: DescribeBudgetsRequest,
     ) -> Result<DescribeBudgetsResponse, RusotoError<DescribeBudgetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeBudgets");
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.DescribeBudgets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2812,6 +3325,7 @@ impl Budgets for BudgetsClient {
         RusotoError<DescribeNotificationsForBudgetError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeNotificationsForBudget");
         request.add_header(
             "x-amz-target",
             "AWSBudgetServiceGateway.DescribeNotificationsForBudget",
@@ -2837,6 +3351,7 @@ impl Budgets for BudgetsClient {
         RusotoError<DescribeSubscribersForNotificationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeSubscribersForNotification");
         request.add_header(
             "x-amz-target",
             "AWSBudgetServiceGateway.DescribeSubscribersForNotification",
@@ -2862,6 +3377,7 @@ impl Budgets for BudgetsClient {
         input: ExecuteBudgetActionRequest,
     ) -> Result<ExecuteBudgetActionResponse, RusotoError<ExecuteBudgetActionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ExecuteBudgetAction");
         request.add_header(
             "x-amz-target",
             "AWSBudgetServiceGateway.ExecuteBudgetAction",
@@ -2883,6 +3399,7 @@ impl Budgets for BudgetsClient {
         input: UpdateBudgetRequest,
     ) -> Result<UpdateBudgetResponse, RusotoError<UpdateBudgetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateBudget");
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.UpdateBudget");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2901,6 +3418,7 @@ impl Budgets for BudgetsClient {
         input: UpdateBudgetActionRequest,
     ) -> Result<UpdateBudgetActionResponse, RusotoError<UpdateBudgetActionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateBudgetAction");
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.UpdateBudgetAction");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2919,6 +3437,7 @@ impl Budgets for BudgetsClient {
         input: UpdateNotificationRequest,
     ) -> Result<UpdateNotificationResponse, RusotoError<UpdateNotificationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateNotification");
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.UpdateNotification");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2937,6 +3456,7 @@ impl Budgets for BudgetsClient {
         input: UpdateSubscriberRequest,
     ) -> Result<UpdateSubscriberResponse, RusotoError<UpdateSubscriberError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateSubscriber");
         request.add_header("x-amz-target", "AWSBudgetServiceGateway.UpdateSubscriber");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2949,3 +3469,286 @@ impl Budgets for BudgetsClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateSubscriberResponse, _>()
     }
 }
+
+/// A blocking client for the AWSBudgets API, running the calls of a `BudgetsClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `BudgetsClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct BudgetsBlockingClient {
+    client: BudgetsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl BudgetsBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> BudgetsBlockingClient {
+        BudgetsBlockingClient::from_client(BudgetsClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> BudgetsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        BudgetsBlockingClient::from_client(BudgetsClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> BudgetsBlockingClient {
+        BudgetsBlockingClient::from_client(BudgetsClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> BudgetsBlockingClient {
+        BudgetsBlockingClient::from_client(BudgetsClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: BudgetsClient) -> BudgetsBlockingClient {
+        BudgetsBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> BudgetsBlockingClient {
+        BudgetsBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> BudgetsBlockingClient {
+        BudgetsBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Budgets::create_budget`].
+    pub fn create_budget(
+        &self,
+        input: CreateBudgetRequest,
+    ) -> Result<CreateBudgetResponse, RusotoError<CreateBudgetError>> {
+        self.runtime.block_on(self.client.create_budget(input))
+    }
+
+    /// Blocking version of [`Budgets::create_budget_action`].
+    pub fn create_budget_action(
+        &self,
+        input: CreateBudgetActionRequest,
+    ) -> Result<CreateBudgetActionResponse, RusotoError<CreateBudgetActionError>> {
+        self.runtime
+            .block_on(self.client.create_budget_action(input))
+    }
+
+    /// Blocking version of [`Budgets::create_notification`].
+    pub fn create_notification(
+        &self,
+        input: CreateNotificationRequest,
+    ) -> Result<CreateNotificationResponse, RusotoError<CreateNotificationError>> {
+        self.runtime
+            .block_on(self.client.create_notification(input))
+    }
+
+    /// Blocking version of [`Budgets::create_subscriber`].
+    pub fn create_subscriber(
+        &self,
+        input: CreateSubscriberRequest,
+    ) -> Result<CreateSubscriberResponse, RusotoError<CreateSubscriberError>> {
+        self.runtime.block_on(self.client.create_subscriber(input))
+    }
+
+    /// Blocking version of [`Budgets::delete_budget`].
+    pub fn delete_budget(
+        &self,
+        input: DeleteBudgetRequest,
+    ) -> Result<DeleteBudgetResponse, RusotoError<DeleteBudgetError>> {
+        self.runtime.block_on(self.client.delete_budget(input))
+    }
+
+    /// Blocking version of [`Budgets::delete_budget_action`].
+    pub fn delete_budget_action(
+        &self,
+        input: DeleteBudgetActionRequest,
+    ) -> Result<DeleteBudgetActionResponse, RusotoError<DeleteBudgetActionError>> {
+        self.runtime
+            .block_on(self.client.delete_budget_action(input))
+    }
+
+    /// Blocking version of [`Budgets::delete_notification`].
+    pub fn delete_notification(
+        &self,
+        input: DeleteNotificationRequest,
+    ) -> Result<DeleteNotificationResponse, RusotoError<DeleteNotificationError>> {
+        self.runtime
+            .block_on(self.client.delete_notification(input))
+    }
+
+    /// Blocking version of [`Budgets::delete_subscriber`].
+    pub fn delete_subscriber(
+        &self,
+        input: DeleteSubscriberRequest,
+    ) -> Result<DeleteSubscriberResponse, RusotoError<DeleteSubscriberError>> {
+        self.runtime.block_on(self.client.delete_subscriber(input))
+    }
+
+    /// Blocking version of [`Budgets::describe_budget`].
+    pub fn describe_budget(
+        &self,
+        input: DescribeBudgetRequest,
+    ) -> Result<DescribeBudgetResponse, RusotoError<DescribeBudgetError>> {
+        self.runtime.block_on(self.client.describe_budget(input))
+    }
+
+    /// Blocking version of [`Budgets::describe_budget_action`].
+    pub fn describe_budget_action(
+        &self,
+        input: DescribeBudgetActionRequest,
+    ) -> Result<DescribeBudgetActionResponse, RusotoError<DescribeBudgetActionError>> {
+        self.runtime
+            .block_on(self.client.describe_budget_action(input))
+    }
+
+    /// Blocking version of [`Budgets::describe_budget_action_histories`].
+    pub fn describe_budget_action_histories(
+        &self,
+        input: DescribeBudgetActionHistoriesRequest,
+    ) -> Result<
+        DescribeBudgetActionHistoriesResponse,
+        RusotoError<DescribeBudgetActionHistoriesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_budget_action_histories(input))
+    }
+
+    /// Blocking version of [`Budgets::describe_budget_actions_for_account`].
+    pub fn describe_budget_actions_for_account(
+        &self,
+        input: DescribeBudgetActionsForAccountRequest,
+    ) -> Result<
+        DescribeBudgetActionsForAccountResponse,
+        RusotoError<DescribeBudgetActionsForAccountError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_budget_actions_for_account(input))
+    }
+
+    /// Blocking version of [`Budgets::describe_budget_actions_for_budget`].
+    pub fn describe_budget_actions_for_budget(
+        &self,
+        input: DescribeBudgetActionsForBudgetRequest,
+    ) -> Result<
+        DescribeBudgetActionsForBudgetResponse,
+        RusotoError<DescribeBudgetActionsForBudgetError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_budget_actions_for_budget(input))
+    }
+
+    /// Blocking version of [`Budgets::describe_budget_performance_history`].
+    pub fn describe_budget_performance_history(
+        &self,
+        input: DescribeBudgetPerformanceHistoryRequest,
+    ) -> Result<
+        DescribeBudgetPerformanceHistoryResponse,
+        RusotoError<DescribeBudgetPerformanceHistoryError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_budget_performance_history(input))
+    }
+
+    /// Blocking version of [`Budgets::describe_budgets`].
+    pub fn describe_budgets(
+        &self,
+        input: DescribeBudgetsRequest,
+    ) -> Result<DescribeBudgetsResponse, RusotoError<DescribeBudgetsError>> {
+        self.runtime.block_on(self.client.describe_budgets(input))
+    }
+
+    /// Blocking version of [`Budgets::describe_notifications_for_budget`].
+    pub fn describe_notifications_for_budget(
+        &self,
+        input: DescribeNotificationsForBudgetRequest,
+    ) -> Result<
+        DescribeNotificationsForBudgetResponse,
+        RusotoError<DescribeNotificationsForBudgetError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_notifications_for_budget(input))
+    }
+
+    /// Blocking version of [`Budgets::describe_subscribers_for_notification`].
+    pub fn describe_subscribers_for_notification(
+        &self,
+        input: DescribeSubscribersForNotificationRequest,
+    ) -> Result<
+        DescribeSubscribersForNotificationResponse,
+        RusotoError<DescribeSubscribersForNotificationError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_subscribers_for_notification(input))
+    }
+
+    /// Blocking version of [`Budgets::execute_budget_action`].
+    pub fn execute_budget_action(
+        &self,
+        input: ExecuteBudgetActionRequest,
+    ) -> Result<ExecuteBudgetActionResponse, RusotoError<ExecuteBudgetActionError>> {
+        self.runtime
+            .block_on(self.client.execute_budget_action(input))
+    }
+
+    /// Blocking version of [`Budgets::update_budget`].
+    pub fn update_budget(
+        &self,
+        input: UpdateBudgetRequest,
+    ) -> Result<UpdateBudgetResponse, RusotoError<UpdateBudgetError>> {
+        self.runtime.block_on(self.client.update_budget(input))
+    }
+
+    /// Blocking version of [`Budgets::update_budget_action`].
+    pub fn update_budget_action(
+        &self,
+        input: UpdateBudgetActionRequest,
+    ) -> Result<UpdateBudgetActionResponse, RusotoError<UpdateBudgetActionError>> {
+        self.runtime
+            .block_on(self.client.update_budget_action(input))
+    }
+
+    /// Blocking version of [`Budgets::update_notification`].
+    pub fn update_notification(
+        &self,
+        input: UpdateNotificationRequest,
+    ) -> Result<UpdateNotificationResponse, RusotoError<UpdateNotificationError>> {
+        self.runtime
+            .block_on(self.client.update_notification(input))
+    }
+
+    /// Blocking version of [`Budgets::update_subscriber`].
+    pub fn update_subscriber(
+        &self,
+        input: UpdateSubscriberRequest,
+    ) -> Result<UpdateSubscriberResponse, RusotoError<UpdateSubscriberError>> {
+        self.runtime.block_on(self.client.update_subscriber(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -2672,9 +2673,14 @@ impl CreateAnomalyMonitorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateAnomalyMonitorError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAnomalyMonitorError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2704,16 +2710,22 @@ impl CreateAnomalySubscriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateAnomalySubscriptionError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAnomalySubscriptionError::LimitExceeded,
+                    )
                 }
                 "UnknownMonitorException" => {
-                    return RusotoError::Service(CreateAnomalySubscriptionError::UnknownMonitor(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAnomalySubscriptionError::UnknownMonitor,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2746,16 +2758,22 @@ impl CreateCostCategoryDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateCostCategoryDefinitionError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCostCategoryDefinitionError::LimitExceeded,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(
-                        CreateCostCategoryDefinitionError::ServiceQuotaExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCostCategoryDefinitionError::ServiceQuotaExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2788,12 +2806,22 @@ impl DeleteAnomalyMonitorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteAnomalyMonitorError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAnomalyMonitorError::LimitExceeded,
+                    )
                 }
                 "UnknownMonitorException" => {
-                    return RusotoError::Service(DeleteAnomalyMonitorError::UnknownMonitor(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAnomalyMonitorError::UnknownMonitor,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2824,16 +2852,22 @@ impl DeleteAnomalySubscriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteAnomalySubscriptionError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteAnomalySubscriptionError::LimitExceeded,
+                    )
                 }
                 "UnknownSubscriptionException" => {
-                    return RusotoError::Service(
-                        DeleteAnomalySubscriptionError::UnknownSubscription(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAnomalySubscriptionError::UnknownSubscription,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2868,16 +2902,22 @@ impl DeleteCostCategoryDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteCostCategoryDefinitionError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteCostCategoryDefinitionError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteCostCategoryDefinitionError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCostCategoryDefinitionError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2912,16 +2952,22 @@ impl DescribeCostCategoryDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(
-                        DescribeCostCategoryDefinitionError::LimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCostCategoryDefinitionError::LimitExceeded,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeCostCategoryDefinitionError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCostCategoryDefinitionError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2954,12 +3000,22 @@ impl GetAnomaliesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(GetAnomaliesError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAnomaliesError::InvalidNextToken,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetAnomaliesError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAnomaliesError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2992,15 +3048,30 @@ impl GetAnomalyMonitorsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(GetAnomalyMonitorsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAnomalyMonitorsError::InvalidNextToken,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetAnomalyMonitorsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAnomalyMonitorsError::LimitExceeded,
+                    )
                 }
                 "UnknownMonitorException" => {
-                    return RusotoError::Service(GetAnomalyMonitorsError::UnknownMonitor(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAnomalyMonitorsError::UnknownMonitor,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3034,21 +3105,30 @@ impl GetAnomalySubscriptionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(GetAnomalySubscriptionsError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetAnomalySubscriptionsError::InvalidNextToken,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetAnomalySubscriptionsError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetAnomalySubscriptionsError::LimitExceeded,
+                    )
                 }
                 "UnknownSubscriptionException" => {
-                    return RusotoError::Service(GetAnomalySubscriptionsError::UnknownSubscription(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetAnomalySubscriptionsError::UnknownSubscription,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3086,21 +3166,46 @@ impl GetCostAndUsageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BillExpirationException" => {
-                    return RusotoError::Service(GetCostAndUsageError::BillExpiration(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCostAndUsageError::BillExpiration,
+                    )
                 }
                 "DataUnavailableException" => {
-                    return RusotoError::Service(GetCostAndUsageError::DataUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCostAndUsageError::DataUnavailable,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(GetCostAndUsageError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCostAndUsageError::InvalidNextToken,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetCostAndUsageError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCostAndUsageError::LimitExceeded,
+                    )
                 }
                 "RequestChangedException" => {
-                    return RusotoError::Service(GetCostAndUsageError::RequestChanged(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCostAndUsageError::RequestChanged,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3142,31 +3247,46 @@ impl GetCostAndUsageWithResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BillExpirationException" => {
-                    return RusotoError::Service(GetCostAndUsageWithResourcesError::BillExpiration(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCostAndUsageWithResourcesError::BillExpiration,
+                    )
                 }
                 "DataUnavailableException" => {
-                    return RusotoError::Service(
-                        GetCostAndUsageWithResourcesError::DataUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCostAndUsageWithResourcesError::DataUnavailable,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        GetCostAndUsageWithResourcesError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCostAndUsageWithResourcesError::InvalidNextToken,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetCostAndUsageWithResourcesError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCostAndUsageWithResourcesError::LimitExceeded,
+                    )
                 }
                 "RequestChangedException" => {
-                    return RusotoError::Service(GetCostAndUsageWithResourcesError::RequestChanged(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCostAndUsageWithResourcesError::RequestChanged,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3202,12 +3322,22 @@ impl GetCostForecastError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DataUnavailableException" => {
-                    return RusotoError::Service(GetCostForecastError::DataUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCostForecastError::DataUnavailable,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetCostForecastError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCostForecastError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3244,21 +3374,46 @@ impl GetDimensionValuesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BillExpirationException" => {
-                    return RusotoError::Service(GetDimensionValuesError::BillExpiration(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDimensionValuesError::BillExpiration,
+                    )
                 }
                 "DataUnavailableException" => {
-                    return RusotoError::Service(GetDimensionValuesError::DataUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDimensionValuesError::DataUnavailable,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(GetDimensionValuesError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDimensionValuesError::InvalidNextToken,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetDimensionValuesError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDimensionValuesError::LimitExceeded,
+                    )
                 }
                 "RequestChangedException" => {
-                    return RusotoError::Service(GetDimensionValuesError::RequestChanged(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDimensionValuesError::RequestChanged,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3294,21 +3449,30 @@ impl GetReservationCoverageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DataUnavailableException" => {
-                    return RusotoError::Service(GetReservationCoverageError::DataUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetReservationCoverageError::DataUnavailable,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(GetReservationCoverageError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetReservationCoverageError::InvalidNextToken,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetReservationCoverageError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetReservationCoverageError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3344,21 +3508,30 @@ impl GetReservationPurchaseRecommendationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DataUnavailableException" => {
-                    return RusotoError::Service(
-                        GetReservationPurchaseRecommendationError::DataUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetReservationPurchaseRecommendationError::DataUnavailable,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        GetReservationPurchaseRecommendationError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetReservationPurchaseRecommendationError::InvalidNextToken,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
-                        GetReservationPurchaseRecommendationError::LimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetReservationPurchaseRecommendationError::LimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3398,21 +3571,30 @@ impl GetReservationUtilizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DataUnavailableException" => {
-                    return RusotoError::Service(GetReservationUtilizationError::DataUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetReservationUtilizationError::DataUnavailable,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(GetReservationUtilizationError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetReservationUtilizationError::InvalidNextToken,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetReservationUtilizationError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetReservationUtilizationError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3446,16 +3628,22 @@ impl GetRightsizingRecommendationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        GetRightsizingRecommendationError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRightsizingRecommendationError::InvalidNextToken,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetRightsizingRecommendationError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRightsizingRecommendationError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3490,21 +3678,30 @@ impl GetSavingsPlansCoverageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DataUnavailableException" => {
-                    return RusotoError::Service(GetSavingsPlansCoverageError::DataUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSavingsPlansCoverageError::DataUnavailable,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(GetSavingsPlansCoverageError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSavingsPlansCoverageError::InvalidNextToken,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetSavingsPlansCoverageError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSavingsPlansCoverageError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3538,16 +3735,22 @@ impl GetSavingsPlansPurchaseRecommendationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        GetSavingsPlansPurchaseRecommendationError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSavingsPlansPurchaseRecommendationError::InvalidNextToken,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
-                        GetSavingsPlansPurchaseRecommendationError::LimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSavingsPlansPurchaseRecommendationError::LimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3584,16 +3787,22 @@ impl GetSavingsPlansUtilizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DataUnavailableException" => {
-                    return RusotoError::Service(GetSavingsPlansUtilizationError::DataUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSavingsPlansUtilizationError::DataUnavailable,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetSavingsPlansUtilizationError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSavingsPlansUtilizationError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3628,21 +3837,30 @@ impl GetSavingsPlansUtilizationDetailsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DataUnavailableException" => {
-                    return RusotoError::Service(
-                        GetSavingsPlansUtilizationDetailsError::DataUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSavingsPlansUtilizationDetailsError::DataUnavailable,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        GetSavingsPlansUtilizationDetailsError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSavingsPlansUtilizationDetailsError::InvalidNextToken,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
-                        GetSavingsPlansUtilizationDetailsError::LimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSavingsPlansUtilizationDetailsError::LimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3686,21 +3904,46 @@ impl GetTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BillExpirationException" => {
-                    return RusotoError::Service(GetTagsError::BillExpiration(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTagsError::BillExpiration,
+                    )
                 }
                 "DataUnavailableException" => {
-                    return RusotoError::Service(GetTagsError::DataUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTagsError::DataUnavailable,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(GetTagsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTagsError::InvalidNextToken,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetTagsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTagsError::LimitExceeded,
+                    )
                 }
                 "RequestChangedException" => {
-                    return RusotoError::Service(GetTagsError::RequestChanged(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTagsError::RequestChanged,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3736,17 +3979,30 @@ impl GetUsageForecastError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DataUnavailableException" => {
-                    return RusotoError::Service(GetUsageForecastError::DataUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUsageForecastError::DataUnavailable,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetUsageForecastError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUsageForecastError::LimitExceeded,
+                    )
                 }
                 "UnresolvableUsageUnitException" => {
-                    return RusotoError::Service(GetUsageForecastError::UnresolvableUsageUnit(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetUsageForecastError::UnresolvableUsageUnit,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3778,11 +4034,14 @@ impl ListCostCategoryDefinitionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListCostCategoryDefinitionsError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListCostCategoryDefinitionsError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3810,11 +4069,14 @@ impl ProvideAnomalyFeedbackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(ProvideAnomalyFeedbackError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ProvideAnomalyFeedbackError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3844,12 +4106,22 @@ impl UpdateAnomalyMonitorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateAnomalyMonitorError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAnomalyMonitorError::LimitExceeded,
+                    )
                 }
                 "UnknownMonitorException" => {
-                    return RusotoError::Service(UpdateAnomalyMonitorError::UnknownMonitor(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAnomalyMonitorError::UnknownMonitor,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3882,21 +4154,30 @@ impl UpdateAnomalySubscriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateAnomalySubscriptionError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAnomalySubscriptionError::LimitExceeded,
+                    )
                 }
                 "UnknownMonitorException" => {
-                    return RusotoError::Service(UpdateAnomalySubscriptionError::UnknownMonitor(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAnomalySubscriptionError::UnknownMonitor,
+                    )
                 }
                 "UnknownSubscriptionException" => {
-                    return RusotoError::Service(
-                        UpdateAnomalySubscriptionError::UnknownSubscription(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAnomalySubscriptionError::UnknownSubscription,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3934,21 +4215,30 @@ impl UpdateCostCategoryDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateCostCategoryDefinitionError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateCostCategoryDefinitionError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateCostCategoryDefinitionError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCostCategoryDefinitionError::ResourceNotFound,
                     )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(
-                        UpdateCostCategoryDefinitionError::ServiceQuotaExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCostCategoryDefinitionError::ServiceQuotaExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4169,11 +4459,12 @@ pub struct CostExplorerClient {
 impl CostExplorerClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> CostExplorerClient {
         CostExplorerClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Cost Explorer"),
         }
     }
 
@@ -4188,12 +4479,32 @@ impl CostExplorerClient {
     {
         CostExplorerClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Cost Explorer"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CostExplorerClient {
-        CostExplorerClient { client, region }
+        CostExplorerClient {
+            client,
+            region: region_for_service(region, "Cost Explorer"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> CostExplorerClient {
+        CostExplorerClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Cost Explorer"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CostExplorerClient {
+        CostExplorerClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -4205,6 +4516,7 @@ impl CostExplorer for CostExplorerClient {
         input: CreateAnomalyMonitorRequest,
     ) -> Result<CreateAnomalyMonitorResponse, RusotoError<CreateAnomalyMonitorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateAnomalyMonitor");
         request.add_header(
             "x-amz-target",
             "AWSInsightsIndexService.CreateAnomalyMonitor",
@@ -4228,6 +4540,7 @@ impl CostExplorer for CostExplorerClient {
     ) -> Result<CreateAnomalySubscriptionResponse, RusotoError<CreateAnomalySubscriptionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateAnomalySubscription");
         request.add_header(
             "x-amz-target",
             "AWSInsightsIndexService.CreateAnomalySubscription",
@@ -4251,6 +4564,7 @@ impl CostExplorer for CostExplorerClient {
     ) -> Result<CreateCostCategoryDefinitionResponse, RusotoError<CreateCostCategoryDefinitionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateCostCategoryDefinition");
         request.add_header(
             "x-amz-target",
             "AWSInsightsIndexService.CreateCostCategoryDefinition",
@@ -4273,6 +4587,7 @@ impl CostExplorer for CostExplorerClient {
         input: DeleteAnomalyMonitorRequest,
     ) -> Result<DeleteAnomalyMonitorResponse, RusotoError<DeleteAnomalyMonitorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteAnomalyMonitor");
         request.add_header(
             "x-amz-target",
             "AWSInsightsIndexService.DeleteAnomalyMonitor",
@@ -4296,6 +4611,7 @@ impl CostExplorer for CostExplorerClient {
     ) -> Result<DeleteAnomalySubscriptionResponse, RusotoError<DeleteAnomalySubscriptionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteAnomalySubscription");
         request.add_header(
             "x-amz-target",
             "AWSInsightsIndexService.DeleteAnomalySubscription",
@@ -4319,6 +4635,7 @@ impl CostExplorer for CostExplorerClient {
     ) -> Result<DeleteCostCategoryDefinitionResponse, RusotoError<DeleteCostCategoryDefinitionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteCostCategoryDefinition");
         request.add_header(
             "x-amz-target",
             "AWSInsightsIndexService.DeleteCostCategoryDefinition",
@@ -4344,6 +4661,7 @@ impl CostExplorer for CostExplorerClient {
         RusotoError<DescribeCostCategoryDefinitionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeCostCategoryDefinition");
         request.add_header(
             "x-amz-target",
             "AWSInsightsIndexService.DescribeCostCategoryDefinition",
@@ -4366,6 +4684,7 @@ impl CostExplorer for CostExplorerClient {
         input: GetAnomaliesRequest,
     ) -> Result<GetAnomaliesResponse, RusotoError<GetAnomaliesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetAnomalies");
         request.add_header("x-amz-target", "AWSInsightsIndexService.GetAnomalies");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4384,6 +4703,7 @@ impl CostExplorer for CostExplorerClient {
         input: GetAnomalyMonitorsRequest,
     ) -> Result<GetAnomalyMonitorsResponse, RusotoError<GetAnomalyMonitorsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetAnomalyMonitors");
         request.add_header("x-amz-target", "AWSInsightsIndexService.GetAnomalyMonitors");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4402,6 +4722,7 @@ impl CostExplorer for CostExplorerClient {
         input: GetAnomalySubscriptionsRequest,
     ) -> Result<GetAnomalySubscriptionsResponse, RusotoError<GetAnomalySubscriptionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetAnomalySubscriptions");
         request.add_header(
             "x-amz-target",
             "AWSInsightsIndexService.GetAnomalySubscriptions",
@@ -4424,6 +4745,7 @@ impl CostExplorer for CostExplorerClient {
         input: GetCostAndUsageRequest,
     ) -> Result<GetCostAndUsageResponse, RusotoError<GetCostAndUsageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetCostAndUsage");
         request.add_header("x-amz-target", "AWSInsightsIndexService.GetCostAndUsage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4443,6 +4765,7 @@ impl CostExplorer for CostExplorerClient {
     ) -> Result<GetCostAndUsageWithResourcesResponse, RusotoError<GetCostAndUsageWithResourcesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetCostAndUsageWithResources");
         request.add_header(
             "x-amz-target",
             "AWSInsightsIndexService.GetCostAndUsageWithResources",
@@ -4465,6 +4788,7 @@ impl CostExplorer for CostExplorerClient {
         input: GetCostForecastRequest,
     ) -> Result<GetCostForecastResponse, RusotoError<GetCostForecastError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetCostForecast");
         request.add_header("x-amz-target", "AWSInsightsIndexService.GetCostForecast");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4483,6 +4807,7 @@ impl CostExplorer for CostExplorerClient {
         input: GetDimensionValuesRequest,
     ) -> Result<GetDimensionValuesResponse, RusotoError<GetDimensionValuesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDimensionValues");
         request.add_header("x-amz-target", "AWSInsightsIndexService.GetDimensionValues");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4501,6 +4826,7 @@ impl CostExplorer for CostExplorerClient {
         input: GetReservationCoverageRequest,
     ) -> Result<GetReservationCoverageResponse, RusotoError<GetReservationCoverageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetReservationCoverage");
         request.add_header(
             "x-amz-target",
             "AWSInsightsIndexService.GetReservationCoverage",
@@ -4526,6 +4852,7 @@ impl CostExplorer for CostExplorerClient {
         RusotoError<GetReservationPurchaseRecommendationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetReservationPurchaseRecommendation");
         request.add_header(
             "x-amz-target",
             "AWSInsightsIndexService.GetReservationPurchaseRecommendation",
@@ -4552,6 +4879,7 @@ impl CostExplorer for CostExplorerClient {
     ) -> Result<GetReservationUtilizationResponse, RusotoError<GetReservationUtilizationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetReservationUtilization");
         request.add_header(
             "x-amz-target",
             "AWSInsightsIndexService.GetReservationUtilization",
@@ -4575,6 +4903,7 @@ impl CostExplorer for CostExplorerClient {
     ) -> Result<GetRightsizingRecommendationResponse, RusotoError<GetRightsizingRecommendationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRightsizingRecommendation");
         request.add_header(
             "x-amz-target",
             "AWSInsightsIndexService.GetRightsizingRecommendation",
@@ -4597,6 +4926,7 @@ impl CostExplorer for CostExplorerClient {
         input: GetSavingsPlansCoverageRequest,
     ) -> Result<GetSavingsPlansCoverageResponse, RusotoError<GetSavingsPlansCoverageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetSavingsPlansCoverage");
         request.add_header(
             "x-amz-target",
             "AWSInsightsIndexService.GetSavingsPlansCoverage",
@@ -4622,6 +4952,7 @@ impl CostExplorer for CostExplorerClient {
         RusotoError<GetSavingsPlansPurchaseRecommendationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetSavingsPlansPurchaseRecommendation");
         request.add_header(
             "x-amz-target",
             "AWSInsightsIndexService.GetSavingsPlansPurchaseRecommendation",
@@ -4648,6 +4979,7 @@ impl CostExplorer for CostExplorerClient {
     ) -> Result<GetSavingsPlansUtilizationResponse, RusotoError<GetSavingsPlansUtilizationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetSavingsPlansUtilization");
         request.add_header(
             "x-amz-target",
             "AWSInsightsIndexService.GetSavingsPlansUtilization",
@@ -4673,6 +5005,7 @@ impl CostExplorer for CostExplorerClient {
         RusotoError<GetSavingsPlansUtilizationDetailsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetSavingsPlansUtilizationDetails");
         request.add_header(
             "x-amz-target",
             "AWSInsightsIndexService.GetSavingsPlansUtilizationDetails",
@@ -4698,6 +5031,7 @@ impl CostExplorer for CostExplorerClient {
         input: GetTagsRequest,
     ) -> Result<GetTagsResponse, RusotoError<GetTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetTags");
         request.add_header("x-amz-target", "AWSInsightsIndexService.GetTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4716,6 +5050,7 @@ impl CostExplorer for CostExplorerClient {
         input: GetUsageForecastRequest,
     ) -> Result<GetUsageForecastResponse, RusotoError<GetUsageForecastError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetUsageForecast");
         request.add_header("x-amz-target", "AWSInsightsIndexService.GetUsageForecast");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4735,6 +5070,7 @@ impl CostExplorer for CostExplorerClient {
     ) -> Result<ListCostCategoryDefinitionsResponse, RusotoError<ListCostCategoryDefinitionsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListCostCategoryDefinitions");
         request.add_header(
             "x-amz-target",
             "AWSInsightsIndexService.ListCostCategoryDefinitions",
@@ -4757,6 +5093,7 @@ impl CostExplorer for CostExplorerClient {
         input: ProvideAnomalyFeedbackRequest,
     ) -> Result<ProvideAnomalyFeedbackResponse, RusotoError<ProvideAnomalyFeedbackError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ProvideAnomalyFeedback");
         request.add_header(
             "x-amz-target",
             "AWSInsightsIndexService.ProvideAnomalyFeedback",
@@ -4779,6 +5116,7 @@ impl CostExplorer for CostExplorerClient {
         input: UpdateAnomalyMonitorRequest,
     ) -> Result<UpdateAnomalyMonitorResponse, RusotoError<UpdateAnomalyMonitorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateAnomalyMonitor");
         request.add_header(
             "x-amz-target",
             "AWSInsightsIndexService.UpdateAnomalyMonitor",
@@ -4802,6 +5140,7 @@ impl CostExplorer for CostExplorerClient {
     ) -> Result<UpdateAnomalySubscriptionResponse, RusotoError<UpdateAnomalySubscriptionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateAnomalySubscription");
         request.add_header(
             "x-amz-target",
             "AWSInsightsIndexService.UpdateAnomalySubscription",
@@ -4825,6 +5164,7 @@ impl CostExplorer for CostExplorerClient {
     ) -> Result<UpdateCostCategoryDefinitionResponse, RusotoError<UpdateCostCategoryDefinitionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateCostCategoryDefinition");
         request.add_header(
             "x-amz-target",
             "AWSInsightsIndexService.UpdateCostCategoryDefinition",
@@ -4841,3 +5181,360 @@ impl CostExplorer for CostExplorerClient {
             .deserialize::<UpdateCostCategoryDefinitionResponse, _>()
     }
 }
+
+/// A blocking client for the AWS Cost Explorer API, running the calls of a `CostExplorerClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `CostExplorerClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CostExplorerBlockingClient {
+    client: CostExplorerClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CostExplorerBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> CostExplorerBlockingClient {
+        CostExplorerBlockingClient::from_client(CostExplorerClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CostExplorerBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CostExplorerBlockingClient::from_client(CostExplorerClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> CostExplorerBlockingClient {
+        CostExplorerBlockingClient::from_client(CostExplorerClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> CostExplorerBlockingClient {
+        CostExplorerBlockingClient::from_client(CostExplorerClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: CostExplorerClient) -> CostExplorerBlockingClient {
+        CostExplorerBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> CostExplorerBlockingClient {
+        CostExplorerBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CostExplorerBlockingClient {
+        CostExplorerBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`CostExplorer::create_anomaly_monitor`].
+    pub fn create_anomaly_monitor(
+        &self,
+        input: CreateAnomalyMonitorRequest,
+    ) -> Result<CreateAnomalyMonitorResponse, RusotoError<CreateAnomalyMonitorError>> {
+        self.runtime
+            .block_on(self.client.create_anomaly_monitor(input))
+    }
+
+    /// Blocking version of [`CostExplorer::create_anomaly_subscription`].
+    pub fn create_anomaly_subscription(
+        &self,
+        input: CreateAnomalySubscriptionRequest,
+    ) -> Result<CreateAnomalySubscriptionResponse, RusotoError<CreateAnomalySubscriptionError>>
+    {
+        self.runtime
+            .block_on(self.client.create_anomaly_subscription(input))
+    }
+
+    /// Blocking version of [`CostExplorer::create_cost_category_definition`].
+    pub fn create_cost_category_definition(
+        &self,
+        input: CreateCostCategoryDefinitionRequest,
+    ) -> Result<CreateCostCategoryDefinitionResponse, RusotoError<CreateCostCategoryDefinitionError>>
+    {
+        self.runtime
+            .block_on(self.client.create_cost_category_definition(input))
+    }
+
+    /// Blocking version of [`CostExplorer::delete_anomaly_monitor`].
+    pub fn delete_anomaly_monitor(
+        &self,
+        input: DeleteAnomalyMonitorRequest,
+    ) -> Result<DeleteAnomalyMonitorResponse, RusotoError<DeleteAnomalyMonitorError>> {
+        self.runtime
+            .block_on(self.client.delete_anomaly_monitor(input))
+    }
+
+    /// Blocking version of [`CostExplorer::delete_anomaly_subscription`].
+    pub fn delete_anomaly_subscription(
+        &self,
+        input: DeleteAnomalySubscriptionRequest,
+    ) -> Result<DeleteAnomalySubscriptionResponse, RusotoError<DeleteAnomalySubscriptionError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_anomaly_subscription(input))
+    }
+
+    /// Blocking version of [`CostExplorer::delete_cost_category_definition`].
+    pub fn delete_cost_category_definition(
+        &self,
+        input: DeleteCostCategoryDefinitionRequest,
+    ) -> Result<DeleteCostCategoryDefinitionResponse, RusotoError<DeleteCostCategoryDefinitionError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_cost_category_definition(input))
+    }
+
+    /// Blocking version of [`CostExplorer::describe_cost_category_definition`].
+    pub fn describe_cost_category_definition(
+        &self,
+        input: DescribeCostCategoryDefinitionRequest,
+    ) -> Result<
+        DescribeCostCategoryDefinitionResponse,
+        RusotoError<DescribeCostCategoryDefinitionError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_cost_category_definition(input))
+    }
+
+    /// Blocking version of [`CostExplorer::get_anomalies`].
+    pub fn get_anomalies(
+        &self,
+        input: GetAnomaliesRequest,
+    ) -> Result<GetAnomaliesResponse, RusotoError<GetAnomaliesError>> {
+        self.runtime.block_on(self.client.get_anomalies(input))
+    }
+
+    /// Blocking version of [`CostExplorer::get_anomaly_monitors`].
+    pub fn get_anomaly_monitors(
+        &self,
+        input: GetAnomalyMonitorsRequest,
+    ) -> Result<GetAnomalyMonitorsResponse, RusotoError<GetAnomalyMonitorsError>> {
+        self.runtime
+            .block_on(self.client.get_anomaly_monitors(input))
+    }
+
+    /// Blocking version of [`CostExplorer::get_anomaly_subscriptions`].
+    pub fn get_anomaly_subscriptions(
+        &self,
+        input: GetAnomalySubscriptionsRequest,
+    ) -> Result<GetAnomalySubscriptionsResponse, RusotoError<GetAnomalySubscriptionsError>> {
+        self.runtime
+            .block_on(self.client.get_anomaly_subscriptions(input))
+    }
+
+    /// Blocking version of [`CostExplorer::get_cost_and_usage`].
+    pub fn get_cost_and_usage(
+        &self,
+        input: GetCostAndUsageRequest,
+    ) -> Result<GetCostAndUsageResponse, RusotoError<GetCostAndUsageError>> {
+        self.runtime.block_on(self.client.get_cost_and_usage(input))
+    }
+
+    /// Blocking version of [`CostExplorer::get_cost_and_usage_with_resources`].
+    pub fn get_cost_and_usage_with_resources(
+        &self,
+        input: GetCostAndUsageWithResourcesRequest,
+    ) -> Result<GetCostAndUsageWithResourcesResponse, RusotoError<GetCostAndUsageWithResourcesError>>
+    {
+        self.runtime
+            .block_on(self.client.get_cost_and_usage_with_resources(input))
+    }
+
+    /// Blocking version of [`CostExplorer::get_cost_forecast`].
+    pub fn get_cost_forecast(
+        &self,
+        input: GetCostForecastRequest,
+    ) -> Result<GetCostForecastResponse, RusotoError<GetCostForecastError>> {
+        self.runtime.block_on(self.client.get_cost_forecast(input))
+    }
+
+    /// Blocking version of [`CostExplorer::get_dimension_values`].
+    pub fn get_dimension_values(
+        &self,
+        input: GetDimensionValuesRequest,
+    ) -> Result<GetDimensionValuesResponse, RusotoError<GetDimensionValuesError>> {
+        self.runtime
+            .block_on(self.client.get_dimension_values(input))
+    }
+
+    /// Blocking version of [`CostExplorer::get_reservation_coverage`].
+    pub fn get_reservation_coverage(
+        &self,
+        input: GetReservationCoverageRequest,
+    ) -> Result<GetReservationCoverageResponse, RusotoError<GetReservationCoverageError>> {
+        self.runtime
+            .block_on(self.client.get_reservation_coverage(input))
+    }
+
+    /// Blocking version of [`CostExplorer::get_reservation_purchase_recommendation`].
+    pub fn get_reservation_purchase_recommendation(
+        &self,
+        input: GetReservationPurchaseRecommendationRequest,
+    ) -> Result<
+        GetReservationPurchaseRecommendationResponse,
+        RusotoError<GetReservationPurchaseRecommendationError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_reservation_purchase_recommendation(input))
+    }
+
+    /// Blocking version of [`CostExplorer::get_reservation_utilization`].
+    pub fn get_reservation_utilization(
+        &self,
+        input: GetReservationUtilizationRequest,
+    ) -> Result<GetReservationUtilizationResponse, RusotoError<GetReservationUtilizationError>>
+    {
+        self.runtime
+            .block_on(self.client.get_reservation_utilization(input))
+    }
+
+    /// Blocking version of [`CostExplorer::get_rightsizing_recommendation`].
+    pub fn get_rightsizing_recommendation(
+        &self,
+        input: GetRightsizingRecommendationRequest,
+    ) -> Result<GetRightsizingRecommendationResponse, RusotoError<GetRightsizingRecommendationError>>
+    {
+        self.runtime
+            .block_on(self.client.get_rightsizing_recommendation(input))
+    }
+
+    /// Blocking version of [`CostExplorer::get_savings_plans_coverage`].
+    pub fn get_savings_plans_coverage(
+        &self,
+        input: GetSavingsPlansCoverageRequest,
+    ) -> Result<GetSavingsPlansCoverageResponse, RusotoError<GetSavingsPlansCoverageError>> {
+        self.runtime
+            .block_on(self.client.get_savings_plans_coverage(input))
+    }
+
+    /// Blocking version of [`CostExplorer::get_savings_plans_purchase_recommendation`].
+    pub fn get_savings_plans_purchase_recommendation(
+        &self,
+        input: GetSavingsPlansPurchaseRecommendationRequest,
+    ) -> Result<
+        GetSavingsPlansPurchaseRecommendationResponse,
+        RusotoError<GetSavingsPlansPurchaseRecommendationError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_savings_plans_purchase_recommendation(input))
+    }
+
+    /// Blocking version of [`CostExplorer::get_savings_plans_utilization`].
+    pub fn get_savings_plans_utilization(
+        &self,
+        input: GetSavingsPlansUtilizationRequest,
+    ) -> Result<GetSavingsPlansUtilizationResponse, RusotoError<GetSavingsPlansUtilizationError>>
+    {
+        self.runtime
+            .block_on(self.client.get_savings_plans_utilization(input))
+    }
+
+    /// Blocking version of [`CostExplorer::get_savings_plans_utilization_details`].
+    pub fn get_savings_plans_utilization_details(
+        &self,
+        input: GetSavingsPlansUtilizationDetailsRequest,
+    ) -> Result<
+        GetSavingsPlansUtilizationDetailsResponse,
+        RusotoError<GetSavingsPlansUtilizationDetailsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_savings_plans_utilization_details(input))
+    }
+
+    /// Blocking version of [`CostExplorer::get_tags`].
+    pub fn get_tags(
+        &self,
+        input: GetTagsRequest,
+    ) -> Result<GetTagsResponse, RusotoError<GetTagsError>> {
+        self.runtime.block_on(self.client.get_tags(input))
+    }
+
+    /// Blocking version of [`CostExplorer::get_usage_forecast`].
+    pub fn get_usage_forecast(
+        &self,
+        input: GetUsageForecastRequest,
+    ) -> Result<GetUsageForecastResponse, RusotoError<GetUsageForecastError>> {
+        self.runtime.block_on(self.client.get_usage_forecast(input))
+    }
+
+    /// Blocking version of [`CostExplorer::list_cost_category_definitions`].
+    pub fn list_cost_category_definitions(
+        &self,
+        input: ListCostCategoryDefinitionsRequest,
+    ) -> Result<ListCostCategoryDefinitionsResponse, RusotoError<ListCostCategoryDefinitionsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_cost_category_definitions(input))
+    }
+
+    /// Blocking version of [`CostExplorer::provide_anomaly_feedback`].
+    pub fn provide_anomaly_feedback(
+        &self,
+        input: ProvideAnomalyFeedbackRequest,
+    ) -> Result<ProvideAnomalyFeedbackResponse, RusotoError<ProvideAnomalyFeedbackError>> {
+        self.runtime
+            .block_on(self.client.provide_anomaly_feedback(input))
+    }
+
+    /// Blocking version of [`CostExplorer::update_anomaly_monitor`].
+    pub fn update_anomaly_monitor(
+        &self,
+        input: UpdateAnomalyMonitorRequest,
+    ) -> Result<UpdateAnomalyMonitorResponse, RusotoError<UpdateAnomalyMonitorError>> {
+        self.runtime
+            .block_on(self.client.update_anomaly_monitor(input))
+    }
+
+    /// Blocking version of [`CostExplorer::update_anomaly_subscription`].
+    pub fn update_anomaly_subscription(
+        &self,
+        input: UpdateAnomalySubscriptionRequest,
+    ) -> Result<UpdateAnomalySubscriptionResponse, RusotoError<UpdateAnomalySubscriptionError>>
+    {
+        self.runtime
+            .block_on(self.client.update_anomaly_subscription(input))
+    }
+
+    /// Blocking version of [`CostExplorer::update_cost_category_definition`].
+    pub fn update_cost_category_definition(
+        &self,
+        input: UpdateCostCategoryDefinitionRequest,
+    ) -> Result<UpdateCostCategoryDefinitionResponse, RusotoError<UpdateCostCategoryDefinitionError>>
+    {
+        self.runtime
+            .block_on(self.client.update_cost_category_definition(input))
+    }
+}
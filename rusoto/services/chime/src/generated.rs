@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -5450,46 +5451,70 @@ impl AssociatePhoneNumberWithUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AssociatePhoneNumberWithUserError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociatePhoneNumberWithUserError::AccessDenied,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(AssociatePhoneNumberWithUserError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociatePhoneNumberWithUserError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(AssociatePhoneNumberWithUserError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociatePhoneNumberWithUserError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AssociatePhoneNumberWithUserError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociatePhoneNumberWithUserError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(AssociatePhoneNumberWithUserError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociatePhoneNumberWithUserError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        AssociatePhoneNumberWithUserError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociatePhoneNumberWithUserError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        AssociatePhoneNumberWithUserError::ThrottledClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociatePhoneNumberWithUserError::ThrottledClient,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        AssociatePhoneNumberWithUserError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociatePhoneNumberWithUserError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5544,46 +5569,70 @@ impl AssociatePhoneNumbersWithVoiceConnectorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        AssociatePhoneNumbersWithVoiceConnectorError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociatePhoneNumbersWithVoiceConnectorError::AccessDenied,
                     )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        AssociatePhoneNumbersWithVoiceConnectorError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociatePhoneNumbersWithVoiceConnectorError::BadRequest,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
-                        AssociatePhoneNumbersWithVoiceConnectorError::Forbidden(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociatePhoneNumbersWithVoiceConnectorError::Forbidden,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
-                        AssociatePhoneNumbersWithVoiceConnectorError::NotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociatePhoneNumbersWithVoiceConnectorError::NotFound,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        AssociatePhoneNumbersWithVoiceConnectorError::ServiceFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociatePhoneNumbersWithVoiceConnectorError::ServiceFailure,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        AssociatePhoneNumbersWithVoiceConnectorError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociatePhoneNumbersWithVoiceConnectorError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        AssociatePhoneNumbersWithVoiceConnectorError::ThrottledClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociatePhoneNumbersWithVoiceConnectorError::ThrottledClient,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        AssociatePhoneNumbersWithVoiceConnectorError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociatePhoneNumbersWithVoiceConnectorError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5650,50 +5699,70 @@ impl AssociatePhoneNumbersWithVoiceConnectorGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        AssociatePhoneNumbersWithVoiceConnectorGroupError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociatePhoneNumbersWithVoiceConnectorGroupError::AccessDenied,
                     )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        AssociatePhoneNumbersWithVoiceConnectorGroupError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociatePhoneNumbersWithVoiceConnectorGroupError::BadRequest,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
-                        AssociatePhoneNumbersWithVoiceConnectorGroupError::Forbidden(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociatePhoneNumbersWithVoiceConnectorGroupError::Forbidden,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
-                        AssociatePhoneNumbersWithVoiceConnectorGroupError::NotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociatePhoneNumbersWithVoiceConnectorGroupError::NotFound,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        AssociatePhoneNumbersWithVoiceConnectorGroupError::ServiceFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociatePhoneNumbersWithVoiceConnectorGroupError::ServiceFailure,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        AssociatePhoneNumbersWithVoiceConnectorGroupError::ServiceUnavailable(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociatePhoneNumbersWithVoiceConnectorGroupError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        AssociatePhoneNumbersWithVoiceConnectorGroupError::ThrottledClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociatePhoneNumbersWithVoiceConnectorGroupError::ThrottledClient,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        AssociatePhoneNumbersWithVoiceConnectorGroupError::UnauthorizedClient(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociatePhoneNumbersWithVoiceConnectorGroupError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5758,41 +5827,62 @@ impl AssociateSigninDelegateGroupsWithAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        AssociateSigninDelegateGroupsWithAccountError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateSigninDelegateGroupsWithAccountError::BadRequest,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
-                        AssociateSigninDelegateGroupsWithAccountError::Forbidden(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateSigninDelegateGroupsWithAccountError::Forbidden,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
-                        AssociateSigninDelegateGroupsWithAccountError::NotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateSigninDelegateGroupsWithAccountError::NotFound,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        AssociateSigninDelegateGroupsWithAccountError::ServiceFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateSigninDelegateGroupsWithAccountError::ServiceFailure,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        AssociateSigninDelegateGroupsWithAccountError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateSigninDelegateGroupsWithAccountError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        AssociateSigninDelegateGroupsWithAccountError::ThrottledClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateSigninDelegateGroupsWithAccountError::ThrottledClient,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        AssociateSigninDelegateGroupsWithAccountError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateSigninDelegateGroupsWithAccountError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5854,36 +5944,70 @@ impl BatchCreateAttendeeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(BatchCreateAttendeeError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchCreateAttendeeError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(BatchCreateAttendeeError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchCreateAttendeeError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(BatchCreateAttendeeError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchCreateAttendeeError::NotFound,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(BatchCreateAttendeeError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchCreateAttendeeError::ResourceLimitExceeded,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(BatchCreateAttendeeError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchCreateAttendeeError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(BatchCreateAttendeeError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchCreateAttendeeError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(BatchCreateAttendeeError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchCreateAttendeeError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(BatchCreateAttendeeError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchCreateAttendeeError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5930,37 +6054,62 @@ impl BatchCreateRoomMembershipError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(BatchCreateRoomMembershipError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchCreateRoomMembershipError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(BatchCreateRoomMembershipError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchCreateRoomMembershipError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(BatchCreateRoomMembershipError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchCreateRoomMembershipError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(BatchCreateRoomMembershipError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchCreateRoomMembershipError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        BatchCreateRoomMembershipError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchCreateRoomMembershipError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(BatchCreateRoomMembershipError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchCreateRoomMembershipError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        BatchCreateRoomMembershipError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchCreateRoomMembershipError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6006,35 +6155,62 @@ impl BatchDeletePhoneNumberError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(BatchDeletePhoneNumberError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDeletePhoneNumberError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(BatchDeletePhoneNumberError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDeletePhoneNumberError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(BatchDeletePhoneNumberError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDeletePhoneNumberError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(BatchDeletePhoneNumberError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDeletePhoneNumberError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(BatchDeletePhoneNumberError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDeletePhoneNumberError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(BatchDeletePhoneNumberError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDeletePhoneNumberError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(BatchDeletePhoneNumberError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDeletePhoneNumberError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6080,27 +6256,62 @@ impl BatchSuspendUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(BatchSuspendUserError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchSuspendUserError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(BatchSuspendUserError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchSuspendUserError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(BatchSuspendUserError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchSuspendUserError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(BatchSuspendUserError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchSuspendUserError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(BatchSuspendUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchSuspendUserError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(BatchSuspendUserError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchSuspendUserError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(BatchSuspendUserError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchSuspendUserError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6146,31 +6357,62 @@ impl BatchUnsuspendUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(BatchUnsuspendUserError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchUnsuspendUserError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(BatchUnsuspendUserError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchUnsuspendUserError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(BatchUnsuspendUserError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchUnsuspendUserError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(BatchUnsuspendUserError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchUnsuspendUserError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(BatchUnsuspendUserError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchUnsuspendUserError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(BatchUnsuspendUserError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchUnsuspendUserError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(BatchUnsuspendUserError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchUnsuspendUserError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6216,35 +6458,62 @@ impl BatchUpdatePhoneNumberError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(BatchUpdatePhoneNumberError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchUpdatePhoneNumberError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(BatchUpdatePhoneNumberError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchUpdatePhoneNumberError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(BatchUpdatePhoneNumberError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchUpdatePhoneNumberError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(BatchUpdatePhoneNumberError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchUpdatePhoneNumberError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(BatchUpdatePhoneNumberError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchUpdatePhoneNumberError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(BatchUpdatePhoneNumberError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchUpdatePhoneNumberError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(BatchUpdatePhoneNumberError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchUpdatePhoneNumberError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6290,27 +6559,62 @@ impl BatchUpdateUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(BatchUpdateUserError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchUpdateUserError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(BatchUpdateUserError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchUpdateUserError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(BatchUpdateUserError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchUpdateUserError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(BatchUpdateUserError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchUpdateUserError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(BatchUpdateUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchUpdateUserError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(BatchUpdateUserError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchUpdateUserError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(BatchUpdateUserError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchUpdateUserError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6356,27 +6660,62 @@ impl CreateAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateAccountError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAccountError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateAccountError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAccountError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateAccountError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAccountError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateAccountError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAccountError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateAccountError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAccountError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateAccountError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAccountError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateAccountError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAccountError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6424,36 +6763,70 @@ impl CreateAppInstanceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateAppInstanceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAppInstanceError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateAppInstanceError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAppInstanceError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateAppInstanceError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAppInstanceError::Forbidden,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateAppInstanceError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAppInstanceError::ResourceLimitExceeded,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateAppInstanceError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAppInstanceError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateAppInstanceError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAppInstanceError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateAppInstanceError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAppInstanceError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateAppInstanceError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAppInstanceError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6502,40 +6875,70 @@ impl CreateAppInstanceAdminError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateAppInstanceAdminError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAppInstanceAdminError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateAppInstanceAdminError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAppInstanceAdminError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateAppInstanceAdminError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAppInstanceAdminError::Forbidden,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
-                        CreateAppInstanceAdminError::ResourceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAppInstanceAdminError::ResourceLimitExceeded,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateAppInstanceAdminError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAppInstanceAdminError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateAppInstanceAdminError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAppInstanceAdminError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateAppInstanceAdminError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAppInstanceAdminError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateAppInstanceAdminError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAppInstanceAdminError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6584,40 +6987,70 @@ impl CreateAppInstanceUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateAppInstanceUserError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAppInstanceUserError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateAppInstanceUserError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAppInstanceUserError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateAppInstanceUserError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAppInstanceUserError::Forbidden,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateAppInstanceUserError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAppInstanceUserError::ResourceLimitExceeded,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateAppInstanceUserError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAppInstanceUserError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateAppInstanceUserError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAppInstanceUserError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateAppInstanceUserError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAppInstanceUserError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateAppInstanceUserError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAppInstanceUserError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6666,32 +7099,70 @@ impl CreateAttendeeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateAttendeeError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAttendeeError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateAttendeeError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAttendeeError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateAttendeeError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAttendeeError::NotFound,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateAttendeeError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAttendeeError::ResourceLimitExceeded,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateAttendeeError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAttendeeError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateAttendeeError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAttendeeError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateAttendeeError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAttendeeError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateAttendeeError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAttendeeError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6740,30 +7211,70 @@ impl CreateBotError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateBotError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBotError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateBotError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBotError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateBotError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBotError::NotFound,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateBotError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBotError::ResourceLimitExceeded,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateBotError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBotError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateBotError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBotError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateBotError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBotError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateBotError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBotError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6812,30 +7323,70 @@ impl CreateChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateChannelError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateChannelError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateChannelError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateChannelError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateChannelError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateChannelError::Forbidden,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateChannelError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateChannelError::ResourceLimitExceeded,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateChannelError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateChannelError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateChannelError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateChannelError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateChannelError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateChannelError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateChannelError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateChannelError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6884,32 +7435,70 @@ impl CreateChannelBanError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateChannelBanError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateChannelBanError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateChannelBanError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateChannelBanError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateChannelBanError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateChannelBanError::Forbidden,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateChannelBanError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateChannelBanError::ResourceLimitExceeded,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateChannelBanError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateChannelBanError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateChannelBanError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateChannelBanError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateChannelBanError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateChannelBanError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateChannelBanError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateChannelBanError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6958,40 +7547,70 @@ impl CreateChannelMembershipError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateChannelMembershipError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateChannelMembershipError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateChannelMembershipError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateChannelMembershipError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateChannelMembershipError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateChannelMembershipError::Forbidden,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
-                        CreateChannelMembershipError::ResourceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateChannelMembershipError::ResourceLimitExceeded,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateChannelMembershipError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateChannelMembershipError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateChannelMembershipError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateChannelMembershipError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateChannelMembershipError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateChannelMembershipError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateChannelMembershipError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateChannelMembershipError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7042,40 +7661,70 @@ impl CreateChannelModeratorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateChannelModeratorError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateChannelModeratorError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateChannelModeratorError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateChannelModeratorError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateChannelModeratorError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateChannelModeratorError::Forbidden,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
-                        CreateChannelModeratorError::ResourceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateChannelModeratorError::ResourceLimitExceeded,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateChannelModeratorError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateChannelModeratorError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateChannelModeratorError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateChannelModeratorError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateChannelModeratorError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateChannelModeratorError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateChannelModeratorError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateChannelModeratorError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7122,27 +7771,62 @@ impl CreateMeetingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateMeetingError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMeetingError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateMeetingError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMeetingError::Forbidden,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateMeetingError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMeetingError::ResourceLimitExceeded,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateMeetingError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMeetingError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateMeetingError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMeetingError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateMeetingError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMeetingError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateMeetingError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMeetingError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7188,35 +7872,62 @@ impl CreateMeetingDialOutError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateMeetingDialOutError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMeetingDialOutError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateMeetingDialOutError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMeetingDialOutError::Forbidden,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateMeetingDialOutError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateMeetingDialOutError::ResourceLimitExceeded,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateMeetingDialOutError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMeetingDialOutError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateMeetingDialOutError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateMeetingDialOutError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateMeetingDialOutError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateMeetingDialOutError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateMeetingDialOutError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateMeetingDialOutError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7264,41 +7975,62 @@ impl CreateMeetingWithAttendeesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateMeetingWithAttendeesError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateMeetingWithAttendeesError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateMeetingWithAttendeesError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateMeetingWithAttendeesError::Forbidden,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
-                        CreateMeetingWithAttendeesError::ResourceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMeetingWithAttendeesError::ResourceLimitExceeded,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateMeetingWithAttendeesError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateMeetingWithAttendeesError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        CreateMeetingWithAttendeesError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMeetingWithAttendeesError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateMeetingWithAttendeesError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateMeetingWithAttendeesError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        CreateMeetingWithAttendeesError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMeetingWithAttendeesError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7352,40 +8084,70 @@ impl CreatePhoneNumberOrderError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreatePhoneNumberOrderError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePhoneNumberOrderError::AccessDenied,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreatePhoneNumberOrderError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePhoneNumberOrderError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreatePhoneNumberOrderError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePhoneNumberOrderError::Forbidden,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
-                        CreatePhoneNumberOrderError::ResourceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePhoneNumberOrderError::ResourceLimitExceeded,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreatePhoneNumberOrderError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePhoneNumberOrderError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreatePhoneNumberOrderError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePhoneNumberOrderError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreatePhoneNumberOrderError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePhoneNumberOrderError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreatePhoneNumberOrderError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePhoneNumberOrderError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7432,31 +8194,62 @@ impl CreateProxySessionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateProxySessionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProxySessionError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateProxySessionError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProxySessionError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateProxySessionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProxySessionError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateProxySessionError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProxySessionError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateProxySessionError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateProxySessionError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateProxySessionError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProxySessionError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateProxySessionError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateProxySessionError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7504,30 +8297,70 @@ impl CreateRoomError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateRoomError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRoomError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateRoomError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRoomError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateRoomError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRoomError::NotFound,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateRoomError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRoomError::ResourceLimitExceeded,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateRoomError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRoomError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateRoomError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRoomError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateRoomError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRoomError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateRoomError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRoomError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7578,41 +8411,78 @@ impl CreateRoomMembershipError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateRoomMembershipError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRoomMembershipError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateRoomMembershipError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRoomMembershipError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateRoomMembershipError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRoomMembershipError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateRoomMembershipError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRoomMembershipError::NotFound,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateRoomMembershipError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRoomMembershipError::ResourceLimitExceeded,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateRoomMembershipError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRoomMembershipError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateRoomMembershipError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRoomMembershipError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateRoomMembershipError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRoomMembershipError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateRoomMembershipError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRoomMembershipError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7664,47 +8534,78 @@ impl CreateSipMediaApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateSipMediaApplicationError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSipMediaApplicationError::AccessDenied,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateSipMediaApplicationError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSipMediaApplicationError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateSipMediaApplicationError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSipMediaApplicationError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateSipMediaApplicationError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSipMediaApplicationError::Forbidden,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
-                        CreateSipMediaApplicationError::ResourceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSipMediaApplicationError::ResourceLimitExceeded,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateSipMediaApplicationError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSipMediaApplicationError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        CreateSipMediaApplicationError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSipMediaApplicationError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateSipMediaApplicationError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSipMediaApplicationError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        CreateSipMediaApplicationError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSipMediaApplicationError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7756,41 +8657,62 @@ impl CreateSipMediaApplicationCallError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateSipMediaApplicationCallError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSipMediaApplicationCallError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateSipMediaApplicationCallError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSipMediaApplicationCallError::Forbidden,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
-                        CreateSipMediaApplicationCallError::ResourceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSipMediaApplicationCallError::ResourceLimitExceeded,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        CreateSipMediaApplicationCallError::ServiceFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSipMediaApplicationCallError::ServiceFailure,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        CreateSipMediaApplicationCallError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSipMediaApplicationCallError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        CreateSipMediaApplicationCallError::ThrottledClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSipMediaApplicationCallError::ThrottledClient,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        CreateSipMediaApplicationCallError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSipMediaApplicationCallError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7848,33 +8770,78 @@ impl CreateSipRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateSipRuleError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSipRuleError::AccessDenied,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateSipRuleError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSipRuleError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateSipRuleError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSipRuleError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateSipRuleError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSipRuleError::Forbidden,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateSipRuleError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSipRuleError::ResourceLimitExceeded,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateSipRuleError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSipRuleError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateSipRuleError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSipRuleError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateSipRuleError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSipRuleError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateSipRuleError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSipRuleError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7924,30 +8891,70 @@ impl CreateUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateUserError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateUserError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateUserError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateUserError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateUserError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateUserError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateUserError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7996,38 +9003,70 @@ impl CreateVoiceConnectorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateVoiceConnectorError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVoiceConnectorError::AccessDenied,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateVoiceConnectorError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVoiceConnectorError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateVoiceConnectorError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVoiceConnectorError::Forbidden,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateVoiceConnectorError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateVoiceConnectorError::ResourceLimitExceeded,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateVoiceConnectorError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVoiceConnectorError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateVoiceConnectorError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateVoiceConnectorError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateVoiceConnectorError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateVoiceConnectorError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(CreateVoiceConnectorError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateVoiceConnectorError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8076,44 +9115,70 @@ impl CreateVoiceConnectorGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateVoiceConnectorGroupError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateVoiceConnectorGroupError::AccessDenied,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateVoiceConnectorGroupError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateVoiceConnectorGroupError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateVoiceConnectorGroupError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVoiceConnectorGroupError::Forbidden,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
-                        CreateVoiceConnectorGroupError::ResourceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVoiceConnectorGroupError::ResourceLimitExceeded,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(CreateVoiceConnectorGroupError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateVoiceConnectorGroupError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        CreateVoiceConnectorGroupError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVoiceConnectorGroupError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(CreateVoiceConnectorGroupError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateVoiceConnectorGroupError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        CreateVoiceConnectorGroupError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVoiceConnectorGroupError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8164,30 +9229,70 @@ impl DeleteAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteAccountError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAccountError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteAccountError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAccountError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteAccountError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAccountError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteAccountError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAccountError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteAccountError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAccountError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeleteAccountError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAccountError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DeleteAccountError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAccountError::UnauthorizedClient,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(DeleteAccountError::UnprocessableEntity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAccountError::UnprocessableEntity,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8232,28 +9337,54 @@ impl DeleteAppInstanceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteAppInstanceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppInstanceError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteAppInstanceError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppInstanceError::Forbidden,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteAppInstanceError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppInstanceError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteAppInstanceError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteAppInstanceError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeleteAppInstanceError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppInstanceError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DeleteAppInstanceError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteAppInstanceError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8300,40 +9431,70 @@ impl DeleteAppInstanceAdminError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteAppInstanceAdminError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppInstanceAdminError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteAppInstanceAdminError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppInstanceAdminError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteAppInstanceAdminError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppInstanceAdminError::Forbidden,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
-                        DeleteAppInstanceAdminError::ResourceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppInstanceAdminError::ResourceLimitExceeded,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteAppInstanceAdminError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteAppInstanceAdminError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteAppInstanceAdminError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteAppInstanceAdminError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeleteAppInstanceAdminError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteAppInstanceAdminError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DeleteAppInstanceAdminError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteAppInstanceAdminError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8382,41 +9543,62 @@ impl DeleteAppInstanceStreamingConfigurationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        DeleteAppInstanceStreamingConfigurationsError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppInstanceStreamingConfigurationsError::BadRequest,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
-                        DeleteAppInstanceStreamingConfigurationsError::Forbidden(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppInstanceStreamingConfigurationsError::Forbidden,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteAppInstanceStreamingConfigurationsError::NotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppInstanceStreamingConfigurationsError::NotFound,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        DeleteAppInstanceStreamingConfigurationsError::ServiceFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppInstanceStreamingConfigurationsError::ServiceFailure,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DeleteAppInstanceStreamingConfigurationsError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppInstanceStreamingConfigurationsError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        DeleteAppInstanceStreamingConfigurationsError::ThrottledClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppInstanceStreamingConfigurationsError::ThrottledClient,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        DeleteAppInstanceStreamingConfigurationsError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppInstanceStreamingConfigurationsError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8474,32 +9656,54 @@ impl DeleteAppInstanceUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteAppInstanceUserError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppInstanceUserError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteAppInstanceUserError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppInstanceUserError::Forbidden,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteAppInstanceUserError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteAppInstanceUserError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteAppInstanceUserError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteAppInstanceUserError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeleteAppInstanceUserError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteAppInstanceUserError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DeleteAppInstanceUserError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteAppInstanceUserError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8544,27 +9748,62 @@ impl DeleteAttendeeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteAttendeeError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAttendeeError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteAttendeeError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAttendeeError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteAttendeeError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAttendeeError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteAttendeeError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAttendeeError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteAttendeeError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAttendeeError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeleteAttendeeError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAttendeeError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DeleteAttendeeError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAttendeeError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8608,24 +9847,54 @@ impl DeleteChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteChannelError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteChannelError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteChannelError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteChannelError::Forbidden,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteChannelError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteChannelError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteChannelError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteChannelError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeleteChannelError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteChannelError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DeleteChannelError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteChannelError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8668,24 +9937,54 @@ impl DeleteChannelBanError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteChannelBanError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteChannelBanError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteChannelBanError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteChannelBanError::Forbidden,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteChannelBanError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteChannelBanError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteChannelBanError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteChannelBanError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeleteChannelBanError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteChannelBanError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DeleteChannelBanError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteChannelBanError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8728,32 +10027,54 @@ impl DeleteChannelMembershipError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteChannelMembershipError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteChannelMembershipError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteChannelMembershipError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteChannelMembershipError::Forbidden,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteChannelMembershipError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteChannelMembershipError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteChannelMembershipError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteChannelMembershipError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeleteChannelMembershipError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteChannelMembershipError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DeleteChannelMembershipError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteChannelMembershipError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8796,30 +10117,54 @@ impl DeleteChannelMessageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteChannelMessageError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteChannelMessageError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteChannelMessageError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteChannelMessageError::Forbidden,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteChannelMessageError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteChannelMessageError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteChannelMessageError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteChannelMessageError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeleteChannelMessageError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteChannelMessageError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DeleteChannelMessageError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteChannelMessageError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8862,32 +10207,54 @@ impl DeleteChannelModeratorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteChannelModeratorError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteChannelModeratorError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteChannelModeratorError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteChannelModeratorError::Forbidden,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteChannelModeratorError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteChannelModeratorError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteChannelModeratorError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteChannelModeratorError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeleteChannelModeratorError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteChannelModeratorError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DeleteChannelModeratorError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteChannelModeratorError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8930,34 +10297,54 @@ impl DeleteEventsConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteEventsConfigurationError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteEventsConfigurationError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteEventsConfigurationError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEventsConfigurationError::Forbidden,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
-                        DeleteEventsConfigurationError::ResourceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEventsConfigurationError::ResourceLimitExceeded,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteEventsConfigurationError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteEventsConfigurationError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DeleteEventsConfigurationError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEventsConfigurationError::ServiceUnavailable,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        DeleteEventsConfigurationError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEventsConfigurationError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9004,27 +10391,62 @@ impl DeleteMeetingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteMeetingError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMeetingError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteMeetingError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMeetingError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteMeetingError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMeetingError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteMeetingError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMeetingError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteMeetingError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMeetingError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeleteMeetingError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMeetingError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DeleteMeetingError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMeetingError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9070,31 +10492,62 @@ impl DeletePhoneNumberError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeletePhoneNumberError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePhoneNumberError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeletePhoneNumberError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePhoneNumberError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeletePhoneNumberError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePhoneNumberError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeletePhoneNumberError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePhoneNumberError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeletePhoneNumberError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeletePhoneNumberError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeletePhoneNumberError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePhoneNumberError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DeletePhoneNumberError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeletePhoneNumberError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9140,31 +10593,62 @@ impl DeleteProxySessionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteProxySessionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProxySessionError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteProxySessionError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProxySessionError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteProxySessionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProxySessionError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteProxySessionError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProxySessionError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteProxySessionError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteProxySessionError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeleteProxySessionError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProxySessionError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DeleteProxySessionError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteProxySessionError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9210,27 +10694,62 @@ impl DeleteRoomError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteRoomError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRoomError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteRoomError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRoomError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRoomError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRoomError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteRoomError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRoomError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteRoomError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRoomError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeleteRoomError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRoomError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DeleteRoomError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRoomError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9276,33 +10795,62 @@ impl DeleteRoomMembershipError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteRoomMembershipError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRoomMembershipError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteRoomMembershipError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRoomMembershipError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRoomMembershipError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRoomMembershipError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteRoomMembershipError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRoomMembershipError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteRoomMembershipError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRoomMembershipError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeleteRoomMembershipError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRoomMembershipError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DeleteRoomMembershipError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRoomMembershipError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9350,40 +10898,70 @@ impl DeleteSipMediaApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteSipMediaApplicationError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSipMediaApplicationError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteSipMediaApplicationError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSipMediaApplicationError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteSipMediaApplicationError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSipMediaApplicationError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteSipMediaApplicationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSipMediaApplicationError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteSipMediaApplicationError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSipMediaApplicationError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DeleteSipMediaApplicationError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSipMediaApplicationError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeleteSipMediaApplicationError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSipMediaApplicationError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        DeleteSipMediaApplicationError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSipMediaApplicationError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9432,30 +11010,70 @@ impl DeleteSipRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteSipRuleError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSipRuleError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteSipRuleError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSipRuleError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteSipRuleError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSipRuleError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteSipRuleError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSipRuleError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteSipRuleError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSipRuleError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteSipRuleError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSipRuleError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeleteSipRuleError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSipRuleError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DeleteSipRuleError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSipRuleError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9504,36 +11122,70 @@ impl DeleteVoiceConnectorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteVoiceConnectorError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteVoiceConnectorError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteVoiceConnectorError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9582,49 +11234,62 @@ impl DeleteVoiceConnectorEmergencyCallingConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorEmergencyCallingConfigurationError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorEmergencyCallingConfigurationError::BadRequest,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorEmergencyCallingConfigurationError::Forbidden(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorEmergencyCallingConfigurationError::Forbidden,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorEmergencyCallingConfigurationError::NotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorEmergencyCallingConfigurationError::NotFound,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorEmergencyCallingConfigurationError::ServiceFailure(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorEmergencyCallingConfigurationError::ServiceFailure,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorEmergencyCallingConfigurationError::ServiceUnavailable(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorEmergencyCallingConfigurationError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorEmergencyCallingConfigurationError::ThrottledClient(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorEmergencyCallingConfigurationError::ThrottledClient,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorEmergencyCallingConfigurationError::UnauthorizedClient(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorEmergencyCallingConfigurationError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9686,40 +11351,70 @@ impl DeleteVoiceConnectorGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorGroupError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteVoiceConnectorGroupError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorGroupError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorGroupError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorGroupError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorGroupError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorGroupError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorGroupError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorGroupError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteVoiceConnectorGroupError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorGroupError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorGroupError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorGroupError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteVoiceConnectorGroupError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorGroupError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorGroupError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9768,41 +11463,62 @@ impl DeleteVoiceConnectorOriginationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorOriginationError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteVoiceConnectorOriginationError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorOriginationError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteVoiceConnectorOriginationError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorOriginationError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteVoiceConnectorOriginationError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorOriginationError::ServiceFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorOriginationError::ServiceFailure,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorOriginationError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorOriginationError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorOriginationError::ThrottledClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorOriginationError::ThrottledClient,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorOriginationError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorOriginationError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9856,37 +11572,62 @@ impl DeleteVoiceConnectorProxyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorProxyError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteVoiceConnectorProxyError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorProxyError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorProxyError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorProxyError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorProxyError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorProxyError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteVoiceConnectorProxyError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorProxyError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorProxyError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorProxyError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteVoiceConnectorProxyError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorProxyError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorProxyError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9934,45 +11675,62 @@ impl DeleteVoiceConnectorStreamingConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorStreamingConfigurationError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorStreamingConfigurationError::BadRequest,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorStreamingConfigurationError::Forbidden(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorStreamingConfigurationError::Forbidden,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorStreamingConfigurationError::NotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorStreamingConfigurationError::NotFound,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorStreamingConfigurationError::ServiceFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorStreamingConfigurationError::ServiceFailure,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorStreamingConfigurationError::ServiceUnavailable(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorStreamingConfigurationError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorStreamingConfigurationError::ThrottledClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorStreamingConfigurationError::ThrottledClient,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorStreamingConfigurationError::UnauthorizedClient(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorStreamingConfigurationError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10034,41 +11792,62 @@ impl DeleteVoiceConnectorTerminationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorTerminationError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteVoiceConnectorTerminationError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorTerminationError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteVoiceConnectorTerminationError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVoiceConnectorTerminationError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteVoiceConnectorTerminationError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorTerminationError::ServiceFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorTerminationError::ServiceFailure,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorTerminationError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorTerminationError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorTerminationError::ThrottledClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorTerminationError::ThrottledClient,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorTerminationError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorTerminationError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10124,45 +11903,62 @@ impl DeleteVoiceConnectorTerminationCredentialsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorTerminationCredentialsError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorTerminationCredentialsError::BadRequest,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorTerminationCredentialsError::Forbidden(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorTerminationCredentialsError::Forbidden,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorTerminationCredentialsError::NotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorTerminationCredentialsError::NotFound,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorTerminationCredentialsError::ServiceFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorTerminationCredentialsError::ServiceFailure,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorTerminationCredentialsError::ServiceUnavailable(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorTerminationCredentialsError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorTerminationCredentialsError::ThrottledClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorTerminationCredentialsError::ThrottledClient,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        DeleteVoiceConnectorTerminationCredentialsError::UnauthorizedClient(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVoiceConnectorTerminationCredentialsError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10220,28 +12016,54 @@ impl DescribeAppInstanceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeAppInstanceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAppInstanceError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeAppInstanceError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAppInstanceError::Forbidden,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DescribeAppInstanceError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAppInstanceError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeAppInstanceError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAppInstanceError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DescribeAppInstanceError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAppInstanceError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DescribeAppInstanceError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAppInstanceError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10284,32 +12106,54 @@ impl DescribeAppInstanceAdminError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeAppInstanceAdminError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAppInstanceAdminError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeAppInstanceAdminError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAppInstanceAdminError::Forbidden,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DescribeAppInstanceAdminError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAppInstanceAdminError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeAppInstanceAdminError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAppInstanceAdminError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DescribeAppInstanceAdminError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAppInstanceAdminError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DescribeAppInstanceAdminError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAppInstanceAdminError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10352,32 +12196,54 @@ impl DescribeAppInstanceUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeAppInstanceUserError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAppInstanceUserError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeAppInstanceUserError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAppInstanceUserError::Forbidden,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DescribeAppInstanceUserError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAppInstanceUserError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeAppInstanceUserError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAppInstanceUserError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DescribeAppInstanceUserError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAppInstanceUserError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DescribeAppInstanceUserError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAppInstanceUserError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10420,24 +12286,54 @@ impl DescribeChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeChannelError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChannelError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeChannelError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChannelError::Forbidden,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DescribeChannelError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChannelError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeChannelError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChannelError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DescribeChannelError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChannelError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DescribeChannelError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChannelError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10482,31 +12378,62 @@ impl DescribeChannelBanError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeChannelBanError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChannelBanError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeChannelBanError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChannelBanError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeChannelBanError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChannelBanError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DescribeChannelBanError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChannelBanError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeChannelBanError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeChannelBanError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DescribeChannelBanError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChannelBanError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DescribeChannelBanError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeChannelBanError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10552,37 +12479,62 @@ impl DescribeChannelMembershipError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeChannelMembershipError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeChannelMembershipError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeChannelMembershipError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChannelMembershipError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeChannelMembershipError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChannelMembershipError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DescribeChannelMembershipError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeChannelMembershipError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DescribeChannelMembershipError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChannelMembershipError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DescribeChannelMembershipError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeChannelMembershipError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        DescribeChannelMembershipError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChannelMembershipError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10628,40 +12580,54 @@ impl DescribeChannelMembershipForAppInstanceUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        DescribeChannelMembershipForAppInstanceUserError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChannelMembershipForAppInstanceUserError::BadRequest,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
-                        DescribeChannelMembershipForAppInstanceUserError::Forbidden(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChannelMembershipForAppInstanceUserError::Forbidden,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        DescribeChannelMembershipForAppInstanceUserError::ServiceFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChannelMembershipForAppInstanceUserError::ServiceFailure,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DescribeChannelMembershipForAppInstanceUserError::ServiceUnavailable(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChannelMembershipForAppInstanceUserError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        DescribeChannelMembershipForAppInstanceUserError::ThrottledClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChannelMembershipForAppInstanceUserError::ThrottledClient,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        DescribeChannelMembershipForAppInstanceUserError::UnauthorizedClient(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChannelMembershipForAppInstanceUserError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10718,36 +12684,54 @@ impl DescribeChannelModeratedByAppInstanceUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        DescribeChannelModeratedByAppInstanceUserError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChannelModeratedByAppInstanceUserError::BadRequest,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
-                        DescribeChannelModeratedByAppInstanceUserError::Forbidden(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChannelModeratedByAppInstanceUserError::Forbidden,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        DescribeChannelModeratedByAppInstanceUserError::ServiceFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChannelModeratedByAppInstanceUserError::ServiceFailure,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DescribeChannelModeratedByAppInstanceUserError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChannelModeratedByAppInstanceUserError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        DescribeChannelModeratedByAppInstanceUserError::ThrottledClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChannelModeratedByAppInstanceUserError::ThrottledClient,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        DescribeChannelModeratedByAppInstanceUserError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChannelModeratedByAppInstanceUserError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10804,35 +12788,62 @@ impl DescribeChannelModeratorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeChannelModeratorError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChannelModeratorError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeChannelModeratorError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChannelModeratorError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeChannelModeratorError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChannelModeratorError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DescribeChannelModeratorError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeChannelModeratorError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeChannelModeratorError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeChannelModeratorError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(DescribeChannelModeratorError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeChannelModeratorError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(DescribeChannelModeratorError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeChannelModeratorError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10880,41 +12891,62 @@ impl DisassociatePhoneNumberFromUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DisassociatePhoneNumberFromUserError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociatePhoneNumberFromUserError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DisassociatePhoneNumberFromUserError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociatePhoneNumberFromUserError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DisassociatePhoneNumberFromUserError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociatePhoneNumberFromUserError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        DisassociatePhoneNumberFromUserError::ServiceFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociatePhoneNumberFromUserError::ServiceFailure,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DisassociatePhoneNumberFromUserError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociatePhoneNumberFromUserError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        DisassociatePhoneNumberFromUserError::ThrottledClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociatePhoneNumberFromUserError::ThrottledClient,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        DisassociatePhoneNumberFromUserError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociatePhoneNumberFromUserError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10970,45 +13002,62 @@ impl DisassociatePhoneNumbersFromVoiceConnectorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        DisassociatePhoneNumbersFromVoiceConnectorError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociatePhoneNumbersFromVoiceConnectorError::BadRequest,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
-                        DisassociatePhoneNumbersFromVoiceConnectorError::Forbidden(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociatePhoneNumbersFromVoiceConnectorError::Forbidden,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
-                        DisassociatePhoneNumbersFromVoiceConnectorError::NotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociatePhoneNumbersFromVoiceConnectorError::NotFound,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        DisassociatePhoneNumbersFromVoiceConnectorError::ServiceFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociatePhoneNumbersFromVoiceConnectorError::ServiceFailure,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DisassociatePhoneNumbersFromVoiceConnectorError::ServiceUnavailable(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociatePhoneNumbersFromVoiceConnectorError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        DisassociatePhoneNumbersFromVoiceConnectorError::ThrottledClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociatePhoneNumbersFromVoiceConnectorError::ThrottledClient,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        DisassociatePhoneNumbersFromVoiceConnectorError::UnauthorizedClient(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociatePhoneNumbersFromVoiceConnectorError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11070,49 +13119,62 @@ impl DisassociatePhoneNumbersFromVoiceConnectorGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        DisassociatePhoneNumbersFromVoiceConnectorGroupError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociatePhoneNumbersFromVoiceConnectorGroupError::BadRequest,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
-                        DisassociatePhoneNumbersFromVoiceConnectorGroupError::Forbidden(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociatePhoneNumbersFromVoiceConnectorGroupError::Forbidden,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
-                        DisassociatePhoneNumbersFromVoiceConnectorGroupError::NotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociatePhoneNumbersFromVoiceConnectorGroupError::NotFound,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        DisassociatePhoneNumbersFromVoiceConnectorGroupError::ServiceFailure(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociatePhoneNumbersFromVoiceConnectorGroupError::ServiceFailure,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DisassociatePhoneNumbersFromVoiceConnectorGroupError::ServiceUnavailable(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociatePhoneNumbersFromVoiceConnectorGroupError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        DisassociatePhoneNumbersFromVoiceConnectorGroupError::ThrottledClient(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociatePhoneNumbersFromVoiceConnectorGroupError::ThrottledClient,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        DisassociatePhoneNumbersFromVoiceConnectorGroupError::UnauthorizedClient(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociatePhoneNumbersFromVoiceConnectorGroupError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11174,45 +13236,62 @@ impl DisassociateSigninDelegateGroupsFromAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        DisassociateSigninDelegateGroupsFromAccountError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateSigninDelegateGroupsFromAccountError::BadRequest,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
-                        DisassociateSigninDelegateGroupsFromAccountError::Forbidden(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateSigninDelegateGroupsFromAccountError::Forbidden,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
-                        DisassociateSigninDelegateGroupsFromAccountError::NotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateSigninDelegateGroupsFromAccountError::NotFound,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        DisassociateSigninDelegateGroupsFromAccountError::ServiceFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateSigninDelegateGroupsFromAccountError::ServiceFailure,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DisassociateSigninDelegateGroupsFromAccountError::ServiceUnavailable(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateSigninDelegateGroupsFromAccountError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        DisassociateSigninDelegateGroupsFromAccountError::ThrottledClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateSigninDelegateGroupsFromAccountError::ThrottledClient,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        DisassociateSigninDelegateGroupsFromAccountError::UnauthorizedClient(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateSigninDelegateGroupsFromAccountError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11272,27 +13351,62 @@ impl GetAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetAccountError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAccountError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetAccountError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAccountError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetAccountError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAccountError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetAccountError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAccountError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetAccountError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAccountError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetAccountError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAccountError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetAccountError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAccountError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11338,31 +13452,62 @@ impl GetAccountSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetAccountSettingsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAccountSettingsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetAccountSettingsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAccountSettingsError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetAccountSettingsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAccountSettingsError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetAccountSettingsError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAccountSettingsError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetAccountSettingsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetAccountSettingsError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetAccountSettingsError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAccountSettingsError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetAccountSettingsError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetAccountSettingsError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11410,41 +13555,62 @@ impl GetAppInstanceRetentionSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetAppInstanceRetentionSettingsError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetAppInstanceRetentionSettingsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetAppInstanceRetentionSettingsError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetAppInstanceRetentionSettingsError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetAppInstanceRetentionSettingsError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetAppInstanceRetentionSettingsError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        GetAppInstanceRetentionSettingsError::ServiceFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAppInstanceRetentionSettingsError::ServiceFailure,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        GetAppInstanceRetentionSettingsError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAppInstanceRetentionSettingsError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        GetAppInstanceRetentionSettingsError::ThrottledClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAppInstanceRetentionSettingsError::ThrottledClient,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        GetAppInstanceRetentionSettingsError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAppInstanceRetentionSettingsError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11500,41 +13666,62 @@ impl GetAppInstanceStreamingConfigurationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        GetAppInstanceStreamingConfigurationsError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAppInstanceStreamingConfigurationsError::BadRequest,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
-                        GetAppInstanceStreamingConfigurationsError::Forbidden(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAppInstanceStreamingConfigurationsError::Forbidden,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
-                        GetAppInstanceStreamingConfigurationsError::NotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAppInstanceStreamingConfigurationsError::NotFound,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        GetAppInstanceStreamingConfigurationsError::ServiceFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAppInstanceStreamingConfigurationsError::ServiceFailure,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        GetAppInstanceStreamingConfigurationsError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAppInstanceStreamingConfigurationsError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        GetAppInstanceStreamingConfigurationsError::ThrottledClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAppInstanceStreamingConfigurationsError::ThrottledClient,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        GetAppInstanceStreamingConfigurationsError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAppInstanceStreamingConfigurationsError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11594,27 +13781,62 @@ impl GetAttendeeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetAttendeeError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAttendeeError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetAttendeeError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAttendeeError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetAttendeeError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAttendeeError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetAttendeeError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAttendeeError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetAttendeeError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAttendeeError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetAttendeeError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAttendeeError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetAttendeeError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAttendeeError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11660,25 +13882,62 @@ impl GetBotError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBotError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBotError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetBotError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBotError::Forbidden,
+                    )
+                }
+                "NotFoundException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBotError::NotFound,
+                    )
                 }
-                "NotFoundException" => return RusotoError::Service(GetBotError::NotFound(err.msg)),
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetBotError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBotError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetBotError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBotError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetBotError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBotError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetBotError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBotError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11724,31 +13983,62 @@ impl GetChannelMessageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetChannelMessageError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetChannelMessageError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetChannelMessageError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetChannelMessageError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetChannelMessageError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetChannelMessageError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetChannelMessageError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetChannelMessageError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetChannelMessageError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetChannelMessageError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetChannelMessageError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetChannelMessageError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetChannelMessageError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetChannelMessageError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11794,35 +14084,62 @@ impl GetEventsConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetEventsConfigurationError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEventsConfigurationError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetEventsConfigurationError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEventsConfigurationError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetEventsConfigurationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEventsConfigurationError::NotFound,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
-                        GetEventsConfigurationError::ResourceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEventsConfigurationError::ResourceLimitExceeded,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetEventsConfigurationError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetEventsConfigurationError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetEventsConfigurationError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetEventsConfigurationError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetEventsConfigurationError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetEventsConfigurationError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11866,28 +14183,54 @@ impl GetGlobalSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetGlobalSettingsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGlobalSettingsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetGlobalSettingsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGlobalSettingsError::Forbidden,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetGlobalSettingsError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGlobalSettingsError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetGlobalSettingsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetGlobalSettingsError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetGlobalSettingsError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGlobalSettingsError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetGlobalSettingsError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetGlobalSettingsError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11932,27 +14275,62 @@ impl GetMeetingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetMeetingError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMeetingError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetMeetingError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMeetingError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetMeetingError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMeetingError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetMeetingError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMeetingError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetMeetingError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMeetingError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetMeetingError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMeetingError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetMeetingError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMeetingError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11996,31 +14374,46 @@ impl GetMessagingSessionEndpointError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetMessagingSessionEndpointError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMessagingSessionEndpointError::Forbidden,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetMessagingSessionEndpointError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMessagingSessionEndpointError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        GetMessagingSessionEndpointError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMessagingSessionEndpointError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetMessagingSessionEndpointError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMessagingSessionEndpointError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        GetMessagingSessionEndpointError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMessagingSessionEndpointError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12068,27 +14461,62 @@ impl GetPhoneNumberError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetPhoneNumberError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPhoneNumberError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetPhoneNumberError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPhoneNumberError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetPhoneNumberError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPhoneNumberError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetPhoneNumberError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPhoneNumberError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetPhoneNumberError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPhoneNumberError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetPhoneNumberError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPhoneNumberError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetPhoneNumberError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPhoneNumberError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12134,31 +14562,62 @@ impl GetPhoneNumberOrderError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetPhoneNumberOrderError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPhoneNumberOrderError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetPhoneNumberOrderError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPhoneNumberOrderError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetPhoneNumberOrderError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPhoneNumberOrderError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetPhoneNumberOrderError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPhoneNumberOrderError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetPhoneNumberOrderError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetPhoneNumberOrderError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetPhoneNumberOrderError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPhoneNumberOrderError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetPhoneNumberOrderError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetPhoneNumberOrderError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12202,32 +14661,54 @@ impl GetPhoneNumberSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetPhoneNumberSettingsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPhoneNumberSettingsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetPhoneNumberSettingsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPhoneNumberSettingsError::Forbidden,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetPhoneNumberSettingsError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetPhoneNumberSettingsError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetPhoneNumberSettingsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetPhoneNumberSettingsError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetPhoneNumberSettingsError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetPhoneNumberSettingsError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetPhoneNumberSettingsError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetPhoneNumberSettingsError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12272,27 +14753,62 @@ impl GetProxySessionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetProxySessionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetProxySessionError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetProxySessionError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetProxySessionError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetProxySessionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetProxySessionError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetProxySessionError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetProxySessionError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetProxySessionError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetProxySessionError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetProxySessionError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetProxySessionError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetProxySessionError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetProxySessionError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12338,33 +14854,62 @@ impl GetRetentionSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetRetentionSettingsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRetentionSettingsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetRetentionSettingsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRetentionSettingsError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRetentionSettingsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRetentionSettingsError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetRetentionSettingsError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRetentionSettingsError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetRetentionSettingsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRetentionSettingsError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetRetentionSettingsError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRetentionSettingsError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetRetentionSettingsError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRetentionSettingsError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12410,27 +14955,62 @@ impl GetRoomError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetRoomError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRoomError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetRoomError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRoomError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRoomError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRoomError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetRoomError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRoomError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetRoomError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRoomError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetRoomError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRoomError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetRoomError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRoomError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12476,35 +15056,62 @@ impl GetSipMediaApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetSipMediaApplicationError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSipMediaApplicationError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetSipMediaApplicationError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSipMediaApplicationError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetSipMediaApplicationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSipMediaApplicationError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetSipMediaApplicationError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSipMediaApplicationError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetSipMediaApplicationError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSipMediaApplicationError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetSipMediaApplicationError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSipMediaApplicationError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetSipMediaApplicationError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSipMediaApplicationError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12552,45 +15159,62 @@ impl GetSipMediaApplicationLoggingConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        GetSipMediaApplicationLoggingConfigurationError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSipMediaApplicationLoggingConfigurationError::BadRequest,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
-                        GetSipMediaApplicationLoggingConfigurationError::Forbidden(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSipMediaApplicationLoggingConfigurationError::Forbidden,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
-                        GetSipMediaApplicationLoggingConfigurationError::NotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSipMediaApplicationLoggingConfigurationError::NotFound,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        GetSipMediaApplicationLoggingConfigurationError::ServiceFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSipMediaApplicationLoggingConfigurationError::ServiceFailure,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        GetSipMediaApplicationLoggingConfigurationError::ServiceUnavailable(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSipMediaApplicationLoggingConfigurationError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        GetSipMediaApplicationLoggingConfigurationError::ThrottledClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSipMediaApplicationLoggingConfigurationError::ThrottledClient,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        GetSipMediaApplicationLoggingConfigurationError::UnauthorizedClient(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSipMediaApplicationLoggingConfigurationError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12650,27 +15274,62 @@ impl GetSipRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetSipRuleError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSipRuleError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetSipRuleError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSipRuleError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetSipRuleError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSipRuleError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetSipRuleError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSipRuleError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetSipRuleError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSipRuleError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetSipRuleError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSipRuleError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetSipRuleError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSipRuleError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12716,27 +15375,62 @@ impl GetUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetUserError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetUserError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetUserError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetUserError::ServiceFailure(err.msg))
-                }
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserError::ServiceFailure,
+                    )
+                }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetUserError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetUserError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12782,27 +15476,62 @@ impl GetUserSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetUserSettingsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserSettingsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetUserSettingsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserSettingsError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetUserSettingsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserSettingsError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetUserSettingsError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserSettingsError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetUserSettingsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserSettingsError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetUserSettingsError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserSettingsError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetUserSettingsError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserSettingsError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12848,31 +15577,62 @@ impl GetVoiceConnectorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetVoiceConnectorError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetVoiceConnectorError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetVoiceConnectorError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetVoiceConnectorError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetVoiceConnectorError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetVoiceConnectorError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetVoiceConnectorError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetVoiceConnectorError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetVoiceConnectorError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12920,49 +15680,62 @@ impl GetVoiceConnectorEmergencyCallingConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorEmergencyCallingConfigurationError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorEmergencyCallingConfigurationError::BadRequest,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorEmergencyCallingConfigurationError::Forbidden(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorEmergencyCallingConfigurationError::Forbidden,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorEmergencyCallingConfigurationError::NotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorEmergencyCallingConfigurationError::NotFound,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorEmergencyCallingConfigurationError::ServiceFailure(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorEmergencyCallingConfigurationError::ServiceFailure,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorEmergencyCallingConfigurationError::ServiceUnavailable(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorEmergencyCallingConfigurationError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorEmergencyCallingConfigurationError::ThrottledClient(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorEmergencyCallingConfigurationError::ThrottledClient,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorEmergencyCallingConfigurationError::UnauthorizedClient(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorEmergencyCallingConfigurationError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13022,35 +15795,62 @@ impl GetVoiceConnectorGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetVoiceConnectorGroupError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorGroupError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetVoiceConnectorGroupError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorGroupError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetVoiceConnectorGroupError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorGroupError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetVoiceConnectorGroupError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetVoiceConnectorGroupError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetVoiceConnectorGroupError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetVoiceConnectorGroupError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetVoiceConnectorGroupError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetVoiceConnectorGroupError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetVoiceConnectorGroupError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetVoiceConnectorGroupError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13098,41 +15898,62 @@ impl GetVoiceConnectorLoggingConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorLoggingConfigurationError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorLoggingConfigurationError::BadRequest,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorLoggingConfigurationError::Forbidden(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorLoggingConfigurationError::Forbidden,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorLoggingConfigurationError::NotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorLoggingConfigurationError::NotFound,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorLoggingConfigurationError::ServiceFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorLoggingConfigurationError::ServiceFailure,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorLoggingConfigurationError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorLoggingConfigurationError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorLoggingConfigurationError::ThrottledClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorLoggingConfigurationError::ThrottledClient,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorLoggingConfigurationError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorLoggingConfigurationError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13194,41 +16015,62 @@ impl GetVoiceConnectorOriginationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetVoiceConnectorOriginationError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetVoiceConnectorOriginationError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetVoiceConnectorOriginationError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetVoiceConnectorOriginationError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetVoiceConnectorOriginationError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetVoiceConnectorOriginationError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetVoiceConnectorOriginationError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetVoiceConnectorOriginationError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorOriginationError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorOriginationError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorOriginationError::ThrottledClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorOriginationError::ThrottledClient,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorOriginationError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorOriginationError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13278,35 +16120,62 @@ impl GetVoiceConnectorProxyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetVoiceConnectorProxyError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorProxyError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetVoiceConnectorProxyError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorProxyError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetVoiceConnectorProxyError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorProxyError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetVoiceConnectorProxyError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetVoiceConnectorProxyError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetVoiceConnectorProxyError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetVoiceConnectorProxyError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(GetVoiceConnectorProxyError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetVoiceConnectorProxyError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(GetVoiceConnectorProxyError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetVoiceConnectorProxyError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13354,41 +16223,62 @@ impl GetVoiceConnectorStreamingConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorStreamingConfigurationError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorStreamingConfigurationError::BadRequest,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorStreamingConfigurationError::Forbidden(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorStreamingConfigurationError::Forbidden,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorStreamingConfigurationError::NotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorStreamingConfigurationError::NotFound,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorStreamingConfigurationError::ServiceFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorStreamingConfigurationError::ServiceFailure,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorStreamingConfigurationError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorStreamingConfigurationError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorStreamingConfigurationError::ThrottledClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorStreamingConfigurationError::ThrottledClient,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorStreamingConfigurationError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorStreamingConfigurationError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13450,41 +16340,62 @@ impl GetVoiceConnectorTerminationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetVoiceConnectorTerminationError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetVoiceConnectorTerminationError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetVoiceConnectorTerminationError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetVoiceConnectorTerminationError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetVoiceConnectorTerminationError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetVoiceConnectorTerminationError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetVoiceConnectorTerminationError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetVoiceConnectorTerminationError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorTerminationError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorTerminationError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorTerminationError::ThrottledClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorTerminationError::ThrottledClient,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorTerminationError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorTerminationError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13536,41 +16447,62 @@ impl GetVoiceConnectorTerminationHealthError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorTerminationHealthError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorTerminationHealthError::BadRequest,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorTerminationHealthError::Forbidden(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorTerminationHealthError::Forbidden,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetVoiceConnectorTerminationHealthError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetVoiceConnectorTerminationHealthError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorTerminationHealthError::ServiceFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorTerminationHealthError::ServiceFailure,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorTerminationHealthError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorTerminationHealthError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorTerminationHealthError::ThrottledClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorTerminationHealthError::ThrottledClient,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        GetVoiceConnectorTerminationHealthError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVoiceConnectorTerminationHealthError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13626,27 +16558,62 @@ impl InviteUsersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(InviteUsersError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InviteUsersError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(InviteUsersError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InviteUsersError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(InviteUsersError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InviteUsersError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(InviteUsersError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InviteUsersError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(InviteUsersError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InviteUsersError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(InviteUsersError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InviteUsersError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(InviteUsersError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InviteUsersError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13692,27 +16659,62 @@ impl ListAccountsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListAccountsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAccountsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListAccountsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAccountsError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListAccountsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAccountsError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListAccountsError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAccountsError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListAccountsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAccountsError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListAccountsError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAccountsError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListAccountsError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAccountsError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13758,37 +16760,62 @@ impl ListAppInstanceAdminsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListAppInstanceAdminsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAppInstanceAdminsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListAppInstanceAdminsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAppInstanceAdminsError::Forbidden,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(ListAppInstanceAdminsError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAppInstanceAdminsError::ResourceLimitExceeded,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListAppInstanceAdminsError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAppInstanceAdminsError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListAppInstanceAdminsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAppInstanceAdminsError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListAppInstanceAdminsError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAppInstanceAdminsError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListAppInstanceAdminsError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAppInstanceAdminsError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13832,30 +16859,54 @@ impl ListAppInstanceUsersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListAppInstanceUsersError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAppInstanceUsersError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListAppInstanceUsersError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAppInstanceUsersError::Forbidden,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListAppInstanceUsersError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAppInstanceUsersError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListAppInstanceUsersError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAppInstanceUsersError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListAppInstanceUsersError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAppInstanceUsersError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListAppInstanceUsersError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAppInstanceUsersError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13898,24 +16949,54 @@ impl ListAppInstancesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListAppInstancesError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAppInstancesError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListAppInstancesError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAppInstancesError::Forbidden,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListAppInstancesError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAppInstancesError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListAppInstancesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAppInstancesError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListAppInstancesError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAppInstancesError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListAppInstancesError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAppInstancesError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13960,27 +17041,62 @@ impl ListAttendeeTagsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListAttendeeTagsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAttendeeTagsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListAttendeeTagsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAttendeeTagsError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListAttendeeTagsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAttendeeTagsError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListAttendeeTagsError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAttendeeTagsError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListAttendeeTagsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAttendeeTagsError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListAttendeeTagsError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAttendeeTagsError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListAttendeeTagsError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAttendeeTagsError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14026,27 +17142,62 @@ impl ListAttendeesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListAttendeesError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAttendeesError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListAttendeesError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAttendeesError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListAttendeesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAttendeesError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListAttendeesError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAttendeesError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListAttendeesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAttendeesError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListAttendeesError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAttendeesError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListAttendeesError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAttendeesError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14092,27 +17243,62 @@ impl ListBotsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListBotsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBotsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListBotsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBotsError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListBotsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBotsError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListBotsError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBotsError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListBotsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBotsError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListBotsError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBotsError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListBotsError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBotsError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14156,24 +17342,54 @@ impl ListChannelBansError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListChannelBansError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChannelBansError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListChannelBansError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChannelBansError::Forbidden,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListChannelBansError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChannelBansError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListChannelBansError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChannelBansError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListChannelBansError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChannelBansError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListChannelBansError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChannelBansError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14216,32 +17432,54 @@ impl ListChannelMembershipsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListChannelMembershipsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChannelMembershipsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListChannelMembershipsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChannelMembershipsError::Forbidden,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListChannelMembershipsError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListChannelMembershipsError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListChannelMembershipsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListChannelMembershipsError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListChannelMembershipsError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListChannelMembershipsError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListChannelMembershipsError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListChannelMembershipsError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14286,36 +17524,54 @@ impl ListChannelMembershipsForAppInstanceUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        ListChannelMembershipsForAppInstanceUserError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChannelMembershipsForAppInstanceUserError::BadRequest,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
-                        ListChannelMembershipsForAppInstanceUserError::Forbidden(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChannelMembershipsForAppInstanceUserError::Forbidden,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        ListChannelMembershipsForAppInstanceUserError::ServiceFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChannelMembershipsForAppInstanceUserError::ServiceFailure,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        ListChannelMembershipsForAppInstanceUserError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChannelMembershipsForAppInstanceUserError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        ListChannelMembershipsForAppInstanceUserError::ThrottledClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChannelMembershipsForAppInstanceUserError::ThrottledClient,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        ListChannelMembershipsForAppInstanceUserError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChannelMembershipsForAppInstanceUserError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14370,28 +17626,54 @@ impl ListChannelMessagesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListChannelMessagesError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChannelMessagesError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListChannelMessagesError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChannelMessagesError::Forbidden,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListChannelMessagesError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChannelMessagesError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListChannelMessagesError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListChannelMessagesError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListChannelMessagesError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChannelMessagesError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListChannelMessagesError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListChannelMessagesError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14434,32 +17716,54 @@ impl ListChannelModeratorsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListChannelModeratorsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChannelModeratorsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListChannelModeratorsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChannelModeratorsError::Forbidden,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListChannelModeratorsError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListChannelModeratorsError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListChannelModeratorsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListChannelModeratorsError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListChannelModeratorsError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListChannelModeratorsError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListChannelModeratorsError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListChannelModeratorsError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14502,24 +17806,54 @@ impl ListChannelsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListChannelsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChannelsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListChannelsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChannelsError::Forbidden,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListChannelsError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChannelsError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListChannelsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChannelsError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListChannelsError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChannelsError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListChannelsError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChannelsError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14564,36 +17898,54 @@ impl ListChannelsModeratedByAppInstanceUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        ListChannelsModeratedByAppInstanceUserError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChannelsModeratedByAppInstanceUserError::BadRequest,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
-                        ListChannelsModeratedByAppInstanceUserError::Forbidden(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChannelsModeratedByAppInstanceUserError::Forbidden,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        ListChannelsModeratedByAppInstanceUserError::ServiceFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChannelsModeratedByAppInstanceUserError::ServiceFailure,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        ListChannelsModeratedByAppInstanceUserError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChannelsModeratedByAppInstanceUserError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        ListChannelsModeratedByAppInstanceUserError::ThrottledClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChannelsModeratedByAppInstanceUserError::ThrottledClient,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        ListChannelsModeratedByAppInstanceUserError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChannelsModeratedByAppInstanceUserError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14650,27 +18002,62 @@ impl ListMeetingTagsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListMeetingTagsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMeetingTagsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListMeetingTagsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMeetingTagsError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListMeetingTagsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMeetingTagsError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListMeetingTagsError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMeetingTagsError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListMeetingTagsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMeetingTagsError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListMeetingTagsError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMeetingTagsError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListMeetingTagsError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMeetingTagsError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14714,24 +18101,54 @@ impl ListMeetingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListMeetingsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMeetingsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListMeetingsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMeetingsError::Forbidden,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListMeetingsError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMeetingsError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListMeetingsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMeetingsError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListMeetingsError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMeetingsError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListMeetingsError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMeetingsError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14774,32 +18191,54 @@ impl ListPhoneNumberOrdersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListPhoneNumberOrdersError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPhoneNumberOrdersError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListPhoneNumberOrdersError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPhoneNumberOrdersError::Forbidden,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListPhoneNumberOrdersError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPhoneNumberOrdersError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListPhoneNumberOrdersError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPhoneNumberOrdersError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListPhoneNumberOrdersError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPhoneNumberOrdersError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListPhoneNumberOrdersError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPhoneNumberOrdersError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14842,24 +18281,54 @@ impl ListPhoneNumbersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListPhoneNumbersError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPhoneNumbersError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListPhoneNumbersError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPhoneNumbersError::Forbidden,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListPhoneNumbersError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPhoneNumbersError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListPhoneNumbersError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPhoneNumbersError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListPhoneNumbersError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPhoneNumbersError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListPhoneNumbersError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPhoneNumbersError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14904,31 +18373,62 @@ impl ListProxySessionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListProxySessionsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProxySessionsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListProxySessionsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProxySessionsError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListProxySessionsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProxySessionsError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListProxySessionsError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProxySessionsError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListProxySessionsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListProxySessionsError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListProxySessionsError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProxySessionsError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListProxySessionsError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListProxySessionsError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14974,31 +18474,62 @@ impl ListRoomMembershipsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListRoomMembershipsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRoomMembershipsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListRoomMembershipsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRoomMembershipsError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListRoomMembershipsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRoomMembershipsError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListRoomMembershipsError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRoomMembershipsError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListRoomMembershipsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListRoomMembershipsError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListRoomMembershipsError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRoomMembershipsError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListRoomMembershipsError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListRoomMembershipsError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15044,27 +18575,62 @@ impl ListRoomsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListRoomsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRoomsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListRoomsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRoomsError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListRoomsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRoomsError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListRoomsError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRoomsError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListRoomsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRoomsError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListRoomsError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRoomsError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListRoomsError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRoomsError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15108,32 +18674,54 @@ impl ListSipMediaApplicationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListSipMediaApplicationsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSipMediaApplicationsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListSipMediaApplicationsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSipMediaApplicationsError::Forbidden,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListSipMediaApplicationsError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSipMediaApplicationsError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListSipMediaApplicationsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSipMediaApplicationsError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListSipMediaApplicationsError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSipMediaApplicationsError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListSipMediaApplicationsError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSipMediaApplicationsError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15176,24 +18764,54 @@ impl ListSipRulesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListSipRulesError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSipRulesError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListSipRulesError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSipRulesError::Forbidden,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListSipRulesError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSipRulesError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListSipRulesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSipRulesError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListSipRulesError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSipRulesError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListSipRulesError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSipRulesError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15236,28 +18854,54 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListTagsForResourceError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15302,27 +18946,62 @@ impl ListUsersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListUsersError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUsersError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListUsersError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUsersError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListUsersError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUsersError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListUsersError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUsersError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListUsersError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUsersError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListUsersError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUsersError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListUsersError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUsersError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15366,32 +19045,54 @@ impl ListVoiceConnectorGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListVoiceConnectorGroupsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVoiceConnectorGroupsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListVoiceConnectorGroupsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVoiceConnectorGroupsError::Forbidden,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListVoiceConnectorGroupsError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListVoiceConnectorGroupsError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListVoiceConnectorGroupsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListVoiceConnectorGroupsError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListVoiceConnectorGroupsError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListVoiceConnectorGroupsError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListVoiceConnectorGroupsError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListVoiceConnectorGroupsError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15438,41 +19139,62 @@ impl ListVoiceConnectorTerminationCredentialsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        ListVoiceConnectorTerminationCredentialsError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVoiceConnectorTerminationCredentialsError::BadRequest,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
-                        ListVoiceConnectorTerminationCredentialsError::Forbidden(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVoiceConnectorTerminationCredentialsError::Forbidden,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
-                        ListVoiceConnectorTerminationCredentialsError::NotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVoiceConnectorTerminationCredentialsError::NotFound,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        ListVoiceConnectorTerminationCredentialsError::ServiceFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVoiceConnectorTerminationCredentialsError::ServiceFailure,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        ListVoiceConnectorTerminationCredentialsError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVoiceConnectorTerminationCredentialsError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        ListVoiceConnectorTerminationCredentialsError::ThrottledClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVoiceConnectorTerminationCredentialsError::ThrottledClient,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        ListVoiceConnectorTerminationCredentialsError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVoiceConnectorTerminationCredentialsError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15530,28 +19252,54 @@ impl ListVoiceConnectorsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListVoiceConnectorsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVoiceConnectorsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListVoiceConnectorsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVoiceConnectorsError::Forbidden,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListVoiceConnectorsError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVoiceConnectorsError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListVoiceConnectorsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListVoiceConnectorsError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ListVoiceConnectorsError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVoiceConnectorsError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ListVoiceConnectorsError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListVoiceConnectorsError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15596,27 +19344,62 @@ impl LogoutUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(LogoutUserError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        LogoutUserError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(LogoutUserError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        LogoutUserError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(LogoutUserError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        LogoutUserError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(LogoutUserError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        LogoutUserError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(LogoutUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        LogoutUserError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(LogoutUserError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        LogoutUserError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(LogoutUserError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        LogoutUserError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15666,46 +19449,70 @@ impl PutAppInstanceRetentionSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutAppInstanceRetentionSettingsError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutAppInstanceRetentionSettingsError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(PutAppInstanceRetentionSettingsError::Conflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutAppInstanceRetentionSettingsError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(PutAppInstanceRetentionSettingsError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutAppInstanceRetentionSettingsError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutAppInstanceRetentionSettingsError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutAppInstanceRetentionSettingsError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        PutAppInstanceRetentionSettingsError::ServiceFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAppInstanceRetentionSettingsError::ServiceFailure,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        PutAppInstanceRetentionSettingsError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAppInstanceRetentionSettingsError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        PutAppInstanceRetentionSettingsError::ThrottledClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAppInstanceRetentionSettingsError::ThrottledClient,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        PutAppInstanceRetentionSettingsError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAppInstanceRetentionSettingsError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15762,41 +19569,62 @@ impl PutAppInstanceStreamingConfigurationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        PutAppInstanceStreamingConfigurationsError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAppInstanceStreamingConfigurationsError::BadRequest,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
-                        PutAppInstanceStreamingConfigurationsError::Forbidden(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAppInstanceStreamingConfigurationsError::Forbidden,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
-                        PutAppInstanceStreamingConfigurationsError::NotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAppInstanceStreamingConfigurationsError::NotFound,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        PutAppInstanceStreamingConfigurationsError::ServiceFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAppInstanceStreamingConfigurationsError::ServiceFailure,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        PutAppInstanceStreamingConfigurationsError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAppInstanceStreamingConfigurationsError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        PutAppInstanceStreamingConfigurationsError::ThrottledClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAppInstanceStreamingConfigurationsError::ThrottledClient,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        PutAppInstanceStreamingConfigurationsError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAppInstanceStreamingConfigurationsError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15856,35 +19684,62 @@ impl PutEventsConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutEventsConfigurationError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutEventsConfigurationError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(PutEventsConfigurationError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutEventsConfigurationError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutEventsConfigurationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutEventsConfigurationError::NotFound,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
-                        PutEventsConfigurationError::ResourceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutEventsConfigurationError::ResourceLimitExceeded,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(PutEventsConfigurationError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutEventsConfigurationError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutEventsConfigurationError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutEventsConfigurationError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(PutEventsConfigurationError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutEventsConfigurationError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15932,36 +19787,70 @@ impl PutRetentionSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutRetentionSettingsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRetentionSettingsError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(PutRetentionSettingsError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRetentionSettingsError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(PutRetentionSettingsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRetentionSettingsError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutRetentionSettingsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRetentionSettingsError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(PutRetentionSettingsError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRetentionSettingsError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutRetentionSettingsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutRetentionSettingsError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(PutRetentionSettingsError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutRetentionSettingsError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(PutRetentionSettingsError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutRetentionSettingsError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16010,45 +19899,62 @@ impl PutSipMediaApplicationLoggingConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        PutSipMediaApplicationLoggingConfigurationError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSipMediaApplicationLoggingConfigurationError::BadRequest,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
-                        PutSipMediaApplicationLoggingConfigurationError::Forbidden(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSipMediaApplicationLoggingConfigurationError::Forbidden,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
-                        PutSipMediaApplicationLoggingConfigurationError::NotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSipMediaApplicationLoggingConfigurationError::NotFound,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        PutSipMediaApplicationLoggingConfigurationError::ServiceFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSipMediaApplicationLoggingConfigurationError::ServiceFailure,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        PutSipMediaApplicationLoggingConfigurationError::ServiceUnavailable(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSipMediaApplicationLoggingConfigurationError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        PutSipMediaApplicationLoggingConfigurationError::ThrottledClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSipMediaApplicationLoggingConfigurationError::ThrottledClient,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        PutSipMediaApplicationLoggingConfigurationError::UnauthorizedClient(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSipMediaApplicationLoggingConfigurationError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16110,49 +20016,62 @@ impl PutVoiceConnectorEmergencyCallingConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorEmergencyCallingConfigurationError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutVoiceConnectorEmergencyCallingConfigurationError::BadRequest,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorEmergencyCallingConfigurationError::Forbidden(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutVoiceConnectorEmergencyCallingConfigurationError::Forbidden,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorEmergencyCallingConfigurationError::NotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutVoiceConnectorEmergencyCallingConfigurationError::NotFound,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorEmergencyCallingConfigurationError::ServiceFailure(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutVoiceConnectorEmergencyCallingConfigurationError::ServiceFailure,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorEmergencyCallingConfigurationError::ServiceUnavailable(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutVoiceConnectorEmergencyCallingConfigurationError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorEmergencyCallingConfigurationError::ThrottledClient(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutVoiceConnectorEmergencyCallingConfigurationError::ThrottledClient,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorEmergencyCallingConfigurationError::UnauthorizedClient(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutVoiceConnectorEmergencyCallingConfigurationError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16214,41 +20133,62 @@ impl PutVoiceConnectorLoggingConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorLoggingConfigurationError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutVoiceConnectorLoggingConfigurationError::BadRequest,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorLoggingConfigurationError::Forbidden(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutVoiceConnectorLoggingConfigurationError::Forbidden,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorLoggingConfigurationError::NotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutVoiceConnectorLoggingConfigurationError::NotFound,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorLoggingConfigurationError::ServiceFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutVoiceConnectorLoggingConfigurationError::ServiceFailure,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorLoggingConfigurationError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutVoiceConnectorLoggingConfigurationError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorLoggingConfigurationError::ThrottledClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutVoiceConnectorLoggingConfigurationError::ThrottledClient,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorLoggingConfigurationError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutVoiceConnectorLoggingConfigurationError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16310,41 +20250,62 @@ impl PutVoiceConnectorOriginationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutVoiceConnectorOriginationError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutVoiceConnectorOriginationError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(PutVoiceConnectorOriginationError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutVoiceConnectorOriginationError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutVoiceConnectorOriginationError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutVoiceConnectorOriginationError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(PutVoiceConnectorOriginationError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutVoiceConnectorOriginationError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorOriginationError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutVoiceConnectorOriginationError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorOriginationError::ThrottledClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutVoiceConnectorOriginationError::ThrottledClient,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorOriginationError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutVoiceConnectorOriginationError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16396,38 +20357,70 @@ impl PutVoiceConnectorProxyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(PutVoiceConnectorProxyError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutVoiceConnectorProxyError::AccessDenied,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(PutVoiceConnectorProxyError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutVoiceConnectorProxyError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(PutVoiceConnectorProxyError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutVoiceConnectorProxyError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutVoiceConnectorProxyError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutVoiceConnectorProxyError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(PutVoiceConnectorProxyError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutVoiceConnectorProxyError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutVoiceConnectorProxyError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutVoiceConnectorProxyError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(PutVoiceConnectorProxyError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutVoiceConnectorProxyError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(PutVoiceConnectorProxyError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutVoiceConnectorProxyError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16476,41 +20469,62 @@ impl PutVoiceConnectorStreamingConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorStreamingConfigurationError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutVoiceConnectorStreamingConfigurationError::BadRequest,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorStreamingConfigurationError::Forbidden(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutVoiceConnectorStreamingConfigurationError::Forbidden,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorStreamingConfigurationError::NotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutVoiceConnectorStreamingConfigurationError::NotFound,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorStreamingConfigurationError::ServiceFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutVoiceConnectorStreamingConfigurationError::ServiceFailure,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorStreamingConfigurationError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutVoiceConnectorStreamingConfigurationError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorStreamingConfigurationError::ThrottledClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutVoiceConnectorStreamingConfigurationError::ThrottledClient,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorStreamingConfigurationError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutVoiceConnectorStreamingConfigurationError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16574,46 +20588,70 @@ impl PutVoiceConnectorTerminationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(PutVoiceConnectorTerminationError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutVoiceConnectorTerminationError::AccessDenied,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(PutVoiceConnectorTerminationError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutVoiceConnectorTerminationError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(PutVoiceConnectorTerminationError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutVoiceConnectorTerminationError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutVoiceConnectorTerminationError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutVoiceConnectorTerminationError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(PutVoiceConnectorTerminationError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutVoiceConnectorTerminationError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorTerminationError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutVoiceConnectorTerminationError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorTerminationError::ThrottledClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutVoiceConnectorTerminationError::ThrottledClient,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorTerminationError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutVoiceConnectorTerminationError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16666,41 +20704,62 @@ impl PutVoiceConnectorTerminationCredentialsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorTerminationCredentialsError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutVoiceConnectorTerminationCredentialsError::BadRequest,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorTerminationCredentialsError::Forbidden(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutVoiceConnectorTerminationCredentialsError::Forbidden,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorTerminationCredentialsError::NotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutVoiceConnectorTerminationCredentialsError::NotFound,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorTerminationCredentialsError::ServiceFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutVoiceConnectorTerminationCredentialsError::ServiceFailure,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorTerminationCredentialsError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutVoiceConnectorTerminationCredentialsError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorTerminationCredentialsError::ThrottledClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutVoiceConnectorTerminationCredentialsError::ThrottledClient,
                     )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        PutVoiceConnectorTerminationCredentialsError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutVoiceConnectorTerminationCredentialsError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16758,30 +20817,54 @@ impl RedactChannelMessageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(RedactChannelMessageError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RedactChannelMessageError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(RedactChannelMessageError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RedactChannelMessageError::Forbidden,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(RedactChannelMessageError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RedactChannelMessageError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(RedactChannelMessageError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RedactChannelMessageError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(RedactChannelMessageError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RedactChannelMessageError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(RedactChannelMessageError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RedactChannelMessageError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16826,37 +20909,62 @@ impl RedactConversationMessageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(RedactConversationMessageError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RedactConversationMessageError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(RedactConversationMessageError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RedactConversationMessageError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RedactConversationMessageError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RedactConversationMessageError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(RedactConversationMessageError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RedactConversationMessageError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        RedactConversationMessageError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RedactConversationMessageError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(RedactConversationMessageError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RedactConversationMessageError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        RedactConversationMessageError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RedactConversationMessageError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16902,31 +21010,62 @@ impl RedactRoomMessageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(RedactRoomMessageError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RedactRoomMessageError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(RedactRoomMessageError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RedactRoomMessageError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RedactRoomMessageError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RedactRoomMessageError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(RedactRoomMessageError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RedactRoomMessageError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(RedactRoomMessageError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RedactRoomMessageError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(RedactRoomMessageError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RedactRoomMessageError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(RedactRoomMessageError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RedactRoomMessageError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16972,35 +21111,62 @@ impl RegenerateSecurityTokenError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(RegenerateSecurityTokenError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegenerateSecurityTokenError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(RegenerateSecurityTokenError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegenerateSecurityTokenError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RegenerateSecurityTokenError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegenerateSecurityTokenError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(RegenerateSecurityTokenError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegenerateSecurityTokenError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(RegenerateSecurityTokenError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegenerateSecurityTokenError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(RegenerateSecurityTokenError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegenerateSecurityTokenError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(RegenerateSecurityTokenError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegenerateSecurityTokenError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17046,27 +21212,62 @@ impl ResetPersonalPINError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ResetPersonalPINError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResetPersonalPINError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ResetPersonalPINError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResetPersonalPINError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ResetPersonalPINError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResetPersonalPINError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ResetPersonalPINError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResetPersonalPINError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ResetPersonalPINError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResetPersonalPINError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(ResetPersonalPINError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResetPersonalPINError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(ResetPersonalPINError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResetPersonalPINError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17114,36 +21315,70 @@ impl RestorePhoneNumberError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(RestorePhoneNumberError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RestorePhoneNumberError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(RestorePhoneNumberError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RestorePhoneNumberError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RestorePhoneNumberError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RestorePhoneNumberError::NotFound,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(RestorePhoneNumberError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RestorePhoneNumberError::ResourceLimitExceeded,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(RestorePhoneNumberError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RestorePhoneNumberError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(RestorePhoneNumberError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RestorePhoneNumberError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(RestorePhoneNumberError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RestorePhoneNumberError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(RestorePhoneNumberError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RestorePhoneNumberError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17192,41 +21427,62 @@ impl SearchAvailablePhoneNumbersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(SearchAvailablePhoneNumbersError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SearchAvailablePhoneNumbersError::AccessDenied,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(SearchAvailablePhoneNumbersError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SearchAvailablePhoneNumbersError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(SearchAvailablePhoneNumbersError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SearchAvailablePhoneNumbersError::Forbidden,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(SearchAvailablePhoneNumbersError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SearchAvailablePhoneNumbersError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        SearchAvailablePhoneNumbersError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchAvailablePhoneNumbersError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(SearchAvailablePhoneNumbersError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SearchAvailablePhoneNumbersError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        SearchAvailablePhoneNumbersError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchAvailablePhoneNumbersError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17276,31 +21532,62 @@ impl SendChannelMessageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(SendChannelMessageError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendChannelMessageError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(SendChannelMessageError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendChannelMessageError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(SendChannelMessageError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendChannelMessageError::Forbidden,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(SendChannelMessageError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendChannelMessageError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(SendChannelMessageError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SendChannelMessageError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(SendChannelMessageError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendChannelMessageError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(SendChannelMessageError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SendChannelMessageError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17348,30 +21635,70 @@ impl TagAttendeeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagAttendeeError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagAttendeeError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(TagAttendeeError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagAttendeeError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagAttendeeError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagAttendeeError::NotFound,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(TagAttendeeError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagAttendeeError::ResourceLimitExceeded,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(TagAttendeeError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagAttendeeError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(TagAttendeeError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagAttendeeError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(TagAttendeeError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagAttendeeError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(TagAttendeeError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagAttendeeError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17420,30 +21747,70 @@ impl TagMeetingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagMeetingError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagMeetingError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(TagMeetingError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagMeetingError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagMeetingError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagMeetingError::NotFound,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(TagMeetingError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagMeetingError::ResourceLimitExceeded,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(TagMeetingError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagMeetingError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(TagMeetingError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagMeetingError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(TagMeetingError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagMeetingError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(TagMeetingError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagMeetingError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17488,24 +21855,54 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(TagResourceError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(TagResourceError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(TagResourceError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(TagResourceError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17550,27 +21947,62 @@ impl UntagAttendeeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagAttendeeError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagAttendeeError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UntagAttendeeError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagAttendeeError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagAttendeeError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagAttendeeError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UntagAttendeeError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagAttendeeError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UntagAttendeeError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagAttendeeError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UntagAttendeeError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagAttendeeError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UntagAttendeeError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagAttendeeError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17616,27 +22048,62 @@ impl UntagMeetingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagMeetingError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagMeetingError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UntagMeetingError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagMeetingError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagMeetingError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagMeetingError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UntagMeetingError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagMeetingError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UntagMeetingError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagMeetingError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UntagMeetingError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagMeetingError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UntagMeetingError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagMeetingError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17680,24 +22147,54 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UntagResourceError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UntagResourceError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UntagResourceError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UntagResourceError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17742,27 +22239,62 @@ impl UpdateAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateAccountError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAccountError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateAccountError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAccountError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateAccountError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAccountError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateAccountError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAccountError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateAccountError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAccountError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateAccountError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAccountError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateAccountError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAccountError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17810,38 +22342,70 @@ impl UpdateAccountSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateAccountSettingsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAccountSettingsError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateAccountSettingsError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAccountSettingsError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateAccountSettingsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAccountSettingsError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateAccountSettingsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAccountSettingsError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateAccountSettingsError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAccountSettingsError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateAccountSettingsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAccountSettingsError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateAccountSettingsError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAccountSettingsError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateAccountSettingsError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAccountSettingsError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17888,31 +22452,62 @@ impl UpdateAppInstanceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateAppInstanceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAppInstanceError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateAppInstanceError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAppInstanceError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateAppInstanceError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAppInstanceError::Forbidden,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateAppInstanceError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAppInstanceError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateAppInstanceError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAppInstanceError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateAppInstanceError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAppInstanceError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateAppInstanceError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAppInstanceError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17958,35 +22553,62 @@ impl UpdateAppInstanceUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateAppInstanceUserError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAppInstanceUserError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateAppInstanceUserError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAppInstanceUserError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateAppInstanceUserError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAppInstanceUserError::Forbidden,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateAppInstanceUserError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAppInstanceUserError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateAppInstanceUserError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAppInstanceUserError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateAppInstanceUserError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAppInstanceUserError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateAppInstanceUserError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAppInstanceUserError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18032,27 +22654,62 @@ impl UpdateBotError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateBotError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBotError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateBotError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBotError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateBotError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBotError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateBotError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBotError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateBotError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBotError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateBotError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBotError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateBotError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBotError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18098,27 +22755,62 @@ impl UpdateChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateChannelError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateChannelError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateChannelError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateChannelError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateChannelError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateChannelError::Forbidden,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateChannelError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateChannelError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateChannelError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateChannelError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateChannelError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateChannelError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateChannelError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateChannelError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18164,33 +22856,62 @@ impl UpdateChannelMessageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateChannelMessageError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateChannelMessageError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateChannelMessageError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateChannelMessageError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateChannelMessageError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateChannelMessageError::Forbidden,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateChannelMessageError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateChannelMessageError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateChannelMessageError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateChannelMessageError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateChannelMessageError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateChannelMessageError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateChannelMessageError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateChannelMessageError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18236,35 +22957,62 @@ impl UpdateChannelReadMarkerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateChannelReadMarkerError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateChannelReadMarkerError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateChannelReadMarkerError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateChannelReadMarkerError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateChannelReadMarkerError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateChannelReadMarkerError::Forbidden,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateChannelReadMarkerError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateChannelReadMarkerError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateChannelReadMarkerError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateChannelReadMarkerError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateChannelReadMarkerError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateChannelReadMarkerError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateChannelReadMarkerError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateChannelReadMarkerError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18308,30 +23056,54 @@ impl UpdateGlobalSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateGlobalSettingsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGlobalSettingsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateGlobalSettingsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGlobalSettingsError::Forbidden,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateGlobalSettingsError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGlobalSettingsError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateGlobalSettingsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateGlobalSettingsError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateGlobalSettingsError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateGlobalSettingsError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateGlobalSettingsError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateGlobalSettingsError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18376,31 +23148,62 @@ impl UpdatePhoneNumberError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdatePhoneNumberError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePhoneNumberError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdatePhoneNumberError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePhoneNumberError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdatePhoneNumberError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePhoneNumberError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdatePhoneNumberError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePhoneNumberError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdatePhoneNumberError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdatePhoneNumberError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdatePhoneNumberError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePhoneNumberError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdatePhoneNumberError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdatePhoneNumberError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18444,34 +23247,54 @@ impl UpdatePhoneNumberSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdatePhoneNumberSettingsError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdatePhoneNumberSettingsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdatePhoneNumberSettingsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePhoneNumberSettingsError::Forbidden,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdatePhoneNumberSettingsError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdatePhoneNumberSettingsError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        UpdatePhoneNumberSettingsError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePhoneNumberSettingsError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdatePhoneNumberSettingsError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdatePhoneNumberSettingsError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        UpdatePhoneNumberSettingsError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePhoneNumberSettingsError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18516,31 +23339,62 @@ impl UpdateProxySessionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateProxySessionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateProxySessionError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateProxySessionError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateProxySessionError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateProxySessionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateProxySessionError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateProxySessionError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateProxySessionError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateProxySessionError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateProxySessionError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateProxySessionError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateProxySessionError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateProxySessionError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateProxySessionError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18586,27 +23440,62 @@ impl UpdateRoomError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateRoomError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRoomError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateRoomError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRoomError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateRoomError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRoomError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateRoomError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRoomError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateRoomError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRoomError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateRoomError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRoomError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateRoomError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRoomError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18652,33 +23541,62 @@ impl UpdateRoomMembershipError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateRoomMembershipError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRoomMembershipError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateRoomMembershipError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRoomMembershipError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateRoomMembershipError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRoomMembershipError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateRoomMembershipError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRoomMembershipError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateRoomMembershipError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRoomMembershipError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateRoomMembershipError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRoomMembershipError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateRoomMembershipError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRoomMembershipError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18726,40 +23644,70 @@ impl UpdateSipMediaApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateSipMediaApplicationError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSipMediaApplicationError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateSipMediaApplicationError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSipMediaApplicationError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateSipMediaApplicationError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSipMediaApplicationError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateSipMediaApplicationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSipMediaApplicationError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateSipMediaApplicationError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSipMediaApplicationError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        UpdateSipMediaApplicationError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSipMediaApplicationError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateSipMediaApplicationError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSipMediaApplicationError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        UpdateSipMediaApplicationError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSipMediaApplicationError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18810,33 +23758,78 @@ impl UpdateSipRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateSipRuleError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSipRuleError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateSipRuleError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSipRuleError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateSipRuleError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSipRuleError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateSipRuleError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSipRuleError::NotFound,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(UpdateSipRuleError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSipRuleError::ResourceLimitExceeded,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateSipRuleError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSipRuleError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateSipRuleError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSipRuleError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateSipRuleError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSipRuleError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateSipRuleError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSipRuleError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18884,27 +23877,62 @@ impl UpdateUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateUserError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateUserError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateUserError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateUserError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateUserError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateUserError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateUserError::UnauthorizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18950,31 +23978,62 @@ impl UpdateUserSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateUserSettingsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserSettingsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateUserSettingsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserSettingsError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateUserSettingsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserSettingsError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateUserSettingsError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserSettingsError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateUserSettingsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserSettingsError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateUserSettingsError::ThrottledClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserSettingsError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateUserSettingsError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserSettingsError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19020,33 +24079,62 @@ impl UpdateVoiceConnectorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVoiceConnectorError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVoiceConnectorError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVoiceConnectorError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVoiceConnectorError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateVoiceConnectorError::ServiceUnavailable,
+                    )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateVoiceConnectorError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorError::UnauthorizedClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateVoiceConnectorError::UnauthorizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19094,40 +24182,70 @@ impl UpdateVoiceConnectorGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorGroupError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateVoiceConnectorGroupError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorGroupError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVoiceConnectorGroupError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorGroupError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVoiceConnectorGroupError::Forbidden,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorGroupError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVoiceConnectorGroupError::NotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorGroupError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateVoiceConnectorGroupError::ServiceFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        UpdateVoiceConnectorGroupError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVoiceConnectorGroupError::ServiceUnavailable,
                     )
                 }
                 "ThrottledClientException" => {
-                    return RusotoError::Service(UpdateVoiceConnectorGroupError::ThrottledClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateVoiceConnectorGroupError::ThrottledClient,
+                    )
                 }
                 "UnauthorizedClientException" => {
-                    return RusotoError::Service(
-                        UpdateVoiceConnectorGroupError::UnauthorizedClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVoiceConnectorGroupError::UnauthorizedClient,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20324,11 +25442,12 @@ pub struct ChimeClient {
 impl ChimeClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> ChimeClient {
         ChimeClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Chime"),
         }
     }
 
@@ -20343,12 +25462,32 @@ impl ChimeClient {
     {
         ChimeClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Chime"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ChimeClient {
-        ChimeClient { client, region }
+        ChimeClient {
+            client,
+            region: region_for_service(region, "Chime"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> ChimeClient {
+        ChimeClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Chime"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ChimeClient {
+        ChimeClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -20368,6 +25507,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("AssociatePhoneNumberWithUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -20409,6 +25549,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("AssociatePhoneNumbersWithVoiceConnector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -20452,6 +25593,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("AssociatePhoneNumbersWithVoiceConnectorGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -20491,6 +25633,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/accounts/{account_id}", account_id = input.account_id);
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("AssociateSigninDelegateGroupsWithAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -20529,6 +25672,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("BatchCreateAttendee");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -20569,6 +25713,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("BatchCreateRoomMembership");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -20604,6 +25749,7 @@ impl Chime for ChimeClient {
         let request_uri = "/phone-numbers";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("BatchDeletePhoneNumber");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -20642,6 +25788,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("BatchSuspendUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -20680,6 +25827,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("BatchUnsuspendUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -20715,6 +25863,7 @@ impl Chime for ChimeClient {
         let request_uri = "/phone-numbers";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("BatchUpdatePhoneNumber");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -20753,6 +25902,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("BatchUpdateUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -20784,6 +25934,7 @@ impl Chime for ChimeClient {
         let request_uri = "/accounts";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -20815,6 +25966,7 @@ impl Chime for ChimeClient {
         let request_uri = "/app-instances";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateAppInstance");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -20849,6 +26001,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateAppInstanceAdmin");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -20880,6 +26033,7 @@ impl Chime for ChimeClient {
         let request_uri = "/app-instance-users";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateAppInstanceUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -20914,6 +26068,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateAttendee");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -20945,6 +26100,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/accounts/{account_id}/bots", account_id = input.account_id);
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateBot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -20976,6 +26132,7 @@ impl Chime for ChimeClient {
         let request_uri = "/channels";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -21010,6 +26167,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateChannelBan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -21044,6 +26202,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateChannelMembership");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -21078,6 +26237,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateChannelModerator");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -21109,6 +26269,7 @@ impl Chime for ChimeClient {
         let request_uri = "/meetings";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateMeeting");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -21143,6 +26304,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateMeetingDialOut");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -21175,6 +26337,7 @@ impl Chime for ChimeClient {
         let request_uri = "/meetings";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateMeetingWithAttendees");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -21210,6 +26373,7 @@ impl Chime for ChimeClient {
         let request_uri = "/phone-number-orders";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreatePhoneNumberOrder");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -21244,6 +26408,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateProxySession");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -21278,6 +26443,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateRoom");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -21313,6 +26479,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateRoomMembership");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -21345,6 +26512,7 @@ impl Chime for ChimeClient {
         let request_uri = "/sip-media-applications";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateSipMediaApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -21382,6 +26550,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateSipMediaApplicationCall");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -21413,6 +26582,7 @@ impl Chime for ChimeClient {
         let request_uri = "/sip-rules";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateSipRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -21447,6 +26617,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -21482,6 +26653,7 @@ impl Chime for ChimeClient {
         let request_uri = "/voice-connectors";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateVoiceConnector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -21514,6 +26686,7 @@ impl Chime for ChimeClient {
         let request_uri = "/voice-connector-groups";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("CreateVoiceConnectorGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -21545,6 +26718,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/accounts/{account_id}", account_id = input.account_id);
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -21576,6 +26750,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteAppInstance");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -21607,6 +26782,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteAppInstanceAdmin");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -21637,6 +26813,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteAppInstanceStreamingConfigurations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -21667,6 +26844,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteAppInstanceUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -21698,6 +26876,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteAttendee");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -21725,6 +26904,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/channels/{channel_arn}", channel_arn = input.channel_arn);
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -21756,6 +26936,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteChannelBan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -21787,6 +26968,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteChannelMembership");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -21818,6 +27000,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteChannelMessage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -21849,6 +27032,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteChannelModerator");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -21880,6 +27064,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteEventsConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -21907,6 +27092,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/meetings/{meeting_id}", meeting_id = input.meeting_id);
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteMeeting");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -21937,6 +27123,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeletePhoneNumber");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -21968,6 +27155,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteProxySession");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -21999,6 +27187,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteRoom");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -22031,6 +27220,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteRoomMembership");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -22061,6 +27251,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteSipMediaApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -22088,6 +27279,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/sip-rules/{sip_rule_id}", sip_rule_id = input.sip_rule_id);
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteSipRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -22118,6 +27310,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteVoiceConnector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -22148,6 +27341,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteVoiceConnectorEmergencyCallingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -22178,6 +27372,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteVoiceConnectorGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -22208,6 +27403,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteVoiceConnectorOrigination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -22240,6 +27436,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteVoiceConnectorProxy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -22270,6 +27467,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteVoiceConnectorStreamingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -22300,6 +27498,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteVoiceConnectorTermination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -22332,6 +27531,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("DeleteVoiceConnectorTerminationCredentials");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -22369,6 +27569,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("DescribeAppInstance");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -22401,6 +27602,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("DescribeAppInstanceAdmin");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -22432,6 +27634,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("DescribeAppInstanceUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -22460,6 +27663,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/channels/{channel_arn}", channel_arn = input.channel_arn);
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("DescribeChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -22492,6 +27696,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("DescribeChannelBan");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -22525,6 +27730,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("DescribeChannelMembership");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -22556,6 +27762,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/channels/{channel_arn}", channel_arn = input.channel_arn);
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("DescribeChannelMembershipForAppInstanceUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -22592,6 +27799,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/channels/{channel_arn}", channel_arn = input.channel_arn);
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("DescribeChannelModeratedByAppInstanceUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -22629,6 +27837,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("DescribeChannelModerator");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -22664,6 +27873,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("DisassociatePhoneNumberFromUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -22704,6 +27914,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("DisassociatePhoneNumbersFromVoiceConnector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -22745,6 +27956,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("DisassociatePhoneNumbersFromVoiceConnectorGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -22784,6 +27996,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/accounts/{account_id}", account_id = input.account_id);
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("DisassociateSigninDelegateGroupsFromAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -22819,6 +28032,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/accounts/{account_id}", account_id = input.account_id);
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -22850,6 +28064,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetAccountSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -22884,6 +28099,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetAppInstanceRetentionSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -22920,6 +28136,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetAppInstanceStreamingConfigurations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -22954,6 +28171,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetAttendee");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -22986,6 +28204,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetBot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -23018,6 +28237,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetChannelMessage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -23050,6 +28270,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetEventsConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -23077,6 +28298,7 @@ impl Chime for ChimeClient {
         let request_uri = "/settings";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetGlobalSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -23105,6 +28327,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/meetings/{meeting_id}", meeting_id = input.meeting_id);
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetMeeting");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -23133,6 +28356,7 @@ impl Chime for ChimeClient {
         let request_uri = "/endpoints/messaging-session";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetMessagingSessionEndpoint");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -23164,6 +28388,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetPhoneNumber");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -23195,6 +28420,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetPhoneNumberOrder");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -23222,6 +28448,7 @@ impl Chime for ChimeClient {
         let request_uri = "/settings/phone-number";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetPhoneNumberSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -23254,6 +28481,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetProxySession");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -23285,6 +28513,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetRetentionSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -23317,6 +28546,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetRoom");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -23348,6 +28578,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetSipMediaApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -23382,6 +28613,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetSipMediaApplicationLoggingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -23410,6 +28642,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/sip-rules/{sip_rule_id}", sip_rule_id = input.sip_rule_id);
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetSipRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -23442,6 +28675,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -23474,6 +28708,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetUserSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -23505,6 +28740,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetVoiceConnector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -23539,6 +28775,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetVoiceConnectorEmergencyCallingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -23571,6 +28808,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetVoiceConnectorGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -23605,6 +28843,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetVoiceConnectorLoggingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -23639,6 +28878,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetVoiceConnectorOrigination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -23670,6 +28910,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetVoiceConnectorProxy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -23704,6 +28945,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetVoiceConnectorStreamingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -23738,6 +28980,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetVoiceConnectorTermination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -23772,6 +29015,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("GetVoiceConnectorTerminationHealth");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -23805,6 +29049,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("InviteUsers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -23840,6 +29085,7 @@ impl Chime for ChimeClient {
         let request_uri = "/accounts";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListAccounts");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -23886,6 +29132,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListAppInstanceAdmins");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -23923,6 +29170,7 @@ impl Chime for ChimeClient {
         let request_uri = "/app-instance-users";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListAppInstanceUsers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -23961,6 +29209,7 @@ impl Chime for ChimeClient {
         let request_uri = "/app-instances";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListAppInstances");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -24002,6 +29251,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListAttendeeTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -24033,6 +29283,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListAttendees");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -24070,6 +29321,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/accounts/{account_id}/bots", account_id = input.account_id);
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListBots");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -24110,6 +29362,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListChannelBans");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -24150,6 +29403,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListChannelMemberships");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -24193,6 +29447,7 @@ impl Chime for ChimeClient {
         let request_uri = "/channels";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListChannelMembershipsForAppInstanceUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -24237,6 +29492,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListChannelMessages");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -24286,6 +29542,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListChannelModerators");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -24323,6 +29580,7 @@ impl Chime for ChimeClient {
         let request_uri = "/channels";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListChannels");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -24367,6 +29625,7 @@ impl Chime for ChimeClient {
         let request_uri = "/channels";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListChannelsModeratedByAppInstanceUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -24410,6 +29669,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/meetings/{meeting_id}/tags", meeting_id = input.meeting_id);
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListMeetingTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -24438,6 +29698,7 @@ impl Chime for ChimeClient {
         let request_uri = "/meetings";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListMeetings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -24475,6 +29736,7 @@ impl Chime for ChimeClient {
         let request_uri = "/phone-number-orders";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListPhoneNumberOrders");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -24512,6 +29774,7 @@ impl Chime for ChimeClient {
         let request_uri = "/phone-numbers";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListPhoneNumbers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -24564,6 +29827,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListProxySessions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -24608,6 +29872,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListRoomMemberships");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -24648,6 +29913,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListRooms");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -24688,6 +29954,7 @@ impl Chime for ChimeClient {
         let request_uri = "/sip-media-applications";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListSipMediaApplications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -24725,6 +29992,7 @@ impl Chime for ChimeClient {
         let request_uri = "/sip-rules";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListSipRules");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -24765,6 +30033,7 @@ impl Chime for ChimeClient {
         let request_uri = "/tags";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -24800,6 +30069,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListUsers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -24843,6 +30113,7 @@ impl Chime for ChimeClient {
         let request_uri = "/voice-connector-groups";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListVoiceConnectorGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -24886,6 +30157,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListVoiceConnectorTerminationCredentials");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -24914,6 +30186,7 @@ impl Chime for ChimeClient {
         let request_uri = "/voice-connectors";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("ListVoiceConnectors");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -24955,6 +30228,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("LogoutUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -24993,6 +30267,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("PutAppInstanceRetentionSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -25032,6 +30307,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("PutAppInstanceStreamingConfigurations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -25069,6 +30345,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("PutEventsConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -25103,6 +30380,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("PutRetentionSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -25140,6 +30418,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("PutSipMediaApplicationLoggingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -25177,6 +30456,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("PutVoiceConnectorEmergencyCallingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -25215,6 +30495,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("PutVoiceConnectorLoggingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -25252,6 +30533,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("PutVoiceConnectorOrigination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -25286,6 +30568,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("PutVoiceConnectorProxy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -25323,6 +30606,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("PutVoiceConnectorStreamingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -25360,6 +30644,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("PutVoiceConnectorTermination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -25394,6 +30679,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("PutVoiceConnectorTerminationCredentials");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -25434,6 +30720,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("RedactChannelMessage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -25472,6 +30759,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("RedactConversationMessage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -25509,6 +30797,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("RedactRoomMessage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -25545,6 +30834,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("RegenerateSecurityToken");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -25581,6 +30871,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("ResetPersonalPIN");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -25616,6 +30907,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("RestorePhoneNumber");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -25649,6 +30941,7 @@ impl Chime for ChimeClient {
         let request_uri = "/search";
 
         let mut request = SignedRequest::new("GET", "chime", &self.region, &request_uri);
+        request.set_operation("SearchAvailablePhoneNumbers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -25705,6 +30998,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("SendChannelMessage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -25740,6 +31034,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("TagAttendee");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -25774,6 +31069,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/meetings/{meeting_id}/tags", meeting_id = input.meeting_id);
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("TagMeeting");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -25808,6 +31104,7 @@ impl Chime for ChimeClient {
         let request_uri = "/tags";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -25846,6 +31143,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("UntagAttendee");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -25880,6 +31178,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/meetings/{meeting_id}/tags", meeting_id = input.meeting_id);
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("UntagMeeting");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -25914,6 +31213,7 @@ impl Chime for ChimeClient {
         let request_uri = "/tags";
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -25948,6 +31248,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/accounts/{account_id}", account_id = input.account_id);
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -25982,6 +31283,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateAccountSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -26016,6 +31318,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateAppInstance");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -26050,6 +31353,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateAppInstanceUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -26085,6 +31389,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateBot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -26116,6 +31421,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/channels/{channel_arn}", channel_arn = input.channel_arn);
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -26151,6 +31457,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateChannelMessage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -26185,6 +31492,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateChannelReadMarker");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -26213,6 +31521,7 @@ impl Chime for ChimeClient {
         let request_uri = "/settings";
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateGlobalSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -26246,6 +31555,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("UpdatePhoneNumber");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -26277,6 +31587,7 @@ impl Chime for ChimeClient {
         let request_uri = "/settings/phone-number";
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("UpdatePhoneNumberSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -26311,6 +31622,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateProxySession");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -26346,6 +31658,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateRoom");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -26382,6 +31695,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateRoomMembership");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -26417,6 +31731,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateSipMediaApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -26448,6 +31763,7 @@ impl Chime for ChimeClient {
         let request_uri = format!("/sip-rules/{sip_rule_id}", sip_rule_id = input.sip_rule_id);
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateSipRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -26483,6 +31799,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -26518,6 +31835,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateUserSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -26551,6 +31869,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateVoiceConnector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -26586,6 +31905,7 @@ impl Chime for ChimeClient {
         );
 
         let mut request = SignedRequest::new("PUT", "chime", &self.region, &request_uri);
+        request.set_operation("UpdateVoiceConnectorGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -26608,3 +31928,1783 @@ impl Chime for ChimeClient {
         }
     }
 }
+
+/// A blocking client for the Amazon Chime API, running the calls of a `ChimeClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `ChimeClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ChimeBlockingClient {
+    client: ChimeClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ChimeBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> ChimeBlockingClient {
+        ChimeBlockingClient::from_client(ChimeClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ChimeBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ChimeBlockingClient::from_client(ChimeClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> ChimeBlockingClient {
+        ChimeBlockingClient::from_client(ChimeClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> ChimeBlockingClient {
+        ChimeBlockingClient::from_client(ChimeClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: ChimeClient) -> ChimeBlockingClient {
+        ChimeBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> ChimeBlockingClient {
+        ChimeBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ChimeBlockingClient {
+        ChimeBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Chime::associate_phone_number_with_user`].
+    pub fn associate_phone_number_with_user(
+        &self,
+        input: AssociatePhoneNumberWithUserRequest,
+    ) -> Result<AssociatePhoneNumberWithUserResponse, RusotoError<AssociatePhoneNumberWithUserError>>
+    {
+        self.runtime
+            .block_on(self.client.associate_phone_number_with_user(input))
+    }
+
+    /// Blocking version of [`Chime::associate_phone_numbers_with_voice_connector`].
+    pub fn associate_phone_numbers_with_voice_connector(
+        &self,
+        input: AssociatePhoneNumbersWithVoiceConnectorRequest,
+    ) -> Result<
+        AssociatePhoneNumbersWithVoiceConnectorResponse,
+        RusotoError<AssociatePhoneNumbersWithVoiceConnectorError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .associate_phone_numbers_with_voice_connector(input),
+        )
+    }
+
+    /// Blocking version of [`Chime::associate_phone_numbers_with_voice_connector_group`].
+    pub fn associate_phone_numbers_with_voice_connector_group(
+        &self,
+        input: AssociatePhoneNumbersWithVoiceConnectorGroupRequest,
+    ) -> Result<
+        AssociatePhoneNumbersWithVoiceConnectorGroupResponse,
+        RusotoError<AssociatePhoneNumbersWithVoiceConnectorGroupError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .associate_phone_numbers_with_voice_connector_group(input),
+        )
+    }
+
+    /// Blocking version of [`Chime::associate_signin_delegate_groups_with_account`].
+    pub fn associate_signin_delegate_groups_with_account(
+        &self,
+        input: AssociateSigninDelegateGroupsWithAccountRequest,
+    ) -> Result<
+        AssociateSigninDelegateGroupsWithAccountResponse,
+        RusotoError<AssociateSigninDelegateGroupsWithAccountError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .associate_signin_delegate_groups_with_account(input),
+        )
+    }
+
+    /// Blocking version of [`Chime::batch_create_attendee`].
+    pub fn batch_create_attendee(
+        &self,
+        input: BatchCreateAttendeeRequest,
+    ) -> Result<BatchCreateAttendeeResponse, RusotoError<BatchCreateAttendeeError>> {
+        self.runtime
+            .block_on(self.client.batch_create_attendee(input))
+    }
+
+    /// Blocking version of [`Chime::batch_create_room_membership`].
+    pub fn batch_create_room_membership(
+        &self,
+        input: BatchCreateRoomMembershipRequest,
+    ) -> Result<BatchCreateRoomMembershipResponse, RusotoError<BatchCreateRoomMembershipError>>
+    {
+        self.runtime
+            .block_on(self.client.batch_create_room_membership(input))
+    }
+
+    /// Blocking version of [`Chime::batch_delete_phone_number`].
+    pub fn batch_delete_phone_number(
+        &self,
+        input: BatchDeletePhoneNumberRequest,
+    ) -> Result<BatchDeletePhoneNumberResponse, RusotoError<BatchDeletePhoneNumberError>> {
+        self.runtime
+            .block_on(self.client.batch_delete_phone_number(input))
+    }
+
+    /// Blocking version of [`Chime::batch_suspend_user`].
+    pub fn batch_suspend_user(
+        &self,
+        input: BatchSuspendUserRequest,
+    ) -> Result<BatchSuspendUserResponse, RusotoError<BatchSuspendUserError>> {
+        self.runtime.block_on(self.client.batch_suspend_user(input))
+    }
+
+    /// Blocking version of [`Chime::batch_unsuspend_user`].
+    pub fn batch_unsuspend_user(
+        &self,
+        input: BatchUnsuspendUserRequest,
+    ) -> Result<BatchUnsuspendUserResponse, RusotoError<BatchUnsuspendUserError>> {
+        self.runtime
+            .block_on(self.client.batch_unsuspend_user(input))
+    }
+
+    /// Blocking version of [`Chime::batch_update_phone_number`].
+    pub fn batch_update_phone_number(
+        &self,
+        input: BatchUpdatePhoneNumberRequest,
+    ) -> Result<BatchUpdatePhoneNumberResponse, RusotoError<BatchUpdatePhoneNumberError>> {
+        self.runtime
+            .block_on(self.client.batch_update_phone_number(input))
+    }
+
+    /// Blocking version of [`Chime::batch_update_user`].
+    pub fn batch_update_user(
+        &self,
+        input: BatchUpdateUserRequest,
+    ) -> Result<BatchUpdateUserResponse, RusotoError<BatchUpdateUserError>> {
+        self.runtime.block_on(self.client.batch_update_user(input))
+    }
+
+    /// Blocking version of [`Chime::create_account`].
+    pub fn create_account(
+        &self,
+        input: CreateAccountRequest,
+    ) -> Result<CreateAccountResponse, RusotoError<CreateAccountError>> {
+        self.runtime.block_on(self.client.create_account(input))
+    }
+
+    /// Blocking version of [`Chime::create_app_instance`].
+    pub fn create_app_instance(
+        &self,
+        input: CreateAppInstanceRequest,
+    ) -> Result<CreateAppInstanceResponse, RusotoError<CreateAppInstanceError>> {
+        self.runtime
+            .block_on(self.client.create_app_instance(input))
+    }
+
+    /// Blocking version of [`Chime::create_app_instance_admin`].
+    pub fn create_app_instance_admin(
+        &self,
+        input: CreateAppInstanceAdminRequest,
+    ) -> Result<CreateAppInstanceAdminResponse, RusotoError<CreateAppInstanceAdminError>> {
+        self.runtime
+            .block_on(self.client.create_app_instance_admin(input))
+    }
+
+    /// Blocking version of [`Chime::create_app_instance_user`].
+    pub fn create_app_instance_user(
+        &self,
+        input: CreateAppInstanceUserRequest,
+    ) -> Result<CreateAppInstanceUserResponse, RusotoError<CreateAppInstanceUserError>> {
+        self.runtime
+            .block_on(self.client.create_app_instance_user(input))
+    }
+
+    /// Blocking version of [`Chime::create_attendee`].
+    pub fn create_attendee(
+        &self,
+        input: CreateAttendeeRequest,
+    ) -> Result<CreateAttendeeResponse, RusotoError<CreateAttendeeError>> {
+        self.runtime.block_on(self.client.create_attendee(input))
+    }
+
+    /// Blocking version of [`Chime::create_bot`].
+    pub fn create_bot(
+        &self,
+        input: CreateBotRequest,
+    ) -> Result<CreateBotResponse, RusotoError<CreateBotError>> {
+        self.runtime.block_on(self.client.create_bot(input))
+    }
+
+    /// Blocking version of [`Chime::create_channel`].
+    pub fn create_channel(
+        &self,
+        input: CreateChannelRequest,
+    ) -> Result<CreateChannelResponse, RusotoError<CreateChannelError>> {
+        self.runtime.block_on(self.client.create_channel(input))
+    }
+
+    /// Blocking version of [`Chime::create_channel_ban`].
+    pub fn create_channel_ban(
+        &self,
+        input: CreateChannelBanRequest,
+    ) -> Result<CreateChannelBanResponse, RusotoError<CreateChannelBanError>> {
+        self.runtime.block_on(self.client.create_channel_ban(input))
+    }
+
+    /// Blocking version of [`Chime::create_channel_membership`].
+    pub fn create_channel_membership(
+        &self,
+        input: CreateChannelMembershipRequest,
+    ) -> Result<CreateChannelMembershipResponse, RusotoError<CreateChannelMembershipError>> {
+        self.runtime
+            .block_on(self.client.create_channel_membership(input))
+    }
+
+    /// Blocking version of [`Chime::create_channel_moderator`].
+    pub fn create_channel_moderator(
+        &self,
+        input: CreateChannelModeratorRequest,
+    ) -> Result<CreateChannelModeratorResponse, RusotoError<CreateChannelModeratorError>> {
+        self.runtime
+            .block_on(self.client.create_channel_moderator(input))
+    }
+
+    /// Blocking version of [`Chime::create_meeting`].
+    pub fn create_meeting(
+        &self,
+        input: CreateMeetingRequest,
+    ) -> Result<CreateMeetingResponse, RusotoError<CreateMeetingError>> {
+        self.runtime.block_on(self.client.create_meeting(input))
+    }
+
+    /// Blocking version of [`Chime::create_meeting_dial_out`].
+    pub fn create_meeting_dial_out(
+        &self,
+        input: CreateMeetingDialOutRequest,
+    ) -> Result<CreateMeetingDialOutResponse, RusotoError<CreateMeetingDialOutError>> {
+        self.runtime
+            .block_on(self.client.create_meeting_dial_out(input))
+    }
+
+    /// Blocking version of [`Chime::create_meeting_with_attendees`].
+    pub fn create_meeting_with_attendees(
+        &self,
+        input: CreateMeetingWithAttendeesRequest,
+    ) -> Result<CreateMeetingWithAttendeesResponse, RusotoError<CreateMeetingWithAttendeesError>>
+    {
+        self.runtime
+            .block_on(self.client.create_meeting_with_attendees(input))
+    }
+
+    /// Blocking version of [`Chime::create_phone_number_order`].
+    pub fn create_phone_number_order(
+        &self,
+        input: CreatePhoneNumberOrderRequest,
+    ) -> Result<CreatePhoneNumberOrderResponse, RusotoError<CreatePhoneNumberOrderError>> {
+        self.runtime
+            .block_on(self.client.create_phone_number_order(input))
+    }
+
+    /// Blocking version of [`Chime::create_proxy_session`].
+    pub fn create_proxy_session(
+        &self,
+        input: CreateProxySessionRequest,
+    ) -> Result<CreateProxySessionResponse, RusotoError<CreateProxySessionError>> {
+        self.runtime
+            .block_on(self.client.create_proxy_session(input))
+    }
+
+    /// Blocking version of [`Chime::create_room`].
+    pub fn create_room(
+        &self,
+        input: CreateRoomRequest,
+    ) -> Result<CreateRoomResponse, RusotoError<CreateRoomError>> {
+        self.runtime.block_on(self.client.create_room(input))
+    }
+
+    /// Blocking version of [`Chime::create_room_membership`].
+    pub fn create_room_membership(
+        &self,
+        input: CreateRoomMembershipRequest,
+    ) -> Result<CreateRoomMembershipResponse, RusotoError<CreateRoomMembershipError>> {
+        self.runtime
+            .block_on(self.client.create_room_membership(input))
+    }
+
+    /// Blocking version of [`Chime::create_sip_media_application`].
+    pub fn create_sip_media_application(
+        &self,
+        input: CreateSipMediaApplicationRequest,
+    ) -> Result<CreateSipMediaApplicationResponse, RusotoError<CreateSipMediaApplicationError>>
+    {
+        self.runtime
+            .block_on(self.client.create_sip_media_application(input))
+    }
+
+    /// Blocking version of [`Chime::create_sip_media_application_call`].
+    pub fn create_sip_media_application_call(
+        &self,
+        input: CreateSipMediaApplicationCallRequest,
+    ) -> Result<
+        CreateSipMediaApplicationCallResponse,
+        RusotoError<CreateSipMediaApplicationCallError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_sip_media_application_call(input))
+    }
+
+    /// Blocking version of [`Chime::create_sip_rule`].
+    pub fn create_sip_rule(
+        &self,
+        input: CreateSipRuleRequest,
+    ) -> Result<CreateSipRuleResponse, RusotoError<CreateSipRuleError>> {
+        self.runtime.block_on(self.client.create_sip_rule(input))
+    }
+
+    /// Blocking version of [`Chime::create_user`].
+    pub fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> Result<CreateUserResponse, RusotoError<CreateUserError>> {
+        self.runtime.block_on(self.client.create_user(input))
+    }
+
+    /// Blocking version of [`Chime::create_voice_connector`].
+    pub fn create_voice_connector(
+        &self,
+        input: CreateVoiceConnectorRequest,
+    ) -> Result<CreateVoiceConnectorResponse, RusotoError<CreateVoiceConnectorError>> {
+        self.runtime
+            .block_on(self.client.create_voice_connector(input))
+    }
+
+    /// Blocking version of [`Chime::create_voice_connector_group`].
+    pub fn create_voice_connector_group(
+        &self,
+        input: CreateVoiceConnectorGroupRequest,
+    ) -> Result<CreateVoiceConnectorGroupResponse, RusotoError<CreateVoiceConnectorGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.create_voice_connector_group(input))
+    }
+
+    /// Blocking version of [`Chime::delete_account`].
+    pub fn delete_account(
+        &self,
+        input: DeleteAccountRequest,
+    ) -> Result<DeleteAccountResponse, RusotoError<DeleteAccountError>> {
+        self.runtime.block_on(self.client.delete_account(input))
+    }
+
+    /// Blocking version of [`Chime::delete_app_instance`].
+    pub fn delete_app_instance(
+        &self,
+        input: DeleteAppInstanceRequest,
+    ) -> Result<(), RusotoError<DeleteAppInstanceError>> {
+        self.runtime
+            .block_on(self.client.delete_app_instance(input))
+    }
+
+    /// Blocking version of [`Chime::delete_app_instance_admin`].
+    pub fn delete_app_instance_admin(
+        &self,
+        input: DeleteAppInstanceAdminRequest,
+    ) -> Result<(), RusotoError<DeleteAppInstanceAdminError>> {
+        self.runtime
+            .block_on(self.client.delete_app_instance_admin(input))
+    }
+
+    /// Blocking version of [`Chime::delete_app_instance_streaming_configurations`].
+    pub fn delete_app_instance_streaming_configurations(
+        &self,
+        input: DeleteAppInstanceStreamingConfigurationsRequest,
+    ) -> Result<(), RusotoError<DeleteAppInstanceStreamingConfigurationsError>> {
+        self.runtime.block_on(
+            self.client
+                .delete_app_instance_streaming_configurations(input),
+        )
+    }
+
+    /// Blocking version of [`Chime::delete_app_instance_user`].
+    pub fn delete_app_instance_user(
+        &self,
+        input: DeleteAppInstanceUserRequest,
+    ) -> Result<(), RusotoError<DeleteAppInstanceUserError>> {
+        self.runtime
+            .block_on(self.client.delete_app_instance_user(input))
+    }
+
+    /// Blocking version of [`Chime::delete_attendee`].
+    pub fn delete_attendee(
+        &self,
+        input: DeleteAttendeeRequest,
+    ) -> Result<(), RusotoError<DeleteAttendeeError>> {
+        self.runtime.block_on(self.client.delete_attendee(input))
+    }
+
+    /// Blocking version of [`Chime::delete_channel`].
+    pub fn delete_channel(
+        &self,
+        input: DeleteChannelRequest,
+    ) -> Result<(), RusotoError<DeleteChannelError>> {
+        self.runtime.block_on(self.client.delete_channel(input))
+    }
+
+    /// Blocking version of [`Chime::delete_channel_ban`].
+    pub fn delete_channel_ban(
+        &self,
+        input: DeleteChannelBanRequest,
+    ) -> Result<(), RusotoError<DeleteChannelBanError>> {
+        self.runtime.block_on(self.client.delete_channel_ban(input))
+    }
+
+    /// Blocking version of [`Chime::delete_channel_membership`].
+    pub fn delete_channel_membership(
+        &self,
+        input: DeleteChannelMembershipRequest,
+    ) -> Result<(), RusotoError<DeleteChannelMembershipError>> {
+        self.runtime
+            .block_on(self.client.delete_channel_membership(input))
+    }
+
+    /// Blocking version of [`Chime::delete_channel_message`].
+    pub fn delete_channel_message(
+        &self,
+        input: DeleteChannelMessageRequest,
+    ) -> Result<(), RusotoError<DeleteChannelMessageError>> {
+        self.runtime
+            .block_on(self.client.delete_channel_message(input))
+    }
+
+    /// Blocking version of [`Chime::delete_channel_moderator`].
+    pub fn delete_channel_moderator(
+        &self,
+        input: DeleteChannelModeratorRequest,
+    ) -> Result<(), RusotoError<DeleteChannelModeratorError>> {
+        self.runtime
+            .block_on(self.client.delete_channel_moderator(input))
+    }
+
+    /// Blocking version of [`Chime::delete_events_configuration`].
+    pub fn delete_events_configuration(
+        &self,
+        input: DeleteEventsConfigurationRequest,
+    ) -> Result<(), RusotoError<DeleteEventsConfigurationError>> {
+        self.runtime
+            .block_on(self.client.delete_events_configuration(input))
+    }
+
+    /// Blocking version of [`Chime::delete_meeting`].
+    pub fn delete_meeting(
+        &self,
+        input: DeleteMeetingRequest,
+    ) -> Result<(), RusotoError<DeleteMeetingError>> {
+        self.runtime.block_on(self.client.delete_meeting(input))
+    }
+
+    /// Blocking version of [`Chime::delete_phone_number`].
+    pub fn delete_phone_number(
+        &self,
+        input: DeletePhoneNumberRequest,
+    ) -> Result<(), RusotoError<DeletePhoneNumberError>> {
+        self.runtime
+            .block_on(self.client.delete_phone_number(input))
+    }
+
+    /// Blocking version of [`Chime::delete_proxy_session`].
+    pub fn delete_proxy_session(
+        &self,
+        input: DeleteProxySessionRequest,
+    ) -> Result<(), RusotoError<DeleteProxySessionError>> {
+        self.runtime
+            .block_on(self.client.delete_proxy_session(input))
+    }
+
+    /// Blocking version of [`Chime::delete_room`].
+    pub fn delete_room(
+        &self,
+        input: DeleteRoomRequest,
+    ) -> Result<(), RusotoError<DeleteRoomError>> {
+        self.runtime.block_on(self.client.delete_room(input))
+    }
+
+    /// Blocking version of [`Chime::delete_room_membership`].
+    pub fn delete_room_membership(
+        &self,
+        input: DeleteRoomMembershipRequest,
+    ) -> Result<(), RusotoError<DeleteRoomMembershipError>> {
+        self.runtime
+            .block_on(self.client.delete_room_membership(input))
+    }
+
+    /// Blocking version of [`Chime::delete_sip_media_application`].
+    pub fn delete_sip_media_application(
+        &self,
+        input: DeleteSipMediaApplicationRequest,
+    ) -> Result<(), RusotoError<DeleteSipMediaApplicationError>> {
+        self.runtime
+            .block_on(self.client.delete_sip_media_application(input))
+    }
+
+    /// Blocking version of [`Chime::delete_sip_rule`].
+    pub fn delete_sip_rule(
+        &self,
+        input: DeleteSipRuleRequest,
+    ) -> Result<(), RusotoError<DeleteSipRuleError>> {
+        self.runtime.block_on(self.client.delete_sip_rule(input))
+    }
+
+    /// Blocking version of [`Chime::delete_voice_connector`].
+    pub fn delete_voice_connector(
+        &self,
+        input: DeleteVoiceConnectorRequest,
+    ) -> Result<(), RusotoError<DeleteVoiceConnectorError>> {
+        self.runtime
+            .block_on(self.client.delete_voice_connector(input))
+    }
+
+    /// Blocking version of [`Chime::delete_voice_connector_emergency_calling_configuration`].
+    pub fn delete_voice_connector_emergency_calling_configuration(
+        &self,
+        input: DeleteVoiceConnectorEmergencyCallingConfigurationRequest,
+    ) -> Result<(), RusotoError<DeleteVoiceConnectorEmergencyCallingConfigurationError>> {
+        self.runtime.block_on(
+            self.client
+                .delete_voice_connector_emergency_calling_configuration(input),
+        )
+    }
+
+    /// Blocking version of [`Chime::delete_voice_connector_group`].
+    pub fn delete_voice_connector_group(
+        &self,
+        input: DeleteVoiceConnectorGroupRequest,
+    ) -> Result<(), RusotoError<DeleteVoiceConnectorGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_voice_connector_group(input))
+    }
+
+    /// Blocking version of [`Chime::delete_voice_connector_origination`].
+    pub fn delete_voice_connector_origination(
+        &self,
+        input: DeleteVoiceConnectorOriginationRequest,
+    ) -> Result<(), RusotoError<DeleteVoiceConnectorOriginationError>> {
+        self.runtime
+            .block_on(self.client.delete_voice_connector_origination(input))
+    }
+
+    /// Blocking version of [`Chime::delete_voice_connector_proxy`].
+    pub fn delete_voice_connector_proxy(
+        &self,
+        input: DeleteVoiceConnectorProxyRequest,
+    ) -> Result<(), RusotoError<DeleteVoiceConnectorProxyError>> {
+        self.runtime
+            .block_on(self.client.delete_voice_connector_proxy(input))
+    }
+
+    /// Blocking version of [`Chime::delete_voice_connector_streaming_configuration`].
+    pub fn delete_voice_connector_streaming_configuration(
+        &self,
+        input: DeleteVoiceConnectorStreamingConfigurationRequest,
+    ) -> Result<(), RusotoError<DeleteVoiceConnectorStreamingConfigurationError>> {
+        self.runtime.block_on(
+            self.client
+                .delete_voice_connector_streaming_configuration(input),
+        )
+    }
+
+    /// Blocking version of [`Chime::delete_voice_connector_termination`].
+    pub fn delete_voice_connector_termination(
+        &self,
+        input: DeleteVoiceConnectorTerminationRequest,
+    ) -> Result<(), RusotoError<DeleteVoiceConnectorTerminationError>> {
+        self.runtime
+            .block_on(self.client.delete_voice_connector_termination(input))
+    }
+
+    /// Blocking version of [`Chime::delete_voice_connector_termination_credentials`].
+    pub fn delete_voice_connector_termination_credentials(
+        &self,
+        input: DeleteVoiceConnectorTerminationCredentialsRequest,
+    ) -> Result<(), RusotoError<DeleteVoiceConnectorTerminationCredentialsError>> {
+        self.runtime.block_on(
+            self.client
+                .delete_voice_connector_termination_credentials(input),
+        )
+    }
+
+    /// Blocking version of [`Chime::describe_app_instance`].
+    pub fn describe_app_instance(
+        &self,
+        input: DescribeAppInstanceRequest,
+    ) -> Result<DescribeAppInstanceResponse, RusotoError<DescribeAppInstanceError>> {
+        self.runtime
+            .block_on(self.client.describe_app_instance(input))
+    }
+
+    /// Blocking version of [`Chime::describe_app_instance_admin`].
+    pub fn describe_app_instance_admin(
+        &self,
+        input: DescribeAppInstanceAdminRequest,
+    ) -> Result<DescribeAppInstanceAdminResponse, RusotoError<DescribeAppInstanceAdminError>> {
+        self.runtime
+            .block_on(self.client.describe_app_instance_admin(input))
+    }
+
+    /// Blocking version of [`Chime::describe_app_instance_user`].
+    pub fn describe_app_instance_user(
+        &self,
+        input: DescribeAppInstanceUserRequest,
+    ) -> Result<DescribeAppInstanceUserResponse, RusotoError<DescribeAppInstanceUserError>> {
+        self.runtime
+            .block_on(self.client.describe_app_instance_user(input))
+    }
+
+    /// Blocking version of [`Chime::describe_channel`].
+    pub fn describe_channel(
+        &self,
+        input: DescribeChannelRequest,
+    ) -> Result<DescribeChannelResponse, RusotoError<DescribeChannelError>> {
+        self.runtime.block_on(self.client.describe_channel(input))
+    }
+
+    /// Blocking version of [`Chime::describe_channel_ban`].
+    pub fn describe_channel_ban(
+        &self,
+        input: DescribeChannelBanRequest,
+    ) -> Result<DescribeChannelBanResponse, RusotoError<DescribeChannelBanError>> {
+        self.runtime
+            .block_on(self.client.describe_channel_ban(input))
+    }
+
+    /// Blocking version of [`Chime::describe_channel_membership`].
+    pub fn describe_channel_membership(
+        &self,
+        input: DescribeChannelMembershipRequest,
+    ) -> Result<DescribeChannelMembershipResponse, RusotoError<DescribeChannelMembershipError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_channel_membership(input))
+    }
+
+    /// Blocking version of [`Chime::describe_channel_membership_for_app_instance_user`].
+    pub fn describe_channel_membership_for_app_instance_user(
+        &self,
+        input: DescribeChannelMembershipForAppInstanceUserRequest,
+    ) -> Result<
+        DescribeChannelMembershipForAppInstanceUserResponse,
+        RusotoError<DescribeChannelMembershipForAppInstanceUserError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_channel_membership_for_app_instance_user(input),
+        )
+    }
+
+    /// Blocking version of [`Chime::describe_channel_moderated_by_app_instance_user`].
+    pub fn describe_channel_moderated_by_app_instance_user(
+        &self,
+        input: DescribeChannelModeratedByAppInstanceUserRequest,
+    ) -> Result<
+        DescribeChannelModeratedByAppInstanceUserResponse,
+        RusotoError<DescribeChannelModeratedByAppInstanceUserError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_channel_moderated_by_app_instance_user(input),
+        )
+    }
+
+    /// Blocking version of [`Chime::describe_channel_moderator`].
+    pub fn describe_channel_moderator(
+        &self,
+        input: DescribeChannelModeratorRequest,
+    ) -> Result<DescribeChannelModeratorResponse, RusotoError<DescribeChannelModeratorError>> {
+        self.runtime
+            .block_on(self.client.describe_channel_moderator(input))
+    }
+
+    /// Blocking version of [`Chime::disassociate_phone_number_from_user`].
+    pub fn disassociate_phone_number_from_user(
+        &self,
+        input: DisassociatePhoneNumberFromUserRequest,
+    ) -> Result<
+        DisassociatePhoneNumberFromUserResponse,
+        RusotoError<DisassociatePhoneNumberFromUserError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_phone_number_from_user(input))
+    }
+
+    /// Blocking version of [`Chime::disassociate_phone_numbers_from_voice_connector`].
+    pub fn disassociate_phone_numbers_from_voice_connector(
+        &self,
+        input: DisassociatePhoneNumbersFromVoiceConnectorRequest,
+    ) -> Result<
+        DisassociatePhoneNumbersFromVoiceConnectorResponse,
+        RusotoError<DisassociatePhoneNumbersFromVoiceConnectorError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .disassociate_phone_numbers_from_voice_connector(input),
+        )
+    }
+
+    /// Blocking version of [`Chime::disassociate_phone_numbers_from_voice_connector_group`].
+    pub fn disassociate_phone_numbers_from_voice_connector_group(
+        &self,
+        input: DisassociatePhoneNumbersFromVoiceConnectorGroupRequest,
+    ) -> Result<
+        DisassociatePhoneNumbersFromVoiceConnectorGroupResponse,
+        RusotoError<DisassociatePhoneNumbersFromVoiceConnectorGroupError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .disassociate_phone_numbers_from_voice_connector_group(input),
+        )
+    }
+
+    /// Blocking version of [`Chime::disassociate_signin_delegate_groups_from_account`].
+    pub fn disassociate_signin_delegate_groups_from_account(
+        &self,
+        input: DisassociateSigninDelegateGroupsFromAccountRequest,
+    ) -> Result<
+        DisassociateSigninDelegateGroupsFromAccountResponse,
+        RusotoError<DisassociateSigninDelegateGroupsFromAccountError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .disassociate_signin_delegate_groups_from_account(input),
+        )
+    }
+
+    /// Blocking version of [`Chime::get_account`].
+    pub fn get_account(
+        &self,
+        input: GetAccountRequest,
+    ) -> Result<GetAccountResponse, RusotoError<GetAccountError>> {
+        self.runtime.block_on(self.client.get_account(input))
+    }
+
+    /// Blocking version of [`Chime::get_account_settings`].
+    pub fn get_account_settings(
+        &self,
+        input: GetAccountSettingsRequest,
+    ) -> Result<GetAccountSettingsResponse, RusotoError<GetAccountSettingsError>> {
+        self.runtime
+            .block_on(self.client.get_account_settings(input))
+    }
+
+    /// Blocking version of [`Chime::get_app_instance_retention_settings`].
+    pub fn get_app_instance_retention_settings(
+        &self,
+        input: GetAppInstanceRetentionSettingsRequest,
+    ) -> Result<
+        GetAppInstanceRetentionSettingsResponse,
+        RusotoError<GetAppInstanceRetentionSettingsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_app_instance_retention_settings(input))
+    }
+
+    /// Blocking version of [`Chime::get_app_instance_streaming_configurations`].
+    pub fn get_app_instance_streaming_configurations(
+        &self,
+        input: GetAppInstanceStreamingConfigurationsRequest,
+    ) -> Result<
+        GetAppInstanceStreamingConfigurationsResponse,
+        RusotoError<GetAppInstanceStreamingConfigurationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_app_instance_streaming_configurations(input))
+    }
+
+    /// Blocking version of [`Chime::get_attendee`].
+    pub fn get_attendee(
+        &self,
+        input: GetAttendeeRequest,
+    ) -> Result<GetAttendeeResponse, RusotoError<GetAttendeeError>> {
+        self.runtime.block_on(self.client.get_attendee(input))
+    }
+
+    /// Blocking version of [`Chime::get_bot`].
+    pub fn get_bot(
+        &self,
+        input: GetBotRequest,
+    ) -> Result<GetBotResponse, RusotoError<GetBotError>> {
+        self.runtime.block_on(self.client.get_bot(input))
+    }
+
+    /// Blocking version of [`Chime::get_channel_message`].
+    pub fn get_channel_message(
+        &self,
+        input: GetChannelMessageRequest,
+    ) -> Result<GetChannelMessageResponse, RusotoError<GetChannelMessageError>> {
+        self.runtime
+            .block_on(self.client.get_channel_message(input))
+    }
+
+    /// Blocking version of [`Chime::get_events_configuration`].
+    pub fn get_events_configuration(
+        &self,
+        input: GetEventsConfigurationRequest,
+    ) -> Result<GetEventsConfigurationResponse, RusotoError<GetEventsConfigurationError>> {
+        self.runtime
+            .block_on(self.client.get_events_configuration(input))
+    }
+
+    /// Blocking version of [`Chime::get_global_settings`].
+    pub fn get_global_settings(
+        &self,
+    ) -> Result<GetGlobalSettingsResponse, RusotoError<GetGlobalSettingsError>> {
+        self.runtime.block_on(self.client.get_global_settings())
+    }
+
+    /// Blocking version of [`Chime::get_meeting`].
+    pub fn get_meeting(
+        &self,
+        input: GetMeetingRequest,
+    ) -> Result<GetMeetingResponse, RusotoError<GetMeetingError>> {
+        self.runtime.block_on(self.client.get_meeting(input))
+    }
+
+    /// Blocking version of [`Chime::get_messaging_session_endpoint`].
+    pub fn get_messaging_session_endpoint(
+        &self,
+    ) -> Result<GetMessagingSessionEndpointResponse, RusotoError<GetMessagingSessionEndpointError>>
+    {
+        self.runtime
+            .block_on(self.client.get_messaging_session_endpoint())
+    }
+
+    /// Blocking version of [`Chime::get_phone_number`].
+    pub fn get_phone_number(
+        &self,
+        input: GetPhoneNumberRequest,
+    ) -> Result<GetPhoneNumberResponse, RusotoError<GetPhoneNumberError>> {
+        self.runtime.block_on(self.client.get_phone_number(input))
+    }
+
+    /// Blocking version of [`Chime::get_phone_number_order`].
+    pub fn get_phone_number_order(
+        &self,
+        input: GetPhoneNumberOrderRequest,
+    ) -> Result<GetPhoneNumberOrderResponse, RusotoError<GetPhoneNumberOrderError>> {
+        self.runtime
+            .block_on(self.client.get_phone_number_order(input))
+    }
+
+    /// Blocking version of [`Chime::get_phone_number_settings`].
+    pub fn get_phone_number_settings(
+        &self,
+    ) -> Result<GetPhoneNumberSettingsResponse, RusotoError<GetPhoneNumberSettingsError>> {
+        self.runtime
+            .block_on(self.client.get_phone_number_settings())
+    }
+
+    /// Blocking version of [`Chime::get_proxy_session`].
+    pub fn get_proxy_session(
+        &self,
+        input: GetProxySessionRequest,
+    ) -> Result<GetProxySessionResponse, RusotoError<GetProxySessionError>> {
+        self.runtime.block_on(self.client.get_proxy_session(input))
+    }
+
+    /// Blocking version of [`Chime::get_retention_settings`].
+    pub fn get_retention_settings(
+        &self,
+        input: GetRetentionSettingsRequest,
+    ) -> Result<GetRetentionSettingsResponse, RusotoError<GetRetentionSettingsError>> {
+        self.runtime
+            .block_on(self.client.get_retention_settings(input))
+    }
+
+    /// Blocking version of [`Chime::get_room`].
+    pub fn get_room(
+        &self,
+        input: GetRoomRequest,
+    ) -> Result<GetRoomResponse, RusotoError<GetRoomError>> {
+        self.runtime.block_on(self.client.get_room(input))
+    }
+
+    /// Blocking version of [`Chime::get_sip_media_application`].
+    pub fn get_sip_media_application(
+        &self,
+        input: GetSipMediaApplicationRequest,
+    ) -> Result<GetSipMediaApplicationResponse, RusotoError<GetSipMediaApplicationError>> {
+        self.runtime
+            .block_on(self.client.get_sip_media_application(input))
+    }
+
+    /// Blocking version of [`Chime::get_sip_media_application_logging_configuration`].
+    pub fn get_sip_media_application_logging_configuration(
+        &self,
+        input: GetSipMediaApplicationLoggingConfigurationRequest,
+    ) -> Result<
+        GetSipMediaApplicationLoggingConfigurationResponse,
+        RusotoError<GetSipMediaApplicationLoggingConfigurationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .get_sip_media_application_logging_configuration(input),
+        )
+    }
+
+    /// Blocking version of [`Chime::get_sip_rule`].
+    pub fn get_sip_rule(
+        &self,
+        input: GetSipRuleRequest,
+    ) -> Result<GetSipRuleResponse, RusotoError<GetSipRuleError>> {
+        self.runtime.block_on(self.client.get_sip_rule(input))
+    }
+
+    /// Blocking version of [`Chime::get_user`].
+    pub fn get_user(
+        &self,
+        input: GetUserRequest,
+    ) -> Result<GetUserResponse, RusotoError<GetUserError>> {
+        self.runtime.block_on(self.client.get_user(input))
+    }
+
+    /// Blocking version of [`Chime::get_user_settings`].
+    pub fn get_user_settings(
+        &self,
+        input: GetUserSettingsRequest,
+    ) -> Result<GetUserSettingsResponse, RusotoError<GetUserSettingsError>> {
+        self.runtime.block_on(self.client.get_user_settings(input))
+    }
+
+    /// Blocking version of [`Chime::get_voice_connector`].
+    pub fn get_voice_connector(
+        &self,
+        input: GetVoiceConnectorRequest,
+    ) -> Result<GetVoiceConnectorResponse, RusotoError<GetVoiceConnectorError>> {
+        self.runtime
+            .block_on(self.client.get_voice_connector(input))
+    }
+
+    /// Blocking version of [`Chime::get_voice_connector_emergency_calling_configuration`].
+    pub fn get_voice_connector_emergency_calling_configuration(
+        &self,
+        input: GetVoiceConnectorEmergencyCallingConfigurationRequest,
+    ) -> Result<
+        GetVoiceConnectorEmergencyCallingConfigurationResponse,
+        RusotoError<GetVoiceConnectorEmergencyCallingConfigurationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .get_voice_connector_emergency_calling_configuration(input),
+        )
+    }
+
+    /// Blocking version of [`Chime::get_voice_connector_group`].
+    pub fn get_voice_connector_group(
+        &self,
+        input: GetVoiceConnectorGroupRequest,
+    ) -> Result<GetVoiceConnectorGroupResponse, RusotoError<GetVoiceConnectorGroupError>> {
+        self.runtime
+            .block_on(self.client.get_voice_connector_group(input))
+    }
+
+    /// Blocking version of [`Chime::get_voice_connector_logging_configuration`].
+    pub fn get_voice_connector_logging_configuration(
+        &self,
+        input: GetVoiceConnectorLoggingConfigurationRequest,
+    ) -> Result<
+        GetVoiceConnectorLoggingConfigurationResponse,
+        RusotoError<GetVoiceConnectorLoggingConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_voice_connector_logging_configuration(input))
+    }
+
+    /// Blocking version of [`Chime::get_voice_connector_origination`].
+    pub fn get_voice_connector_origination(
+        &self,
+        input: GetVoiceConnectorOriginationRequest,
+    ) -> Result<GetVoiceConnectorOriginationResponse, RusotoError<GetVoiceConnectorOriginationError>>
+    {
+        self.runtime
+            .block_on(self.client.get_voice_connector_origination(input))
+    }
+
+    /// Blocking version of [`Chime::get_voice_connector_proxy`].
+    pub fn get_voice_connector_proxy(
+        &self,
+        input: GetVoiceConnectorProxyRequest,
+    ) -> Result<GetVoiceConnectorProxyResponse, RusotoError<GetVoiceConnectorProxyError>> {
+        self.runtime
+            .block_on(self.client.get_voice_connector_proxy(input))
+    }
+
+    /// Blocking version of [`Chime::get_voice_connector_streaming_configuration`].
+    pub fn get_voice_connector_streaming_configuration(
+        &self,
+        input: GetVoiceConnectorStreamingConfigurationRequest,
+    ) -> Result<
+        GetVoiceConnectorStreamingConfigurationResponse,
+        RusotoError<GetVoiceConnectorStreamingConfigurationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .get_voice_connector_streaming_configuration(input),
+        )
+    }
+
+    /// Blocking version of [`Chime::get_voice_connector_termination`].
+    pub fn get_voice_connector_termination(
+        &self,
+        input: GetVoiceConnectorTerminationRequest,
+    ) -> Result<GetVoiceConnectorTerminationResponse, RusotoError<GetVoiceConnectorTerminationError>>
+    {
+        self.runtime
+            .block_on(self.client.get_voice_connector_termination(input))
+    }
+
+    /// Blocking version of [`Chime::get_voice_connector_termination_health`].
+    pub fn get_voice_connector_termination_health(
+        &self,
+        input: GetVoiceConnectorTerminationHealthRequest,
+    ) -> Result<
+        GetVoiceConnectorTerminationHealthResponse,
+        RusotoError<GetVoiceConnectorTerminationHealthError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_voice_connector_termination_health(input))
+    }
+
+    /// Blocking version of [`Chime::invite_users`].
+    pub fn invite_users(
+        &self,
+        input: InviteUsersRequest,
+    ) -> Result<InviteUsersResponse, RusotoError<InviteUsersError>> {
+        self.runtime.block_on(self.client.invite_users(input))
+    }
+
+    /// Blocking version of [`Chime::list_accounts`].
+    pub fn list_accounts(
+        &self,
+        input: ListAccountsRequest,
+    ) -> Result<ListAccountsResponse, RusotoError<ListAccountsError>> {
+        self.runtime.block_on(self.client.list_accounts(input))
+    }
+
+    /// Blocking version of [`Chime::list_app_instance_admins`].
+    pub fn list_app_instance_admins(
+        &self,
+        input: ListAppInstanceAdminsRequest,
+    ) -> Result<ListAppInstanceAdminsResponse, RusotoError<ListAppInstanceAdminsError>> {
+        self.runtime
+            .block_on(self.client.list_app_instance_admins(input))
+    }
+
+    /// Blocking version of [`Chime::list_app_instance_users`].
+    pub fn list_app_instance_users(
+        &self,
+        input: ListAppInstanceUsersRequest,
+    ) -> Result<ListAppInstanceUsersResponse, RusotoError<ListAppInstanceUsersError>> {
+        self.runtime
+            .block_on(self.client.list_app_instance_users(input))
+    }
+
+    /// Blocking version of [`Chime::list_app_instances`].
+    pub fn list_app_instances(
+        &self,
+        input: ListAppInstancesRequest,
+    ) -> Result<ListAppInstancesResponse, RusotoError<ListAppInstancesError>> {
+        self.runtime.block_on(self.client.list_app_instances(input))
+    }
+
+    /// Blocking version of [`Chime::list_attendee_tags`].
+    pub fn list_attendee_tags(
+        &self,
+        input: ListAttendeeTagsRequest,
+    ) -> Result<ListAttendeeTagsResponse, RusotoError<ListAttendeeTagsError>> {
+        self.runtime.block_on(self.client.list_attendee_tags(input))
+    }
+
+    /// Blocking version of [`Chime::list_attendees`].
+    pub fn list_attendees(
+        &self,
+        input: ListAttendeesRequest,
+    ) -> Result<ListAttendeesResponse, RusotoError<ListAttendeesError>> {
+        self.runtime.block_on(self.client.list_attendees(input))
+    }
+
+    /// Blocking version of [`Chime::list_bots`].
+    pub fn list_bots(
+        &self,
+        input: ListBotsRequest,
+    ) -> Result<ListBotsResponse, RusotoError<ListBotsError>> {
+        self.runtime.block_on(self.client.list_bots(input))
+    }
+
+    /// Blocking version of [`Chime::list_channel_bans`].
+    pub fn list_channel_bans(
+        &self,
+        input: ListChannelBansRequest,
+    ) -> Result<ListChannelBansResponse, RusotoError<ListChannelBansError>> {
+        self.runtime.block_on(self.client.list_channel_bans(input))
+    }
+
+    /// Blocking version of [`Chime::list_channel_memberships`].
+    pub fn list_channel_memberships(
+        &self,
+        input: ListChannelMembershipsRequest,
+    ) -> Result<ListChannelMembershipsResponse, RusotoError<ListChannelMembershipsError>> {
+        self.runtime
+            .block_on(self.client.list_channel_memberships(input))
+    }
+
+    /// Blocking version of [`Chime::list_channel_memberships_for_app_instance_user`].
+    pub fn list_channel_memberships_for_app_instance_user(
+        &self,
+        input: ListChannelMembershipsForAppInstanceUserRequest,
+    ) -> Result<
+        ListChannelMembershipsForAppInstanceUserResponse,
+        RusotoError<ListChannelMembershipsForAppInstanceUserError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .list_channel_memberships_for_app_instance_user(input),
+        )
+    }
+
+    /// Blocking version of [`Chime::list_channel_messages`].
+    pub fn list_channel_messages(
+        &self,
+        input: ListChannelMessagesRequest,
+    ) -> Result<ListChannelMessagesResponse, RusotoError<ListChannelMessagesError>> {
+        self.runtime
+            .block_on(self.client.list_channel_messages(input))
+    }
+
+    /// Blocking version of [`Chime::list_channel_moderators`].
+    pub fn list_channel_moderators(
+        &self,
+        input: ListChannelModeratorsRequest,
+    ) -> Result<ListChannelModeratorsResponse, RusotoError<ListChannelModeratorsError>> {
+        self.runtime
+            .block_on(self.client.list_channel_moderators(input))
+    }
+
+    /// Blocking version of [`Chime::list_channels`].
+    pub fn list_channels(
+        &self,
+        input: ListChannelsRequest,
+    ) -> Result<ListChannelsResponse, RusotoError<ListChannelsError>> {
+        self.runtime.block_on(self.client.list_channels(input))
+    }
+
+    /// Blocking version of [`Chime::list_channels_moderated_by_app_instance_user`].
+    pub fn list_channels_moderated_by_app_instance_user(
+        &self,
+        input: ListChannelsModeratedByAppInstanceUserRequest,
+    ) -> Result<
+        ListChannelsModeratedByAppInstanceUserResponse,
+        RusotoError<ListChannelsModeratedByAppInstanceUserError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .list_channels_moderated_by_app_instance_user(input),
+        )
+    }
+
+    /// Blocking version of [`Chime::list_meeting_tags`].
+    pub fn list_meeting_tags(
+        &self,
+        input: ListMeetingTagsRequest,
+    ) -> Result<ListMeetingTagsResponse, RusotoError<ListMeetingTagsError>> {
+        self.runtime.block_on(self.client.list_meeting_tags(input))
+    }
+
+    /// Blocking version of [`Chime::list_meetings`].
+    pub fn list_meetings(
+        &self,
+        input: ListMeetingsRequest,
+    ) -> Result<ListMeetingsResponse, RusotoError<ListMeetingsError>> {
+        self.runtime.block_on(self.client.list_meetings(input))
+    }
+
+    /// Blocking version of [`Chime::list_phone_number_orders`].
+    pub fn list_phone_number_orders(
+        &self,
+        input: ListPhoneNumberOrdersRequest,
+    ) -> Result<ListPhoneNumberOrdersResponse, RusotoError<ListPhoneNumberOrdersError>> {
+        self.runtime
+            .block_on(self.client.list_phone_number_orders(input))
+    }
+
+    /// Blocking version of [`Chime::list_phone_numbers`].
+    pub fn list_phone_numbers(
+        &self,
+        input: ListPhoneNumbersRequest,
+    ) -> Result<ListPhoneNumbersResponse, RusotoError<ListPhoneNumbersError>> {
+        self.runtime.block_on(self.client.list_phone_numbers(input))
+    }
+
+    /// Blocking version of [`Chime::list_proxy_sessions`].
+    pub fn list_proxy_sessions(
+        &self,
+        input: ListProxySessionsRequest,
+    ) -> Result<ListProxySessionsResponse, RusotoError<ListProxySessionsError>> {
+        self.runtime
+            .block_on(self.client.list_proxy_sessions(input))
+    }
+
+    /// Blocking version of [`Chime::list_room_memberships`].
+    pub fn list_room_memberships(
+        &self,
+        input: ListRoomMembershipsRequest,
+    ) -> Result<ListRoomMembershipsResponse, RusotoError<ListRoomMembershipsError>> {
+        self.runtime
+            .block_on(self.client.list_room_memberships(input))
+    }
+
+    /// Blocking version of [`Chime::list_rooms`].
+    pub fn list_rooms(
+        &self,
+        input: ListRoomsRequest,
+    ) -> Result<ListRoomsResponse, RusotoError<ListRoomsError>> {
+        self.runtime.block_on(self.client.list_rooms(input))
+    }
+
+    /// Blocking version of [`Chime::list_sip_media_applications`].
+    pub fn list_sip_media_applications(
+        &self,
+        input: ListSipMediaApplicationsRequest,
+    ) -> Result<ListSipMediaApplicationsResponse, RusotoError<ListSipMediaApplicationsError>> {
+        self.runtime
+            .block_on(self.client.list_sip_media_applications(input))
+    }
+
+    /// Blocking version of [`Chime::list_sip_rules`].
+    pub fn list_sip_rules(
+        &self,
+        input: ListSipRulesRequest,
+    ) -> Result<ListSipRulesResponse, RusotoError<ListSipRulesError>> {
+        self.runtime.block_on(self.client.list_sip_rules(input))
+    }
+
+    /// Blocking version of [`Chime::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`Chime::list_users`].
+    pub fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> Result<ListUsersResponse, RusotoError<ListUsersError>> {
+        self.runtime.block_on(self.client.list_users(input))
+    }
+
+    /// Blocking version of [`Chime::list_voice_connector_groups`].
+    pub fn list_voice_connector_groups(
+        &self,
+        input: ListVoiceConnectorGroupsRequest,
+    ) -> Result<ListVoiceConnectorGroupsResponse, RusotoError<ListVoiceConnectorGroupsError>> {
+        self.runtime
+            .block_on(self.client.list_voice_connector_groups(input))
+    }
+
+    /// Blocking version of [`Chime::list_voice_connector_termination_credentials`].
+    pub fn list_voice_connector_termination_credentials(
+        &self,
+        input: ListVoiceConnectorTerminationCredentialsRequest,
+    ) -> Result<
+        ListVoiceConnectorTerminationCredentialsResponse,
+        RusotoError<ListVoiceConnectorTerminationCredentialsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .list_voice_connector_termination_credentials(input),
+        )
+    }
+
+    /// Blocking version of [`Chime::list_voice_connectors`].
+    pub fn list_voice_connectors(
+        &self,
+        input: ListVoiceConnectorsRequest,
+    ) -> Result<ListVoiceConnectorsResponse, RusotoError<ListVoiceConnectorsError>> {
+        self.runtime
+            .block_on(self.client.list_voice_connectors(input))
+    }
+
+    /// Blocking version of [`Chime::logout_user`].
+    pub fn logout_user(
+        &self,
+        input: LogoutUserRequest,
+    ) -> Result<LogoutUserResponse, RusotoError<LogoutUserError>> {
+        self.runtime.block_on(self.client.logout_user(input))
+    }
+
+    /// Blocking version of [`Chime::put_app_instance_retention_settings`].
+    pub fn put_app_instance_retention_settings(
+        &self,
+        input: PutAppInstanceRetentionSettingsRequest,
+    ) -> Result<
+        PutAppInstanceRetentionSettingsResponse,
+        RusotoError<PutAppInstanceRetentionSettingsError>,
+    > {
+        self.runtime
+            .block_on(self.client.put_app_instance_retention_settings(input))
+    }
+
+    /// Blocking version of [`Chime::put_app_instance_streaming_configurations`].
+    pub fn put_app_instance_streaming_configurations(
+        &self,
+        input: PutAppInstanceStreamingConfigurationsRequest,
+    ) -> Result<
+        PutAppInstanceStreamingConfigurationsResponse,
+        RusotoError<PutAppInstanceStreamingConfigurationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.put_app_instance_streaming_configurations(input))
+    }
+
+    /// Blocking version of [`Chime::put_events_configuration`].
+    pub fn put_events_configuration(
+        &self,
+        input: PutEventsConfigurationRequest,
+    ) -> Result<PutEventsConfigurationResponse, RusotoError<PutEventsConfigurationError>> {
+        self.runtime
+            .block_on(self.client.put_events_configuration(input))
+    }
+
+    /// Blocking version of [`Chime::put_retention_settings`].
+    pub fn put_retention_settings(
+        &self,
+        input: PutRetentionSettingsRequest,
+    ) -> Result<PutRetentionSettingsResponse, RusotoError<PutRetentionSettingsError>> {
+        self.runtime
+            .block_on(self.client.put_retention_settings(input))
+    }
+
+    /// Blocking version of [`Chime::put_sip_media_application_logging_configuration`].
+    pub fn put_sip_media_application_logging_configuration(
+        &self,
+        input: PutSipMediaApplicationLoggingConfigurationRequest,
+    ) -> Result<
+        PutSipMediaApplicationLoggingConfigurationResponse,
+        RusotoError<PutSipMediaApplicationLoggingConfigurationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .put_sip_media_application_logging_configuration(input),
+        )
+    }
+
+    /// Blocking version of [`Chime::put_voice_connector_emergency_calling_configuration`].
+    pub fn put_voice_connector_emergency_calling_configuration(
+        &self,
+        input: PutVoiceConnectorEmergencyCallingConfigurationRequest,
+    ) -> Result<
+        PutVoiceConnectorEmergencyCallingConfigurationResponse,
+        RusotoError<PutVoiceConnectorEmergencyCallingConfigurationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .put_voice_connector_emergency_calling_configuration(input),
+        )
+    }
+
+    /// Blocking version of [`Chime::put_voice_connector_logging_configuration`].
+    pub fn put_voice_connector_logging_configuration(
+        &self,
+        input: PutVoiceConnectorLoggingConfigurationRequest,
+    ) -> Result<
+        PutVoiceConnectorLoggingConfigurationResponse,
+        RusotoError<PutVoiceConnectorLoggingConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.put_voice_connector_logging_configuration(input))
+    }
+
+    /// Blocking version of [`Chime::put_voice_connector_origination`].
+    pub fn put_voice_connector_origination(
+        &self,
+        input: PutVoiceConnectorOriginationRequest,
+    ) -> Result<PutVoiceConnectorOriginationResponse, RusotoError<PutVoiceConnectorOriginationError>>
+    {
+        self.runtime
+            .block_on(self.client.put_voice_connector_origination(input))
+    }
+
+    /// Blocking version of [`Chime::put_voice_connector_proxy`].
+    pub fn put_voice_connector_proxy(
+        &self,
+        input: PutVoiceConnectorProxyRequest,
+    ) -> Result<PutVoiceConnectorProxyResponse, RusotoError<PutVoiceConnectorProxyError>> {
+        self.runtime
+            .block_on(self.client.put_voice_connector_proxy(input))
+    }
+
+    /// Blocking version of [`Chime::put_voice_connector_streaming_configuration`].
+    pub fn put_voice_connector_streaming_configuration(
+        &self,
+        input: PutVoiceConnectorStreamingConfigurationRequest,
+    ) -> Result<
+        PutVoiceConnectorStreamingConfigurationResponse,
+        RusotoError<PutVoiceConnectorStreamingConfigurationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .put_voice_connector_streaming_configuration(input),
+        )
+    }
+
+    /// Blocking version of [`Chime::put_voice_connector_termination`].
+    pub fn put_voice_connector_termination(
+        &self,
+        input: PutVoiceConnectorTerminationRequest,
+    ) -> Result<PutVoiceConnectorTerminationResponse, RusotoError<PutVoiceConnectorTerminationError>>
+    {
+        self.runtime
+            .block_on(self.client.put_voice_connector_termination(input))
+    }
+
+    /// Blocking version of [`Chime::put_voice_connector_termination_credentials`].
+    pub fn put_voice_connector_termination_credentials(
+        &self,
+        input: PutVoiceConnectorTerminationCredentialsRequest,
+    ) -> Result<(), RusotoError<PutVoiceConnectorTerminationCredentialsError>> {
+        self.runtime.block_on(
+            self.client
+                .put_voice_connector_termination_credentials(input),
+        )
+    }
+
+    /// Blocking version of [`Chime::redact_channel_message`].
+    pub fn redact_channel_message(
+        &self,
+        input: RedactChannelMessageRequest,
+    ) -> Result<RedactChannelMessageResponse, RusotoError<RedactChannelMessageError>> {
+        self.runtime
+            .block_on(self.client.redact_channel_message(input))
+    }
+
+    /// Blocking version of [`Chime::redact_conversation_message`].
+    pub fn redact_conversation_message(
+        &self,
+        input: RedactConversationMessageRequest,
+    ) -> Result<RedactConversationMessageResponse, RusotoError<RedactConversationMessageError>>
+    {
+        self.runtime
+            .block_on(self.client.redact_conversation_message(input))
+    }
+
+    /// Blocking version of [`Chime::redact_room_message`].
+    pub fn redact_room_message(
+        &self,
+        input: RedactRoomMessageRequest,
+    ) -> Result<RedactRoomMessageResponse, RusotoError<RedactRoomMessageError>> {
+        self.runtime
+            .block_on(self.client.redact_room_message(input))
+    }
+
+    /// Blocking version of [`Chime::regenerate_security_token`].
+    pub fn regenerate_security_token(
+        &self,
+        input: RegenerateSecurityTokenRequest,
+    ) -> Result<RegenerateSecurityTokenResponse, RusotoError<RegenerateSecurityTokenError>> {
+        self.runtime
+            .block_on(self.client.regenerate_security_token(input))
+    }
+
+    /// Blocking version of [`Chime::reset_personal_pin`].
+    pub fn reset_personal_pin(
+        &self,
+        input: ResetPersonalPINRequest,
+    ) -> Result<ResetPersonalPINResponse, RusotoError<ResetPersonalPINError>> {
+        self.runtime.block_on(self.client.reset_personal_pin(input))
+    }
+
+    /// Blocking version of [`Chime::restore_phone_number`].
+    pub fn restore_phone_number(
+        &self,
+        input: RestorePhoneNumberRequest,
+    ) -> Result<RestorePhoneNumberResponse, RusotoError<RestorePhoneNumberError>> {
+        self.runtime
+            .block_on(self.client.restore_phone_number(input))
+    }
+
+    /// Blocking version of [`Chime::search_available_phone_numbers`].
+    pub fn search_available_phone_numbers(
+        &self,
+        input: SearchAvailablePhoneNumbersRequest,
+    ) -> Result<SearchAvailablePhoneNumbersResponse, RusotoError<SearchAvailablePhoneNumbersError>>
+    {
+        self.runtime
+            .block_on(self.client.search_available_phone_numbers(input))
+    }
+
+    /// Blocking version of [`Chime::send_channel_message`].
+    pub fn send_channel_message(
+        &self,
+        input: SendChannelMessageRequest,
+    ) -> Result<SendChannelMessageResponse, RusotoError<SendChannelMessageError>> {
+        self.runtime
+            .block_on(self.client.send_channel_message(input))
+    }
+
+    /// Blocking version of [`Chime::tag_attendee`].
+    pub fn tag_attendee(
+        &self,
+        input: TagAttendeeRequest,
+    ) -> Result<(), RusotoError<TagAttendeeError>> {
+        self.runtime.block_on(self.client.tag_attendee(input))
+    }
+
+    /// Blocking version of [`Chime::tag_meeting`].
+    pub fn tag_meeting(
+        &self,
+        input: TagMeetingRequest,
+    ) -> Result<(), RusotoError<TagMeetingError>> {
+        self.runtime.block_on(self.client.tag_meeting(input))
+    }
+
+    /// Blocking version of [`Chime::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`Chime::untag_attendee`].
+    pub fn untag_attendee(
+        &self,
+        input: UntagAttendeeRequest,
+    ) -> Result<(), RusotoError<UntagAttendeeError>> {
+        self.runtime.block_on(self.client.untag_attendee(input))
+    }
+
+    /// Blocking version of [`Chime::untag_meeting`].
+    pub fn untag_meeting(
+        &self,
+        input: UntagMeetingRequest,
+    ) -> Result<(), RusotoError<UntagMeetingError>> {
+        self.runtime.block_on(self.client.untag_meeting(input))
+    }
+
+    /// Blocking version of [`Chime::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`Chime::update_account`].
+    pub fn update_account(
+        &self,
+        input: UpdateAccountRequest,
+    ) -> Result<UpdateAccountResponse, RusotoError<UpdateAccountError>> {
+        self.runtime.block_on(self.client.update_account(input))
+    }
+
+    /// Blocking version of [`Chime::update_account_settings`].
+    pub fn update_account_settings(
+        &self,
+        input: UpdateAccountSettingsRequest,
+    ) -> Result<UpdateAccountSettingsResponse, RusotoError<UpdateAccountSettingsError>> {
+        self.runtime
+            .block_on(self.client.update_account_settings(input))
+    }
+
+    /// Blocking version of [`Chime::update_app_instance`].
+    pub fn update_app_instance(
+        &self,
+        input: UpdateAppInstanceRequest,
+    ) -> Result<UpdateAppInstanceResponse, RusotoError<UpdateAppInstanceError>> {
+        self.runtime
+            .block_on(self.client.update_app_instance(input))
+    }
+
+    /// Blocking version of [`Chime::update_app_instance_user`].
+    pub fn update_app_instance_user(
+        &self,
+        input: UpdateAppInstanceUserRequest,
+    ) -> Result<UpdateAppInstanceUserResponse, RusotoError<UpdateAppInstanceUserError>> {
+        self.runtime
+            .block_on(self.client.update_app_instance_user(input))
+    }
+
+    /// Blocking version of [`Chime::update_bot`].
+    pub fn update_bot(
+        &self,
+        input: UpdateBotRequest,
+    ) -> Result<UpdateBotResponse, RusotoError<UpdateBotError>> {
+        self.runtime.block_on(self.client.update_bot(input))
+    }
+
+    /// Blocking version of [`Chime::update_channel`].
+    pub fn update_channel(
+        &self,
+        input: UpdateChannelRequest,
+    ) -> Result<UpdateChannelResponse, RusotoError<UpdateChannelError>> {
+        self.runtime.block_on(self.client.update_channel(input))
+    }
+
+    /// Blocking version of [`Chime::update_channel_message`].
+    pub fn update_channel_message(
+        &self,
+        input: UpdateChannelMessageRequest,
+    ) -> Result<UpdateChannelMessageResponse, RusotoError<UpdateChannelMessageError>> {
+        self.runtime
+            .block_on(self.client.update_channel_message(input))
+    }
+
+    /// Blocking version of [`Chime::update_channel_read_marker`].
+    pub fn update_channel_read_marker(
+        &self,
+        input: UpdateChannelReadMarkerRequest,
+    ) -> Result<UpdateChannelReadMarkerResponse, RusotoError<UpdateChannelReadMarkerError>> {
+        self.runtime
+            .block_on(self.client.update_channel_read_marker(input))
+    }
+
+    /// Blocking version of [`Chime::update_global_settings`].
+    pub fn update_global_settings(
+        &self,
+        input: UpdateGlobalSettingsRequest,
+    ) -> Result<(), RusotoError<UpdateGlobalSettingsError>> {
+        self.runtime
+            .block_on(self.client.update_global_settings(input))
+    }
+
+    /// Blocking version of [`Chime::update_phone_number`].
+    pub fn update_phone_number(
+        &self,
+        input: UpdatePhoneNumberRequest,
+    ) -> Result<UpdatePhoneNumberResponse, RusotoError<UpdatePhoneNumberError>> {
+        self.runtime
+            .block_on(self.client.update_phone_number(input))
+    }
+
+    /// Blocking version of [`Chime::update_phone_number_settings`].
+    pub fn update_phone_number_settings(
+        &self,
+        input: UpdatePhoneNumberSettingsRequest,
+    ) -> Result<(), RusotoError<UpdatePhoneNumberSettingsError>> {
+        self.runtime
+            .block_on(self.client.update_phone_number_settings(input))
+    }
+
+    /// Blocking version of [`Chime::update_proxy_session`].
+    pub fn update_proxy_session(
+        &self,
+        input: UpdateProxySessionRequest,
+    ) -> Result<UpdateProxySessionResponse, RusotoError<UpdateProxySessionError>> {
+        self.runtime
+            .block_on(self.client.update_proxy_session(input))
+    }
+
+    /// Blocking version of [`Chime::update_room`].
+    pub fn update_room(
+        &self,
+        input: UpdateRoomRequest,
+    ) -> Result<UpdateRoomResponse, RusotoError<UpdateRoomError>> {
+        self.runtime.block_on(self.client.update_room(input))
+    }
+
+    /// Blocking version of [`Chime::update_room_membership`].
+    pub fn update_room_membership(
+        &self,
+        input: UpdateRoomMembershipRequest,
+    ) -> Result<UpdateRoomMembershipResponse, RusotoError<UpdateRoomMembershipError>> {
+        self.runtime
+            .block_on(self.client.update_room_membership(input))
+    }
+
+    /// Blocking version of [`Chime::update_sip_media_application`].
+    pub fn update_sip_media_application(
+        &self,
+        input: UpdateSipMediaApplicationRequest,
+    ) -> Result<UpdateSipMediaApplicationResponse, RusotoError<UpdateSipMediaApplicationError>>
+    {
+        self.runtime
+            .block_on(self.client.update_sip_media_application(input))
+    }
+
+    /// Blocking version of [`Chime::update_sip_rule`].
+    pub fn update_sip_rule(
+        &self,
+        input: UpdateSipRuleRequest,
+    ) -> Result<UpdateSipRuleResponse, RusotoError<UpdateSipRuleError>> {
+        self.runtime.block_on(self.client.update_sip_rule(input))
+    }
+
+    /// Blocking version of [`Chime::update_user`].
+    pub fn update_user(
+        &self,
+        input: UpdateUserRequest,
+    ) -> Result<UpdateUserResponse, RusotoError<UpdateUserError>> {
+        self.runtime.block_on(self.client.update_user(input))
+    }
+
+    /// Blocking version of [`Chime::update_user_settings`].
+    pub fn update_user_settings(
+        &self,
+        input: UpdateUserSettingsRequest,
+    ) -> Result<(), RusotoError<UpdateUserSettingsError>> {
+        self.runtime
+            .block_on(self.client.update_user_settings(input))
+    }
+
+    /// Blocking version of [`Chime::update_voice_connector`].
+    pub fn update_voice_connector(
+        &self,
+        input: UpdateVoiceConnectorRequest,
+    ) -> Result<UpdateVoiceConnectorResponse, RusotoError<UpdateVoiceConnectorError>> {
+        self.runtime
+            .block_on(self.client.update_voice_connector(input))
+    }
+
+    /// Blocking version of [`Chime::update_voice_connector_group`].
+    pub fn update_voice_connector_group(
+        &self,
+        input: UpdateVoiceConnectorGroupRequest,
+    ) -> Result<UpdateVoiceConnectorGroupResponse, RusotoError<UpdateVoiceConnectorGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.update_voice_connector_group(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -457,31 +458,62 @@ impl CreateEnvironmentEC2Error {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateEnvironmentEC2Error::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEnvironmentEC2Error::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateEnvironmentEC2Error::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEnvironmentEC2Error::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateEnvironmentEC2Error::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEnvironmentEC2Error::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateEnvironmentEC2Error::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateEnvironmentEC2Error::InternalServerError,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateEnvironmentEC2Error::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEnvironmentEC2Error::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateEnvironmentEC2Error::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEnvironmentEC2Error::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateEnvironmentEC2Error::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateEnvironmentEC2Error::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -529,41 +561,62 @@ impl CreateEnvironmentMembershipError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateEnvironmentMembershipError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateEnvironmentMembershipError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateEnvironmentMembershipError::Conflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateEnvironmentMembershipError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateEnvironmentMembershipError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateEnvironmentMembershipError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        CreateEnvironmentMembershipError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEnvironmentMembershipError::InternalServerError,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateEnvironmentMembershipError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateEnvironmentMembershipError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateEnvironmentMembershipError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateEnvironmentMembershipError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateEnvironmentMembershipError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateEnvironmentMembershipError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -611,29 +664,62 @@ impl DeleteEnvironmentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteEnvironmentError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEnvironmentError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteEnvironmentError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEnvironmentError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteEnvironmentError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEnvironmentError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteEnvironmentError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteEnvironmentError::InternalServerError,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteEnvironmentError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEnvironmentError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteEnvironmentError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEnvironmentError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteEnvironmentError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEnvironmentError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -681,41 +767,62 @@ impl DeleteEnvironmentMembershipError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteEnvironmentMembershipError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteEnvironmentMembershipError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteEnvironmentMembershipError::Conflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteEnvironmentMembershipError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteEnvironmentMembershipError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteEnvironmentMembershipError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        DeleteEnvironmentMembershipError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEnvironmentMembershipError::InternalServerError,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteEnvironmentMembershipError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteEnvironmentMembershipError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteEnvironmentMembershipError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteEnvironmentMembershipError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteEnvironmentMembershipError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteEnvironmentMembershipError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -765,41 +872,62 @@ impl DescribeEnvironmentMembershipsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeEnvironmentMembershipsError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEnvironmentMembershipsError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DescribeEnvironmentMembershipsError::Conflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEnvironmentMembershipsError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeEnvironmentMembershipsError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEnvironmentMembershipsError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        DescribeEnvironmentMembershipsError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEnvironmentMembershipsError::InternalServerError,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
-                        DescribeEnvironmentMembershipsError::LimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEnvironmentMembershipsError::LimitExceeded,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeEnvironmentMembershipsError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEnvironmentMembershipsError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        DescribeEnvironmentMembershipsError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEnvironmentMembershipsError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -849,35 +977,62 @@ impl DescribeEnvironmentStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeEnvironmentStatusError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEnvironmentStatusError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DescribeEnvironmentStatusError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEnvironmentStatusError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeEnvironmentStatusError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEnvironmentStatusError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        DescribeEnvironmentStatusError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEnvironmentStatusError::InternalServerError,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DescribeEnvironmentStatusError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEnvironmentStatusError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeEnvironmentStatusError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEnvironmentStatusError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeEnvironmentStatusError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEnvironmentStatusError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -925,31 +1080,62 @@ impl DescribeEnvironmentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeEnvironmentsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEnvironmentsError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DescribeEnvironmentsError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEnvironmentsError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeEnvironmentsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEnvironmentsError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeEnvironmentsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEnvironmentsError::InternalServerError,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DescribeEnvironmentsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEnvironmentsError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeEnvironmentsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEnvironmentsError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeEnvironmentsError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEnvironmentsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -995,29 +1181,62 @@ impl ListEnvironmentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListEnvironmentsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListEnvironmentsError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(ListEnvironmentsError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListEnvironmentsError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListEnvironmentsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListEnvironmentsError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListEnvironmentsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListEnvironmentsError::InternalServerError,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListEnvironmentsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListEnvironmentsError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListEnvironmentsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListEnvironmentsError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListEnvironmentsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListEnvironmentsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1055,17 +1274,30 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1101,18 +1333,38 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::BadRequest,
+                    )
                 }
                 "ConcurrentAccessException" => {
-                    return RusotoError::Service(TagResourceError::ConcurrentAccess(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ConcurrentAccess,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(TagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1149,18 +1401,38 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::BadRequest,
+                    )
                 }
                 "ConcurrentAccessException" => {
-                    return RusotoError::Service(UntagResourceError::ConcurrentAccess(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ConcurrentAccess,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1203,29 +1475,62 @@ impl UpdateEnvironmentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateEnvironmentError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateEnvironmentError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateEnvironmentError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateEnvironmentError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateEnvironmentError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateEnvironmentError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateEnvironmentError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateEnvironmentError::InternalServerError,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateEnvironmentError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateEnvironmentError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateEnvironmentError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateEnvironmentError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateEnvironmentError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateEnvironmentError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1273,41 +1578,62 @@ impl UpdateEnvironmentMembershipError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateEnvironmentMembershipError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateEnvironmentMembershipError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateEnvironmentMembershipError::Conflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateEnvironmentMembershipError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateEnvironmentMembershipError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateEnvironmentMembershipError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        UpdateEnvironmentMembershipError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateEnvironmentMembershipError::InternalServerError,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateEnvironmentMembershipError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateEnvironmentMembershipError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateEnvironmentMembershipError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateEnvironmentMembershipError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateEnvironmentMembershipError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateEnvironmentMembershipError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1425,11 +1751,12 @@ pub struct Cloud9Client {
 impl Cloud9Client {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> Cloud9Client {
         Cloud9Client {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Cloud9"),
         }
     }
 
@@ -1444,12 +1771,32 @@ impl Cloud9Client {
     {
         Cloud9Client {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Cloud9"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> Cloud9Client {
-        Cloud9Client { client, region }
+        Cloud9Client {
+            client,
+            region: region_for_service(region, "Cloud9"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> Cloud9Client {
+        Cloud9Client {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Cloud9"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> Cloud9Client {
+        Cloud9Client {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -1461,6 +1808,7 @@ impl Cloud9 for Cloud9Client {
         input: CreateEnvironmentEC2Request,
     ) -> Result<CreateEnvironmentEC2Result, RusotoError<CreateEnvironmentEC2Error>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateEnvironmentEC2");
         request.add_header(
             "x-amz-target",
             "AWSCloud9WorkspaceManagementService.CreateEnvironmentEC2",
@@ -1483,6 +1831,7 @@ impl Cloud9 for Cloud9Client {
     ) -> Result<CreateEnvironmentMembershipResult, RusotoError<CreateEnvironmentMembershipError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateEnvironmentMembership");
         request.add_header(
             "x-amz-target",
             "AWSCloud9WorkspaceManagementService.CreateEnvironmentMembership",
@@ -1505,6 +1854,7 @@ impl Cloud9 for Cloud9Client {
         input: DeleteEnvironmentRequest,
     ) -> Result<DeleteEnvironmentResult, RusotoError<DeleteEnvironmentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteEnvironment");
         request.add_header(
             "x-amz-target",
             "AWSCloud9WorkspaceManagementService.DeleteEnvironment",
@@ -1527,6 +1877,7 @@ impl Cloud9 for Cloud9Client {
     ) -> Result<DeleteEnvironmentMembershipResult, RusotoError<DeleteEnvironmentMembershipError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteEnvironmentMembership");
         request.add_header(
             "x-amz-target",
             "AWSCloud9WorkspaceManagementService.DeleteEnvironmentMembership",
@@ -1552,6 +1903,7 @@ impl Cloud9 for Cloud9Client {
         RusotoError<DescribeEnvironmentMembershipsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeEnvironmentMemberships");
         request.add_header(
             "x-amz-target",
             "AWSCloud9WorkspaceManagementService.DescribeEnvironmentMemberships",
@@ -1574,6 +1926,7 @@ impl Cloud9 for Cloud9Client {
         input: DescribeEnvironmentStatusRequest,
     ) -> Result<DescribeEnvironmentStatusResult, RusotoError<DescribeEnvironmentStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeEnvironmentStatus");
         request.add_header(
             "x-amz-target",
             "AWSCloud9WorkspaceManagementService.DescribeEnvironmentStatus",
@@ -1596,6 +1949,7 @@ impl Cloud9 for Cloud9Client {
         input: DescribeEnvironmentsRequest,
     ) -> Result<DescribeEnvironmentsResult, RusotoError<DescribeEnvironmentsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeEnvironments");
         request.add_header(
             "x-amz-target",
             "AWSCloud9WorkspaceManagementService.DescribeEnvironments",
@@ -1617,6 +1971,7 @@ impl Cloud9 for Cloud9Client {
         input: ListEnvironmentsRequest,
     ) -> Result<ListEnvironmentsResult, RusotoError<ListEnvironmentsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListEnvironments");
         request.add_header(
             "x-amz-target",
             "AWSCloud9WorkspaceManagementService.ListEnvironments",
@@ -1638,6 +1993,7 @@ impl Cloud9 for Cloud9Client {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header(
             "x-amz-target",
             "AWSCloud9WorkspaceManagementService.ListTagsForResource",
@@ -1659,6 +2015,7 @@ impl Cloud9 for Cloud9Client {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header(
             "x-amz-target",
             "AWSCloud9WorkspaceManagementService.TagResource",
@@ -1680,6 +2037,7 @@ impl Cloud9 for Cloud9Client {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header(
             "x-amz-target",
             "AWSCloud9WorkspaceManagementService.UntagResource",
@@ -1701,6 +2059,7 @@ impl Cloud9 for Cloud9Client {
         input: UpdateEnvironmentRequest,
     ) -> Result<UpdateEnvironmentResult, RusotoError<UpdateEnvironmentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateEnvironment");
         request.add_header(
             "x-amz-target",
             "AWSCloud9WorkspaceManagementService.UpdateEnvironment",
@@ -1723,6 +2082,7 @@ impl Cloud9 for Cloud9Client {
     ) -> Result<UpdateEnvironmentMembershipResult, RusotoError<UpdateEnvironmentMembershipError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateEnvironmentMembership");
         request.add_header(
             "x-amz-target",
             "AWSCloud9WorkspaceManagementService.UpdateEnvironmentMembership",
@@ -1739,3 +2099,196 @@ impl Cloud9 for Cloud9Client {
             .deserialize::<UpdateEnvironmentMembershipResult, _>()
     }
 }
+
+/// A blocking client for the AWS Cloud9 API, running the calls of a `Cloud9Client`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `Cloud9Client`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct Cloud9BlockingClient {
+    client: Cloud9Client,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl Cloud9BlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> Cloud9BlockingClient {
+        Cloud9BlockingClient::from_client(Cloud9Client::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> Cloud9BlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        Cloud9BlockingClient::from_client(Cloud9Client::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> Cloud9BlockingClient {
+        Cloud9BlockingClient::from_client(Cloud9Client::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> Cloud9BlockingClient {
+        Cloud9BlockingClient::from_client(Cloud9Client::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: Cloud9Client) -> Cloud9BlockingClient {
+        Cloud9BlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> Cloud9BlockingClient {
+        Cloud9BlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> Cloud9BlockingClient {
+        Cloud9BlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Cloud9::create_environment_ec2`].
+    pub fn create_environment_ec2(
+        &self,
+        input: CreateEnvironmentEC2Request,
+    ) -> Result<CreateEnvironmentEC2Result, RusotoError<CreateEnvironmentEC2Error>> {
+        self.runtime
+            .block_on(self.client.create_environment_ec2(input))
+    }
+
+    /// Blocking version of [`Cloud9::create_environment_membership`].
+    pub fn create_environment_membership(
+        &self,
+        input: CreateEnvironmentMembershipRequest,
+    ) -> Result<CreateEnvironmentMembershipResult, RusotoError<CreateEnvironmentMembershipError>>
+    {
+        self.runtime
+            .block_on(self.client.create_environment_membership(input))
+    }
+
+    /// Blocking version of [`Cloud9::delete_environment`].
+    pub fn delete_environment(
+        &self,
+        input: DeleteEnvironmentRequest,
+    ) -> Result<DeleteEnvironmentResult, RusotoError<DeleteEnvironmentError>> {
+        self.runtime.block_on(self.client.delete_environment(input))
+    }
+
+    /// Blocking version of [`Cloud9::delete_environment_membership`].
+    pub fn delete_environment_membership(
+        &self,
+        input: DeleteEnvironmentMembershipRequest,
+    ) -> Result<DeleteEnvironmentMembershipResult, RusotoError<DeleteEnvironmentMembershipError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_environment_membership(input))
+    }
+
+    /// Blocking version of [`Cloud9::describe_environment_memberships`].
+    pub fn describe_environment_memberships(
+        &self,
+        input: DescribeEnvironmentMembershipsRequest,
+    ) -> Result<
+        DescribeEnvironmentMembershipsResult,
+        RusotoError<DescribeEnvironmentMembershipsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_environment_memberships(input))
+    }
+
+    /// Blocking version of [`Cloud9::describe_environment_status`].
+    pub fn describe_environment_status(
+        &self,
+        input: DescribeEnvironmentStatusRequest,
+    ) -> Result<DescribeEnvironmentStatusResult, RusotoError<DescribeEnvironmentStatusError>> {
+        self.runtime
+            .block_on(self.client.describe_environment_status(input))
+    }
+
+    /// Blocking version of [`Cloud9::describe_environments`].
+    pub fn describe_environments(
+        &self,
+        input: DescribeEnvironmentsRequest,
+    ) -> Result<DescribeEnvironmentsResult, RusotoError<DescribeEnvironmentsError>> {
+        self.runtime
+            .block_on(self.client.describe_environments(input))
+    }
+
+    /// Blocking version of [`Cloud9::list_environments`].
+    pub fn list_environments(
+        &self,
+        input: ListEnvironmentsRequest,
+    ) -> Result<ListEnvironmentsResult, RusotoError<ListEnvironmentsError>> {
+        self.runtime.block_on(self.client.list_environments(input))
+    }
+
+    /// Blocking version of [`Cloud9::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`Cloud9::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`Cloud9::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`Cloud9::update_environment`].
+    pub fn update_environment(
+        &self,
+        input: UpdateEnvironmentRequest,
+    ) -> Result<UpdateEnvironmentResult, RusotoError<UpdateEnvironmentError>> {
+        self.runtime.block_on(self.client.update_environment(input))
+    }
+
+    /// Blocking version of [`Cloud9::update_environment_membership`].
+    pub fn update_environment_membership(
+        &self,
+        input: UpdateEnvironmentMembershipRequest,
+    ) -> Result<UpdateEnvironmentMembershipResult, RusotoError<UpdateEnvironmentMembershipError>>
+    {
+        self.runtime
+            .block_on(self.client.update_environment_membership(input))
+    }
+}
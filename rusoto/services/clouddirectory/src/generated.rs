@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -3241,32 +3242,70 @@ impl AddFacetToObjectError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AddFacetToObjectError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddFacetToObjectError::AccessDenied,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(AddFacetToObjectError::DirectoryNotEnabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddFacetToObjectError::DirectoryNotEnabled,
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(AddFacetToObjectError::FacetValidation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddFacetToObjectError::FacetValidation,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(AddFacetToObjectError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddFacetToObjectError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(AddFacetToObjectError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddFacetToObjectError::InvalidArn,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AddFacetToObjectError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddFacetToObjectError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddFacetToObjectError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddFacetToObjectError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(AddFacetToObjectError::RetryableConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddFacetToObjectError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3315,30 +3354,70 @@ impl ApplySchemaError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ApplySchemaError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ApplySchemaError::AccessDenied,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ApplySchemaError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ApplySchemaError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ApplySchemaError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ApplySchemaError::InvalidArn,
+                    )
                 }
                 "InvalidAttachmentException" => {
-                    return RusotoError::Service(ApplySchemaError::InvalidAttachment(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ApplySchemaError::InvalidAttachment,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ApplySchemaError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ApplySchemaError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ApplySchemaError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ApplySchemaError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ApplySchemaError::RetryableConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ApplySchemaError::RetryableConflict,
+                    )
                 }
                 "SchemaAlreadyExistsException" => {
-                    return RusotoError::Service(ApplySchemaError::SchemaAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ApplySchemaError::SchemaAlreadyExists,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3391,36 +3470,86 @@ impl AttachObjectError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AttachObjectError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachObjectError::AccessDenied,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(AttachObjectError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachObjectError::DirectoryNotEnabled,
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(AttachObjectError::FacetValidation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachObjectError::FacetValidation,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(AttachObjectError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachObjectError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(AttachObjectError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachObjectError::InvalidArn,
+                    )
                 }
                 "InvalidAttachmentException" => {
-                    return RusotoError::Service(AttachObjectError::InvalidAttachment(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachObjectError::InvalidAttachment,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AttachObjectError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachObjectError::LimitExceeded,
+                    )
                 }
                 "LinkNameAlreadyInUseException" => {
-                    return RusotoError::Service(AttachObjectError::LinkNameAlreadyInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachObjectError::LinkNameAlreadyInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AttachObjectError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachObjectError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(AttachObjectError::RetryableConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachObjectError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3471,30 +3600,70 @@ impl AttachPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AttachPolicyError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachPolicyError::AccessDenied,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(AttachPolicyError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachPolicyError::DirectoryNotEnabled,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(AttachPolicyError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachPolicyError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(AttachPolicyError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachPolicyError::InvalidArn,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AttachPolicyError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachPolicyError::LimitExceeded,
+                    )
                 }
                 "NotPolicyException" => {
-                    return RusotoError::Service(AttachPolicyError::NotPolicy(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachPolicyError::NotPolicy,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AttachPolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachPolicyError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(AttachPolicyError::RetryableConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachPolicyError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3549,41 +3718,94 @@ impl AttachToIndexError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AttachToIndexError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachToIndexError::AccessDenied,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(AttachToIndexError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachToIndexError::DirectoryNotEnabled,
+                    )
                 }
                 "IndexedAttributeMissingException" => {
-                    return RusotoError::Service(AttachToIndexError::IndexedAttributeMissing(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AttachToIndexError::IndexedAttributeMissing,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(AttachToIndexError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachToIndexError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(AttachToIndexError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachToIndexError::InvalidArn,
+                    )
                 }
                 "InvalidAttachmentException" => {
-                    return RusotoError::Service(AttachToIndexError::InvalidAttachment(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachToIndexError::InvalidAttachment,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AttachToIndexError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachToIndexError::LimitExceeded,
+                    )
                 }
                 "LinkNameAlreadyInUseException" => {
-                    return RusotoError::Service(AttachToIndexError::LinkNameAlreadyInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachToIndexError::LinkNameAlreadyInUse,
+                    )
                 }
                 "NotIndexException" => {
-                    return RusotoError::Service(AttachToIndexError::NotIndex(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachToIndexError::NotIndex,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AttachToIndexError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachToIndexError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(AttachToIndexError::RetryableConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachToIndexError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3637,33 +3859,78 @@ impl AttachTypedLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AttachTypedLinkError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachTypedLinkError::AccessDenied,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(AttachTypedLinkError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachTypedLinkError::DirectoryNotEnabled,
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(AttachTypedLinkError::FacetValidation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachTypedLinkError::FacetValidation,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(AttachTypedLinkError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachTypedLinkError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(AttachTypedLinkError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachTypedLinkError::InvalidArn,
+                    )
                 }
                 "InvalidAttachmentException" => {
-                    return RusotoError::Service(AttachTypedLinkError::InvalidAttachment(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachTypedLinkError::InvalidAttachment,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AttachTypedLinkError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachTypedLinkError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AttachTypedLinkError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachTypedLinkError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(AttachTypedLinkError::RetryableConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachTypedLinkError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3709,24 +3976,54 @@ impl BatchReadError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(BatchReadError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchReadError::AccessDenied,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(BatchReadError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchReadError::DirectoryNotEnabled,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(BatchReadError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchReadError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(BatchReadError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchReadError::InvalidArn,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(BatchReadError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchReadError::LimitExceeded,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(BatchReadError::RetryableConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchReadError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3771,27 +4068,62 @@ impl BatchWriteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(BatchWriteError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchWriteError::AccessDenied,
+                    )
                 }
                 "BatchWriteException" => {
-                    return RusotoError::Service(BatchWriteError::BatchWrite(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchWriteError::BatchWrite,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(BatchWriteError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchWriteError::DirectoryNotEnabled,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(BatchWriteError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchWriteError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(BatchWriteError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchWriteError::InvalidArn,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(BatchWriteError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchWriteError::LimitExceeded,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(BatchWriteError::RetryableConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchWriteError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3837,29 +4169,62 @@ impl CreateDirectoryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDirectoryError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDirectoryError::AccessDenied,
+                    )
                 }
                 "DirectoryAlreadyExistsException" => {
-                    return RusotoError::Service(CreateDirectoryError::DirectoryAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDirectoryError::DirectoryAlreadyExists,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateDirectoryError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDirectoryError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(CreateDirectoryError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDirectoryError::InvalidArn,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDirectoryError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDirectoryError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDirectoryError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDirectoryError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(CreateDirectoryError::RetryableConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDirectoryError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3909,33 +4274,78 @@ impl CreateFacetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateFacetError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFacetError::AccessDenied,
+                    )
                 }
                 "FacetAlreadyExistsException" => {
-                    return RusotoError::Service(CreateFacetError::FacetAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFacetError::FacetAlreadyExists,
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(CreateFacetError::FacetValidation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFacetError::FacetValidation,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateFacetError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFacetError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(CreateFacetError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFacetError::InvalidArn,
+                    )
                 }
                 "InvalidRuleException" => {
-                    return RusotoError::Service(CreateFacetError::InvalidRule(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFacetError::InvalidRule,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateFacetError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFacetError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateFacetError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFacetError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(CreateFacetError::RetryableConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFacetError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3989,36 +4399,86 @@ impl CreateIndexError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateIndexError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIndexError::AccessDenied,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(CreateIndexError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIndexError::DirectoryNotEnabled,
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(CreateIndexError::FacetValidation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIndexError::FacetValidation,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateIndexError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIndexError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(CreateIndexError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIndexError::InvalidArn,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateIndexError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIndexError::LimitExceeded,
+                    )
                 }
                 "LinkNameAlreadyInUseException" => {
-                    return RusotoError::Service(CreateIndexError::LinkNameAlreadyInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIndexError::LinkNameAlreadyInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateIndexError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIndexError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(CreateIndexError::RetryableConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIndexError::RetryableConflict,
+                    )
                 }
                 "UnsupportedIndexTypeException" => {
-                    return RusotoError::Service(CreateIndexError::UnsupportedIndexType(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIndexError::UnsupportedIndexType,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4073,36 +4533,86 @@ impl CreateObjectError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateObjectError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateObjectError::AccessDenied,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(CreateObjectError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateObjectError::DirectoryNotEnabled,
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(CreateObjectError::FacetValidation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateObjectError::FacetValidation,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateObjectError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateObjectError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(CreateObjectError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateObjectError::InvalidArn,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateObjectError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateObjectError::LimitExceeded,
+                    )
                 }
                 "LinkNameAlreadyInUseException" => {
-                    return RusotoError::Service(CreateObjectError::LinkNameAlreadyInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateObjectError::LinkNameAlreadyInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateObjectError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateObjectError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(CreateObjectError::RetryableConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateObjectError::RetryableConflict,
+                    )
                 }
                 "UnsupportedIndexTypeException" => {
-                    return RusotoError::Service(CreateObjectError::UnsupportedIndexType(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateObjectError::UnsupportedIndexType,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4149,24 +4659,54 @@ impl CreateSchemaError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateSchemaError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSchemaError::AccessDenied,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateSchemaError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSchemaError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(CreateSchemaError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSchemaError::InvalidArn,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateSchemaError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSchemaError::LimitExceeded,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(CreateSchemaError::RetryableConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSchemaError::RetryableConflict,
+                    )
                 }
                 "SchemaAlreadyExistsException" => {
-                    return RusotoError::Service(CreateSchemaError::SchemaAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSchemaError::SchemaAlreadyExists,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4215,43 +4755,78 @@ impl CreateTypedLinkFacetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateTypedLinkFacetError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTypedLinkFacetError::AccessDenied,
+                    )
                 }
                 "FacetAlreadyExistsException" => {
-                    return RusotoError::Service(CreateTypedLinkFacetError::FacetAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateTypedLinkFacetError::FacetAlreadyExists,
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(CreateTypedLinkFacetError::FacetValidation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateTypedLinkFacetError::FacetValidation,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateTypedLinkFacetError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateTypedLinkFacetError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(CreateTypedLinkFacetError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTypedLinkFacetError::InvalidArn,
+                    )
                 }
                 "InvalidRuleException" => {
-                    return RusotoError::Service(CreateTypedLinkFacetError::InvalidRule(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTypedLinkFacetError::InvalidRule,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateTypedLinkFacetError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTypedLinkFacetError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateTypedLinkFacetError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateTypedLinkFacetError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(CreateTypedLinkFacetError::RetryableConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateTypedLinkFacetError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4301,32 +4876,70 @@ impl DeleteDirectoryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteDirectoryError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDirectoryError::AccessDenied,
+                    )
                 }
                 "DirectoryDeletedException" => {
-                    return RusotoError::Service(DeleteDirectoryError::DirectoryDeleted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDirectoryError::DirectoryDeleted,
+                    )
                 }
                 "DirectoryNotDisabledException" => {
-                    return RusotoError::Service(DeleteDirectoryError::DirectoryNotDisabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDirectoryError::DirectoryNotDisabled,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteDirectoryError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDirectoryError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DeleteDirectoryError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDirectoryError::InvalidArn,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteDirectoryError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDirectoryError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDirectoryError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDirectoryError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(DeleteDirectoryError::RetryableConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDirectoryError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4375,30 +4988,70 @@ impl DeleteFacetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteFacetError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFacetError::AccessDenied,
+                    )
                 }
                 "FacetInUseException" => {
-                    return RusotoError::Service(DeleteFacetError::FacetInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFacetError::FacetInUse,
+                    )
                 }
                 "FacetNotFoundException" => {
-                    return RusotoError::Service(DeleteFacetError::FacetNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFacetError::FacetNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteFacetError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFacetError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DeleteFacetError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFacetError::InvalidArn,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteFacetError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFacetError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteFacetError::ResourceNotFound(err.msg))
-                }
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFacetError::ResourceNotFound,
+                    )
+                }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(DeleteFacetError::RetryableConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFacetError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4447,30 +5100,70 @@ impl DeleteObjectError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteObjectError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteObjectError::AccessDenied,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(DeleteObjectError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteObjectError::DirectoryNotEnabled,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteObjectError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteObjectError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DeleteObjectError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteObjectError::InvalidArn,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteObjectError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteObjectError::LimitExceeded,
+                    )
                 }
                 "ObjectNotDetachedException" => {
-                    return RusotoError::Service(DeleteObjectError::ObjectNotDetached(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteObjectError::ObjectNotDetached,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteObjectError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteObjectError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(DeleteObjectError::RetryableConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteObjectError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4517,27 +5210,62 @@ impl DeleteSchemaError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteSchemaError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSchemaError::AccessDenied,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteSchemaError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSchemaError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DeleteSchemaError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSchemaError::InvalidArn,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteSchemaError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSchemaError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteSchemaError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSchemaError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(DeleteSchemaError::RetryableConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSchemaError::RetryableConflict,
+                    )
                 }
                 "StillContainsLinksException" => {
-                    return RusotoError::Service(DeleteSchemaError::StillContainsLinks(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSchemaError::StillContainsLinks,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4583,33 +5311,62 @@ impl DeleteTypedLinkFacetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteTypedLinkFacetError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTypedLinkFacetError::AccessDenied,
+                    )
                 }
                 "FacetNotFoundException" => {
-                    return RusotoError::Service(DeleteTypedLinkFacetError::FacetNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTypedLinkFacetError::FacetNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteTypedLinkFacetError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteTypedLinkFacetError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DeleteTypedLinkFacetError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTypedLinkFacetError::InvalidArn,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteTypedLinkFacetError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTypedLinkFacetError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteTypedLinkFacetError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteTypedLinkFacetError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(DeleteTypedLinkFacetError::RetryableConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteTypedLinkFacetError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4659,35 +5416,78 @@ impl DetachFromIndexError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DetachFromIndexError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachFromIndexError::AccessDenied,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(DetachFromIndexError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachFromIndexError::DirectoryNotEnabled,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DetachFromIndexError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachFromIndexError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DetachFromIndexError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachFromIndexError::InvalidArn,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DetachFromIndexError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachFromIndexError::LimitExceeded,
+                    )
                 }
                 "NotIndexException" => {
-                    return RusotoError::Service(DetachFromIndexError::NotIndex(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachFromIndexError::NotIndex,
+                    )
                 }
                 "ObjectAlreadyDetachedException" => {
-                    return RusotoError::Service(DetachFromIndexError::ObjectAlreadyDetached(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetachFromIndexError::ObjectAlreadyDetached,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DetachFromIndexError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachFromIndexError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(DetachFromIndexError::RetryableConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachFromIndexError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4737,30 +5537,70 @@ impl DetachObjectError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DetachObjectError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachObjectError::AccessDenied,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(DetachObjectError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachObjectError::DirectoryNotEnabled,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DetachObjectError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachObjectError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DetachObjectError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachObjectError::InvalidArn,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DetachObjectError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachObjectError::LimitExceeded,
+                    )
                 }
                 "NotNodeException" => {
-                    return RusotoError::Service(DetachObjectError::NotNode(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachObjectError::NotNode,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DetachObjectError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachObjectError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(DetachObjectError::RetryableConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachObjectError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4809,30 +5649,70 @@ impl DetachPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DetachPolicyError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachPolicyError::AccessDenied,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(DetachPolicyError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachPolicyError::DirectoryNotEnabled,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DetachPolicyError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachPolicyError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DetachPolicyError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachPolicyError::InvalidArn,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DetachPolicyError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachPolicyError::LimitExceeded,
+                    )
                 }
                 "NotPolicyException" => {
-                    return RusotoError::Service(DetachPolicyError::NotPolicy(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachPolicyError::NotPolicy,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DetachPolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachPolicyError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(DetachPolicyError::RetryableConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachPolicyError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4881,30 +5761,70 @@ impl DetachTypedLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DetachTypedLinkError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachTypedLinkError::AccessDenied,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(DetachTypedLinkError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachTypedLinkError::DirectoryNotEnabled,
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(DetachTypedLinkError::FacetValidation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachTypedLinkError::FacetValidation,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DetachTypedLinkError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachTypedLinkError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DetachTypedLinkError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachTypedLinkError::InvalidArn,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DetachTypedLinkError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachTypedLinkError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DetachTypedLinkError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachTypedLinkError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(DetachTypedLinkError::RetryableConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachTypedLinkError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4951,27 +5871,62 @@ impl DisableDirectoryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DisableDirectoryError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableDirectoryError::AccessDenied,
+                    )
                 }
                 "DirectoryDeletedException" => {
-                    return RusotoError::Service(DisableDirectoryError::DirectoryDeleted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableDirectoryError::DirectoryDeleted,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DisableDirectoryError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableDirectoryError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DisableDirectoryError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableDirectoryError::InvalidArn,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DisableDirectoryError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableDirectoryError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisableDirectoryError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableDirectoryError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(DisableDirectoryError::RetryableConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableDirectoryError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5017,27 +5972,62 @@ impl EnableDirectoryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(EnableDirectoryError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableDirectoryError::AccessDenied,
+                    )
                 }
                 "DirectoryDeletedException" => {
-                    return RusotoError::Service(EnableDirectoryError::DirectoryDeleted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableDirectoryError::DirectoryDeleted,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(EnableDirectoryError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableDirectoryError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(EnableDirectoryError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableDirectoryError::InvalidArn,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(EnableDirectoryError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableDirectoryError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(EnableDirectoryError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableDirectoryError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(EnableDirectoryError::RetryableConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableDirectoryError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5081,34 +6071,54 @@ impl GetAppliedSchemaVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetAppliedSchemaVersionError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetAppliedSchemaVersionError::AccessDenied,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetAppliedSchemaVersionError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetAppliedSchemaVersionError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetAppliedSchemaVersionError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAppliedSchemaVersionError::InvalidArn,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetAppliedSchemaVersionError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetAppliedSchemaVersionError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetAppliedSchemaVersionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetAppliedSchemaVersionError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(GetAppliedSchemaVersionError::RetryableConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetAppliedSchemaVersionError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5149,21 +6159,46 @@ impl GetDirectoryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetDirectoryError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDirectoryError::AccessDenied,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetDirectoryError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDirectoryError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetDirectoryError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDirectoryError::InvalidArn,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetDirectoryError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDirectoryError::LimitExceeded,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(GetDirectoryError::RetryableConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDirectoryError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5207,27 +6242,62 @@ impl GetFacetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetFacetError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFacetError::AccessDenied,
+                    )
                 }
                 "FacetNotFoundException" => {
-                    return RusotoError::Service(GetFacetError::FacetNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFacetError::FacetNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetFacetError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFacetError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetFacetError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFacetError::InvalidArn,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetFacetError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFacetError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetFacetError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFacetError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(GetFacetError::RetryableConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFacetError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5275,32 +6345,70 @@ impl GetLinkAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetLinkAttributesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLinkAttributesError::AccessDenied,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(GetLinkAttributesError::DirectoryNotEnabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetLinkAttributesError::DirectoryNotEnabled,
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(GetLinkAttributesError::FacetValidation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLinkAttributesError::FacetValidation,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetLinkAttributesError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLinkAttributesError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetLinkAttributesError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLinkAttributesError::InvalidArn,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetLinkAttributesError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLinkAttributesError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetLinkAttributesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLinkAttributesError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(GetLinkAttributesError::RetryableConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLinkAttributesError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5349,36 +6457,70 @@ impl GetObjectAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetObjectAttributesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetObjectAttributesError::AccessDenied,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(GetObjectAttributesError::DirectoryNotEnabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetObjectAttributesError::DirectoryNotEnabled,
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(GetObjectAttributesError::FacetValidation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetObjectAttributesError::FacetValidation,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetObjectAttributesError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetObjectAttributesError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetObjectAttributesError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetObjectAttributesError::InvalidArn,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetObjectAttributesError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetObjectAttributesError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetObjectAttributesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetObjectAttributesError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(GetObjectAttributesError::RetryableConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetObjectAttributesError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5425,35 +6567,62 @@ impl GetObjectInformationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetObjectInformationError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetObjectInformationError::AccessDenied,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(GetObjectInformationError::DirectoryNotEnabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetObjectInformationError::DirectoryNotEnabled,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetObjectInformationError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetObjectInformationError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetObjectInformationError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetObjectInformationError::InvalidArn,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetObjectInformationError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetObjectInformationError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetObjectInformationError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetObjectInformationError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(GetObjectInformationError::RetryableConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetObjectInformationError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5497,24 +6666,54 @@ impl GetSchemaAsJsonError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetSchemaAsJsonError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSchemaAsJsonError::AccessDenied,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetSchemaAsJsonError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSchemaAsJsonError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetSchemaAsJsonError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSchemaAsJsonError::InvalidArn,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetSchemaAsJsonError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSchemaAsJsonError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetSchemaAsJsonError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSchemaAsJsonError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(GetSchemaAsJsonError::RetryableConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSchemaAsJsonError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5563,46 +6762,70 @@ impl GetTypedLinkFacetInformationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetTypedLinkFacetInformationError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetTypedLinkFacetInformationError::AccessDenied,
+                    )
                 }
                 "FacetNotFoundException" => {
-                    return RusotoError::Service(GetTypedLinkFacetInformationError::FacetNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetTypedLinkFacetInformationError::FacetNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(
-                        GetTypedLinkFacetInformationError::InternalService(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTypedLinkFacetInformationError::InternalService,
                     )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetTypedLinkFacetInformationError::InvalidArn(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetTypedLinkFacetInformationError::InvalidArn,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        GetTypedLinkFacetInformationError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTypedLinkFacetInformationError::InvalidNextToken,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetTypedLinkFacetInformationError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetTypedLinkFacetInformationError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        GetTypedLinkFacetInformationError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTypedLinkFacetInformationError::ResourceNotFound,
                     )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(
-                        GetTypedLinkFacetInformationError::RetryableConflict(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTypedLinkFacetInformationError::RetryableConflict,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5655,35 +6878,62 @@ impl ListAppliedSchemaArnsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListAppliedSchemaArnsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAppliedSchemaArnsError::AccessDenied,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListAppliedSchemaArnsError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAppliedSchemaArnsError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListAppliedSchemaArnsError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAppliedSchemaArnsError::InvalidArn,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListAppliedSchemaArnsError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAppliedSchemaArnsError::InvalidNextToken,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListAppliedSchemaArnsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAppliedSchemaArnsError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListAppliedSchemaArnsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAppliedSchemaArnsError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListAppliedSchemaArnsError::RetryableConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAppliedSchemaArnsError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5729,33 +6979,62 @@ impl ListAttachedIndicesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListAttachedIndicesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAttachedIndicesError::AccessDenied,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(ListAttachedIndicesError::DirectoryNotEnabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAttachedIndicesError::DirectoryNotEnabled,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListAttachedIndicesError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAttachedIndicesError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListAttachedIndicesError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAttachedIndicesError::InvalidArn,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListAttachedIndicesError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAttachedIndicesError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListAttachedIndicesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAttachedIndicesError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListAttachedIndicesError::RetryableConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAttachedIndicesError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5801,41 +7080,62 @@ impl ListDevelopmentSchemaArnsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListDevelopmentSchemaArnsError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDevelopmentSchemaArnsError::AccessDenied,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListDevelopmentSchemaArnsError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDevelopmentSchemaArnsError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListDevelopmentSchemaArnsError::InvalidArn(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDevelopmentSchemaArnsError::InvalidArn,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDevelopmentSchemaArnsError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDevelopmentSchemaArnsError::InvalidNextToken,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListDevelopmentSchemaArnsError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDevelopmentSchemaArnsError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDevelopmentSchemaArnsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDevelopmentSchemaArnsError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListDevelopmentSchemaArnsError::RetryableConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDevelopmentSchemaArnsError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5879,24 +7179,54 @@ impl ListDirectoriesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListDirectoriesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDirectoriesError::AccessDenied,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListDirectoriesError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDirectoriesError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListDirectoriesError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDirectoriesError::InvalidArn,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDirectoriesError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDirectoriesError::InvalidNextToken,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListDirectoriesError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDirectoriesError::LimitExceeded,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListDirectoriesError::RetryableConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDirectoriesError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5943,36 +7273,70 @@ impl ListFacetAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListFacetAttributesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFacetAttributesError::AccessDenied,
+                    )
                 }
                 "FacetNotFoundException" => {
-                    return RusotoError::Service(ListFacetAttributesError::FacetNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFacetAttributesError::FacetNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListFacetAttributesError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFacetAttributesError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListFacetAttributesError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFacetAttributesError::InvalidArn,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListFacetAttributesError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListFacetAttributesError::InvalidNextToken,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListFacetAttributesError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFacetAttributesError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListFacetAttributesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListFacetAttributesError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListFacetAttributesError::RetryableConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListFacetAttributesError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6019,27 +7383,62 @@ impl ListFacetNamesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListFacetNamesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFacetNamesError::AccessDenied,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListFacetNamesError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFacetNamesError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListFacetNamesError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFacetNamesError::InvalidArn,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListFacetNamesError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFacetNamesError::InvalidNextToken,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListFacetNamesError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFacetNamesError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListFacetNamesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFacetNamesError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListFacetNamesError::RetryableConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFacetNamesError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6089,47 +7488,78 @@ impl ListIncomingTypedLinksError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListIncomingTypedLinksError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIncomingTypedLinksError::AccessDenied,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(ListIncomingTypedLinksError::DirectoryNotEnabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListIncomingTypedLinksError::DirectoryNotEnabled,
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(ListIncomingTypedLinksError::FacetValidation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListIncomingTypedLinksError::FacetValidation,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListIncomingTypedLinksError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListIncomingTypedLinksError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListIncomingTypedLinksError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIncomingTypedLinksError::InvalidArn,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListIncomingTypedLinksError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListIncomingTypedLinksError::InvalidNextToken,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListIncomingTypedLinksError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListIncomingTypedLinksError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListIncomingTypedLinksError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListIncomingTypedLinksError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListIncomingTypedLinksError::RetryableConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListIncomingTypedLinksError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6183,36 +7613,86 @@ impl ListIndexError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListIndexError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIndexError::AccessDenied,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(ListIndexError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIndexError::DirectoryNotEnabled,
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(ListIndexError::FacetValidation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIndexError::FacetValidation,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListIndexError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIndexError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListIndexError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIndexError::InvalidArn,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListIndexError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIndexError::InvalidNextToken,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListIndexError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIndexError::LimitExceeded,
+                    )
                 }
                 "NotIndexException" => {
-                    return RusotoError::Service(ListIndexError::NotIndex(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIndexError::NotIndex,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListIndexError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIndexError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListIndexError::RetryableConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIndexError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6257,27 +7737,46 @@ impl ListManagedSchemaArnsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListManagedSchemaArnsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListManagedSchemaArnsError::AccessDenied,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListManagedSchemaArnsError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListManagedSchemaArnsError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListManagedSchemaArnsError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListManagedSchemaArnsError::InvalidArn,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListManagedSchemaArnsError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListManagedSchemaArnsError::InvalidNextToken,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListManagedSchemaArnsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListManagedSchemaArnsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6325,45 +7824,78 @@ impl ListObjectAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListObjectAttributesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListObjectAttributesError::AccessDenied,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(ListObjectAttributesError::DirectoryNotEnabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListObjectAttributesError::DirectoryNotEnabled,
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(ListObjectAttributesError::FacetValidation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListObjectAttributesError::FacetValidation,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListObjectAttributesError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListObjectAttributesError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListObjectAttributesError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListObjectAttributesError::InvalidArn,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListObjectAttributesError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListObjectAttributesError::InvalidNextToken,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListObjectAttributesError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListObjectAttributesError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListObjectAttributesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListObjectAttributesError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListObjectAttributesError::RetryableConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListObjectAttributesError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6415,37 +7947,78 @@ impl ListObjectChildrenError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListObjectChildrenError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListObjectChildrenError::AccessDenied,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(ListObjectChildrenError::DirectoryNotEnabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListObjectChildrenError::DirectoryNotEnabled,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListObjectChildrenError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListObjectChildrenError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListObjectChildrenError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListObjectChildrenError::InvalidArn,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListObjectChildrenError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListObjectChildrenError::InvalidNextToken,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListObjectChildrenError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListObjectChildrenError::LimitExceeded,
+                    )
                 }
                 "NotNodeException" => {
-                    return RusotoError::Service(ListObjectChildrenError::NotNode(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListObjectChildrenError::NotNode,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListObjectChildrenError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListObjectChildrenError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListObjectChildrenError::RetryableConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListObjectChildrenError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6495,40 +8068,70 @@ impl ListObjectParentPathsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListObjectParentPathsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListObjectParentPathsError::AccessDenied,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(ListObjectParentPathsError::DirectoryNotEnabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListObjectParentPathsError::DirectoryNotEnabled,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListObjectParentPathsError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListObjectParentPathsError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListObjectParentPathsError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListObjectParentPathsError::InvalidArn,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListObjectParentPathsError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListObjectParentPathsError::InvalidNextToken,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListObjectParentPathsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListObjectParentPathsError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListObjectParentPathsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListObjectParentPathsError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListObjectParentPathsError::RetryableConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListObjectParentPathsError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6579,37 +8182,78 @@ impl ListObjectParentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListObjectParentsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListObjectParentsError::AccessDenied,
+                    )
                 }
                 "CannotListParentOfRootException" => {
-                    return RusotoError::Service(ListObjectParentsError::CannotListParentOfRoot(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListObjectParentsError::CannotListParentOfRoot,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(ListObjectParentsError::DirectoryNotEnabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListObjectParentsError::DirectoryNotEnabled,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListObjectParentsError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListObjectParentsError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListObjectParentsError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListObjectParentsError::InvalidArn,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListObjectParentsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListObjectParentsError::InvalidNextToken,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListObjectParentsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListObjectParentsError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListObjectParentsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListObjectParentsError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListObjectParentsError::RetryableConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListObjectParentsError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6659,34 +8303,70 @@ impl ListObjectPoliciesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListObjectPoliciesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListObjectPoliciesError::AccessDenied,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(ListObjectPoliciesError::DirectoryNotEnabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListObjectPoliciesError::DirectoryNotEnabled,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListObjectPoliciesError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListObjectPoliciesError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListObjectPoliciesError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListObjectPoliciesError::InvalidArn,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListObjectPoliciesError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListObjectPoliciesError::InvalidNextToken,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListObjectPoliciesError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListObjectPoliciesError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListObjectPoliciesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListObjectPoliciesError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListObjectPoliciesError::RetryableConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListObjectPoliciesError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6737,47 +8417,78 @@ impl ListOutgoingTypedLinksError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListOutgoingTypedLinksError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOutgoingTypedLinksError::AccessDenied,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(ListOutgoingTypedLinksError::DirectoryNotEnabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListOutgoingTypedLinksError::DirectoryNotEnabled,
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(ListOutgoingTypedLinksError::FacetValidation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListOutgoingTypedLinksError::FacetValidation,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListOutgoingTypedLinksError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListOutgoingTypedLinksError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListOutgoingTypedLinksError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOutgoingTypedLinksError::InvalidArn,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListOutgoingTypedLinksError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListOutgoingTypedLinksError::InvalidNextToken,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListOutgoingTypedLinksError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListOutgoingTypedLinksError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListOutgoingTypedLinksError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListOutgoingTypedLinksError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListOutgoingTypedLinksError::RetryableConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListOutgoingTypedLinksError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6829,43 +8540,78 @@ impl ListPolicyAttachmentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListPolicyAttachmentsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPolicyAttachmentsError::AccessDenied,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(ListPolicyAttachmentsError::DirectoryNotEnabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPolicyAttachmentsError::DirectoryNotEnabled,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListPolicyAttachmentsError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPolicyAttachmentsError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListPolicyAttachmentsError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPolicyAttachmentsError::InvalidArn,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListPolicyAttachmentsError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPolicyAttachmentsError::InvalidNextToken,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListPolicyAttachmentsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPolicyAttachmentsError::LimitExceeded,
+                    )
                 }
                 "NotPolicyException" => {
-                    return RusotoError::Service(ListPolicyAttachmentsError::NotPolicy(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPolicyAttachmentsError::NotPolicy,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListPolicyAttachmentsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPolicyAttachmentsError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListPolicyAttachmentsError::RetryableConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPolicyAttachmentsError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6913,39 +8659,62 @@ impl ListPublishedSchemaArnsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListPublishedSchemaArnsError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPublishedSchemaArnsError::AccessDenied,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListPublishedSchemaArnsError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPublishedSchemaArnsError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListPublishedSchemaArnsError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPublishedSchemaArnsError::InvalidArn,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListPublishedSchemaArnsError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPublishedSchemaArnsError::InvalidNextToken,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListPublishedSchemaArnsError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPublishedSchemaArnsError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListPublishedSchemaArnsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPublishedSchemaArnsError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListPublishedSchemaArnsError::RetryableConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPublishedSchemaArnsError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6991,33 +8760,62 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::AccessDenied,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InvalidArn,
+                    )
                 }
                 "InvalidTaggingRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidTaggingRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::InvalidTaggingRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListTagsForResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListTagsForResourceError::RetryableConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7067,46 +8865,70 @@ impl ListTypedLinkFacetAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTypedLinkFacetAttributesError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTypedLinkFacetAttributesError::AccessDenied,
+                    )
                 }
                 "FacetNotFoundException" => {
-                    return RusotoError::Service(ListTypedLinkFacetAttributesError::FacetNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTypedLinkFacetAttributesError::FacetNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(
-                        ListTypedLinkFacetAttributesError::InternalService(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTypedLinkFacetAttributesError::InternalService,
                     )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListTypedLinkFacetAttributesError::InvalidArn(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTypedLinkFacetAttributesError::InvalidArn,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        ListTypedLinkFacetAttributesError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTypedLinkFacetAttributesError::InvalidNextToken,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListTypedLinkFacetAttributesError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTypedLinkFacetAttributesError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        ListTypedLinkFacetAttributesError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTypedLinkFacetAttributesError::ResourceNotFound,
                     )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(
-                        ListTypedLinkFacetAttributesError::RetryableConflict(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTypedLinkFacetAttributesError::RetryableConflict,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7159,39 +8981,62 @@ impl ListTypedLinkFacetNamesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTypedLinkFacetNamesError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTypedLinkFacetNamesError::AccessDenied,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListTypedLinkFacetNamesError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTypedLinkFacetNamesError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListTypedLinkFacetNamesError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTypedLinkFacetNamesError::InvalidArn,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListTypedLinkFacetNamesError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTypedLinkFacetNamesError::InvalidNextToken,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListTypedLinkFacetNamesError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTypedLinkFacetNamesError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTypedLinkFacetNamesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTypedLinkFacetNamesError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(ListTypedLinkFacetNamesError::RetryableConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTypedLinkFacetNamesError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7239,30 +9084,70 @@ impl LookupPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(LookupPolicyError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        LookupPolicyError::AccessDenied,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(LookupPolicyError::DirectoryNotEnabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        LookupPolicyError::DirectoryNotEnabled,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(LookupPolicyError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        LookupPolicyError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(LookupPolicyError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        LookupPolicyError::InvalidArn,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(LookupPolicyError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        LookupPolicyError::InvalidNextToken,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(LookupPolicyError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        LookupPolicyError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(LookupPolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        LookupPolicyError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(LookupPolicyError::RetryableConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        LookupPolicyError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7309,29 +9194,62 @@ impl PublishSchemaError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(PublishSchemaError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PublishSchemaError::AccessDenied,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(PublishSchemaError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PublishSchemaError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(PublishSchemaError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PublishSchemaError::InvalidArn,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PublishSchemaError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PublishSchemaError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PublishSchemaError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PublishSchemaError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(PublishSchemaError::RetryableConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PublishSchemaError::RetryableConflict,
+                    )
                 }
                 "SchemaAlreadyPublishedException" => {
-                    return RusotoError::Service(PublishSchemaError::SchemaAlreadyPublished(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PublishSchemaError::SchemaAlreadyPublished,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7377,27 +9295,62 @@ impl PutSchemaFromJsonError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(PutSchemaFromJsonError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSchemaFromJsonError::AccessDenied,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(PutSchemaFromJsonError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSchemaFromJsonError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(PutSchemaFromJsonError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSchemaFromJsonError::InvalidArn,
+                    )
                 }
                 "InvalidRuleException" => {
-                    return RusotoError::Service(PutSchemaFromJsonError::InvalidRule(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSchemaFromJsonError::InvalidRule,
+                    )
                 }
                 "InvalidSchemaDocException" => {
-                    return RusotoError::Service(PutSchemaFromJsonError::InvalidSchemaDoc(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSchemaFromJsonError::InvalidSchemaDoc,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutSchemaFromJsonError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSchemaFromJsonError::LimitExceeded,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(PutSchemaFromJsonError::RetryableConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSchemaFromJsonError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7445,40 +9398,70 @@ impl RemoveFacetFromObjectError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RemoveFacetFromObjectError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveFacetFromObjectError::AccessDenied,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(RemoveFacetFromObjectError::DirectoryNotEnabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveFacetFromObjectError::DirectoryNotEnabled,
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(RemoveFacetFromObjectError::FacetValidation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveFacetFromObjectError::FacetValidation,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(RemoveFacetFromObjectError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveFacetFromObjectError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(RemoveFacetFromObjectError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveFacetFromObjectError::InvalidArn,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RemoveFacetFromObjectError::LimitExceeded(err.msg))
-                }
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveFacetFromObjectError::LimitExceeded,
+                    )
+                }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RemoveFacetFromObjectError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveFacetFromObjectError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(RemoveFacetFromObjectError::RetryableConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveFacetFromObjectError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7525,27 +9508,62 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(TagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::AccessDenied,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(TagResourceError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(TagResourceError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidArn,
+                    )
                 }
                 "InvalidTaggingRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidTaggingRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidTaggingRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(TagResourceError::RetryableConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7591,27 +9609,62 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UntagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::AccessDenied,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UntagResourceError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidArn,
+                    )
                 }
                 "InvalidTaggingRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidTaggingRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidTaggingRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UntagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(UntagResourceError::RetryableConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7663,36 +9716,86 @@ impl UpdateFacetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateFacetError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFacetError::AccessDenied,
+                    )
                 }
                 "FacetNotFoundException" => {
-                    return RusotoError::Service(UpdateFacetError::FacetNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFacetError::FacetNotFound,
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(UpdateFacetError::FacetValidation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFacetError::FacetValidation,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateFacetError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFacetError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(UpdateFacetError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFacetError::InvalidArn,
+                    )
                 }
                 "InvalidFacetUpdateException" => {
-                    return RusotoError::Service(UpdateFacetError::InvalidFacetUpdate(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFacetError::InvalidFacetUpdate,
+                    )
                 }
                 "InvalidRuleException" => {
-                    return RusotoError::Service(UpdateFacetError::InvalidRule(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFacetError::InvalidRule,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateFacetError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFacetError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateFacetError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFacetError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(UpdateFacetError::RetryableConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFacetError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7743,40 +9846,70 @@ impl UpdateLinkAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateLinkAttributesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateLinkAttributesError::AccessDenied,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(UpdateLinkAttributesError::DirectoryNotEnabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateLinkAttributesError::DirectoryNotEnabled,
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(UpdateLinkAttributesError::FacetValidation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateLinkAttributesError::FacetValidation,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateLinkAttributesError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateLinkAttributesError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(UpdateLinkAttributesError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateLinkAttributesError::InvalidArn,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateLinkAttributesError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateLinkAttributesError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateLinkAttributesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateLinkAttributesError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(UpdateLinkAttributesError::RetryableConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateLinkAttributesError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7827,47 +9960,78 @@ impl UpdateObjectAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateObjectAttributesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateObjectAttributesError::AccessDenied,
+                    )
                 }
                 "DirectoryNotEnabledException" => {
-                    return RusotoError::Service(UpdateObjectAttributesError::DirectoryNotEnabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateObjectAttributesError::DirectoryNotEnabled,
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(UpdateObjectAttributesError::FacetValidation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateObjectAttributesError::FacetValidation,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateObjectAttributesError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateObjectAttributesError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(UpdateObjectAttributesError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateObjectAttributesError::InvalidArn,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateObjectAttributesError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateObjectAttributesError::LimitExceeded,
+                    )
                 }
                 "LinkNameAlreadyInUseException" => {
-                    return RusotoError::Service(UpdateObjectAttributesError::LinkNameAlreadyInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateObjectAttributesError::LinkNameAlreadyInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateObjectAttributesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateObjectAttributesError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(UpdateObjectAttributesError::RetryableConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateObjectAttributesError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7913,24 +10077,54 @@ impl UpdateSchemaError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateSchemaError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSchemaError::AccessDenied,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateSchemaError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSchemaError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(UpdateSchemaError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSchemaError::InvalidArn,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateSchemaError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSchemaError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateSchemaError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSchemaError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(UpdateSchemaError::RetryableConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSchemaError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7981,46 +10175,86 @@ impl UpdateTypedLinkFacetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateTypedLinkFacetError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTypedLinkFacetError::AccessDenied,
+                    )
                 }
                 "FacetNotFoundException" => {
-                    return RusotoError::Service(UpdateTypedLinkFacetError::FacetNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTypedLinkFacetError::FacetNotFound,
+                    )
                 }
                 "FacetValidationException" => {
-                    return RusotoError::Service(UpdateTypedLinkFacetError::FacetValidation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateTypedLinkFacetError::FacetValidation,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateTypedLinkFacetError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateTypedLinkFacetError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(UpdateTypedLinkFacetError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTypedLinkFacetError::InvalidArn,
+                    )
                 }
                 "InvalidFacetUpdateException" => {
-                    return RusotoError::Service(UpdateTypedLinkFacetError::InvalidFacetUpdate(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateTypedLinkFacetError::InvalidFacetUpdate,
+                    )
                 }
                 "InvalidRuleException" => {
-                    return RusotoError::Service(UpdateTypedLinkFacetError::InvalidRule(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTypedLinkFacetError::InvalidRule,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateTypedLinkFacetError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTypedLinkFacetError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateTypedLinkFacetError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateTypedLinkFacetError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(UpdateTypedLinkFacetError::RetryableConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateTypedLinkFacetError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8071,42 +10305,70 @@ impl UpgradeAppliedSchemaError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpgradeAppliedSchemaError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpgradeAppliedSchemaError::AccessDenied,
+                    )
                 }
                 "IncompatibleSchemaException" => {
-                    return RusotoError::Service(UpgradeAppliedSchemaError::IncompatibleSchema(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpgradeAppliedSchemaError::IncompatibleSchema,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpgradeAppliedSchemaError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpgradeAppliedSchemaError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(UpgradeAppliedSchemaError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpgradeAppliedSchemaError::InvalidArn,
+                    )
                 }
                 "InvalidAttachmentException" => {
-                    return RusotoError::Service(UpgradeAppliedSchemaError::InvalidAttachment(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpgradeAppliedSchemaError::InvalidAttachment,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpgradeAppliedSchemaError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpgradeAppliedSchemaError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(UpgradeAppliedSchemaError::RetryableConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpgradeAppliedSchemaError::RetryableConflict,
+                    )
                 }
                 "SchemaAlreadyExistsException" => {
-                    return RusotoError::Service(UpgradeAppliedSchemaError::SchemaAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpgradeAppliedSchemaError::SchemaAlreadyExists,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8155,42 +10417,70 @@ impl UpgradePublishedSchemaError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpgradePublishedSchemaError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpgradePublishedSchemaError::AccessDenied,
+                    )
                 }
                 "IncompatibleSchemaException" => {
-                    return RusotoError::Service(UpgradePublishedSchemaError::IncompatibleSchema(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpgradePublishedSchemaError::IncompatibleSchema,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpgradePublishedSchemaError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpgradePublishedSchemaError::InternalService,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(UpgradePublishedSchemaError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpgradePublishedSchemaError::InvalidArn,
+                    )
                 }
                 "InvalidAttachmentException" => {
-                    return RusotoError::Service(UpgradePublishedSchemaError::InvalidAttachment(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpgradePublishedSchemaError::InvalidAttachment,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpgradePublishedSchemaError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpgradePublishedSchemaError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpgradePublishedSchemaError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpgradePublishedSchemaError::ResourceNotFound,
+                    )
                 }
                 "RetryableConflictException" => {
-                    return RusotoError::Service(UpgradePublishedSchemaError::RetryableConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpgradePublishedSchemaError::RetryableConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8622,11 +10912,12 @@ pub struct CloudDirectoryClient {
 impl CloudDirectoryClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> CloudDirectoryClient {
         CloudDirectoryClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "CloudDirectory"),
         }
     }
 
@@ -8641,12 +10932,32 @@ impl CloudDirectoryClient {
     {
         CloudDirectoryClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "CloudDirectory"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CloudDirectoryClient {
-        CloudDirectoryClient { client, region }
+        CloudDirectoryClient {
+            client,
+            region: region_for_service(region, "CloudDirectory"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> CloudDirectoryClient {
+        CloudDirectoryClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("CloudDirectory"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CloudDirectoryClient {
+        CloudDirectoryClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -8661,6 +10972,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/facets";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("AddFacetToObject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8693,6 +11005,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/apply";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ApplySchema");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8725,6 +11038,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/attach";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("AttachObject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8757,6 +11071,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/policy/attach";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("AttachPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8789,6 +11104,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/index/attach";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("AttachToIndex");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8821,6 +11137,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/attach";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("AttachTypedLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8853,6 +11170,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/batchread";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("BatchRead");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8886,6 +11204,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/batchwrite";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("BatchWrite");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8918,6 +11237,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/directory/create";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("CreateDirectory");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8950,6 +11270,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/facet/create";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("CreateFacet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8982,6 +11303,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/index";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("CreateIndex");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9014,6 +11336,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("CreateObject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9046,6 +11369,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/create";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("CreateSchema");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9077,6 +11401,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/facet/create";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("CreateTypedLinkFacet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9109,6 +11434,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/directory";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("DeleteDirectory");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_header("x-amz-data-partition", &input.directory_arn.to_string());
@@ -9139,6 +11465,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/facet/delete";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("DeleteFacet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9171,6 +11498,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/delete";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("DeleteObject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9203,6 +11531,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("DeleteSchema");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_header("x-amz-data-partition", &input.schema_arn.to_string());
@@ -9233,6 +11562,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/facet/delete";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("DeleteTypedLinkFacet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9265,6 +11595,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/index/detach";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("DetachFromIndex");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9297,6 +11628,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/detach";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("DetachObject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9329,6 +11661,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/policy/detach";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("DetachPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9361,6 +11694,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/detach";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("DetachTypedLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9392,6 +11726,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/directory/disable";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("DisableDirectory");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_header("x-amz-data-partition", &input.directory_arn.to_string());
@@ -9422,6 +11757,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/directory/enable";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("EnableDirectory");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_header("x-amz-data-partition", &input.directory_arn.to_string());
@@ -9452,6 +11788,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/getappliedschema";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("GetAppliedSchemaVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9483,6 +11820,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/directory/get";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("GetDirectory");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_header("x-amz-data-partition", &input.directory_arn.to_string());
@@ -9513,6 +11851,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/facet";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("GetFacet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9545,6 +11884,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/attributes/get";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("GetLinkAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9577,6 +11917,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/attributes/get";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("GetObjectAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9610,6 +11951,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/information";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("GetObjectInformation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9643,6 +11985,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/json";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("GetSchemaAsJson");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_header("x-amz-data-partition", &input.schema_arn.to_string());
@@ -9674,6 +12017,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/facet/get";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("GetTypedLinkFacetInformation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9706,6 +12050,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/applied";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListAppliedSchemaArns");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9737,6 +12082,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/indices";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListAttachedIndices");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9771,6 +12117,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/development";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListDevelopmentSchemaArns");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9802,6 +12149,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/directory/list";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListDirectories");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9833,6 +12181,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/facet/attributes";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListFacetAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9865,6 +12214,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/facet/list";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListFacetNames");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9897,6 +12247,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/incoming";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListIncomingTypedLinks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9929,6 +12280,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/index/targets";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListIndex");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9962,6 +12314,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/managed";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListManagedSchemaArns");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9993,6 +12346,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/attributes";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListObjectAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10026,6 +12380,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/children";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListObjectChildren");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10059,6 +12414,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/parentpaths";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListObjectParentPaths");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10091,6 +12447,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/parent";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListObjectParents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10124,6 +12481,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/policy";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListObjectPolicies");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10157,6 +12515,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/outgoing";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListOutgoingTypedLinks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10189,6 +12548,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/policy/attachment";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListPolicyAttachments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10222,6 +12582,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/published";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListPublishedSchemaArns");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10253,6 +12614,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/tags";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10285,6 +12647,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/facet/attributes";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListTypedLinkFacetAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10317,6 +12680,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/facet/list";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("ListTypedLinkFacetNames");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10349,6 +12713,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/policy/lookup";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("LookupPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10381,6 +12746,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/publish";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("PublishSchema");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10416,6 +12782,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/json";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("PutSchemaFromJson");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10448,6 +12815,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/facets/delete";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("RemoveFacetFromObject");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10480,6 +12848,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/tags/add";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10511,6 +12880,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/tags/remove";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10542,6 +12912,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/facet";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("UpdateFacet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10574,6 +12945,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/attributes/update";
 
         let mut request = SignedRequest::new("POST", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("UpdateLinkAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10606,6 +12978,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/object/update";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("UpdateObjectAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10638,6 +13011,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/update";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("UpdateSchema");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10670,6 +13044,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/typedlink/facet";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("UpdateTypedLinkFacet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10702,6 +13077,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/upgradeapplied";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("UpgradeAppliedSchema");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10733,6 +13109,7 @@ impl CloudDirectory for CloudDirectoryClient {
         let request_uri = "/amazonclouddirectory/2017-01-11/schema/upgradepublished";
 
         let mut request = SignedRequest::new("PUT", "clouddirectory", &self.region, &request_uri);
+        request.set_operation("UpgradePublishedSchema");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10755,3 +13132,646 @@ impl CloudDirectory for CloudDirectoryClient {
         }
     }
 }
+
+/// A blocking client for the Amazon CloudDirectory API, running the calls of a `CloudDirectoryClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `CloudDirectoryClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CloudDirectoryBlockingClient {
+    client: CloudDirectoryClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CloudDirectoryBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> CloudDirectoryBlockingClient {
+        CloudDirectoryBlockingClient::from_client(CloudDirectoryClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CloudDirectoryBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CloudDirectoryBlockingClient::from_client(CloudDirectoryClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> CloudDirectoryBlockingClient {
+        CloudDirectoryBlockingClient::from_client(CloudDirectoryClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> CloudDirectoryBlockingClient {
+        CloudDirectoryBlockingClient::from_client(CloudDirectoryClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: CloudDirectoryClient) -> CloudDirectoryBlockingClient {
+        CloudDirectoryBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> CloudDirectoryBlockingClient {
+        CloudDirectoryBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CloudDirectoryBlockingClient {
+        CloudDirectoryBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`CloudDirectory::add_facet_to_object`].
+    pub fn add_facet_to_object(
+        &self,
+        input: AddFacetToObjectRequest,
+    ) -> Result<AddFacetToObjectResponse, RusotoError<AddFacetToObjectError>> {
+        self.runtime
+            .block_on(self.client.add_facet_to_object(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::apply_schema`].
+    pub fn apply_schema(
+        &self,
+        input: ApplySchemaRequest,
+    ) -> Result<ApplySchemaResponse, RusotoError<ApplySchemaError>> {
+        self.runtime.block_on(self.client.apply_schema(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::attach_object`].
+    pub fn attach_object(
+        &self,
+        input: AttachObjectRequest,
+    ) -> Result<AttachObjectResponse, RusotoError<AttachObjectError>> {
+        self.runtime.block_on(self.client.attach_object(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::attach_policy`].
+    pub fn attach_policy(
+        &self,
+        input: AttachPolicyRequest,
+    ) -> Result<AttachPolicyResponse, RusotoError<AttachPolicyError>> {
+        self.runtime.block_on(self.client.attach_policy(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::attach_to_index`].
+    pub fn attach_to_index(
+        &self,
+        input: AttachToIndexRequest,
+    ) -> Result<AttachToIndexResponse, RusotoError<AttachToIndexError>> {
+        self.runtime.block_on(self.client.attach_to_index(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::attach_typed_link`].
+    pub fn attach_typed_link(
+        &self,
+        input: AttachTypedLinkRequest,
+    ) -> Result<AttachTypedLinkResponse, RusotoError<AttachTypedLinkError>> {
+        self.runtime.block_on(self.client.attach_typed_link(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::batch_read`].
+    pub fn batch_read(
+        &self,
+        input: BatchReadRequest,
+    ) -> Result<BatchReadResponse, RusotoError<BatchReadError>> {
+        self.runtime.block_on(self.client.batch_read(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::batch_write`].
+    pub fn batch_write(
+        &self,
+        input: BatchWriteRequest,
+    ) -> Result<BatchWriteResponse, RusotoError<BatchWriteError>> {
+        self.runtime.block_on(self.client.batch_write(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::create_directory`].
+    pub fn create_directory(
+        &self,
+        input: CreateDirectoryRequest,
+    ) -> Result<CreateDirectoryResponse, RusotoError<CreateDirectoryError>> {
+        self.runtime.block_on(self.client.create_directory(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::create_facet`].
+    pub fn create_facet(
+        &self,
+        input: CreateFacetRequest,
+    ) -> Result<CreateFacetResponse, RusotoError<CreateFacetError>> {
+        self.runtime.block_on(self.client.create_facet(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::create_index`].
+    pub fn create_index(
+        &self,
+        input: CreateIndexRequest,
+    ) -> Result<CreateIndexResponse, RusotoError<CreateIndexError>> {
+        self.runtime.block_on(self.client.create_index(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::create_object`].
+    pub fn create_object(
+        &self,
+        input: CreateObjectRequest,
+    ) -> Result<CreateObjectResponse, RusotoError<CreateObjectError>> {
+        self.runtime.block_on(self.client.create_object(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::create_schema`].
+    pub fn create_schema(
+        &self,
+        input: CreateSchemaRequest,
+    ) -> Result<CreateSchemaResponse, RusotoError<CreateSchemaError>> {
+        self.runtime.block_on(self.client.create_schema(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::create_typed_link_facet`].
+    pub fn create_typed_link_facet(
+        &self,
+        input: CreateTypedLinkFacetRequest,
+    ) -> Result<CreateTypedLinkFacetResponse, RusotoError<CreateTypedLinkFacetError>> {
+        self.runtime
+            .block_on(self.client.create_typed_link_facet(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::delete_directory`].
+    pub fn delete_directory(
+        &self,
+        input: DeleteDirectoryRequest,
+    ) -> Result<DeleteDirectoryResponse, RusotoError<DeleteDirectoryError>> {
+        self.runtime.block_on(self.client.delete_directory(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::delete_facet`].
+    pub fn delete_facet(
+        &self,
+        input: DeleteFacetRequest,
+    ) -> Result<DeleteFacetResponse, RusotoError<DeleteFacetError>> {
+        self.runtime.block_on(self.client.delete_facet(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::delete_object`].
+    pub fn delete_object(
+        &self,
+        input: DeleteObjectRequest,
+    ) -> Result<DeleteObjectResponse, RusotoError<DeleteObjectError>> {
+        self.runtime.block_on(self.client.delete_object(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::delete_schema`].
+    pub fn delete_schema(
+        &self,
+        input: DeleteSchemaRequest,
+    ) -> Result<DeleteSchemaResponse, RusotoError<DeleteSchemaError>> {
+        self.runtime.block_on(self.client.delete_schema(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::delete_typed_link_facet`].
+    pub fn delete_typed_link_facet(
+        &self,
+        input: DeleteTypedLinkFacetRequest,
+    ) -> Result<DeleteTypedLinkFacetResponse, RusotoError<DeleteTypedLinkFacetError>> {
+        self.runtime
+            .block_on(self.client.delete_typed_link_facet(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::detach_from_index`].
+    pub fn detach_from_index(
+        &self,
+        input: DetachFromIndexRequest,
+    ) -> Result<DetachFromIndexResponse, RusotoError<DetachFromIndexError>> {
+        self.runtime.block_on(self.client.detach_from_index(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::detach_object`].
+    pub fn detach_object(
+        &self,
+        input: DetachObjectRequest,
+    ) -> Result<DetachObjectResponse, RusotoError<DetachObjectError>> {
+        self.runtime.block_on(self.client.detach_object(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::detach_policy`].
+    pub fn detach_policy(
+        &self,
+        input: DetachPolicyRequest,
+    ) -> Result<DetachPolicyResponse, RusotoError<DetachPolicyError>> {
+        self.runtime.block_on(self.client.detach_policy(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::detach_typed_link`].
+    pub fn detach_typed_link(
+        &self,
+        input: DetachTypedLinkRequest,
+    ) -> Result<(), RusotoError<DetachTypedLinkError>> {
+        self.runtime.block_on(self.client.detach_typed_link(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::disable_directory`].
+    pub fn disable_directory(
+        &self,
+        input: DisableDirectoryRequest,
+    ) -> Result<DisableDirectoryResponse, RusotoError<DisableDirectoryError>> {
+        self.runtime.block_on(self.client.disable_directory(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::enable_directory`].
+    pub fn enable_directory(
+        &self,
+        input: EnableDirectoryRequest,
+    ) -> Result<EnableDirectoryResponse, RusotoError<EnableDirectoryError>> {
+        self.runtime.block_on(self.client.enable_directory(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::get_applied_schema_version`].
+    pub fn get_applied_schema_version(
+        &self,
+        input: GetAppliedSchemaVersionRequest,
+    ) -> Result<GetAppliedSchemaVersionResponse, RusotoError<GetAppliedSchemaVersionError>> {
+        self.runtime
+            .block_on(self.client.get_applied_schema_version(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::get_directory`].
+    pub fn get_directory(
+        &self,
+        input: GetDirectoryRequest,
+    ) -> Result<GetDirectoryResponse, RusotoError<GetDirectoryError>> {
+        self.runtime.block_on(self.client.get_directory(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::get_facet`].
+    pub fn get_facet(
+        &self,
+        input: GetFacetRequest,
+    ) -> Result<GetFacetResponse, RusotoError<GetFacetError>> {
+        self.runtime.block_on(self.client.get_facet(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::get_link_attributes`].
+    pub fn get_link_attributes(
+        &self,
+        input: GetLinkAttributesRequest,
+    ) -> Result<GetLinkAttributesResponse, RusotoError<GetLinkAttributesError>> {
+        self.runtime
+            .block_on(self.client.get_link_attributes(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::get_object_attributes`].
+    pub fn get_object_attributes(
+        &self,
+        input: GetObjectAttributesRequest,
+    ) -> Result<GetObjectAttributesResponse, RusotoError<GetObjectAttributesError>> {
+        self.runtime
+            .block_on(self.client.get_object_attributes(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::get_object_information`].
+    pub fn get_object_information(
+        &self,
+        input: GetObjectInformationRequest,
+    ) -> Result<GetObjectInformationResponse, RusotoError<GetObjectInformationError>> {
+        self.runtime
+            .block_on(self.client.get_object_information(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::get_schema_as_json`].
+    pub fn get_schema_as_json(
+        &self,
+        input: GetSchemaAsJsonRequest,
+    ) -> Result<GetSchemaAsJsonResponse, RusotoError<GetSchemaAsJsonError>> {
+        self.runtime.block_on(self.client.get_schema_as_json(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::get_typed_link_facet_information`].
+    pub fn get_typed_link_facet_information(
+        &self,
+        input: GetTypedLinkFacetInformationRequest,
+    ) -> Result<GetTypedLinkFacetInformationResponse, RusotoError<GetTypedLinkFacetInformationError>>
+    {
+        self.runtime
+            .block_on(self.client.get_typed_link_facet_information(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::list_applied_schema_arns`].
+    pub fn list_applied_schema_arns(
+        &self,
+        input: ListAppliedSchemaArnsRequest,
+    ) -> Result<ListAppliedSchemaArnsResponse, RusotoError<ListAppliedSchemaArnsError>> {
+        self.runtime
+            .block_on(self.client.list_applied_schema_arns(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::list_attached_indices`].
+    pub fn list_attached_indices(
+        &self,
+        input: ListAttachedIndicesRequest,
+    ) -> Result<ListAttachedIndicesResponse, RusotoError<ListAttachedIndicesError>> {
+        self.runtime
+            .block_on(self.client.list_attached_indices(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::list_development_schema_arns`].
+    pub fn list_development_schema_arns(
+        &self,
+        input: ListDevelopmentSchemaArnsRequest,
+    ) -> Result<ListDevelopmentSchemaArnsResponse, RusotoError<ListDevelopmentSchemaArnsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_development_schema_arns(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::list_directories`].
+    pub fn list_directories(
+        &self,
+        input: ListDirectoriesRequest,
+    ) -> Result<ListDirectoriesResponse, RusotoError<ListDirectoriesError>> {
+        self.runtime.block_on(self.client.list_directories(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::list_facet_attributes`].
+    pub fn list_facet_attributes(
+        &self,
+        input: ListFacetAttributesRequest,
+    ) -> Result<ListFacetAttributesResponse, RusotoError<ListFacetAttributesError>> {
+        self.runtime
+            .block_on(self.client.list_facet_attributes(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::list_facet_names`].
+    pub fn list_facet_names(
+        &self,
+        input: ListFacetNamesRequest,
+    ) -> Result<ListFacetNamesResponse, RusotoError<ListFacetNamesError>> {
+        self.runtime.block_on(self.client.list_facet_names(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::list_incoming_typed_links`].
+    pub fn list_incoming_typed_links(
+        &self,
+        input: ListIncomingTypedLinksRequest,
+    ) -> Result<ListIncomingTypedLinksResponse, RusotoError<ListIncomingTypedLinksError>> {
+        self.runtime
+            .block_on(self.client.list_incoming_typed_links(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::list_index`].
+    pub fn list_index(
+        &self,
+        input: ListIndexRequest,
+    ) -> Result<ListIndexResponse, RusotoError<ListIndexError>> {
+        self.runtime.block_on(self.client.list_index(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::list_managed_schema_arns`].
+    pub fn list_managed_schema_arns(
+        &self,
+        input: ListManagedSchemaArnsRequest,
+    ) -> Result<ListManagedSchemaArnsResponse, RusotoError<ListManagedSchemaArnsError>> {
+        self.runtime
+            .block_on(self.client.list_managed_schema_arns(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::list_object_attributes`].
+    pub fn list_object_attributes(
+        &self,
+        input: ListObjectAttributesRequest,
+    ) -> Result<ListObjectAttributesResponse, RusotoError<ListObjectAttributesError>> {
+        self.runtime
+            .block_on(self.client.list_object_attributes(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::list_object_children`].
+    pub fn list_object_children(
+        &self,
+        input: ListObjectChildrenRequest,
+    ) -> Result<ListObjectChildrenResponse, RusotoError<ListObjectChildrenError>> {
+        self.runtime
+            .block_on(self.client.list_object_children(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::list_object_parent_paths`].
+    pub fn list_object_parent_paths(
+        &self,
+        input: ListObjectParentPathsRequest,
+    ) -> Result<ListObjectParentPathsResponse, RusotoError<ListObjectParentPathsError>> {
+        self.runtime
+            .block_on(self.client.list_object_parent_paths(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::list_object_parents`].
+    pub fn list_object_parents(
+        &self,
+        input: ListObjectParentsRequest,
+    ) -> Result<ListObjectParentsResponse, RusotoError<ListObjectParentsError>> {
+        self.runtime
+            .block_on(self.client.list_object_parents(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::list_object_policies`].
+    pub fn list_object_policies(
+        &self,
+        input: ListObjectPoliciesRequest,
+    ) -> Result<ListObjectPoliciesResponse, RusotoError<ListObjectPoliciesError>> {
+        self.runtime
+            .block_on(self.client.list_object_policies(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::list_outgoing_typed_links`].
+    pub fn list_outgoing_typed_links(
+        &self,
+        input: ListOutgoingTypedLinksRequest,
+    ) -> Result<ListOutgoingTypedLinksResponse, RusotoError<ListOutgoingTypedLinksError>> {
+        self.runtime
+            .block_on(self.client.list_outgoing_typed_links(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::list_policy_attachments`].
+    pub fn list_policy_attachments(
+        &self,
+        input: ListPolicyAttachmentsRequest,
+    ) -> Result<ListPolicyAttachmentsResponse, RusotoError<ListPolicyAttachmentsError>> {
+        self.runtime
+            .block_on(self.client.list_policy_attachments(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::list_published_schema_arns`].
+    pub fn list_published_schema_arns(
+        &self,
+        input: ListPublishedSchemaArnsRequest,
+    ) -> Result<ListPublishedSchemaArnsResponse, RusotoError<ListPublishedSchemaArnsError>> {
+        self.runtime
+            .block_on(self.client.list_published_schema_arns(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::list_typed_link_facet_attributes`].
+    pub fn list_typed_link_facet_attributes(
+        &self,
+        input: ListTypedLinkFacetAttributesRequest,
+    ) -> Result<ListTypedLinkFacetAttributesResponse, RusotoError<ListTypedLinkFacetAttributesError>>
+    {
+        self.runtime
+            .block_on(self.client.list_typed_link_facet_attributes(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::list_typed_link_facet_names`].
+    pub fn list_typed_link_facet_names(
+        &self,
+        input: ListTypedLinkFacetNamesRequest,
+    ) -> Result<ListTypedLinkFacetNamesResponse, RusotoError<ListTypedLinkFacetNamesError>> {
+        self.runtime
+            .block_on(self.client.list_typed_link_facet_names(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::lookup_policy`].
+    pub fn lookup_policy(
+        &self,
+        input: LookupPolicyRequest,
+    ) -> Result<LookupPolicyResponse, RusotoError<LookupPolicyError>> {
+        self.runtime.block_on(self.client.lookup_policy(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::publish_schema`].
+    pub fn publish_schema(
+        &self,
+        input: PublishSchemaRequest,
+    ) -> Result<PublishSchemaResponse, RusotoError<PublishSchemaError>> {
+        self.runtime.block_on(self.client.publish_schema(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::put_schema_from_json`].
+    pub fn put_schema_from_json(
+        &self,
+        input: PutSchemaFromJsonRequest,
+    ) -> Result<PutSchemaFromJsonResponse, RusotoError<PutSchemaFromJsonError>> {
+        self.runtime
+            .block_on(self.client.put_schema_from_json(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::remove_facet_from_object`].
+    pub fn remove_facet_from_object(
+        &self,
+        input: RemoveFacetFromObjectRequest,
+    ) -> Result<RemoveFacetFromObjectResponse, RusotoError<RemoveFacetFromObjectError>> {
+        self.runtime
+            .block_on(self.client.remove_facet_from_object(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::update_facet`].
+    pub fn update_facet(
+        &self,
+        input: UpdateFacetRequest,
+    ) -> Result<UpdateFacetResponse, RusotoError<UpdateFacetError>> {
+        self.runtime.block_on(self.client.update_facet(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::update_link_attributes`].
+    pub fn update_link_attributes(
+        &self,
+        input: UpdateLinkAttributesRequest,
+    ) -> Result<UpdateLinkAttributesResponse, RusotoError<UpdateLinkAttributesError>> {
+        self.runtime
+            .block_on(self.client.update_link_attributes(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::update_object_attributes`].
+    pub fn update_object_attributes(
+        &self,
+        input: UpdateObjectAttributesRequest,
+    ) -> Result<UpdateObjectAttributesResponse, RusotoError<UpdateObjectAttributesError>> {
+        self.runtime
+            .block_on(self.client.update_object_attributes(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::update_schema`].
+    pub fn update_schema(
+        &self,
+        input: UpdateSchemaRequest,
+    ) -> Result<UpdateSchemaResponse, RusotoError<UpdateSchemaError>> {
+        self.runtime.block_on(self.client.update_schema(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::update_typed_link_facet`].
+    pub fn update_typed_link_facet(
+        &self,
+        input: UpdateTypedLinkFacetRequest,
+    ) -> Result<UpdateTypedLinkFacetResponse, RusotoError<UpdateTypedLinkFacetError>> {
+        self.runtime
+            .block_on(self.client.update_typed_link_facet(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::upgrade_applied_schema`].
+    pub fn upgrade_applied_schema(
+        &self,
+        input: UpgradeAppliedSchemaRequest,
+    ) -> Result<UpgradeAppliedSchemaResponse, RusotoError<UpgradeAppliedSchemaError>> {
+        self.runtime
+            .block_on(self.client.upgrade_applied_schema(input))
+    }
+
+    /// Blocking version of [`CloudDirectory::upgrade_published_schema`].
+    pub fn upgrade_published_schema(
+        &self,
+        input: UpgradePublishedSchemaRequest,
+    ) -> Result<UpgradePublishedSchemaResponse, RusotoError<UpgradePublishedSchemaError>> {
+        self.runtime
+            .block_on(self.client.upgrade_published_schema(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -9522,9 +9523,12 @@ impl CancelUpdateStackError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "TokenAlreadyExistsException" => {
-                        return RusotoError::Service(CancelUpdateStackError::TokenAlreadyExists(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CancelUpdateStackError::TokenAlreadyExists,
+                        )
                     }
                     _ => {}
                 }
@@ -9566,8 +9570,11 @@ impl ContinueUpdateRollbackError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "TokenAlreadyExistsException" => {
-                        return RusotoError::Service(
-                            ContinueUpdateRollbackError::TokenAlreadyExists(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ContinueUpdateRollbackError::TokenAlreadyExists,
                         )
                     }
                     _ => {}
@@ -9614,19 +9621,28 @@ impl CreateChangeSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AlreadyExistsException" => {
-                        return RusotoError::Service(CreateChangeSetError::AlreadyExists(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateChangeSetError::AlreadyExists,
+                        )
                     }
                     "InsufficientCapabilitiesException" => {
-                        return RusotoError::Service(
-                            CreateChangeSetError::InsufficientCapabilities(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateChangeSetError::InsufficientCapabilities,
                         )
                     }
                     "LimitExceededException" => {
-                        return RusotoError::Service(CreateChangeSetError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateChangeSetError::LimitExceeded,
+                        )
                     }
                     _ => {}
                 }
@@ -9676,24 +9692,36 @@ impl CreateStackError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AlreadyExistsException" => {
-                        return RusotoError::Service(CreateStackError::AlreadyExists(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateStackError::AlreadyExists,
+                        )
                     }
                     "InsufficientCapabilitiesException" => {
-                        return RusotoError::Service(CreateStackError::InsufficientCapabilities(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateStackError::InsufficientCapabilities,
+                        )
                     }
                     "LimitExceededException" => {
-                        return RusotoError::Service(CreateStackError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateStackError::LimitExceeded,
+                        )
                     }
                     "TokenAlreadyExistsException" => {
-                        return RusotoError::Service(CreateStackError::TokenAlreadyExists(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateStackError::TokenAlreadyExists,
+                        )
                     }
                     _ => {}
                 }
@@ -9748,36 +9776,52 @@ impl CreateStackInstancesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidOperationException" => {
-                        return RusotoError::Service(CreateStackInstancesError::InvalidOperation(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateStackInstancesError::InvalidOperation,
+                        )
                     }
                     "LimitExceededException" => {
-                        return RusotoError::Service(CreateStackInstancesError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateStackInstancesError::LimitExceeded,
+                        )
                     }
                     "OperationIdAlreadyExistsException" => {
-                        return RusotoError::Service(
-                            CreateStackInstancesError::OperationIdAlreadyExists(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateStackInstancesError::OperationIdAlreadyExists,
                         )
                     }
                     "OperationInProgressException" => {
-                        return RusotoError::Service(
-                            CreateStackInstancesError::OperationInProgress(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateStackInstancesError::OperationInProgress,
                         )
                     }
                     "StackSetNotFoundException" => {
-                        return RusotoError::Service(CreateStackInstancesError::StackSetNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateStackInstancesError::StackSetNotFound,
+                        )
                     }
                     "StaleRequestException" => {
-                        return RusotoError::Service(CreateStackInstancesError::StaleRequest(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateStackInstancesError::StaleRequest,
+                        )
                     }
                     _ => {}
                 }
@@ -9830,19 +9874,28 @@ impl CreateStackSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CreatedButModifiedException" => {
-                        return RusotoError::Service(CreateStackSetError::CreatedButModified(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateStackSetError::CreatedButModified,
+                        )
                     }
                     "LimitExceededException" => {
-                        return RusotoError::Service(CreateStackSetError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateStackSetError::LimitExceeded,
+                        )
                     }
                     "NameAlreadyExistsException" => {
-                        return RusotoError::Service(CreateStackSetError::NameAlreadyExists(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateStackSetError::NameAlreadyExists,
+                        )
                     }
                     _ => {}
                 }
@@ -9886,9 +9939,12 @@ impl DeleteChangeSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidChangeSetStatus" => {
-                        return RusotoError::Service(DeleteChangeSetError::InvalidChangeSetStatus(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteChangeSetError::InvalidChangeSetStatus,
+                        )
                     }
                     _ => {}
                 }
@@ -9930,9 +9986,12 @@ impl DeleteStackError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "TokenAlreadyExistsException" => {
-                        return RusotoError::Service(DeleteStackError::TokenAlreadyExists(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteStackError::TokenAlreadyExists,
+                        )
                     }
                     _ => {}
                 }
@@ -9982,31 +10041,44 @@ impl DeleteStackInstancesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidOperationException" => {
-                        return RusotoError::Service(DeleteStackInstancesError::InvalidOperation(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteStackInstancesError::InvalidOperation,
+                        )
                     }
                     "OperationIdAlreadyExistsException" => {
-                        return RusotoError::Service(
-                            DeleteStackInstancesError::OperationIdAlreadyExists(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteStackInstancesError::OperationIdAlreadyExists,
                         )
                     }
                     "OperationInProgressException" => {
-                        return RusotoError::Service(
-                            DeleteStackInstancesError::OperationInProgress(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteStackInstancesError::OperationInProgress,
                         )
                     }
                     "StackSetNotFoundException" => {
-                        return RusotoError::Service(DeleteStackInstancesError::StackSetNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteStackInstancesError::StackSetNotFound,
+                        )
                     }
                     "StaleRequestException" => {
-                        return RusotoError::Service(DeleteStackInstancesError::StaleRequest(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteStackInstancesError::StaleRequest,
+                        )
                     }
                     _ => {}
                 }
@@ -10056,14 +10128,20 @@ impl DeleteStackSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "OperationInProgressException" => {
-                        return RusotoError::Service(DeleteStackSetError::OperationInProgress(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteStackSetError::OperationInProgress,
+                        )
                     }
                     "StackSetNotEmptyException" => {
-                        return RusotoError::Service(DeleteStackSetError::StackSetNotEmpty(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteStackSetError::StackSetNotEmpty,
+                        )
                     }
                     _ => {}
                 }
@@ -10108,14 +10186,20 @@ impl DeregisterTypeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CFNRegistryException" => {
-                        return RusotoError::Service(DeregisterTypeError::CFNRegistry(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeregisterTypeError::CFNRegistry,
+                        )
                     }
                     "TypeNotFoundException" => {
-                        return RusotoError::Service(DeregisterTypeError::TypeNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeregisterTypeError::TypeNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -10192,9 +10276,12 @@ impl DescribeChangeSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ChangeSetNotFound" => {
-                        return RusotoError::Service(DescribeChangeSetError::ChangeSetNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeChangeSetError::ChangeSetNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -10308,14 +10395,20 @@ impl DescribeStackInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "StackInstanceNotFoundException" => {
-                        return RusotoError::Service(
-                            DescribeStackInstanceError::StackInstanceNotFound(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeStackInstanceError::StackInstanceNotFound,
                         )
                     }
                     "StackSetNotFoundException" => {
-                        return RusotoError::Service(DescribeStackInstanceError::StackSetNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeStackInstanceError::StackSetNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -10462,9 +10555,12 @@ impl DescribeStackSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "StackSetNotFoundException" => {
-                        return RusotoError::Service(DescribeStackSetError::StackSetNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeStackSetError::StackSetNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -10508,13 +10604,19 @@ impl DescribeStackSetOperationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "OperationNotFoundException" => {
-                        return RusotoError::Service(
-                            DescribeStackSetOperationError::OperationNotFound(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeStackSetOperationError::OperationNotFound,
                         )
                     }
                     "StackSetNotFoundException" => {
-                        return RusotoError::Service(
-                            DescribeStackSetOperationError::StackSetNotFound(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeStackSetOperationError::StackSetNotFound,
                         )
                     }
                     _ => {}
@@ -10594,14 +10696,20 @@ impl DescribeTypeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CFNRegistryException" => {
-                        return RusotoError::Service(DescribeTypeError::CFNRegistry(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeTypeError::CFNRegistry,
+                        )
                     }
                     "TypeNotFoundException" => {
-                        return RusotoError::Service(DescribeTypeError::TypeNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeTypeError::TypeNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -10644,9 +10752,12 @@ impl DescribeTypeRegistrationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CFNRegistryException" => {
-                        return RusotoError::Service(DescribeTypeRegistrationError::CFNRegistry(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeTypeRegistrationError::CFNRegistry,
+                        )
                     }
                     _ => {}
                 }
@@ -10760,19 +10871,28 @@ impl DetectStackSetDriftError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidOperationException" => {
-                        return RusotoError::Service(DetectStackSetDriftError::InvalidOperation(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DetectStackSetDriftError::InvalidOperation,
+                        )
                     }
                     "OperationInProgressException" => {
-                        return RusotoError::Service(DetectStackSetDriftError::OperationInProgress(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DetectStackSetDriftError::OperationInProgress,
+                        )
                     }
                     "StackSetNotFoundException" => {
-                        return RusotoError::Service(DetectStackSetDriftError::StackSetNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DetectStackSetDriftError::StackSetNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -10856,24 +10976,36 @@ impl ExecuteChangeSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ChangeSetNotFound" => {
-                        return RusotoError::Service(ExecuteChangeSetError::ChangeSetNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ExecuteChangeSetError::ChangeSetNotFound,
+                        )
                     }
                     "InsufficientCapabilitiesException" => {
-                        return RusotoError::Service(
-                            ExecuteChangeSetError::InsufficientCapabilities(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ExecuteChangeSetError::InsufficientCapabilities,
                         )
                     }
                     "InvalidChangeSetStatus" => {
-                        return RusotoError::Service(ExecuteChangeSetError::InvalidChangeSetStatus(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ExecuteChangeSetError::InvalidChangeSetStatus,
+                        )
                     }
                     "TokenAlreadyExistsException" => {
-                        return RusotoError::Service(ExecuteChangeSetError::TokenAlreadyExists(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ExecuteChangeSetError::TokenAlreadyExists,
+                        )
                     }
                     _ => {}
                 }
@@ -10952,9 +11084,12 @@ impl GetTemplateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ChangeSetNotFound" => {
-                        return RusotoError::Service(GetTemplateError::ChangeSetNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetTemplateError::ChangeSetNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -10996,9 +11131,12 @@ impl GetTemplateSummaryError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "StackSetNotFoundException" => {
-                        return RusotoError::Service(GetTemplateSummaryError::StackSetNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetTemplateSummaryError::StackSetNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -11142,9 +11280,12 @@ impl ListStackInstancesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "StackSetNotFoundException" => {
-                        return RusotoError::Service(ListStackInstancesError::StackSetNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListStackInstancesError::StackSetNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -11224,17 +11365,19 @@ impl ListStackSetOperationResultsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "OperationNotFoundException" => {
-                        return RusotoError::Service(
-                            ListStackSetOperationResultsError::OperationNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListStackSetOperationResultsError::OperationNotFound,
                         )
                     }
                     "StackSetNotFoundException" => {
-                        return RusotoError::Service(
-                            ListStackSetOperationResultsError::StackSetNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListStackSetOperationResultsError::StackSetNotFound,
                         )
                     }
                     _ => {}
@@ -11282,9 +11425,12 @@ impl ListStackSetOperationsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "StackSetNotFoundException" => {
-                        return RusotoError::Service(ListStackSetOperationsError::StackSetNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListStackSetOperationsError::StackSetNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -11394,9 +11540,12 @@ impl ListTypeRegistrationsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CFNRegistryException" => {
-                        return RusotoError::Service(ListTypeRegistrationsError::CFNRegistry(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListTypeRegistrationsError::CFNRegistry,
+                        )
                     }
                     _ => {}
                 }
@@ -11438,9 +11587,12 @@ impl ListTypeVersionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CFNRegistryException" => {
-                        return RusotoError::Service(ListTypeVersionsError::CFNRegistry(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListTypeVersionsError::CFNRegistry,
+                        )
                     }
                     _ => {}
                 }
@@ -11482,9 +11634,12 @@ impl ListTypesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CFNRegistryException" => {
-                        return RusotoError::Service(ListTypesError::CFNRegistry(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListTypesError::CFNRegistry,
+                        )
                     }
                     _ => {}
                 }
@@ -11528,17 +11683,19 @@ impl RecordHandlerProgressError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidStateTransition" => {
-                        return RusotoError::Service(
-                            RecordHandlerProgressError::InvalidStateTransition(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RecordHandlerProgressError::InvalidStateTransition,
                         )
                     }
                     "ConditionalCheckFailed" => {
-                        return RusotoError::Service(
-                            RecordHandlerProgressError::OperationStatusCheckFailed(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RecordHandlerProgressError::OperationStatusCheckFailed,
                         )
                     }
                     _ => {}
@@ -11584,9 +11741,12 @@ impl RegisterTypeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CFNRegistryException" => {
-                        return RusotoError::Service(RegisterTypeError::CFNRegistry(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            RegisterTypeError::CFNRegistry,
+                        )
                     }
                     _ => {}
                 }
@@ -11664,14 +11824,20 @@ impl SetTypeDefaultVersionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CFNRegistryException" => {
-                        return RusotoError::Service(SetTypeDefaultVersionError::CFNRegistry(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SetTypeDefaultVersionError::CFNRegistry,
+                        )
                     }
                     "TypeNotFoundException" => {
-                        return RusotoError::Service(SetTypeDefaultVersionError::TypeNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SetTypeDefaultVersionError::TypeNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -11752,19 +11918,28 @@ impl StopStackSetOperationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidOperationException" => {
-                        return RusotoError::Service(StopStackSetOperationError::InvalidOperation(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            StopStackSetOperationError::InvalidOperation,
+                        )
                     }
                     "OperationNotFoundException" => {
-                        return RusotoError::Service(StopStackSetOperationError::OperationNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            StopStackSetOperationError::OperationNotFound,
+                        )
                     }
                     "StackSetNotFoundException" => {
-                        return RusotoError::Service(StopStackSetOperationError::StackSetNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            StopStackSetOperationError::StackSetNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -11810,14 +11985,20 @@ impl UpdateStackError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientCapabilitiesException" => {
-                        return RusotoError::Service(UpdateStackError::InsufficientCapabilities(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateStackError::InsufficientCapabilities,
+                        )
                     }
                     "TokenAlreadyExistsException" => {
-                        return RusotoError::Service(UpdateStackError::TokenAlreadyExists(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateStackError::TokenAlreadyExists,
+                        )
                     }
                     _ => {}
                 }
@@ -11870,36 +12051,52 @@ impl UpdateStackInstancesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidOperationException" => {
-                        return RusotoError::Service(UpdateStackInstancesError::InvalidOperation(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateStackInstancesError::InvalidOperation,
+                        )
                     }
                     "OperationIdAlreadyExistsException" => {
-                        return RusotoError::Service(
-                            UpdateStackInstancesError::OperationIdAlreadyExists(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateStackInstancesError::OperationIdAlreadyExists,
                         )
                     }
                     "OperationInProgressException" => {
-                        return RusotoError::Service(
-                            UpdateStackInstancesError::OperationInProgress(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateStackInstancesError::OperationInProgress,
                         )
                     }
                     "StackInstanceNotFoundException" => {
-                        return RusotoError::Service(
-                            UpdateStackInstancesError::StackInstanceNotFound(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateStackInstancesError::StackInstanceNotFound,
                         )
                     }
                     "StackSetNotFoundException" => {
-                        return RusotoError::Service(UpdateStackInstancesError::StackSetNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateStackInstancesError::StackSetNotFound,
+                        )
                     }
                     "StaleRequestException" => {
-                        return RusotoError::Service(UpdateStackInstancesError::StaleRequest(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateStackInstancesError::StaleRequest,
+                        )
                     }
                     _ => {}
                 }
@@ -11958,34 +12155,52 @@ impl UpdateStackSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidOperationException" => {
-                        return RusotoError::Service(UpdateStackSetError::InvalidOperation(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateStackSetError::InvalidOperation,
+                        )
                     }
                     "OperationIdAlreadyExistsException" => {
-                        return RusotoError::Service(UpdateStackSetError::OperationIdAlreadyExists(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateStackSetError::OperationIdAlreadyExists,
+                        )
                     }
                     "OperationInProgressException" => {
-                        return RusotoError::Service(UpdateStackSetError::OperationInProgress(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateStackSetError::OperationInProgress,
+                        )
                     }
                     "StackInstanceNotFoundException" => {
-                        return RusotoError::Service(UpdateStackSetError::StackInstanceNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateStackSetError::StackInstanceNotFound,
+                        )
                     }
                     "StackSetNotFoundException" => {
-                        return RusotoError::Service(UpdateStackSetError::StackSetNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateStackSetError::StackSetNotFound,
+                        )
                     }
                     "StaleRequestException" => {
-                        return RusotoError::Service(UpdateStackSetError::StaleRequest(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateStackSetError::StaleRequest,
+                        )
                     }
                     _ => {}
                 }
@@ -12432,11 +12647,12 @@ pub struct CloudFormationClient {
 impl CloudFormationClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> CloudFormationClient {
         CloudFormationClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "CloudFormation"),
         }
     }
 
@@ -12451,12 +12667,32 @@ impl CloudFormationClient {
     {
         CloudFormationClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "CloudFormation"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CloudFormationClient {
-        CloudFormationClient { client, region }
+        CloudFormationClient {
+            client,
+            region: region_for_service(region, "CloudFormation"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> CloudFormationClient {
+        CloudFormationClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("CloudFormation"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CloudFormationClient {
+        CloudFormationClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -12468,6 +12704,7 @@ impl CloudFormation for CloudFormationClient {
         input: CancelUpdateStackInput,
     ) -> Result<(), RusotoError<CancelUpdateStackError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("CancelUpdateStack");
         let params = self.new_params("CancelUpdateStack");
         let mut params = params;
         CancelUpdateStackInputSerializer::serialize(&mut params, "", &input);
@@ -12488,6 +12725,7 @@ impl CloudFormation for CloudFormationClient {
         input: ContinueUpdateRollbackInput,
     ) -> Result<ContinueUpdateRollbackOutput, RusotoError<ContinueUpdateRollbackError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ContinueUpdateRollback");
         let params = self.new_params("ContinueUpdateRollback");
         let mut params = params;
         ContinueUpdateRollbackInputSerializer::serialize(&mut params, "", &input);
@@ -12510,6 +12748,7 @@ impl CloudFormation for CloudFormationClient {
         input: CreateChangeSetInput,
     ) -> Result<CreateChangeSetOutput, RusotoError<CreateChangeSetError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("CreateChangeSet");
         let params = self.new_params("CreateChangeSet");
         let mut params = params;
         CreateChangeSetInputSerializer::serialize(&mut params, "", &input);
@@ -12541,6 +12780,7 @@ impl CloudFormation for CloudFormationClient {
         input: CreateStackInput,
     ) -> Result<CreateStackOutput, RusotoError<CreateStackError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("CreateStack");
         let params = self.new_params("CreateStack");
         let mut params = params;
         CreateStackInputSerializer::serialize(&mut params, "", &input);
@@ -12571,6 +12811,7 @@ impl CloudFormation for CloudFormationClient {
         input: CreateStackInstancesInput,
     ) -> Result<CreateStackInstancesOutput, RusotoError<CreateStackInstancesError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("CreateStackInstances");
         let params = self.new_params("CreateStackInstances");
         let mut params = params;
         CreateStackInstancesInputSerializer::serialize(&mut params, "", &input);
@@ -12604,6 +12845,7 @@ impl CloudFormation for CloudFormationClient {
         input: CreateStackSetInput,
     ) -> Result<CreateStackSetOutput, RusotoError<CreateStackSetError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("CreateStackSet");
         let params = self.new_params("CreateStackSet");
         let mut params = params;
         CreateStackSetInputSerializer::serialize(&mut params, "", &input);
@@ -12635,6 +12877,7 @@ impl CloudFormation for CloudFormationClient {
         input: DeleteChangeSetInput,
     ) -> Result<DeleteChangeSetOutput, RusotoError<DeleteChangeSetError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DeleteChangeSet");
         let params = self.new_params("DeleteChangeSet");
         let mut params = params;
         DeleteChangeSetInputSerializer::serialize(&mut params, "", &input);
@@ -12657,6 +12900,7 @@ impl CloudFormation for CloudFormationClient {
         input: DeleteStackInput,
     ) -> Result<(), RusotoError<DeleteStackError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DeleteStack");
         let params = self.new_params("DeleteStack");
         let mut params = params;
         DeleteStackInputSerializer::serialize(&mut params, "", &input);
@@ -12677,6 +12921,7 @@ impl CloudFormation for CloudFormationClient {
         input: DeleteStackInstancesInput,
     ) -> Result<DeleteStackInstancesOutput, RusotoError<DeleteStackInstancesError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DeleteStackInstances");
         let params = self.new_params("DeleteStackInstances");
         let mut params = params;
         DeleteStackInstancesInputSerializer::serialize(&mut params, "", &input);
@@ -12710,6 +12955,7 @@ impl CloudFormation for CloudFormationClient {
         input: DeleteStackSetInput,
     ) -> Result<DeleteStackSetOutput, RusotoError<DeleteStackSetError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DeleteStackSet");
         let params = self.new_params("DeleteStackSet");
         let mut params = params;
         DeleteStackSetInputSerializer::serialize(&mut params, "", &input);
@@ -12732,6 +12978,7 @@ impl CloudFormation for CloudFormationClient {
         input: DeregisterTypeInput,
     ) -> Result<DeregisterTypeOutput, RusotoError<DeregisterTypeError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DeregisterType");
         let params = self.new_params("DeregisterType");
         let mut params = params;
         DeregisterTypeInputSerializer::serialize(&mut params, "", &input);
@@ -12754,6 +13001,7 @@ impl CloudFormation for CloudFormationClient {
         input: DescribeAccountLimitsInput,
     ) -> Result<DescribeAccountLimitsOutput, RusotoError<DescribeAccountLimitsError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeAccountLimits");
         let params = self.new_params("DescribeAccountLimits");
         let mut params = params;
         DescribeAccountLimitsInputSerializer::serialize(&mut params, "", &input);
@@ -12787,6 +13035,7 @@ impl CloudFormation for CloudFormationClient {
         input: DescribeChangeSetInput,
     ) -> Result<DescribeChangeSetOutput, RusotoError<DescribeChangeSetError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeChangeSet");
         let params = self.new_params("DescribeChangeSet");
         let mut params = params;
         DescribeChangeSetInputSerializer::serialize(&mut params, "", &input);
@@ -12821,6 +13070,7 @@ impl CloudFormation for CloudFormationClient {
         RusotoError<DescribeStackDriftDetectionStatusError>,
     > {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeStackDriftDetectionStatus");
         let params = self.new_params("DescribeStackDriftDetectionStatus");
         let mut params = params;
         DescribeStackDriftDetectionStatusInputSerializer::serialize(&mut params, "", &input);
@@ -12857,6 +13107,7 @@ impl CloudFormation for CloudFormationClient {
         input: DescribeStackEventsInput,
     ) -> Result<DescribeStackEventsOutput, RusotoError<DescribeStackEventsError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeStackEvents");
         let params = self.new_params("DescribeStackEvents");
         let mut params = params;
         DescribeStackEventsInputSerializer::serialize(&mut params, "", &input);
@@ -12890,6 +13141,7 @@ impl CloudFormation for CloudFormationClient {
         input: DescribeStackInstanceInput,
     ) -> Result<DescribeStackInstanceOutput, RusotoError<DescribeStackInstanceError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeStackInstance");
         let params = self.new_params("DescribeStackInstance");
         let mut params = params;
         DescribeStackInstanceInputSerializer::serialize(&mut params, "", &input);
@@ -12923,6 +13175,7 @@ impl CloudFormation for CloudFormationClient {
         input: DescribeStackResourceInput,
     ) -> Result<DescribeStackResourceOutput, RusotoError<DescribeStackResourceError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeStackResource");
         let params = self.new_params("DescribeStackResource");
         let mut params = params;
         DescribeStackResourceInputSerializer::serialize(&mut params, "", &input);
@@ -12957,6 +13210,7 @@ impl CloudFormation for CloudFormationClient {
     ) -> Result<DescribeStackResourceDriftsOutput, RusotoError<DescribeStackResourceDriftsError>>
     {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeStackResourceDrifts");
         let params = self.new_params("DescribeStackResourceDrifts");
         let mut params = params;
         DescribeStackResourceDriftsInputSerializer::serialize(&mut params, "", &input);
@@ -12990,6 +13244,7 @@ impl CloudFormation for CloudFormationClient {
         input: DescribeStackResourcesInput,
     ) -> Result<DescribeStackResourcesOutput, RusotoError<DescribeStackResourcesError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeStackResources");
         let params = self.new_params("DescribeStackResources");
         let mut params = params;
         DescribeStackResourcesInputSerializer::serialize(&mut params, "", &input);
@@ -13023,6 +13278,7 @@ impl CloudFormation for CloudFormationClient {
         input: DescribeStackSetInput,
     ) -> Result<DescribeStackSetOutput, RusotoError<DescribeStackSetError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeStackSet");
         let params = self.new_params("DescribeStackSet");
         let mut params = params;
         DescribeStackSetInputSerializer::serialize(&mut params, "", &input);
@@ -13054,6 +13310,7 @@ impl CloudFormation for CloudFormationClient {
         input: DescribeStackSetOperationInput,
     ) -> Result<DescribeStackSetOperationOutput, RusotoError<DescribeStackSetOperationError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeStackSetOperation");
         let params = self.new_params("DescribeStackSetOperation");
         let mut params = params;
         DescribeStackSetOperationInputSerializer::serialize(&mut params, "", &input);
@@ -13087,6 +13344,7 @@ impl CloudFormation for CloudFormationClient {
         input: DescribeStacksInput,
     ) -> Result<DescribeStacksOutput, RusotoError<DescribeStacksError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeStacks");
         let params = self.new_params("DescribeStacks");
         let mut params = params;
         DescribeStacksInputSerializer::serialize(&mut params, "", &input);
@@ -13118,6 +13376,7 @@ impl CloudFormation for CloudFormationClient {
         input: DescribeTypeInput,
     ) -> Result<DescribeTypeOutput, RusotoError<DescribeTypeError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeType");
         let params = self.new_params("DescribeType");
         let mut params = params;
         DescribeTypeInputSerializer::serialize(&mut params, "", &input);
@@ -13148,6 +13407,7 @@ impl CloudFormation for CloudFormationClient {
         input: DescribeTypeRegistrationInput,
     ) -> Result<DescribeTypeRegistrationOutput, RusotoError<DescribeTypeRegistrationError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DescribeTypeRegistration");
         let params = self.new_params("DescribeTypeRegistration");
         let mut params = params;
         DescribeTypeRegistrationInputSerializer::serialize(&mut params, "", &input);
@@ -13181,6 +13441,7 @@ impl CloudFormation for CloudFormationClient {
         input: DetectStackDriftInput,
     ) -> Result<DetectStackDriftOutput, RusotoError<DetectStackDriftError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DetectStackDrift");
         let params = self.new_params("DetectStackDrift");
         let mut params = params;
         DetectStackDriftInputSerializer::serialize(&mut params, "", &input);
@@ -13212,6 +13473,7 @@ impl CloudFormation for CloudFormationClient {
         input: DetectStackResourceDriftInput,
     ) -> Result<DetectStackResourceDriftOutput, RusotoError<DetectStackResourceDriftError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DetectStackResourceDrift");
         let params = self.new_params("DetectStackResourceDrift");
         let mut params = params;
         DetectStackResourceDriftInputSerializer::serialize(&mut params, "", &input);
@@ -13245,6 +13507,7 @@ impl CloudFormation for CloudFormationClient {
         input: DetectStackSetDriftInput,
     ) -> Result<DetectStackSetDriftOutput, RusotoError<DetectStackSetDriftError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("DetectStackSetDrift");
         let params = self.new_params("DetectStackSetDrift");
         let mut params = params;
         DetectStackSetDriftInputSerializer::serialize(&mut params, "", &input);
@@ -13278,6 +13541,7 @@ impl CloudFormation for CloudFormationClient {
         input: EstimateTemplateCostInput,
     ) -> Result<EstimateTemplateCostOutput, RusotoError<EstimateTemplateCostError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("EstimateTemplateCost");
         let params = self.new_params("EstimateTemplateCost");
         let mut params = params;
         EstimateTemplateCostInputSerializer::serialize(&mut params, "", &input);
@@ -13311,6 +13575,7 @@ impl CloudFormation for CloudFormationClient {
         input: ExecuteChangeSetInput,
     ) -> Result<ExecuteChangeSetOutput, RusotoError<ExecuteChangeSetError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ExecuteChangeSet");
         let params = self.new_params("ExecuteChangeSet");
         let mut params = params;
         ExecuteChangeSetInputSerializer::serialize(&mut params, "", &input);
@@ -13333,6 +13598,7 @@ impl CloudFormation for CloudFormationClient {
         input: GetStackPolicyInput,
     ) -> Result<GetStackPolicyOutput, RusotoError<GetStackPolicyError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("GetStackPolicy");
         let params = self.new_params("GetStackPolicy");
         let mut params = params;
         GetStackPolicyInputSerializer::serialize(&mut params, "", &input);
@@ -13364,6 +13630,7 @@ impl CloudFormation for CloudFormationClient {
         input: GetTemplateInput,
     ) -> Result<GetTemplateOutput, RusotoError<GetTemplateError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("GetTemplate");
         let params = self.new_params("GetTemplate");
         let mut params = params;
         GetTemplateInputSerializer::serialize(&mut params, "", &input);
@@ -13394,6 +13661,7 @@ impl CloudFormation for CloudFormationClient {
         input: GetTemplateSummaryInput,
     ) -> Result<GetTemplateSummaryOutput, RusotoError<GetTemplateSummaryError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("GetTemplateSummary");
         let params = self.new_params("GetTemplateSummary");
         let mut params = params;
         GetTemplateSummaryInputSerializer::serialize(&mut params, "", &input);
@@ -13427,6 +13695,7 @@ impl CloudFormation for CloudFormationClient {
         input: ListChangeSetsInput,
     ) -> Result<ListChangeSetsOutput, RusotoError<ListChangeSetsError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListChangeSets");
         let params = self.new_params("ListChangeSets");
         let mut params = params;
         ListChangeSetsInputSerializer::serialize(&mut params, "", &input);
@@ -13458,6 +13727,7 @@ impl CloudFormation for CloudFormationClient {
         input: ListExportsInput,
     ) -> Result<ListExportsOutput, RusotoError<ListExportsError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListExports");
         let params = self.new_params("ListExports");
         let mut params = params;
         ListExportsInputSerializer::serialize(&mut params, "", &input);
@@ -13488,6 +13758,7 @@ impl CloudFormation for CloudFormationClient {
         input: ListImportsInput,
     ) -> Result<ListImportsOutput, RusotoError<ListImportsError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListImports");
         let params = self.new_params("ListImports");
         let mut params = params;
         ListImportsInputSerializer::serialize(&mut params, "", &input);
@@ -13518,6 +13789,7 @@ impl CloudFormation for CloudFormationClient {
         input: ListStackInstancesInput,
     ) -> Result<ListStackInstancesOutput, RusotoError<ListStackInstancesError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListStackInstances");
         let params = self.new_params("ListStackInstances");
         let mut params = params;
         ListStackInstancesInputSerializer::serialize(&mut params, "", &input);
@@ -13551,6 +13823,7 @@ impl CloudFormation for CloudFormationClient {
         input: ListStackResourcesInput,
     ) -> Result<ListStackResourcesOutput, RusotoError<ListStackResourcesError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListStackResources");
         let params = self.new_params("ListStackResources");
         let mut params = params;
         ListStackResourcesInputSerializer::serialize(&mut params, "", &input);
@@ -13585,6 +13858,7 @@ impl CloudFormation for CloudFormationClient {
     ) -> Result<ListStackSetOperationResultsOutput, RusotoError<ListStackSetOperationResultsError>>
     {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListStackSetOperationResults");
         let params = self.new_params("ListStackSetOperationResults");
         let mut params = params;
         ListStackSetOperationResultsInputSerializer::serialize(&mut params, "", &input);
@@ -13618,6 +13892,7 @@ impl CloudFormation for CloudFormationClient {
         input: ListStackSetOperationsInput,
     ) -> Result<ListStackSetOperationsOutput, RusotoError<ListStackSetOperationsError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListStackSetOperations");
         let params = self.new_params("ListStackSetOperations");
         let mut params = params;
         ListStackSetOperationsInputSerializer::serialize(&mut params, "", &input);
@@ -13651,6 +13926,7 @@ impl CloudFormation for CloudFormationClient {
         input: ListStackSetsInput,
     ) -> Result<ListStackSetsOutput, RusotoError<ListStackSetsError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListStackSets");
         let params = self.new_params("ListStackSets");
         let mut params = params;
         ListStackSetsInputSerializer::serialize(&mut params, "", &input);
@@ -13682,6 +13958,7 @@ impl CloudFormation for CloudFormationClient {
         input: ListStacksInput,
     ) -> Result<ListStacksOutput, RusotoError<ListStacksError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListStacks");
         let params = self.new_params("ListStacks");
         let mut params = params;
         ListStacksInputSerializer::serialize(&mut params, "", &input);
@@ -13712,6 +13989,7 @@ impl CloudFormation for CloudFormationClient {
         input: ListTypeRegistrationsInput,
     ) -> Result<ListTypeRegistrationsOutput, RusotoError<ListTypeRegistrationsError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListTypeRegistrations");
         let params = self.new_params("ListTypeRegistrations");
         let mut params = params;
         ListTypeRegistrationsInputSerializer::serialize(&mut params, "", &input);
@@ -13745,6 +14023,7 @@ impl CloudFormation for CloudFormationClient {
         input: ListTypeVersionsInput,
     ) -> Result<ListTypeVersionsOutput, RusotoError<ListTypeVersionsError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListTypeVersions");
         let params = self.new_params("ListTypeVersions");
         let mut params = params;
         ListTypeVersionsInputSerializer::serialize(&mut params, "", &input);
@@ -13776,6 +14055,7 @@ impl CloudFormation for CloudFormationClient {
         input: ListTypesInput,
     ) -> Result<ListTypesOutput, RusotoError<ListTypesError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ListTypes");
         let params = self.new_params("ListTypes");
         let mut params = params;
         ListTypesInputSerializer::serialize(&mut params, "", &input);
@@ -13806,6 +14086,7 @@ impl CloudFormation for CloudFormationClient {
         input: RecordHandlerProgressInput,
     ) -> Result<RecordHandlerProgressOutput, RusotoError<RecordHandlerProgressError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("RecordHandlerProgress");
         let params = self.new_params("RecordHandlerProgress");
         let mut params = params;
         RecordHandlerProgressInputSerializer::serialize(&mut params, "", &input);
@@ -13828,6 +14109,7 @@ impl CloudFormation for CloudFormationClient {
         input: RegisterTypeInput,
     ) -> Result<RegisterTypeOutput, RusotoError<RegisterTypeError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("RegisterType");
         let params = self.new_params("RegisterType");
         let mut params = params;
         RegisterTypeInputSerializer::serialize(&mut params, "", &input);
@@ -13858,6 +14140,7 @@ impl CloudFormation for CloudFormationClient {
         input: SetStackPolicyInput,
     ) -> Result<(), RusotoError<SetStackPolicyError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("SetStackPolicy");
         let params = self.new_params("SetStackPolicy");
         let mut params = params;
         SetStackPolicyInputSerializer::serialize(&mut params, "", &input);
@@ -13878,6 +14161,7 @@ impl CloudFormation for CloudFormationClient {
         input: SetTypeDefaultVersionInput,
     ) -> Result<SetTypeDefaultVersionOutput, RusotoError<SetTypeDefaultVersionError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("SetTypeDefaultVersion");
         let params = self.new_params("SetTypeDefaultVersion");
         let mut params = params;
         SetTypeDefaultVersionInputSerializer::serialize(&mut params, "", &input);
@@ -13900,6 +14184,7 @@ impl CloudFormation for CloudFormationClient {
         input: SignalResourceInput,
     ) -> Result<(), RusotoError<SignalResourceError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("SignalResource");
         let params = self.new_params("SignalResource");
         let mut params = params;
         SignalResourceInputSerializer::serialize(&mut params, "", &input);
@@ -13920,6 +14205,7 @@ impl CloudFormation for CloudFormationClient {
         input: StopStackSetOperationInput,
     ) -> Result<StopStackSetOperationOutput, RusotoError<StopStackSetOperationError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("StopStackSetOperation");
         let params = self.new_params("StopStackSetOperation");
         let mut params = params;
         StopStackSetOperationInputSerializer::serialize(&mut params, "", &input);
@@ -13942,6 +14228,7 @@ impl CloudFormation for CloudFormationClient {
         input: UpdateStackInput,
     ) -> Result<UpdateStackOutput, RusotoError<UpdateStackError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("UpdateStack");
         let params = self.new_params("UpdateStack");
         let mut params = params;
         UpdateStackInputSerializer::serialize(&mut params, "", &input);
@@ -13972,6 +14259,7 @@ impl CloudFormation for CloudFormationClient {
         input: UpdateStackInstancesInput,
     ) -> Result<UpdateStackInstancesOutput, RusotoError<UpdateStackInstancesError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("UpdateStackInstances");
         let params = self.new_params("UpdateStackInstances");
         let mut params = params;
         UpdateStackInstancesInputSerializer::serialize(&mut params, "", &input);
@@ -14005,6 +14293,7 @@ impl CloudFormation for CloudFormationClient {
         input: UpdateStackSetInput,
     ) -> Result<UpdateStackSetOutput, RusotoError<UpdateStackSetError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("UpdateStackSet");
         let params = self.new_params("UpdateStackSet");
         let mut params = params;
         UpdateStackSetInputSerializer::serialize(&mut params, "", &input);
@@ -14037,6 +14326,7 @@ impl CloudFormation for CloudFormationClient {
     ) -> Result<UpdateTerminationProtectionOutput, RusotoError<UpdateTerminationProtectionError>>
     {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("UpdateTerminationProtection");
         let params = self.new_params("UpdateTerminationProtection");
         let mut params = params;
         UpdateTerminationProtectionInputSerializer::serialize(&mut params, "", &input);
@@ -14070,6 +14360,7 @@ impl CloudFormation for CloudFormationClient {
         input: ValidateTemplateInput,
     ) -> Result<ValidateTemplateOutput, RusotoError<ValidateTemplateError>> {
         let mut request = SignedRequest::new("POST", "cloudformation", &self.region, "/");
+        request.set_operation("ValidateTemplate");
         let params = self.new_params("ValidateTemplate");
         let mut params = params;
         ValidateTemplateInputSerializer::serialize(&mut params, "", &input);
@@ -14096,6 +14387,560 @@ impl CloudFormation for CloudFormationClient {
     }
 }
 
+/// A blocking client for the AWS CloudFormation API, running the calls of a `CloudFormationClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `CloudFormationClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CloudFormationBlockingClient {
+    client: CloudFormationClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CloudFormationBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> CloudFormationBlockingClient {
+        CloudFormationBlockingClient::from_client(CloudFormationClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CloudFormationBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CloudFormationBlockingClient::from_client(CloudFormationClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> CloudFormationBlockingClient {
+        CloudFormationBlockingClient::from_client(CloudFormationClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> CloudFormationBlockingClient {
+        CloudFormationBlockingClient::from_client(CloudFormationClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: CloudFormationClient) -> CloudFormationBlockingClient {
+        CloudFormationBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> CloudFormationBlockingClient {
+        CloudFormationBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CloudFormationBlockingClient {
+        CloudFormationBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`CloudFormation::cancel_update_stack`].
+    pub fn cancel_update_stack(
+        &self,
+        input: CancelUpdateStackInput,
+    ) -> Result<(), RusotoError<CancelUpdateStackError>> {
+        self.runtime
+            .block_on(self.client.cancel_update_stack(input))
+    }
+
+    /// Blocking version of [`CloudFormation::continue_update_rollback`].
+    pub fn continue_update_rollback(
+        &self,
+        input: ContinueUpdateRollbackInput,
+    ) -> Result<ContinueUpdateRollbackOutput, RusotoError<ContinueUpdateRollbackError>> {
+        self.runtime
+            .block_on(self.client.continue_update_rollback(input))
+    }
+
+    /// Blocking version of [`CloudFormation::create_change_set`].
+    pub fn create_change_set(
+        &self,
+        input: CreateChangeSetInput,
+    ) -> Result<CreateChangeSetOutput, RusotoError<CreateChangeSetError>> {
+        self.runtime.block_on(self.client.create_change_set(input))
+    }
+
+    /// Blocking version of [`CloudFormation::create_stack`].
+    pub fn create_stack(
+        &self,
+        input: CreateStackInput,
+    ) -> Result<CreateStackOutput, RusotoError<CreateStackError>> {
+        self.runtime.block_on(self.client.create_stack(input))
+    }
+
+    /// Blocking version of [`CloudFormation::create_stack_instances`].
+    pub fn create_stack_instances(
+        &self,
+        input: CreateStackInstancesInput,
+    ) -> Result<CreateStackInstancesOutput, RusotoError<CreateStackInstancesError>> {
+        self.runtime
+            .block_on(self.client.create_stack_instances(input))
+    }
+
+    /// Blocking version of [`CloudFormation::create_stack_set`].
+    pub fn create_stack_set(
+        &self,
+        input: CreateStackSetInput,
+    ) -> Result<CreateStackSetOutput, RusotoError<CreateStackSetError>> {
+        self.runtime.block_on(self.client.create_stack_set(input))
+    }
+
+    /// Blocking version of [`CloudFormation::delete_change_set`].
+    pub fn delete_change_set(
+        &self,
+        input: DeleteChangeSetInput,
+    ) -> Result<DeleteChangeSetOutput, RusotoError<DeleteChangeSetError>> {
+        self.runtime.block_on(self.client.delete_change_set(input))
+    }
+
+    /// Blocking version of [`CloudFormation::delete_stack`].
+    pub fn delete_stack(
+        &self,
+        input: DeleteStackInput,
+    ) -> Result<(), RusotoError<DeleteStackError>> {
+        self.runtime.block_on(self.client.delete_stack(input))
+    }
+
+    /// Blocking version of [`CloudFormation::delete_stack_instances`].
+    pub fn delete_stack_instances(
+        &self,
+        input: DeleteStackInstancesInput,
+    ) -> Result<DeleteStackInstancesOutput, RusotoError<DeleteStackInstancesError>> {
+        self.runtime
+            .block_on(self.client.delete_stack_instances(input))
+    }
+
+    /// Blocking version of [`CloudFormation::delete_stack_set`].
+    pub fn delete_stack_set(
+        &self,
+        input: DeleteStackSetInput,
+    ) -> Result<DeleteStackSetOutput, RusotoError<DeleteStackSetError>> {
+        self.runtime.block_on(self.client.delete_stack_set(input))
+    }
+
+    /// Blocking version of [`CloudFormation::deregister_type`].
+    pub fn deregister_type(
+        &self,
+        input: DeregisterTypeInput,
+    ) -> Result<DeregisterTypeOutput, RusotoError<DeregisterTypeError>> {
+        self.runtime.block_on(self.client.deregister_type(input))
+    }
+
+    /// Blocking version of [`CloudFormation::describe_account_limits`].
+    pub fn describe_account_limits(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> Result<DescribeAccountLimitsOutput, RusotoError<DescribeAccountLimitsError>> {
+        self.runtime
+            .block_on(self.client.describe_account_limits(input))
+    }
+
+    /// Blocking version of [`CloudFormation::describe_change_set`].
+    pub fn describe_change_set(
+        &self,
+        input: DescribeChangeSetInput,
+    ) -> Result<DescribeChangeSetOutput, RusotoError<DescribeChangeSetError>> {
+        self.runtime
+            .block_on(self.client.describe_change_set(input))
+    }
+
+    /// Blocking version of [`CloudFormation::describe_stack_drift_detection_status`].
+    pub fn describe_stack_drift_detection_status(
+        &self,
+        input: DescribeStackDriftDetectionStatusInput,
+    ) -> Result<
+        DescribeStackDriftDetectionStatusOutput,
+        RusotoError<DescribeStackDriftDetectionStatusError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_stack_drift_detection_status(input))
+    }
+
+    /// Blocking version of [`CloudFormation::describe_stack_events`].
+    pub fn describe_stack_events(
+        &self,
+        input: DescribeStackEventsInput,
+    ) -> Result<DescribeStackEventsOutput, RusotoError<DescribeStackEventsError>> {
+        self.runtime
+            .block_on(self.client.describe_stack_events(input))
+    }
+
+    /// Blocking version of [`CloudFormation::describe_stack_instance`].
+    pub fn describe_stack_instance(
+        &self,
+        input: DescribeStackInstanceInput,
+    ) -> Result<DescribeStackInstanceOutput, RusotoError<DescribeStackInstanceError>> {
+        self.runtime
+            .block_on(self.client.describe_stack_instance(input))
+    }
+
+    /// Blocking version of [`CloudFormation::describe_stack_resource`].
+    pub fn describe_stack_resource(
+        &self,
+        input: DescribeStackResourceInput,
+    ) -> Result<DescribeStackResourceOutput, RusotoError<DescribeStackResourceError>> {
+        self.runtime
+            .block_on(self.client.describe_stack_resource(input))
+    }
+
+    /// Blocking version of [`CloudFormation::describe_stack_resource_drifts`].
+    pub fn describe_stack_resource_drifts(
+        &self,
+        input: DescribeStackResourceDriftsInput,
+    ) -> Result<DescribeStackResourceDriftsOutput, RusotoError<DescribeStackResourceDriftsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_stack_resource_drifts(input))
+    }
+
+    /// Blocking version of [`CloudFormation::describe_stack_resources`].
+    pub fn describe_stack_resources(
+        &self,
+        input: DescribeStackResourcesInput,
+    ) -> Result<DescribeStackResourcesOutput, RusotoError<DescribeStackResourcesError>> {
+        self.runtime
+            .block_on(self.client.describe_stack_resources(input))
+    }
+
+    /// Blocking version of [`CloudFormation::describe_stack_set`].
+    pub fn describe_stack_set(
+        &self,
+        input: DescribeStackSetInput,
+    ) -> Result<DescribeStackSetOutput, RusotoError<DescribeStackSetError>> {
+        self.runtime.block_on(self.client.describe_stack_set(input))
+    }
+
+    /// Blocking version of [`CloudFormation::describe_stack_set_operation`].
+    pub fn describe_stack_set_operation(
+        &self,
+        input: DescribeStackSetOperationInput,
+    ) -> Result<DescribeStackSetOperationOutput, RusotoError<DescribeStackSetOperationError>> {
+        self.runtime
+            .block_on(self.client.describe_stack_set_operation(input))
+    }
+
+    /// Blocking version of [`CloudFormation::describe_stacks`].
+    pub fn describe_stacks(
+        &self,
+        input: DescribeStacksInput,
+    ) -> Result<DescribeStacksOutput, RusotoError<DescribeStacksError>> {
+        self.runtime.block_on(self.client.describe_stacks(input))
+    }
+
+    /// Blocking version of [`CloudFormation::describe_type`].
+    pub fn describe_type(
+        &self,
+        input: DescribeTypeInput,
+    ) -> Result<DescribeTypeOutput, RusotoError<DescribeTypeError>> {
+        self.runtime.block_on(self.client.describe_type(input))
+    }
+
+    /// Blocking version of [`CloudFormation::describe_type_registration`].
+    pub fn describe_type_registration(
+        &self,
+        input: DescribeTypeRegistrationInput,
+    ) -> Result<DescribeTypeRegistrationOutput, RusotoError<DescribeTypeRegistrationError>> {
+        self.runtime
+            .block_on(self.client.describe_type_registration(input))
+    }
+
+    /// Blocking version of [`CloudFormation::detect_stack_drift`].
+    pub fn detect_stack_drift(
+        &self,
+        input: DetectStackDriftInput,
+    ) -> Result<DetectStackDriftOutput, RusotoError<DetectStackDriftError>> {
+        self.runtime.block_on(self.client.detect_stack_drift(input))
+    }
+
+    /// Blocking version of [`CloudFormation::detect_stack_resource_drift`].
+    pub fn detect_stack_resource_drift(
+        &self,
+        input: DetectStackResourceDriftInput,
+    ) -> Result<DetectStackResourceDriftOutput, RusotoError<DetectStackResourceDriftError>> {
+        self.runtime
+            .block_on(self.client.detect_stack_resource_drift(input))
+    }
+
+    /// Blocking version of [`CloudFormation::detect_stack_set_drift`].
+    pub fn detect_stack_set_drift(
+        &self,
+        input: DetectStackSetDriftInput,
+    ) -> Result<DetectStackSetDriftOutput, RusotoError<DetectStackSetDriftError>> {
+        self.runtime
+            .block_on(self.client.detect_stack_set_drift(input))
+    }
+
+    /// Blocking version of [`CloudFormation::estimate_template_cost`].
+    pub fn estimate_template_cost(
+        &self,
+        input: EstimateTemplateCostInput,
+    ) -> Result<EstimateTemplateCostOutput, RusotoError<EstimateTemplateCostError>> {
+        self.runtime
+            .block_on(self.client.estimate_template_cost(input))
+    }
+
+    /// Blocking version of [`CloudFormation::execute_change_set`].
+    pub fn execute_change_set(
+        &self,
+        input: ExecuteChangeSetInput,
+    ) -> Result<ExecuteChangeSetOutput, RusotoError<ExecuteChangeSetError>> {
+        self.runtime.block_on(self.client.execute_change_set(input))
+    }
+
+    /// Blocking version of [`CloudFormation::get_stack_policy`].
+    pub fn get_stack_policy(
+        &self,
+        input: GetStackPolicyInput,
+    ) -> Result<GetStackPolicyOutput, RusotoError<GetStackPolicyError>> {
+        self.runtime.block_on(self.client.get_stack_policy(input))
+    }
+
+    /// Blocking version of [`CloudFormation::get_template`].
+    pub fn get_template(
+        &self,
+        input: GetTemplateInput,
+    ) -> Result<GetTemplateOutput, RusotoError<GetTemplateError>> {
+        self.runtime.block_on(self.client.get_template(input))
+    }
+
+    /// Blocking version of [`CloudFormation::get_template_summary`].
+    pub fn get_template_summary(
+        &self,
+        input: GetTemplateSummaryInput,
+    ) -> Result<GetTemplateSummaryOutput, RusotoError<GetTemplateSummaryError>> {
+        self.runtime
+            .block_on(self.client.get_template_summary(input))
+    }
+
+    /// Blocking version of [`CloudFormation::list_change_sets`].
+    pub fn list_change_sets(
+        &self,
+        input: ListChangeSetsInput,
+    ) -> Result<ListChangeSetsOutput, RusotoError<ListChangeSetsError>> {
+        self.runtime.block_on(self.client.list_change_sets(input))
+    }
+
+    /// Blocking version of [`CloudFormation::list_exports`].
+    pub fn list_exports(
+        &self,
+        input: ListExportsInput,
+    ) -> Result<ListExportsOutput, RusotoError<ListExportsError>> {
+        self.runtime.block_on(self.client.list_exports(input))
+    }
+
+    /// Blocking version of [`CloudFormation::list_imports`].
+    pub fn list_imports(
+        &self,
+        input: ListImportsInput,
+    ) -> Result<ListImportsOutput, RusotoError<ListImportsError>> {
+        self.runtime.block_on(self.client.list_imports(input))
+    }
+
+    /// Blocking version of [`CloudFormation::list_stack_instances`].
+    pub fn list_stack_instances(
+        &self,
+        input: ListStackInstancesInput,
+    ) -> Result<ListStackInstancesOutput, RusotoError<ListStackInstancesError>> {
+        self.runtime
+            .block_on(self.client.list_stack_instances(input))
+    }
+
+    /// Blocking version of [`CloudFormation::list_stack_resources`].
+    pub fn list_stack_resources(
+        &self,
+        input: ListStackResourcesInput,
+    ) -> Result<ListStackResourcesOutput, RusotoError<ListStackResourcesError>> {
+        self.runtime
+            .block_on(self.client.list_stack_resources(input))
+    }
+
+    /// Blocking version of [`CloudFormation::list_stack_set_operation_results`].
+    pub fn list_stack_set_operation_results(
+        &self,
+        input: ListStackSetOperationResultsInput,
+    ) -> Result<ListStackSetOperationResultsOutput, RusotoError<ListStackSetOperationResultsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_stack_set_operation_results(input))
+    }
+
+    /// Blocking version of [`CloudFormation::list_stack_set_operations`].
+    pub fn list_stack_set_operations(
+        &self,
+        input: ListStackSetOperationsInput,
+    ) -> Result<ListStackSetOperationsOutput, RusotoError<ListStackSetOperationsError>> {
+        self.runtime
+            .block_on(self.client.list_stack_set_operations(input))
+    }
+
+    /// Blocking version of [`CloudFormation::list_stack_sets`].
+    pub fn list_stack_sets(
+        &self,
+        input: ListStackSetsInput,
+    ) -> Result<ListStackSetsOutput, RusotoError<ListStackSetsError>> {
+        self.runtime.block_on(self.client.list_stack_sets(input))
+    }
+
+    /// Blocking version of [`CloudFormation::list_stacks`].
+    pub fn list_stacks(
+        &self,
+        input: ListStacksInput,
+    ) -> Result<ListStacksOutput, RusotoError<ListStacksError>> {
+        self.runtime.block_on(self.client.list_stacks(input))
+    }
+
+    /// Blocking version of [`CloudFormation::list_type_registrations`].
+    pub fn list_type_registrations(
+        &self,
+        input: ListTypeRegistrationsInput,
+    ) -> Result<ListTypeRegistrationsOutput, RusotoError<ListTypeRegistrationsError>> {
+        self.runtime
+            .block_on(self.client.list_type_registrations(input))
+    }
+
+    /// Blocking version of [`CloudFormation::list_type_versions`].
+    pub fn list_type_versions(
+        &self,
+        input: ListTypeVersionsInput,
+    ) -> Result<ListTypeVersionsOutput, RusotoError<ListTypeVersionsError>> {
+        self.runtime.block_on(self.client.list_type_versions(input))
+    }
+
+    /// Blocking version of [`CloudFormation::list_types`].
+    pub fn list_types(
+        &self,
+        input: ListTypesInput,
+    ) -> Result<ListTypesOutput, RusotoError<ListTypesError>> {
+        self.runtime.block_on(self.client.list_types(input))
+    }
+
+    /// Blocking version of [`CloudFormation::record_handler_progress`].
+    pub fn record_handler_progress(
+        &self,
+        input: RecordHandlerProgressInput,
+    ) -> Result<RecordHandlerProgressOutput, RusotoError<RecordHandlerProgressError>> {
+        self.runtime
+            .block_on(self.client.record_handler_progress(input))
+    }
+
+    /// Blocking version of [`CloudFormation::register_type`].
+    pub fn register_type(
+        &self,
+        input: RegisterTypeInput,
+    ) -> Result<RegisterTypeOutput, RusotoError<RegisterTypeError>> {
+        self.runtime.block_on(self.client.register_type(input))
+    }
+
+    /// Blocking version of [`CloudFormation::set_stack_policy`].
+    pub fn set_stack_policy(
+        &self,
+        input: SetStackPolicyInput,
+    ) -> Result<(), RusotoError<SetStackPolicyError>> {
+        self.runtime.block_on(self.client.set_stack_policy(input))
+    }
+
+    /// Blocking version of [`CloudFormation::set_type_default_version`].
+    pub fn set_type_default_version(
+        &self,
+        input: SetTypeDefaultVersionInput,
+    ) -> Result<SetTypeDefaultVersionOutput, RusotoError<SetTypeDefaultVersionError>> {
+        self.runtime
+            .block_on(self.client.set_type_default_version(input))
+    }
+
+    /// Blocking version of [`CloudFormation::signal_resource`].
+    pub fn signal_resource(
+        &self,
+        input: SignalResourceInput,
+    ) -> Result<(), RusotoError<SignalResourceError>> {
+        self.runtime.block_on(self.client.signal_resource(input))
+    }
+
+    /// Blocking version of [`CloudFormation::stop_stack_set_operation`].
+    pub fn stop_stack_set_operation(
+        &self,
+        input: StopStackSetOperationInput,
+    ) -> Result<StopStackSetOperationOutput, RusotoError<StopStackSetOperationError>> {
+        self.runtime
+            .block_on(self.client.stop_stack_set_operation(input))
+    }
+
+    /// Blocking version of [`CloudFormation::update_stack`].
+    pub fn update_stack(
+        &self,
+        input: UpdateStackInput,
+    ) -> Result<UpdateStackOutput, RusotoError<UpdateStackError>> {
+        self.runtime.block_on(self.client.update_stack(input))
+    }
+
+    /// Blocking version of [`CloudFormation::update_stack_instances`].
+    pub fn update_stack_instances(
+        &self,
+        input: UpdateStackInstancesInput,
+    ) -> Result<UpdateStackInstancesOutput, RusotoError<UpdateStackInstancesError>> {
+        self.runtime
+            .block_on(self.client.update_stack_instances(input))
+    }
+
+    /// Blocking version of [`CloudFormation::update_stack_set`].
+    pub fn update_stack_set(
+        &self,
+        input: UpdateStackSetInput,
+    ) -> Result<UpdateStackSetOutput, RusotoError<UpdateStackSetError>> {
+        self.runtime.block_on(self.client.update_stack_set(input))
+    }
+
+    /// Blocking version of [`CloudFormation::update_termination_protection`].
+    pub fn update_termination_protection(
+        &self,
+        input: UpdateTerminationProtectionInput,
+    ) -> Result<UpdateTerminationProtectionOutput, RusotoError<UpdateTerminationProtectionError>>
+    {
+        self.runtime
+            .block_on(self.client.update_termination_protection(input))
+    }
+
+    /// Blocking version of [`CloudFormation::validate_template`].
+    pub fn validate_template(
+        &self,
+        input: ValidateTemplateInput,
+    ) -> Result<ValidateTemplateOutput, RusotoError<ValidateTemplateError>> {
+        self.runtime.block_on(self.client.validate_template(input))
+    }
+}
+
 #[cfg(test)]
 mod protocol_tests {
 
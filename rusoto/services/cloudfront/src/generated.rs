@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -11356,49 +11357,67 @@ impl CreateCachePolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(CreateCachePolicyError::AccessDenied(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateCachePolicyError::AccessDenied,
+                        )
                     }
                     "CachePolicyAlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateCachePolicyError::CachePolicyAlreadyExists(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateCachePolicyError::CachePolicyAlreadyExists,
                         )
                     }
                     "InconsistentQuantities" => {
-                        return RusotoError::Service(
-                            CreateCachePolicyError::InconsistentQuantities(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateCachePolicyError::InconsistentQuantities,
                         )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(CreateCachePolicyError::InvalidArgument(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateCachePolicyError::InvalidArgument,
+                        )
                     }
                     "TooManyCachePolicies" => {
-                        return RusotoError::Service(CreateCachePolicyError::TooManyCachePolicies(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateCachePolicyError::TooManyCachePolicies,
+                        )
                     }
                     "TooManyCookiesInCachePolicy" => {
-                        return RusotoError::Service(
-                            CreateCachePolicyError::TooManyCookiesInCachePolicy(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateCachePolicyError::TooManyCookiesInCachePolicy,
                         )
                     }
                     "TooManyHeadersInCachePolicy" => {
-                        return RusotoError::Service(
-                            CreateCachePolicyError::TooManyHeadersInCachePolicy(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateCachePolicyError::TooManyHeadersInCachePolicy,
                         )
                     }
                     "TooManyQueryStringsInCachePolicy" => {
-                        return RusotoError::Service(
-                            CreateCachePolicyError::TooManyQueryStringsInCachePolicy(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateCachePolicyError::TooManyQueryStringsInCachePolicy,
                         )
                     }
                     _ => {}
@@ -11463,7 +11482,7 @@ impl CreateCloudFrontOriginAccessIdentityError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "CloudFrontOriginAccessIdentityAlreadyExists" => return RusotoError::Service(CreateCloudFrontOriginAccessIdentityError::CloudFrontOriginAccessIdentityAlreadyExists(parsed_error.message)),"InconsistentQuantities" => return RusotoError::Service(CreateCloudFrontOriginAccessIdentityError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::Service(CreateCloudFrontOriginAccessIdentityError::InvalidArgument(parsed_error.message)),"MissingBody" => return RusotoError::Service(CreateCloudFrontOriginAccessIdentityError::MissingBody(parsed_error.message)),"TooManyCloudFrontOriginAccessIdentities" => return RusotoError::Service(CreateCloudFrontOriginAccessIdentityError::TooManyCloudFrontOriginAccessIdentities(parsed_error.message)),_ => {}
+                                    "CloudFrontOriginAccessIdentityAlreadyExists" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateCloudFrontOriginAccessIdentityError::CloudFrontOriginAccessIdentityAlreadyExists),"InconsistentQuantities" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateCloudFrontOriginAccessIdentityError::InconsistentQuantities),"InvalidArgument" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateCloudFrontOriginAccessIdentityError::InvalidArgument),"MissingBody" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateCloudFrontOriginAccessIdentityError::MissingBody),"TooManyCloudFrontOriginAccessIdentities" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateCloudFrontOriginAccessIdentityError::TooManyCloudFrontOriginAccessIdentities),_ => {}
                                 }
             }
         }
@@ -11608,7 +11627,7 @@ impl CreateDistributionError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(CreateDistributionError::AccessDenied(parsed_error.message)),"CNAMEAlreadyExists" => return RusotoError::Service(CreateDistributionError::CNAMEAlreadyExists(parsed_error.message)),"DistributionAlreadyExists" => return RusotoError::Service(CreateDistributionError::DistributionAlreadyExists(parsed_error.message)),"IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior" => return RusotoError::Service(CreateDistributionError::IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior(parsed_error.message)),"InconsistentQuantities" => return RusotoError::Service(CreateDistributionError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::Service(CreateDistributionError::InvalidArgument(parsed_error.message)),"InvalidDefaultRootObject" => return RusotoError::Service(CreateDistributionError::InvalidDefaultRootObject(parsed_error.message)),"InvalidErrorCode" => return RusotoError::Service(CreateDistributionError::InvalidErrorCode(parsed_error.message)),"InvalidForwardCookies" => return RusotoError::Service(CreateDistributionError::InvalidForwardCookies(parsed_error.message)),"InvalidGeoRestrictionParameter" => return RusotoError::Service(CreateDistributionError::InvalidGeoRestrictionParameter(parsed_error.message)),"InvalidHeadersForS3Origin" => return RusotoError::Service(CreateDistributionError::InvalidHeadersForS3Origin(parsed_error.message)),"InvalidLambdaFunctionAssociation" => return RusotoError::Service(CreateDistributionError::InvalidLambdaFunctionAssociation(parsed_error.message)),"InvalidLocationCode" => return RusotoError::Service(CreateDistributionError::InvalidLocationCode(parsed_error.message)),"InvalidMinimumProtocolVersion" => return RusotoError::Service(CreateDistributionError::InvalidMinimumProtocolVersion(parsed_error.message)),"InvalidOrigin" => return RusotoError::Service(CreateDistributionError::InvalidOrigin(parsed_error.message)),"InvalidOriginAccessIdentity" => return RusotoError::Service(CreateDistributionError::InvalidOriginAccessIdentity(parsed_error.message)),"InvalidOriginKeepaliveTimeout" => return RusotoError::Service(CreateDistributionError::InvalidOriginKeepaliveTimeout(parsed_error.message)),"InvalidOriginReadTimeout" => return RusotoError::Service(CreateDistributionError::InvalidOriginReadTimeout(parsed_error.message)),"InvalidProtocolSettings" => return RusotoError::Service(CreateDistributionError::InvalidProtocolSettings(parsed_error.message)),"InvalidQueryStringParameters" => return RusotoError::Service(CreateDistributionError::InvalidQueryStringParameters(parsed_error.message)),"InvalidRelativePath" => return RusotoError::Service(CreateDistributionError::InvalidRelativePath(parsed_error.message)),"InvalidRequiredProtocol" => return RusotoError::Service(CreateDistributionError::InvalidRequiredProtocol(parsed_error.message)),"InvalidResponseCode" => return RusotoError::Service(CreateDistributionError::InvalidResponseCode(parsed_error.message)),"InvalidTTLOrder" => return RusotoError::Service(CreateDistributionError::InvalidTTLOrder(parsed_error.message)),"InvalidViewerCertificate" => return RusotoError::Service(CreateDistributionError::InvalidViewerCertificate(parsed_error.message)),"InvalidWebACLId" => return RusotoError::Service(CreateDistributionError::InvalidWebACLId(parsed_error.message)),"MissingBody" => return RusotoError::Service(CreateDistributionError::MissingBody(parsed_error.message)),"NoSuchCachePolicy" => return RusotoError::Service(CreateDistributionError::NoSuchCachePolicy(parsed_error.message)),"NoSuchFieldLevelEncryptionConfig" => return RusotoError::Service(CreateDistributionError::NoSuchFieldLevelEncryptionConfig(parsed_error.message)),"NoSuchOrigin" => return RusotoError::Service(CreateDistributionError::NoSuchOrigin(parsed_error.message)),"NoSuchOriginRequestPolicy" => return RusotoError::Service(CreateDistributionError::NoSuchOriginRequestPolicy(parsed_error.message)),"TooManyCacheBehaviors" => return RusotoError::Service(CreateDistributionError::TooManyCacheBehaviors(parsed_error.message)),"TooManyCertificates" => return RusotoError::Service(CreateDistributionError::TooManyCertificates(parsed_error.message)),"TooManyCookieNamesInWhiteList" => return RusotoError::Service(CreateDistributionError::TooManyCookieNamesInWhiteList(parsed_error.message)),"TooManyDistributionCNAMEs" => return RusotoError::Service(CreateDistributionError::TooManyDistributionCNAMEs(parsed_error.message)),"TooManyDistributions" => return RusotoError::Service(CreateDistributionError::TooManyDistributions(parsed_error.message)),"TooManyDistributionsAssociatedToCachePolicy" => return RusotoError::Service(CreateDistributionError::TooManyDistributionsAssociatedToCachePolicy(parsed_error.message)),"TooManyDistributionsAssociatedToFieldLevelEncryptionConfig" => return RusotoError::Service(CreateDistributionError::TooManyDistributionsAssociatedToFieldLevelEncryptionConfig(parsed_error.message)),"TooManyDistributionsAssociatedToKeyGroup" => return RusotoError::Service(CreateDistributionError::TooManyDistributionsAssociatedToKeyGroup(parsed_error.message)),"TooManyDistributionsAssociatedToOriginRequestPolicy" => return RusotoError::Service(CreateDistributionError::TooManyDistributionsAssociatedToOriginRequestPolicy(parsed_error.message)),"TooManyDistributionsWithLambdaAssociations" => return RusotoError::Service(CreateDistributionError::TooManyDistributionsWithLambdaAssociations(parsed_error.message)),"TooManyDistributionsWithSingleFunctionARN" => return RusotoError::Service(CreateDistributionError::TooManyDistributionsWithSingleFunctionARN(parsed_error.message)),"TooManyHeadersInForwardedValues" => return RusotoError::Service(CreateDistributionError::TooManyHeadersInForwardedValues(parsed_error.message)),"TooManyKeyGroupsAssociatedToDistribution" => return RusotoError::Service(CreateDistributionError::TooManyKeyGroupsAssociatedToDistribution(parsed_error.message)),"TooManyLambdaFunctionAssociations" => return RusotoError::Service(CreateDistributionError::TooManyLambdaFunctionAssociations(parsed_error.message)),"TooManyOriginCustomHeaders" => return RusotoError::Service(CreateDistributionError::TooManyOriginCustomHeaders(parsed_error.message)),"TooManyOriginGroupsPerDistribution" => return RusotoError::Service(CreateDistributionError::TooManyOriginGroupsPerDistribution(parsed_error.message)),"TooManyOrigins" => return RusotoError::Service(CreateDistributionError::TooManyOrigins(parsed_error.message)),"TooManyQueryStringParameters" => return RusotoError::Service(CreateDistributionError::TooManyQueryStringParameters(parsed_error.message)),"TooManyTrustedSigners" => return RusotoError::Service(CreateDistributionError::TooManyTrustedSigners(parsed_error.message)),"TrustedKeyGroupDoesNotExist" => return RusotoError::Service(CreateDistributionError::TrustedKeyGroupDoesNotExist(parsed_error.message)),"TrustedSignerDoesNotExist" => return RusotoError::Service(CreateDistributionError::TrustedSignerDoesNotExist(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::AccessDenied),"CNAMEAlreadyExists" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::CNAMEAlreadyExists),"DistributionAlreadyExists" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::DistributionAlreadyExists),"IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior),"InconsistentQuantities" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::InconsistentQuantities),"InvalidArgument" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::InvalidArgument),"InvalidDefaultRootObject" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::InvalidDefaultRootObject),"InvalidErrorCode" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::InvalidErrorCode),"InvalidForwardCookies" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::InvalidForwardCookies),"InvalidGeoRestrictionParameter" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::InvalidGeoRestrictionParameter),"InvalidHeadersForS3Origin" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::InvalidHeadersForS3Origin),"InvalidLambdaFunctionAssociation" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::InvalidLambdaFunctionAssociation),"InvalidLocationCode" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::InvalidLocationCode),"InvalidMinimumProtocolVersion" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::InvalidMinimumProtocolVersion),"InvalidOrigin" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::InvalidOrigin),"InvalidOriginAccessIdentity" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::InvalidOriginAccessIdentity),"InvalidOriginKeepaliveTimeout" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::InvalidOriginKeepaliveTimeout),"InvalidOriginReadTimeout" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::InvalidOriginReadTimeout),"InvalidProtocolSettings" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::InvalidProtocolSettings),"InvalidQueryStringParameters" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::InvalidQueryStringParameters),"InvalidRelativePath" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::InvalidRelativePath),"InvalidRequiredProtocol" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::InvalidRequiredProtocol),"InvalidResponseCode" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::InvalidResponseCode),"InvalidTTLOrder" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::InvalidTTLOrder),"InvalidViewerCertificate" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::InvalidViewerCertificate),"InvalidWebACLId" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::InvalidWebACLId),"MissingBody" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::MissingBody),"NoSuchCachePolicy" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::NoSuchCachePolicy),"NoSuchFieldLevelEncryptionConfig" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::NoSuchFieldLevelEncryptionConfig),"NoSuchOrigin" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::NoSuchOrigin),"NoSuchOriginRequestPolicy" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::NoSuchOriginRequestPolicy),"TooManyCacheBehaviors" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::TooManyCacheBehaviors),"TooManyCertificates" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::TooManyCertificates),"TooManyCookieNamesInWhiteList" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::TooManyCookieNamesInWhiteList),"TooManyDistributionCNAMEs" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::TooManyDistributionCNAMEs),"TooManyDistributions" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::TooManyDistributions),"TooManyDistributionsAssociatedToCachePolicy" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::TooManyDistributionsAssociatedToCachePolicy),"TooManyDistributionsAssociatedToFieldLevelEncryptionConfig" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::TooManyDistributionsAssociatedToFieldLevelEncryptionConfig),"TooManyDistributionsAssociatedToKeyGroup" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::TooManyDistributionsAssociatedToKeyGroup),"TooManyDistributionsAssociatedToOriginRequestPolicy" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::TooManyDistributionsAssociatedToOriginRequestPolicy),"TooManyDistributionsWithLambdaAssociations" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::TooManyDistributionsWithLambdaAssociations),"TooManyDistributionsWithSingleFunctionARN" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::TooManyDistributionsWithSingleFunctionARN),"TooManyHeadersInForwardedValues" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::TooManyHeadersInForwardedValues),"TooManyKeyGroupsAssociatedToDistribution" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::TooManyKeyGroupsAssociatedToDistribution),"TooManyLambdaFunctionAssociations" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::TooManyLambdaFunctionAssociations),"TooManyOriginCustomHeaders" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::TooManyOriginCustomHeaders),"TooManyOriginGroupsPerDistribution" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::TooManyOriginGroupsPerDistribution),"TooManyOrigins" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::TooManyOrigins),"TooManyQueryStringParameters" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::TooManyQueryStringParameters),"TooManyTrustedSigners" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::TooManyTrustedSigners),"TrustedKeyGroupDoesNotExist" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::TrustedKeyGroupDoesNotExist),"TrustedSignerDoesNotExist" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionError::TrustedSignerDoesNotExist),_ => {}
                                 }
             }
         }
@@ -11804,7 +11823,7 @@ impl CreateDistributionWithTagsError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(CreateDistributionWithTagsError::AccessDenied(parsed_error.message)),"CNAMEAlreadyExists" => return RusotoError::Service(CreateDistributionWithTagsError::CNAMEAlreadyExists(parsed_error.message)),"DistributionAlreadyExists" => return RusotoError::Service(CreateDistributionWithTagsError::DistributionAlreadyExists(parsed_error.message)),"IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior" => return RusotoError::Service(CreateDistributionWithTagsError::IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior(parsed_error.message)),"InconsistentQuantities" => return RusotoError::Service(CreateDistributionWithTagsError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidArgument(parsed_error.message)),"InvalidDefaultRootObject" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidDefaultRootObject(parsed_error.message)),"InvalidErrorCode" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidErrorCode(parsed_error.message)),"InvalidForwardCookies" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidForwardCookies(parsed_error.message)),"InvalidGeoRestrictionParameter" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidGeoRestrictionParameter(parsed_error.message)),"InvalidHeadersForS3Origin" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidHeadersForS3Origin(parsed_error.message)),"InvalidLambdaFunctionAssociation" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidLambdaFunctionAssociation(parsed_error.message)),"InvalidLocationCode" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidLocationCode(parsed_error.message)),"InvalidMinimumProtocolVersion" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidMinimumProtocolVersion(parsed_error.message)),"InvalidOrigin" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidOrigin(parsed_error.message)),"InvalidOriginAccessIdentity" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidOriginAccessIdentity(parsed_error.message)),"InvalidOriginKeepaliveTimeout" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidOriginKeepaliveTimeout(parsed_error.message)),"InvalidOriginReadTimeout" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidOriginReadTimeout(parsed_error.message)),"InvalidProtocolSettings" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidProtocolSettings(parsed_error.message)),"InvalidQueryStringParameters" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidQueryStringParameters(parsed_error.message)),"InvalidRelativePath" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidRelativePath(parsed_error.message)),"InvalidRequiredProtocol" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidRequiredProtocol(parsed_error.message)),"InvalidResponseCode" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidResponseCode(parsed_error.message)),"InvalidTTLOrder" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidTTLOrder(parsed_error.message)),"InvalidTagging" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidTagging(parsed_error.message)),"InvalidViewerCertificate" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidViewerCertificate(parsed_error.message)),"InvalidWebACLId" => return RusotoError::Service(CreateDistributionWithTagsError::InvalidWebACLId(parsed_error.message)),"MissingBody" => return RusotoError::Service(CreateDistributionWithTagsError::MissingBody(parsed_error.message)),"NoSuchCachePolicy" => return RusotoError::Service(CreateDistributionWithTagsError::NoSuchCachePolicy(parsed_error.message)),"NoSuchFieldLevelEncryptionConfig" => return RusotoError::Service(CreateDistributionWithTagsError::NoSuchFieldLevelEncryptionConfig(parsed_error.message)),"NoSuchOrigin" => return RusotoError::Service(CreateDistributionWithTagsError::NoSuchOrigin(parsed_error.message)),"NoSuchOriginRequestPolicy" => return RusotoError::Service(CreateDistributionWithTagsError::NoSuchOriginRequestPolicy(parsed_error.message)),"TooManyCacheBehaviors" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyCacheBehaviors(parsed_error.message)),"TooManyCertificates" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyCertificates(parsed_error.message)),"TooManyCookieNamesInWhiteList" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyCookieNamesInWhiteList(parsed_error.message)),"TooManyDistributionCNAMEs" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyDistributionCNAMEs(parsed_error.message)),"TooManyDistributions" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyDistributions(parsed_error.message)),"TooManyDistributionsAssociatedToCachePolicy" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyDistributionsAssociatedToCachePolicy(parsed_error.message)),"TooManyDistributionsAssociatedToFieldLevelEncryptionConfig" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyDistributionsAssociatedToFieldLevelEncryptionConfig(parsed_error.message)),"TooManyDistributionsAssociatedToKeyGroup" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyDistributionsAssociatedToKeyGroup(parsed_error.message)),"TooManyDistributionsAssociatedToOriginRequestPolicy" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyDistributionsAssociatedToOriginRequestPolicy(parsed_error.message)),"TooManyDistributionsWithLambdaAssociations" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyDistributionsWithLambdaAssociations(parsed_error.message)),"TooManyDistributionsWithSingleFunctionARN" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyDistributionsWithSingleFunctionARN(parsed_error.message)),"TooManyHeadersInForwardedValues" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyHeadersInForwardedValues(parsed_error.message)),"TooManyKeyGroupsAssociatedToDistribution" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyKeyGroupsAssociatedToDistribution(parsed_error.message)),"TooManyLambdaFunctionAssociations" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyLambdaFunctionAssociations(parsed_error.message)),"TooManyOriginCustomHeaders" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyOriginCustomHeaders(parsed_error.message)),"TooManyOriginGroupsPerDistribution" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyOriginGroupsPerDistribution(parsed_error.message)),"TooManyOrigins" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyOrigins(parsed_error.message)),"TooManyQueryStringParameters" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyQueryStringParameters(parsed_error.message)),"TooManyTrustedSigners" => return RusotoError::Service(CreateDistributionWithTagsError::TooManyTrustedSigners(parsed_error.message)),"TrustedKeyGroupDoesNotExist" => return RusotoError::Service(CreateDistributionWithTagsError::TrustedKeyGroupDoesNotExist(parsed_error.message)),"TrustedSignerDoesNotExist" => return RusotoError::Service(CreateDistributionWithTagsError::TrustedSignerDoesNotExist(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::AccessDenied),"CNAMEAlreadyExists" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::CNAMEAlreadyExists),"DistributionAlreadyExists" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::DistributionAlreadyExists),"IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior),"InconsistentQuantities" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::InconsistentQuantities),"InvalidArgument" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::InvalidArgument),"InvalidDefaultRootObject" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::InvalidDefaultRootObject),"InvalidErrorCode" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::InvalidErrorCode),"InvalidForwardCookies" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::InvalidForwardCookies),"InvalidGeoRestrictionParameter" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::InvalidGeoRestrictionParameter),"InvalidHeadersForS3Origin" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::InvalidHeadersForS3Origin),"InvalidLambdaFunctionAssociation" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::InvalidLambdaFunctionAssociation),"InvalidLocationCode" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::InvalidLocationCode),"InvalidMinimumProtocolVersion" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::InvalidMinimumProtocolVersion),"InvalidOrigin" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::InvalidOrigin),"InvalidOriginAccessIdentity" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::InvalidOriginAccessIdentity),"InvalidOriginKeepaliveTimeout" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::InvalidOriginKeepaliveTimeout),"InvalidOriginReadTimeout" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::InvalidOriginReadTimeout),"InvalidProtocolSettings" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::InvalidProtocolSettings),"InvalidQueryStringParameters" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::InvalidQueryStringParameters),"InvalidRelativePath" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::InvalidRelativePath),"InvalidRequiredProtocol" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::InvalidRequiredProtocol),"InvalidResponseCode" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::InvalidResponseCode),"InvalidTTLOrder" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::InvalidTTLOrder),"InvalidTagging" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::InvalidTagging),"InvalidViewerCertificate" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::InvalidViewerCertificate),"InvalidWebACLId" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::InvalidWebACLId),"MissingBody" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::MissingBody),"NoSuchCachePolicy" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::NoSuchCachePolicy),"NoSuchFieldLevelEncryptionConfig" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::NoSuchFieldLevelEncryptionConfig),"NoSuchOrigin" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::NoSuchOrigin),"NoSuchOriginRequestPolicy" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::NoSuchOriginRequestPolicy),"TooManyCacheBehaviors" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::TooManyCacheBehaviors),"TooManyCertificates" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::TooManyCertificates),"TooManyCookieNamesInWhiteList" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::TooManyCookieNamesInWhiteList),"TooManyDistributionCNAMEs" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::TooManyDistributionCNAMEs),"TooManyDistributions" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::TooManyDistributions),"TooManyDistributionsAssociatedToCachePolicy" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::TooManyDistributionsAssociatedToCachePolicy),"TooManyDistributionsAssociatedToFieldLevelEncryptionConfig" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::TooManyDistributionsAssociatedToFieldLevelEncryptionConfig),"TooManyDistributionsAssociatedToKeyGroup" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::TooManyDistributionsAssociatedToKeyGroup),"TooManyDistributionsAssociatedToOriginRequestPolicy" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::TooManyDistributionsAssociatedToOriginRequestPolicy),"TooManyDistributionsWithLambdaAssociations" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::TooManyDistributionsWithLambdaAssociations),"TooManyDistributionsWithSingleFunctionARN" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::TooManyDistributionsWithSingleFunctionARN),"TooManyHeadersInForwardedValues" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::TooManyHeadersInForwardedValues),"TooManyKeyGroupsAssociatedToDistribution" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::TooManyKeyGroupsAssociatedToDistribution),"TooManyLambdaFunctionAssociations" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::TooManyLambdaFunctionAssociations),"TooManyOriginCustomHeaders" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::TooManyOriginCustomHeaders),"TooManyOriginGroupsPerDistribution" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::TooManyOriginGroupsPerDistribution),"TooManyOrigins" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::TooManyOrigins),"TooManyQueryStringParameters" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::TooManyQueryStringParameters),"TooManyTrustedSigners" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::TooManyTrustedSigners),"TrustedKeyGroupDoesNotExist" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::TrustedKeyGroupDoesNotExist),"TrustedSignerDoesNotExist" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateDistributionWithTagsError::TrustedSignerDoesNotExist),_ => {}
                                 }
             }
         }
@@ -11911,7 +11930,7 @@ impl CreateFieldLevelEncryptionConfigError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "FieldLevelEncryptionConfigAlreadyExists" => return RusotoError::Service(CreateFieldLevelEncryptionConfigError::FieldLevelEncryptionConfigAlreadyExists(parsed_error.message)),"InconsistentQuantities" => return RusotoError::Service(CreateFieldLevelEncryptionConfigError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::Service(CreateFieldLevelEncryptionConfigError::InvalidArgument(parsed_error.message)),"NoSuchFieldLevelEncryptionProfile" => return RusotoError::Service(CreateFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionProfile(parsed_error.message)),"QueryArgProfileEmpty" => return RusotoError::Service(CreateFieldLevelEncryptionConfigError::QueryArgProfileEmpty(parsed_error.message)),"TooManyFieldLevelEncryptionConfigs" => return RusotoError::Service(CreateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionConfigs(parsed_error.message)),"TooManyFieldLevelEncryptionContentTypeProfiles" => return RusotoError::Service(CreateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionContentTypeProfiles(parsed_error.message)),"TooManyFieldLevelEncryptionQueryArgProfiles" => return RusotoError::Service(CreateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionQueryArgProfiles(parsed_error.message)),_ => {}
+                                    "FieldLevelEncryptionConfigAlreadyExists" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateFieldLevelEncryptionConfigError::FieldLevelEncryptionConfigAlreadyExists),"InconsistentQuantities" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateFieldLevelEncryptionConfigError::InconsistentQuantities),"InvalidArgument" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateFieldLevelEncryptionConfigError::InvalidArgument),"NoSuchFieldLevelEncryptionProfile" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionProfile),"QueryArgProfileEmpty" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateFieldLevelEncryptionConfigError::QueryArgProfileEmpty),"TooManyFieldLevelEncryptionConfigs" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionConfigs),"TooManyFieldLevelEncryptionContentTypeProfiles" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionContentTypeProfiles),"TooManyFieldLevelEncryptionQueryArgProfiles" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionQueryArgProfiles),_ => {}
                                 }
             }
         }
@@ -11973,7 +11992,7 @@ impl CreateFieldLevelEncryptionProfileError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "FieldLevelEncryptionProfileAlreadyExists" => return RusotoError::Service(CreateFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileAlreadyExists(parsed_error.message)),"FieldLevelEncryptionProfileSizeExceeded" => return RusotoError::Service(CreateFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileSizeExceeded(parsed_error.message)),"InconsistentQuantities" => return RusotoError::Service(CreateFieldLevelEncryptionProfileError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::Service(CreateFieldLevelEncryptionProfileError::InvalidArgument(parsed_error.message)),"NoSuchPublicKey" => return RusotoError::Service(CreateFieldLevelEncryptionProfileError::NoSuchPublicKey(parsed_error.message)),"TooManyFieldLevelEncryptionEncryptionEntities" => return RusotoError::Service(CreateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionEncryptionEntities(parsed_error.message)),"TooManyFieldLevelEncryptionFieldPatterns" => return RusotoError::Service(CreateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionFieldPatterns(parsed_error.message)),"TooManyFieldLevelEncryptionProfiles" => return RusotoError::Service(CreateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionProfiles(parsed_error.message)),_ => {}
+                                    "FieldLevelEncryptionProfileAlreadyExists" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileAlreadyExists),"FieldLevelEncryptionProfileSizeExceeded" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileSizeExceeded),"InconsistentQuantities" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateFieldLevelEncryptionProfileError::InconsistentQuantities),"InvalidArgument" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateFieldLevelEncryptionProfileError::InvalidArgument),"NoSuchPublicKey" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateFieldLevelEncryptionProfileError::NoSuchPublicKey),"TooManyFieldLevelEncryptionEncryptionEntities" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionEncryptionEntities),"TooManyFieldLevelEncryptionFieldPatterns" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionFieldPatterns),"TooManyFieldLevelEncryptionProfiles" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionProfiles),_ => {}
                                 }
             }
         }
@@ -12032,40 +12051,59 @@ impl CreateInvalidationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(CreateInvalidationError::AccessDenied(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateInvalidationError::AccessDenied,
+                        )
                     }
                     "BatchTooLarge" => {
-                        return RusotoError::Service(CreateInvalidationError::BatchTooLarge(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateInvalidationError::BatchTooLarge,
+                        )
                     }
                     "InconsistentQuantities" => {
-                        return RusotoError::Service(
-                            CreateInvalidationError::InconsistentQuantities(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateInvalidationError::InconsistentQuantities,
                         )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(CreateInvalidationError::InvalidArgument(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateInvalidationError::InvalidArgument,
+                        )
                     }
                     "MissingBody" => {
-                        return RusotoError::Service(CreateInvalidationError::MissingBody(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateInvalidationError::MissingBody,
+                        )
                     }
                     "NoSuchDistribution" => {
-                        return RusotoError::Service(CreateInvalidationError::NoSuchDistribution(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateInvalidationError::NoSuchDistribution,
+                        )
                     }
                     "TooManyInvalidationsInProgress" => {
-                        return RusotoError::Service(
-                            CreateInvalidationError::TooManyInvalidationsInProgress(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateInvalidationError::TooManyInvalidationsInProgress,
                         )
                     }
                     _ => {}
@@ -12122,23 +12160,35 @@ impl CreateKeyGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidArgument" => {
-                        return RusotoError::Service(CreateKeyGroupError::InvalidArgument(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateKeyGroupError::InvalidArgument,
+                        )
                     }
                     "KeyGroupAlreadyExists" => {
-                        return RusotoError::Service(CreateKeyGroupError::KeyGroupAlreadyExists(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateKeyGroupError::KeyGroupAlreadyExists,
+                        )
                     }
                     "TooManyKeyGroups" => {
-                        return RusotoError::Service(CreateKeyGroupError::TooManyKeyGroups(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateKeyGroupError::TooManyKeyGroups,
+                        )
                     }
                     "TooManyPublicKeysInKeyGroup" => {
-                        return RusotoError::Service(
-                            CreateKeyGroupError::TooManyPublicKeysInKeyGroup(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateKeyGroupError::TooManyPublicKeysInKeyGroup,
                         )
                     }
                     _ => {}
@@ -12188,15 +12238,19 @@ impl CreateMonitoringSubscriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(
-                            CreateMonitoringSubscriptionError::AccessDenied(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateMonitoringSubscriptionError::AccessDenied,
                         )
                     }
                     "NoSuchDistribution" => {
-                        return RusotoError::Service(
-                            CreateMonitoringSubscriptionError::NoSuchDistribution(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateMonitoringSubscriptionError::NoSuchDistribution,
                         )
                     }
                     _ => {}
@@ -12256,55 +12310,27 @@ impl CreateOriginRequestPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(CreateOriginRequestPolicyError::AccessDenied(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateOriginRequestPolicyError::AccessDenied)
                     }
                     "InconsistentQuantities" => {
-                        return RusotoError::Service(
-                            CreateOriginRequestPolicyError::InconsistentQuantities(
-                                parsed_error.message,
-                            ),
-                        )
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateOriginRequestPolicyError::InconsistentQuantities)
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(
-                            CreateOriginRequestPolicyError::InvalidArgument(parsed_error.message),
-                        )
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateOriginRequestPolicyError::InvalidArgument)
                     }
                     "OriginRequestPolicyAlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateOriginRequestPolicyError::OriginRequestPolicyAlreadyExists(
-                                parsed_error.message,
-                            ),
-                        )
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateOriginRequestPolicyError::OriginRequestPolicyAlreadyExists)
                     }
                     "TooManyCookiesInOriginRequestPolicy" => {
-                        return RusotoError::Service(
-                            CreateOriginRequestPolicyError::TooManyCookiesInOriginRequestPolicy(
-                                parsed_error.message,
-                            ),
-                        )
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateOriginRequestPolicyError::TooManyCookiesInOriginRequestPolicy)
                     }
                     "TooManyHeadersInOriginRequestPolicy" => {
-                        return RusotoError::Service(
-                            CreateOriginRequestPolicyError::TooManyHeadersInOriginRequestPolicy(
-                                parsed_error.message,
-                            ),
-                        )
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateOriginRequestPolicyError::TooManyHeadersInOriginRequestPolicy)
                     }
                     "TooManyOriginRequestPolicies" => {
-                        return RusotoError::Service(
-                            CreateOriginRequestPolicyError::TooManyOriginRequestPolicies(
-                                parsed_error.message,
-                            ),
-                        )
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateOriginRequestPolicyError::TooManyOriginRequestPolicies)
                     }
-                    "TooManyQueryStringsInOriginRequestPolicy" => return RusotoError::Service(
-                        CreateOriginRequestPolicyError::TooManyQueryStringsInOriginRequestPolicy(
-                            parsed_error.message,
-                        ),
-                    ),
+                    "TooManyQueryStringsInOriginRequestPolicy" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateOriginRequestPolicyError::TooManyQueryStringsInOriginRequestPolicy),
                     _ => {}
                 }
             }
@@ -12368,19 +12394,28 @@ impl CreatePublicKeyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidArgument" => {
-                        return RusotoError::Service(CreatePublicKeyError::InvalidArgument(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreatePublicKeyError::InvalidArgument,
+                        )
                     }
                     "PublicKeyAlreadyExists" => {
-                        return RusotoError::Service(CreatePublicKeyError::PublicKeyAlreadyExists(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreatePublicKeyError::PublicKeyAlreadyExists,
+                        )
                     }
                     "TooManyPublicKeys" => {
-                        return RusotoError::Service(CreatePublicKeyError::TooManyPublicKeys(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreatePublicKeyError::TooManyPublicKeys,
+                        )
                     }
                     _ => {}
                 }
@@ -12430,27 +12465,35 @@ impl CreateRealtimeLogConfigError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(CreateRealtimeLogConfigError::AccessDenied(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateRealtimeLogConfigError::AccessDenied,
+                        )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(CreateRealtimeLogConfigError::InvalidArgument(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateRealtimeLogConfigError::InvalidArgument,
+                        )
                     }
                     "RealtimeLogConfigAlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateRealtimeLogConfigError::RealtimeLogConfigAlreadyExists(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateRealtimeLogConfigError::RealtimeLogConfigAlreadyExists,
                         )
                     }
                     "TooManyRealtimeLogConfigs" => {
-                        return RusotoError::Service(
-                            CreateRealtimeLogConfigError::TooManyRealtimeLogConfigs(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateRealtimeLogConfigError::TooManyRealtimeLogConfigs,
                         )
                     }
                     _ => {}
@@ -12524,79 +12567,99 @@ impl CreateStreamingDistributionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(
-                            CreateStreamingDistributionError::AccessDenied(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateStreamingDistributionError::AccessDenied,
                         )
                     }
                     "CNAMEAlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateStreamingDistributionError::CNAMEAlreadyExists(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateStreamingDistributionError::CNAMEAlreadyExists,
                         )
                     }
                     "InconsistentQuantities" => {
-                        return RusotoError::Service(
-                            CreateStreamingDistributionError::InconsistentQuantities(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateStreamingDistributionError::InconsistentQuantities,
                         )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(
-                            CreateStreamingDistributionError::InvalidArgument(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateStreamingDistributionError::InvalidArgument,
                         )
                     }
                     "InvalidOrigin" => {
-                        return RusotoError::Service(
-                            CreateStreamingDistributionError::InvalidOrigin(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateStreamingDistributionError::InvalidOrigin,
                         )
                     }
                     "InvalidOriginAccessIdentity" => {
-                        return RusotoError::Service(
-                            CreateStreamingDistributionError::InvalidOriginAccessIdentity(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateStreamingDistributionError::InvalidOriginAccessIdentity,
                         )
                     }
                     "MissingBody" => {
-                        return RusotoError::Service(CreateStreamingDistributionError::MissingBody(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateStreamingDistributionError::MissingBody,
+                        )
                     }
                     "StreamingDistributionAlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateStreamingDistributionError::StreamingDistributionAlreadyExists(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateStreamingDistributionError::StreamingDistributionAlreadyExists,
                         )
                     }
                     "TooManyStreamingDistributionCNAMEs" => {
-                        return RusotoError::Service(
-                            CreateStreamingDistributionError::TooManyStreamingDistributionCNAMEs(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateStreamingDistributionError::TooManyStreamingDistributionCNAMEs,
                         )
                     }
                     "TooManyStreamingDistributions" => {
-                        return RusotoError::Service(
-                            CreateStreamingDistributionError::TooManyStreamingDistributions(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateStreamingDistributionError::TooManyStreamingDistributions,
                         )
                     }
                     "TooManyTrustedSigners" => {
-                        return RusotoError::Service(
-                            CreateStreamingDistributionError::TooManyTrustedSigners(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateStreamingDistributionError::TooManyTrustedSigners,
                         )
                     }
                     "TrustedSignerDoesNotExist" => {
-                        return RusotoError::Service(
-                            CreateStreamingDistributionError::TrustedSignerDoesNotExist(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateStreamingDistributionError::TrustedSignerDoesNotExist,
                         )
                     }
                     _ => {}
@@ -12691,7 +12754,7 @@ impl CreateStreamingDistributionWithTagsError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::AccessDenied(parsed_error.message)),"CNAMEAlreadyExists" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::CNAMEAlreadyExists(parsed_error.message)),"InconsistentQuantities" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::InvalidArgument(parsed_error.message)),"InvalidOrigin" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::InvalidOrigin(parsed_error.message)),"InvalidOriginAccessIdentity" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::InvalidOriginAccessIdentity(parsed_error.message)),"InvalidTagging" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::InvalidTagging(parsed_error.message)),"MissingBody" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::MissingBody(parsed_error.message)),"StreamingDistributionAlreadyExists" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::StreamingDistributionAlreadyExists(parsed_error.message)),"TooManyStreamingDistributionCNAMEs" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::TooManyStreamingDistributionCNAMEs(parsed_error.message)),"TooManyStreamingDistributions" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::TooManyStreamingDistributions(parsed_error.message)),"TooManyTrustedSigners" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::TooManyTrustedSigners(parsed_error.message)),"TrustedSignerDoesNotExist" => return RusotoError::Service(CreateStreamingDistributionWithTagsError::TrustedSignerDoesNotExist(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateStreamingDistributionWithTagsError::AccessDenied),"CNAMEAlreadyExists" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateStreamingDistributionWithTagsError::CNAMEAlreadyExists),"InconsistentQuantities" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateStreamingDistributionWithTagsError::InconsistentQuantities),"InvalidArgument" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateStreamingDistributionWithTagsError::InvalidArgument),"InvalidOrigin" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateStreamingDistributionWithTagsError::InvalidOrigin),"InvalidOriginAccessIdentity" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateStreamingDistributionWithTagsError::InvalidOriginAccessIdentity),"InvalidTagging" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateStreamingDistributionWithTagsError::InvalidTagging),"MissingBody" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateStreamingDistributionWithTagsError::MissingBody),"StreamingDistributionAlreadyExists" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateStreamingDistributionWithTagsError::StreamingDistributionAlreadyExists),"TooManyStreamingDistributionCNAMEs" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateStreamingDistributionWithTagsError::TooManyStreamingDistributionCNAMEs),"TooManyStreamingDistributions" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateStreamingDistributionWithTagsError::TooManyStreamingDistributions),"TooManyTrustedSigners" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateStreamingDistributionWithTagsError::TooManyTrustedSigners),"TrustedSignerDoesNotExist" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateStreamingDistributionWithTagsError::TrustedSignerDoesNotExist),_ => {}
                                 }
             }
         }
@@ -12779,34 +12842,52 @@ impl DeleteCachePolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(DeleteCachePolicyError::AccessDenied(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteCachePolicyError::AccessDenied,
+                        )
                     }
                     "CachePolicyInUse" => {
-                        return RusotoError::Service(DeleteCachePolicyError::CachePolicyInUse(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteCachePolicyError::CachePolicyInUse,
+                        )
                     }
                     "IllegalDelete" => {
-                        return RusotoError::Service(DeleteCachePolicyError::IllegalDelete(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteCachePolicyError::IllegalDelete,
+                        )
                     }
                     "InvalidIfMatchVersion" => {
-                        return RusotoError::Service(DeleteCachePolicyError::InvalidIfMatchVersion(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteCachePolicyError::InvalidIfMatchVersion,
+                        )
                     }
                     "NoSuchCachePolicy" => {
-                        return RusotoError::Service(DeleteCachePolicyError::NoSuchCachePolicy(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteCachePolicyError::NoSuchCachePolicy,
+                        )
                     }
                     "PreconditionFailed" => {
-                        return RusotoError::Service(DeleteCachePolicyError::PreconditionFailed(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteCachePolicyError::PreconditionFailed,
+                        )
                     }
                     _ => {}
                 }
@@ -12862,7 +12943,7 @@ impl DeleteCloudFrontOriginAccessIdentityError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(DeleteCloudFrontOriginAccessIdentityError::AccessDenied(parsed_error.message)),"CloudFrontOriginAccessIdentityInUse" => return RusotoError::Service(DeleteCloudFrontOriginAccessIdentityError::CloudFrontOriginAccessIdentityInUse(parsed_error.message)),"InvalidIfMatchVersion" => return RusotoError::Service(DeleteCloudFrontOriginAccessIdentityError::InvalidIfMatchVersion(parsed_error.message)),"NoSuchCloudFrontOriginAccessIdentity" => return RusotoError::Service(DeleteCloudFrontOriginAccessIdentityError::NoSuchCloudFrontOriginAccessIdentity(parsed_error.message)),"PreconditionFailed" => return RusotoError::Service(DeleteCloudFrontOriginAccessIdentityError::PreconditionFailed(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, DeleteCloudFrontOriginAccessIdentityError::AccessDenied),"CloudFrontOriginAccessIdentityInUse" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, DeleteCloudFrontOriginAccessIdentityError::CloudFrontOriginAccessIdentityInUse),"InvalidIfMatchVersion" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, DeleteCloudFrontOriginAccessIdentityError::InvalidIfMatchVersion),"NoSuchCloudFrontOriginAccessIdentity" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, DeleteCloudFrontOriginAccessIdentityError::NoSuchCloudFrontOriginAccessIdentity),"PreconditionFailed" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, DeleteCloudFrontOriginAccessIdentityError::PreconditionFailed),_ => {}
                                 }
             }
         }
@@ -12924,29 +13005,44 @@ impl DeleteDistributionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(DeleteDistributionError::AccessDenied(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteDistributionError::AccessDenied,
+                        )
                     }
                     "DistributionNotDisabled" => {
-                        return RusotoError::Service(
-                            DeleteDistributionError::DistributionNotDisabled(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDistributionError::DistributionNotDisabled,
                         )
                     }
                     "InvalidIfMatchVersion" => {
-                        return RusotoError::Service(
-                            DeleteDistributionError::InvalidIfMatchVersion(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDistributionError::InvalidIfMatchVersion,
                         )
                     }
                     "NoSuchDistribution" => {
-                        return RusotoError::Service(DeleteDistributionError::NoSuchDistribution(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteDistributionError::NoSuchDistribution,
+                        )
                     }
                     "PreconditionFailed" => {
-                        return RusotoError::Service(DeleteDistributionError::PreconditionFailed(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteDistributionError::PreconditionFailed,
+                        )
                     }
                     _ => {}
                 }
@@ -13002,38 +13098,43 @@ impl DeleteFieldLevelEncryptionConfigError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(
-                            DeleteFieldLevelEncryptionConfigError::AccessDenied(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteFieldLevelEncryptionConfigError::AccessDenied,
                         )
                     }
                     "FieldLevelEncryptionConfigInUse" => {
-                        return RusotoError::Service(
-                            DeleteFieldLevelEncryptionConfigError::FieldLevelEncryptionConfigInUse(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteFieldLevelEncryptionConfigError::FieldLevelEncryptionConfigInUse,
                         )
                     }
                     "InvalidIfMatchVersion" => {
-                        return RusotoError::Service(
-                            DeleteFieldLevelEncryptionConfigError::InvalidIfMatchVersion(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteFieldLevelEncryptionConfigError::InvalidIfMatchVersion,
                         )
                     }
                     "NoSuchFieldLevelEncryptionConfig" => {
-                        return RusotoError::Service(
-                            DeleteFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionConfig(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionConfig,
                         )
                     }
                     "PreconditionFailed" => {
-                        return RusotoError::Service(
-                            DeleteFieldLevelEncryptionConfigError::PreconditionFailed(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteFieldLevelEncryptionConfigError::PreconditionFailed,
                         )
                     }
                     _ => {}
@@ -13100,34 +13201,39 @@ impl DeleteFieldLevelEncryptionProfileError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(
-                            DeleteFieldLevelEncryptionProfileError::AccessDenied(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteFieldLevelEncryptionProfileError::AccessDenied,
                         )
                     }
-                    "FieldLevelEncryptionProfileInUse" => return RusotoError::Service(
-                        DeleteFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileInUse(
-                            parsed_error.message,
-                        ),
+                    "FieldLevelEncryptionProfileInUse" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        DeleteFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileInUse,
                     ),
                     "InvalidIfMatchVersion" => {
-                        return RusotoError::Service(
-                            DeleteFieldLevelEncryptionProfileError::InvalidIfMatchVersion(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteFieldLevelEncryptionProfileError::InvalidIfMatchVersion,
                         )
                     }
-                    "NoSuchFieldLevelEncryptionProfile" => return RusotoError::Service(
-                        DeleteFieldLevelEncryptionProfileError::NoSuchFieldLevelEncryptionProfile(
-                            parsed_error.message,
-                        ),
+                    "NoSuchFieldLevelEncryptionProfile" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        DeleteFieldLevelEncryptionProfileError::NoSuchFieldLevelEncryptionProfile,
                     ),
                     "PreconditionFailed" => {
-                        return RusotoError::Service(
-                            DeleteFieldLevelEncryptionProfileError::PreconditionFailed(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteFieldLevelEncryptionProfileError::PreconditionFailed,
                         )
                     }
                     _ => {}
@@ -13190,24 +13296,36 @@ impl DeleteKeyGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidIfMatchVersion" => {
-                        return RusotoError::Service(DeleteKeyGroupError::InvalidIfMatchVersion(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteKeyGroupError::InvalidIfMatchVersion,
+                        )
                     }
                     "NoSuchResource" => {
-                        return RusotoError::Service(DeleteKeyGroupError::NoSuchResource(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteKeyGroupError::NoSuchResource,
+                        )
                     }
                     "PreconditionFailed" => {
-                        return RusotoError::Service(DeleteKeyGroupError::PreconditionFailed(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteKeyGroupError::PreconditionFailed,
+                        )
                     }
                     "ResourceInUse" => {
-                        return RusotoError::Service(DeleteKeyGroupError::ResourceInUse(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteKeyGroupError::ResourceInUse,
+                        )
                     }
                     _ => {}
                 }
@@ -13256,15 +13374,19 @@ impl DeleteMonitoringSubscriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(
-                            DeleteMonitoringSubscriptionError::AccessDenied(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteMonitoringSubscriptionError::AccessDenied,
                         )
                     }
                     "NoSuchDistribution" => {
-                        return RusotoError::Service(
-                            DeleteMonitoringSubscriptionError::NoSuchDistribution(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteMonitoringSubscriptionError::NoSuchDistribution,
                         )
                     }
                     _ => {}
@@ -13320,41 +13442,51 @@ impl DeleteOriginRequestPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(DeleteOriginRequestPolicyError::AccessDenied(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteOriginRequestPolicyError::AccessDenied,
+                        )
                     }
                     "IllegalDelete" => {
-                        return RusotoError::Service(DeleteOriginRequestPolicyError::IllegalDelete(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteOriginRequestPolicyError::IllegalDelete,
+                        )
                     }
                     "InvalidIfMatchVersion" => {
-                        return RusotoError::Service(
-                            DeleteOriginRequestPolicyError::InvalidIfMatchVersion(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteOriginRequestPolicyError::InvalidIfMatchVersion,
                         )
                     }
                     "NoSuchOriginRequestPolicy" => {
-                        return RusotoError::Service(
-                            DeleteOriginRequestPolicyError::NoSuchOriginRequestPolicy(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteOriginRequestPolicyError::NoSuchOriginRequestPolicy,
                         )
                     }
                     "OriginRequestPolicyInUse" => {
-                        return RusotoError::Service(
-                            DeleteOriginRequestPolicyError::OriginRequestPolicyInUse(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteOriginRequestPolicyError::OriginRequestPolicyInUse,
                         )
                     }
                     "PreconditionFailed" => {
-                        return RusotoError::Service(
-                            DeleteOriginRequestPolicyError::PreconditionFailed(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteOriginRequestPolicyError::PreconditionFailed,
                         )
                     }
                     _ => {}
@@ -13416,29 +13548,44 @@ impl DeletePublicKeyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(DeletePublicKeyError::AccessDenied(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeletePublicKeyError::AccessDenied,
+                        )
                     }
                     "InvalidIfMatchVersion" => {
-                        return RusotoError::Service(DeletePublicKeyError::InvalidIfMatchVersion(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeletePublicKeyError::InvalidIfMatchVersion,
+                        )
                     }
                     "NoSuchPublicKey" => {
-                        return RusotoError::Service(DeletePublicKeyError::NoSuchPublicKey(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeletePublicKeyError::NoSuchPublicKey,
+                        )
                     }
                     "PreconditionFailed" => {
-                        return RusotoError::Service(DeletePublicKeyError::PreconditionFailed(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeletePublicKeyError::PreconditionFailed,
+                        )
                     }
                     "PublicKeyInUse" => {
-                        return RusotoError::Service(DeletePublicKeyError::PublicKeyInUse(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeletePublicKeyError::PublicKeyInUse,
+                        )
                     }
                     _ => {}
                 }
@@ -13490,27 +13637,35 @@ impl DeleteRealtimeLogConfigError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(DeleteRealtimeLogConfigError::AccessDenied(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteRealtimeLogConfigError::AccessDenied,
+                        )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(DeleteRealtimeLogConfigError::InvalidArgument(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteRealtimeLogConfigError::InvalidArgument,
+                        )
                     }
                     "NoSuchRealtimeLogConfig" => {
-                        return RusotoError::Service(
-                            DeleteRealtimeLogConfigError::NoSuchRealtimeLogConfig(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteRealtimeLogConfigError::NoSuchRealtimeLogConfig,
                         )
                     }
                     "RealtimeLogConfigInUse" => {
-                        return RusotoError::Service(
-                            DeleteRealtimeLogConfigError::RealtimeLogConfigInUse(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteRealtimeLogConfigError::RealtimeLogConfigInUse,
                         )
                     }
                     _ => {}
@@ -13570,36 +13725,43 @@ impl DeleteStreamingDistributionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(
-                            DeleteStreamingDistributionError::AccessDenied(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteStreamingDistributionError::AccessDenied,
                         )
                     }
                     "InvalidIfMatchVersion" => {
-                        return RusotoError::Service(
-                            DeleteStreamingDistributionError::InvalidIfMatchVersion(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteStreamingDistributionError::InvalidIfMatchVersion,
                         )
                     }
                     "NoSuchStreamingDistribution" => {
-                        return RusotoError::Service(
-                            DeleteStreamingDistributionError::NoSuchStreamingDistribution(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteStreamingDistributionError::NoSuchStreamingDistribution,
                         )
                     }
                     "PreconditionFailed" => {
-                        return RusotoError::Service(
-                            DeleteStreamingDistributionError::PreconditionFailed(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteStreamingDistributionError::PreconditionFailed,
                         )
                     }
                     "StreamingDistributionNotDisabled" => {
-                        return RusotoError::Service(
-                            DeleteStreamingDistributionError::StreamingDistributionNotDisabled(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteStreamingDistributionError::StreamingDistributionNotDisabled,
                         )
                     }
                     _ => {}
@@ -13656,14 +13818,20 @@ impl GetCachePolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(GetCachePolicyError::AccessDenied(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetCachePolicyError::AccessDenied,
+                        )
                     }
                     "NoSuchCachePolicy" => {
-                        return RusotoError::Service(GetCachePolicyError::NoSuchCachePolicy(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetCachePolicyError::NoSuchCachePolicy,
+                        )
                     }
                     _ => {}
                 }
@@ -13708,14 +13876,20 @@ impl GetCachePolicyConfigError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(GetCachePolicyConfigError::AccessDenied(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetCachePolicyConfigError::AccessDenied,
+                        )
                     }
                     "NoSuchCachePolicy" => {
-                        return RusotoError::Service(GetCachePolicyConfigError::NoSuchCachePolicy(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetCachePolicyConfigError::NoSuchCachePolicy,
+                        )
                     }
                     _ => {}
                 }
@@ -13761,7 +13935,7 @@ impl GetCloudFrontOriginAccessIdentityError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(GetCloudFrontOriginAccessIdentityError::AccessDenied(parsed_error.message)),"NoSuchCloudFrontOriginAccessIdentity" => return RusotoError::Service(GetCloudFrontOriginAccessIdentityError::NoSuchCloudFrontOriginAccessIdentity(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, GetCloudFrontOriginAccessIdentityError::AccessDenied),"NoSuchCloudFrontOriginAccessIdentity" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, GetCloudFrontOriginAccessIdentityError::NoSuchCloudFrontOriginAccessIdentity),_ => {}
                                 }
             }
         }
@@ -13809,7 +13983,7 @@ impl GetCloudFrontOriginAccessIdentityConfigError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(GetCloudFrontOriginAccessIdentityConfigError::AccessDenied(parsed_error.message)),"NoSuchCloudFrontOriginAccessIdentity" => return RusotoError::Service(GetCloudFrontOriginAccessIdentityConfigError::NoSuchCloudFrontOriginAccessIdentity(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, GetCloudFrontOriginAccessIdentityConfigError::AccessDenied),"NoSuchCloudFrontOriginAccessIdentity" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, GetCloudFrontOriginAccessIdentityConfigError::NoSuchCloudFrontOriginAccessIdentity),_ => {}
                                 }
             }
         }
@@ -13856,14 +14030,20 @@ impl GetDistributionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(GetDistributionError::AccessDenied(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetDistributionError::AccessDenied,
+                        )
                     }
                     "NoSuchDistribution" => {
-                        return RusotoError::Service(GetDistributionError::NoSuchDistribution(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetDistributionError::NoSuchDistribution,
+                        )
                     }
                     _ => {}
                 }
@@ -13908,13 +14088,19 @@ impl GetDistributionConfigError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(GetDistributionConfigError::AccessDenied(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetDistributionConfigError::AccessDenied,
+                        )
                     }
                     "NoSuchDistribution" => {
-                        return RusotoError::Service(
-                            GetDistributionConfigError::NoSuchDistribution(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetDistributionConfigError::NoSuchDistribution,
                         )
                     }
                     _ => {}
@@ -13960,15 +14146,19 @@ impl GetFieldLevelEncryptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(GetFieldLevelEncryptionError::AccessDenied(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetFieldLevelEncryptionError::AccessDenied,
+                        )
                     }
                     "NoSuchFieldLevelEncryptionConfig" => {
-                        return RusotoError::Service(
-                            GetFieldLevelEncryptionError::NoSuchFieldLevelEncryptionConfig(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetFieldLevelEncryptionError::NoSuchFieldLevelEncryptionConfig,
                         )
                     }
                     _ => {}
@@ -14018,15 +14208,19 @@ impl GetFieldLevelEncryptionConfigError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(
-                            GetFieldLevelEncryptionConfigError::AccessDenied(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetFieldLevelEncryptionConfigError::AccessDenied,
                         )
                     }
                     "NoSuchFieldLevelEncryptionConfig" => {
-                        return RusotoError::Service(
-                            GetFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionConfig(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionConfig,
                         )
                     }
                     _ => {}
@@ -14076,15 +14270,19 @@ impl GetFieldLevelEncryptionProfileError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(
-                            GetFieldLevelEncryptionProfileError::AccessDenied(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetFieldLevelEncryptionProfileError::AccessDenied,
                         )
                     }
                     "NoSuchFieldLevelEncryptionProfile" => {
-                        return RusotoError::Service(
-                            GetFieldLevelEncryptionProfileError::NoSuchFieldLevelEncryptionProfile(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetFieldLevelEncryptionProfileError::NoSuchFieldLevelEncryptionProfile,
                         )
                     }
                     _ => {}
@@ -14133,7 +14331,7 @@ impl GetFieldLevelEncryptionProfileConfigError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(GetFieldLevelEncryptionProfileConfigError::AccessDenied(parsed_error.message)),"NoSuchFieldLevelEncryptionProfile" => return RusotoError::Service(GetFieldLevelEncryptionProfileConfigError::NoSuchFieldLevelEncryptionProfile(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, GetFieldLevelEncryptionProfileConfigError::AccessDenied),"NoSuchFieldLevelEncryptionProfile" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, GetFieldLevelEncryptionProfileConfigError::NoSuchFieldLevelEncryptionProfile),_ => {}
                                 }
             }
         }
@@ -14182,19 +14380,28 @@ impl GetInvalidationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(GetInvalidationError::AccessDenied(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetInvalidationError::AccessDenied,
+                        )
                     }
                     "NoSuchDistribution" => {
-                        return RusotoError::Service(GetInvalidationError::NoSuchDistribution(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetInvalidationError::NoSuchDistribution,
+                        )
                     }
                     "NoSuchInvalidation" => {
-                        return RusotoError::Service(GetInvalidationError::NoSuchInvalidation(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetInvalidationError::NoSuchInvalidation,
+                        )
                     }
                     _ => {}
                 }
@@ -14238,9 +14445,12 @@ impl GetKeyGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchResource" => {
-                        return RusotoError::Service(GetKeyGroupError::NoSuchResource(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetKeyGroupError::NoSuchResource,
+                        )
                     }
                     _ => {}
                 }
@@ -14282,9 +14492,12 @@ impl GetKeyGroupConfigError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchResource" => {
-                        return RusotoError::Service(GetKeyGroupConfigError::NoSuchResource(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetKeyGroupConfigError::NoSuchResource,
+                        )
                     }
                     _ => {}
                 }
@@ -14328,15 +14541,19 @@ impl GetMonitoringSubscriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(GetMonitoringSubscriptionError::AccessDenied(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetMonitoringSubscriptionError::AccessDenied,
+                        )
                     }
                     "NoSuchDistribution" => {
-                        return RusotoError::Service(
-                            GetMonitoringSubscriptionError::NoSuchDistribution(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetMonitoringSubscriptionError::NoSuchDistribution,
                         )
                     }
                     _ => {}
@@ -14382,15 +14599,19 @@ impl GetOriginRequestPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(GetOriginRequestPolicyError::AccessDenied(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetOriginRequestPolicyError::AccessDenied,
+                        )
                     }
                     "NoSuchOriginRequestPolicy" => {
-                        return RusotoError::Service(
-                            GetOriginRequestPolicyError::NoSuchOriginRequestPolicy(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetOriginRequestPolicyError::NoSuchOriginRequestPolicy,
                         )
                     }
                     _ => {}
@@ -14440,15 +14661,19 @@ impl GetOriginRequestPolicyConfigError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(
-                            GetOriginRequestPolicyConfigError::AccessDenied(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetOriginRequestPolicyConfigError::AccessDenied,
                         )
                     }
                     "NoSuchOriginRequestPolicy" => {
-                        return RusotoError::Service(
-                            GetOriginRequestPolicyConfigError::NoSuchOriginRequestPolicy(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetOriginRequestPolicyConfigError::NoSuchOriginRequestPolicy,
                         )
                     }
                     _ => {}
@@ -14496,14 +14721,20 @@ impl GetPublicKeyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(GetPublicKeyError::AccessDenied(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetPublicKeyError::AccessDenied,
+                        )
                     }
                     "NoSuchPublicKey" => {
-                        return RusotoError::Service(GetPublicKeyError::NoSuchPublicKey(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetPublicKeyError::NoSuchPublicKey,
+                        )
                     }
                     _ => {}
                 }
@@ -14548,14 +14779,20 @@ impl GetPublicKeyConfigError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(GetPublicKeyConfigError::AccessDenied(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetPublicKeyConfigError::AccessDenied,
+                        )
                     }
                     "NoSuchPublicKey" => {
-                        return RusotoError::Service(GetPublicKeyConfigError::NoSuchPublicKey(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetPublicKeyConfigError::NoSuchPublicKey,
+                        )
                     }
                     _ => {}
                 }
@@ -14602,20 +14839,27 @@ impl GetRealtimeLogConfigError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(GetRealtimeLogConfigError::AccessDenied(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetRealtimeLogConfigError::AccessDenied,
+                        )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(GetRealtimeLogConfigError::InvalidArgument(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetRealtimeLogConfigError::InvalidArgument,
+                        )
                     }
                     "NoSuchRealtimeLogConfig" => {
-                        return RusotoError::Service(
-                            GetRealtimeLogConfigError::NoSuchRealtimeLogConfig(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetRealtimeLogConfigError::NoSuchRealtimeLogConfig,
                         )
                     }
                     _ => {}
@@ -14662,15 +14906,19 @@ impl GetStreamingDistributionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(GetStreamingDistributionError::AccessDenied(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetStreamingDistributionError::AccessDenied,
+                        )
                     }
                     "NoSuchStreamingDistribution" => {
-                        return RusotoError::Service(
-                            GetStreamingDistributionError::NoSuchStreamingDistribution(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetStreamingDistributionError::NoSuchStreamingDistribution,
                         )
                     }
                     _ => {}
@@ -14720,15 +14968,19 @@ impl GetStreamingDistributionConfigError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(
-                            GetStreamingDistributionConfigError::AccessDenied(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetStreamingDistributionConfigError::AccessDenied,
                         )
                     }
                     "NoSuchStreamingDistribution" => {
-                        return RusotoError::Service(
-                            GetStreamingDistributionConfigError::NoSuchStreamingDistribution(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetStreamingDistributionConfigError::NoSuchStreamingDistribution,
                         )
                     }
                     _ => {}
@@ -14778,19 +15030,28 @@ impl ListCachePoliciesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(ListCachePoliciesError::AccessDenied(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListCachePoliciesError::AccessDenied,
+                        )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(ListCachePoliciesError::InvalidArgument(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListCachePoliciesError::InvalidArgument,
+                        )
                     }
                     "NoSuchCachePolicy" => {
-                        return RusotoError::Service(ListCachePoliciesError::NoSuchCachePolicy(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListCachePoliciesError::NoSuchCachePolicy,
+                        )
                     }
                     _ => {}
                 }
@@ -14836,10 +15097,11 @@ impl ListCloudFrontOriginAccessIdentitiesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidArgument" => {
-                        return RusotoError::Service(
-                            ListCloudFrontOriginAccessIdentitiesError::InvalidArgument(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListCloudFrontOriginAccessIdentitiesError::InvalidArgument,
                         )
                     }
                     _ => {}
@@ -14884,9 +15146,12 @@ impl ListDistributionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidArgument" => {
-                        return RusotoError::Service(ListDistributionsError::InvalidArgument(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListDistributionsError::InvalidArgument,
+                        )
                     }
                     _ => {}
                 }
@@ -14934,24 +15199,27 @@ impl ListDistributionsByCachePolicyIdError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(
-                            ListDistributionsByCachePolicyIdError::AccessDenied(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListDistributionsByCachePolicyIdError::AccessDenied,
                         )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(
-                            ListDistributionsByCachePolicyIdError::InvalidArgument(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListDistributionsByCachePolicyIdError::InvalidArgument,
                         )
                     }
                     "NoSuchCachePolicy" => {
-                        return RusotoError::Service(
-                            ListDistributionsByCachePolicyIdError::NoSuchCachePolicy(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListDistributionsByCachePolicyIdError::NoSuchCachePolicy,
                         )
                     }
                     _ => {}
@@ -15006,13 +15274,19 @@ impl ListDistributionsByKeyGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidArgument" => {
-                        return RusotoError::Service(
-                            ListDistributionsByKeyGroupError::InvalidArgument(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListDistributionsByKeyGroupError::InvalidArgument,
                         )
                     }
                     "NoSuchResource" => {
-                        return RusotoError::Service(
-                            ListDistributionsByKeyGroupError::NoSuchResource(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListDistributionsByKeyGroupError::NoSuchResource,
                         )
                     }
                     _ => {}
@@ -15062,23 +15336,26 @@ impl ListDistributionsByOriginRequestPolicyIdError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(
-                            ListDistributionsByOriginRequestPolicyIdError::AccessDenied(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListDistributionsByOriginRequestPolicyIdError::AccessDenied,
                         )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(
-                            ListDistributionsByOriginRequestPolicyIdError::InvalidArgument(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListDistributionsByOriginRequestPolicyIdError::InvalidArgument,
                         )
                     }
-                    "NoSuchOriginRequestPolicy" => return RusotoError::Service(
-                        ListDistributionsByOriginRequestPolicyIdError::NoSuchOriginRequestPolicy(
-                            parsed_error.message,
-                        ),
+                    "NoSuchOriginRequestPolicy" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        ListDistributionsByOriginRequestPolicyIdError::NoSuchOriginRequestPolicy,
                     ),
                     _ => {}
                 }
@@ -15130,10 +15407,11 @@ impl ListDistributionsByRealtimeLogConfigError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidArgument" => {
-                        return RusotoError::Service(
-                            ListDistributionsByRealtimeLogConfigError::InvalidArgument(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListDistributionsByRealtimeLogConfigError::InvalidArgument,
                         )
                     }
                     _ => {}
@@ -15182,13 +15460,19 @@ impl ListDistributionsByWebACLIdError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidArgument" => {
-                        return RusotoError::Service(
-                            ListDistributionsByWebACLIdError::InvalidArgument(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListDistributionsByWebACLIdError::InvalidArgument,
                         )
                     }
                     "InvalidWebACLId" => {
-                        return RusotoError::Service(
-                            ListDistributionsByWebACLIdError::InvalidWebACLId(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListDistributionsByWebACLIdError::InvalidWebACLId,
                         )
                     }
                     _ => {}
@@ -15234,10 +15518,11 @@ impl ListFieldLevelEncryptionConfigsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidArgument" => {
-                        return RusotoError::Service(
-                            ListFieldLevelEncryptionConfigsError::InvalidArgument(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListFieldLevelEncryptionConfigsError::InvalidArgument,
                         )
                     }
                     _ => {}
@@ -15284,10 +15569,11 @@ impl ListFieldLevelEncryptionProfilesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidArgument" => {
-                        return RusotoError::Service(
-                            ListFieldLevelEncryptionProfilesError::InvalidArgument(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListFieldLevelEncryptionProfilesError::InvalidArgument,
                         )
                     }
                     _ => {}
@@ -15336,19 +15622,28 @@ impl ListInvalidationsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(ListInvalidationsError::AccessDenied(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListInvalidationsError::AccessDenied,
+                        )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(ListInvalidationsError::InvalidArgument(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListInvalidationsError::InvalidArgument,
+                        )
                     }
                     "NoSuchDistribution" => {
-                        return RusotoError::Service(ListInvalidationsError::NoSuchDistribution(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListInvalidationsError::NoSuchDistribution,
+                        )
                     }
                     _ => {}
                 }
@@ -15392,9 +15687,12 @@ impl ListKeyGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidArgument" => {
-                        return RusotoError::Service(ListKeyGroupsError::InvalidArgument(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListKeyGroupsError::InvalidArgument,
+                        )
                     }
                     _ => {}
                 }
@@ -15440,20 +15738,27 @@ impl ListOriginRequestPoliciesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(ListOriginRequestPoliciesError::AccessDenied(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListOriginRequestPoliciesError::AccessDenied,
+                        )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(
-                            ListOriginRequestPoliciesError::InvalidArgument(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListOriginRequestPoliciesError::InvalidArgument,
                         )
                     }
                     "NoSuchOriginRequestPolicy" => {
-                        return RusotoError::Service(
-                            ListOriginRequestPoliciesError::NoSuchOriginRequestPolicy(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListOriginRequestPoliciesError::NoSuchOriginRequestPolicy,
                         )
                     }
                     _ => {}
@@ -15500,9 +15805,12 @@ impl ListPublicKeysError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidArgument" => {
-                        return RusotoError::Service(ListPublicKeysError::InvalidArgument(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListPublicKeysError::InvalidArgument,
+                        )
                     }
                     _ => {}
                 }
@@ -15548,20 +15856,27 @@ impl ListRealtimeLogConfigsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(ListRealtimeLogConfigsError::AccessDenied(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListRealtimeLogConfigsError::AccessDenied,
+                        )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(ListRealtimeLogConfigsError::InvalidArgument(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListRealtimeLogConfigsError::InvalidArgument,
+                        )
                     }
                     "NoSuchRealtimeLogConfig" => {
-                        return RusotoError::Service(
-                            ListRealtimeLogConfigsError::NoSuchRealtimeLogConfig(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListRealtimeLogConfigsError::NoSuchRealtimeLogConfig,
                         )
                     }
                     _ => {}
@@ -15610,8 +15925,11 @@ impl ListStreamingDistributionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidArgument" => {
-                        return RusotoError::Service(
-                            ListStreamingDistributionsError::InvalidArgument(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListStreamingDistributionsError::InvalidArgument,
                         )
                     }
                     _ => {}
@@ -15660,24 +15978,36 @@ impl ListTagsForResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(ListTagsForResourceError::AccessDenied(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListTagsForResourceError::AccessDenied,
+                        )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(ListTagsForResourceError::InvalidArgument(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListTagsForResourceError::InvalidArgument,
+                        )
                     }
                     "InvalidTagging" => {
-                        return RusotoError::Service(ListTagsForResourceError::InvalidTagging(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListTagsForResourceError::InvalidTagging,
+                        )
                     }
                     "NoSuchResource" => {
-                        return RusotoError::Service(ListTagsForResourceError::NoSuchResource(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListTagsForResourceError::NoSuchResource,
+                        )
                     }
                     _ => {}
                 }
@@ -15728,24 +16058,36 @@ impl TagResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(TagResourceError::AccessDenied(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            TagResourceError::AccessDenied,
+                        )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(TagResourceError::InvalidArgument(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            TagResourceError::InvalidArgument,
+                        )
                     }
                     "InvalidTagging" => {
-                        return RusotoError::Service(TagResourceError::InvalidTagging(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            TagResourceError::InvalidTagging,
+                        )
                     }
                     "NoSuchResource" => {
-                        return RusotoError::Service(TagResourceError::NoSuchResource(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            TagResourceError::NoSuchResource,
+                        )
                     }
                     _ => {}
                 }
@@ -15796,24 +16138,36 @@ impl UntagResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(UntagResourceError::AccessDenied(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UntagResourceError::AccessDenied,
+                        )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(UntagResourceError::InvalidArgument(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UntagResourceError::InvalidArgument,
+                        )
                     }
                     "InvalidTagging" => {
-                        return RusotoError::Service(UntagResourceError::InvalidTagging(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UntagResourceError::InvalidTagging,
+                        )
                     }
                     "NoSuchResource" => {
-                        return RusotoError::Service(UntagResourceError::NoSuchResource(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UntagResourceError::NoSuchResource,
+                        )
                     }
                     _ => {}
                 }
@@ -15878,64 +16232,91 @@ impl UpdateCachePolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(UpdateCachePolicyError::AccessDenied(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateCachePolicyError::AccessDenied,
+                        )
                     }
                     "CachePolicyAlreadyExists" => {
-                        return RusotoError::Service(
-                            UpdateCachePolicyError::CachePolicyAlreadyExists(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateCachePolicyError::CachePolicyAlreadyExists,
                         )
                     }
                     "IllegalUpdate" => {
-                        return RusotoError::Service(UpdateCachePolicyError::IllegalUpdate(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateCachePolicyError::IllegalUpdate,
+                        )
                     }
                     "InconsistentQuantities" => {
-                        return RusotoError::Service(
-                            UpdateCachePolicyError::InconsistentQuantities(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateCachePolicyError::InconsistentQuantities,
                         )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(UpdateCachePolicyError::InvalidArgument(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateCachePolicyError::InvalidArgument,
+                        )
                     }
                     "InvalidIfMatchVersion" => {
-                        return RusotoError::Service(UpdateCachePolicyError::InvalidIfMatchVersion(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateCachePolicyError::InvalidIfMatchVersion,
+                        )
                     }
                     "NoSuchCachePolicy" => {
-                        return RusotoError::Service(UpdateCachePolicyError::NoSuchCachePolicy(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateCachePolicyError::NoSuchCachePolicy,
+                        )
                     }
                     "PreconditionFailed" => {
-                        return RusotoError::Service(UpdateCachePolicyError::PreconditionFailed(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateCachePolicyError::PreconditionFailed,
+                        )
                     }
                     "TooManyCookiesInCachePolicy" => {
-                        return RusotoError::Service(
-                            UpdateCachePolicyError::TooManyCookiesInCachePolicy(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateCachePolicyError::TooManyCookiesInCachePolicy,
                         )
                     }
                     "TooManyHeadersInCachePolicy" => {
-                        return RusotoError::Service(
-                            UpdateCachePolicyError::TooManyHeadersInCachePolicy(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateCachePolicyError::TooManyHeadersInCachePolicy,
                         )
                     }
                     "TooManyQueryStringsInCachePolicy" => {
-                        return RusotoError::Service(
-                            UpdateCachePolicyError::TooManyQueryStringsInCachePolicy(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateCachePolicyError::TooManyQueryStringsInCachePolicy,
                         )
                     }
                     _ => {}
@@ -16009,7 +16390,7 @@ impl UpdateCloudFrontOriginAccessIdentityError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(UpdateCloudFrontOriginAccessIdentityError::AccessDenied(parsed_error.message)),"IllegalUpdate" => return RusotoError::Service(UpdateCloudFrontOriginAccessIdentityError::IllegalUpdate(parsed_error.message)),"InconsistentQuantities" => return RusotoError::Service(UpdateCloudFrontOriginAccessIdentityError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::Service(UpdateCloudFrontOriginAccessIdentityError::InvalidArgument(parsed_error.message)),"InvalidIfMatchVersion" => return RusotoError::Service(UpdateCloudFrontOriginAccessIdentityError::InvalidIfMatchVersion(parsed_error.message)),"MissingBody" => return RusotoError::Service(UpdateCloudFrontOriginAccessIdentityError::MissingBody(parsed_error.message)),"NoSuchCloudFrontOriginAccessIdentity" => return RusotoError::Service(UpdateCloudFrontOriginAccessIdentityError::NoSuchCloudFrontOriginAccessIdentity(parsed_error.message)),"PreconditionFailed" => return RusotoError::Service(UpdateCloudFrontOriginAccessIdentityError::PreconditionFailed(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateCloudFrontOriginAccessIdentityError::AccessDenied),"IllegalUpdate" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateCloudFrontOriginAccessIdentityError::IllegalUpdate),"InconsistentQuantities" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateCloudFrontOriginAccessIdentityError::InconsistentQuantities),"InvalidArgument" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateCloudFrontOriginAccessIdentityError::InvalidArgument),"InvalidIfMatchVersion" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateCloudFrontOriginAccessIdentityError::InvalidIfMatchVersion),"MissingBody" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateCloudFrontOriginAccessIdentityError::MissingBody),"NoSuchCloudFrontOriginAccessIdentity" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateCloudFrontOriginAccessIdentityError::NoSuchCloudFrontOriginAccessIdentity),"PreconditionFailed" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateCloudFrontOriginAccessIdentityError::PreconditionFailed),_ => {}
                                 }
             }
         }
@@ -16173,7 +16554,7 @@ impl UpdateDistributionError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(UpdateDistributionError::AccessDenied(parsed_error.message)),"CNAMEAlreadyExists" => return RusotoError::Service(UpdateDistributionError::CNAMEAlreadyExists(parsed_error.message)),"IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior" => return RusotoError::Service(UpdateDistributionError::IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior(parsed_error.message)),"IllegalUpdate" => return RusotoError::Service(UpdateDistributionError::IllegalUpdate(parsed_error.message)),"InconsistentQuantities" => return RusotoError::Service(UpdateDistributionError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::Service(UpdateDistributionError::InvalidArgument(parsed_error.message)),"InvalidDefaultRootObject" => return RusotoError::Service(UpdateDistributionError::InvalidDefaultRootObject(parsed_error.message)),"InvalidErrorCode" => return RusotoError::Service(UpdateDistributionError::InvalidErrorCode(parsed_error.message)),"InvalidForwardCookies" => return RusotoError::Service(UpdateDistributionError::InvalidForwardCookies(parsed_error.message)),"InvalidGeoRestrictionParameter" => return RusotoError::Service(UpdateDistributionError::InvalidGeoRestrictionParameter(parsed_error.message)),"InvalidHeadersForS3Origin" => return RusotoError::Service(UpdateDistributionError::InvalidHeadersForS3Origin(parsed_error.message)),"InvalidIfMatchVersion" => return RusotoError::Service(UpdateDistributionError::InvalidIfMatchVersion(parsed_error.message)),"InvalidLambdaFunctionAssociation" => return RusotoError::Service(UpdateDistributionError::InvalidLambdaFunctionAssociation(parsed_error.message)),"InvalidLocationCode" => return RusotoError::Service(UpdateDistributionError::InvalidLocationCode(parsed_error.message)),"InvalidMinimumProtocolVersion" => return RusotoError::Service(UpdateDistributionError::InvalidMinimumProtocolVersion(parsed_error.message)),"InvalidOriginAccessIdentity" => return RusotoError::Service(UpdateDistributionError::InvalidOriginAccessIdentity(parsed_error.message)),"InvalidOriginKeepaliveTimeout" => return RusotoError::Service(UpdateDistributionError::InvalidOriginKeepaliveTimeout(parsed_error.message)),"InvalidOriginReadTimeout" => return RusotoError::Service(UpdateDistributionError::InvalidOriginReadTimeout(parsed_error.message)),"InvalidQueryStringParameters" => return RusotoError::Service(UpdateDistributionError::InvalidQueryStringParameters(parsed_error.message)),"InvalidRelativePath" => return RusotoError::Service(UpdateDistributionError::InvalidRelativePath(parsed_error.message)),"InvalidRequiredProtocol" => return RusotoError::Service(UpdateDistributionError::InvalidRequiredProtocol(parsed_error.message)),"InvalidResponseCode" => return RusotoError::Service(UpdateDistributionError::InvalidResponseCode(parsed_error.message)),"InvalidTTLOrder" => return RusotoError::Service(UpdateDistributionError::InvalidTTLOrder(parsed_error.message)),"InvalidViewerCertificate" => return RusotoError::Service(UpdateDistributionError::InvalidViewerCertificate(parsed_error.message)),"InvalidWebACLId" => return RusotoError::Service(UpdateDistributionError::InvalidWebACLId(parsed_error.message)),"MissingBody" => return RusotoError::Service(UpdateDistributionError::MissingBody(parsed_error.message)),"NoSuchCachePolicy" => return RusotoError::Service(UpdateDistributionError::NoSuchCachePolicy(parsed_error.message)),"NoSuchDistribution" => return RusotoError::Service(UpdateDistributionError::NoSuchDistribution(parsed_error.message)),"NoSuchFieldLevelEncryptionConfig" => return RusotoError::Service(UpdateDistributionError::NoSuchFieldLevelEncryptionConfig(parsed_error.message)),"NoSuchOrigin" => return RusotoError::Service(UpdateDistributionError::NoSuchOrigin(parsed_error.message)),"NoSuchOriginRequestPolicy" => return RusotoError::Service(UpdateDistributionError::NoSuchOriginRequestPolicy(parsed_error.message)),"PreconditionFailed" => return RusotoError::Service(UpdateDistributionError::PreconditionFailed(parsed_error.message)),"TooManyCacheBehaviors" => return RusotoError::Service(UpdateDistributionError::TooManyCacheBehaviors(parsed_error.message)),"TooManyCertificates" => return RusotoError::Service(UpdateDistributionError::TooManyCertificates(parsed_error.message)),"TooManyCookieNamesInWhiteList" => return RusotoError::Service(UpdateDistributionError::TooManyCookieNamesInWhiteList(parsed_error.message)),"TooManyDistributionCNAMEs" => return RusotoError::Service(UpdateDistributionError::TooManyDistributionCNAMEs(parsed_error.message)),"TooManyDistributionsAssociatedToCachePolicy" => return RusotoError::Service(UpdateDistributionError::TooManyDistributionsAssociatedToCachePolicy(parsed_error.message)),"TooManyDistributionsAssociatedToFieldLevelEncryptionConfig" => return RusotoError::Service(UpdateDistributionError::TooManyDistributionsAssociatedToFieldLevelEncryptionConfig(parsed_error.message)),"TooManyDistributionsAssociatedToKeyGroup" => return RusotoError::Service(UpdateDistributionError::TooManyDistributionsAssociatedToKeyGroup(parsed_error.message)),"TooManyDistributionsAssociatedToOriginRequestPolicy" => return RusotoError::Service(UpdateDistributionError::TooManyDistributionsAssociatedToOriginRequestPolicy(parsed_error.message)),"TooManyDistributionsWithLambdaAssociations" => return RusotoError::Service(UpdateDistributionError::TooManyDistributionsWithLambdaAssociations(parsed_error.message)),"TooManyDistributionsWithSingleFunctionARN" => return RusotoError::Service(UpdateDistributionError::TooManyDistributionsWithSingleFunctionARN(parsed_error.message)),"TooManyHeadersInForwardedValues" => return RusotoError::Service(UpdateDistributionError::TooManyHeadersInForwardedValues(parsed_error.message)),"TooManyKeyGroupsAssociatedToDistribution" => return RusotoError::Service(UpdateDistributionError::TooManyKeyGroupsAssociatedToDistribution(parsed_error.message)),"TooManyLambdaFunctionAssociations" => return RusotoError::Service(UpdateDistributionError::TooManyLambdaFunctionAssociations(parsed_error.message)),"TooManyOriginCustomHeaders" => return RusotoError::Service(UpdateDistributionError::TooManyOriginCustomHeaders(parsed_error.message)),"TooManyOriginGroupsPerDistribution" => return RusotoError::Service(UpdateDistributionError::TooManyOriginGroupsPerDistribution(parsed_error.message)),"TooManyOrigins" => return RusotoError::Service(UpdateDistributionError::TooManyOrigins(parsed_error.message)),"TooManyQueryStringParameters" => return RusotoError::Service(UpdateDistributionError::TooManyQueryStringParameters(parsed_error.message)),"TooManyTrustedSigners" => return RusotoError::Service(UpdateDistributionError::TooManyTrustedSigners(parsed_error.message)),"TrustedKeyGroupDoesNotExist" => return RusotoError::Service(UpdateDistributionError::TrustedKeyGroupDoesNotExist(parsed_error.message)),"TrustedSignerDoesNotExist" => return RusotoError::Service(UpdateDistributionError::TrustedSignerDoesNotExist(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::AccessDenied),"CNAMEAlreadyExists" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::CNAMEAlreadyExists),"IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::IllegalFieldLevelEncryptionConfigAssociationWithCacheBehavior),"IllegalUpdate" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::IllegalUpdate),"InconsistentQuantities" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::InconsistentQuantities),"InvalidArgument" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::InvalidArgument),"InvalidDefaultRootObject" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::InvalidDefaultRootObject),"InvalidErrorCode" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::InvalidErrorCode),"InvalidForwardCookies" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::InvalidForwardCookies),"InvalidGeoRestrictionParameter" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::InvalidGeoRestrictionParameter),"InvalidHeadersForS3Origin" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::InvalidHeadersForS3Origin),"InvalidIfMatchVersion" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::InvalidIfMatchVersion),"InvalidLambdaFunctionAssociation" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::InvalidLambdaFunctionAssociation),"InvalidLocationCode" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::InvalidLocationCode),"InvalidMinimumProtocolVersion" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::InvalidMinimumProtocolVersion),"InvalidOriginAccessIdentity" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::InvalidOriginAccessIdentity),"InvalidOriginKeepaliveTimeout" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::InvalidOriginKeepaliveTimeout),"InvalidOriginReadTimeout" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::InvalidOriginReadTimeout),"InvalidQueryStringParameters" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::InvalidQueryStringParameters),"InvalidRelativePath" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::InvalidRelativePath),"InvalidRequiredProtocol" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::InvalidRequiredProtocol),"InvalidResponseCode" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::InvalidResponseCode),"InvalidTTLOrder" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::InvalidTTLOrder),"InvalidViewerCertificate" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::InvalidViewerCertificate),"InvalidWebACLId" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::InvalidWebACLId),"MissingBody" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::MissingBody),"NoSuchCachePolicy" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::NoSuchCachePolicy),"NoSuchDistribution" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::NoSuchDistribution),"NoSuchFieldLevelEncryptionConfig" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::NoSuchFieldLevelEncryptionConfig),"NoSuchOrigin" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::NoSuchOrigin),"NoSuchOriginRequestPolicy" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::NoSuchOriginRequestPolicy),"PreconditionFailed" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::PreconditionFailed),"TooManyCacheBehaviors" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::TooManyCacheBehaviors),"TooManyCertificates" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::TooManyCertificates),"TooManyCookieNamesInWhiteList" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::TooManyCookieNamesInWhiteList),"TooManyDistributionCNAMEs" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::TooManyDistributionCNAMEs),"TooManyDistributionsAssociatedToCachePolicy" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::TooManyDistributionsAssociatedToCachePolicy),"TooManyDistributionsAssociatedToFieldLevelEncryptionConfig" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::TooManyDistributionsAssociatedToFieldLevelEncryptionConfig),"TooManyDistributionsAssociatedToKeyGroup" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::TooManyDistributionsAssociatedToKeyGroup),"TooManyDistributionsAssociatedToOriginRequestPolicy" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::TooManyDistributionsAssociatedToOriginRequestPolicy),"TooManyDistributionsWithLambdaAssociations" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::TooManyDistributionsWithLambdaAssociations),"TooManyDistributionsWithSingleFunctionARN" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::TooManyDistributionsWithSingleFunctionARN),"TooManyHeadersInForwardedValues" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::TooManyHeadersInForwardedValues),"TooManyKeyGroupsAssociatedToDistribution" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::TooManyKeyGroupsAssociatedToDistribution),"TooManyLambdaFunctionAssociations" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::TooManyLambdaFunctionAssociations),"TooManyOriginCustomHeaders" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::TooManyOriginCustomHeaders),"TooManyOriginGroupsPerDistribution" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::TooManyOriginGroupsPerDistribution),"TooManyOrigins" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::TooManyOrigins),"TooManyQueryStringParameters" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::TooManyQueryStringParameters),"TooManyTrustedSigners" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::TooManyTrustedSigners),"TrustedKeyGroupDoesNotExist" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::TrustedKeyGroupDoesNotExist),"TrustedSignerDoesNotExist" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateDistributionError::TrustedSignerDoesNotExist),_ => {}
                                 }
             }
         }
@@ -16285,7 +16666,7 @@ impl UpdateFieldLevelEncryptionConfigError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::AccessDenied(parsed_error.message)),"IllegalUpdate" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::IllegalUpdate(parsed_error.message)),"InconsistentQuantities" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::InvalidArgument(parsed_error.message)),"InvalidIfMatchVersion" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::InvalidIfMatchVersion(parsed_error.message)),"NoSuchFieldLevelEncryptionConfig" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionConfig(parsed_error.message)),"NoSuchFieldLevelEncryptionProfile" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionProfile(parsed_error.message)),"PreconditionFailed" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::PreconditionFailed(parsed_error.message)),"QueryArgProfileEmpty" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::QueryArgProfileEmpty(parsed_error.message)),"TooManyFieldLevelEncryptionContentTypeProfiles" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionContentTypeProfiles(parsed_error.message)),"TooManyFieldLevelEncryptionQueryArgProfiles" => return RusotoError::Service(UpdateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionQueryArgProfiles(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateFieldLevelEncryptionConfigError::AccessDenied),"IllegalUpdate" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateFieldLevelEncryptionConfigError::IllegalUpdate),"InconsistentQuantities" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateFieldLevelEncryptionConfigError::InconsistentQuantities),"InvalidArgument" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateFieldLevelEncryptionConfigError::InvalidArgument),"InvalidIfMatchVersion" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateFieldLevelEncryptionConfigError::InvalidIfMatchVersion),"NoSuchFieldLevelEncryptionConfig" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionConfig),"NoSuchFieldLevelEncryptionProfile" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateFieldLevelEncryptionConfigError::NoSuchFieldLevelEncryptionProfile),"PreconditionFailed" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateFieldLevelEncryptionConfigError::PreconditionFailed),"QueryArgProfileEmpty" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateFieldLevelEncryptionConfigError::QueryArgProfileEmpty),"TooManyFieldLevelEncryptionContentTypeProfiles" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionContentTypeProfiles),"TooManyFieldLevelEncryptionQueryArgProfiles" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateFieldLevelEncryptionConfigError::TooManyFieldLevelEncryptionQueryArgProfiles),_ => {}
                                 }
             }
         }
@@ -16358,7 +16739,7 @@ impl UpdateFieldLevelEncryptionProfileError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AccessDenied" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::AccessDenied(parsed_error.message)),"FieldLevelEncryptionProfileAlreadyExists" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileAlreadyExists(parsed_error.message)),"FieldLevelEncryptionProfileSizeExceeded" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileSizeExceeded(parsed_error.message)),"IllegalUpdate" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::IllegalUpdate(parsed_error.message)),"InconsistentQuantities" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::InconsistentQuantities(parsed_error.message)),"InvalidArgument" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::InvalidArgument(parsed_error.message)),"InvalidIfMatchVersion" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::InvalidIfMatchVersion(parsed_error.message)),"NoSuchFieldLevelEncryptionProfile" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::NoSuchFieldLevelEncryptionProfile(parsed_error.message)),"NoSuchPublicKey" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::NoSuchPublicKey(parsed_error.message)),"PreconditionFailed" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::PreconditionFailed(parsed_error.message)),"TooManyFieldLevelEncryptionEncryptionEntities" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionEncryptionEntities(parsed_error.message)),"TooManyFieldLevelEncryptionFieldPatterns" => return RusotoError::Service(UpdateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionFieldPatterns(parsed_error.message)),_ => {}
+                                    "AccessDenied" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateFieldLevelEncryptionProfileError::AccessDenied),"FieldLevelEncryptionProfileAlreadyExists" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileAlreadyExists),"FieldLevelEncryptionProfileSizeExceeded" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateFieldLevelEncryptionProfileError::FieldLevelEncryptionProfileSizeExceeded),"IllegalUpdate" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateFieldLevelEncryptionProfileError::IllegalUpdate),"InconsistentQuantities" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateFieldLevelEncryptionProfileError::InconsistentQuantities),"InvalidArgument" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateFieldLevelEncryptionProfileError::InvalidArgument),"InvalidIfMatchVersion" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateFieldLevelEncryptionProfileError::InvalidIfMatchVersion),"NoSuchFieldLevelEncryptionProfile" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateFieldLevelEncryptionProfileError::NoSuchFieldLevelEncryptionProfile),"NoSuchPublicKey" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateFieldLevelEncryptionProfileError::NoSuchPublicKey),"PreconditionFailed" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateFieldLevelEncryptionProfileError::PreconditionFailed),"TooManyFieldLevelEncryptionEncryptionEntities" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionEncryptionEntities),"TooManyFieldLevelEncryptionFieldPatterns" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateFieldLevelEncryptionProfileError::TooManyFieldLevelEncryptionFieldPatterns),_ => {}
                                 }
             }
         }
@@ -16419,33 +16800,51 @@ impl UpdateKeyGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidArgument" => {
-                        return RusotoError::Service(UpdateKeyGroupError::InvalidArgument(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateKeyGroupError::InvalidArgument,
+                        )
                     }
                     "InvalidIfMatchVersion" => {
-                        return RusotoError::Service(UpdateKeyGroupError::InvalidIfMatchVersion(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateKeyGroupError::InvalidIfMatchVersion,
+                        )
                     }
                     "KeyGroupAlreadyExists" => {
-                        return RusotoError::Service(UpdateKeyGroupError::KeyGroupAlreadyExists(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateKeyGroupError::KeyGroupAlreadyExists,
+                        )
                     }
                     "NoSuchResource" => {
-                        return RusotoError::Service(UpdateKeyGroupError::NoSuchResource(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateKeyGroupError::NoSuchResource,
+                        )
                     }
                     "PreconditionFailed" => {
-                        return RusotoError::Service(UpdateKeyGroupError::PreconditionFailed(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateKeyGroupError::PreconditionFailed,
+                        )
                     }
                     "TooManyPublicKeysInKeyGroup" => {
-                        return RusotoError::Service(
-                            UpdateKeyGroupError::TooManyPublicKeysInKeyGroup(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateKeyGroupError::TooManyPublicKeysInKeyGroup,
                         )
                     }
                     _ => {}
@@ -16513,74 +16912,36 @@ impl UpdateOriginRequestPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(UpdateOriginRequestPolicyError::AccessDenied(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateOriginRequestPolicyError::AccessDenied)
                     }
                     "IllegalUpdate" => {
-                        return RusotoError::Service(UpdateOriginRequestPolicyError::IllegalUpdate(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateOriginRequestPolicyError::IllegalUpdate)
                     }
                     "InconsistentQuantities" => {
-                        return RusotoError::Service(
-                            UpdateOriginRequestPolicyError::InconsistentQuantities(
-                                parsed_error.message,
-                            ),
-                        )
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateOriginRequestPolicyError::InconsistentQuantities)
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(
-                            UpdateOriginRequestPolicyError::InvalidArgument(parsed_error.message),
-                        )
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateOriginRequestPolicyError::InvalidArgument)
                     }
                     "InvalidIfMatchVersion" => {
-                        return RusotoError::Service(
-                            UpdateOriginRequestPolicyError::InvalidIfMatchVersion(
-                                parsed_error.message,
-                            ),
-                        )
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateOriginRequestPolicyError::InvalidIfMatchVersion)
                     }
                     "NoSuchOriginRequestPolicy" => {
-                        return RusotoError::Service(
-                            UpdateOriginRequestPolicyError::NoSuchOriginRequestPolicy(
-                                parsed_error.message,
-                            ),
-                        )
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateOriginRequestPolicyError::NoSuchOriginRequestPolicy)
                     }
                     "OriginRequestPolicyAlreadyExists" => {
-                        return RusotoError::Service(
-                            UpdateOriginRequestPolicyError::OriginRequestPolicyAlreadyExists(
-                                parsed_error.message,
-                            ),
-                        )
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateOriginRequestPolicyError::OriginRequestPolicyAlreadyExists)
                     }
                     "PreconditionFailed" => {
-                        return RusotoError::Service(
-                            UpdateOriginRequestPolicyError::PreconditionFailed(
-                                parsed_error.message,
-                            ),
-                        )
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateOriginRequestPolicyError::PreconditionFailed)
                     }
                     "TooManyCookiesInOriginRequestPolicy" => {
-                        return RusotoError::Service(
-                            UpdateOriginRequestPolicyError::TooManyCookiesInOriginRequestPolicy(
-                                parsed_error.message,
-                            ),
-                        )
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateOriginRequestPolicyError::TooManyCookiesInOriginRequestPolicy)
                     }
                     "TooManyHeadersInOriginRequestPolicy" => {
-                        return RusotoError::Service(
-                            UpdateOriginRequestPolicyError::TooManyHeadersInOriginRequestPolicy(
-                                parsed_error.message,
-                            ),
-                        )
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateOriginRequestPolicyError::TooManyHeadersInOriginRequestPolicy)
                     }
-                    "TooManyQueryStringsInOriginRequestPolicy" => return RusotoError::Service(
-                        UpdateOriginRequestPolicyError::TooManyQueryStringsInOriginRequestPolicy(
-                            parsed_error.message,
-                        ),
-                    ),
+                    "TooManyQueryStringsInOriginRequestPolicy" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, UpdateOriginRequestPolicyError::TooManyQueryStringsInOriginRequestPolicy),
                     _ => {}
                 }
             }
@@ -16657,41 +17018,60 @@ impl UpdatePublicKeyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(UpdatePublicKeyError::AccessDenied(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdatePublicKeyError::AccessDenied,
+                        )
                     }
                     "CannotChangeImmutablePublicKeyFields" => {
-                        return RusotoError::Service(
-                            UpdatePublicKeyError::CannotChangeImmutablePublicKeyFields(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdatePublicKeyError::CannotChangeImmutablePublicKeyFields,
                         )
                     }
                     "IllegalUpdate" => {
-                        return RusotoError::Service(UpdatePublicKeyError::IllegalUpdate(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdatePublicKeyError::IllegalUpdate,
+                        )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(UpdatePublicKeyError::InvalidArgument(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdatePublicKeyError::InvalidArgument,
+                        )
                     }
                     "InvalidIfMatchVersion" => {
-                        return RusotoError::Service(UpdatePublicKeyError::InvalidIfMatchVersion(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdatePublicKeyError::InvalidIfMatchVersion,
+                        )
                     }
                     "NoSuchPublicKey" => {
-                        return RusotoError::Service(UpdatePublicKeyError::NoSuchPublicKey(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdatePublicKeyError::NoSuchPublicKey,
+                        )
                     }
                     "PreconditionFailed" => {
-                        return RusotoError::Service(UpdatePublicKeyError::PreconditionFailed(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdatePublicKeyError::PreconditionFailed,
+                        )
                     }
                     _ => {}
                 }
@@ -16745,20 +17125,27 @@ impl UpdateRealtimeLogConfigError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(UpdateRealtimeLogConfigError::AccessDenied(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateRealtimeLogConfigError::AccessDenied,
+                        )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(UpdateRealtimeLogConfigError::InvalidArgument(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateRealtimeLogConfigError::InvalidArgument,
+                        )
                     }
                     "NoSuchRealtimeLogConfig" => {
-                        return RusotoError::Service(
-                            UpdateRealtimeLogConfigError::NoSuchRealtimeLogConfig(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateRealtimeLogConfigError::NoSuchRealtimeLogConfig,
                         )
                     }
                     _ => {}
@@ -16831,86 +17218,107 @@ impl UpdateStreamingDistributionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessDenied" => {
-                        return RusotoError::Service(
-                            UpdateStreamingDistributionError::AccessDenied(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateStreamingDistributionError::AccessDenied,
                         )
                     }
                     "CNAMEAlreadyExists" => {
-                        return RusotoError::Service(
-                            UpdateStreamingDistributionError::CNAMEAlreadyExists(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateStreamingDistributionError::CNAMEAlreadyExists,
                         )
                     }
                     "IllegalUpdate" => {
-                        return RusotoError::Service(
-                            UpdateStreamingDistributionError::IllegalUpdate(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateStreamingDistributionError::IllegalUpdate,
                         )
                     }
                     "InconsistentQuantities" => {
-                        return RusotoError::Service(
-                            UpdateStreamingDistributionError::InconsistentQuantities(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateStreamingDistributionError::InconsistentQuantities,
                         )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(
-                            UpdateStreamingDistributionError::InvalidArgument(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateStreamingDistributionError::InvalidArgument,
                         )
                     }
                     "InvalidIfMatchVersion" => {
-                        return RusotoError::Service(
-                            UpdateStreamingDistributionError::InvalidIfMatchVersion(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateStreamingDistributionError::InvalidIfMatchVersion,
                         )
                     }
                     "InvalidOriginAccessIdentity" => {
-                        return RusotoError::Service(
-                            UpdateStreamingDistributionError::InvalidOriginAccessIdentity(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateStreamingDistributionError::InvalidOriginAccessIdentity,
                         )
                     }
                     "MissingBody" => {
-                        return RusotoError::Service(UpdateStreamingDistributionError::MissingBody(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateStreamingDistributionError::MissingBody,
+                        )
                     }
                     "NoSuchStreamingDistribution" => {
-                        return RusotoError::Service(
-                            UpdateStreamingDistributionError::NoSuchStreamingDistribution(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateStreamingDistributionError::NoSuchStreamingDistribution,
                         )
                     }
                     "PreconditionFailed" => {
-                        return RusotoError::Service(
-                            UpdateStreamingDistributionError::PreconditionFailed(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateStreamingDistributionError::PreconditionFailed,
                         )
                     }
                     "TooManyStreamingDistributionCNAMEs" => {
-                        return RusotoError::Service(
-                            UpdateStreamingDistributionError::TooManyStreamingDistributionCNAMEs(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateStreamingDistributionError::TooManyStreamingDistributionCNAMEs,
                         )
                     }
                     "TooManyTrustedSigners" => {
-                        return RusotoError::Service(
-                            UpdateStreamingDistributionError::TooManyTrustedSigners(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateStreamingDistributionError::TooManyTrustedSigners,
                         )
                     }
                     "TrustedSignerDoesNotExist" => {
-                        return RusotoError::Service(
-                            UpdateStreamingDistributionError::TrustedSignerDoesNotExist(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateStreamingDistributionError::TrustedSignerDoesNotExist,
                         )
                     }
                     _ => {}
@@ -17484,11 +17892,12 @@ pub struct CloudFrontClient {
 impl CloudFrontClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> CloudFrontClient {
         CloudFrontClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "CloudFront"),
         }
     }
 
@@ -17503,12 +17912,32 @@ impl CloudFrontClient {
     {
         CloudFrontClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "CloudFront"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CloudFrontClient {
-        CloudFrontClient { client, region }
+        CloudFrontClient {
+            client,
+            region: region_for_service(region, "CloudFront"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> CloudFrontClient {
+        CloudFrontClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("CloudFront"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CloudFrontClient {
+        CloudFrontClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -17523,6 +17952,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2020-05-31/cache-policy";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateCachePolicy");
 
         let mut writer = EventWriter::new(Vec::new());
         CachePolicyConfigSerializer::serialize(
@@ -17559,6 +17989,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2020-05-31/origin-access-identity/cloudfront";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateCloudFrontOriginAccessIdentity");
 
         let mut writer = EventWriter::new(Vec::new());
         CloudFrontOriginAccessIdentityConfigSerializer::serialize(
@@ -17598,6 +18029,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2020-05-31/distribution";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateDistribution");
 
         let mut writer = EventWriter::new(Vec::new());
         DistributionConfigSerializer::serialize(
@@ -17632,6 +18064,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2020-05-31/distribution";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateDistributionWithTags");
 
         let mut params = Params::new();
         params.put_key("WithTags");
@@ -17671,6 +18104,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2020-05-31/field-level-encryption";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateFieldLevelEncryptionConfig");
 
         let mut writer = EventWriter::new(Vec::new());
         FieldLevelEncryptionConfigSerializer::serialize(
@@ -17710,6 +18144,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2020-05-31/field-level-encryption-profile";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateFieldLevelEncryptionProfile");
 
         let mut writer = EventWriter::new(Vec::new());
         FieldLevelEncryptionProfileConfigSerializer::serialize(
@@ -17749,6 +18184,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateInvalidation");
 
         let mut writer = EventWriter::new(Vec::new());
         InvalidationBatchSerializer::serialize(
@@ -17781,6 +18217,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2020-05-31/key-group";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateKeyGroup");
 
         let mut writer = EventWriter::new(Vec::new());
         KeyGroupConfigSerializer::serialize(&mut writer, "KeyGroupConfig", &input.key_group_config);
@@ -17814,6 +18251,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateMonitoringSubscription");
 
         let mut writer = EventWriter::new(Vec::new());
         MonitoringSubscriptionSerializer::serialize(
@@ -17846,6 +18284,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2020-05-31/origin-request-policy";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateOriginRequestPolicy");
 
         let mut writer = EventWriter::new(Vec::new());
         OriginRequestPolicyConfigSerializer::serialize(
@@ -17879,6 +18318,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2020-05-31/public-key";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreatePublicKey");
 
         let mut writer = EventWriter::new(Vec::new());
         PublicKeyConfigSerializer::serialize(
@@ -17912,6 +18352,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2020-05-31/realtime-log-config";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateRealtimeLogConfig");
 
         let mut writer = EventWriter::new(Vec::new());
         CreateRealtimeLogConfigRequestSerializer::serialize(
@@ -17946,6 +18387,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2020-05-31/streaming-distribution";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateStreamingDistribution");
 
         let mut writer = EventWriter::new(Vec::new());
         StreamingDistributionConfigSerializer::serialize(
@@ -17982,6 +18424,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2020-05-31/streaming-distribution";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("CreateStreamingDistributionWithTags");
 
         let mut params = Params::new();
         params.put_key("WithTags");
@@ -18024,6 +18467,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2020-05-31/cache-policy/{id}", id = input.id);
 
         let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request.set_operation("DeleteCachePolicy");
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -18047,6 +18491,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request.set_operation("DeleteCloudFrontOriginAccessIdentity");
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -18070,6 +18515,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2020-05-31/distribution/{id}", id = input.id);
 
         let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request.set_operation("DeleteDistribution");
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -18090,6 +18536,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2020-05-31/field-level-encryption/{id}", id = input.id);
 
         let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request.set_operation("DeleteFieldLevelEncryptionConfig");
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -18116,6 +18563,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request.set_operation("DeleteFieldLevelEncryptionProfile");
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -18139,6 +18587,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2020-05-31/key-group/{id}", id = input.id);
 
         let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request.set_operation("DeleteKeyGroup");
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -18163,6 +18612,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request.set_operation("DeleteMonitoringSubscription");
 
         let mut response = self
             .sign_and_dispatch(request, DeleteMonitoringSubscriptionError::from_response)
@@ -18183,6 +18633,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2020-05-31/origin-request-policy/{id}", id = input.id);
 
         let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request.set_operation("DeleteOriginRequestPolicy");
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -18203,6 +18654,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2020-05-31/public-key/{id}", id = input.id);
 
         let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request.set_operation("DeletePublicKey");
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -18223,6 +18675,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2020-05-31/delete-realtime-log-config/";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("DeleteRealtimeLogConfig");
 
         let mut writer = EventWriter::new(Vec::new());
         DeleteRealtimeLogConfigRequestSerializer::serialize(
@@ -18250,6 +18703,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2020-05-31/streaming-distribution/{id}", id = input.id);
 
         let mut request = SignedRequest::new("DELETE", "cloudfront", &self.region, &request_uri);
+        request.set_operation("DeleteStreamingDistribution");
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -18270,6 +18724,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2020-05-31/cache-policy/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetCachePolicy");
 
         let mut response = self
             .sign_and_dispatch(request, GetCachePolicyError::from_response)
@@ -18294,6 +18749,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2020-05-31/cache-policy/{id}/config", id = input.id);
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetCachePolicyConfig");
 
         let mut response = self
             .sign_and_dispatch(request, GetCachePolicyConfigError::from_response)
@@ -18324,6 +18780,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetCloudFrontOriginAccessIdentity");
 
         let mut response = self
             .sign_and_dispatch(
@@ -18357,6 +18814,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetCloudFrontOriginAccessIdentityConfig");
 
         let mut response = self
             .sign_and_dispatch(
@@ -18387,6 +18845,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2020-05-31/distribution/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetDistribution");
 
         let mut response = self
             .sign_and_dispatch(request, GetDistributionError::from_response)
@@ -18411,6 +18870,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2020-05-31/distribution/{id}/config", id = input.id);
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetDistributionConfig");
 
         let mut response = self
             .sign_and_dispatch(request, GetDistributionConfigError::from_response)
@@ -18435,6 +18895,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2020-05-31/field-level-encryption/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetFieldLevelEncryption");
 
         let mut response = self
             .sign_and_dispatch(request, GetFieldLevelEncryptionError::from_response)
@@ -18463,6 +18924,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetFieldLevelEncryptionConfig");
 
         let mut response = self
             .sign_and_dispatch(request, GetFieldLevelEncryptionConfigError::from_response)
@@ -18493,6 +18955,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetFieldLevelEncryptionProfile");
 
         let mut response = self
             .sign_and_dispatch(request, GetFieldLevelEncryptionProfileError::from_response)
@@ -18523,6 +18986,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetFieldLevelEncryptionProfileConfig");
 
         let mut response = self
             .sign_and_dispatch(
@@ -18557,6 +19021,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetInvalidation");
 
         let mut response = self
             .sign_and_dispatch(request, GetInvalidationError::from_response)
@@ -18581,6 +19046,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2020-05-31/key-group/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetKeyGroup");
 
         let mut response = self
             .sign_and_dispatch(request, GetKeyGroupError::from_response)
@@ -18605,6 +19071,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2020-05-31/key-group/{id}/config", id = input.id);
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetKeyGroupConfig");
 
         let mut response = self
             .sign_and_dispatch(request, GetKeyGroupConfigError::from_response)
@@ -18632,6 +19099,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetMonitoringSubscription");
 
         let mut response = self
             .sign_and_dispatch(request, GetMonitoringSubscriptionError::from_response)
@@ -18656,6 +19124,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2020-05-31/origin-request-policy/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetOriginRequestPolicy");
 
         let mut response = self
             .sign_and_dispatch(request, GetOriginRequestPolicyError::from_response)
@@ -18684,6 +19153,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetOriginRequestPolicyConfig");
 
         let mut response = self
             .sign_and_dispatch(request, GetOriginRequestPolicyConfigError::from_response)
@@ -18708,6 +19178,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2020-05-31/public-key/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetPublicKey");
 
         let mut response = self
             .sign_and_dispatch(request, GetPublicKeyError::from_response)
@@ -18732,6 +19203,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2020-05-31/public-key/{id}/config", id = input.id);
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetPublicKeyConfig");
 
         let mut response = self
             .sign_and_dispatch(request, GetPublicKeyConfigError::from_response)
@@ -18756,6 +19228,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2020-05-31/get-realtime-log-config/";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetRealtimeLogConfig");
 
         let mut writer = EventWriter::new(Vec::new());
         GetRealtimeLogConfigRequestSerializer::serialize(
@@ -18789,6 +19262,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2020-05-31/streaming-distribution/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetStreamingDistribution");
 
         let mut response = self
             .sign_and_dispatch(request, GetStreamingDistributionError::from_response)
@@ -18819,6 +19293,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("GetStreamingDistributionConfig");
 
         let mut response = self
             .sign_and_dispatch(request, GetStreamingDistributionConfigError::from_response)
@@ -18843,6 +19318,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2020-05-31/cache-policy";
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListCachePolicies");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -18882,6 +19358,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2020-05-31/origin-access-identity/cloudfront";
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListCloudFrontOriginAccessIdentities");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -18921,6 +19398,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2020-05-31/distribution";
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListDistributions");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -18960,6 +19438,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListDistributionsByCachePolicyId");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -19000,6 +19479,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListDistributionsByKeyGroup");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -19039,6 +19519,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListDistributionsByOriginRequestPolicyId");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -19081,6 +19562,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2020-05-31/distributionsByRealtimeLogConfig/";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListDistributionsByRealtimeLogConfig");
 
         let mut writer = EventWriter::new(Vec::new());
         ListDistributionsByRealtimeLogConfigRequestSerializer::serialize(
@@ -19124,6 +19606,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListDistributionsByWebACLId");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -19160,6 +19643,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2020-05-31/field-level-encryption";
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListFieldLevelEncryptionConfigs");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -19196,6 +19680,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2020-05-31/field-level-encryption-profile";
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListFieldLevelEncryptionProfiles");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -19235,6 +19720,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListInvalidations");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -19268,6 +19754,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2020-05-31/key-group";
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListKeyGroups");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -19301,6 +19788,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2020-05-31/origin-request-policy";
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListOriginRequestPolicies");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -19337,6 +19825,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2020-05-31/public-key";
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListPublicKeys");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -19370,6 +19859,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2020-05-31/realtime-log-config";
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListRealtimeLogConfigs");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -19404,6 +19894,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2020-05-31/streaming-distribution";
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListStreamingDistributions");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -19437,6 +19928,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2020-05-31/tagging";
 
         let mut request = SignedRequest::new("GET", "cloudfront", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
 
         let mut params = Params::new();
         params.put("Resource", &input.resource);
@@ -19465,6 +19957,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2020-05-31/tagging";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("TagResource");
 
         let mut params = Params::new();
         params.put("Resource", &input.resource);
@@ -19491,6 +19984,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2020-05-31/tagging";
 
         let mut request = SignedRequest::new("POST", "cloudfront", &self.region, &request_uri);
+        request.set_operation("UntagResource");
 
         let mut params = Params::new();
         params.put("Resource", &input.resource);
@@ -19517,6 +20011,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2020-05-31/cache-policy/{id}", id = input.id);
 
         let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        request.set_operation("UpdateCachePolicy");
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -19557,6 +20052,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        request.set_operation("UpdateCloudFrontOriginAccessIdentity");
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -19597,6 +20093,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2020-05-31/distribution/{id}/config", id = input.id);
 
         let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        request.set_operation("UpdateDistribution");
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -19637,6 +20134,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        request.set_operation("UpdateFieldLevelEncryptionConfig");
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -19680,6 +20178,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        request.set_operation("UpdateFieldLevelEncryptionProfile");
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -19717,6 +20216,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2020-05-31/key-group/{id}", id = input.id);
 
         let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        request.set_operation("UpdateKeyGroup");
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -19747,6 +20247,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2020-05-31/origin-request-policy/{id}", id = input.id);
 
         let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        request.set_operation("UpdateOriginRequestPolicy");
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -19781,6 +20282,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = format!("/2020-05-31/public-key/{id}/config", id = input.id);
 
         let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        request.set_operation("UpdatePublicKey");
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -19815,6 +20317,7 @@ impl CloudFront for CloudFrontClient {
         let request_uri = "/2020-05-31/realtime-log-config/";
 
         let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        request.set_operation("UpdateRealtimeLogConfig");
 
         let mut writer = EventWriter::new(Vec::new());
         UpdateRealtimeLogConfigRequestSerializer::serialize(
@@ -19852,6 +20355,7 @@ impl CloudFront for CloudFrontClient {
         );
 
         let mut request = SignedRequest::new("PUT", "cloudfront", &self.region, &request_uri);
+        request.set_operation("UpdateStreamingDistribution");
 
         request.add_optional_header("If-Match", input.if_match.as_ref());
 
@@ -19878,6 +20382,807 @@ impl CloudFront for CloudFrontClient {
     }
 }
 
+/// A blocking client for the CloudFront API, running the calls of a `CloudFrontClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `CloudFrontClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CloudFrontBlockingClient {
+    client: CloudFrontClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CloudFrontBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> CloudFrontBlockingClient {
+        CloudFrontBlockingClient::from_client(CloudFrontClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CloudFrontBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CloudFrontBlockingClient::from_client(CloudFrontClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> CloudFrontBlockingClient {
+        CloudFrontBlockingClient::from_client(CloudFrontClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> CloudFrontBlockingClient {
+        CloudFrontBlockingClient::from_client(CloudFrontClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: CloudFrontClient) -> CloudFrontBlockingClient {
+        CloudFrontBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> CloudFrontBlockingClient {
+        CloudFrontBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CloudFrontBlockingClient {
+        CloudFrontBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`CloudFront::create_cache_policy`].
+    pub fn create_cache_policy(
+        &self,
+        input: CreateCachePolicyRequest,
+    ) -> Result<CreateCachePolicyResult, RusotoError<CreateCachePolicyError>> {
+        self.runtime
+            .block_on(self.client.create_cache_policy(input))
+    }
+
+    /// Blocking version of [`CloudFront::create_cloud_front_origin_access_identity`].
+    pub fn create_cloud_front_origin_access_identity(
+        &self,
+        input: CreateCloudFrontOriginAccessIdentityRequest,
+    ) -> Result<
+        CreateCloudFrontOriginAccessIdentityResult,
+        RusotoError<CreateCloudFrontOriginAccessIdentityError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_cloud_front_origin_access_identity(input))
+    }
+
+    /// Blocking version of [`CloudFront::create_distribution`].
+    pub fn create_distribution(
+        &self,
+        input: CreateDistributionRequest,
+    ) -> Result<CreateDistributionResult, RusotoError<CreateDistributionError>> {
+        self.runtime
+            .block_on(self.client.create_distribution(input))
+    }
+
+    /// Blocking version of [`CloudFront::create_distribution_with_tags`].
+    pub fn create_distribution_with_tags(
+        &self,
+        input: CreateDistributionWithTagsRequest,
+    ) -> Result<CreateDistributionWithTagsResult, RusotoError<CreateDistributionWithTagsError>>
+    {
+        self.runtime
+            .block_on(self.client.create_distribution_with_tags(input))
+    }
+
+    /// Blocking version of [`CloudFront::create_field_level_encryption_config`].
+    pub fn create_field_level_encryption_config(
+        &self,
+        input: CreateFieldLevelEncryptionConfigRequest,
+    ) -> Result<
+        CreateFieldLevelEncryptionConfigResult,
+        RusotoError<CreateFieldLevelEncryptionConfigError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_field_level_encryption_config(input))
+    }
+
+    /// Blocking version of [`CloudFront::create_field_level_encryption_profile`].
+    pub fn create_field_level_encryption_profile(
+        &self,
+        input: CreateFieldLevelEncryptionProfileRequest,
+    ) -> Result<
+        CreateFieldLevelEncryptionProfileResult,
+        RusotoError<CreateFieldLevelEncryptionProfileError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_field_level_encryption_profile(input))
+    }
+
+    /// Blocking version of [`CloudFront::create_invalidation`].
+    pub fn create_invalidation(
+        &self,
+        input: CreateInvalidationRequest,
+    ) -> Result<CreateInvalidationResult, RusotoError<CreateInvalidationError>> {
+        self.runtime
+            .block_on(self.client.create_invalidation(input))
+    }
+
+    /// Blocking version of [`CloudFront::create_key_group`].
+    pub fn create_key_group(
+        &self,
+        input: CreateKeyGroupRequest,
+    ) -> Result<CreateKeyGroupResult, RusotoError<CreateKeyGroupError>> {
+        self.runtime.block_on(self.client.create_key_group(input))
+    }
+
+    /// Blocking version of [`CloudFront::create_monitoring_subscription`].
+    pub fn create_monitoring_subscription(
+        &self,
+        input: CreateMonitoringSubscriptionRequest,
+    ) -> Result<CreateMonitoringSubscriptionResult, RusotoError<CreateMonitoringSubscriptionError>>
+    {
+        self.runtime
+            .block_on(self.client.create_monitoring_subscription(input))
+    }
+
+    /// Blocking version of [`CloudFront::create_origin_request_policy`].
+    pub fn create_origin_request_policy(
+        &self,
+        input: CreateOriginRequestPolicyRequest,
+    ) -> Result<CreateOriginRequestPolicyResult, RusotoError<CreateOriginRequestPolicyError>> {
+        self.runtime
+            .block_on(self.client.create_origin_request_policy(input))
+    }
+
+    /// Blocking version of [`CloudFront::create_public_key`].
+    pub fn create_public_key(
+        &self,
+        input: CreatePublicKeyRequest,
+    ) -> Result<CreatePublicKeyResult, RusotoError<CreatePublicKeyError>> {
+        self.runtime.block_on(self.client.create_public_key(input))
+    }
+
+    /// Blocking version of [`CloudFront::create_realtime_log_config`].
+    pub fn create_realtime_log_config(
+        &self,
+        input: CreateRealtimeLogConfigRequest,
+    ) -> Result<CreateRealtimeLogConfigResult, RusotoError<CreateRealtimeLogConfigError>> {
+        self.runtime
+            .block_on(self.client.create_realtime_log_config(input))
+    }
+
+    /// Blocking version of [`CloudFront::create_streaming_distribution`].
+    pub fn create_streaming_distribution(
+        &self,
+        input: CreateStreamingDistributionRequest,
+    ) -> Result<CreateStreamingDistributionResult, RusotoError<CreateStreamingDistributionError>>
+    {
+        self.runtime
+            .block_on(self.client.create_streaming_distribution(input))
+    }
+
+    /// Blocking version of [`CloudFront::create_streaming_distribution_with_tags`].
+    pub fn create_streaming_distribution_with_tags(
+        &self,
+        input: CreateStreamingDistributionWithTagsRequest,
+    ) -> Result<
+        CreateStreamingDistributionWithTagsResult,
+        RusotoError<CreateStreamingDistributionWithTagsError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_streaming_distribution_with_tags(input))
+    }
+
+    /// Blocking version of [`CloudFront::delete_cache_policy`].
+    pub fn delete_cache_policy(
+        &self,
+        input: DeleteCachePolicyRequest,
+    ) -> Result<(), RusotoError<DeleteCachePolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_cache_policy(input))
+    }
+
+    /// Blocking version of [`CloudFront::delete_cloud_front_origin_access_identity`].
+    pub fn delete_cloud_front_origin_access_identity(
+        &self,
+        input: DeleteCloudFrontOriginAccessIdentityRequest,
+    ) -> Result<(), RusotoError<DeleteCloudFrontOriginAccessIdentityError>> {
+        self.runtime
+            .block_on(self.client.delete_cloud_front_origin_access_identity(input))
+    }
+
+    /// Blocking version of [`CloudFront::delete_distribution`].
+    pub fn delete_distribution(
+        &self,
+        input: DeleteDistributionRequest,
+    ) -> Result<(), RusotoError<DeleteDistributionError>> {
+        self.runtime
+            .block_on(self.client.delete_distribution(input))
+    }
+
+    /// Blocking version of [`CloudFront::delete_field_level_encryption_config`].
+    pub fn delete_field_level_encryption_config(
+        &self,
+        input: DeleteFieldLevelEncryptionConfigRequest,
+    ) -> Result<(), RusotoError<DeleteFieldLevelEncryptionConfigError>> {
+        self.runtime
+            .block_on(self.client.delete_field_level_encryption_config(input))
+    }
+
+    /// Blocking version of [`CloudFront::delete_field_level_encryption_profile`].
+    pub fn delete_field_level_encryption_profile(
+        &self,
+        input: DeleteFieldLevelEncryptionProfileRequest,
+    ) -> Result<(), RusotoError<DeleteFieldLevelEncryptionProfileError>> {
+        self.runtime
+            .block_on(self.client.delete_field_level_encryption_profile(input))
+    }
+
+    /// Blocking version of [`CloudFront::delete_key_group`].
+    pub fn delete_key_group(
+        &self,
+        input: DeleteKeyGroupRequest,
+    ) -> Result<(), RusotoError<DeleteKeyGroupError>> {
+        self.runtime.block_on(self.client.delete_key_group(input))
+    }
+
+    /// Blocking version of [`CloudFront::delete_monitoring_subscription`].
+    pub fn delete_monitoring_subscription(
+        &self,
+        input: DeleteMonitoringSubscriptionRequest,
+    ) -> Result<DeleteMonitoringSubscriptionResult, RusotoError<DeleteMonitoringSubscriptionError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_monitoring_subscription(input))
+    }
+
+    /// Blocking version of [`CloudFront::delete_origin_request_policy`].
+    pub fn delete_origin_request_policy(
+        &self,
+        input: DeleteOriginRequestPolicyRequest,
+    ) -> Result<(), RusotoError<DeleteOriginRequestPolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_origin_request_policy(input))
+    }
+
+    /// Blocking version of [`CloudFront::delete_public_key`].
+    pub fn delete_public_key(
+        &self,
+        input: DeletePublicKeyRequest,
+    ) -> Result<(), RusotoError<DeletePublicKeyError>> {
+        self.runtime.block_on(self.client.delete_public_key(input))
+    }
+
+    /// Blocking version of [`CloudFront::delete_realtime_log_config`].
+    pub fn delete_realtime_log_config(
+        &self,
+        input: DeleteRealtimeLogConfigRequest,
+    ) -> Result<(), RusotoError<DeleteRealtimeLogConfigError>> {
+        self.runtime
+            .block_on(self.client.delete_realtime_log_config(input))
+    }
+
+    /// Blocking version of [`CloudFront::delete_streaming_distribution`].
+    pub fn delete_streaming_distribution(
+        &self,
+        input: DeleteStreamingDistributionRequest,
+    ) -> Result<(), RusotoError<DeleteStreamingDistributionError>> {
+        self.runtime
+            .block_on(self.client.delete_streaming_distribution(input))
+    }
+
+    /// Blocking version of [`CloudFront::get_cache_policy`].
+    pub fn get_cache_policy(
+        &self,
+        input: GetCachePolicyRequest,
+    ) -> Result<GetCachePolicyResult, RusotoError<GetCachePolicyError>> {
+        self.runtime.block_on(self.client.get_cache_policy(input))
+    }
+
+    /// Blocking version of [`CloudFront::get_cache_policy_config`].
+    pub fn get_cache_policy_config(
+        &self,
+        input: GetCachePolicyConfigRequest,
+    ) -> Result<GetCachePolicyConfigResult, RusotoError<GetCachePolicyConfigError>> {
+        self.runtime
+            .block_on(self.client.get_cache_policy_config(input))
+    }
+
+    /// Blocking version of [`CloudFront::get_cloud_front_origin_access_identity`].
+    pub fn get_cloud_front_origin_access_identity(
+        &self,
+        input: GetCloudFrontOriginAccessIdentityRequest,
+    ) -> Result<
+        GetCloudFrontOriginAccessIdentityResult,
+        RusotoError<GetCloudFrontOriginAccessIdentityError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_cloud_front_origin_access_identity(input))
+    }
+
+    /// Blocking version of [`CloudFront::get_cloud_front_origin_access_identity_config`].
+    pub fn get_cloud_front_origin_access_identity_config(
+        &self,
+        input: GetCloudFrontOriginAccessIdentityConfigRequest,
+    ) -> Result<
+        GetCloudFrontOriginAccessIdentityConfigResult,
+        RusotoError<GetCloudFrontOriginAccessIdentityConfigError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .get_cloud_front_origin_access_identity_config(input),
+        )
+    }
+
+    /// Blocking version of [`CloudFront::get_distribution`].
+    pub fn get_distribution(
+        &self,
+        input: GetDistributionRequest,
+    ) -> Result<GetDistributionResult, RusotoError<GetDistributionError>> {
+        self.runtime.block_on(self.client.get_distribution(input))
+    }
+
+    /// Blocking version of [`CloudFront::get_distribution_config`].
+    pub fn get_distribution_config(
+        &self,
+        input: GetDistributionConfigRequest,
+    ) -> Result<GetDistributionConfigResult, RusotoError<GetDistributionConfigError>> {
+        self.runtime
+            .block_on(self.client.get_distribution_config(input))
+    }
+
+    /// Blocking version of [`CloudFront::get_field_level_encryption`].
+    pub fn get_field_level_encryption(
+        &self,
+        input: GetFieldLevelEncryptionRequest,
+    ) -> Result<GetFieldLevelEncryptionResult, RusotoError<GetFieldLevelEncryptionError>> {
+        self.runtime
+            .block_on(self.client.get_field_level_encryption(input))
+    }
+
+    /// Blocking version of [`CloudFront::get_field_level_encryption_config`].
+    pub fn get_field_level_encryption_config(
+        &self,
+        input: GetFieldLevelEncryptionConfigRequest,
+    ) -> Result<GetFieldLevelEncryptionConfigResult, RusotoError<GetFieldLevelEncryptionConfigError>>
+    {
+        self.runtime
+            .block_on(self.client.get_field_level_encryption_config(input))
+    }
+
+    /// Blocking version of [`CloudFront::get_field_level_encryption_profile`].
+    pub fn get_field_level_encryption_profile(
+        &self,
+        input: GetFieldLevelEncryptionProfileRequest,
+    ) -> Result<
+        GetFieldLevelEncryptionProfileResult,
+        RusotoError<GetFieldLevelEncryptionProfileError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_field_level_encryption_profile(input))
+    }
+
+    /// Blocking version of [`CloudFront::get_field_level_encryption_profile_config`].
+    pub fn get_field_level_encryption_profile_config(
+        &self,
+        input: GetFieldLevelEncryptionProfileConfigRequest,
+    ) -> Result<
+        GetFieldLevelEncryptionProfileConfigResult,
+        RusotoError<GetFieldLevelEncryptionProfileConfigError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_field_level_encryption_profile_config(input))
+    }
+
+    /// Blocking version of [`CloudFront::get_invalidation`].
+    pub fn get_invalidation(
+        &self,
+        input: GetInvalidationRequest,
+    ) -> Result<GetInvalidationResult, RusotoError<GetInvalidationError>> {
+        self.runtime.block_on(self.client.get_invalidation(input))
+    }
+
+    /// Blocking version of [`CloudFront::get_key_group`].
+    pub fn get_key_group(
+        &self,
+        input: GetKeyGroupRequest,
+    ) -> Result<GetKeyGroupResult, RusotoError<GetKeyGroupError>> {
+        self.runtime.block_on(self.client.get_key_group(input))
+    }
+
+    /// Blocking version of [`CloudFront::get_key_group_config`].
+    pub fn get_key_group_config(
+        &self,
+        input: GetKeyGroupConfigRequest,
+    ) -> Result<GetKeyGroupConfigResult, RusotoError<GetKeyGroupConfigError>> {
+        self.runtime
+            .block_on(self.client.get_key_group_config(input))
+    }
+
+    /// Blocking version of [`CloudFront::get_monitoring_subscription`].
+    pub fn get_monitoring_subscription(
+        &self,
+        input: GetMonitoringSubscriptionRequest,
+    ) -> Result<GetMonitoringSubscriptionResult, RusotoError<GetMonitoringSubscriptionError>> {
+        self.runtime
+            .block_on(self.client.get_monitoring_subscription(input))
+    }
+
+    /// Blocking version of [`CloudFront::get_origin_request_policy`].
+    pub fn get_origin_request_policy(
+        &self,
+        input: GetOriginRequestPolicyRequest,
+    ) -> Result<GetOriginRequestPolicyResult, RusotoError<GetOriginRequestPolicyError>> {
+        self.runtime
+            .block_on(self.client.get_origin_request_policy(input))
+    }
+
+    /// Blocking version of [`CloudFront::get_origin_request_policy_config`].
+    pub fn get_origin_request_policy_config(
+        &self,
+        input: GetOriginRequestPolicyConfigRequest,
+    ) -> Result<GetOriginRequestPolicyConfigResult, RusotoError<GetOriginRequestPolicyConfigError>>
+    {
+        self.runtime
+            .block_on(self.client.get_origin_request_policy_config(input))
+    }
+
+    /// Blocking version of [`CloudFront::get_public_key`].
+    pub fn get_public_key(
+        &self,
+        input: GetPublicKeyRequest,
+    ) -> Result<GetPublicKeyResult, RusotoError<GetPublicKeyError>> {
+        self.runtime.block_on(self.client.get_public_key(input))
+    }
+
+    /// Blocking version of [`CloudFront::get_public_key_config`].
+    pub fn get_public_key_config(
+        &self,
+        input: GetPublicKeyConfigRequest,
+    ) -> Result<GetPublicKeyConfigResult, RusotoError<GetPublicKeyConfigError>> {
+        self.runtime
+            .block_on(self.client.get_public_key_config(input))
+    }
+
+    /// Blocking version of [`CloudFront::get_realtime_log_config`].
+    pub fn get_realtime_log_config(
+        &self,
+        input: GetRealtimeLogConfigRequest,
+    ) -> Result<GetRealtimeLogConfigResult, RusotoError<GetRealtimeLogConfigError>> {
+        self.runtime
+            .block_on(self.client.get_realtime_log_config(input))
+    }
+
+    /// Blocking version of [`CloudFront::get_streaming_distribution`].
+    pub fn get_streaming_distribution(
+        &self,
+        input: GetStreamingDistributionRequest,
+    ) -> Result<GetStreamingDistributionResult, RusotoError<GetStreamingDistributionError>> {
+        self.runtime
+            .block_on(self.client.get_streaming_distribution(input))
+    }
+
+    /// Blocking version of [`CloudFront::get_streaming_distribution_config`].
+    pub fn get_streaming_distribution_config(
+        &self,
+        input: GetStreamingDistributionConfigRequest,
+    ) -> Result<
+        GetStreamingDistributionConfigResult,
+        RusotoError<GetStreamingDistributionConfigError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_streaming_distribution_config(input))
+    }
+
+    /// Blocking version of [`CloudFront::list_cache_policies`].
+    pub fn list_cache_policies(
+        &self,
+        input: ListCachePoliciesRequest,
+    ) -> Result<ListCachePoliciesResult, RusotoError<ListCachePoliciesError>> {
+        self.runtime
+            .block_on(self.client.list_cache_policies(input))
+    }
+
+    /// Blocking version of [`CloudFront::list_cloud_front_origin_access_identities`].
+    pub fn list_cloud_front_origin_access_identities(
+        &self,
+        input: ListCloudFrontOriginAccessIdentitiesRequest,
+    ) -> Result<
+        ListCloudFrontOriginAccessIdentitiesResult,
+        RusotoError<ListCloudFrontOriginAccessIdentitiesError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_cloud_front_origin_access_identities(input))
+    }
+
+    /// Blocking version of [`CloudFront::list_distributions`].
+    pub fn list_distributions(
+        &self,
+        input: ListDistributionsRequest,
+    ) -> Result<ListDistributionsResult, RusotoError<ListDistributionsError>> {
+        self.runtime.block_on(self.client.list_distributions(input))
+    }
+
+    /// Blocking version of [`CloudFront::list_distributions_by_cache_policy_id`].
+    pub fn list_distributions_by_cache_policy_id(
+        &self,
+        input: ListDistributionsByCachePolicyIdRequest,
+    ) -> Result<
+        ListDistributionsByCachePolicyIdResult,
+        RusotoError<ListDistributionsByCachePolicyIdError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_distributions_by_cache_policy_id(input))
+    }
+
+    /// Blocking version of [`CloudFront::list_distributions_by_key_group`].
+    pub fn list_distributions_by_key_group(
+        &self,
+        input: ListDistributionsByKeyGroupRequest,
+    ) -> Result<ListDistributionsByKeyGroupResult, RusotoError<ListDistributionsByKeyGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.list_distributions_by_key_group(input))
+    }
+
+    /// Blocking version of [`CloudFront::list_distributions_by_origin_request_policy_id`].
+    pub fn list_distributions_by_origin_request_policy_id(
+        &self,
+        input: ListDistributionsByOriginRequestPolicyIdRequest,
+    ) -> Result<
+        ListDistributionsByOriginRequestPolicyIdResult,
+        RusotoError<ListDistributionsByOriginRequestPolicyIdError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .list_distributions_by_origin_request_policy_id(input),
+        )
+    }
+
+    /// Blocking version of [`CloudFront::list_distributions_by_realtime_log_config`].
+    pub fn list_distributions_by_realtime_log_config(
+        &self,
+        input: ListDistributionsByRealtimeLogConfigRequest,
+    ) -> Result<
+        ListDistributionsByRealtimeLogConfigResult,
+        RusotoError<ListDistributionsByRealtimeLogConfigError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_distributions_by_realtime_log_config(input))
+    }
+
+    /// Blocking version of [`CloudFront::list_distributions_by_web_acl_id`].
+    pub fn list_distributions_by_web_acl_id(
+        &self,
+        input: ListDistributionsByWebACLIdRequest,
+    ) -> Result<ListDistributionsByWebACLIdResult, RusotoError<ListDistributionsByWebACLIdError>>
+    {
+        self.runtime
+            .block_on(self.client.list_distributions_by_web_acl_id(input))
+    }
+
+    /// Blocking version of [`CloudFront::list_field_level_encryption_configs`].
+    pub fn list_field_level_encryption_configs(
+        &self,
+        input: ListFieldLevelEncryptionConfigsRequest,
+    ) -> Result<
+        ListFieldLevelEncryptionConfigsResult,
+        RusotoError<ListFieldLevelEncryptionConfigsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_field_level_encryption_configs(input))
+    }
+
+    /// Blocking version of [`CloudFront::list_field_level_encryption_profiles`].
+    pub fn list_field_level_encryption_profiles(
+        &self,
+        input: ListFieldLevelEncryptionProfilesRequest,
+    ) -> Result<
+        ListFieldLevelEncryptionProfilesResult,
+        RusotoError<ListFieldLevelEncryptionProfilesError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_field_level_encryption_profiles(input))
+    }
+
+    /// Blocking version of [`CloudFront::list_invalidations`].
+    pub fn list_invalidations(
+        &self,
+        input: ListInvalidationsRequest,
+    ) -> Result<ListInvalidationsResult, RusotoError<ListInvalidationsError>> {
+        self.runtime.block_on(self.client.list_invalidations(input))
+    }
+
+    /// Blocking version of [`CloudFront::list_key_groups`].
+    pub fn list_key_groups(
+        &self,
+        input: ListKeyGroupsRequest,
+    ) -> Result<ListKeyGroupsResult, RusotoError<ListKeyGroupsError>> {
+        self.runtime.block_on(self.client.list_key_groups(input))
+    }
+
+    /// Blocking version of [`CloudFront::list_origin_request_policies`].
+    pub fn list_origin_request_policies(
+        &self,
+        input: ListOriginRequestPoliciesRequest,
+    ) -> Result<ListOriginRequestPoliciesResult, RusotoError<ListOriginRequestPoliciesError>> {
+        self.runtime
+            .block_on(self.client.list_origin_request_policies(input))
+    }
+
+    /// Blocking version of [`CloudFront::list_public_keys`].
+    pub fn list_public_keys(
+        &self,
+        input: ListPublicKeysRequest,
+    ) -> Result<ListPublicKeysResult, RusotoError<ListPublicKeysError>> {
+        self.runtime.block_on(self.client.list_public_keys(input))
+    }
+
+    /// Blocking version of [`CloudFront::list_realtime_log_configs`].
+    pub fn list_realtime_log_configs(
+        &self,
+        input: ListRealtimeLogConfigsRequest,
+    ) -> Result<ListRealtimeLogConfigsResult, RusotoError<ListRealtimeLogConfigsError>> {
+        self.runtime
+            .block_on(self.client.list_realtime_log_configs(input))
+    }
+
+    /// Blocking version of [`CloudFront::list_streaming_distributions`].
+    pub fn list_streaming_distributions(
+        &self,
+        input: ListStreamingDistributionsRequest,
+    ) -> Result<ListStreamingDistributionsResult, RusotoError<ListStreamingDistributionsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_streaming_distributions(input))
+    }
+
+    /// Blocking version of [`CloudFront::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResult, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`CloudFront::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`CloudFront::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`CloudFront::update_cache_policy`].
+    pub fn update_cache_policy(
+        &self,
+        input: UpdateCachePolicyRequest,
+    ) -> Result<UpdateCachePolicyResult, RusotoError<UpdateCachePolicyError>> {
+        self.runtime
+            .block_on(self.client.update_cache_policy(input))
+    }
+
+    /// Blocking version of [`CloudFront::update_cloud_front_origin_access_identity`].
+    pub fn update_cloud_front_origin_access_identity(
+        &self,
+        input: UpdateCloudFrontOriginAccessIdentityRequest,
+    ) -> Result<
+        UpdateCloudFrontOriginAccessIdentityResult,
+        RusotoError<UpdateCloudFrontOriginAccessIdentityError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_cloud_front_origin_access_identity(input))
+    }
+
+    /// Blocking version of [`CloudFront::update_distribution`].
+    pub fn update_distribution(
+        &self,
+        input: UpdateDistributionRequest,
+    ) -> Result<UpdateDistributionResult, RusotoError<UpdateDistributionError>> {
+        self.runtime
+            .block_on(self.client.update_distribution(input))
+    }
+
+    /// Blocking version of [`CloudFront::update_field_level_encryption_config`].
+    pub fn update_field_level_encryption_config(
+        &self,
+        input: UpdateFieldLevelEncryptionConfigRequest,
+    ) -> Result<
+        UpdateFieldLevelEncryptionConfigResult,
+        RusotoError<UpdateFieldLevelEncryptionConfigError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_field_level_encryption_config(input))
+    }
+
+    /// Blocking version of [`CloudFront::update_field_level_encryption_profile`].
+    pub fn update_field_level_encryption_profile(
+        &self,
+        input: UpdateFieldLevelEncryptionProfileRequest,
+    ) -> Result<
+        UpdateFieldLevelEncryptionProfileResult,
+        RusotoError<UpdateFieldLevelEncryptionProfileError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_field_level_encryption_profile(input))
+    }
+
+    /// Blocking version of [`CloudFront::update_key_group`].
+    pub fn update_key_group(
+        &self,
+        input: UpdateKeyGroupRequest,
+    ) -> Result<UpdateKeyGroupResult, RusotoError<UpdateKeyGroupError>> {
+        self.runtime.block_on(self.client.update_key_group(input))
+    }
+
+    /// Blocking version of [`CloudFront::update_origin_request_policy`].
+    pub fn update_origin_request_policy(
+        &self,
+        input: UpdateOriginRequestPolicyRequest,
+    ) -> Result<UpdateOriginRequestPolicyResult, RusotoError<UpdateOriginRequestPolicyError>> {
+        self.runtime
+            .block_on(self.client.update_origin_request_policy(input))
+    }
+
+    /// Blocking version of [`CloudFront::update_public_key`].
+    pub fn update_public_key(
+        &self,
+        input: UpdatePublicKeyRequest,
+    ) -> Result<UpdatePublicKeyResult, RusotoError<UpdatePublicKeyError>> {
+        self.runtime.block_on(self.client.update_public_key(input))
+    }
+
+    /// Blocking version of [`CloudFront::update_realtime_log_config`].
+    pub fn update_realtime_log_config(
+        &self,
+        input: UpdateRealtimeLogConfigRequest,
+    ) -> Result<UpdateRealtimeLogConfigResult, RusotoError<UpdateRealtimeLogConfigError>> {
+        self.runtime
+            .block_on(self.client.update_realtime_log_config(input))
+    }
+
+    /// Blocking version of [`CloudFront::update_streaming_distribution`].
+    pub fn update_streaming_distribution(
+        &self,
+        input: UpdateStreamingDistributionRequest,
+    ) -> Result<UpdateStreamingDistributionResult, RusotoError<UpdateStreamingDistributionError>>
+    {
+        self.runtime
+            .block_on(self.client.update_streaming_distribution(input))
+    }
+}
+
 #[cfg(test)]
 mod protocol_tests {
 
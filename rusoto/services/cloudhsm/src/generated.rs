@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -654,15 +655,30 @@ impl AddTagsToResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(AddTagsToResourceError::CloudHsmInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsToResourceError::CloudHsmInternal,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(AddTagsToResourceError::CloudHsmService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsToResourceError::CloudHsmService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AddTagsToResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsToResourceError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -696,15 +712,30 @@ impl CreateHapgError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(CreateHapgError::CloudHsmInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateHapgError::CloudHsmInternal,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(CreateHapgError::CloudHsmService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateHapgError::CloudHsmService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateHapgError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateHapgError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -738,15 +769,30 @@ impl CreateHsmError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(CreateHsmError::CloudHsmInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateHsmError::CloudHsmInternal,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(CreateHsmError::CloudHsmService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateHsmError::CloudHsmService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateHsmError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateHsmError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -780,15 +826,30 @@ impl CreateLunaClientError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(CreateLunaClientError::CloudHsmInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLunaClientError::CloudHsmInternal,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(CreateLunaClientError::CloudHsmService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLunaClientError::CloudHsmService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateLunaClientError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLunaClientError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -822,15 +883,30 @@ impl DeleteHapgError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(DeleteHapgError::CloudHsmInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteHapgError::CloudHsmInternal,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(DeleteHapgError::CloudHsmService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteHapgError::CloudHsmService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteHapgError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteHapgError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -864,15 +940,30 @@ impl DeleteHsmError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(DeleteHsmError::CloudHsmInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteHsmError::CloudHsmInternal,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(DeleteHsmError::CloudHsmService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteHsmError::CloudHsmService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteHsmError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteHsmError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -906,15 +997,30 @@ impl DeleteLunaClientError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(DeleteLunaClientError::CloudHsmInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLunaClientError::CloudHsmInternal,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(DeleteLunaClientError::CloudHsmService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLunaClientError::CloudHsmService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteLunaClientError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLunaClientError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -948,15 +1054,30 @@ impl DescribeHapgError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(DescribeHapgError::CloudHsmInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeHapgError::CloudHsmInternal,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(DescribeHapgError::CloudHsmService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeHapgError::CloudHsmService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeHapgError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeHapgError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -990,15 +1111,30 @@ impl DescribeHsmError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(DescribeHsmError::CloudHsmInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeHsmError::CloudHsmInternal,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(DescribeHsmError::CloudHsmService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeHsmError::CloudHsmService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeHsmError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeHsmError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1032,15 +1168,30 @@ impl DescribeLunaClientError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(DescribeLunaClientError::CloudHsmInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeLunaClientError::CloudHsmInternal,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(DescribeLunaClientError::CloudHsmService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeLunaClientError::CloudHsmService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeLunaClientError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeLunaClientError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1074,15 +1225,30 @@ impl GetConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(GetConfigError::CloudHsmInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConfigError::CloudHsmInternal,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(GetConfigError::CloudHsmService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConfigError::CloudHsmService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetConfigError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConfigError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1116,15 +1282,30 @@ impl ListAvailableZonesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(ListAvailableZonesError::CloudHsmInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAvailableZonesError::CloudHsmInternal,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(ListAvailableZonesError::CloudHsmService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAvailableZonesError::CloudHsmService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListAvailableZonesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAvailableZonesError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1158,15 +1339,30 @@ impl ListHapgsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(ListHapgsError::CloudHsmInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListHapgsError::CloudHsmInternal,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(ListHapgsError::CloudHsmService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListHapgsError::CloudHsmService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListHapgsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListHapgsError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1200,15 +1396,30 @@ impl ListHsmsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(ListHsmsError::CloudHsmInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListHsmsError::CloudHsmInternal,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(ListHsmsError::CloudHsmService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListHsmsError::CloudHsmService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListHsmsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListHsmsError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1242,15 +1453,30 @@ impl ListLunaClientsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(ListLunaClientsError::CloudHsmInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLunaClientsError::CloudHsmInternal,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(ListLunaClientsError::CloudHsmService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLunaClientsError::CloudHsmService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListLunaClientsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLunaClientsError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1284,17 +1510,30 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(ListTagsForResourceError::CloudHsmInternal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::CloudHsmInternal,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(ListTagsForResourceError::CloudHsmService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::CloudHsmService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1328,15 +1567,30 @@ impl ModifyHapgError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(ModifyHapgError::CloudHsmInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyHapgError::CloudHsmInternal,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(ModifyHapgError::CloudHsmService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyHapgError::CloudHsmService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ModifyHapgError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyHapgError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1370,15 +1624,30 @@ impl ModifyHsmError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(ModifyHsmError::CloudHsmInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyHsmError::CloudHsmInternal,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(ModifyHsmError::CloudHsmService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyHsmError::CloudHsmService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ModifyHsmError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyHsmError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1408,9 +1677,14 @@ impl ModifyLunaClientError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(ModifyLunaClientError::CloudHsmService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyLunaClientError::CloudHsmService,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1442,21 +1716,30 @@ impl RemoveTagsFromResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmInternalException" => {
-                    return RusotoError::Service(RemoveTagsFromResourceError::CloudHsmInternal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveTagsFromResourceError::CloudHsmInternal,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(RemoveTagsFromResourceError::CloudHsmService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveTagsFromResourceError::CloudHsmService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RemoveTagsFromResourceError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveTagsFromResourceError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1606,11 +1889,12 @@ pub struct CloudHsmClient {
 impl CloudHsmClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> CloudHsmClient {
         CloudHsmClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "CloudHSM"),
         }
     }
 
@@ -1625,12 +1909,32 @@ impl CloudHsmClient {
     {
         CloudHsmClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "CloudHSM"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CloudHsmClient {
-        CloudHsmClient { client, region }
+        CloudHsmClient {
+            client,
+            region: region_for_service(region, "CloudHSM"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> CloudHsmClient {
+        CloudHsmClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("CloudHSM"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CloudHsmClient {
+        CloudHsmClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -1642,6 +1946,7 @@ impl CloudHsm for CloudHsmClient {
         input: AddTagsToResourceRequest,
     ) -> Result<AddTagsToResourceResponse, RusotoError<AddTagsToResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AddTagsToResource");
         request.add_header("x-amz-target", "CloudHsmFrontendService.AddTagsToResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1660,6 +1965,7 @@ impl CloudHsm for CloudHsmClient {
         input: CreateHapgRequest,
     ) -> Result<CreateHapgResponse, RusotoError<CreateHapgError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateHapg");
         request.add_header("x-amz-target", "CloudHsmFrontendService.CreateHapg");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1678,6 +1984,7 @@ impl CloudHsm for CloudHsmClient {
         input: CreateHsmRequest,
     ) -> Result<CreateHsmResponse, RusotoError<CreateHsmError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateHsm");
         request.add_header("x-amz-target", "CloudHsmFrontendService.CreateHsm");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1696,6 +2003,7 @@ impl CloudHsm for CloudHsmClient {
         input: CreateLunaClientRequest,
     ) -> Result<CreateLunaClientResponse, RusotoError<CreateLunaClientError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateLunaClient");
         request.add_header("x-amz-target", "CloudHsmFrontendService.CreateLunaClient");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1714,6 +2022,7 @@ impl CloudHsm for CloudHsmClient {
         input: DeleteHapgRequest,
     ) -> Result<DeleteHapgResponse, RusotoError<DeleteHapgError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteHapg");
         request.add_header("x-amz-target", "CloudHsmFrontendService.DeleteHapg");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1732,6 +2041,7 @@ impl CloudHsm for CloudHsmClient {
         input: DeleteHsmRequest,
     ) -> Result<DeleteHsmResponse, RusotoError<DeleteHsmError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteHsm");
         request.add_header("x-amz-target", "CloudHsmFrontendService.DeleteHsm");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1750,6 +2060,7 @@ impl CloudHsm for CloudHsmClient {
         input: DeleteLunaClientRequest,
     ) -> Result<DeleteLunaClientResponse, RusotoError<DeleteLunaClientError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteLunaClient");
         request.add_header("x-amz-target", "CloudHsmFrontendService.DeleteLunaClient");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1768,6 +2079,7 @@ impl CloudHsm for CloudHsmClient {
         input: DescribeHapgRequest,
     ) -> Result<DescribeHapgResponse, RusotoError<DescribeHapgError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeHapg");
         request.add_header("x-amz-target", "CloudHsmFrontendService.DescribeHapg");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1786,6 +2098,7 @@ impl CloudHsm for CloudHsmClient {
         input: DescribeHsmRequest,
     ) -> Result<DescribeHsmResponse, RusotoError<DescribeHsmError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeHsm");
         request.add_header("x-amz-target", "CloudHsmFrontendService.DescribeHsm");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1804,6 +2117,7 @@ impl CloudHsm for CloudHsmClient {
         input: DescribeLunaClientRequest,
     ) -> Result<DescribeLunaClientResponse, RusotoError<DescribeLunaClientError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeLunaClient");
         request.add_header("x-amz-target", "CloudHsmFrontendService.DescribeLunaClient");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1822,6 +2136,7 @@ impl CloudHsm for CloudHsmClient {
         input: GetConfigRequest,
     ) -> Result<GetConfigResponse, RusotoError<GetConfigError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetConfig");
         request.add_header("x-amz-target", "CloudHsmFrontendService.GetConfig");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1839,6 +2154,7 @@ impl CloudHsm for CloudHsmClient {
         &self,
     ) -> Result<ListAvailableZonesResponse, RusotoError<ListAvailableZonesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListAvailableZones");
         request.add_header("x-amz-target", "CloudHsmFrontendService.ListAvailableZones");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -1856,6 +2172,7 @@ impl CloudHsm for CloudHsmClient {
         input: ListHapgsRequest,
     ) -> Result<ListHapgsResponse, RusotoError<ListHapgsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListHapgs");
         request.add_header("x-amz-target", "CloudHsmFrontendService.ListHapgs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1874,6 +2191,7 @@ impl CloudHsm for CloudHsmClient {
         input: ListHsmsRequest,
     ) -> Result<ListHsmsResponse, RusotoError<ListHsmsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListHsms");
         request.add_header("x-amz-target", "CloudHsmFrontendService.ListHsms");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1892,6 +2210,7 @@ impl CloudHsm for CloudHsmClient {
         input: ListLunaClientsRequest,
     ) -> Result<ListLunaClientsResponse, RusotoError<ListLunaClientsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListLunaClients");
         request.add_header("x-amz-target", "CloudHsmFrontendService.ListLunaClients");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1910,6 +2229,7 @@ impl CloudHsm for CloudHsmClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header(
             "x-amz-target",
             "CloudHsmFrontendService.ListTagsForResource",
@@ -1931,6 +2251,7 @@ impl CloudHsm for CloudHsmClient {
         input: ModifyHapgRequest,
     ) -> Result<ModifyHapgResponse, RusotoError<ModifyHapgError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ModifyHapg");
         request.add_header("x-amz-target", "CloudHsmFrontendService.ModifyHapg");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1949,6 +2270,7 @@ impl CloudHsm for CloudHsmClient {
         input: ModifyHsmRequest,
     ) -> Result<ModifyHsmResponse, RusotoError<ModifyHsmError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ModifyHsm");
         request.add_header("x-amz-target", "CloudHsmFrontendService.ModifyHsm");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1967,6 +2289,7 @@ impl CloudHsm for CloudHsmClient {
         input: ModifyLunaClientRequest,
     ) -> Result<ModifyLunaClientResponse, RusotoError<ModifyLunaClientError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ModifyLunaClient");
         request.add_header("x-amz-target", "CloudHsmFrontendService.ModifyLunaClient");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1985,6 +2308,7 @@ impl CloudHsm for CloudHsmClient {
         input: RemoveTagsFromResourceRequest,
     ) -> Result<RemoveTagsFromResourceResponse, RusotoError<RemoveTagsFromResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RemoveTagsFromResource");
         request.add_header(
             "x-amz-target",
             "CloudHsmFrontendService.RemoveTagsFromResource",
@@ -2001,3 +2325,241 @@ impl CloudHsm for CloudHsmClient {
             .deserialize::<RemoveTagsFromResourceResponse, _>()
     }
 }
+
+/// A blocking client for the CloudHSM API, running the calls of a `CloudHsmClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `CloudHsmClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CloudHsmBlockingClient {
+    client: CloudHsmClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CloudHsmBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> CloudHsmBlockingClient {
+        CloudHsmBlockingClient::from_client(CloudHsmClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CloudHsmBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CloudHsmBlockingClient::from_client(CloudHsmClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> CloudHsmBlockingClient {
+        CloudHsmBlockingClient::from_client(CloudHsmClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> CloudHsmBlockingClient {
+        CloudHsmBlockingClient::from_client(CloudHsmClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: CloudHsmClient) -> CloudHsmBlockingClient {
+        CloudHsmBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> CloudHsmBlockingClient {
+        CloudHsmBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CloudHsmBlockingClient {
+        CloudHsmBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`CloudHsm::add_tags_to_resource`].
+    pub fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceRequest,
+    ) -> Result<AddTagsToResourceResponse, RusotoError<AddTagsToResourceError>> {
+        self.runtime
+            .block_on(self.client.add_tags_to_resource(input))
+    }
+
+    /// Blocking version of [`CloudHsm::create_hapg`].
+    pub fn create_hapg(
+        &self,
+        input: CreateHapgRequest,
+    ) -> Result<CreateHapgResponse, RusotoError<CreateHapgError>> {
+        self.runtime.block_on(self.client.create_hapg(input))
+    }
+
+    /// Blocking version of [`CloudHsm::create_hsm`].
+    pub fn create_hsm(
+        &self,
+        input: CreateHsmRequest,
+    ) -> Result<CreateHsmResponse, RusotoError<CreateHsmError>> {
+        self.runtime.block_on(self.client.create_hsm(input))
+    }
+
+    /// Blocking version of [`CloudHsm::create_luna_client`].
+    pub fn create_luna_client(
+        &self,
+        input: CreateLunaClientRequest,
+    ) -> Result<CreateLunaClientResponse, RusotoError<CreateLunaClientError>> {
+        self.runtime.block_on(self.client.create_luna_client(input))
+    }
+
+    /// Blocking version of [`CloudHsm::delete_hapg`].
+    pub fn delete_hapg(
+        &self,
+        input: DeleteHapgRequest,
+    ) -> Result<DeleteHapgResponse, RusotoError<DeleteHapgError>> {
+        self.runtime.block_on(self.client.delete_hapg(input))
+    }
+
+    /// Blocking version of [`CloudHsm::delete_hsm`].
+    pub fn delete_hsm(
+        &self,
+        input: DeleteHsmRequest,
+    ) -> Result<DeleteHsmResponse, RusotoError<DeleteHsmError>> {
+        self.runtime.block_on(self.client.delete_hsm(input))
+    }
+
+    /// Blocking version of [`CloudHsm::delete_luna_client`].
+    pub fn delete_luna_client(
+        &self,
+        input: DeleteLunaClientRequest,
+    ) -> Result<DeleteLunaClientResponse, RusotoError<DeleteLunaClientError>> {
+        self.runtime.block_on(self.client.delete_luna_client(input))
+    }
+
+    /// Blocking version of [`CloudHsm::describe_hapg`].
+    pub fn describe_hapg(
+        &self,
+        input: DescribeHapgRequest,
+    ) -> Result<DescribeHapgResponse, RusotoError<DescribeHapgError>> {
+        self.runtime.block_on(self.client.describe_hapg(input))
+    }
+
+    /// Blocking version of [`CloudHsm::describe_hsm`].
+    pub fn describe_hsm(
+        &self,
+        input: DescribeHsmRequest,
+    ) -> Result<DescribeHsmResponse, RusotoError<DescribeHsmError>> {
+        self.runtime.block_on(self.client.describe_hsm(input))
+    }
+
+    /// Blocking version of [`CloudHsm::describe_luna_client`].
+    pub fn describe_luna_client(
+        &self,
+        input: DescribeLunaClientRequest,
+    ) -> Result<DescribeLunaClientResponse, RusotoError<DescribeLunaClientError>> {
+        self.runtime
+            .block_on(self.client.describe_luna_client(input))
+    }
+
+    /// Blocking version of [`CloudHsm::get_config`].
+    pub fn get_config(
+        &self,
+        input: GetConfigRequest,
+    ) -> Result<GetConfigResponse, RusotoError<GetConfigError>> {
+        self.runtime.block_on(self.client.get_config(input))
+    }
+
+    /// Blocking version of [`CloudHsm::list_available_zones`].
+    pub fn list_available_zones(
+        &self,
+    ) -> Result<ListAvailableZonesResponse, RusotoError<ListAvailableZonesError>> {
+        self.runtime.block_on(self.client.list_available_zones())
+    }
+
+    /// Blocking version of [`CloudHsm::list_hapgs`].
+    pub fn list_hapgs(
+        &self,
+        input: ListHapgsRequest,
+    ) -> Result<ListHapgsResponse, RusotoError<ListHapgsError>> {
+        self.runtime.block_on(self.client.list_hapgs(input))
+    }
+
+    /// Blocking version of [`CloudHsm::list_hsms`].
+    pub fn list_hsms(
+        &self,
+        input: ListHsmsRequest,
+    ) -> Result<ListHsmsResponse, RusotoError<ListHsmsError>> {
+        self.runtime.block_on(self.client.list_hsms(input))
+    }
+
+    /// Blocking version of [`CloudHsm::list_luna_clients`].
+    pub fn list_luna_clients(
+        &self,
+        input: ListLunaClientsRequest,
+    ) -> Result<ListLunaClientsResponse, RusotoError<ListLunaClientsError>> {
+        self.runtime.block_on(self.client.list_luna_clients(input))
+    }
+
+    /// Blocking version of [`CloudHsm::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`CloudHsm::modify_hapg`].
+    pub fn modify_hapg(
+        &self,
+        input: ModifyHapgRequest,
+    ) -> Result<ModifyHapgResponse, RusotoError<ModifyHapgError>> {
+        self.runtime.block_on(self.client.modify_hapg(input))
+    }
+
+    /// Blocking version of [`CloudHsm::modify_hsm`].
+    pub fn modify_hsm(
+        &self,
+        input: ModifyHsmRequest,
+    ) -> Result<ModifyHsmResponse, RusotoError<ModifyHsmError>> {
+        self.runtime.block_on(self.client.modify_hsm(input))
+    }
+
+    /// Blocking version of [`CloudHsm::modify_luna_client`].
+    pub fn modify_luna_client(
+        &self,
+        input: ModifyLunaClientRequest,
+    ) -> Result<ModifyLunaClientResponse, RusotoError<ModifyLunaClientError>> {
+        self.runtime.block_on(self.client.modify_luna_client(input))
+    }
+
+    /// Blocking version of [`CloudHsm::remove_tags_from_resource`].
+    pub fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceRequest,
+    ) -> Result<RemoveTagsFromResourceResponse, RusotoError<RemoveTagsFromResourceError>> {
+        self.runtime
+            .block_on(self.client.remove_tags_from_resource(input))
+    }
+}
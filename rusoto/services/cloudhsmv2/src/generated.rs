@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -644,32 +645,54 @@ impl CopyBackupToRegionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(CopyBackupToRegionError::CloudHsmAccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CopyBackupToRegionError::CloudHsmAccessDenied,
+                    )
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(CopyBackupToRegionError::CloudHsmInternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CopyBackupToRegionError::CloudHsmInternalFailure,
+                    )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(CopyBackupToRegionError::CloudHsmInvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CopyBackupToRegionError::CloudHsmInvalidRequest,
+                    )
                 }
                 "CloudHsmResourceNotFoundException" => {
-                    return RusotoError::Service(CopyBackupToRegionError::CloudHsmResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CopyBackupToRegionError::CloudHsmResourceNotFound,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(CopyBackupToRegionError::CloudHsmService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CopyBackupToRegionError::CloudHsmService,
+                    )
                 }
                 "CloudHsmTagException" => {
-                    return RusotoError::Service(CopyBackupToRegionError::CloudHsmTag(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CopyBackupToRegionError::CloudHsmTag,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -712,30 +735,54 @@ impl CreateClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(CreateClusterError::CloudHsmAccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateClusterError::CloudHsmAccessDenied,
+                    )
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(CreateClusterError::CloudHsmInternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateClusterError::CloudHsmInternalFailure,
+                    )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(CreateClusterError::CloudHsmInvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateClusterError::CloudHsmInvalidRequest,
+                    )
                 }
                 "CloudHsmResourceNotFoundException" => {
-                    return RusotoError::Service(CreateClusterError::CloudHsmResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateClusterError::CloudHsmResourceNotFound,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(CreateClusterError::CloudHsmService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateClusterError::CloudHsmService,
+                    )
                 }
                 "CloudHsmTagException" => {
-                    return RusotoError::Service(CreateClusterError::CloudHsmTag(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateClusterError::CloudHsmTag,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -776,21 +823,46 @@ impl CreateHsmError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(CreateHsmError::CloudHsmAccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateHsmError::CloudHsmAccessDenied,
+                    )
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(CreateHsmError::CloudHsmInternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateHsmError::CloudHsmInternalFailure,
+                    )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(CreateHsmError::CloudHsmInvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateHsmError::CloudHsmInvalidRequest,
+                    )
                 }
                 "CloudHsmResourceNotFoundException" => {
-                    return RusotoError::Service(CreateHsmError::CloudHsmResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateHsmError::CloudHsmResourceNotFound,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(CreateHsmError::CloudHsmService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateHsmError::CloudHsmService,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -830,25 +902,46 @@ impl DeleteBackupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(DeleteBackupError::CloudHsmAccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBackupError::CloudHsmAccessDenied,
+                    )
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(DeleteBackupError::CloudHsmInternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteBackupError::CloudHsmInternalFailure,
+                    )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(DeleteBackupError::CloudHsmInvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBackupError::CloudHsmInvalidRequest,
+                    )
                 }
                 "CloudHsmResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteBackupError::CloudHsmResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteBackupError::CloudHsmResourceNotFound,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(DeleteBackupError::CloudHsmService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBackupError::CloudHsmService,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -890,30 +983,54 @@ impl DeleteClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(DeleteClusterError::CloudHsmAccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteClusterError::CloudHsmAccessDenied,
+                    )
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(DeleteClusterError::CloudHsmInternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteClusterError::CloudHsmInternalFailure,
+                    )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(DeleteClusterError::CloudHsmInvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteClusterError::CloudHsmInvalidRequest,
+                    )
                 }
                 "CloudHsmResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteClusterError::CloudHsmResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteClusterError::CloudHsmResourceNotFound,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(DeleteClusterError::CloudHsmService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteClusterError::CloudHsmService,
+                    )
                 }
                 "CloudHsmTagException" => {
-                    return RusotoError::Service(DeleteClusterError::CloudHsmTag(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteClusterError::CloudHsmTag,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -954,21 +1071,46 @@ impl DeleteHsmError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(DeleteHsmError::CloudHsmAccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteHsmError::CloudHsmAccessDenied,
+                    )
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(DeleteHsmError::CloudHsmInternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteHsmError::CloudHsmInternalFailure,
+                    )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(DeleteHsmError::CloudHsmInvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteHsmError::CloudHsmInvalidRequest,
+                    )
                 }
                 "CloudHsmResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteHsmError::CloudHsmResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteHsmError::CloudHsmResourceNotFound,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(DeleteHsmError::CloudHsmService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteHsmError::CloudHsmService,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1010,32 +1152,54 @@ impl DescribeBackupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(DescribeBackupsError::CloudHsmAccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeBackupsError::CloudHsmAccessDenied,
+                    )
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(DescribeBackupsError::CloudHsmInternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeBackupsError::CloudHsmInternalFailure,
+                    )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(DescribeBackupsError::CloudHsmInvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeBackupsError::CloudHsmInvalidRequest,
+                    )
                 }
                 "CloudHsmResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeBackupsError::CloudHsmResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeBackupsError::CloudHsmResourceNotFound,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(DescribeBackupsError::CloudHsmService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBackupsError::CloudHsmService,
+                    )
                 }
                 "CloudHsmTagException" => {
-                    return RusotoError::Service(DescribeBackupsError::CloudHsmTag(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBackupsError::CloudHsmTag,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1076,27 +1240,46 @@ impl DescribeClustersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(DescribeClustersError::CloudHsmAccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeClustersError::CloudHsmAccessDenied,
+                    )
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(DescribeClustersError::CloudHsmInternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeClustersError::CloudHsmInternalFailure,
+                    )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(DescribeClustersError::CloudHsmInvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeClustersError::CloudHsmInvalidRequest,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(DescribeClustersError::CloudHsmService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeClustersError::CloudHsmService,
+                    )
                 }
                 "CloudHsmTagException" => {
-                    return RusotoError::Service(DescribeClustersError::CloudHsmTag(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeClustersError::CloudHsmTag,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1136,29 +1319,46 @@ impl InitializeClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(InitializeClusterError::CloudHsmAccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        InitializeClusterError::CloudHsmAccessDenied,
+                    )
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(InitializeClusterError::CloudHsmInternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        InitializeClusterError::CloudHsmInternalFailure,
+                    )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(InitializeClusterError::CloudHsmInvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        InitializeClusterError::CloudHsmInvalidRequest,
+                    )
                 }
                 "CloudHsmResourceNotFoundException" => {
-                    return RusotoError::Service(InitializeClusterError::CloudHsmResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        InitializeClusterError::CloudHsmResourceNotFound,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(InitializeClusterError::CloudHsmService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InitializeClusterError::CloudHsmService,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1200,24 +1400,54 @@ impl ListTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(ListTagsError::CloudHsmAccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsError::CloudHsmAccessDenied,
+                    )
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(ListTagsError::CloudHsmInternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsError::CloudHsmInternalFailure,
+                    )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(ListTagsError::CloudHsmInvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsError::CloudHsmInvalidRequest,
+                    )
                 }
                 "CloudHsmResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsError::CloudHsmResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsError::CloudHsmResourceNotFound,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(ListTagsError::CloudHsmService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsError::CloudHsmService,
+                    )
                 }
                 "CloudHsmTagException" => {
-                    return RusotoError::Service(ListTagsError::CloudHsmTag(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsError::CloudHsmTag,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1258,31 +1488,46 @@ impl ModifyBackupAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(ModifyBackupAttributesError::CloudHsmAccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ModifyBackupAttributesError::CloudHsmAccessDenied,
+                    )
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(
-                        ModifyBackupAttributesError::CloudHsmInternalFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyBackupAttributesError::CloudHsmInternalFailure,
                     )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(
-                        ModifyBackupAttributesError::CloudHsmInvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyBackupAttributesError::CloudHsmInvalidRequest,
                     )
                 }
                 "CloudHsmResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        ModifyBackupAttributesError::CloudHsmResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyBackupAttributesError::CloudHsmResourceNotFound,
                     )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(ModifyBackupAttributesError::CloudHsmService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ModifyBackupAttributesError::CloudHsmService,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1328,27 +1573,46 @@ impl ModifyClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(ModifyClusterError::CloudHsmAccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyClusterError::CloudHsmAccessDenied,
+                    )
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(ModifyClusterError::CloudHsmInternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ModifyClusterError::CloudHsmInternalFailure,
+                    )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(ModifyClusterError::CloudHsmInvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ModifyClusterError::CloudHsmInvalidRequest,
+                    )
                 }
                 "CloudHsmResourceNotFoundException" => {
-                    return RusotoError::Service(ModifyClusterError::CloudHsmResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ModifyClusterError::CloudHsmResourceNotFound,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(ModifyClusterError::CloudHsmService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyClusterError::CloudHsmService,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1388,27 +1652,46 @@ impl RestoreBackupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(RestoreBackupError::CloudHsmAccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RestoreBackupError::CloudHsmAccessDenied,
+                    )
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(RestoreBackupError::CloudHsmInternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RestoreBackupError::CloudHsmInternalFailure,
+                    )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(RestoreBackupError::CloudHsmInvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RestoreBackupError::CloudHsmInvalidRequest,
+                    )
                 }
                 "CloudHsmResourceNotFoundException" => {
-                    return RusotoError::Service(RestoreBackupError::CloudHsmResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RestoreBackupError::CloudHsmResourceNotFound,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(RestoreBackupError::CloudHsmService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RestoreBackupError::CloudHsmService,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1450,26 +1733,54 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(TagResourceError::CloudHsmAccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::CloudHsmAccessDenied,
+                    )
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(TagResourceError::CloudHsmInternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::CloudHsmInternalFailure,
+                    )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::CloudHsmInvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::CloudHsmInvalidRequest,
+                    )
                 }
                 "CloudHsmResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::CloudHsmResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TagResourceError::CloudHsmResourceNotFound,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(TagResourceError::CloudHsmService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::CloudHsmService,
+                    )
                 }
                 "CloudHsmTagException" => {
-                    return RusotoError::Service(TagResourceError::CloudHsmTag(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::CloudHsmTag,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1512,30 +1823,54 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmAccessDeniedException" => {
-                    return RusotoError::Service(UntagResourceError::CloudHsmAccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::CloudHsmAccessDenied,
+                    )
                 }
                 "CloudHsmInternalFailureException" => {
-                    return RusotoError::Service(UntagResourceError::CloudHsmInternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UntagResourceError::CloudHsmInternalFailure,
+                    )
                 }
                 "CloudHsmInvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::CloudHsmInvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UntagResourceError::CloudHsmInvalidRequest,
+                    )
                 }
                 "CloudHsmResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::CloudHsmResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UntagResourceError::CloudHsmResourceNotFound,
+                    )
                 }
                 "CloudHsmServiceException" => {
-                    return RusotoError::Service(UntagResourceError::CloudHsmService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::CloudHsmService,
+                    )
                 }
                 "CloudHsmTagException" => {
-                    return RusotoError::Service(UntagResourceError::CloudHsmTag(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::CloudHsmTag,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1659,11 +1994,12 @@ pub struct CloudHsmv2Client {
 impl CloudHsmv2Client {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> CloudHsmv2Client {
         CloudHsmv2Client {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "CloudHSM V2"),
         }
     }
 
@@ -1678,12 +2014,32 @@ impl CloudHsmv2Client {
     {
         CloudHsmv2Client {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "CloudHSM V2"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CloudHsmv2Client {
-        CloudHsmv2Client { client, region }
+        CloudHsmv2Client {
+            client,
+            region: region_for_service(region, "CloudHSM V2"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> CloudHsmv2Client {
+        CloudHsmv2Client {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("CloudHSM V2"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CloudHsmv2Client {
+        CloudHsmv2Client {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -1695,6 +2051,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: CopyBackupToRegionRequest,
     ) -> Result<CopyBackupToRegionResponse, RusotoError<CopyBackupToRegionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CopyBackupToRegion");
         request.add_header("x-amz-target", "BaldrApiService.CopyBackupToRegion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1713,6 +2070,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: CreateClusterRequest,
     ) -> Result<CreateClusterResponse, RusotoError<CreateClusterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateCluster");
         request.add_header("x-amz-target", "BaldrApiService.CreateCluster");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1731,6 +2089,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: CreateHsmRequest,
     ) -> Result<CreateHsmResponse, RusotoError<CreateHsmError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateHsm");
         request.add_header("x-amz-target", "BaldrApiService.CreateHsm");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1749,6 +2108,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: DeleteBackupRequest,
     ) -> Result<DeleteBackupResponse, RusotoError<DeleteBackupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteBackup");
         request.add_header("x-amz-target", "BaldrApiService.DeleteBackup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1767,6 +2127,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: DeleteClusterRequest,
     ) -> Result<DeleteClusterResponse, RusotoError<DeleteClusterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteCluster");
         request.add_header("x-amz-target", "BaldrApiService.DeleteCluster");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1785,6 +2146,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: DeleteHsmRequest,
     ) -> Result<DeleteHsmResponse, RusotoError<DeleteHsmError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteHsm");
         request.add_header("x-amz-target", "BaldrApiService.DeleteHsm");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1803,6 +2165,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: DescribeBackupsRequest,
     ) -> Result<DescribeBackupsResponse, RusotoError<DescribeBackupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeBackups");
         request.add_header("x-amz-target", "BaldrApiService.DescribeBackups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1821,6 +2184,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: DescribeClustersRequest,
     ) -> Result<DescribeClustersResponse, RusotoError<DescribeClustersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeClusters");
         request.add_header("x-amz-target", "BaldrApiService.DescribeClusters");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1839,6 +2203,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: InitializeClusterRequest,
     ) -> Result<InitializeClusterResponse, RusotoError<InitializeClusterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("InitializeCluster");
         request.add_header("x-amz-target", "BaldrApiService.InitializeCluster");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1857,6 +2222,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: ListTagsRequest,
     ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTags");
         request.add_header("x-amz-target", "BaldrApiService.ListTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1875,6 +2241,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: ModifyBackupAttributesRequest,
     ) -> Result<ModifyBackupAttributesResponse, RusotoError<ModifyBackupAttributesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ModifyBackupAttributes");
         request.add_header("x-amz-target", "BaldrApiService.ModifyBackupAttributes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1894,6 +2261,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: ModifyClusterRequest,
     ) -> Result<ModifyClusterResponse, RusotoError<ModifyClusterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ModifyCluster");
         request.add_header("x-amz-target", "BaldrApiService.ModifyCluster");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1912,6 +2280,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: RestoreBackupRequest,
     ) -> Result<RestoreBackupResponse, RusotoError<RestoreBackupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RestoreBackup");
         request.add_header("x-amz-target", "BaldrApiService.RestoreBackup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1930,6 +2299,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "BaldrApiService.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1948,6 +2318,7 @@ impl CloudHsmv2 for CloudHsmv2Client {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "BaldrApiService.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1960,3 +2331,200 @@ impl CloudHsmv2 for CloudHsmv2Client {
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 }
+
+/// A blocking client for the CloudHSM V2 API, running the calls of a `CloudHsmv2Client`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `CloudHsmv2Client`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CloudHsmv2BlockingClient {
+    client: CloudHsmv2Client,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CloudHsmv2BlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> CloudHsmv2BlockingClient {
+        CloudHsmv2BlockingClient::from_client(CloudHsmv2Client::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CloudHsmv2BlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CloudHsmv2BlockingClient::from_client(CloudHsmv2Client::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> CloudHsmv2BlockingClient {
+        CloudHsmv2BlockingClient::from_client(CloudHsmv2Client::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> CloudHsmv2BlockingClient {
+        CloudHsmv2BlockingClient::from_client(CloudHsmv2Client::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: CloudHsmv2Client) -> CloudHsmv2BlockingClient {
+        CloudHsmv2BlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> CloudHsmv2BlockingClient {
+        CloudHsmv2BlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CloudHsmv2BlockingClient {
+        CloudHsmv2BlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`CloudHsmv2::copy_backup_to_region`].
+    pub fn copy_backup_to_region(
+        &self,
+        input: CopyBackupToRegionRequest,
+    ) -> Result<CopyBackupToRegionResponse, RusotoError<CopyBackupToRegionError>> {
+        self.runtime
+            .block_on(self.client.copy_backup_to_region(input))
+    }
+
+    /// Blocking version of [`CloudHsmv2::create_cluster`].
+    pub fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> Result<CreateClusterResponse, RusotoError<CreateClusterError>> {
+        self.runtime.block_on(self.client.create_cluster(input))
+    }
+
+    /// Blocking version of [`CloudHsmv2::create_hsm`].
+    pub fn create_hsm(
+        &self,
+        input: CreateHsmRequest,
+    ) -> Result<CreateHsmResponse, RusotoError<CreateHsmError>> {
+        self.runtime.block_on(self.client.create_hsm(input))
+    }
+
+    /// Blocking version of [`CloudHsmv2::delete_backup`].
+    pub fn delete_backup(
+        &self,
+        input: DeleteBackupRequest,
+    ) -> Result<DeleteBackupResponse, RusotoError<DeleteBackupError>> {
+        self.runtime.block_on(self.client.delete_backup(input))
+    }
+
+    /// Blocking version of [`CloudHsmv2::delete_cluster`].
+    pub fn delete_cluster(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> Result<DeleteClusterResponse, RusotoError<DeleteClusterError>> {
+        self.runtime.block_on(self.client.delete_cluster(input))
+    }
+
+    /// Blocking version of [`CloudHsmv2::delete_hsm`].
+    pub fn delete_hsm(
+        &self,
+        input: DeleteHsmRequest,
+    ) -> Result<DeleteHsmResponse, RusotoError<DeleteHsmError>> {
+        self.runtime.block_on(self.client.delete_hsm(input))
+    }
+
+    /// Blocking version of [`CloudHsmv2::describe_backups`].
+    pub fn describe_backups(
+        &self,
+        input: DescribeBackupsRequest,
+    ) -> Result<DescribeBackupsResponse, RusotoError<DescribeBackupsError>> {
+        self.runtime.block_on(self.client.describe_backups(input))
+    }
+
+    /// Blocking version of [`CloudHsmv2::describe_clusters`].
+    pub fn describe_clusters(
+        &self,
+        input: DescribeClustersRequest,
+    ) -> Result<DescribeClustersResponse, RusotoError<DescribeClustersError>> {
+        self.runtime.block_on(self.client.describe_clusters(input))
+    }
+
+    /// Blocking version of [`CloudHsmv2::initialize_cluster`].
+    pub fn initialize_cluster(
+        &self,
+        input: InitializeClusterRequest,
+    ) -> Result<InitializeClusterResponse, RusotoError<InitializeClusterError>> {
+        self.runtime.block_on(self.client.initialize_cluster(input))
+    }
+
+    /// Blocking version of [`CloudHsmv2::list_tags`].
+    pub fn list_tags(
+        &self,
+        input: ListTagsRequest,
+    ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
+        self.runtime.block_on(self.client.list_tags(input))
+    }
+
+    /// Blocking version of [`CloudHsmv2::modify_backup_attributes`].
+    pub fn modify_backup_attributes(
+        &self,
+        input: ModifyBackupAttributesRequest,
+    ) -> Result<ModifyBackupAttributesResponse, RusotoError<ModifyBackupAttributesError>> {
+        self.runtime
+            .block_on(self.client.modify_backup_attributes(input))
+    }
+
+    /// Blocking version of [`CloudHsmv2::modify_cluster`].
+    pub fn modify_cluster(
+        &self,
+        input: ModifyClusterRequest,
+    ) -> Result<ModifyClusterResponse, RusotoError<ModifyClusterError>> {
+        self.runtime.block_on(self.client.modify_cluster(input))
+    }
+
+    /// Blocking version of [`CloudHsmv2::restore_backup`].
+    pub fn restore_backup(
+        &self,
+        input: RestoreBackupRequest,
+    ) -> Result<RestoreBackupResponse, RusotoError<RestoreBackupError>> {
+        self.runtime.block_on(self.client.restore_backup(input))
+    }
+
+    /// Blocking version of [`CloudHsmv2::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`CloudHsmv2::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -4108,19 +4109,28 @@ impl BuildSuggestersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(BuildSuggestersError::Base(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            BuildSuggestersError::Base,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(BuildSuggestersError::Internal(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            BuildSuggestersError::Internal,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(BuildSuggestersError::ResourceNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            BuildSuggestersError::ResourceNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -4168,17 +4178,28 @@ impl CreateDomainError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(CreateDomainError::Base(parsed_error.message))
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDomainError::Base,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(CreateDomainError::Internal(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateDomainError::Internal,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateDomainError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateDomainError::LimitExceeded,
+                        )
                     }
                     _ => {}
                 }
@@ -4230,29 +4251,44 @@ impl DefineAnalysisSchemeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DefineAnalysisSchemeError::Base(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DefineAnalysisSchemeError::Base,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DefineAnalysisSchemeError::Internal(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DefineAnalysisSchemeError::Internal,
+                        )
                     }
                     "InvalidType" => {
-                        return RusotoError::Service(DefineAnalysisSchemeError::InvalidType(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DefineAnalysisSchemeError::InvalidType,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(DefineAnalysisSchemeError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DefineAnalysisSchemeError::LimitExceeded,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(DefineAnalysisSchemeError::ResourceNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DefineAnalysisSchemeError::ResourceNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -4306,29 +4342,44 @@ impl DefineExpressionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DefineExpressionError::Base(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DefineExpressionError::Base,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DefineExpressionError::Internal(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DefineExpressionError::Internal,
+                        )
                     }
                     "InvalidType" => {
-                        return RusotoError::Service(DefineExpressionError::InvalidType(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DefineExpressionError::InvalidType,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(DefineExpressionError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DefineExpressionError::LimitExceeded,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(DefineExpressionError::ResourceNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DefineExpressionError::ResourceNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -4382,29 +4433,44 @@ impl DefineIndexFieldError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DefineIndexFieldError::Base(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DefineIndexFieldError::Base,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DefineIndexFieldError::Internal(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DefineIndexFieldError::Internal,
+                        )
                     }
                     "InvalidType" => {
-                        return RusotoError::Service(DefineIndexFieldError::InvalidType(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DefineIndexFieldError::InvalidType,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(DefineIndexFieldError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DefineIndexFieldError::LimitExceeded,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(DefineIndexFieldError::ResourceNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DefineIndexFieldError::ResourceNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -4458,29 +4524,44 @@ impl DefineSuggesterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DefineSuggesterError::Base(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DefineSuggesterError::Base,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DefineSuggesterError::Internal(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DefineSuggesterError::Internal,
+                        )
                     }
                     "InvalidType" => {
-                        return RusotoError::Service(DefineSuggesterError::InvalidType(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DefineSuggesterError::InvalidType,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(DefineSuggesterError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DefineSuggesterError::LimitExceeded,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(DefineSuggesterError::ResourceNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DefineSuggesterError::ResourceNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -4532,24 +4613,36 @@ impl DeleteAnalysisSchemeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DeleteAnalysisSchemeError::Base(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteAnalysisSchemeError::Base,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DeleteAnalysisSchemeError::Internal(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteAnalysisSchemeError::Internal,
+                        )
                     }
                     "InvalidType" => {
-                        return RusotoError::Service(DeleteAnalysisSchemeError::InvalidType(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteAnalysisSchemeError::InvalidType,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(DeleteAnalysisSchemeError::ResourceNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteAnalysisSchemeError::ResourceNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -4596,12 +4689,20 @@ impl DeleteDomainError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DeleteDomainError::Base(parsed_error.message))
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDomainError::Base,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DeleteDomainError::Internal(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteDomainError::Internal,
+                        )
                     }
                     _ => {}
                 }
@@ -4650,24 +4751,36 @@ impl DeleteExpressionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DeleteExpressionError::Base(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteExpressionError::Base,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DeleteExpressionError::Internal(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteExpressionError::Internal,
+                        )
                     }
                     "InvalidType" => {
-                        return RusotoError::Service(DeleteExpressionError::InvalidType(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteExpressionError::InvalidType,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(DeleteExpressionError::ResourceNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteExpressionError::ResourceNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -4718,24 +4831,36 @@ impl DeleteIndexFieldError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DeleteIndexFieldError::Base(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteIndexFieldError::Base,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DeleteIndexFieldError::Internal(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteIndexFieldError::Internal,
+                        )
                     }
                     "InvalidType" => {
-                        return RusotoError::Service(DeleteIndexFieldError::InvalidType(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteIndexFieldError::InvalidType,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(DeleteIndexFieldError::ResourceNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteIndexFieldError::ResourceNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -4786,24 +4911,36 @@ impl DeleteSuggesterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DeleteSuggesterError::Base(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteSuggesterError::Base,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DeleteSuggesterError::Internal(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteSuggesterError::Internal,
+                        )
                     }
                     "InvalidType" => {
-                        return RusotoError::Service(DeleteSuggesterError::InvalidType(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteSuggesterError::InvalidType,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(DeleteSuggesterError::ResourceNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteSuggesterError::ResourceNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -4852,18 +4989,27 @@ impl DescribeAnalysisSchemesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DescribeAnalysisSchemesError::Base(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeAnalysisSchemesError::Base,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DescribeAnalysisSchemesError::Internal(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeAnalysisSchemesError::Internal,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(
-                            DescribeAnalysisSchemesError::ResourceNotFound(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeAnalysisSchemesError::ResourceNotFound,
                         )
                     }
                     _ => {}
@@ -4920,37 +5066,51 @@ impl DescribeAvailabilityOptionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DescribeAvailabilityOptionsError::Base(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeAvailabilityOptionsError::Base,
+                        )
                     }
                     "DisabledAction" => {
-                        return RusotoError::Service(
-                            DescribeAvailabilityOptionsError::DisabledOperation(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeAvailabilityOptionsError::DisabledOperation,
                         )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DescribeAvailabilityOptionsError::Internal(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeAvailabilityOptionsError::Internal,
+                        )
                     }
                     "InvalidType" => {
-                        return RusotoError::Service(DescribeAvailabilityOptionsError::InvalidType(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeAvailabilityOptionsError::InvalidType,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(
-                            DescribeAvailabilityOptionsError::LimitExceeded(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeAvailabilityOptionsError::LimitExceeded,
                         )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(
-                            DescribeAvailabilityOptionsError::ResourceNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeAvailabilityOptionsError::ResourceNotFound,
                         )
                     }
                     _ => {}
@@ -5010,32 +5170,43 @@ impl DescribeDomainEndpointOptionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DescribeDomainEndpointOptionsError::Base(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeDomainEndpointOptionsError::Base,
+                        )
                     }
                     "DisabledAction" => {
-                        return RusotoError::Service(
-                            DescribeDomainEndpointOptionsError::DisabledOperation(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeDomainEndpointOptionsError::DisabledOperation,
                         )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DescribeDomainEndpointOptionsError::Internal(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeDomainEndpointOptionsError::Internal,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(
-                            DescribeDomainEndpointOptionsError::LimitExceeded(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeDomainEndpointOptionsError::LimitExceeded,
                         )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(
-                            DescribeDomainEndpointOptionsError::ResourceNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeDomainEndpointOptionsError::ResourceNotFound,
                         )
                     }
                     _ => {}
@@ -5088,14 +5259,20 @@ impl DescribeDomainsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DescribeDomainsError::Base(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeDomainsError::Base,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DescribeDomainsError::Internal(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeDomainsError::Internal,
+                        )
                     }
                     _ => {}
                 }
@@ -5142,19 +5319,28 @@ impl DescribeExpressionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DescribeExpressionsError::Base(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeExpressionsError::Base,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DescribeExpressionsError::Internal(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeExpressionsError::Internal,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(DescribeExpressionsError::ResourceNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeExpressionsError::ResourceNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -5202,19 +5388,28 @@ impl DescribeIndexFieldsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DescribeIndexFieldsError::Base(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeIndexFieldsError::Base,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DescribeIndexFieldsError::Internal(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeIndexFieldsError::Internal,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(DescribeIndexFieldsError::ResourceNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeIndexFieldsError::ResourceNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -5262,18 +5457,27 @@ impl DescribeScalingParametersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DescribeScalingParametersError::Base(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeScalingParametersError::Base,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DescribeScalingParametersError::Internal(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeScalingParametersError::Internal,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(
-                            DescribeScalingParametersError::ResourceNotFound(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeScalingParametersError::ResourceNotFound,
                         )
                     }
                     _ => {}
@@ -5324,20 +5528,27 @@ impl DescribeServiceAccessPoliciesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DescribeServiceAccessPoliciesError::Base(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeServiceAccessPoliciesError::Base,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DescribeServiceAccessPoliciesError::Internal(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeServiceAccessPoliciesError::Internal,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(
-                            DescribeServiceAccessPoliciesError::ResourceNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeServiceAccessPoliciesError::ResourceNotFound,
                         )
                     }
                     _ => {}
@@ -5388,19 +5599,28 @@ impl DescribeSuggestersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(DescribeSuggestersError::Base(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeSuggestersError::Base,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(DescribeSuggestersError::Internal(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeSuggestersError::Internal,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(DescribeSuggestersError::ResourceNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeSuggestersError::ResourceNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -5448,19 +5668,28 @@ impl IndexDocumentsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(IndexDocumentsError::Base(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            IndexDocumentsError::Base,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(IndexDocumentsError::Internal(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            IndexDocumentsError::Internal,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(IndexDocumentsError::ResourceNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            IndexDocumentsError::ResourceNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -5504,9 +5733,12 @@ impl ListDomainNamesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(ListDomainNamesError::Base(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListDomainNamesError::Base,
+                        )
                     }
                     _ => {}
                 }
@@ -5560,39 +5792,60 @@ impl UpdateAvailabilityOptionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(UpdateAvailabilityOptionsError::Base(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateAvailabilityOptionsError::Base,
+                        )
                     }
                     "DisabledAction" => {
-                        return RusotoError::Service(
-                            UpdateAvailabilityOptionsError::DisabledOperation(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateAvailabilityOptionsError::DisabledOperation,
                         )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(UpdateAvailabilityOptionsError::Internal(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateAvailabilityOptionsError::Internal,
+                        )
                     }
                     "InvalidType" => {
-                        return RusotoError::Service(UpdateAvailabilityOptionsError::InvalidType(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateAvailabilityOptionsError::InvalidType,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(UpdateAvailabilityOptionsError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateAvailabilityOptionsError::LimitExceeded,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(
-                            UpdateAvailabilityOptionsError::ResourceNotFound(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateAvailabilityOptionsError::ResourceNotFound,
                         )
                     }
                     "ValidationException" => {
-                        return RusotoError::Service(UpdateAvailabilityOptionsError::Validation(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateAvailabilityOptionsError::Validation,
+                        )
                     }
                     _ => {}
                 }
@@ -5654,43 +5907,60 @@ impl UpdateDomainEndpointOptionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(UpdateDomainEndpointOptionsError::Base(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateDomainEndpointOptionsError::Base,
+                        )
                     }
                     "DisabledAction" => {
-                        return RusotoError::Service(
-                            UpdateDomainEndpointOptionsError::DisabledOperation(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateDomainEndpointOptionsError::DisabledOperation,
                         )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(UpdateDomainEndpointOptionsError::Internal(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateDomainEndpointOptionsError::Internal,
+                        )
                     }
                     "InvalidType" => {
-                        return RusotoError::Service(UpdateDomainEndpointOptionsError::InvalidType(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateDomainEndpointOptionsError::InvalidType,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(
-                            UpdateDomainEndpointOptionsError::LimitExceeded(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateDomainEndpointOptionsError::LimitExceeded,
                         )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(
-                            UpdateDomainEndpointOptionsError::ResourceNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateDomainEndpointOptionsError::ResourceNotFound,
                         )
                     }
                     "ValidationException" => {
-                        return RusotoError::Service(UpdateDomainEndpointOptionsError::Validation(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateDomainEndpointOptionsError::Validation,
+                        )
                     }
                     _ => {}
                 }
@@ -5748,28 +6018,43 @@ impl UpdateScalingParametersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(UpdateScalingParametersError::Base(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateScalingParametersError::Base,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(UpdateScalingParametersError::Internal(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateScalingParametersError::Internal,
+                        )
                     }
                     "InvalidType" => {
-                        return RusotoError::Service(UpdateScalingParametersError::InvalidType(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateScalingParametersError::InvalidType,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(UpdateScalingParametersError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateScalingParametersError::LimitExceeded,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(
-                            UpdateScalingParametersError::ResourceNotFound(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateScalingParametersError::ResourceNotFound,
                         )
                     }
                     _ => {}
@@ -5826,30 +6111,43 @@ impl UpdateServiceAccessPoliciesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BaseException" => {
-                        return RusotoError::Service(UpdateServiceAccessPoliciesError::Base(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateServiceAccessPoliciesError::Base,
+                        )
                     }
                     "InternalException" => {
-                        return RusotoError::Service(UpdateServiceAccessPoliciesError::Internal(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateServiceAccessPoliciesError::Internal,
+                        )
                     }
                     "InvalidType" => {
-                        return RusotoError::Service(UpdateServiceAccessPoliciesError::InvalidType(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateServiceAccessPoliciesError::InvalidType,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(
-                            UpdateServiceAccessPoliciesError::LimitExceeded(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateServiceAccessPoliciesError::LimitExceeded,
                         )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(
-                            UpdateServiceAccessPoliciesError::ResourceNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateServiceAccessPoliciesError::ResourceNotFound,
                         )
                     }
                     _ => {}
@@ -6054,11 +6352,12 @@ pub struct CloudSearchClient {
 impl CloudSearchClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> CloudSearchClient {
         CloudSearchClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "CloudSearch"),
         }
     }
 
@@ -6073,12 +6372,32 @@ impl CloudSearchClient {
     {
         CloudSearchClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "CloudSearch"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CloudSearchClient {
-        CloudSearchClient { client, region }
+        CloudSearchClient {
+            client,
+            region: region_for_service(region, "CloudSearch"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> CloudSearchClient {
+        CloudSearchClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("CloudSearch"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CloudSearchClient {
+        CloudSearchClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -6090,6 +6409,7 @@ impl CloudSearch for CloudSearchClient {
         input: BuildSuggestersRequest,
     ) -> Result<BuildSuggestersResponse, RusotoError<BuildSuggestersError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("BuildSuggesters");
         let params = self.new_params("BuildSuggesters");
         let mut params = params;
         BuildSuggestersRequestSerializer::serialize(&mut params, "", &input);
@@ -6121,6 +6441,7 @@ impl CloudSearch for CloudSearchClient {
         input: CreateDomainRequest,
     ) -> Result<CreateDomainResponse, RusotoError<CreateDomainError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("CreateDomain");
         let params = self.new_params("CreateDomain");
         let mut params = params;
         CreateDomainRequestSerializer::serialize(&mut params, "", &input);
@@ -6152,6 +6473,7 @@ impl CloudSearch for CloudSearchClient {
         input: DefineAnalysisSchemeRequest,
     ) -> Result<DefineAnalysisSchemeResponse, RusotoError<DefineAnalysisSchemeError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DefineAnalysisScheme");
         let params = self.new_params("DefineAnalysisScheme");
         let mut params = params;
         DefineAnalysisSchemeRequestSerializer::serialize(&mut params, "", &input);
@@ -6185,6 +6507,7 @@ impl CloudSearch for CloudSearchClient {
         input: DefineExpressionRequest,
     ) -> Result<DefineExpressionResponse, RusotoError<DefineExpressionError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DefineExpression");
         let params = self.new_params("DefineExpression");
         let mut params = params;
         DefineExpressionRequestSerializer::serialize(&mut params, "", &input);
@@ -6216,6 +6539,7 @@ impl CloudSearch for CloudSearchClient {
         input: DefineIndexFieldRequest,
     ) -> Result<DefineIndexFieldResponse, RusotoError<DefineIndexFieldError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DefineIndexField");
         let params = self.new_params("DefineIndexField");
         let mut params = params;
         DefineIndexFieldRequestSerializer::serialize(&mut params, "", &input);
@@ -6247,6 +6571,7 @@ impl CloudSearch for CloudSearchClient {
         input: DefineSuggesterRequest,
     ) -> Result<DefineSuggesterResponse, RusotoError<DefineSuggesterError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DefineSuggester");
         let params = self.new_params("DefineSuggester");
         let mut params = params;
         DefineSuggesterRequestSerializer::serialize(&mut params, "", &input);
@@ -6278,6 +6603,7 @@ impl CloudSearch for CloudSearchClient {
         input: DeleteAnalysisSchemeRequest,
     ) -> Result<DeleteAnalysisSchemeResponse, RusotoError<DeleteAnalysisSchemeError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DeleteAnalysisScheme");
         let params = self.new_params("DeleteAnalysisScheme");
         let mut params = params;
         DeleteAnalysisSchemeRequestSerializer::serialize(&mut params, "", &input);
@@ -6311,6 +6637,7 @@ impl CloudSearch for CloudSearchClient {
         input: DeleteDomainRequest,
     ) -> Result<DeleteDomainResponse, RusotoError<DeleteDomainError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DeleteDomain");
         let params = self.new_params("DeleteDomain");
         let mut params = params;
         DeleteDomainRequestSerializer::serialize(&mut params, "", &input);
@@ -6342,6 +6669,7 @@ impl CloudSearch for CloudSearchClient {
         input: DeleteExpressionRequest,
     ) -> Result<DeleteExpressionResponse, RusotoError<DeleteExpressionError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DeleteExpression");
         let params = self.new_params("DeleteExpression");
         let mut params = params;
         DeleteExpressionRequestSerializer::serialize(&mut params, "", &input);
@@ -6373,6 +6701,7 @@ impl CloudSearch for CloudSearchClient {
         input: DeleteIndexFieldRequest,
     ) -> Result<DeleteIndexFieldResponse, RusotoError<DeleteIndexFieldError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DeleteIndexField");
         let params = self.new_params("DeleteIndexField");
         let mut params = params;
         DeleteIndexFieldRequestSerializer::serialize(&mut params, "", &input);
@@ -6404,6 +6733,7 @@ impl CloudSearch for CloudSearchClient {
         input: DeleteSuggesterRequest,
     ) -> Result<DeleteSuggesterResponse, RusotoError<DeleteSuggesterError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DeleteSuggester");
         let params = self.new_params("DeleteSuggester");
         let mut params = params;
         DeleteSuggesterRequestSerializer::serialize(&mut params, "", &input);
@@ -6435,6 +6765,7 @@ impl CloudSearch for CloudSearchClient {
         input: DescribeAnalysisSchemesRequest,
     ) -> Result<DescribeAnalysisSchemesResponse, RusotoError<DescribeAnalysisSchemesError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DescribeAnalysisSchemes");
         let params = self.new_params("DescribeAnalysisSchemes");
         let mut params = params;
         DescribeAnalysisSchemesRequestSerializer::serialize(&mut params, "", &input);
@@ -6469,6 +6800,7 @@ impl CloudSearch for CloudSearchClient {
     ) -> Result<DescribeAvailabilityOptionsResponse, RusotoError<DescribeAvailabilityOptionsError>>
     {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DescribeAvailabilityOptions");
         let params = self.new_params("DescribeAvailabilityOptions");
         let mut params = params;
         DescribeAvailabilityOptionsRequestSerializer::serialize(&mut params, "", &input);
@@ -6505,6 +6837,7 @@ impl CloudSearch for CloudSearchClient {
         RusotoError<DescribeDomainEndpointOptionsError>,
     > {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DescribeDomainEndpointOptions");
         let params = self.new_params("DescribeDomainEndpointOptions");
         let mut params = params;
         DescribeDomainEndpointOptionsRequestSerializer::serialize(&mut params, "", &input);
@@ -6538,6 +6871,7 @@ impl CloudSearch for CloudSearchClient {
         input: DescribeDomainsRequest,
     ) -> Result<DescribeDomainsResponse, RusotoError<DescribeDomainsError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DescribeDomains");
         let params = self.new_params("DescribeDomains");
         let mut params = params;
         DescribeDomainsRequestSerializer::serialize(&mut params, "", &input);
@@ -6569,6 +6903,7 @@ impl CloudSearch for CloudSearchClient {
         input: DescribeExpressionsRequest,
     ) -> Result<DescribeExpressionsResponse, RusotoError<DescribeExpressionsError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DescribeExpressions");
         let params = self.new_params("DescribeExpressions");
         let mut params = params;
         DescribeExpressionsRequestSerializer::serialize(&mut params, "", &input);
@@ -6602,6 +6937,7 @@ impl CloudSearch for CloudSearchClient {
         input: DescribeIndexFieldsRequest,
     ) -> Result<DescribeIndexFieldsResponse, RusotoError<DescribeIndexFieldsError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DescribeIndexFields");
         let params = self.new_params("DescribeIndexFields");
         let mut params = params;
         DescribeIndexFieldsRequestSerializer::serialize(&mut params, "", &input);
@@ -6636,6 +6972,7 @@ impl CloudSearch for CloudSearchClient {
     ) -> Result<DescribeScalingParametersResponse, RusotoError<DescribeScalingParametersError>>
     {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DescribeScalingParameters");
         let params = self.new_params("DescribeScalingParameters");
         let mut params = params;
         DescribeScalingParametersRequestSerializer::serialize(&mut params, "", &input);
@@ -6672,6 +7009,7 @@ impl CloudSearch for CloudSearchClient {
         RusotoError<DescribeServiceAccessPoliciesError>,
     > {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DescribeServiceAccessPolicies");
         let params = self.new_params("DescribeServiceAccessPolicies");
         let mut params = params;
         DescribeServiceAccessPoliciesRequestSerializer::serialize(&mut params, "", &input);
@@ -6705,6 +7043,7 @@ impl CloudSearch for CloudSearchClient {
         input: DescribeSuggestersRequest,
     ) -> Result<DescribeSuggestersResponse, RusotoError<DescribeSuggestersError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("DescribeSuggesters");
         let params = self.new_params("DescribeSuggesters");
         let mut params = params;
         DescribeSuggestersRequestSerializer::serialize(&mut params, "", &input);
@@ -6738,6 +7077,7 @@ impl CloudSearch for CloudSearchClient {
         input: IndexDocumentsRequest,
     ) -> Result<IndexDocumentsResponse, RusotoError<IndexDocumentsError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("IndexDocuments");
         let params = self.new_params("IndexDocuments");
         let mut params = params;
         IndexDocumentsRequestSerializer::serialize(&mut params, "", &input);
@@ -6768,6 +7108,7 @@ impl CloudSearch for CloudSearchClient {
         &self,
     ) -> Result<ListDomainNamesResponse, RusotoError<ListDomainNamesError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("ListDomainNames");
         let params = self.new_params("ListDomainNames");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -6799,6 +7140,7 @@ impl CloudSearch for CloudSearchClient {
     ) -> Result<UpdateAvailabilityOptionsResponse, RusotoError<UpdateAvailabilityOptionsError>>
     {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("UpdateAvailabilityOptions");
         let params = self.new_params("UpdateAvailabilityOptions");
         let mut params = params;
         UpdateAvailabilityOptionsRequestSerializer::serialize(&mut params, "", &input);
@@ -6833,6 +7175,7 @@ impl CloudSearch for CloudSearchClient {
     ) -> Result<UpdateDomainEndpointOptionsResponse, RusotoError<UpdateDomainEndpointOptionsError>>
     {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("UpdateDomainEndpointOptions");
         let params = self.new_params("UpdateDomainEndpointOptions");
         let mut params = params;
         UpdateDomainEndpointOptionsRequestSerializer::serialize(&mut params, "", &input);
@@ -6866,6 +7209,7 @@ impl CloudSearch for CloudSearchClient {
         input: UpdateScalingParametersRequest,
     ) -> Result<UpdateScalingParametersResponse, RusotoError<UpdateScalingParametersError>> {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("UpdateScalingParameters");
         let params = self.new_params("UpdateScalingParameters");
         let mut params = params;
         UpdateScalingParametersRequestSerializer::serialize(&mut params, "", &input);
@@ -6900,6 +7244,7 @@ impl CloudSearch for CloudSearchClient {
     ) -> Result<UpdateServiceAccessPoliciesResponse, RusotoError<UpdateServiceAccessPoliciesError>>
     {
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, "/");
+        request.set_operation("UpdateServiceAccessPolicies");
         let params = self.new_params("UpdateServiceAccessPolicies");
         let mut params = params;
         UpdateServiceAccessPoliciesRequestSerializer::serialize(&mut params, "", &input);
@@ -6927,3 +7272,310 @@ impl CloudSearch for CloudSearchClient {
         Ok(result)
     }
 }
+
+/// A blocking client for the Amazon CloudSearch API, running the calls of a `CloudSearchClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `CloudSearchClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CloudSearchBlockingClient {
+    client: CloudSearchClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CloudSearchBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> CloudSearchBlockingClient {
+        CloudSearchBlockingClient::from_client(CloudSearchClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CloudSearchBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CloudSearchBlockingClient::from_client(CloudSearchClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> CloudSearchBlockingClient {
+        CloudSearchBlockingClient::from_client(CloudSearchClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> CloudSearchBlockingClient {
+        CloudSearchBlockingClient::from_client(CloudSearchClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: CloudSearchClient) -> CloudSearchBlockingClient {
+        CloudSearchBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> CloudSearchBlockingClient {
+        CloudSearchBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CloudSearchBlockingClient {
+        CloudSearchBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`CloudSearch::build_suggesters`].
+    pub fn build_suggesters(
+        &self,
+        input: BuildSuggestersRequest,
+    ) -> Result<BuildSuggestersResponse, RusotoError<BuildSuggestersError>> {
+        self.runtime.block_on(self.client.build_suggesters(input))
+    }
+
+    /// Blocking version of [`CloudSearch::create_domain`].
+    pub fn create_domain(
+        &self,
+        input: CreateDomainRequest,
+    ) -> Result<CreateDomainResponse, RusotoError<CreateDomainError>> {
+        self.runtime.block_on(self.client.create_domain(input))
+    }
+
+    /// Blocking version of [`CloudSearch::define_analysis_scheme`].
+    pub fn define_analysis_scheme(
+        &self,
+        input: DefineAnalysisSchemeRequest,
+    ) -> Result<DefineAnalysisSchemeResponse, RusotoError<DefineAnalysisSchemeError>> {
+        self.runtime
+            .block_on(self.client.define_analysis_scheme(input))
+    }
+
+    /// Blocking version of [`CloudSearch::define_expression`].
+    pub fn define_expression(
+        &self,
+        input: DefineExpressionRequest,
+    ) -> Result<DefineExpressionResponse, RusotoError<DefineExpressionError>> {
+        self.runtime.block_on(self.client.define_expression(input))
+    }
+
+    /// Blocking version of [`CloudSearch::define_index_field`].
+    pub fn define_index_field(
+        &self,
+        input: DefineIndexFieldRequest,
+    ) -> Result<DefineIndexFieldResponse, RusotoError<DefineIndexFieldError>> {
+        self.runtime.block_on(self.client.define_index_field(input))
+    }
+
+    /// Blocking version of [`CloudSearch::define_suggester`].
+    pub fn define_suggester(
+        &self,
+        input: DefineSuggesterRequest,
+    ) -> Result<DefineSuggesterResponse, RusotoError<DefineSuggesterError>> {
+        self.runtime.block_on(self.client.define_suggester(input))
+    }
+
+    /// Blocking version of [`CloudSearch::delete_analysis_scheme`].
+    pub fn delete_analysis_scheme(
+        &self,
+        input: DeleteAnalysisSchemeRequest,
+    ) -> Result<DeleteAnalysisSchemeResponse, RusotoError<DeleteAnalysisSchemeError>> {
+        self.runtime
+            .block_on(self.client.delete_analysis_scheme(input))
+    }
+
+    /// Blocking version of [`CloudSearch::delete_domain`].
+    pub fn delete_domain(
+        &self,
+        input: DeleteDomainRequest,
+    ) -> Result<DeleteDomainResponse, RusotoError<DeleteDomainError>> {
+        self.runtime.block_on(self.client.delete_domain(input))
+    }
+
+    /// Blocking version of [`CloudSearch::delete_expression`].
+    pub fn delete_expression(
+        &self,
+        input: DeleteExpressionRequest,
+    ) -> Result<DeleteExpressionResponse, RusotoError<DeleteExpressionError>> {
+        self.runtime.block_on(self.client.delete_expression(input))
+    }
+
+    /// Blocking version of [`CloudSearch::delete_index_field`].
+    pub fn delete_index_field(
+        &self,
+        input: DeleteIndexFieldRequest,
+    ) -> Result<DeleteIndexFieldResponse, RusotoError<DeleteIndexFieldError>> {
+        self.runtime.block_on(self.client.delete_index_field(input))
+    }
+
+    /// Blocking version of [`CloudSearch::delete_suggester`].
+    pub fn delete_suggester(
+        &self,
+        input: DeleteSuggesterRequest,
+    ) -> Result<DeleteSuggesterResponse, RusotoError<DeleteSuggesterError>> {
+        self.runtime.block_on(self.client.delete_suggester(input))
+    }
+
+    /// Blocking version of [`CloudSearch::describe_analysis_schemes`].
+    pub fn describe_analysis_schemes(
+        &self,
+        input: DescribeAnalysisSchemesRequest,
+    ) -> Result<DescribeAnalysisSchemesResponse, RusotoError<DescribeAnalysisSchemesError>> {
+        self.runtime
+            .block_on(self.client.describe_analysis_schemes(input))
+    }
+
+    /// Blocking version of [`CloudSearch::describe_availability_options`].
+    pub fn describe_availability_options(
+        &self,
+        input: DescribeAvailabilityOptionsRequest,
+    ) -> Result<DescribeAvailabilityOptionsResponse, RusotoError<DescribeAvailabilityOptionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_availability_options(input))
+    }
+
+    /// Blocking version of [`CloudSearch::describe_domain_endpoint_options`].
+    pub fn describe_domain_endpoint_options(
+        &self,
+        input: DescribeDomainEndpointOptionsRequest,
+    ) -> Result<
+        DescribeDomainEndpointOptionsResponse,
+        RusotoError<DescribeDomainEndpointOptionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_domain_endpoint_options(input))
+    }
+
+    /// Blocking version of [`CloudSearch::describe_domains`].
+    pub fn describe_domains(
+        &self,
+        input: DescribeDomainsRequest,
+    ) -> Result<DescribeDomainsResponse, RusotoError<DescribeDomainsError>> {
+        self.runtime.block_on(self.client.describe_domains(input))
+    }
+
+    /// Blocking version of [`CloudSearch::describe_expressions`].
+    pub fn describe_expressions(
+        &self,
+        input: DescribeExpressionsRequest,
+    ) -> Result<DescribeExpressionsResponse, RusotoError<DescribeExpressionsError>> {
+        self.runtime
+            .block_on(self.client.describe_expressions(input))
+    }
+
+    /// Blocking version of [`CloudSearch::describe_index_fields`].
+    pub fn describe_index_fields(
+        &self,
+        input: DescribeIndexFieldsRequest,
+    ) -> Result<DescribeIndexFieldsResponse, RusotoError<DescribeIndexFieldsError>> {
+        self.runtime
+            .block_on(self.client.describe_index_fields(input))
+    }
+
+    /// Blocking version of [`CloudSearch::describe_scaling_parameters`].
+    pub fn describe_scaling_parameters(
+        &self,
+        input: DescribeScalingParametersRequest,
+    ) -> Result<DescribeScalingParametersResponse, RusotoError<DescribeScalingParametersError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_scaling_parameters(input))
+    }
+
+    /// Blocking version of [`CloudSearch::describe_service_access_policies`].
+    pub fn describe_service_access_policies(
+        &self,
+        input: DescribeServiceAccessPoliciesRequest,
+    ) -> Result<
+        DescribeServiceAccessPoliciesResponse,
+        RusotoError<DescribeServiceAccessPoliciesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_service_access_policies(input))
+    }
+
+    /// Blocking version of [`CloudSearch::describe_suggesters`].
+    pub fn describe_suggesters(
+        &self,
+        input: DescribeSuggestersRequest,
+    ) -> Result<DescribeSuggestersResponse, RusotoError<DescribeSuggestersError>> {
+        self.runtime
+            .block_on(self.client.describe_suggesters(input))
+    }
+
+    /// Blocking version of [`CloudSearch::index_documents`].
+    pub fn index_documents(
+        &self,
+        input: IndexDocumentsRequest,
+    ) -> Result<IndexDocumentsResponse, RusotoError<IndexDocumentsError>> {
+        self.runtime.block_on(self.client.index_documents(input))
+    }
+
+    /// Blocking version of [`CloudSearch::list_domain_names`].
+    pub fn list_domain_names(
+        &self,
+    ) -> Result<ListDomainNamesResponse, RusotoError<ListDomainNamesError>> {
+        self.runtime.block_on(self.client.list_domain_names())
+    }
+
+    /// Blocking version of [`CloudSearch::update_availability_options`].
+    pub fn update_availability_options(
+        &self,
+        input: UpdateAvailabilityOptionsRequest,
+    ) -> Result<UpdateAvailabilityOptionsResponse, RusotoError<UpdateAvailabilityOptionsError>>
+    {
+        self.runtime
+            .block_on(self.client.update_availability_options(input))
+    }
+
+    /// Blocking version of [`CloudSearch::update_domain_endpoint_options`].
+    pub fn update_domain_endpoint_options(
+        &self,
+        input: UpdateDomainEndpointOptionsRequest,
+    ) -> Result<UpdateDomainEndpointOptionsResponse, RusotoError<UpdateDomainEndpointOptionsError>>
+    {
+        self.runtime
+            .block_on(self.client.update_domain_endpoint_options(input))
+    }
+
+    /// Blocking version of [`CloudSearch::update_scaling_parameters`].
+    pub fn update_scaling_parameters(
+        &self,
+        input: UpdateScalingParametersRequest,
+    ) -> Result<UpdateScalingParametersResponse, RusotoError<UpdateScalingParametersError>> {
+        self.runtime
+            .block_on(self.client.update_scaling_parameters(input))
+    }
+
+    /// Blocking version of [`CloudSearch::update_service_access_policies`].
+    pub fn update_service_access_policies(
+        &self,
+        input: UpdateServiceAccessPoliciesRequest,
+    ) -> Result<UpdateServiceAccessPoliciesResponse, RusotoError<UpdateServiceAccessPoliciesError>>
+    {
+        self.runtime
+            .block_on(self.client.update_service_access_policies(input))
+    }
+}
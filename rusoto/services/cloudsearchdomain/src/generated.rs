@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -367,8 +368,10 @@ impl SearchError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SearchError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "SearchException" => return RusotoError::Service(SearchError::Search(err.msg)),
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "SearchException" => {
+                    return RusotoError::service_error(&res, &err.typ, err.msg, SearchError::Search)
+                }
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -395,8 +398,15 @@ impl SuggestError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SuggestError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "SearchException" => return RusotoError::Service(SuggestError::Search(err.msg)),
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "SearchException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SuggestError::Search,
+                    )
+                }
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -424,9 +434,14 @@ impl UploadDocumentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DocumentServiceException" => {
-                    return RusotoError::Service(UploadDocumentsError::DocumentService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UploadDocumentsError::DocumentService,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -473,11 +488,12 @@ pub struct CloudSearchDomainClient {
 impl CloudSearchDomainClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> CloudSearchDomainClient {
         CloudSearchDomainClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "CloudSearch Domain"),
         }
     }
 
@@ -492,12 +508,32 @@ impl CloudSearchDomainClient {
     {
         CloudSearchDomainClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "CloudSearch Domain"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CloudSearchDomainClient {
-        CloudSearchDomainClient { client, region }
+        CloudSearchDomainClient {
+            client,
+            region: region_for_service(region, "CloudSearch Domain"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> CloudSearchDomainClient {
+        CloudSearchDomainClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("CloudSearch Domain"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CloudSearchDomainClient {
+        CloudSearchDomainClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -512,6 +548,7 @@ impl CloudSearchDomain for CloudSearchDomainClient {
         let request_uri = "/2013-01-01/search";
 
         let mut request = SignedRequest::new("GET", "cloudsearch", &self.region, &request_uri);
+        request.set_operation("Search");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("cloudsearchdomain".to_string());
@@ -587,6 +624,7 @@ impl CloudSearchDomain for CloudSearchDomainClient {
         let request_uri = "/2013-01-01/suggest";
 
         let mut request = SignedRequest::new("GET", "cloudsearch", &self.region, &request_uri);
+        request.set_operation("Suggest");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("cloudsearchdomain".to_string());
@@ -627,6 +665,7 @@ impl CloudSearchDomain for CloudSearchDomainClient {
         let request_uri = "/2013-01-01/documents/batch";
 
         let mut request = SignedRequest::new("POST", "cloudsearch", &self.region, &request_uri);
+        request.set_operation("UploadDocuments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("cloudsearchdomain".to_string());
@@ -654,3 +693,109 @@ impl CloudSearchDomain for CloudSearchDomainClient {
         }
     }
 }
+
+/// A blocking client for the Amazon CloudSearch Domain API, running the calls of a `CloudSearchDomainClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `CloudSearchDomainClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CloudSearchDomainBlockingClient {
+    client: CloudSearchDomainClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CloudSearchDomainBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> CloudSearchDomainBlockingClient {
+        CloudSearchDomainBlockingClient::from_client(CloudSearchDomainClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CloudSearchDomainBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CloudSearchDomainBlockingClient::from_client(CloudSearchDomainClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> CloudSearchDomainBlockingClient {
+        CloudSearchDomainBlockingClient::from_client(CloudSearchDomainClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> CloudSearchDomainBlockingClient {
+        CloudSearchDomainBlockingClient::from_client(CloudSearchDomainClient::new_with_config(
+            config,
+        ))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: CloudSearchDomainClient) -> CloudSearchDomainBlockingClient {
+        CloudSearchDomainBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> CloudSearchDomainBlockingClient {
+        CloudSearchDomainBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CloudSearchDomainBlockingClient {
+        CloudSearchDomainBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`CloudSearchDomain::search`].
+    pub fn search(&self, input: SearchRequest) -> Result<SearchResponse, RusotoError<SearchError>> {
+        self.runtime.block_on(self.client.search(input))
+    }
+
+    /// Blocking version of [`CloudSearchDomain::suggest`].
+    pub fn suggest(
+        &self,
+        input: SuggestRequest,
+    ) -> Result<SuggestResponse, RusotoError<SuggestError>> {
+        self.runtime.block_on(self.client.suggest(input))
+    }
+
+    /// Blocking version of [`CloudSearchDomain::upload_documents`].
+    pub fn upload_documents(
+        &self,
+        input: UploadDocumentsRequest,
+    ) -> Result<UploadDocumentsResponse, RusotoError<UploadDocumentsError>> {
+        self.runtime.block_on(self.client.upload_documents(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1007,35 +1008,78 @@ impl AddTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudTrailARNInvalidException" => {
-                    return RusotoError::Service(AddTagsError::CloudTrailARNInvalid(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsError::CloudTrailARNInvalid,
+                    )
                 }
                 "InvalidTagParameterException" => {
-                    return RusotoError::Service(AddTagsError::InvalidTagParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsError::InvalidTagParameter,
+                    )
                 }
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(AddTagsError::InvalidTrailName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsError::InvalidTrailName,
+                    )
                 }
                 "NotOrganizationMasterAccountException" => {
-                    return RusotoError::Service(AddTagsError::NotOrganizationMasterAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddTagsError::NotOrganizationMasterAccount,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(AddTagsError::OperationNotPermitted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsError::OperationNotPermitted,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsError::ResourceNotFound,
+                    )
                 }
                 "ResourceTypeNotSupportedException" => {
-                    return RusotoError::Service(AddTagsError::ResourceTypeNotSupported(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsError::ResourceTypeNotSupported,
+                    )
                 }
                 "TagsLimitExceededException" => {
-                    return RusotoError::Service(AddTagsError::TagsLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsError::TagsLimitExceeded,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(AddTagsError::UnsupportedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1125,114 +1169,230 @@ impl CreateTrailError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudTrailAccessNotEnabledException" => {
-                    return RusotoError::Service(CreateTrailError::CloudTrailAccessNotEnabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateTrailError::CloudTrailAccessNotEnabled,
+                    )
                 }
                 "CloudTrailInvalidClientTokenIdException" => {
-                    return RusotoError::Service(CreateTrailError::CloudTrailInvalidClientTokenId(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateTrailError::CloudTrailInvalidClientTokenId,
+                    )
                 }
                 "CloudWatchLogsDeliveryUnavailableException" => {
-                    return RusotoError::Service(
-                        CreateTrailError::CloudWatchLogsDeliveryUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTrailError::CloudWatchLogsDeliveryUnavailable,
                     )
                 }
                 "InsufficientDependencyServiceAccessPermissionException" => {
-                    return RusotoError::Service(
-                        CreateTrailError::InsufficientDependencyServiceAccessPermission(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTrailError::InsufficientDependencyServiceAccessPermission,
                     )
                 }
                 "InsufficientEncryptionPolicyException" => {
-                    return RusotoError::Service(CreateTrailError::InsufficientEncryptionPolicy(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateTrailError::InsufficientEncryptionPolicy,
+                    )
                 }
                 "InsufficientS3BucketPolicyException" => {
-                    return RusotoError::Service(CreateTrailError::InsufficientS3BucketPolicy(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateTrailError::InsufficientS3BucketPolicy,
+                    )
                 }
                 "InsufficientSnsTopicPolicyException" => {
-                    return RusotoError::Service(CreateTrailError::InsufficientSnsTopicPolicy(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateTrailError::InsufficientSnsTopicPolicy,
+                    )
                 }
                 "InvalidCloudWatchLogsLogGroupArnException" => {
-                    return RusotoError::Service(
-                        CreateTrailError::InvalidCloudWatchLogsLogGroupArn(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTrailError::InvalidCloudWatchLogsLogGroupArn,
                     )
                 }
                 "InvalidCloudWatchLogsRoleArnException" => {
-                    return RusotoError::Service(CreateTrailError::InvalidCloudWatchLogsRoleArn(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateTrailError::InvalidCloudWatchLogsRoleArn,
+                    )
                 }
                 "InvalidKmsKeyIdException" => {
-                    return RusotoError::Service(CreateTrailError::InvalidKmsKeyId(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTrailError::InvalidKmsKeyId,
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(CreateTrailError::InvalidParameterCombination(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateTrailError::InvalidParameterCombination,
+                    )
                 }
                 "InvalidS3BucketNameException" => {
-                    return RusotoError::Service(CreateTrailError::InvalidS3BucketName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTrailError::InvalidS3BucketName,
+                    )
                 }
                 "InvalidS3PrefixException" => {
-                    return RusotoError::Service(CreateTrailError::InvalidS3Prefix(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTrailError::InvalidS3Prefix,
+                    )
                 }
                 "InvalidSnsTopicNameException" => {
-                    return RusotoError::Service(CreateTrailError::InvalidSnsTopicName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTrailError::InvalidSnsTopicName,
+                    )
                 }
                 "InvalidTagParameterException" => {
-                    return RusotoError::Service(CreateTrailError::InvalidTagParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTrailError::InvalidTagParameter,
+                    )
                 }
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(CreateTrailError::InvalidTrailName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTrailError::InvalidTrailName,
+                    )
+                }
+                "KmsException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTrailError::Kms,
+                    )
                 }
-                "KmsException" => return RusotoError::Service(CreateTrailError::Kms(err.msg)),
                 "KmsKeyDisabledException" => {
-                    return RusotoError::Service(CreateTrailError::KmsKeyDisabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTrailError::KmsKeyDisabled,
+                    )
                 }
                 "KmsKeyNotFoundException" => {
-                    return RusotoError::Service(CreateTrailError::KmsKeyNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTrailError::KmsKeyNotFound,
+                    )
                 }
                 "MaximumNumberOfTrailsExceededException" => {
-                    return RusotoError::Service(CreateTrailError::MaximumNumberOfTrailsExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateTrailError::MaximumNumberOfTrailsExceeded,
+                    )
                 }
                 "NotOrganizationMasterAccountException" => {
-                    return RusotoError::Service(CreateTrailError::NotOrganizationMasterAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateTrailError::NotOrganizationMasterAccount,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(CreateTrailError::OperationNotPermitted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTrailError::OperationNotPermitted,
+                    )
                 }
                 "OrganizationNotInAllFeaturesModeException" => {
-                    return RusotoError::Service(
-                        CreateTrailError::OrganizationNotInAllFeaturesMode(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTrailError::OrganizationNotInAllFeaturesMode,
                     )
                 }
                 "OrganizationsNotInUseException" => {
-                    return RusotoError::Service(CreateTrailError::OrganizationsNotInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTrailError::OrganizationsNotInUse,
+                    )
                 }
                 "S3BucketDoesNotExistException" => {
-                    return RusotoError::Service(CreateTrailError::S3BucketDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTrailError::S3BucketDoesNotExist,
+                    )
                 }
                 "TrailAlreadyExistsException" => {
-                    return RusotoError::Service(CreateTrailError::TrailAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTrailError::TrailAlreadyExists,
+                    )
                 }
                 "TrailNotProvidedException" => {
-                    return RusotoError::Service(CreateTrailError::TrailNotProvided(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTrailError::TrailNotProvided,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(CreateTrailError::UnsupportedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTrailError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1303,31 +1463,62 @@ impl DeleteTrailError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InsufficientDependencyServiceAccessPermissionException" => {
-                    return RusotoError::Service(
-                        DeleteTrailError::InsufficientDependencyServiceAccessPermission(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTrailError::InsufficientDependencyServiceAccessPermission,
                     )
                 }
                 "InvalidHomeRegionException" => {
-                    return RusotoError::Service(DeleteTrailError::InvalidHomeRegion(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTrailError::InvalidHomeRegion,
+                    )
                 }
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(DeleteTrailError::InvalidTrailName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTrailError::InvalidTrailName,
+                    )
                 }
                 "NotOrganizationMasterAccountException" => {
-                    return RusotoError::Service(DeleteTrailError::NotOrganizationMasterAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteTrailError::NotOrganizationMasterAccount,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(DeleteTrailError::OperationNotPermitted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTrailError::OperationNotPermitted,
+                    )
                 }
                 "TrailNotFoundException" => {
-                    return RusotoError::Service(DeleteTrailError::TrailNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTrailError::TrailNotFound,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DeleteTrailError::UnsupportedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTrailError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1367,17 +1558,30 @@ impl DescribeTrailsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(DescribeTrailsError::InvalidTrailName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTrailsError::InvalidTrailName,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(DescribeTrailsError::OperationNotPermitted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeTrailsError::OperationNotPermitted,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DescribeTrailsError::UnsupportedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTrailsError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1413,22 +1617,38 @@ impl GetEventSelectorsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(GetEventSelectorsError::InvalidTrailName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEventSelectorsError::InvalidTrailName,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(GetEventSelectorsError::OperationNotPermitted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetEventSelectorsError::OperationNotPermitted,
+                    )
                 }
                 "TrailNotFoundException" => {
-                    return RusotoError::Service(GetEventSelectorsError::TrailNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEventSelectorsError::TrailNotFound,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(GetEventSelectorsError::UnsupportedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetEventSelectorsError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1467,29 +1687,46 @@ impl GetInsightSelectorsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InsightNotEnabledException" => {
-                    return RusotoError::Service(GetInsightSelectorsError::InsightNotEnabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetInsightSelectorsError::InsightNotEnabled,
+                    )
                 }
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(GetInsightSelectorsError::InvalidTrailName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetInsightSelectorsError::InvalidTrailName,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(GetInsightSelectorsError::OperationNotPermitted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetInsightSelectorsError::OperationNotPermitted,
+                    )
                 }
                 "TrailNotFoundException" => {
-                    return RusotoError::Service(GetInsightSelectorsError::TrailNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInsightSelectorsError::TrailNotFound,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(GetInsightSelectorsError::UnsupportedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetInsightSelectorsError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1527,18 +1764,38 @@ impl GetTrailError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(GetTrailError::InvalidTrailName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTrailError::InvalidTrailName,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(GetTrailError::OperationNotPermitted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTrailError::OperationNotPermitted,
+                    )
                 }
                 "TrailNotFoundException" => {
-                    return RusotoError::Service(GetTrailError::TrailNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTrailError::TrailNotFound,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(GetTrailError::UnsupportedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTrailError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1575,20 +1832,38 @@ impl GetTrailStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(GetTrailStatusError::InvalidTrailName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTrailStatusError::InvalidTrailName,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(GetTrailStatusError::OperationNotPermitted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetTrailStatusError::OperationNotPermitted,
+                    )
                 }
                 "TrailNotFoundException" => {
-                    return RusotoError::Service(GetTrailStatusError::TrailNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTrailStatusError::TrailNotFound,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(GetTrailStatusError::UnsupportedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTrailStatusError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1625,20 +1900,38 @@ impl ListPublicKeysError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidTimeRangeException" => {
-                    return RusotoError::Service(ListPublicKeysError::InvalidTimeRange(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPublicKeysError::InvalidTimeRange,
+                    )
                 }
                 "InvalidTokenException" => {
-                    return RusotoError::Service(ListPublicKeysError::InvalidToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPublicKeysError::InvalidToken,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(ListPublicKeysError::OperationNotPermitted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPublicKeysError::OperationNotPermitted,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(ListPublicKeysError::UnsupportedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPublicKeysError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1681,27 +1974,62 @@ impl ListTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudTrailARNInvalidException" => {
-                    return RusotoError::Service(ListTagsError::CloudTrailARNInvalid(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsError::CloudTrailARNInvalid,
+                    )
                 }
                 "InvalidTokenException" => {
-                    return RusotoError::Service(ListTagsError::InvalidToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsError::InvalidToken,
+                    )
                 }
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(ListTagsError::InvalidTrailName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsError::InvalidTrailName,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(ListTagsError::OperationNotPermitted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsError::OperationNotPermitted,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsError::ResourceNotFound,
+                    )
                 }
                 "ResourceTypeNotSupportedException" => {
-                    return RusotoError::Service(ListTagsError::ResourceTypeNotSupported(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsError::ResourceTypeNotSupported,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(ListTagsError::UnsupportedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1737,12 +2065,22 @@ impl ListTrailsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(ListTrailsError::OperationNotPermitted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTrailsError::OperationNotPermitted,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(ListTrailsError::UnsupportedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTrailsError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1783,29 +2121,62 @@ impl LookupEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidEventCategoryException" => {
-                    return RusotoError::Service(LookupEventsError::InvalidEventCategory(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        LookupEventsError::InvalidEventCategory,
+                    )
                 }
                 "InvalidLookupAttributesException" => {
-                    return RusotoError::Service(LookupEventsError::InvalidLookupAttributes(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        LookupEventsError::InvalidLookupAttributes,
+                    )
                 }
                 "InvalidMaxResultsException" => {
-                    return RusotoError::Service(LookupEventsError::InvalidMaxResults(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        LookupEventsError::InvalidMaxResults,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(LookupEventsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        LookupEventsError::InvalidNextToken,
+                    )
                 }
                 "InvalidTimeRangeException" => {
-                    return RusotoError::Service(LookupEventsError::InvalidTimeRange(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        LookupEventsError::InvalidTimeRange,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(LookupEventsError::OperationNotPermitted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        LookupEventsError::OperationNotPermitted,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(LookupEventsError::UnsupportedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        LookupEventsError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1853,42 +2224,70 @@ impl PutEventSelectorsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InsufficientDependencyServiceAccessPermissionException" => {
-                    return RusotoError::Service(
-                        PutEventSelectorsError::InsufficientDependencyServiceAccessPermission(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutEventSelectorsError::InsufficientDependencyServiceAccessPermission,
                     )
                 }
                 "InvalidEventSelectorsException" => {
-                    return RusotoError::Service(PutEventSelectorsError::InvalidEventSelectors(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutEventSelectorsError::InvalidEventSelectors,
+                    )
                 }
                 "InvalidHomeRegionException" => {
-                    return RusotoError::Service(PutEventSelectorsError::InvalidHomeRegion(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutEventSelectorsError::InvalidHomeRegion,
+                    )
                 }
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(PutEventSelectorsError::InvalidTrailName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutEventSelectorsError::InvalidTrailName,
+                    )
                 }
                 "NotOrganizationMasterAccountException" => {
-                    return RusotoError::Service(
-                        PutEventSelectorsError::NotOrganizationMasterAccount(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutEventSelectorsError::NotOrganizationMasterAccount,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(PutEventSelectorsError::OperationNotPermitted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutEventSelectorsError::OperationNotPermitted,
+                    )
                 }
                 "TrailNotFoundException" => {
-                    return RusotoError::Service(PutEventSelectorsError::TrailNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutEventSelectorsError::TrailNotFound,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(PutEventSelectorsError::UnsupportedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutEventSelectorsError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1943,49 +2342,78 @@ impl PutInsightSelectorsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InsufficientEncryptionPolicyException" => {
-                    return RusotoError::Service(
-                        PutInsightSelectorsError::InsufficientEncryptionPolicy(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutInsightSelectorsError::InsufficientEncryptionPolicy,
                     )
                 }
                 "InsufficientS3BucketPolicyException" => {
-                    return RusotoError::Service(
-                        PutInsightSelectorsError::InsufficientS3BucketPolicy(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutInsightSelectorsError::InsufficientS3BucketPolicy,
                     )
                 }
                 "InvalidHomeRegionException" => {
-                    return RusotoError::Service(PutInsightSelectorsError::InvalidHomeRegion(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutInsightSelectorsError::InvalidHomeRegion,
+                    )
                 }
                 "InvalidInsightSelectorsException" => {
-                    return RusotoError::Service(PutInsightSelectorsError::InvalidInsightSelectors(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutInsightSelectorsError::InvalidInsightSelectors,
+                    )
                 }
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(PutInsightSelectorsError::InvalidTrailName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutInsightSelectorsError::InvalidTrailName,
+                    )
                 }
                 "NotOrganizationMasterAccountException" => {
-                    return RusotoError::Service(
-                        PutInsightSelectorsError::NotOrganizationMasterAccount(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutInsightSelectorsError::NotOrganizationMasterAccount,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(PutInsightSelectorsError::OperationNotPermitted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutInsightSelectorsError::OperationNotPermitted,
+                    )
                 }
                 "TrailNotFoundException" => {
-                    return RusotoError::Service(PutInsightSelectorsError::TrailNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutInsightSelectorsError::TrailNotFound,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(PutInsightSelectorsError::UnsupportedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutInsightSelectorsError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2041,32 +2469,70 @@ impl RemoveTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudTrailARNInvalidException" => {
-                    return RusotoError::Service(RemoveTagsError::CloudTrailARNInvalid(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveTagsError::CloudTrailARNInvalid,
+                    )
                 }
                 "InvalidTagParameterException" => {
-                    return RusotoError::Service(RemoveTagsError::InvalidTagParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveTagsError::InvalidTagParameter,
+                    )
                 }
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(RemoveTagsError::InvalidTrailName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveTagsError::InvalidTrailName,
+                    )
                 }
                 "NotOrganizationMasterAccountException" => {
-                    return RusotoError::Service(RemoveTagsError::NotOrganizationMasterAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveTagsError::NotOrganizationMasterAccount,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(RemoveTagsError::OperationNotPermitted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveTagsError::OperationNotPermitted,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RemoveTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveTagsError::ResourceNotFound,
+                    )
                 }
                 "ResourceTypeNotSupportedException" => {
-                    return RusotoError::Service(RemoveTagsError::ResourceTypeNotSupported(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveTagsError::ResourceTypeNotSupported,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(RemoveTagsError::UnsupportedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveTagsError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2113,31 +2579,62 @@ impl StartLoggingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InsufficientDependencyServiceAccessPermissionException" => {
-                    return RusotoError::Service(
-                        StartLoggingError::InsufficientDependencyServiceAccessPermission(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartLoggingError::InsufficientDependencyServiceAccessPermission,
                     )
                 }
                 "InvalidHomeRegionException" => {
-                    return RusotoError::Service(StartLoggingError::InvalidHomeRegion(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartLoggingError::InvalidHomeRegion,
+                    )
                 }
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(StartLoggingError::InvalidTrailName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartLoggingError::InvalidTrailName,
+                    )
                 }
                 "NotOrganizationMasterAccountException" => {
-                    return RusotoError::Service(StartLoggingError::NotOrganizationMasterAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartLoggingError::NotOrganizationMasterAccount,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(StartLoggingError::OperationNotPermitted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartLoggingError::OperationNotPermitted,
+                    )
                 }
                 "TrailNotFoundException" => {
-                    return RusotoError::Service(StartLoggingError::TrailNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartLoggingError::TrailNotFound,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(StartLoggingError::UnsupportedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartLoggingError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2185,31 +2682,62 @@ impl StopLoggingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InsufficientDependencyServiceAccessPermissionException" => {
-                    return RusotoError::Service(
-                        StopLoggingError::InsufficientDependencyServiceAccessPermission(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopLoggingError::InsufficientDependencyServiceAccessPermission,
                     )
                 }
                 "InvalidHomeRegionException" => {
-                    return RusotoError::Service(StopLoggingError::InvalidHomeRegion(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopLoggingError::InvalidHomeRegion,
+                    )
                 }
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(StopLoggingError::InvalidTrailName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopLoggingError::InvalidTrailName,
+                    )
                 }
                 "NotOrganizationMasterAccountException" => {
-                    return RusotoError::Service(StopLoggingError::NotOrganizationMasterAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopLoggingError::NotOrganizationMasterAccount,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(StopLoggingError::OperationNotPermitted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopLoggingError::OperationNotPermitted,
+                    )
                 }
                 "TrailNotFoundException" => {
-                    return RusotoError::Service(StopLoggingError::TrailNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopLoggingError::TrailNotFound,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(StopLoggingError::UnsupportedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopLoggingError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2299,112 +2827,230 @@ impl UpdateTrailError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudTrailAccessNotEnabledException" => {
-                    return RusotoError::Service(UpdateTrailError::CloudTrailAccessNotEnabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateTrailError::CloudTrailAccessNotEnabled,
+                    )
                 }
                 "CloudTrailInvalidClientTokenIdException" => {
-                    return RusotoError::Service(UpdateTrailError::CloudTrailInvalidClientTokenId(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateTrailError::CloudTrailInvalidClientTokenId,
+                    )
                 }
                 "CloudWatchLogsDeliveryUnavailableException" => {
-                    return RusotoError::Service(
-                        UpdateTrailError::CloudWatchLogsDeliveryUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTrailError::CloudWatchLogsDeliveryUnavailable,
                     )
                 }
                 "InsufficientDependencyServiceAccessPermissionException" => {
-                    return RusotoError::Service(
-                        UpdateTrailError::InsufficientDependencyServiceAccessPermission(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTrailError::InsufficientDependencyServiceAccessPermission,
                     )
                 }
                 "InsufficientEncryptionPolicyException" => {
-                    return RusotoError::Service(UpdateTrailError::InsufficientEncryptionPolicy(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateTrailError::InsufficientEncryptionPolicy,
+                    )
                 }
                 "InsufficientS3BucketPolicyException" => {
-                    return RusotoError::Service(UpdateTrailError::InsufficientS3BucketPolicy(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateTrailError::InsufficientS3BucketPolicy,
+                    )
                 }
                 "InsufficientSnsTopicPolicyException" => {
-                    return RusotoError::Service(UpdateTrailError::InsufficientSnsTopicPolicy(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateTrailError::InsufficientSnsTopicPolicy,
+                    )
                 }
                 "InvalidCloudWatchLogsLogGroupArnException" => {
-                    return RusotoError::Service(
-                        UpdateTrailError::InvalidCloudWatchLogsLogGroupArn(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTrailError::InvalidCloudWatchLogsLogGroupArn,
                     )
                 }
                 "InvalidCloudWatchLogsRoleArnException" => {
-                    return RusotoError::Service(UpdateTrailError::InvalidCloudWatchLogsRoleArn(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateTrailError::InvalidCloudWatchLogsRoleArn,
+                    )
                 }
                 "InvalidEventSelectorsException" => {
-                    return RusotoError::Service(UpdateTrailError::InvalidEventSelectors(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTrailError::InvalidEventSelectors,
+                    )
                 }
                 "InvalidHomeRegionException" => {
-                    return RusotoError::Service(UpdateTrailError::InvalidHomeRegion(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTrailError::InvalidHomeRegion,
+                    )
                 }
                 "InvalidKmsKeyIdException" => {
-                    return RusotoError::Service(UpdateTrailError::InvalidKmsKeyId(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTrailError::InvalidKmsKeyId,
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(UpdateTrailError::InvalidParameterCombination(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateTrailError::InvalidParameterCombination,
+                    )
                 }
                 "InvalidS3BucketNameException" => {
-                    return RusotoError::Service(UpdateTrailError::InvalidS3BucketName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTrailError::InvalidS3BucketName,
+                    )
                 }
                 "InvalidS3PrefixException" => {
-                    return RusotoError::Service(UpdateTrailError::InvalidS3Prefix(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTrailError::InvalidS3Prefix,
+                    )
                 }
                 "InvalidSnsTopicNameException" => {
-                    return RusotoError::Service(UpdateTrailError::InvalidSnsTopicName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTrailError::InvalidSnsTopicName,
+                    )
                 }
                 "InvalidTrailNameException" => {
-                    return RusotoError::Service(UpdateTrailError::InvalidTrailName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTrailError::InvalidTrailName,
+                    )
+                }
+                "KmsException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTrailError::Kms,
+                    )
                 }
-                "KmsException" => return RusotoError::Service(UpdateTrailError::Kms(err.msg)),
                 "KmsKeyDisabledException" => {
-                    return RusotoError::Service(UpdateTrailError::KmsKeyDisabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTrailError::KmsKeyDisabled,
+                    )
                 }
                 "KmsKeyNotFoundException" => {
-                    return RusotoError::Service(UpdateTrailError::KmsKeyNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTrailError::KmsKeyNotFound,
+                    )
                 }
                 "NotOrganizationMasterAccountException" => {
-                    return RusotoError::Service(UpdateTrailError::NotOrganizationMasterAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateTrailError::NotOrganizationMasterAccount,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(UpdateTrailError::OperationNotPermitted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTrailError::OperationNotPermitted,
+                    )
                 }
                 "OrganizationNotInAllFeaturesModeException" => {
-                    return RusotoError::Service(
-                        UpdateTrailError::OrganizationNotInAllFeaturesMode(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTrailError::OrganizationNotInAllFeaturesMode,
                     )
                 }
                 "OrganizationsNotInUseException" => {
-                    return RusotoError::Service(UpdateTrailError::OrganizationsNotInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTrailError::OrganizationsNotInUse,
+                    )
                 }
                 "S3BucketDoesNotExistException" => {
-                    return RusotoError::Service(UpdateTrailError::S3BucketDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTrailError::S3BucketDoesNotExist,
+                    )
                 }
                 "TrailNotFoundException" => {
-                    return RusotoError::Service(UpdateTrailError::TrailNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTrailError::TrailNotFound,
+                    )
                 }
                 "TrailNotProvidedException" => {
-                    return RusotoError::Service(UpdateTrailError::TrailNotProvided(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTrailError::TrailNotProvided,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(UpdateTrailError::UnsupportedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTrailError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2572,11 +3218,12 @@ pub struct CloudTrailClient {
 impl CloudTrailClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> CloudTrailClient {
         CloudTrailClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "CloudTrail"),
         }
     }
 
@@ -2591,12 +3238,32 @@ impl CloudTrailClient {
     {
         CloudTrailClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "CloudTrail"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CloudTrailClient {
-        CloudTrailClient { client, region }
+        CloudTrailClient {
+            client,
+            region: region_for_service(region, "CloudTrail"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> CloudTrailClient {
+        CloudTrailClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("CloudTrail"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CloudTrailClient {
+        CloudTrailClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -2608,6 +3275,7 @@ impl CloudTrail for CloudTrailClient {
         input: AddTagsRequest,
     ) -> Result<AddTagsResponse, RusotoError<AddTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AddTags");
         request.add_header(
             "x-amz-target",
             "com.amazonaws.cloudtrail.v20131101.CloudTrail_20131101.AddTags",
@@ -2629,6 +3297,7 @@ impl CloudTrail for CloudTrailClient {
         input: CreateTrailRequest,
     ) -> Result<CreateTrailResponse, RusotoError<CreateTrailError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateTrail");
         request.add_header(
             "x-amz-target",
             "com.amazonaws.cloudtrail.v20131101.CloudTrail_20131101.CreateTrail",
@@ -2650,6 +3319,7 @@ impl CloudTrail for CloudTrailClient {
         input: DeleteTrailRequest,
     ) -> Result<DeleteTrailResponse, RusotoError<DeleteTrailError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteTrail");
         request.add_header(
             "x-amz-target",
             "com.amazonaws.cloudtrail.v20131101.CloudTrail_20131101.DeleteTrail",
@@ -2671,6 +3341,7 @@ impl CloudTrail for CloudTrailClient {
         input: DescribeTrailsRequest,
     ) -> Result<DescribeTrailsResponse, RusotoError<DescribeTrailsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeTrails");
         request.add_header(
             "x-amz-target",
             "com.amazonaws.cloudtrail.v20131101.CloudTrail_20131101.DescribeTrails",
@@ -2692,6 +3363,7 @@ impl CloudTrail for CloudTrailClient {
         input: GetEventSelectorsRequest,
     ) -> Result<GetEventSelectorsResponse, RusotoError<GetEventSelectorsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetEventSelectors");
         request.add_header(
             "x-amz-target",
             "com.amazonaws.cloudtrail.v20131101.CloudTrail_20131101.GetEventSelectors",
@@ -2713,6 +3385,7 @@ impl CloudTrail for CloudTrailClient {
         input: GetInsightSelectorsRequest,
     ) -> Result<GetInsightSelectorsResponse, RusotoError<GetInsightSelectorsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetInsightSelectors");
         request.add_header(
             "x-amz-target",
             "com.amazonaws.cloudtrail.v20131101.CloudTrail_20131101.GetInsightSelectors",
@@ -2734,6 +3407,7 @@ impl CloudTrail for CloudTrailClient {
         input: GetTrailRequest,
     ) -> Result<GetTrailResponse, RusotoError<GetTrailError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetTrail");
         request.add_header(
             "x-amz-target",
             "com.amazonaws.cloudtrail.v20131101.CloudTrail_20131101.GetTrail",
@@ -2755,6 +3429,7 @@ impl CloudTrail for CloudTrailClient {
         input: GetTrailStatusRequest,
     ) -> Result<GetTrailStatusResponse, RusotoError<GetTrailStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetTrailStatus");
         request.add_header(
             "x-amz-target",
             "com.amazonaws.cloudtrail.v20131101.CloudTrail_20131101.GetTrailStatus",
@@ -2776,6 +3451,7 @@ impl CloudTrail for CloudTrailClient {
         input: ListPublicKeysRequest,
     ) -> Result<ListPublicKeysResponse, RusotoError<ListPublicKeysError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListPublicKeys");
         request.add_header(
             "x-amz-target",
             "com.amazonaws.cloudtrail.v20131101.CloudTrail_20131101.ListPublicKeys",
@@ -2797,6 +3473,7 @@ impl CloudTrail for CloudTrailClient {
         input: ListTagsRequest,
     ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTags");
         request.add_header(
             "x-amz-target",
             "com.amazonaws.cloudtrail.v20131101.CloudTrail_20131101.ListTags",
@@ -2818,6 +3495,7 @@ impl CloudTrail for CloudTrailClient {
         input: ListTrailsRequest,
     ) -> Result<ListTrailsResponse, RusotoError<ListTrailsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTrails");
         request.add_header(
             "x-amz-target",
             "com.amazonaws.cloudtrail.v20131101.CloudTrail_20131101.ListTrails",
@@ -2839,6 +3517,7 @@ impl CloudTrail for CloudTrailClient {
         input: LookupEventsRequest,
     ) -> Result<LookupEventsResponse, RusotoError<LookupEventsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("LookupEvents");
         request.add_header(
             "x-amz-target",
             "com.amazonaws.cloudtrail.v20131101.CloudTrail_20131101.LookupEvents",
@@ -2860,6 +3539,7 @@ impl CloudTrail for CloudTrailClient {
         input: PutEventSelectorsRequest,
     ) -> Result<PutEventSelectorsResponse, RusotoError<PutEventSelectorsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutEventSelectors");
         request.add_header(
             "x-amz-target",
             "com.amazonaws.cloudtrail.v20131101.CloudTrail_20131101.PutEventSelectors",
@@ -2881,6 +3561,7 @@ impl CloudTrail for CloudTrailClient {
         input: PutInsightSelectorsRequest,
     ) -> Result<PutInsightSelectorsResponse, RusotoError<PutInsightSelectorsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutInsightSelectors");
         request.add_header(
             "x-amz-target",
             "com.amazonaws.cloudtrail.v20131101.CloudTrail_20131101.PutInsightSelectors",
@@ -2902,6 +3583,7 @@ impl CloudTrail for CloudTrailClient {
         input: RemoveTagsRequest,
     ) -> Result<RemoveTagsResponse, RusotoError<RemoveTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RemoveTags");
         request.add_header(
             "x-amz-target",
             "com.amazonaws.cloudtrail.v20131101.CloudTrail_20131101.RemoveTags",
@@ -2923,6 +3605,7 @@ impl CloudTrail for CloudTrailClient {
         input: StartLoggingRequest,
     ) -> Result<StartLoggingResponse, RusotoError<StartLoggingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartLogging");
         request.add_header(
             "x-amz-target",
             "com.amazonaws.cloudtrail.v20131101.CloudTrail_20131101.StartLogging",
@@ -2944,6 +3627,7 @@ impl CloudTrail for CloudTrailClient {
         input: StopLoggingRequest,
     ) -> Result<StopLoggingResponse, RusotoError<StopLoggingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopLogging");
         request.add_header(
             "x-amz-target",
             "com.amazonaws.cloudtrail.v20131101.CloudTrail_20131101.StopLogging",
@@ -2965,6 +3649,7 @@ impl CloudTrail for CloudTrailClient {
         input: UpdateTrailRequest,
     ) -> Result<UpdateTrailResponse, RusotoError<UpdateTrailError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateTrail");
         request.add_header(
             "x-amz-target",
             "com.amazonaws.cloudtrail.v20131101.CloudTrail_20131101.UpdateTrail",
@@ -2980,3 +3665,226 @@ impl CloudTrail for CloudTrailClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateTrailResponse, _>()
     }
 }
+
+/// A blocking client for the CloudTrail API, running the calls of a `CloudTrailClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `CloudTrailClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CloudTrailBlockingClient {
+    client: CloudTrailClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CloudTrailBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> CloudTrailBlockingClient {
+        CloudTrailBlockingClient::from_client(CloudTrailClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CloudTrailBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CloudTrailBlockingClient::from_client(CloudTrailClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> CloudTrailBlockingClient {
+        CloudTrailBlockingClient::from_client(CloudTrailClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> CloudTrailBlockingClient {
+        CloudTrailBlockingClient::from_client(CloudTrailClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: CloudTrailClient) -> CloudTrailBlockingClient {
+        CloudTrailBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> CloudTrailBlockingClient {
+        CloudTrailBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CloudTrailBlockingClient {
+        CloudTrailBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`CloudTrail::add_tags`].
+    pub fn add_tags(
+        &self,
+        input: AddTagsRequest,
+    ) -> Result<AddTagsResponse, RusotoError<AddTagsError>> {
+        self.runtime.block_on(self.client.add_tags(input))
+    }
+
+    /// Blocking version of [`CloudTrail::create_trail`].
+    pub fn create_trail(
+        &self,
+        input: CreateTrailRequest,
+    ) -> Result<CreateTrailResponse, RusotoError<CreateTrailError>> {
+        self.runtime.block_on(self.client.create_trail(input))
+    }
+
+    /// Blocking version of [`CloudTrail::delete_trail`].
+    pub fn delete_trail(
+        &self,
+        input: DeleteTrailRequest,
+    ) -> Result<DeleteTrailResponse, RusotoError<DeleteTrailError>> {
+        self.runtime.block_on(self.client.delete_trail(input))
+    }
+
+    /// Blocking version of [`CloudTrail::describe_trails`].
+    pub fn describe_trails(
+        &self,
+        input: DescribeTrailsRequest,
+    ) -> Result<DescribeTrailsResponse, RusotoError<DescribeTrailsError>> {
+        self.runtime.block_on(self.client.describe_trails(input))
+    }
+
+    /// Blocking version of [`CloudTrail::get_event_selectors`].
+    pub fn get_event_selectors(
+        &self,
+        input: GetEventSelectorsRequest,
+    ) -> Result<GetEventSelectorsResponse, RusotoError<GetEventSelectorsError>> {
+        self.runtime
+            .block_on(self.client.get_event_selectors(input))
+    }
+
+    /// Blocking version of [`CloudTrail::get_insight_selectors`].
+    pub fn get_insight_selectors(
+        &self,
+        input: GetInsightSelectorsRequest,
+    ) -> Result<GetInsightSelectorsResponse, RusotoError<GetInsightSelectorsError>> {
+        self.runtime
+            .block_on(self.client.get_insight_selectors(input))
+    }
+
+    /// Blocking version of [`CloudTrail::get_trail`].
+    pub fn get_trail(
+        &self,
+        input: GetTrailRequest,
+    ) -> Result<GetTrailResponse, RusotoError<GetTrailError>> {
+        self.runtime.block_on(self.client.get_trail(input))
+    }
+
+    /// Blocking version of [`CloudTrail::get_trail_status`].
+    pub fn get_trail_status(
+        &self,
+        input: GetTrailStatusRequest,
+    ) -> Result<GetTrailStatusResponse, RusotoError<GetTrailStatusError>> {
+        self.runtime.block_on(self.client.get_trail_status(input))
+    }
+
+    /// Blocking version of [`CloudTrail::list_public_keys`].
+    pub fn list_public_keys(
+        &self,
+        input: ListPublicKeysRequest,
+    ) -> Result<ListPublicKeysResponse, RusotoError<ListPublicKeysError>> {
+        self.runtime.block_on(self.client.list_public_keys(input))
+    }
+
+    /// Blocking version of [`CloudTrail::list_tags`].
+    pub fn list_tags(
+        &self,
+        input: ListTagsRequest,
+    ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
+        self.runtime.block_on(self.client.list_tags(input))
+    }
+
+    /// Blocking version of [`CloudTrail::list_trails`].
+    pub fn list_trails(
+        &self,
+        input: ListTrailsRequest,
+    ) -> Result<ListTrailsResponse, RusotoError<ListTrailsError>> {
+        self.runtime.block_on(self.client.list_trails(input))
+    }
+
+    /// Blocking version of [`CloudTrail::lookup_events`].
+    pub fn lookup_events(
+        &self,
+        input: LookupEventsRequest,
+    ) -> Result<LookupEventsResponse, RusotoError<LookupEventsError>> {
+        self.runtime.block_on(self.client.lookup_events(input))
+    }
+
+    /// Blocking version of [`CloudTrail::put_event_selectors`].
+    pub fn put_event_selectors(
+        &self,
+        input: PutEventSelectorsRequest,
+    ) -> Result<PutEventSelectorsResponse, RusotoError<PutEventSelectorsError>> {
+        self.runtime
+            .block_on(self.client.put_event_selectors(input))
+    }
+
+    /// Blocking version of [`CloudTrail::put_insight_selectors`].
+    pub fn put_insight_selectors(
+        &self,
+        input: PutInsightSelectorsRequest,
+    ) -> Result<PutInsightSelectorsResponse, RusotoError<PutInsightSelectorsError>> {
+        self.runtime
+            .block_on(self.client.put_insight_selectors(input))
+    }
+
+    /// Blocking version of [`CloudTrail::remove_tags`].
+    pub fn remove_tags(
+        &self,
+        input: RemoveTagsRequest,
+    ) -> Result<RemoveTagsResponse, RusotoError<RemoveTagsError>> {
+        self.runtime.block_on(self.client.remove_tags(input))
+    }
+
+    /// Blocking version of [`CloudTrail::start_logging`].
+    pub fn start_logging(
+        &self,
+        input: StartLoggingRequest,
+    ) -> Result<StartLoggingResponse, RusotoError<StartLoggingError>> {
+        self.runtime.block_on(self.client.start_logging(input))
+    }
+
+    /// Blocking version of [`CloudTrail::stop_logging`].
+    pub fn stop_logging(
+        &self,
+        input: StopLoggingRequest,
+    ) -> Result<StopLoggingResponse, RusotoError<StopLoggingError>> {
+        self.runtime.block_on(self.client.stop_logging(input))
+    }
+
+    /// Blocking version of [`CloudTrail::update_trail`].
+    pub fn update_trail(
+        &self,
+        input: UpdateTrailRequest,
+    ) -> Result<UpdateTrailResponse, RusotoError<UpdateTrailError>> {
+        self.runtime.block_on(self.client.update_trail(input))
+    }
+}
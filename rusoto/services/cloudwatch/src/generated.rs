@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -4734,9 +4735,12 @@ impl DeleteAlarmsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceNotFound" => {
-                        return RusotoError::Service(DeleteAlarmsError::ResourceNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteAlarmsError::ResourceNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -4784,26 +4788,36 @@ impl DeleteAnomalyDetectorError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InternalServiceError" => {
-                        return RusotoError::Service(
-                            DeleteAnomalyDetectorError::InternalServiceFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteAnomalyDetectorError::InternalServiceFault,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
-                            DeleteAnomalyDetectorError::InvalidParameterValue(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteAnomalyDetectorError::InvalidParameterValue,
                         )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(
-                            DeleteAnomalyDetectorError::MissingRequiredParameter(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteAnomalyDetectorError::MissingRequiredParameter,
                         )
                     }
                     "ResourceNotFoundException" => {
-                        return RusotoError::Service(DeleteAnomalyDetectorError::ResourceNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteAnomalyDetectorError::ResourceNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -4854,19 +4868,28 @@ impl DeleteDashboardsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceNotFound" => {
-                        return RusotoError::Service(DeleteDashboardsError::DashboardNotFoundError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteDashboardsError::DashboardNotFoundError,
+                        )
                     }
                     "InternalServiceError" => {
-                        return RusotoError::Service(DeleteDashboardsError::InternalServiceFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteDashboardsError::InternalServiceFault,
+                        )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(DeleteDashboardsError::InvalidParameterValue(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteDashboardsError::InvalidParameterValue,
+                        )
                     }
                     _ => {}
                 }
@@ -4912,13 +4935,19 @@ impl DeleteInsightRulesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
-                            DeleteInsightRulesError::InvalidParameterValue(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteInsightRulesError::InvalidParameterValue,
                         )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(
-                            DeleteInsightRulesError::MissingRequiredParameter(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteInsightRulesError::MissingRequiredParameter,
                         )
                     }
                     _ => {}
@@ -4962,9 +4991,12 @@ impl DescribeAlarmHistoryError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(DescribeAlarmHistoryError::InvalidNextToken(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeAlarmHistoryError::InvalidNextToken,
+                        )
                     }
                     _ => {}
                 }
@@ -5006,9 +5038,12 @@ impl DescribeAlarmsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(DescribeAlarmsError::InvalidNextToken(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeAlarmsError::InvalidNextToken,
+                        )
                     }
                     _ => {}
                 }
@@ -5088,22 +5123,27 @@ impl DescribeAnomalyDetectorsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InternalServiceError" => {
-                        return RusotoError::Service(
-                            DescribeAnomalyDetectorsError::InternalServiceFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeAnomalyDetectorsError::InternalServiceFault,
                         )
                     }
                     "InvalidNextToken" => {
-                        return RusotoError::Service(
-                            DescribeAnomalyDetectorsError::InvalidNextToken(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeAnomalyDetectorsError::InvalidNextToken,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
-                            DescribeAnomalyDetectorsError::InvalidParameterValue(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeAnomalyDetectorsError::InvalidParameterValue,
                         )
                     }
                     _ => {}
@@ -5152,9 +5192,12 @@ impl DescribeInsightRulesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(DescribeInsightRulesError::InvalidNextToken(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeInsightRulesError::InvalidNextToken,
+                        )
                     }
                     _ => {}
                 }
@@ -5232,15 +5275,19 @@ impl DisableInsightRulesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
-                            DisableInsightRulesError::InvalidParameterValue(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DisableInsightRulesError::InvalidParameterValue,
                         )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(
-                            DisableInsightRulesError::MissingRequiredParameter(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DisableInsightRulesError::MissingRequiredParameter,
                         )
                     }
                     _ => {}
@@ -5322,18 +5369,27 @@ impl EnableInsightRulesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
-                            EnableInsightRulesError::InvalidParameterValue(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            EnableInsightRulesError::InvalidParameterValue,
                         )
                     }
                     "LimitExceededException" => {
-                        return RusotoError::Service(EnableInsightRulesError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            EnableInsightRulesError::LimitExceeded,
+                        )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(
-                            EnableInsightRulesError::MissingRequiredParameter(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            EnableInsightRulesError::MissingRequiredParameter,
                         )
                     }
                     _ => {}
@@ -5382,19 +5438,28 @@ impl GetDashboardError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceNotFound" => {
-                        return RusotoError::Service(GetDashboardError::DashboardNotFoundError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetDashboardError::DashboardNotFoundError,
+                        )
                     }
                     "InternalServiceError" => {
-                        return RusotoError::Service(GetDashboardError::InternalServiceFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetDashboardError::InternalServiceFault,
+                        )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(GetDashboardError::InvalidParameterValue(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetDashboardError::InvalidParameterValue,
+                        )
                     }
                     _ => {}
                 }
@@ -5442,21 +5507,28 @@ impl GetInsightRuleReportError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
-                            GetInsightRuleReportError::InvalidParameterValue(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetInsightRuleReportError::InvalidParameterValue,
                         )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(
-                            GetInsightRuleReportError::MissingRequiredParameter(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetInsightRuleReportError::MissingRequiredParameter,
                         )
                     }
                     "ResourceNotFoundException" => {
-                        return RusotoError::Service(GetInsightRuleReportError::ResourceNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetInsightRuleReportError::ResourceNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -5502,9 +5574,12 @@ impl GetMetricDataError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidNextToken" => {
-                        return RusotoError::Service(GetMetricDataError::InvalidNextToken(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetMetricDataError::InvalidNextToken,
+                        )
                     }
                     _ => {}
                 }
@@ -5552,27 +5627,35 @@ impl GetMetricStatisticsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InternalServiceError" => {
-                        return RusotoError::Service(
-                            GetMetricStatisticsError::InternalServiceFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetMetricStatisticsError::InternalServiceFault,
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
-                            GetMetricStatisticsError::InvalidParameterCombination(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetMetricStatisticsError::InvalidParameterCombination,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
-                            GetMetricStatisticsError::InvalidParameterValue(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetMetricStatisticsError::InvalidParameterValue,
                         )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(
-                            GetMetricStatisticsError::MissingRequiredParameter(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetMetricStatisticsError::MissingRequiredParameter,
                         )
                     }
                     _ => {}
@@ -5656,14 +5739,20 @@ impl ListDashboardsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InternalServiceError" => {
-                        return RusotoError::Service(ListDashboardsError::InternalServiceFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListDashboardsError::InternalServiceFault,
+                        )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(ListDashboardsError::InvalidParameterValue(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListDashboardsError::InvalidParameterValue,
+                        )
                     }
                     _ => {}
                 }
@@ -5708,14 +5797,20 @@ impl ListMetricsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InternalServiceError" => {
-                        return RusotoError::Service(ListMetricsError::InternalServiceFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListMetricsError::InternalServiceFault,
+                        )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(ListMetricsError::InvalidParameterValue(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListMetricsError::InvalidParameterValue,
+                        )
                     }
                     _ => {}
                 }
@@ -5762,19 +5857,28 @@ impl ListTagsForResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InternalServiceError" => {
-                        return RusotoError::Service(
-                            ListTagsForResourceError::InternalServiceFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListTagsForResourceError::InternalServiceFault,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
-                            ListTagsForResourceError::InvalidParameterValue(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListTagsForResourceError::InvalidParameterValue,
                         )
                     }
                     "ResourceNotFoundException" => {
-                        return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListTagsForResourceError::ResourceNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -5824,23 +5928,35 @@ impl PutAnomalyDetectorError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InternalServiceError" => {
-                        return RusotoError::Service(PutAnomalyDetectorError::InternalServiceFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PutAnomalyDetectorError::InternalServiceFault,
+                        )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
-                            PutAnomalyDetectorError::InvalidParameterValue(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            PutAnomalyDetectorError::InvalidParameterValue,
                         )
                     }
                     "LimitExceededException" => {
-                        return RusotoError::Service(PutAnomalyDetectorError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PutAnomalyDetectorError::LimitExceeded,
+                        )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(
-                            PutAnomalyDetectorError::MissingRequiredParameter(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            PutAnomalyDetectorError::MissingRequiredParameter,
                         )
                     }
                     _ => {}
@@ -5886,9 +6002,12 @@ impl PutCompositeAlarmError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(PutCompositeAlarmError::LimitExceededFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PutCompositeAlarmError::LimitExceededFault,
+                        )
                     }
                     _ => {}
                 }
@@ -5932,14 +6051,20 @@ impl PutDashboardError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterInput" => {
-                        return RusotoError::Service(PutDashboardError::DashboardInvalidInputError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PutDashboardError::DashboardInvalidInputError,
+                        )
                     }
                     "InternalServiceError" => {
-                        return RusotoError::Service(PutDashboardError::InternalServiceFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PutDashboardError::InternalServiceFault,
+                        )
                     }
                     _ => {}
                 }
@@ -5986,19 +6111,28 @@ impl PutInsightRuleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(PutInsightRuleError::InvalidParameterValue(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PutInsightRuleError::InvalidParameterValue,
+                        )
                     }
                     "LimitExceededException" => {
-                        return RusotoError::Service(PutInsightRuleError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PutInsightRuleError::LimitExceeded,
+                        )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(PutInsightRuleError::MissingRequiredParameter(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PutInsightRuleError::MissingRequiredParameter,
+                        )
                     }
                     _ => {}
                 }
@@ -6042,9 +6176,12 @@ impl PutMetricAlarmError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(PutMetricAlarmError::LimitExceededFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PutMetricAlarmError::LimitExceededFault,
+                        )
                     }
                     _ => {}
                 }
@@ -6092,24 +6229,36 @@ impl PutMetricDataError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InternalServiceError" => {
-                        return RusotoError::Service(PutMetricDataError::InternalServiceFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PutMetricDataError::InternalServiceFault,
+                        )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
-                            PutMetricDataError::InvalidParameterCombination(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            PutMetricDataError::InvalidParameterCombination,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(PutMetricDataError::InvalidParameterValue(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PutMetricDataError::InvalidParameterValue,
+                        )
                     }
                     "MissingParameter" => {
-                        return RusotoError::Service(PutMetricDataError::MissingRequiredParameter(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PutMetricDataError::MissingRequiredParameter,
+                        )
                     }
                     _ => {}
                 }
@@ -6156,14 +6305,20 @@ impl SetAlarmStateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidFormat" => {
-                        return RusotoError::Service(SetAlarmStateError::InvalidFormatFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SetAlarmStateError::InvalidFormatFault,
+                        )
                     }
                     "ResourceNotFound" => {
-                        return RusotoError::Service(SetAlarmStateError::ResourceNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SetAlarmStateError::ResourceNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -6212,24 +6367,36 @@ impl TagResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModificationException" => {
-                        return RusotoError::Service(TagResourceError::ConcurrentModification(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            TagResourceError::ConcurrentModification,
+                        )
                     }
                     "InternalServiceError" => {
-                        return RusotoError::Service(TagResourceError::InternalServiceFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            TagResourceError::InternalServiceFault,
+                        )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(TagResourceError::InvalidParameterValue(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            TagResourceError::InvalidParameterValue,
+                        )
                     }
                     "ResourceNotFoundException" => {
-                        return RusotoError::Service(TagResourceError::ResourceNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            TagResourceError::ResourceNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -6280,24 +6447,36 @@ impl UntagResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModificationException" => {
-                        return RusotoError::Service(UntagResourceError::ConcurrentModification(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UntagResourceError::ConcurrentModification,
+                        )
                     }
                     "InternalServiceError" => {
-                        return RusotoError::Service(UntagResourceError::InternalServiceFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UntagResourceError::InternalServiceFault,
+                        )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(UntagResourceError::InvalidParameterValue(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UntagResourceError::InvalidParameterValue,
+                        )
                     }
                     "ResourceNotFoundException" => {
-                        return RusotoError::Service(UntagResourceError::ResourceNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UntagResourceError::ResourceNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -6519,11 +6698,12 @@ pub struct CloudWatchClient {
 impl CloudWatchClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> CloudWatchClient {
         CloudWatchClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "CloudWatch"),
         }
     }
 
@@ -6538,12 +6718,32 @@ impl CloudWatchClient {
     {
         CloudWatchClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "CloudWatch"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CloudWatchClient {
-        CloudWatchClient { client, region }
+        CloudWatchClient {
+            client,
+            region: region_for_service(region, "CloudWatch"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> CloudWatchClient {
+        CloudWatchClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("CloudWatch"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CloudWatchClient {
+        CloudWatchClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -6555,6 +6755,7 @@ impl CloudWatch for CloudWatchClient {
         input: DeleteAlarmsInput,
     ) -> Result<(), RusotoError<DeleteAlarmsError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DeleteAlarms");
         let params = self.new_params("DeleteAlarms");
         let mut params = params;
         DeleteAlarmsInputSerializer::serialize(&mut params, "", &input);
@@ -6575,6 +6776,7 @@ impl CloudWatch for CloudWatchClient {
         input: DeleteAnomalyDetectorInput,
     ) -> Result<DeleteAnomalyDetectorOutput, RusotoError<DeleteAnomalyDetectorError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DeleteAnomalyDetector");
         let params = self.new_params("DeleteAnomalyDetector");
         let mut params = params;
         DeleteAnomalyDetectorInputSerializer::serialize(&mut params, "", &input);
@@ -6597,6 +6799,7 @@ impl CloudWatch for CloudWatchClient {
         input: DeleteDashboardsInput,
     ) -> Result<DeleteDashboardsOutput, RusotoError<DeleteDashboardsError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DeleteDashboards");
         let params = self.new_params("DeleteDashboards");
         let mut params = params;
         DeleteDashboardsInputSerializer::serialize(&mut params, "", &input);
@@ -6619,6 +6822,7 @@ impl CloudWatch for CloudWatchClient {
         input: DeleteInsightRulesInput,
     ) -> Result<DeleteInsightRulesOutput, RusotoError<DeleteInsightRulesError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DeleteInsightRules");
         let params = self.new_params("DeleteInsightRules");
         let mut params = params;
         DeleteInsightRulesInputSerializer::serialize(&mut params, "", &input);
@@ -6652,6 +6856,7 @@ impl CloudWatch for CloudWatchClient {
         input: DescribeAlarmHistoryInput,
     ) -> Result<DescribeAlarmHistoryOutput, RusotoError<DescribeAlarmHistoryError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DescribeAlarmHistory");
         let params = self.new_params("DescribeAlarmHistory");
         let mut params = params;
         DescribeAlarmHistoryInputSerializer::serialize(&mut params, "", &input);
@@ -6685,6 +6890,7 @@ impl CloudWatch for CloudWatchClient {
         input: DescribeAlarmsInput,
     ) -> Result<DescribeAlarmsOutput, RusotoError<DescribeAlarmsError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DescribeAlarms");
         let params = self.new_params("DescribeAlarms");
         let mut params = params;
         DescribeAlarmsInputSerializer::serialize(&mut params, "", &input);
@@ -6716,6 +6922,7 @@ impl CloudWatch for CloudWatchClient {
         input: DescribeAlarmsForMetricInput,
     ) -> Result<DescribeAlarmsForMetricOutput, RusotoError<DescribeAlarmsForMetricError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DescribeAlarmsForMetric");
         let params = self.new_params("DescribeAlarmsForMetric");
         let mut params = params;
         DescribeAlarmsForMetricInputSerializer::serialize(&mut params, "", &input);
@@ -6749,6 +6956,7 @@ impl CloudWatch for CloudWatchClient {
         input: DescribeAnomalyDetectorsInput,
     ) -> Result<DescribeAnomalyDetectorsOutput, RusotoError<DescribeAnomalyDetectorsError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DescribeAnomalyDetectors");
         let params = self.new_params("DescribeAnomalyDetectors");
         let mut params = params;
         DescribeAnomalyDetectorsInputSerializer::serialize(&mut params, "", &input);
@@ -6782,6 +6990,7 @@ impl CloudWatch for CloudWatchClient {
         input: DescribeInsightRulesInput,
     ) -> Result<DescribeInsightRulesOutput, RusotoError<DescribeInsightRulesError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DescribeInsightRules");
         let params = self.new_params("DescribeInsightRules");
         let mut params = params;
         DescribeInsightRulesInputSerializer::serialize(&mut params, "", &input);
@@ -6815,6 +7024,7 @@ impl CloudWatch for CloudWatchClient {
         input: DisableAlarmActionsInput,
     ) -> Result<(), RusotoError<DisableAlarmActionsError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DisableAlarmActions");
         let params = self.new_params("DisableAlarmActions");
         let mut params = params;
         DisableAlarmActionsInputSerializer::serialize(&mut params, "", &input);
@@ -6835,6 +7045,7 @@ impl CloudWatch for CloudWatchClient {
         input: DisableInsightRulesInput,
     ) -> Result<DisableInsightRulesOutput, RusotoError<DisableInsightRulesError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("DisableInsightRules");
         let params = self.new_params("DisableInsightRules");
         let mut params = params;
         DisableInsightRulesInputSerializer::serialize(&mut params, "", &input);
@@ -6868,6 +7079,7 @@ impl CloudWatch for CloudWatchClient {
         input: EnableAlarmActionsInput,
     ) -> Result<(), RusotoError<EnableAlarmActionsError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("EnableAlarmActions");
         let params = self.new_params("EnableAlarmActions");
         let mut params = params;
         EnableAlarmActionsInputSerializer::serialize(&mut params, "", &input);
@@ -6888,6 +7100,7 @@ impl CloudWatch for CloudWatchClient {
         input: EnableInsightRulesInput,
     ) -> Result<EnableInsightRulesOutput, RusotoError<EnableInsightRulesError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("EnableInsightRules");
         let params = self.new_params("EnableInsightRules");
         let mut params = params;
         EnableInsightRulesInputSerializer::serialize(&mut params, "", &input);
@@ -6921,6 +7134,7 @@ impl CloudWatch for CloudWatchClient {
         input: GetDashboardInput,
     ) -> Result<GetDashboardOutput, RusotoError<GetDashboardError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("GetDashboard");
         let params = self.new_params("GetDashboard");
         let mut params = params;
         GetDashboardInputSerializer::serialize(&mut params, "", &input);
@@ -6951,6 +7165,7 @@ impl CloudWatch for CloudWatchClient {
         input: GetInsightRuleReportInput,
     ) -> Result<GetInsightRuleReportOutput, RusotoError<GetInsightRuleReportError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("GetInsightRuleReport");
         let params = self.new_params("GetInsightRuleReport");
         let mut params = params;
         GetInsightRuleReportInputSerializer::serialize(&mut params, "", &input);
@@ -6984,6 +7199,7 @@ impl CloudWatch for CloudWatchClient {
         input: GetMetricDataInput,
     ) -> Result<GetMetricDataOutput, RusotoError<GetMetricDataError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("GetMetricData");
         let params = self.new_params("GetMetricData");
         let mut params = params;
         GetMetricDataInputSerializer::serialize(&mut params, "", &input);
@@ -7015,6 +7231,7 @@ impl CloudWatch for CloudWatchClient {
         input: GetMetricStatisticsInput,
     ) -> Result<GetMetricStatisticsOutput, RusotoError<GetMetricStatisticsError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("GetMetricStatistics");
         let params = self.new_params("GetMetricStatistics");
         let mut params = params;
         GetMetricStatisticsInputSerializer::serialize(&mut params, "", &input);
@@ -7048,6 +7265,7 @@ impl CloudWatch for CloudWatchClient {
         input: GetMetricWidgetImageInput,
     ) -> Result<GetMetricWidgetImageOutput, RusotoError<GetMetricWidgetImageError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("GetMetricWidgetImage");
         let params = self.new_params("GetMetricWidgetImage");
         let mut params = params;
         GetMetricWidgetImageInputSerializer::serialize(&mut params, "", &input);
@@ -7081,6 +7299,7 @@ impl CloudWatch for CloudWatchClient {
         input: ListDashboardsInput,
     ) -> Result<ListDashboardsOutput, RusotoError<ListDashboardsError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("ListDashboards");
         let params = self.new_params("ListDashboards");
         let mut params = params;
         ListDashboardsInputSerializer::serialize(&mut params, "", &input);
@@ -7112,6 +7331,7 @@ impl CloudWatch for CloudWatchClient {
         input: ListMetricsInput,
     ) -> Result<ListMetricsOutput, RusotoError<ListMetricsError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("ListMetrics");
         let params = self.new_params("ListMetrics");
         let mut params = params;
         ListMetricsInputSerializer::serialize(&mut params, "", &input);
@@ -7142,6 +7362,7 @@ impl CloudWatch for CloudWatchClient {
         input: ListTagsForResourceInput,
     ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("ListTagsForResource");
         let params = self.new_params("ListTagsForResource");
         let mut params = params;
         ListTagsForResourceInputSerializer::serialize(&mut params, "", &input);
@@ -7175,6 +7396,7 @@ impl CloudWatch for CloudWatchClient {
         input: PutAnomalyDetectorInput,
     ) -> Result<PutAnomalyDetectorOutput, RusotoError<PutAnomalyDetectorError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("PutAnomalyDetector");
         let params = self.new_params("PutAnomalyDetector");
         let mut params = params;
         PutAnomalyDetectorInputSerializer::serialize(&mut params, "", &input);
@@ -7197,6 +7419,7 @@ impl CloudWatch for CloudWatchClient {
         input: PutCompositeAlarmInput,
     ) -> Result<(), RusotoError<PutCompositeAlarmError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("PutCompositeAlarm");
         let params = self.new_params("PutCompositeAlarm");
         let mut params = params;
         PutCompositeAlarmInputSerializer::serialize(&mut params, "", &input);
@@ -7217,6 +7440,7 @@ impl CloudWatch for CloudWatchClient {
         input: PutDashboardInput,
     ) -> Result<PutDashboardOutput, RusotoError<PutDashboardError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("PutDashboard");
         let params = self.new_params("PutDashboard");
         let mut params = params;
         PutDashboardInputSerializer::serialize(&mut params, "", &input);
@@ -7247,6 +7471,7 @@ impl CloudWatch for CloudWatchClient {
         input: PutInsightRuleInput,
     ) -> Result<PutInsightRuleOutput, RusotoError<PutInsightRuleError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("PutInsightRule");
         let params = self.new_params("PutInsightRule");
         let mut params = params;
         PutInsightRuleInputSerializer::serialize(&mut params, "", &input);
@@ -7269,6 +7494,7 @@ impl CloudWatch for CloudWatchClient {
         input: PutMetricAlarmInput,
     ) -> Result<(), RusotoError<PutMetricAlarmError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("PutMetricAlarm");
         let params = self.new_params("PutMetricAlarm");
         let mut params = params;
         PutMetricAlarmInputSerializer::serialize(&mut params, "", &input);
@@ -7289,6 +7515,7 @@ impl CloudWatch for CloudWatchClient {
         input: PutMetricDataInput,
     ) -> Result<(), RusotoError<PutMetricDataError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("PutMetricData");
         let params = self.new_params("PutMetricData");
         let mut params = params;
         PutMetricDataInputSerializer::serialize(&mut params, "", &input);
@@ -7309,6 +7536,7 @@ impl CloudWatch for CloudWatchClient {
         input: SetAlarmStateInput,
     ) -> Result<(), RusotoError<SetAlarmStateError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("SetAlarmState");
         let params = self.new_params("SetAlarmState");
         let mut params = params;
         SetAlarmStateInputSerializer::serialize(&mut params, "", &input);
@@ -7329,6 +7557,7 @@ impl CloudWatch for CloudWatchClient {
         input: TagResourceInput,
     ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("TagResource");
         let params = self.new_params("TagResource");
         let mut params = params;
         TagResourceInputSerializer::serialize(&mut params, "", &input);
@@ -7351,6 +7580,7 @@ impl CloudWatch for CloudWatchClient {
         input: UntagResourceInput,
     ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
         let mut request = SignedRequest::new("POST", "monitoring", &self.region, "/");
+        request.set_operation("UntagResource");
         let params = self.new_params("UntagResource");
         let mut params = params;
         UntagResourceInputSerializer::serialize(&mut params, "", &input);
@@ -7368,6 +7598,337 @@ impl CloudWatch for CloudWatchClient {
     }
 }
 
+/// A blocking client for the CloudWatch API, running the calls of a `CloudWatchClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `CloudWatchClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CloudWatchBlockingClient {
+    client: CloudWatchClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CloudWatchBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> CloudWatchBlockingClient {
+        CloudWatchBlockingClient::from_client(CloudWatchClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CloudWatchBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CloudWatchBlockingClient::from_client(CloudWatchClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> CloudWatchBlockingClient {
+        CloudWatchBlockingClient::from_client(CloudWatchClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> CloudWatchBlockingClient {
+        CloudWatchBlockingClient::from_client(CloudWatchClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: CloudWatchClient) -> CloudWatchBlockingClient {
+        CloudWatchBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> CloudWatchBlockingClient {
+        CloudWatchBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CloudWatchBlockingClient {
+        CloudWatchBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`CloudWatch::delete_alarms`].
+    pub fn delete_alarms(
+        &self,
+        input: DeleteAlarmsInput,
+    ) -> Result<(), RusotoError<DeleteAlarmsError>> {
+        self.runtime.block_on(self.client.delete_alarms(input))
+    }
+
+    /// Blocking version of [`CloudWatch::delete_anomaly_detector`].
+    pub fn delete_anomaly_detector(
+        &self,
+        input: DeleteAnomalyDetectorInput,
+    ) -> Result<DeleteAnomalyDetectorOutput, RusotoError<DeleteAnomalyDetectorError>> {
+        self.runtime
+            .block_on(self.client.delete_anomaly_detector(input))
+    }
+
+    /// Blocking version of [`CloudWatch::delete_dashboards`].
+    pub fn delete_dashboards(
+        &self,
+        input: DeleteDashboardsInput,
+    ) -> Result<DeleteDashboardsOutput, RusotoError<DeleteDashboardsError>> {
+        self.runtime.block_on(self.client.delete_dashboards(input))
+    }
+
+    /// Blocking version of [`CloudWatch::delete_insight_rules`].
+    pub fn delete_insight_rules(
+        &self,
+        input: DeleteInsightRulesInput,
+    ) -> Result<DeleteInsightRulesOutput, RusotoError<DeleteInsightRulesError>> {
+        self.runtime
+            .block_on(self.client.delete_insight_rules(input))
+    }
+
+    /// Blocking version of [`CloudWatch::describe_alarm_history`].
+    pub fn describe_alarm_history(
+        &self,
+        input: DescribeAlarmHistoryInput,
+    ) -> Result<DescribeAlarmHistoryOutput, RusotoError<DescribeAlarmHistoryError>> {
+        self.runtime
+            .block_on(self.client.describe_alarm_history(input))
+    }
+
+    /// Blocking version of [`CloudWatch::describe_alarms`].
+    pub fn describe_alarms(
+        &self,
+        input: DescribeAlarmsInput,
+    ) -> Result<DescribeAlarmsOutput, RusotoError<DescribeAlarmsError>> {
+        self.runtime.block_on(self.client.describe_alarms(input))
+    }
+
+    /// Blocking version of [`CloudWatch::describe_alarms_for_metric`].
+    pub fn describe_alarms_for_metric(
+        &self,
+        input: DescribeAlarmsForMetricInput,
+    ) -> Result<DescribeAlarmsForMetricOutput, RusotoError<DescribeAlarmsForMetricError>> {
+        self.runtime
+            .block_on(self.client.describe_alarms_for_metric(input))
+    }
+
+    /// Blocking version of [`CloudWatch::describe_anomaly_detectors`].
+    pub fn describe_anomaly_detectors(
+        &self,
+        input: DescribeAnomalyDetectorsInput,
+    ) -> Result<DescribeAnomalyDetectorsOutput, RusotoError<DescribeAnomalyDetectorsError>> {
+        self.runtime
+            .block_on(self.client.describe_anomaly_detectors(input))
+    }
+
+    /// Blocking version of [`CloudWatch::describe_insight_rules`].
+    pub fn describe_insight_rules(
+        &self,
+        input: DescribeInsightRulesInput,
+    ) -> Result<DescribeInsightRulesOutput, RusotoError<DescribeInsightRulesError>> {
+        self.runtime
+            .block_on(self.client.describe_insight_rules(input))
+    }
+
+    /// Blocking version of [`CloudWatch::disable_alarm_actions`].
+    pub fn disable_alarm_actions(
+        &self,
+        input: DisableAlarmActionsInput,
+    ) -> Result<(), RusotoError<DisableAlarmActionsError>> {
+        self.runtime
+            .block_on(self.client.disable_alarm_actions(input))
+    }
+
+    /// Blocking version of [`CloudWatch::disable_insight_rules`].
+    pub fn disable_insight_rules(
+        &self,
+        input: DisableInsightRulesInput,
+    ) -> Result<DisableInsightRulesOutput, RusotoError<DisableInsightRulesError>> {
+        self.runtime
+            .block_on(self.client.disable_insight_rules(input))
+    }
+
+    /// Blocking version of [`CloudWatch::enable_alarm_actions`].
+    pub fn enable_alarm_actions(
+        &self,
+        input: EnableAlarmActionsInput,
+    ) -> Result<(), RusotoError<EnableAlarmActionsError>> {
+        self.runtime
+            .block_on(self.client.enable_alarm_actions(input))
+    }
+
+    /// Blocking version of [`CloudWatch::enable_insight_rules`].
+    pub fn enable_insight_rules(
+        &self,
+        input: EnableInsightRulesInput,
+    ) -> Result<EnableInsightRulesOutput, RusotoError<EnableInsightRulesError>> {
+        self.runtime
+            .block_on(self.client.enable_insight_rules(input))
+    }
+
+    /// Blocking version of [`CloudWatch::get_dashboard`].
+    pub fn get_dashboard(
+        &self,
+        input: GetDashboardInput,
+    ) -> Result<GetDashboardOutput, RusotoError<GetDashboardError>> {
+        self.runtime.block_on(self.client.get_dashboard(input))
+    }
+
+    /// Blocking version of [`CloudWatch::get_insight_rule_report`].
+    pub fn get_insight_rule_report(
+        &self,
+        input: GetInsightRuleReportInput,
+    ) -> Result<GetInsightRuleReportOutput, RusotoError<GetInsightRuleReportError>> {
+        self.runtime
+            .block_on(self.client.get_insight_rule_report(input))
+    }
+
+    /// Blocking version of [`CloudWatch::get_metric_data`].
+    pub fn get_metric_data(
+        &self,
+        input: GetMetricDataInput,
+    ) -> Result<GetMetricDataOutput, RusotoError<GetMetricDataError>> {
+        self.runtime.block_on(self.client.get_metric_data(input))
+    }
+
+    /// Blocking version of [`CloudWatch::get_metric_statistics`].
+    pub fn get_metric_statistics(
+        &self,
+        input: GetMetricStatisticsInput,
+    ) -> Result<GetMetricStatisticsOutput, RusotoError<GetMetricStatisticsError>> {
+        self.runtime
+            .block_on(self.client.get_metric_statistics(input))
+    }
+
+    /// Blocking version of [`CloudWatch::get_metric_widget_image`].
+    pub fn get_metric_widget_image(
+        &self,
+        input: GetMetricWidgetImageInput,
+    ) -> Result<GetMetricWidgetImageOutput, RusotoError<GetMetricWidgetImageError>> {
+        self.runtime
+            .block_on(self.client.get_metric_widget_image(input))
+    }
+
+    /// Blocking version of [`CloudWatch::list_dashboards`].
+    pub fn list_dashboards(
+        &self,
+        input: ListDashboardsInput,
+    ) -> Result<ListDashboardsOutput, RusotoError<ListDashboardsError>> {
+        self.runtime.block_on(self.client.list_dashboards(input))
+    }
+
+    /// Blocking version of [`CloudWatch::list_metrics`].
+    pub fn list_metrics(
+        &self,
+        input: ListMetricsInput,
+    ) -> Result<ListMetricsOutput, RusotoError<ListMetricsError>> {
+        self.runtime.block_on(self.client.list_metrics(input))
+    }
+
+    /// Blocking version of [`CloudWatch::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`CloudWatch::put_anomaly_detector`].
+    pub fn put_anomaly_detector(
+        &self,
+        input: PutAnomalyDetectorInput,
+    ) -> Result<PutAnomalyDetectorOutput, RusotoError<PutAnomalyDetectorError>> {
+        self.runtime
+            .block_on(self.client.put_anomaly_detector(input))
+    }
+
+    /// Blocking version of [`CloudWatch::put_composite_alarm`].
+    pub fn put_composite_alarm(
+        &self,
+        input: PutCompositeAlarmInput,
+    ) -> Result<(), RusotoError<PutCompositeAlarmError>> {
+        self.runtime
+            .block_on(self.client.put_composite_alarm(input))
+    }
+
+    /// Blocking version of [`CloudWatch::put_dashboard`].
+    pub fn put_dashboard(
+        &self,
+        input: PutDashboardInput,
+    ) -> Result<PutDashboardOutput, RusotoError<PutDashboardError>> {
+        self.runtime.block_on(self.client.put_dashboard(input))
+    }
+
+    /// Blocking version of [`CloudWatch::put_insight_rule`].
+    pub fn put_insight_rule(
+        &self,
+        input: PutInsightRuleInput,
+    ) -> Result<PutInsightRuleOutput, RusotoError<PutInsightRuleError>> {
+        self.runtime.block_on(self.client.put_insight_rule(input))
+    }
+
+    /// Blocking version of [`CloudWatch::put_metric_alarm`].
+    pub fn put_metric_alarm(
+        &self,
+        input: PutMetricAlarmInput,
+    ) -> Result<(), RusotoError<PutMetricAlarmError>> {
+        self.runtime.block_on(self.client.put_metric_alarm(input))
+    }
+
+    /// Blocking version of [`CloudWatch::put_metric_data`].
+    pub fn put_metric_data(
+        &self,
+        input: PutMetricDataInput,
+    ) -> Result<(), RusotoError<PutMetricDataError>> {
+        self.runtime.block_on(self.client.put_metric_data(input))
+    }
+
+    /// Blocking version of [`CloudWatch::set_alarm_state`].
+    pub fn set_alarm_state(
+        &self,
+        input: SetAlarmStateInput,
+    ) -> Result<(), RusotoError<SetAlarmStateError>> {
+        self.runtime.block_on(self.client.set_alarm_state(input))
+    }
+
+    /// Blocking version of [`CloudWatch::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`CloudWatch::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+}
+
 #[cfg(test)]
 mod protocol_tests {
 
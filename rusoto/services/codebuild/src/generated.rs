@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -2877,9 +2878,14 @@ impl BatchDeleteBuildsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchDeleteBuildsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDeleteBuildsError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2907,9 +2913,14 @@ impl BatchGetBuildBatchesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchGetBuildBatchesError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetBuildBatchesError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2937,9 +2948,14 @@ impl BatchGetBuildsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchGetBuildsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetBuildsError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2967,9 +2983,14 @@ impl BatchGetProjectsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchGetProjectsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetProjectsError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2997,9 +3018,14 @@ impl BatchGetReportGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchGetReportGroupsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetReportGroupsError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3027,9 +3053,14 @@ impl BatchGetReportsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchGetReportsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetReportsError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3061,15 +3092,30 @@ impl CreateProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccountLimitExceededException" => {
-                    return RusotoError::Service(CreateProjectError::AccountLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProjectError::AccountLimitExceeded,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateProjectError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProjectError::InvalidInput,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateProjectError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProjectError::ResourceAlreadyExists,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3103,19 +3149,30 @@ impl CreateReportGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccountLimitExceededException" => {
-                    return RusotoError::Service(CreateReportGroupError::AccountLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateReportGroupError::AccountLimitExceeded,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateReportGroupError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateReportGroupError::InvalidInput,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateReportGroupError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateReportGroupError::ResourceAlreadyExists,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3151,18 +3208,38 @@ impl CreateWebhookError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateWebhookError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWebhookError::InvalidInput,
+                    )
                 }
                 "OAuthProviderException" => {
-                    return RusotoError::Service(CreateWebhookError::OAuthProvider(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWebhookError::OAuthProvider,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateWebhookError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWebhookError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateWebhookError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWebhookError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3193,9 +3270,14 @@ impl DeleteBuildBatchError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteBuildBatchError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBuildBatchError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3223,9 +3305,14 @@ impl DeleteProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteProjectError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProjectError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3253,9 +3340,14 @@ impl DeleteReportError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteReportError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteReportError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3283,9 +3375,14 @@ impl DeleteReportGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteReportGroupError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteReportGroupError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3313,9 +3410,14 @@ impl DeleteResourcePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteResourcePolicyError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3345,16 +3447,22 @@ impl DeleteSourceCredentialsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteSourceCredentialsError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSourceCredentialsError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteSourceCredentialsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSourceCredentialsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3387,15 +3495,30 @@ impl DeleteWebhookError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteWebhookError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteWebhookError::InvalidInput,
+                    )
                 }
                 "OAuthProviderException" => {
-                    return RusotoError::Service(DeleteWebhookError::OAuthProvider(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteWebhookError::OAuthProvider,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteWebhookError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteWebhookError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3425,9 +3548,14 @@ impl DescribeCodeCoveragesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeCodeCoveragesError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCodeCoveragesError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3457,12 +3585,22 @@ impl DescribeTestCasesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeTestCasesError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTestCasesError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeTestCasesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTestCasesError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3493,14 +3631,22 @@ impl GetReportGroupTrendError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetReportGroupTrendError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetReportGroupTrendError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetReportGroupTrendError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetReportGroupTrendError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3531,12 +3677,22 @@ impl GetResourcePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetResourcePolicyError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourcePolicyError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetResourcePolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourcePolicyError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3569,21 +3725,30 @@ impl ImportSourceCredentialsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccountLimitExceededException" => {
-                    return RusotoError::Service(
-                        ImportSourceCredentialsError::AccountLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportSourceCredentialsError::AccountLimitExceeded,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ImportSourceCredentialsError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ImportSourceCredentialsError::InvalidInput,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        ImportSourceCredentialsError::ResourceAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportSourceCredentialsError::ResourceAlreadyExists,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3617,14 +3782,22 @@ impl InvalidateProjectCacheError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(InvalidateProjectCacheError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InvalidateProjectCacheError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(InvalidateProjectCacheError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        InvalidateProjectCacheError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3653,9 +3826,14 @@ impl ListBuildBatchesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListBuildBatchesError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBuildBatchesError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3687,16 +3865,22 @@ impl ListBuildBatchesForProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListBuildBatchesForProjectError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListBuildBatchesForProjectError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListBuildBatchesForProjectError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListBuildBatchesForProjectError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3725,9 +3909,14 @@ impl ListBuildsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListBuildsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBuildsError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3757,14 +3946,22 @@ impl ListBuildsForProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListBuildsForProjectError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBuildsForProjectError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListBuildsForProjectError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListBuildsForProjectError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3791,7 +3988,7 @@ impl ListCuratedEnvironmentImagesError {
     ) -> RusotoError<ListCuratedEnvironmentImagesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3817,9 +4014,14 @@ impl ListProjectsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListProjectsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProjectsError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3847,9 +4049,14 @@ impl ListReportGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListReportGroupsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListReportGroupsError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3877,9 +4084,14 @@ impl ListReportsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListReportsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListReportsError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3909,16 +4121,22 @@ impl ListReportsForReportGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListReportsForReportGroupError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListReportsForReportGroupError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListReportsForReportGroupError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListReportsForReportGroupError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3947,9 +4165,14 @@ impl ListSharedProjectsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListSharedProjectsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSharedProjectsError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3977,9 +4200,14 @@ impl ListSharedReportGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListSharedReportGroupsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSharedReportGroupsError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4007,9 +4235,14 @@ impl ListSourceCredentialsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListSourceCredentialsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSourceCredentialsError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4039,12 +4272,22 @@ impl PutResourcePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(PutResourcePolicyError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutResourcePolicyError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutResourcePolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutResourcePolicyError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4077,15 +4320,30 @@ impl RetryBuildError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccountLimitExceededException" => {
-                    return RusotoError::Service(RetryBuildError::AccountLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RetryBuildError::AccountLimitExceeded,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(RetryBuildError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RetryBuildError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RetryBuildError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RetryBuildError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4117,12 +4375,22 @@ impl RetryBuildBatchError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(RetryBuildBatchError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RetryBuildBatchError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RetryBuildBatchError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RetryBuildBatchError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4155,15 +4423,30 @@ impl StartBuildError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccountLimitExceededException" => {
-                    return RusotoError::Service(StartBuildError::AccountLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartBuildError::AccountLimitExceeded,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StartBuildError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartBuildError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartBuildError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartBuildError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4195,12 +4478,22 @@ impl StartBuildBatchError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(StartBuildBatchError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartBuildBatchError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartBuildBatchError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartBuildBatchError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4231,12 +4524,22 @@ impl StopBuildError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(StopBuildError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopBuildError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopBuildError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopBuildError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4267,12 +4570,22 @@ impl StopBuildBatchError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(StopBuildBatchError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopBuildBatchError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopBuildBatchError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopBuildBatchError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4303,12 +4616,22 @@ impl UpdateProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateProjectError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateProjectError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateProjectError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateProjectError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4339,12 +4662,22 @@ impl UpdateReportGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateReportGroupError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateReportGroupError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateReportGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateReportGroupError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4377,15 +4710,30 @@ impl UpdateWebhookError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateWebhookError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateWebhookError::InvalidInput,
+                    )
                 }
                 "OAuthProviderException" => {
-                    return RusotoError::Service(UpdateWebhookError::OAuthProvider(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateWebhookError::OAuthProvider,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateWebhookError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateWebhookError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4677,11 +5025,12 @@ pub struct CodeBuildClient {
 impl CodeBuildClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> CodeBuildClient {
         CodeBuildClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "CodeBuild"),
         }
     }
 
@@ -4696,12 +5045,32 @@ impl CodeBuildClient {
     {
         CodeBuildClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "CodeBuild"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CodeBuildClient {
-        CodeBuildClient { client, region }
+        CodeBuildClient {
+            client,
+            region: region_for_service(region, "CodeBuild"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> CodeBuildClient {
+        CodeBuildClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("CodeBuild"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CodeBuildClient {
+        CodeBuildClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -4713,6 +5082,7 @@ impl CodeBuild for CodeBuildClient {
         input: BatchDeleteBuildsInput,
     ) -> Result<BatchDeleteBuildsOutput, RusotoError<BatchDeleteBuildsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchDeleteBuilds");
         request.add_header("x-amz-target", "CodeBuild_20161006.BatchDeleteBuilds");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4731,6 +5101,7 @@ impl CodeBuild for CodeBuildClient {
         input: BatchGetBuildBatchesInput,
     ) -> Result<BatchGetBuildBatchesOutput, RusotoError<BatchGetBuildBatchesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchGetBuildBatches");
         request.add_header("x-amz-target", "CodeBuild_20161006.BatchGetBuildBatches");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4749,6 +5120,7 @@ impl CodeBuild for CodeBuildClient {
         input: BatchGetBuildsInput,
     ) -> Result<BatchGetBuildsOutput, RusotoError<BatchGetBuildsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchGetBuilds");
         request.add_header("x-amz-target", "CodeBuild_20161006.BatchGetBuilds");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4767,6 +5139,7 @@ impl CodeBuild for CodeBuildClient {
         input: BatchGetProjectsInput,
     ) -> Result<BatchGetProjectsOutput, RusotoError<BatchGetProjectsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchGetProjects");
         request.add_header("x-amz-target", "CodeBuild_20161006.BatchGetProjects");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4785,6 +5158,7 @@ impl CodeBuild for CodeBuildClient {
         input: BatchGetReportGroupsInput,
     ) -> Result<BatchGetReportGroupsOutput, RusotoError<BatchGetReportGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchGetReportGroups");
         request.add_header("x-amz-target", "CodeBuild_20161006.BatchGetReportGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4803,6 +5177,7 @@ impl CodeBuild for CodeBuildClient {
         input: BatchGetReportsInput,
     ) -> Result<BatchGetReportsOutput, RusotoError<BatchGetReportsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchGetReports");
         request.add_header("x-amz-target", "CodeBuild_20161006.BatchGetReports");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4821,6 +5196,7 @@ impl CodeBuild for CodeBuildClient {
         input: CreateProjectInput,
     ) -> Result<CreateProjectOutput, RusotoError<CreateProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateProject");
         request.add_header("x-amz-target", "CodeBuild_20161006.CreateProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4839,6 +5215,7 @@ impl CodeBuild for CodeBuildClient {
         input: CreateReportGroupInput,
     ) -> Result<CreateReportGroupOutput, RusotoError<CreateReportGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateReportGroup");
         request.add_header("x-amz-target", "CodeBuild_20161006.CreateReportGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4857,6 +5234,7 @@ impl CodeBuild for CodeBuildClient {
         input: CreateWebhookInput,
     ) -> Result<CreateWebhookOutput, RusotoError<CreateWebhookError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateWebhook");
         request.add_header("x-amz-target", "CodeBuild_20161006.CreateWebhook");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4875,6 +5253,7 @@ impl CodeBuild for CodeBuildClient {
         input: DeleteBuildBatchInput,
     ) -> Result<DeleteBuildBatchOutput, RusotoError<DeleteBuildBatchError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteBuildBatch");
         request.add_header("x-amz-target", "CodeBuild_20161006.DeleteBuildBatch");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4893,6 +5272,7 @@ impl CodeBuild for CodeBuildClient {
         input: DeleteProjectInput,
     ) -> Result<DeleteProjectOutput, RusotoError<DeleteProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteProject");
         request.add_header("x-amz-target", "CodeBuild_20161006.DeleteProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4911,6 +5291,7 @@ impl CodeBuild for CodeBuildClient {
         input: DeleteReportInput,
     ) -> Result<DeleteReportOutput, RusotoError<DeleteReportError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteReport");
         request.add_header("x-amz-target", "CodeBuild_20161006.DeleteReport");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4929,6 +5310,7 @@ impl CodeBuild for CodeBuildClient {
         input: DeleteReportGroupInput,
     ) -> Result<DeleteReportGroupOutput, RusotoError<DeleteReportGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteReportGroup");
         request.add_header("x-amz-target", "CodeBuild_20161006.DeleteReportGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4947,6 +5329,7 @@ impl CodeBuild for CodeBuildClient {
         input: DeleteResourcePolicyInput,
     ) -> Result<DeleteResourcePolicyOutput, RusotoError<DeleteResourcePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteResourcePolicy");
         request.add_header("x-amz-target", "CodeBuild_20161006.DeleteResourcePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4965,6 +5348,7 @@ impl CodeBuild for CodeBuildClient {
         input: DeleteSourceCredentialsInput,
     ) -> Result<DeleteSourceCredentialsOutput, RusotoError<DeleteSourceCredentialsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteSourceCredentials");
         request.add_header("x-amz-target", "CodeBuild_20161006.DeleteSourceCredentials");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4984,6 +5368,7 @@ impl CodeBuild for CodeBuildClient {
         input: DeleteWebhookInput,
     ) -> Result<DeleteWebhookOutput, RusotoError<DeleteWebhookError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteWebhook");
         request.add_header("x-amz-target", "CodeBuild_20161006.DeleteWebhook");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5002,6 +5387,7 @@ impl CodeBuild for CodeBuildClient {
         input: DescribeCodeCoveragesInput,
     ) -> Result<DescribeCodeCoveragesOutput, RusotoError<DescribeCodeCoveragesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeCodeCoverages");
         request.add_header("x-amz-target", "CodeBuild_20161006.DescribeCodeCoverages");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5020,6 +5406,7 @@ impl CodeBuild for CodeBuildClient {
         input: DescribeTestCasesInput,
     ) -> Result<DescribeTestCasesOutput, RusotoError<DescribeTestCasesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeTestCases");
         request.add_header("x-amz-target", "CodeBuild_20161006.DescribeTestCases");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5037,6 +5424,7 @@ impl CodeBuild for CodeBuildClient {
         input: GetReportGroupTrendInput,
     ) -> Result<GetReportGroupTrendOutput, RusotoError<GetReportGroupTrendError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetReportGroupTrend");
         request.add_header("x-amz-target", "CodeBuild_20161006.GetReportGroupTrend");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5055,6 +5443,7 @@ impl CodeBuild for CodeBuildClient {
         input: GetResourcePolicyInput,
     ) -> Result<GetResourcePolicyOutput, RusotoError<GetResourcePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetResourcePolicy");
         request.add_header("x-amz-target", "CodeBuild_20161006.GetResourcePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5073,6 +5462,7 @@ impl CodeBuild for CodeBuildClient {
         input: ImportSourceCredentialsInput,
     ) -> Result<ImportSourceCredentialsOutput, RusotoError<ImportSourceCredentialsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ImportSourceCredentials");
         request.add_header("x-amz-target", "CodeBuild_20161006.ImportSourceCredentials");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5092,6 +5482,7 @@ impl CodeBuild for CodeBuildClient {
         input: InvalidateProjectCacheInput,
     ) -> Result<InvalidateProjectCacheOutput, RusotoError<InvalidateProjectCacheError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("InvalidateProjectCache");
         request.add_header("x-amz-target", "CodeBuild_20161006.InvalidateProjectCache");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5111,6 +5502,7 @@ impl CodeBuild for CodeBuildClient {
         input: ListBuildBatchesInput,
     ) -> Result<ListBuildBatchesOutput, RusotoError<ListBuildBatchesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListBuildBatches");
         request.add_header("x-amz-target", "CodeBuild_20161006.ListBuildBatches");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5130,6 +5522,7 @@ impl CodeBuild for CodeBuildClient {
     ) -> Result<ListBuildBatchesForProjectOutput, RusotoError<ListBuildBatchesForProjectError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListBuildBatchesForProject");
         request.add_header(
             "x-amz-target",
             "CodeBuild_20161006.ListBuildBatchesForProject",
@@ -5152,6 +5545,7 @@ impl CodeBuild for CodeBuildClient {
         input: ListBuildsInput,
     ) -> Result<ListBuildsOutput, RusotoError<ListBuildsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListBuilds");
         request.add_header("x-amz-target", "CodeBuild_20161006.ListBuilds");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5170,6 +5564,7 @@ impl CodeBuild for CodeBuildClient {
         input: ListBuildsForProjectInput,
     ) -> Result<ListBuildsForProjectOutput, RusotoError<ListBuildsForProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListBuildsForProject");
         request.add_header("x-amz-target", "CodeBuild_20161006.ListBuildsForProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5188,6 +5583,7 @@ impl CodeBuild for CodeBuildClient {
     ) -> Result<ListCuratedEnvironmentImagesOutput, RusotoError<ListCuratedEnvironmentImagesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListCuratedEnvironmentImages");
         request.add_header(
             "x-amz-target",
             "CodeBuild_20161006.ListCuratedEnvironmentImages",
@@ -5209,6 +5605,7 @@ impl CodeBuild for CodeBuildClient {
         input: ListProjectsInput,
     ) -> Result<ListProjectsOutput, RusotoError<ListProjectsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListProjects");
         request.add_header("x-amz-target", "CodeBuild_20161006.ListProjects");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5227,6 +5624,7 @@ impl CodeBuild for CodeBuildClient {
         input: ListReportGroupsInput,
     ) -> Result<ListReportGroupsOutput, RusotoError<ListReportGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListReportGroups");
         request.add_header("x-amz-target", "CodeBuild_20161006.ListReportGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5245,6 +5643,7 @@ impl CodeBuild for CodeBuildClient {
         input: ListReportsInput,
     ) -> Result<ListReportsOutput, RusotoError<ListReportsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListReports");
         request.add_header("x-amz-target", "CodeBuild_20161006.ListReports");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5263,6 +5662,7 @@ impl CodeBuild for CodeBuildClient {
         input: ListReportsForReportGroupInput,
     ) -> Result<ListReportsForReportGroupOutput, RusotoError<ListReportsForReportGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListReportsForReportGroup");
         request.add_header(
             "x-amz-target",
             "CodeBuild_20161006.ListReportsForReportGroup",
@@ -5285,6 +5685,7 @@ impl CodeBuild for CodeBuildClient {
         input: ListSharedProjectsInput,
     ) -> Result<ListSharedProjectsOutput, RusotoError<ListSharedProjectsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListSharedProjects");
         request.add_header("x-amz-target", "CodeBuild_20161006.ListSharedProjects");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5303,6 +5704,7 @@ impl CodeBuild for CodeBuildClient {
         input: ListSharedReportGroupsInput,
     ) -> Result<ListSharedReportGroupsOutput, RusotoError<ListSharedReportGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListSharedReportGroups");
         request.add_header("x-amz-target", "CodeBuild_20161006.ListSharedReportGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5321,6 +5723,7 @@ impl CodeBuild for CodeBuildClient {
         &self,
     ) -> Result<ListSourceCredentialsOutput, RusotoError<ListSourceCredentialsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListSourceCredentials");
         request.add_header("x-amz-target", "CodeBuild_20161006.ListSourceCredentials");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -5338,6 +5741,7 @@ impl CodeBuild for CodeBuildClient {
         input: PutResourcePolicyInput,
     ) -> Result<PutResourcePolicyOutput, RusotoError<PutResourcePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutResourcePolicy");
         request.add_header("x-amz-target", "CodeBuild_20161006.PutResourcePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5356,6 +5760,7 @@ impl CodeBuild for CodeBuildClient {
         input: RetryBuildInput,
     ) -> Result<RetryBuildOutput, RusotoError<RetryBuildError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RetryBuild");
         request.add_header("x-amz-target", "CodeBuild_20161006.RetryBuild");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5374,6 +5779,7 @@ impl CodeBuild for CodeBuildClient {
         input: RetryBuildBatchInput,
     ) -> Result<RetryBuildBatchOutput, RusotoError<RetryBuildBatchError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RetryBuildBatch");
         request.add_header("x-amz-target", "CodeBuild_20161006.RetryBuildBatch");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5392,6 +5798,7 @@ impl CodeBuild for CodeBuildClient {
         input: StartBuildInput,
     ) -> Result<StartBuildOutput, RusotoError<StartBuildError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartBuild");
         request.add_header("x-amz-target", "CodeBuild_20161006.StartBuild");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5410,6 +5817,7 @@ impl CodeBuild for CodeBuildClient {
         input: StartBuildBatchInput,
     ) -> Result<StartBuildBatchOutput, RusotoError<StartBuildBatchError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartBuildBatch");
         request.add_header("x-amz-target", "CodeBuild_20161006.StartBuildBatch");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5428,6 +5836,7 @@ impl CodeBuild for CodeBuildClient {
         input: StopBuildInput,
     ) -> Result<StopBuildOutput, RusotoError<StopBuildError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopBuild");
         request.add_header("x-amz-target", "CodeBuild_20161006.StopBuild");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5446,6 +5855,7 @@ impl CodeBuild for CodeBuildClient {
         input: StopBuildBatchInput,
     ) -> Result<StopBuildBatchOutput, RusotoError<StopBuildBatchError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopBuildBatch");
         request.add_header("x-amz-target", "CodeBuild_20161006.StopBuildBatch");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5464,6 +5874,7 @@ impl CodeBuild for CodeBuildClient {
         input: UpdateProjectInput,
     ) -> Result<UpdateProjectOutput, RusotoError<UpdateProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateProject");
         request.add_header("x-amz-target", "CodeBuild_20161006.UpdateProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5482,6 +5893,7 @@ impl CodeBuild for CodeBuildClient {
         input: UpdateReportGroupInput,
     ) -> Result<UpdateReportGroupOutput, RusotoError<UpdateReportGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateReportGroup");
         request.add_header("x-amz-target", "CodeBuild_20161006.UpdateReportGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5500,6 +5912,7 @@ impl CodeBuild for CodeBuildClient {
         input: UpdateWebhookInput,
     ) -> Result<UpdateWebhookOutput, RusotoError<UpdateWebhookError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateWebhook");
         request.add_header("x-amz-target", "CodeBuild_20161006.UpdateWebhook");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5512,3 +5925,451 @@ impl CodeBuild for CodeBuildClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateWebhookOutput, _>()
     }
 }
+
+/// A blocking client for the AWS CodeBuild API, running the calls of a `CodeBuildClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `CodeBuildClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CodeBuildBlockingClient {
+    client: CodeBuildClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CodeBuildBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> CodeBuildBlockingClient {
+        CodeBuildBlockingClient::from_client(CodeBuildClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CodeBuildBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CodeBuildBlockingClient::from_client(CodeBuildClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> CodeBuildBlockingClient {
+        CodeBuildBlockingClient::from_client(CodeBuildClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> CodeBuildBlockingClient {
+        CodeBuildBlockingClient::from_client(CodeBuildClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: CodeBuildClient) -> CodeBuildBlockingClient {
+        CodeBuildBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> CodeBuildBlockingClient {
+        CodeBuildBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CodeBuildBlockingClient {
+        CodeBuildBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`CodeBuild::batch_delete_builds`].
+    pub fn batch_delete_builds(
+        &self,
+        input: BatchDeleteBuildsInput,
+    ) -> Result<BatchDeleteBuildsOutput, RusotoError<BatchDeleteBuildsError>> {
+        self.runtime
+            .block_on(self.client.batch_delete_builds(input))
+    }
+
+    /// Blocking version of [`CodeBuild::batch_get_build_batches`].
+    pub fn batch_get_build_batches(
+        &self,
+        input: BatchGetBuildBatchesInput,
+    ) -> Result<BatchGetBuildBatchesOutput, RusotoError<BatchGetBuildBatchesError>> {
+        self.runtime
+            .block_on(self.client.batch_get_build_batches(input))
+    }
+
+    /// Blocking version of [`CodeBuild::batch_get_builds`].
+    pub fn batch_get_builds(
+        &self,
+        input: BatchGetBuildsInput,
+    ) -> Result<BatchGetBuildsOutput, RusotoError<BatchGetBuildsError>> {
+        self.runtime.block_on(self.client.batch_get_builds(input))
+    }
+
+    /// Blocking version of [`CodeBuild::batch_get_projects`].
+    pub fn batch_get_projects(
+        &self,
+        input: BatchGetProjectsInput,
+    ) -> Result<BatchGetProjectsOutput, RusotoError<BatchGetProjectsError>> {
+        self.runtime.block_on(self.client.batch_get_projects(input))
+    }
+
+    /// Blocking version of [`CodeBuild::batch_get_report_groups`].
+    pub fn batch_get_report_groups(
+        &self,
+        input: BatchGetReportGroupsInput,
+    ) -> Result<BatchGetReportGroupsOutput, RusotoError<BatchGetReportGroupsError>> {
+        self.runtime
+            .block_on(self.client.batch_get_report_groups(input))
+    }
+
+    /// Blocking version of [`CodeBuild::batch_get_reports`].
+    pub fn batch_get_reports(
+        &self,
+        input: BatchGetReportsInput,
+    ) -> Result<BatchGetReportsOutput, RusotoError<BatchGetReportsError>> {
+        self.runtime.block_on(self.client.batch_get_reports(input))
+    }
+
+    /// Blocking version of [`CodeBuild::create_project`].
+    pub fn create_project(
+        &self,
+        input: CreateProjectInput,
+    ) -> Result<CreateProjectOutput, RusotoError<CreateProjectError>> {
+        self.runtime.block_on(self.client.create_project(input))
+    }
+
+    /// Blocking version of [`CodeBuild::create_report_group`].
+    pub fn create_report_group(
+        &self,
+        input: CreateReportGroupInput,
+    ) -> Result<CreateReportGroupOutput, RusotoError<CreateReportGroupError>> {
+        self.runtime
+            .block_on(self.client.create_report_group(input))
+    }
+
+    /// Blocking version of [`CodeBuild::create_webhook`].
+    pub fn create_webhook(
+        &self,
+        input: CreateWebhookInput,
+    ) -> Result<CreateWebhookOutput, RusotoError<CreateWebhookError>> {
+        self.runtime.block_on(self.client.create_webhook(input))
+    }
+
+    /// Blocking version of [`CodeBuild::delete_build_batch`].
+    pub fn delete_build_batch(
+        &self,
+        input: DeleteBuildBatchInput,
+    ) -> Result<DeleteBuildBatchOutput, RusotoError<DeleteBuildBatchError>> {
+        self.runtime.block_on(self.client.delete_build_batch(input))
+    }
+
+    /// Blocking version of [`CodeBuild::delete_project`].
+    pub fn delete_project(
+        &self,
+        input: DeleteProjectInput,
+    ) -> Result<DeleteProjectOutput, RusotoError<DeleteProjectError>> {
+        self.runtime.block_on(self.client.delete_project(input))
+    }
+
+    /// Blocking version of [`CodeBuild::delete_report`].
+    pub fn delete_report(
+        &self,
+        input: DeleteReportInput,
+    ) -> Result<DeleteReportOutput, RusotoError<DeleteReportError>> {
+        self.runtime.block_on(self.client.delete_report(input))
+    }
+
+    /// Blocking version of [`CodeBuild::delete_report_group`].
+    pub fn delete_report_group(
+        &self,
+        input: DeleteReportGroupInput,
+    ) -> Result<DeleteReportGroupOutput, RusotoError<DeleteReportGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_report_group(input))
+    }
+
+    /// Blocking version of [`CodeBuild::delete_resource_policy`].
+    pub fn delete_resource_policy(
+        &self,
+        input: DeleteResourcePolicyInput,
+    ) -> Result<DeleteResourcePolicyOutput, RusotoError<DeleteResourcePolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_resource_policy(input))
+    }
+
+    /// Blocking version of [`CodeBuild::delete_source_credentials`].
+    pub fn delete_source_credentials(
+        &self,
+        input: DeleteSourceCredentialsInput,
+    ) -> Result<DeleteSourceCredentialsOutput, RusotoError<DeleteSourceCredentialsError>> {
+        self.runtime
+            .block_on(self.client.delete_source_credentials(input))
+    }
+
+    /// Blocking version of [`CodeBuild::delete_webhook`].
+    pub fn delete_webhook(
+        &self,
+        input: DeleteWebhookInput,
+    ) -> Result<DeleteWebhookOutput, RusotoError<DeleteWebhookError>> {
+        self.runtime.block_on(self.client.delete_webhook(input))
+    }
+
+    /// Blocking version of [`CodeBuild::describe_code_coverages`].
+    pub fn describe_code_coverages(
+        &self,
+        input: DescribeCodeCoveragesInput,
+    ) -> Result<DescribeCodeCoveragesOutput, RusotoError<DescribeCodeCoveragesError>> {
+        self.runtime
+            .block_on(self.client.describe_code_coverages(input))
+    }
+
+    /// Blocking version of [`CodeBuild::describe_test_cases`].
+    pub fn describe_test_cases(
+        &self,
+        input: DescribeTestCasesInput,
+    ) -> Result<DescribeTestCasesOutput, RusotoError<DescribeTestCasesError>> {
+        self.runtime
+            .block_on(self.client.describe_test_cases(input))
+    }
+
+    /// Blocking version of [`CodeBuild::get_report_group_trend`].
+    pub fn get_report_group_trend(
+        &self,
+        input: GetReportGroupTrendInput,
+    ) -> Result<GetReportGroupTrendOutput, RusotoError<GetReportGroupTrendError>> {
+        self.runtime
+            .block_on(self.client.get_report_group_trend(input))
+    }
+
+    /// Blocking version of [`CodeBuild::get_resource_policy`].
+    pub fn get_resource_policy(
+        &self,
+        input: GetResourcePolicyInput,
+    ) -> Result<GetResourcePolicyOutput, RusotoError<GetResourcePolicyError>> {
+        self.runtime
+            .block_on(self.client.get_resource_policy(input))
+    }
+
+    /// Blocking version of [`CodeBuild::import_source_credentials`].
+    pub fn import_source_credentials(
+        &self,
+        input: ImportSourceCredentialsInput,
+    ) -> Result<ImportSourceCredentialsOutput, RusotoError<ImportSourceCredentialsError>> {
+        self.runtime
+            .block_on(self.client.import_source_credentials(input))
+    }
+
+    /// Blocking version of [`CodeBuild::invalidate_project_cache`].
+    pub fn invalidate_project_cache(
+        &self,
+        input: InvalidateProjectCacheInput,
+    ) -> Result<InvalidateProjectCacheOutput, RusotoError<InvalidateProjectCacheError>> {
+        self.runtime
+            .block_on(self.client.invalidate_project_cache(input))
+    }
+
+    /// Blocking version of [`CodeBuild::list_build_batches`].
+    pub fn list_build_batches(
+        &self,
+        input: ListBuildBatchesInput,
+    ) -> Result<ListBuildBatchesOutput, RusotoError<ListBuildBatchesError>> {
+        self.runtime.block_on(self.client.list_build_batches(input))
+    }
+
+    /// Blocking version of [`CodeBuild::list_build_batches_for_project`].
+    pub fn list_build_batches_for_project(
+        &self,
+        input: ListBuildBatchesForProjectInput,
+    ) -> Result<ListBuildBatchesForProjectOutput, RusotoError<ListBuildBatchesForProjectError>>
+    {
+        self.runtime
+            .block_on(self.client.list_build_batches_for_project(input))
+    }
+
+    /// Blocking version of [`CodeBuild::list_builds`].
+    pub fn list_builds(
+        &self,
+        input: ListBuildsInput,
+    ) -> Result<ListBuildsOutput, RusotoError<ListBuildsError>> {
+        self.runtime.block_on(self.client.list_builds(input))
+    }
+
+    /// Blocking version of [`CodeBuild::list_builds_for_project`].
+    pub fn list_builds_for_project(
+        &self,
+        input: ListBuildsForProjectInput,
+    ) -> Result<ListBuildsForProjectOutput, RusotoError<ListBuildsForProjectError>> {
+        self.runtime
+            .block_on(self.client.list_builds_for_project(input))
+    }
+
+    /// Blocking version of [`CodeBuild::list_curated_environment_images`].
+    pub fn list_curated_environment_images(
+        &self,
+    ) -> Result<ListCuratedEnvironmentImagesOutput, RusotoError<ListCuratedEnvironmentImagesError>>
+    {
+        self.runtime
+            .block_on(self.client.list_curated_environment_images())
+    }
+
+    /// Blocking version of [`CodeBuild::list_projects`].
+    pub fn list_projects(
+        &self,
+        input: ListProjectsInput,
+    ) -> Result<ListProjectsOutput, RusotoError<ListProjectsError>> {
+        self.runtime.block_on(self.client.list_projects(input))
+    }
+
+    /// Blocking version of [`CodeBuild::list_report_groups`].
+    pub fn list_report_groups(
+        &self,
+        input: ListReportGroupsInput,
+    ) -> Result<ListReportGroupsOutput, RusotoError<ListReportGroupsError>> {
+        self.runtime.block_on(self.client.list_report_groups(input))
+    }
+
+    /// Blocking version of [`CodeBuild::list_reports`].
+    pub fn list_reports(
+        &self,
+        input: ListReportsInput,
+    ) -> Result<ListReportsOutput, RusotoError<ListReportsError>> {
+        self.runtime.block_on(self.client.list_reports(input))
+    }
+
+    /// Blocking version of [`CodeBuild::list_reports_for_report_group`].
+    pub fn list_reports_for_report_group(
+        &self,
+        input: ListReportsForReportGroupInput,
+    ) -> Result<ListReportsForReportGroupOutput, RusotoError<ListReportsForReportGroupError>> {
+        self.runtime
+            .block_on(self.client.list_reports_for_report_group(input))
+    }
+
+    /// Blocking version of [`CodeBuild::list_shared_projects`].
+    pub fn list_shared_projects(
+        &self,
+        input: ListSharedProjectsInput,
+    ) -> Result<ListSharedProjectsOutput, RusotoError<ListSharedProjectsError>> {
+        self.runtime
+            .block_on(self.client.list_shared_projects(input))
+    }
+
+    /// Blocking version of [`CodeBuild::list_shared_report_groups`].
+    pub fn list_shared_report_groups(
+        &self,
+        input: ListSharedReportGroupsInput,
+    ) -> Result<ListSharedReportGroupsOutput, RusotoError<ListSharedReportGroupsError>> {
+        self.runtime
+            .block_on(self.client.list_shared_report_groups(input))
+    }
+
+    /// Blocking version of [`CodeBuild::list_source_credentials`].
+    pub fn list_source_credentials(
+        &self,
+    ) -> Result<ListSourceCredentialsOutput, RusotoError<ListSourceCredentialsError>> {
+        self.runtime.block_on(self.client.list_source_credentials())
+    }
+
+    /// Blocking version of [`CodeBuild::put_resource_policy`].
+    pub fn put_resource_policy(
+        &self,
+        input: PutResourcePolicyInput,
+    ) -> Result<PutResourcePolicyOutput, RusotoError<PutResourcePolicyError>> {
+        self.runtime
+            .block_on(self.client.put_resource_policy(input))
+    }
+
+    /// Blocking version of [`CodeBuild::retry_build`].
+    pub fn retry_build(
+        &self,
+        input: RetryBuildInput,
+    ) -> Result<RetryBuildOutput, RusotoError<RetryBuildError>> {
+        self.runtime.block_on(self.client.retry_build(input))
+    }
+
+    /// Blocking version of [`CodeBuild::retry_build_batch`].
+    pub fn retry_build_batch(
+        &self,
+        input: RetryBuildBatchInput,
+    ) -> Result<RetryBuildBatchOutput, RusotoError<RetryBuildBatchError>> {
+        self.runtime.block_on(self.client.retry_build_batch(input))
+    }
+
+    /// Blocking version of [`CodeBuild::start_build`].
+    pub fn start_build(
+        &self,
+        input: StartBuildInput,
+    ) -> Result<StartBuildOutput, RusotoError<StartBuildError>> {
+        self.runtime.block_on(self.client.start_build(input))
+    }
+
+    /// Blocking version of [`CodeBuild::start_build_batch`].
+    pub fn start_build_batch(
+        &self,
+        input: StartBuildBatchInput,
+    ) -> Result<StartBuildBatchOutput, RusotoError<StartBuildBatchError>> {
+        self.runtime.block_on(self.client.start_build_batch(input))
+    }
+
+    /// Blocking version of [`CodeBuild::stop_build`].
+    pub fn stop_build(
+        &self,
+        input: StopBuildInput,
+    ) -> Result<StopBuildOutput, RusotoError<StopBuildError>> {
+        self.runtime.block_on(self.client.stop_build(input))
+    }
+
+    /// Blocking version of [`CodeBuild::stop_build_batch`].
+    pub fn stop_build_batch(
+        &self,
+        input: StopBuildBatchInput,
+    ) -> Result<StopBuildBatchOutput, RusotoError<StopBuildBatchError>> {
+        self.runtime.block_on(self.client.stop_build_batch(input))
+    }
+
+    /// Blocking version of [`CodeBuild::update_project`].
+    pub fn update_project(
+        &self,
+        input: UpdateProjectInput,
+    ) -> Result<UpdateProjectOutput, RusotoError<UpdateProjectError>> {
+        self.runtime.block_on(self.client.update_project(input))
+    }
+
+    /// Blocking version of [`CodeBuild::update_report_group`].
+    pub fn update_report_group(
+        &self,
+        input: UpdateReportGroupInput,
+    ) -> Result<UpdateReportGroupOutput, RusotoError<UpdateReportGroupError>> {
+        self.runtime
+            .block_on(self.client.update_report_group(input))
+    }
+
+    /// Blocking version of [`CodeBuild::update_webhook`].
+    pub fn update_webhook(
+        &self,
+        input: UpdateWebhookInput,
+    ) -> Result<UpdateWebhookOutput, RusotoError<UpdateWebhookError>> {
+        self.runtime.block_on(self.client.update_webhook(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -3587,19 +3588,19 @@ impl AssociateApprovalRuleTemplateWithRepositoryError {
     ) -> RusotoError<AssociateApprovalRuleTemplateWithRepositoryError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                                "ApprovalRuleTemplateDoesNotExistException" => return RusotoError::Service(AssociateApprovalRuleTemplateWithRepositoryError::ApprovalRuleTemplateDoesNotExist(err.msg)),
-"ApprovalRuleTemplateNameRequiredException" => return RusotoError::Service(AssociateApprovalRuleTemplateWithRepositoryError::ApprovalRuleTemplateNameRequired(err.msg)),
-"EncryptionIntegrityChecksFailedException" => return RusotoError::Service(AssociateApprovalRuleTemplateWithRepositoryError::EncryptionIntegrityChecksFailed(err.msg)),
-"EncryptionKeyAccessDeniedException" => return RusotoError::Service(AssociateApprovalRuleTemplateWithRepositoryError::EncryptionKeyAccessDenied(err.msg)),
-"EncryptionKeyDisabledException" => return RusotoError::Service(AssociateApprovalRuleTemplateWithRepositoryError::EncryptionKeyDisabled(err.msg)),
-"EncryptionKeyNotFoundException" => return RusotoError::Service(AssociateApprovalRuleTemplateWithRepositoryError::EncryptionKeyNotFound(err.msg)),
-"EncryptionKeyUnavailableException" => return RusotoError::Service(AssociateApprovalRuleTemplateWithRepositoryError::EncryptionKeyUnavailable(err.msg)),
-"InvalidApprovalRuleTemplateNameException" => return RusotoError::Service(AssociateApprovalRuleTemplateWithRepositoryError::InvalidApprovalRuleTemplateName(err.msg)),
-"InvalidRepositoryNameException" => return RusotoError::Service(AssociateApprovalRuleTemplateWithRepositoryError::InvalidRepositoryName(err.msg)),
-"MaximumRuleTemplatesAssociatedWithRepositoryException" => return RusotoError::Service(AssociateApprovalRuleTemplateWithRepositoryError::MaximumRuleTemplatesAssociatedWithRepository(err.msg)),
-"RepositoryDoesNotExistException" => return RusotoError::Service(AssociateApprovalRuleTemplateWithRepositoryError::RepositoryDoesNotExist(err.msg)),
-"RepositoryNameRequiredException" => return RusotoError::Service(AssociateApprovalRuleTemplateWithRepositoryError::RepositoryNameRequired(err.msg)),
-"ValidationException" => return RusotoError::Validation(err.msg),
+                                "ApprovalRuleTemplateDoesNotExistException" => return RusotoError::service_error(&res, &err.typ, err.msg, AssociateApprovalRuleTemplateWithRepositoryError::ApprovalRuleTemplateDoesNotExist),
+"ApprovalRuleTemplateNameRequiredException" => return RusotoError::service_error(&res, &err.typ, err.msg, AssociateApprovalRuleTemplateWithRepositoryError::ApprovalRuleTemplateNameRequired),
+"EncryptionIntegrityChecksFailedException" => return RusotoError::service_error(&res, &err.typ, err.msg, AssociateApprovalRuleTemplateWithRepositoryError::EncryptionIntegrityChecksFailed),
+"EncryptionKeyAccessDeniedException" => return RusotoError::service_error(&res, &err.typ, err.msg, AssociateApprovalRuleTemplateWithRepositoryError::EncryptionKeyAccessDenied),
+"EncryptionKeyDisabledException" => return RusotoError::service_error(&res, &err.typ, err.msg, AssociateApprovalRuleTemplateWithRepositoryError::EncryptionKeyDisabled),
+"EncryptionKeyNotFoundException" => return RusotoError::service_error(&res, &err.typ, err.msg, AssociateApprovalRuleTemplateWithRepositoryError::EncryptionKeyNotFound),
+"EncryptionKeyUnavailableException" => return RusotoError::service_error(&res, &err.typ, err.msg, AssociateApprovalRuleTemplateWithRepositoryError::EncryptionKeyUnavailable),
+"InvalidApprovalRuleTemplateNameException" => return RusotoError::service_error(&res, &err.typ, err.msg, AssociateApprovalRuleTemplateWithRepositoryError::InvalidApprovalRuleTemplateName),
+"InvalidRepositoryNameException" => return RusotoError::service_error(&res, &err.typ, err.msg, AssociateApprovalRuleTemplateWithRepositoryError::InvalidRepositoryName),
+"MaximumRuleTemplatesAssociatedWithRepositoryException" => return RusotoError::service_error(&res, &err.typ, err.msg, AssociateApprovalRuleTemplateWithRepositoryError::MaximumRuleTemplatesAssociatedWithRepository),
+"RepositoryDoesNotExistException" => return RusotoError::service_error(&res, &err.typ, err.msg, AssociateApprovalRuleTemplateWithRepositoryError::RepositoryDoesNotExist),
+"RepositoryNameRequiredException" => return RusotoError::service_error(&res, &err.typ, err.msg, AssociateApprovalRuleTemplateWithRepositoryError::RepositoryNameRequired),
+"ValidationException" => return RusotoError::validation_error(&res, err.msg),
 _ => {}
                             }
         }
@@ -3657,17 +3658,17 @@ impl BatchAssociateApprovalRuleTemplateWithRepositoriesError {
     ) -> RusotoError<BatchAssociateApprovalRuleTemplateWithRepositoriesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                                "ApprovalRuleTemplateDoesNotExistException" => return RusotoError::Service(BatchAssociateApprovalRuleTemplateWithRepositoriesError::ApprovalRuleTemplateDoesNotExist(err.msg)),
-"ApprovalRuleTemplateNameRequiredException" => return RusotoError::Service(BatchAssociateApprovalRuleTemplateWithRepositoriesError::ApprovalRuleTemplateNameRequired(err.msg)),
-"EncryptionIntegrityChecksFailedException" => return RusotoError::Service(BatchAssociateApprovalRuleTemplateWithRepositoriesError::EncryptionIntegrityChecksFailed(err.msg)),
-"EncryptionKeyAccessDeniedException" => return RusotoError::Service(BatchAssociateApprovalRuleTemplateWithRepositoriesError::EncryptionKeyAccessDenied(err.msg)),
-"EncryptionKeyDisabledException" => return RusotoError::Service(BatchAssociateApprovalRuleTemplateWithRepositoriesError::EncryptionKeyDisabled(err.msg)),
-"EncryptionKeyNotFoundException" => return RusotoError::Service(BatchAssociateApprovalRuleTemplateWithRepositoriesError::EncryptionKeyNotFound(err.msg)),
-"EncryptionKeyUnavailableException" => return RusotoError::Service(BatchAssociateApprovalRuleTemplateWithRepositoriesError::EncryptionKeyUnavailable(err.msg)),
-"InvalidApprovalRuleTemplateNameException" => return RusotoError::Service(BatchAssociateApprovalRuleTemplateWithRepositoriesError::InvalidApprovalRuleTemplateName(err.msg)),
-"MaximumRepositoryNamesExceededException" => return RusotoError::Service(BatchAssociateApprovalRuleTemplateWithRepositoriesError::MaximumRepositoryNamesExceeded(err.msg)),
-"RepositoryNamesRequiredException" => return RusotoError::Service(BatchAssociateApprovalRuleTemplateWithRepositoriesError::RepositoryNamesRequired(err.msg)),
-"ValidationException" => return RusotoError::Validation(err.msg),
+                                "ApprovalRuleTemplateDoesNotExistException" => return RusotoError::service_error(&res, &err.typ, err.msg, BatchAssociateApprovalRuleTemplateWithRepositoriesError::ApprovalRuleTemplateDoesNotExist),
+"ApprovalRuleTemplateNameRequiredException" => return RusotoError::service_error(&res, &err.typ, err.msg, BatchAssociateApprovalRuleTemplateWithRepositoriesError::ApprovalRuleTemplateNameRequired),
+"EncryptionIntegrityChecksFailedException" => return RusotoError::service_error(&res, &err.typ, err.msg, BatchAssociateApprovalRuleTemplateWithRepositoriesError::EncryptionIntegrityChecksFailed),
+"EncryptionKeyAccessDeniedException" => return RusotoError::service_error(&res, &err.typ, err.msg, BatchAssociateApprovalRuleTemplateWithRepositoriesError::EncryptionKeyAccessDenied),
+"EncryptionKeyDisabledException" => return RusotoError::service_error(&res, &err.typ, err.msg, BatchAssociateApprovalRuleTemplateWithRepositoriesError::EncryptionKeyDisabled),
+"EncryptionKeyNotFoundException" => return RusotoError::service_error(&res, &err.typ, err.msg, BatchAssociateApprovalRuleTemplateWithRepositoriesError::EncryptionKeyNotFound),
+"EncryptionKeyUnavailableException" => return RusotoError::service_error(&res, &err.typ, err.msg, BatchAssociateApprovalRuleTemplateWithRepositoriesError::EncryptionKeyUnavailable),
+"InvalidApprovalRuleTemplateNameException" => return RusotoError::service_error(&res, &err.typ, err.msg, BatchAssociateApprovalRuleTemplateWithRepositoriesError::InvalidApprovalRuleTemplateName),
+"MaximumRepositoryNamesExceededException" => return RusotoError::service_error(&res, &err.typ, err.msg, BatchAssociateApprovalRuleTemplateWithRepositoriesError::MaximumRepositoryNamesExceeded),
+"RepositoryNamesRequiredException" => return RusotoError::service_error(&res, &err.typ, err.msg, BatchAssociateApprovalRuleTemplateWithRepositoriesError::RepositoryNamesRequired),
+"ValidationException" => return RusotoError::validation_error(&res, err.msg),
 _ => {}
                             }
         }
@@ -3746,113 +3747,174 @@ impl BatchDescribeMergeConflictsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(
-                        BatchDescribeMergeConflictsError::CommitDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDescribeMergeConflictsError::CommitDoesNotExist,
                     )
                 }
                 "CommitRequiredException" => {
-                    return RusotoError::Service(BatchDescribeMergeConflictsError::CommitRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDescribeMergeConflictsError::CommitRequired,
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        BatchDescribeMergeConflictsError::EncryptionIntegrityChecksFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDescribeMergeConflictsError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
-                        BatchDescribeMergeConflictsError::EncryptionKeyAccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDescribeMergeConflictsError::EncryptionKeyAccessDenied,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
-                        BatchDescribeMergeConflictsError::EncryptionKeyDisabled(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDescribeMergeConflictsError::EncryptionKeyDisabled,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
-                        BatchDescribeMergeConflictsError::EncryptionKeyNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDescribeMergeConflictsError::EncryptionKeyNotFound,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
-                        BatchDescribeMergeConflictsError::EncryptionKeyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDescribeMergeConflictsError::EncryptionKeyUnavailable,
                     )
                 }
                 "InvalidCommitException" => {
-                    return RusotoError::Service(BatchDescribeMergeConflictsError::InvalidCommit(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDescribeMergeConflictsError::InvalidCommit,
+                    )
                 }
                 "InvalidConflictDetailLevelException" => {
-                    return RusotoError::Service(
-                        BatchDescribeMergeConflictsError::InvalidConflictDetailLevel(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDescribeMergeConflictsError::InvalidConflictDetailLevel,
                     )
                 }
                 "InvalidConflictResolutionStrategyException" => {
-                    return RusotoError::Service(
-                        BatchDescribeMergeConflictsError::InvalidConflictResolutionStrategy(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDescribeMergeConflictsError::InvalidConflictResolutionStrategy,
                     )
                 }
                 "InvalidContinuationTokenException" => {
-                    return RusotoError::Service(
-                        BatchDescribeMergeConflictsError::InvalidContinuationToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDescribeMergeConflictsError::InvalidContinuationToken,
                     )
                 }
                 "InvalidMaxConflictFilesException" => {
-                    return RusotoError::Service(
-                        BatchDescribeMergeConflictsError::InvalidMaxConflictFiles(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDescribeMergeConflictsError::InvalidMaxConflictFiles,
                     )
                 }
                 "InvalidMaxMergeHunksException" => {
-                    return RusotoError::Service(
-                        BatchDescribeMergeConflictsError::InvalidMaxMergeHunks(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDescribeMergeConflictsError::InvalidMaxMergeHunks,
                     )
                 }
                 "InvalidMergeOptionException" => {
-                    return RusotoError::Service(
-                        BatchDescribeMergeConflictsError::InvalidMergeOption(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDescribeMergeConflictsError::InvalidMergeOption,
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
-                        BatchDescribeMergeConflictsError::InvalidRepositoryName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDescribeMergeConflictsError::InvalidRepositoryName,
                     )
                 }
                 "MaximumFileContentToLoadExceededException" => {
-                    return RusotoError::Service(
-                        BatchDescribeMergeConflictsError::MaximumFileContentToLoadExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDescribeMergeConflictsError::MaximumFileContentToLoadExceeded,
                     )
                 }
                 "MaximumItemsToCompareExceededException" => {
-                    return RusotoError::Service(
-                        BatchDescribeMergeConflictsError::MaximumItemsToCompareExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDescribeMergeConflictsError::MaximumItemsToCompareExceeded,
                     )
                 }
                 "MergeOptionRequiredException" => {
-                    return RusotoError::Service(
-                        BatchDescribeMergeConflictsError::MergeOptionRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDescribeMergeConflictsError::MergeOptionRequired,
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
-                        BatchDescribeMergeConflictsError::RepositoryDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDescribeMergeConflictsError::RepositoryDoesNotExist,
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
-                        BatchDescribeMergeConflictsError::RepositoryNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDescribeMergeConflictsError::RepositoryNameRequired,
                     )
                 }
                 "TipsDivergenceExceededException" => {
-                    return RusotoError::Service(
-                        BatchDescribeMergeConflictsError::TipsDivergenceExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDescribeMergeConflictsError::TipsDivergenceExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3957,17 +4019,17 @@ impl BatchDisassociateApprovalRuleTemplateFromRepositoriesError {
     ) -> RusotoError<BatchDisassociateApprovalRuleTemplateFromRepositoriesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                                "ApprovalRuleTemplateDoesNotExistException" => return RusotoError::Service(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::ApprovalRuleTemplateDoesNotExist(err.msg)),
-"ApprovalRuleTemplateNameRequiredException" => return RusotoError::Service(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::ApprovalRuleTemplateNameRequired(err.msg)),
-"EncryptionIntegrityChecksFailedException" => return RusotoError::Service(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::EncryptionIntegrityChecksFailed(err.msg)),
-"EncryptionKeyAccessDeniedException" => return RusotoError::Service(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::EncryptionKeyAccessDenied(err.msg)),
-"EncryptionKeyDisabledException" => return RusotoError::Service(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::EncryptionKeyDisabled(err.msg)),
-"EncryptionKeyNotFoundException" => return RusotoError::Service(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::EncryptionKeyNotFound(err.msg)),
-"EncryptionKeyUnavailableException" => return RusotoError::Service(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::EncryptionKeyUnavailable(err.msg)),
-"InvalidApprovalRuleTemplateNameException" => return RusotoError::Service(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::InvalidApprovalRuleTemplateName(err.msg)),
-"MaximumRepositoryNamesExceededException" => return RusotoError::Service(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::MaximumRepositoryNamesExceeded(err.msg)),
-"RepositoryNamesRequiredException" => return RusotoError::Service(BatchDisassociateApprovalRuleTemplateFromRepositoriesError::RepositoryNamesRequired(err.msg)),
-"ValidationException" => return RusotoError::Validation(err.msg),
+                                "ApprovalRuleTemplateDoesNotExistException" => return RusotoError::service_error(&res, &err.typ, err.msg, BatchDisassociateApprovalRuleTemplateFromRepositoriesError::ApprovalRuleTemplateDoesNotExist),
+"ApprovalRuleTemplateNameRequiredException" => return RusotoError::service_error(&res, &err.typ, err.msg, BatchDisassociateApprovalRuleTemplateFromRepositoriesError::ApprovalRuleTemplateNameRequired),
+"EncryptionIntegrityChecksFailedException" => return RusotoError::service_error(&res, &err.typ, err.msg, BatchDisassociateApprovalRuleTemplateFromRepositoriesError::EncryptionIntegrityChecksFailed),
+"EncryptionKeyAccessDeniedException" => return RusotoError::service_error(&res, &err.typ, err.msg, BatchDisassociateApprovalRuleTemplateFromRepositoriesError::EncryptionKeyAccessDenied),
+"EncryptionKeyDisabledException" => return RusotoError::service_error(&res, &err.typ, err.msg, BatchDisassociateApprovalRuleTemplateFromRepositoriesError::EncryptionKeyDisabled),
+"EncryptionKeyNotFoundException" => return RusotoError::service_error(&res, &err.typ, err.msg, BatchDisassociateApprovalRuleTemplateFromRepositoriesError::EncryptionKeyNotFound),
+"EncryptionKeyUnavailableException" => return RusotoError::service_error(&res, &err.typ, err.msg, BatchDisassociateApprovalRuleTemplateFromRepositoriesError::EncryptionKeyUnavailable),
+"InvalidApprovalRuleTemplateNameException" => return RusotoError::service_error(&res, &err.typ, err.msg, BatchDisassociateApprovalRuleTemplateFromRepositoriesError::InvalidApprovalRuleTemplateName),
+"MaximumRepositoryNamesExceededException" => return RusotoError::service_error(&res, &err.typ, err.msg, BatchDisassociateApprovalRuleTemplateFromRepositoriesError::MaximumRepositoryNamesExceeded),
+"RepositoryNamesRequiredException" => return RusotoError::service_error(&res, &err.typ, err.msg, BatchDisassociateApprovalRuleTemplateFromRepositoriesError::RepositoryNamesRequired),
+"ValidationException" => return RusotoError::validation_error(&res, err.msg),
 _ => {}
                             }
         }
@@ -4022,56 +4084,86 @@ impl BatchGetCommitsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitIdsLimitExceededException" => {
-                    return RusotoError::Service(BatchGetCommitsError::CommitIdsLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchGetCommitsError::CommitIdsLimitExceeded,
+                    )
                 }
                 "CommitIdsListRequiredException" => {
-                    return RusotoError::Service(BatchGetCommitsError::CommitIdsListRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchGetCommitsError::CommitIdsListRequired,
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        BatchGetCommitsError::EncryptionIntegrityChecksFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetCommitsError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(BatchGetCommitsError::EncryptionKeyAccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchGetCommitsError::EncryptionKeyAccessDenied,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(BatchGetCommitsError::EncryptionKeyDisabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchGetCommitsError::EncryptionKeyDisabled,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(BatchGetCommitsError::EncryptionKeyNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchGetCommitsError::EncryptionKeyNotFound,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(BatchGetCommitsError::EncryptionKeyUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchGetCommitsError::EncryptionKeyUnavailable,
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(BatchGetCommitsError::InvalidRepositoryName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchGetCommitsError::InvalidRepositoryName,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(BatchGetCommitsError::RepositoryDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchGetCommitsError::RepositoryDoesNotExist,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(BatchGetCommitsError::RepositoryNameRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchGetCommitsError::RepositoryNameRequired,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4124,46 +4216,70 @@ impl BatchGetRepositoriesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        BatchGetRepositoriesError::EncryptionIntegrityChecksFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetRepositoriesError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
-                        BatchGetRepositoriesError::EncryptionKeyAccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetRepositoriesError::EncryptionKeyAccessDenied,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(BatchGetRepositoriesError::EncryptionKeyDisabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchGetRepositoriesError::EncryptionKeyDisabled,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(BatchGetRepositoriesError::EncryptionKeyNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchGetRepositoriesError::EncryptionKeyNotFound,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
-                        BatchGetRepositoriesError::EncryptionKeyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetRepositoriesError::EncryptionKeyUnavailable,
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(BatchGetRepositoriesError::InvalidRepositoryName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchGetRepositoriesError::InvalidRepositoryName,
+                    )
                 }
                 "MaximumRepositoryNamesExceededException" => {
-                    return RusotoError::Service(
-                        BatchGetRepositoriesError::MaximumRepositoryNamesExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetRepositoriesError::MaximumRepositoryNamesExceeded,
                     )
                 }
                 "RepositoryNamesRequiredException" => {
-                    return RusotoError::Service(
-                        BatchGetRepositoriesError::RepositoryNamesRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetRepositoriesError::RepositoryNamesRequired,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4220,49 +4336,62 @@ impl CreateApprovalRuleTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApprovalRuleTemplateContentRequiredException" => {
-                    return RusotoError::Service(
-                        CreateApprovalRuleTemplateError::ApprovalRuleTemplateContentRequired(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApprovalRuleTemplateError::ApprovalRuleTemplateContentRequired,
                     )
                 }
                 "ApprovalRuleTemplateNameAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        CreateApprovalRuleTemplateError::ApprovalRuleTemplateNameAlreadyExists(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApprovalRuleTemplateError::ApprovalRuleTemplateNameAlreadyExists,
                     )
                 }
                 "ApprovalRuleTemplateNameRequiredException" => {
-                    return RusotoError::Service(
-                        CreateApprovalRuleTemplateError::ApprovalRuleTemplateNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApprovalRuleTemplateError::ApprovalRuleTemplateNameRequired,
                     )
                 }
                 "InvalidApprovalRuleTemplateContentException" => {
-                    return RusotoError::Service(
-                        CreateApprovalRuleTemplateError::InvalidApprovalRuleTemplateContent(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApprovalRuleTemplateError::InvalidApprovalRuleTemplateContent,
                     )
                 }
                 "InvalidApprovalRuleTemplateDescriptionException" => {
-                    return RusotoError::Service(
-                        CreateApprovalRuleTemplateError::InvalidApprovalRuleTemplateDescription(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApprovalRuleTemplateError::InvalidApprovalRuleTemplateDescription,
                     )
                 }
                 "InvalidApprovalRuleTemplateNameException" => {
-                    return RusotoError::Service(
-                        CreateApprovalRuleTemplateError::InvalidApprovalRuleTemplateName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApprovalRuleTemplateError::InvalidApprovalRuleTemplateName,
                     )
                 }
                 "NumberOfRuleTemplatesExceededException" => {
-                    return RusotoError::Service(
-                        CreateApprovalRuleTemplateError::NumberOfRuleTemplatesExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApprovalRuleTemplateError::NumberOfRuleTemplatesExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4336,54 +4465,118 @@ impl CreateBranchError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BranchNameExistsException" => {
-                    return RusotoError::Service(CreateBranchError::BranchNameExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBranchError::BranchNameExists,
+                    )
                 }
                 "BranchNameRequiredException" => {
-                    return RusotoError::Service(CreateBranchError::BranchNameRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBranchError::BranchNameRequired,
+                    )
                 }
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(CreateBranchError::CommitDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBranchError::CommitDoesNotExist,
+                    )
                 }
                 "CommitIdRequiredException" => {
-                    return RusotoError::Service(CreateBranchError::CommitIdRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBranchError::CommitIdRequired,
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        CreateBranchError::EncryptionIntegrityChecksFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBranchError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(CreateBranchError::EncryptionKeyAccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateBranchError::EncryptionKeyAccessDenied,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(CreateBranchError::EncryptionKeyDisabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBranchError::EncryptionKeyDisabled,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(CreateBranchError::EncryptionKeyNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBranchError::EncryptionKeyNotFound,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(CreateBranchError::EncryptionKeyUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateBranchError::EncryptionKeyUnavailable,
+                    )
                 }
                 "InvalidBranchNameException" => {
-                    return RusotoError::Service(CreateBranchError::InvalidBranchName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBranchError::InvalidBranchName,
+                    )
                 }
                 "InvalidCommitIdException" => {
-                    return RusotoError::Service(CreateBranchError::InvalidCommitId(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBranchError::InvalidCommitId,
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(CreateBranchError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBranchError::InvalidRepositoryName,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(CreateBranchError::RepositoryDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBranchError::RepositoryDoesNotExist,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(CreateBranchError::RepositoryNameRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBranchError::RepositoryNameRequired,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4498,148 +4691,310 @@ impl CreateCommitError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BranchDoesNotExistException" => {
-                    return RusotoError::Service(CreateCommitError::BranchDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCommitError::BranchDoesNotExist,
+                    )
                 }
                 "BranchNameIsTagNameException" => {
-                    return RusotoError::Service(CreateCommitError::BranchNameIsTagName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCommitError::BranchNameIsTagName,
+                    )
                 }
                 "BranchNameRequiredException" => {
-                    return RusotoError::Service(CreateCommitError::BranchNameRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCommitError::BranchNameRequired,
+                    )
                 }
                 "CommitMessageLengthExceededException" => {
-                    return RusotoError::Service(CreateCommitError::CommitMessageLengthExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCommitError::CommitMessageLengthExceeded,
+                    )
                 }
                 "DirectoryNameConflictsWithFileNameException" => {
-                    return RusotoError::Service(
-                        CreateCommitError::DirectoryNameConflictsWithFileName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCommitError::DirectoryNameConflictsWithFileName,
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        CreateCommitError::EncryptionIntegrityChecksFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCommitError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(CreateCommitError::EncryptionKeyAccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCommitError::EncryptionKeyAccessDenied,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(CreateCommitError::EncryptionKeyDisabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCommitError::EncryptionKeyDisabled,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(CreateCommitError::EncryptionKeyNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCommitError::EncryptionKeyNotFound,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(CreateCommitError::EncryptionKeyUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCommitError::EncryptionKeyUnavailable,
+                    )
                 }
                 "FileContentAndSourceFileSpecifiedException" => {
-                    return RusotoError::Service(
-                        CreateCommitError::FileContentAndSourceFileSpecified(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCommitError::FileContentAndSourceFileSpecified,
                     )
                 }
                 "FileContentSizeLimitExceededException" => {
-                    return RusotoError::Service(CreateCommitError::FileContentSizeLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCommitError::FileContentSizeLimitExceeded,
+                    )
                 }
                 "FileDoesNotExistException" => {
-                    return RusotoError::Service(CreateCommitError::FileDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCommitError::FileDoesNotExist,
+                    )
                 }
                 "FileEntryRequiredException" => {
-                    return RusotoError::Service(CreateCommitError::FileEntryRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCommitError::FileEntryRequired,
+                    )
                 }
                 "FileModeRequiredException" => {
-                    return RusotoError::Service(CreateCommitError::FileModeRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCommitError::FileModeRequired,
+                    )
                 }
                 "FileNameConflictsWithDirectoryNameException" => {
-                    return RusotoError::Service(
-                        CreateCommitError::FileNameConflictsWithDirectoryName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCommitError::FileNameConflictsWithDirectoryName,
                     )
                 }
                 "FilePathConflictsWithSubmodulePathException" => {
-                    return RusotoError::Service(
-                        CreateCommitError::FilePathConflictsWithSubmodulePath(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCommitError::FilePathConflictsWithSubmodulePath,
                     )
                 }
                 "FolderContentSizeLimitExceededException" => {
-                    return RusotoError::Service(CreateCommitError::FolderContentSizeLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCommitError::FolderContentSizeLimitExceeded,
+                    )
                 }
                 "InvalidBranchNameException" => {
-                    return RusotoError::Service(CreateCommitError::InvalidBranchName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCommitError::InvalidBranchName,
+                    )
                 }
                 "InvalidDeletionParameterException" => {
-                    return RusotoError::Service(CreateCommitError::InvalidDeletionParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCommitError::InvalidDeletionParameter,
+                    )
                 }
                 "InvalidEmailException" => {
-                    return RusotoError::Service(CreateCommitError::InvalidEmail(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCommitError::InvalidEmail,
+                    )
                 }
                 "InvalidFileModeException" => {
-                    return RusotoError::Service(CreateCommitError::InvalidFileMode(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCommitError::InvalidFileMode,
+                    )
                 }
                 "InvalidParentCommitIdException" => {
-                    return RusotoError::Service(CreateCommitError::InvalidParentCommitId(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCommitError::InvalidParentCommitId,
+                    )
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(CreateCommitError::InvalidPath(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCommitError::InvalidPath,
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(CreateCommitError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCommitError::InvalidRepositoryName,
+                    )
                 }
                 "MaximumFileEntriesExceededException" => {
-                    return RusotoError::Service(CreateCommitError::MaximumFileEntriesExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCommitError::MaximumFileEntriesExceeded,
+                    )
                 }
                 "NameLengthExceededException" => {
-                    return RusotoError::Service(CreateCommitError::NameLengthExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCommitError::NameLengthExceeded,
+                    )
                 }
                 "NoChangeException" => {
-                    return RusotoError::Service(CreateCommitError::NoChange(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCommitError::NoChange,
+                    )
                 }
                 "ParentCommitDoesNotExistException" => {
-                    return RusotoError::Service(CreateCommitError::ParentCommitDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCommitError::ParentCommitDoesNotExist,
+                    )
                 }
                 "ParentCommitIdOutdatedException" => {
-                    return RusotoError::Service(CreateCommitError::ParentCommitIdOutdated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCommitError::ParentCommitIdOutdated,
+                    )
                 }
                 "ParentCommitIdRequiredException" => {
-                    return RusotoError::Service(CreateCommitError::ParentCommitIdRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCommitError::ParentCommitIdRequired,
+                    )
                 }
                 "PathRequiredException" => {
-                    return RusotoError::Service(CreateCommitError::PathRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCommitError::PathRequired,
+                    )
                 }
                 "PutFileEntryConflictException" => {
-                    return RusotoError::Service(CreateCommitError::PutFileEntryConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCommitError::PutFileEntryConflict,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(CreateCommitError::RepositoryDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCommitError::RepositoryDoesNotExist,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(CreateCommitError::RepositoryNameRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCommitError::RepositoryNameRequired,
+                    )
                 }
                 "RestrictedSourceFileException" => {
-                    return RusotoError::Service(CreateCommitError::RestrictedSourceFile(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCommitError::RestrictedSourceFile,
+                    )
                 }
                 "SamePathRequestException" => {
-                    return RusotoError::Service(CreateCommitError::SamePathRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCommitError::SamePathRequest,
+                    )
                 }
                 "SourceFileOrContentRequiredException" => {
-                    return RusotoError::Service(CreateCommitError::SourceFileOrContentRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCommitError::SourceFileOrContentRequired,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4760,119 +5115,206 @@ impl CreatePullRequestError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientRequestTokenRequiredException" => {
-                    return RusotoError::Service(
-                        CreatePullRequestError::ClientRequestTokenRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePullRequestError::ClientRequestTokenRequired,
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        CreatePullRequestError::EncryptionIntegrityChecksFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePullRequestError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(CreatePullRequestError::EncryptionKeyAccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePullRequestError::EncryptionKeyAccessDenied,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(CreatePullRequestError::EncryptionKeyDisabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePullRequestError::EncryptionKeyDisabled,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(CreatePullRequestError::EncryptionKeyNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePullRequestError::EncryptionKeyNotFound,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(CreatePullRequestError::EncryptionKeyUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePullRequestError::EncryptionKeyUnavailable,
+                    )
                 }
                 "IdempotencyParameterMismatchException" => {
-                    return RusotoError::Service(
-                        CreatePullRequestError::IdempotencyParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePullRequestError::IdempotencyParameterMismatch,
                     )
                 }
                 "InvalidClientRequestTokenException" => {
-                    return RusotoError::Service(CreatePullRequestError::InvalidClientRequestToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePullRequestError::InvalidClientRequestToken,
+                    )
                 }
                 "InvalidDescriptionException" => {
-                    return RusotoError::Service(CreatePullRequestError::InvalidDescription(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePullRequestError::InvalidDescription,
+                    )
                 }
                 "InvalidReferenceNameException" => {
-                    return RusotoError::Service(CreatePullRequestError::InvalidReferenceName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePullRequestError::InvalidReferenceName,
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(CreatePullRequestError::InvalidRepositoryName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePullRequestError::InvalidRepositoryName,
+                    )
                 }
                 "InvalidTargetException" => {
-                    return RusotoError::Service(CreatePullRequestError::InvalidTarget(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePullRequestError::InvalidTarget,
+                    )
                 }
                 "InvalidTargetsException" => {
-                    return RusotoError::Service(CreatePullRequestError::InvalidTargets(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePullRequestError::InvalidTargets,
+                    )
                 }
                 "InvalidTitleException" => {
-                    return RusotoError::Service(CreatePullRequestError::InvalidTitle(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePullRequestError::InvalidTitle,
+                    )
                 }
                 "MaximumOpenPullRequestsExceededException" => {
-                    return RusotoError::Service(
-                        CreatePullRequestError::MaximumOpenPullRequestsExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePullRequestError::MaximumOpenPullRequestsExceeded,
                     )
                 }
                 "MultipleRepositoriesInPullRequestException" => {
-                    return RusotoError::Service(
-                        CreatePullRequestError::MultipleRepositoriesInPullRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePullRequestError::MultipleRepositoriesInPullRequest,
                     )
                 }
                 "ReferenceDoesNotExistException" => {
-                    return RusotoError::Service(CreatePullRequestError::ReferenceDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePullRequestError::ReferenceDoesNotExist,
+                    )
                 }
                 "ReferenceNameRequiredException" => {
-                    return RusotoError::Service(CreatePullRequestError::ReferenceNameRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePullRequestError::ReferenceNameRequired,
+                    )
                 }
                 "ReferenceTypeNotSupportedException" => {
-                    return RusotoError::Service(CreatePullRequestError::ReferenceTypeNotSupported(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePullRequestError::ReferenceTypeNotSupported,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(CreatePullRequestError::RepositoryDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePullRequestError::RepositoryDoesNotExist,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(CreatePullRequestError::RepositoryNameRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePullRequestError::RepositoryNameRequired,
+                    )
                 }
                 "SourceAndDestinationAreSameException" => {
-                    return RusotoError::Service(
-                        CreatePullRequestError::SourceAndDestinationAreSame(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePullRequestError::SourceAndDestinationAreSame,
                     )
                 }
                 "TargetRequiredException" => {
-                    return RusotoError::Service(CreatePullRequestError::TargetRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePullRequestError::TargetRequired,
+                    )
                 }
                 "TargetsRequiredException" => {
-                    return RusotoError::Service(CreatePullRequestError::TargetsRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePullRequestError::TargetsRequired,
+                    )
                 }
                 "TitleRequiredException" => {
-                    return RusotoError::Service(CreatePullRequestError::TitleRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePullRequestError::TitleRequired,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4964,83 +5406,126 @@ impl CreatePullRequestApprovalRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApprovalRuleContentRequiredException" => {
-                    return RusotoError::Service(
-                        CreatePullRequestApprovalRuleError::ApprovalRuleContentRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePullRequestApprovalRuleError::ApprovalRuleContentRequired,
                     )
                 }
                 "ApprovalRuleNameAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        CreatePullRequestApprovalRuleError::ApprovalRuleNameAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePullRequestApprovalRuleError::ApprovalRuleNameAlreadyExists,
                     )
                 }
                 "ApprovalRuleNameRequiredException" => {
-                    return RusotoError::Service(
-                        CreatePullRequestApprovalRuleError::ApprovalRuleNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePullRequestApprovalRuleError::ApprovalRuleNameRequired,
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        CreatePullRequestApprovalRuleError::EncryptionIntegrityChecksFailed(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePullRequestApprovalRuleError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
-                        CreatePullRequestApprovalRuleError::EncryptionKeyAccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePullRequestApprovalRuleError::EncryptionKeyAccessDenied,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
-                        CreatePullRequestApprovalRuleError::EncryptionKeyDisabled(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePullRequestApprovalRuleError::EncryptionKeyDisabled,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
-                        CreatePullRequestApprovalRuleError::EncryptionKeyNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePullRequestApprovalRuleError::EncryptionKeyNotFound,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
-                        CreatePullRequestApprovalRuleError::EncryptionKeyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePullRequestApprovalRuleError::EncryptionKeyUnavailable,
                     )
                 }
                 "InvalidApprovalRuleContentException" => {
-                    return RusotoError::Service(
-                        CreatePullRequestApprovalRuleError::InvalidApprovalRuleContent(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePullRequestApprovalRuleError::InvalidApprovalRuleContent,
                     )
                 }
                 "InvalidApprovalRuleNameException" => {
-                    return RusotoError::Service(
-                        CreatePullRequestApprovalRuleError::InvalidApprovalRuleName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePullRequestApprovalRuleError::InvalidApprovalRuleName,
                     )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
-                        CreatePullRequestApprovalRuleError::InvalidPullRequestId(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePullRequestApprovalRuleError::InvalidPullRequestId,
                     )
                 }
                 "NumberOfRulesExceededException" => {
-                    return RusotoError::Service(
-                        CreatePullRequestApprovalRuleError::NumberOfRulesExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePullRequestApprovalRuleError::NumberOfRulesExceeded,
                     )
                 }
                 "PullRequestAlreadyClosedException" => {
-                    return RusotoError::Service(
-                        CreatePullRequestApprovalRuleError::PullRequestAlreadyClosed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePullRequestApprovalRuleError::PullRequestAlreadyClosed,
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
-                        CreatePullRequestApprovalRuleError::PullRequestDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePullRequestApprovalRuleError::PullRequestDoesNotExist,
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
-                        CreatePullRequestApprovalRuleError::PullRequestIdRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePullRequestApprovalRuleError::PullRequestIdRequired,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5138,70 +5623,118 @@ impl CreateRepositoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        CreateRepositoryError::EncryptionIntegrityChecksFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRepositoryError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(CreateRepositoryError::EncryptionKeyAccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRepositoryError::EncryptionKeyAccessDenied,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(CreateRepositoryError::EncryptionKeyDisabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRepositoryError::EncryptionKeyDisabled,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(CreateRepositoryError::EncryptionKeyNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRepositoryError::EncryptionKeyNotFound,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(CreateRepositoryError::EncryptionKeyUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRepositoryError::EncryptionKeyUnavailable,
+                    )
                 }
                 "InvalidRepositoryDescriptionException" => {
-                    return RusotoError::Service(
-                        CreateRepositoryError::InvalidRepositoryDescription(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRepositoryError::InvalidRepositoryDescription,
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(CreateRepositoryError::InvalidRepositoryName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRepositoryError::InvalidRepositoryName,
+                    )
                 }
                 "InvalidSystemTagUsageException" => {
-                    return RusotoError::Service(CreateRepositoryError::InvalidSystemTagUsage(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRepositoryError::InvalidSystemTagUsage,
+                    )
                 }
                 "InvalidTagsMapException" => {
-                    return RusotoError::Service(CreateRepositoryError::InvalidTagsMap(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRepositoryError::InvalidTagsMap,
+                    )
                 }
                 "RepositoryLimitExceededException" => {
-                    return RusotoError::Service(CreateRepositoryError::RepositoryLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRepositoryError::RepositoryLimitExceeded,
+                    )
                 }
                 "RepositoryNameExistsException" => {
-                    return RusotoError::Service(CreateRepositoryError::RepositoryNameExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRepositoryError::RepositoryNameExists,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(CreateRepositoryError::RepositoryNameRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRepositoryError::RepositoryNameRequired,
+                    )
                 }
                 "TagPolicyException" => {
-                    return RusotoError::Service(CreateRepositoryError::TagPolicy(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRepositoryError::TagPolicy,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreateRepositoryError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRepositoryError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5318,194 +5851,112 @@ impl CreateUnreferencedMergeCommitError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(
-                        CreateUnreferencedMergeCommitError::CommitDoesNotExist(err.msg),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, CreateUnreferencedMergeCommitError::CommitDoesNotExist)
                 }
                 "CommitMessageLengthExceededException" => {
-                    return RusotoError::Service(
-                        CreateUnreferencedMergeCommitError::CommitMessageLengthExceeded(err.msg),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, CreateUnreferencedMergeCommitError::CommitMessageLengthExceeded)
                 }
                 "CommitRequiredException" => {
-                    return RusotoError::Service(
-                        CreateUnreferencedMergeCommitError::CommitRequired(err.msg),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, CreateUnreferencedMergeCommitError::CommitRequired)
                 }
                 "ConcurrentReferenceUpdateException" => {
-                    return RusotoError::Service(
-                        CreateUnreferencedMergeCommitError::ConcurrentReferenceUpdate(err.msg),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, CreateUnreferencedMergeCommitError::ConcurrentReferenceUpdate)
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        CreateUnreferencedMergeCommitError::EncryptionIntegrityChecksFailed(
-                            err.msg,
-                        ),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, CreateUnreferencedMergeCommitError::EncryptionIntegrityChecksFailed)
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
-                        CreateUnreferencedMergeCommitError::EncryptionKeyAccessDenied(err.msg),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, CreateUnreferencedMergeCommitError::EncryptionKeyAccessDenied)
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
-                        CreateUnreferencedMergeCommitError::EncryptionKeyDisabled(err.msg),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, CreateUnreferencedMergeCommitError::EncryptionKeyDisabled)
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
-                        CreateUnreferencedMergeCommitError::EncryptionKeyNotFound(err.msg),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, CreateUnreferencedMergeCommitError::EncryptionKeyNotFound)
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
-                        CreateUnreferencedMergeCommitError::EncryptionKeyUnavailable(err.msg),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, CreateUnreferencedMergeCommitError::EncryptionKeyUnavailable)
                 }
                 "FileContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
-                        CreateUnreferencedMergeCommitError::FileContentSizeLimitExceeded(err.msg),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, CreateUnreferencedMergeCommitError::FileContentSizeLimitExceeded)
                 }
                 "FileModeRequiredException" => {
-                    return RusotoError::Service(
-                        CreateUnreferencedMergeCommitError::FileModeRequired(err.msg),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, CreateUnreferencedMergeCommitError::FileModeRequired)
                 }
                 "FolderContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
-                        CreateUnreferencedMergeCommitError::FolderContentSizeLimitExceeded(err.msg),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, CreateUnreferencedMergeCommitError::FolderContentSizeLimitExceeded)
                 }
                 "InvalidCommitException" => {
-                    return RusotoError::Service(CreateUnreferencedMergeCommitError::InvalidCommit(
-                        err.msg,
-                    ))
+                    return RusotoError::service_error(&res, &err.typ, err.msg, CreateUnreferencedMergeCommitError::InvalidCommit)
                 }
                 "InvalidConflictDetailLevelException" => {
-                    return RusotoError::Service(
-                        CreateUnreferencedMergeCommitError::InvalidConflictDetailLevel(err.msg),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, CreateUnreferencedMergeCommitError::InvalidConflictDetailLevel)
                 }
                 "InvalidConflictResolutionException" => {
-                    return RusotoError::Service(
-                        CreateUnreferencedMergeCommitError::InvalidConflictResolution(err.msg),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, CreateUnreferencedMergeCommitError::InvalidConflictResolution)
                 }
                 "InvalidConflictResolutionStrategyException" => {
-                    return RusotoError::Service(
-                        CreateUnreferencedMergeCommitError::InvalidConflictResolutionStrategy(
-                            err.msg,
-                        ),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, CreateUnreferencedMergeCommitError::InvalidConflictResolutionStrategy)
                 }
                 "InvalidEmailException" => {
-                    return RusotoError::Service(CreateUnreferencedMergeCommitError::InvalidEmail(
-                        err.msg,
-                    ))
+                    return RusotoError::service_error(&res, &err.typ, err.msg, CreateUnreferencedMergeCommitError::InvalidEmail)
                 }
                 "InvalidFileModeException" => {
-                    return RusotoError::Service(
-                        CreateUnreferencedMergeCommitError::InvalidFileMode(err.msg),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, CreateUnreferencedMergeCommitError::InvalidFileMode)
                 }
                 "InvalidMergeOptionException" => {
-                    return RusotoError::Service(
-                        CreateUnreferencedMergeCommitError::InvalidMergeOption(err.msg),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, CreateUnreferencedMergeCommitError::InvalidMergeOption)
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(CreateUnreferencedMergeCommitError::InvalidPath(
-                        err.msg,
-                    ))
+                    return RusotoError::service_error(&res, &err.typ, err.msg, CreateUnreferencedMergeCommitError::InvalidPath)
                 }
                 "InvalidReplacementContentException" => {
-                    return RusotoError::Service(
-                        CreateUnreferencedMergeCommitError::InvalidReplacementContent(err.msg),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, CreateUnreferencedMergeCommitError::InvalidReplacementContent)
                 }
                 "InvalidReplacementTypeException" => {
-                    return RusotoError::Service(
-                        CreateUnreferencedMergeCommitError::InvalidReplacementType(err.msg),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, CreateUnreferencedMergeCommitError::InvalidReplacementType)
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
-                        CreateUnreferencedMergeCommitError::InvalidRepositoryName(err.msg),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, CreateUnreferencedMergeCommitError::InvalidRepositoryName)
                 }
                 "ManualMergeRequiredException" => {
-                    return RusotoError::Service(
-                        CreateUnreferencedMergeCommitError::ManualMergeRequired(err.msg),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, CreateUnreferencedMergeCommitError::ManualMergeRequired)
                 }
-                "MaximumConflictResolutionEntriesExceededException" => return RusotoError::Service(
-                    CreateUnreferencedMergeCommitError::MaximumConflictResolutionEntriesExceeded(
-                        err.msg,
-                    ),
-                ),
+                "MaximumConflictResolutionEntriesExceededException" => return RusotoError::service_error(&res, &err.typ, err.msg, CreateUnreferencedMergeCommitError::MaximumConflictResolutionEntriesExceeded),
                 "MaximumFileContentToLoadExceededException" => {
-                    return RusotoError::Service(
-                        CreateUnreferencedMergeCommitError::MaximumFileContentToLoadExceeded(
-                            err.msg,
-                        ),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, CreateUnreferencedMergeCommitError::MaximumFileContentToLoadExceeded)
                 }
                 "MaximumItemsToCompareExceededException" => {
-                    return RusotoError::Service(
-                        CreateUnreferencedMergeCommitError::MaximumItemsToCompareExceeded(err.msg),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, CreateUnreferencedMergeCommitError::MaximumItemsToCompareExceeded)
                 }
                 "MergeOptionRequiredException" => {
-                    return RusotoError::Service(
-                        CreateUnreferencedMergeCommitError::MergeOptionRequired(err.msg),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, CreateUnreferencedMergeCommitError::MergeOptionRequired)
                 }
                 "MultipleConflictResolutionEntriesException" => {
-                    return RusotoError::Service(
-                        CreateUnreferencedMergeCommitError::MultipleConflictResolutionEntries(
-                            err.msg,
-                        ),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, CreateUnreferencedMergeCommitError::MultipleConflictResolutionEntries)
                 }
                 "NameLengthExceededException" => {
-                    return RusotoError::Service(
-                        CreateUnreferencedMergeCommitError::NameLengthExceeded(err.msg),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, CreateUnreferencedMergeCommitError::NameLengthExceeded)
                 }
                 "PathRequiredException" => {
-                    return RusotoError::Service(CreateUnreferencedMergeCommitError::PathRequired(
-                        err.msg,
-                    ))
+                    return RusotoError::service_error(&res, &err.typ, err.msg, CreateUnreferencedMergeCommitError::PathRequired)
                 }
                 "ReplacementContentRequiredException" => {
-                    return RusotoError::Service(
-                        CreateUnreferencedMergeCommitError::ReplacementContentRequired(err.msg),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, CreateUnreferencedMergeCommitError::ReplacementContentRequired)
                 }
                 "ReplacementTypeRequiredException" => {
-                    return RusotoError::Service(
-                        CreateUnreferencedMergeCommitError::ReplacementTypeRequired(err.msg),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, CreateUnreferencedMergeCommitError::ReplacementTypeRequired)
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
-                        CreateUnreferencedMergeCommitError::RepositoryDoesNotExist(err.msg),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, CreateUnreferencedMergeCommitError::RepositoryDoesNotExist)
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
-                        CreateUnreferencedMergeCommitError::RepositoryNameRequired(err.msg),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, CreateUnreferencedMergeCommitError::RepositoryNameRequired)
                 }
                 "TipsDivergenceExceededException" => {
-                    return RusotoError::Service(
-                        CreateUnreferencedMergeCommitError::TipsDivergenceExceeded(err.msg),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, CreateUnreferencedMergeCommitError::TipsDivergenceExceeded)
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5636,21 +6087,30 @@ impl DeleteApprovalRuleTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApprovalRuleTemplateInUseException" => {
-                    return RusotoError::Service(
-                        DeleteApprovalRuleTemplateError::ApprovalRuleTemplateInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApprovalRuleTemplateError::ApprovalRuleTemplateInUse,
                     )
                 }
                 "ApprovalRuleTemplateNameRequiredException" => {
-                    return RusotoError::Service(
-                        DeleteApprovalRuleTemplateError::ApprovalRuleTemplateNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApprovalRuleTemplateError::ApprovalRuleTemplateNameRequired,
                     )
                 }
                 "InvalidApprovalRuleTemplateNameException" => {
-                    return RusotoError::Service(
-                        DeleteApprovalRuleTemplateError::InvalidApprovalRuleTemplateName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApprovalRuleTemplateError::InvalidApprovalRuleTemplateName,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5706,47 +6166,94 @@ impl DeleteBranchError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BranchNameRequiredException" => {
-                    return RusotoError::Service(DeleteBranchError::BranchNameRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBranchError::BranchNameRequired,
+                    )
                 }
                 "DefaultBranchCannotBeDeletedException" => {
-                    return RusotoError::Service(DeleteBranchError::DefaultBranchCannotBeDeleted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteBranchError::DefaultBranchCannotBeDeleted,
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        DeleteBranchError::EncryptionIntegrityChecksFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBranchError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(DeleteBranchError::EncryptionKeyAccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteBranchError::EncryptionKeyAccessDenied,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(DeleteBranchError::EncryptionKeyDisabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBranchError::EncryptionKeyDisabled,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(DeleteBranchError::EncryptionKeyNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBranchError::EncryptionKeyNotFound,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(DeleteBranchError::EncryptionKeyUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteBranchError::EncryptionKeyUnavailable,
+                    )
                 }
                 "InvalidBranchNameException" => {
-                    return RusotoError::Service(DeleteBranchError::InvalidBranchName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBranchError::InvalidBranchName,
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(DeleteBranchError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBranchError::InvalidRepositoryName,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(DeleteBranchError::RepositoryDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBranchError::RepositoryDoesNotExist,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(DeleteBranchError::RepositoryNameRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBranchError::RepositoryNameRequired,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5790,24 +6297,38 @@ impl DeleteCommentContentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommentDeletedException" => {
-                    return RusotoError::Service(DeleteCommentContentError::CommentDeleted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCommentContentError::CommentDeleted,
+                    )
                 }
                 "CommentDoesNotExistException" => {
-                    return RusotoError::Service(DeleteCommentContentError::CommentDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteCommentContentError::CommentDoesNotExist,
+                    )
                 }
                 "CommentIdRequiredException" => {
-                    return RusotoError::Service(DeleteCommentContentError::CommentIdRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteCommentContentError::CommentIdRequired,
+                    )
                 }
                 "InvalidCommentIdException" => {
-                    return RusotoError::Service(DeleteCommentContentError::InvalidCommentId(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteCommentContentError::InvalidCommentId,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5880,78 +6401,182 @@ impl DeleteFileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BranchDoesNotExistException" => {
-                    return RusotoError::Service(DeleteFileError::BranchDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFileError::BranchDoesNotExist,
+                    )
                 }
                 "BranchNameIsTagNameException" => {
-                    return RusotoError::Service(DeleteFileError::BranchNameIsTagName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFileError::BranchNameIsTagName,
+                    )
                 }
                 "BranchNameRequiredException" => {
-                    return RusotoError::Service(DeleteFileError::BranchNameRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFileError::BranchNameRequired,
+                    )
                 }
                 "CommitMessageLengthExceededException" => {
-                    return RusotoError::Service(DeleteFileError::CommitMessageLengthExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteFileError::CommitMessageLengthExceeded,
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(DeleteFileError::EncryptionIntegrityChecksFailed(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteFileError::EncryptionIntegrityChecksFailed,
+                    )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(DeleteFileError::EncryptionKeyAccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteFileError::EncryptionKeyAccessDenied,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(DeleteFileError::EncryptionKeyDisabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFileError::EncryptionKeyDisabled,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(DeleteFileError::EncryptionKeyNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFileError::EncryptionKeyNotFound,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(DeleteFileError::EncryptionKeyUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFileError::EncryptionKeyUnavailable,
+                    )
                 }
                 "FileDoesNotExistException" => {
-                    return RusotoError::Service(DeleteFileError::FileDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFileError::FileDoesNotExist,
+                    )
                 }
                 "InvalidBranchNameException" => {
-                    return RusotoError::Service(DeleteFileError::InvalidBranchName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFileError::InvalidBranchName,
+                    )
                 }
                 "InvalidEmailException" => {
-                    return RusotoError::Service(DeleteFileError::InvalidEmail(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFileError::InvalidEmail,
+                    )
                 }
                 "InvalidParentCommitIdException" => {
-                    return RusotoError::Service(DeleteFileError::InvalidParentCommitId(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFileError::InvalidParentCommitId,
+                    )
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(DeleteFileError::InvalidPath(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFileError::InvalidPath,
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(DeleteFileError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFileError::InvalidRepositoryName,
+                    )
                 }
                 "NameLengthExceededException" => {
-                    return RusotoError::Service(DeleteFileError::NameLengthExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFileError::NameLengthExceeded,
+                    )
                 }
                 "ParentCommitDoesNotExistException" => {
-                    return RusotoError::Service(DeleteFileError::ParentCommitDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFileError::ParentCommitDoesNotExist,
+                    )
                 }
                 "ParentCommitIdOutdatedException" => {
-                    return RusotoError::Service(DeleteFileError::ParentCommitIdOutdated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFileError::ParentCommitIdOutdated,
+                    )
                 }
                 "ParentCommitIdRequiredException" => {
-                    return RusotoError::Service(DeleteFileError::ParentCommitIdRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFileError::ParentCommitIdRequired,
+                    )
                 }
                 "PathRequiredException" => {
-                    return RusotoError::Service(DeleteFileError::PathRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFileError::PathRequired,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(DeleteFileError::RepositoryDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFileError::RepositoryDoesNotExist,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(DeleteFileError::RepositoryNameRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFileError::RepositoryNameRequired,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6024,70 +6649,102 @@ impl DeletePullRequestApprovalRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApprovalRuleNameRequiredException" => {
-                    return RusotoError::Service(
-                        DeletePullRequestApprovalRuleError::ApprovalRuleNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePullRequestApprovalRuleError::ApprovalRuleNameRequired,
                     )
                 }
                 "CannotDeleteApprovalRuleFromTemplateException" => {
-                    return RusotoError::Service(
-                        DeletePullRequestApprovalRuleError::CannotDeleteApprovalRuleFromTemplate(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePullRequestApprovalRuleError::CannotDeleteApprovalRuleFromTemplate,
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        DeletePullRequestApprovalRuleError::EncryptionIntegrityChecksFailed(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePullRequestApprovalRuleError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
-                        DeletePullRequestApprovalRuleError::EncryptionKeyAccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePullRequestApprovalRuleError::EncryptionKeyAccessDenied,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
-                        DeletePullRequestApprovalRuleError::EncryptionKeyDisabled(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePullRequestApprovalRuleError::EncryptionKeyDisabled,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
-                        DeletePullRequestApprovalRuleError::EncryptionKeyNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePullRequestApprovalRuleError::EncryptionKeyNotFound,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
-                        DeletePullRequestApprovalRuleError::EncryptionKeyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePullRequestApprovalRuleError::EncryptionKeyUnavailable,
                     )
                 }
                 "InvalidApprovalRuleNameException" => {
-                    return RusotoError::Service(
-                        DeletePullRequestApprovalRuleError::InvalidApprovalRuleName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePullRequestApprovalRuleError::InvalidApprovalRuleName,
                     )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
-                        DeletePullRequestApprovalRuleError::InvalidPullRequestId(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePullRequestApprovalRuleError::InvalidPullRequestId,
                     )
                 }
                 "PullRequestAlreadyClosedException" => {
-                    return RusotoError::Service(
-                        DeletePullRequestApprovalRuleError::PullRequestAlreadyClosed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePullRequestApprovalRuleError::PullRequestAlreadyClosed,
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
-                        DeletePullRequestApprovalRuleError::PullRequestDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePullRequestApprovalRuleError::PullRequestDoesNotExist,
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
-                        DeletePullRequestApprovalRuleError::PullRequestIdRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePullRequestApprovalRuleError::PullRequestIdRequired,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6162,41 +6819,62 @@ impl DeleteRepositoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        DeleteRepositoryError::EncryptionIntegrityChecksFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRepositoryError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(DeleteRepositoryError::EncryptionKeyAccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRepositoryError::EncryptionKeyAccessDenied,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(DeleteRepositoryError::EncryptionKeyDisabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRepositoryError::EncryptionKeyDisabled,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(DeleteRepositoryError::EncryptionKeyNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRepositoryError::EncryptionKeyNotFound,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(DeleteRepositoryError::EncryptionKeyUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRepositoryError::EncryptionKeyUnavailable,
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(DeleteRepositoryError::InvalidRepositoryName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRepositoryError::InvalidRepositoryName,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(DeleteRepositoryError::RepositoryNameRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRepositoryError::RepositoryNameRequired,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6276,117 +6954,190 @@ impl DescribeMergeConflictsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(DescribeMergeConflictsError::CommitDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeMergeConflictsError::CommitDoesNotExist,
+                    )
                 }
                 "CommitRequiredException" => {
-                    return RusotoError::Service(DescribeMergeConflictsError::CommitRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeMergeConflictsError::CommitRequired,
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        DescribeMergeConflictsError::EncryptionIntegrityChecksFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMergeConflictsError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
-                        DescribeMergeConflictsError::EncryptionKeyAccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMergeConflictsError::EncryptionKeyAccessDenied,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
-                        DescribeMergeConflictsError::EncryptionKeyDisabled(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMergeConflictsError::EncryptionKeyDisabled,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeMergeConflictsError::EncryptionKeyNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMergeConflictsError::EncryptionKeyNotFound,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
-                        DescribeMergeConflictsError::EncryptionKeyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMergeConflictsError::EncryptionKeyUnavailable,
                     )
                 }
                 "FileDoesNotExistException" => {
-                    return RusotoError::Service(DescribeMergeConflictsError::FileDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeMergeConflictsError::FileDoesNotExist,
+                    )
                 }
                 "InvalidCommitException" => {
-                    return RusotoError::Service(DescribeMergeConflictsError::InvalidCommit(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeMergeConflictsError::InvalidCommit,
+                    )
                 }
                 "InvalidConflictDetailLevelException" => {
-                    return RusotoError::Service(
-                        DescribeMergeConflictsError::InvalidConflictDetailLevel(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMergeConflictsError::InvalidConflictDetailLevel,
                     )
                 }
                 "InvalidConflictResolutionStrategyException" => {
-                    return RusotoError::Service(
-                        DescribeMergeConflictsError::InvalidConflictResolutionStrategy(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMergeConflictsError::InvalidConflictResolutionStrategy,
                     )
                 }
                 "InvalidContinuationTokenException" => {
-                    return RusotoError::Service(
-                        DescribeMergeConflictsError::InvalidContinuationToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMergeConflictsError::InvalidContinuationToken,
                     )
                 }
                 "InvalidMaxMergeHunksException" => {
-                    return RusotoError::Service(DescribeMergeConflictsError::InvalidMaxMergeHunks(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeMergeConflictsError::InvalidMaxMergeHunks,
+                    )
                 }
                 "InvalidMergeOptionException" => {
-                    return RusotoError::Service(DescribeMergeConflictsError::InvalidMergeOption(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeMergeConflictsError::InvalidMergeOption,
+                    )
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(DescribeMergeConflictsError::InvalidPath(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMergeConflictsError::InvalidPath,
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
-                        DescribeMergeConflictsError::InvalidRepositoryName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMergeConflictsError::InvalidRepositoryName,
                     )
                 }
                 "MaximumFileContentToLoadExceededException" => {
-                    return RusotoError::Service(
-                        DescribeMergeConflictsError::MaximumFileContentToLoadExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMergeConflictsError::MaximumFileContentToLoadExceeded,
                     )
                 }
                 "MaximumItemsToCompareExceededException" => {
-                    return RusotoError::Service(
-                        DescribeMergeConflictsError::MaximumItemsToCompareExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMergeConflictsError::MaximumItemsToCompareExceeded,
                     )
                 }
                 "MergeOptionRequiredException" => {
-                    return RusotoError::Service(DescribeMergeConflictsError::MergeOptionRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeMergeConflictsError::MergeOptionRequired,
+                    )
                 }
                 "PathRequiredException" => {
-                    return RusotoError::Service(DescribeMergeConflictsError::PathRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMergeConflictsError::PathRequired,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
-                        DescribeMergeConflictsError::RepositoryDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMergeConflictsError::RepositoryDoesNotExist,
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
-                        DescribeMergeConflictsError::RepositoryNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMergeConflictsError::RepositoryNameRequired,
                     )
                 }
                 "TipsDivergenceExceededException" => {
-                    return RusotoError::Service(
-                        DescribeMergeConflictsError::TipsDivergenceExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMergeConflictsError::TipsDivergenceExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6482,71 +7233,110 @@ impl DescribePullRequestEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ActorDoesNotExistException" => {
-                    return RusotoError::Service(DescribePullRequestEventsError::ActorDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribePullRequestEventsError::ActorDoesNotExist,
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        DescribePullRequestEventsError::EncryptionIntegrityChecksFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePullRequestEventsError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
-                        DescribePullRequestEventsError::EncryptionKeyAccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePullRequestEventsError::EncryptionKeyAccessDenied,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
-                        DescribePullRequestEventsError::EncryptionKeyDisabled(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePullRequestEventsError::EncryptionKeyDisabled,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribePullRequestEventsError::EncryptionKeyNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePullRequestEventsError::EncryptionKeyNotFound,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
-                        DescribePullRequestEventsError::EncryptionKeyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePullRequestEventsError::EncryptionKeyUnavailable,
                     )
                 }
                 "InvalidActorArnException" => {
-                    return RusotoError::Service(DescribePullRequestEventsError::InvalidActorArn(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribePullRequestEventsError::InvalidActorArn,
+                    )
                 }
                 "InvalidContinuationTokenException" => {
-                    return RusotoError::Service(
-                        DescribePullRequestEventsError::InvalidContinuationToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePullRequestEventsError::InvalidContinuationToken,
                     )
                 }
                 "InvalidMaxResultsException" => {
-                    return RusotoError::Service(DescribePullRequestEventsError::InvalidMaxResults(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribePullRequestEventsError::InvalidMaxResults,
+                    )
                 }
                 "InvalidPullRequestEventTypeException" => {
-                    return RusotoError::Service(
-                        DescribePullRequestEventsError::InvalidPullRequestEventType(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePullRequestEventsError::InvalidPullRequestEventType,
                     )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
-                        DescribePullRequestEventsError::InvalidPullRequestId(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePullRequestEventsError::InvalidPullRequestId,
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
-                        DescribePullRequestEventsError::PullRequestDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePullRequestEventsError::PullRequestDoesNotExist,
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
-                        DescribePullRequestEventsError::PullRequestIdRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePullRequestEventsError::PullRequestIdRequired,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6627,18 +7417,18 @@ impl DisassociateApprovalRuleTemplateFromRepositoryError {
     ) -> RusotoError<DisassociateApprovalRuleTemplateFromRepositoryError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                                "ApprovalRuleTemplateDoesNotExistException" => return RusotoError::Service(DisassociateApprovalRuleTemplateFromRepositoryError::ApprovalRuleTemplateDoesNotExist(err.msg)),
-"ApprovalRuleTemplateNameRequiredException" => return RusotoError::Service(DisassociateApprovalRuleTemplateFromRepositoryError::ApprovalRuleTemplateNameRequired(err.msg)),
-"EncryptionIntegrityChecksFailedException" => return RusotoError::Service(DisassociateApprovalRuleTemplateFromRepositoryError::EncryptionIntegrityChecksFailed(err.msg)),
-"EncryptionKeyAccessDeniedException" => return RusotoError::Service(DisassociateApprovalRuleTemplateFromRepositoryError::EncryptionKeyAccessDenied(err.msg)),
-"EncryptionKeyDisabledException" => return RusotoError::Service(DisassociateApprovalRuleTemplateFromRepositoryError::EncryptionKeyDisabled(err.msg)),
-"EncryptionKeyNotFoundException" => return RusotoError::Service(DisassociateApprovalRuleTemplateFromRepositoryError::EncryptionKeyNotFound(err.msg)),
-"EncryptionKeyUnavailableException" => return RusotoError::Service(DisassociateApprovalRuleTemplateFromRepositoryError::EncryptionKeyUnavailable(err.msg)),
-"InvalidApprovalRuleTemplateNameException" => return RusotoError::Service(DisassociateApprovalRuleTemplateFromRepositoryError::InvalidApprovalRuleTemplateName(err.msg)),
-"InvalidRepositoryNameException" => return RusotoError::Service(DisassociateApprovalRuleTemplateFromRepositoryError::InvalidRepositoryName(err.msg)),
-"RepositoryDoesNotExistException" => return RusotoError::Service(DisassociateApprovalRuleTemplateFromRepositoryError::RepositoryDoesNotExist(err.msg)),
-"RepositoryNameRequiredException" => return RusotoError::Service(DisassociateApprovalRuleTemplateFromRepositoryError::RepositoryNameRequired(err.msg)),
-"ValidationException" => return RusotoError::Validation(err.msg),
+                                "ApprovalRuleTemplateDoesNotExistException" => return RusotoError::service_error(&res, &err.typ, err.msg, DisassociateApprovalRuleTemplateFromRepositoryError::ApprovalRuleTemplateDoesNotExist),
+"ApprovalRuleTemplateNameRequiredException" => return RusotoError::service_error(&res, &err.typ, err.msg, DisassociateApprovalRuleTemplateFromRepositoryError::ApprovalRuleTemplateNameRequired),
+"EncryptionIntegrityChecksFailedException" => return RusotoError::service_error(&res, &err.typ, err.msg, DisassociateApprovalRuleTemplateFromRepositoryError::EncryptionIntegrityChecksFailed),
+"EncryptionKeyAccessDeniedException" => return RusotoError::service_error(&res, &err.typ, err.msg, DisassociateApprovalRuleTemplateFromRepositoryError::EncryptionKeyAccessDenied),
+"EncryptionKeyDisabledException" => return RusotoError::service_error(&res, &err.typ, err.msg, DisassociateApprovalRuleTemplateFromRepositoryError::EncryptionKeyDisabled),
+"EncryptionKeyNotFoundException" => return RusotoError::service_error(&res, &err.typ, err.msg, DisassociateApprovalRuleTemplateFromRepositoryError::EncryptionKeyNotFound),
+"EncryptionKeyUnavailableException" => return RusotoError::service_error(&res, &err.typ, err.msg, DisassociateApprovalRuleTemplateFromRepositoryError::EncryptionKeyUnavailable),
+"InvalidApprovalRuleTemplateNameException" => return RusotoError::service_error(&res, &err.typ, err.msg, DisassociateApprovalRuleTemplateFromRepositoryError::InvalidApprovalRuleTemplateName),
+"InvalidRepositoryNameException" => return RusotoError::service_error(&res, &err.typ, err.msg, DisassociateApprovalRuleTemplateFromRepositoryError::InvalidRepositoryName),
+"RepositoryDoesNotExistException" => return RusotoError::service_error(&res, &err.typ, err.msg, DisassociateApprovalRuleTemplateFromRepositoryError::RepositoryDoesNotExist),
+"RepositoryNameRequiredException" => return RusotoError::service_error(&res, &err.typ, err.msg, DisassociateApprovalRuleTemplateFromRepositoryError::RepositoryNameRequired),
+"ValidationException" => return RusotoError::validation_error(&res, err.msg),
 _ => {}
                             }
         }
@@ -6698,63 +7488,94 @@ impl EvaluatePullRequestApprovalRulesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        EvaluatePullRequestApprovalRulesError::EncryptionIntegrityChecksFailed(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EvaluatePullRequestApprovalRulesError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
-                        EvaluatePullRequestApprovalRulesError::EncryptionKeyAccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EvaluatePullRequestApprovalRulesError::EncryptionKeyAccessDenied,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
-                        EvaluatePullRequestApprovalRulesError::EncryptionKeyDisabled(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EvaluatePullRequestApprovalRulesError::EncryptionKeyDisabled,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
-                        EvaluatePullRequestApprovalRulesError::EncryptionKeyNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EvaluatePullRequestApprovalRulesError::EncryptionKeyNotFound,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
-                        EvaluatePullRequestApprovalRulesError::EncryptionKeyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EvaluatePullRequestApprovalRulesError::EncryptionKeyUnavailable,
                     )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
-                        EvaluatePullRequestApprovalRulesError::InvalidPullRequestId(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EvaluatePullRequestApprovalRulesError::InvalidPullRequestId,
                     )
                 }
                 "InvalidRevisionIdException" => {
-                    return RusotoError::Service(
-                        EvaluatePullRequestApprovalRulesError::InvalidRevisionId(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EvaluatePullRequestApprovalRulesError::InvalidRevisionId,
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
-                        EvaluatePullRequestApprovalRulesError::PullRequestDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EvaluatePullRequestApprovalRulesError::PullRequestDoesNotExist,
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
-                        EvaluatePullRequestApprovalRulesError::PullRequestIdRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EvaluatePullRequestApprovalRulesError::PullRequestIdRequired,
                     )
                 }
                 "RevisionIdRequiredException" => {
-                    return RusotoError::Service(
-                        EvaluatePullRequestApprovalRulesError::RevisionIdRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EvaluatePullRequestApprovalRulesError::RevisionIdRequired,
                     )
                 }
                 "RevisionNotCurrentException" => {
-                    return RusotoError::Service(
-                        EvaluatePullRequestApprovalRulesError::RevisionNotCurrent(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EvaluatePullRequestApprovalRulesError::RevisionNotCurrent,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6818,21 +7639,30 @@ impl GetApprovalRuleTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApprovalRuleTemplateDoesNotExistException" => {
-                    return RusotoError::Service(
-                        GetApprovalRuleTemplateError::ApprovalRuleTemplateDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetApprovalRuleTemplateError::ApprovalRuleTemplateDoesNotExist,
                     )
                 }
                 "ApprovalRuleTemplateNameRequiredException" => {
-                    return RusotoError::Service(
-                        GetApprovalRuleTemplateError::ApprovalRuleTemplateNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetApprovalRuleTemplateError::ApprovalRuleTemplateNameRequired,
                     )
                 }
                 "InvalidApprovalRuleTemplateNameException" => {
-                    return RusotoError::Service(
-                        GetApprovalRuleTemplateError::InvalidApprovalRuleTemplateName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetApprovalRuleTemplateError::InvalidApprovalRuleTemplateName,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6890,44 +7720,102 @@ impl GetBlobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BlobIdDoesNotExistException" => {
-                    return RusotoError::Service(GetBlobError::BlobIdDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBlobError::BlobIdDoesNotExist,
+                    )
                 }
                 "BlobIdRequiredException" => {
-                    return RusotoError::Service(GetBlobError::BlobIdRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBlobError::BlobIdRequired,
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(GetBlobError::EncryptionIntegrityChecksFailed(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetBlobError::EncryptionIntegrityChecksFailed,
+                    )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(GetBlobError::EncryptionKeyAccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBlobError::EncryptionKeyAccessDenied,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(GetBlobError::EncryptionKeyDisabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBlobError::EncryptionKeyDisabled,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(GetBlobError::EncryptionKeyNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBlobError::EncryptionKeyNotFound,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(GetBlobError::EncryptionKeyUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBlobError::EncryptionKeyUnavailable,
+                    )
                 }
                 "FileTooLargeException" => {
-                    return RusotoError::Service(GetBlobError::FileTooLarge(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBlobError::FileTooLarge,
+                    )
                 }
                 "InvalidBlobIdException" => {
-                    return RusotoError::Service(GetBlobError::InvalidBlobId(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBlobError::InvalidBlobId,
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(GetBlobError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBlobError::InvalidRepositoryName,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(GetBlobError::RepositoryDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBlobError::RepositoryDoesNotExist,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(GetBlobError::RepositoryNameRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBlobError::RepositoryNameRequired,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6986,41 +7874,94 @@ impl GetBranchError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BranchDoesNotExistException" => {
-                    return RusotoError::Service(GetBranchError::BranchDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBranchError::BranchDoesNotExist,
+                    )
                 }
                 "BranchNameRequiredException" => {
-                    return RusotoError::Service(GetBranchError::BranchNameRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBranchError::BranchNameRequired,
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(GetBranchError::EncryptionIntegrityChecksFailed(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetBranchError::EncryptionIntegrityChecksFailed,
+                    )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(GetBranchError::EncryptionKeyAccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBranchError::EncryptionKeyAccessDenied,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(GetBranchError::EncryptionKeyDisabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBranchError::EncryptionKeyDisabled,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(GetBranchError::EncryptionKeyNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBranchError::EncryptionKeyNotFound,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(GetBranchError::EncryptionKeyUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBranchError::EncryptionKeyUnavailable,
+                    )
                 }
                 "InvalidBranchNameException" => {
-                    return RusotoError::Service(GetBranchError::InvalidBranchName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBranchError::InvalidBranchName,
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(GetBranchError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBranchError::InvalidRepositoryName,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(GetBranchError::RepositoryDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBranchError::RepositoryDoesNotExist,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(GetBranchError::RepositoryNameRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBranchError::RepositoryNameRequired,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7074,37 +8015,78 @@ impl GetCommentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommentDeletedException" => {
-                    return RusotoError::Service(GetCommentError::CommentDeleted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommentError::CommentDeleted,
+                    )
                 }
                 "CommentDoesNotExistException" => {
-                    return RusotoError::Service(GetCommentError::CommentDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommentError::CommentDoesNotExist,
+                    )
                 }
                 "CommentIdRequiredException" => {
-                    return RusotoError::Service(GetCommentError::CommentIdRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommentError::CommentIdRequired,
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(GetCommentError::EncryptionIntegrityChecksFailed(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCommentError::EncryptionIntegrityChecksFailed,
+                    )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(GetCommentError::EncryptionKeyAccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCommentError::EncryptionKeyAccessDenied,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(GetCommentError::EncryptionKeyDisabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommentError::EncryptionKeyDisabled,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(GetCommentError::EncryptionKeyNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommentError::EncryptionKeyNotFound,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(GetCommentError::EncryptionKeyUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommentError::EncryptionKeyUnavailable,
+                    )
                 }
                 "InvalidCommentIdException" => {
-                    return RusotoError::Service(GetCommentError::InvalidCommentId(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommentError::InvalidCommentId,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7152,39 +8134,62 @@ impl GetCommentReactionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommentDeletedException" => {
-                    return RusotoError::Service(GetCommentReactionsError::CommentDeleted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommentReactionsError::CommentDeleted,
+                    )
                 }
                 "CommentDoesNotExistException" => {
-                    return RusotoError::Service(GetCommentReactionsError::CommentDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCommentReactionsError::CommentDoesNotExist,
+                    )
                 }
                 "CommentIdRequiredException" => {
-                    return RusotoError::Service(GetCommentReactionsError::CommentIdRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCommentReactionsError::CommentIdRequired,
+                    )
                 }
                 "InvalidCommentIdException" => {
-                    return RusotoError::Service(GetCommentReactionsError::InvalidCommentId(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCommentReactionsError::InvalidCommentId,
+                    )
                 }
                 "InvalidContinuationTokenException" => {
-                    return RusotoError::Service(
-                        GetCommentReactionsError::InvalidContinuationToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommentReactionsError::InvalidContinuationToken,
                     )
                 }
                 "InvalidMaxResultsException" => {
-                    return RusotoError::Service(GetCommentReactionsError::InvalidMaxResults(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCommentReactionsError::InvalidMaxResults,
+                    )
                 }
                 "InvalidReactionUserArnException" => {
-                    return RusotoError::Service(GetCommentReactionsError::InvalidReactionUserArn(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCommentReactionsError::InvalidReactionUserArn,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7244,71 +8249,110 @@ impl GetCommentsForComparedCommitError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(
-                        GetCommentsForComparedCommitError::CommitDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommentsForComparedCommitError::CommitDoesNotExist,
                     )
                 }
                 "CommitIdRequiredException" => {
-                    return RusotoError::Service(
-                        GetCommentsForComparedCommitError::CommitIdRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommentsForComparedCommitError::CommitIdRequired,
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        GetCommentsForComparedCommitError::EncryptionIntegrityChecksFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommentsForComparedCommitError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
-                        GetCommentsForComparedCommitError::EncryptionKeyAccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommentsForComparedCommitError::EncryptionKeyAccessDenied,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
-                        GetCommentsForComparedCommitError::EncryptionKeyDisabled(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommentsForComparedCommitError::EncryptionKeyDisabled,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
-                        GetCommentsForComparedCommitError::EncryptionKeyNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommentsForComparedCommitError::EncryptionKeyNotFound,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
-                        GetCommentsForComparedCommitError::EncryptionKeyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommentsForComparedCommitError::EncryptionKeyUnavailable,
                     )
                 }
                 "InvalidCommitIdException" => {
-                    return RusotoError::Service(
-                        GetCommentsForComparedCommitError::InvalidCommitId(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommentsForComparedCommitError::InvalidCommitId,
                     )
                 }
                 "InvalidContinuationTokenException" => {
-                    return RusotoError::Service(
-                        GetCommentsForComparedCommitError::InvalidContinuationToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommentsForComparedCommitError::InvalidContinuationToken,
                     )
                 }
                 "InvalidMaxResultsException" => {
-                    return RusotoError::Service(
-                        GetCommentsForComparedCommitError::InvalidMaxResults(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommentsForComparedCommitError::InvalidMaxResults,
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
-                        GetCommentsForComparedCommitError::InvalidRepositoryName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommentsForComparedCommitError::InvalidRepositoryName,
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
-                        GetCommentsForComparedCommitError::RepositoryDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommentsForComparedCommitError::RepositoryDoesNotExist,
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
-                        GetCommentsForComparedCommitError::RepositoryNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommentsForComparedCommitError::RepositoryNameRequired,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7404,93 +8448,142 @@ impl GetCommentsForPullRequestError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(
-                        GetCommentsForPullRequestError::CommitDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommentsForPullRequestError::CommitDoesNotExist,
                     )
                 }
                 "CommitIdRequiredException" => {
-                    return RusotoError::Service(GetCommentsForPullRequestError::CommitIdRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCommentsForPullRequestError::CommitIdRequired,
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        GetCommentsForPullRequestError::EncryptionIntegrityChecksFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommentsForPullRequestError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
-                        GetCommentsForPullRequestError::EncryptionKeyAccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommentsForPullRequestError::EncryptionKeyAccessDenied,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
-                        GetCommentsForPullRequestError::EncryptionKeyDisabled(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommentsForPullRequestError::EncryptionKeyDisabled,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
-                        GetCommentsForPullRequestError::EncryptionKeyNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommentsForPullRequestError::EncryptionKeyNotFound,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
-                        GetCommentsForPullRequestError::EncryptionKeyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommentsForPullRequestError::EncryptionKeyUnavailable,
                     )
                 }
                 "InvalidCommitIdException" => {
-                    return RusotoError::Service(GetCommentsForPullRequestError::InvalidCommitId(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCommentsForPullRequestError::InvalidCommitId,
+                    )
                 }
                 "InvalidContinuationTokenException" => {
-                    return RusotoError::Service(
-                        GetCommentsForPullRequestError::InvalidContinuationToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommentsForPullRequestError::InvalidContinuationToken,
                     )
                 }
                 "InvalidMaxResultsException" => {
-                    return RusotoError::Service(GetCommentsForPullRequestError::InvalidMaxResults(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCommentsForPullRequestError::InvalidMaxResults,
+                    )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
-                        GetCommentsForPullRequestError::InvalidPullRequestId(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommentsForPullRequestError::InvalidPullRequestId,
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
-                        GetCommentsForPullRequestError::InvalidRepositoryName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommentsForPullRequestError::InvalidRepositoryName,
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
-                        GetCommentsForPullRequestError::PullRequestDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommentsForPullRequestError::PullRequestDoesNotExist,
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
-                        GetCommentsForPullRequestError::PullRequestIdRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommentsForPullRequestError::PullRequestIdRequired,
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
-                        GetCommentsForPullRequestError::RepositoryDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommentsForPullRequestError::RepositoryDoesNotExist,
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
-                        GetCommentsForPullRequestError::RepositoryNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommentsForPullRequestError::RepositoryNameRequired,
                     )
                 }
                 "RepositoryNotAssociatedWithPullRequestException" => {
-                    return RusotoError::Service(
-                        GetCommentsForPullRequestError::RepositoryNotAssociatedWithPullRequest(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommentsForPullRequestError::RepositoryNotAssociatedWithPullRequest,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7580,41 +8673,94 @@ impl GetCommitError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitIdDoesNotExistException" => {
-                    return RusotoError::Service(GetCommitError::CommitIdDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommitError::CommitIdDoesNotExist,
+                    )
                 }
                 "CommitIdRequiredException" => {
-                    return RusotoError::Service(GetCommitError::CommitIdRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommitError::CommitIdRequired,
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(GetCommitError::EncryptionIntegrityChecksFailed(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCommitError::EncryptionIntegrityChecksFailed,
+                    )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(GetCommitError::EncryptionKeyAccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommitError::EncryptionKeyAccessDenied,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(GetCommitError::EncryptionKeyDisabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommitError::EncryptionKeyDisabled,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(GetCommitError::EncryptionKeyNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommitError::EncryptionKeyNotFound,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(GetCommitError::EncryptionKeyUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommitError::EncryptionKeyUnavailable,
+                    )
                 }
                 "InvalidCommitIdException" => {
-                    return RusotoError::Service(GetCommitError::InvalidCommitId(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommitError::InvalidCommitId,
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(GetCommitError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommitError::InvalidRepositoryName,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(GetCommitError::RepositoryDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommitError::RepositoryDoesNotExist,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(GetCommitError::RepositoryNameRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCommitError::RepositoryNameRequired,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7682,72 +8828,134 @@ impl GetDifferencesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(GetDifferencesError::CommitDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDifferencesError::CommitDoesNotExist,
+                    )
                 }
                 "CommitRequiredException" => {
-                    return RusotoError::Service(GetDifferencesError::CommitRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDifferencesError::CommitRequired,
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        GetDifferencesError::EncryptionIntegrityChecksFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDifferencesError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(GetDifferencesError::EncryptionKeyAccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDifferencesError::EncryptionKeyAccessDenied,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(GetDifferencesError::EncryptionKeyDisabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDifferencesError::EncryptionKeyDisabled,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(GetDifferencesError::EncryptionKeyNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDifferencesError::EncryptionKeyNotFound,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(GetDifferencesError::EncryptionKeyUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDifferencesError::EncryptionKeyUnavailable,
+                    )
                 }
                 "InvalidCommitException" => {
-                    return RusotoError::Service(GetDifferencesError::InvalidCommit(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDifferencesError::InvalidCommit,
+                    )
                 }
                 "InvalidCommitIdException" => {
-                    return RusotoError::Service(GetDifferencesError::InvalidCommitId(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDifferencesError::InvalidCommitId,
+                    )
                 }
                 "InvalidContinuationTokenException" => {
-                    return RusotoError::Service(GetDifferencesError::InvalidContinuationToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDifferencesError::InvalidContinuationToken,
+                    )
                 }
                 "InvalidMaxResultsException" => {
-                    return RusotoError::Service(GetDifferencesError::InvalidMaxResults(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDifferencesError::InvalidMaxResults,
+                    )
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(GetDifferencesError::InvalidPath(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDifferencesError::InvalidPath,
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(GetDifferencesError::InvalidRepositoryName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDifferencesError::InvalidRepositoryName,
+                    )
                 }
                 "PathDoesNotExistException" => {
-                    return RusotoError::Service(GetDifferencesError::PathDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDifferencesError::PathDoesNotExist,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(GetDifferencesError::RepositoryDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDifferencesError::RepositoryDoesNotExist,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(GetDifferencesError::RepositoryNameRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDifferencesError::RepositoryNameRequired,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7818,50 +9026,118 @@ impl GetFileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(GetFileError::CommitDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFileError::CommitDoesNotExist,
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(GetFileError::EncryptionIntegrityChecksFailed(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetFileError::EncryptionIntegrityChecksFailed,
+                    )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(GetFileError::EncryptionKeyAccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFileError::EncryptionKeyAccessDenied,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(GetFileError::EncryptionKeyDisabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFileError::EncryptionKeyDisabled,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(GetFileError::EncryptionKeyNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFileError::EncryptionKeyNotFound,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(GetFileError::EncryptionKeyUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFileError::EncryptionKeyUnavailable,
+                    )
                 }
                 "FileDoesNotExistException" => {
-                    return RusotoError::Service(GetFileError::FileDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFileError::FileDoesNotExist,
+                    )
                 }
                 "FileTooLargeException" => {
-                    return RusotoError::Service(GetFileError::FileTooLarge(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFileError::FileTooLarge,
+                    )
                 }
                 "InvalidCommitException" => {
-                    return RusotoError::Service(GetFileError::InvalidCommit(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFileError::InvalidCommit,
+                    )
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(GetFileError::InvalidPath(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFileError::InvalidPath,
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(GetFileError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFileError::InvalidRepositoryName,
+                    )
                 }
                 "PathRequiredException" => {
-                    return RusotoError::Service(GetFileError::PathRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFileError::PathRequired,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(GetFileError::RepositoryDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFileError::RepositoryDoesNotExist,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(GetFileError::RepositoryNameRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFileError::RepositoryNameRequired,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7926,47 +9202,110 @@ impl GetFolderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(GetFolderError::CommitDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFolderError::CommitDoesNotExist,
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(GetFolderError::EncryptionIntegrityChecksFailed(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetFolderError::EncryptionIntegrityChecksFailed,
+                    )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(GetFolderError::EncryptionKeyAccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFolderError::EncryptionKeyAccessDenied,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(GetFolderError::EncryptionKeyDisabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFolderError::EncryptionKeyDisabled,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(GetFolderError::EncryptionKeyNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFolderError::EncryptionKeyNotFound,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(GetFolderError::EncryptionKeyUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFolderError::EncryptionKeyUnavailable,
+                    )
                 }
                 "FolderDoesNotExistException" => {
-                    return RusotoError::Service(GetFolderError::FolderDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFolderError::FolderDoesNotExist,
+                    )
                 }
                 "InvalidCommitException" => {
-                    return RusotoError::Service(GetFolderError::InvalidCommit(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFolderError::InvalidCommit,
+                    )
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(GetFolderError::InvalidPath(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFolderError::InvalidPath,
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(GetFolderError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFolderError::InvalidRepositoryName,
+                    )
                 }
                 "PathRequiredException" => {
-                    return RusotoError::Service(GetFolderError::PathRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFolderError::PathRequired,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(GetFolderError::RepositoryDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFolderError::RepositoryDoesNotExist,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(GetFolderError::RepositoryNameRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFolderError::RepositoryNameRequired,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8030,65 +9369,110 @@ impl GetMergeCommitError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(GetMergeCommitError::CommitDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMergeCommitError::CommitDoesNotExist,
+                    )
                 }
                 "CommitRequiredException" => {
-                    return RusotoError::Service(GetMergeCommitError::CommitRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMergeCommitError::CommitRequired,
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        GetMergeCommitError::EncryptionIntegrityChecksFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMergeCommitError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(GetMergeCommitError::EncryptionKeyAccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMergeCommitError::EncryptionKeyAccessDenied,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(GetMergeCommitError::EncryptionKeyDisabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMergeCommitError::EncryptionKeyDisabled,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(GetMergeCommitError::EncryptionKeyNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMergeCommitError::EncryptionKeyNotFound,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(GetMergeCommitError::EncryptionKeyUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMergeCommitError::EncryptionKeyUnavailable,
+                    )
                 }
                 "InvalidCommitException" => {
-                    return RusotoError::Service(GetMergeCommitError::InvalidCommit(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMergeCommitError::InvalidCommit,
+                    )
                 }
                 "InvalidConflictDetailLevelException" => {
-                    return RusotoError::Service(GetMergeCommitError::InvalidConflictDetailLevel(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMergeCommitError::InvalidConflictDetailLevel,
+                    )
                 }
                 "InvalidConflictResolutionStrategyException" => {
-                    return RusotoError::Service(
-                        GetMergeCommitError::InvalidConflictResolutionStrategy(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMergeCommitError::InvalidConflictResolutionStrategy,
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(GetMergeCommitError::InvalidRepositoryName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMergeCommitError::InvalidRepositoryName,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(GetMergeCommitError::RepositoryDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMergeCommitError::RepositoryDoesNotExist,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(GetMergeCommitError::RepositoryNameRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMergeCommitError::RepositoryNameRequired,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8174,112 +9558,182 @@ impl GetMergeConflictsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(GetMergeConflictsError::CommitDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMergeConflictsError::CommitDoesNotExist,
+                    )
                 }
                 "CommitRequiredException" => {
-                    return RusotoError::Service(GetMergeConflictsError::CommitRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMergeConflictsError::CommitRequired,
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        GetMergeConflictsError::EncryptionIntegrityChecksFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMergeConflictsError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(GetMergeConflictsError::EncryptionKeyAccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMergeConflictsError::EncryptionKeyAccessDenied,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(GetMergeConflictsError::EncryptionKeyDisabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMergeConflictsError::EncryptionKeyDisabled,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(GetMergeConflictsError::EncryptionKeyNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMergeConflictsError::EncryptionKeyNotFound,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(GetMergeConflictsError::EncryptionKeyUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMergeConflictsError::EncryptionKeyUnavailable,
+                    )
                 }
                 "InvalidCommitException" => {
-                    return RusotoError::Service(GetMergeConflictsError::InvalidCommit(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMergeConflictsError::InvalidCommit,
+                    )
                 }
                 "InvalidConflictDetailLevelException" => {
-                    return RusotoError::Service(
-                        GetMergeConflictsError::InvalidConflictDetailLevel(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMergeConflictsError::InvalidConflictDetailLevel,
                     )
                 }
                 "InvalidConflictResolutionStrategyException" => {
-                    return RusotoError::Service(
-                        GetMergeConflictsError::InvalidConflictResolutionStrategy(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMergeConflictsError::InvalidConflictResolutionStrategy,
                     )
                 }
                 "InvalidContinuationTokenException" => {
-                    return RusotoError::Service(GetMergeConflictsError::InvalidContinuationToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMergeConflictsError::InvalidContinuationToken,
+                    )
                 }
                 "InvalidDestinationCommitSpecifierException" => {
-                    return RusotoError::Service(
-                        GetMergeConflictsError::InvalidDestinationCommitSpecifier(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMergeConflictsError::InvalidDestinationCommitSpecifier,
                     )
                 }
                 "InvalidMaxConflictFilesException" => {
-                    return RusotoError::Service(GetMergeConflictsError::InvalidMaxConflictFiles(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMergeConflictsError::InvalidMaxConflictFiles,
+                    )
                 }
                 "InvalidMergeOptionException" => {
-                    return RusotoError::Service(GetMergeConflictsError::InvalidMergeOption(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMergeConflictsError::InvalidMergeOption,
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(GetMergeConflictsError::InvalidRepositoryName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMergeConflictsError::InvalidRepositoryName,
+                    )
                 }
                 "InvalidSourceCommitSpecifierException" => {
-                    return RusotoError::Service(
-                        GetMergeConflictsError::InvalidSourceCommitSpecifier(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMergeConflictsError::InvalidSourceCommitSpecifier,
                     )
                 }
                 "MaximumFileContentToLoadExceededException" => {
-                    return RusotoError::Service(
-                        GetMergeConflictsError::MaximumFileContentToLoadExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMergeConflictsError::MaximumFileContentToLoadExceeded,
                     )
                 }
                 "MaximumItemsToCompareExceededException" => {
-                    return RusotoError::Service(
-                        GetMergeConflictsError::MaximumItemsToCompareExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMergeConflictsError::MaximumItemsToCompareExceeded,
                     )
                 }
                 "MergeOptionRequiredException" => {
-                    return RusotoError::Service(GetMergeConflictsError::MergeOptionRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMergeConflictsError::MergeOptionRequired,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(GetMergeConflictsError::RepositoryDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMergeConflictsError::RepositoryDoesNotExist,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(GetMergeConflictsError::RepositoryNameRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMergeConflictsError::RepositoryNameRequired,
+                    )
                 }
                 "TipsDivergenceExceededException" => {
-                    return RusotoError::Service(GetMergeConflictsError::TipsDivergenceExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMergeConflictsError::TipsDivergenceExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8370,80 +9824,134 @@ impl GetMergeOptionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(GetMergeOptionsError::CommitDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMergeOptionsError::CommitDoesNotExist,
+                    )
                 }
                 "CommitRequiredException" => {
-                    return RusotoError::Service(GetMergeOptionsError::CommitRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMergeOptionsError::CommitRequired,
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        GetMergeOptionsError::EncryptionIntegrityChecksFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMergeOptionsError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(GetMergeOptionsError::EncryptionKeyAccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMergeOptionsError::EncryptionKeyAccessDenied,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(GetMergeOptionsError::EncryptionKeyDisabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMergeOptionsError::EncryptionKeyDisabled,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(GetMergeOptionsError::EncryptionKeyNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMergeOptionsError::EncryptionKeyNotFound,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(GetMergeOptionsError::EncryptionKeyUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMergeOptionsError::EncryptionKeyUnavailable,
+                    )
                 }
                 "InvalidCommitException" => {
-                    return RusotoError::Service(GetMergeOptionsError::InvalidCommit(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMergeOptionsError::InvalidCommit,
+                    )
                 }
                 "InvalidConflictDetailLevelException" => {
-                    return RusotoError::Service(GetMergeOptionsError::InvalidConflictDetailLevel(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMergeOptionsError::InvalidConflictDetailLevel,
+                    )
                 }
                 "InvalidConflictResolutionStrategyException" => {
-                    return RusotoError::Service(
-                        GetMergeOptionsError::InvalidConflictResolutionStrategy(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMergeOptionsError::InvalidConflictResolutionStrategy,
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(GetMergeOptionsError::InvalidRepositoryName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMergeOptionsError::InvalidRepositoryName,
+                    )
                 }
                 "MaximumFileContentToLoadExceededException" => {
-                    return RusotoError::Service(
-                        GetMergeOptionsError::MaximumFileContentToLoadExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMergeOptionsError::MaximumFileContentToLoadExceeded,
                     )
                 }
                 "MaximumItemsToCompareExceededException" => {
-                    return RusotoError::Service(
-                        GetMergeOptionsError::MaximumItemsToCompareExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMergeOptionsError::MaximumItemsToCompareExceeded,
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(GetMergeOptionsError::RepositoryDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMergeOptionsError::RepositoryDoesNotExist,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(GetMergeOptionsError::RepositoryNameRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMergeOptionsError::RepositoryNameRequired,
+                    )
                 }
                 "TipsDivergenceExceededException" => {
-                    return RusotoError::Service(GetMergeOptionsError::TipsDivergenceExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMergeOptionsError::TipsDivergenceExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8508,44 +10016,70 @@ impl GetPullRequestError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        GetPullRequestError::EncryptionIntegrityChecksFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPullRequestError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(GetPullRequestError::EncryptionKeyAccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetPullRequestError::EncryptionKeyAccessDenied,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(GetPullRequestError::EncryptionKeyDisabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetPullRequestError::EncryptionKeyDisabled,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(GetPullRequestError::EncryptionKeyNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetPullRequestError::EncryptionKeyNotFound,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(GetPullRequestError::EncryptionKeyUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetPullRequestError::EncryptionKeyUnavailable,
+                    )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(GetPullRequestError::InvalidPullRequestId(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPullRequestError::InvalidPullRequestId,
+                    )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(GetPullRequestError::PullRequestDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetPullRequestError::PullRequestDoesNotExist,
+                    )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(GetPullRequestError::PullRequestIdRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetPullRequestError::PullRequestIdRequired,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8602,56 +10136,86 @@ impl GetPullRequestApprovalStatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        GetPullRequestApprovalStatesError::EncryptionIntegrityChecksFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPullRequestApprovalStatesError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
-                        GetPullRequestApprovalStatesError::EncryptionKeyAccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPullRequestApprovalStatesError::EncryptionKeyAccessDenied,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
-                        GetPullRequestApprovalStatesError::EncryptionKeyDisabled(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPullRequestApprovalStatesError::EncryptionKeyDisabled,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
-                        GetPullRequestApprovalStatesError::EncryptionKeyNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPullRequestApprovalStatesError::EncryptionKeyNotFound,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
-                        GetPullRequestApprovalStatesError::EncryptionKeyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPullRequestApprovalStatesError::EncryptionKeyUnavailable,
                     )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
-                        GetPullRequestApprovalStatesError::InvalidPullRequestId(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPullRequestApprovalStatesError::InvalidPullRequestId,
                     )
                 }
                 "InvalidRevisionIdException" => {
-                    return RusotoError::Service(
-                        GetPullRequestApprovalStatesError::InvalidRevisionId(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPullRequestApprovalStatesError::InvalidRevisionId,
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
-                        GetPullRequestApprovalStatesError::PullRequestDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPullRequestApprovalStatesError::PullRequestDoesNotExist,
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
-                        GetPullRequestApprovalStatesError::PullRequestIdRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPullRequestApprovalStatesError::PullRequestIdRequired,
                     )
                 }
                 "RevisionIdRequiredException" => {
-                    return RusotoError::Service(
-                        GetPullRequestApprovalStatesError::RevisionIdRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPullRequestApprovalStatesError::RevisionIdRequired,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8728,56 +10292,86 @@ impl GetPullRequestOverrideStateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        GetPullRequestOverrideStateError::EncryptionIntegrityChecksFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPullRequestOverrideStateError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
-                        GetPullRequestOverrideStateError::EncryptionKeyAccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPullRequestOverrideStateError::EncryptionKeyAccessDenied,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
-                        GetPullRequestOverrideStateError::EncryptionKeyDisabled(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPullRequestOverrideStateError::EncryptionKeyDisabled,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
-                        GetPullRequestOverrideStateError::EncryptionKeyNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPullRequestOverrideStateError::EncryptionKeyNotFound,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
-                        GetPullRequestOverrideStateError::EncryptionKeyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPullRequestOverrideStateError::EncryptionKeyUnavailable,
                     )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
-                        GetPullRequestOverrideStateError::InvalidPullRequestId(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPullRequestOverrideStateError::InvalidPullRequestId,
                     )
                 }
                 "InvalidRevisionIdException" => {
-                    return RusotoError::Service(
-                        GetPullRequestOverrideStateError::InvalidRevisionId(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPullRequestOverrideStateError::InvalidRevisionId,
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
-                        GetPullRequestOverrideStateError::PullRequestDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPullRequestOverrideStateError::PullRequestDoesNotExist,
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
-                        GetPullRequestOverrideStateError::PullRequestIdRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPullRequestOverrideStateError::PullRequestIdRequired,
                     )
                 }
                 "RevisionIdRequiredException" => {
-                    return RusotoError::Service(
-                        GetPullRequestOverrideStateError::RevisionIdRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPullRequestOverrideStateError::RevisionIdRequired,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8848,40 +10442,70 @@ impl GetRepositoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        GetRepositoryError::EncryptionIntegrityChecksFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRepositoryError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(GetRepositoryError::EncryptionKeyAccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRepositoryError::EncryptionKeyAccessDenied,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(GetRepositoryError::EncryptionKeyDisabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRepositoryError::EncryptionKeyDisabled,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(GetRepositoryError::EncryptionKeyNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRepositoryError::EncryptionKeyNotFound,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(GetRepositoryError::EncryptionKeyUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRepositoryError::EncryptionKeyUnavailable,
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(GetRepositoryError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRepositoryError::InvalidRepositoryName,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(GetRepositoryError::RepositoryDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRepositoryError::RepositoryDoesNotExist,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(GetRepositoryError::RepositoryNameRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRepositoryError::RepositoryNameRequired,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8932,46 +10556,70 @@ impl GetRepositoryTriggersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        GetRepositoryTriggersError::EncryptionIntegrityChecksFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRepositoryTriggersError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
-                        GetRepositoryTriggersError::EncryptionKeyAccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRepositoryTriggersError::EncryptionKeyAccessDenied,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(GetRepositoryTriggersError::EncryptionKeyDisabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRepositoryTriggersError::EncryptionKeyDisabled,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(GetRepositoryTriggersError::EncryptionKeyNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRepositoryTriggersError::EncryptionKeyNotFound,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
-                        GetRepositoryTriggersError::EncryptionKeyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRepositoryTriggersError::EncryptionKeyUnavailable,
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(GetRepositoryTriggersError::InvalidRepositoryName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRepositoryTriggersError::InvalidRepositoryName,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
-                        GetRepositoryTriggersError::RepositoryDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRepositoryTriggersError::RepositoryDoesNotExist,
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
-                        GetRepositoryTriggersError::RepositoryNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRepositoryTriggersError::RepositoryNameRequired,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9014,16 +10662,22 @@ impl ListApprovalRuleTemplatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidContinuationTokenException" => {
-                    return RusotoError::Service(
-                        ListApprovalRuleTemplatesError::InvalidContinuationToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListApprovalRuleTemplatesError::InvalidContinuationToken,
                     )
                 }
                 "InvalidMaxResultsException" => {
-                    return RusotoError::Service(ListApprovalRuleTemplatesError::InvalidMaxResults(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListApprovalRuleTemplatesError::InvalidMaxResults,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9073,17 +10727,17 @@ impl ListAssociatedApprovalRuleTemplatesForRepositoryError {
     ) -> RusotoError<ListAssociatedApprovalRuleTemplatesForRepositoryError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                                "EncryptionIntegrityChecksFailedException" => return RusotoError::Service(ListAssociatedApprovalRuleTemplatesForRepositoryError::EncryptionIntegrityChecksFailed(err.msg)),
-"EncryptionKeyAccessDeniedException" => return RusotoError::Service(ListAssociatedApprovalRuleTemplatesForRepositoryError::EncryptionKeyAccessDenied(err.msg)),
-"EncryptionKeyDisabledException" => return RusotoError::Service(ListAssociatedApprovalRuleTemplatesForRepositoryError::EncryptionKeyDisabled(err.msg)),
-"EncryptionKeyNotFoundException" => return RusotoError::Service(ListAssociatedApprovalRuleTemplatesForRepositoryError::EncryptionKeyNotFound(err.msg)),
-"EncryptionKeyUnavailableException" => return RusotoError::Service(ListAssociatedApprovalRuleTemplatesForRepositoryError::EncryptionKeyUnavailable(err.msg)),
-"InvalidContinuationTokenException" => return RusotoError::Service(ListAssociatedApprovalRuleTemplatesForRepositoryError::InvalidContinuationToken(err.msg)),
-"InvalidMaxResultsException" => return RusotoError::Service(ListAssociatedApprovalRuleTemplatesForRepositoryError::InvalidMaxResults(err.msg)),
-"InvalidRepositoryNameException" => return RusotoError::Service(ListAssociatedApprovalRuleTemplatesForRepositoryError::InvalidRepositoryName(err.msg)),
-"RepositoryDoesNotExistException" => return RusotoError::Service(ListAssociatedApprovalRuleTemplatesForRepositoryError::RepositoryDoesNotExist(err.msg)),
-"RepositoryNameRequiredException" => return RusotoError::Service(ListAssociatedApprovalRuleTemplatesForRepositoryError::RepositoryNameRequired(err.msg)),
-"ValidationException" => return RusotoError::Validation(err.msg),
+                                "EncryptionIntegrityChecksFailedException" => return RusotoError::service_error(&res, &err.typ, err.msg, ListAssociatedApprovalRuleTemplatesForRepositoryError::EncryptionIntegrityChecksFailed),
+"EncryptionKeyAccessDeniedException" => return RusotoError::service_error(&res, &err.typ, err.msg, ListAssociatedApprovalRuleTemplatesForRepositoryError::EncryptionKeyAccessDenied),
+"EncryptionKeyDisabledException" => return RusotoError::service_error(&res, &err.typ, err.msg, ListAssociatedApprovalRuleTemplatesForRepositoryError::EncryptionKeyDisabled),
+"EncryptionKeyNotFoundException" => return RusotoError::service_error(&res, &err.typ, err.msg, ListAssociatedApprovalRuleTemplatesForRepositoryError::EncryptionKeyNotFound),
+"EncryptionKeyUnavailableException" => return RusotoError::service_error(&res, &err.typ, err.msg, ListAssociatedApprovalRuleTemplatesForRepositoryError::EncryptionKeyUnavailable),
+"InvalidContinuationTokenException" => return RusotoError::service_error(&res, &err.typ, err.msg, ListAssociatedApprovalRuleTemplatesForRepositoryError::InvalidContinuationToken),
+"InvalidMaxResultsException" => return RusotoError::service_error(&res, &err.typ, err.msg, ListAssociatedApprovalRuleTemplatesForRepositoryError::InvalidMaxResults),
+"InvalidRepositoryNameException" => return RusotoError::service_error(&res, &err.typ, err.msg, ListAssociatedApprovalRuleTemplatesForRepositoryError::InvalidRepositoryName),
+"RepositoryDoesNotExistException" => return RusotoError::service_error(&res, &err.typ, err.msg, ListAssociatedApprovalRuleTemplatesForRepositoryError::RepositoryDoesNotExist),
+"RepositoryNameRequiredException" => return RusotoError::service_error(&res, &err.typ, err.msg, ListAssociatedApprovalRuleTemplatesForRepositoryError::RepositoryNameRequired),
+"ValidationException" => return RusotoError::validation_error(&res, err.msg),
 _ => {}
                             }
         }
@@ -9136,41 +10790,78 @@ impl ListBranchesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        ListBranchesError::EncryptionIntegrityChecksFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBranchesError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(ListBranchesError::EncryptionKeyAccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListBranchesError::EncryptionKeyAccessDenied,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(ListBranchesError::EncryptionKeyDisabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBranchesError::EncryptionKeyDisabled,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(ListBranchesError::EncryptionKeyNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBranchesError::EncryptionKeyNotFound,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(ListBranchesError::EncryptionKeyUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListBranchesError::EncryptionKeyUnavailable,
+                    )
                 }
                 "InvalidContinuationTokenException" => {
-                    return RusotoError::Service(ListBranchesError::InvalidContinuationToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListBranchesError::InvalidContinuationToken,
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(ListBranchesError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBranchesError::InvalidRepositoryName,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(ListBranchesError::RepositoryDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBranchesError::RepositoryDoesNotExist,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(ListBranchesError::RepositoryNameRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBranchesError::RepositoryNameRequired,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9230,65 +10921,110 @@ impl ListPullRequestsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorDoesNotExistException" => {
-                    return RusotoError::Service(ListPullRequestsError::AuthorDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPullRequestsError::AuthorDoesNotExist,
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        ListPullRequestsError::EncryptionIntegrityChecksFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPullRequestsError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(ListPullRequestsError::EncryptionKeyAccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPullRequestsError::EncryptionKeyAccessDenied,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(ListPullRequestsError::EncryptionKeyDisabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPullRequestsError::EncryptionKeyDisabled,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(ListPullRequestsError::EncryptionKeyNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPullRequestsError::EncryptionKeyNotFound,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(ListPullRequestsError::EncryptionKeyUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPullRequestsError::EncryptionKeyUnavailable,
+                    )
                 }
                 "InvalidAuthorArnException" => {
-                    return RusotoError::Service(ListPullRequestsError::InvalidAuthorArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPullRequestsError::InvalidAuthorArn,
+                    )
                 }
                 "InvalidContinuationTokenException" => {
-                    return RusotoError::Service(ListPullRequestsError::InvalidContinuationToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPullRequestsError::InvalidContinuationToken,
+                    )
                 }
                 "InvalidMaxResultsException" => {
-                    return RusotoError::Service(ListPullRequestsError::InvalidMaxResults(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPullRequestsError::InvalidMaxResults,
+                    )
                 }
                 "InvalidPullRequestStatusException" => {
-                    return RusotoError::Service(ListPullRequestsError::InvalidPullRequestStatus(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPullRequestsError::InvalidPullRequestStatus,
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(ListPullRequestsError::InvalidRepositoryName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPullRequestsError::InvalidRepositoryName,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(ListPullRequestsError::RepositoryDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPullRequestsError::RepositoryDoesNotExist,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(ListPullRequestsError::RepositoryNameRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPullRequestsError::RepositoryNameRequired,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9334,17 +11070,30 @@ impl ListRepositoriesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidContinuationTokenException" => {
-                    return RusotoError::Service(ListRepositoriesError::InvalidContinuationToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListRepositoriesError::InvalidContinuationToken,
+                    )
                 }
                 "InvalidOrderException" => {
-                    return RusotoError::Service(ListRepositoriesError::InvalidOrder(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRepositoriesError::InvalidOrder,
+                    )
                 }
                 "InvalidSortByException" => {
-                    return RusotoError::Service(ListRepositoriesError::InvalidSortBy(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRepositoriesError::InvalidSortBy,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9393,67 +11142,79 @@ impl ListRepositoriesForApprovalRuleTemplateError {
     ) -> RusotoError<ListRepositoriesForApprovalRuleTemplateError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ApprovalRuleTemplateDoesNotExistException" => return RusotoError::Service(
-                    ListRepositoriesForApprovalRuleTemplateError::ApprovalRuleTemplateDoesNotExist(
-                        err.msg,
-                    ),
+                "ApprovalRuleTemplateDoesNotExistException" => return RusotoError::service_error(
+                    &res,
+                    &err.typ,
+                    err.msg,
+                    ListRepositoriesForApprovalRuleTemplateError::ApprovalRuleTemplateDoesNotExist,
                 ),
-                "ApprovalRuleTemplateNameRequiredException" => return RusotoError::Service(
-                    ListRepositoriesForApprovalRuleTemplateError::ApprovalRuleTemplateNameRequired(
-                        err.msg,
-                    ),
+                "ApprovalRuleTemplateNameRequiredException" => return RusotoError::service_error(
+                    &res,
+                    &err.typ,
+                    err.msg,
+                    ListRepositoriesForApprovalRuleTemplateError::ApprovalRuleTemplateNameRequired,
                 ),
-                "EncryptionIntegrityChecksFailedException" => return RusotoError::Service(
-                    ListRepositoriesForApprovalRuleTemplateError::EncryptionIntegrityChecksFailed(
-                        err.msg,
-                    ),
+                "EncryptionIntegrityChecksFailedException" => return RusotoError::service_error(
+                    &res,
+                    &err.typ,
+                    err.msg,
+                    ListRepositoriesForApprovalRuleTemplateError::EncryptionIntegrityChecksFailed,
                 ),
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
-                        ListRepositoriesForApprovalRuleTemplateError::EncryptionKeyAccessDenied(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRepositoriesForApprovalRuleTemplateError::EncryptionKeyAccessDenied,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
-                        ListRepositoriesForApprovalRuleTemplateError::EncryptionKeyDisabled(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRepositoriesForApprovalRuleTemplateError::EncryptionKeyDisabled,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
-                        ListRepositoriesForApprovalRuleTemplateError::EncryptionKeyNotFound(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRepositoriesForApprovalRuleTemplateError::EncryptionKeyNotFound,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
-                        ListRepositoriesForApprovalRuleTemplateError::EncryptionKeyUnavailable(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRepositoriesForApprovalRuleTemplateError::EncryptionKeyUnavailable,
                     )
                 }
-                "InvalidApprovalRuleTemplateNameException" => return RusotoError::Service(
-                    ListRepositoriesForApprovalRuleTemplateError::InvalidApprovalRuleTemplateName(
-                        err.msg,
-                    ),
+                "InvalidApprovalRuleTemplateNameException" => return RusotoError::service_error(
+                    &res,
+                    &err.typ,
+                    err.msg,
+                    ListRepositoriesForApprovalRuleTemplateError::InvalidApprovalRuleTemplateName,
                 ),
                 "InvalidContinuationTokenException" => {
-                    return RusotoError::Service(
-                        ListRepositoriesForApprovalRuleTemplateError::InvalidContinuationToken(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRepositoriesForApprovalRuleTemplateError::InvalidContinuationToken,
                     )
                 }
                 "InvalidMaxResultsException" => {
-                    return RusotoError::Service(
-                        ListRepositoriesForApprovalRuleTemplateError::InvalidMaxResults(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRepositoriesForApprovalRuleTemplateError::InvalidMaxResults,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9516,26 +11277,38 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRepositoryName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::InvalidRepositoryName,
+                    )
                 }
                 "InvalidResourceArnException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidResourceArn(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::InvalidResourceArn,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(ListTagsForResourceError::RepositoryDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::RepositoryDoesNotExist,
+                    )
                 }
                 "ResourceArnRequiredException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceArnRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceArnRequired,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9604,101 +11377,158 @@ impl MergeBranchesByFastForwardError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BranchDoesNotExistException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByFastForwardError::BranchDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByFastForwardError::BranchDoesNotExist,
                     )
                 }
                 "BranchNameIsTagNameException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByFastForwardError::BranchNameIsTagName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByFastForwardError::BranchNameIsTagName,
                     )
                 }
                 "BranchNameRequiredException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByFastForwardError::BranchNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByFastForwardError::BranchNameRequired,
                     )
                 }
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByFastForwardError::CommitDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByFastForwardError::CommitDoesNotExist,
                     )
                 }
                 "CommitRequiredException" => {
-                    return RusotoError::Service(MergeBranchesByFastForwardError::CommitRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergeBranchesByFastForwardError::CommitRequired,
+                    )
                 }
                 "ConcurrentReferenceUpdateException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByFastForwardError::ConcurrentReferenceUpdate(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByFastForwardError::ConcurrentReferenceUpdate,
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByFastForwardError::EncryptionIntegrityChecksFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByFastForwardError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByFastForwardError::EncryptionKeyAccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByFastForwardError::EncryptionKeyAccessDenied,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByFastForwardError::EncryptionKeyDisabled(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByFastForwardError::EncryptionKeyDisabled,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByFastForwardError::EncryptionKeyNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByFastForwardError::EncryptionKeyNotFound,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByFastForwardError::EncryptionKeyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByFastForwardError::EncryptionKeyUnavailable,
                     )
                 }
                 "InvalidBranchNameException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByFastForwardError::InvalidBranchName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByFastForwardError::InvalidBranchName,
                     )
                 }
                 "InvalidCommitException" => {
-                    return RusotoError::Service(MergeBranchesByFastForwardError::InvalidCommit(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergeBranchesByFastForwardError::InvalidCommit,
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByFastForwardError::InvalidRepositoryName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByFastForwardError::InvalidRepositoryName,
                     )
                 }
                 "InvalidTargetBranchException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByFastForwardError::InvalidTargetBranch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByFastForwardError::InvalidTargetBranch,
                     )
                 }
                 "ManualMergeRequiredException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByFastForwardError::ManualMergeRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByFastForwardError::ManualMergeRequired,
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByFastForwardError::RepositoryDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByFastForwardError::RepositoryDoesNotExist,
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByFastForwardError::RepositoryNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByFastForwardError::RepositoryNameRequired,
                     )
                 }
                 "TipsDivergenceExceededException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByFastForwardError::TipsDivergenceExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByFastForwardError::TipsDivergenceExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9852,195 +11682,318 @@ impl MergeBranchesBySquashError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BranchDoesNotExistException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::BranchDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergeBranchesBySquashError::BranchDoesNotExist,
+                    )
                 }
                 "BranchNameIsTagNameException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::BranchNameIsTagName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergeBranchesBySquashError::BranchNameIsTagName,
+                    )
                 }
                 "BranchNameRequiredException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::BranchNameRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergeBranchesBySquashError::BranchNameRequired,
+                    )
                 }
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::CommitDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergeBranchesBySquashError::CommitDoesNotExist,
+                    )
                 }
                 "CommitMessageLengthExceededException" => {
-                    return RusotoError::Service(
-                        MergeBranchesBySquashError::CommitMessageLengthExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesBySquashError::CommitMessageLengthExceeded,
                     )
                 }
                 "CommitRequiredException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::CommitRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergeBranchesBySquashError::CommitRequired,
+                    )
                 }
                 "ConcurrentReferenceUpdateException" => {
-                    return RusotoError::Service(
-                        MergeBranchesBySquashError::ConcurrentReferenceUpdate(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesBySquashError::ConcurrentReferenceUpdate,
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        MergeBranchesBySquashError::EncryptionIntegrityChecksFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesBySquashError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
-                        MergeBranchesBySquashError::EncryptionKeyAccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesBySquashError::EncryptionKeyAccessDenied,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::EncryptionKeyDisabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergeBranchesBySquashError::EncryptionKeyDisabled,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::EncryptionKeyNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergeBranchesBySquashError::EncryptionKeyNotFound,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
-                        MergeBranchesBySquashError::EncryptionKeyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesBySquashError::EncryptionKeyUnavailable,
                     )
                 }
                 "FileContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
-                        MergeBranchesBySquashError::FileContentSizeLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesBySquashError::FileContentSizeLimitExceeded,
                     )
                 }
                 "FileModeRequiredException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::FileModeRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergeBranchesBySquashError::FileModeRequired,
+                    )
                 }
                 "FolderContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
-                        MergeBranchesBySquashError::FolderContentSizeLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesBySquashError::FolderContentSizeLimitExceeded,
                     )
                 }
                 "InvalidBranchNameException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::InvalidBranchName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergeBranchesBySquashError::InvalidBranchName,
+                    )
                 }
                 "InvalidCommitException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::InvalidCommit(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesBySquashError::InvalidCommit,
+                    )
                 }
                 "InvalidConflictDetailLevelException" => {
-                    return RusotoError::Service(
-                        MergeBranchesBySquashError::InvalidConflictDetailLevel(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesBySquashError::InvalidConflictDetailLevel,
                     )
                 }
                 "InvalidConflictResolutionException" => {
-                    return RusotoError::Service(
-                        MergeBranchesBySquashError::InvalidConflictResolution(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesBySquashError::InvalidConflictResolution,
                     )
                 }
                 "InvalidConflictResolutionStrategyException" => {
-                    return RusotoError::Service(
-                        MergeBranchesBySquashError::InvalidConflictResolutionStrategy(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesBySquashError::InvalidConflictResolutionStrategy,
                     )
                 }
                 "InvalidEmailException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::InvalidEmail(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesBySquashError::InvalidEmail,
+                    )
                 }
                 "InvalidFileModeException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::InvalidFileMode(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergeBranchesBySquashError::InvalidFileMode,
+                    )
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::InvalidPath(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesBySquashError::InvalidPath,
+                    )
                 }
                 "InvalidReplacementContentException" => {
-                    return RusotoError::Service(
-                        MergeBranchesBySquashError::InvalidReplacementContent(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesBySquashError::InvalidReplacementContent,
                     )
                 }
                 "InvalidReplacementTypeException" => {
-                    return RusotoError::Service(
-                        MergeBranchesBySquashError::InvalidReplacementType(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesBySquashError::InvalidReplacementType,
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::InvalidRepositoryName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergeBranchesBySquashError::InvalidRepositoryName,
+                    )
                 }
                 "InvalidTargetBranchException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::InvalidTargetBranch(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergeBranchesBySquashError::InvalidTargetBranch,
+                    )
                 }
                 "ManualMergeRequiredException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::ManualMergeRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergeBranchesBySquashError::ManualMergeRequired,
+                    )
                 }
                 "MaximumConflictResolutionEntriesExceededException" => {
-                    return RusotoError::Service(
-                        MergeBranchesBySquashError::MaximumConflictResolutionEntriesExceeded(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesBySquashError::MaximumConflictResolutionEntriesExceeded,
                     )
                 }
                 "MaximumFileContentToLoadExceededException" => {
-                    return RusotoError::Service(
-                        MergeBranchesBySquashError::MaximumFileContentToLoadExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesBySquashError::MaximumFileContentToLoadExceeded,
                     )
                 }
                 "MaximumItemsToCompareExceededException" => {
-                    return RusotoError::Service(
-                        MergeBranchesBySquashError::MaximumItemsToCompareExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesBySquashError::MaximumItemsToCompareExceeded,
                     )
                 }
                 "MultipleConflictResolutionEntriesException" => {
-                    return RusotoError::Service(
-                        MergeBranchesBySquashError::MultipleConflictResolutionEntries(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesBySquashError::MultipleConflictResolutionEntries,
                     )
                 }
                 "NameLengthExceededException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::NameLengthExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergeBranchesBySquashError::NameLengthExceeded,
+                    )
                 }
                 "PathRequiredException" => {
-                    return RusotoError::Service(MergeBranchesBySquashError::PathRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesBySquashError::PathRequired,
+                    )
                 }
                 "ReplacementContentRequiredException" => {
-                    return RusotoError::Service(
-                        MergeBranchesBySquashError::ReplacementContentRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesBySquashError::ReplacementContentRequired,
                     )
                 }
                 "ReplacementTypeRequiredException" => {
-                    return RusotoError::Service(
-                        MergeBranchesBySquashError::ReplacementTypeRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesBySquashError::ReplacementTypeRequired,
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
-                        MergeBranchesBySquashError::RepositoryDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesBySquashError::RepositoryDoesNotExist,
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
-                        MergeBranchesBySquashError::RepositoryNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesBySquashError::RepositoryNameRequired,
                     )
                 }
                 "TipsDivergenceExceededException" => {
-                    return RusotoError::Service(
-                        MergeBranchesBySquashError::TipsDivergenceExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesBySquashError::TipsDivergenceExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10216,201 +12169,318 @@ impl MergeBranchesByThreeWayError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BranchDoesNotExistException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::BranchDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergeBranchesByThreeWayError::BranchDoesNotExist,
+                    )
                 }
                 "BranchNameIsTagNameException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::BranchNameIsTagName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergeBranchesByThreeWayError::BranchNameIsTagName,
+                    )
                 }
                 "BranchNameRequiredException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::BranchNameRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergeBranchesByThreeWayError::BranchNameRequired,
+                    )
                 }
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::CommitDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergeBranchesByThreeWayError::CommitDoesNotExist,
+                    )
                 }
                 "CommitMessageLengthExceededException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByThreeWayError::CommitMessageLengthExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByThreeWayError::CommitMessageLengthExceeded,
                     )
                 }
                 "CommitRequiredException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::CommitRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergeBranchesByThreeWayError::CommitRequired,
+                    )
                 }
                 "ConcurrentReferenceUpdateException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByThreeWayError::ConcurrentReferenceUpdate(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByThreeWayError::ConcurrentReferenceUpdate,
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByThreeWayError::EncryptionIntegrityChecksFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByThreeWayError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByThreeWayError::EncryptionKeyAccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByThreeWayError::EncryptionKeyAccessDenied,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByThreeWayError::EncryptionKeyDisabled(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByThreeWayError::EncryptionKeyDisabled,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByThreeWayError::EncryptionKeyNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByThreeWayError::EncryptionKeyNotFound,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByThreeWayError::EncryptionKeyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByThreeWayError::EncryptionKeyUnavailable,
                     )
                 }
                 "FileContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByThreeWayError::FileContentSizeLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByThreeWayError::FileContentSizeLimitExceeded,
                     )
                 }
                 "FileModeRequiredException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::FileModeRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergeBranchesByThreeWayError::FileModeRequired,
+                    )
                 }
                 "FolderContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByThreeWayError::FolderContentSizeLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByThreeWayError::FolderContentSizeLimitExceeded,
                     )
                 }
                 "InvalidBranchNameException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::InvalidBranchName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergeBranchesByThreeWayError::InvalidBranchName,
+                    )
                 }
                 "InvalidCommitException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::InvalidCommit(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergeBranchesByThreeWayError::InvalidCommit,
+                    )
                 }
                 "InvalidConflictDetailLevelException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByThreeWayError::InvalidConflictDetailLevel(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByThreeWayError::InvalidConflictDetailLevel,
                     )
                 }
                 "InvalidConflictResolutionException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByThreeWayError::InvalidConflictResolution(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByThreeWayError::InvalidConflictResolution,
                     )
                 }
                 "InvalidConflictResolutionStrategyException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByThreeWayError::InvalidConflictResolutionStrategy(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByThreeWayError::InvalidConflictResolutionStrategy,
                     )
                 }
                 "InvalidEmailException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::InvalidEmail(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergeBranchesByThreeWayError::InvalidEmail,
+                    )
                 }
                 "InvalidFileModeException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::InvalidFileMode(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergeBranchesByThreeWayError::InvalidFileMode,
+                    )
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::InvalidPath(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByThreeWayError::InvalidPath,
+                    )
                 }
                 "InvalidReplacementContentException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByThreeWayError::InvalidReplacementContent(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByThreeWayError::InvalidReplacementContent,
                     )
                 }
                 "InvalidReplacementTypeException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByThreeWayError::InvalidReplacementType(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByThreeWayError::InvalidReplacementType,
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByThreeWayError::InvalidRepositoryName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByThreeWayError::InvalidRepositoryName,
                     )
                 }
                 "InvalidTargetBranchException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::InvalidTargetBranch(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergeBranchesByThreeWayError::InvalidTargetBranch,
+                    )
                 }
                 "ManualMergeRequiredException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::ManualMergeRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergeBranchesByThreeWayError::ManualMergeRequired,
+                    )
                 }
                 "MaximumConflictResolutionEntriesExceededException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByThreeWayError::MaximumConflictResolutionEntriesExceeded(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByThreeWayError::MaximumConflictResolutionEntriesExceeded,
                     )
                 }
                 "MaximumFileContentToLoadExceededException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByThreeWayError::MaximumFileContentToLoadExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByThreeWayError::MaximumFileContentToLoadExceeded,
                     )
                 }
                 "MaximumItemsToCompareExceededException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByThreeWayError::MaximumItemsToCompareExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByThreeWayError::MaximumItemsToCompareExceeded,
                     )
                 }
                 "MultipleConflictResolutionEntriesException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByThreeWayError::MultipleConflictResolutionEntries(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByThreeWayError::MultipleConflictResolutionEntries,
                     )
                 }
                 "NameLengthExceededException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::NameLengthExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergeBranchesByThreeWayError::NameLengthExceeded,
+                    )
                 }
                 "PathRequiredException" => {
-                    return RusotoError::Service(MergeBranchesByThreeWayError::PathRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergeBranchesByThreeWayError::PathRequired,
+                    )
                 }
                 "ReplacementContentRequiredException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByThreeWayError::ReplacementContentRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByThreeWayError::ReplacementContentRequired,
                     )
                 }
                 "ReplacementTypeRequiredException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByThreeWayError::ReplacementTypeRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByThreeWayError::ReplacementTypeRequired,
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByThreeWayError::RepositoryDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByThreeWayError::RepositoryDoesNotExist,
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByThreeWayError::RepositoryNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByThreeWayError::RepositoryNameRequired,
                     )
                 }
                 "TipsDivergenceExceededException" => {
-                    return RusotoError::Service(
-                        MergeBranchesByThreeWayError::TipsDivergenceExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeBranchesByThreeWayError::TipsDivergenceExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10562,109 +12632,158 @@ impl MergePullRequestByFastForwardError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentReferenceUpdateException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByFastForwardError::ConcurrentReferenceUpdate(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByFastForwardError::ConcurrentReferenceUpdate,
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByFastForwardError::EncryptionIntegrityChecksFailed(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByFastForwardError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByFastForwardError::EncryptionKeyAccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByFastForwardError::EncryptionKeyAccessDenied,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByFastForwardError::EncryptionKeyDisabled(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByFastForwardError::EncryptionKeyDisabled,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByFastForwardError::EncryptionKeyNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByFastForwardError::EncryptionKeyNotFound,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByFastForwardError::EncryptionKeyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByFastForwardError::EncryptionKeyUnavailable,
                     )
                 }
                 "InvalidCommitIdException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByFastForwardError::InvalidCommitId(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByFastForwardError::InvalidCommitId,
                     )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByFastForwardError::InvalidPullRequestId(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByFastForwardError::InvalidPullRequestId,
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByFastForwardError::InvalidRepositoryName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByFastForwardError::InvalidRepositoryName,
                     )
                 }
                 "ManualMergeRequiredException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByFastForwardError::ManualMergeRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByFastForwardError::ManualMergeRequired,
                     )
                 }
                 "PullRequestAlreadyClosedException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByFastForwardError::PullRequestAlreadyClosed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByFastForwardError::PullRequestAlreadyClosed,
                     )
                 }
                 "PullRequestApprovalRulesNotSatisfiedException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByFastForwardError::PullRequestApprovalRulesNotSatisfied(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByFastForwardError::PullRequestApprovalRulesNotSatisfied,
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByFastForwardError::PullRequestDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByFastForwardError::PullRequestDoesNotExist,
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByFastForwardError::PullRequestIdRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByFastForwardError::PullRequestIdRequired,
                     )
                 }
                 "ReferenceDoesNotExistException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByFastForwardError::ReferenceDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByFastForwardError::ReferenceDoesNotExist,
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByFastForwardError::RepositoryDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByFastForwardError::RepositoryDoesNotExist,
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByFastForwardError::RepositoryNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByFastForwardError::RepositoryNameRequired,
                     )
                 }
                 "RepositoryNotAssociatedWithPullRequestException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByFastForwardError::RepositoryNotAssociatedWithPullRequest(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByFastForwardError::RepositoryNotAssociatedWithPullRequest,
                     )
                 }
                 "TipOfSourceReferenceIsDifferentException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByFastForwardError::TipOfSourceReferenceIsDifferent(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByFastForwardError::TipOfSourceReferenceIsDifferent,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10822,202 +12941,310 @@ impl MergePullRequestBySquashError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitMessageLengthExceededException" => {
-                    return RusotoError::Service(
-                        MergePullRequestBySquashError::CommitMessageLengthExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestBySquashError::CommitMessageLengthExceeded,
                     )
                 }
                 "ConcurrentReferenceUpdateException" => {
-                    return RusotoError::Service(
-                        MergePullRequestBySquashError::ConcurrentReferenceUpdate(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestBySquashError::ConcurrentReferenceUpdate,
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        MergePullRequestBySquashError::EncryptionIntegrityChecksFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestBySquashError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
-                        MergePullRequestBySquashError::EncryptionKeyAccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestBySquashError::EncryptionKeyAccessDenied,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
-                        MergePullRequestBySquashError::EncryptionKeyDisabled(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestBySquashError::EncryptionKeyDisabled,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
-                        MergePullRequestBySquashError::EncryptionKeyNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestBySquashError::EncryptionKeyNotFound,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
-                        MergePullRequestBySquashError::EncryptionKeyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestBySquashError::EncryptionKeyUnavailable,
                     )
                 }
                 "FileContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
-                        MergePullRequestBySquashError::FileContentSizeLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestBySquashError::FileContentSizeLimitExceeded,
                     )
                 }
                 "FolderContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
-                        MergePullRequestBySquashError::FolderContentSizeLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestBySquashError::FolderContentSizeLimitExceeded,
                     )
                 }
                 "InvalidCommitIdException" => {
-                    return RusotoError::Service(MergePullRequestBySquashError::InvalidCommitId(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergePullRequestBySquashError::InvalidCommitId,
+                    )
                 }
                 "InvalidConflictDetailLevelException" => {
-                    return RusotoError::Service(
-                        MergePullRequestBySquashError::InvalidConflictDetailLevel(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestBySquashError::InvalidConflictDetailLevel,
                     )
                 }
                 "InvalidConflictResolutionException" => {
-                    return RusotoError::Service(
-                        MergePullRequestBySquashError::InvalidConflictResolution(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestBySquashError::InvalidConflictResolution,
                     )
                 }
                 "InvalidConflictResolutionStrategyException" => {
-                    return RusotoError::Service(
-                        MergePullRequestBySquashError::InvalidConflictResolutionStrategy(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestBySquashError::InvalidConflictResolutionStrategy,
                     )
                 }
                 "InvalidEmailException" => {
-                    return RusotoError::Service(MergePullRequestBySquashError::InvalidEmail(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergePullRequestBySquashError::InvalidEmail,
+                    )
                 }
                 "InvalidFileModeException" => {
-                    return RusotoError::Service(MergePullRequestBySquashError::InvalidFileMode(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergePullRequestBySquashError::InvalidFileMode,
+                    )
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(MergePullRequestBySquashError::InvalidPath(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergePullRequestBySquashError::InvalidPath,
+                    )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
-                        MergePullRequestBySquashError::InvalidPullRequestId(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestBySquashError::InvalidPullRequestId,
                     )
                 }
                 "InvalidReplacementContentException" => {
-                    return RusotoError::Service(
-                        MergePullRequestBySquashError::InvalidReplacementContent(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestBySquashError::InvalidReplacementContent,
                     )
                 }
                 "InvalidReplacementTypeException" => {
-                    return RusotoError::Service(
-                        MergePullRequestBySquashError::InvalidReplacementType(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestBySquashError::InvalidReplacementType,
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
-                        MergePullRequestBySquashError::InvalidRepositoryName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestBySquashError::InvalidRepositoryName,
                     )
                 }
                 "ManualMergeRequiredException" => {
-                    return RusotoError::Service(
-                        MergePullRequestBySquashError::ManualMergeRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestBySquashError::ManualMergeRequired,
                     )
                 }
                 "MaximumConflictResolutionEntriesExceededException" => {
-                    return RusotoError::Service(
-                        MergePullRequestBySquashError::MaximumConflictResolutionEntriesExceeded(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestBySquashError::MaximumConflictResolutionEntriesExceeded,
                     )
                 }
                 "MaximumFileContentToLoadExceededException" => {
-                    return RusotoError::Service(
-                        MergePullRequestBySquashError::MaximumFileContentToLoadExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestBySquashError::MaximumFileContentToLoadExceeded,
                     )
                 }
                 "MaximumItemsToCompareExceededException" => {
-                    return RusotoError::Service(
-                        MergePullRequestBySquashError::MaximumItemsToCompareExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestBySquashError::MaximumItemsToCompareExceeded,
                     )
                 }
                 "MultipleConflictResolutionEntriesException" => {
-                    return RusotoError::Service(
-                        MergePullRequestBySquashError::MultipleConflictResolutionEntries(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestBySquashError::MultipleConflictResolutionEntries,
                     )
                 }
                 "NameLengthExceededException" => {
-                    return RusotoError::Service(MergePullRequestBySquashError::NameLengthExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergePullRequestBySquashError::NameLengthExceeded,
+                    )
                 }
                 "PathRequiredException" => {
-                    return RusotoError::Service(MergePullRequestBySquashError::PathRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergePullRequestBySquashError::PathRequired,
+                    )
                 }
                 "PullRequestAlreadyClosedException" => {
-                    return RusotoError::Service(
-                        MergePullRequestBySquashError::PullRequestAlreadyClosed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestBySquashError::PullRequestAlreadyClosed,
                     )
                 }
                 "PullRequestApprovalRulesNotSatisfiedException" => {
-                    return RusotoError::Service(
-                        MergePullRequestBySquashError::PullRequestApprovalRulesNotSatisfied(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestBySquashError::PullRequestApprovalRulesNotSatisfied,
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
-                        MergePullRequestBySquashError::PullRequestDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestBySquashError::PullRequestDoesNotExist,
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
-                        MergePullRequestBySquashError::PullRequestIdRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestBySquashError::PullRequestIdRequired,
                     )
                 }
                 "ReplacementContentRequiredException" => {
-                    return RusotoError::Service(
-                        MergePullRequestBySquashError::ReplacementContentRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestBySquashError::ReplacementContentRequired,
                     )
                 }
                 "ReplacementTypeRequiredException" => {
-                    return RusotoError::Service(
-                        MergePullRequestBySquashError::ReplacementTypeRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestBySquashError::ReplacementTypeRequired,
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
-                        MergePullRequestBySquashError::RepositoryDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestBySquashError::RepositoryDoesNotExist,
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
-                        MergePullRequestBySquashError::RepositoryNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestBySquashError::RepositoryNameRequired,
                     )
                 }
                 "RepositoryNotAssociatedWithPullRequestException" => {
-                    return RusotoError::Service(
-                        MergePullRequestBySquashError::RepositoryNotAssociatedWithPullRequest(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestBySquashError::RepositoryNotAssociatedWithPullRequest,
                     )
                 }
                 "TipOfSourceReferenceIsDifferentException" => {
-                    return RusotoError::Service(
-                        MergePullRequestBySquashError::TipOfSourceReferenceIsDifferent(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestBySquashError::TipOfSourceReferenceIsDifferent,
                     )
                 }
                 "TipsDivergenceExceededException" => {
-                    return RusotoError::Service(
-                        MergePullRequestBySquashError::TipsDivergenceExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestBySquashError::TipsDivergenceExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11220,202 +13447,310 @@ impl MergePullRequestByThreeWayError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommitMessageLengthExceededException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByThreeWayError::CommitMessageLengthExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByThreeWayError::CommitMessageLengthExceeded,
                     )
                 }
                 "ConcurrentReferenceUpdateException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByThreeWayError::ConcurrentReferenceUpdate(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByThreeWayError::ConcurrentReferenceUpdate,
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByThreeWayError::EncryptionIntegrityChecksFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByThreeWayError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByThreeWayError::EncryptionKeyAccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByThreeWayError::EncryptionKeyAccessDenied,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByThreeWayError::EncryptionKeyDisabled(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByThreeWayError::EncryptionKeyDisabled,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByThreeWayError::EncryptionKeyNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByThreeWayError::EncryptionKeyNotFound,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByThreeWayError::EncryptionKeyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByThreeWayError::EncryptionKeyUnavailable,
                     )
                 }
                 "FileContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByThreeWayError::FileContentSizeLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByThreeWayError::FileContentSizeLimitExceeded,
                     )
                 }
                 "FolderContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByThreeWayError::FolderContentSizeLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByThreeWayError::FolderContentSizeLimitExceeded,
                     )
                 }
                 "InvalidCommitIdException" => {
-                    return RusotoError::Service(MergePullRequestByThreeWayError::InvalidCommitId(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergePullRequestByThreeWayError::InvalidCommitId,
+                    )
                 }
                 "InvalidConflictDetailLevelException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByThreeWayError::InvalidConflictDetailLevel(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByThreeWayError::InvalidConflictDetailLevel,
                     )
                 }
                 "InvalidConflictResolutionException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByThreeWayError::InvalidConflictResolution(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByThreeWayError::InvalidConflictResolution,
                     )
                 }
                 "InvalidConflictResolutionStrategyException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByThreeWayError::InvalidConflictResolutionStrategy(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByThreeWayError::InvalidConflictResolutionStrategy,
                     )
                 }
                 "InvalidEmailException" => {
-                    return RusotoError::Service(MergePullRequestByThreeWayError::InvalidEmail(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergePullRequestByThreeWayError::InvalidEmail,
+                    )
                 }
                 "InvalidFileModeException" => {
-                    return RusotoError::Service(MergePullRequestByThreeWayError::InvalidFileMode(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergePullRequestByThreeWayError::InvalidFileMode,
+                    )
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(MergePullRequestByThreeWayError::InvalidPath(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergePullRequestByThreeWayError::InvalidPath,
+                    )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByThreeWayError::InvalidPullRequestId(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByThreeWayError::InvalidPullRequestId,
                     )
                 }
                 "InvalidReplacementContentException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByThreeWayError::InvalidReplacementContent(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByThreeWayError::InvalidReplacementContent,
                     )
                 }
                 "InvalidReplacementTypeException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByThreeWayError::InvalidReplacementType(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByThreeWayError::InvalidReplacementType,
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByThreeWayError::InvalidRepositoryName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByThreeWayError::InvalidRepositoryName,
                     )
                 }
                 "ManualMergeRequiredException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByThreeWayError::ManualMergeRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByThreeWayError::ManualMergeRequired,
                     )
                 }
                 "MaximumConflictResolutionEntriesExceededException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByThreeWayError::MaximumConflictResolutionEntriesExceeded(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByThreeWayError::MaximumConflictResolutionEntriesExceeded,
                     )
                 }
                 "MaximumFileContentToLoadExceededException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByThreeWayError::MaximumFileContentToLoadExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByThreeWayError::MaximumFileContentToLoadExceeded,
                     )
                 }
                 "MaximumItemsToCompareExceededException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByThreeWayError::MaximumItemsToCompareExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByThreeWayError::MaximumItemsToCompareExceeded,
                     )
                 }
                 "MultipleConflictResolutionEntriesException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByThreeWayError::MultipleConflictResolutionEntries(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByThreeWayError::MultipleConflictResolutionEntries,
                     )
                 }
                 "NameLengthExceededException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByThreeWayError::NameLengthExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByThreeWayError::NameLengthExceeded,
                     )
                 }
                 "PathRequiredException" => {
-                    return RusotoError::Service(MergePullRequestByThreeWayError::PathRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergePullRequestByThreeWayError::PathRequired,
+                    )
                 }
                 "PullRequestAlreadyClosedException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByThreeWayError::PullRequestAlreadyClosed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByThreeWayError::PullRequestAlreadyClosed,
                     )
                 }
                 "PullRequestApprovalRulesNotSatisfiedException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByThreeWayError::PullRequestApprovalRulesNotSatisfied(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByThreeWayError::PullRequestApprovalRulesNotSatisfied,
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByThreeWayError::PullRequestDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByThreeWayError::PullRequestDoesNotExist,
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByThreeWayError::PullRequestIdRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByThreeWayError::PullRequestIdRequired,
                     )
                 }
                 "ReplacementContentRequiredException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByThreeWayError::ReplacementContentRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByThreeWayError::ReplacementContentRequired,
                     )
                 }
                 "ReplacementTypeRequiredException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByThreeWayError::ReplacementTypeRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByThreeWayError::ReplacementTypeRequired,
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByThreeWayError::RepositoryDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByThreeWayError::RepositoryDoesNotExist,
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByThreeWayError::RepositoryNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByThreeWayError::RepositoryNameRequired,
                     )
                 }
                 "RepositoryNotAssociatedWithPullRequestException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByThreeWayError::RepositoryNotAssociatedWithPullRequest(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByThreeWayError::RepositoryNotAssociatedWithPullRequest,
                     )
                 }
                 "TipOfSourceReferenceIsDifferentException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByThreeWayError::TipOfSourceReferenceIsDifferent(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByThreeWayError::TipOfSourceReferenceIsDifferent,
                     )
                 }
                 "TipsDivergenceExceededException" => {
-                    return RusotoError::Service(
-                        MergePullRequestByThreeWayError::TipsDivergenceExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergePullRequestByThreeWayError::TipsDivergenceExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11576,83 +13911,126 @@ impl OverridePullRequestApprovalRulesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        OverridePullRequestApprovalRulesError::EncryptionIntegrityChecksFailed(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        OverridePullRequestApprovalRulesError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
-                        OverridePullRequestApprovalRulesError::EncryptionKeyAccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        OverridePullRequestApprovalRulesError::EncryptionKeyAccessDenied,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
-                        OverridePullRequestApprovalRulesError::EncryptionKeyDisabled(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        OverridePullRequestApprovalRulesError::EncryptionKeyDisabled,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
-                        OverridePullRequestApprovalRulesError::EncryptionKeyNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        OverridePullRequestApprovalRulesError::EncryptionKeyNotFound,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
-                        OverridePullRequestApprovalRulesError::EncryptionKeyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        OverridePullRequestApprovalRulesError::EncryptionKeyUnavailable,
                     )
                 }
                 "InvalidOverrideStatusException" => {
-                    return RusotoError::Service(
-                        OverridePullRequestApprovalRulesError::InvalidOverrideStatus(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        OverridePullRequestApprovalRulesError::InvalidOverrideStatus,
                     )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
-                        OverridePullRequestApprovalRulesError::InvalidPullRequestId(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        OverridePullRequestApprovalRulesError::InvalidPullRequestId,
                     )
                 }
                 "InvalidRevisionIdException" => {
-                    return RusotoError::Service(
-                        OverridePullRequestApprovalRulesError::InvalidRevisionId(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        OverridePullRequestApprovalRulesError::InvalidRevisionId,
                     )
                 }
                 "OverrideAlreadySetException" => {
-                    return RusotoError::Service(
-                        OverridePullRequestApprovalRulesError::OverrideAlreadySet(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        OverridePullRequestApprovalRulesError::OverrideAlreadySet,
                     )
                 }
                 "OverrideStatusRequiredException" => {
-                    return RusotoError::Service(
-                        OverridePullRequestApprovalRulesError::OverrideStatusRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        OverridePullRequestApprovalRulesError::OverrideStatusRequired,
                     )
                 }
                 "PullRequestAlreadyClosedException" => {
-                    return RusotoError::Service(
-                        OverridePullRequestApprovalRulesError::PullRequestAlreadyClosed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        OverridePullRequestApprovalRulesError::PullRequestAlreadyClosed,
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
-                        OverridePullRequestApprovalRulesError::PullRequestDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        OverridePullRequestApprovalRulesError::PullRequestDoesNotExist,
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
-                        OverridePullRequestApprovalRulesError::PullRequestIdRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        OverridePullRequestApprovalRulesError::PullRequestIdRequired,
                     )
                 }
                 "RevisionIdRequiredException" => {
-                    return RusotoError::Service(
-                        OverridePullRequestApprovalRulesError::RevisionIdRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        OverridePullRequestApprovalRulesError::RevisionIdRequired,
                     )
                 }
                 "RevisionNotCurrentException" => {
-                    return RusotoError::Service(
-                        OverridePullRequestApprovalRulesError::RevisionNotCurrent(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        OverridePullRequestApprovalRulesError::RevisionNotCurrent,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11770,123 +14148,190 @@ impl PostCommentForComparedCommitError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BeforeCommitIdAndAfterCommitIdAreSameException" => {
-                    return RusotoError::Service(
-                        PostCommentForComparedCommitError::BeforeCommitIdAndAfterCommitIdAreSame(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForComparedCommitError::BeforeCommitIdAndAfterCommitIdAreSame,
                     )
                 }
                 "ClientRequestTokenRequiredException" => {
-                    return RusotoError::Service(
-                        PostCommentForComparedCommitError::ClientRequestTokenRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForComparedCommitError::ClientRequestTokenRequired,
                     )
                 }
                 "CommentContentRequiredException" => {
-                    return RusotoError::Service(
-                        PostCommentForComparedCommitError::CommentContentRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForComparedCommitError::CommentContentRequired,
                     )
                 }
                 "CommentContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
-                        PostCommentForComparedCommitError::CommentContentSizeLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForComparedCommitError::CommentContentSizeLimitExceeded,
                     )
                 }
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(
-                        PostCommentForComparedCommitError::CommitDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForComparedCommitError::CommitDoesNotExist,
                     )
                 }
                 "CommitIdRequiredException" => {
-                    return RusotoError::Service(
-                        PostCommentForComparedCommitError::CommitIdRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForComparedCommitError::CommitIdRequired,
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        PostCommentForComparedCommitError::EncryptionIntegrityChecksFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForComparedCommitError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
-                        PostCommentForComparedCommitError::EncryptionKeyAccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForComparedCommitError::EncryptionKeyAccessDenied,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
-                        PostCommentForComparedCommitError::EncryptionKeyDisabled(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForComparedCommitError::EncryptionKeyDisabled,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
-                        PostCommentForComparedCommitError::EncryptionKeyNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForComparedCommitError::EncryptionKeyNotFound,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
-                        PostCommentForComparedCommitError::EncryptionKeyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForComparedCommitError::EncryptionKeyUnavailable,
                     )
                 }
                 "IdempotencyParameterMismatchException" => {
-                    return RusotoError::Service(
-                        PostCommentForComparedCommitError::IdempotencyParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForComparedCommitError::IdempotencyParameterMismatch,
                     )
                 }
                 "InvalidClientRequestTokenException" => {
-                    return RusotoError::Service(
-                        PostCommentForComparedCommitError::InvalidClientRequestToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForComparedCommitError::InvalidClientRequestToken,
                     )
                 }
                 "InvalidCommitIdException" => {
-                    return RusotoError::Service(
-                        PostCommentForComparedCommitError::InvalidCommitId(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForComparedCommitError::InvalidCommitId,
                     )
                 }
                 "InvalidFileLocationException" => {
-                    return RusotoError::Service(
-                        PostCommentForComparedCommitError::InvalidFileLocation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForComparedCommitError::InvalidFileLocation,
                     )
                 }
                 "InvalidFilePositionException" => {
-                    return RusotoError::Service(
-                        PostCommentForComparedCommitError::InvalidFilePosition(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForComparedCommitError::InvalidFilePosition,
                     )
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(PostCommentForComparedCommitError::InvalidPath(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PostCommentForComparedCommitError::InvalidPath,
+                    )
                 }
                 "InvalidRelativeFileVersionEnumException" => {
-                    return RusotoError::Service(
-                        PostCommentForComparedCommitError::InvalidRelativeFileVersionEnum(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForComparedCommitError::InvalidRelativeFileVersionEnum,
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
-                        PostCommentForComparedCommitError::InvalidRepositoryName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForComparedCommitError::InvalidRepositoryName,
                     )
                 }
                 "PathDoesNotExistException" => {
-                    return RusotoError::Service(
-                        PostCommentForComparedCommitError::PathDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForComparedCommitError::PathDoesNotExist,
                     )
                 }
                 "PathRequiredException" => {
-                    return RusotoError::Service(PostCommentForComparedCommitError::PathRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PostCommentForComparedCommitError::PathRequired,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
-                        PostCommentForComparedCommitError::RepositoryDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForComparedCommitError::RepositoryDoesNotExist,
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
-                        PostCommentForComparedCommitError::RepositoryNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForComparedCommitError::RepositoryNameRequired,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12028,145 +14473,222 @@ impl PostCommentForPullRequestError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BeforeCommitIdAndAfterCommitIdAreSameException" => {
-                    return RusotoError::Service(
-                        PostCommentForPullRequestError::BeforeCommitIdAndAfterCommitIdAreSame(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForPullRequestError::BeforeCommitIdAndAfterCommitIdAreSame,
                     )
                 }
                 "ClientRequestTokenRequiredException" => {
-                    return RusotoError::Service(
-                        PostCommentForPullRequestError::ClientRequestTokenRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForPullRequestError::ClientRequestTokenRequired,
                     )
                 }
                 "CommentContentRequiredException" => {
-                    return RusotoError::Service(
-                        PostCommentForPullRequestError::CommentContentRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForPullRequestError::CommentContentRequired,
                     )
                 }
                 "CommentContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
-                        PostCommentForPullRequestError::CommentContentSizeLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForPullRequestError::CommentContentSizeLimitExceeded,
                     )
                 }
                 "CommitDoesNotExistException" => {
-                    return RusotoError::Service(
-                        PostCommentForPullRequestError::CommitDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForPullRequestError::CommitDoesNotExist,
                     )
                 }
                 "CommitIdRequiredException" => {
-                    return RusotoError::Service(PostCommentForPullRequestError::CommitIdRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PostCommentForPullRequestError::CommitIdRequired,
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        PostCommentForPullRequestError::EncryptionIntegrityChecksFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForPullRequestError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
-                        PostCommentForPullRequestError::EncryptionKeyAccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForPullRequestError::EncryptionKeyAccessDenied,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
-                        PostCommentForPullRequestError::EncryptionKeyDisabled(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForPullRequestError::EncryptionKeyDisabled,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
-                        PostCommentForPullRequestError::EncryptionKeyNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForPullRequestError::EncryptionKeyNotFound,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
-                        PostCommentForPullRequestError::EncryptionKeyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForPullRequestError::EncryptionKeyUnavailable,
                     )
                 }
                 "IdempotencyParameterMismatchException" => {
-                    return RusotoError::Service(
-                        PostCommentForPullRequestError::IdempotencyParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForPullRequestError::IdempotencyParameterMismatch,
                     )
                 }
                 "InvalidClientRequestTokenException" => {
-                    return RusotoError::Service(
-                        PostCommentForPullRequestError::InvalidClientRequestToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForPullRequestError::InvalidClientRequestToken,
                     )
                 }
                 "InvalidCommitIdException" => {
-                    return RusotoError::Service(PostCommentForPullRequestError::InvalidCommitId(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PostCommentForPullRequestError::InvalidCommitId,
+                    )
                 }
                 "InvalidFileLocationException" => {
-                    return RusotoError::Service(
-                        PostCommentForPullRequestError::InvalidFileLocation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForPullRequestError::InvalidFileLocation,
                     )
                 }
                 "InvalidFilePositionException" => {
-                    return RusotoError::Service(
-                        PostCommentForPullRequestError::InvalidFilePosition(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForPullRequestError::InvalidFilePosition,
                     )
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(PostCommentForPullRequestError::InvalidPath(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PostCommentForPullRequestError::InvalidPath,
+                    )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
-                        PostCommentForPullRequestError::InvalidPullRequestId(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForPullRequestError::InvalidPullRequestId,
                     )
                 }
                 "InvalidRelativeFileVersionEnumException" => {
-                    return RusotoError::Service(
-                        PostCommentForPullRequestError::InvalidRelativeFileVersionEnum(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForPullRequestError::InvalidRelativeFileVersionEnum,
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
-                        PostCommentForPullRequestError::InvalidRepositoryName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForPullRequestError::InvalidRepositoryName,
                     )
                 }
                 "PathDoesNotExistException" => {
-                    return RusotoError::Service(PostCommentForPullRequestError::PathDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PostCommentForPullRequestError::PathDoesNotExist,
+                    )
                 }
                 "PathRequiredException" => {
-                    return RusotoError::Service(PostCommentForPullRequestError::PathRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PostCommentForPullRequestError::PathRequired,
+                    )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
-                        PostCommentForPullRequestError::PullRequestDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForPullRequestError::PullRequestDoesNotExist,
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
-                        PostCommentForPullRequestError::PullRequestIdRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForPullRequestError::PullRequestIdRequired,
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
-                        PostCommentForPullRequestError::RepositoryDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForPullRequestError::RepositoryDoesNotExist,
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
-                        PostCommentForPullRequestError::RepositoryNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForPullRequestError::RepositoryNameRequired,
                     )
                 }
                 "RepositoryNotAssociatedWithPullRequestException" => {
-                    return RusotoError::Service(
-                        PostCommentForPullRequestError::RepositoryNotAssociatedWithPullRequest(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentForPullRequestError::RepositoryNotAssociatedWithPullRequest,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12276,42 +14798,70 @@ impl PostCommentReplyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientRequestTokenRequiredException" => {
-                    return RusotoError::Service(PostCommentReplyError::ClientRequestTokenRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PostCommentReplyError::ClientRequestTokenRequired,
+                    )
                 }
                 "CommentContentRequiredException" => {
-                    return RusotoError::Service(PostCommentReplyError::CommentContentRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PostCommentReplyError::CommentContentRequired,
+                    )
                 }
                 "CommentContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
-                        PostCommentReplyError::CommentContentSizeLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentReplyError::CommentContentSizeLimitExceeded,
                     )
                 }
                 "CommentDoesNotExistException" => {
-                    return RusotoError::Service(PostCommentReplyError::CommentDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PostCommentReplyError::CommentDoesNotExist,
+                    )
                 }
                 "CommentIdRequiredException" => {
-                    return RusotoError::Service(PostCommentReplyError::CommentIdRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentReplyError::CommentIdRequired,
+                    )
                 }
                 "IdempotencyParameterMismatchException" => {
-                    return RusotoError::Service(
-                        PostCommentReplyError::IdempotencyParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentReplyError::IdempotencyParameterMismatch,
                     )
                 }
                 "InvalidClientRequestTokenException" => {
-                    return RusotoError::Service(PostCommentReplyError::InvalidClientRequestToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PostCommentReplyError::InvalidClientRequestToken,
+                    )
                 }
                 "InvalidCommentIdException" => {
-                    return RusotoError::Service(PostCommentReplyError::InvalidCommentId(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostCommentReplyError::InvalidCommentId,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12362,37 +14912,62 @@ impl PutCommentReactionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommentDeletedException" => {
-                    return RusotoError::Service(PutCommentReactionError::CommentDeleted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutCommentReactionError::CommentDeleted,
+                    )
                 }
                 "CommentDoesNotExistException" => {
-                    return RusotoError::Service(PutCommentReactionError::CommentDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutCommentReactionError::CommentDoesNotExist,
+                    )
                 }
                 "CommentIdRequiredException" => {
-                    return RusotoError::Service(PutCommentReactionError::CommentIdRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutCommentReactionError::CommentIdRequired,
+                    )
                 }
                 "InvalidCommentIdException" => {
-                    return RusotoError::Service(PutCommentReactionError::InvalidCommentId(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutCommentReactionError::InvalidCommentId,
+                    )
                 }
                 "InvalidReactionValueException" => {
-                    return RusotoError::Service(PutCommentReactionError::InvalidReactionValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutCommentReactionError::InvalidReactionValue,
+                    )
                 }
                 "ReactionLimitExceededException" => {
-                    return RusotoError::Service(PutCommentReactionError::ReactionLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutCommentReactionError::ReactionLimitExceeded,
+                    )
                 }
                 "ReactionValueRequiredException" => {
-                    return RusotoError::Service(PutCommentReactionError::ReactionValueRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutCommentReactionError::ReactionValueRequired,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12484,108 +15059,246 @@ impl PutFileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BranchDoesNotExistException" => {
-                    return RusotoError::Service(PutFileError::BranchDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutFileError::BranchDoesNotExist,
+                    )
                 }
                 "BranchNameIsTagNameException" => {
-                    return RusotoError::Service(PutFileError::BranchNameIsTagName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutFileError::BranchNameIsTagName,
+                    )
                 }
                 "BranchNameRequiredException" => {
-                    return RusotoError::Service(PutFileError::BranchNameRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutFileError::BranchNameRequired,
+                    )
                 }
                 "CommitMessageLengthExceededException" => {
-                    return RusotoError::Service(PutFileError::CommitMessageLengthExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutFileError::CommitMessageLengthExceeded,
+                    )
                 }
                 "DirectoryNameConflictsWithFileNameException" => {
-                    return RusotoError::Service(PutFileError::DirectoryNameConflictsWithFileName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutFileError::DirectoryNameConflictsWithFileName,
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(PutFileError::EncryptionIntegrityChecksFailed(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutFileError::EncryptionIntegrityChecksFailed,
+                    )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(PutFileError::EncryptionKeyAccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutFileError::EncryptionKeyAccessDenied,
+                    )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(PutFileError::EncryptionKeyDisabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutFileError::EncryptionKeyDisabled,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(PutFileError::EncryptionKeyNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutFileError::EncryptionKeyNotFound,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(PutFileError::EncryptionKeyUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutFileError::EncryptionKeyUnavailable,
+                    )
                 }
                 "FileContentRequiredException" => {
-                    return RusotoError::Service(PutFileError::FileContentRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutFileError::FileContentRequired,
+                    )
                 }
                 "FileContentSizeLimitExceededException" => {
-                    return RusotoError::Service(PutFileError::FileContentSizeLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutFileError::FileContentSizeLimitExceeded,
+                    )
                 }
                 "FileNameConflictsWithDirectoryNameException" => {
-                    return RusotoError::Service(PutFileError::FileNameConflictsWithDirectoryName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutFileError::FileNameConflictsWithDirectoryName,
+                    )
                 }
                 "FilePathConflictsWithSubmodulePathException" => {
-                    return RusotoError::Service(PutFileError::FilePathConflictsWithSubmodulePath(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutFileError::FilePathConflictsWithSubmodulePath,
+                    )
                 }
                 "FolderContentSizeLimitExceededException" => {
-                    return RusotoError::Service(PutFileError::FolderContentSizeLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutFileError::FolderContentSizeLimitExceeded,
+                    )
                 }
                 "InvalidBranchNameException" => {
-                    return RusotoError::Service(PutFileError::InvalidBranchName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutFileError::InvalidBranchName,
+                    )
                 }
                 "InvalidDeletionParameterException" => {
-                    return RusotoError::Service(PutFileError::InvalidDeletionParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutFileError::InvalidDeletionParameter,
+                    )
                 }
                 "InvalidEmailException" => {
-                    return RusotoError::Service(PutFileError::InvalidEmail(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutFileError::InvalidEmail,
+                    )
                 }
                 "InvalidFileModeException" => {
-                    return RusotoError::Service(PutFileError::InvalidFileMode(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutFileError::InvalidFileMode,
+                    )
                 }
                 "InvalidParentCommitIdException" => {
-                    return RusotoError::Service(PutFileError::InvalidParentCommitId(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutFileError::InvalidParentCommitId,
+                    )
                 }
                 "InvalidPathException" => {
-                    return RusotoError::Service(PutFileError::InvalidPath(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutFileError::InvalidPath,
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(PutFileError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutFileError::InvalidRepositoryName,
+                    )
                 }
                 "NameLengthExceededException" => {
-                    return RusotoError::Service(PutFileError::NameLengthExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutFileError::NameLengthExceeded,
+                    )
                 }
                 "ParentCommitDoesNotExistException" => {
-                    return RusotoError::Service(PutFileError::ParentCommitDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutFileError::ParentCommitDoesNotExist,
+                    )
                 }
                 "ParentCommitIdOutdatedException" => {
-                    return RusotoError::Service(PutFileError::ParentCommitIdOutdated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutFileError::ParentCommitIdOutdated,
+                    )
                 }
                 "ParentCommitIdRequiredException" => {
-                    return RusotoError::Service(PutFileError::ParentCommitIdRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutFileError::ParentCommitIdRequired,
+                    )
                 }
                 "PathRequiredException" => {
-                    return RusotoError::Service(PutFileError::PathRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutFileError::PathRequired,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(PutFileError::RepositoryDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutFileError::RepositoryDoesNotExist,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(PutFileError::RepositoryNameRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutFileError::RepositoryNameRequired,
+                    )
                 }
                 "SameFileContentException" => {
-                    return RusotoError::Service(PutFileError::SameFileContent(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutFileError::SameFileContent,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12682,115 +15395,174 @@ impl PutRepositoryTriggersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        PutRepositoryTriggersError::EncryptionIntegrityChecksFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRepositoryTriggersError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
-                        PutRepositoryTriggersError::EncryptionKeyAccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRepositoryTriggersError::EncryptionKeyAccessDenied,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(PutRepositoryTriggersError::EncryptionKeyDisabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutRepositoryTriggersError::EncryptionKeyDisabled,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(PutRepositoryTriggersError::EncryptionKeyNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutRepositoryTriggersError::EncryptionKeyNotFound,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
-                        PutRepositoryTriggersError::EncryptionKeyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRepositoryTriggersError::EncryptionKeyUnavailable,
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(PutRepositoryTriggersError::InvalidRepositoryName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutRepositoryTriggersError::InvalidRepositoryName,
+                    )
                 }
                 "InvalidRepositoryTriggerBranchNameException" => {
-                    return RusotoError::Service(
-                        PutRepositoryTriggersError::InvalidRepositoryTriggerBranchName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRepositoryTriggersError::InvalidRepositoryTriggerBranchName,
                     )
                 }
                 "InvalidRepositoryTriggerCustomDataException" => {
-                    return RusotoError::Service(
-                        PutRepositoryTriggersError::InvalidRepositoryTriggerCustomData(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRepositoryTriggersError::InvalidRepositoryTriggerCustomData,
                     )
                 }
                 "InvalidRepositoryTriggerDestinationArnException" => {
-                    return RusotoError::Service(
-                        PutRepositoryTriggersError::InvalidRepositoryTriggerDestinationArn(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRepositoryTriggersError::InvalidRepositoryTriggerDestinationArn,
                     )
                 }
                 "InvalidRepositoryTriggerEventsException" => {
-                    return RusotoError::Service(
-                        PutRepositoryTriggersError::InvalidRepositoryTriggerEvents(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRepositoryTriggersError::InvalidRepositoryTriggerEvents,
                     )
                 }
                 "InvalidRepositoryTriggerNameException" => {
-                    return RusotoError::Service(
-                        PutRepositoryTriggersError::InvalidRepositoryTriggerName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRepositoryTriggersError::InvalidRepositoryTriggerName,
                     )
                 }
                 "InvalidRepositoryTriggerRegionException" => {
-                    return RusotoError::Service(
-                        PutRepositoryTriggersError::InvalidRepositoryTriggerRegion(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRepositoryTriggersError::InvalidRepositoryTriggerRegion,
                     )
                 }
                 "MaximumBranchesExceededException" => {
-                    return RusotoError::Service(
-                        PutRepositoryTriggersError::MaximumBranchesExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRepositoryTriggersError::MaximumBranchesExceeded,
                     )
                 }
                 "MaximumRepositoryTriggersExceededException" => {
-                    return RusotoError::Service(
-                        PutRepositoryTriggersError::MaximumRepositoryTriggersExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRepositoryTriggersError::MaximumRepositoryTriggersExceeded,
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
-                        PutRepositoryTriggersError::RepositoryDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRepositoryTriggersError::RepositoryDoesNotExist,
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
-                        PutRepositoryTriggersError::RepositoryNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRepositoryTriggersError::RepositoryNameRequired,
                     )
                 }
                 "RepositoryTriggerBranchNameListRequiredException" => {
-                    return RusotoError::Service(
-                        PutRepositoryTriggersError::RepositoryTriggerBranchNameListRequired(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRepositoryTriggersError::RepositoryTriggerBranchNameListRequired,
                     )
                 }
                 "RepositoryTriggerDestinationArnRequiredException" => {
-                    return RusotoError::Service(
-                        PutRepositoryTriggersError::RepositoryTriggerDestinationArnRequired(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRepositoryTriggersError::RepositoryTriggerDestinationArnRequired,
                     )
                 }
                 "RepositoryTriggerEventsListRequiredException" => {
-                    return RusotoError::Service(
-                        PutRepositoryTriggersError::RepositoryTriggerEventsListRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRepositoryTriggersError::RepositoryTriggerEventsListRequired,
                     )
                 }
                 "RepositoryTriggerNameRequiredException" => {
-                    return RusotoError::Service(
-                        PutRepositoryTriggersError::RepositoryTriggerNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRepositoryTriggersError::RepositoryTriggerNameRequired,
                     )
                 }
                 "RepositoryTriggersListRequiredException" => {
-                    return RusotoError::Service(
-                        PutRepositoryTriggersError::RepositoryTriggersListRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRepositoryTriggersError::RepositoryTriggersListRequired,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12886,33 +15658,78 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidRepositoryName,
+                    )
                 }
                 "InvalidResourceArnException" => {
-                    return RusotoError::Service(TagResourceError::InvalidResourceArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidResourceArn,
+                    )
                 }
                 "InvalidSystemTagUsageException" => {
-                    return RusotoError::Service(TagResourceError::InvalidSystemTagUsage(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidSystemTagUsage,
+                    )
                 }
                 "InvalidTagsMapException" => {
-                    return RusotoError::Service(TagResourceError::InvalidTagsMap(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidTagsMap,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(TagResourceError::RepositoryDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::RepositoryDoesNotExist,
+                    )
                 }
                 "ResourceArnRequiredException" => {
-                    return RusotoError::Service(TagResourceError::ResourceArnRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceArnRequired,
+                    )
                 }
                 "TagPolicyException" => {
-                    return RusotoError::Service(TagResourceError::TagPolicy(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::TagPolicy,
+                    )
                 }
                 "TagsMapRequiredException" => {
-                    return RusotoError::Service(TagResourceError::TagsMapRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::TagsMapRequired,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12988,117 +15805,174 @@ impl TestRepositoryTriggersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        TestRepositoryTriggersError::EncryptionIntegrityChecksFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestRepositoryTriggersError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
-                        TestRepositoryTriggersError::EncryptionKeyAccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestRepositoryTriggersError::EncryptionKeyAccessDenied,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
-                        TestRepositoryTriggersError::EncryptionKeyDisabled(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestRepositoryTriggersError::EncryptionKeyDisabled,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
-                        TestRepositoryTriggersError::EncryptionKeyNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestRepositoryTriggersError::EncryptionKeyNotFound,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
-                        TestRepositoryTriggersError::EncryptionKeyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestRepositoryTriggersError::EncryptionKeyUnavailable,
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
-                        TestRepositoryTriggersError::InvalidRepositoryName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestRepositoryTriggersError::InvalidRepositoryName,
                     )
                 }
                 "InvalidRepositoryTriggerBranchNameException" => {
-                    return RusotoError::Service(
-                        TestRepositoryTriggersError::InvalidRepositoryTriggerBranchName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestRepositoryTriggersError::InvalidRepositoryTriggerBranchName,
                     )
                 }
                 "InvalidRepositoryTriggerCustomDataException" => {
-                    return RusotoError::Service(
-                        TestRepositoryTriggersError::InvalidRepositoryTriggerCustomData(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestRepositoryTriggersError::InvalidRepositoryTriggerCustomData,
                     )
                 }
                 "InvalidRepositoryTriggerDestinationArnException" => {
-                    return RusotoError::Service(
-                        TestRepositoryTriggersError::InvalidRepositoryTriggerDestinationArn(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestRepositoryTriggersError::InvalidRepositoryTriggerDestinationArn,
                     )
                 }
                 "InvalidRepositoryTriggerEventsException" => {
-                    return RusotoError::Service(
-                        TestRepositoryTriggersError::InvalidRepositoryTriggerEvents(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestRepositoryTriggersError::InvalidRepositoryTriggerEvents,
                     )
                 }
                 "InvalidRepositoryTriggerNameException" => {
-                    return RusotoError::Service(
-                        TestRepositoryTriggersError::InvalidRepositoryTriggerName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestRepositoryTriggersError::InvalidRepositoryTriggerName,
                     )
                 }
                 "InvalidRepositoryTriggerRegionException" => {
-                    return RusotoError::Service(
-                        TestRepositoryTriggersError::InvalidRepositoryTriggerRegion(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestRepositoryTriggersError::InvalidRepositoryTriggerRegion,
                     )
                 }
                 "MaximumBranchesExceededException" => {
-                    return RusotoError::Service(
-                        TestRepositoryTriggersError::MaximumBranchesExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestRepositoryTriggersError::MaximumBranchesExceeded,
                     )
                 }
                 "MaximumRepositoryTriggersExceededException" => {
-                    return RusotoError::Service(
-                        TestRepositoryTriggersError::MaximumRepositoryTriggersExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestRepositoryTriggersError::MaximumRepositoryTriggersExceeded,
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
-                        TestRepositoryTriggersError::RepositoryDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestRepositoryTriggersError::RepositoryDoesNotExist,
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
-                        TestRepositoryTriggersError::RepositoryNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestRepositoryTriggersError::RepositoryNameRequired,
                     )
                 }
                 "RepositoryTriggerBranchNameListRequiredException" => {
-                    return RusotoError::Service(
-                        TestRepositoryTriggersError::RepositoryTriggerBranchNameListRequired(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestRepositoryTriggersError::RepositoryTriggerBranchNameListRequired,
                     )
                 }
                 "RepositoryTriggerDestinationArnRequiredException" => {
-                    return RusotoError::Service(
-                        TestRepositoryTriggersError::RepositoryTriggerDestinationArnRequired(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestRepositoryTriggersError::RepositoryTriggerDestinationArnRequired,
                     )
                 }
                 "RepositoryTriggerEventsListRequiredException" => {
-                    return RusotoError::Service(
-                        TestRepositoryTriggersError::RepositoryTriggerEventsListRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestRepositoryTriggersError::RepositoryTriggerEventsListRequired,
                     )
                 }
                 "RepositoryTriggerNameRequiredException" => {
-                    return RusotoError::Service(
-                        TestRepositoryTriggersError::RepositoryTriggerNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestRepositoryTriggersError::RepositoryTriggerNameRequired,
                     )
                 }
                 "RepositoryTriggersListRequiredException" => {
-                    return RusotoError::Service(
-                        TestRepositoryTriggersError::RepositoryTriggersListRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestRepositoryTriggersError::RepositoryTriggersListRequired,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13198,35 +16072,78 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRepositoryName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidRepositoryName,
+                    )
                 }
                 "InvalidResourceArnException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidResourceArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidResourceArn,
+                    )
                 }
                 "InvalidSystemTagUsageException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidSystemTagUsage(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidSystemTagUsage,
+                    )
                 }
                 "InvalidTagKeysListException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidTagKeysList(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidTagKeysList,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(UntagResourceError::RepositoryDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UntagResourceError::RepositoryDoesNotExist,
+                    )
                 }
                 "ResourceArnRequiredException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceArnRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceArnRequired,
+                    )
                 }
                 "TagKeysListRequiredException" => {
-                    return RusotoError::Service(UntagResourceError::TagKeysListRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::TagKeysListRequired,
+                    )
                 }
                 "TagPolicyException" => {
-                    return RusotoError::Service(UntagResourceError::TagPolicy(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::TagPolicy,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13274,46 +16191,54 @@ impl UpdateApprovalRuleTemplateContentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApprovalRuleTemplateContentRequiredException" => {
-                    return RusotoError::Service(
-                        UpdateApprovalRuleTemplateContentError::ApprovalRuleTemplateContentRequired(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApprovalRuleTemplateContentError::ApprovalRuleTemplateContentRequired,
                     )
                 }
                 "ApprovalRuleTemplateDoesNotExistException" => {
-                    return RusotoError::Service(
-                        UpdateApprovalRuleTemplateContentError::ApprovalRuleTemplateDoesNotExist(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApprovalRuleTemplateContentError::ApprovalRuleTemplateDoesNotExist,
                     )
                 }
                 "ApprovalRuleTemplateNameRequiredException" => {
-                    return RusotoError::Service(
-                        UpdateApprovalRuleTemplateContentError::ApprovalRuleTemplateNameRequired(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApprovalRuleTemplateContentError::ApprovalRuleTemplateNameRequired,
                     )
                 }
                 "InvalidApprovalRuleTemplateContentException" => {
-                    return RusotoError::Service(
-                        UpdateApprovalRuleTemplateContentError::InvalidApprovalRuleTemplateContent(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApprovalRuleTemplateContentError::InvalidApprovalRuleTemplateContent,
                     )
                 }
                 "InvalidApprovalRuleTemplateNameException" => {
-                    return RusotoError::Service(
-                        UpdateApprovalRuleTemplateContentError::InvalidApprovalRuleTemplateName(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApprovalRuleTemplateContentError::InvalidApprovalRuleTemplateName,
                     )
                 }
                 "InvalidRuleContentSha256Exception" => {
-                    return RusotoError::Service(
-                        UpdateApprovalRuleTemplateContentError::InvalidRuleContentSha256(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApprovalRuleTemplateContentError::InvalidRuleContentSha256,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13365,11 +16290,11 @@ impl UpdateApprovalRuleTemplateDescriptionError {
     ) -> RusotoError<UpdateApprovalRuleTemplateDescriptionError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                                "ApprovalRuleTemplateDoesNotExistException" => return RusotoError::Service(UpdateApprovalRuleTemplateDescriptionError::ApprovalRuleTemplateDoesNotExist(err.msg)),
-"ApprovalRuleTemplateNameRequiredException" => return RusotoError::Service(UpdateApprovalRuleTemplateDescriptionError::ApprovalRuleTemplateNameRequired(err.msg)),
-"InvalidApprovalRuleTemplateDescriptionException" => return RusotoError::Service(UpdateApprovalRuleTemplateDescriptionError::InvalidApprovalRuleTemplateDescription(err.msg)),
-"InvalidApprovalRuleTemplateNameException" => return RusotoError::Service(UpdateApprovalRuleTemplateDescriptionError::InvalidApprovalRuleTemplateName(err.msg)),
-"ValidationException" => return RusotoError::Validation(err.msg),
+                                "ApprovalRuleTemplateDoesNotExistException" => return RusotoError::service_error(&res, &err.typ, err.msg, UpdateApprovalRuleTemplateDescriptionError::ApprovalRuleTemplateDoesNotExist),
+"ApprovalRuleTemplateNameRequiredException" => return RusotoError::service_error(&res, &err.typ, err.msg, UpdateApprovalRuleTemplateDescriptionError::ApprovalRuleTemplateNameRequired),
+"InvalidApprovalRuleTemplateDescriptionException" => return RusotoError::service_error(&res, &err.typ, err.msg, UpdateApprovalRuleTemplateDescriptionError::InvalidApprovalRuleTemplateDescription),
+"InvalidApprovalRuleTemplateNameException" => return RusotoError::service_error(&res, &err.typ, err.msg, UpdateApprovalRuleTemplateDescriptionError::InvalidApprovalRuleTemplateName),
+"ValidationException" => return RusotoError::validation_error(&res, err.msg),
 _ => {}
                             }
         }
@@ -13416,34 +16341,38 @@ impl UpdateApprovalRuleTemplateNameError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApprovalRuleTemplateDoesNotExistException" => {
-                    return RusotoError::Service(
-                        UpdateApprovalRuleTemplateNameError::ApprovalRuleTemplateDoesNotExist(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApprovalRuleTemplateNameError::ApprovalRuleTemplateDoesNotExist,
                     )
                 }
                 "ApprovalRuleTemplateNameAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        UpdateApprovalRuleTemplateNameError::ApprovalRuleTemplateNameAlreadyExists(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApprovalRuleTemplateNameError::ApprovalRuleTemplateNameAlreadyExists,
                     )
                 }
                 "ApprovalRuleTemplateNameRequiredException" => {
-                    return RusotoError::Service(
-                        UpdateApprovalRuleTemplateNameError::ApprovalRuleTemplateNameRequired(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApprovalRuleTemplateNameError::ApprovalRuleTemplateNameRequired,
                     )
                 }
                 "InvalidApprovalRuleTemplateNameException" => {
-                    return RusotoError::Service(
-                        UpdateApprovalRuleTemplateNameError::InvalidApprovalRuleTemplateName(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApprovalRuleTemplateNameError::InvalidApprovalRuleTemplateName,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13494,33 +16423,62 @@ impl UpdateCommentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CommentContentRequiredException" => {
-                    return RusotoError::Service(UpdateCommentError::CommentContentRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateCommentError::CommentContentRequired,
+                    )
                 }
                 "CommentContentSizeLimitExceededException" => {
-                    return RusotoError::Service(
-                        UpdateCommentError::CommentContentSizeLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCommentError::CommentContentSizeLimitExceeded,
                     )
                 }
                 "CommentDeletedException" => {
-                    return RusotoError::Service(UpdateCommentError::CommentDeleted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCommentError::CommentDeleted,
+                    )
                 }
                 "CommentDoesNotExistException" => {
-                    return RusotoError::Service(UpdateCommentError::CommentDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCommentError::CommentDoesNotExist,
+                    )
                 }
                 "CommentIdRequiredException" => {
-                    return RusotoError::Service(UpdateCommentError::CommentIdRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCommentError::CommentIdRequired,
+                    )
                 }
                 "CommentNotCreatedByCallerException" => {
-                    return RusotoError::Service(UpdateCommentError::CommentNotCreatedByCaller(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateCommentError::CommentNotCreatedByCaller,
+                    )
                 }
                 "InvalidCommentIdException" => {
-                    return RusotoError::Service(UpdateCommentError::InvalidCommentId(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCommentError::InvalidCommentId,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13576,61 +16534,94 @@ impl UpdateDefaultBranchError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BranchDoesNotExistException" => {
-                    return RusotoError::Service(UpdateDefaultBranchError::BranchDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDefaultBranchError::BranchDoesNotExist,
+                    )
                 }
                 "BranchNameRequiredException" => {
-                    return RusotoError::Service(UpdateDefaultBranchError::BranchNameRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDefaultBranchError::BranchNameRequired,
+                    )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        UpdateDefaultBranchError::EncryptionIntegrityChecksFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDefaultBranchError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
-                        UpdateDefaultBranchError::EncryptionKeyAccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDefaultBranchError::EncryptionKeyAccessDenied,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(UpdateDefaultBranchError::EncryptionKeyDisabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDefaultBranchError::EncryptionKeyDisabled,
+                    )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(UpdateDefaultBranchError::EncryptionKeyNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDefaultBranchError::EncryptionKeyNotFound,
+                    )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
-                        UpdateDefaultBranchError::EncryptionKeyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDefaultBranchError::EncryptionKeyUnavailable,
                     )
                 }
                 "InvalidBranchNameException" => {
-                    return RusotoError::Service(UpdateDefaultBranchError::InvalidBranchName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDefaultBranchError::InvalidBranchName,
+                    )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(UpdateDefaultBranchError::InvalidRepositoryName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDefaultBranchError::InvalidRepositoryName,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(UpdateDefaultBranchError::RepositoryDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDefaultBranchError::RepositoryDoesNotExist,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(UpdateDefaultBranchError::RepositoryNameRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDefaultBranchError::RepositoryNameRequired,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13704,104 +16695,52 @@ impl UpdatePullRequestApprovalRuleContentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApprovalRuleContentRequiredException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestApprovalRuleContentError::ApprovalRuleContentRequired(
-                            err.msg,
-                        ),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, UpdatePullRequestApprovalRuleContentError::ApprovalRuleContentRequired)
                 }
                 "ApprovalRuleDoesNotExistException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestApprovalRuleContentError::ApprovalRuleDoesNotExist(
-                            err.msg,
-                        ),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, UpdatePullRequestApprovalRuleContentError::ApprovalRuleDoesNotExist)
                 }
                 "ApprovalRuleNameRequiredException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestApprovalRuleContentError::ApprovalRuleNameRequired(
-                            err.msg,
-                        ),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, UpdatePullRequestApprovalRuleContentError::ApprovalRuleNameRequired)
                 }
-                "CannotModifyApprovalRuleFromTemplateException" => return RusotoError::Service(
-                    UpdatePullRequestApprovalRuleContentError::CannotModifyApprovalRuleFromTemplate(
-                        err.msg,
-                    ),
-                ),
+                "CannotModifyApprovalRuleFromTemplateException" => return RusotoError::service_error(&res, &err.typ, err.msg, UpdatePullRequestApprovalRuleContentError::CannotModifyApprovalRuleFromTemplate),
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestApprovalRuleContentError::EncryptionIntegrityChecksFailed(
-                            err.msg,
-                        ),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, UpdatePullRequestApprovalRuleContentError::EncryptionIntegrityChecksFailed)
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestApprovalRuleContentError::EncryptionKeyAccessDenied(
-                            err.msg,
-                        ),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, UpdatePullRequestApprovalRuleContentError::EncryptionKeyAccessDenied)
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestApprovalRuleContentError::EncryptionKeyDisabled(err.msg),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, UpdatePullRequestApprovalRuleContentError::EncryptionKeyDisabled)
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestApprovalRuleContentError::EncryptionKeyNotFound(err.msg),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, UpdatePullRequestApprovalRuleContentError::EncryptionKeyNotFound)
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestApprovalRuleContentError::EncryptionKeyUnavailable(
-                            err.msg,
-                        ),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, UpdatePullRequestApprovalRuleContentError::EncryptionKeyUnavailable)
                 }
                 "InvalidApprovalRuleContentException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestApprovalRuleContentError::InvalidApprovalRuleContent(
-                            err.msg,
-                        ),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, UpdatePullRequestApprovalRuleContentError::InvalidApprovalRuleContent)
                 }
                 "InvalidApprovalRuleNameException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestApprovalRuleContentError::InvalidApprovalRuleName(err.msg),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, UpdatePullRequestApprovalRuleContentError::InvalidApprovalRuleName)
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestApprovalRuleContentError::InvalidPullRequestId(err.msg),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, UpdatePullRequestApprovalRuleContentError::InvalidPullRequestId)
                 }
                 "InvalidRuleContentSha256Exception" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestApprovalRuleContentError::InvalidRuleContentSha256(
-                            err.msg,
-                        ),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, UpdatePullRequestApprovalRuleContentError::InvalidRuleContentSha256)
                 }
                 "PullRequestAlreadyClosedException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestApprovalRuleContentError::PullRequestAlreadyClosed(
-                            err.msg,
-                        ),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, UpdatePullRequestApprovalRuleContentError::PullRequestAlreadyClosed)
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestApprovalRuleContentError::PullRequestDoesNotExist(err.msg),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, UpdatePullRequestApprovalRuleContentError::PullRequestDoesNotExist)
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestApprovalRuleContentError::PullRequestIdRequired(err.msg),
-                    )
+                    return RusotoError::service_error(&res, &err.typ, err.msg, UpdatePullRequestApprovalRuleContentError::PullRequestIdRequired)
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13908,92 +16847,134 @@ impl UpdatePullRequestApprovalStateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApprovalStateRequiredException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestApprovalStateError::ApprovalStateRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePullRequestApprovalStateError::ApprovalStateRequired,
                     )
                 }
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestApprovalStateError::EncryptionIntegrityChecksFailed(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePullRequestApprovalStateError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestApprovalStateError::EncryptionKeyAccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePullRequestApprovalStateError::EncryptionKeyAccessDenied,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestApprovalStateError::EncryptionKeyDisabled(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePullRequestApprovalStateError::EncryptionKeyDisabled,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestApprovalStateError::EncryptionKeyNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePullRequestApprovalStateError::EncryptionKeyNotFound,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestApprovalStateError::EncryptionKeyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePullRequestApprovalStateError::EncryptionKeyUnavailable,
                     )
                 }
                 "InvalidApprovalStateException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestApprovalStateError::InvalidApprovalState(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePullRequestApprovalStateError::InvalidApprovalState,
                     )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestApprovalStateError::InvalidPullRequestId(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePullRequestApprovalStateError::InvalidPullRequestId,
                     )
                 }
                 "InvalidRevisionIdException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestApprovalStateError::InvalidRevisionId(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePullRequestApprovalStateError::InvalidRevisionId,
                     )
                 }
                 "MaximumNumberOfApprovalsExceededException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestApprovalStateError::MaximumNumberOfApprovalsExceeded(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePullRequestApprovalStateError::MaximumNumberOfApprovalsExceeded,
                     )
                 }
                 "PullRequestAlreadyClosedException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestApprovalStateError::PullRequestAlreadyClosed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePullRequestApprovalStateError::PullRequestAlreadyClosed,
                     )
                 }
                 "PullRequestCannotBeApprovedByAuthorException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestApprovalStateError::PullRequestCannotBeApprovedByAuthor(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePullRequestApprovalStateError::PullRequestCannotBeApprovedByAuthor,
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestApprovalStateError::PullRequestDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePullRequestApprovalStateError::PullRequestDoesNotExist,
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestApprovalStateError::PullRequestIdRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePullRequestApprovalStateError::PullRequestIdRequired,
                     )
                 }
                 "RevisionIdRequiredException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestApprovalStateError::RevisionIdRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePullRequestApprovalStateError::RevisionIdRequired,
                     )
                 }
                 "RevisionNotCurrentException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestApprovalStateError::RevisionNotCurrent(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePullRequestApprovalStateError::RevisionNotCurrent,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14078,31 +17059,46 @@ impl UpdatePullRequestDescriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidDescriptionException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestDescriptionError::InvalidDescription(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePullRequestDescriptionError::InvalidDescription,
                     )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestDescriptionError::InvalidPullRequestId(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePullRequestDescriptionError::InvalidPullRequestId,
                     )
                 }
                 "PullRequestAlreadyClosedException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestDescriptionError::PullRequestAlreadyClosed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePullRequestDescriptionError::PullRequestAlreadyClosed,
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestDescriptionError::PullRequestDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePullRequestDescriptionError::PullRequestDoesNotExist,
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestDescriptionError::PullRequestIdRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePullRequestDescriptionError::PullRequestIdRequired,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14164,61 +17160,94 @@ impl UpdatePullRequestStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestStatusError::EncryptionIntegrityChecksFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePullRequestStatusError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestStatusError::EncryptionKeyAccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePullRequestStatusError::EncryptionKeyAccessDenied,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestStatusError::EncryptionKeyDisabled(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePullRequestStatusError::EncryptionKeyDisabled,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestStatusError::EncryptionKeyNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePullRequestStatusError::EncryptionKeyNotFound,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestStatusError::EncryptionKeyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePullRequestStatusError::EncryptionKeyUnavailable,
                     )
                 }
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestStatusError::InvalidPullRequestId(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePullRequestStatusError::InvalidPullRequestId,
                     )
                 }
                 "InvalidPullRequestStatusException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestStatusError::InvalidPullRequestStatus(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePullRequestStatusError::InvalidPullRequestStatus,
                     )
                 }
                 "InvalidPullRequestStatusUpdateException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestStatusError::InvalidPullRequestStatusUpdate(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePullRequestStatusError::InvalidPullRequestStatusUpdate,
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestStatusError::PullRequestDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePullRequestStatusError::PullRequestDoesNotExist,
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestStatusError::PullRequestIdRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePullRequestStatusError::PullRequestIdRequired,
                     )
                 }
                 "PullRequestStatusRequiredException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestStatusError::PullRequestStatusRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePullRequestStatusError::PullRequestStatusRequired,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14286,34 +17315,54 @@ impl UpdatePullRequestTitleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidPullRequestIdException" => {
-                    return RusotoError::Service(UpdatePullRequestTitleError::InvalidPullRequestId(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdatePullRequestTitleError::InvalidPullRequestId,
+                    )
                 }
                 "InvalidTitleException" => {
-                    return RusotoError::Service(UpdatePullRequestTitleError::InvalidTitle(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePullRequestTitleError::InvalidTitle,
+                    )
                 }
                 "PullRequestAlreadyClosedException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestTitleError::PullRequestAlreadyClosed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePullRequestTitleError::PullRequestAlreadyClosed,
                     )
                 }
                 "PullRequestDoesNotExistException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestTitleError::PullRequestDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePullRequestTitleError::PullRequestDoesNotExist,
                     )
                 }
                 "PullRequestIdRequiredException" => {
-                    return RusotoError::Service(
-                        UpdatePullRequestTitleError::PullRequestIdRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePullRequestTitleError::PullRequestIdRequired,
                     )
                 }
                 "TitleRequiredException" => {
-                    return RusotoError::Service(UpdatePullRequestTitleError::TitleRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdatePullRequestTitleError::TitleRequired,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14368,51 +17417,78 @@ impl UpdateRepositoryDescriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EncryptionIntegrityChecksFailedException" => {
-                    return RusotoError::Service(
-                        UpdateRepositoryDescriptionError::EncryptionIntegrityChecksFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRepositoryDescriptionError::EncryptionIntegrityChecksFailed,
                     )
                 }
                 "EncryptionKeyAccessDeniedException" => {
-                    return RusotoError::Service(
-                        UpdateRepositoryDescriptionError::EncryptionKeyAccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRepositoryDescriptionError::EncryptionKeyAccessDenied,
                     )
                 }
                 "EncryptionKeyDisabledException" => {
-                    return RusotoError::Service(
-                        UpdateRepositoryDescriptionError::EncryptionKeyDisabled(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRepositoryDescriptionError::EncryptionKeyDisabled,
                     )
                 }
                 "EncryptionKeyNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateRepositoryDescriptionError::EncryptionKeyNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRepositoryDescriptionError::EncryptionKeyNotFound,
                     )
                 }
                 "EncryptionKeyUnavailableException" => {
-                    return RusotoError::Service(
-                        UpdateRepositoryDescriptionError::EncryptionKeyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRepositoryDescriptionError::EncryptionKeyUnavailable,
                     )
                 }
                 "InvalidRepositoryDescriptionException" => {
-                    return RusotoError::Service(
-                        UpdateRepositoryDescriptionError::InvalidRepositoryDescription(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRepositoryDescriptionError::InvalidRepositoryDescription,
                     )
                 }
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(
-                        UpdateRepositoryDescriptionError::InvalidRepositoryName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRepositoryDescriptionError::InvalidRepositoryName,
                     )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(
-                        UpdateRepositoryDescriptionError::RepositoryDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRepositoryDescriptionError::RepositoryDoesNotExist,
                     )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(
-                        UpdateRepositoryDescriptionError::RepositoryNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRepositoryDescriptionError::RepositoryNameRequired,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14472,26 +17548,38 @@ impl UpdateRepositoryNameError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidRepositoryNameException" => {
-                    return RusotoError::Service(UpdateRepositoryNameError::InvalidRepositoryName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRepositoryNameError::InvalidRepositoryName,
+                    )
                 }
                 "RepositoryDoesNotExistException" => {
-                    return RusotoError::Service(UpdateRepositoryNameError::RepositoryDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRepositoryNameError::RepositoryDoesNotExist,
+                    )
                 }
                 "RepositoryNameExistsException" => {
-                    return RusotoError::Service(UpdateRepositoryNameError::RepositoryNameExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRepositoryNameError::RepositoryNameExists,
+                    )
                 }
                 "RepositoryNameRequiredException" => {
-                    return RusotoError::Service(UpdateRepositoryNameError::RepositoryNameRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRepositoryNameError::RepositoryNameRequired,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15012,11 +18100,12 @@ pub struct CodeCommitClient {
 impl CodeCommitClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> CodeCommitClient {
         CodeCommitClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "CodeCommit"),
         }
     }
 
@@ -15031,12 +18120,32 @@ impl CodeCommitClient {
     {
         CodeCommitClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "CodeCommit"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CodeCommitClient {
-        CodeCommitClient { client, region }
+        CodeCommitClient {
+            client,
+            region: region_for_service(region, "CodeCommit"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> CodeCommitClient {
+        CodeCommitClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("CodeCommit"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CodeCommitClient {
+        CodeCommitClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -15048,6 +18157,7 @@ impl CodeCommit for CodeCommitClient {
         input: AssociateApprovalRuleTemplateWithRepositoryInput,
     ) -> Result<(), RusotoError<AssociateApprovalRuleTemplateWithRepositoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateApprovalRuleTemplateWithRepository");
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.AssociateApprovalRuleTemplateWithRepository",
@@ -15074,6 +18184,7 @@ impl CodeCommit for CodeCommitClient {
         RusotoError<BatchAssociateApprovalRuleTemplateWithRepositoriesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchAssociateApprovalRuleTemplateWithRepositories");
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.BatchAssociateApprovalRuleTemplateWithRepositories",
@@ -15100,6 +18211,7 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<BatchDescribeMergeConflictsOutput, RusotoError<BatchDescribeMergeConflictsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchDescribeMergeConflicts");
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.BatchDescribeMergeConflicts",
@@ -15125,6 +18237,7 @@ impl CodeCommit for CodeCommitClient {
         RusotoError<BatchDisassociateApprovalRuleTemplateFromRepositoriesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchDisassociateApprovalRuleTemplateFromRepositories");
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.BatchDisassociateApprovalRuleTemplateFromRepositories",
@@ -15150,6 +18263,7 @@ impl CodeCommit for CodeCommitClient {
         input: BatchGetCommitsInput,
     ) -> Result<BatchGetCommitsOutput, RusotoError<BatchGetCommitsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchGetCommits");
         request.add_header("x-amz-target", "CodeCommit_20150413.BatchGetCommits");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15168,6 +18282,7 @@ impl CodeCommit for CodeCommitClient {
         input: BatchGetRepositoriesInput,
     ) -> Result<BatchGetRepositoriesOutput, RusotoError<BatchGetRepositoriesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchGetRepositories");
         request.add_header("x-amz-target", "CodeCommit_20150413.BatchGetRepositories");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15187,6 +18302,7 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<CreateApprovalRuleTemplateOutput, RusotoError<CreateApprovalRuleTemplateError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateApprovalRuleTemplate");
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.CreateApprovalRuleTemplate",
@@ -15209,6 +18325,7 @@ impl CodeCommit for CodeCommitClient {
         input: CreateBranchInput,
     ) -> Result<(), RusotoError<CreateBranchError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateBranch");
         request.add_header("x-amz-target", "CodeCommit_20150413.CreateBranch");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15226,6 +18343,7 @@ impl CodeCommit for CodeCommitClient {
         input: CreateCommitInput,
     ) -> Result<CreateCommitOutput, RusotoError<CreateCommitError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateCommit");
         request.add_header("x-amz-target", "CodeCommit_20150413.CreateCommit");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15244,6 +18362,7 @@ impl CodeCommit for CodeCommitClient {
         input: CreatePullRequestInput,
     ) -> Result<CreatePullRequestOutput, RusotoError<CreatePullRequestError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreatePullRequest");
         request.add_header("x-amz-target", "CodeCommit_20150413.CreatePullRequest");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15263,6 +18382,7 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<CreatePullRequestApprovalRuleOutput, RusotoError<CreatePullRequestApprovalRuleError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreatePullRequestApprovalRule");
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.CreatePullRequestApprovalRule",
@@ -15285,6 +18405,7 @@ impl CodeCommit for CodeCommitClient {
         input: CreateRepositoryInput,
     ) -> Result<CreateRepositoryOutput, RusotoError<CreateRepositoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateRepository");
         request.add_header("x-amz-target", "CodeCommit_20150413.CreateRepository");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15304,6 +18425,7 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<CreateUnreferencedMergeCommitOutput, RusotoError<CreateUnreferencedMergeCommitError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateUnreferencedMergeCommit");
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.CreateUnreferencedMergeCommit",
@@ -15327,6 +18449,7 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<DeleteApprovalRuleTemplateOutput, RusotoError<DeleteApprovalRuleTemplateError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteApprovalRuleTemplate");
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.DeleteApprovalRuleTemplate",
@@ -15349,6 +18472,7 @@ impl CodeCommit for CodeCommitClient {
         input: DeleteBranchInput,
     ) -> Result<DeleteBranchOutput, RusotoError<DeleteBranchError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteBranch");
         request.add_header("x-amz-target", "CodeCommit_20150413.DeleteBranch");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15367,6 +18491,7 @@ impl CodeCommit for CodeCommitClient {
         input: DeleteCommentContentInput,
     ) -> Result<DeleteCommentContentOutput, RusotoError<DeleteCommentContentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteCommentContent");
         request.add_header("x-amz-target", "CodeCommit_20150413.DeleteCommentContent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15385,6 +18510,7 @@ impl CodeCommit for CodeCommitClient {
         input: DeleteFileInput,
     ) -> Result<DeleteFileOutput, RusotoError<DeleteFileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteFile");
         request.add_header("x-amz-target", "CodeCommit_20150413.DeleteFile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15404,6 +18530,7 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<DeletePullRequestApprovalRuleOutput, RusotoError<DeletePullRequestApprovalRuleError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeletePullRequestApprovalRule");
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.DeletePullRequestApprovalRule",
@@ -15426,6 +18553,7 @@ impl CodeCommit for CodeCommitClient {
         input: DeleteRepositoryInput,
     ) -> Result<DeleteRepositoryOutput, RusotoError<DeleteRepositoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteRepository");
         request.add_header("x-amz-target", "CodeCommit_20150413.DeleteRepository");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15444,6 +18572,7 @@ impl CodeCommit for CodeCommitClient {
         input: DescribeMergeConflictsInput,
     ) -> Result<DescribeMergeConflictsOutput, RusotoError<DescribeMergeConflictsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeMergeConflicts");
         request.add_header("x-amz-target", "CodeCommit_20150413.DescribeMergeConflicts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15463,6 +18592,7 @@ impl CodeCommit for CodeCommitClient {
         input: DescribePullRequestEventsInput,
     ) -> Result<DescribePullRequestEventsOutput, RusotoError<DescribePullRequestEventsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribePullRequestEvents");
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.DescribePullRequestEvents",
@@ -15485,6 +18615,7 @@ impl CodeCommit for CodeCommitClient {
         input: DisassociateApprovalRuleTemplateFromRepositoryInput,
     ) -> Result<(), RusotoError<DisassociateApprovalRuleTemplateFromRepositoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisassociateApprovalRuleTemplateFromRepository");
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.DisassociateApprovalRuleTemplateFromRepository",
@@ -15511,6 +18642,7 @@ impl CodeCommit for CodeCommitClient {
         RusotoError<EvaluatePullRequestApprovalRulesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("EvaluatePullRequestApprovalRules");
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.EvaluatePullRequestApprovalRules",
@@ -15536,6 +18668,7 @@ impl CodeCommit for CodeCommitClient {
         input: GetApprovalRuleTemplateInput,
     ) -> Result<GetApprovalRuleTemplateOutput, RusotoError<GetApprovalRuleTemplateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetApprovalRuleTemplate");
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.GetApprovalRuleTemplate",
@@ -15558,6 +18691,7 @@ impl CodeCommit for CodeCommitClient {
         input: GetBlobInput,
     ) -> Result<GetBlobOutput, RusotoError<GetBlobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetBlob");
         request.add_header("x-amz-target", "CodeCommit_20150413.GetBlob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15576,6 +18710,7 @@ impl CodeCommit for CodeCommitClient {
         input: GetBranchInput,
     ) -> Result<GetBranchOutput, RusotoError<GetBranchError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetBranch");
         request.add_header("x-amz-target", "CodeCommit_20150413.GetBranch");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15594,6 +18729,7 @@ impl CodeCommit for CodeCommitClient {
         input: GetCommentInput,
     ) -> Result<GetCommentOutput, RusotoError<GetCommentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetComment");
         request.add_header("x-amz-target", "CodeCommit_20150413.GetComment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15612,6 +18748,7 @@ impl CodeCommit for CodeCommitClient {
         input: GetCommentReactionsInput,
     ) -> Result<GetCommentReactionsOutput, RusotoError<GetCommentReactionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetCommentReactions");
         request.add_header("x-amz-target", "CodeCommit_20150413.GetCommentReactions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15631,6 +18768,7 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<GetCommentsForComparedCommitOutput, RusotoError<GetCommentsForComparedCommitError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetCommentsForComparedCommit");
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.GetCommentsForComparedCommit",
@@ -15653,6 +18791,7 @@ impl CodeCommit for CodeCommitClient {
         input: GetCommentsForPullRequestInput,
     ) -> Result<GetCommentsForPullRequestOutput, RusotoError<GetCommentsForPullRequestError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetCommentsForPullRequest");
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.GetCommentsForPullRequest",
@@ -15675,6 +18814,7 @@ impl CodeCommit for CodeCommitClient {
         input: GetCommitInput,
     ) -> Result<GetCommitOutput, RusotoError<GetCommitError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetCommit");
         request.add_header("x-amz-target", "CodeCommit_20150413.GetCommit");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15693,6 +18833,7 @@ impl CodeCommit for CodeCommitClient {
         input: GetDifferencesInput,
     ) -> Result<GetDifferencesOutput, RusotoError<GetDifferencesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDifferences");
         request.add_header("x-amz-target", "CodeCommit_20150413.GetDifferences");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15711,6 +18852,7 @@ impl CodeCommit for CodeCommitClient {
         input: GetFileInput,
     ) -> Result<GetFileOutput, RusotoError<GetFileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetFile");
         request.add_header("x-amz-target", "CodeCommit_20150413.GetFile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15729,6 +18871,7 @@ impl CodeCommit for CodeCommitClient {
         input: GetFolderInput,
     ) -> Result<GetFolderOutput, RusotoError<GetFolderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetFolder");
         request.add_header("x-amz-target", "CodeCommit_20150413.GetFolder");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15747,6 +18890,7 @@ impl CodeCommit for CodeCommitClient {
         input: GetMergeCommitInput,
     ) -> Result<GetMergeCommitOutput, RusotoError<GetMergeCommitError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetMergeCommit");
         request.add_header("x-amz-target", "CodeCommit_20150413.GetMergeCommit");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15765,6 +18909,7 @@ impl CodeCommit for CodeCommitClient {
         input: GetMergeConflictsInput,
     ) -> Result<GetMergeConflictsOutput, RusotoError<GetMergeConflictsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetMergeConflicts");
         request.add_header("x-amz-target", "CodeCommit_20150413.GetMergeConflicts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15783,6 +18928,7 @@ impl CodeCommit for CodeCommitClient {
         input: GetMergeOptionsInput,
     ) -> Result<GetMergeOptionsOutput, RusotoError<GetMergeOptionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetMergeOptions");
         request.add_header("x-amz-target", "CodeCommit_20150413.GetMergeOptions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15801,6 +18947,7 @@ impl CodeCommit for CodeCommitClient {
         input: GetPullRequestInput,
     ) -> Result<GetPullRequestOutput, RusotoError<GetPullRequestError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetPullRequest");
         request.add_header("x-amz-target", "CodeCommit_20150413.GetPullRequest");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15820,6 +18967,7 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<GetPullRequestApprovalStatesOutput, RusotoError<GetPullRequestApprovalStatesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetPullRequestApprovalStates");
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.GetPullRequestApprovalStates",
@@ -15843,6 +18991,7 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<GetPullRequestOverrideStateOutput, RusotoError<GetPullRequestOverrideStateError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetPullRequestOverrideState");
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.GetPullRequestOverrideState",
@@ -15865,6 +19014,7 @@ impl CodeCommit for CodeCommitClient {
         input: GetRepositoryInput,
     ) -> Result<GetRepositoryOutput, RusotoError<GetRepositoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRepository");
         request.add_header("x-amz-target", "CodeCommit_20150413.GetRepository");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15883,6 +19033,7 @@ impl CodeCommit for CodeCommitClient {
         input: GetRepositoryTriggersInput,
     ) -> Result<GetRepositoryTriggersOutput, RusotoError<GetRepositoryTriggersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRepositoryTriggers");
         request.add_header("x-amz-target", "CodeCommit_20150413.GetRepositoryTriggers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15901,6 +19052,7 @@ impl CodeCommit for CodeCommitClient {
         input: ListApprovalRuleTemplatesInput,
     ) -> Result<ListApprovalRuleTemplatesOutput, RusotoError<ListApprovalRuleTemplatesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListApprovalRuleTemplates");
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.ListApprovalRuleTemplates",
@@ -15926,6 +19078,7 @@ impl CodeCommit for CodeCommitClient {
         RusotoError<ListAssociatedApprovalRuleTemplatesForRepositoryError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListAssociatedApprovalRuleTemplatesForRepository");
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.ListAssociatedApprovalRuleTemplatesForRepository",
@@ -15951,6 +19104,7 @@ impl CodeCommit for CodeCommitClient {
         input: ListBranchesInput,
     ) -> Result<ListBranchesOutput, RusotoError<ListBranchesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListBranches");
         request.add_header("x-amz-target", "CodeCommit_20150413.ListBranches");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15969,6 +19123,7 @@ impl CodeCommit for CodeCommitClient {
         input: ListPullRequestsInput,
     ) -> Result<ListPullRequestsOutput, RusotoError<ListPullRequestsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListPullRequests");
         request.add_header("x-amz-target", "CodeCommit_20150413.ListPullRequests");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -15987,6 +19142,7 @@ impl CodeCommit for CodeCommitClient {
         input: ListRepositoriesInput,
     ) -> Result<ListRepositoriesOutput, RusotoError<ListRepositoriesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListRepositories");
         request.add_header("x-amz-target", "CodeCommit_20150413.ListRepositories");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16008,6 +19164,7 @@ impl CodeCommit for CodeCommitClient {
         RusotoError<ListRepositoriesForApprovalRuleTemplateError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListRepositoriesForApprovalRuleTemplate");
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.ListRepositoriesForApprovalRuleTemplate",
@@ -16033,6 +19190,7 @@ impl CodeCommit for CodeCommitClient {
         input: ListTagsForResourceInput,
     ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header("x-amz-target", "CodeCommit_20150413.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16052,6 +19210,7 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<MergeBranchesByFastForwardOutput, RusotoError<MergeBranchesByFastForwardError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("MergeBranchesByFastForward");
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.MergeBranchesByFastForward",
@@ -16074,6 +19233,7 @@ impl CodeCommit for CodeCommitClient {
         input: MergeBranchesBySquashInput,
     ) -> Result<MergeBranchesBySquashOutput, RusotoError<MergeBranchesBySquashError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("MergeBranchesBySquash");
         request.add_header("x-amz-target", "CodeCommit_20150413.MergeBranchesBySquash");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16092,6 +19252,7 @@ impl CodeCommit for CodeCommitClient {
         input: MergeBranchesByThreeWayInput,
     ) -> Result<MergeBranchesByThreeWayOutput, RusotoError<MergeBranchesByThreeWayError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("MergeBranchesByThreeWay");
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.MergeBranchesByThreeWay",
@@ -16115,6 +19276,7 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<MergePullRequestByFastForwardOutput, RusotoError<MergePullRequestByFastForwardError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("MergePullRequestByFastForward");
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.MergePullRequestByFastForward",
@@ -16137,6 +19299,7 @@ impl CodeCommit for CodeCommitClient {
         input: MergePullRequestBySquashInput,
     ) -> Result<MergePullRequestBySquashOutput, RusotoError<MergePullRequestBySquashError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("MergePullRequestBySquash");
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.MergePullRequestBySquash",
@@ -16160,6 +19323,7 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<MergePullRequestByThreeWayOutput, RusotoError<MergePullRequestByThreeWayError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("MergePullRequestByThreeWay");
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.MergePullRequestByThreeWay",
@@ -16182,6 +19346,7 @@ impl CodeCommit for CodeCommitClient {
         input: OverridePullRequestApprovalRulesInput,
     ) -> Result<(), RusotoError<OverridePullRequestApprovalRulesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("OverridePullRequestApprovalRules");
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.OverridePullRequestApprovalRules",
@@ -16206,6 +19371,7 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<PostCommentForComparedCommitOutput, RusotoError<PostCommentForComparedCommitError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PostCommentForComparedCommit");
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.PostCommentForComparedCommit",
@@ -16228,6 +19394,7 @@ impl CodeCommit for CodeCommitClient {
         input: PostCommentForPullRequestInput,
     ) -> Result<PostCommentForPullRequestOutput, RusotoError<PostCommentForPullRequestError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PostCommentForPullRequest");
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.PostCommentForPullRequest",
@@ -16250,6 +19417,7 @@ impl CodeCommit for CodeCommitClient {
         input: PostCommentReplyInput,
     ) -> Result<PostCommentReplyOutput, RusotoError<PostCommentReplyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PostCommentReply");
         request.add_header("x-amz-target", "CodeCommit_20150413.PostCommentReply");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16268,6 +19436,7 @@ impl CodeCommit for CodeCommitClient {
         input: PutCommentReactionInput,
     ) -> Result<(), RusotoError<PutCommentReactionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutCommentReaction");
         request.add_header("x-amz-target", "CodeCommit_20150413.PutCommentReaction");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16285,6 +19454,7 @@ impl CodeCommit for CodeCommitClient {
         input: PutFileInput,
     ) -> Result<PutFileOutput, RusotoError<PutFileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutFile");
         request.add_header("x-amz-target", "CodeCommit_20150413.PutFile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16303,6 +19473,7 @@ impl CodeCommit for CodeCommitClient {
         input: PutRepositoryTriggersInput,
     ) -> Result<PutRepositoryTriggersOutput, RusotoError<PutRepositoryTriggersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutRepositoryTriggers");
         request.add_header("x-amz-target", "CodeCommit_20150413.PutRepositoryTriggers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16321,6 +19492,7 @@ impl CodeCommit for CodeCommitClient {
         input: TagResourceInput,
     ) -> Result<(), RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "CodeCommit_20150413.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16338,6 +19510,7 @@ impl CodeCommit for CodeCommitClient {
         input: TestRepositoryTriggersInput,
     ) -> Result<TestRepositoryTriggersOutput, RusotoError<TestRepositoryTriggersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TestRepositoryTriggers");
         request.add_header("x-amz-target", "CodeCommit_20150413.TestRepositoryTriggers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16357,6 +19530,7 @@ impl CodeCommit for CodeCommitClient {
         input: UntagResourceInput,
     ) -> Result<(), RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "CodeCommit_20150413.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16377,6 +19551,7 @@ impl CodeCommit for CodeCommitClient {
         RusotoError<UpdateApprovalRuleTemplateContentError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateApprovalRuleTemplateContent");
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.UpdateApprovalRuleTemplateContent",
@@ -16405,6 +19580,7 @@ impl CodeCommit for CodeCommitClient {
         RusotoError<UpdateApprovalRuleTemplateDescriptionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateApprovalRuleTemplateDescription");
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.UpdateApprovalRuleTemplateDescription",
@@ -16433,6 +19609,7 @@ impl CodeCommit for CodeCommitClient {
         RusotoError<UpdateApprovalRuleTemplateNameError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateApprovalRuleTemplateName");
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.UpdateApprovalRuleTemplateName",
@@ -16455,6 +19632,7 @@ impl CodeCommit for CodeCommitClient {
         input: UpdateCommentInput,
     ) -> Result<UpdateCommentOutput, RusotoError<UpdateCommentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateComment");
         request.add_header("x-amz-target", "CodeCommit_20150413.UpdateComment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16473,6 +19651,7 @@ impl CodeCommit for CodeCommitClient {
         input: UpdateDefaultBranchInput,
     ) -> Result<(), RusotoError<UpdateDefaultBranchError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateDefaultBranch");
         request.add_header("x-amz-target", "CodeCommit_20150413.UpdateDefaultBranch");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16493,6 +19672,7 @@ impl CodeCommit for CodeCommitClient {
         RusotoError<UpdatePullRequestApprovalRuleContentError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdatePullRequestApprovalRuleContent");
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.UpdatePullRequestApprovalRuleContent",
@@ -16518,6 +19698,7 @@ impl CodeCommit for CodeCommitClient {
         input: UpdatePullRequestApprovalStateInput,
     ) -> Result<(), RusotoError<UpdatePullRequestApprovalStateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdatePullRequestApprovalState");
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.UpdatePullRequestApprovalState",
@@ -16539,6 +19720,7 @@ impl CodeCommit for CodeCommitClient {
     ) -> Result<UpdatePullRequestDescriptionOutput, RusotoError<UpdatePullRequestDescriptionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdatePullRequestDescription");
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.UpdatePullRequestDescription",
@@ -16561,6 +19743,7 @@ impl CodeCommit for CodeCommitClient {
         input: UpdatePullRequestStatusInput,
     ) -> Result<UpdatePullRequestStatusOutput, RusotoError<UpdatePullRequestStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdatePullRequestStatus");
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.UpdatePullRequestStatus",
@@ -16583,6 +19766,7 @@ impl CodeCommit for CodeCommitClient {
         input: UpdatePullRequestTitleInput,
     ) -> Result<UpdatePullRequestTitleOutput, RusotoError<UpdatePullRequestTitleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdatePullRequestTitle");
         request.add_header("x-amz-target", "CodeCommit_20150413.UpdatePullRequestTitle");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16602,6 +19786,7 @@ impl CodeCommit for CodeCommitClient {
         input: UpdateRepositoryDescriptionInput,
     ) -> Result<(), RusotoError<UpdateRepositoryDescriptionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateRepositoryDescription");
         request.add_header(
             "x-amz-target",
             "CodeCommit_20150413.UpdateRepositoryDescription",
@@ -16622,6 +19807,7 @@ impl CodeCommit for CodeCommitClient {
         input: UpdateRepositoryNameInput,
     ) -> Result<(), RusotoError<UpdateRepositoryNameError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateRepositoryName");
         request.add_header("x-amz-target", "CodeCommit_20150413.UpdateRepositoryName");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -16633,3 +19819,798 @@ impl CodeCommit for CodeCommitClient {
         Ok(())
     }
 }
+
+/// A blocking client for the CodeCommit API, running the calls of a `CodeCommitClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `CodeCommitClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CodeCommitBlockingClient {
+    client: CodeCommitClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CodeCommitBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> CodeCommitBlockingClient {
+        CodeCommitBlockingClient::from_client(CodeCommitClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CodeCommitBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CodeCommitBlockingClient::from_client(CodeCommitClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> CodeCommitBlockingClient {
+        CodeCommitBlockingClient::from_client(CodeCommitClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> CodeCommitBlockingClient {
+        CodeCommitBlockingClient::from_client(CodeCommitClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: CodeCommitClient) -> CodeCommitBlockingClient {
+        CodeCommitBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> CodeCommitBlockingClient {
+        CodeCommitBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CodeCommitBlockingClient {
+        CodeCommitBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`CodeCommit::associate_approval_rule_template_with_repository`].
+    pub fn associate_approval_rule_template_with_repository(
+        &self,
+        input: AssociateApprovalRuleTemplateWithRepositoryInput,
+    ) -> Result<(), RusotoError<AssociateApprovalRuleTemplateWithRepositoryError>> {
+        self.runtime.block_on(
+            self.client
+                .associate_approval_rule_template_with_repository(input),
+        )
+    }
+
+    /// Blocking version of [`CodeCommit::batch_associate_approval_rule_template_with_repositories`].
+    pub fn batch_associate_approval_rule_template_with_repositories(
+        &self,
+        input: BatchAssociateApprovalRuleTemplateWithRepositoriesInput,
+    ) -> Result<
+        BatchAssociateApprovalRuleTemplateWithRepositoriesOutput,
+        RusotoError<BatchAssociateApprovalRuleTemplateWithRepositoriesError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .batch_associate_approval_rule_template_with_repositories(input),
+        )
+    }
+
+    /// Blocking version of [`CodeCommit::batch_describe_merge_conflicts`].
+    pub fn batch_describe_merge_conflicts(
+        &self,
+        input: BatchDescribeMergeConflictsInput,
+    ) -> Result<BatchDescribeMergeConflictsOutput, RusotoError<BatchDescribeMergeConflictsError>>
+    {
+        self.runtime
+            .block_on(self.client.batch_describe_merge_conflicts(input))
+    }
+
+    /// Blocking version of [`CodeCommit::batch_disassociate_approval_rule_template_from_repositories`].
+    pub fn batch_disassociate_approval_rule_template_from_repositories(
+        &self,
+        input: BatchDisassociateApprovalRuleTemplateFromRepositoriesInput,
+    ) -> Result<
+        BatchDisassociateApprovalRuleTemplateFromRepositoriesOutput,
+        RusotoError<BatchDisassociateApprovalRuleTemplateFromRepositoriesError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .batch_disassociate_approval_rule_template_from_repositories(input),
+        )
+    }
+
+    /// Blocking version of [`CodeCommit::batch_get_commits`].
+    pub fn batch_get_commits(
+        &self,
+        input: BatchGetCommitsInput,
+    ) -> Result<BatchGetCommitsOutput, RusotoError<BatchGetCommitsError>> {
+        self.runtime.block_on(self.client.batch_get_commits(input))
+    }
+
+    /// Blocking version of [`CodeCommit::batch_get_repositories`].
+    pub fn batch_get_repositories(
+        &self,
+        input: BatchGetRepositoriesInput,
+    ) -> Result<BatchGetRepositoriesOutput, RusotoError<BatchGetRepositoriesError>> {
+        self.runtime
+            .block_on(self.client.batch_get_repositories(input))
+    }
+
+    /// Blocking version of [`CodeCommit::create_approval_rule_template`].
+    pub fn create_approval_rule_template(
+        &self,
+        input: CreateApprovalRuleTemplateInput,
+    ) -> Result<CreateApprovalRuleTemplateOutput, RusotoError<CreateApprovalRuleTemplateError>>
+    {
+        self.runtime
+            .block_on(self.client.create_approval_rule_template(input))
+    }
+
+    /// Blocking version of [`CodeCommit::create_branch`].
+    pub fn create_branch(
+        &self,
+        input: CreateBranchInput,
+    ) -> Result<(), RusotoError<CreateBranchError>> {
+        self.runtime.block_on(self.client.create_branch(input))
+    }
+
+    /// Blocking version of [`CodeCommit::create_commit`].
+    pub fn create_commit(
+        &self,
+        input: CreateCommitInput,
+    ) -> Result<CreateCommitOutput, RusotoError<CreateCommitError>> {
+        self.runtime.block_on(self.client.create_commit(input))
+    }
+
+    /// Blocking version of [`CodeCommit::create_pull_request`].
+    pub fn create_pull_request(
+        &self,
+        input: CreatePullRequestInput,
+    ) -> Result<CreatePullRequestOutput, RusotoError<CreatePullRequestError>> {
+        self.runtime
+            .block_on(self.client.create_pull_request(input))
+    }
+
+    /// Blocking version of [`CodeCommit::create_pull_request_approval_rule`].
+    pub fn create_pull_request_approval_rule(
+        &self,
+        input: CreatePullRequestApprovalRuleInput,
+    ) -> Result<CreatePullRequestApprovalRuleOutput, RusotoError<CreatePullRequestApprovalRuleError>>
+    {
+        self.runtime
+            .block_on(self.client.create_pull_request_approval_rule(input))
+    }
+
+    /// Blocking version of [`CodeCommit::create_repository`].
+    pub fn create_repository(
+        &self,
+        input: CreateRepositoryInput,
+    ) -> Result<CreateRepositoryOutput, RusotoError<CreateRepositoryError>> {
+        self.runtime.block_on(self.client.create_repository(input))
+    }
+
+    /// Blocking version of [`CodeCommit::create_unreferenced_merge_commit`].
+    pub fn create_unreferenced_merge_commit(
+        &self,
+        input: CreateUnreferencedMergeCommitInput,
+    ) -> Result<CreateUnreferencedMergeCommitOutput, RusotoError<CreateUnreferencedMergeCommitError>>
+    {
+        self.runtime
+            .block_on(self.client.create_unreferenced_merge_commit(input))
+    }
+
+    /// Blocking version of [`CodeCommit::delete_approval_rule_template`].
+    pub fn delete_approval_rule_template(
+        &self,
+        input: DeleteApprovalRuleTemplateInput,
+    ) -> Result<DeleteApprovalRuleTemplateOutput, RusotoError<DeleteApprovalRuleTemplateError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_approval_rule_template(input))
+    }
+
+    /// Blocking version of [`CodeCommit::delete_branch`].
+    pub fn delete_branch(
+        &self,
+        input: DeleteBranchInput,
+    ) -> Result<DeleteBranchOutput, RusotoError<DeleteBranchError>> {
+        self.runtime.block_on(self.client.delete_branch(input))
+    }
+
+    /// Blocking version of [`CodeCommit::delete_comment_content`].
+    pub fn delete_comment_content(
+        &self,
+        input: DeleteCommentContentInput,
+    ) -> Result<DeleteCommentContentOutput, RusotoError<DeleteCommentContentError>> {
+        self.runtime
+            .block_on(self.client.delete_comment_content(input))
+    }
+
+    /// Blocking version of [`CodeCommit::delete_file`].
+    pub fn delete_file(
+        &self,
+        input: DeleteFileInput,
+    ) -> Result<DeleteFileOutput, RusotoError<DeleteFileError>> {
+        self.runtime.block_on(self.client.delete_file(input))
+    }
+
+    /// Blocking version of [`CodeCommit::delete_pull_request_approval_rule`].
+    pub fn delete_pull_request_approval_rule(
+        &self,
+        input: DeletePullRequestApprovalRuleInput,
+    ) -> Result<DeletePullRequestApprovalRuleOutput, RusotoError<DeletePullRequestApprovalRuleError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_pull_request_approval_rule(input))
+    }
+
+    /// Blocking version of [`CodeCommit::delete_repository`].
+    pub fn delete_repository(
+        &self,
+        input: DeleteRepositoryInput,
+    ) -> Result<DeleteRepositoryOutput, RusotoError<DeleteRepositoryError>> {
+        self.runtime.block_on(self.client.delete_repository(input))
+    }
+
+    /// Blocking version of [`CodeCommit::describe_merge_conflicts`].
+    pub fn describe_merge_conflicts(
+        &self,
+        input: DescribeMergeConflictsInput,
+    ) -> Result<DescribeMergeConflictsOutput, RusotoError<DescribeMergeConflictsError>> {
+        self.runtime
+            .block_on(self.client.describe_merge_conflicts(input))
+    }
+
+    /// Blocking version of [`CodeCommit::describe_pull_request_events`].
+    pub fn describe_pull_request_events(
+        &self,
+        input: DescribePullRequestEventsInput,
+    ) -> Result<DescribePullRequestEventsOutput, RusotoError<DescribePullRequestEventsError>> {
+        self.runtime
+            .block_on(self.client.describe_pull_request_events(input))
+    }
+
+    /// Blocking version of [`CodeCommit::disassociate_approval_rule_template_from_repository`].
+    pub fn disassociate_approval_rule_template_from_repository(
+        &self,
+        input: DisassociateApprovalRuleTemplateFromRepositoryInput,
+    ) -> Result<(), RusotoError<DisassociateApprovalRuleTemplateFromRepositoryError>> {
+        self.runtime.block_on(
+            self.client
+                .disassociate_approval_rule_template_from_repository(input),
+        )
+    }
+
+    /// Blocking version of [`CodeCommit::evaluate_pull_request_approval_rules`].
+    pub fn evaluate_pull_request_approval_rules(
+        &self,
+        input: EvaluatePullRequestApprovalRulesInput,
+    ) -> Result<
+        EvaluatePullRequestApprovalRulesOutput,
+        RusotoError<EvaluatePullRequestApprovalRulesError>,
+    > {
+        self.runtime
+            .block_on(self.client.evaluate_pull_request_approval_rules(input))
+    }
+
+    /// Blocking version of [`CodeCommit::get_approval_rule_template`].
+    pub fn get_approval_rule_template(
+        &self,
+        input: GetApprovalRuleTemplateInput,
+    ) -> Result<GetApprovalRuleTemplateOutput, RusotoError<GetApprovalRuleTemplateError>> {
+        self.runtime
+            .block_on(self.client.get_approval_rule_template(input))
+    }
+
+    /// Blocking version of [`CodeCommit::get_blob`].
+    pub fn get_blob(
+        &self,
+        input: GetBlobInput,
+    ) -> Result<GetBlobOutput, RusotoError<GetBlobError>> {
+        self.runtime.block_on(self.client.get_blob(input))
+    }
+
+    /// Blocking version of [`CodeCommit::get_branch`].
+    pub fn get_branch(
+        &self,
+        input: GetBranchInput,
+    ) -> Result<GetBranchOutput, RusotoError<GetBranchError>> {
+        self.runtime.block_on(self.client.get_branch(input))
+    }
+
+    /// Blocking version of [`CodeCommit::get_comment`].
+    pub fn get_comment(
+        &self,
+        input: GetCommentInput,
+    ) -> Result<GetCommentOutput, RusotoError<GetCommentError>> {
+        self.runtime.block_on(self.client.get_comment(input))
+    }
+
+    /// Blocking version of [`CodeCommit::get_comment_reactions`].
+    pub fn get_comment_reactions(
+        &self,
+        input: GetCommentReactionsInput,
+    ) -> Result<GetCommentReactionsOutput, RusotoError<GetCommentReactionsError>> {
+        self.runtime
+            .block_on(self.client.get_comment_reactions(input))
+    }
+
+    /// Blocking version of [`CodeCommit::get_comments_for_compared_commit`].
+    pub fn get_comments_for_compared_commit(
+        &self,
+        input: GetCommentsForComparedCommitInput,
+    ) -> Result<GetCommentsForComparedCommitOutput, RusotoError<GetCommentsForComparedCommitError>>
+    {
+        self.runtime
+            .block_on(self.client.get_comments_for_compared_commit(input))
+    }
+
+    /// Blocking version of [`CodeCommit::get_comments_for_pull_request`].
+    pub fn get_comments_for_pull_request(
+        &self,
+        input: GetCommentsForPullRequestInput,
+    ) -> Result<GetCommentsForPullRequestOutput, RusotoError<GetCommentsForPullRequestError>> {
+        self.runtime
+            .block_on(self.client.get_comments_for_pull_request(input))
+    }
+
+    /// Blocking version of [`CodeCommit::get_commit`].
+    pub fn get_commit(
+        &self,
+        input: GetCommitInput,
+    ) -> Result<GetCommitOutput, RusotoError<GetCommitError>> {
+        self.runtime.block_on(self.client.get_commit(input))
+    }
+
+    /// Blocking version of [`CodeCommit::get_differences`].
+    pub fn get_differences(
+        &self,
+        input: GetDifferencesInput,
+    ) -> Result<GetDifferencesOutput, RusotoError<GetDifferencesError>> {
+        self.runtime.block_on(self.client.get_differences(input))
+    }
+
+    /// Blocking version of [`CodeCommit::get_file`].
+    pub fn get_file(
+        &self,
+        input: GetFileInput,
+    ) -> Result<GetFileOutput, RusotoError<GetFileError>> {
+        self.runtime.block_on(self.client.get_file(input))
+    }
+
+    /// Blocking version of [`CodeCommit::get_folder`].
+    pub fn get_folder(
+        &self,
+        input: GetFolderInput,
+    ) -> Result<GetFolderOutput, RusotoError<GetFolderError>> {
+        self.runtime.block_on(self.client.get_folder(input))
+    }
+
+    /// Blocking version of [`CodeCommit::get_merge_commit`].
+    pub fn get_merge_commit(
+        &self,
+        input: GetMergeCommitInput,
+    ) -> Result<GetMergeCommitOutput, RusotoError<GetMergeCommitError>> {
+        self.runtime.block_on(self.client.get_merge_commit(input))
+    }
+
+    /// Blocking version of [`CodeCommit::get_merge_conflicts`].
+    pub fn get_merge_conflicts(
+        &self,
+        input: GetMergeConflictsInput,
+    ) -> Result<GetMergeConflictsOutput, RusotoError<GetMergeConflictsError>> {
+        self.runtime
+            .block_on(self.client.get_merge_conflicts(input))
+    }
+
+    /// Blocking version of [`CodeCommit::get_merge_options`].
+    pub fn get_merge_options(
+        &self,
+        input: GetMergeOptionsInput,
+    ) -> Result<GetMergeOptionsOutput, RusotoError<GetMergeOptionsError>> {
+        self.runtime.block_on(self.client.get_merge_options(input))
+    }
+
+    /// Blocking version of [`CodeCommit::get_pull_request`].
+    pub fn get_pull_request(
+        &self,
+        input: GetPullRequestInput,
+    ) -> Result<GetPullRequestOutput, RusotoError<GetPullRequestError>> {
+        self.runtime.block_on(self.client.get_pull_request(input))
+    }
+
+    /// Blocking version of [`CodeCommit::get_pull_request_approval_states`].
+    pub fn get_pull_request_approval_states(
+        &self,
+        input: GetPullRequestApprovalStatesInput,
+    ) -> Result<GetPullRequestApprovalStatesOutput, RusotoError<GetPullRequestApprovalStatesError>>
+    {
+        self.runtime
+            .block_on(self.client.get_pull_request_approval_states(input))
+    }
+
+    /// Blocking version of [`CodeCommit::get_pull_request_override_state`].
+    pub fn get_pull_request_override_state(
+        &self,
+        input: GetPullRequestOverrideStateInput,
+    ) -> Result<GetPullRequestOverrideStateOutput, RusotoError<GetPullRequestOverrideStateError>>
+    {
+        self.runtime
+            .block_on(self.client.get_pull_request_override_state(input))
+    }
+
+    /// Blocking version of [`CodeCommit::get_repository`].
+    pub fn get_repository(
+        &self,
+        input: GetRepositoryInput,
+    ) -> Result<GetRepositoryOutput, RusotoError<GetRepositoryError>> {
+        self.runtime.block_on(self.client.get_repository(input))
+    }
+
+    /// Blocking version of [`CodeCommit::get_repository_triggers`].
+    pub fn get_repository_triggers(
+        &self,
+        input: GetRepositoryTriggersInput,
+    ) -> Result<GetRepositoryTriggersOutput, RusotoError<GetRepositoryTriggersError>> {
+        self.runtime
+            .block_on(self.client.get_repository_triggers(input))
+    }
+
+    /// Blocking version of [`CodeCommit::list_approval_rule_templates`].
+    pub fn list_approval_rule_templates(
+        &self,
+        input: ListApprovalRuleTemplatesInput,
+    ) -> Result<ListApprovalRuleTemplatesOutput, RusotoError<ListApprovalRuleTemplatesError>> {
+        self.runtime
+            .block_on(self.client.list_approval_rule_templates(input))
+    }
+
+    /// Blocking version of [`CodeCommit::list_associated_approval_rule_templates_for_repository`].
+    pub fn list_associated_approval_rule_templates_for_repository(
+        &self,
+        input: ListAssociatedApprovalRuleTemplatesForRepositoryInput,
+    ) -> Result<
+        ListAssociatedApprovalRuleTemplatesForRepositoryOutput,
+        RusotoError<ListAssociatedApprovalRuleTemplatesForRepositoryError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .list_associated_approval_rule_templates_for_repository(input),
+        )
+    }
+
+    /// Blocking version of [`CodeCommit::list_branches`].
+    pub fn list_branches(
+        &self,
+        input: ListBranchesInput,
+    ) -> Result<ListBranchesOutput, RusotoError<ListBranchesError>> {
+        self.runtime.block_on(self.client.list_branches(input))
+    }
+
+    /// Blocking version of [`CodeCommit::list_pull_requests`].
+    pub fn list_pull_requests(
+        &self,
+        input: ListPullRequestsInput,
+    ) -> Result<ListPullRequestsOutput, RusotoError<ListPullRequestsError>> {
+        self.runtime.block_on(self.client.list_pull_requests(input))
+    }
+
+    /// Blocking version of [`CodeCommit::list_repositories`].
+    pub fn list_repositories(
+        &self,
+        input: ListRepositoriesInput,
+    ) -> Result<ListRepositoriesOutput, RusotoError<ListRepositoriesError>> {
+        self.runtime.block_on(self.client.list_repositories(input))
+    }
+
+    /// Blocking version of [`CodeCommit::list_repositories_for_approval_rule_template`].
+    pub fn list_repositories_for_approval_rule_template(
+        &self,
+        input: ListRepositoriesForApprovalRuleTemplateInput,
+    ) -> Result<
+        ListRepositoriesForApprovalRuleTemplateOutput,
+        RusotoError<ListRepositoriesForApprovalRuleTemplateError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .list_repositories_for_approval_rule_template(input),
+        )
+    }
+
+    /// Blocking version of [`CodeCommit::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`CodeCommit::merge_branches_by_fast_forward`].
+    pub fn merge_branches_by_fast_forward(
+        &self,
+        input: MergeBranchesByFastForwardInput,
+    ) -> Result<MergeBranchesByFastForwardOutput, RusotoError<MergeBranchesByFastForwardError>>
+    {
+        self.runtime
+            .block_on(self.client.merge_branches_by_fast_forward(input))
+    }
+
+    /// Blocking version of [`CodeCommit::merge_branches_by_squash`].
+    pub fn merge_branches_by_squash(
+        &self,
+        input: MergeBranchesBySquashInput,
+    ) -> Result<MergeBranchesBySquashOutput, RusotoError<MergeBranchesBySquashError>> {
+        self.runtime
+            .block_on(self.client.merge_branches_by_squash(input))
+    }
+
+    /// Blocking version of [`CodeCommit::merge_branches_by_three_way`].
+    pub fn merge_branches_by_three_way(
+        &self,
+        input: MergeBranchesByThreeWayInput,
+    ) -> Result<MergeBranchesByThreeWayOutput, RusotoError<MergeBranchesByThreeWayError>> {
+        self.runtime
+            .block_on(self.client.merge_branches_by_three_way(input))
+    }
+
+    /// Blocking version of [`CodeCommit::merge_pull_request_by_fast_forward`].
+    pub fn merge_pull_request_by_fast_forward(
+        &self,
+        input: MergePullRequestByFastForwardInput,
+    ) -> Result<MergePullRequestByFastForwardOutput, RusotoError<MergePullRequestByFastForwardError>>
+    {
+        self.runtime
+            .block_on(self.client.merge_pull_request_by_fast_forward(input))
+    }
+
+    /// Blocking version of [`CodeCommit::merge_pull_request_by_squash`].
+    pub fn merge_pull_request_by_squash(
+        &self,
+        input: MergePullRequestBySquashInput,
+    ) -> Result<MergePullRequestBySquashOutput, RusotoError<MergePullRequestBySquashError>> {
+        self.runtime
+            .block_on(self.client.merge_pull_request_by_squash(input))
+    }
+
+    /// Blocking version of [`CodeCommit::merge_pull_request_by_three_way`].
+    pub fn merge_pull_request_by_three_way(
+        &self,
+        input: MergePullRequestByThreeWayInput,
+    ) -> Result<MergePullRequestByThreeWayOutput, RusotoError<MergePullRequestByThreeWayError>>
+    {
+        self.runtime
+            .block_on(self.client.merge_pull_request_by_three_way(input))
+    }
+
+    /// Blocking version of [`CodeCommit::override_pull_request_approval_rules`].
+    pub fn override_pull_request_approval_rules(
+        &self,
+        input: OverridePullRequestApprovalRulesInput,
+    ) -> Result<(), RusotoError<OverridePullRequestApprovalRulesError>> {
+        self.runtime
+            .block_on(self.client.override_pull_request_approval_rules(input))
+    }
+
+    /// Blocking version of [`CodeCommit::post_comment_for_compared_commit`].
+    pub fn post_comment_for_compared_commit(
+        &self,
+        input: PostCommentForComparedCommitInput,
+    ) -> Result<PostCommentForComparedCommitOutput, RusotoError<PostCommentForComparedCommitError>>
+    {
+        self.runtime
+            .block_on(self.client.post_comment_for_compared_commit(input))
+    }
+
+    /// Blocking version of [`CodeCommit::post_comment_for_pull_request`].
+    pub fn post_comment_for_pull_request(
+        &self,
+        input: PostCommentForPullRequestInput,
+    ) -> Result<PostCommentForPullRequestOutput, RusotoError<PostCommentForPullRequestError>> {
+        self.runtime
+            .block_on(self.client.post_comment_for_pull_request(input))
+    }
+
+    /// Blocking version of [`CodeCommit::post_comment_reply`].
+    pub fn post_comment_reply(
+        &self,
+        input: PostCommentReplyInput,
+    ) -> Result<PostCommentReplyOutput, RusotoError<PostCommentReplyError>> {
+        self.runtime.block_on(self.client.post_comment_reply(input))
+    }
+
+    /// Blocking version of [`CodeCommit::put_comment_reaction`].
+    pub fn put_comment_reaction(
+        &self,
+        input: PutCommentReactionInput,
+    ) -> Result<(), RusotoError<PutCommentReactionError>> {
+        self.runtime
+            .block_on(self.client.put_comment_reaction(input))
+    }
+
+    /// Blocking version of [`CodeCommit::put_file`].
+    pub fn put_file(
+        &self,
+        input: PutFileInput,
+    ) -> Result<PutFileOutput, RusotoError<PutFileError>> {
+        self.runtime.block_on(self.client.put_file(input))
+    }
+
+    /// Blocking version of [`CodeCommit::put_repository_triggers`].
+    pub fn put_repository_triggers(
+        &self,
+        input: PutRepositoryTriggersInput,
+    ) -> Result<PutRepositoryTriggersOutput, RusotoError<PutRepositoryTriggersError>> {
+        self.runtime
+            .block_on(self.client.put_repository_triggers(input))
+    }
+
+    /// Blocking version of [`CodeCommit::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`CodeCommit::test_repository_triggers`].
+    pub fn test_repository_triggers(
+        &self,
+        input: TestRepositoryTriggersInput,
+    ) -> Result<TestRepositoryTriggersOutput, RusotoError<TestRepositoryTriggersError>> {
+        self.runtime
+            .block_on(self.client.test_repository_triggers(input))
+    }
+
+    /// Blocking version of [`CodeCommit::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`CodeCommit::update_approval_rule_template_content`].
+    pub fn update_approval_rule_template_content(
+        &self,
+        input: UpdateApprovalRuleTemplateContentInput,
+    ) -> Result<
+        UpdateApprovalRuleTemplateContentOutput,
+        RusotoError<UpdateApprovalRuleTemplateContentError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_approval_rule_template_content(input))
+    }
+
+    /// Blocking version of [`CodeCommit::update_approval_rule_template_description`].
+    pub fn update_approval_rule_template_description(
+        &self,
+        input: UpdateApprovalRuleTemplateDescriptionInput,
+    ) -> Result<
+        UpdateApprovalRuleTemplateDescriptionOutput,
+        RusotoError<UpdateApprovalRuleTemplateDescriptionError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_approval_rule_template_description(input))
+    }
+
+    /// Blocking version of [`CodeCommit::update_approval_rule_template_name`].
+    pub fn update_approval_rule_template_name(
+        &self,
+        input: UpdateApprovalRuleTemplateNameInput,
+    ) -> Result<
+        UpdateApprovalRuleTemplateNameOutput,
+        RusotoError<UpdateApprovalRuleTemplateNameError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_approval_rule_template_name(input))
+    }
+
+    /// Blocking version of [`CodeCommit::update_comment`].
+    pub fn update_comment(
+        &self,
+        input: UpdateCommentInput,
+    ) -> Result<UpdateCommentOutput, RusotoError<UpdateCommentError>> {
+        self.runtime.block_on(self.client.update_comment(input))
+    }
+
+    /// Blocking version of [`CodeCommit::update_default_branch`].
+    pub fn update_default_branch(
+        &self,
+        input: UpdateDefaultBranchInput,
+    ) -> Result<(), RusotoError<UpdateDefaultBranchError>> {
+        self.runtime
+            .block_on(self.client.update_default_branch(input))
+    }
+
+    /// Blocking version of [`CodeCommit::update_pull_request_approval_rule_content`].
+    pub fn update_pull_request_approval_rule_content(
+        &self,
+        input: UpdatePullRequestApprovalRuleContentInput,
+    ) -> Result<
+        UpdatePullRequestApprovalRuleContentOutput,
+        RusotoError<UpdatePullRequestApprovalRuleContentError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_pull_request_approval_rule_content(input))
+    }
+
+    /// Blocking version of [`CodeCommit::update_pull_request_approval_state`].
+    pub fn update_pull_request_approval_state(
+        &self,
+        input: UpdatePullRequestApprovalStateInput,
+    ) -> Result<(), RusotoError<UpdatePullRequestApprovalStateError>> {
+        self.runtime
+            .block_on(self.client.update_pull_request_approval_state(input))
+    }
+
+    /// Blocking version of [`CodeCommit::update_pull_request_description`].
+    pub fn update_pull_request_description(
+        &self,
+        input: UpdatePullRequestDescriptionInput,
+    ) -> Result<UpdatePullRequestDescriptionOutput, RusotoError<UpdatePullRequestDescriptionError>>
+    {
+        self.runtime
+            .block_on(self.client.update_pull_request_description(input))
+    }
+
+    /// Blocking version of [`CodeCommit::update_pull_request_status`].
+    pub fn update_pull_request_status(
+        &self,
+        input: UpdatePullRequestStatusInput,
+    ) -> Result<UpdatePullRequestStatusOutput, RusotoError<UpdatePullRequestStatusError>> {
+        self.runtime
+            .block_on(self.client.update_pull_request_status(input))
+    }
+
+    /// Blocking version of [`CodeCommit::update_pull_request_title`].
+    pub fn update_pull_request_title(
+        &self,
+        input: UpdatePullRequestTitleInput,
+    ) -> Result<UpdatePullRequestTitleOutput, RusotoError<UpdatePullRequestTitleError>> {
+        self.runtime
+            .block_on(self.client.update_pull_request_title(input))
+    }
+
+    /// Blocking version of [`CodeCommit::update_repository_description`].
+    pub fn update_repository_description(
+        &self,
+        input: UpdateRepositoryDescriptionInput,
+    ) -> Result<(), RusotoError<UpdateRepositoryDescriptionError>> {
+        self.runtime
+            .block_on(self.client.update_repository_description(input))
+    }
+
+    /// Blocking version of [`CodeCommit::update_repository_name`].
+    pub fn update_repository_name(
+        &self,
+        input: UpdateRepositoryNameInput,
+    ) -> Result<(), RusotoError<UpdateRepositoryNameError>> {
+        self.runtime
+            .block_on(self.client.update_repository_name(input))
+    }
+}
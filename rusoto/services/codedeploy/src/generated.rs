@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -2438,41 +2439,62 @@ impl AddTagsToOnPremisesInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InstanceLimitExceededException" => {
-                    return RusotoError::Service(
-                        AddTagsToOnPremisesInstancesError::InstanceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsToOnPremisesInstancesError::InstanceLimitExceeded,
                     )
                 }
                 "InstanceNameRequiredException" => {
-                    return RusotoError::Service(
-                        AddTagsToOnPremisesInstancesError::InstanceNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsToOnPremisesInstancesError::InstanceNameRequired,
                     )
                 }
                 "InstanceNotRegisteredException" => {
-                    return RusotoError::Service(
-                        AddTagsToOnPremisesInstancesError::InstanceNotRegistered(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsToOnPremisesInstancesError::InstanceNotRegistered,
                     )
                 }
                 "InvalidInstanceNameException" => {
-                    return RusotoError::Service(
-                        AddTagsToOnPremisesInstancesError::InvalidInstanceName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsToOnPremisesInstancesError::InvalidInstanceName,
                     )
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(AddTagsToOnPremisesInstancesError::InvalidTag(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddTagsToOnPremisesInstancesError::InvalidTag,
+                    )
                 }
                 "TagLimitExceededException" => {
-                    return RusotoError::Service(
-                        AddTagsToOnPremisesInstancesError::TagLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsToOnPremisesInstancesError::TagLimitExceeded,
                     )
                 }
                 "TagRequiredException" => {
-                    return RusotoError::Service(AddTagsToOnPremisesInstancesError::TagRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddTagsToOnPremisesInstancesError::TagRequired,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2528,36 +2550,54 @@ impl BatchGetApplicationRevisionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(
-                        BatchGetApplicationRevisionsError::ApplicationDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetApplicationRevisionsError::ApplicationDoesNotExist,
                     )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(
-                        BatchGetApplicationRevisionsError::ApplicationNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetApplicationRevisionsError::ApplicationNameRequired,
                     )
                 }
                 "BatchLimitExceededException" => {
-                    return RusotoError::Service(
-                        BatchGetApplicationRevisionsError::BatchLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetApplicationRevisionsError::BatchLimitExceeded,
                     )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(
-                        BatchGetApplicationRevisionsError::InvalidApplicationName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetApplicationRevisionsError::InvalidApplicationName,
                     )
                 }
                 "InvalidRevisionException" => {
-                    return RusotoError::Service(
-                        BatchGetApplicationRevisionsError::InvalidRevision(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetApplicationRevisionsError::InvalidRevision,
                     )
                 }
                 "RevisionRequiredException" => {
-                    return RusotoError::Service(
-                        BatchGetApplicationRevisionsError::RevisionRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetApplicationRevisionsError::RevisionRequired,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2606,26 +2646,38 @@ impl BatchGetApplicationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(
-                        BatchGetApplicationsError::ApplicationDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetApplicationsError::ApplicationDoesNotExist,
                     )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(
-                        BatchGetApplicationsError::ApplicationNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetApplicationsError::ApplicationNameRequired,
                     )
                 }
                 "BatchLimitExceededException" => {
-                    return RusotoError::Service(BatchGetApplicationsError::BatchLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchGetApplicationsError::BatchLimitExceeded,
+                    )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(BatchGetApplicationsError::InvalidApplicationName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchGetApplicationsError::InvalidApplicationName,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2668,41 +2720,62 @@ impl BatchGetDeploymentGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(
-                        BatchGetDeploymentGroupsError::ApplicationDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetDeploymentGroupsError::ApplicationDoesNotExist,
                     )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(
-                        BatchGetDeploymentGroupsError::ApplicationNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetDeploymentGroupsError::ApplicationNameRequired,
                     )
                 }
                 "BatchLimitExceededException" => {
-                    return RusotoError::Service(BatchGetDeploymentGroupsError::BatchLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchGetDeploymentGroupsError::BatchLimitExceeded,
+                    )
                 }
                 "DeploymentConfigDoesNotExistException" => {
-                    return RusotoError::Service(
-                        BatchGetDeploymentGroupsError::DeploymentConfigDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetDeploymentGroupsError::DeploymentConfigDoesNotExist,
                     )
                 }
                 "DeploymentGroupNameRequiredException" => {
-                    return RusotoError::Service(
-                        BatchGetDeploymentGroupsError::DeploymentGroupNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetDeploymentGroupsError::DeploymentGroupNameRequired,
                     )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(
-                        BatchGetDeploymentGroupsError::InvalidApplicationName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetDeploymentGroupsError::InvalidApplicationName,
                     )
                 }
                 "InvalidDeploymentGroupNameException" => {
-                    return RusotoError::Service(
-                        BatchGetDeploymentGroupsError::InvalidDeploymentGroupName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetDeploymentGroupsError::InvalidDeploymentGroupName,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2762,41 +2835,62 @@ impl BatchGetDeploymentInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BatchLimitExceededException" => {
-                    return RusotoError::Service(
-                        BatchGetDeploymentInstancesError::BatchLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetDeploymentInstancesError::BatchLimitExceeded,
                     )
                 }
                 "DeploymentDoesNotExistException" => {
-                    return RusotoError::Service(
-                        BatchGetDeploymentInstancesError::DeploymentDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetDeploymentInstancesError::DeploymentDoesNotExist,
                     )
                 }
                 "DeploymentIdRequiredException" => {
-                    return RusotoError::Service(
-                        BatchGetDeploymentInstancesError::DeploymentIdRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetDeploymentInstancesError::DeploymentIdRequired,
                     )
                 }
                 "InstanceIdRequiredException" => {
-                    return RusotoError::Service(
-                        BatchGetDeploymentInstancesError::InstanceIdRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetDeploymentInstancesError::InstanceIdRequired,
                     )
                 }
                 "InvalidComputePlatformException" => {
-                    return RusotoError::Service(
-                        BatchGetDeploymentInstancesError::InvalidComputePlatform(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetDeploymentInstancesError::InvalidComputePlatform,
                     )
                 }
                 "InvalidDeploymentIdException" => {
-                    return RusotoError::Service(
-                        BatchGetDeploymentInstancesError::InvalidDeploymentId(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetDeploymentInstancesError::InvalidDeploymentId,
                     )
                 }
                 "InvalidInstanceNameException" => {
-                    return RusotoError::Service(
-                        BatchGetDeploymentInstancesError::InvalidInstanceName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetDeploymentInstancesError::InvalidInstanceName,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2860,51 +2954,78 @@ impl BatchGetDeploymentTargetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeploymentDoesNotExistException" => {
-                    return RusotoError::Service(
-                        BatchGetDeploymentTargetsError::DeploymentDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetDeploymentTargetsError::DeploymentDoesNotExist,
                     )
                 }
                 "DeploymentIdRequiredException" => {
-                    return RusotoError::Service(
-                        BatchGetDeploymentTargetsError::DeploymentIdRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetDeploymentTargetsError::DeploymentIdRequired,
                     )
                 }
                 "DeploymentNotStartedException" => {
-                    return RusotoError::Service(
-                        BatchGetDeploymentTargetsError::DeploymentNotStarted(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetDeploymentTargetsError::DeploymentNotStarted,
                     )
                 }
                 "DeploymentTargetDoesNotExistException" => {
-                    return RusotoError::Service(
-                        BatchGetDeploymentTargetsError::DeploymentTargetDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetDeploymentTargetsError::DeploymentTargetDoesNotExist,
                     )
                 }
                 "DeploymentTargetIdRequiredException" => {
-                    return RusotoError::Service(
-                        BatchGetDeploymentTargetsError::DeploymentTargetIdRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetDeploymentTargetsError::DeploymentTargetIdRequired,
                     )
                 }
                 "DeploymentTargetListSizeExceededException" => {
-                    return RusotoError::Service(
-                        BatchGetDeploymentTargetsError::DeploymentTargetListSizeExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetDeploymentTargetsError::DeploymentTargetListSizeExceeded,
                     )
                 }
                 "InstanceDoesNotExistException" => {
-                    return RusotoError::Service(
-                        BatchGetDeploymentTargetsError::InstanceDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetDeploymentTargetsError::InstanceDoesNotExist,
                     )
                 }
                 "InvalidDeploymentIdException" => {
-                    return RusotoError::Service(
-                        BatchGetDeploymentTargetsError::InvalidDeploymentId(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetDeploymentTargetsError::InvalidDeploymentId,
                     )
                 }
                 "InvalidDeploymentTargetIdException" => {
-                    return RusotoError::Service(
-                        BatchGetDeploymentTargetsError::InvalidDeploymentTargetId(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetDeploymentTargetsError::InvalidDeploymentTargetId,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2962,21 +3083,30 @@ impl BatchGetDeploymentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BatchLimitExceededException" => {
-                    return RusotoError::Service(BatchGetDeploymentsError::BatchLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchGetDeploymentsError::BatchLimitExceeded,
+                    )
                 }
                 "DeploymentIdRequiredException" => {
-                    return RusotoError::Service(BatchGetDeploymentsError::DeploymentIdRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchGetDeploymentsError::DeploymentIdRequired,
+                    )
                 }
                 "InvalidDeploymentIdException" => {
-                    return RusotoError::Service(BatchGetDeploymentsError::InvalidDeploymentId(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchGetDeploymentsError::InvalidDeploymentId,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3012,21 +3142,30 @@ impl BatchGetOnPremisesInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BatchLimitExceededException" => {
-                    return RusotoError::Service(
-                        BatchGetOnPremisesInstancesError::BatchLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetOnPremisesInstancesError::BatchLimitExceeded,
                     )
                 }
                 "InstanceNameRequiredException" => {
-                    return RusotoError::Service(
-                        BatchGetOnPremisesInstancesError::InstanceNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetOnPremisesInstancesError::InstanceNameRequired,
                     )
                 }
                 "InvalidInstanceNameException" => {
-                    return RusotoError::Service(
-                        BatchGetOnPremisesInstancesError::InvalidInstanceName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetOnPremisesInstancesError::InvalidInstanceName,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3076,46 +3215,70 @@ impl ContinueDeploymentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeploymentAlreadyCompletedException" => {
-                    return RusotoError::Service(
-                        ContinueDeploymentError::DeploymentAlreadyCompleted(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ContinueDeploymentError::DeploymentAlreadyCompleted,
                     )
                 }
                 "DeploymentDoesNotExistException" => {
-                    return RusotoError::Service(ContinueDeploymentError::DeploymentDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ContinueDeploymentError::DeploymentDoesNotExist,
+                    )
                 }
                 "DeploymentIdRequiredException" => {
-                    return RusotoError::Service(ContinueDeploymentError::DeploymentIdRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ContinueDeploymentError::DeploymentIdRequired,
+                    )
                 }
                 "DeploymentIsNotInReadyStateException" => {
-                    return RusotoError::Service(
-                        ContinueDeploymentError::DeploymentIsNotInReadyState(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ContinueDeploymentError::DeploymentIsNotInReadyState,
                     )
                 }
                 "InvalidDeploymentIdException" => {
-                    return RusotoError::Service(ContinueDeploymentError::InvalidDeploymentId(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ContinueDeploymentError::InvalidDeploymentId,
+                    )
                 }
                 "InvalidDeploymentStatusException" => {
-                    return RusotoError::Service(ContinueDeploymentError::InvalidDeploymentStatus(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ContinueDeploymentError::InvalidDeploymentStatus,
+                    )
                 }
                 "InvalidDeploymentWaitTypeException" => {
-                    return RusotoError::Service(
-                        ContinueDeploymentError::InvalidDeploymentWaitType(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ContinueDeploymentError::InvalidDeploymentWaitType,
                     )
                 }
                 "UnsupportedActionForDeploymentTypeException" => {
-                    return RusotoError::Service(
-                        ContinueDeploymentError::UnsupportedActionForDeploymentType(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ContinueDeploymentError::UnsupportedActionForDeploymentType,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3166,34 +3329,54 @@ impl CreateApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationAlreadyExistsException" => {
-                    return RusotoError::Service(CreateApplicationError::ApplicationAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateApplicationError::ApplicationAlreadyExists,
+                    )
                 }
                 "ApplicationLimitExceededException" => {
-                    return RusotoError::Service(CreateApplicationError::ApplicationLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateApplicationError::ApplicationLimitExceeded,
+                    )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(CreateApplicationError::ApplicationNameRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateApplicationError::ApplicationNameRequired,
+                    )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(CreateApplicationError::InvalidApplicationName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateApplicationError::InvalidApplicationName,
+                    )
                 }
                 "InvalidComputePlatformException" => {
-                    return RusotoError::Service(CreateApplicationError::InvalidComputePlatform(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateApplicationError::InvalidComputePlatform,
+                    )
                 }
                 "InvalidTagsToAddException" => {
-                    return RusotoError::Service(CreateApplicationError::InvalidTagsToAdd(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApplicationError::InvalidTagsToAdd,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3272,116 +3455,198 @@ impl CreateDeploymentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(CreateDeploymentError::ApplicationDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDeploymentError::ApplicationDoesNotExist,
+                    )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(CreateDeploymentError::ApplicationNameRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDeploymentError::ApplicationNameRequired,
+                    )
                 }
                 "DeploymentConfigDoesNotExistException" => {
-                    return RusotoError::Service(
-                        CreateDeploymentError::DeploymentConfigDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentError::DeploymentConfigDoesNotExist,
                     )
                 }
                 "DeploymentGroupDoesNotExistException" => {
-                    return RusotoError::Service(
-                        CreateDeploymentError::DeploymentGroupDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentError::DeploymentGroupDoesNotExist,
                     )
                 }
                 "DeploymentGroupNameRequiredException" => {
-                    return RusotoError::Service(
-                        CreateDeploymentError::DeploymentGroupNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentError::DeploymentGroupNameRequired,
                     )
                 }
                 "DeploymentLimitExceededException" => {
-                    return RusotoError::Service(CreateDeploymentError::DeploymentLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDeploymentError::DeploymentLimitExceeded,
+                    )
                 }
                 "DescriptionTooLongException" => {
-                    return RusotoError::Service(CreateDeploymentError::DescriptionTooLong(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentError::DescriptionTooLong,
+                    )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(CreateDeploymentError::InvalidApplicationName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDeploymentError::InvalidApplicationName,
+                    )
                 }
                 "InvalidAutoRollbackConfigException" => {
-                    return RusotoError::Service(CreateDeploymentError::InvalidAutoRollbackConfig(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDeploymentError::InvalidAutoRollbackConfig,
+                    )
                 }
                 "InvalidAutoScalingGroupException" => {
-                    return RusotoError::Service(CreateDeploymentError::InvalidAutoScalingGroup(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDeploymentError::InvalidAutoScalingGroup,
+                    )
                 }
                 "InvalidDeploymentConfigNameException" => {
-                    return RusotoError::Service(
-                        CreateDeploymentError::InvalidDeploymentConfigName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentError::InvalidDeploymentConfigName,
                     )
                 }
                 "InvalidDeploymentGroupNameException" => {
-                    return RusotoError::Service(CreateDeploymentError::InvalidDeploymentGroupName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDeploymentError::InvalidDeploymentGroupName,
+                    )
                 }
                 "InvalidFileExistsBehaviorException" => {
-                    return RusotoError::Service(CreateDeploymentError::InvalidFileExistsBehavior(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDeploymentError::InvalidFileExistsBehavior,
+                    )
                 }
                 "InvalidGitHubAccountTokenException" => {
-                    return RusotoError::Service(CreateDeploymentError::InvalidGitHubAccountToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDeploymentError::InvalidGitHubAccountToken,
+                    )
                 }
                 "InvalidIgnoreApplicationStopFailuresValueException" => {
-                    return RusotoError::Service(
-                        CreateDeploymentError::InvalidIgnoreApplicationStopFailuresValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentError::InvalidIgnoreApplicationStopFailuresValue,
                     )
                 }
                 "InvalidLoadBalancerInfoException" => {
-                    return RusotoError::Service(CreateDeploymentError::InvalidLoadBalancerInfo(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDeploymentError::InvalidLoadBalancerInfo,
+                    )
                 }
                 "InvalidRevisionException" => {
-                    return RusotoError::Service(CreateDeploymentError::InvalidRevision(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentError::InvalidRevision,
+                    )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(CreateDeploymentError::InvalidRole(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentError::InvalidRole,
+                    )
                 }
                 "InvalidTargetInstancesException" => {
-                    return RusotoError::Service(CreateDeploymentError::InvalidTargetInstances(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDeploymentError::InvalidTargetInstances,
+                    )
                 }
                 "InvalidTrafficRoutingConfigurationException" => {
-                    return RusotoError::Service(
-                        CreateDeploymentError::InvalidTrafficRoutingConfiguration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentError::InvalidTrafficRoutingConfiguration,
                     )
                 }
                 "InvalidUpdateOutdatedInstancesOnlyValueException" => {
-                    return RusotoError::Service(
-                        CreateDeploymentError::InvalidUpdateOutdatedInstancesOnlyValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentError::InvalidUpdateOutdatedInstancesOnlyValue,
                     )
                 }
                 "RevisionDoesNotExistException" => {
-                    return RusotoError::Service(CreateDeploymentError::RevisionDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDeploymentError::RevisionDoesNotExist,
+                    )
                 }
                 "RevisionRequiredException" => {
-                    return RusotoError::Service(CreateDeploymentError::RevisionRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentError::RevisionRequired,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDeploymentError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3452,41 +3717,62 @@ impl CreateDeploymentConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeploymentConfigAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        CreateDeploymentConfigError::DeploymentConfigAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentConfigError::DeploymentConfigAlreadyExists,
                     )
                 }
                 "DeploymentConfigLimitExceededException" => {
-                    return RusotoError::Service(
-                        CreateDeploymentConfigError::DeploymentConfigLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentConfigError::DeploymentConfigLimitExceeded,
                     )
                 }
                 "DeploymentConfigNameRequiredException" => {
-                    return RusotoError::Service(
-                        CreateDeploymentConfigError::DeploymentConfigNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentConfigError::DeploymentConfigNameRequired,
                     )
                 }
                 "InvalidComputePlatformException" => {
-                    return RusotoError::Service(
-                        CreateDeploymentConfigError::InvalidComputePlatform(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentConfigError::InvalidComputePlatform,
                     )
                 }
                 "InvalidDeploymentConfigNameException" => {
-                    return RusotoError::Service(
-                        CreateDeploymentConfigError::InvalidDeploymentConfigName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentConfigError::InvalidDeploymentConfigName,
                     )
                 }
                 "InvalidMinimumHealthyHostValueException" => {
-                    return RusotoError::Service(
-                        CreateDeploymentConfigError::InvalidMinimumHealthyHostValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentConfigError::InvalidMinimumHealthyHostValue,
                     )
                 }
                 "InvalidTrafficRoutingConfigurationException" => {
-                    return RusotoError::Service(
-                        CreateDeploymentConfigError::InvalidTrafficRoutingConfiguration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentConfigError::InvalidTrafficRoutingConfiguration,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3598,161 +3884,270 @@ impl CreateDeploymentGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlarmsLimitExceededException" => {
-                    return RusotoError::Service(CreateDeploymentGroupError::AlarmsLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDeploymentGroupError::AlarmsLimitExceeded,
+                    )
                 }
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(
-                        CreateDeploymentGroupError::ApplicationDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentGroupError::ApplicationDoesNotExist,
                     )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(
-                        CreateDeploymentGroupError::ApplicationNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentGroupError::ApplicationNameRequired,
                     )
                 }
                 "DeploymentConfigDoesNotExistException" => {
-                    return RusotoError::Service(
-                        CreateDeploymentGroupError::DeploymentConfigDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentGroupError::DeploymentConfigDoesNotExist,
                     )
                 }
                 "DeploymentGroupAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        CreateDeploymentGroupError::DeploymentGroupAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentGroupError::DeploymentGroupAlreadyExists,
                     )
                 }
                 "DeploymentGroupLimitExceededException" => {
-                    return RusotoError::Service(
-                        CreateDeploymentGroupError::DeploymentGroupLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentGroupError::DeploymentGroupLimitExceeded,
                     )
                 }
                 "DeploymentGroupNameRequiredException" => {
-                    return RusotoError::Service(
-                        CreateDeploymentGroupError::DeploymentGroupNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentGroupError::DeploymentGroupNameRequired,
                     )
                 }
                 "ECSServiceMappingLimitExceededException" => {
-                    return RusotoError::Service(
-                        CreateDeploymentGroupError::ECSServiceMappingLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentGroupError::ECSServiceMappingLimitExceeded,
                     )
                 }
                 "InvalidAlarmConfigException" => {
-                    return RusotoError::Service(CreateDeploymentGroupError::InvalidAlarmConfig(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDeploymentGroupError::InvalidAlarmConfig,
+                    )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(
-                        CreateDeploymentGroupError::InvalidApplicationName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentGroupError::InvalidApplicationName,
                     )
                 }
                 "InvalidAutoRollbackConfigException" => {
-                    return RusotoError::Service(
-                        CreateDeploymentGroupError::InvalidAutoRollbackConfig(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentGroupError::InvalidAutoRollbackConfig,
                     )
                 }
                 "InvalidAutoScalingGroupException" => {
-                    return RusotoError::Service(
-                        CreateDeploymentGroupError::InvalidAutoScalingGroup(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentGroupError::InvalidAutoScalingGroup,
                     )
                 }
                 "InvalidBlueGreenDeploymentConfigurationException" => {
-                    return RusotoError::Service(
-                        CreateDeploymentGroupError::InvalidBlueGreenDeploymentConfiguration(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentGroupError::InvalidBlueGreenDeploymentConfiguration,
                     )
                 }
                 "InvalidDeploymentConfigNameException" => {
-                    return RusotoError::Service(
-                        CreateDeploymentGroupError::InvalidDeploymentConfigName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentGroupError::InvalidDeploymentConfigName,
                     )
                 }
                 "InvalidDeploymentGroupNameException" => {
-                    return RusotoError::Service(
-                        CreateDeploymentGroupError::InvalidDeploymentGroupName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentGroupError::InvalidDeploymentGroupName,
                     )
                 }
                 "InvalidDeploymentStyleException" => {
-                    return RusotoError::Service(
-                        CreateDeploymentGroupError::InvalidDeploymentStyle(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentGroupError::InvalidDeploymentStyle,
                     )
                 }
                 "InvalidEC2TagCombinationException" => {
-                    return RusotoError::Service(
-                        CreateDeploymentGroupError::InvalidEC2TagCombination(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentGroupError::InvalidEC2TagCombination,
                     )
                 }
                 "InvalidEC2TagException" => {
-                    return RusotoError::Service(CreateDeploymentGroupError::InvalidEC2Tag(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentGroupError::InvalidEC2Tag,
+                    )
                 }
                 "InvalidECSServiceException" => {
-                    return RusotoError::Service(CreateDeploymentGroupError::InvalidECSService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDeploymentGroupError::InvalidECSService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDeploymentGroupError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentGroupError::InvalidInput,
+                    )
                 }
                 "InvalidLoadBalancerInfoException" => {
-                    return RusotoError::Service(
-                        CreateDeploymentGroupError::InvalidLoadBalancerInfo(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentGroupError::InvalidLoadBalancerInfo,
                     )
                 }
                 "InvalidOnPremisesTagCombinationException" => {
-                    return RusotoError::Service(
-                        CreateDeploymentGroupError::InvalidOnPremisesTagCombination(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentGroupError::InvalidOnPremisesTagCombination,
                     )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(CreateDeploymentGroupError::InvalidRole(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentGroupError::InvalidRole,
+                    )
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(CreateDeploymentGroupError::InvalidTag(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentGroupError::InvalidTag,
+                    )
                 }
                 "InvalidTagsToAddException" => {
-                    return RusotoError::Service(CreateDeploymentGroupError::InvalidTagsToAdd(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDeploymentGroupError::InvalidTagsToAdd,
+                    )
                 }
                 "InvalidTargetGroupPairException" => {
-                    return RusotoError::Service(
-                        CreateDeploymentGroupError::InvalidTargetGroupPair(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentGroupError::InvalidTargetGroupPair,
                     )
                 }
                 "InvalidTrafficRoutingConfigurationException" => {
-                    return RusotoError::Service(
-                        CreateDeploymentGroupError::InvalidTrafficRoutingConfiguration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentGroupError::InvalidTrafficRoutingConfiguration,
                     )
                 }
                 "InvalidTriggerConfigException" => {
-                    return RusotoError::Service(CreateDeploymentGroupError::InvalidTriggerConfig(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDeploymentGroupError::InvalidTriggerConfig,
+                    )
                 }
                 "LifecycleHookLimitExceededException" => {
-                    return RusotoError::Service(
-                        CreateDeploymentGroupError::LifecycleHookLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentGroupError::LifecycleHookLimitExceeded,
                     )
                 }
                 "RoleRequiredException" => {
-                    return RusotoError::Service(CreateDeploymentGroupError::RoleRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentGroupError::RoleRequired,
+                    )
                 }
                 "TagSetListLimitExceededException" => {
-                    return RusotoError::Service(
-                        CreateDeploymentGroupError::TagSetListLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentGroupError::TagSetListLimitExceeded,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDeploymentGroupError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentGroupError::Throttling,
+                    )
                 }
                 "TriggerTargetsLimitExceededException" => {
-                    return RusotoError::Service(
-                        CreateDeploymentGroupError::TriggerTargetsLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentGroupError::TriggerTargetsLimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3854,19 +4249,30 @@ impl DeleteApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(DeleteApplicationError::ApplicationNameRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteApplicationError::ApplicationNameRequired,
+                    )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(DeleteApplicationError::InvalidApplicationName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteApplicationError::InvalidApplicationName,
+                    )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(DeleteApplicationError::InvalidRole(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationError::InvalidRole,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3902,26 +4308,38 @@ impl DeleteDeploymentConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeploymentConfigInUseException" => {
-                    return RusotoError::Service(
-                        DeleteDeploymentConfigError::DeploymentConfigInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDeploymentConfigError::DeploymentConfigInUse,
                     )
                 }
                 "DeploymentConfigNameRequiredException" => {
-                    return RusotoError::Service(
-                        DeleteDeploymentConfigError::DeploymentConfigNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDeploymentConfigError::DeploymentConfigNameRequired,
                     )
                 }
                 "InvalidDeploymentConfigNameException" => {
-                    return RusotoError::Service(
-                        DeleteDeploymentConfigError::InvalidDeploymentConfigName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDeploymentConfigError::InvalidDeploymentConfigName,
                     )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(DeleteDeploymentConfigError::InvalidOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDeploymentConfigError::InvalidOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3964,29 +4382,46 @@ impl DeleteDeploymentGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(
-                        DeleteDeploymentGroupError::ApplicationNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDeploymentGroupError::ApplicationNameRequired,
                     )
                 }
                 "DeploymentGroupNameRequiredException" => {
-                    return RusotoError::Service(
-                        DeleteDeploymentGroupError::DeploymentGroupNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDeploymentGroupError::DeploymentGroupNameRequired,
                     )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(
-                        DeleteDeploymentGroupError::InvalidApplicationName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDeploymentGroupError::InvalidApplicationName,
                     )
                 }
                 "InvalidDeploymentGroupNameException" => {
-                    return RusotoError::Service(
-                        DeleteDeploymentGroupError::InvalidDeploymentGroupName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDeploymentGroupError::InvalidDeploymentGroupName,
                     )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(DeleteDeploymentGroupError::InvalidRole(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDeploymentGroupError::InvalidRole,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4032,31 +4467,46 @@ impl DeleteGitHubAccountTokenError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "GitHubAccountTokenDoesNotExistException" => {
-                    return RusotoError::Service(
-                        DeleteGitHubAccountTokenError::GitHubAccountTokenDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGitHubAccountTokenError::GitHubAccountTokenDoesNotExist,
                     )
                 }
                 "GitHubAccountTokenNameRequiredException" => {
-                    return RusotoError::Service(
-                        DeleteGitHubAccountTokenError::GitHubAccountTokenNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGitHubAccountTokenError::GitHubAccountTokenNameRequired,
                     )
                 }
                 "InvalidGitHubAccountTokenNameException" => {
-                    return RusotoError::Service(
-                        DeleteGitHubAccountTokenError::InvalidGitHubAccountTokenName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGitHubAccountTokenError::InvalidGitHubAccountTokenName,
                     )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(
-                        DeleteGitHubAccountTokenError::OperationNotSupported(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGitHubAccountTokenError::OperationNotSupported,
                     )
                 }
                 "ResourceValidationException" => {
-                    return RusotoError::Service(DeleteGitHubAccountTokenError::ResourceValidation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteGitHubAccountTokenError::ResourceValidation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4094,7 +4544,7 @@ impl DeleteResourcesByExternalIdError {
     ) -> RusotoError<DeleteResourcesByExternalIdError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4124,16 +4574,22 @@ impl DeregisterOnPremisesInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InstanceNameRequiredException" => {
-                    return RusotoError::Service(
-                        DeregisterOnPremisesInstanceError::InstanceNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterOnPremisesInstanceError::InstanceNameRequired,
                     )
                 }
                 "InvalidInstanceNameException" => {
-                    return RusotoError::Service(
-                        DeregisterOnPremisesInstanceError::InvalidInstanceName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterOnPremisesInstanceError::InvalidInstanceName,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4170,21 +4626,30 @@ impl GetApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(GetApplicationError::ApplicationDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetApplicationError::ApplicationDoesNotExist,
+                    )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(GetApplicationError::ApplicationNameRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetApplicationError::ApplicationNameRequired,
+                    )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(GetApplicationError::InvalidApplicationName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetApplicationError::InvalidApplicationName,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4224,36 +4689,54 @@ impl GetApplicationRevisionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(
-                        GetApplicationRevisionError::ApplicationDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetApplicationRevisionError::ApplicationDoesNotExist,
                     )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(
-                        GetApplicationRevisionError::ApplicationNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetApplicationRevisionError::ApplicationNameRequired,
                     )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(
-                        GetApplicationRevisionError::InvalidApplicationName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetApplicationRevisionError::InvalidApplicationName,
                     )
                 }
                 "InvalidRevisionException" => {
-                    return RusotoError::Service(GetApplicationRevisionError::InvalidRevision(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetApplicationRevisionError::InvalidRevision,
+                    )
                 }
                 "RevisionDoesNotExistException" => {
-                    return RusotoError::Service(GetApplicationRevisionError::RevisionDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetApplicationRevisionError::RevisionDoesNotExist,
+                    )
                 }
                 "RevisionRequiredException" => {
-                    return RusotoError::Service(GetApplicationRevisionError::RevisionRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetApplicationRevisionError::RevisionRequired,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4296,17 +4779,30 @@ impl GetDeploymentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeploymentDoesNotExistException" => {
-                    return RusotoError::Service(GetDeploymentError::DeploymentDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDeploymentError::DeploymentDoesNotExist,
+                    )
                 }
                 "DeploymentIdRequiredException" => {
-                    return RusotoError::Service(GetDeploymentError::DeploymentIdRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeploymentError::DeploymentIdRequired,
+                    )
                 }
                 "InvalidDeploymentIdException" => {
-                    return RusotoError::Service(GetDeploymentError::InvalidDeploymentId(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeploymentError::InvalidDeploymentId,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4342,26 +4838,38 @@ impl GetDeploymentConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeploymentConfigDoesNotExistException" => {
-                    return RusotoError::Service(
-                        GetDeploymentConfigError::DeploymentConfigDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeploymentConfigError::DeploymentConfigDoesNotExist,
                     )
                 }
                 "DeploymentConfigNameRequiredException" => {
-                    return RusotoError::Service(
-                        GetDeploymentConfigError::DeploymentConfigNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeploymentConfigError::DeploymentConfigNameRequired,
                     )
                 }
                 "InvalidComputePlatformException" => {
-                    return RusotoError::Service(GetDeploymentConfigError::InvalidComputePlatform(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDeploymentConfigError::InvalidComputePlatform,
+                    )
                 }
                 "InvalidDeploymentConfigNameException" => {
-                    return RusotoError::Service(
-                        GetDeploymentConfigError::InvalidDeploymentConfigName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeploymentConfigError::InvalidDeploymentConfigName,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4410,41 +4918,62 @@ impl GetDeploymentGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(GetDeploymentGroupError::ApplicationDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDeploymentGroupError::ApplicationDoesNotExist,
+                    )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(GetDeploymentGroupError::ApplicationNameRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDeploymentGroupError::ApplicationNameRequired,
+                    )
                 }
                 "DeploymentConfigDoesNotExistException" => {
-                    return RusotoError::Service(
-                        GetDeploymentGroupError::DeploymentConfigDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeploymentGroupError::DeploymentConfigDoesNotExist,
                     )
                 }
                 "DeploymentGroupDoesNotExistException" => {
-                    return RusotoError::Service(
-                        GetDeploymentGroupError::DeploymentGroupDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeploymentGroupError::DeploymentGroupDoesNotExist,
                     )
                 }
                 "DeploymentGroupNameRequiredException" => {
-                    return RusotoError::Service(
-                        GetDeploymentGroupError::DeploymentGroupNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeploymentGroupError::DeploymentGroupNameRequired,
                     )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(GetDeploymentGroupError::InvalidApplicationName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDeploymentGroupError::InvalidApplicationName,
+                    )
                 }
                 "InvalidDeploymentGroupNameException" => {
-                    return RusotoError::Service(
-                        GetDeploymentGroupError::InvalidDeploymentGroupName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeploymentGroupError::InvalidDeploymentGroupName,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4498,41 +5027,62 @@ impl GetDeploymentInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeploymentDoesNotExistException" => {
-                    return RusotoError::Service(
-                        GetDeploymentInstanceError::DeploymentDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeploymentInstanceError::DeploymentDoesNotExist,
                     )
                 }
                 "DeploymentIdRequiredException" => {
-                    return RusotoError::Service(GetDeploymentInstanceError::DeploymentIdRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDeploymentInstanceError::DeploymentIdRequired,
+                    )
                 }
                 "InstanceDoesNotExistException" => {
-                    return RusotoError::Service(GetDeploymentInstanceError::InstanceDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDeploymentInstanceError::InstanceDoesNotExist,
+                    )
                 }
                 "InstanceIdRequiredException" => {
-                    return RusotoError::Service(GetDeploymentInstanceError::InstanceIdRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDeploymentInstanceError::InstanceIdRequired,
+                    )
                 }
                 "InvalidComputePlatformException" => {
-                    return RusotoError::Service(
-                        GetDeploymentInstanceError::InvalidComputePlatform(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeploymentInstanceError::InvalidComputePlatform,
                     )
                 }
                 "InvalidDeploymentIdException" => {
-                    return RusotoError::Service(GetDeploymentInstanceError::InvalidDeploymentId(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDeploymentInstanceError::InvalidDeploymentId,
+                    )
                 }
                 "InvalidInstanceNameException" => {
-                    return RusotoError::Service(GetDeploymentInstanceError::InvalidInstanceName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDeploymentInstanceError::InvalidInstanceName,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4580,46 +5130,70 @@ impl GetDeploymentTargetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeploymentDoesNotExistException" => {
-                    return RusotoError::Service(GetDeploymentTargetError::DeploymentDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDeploymentTargetError::DeploymentDoesNotExist,
+                    )
                 }
                 "DeploymentIdRequiredException" => {
-                    return RusotoError::Service(GetDeploymentTargetError::DeploymentIdRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDeploymentTargetError::DeploymentIdRequired,
+                    )
                 }
                 "DeploymentNotStartedException" => {
-                    return RusotoError::Service(GetDeploymentTargetError::DeploymentNotStarted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDeploymentTargetError::DeploymentNotStarted,
+                    )
                 }
                 "DeploymentTargetDoesNotExistException" => {
-                    return RusotoError::Service(
-                        GetDeploymentTargetError::DeploymentTargetDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeploymentTargetError::DeploymentTargetDoesNotExist,
                     )
                 }
                 "DeploymentTargetIdRequiredException" => {
-                    return RusotoError::Service(
-                        GetDeploymentTargetError::DeploymentTargetIdRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeploymentTargetError::DeploymentTargetIdRequired,
                     )
                 }
                 "InvalidDeploymentIdException" => {
-                    return RusotoError::Service(GetDeploymentTargetError::InvalidDeploymentId(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDeploymentTargetError::InvalidDeploymentId,
+                    )
                 }
                 "InvalidDeploymentTargetIdException" => {
-                    return RusotoError::Service(
-                        GetDeploymentTargetError::InvalidDeploymentTargetId(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeploymentTargetError::InvalidDeploymentTargetId,
                     )
                 }
                 "InvalidInstanceNameException" => {
-                    return RusotoError::Service(GetDeploymentTargetError::InvalidInstanceName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDeploymentTargetError::InvalidInstanceName,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4664,21 +5238,30 @@ impl GetOnPremisesInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InstanceNameRequiredException" => {
-                    return RusotoError::Service(GetOnPremisesInstanceError::InstanceNameRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetOnPremisesInstanceError::InstanceNameRequired,
+                    )
                 }
                 "InstanceNotRegisteredException" => {
-                    return RusotoError::Service(GetOnPremisesInstanceError::InstanceNotRegistered(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetOnPremisesInstanceError::InstanceNotRegistered,
+                    )
                 }
                 "InvalidInstanceNameException" => {
-                    return RusotoError::Service(GetOnPremisesInstanceError::InvalidInstanceName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetOnPremisesInstanceError::InvalidInstanceName,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4726,56 +5309,86 @@ impl ListApplicationRevisionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(
-                        ListApplicationRevisionsError::ApplicationDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListApplicationRevisionsError::ApplicationDoesNotExist,
                     )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(
-                        ListApplicationRevisionsError::ApplicationNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListApplicationRevisionsError::ApplicationNameRequired,
                     )
                 }
                 "BucketNameFilterRequiredException" => {
-                    return RusotoError::Service(
-                        ListApplicationRevisionsError::BucketNameFilterRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListApplicationRevisionsError::BucketNameFilterRequired,
                     )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(
-                        ListApplicationRevisionsError::InvalidApplicationName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListApplicationRevisionsError::InvalidApplicationName,
                     )
                 }
                 "InvalidBucketNameFilterException" => {
-                    return RusotoError::Service(
-                        ListApplicationRevisionsError::InvalidBucketNameFilter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListApplicationRevisionsError::InvalidBucketNameFilter,
                     )
                 }
                 "InvalidDeployedStateFilterException" => {
-                    return RusotoError::Service(
-                        ListApplicationRevisionsError::InvalidDeployedStateFilter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListApplicationRevisionsError::InvalidDeployedStateFilter,
                     )
                 }
                 "InvalidKeyPrefixFilterException" => {
-                    return RusotoError::Service(
-                        ListApplicationRevisionsError::InvalidKeyPrefixFilter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListApplicationRevisionsError::InvalidKeyPrefixFilter,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListApplicationRevisionsError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListApplicationRevisionsError::InvalidNextToken,
+                    )
                 }
                 "InvalidSortByException" => {
-                    return RusotoError::Service(ListApplicationRevisionsError::InvalidSortBy(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListApplicationRevisionsError::InvalidSortBy,
+                    )
                 }
                 "InvalidSortOrderException" => {
-                    return RusotoError::Service(ListApplicationRevisionsError::InvalidSortOrder(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListApplicationRevisionsError::InvalidSortOrder,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4826,9 +5439,14 @@ impl ListApplicationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListApplicationsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListApplicationsError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4856,11 +5474,14 @@ impl ListDeploymentConfigsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDeploymentConfigsError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDeploymentConfigsError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4894,26 +5515,38 @@ impl ListDeploymentGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(
-                        ListDeploymentGroupsError::ApplicationDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDeploymentGroupsError::ApplicationDoesNotExist,
                     )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(
-                        ListDeploymentGroupsError::ApplicationNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDeploymentGroupsError::ApplicationNameRequired,
                     )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(ListDeploymentGroupsError::InvalidApplicationName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDeploymentGroupsError::InvalidApplicationName,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDeploymentGroupsError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDeploymentGroupsError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4962,56 +5595,86 @@ impl ListDeploymentInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeploymentDoesNotExistException" => {
-                    return RusotoError::Service(
-                        ListDeploymentInstancesError::DeploymentDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDeploymentInstancesError::DeploymentDoesNotExist,
                     )
                 }
                 "DeploymentIdRequiredException" => {
-                    return RusotoError::Service(
-                        ListDeploymentInstancesError::DeploymentIdRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDeploymentInstancesError::DeploymentIdRequired,
                     )
                 }
                 "DeploymentNotStartedException" => {
-                    return RusotoError::Service(
-                        ListDeploymentInstancesError::DeploymentNotStarted(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDeploymentInstancesError::DeploymentNotStarted,
                     )
                 }
                 "InvalidComputePlatformException" => {
-                    return RusotoError::Service(
-                        ListDeploymentInstancesError::InvalidComputePlatform(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDeploymentInstancesError::InvalidComputePlatform,
                     )
                 }
                 "InvalidDeploymentIdException" => {
-                    return RusotoError::Service(ListDeploymentInstancesError::InvalidDeploymentId(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDeploymentInstancesError::InvalidDeploymentId,
+                    )
                 }
                 "InvalidDeploymentInstanceTypeException" => {
-                    return RusotoError::Service(
-                        ListDeploymentInstancesError::InvalidDeploymentInstanceType(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDeploymentInstancesError::InvalidDeploymentInstanceType,
                     )
                 }
                 "InvalidInstanceStatusException" => {
-                    return RusotoError::Service(
-                        ListDeploymentInstancesError::InvalidInstanceStatus(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDeploymentInstancesError::InvalidInstanceStatus,
                     )
                 }
                 "InvalidInstanceTypeException" => {
-                    return RusotoError::Service(ListDeploymentInstancesError::InvalidInstanceType(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDeploymentInstancesError::InvalidInstanceType,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDeploymentInstancesError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDeploymentInstancesError::InvalidNextToken,
+                    )
                 }
                 "InvalidTargetFilterNameException" => {
-                    return RusotoError::Service(
-                        ListDeploymentInstancesError::InvalidTargetFilterName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDeploymentInstancesError::InvalidTargetFilterName,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5072,46 +5735,70 @@ impl ListDeploymentTargetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeploymentDoesNotExistException" => {
-                    return RusotoError::Service(
-                        ListDeploymentTargetsError::DeploymentDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDeploymentTargetsError::DeploymentDoesNotExist,
                     )
                 }
                 "DeploymentIdRequiredException" => {
-                    return RusotoError::Service(ListDeploymentTargetsError::DeploymentIdRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDeploymentTargetsError::DeploymentIdRequired,
+                    )
                 }
                 "DeploymentNotStartedException" => {
-                    return RusotoError::Service(ListDeploymentTargetsError::DeploymentNotStarted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDeploymentTargetsError::DeploymentNotStarted,
+                    )
                 }
                 "InvalidDeploymentIdException" => {
-                    return RusotoError::Service(ListDeploymentTargetsError::InvalidDeploymentId(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDeploymentTargetsError::InvalidDeploymentId,
+                    )
                 }
                 "InvalidDeploymentInstanceTypeException" => {
-                    return RusotoError::Service(
-                        ListDeploymentTargetsError::InvalidDeploymentInstanceType(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDeploymentTargetsError::InvalidDeploymentInstanceType,
                     )
                 }
                 "InvalidInstanceStatusException" => {
-                    return RusotoError::Service(ListDeploymentTargetsError::InvalidInstanceStatus(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDeploymentTargetsError::InvalidInstanceStatus,
+                    )
                 }
                 "InvalidInstanceTypeException" => {
-                    return RusotoError::Service(ListDeploymentTargetsError::InvalidInstanceType(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDeploymentTargetsError::InvalidInstanceType,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDeploymentTargetsError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDeploymentTargetsError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5168,53 +5855,94 @@ impl ListDeploymentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(ListDeploymentsError::ApplicationDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDeploymentsError::ApplicationDoesNotExist,
+                    )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(ListDeploymentsError::ApplicationNameRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDeploymentsError::ApplicationNameRequired,
+                    )
                 }
                 "DeploymentGroupDoesNotExistException" => {
-                    return RusotoError::Service(ListDeploymentsError::DeploymentGroupDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDeploymentsError::DeploymentGroupDoesNotExist,
+                    )
                 }
                 "DeploymentGroupNameRequiredException" => {
-                    return RusotoError::Service(ListDeploymentsError::DeploymentGroupNameRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDeploymentsError::DeploymentGroupNameRequired,
+                    )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(ListDeploymentsError::InvalidApplicationName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDeploymentsError::InvalidApplicationName,
+                    )
                 }
                 "InvalidDeploymentGroupNameException" => {
-                    return RusotoError::Service(ListDeploymentsError::InvalidDeploymentGroupName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDeploymentsError::InvalidDeploymentGroupName,
+                    )
                 }
                 "InvalidDeploymentStatusException" => {
-                    return RusotoError::Service(ListDeploymentsError::InvalidDeploymentStatus(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDeploymentsError::InvalidDeploymentStatus,
+                    )
                 }
                 "InvalidExternalIdException" => {
-                    return RusotoError::Service(ListDeploymentsError::InvalidExternalId(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDeploymentsError::InvalidExternalId,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListDeploymentsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDeploymentsError::InvalidInput,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDeploymentsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDeploymentsError::InvalidNextToken,
+                    )
                 }
                 "InvalidTimeRangeException" => {
-                    return RusotoError::Service(ListDeploymentsError::InvalidTimeRange(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDeploymentsError::InvalidTimeRange,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5258,21 +5986,30 @@ impl ListGitHubAccountTokenNamesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        ListGitHubAccountTokenNamesError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGitHubAccountTokenNamesError::InvalidNextToken,
                     )
                 }
                 "OperationNotSupportedException" => {
-                    return RusotoError::Service(
-                        ListGitHubAccountTokenNamesError::OperationNotSupported(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGitHubAccountTokenNamesError::OperationNotSupported,
                     )
                 }
                 "ResourceValidationException" => {
-                    return RusotoError::Service(
-                        ListGitHubAccountTokenNamesError::ResourceValidation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGitHubAccountTokenNamesError::ResourceValidation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5310,21 +6047,30 @@ impl ListOnPremisesInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListOnPremisesInstancesError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListOnPremisesInstancesError::InvalidNextToken,
+                    )
                 }
                 "InvalidRegistrationStatusException" => {
-                    return RusotoError::Service(
-                        ListOnPremisesInstancesError::InvalidRegistrationStatus(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOnPremisesInstancesError::InvalidRegistrationStatus,
                     )
                 }
                 "InvalidTagFilterException" => {
-                    return RusotoError::Service(ListOnPremisesInstancesError::InvalidTagFilter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListOnPremisesInstancesError::InvalidTagFilter,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5360,17 +6106,30 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArnNotSupportedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ArnNotSupported(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::ArnNotSupported,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InvalidArn,
+                    )
                 }
                 "ResourceArnRequiredException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceArnRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceArnRequired,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5413,14 +6172,14 @@ impl PutLifecycleEventHookExecutionStatusError {
     ) -> RusotoError<PutLifecycleEventHookExecutionStatusError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                                "DeploymentDoesNotExistException" => return RusotoError::Service(PutLifecycleEventHookExecutionStatusError::DeploymentDoesNotExist(err.msg)),
-"DeploymentIdRequiredException" => return RusotoError::Service(PutLifecycleEventHookExecutionStatusError::DeploymentIdRequired(err.msg)),
-"InvalidDeploymentIdException" => return RusotoError::Service(PutLifecycleEventHookExecutionStatusError::InvalidDeploymentId(err.msg)),
-"InvalidLifecycleEventHookExecutionIdException" => return RusotoError::Service(PutLifecycleEventHookExecutionStatusError::InvalidLifecycleEventHookExecutionId(err.msg)),
-"InvalidLifecycleEventHookExecutionStatusException" => return RusotoError::Service(PutLifecycleEventHookExecutionStatusError::InvalidLifecycleEventHookExecutionStatus(err.msg)),
-"LifecycleEventAlreadyCompletedException" => return RusotoError::Service(PutLifecycleEventHookExecutionStatusError::LifecycleEventAlreadyCompleted(err.msg)),
-"UnsupportedActionForDeploymentTypeException" => return RusotoError::Service(PutLifecycleEventHookExecutionStatusError::UnsupportedActionForDeploymentType(err.msg)),
-"ValidationException" => return RusotoError::Validation(err.msg),
+                                "DeploymentDoesNotExistException" => return RusotoError::service_error(&res, &err.typ, err.msg, PutLifecycleEventHookExecutionStatusError::DeploymentDoesNotExist),
+"DeploymentIdRequiredException" => return RusotoError::service_error(&res, &err.typ, err.msg, PutLifecycleEventHookExecutionStatusError::DeploymentIdRequired),
+"InvalidDeploymentIdException" => return RusotoError::service_error(&res, &err.typ, err.msg, PutLifecycleEventHookExecutionStatusError::InvalidDeploymentId),
+"InvalidLifecycleEventHookExecutionIdException" => return RusotoError::service_error(&res, &err.typ, err.msg, PutLifecycleEventHookExecutionStatusError::InvalidLifecycleEventHookExecutionId),
+"InvalidLifecycleEventHookExecutionStatusException" => return RusotoError::service_error(&res, &err.typ, err.msg, PutLifecycleEventHookExecutionStatusError::InvalidLifecycleEventHookExecutionStatus),
+"LifecycleEventAlreadyCompletedException" => return RusotoError::service_error(&res, &err.typ, err.msg, PutLifecycleEventHookExecutionStatusError::LifecycleEventAlreadyCompleted),
+"UnsupportedActionForDeploymentTypeException" => return RusotoError::service_error(&res, &err.typ, err.msg, PutLifecycleEventHookExecutionStatusError::UnsupportedActionForDeploymentType),
+"ValidationException" => return RusotoError::validation_error(&res, err.msg),
 _ => {}
                             }
         }
@@ -5480,36 +6239,54 @@ impl RegisterApplicationRevisionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(
-                        RegisterApplicationRevisionError::ApplicationDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterApplicationRevisionError::ApplicationDoesNotExist,
                     )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(
-                        RegisterApplicationRevisionError::ApplicationNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterApplicationRevisionError::ApplicationNameRequired,
                     )
                 }
                 "DescriptionTooLongException" => {
-                    return RusotoError::Service(
-                        RegisterApplicationRevisionError::DescriptionTooLong(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterApplicationRevisionError::DescriptionTooLong,
                     )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(
-                        RegisterApplicationRevisionError::InvalidApplicationName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterApplicationRevisionError::InvalidApplicationName,
                     )
                 }
                 "InvalidRevisionException" => {
-                    return RusotoError::Service(RegisterApplicationRevisionError::InvalidRevision(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterApplicationRevisionError::InvalidRevision,
+                    )
                 }
                 "RevisionRequiredException" => {
-                    return RusotoError::Service(
-                        RegisterApplicationRevisionError::RevisionRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterApplicationRevisionError::RevisionRequired,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5570,56 +6347,86 @@ impl RegisterOnPremisesInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "IamArnRequiredException" => {
-                    return RusotoError::Service(RegisterOnPremisesInstanceError::IamArnRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterOnPremisesInstanceError::IamArnRequired,
+                    )
                 }
                 "IamSessionArnAlreadyRegisteredException" => {
-                    return RusotoError::Service(
-                        RegisterOnPremisesInstanceError::IamSessionArnAlreadyRegistered(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterOnPremisesInstanceError::IamSessionArnAlreadyRegistered,
                     )
                 }
                 "IamUserArnAlreadyRegisteredException" => {
-                    return RusotoError::Service(
-                        RegisterOnPremisesInstanceError::IamUserArnAlreadyRegistered(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterOnPremisesInstanceError::IamUserArnAlreadyRegistered,
                     )
                 }
                 "IamUserArnRequiredException" => {
-                    return RusotoError::Service(
-                        RegisterOnPremisesInstanceError::IamUserArnRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterOnPremisesInstanceError::IamUserArnRequired,
                     )
                 }
                 "InstanceNameAlreadyRegisteredException" => {
-                    return RusotoError::Service(
-                        RegisterOnPremisesInstanceError::InstanceNameAlreadyRegistered(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterOnPremisesInstanceError::InstanceNameAlreadyRegistered,
                     )
                 }
                 "InstanceNameRequiredException" => {
-                    return RusotoError::Service(
-                        RegisterOnPremisesInstanceError::InstanceNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterOnPremisesInstanceError::InstanceNameRequired,
                     )
                 }
                 "InvalidIamSessionArnException" => {
-                    return RusotoError::Service(
-                        RegisterOnPremisesInstanceError::InvalidIamSessionArn(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterOnPremisesInstanceError::InvalidIamSessionArn,
                     )
                 }
                 "InvalidIamUserArnException" => {
-                    return RusotoError::Service(
-                        RegisterOnPremisesInstanceError::InvalidIamUserArn(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterOnPremisesInstanceError::InvalidIamUserArn,
                     )
                 }
                 "InvalidInstanceNameException" => {
-                    return RusotoError::Service(
-                        RegisterOnPremisesInstanceError::InvalidInstanceName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterOnPremisesInstanceError::InvalidInstanceName,
                     )
                 }
                 "MultipleIamArnsProvidedException" => {
-                    return RusotoError::Service(
-                        RegisterOnPremisesInstanceError::MultipleIamArnsProvided(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterOnPremisesInstanceError::MultipleIamArnsProvided,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5686,41 +6493,62 @@ impl RemoveTagsFromOnPremisesInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InstanceLimitExceededException" => {
-                    return RusotoError::Service(
-                        RemoveTagsFromOnPremisesInstancesError::InstanceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveTagsFromOnPremisesInstancesError::InstanceLimitExceeded,
                     )
                 }
                 "InstanceNameRequiredException" => {
-                    return RusotoError::Service(
-                        RemoveTagsFromOnPremisesInstancesError::InstanceNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveTagsFromOnPremisesInstancesError::InstanceNameRequired,
                     )
                 }
                 "InstanceNotRegisteredException" => {
-                    return RusotoError::Service(
-                        RemoveTagsFromOnPremisesInstancesError::InstanceNotRegistered(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveTagsFromOnPremisesInstancesError::InstanceNotRegistered,
                     )
                 }
                 "InvalidInstanceNameException" => {
-                    return RusotoError::Service(
-                        RemoveTagsFromOnPremisesInstancesError::InvalidInstanceName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveTagsFromOnPremisesInstancesError::InvalidInstanceName,
                     )
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(
-                        RemoveTagsFromOnPremisesInstancesError::InvalidTag(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveTagsFromOnPremisesInstancesError::InvalidTag,
                     )
                 }
                 "TagLimitExceededException" => {
-                    return RusotoError::Service(
-                        RemoveTagsFromOnPremisesInstancesError::TagLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveTagsFromOnPremisesInstancesError::TagLimitExceeded,
                     )
                 }
                 "TagRequiredException" => {
-                    return RusotoError::Service(
-                        RemoveTagsFromOnPremisesInstancesError::TagRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveTagsFromOnPremisesInstancesError::TagRequired,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5778,40 +6606,54 @@ impl SkipWaitTimeForInstanceTerminationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeploymentAlreadyCompletedException" => {
-                    return RusotoError::Service(
-                        SkipWaitTimeForInstanceTerminationError::DeploymentAlreadyCompleted(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SkipWaitTimeForInstanceTerminationError::DeploymentAlreadyCompleted,
                     )
                 }
                 "DeploymentDoesNotExistException" => {
-                    return RusotoError::Service(
-                        SkipWaitTimeForInstanceTerminationError::DeploymentDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SkipWaitTimeForInstanceTerminationError::DeploymentDoesNotExist,
                     )
                 }
                 "DeploymentIdRequiredException" => {
-                    return RusotoError::Service(
-                        SkipWaitTimeForInstanceTerminationError::DeploymentIdRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SkipWaitTimeForInstanceTerminationError::DeploymentIdRequired,
                     )
                 }
                 "DeploymentNotStartedException" => {
-                    return RusotoError::Service(
-                        SkipWaitTimeForInstanceTerminationError::DeploymentNotStarted(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SkipWaitTimeForInstanceTerminationError::DeploymentNotStarted,
                     )
                 }
                 "InvalidDeploymentIdException" => {
-                    return RusotoError::Service(
-                        SkipWaitTimeForInstanceTerminationError::InvalidDeploymentId(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SkipWaitTimeForInstanceTerminationError::InvalidDeploymentId,
                     )
                 }
                 "UnsupportedActionForDeploymentTypeException" => {
-                    return RusotoError::Service(
-                        SkipWaitTimeForInstanceTerminationError::UnsupportedActionForDeploymentType(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SkipWaitTimeForInstanceTerminationError::UnsupportedActionForDeploymentType,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5866,32 +6708,54 @@ impl StopDeploymentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeploymentAlreadyCompletedException" => {
-                    return RusotoError::Service(StopDeploymentError::DeploymentAlreadyCompleted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopDeploymentError::DeploymentAlreadyCompleted,
+                    )
                 }
                 "DeploymentDoesNotExistException" => {
-                    return RusotoError::Service(StopDeploymentError::DeploymentDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopDeploymentError::DeploymentDoesNotExist,
+                    )
                 }
                 "DeploymentGroupDoesNotExistException" => {
-                    return RusotoError::Service(StopDeploymentError::DeploymentGroupDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopDeploymentError::DeploymentGroupDoesNotExist,
+                    )
                 }
                 "DeploymentIdRequiredException" => {
-                    return RusotoError::Service(StopDeploymentError::DeploymentIdRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopDeploymentError::DeploymentIdRequired,
+                    )
                 }
                 "InvalidDeploymentIdException" => {
-                    return RusotoError::Service(StopDeploymentError::InvalidDeploymentId(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopDeploymentError::InvalidDeploymentId,
+                    )
                 }
                 "UnsupportedActionForDeploymentTypeException" => {
-                    return RusotoError::Service(
-                        StopDeploymentError::UnsupportedActionForDeploymentType(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopDeploymentError::UnsupportedActionForDeploymentType,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5940,34 +6804,70 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(TagResourceError::ApplicationDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ApplicationDoesNotExist,
+                    )
                 }
                 "ArnNotSupportedException" => {
-                    return RusotoError::Service(TagResourceError::ArnNotSupported(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ArnNotSupported,
+                    )
                 }
                 "DeploymentConfigDoesNotExistException" => {
-                    return RusotoError::Service(TagResourceError::DeploymentConfigDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TagResourceError::DeploymentConfigDoesNotExist,
+                    )
                 }
                 "DeploymentGroupDoesNotExistException" => {
-                    return RusotoError::Service(TagResourceError::DeploymentGroupDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TagResourceError::DeploymentGroupDoesNotExist,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(TagResourceError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidArn,
+                    )
                 }
                 "InvalidTagsToAddException" => {
-                    return RusotoError::Service(TagResourceError::InvalidTagsToAdd(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidTagsToAdd,
+                    )
                 }
                 "ResourceArnRequiredException" => {
-                    return RusotoError::Service(TagResourceError::ResourceArnRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceArnRequired,
+                    )
                 }
                 "TagRequiredException" => {
-                    return RusotoError::Service(TagResourceError::TagRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::TagRequired,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6016,36 +6916,70 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(UntagResourceError::ApplicationDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UntagResourceError::ApplicationDoesNotExist,
+                    )
                 }
                 "ArnNotSupportedException" => {
-                    return RusotoError::Service(UntagResourceError::ArnNotSupported(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ArnNotSupported,
+                    )
                 }
                 "DeploymentConfigDoesNotExistException" => {
-                    return RusotoError::Service(UntagResourceError::DeploymentConfigDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UntagResourceError::DeploymentConfigDoesNotExist,
+                    )
                 }
                 "DeploymentGroupDoesNotExistException" => {
-                    return RusotoError::Service(UntagResourceError::DeploymentGroupDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UntagResourceError::DeploymentGroupDoesNotExist,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidArn,
+                    )
                 }
                 "InvalidTagsToAddException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidTagsToAdd(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidTagsToAdd,
+                    )
                 }
                 "ResourceArnRequiredException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceArnRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceArnRequired,
+                    )
                 }
                 "TagRequiredException" => {
-                    return RusotoError::Service(UntagResourceError::TagRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::TagRequired,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6086,26 +7020,38 @@ impl UpdateApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ApplicationAlreadyExistsException" => {
-                    return RusotoError::Service(UpdateApplicationError::ApplicationAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateApplicationError::ApplicationAlreadyExists,
+                    )
                 }
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(UpdateApplicationError::ApplicationDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateApplicationError::ApplicationDoesNotExist,
+                    )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(UpdateApplicationError::ApplicationNameRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateApplicationError::ApplicationNameRequired,
+                    )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(UpdateApplicationError::InvalidApplicationName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateApplicationError::InvalidApplicationName,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6196,153 +7142,254 @@ impl UpdateDeploymentGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlarmsLimitExceededException" => {
-                    return RusotoError::Service(UpdateDeploymentGroupError::AlarmsLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDeploymentGroupError::AlarmsLimitExceeded,
+                    )
                 }
                 "ApplicationDoesNotExistException" => {
-                    return RusotoError::Service(
-                        UpdateDeploymentGroupError::ApplicationDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeploymentGroupError::ApplicationDoesNotExist,
                     )
                 }
                 "ApplicationNameRequiredException" => {
-                    return RusotoError::Service(
-                        UpdateDeploymentGroupError::ApplicationNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeploymentGroupError::ApplicationNameRequired,
                     )
                 }
                 "DeploymentConfigDoesNotExistException" => {
-                    return RusotoError::Service(
-                        UpdateDeploymentGroupError::DeploymentConfigDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeploymentGroupError::DeploymentConfigDoesNotExist,
                     )
                 }
                 "DeploymentGroupAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        UpdateDeploymentGroupError::DeploymentGroupAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeploymentGroupError::DeploymentGroupAlreadyExists,
                     )
                 }
                 "DeploymentGroupDoesNotExistException" => {
-                    return RusotoError::Service(
-                        UpdateDeploymentGroupError::DeploymentGroupDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeploymentGroupError::DeploymentGroupDoesNotExist,
                     )
                 }
                 "DeploymentGroupNameRequiredException" => {
-                    return RusotoError::Service(
-                        UpdateDeploymentGroupError::DeploymentGroupNameRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeploymentGroupError::DeploymentGroupNameRequired,
                     )
                 }
                 "ECSServiceMappingLimitExceededException" => {
-                    return RusotoError::Service(
-                        UpdateDeploymentGroupError::ECSServiceMappingLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeploymentGroupError::ECSServiceMappingLimitExceeded,
                     )
                 }
                 "InvalidAlarmConfigException" => {
-                    return RusotoError::Service(UpdateDeploymentGroupError::InvalidAlarmConfig(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDeploymentGroupError::InvalidAlarmConfig,
+                    )
                 }
                 "InvalidApplicationNameException" => {
-                    return RusotoError::Service(
-                        UpdateDeploymentGroupError::InvalidApplicationName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeploymentGroupError::InvalidApplicationName,
                     )
                 }
                 "InvalidAutoRollbackConfigException" => {
-                    return RusotoError::Service(
-                        UpdateDeploymentGroupError::InvalidAutoRollbackConfig(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeploymentGroupError::InvalidAutoRollbackConfig,
                     )
                 }
                 "InvalidAutoScalingGroupException" => {
-                    return RusotoError::Service(
-                        UpdateDeploymentGroupError::InvalidAutoScalingGroup(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeploymentGroupError::InvalidAutoScalingGroup,
                     )
                 }
                 "InvalidBlueGreenDeploymentConfigurationException" => {
-                    return RusotoError::Service(
-                        UpdateDeploymentGroupError::InvalidBlueGreenDeploymentConfiguration(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeploymentGroupError::InvalidBlueGreenDeploymentConfiguration,
                     )
                 }
                 "InvalidDeploymentConfigNameException" => {
-                    return RusotoError::Service(
-                        UpdateDeploymentGroupError::InvalidDeploymentConfigName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeploymentGroupError::InvalidDeploymentConfigName,
                     )
                 }
                 "InvalidDeploymentGroupNameException" => {
-                    return RusotoError::Service(
-                        UpdateDeploymentGroupError::InvalidDeploymentGroupName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeploymentGroupError::InvalidDeploymentGroupName,
                     )
                 }
                 "InvalidDeploymentStyleException" => {
-                    return RusotoError::Service(
-                        UpdateDeploymentGroupError::InvalidDeploymentStyle(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeploymentGroupError::InvalidDeploymentStyle,
                     )
                 }
                 "InvalidEC2TagCombinationException" => {
-                    return RusotoError::Service(
-                        UpdateDeploymentGroupError::InvalidEC2TagCombination(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeploymentGroupError::InvalidEC2TagCombination,
                     )
                 }
                 "InvalidEC2TagException" => {
-                    return RusotoError::Service(UpdateDeploymentGroupError::InvalidEC2Tag(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeploymentGroupError::InvalidEC2Tag,
+                    )
                 }
                 "InvalidECSServiceException" => {
-                    return RusotoError::Service(UpdateDeploymentGroupError::InvalidECSService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDeploymentGroupError::InvalidECSService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateDeploymentGroupError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeploymentGroupError::InvalidInput,
+                    )
                 }
                 "InvalidLoadBalancerInfoException" => {
-                    return RusotoError::Service(
-                        UpdateDeploymentGroupError::InvalidLoadBalancerInfo(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeploymentGroupError::InvalidLoadBalancerInfo,
                     )
                 }
                 "InvalidOnPremisesTagCombinationException" => {
-                    return RusotoError::Service(
-                        UpdateDeploymentGroupError::InvalidOnPremisesTagCombination(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeploymentGroupError::InvalidOnPremisesTagCombination,
                     )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(UpdateDeploymentGroupError::InvalidRole(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeploymentGroupError::InvalidRole,
+                    )
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(UpdateDeploymentGroupError::InvalidTag(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeploymentGroupError::InvalidTag,
+                    )
                 }
                 "InvalidTargetGroupPairException" => {
-                    return RusotoError::Service(
-                        UpdateDeploymentGroupError::InvalidTargetGroupPair(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeploymentGroupError::InvalidTargetGroupPair,
                     )
                 }
                 "InvalidTrafficRoutingConfigurationException" => {
-                    return RusotoError::Service(
-                        UpdateDeploymentGroupError::InvalidTrafficRoutingConfiguration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeploymentGroupError::InvalidTrafficRoutingConfiguration,
                     )
                 }
                 "InvalidTriggerConfigException" => {
-                    return RusotoError::Service(UpdateDeploymentGroupError::InvalidTriggerConfig(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDeploymentGroupError::InvalidTriggerConfig,
+                    )
                 }
                 "LifecycleHookLimitExceededException" => {
-                    return RusotoError::Service(
-                        UpdateDeploymentGroupError::LifecycleHookLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeploymentGroupError::LifecycleHookLimitExceeded,
                     )
                 }
                 "TagSetListLimitExceededException" => {
-                    return RusotoError::Service(
-                        UpdateDeploymentGroupError::TagSetListLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeploymentGroupError::TagSetListLimitExceeded,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDeploymentGroupError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeploymentGroupError::Throttling,
+                    )
                 }
                 "TriggerTargetsLimitExceededException" => {
-                    return RusotoError::Service(
-                        UpdateDeploymentGroupError::TriggerTargetsLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeploymentGroupError::TriggerTargetsLimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6724,11 +7771,12 @@ pub struct CodeDeployClient {
 impl CodeDeployClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> CodeDeployClient {
         CodeDeployClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "CodeDeploy"),
         }
     }
 
@@ -6743,12 +7791,32 @@ impl CodeDeployClient {
     {
         CodeDeployClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "CodeDeploy"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CodeDeployClient {
-        CodeDeployClient { client, region }
+        CodeDeployClient {
+            client,
+            region: region_for_service(region, "CodeDeploy"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> CodeDeployClient {
+        CodeDeployClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("CodeDeploy"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CodeDeployClient {
+        CodeDeployClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -6760,6 +7828,7 @@ impl CodeDeploy for CodeDeployClient {
         input: AddTagsToOnPremisesInstancesInput,
     ) -> Result<(), RusotoError<AddTagsToOnPremisesInstancesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AddTagsToOnPremisesInstances");
         request.add_header(
             "x-amz-target",
             "CodeDeploy_20141006.AddTagsToOnPremisesInstances",
@@ -6781,6 +7850,7 @@ impl CodeDeploy for CodeDeployClient {
     ) -> Result<BatchGetApplicationRevisionsOutput, RusotoError<BatchGetApplicationRevisionsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchGetApplicationRevisions");
         request.add_header(
             "x-amz-target",
             "CodeDeploy_20141006.BatchGetApplicationRevisions",
@@ -6803,6 +7873,7 @@ impl CodeDeploy for CodeDeployClient {
         input: BatchGetApplicationsInput,
     ) -> Result<BatchGetApplicationsOutput, RusotoError<BatchGetApplicationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchGetApplications");
         request.add_header("x-amz-target", "CodeDeploy_20141006.BatchGetApplications");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6821,6 +7892,7 @@ impl CodeDeploy for CodeDeployClient {
         input: BatchGetDeploymentGroupsInput,
     ) -> Result<BatchGetDeploymentGroupsOutput, RusotoError<BatchGetDeploymentGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchGetDeploymentGroups");
         request.add_header(
             "x-amz-target",
             "CodeDeploy_20141006.BatchGetDeploymentGroups",
@@ -6844,6 +7916,7 @@ impl CodeDeploy for CodeDeployClient {
     ) -> Result<BatchGetDeploymentInstancesOutput, RusotoError<BatchGetDeploymentInstancesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchGetDeploymentInstances");
         request.add_header(
             "x-amz-target",
             "CodeDeploy_20141006.BatchGetDeploymentInstances",
@@ -6866,6 +7939,7 @@ impl CodeDeploy for CodeDeployClient {
         input: BatchGetDeploymentTargetsInput,
     ) -> Result<BatchGetDeploymentTargetsOutput, RusotoError<BatchGetDeploymentTargetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchGetDeploymentTargets");
         request.add_header(
             "x-amz-target",
             "CodeDeploy_20141006.BatchGetDeploymentTargets",
@@ -6888,6 +7962,7 @@ impl CodeDeploy for CodeDeployClient {
         input: BatchGetDeploymentsInput,
     ) -> Result<BatchGetDeploymentsOutput, RusotoError<BatchGetDeploymentsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchGetDeployments");
         request.add_header("x-amz-target", "CodeDeploy_20141006.BatchGetDeployments");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6907,6 +7982,7 @@ impl CodeDeploy for CodeDeployClient {
     ) -> Result<BatchGetOnPremisesInstancesOutput, RusotoError<BatchGetOnPremisesInstancesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchGetOnPremisesInstances");
         request.add_header(
             "x-amz-target",
             "CodeDeploy_20141006.BatchGetOnPremisesInstances",
@@ -6929,6 +8005,7 @@ impl CodeDeploy for CodeDeployClient {
         input: ContinueDeploymentInput,
     ) -> Result<(), RusotoError<ContinueDeploymentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ContinueDeployment");
         request.add_header("x-amz-target", "CodeDeploy_20141006.ContinueDeployment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6946,6 +8023,7 @@ impl CodeDeploy for CodeDeployClient {
         input: CreateApplicationInput,
     ) -> Result<CreateApplicationOutput, RusotoError<CreateApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateApplication");
         request.add_header("x-amz-target", "CodeDeploy_20141006.CreateApplication");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6964,6 +8042,7 @@ impl CodeDeploy for CodeDeployClient {
         input: CreateDeploymentInput,
     ) -> Result<CreateDeploymentOutput, RusotoError<CreateDeploymentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateDeployment");
         request.add_header("x-amz-target", "CodeDeploy_20141006.CreateDeployment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6982,6 +8061,7 @@ impl CodeDeploy for CodeDeployClient {
         input: CreateDeploymentConfigInput,
     ) -> Result<CreateDeploymentConfigOutput, RusotoError<CreateDeploymentConfigError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateDeploymentConfig");
         request.add_header("x-amz-target", "CodeDeploy_20141006.CreateDeploymentConfig");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7001,6 +8081,7 @@ impl CodeDeploy for CodeDeployClient {
         input: CreateDeploymentGroupInput,
     ) -> Result<CreateDeploymentGroupOutput, RusotoError<CreateDeploymentGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateDeploymentGroup");
         request.add_header("x-amz-target", "CodeDeploy_20141006.CreateDeploymentGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7019,6 +8100,7 @@ impl CodeDeploy for CodeDeployClient {
         input: DeleteApplicationInput,
     ) -> Result<(), RusotoError<DeleteApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteApplication");
         request.add_header("x-amz-target", "CodeDeploy_20141006.DeleteApplication");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7036,6 +8118,7 @@ impl CodeDeploy for CodeDeployClient {
         input: DeleteDeploymentConfigInput,
     ) -> Result<(), RusotoError<DeleteDeploymentConfigError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteDeploymentConfig");
         request.add_header("x-amz-target", "CodeDeploy_20141006.DeleteDeploymentConfig");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7053,6 +8136,7 @@ impl CodeDeploy for CodeDeployClient {
         input: DeleteDeploymentGroupInput,
     ) -> Result<DeleteDeploymentGroupOutput, RusotoError<DeleteDeploymentGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteDeploymentGroup");
         request.add_header("x-amz-target", "CodeDeploy_20141006.DeleteDeploymentGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7071,6 +8155,7 @@ impl CodeDeploy for CodeDeployClient {
         input: DeleteGitHubAccountTokenInput,
     ) -> Result<DeleteGitHubAccountTokenOutput, RusotoError<DeleteGitHubAccountTokenError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteGitHubAccountToken");
         request.add_header(
             "x-amz-target",
             "CodeDeploy_20141006.DeleteGitHubAccountToken",
@@ -7094,6 +8179,7 @@ impl CodeDeploy for CodeDeployClient {
     ) -> Result<DeleteResourcesByExternalIdOutput, RusotoError<DeleteResourcesByExternalIdError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteResourcesByExternalId");
         request.add_header(
             "x-amz-target",
             "CodeDeploy_20141006.DeleteResourcesByExternalId",
@@ -7116,6 +8202,7 @@ impl CodeDeploy for CodeDeployClient {
         input: DeregisterOnPremisesInstanceInput,
     ) -> Result<(), RusotoError<DeregisterOnPremisesInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeregisterOnPremisesInstance");
         request.add_header(
             "x-amz-target",
             "CodeDeploy_20141006.DeregisterOnPremisesInstance",
@@ -7136,6 +8223,7 @@ impl CodeDeploy for CodeDeployClient {
         input: GetApplicationInput,
     ) -> Result<GetApplicationOutput, RusotoError<GetApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetApplication");
         request.add_header("x-amz-target", "CodeDeploy_20141006.GetApplication");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7154,6 +8242,7 @@ impl CodeDeploy for CodeDeployClient {
         input: GetApplicationRevisionInput,
     ) -> Result<GetApplicationRevisionOutput, RusotoError<GetApplicationRevisionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetApplicationRevision");
         request.add_header("x-amz-target", "CodeDeploy_20141006.GetApplicationRevision");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7173,6 +8262,7 @@ impl CodeDeploy for CodeDeployClient {
         input: GetDeploymentInput,
     ) -> Result<GetDeploymentOutput, RusotoError<GetDeploymentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDeployment");
         request.add_header("x-amz-target", "CodeDeploy_20141006.GetDeployment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7191,6 +8281,7 @@ impl CodeDeploy for CodeDeployClient {
         input: GetDeploymentConfigInput,
     ) -> Result<GetDeploymentConfigOutput, RusotoError<GetDeploymentConfigError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDeploymentConfig");
         request.add_header("x-amz-target", "CodeDeploy_20141006.GetDeploymentConfig");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7209,6 +8300,7 @@ impl CodeDeploy for CodeDeployClient {
         input: GetDeploymentGroupInput,
     ) -> Result<GetDeploymentGroupOutput, RusotoError<GetDeploymentGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDeploymentGroup");
         request.add_header("x-amz-target", "CodeDeploy_20141006.GetDeploymentGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7227,6 +8319,7 @@ impl CodeDeploy for CodeDeployClient {
         input: GetDeploymentInstanceInput,
     ) -> Result<GetDeploymentInstanceOutput, RusotoError<GetDeploymentInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDeploymentInstance");
         request.add_header("x-amz-target", "CodeDeploy_20141006.GetDeploymentInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7245,6 +8338,7 @@ impl CodeDeploy for CodeDeployClient {
         input: GetDeploymentTargetInput,
     ) -> Result<GetDeploymentTargetOutput, RusotoError<GetDeploymentTargetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDeploymentTarget");
         request.add_header("x-amz-target", "CodeDeploy_20141006.GetDeploymentTarget");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7263,6 +8357,7 @@ impl CodeDeploy for CodeDeployClient {
         input: GetOnPremisesInstanceInput,
     ) -> Result<GetOnPremisesInstanceOutput, RusotoError<GetOnPremisesInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetOnPremisesInstance");
         request.add_header("x-amz-target", "CodeDeploy_20141006.GetOnPremisesInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7281,6 +8376,7 @@ impl CodeDeploy for CodeDeployClient {
         input: ListApplicationRevisionsInput,
     ) -> Result<ListApplicationRevisionsOutput, RusotoError<ListApplicationRevisionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListApplicationRevisions");
         request.add_header(
             "x-amz-target",
             "CodeDeploy_20141006.ListApplicationRevisions",
@@ -7303,6 +8399,7 @@ impl CodeDeploy for CodeDeployClient {
         input: ListApplicationsInput,
     ) -> Result<ListApplicationsOutput, RusotoError<ListApplicationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListApplications");
         request.add_header("x-amz-target", "CodeDeploy_20141006.ListApplications");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7321,6 +8418,7 @@ impl CodeDeploy for CodeDeployClient {
         input: ListDeploymentConfigsInput,
     ) -> Result<ListDeploymentConfigsOutput, RusotoError<ListDeploymentConfigsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListDeploymentConfigs");
         request.add_header("x-amz-target", "CodeDeploy_20141006.ListDeploymentConfigs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7339,6 +8437,7 @@ impl CodeDeploy for CodeDeployClient {
         input: ListDeploymentGroupsInput,
     ) -> Result<ListDeploymentGroupsOutput, RusotoError<ListDeploymentGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListDeploymentGroups");
         request.add_header("x-amz-target", "CodeDeploy_20141006.ListDeploymentGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7357,6 +8456,7 @@ impl CodeDeploy for CodeDeployClient {
         input: ListDeploymentInstancesInput,
     ) -> Result<ListDeploymentInstancesOutput, RusotoError<ListDeploymentInstancesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListDeploymentInstances");
         request.add_header(
             "x-amz-target",
             "CodeDeploy_20141006.ListDeploymentInstances",
@@ -7379,6 +8479,7 @@ impl CodeDeploy for CodeDeployClient {
         input: ListDeploymentTargetsInput,
     ) -> Result<ListDeploymentTargetsOutput, RusotoError<ListDeploymentTargetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListDeploymentTargets");
         request.add_header("x-amz-target", "CodeDeploy_20141006.ListDeploymentTargets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7397,6 +8498,7 @@ impl CodeDeploy for CodeDeployClient {
         input: ListDeploymentsInput,
     ) -> Result<ListDeploymentsOutput, RusotoError<ListDeploymentsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListDeployments");
         request.add_header("x-amz-target", "CodeDeploy_20141006.ListDeployments");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7416,6 +8518,7 @@ impl CodeDeploy for CodeDeployClient {
     ) -> Result<ListGitHubAccountTokenNamesOutput, RusotoError<ListGitHubAccountTokenNamesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListGitHubAccountTokenNames");
         request.add_header(
             "x-amz-target",
             "CodeDeploy_20141006.ListGitHubAccountTokenNames",
@@ -7438,6 +8541,7 @@ impl CodeDeploy for CodeDeployClient {
         input: ListOnPremisesInstancesInput,
     ) -> Result<ListOnPremisesInstancesOutput, RusotoError<ListOnPremisesInstancesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListOnPremisesInstances");
         request.add_header(
             "x-amz-target",
             "CodeDeploy_20141006.ListOnPremisesInstances",
@@ -7460,6 +8564,7 @@ impl CodeDeploy for CodeDeployClient {
         input: ListTagsForResourceInput,
     ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header("x-amz-target", "CodeDeploy_20141006.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7481,6 +8586,7 @@ impl CodeDeploy for CodeDeployClient {
         RusotoError<PutLifecycleEventHookExecutionStatusError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutLifecycleEventHookExecutionStatus");
         request.add_header(
             "x-amz-target",
             "CodeDeploy_20141006.PutLifecycleEventHookExecutionStatus",
@@ -7506,6 +8612,7 @@ impl CodeDeploy for CodeDeployClient {
         input: RegisterApplicationRevisionInput,
     ) -> Result<(), RusotoError<RegisterApplicationRevisionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RegisterApplicationRevision");
         request.add_header(
             "x-amz-target",
             "CodeDeploy_20141006.RegisterApplicationRevision",
@@ -7526,6 +8633,7 @@ impl CodeDeploy for CodeDeployClient {
         input: RegisterOnPremisesInstanceInput,
     ) -> Result<(), RusotoError<RegisterOnPremisesInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RegisterOnPremisesInstance");
         request.add_header(
             "x-amz-target",
             "CodeDeploy_20141006.RegisterOnPremisesInstance",
@@ -7546,6 +8654,7 @@ impl CodeDeploy for CodeDeployClient {
         input: RemoveTagsFromOnPremisesInstancesInput,
     ) -> Result<(), RusotoError<RemoveTagsFromOnPremisesInstancesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RemoveTagsFromOnPremisesInstances");
         request.add_header(
             "x-amz-target",
             "CodeDeploy_20141006.RemoveTagsFromOnPremisesInstances",
@@ -7569,6 +8678,7 @@ impl CodeDeploy for CodeDeployClient {
         input: SkipWaitTimeForInstanceTerminationInput,
     ) -> Result<(), RusotoError<SkipWaitTimeForInstanceTerminationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SkipWaitTimeForInstanceTermination");
         request.add_header(
             "x-amz-target",
             "CodeDeploy_20141006.SkipWaitTimeForInstanceTermination",
@@ -7592,6 +8702,7 @@ impl CodeDeploy for CodeDeployClient {
         input: StopDeploymentInput,
     ) -> Result<StopDeploymentOutput, RusotoError<StopDeploymentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopDeployment");
         request.add_header("x-amz-target", "CodeDeploy_20141006.StopDeployment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7610,6 +8721,7 @@ impl CodeDeploy for CodeDeployClient {
         input: TagResourceInput,
     ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "CodeDeploy_20141006.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7628,6 +8740,7 @@ impl CodeDeploy for CodeDeployClient {
         input: UntagResourceInput,
     ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "CodeDeploy_20141006.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7646,6 +8759,7 @@ impl CodeDeploy for CodeDeployClient {
         input: UpdateApplicationInput,
     ) -> Result<(), RusotoError<UpdateApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateApplication");
         request.add_header("x-amz-target", "CodeDeploy_20141006.UpdateApplication");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7663,6 +8777,7 @@ impl CodeDeploy for CodeDeployClient {
         input: UpdateDeploymentGroupInput,
     ) -> Result<UpdateDeploymentGroupOutput, RusotoError<UpdateDeploymentGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateDeploymentGroup");
         request.add_header("x-amz-target", "CodeDeploy_20141006.UpdateDeploymentGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7675,3 +8790,498 @@ impl CodeDeploy for CodeDeployClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateDeploymentGroupOutput, _>()
     }
 }
+
+/// A blocking client for the CodeDeploy API, running the calls of a `CodeDeployClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `CodeDeployClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CodeDeployBlockingClient {
+    client: CodeDeployClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CodeDeployBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> CodeDeployBlockingClient {
+        CodeDeployBlockingClient::from_client(CodeDeployClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CodeDeployBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CodeDeployBlockingClient::from_client(CodeDeployClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> CodeDeployBlockingClient {
+        CodeDeployBlockingClient::from_client(CodeDeployClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> CodeDeployBlockingClient {
+        CodeDeployBlockingClient::from_client(CodeDeployClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: CodeDeployClient) -> CodeDeployBlockingClient {
+        CodeDeployBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> CodeDeployBlockingClient {
+        CodeDeployBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CodeDeployBlockingClient {
+        CodeDeployBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`CodeDeploy::add_tags_to_on_premises_instances`].
+    pub fn add_tags_to_on_premises_instances(
+        &self,
+        input: AddTagsToOnPremisesInstancesInput,
+    ) -> Result<(), RusotoError<AddTagsToOnPremisesInstancesError>> {
+        self.runtime
+            .block_on(self.client.add_tags_to_on_premises_instances(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::batch_get_application_revisions`].
+    pub fn batch_get_application_revisions(
+        &self,
+        input: BatchGetApplicationRevisionsInput,
+    ) -> Result<BatchGetApplicationRevisionsOutput, RusotoError<BatchGetApplicationRevisionsError>>
+    {
+        self.runtime
+            .block_on(self.client.batch_get_application_revisions(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::batch_get_applications`].
+    pub fn batch_get_applications(
+        &self,
+        input: BatchGetApplicationsInput,
+    ) -> Result<BatchGetApplicationsOutput, RusotoError<BatchGetApplicationsError>> {
+        self.runtime
+            .block_on(self.client.batch_get_applications(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::batch_get_deployment_groups`].
+    pub fn batch_get_deployment_groups(
+        &self,
+        input: BatchGetDeploymentGroupsInput,
+    ) -> Result<BatchGetDeploymentGroupsOutput, RusotoError<BatchGetDeploymentGroupsError>> {
+        self.runtime
+            .block_on(self.client.batch_get_deployment_groups(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::batch_get_deployment_instances`].
+    pub fn batch_get_deployment_instances(
+        &self,
+        input: BatchGetDeploymentInstancesInput,
+    ) -> Result<BatchGetDeploymentInstancesOutput, RusotoError<BatchGetDeploymentInstancesError>>
+    {
+        self.runtime
+            .block_on(self.client.batch_get_deployment_instances(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::batch_get_deployment_targets`].
+    pub fn batch_get_deployment_targets(
+        &self,
+        input: BatchGetDeploymentTargetsInput,
+    ) -> Result<BatchGetDeploymentTargetsOutput, RusotoError<BatchGetDeploymentTargetsError>> {
+        self.runtime
+            .block_on(self.client.batch_get_deployment_targets(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::batch_get_deployments`].
+    pub fn batch_get_deployments(
+        &self,
+        input: BatchGetDeploymentsInput,
+    ) -> Result<BatchGetDeploymentsOutput, RusotoError<BatchGetDeploymentsError>> {
+        self.runtime
+            .block_on(self.client.batch_get_deployments(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::batch_get_on_premises_instances`].
+    pub fn batch_get_on_premises_instances(
+        &self,
+        input: BatchGetOnPremisesInstancesInput,
+    ) -> Result<BatchGetOnPremisesInstancesOutput, RusotoError<BatchGetOnPremisesInstancesError>>
+    {
+        self.runtime
+            .block_on(self.client.batch_get_on_premises_instances(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::continue_deployment`].
+    pub fn continue_deployment(
+        &self,
+        input: ContinueDeploymentInput,
+    ) -> Result<(), RusotoError<ContinueDeploymentError>> {
+        self.runtime
+            .block_on(self.client.continue_deployment(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::create_application`].
+    pub fn create_application(
+        &self,
+        input: CreateApplicationInput,
+    ) -> Result<CreateApplicationOutput, RusotoError<CreateApplicationError>> {
+        self.runtime.block_on(self.client.create_application(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::create_deployment`].
+    pub fn create_deployment(
+        &self,
+        input: CreateDeploymentInput,
+    ) -> Result<CreateDeploymentOutput, RusotoError<CreateDeploymentError>> {
+        self.runtime.block_on(self.client.create_deployment(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::create_deployment_config`].
+    pub fn create_deployment_config(
+        &self,
+        input: CreateDeploymentConfigInput,
+    ) -> Result<CreateDeploymentConfigOutput, RusotoError<CreateDeploymentConfigError>> {
+        self.runtime
+            .block_on(self.client.create_deployment_config(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::create_deployment_group`].
+    pub fn create_deployment_group(
+        &self,
+        input: CreateDeploymentGroupInput,
+    ) -> Result<CreateDeploymentGroupOutput, RusotoError<CreateDeploymentGroupError>> {
+        self.runtime
+            .block_on(self.client.create_deployment_group(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::delete_application`].
+    pub fn delete_application(
+        &self,
+        input: DeleteApplicationInput,
+    ) -> Result<(), RusotoError<DeleteApplicationError>> {
+        self.runtime.block_on(self.client.delete_application(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::delete_deployment_config`].
+    pub fn delete_deployment_config(
+        &self,
+        input: DeleteDeploymentConfigInput,
+    ) -> Result<(), RusotoError<DeleteDeploymentConfigError>> {
+        self.runtime
+            .block_on(self.client.delete_deployment_config(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::delete_deployment_group`].
+    pub fn delete_deployment_group(
+        &self,
+        input: DeleteDeploymentGroupInput,
+    ) -> Result<DeleteDeploymentGroupOutput, RusotoError<DeleteDeploymentGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_deployment_group(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::delete_git_hub_account_token`].
+    pub fn delete_git_hub_account_token(
+        &self,
+        input: DeleteGitHubAccountTokenInput,
+    ) -> Result<DeleteGitHubAccountTokenOutput, RusotoError<DeleteGitHubAccountTokenError>> {
+        self.runtime
+            .block_on(self.client.delete_git_hub_account_token(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::delete_resources_by_external_id`].
+    pub fn delete_resources_by_external_id(
+        &self,
+        input: DeleteResourcesByExternalIdInput,
+    ) -> Result<DeleteResourcesByExternalIdOutput, RusotoError<DeleteResourcesByExternalIdError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_resources_by_external_id(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::deregister_on_premises_instance`].
+    pub fn deregister_on_premises_instance(
+        &self,
+        input: DeregisterOnPremisesInstanceInput,
+    ) -> Result<(), RusotoError<DeregisterOnPremisesInstanceError>> {
+        self.runtime
+            .block_on(self.client.deregister_on_premises_instance(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::get_application`].
+    pub fn get_application(
+        &self,
+        input: GetApplicationInput,
+    ) -> Result<GetApplicationOutput, RusotoError<GetApplicationError>> {
+        self.runtime.block_on(self.client.get_application(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::get_application_revision`].
+    pub fn get_application_revision(
+        &self,
+        input: GetApplicationRevisionInput,
+    ) -> Result<GetApplicationRevisionOutput, RusotoError<GetApplicationRevisionError>> {
+        self.runtime
+            .block_on(self.client.get_application_revision(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::get_deployment`].
+    pub fn get_deployment(
+        &self,
+        input: GetDeploymentInput,
+    ) -> Result<GetDeploymentOutput, RusotoError<GetDeploymentError>> {
+        self.runtime.block_on(self.client.get_deployment(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::get_deployment_config`].
+    pub fn get_deployment_config(
+        &self,
+        input: GetDeploymentConfigInput,
+    ) -> Result<GetDeploymentConfigOutput, RusotoError<GetDeploymentConfigError>> {
+        self.runtime
+            .block_on(self.client.get_deployment_config(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::get_deployment_group`].
+    pub fn get_deployment_group(
+        &self,
+        input: GetDeploymentGroupInput,
+    ) -> Result<GetDeploymentGroupOutput, RusotoError<GetDeploymentGroupError>> {
+        self.runtime
+            .block_on(self.client.get_deployment_group(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::get_deployment_instance`].
+    pub fn get_deployment_instance(
+        &self,
+        input: GetDeploymentInstanceInput,
+    ) -> Result<GetDeploymentInstanceOutput, RusotoError<GetDeploymentInstanceError>> {
+        self.runtime
+            .block_on(self.client.get_deployment_instance(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::get_deployment_target`].
+    pub fn get_deployment_target(
+        &self,
+        input: GetDeploymentTargetInput,
+    ) -> Result<GetDeploymentTargetOutput, RusotoError<GetDeploymentTargetError>> {
+        self.runtime
+            .block_on(self.client.get_deployment_target(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::get_on_premises_instance`].
+    pub fn get_on_premises_instance(
+        &self,
+        input: GetOnPremisesInstanceInput,
+    ) -> Result<GetOnPremisesInstanceOutput, RusotoError<GetOnPremisesInstanceError>> {
+        self.runtime
+            .block_on(self.client.get_on_premises_instance(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::list_application_revisions`].
+    pub fn list_application_revisions(
+        &self,
+        input: ListApplicationRevisionsInput,
+    ) -> Result<ListApplicationRevisionsOutput, RusotoError<ListApplicationRevisionsError>> {
+        self.runtime
+            .block_on(self.client.list_application_revisions(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::list_applications`].
+    pub fn list_applications(
+        &self,
+        input: ListApplicationsInput,
+    ) -> Result<ListApplicationsOutput, RusotoError<ListApplicationsError>> {
+        self.runtime.block_on(self.client.list_applications(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::list_deployment_configs`].
+    pub fn list_deployment_configs(
+        &self,
+        input: ListDeploymentConfigsInput,
+    ) -> Result<ListDeploymentConfigsOutput, RusotoError<ListDeploymentConfigsError>> {
+        self.runtime
+            .block_on(self.client.list_deployment_configs(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::list_deployment_groups`].
+    pub fn list_deployment_groups(
+        &self,
+        input: ListDeploymentGroupsInput,
+    ) -> Result<ListDeploymentGroupsOutput, RusotoError<ListDeploymentGroupsError>> {
+        self.runtime
+            .block_on(self.client.list_deployment_groups(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::list_deployment_instances`].
+    pub fn list_deployment_instances(
+        &self,
+        input: ListDeploymentInstancesInput,
+    ) -> Result<ListDeploymentInstancesOutput, RusotoError<ListDeploymentInstancesError>> {
+        self.runtime
+            .block_on(self.client.list_deployment_instances(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::list_deployment_targets`].
+    pub fn list_deployment_targets(
+        &self,
+        input: ListDeploymentTargetsInput,
+    ) -> Result<ListDeploymentTargetsOutput, RusotoError<ListDeploymentTargetsError>> {
+        self.runtime
+            .block_on(self.client.list_deployment_targets(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::list_deployments`].
+    pub fn list_deployments(
+        &self,
+        input: ListDeploymentsInput,
+    ) -> Result<ListDeploymentsOutput, RusotoError<ListDeploymentsError>> {
+        self.runtime.block_on(self.client.list_deployments(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::list_git_hub_account_token_names`].
+    pub fn list_git_hub_account_token_names(
+        &self,
+        input: ListGitHubAccountTokenNamesInput,
+    ) -> Result<ListGitHubAccountTokenNamesOutput, RusotoError<ListGitHubAccountTokenNamesError>>
+    {
+        self.runtime
+            .block_on(self.client.list_git_hub_account_token_names(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::list_on_premises_instances`].
+    pub fn list_on_premises_instances(
+        &self,
+        input: ListOnPremisesInstancesInput,
+    ) -> Result<ListOnPremisesInstancesOutput, RusotoError<ListOnPremisesInstancesError>> {
+        self.runtime
+            .block_on(self.client.list_on_premises_instances(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::put_lifecycle_event_hook_execution_status`].
+    pub fn put_lifecycle_event_hook_execution_status(
+        &self,
+        input: PutLifecycleEventHookExecutionStatusInput,
+    ) -> Result<
+        PutLifecycleEventHookExecutionStatusOutput,
+        RusotoError<PutLifecycleEventHookExecutionStatusError>,
+    > {
+        self.runtime
+            .block_on(self.client.put_lifecycle_event_hook_execution_status(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::register_application_revision`].
+    pub fn register_application_revision(
+        &self,
+        input: RegisterApplicationRevisionInput,
+    ) -> Result<(), RusotoError<RegisterApplicationRevisionError>> {
+        self.runtime
+            .block_on(self.client.register_application_revision(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::register_on_premises_instance`].
+    pub fn register_on_premises_instance(
+        &self,
+        input: RegisterOnPremisesInstanceInput,
+    ) -> Result<(), RusotoError<RegisterOnPremisesInstanceError>> {
+        self.runtime
+            .block_on(self.client.register_on_premises_instance(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::remove_tags_from_on_premises_instances`].
+    pub fn remove_tags_from_on_premises_instances(
+        &self,
+        input: RemoveTagsFromOnPremisesInstancesInput,
+    ) -> Result<(), RusotoError<RemoveTagsFromOnPremisesInstancesError>> {
+        self.runtime
+            .block_on(self.client.remove_tags_from_on_premises_instances(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::skip_wait_time_for_instance_termination`].
+    pub fn skip_wait_time_for_instance_termination(
+        &self,
+        input: SkipWaitTimeForInstanceTerminationInput,
+    ) -> Result<(), RusotoError<SkipWaitTimeForInstanceTerminationError>> {
+        self.runtime
+            .block_on(self.client.skip_wait_time_for_instance_termination(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::stop_deployment`].
+    pub fn stop_deployment(
+        &self,
+        input: StopDeploymentInput,
+    ) -> Result<StopDeploymentOutput, RusotoError<StopDeploymentError>> {
+        self.runtime.block_on(self.client.stop_deployment(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::update_application`].
+    pub fn update_application(
+        &self,
+        input: UpdateApplicationInput,
+    ) -> Result<(), RusotoError<UpdateApplicationError>> {
+        self.runtime.block_on(self.client.update_application(input))
+    }
+
+    /// Blocking version of [`CodeDeploy::update_deployment_group`].
+    pub fn update_deployment_group(
+        &self,
+        input: UpdateDeploymentGroupInput,
+    ) -> Result<UpdateDeploymentGroupOutput, RusotoError<UpdateDeploymentGroupError>> {
+        self.runtime
+            .block_on(self.client.update_deployment_group(input))
+    }
+}
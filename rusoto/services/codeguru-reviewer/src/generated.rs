@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -788,18 +789,38 @@ impl AssociateRepositoryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AssociateRepositoryError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateRepositoryError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(AssociateRepositoryError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateRepositoryError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(AssociateRepositoryError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateRepositoryError::InternalServer,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AssociateRepositoryError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateRepositoryError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -838,21 +859,46 @@ impl CreateCodeReviewError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateCodeReviewError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCodeReviewError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateCodeReviewError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCodeReviewError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateCodeReviewError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCodeReviewError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateCodeReviewError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCodeReviewError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateCodeReviewError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCodeReviewError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -890,18 +936,38 @@ impl DescribeCodeReviewError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeCodeReviewError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCodeReviewError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeCodeReviewError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCodeReviewError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeCodeReviewError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCodeReviewError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeCodeReviewError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCodeReviewError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -940,26 +1006,38 @@ impl DescribeRecommendationFeedbackError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeRecommendationFeedbackError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRecommendationFeedbackError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
-                        DescribeRecommendationFeedbackError::InternalServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRecommendationFeedbackError::InternalServer,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeRecommendationFeedbackError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRecommendationFeedbackError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeRecommendationFeedbackError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRecommendationFeedbackError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1002,26 +1080,38 @@ impl DescribeRepositoryAssociationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeRepositoryAssociationError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRepositoryAssociationError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
-                        DescribeRepositoryAssociationError::InternalServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRepositoryAssociationError::InternalServer,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeRepositoryAssociationError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRepositoryAssociationError::NotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeRepositoryAssociationError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRepositoryAssociationError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1060,23 +1150,46 @@ impl DisassociateRepositoryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DisassociateRepositoryError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateRepositoryError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DisassociateRepositoryError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateRepositoryError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DisassociateRepositoryError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateRepositoryError::InternalServer,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DisassociateRepositoryError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateRepositoryError::NotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DisassociateRepositoryError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateRepositoryError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1112,15 +1225,30 @@ impl ListCodeReviewsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListCodeReviewsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCodeReviewsError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListCodeReviewsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCodeReviewsError::InternalServer,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListCodeReviewsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCodeReviewsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1158,26 +1286,38 @@ impl ListRecommendationFeedbackError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListRecommendationFeedbackError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListRecommendationFeedbackError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListRecommendationFeedbackError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListRecommendationFeedbackError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListRecommendationFeedbackError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListRecommendationFeedbackError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListRecommendationFeedbackError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListRecommendationFeedbackError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1214,20 +1354,38 @@ impl ListRecommendationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListRecommendationsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRecommendationsError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListRecommendationsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRecommendationsError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListRecommendationsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListRecommendationsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListRecommendationsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRecommendationsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1262,16 +1420,22 @@ impl ListRepositoryAssociationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListRepositoryAssociationsError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListRepositoryAssociationsError::InternalServer,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListRepositoryAssociationsError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListRepositoryAssociationsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1302,14 +1466,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1344,26 +1516,38 @@ impl PutRecommendationFeedbackError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(PutRecommendationFeedbackError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutRecommendationFeedbackError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(PutRecommendationFeedbackError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutRecommendationFeedbackError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutRecommendationFeedbackError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutRecommendationFeedbackError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(PutRecommendationFeedbackError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutRecommendationFeedbackError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1396,12 +1580,22 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(TagResourceError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1432,12 +1626,22 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1557,11 +1761,12 @@ pub struct CodeGuruReviewerClient {
 impl CodeGuruReviewerClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> CodeGuruReviewerClient {
         CodeGuruReviewerClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "CodeGuru Reviewer"),
         }
     }
 
@@ -1576,12 +1781,32 @@ impl CodeGuruReviewerClient {
     {
         CodeGuruReviewerClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "CodeGuru Reviewer"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CodeGuruReviewerClient {
-        CodeGuruReviewerClient { client, region }
+        CodeGuruReviewerClient {
+            client,
+            region: region_for_service(region, "CodeGuru Reviewer"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> CodeGuruReviewerClient {
+        CodeGuruReviewerClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("CodeGuru Reviewer"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CodeGuruReviewerClient {
+        CodeGuruReviewerClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -1597,6 +1822,7 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
 
         let mut request =
             SignedRequest::new("POST", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation("AssociateRepository");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1629,6 +1855,7 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
 
         let mut request =
             SignedRequest::new("POST", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation("CreateCodeReview");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1664,6 +1891,7 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation("DescribeCodeReview");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1699,6 +1927,7 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation("DescribeRecommendationFeedback");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1741,6 +1970,7 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation("DescribeRepositoryAssociation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1773,6 +2003,7 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation("DisassociateRepository");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1802,6 +2033,7 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation("ListCodeReviews");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1860,6 +2092,7 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation("ListRecommendationFeedback");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1911,6 +2144,7 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation("ListRecommendations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1950,6 +2184,7 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation("ListRepositoryAssociations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2008,6 +2243,7 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2038,6 +2274,7 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
 
         let mut request =
             SignedRequest::new("PUT", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation("PutRecommendationFeedback");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2070,6 +2307,7 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
 
         let mut request =
             SignedRequest::new("POST", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2102,6 +2340,7 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "codeguru-reviewer", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2127,3 +2366,217 @@ impl CodeGuruReviewer for CodeGuruReviewerClient {
         }
     }
 }
+
+/// A blocking client for the CodeGuruReviewer API, running the calls of a `CodeGuruReviewerClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `CodeGuruReviewerClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CodeGuruReviewerBlockingClient {
+    client: CodeGuruReviewerClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CodeGuruReviewerBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> CodeGuruReviewerBlockingClient {
+        CodeGuruReviewerBlockingClient::from_client(CodeGuruReviewerClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CodeGuruReviewerBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CodeGuruReviewerBlockingClient::from_client(CodeGuruReviewerClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> CodeGuruReviewerBlockingClient {
+        CodeGuruReviewerBlockingClient::from_client(CodeGuruReviewerClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> CodeGuruReviewerBlockingClient {
+        CodeGuruReviewerBlockingClient::from_client(CodeGuruReviewerClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: CodeGuruReviewerClient) -> CodeGuruReviewerBlockingClient {
+        CodeGuruReviewerBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> CodeGuruReviewerBlockingClient {
+        CodeGuruReviewerBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CodeGuruReviewerBlockingClient {
+        CodeGuruReviewerBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`CodeGuruReviewer::associate_repository`].
+    pub fn associate_repository(
+        &self,
+        input: AssociateRepositoryRequest,
+    ) -> Result<AssociateRepositoryResponse, RusotoError<AssociateRepositoryError>> {
+        self.runtime
+            .block_on(self.client.associate_repository(input))
+    }
+
+    /// Blocking version of [`CodeGuruReviewer::create_code_review`].
+    pub fn create_code_review(
+        &self,
+        input: CreateCodeReviewRequest,
+    ) -> Result<CreateCodeReviewResponse, RusotoError<CreateCodeReviewError>> {
+        self.runtime.block_on(self.client.create_code_review(input))
+    }
+
+    /// Blocking version of [`CodeGuruReviewer::describe_code_review`].
+    pub fn describe_code_review(
+        &self,
+        input: DescribeCodeReviewRequest,
+    ) -> Result<DescribeCodeReviewResponse, RusotoError<DescribeCodeReviewError>> {
+        self.runtime
+            .block_on(self.client.describe_code_review(input))
+    }
+
+    /// Blocking version of [`CodeGuruReviewer::describe_recommendation_feedback`].
+    pub fn describe_recommendation_feedback(
+        &self,
+        input: DescribeRecommendationFeedbackRequest,
+    ) -> Result<
+        DescribeRecommendationFeedbackResponse,
+        RusotoError<DescribeRecommendationFeedbackError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_recommendation_feedback(input))
+    }
+
+    /// Blocking version of [`CodeGuruReviewer::describe_repository_association`].
+    pub fn describe_repository_association(
+        &self,
+        input: DescribeRepositoryAssociationRequest,
+    ) -> Result<
+        DescribeRepositoryAssociationResponse,
+        RusotoError<DescribeRepositoryAssociationError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_repository_association(input))
+    }
+
+    /// Blocking version of [`CodeGuruReviewer::disassociate_repository`].
+    pub fn disassociate_repository(
+        &self,
+        input: DisassociateRepositoryRequest,
+    ) -> Result<DisassociateRepositoryResponse, RusotoError<DisassociateRepositoryError>> {
+        self.runtime
+            .block_on(self.client.disassociate_repository(input))
+    }
+
+    /// Blocking version of [`CodeGuruReviewer::list_code_reviews`].
+    pub fn list_code_reviews(
+        &self,
+        input: ListCodeReviewsRequest,
+    ) -> Result<ListCodeReviewsResponse, RusotoError<ListCodeReviewsError>> {
+        self.runtime.block_on(self.client.list_code_reviews(input))
+    }
+
+    /// Blocking version of [`CodeGuruReviewer::list_recommendation_feedback`].
+    pub fn list_recommendation_feedback(
+        &self,
+        input: ListRecommendationFeedbackRequest,
+    ) -> Result<ListRecommendationFeedbackResponse, RusotoError<ListRecommendationFeedbackError>>
+    {
+        self.runtime
+            .block_on(self.client.list_recommendation_feedback(input))
+    }
+
+    /// Blocking version of [`CodeGuruReviewer::list_recommendations`].
+    pub fn list_recommendations(
+        &self,
+        input: ListRecommendationsRequest,
+    ) -> Result<ListRecommendationsResponse, RusotoError<ListRecommendationsError>> {
+        self.runtime
+            .block_on(self.client.list_recommendations(input))
+    }
+
+    /// Blocking version of [`CodeGuruReviewer::list_repository_associations`].
+    pub fn list_repository_associations(
+        &self,
+        input: ListRepositoryAssociationsRequest,
+    ) -> Result<ListRepositoryAssociationsResponse, RusotoError<ListRepositoryAssociationsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_repository_associations(input))
+    }
+
+    /// Blocking version of [`CodeGuruReviewer::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`CodeGuruReviewer::put_recommendation_feedback`].
+    pub fn put_recommendation_feedback(
+        &self,
+        input: PutRecommendationFeedbackRequest,
+    ) -> Result<PutRecommendationFeedbackResponse, RusotoError<PutRecommendationFeedbackError>>
+    {
+        self.runtime
+            .block_on(self.client.put_recommendation_feedback(input))
+    }
+
+    /// Blocking version of [`CodeGuruReviewer::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`CodeGuruReviewer::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+}
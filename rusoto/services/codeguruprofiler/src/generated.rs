@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1004,27 +1005,46 @@ impl AddNotificationChannelsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(AddNotificationChannelsError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddNotificationChannelsError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(AddNotificationChannelsError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddNotificationChannelsError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddNotificationChannelsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddNotificationChannelsError::ResourceNotFound,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(
-                        AddNotificationChannelsError::ServiceQuotaExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddNotificationChannelsError::ServiceQuotaExceeded,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AddNotificationChannelsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddNotificationChannelsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1060,19 +1080,30 @@ impl BatchGetFrameMetricDataError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(BatchGetFrameMetricDataError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchGetFrameMetricDataError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(BatchGetFrameMetricDataError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchGetFrameMetricDataError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(BatchGetFrameMetricDataError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetFrameMetricDataError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1106,15 +1137,30 @@ impl ConfigureAgentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ConfigureAgentError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfigureAgentError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ConfigureAgentError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfigureAgentError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ConfigureAgentError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfigureAgentError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1150,20 +1196,38 @@ impl CreateProfilingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(CreateProfilingGroupError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProfilingGroupError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateProfilingGroupError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProfilingGroupError::InternalServer,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateProfilingGroupError::ServiceQuotaExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateProfilingGroupError::ServiceQuotaExceeded,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateProfilingGroupError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProfilingGroupError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1198,17 +1262,30 @@ impl DeleteProfilingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteProfilingGroupError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProfilingGroupError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteProfilingGroupError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteProfilingGroupError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteProfilingGroupError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProfilingGroupError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1242,19 +1319,30 @@ impl DescribeProfilingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeProfilingGroupError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeProfilingGroupError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeProfilingGroupError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeProfilingGroupError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeProfilingGroupError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeProfilingGroupError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1288,16 +1376,22 @@ impl GetFindingsReportAccountSummaryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
-                        GetFindingsReportAccountSummaryError::InternalServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFindingsReportAccountSummaryError::InternalServer,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetFindingsReportAccountSummaryError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetFindingsReportAccountSummaryError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1334,21 +1428,30 @@ impl GetNotificationConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetNotificationConfigurationError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetNotificationConfigurationError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        GetNotificationConfigurationError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetNotificationConfigurationError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetNotificationConfigurationError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetNotificationConfigurationError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1384,15 +1487,30 @@ impl GetPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetPolicyError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPolicyError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetPolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPolicyError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetPolicyError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPolicyError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1426,15 +1544,30 @@ impl GetProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetProfileError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetProfileError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetProfileError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetProfileError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetProfileError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetProfileError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1468,15 +1601,30 @@ impl GetRecommendationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetRecommendationsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRecommendationsError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetRecommendationsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRecommendationsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetRecommendationsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRecommendationsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1510,17 +1658,30 @@ impl ListFindingsReportsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListFindingsReportsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFindingsReportsError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListFindingsReportsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListFindingsReportsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListFindingsReportsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFindingsReportsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1554,15 +1715,30 @@ impl ListProfileTimesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListProfileTimesError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProfileTimesError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListProfileTimesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProfileTimesError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListProfileTimesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProfileTimesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1594,12 +1770,22 @@ impl ListProfilingGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListProfilingGroupsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProfilingGroupsError::InternalServer,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListProfilingGroupsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProfilingGroupsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1630,14 +1816,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1670,15 +1864,30 @@ impl PostAgentProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(PostAgentProfileError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostAgentProfileError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PostAgentProfileError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostAgentProfileError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(PostAgentProfileError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostAgentProfileError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1714,18 +1923,38 @@ impl PutPermissionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(PutPermissionError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutPermissionError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(PutPermissionError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutPermissionError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutPermissionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutPermissionError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(PutPermissionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutPermissionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1760,21 +1989,30 @@ impl RemoveNotificationChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(RemoveNotificationChannelError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveNotificationChannelError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RemoveNotificationChannelError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveNotificationChannelError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RemoveNotificationChannelError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveNotificationChannelError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1810,18 +2048,38 @@ impl RemovePermissionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(RemovePermissionError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemovePermissionError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(RemovePermissionError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemovePermissionError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RemovePermissionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemovePermissionError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RemovePermissionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemovePermissionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1856,15 +2114,30 @@ impl SubmitFeedbackError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(SubmitFeedbackError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SubmitFeedbackError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SubmitFeedbackError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SubmitFeedbackError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SubmitFeedbackError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SubmitFeedbackError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1896,12 +2169,22 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(TagResourceError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1932,12 +2215,22 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1972,20 +2265,38 @@ impl UpdateProfilingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateProfilingGroupError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateProfilingGroupError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateProfilingGroupError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateProfilingGroupError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateProfilingGroupError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateProfilingGroupError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateProfilingGroupError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateProfilingGroupError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2158,11 +2469,12 @@ pub struct CodeGuruProfilerClient {
 impl CodeGuruProfilerClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> CodeGuruProfilerClient {
         CodeGuruProfilerClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "CodeGuruProfiler"),
         }
     }
 
@@ -2177,12 +2489,32 @@ impl CodeGuruProfilerClient {
     {
         CodeGuruProfilerClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "CodeGuruProfiler"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CodeGuruProfilerClient {
-        CodeGuruProfilerClient { client, region }
+        CodeGuruProfilerClient {
+            client,
+            region: region_for_service(region, "CodeGuruProfiler"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> CodeGuruProfilerClient {
+        CodeGuruProfilerClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("CodeGuruProfiler"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CodeGuruProfilerClient {
+        CodeGuruProfilerClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -2201,6 +2533,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("POST", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("AddNotificationChannels");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2236,6 +2569,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("POST", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("BatchGetFrameMetricData");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2286,6 +2620,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("POST", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("ConfigureAgent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2318,6 +2653,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("POST", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("CreateProfilingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2357,6 +2693,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("DeleteProfilingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2389,6 +2726,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("DescribeProfilingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2421,6 +2759,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("GetFindingsReportAccountSummary");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2468,6 +2807,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("GetNotificationConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2500,6 +2840,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("GetPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2532,6 +2873,8 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("GetProfile");
+        request.set_raw_response(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("Accept", input.accept.as_ref());
@@ -2585,6 +2928,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("GetRecommendations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2625,6 +2969,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("ListFindingsReports");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2671,6 +3016,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("ListProfileTimes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2715,6 +3061,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("ListProfilingGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2756,6 +3103,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("GET", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2788,6 +3136,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("POST", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("PostAgentProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.agent_profile.to_owned());
@@ -2830,6 +3179,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("PUT", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("PutPermission");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2867,6 +3217,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("RemoveNotificationChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2900,6 +3251,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("RemovePermission");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2933,6 +3285,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("POST", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("SubmitFeedback");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2965,6 +3318,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("POST", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2997,6 +3351,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3035,6 +3390,7 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
 
         let mut request =
             SignedRequest::new("PUT", "codeguru-profiler", &self.region, &request_uri);
+        request.set_operation("UpdateProfilingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3057,3 +3413,288 @@ impl CodeGuruProfiler for CodeGuruProfilerClient {
         }
     }
 }
+
+/// A blocking client for the Amazon CodeGuru Profiler API, running the calls of a `CodeGuruProfilerClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `CodeGuruProfilerClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CodeGuruProfilerBlockingClient {
+    client: CodeGuruProfilerClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CodeGuruProfilerBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> CodeGuruProfilerBlockingClient {
+        CodeGuruProfilerBlockingClient::from_client(CodeGuruProfilerClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CodeGuruProfilerBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CodeGuruProfilerBlockingClient::from_client(CodeGuruProfilerClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> CodeGuruProfilerBlockingClient {
+        CodeGuruProfilerBlockingClient::from_client(CodeGuruProfilerClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> CodeGuruProfilerBlockingClient {
+        CodeGuruProfilerBlockingClient::from_client(CodeGuruProfilerClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: CodeGuruProfilerClient) -> CodeGuruProfilerBlockingClient {
+        CodeGuruProfilerBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> CodeGuruProfilerBlockingClient {
+        CodeGuruProfilerBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CodeGuruProfilerBlockingClient {
+        CodeGuruProfilerBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`CodeGuruProfiler::add_notification_channels`].
+    pub fn add_notification_channels(
+        &self,
+        input: AddNotificationChannelsRequest,
+    ) -> Result<AddNotificationChannelsResponse, RusotoError<AddNotificationChannelsError>> {
+        self.runtime
+            .block_on(self.client.add_notification_channels(input))
+    }
+
+    /// Blocking version of [`CodeGuruProfiler::batch_get_frame_metric_data`].
+    pub fn batch_get_frame_metric_data(
+        &self,
+        input: BatchGetFrameMetricDataRequest,
+    ) -> Result<BatchGetFrameMetricDataResponse, RusotoError<BatchGetFrameMetricDataError>> {
+        self.runtime
+            .block_on(self.client.batch_get_frame_metric_data(input))
+    }
+
+    /// Blocking version of [`CodeGuruProfiler::configure_agent`].
+    pub fn configure_agent(
+        &self,
+        input: ConfigureAgentRequest,
+    ) -> Result<ConfigureAgentResponse, RusotoError<ConfigureAgentError>> {
+        self.runtime.block_on(self.client.configure_agent(input))
+    }
+
+    /// Blocking version of [`CodeGuruProfiler::create_profiling_group`].
+    pub fn create_profiling_group(
+        &self,
+        input: CreateProfilingGroupRequest,
+    ) -> Result<CreateProfilingGroupResponse, RusotoError<CreateProfilingGroupError>> {
+        self.runtime
+            .block_on(self.client.create_profiling_group(input))
+    }
+
+    /// Blocking version of [`CodeGuruProfiler::delete_profiling_group`].
+    pub fn delete_profiling_group(
+        &self,
+        input: DeleteProfilingGroupRequest,
+    ) -> Result<DeleteProfilingGroupResponse, RusotoError<DeleteProfilingGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_profiling_group(input))
+    }
+
+    /// Blocking version of [`CodeGuruProfiler::describe_profiling_group`].
+    pub fn describe_profiling_group(
+        &self,
+        input: DescribeProfilingGroupRequest,
+    ) -> Result<DescribeProfilingGroupResponse, RusotoError<DescribeProfilingGroupError>> {
+        self.runtime
+            .block_on(self.client.describe_profiling_group(input))
+    }
+
+    /// Blocking version of [`CodeGuruProfiler::get_findings_report_account_summary`].
+    pub fn get_findings_report_account_summary(
+        &self,
+        input: GetFindingsReportAccountSummaryRequest,
+    ) -> Result<
+        GetFindingsReportAccountSummaryResponse,
+        RusotoError<GetFindingsReportAccountSummaryError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_findings_report_account_summary(input))
+    }
+
+    /// Blocking version of [`CodeGuruProfiler::get_notification_configuration`].
+    pub fn get_notification_configuration(
+        &self,
+        input: GetNotificationConfigurationRequest,
+    ) -> Result<GetNotificationConfigurationResponse, RusotoError<GetNotificationConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.get_notification_configuration(input))
+    }
+
+    /// Blocking version of [`CodeGuruProfiler::get_policy`].
+    pub fn get_policy(
+        &self,
+        input: GetPolicyRequest,
+    ) -> Result<GetPolicyResponse, RusotoError<GetPolicyError>> {
+        self.runtime.block_on(self.client.get_policy(input))
+    }
+
+    /// Blocking version of [`CodeGuruProfiler::get_profile`].
+    pub fn get_profile(
+        &self,
+        input: GetProfileRequest,
+    ) -> Result<GetProfileResponse, RusotoError<GetProfileError>> {
+        self.runtime.block_on(self.client.get_profile(input))
+    }
+
+    /// Blocking version of [`CodeGuruProfiler::get_recommendations`].
+    pub fn get_recommendations(
+        &self,
+        input: GetRecommendationsRequest,
+    ) -> Result<GetRecommendationsResponse, RusotoError<GetRecommendationsError>> {
+        self.runtime
+            .block_on(self.client.get_recommendations(input))
+    }
+
+    /// Blocking version of [`CodeGuruProfiler::list_findings_reports`].
+    pub fn list_findings_reports(
+        &self,
+        input: ListFindingsReportsRequest,
+    ) -> Result<ListFindingsReportsResponse, RusotoError<ListFindingsReportsError>> {
+        self.runtime
+            .block_on(self.client.list_findings_reports(input))
+    }
+
+    /// Blocking version of [`CodeGuruProfiler::list_profile_times`].
+    pub fn list_profile_times(
+        &self,
+        input: ListProfileTimesRequest,
+    ) -> Result<ListProfileTimesResponse, RusotoError<ListProfileTimesError>> {
+        self.runtime.block_on(self.client.list_profile_times(input))
+    }
+
+    /// Blocking version of [`CodeGuruProfiler::list_profiling_groups`].
+    pub fn list_profiling_groups(
+        &self,
+        input: ListProfilingGroupsRequest,
+    ) -> Result<ListProfilingGroupsResponse, RusotoError<ListProfilingGroupsError>> {
+        self.runtime
+            .block_on(self.client.list_profiling_groups(input))
+    }
+
+    /// Blocking version of [`CodeGuruProfiler::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`CodeGuruProfiler::post_agent_profile`].
+    pub fn post_agent_profile(
+        &self,
+        input: PostAgentProfileRequest,
+    ) -> Result<PostAgentProfileResponse, RusotoError<PostAgentProfileError>> {
+        self.runtime.block_on(self.client.post_agent_profile(input))
+    }
+
+    /// Blocking version of [`CodeGuruProfiler::put_permission`].
+    pub fn put_permission(
+        &self,
+        input: PutPermissionRequest,
+    ) -> Result<PutPermissionResponse, RusotoError<PutPermissionError>> {
+        self.runtime.block_on(self.client.put_permission(input))
+    }
+
+    /// Blocking version of [`CodeGuruProfiler::remove_notification_channel`].
+    pub fn remove_notification_channel(
+        &self,
+        input: RemoveNotificationChannelRequest,
+    ) -> Result<RemoveNotificationChannelResponse, RusotoError<RemoveNotificationChannelError>>
+    {
+        self.runtime
+            .block_on(self.client.remove_notification_channel(input))
+    }
+
+    /// Blocking version of [`CodeGuruProfiler::remove_permission`].
+    pub fn remove_permission(
+        &self,
+        input: RemovePermissionRequest,
+    ) -> Result<RemovePermissionResponse, RusotoError<RemovePermissionError>> {
+        self.runtime.block_on(self.client.remove_permission(input))
+    }
+
+    /// Blocking version of [`CodeGuruProfiler::submit_feedback`].
+    pub fn submit_feedback(
+        &self,
+        input: SubmitFeedbackRequest,
+    ) -> Result<SubmitFeedbackResponse, RusotoError<SubmitFeedbackError>> {
+        self.runtime.block_on(self.client.submit_feedback(input))
+    }
+
+    /// Blocking version of [`CodeGuruProfiler::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`CodeGuruProfiler::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`CodeGuruProfiler::update_profiling_group`].
+    pub fn update_profiling_group(
+        &self,
+        input: UpdateProfilingGroupRequest,
+    ) -> Result<UpdateProfilingGroupResponse, RusotoError<UpdateProfilingGroupError>> {
+        self.runtime
+            .block_on(self.client.update_profiling_group(input))
+    }
+}
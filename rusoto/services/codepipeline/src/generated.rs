@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -2058,12 +2059,22 @@ impl AcknowledgeJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNonceException" => {
-                    return RusotoError::Service(AcknowledgeJobError::InvalidNonce(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcknowledgeJobError::InvalidNonce,
+                    )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(AcknowledgeJobError::JobNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcknowledgeJobError::JobNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2096,21 +2107,30 @@ impl AcknowledgeThirdPartyJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidClientTokenException" => {
-                    return RusotoError::Service(AcknowledgeThirdPartyJobError::InvalidClientToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AcknowledgeThirdPartyJobError::InvalidClientToken,
+                    )
                 }
                 "InvalidNonceException" => {
-                    return RusotoError::Service(AcknowledgeThirdPartyJobError::InvalidNonce(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AcknowledgeThirdPartyJobError::InvalidNonce,
+                    )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(AcknowledgeThirdPartyJobError::JobNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AcknowledgeThirdPartyJobError::JobNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2146,22 +2166,38 @@ impl CreateCustomActionTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        CreateCustomActionTypeError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCustomActionTypeError::ConcurrentModification,
                     )
                 }
                 "InvalidTagsException" => {
-                    return RusotoError::Service(CreateCustomActionTypeError::InvalidTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCustomActionTypeError::InvalidTags,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateCustomActionTypeError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCustomActionTypeError::LimitExceeded,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreateCustomActionTypeError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCustomActionTypeError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2210,41 +2246,78 @@ impl CreatePipelineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreatePipelineError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePipelineError::ConcurrentModification,
+                    )
                 }
                 "InvalidActionDeclarationException" => {
-                    return RusotoError::Service(CreatePipelineError::InvalidActionDeclaration(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePipelineError::InvalidActionDeclaration,
+                    )
                 }
                 "InvalidBlockerDeclarationException" => {
-                    return RusotoError::Service(CreatePipelineError::InvalidBlockerDeclaration(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePipelineError::InvalidBlockerDeclaration,
+                    )
                 }
                 "InvalidStageDeclarationException" => {
-                    return RusotoError::Service(CreatePipelineError::InvalidStageDeclaration(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePipelineError::InvalidStageDeclaration,
+                    )
                 }
                 "InvalidStructureException" => {
-                    return RusotoError::Service(CreatePipelineError::InvalidStructure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePipelineError::InvalidStructure,
+                    )
                 }
                 "InvalidTagsException" => {
-                    return RusotoError::Service(CreatePipelineError::InvalidTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePipelineError::InvalidTags,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreatePipelineError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePipelineError::LimitExceeded,
+                    )
                 }
                 "PipelineNameInUseException" => {
-                    return RusotoError::Service(CreatePipelineError::PipelineNameInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePipelineError::PipelineNameInUse,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreatePipelineError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePipelineError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2280,11 +2353,14 @@ impl DeleteCustomActionTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        DeleteCustomActionTypeError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCustomActionTypeError::ConcurrentModification,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2314,11 +2390,14 @@ impl DeletePipelineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeletePipelineError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeletePipelineError::ConcurrentModification,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2346,11 +2425,14 @@ impl DeleteWebhookError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteWebhookError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteWebhookError::ConcurrentModification,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2380,11 +2462,14 @@ impl DeregisterWebhookWithThirdPartyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WebhookNotFoundException" => {
-                    return RusotoError::Service(
-                        DeregisterWebhookWithThirdPartyError::WebhookNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterWebhookWithThirdPartyError::WebhookNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2416,16 +2501,22 @@ impl DisableStageTransitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(DisableStageTransitionError::PipelineNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisableStageTransitionError::PipelineNotFound,
+                    )
                 }
                 "StageNotFoundException" => {
-                    return RusotoError::Service(DisableStageTransitionError::StageNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisableStageTransitionError::StageNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2456,14 +2547,22 @@ impl EnableStageTransitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(EnableStageTransitionError::PipelineNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        EnableStageTransitionError::PipelineNotFound,
+                    )
                 }
                 "StageNotFoundException" => {
-                    return RusotoError::Service(EnableStageTransitionError::StageNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableStageTransitionError::StageNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2492,9 +2591,14 @@ impl GetJobDetailsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "JobNotFoundException" => {
-                    return RusotoError::Service(GetJobDetailsError::JobNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobDetailsError::JobNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2524,12 +2628,22 @@ impl GetPipelineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(GetPipelineError::PipelineNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPipelineError::PipelineNotFound,
+                    )
                 }
                 "PipelineVersionNotFoundException" => {
-                    return RusotoError::Service(GetPipelineError::PipelineVersionNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPipelineError::PipelineVersionNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2560,16 +2674,22 @@ impl GetPipelineExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "PipelineExecutionNotFoundException" => {
-                    return RusotoError::Service(
-                        GetPipelineExecutionError::PipelineExecutionNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPipelineExecutionError::PipelineExecutionNotFound,
                     )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(GetPipelineExecutionError::PipelineNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetPipelineExecutionError::PipelineNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2600,9 +2720,14 @@ impl GetPipelineStateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(GetPipelineStateError::PipelineNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPipelineStateError::PipelineNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2634,17 +2759,30 @@ impl GetThirdPartyJobDetailsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidClientTokenException" => {
-                    return RusotoError::Service(GetThirdPartyJobDetailsError::InvalidClientToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetThirdPartyJobDetailsError::InvalidClientToken,
+                    )
                 }
                 "InvalidJobException" => {
-                    return RusotoError::Service(GetThirdPartyJobDetailsError::InvalidJob(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetThirdPartyJobDetailsError::InvalidJob,
+                    )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(GetThirdPartyJobDetailsError::JobNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetThirdPartyJobDetailsError::JobNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2678,21 +2816,30 @@ impl ListActionExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListActionExecutionsError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListActionExecutionsError::InvalidNextToken,
+                    )
                 }
                 "PipelineExecutionNotFoundException" => {
-                    return RusotoError::Service(
-                        ListActionExecutionsError::PipelineExecutionNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListActionExecutionsError::PipelineExecutionNotFound,
                     )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(ListActionExecutionsError::PipelineNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListActionExecutionsError::PipelineNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2724,9 +2871,14 @@ impl ListActionTypesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListActionTypesError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListActionTypesError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2756,16 +2908,22 @@ impl ListPipelineExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListPipelineExecutionsError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPipelineExecutionsError::InvalidNextToken,
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(ListPipelineExecutionsError::PipelineNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPipelineExecutionsError::PipelineNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2794,9 +2952,14 @@ impl ListPipelinesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListPipelinesError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPipelinesError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2828,19 +2991,30 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InvalidArn,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::InvalidNextToken,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2870,9 +3044,14 @@ impl ListWebhooksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListWebhooksError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListWebhooksError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2900,9 +3079,14 @@ impl PollForJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ActionTypeNotFoundException" => {
-                    return RusotoError::Service(PollForJobsError::ActionTypeNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PollForJobsError::ActionTypeNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2930,11 +3114,14 @@ impl PollForThirdPartyJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ActionTypeNotFoundException" => {
-                    return RusotoError::Service(PollForThirdPartyJobsError::ActionTypeNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PollForThirdPartyJobsError::ActionTypeNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2966,15 +3153,30 @@ impl PutActionRevisionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ActionNotFoundException" => {
-                    return RusotoError::Service(PutActionRevisionError::ActionNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutActionRevisionError::ActionNotFound,
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(PutActionRevisionError::PipelineNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutActionRevisionError::PipelineNotFound,
+                    )
                 }
                 "StageNotFoundException" => {
-                    return RusotoError::Service(PutActionRevisionError::StageNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutActionRevisionError::StageNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3012,25 +3214,46 @@ impl PutApprovalResultError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ActionNotFoundException" => {
-                    return RusotoError::Service(PutApprovalResultError::ActionNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutApprovalResultError::ActionNotFound,
+                    )
                 }
                 "ApprovalAlreadyCompletedException" => {
-                    return RusotoError::Service(PutApprovalResultError::ApprovalAlreadyCompleted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutApprovalResultError::ApprovalAlreadyCompleted,
+                    )
                 }
                 "InvalidApprovalTokenException" => {
-                    return RusotoError::Service(PutApprovalResultError::InvalidApprovalToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutApprovalResultError::InvalidApprovalToken,
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(PutApprovalResultError::PipelineNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutApprovalResultError::PipelineNotFound,
+                    )
                 }
                 "StageNotFoundException" => {
-                    return RusotoError::Service(PutApprovalResultError::StageNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutApprovalResultError::StageNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3064,12 +3287,22 @@ impl PutJobFailureResultError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidJobStateException" => {
-                    return RusotoError::Service(PutJobFailureResultError::InvalidJobState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutJobFailureResultError::InvalidJobState,
+                    )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(PutJobFailureResultError::JobNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutJobFailureResultError::JobNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3102,17 +3335,30 @@ impl PutJobSuccessResultError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidJobStateException" => {
-                    return RusotoError::Service(PutJobSuccessResultError::InvalidJobState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutJobSuccessResultError::InvalidJobState,
+                    )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(PutJobSuccessResultError::JobNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutJobSuccessResultError::JobNotFound,
+                    )
                 }
                 "OutputVariablesSizeExceededException" => {
-                    return RusotoError::Service(
-                        PutJobSuccessResultError::OutputVariablesSizeExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutJobSuccessResultError::OutputVariablesSizeExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3150,21 +3396,30 @@ impl PutThirdPartyJobFailureResultError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidClientTokenException" => {
-                    return RusotoError::Service(
-                        PutThirdPartyJobFailureResultError::InvalidClientToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutThirdPartyJobFailureResultError::InvalidClientToken,
                     )
                 }
                 "InvalidJobStateException" => {
-                    return RusotoError::Service(
-                        PutThirdPartyJobFailureResultError::InvalidJobState(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutThirdPartyJobFailureResultError::InvalidJobState,
                     )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(PutThirdPartyJobFailureResultError::JobNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutThirdPartyJobFailureResultError::JobNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3204,21 +3459,30 @@ impl PutThirdPartyJobSuccessResultError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidClientTokenException" => {
-                    return RusotoError::Service(
-                        PutThirdPartyJobSuccessResultError::InvalidClientToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutThirdPartyJobSuccessResultError::InvalidClientToken,
                     )
                 }
                 "InvalidJobStateException" => {
-                    return RusotoError::Service(
-                        PutThirdPartyJobSuccessResultError::InvalidJobState(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutThirdPartyJobSuccessResultError::InvalidJobState,
                     )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(PutThirdPartyJobSuccessResultError::JobNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutThirdPartyJobSuccessResultError::JobNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3264,31 +3528,62 @@ impl PutWebhookError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(PutWebhookError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutWebhookError::ConcurrentModification,
+                    )
                 }
                 "InvalidTagsException" => {
-                    return RusotoError::Service(PutWebhookError::InvalidTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutWebhookError::InvalidTags,
+                    )
                 }
                 "InvalidWebhookAuthenticationParametersException" => {
-                    return RusotoError::Service(
-                        PutWebhookError::InvalidWebhookAuthenticationParameters(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutWebhookError::InvalidWebhookAuthenticationParameters,
                     )
                 }
                 "InvalidWebhookFilterPatternException" => {
-                    return RusotoError::Service(PutWebhookError::InvalidWebhookFilterPattern(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutWebhookError::InvalidWebhookFilterPattern,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutWebhookError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutWebhookError::LimitExceeded,
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(PutWebhookError::PipelineNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutWebhookError::PipelineNotFound,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(PutWebhookError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutWebhookError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3326,11 +3621,14 @@ impl RegisterWebhookWithThirdPartyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "WebhookNotFoundException" => {
-                    return RusotoError::Service(
-                        RegisterWebhookWithThirdPartyError::WebhookNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterWebhookWithThirdPartyError::WebhookNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3368,27 +3666,46 @@ impl RetryStageExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(RetryStageExecutionError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RetryStageExecutionError::Conflict,
+                    )
                 }
                 "NotLatestPipelineExecutionException" => {
-                    return RusotoError::Service(
-                        RetryStageExecutionError::NotLatestPipelineExecution(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RetryStageExecutionError::NotLatestPipelineExecution,
                     )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(RetryStageExecutionError::PipelineNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RetryStageExecutionError::PipelineNotFound,
+                    )
                 }
                 "StageNotFoundException" => {
-                    return RusotoError::Service(RetryStageExecutionError::StageNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RetryStageExecutionError::StageNotFound,
+                    )
                 }
                 "StageNotRetryableException" => {
-                    return RusotoError::Service(RetryStageExecutionError::StageNotRetryable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RetryStageExecutionError::StageNotRetryable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3424,14 +3741,22 @@ impl StartPipelineExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(StartPipelineExecutionError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartPipelineExecutionError::Conflict,
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(StartPipelineExecutionError::PipelineNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartPipelineExecutionError::PipelineNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3466,24 +3791,38 @@ impl StopPipelineExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(StopPipelineExecutionError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopPipelineExecutionError::Conflict,
+                    )
                 }
                 "DuplicatedStopRequestException" => {
-                    return RusotoError::Service(StopPipelineExecutionError::DuplicatedStopRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopPipelineExecutionError::DuplicatedStopRequest,
+                    )
                 }
                 "PipelineExecutionNotStoppableException" => {
-                    return RusotoError::Service(
-                        StopPipelineExecutionError::PipelineExecutionNotStoppable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopPipelineExecutionError::PipelineExecutionNotStoppable,
                     )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(StopPipelineExecutionError::PipelineNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopPipelineExecutionError::PipelineNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3524,21 +3863,46 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(TagResourceError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ConcurrentModification,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(TagResourceError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidArn,
+                    )
                 }
                 "InvalidTagsException" => {
-                    return RusotoError::Service(TagResourceError::InvalidTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidTags,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3576,20 +3940,38 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UntagResourceError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UntagResourceError::ConcurrentModification,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidArn,
+                    )
                 }
                 "InvalidTagsException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidTags,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3628,27 +4010,46 @@ impl UpdatePipelineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidActionDeclarationException" => {
-                    return RusotoError::Service(UpdatePipelineError::InvalidActionDeclaration(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdatePipelineError::InvalidActionDeclaration,
+                    )
                 }
                 "InvalidBlockerDeclarationException" => {
-                    return RusotoError::Service(UpdatePipelineError::InvalidBlockerDeclaration(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdatePipelineError::InvalidBlockerDeclaration,
+                    )
                 }
                 "InvalidStageDeclarationException" => {
-                    return RusotoError::Service(UpdatePipelineError::InvalidStageDeclaration(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdatePipelineError::InvalidStageDeclaration,
+                    )
                 }
                 "InvalidStructureException" => {
-                    return RusotoError::Service(UpdatePipelineError::InvalidStructure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePipelineError::InvalidStructure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdatePipelineError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePipelineError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3906,11 +4307,12 @@ pub struct CodePipelineClient {
 impl CodePipelineClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> CodePipelineClient {
         CodePipelineClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "CodePipeline"),
         }
     }
 
@@ -3925,12 +4327,32 @@ impl CodePipelineClient {
     {
         CodePipelineClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "CodePipeline"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CodePipelineClient {
-        CodePipelineClient { client, region }
+        CodePipelineClient {
+            client,
+            region: region_for_service(region, "CodePipeline"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> CodePipelineClient {
+        CodePipelineClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("CodePipeline"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CodePipelineClient {
+        CodePipelineClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -3942,6 +4364,7 @@ impl CodePipeline for CodePipelineClient {
         input: AcknowledgeJobInput,
     ) -> Result<AcknowledgeJobOutput, RusotoError<AcknowledgeJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AcknowledgeJob");
         request.add_header("x-amz-target", "CodePipeline_20150709.AcknowledgeJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3960,6 +4383,7 @@ impl CodePipeline for CodePipelineClient {
         input: AcknowledgeThirdPartyJobInput,
     ) -> Result<AcknowledgeThirdPartyJobOutput, RusotoError<AcknowledgeThirdPartyJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AcknowledgeThirdPartyJob");
         request.add_header(
             "x-amz-target",
             "CodePipeline_20150709.AcknowledgeThirdPartyJob",
@@ -3982,6 +4406,7 @@ impl CodePipeline for CodePipelineClient {
         input: CreateCustomActionTypeInput,
     ) -> Result<CreateCustomActionTypeOutput, RusotoError<CreateCustomActionTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateCustomActionType");
         request.add_header(
             "x-amz-target",
             "CodePipeline_20150709.CreateCustomActionType",
@@ -4004,6 +4429,7 @@ impl CodePipeline for CodePipelineClient {
         input: CreatePipelineInput,
     ) -> Result<CreatePipelineOutput, RusotoError<CreatePipelineError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreatePipeline");
         request.add_header("x-amz-target", "CodePipeline_20150709.CreatePipeline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4022,6 +4448,7 @@ impl CodePipeline for CodePipelineClient {
         input: DeleteCustomActionTypeInput,
     ) -> Result<(), RusotoError<DeleteCustomActionTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteCustomActionType");
         request.add_header(
             "x-amz-target",
             "CodePipeline_20150709.DeleteCustomActionType",
@@ -4042,6 +4469,7 @@ impl CodePipeline for CodePipelineClient {
         input: DeletePipelineInput,
     ) -> Result<(), RusotoError<DeletePipelineError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeletePipeline");
         request.add_header("x-amz-target", "CodePipeline_20150709.DeletePipeline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4059,6 +4487,7 @@ impl CodePipeline for CodePipelineClient {
         input: DeleteWebhookInput,
     ) -> Result<DeleteWebhookOutput, RusotoError<DeleteWebhookError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteWebhook");
         request.add_header("x-amz-target", "CodePipeline_20150709.DeleteWebhook");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4080,6 +4509,7 @@ impl CodePipeline for CodePipelineClient {
         RusotoError<DeregisterWebhookWithThirdPartyError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeregisterWebhookWithThirdParty");
         request.add_header(
             "x-amz-target",
             "CodePipeline_20150709.DeregisterWebhookWithThirdParty",
@@ -4102,6 +4532,7 @@ impl CodePipeline for CodePipelineClient {
         input: DisableStageTransitionInput,
     ) -> Result<(), RusotoError<DisableStageTransitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisableStageTransition");
         request.add_header(
             "x-amz-target",
             "CodePipeline_20150709.DisableStageTransition",
@@ -4122,6 +4553,7 @@ impl CodePipeline for CodePipelineClient {
         input: EnableStageTransitionInput,
     ) -> Result<(), RusotoError<EnableStageTransitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("EnableStageTransition");
         request.add_header(
             "x-amz-target",
             "CodePipeline_20150709.EnableStageTransition",
@@ -4142,6 +4574,7 @@ impl CodePipeline for CodePipelineClient {
         input: GetJobDetailsInput,
     ) -> Result<GetJobDetailsOutput, RusotoError<GetJobDetailsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetJobDetails");
         request.add_header("x-amz-target", "CodePipeline_20150709.GetJobDetails");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4160,6 +4593,7 @@ impl CodePipeline for CodePipelineClient {
         input: GetPipelineInput,
     ) -> Result<GetPipelineOutput, RusotoError<GetPipelineError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetPipeline");
         request.add_header("x-amz-target", "CodePipeline_20150709.GetPipeline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4178,6 +4612,7 @@ impl CodePipeline for CodePipelineClient {
         input: GetPipelineExecutionInput,
     ) -> Result<GetPipelineExecutionOutput, RusotoError<GetPipelineExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetPipelineExecution");
         request.add_header("x-amz-target", "CodePipeline_20150709.GetPipelineExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4196,6 +4631,7 @@ impl CodePipeline for CodePipelineClient {
         input: GetPipelineStateInput,
     ) -> Result<GetPipelineStateOutput, RusotoError<GetPipelineStateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetPipelineState");
         request.add_header("x-amz-target", "CodePipeline_20150709.GetPipelineState");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4214,6 +4650,7 @@ impl CodePipeline for CodePipelineClient {
         input: GetThirdPartyJobDetailsInput,
     ) -> Result<GetThirdPartyJobDetailsOutput, RusotoError<GetThirdPartyJobDetailsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetThirdPartyJobDetails");
         request.add_header(
             "x-amz-target",
             "CodePipeline_20150709.GetThirdPartyJobDetails",
@@ -4236,6 +4673,7 @@ impl CodePipeline for CodePipelineClient {
         input: ListActionExecutionsInput,
     ) -> Result<ListActionExecutionsOutput, RusotoError<ListActionExecutionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListActionExecutions");
         request.add_header("x-amz-target", "CodePipeline_20150709.ListActionExecutions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4254,6 +4692,7 @@ impl CodePipeline for CodePipelineClient {
         input: ListActionTypesInput,
     ) -> Result<ListActionTypesOutput, RusotoError<ListActionTypesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListActionTypes");
         request.add_header("x-amz-target", "CodePipeline_20150709.ListActionTypes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4272,6 +4711,7 @@ impl CodePipeline for CodePipelineClient {
         input: ListPipelineExecutionsInput,
     ) -> Result<ListPipelineExecutionsOutput, RusotoError<ListPipelineExecutionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListPipelineExecutions");
         request.add_header(
             "x-amz-target",
             "CodePipeline_20150709.ListPipelineExecutions",
@@ -4294,6 +4734,7 @@ impl CodePipeline for CodePipelineClient {
         input: ListPipelinesInput,
     ) -> Result<ListPipelinesOutput, RusotoError<ListPipelinesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListPipelines");
         request.add_header("x-amz-target", "CodePipeline_20150709.ListPipelines");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4312,6 +4753,7 @@ impl CodePipeline for CodePipelineClient {
         input: ListTagsForResourceInput,
     ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header("x-amz-target", "CodePipeline_20150709.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4330,6 +4772,7 @@ impl CodePipeline for CodePipelineClient {
         input: ListWebhooksInput,
     ) -> Result<ListWebhooksOutput, RusotoError<ListWebhooksError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListWebhooks");
         request.add_header("x-amz-target", "CodePipeline_20150709.ListWebhooks");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4348,6 +4791,7 @@ impl CodePipeline for CodePipelineClient {
         input: PollForJobsInput,
     ) -> Result<PollForJobsOutput, RusotoError<PollForJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PollForJobs");
         request.add_header("x-amz-target", "CodePipeline_20150709.PollForJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4366,6 +4810,7 @@ impl CodePipeline for CodePipelineClient {
         input: PollForThirdPartyJobsInput,
     ) -> Result<PollForThirdPartyJobsOutput, RusotoError<PollForThirdPartyJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PollForThirdPartyJobs");
         request.add_header(
             "x-amz-target",
             "CodePipeline_20150709.PollForThirdPartyJobs",
@@ -4387,6 +4832,7 @@ impl CodePipeline for CodePipelineClient {
         input: PutActionRevisionInput,
     ) -> Result<PutActionRevisionOutput, RusotoError<PutActionRevisionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutActionRevision");
         request.add_header("x-amz-target", "CodePipeline_20150709.PutActionRevision");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4405,6 +4851,7 @@ impl CodePipeline for CodePipelineClient {
         input: PutApprovalResultInput,
     ) -> Result<PutApprovalResultOutput, RusotoError<PutApprovalResultError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutApprovalResult");
         request.add_header("x-amz-target", "CodePipeline_20150709.PutApprovalResult");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4423,6 +4870,7 @@ impl CodePipeline for CodePipelineClient {
         input: PutJobFailureResultInput,
     ) -> Result<(), RusotoError<PutJobFailureResultError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutJobFailureResult");
         request.add_header("x-amz-target", "CodePipeline_20150709.PutJobFailureResult");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4440,6 +4888,7 @@ impl CodePipeline for CodePipelineClient {
         input: PutJobSuccessResultInput,
     ) -> Result<(), RusotoError<PutJobSuccessResultError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutJobSuccessResult");
         request.add_header("x-amz-target", "CodePipeline_20150709.PutJobSuccessResult");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4457,6 +4906,7 @@ impl CodePipeline for CodePipelineClient {
         input: PutThirdPartyJobFailureResultInput,
     ) -> Result<(), RusotoError<PutThirdPartyJobFailureResultError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutThirdPartyJobFailureResult");
         request.add_header(
             "x-amz-target",
             "CodePipeline_20150709.PutThirdPartyJobFailureResult",
@@ -4477,6 +4927,7 @@ impl CodePipeline for CodePipelineClient {
         input: PutThirdPartyJobSuccessResultInput,
     ) -> Result<(), RusotoError<PutThirdPartyJobSuccessResultError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutThirdPartyJobSuccessResult");
         request.add_header(
             "x-amz-target",
             "CodePipeline_20150709.PutThirdPartyJobSuccessResult",
@@ -4497,6 +4948,7 @@ impl CodePipeline for CodePipelineClient {
         input: PutWebhookInput,
     ) -> Result<PutWebhookOutput, RusotoError<PutWebhookError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutWebhook");
         request.add_header("x-amz-target", "CodePipeline_20150709.PutWebhook");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4516,6 +4968,7 @@ impl CodePipeline for CodePipelineClient {
     ) -> Result<RegisterWebhookWithThirdPartyOutput, RusotoError<RegisterWebhookWithThirdPartyError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RegisterWebhookWithThirdParty");
         request.add_header(
             "x-amz-target",
             "CodePipeline_20150709.RegisterWebhookWithThirdParty",
@@ -4538,6 +4991,7 @@ impl CodePipeline for CodePipelineClient {
         input: RetryStageExecutionInput,
     ) -> Result<RetryStageExecutionOutput, RusotoError<RetryStageExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RetryStageExecution");
         request.add_header("x-amz-target", "CodePipeline_20150709.RetryStageExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4556,6 +5010,7 @@ impl CodePipeline for CodePipelineClient {
         input: StartPipelineExecutionInput,
     ) -> Result<StartPipelineExecutionOutput, RusotoError<StartPipelineExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartPipelineExecution");
         request.add_header(
             "x-amz-target",
             "CodePipeline_20150709.StartPipelineExecution",
@@ -4578,6 +5033,7 @@ impl CodePipeline for CodePipelineClient {
         input: StopPipelineExecutionInput,
     ) -> Result<StopPipelineExecutionOutput, RusotoError<StopPipelineExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopPipelineExecution");
         request.add_header(
             "x-amz-target",
             "CodePipeline_20150709.StopPipelineExecution",
@@ -4599,6 +5055,7 @@ impl CodePipeline for CodePipelineClient {
         input: TagResourceInput,
     ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "CodePipeline_20150709.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4617,6 +5074,7 @@ impl CodePipeline for CodePipelineClient {
         input: UntagResourceInput,
     ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "CodePipeline_20150709.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4635,6 +5093,7 @@ impl CodePipeline for CodePipelineClient {
         input: UpdatePipelineInput,
     ) -> Result<UpdatePipelineOutput, RusotoError<UpdatePipelineError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdatePipeline");
         request.add_header("x-amz-target", "CodePipeline_20150709.UpdatePipeline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4647,3 +5106,403 @@ impl CodePipeline for CodePipelineClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdatePipelineOutput, _>()
     }
 }
+
+/// A blocking client for the CodePipeline API, running the calls of a `CodePipelineClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `CodePipelineClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CodePipelineBlockingClient {
+    client: CodePipelineClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CodePipelineBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> CodePipelineBlockingClient {
+        CodePipelineBlockingClient::from_client(CodePipelineClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CodePipelineBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CodePipelineBlockingClient::from_client(CodePipelineClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> CodePipelineBlockingClient {
+        CodePipelineBlockingClient::from_client(CodePipelineClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> CodePipelineBlockingClient {
+        CodePipelineBlockingClient::from_client(CodePipelineClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: CodePipelineClient) -> CodePipelineBlockingClient {
+        CodePipelineBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> CodePipelineBlockingClient {
+        CodePipelineBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CodePipelineBlockingClient {
+        CodePipelineBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`CodePipeline::acknowledge_job`].
+    pub fn acknowledge_job(
+        &self,
+        input: AcknowledgeJobInput,
+    ) -> Result<AcknowledgeJobOutput, RusotoError<AcknowledgeJobError>> {
+        self.runtime.block_on(self.client.acknowledge_job(input))
+    }
+
+    /// Blocking version of [`CodePipeline::acknowledge_third_party_job`].
+    pub fn acknowledge_third_party_job(
+        &self,
+        input: AcknowledgeThirdPartyJobInput,
+    ) -> Result<AcknowledgeThirdPartyJobOutput, RusotoError<AcknowledgeThirdPartyJobError>> {
+        self.runtime
+            .block_on(self.client.acknowledge_third_party_job(input))
+    }
+
+    /// Blocking version of [`CodePipeline::create_custom_action_type`].
+    pub fn create_custom_action_type(
+        &self,
+        input: CreateCustomActionTypeInput,
+    ) -> Result<CreateCustomActionTypeOutput, RusotoError<CreateCustomActionTypeError>> {
+        self.runtime
+            .block_on(self.client.create_custom_action_type(input))
+    }
+
+    /// Blocking version of [`CodePipeline::create_pipeline`].
+    pub fn create_pipeline(
+        &self,
+        input: CreatePipelineInput,
+    ) -> Result<CreatePipelineOutput, RusotoError<CreatePipelineError>> {
+        self.runtime.block_on(self.client.create_pipeline(input))
+    }
+
+    /// Blocking version of [`CodePipeline::delete_custom_action_type`].
+    pub fn delete_custom_action_type(
+        &self,
+        input: DeleteCustomActionTypeInput,
+    ) -> Result<(), RusotoError<DeleteCustomActionTypeError>> {
+        self.runtime
+            .block_on(self.client.delete_custom_action_type(input))
+    }
+
+    /// Blocking version of [`CodePipeline::delete_pipeline`].
+    pub fn delete_pipeline(
+        &self,
+        input: DeletePipelineInput,
+    ) -> Result<(), RusotoError<DeletePipelineError>> {
+        self.runtime.block_on(self.client.delete_pipeline(input))
+    }
+
+    /// Blocking version of [`CodePipeline::delete_webhook`].
+    pub fn delete_webhook(
+        &self,
+        input: DeleteWebhookInput,
+    ) -> Result<DeleteWebhookOutput, RusotoError<DeleteWebhookError>> {
+        self.runtime.block_on(self.client.delete_webhook(input))
+    }
+
+    /// Blocking version of [`CodePipeline::deregister_webhook_with_third_party`].
+    pub fn deregister_webhook_with_third_party(
+        &self,
+        input: DeregisterWebhookWithThirdPartyInput,
+    ) -> Result<
+        DeregisterWebhookWithThirdPartyOutput,
+        RusotoError<DeregisterWebhookWithThirdPartyError>,
+    > {
+        self.runtime
+            .block_on(self.client.deregister_webhook_with_third_party(input))
+    }
+
+    /// Blocking version of [`CodePipeline::disable_stage_transition`].
+    pub fn disable_stage_transition(
+        &self,
+        input: DisableStageTransitionInput,
+    ) -> Result<(), RusotoError<DisableStageTransitionError>> {
+        self.runtime
+            .block_on(self.client.disable_stage_transition(input))
+    }
+
+    /// Blocking version of [`CodePipeline::enable_stage_transition`].
+    pub fn enable_stage_transition(
+        &self,
+        input: EnableStageTransitionInput,
+    ) -> Result<(), RusotoError<EnableStageTransitionError>> {
+        self.runtime
+            .block_on(self.client.enable_stage_transition(input))
+    }
+
+    /// Blocking version of [`CodePipeline::get_job_details`].
+    pub fn get_job_details(
+        &self,
+        input: GetJobDetailsInput,
+    ) -> Result<GetJobDetailsOutput, RusotoError<GetJobDetailsError>> {
+        self.runtime.block_on(self.client.get_job_details(input))
+    }
+
+    /// Blocking version of [`CodePipeline::get_pipeline`].
+    pub fn get_pipeline(
+        &self,
+        input: GetPipelineInput,
+    ) -> Result<GetPipelineOutput, RusotoError<GetPipelineError>> {
+        self.runtime.block_on(self.client.get_pipeline(input))
+    }
+
+    /// Blocking version of [`CodePipeline::get_pipeline_execution`].
+    pub fn get_pipeline_execution(
+        &self,
+        input: GetPipelineExecutionInput,
+    ) -> Result<GetPipelineExecutionOutput, RusotoError<GetPipelineExecutionError>> {
+        self.runtime
+            .block_on(self.client.get_pipeline_execution(input))
+    }
+
+    /// Blocking version of [`CodePipeline::get_pipeline_state`].
+    pub fn get_pipeline_state(
+        &self,
+        input: GetPipelineStateInput,
+    ) -> Result<GetPipelineStateOutput, RusotoError<GetPipelineStateError>> {
+        self.runtime.block_on(self.client.get_pipeline_state(input))
+    }
+
+    /// Blocking version of [`CodePipeline::get_third_party_job_details`].
+    pub fn get_third_party_job_details(
+        &self,
+        input: GetThirdPartyJobDetailsInput,
+    ) -> Result<GetThirdPartyJobDetailsOutput, RusotoError<GetThirdPartyJobDetailsError>> {
+        self.runtime
+            .block_on(self.client.get_third_party_job_details(input))
+    }
+
+    /// Blocking version of [`CodePipeline::list_action_executions`].
+    pub fn list_action_executions(
+        &self,
+        input: ListActionExecutionsInput,
+    ) -> Result<ListActionExecutionsOutput, RusotoError<ListActionExecutionsError>> {
+        self.runtime
+            .block_on(self.client.list_action_executions(input))
+    }
+
+    /// Blocking version of [`CodePipeline::list_action_types`].
+    pub fn list_action_types(
+        &self,
+        input: ListActionTypesInput,
+    ) -> Result<ListActionTypesOutput, RusotoError<ListActionTypesError>> {
+        self.runtime.block_on(self.client.list_action_types(input))
+    }
+
+    /// Blocking version of [`CodePipeline::list_pipeline_executions`].
+    pub fn list_pipeline_executions(
+        &self,
+        input: ListPipelineExecutionsInput,
+    ) -> Result<ListPipelineExecutionsOutput, RusotoError<ListPipelineExecutionsError>> {
+        self.runtime
+            .block_on(self.client.list_pipeline_executions(input))
+    }
+
+    /// Blocking version of [`CodePipeline::list_pipelines`].
+    pub fn list_pipelines(
+        &self,
+        input: ListPipelinesInput,
+    ) -> Result<ListPipelinesOutput, RusotoError<ListPipelinesError>> {
+        self.runtime.block_on(self.client.list_pipelines(input))
+    }
+
+    /// Blocking version of [`CodePipeline::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`CodePipeline::list_webhooks`].
+    pub fn list_webhooks(
+        &self,
+        input: ListWebhooksInput,
+    ) -> Result<ListWebhooksOutput, RusotoError<ListWebhooksError>> {
+        self.runtime.block_on(self.client.list_webhooks(input))
+    }
+
+    /// Blocking version of [`CodePipeline::poll_for_jobs`].
+    pub fn poll_for_jobs(
+        &self,
+        input: PollForJobsInput,
+    ) -> Result<PollForJobsOutput, RusotoError<PollForJobsError>> {
+        self.runtime.block_on(self.client.poll_for_jobs(input))
+    }
+
+    /// Blocking version of [`CodePipeline::poll_for_third_party_jobs`].
+    pub fn poll_for_third_party_jobs(
+        &self,
+        input: PollForThirdPartyJobsInput,
+    ) -> Result<PollForThirdPartyJobsOutput, RusotoError<PollForThirdPartyJobsError>> {
+        self.runtime
+            .block_on(self.client.poll_for_third_party_jobs(input))
+    }
+
+    /// Blocking version of [`CodePipeline::put_action_revision`].
+    pub fn put_action_revision(
+        &self,
+        input: PutActionRevisionInput,
+    ) -> Result<PutActionRevisionOutput, RusotoError<PutActionRevisionError>> {
+        self.runtime
+            .block_on(self.client.put_action_revision(input))
+    }
+
+    /// Blocking version of [`CodePipeline::put_approval_result`].
+    pub fn put_approval_result(
+        &self,
+        input: PutApprovalResultInput,
+    ) -> Result<PutApprovalResultOutput, RusotoError<PutApprovalResultError>> {
+        self.runtime
+            .block_on(self.client.put_approval_result(input))
+    }
+
+    /// Blocking version of [`CodePipeline::put_job_failure_result`].
+    pub fn put_job_failure_result(
+        &self,
+        input: PutJobFailureResultInput,
+    ) -> Result<(), RusotoError<PutJobFailureResultError>> {
+        self.runtime
+            .block_on(self.client.put_job_failure_result(input))
+    }
+
+    /// Blocking version of [`CodePipeline::put_job_success_result`].
+    pub fn put_job_success_result(
+        &self,
+        input: PutJobSuccessResultInput,
+    ) -> Result<(), RusotoError<PutJobSuccessResultError>> {
+        self.runtime
+            .block_on(self.client.put_job_success_result(input))
+    }
+
+    /// Blocking version of [`CodePipeline::put_third_party_job_failure_result`].
+    pub fn put_third_party_job_failure_result(
+        &self,
+        input: PutThirdPartyJobFailureResultInput,
+    ) -> Result<(), RusotoError<PutThirdPartyJobFailureResultError>> {
+        self.runtime
+            .block_on(self.client.put_third_party_job_failure_result(input))
+    }
+
+    /// Blocking version of [`CodePipeline::put_third_party_job_success_result`].
+    pub fn put_third_party_job_success_result(
+        &self,
+        input: PutThirdPartyJobSuccessResultInput,
+    ) -> Result<(), RusotoError<PutThirdPartyJobSuccessResultError>> {
+        self.runtime
+            .block_on(self.client.put_third_party_job_success_result(input))
+    }
+
+    /// Blocking version of [`CodePipeline::put_webhook`].
+    pub fn put_webhook(
+        &self,
+        input: PutWebhookInput,
+    ) -> Result<PutWebhookOutput, RusotoError<PutWebhookError>> {
+        self.runtime.block_on(self.client.put_webhook(input))
+    }
+
+    /// Blocking version of [`CodePipeline::register_webhook_with_third_party`].
+    pub fn register_webhook_with_third_party(
+        &self,
+        input: RegisterWebhookWithThirdPartyInput,
+    ) -> Result<RegisterWebhookWithThirdPartyOutput, RusotoError<RegisterWebhookWithThirdPartyError>>
+    {
+        self.runtime
+            .block_on(self.client.register_webhook_with_third_party(input))
+    }
+
+    /// Blocking version of [`CodePipeline::retry_stage_execution`].
+    pub fn retry_stage_execution(
+        &self,
+        input: RetryStageExecutionInput,
+    ) -> Result<RetryStageExecutionOutput, RusotoError<RetryStageExecutionError>> {
+        self.runtime
+            .block_on(self.client.retry_stage_execution(input))
+    }
+
+    /// Blocking version of [`CodePipeline::start_pipeline_execution`].
+    pub fn start_pipeline_execution(
+        &self,
+        input: StartPipelineExecutionInput,
+    ) -> Result<StartPipelineExecutionOutput, RusotoError<StartPipelineExecutionError>> {
+        self.runtime
+            .block_on(self.client.start_pipeline_execution(input))
+    }
+
+    /// Blocking version of [`CodePipeline::stop_pipeline_execution`].
+    pub fn stop_pipeline_execution(
+        &self,
+        input: StopPipelineExecutionInput,
+    ) -> Result<StopPipelineExecutionOutput, RusotoError<StopPipelineExecutionError>> {
+        self.runtime
+            .block_on(self.client.stop_pipeline_execution(input))
+    }
+
+    /// Blocking version of [`CodePipeline::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`CodePipeline::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`CodePipeline::update_pipeline`].
+    pub fn update_pipeline(
+        &self,
+        input: UpdatePipelineInput,
+    ) -> Result<UpdatePipelineOutput, RusotoError<UpdatePipelineError>> {
+        self.runtime.block_on(self.client.update_pipeline(input))
+    }
+}
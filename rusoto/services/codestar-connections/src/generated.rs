@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -424,17 +425,30 @@ impl CreateConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateConnectionError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConnectionError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateConnectionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConnectionError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(CreateConnectionError::ResourceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateConnectionError::ResourceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -464,9 +478,14 @@ impl CreateHostError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateHostError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateHostError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -494,9 +513,14 @@ impl DeleteConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteConnectionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteConnectionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -526,12 +550,22 @@ impl DeleteHostError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteHostError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteHostError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(DeleteHostError::ResourceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteHostError::ResourceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -562,12 +596,22 @@ impl GetConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetConnectionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConnectionError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(GetConnectionError::ResourceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConnectionError::ResourceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -598,12 +642,22 @@ impl GetHostError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetHostError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetHostError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(GetHostError::ResourceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetHostError::ResourceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -628,7 +682,7 @@ impl ListConnectionsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListConnectionsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -650,7 +704,7 @@ impl ListHostsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListHostsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -676,11 +730,14 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -710,12 +767,22 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -744,9 +811,14 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -780,18 +852,38 @@ impl UpdateHostError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateHostError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateHostError::Conflict,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateHostError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateHostError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(UpdateHostError::ResourceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateHostError::ResourceUnavailable,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(UpdateHostError::UnsupportedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateHostError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -895,11 +987,12 @@ pub struct CodeStarConnectionsClient {
 impl CodeStarConnectionsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> CodeStarConnectionsClient {
         CodeStarConnectionsClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "CodeStar connections"),
         }
     }
 
@@ -914,12 +1007,32 @@ impl CodeStarConnectionsClient {
     {
         CodeStarConnectionsClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "CodeStar connections"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CodeStarConnectionsClient {
-        CodeStarConnectionsClient { client, region }
+        CodeStarConnectionsClient {
+            client,
+            region: region_for_service(region, "CodeStar connections"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> CodeStarConnectionsClient {
+        CodeStarConnectionsClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("CodeStar connections"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CodeStarConnectionsClient {
+        CodeStarConnectionsClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -931,6 +1044,7 @@ impl CodeStarConnections for CodeStarConnectionsClient {
         input: CreateConnectionInput,
     ) -> Result<CreateConnectionOutput, RusotoError<CreateConnectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateConnection");
         request.add_header(
             "x-amz-target",
             "com.amazonaws.codestar.connections.CodeStar_connections_20191201.CreateConnection",
@@ -952,6 +1066,7 @@ impl CodeStarConnections for CodeStarConnectionsClient {
         input: CreateHostInput,
     ) -> Result<CreateHostOutput, RusotoError<CreateHostError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateHost");
         request.add_header(
             "x-amz-target",
             "com.amazonaws.codestar.connections.CodeStar_connections_20191201.CreateHost",
@@ -973,6 +1088,7 @@ impl CodeStarConnections for CodeStarConnectionsClient {
         input: DeleteConnectionInput,
     ) -> Result<DeleteConnectionOutput, RusotoError<DeleteConnectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteConnection");
         request.add_header(
             "x-amz-target",
             "com.amazonaws.codestar.connections.CodeStar_connections_20191201.DeleteConnection",
@@ -994,6 +1110,7 @@ impl CodeStarConnections for CodeStarConnectionsClient {
         input: DeleteHostInput,
     ) -> Result<DeleteHostOutput, RusotoError<DeleteHostError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteHost");
         request.add_header(
             "x-amz-target",
             "com.amazonaws.codestar.connections.CodeStar_connections_20191201.DeleteHost",
@@ -1015,6 +1132,7 @@ impl CodeStarConnections for CodeStarConnectionsClient {
         input: GetConnectionInput,
     ) -> Result<GetConnectionOutput, RusotoError<GetConnectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetConnection");
         request.add_header(
             "x-amz-target",
             "com.amazonaws.codestar.connections.CodeStar_connections_20191201.GetConnection",
@@ -1036,6 +1154,7 @@ impl CodeStarConnections for CodeStarConnectionsClient {
         input: GetHostInput,
     ) -> Result<GetHostOutput, RusotoError<GetHostError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetHost");
         request.add_header(
             "x-amz-target",
             "com.amazonaws.codestar.connections.CodeStar_connections_20191201.GetHost",
@@ -1057,6 +1176,7 @@ impl CodeStarConnections for CodeStarConnectionsClient {
         input: ListConnectionsInput,
     ) -> Result<ListConnectionsOutput, RusotoError<ListConnectionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListConnections");
         request.add_header(
             "x-amz-target",
             "com.amazonaws.codestar.connections.CodeStar_connections_20191201.ListConnections",
@@ -1078,6 +1198,7 @@ impl CodeStarConnections for CodeStarConnectionsClient {
         input: ListHostsInput,
     ) -> Result<ListHostsOutput, RusotoError<ListHostsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListHosts");
         request.add_header(
             "x-amz-target",
             "com.amazonaws.codestar.connections.CodeStar_connections_20191201.ListHosts",
@@ -1099,6 +1220,7 @@ impl CodeStarConnections for CodeStarConnectionsClient {
         input: ListTagsForResourceInput,
     ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header(
             "x-amz-target",
             "com.amazonaws.codestar.connections.CodeStar_connections_20191201.ListTagsForResource",
@@ -1120,6 +1242,7 @@ impl CodeStarConnections for CodeStarConnectionsClient {
         input: TagResourceInput,
     ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header(
             "x-amz-target",
             "com.amazonaws.codestar.connections.CodeStar_connections_20191201.TagResource",
@@ -1141,6 +1264,7 @@ impl CodeStarConnections for CodeStarConnectionsClient {
         input: UntagResourceInput,
     ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header(
             "x-amz-target",
             "com.amazonaws.codestar.connections.CodeStar_connections_20191201.UntagResource",
@@ -1162,6 +1286,7 @@ impl CodeStarConnections for CodeStarConnectionsClient {
         input: UpdateHostInput,
     ) -> Result<UpdateHostOutput, RusotoError<UpdateHostError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateHost");
         request.add_header(
             "x-amz-target",
             "com.amazonaws.codestar.connections.CodeStar_connections_20191201.UpdateHost",
@@ -1177,3 +1302,185 @@ impl CodeStarConnections for CodeStarConnectionsClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateHostOutput, _>()
     }
 }
+
+/// A blocking client for the AWS CodeStar connections API, running the calls of a `CodeStarConnectionsClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `CodeStarConnectionsClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CodeStarConnectionsBlockingClient {
+    client: CodeStarConnectionsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CodeStarConnectionsBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> CodeStarConnectionsBlockingClient {
+        CodeStarConnectionsBlockingClient::from_client(CodeStarConnectionsClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CodeStarConnectionsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CodeStarConnectionsBlockingClient::from_client(CodeStarConnectionsClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> CodeStarConnectionsBlockingClient {
+        CodeStarConnectionsBlockingClient::from_client(CodeStarConnectionsClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> CodeStarConnectionsBlockingClient {
+        CodeStarConnectionsBlockingClient::from_client(CodeStarConnectionsClient::new_with_config(
+            config,
+        ))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: CodeStarConnectionsClient) -> CodeStarConnectionsBlockingClient {
+        CodeStarConnectionsBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> CodeStarConnectionsBlockingClient {
+        CodeStarConnectionsBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CodeStarConnectionsBlockingClient {
+        CodeStarConnectionsBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`CodeStarConnections::create_connection`].
+    pub fn create_connection(
+        &self,
+        input: CreateConnectionInput,
+    ) -> Result<CreateConnectionOutput, RusotoError<CreateConnectionError>> {
+        self.runtime.block_on(self.client.create_connection(input))
+    }
+
+    /// Blocking version of [`CodeStarConnections::create_host`].
+    pub fn create_host(
+        &self,
+        input: CreateHostInput,
+    ) -> Result<CreateHostOutput, RusotoError<CreateHostError>> {
+        self.runtime.block_on(self.client.create_host(input))
+    }
+
+    /// Blocking version of [`CodeStarConnections::delete_connection`].
+    pub fn delete_connection(
+        &self,
+        input: DeleteConnectionInput,
+    ) -> Result<DeleteConnectionOutput, RusotoError<DeleteConnectionError>> {
+        self.runtime.block_on(self.client.delete_connection(input))
+    }
+
+    /// Blocking version of [`CodeStarConnections::delete_host`].
+    pub fn delete_host(
+        &self,
+        input: DeleteHostInput,
+    ) -> Result<DeleteHostOutput, RusotoError<DeleteHostError>> {
+        self.runtime.block_on(self.client.delete_host(input))
+    }
+
+    /// Blocking version of [`CodeStarConnections::get_connection`].
+    pub fn get_connection(
+        &self,
+        input: GetConnectionInput,
+    ) -> Result<GetConnectionOutput, RusotoError<GetConnectionError>> {
+        self.runtime.block_on(self.client.get_connection(input))
+    }
+
+    /// Blocking version of [`CodeStarConnections::get_host`].
+    pub fn get_host(
+        &self,
+        input: GetHostInput,
+    ) -> Result<GetHostOutput, RusotoError<GetHostError>> {
+        self.runtime.block_on(self.client.get_host(input))
+    }
+
+    /// Blocking version of [`CodeStarConnections::list_connections`].
+    pub fn list_connections(
+        &self,
+        input: ListConnectionsInput,
+    ) -> Result<ListConnectionsOutput, RusotoError<ListConnectionsError>> {
+        self.runtime.block_on(self.client.list_connections(input))
+    }
+
+    /// Blocking version of [`CodeStarConnections::list_hosts`].
+    pub fn list_hosts(
+        &self,
+        input: ListHostsInput,
+    ) -> Result<ListHostsOutput, RusotoError<ListHostsError>> {
+        self.runtime.block_on(self.client.list_hosts(input))
+    }
+
+    /// Blocking version of [`CodeStarConnections::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`CodeStarConnections::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`CodeStarConnections::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`CodeStarConnections::update_host`].
+    pub fn update_host(
+        &self,
+        input: UpdateHostInput,
+    ) -> Result<UpdateHostOutput, RusotoError<UpdateHostError>> {
+        self.runtime.block_on(self.client.update_host(input))
+    }
+}
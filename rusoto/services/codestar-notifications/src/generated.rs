@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -494,29 +495,46 @@ impl CreateNotificationRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateNotificationRuleError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNotificationRuleError::AccessDenied,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        CreateNotificationRuleError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNotificationRuleError::ConcurrentModification,
                     )
                 }
                 "ConfigurationException" => {
-                    return RusotoError::Service(CreateNotificationRuleError::Configuration(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateNotificationRuleError::Configuration,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateNotificationRuleError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateNotificationRuleError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        CreateNotificationRuleError::ResourceAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNotificationRuleError::ResourceAlreadyExists,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -552,16 +570,22 @@ impl DeleteNotificationRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        DeleteNotificationRuleError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNotificationRuleError::ConcurrentModification,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteNotificationRuleError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteNotificationRuleError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -588,7 +612,7 @@ impl DeleteTargetError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteTargetError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -614,11 +638,14 @@ impl DescribeNotificationRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeNotificationRuleError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeNotificationRuleError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -646,9 +673,14 @@ impl ListEventTypesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListEventTypesError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListEventTypesError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -676,11 +708,14 @@ impl ListNotificationRulesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListNotificationRulesError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListNotificationRulesError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -708,11 +743,14 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -740,9 +778,14 @@ impl ListTargetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListTargetsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTargetsError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -770,9 +813,14 @@ impl SubscribeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SubscribeError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SubscribeError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -802,12 +850,22 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(TagResourceError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ConcurrentModification,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -832,7 +890,7 @@ impl UnsubscribeError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UnsubscribeError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -860,14 +918,22 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UntagResourceError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UntagResourceError::ConcurrentModification,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -896,11 +962,14 @@ impl UpdateNotificationRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateNotificationRuleError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateNotificationRuleError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1007,11 +1076,12 @@ pub struct CodeStarNotificationsClient {
 impl CodeStarNotificationsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> CodeStarNotificationsClient {
         CodeStarNotificationsClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "codestar notifications"),
         }
     }
 
@@ -1026,12 +1096,32 @@ impl CodeStarNotificationsClient {
     {
         CodeStarNotificationsClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "codestar notifications"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CodeStarNotificationsClient {
-        CodeStarNotificationsClient { client, region }
+        CodeStarNotificationsClient {
+            client,
+            region: region_for_service(region, "codestar notifications"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> CodeStarNotificationsClient {
+        CodeStarNotificationsClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("codestar notifications"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CodeStarNotificationsClient {
+        CodeStarNotificationsClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -1047,6 +1137,7 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("CreateNotificationRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1079,6 +1170,7 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("DeleteNotificationRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1111,6 +1203,7 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("DeleteTarget");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1143,6 +1236,7 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("DescribeNotificationRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1175,6 +1269,7 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("ListEventTypes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1207,6 +1302,7 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("ListNotificationRules");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1239,6 +1335,7 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1271,6 +1368,7 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("ListTargets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1303,6 +1401,7 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("Subscribe");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1335,6 +1434,7 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1367,6 +1467,7 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("Unsubscribe");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1399,6 +1500,7 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1431,6 +1533,7 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
 
         let mut request =
             SignedRequest::new("POST", "codestar-notifications", &self.region, &request_uri);
+        request.set_operation("UpdateNotificationRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1453,3 +1556,198 @@ impl CodeStarNotifications for CodeStarNotificationsClient {
         }
     }
 }
+
+/// A blocking client for the AWS CodeStar Notifications API, running the calls of a `CodeStarNotificationsClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `CodeStarNotificationsClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CodeStarNotificationsBlockingClient {
+    client: CodeStarNotificationsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CodeStarNotificationsBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> CodeStarNotificationsBlockingClient {
+        CodeStarNotificationsBlockingClient::from_client(CodeStarNotificationsClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CodeStarNotificationsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CodeStarNotificationsBlockingClient::from_client(CodeStarNotificationsClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> CodeStarNotificationsBlockingClient {
+        CodeStarNotificationsBlockingClient::from_client(
+            CodeStarNotificationsClient::new_with_client(client, region),
+        )
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> CodeStarNotificationsBlockingClient {
+        CodeStarNotificationsBlockingClient::from_client(
+            CodeStarNotificationsClient::new_with_config(config),
+        )
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: CodeStarNotificationsClient) -> CodeStarNotificationsBlockingClient {
+        CodeStarNotificationsBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> CodeStarNotificationsBlockingClient {
+        CodeStarNotificationsBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CodeStarNotificationsBlockingClient {
+        CodeStarNotificationsBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`CodeStarNotifications::create_notification_rule`].
+    pub fn create_notification_rule(
+        &self,
+        input: CreateNotificationRuleRequest,
+    ) -> Result<CreateNotificationRuleResult, RusotoError<CreateNotificationRuleError>> {
+        self.runtime
+            .block_on(self.client.create_notification_rule(input))
+    }
+
+    /// Blocking version of [`CodeStarNotifications::delete_notification_rule`].
+    pub fn delete_notification_rule(
+        &self,
+        input: DeleteNotificationRuleRequest,
+    ) -> Result<DeleteNotificationRuleResult, RusotoError<DeleteNotificationRuleError>> {
+        self.runtime
+            .block_on(self.client.delete_notification_rule(input))
+    }
+
+    /// Blocking version of [`CodeStarNotifications::delete_target`].
+    pub fn delete_target(
+        &self,
+        input: DeleteTargetRequest,
+    ) -> Result<DeleteTargetResult, RusotoError<DeleteTargetError>> {
+        self.runtime.block_on(self.client.delete_target(input))
+    }
+
+    /// Blocking version of [`CodeStarNotifications::describe_notification_rule`].
+    pub fn describe_notification_rule(
+        &self,
+        input: DescribeNotificationRuleRequest,
+    ) -> Result<DescribeNotificationRuleResult, RusotoError<DescribeNotificationRuleError>> {
+        self.runtime
+            .block_on(self.client.describe_notification_rule(input))
+    }
+
+    /// Blocking version of [`CodeStarNotifications::list_event_types`].
+    pub fn list_event_types(
+        &self,
+        input: ListEventTypesRequest,
+    ) -> Result<ListEventTypesResult, RusotoError<ListEventTypesError>> {
+        self.runtime.block_on(self.client.list_event_types(input))
+    }
+
+    /// Blocking version of [`CodeStarNotifications::list_notification_rules`].
+    pub fn list_notification_rules(
+        &self,
+        input: ListNotificationRulesRequest,
+    ) -> Result<ListNotificationRulesResult, RusotoError<ListNotificationRulesError>> {
+        self.runtime
+            .block_on(self.client.list_notification_rules(input))
+    }
+
+    /// Blocking version of [`CodeStarNotifications::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResult, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`CodeStarNotifications::list_targets`].
+    pub fn list_targets(
+        &self,
+        input: ListTargetsRequest,
+    ) -> Result<ListTargetsResult, RusotoError<ListTargetsError>> {
+        self.runtime.block_on(self.client.list_targets(input))
+    }
+
+    /// Blocking version of [`CodeStarNotifications::subscribe`].
+    pub fn subscribe(
+        &self,
+        input: SubscribeRequest,
+    ) -> Result<SubscribeResult, RusotoError<SubscribeError>> {
+        self.runtime.block_on(self.client.subscribe(input))
+    }
+
+    /// Blocking version of [`CodeStarNotifications::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResult, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`CodeStarNotifications::unsubscribe`].
+    pub fn unsubscribe(
+        &self,
+        input: UnsubscribeRequest,
+    ) -> Result<UnsubscribeResult, RusotoError<UnsubscribeError>> {
+        self.runtime.block_on(self.client.unsubscribe(input))
+    }
+
+    /// Blocking version of [`CodeStarNotifications::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResult, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`CodeStarNotifications::update_notification_rule`].
+    pub fn update_notification_rule(
+        &self,
+        input: UpdateNotificationRuleRequest,
+    ) -> Result<UpdateNotificationRuleResult, RusotoError<UpdateNotificationRuleError>> {
+        self.runtime
+            .block_on(self.client.update_notification_rule(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -803,32 +804,54 @@ impl AssociateTeamMemberError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(AssociateTeamMemberError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateTeamMemberError::ConcurrentModification,
+                    )
                 }
                 "InvalidServiceRoleException" => {
-                    return RusotoError::Service(AssociateTeamMemberError::InvalidServiceRole(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateTeamMemberError::InvalidServiceRole,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AssociateTeamMemberError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateTeamMemberError::LimitExceeded,
+                    )
                 }
                 "ProjectConfigurationException" => {
-                    return RusotoError::Service(AssociateTeamMemberError::ProjectConfiguration(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateTeamMemberError::ProjectConfiguration,
+                    )
                 }
                 "ProjectNotFoundException" => {
-                    return RusotoError::Service(AssociateTeamMemberError::ProjectNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateTeamMemberError::ProjectNotFound,
+                    )
                 }
                 "TeamMemberAlreadyAssociatedException" => {
-                    return RusotoError::Service(
-                        AssociateTeamMemberError::TeamMemberAlreadyAssociated(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateTeamMemberError::TeamMemberAlreadyAssociated,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -873,26 +896,54 @@ impl CreateProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateProjectError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateProjectError::ConcurrentModification,
+                    )
                 }
                 "InvalidServiceRoleException" => {
-                    return RusotoError::Service(CreateProjectError::InvalidServiceRole(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProjectError::InvalidServiceRole,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateProjectError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProjectError::LimitExceeded,
+                    )
                 }
                 "ProjectAlreadyExistsException" => {
-                    return RusotoError::Service(CreateProjectError::ProjectAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProjectError::ProjectAlreadyExists,
+                    )
                 }
                 "ProjectConfigurationException" => {
-                    return RusotoError::Service(CreateProjectError::ProjectConfiguration(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProjectError::ProjectConfiguration,
+                    )
                 }
                 "ProjectCreationFailedException" => {
-                    return RusotoError::Service(CreateProjectError::ProjectCreationFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProjectError::ProjectCreationFailed,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -925,11 +976,14 @@ impl CreateUserProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "UserProfileAlreadyExistsException" => {
-                    return RusotoError::Service(CreateUserProfileError::UserProfileAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateUserProfileError::UserProfileAlreadyExists,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -959,14 +1013,22 @@ impl DeleteProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteProjectError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteProjectError::ConcurrentModification,
+                    )
                 }
                 "InvalidServiceRoleException" => {
-                    return RusotoError::Service(DeleteProjectError::InvalidServiceRole(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProjectError::InvalidServiceRole,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -991,7 +1053,7 @@ impl DeleteUserProfileError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteUserProfileError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1023,22 +1085,38 @@ impl DescribeProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DescribeProjectError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeProjectError::ConcurrentModification,
+                    )
                 }
                 "InvalidServiceRoleException" => {
-                    return RusotoError::Service(DescribeProjectError::InvalidServiceRole(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeProjectError::InvalidServiceRole,
+                    )
                 }
                 "ProjectConfigurationException" => {
-                    return RusotoError::Service(DescribeProjectError::ProjectConfiguration(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeProjectError::ProjectConfiguration,
+                    )
                 }
                 "ProjectNotFoundException" => {
-                    return RusotoError::Service(DescribeProjectError::ProjectNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeProjectError::ProjectNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1069,11 +1147,14 @@ impl DescribeUserProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "UserProfileNotFoundException" => {
-                    return RusotoError::Service(DescribeUserProfileError::UserProfileNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeUserProfileError::UserProfileNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1105,21 +1186,30 @@ impl DisassociateTeamMemberError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        DisassociateTeamMemberError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateTeamMemberError::ConcurrentModification,
                     )
                 }
                 "InvalidServiceRoleException" => {
-                    return RusotoError::Service(DisassociateTeamMemberError::InvalidServiceRole(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateTeamMemberError::InvalidServiceRole,
+                    )
                 }
                 "ProjectNotFoundException" => {
-                    return RusotoError::Service(DisassociateTeamMemberError::ProjectNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateTeamMemberError::ProjectNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1151,9 +1241,14 @@ impl ListProjectsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListProjectsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProjectsError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1183,12 +1278,22 @@ impl ListResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListResourcesError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourcesError::InvalidNextToken,
+                    )
                 }
                 "ProjectNotFoundException" => {
-                    return RusotoError::Service(ListResourcesError::ProjectNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourcesError::ProjectNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1219,12 +1324,22 @@ impl ListTagsForProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListTagsForProjectError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForProjectError::InvalidNextToken,
+                    )
                 }
                 "ProjectNotFoundException" => {
-                    return RusotoError::Service(ListTagsForProjectError::ProjectNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForProjectError::ProjectNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1255,12 +1370,22 @@ impl ListTeamMembersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListTeamMembersError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTeamMembersError::InvalidNextToken,
+                    )
                 }
                 "ProjectNotFoundException" => {
-                    return RusotoError::Service(ListTeamMembersError::ProjectNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTeamMembersError::ProjectNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1289,9 +1414,14 @@ impl ListUserProfilesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListUserProfilesError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUserProfilesError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1323,15 +1453,30 @@ impl TagProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(TagProjectError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagProjectError::ConcurrentModification,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagProjectError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagProjectError::LimitExceeded,
+                    )
                 }
                 "ProjectNotFoundException" => {
-                    return RusotoError::Service(TagProjectError::ProjectNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagProjectError::ProjectNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1365,15 +1510,30 @@ impl UntagProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UntagProjectError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagProjectError::ConcurrentModification,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UntagProjectError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagProjectError::LimitExceeded,
+                    )
                 }
                 "ProjectNotFoundException" => {
-                    return RusotoError::Service(UntagProjectError::ProjectNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagProjectError::ProjectNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1403,9 +1563,14 @@ impl UpdateProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ProjectNotFoundException" => {
-                    return RusotoError::Service(UpdateProjectError::ProjectNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateProjectError::ProjectNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1443,28 +1608,54 @@ impl UpdateTeamMemberError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateTeamMemberError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateTeamMemberError::ConcurrentModification,
+                    )
                 }
                 "InvalidServiceRoleException" => {
-                    return RusotoError::Service(UpdateTeamMemberError::InvalidServiceRole(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTeamMemberError::InvalidServiceRole,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateTeamMemberError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTeamMemberError::LimitExceeded,
+                    )
                 }
                 "ProjectConfigurationException" => {
-                    return RusotoError::Service(UpdateTeamMemberError::ProjectConfiguration(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateTeamMemberError::ProjectConfiguration,
+                    )
                 }
                 "ProjectNotFoundException" => {
-                    return RusotoError::Service(UpdateTeamMemberError::ProjectNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTeamMemberError::ProjectNotFound,
+                    )
                 }
                 "TeamMemberNotFoundException" => {
-                    return RusotoError::Service(UpdateTeamMemberError::TeamMemberNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTeamMemberError::TeamMemberNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1497,11 +1688,14 @@ impl UpdateUserProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "UserProfileNotFoundException" => {
-                    return RusotoError::Service(UpdateUserProfileError::UserProfileNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserProfileError::UserProfileNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1638,11 +1832,12 @@ pub struct CodeStarClient {
 impl CodeStarClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> CodeStarClient {
         CodeStarClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "CodeStar"),
         }
     }
 
@@ -1657,12 +1852,32 @@ impl CodeStarClient {
     {
         CodeStarClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "CodeStar"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CodeStarClient {
-        CodeStarClient { client, region }
+        CodeStarClient {
+            client,
+            region: region_for_service(region, "CodeStar"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> CodeStarClient {
+        CodeStarClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("CodeStar"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CodeStarClient {
+        CodeStarClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -1674,6 +1889,7 @@ impl CodeStar for CodeStarClient {
         input: AssociateTeamMemberRequest,
     ) -> Result<AssociateTeamMemberResult, RusotoError<AssociateTeamMemberError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateTeamMember");
         request.add_header("x-amz-target", "CodeStar_20170419.AssociateTeamMember");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1692,6 +1908,7 @@ impl CodeStar for CodeStarClient {
         input: CreateProjectRequest,
     ) -> Result<CreateProjectResult, RusotoError<CreateProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateProject");
         request.add_header("x-amz-target", "CodeStar_20170419.CreateProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1710,6 +1927,7 @@ impl CodeStar for CodeStarClient {
         input: CreateUserProfileRequest,
     ) -> Result<CreateUserProfileResult, RusotoError<CreateUserProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateUserProfile");
         request.add_header("x-amz-target", "CodeStar_20170419.CreateUserProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1728,6 +1946,7 @@ impl CodeStar for CodeStarClient {
         input: DeleteProjectRequest,
     ) -> Result<DeleteProjectResult, RusotoError<DeleteProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteProject");
         request.add_header("x-amz-target", "CodeStar_20170419.DeleteProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1746,6 +1965,7 @@ impl CodeStar for CodeStarClient {
         input: DeleteUserProfileRequest,
     ) -> Result<DeleteUserProfileResult, RusotoError<DeleteUserProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteUserProfile");
         request.add_header("x-amz-target", "CodeStar_20170419.DeleteUserProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1764,6 +1984,7 @@ impl CodeStar for CodeStarClient {
         input: DescribeProjectRequest,
     ) -> Result<DescribeProjectResult, RusotoError<DescribeProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeProject");
         request.add_header("x-amz-target", "CodeStar_20170419.DescribeProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1782,6 +2003,7 @@ impl CodeStar for CodeStarClient {
         input: DescribeUserProfileRequest,
     ) -> Result<DescribeUserProfileResult, RusotoError<DescribeUserProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeUserProfile");
         request.add_header("x-amz-target", "CodeStar_20170419.DescribeUserProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1800,6 +2022,7 @@ impl CodeStar for CodeStarClient {
         input: DisassociateTeamMemberRequest,
     ) -> Result<DisassociateTeamMemberResult, RusotoError<DisassociateTeamMemberError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisassociateTeamMember");
         request.add_header("x-amz-target", "CodeStar_20170419.DisassociateTeamMember");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1819,6 +2042,7 @@ impl CodeStar for CodeStarClient {
         input: ListProjectsRequest,
     ) -> Result<ListProjectsResult, RusotoError<ListProjectsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListProjects");
         request.add_header("x-amz-target", "CodeStar_20170419.ListProjects");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1837,6 +2061,7 @@ impl CodeStar for CodeStarClient {
         input: ListResourcesRequest,
     ) -> Result<ListResourcesResult, RusotoError<ListResourcesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListResources");
         request.add_header("x-amz-target", "CodeStar_20170419.ListResources");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1855,6 +2080,7 @@ impl CodeStar for CodeStarClient {
         input: ListTagsForProjectRequest,
     ) -> Result<ListTagsForProjectResult, RusotoError<ListTagsForProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForProject");
         request.add_header("x-amz-target", "CodeStar_20170419.ListTagsForProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1873,6 +2099,7 @@ impl CodeStar for CodeStarClient {
         input: ListTeamMembersRequest,
     ) -> Result<ListTeamMembersResult, RusotoError<ListTeamMembersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTeamMembers");
         request.add_header("x-amz-target", "CodeStar_20170419.ListTeamMembers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1891,6 +2118,7 @@ impl CodeStar for CodeStarClient {
         input: ListUserProfilesRequest,
     ) -> Result<ListUserProfilesResult, RusotoError<ListUserProfilesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListUserProfiles");
         request.add_header("x-amz-target", "CodeStar_20170419.ListUserProfiles");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1909,6 +2137,7 @@ impl CodeStar for CodeStarClient {
         input: TagProjectRequest,
     ) -> Result<TagProjectResult, RusotoError<TagProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagProject");
         request.add_header("x-amz-target", "CodeStar_20170419.TagProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1927,6 +2156,7 @@ impl CodeStar for CodeStarClient {
         input: UntagProjectRequest,
     ) -> Result<UntagProjectResult, RusotoError<UntagProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagProject");
         request.add_header("x-amz-target", "CodeStar_20170419.UntagProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1945,6 +2175,7 @@ impl CodeStar for CodeStarClient {
         input: UpdateProjectRequest,
     ) -> Result<UpdateProjectResult, RusotoError<UpdateProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateProject");
         request.add_header("x-amz-target", "CodeStar_20170419.UpdateProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1963,6 +2194,7 @@ impl CodeStar for CodeStarClient {
         input: UpdateTeamMemberRequest,
     ) -> Result<UpdateTeamMemberResult, RusotoError<UpdateTeamMemberError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateTeamMember");
         request.add_header("x-amz-target", "CodeStar_20170419.UpdateTeamMember");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1981,6 +2213,7 @@ impl CodeStar for CodeStarClient {
         input: UpdateUserProfileRequest,
     ) -> Result<UpdateUserProfileResult, RusotoError<UpdateUserProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateUserProfile");
         request.add_header("x-amz-target", "CodeStar_20170419.UpdateUserProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1993,3 +2226,229 @@ impl CodeStar for CodeStarClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateUserProfileResult, _>()
     }
 }
+
+/// A blocking client for the CodeStar API, running the calls of a `CodeStarClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `CodeStarClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CodeStarBlockingClient {
+    client: CodeStarClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CodeStarBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> CodeStarBlockingClient {
+        CodeStarBlockingClient::from_client(CodeStarClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CodeStarBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CodeStarBlockingClient::from_client(CodeStarClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> CodeStarBlockingClient {
+        CodeStarBlockingClient::from_client(CodeStarClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> CodeStarBlockingClient {
+        CodeStarBlockingClient::from_client(CodeStarClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: CodeStarClient) -> CodeStarBlockingClient {
+        CodeStarBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> CodeStarBlockingClient {
+        CodeStarBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CodeStarBlockingClient {
+        CodeStarBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`CodeStar::associate_team_member`].
+    pub fn associate_team_member(
+        &self,
+        input: AssociateTeamMemberRequest,
+    ) -> Result<AssociateTeamMemberResult, RusotoError<AssociateTeamMemberError>> {
+        self.runtime
+            .block_on(self.client.associate_team_member(input))
+    }
+
+    /// Blocking version of [`CodeStar::create_project`].
+    pub fn create_project(
+        &self,
+        input: CreateProjectRequest,
+    ) -> Result<CreateProjectResult, RusotoError<CreateProjectError>> {
+        self.runtime.block_on(self.client.create_project(input))
+    }
+
+    /// Blocking version of [`CodeStar::create_user_profile`].
+    pub fn create_user_profile(
+        &self,
+        input: CreateUserProfileRequest,
+    ) -> Result<CreateUserProfileResult, RusotoError<CreateUserProfileError>> {
+        self.runtime
+            .block_on(self.client.create_user_profile(input))
+    }
+
+    /// Blocking version of [`CodeStar::delete_project`].
+    pub fn delete_project(
+        &self,
+        input: DeleteProjectRequest,
+    ) -> Result<DeleteProjectResult, RusotoError<DeleteProjectError>> {
+        self.runtime.block_on(self.client.delete_project(input))
+    }
+
+    /// Blocking version of [`CodeStar::delete_user_profile`].
+    pub fn delete_user_profile(
+        &self,
+        input: DeleteUserProfileRequest,
+    ) -> Result<DeleteUserProfileResult, RusotoError<DeleteUserProfileError>> {
+        self.runtime
+            .block_on(self.client.delete_user_profile(input))
+    }
+
+    /// Blocking version of [`CodeStar::describe_project`].
+    pub fn describe_project(
+        &self,
+        input: DescribeProjectRequest,
+    ) -> Result<DescribeProjectResult, RusotoError<DescribeProjectError>> {
+        self.runtime.block_on(self.client.describe_project(input))
+    }
+
+    /// Blocking version of [`CodeStar::describe_user_profile`].
+    pub fn describe_user_profile(
+        &self,
+        input: DescribeUserProfileRequest,
+    ) -> Result<DescribeUserProfileResult, RusotoError<DescribeUserProfileError>> {
+        self.runtime
+            .block_on(self.client.describe_user_profile(input))
+    }
+
+    /// Blocking version of [`CodeStar::disassociate_team_member`].
+    pub fn disassociate_team_member(
+        &self,
+        input: DisassociateTeamMemberRequest,
+    ) -> Result<DisassociateTeamMemberResult, RusotoError<DisassociateTeamMemberError>> {
+        self.runtime
+            .block_on(self.client.disassociate_team_member(input))
+    }
+
+    /// Blocking version of [`CodeStar::list_projects`].
+    pub fn list_projects(
+        &self,
+        input: ListProjectsRequest,
+    ) -> Result<ListProjectsResult, RusotoError<ListProjectsError>> {
+        self.runtime.block_on(self.client.list_projects(input))
+    }
+
+    /// Blocking version of [`CodeStar::list_resources`].
+    pub fn list_resources(
+        &self,
+        input: ListResourcesRequest,
+    ) -> Result<ListResourcesResult, RusotoError<ListResourcesError>> {
+        self.runtime.block_on(self.client.list_resources(input))
+    }
+
+    /// Blocking version of [`CodeStar::list_tags_for_project`].
+    pub fn list_tags_for_project(
+        &self,
+        input: ListTagsForProjectRequest,
+    ) -> Result<ListTagsForProjectResult, RusotoError<ListTagsForProjectError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_project(input))
+    }
+
+    /// Blocking version of [`CodeStar::list_team_members`].
+    pub fn list_team_members(
+        &self,
+        input: ListTeamMembersRequest,
+    ) -> Result<ListTeamMembersResult, RusotoError<ListTeamMembersError>> {
+        self.runtime.block_on(self.client.list_team_members(input))
+    }
+
+    /// Blocking version of [`CodeStar::list_user_profiles`].
+    pub fn list_user_profiles(
+        &self,
+        input: ListUserProfilesRequest,
+    ) -> Result<ListUserProfilesResult, RusotoError<ListUserProfilesError>> {
+        self.runtime.block_on(self.client.list_user_profiles(input))
+    }
+
+    /// Blocking version of [`CodeStar::tag_project`].
+    pub fn tag_project(
+        &self,
+        input: TagProjectRequest,
+    ) -> Result<TagProjectResult, RusotoError<TagProjectError>> {
+        self.runtime.block_on(self.client.tag_project(input))
+    }
+
+    /// Blocking version of [`CodeStar::untag_project`].
+    pub fn untag_project(
+        &self,
+        input: UntagProjectRequest,
+    ) -> Result<UntagProjectResult, RusotoError<UntagProjectError>> {
+        self.runtime.block_on(self.client.untag_project(input))
+    }
+
+    /// Blocking version of [`CodeStar::update_project`].
+    pub fn update_project(
+        &self,
+        input: UpdateProjectRequest,
+    ) -> Result<UpdateProjectResult, RusotoError<UpdateProjectError>> {
+        self.runtime.block_on(self.client.update_project(input))
+    }
+
+    /// Blocking version of [`CodeStar::update_team_member`].
+    pub fn update_team_member(
+        &self,
+        input: UpdateTeamMemberRequest,
+    ) -> Result<UpdateTeamMemberResult, RusotoError<UpdateTeamMemberError>> {
+        self.runtime.block_on(self.client.update_team_member(input))
+    }
+
+    /// Blocking version of [`CodeStar::update_user_profile`].
+    pub fn update_user_profile(
+        &self,
+        input: UpdateUserProfileRequest,
+    ) -> Result<UpdateUserProfileResult, RusotoError<UpdateUserProfileError>> {
+        self.runtime
+            .block_on(self.client.update_user_profile(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -709,24 +710,54 @@ impl CreateIdentityPoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateIdentityPoolError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIdentityPoolError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateIdentityPoolError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIdentityPoolError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateIdentityPoolError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIdentityPoolError::LimitExceeded,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(CreateIdentityPoolError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIdentityPoolError::NotAuthorized,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(CreateIdentityPoolError::ResourceConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIdentityPoolError::ResourceConflict,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateIdentityPoolError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIdentityPoolError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -763,15 +794,30 @@ impl DeleteIdentitiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteIdentitiesError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIdentitiesError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteIdentitiesError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIdentitiesError::InvalidParameter,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteIdentitiesError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIdentitiesError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -809,21 +855,46 @@ impl DeleteIdentityPoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteIdentityPoolError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIdentityPoolError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteIdentityPoolError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIdentityPoolError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DeleteIdentityPoolError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIdentityPoolError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteIdentityPoolError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIdentityPoolError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteIdentityPoolError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIdentityPoolError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -863,21 +934,46 @@ impl DescribeIdentityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeIdentityError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeIdentityError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeIdentityError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeIdentityError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeIdentityError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeIdentityError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeIdentityError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeIdentityError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeIdentityError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeIdentityError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -917,27 +1013,46 @@ impl DescribeIdentityPoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeIdentityPoolError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeIdentityPoolError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeIdentityPoolError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeIdentityPoolError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeIdentityPoolError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeIdentityPoolError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeIdentityPoolError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeIdentityPoolError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeIdentityPoolError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeIdentityPoolError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -983,46 +1098,70 @@ impl GetCredentialsForIdentityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExternalServiceException" => {
-                    return RusotoError::Service(GetCredentialsForIdentityError::ExternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCredentialsForIdentityError::ExternalService,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetCredentialsForIdentityError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCredentialsForIdentityError::InternalError,
+                    )
                 }
                 "InvalidIdentityPoolConfigurationException" => {
-                    return RusotoError::Service(
-                        GetCredentialsForIdentityError::InvalidIdentityPoolConfiguration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCredentialsForIdentityError::InvalidIdentityPoolConfiguration,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetCredentialsForIdentityError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCredentialsForIdentityError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetCredentialsForIdentityError::NotAuthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCredentialsForIdentityError::NotAuthorized,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(GetCredentialsForIdentityError::ResourceConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCredentialsForIdentityError::ResourceConflict,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetCredentialsForIdentityError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCredentialsForIdentityError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetCredentialsForIdentityError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCredentialsForIdentityError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1073,30 +1212,70 @@ impl GetIdError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExternalServiceException" => {
-                    return RusotoError::Service(GetIdError::ExternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIdError::ExternalService,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetIdError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIdError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetIdError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIdError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetIdError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIdError::LimitExceeded,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetIdError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIdError::NotAuthorized,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(GetIdError::ResourceConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIdError::ResourceConflict,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetIdError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIdError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetIdError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIdError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1141,32 +1320,54 @@ impl GetIdentityPoolRolesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetIdentityPoolRolesError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIdentityPoolRolesError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetIdentityPoolRolesError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetIdentityPoolRolesError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetIdentityPoolRolesError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIdentityPoolRolesError::NotAuthorized,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(GetIdentityPoolRolesError::ResourceConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetIdentityPoolRolesError::ResourceConflict,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetIdentityPoolRolesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetIdentityPoolRolesError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetIdentityPoolRolesError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetIdentityPoolRolesError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1211,27 +1412,62 @@ impl GetOpenIdTokenError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExternalServiceException" => {
-                    return RusotoError::Service(GetOpenIdTokenError::ExternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOpenIdTokenError::ExternalService,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetOpenIdTokenError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOpenIdTokenError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetOpenIdTokenError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOpenIdTokenError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetOpenIdTokenError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOpenIdTokenError::NotAuthorized,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(GetOpenIdTokenError::ResourceConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOpenIdTokenError::ResourceConflict,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetOpenIdTokenError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOpenIdTokenError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetOpenIdTokenError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOpenIdTokenError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1279,43 +1515,62 @@ impl GetOpenIdTokenForDeveloperIdentityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DeveloperUserAlreadyRegisteredException" => {
-                    return RusotoError::Service(
-                        GetOpenIdTokenForDeveloperIdentityError::DeveloperUserAlreadyRegistered(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOpenIdTokenForDeveloperIdentityError::DeveloperUserAlreadyRegistered,
                     )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(
-                        GetOpenIdTokenForDeveloperIdentityError::InternalError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOpenIdTokenForDeveloperIdentityError::InternalError,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        GetOpenIdTokenForDeveloperIdentityError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOpenIdTokenForDeveloperIdentityError::InvalidParameter,
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(
-                        GetOpenIdTokenForDeveloperIdentityError::NotAuthorized(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOpenIdTokenForDeveloperIdentityError::NotAuthorized,
                     )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(
-                        GetOpenIdTokenForDeveloperIdentityError::ResourceConflict(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOpenIdTokenForDeveloperIdentityError::ResourceConflict,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        GetOpenIdTokenForDeveloperIdentityError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOpenIdTokenForDeveloperIdentityError::ResourceNotFound,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        GetOpenIdTokenForDeveloperIdentityError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOpenIdTokenForDeveloperIdentityError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1371,21 +1626,46 @@ impl ListIdentitiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListIdentitiesError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIdentitiesError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListIdentitiesError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIdentitiesError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListIdentitiesError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIdentitiesError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListIdentitiesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIdentitiesError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListIdentitiesError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIdentitiesError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1425,21 +1705,46 @@ impl ListIdentityPoolsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListIdentityPoolsError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIdentityPoolsError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListIdentityPoolsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIdentityPoolsError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListIdentityPoolsError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIdentityPoolsError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListIdentityPoolsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIdentityPoolsError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListIdentityPoolsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIdentityPoolsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1479,25 +1784,46 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListTagsForResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1539,36 +1865,54 @@ impl LookupDeveloperIdentityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(LookupDeveloperIdentityError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        LookupDeveloperIdentityError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(LookupDeveloperIdentityError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        LookupDeveloperIdentityError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(LookupDeveloperIdentityError::NotAuthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        LookupDeveloperIdentityError::NotAuthorized,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(LookupDeveloperIdentityError::ResourceConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        LookupDeveloperIdentityError::ResourceConflict,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(LookupDeveloperIdentityError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        LookupDeveloperIdentityError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(LookupDeveloperIdentityError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        LookupDeveloperIdentityError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1611,36 +1955,54 @@ impl MergeDeveloperIdentitiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(MergeDeveloperIdentitiesError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergeDeveloperIdentitiesError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(MergeDeveloperIdentitiesError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergeDeveloperIdentitiesError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(MergeDeveloperIdentitiesError::NotAuthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergeDeveloperIdentitiesError::NotAuthorized,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(MergeDeveloperIdentitiesError::ResourceConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergeDeveloperIdentitiesError::ResourceConflict,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(MergeDeveloperIdentitiesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergeDeveloperIdentitiesError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(MergeDeveloperIdentitiesError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MergeDeveloperIdentitiesError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1685,37 +2047,62 @@ impl SetIdentityPoolRolesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(SetIdentityPoolRolesError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetIdentityPoolRolesError::ConcurrentModification,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(SetIdentityPoolRolesError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetIdentityPoolRolesError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SetIdentityPoolRolesError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetIdentityPoolRolesError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(SetIdentityPoolRolesError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetIdentityPoolRolesError::NotAuthorized,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(SetIdentityPoolRolesError::ResourceConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetIdentityPoolRolesError::ResourceConflict,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SetIdentityPoolRolesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetIdentityPoolRolesError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(SetIdentityPoolRolesError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetIdentityPoolRolesError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1757,21 +2144,46 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(TagResourceError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(TagResourceError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1813,36 +2225,54 @@ impl UnlinkDeveloperIdentityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(UnlinkDeveloperIdentityError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UnlinkDeveloperIdentityError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UnlinkDeveloperIdentityError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UnlinkDeveloperIdentityError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UnlinkDeveloperIdentityError::NotAuthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UnlinkDeveloperIdentityError::NotAuthorized,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(UnlinkDeveloperIdentityError::ResourceConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UnlinkDeveloperIdentityError::ResourceConflict,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UnlinkDeveloperIdentityError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UnlinkDeveloperIdentityError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UnlinkDeveloperIdentityError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UnlinkDeveloperIdentityError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1887,27 +2317,62 @@ impl UnlinkIdentityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExternalServiceException" => {
-                    return RusotoError::Service(UnlinkIdentityError::ExternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UnlinkIdentityError::ExternalService,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(UnlinkIdentityError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UnlinkIdentityError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UnlinkIdentityError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UnlinkIdentityError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UnlinkIdentityError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UnlinkIdentityError::NotAuthorized,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(UnlinkIdentityError::ResourceConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UnlinkIdentityError::ResourceConflict,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UnlinkIdentityError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UnlinkIdentityError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UnlinkIdentityError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UnlinkIdentityError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1949,21 +2414,46 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(UntagResourceError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UntagResourceError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2009,32 +2499,70 @@ impl UpdateIdentityPoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateIdentityPoolError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateIdentityPoolError::ConcurrentModification,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateIdentityPoolError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIdentityPoolError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateIdentityPoolError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIdentityPoolError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateIdentityPoolError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIdentityPoolError::LimitExceeded,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UpdateIdentityPoolError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIdentityPoolError::NotAuthorized,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(UpdateIdentityPoolError::ResourceConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIdentityPoolError::ResourceConflict,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateIdentityPoolError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIdentityPoolError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateIdentityPoolError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIdentityPoolError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2196,11 +2724,12 @@ pub struct CognitoIdentityClient {
 impl CognitoIdentityClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> CognitoIdentityClient {
         CognitoIdentityClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Cognito Identity"),
         }
     }
 
@@ -2215,12 +2744,32 @@ impl CognitoIdentityClient {
     {
         CognitoIdentityClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Cognito Identity"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CognitoIdentityClient {
-        CognitoIdentityClient { client, region }
+        CognitoIdentityClient {
+            client,
+            region: region_for_service(region, "Cognito Identity"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> CognitoIdentityClient {
+        CognitoIdentityClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Cognito Identity"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CognitoIdentityClient {
+        CognitoIdentityClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -2232,6 +2781,7 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: CreateIdentityPoolInput,
     ) -> Result<IdentityPool, RusotoError<CreateIdentityPoolError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateIdentityPool");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityService.CreateIdentityPool",
@@ -2253,6 +2803,7 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: DeleteIdentitiesInput,
     ) -> Result<DeleteIdentitiesResponse, RusotoError<DeleteIdentitiesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteIdentities");
         request.add_header("x-amz-target", "AWSCognitoIdentityService.DeleteIdentities");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2271,6 +2822,7 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: DeleteIdentityPoolInput,
     ) -> Result<(), RusotoError<DeleteIdentityPoolError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteIdentityPool");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityService.DeleteIdentityPool",
@@ -2291,6 +2843,7 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: DescribeIdentityInput,
     ) -> Result<IdentityDescription, RusotoError<DescribeIdentityError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeIdentity");
         request.add_header("x-amz-target", "AWSCognitoIdentityService.DescribeIdentity");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2309,6 +2862,7 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: DescribeIdentityPoolInput,
     ) -> Result<IdentityPool, RusotoError<DescribeIdentityPoolError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeIdentityPool");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityService.DescribeIdentityPool",
@@ -2331,6 +2885,7 @@ impl CognitoIdentity for CognitoIdentityClient {
     ) -> Result<GetCredentialsForIdentityResponse, RusotoError<GetCredentialsForIdentityError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetCredentialsForIdentity");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityService.GetCredentialsForIdentity",
@@ -2350,6 +2905,7 @@ impl CognitoIdentity for CognitoIdentityClient {
     /// <p>Generates (or retrieves) a Cognito ID. Supplying multiple logins will create an implicit linked account.</p> <p>This is a public API. You do not need any credentials to call this API.</p>
     async fn get_id(&self, input: GetIdInput) -> Result<GetIdResponse, RusotoError<GetIdError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetId");
         request.add_header("x-amz-target", "AWSCognitoIdentityService.GetId");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2368,6 +2924,7 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: GetIdentityPoolRolesInput,
     ) -> Result<GetIdentityPoolRolesResponse, RusotoError<GetIdentityPoolRolesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetIdentityPoolRoles");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityService.GetIdentityPoolRoles",
@@ -2390,6 +2947,7 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: GetOpenIdTokenInput,
     ) -> Result<GetOpenIdTokenResponse, RusotoError<GetOpenIdTokenError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetOpenIdToken");
         request.add_header("x-amz-target", "AWSCognitoIdentityService.GetOpenIdToken");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2411,6 +2969,7 @@ impl CognitoIdentity for CognitoIdentityClient {
         RusotoError<GetOpenIdTokenForDeveloperIdentityError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetOpenIdTokenForDeveloperIdentity");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityService.GetOpenIdTokenForDeveloperIdentity",
@@ -2436,6 +2995,7 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: ListIdentitiesInput,
     ) -> Result<ListIdentitiesResponse, RusotoError<ListIdentitiesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListIdentities");
         request.add_header("x-amz-target", "AWSCognitoIdentityService.ListIdentities");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2454,6 +3014,7 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: ListIdentityPoolsInput,
     ) -> Result<ListIdentityPoolsResponse, RusotoError<ListIdentityPoolsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListIdentityPools");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityService.ListIdentityPools",
@@ -2475,6 +3036,7 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: ListTagsForResourceInput,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityService.ListTagsForResource",
@@ -2496,6 +3058,7 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: LookupDeveloperIdentityInput,
     ) -> Result<LookupDeveloperIdentityResponse, RusotoError<LookupDeveloperIdentityError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("LookupDeveloperIdentity");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityService.LookupDeveloperIdentity",
@@ -2518,6 +3081,7 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: MergeDeveloperIdentitiesInput,
     ) -> Result<MergeDeveloperIdentitiesResponse, RusotoError<MergeDeveloperIdentitiesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("MergeDeveloperIdentities");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityService.MergeDeveloperIdentities",
@@ -2540,6 +3104,7 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: SetIdentityPoolRolesInput,
     ) -> Result<(), RusotoError<SetIdentityPoolRolesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SetIdentityPoolRoles");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityService.SetIdentityPoolRoles",
@@ -2560,6 +3125,7 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: TagResourceInput,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "AWSCognitoIdentityService.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2578,6 +3144,7 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: UnlinkDeveloperIdentityInput,
     ) -> Result<(), RusotoError<UnlinkDeveloperIdentityError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UnlinkDeveloperIdentity");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityService.UnlinkDeveloperIdentity",
@@ -2598,6 +3165,7 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: UnlinkIdentityInput,
     ) -> Result<(), RusotoError<UnlinkIdentityError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UnlinkIdentity");
         request.add_header("x-amz-target", "AWSCognitoIdentityService.UnlinkIdentity");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2615,6 +3183,7 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: UntagResourceInput,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "AWSCognitoIdentityService.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2633,6 +3202,7 @@ impl CognitoIdentity for CognitoIdentityClient {
         input: IdentityPool,
     ) -> Result<IdentityPool, RusotoError<UpdateIdentityPoolError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateIdentityPool");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityService.UpdateIdentityPool",
@@ -2648,3 +3218,268 @@ impl CognitoIdentity for CognitoIdentityClient {
         proto::json::ResponsePayload::new(&response).deserialize::<IdentityPool, _>()
     }
 }
+
+/// A blocking client for the Amazon Cognito Identity API, running the calls of a `CognitoIdentityClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `CognitoIdentityClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CognitoIdentityBlockingClient {
+    client: CognitoIdentityClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CognitoIdentityBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> CognitoIdentityBlockingClient {
+        CognitoIdentityBlockingClient::from_client(CognitoIdentityClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CognitoIdentityBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CognitoIdentityBlockingClient::from_client(CognitoIdentityClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> CognitoIdentityBlockingClient {
+        CognitoIdentityBlockingClient::from_client(CognitoIdentityClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> CognitoIdentityBlockingClient {
+        CognitoIdentityBlockingClient::from_client(CognitoIdentityClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: CognitoIdentityClient) -> CognitoIdentityBlockingClient {
+        CognitoIdentityBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> CognitoIdentityBlockingClient {
+        CognitoIdentityBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CognitoIdentityBlockingClient {
+        CognitoIdentityBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`CognitoIdentity::create_identity_pool`].
+    pub fn create_identity_pool(
+        &self,
+        input: CreateIdentityPoolInput,
+    ) -> Result<IdentityPool, RusotoError<CreateIdentityPoolError>> {
+        self.runtime
+            .block_on(self.client.create_identity_pool(input))
+    }
+
+    /// Blocking version of [`CognitoIdentity::delete_identities`].
+    pub fn delete_identities(
+        &self,
+        input: DeleteIdentitiesInput,
+    ) -> Result<DeleteIdentitiesResponse, RusotoError<DeleteIdentitiesError>> {
+        self.runtime.block_on(self.client.delete_identities(input))
+    }
+
+    /// Blocking version of [`CognitoIdentity::delete_identity_pool`].
+    pub fn delete_identity_pool(
+        &self,
+        input: DeleteIdentityPoolInput,
+    ) -> Result<(), RusotoError<DeleteIdentityPoolError>> {
+        self.runtime
+            .block_on(self.client.delete_identity_pool(input))
+    }
+
+    /// Blocking version of [`CognitoIdentity::describe_identity`].
+    pub fn describe_identity(
+        &self,
+        input: DescribeIdentityInput,
+    ) -> Result<IdentityDescription, RusotoError<DescribeIdentityError>> {
+        self.runtime.block_on(self.client.describe_identity(input))
+    }
+
+    /// Blocking version of [`CognitoIdentity::describe_identity_pool`].
+    pub fn describe_identity_pool(
+        &self,
+        input: DescribeIdentityPoolInput,
+    ) -> Result<IdentityPool, RusotoError<DescribeIdentityPoolError>> {
+        self.runtime
+            .block_on(self.client.describe_identity_pool(input))
+    }
+
+    /// Blocking version of [`CognitoIdentity::get_credentials_for_identity`].
+    pub fn get_credentials_for_identity(
+        &self,
+        input: GetCredentialsForIdentityInput,
+    ) -> Result<GetCredentialsForIdentityResponse, RusotoError<GetCredentialsForIdentityError>>
+    {
+        self.runtime
+            .block_on(self.client.get_credentials_for_identity(input))
+    }
+
+    /// Blocking version of [`CognitoIdentity::get_id`].
+    pub fn get_id(&self, input: GetIdInput) -> Result<GetIdResponse, RusotoError<GetIdError>> {
+        self.runtime.block_on(self.client.get_id(input))
+    }
+
+    /// Blocking version of [`CognitoIdentity::get_identity_pool_roles`].
+    pub fn get_identity_pool_roles(
+        &self,
+        input: GetIdentityPoolRolesInput,
+    ) -> Result<GetIdentityPoolRolesResponse, RusotoError<GetIdentityPoolRolesError>> {
+        self.runtime
+            .block_on(self.client.get_identity_pool_roles(input))
+    }
+
+    /// Blocking version of [`CognitoIdentity::get_open_id_token`].
+    pub fn get_open_id_token(
+        &self,
+        input: GetOpenIdTokenInput,
+    ) -> Result<GetOpenIdTokenResponse, RusotoError<GetOpenIdTokenError>> {
+        self.runtime.block_on(self.client.get_open_id_token(input))
+    }
+
+    /// Blocking version of [`CognitoIdentity::get_open_id_token_for_developer_identity`].
+    pub fn get_open_id_token_for_developer_identity(
+        &self,
+        input: GetOpenIdTokenForDeveloperIdentityInput,
+    ) -> Result<
+        GetOpenIdTokenForDeveloperIdentityResponse,
+        RusotoError<GetOpenIdTokenForDeveloperIdentityError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_open_id_token_for_developer_identity(input))
+    }
+
+    /// Blocking version of [`CognitoIdentity::list_identities`].
+    pub fn list_identities(
+        &self,
+        input: ListIdentitiesInput,
+    ) -> Result<ListIdentitiesResponse, RusotoError<ListIdentitiesError>> {
+        self.runtime.block_on(self.client.list_identities(input))
+    }
+
+    /// Blocking version of [`CognitoIdentity::list_identity_pools`].
+    pub fn list_identity_pools(
+        &self,
+        input: ListIdentityPoolsInput,
+    ) -> Result<ListIdentityPoolsResponse, RusotoError<ListIdentityPoolsError>> {
+        self.runtime
+            .block_on(self.client.list_identity_pools(input))
+    }
+
+    /// Blocking version of [`CognitoIdentity::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`CognitoIdentity::lookup_developer_identity`].
+    pub fn lookup_developer_identity(
+        &self,
+        input: LookupDeveloperIdentityInput,
+    ) -> Result<LookupDeveloperIdentityResponse, RusotoError<LookupDeveloperIdentityError>> {
+        self.runtime
+            .block_on(self.client.lookup_developer_identity(input))
+    }
+
+    /// Blocking version of [`CognitoIdentity::merge_developer_identities`].
+    pub fn merge_developer_identities(
+        &self,
+        input: MergeDeveloperIdentitiesInput,
+    ) -> Result<MergeDeveloperIdentitiesResponse, RusotoError<MergeDeveloperIdentitiesError>> {
+        self.runtime
+            .block_on(self.client.merge_developer_identities(input))
+    }
+
+    /// Blocking version of [`CognitoIdentity::set_identity_pool_roles`].
+    pub fn set_identity_pool_roles(
+        &self,
+        input: SetIdentityPoolRolesInput,
+    ) -> Result<(), RusotoError<SetIdentityPoolRolesError>> {
+        self.runtime
+            .block_on(self.client.set_identity_pool_roles(input))
+    }
+
+    /// Blocking version of [`CognitoIdentity::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`CognitoIdentity::unlink_developer_identity`].
+    pub fn unlink_developer_identity(
+        &self,
+        input: UnlinkDeveloperIdentityInput,
+    ) -> Result<(), RusotoError<UnlinkDeveloperIdentityError>> {
+        self.runtime
+            .block_on(self.client.unlink_developer_identity(input))
+    }
+
+    /// Blocking version of [`CognitoIdentity::unlink_identity`].
+    pub fn unlink_identity(
+        &self,
+        input: UnlinkIdentityInput,
+    ) -> Result<(), RusotoError<UnlinkIdentityError>> {
+        self.runtime.block_on(self.client.unlink_identity(input))
+    }
+
+    /// Blocking version of [`CognitoIdentity::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`CognitoIdentity::update_identity_pool`].
+    pub fn update_identity_pool(
+        &self,
+        input: IdentityPool,
+    ) -> Result<IdentityPool, RusotoError<UpdateIdentityPoolError>> {
+        self.runtime
+            .block_on(self.client.update_identity_pool(input))
+    }
+}
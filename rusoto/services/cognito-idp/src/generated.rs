@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -4306,30 +4307,54 @@ impl AddCustomAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AddCustomAttributesError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddCustomAttributesError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AddCustomAttributesError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddCustomAttributesError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AddCustomAttributesError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddCustomAttributesError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddCustomAttributesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddCustomAttributesError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AddCustomAttributesError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddCustomAttributesError::TooManyRequests,
+                    )
                 }
                 "UserImportInProgressException" => {
-                    return RusotoError::Service(AddCustomAttributesError::UserImportInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddCustomAttributesError::UserImportInProgress,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4372,28 +4397,54 @@ impl AdminAddUserToGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminAddUserToGroupError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminAddUserToGroupError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminAddUserToGroupError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminAddUserToGroupError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminAddUserToGroupError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminAddUserToGroupError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminAddUserToGroupError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminAddUserToGroupError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminAddUserToGroupError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminAddUserToGroupError::TooManyRequests,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminAddUserToGroupError::UserNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminAddUserToGroupError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4446,45 +4497,94 @@ impl AdminConfirmSignUpError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminConfirmSignUpError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminConfirmSignUpError::InternalError,
+                    )
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(AdminConfirmSignUpError::InvalidLambdaResponse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminConfirmSignUpError::InvalidLambdaResponse,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminConfirmSignUpError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminConfirmSignUpError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AdminConfirmSignUpError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminConfirmSignUpError::LimitExceeded,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminConfirmSignUpError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminConfirmSignUpError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminConfirmSignUpError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminConfirmSignUpError::ResourceNotFound,
+                    )
                 }
                 "TooManyFailedAttemptsException" => {
-                    return RusotoError::Service(AdminConfirmSignUpError::TooManyFailedAttempts(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminConfirmSignUpError::TooManyFailedAttempts,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminConfirmSignUpError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminConfirmSignUpError::TooManyRequests,
+                    )
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(AdminConfirmSignUpError::UnexpectedLambda(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminConfirmSignUpError::UnexpectedLambda,
+                    )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(AdminConfirmSignUpError::UserLambdaValidation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminConfirmSignUpError::UserLambdaValidation,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminConfirmSignUpError::UserNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminConfirmSignUpError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4552,64 +4652,134 @@ impl AdminCreateUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeDeliveryFailureException" => {
-                    return RusotoError::Service(AdminCreateUserError::CodeDeliveryFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminCreateUserError::CodeDeliveryFailure,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminCreateUserError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminCreateUserError::InternalError,
+                    )
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(AdminCreateUserError::InvalidLambdaResponse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminCreateUserError::InvalidLambdaResponse,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminCreateUserError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminCreateUserError::InvalidParameter,
+                    )
                 }
                 "InvalidPasswordException" => {
-                    return RusotoError::Service(AdminCreateUserError::InvalidPassword(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminCreateUserError::InvalidPassword,
+                    )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(AdminCreateUserError::InvalidSmsRoleAccessPolicy(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminCreateUserError::InvalidSmsRoleAccessPolicy,
+                    )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
-                        AdminCreateUserError::InvalidSmsRoleTrustRelationship(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminCreateUserError::InvalidSmsRoleTrustRelationship,
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminCreateUserError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminCreateUserError::NotAuthorized,
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(AdminCreateUserError::PreconditionNotMet(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminCreateUserError::PreconditionNotMet,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminCreateUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminCreateUserError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminCreateUserError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminCreateUserError::TooManyRequests,
+                    )
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(AdminCreateUserError::UnexpectedLambda(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminCreateUserError::UnexpectedLambda,
+                    )
                 }
                 "UnsupportedUserStateException" => {
-                    return RusotoError::Service(AdminCreateUserError::UnsupportedUserState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminCreateUserError::UnsupportedUserState,
+                    )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(AdminCreateUserError::UserLambdaValidation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminCreateUserError::UserLambdaValidation,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminCreateUserError::UserNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminCreateUserError::UserNotFound,
+                    )
                 }
                 "UsernameExistsException" => {
-                    return RusotoError::Service(AdminCreateUserError::UsernameExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminCreateUserError::UsernameExists,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4664,24 +4834,54 @@ impl AdminDeleteUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminDeleteUserError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminDeleteUserError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminDeleteUserError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminDeleteUserError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminDeleteUserError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminDeleteUserError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminDeleteUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminDeleteUserError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminDeleteUserError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminDeleteUserError::TooManyRequests,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminDeleteUserError::UserNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminDeleteUserError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4724,36 +4924,54 @@ impl AdminDeleteUserAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminDeleteUserAttributesError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminDeleteUserAttributesError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminDeleteUserAttributesError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminDeleteUserAttributesError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminDeleteUserAttributesError::NotAuthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminDeleteUserAttributesError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminDeleteUserAttributesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminDeleteUserAttributesError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminDeleteUserAttributesError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminDeleteUserAttributesError::TooManyRequests,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminDeleteUserAttributesError::UserNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminDeleteUserAttributesError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4800,41 +5018,62 @@ impl AdminDisableProviderForUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AliasExistsException" => {
-                    return RusotoError::Service(AdminDisableProviderForUserError::AliasExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminDisableProviderForUserError::AliasExists,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminDisableProviderForUserError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminDisableProviderForUserError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        AdminDisableProviderForUserError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminDisableProviderForUserError::InvalidParameter,
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminDisableProviderForUserError::NotAuthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminDisableProviderForUserError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        AdminDisableProviderForUserError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminDisableProviderForUserError::ResourceNotFound,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminDisableProviderForUserError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminDisableProviderForUserError::TooManyRequests,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminDisableProviderForUserError::UserNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminDisableProviderForUserError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4878,24 +5117,54 @@ impl AdminDisableUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminDisableUserError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminDisableUserError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminDisableUserError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminDisableUserError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminDisableUserError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminDisableUserError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminDisableUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminDisableUserError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminDisableUserError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminDisableUserError::TooManyRequests,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminDisableUserError::UserNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminDisableUserError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4938,24 +5207,54 @@ impl AdminEnableUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminEnableUserError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminEnableUserError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminEnableUserError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminEnableUserError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminEnableUserError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminEnableUserError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminEnableUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminEnableUserError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminEnableUserError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminEnableUserError::TooManyRequests,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminEnableUserError::UserNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminEnableUserError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5000,29 +5299,62 @@ impl AdminForgetDeviceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminForgetDeviceError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminForgetDeviceError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminForgetDeviceError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminForgetDeviceError::InvalidParameter,
+                    )
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(
-                        AdminForgetDeviceError::InvalidUserPoolConfiguration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminForgetDeviceError::InvalidUserPoolConfiguration,
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminForgetDeviceError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminForgetDeviceError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminForgetDeviceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminForgetDeviceError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminForgetDeviceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminForgetDeviceError::TooManyRequests,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminForgetDeviceError::UserNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminForgetDeviceError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5068,26 +5400,54 @@ impl AdminGetDeviceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminGetDeviceError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminGetDeviceError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminGetDeviceError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminGetDeviceError::InvalidParameter,
+                    )
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(AdminGetDeviceError::InvalidUserPoolConfiguration(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminGetDeviceError::InvalidUserPoolConfiguration,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminGetDeviceError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminGetDeviceError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminGetDeviceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminGetDeviceError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminGetDeviceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminGetDeviceError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5130,24 +5490,54 @@ impl AdminGetUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminGetUserError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminGetUserError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminGetUserError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminGetUserError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminGetUserError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminGetUserError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminGetUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminGetUserError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminGetUserError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminGetUserError::TooManyRequests,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminGetUserError::UserNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminGetUserError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5208,63 +5598,126 @@ impl AdminInitiateAuthError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminInitiateAuthError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminInitiateAuthError::InternalError,
+                    )
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(AdminInitiateAuthError::InvalidLambdaResponse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminInitiateAuthError::InvalidLambdaResponse,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminInitiateAuthError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminInitiateAuthError::InvalidParameter,
+                    )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(
-                        AdminInitiateAuthError::InvalidSmsRoleAccessPolicy(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminInitiateAuthError::InvalidSmsRoleAccessPolicy,
                     )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
-                        AdminInitiateAuthError::InvalidSmsRoleTrustRelationship(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminInitiateAuthError::InvalidSmsRoleTrustRelationship,
                     )
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(
-                        AdminInitiateAuthError::InvalidUserPoolConfiguration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminInitiateAuthError::InvalidUserPoolConfiguration,
                     )
                 }
                 "MFAMethodNotFoundException" => {
-                    return RusotoError::Service(AdminInitiateAuthError::MFAMethodNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminInitiateAuthError::MFAMethodNotFound,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminInitiateAuthError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminInitiateAuthError::NotAuthorized,
+                    )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(AdminInitiateAuthError::PasswordResetRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminInitiateAuthError::PasswordResetRequired,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminInitiateAuthError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminInitiateAuthError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminInitiateAuthError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminInitiateAuthError::TooManyRequests,
+                    )
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(AdminInitiateAuthError::UnexpectedLambda(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminInitiateAuthError::UnexpectedLambda,
+                    )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(AdminInitiateAuthError::UserLambdaValidation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminInitiateAuthError::UserLambdaValidation,
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(AdminInitiateAuthError::UserNotConfirmed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminInitiateAuthError::UserNotConfirmed,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminInitiateAuthError::UserNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminInitiateAuthError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5324,46 +5777,70 @@ impl AdminLinkProviderForUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AliasExistsException" => {
-                    return RusotoError::Service(AdminLinkProviderForUserError::AliasExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminLinkProviderForUserError::AliasExists,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminLinkProviderForUserError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminLinkProviderForUserError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminLinkProviderForUserError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminLinkProviderForUserError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AdminLinkProviderForUserError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminLinkProviderForUserError::LimitExceeded,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminLinkProviderForUserError::NotAuthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminLinkProviderForUserError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminLinkProviderForUserError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminLinkProviderForUserError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminLinkProviderForUserError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminLinkProviderForUserError::TooManyRequests,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminLinkProviderForUserError::UserNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminLinkProviderForUserError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5408,26 +5885,54 @@ impl AdminListDevicesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminListDevicesError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminListDevicesError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminListDevicesError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminListDevicesError::InvalidParameter,
+                    )
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(
-                        AdminListDevicesError::InvalidUserPoolConfiguration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminListDevicesError::InvalidUserPoolConfiguration,
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminListDevicesError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminListDevicesError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminListDevicesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminListDevicesError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminListDevicesError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminListDevicesError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5472,34 +5977,54 @@ impl AdminListGroupsForUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminListGroupsForUserError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminListGroupsForUserError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminListGroupsForUserError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminListGroupsForUserError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminListGroupsForUserError::NotAuthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminListGroupsForUserError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminListGroupsForUserError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminListGroupsForUserError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminListGroupsForUserError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminListGroupsForUserError::TooManyRequests,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminListGroupsForUserError::UserNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminListGroupsForUserError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5544,41 +6069,62 @@ impl AdminListUserAuthEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminListUserAuthEventsError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminListUserAuthEventsError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminListUserAuthEventsError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminListUserAuthEventsError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminListUserAuthEventsError::NotAuthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminListUserAuthEventsError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminListUserAuthEventsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminListUserAuthEventsError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminListUserAuthEventsError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminListUserAuthEventsError::TooManyRequests,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminListUserAuthEventsError::UserNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminListUserAuthEventsError::UserNotFound,
+                    )
                 }
                 "UserPoolAddOnNotEnabledException" => {
-                    return RusotoError::Service(
-                        AdminListUserAuthEventsError::UserPoolAddOnNotEnabled(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminListUserAuthEventsError::UserPoolAddOnNotEnabled,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5624,36 +6170,54 @@ impl AdminRemoveUserFromGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminRemoveUserFromGroupError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminRemoveUserFromGroupError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminRemoveUserFromGroupError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminRemoveUserFromGroupError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminRemoveUserFromGroupError::NotAuthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminRemoveUserFromGroupError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminRemoveUserFromGroupError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminRemoveUserFromGroupError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminRemoveUserFromGroupError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminRemoveUserFromGroupError::TooManyRequests,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminRemoveUserFromGroupError::UserNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminRemoveUserFromGroupError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5710,69 +6274,110 @@ impl AdminResetUserPasswordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminResetUserPasswordError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminResetUserPasswordError::InternalError,
+                    )
                 }
                 "InvalidEmailRoleAccessPolicyException" => {
-                    return RusotoError::Service(
-                        AdminResetUserPasswordError::InvalidEmailRoleAccessPolicy(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminResetUserPasswordError::InvalidEmailRoleAccessPolicy,
                     )
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(
-                        AdminResetUserPasswordError::InvalidLambdaResponse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminResetUserPasswordError::InvalidLambdaResponse,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminResetUserPasswordError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminResetUserPasswordError::InvalidParameter,
+                    )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(
-                        AdminResetUserPasswordError::InvalidSmsRoleAccessPolicy(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminResetUserPasswordError::InvalidSmsRoleAccessPolicy,
                     )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
-                        AdminResetUserPasswordError::InvalidSmsRoleTrustRelationship(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminResetUserPasswordError::InvalidSmsRoleTrustRelationship,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AdminResetUserPasswordError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminResetUserPasswordError::LimitExceeded,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminResetUserPasswordError::NotAuthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminResetUserPasswordError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminResetUserPasswordError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminResetUserPasswordError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminResetUserPasswordError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminResetUserPasswordError::TooManyRequests,
+                    )
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(AdminResetUserPasswordError::UnexpectedLambda(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminResetUserPasswordError::UnexpectedLambda,
+                    )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(AdminResetUserPasswordError::UserLambdaValidation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminResetUserPasswordError::UserLambdaValidation,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminResetUserPasswordError::UserNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminResetUserPasswordError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5858,106 +6463,166 @@ impl AdminRespondToAuthChallengeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AliasExistsException" => {
-                    return RusotoError::Service(AdminRespondToAuthChallengeError::AliasExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminRespondToAuthChallengeError::AliasExists,
+                    )
                 }
                 "CodeMismatchException" => {
-                    return RusotoError::Service(AdminRespondToAuthChallengeError::CodeMismatch(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminRespondToAuthChallengeError::CodeMismatch,
+                    )
                 }
                 "ExpiredCodeException" => {
-                    return RusotoError::Service(AdminRespondToAuthChallengeError::ExpiredCode(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminRespondToAuthChallengeError::ExpiredCode,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminRespondToAuthChallengeError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminRespondToAuthChallengeError::InternalError,
+                    )
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(
-                        AdminRespondToAuthChallengeError::InvalidLambdaResponse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminRespondToAuthChallengeError::InvalidLambdaResponse,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        AdminRespondToAuthChallengeError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminRespondToAuthChallengeError::InvalidParameter,
                     )
                 }
                 "InvalidPasswordException" => {
-                    return RusotoError::Service(AdminRespondToAuthChallengeError::InvalidPassword(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminRespondToAuthChallengeError::InvalidPassword,
+                    )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(
-                        AdminRespondToAuthChallengeError::InvalidSmsRoleAccessPolicy(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminRespondToAuthChallengeError::InvalidSmsRoleAccessPolicy,
                     )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
-                        AdminRespondToAuthChallengeError::InvalidSmsRoleTrustRelationship(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminRespondToAuthChallengeError::InvalidSmsRoleTrustRelationship,
                     )
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(
-                        AdminRespondToAuthChallengeError::InvalidUserPoolConfiguration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminRespondToAuthChallengeError::InvalidUserPoolConfiguration,
                     )
                 }
                 "MFAMethodNotFoundException" => {
-                    return RusotoError::Service(
-                        AdminRespondToAuthChallengeError::MFAMethodNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminRespondToAuthChallengeError::MFAMethodNotFound,
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminRespondToAuthChallengeError::NotAuthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminRespondToAuthChallengeError::NotAuthorized,
+                    )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(
-                        AdminRespondToAuthChallengeError::PasswordResetRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminRespondToAuthChallengeError::PasswordResetRequired,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        AdminRespondToAuthChallengeError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminRespondToAuthChallengeError::ResourceNotFound,
                     )
                 }
                 "SoftwareTokenMFANotFoundException" => {
-                    return RusotoError::Service(
-                        AdminRespondToAuthChallengeError::SoftwareTokenMFANotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminRespondToAuthChallengeError::SoftwareTokenMFANotFound,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminRespondToAuthChallengeError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminRespondToAuthChallengeError::TooManyRequests,
+                    )
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(
-                        AdminRespondToAuthChallengeError::UnexpectedLambda(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminRespondToAuthChallengeError::UnexpectedLambda,
                     )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(
-                        AdminRespondToAuthChallengeError::UserLambdaValidation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminRespondToAuthChallengeError::UserLambdaValidation,
                     )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(
-                        AdminRespondToAuthChallengeError::UserNotConfirmed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminRespondToAuthChallengeError::UserNotConfirmed,
                     )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminRespondToAuthChallengeError::UserNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminRespondToAuthChallengeError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6032,41 +6697,62 @@ impl AdminSetUserMFAPreferenceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminSetUserMFAPreferenceError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminSetUserMFAPreferenceError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminSetUserMFAPreferenceError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminSetUserMFAPreferenceError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminSetUserMFAPreferenceError::NotAuthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminSetUserMFAPreferenceError::NotAuthorized,
+                    )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(
-                        AdminSetUserMFAPreferenceError::PasswordResetRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminSetUserMFAPreferenceError::PasswordResetRequired,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminSetUserMFAPreferenceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminSetUserMFAPreferenceError::ResourceNotFound,
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(AdminSetUserMFAPreferenceError::UserNotConfirmed(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminSetUserMFAPreferenceError::UserNotConfirmed,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminSetUserMFAPreferenceError::UserNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminSetUserMFAPreferenceError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6114,35 +6800,62 @@ impl AdminSetUserPasswordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminSetUserPasswordError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminSetUserPasswordError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminSetUserPasswordError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminSetUserPasswordError::InvalidParameter,
+                    )
                 }
                 "InvalidPasswordException" => {
-                    return RusotoError::Service(AdminSetUserPasswordError::InvalidPassword(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminSetUserPasswordError::InvalidPassword,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminSetUserPasswordError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminSetUserPasswordError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminSetUserPasswordError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminSetUserPasswordError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminSetUserPasswordError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminSetUserPasswordError::TooManyRequests,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminSetUserPasswordError::UserNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminSetUserPasswordError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6184,25 +6897,46 @@ impl AdminSetUserSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminSetUserSettingsError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminSetUserSettingsError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminSetUserSettingsError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminSetUserSettingsError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminSetUserSettingsError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminSetUserSettingsError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminSetUserSettingsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminSetUserSettingsError::ResourceNotFound,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminSetUserSettingsError::UserNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminSetUserSettingsError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6248,41 +6982,62 @@ impl AdminUpdateAuthEventFeedbackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminUpdateAuthEventFeedbackError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminUpdateAuthEventFeedbackError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        AdminUpdateAuthEventFeedbackError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminUpdateAuthEventFeedbackError::InvalidParameter,
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminUpdateAuthEventFeedbackError::NotAuthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminUpdateAuthEventFeedbackError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        AdminUpdateAuthEventFeedbackError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminUpdateAuthEventFeedbackError::ResourceNotFound,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        AdminUpdateAuthEventFeedbackError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminUpdateAuthEventFeedbackError::TooManyRequests,
                     )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminUpdateAuthEventFeedbackError::UserNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminUpdateAuthEventFeedbackError::UserNotFound,
+                    )
                 }
                 "UserPoolAddOnNotEnabledException" => {
-                    return RusotoError::Service(
-                        AdminUpdateAuthEventFeedbackError::UserPoolAddOnNotEnabled(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminUpdateAuthEventFeedbackError::UserPoolAddOnNotEnabled,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6334,41 +7089,62 @@ impl AdminUpdateDeviceStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminUpdateDeviceStatusError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminUpdateDeviceStatusError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminUpdateDeviceStatusError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminUpdateDeviceStatusError::InvalidParameter,
+                    )
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(
-                        AdminUpdateDeviceStatusError::InvalidUserPoolConfiguration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminUpdateDeviceStatusError::InvalidUserPoolConfiguration,
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminUpdateDeviceStatusError::NotAuthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminUpdateDeviceStatusError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminUpdateDeviceStatusError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminUpdateDeviceStatusError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminUpdateDeviceStatusError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminUpdateDeviceStatusError::TooManyRequests,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminUpdateDeviceStatusError::UserNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminUpdateDeviceStatusError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6428,71 +7204,110 @@ impl AdminUpdateUserAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AliasExistsException" => {
-                    return RusotoError::Service(AdminUpdateUserAttributesError::AliasExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminUpdateUserAttributesError::AliasExists,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminUpdateUserAttributesError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminUpdateUserAttributesError::InternalError,
+                    )
                 }
                 "InvalidEmailRoleAccessPolicyException" => {
-                    return RusotoError::Service(
-                        AdminUpdateUserAttributesError::InvalidEmailRoleAccessPolicy(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminUpdateUserAttributesError::InvalidEmailRoleAccessPolicy,
                     )
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(
-                        AdminUpdateUserAttributesError::InvalidLambdaResponse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminUpdateUserAttributesError::InvalidLambdaResponse,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminUpdateUserAttributesError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminUpdateUserAttributesError::InvalidParameter,
+                    )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(
-                        AdminUpdateUserAttributesError::InvalidSmsRoleAccessPolicy(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminUpdateUserAttributesError::InvalidSmsRoleAccessPolicy,
                     )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
-                        AdminUpdateUserAttributesError::InvalidSmsRoleTrustRelationship(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminUpdateUserAttributesError::InvalidSmsRoleTrustRelationship,
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminUpdateUserAttributesError::NotAuthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminUpdateUserAttributesError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminUpdateUserAttributesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminUpdateUserAttributesError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminUpdateUserAttributesError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminUpdateUserAttributesError::TooManyRequests,
+                    )
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(AdminUpdateUserAttributesError::UnexpectedLambda(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminUpdateUserAttributesError::UnexpectedLambda,
+                    )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(
-                        AdminUpdateUserAttributesError::UserLambdaValidation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminUpdateUserAttributesError::UserLambdaValidation,
                     )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminUpdateUserAttributesError::UserNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminUpdateUserAttributesError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6552,34 +7367,54 @@ impl AdminUserGlobalSignOutError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AdminUserGlobalSignOutError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminUserGlobalSignOutError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AdminUserGlobalSignOutError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminUserGlobalSignOutError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AdminUserGlobalSignOutError::NotAuthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminUserGlobalSignOutError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AdminUserGlobalSignOutError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminUserGlobalSignOutError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AdminUserGlobalSignOutError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdminUserGlobalSignOutError::TooManyRequests,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(AdminUserGlobalSignOutError::UserNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdminUserGlobalSignOutError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6622,36 +7457,54 @@ impl AssociateSoftwareTokenError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        AssociateSoftwareTokenError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateSoftwareTokenError::ConcurrentModification,
                     )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(AssociateSoftwareTokenError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateSoftwareTokenError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AssociateSoftwareTokenError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateSoftwareTokenError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(AssociateSoftwareTokenError::NotAuthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateSoftwareTokenError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateSoftwareTokenError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateSoftwareTokenError::ResourceNotFound,
+                    )
                 }
                 "SoftwareTokenMFANotFoundException" => {
-                    return RusotoError::Service(
-                        AssociateSoftwareTokenError::SoftwareTokenMFANotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateSoftwareTokenError::SoftwareTokenMFANotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6706,38 +7559,86 @@ impl ChangePasswordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ChangePasswordError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ChangePasswordError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ChangePasswordError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ChangePasswordError::InvalidParameter,
+                    )
                 }
                 "InvalidPasswordException" => {
-                    return RusotoError::Service(ChangePasswordError::InvalidPassword(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ChangePasswordError::InvalidPassword,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ChangePasswordError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ChangePasswordError::LimitExceeded,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ChangePasswordError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ChangePasswordError::NotAuthorized,
+                    )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(ChangePasswordError::PasswordResetRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ChangePasswordError::PasswordResetRequired,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ChangePasswordError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ChangePasswordError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ChangePasswordError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ChangePasswordError::TooManyRequests,
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(ChangePasswordError::UserNotConfirmed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ChangePasswordError::UserNotConfirmed,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(ChangePasswordError::UserNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ChangePasswordError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6796,44 +7697,102 @@ impl ConfirmDeviceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ConfirmDeviceError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfirmDeviceError::InternalError,
+                    )
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(ConfirmDeviceError::InvalidLambdaResponse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfirmDeviceError::InvalidLambdaResponse,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ConfirmDeviceError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfirmDeviceError::InvalidParameter,
+                    )
                 }
                 "InvalidPasswordException" => {
-                    return RusotoError::Service(ConfirmDeviceError::InvalidPassword(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfirmDeviceError::InvalidPassword,
+                    )
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(ConfirmDeviceError::InvalidUserPoolConfiguration(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ConfirmDeviceError::InvalidUserPoolConfiguration,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ConfirmDeviceError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfirmDeviceError::NotAuthorized,
+                    )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(ConfirmDeviceError::PasswordResetRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfirmDeviceError::PasswordResetRequired,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ConfirmDeviceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfirmDeviceError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ConfirmDeviceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfirmDeviceError::TooManyRequests,
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(ConfirmDeviceError::UserNotConfirmed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfirmDeviceError::UserNotConfirmed,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(ConfirmDeviceError::UserNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfirmDeviceError::UserNotFound,
+                    )
                 }
                 "UsernameExistsException" => {
-                    return RusotoError::Service(ConfirmDeviceError::UsernameExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfirmDeviceError::UsernameExists,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6900,69 +7859,126 @@ impl ConfirmForgotPasswordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeMismatchException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::CodeMismatch(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfirmForgotPasswordError::CodeMismatch,
+                    )
                 }
                 "ExpiredCodeException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::ExpiredCode(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfirmForgotPasswordError::ExpiredCode,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfirmForgotPasswordError::InternalError,
+                    )
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::InvalidLambdaResponse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ConfirmForgotPasswordError::InvalidLambdaResponse,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ConfirmForgotPasswordError::InvalidParameter,
+                    )
                 }
                 "InvalidPasswordException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::InvalidPassword(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ConfirmForgotPasswordError::InvalidPassword,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfirmForgotPasswordError::LimitExceeded,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfirmForgotPasswordError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ConfirmForgotPasswordError::ResourceNotFound,
+                    )
                 }
                 "TooManyFailedAttemptsException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::TooManyFailedAttempts(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ConfirmForgotPasswordError::TooManyFailedAttempts,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ConfirmForgotPasswordError::TooManyRequests,
+                    )
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::UnexpectedLambda(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ConfirmForgotPasswordError::UnexpectedLambda,
+                    )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::UserLambdaValidation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ConfirmForgotPasswordError::UserLambdaValidation,
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::UserNotConfirmed(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ConfirmForgotPasswordError::UserNotConfirmed,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(ConfirmForgotPasswordError::UserNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfirmForgotPasswordError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7030,48 +8046,118 @@ impl ConfirmSignUpError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AliasExistsException" => {
-                    return RusotoError::Service(ConfirmSignUpError::AliasExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfirmSignUpError::AliasExists,
+                    )
                 }
                 "CodeMismatchException" => {
-                    return RusotoError::Service(ConfirmSignUpError::CodeMismatch(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfirmSignUpError::CodeMismatch,
+                    )
                 }
                 "ExpiredCodeException" => {
-                    return RusotoError::Service(ConfirmSignUpError::ExpiredCode(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfirmSignUpError::ExpiredCode,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(ConfirmSignUpError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfirmSignUpError::InternalError,
+                    )
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(ConfirmSignUpError::InvalidLambdaResponse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfirmSignUpError::InvalidLambdaResponse,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ConfirmSignUpError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfirmSignUpError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ConfirmSignUpError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfirmSignUpError::LimitExceeded,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ConfirmSignUpError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfirmSignUpError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ConfirmSignUpError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfirmSignUpError::ResourceNotFound,
+                    )
                 }
                 "TooManyFailedAttemptsException" => {
-                    return RusotoError::Service(ConfirmSignUpError::TooManyFailedAttempts(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfirmSignUpError::TooManyFailedAttempts,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ConfirmSignUpError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfirmSignUpError::TooManyRequests,
+                    )
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(ConfirmSignUpError::UnexpectedLambda(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfirmSignUpError::UnexpectedLambda,
+                    )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(ConfirmSignUpError::UserLambdaValidation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfirmSignUpError::UserLambdaValidation,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(ConfirmSignUpError::UserNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfirmSignUpError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7124,27 +8210,62 @@ impl CreateGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "GroupExistsException" => {
-                    return RusotoError::Service(CreateGroupError::GroupExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGroupError::GroupExists,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateGroupError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGroupError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateGroupError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGroupError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateGroupError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGroupError::LimitExceeded,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(CreateGroupError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGroupError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGroupError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateGroupError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGroupError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7190,41 +8311,62 @@ impl CreateIdentityProviderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateProviderException" => {
-                    return RusotoError::Service(CreateIdentityProviderError::DuplicateProvider(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateIdentityProviderError::DuplicateProvider,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateIdentityProviderError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateIdentityProviderError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateIdentityProviderError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateIdentityProviderError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateIdentityProviderError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateIdentityProviderError::LimitExceeded,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(CreateIdentityProviderError::NotAuthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateIdentityProviderError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateIdentityProviderError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateIdentityProviderError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateIdentityProviderError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateIdentityProviderError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7268,30 +8410,54 @@ impl CreateResourceServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateResourceServerError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResourceServerError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateResourceServerError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateResourceServerError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateResourceServerError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResourceServerError::LimitExceeded,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(CreateResourceServerError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResourceServerError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateResourceServerError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateResourceServerError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateResourceServerError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateResourceServerError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7336,33 +8502,62 @@ impl CreateUserImportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateUserImportJobError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserImportJobError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateUserImportJobError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateUserImportJobError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateUserImportJobError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserImportJobError::LimitExceeded,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(CreateUserImportJobError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserImportJobError::NotAuthorized,
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(CreateUserImportJobError::PreconditionNotMet(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateUserImportJobError::PreconditionNotMet,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateUserImportJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateUserImportJobError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateUserImportJobError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserImportJobError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7412,39 +8607,78 @@ impl CreateUserPoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateUserPoolError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserPoolError::InternalError,
+                    )
                 }
                 "InvalidEmailRoleAccessPolicyException" => {
-                    return RusotoError::Service(CreateUserPoolError::InvalidEmailRoleAccessPolicy(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateUserPoolError::InvalidEmailRoleAccessPolicy,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateUserPoolError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserPoolError::InvalidParameter,
+                    )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(CreateUserPoolError::InvalidSmsRoleAccessPolicy(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateUserPoolError::InvalidSmsRoleAccessPolicy,
+                    )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
-                        CreateUserPoolError::InvalidSmsRoleTrustRelationship(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserPoolError::InvalidSmsRoleTrustRelationship,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateUserPoolError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserPoolError::LimitExceeded,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(CreateUserPoolError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserPoolError::NotAuthorized,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateUserPoolError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserPoolError::TooManyRequests,
+                    )
                 }
                 "UserPoolTaggingException" => {
-                    return RusotoError::Service(CreateUserPoolError::UserPoolTagging(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserPoolError::UserPoolTagging,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7496,40 +8730,70 @@ impl CreateUserPoolClientError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateUserPoolClientError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserPoolClientError::InternalError,
+                    )
                 }
                 "InvalidOAuthFlowException" => {
-                    return RusotoError::Service(CreateUserPoolClientError::InvalidOAuthFlow(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateUserPoolClientError::InvalidOAuthFlow,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateUserPoolClientError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateUserPoolClientError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateUserPoolClientError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserPoolClientError::LimitExceeded,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(CreateUserPoolClientError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserPoolClientError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateUserPoolClientError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateUserPoolClientError::ResourceNotFound,
+                    )
                 }
                 "ScopeDoesNotExistException" => {
-                    return RusotoError::Service(CreateUserPoolClientError::ScopeDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateUserPoolClientError::ScopeDoesNotExist,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateUserPoolClientError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateUserPoolClientError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7572,25 +8836,46 @@ impl CreateUserPoolDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(CreateUserPoolDomainError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserPoolDomainError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateUserPoolDomainError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateUserPoolDomainError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateUserPoolDomainError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserPoolDomainError::LimitExceeded,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(CreateUserPoolDomainError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserPoolDomainError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateUserPoolDomainError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateUserPoolDomainError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7630,21 +8915,46 @@ impl DeleteGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteGroupError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGroupError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteGroupError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGroupError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DeleteGroupError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGroupError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGroupError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteGroupError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGroupError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7686,36 +8996,54 @@ impl DeleteIdentityProviderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteIdentityProviderError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteIdentityProviderError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteIdentityProviderError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteIdentityProviderError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DeleteIdentityProviderError::NotAuthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteIdentityProviderError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteIdentityProviderError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteIdentityProviderError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteIdentityProviderError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteIdentityProviderError::TooManyRequests,
+                    )
                 }
                 "UnsupportedIdentityProviderException" => {
-                    return RusotoError::Service(
-                        DeleteIdentityProviderError::UnsupportedIdentityProvider(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIdentityProviderError::UnsupportedIdentityProvider,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7758,27 +9086,46 @@ impl DeleteResourceServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteResourceServerError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteResourceServerError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteResourceServerError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteResourceServerError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DeleteResourceServerError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteResourceServerError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteResourceServerError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteResourceServerError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteResourceServerError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteResourceServerError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7824,30 +9171,70 @@ impl DeleteUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteUserError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteUserError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DeleteUserError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserError::NotAuthorized,
+                    )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(DeleteUserError::PasswordResetRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserError::PasswordResetRequired,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteUserError::TooManyRequests(err.msg))
-                }
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserError::TooManyRequests,
+                    )
+                }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(DeleteUserError::UserNotConfirmed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserError::UserNotConfirmed,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(DeleteUserError::UserNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7896,40 +9283,70 @@ impl DeleteUserAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteUserAttributesError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserAttributesError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteUserAttributesError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteUserAttributesError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DeleteUserAttributesError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserAttributesError::NotAuthorized,
+                    )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(DeleteUserAttributesError::PasswordResetRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteUserAttributesError::PasswordResetRequired,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteUserAttributesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteUserAttributesError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteUserAttributesError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteUserAttributesError::TooManyRequests,
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(DeleteUserAttributesError::UserNotConfirmed(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteUserAttributesError::UserNotConfirmed,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(DeleteUserAttributesError::UserNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserAttributesError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7974,24 +9391,54 @@ impl DeleteUserPoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteUserPoolError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserPoolError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteUserPoolError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserPoolError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DeleteUserPoolError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserPoolError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteUserPoolError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserPoolError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteUserPoolError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserPoolError::TooManyRequests,
+                    )
                 }
                 "UserImportInProgressException" => {
-                    return RusotoError::Service(DeleteUserPoolError::UserImportInProgress(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserPoolError::UserImportInProgress,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8032,27 +9479,46 @@ impl DeleteUserPoolClientError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteUserPoolClientError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserPoolClientError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteUserPoolClientError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteUserPoolClientError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DeleteUserPoolClientError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserPoolClientError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteUserPoolClientError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteUserPoolClientError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteUserPoolClientError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteUserPoolClientError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8090,22 +9556,38 @@ impl DeleteUserPoolDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteUserPoolDomainError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserPoolDomainError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteUserPoolDomainError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteUserPoolDomainError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DeleteUserPoolDomainError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserPoolDomainError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteUserPoolDomainError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteUserPoolDomainError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8144,31 +9626,46 @@ impl DescribeIdentityProviderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeIdentityProviderError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeIdentityProviderError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeIdentityProviderError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeIdentityProviderError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeIdentityProviderError::NotAuthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeIdentityProviderError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeIdentityProviderError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeIdentityProviderError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeIdentityProviderError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeIdentityProviderError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8208,31 +9705,46 @@ impl DescribeResourceServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeResourceServerError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeResourceServerError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeResourceServerError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeResourceServerError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeResourceServerError::NotAuthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeResourceServerError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeResourceServerError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeResourceServerError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeResourceServerError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeResourceServerError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8274,36 +9786,54 @@ impl DescribeRiskConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeRiskConfigurationError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRiskConfigurationError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeRiskConfigurationError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRiskConfigurationError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeRiskConfigurationError::NotAuthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRiskConfigurationError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeRiskConfigurationError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRiskConfigurationError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeRiskConfigurationError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRiskConfigurationError::TooManyRequests,
+                    )
                 }
                 "UserPoolAddOnNotEnabledException" => {
-                    return RusotoError::Service(
-                        DescribeRiskConfigurationError::UserPoolAddOnNotEnabled(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRiskConfigurationError::UserPoolAddOnNotEnabled,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8346,27 +9876,46 @@ impl DescribeUserImportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeUserImportJobError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUserImportJobError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeUserImportJobError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeUserImportJobError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeUserImportJobError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUserImportJobError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeUserImportJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeUserImportJobError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeUserImportJobError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeUserImportJobError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8408,24 +9957,54 @@ impl DescribeUserPoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeUserPoolError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUserPoolError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeUserPoolError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUserPoolError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeUserPoolError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUserPoolError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeUserPoolError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUserPoolError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeUserPoolError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUserPoolError::TooManyRequests,
+                    )
                 }
                 "UserPoolTaggingException" => {
-                    return RusotoError::Service(DescribeUserPoolError::UserPoolTagging(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUserPoolError::UserPoolTagging,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8466,31 +10045,46 @@ impl DescribeUserPoolClientError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeUserPoolClientError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeUserPoolClientError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeUserPoolClientError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeUserPoolClientError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeUserPoolClientError::NotAuthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeUserPoolClientError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeUserPoolClientError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeUserPoolClientError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeUserPoolClientError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeUserPoolClientError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8528,26 +10122,38 @@ impl DescribeUserPoolDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeUserPoolDomainError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeUserPoolDomainError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeUserPoolDomainError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeUserPoolDomainError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeUserPoolDomainError::NotAuthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeUserPoolDomainError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeUserPoolDomainError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeUserPoolDomainError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8594,35 +10200,78 @@ impl ForgetDeviceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ForgetDeviceError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ForgetDeviceError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ForgetDeviceError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ForgetDeviceError::InvalidParameter,
+                    )
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(ForgetDeviceError::InvalidUserPoolConfiguration(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ForgetDeviceError::InvalidUserPoolConfiguration,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ForgetDeviceError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ForgetDeviceError::NotAuthorized,
+                    )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(ForgetDeviceError::PasswordResetRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ForgetDeviceError::PasswordResetRequired,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ForgetDeviceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ForgetDeviceError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ForgetDeviceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ForgetDeviceError::TooManyRequests,
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(ForgetDeviceError::UserNotConfirmed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ForgetDeviceError::UserNotConfirmed,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(ForgetDeviceError::UserNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ForgetDeviceError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8686,59 +10335,126 @@ impl ForgotPasswordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeDeliveryFailureException" => {
-                    return RusotoError::Service(ForgotPasswordError::CodeDeliveryFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ForgotPasswordError::CodeDeliveryFailure,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(ForgotPasswordError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ForgotPasswordError::InternalError,
+                    )
                 }
                 "InvalidEmailRoleAccessPolicyException" => {
-                    return RusotoError::Service(ForgotPasswordError::InvalidEmailRoleAccessPolicy(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ForgotPasswordError::InvalidEmailRoleAccessPolicy,
+                    )
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(ForgotPasswordError::InvalidLambdaResponse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ForgotPasswordError::InvalidLambdaResponse,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ForgotPasswordError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ForgotPasswordError::InvalidParameter,
+                    )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(ForgotPasswordError::InvalidSmsRoleAccessPolicy(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ForgotPasswordError::InvalidSmsRoleAccessPolicy,
+                    )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
-                        ForgotPasswordError::InvalidSmsRoleTrustRelationship(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ForgotPasswordError::InvalidSmsRoleTrustRelationship,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ForgotPasswordError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ForgotPasswordError::LimitExceeded,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ForgotPasswordError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ForgotPasswordError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ForgotPasswordError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ForgotPasswordError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ForgotPasswordError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ForgotPasswordError::TooManyRequests,
+                    )
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(ForgotPasswordError::UnexpectedLambda(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ForgotPasswordError::UnexpectedLambda,
+                    )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(ForgotPasswordError::UserLambdaValidation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ForgotPasswordError::UserLambdaValidation,
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(ForgotPasswordError::UserNotConfirmed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ForgotPasswordError::UserNotConfirmed,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(ForgotPasswordError::UserNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ForgotPasswordError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8790,21 +10506,46 @@ impl GetCSVHeaderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetCSVHeaderError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCSVHeaderError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetCSVHeaderError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCSVHeaderError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetCSVHeaderError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCSVHeaderError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetCSVHeaderError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCSVHeaderError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetCSVHeaderError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCSVHeaderError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8852,35 +10593,78 @@ impl GetDeviceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetDeviceError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeviceError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetDeviceError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeviceError::InvalidParameter,
+                    )
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(GetDeviceError::InvalidUserPoolConfiguration(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDeviceError::InvalidUserPoolConfiguration,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetDeviceError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeviceError::NotAuthorized,
+                    )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(GetDeviceError::PasswordResetRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeviceError::PasswordResetRequired,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDeviceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeviceError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDeviceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeviceError::TooManyRequests,
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(GetDeviceError::UserNotConfirmed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeviceError::UserNotConfirmed,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(GetDeviceError::UserNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeviceError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8924,21 +10708,46 @@ impl GetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetGroupError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGroupError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetGroupError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGroupError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetGroupError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGroupError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGroupError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetGroupError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGroupError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8980,31 +10789,46 @@ impl GetIdentityProviderByIdentifierError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(
-                        GetIdentityProviderByIdentifierError::InternalError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIdentityProviderByIdentifierError::InternalError,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        GetIdentityProviderByIdentifierError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIdentityProviderByIdentifierError::InvalidParameter,
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(
-                        GetIdentityProviderByIdentifierError::NotAuthorized(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIdentityProviderByIdentifierError::NotAuthorized,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        GetIdentityProviderByIdentifierError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIdentityProviderByIdentifierError::ResourceNotFound,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        GetIdentityProviderByIdentifierError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIdentityProviderByIdentifierError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9050,19 +10874,30 @@ impl GetSigningCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetSigningCertificateError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSigningCertificateError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetSigningCertificateError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSigningCertificateError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetSigningCertificateError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSigningCertificateError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9100,21 +10935,46 @@ impl GetUICustomizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetUICustomizationError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUICustomizationError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetUICustomizationError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUICustomizationError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetUICustomizationError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUICustomizationError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetUICustomizationError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUICustomizationError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetUICustomizationError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUICustomizationError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9160,30 +11020,70 @@ impl GetUserError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetUserError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetUserError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetUserError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserError::NotAuthorized,
+                    )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(GetUserError::PasswordResetRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserError::PasswordResetRequired,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetUserError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserError::TooManyRequests,
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(GetUserError::UserNotConfirmed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserError::UserNotConfirmed,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(GetUserError::UserNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9250,90 +11150,134 @@ impl GetUserAttributeVerificationCodeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeDeliveryFailureException" => {
-                    return RusotoError::Service(
-                        GetUserAttributeVerificationCodeError::CodeDeliveryFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserAttributeVerificationCodeError::CodeDeliveryFailure,
                     )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(
-                        GetUserAttributeVerificationCodeError::InternalError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserAttributeVerificationCodeError::InternalError,
                     )
                 }
                 "InvalidEmailRoleAccessPolicyException" => {
-                    return RusotoError::Service(
-                        GetUserAttributeVerificationCodeError::InvalidEmailRoleAccessPolicy(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserAttributeVerificationCodeError::InvalidEmailRoleAccessPolicy,
                     )
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(
-                        GetUserAttributeVerificationCodeError::InvalidLambdaResponse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserAttributeVerificationCodeError::InvalidLambdaResponse,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        GetUserAttributeVerificationCodeError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserAttributeVerificationCodeError::InvalidParameter,
                     )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(
-                        GetUserAttributeVerificationCodeError::InvalidSmsRoleAccessPolicy(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserAttributeVerificationCodeError::InvalidSmsRoleAccessPolicy,
                     )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
-                        GetUserAttributeVerificationCodeError::InvalidSmsRoleTrustRelationship(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserAttributeVerificationCodeError::InvalidSmsRoleTrustRelationship,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
-                        GetUserAttributeVerificationCodeError::LimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserAttributeVerificationCodeError::LimitExceeded,
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(
-                        GetUserAttributeVerificationCodeError::NotAuthorized(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserAttributeVerificationCodeError::NotAuthorized,
                     )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(
-                        GetUserAttributeVerificationCodeError::PasswordResetRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserAttributeVerificationCodeError::PasswordResetRequired,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        GetUserAttributeVerificationCodeError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserAttributeVerificationCodeError::ResourceNotFound,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        GetUserAttributeVerificationCodeError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserAttributeVerificationCodeError::TooManyRequests,
                     )
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(
-                        GetUserAttributeVerificationCodeError::UnexpectedLambda(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserAttributeVerificationCodeError::UnexpectedLambda,
                     )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(
-                        GetUserAttributeVerificationCodeError::UserLambdaValidation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserAttributeVerificationCodeError::UserLambdaValidation,
                     )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(
-                        GetUserAttributeVerificationCodeError::UserNotConfirmed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserAttributeVerificationCodeError::UserNotConfirmed,
                     )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(
-                        GetUserAttributeVerificationCodeError::UserNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserAttributeVerificationCodeError::UserNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9416,27 +11360,46 @@ impl GetUserPoolMfaConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetUserPoolMfaConfigError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserPoolMfaConfigError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetUserPoolMfaConfigError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetUserPoolMfaConfigError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetUserPoolMfaConfigError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserPoolMfaConfigError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetUserPoolMfaConfigError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetUserPoolMfaConfigError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetUserPoolMfaConfigError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetUserPoolMfaConfigError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9480,27 +11443,62 @@ impl GlobalSignOutError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GlobalSignOutError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GlobalSignOutError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GlobalSignOutError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GlobalSignOutError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GlobalSignOutError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GlobalSignOutError::NotAuthorized,
+                    )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(GlobalSignOutError::PasswordResetRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GlobalSignOutError::PasswordResetRequired,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GlobalSignOutError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GlobalSignOutError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GlobalSignOutError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GlobalSignOutError::TooManyRequests,
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(GlobalSignOutError::UserNotConfirmed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GlobalSignOutError::UserNotConfirmed,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9560,54 +11558,118 @@ impl InitiateAuthError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(InitiateAuthError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InitiateAuthError::InternalError,
+                    )
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(InitiateAuthError::InvalidLambdaResponse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InitiateAuthError::InvalidLambdaResponse,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(InitiateAuthError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InitiateAuthError::InvalidParameter,
+                    )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(InitiateAuthError::InvalidSmsRoleAccessPolicy(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        InitiateAuthError::InvalidSmsRoleAccessPolicy,
+                    )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
-                        InitiateAuthError::InvalidSmsRoleTrustRelationship(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InitiateAuthError::InvalidSmsRoleTrustRelationship,
                     )
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(InitiateAuthError::InvalidUserPoolConfiguration(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        InitiateAuthError::InvalidUserPoolConfiguration,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(InitiateAuthError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InitiateAuthError::NotAuthorized,
+                    )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(InitiateAuthError::PasswordResetRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InitiateAuthError::PasswordResetRequired,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(InitiateAuthError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InitiateAuthError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(InitiateAuthError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InitiateAuthError::TooManyRequests,
+                    )
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(InitiateAuthError::UnexpectedLambda(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InitiateAuthError::UnexpectedLambda,
+                    )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(InitiateAuthError::UserLambdaValidation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InitiateAuthError::UserLambdaValidation,
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(InitiateAuthError::UserNotConfirmed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InitiateAuthError::UserNotConfirmed,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(InitiateAuthError::UserNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InitiateAuthError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9664,35 +11726,78 @@ impl ListDevicesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListDevicesError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDevicesError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListDevicesError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDevicesError::InvalidParameter,
+                    )
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(ListDevicesError::InvalidUserPoolConfiguration(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDevicesError::InvalidUserPoolConfiguration,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListDevicesError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDevicesError::NotAuthorized,
+                    )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(ListDevicesError::PasswordResetRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDevicesError::PasswordResetRequired,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDevicesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDevicesError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListDevicesError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDevicesError::TooManyRequests,
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(ListDevicesError::UserNotConfirmed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDevicesError::UserNotConfirmed,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(ListDevicesError::UserNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDevicesError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9736,21 +11841,46 @@ impl ListGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListGroupsError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGroupsError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListGroupsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGroupsError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListGroupsError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGroupsError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListGroupsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGroupsError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListGroupsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGroupsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9790,27 +11920,46 @@ impl ListIdentityProvidersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListIdentityProvidersError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIdentityProvidersError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListIdentityProvidersError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListIdentityProvidersError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListIdentityProvidersError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIdentityProvidersError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListIdentityProvidersError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListIdentityProvidersError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListIdentityProvidersError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListIdentityProvidersError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9850,25 +11999,46 @@ impl ListResourceServersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListResourceServersError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourceServersError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListResourceServersError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResourceServersError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListResourceServersError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourceServersError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListResourceServersError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResourceServersError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListResourceServersError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourceServersError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9908,25 +12078,46 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListTagsForResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9966,21 +12157,46 @@ impl ListUserImportJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListUserImportJobsError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUserImportJobsError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListUserImportJobsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUserImportJobsError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListUserImportJobsError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUserImportJobsError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListUserImportJobsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUserImportJobsError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListUserImportJobsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUserImportJobsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10020,25 +12236,46 @@ impl ListUserPoolClientsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListUserPoolClientsError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUserPoolClientsError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListUserPoolClientsError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListUserPoolClientsError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListUserPoolClientsError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUserPoolClientsError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListUserPoolClientsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListUserPoolClientsError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListUserPoolClientsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUserPoolClientsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10076,18 +12313,38 @@ impl ListUserPoolsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListUserPoolsError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUserPoolsError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListUserPoolsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUserPoolsError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListUserPoolsError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUserPoolsError::NotAuthorized,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListUserPoolsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUserPoolsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10126,21 +12383,46 @@ impl ListUsersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListUsersError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUsersError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListUsersError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUsersError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListUsersError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUsersError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListUsersError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUsersError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListUsersError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUsersError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10180,21 +12462,46 @@ impl ListUsersInGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListUsersInGroupError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUsersInGroupError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListUsersInGroupError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUsersInGroupError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListUsersInGroupError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUsersInGroupError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListUsersInGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUsersInGroupError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListUsersInGroupError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUsersInGroupError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10252,74 +12559,118 @@ impl ResendConfirmationCodeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeDeliveryFailureException" => {
-                    return RusotoError::Service(ResendConfirmationCodeError::CodeDeliveryFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ResendConfirmationCodeError::CodeDeliveryFailure,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(ResendConfirmationCodeError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ResendConfirmationCodeError::InternalError,
+                    )
                 }
                 "InvalidEmailRoleAccessPolicyException" => {
-                    return RusotoError::Service(
-                        ResendConfirmationCodeError::InvalidEmailRoleAccessPolicy(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResendConfirmationCodeError::InvalidEmailRoleAccessPolicy,
                     )
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(
-                        ResendConfirmationCodeError::InvalidLambdaResponse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResendConfirmationCodeError::InvalidLambdaResponse,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ResendConfirmationCodeError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ResendConfirmationCodeError::InvalidParameter,
+                    )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(
-                        ResendConfirmationCodeError::InvalidSmsRoleAccessPolicy(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResendConfirmationCodeError::InvalidSmsRoleAccessPolicy,
                     )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
-                        ResendConfirmationCodeError::InvalidSmsRoleTrustRelationship(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResendConfirmationCodeError::InvalidSmsRoleTrustRelationship,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ResendConfirmationCodeError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ResendConfirmationCodeError::LimitExceeded,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ResendConfirmationCodeError::NotAuthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ResendConfirmationCodeError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ResendConfirmationCodeError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ResendConfirmationCodeError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ResendConfirmationCodeError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ResendConfirmationCodeError::TooManyRequests,
+                    )
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(ResendConfirmationCodeError::UnexpectedLambda(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ResendConfirmationCodeError::UnexpectedLambda,
+                    )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(ResendConfirmationCodeError::UserLambdaValidation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ResendConfirmationCodeError::UserLambdaValidation,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(ResendConfirmationCodeError::UserNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResendConfirmationCodeError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10404,98 +12755,166 @@ impl RespondToAuthChallengeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AliasExistsException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::AliasExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RespondToAuthChallengeError::AliasExists,
+                    )
                 }
                 "CodeMismatchException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::CodeMismatch(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RespondToAuthChallengeError::CodeMismatch,
+                    )
                 }
                 "ExpiredCodeException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::ExpiredCode(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RespondToAuthChallengeError::ExpiredCode,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RespondToAuthChallengeError::InternalError,
+                    )
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(
-                        RespondToAuthChallengeError::InvalidLambdaResponse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RespondToAuthChallengeError::InvalidLambdaResponse,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RespondToAuthChallengeError::InvalidParameter,
+                    )
                 }
                 "InvalidPasswordException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::InvalidPassword(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RespondToAuthChallengeError::InvalidPassword,
+                    )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(
-                        RespondToAuthChallengeError::InvalidSmsRoleAccessPolicy(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RespondToAuthChallengeError::InvalidSmsRoleAccessPolicy,
                     )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
-                        RespondToAuthChallengeError::InvalidSmsRoleTrustRelationship(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RespondToAuthChallengeError::InvalidSmsRoleTrustRelationship,
                     )
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(
-                        RespondToAuthChallengeError::InvalidUserPoolConfiguration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RespondToAuthChallengeError::InvalidUserPoolConfiguration,
                     )
                 }
                 "MFAMethodNotFoundException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::MFAMethodNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RespondToAuthChallengeError::MFAMethodNotFound,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::NotAuthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RespondToAuthChallengeError::NotAuthorized,
+                    )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(
-                        RespondToAuthChallengeError::PasswordResetRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RespondToAuthChallengeError::PasswordResetRequired,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RespondToAuthChallengeError::ResourceNotFound,
+                    )
                 }
                 "SoftwareTokenMFANotFoundException" => {
-                    return RusotoError::Service(
-                        RespondToAuthChallengeError::SoftwareTokenMFANotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RespondToAuthChallengeError::SoftwareTokenMFANotFound,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RespondToAuthChallengeError::TooManyRequests,
+                    )
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::UnexpectedLambda(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RespondToAuthChallengeError::UnexpectedLambda,
+                    )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::UserLambdaValidation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RespondToAuthChallengeError::UserLambdaValidation,
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::UserNotConfirmed(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RespondToAuthChallengeError::UserNotConfirmed,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(RespondToAuthChallengeError::UserNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RespondToAuthChallengeError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10564,42 +12983,70 @@ impl SetRiskConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeDeliveryFailureException" => {
-                    return RusotoError::Service(SetRiskConfigurationError::CodeDeliveryFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetRiskConfigurationError::CodeDeliveryFailure,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(SetRiskConfigurationError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetRiskConfigurationError::InternalError,
+                    )
                 }
                 "InvalidEmailRoleAccessPolicyException" => {
-                    return RusotoError::Service(
-                        SetRiskConfigurationError::InvalidEmailRoleAccessPolicy(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetRiskConfigurationError::InvalidEmailRoleAccessPolicy,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SetRiskConfigurationError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetRiskConfigurationError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(SetRiskConfigurationError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetRiskConfigurationError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SetRiskConfigurationError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetRiskConfigurationError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(SetRiskConfigurationError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetRiskConfigurationError::TooManyRequests,
+                    )
                 }
                 "UserPoolAddOnNotEnabledException" => {
-                    return RusotoError::Service(
-                        SetRiskConfigurationError::UserPoolAddOnNotEnabled(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetRiskConfigurationError::UserPoolAddOnNotEnabled,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10644,21 +13091,46 @@ impl SetUICustomizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(SetUICustomizationError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetUICustomizationError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SetUICustomizationError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetUICustomizationError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(SetUICustomizationError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetUICustomizationError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SetUICustomizationError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetUICustomizationError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(SetUICustomizationError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetUICustomizationError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10702,35 +13174,62 @@ impl SetUserMFAPreferenceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(SetUserMFAPreferenceError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetUserMFAPreferenceError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SetUserMFAPreferenceError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetUserMFAPreferenceError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(SetUserMFAPreferenceError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetUserMFAPreferenceError::NotAuthorized,
+                    )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(SetUserMFAPreferenceError::PasswordResetRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetUserMFAPreferenceError::PasswordResetRequired,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SetUserMFAPreferenceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetUserMFAPreferenceError::ResourceNotFound,
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(SetUserMFAPreferenceError::UserNotConfirmed(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetUserMFAPreferenceError::UserNotConfirmed,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(SetUserMFAPreferenceError::UserNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetUserMFAPreferenceError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10776,37 +13275,62 @@ impl SetUserPoolMfaConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(SetUserPoolMfaConfigError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetUserPoolMfaConfigError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SetUserPoolMfaConfigError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetUserPoolMfaConfigError::InvalidParameter,
+                    )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(
-                        SetUserPoolMfaConfigError::InvalidSmsRoleAccessPolicy(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetUserPoolMfaConfigError::InvalidSmsRoleAccessPolicy,
                     )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
-                        SetUserPoolMfaConfigError::InvalidSmsRoleTrustRelationship(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetUserPoolMfaConfigError::InvalidSmsRoleTrustRelationship,
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(SetUserPoolMfaConfigError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetUserPoolMfaConfigError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SetUserPoolMfaConfigError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetUserPoolMfaConfigError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(SetUserPoolMfaConfigError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetUserPoolMfaConfigError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10856,29 +13380,62 @@ impl SetUserSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(SetUserSettingsError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetUserSettingsError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SetUserSettingsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetUserSettingsError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(SetUserSettingsError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetUserSettingsError::NotAuthorized,
+                    )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(SetUserSettingsError::PasswordResetRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetUserSettingsError::PasswordResetRequired,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SetUserSettingsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetUserSettingsError::ResourceNotFound,
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(SetUserSettingsError::UserNotConfirmed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetUserSettingsError::UserNotConfirmed,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(SetUserSettingsError::UserNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetUserSettingsError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10938,50 +13495,118 @@ impl SignUpError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeDeliveryFailureException" => {
-                    return RusotoError::Service(SignUpError::CodeDeliveryFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SignUpError::CodeDeliveryFailure,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(SignUpError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SignUpError::InternalError,
+                    )
                 }
                 "InvalidEmailRoleAccessPolicyException" => {
-                    return RusotoError::Service(SignUpError::InvalidEmailRoleAccessPolicy(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SignUpError::InvalidEmailRoleAccessPolicy,
+                    )
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(SignUpError::InvalidLambdaResponse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SignUpError::InvalidLambdaResponse,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SignUpError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SignUpError::InvalidParameter,
+                    )
                 }
                 "InvalidPasswordException" => {
-                    return RusotoError::Service(SignUpError::InvalidPassword(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SignUpError::InvalidPassword,
+                    )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(SignUpError::InvalidSmsRoleAccessPolicy(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SignUpError::InvalidSmsRoleAccessPolicy,
+                    )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(SignUpError::InvalidSmsRoleTrustRelationship(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SignUpError::InvalidSmsRoleTrustRelationship,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(SignUpError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SignUpError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SignUpError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SignUpError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(SignUpError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SignUpError::TooManyRequests,
+                    )
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(SignUpError::UnexpectedLambda(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SignUpError::UnexpectedLambda,
+                    )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(SignUpError::UserLambdaValidation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SignUpError::UserLambdaValidation,
+                    )
                 }
                 "UsernameExistsException" => {
-                    return RusotoError::Service(SignUpError::UsernameExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SignUpError::UsernameExists,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11032,26 +13657,54 @@ impl StartUserImportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(StartUserImportJobError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartUserImportJobError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartUserImportJobError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartUserImportJobError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(StartUserImportJobError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartUserImportJobError::NotAuthorized,
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(StartUserImportJobError::PreconditionNotMet(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartUserImportJobError::PreconditionNotMet,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartUserImportJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartUserImportJobError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartUserImportJobError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartUserImportJobError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11094,26 +13747,54 @@ impl StopUserImportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(StopUserImportJobError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopUserImportJobError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StopUserImportJobError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopUserImportJobError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(StopUserImportJobError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopUserImportJobError::NotAuthorized,
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(StopUserImportJobError::PreconditionNotMet(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopUserImportJobError::PreconditionNotMet,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopUserImportJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopUserImportJobError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StopUserImportJobError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopUserImportJobError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11154,21 +13835,46 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(TagResourceError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(TagResourceError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11208,21 +13914,46 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(UntagResourceError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UntagResourceError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11266,41 +13997,62 @@ impl UpdateAuthEventFeedbackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateAuthEventFeedbackError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAuthEventFeedbackError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateAuthEventFeedbackError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAuthEventFeedbackError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UpdateAuthEventFeedbackError::NotAuthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAuthEventFeedbackError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateAuthEventFeedbackError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAuthEventFeedbackError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateAuthEventFeedbackError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAuthEventFeedbackError::TooManyRequests,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(UpdateAuthEventFeedbackError::UserNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAuthEventFeedbackError::UserNotFound,
+                    )
                 }
                 "UserPoolAddOnNotEnabledException" => {
-                    return RusotoError::Service(
-                        UpdateAuthEventFeedbackError::UserPoolAddOnNotEnabled(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAuthEventFeedbackError::UserPoolAddOnNotEnabled,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11352,37 +14104,78 @@ impl UpdateDeviceStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateDeviceStatusError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeviceStatusError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateDeviceStatusError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeviceStatusError::InvalidParameter,
+                    )
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(
-                        UpdateDeviceStatusError::InvalidUserPoolConfiguration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeviceStatusError::InvalidUserPoolConfiguration,
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UpdateDeviceStatusError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeviceStatusError::NotAuthorized,
+                    )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(UpdateDeviceStatusError::PasswordResetRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDeviceStatusError::PasswordResetRequired,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDeviceStatusError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeviceStatusError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateDeviceStatusError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeviceStatusError::TooManyRequests,
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(UpdateDeviceStatusError::UserNotConfirmed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeviceStatusError::UserNotConfirmed,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(UpdateDeviceStatusError::UserNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeviceStatusError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11428,21 +14221,46 @@ impl UpdateGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateGroupError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGroupError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateGroupError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGroupError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UpdateGroupError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGroupError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGroupError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateGroupError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGroupError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11484,36 +14302,54 @@ impl UpdateIdentityProviderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateIdentityProviderError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateIdentityProviderError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateIdentityProviderError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateIdentityProviderError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UpdateIdentityProviderError::NotAuthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateIdentityProviderError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateIdentityProviderError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateIdentityProviderError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateIdentityProviderError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateIdentityProviderError::TooManyRequests,
+                    )
                 }
                 "UnsupportedIdentityProviderException" => {
-                    return RusotoError::Service(
-                        UpdateIdentityProviderError::UnsupportedIdentityProvider(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIdentityProviderError::UnsupportedIdentityProvider,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11556,27 +14392,46 @@ impl UpdateResourceServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateResourceServerError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateResourceServerError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateResourceServerError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateResourceServerError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UpdateResourceServerError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateResourceServerError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateResourceServerError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateResourceServerError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateResourceServerError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateResourceServerError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11642,84 +14497,150 @@ impl UpdateUserAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AliasExistsException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::AliasExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserAttributesError::AliasExists,
+                    )
                 }
                 "CodeDeliveryFailureException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::CodeDeliveryFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserAttributesError::CodeDeliveryFailure,
+                    )
                 }
                 "CodeMismatchException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::CodeMismatch(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserAttributesError::CodeMismatch,
+                    )
                 }
                 "ExpiredCodeException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::ExpiredCode(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserAttributesError::ExpiredCode,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserAttributesError::InternalError,
+                    )
                 }
                 "InvalidEmailRoleAccessPolicyException" => {
-                    return RusotoError::Service(
-                        UpdateUserAttributesError::InvalidEmailRoleAccessPolicy(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserAttributesError::InvalidEmailRoleAccessPolicy,
                     )
                 }
                 "InvalidLambdaResponseException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::InvalidLambdaResponse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserAttributesError::InvalidLambdaResponse,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserAttributesError::InvalidParameter,
+                    )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(
-                        UpdateUserAttributesError::InvalidSmsRoleAccessPolicy(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserAttributesError::InvalidSmsRoleAccessPolicy,
                     )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
-                        UpdateUserAttributesError::InvalidSmsRoleTrustRelationship(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserAttributesError::InvalidSmsRoleTrustRelationship,
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserAttributesError::NotAuthorized,
+                    )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::PasswordResetRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserAttributesError::PasswordResetRequired,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserAttributesError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserAttributesError::TooManyRequests,
+                    )
                 }
                 "UnexpectedLambdaException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::UnexpectedLambda(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserAttributesError::UnexpectedLambda,
+                    )
                 }
                 "UserLambdaValidationException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::UserLambdaValidation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserAttributesError::UserLambdaValidation,
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::UserNotConfirmed(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserAttributesError::UserNotConfirmed,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(UpdateUserAttributesError::UserNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserAttributesError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11790,47 +14711,94 @@ impl UpdateUserPoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateUserPoolError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserPoolError::ConcurrentModification,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateUserPoolError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserPoolError::InternalError,
+                    )
                 }
                 "InvalidEmailRoleAccessPolicyException" => {
-                    return RusotoError::Service(UpdateUserPoolError::InvalidEmailRoleAccessPolicy(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserPoolError::InvalidEmailRoleAccessPolicy,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateUserPoolError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserPoolError::InvalidParameter,
+                    )
                 }
                 "InvalidSmsRoleAccessPolicyException" => {
-                    return RusotoError::Service(UpdateUserPoolError::InvalidSmsRoleAccessPolicy(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserPoolError::InvalidSmsRoleAccessPolicy,
+                    )
                 }
                 "InvalidSmsRoleTrustRelationshipException" => {
-                    return RusotoError::Service(
-                        UpdateUserPoolError::InvalidSmsRoleTrustRelationship(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserPoolError::InvalidSmsRoleTrustRelationship,
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UpdateUserPoolError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserPoolError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateUserPoolError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserPoolError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateUserPoolError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserPoolError::TooManyRequests,
+                    )
                 }
                 "UserImportInProgressException" => {
-                    return RusotoError::Service(UpdateUserPoolError::UserImportInProgress(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserPoolError::UserImportInProgress,
+                    )
                 }
                 "UserPoolTaggingException" => {
-                    return RusotoError::Service(UpdateUserPoolError::UserPoolTagging(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserPoolError::UserPoolTagging,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11884,42 +14852,70 @@ impl UpdateUserPoolClientError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateUserPoolClientError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserPoolClientError::ConcurrentModification,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateUserPoolClientError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserPoolClientError::InternalError,
+                    )
                 }
                 "InvalidOAuthFlowException" => {
-                    return RusotoError::Service(UpdateUserPoolClientError::InvalidOAuthFlow(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserPoolClientError::InvalidOAuthFlow,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateUserPoolClientError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserPoolClientError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UpdateUserPoolClientError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserPoolClientError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateUserPoolClientError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserPoolClientError::ResourceNotFound,
+                    )
                 }
                 "ScopeDoesNotExistException" => {
-                    return RusotoError::Service(UpdateUserPoolClientError::ScopeDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserPoolClientError::ScopeDoesNotExist,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateUserPoolClientError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserPoolClientError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11962,27 +14958,46 @@ impl UpdateUserPoolDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateUserPoolDomainError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserPoolDomainError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateUserPoolDomainError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserPoolDomainError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UpdateUserPoolDomainError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserPoolDomainError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateUserPoolDomainError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserPoolDomainError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateUserPoolDomainError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserPoolDomainError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12036,56 +15051,102 @@ impl VerifySoftwareTokenError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeMismatchException" => {
-                    return RusotoError::Service(VerifySoftwareTokenError::CodeMismatch(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        VerifySoftwareTokenError::CodeMismatch,
+                    )
                 }
                 "EnableSoftwareTokenMFAException" => {
-                    return RusotoError::Service(VerifySoftwareTokenError::EnableSoftwareTokenMFA(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        VerifySoftwareTokenError::EnableSoftwareTokenMFA,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(VerifySoftwareTokenError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        VerifySoftwareTokenError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(VerifySoftwareTokenError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        VerifySoftwareTokenError::InvalidParameter,
+                    )
                 }
                 "InvalidUserPoolConfigurationException" => {
-                    return RusotoError::Service(
-                        VerifySoftwareTokenError::InvalidUserPoolConfiguration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        VerifySoftwareTokenError::InvalidUserPoolConfiguration,
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(VerifySoftwareTokenError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        VerifySoftwareTokenError::NotAuthorized,
+                    )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(VerifySoftwareTokenError::PasswordResetRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        VerifySoftwareTokenError::PasswordResetRequired,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(VerifySoftwareTokenError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        VerifySoftwareTokenError::ResourceNotFound,
+                    )
                 }
                 "SoftwareTokenMFANotFoundException" => {
-                    return RusotoError::Service(
-                        VerifySoftwareTokenError::SoftwareTokenMFANotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        VerifySoftwareTokenError::SoftwareTokenMFANotFound,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(VerifySoftwareTokenError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        VerifySoftwareTokenError::TooManyRequests,
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(VerifySoftwareTokenError::UserNotConfirmed(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        VerifySoftwareTokenError::UserNotConfirmed,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(VerifySoftwareTokenError::UserNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        VerifySoftwareTokenError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12146,47 +15207,94 @@ impl VerifyUserAttributeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeMismatchException" => {
-                    return RusotoError::Service(VerifyUserAttributeError::CodeMismatch(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        VerifyUserAttributeError::CodeMismatch,
+                    )
                 }
                 "ExpiredCodeException" => {
-                    return RusotoError::Service(VerifyUserAttributeError::ExpiredCode(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        VerifyUserAttributeError::ExpiredCode,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(VerifyUserAttributeError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        VerifyUserAttributeError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(VerifyUserAttributeError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        VerifyUserAttributeError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(VerifyUserAttributeError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        VerifyUserAttributeError::LimitExceeded,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(VerifyUserAttributeError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        VerifyUserAttributeError::NotAuthorized,
+                    )
                 }
                 "PasswordResetRequiredException" => {
-                    return RusotoError::Service(VerifyUserAttributeError::PasswordResetRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        VerifyUserAttributeError::PasswordResetRequired,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(VerifyUserAttributeError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        VerifyUserAttributeError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(VerifyUserAttributeError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        VerifyUserAttributeError::TooManyRequests,
+                    )
                 }
                 "UserNotConfirmedException" => {
-                    return RusotoError::Service(VerifyUserAttributeError::UserNotConfirmed(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        VerifyUserAttributeError::UserNotConfirmed,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(VerifyUserAttributeError::UserNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        VerifyUserAttributeError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12831,11 +15939,12 @@ pub struct CognitoIdentityProviderClient {
 impl CognitoIdentityProviderClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> CognitoIdentityProviderClient {
         CognitoIdentityProviderClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Cognito Identity Provider"),
         }
     }
 
@@ -12850,7 +15959,7 @@ impl CognitoIdentityProviderClient {
     {
         CognitoIdentityProviderClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Cognito Identity Provider"),
         }
     }
 
@@ -12858,7 +15967,27 @@ impl CognitoIdentityProviderClient {
         client: Client,
         region: region::Region,
     ) -> CognitoIdentityProviderClient {
-        CognitoIdentityProviderClient { client, region }
+        CognitoIdentityProviderClient {
+            client,
+            region: region_for_service(region, "Cognito Identity Provider"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> CognitoIdentityProviderClient {
+        CognitoIdentityProviderClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Cognito Identity Provider"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CognitoIdentityProviderClient {
+        CognitoIdentityProviderClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -12870,6 +15999,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AddCustomAttributesRequest,
     ) -> Result<AddCustomAttributesResponse, RusotoError<AddCustomAttributesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AddCustomAttributes");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AddCustomAttributes",
@@ -12891,6 +16021,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminAddUserToGroupRequest,
     ) -> Result<(), RusotoError<AdminAddUserToGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AdminAddUserToGroup");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminAddUserToGroup",
@@ -12911,6 +16042,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminConfirmSignUpRequest,
     ) -> Result<AdminConfirmSignUpResponse, RusotoError<AdminConfirmSignUpError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AdminConfirmSignUp");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminConfirmSignUp",
@@ -12932,6 +16064,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminCreateUserRequest,
     ) -> Result<AdminCreateUserResponse, RusotoError<AdminCreateUserError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AdminCreateUser");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminCreateUser",
@@ -12953,6 +16086,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminDeleteUserRequest,
     ) -> Result<(), RusotoError<AdminDeleteUserError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AdminDeleteUser");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminDeleteUser",
@@ -12974,6 +16108,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
     ) -> Result<AdminDeleteUserAttributesResponse, RusotoError<AdminDeleteUserAttributesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AdminDeleteUserAttributes");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminDeleteUserAttributes",
@@ -12997,6 +16132,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
     ) -> Result<AdminDisableProviderForUserResponse, RusotoError<AdminDisableProviderForUserError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AdminDisableProviderForUser");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminDisableProviderForUser",
@@ -13019,6 +16155,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminDisableUserRequest,
     ) -> Result<AdminDisableUserResponse, RusotoError<AdminDisableUserError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AdminDisableUser");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminDisableUser",
@@ -13040,6 +16177,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminEnableUserRequest,
     ) -> Result<AdminEnableUserResponse, RusotoError<AdminEnableUserError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AdminEnableUser");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminEnableUser",
@@ -13061,6 +16199,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminForgetDeviceRequest,
     ) -> Result<(), RusotoError<AdminForgetDeviceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AdminForgetDevice");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminForgetDevice",
@@ -13081,6 +16220,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminGetDeviceRequest,
     ) -> Result<AdminGetDeviceResponse, RusotoError<AdminGetDeviceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AdminGetDevice");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminGetDevice",
@@ -13102,6 +16242,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminGetUserRequest,
     ) -> Result<AdminGetUserResponse, RusotoError<AdminGetUserError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AdminGetUser");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminGetUser",
@@ -13123,6 +16264,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminInitiateAuthRequest,
     ) -> Result<AdminInitiateAuthResponse, RusotoError<AdminInitiateAuthError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AdminInitiateAuth");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminInitiateAuth",
@@ -13144,6 +16286,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminLinkProviderForUserRequest,
     ) -> Result<AdminLinkProviderForUserResponse, RusotoError<AdminLinkProviderForUserError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AdminLinkProviderForUser");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminLinkProviderForUser",
@@ -13166,6 +16309,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminListDevicesRequest,
     ) -> Result<AdminListDevicesResponse, RusotoError<AdminListDevicesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AdminListDevices");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminListDevices",
@@ -13187,6 +16331,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminListGroupsForUserRequest,
     ) -> Result<AdminListGroupsForUserResponse, RusotoError<AdminListGroupsForUserError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AdminListGroupsForUser");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminListGroupsForUser",
@@ -13209,6 +16354,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminListUserAuthEventsRequest,
     ) -> Result<AdminListUserAuthEventsResponse, RusotoError<AdminListUserAuthEventsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AdminListUserAuthEvents");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminListUserAuthEvents",
@@ -13231,6 +16377,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminRemoveUserFromGroupRequest,
     ) -> Result<(), RusotoError<AdminRemoveUserFromGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AdminRemoveUserFromGroup");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminRemoveUserFromGroup",
@@ -13251,6 +16398,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminResetUserPasswordRequest,
     ) -> Result<AdminResetUserPasswordResponse, RusotoError<AdminResetUserPasswordError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AdminResetUserPassword");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminResetUserPassword",
@@ -13274,6 +16422,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
     ) -> Result<AdminRespondToAuthChallengeResponse, RusotoError<AdminRespondToAuthChallengeError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AdminRespondToAuthChallenge");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminRespondToAuthChallenge",
@@ -13297,6 +16446,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
     ) -> Result<AdminSetUserMFAPreferenceResponse, RusotoError<AdminSetUserMFAPreferenceError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AdminSetUserMFAPreference");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminSetUserMFAPreference",
@@ -13319,6 +16469,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminSetUserPasswordRequest,
     ) -> Result<AdminSetUserPasswordResponse, RusotoError<AdminSetUserPasswordError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AdminSetUserPassword");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminSetUserPassword",
@@ -13341,6 +16492,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminSetUserSettingsRequest,
     ) -> Result<AdminSetUserSettingsResponse, RusotoError<AdminSetUserSettingsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AdminSetUserSettings");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminSetUserSettings",
@@ -13364,6 +16516,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
     ) -> Result<AdminUpdateAuthEventFeedbackResponse, RusotoError<AdminUpdateAuthEventFeedbackError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AdminUpdateAuthEventFeedback");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminUpdateAuthEventFeedback",
@@ -13386,6 +16539,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminUpdateDeviceStatusRequest,
     ) -> Result<AdminUpdateDeviceStatusResponse, RusotoError<AdminUpdateDeviceStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AdminUpdateDeviceStatus");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminUpdateDeviceStatus",
@@ -13409,6 +16563,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
     ) -> Result<AdminUpdateUserAttributesResponse, RusotoError<AdminUpdateUserAttributesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AdminUpdateUserAttributes");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminUpdateUserAttributes",
@@ -13431,6 +16586,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AdminUserGlobalSignOutRequest,
     ) -> Result<AdminUserGlobalSignOutResponse, RusotoError<AdminUserGlobalSignOutError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AdminUserGlobalSignOut");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AdminUserGlobalSignOut",
@@ -13453,6 +16609,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: AssociateSoftwareTokenRequest,
     ) -> Result<AssociateSoftwareTokenResponse, RusotoError<AssociateSoftwareTokenError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateSoftwareToken");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.AssociateSoftwareToken",
@@ -13475,6 +16632,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ChangePasswordRequest,
     ) -> Result<ChangePasswordResponse, RusotoError<ChangePasswordError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ChangePassword");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.ChangePassword",
@@ -13496,6 +16654,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ConfirmDeviceRequest,
     ) -> Result<ConfirmDeviceResponse, RusotoError<ConfirmDeviceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ConfirmDevice");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.ConfirmDevice",
@@ -13517,6 +16676,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ConfirmForgotPasswordRequest,
     ) -> Result<ConfirmForgotPasswordResponse, RusotoError<ConfirmForgotPasswordError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ConfirmForgotPassword");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.ConfirmForgotPassword",
@@ -13539,6 +16699,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ConfirmSignUpRequest,
     ) -> Result<ConfirmSignUpResponse, RusotoError<ConfirmSignUpError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ConfirmSignUp");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.ConfirmSignUp",
@@ -13560,6 +16721,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: CreateGroupRequest,
     ) -> Result<CreateGroupResponse, RusotoError<CreateGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateGroup");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.CreateGroup",
@@ -13581,6 +16743,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: CreateIdentityProviderRequest,
     ) -> Result<CreateIdentityProviderResponse, RusotoError<CreateIdentityProviderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateIdentityProvider");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.CreateIdentityProvider",
@@ -13603,6 +16766,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: CreateResourceServerRequest,
     ) -> Result<CreateResourceServerResponse, RusotoError<CreateResourceServerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateResourceServer");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.CreateResourceServer",
@@ -13625,6 +16789,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: CreateUserImportJobRequest,
     ) -> Result<CreateUserImportJobResponse, RusotoError<CreateUserImportJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateUserImportJob");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.CreateUserImportJob",
@@ -13646,6 +16811,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: CreateUserPoolRequest,
     ) -> Result<CreateUserPoolResponse, RusotoError<CreateUserPoolError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateUserPool");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.CreateUserPool",
@@ -13667,6 +16833,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: CreateUserPoolClientRequest,
     ) -> Result<CreateUserPoolClientResponse, RusotoError<CreateUserPoolClientError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateUserPoolClient");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.CreateUserPoolClient",
@@ -13689,6 +16856,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: CreateUserPoolDomainRequest,
     ) -> Result<CreateUserPoolDomainResponse, RusotoError<CreateUserPoolDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateUserPoolDomain");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.CreateUserPoolDomain",
@@ -13711,6 +16879,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DeleteGroupRequest,
     ) -> Result<(), RusotoError<DeleteGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteGroup");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.DeleteGroup",
@@ -13731,6 +16900,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DeleteIdentityProviderRequest,
     ) -> Result<(), RusotoError<DeleteIdentityProviderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteIdentityProvider");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.DeleteIdentityProvider",
@@ -13751,6 +16921,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DeleteResourceServerRequest,
     ) -> Result<(), RusotoError<DeleteResourceServerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteResourceServer");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.DeleteResourceServer",
@@ -13771,6 +16942,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DeleteUserRequest,
     ) -> Result<(), RusotoError<DeleteUserError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteUser");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.DeleteUser",
@@ -13791,6 +16963,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DeleteUserAttributesRequest,
     ) -> Result<DeleteUserAttributesResponse, RusotoError<DeleteUserAttributesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteUserAttributes");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.DeleteUserAttributes",
@@ -13813,6 +16986,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DeleteUserPoolRequest,
     ) -> Result<(), RusotoError<DeleteUserPoolError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteUserPool");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.DeleteUserPool",
@@ -13833,6 +17007,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DeleteUserPoolClientRequest,
     ) -> Result<(), RusotoError<DeleteUserPoolClientError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteUserPoolClient");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.DeleteUserPoolClient",
@@ -13853,6 +17028,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DeleteUserPoolDomainRequest,
     ) -> Result<DeleteUserPoolDomainResponse, RusotoError<DeleteUserPoolDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteUserPoolDomain");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.DeleteUserPoolDomain",
@@ -13875,6 +17051,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DescribeIdentityProviderRequest,
     ) -> Result<DescribeIdentityProviderResponse, RusotoError<DescribeIdentityProviderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeIdentityProvider");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.DescribeIdentityProvider",
@@ -13897,6 +17074,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DescribeResourceServerRequest,
     ) -> Result<DescribeResourceServerResponse, RusotoError<DescribeResourceServerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeResourceServer");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.DescribeResourceServer",
@@ -13920,6 +17098,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
     ) -> Result<DescribeRiskConfigurationResponse, RusotoError<DescribeRiskConfigurationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeRiskConfiguration");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.DescribeRiskConfiguration",
@@ -13942,6 +17121,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DescribeUserImportJobRequest,
     ) -> Result<DescribeUserImportJobResponse, RusotoError<DescribeUserImportJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeUserImportJob");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.DescribeUserImportJob",
@@ -13964,6 +17144,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DescribeUserPoolRequest,
     ) -> Result<DescribeUserPoolResponse, RusotoError<DescribeUserPoolError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeUserPool");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.DescribeUserPool",
@@ -13985,6 +17166,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DescribeUserPoolClientRequest,
     ) -> Result<DescribeUserPoolClientResponse, RusotoError<DescribeUserPoolClientError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeUserPoolClient");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.DescribeUserPoolClient",
@@ -14007,6 +17189,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: DescribeUserPoolDomainRequest,
     ) -> Result<DescribeUserPoolDomainResponse, RusotoError<DescribeUserPoolDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeUserPoolDomain");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.DescribeUserPoolDomain",
@@ -14029,6 +17212,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ForgetDeviceRequest,
     ) -> Result<(), RusotoError<ForgetDeviceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ForgetDevice");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.ForgetDevice",
@@ -14049,6 +17233,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ForgotPasswordRequest,
     ) -> Result<ForgotPasswordResponse, RusotoError<ForgotPasswordError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ForgotPassword");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.ForgotPassword",
@@ -14070,6 +17255,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: GetCSVHeaderRequest,
     ) -> Result<GetCSVHeaderResponse, RusotoError<GetCSVHeaderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetCSVHeader");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.GetCSVHeader",
@@ -14091,6 +17277,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: GetDeviceRequest,
     ) -> Result<GetDeviceResponse, RusotoError<GetDeviceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDevice");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.GetDevice",
@@ -14112,6 +17299,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: GetGroupRequest,
     ) -> Result<GetGroupResponse, RusotoError<GetGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetGroup");
         request.add_header("x-amz-target", "AWSCognitoIdentityProviderService.GetGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14133,6 +17321,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         RusotoError<GetIdentityProviderByIdentifierError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetIdentityProviderByIdentifier");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.GetIdentityProviderByIdentifier",
@@ -14155,6 +17344,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: GetSigningCertificateRequest,
     ) -> Result<GetSigningCertificateResponse, RusotoError<GetSigningCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetSigningCertificate");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.GetSigningCertificate",
@@ -14177,6 +17367,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: GetUICustomizationRequest,
     ) -> Result<GetUICustomizationResponse, RusotoError<GetUICustomizationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetUICustomization");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.GetUICustomization",
@@ -14198,6 +17389,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: GetUserRequest,
     ) -> Result<GetUserResponse, RusotoError<GetUserError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetUser");
         request.add_header("x-amz-target", "AWSCognitoIdentityProviderService.GetUser");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14219,6 +17411,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         RusotoError<GetUserAttributeVerificationCodeError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetUserAttributeVerificationCode");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.GetUserAttributeVerificationCode",
@@ -14244,6 +17437,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: GetUserPoolMfaConfigRequest,
     ) -> Result<GetUserPoolMfaConfigResponse, RusotoError<GetUserPoolMfaConfigError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetUserPoolMfaConfig");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.GetUserPoolMfaConfig",
@@ -14266,6 +17460,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: GlobalSignOutRequest,
     ) -> Result<GlobalSignOutResponse, RusotoError<GlobalSignOutError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GlobalSignOut");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.GlobalSignOut",
@@ -14287,6 +17482,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: InitiateAuthRequest,
     ) -> Result<InitiateAuthResponse, RusotoError<InitiateAuthError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("InitiateAuth");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.InitiateAuth",
@@ -14308,6 +17504,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ListDevicesRequest,
     ) -> Result<ListDevicesResponse, RusotoError<ListDevicesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListDevices");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.ListDevices",
@@ -14329,6 +17526,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ListGroupsRequest,
     ) -> Result<ListGroupsResponse, RusotoError<ListGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListGroups");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.ListGroups",
@@ -14350,6 +17548,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ListIdentityProvidersRequest,
     ) -> Result<ListIdentityProvidersResponse, RusotoError<ListIdentityProvidersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListIdentityProviders");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.ListIdentityProviders",
@@ -14372,6 +17571,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ListResourceServersRequest,
     ) -> Result<ListResourceServersResponse, RusotoError<ListResourceServersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListResourceServers");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.ListResourceServers",
@@ -14393,6 +17593,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.ListTagsForResource",
@@ -14414,6 +17615,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ListUserImportJobsRequest,
     ) -> Result<ListUserImportJobsResponse, RusotoError<ListUserImportJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListUserImportJobs");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.ListUserImportJobs",
@@ -14435,6 +17637,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ListUserPoolClientsRequest,
     ) -> Result<ListUserPoolClientsResponse, RusotoError<ListUserPoolClientsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListUserPoolClients");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.ListUserPoolClients",
@@ -14456,6 +17659,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ListUserPoolsRequest,
     ) -> Result<ListUserPoolsResponse, RusotoError<ListUserPoolsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListUserPools");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.ListUserPools",
@@ -14477,6 +17681,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ListUsersRequest,
     ) -> Result<ListUsersResponse, RusotoError<ListUsersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListUsers");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.ListUsers",
@@ -14498,6 +17703,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ListUsersInGroupRequest,
     ) -> Result<ListUsersInGroupResponse, RusotoError<ListUsersInGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListUsersInGroup");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.ListUsersInGroup",
@@ -14519,6 +17725,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: ResendConfirmationCodeRequest,
     ) -> Result<ResendConfirmationCodeResponse, RusotoError<ResendConfirmationCodeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ResendConfirmationCode");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.ResendConfirmationCode",
@@ -14541,6 +17748,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: RespondToAuthChallengeRequest,
     ) -> Result<RespondToAuthChallengeResponse, RusotoError<RespondToAuthChallengeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RespondToAuthChallenge");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.RespondToAuthChallenge",
@@ -14563,6 +17771,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: SetRiskConfigurationRequest,
     ) -> Result<SetRiskConfigurationResponse, RusotoError<SetRiskConfigurationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SetRiskConfiguration");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.SetRiskConfiguration",
@@ -14585,6 +17794,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: SetUICustomizationRequest,
     ) -> Result<SetUICustomizationResponse, RusotoError<SetUICustomizationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SetUICustomization");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.SetUICustomization",
@@ -14606,6 +17816,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: SetUserMFAPreferenceRequest,
     ) -> Result<SetUserMFAPreferenceResponse, RusotoError<SetUserMFAPreferenceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SetUserMFAPreference");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.SetUserMFAPreference",
@@ -14628,6 +17839,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: SetUserPoolMfaConfigRequest,
     ) -> Result<SetUserPoolMfaConfigResponse, RusotoError<SetUserPoolMfaConfigError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SetUserPoolMfaConfig");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.SetUserPoolMfaConfig",
@@ -14650,6 +17862,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: SetUserSettingsRequest,
     ) -> Result<SetUserSettingsResponse, RusotoError<SetUserSettingsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SetUserSettings");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.SetUserSettings",
@@ -14671,6 +17884,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: SignUpRequest,
     ) -> Result<SignUpResponse, RusotoError<SignUpError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SignUp");
         request.add_header("x-amz-target", "AWSCognitoIdentityProviderService.SignUp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -14689,6 +17903,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: StartUserImportJobRequest,
     ) -> Result<StartUserImportJobResponse, RusotoError<StartUserImportJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartUserImportJob");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.StartUserImportJob",
@@ -14710,6 +17925,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: StopUserImportJobRequest,
     ) -> Result<StopUserImportJobResponse, RusotoError<StopUserImportJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopUserImportJob");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.StopUserImportJob",
@@ -14731,6 +17947,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.TagResource",
@@ -14752,6 +17969,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.UntagResource",
@@ -14773,6 +17991,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: UpdateAuthEventFeedbackRequest,
     ) -> Result<UpdateAuthEventFeedbackResponse, RusotoError<UpdateAuthEventFeedbackError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateAuthEventFeedback");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.UpdateAuthEventFeedback",
@@ -14795,6 +18014,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: UpdateDeviceStatusRequest,
     ) -> Result<UpdateDeviceStatusResponse, RusotoError<UpdateDeviceStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateDeviceStatus");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.UpdateDeviceStatus",
@@ -14816,6 +18036,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: UpdateGroupRequest,
     ) -> Result<UpdateGroupResponse, RusotoError<UpdateGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateGroup");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.UpdateGroup",
@@ -14837,6 +18058,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: UpdateIdentityProviderRequest,
     ) -> Result<UpdateIdentityProviderResponse, RusotoError<UpdateIdentityProviderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateIdentityProvider");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.UpdateIdentityProvider",
@@ -14859,6 +18081,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: UpdateResourceServerRequest,
     ) -> Result<UpdateResourceServerResponse, RusotoError<UpdateResourceServerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateResourceServer");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.UpdateResourceServer",
@@ -14881,6 +18104,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: UpdateUserAttributesRequest,
     ) -> Result<UpdateUserAttributesResponse, RusotoError<UpdateUserAttributesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateUserAttributes");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.UpdateUserAttributes",
@@ -14903,6 +18127,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: UpdateUserPoolRequest,
     ) -> Result<UpdateUserPoolResponse, RusotoError<UpdateUserPoolError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateUserPool");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.UpdateUserPool",
@@ -14924,6 +18149,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: UpdateUserPoolClientRequest,
     ) -> Result<UpdateUserPoolClientResponse, RusotoError<UpdateUserPoolClientError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateUserPoolClient");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.UpdateUserPoolClient",
@@ -14946,6 +18172,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: UpdateUserPoolDomainRequest,
     ) -> Result<UpdateUserPoolDomainResponse, RusotoError<UpdateUserPoolDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateUserPoolDomain");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.UpdateUserPoolDomain",
@@ -14968,6 +18195,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: VerifySoftwareTokenRequest,
     ) -> Result<VerifySoftwareTokenResponse, RusotoError<VerifySoftwareTokenError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("VerifySoftwareToken");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.VerifySoftwareToken",
@@ -14989,6 +18217,7 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         input: VerifyUserAttributeRequest,
     ) -> Result<VerifyUserAttributeResponse, RusotoError<VerifyUserAttributeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("VerifyUserAttribute");
         request.add_header(
             "x-amz-target",
             "AWSCognitoIdentityProviderService.VerifyUserAttribute",
@@ -15004,3 +18233,971 @@ impl CognitoIdentityProvider for CognitoIdentityProviderClient {
         proto::json::ResponsePayload::new(&response).deserialize::<VerifyUserAttributeResponse, _>()
     }
 }
+
+/// A blocking client for the Amazon Cognito Identity Provider API, running the calls of a `CognitoIdentityProviderClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `CognitoIdentityProviderClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CognitoIdentityProviderBlockingClient {
+    client: CognitoIdentityProviderClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CognitoIdentityProviderBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> CognitoIdentityProviderBlockingClient {
+        CognitoIdentityProviderBlockingClient::from_client(CognitoIdentityProviderClient::new(
+            region,
+        ))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CognitoIdentityProviderBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CognitoIdentityProviderBlockingClient::from_client(CognitoIdentityProviderClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> CognitoIdentityProviderBlockingClient {
+        CognitoIdentityProviderBlockingClient::from_client(
+            CognitoIdentityProviderClient::new_with_client(client, region),
+        )
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> CognitoIdentityProviderBlockingClient {
+        CognitoIdentityProviderBlockingClient::from_client(
+            CognitoIdentityProviderClient::new_with_config(config),
+        )
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(
+        client: CognitoIdentityProviderClient,
+    ) -> CognitoIdentityProviderBlockingClient {
+        CognitoIdentityProviderBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> CognitoIdentityProviderBlockingClient {
+        CognitoIdentityProviderBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CognitoIdentityProviderBlockingClient {
+        CognitoIdentityProviderBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::add_custom_attributes`].
+    pub fn add_custom_attributes(
+        &self,
+        input: AddCustomAttributesRequest,
+    ) -> Result<AddCustomAttributesResponse, RusotoError<AddCustomAttributesError>> {
+        self.runtime
+            .block_on(self.client.add_custom_attributes(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::admin_add_user_to_group`].
+    pub fn admin_add_user_to_group(
+        &self,
+        input: AdminAddUserToGroupRequest,
+    ) -> Result<(), RusotoError<AdminAddUserToGroupError>> {
+        self.runtime
+            .block_on(self.client.admin_add_user_to_group(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::admin_confirm_sign_up`].
+    pub fn admin_confirm_sign_up(
+        &self,
+        input: AdminConfirmSignUpRequest,
+    ) -> Result<AdminConfirmSignUpResponse, RusotoError<AdminConfirmSignUpError>> {
+        self.runtime
+            .block_on(self.client.admin_confirm_sign_up(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::admin_create_user`].
+    pub fn admin_create_user(
+        &self,
+        input: AdminCreateUserRequest,
+    ) -> Result<AdminCreateUserResponse, RusotoError<AdminCreateUserError>> {
+        self.runtime.block_on(self.client.admin_create_user(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::admin_delete_user`].
+    pub fn admin_delete_user(
+        &self,
+        input: AdminDeleteUserRequest,
+    ) -> Result<(), RusotoError<AdminDeleteUserError>> {
+        self.runtime.block_on(self.client.admin_delete_user(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::admin_delete_user_attributes`].
+    pub fn admin_delete_user_attributes(
+        &self,
+        input: AdminDeleteUserAttributesRequest,
+    ) -> Result<AdminDeleteUserAttributesResponse, RusotoError<AdminDeleteUserAttributesError>>
+    {
+        self.runtime
+            .block_on(self.client.admin_delete_user_attributes(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::admin_disable_provider_for_user`].
+    pub fn admin_disable_provider_for_user(
+        &self,
+        input: AdminDisableProviderForUserRequest,
+    ) -> Result<AdminDisableProviderForUserResponse, RusotoError<AdminDisableProviderForUserError>>
+    {
+        self.runtime
+            .block_on(self.client.admin_disable_provider_for_user(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::admin_disable_user`].
+    pub fn admin_disable_user(
+        &self,
+        input: AdminDisableUserRequest,
+    ) -> Result<AdminDisableUserResponse, RusotoError<AdminDisableUserError>> {
+        self.runtime.block_on(self.client.admin_disable_user(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::admin_enable_user`].
+    pub fn admin_enable_user(
+        &self,
+        input: AdminEnableUserRequest,
+    ) -> Result<AdminEnableUserResponse, RusotoError<AdminEnableUserError>> {
+        self.runtime.block_on(self.client.admin_enable_user(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::admin_forget_device`].
+    pub fn admin_forget_device(
+        &self,
+        input: AdminForgetDeviceRequest,
+    ) -> Result<(), RusotoError<AdminForgetDeviceError>> {
+        self.runtime
+            .block_on(self.client.admin_forget_device(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::admin_get_device`].
+    pub fn admin_get_device(
+        &self,
+        input: AdminGetDeviceRequest,
+    ) -> Result<AdminGetDeviceResponse, RusotoError<AdminGetDeviceError>> {
+        self.runtime.block_on(self.client.admin_get_device(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::admin_get_user`].
+    pub fn admin_get_user(
+        &self,
+        input: AdminGetUserRequest,
+    ) -> Result<AdminGetUserResponse, RusotoError<AdminGetUserError>> {
+        self.runtime.block_on(self.client.admin_get_user(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::admin_initiate_auth`].
+    pub fn admin_initiate_auth(
+        &self,
+        input: AdminInitiateAuthRequest,
+    ) -> Result<AdminInitiateAuthResponse, RusotoError<AdminInitiateAuthError>> {
+        self.runtime
+            .block_on(self.client.admin_initiate_auth(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::admin_link_provider_for_user`].
+    pub fn admin_link_provider_for_user(
+        &self,
+        input: AdminLinkProviderForUserRequest,
+    ) -> Result<AdminLinkProviderForUserResponse, RusotoError<AdminLinkProviderForUserError>> {
+        self.runtime
+            .block_on(self.client.admin_link_provider_for_user(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::admin_list_devices`].
+    pub fn admin_list_devices(
+        &self,
+        input: AdminListDevicesRequest,
+    ) -> Result<AdminListDevicesResponse, RusotoError<AdminListDevicesError>> {
+        self.runtime.block_on(self.client.admin_list_devices(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::admin_list_groups_for_user`].
+    pub fn admin_list_groups_for_user(
+        &self,
+        input: AdminListGroupsForUserRequest,
+    ) -> Result<AdminListGroupsForUserResponse, RusotoError<AdminListGroupsForUserError>> {
+        self.runtime
+            .block_on(self.client.admin_list_groups_for_user(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::admin_list_user_auth_events`].
+    pub fn admin_list_user_auth_events(
+        &self,
+        input: AdminListUserAuthEventsRequest,
+    ) -> Result<AdminListUserAuthEventsResponse, RusotoError<AdminListUserAuthEventsError>> {
+        self.runtime
+            .block_on(self.client.admin_list_user_auth_events(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::admin_remove_user_from_group`].
+    pub fn admin_remove_user_from_group(
+        &self,
+        input: AdminRemoveUserFromGroupRequest,
+    ) -> Result<(), RusotoError<AdminRemoveUserFromGroupError>> {
+        self.runtime
+            .block_on(self.client.admin_remove_user_from_group(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::admin_reset_user_password`].
+    pub fn admin_reset_user_password(
+        &self,
+        input: AdminResetUserPasswordRequest,
+    ) -> Result<AdminResetUserPasswordResponse, RusotoError<AdminResetUserPasswordError>> {
+        self.runtime
+            .block_on(self.client.admin_reset_user_password(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::admin_respond_to_auth_challenge`].
+    pub fn admin_respond_to_auth_challenge(
+        &self,
+        input: AdminRespondToAuthChallengeRequest,
+    ) -> Result<AdminRespondToAuthChallengeResponse, RusotoError<AdminRespondToAuthChallengeError>>
+    {
+        self.runtime
+            .block_on(self.client.admin_respond_to_auth_challenge(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::admin_set_user_mfa_preference`].
+    pub fn admin_set_user_mfa_preference(
+        &self,
+        input: AdminSetUserMFAPreferenceRequest,
+    ) -> Result<AdminSetUserMFAPreferenceResponse, RusotoError<AdminSetUserMFAPreferenceError>>
+    {
+        self.runtime
+            .block_on(self.client.admin_set_user_mfa_preference(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::admin_set_user_password`].
+    pub fn admin_set_user_password(
+        &self,
+        input: AdminSetUserPasswordRequest,
+    ) -> Result<AdminSetUserPasswordResponse, RusotoError<AdminSetUserPasswordError>> {
+        self.runtime
+            .block_on(self.client.admin_set_user_password(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::admin_set_user_settings`].
+    pub fn admin_set_user_settings(
+        &self,
+        input: AdminSetUserSettingsRequest,
+    ) -> Result<AdminSetUserSettingsResponse, RusotoError<AdminSetUserSettingsError>> {
+        self.runtime
+            .block_on(self.client.admin_set_user_settings(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::admin_update_auth_event_feedback`].
+    pub fn admin_update_auth_event_feedback(
+        &self,
+        input: AdminUpdateAuthEventFeedbackRequest,
+    ) -> Result<AdminUpdateAuthEventFeedbackResponse, RusotoError<AdminUpdateAuthEventFeedbackError>>
+    {
+        self.runtime
+            .block_on(self.client.admin_update_auth_event_feedback(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::admin_update_device_status`].
+    pub fn admin_update_device_status(
+        &self,
+        input: AdminUpdateDeviceStatusRequest,
+    ) -> Result<AdminUpdateDeviceStatusResponse, RusotoError<AdminUpdateDeviceStatusError>> {
+        self.runtime
+            .block_on(self.client.admin_update_device_status(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::admin_update_user_attributes`].
+    pub fn admin_update_user_attributes(
+        &self,
+        input: AdminUpdateUserAttributesRequest,
+    ) -> Result<AdminUpdateUserAttributesResponse, RusotoError<AdminUpdateUserAttributesError>>
+    {
+        self.runtime
+            .block_on(self.client.admin_update_user_attributes(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::admin_user_global_sign_out`].
+    pub fn admin_user_global_sign_out(
+        &self,
+        input: AdminUserGlobalSignOutRequest,
+    ) -> Result<AdminUserGlobalSignOutResponse, RusotoError<AdminUserGlobalSignOutError>> {
+        self.runtime
+            .block_on(self.client.admin_user_global_sign_out(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::associate_software_token`].
+    pub fn associate_software_token(
+        &self,
+        input: AssociateSoftwareTokenRequest,
+    ) -> Result<AssociateSoftwareTokenResponse, RusotoError<AssociateSoftwareTokenError>> {
+        self.runtime
+            .block_on(self.client.associate_software_token(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::change_password`].
+    pub fn change_password(
+        &self,
+        input: ChangePasswordRequest,
+    ) -> Result<ChangePasswordResponse, RusotoError<ChangePasswordError>> {
+        self.runtime.block_on(self.client.change_password(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::confirm_device`].
+    pub fn confirm_device(
+        &self,
+        input: ConfirmDeviceRequest,
+    ) -> Result<ConfirmDeviceResponse, RusotoError<ConfirmDeviceError>> {
+        self.runtime.block_on(self.client.confirm_device(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::confirm_forgot_password`].
+    pub fn confirm_forgot_password(
+        &self,
+        input: ConfirmForgotPasswordRequest,
+    ) -> Result<ConfirmForgotPasswordResponse, RusotoError<ConfirmForgotPasswordError>> {
+        self.runtime
+            .block_on(self.client.confirm_forgot_password(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::confirm_sign_up`].
+    pub fn confirm_sign_up(
+        &self,
+        input: ConfirmSignUpRequest,
+    ) -> Result<ConfirmSignUpResponse, RusotoError<ConfirmSignUpError>> {
+        self.runtime.block_on(self.client.confirm_sign_up(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::create_group`].
+    pub fn create_group(
+        &self,
+        input: CreateGroupRequest,
+    ) -> Result<CreateGroupResponse, RusotoError<CreateGroupError>> {
+        self.runtime.block_on(self.client.create_group(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::create_identity_provider`].
+    pub fn create_identity_provider(
+        &self,
+        input: CreateIdentityProviderRequest,
+    ) -> Result<CreateIdentityProviderResponse, RusotoError<CreateIdentityProviderError>> {
+        self.runtime
+            .block_on(self.client.create_identity_provider(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::create_resource_server`].
+    pub fn create_resource_server(
+        &self,
+        input: CreateResourceServerRequest,
+    ) -> Result<CreateResourceServerResponse, RusotoError<CreateResourceServerError>> {
+        self.runtime
+            .block_on(self.client.create_resource_server(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::create_user_import_job`].
+    pub fn create_user_import_job(
+        &self,
+        input: CreateUserImportJobRequest,
+    ) -> Result<CreateUserImportJobResponse, RusotoError<CreateUserImportJobError>> {
+        self.runtime
+            .block_on(self.client.create_user_import_job(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::create_user_pool`].
+    pub fn create_user_pool(
+        &self,
+        input: CreateUserPoolRequest,
+    ) -> Result<CreateUserPoolResponse, RusotoError<CreateUserPoolError>> {
+        self.runtime.block_on(self.client.create_user_pool(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::create_user_pool_client`].
+    pub fn create_user_pool_client(
+        &self,
+        input: CreateUserPoolClientRequest,
+    ) -> Result<CreateUserPoolClientResponse, RusotoError<CreateUserPoolClientError>> {
+        self.runtime
+            .block_on(self.client.create_user_pool_client(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::create_user_pool_domain`].
+    pub fn create_user_pool_domain(
+        &self,
+        input: CreateUserPoolDomainRequest,
+    ) -> Result<CreateUserPoolDomainResponse, RusotoError<CreateUserPoolDomainError>> {
+        self.runtime
+            .block_on(self.client.create_user_pool_domain(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::delete_group`].
+    pub fn delete_group(
+        &self,
+        input: DeleteGroupRequest,
+    ) -> Result<(), RusotoError<DeleteGroupError>> {
+        self.runtime.block_on(self.client.delete_group(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::delete_identity_provider`].
+    pub fn delete_identity_provider(
+        &self,
+        input: DeleteIdentityProviderRequest,
+    ) -> Result<(), RusotoError<DeleteIdentityProviderError>> {
+        self.runtime
+            .block_on(self.client.delete_identity_provider(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::delete_resource_server`].
+    pub fn delete_resource_server(
+        &self,
+        input: DeleteResourceServerRequest,
+    ) -> Result<(), RusotoError<DeleteResourceServerError>> {
+        self.runtime
+            .block_on(self.client.delete_resource_server(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::delete_user`].
+    pub fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> Result<(), RusotoError<DeleteUserError>> {
+        self.runtime.block_on(self.client.delete_user(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::delete_user_attributes`].
+    pub fn delete_user_attributes(
+        &self,
+        input: DeleteUserAttributesRequest,
+    ) -> Result<DeleteUserAttributesResponse, RusotoError<DeleteUserAttributesError>> {
+        self.runtime
+            .block_on(self.client.delete_user_attributes(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::delete_user_pool`].
+    pub fn delete_user_pool(
+        &self,
+        input: DeleteUserPoolRequest,
+    ) -> Result<(), RusotoError<DeleteUserPoolError>> {
+        self.runtime.block_on(self.client.delete_user_pool(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::delete_user_pool_client`].
+    pub fn delete_user_pool_client(
+        &self,
+        input: DeleteUserPoolClientRequest,
+    ) -> Result<(), RusotoError<DeleteUserPoolClientError>> {
+        self.runtime
+            .block_on(self.client.delete_user_pool_client(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::delete_user_pool_domain`].
+    pub fn delete_user_pool_domain(
+        &self,
+        input: DeleteUserPoolDomainRequest,
+    ) -> Result<DeleteUserPoolDomainResponse, RusotoError<DeleteUserPoolDomainError>> {
+        self.runtime
+            .block_on(self.client.delete_user_pool_domain(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::describe_identity_provider`].
+    pub fn describe_identity_provider(
+        &self,
+        input: DescribeIdentityProviderRequest,
+    ) -> Result<DescribeIdentityProviderResponse, RusotoError<DescribeIdentityProviderError>> {
+        self.runtime
+            .block_on(self.client.describe_identity_provider(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::describe_resource_server`].
+    pub fn describe_resource_server(
+        &self,
+        input: DescribeResourceServerRequest,
+    ) -> Result<DescribeResourceServerResponse, RusotoError<DescribeResourceServerError>> {
+        self.runtime
+            .block_on(self.client.describe_resource_server(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::describe_risk_configuration`].
+    pub fn describe_risk_configuration(
+        &self,
+        input: DescribeRiskConfigurationRequest,
+    ) -> Result<DescribeRiskConfigurationResponse, RusotoError<DescribeRiskConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_risk_configuration(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::describe_user_import_job`].
+    pub fn describe_user_import_job(
+        &self,
+        input: DescribeUserImportJobRequest,
+    ) -> Result<DescribeUserImportJobResponse, RusotoError<DescribeUserImportJobError>> {
+        self.runtime
+            .block_on(self.client.describe_user_import_job(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::describe_user_pool`].
+    pub fn describe_user_pool(
+        &self,
+        input: DescribeUserPoolRequest,
+    ) -> Result<DescribeUserPoolResponse, RusotoError<DescribeUserPoolError>> {
+        self.runtime.block_on(self.client.describe_user_pool(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::describe_user_pool_client`].
+    pub fn describe_user_pool_client(
+        &self,
+        input: DescribeUserPoolClientRequest,
+    ) -> Result<DescribeUserPoolClientResponse, RusotoError<DescribeUserPoolClientError>> {
+        self.runtime
+            .block_on(self.client.describe_user_pool_client(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::describe_user_pool_domain`].
+    pub fn describe_user_pool_domain(
+        &self,
+        input: DescribeUserPoolDomainRequest,
+    ) -> Result<DescribeUserPoolDomainResponse, RusotoError<DescribeUserPoolDomainError>> {
+        self.runtime
+            .block_on(self.client.describe_user_pool_domain(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::forget_device`].
+    pub fn forget_device(
+        &self,
+        input: ForgetDeviceRequest,
+    ) -> Result<(), RusotoError<ForgetDeviceError>> {
+        self.runtime.block_on(self.client.forget_device(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::forgot_password`].
+    pub fn forgot_password(
+        &self,
+        input: ForgotPasswordRequest,
+    ) -> Result<ForgotPasswordResponse, RusotoError<ForgotPasswordError>> {
+        self.runtime.block_on(self.client.forgot_password(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::get_csv_header`].
+    pub fn get_csv_header(
+        &self,
+        input: GetCSVHeaderRequest,
+    ) -> Result<GetCSVHeaderResponse, RusotoError<GetCSVHeaderError>> {
+        self.runtime.block_on(self.client.get_csv_header(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::get_device`].
+    pub fn get_device(
+        &self,
+        input: GetDeviceRequest,
+    ) -> Result<GetDeviceResponse, RusotoError<GetDeviceError>> {
+        self.runtime.block_on(self.client.get_device(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::get_group`].
+    pub fn get_group(
+        &self,
+        input: GetGroupRequest,
+    ) -> Result<GetGroupResponse, RusotoError<GetGroupError>> {
+        self.runtime.block_on(self.client.get_group(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::get_identity_provider_by_identifier`].
+    pub fn get_identity_provider_by_identifier(
+        &self,
+        input: GetIdentityProviderByIdentifierRequest,
+    ) -> Result<
+        GetIdentityProviderByIdentifierResponse,
+        RusotoError<GetIdentityProviderByIdentifierError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_identity_provider_by_identifier(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::get_signing_certificate`].
+    pub fn get_signing_certificate(
+        &self,
+        input: GetSigningCertificateRequest,
+    ) -> Result<GetSigningCertificateResponse, RusotoError<GetSigningCertificateError>> {
+        self.runtime
+            .block_on(self.client.get_signing_certificate(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::get_ui_customization`].
+    pub fn get_ui_customization(
+        &self,
+        input: GetUICustomizationRequest,
+    ) -> Result<GetUICustomizationResponse, RusotoError<GetUICustomizationError>> {
+        self.runtime
+            .block_on(self.client.get_ui_customization(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::get_user`].
+    pub fn get_user(
+        &self,
+        input: GetUserRequest,
+    ) -> Result<GetUserResponse, RusotoError<GetUserError>> {
+        self.runtime.block_on(self.client.get_user(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::get_user_attribute_verification_code`].
+    pub fn get_user_attribute_verification_code(
+        &self,
+        input: GetUserAttributeVerificationCodeRequest,
+    ) -> Result<
+        GetUserAttributeVerificationCodeResponse,
+        RusotoError<GetUserAttributeVerificationCodeError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_user_attribute_verification_code(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::get_user_pool_mfa_config`].
+    pub fn get_user_pool_mfa_config(
+        &self,
+        input: GetUserPoolMfaConfigRequest,
+    ) -> Result<GetUserPoolMfaConfigResponse, RusotoError<GetUserPoolMfaConfigError>> {
+        self.runtime
+            .block_on(self.client.get_user_pool_mfa_config(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::global_sign_out`].
+    pub fn global_sign_out(
+        &self,
+        input: GlobalSignOutRequest,
+    ) -> Result<GlobalSignOutResponse, RusotoError<GlobalSignOutError>> {
+        self.runtime.block_on(self.client.global_sign_out(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::initiate_auth`].
+    pub fn initiate_auth(
+        &self,
+        input: InitiateAuthRequest,
+    ) -> Result<InitiateAuthResponse, RusotoError<InitiateAuthError>> {
+        self.runtime.block_on(self.client.initiate_auth(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::list_devices`].
+    pub fn list_devices(
+        &self,
+        input: ListDevicesRequest,
+    ) -> Result<ListDevicesResponse, RusotoError<ListDevicesError>> {
+        self.runtime.block_on(self.client.list_devices(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::list_groups`].
+    pub fn list_groups(
+        &self,
+        input: ListGroupsRequest,
+    ) -> Result<ListGroupsResponse, RusotoError<ListGroupsError>> {
+        self.runtime.block_on(self.client.list_groups(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::list_identity_providers`].
+    pub fn list_identity_providers(
+        &self,
+        input: ListIdentityProvidersRequest,
+    ) -> Result<ListIdentityProvidersResponse, RusotoError<ListIdentityProvidersError>> {
+        self.runtime
+            .block_on(self.client.list_identity_providers(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::list_resource_servers`].
+    pub fn list_resource_servers(
+        &self,
+        input: ListResourceServersRequest,
+    ) -> Result<ListResourceServersResponse, RusotoError<ListResourceServersError>> {
+        self.runtime
+            .block_on(self.client.list_resource_servers(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::list_user_import_jobs`].
+    pub fn list_user_import_jobs(
+        &self,
+        input: ListUserImportJobsRequest,
+    ) -> Result<ListUserImportJobsResponse, RusotoError<ListUserImportJobsError>> {
+        self.runtime
+            .block_on(self.client.list_user_import_jobs(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::list_user_pool_clients`].
+    pub fn list_user_pool_clients(
+        &self,
+        input: ListUserPoolClientsRequest,
+    ) -> Result<ListUserPoolClientsResponse, RusotoError<ListUserPoolClientsError>> {
+        self.runtime
+            .block_on(self.client.list_user_pool_clients(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::list_user_pools`].
+    pub fn list_user_pools(
+        &self,
+        input: ListUserPoolsRequest,
+    ) -> Result<ListUserPoolsResponse, RusotoError<ListUserPoolsError>> {
+        self.runtime.block_on(self.client.list_user_pools(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::list_users`].
+    pub fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> Result<ListUsersResponse, RusotoError<ListUsersError>> {
+        self.runtime.block_on(self.client.list_users(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::list_users_in_group`].
+    pub fn list_users_in_group(
+        &self,
+        input: ListUsersInGroupRequest,
+    ) -> Result<ListUsersInGroupResponse, RusotoError<ListUsersInGroupError>> {
+        self.runtime
+            .block_on(self.client.list_users_in_group(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::resend_confirmation_code`].
+    pub fn resend_confirmation_code(
+        &self,
+        input: ResendConfirmationCodeRequest,
+    ) -> Result<ResendConfirmationCodeResponse, RusotoError<ResendConfirmationCodeError>> {
+        self.runtime
+            .block_on(self.client.resend_confirmation_code(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::respond_to_auth_challenge`].
+    pub fn respond_to_auth_challenge(
+        &self,
+        input: RespondToAuthChallengeRequest,
+    ) -> Result<RespondToAuthChallengeResponse, RusotoError<RespondToAuthChallengeError>> {
+        self.runtime
+            .block_on(self.client.respond_to_auth_challenge(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::set_risk_configuration`].
+    pub fn set_risk_configuration(
+        &self,
+        input: SetRiskConfigurationRequest,
+    ) -> Result<SetRiskConfigurationResponse, RusotoError<SetRiskConfigurationError>> {
+        self.runtime
+            .block_on(self.client.set_risk_configuration(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::set_ui_customization`].
+    pub fn set_ui_customization(
+        &self,
+        input: SetUICustomizationRequest,
+    ) -> Result<SetUICustomizationResponse, RusotoError<SetUICustomizationError>> {
+        self.runtime
+            .block_on(self.client.set_ui_customization(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::set_user_mfa_preference`].
+    pub fn set_user_mfa_preference(
+        &self,
+        input: SetUserMFAPreferenceRequest,
+    ) -> Result<SetUserMFAPreferenceResponse, RusotoError<SetUserMFAPreferenceError>> {
+        self.runtime
+            .block_on(self.client.set_user_mfa_preference(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::set_user_pool_mfa_config`].
+    pub fn set_user_pool_mfa_config(
+        &self,
+        input: SetUserPoolMfaConfigRequest,
+    ) -> Result<SetUserPoolMfaConfigResponse, RusotoError<SetUserPoolMfaConfigError>> {
+        self.runtime
+            .block_on(self.client.set_user_pool_mfa_config(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::set_user_settings`].
+    pub fn set_user_settings(
+        &self,
+        input: SetUserSettingsRequest,
+    ) -> Result<SetUserSettingsResponse, RusotoError<SetUserSettingsError>> {
+        self.runtime.block_on(self.client.set_user_settings(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::sign_up`].
+    pub fn sign_up(
+        &self,
+        input: SignUpRequest,
+    ) -> Result<SignUpResponse, RusotoError<SignUpError>> {
+        self.runtime.block_on(self.client.sign_up(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::start_user_import_job`].
+    pub fn start_user_import_job(
+        &self,
+        input: StartUserImportJobRequest,
+    ) -> Result<StartUserImportJobResponse, RusotoError<StartUserImportJobError>> {
+        self.runtime
+            .block_on(self.client.start_user_import_job(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::stop_user_import_job`].
+    pub fn stop_user_import_job(
+        &self,
+        input: StopUserImportJobRequest,
+    ) -> Result<StopUserImportJobResponse, RusotoError<StopUserImportJobError>> {
+        self.runtime
+            .block_on(self.client.stop_user_import_job(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::update_auth_event_feedback`].
+    pub fn update_auth_event_feedback(
+        &self,
+        input: UpdateAuthEventFeedbackRequest,
+    ) -> Result<UpdateAuthEventFeedbackResponse, RusotoError<UpdateAuthEventFeedbackError>> {
+        self.runtime
+            .block_on(self.client.update_auth_event_feedback(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::update_device_status`].
+    pub fn update_device_status(
+        &self,
+        input: UpdateDeviceStatusRequest,
+    ) -> Result<UpdateDeviceStatusResponse, RusotoError<UpdateDeviceStatusError>> {
+        self.runtime
+            .block_on(self.client.update_device_status(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::update_group`].
+    pub fn update_group(
+        &self,
+        input: UpdateGroupRequest,
+    ) -> Result<UpdateGroupResponse, RusotoError<UpdateGroupError>> {
+        self.runtime.block_on(self.client.update_group(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::update_identity_provider`].
+    pub fn update_identity_provider(
+        &self,
+        input: UpdateIdentityProviderRequest,
+    ) -> Result<UpdateIdentityProviderResponse, RusotoError<UpdateIdentityProviderError>> {
+        self.runtime
+            .block_on(self.client.update_identity_provider(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::update_resource_server`].
+    pub fn update_resource_server(
+        &self,
+        input: UpdateResourceServerRequest,
+    ) -> Result<UpdateResourceServerResponse, RusotoError<UpdateResourceServerError>> {
+        self.runtime
+            .block_on(self.client.update_resource_server(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::update_user_attributes`].
+    pub fn update_user_attributes(
+        &self,
+        input: UpdateUserAttributesRequest,
+    ) -> Result<UpdateUserAttributesResponse, RusotoError<UpdateUserAttributesError>> {
+        self.runtime
+            .block_on(self.client.update_user_attributes(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::update_user_pool`].
+    pub fn update_user_pool(
+        &self,
+        input: UpdateUserPoolRequest,
+    ) -> Result<UpdateUserPoolResponse, RusotoError<UpdateUserPoolError>> {
+        self.runtime.block_on(self.client.update_user_pool(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::update_user_pool_client`].
+    pub fn update_user_pool_client(
+        &self,
+        input: UpdateUserPoolClientRequest,
+    ) -> Result<UpdateUserPoolClientResponse, RusotoError<UpdateUserPoolClientError>> {
+        self.runtime
+            .block_on(self.client.update_user_pool_client(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::update_user_pool_domain`].
+    pub fn update_user_pool_domain(
+        &self,
+        input: UpdateUserPoolDomainRequest,
+    ) -> Result<UpdateUserPoolDomainResponse, RusotoError<UpdateUserPoolDomainError>> {
+        self.runtime
+            .block_on(self.client.update_user_pool_domain(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::verify_software_token`].
+    pub fn verify_software_token(
+        &self,
+        input: VerifySoftwareTokenRequest,
+    ) -> Result<VerifySoftwareTokenResponse, RusotoError<VerifySoftwareTokenError>> {
+        self.runtime
+            .block_on(self.client.verify_software_token(input))
+    }
+
+    /// Blocking version of [`CognitoIdentityProvider::verify_user_attribute`].
+    pub fn verify_user_attribute(
+        &self,
+        input: VerifyUserAttributeRequest,
+    ) -> Result<VerifyUserAttributeResponse, RusotoError<VerifyUserAttributeError>> {
+        self.runtime
+            .block_on(self.client.verify_user_attribute(input))
+    }
+}
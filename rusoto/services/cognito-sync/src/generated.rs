@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -711,24 +712,54 @@ impl BulkPublishError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AlreadyStreamedException" => {
-                    return RusotoError::Service(BulkPublishError::AlreadyStreamed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BulkPublishError::AlreadyStreamed,
+                    )
                 }
                 "DuplicateRequestException" => {
-                    return RusotoError::Service(BulkPublishError::DuplicateRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BulkPublishError::DuplicateRequest,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(BulkPublishError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BulkPublishError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(BulkPublishError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BulkPublishError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(BulkPublishError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BulkPublishError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(BulkPublishError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BulkPublishError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -771,24 +802,54 @@ impl DeleteDatasetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteDatasetError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDatasetError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteDatasetError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDatasetError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DeleteDatasetError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDatasetError::NotAuthorized,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(DeleteDatasetError::ResourceConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDatasetError::ResourceConflict,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDatasetError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDatasetError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteDatasetError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDatasetError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -829,21 +890,46 @@ impl DescribeDatasetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeDatasetError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDatasetError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeDatasetError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDatasetError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeDatasetError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDatasetError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDatasetError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDatasetError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeDatasetError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDatasetError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -883,31 +969,46 @@ impl DescribeIdentityPoolUsageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeIdentityPoolUsageError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeIdentityPoolUsageError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeIdentityPoolUsageError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeIdentityPoolUsageError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeIdentityPoolUsageError::NotAuthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeIdentityPoolUsageError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeIdentityPoolUsageError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeIdentityPoolUsageError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeIdentityPoolUsageError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeIdentityPoolUsageError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -947,27 +1048,46 @@ impl DescribeIdentityUsageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeIdentityUsageError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeIdentityUsageError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeIdentityUsageError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeIdentityUsageError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeIdentityUsageError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeIdentityUsageError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeIdentityUsageError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeIdentityUsageError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeIdentityUsageError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeIdentityUsageError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1005,22 +1125,38 @@ impl GetBulkPublishDetailsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetBulkPublishDetailsError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBulkPublishDetailsError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetBulkPublishDetailsError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetBulkPublishDetailsError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetBulkPublishDetailsError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBulkPublishDetailsError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetBulkPublishDetailsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetBulkPublishDetailsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1059,21 +1195,46 @@ impl GetCognitoEventsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetCognitoEventsError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCognitoEventsError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetCognitoEventsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCognitoEventsError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetCognitoEventsError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCognitoEventsError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetCognitoEventsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCognitoEventsError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetCognitoEventsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCognitoEventsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1115,31 +1276,46 @@ impl GetIdentityPoolConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetIdentityPoolConfigurationError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetIdentityPoolConfigurationError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        GetIdentityPoolConfigurationError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIdentityPoolConfigurationError::InvalidParameter,
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetIdentityPoolConfigurationError::NotAuthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetIdentityPoolConfigurationError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        GetIdentityPoolConfigurationError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIdentityPoolConfigurationError::ResourceNotFound,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        GetIdentityPoolConfigurationError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIdentityPoolConfigurationError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1181,18 +1357,38 @@ impl ListDatasetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListDatasetsError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDatasetsError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListDatasetsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDatasetsError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListDatasetsError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDatasetsError::NotAuthorized,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListDatasetsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDatasetsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1229,22 +1425,38 @@ impl ListIdentityPoolUsageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListIdentityPoolUsageError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIdentityPoolUsageError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListIdentityPoolUsageError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListIdentityPoolUsageError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListIdentityPoolUsageError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIdentityPoolUsageError::NotAuthorized,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListIdentityPoolUsageError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListIdentityPoolUsageError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1281,18 +1493,38 @@ impl ListRecordsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListRecordsError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRecordsError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListRecordsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRecordsError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListRecordsError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRecordsError::NotAuthorized,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListRecordsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRecordsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1333,24 +1565,54 @@ impl RegisterDeviceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(RegisterDeviceError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterDeviceError::InternalError,
+                    )
                 }
                 "InvalidConfigurationException" => {
-                    return RusotoError::Service(RegisterDeviceError::InvalidConfiguration(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterDeviceError::InvalidConfiguration,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RegisterDeviceError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterDeviceError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(RegisterDeviceError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterDeviceError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RegisterDeviceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterDeviceError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(RegisterDeviceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterDeviceError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1391,21 +1653,46 @@ impl SetCognitoEventsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(SetCognitoEventsError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetCognitoEventsError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SetCognitoEventsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetCognitoEventsError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(SetCognitoEventsError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetCognitoEventsError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SetCognitoEventsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetCognitoEventsError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(SetCognitoEventsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetCognitoEventsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1449,36 +1736,54 @@ impl SetIdentityPoolConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        SetIdentityPoolConfigurationError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetIdentityPoolConfigurationError::ConcurrentModification,
                     )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(SetIdentityPoolConfigurationError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetIdentityPoolConfigurationError::InternalError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        SetIdentityPoolConfigurationError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetIdentityPoolConfigurationError::InvalidParameter,
                     )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(SetIdentityPoolConfigurationError::NotAuthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetIdentityPoolConfigurationError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        SetIdentityPoolConfigurationError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetIdentityPoolConfigurationError::ResourceNotFound,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        SetIdentityPoolConfigurationError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetIdentityPoolConfigurationError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1527,26 +1832,54 @@ impl SubscribeToDatasetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(SubscribeToDatasetError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SubscribeToDatasetError::InternalError,
+                    )
                 }
                 "InvalidConfigurationException" => {
-                    return RusotoError::Service(SubscribeToDatasetError::InvalidConfiguration(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SubscribeToDatasetError::InvalidConfiguration,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SubscribeToDatasetError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SubscribeToDatasetError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(SubscribeToDatasetError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SubscribeToDatasetError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SubscribeToDatasetError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SubscribeToDatasetError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(SubscribeToDatasetError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SubscribeToDatasetError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1589,36 +1922,54 @@ impl UnsubscribeFromDatasetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(UnsubscribeFromDatasetError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UnsubscribeFromDatasetError::InternalError,
+                    )
                 }
                 "InvalidConfigurationException" => {
-                    return RusotoError::Service(UnsubscribeFromDatasetError::InvalidConfiguration(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UnsubscribeFromDatasetError::InvalidConfiguration,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UnsubscribeFromDatasetError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UnsubscribeFromDatasetError::InvalidParameter,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UnsubscribeFromDatasetError::NotAuthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UnsubscribeFromDatasetError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UnsubscribeFromDatasetError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UnsubscribeFromDatasetError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UnsubscribeFromDatasetError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UnsubscribeFromDatasetError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1667,35 +2018,78 @@ impl UpdateRecordsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(UpdateRecordsError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRecordsError::InternalError,
+                    )
                 }
                 "InvalidLambdaFunctionOutputException" => {
-                    return RusotoError::Service(UpdateRecordsError::InvalidLambdaFunctionOutput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRecordsError::InvalidLambdaFunctionOutput,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateRecordsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRecordsError::InvalidParameter,
+                    )
                 }
                 "LambdaThrottledException" => {
-                    return RusotoError::Service(UpdateRecordsError::LambdaThrottled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRecordsError::LambdaThrottled,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateRecordsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRecordsError::LimitExceeded,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UpdateRecordsError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRecordsError::NotAuthorized,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(UpdateRecordsError::ResourceConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRecordsError::ResourceConflict,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateRecordsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRecordsError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateRecordsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRecordsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1834,11 +2228,12 @@ pub struct CognitoSyncClient {
 impl CognitoSyncClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> CognitoSyncClient {
         CognitoSyncClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Cognito Sync"),
         }
     }
 
@@ -1853,12 +2248,32 @@ impl CognitoSyncClient {
     {
         CognitoSyncClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Cognito Sync"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CognitoSyncClient {
-        CognitoSyncClient { client, region }
+        CognitoSyncClient {
+            client,
+            region: region_for_service(region, "Cognito Sync"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> CognitoSyncClient {
+        CognitoSyncClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Cognito Sync"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CognitoSyncClient {
+        CognitoSyncClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -1876,6 +2291,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("BulkPublish");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1909,6 +2325,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("DeleteDataset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1942,6 +2359,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("DescribeDataset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1974,6 +2392,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("DescribeIdentityPoolUsage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2006,6 +2425,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("DescribeIdentityUsage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2037,6 +2457,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("GetBulkPublishDetails");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2068,6 +2489,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("GetCognitoEvents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2100,6 +2522,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("GetIdentityPoolConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2132,6 +2555,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("ListDatasets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2169,6 +2593,7 @@ impl CognitoSync for CognitoSyncClient {
         let request_uri = "/identitypools";
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("ListIdentityPoolUsage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2206,6 +2631,7 @@ impl CognitoSync for CognitoSyncClient {
         let request_uri = format!("/identitypools/{identity_pool_id}/identities/{identity_id}/datasets/{dataset_name}/records", dataset_name = input.dataset_name, identity_id = input.identity_id, identity_pool_id = input.identity_pool_id);
 
         let mut request = SignedRequest::new("GET", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("ListRecords");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2253,6 +2679,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("RegisterDevice");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2287,6 +2714,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("SetCognitoEvents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2321,6 +2749,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("SetIdentityPoolConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2352,6 +2781,7 @@ impl CognitoSync for CognitoSyncClient {
         let request_uri = format!("/identitypools/{identity_pool_id}/identities/{identity_id}/datasets/{dataset_name}/subscriptions/{device_id}", dataset_name = input.dataset_name, device_id = input.device_id, identity_id = input.identity_id, identity_pool_id = input.identity_pool_id);
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("SubscribeToDataset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2380,6 +2810,7 @@ impl CognitoSync for CognitoSyncClient {
         let request_uri = format!("/identitypools/{identity_pool_id}/identities/{identity_id}/datasets/{dataset_name}/subscriptions/{device_id}", dataset_name = input.dataset_name, device_id = input.device_id, identity_id = input.identity_id, identity_pool_id = input.identity_pool_id);
 
         let mut request = SignedRequest::new("DELETE", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("UnsubscribeFromDataset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2413,6 +2844,7 @@ impl CognitoSync for CognitoSyncClient {
         );
 
         let mut request = SignedRequest::new("POST", "cognito-sync", &self.region, &request_uri);
+        request.set_operation("UpdateRecords");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2436,3 +2868,225 @@ impl CognitoSync for CognitoSyncClient {
         }
     }
 }
+
+/// A blocking client for the Amazon Cognito Sync API, running the calls of a `CognitoSyncClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `CognitoSyncClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CognitoSyncBlockingClient {
+    client: CognitoSyncClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CognitoSyncBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> CognitoSyncBlockingClient {
+        CognitoSyncBlockingClient::from_client(CognitoSyncClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CognitoSyncBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CognitoSyncBlockingClient::from_client(CognitoSyncClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> CognitoSyncBlockingClient {
+        CognitoSyncBlockingClient::from_client(CognitoSyncClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> CognitoSyncBlockingClient {
+        CognitoSyncBlockingClient::from_client(CognitoSyncClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: CognitoSyncClient) -> CognitoSyncBlockingClient {
+        CognitoSyncBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> CognitoSyncBlockingClient {
+        CognitoSyncBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CognitoSyncBlockingClient {
+        CognitoSyncBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`CognitoSync::bulk_publish`].
+    pub fn bulk_publish(
+        &self,
+        input: BulkPublishRequest,
+    ) -> Result<BulkPublishResponse, RusotoError<BulkPublishError>> {
+        self.runtime.block_on(self.client.bulk_publish(input))
+    }
+
+    /// Blocking version of [`CognitoSync::delete_dataset`].
+    pub fn delete_dataset(
+        &self,
+        input: DeleteDatasetRequest,
+    ) -> Result<DeleteDatasetResponse, RusotoError<DeleteDatasetError>> {
+        self.runtime.block_on(self.client.delete_dataset(input))
+    }
+
+    /// Blocking version of [`CognitoSync::describe_dataset`].
+    pub fn describe_dataset(
+        &self,
+        input: DescribeDatasetRequest,
+    ) -> Result<DescribeDatasetResponse, RusotoError<DescribeDatasetError>> {
+        self.runtime.block_on(self.client.describe_dataset(input))
+    }
+
+    /// Blocking version of [`CognitoSync::describe_identity_pool_usage`].
+    pub fn describe_identity_pool_usage(
+        &self,
+        input: DescribeIdentityPoolUsageRequest,
+    ) -> Result<DescribeIdentityPoolUsageResponse, RusotoError<DescribeIdentityPoolUsageError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_identity_pool_usage(input))
+    }
+
+    /// Blocking version of [`CognitoSync::describe_identity_usage`].
+    pub fn describe_identity_usage(
+        &self,
+        input: DescribeIdentityUsageRequest,
+    ) -> Result<DescribeIdentityUsageResponse, RusotoError<DescribeIdentityUsageError>> {
+        self.runtime
+            .block_on(self.client.describe_identity_usage(input))
+    }
+
+    /// Blocking version of [`CognitoSync::get_bulk_publish_details`].
+    pub fn get_bulk_publish_details(
+        &self,
+        input: GetBulkPublishDetailsRequest,
+    ) -> Result<GetBulkPublishDetailsResponse, RusotoError<GetBulkPublishDetailsError>> {
+        self.runtime
+            .block_on(self.client.get_bulk_publish_details(input))
+    }
+
+    /// Blocking version of [`CognitoSync::get_cognito_events`].
+    pub fn get_cognito_events(
+        &self,
+        input: GetCognitoEventsRequest,
+    ) -> Result<GetCognitoEventsResponse, RusotoError<GetCognitoEventsError>> {
+        self.runtime.block_on(self.client.get_cognito_events(input))
+    }
+
+    /// Blocking version of [`CognitoSync::get_identity_pool_configuration`].
+    pub fn get_identity_pool_configuration(
+        &self,
+        input: GetIdentityPoolConfigurationRequest,
+    ) -> Result<GetIdentityPoolConfigurationResponse, RusotoError<GetIdentityPoolConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.get_identity_pool_configuration(input))
+    }
+
+    /// Blocking version of [`CognitoSync::list_datasets`].
+    pub fn list_datasets(
+        &self,
+        input: ListDatasetsRequest,
+    ) -> Result<ListDatasetsResponse, RusotoError<ListDatasetsError>> {
+        self.runtime.block_on(self.client.list_datasets(input))
+    }
+
+    /// Blocking version of [`CognitoSync::list_identity_pool_usage`].
+    pub fn list_identity_pool_usage(
+        &self,
+        input: ListIdentityPoolUsageRequest,
+    ) -> Result<ListIdentityPoolUsageResponse, RusotoError<ListIdentityPoolUsageError>> {
+        self.runtime
+            .block_on(self.client.list_identity_pool_usage(input))
+    }
+
+    /// Blocking version of [`CognitoSync::list_records`].
+    pub fn list_records(
+        &self,
+        input: ListRecordsRequest,
+    ) -> Result<ListRecordsResponse, RusotoError<ListRecordsError>> {
+        self.runtime.block_on(self.client.list_records(input))
+    }
+
+    /// Blocking version of [`CognitoSync::register_device`].
+    pub fn register_device(
+        &self,
+        input: RegisterDeviceRequest,
+    ) -> Result<RegisterDeviceResponse, RusotoError<RegisterDeviceError>> {
+        self.runtime.block_on(self.client.register_device(input))
+    }
+
+    /// Blocking version of [`CognitoSync::set_cognito_events`].
+    pub fn set_cognito_events(
+        &self,
+        input: SetCognitoEventsRequest,
+    ) -> Result<(), RusotoError<SetCognitoEventsError>> {
+        self.runtime.block_on(self.client.set_cognito_events(input))
+    }
+
+    /// Blocking version of [`CognitoSync::set_identity_pool_configuration`].
+    pub fn set_identity_pool_configuration(
+        &self,
+        input: SetIdentityPoolConfigurationRequest,
+    ) -> Result<SetIdentityPoolConfigurationResponse, RusotoError<SetIdentityPoolConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.set_identity_pool_configuration(input))
+    }
+
+    /// Blocking version of [`CognitoSync::subscribe_to_dataset`].
+    pub fn subscribe_to_dataset(
+        &self,
+        input: SubscribeToDatasetRequest,
+    ) -> Result<SubscribeToDatasetResponse, RusotoError<SubscribeToDatasetError>> {
+        self.runtime
+            .block_on(self.client.subscribe_to_dataset(input))
+    }
+
+    /// Blocking version of [`CognitoSync::unsubscribe_from_dataset`].
+    pub fn unsubscribe_from_dataset(
+        &self,
+        input: UnsubscribeFromDatasetRequest,
+    ) -> Result<UnsubscribeFromDatasetResponse, RusotoError<UnsubscribeFromDatasetError>> {
+        self.runtime
+            .block_on(self.client.unsubscribe_from_dataset(input))
+    }
+
+    /// Blocking version of [`CognitoSync::update_records`].
+    pub fn update_records(
+        &self,
+        input: UpdateRecordsRequest,
+    ) -> Result<UpdateRecordsResponse, RusotoError<UpdateRecordsError>> {
+        self.runtime.block_on(self.client.update_records(input))
+    }
+}
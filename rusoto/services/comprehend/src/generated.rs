@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -2992,26 +2993,38 @@ impl BatchDetectDominantLanguageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BatchSizeLimitExceededException" => {
-                    return RusotoError::Service(
-                        BatchDetectDominantLanguageError::BatchSizeLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDetectDominantLanguageError::BatchSizeLimitExceeded,
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(BatchDetectDominantLanguageError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDetectDominantLanguageError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(BatchDetectDominantLanguageError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDetectDominantLanguageError::InvalidRequest,
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(
-                        BatchDetectDominantLanguageError::TextSizeLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDetectDominantLanguageError::TextSizeLimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3054,27 +3067,46 @@ impl BatchDetectEntitiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BatchSizeLimitExceededException" => {
-                    return RusotoError::Service(BatchDetectEntitiesError::BatchSizeLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDetectEntitiesError::BatchSizeLimitExceeded,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(BatchDetectEntitiesError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDetectEntitiesError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(BatchDetectEntitiesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDetectEntitiesError::InvalidRequest,
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(BatchDetectEntitiesError::TextSizeLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDetectEntitiesError::TextSizeLimitExceeded,
+                    )
                 }
                 "UnsupportedLanguageException" => {
-                    return RusotoError::Service(BatchDetectEntitiesError::UnsupportedLanguage(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDetectEntitiesError::UnsupportedLanguage,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3114,31 +3146,46 @@ impl BatchDetectKeyPhrasesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BatchSizeLimitExceededException" => {
-                    return RusotoError::Service(
-                        BatchDetectKeyPhrasesError::BatchSizeLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDetectKeyPhrasesError::BatchSizeLimitExceeded,
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(BatchDetectKeyPhrasesError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDetectKeyPhrasesError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(BatchDetectKeyPhrasesError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDetectKeyPhrasesError::InvalidRequest,
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(BatchDetectKeyPhrasesError::TextSizeLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDetectKeyPhrasesError::TextSizeLimitExceeded,
+                    )
                 }
                 "UnsupportedLanguageException" => {
-                    return RusotoError::Service(BatchDetectKeyPhrasesError::UnsupportedLanguage(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDetectKeyPhrasesError::UnsupportedLanguage,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3178,27 +3225,46 @@ impl BatchDetectSentimentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BatchSizeLimitExceededException" => {
-                    return RusotoError::Service(BatchDetectSentimentError::BatchSizeLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDetectSentimentError::BatchSizeLimitExceeded,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(BatchDetectSentimentError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDetectSentimentError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(BatchDetectSentimentError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDetectSentimentError::InvalidRequest,
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(BatchDetectSentimentError::TextSizeLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDetectSentimentError::TextSizeLimitExceeded,
+                    )
                 }
                 "UnsupportedLanguageException" => {
-                    return RusotoError::Service(BatchDetectSentimentError::UnsupportedLanguage(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDetectSentimentError::UnsupportedLanguage,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3238,27 +3304,46 @@ impl BatchDetectSyntaxError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BatchSizeLimitExceededException" => {
-                    return RusotoError::Service(BatchDetectSyntaxError::BatchSizeLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDetectSyntaxError::BatchSizeLimitExceeded,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(BatchDetectSyntaxError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDetectSyntaxError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(BatchDetectSyntaxError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDetectSyntaxError::InvalidRequest,
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(BatchDetectSyntaxError::TextSizeLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDetectSyntaxError::TextSizeLimitExceeded,
+                    )
                 }
                 "UnsupportedLanguageException" => {
-                    return RusotoError::Service(BatchDetectSyntaxError::UnsupportedLanguage(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDetectSyntaxError::UnsupportedLanguage,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3296,22 +3381,38 @@ impl ClassifyDocumentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ClassifyDocumentError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ClassifyDocumentError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ClassifyDocumentError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ClassifyDocumentError::InvalidRequest,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(ClassifyDocumentError::ResourceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ClassifyDocumentError::ResourceUnavailable,
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(ClassifyDocumentError::TextSizeLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ClassifyDocumentError::TextSizeLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3356,46 +3457,70 @@ impl CreateDocumentClassifierError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateDocumentClassifierError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDocumentClassifierError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateDocumentClassifierError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDocumentClassifierError::InvalidRequest,
+                    )
                 }
                 "KmsKeyValidationException" => {
-                    return RusotoError::Service(CreateDocumentClassifierError::KmsKeyValidation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDocumentClassifierError::KmsKeyValidation,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateDocumentClassifierError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDocumentClassifierError::ResourceInUse,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
-                        CreateDocumentClassifierError::ResourceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDocumentClassifierError::ResourceLimitExceeded,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateDocumentClassifierError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDocumentClassifierError::TooManyRequests,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreateDocumentClassifierError::TooManyTags(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDocumentClassifierError::TooManyTags,
+                    )
                 }
                 "UnsupportedLanguageException" => {
-                    return RusotoError::Service(
-                        CreateDocumentClassifierError::UnsupportedLanguage(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDocumentClassifierError::UnsupportedLanguage,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3446,32 +3571,70 @@ impl CreateEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateEndpointError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEndpointError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateEndpointError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEndpointError::InvalidRequest,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateEndpointError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEndpointError::ResourceInUse,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateEndpointError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateEndpointError::ResourceLimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateEndpointError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEndpointError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(CreateEndpointError::ResourceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEndpointError::ResourceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateEndpointError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEndpointError::TooManyRequests,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreateEndpointError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEndpointError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3520,44 +3683,70 @@ impl CreateEntityRecognizerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateEntityRecognizerError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateEntityRecognizerError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateEntityRecognizerError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateEntityRecognizerError::InvalidRequest,
+                    )
                 }
                 "KmsKeyValidationException" => {
-                    return RusotoError::Service(CreateEntityRecognizerError::KmsKeyValidation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateEntityRecognizerError::KmsKeyValidation,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateEntityRecognizerError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateEntityRecognizerError::ResourceInUse,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
-                        CreateEntityRecognizerError::ResourceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEntityRecognizerError::ResourceLimitExceeded,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateEntityRecognizerError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateEntityRecognizerError::TooManyRequests,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreateEntityRecognizerError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEntityRecognizerError::TooManyTags,
+                    )
                 }
                 "UnsupportedLanguageException" => {
-                    return RusotoError::Service(CreateEntityRecognizerError::UnsupportedLanguage(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateEntityRecognizerError::UnsupportedLanguage,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3602,36 +3791,54 @@ impl DeleteDocumentClassifierError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteDocumentClassifierError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDocumentClassifierError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteDocumentClassifierError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDocumentClassifierError::InvalidRequest,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteDocumentClassifierError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDocumentClassifierError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDocumentClassifierError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDocumentClassifierError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(
-                        DeleteDocumentClassifierError::ResourceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDocumentClassifierError::ResourceUnavailable,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteDocumentClassifierError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDocumentClassifierError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3672,21 +3879,46 @@ impl DeleteEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteEndpointError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEndpointError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteEndpointError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEndpointError::InvalidRequest,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteEndpointError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEndpointError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteEndpointError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEndpointError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteEndpointError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEndpointError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3728,36 +3960,54 @@ impl DeleteEntityRecognizerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteEntityRecognizerError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteEntityRecognizerError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteEntityRecognizerError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteEntityRecognizerError::InvalidRequest,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteEntityRecognizerError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteEntityRecognizerError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteEntityRecognizerError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteEntityRecognizerError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(DeleteEntityRecognizerError::ResourceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteEntityRecognizerError::ResourceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteEntityRecognizerError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteEntityRecognizerError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3798,26 +4048,38 @@ impl DescribeDocumentClassificationJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
-                        DescribeDocumentClassificationJobError::InternalServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDocumentClassificationJobError::InternalServer,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        DescribeDocumentClassificationJobError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDocumentClassificationJobError::InvalidRequest,
                     )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeDocumentClassificationJobError::JobNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDocumentClassificationJobError::JobNotFound,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        DescribeDocumentClassificationJobError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDocumentClassificationJobError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3864,26 +4126,38 @@ impl DescribeDocumentClassifierError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeDocumentClassifierError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDocumentClassifierError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeDocumentClassifierError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDocumentClassifierError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDocumentClassifierError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDocumentClassifierError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeDocumentClassifierError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDocumentClassifierError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3922,26 +4196,38 @@ impl DescribeDominantLanguageDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
-                        DescribeDominantLanguageDetectionJobError::InternalServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDominantLanguageDetectionJobError::InternalServer,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        DescribeDominantLanguageDetectionJobError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDominantLanguageDetectionJobError::InvalidRequest,
                     )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeDominantLanguageDetectionJobError::JobNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDominantLanguageDetectionJobError::JobNotFound,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        DescribeDominantLanguageDetectionJobError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDominantLanguageDetectionJobError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3986,18 +4272,38 @@ impl DescribeEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeEndpointError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEndpointError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeEndpointError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEndpointError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeEndpointError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEndpointError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeEndpointError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEndpointError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4036,26 +4342,38 @@ impl DescribeEntitiesDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeEntitiesDetectionJobError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEntitiesDetectionJobError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeEntitiesDetectionJobError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEntitiesDetectionJobError::InvalidRequest,
+                    )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(DescribeEntitiesDetectionJobError::JobNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEntitiesDetectionJobError::JobNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        DescribeEntitiesDetectionJobError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEntitiesDetectionJobError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4092,26 +4410,38 @@ impl DescribeEntityRecognizerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeEntityRecognizerError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEntityRecognizerError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeEntityRecognizerError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEntityRecognizerError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeEntityRecognizerError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEntityRecognizerError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeEntityRecognizerError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEntityRecognizerError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4150,26 +4480,38 @@ impl DescribeEventsDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeEventsDetectionJobError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEventsDetectionJobError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeEventsDetectionJobError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEventsDetectionJobError::InvalidRequest,
+                    )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(DescribeEventsDetectionJobError::JobNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEventsDetectionJobError::JobNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeEventsDetectionJobError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEventsDetectionJobError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4208,26 +4550,38 @@ impl DescribeKeyPhrasesDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
-                        DescribeKeyPhrasesDetectionJobError::InternalServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeKeyPhrasesDetectionJobError::InternalServer,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        DescribeKeyPhrasesDetectionJobError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeKeyPhrasesDetectionJobError::InvalidRequest,
                     )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(DescribeKeyPhrasesDetectionJobError::JobNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeKeyPhrasesDetectionJobError::JobNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        DescribeKeyPhrasesDetectionJobError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeKeyPhrasesDetectionJobError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4272,26 +4626,38 @@ impl DescribePiiEntitiesDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
-                        DescribePiiEntitiesDetectionJobError::InternalServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePiiEntitiesDetectionJobError::InternalServer,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        DescribePiiEntitiesDetectionJobError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePiiEntitiesDetectionJobError::InvalidRequest,
                     )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(DescribePiiEntitiesDetectionJobError::JobNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribePiiEntitiesDetectionJobError::JobNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        DescribePiiEntitiesDetectionJobError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePiiEntitiesDetectionJobError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4336,26 +4702,38 @@ impl DescribeSentimentDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
-                        DescribeSentimentDetectionJobError::InternalServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSentimentDetectionJobError::InternalServer,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        DescribeSentimentDetectionJobError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSentimentDetectionJobError::InvalidRequest,
                     )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(DescribeSentimentDetectionJobError::JobNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeSentimentDetectionJobError::JobNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        DescribeSentimentDetectionJobError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSentimentDetectionJobError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4396,26 +4774,38 @@ impl DescribeTopicsDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeTopicsDetectionJobError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeTopicsDetectionJobError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeTopicsDetectionJobError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeTopicsDetectionJobError::InvalidRequest,
+                    )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(DescribeTopicsDetectionJobError::JobNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeTopicsDetectionJobError::JobNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeTopicsDetectionJobError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeTopicsDetectionJobError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4450,21 +4840,30 @@ impl DetectDominantLanguageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DetectDominantLanguageError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetectDominantLanguageError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DetectDominantLanguageError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetectDominantLanguageError::InvalidRequest,
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(
-                        DetectDominantLanguageError::TextSizeLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectDominantLanguageError::TextSizeLimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4502,23 +4901,46 @@ impl DetectEntitiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DetectEntitiesError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectEntitiesError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DetectEntitiesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectEntitiesError::InvalidRequest,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(DetectEntitiesError::ResourceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectEntitiesError::ResourceUnavailable,
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(DetectEntitiesError::TextSizeLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetectEntitiesError::TextSizeLimitExceeded,
+                    )
                 }
                 "UnsupportedLanguageException" => {
-                    return RusotoError::Service(DetectEntitiesError::UnsupportedLanguage(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectEntitiesError::UnsupportedLanguage,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4556,22 +4978,38 @@ impl DetectKeyPhrasesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DetectKeyPhrasesError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectKeyPhrasesError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DetectKeyPhrasesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectKeyPhrasesError::InvalidRequest,
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(DetectKeyPhrasesError::TextSizeLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetectKeyPhrasesError::TextSizeLimitExceeded,
+                    )
                 }
                 "UnsupportedLanguageException" => {
-                    return RusotoError::Service(DetectKeyPhrasesError::UnsupportedLanguage(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetectKeyPhrasesError::UnsupportedLanguage,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4608,22 +5046,38 @@ impl DetectPiiEntitiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DetectPiiEntitiesError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectPiiEntitiesError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DetectPiiEntitiesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectPiiEntitiesError::InvalidRequest,
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(DetectPiiEntitiesError::TextSizeLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetectPiiEntitiesError::TextSizeLimitExceeded,
+                    )
                 }
                 "UnsupportedLanguageException" => {
-                    return RusotoError::Service(DetectPiiEntitiesError::UnsupportedLanguage(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetectPiiEntitiesError::UnsupportedLanguage,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4660,20 +5114,38 @@ impl DetectSentimentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DetectSentimentError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectSentimentError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DetectSentimentError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectSentimentError::InvalidRequest,
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(DetectSentimentError::TextSizeLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetectSentimentError::TextSizeLimitExceeded,
+                    )
                 }
                 "UnsupportedLanguageException" => {
-                    return RusotoError::Service(DetectSentimentError::UnsupportedLanguage(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectSentimentError::UnsupportedLanguage,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4710,18 +5182,38 @@ impl DetectSyntaxError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DetectSyntaxError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectSyntaxError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DetectSyntaxError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectSyntaxError::InvalidRequest,
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(DetectSyntaxError::TextSizeLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectSyntaxError::TextSizeLimitExceeded,
+                    )
                 }
                 "UnsupportedLanguageException" => {
-                    return RusotoError::Service(DetectSyntaxError::UnsupportedLanguage(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectSyntaxError::UnsupportedLanguage,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4760,26 +5252,38 @@ impl ListDocumentClassificationJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
-                        ListDocumentClassificationJobsError::InternalServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDocumentClassificationJobsError::InternalServer,
                     )
                 }
                 "InvalidFilterException" => {
-                    return RusotoError::Service(
-                        ListDocumentClassificationJobsError::InvalidFilter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDocumentClassificationJobsError::InvalidFilter,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        ListDocumentClassificationJobsError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDocumentClassificationJobsError::InvalidRequest,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        ListDocumentClassificationJobsError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDocumentClassificationJobsError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4822,26 +5326,38 @@ impl ListDocumentClassifiersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListDocumentClassifiersError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDocumentClassifiersError::InternalServer,
+                    )
                 }
                 "InvalidFilterException" => {
-                    return RusotoError::Service(ListDocumentClassifiersError::InvalidFilter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDocumentClassifiersError::InvalidFilter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListDocumentClassifiersError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDocumentClassifiersError::InvalidRequest,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListDocumentClassifiersError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDocumentClassifiersError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4880,26 +5396,38 @@ impl ListDominantLanguageDetectionJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
-                        ListDominantLanguageDetectionJobsError::InternalServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDominantLanguageDetectionJobsError::InternalServer,
                     )
                 }
                 "InvalidFilterException" => {
-                    return RusotoError::Service(
-                        ListDominantLanguageDetectionJobsError::InvalidFilter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDominantLanguageDetectionJobsError::InvalidFilter,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        ListDominantLanguageDetectionJobsError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDominantLanguageDetectionJobsError::InvalidRequest,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        ListDominantLanguageDetectionJobsError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDominantLanguageDetectionJobsError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4942,15 +5470,30 @@ impl ListEndpointsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListEndpointsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListEndpointsError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListEndpointsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListEndpointsError::InvalidRequest,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListEndpointsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListEndpointsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4986,26 +5529,38 @@ impl ListEntitiesDetectionJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListEntitiesDetectionJobsError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListEntitiesDetectionJobsError::InternalServer,
+                    )
                 }
                 "InvalidFilterException" => {
-                    return RusotoError::Service(ListEntitiesDetectionJobsError::InvalidFilter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListEntitiesDetectionJobsError::InvalidFilter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListEntitiesDetectionJobsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListEntitiesDetectionJobsError::InvalidRequest,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListEntitiesDetectionJobsError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListEntitiesDetectionJobsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5042,24 +5597,38 @@ impl ListEntityRecognizersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListEntityRecognizersError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListEntityRecognizersError::InternalServer,
+                    )
                 }
                 "InvalidFilterException" => {
-                    return RusotoError::Service(ListEntityRecognizersError::InvalidFilter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListEntityRecognizersError::InvalidFilter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListEntityRecognizersError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListEntityRecognizersError::InvalidRequest,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListEntityRecognizersError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListEntityRecognizersError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5096,26 +5665,38 @@ impl ListEventsDetectionJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListEventsDetectionJobsError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListEventsDetectionJobsError::InternalServer,
+                    )
                 }
                 "InvalidFilterException" => {
-                    return RusotoError::Service(ListEventsDetectionJobsError::InvalidFilter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListEventsDetectionJobsError::InvalidFilter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListEventsDetectionJobsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListEventsDetectionJobsError::InvalidRequest,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListEventsDetectionJobsError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListEventsDetectionJobsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5154,26 +5735,38 @@ impl ListKeyPhrasesDetectionJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListKeyPhrasesDetectionJobsError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListKeyPhrasesDetectionJobsError::InternalServer,
+                    )
                 }
                 "InvalidFilterException" => {
-                    return RusotoError::Service(ListKeyPhrasesDetectionJobsError::InvalidFilter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListKeyPhrasesDetectionJobsError::InvalidFilter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListKeyPhrasesDetectionJobsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListKeyPhrasesDetectionJobsError::InvalidRequest,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListKeyPhrasesDetectionJobsError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListKeyPhrasesDetectionJobsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5212,26 +5805,38 @@ impl ListPiiEntitiesDetectionJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListPiiEntitiesDetectionJobsError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPiiEntitiesDetectionJobsError::InternalServer,
+                    )
                 }
                 "InvalidFilterException" => {
-                    return RusotoError::Service(ListPiiEntitiesDetectionJobsError::InvalidFilter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPiiEntitiesDetectionJobsError::InvalidFilter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListPiiEntitiesDetectionJobsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPiiEntitiesDetectionJobsError::InvalidRequest,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        ListPiiEntitiesDetectionJobsError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPiiEntitiesDetectionJobsError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5270,26 +5875,38 @@ impl ListSentimentDetectionJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListSentimentDetectionJobsError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSentimentDetectionJobsError::InternalServer,
+                    )
                 }
                 "InvalidFilterException" => {
-                    return RusotoError::Service(ListSentimentDetectionJobsError::InvalidFilter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSentimentDetectionJobsError::InvalidFilter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListSentimentDetectionJobsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSentimentDetectionJobsError::InvalidRequest,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListSentimentDetectionJobsError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSentimentDetectionJobsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5324,17 +5941,30 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5370,26 +6000,38 @@ impl ListTopicsDetectionJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListTopicsDetectionJobsError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTopicsDetectionJobsError::InternalServer,
+                    )
                 }
                 "InvalidFilterException" => {
-                    return RusotoError::Service(ListTopicsDetectionJobsError::InvalidFilter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTopicsDetectionJobsError::InvalidFilter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTopicsDetectionJobsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTopicsDetectionJobsError::InvalidRequest,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListTopicsDetectionJobsError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTopicsDetectionJobsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5432,36 +6074,54 @@ impl StartDocumentClassificationJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
-                        StartDocumentClassificationJobError::InternalServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDocumentClassificationJobError::InternalServer,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        StartDocumentClassificationJobError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDocumentClassificationJobError::InvalidRequest,
                     )
                 }
                 "KmsKeyValidationException" => {
-                    return RusotoError::Service(
-                        StartDocumentClassificationJobError::KmsKeyValidation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDocumentClassificationJobError::KmsKeyValidation,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        StartDocumentClassificationJobError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDocumentClassificationJobError::ResourceNotFound,
                     )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(
-                        StartDocumentClassificationJobError::ResourceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDocumentClassificationJobError::ResourceUnavailable,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        StartDocumentClassificationJobError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDocumentClassificationJobError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5514,26 +6174,38 @@ impl StartDominantLanguageDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
-                        StartDominantLanguageDetectionJobError::InternalServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDominantLanguageDetectionJobError::InternalServer,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        StartDominantLanguageDetectionJobError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDominantLanguageDetectionJobError::InvalidRequest,
                     )
                 }
                 "KmsKeyValidationException" => {
-                    return RusotoError::Service(
-                        StartDominantLanguageDetectionJobError::KmsKeyValidation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDominantLanguageDetectionJobError::KmsKeyValidation,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        StartDominantLanguageDetectionJobError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDominantLanguageDetectionJobError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5582,36 +6254,54 @@ impl StartEntitiesDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StartEntitiesDetectionJobError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartEntitiesDetectionJobError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartEntitiesDetectionJobError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartEntitiesDetectionJobError::InvalidRequest,
+                    )
                 }
                 "KmsKeyValidationException" => {
-                    return RusotoError::Service(StartEntitiesDetectionJobError::KmsKeyValidation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartEntitiesDetectionJobError::KmsKeyValidation,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartEntitiesDetectionJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartEntitiesDetectionJobError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(
-                        StartEntitiesDetectionJobError::ResourceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartEntitiesDetectionJobError::ResourceUnavailable,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartEntitiesDetectionJobError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartEntitiesDetectionJobError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5652,26 +6342,38 @@ impl StartEventsDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StartEventsDetectionJobError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartEventsDetectionJobError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartEventsDetectionJobError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartEventsDetectionJobError::InvalidRequest,
+                    )
                 }
                 "KmsKeyValidationException" => {
-                    return RusotoError::Service(StartEventsDetectionJobError::KmsKeyValidation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartEventsDetectionJobError::KmsKeyValidation,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartEventsDetectionJobError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartEventsDetectionJobError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5710,26 +6412,38 @@ impl StartKeyPhrasesDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StartKeyPhrasesDetectionJobError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartKeyPhrasesDetectionJobError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartKeyPhrasesDetectionJobError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartKeyPhrasesDetectionJobError::InvalidRequest,
+                    )
                 }
                 "KmsKeyValidationException" => {
-                    return RusotoError::Service(
-                        StartKeyPhrasesDetectionJobError::KmsKeyValidation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartKeyPhrasesDetectionJobError::KmsKeyValidation,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartKeyPhrasesDetectionJobError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartKeyPhrasesDetectionJobError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5768,26 +6482,38 @@ impl StartPiiEntitiesDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StartPiiEntitiesDetectionJobError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartPiiEntitiesDetectionJobError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartPiiEntitiesDetectionJobError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartPiiEntitiesDetectionJobError::InvalidRequest,
+                    )
                 }
                 "KmsKeyValidationException" => {
-                    return RusotoError::Service(
-                        StartPiiEntitiesDetectionJobError::KmsKeyValidation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartPiiEntitiesDetectionJobError::KmsKeyValidation,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        StartPiiEntitiesDetectionJobError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartPiiEntitiesDetectionJobError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5828,26 +6554,38 @@ impl StartSentimentDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StartSentimentDetectionJobError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartSentimentDetectionJobError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartSentimentDetectionJobError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartSentimentDetectionJobError::InvalidRequest,
+                    )
                 }
                 "KmsKeyValidationException" => {
-                    return RusotoError::Service(StartSentimentDetectionJobError::KmsKeyValidation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartSentimentDetectionJobError::KmsKeyValidation,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartSentimentDetectionJobError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartSentimentDetectionJobError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5884,26 +6622,38 @@ impl StartTopicsDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StartTopicsDetectionJobError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartTopicsDetectionJobError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartTopicsDetectionJobError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartTopicsDetectionJobError::InvalidRequest,
+                    )
                 }
                 "KmsKeyValidationException" => {
-                    return RusotoError::Service(StartTopicsDetectionJobError::KmsKeyValidation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartTopicsDetectionJobError::KmsKeyValidation,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartTopicsDetectionJobError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartTopicsDetectionJobError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5940,21 +6690,30 @@ impl StopDominantLanguageDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
-                        StopDominantLanguageDetectionJobError::InternalServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopDominantLanguageDetectionJobError::InternalServer,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        StopDominantLanguageDetectionJobError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopDominantLanguageDetectionJobError::InvalidRequest,
                     )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(
-                        StopDominantLanguageDetectionJobError::JobNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopDominantLanguageDetectionJobError::JobNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5992,21 +6751,30 @@ impl StopEntitiesDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StopEntitiesDetectionJobError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopEntitiesDetectionJobError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopEntitiesDetectionJobError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopEntitiesDetectionJobError::InvalidRequest,
+                    )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(StopEntitiesDetectionJobError::JobNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopEntitiesDetectionJobError::JobNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6040,19 +6808,30 @@ impl StopEventsDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StopEventsDetectionJobError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopEventsDetectionJobError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopEventsDetectionJobError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopEventsDetectionJobError::InvalidRequest,
+                    )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(StopEventsDetectionJobError::JobNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopEventsDetectionJobError::JobNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6088,21 +6867,30 @@ impl StopKeyPhrasesDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StopKeyPhrasesDetectionJobError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopKeyPhrasesDetectionJobError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopKeyPhrasesDetectionJobError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopKeyPhrasesDetectionJobError::InvalidRequest,
+                    )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(StopKeyPhrasesDetectionJobError::JobNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopKeyPhrasesDetectionJobError::JobNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6138,21 +6926,30 @@ impl StopPiiEntitiesDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StopPiiEntitiesDetectionJobError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopPiiEntitiesDetectionJobError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopPiiEntitiesDetectionJobError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopPiiEntitiesDetectionJobError::InvalidRequest,
+                    )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(StopPiiEntitiesDetectionJobError::JobNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopPiiEntitiesDetectionJobError::JobNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6186,21 +6983,30 @@ impl StopSentimentDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StopSentimentDetectionJobError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopSentimentDetectionJobError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopSentimentDetectionJobError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopSentimentDetectionJobError::InvalidRequest,
+                    )
                 }
                 "JobNotFoundException" => {
-                    return RusotoError::Service(StopSentimentDetectionJobError::JobNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopSentimentDetectionJobError::JobNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6238,26 +7044,38 @@ impl StopTrainingDocumentClassifierError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
-                        StopTrainingDocumentClassifierError::InternalServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopTrainingDocumentClassifierError::InternalServer,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        StopTrainingDocumentClassifierError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopTrainingDocumentClassifierError::InvalidRequest,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        StopTrainingDocumentClassifierError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopTrainingDocumentClassifierError::ResourceNotFound,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        StopTrainingDocumentClassifierError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopTrainingDocumentClassifierError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6304,26 +7122,38 @@ impl StopTrainingEntityRecognizerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StopTrainingEntityRecognizerError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopTrainingEntityRecognizerError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopTrainingEntityRecognizerError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopTrainingEntityRecognizerError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        StopTrainingEntityRecognizerError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopTrainingEntityRecognizerError::ResourceNotFound,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        StopTrainingEntityRecognizerError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopTrainingEntityRecognizerError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6364,21 +7194,46 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(TagResourceError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ConcurrentModification,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(TagResourceError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6418,23 +7273,46 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UntagResourceError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UntagResourceError::ConcurrentModification,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
                 "TooManyTagKeysException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyTagKeys(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::TooManyTagKeys,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6478,29 +7356,62 @@ impl UpdateEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateEndpointError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateEndpointError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateEndpointError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateEndpointError::InvalidRequest,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateEndpointError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateEndpointError::ResourceInUse,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(UpdateEndpointError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateEndpointError::ResourceLimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateEndpointError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateEndpointError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(UpdateEndpointError::ResourceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateEndpointError::ResourceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateEndpointError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateEndpointError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6928,11 +7839,12 @@ pub struct ComprehendClient {
 impl ComprehendClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> ComprehendClient {
         ComprehendClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Comprehend"),
         }
     }
 
@@ -6947,12 +7859,32 @@ impl ComprehendClient {
     {
         ComprehendClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Comprehend"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ComprehendClient {
-        ComprehendClient { client, region }
+        ComprehendClient {
+            client,
+            region: region_for_service(region, "Comprehend"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> ComprehendClient {
+        ComprehendClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Comprehend"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ComprehendClient {
+        ComprehendClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -6965,6 +7897,7 @@ impl Comprehend for ComprehendClient {
     ) -> Result<BatchDetectDominantLanguageResponse, RusotoError<BatchDetectDominantLanguageError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchDetectDominantLanguage");
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.BatchDetectDominantLanguage",
@@ -6987,6 +7920,7 @@ impl Comprehend for ComprehendClient {
         input: BatchDetectEntitiesRequest,
     ) -> Result<BatchDetectEntitiesResponse, RusotoError<BatchDetectEntitiesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchDetectEntities");
         request.add_header("x-amz-target", "Comprehend_20171127.BatchDetectEntities");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7005,6 +7939,7 @@ impl Comprehend for ComprehendClient {
         input: BatchDetectKeyPhrasesRequest,
     ) -> Result<BatchDetectKeyPhrasesResponse, RusotoError<BatchDetectKeyPhrasesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchDetectKeyPhrases");
         request.add_header("x-amz-target", "Comprehend_20171127.BatchDetectKeyPhrases");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7024,6 +7959,7 @@ impl Comprehend for ComprehendClient {
         input: BatchDetectSentimentRequest,
     ) -> Result<BatchDetectSentimentResponse, RusotoError<BatchDetectSentimentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchDetectSentiment");
         request.add_header("x-amz-target", "Comprehend_20171127.BatchDetectSentiment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7043,6 +7979,7 @@ impl Comprehend for ComprehendClient {
         input: BatchDetectSyntaxRequest,
     ) -> Result<BatchDetectSyntaxResponse, RusotoError<BatchDetectSyntaxError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchDetectSyntax");
         request.add_header("x-amz-target", "Comprehend_20171127.BatchDetectSyntax");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7061,6 +7998,7 @@ impl Comprehend for ComprehendClient {
         input: ClassifyDocumentRequest,
     ) -> Result<ClassifyDocumentResponse, RusotoError<ClassifyDocumentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ClassifyDocument");
         request.add_header("x-amz-target", "Comprehend_20171127.ClassifyDocument");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7079,6 +8017,7 @@ impl Comprehend for ComprehendClient {
         input: CreateDocumentClassifierRequest,
     ) -> Result<CreateDocumentClassifierResponse, RusotoError<CreateDocumentClassifierError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateDocumentClassifier");
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.CreateDocumentClassifier",
@@ -7101,6 +8040,7 @@ impl Comprehend for ComprehendClient {
         input: CreateEndpointRequest,
     ) -> Result<CreateEndpointResponse, RusotoError<CreateEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateEndpoint");
         request.add_header("x-amz-target", "Comprehend_20171127.CreateEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7119,6 +8059,7 @@ impl Comprehend for ComprehendClient {
         input: CreateEntityRecognizerRequest,
     ) -> Result<CreateEntityRecognizerResponse, RusotoError<CreateEntityRecognizerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateEntityRecognizer");
         request.add_header("x-amz-target", "Comprehend_20171127.CreateEntityRecognizer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7138,6 +8079,7 @@ impl Comprehend for ComprehendClient {
         input: DeleteDocumentClassifierRequest,
     ) -> Result<DeleteDocumentClassifierResponse, RusotoError<DeleteDocumentClassifierError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteDocumentClassifier");
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.DeleteDocumentClassifier",
@@ -7160,6 +8102,7 @@ impl Comprehend for ComprehendClient {
         input: DeleteEndpointRequest,
     ) -> Result<DeleteEndpointResponse, RusotoError<DeleteEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteEndpoint");
         request.add_header("x-amz-target", "Comprehend_20171127.DeleteEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7178,6 +8121,7 @@ impl Comprehend for ComprehendClient {
         input: DeleteEntityRecognizerRequest,
     ) -> Result<DeleteEntityRecognizerResponse, RusotoError<DeleteEntityRecognizerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteEntityRecognizer");
         request.add_header("x-amz-target", "Comprehend_20171127.DeleteEntityRecognizer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7200,6 +8144,7 @@ impl Comprehend for ComprehendClient {
         RusotoError<DescribeDocumentClassificationJobError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeDocumentClassificationJob");
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.DescribeDocumentClassificationJob",
@@ -7226,6 +8171,7 @@ impl Comprehend for ComprehendClient {
     ) -> Result<DescribeDocumentClassifierResponse, RusotoError<DescribeDocumentClassifierError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeDocumentClassifier");
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.DescribeDocumentClassifier",
@@ -7251,6 +8197,7 @@ impl Comprehend for ComprehendClient {
         RusotoError<DescribeDominantLanguageDetectionJobError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeDominantLanguageDetectionJob");
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.DescribeDominantLanguageDetectionJob",
@@ -7276,6 +8223,7 @@ impl Comprehend for ComprehendClient {
         input: DescribeEndpointRequest,
     ) -> Result<DescribeEndpointResponse, RusotoError<DescribeEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeEndpoint");
         request.add_header("x-amz-target", "Comprehend_20171127.DescribeEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7295,6 +8243,7 @@ impl Comprehend for ComprehendClient {
     ) -> Result<DescribeEntitiesDetectionJobResponse, RusotoError<DescribeEntitiesDetectionJobError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeEntitiesDetectionJob");
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.DescribeEntitiesDetectionJob",
@@ -7317,6 +8266,7 @@ impl Comprehend for ComprehendClient {
         input: DescribeEntityRecognizerRequest,
     ) -> Result<DescribeEntityRecognizerResponse, RusotoError<DescribeEntityRecognizerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeEntityRecognizer");
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.DescribeEntityRecognizer",
@@ -7340,6 +8290,7 @@ impl Comprehend for ComprehendClient {
     ) -> Result<DescribeEventsDetectionJobResponse, RusotoError<DescribeEventsDetectionJobError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeEventsDetectionJob");
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.DescribeEventsDetectionJob",
@@ -7365,6 +8316,7 @@ impl Comprehend for ComprehendClient {
         RusotoError<DescribeKeyPhrasesDetectionJobError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeKeyPhrasesDetectionJob");
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.DescribeKeyPhrasesDetectionJob",
@@ -7390,6 +8342,7 @@ impl Comprehend for ComprehendClient {
         RusotoError<DescribePiiEntitiesDetectionJobError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribePiiEntitiesDetectionJob");
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.DescribePiiEntitiesDetectionJob",
@@ -7415,6 +8368,7 @@ impl Comprehend for ComprehendClient {
         RusotoError<DescribeSentimentDetectionJobError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeSentimentDetectionJob");
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.DescribeSentimentDetectionJob",
@@ -7438,6 +8392,7 @@ impl Comprehend for ComprehendClient {
     ) -> Result<DescribeTopicsDetectionJobResponse, RusotoError<DescribeTopicsDetectionJobError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeTopicsDetectionJob");
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.DescribeTopicsDetectionJob",
@@ -7460,6 +8415,7 @@ impl Comprehend for ComprehendClient {
         input: DetectDominantLanguageRequest,
     ) -> Result<DetectDominantLanguageResponse, RusotoError<DetectDominantLanguageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DetectDominantLanguage");
         request.add_header("x-amz-target", "Comprehend_20171127.DetectDominantLanguage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7479,6 +8435,7 @@ impl Comprehend for ComprehendClient {
         input: DetectEntitiesRequest,
     ) -> Result<DetectEntitiesResponse, RusotoError<DetectEntitiesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DetectEntities");
         request.add_header("x-amz-target", "Comprehend_20171127.DetectEntities");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7497,6 +8454,7 @@ impl Comprehend for ComprehendClient {
         input: DetectKeyPhrasesRequest,
     ) -> Result<DetectKeyPhrasesResponse, RusotoError<DetectKeyPhrasesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DetectKeyPhrases");
         request.add_header("x-amz-target", "Comprehend_20171127.DetectKeyPhrases");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7515,6 +8473,7 @@ impl Comprehend for ComprehendClient {
         input: DetectPiiEntitiesRequest,
     ) -> Result<DetectPiiEntitiesResponse, RusotoError<DetectPiiEntitiesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DetectPiiEntities");
         request.add_header("x-amz-target", "Comprehend_20171127.DetectPiiEntities");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7533,6 +8492,7 @@ impl Comprehend for ComprehendClient {
         input: DetectSentimentRequest,
     ) -> Result<DetectSentimentResponse, RusotoError<DetectSentimentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DetectSentiment");
         request.add_header("x-amz-target", "Comprehend_20171127.DetectSentiment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7551,6 +8511,7 @@ impl Comprehend for ComprehendClient {
         input: DetectSyntaxRequest,
     ) -> Result<DetectSyntaxResponse, RusotoError<DetectSyntaxError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DetectSyntax");
         request.add_header("x-amz-target", "Comprehend_20171127.DetectSyntax");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7572,6 +8533,7 @@ impl Comprehend for ComprehendClient {
         RusotoError<ListDocumentClassificationJobsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListDocumentClassificationJobs");
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.ListDocumentClassificationJobs",
@@ -7594,6 +8556,7 @@ impl Comprehend for ComprehendClient {
         input: ListDocumentClassifiersRequest,
     ) -> Result<ListDocumentClassifiersResponse, RusotoError<ListDocumentClassifiersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListDocumentClassifiers");
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.ListDocumentClassifiers",
@@ -7619,6 +8582,7 @@ impl Comprehend for ComprehendClient {
         RusotoError<ListDominantLanguageDetectionJobsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListDominantLanguageDetectionJobs");
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.ListDominantLanguageDetectionJobs",
@@ -7644,6 +8608,7 @@ impl Comprehend for ComprehendClient {
         input: ListEndpointsRequest,
     ) -> Result<ListEndpointsResponse, RusotoError<ListEndpointsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListEndpoints");
         request.add_header("x-amz-target", "Comprehend_20171127.ListEndpoints");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7663,6 +8628,7 @@ impl Comprehend for ComprehendClient {
     ) -> Result<ListEntitiesDetectionJobsResponse, RusotoError<ListEntitiesDetectionJobsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListEntitiesDetectionJobs");
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.ListEntitiesDetectionJobs",
@@ -7685,6 +8651,7 @@ impl Comprehend for ComprehendClient {
         input: ListEntityRecognizersRequest,
     ) -> Result<ListEntityRecognizersResponse, RusotoError<ListEntityRecognizersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListEntityRecognizers");
         request.add_header("x-amz-target", "Comprehend_20171127.ListEntityRecognizers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7704,6 +8671,7 @@ impl Comprehend for ComprehendClient {
         input: ListEventsDetectionJobsRequest,
     ) -> Result<ListEventsDetectionJobsResponse, RusotoError<ListEventsDetectionJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListEventsDetectionJobs");
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.ListEventsDetectionJobs",
@@ -7727,6 +8695,7 @@ impl Comprehend for ComprehendClient {
     ) -> Result<ListKeyPhrasesDetectionJobsResponse, RusotoError<ListKeyPhrasesDetectionJobsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListKeyPhrasesDetectionJobs");
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.ListKeyPhrasesDetectionJobs",
@@ -7750,6 +8719,7 @@ impl Comprehend for ComprehendClient {
     ) -> Result<ListPiiEntitiesDetectionJobsResponse, RusotoError<ListPiiEntitiesDetectionJobsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListPiiEntitiesDetectionJobs");
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.ListPiiEntitiesDetectionJobs",
@@ -7773,6 +8743,7 @@ impl Comprehend for ComprehendClient {
     ) -> Result<ListSentimentDetectionJobsResponse, RusotoError<ListSentimentDetectionJobsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListSentimentDetectionJobs");
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.ListSentimentDetectionJobs",
@@ -7795,6 +8766,7 @@ impl Comprehend for ComprehendClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header("x-amz-target", "Comprehend_20171127.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7813,6 +8785,7 @@ impl Comprehend for ComprehendClient {
         input: ListTopicsDetectionJobsRequest,
     ) -> Result<ListTopicsDetectionJobsResponse, RusotoError<ListTopicsDetectionJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTopicsDetectionJobs");
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.ListTopicsDetectionJobs",
@@ -7838,6 +8811,7 @@ impl Comprehend for ComprehendClient {
         RusotoError<StartDocumentClassificationJobError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartDocumentClassificationJob");
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.StartDocumentClassificationJob",
@@ -7863,6 +8837,7 @@ impl Comprehend for ComprehendClient {
         RusotoError<StartDominantLanguageDetectionJobError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartDominantLanguageDetectionJob");
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.StartDominantLanguageDetectionJob",
@@ -7889,6 +8864,7 @@ impl Comprehend for ComprehendClient {
     ) -> Result<StartEntitiesDetectionJobResponse, RusotoError<StartEntitiesDetectionJobError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartEntitiesDetectionJob");
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.StartEntitiesDetectionJob",
@@ -7911,6 +8887,7 @@ impl Comprehend for ComprehendClient {
         input: StartEventsDetectionJobRequest,
     ) -> Result<StartEventsDetectionJobResponse, RusotoError<StartEventsDetectionJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartEventsDetectionJob");
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.StartEventsDetectionJob",
@@ -7934,6 +8911,7 @@ impl Comprehend for ComprehendClient {
     ) -> Result<StartKeyPhrasesDetectionJobResponse, RusotoError<StartKeyPhrasesDetectionJobError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartKeyPhrasesDetectionJob");
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.StartKeyPhrasesDetectionJob",
@@ -7957,6 +8935,7 @@ impl Comprehend for ComprehendClient {
     ) -> Result<StartPiiEntitiesDetectionJobResponse, RusotoError<StartPiiEntitiesDetectionJobError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartPiiEntitiesDetectionJob");
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.StartPiiEntitiesDetectionJob",
@@ -7980,6 +8959,7 @@ impl Comprehend for ComprehendClient {
     ) -> Result<StartSentimentDetectionJobResponse, RusotoError<StartSentimentDetectionJobError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartSentimentDetectionJob");
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.StartSentimentDetectionJob",
@@ -8002,6 +8982,7 @@ impl Comprehend for ComprehendClient {
         input: StartTopicsDetectionJobRequest,
     ) -> Result<StartTopicsDetectionJobResponse, RusotoError<StartTopicsDetectionJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartTopicsDetectionJob");
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.StartTopicsDetectionJob",
@@ -8027,6 +9008,7 @@ impl Comprehend for ComprehendClient {
         RusotoError<StopDominantLanguageDetectionJobError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopDominantLanguageDetectionJob");
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.StopDominantLanguageDetectionJob",
@@ -8052,6 +9034,7 @@ impl Comprehend for ComprehendClient {
         input: StopEntitiesDetectionJobRequest,
     ) -> Result<StopEntitiesDetectionJobResponse, RusotoError<StopEntitiesDetectionJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopEntitiesDetectionJob");
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.StopEntitiesDetectionJob",
@@ -8074,6 +9057,7 @@ impl Comprehend for ComprehendClient {
         input: StopEventsDetectionJobRequest,
     ) -> Result<StopEventsDetectionJobResponse, RusotoError<StopEventsDetectionJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopEventsDetectionJob");
         request.add_header("x-amz-target", "Comprehend_20171127.StopEventsDetectionJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8094,6 +9078,7 @@ impl Comprehend for ComprehendClient {
     ) -> Result<StopKeyPhrasesDetectionJobResponse, RusotoError<StopKeyPhrasesDetectionJobError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopKeyPhrasesDetectionJob");
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.StopKeyPhrasesDetectionJob",
@@ -8117,6 +9102,7 @@ impl Comprehend for ComprehendClient {
     ) -> Result<StopPiiEntitiesDetectionJobResponse, RusotoError<StopPiiEntitiesDetectionJobError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopPiiEntitiesDetectionJob");
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.StopPiiEntitiesDetectionJob",
@@ -8140,6 +9126,7 @@ impl Comprehend for ComprehendClient {
     ) -> Result<StopSentimentDetectionJobResponse, RusotoError<StopSentimentDetectionJobError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopSentimentDetectionJob");
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.StopSentimentDetectionJob",
@@ -8165,6 +9152,7 @@ impl Comprehend for ComprehendClient {
         RusotoError<StopTrainingDocumentClassifierError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopTrainingDocumentClassifier");
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.StopTrainingDocumentClassifier",
@@ -8188,6 +9176,7 @@ impl Comprehend for ComprehendClient {
     ) -> Result<StopTrainingEntityRecognizerResponse, RusotoError<StopTrainingEntityRecognizerError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopTrainingEntityRecognizer");
         request.add_header(
             "x-amz-target",
             "Comprehend_20171127.StopTrainingEntityRecognizer",
@@ -8210,6 +9199,7 @@ impl Comprehend for ComprehendClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "Comprehend_20171127.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8228,6 +9218,7 @@ impl Comprehend for ComprehendClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "Comprehend_20171127.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8246,6 +9237,7 @@ impl Comprehend for ComprehendClient {
         input: UpdateEndpointRequest,
     ) -> Result<UpdateEndpointResponse, RusotoError<UpdateEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateEndpoint");
         request.add_header("x-amz-target", "Comprehend_20171127.UpdateEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8258,3 +9250,656 @@ impl Comprehend for ComprehendClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateEndpointResponse, _>()
     }
 }
+
+/// A blocking client for the Amazon Comprehend API, running the calls of a `ComprehendClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `ComprehendClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ComprehendBlockingClient {
+    client: ComprehendClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ComprehendBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> ComprehendBlockingClient {
+        ComprehendBlockingClient::from_client(ComprehendClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ComprehendBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ComprehendBlockingClient::from_client(ComprehendClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> ComprehendBlockingClient {
+        ComprehendBlockingClient::from_client(ComprehendClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> ComprehendBlockingClient {
+        ComprehendBlockingClient::from_client(ComprehendClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: ComprehendClient) -> ComprehendBlockingClient {
+        ComprehendBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> ComprehendBlockingClient {
+        ComprehendBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ComprehendBlockingClient {
+        ComprehendBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Comprehend::batch_detect_dominant_language`].
+    pub fn batch_detect_dominant_language(
+        &self,
+        input: BatchDetectDominantLanguageRequest,
+    ) -> Result<BatchDetectDominantLanguageResponse, RusotoError<BatchDetectDominantLanguageError>>
+    {
+        self.runtime
+            .block_on(self.client.batch_detect_dominant_language(input))
+    }
+
+    /// Blocking version of [`Comprehend::batch_detect_entities`].
+    pub fn batch_detect_entities(
+        &self,
+        input: BatchDetectEntitiesRequest,
+    ) -> Result<BatchDetectEntitiesResponse, RusotoError<BatchDetectEntitiesError>> {
+        self.runtime
+            .block_on(self.client.batch_detect_entities(input))
+    }
+
+    /// Blocking version of [`Comprehend::batch_detect_key_phrases`].
+    pub fn batch_detect_key_phrases(
+        &self,
+        input: BatchDetectKeyPhrasesRequest,
+    ) -> Result<BatchDetectKeyPhrasesResponse, RusotoError<BatchDetectKeyPhrasesError>> {
+        self.runtime
+            .block_on(self.client.batch_detect_key_phrases(input))
+    }
+
+    /// Blocking version of [`Comprehend::batch_detect_sentiment`].
+    pub fn batch_detect_sentiment(
+        &self,
+        input: BatchDetectSentimentRequest,
+    ) -> Result<BatchDetectSentimentResponse, RusotoError<BatchDetectSentimentError>> {
+        self.runtime
+            .block_on(self.client.batch_detect_sentiment(input))
+    }
+
+    /// Blocking version of [`Comprehend::batch_detect_syntax`].
+    pub fn batch_detect_syntax(
+        &self,
+        input: BatchDetectSyntaxRequest,
+    ) -> Result<BatchDetectSyntaxResponse, RusotoError<BatchDetectSyntaxError>> {
+        self.runtime
+            .block_on(self.client.batch_detect_syntax(input))
+    }
+
+    /// Blocking version of [`Comprehend::classify_document`].
+    pub fn classify_document(
+        &self,
+        input: ClassifyDocumentRequest,
+    ) -> Result<ClassifyDocumentResponse, RusotoError<ClassifyDocumentError>> {
+        self.runtime.block_on(self.client.classify_document(input))
+    }
+
+    /// Blocking version of [`Comprehend::create_document_classifier`].
+    pub fn create_document_classifier(
+        &self,
+        input: CreateDocumentClassifierRequest,
+    ) -> Result<CreateDocumentClassifierResponse, RusotoError<CreateDocumentClassifierError>> {
+        self.runtime
+            .block_on(self.client.create_document_classifier(input))
+    }
+
+    /// Blocking version of [`Comprehend::create_endpoint`].
+    pub fn create_endpoint(
+        &self,
+        input: CreateEndpointRequest,
+    ) -> Result<CreateEndpointResponse, RusotoError<CreateEndpointError>> {
+        self.runtime.block_on(self.client.create_endpoint(input))
+    }
+
+    /// Blocking version of [`Comprehend::create_entity_recognizer`].
+    pub fn create_entity_recognizer(
+        &self,
+        input: CreateEntityRecognizerRequest,
+    ) -> Result<CreateEntityRecognizerResponse, RusotoError<CreateEntityRecognizerError>> {
+        self.runtime
+            .block_on(self.client.create_entity_recognizer(input))
+    }
+
+    /// Blocking version of [`Comprehend::delete_document_classifier`].
+    pub fn delete_document_classifier(
+        &self,
+        input: DeleteDocumentClassifierRequest,
+    ) -> Result<DeleteDocumentClassifierResponse, RusotoError<DeleteDocumentClassifierError>> {
+        self.runtime
+            .block_on(self.client.delete_document_classifier(input))
+    }
+
+    /// Blocking version of [`Comprehend::delete_endpoint`].
+    pub fn delete_endpoint(
+        &self,
+        input: DeleteEndpointRequest,
+    ) -> Result<DeleteEndpointResponse, RusotoError<DeleteEndpointError>> {
+        self.runtime.block_on(self.client.delete_endpoint(input))
+    }
+
+    /// Blocking version of [`Comprehend::delete_entity_recognizer`].
+    pub fn delete_entity_recognizer(
+        &self,
+        input: DeleteEntityRecognizerRequest,
+    ) -> Result<DeleteEntityRecognizerResponse, RusotoError<DeleteEntityRecognizerError>> {
+        self.runtime
+            .block_on(self.client.delete_entity_recognizer(input))
+    }
+
+    /// Blocking version of [`Comprehend::describe_document_classification_job`].
+    pub fn describe_document_classification_job(
+        &self,
+        input: DescribeDocumentClassificationJobRequest,
+    ) -> Result<
+        DescribeDocumentClassificationJobResponse,
+        RusotoError<DescribeDocumentClassificationJobError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_document_classification_job(input))
+    }
+
+    /// Blocking version of [`Comprehend::describe_document_classifier`].
+    pub fn describe_document_classifier(
+        &self,
+        input: DescribeDocumentClassifierRequest,
+    ) -> Result<DescribeDocumentClassifierResponse, RusotoError<DescribeDocumentClassifierError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_document_classifier(input))
+    }
+
+    /// Blocking version of [`Comprehend::describe_dominant_language_detection_job`].
+    pub fn describe_dominant_language_detection_job(
+        &self,
+        input: DescribeDominantLanguageDetectionJobRequest,
+    ) -> Result<
+        DescribeDominantLanguageDetectionJobResponse,
+        RusotoError<DescribeDominantLanguageDetectionJobError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_dominant_language_detection_job(input))
+    }
+
+    /// Blocking version of [`Comprehend::describe_endpoint`].
+    pub fn describe_endpoint(
+        &self,
+        input: DescribeEndpointRequest,
+    ) -> Result<DescribeEndpointResponse, RusotoError<DescribeEndpointError>> {
+        self.runtime.block_on(self.client.describe_endpoint(input))
+    }
+
+    /// Blocking version of [`Comprehend::describe_entities_detection_job`].
+    pub fn describe_entities_detection_job(
+        &self,
+        input: DescribeEntitiesDetectionJobRequest,
+    ) -> Result<DescribeEntitiesDetectionJobResponse, RusotoError<DescribeEntitiesDetectionJobError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_entities_detection_job(input))
+    }
+
+    /// Blocking version of [`Comprehend::describe_entity_recognizer`].
+    pub fn describe_entity_recognizer(
+        &self,
+        input: DescribeEntityRecognizerRequest,
+    ) -> Result<DescribeEntityRecognizerResponse, RusotoError<DescribeEntityRecognizerError>> {
+        self.runtime
+            .block_on(self.client.describe_entity_recognizer(input))
+    }
+
+    /// Blocking version of [`Comprehend::describe_events_detection_job`].
+    pub fn describe_events_detection_job(
+        &self,
+        input: DescribeEventsDetectionJobRequest,
+    ) -> Result<DescribeEventsDetectionJobResponse, RusotoError<DescribeEventsDetectionJobError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_events_detection_job(input))
+    }
+
+    /// Blocking version of [`Comprehend::describe_key_phrases_detection_job`].
+    pub fn describe_key_phrases_detection_job(
+        &self,
+        input: DescribeKeyPhrasesDetectionJobRequest,
+    ) -> Result<
+        DescribeKeyPhrasesDetectionJobResponse,
+        RusotoError<DescribeKeyPhrasesDetectionJobError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_key_phrases_detection_job(input))
+    }
+
+    /// Blocking version of [`Comprehend::describe_pii_entities_detection_job`].
+    pub fn describe_pii_entities_detection_job(
+        &self,
+        input: DescribePiiEntitiesDetectionJobRequest,
+    ) -> Result<
+        DescribePiiEntitiesDetectionJobResponse,
+        RusotoError<DescribePiiEntitiesDetectionJobError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_pii_entities_detection_job(input))
+    }
+
+    /// Blocking version of [`Comprehend::describe_sentiment_detection_job`].
+    pub fn describe_sentiment_detection_job(
+        &self,
+        input: DescribeSentimentDetectionJobRequest,
+    ) -> Result<
+        DescribeSentimentDetectionJobResponse,
+        RusotoError<DescribeSentimentDetectionJobError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_sentiment_detection_job(input))
+    }
+
+    /// Blocking version of [`Comprehend::describe_topics_detection_job`].
+    pub fn describe_topics_detection_job(
+        &self,
+        input: DescribeTopicsDetectionJobRequest,
+    ) -> Result<DescribeTopicsDetectionJobResponse, RusotoError<DescribeTopicsDetectionJobError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_topics_detection_job(input))
+    }
+
+    /// Blocking version of [`Comprehend::detect_dominant_language`].
+    pub fn detect_dominant_language(
+        &self,
+        input: DetectDominantLanguageRequest,
+    ) -> Result<DetectDominantLanguageResponse, RusotoError<DetectDominantLanguageError>> {
+        self.runtime
+            .block_on(self.client.detect_dominant_language(input))
+    }
+
+    /// Blocking version of [`Comprehend::detect_entities`].
+    pub fn detect_entities(
+        &self,
+        input: DetectEntitiesRequest,
+    ) -> Result<DetectEntitiesResponse, RusotoError<DetectEntitiesError>> {
+        self.runtime.block_on(self.client.detect_entities(input))
+    }
+
+    /// Blocking version of [`Comprehend::detect_key_phrases`].
+    pub fn detect_key_phrases(
+        &self,
+        input: DetectKeyPhrasesRequest,
+    ) -> Result<DetectKeyPhrasesResponse, RusotoError<DetectKeyPhrasesError>> {
+        self.runtime.block_on(self.client.detect_key_phrases(input))
+    }
+
+    /// Blocking version of [`Comprehend::detect_pii_entities`].
+    pub fn detect_pii_entities(
+        &self,
+        input: DetectPiiEntitiesRequest,
+    ) -> Result<DetectPiiEntitiesResponse, RusotoError<DetectPiiEntitiesError>> {
+        self.runtime
+            .block_on(self.client.detect_pii_entities(input))
+    }
+
+    /// Blocking version of [`Comprehend::detect_sentiment`].
+    pub fn detect_sentiment(
+        &self,
+        input: DetectSentimentRequest,
+    ) -> Result<DetectSentimentResponse, RusotoError<DetectSentimentError>> {
+        self.runtime.block_on(self.client.detect_sentiment(input))
+    }
+
+    /// Blocking version of [`Comprehend::detect_syntax`].
+    pub fn detect_syntax(
+        &self,
+        input: DetectSyntaxRequest,
+    ) -> Result<DetectSyntaxResponse, RusotoError<DetectSyntaxError>> {
+        self.runtime.block_on(self.client.detect_syntax(input))
+    }
+
+    /// Blocking version of [`Comprehend::list_document_classification_jobs`].
+    pub fn list_document_classification_jobs(
+        &self,
+        input: ListDocumentClassificationJobsRequest,
+    ) -> Result<
+        ListDocumentClassificationJobsResponse,
+        RusotoError<ListDocumentClassificationJobsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_document_classification_jobs(input))
+    }
+
+    /// Blocking version of [`Comprehend::list_document_classifiers`].
+    pub fn list_document_classifiers(
+        &self,
+        input: ListDocumentClassifiersRequest,
+    ) -> Result<ListDocumentClassifiersResponse, RusotoError<ListDocumentClassifiersError>> {
+        self.runtime
+            .block_on(self.client.list_document_classifiers(input))
+    }
+
+    /// Blocking version of [`Comprehend::list_dominant_language_detection_jobs`].
+    pub fn list_dominant_language_detection_jobs(
+        &self,
+        input: ListDominantLanguageDetectionJobsRequest,
+    ) -> Result<
+        ListDominantLanguageDetectionJobsResponse,
+        RusotoError<ListDominantLanguageDetectionJobsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_dominant_language_detection_jobs(input))
+    }
+
+    /// Blocking version of [`Comprehend::list_endpoints`].
+    pub fn list_endpoints(
+        &self,
+        input: ListEndpointsRequest,
+    ) -> Result<ListEndpointsResponse, RusotoError<ListEndpointsError>> {
+        self.runtime.block_on(self.client.list_endpoints(input))
+    }
+
+    /// Blocking version of [`Comprehend::list_entities_detection_jobs`].
+    pub fn list_entities_detection_jobs(
+        &self,
+        input: ListEntitiesDetectionJobsRequest,
+    ) -> Result<ListEntitiesDetectionJobsResponse, RusotoError<ListEntitiesDetectionJobsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_entities_detection_jobs(input))
+    }
+
+    /// Blocking version of [`Comprehend::list_entity_recognizers`].
+    pub fn list_entity_recognizers(
+        &self,
+        input: ListEntityRecognizersRequest,
+    ) -> Result<ListEntityRecognizersResponse, RusotoError<ListEntityRecognizersError>> {
+        self.runtime
+            .block_on(self.client.list_entity_recognizers(input))
+    }
+
+    /// Blocking version of [`Comprehend::list_events_detection_jobs`].
+    pub fn list_events_detection_jobs(
+        &self,
+        input: ListEventsDetectionJobsRequest,
+    ) -> Result<ListEventsDetectionJobsResponse, RusotoError<ListEventsDetectionJobsError>> {
+        self.runtime
+            .block_on(self.client.list_events_detection_jobs(input))
+    }
+
+    /// Blocking version of [`Comprehend::list_key_phrases_detection_jobs`].
+    pub fn list_key_phrases_detection_jobs(
+        &self,
+        input: ListKeyPhrasesDetectionJobsRequest,
+    ) -> Result<ListKeyPhrasesDetectionJobsResponse, RusotoError<ListKeyPhrasesDetectionJobsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_key_phrases_detection_jobs(input))
+    }
+
+    /// Blocking version of [`Comprehend::list_pii_entities_detection_jobs`].
+    pub fn list_pii_entities_detection_jobs(
+        &self,
+        input: ListPiiEntitiesDetectionJobsRequest,
+    ) -> Result<ListPiiEntitiesDetectionJobsResponse, RusotoError<ListPiiEntitiesDetectionJobsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_pii_entities_detection_jobs(input))
+    }
+
+    /// Blocking version of [`Comprehend::list_sentiment_detection_jobs`].
+    pub fn list_sentiment_detection_jobs(
+        &self,
+        input: ListSentimentDetectionJobsRequest,
+    ) -> Result<ListSentimentDetectionJobsResponse, RusotoError<ListSentimentDetectionJobsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_sentiment_detection_jobs(input))
+    }
+
+    /// Blocking version of [`Comprehend::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`Comprehend::list_topics_detection_jobs`].
+    pub fn list_topics_detection_jobs(
+        &self,
+        input: ListTopicsDetectionJobsRequest,
+    ) -> Result<ListTopicsDetectionJobsResponse, RusotoError<ListTopicsDetectionJobsError>> {
+        self.runtime
+            .block_on(self.client.list_topics_detection_jobs(input))
+    }
+
+    /// Blocking version of [`Comprehend::start_document_classification_job`].
+    pub fn start_document_classification_job(
+        &self,
+        input: StartDocumentClassificationJobRequest,
+    ) -> Result<
+        StartDocumentClassificationJobResponse,
+        RusotoError<StartDocumentClassificationJobError>,
+    > {
+        self.runtime
+            .block_on(self.client.start_document_classification_job(input))
+    }
+
+    /// Blocking version of [`Comprehend::start_dominant_language_detection_job`].
+    pub fn start_dominant_language_detection_job(
+        &self,
+        input: StartDominantLanguageDetectionJobRequest,
+    ) -> Result<
+        StartDominantLanguageDetectionJobResponse,
+        RusotoError<StartDominantLanguageDetectionJobError>,
+    > {
+        self.runtime
+            .block_on(self.client.start_dominant_language_detection_job(input))
+    }
+
+    /// Blocking version of [`Comprehend::start_entities_detection_job`].
+    pub fn start_entities_detection_job(
+        &self,
+        input: StartEntitiesDetectionJobRequest,
+    ) -> Result<StartEntitiesDetectionJobResponse, RusotoError<StartEntitiesDetectionJobError>>
+    {
+        self.runtime
+            .block_on(self.client.start_entities_detection_job(input))
+    }
+
+    /// Blocking version of [`Comprehend::start_events_detection_job`].
+    pub fn start_events_detection_job(
+        &self,
+        input: StartEventsDetectionJobRequest,
+    ) -> Result<StartEventsDetectionJobResponse, RusotoError<StartEventsDetectionJobError>> {
+        self.runtime
+            .block_on(self.client.start_events_detection_job(input))
+    }
+
+    /// Blocking version of [`Comprehend::start_key_phrases_detection_job`].
+    pub fn start_key_phrases_detection_job(
+        &self,
+        input: StartKeyPhrasesDetectionJobRequest,
+    ) -> Result<StartKeyPhrasesDetectionJobResponse, RusotoError<StartKeyPhrasesDetectionJobError>>
+    {
+        self.runtime
+            .block_on(self.client.start_key_phrases_detection_job(input))
+    }
+
+    /// Blocking version of [`Comprehend::start_pii_entities_detection_job`].
+    pub fn start_pii_entities_detection_job(
+        &self,
+        input: StartPiiEntitiesDetectionJobRequest,
+    ) -> Result<StartPiiEntitiesDetectionJobResponse, RusotoError<StartPiiEntitiesDetectionJobError>>
+    {
+        self.runtime
+            .block_on(self.client.start_pii_entities_detection_job(input))
+    }
+
+    /// Blocking version of [`Comprehend::start_sentiment_detection_job`].
+    pub fn start_sentiment_detection_job(
+        &self,
+        input: StartSentimentDetectionJobRequest,
+    ) -> Result<StartSentimentDetectionJobResponse, RusotoError<StartSentimentDetectionJobError>>
+    {
+        self.runtime
+            .block_on(self.client.start_sentiment_detection_job(input))
+    }
+
+    /// Blocking version of [`Comprehend::start_topics_detection_job`].
+    pub fn start_topics_detection_job(
+        &self,
+        input: StartTopicsDetectionJobRequest,
+    ) -> Result<StartTopicsDetectionJobResponse, RusotoError<StartTopicsDetectionJobError>> {
+        self.runtime
+            .block_on(self.client.start_topics_detection_job(input))
+    }
+
+    /// Blocking version of [`Comprehend::stop_dominant_language_detection_job`].
+    pub fn stop_dominant_language_detection_job(
+        &self,
+        input: StopDominantLanguageDetectionJobRequest,
+    ) -> Result<
+        StopDominantLanguageDetectionJobResponse,
+        RusotoError<StopDominantLanguageDetectionJobError>,
+    > {
+        self.runtime
+            .block_on(self.client.stop_dominant_language_detection_job(input))
+    }
+
+    /// Blocking version of [`Comprehend::stop_entities_detection_job`].
+    pub fn stop_entities_detection_job(
+        &self,
+        input: StopEntitiesDetectionJobRequest,
+    ) -> Result<StopEntitiesDetectionJobResponse, RusotoError<StopEntitiesDetectionJobError>> {
+        self.runtime
+            .block_on(self.client.stop_entities_detection_job(input))
+    }
+
+    /// Blocking version of [`Comprehend::stop_events_detection_job`].
+    pub fn stop_events_detection_job(
+        &self,
+        input: StopEventsDetectionJobRequest,
+    ) -> Result<StopEventsDetectionJobResponse, RusotoError<StopEventsDetectionJobError>> {
+        self.runtime
+            .block_on(self.client.stop_events_detection_job(input))
+    }
+
+    /// Blocking version of [`Comprehend::stop_key_phrases_detection_job`].
+    pub fn stop_key_phrases_detection_job(
+        &self,
+        input: StopKeyPhrasesDetectionJobRequest,
+    ) -> Result<StopKeyPhrasesDetectionJobResponse, RusotoError<StopKeyPhrasesDetectionJobError>>
+    {
+        self.runtime
+            .block_on(self.client.stop_key_phrases_detection_job(input))
+    }
+
+    /// Blocking version of [`Comprehend::stop_pii_entities_detection_job`].
+    pub fn stop_pii_entities_detection_job(
+        &self,
+        input: StopPiiEntitiesDetectionJobRequest,
+    ) -> Result<StopPiiEntitiesDetectionJobResponse, RusotoError<StopPiiEntitiesDetectionJobError>>
+    {
+        self.runtime
+            .block_on(self.client.stop_pii_entities_detection_job(input))
+    }
+
+    /// Blocking version of [`Comprehend::stop_sentiment_detection_job`].
+    pub fn stop_sentiment_detection_job(
+        &self,
+        input: StopSentimentDetectionJobRequest,
+    ) -> Result<StopSentimentDetectionJobResponse, RusotoError<StopSentimentDetectionJobError>>
+    {
+        self.runtime
+            .block_on(self.client.stop_sentiment_detection_job(input))
+    }
+
+    /// Blocking version of [`Comprehend::stop_training_document_classifier`].
+    pub fn stop_training_document_classifier(
+        &self,
+        input: StopTrainingDocumentClassifierRequest,
+    ) -> Result<
+        StopTrainingDocumentClassifierResponse,
+        RusotoError<StopTrainingDocumentClassifierError>,
+    > {
+        self.runtime
+            .block_on(self.client.stop_training_document_classifier(input))
+    }
+
+    /// Blocking version of [`Comprehend::stop_training_entity_recognizer`].
+    pub fn stop_training_entity_recognizer(
+        &self,
+        input: StopTrainingEntityRecognizerRequest,
+    ) -> Result<StopTrainingEntityRecognizerResponse, RusotoError<StopTrainingEntityRecognizerError>>
+    {
+        self.runtime
+            .block_on(self.client.stop_training_entity_recognizer(input))
+    }
+
+    /// Blocking version of [`Comprehend::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`Comprehend::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`Comprehend::update_endpoint`].
+    pub fn update_endpoint(
+        &self,
+        input: UpdateEndpointRequest,
+    ) -> Result<UpdateEndpointResponse, RusotoError<UpdateEndpointError>> {
+        self.runtime.block_on(self.client.update_endpoint(input))
+    }
+}
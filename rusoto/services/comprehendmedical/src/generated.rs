@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1064,26 +1065,38 @@ impl DescribeEntitiesDetectionV2JobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
-                        DescribeEntitiesDetectionV2JobError::InternalServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEntitiesDetectionV2JobError::InternalServer,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        DescribeEntitiesDetectionV2JobError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEntitiesDetectionV2JobError::InvalidRequest,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeEntitiesDetectionV2JobError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEntitiesDetectionV2JobError::ResourceNotFound,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        DescribeEntitiesDetectionV2JobError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEntitiesDetectionV2JobError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1130,26 +1143,38 @@ impl DescribeICD10CMInferenceJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeICD10CMInferenceJobError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeICD10CMInferenceJobError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeICD10CMInferenceJobError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeICD10CMInferenceJobError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeICD10CMInferenceJobError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeICD10CMInferenceJobError::ResourceNotFound,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeICD10CMInferenceJobError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeICD10CMInferenceJobError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1186,26 +1211,38 @@ impl DescribePHIDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribePHIDetectionJobError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribePHIDetectionJobError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribePHIDetectionJobError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribePHIDetectionJobError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribePHIDetectionJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribePHIDetectionJobError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribePHIDetectionJobError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribePHIDetectionJobError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1244,26 +1281,38 @@ impl DescribeRxNormInferenceJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeRxNormInferenceJobError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRxNormInferenceJobError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeRxNormInferenceJobError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRxNormInferenceJobError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeRxNormInferenceJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRxNormInferenceJobError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeRxNormInferenceJobError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRxNormInferenceJobError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1304,26 +1353,54 @@ impl DetectEntitiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DetectEntitiesError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectEntitiesError::InternalServer,
+                    )
                 }
                 "InvalidEncodingException" => {
-                    return RusotoError::Service(DetectEntitiesError::InvalidEncoding(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectEntitiesError::InvalidEncoding,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DetectEntitiesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectEntitiesError::InvalidRequest,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DetectEntitiesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectEntitiesError::ServiceUnavailable,
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(DetectEntitiesError::TextSizeLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetectEntitiesError::TextSizeLimitExceeded,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DetectEntitiesError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectEntitiesError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1366,26 +1443,54 @@ impl DetectEntitiesV2Error {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DetectEntitiesV2Error::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectEntitiesV2Error::InternalServer,
+                    )
                 }
                 "InvalidEncodingException" => {
-                    return RusotoError::Service(DetectEntitiesV2Error::InvalidEncoding(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectEntitiesV2Error::InvalidEncoding,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DetectEntitiesV2Error::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectEntitiesV2Error::InvalidRequest,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DetectEntitiesV2Error::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectEntitiesV2Error::ServiceUnavailable,
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(DetectEntitiesV2Error::TextSizeLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetectEntitiesV2Error::TextSizeLimitExceeded,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DetectEntitiesV2Error::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectEntitiesV2Error::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1428,24 +1533,54 @@ impl DetectPHIError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DetectPHIError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectPHIError::InternalServer,
+                    )
                 }
                 "InvalidEncodingException" => {
-                    return RusotoError::Service(DetectPHIError::InvalidEncoding(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectPHIError::InvalidEncoding,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DetectPHIError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectPHIError::InvalidRequest,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DetectPHIError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectPHIError::ServiceUnavailable,
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(DetectPHIError::TextSizeLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectPHIError::TextSizeLimitExceeded,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DetectPHIError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectPHIError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1488,24 +1623,54 @@ impl InferICD10CMError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(InferICD10CMError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InferICD10CMError::InternalServer,
+                    )
                 }
                 "InvalidEncodingException" => {
-                    return RusotoError::Service(InferICD10CMError::InvalidEncoding(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InferICD10CMError::InvalidEncoding,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(InferICD10CMError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InferICD10CMError::InvalidRequest,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(InferICD10CMError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InferICD10CMError::ServiceUnavailable,
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(InferICD10CMError::TextSizeLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InferICD10CMError::TextSizeLimitExceeded,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(InferICD10CMError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InferICD10CMError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1548,24 +1713,54 @@ impl InferRxNormError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(InferRxNormError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InferRxNormError::InternalServer,
+                    )
                 }
                 "InvalidEncodingException" => {
-                    return RusotoError::Service(InferRxNormError::InvalidEncoding(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InferRxNormError::InvalidEncoding,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(InferRxNormError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InferRxNormError::InvalidRequest,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(InferRxNormError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InferRxNormError::ServiceUnavailable,
+                    )
                 }
                 "TextSizeLimitExceededException" => {
-                    return RusotoError::Service(InferRxNormError::TextSizeLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InferRxNormError::TextSizeLimitExceeded,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(InferRxNormError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InferRxNormError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1604,21 +1799,30 @@ impl ListEntitiesDetectionV2JobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListEntitiesDetectionV2JobsError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListEntitiesDetectionV2JobsError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListEntitiesDetectionV2JobsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListEntitiesDetectionV2JobsError::InvalidRequest,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListEntitiesDetectionV2JobsError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListEntitiesDetectionV2JobsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1652,21 +1856,30 @@ impl ListICD10CMInferenceJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListICD10CMInferenceJobsError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListICD10CMInferenceJobsError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListICD10CMInferenceJobsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListICD10CMInferenceJobsError::InvalidRequest,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListICD10CMInferenceJobsError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListICD10CMInferenceJobsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1700,17 +1913,30 @@ impl ListPHIDetectionJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListPHIDetectionJobsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPHIDetectionJobsError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListPHIDetectionJobsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPHIDetectionJobsError::InvalidRequest,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListPHIDetectionJobsError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPHIDetectionJobsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1744,21 +1970,30 @@ impl ListRxNormInferenceJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListRxNormInferenceJobsError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListRxNormInferenceJobsError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListRxNormInferenceJobsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListRxNormInferenceJobsError::InvalidRequest,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListRxNormInferenceJobsError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListRxNormInferenceJobsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1796,26 +2031,38 @@ impl StartEntitiesDetectionV2JobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StartEntitiesDetectionV2JobError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartEntitiesDetectionV2JobError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartEntitiesDetectionV2JobError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartEntitiesDetectionV2JobError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        StartEntitiesDetectionV2JobError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartEntitiesDetectionV2JobError::ResourceNotFound,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartEntitiesDetectionV2JobError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartEntitiesDetectionV2JobError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1852,26 +2099,38 @@ impl StartICD10CMInferenceJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StartICD10CMInferenceJobError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartICD10CMInferenceJobError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartICD10CMInferenceJobError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartICD10CMInferenceJobError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartICD10CMInferenceJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartICD10CMInferenceJobError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartICD10CMInferenceJobError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartICD10CMInferenceJobError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1908,22 +2167,38 @@ impl StartPHIDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StartPHIDetectionJobError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartPHIDetectionJobError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartPHIDetectionJobError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartPHIDetectionJobError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartPHIDetectionJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartPHIDetectionJobError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartPHIDetectionJobError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartPHIDetectionJobError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1960,26 +2235,38 @@ impl StartRxNormInferenceJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StartRxNormInferenceJobError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartRxNormInferenceJobError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartRxNormInferenceJobError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartRxNormInferenceJobError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartRxNormInferenceJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartRxNormInferenceJobError::ResourceNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartRxNormInferenceJobError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartRxNormInferenceJobError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2016,21 +2303,30 @@ impl StopEntitiesDetectionV2JobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StopEntitiesDetectionV2JobError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopEntitiesDetectionV2JobError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopEntitiesDetectionV2JobError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopEntitiesDetectionV2JobError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopEntitiesDetectionV2JobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopEntitiesDetectionV2JobError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2064,21 +2360,30 @@ impl StopICD10CMInferenceJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StopICD10CMInferenceJobError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopICD10CMInferenceJobError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopICD10CMInferenceJobError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopICD10CMInferenceJobError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopICD10CMInferenceJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopICD10CMInferenceJobError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2112,17 +2417,30 @@ impl StopPHIDetectionJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StopPHIDetectionJobError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopPHIDetectionJobError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopPHIDetectionJobError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopPHIDetectionJobError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopPHIDetectionJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopPHIDetectionJobError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2156,21 +2474,30 @@ impl StopRxNormInferenceJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StopRxNormInferenceJobError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopRxNormInferenceJobError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopRxNormInferenceJobError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopRxNormInferenceJobError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopRxNormInferenceJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopRxNormInferenceJobError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2330,11 +2657,12 @@ pub struct ComprehendMedicalClient {
 impl ComprehendMedicalClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> ComprehendMedicalClient {
         ComprehendMedicalClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "ComprehendMedical"),
         }
     }
 
@@ -2349,12 +2677,32 @@ impl ComprehendMedicalClient {
     {
         ComprehendMedicalClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "ComprehendMedical"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ComprehendMedicalClient {
-        ComprehendMedicalClient { client, region }
+        ComprehendMedicalClient {
+            client,
+            region: region_for_service(region, "ComprehendMedical"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> ComprehendMedicalClient {
+        ComprehendMedicalClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("ComprehendMedical"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ComprehendMedicalClient {
+        ComprehendMedicalClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -2369,6 +2717,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
         RusotoError<DescribeEntitiesDetectionV2JobError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeEntitiesDetectionV2Job");
         request.add_header(
             "x-amz-target",
             "ComprehendMedical_20181030.DescribeEntitiesDetectionV2Job",
@@ -2392,6 +2741,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
     ) -> Result<DescribeICD10CMInferenceJobResponse, RusotoError<DescribeICD10CMInferenceJobError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeICD10CMInferenceJob");
         request.add_header(
             "x-amz-target",
             "ComprehendMedical_20181030.DescribeICD10CMInferenceJob",
@@ -2414,6 +2764,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
         input: DescribePHIDetectionJobRequest,
     ) -> Result<DescribePHIDetectionJobResponse, RusotoError<DescribePHIDetectionJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribePHIDetectionJob");
         request.add_header(
             "x-amz-target",
             "ComprehendMedical_20181030.DescribePHIDetectionJob",
@@ -2437,6 +2788,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
     ) -> Result<DescribeRxNormInferenceJobResponse, RusotoError<DescribeRxNormInferenceJobError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeRxNormInferenceJob");
         request.add_header(
             "x-amz-target",
             "ComprehendMedical_20181030.DescribeRxNormInferenceJob",
@@ -2459,6 +2811,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
         input: DetectEntitiesRequest,
     ) -> Result<DetectEntitiesResponse, RusotoError<DetectEntitiesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DetectEntities");
         request.add_header("x-amz-target", "ComprehendMedical_20181030.DetectEntities");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2477,6 +2830,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
         input: DetectEntitiesV2Request,
     ) -> Result<DetectEntitiesV2Response, RusotoError<DetectEntitiesV2Error>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DetectEntitiesV2");
         request.add_header(
             "x-amz-target",
             "ComprehendMedical_20181030.DetectEntitiesV2",
@@ -2498,6 +2852,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
         input: DetectPHIRequest,
     ) -> Result<DetectPHIResponse, RusotoError<DetectPHIError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DetectPHI");
         request.add_header("x-amz-target", "ComprehendMedical_20181030.DetectPHI");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2516,6 +2871,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
         input: InferICD10CMRequest,
     ) -> Result<InferICD10CMResponse, RusotoError<InferICD10CMError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("InferICD10CM");
         request.add_header("x-amz-target", "ComprehendMedical_20181030.InferICD10CM");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2534,6 +2890,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
         input: InferRxNormRequest,
     ) -> Result<InferRxNormResponse, RusotoError<InferRxNormError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("InferRxNorm");
         request.add_header("x-amz-target", "ComprehendMedical_20181030.InferRxNorm");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2553,6 +2910,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
     ) -> Result<ListEntitiesDetectionV2JobsResponse, RusotoError<ListEntitiesDetectionV2JobsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListEntitiesDetectionV2Jobs");
         request.add_header(
             "x-amz-target",
             "ComprehendMedical_20181030.ListEntitiesDetectionV2Jobs",
@@ -2575,6 +2933,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
         input: ListICD10CMInferenceJobsRequest,
     ) -> Result<ListICD10CMInferenceJobsResponse, RusotoError<ListICD10CMInferenceJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListICD10CMInferenceJobs");
         request.add_header(
             "x-amz-target",
             "ComprehendMedical_20181030.ListICD10CMInferenceJobs",
@@ -2597,6 +2956,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
         input: ListPHIDetectionJobsRequest,
     ) -> Result<ListPHIDetectionJobsResponse, RusotoError<ListPHIDetectionJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListPHIDetectionJobs");
         request.add_header(
             "x-amz-target",
             "ComprehendMedical_20181030.ListPHIDetectionJobs",
@@ -2619,6 +2979,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
         input: ListRxNormInferenceJobsRequest,
     ) -> Result<ListRxNormInferenceJobsResponse, RusotoError<ListRxNormInferenceJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListRxNormInferenceJobs");
         request.add_header(
             "x-amz-target",
             "ComprehendMedical_20181030.ListRxNormInferenceJobs",
@@ -2642,6 +3003,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
     ) -> Result<StartEntitiesDetectionV2JobResponse, RusotoError<StartEntitiesDetectionV2JobError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartEntitiesDetectionV2Job");
         request.add_header(
             "x-amz-target",
             "ComprehendMedical_20181030.StartEntitiesDetectionV2Job",
@@ -2664,6 +3026,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
         input: StartICD10CMInferenceJobRequest,
     ) -> Result<StartICD10CMInferenceJobResponse, RusotoError<StartICD10CMInferenceJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartICD10CMInferenceJob");
         request.add_header(
             "x-amz-target",
             "ComprehendMedical_20181030.StartICD10CMInferenceJob",
@@ -2686,6 +3049,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
         input: StartPHIDetectionJobRequest,
     ) -> Result<StartPHIDetectionJobResponse, RusotoError<StartPHIDetectionJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartPHIDetectionJob");
         request.add_header(
             "x-amz-target",
             "ComprehendMedical_20181030.StartPHIDetectionJob",
@@ -2708,6 +3072,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
         input: StartRxNormInferenceJobRequest,
     ) -> Result<StartRxNormInferenceJobResponse, RusotoError<StartRxNormInferenceJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartRxNormInferenceJob");
         request.add_header(
             "x-amz-target",
             "ComprehendMedical_20181030.StartRxNormInferenceJob",
@@ -2731,6 +3096,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
     ) -> Result<StopEntitiesDetectionV2JobResponse, RusotoError<StopEntitiesDetectionV2JobError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopEntitiesDetectionV2Job");
         request.add_header(
             "x-amz-target",
             "ComprehendMedical_20181030.StopEntitiesDetectionV2Job",
@@ -2753,6 +3119,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
         input: StopICD10CMInferenceJobRequest,
     ) -> Result<StopICD10CMInferenceJobResponse, RusotoError<StopICD10CMInferenceJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopICD10CMInferenceJob");
         request.add_header(
             "x-amz-target",
             "ComprehendMedical_20181030.StopICD10CMInferenceJob",
@@ -2775,6 +3142,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
         input: StopPHIDetectionJobRequest,
     ) -> Result<StopPHIDetectionJobResponse, RusotoError<StopPHIDetectionJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopPHIDetectionJob");
         request.add_header(
             "x-amz-target",
             "ComprehendMedical_20181030.StopPHIDetectionJob",
@@ -2796,6 +3164,7 @@ impl ComprehendMedical for ComprehendMedicalClient {
         input: StopRxNormInferenceJobRequest,
     ) -> Result<StopRxNormInferenceJobResponse, RusotoError<StopRxNormInferenceJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopRxNormInferenceJob");
         request.add_header(
             "x-amz-target",
             "ComprehendMedical_20181030.StopRxNormInferenceJob",
@@ -2812,3 +3181,280 @@ impl ComprehendMedical for ComprehendMedicalClient {
             .deserialize::<StopRxNormInferenceJobResponse, _>()
     }
 }
+
+/// A blocking client for the ComprehendMedical API, running the calls of a `ComprehendMedicalClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `ComprehendMedicalClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ComprehendMedicalBlockingClient {
+    client: ComprehendMedicalClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ComprehendMedicalBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> ComprehendMedicalBlockingClient {
+        ComprehendMedicalBlockingClient::from_client(ComprehendMedicalClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ComprehendMedicalBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ComprehendMedicalBlockingClient::from_client(ComprehendMedicalClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> ComprehendMedicalBlockingClient {
+        ComprehendMedicalBlockingClient::from_client(ComprehendMedicalClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> ComprehendMedicalBlockingClient {
+        ComprehendMedicalBlockingClient::from_client(ComprehendMedicalClient::new_with_config(
+            config,
+        ))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: ComprehendMedicalClient) -> ComprehendMedicalBlockingClient {
+        ComprehendMedicalBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> ComprehendMedicalBlockingClient {
+        ComprehendMedicalBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ComprehendMedicalBlockingClient {
+        ComprehendMedicalBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`ComprehendMedical::describe_entities_detection_v2_job`].
+    pub fn describe_entities_detection_v2_job(
+        &self,
+        input: DescribeEntitiesDetectionV2JobRequest,
+    ) -> Result<
+        DescribeEntitiesDetectionV2JobResponse,
+        RusotoError<DescribeEntitiesDetectionV2JobError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_entities_detection_v2_job(input))
+    }
+
+    /// Blocking version of [`ComprehendMedical::describe_icd10cm_inference_job`].
+    pub fn describe_icd10cm_inference_job(
+        &self,
+        input: DescribeICD10CMInferenceJobRequest,
+    ) -> Result<DescribeICD10CMInferenceJobResponse, RusotoError<DescribeICD10CMInferenceJobError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_icd10cm_inference_job(input))
+    }
+
+    /// Blocking version of [`ComprehendMedical::describe_phi_detection_job`].
+    pub fn describe_phi_detection_job(
+        &self,
+        input: DescribePHIDetectionJobRequest,
+    ) -> Result<DescribePHIDetectionJobResponse, RusotoError<DescribePHIDetectionJobError>> {
+        self.runtime
+            .block_on(self.client.describe_phi_detection_job(input))
+    }
+
+    /// Blocking version of [`ComprehendMedical::describe_rx_norm_inference_job`].
+    pub fn describe_rx_norm_inference_job(
+        &self,
+        input: DescribeRxNormInferenceJobRequest,
+    ) -> Result<DescribeRxNormInferenceJobResponse, RusotoError<DescribeRxNormInferenceJobError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_rx_norm_inference_job(input))
+    }
+
+    /// Blocking version of [`ComprehendMedical::detect_entities`].
+    pub fn detect_entities(
+        &self,
+        input: DetectEntitiesRequest,
+    ) -> Result<DetectEntitiesResponse, RusotoError<DetectEntitiesError>> {
+        self.runtime.block_on(self.client.detect_entities(input))
+    }
+
+    /// Blocking version of [`ComprehendMedical::detect_entities_v2`].
+    pub fn detect_entities_v2(
+        &self,
+        input: DetectEntitiesV2Request,
+    ) -> Result<DetectEntitiesV2Response, RusotoError<DetectEntitiesV2Error>> {
+        self.runtime.block_on(self.client.detect_entities_v2(input))
+    }
+
+    /// Blocking version of [`ComprehendMedical::detect_phi`].
+    pub fn detect_phi(
+        &self,
+        input: DetectPHIRequest,
+    ) -> Result<DetectPHIResponse, RusotoError<DetectPHIError>> {
+        self.runtime.block_on(self.client.detect_phi(input))
+    }
+
+    /// Blocking version of [`ComprehendMedical::infer_icd10cm`].
+    pub fn infer_icd10cm(
+        &self,
+        input: InferICD10CMRequest,
+    ) -> Result<InferICD10CMResponse, RusotoError<InferICD10CMError>> {
+        self.runtime.block_on(self.client.infer_icd10cm(input))
+    }
+
+    /// Blocking version of [`ComprehendMedical::infer_rx_norm`].
+    pub fn infer_rx_norm(
+        &self,
+        input: InferRxNormRequest,
+    ) -> Result<InferRxNormResponse, RusotoError<InferRxNormError>> {
+        self.runtime.block_on(self.client.infer_rx_norm(input))
+    }
+
+    /// Blocking version of [`ComprehendMedical::list_entities_detection_v2_jobs`].
+    pub fn list_entities_detection_v2_jobs(
+        &self,
+        input: ListEntitiesDetectionV2JobsRequest,
+    ) -> Result<ListEntitiesDetectionV2JobsResponse, RusotoError<ListEntitiesDetectionV2JobsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_entities_detection_v2_jobs(input))
+    }
+
+    /// Blocking version of [`ComprehendMedical::list_icd10cm_inference_jobs`].
+    pub fn list_icd10cm_inference_jobs(
+        &self,
+        input: ListICD10CMInferenceJobsRequest,
+    ) -> Result<ListICD10CMInferenceJobsResponse, RusotoError<ListICD10CMInferenceJobsError>> {
+        self.runtime
+            .block_on(self.client.list_icd10cm_inference_jobs(input))
+    }
+
+    /// Blocking version of [`ComprehendMedical::list_phi_detection_jobs`].
+    pub fn list_phi_detection_jobs(
+        &self,
+        input: ListPHIDetectionJobsRequest,
+    ) -> Result<ListPHIDetectionJobsResponse, RusotoError<ListPHIDetectionJobsError>> {
+        self.runtime
+            .block_on(self.client.list_phi_detection_jobs(input))
+    }
+
+    /// Blocking version of [`ComprehendMedical::list_rx_norm_inference_jobs`].
+    pub fn list_rx_norm_inference_jobs(
+        &self,
+        input: ListRxNormInferenceJobsRequest,
+    ) -> Result<ListRxNormInferenceJobsResponse, RusotoError<ListRxNormInferenceJobsError>> {
+        self.runtime
+            .block_on(self.client.list_rx_norm_inference_jobs(input))
+    }
+
+    /// Blocking version of [`ComprehendMedical::start_entities_detection_v2_job`].
+    pub fn start_entities_detection_v2_job(
+        &self,
+        input: StartEntitiesDetectionV2JobRequest,
+    ) -> Result<StartEntitiesDetectionV2JobResponse, RusotoError<StartEntitiesDetectionV2JobError>>
+    {
+        self.runtime
+            .block_on(self.client.start_entities_detection_v2_job(input))
+    }
+
+    /// Blocking version of [`ComprehendMedical::start_icd10cm_inference_job`].
+    pub fn start_icd10cm_inference_job(
+        &self,
+        input: StartICD10CMInferenceJobRequest,
+    ) -> Result<StartICD10CMInferenceJobResponse, RusotoError<StartICD10CMInferenceJobError>> {
+        self.runtime
+            .block_on(self.client.start_icd10cm_inference_job(input))
+    }
+
+    /// Blocking version of [`ComprehendMedical::start_phi_detection_job`].
+    pub fn start_phi_detection_job(
+        &self,
+        input: StartPHIDetectionJobRequest,
+    ) -> Result<StartPHIDetectionJobResponse, RusotoError<StartPHIDetectionJobError>> {
+        self.runtime
+            .block_on(self.client.start_phi_detection_job(input))
+    }
+
+    /// Blocking version of [`ComprehendMedical::start_rx_norm_inference_job`].
+    pub fn start_rx_norm_inference_job(
+        &self,
+        input: StartRxNormInferenceJobRequest,
+    ) -> Result<StartRxNormInferenceJobResponse, RusotoError<StartRxNormInferenceJobError>> {
+        self.runtime
+            .block_on(self.client.start_rx_norm_inference_job(input))
+    }
+
+    /// Blocking version of [`ComprehendMedical::stop_entities_detection_v2_job`].
+    pub fn stop_entities_detection_v2_job(
+        &self,
+        input: StopEntitiesDetectionV2JobRequest,
+    ) -> Result<StopEntitiesDetectionV2JobResponse, RusotoError<StopEntitiesDetectionV2JobError>>
+    {
+        self.runtime
+            .block_on(self.client.stop_entities_detection_v2_job(input))
+    }
+
+    /// Blocking version of [`ComprehendMedical::stop_icd10cm_inference_job`].
+    pub fn stop_icd10cm_inference_job(
+        &self,
+        input: StopICD10CMInferenceJobRequest,
+    ) -> Result<StopICD10CMInferenceJobResponse, RusotoError<StopICD10CMInferenceJobError>> {
+        self.runtime
+            .block_on(self.client.stop_icd10cm_inference_job(input))
+    }
+
+    /// Blocking version of [`ComprehendMedical::stop_phi_detection_job`].
+    pub fn stop_phi_detection_job(
+        &self,
+        input: StopPHIDetectionJobRequest,
+    ) -> Result<StopPHIDetectionJobResponse, RusotoError<StopPHIDetectionJobError>> {
+        self.runtime
+            .block_on(self.client.stop_phi_detection_job(input))
+    }
+
+    /// Blocking version of [`ComprehendMedical::stop_rx_norm_inference_job`].
+    pub fn stop_rx_norm_inference_job(
+        &self,
+        input: StopRxNormInferenceJobRequest,
+    ) -> Result<StopRxNormInferenceJobResponse, RusotoError<StopRxNormInferenceJobError>> {
+        self.runtime
+            .block_on(self.client.stop_rx_norm_inference_job(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -920,46 +921,70 @@ impl DescribeRecommendationExportJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        DescribeRecommendationExportJobsError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRecommendationExportJobsError::AccessDenied,
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
-                        DescribeRecommendationExportJobsError::InternalServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRecommendationExportJobsError::InternalServer,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DescribeRecommendationExportJobsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRecommendationExportJobsError::InvalidParameterValue,
                     )
                 }
                 "MissingAuthenticationToken" => {
-                    return RusotoError::Service(
-                        DescribeRecommendationExportJobsError::MissingAuthenticationToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRecommendationExportJobsError::MissingAuthenticationToken,
                     )
                 }
                 "OptInRequiredException" => {
-                    return RusotoError::Service(
-                        DescribeRecommendationExportJobsError::OptInRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRecommendationExportJobsError::OptInRequired,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeRecommendationExportJobsError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRecommendationExportJobsError::ResourceNotFound,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DescribeRecommendationExportJobsError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRecommendationExportJobsError::ServiceUnavailable,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeRecommendationExportJobsError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRecommendationExportJobsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1024,48 +1049,70 @@ impl ExportAutoScalingGroupRecommendationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        ExportAutoScalingGroupRecommendationsError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExportAutoScalingGroupRecommendationsError::AccessDenied,
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
-                        ExportAutoScalingGroupRecommendationsError::InternalServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExportAutoScalingGroupRecommendationsError::InternalServer,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        ExportAutoScalingGroupRecommendationsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExportAutoScalingGroupRecommendationsError::InvalidParameterValue,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
-                        ExportAutoScalingGroupRecommendationsError::LimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExportAutoScalingGroupRecommendationsError::LimitExceeded,
                     )
                 }
                 "MissingAuthenticationToken" => {
-                    return RusotoError::Service(
-                        ExportAutoScalingGroupRecommendationsError::MissingAuthenticationToken(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExportAutoScalingGroupRecommendationsError::MissingAuthenticationToken,
                     )
                 }
                 "OptInRequiredException" => {
-                    return RusotoError::Service(
-                        ExportAutoScalingGroupRecommendationsError::OptInRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExportAutoScalingGroupRecommendationsError::OptInRequired,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        ExportAutoScalingGroupRecommendationsError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExportAutoScalingGroupRecommendationsError::ServiceUnavailable,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
-                        ExportAutoScalingGroupRecommendationsError::Throttling(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExportAutoScalingGroupRecommendationsError::Throttling,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1132,46 +1179,70 @@ impl ExportEC2InstanceRecommendationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        ExportEC2InstanceRecommendationsError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExportEC2InstanceRecommendationsError::AccessDenied,
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
-                        ExportEC2InstanceRecommendationsError::InternalServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExportEC2InstanceRecommendationsError::InternalServer,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        ExportEC2InstanceRecommendationsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExportEC2InstanceRecommendationsError::InvalidParameterValue,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
-                        ExportEC2InstanceRecommendationsError::LimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExportEC2InstanceRecommendationsError::LimitExceeded,
                     )
                 }
                 "MissingAuthenticationToken" => {
-                    return RusotoError::Service(
-                        ExportEC2InstanceRecommendationsError::MissingAuthenticationToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExportEC2InstanceRecommendationsError::MissingAuthenticationToken,
                     )
                 }
                 "OptInRequiredException" => {
-                    return RusotoError::Service(
-                        ExportEC2InstanceRecommendationsError::OptInRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExportEC2InstanceRecommendationsError::OptInRequired,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        ExportEC2InstanceRecommendationsError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExportEC2InstanceRecommendationsError::ServiceUnavailable,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ExportEC2InstanceRecommendationsError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ExportEC2InstanceRecommendationsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1236,48 +1307,70 @@ impl GetAutoScalingGroupRecommendationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        GetAutoScalingGroupRecommendationsError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAutoScalingGroupRecommendationsError::AccessDenied,
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
-                        GetAutoScalingGroupRecommendationsError::InternalServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAutoScalingGroupRecommendationsError::InternalServer,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        GetAutoScalingGroupRecommendationsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAutoScalingGroupRecommendationsError::InvalidParameterValue,
                     )
                 }
                 "MissingAuthenticationToken" => {
-                    return RusotoError::Service(
-                        GetAutoScalingGroupRecommendationsError::MissingAuthenticationToken(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAutoScalingGroupRecommendationsError::MissingAuthenticationToken,
                     )
                 }
                 "OptInRequiredException" => {
-                    return RusotoError::Service(
-                        GetAutoScalingGroupRecommendationsError::OptInRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAutoScalingGroupRecommendationsError::OptInRequired,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        GetAutoScalingGroupRecommendationsError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAutoScalingGroupRecommendationsError::ResourceNotFound,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        GetAutoScalingGroupRecommendationsError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAutoScalingGroupRecommendationsError::ServiceUnavailable,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
-                        GetAutoScalingGroupRecommendationsError::Throttling(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAutoScalingGroupRecommendationsError::Throttling,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1344,46 +1437,70 @@ impl GetEBSVolumeRecommendationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetEBSVolumeRecommendationsError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetEBSVolumeRecommendationsError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetEBSVolumeRecommendationsError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetEBSVolumeRecommendationsError::InternalServer,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        GetEBSVolumeRecommendationsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEBSVolumeRecommendationsError::InvalidParameterValue,
                     )
                 }
                 "MissingAuthenticationToken" => {
-                    return RusotoError::Service(
-                        GetEBSVolumeRecommendationsError::MissingAuthenticationToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEBSVolumeRecommendationsError::MissingAuthenticationToken,
                     )
                 }
                 "OptInRequiredException" => {
-                    return RusotoError::Service(GetEBSVolumeRecommendationsError::OptInRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetEBSVolumeRecommendationsError::OptInRequired,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        GetEBSVolumeRecommendationsError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEBSVolumeRecommendationsError::ResourceNotFound,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        GetEBSVolumeRecommendationsError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEBSVolumeRecommendationsError::ServiceUnavailable,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetEBSVolumeRecommendationsError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetEBSVolumeRecommendationsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1440,46 +1557,70 @@ impl GetEC2InstanceRecommendationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetEC2InstanceRecommendationsError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetEC2InstanceRecommendationsError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
-                        GetEC2InstanceRecommendationsError::InternalServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEC2InstanceRecommendationsError::InternalServer,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        GetEC2InstanceRecommendationsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEC2InstanceRecommendationsError::InvalidParameterValue,
                     )
                 }
                 "MissingAuthenticationToken" => {
-                    return RusotoError::Service(
-                        GetEC2InstanceRecommendationsError::MissingAuthenticationToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEC2InstanceRecommendationsError::MissingAuthenticationToken,
                     )
                 }
                 "OptInRequiredException" => {
-                    return RusotoError::Service(GetEC2InstanceRecommendationsError::OptInRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetEC2InstanceRecommendationsError::OptInRequired,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        GetEC2InstanceRecommendationsError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEC2InstanceRecommendationsError::ResourceNotFound,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        GetEC2InstanceRecommendationsError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEC2InstanceRecommendationsError::ServiceUnavailable,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetEC2InstanceRecommendationsError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetEC2InstanceRecommendationsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1538,48 +1679,70 @@ impl GetEC2RecommendationProjectedMetricsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        GetEC2RecommendationProjectedMetricsError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEC2RecommendationProjectedMetricsError::AccessDenied,
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
-                        GetEC2RecommendationProjectedMetricsError::InternalServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEC2RecommendationProjectedMetricsError::InternalServer,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        GetEC2RecommendationProjectedMetricsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEC2RecommendationProjectedMetricsError::InvalidParameterValue,
                     )
                 }
                 "MissingAuthenticationToken" => {
-                    return RusotoError::Service(
-                        GetEC2RecommendationProjectedMetricsError::MissingAuthenticationToken(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEC2RecommendationProjectedMetricsError::MissingAuthenticationToken,
                     )
                 }
                 "OptInRequiredException" => {
-                    return RusotoError::Service(
-                        GetEC2RecommendationProjectedMetricsError::OptInRequired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEC2RecommendationProjectedMetricsError::OptInRequired,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        GetEC2RecommendationProjectedMetricsError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEC2RecommendationProjectedMetricsError::ResourceNotFound,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        GetEC2RecommendationProjectedMetricsError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEC2RecommendationProjectedMetricsError::ServiceUnavailable,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
-                        GetEC2RecommendationProjectedMetricsError::Throttling(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEC2RecommendationProjectedMetricsError::Throttling,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1640,30 +1803,54 @@ impl GetEnrollmentStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetEnrollmentStatusError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEnrollmentStatusError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetEnrollmentStatusError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEnrollmentStatusError::InternalServer,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetEnrollmentStatusError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetEnrollmentStatusError::InvalidParameterValue,
+                    )
                 }
                 "MissingAuthenticationToken" => {
-                    return RusotoError::Service(
-                        GetEnrollmentStatusError::MissingAuthenticationToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEnrollmentStatusError::MissingAuthenticationToken,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetEnrollmentStatusError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetEnrollmentStatusError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetEnrollmentStatusError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEnrollmentStatusError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1712,41 +1899,62 @@ impl GetRecommendationSummariesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetRecommendationSummariesError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRecommendationSummariesError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetRecommendationSummariesError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRecommendationSummariesError::InternalServer,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        GetRecommendationSummariesError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRecommendationSummariesError::InvalidParameterValue,
                     )
                 }
                 "MissingAuthenticationToken" => {
-                    return RusotoError::Service(
-                        GetRecommendationSummariesError::MissingAuthenticationToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRecommendationSummariesError::MissingAuthenticationToken,
                     )
                 }
                 "OptInRequiredException" => {
-                    return RusotoError::Service(GetRecommendationSummariesError::OptInRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRecommendationSummariesError::OptInRequired,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        GetRecommendationSummariesError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRecommendationSummariesError::ServiceUnavailable,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetRecommendationSummariesError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRecommendationSummariesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1796,32 +2004,54 @@ impl UpdateEnrollmentStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateEnrollmentStatusError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateEnrollmentStatusError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateEnrollmentStatusError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateEnrollmentStatusError::InternalServer,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        UpdateEnrollmentStatusError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateEnrollmentStatusError::InvalidParameterValue,
                     )
                 }
                 "MissingAuthenticationToken" => {
-                    return RusotoError::Service(
-                        UpdateEnrollmentStatusError::MissingAuthenticationToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateEnrollmentStatusError::MissingAuthenticationToken,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateEnrollmentStatusError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateEnrollmentStatusError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateEnrollmentStatusError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateEnrollmentStatusError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1934,11 +2164,12 @@ pub struct ComputeOptimizerClient {
 impl ComputeOptimizerClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> ComputeOptimizerClient {
         ComputeOptimizerClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Compute Optimizer"),
         }
     }
 
@@ -1953,12 +2184,32 @@ impl ComputeOptimizerClient {
     {
         ComputeOptimizerClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Compute Optimizer"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ComputeOptimizerClient {
-        ComputeOptimizerClient { client, region }
+        ComputeOptimizerClient {
+            client,
+            region: region_for_service(region, "Compute Optimizer"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> ComputeOptimizerClient {
+        ComputeOptimizerClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Compute Optimizer"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ComputeOptimizerClient {
+        ComputeOptimizerClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -1973,6 +2224,7 @@ impl ComputeOptimizer for ComputeOptimizerClient {
         RusotoError<DescribeRecommendationExportJobsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeRecommendationExportJobs");
         request.add_header(
             "x-amz-target",
             "ComputeOptimizerService.DescribeRecommendationExportJobs",
@@ -2001,6 +2253,7 @@ impl ComputeOptimizer for ComputeOptimizerClient {
         RusotoError<ExportAutoScalingGroupRecommendationsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ExportAutoScalingGroupRecommendations");
         request.add_header(
             "x-amz-target",
             "ComputeOptimizerService.ExportAutoScalingGroupRecommendations",
@@ -2029,6 +2282,7 @@ impl ComputeOptimizer for ComputeOptimizerClient {
         RusotoError<ExportEC2InstanceRecommendationsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ExportEC2InstanceRecommendations");
         request.add_header(
             "x-amz-target",
             "ComputeOptimizerService.ExportEC2InstanceRecommendations",
@@ -2057,6 +2311,7 @@ impl ComputeOptimizer for ComputeOptimizerClient {
         RusotoError<GetAutoScalingGroupRecommendationsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetAutoScalingGroupRecommendations");
         request.add_header(
             "x-amz-target",
             "ComputeOptimizerService.GetAutoScalingGroupRecommendations",
@@ -2083,6 +2338,7 @@ impl ComputeOptimizer for ComputeOptimizerClient {
     ) -> Result<GetEBSVolumeRecommendationsResponse, RusotoError<GetEBSVolumeRecommendationsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetEBSVolumeRecommendations");
         request.add_header(
             "x-amz-target",
             "ComputeOptimizerService.GetEBSVolumeRecommendations",
@@ -2108,6 +2364,7 @@ impl ComputeOptimizer for ComputeOptimizerClient {
         RusotoError<GetEC2InstanceRecommendationsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetEC2InstanceRecommendations");
         request.add_header(
             "x-amz-target",
             "ComputeOptimizerService.GetEC2InstanceRecommendations",
@@ -2133,6 +2390,7 @@ impl ComputeOptimizer for ComputeOptimizerClient {
         RusotoError<GetEC2RecommendationProjectedMetricsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetEC2RecommendationProjectedMetrics");
         request.add_header(
             "x-amz-target",
             "ComputeOptimizerService.GetEC2RecommendationProjectedMetrics",
@@ -2157,6 +2415,7 @@ impl ComputeOptimizer for ComputeOptimizerClient {
         &self,
     ) -> Result<GetEnrollmentStatusResponse, RusotoError<GetEnrollmentStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetEnrollmentStatus");
         request.add_header(
             "x-amz-target",
             "ComputeOptimizerService.GetEnrollmentStatus",
@@ -2178,6 +2437,7 @@ impl ComputeOptimizer for ComputeOptimizerClient {
     ) -> Result<GetRecommendationSummariesResponse, RusotoError<GetRecommendationSummariesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRecommendationSummaries");
         request.add_header(
             "x-amz-target",
             "ComputeOptimizerService.GetRecommendationSummaries",
@@ -2200,6 +2460,7 @@ impl ComputeOptimizer for ComputeOptimizerClient {
         input: UpdateEnrollmentStatusRequest,
     ) -> Result<UpdateEnrollmentStatusResponse, RusotoError<UpdateEnrollmentStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateEnrollmentStatus");
         request.add_header(
             "x-amz-target",
             "ComputeOptimizerService.UpdateEnrollmentStatus",
@@ -2216,3 +2477,194 @@ impl ComputeOptimizer for ComputeOptimizerClient {
             .deserialize::<UpdateEnrollmentStatusResponse, _>()
     }
 }
+
+/// A blocking client for the AWS Compute Optimizer API, running the calls of a `ComputeOptimizerClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `ComputeOptimizerClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ComputeOptimizerBlockingClient {
+    client: ComputeOptimizerClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ComputeOptimizerBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> ComputeOptimizerBlockingClient {
+        ComputeOptimizerBlockingClient::from_client(ComputeOptimizerClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ComputeOptimizerBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ComputeOptimizerBlockingClient::from_client(ComputeOptimizerClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> ComputeOptimizerBlockingClient {
+        ComputeOptimizerBlockingClient::from_client(ComputeOptimizerClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> ComputeOptimizerBlockingClient {
+        ComputeOptimizerBlockingClient::from_client(ComputeOptimizerClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: ComputeOptimizerClient) -> ComputeOptimizerBlockingClient {
+        ComputeOptimizerBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> ComputeOptimizerBlockingClient {
+        ComputeOptimizerBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ComputeOptimizerBlockingClient {
+        ComputeOptimizerBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`ComputeOptimizer::describe_recommendation_export_jobs`].
+    pub fn describe_recommendation_export_jobs(
+        &self,
+        input: DescribeRecommendationExportJobsRequest,
+    ) -> Result<
+        DescribeRecommendationExportJobsResponse,
+        RusotoError<DescribeRecommendationExportJobsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_recommendation_export_jobs(input))
+    }
+
+    /// Blocking version of [`ComputeOptimizer::export_auto_scaling_group_recommendations`].
+    pub fn export_auto_scaling_group_recommendations(
+        &self,
+        input: ExportAutoScalingGroupRecommendationsRequest,
+    ) -> Result<
+        ExportAutoScalingGroupRecommendationsResponse,
+        RusotoError<ExportAutoScalingGroupRecommendationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.export_auto_scaling_group_recommendations(input))
+    }
+
+    /// Blocking version of [`ComputeOptimizer::export_ec2_instance_recommendations`].
+    pub fn export_ec2_instance_recommendations(
+        &self,
+        input: ExportEC2InstanceRecommendationsRequest,
+    ) -> Result<
+        ExportEC2InstanceRecommendationsResponse,
+        RusotoError<ExportEC2InstanceRecommendationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.export_ec2_instance_recommendations(input))
+    }
+
+    /// Blocking version of [`ComputeOptimizer::get_auto_scaling_group_recommendations`].
+    pub fn get_auto_scaling_group_recommendations(
+        &self,
+        input: GetAutoScalingGroupRecommendationsRequest,
+    ) -> Result<
+        GetAutoScalingGroupRecommendationsResponse,
+        RusotoError<GetAutoScalingGroupRecommendationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_auto_scaling_group_recommendations(input))
+    }
+
+    /// Blocking version of [`ComputeOptimizer::get_ebs_volume_recommendations`].
+    pub fn get_ebs_volume_recommendations(
+        &self,
+        input: GetEBSVolumeRecommendationsRequest,
+    ) -> Result<GetEBSVolumeRecommendationsResponse, RusotoError<GetEBSVolumeRecommendationsError>>
+    {
+        self.runtime
+            .block_on(self.client.get_ebs_volume_recommendations(input))
+    }
+
+    /// Blocking version of [`ComputeOptimizer::get_ec2_instance_recommendations`].
+    pub fn get_ec2_instance_recommendations(
+        &self,
+        input: GetEC2InstanceRecommendationsRequest,
+    ) -> Result<
+        GetEC2InstanceRecommendationsResponse,
+        RusotoError<GetEC2InstanceRecommendationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_ec2_instance_recommendations(input))
+    }
+
+    /// Blocking version of [`ComputeOptimizer::get_ec2_recommendation_projected_metrics`].
+    pub fn get_ec2_recommendation_projected_metrics(
+        &self,
+        input: GetEC2RecommendationProjectedMetricsRequest,
+    ) -> Result<
+        GetEC2RecommendationProjectedMetricsResponse,
+        RusotoError<GetEC2RecommendationProjectedMetricsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_ec2_recommendation_projected_metrics(input))
+    }
+
+    /// Blocking version of [`ComputeOptimizer::get_enrollment_status`].
+    pub fn get_enrollment_status(
+        &self,
+    ) -> Result<GetEnrollmentStatusResponse, RusotoError<GetEnrollmentStatusError>> {
+        self.runtime.block_on(self.client.get_enrollment_status())
+    }
+
+    /// Blocking version of [`ComputeOptimizer::get_recommendation_summaries`].
+    pub fn get_recommendation_summaries(
+        &self,
+        input: GetRecommendationSummariesRequest,
+    ) -> Result<GetRecommendationSummariesResponse, RusotoError<GetRecommendationSummariesError>>
+    {
+        self.runtime
+            .block_on(self.client.get_recommendation_summaries(input))
+    }
+
+    /// Blocking version of [`ComputeOptimizer::update_enrollment_status`].
+    pub fn update_enrollment_status(
+        &self,
+        input: UpdateEnrollmentStatusRequest,
+    ) -> Result<UpdateEnrollmentStatusResponse, RusotoError<UpdateEnrollmentStatusError>> {
+        self.runtime
+            .block_on(self.client.update_enrollment_status(input))
+    }
+}
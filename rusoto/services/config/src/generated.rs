@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -3861,13 +3862,14 @@ impl BatchGetAggregateResourceConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchConfigurationAggregatorException" => {
-                    return RusotoError::Service(
-                        BatchGetAggregateResourceConfigError::NoSuchConfigurationAggregator(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetAggregateResourceConfigError::NoSuchConfigurationAggregator,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3897,11 +3899,14 @@ impl BatchGetResourceConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoAvailableConfigurationRecorderException" => {
-                    return RusotoError::Service(
-                        BatchGetResourceConfigError::NoAvailableConfigurationRecorder(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetResourceConfigError::NoAvailableConfigurationRecorder,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3933,11 +3938,14 @@ impl DeleteAggregationAuthorizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DeleteAggregationAuthorizationError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAggregationAuthorizationError::InvalidParameterValue,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3969,12 +3977,22 @@ impl DeleteConfigRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchConfigRuleException" => {
-                    return RusotoError::Service(DeleteConfigRuleError::NoSuchConfigRule(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteConfigRuleError::NoSuchConfigRule,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteConfigRuleError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteConfigRuleError::ResourceInUse,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4005,11 +4023,14 @@ impl DeleteConfigurationAggregatorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchConfigurationAggregatorException" => {
-                    return RusotoError::Service(
-                        DeleteConfigurationAggregatorError::NoSuchConfigurationAggregator(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteConfigurationAggregatorError::NoSuchConfigurationAggregator,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4041,11 +4062,14 @@ impl DeleteConfigurationRecorderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchConfigurationRecorderException" => {
-                    return RusotoError::Service(
-                        DeleteConfigurationRecorderError::NoSuchConfigurationRecorder(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteConfigurationRecorderError::NoSuchConfigurationRecorder,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4077,14 +4101,22 @@ impl DeleteConformancePackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchConformancePackException" => {
-                    return RusotoError::Service(DeleteConformancePackError::NoSuchConformancePack(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteConformancePackError::NoSuchConformancePack,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteConformancePackError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteConformancePackError::ResourceInUse,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4115,16 +4147,22 @@ impl DeleteDeliveryChannelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LastDeliveryChannelDeleteFailedException" => {
-                    return RusotoError::Service(
-                        DeleteDeliveryChannelError::LastDeliveryChannelDeleteFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDeliveryChannelError::LastDeliveryChannelDeleteFailed,
                     )
                 }
                 "NoSuchDeliveryChannelException" => {
-                    return RusotoError::Service(DeleteDeliveryChannelError::NoSuchDeliveryChannel(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDeliveryChannelError::NoSuchDeliveryChannel,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4157,16 +4195,22 @@ impl DeleteEvaluationResultsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchConfigRuleException" => {
-                    return RusotoError::Service(DeleteEvaluationResultsError::NoSuchConfigRule(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteEvaluationResultsError::NoSuchConfigRule,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteEvaluationResultsError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteEvaluationResultsError::ResourceInUse,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4201,21 +4245,30 @@ impl DeleteOrganizationConfigRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchOrganizationConfigRuleException" => {
-                    return RusotoError::Service(
-                        DeleteOrganizationConfigRuleError::NoSuchOrganizationConfigRule(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteOrganizationConfigRuleError::NoSuchOrganizationConfigRule,
                     )
                 }
                 "OrganizationAccessDeniedException" => {
-                    return RusotoError::Service(
-                        DeleteOrganizationConfigRuleError::OrganizationAccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteOrganizationConfigRuleError::OrganizationAccessDenied,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteOrganizationConfigRuleError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteOrganizationConfigRuleError::ResourceInUse,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4255,23 +4308,30 @@ impl DeleteOrganizationConformancePackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchOrganizationConformancePackException" => {
-                    return RusotoError::Service(
-                        DeleteOrganizationConformancePackError::NoSuchOrganizationConformancePack(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteOrganizationConformancePackError::NoSuchOrganizationConformancePack,
                     )
                 }
                 "OrganizationAccessDeniedException" => {
-                    return RusotoError::Service(
-                        DeleteOrganizationConformancePackError::OrganizationAccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteOrganizationConformancePackError::OrganizationAccessDenied,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
-                        DeleteOrganizationConformancePackError::ResourceInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteOrganizationConformancePackError::ResourceInUse,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4309,11 +4369,14 @@ impl DeletePendingAggregationRequestError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DeletePendingAggregationRequestError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePendingAggregationRequestError::InvalidParameterValue,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4349,23 +4412,30 @@ impl DeleteRemediationConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InsufficientPermissionsException" => {
-                    return RusotoError::Service(
-                        DeleteRemediationConfigurationError::InsufficientPermissions(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRemediationConfigurationError::InsufficientPermissions,
                     )
                 }
                 "NoSuchRemediationConfigurationException" => {
-                    return RusotoError::Service(
-                        DeleteRemediationConfigurationError::NoSuchRemediationConfiguration(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRemediationConfigurationError::NoSuchRemediationConfiguration,
                     )
                 }
                 "RemediationInProgressException" => {
-                    return RusotoError::Service(
-                        DeleteRemediationConfigurationError::RemediationInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRemediationConfigurationError::RemediationInProgress,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4403,11 +4473,14 @@ impl DeleteRemediationExceptionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchRemediationExceptionException" => {
-                    return RusotoError::Service(
-                        DeleteRemediationExceptionsError::NoSuchRemediation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRemediationExceptionsError::NoSuchRemediation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4437,11 +4510,14 @@ impl DeleteResourceConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoRunningConfigurationRecorderException" => {
-                    return RusotoError::Service(
-                        DeleteResourceConfigError::NoRunningConfigurationRecorder(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteResourceConfigError::NoRunningConfigurationRecorder,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4475,16 +4551,22 @@ impl DeleteRetentionConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DeleteRetentionConfigurationError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRetentionConfigurationError::InvalidParameterValue,
                     )
                 }
                 "NoSuchRetentionConfigurationException" => {
-                    return RusotoError::Service(
-                        DeleteRetentionConfigurationError::NoSuchRetentionConfiguration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRetentionConfigurationError::NoSuchRetentionConfiguration,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4517,9 +4599,14 @@ impl DeleteStoredQueryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteStoredQueryError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteStoredQueryError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4551,21 +4638,30 @@ impl DeliverConfigSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoAvailableConfigurationRecorderException" => {
-                    return RusotoError::Service(
-                        DeliverConfigSnapshotError::NoAvailableConfigurationRecorder(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeliverConfigSnapshotError::NoAvailableConfigurationRecorder,
                     )
                 }
                 "NoRunningConfigurationRecorderException" => {
-                    return RusotoError::Service(
-                        DeliverConfigSnapshotError::NoRunningConfigurationRecorder(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeliverConfigSnapshotError::NoRunningConfigurationRecorder,
                     )
                 }
                 "NoSuchDeliveryChannelException" => {
-                    return RusotoError::Service(DeliverConfigSnapshotError::NoSuchDeliveryChannel(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeliverConfigSnapshotError::NoSuchDeliveryChannel,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4605,21 +4701,28 @@ impl DescribeAggregateComplianceByConfigRulesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
-                        DescribeAggregateComplianceByConfigRulesError::InvalidLimit(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAggregateComplianceByConfigRulesError::InvalidLimit,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        DescribeAggregateComplianceByConfigRulesError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAggregateComplianceByConfigRulesError::InvalidNextToken,
                     )
                 }
-                "NoSuchConfigurationAggregatorException" => return RusotoError::Service(
-                    DescribeAggregateComplianceByConfigRulesError::NoSuchConfigurationAggregator(
-                        err.msg,
-                    ),
+                "NoSuchConfigurationAggregatorException" => return RusotoError::service_error(
+                    &res,
+                    &err.typ,
+                    err.msg,
+                    DescribeAggregateComplianceByConfigRulesError::NoSuchConfigurationAggregator,
                 ),
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4661,21 +4764,30 @@ impl DescribeAggregationAuthorizationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
-                        DescribeAggregationAuthorizationsError::InvalidLimit(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAggregationAuthorizationsError::InvalidLimit,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        DescribeAggregationAuthorizationsError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAggregationAuthorizationsError::InvalidNextToken,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DescribeAggregationAuthorizationsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAggregationAuthorizationsError::InvalidParameterValue,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4717,21 +4829,30 @@ impl DescribeComplianceByConfigRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        DescribeComplianceByConfigRuleError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeComplianceByConfigRuleError::InvalidNextToken,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DescribeComplianceByConfigRuleError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeComplianceByConfigRuleError::InvalidParameterValue,
                     )
                 }
                 "NoSuchConfigRuleException" => {
-                    return RusotoError::Service(
-                        DescribeComplianceByConfigRuleError::NoSuchConfigRule(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeComplianceByConfigRuleError::NoSuchConfigRule,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4771,16 +4892,22 @@ impl DescribeComplianceByResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        DescribeComplianceByResourceError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeComplianceByResourceError::InvalidNextToken,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DescribeComplianceByResourceError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeComplianceByResourceError::InvalidParameterValue,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4819,21 +4946,30 @@ impl DescribeConfigRuleEvaluationStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        DescribeConfigRuleEvaluationStatusError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeConfigRuleEvaluationStatusError::InvalidNextToken,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DescribeConfigRuleEvaluationStatusError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeConfigRuleEvaluationStatusError::InvalidParameterValue,
                     )
                 }
                 "NoSuchConfigRuleException" => {
-                    return RusotoError::Service(
-                        DescribeConfigRuleEvaluationStatusError::NoSuchConfigRule(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeConfigRuleEvaluationStatusError::NoSuchConfigRule,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4871,16 +5007,22 @@ impl DescribeConfigRulesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeConfigRulesError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeConfigRulesError::InvalidNextToken,
+                    )
                 }
                 "NoSuchConfigRuleException" => {
-                    return RusotoError::Service(DescribeConfigRulesError::NoSuchConfigRule(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeConfigRulesError::NoSuchConfigRule,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4916,11 +5058,11 @@ impl DescribeConfigurationAggregatorSourcesStatusError {
     ) -> RusotoError<DescribeConfigurationAggregatorSourcesStatusError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                                "InvalidLimitException" => return RusotoError::Service(DescribeConfigurationAggregatorSourcesStatusError::InvalidLimit(err.msg)),
-"InvalidNextTokenException" => return RusotoError::Service(DescribeConfigurationAggregatorSourcesStatusError::InvalidNextToken(err.msg)),
-"InvalidParameterValueException" => return RusotoError::Service(DescribeConfigurationAggregatorSourcesStatusError::InvalidParameterValue(err.msg)),
-"NoSuchConfigurationAggregatorException" => return RusotoError::Service(DescribeConfigurationAggregatorSourcesStatusError::NoSuchConfigurationAggregator(err.msg)),
-"ValidationException" => return RusotoError::Validation(err.msg),
+                                "InvalidLimitException" => return RusotoError::service_error(&res, &err.typ, err.msg, DescribeConfigurationAggregatorSourcesStatusError::InvalidLimit),
+"InvalidNextTokenException" => return RusotoError::service_error(&res, &err.typ, err.msg, DescribeConfigurationAggregatorSourcesStatusError::InvalidNextToken),
+"InvalidParameterValueException" => return RusotoError::service_error(&res, &err.typ, err.msg, DescribeConfigurationAggregatorSourcesStatusError::InvalidParameterValue),
+"NoSuchConfigurationAggregatorException" => return RusotoError::service_error(&res, &err.typ, err.msg, DescribeConfigurationAggregatorSourcesStatusError::NoSuchConfigurationAggregator),
+"ValidationException" => return RusotoError::validation_error(&res, err.msg),
 _ => {}
                             }
         }
@@ -4967,28 +5109,38 @@ impl DescribeConfigurationAggregatorsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
-                        DescribeConfigurationAggregatorsError::InvalidLimit(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeConfigurationAggregatorsError::InvalidLimit,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        DescribeConfigurationAggregatorsError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeConfigurationAggregatorsError::InvalidNextToken,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DescribeConfigurationAggregatorsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeConfigurationAggregatorsError::InvalidParameterValue,
                     )
                 }
                 "NoSuchConfigurationAggregatorException" => {
-                    return RusotoError::Service(
-                        DescribeConfigurationAggregatorsError::NoSuchConfigurationAggregator(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeConfigurationAggregatorsError::NoSuchConfigurationAggregator,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5029,13 +5181,14 @@ impl DescribeConfigurationRecorderStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchConfigurationRecorderException" => {
-                    return RusotoError::Service(
-                        DescribeConfigurationRecorderStatusError::NoSuchConfigurationRecorder(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeConfigurationRecorderStatusError::NoSuchConfigurationRecorder,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5067,11 +5220,14 @@ impl DescribeConfigurationRecordersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchConfigurationRecorderException" => {
-                    return RusotoError::Service(
-                        DescribeConfigurationRecordersError::NoSuchConfigurationRecorder(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeConfigurationRecordersError::NoSuchConfigurationRecorder,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5111,33 +5267,46 @@ impl DescribeConformancePackComplianceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
-                        DescribeConformancePackComplianceError::InvalidLimit(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeConformancePackComplianceError::InvalidLimit,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        DescribeConformancePackComplianceError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeConformancePackComplianceError::InvalidNextToken,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DescribeConformancePackComplianceError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeConformancePackComplianceError::InvalidParameterValue,
                     )
                 }
                 "NoSuchConfigRuleInConformancePackException" => {
-                    return RusotoError::Service(
-                        DescribeConformancePackComplianceError::NoSuchConfigRuleInConformancePack(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeConformancePackComplianceError::NoSuchConfigRuleInConformancePack,
                     )
                 }
                 "NoSuchConformancePackException" => {
-                    return RusotoError::Service(
-                        DescribeConformancePackComplianceError::NoSuchConformancePack(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeConformancePackComplianceError::NoSuchConformancePack,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5185,21 +5354,30 @@ impl DescribeConformancePackStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(DescribeConformancePackStatusError::InvalidLimit(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeConformancePackStatusError::InvalidLimit,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        DescribeConformancePackStatusError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeConformancePackStatusError::InvalidNextToken,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DescribeConformancePackStatusError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeConformancePackStatusError::InvalidParameterValue,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5239,26 +5417,38 @@ impl DescribeConformancePacksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(DescribeConformancePacksError::InvalidLimit(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeConformancePacksError::InvalidLimit,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeConformancePacksError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeConformancePacksError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DescribeConformancePacksError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeConformancePacksError::InvalidParameterValue,
                     )
                 }
                 "NoSuchConformancePackException" => {
-                    return RusotoError::Service(
-                        DescribeConformancePacksError::NoSuchConformancePack(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeConformancePacksError::NoSuchConformancePack,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5295,11 +5485,14 @@ impl DescribeDeliveryChannelStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchDeliveryChannelException" => {
-                    return RusotoError::Service(
-                        DescribeDeliveryChannelStatusError::NoSuchDeliveryChannel(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDeliveryChannelStatusError::NoSuchDeliveryChannel,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5329,11 +5522,14 @@ impl DescribeDeliveryChannelsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchDeliveryChannelException" => {
-                    return RusotoError::Service(
-                        DescribeDeliveryChannelsError::NoSuchDeliveryChannel(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDeliveryChannelsError::NoSuchDeliveryChannel,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5371,30 +5567,38 @@ impl DescribeOrganizationConfigRuleStatusesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
-                        DescribeOrganizationConfigRuleStatusesError::InvalidLimit(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeOrganizationConfigRuleStatusesError::InvalidLimit,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        DescribeOrganizationConfigRuleStatusesError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeOrganizationConfigRuleStatusesError::InvalidNextToken,
                     )
                 }
                 "NoSuchOrganizationConfigRuleException" => {
-                    return RusotoError::Service(
-                        DescribeOrganizationConfigRuleStatusesError::NoSuchOrganizationConfigRule(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeOrganizationConfigRuleStatusesError::NoSuchOrganizationConfigRule,
                     )
                 }
                 "OrganizationAccessDeniedException" => {
-                    return RusotoError::Service(
-                        DescribeOrganizationConfigRuleStatusesError::OrganizationAccessDenied(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeOrganizationConfigRuleStatusesError::OrganizationAccessDenied,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5441,26 +5645,38 @@ impl DescribeOrganizationConfigRulesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
-                        DescribeOrganizationConfigRulesError::InvalidLimit(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeOrganizationConfigRulesError::InvalidLimit,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        DescribeOrganizationConfigRulesError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeOrganizationConfigRulesError::InvalidNextToken,
                     )
                 }
                 "NoSuchOrganizationConfigRuleException" => {
-                    return RusotoError::Service(
-                        DescribeOrganizationConfigRulesError::NoSuchOrganizationConfigRule(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeOrganizationConfigRulesError::NoSuchOrganizationConfigRule,
                     )
                 }
                 "OrganizationAccessDeniedException" => {
-                    return RusotoError::Service(
-                        DescribeOrganizationConfigRulesError::OrganizationAccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeOrganizationConfigRulesError::OrganizationAccessDenied,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5504,11 +5720,11 @@ impl DescribeOrganizationConformancePackStatusesError {
     ) -> RusotoError<DescribeOrganizationConformancePackStatusesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                                "InvalidLimitException" => return RusotoError::Service(DescribeOrganizationConformancePackStatusesError::InvalidLimit(err.msg)),
-"InvalidNextTokenException" => return RusotoError::Service(DescribeOrganizationConformancePackStatusesError::InvalidNextToken(err.msg)),
-"NoSuchOrganizationConformancePackException" => return RusotoError::Service(DescribeOrganizationConformancePackStatusesError::NoSuchOrganizationConformancePack(err.msg)),
-"OrganizationAccessDeniedException" => return RusotoError::Service(DescribeOrganizationConformancePackStatusesError::OrganizationAccessDenied(err.msg)),
-"ValidationException" => return RusotoError::Validation(err.msg),
+                                "InvalidLimitException" => return RusotoError::service_error(&res, &err.typ, err.msg, DescribeOrganizationConformancePackStatusesError::InvalidLimit),
+"InvalidNextTokenException" => return RusotoError::service_error(&res, &err.typ, err.msg, DescribeOrganizationConformancePackStatusesError::InvalidNextToken),
+"NoSuchOrganizationConformancePackException" => return RusotoError::service_error(&res, &err.typ, err.msg, DescribeOrganizationConformancePackStatusesError::NoSuchOrganizationConformancePack),
+"OrganizationAccessDeniedException" => return RusotoError::service_error(&res, &err.typ, err.msg, DescribeOrganizationConformancePackStatusesError::OrganizationAccessDenied),
+"ValidationException" => return RusotoError::validation_error(&res, err.msg),
 _ => {}
                             }
         }
@@ -5555,28 +5771,36 @@ impl DescribeOrganizationConformancePacksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
-                        DescribeOrganizationConformancePacksError::InvalidLimit(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeOrganizationConformancePacksError::InvalidLimit,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        DescribeOrganizationConformancePacksError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeOrganizationConformancePacksError::InvalidNextToken,
                     )
                 }
-                "NoSuchOrganizationConformancePackException" => return RusotoError::Service(
-                    DescribeOrganizationConformancePacksError::NoSuchOrganizationConformancePack(
-                        err.msg,
-                    ),
+                "NoSuchOrganizationConformancePackException" => return RusotoError::service_error(
+                    &res,
+                    &err.typ,
+                    err.msg,
+                    DescribeOrganizationConformancePacksError::NoSuchOrganizationConformancePack,
                 ),
                 "OrganizationAccessDeniedException" => {
-                    return RusotoError::Service(
-                        DescribeOrganizationConformancePacksError::OrganizationAccessDenied(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeOrganizationConformancePacksError::OrganizationAccessDenied,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5621,21 +5845,30 @@ impl DescribePendingAggregationRequestsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
-                        DescribePendingAggregationRequestsError::InvalidLimit(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePendingAggregationRequestsError::InvalidLimit,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        DescribePendingAggregationRequestsError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePendingAggregationRequestsError::InvalidNextToken,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DescribePendingAggregationRequestsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePendingAggregationRequestsError::InvalidParameterValue,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5669,7 +5902,7 @@ impl DescribeRemediationConfigurationsError {
     ) -> RusotoError<DescribeRemediationConfigurationsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5699,16 +5932,22 @@ impl DescribeRemediationExceptionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        DescribeRemediationExceptionsError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRemediationExceptionsError::InvalidNextToken,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DescribeRemediationExceptionsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRemediationExceptionsError::InvalidParameterValue,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5745,18 +5984,22 @@ impl DescribeRemediationExecutionStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        DescribeRemediationExecutionStatusError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRemediationExecutionStatusError::InvalidNextToken,
                     )
                 }
                 "NoSuchRemediationConfigurationException" => {
-                    return RusotoError::Service(
-                        DescribeRemediationExecutionStatusError::NoSuchRemediationConfiguration(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRemediationExecutionStatusError::NoSuchRemediationConfiguration,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5795,21 +6038,30 @@ impl DescribeRetentionConfigurationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        DescribeRetentionConfigurationsError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRetentionConfigurationsError::InvalidNextToken,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DescribeRetentionConfigurationsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRetentionConfigurationsError::InvalidParameterValue,
                     )
                 }
                 "NoSuchRetentionConfigurationException" => {
-                    return RusotoError::Service(
-                        DescribeRetentionConfigurationsError::NoSuchRetentionConfiguration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRetentionConfigurationsError::NoSuchRetentionConfiguration,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5851,21 +6103,28 @@ impl GetAggregateComplianceDetailsByConfigRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
-                        GetAggregateComplianceDetailsByConfigRuleError::InvalidLimit(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAggregateComplianceDetailsByConfigRuleError::InvalidLimit,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        GetAggregateComplianceDetailsByConfigRuleError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAggregateComplianceDetailsByConfigRuleError::InvalidNextToken,
                     )
                 }
-                "NoSuchConfigurationAggregatorException" => return RusotoError::Service(
-                    GetAggregateComplianceDetailsByConfigRuleError::NoSuchConfigurationAggregator(
-                        err.msg,
-                    ),
+                "NoSuchConfigurationAggregatorException" => return RusotoError::service_error(
+                    &res,
+                    &err.typ,
+                    err.msg,
+                    GetAggregateComplianceDetailsByConfigRuleError::NoSuchConfigurationAggregator,
                 ),
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5907,23 +6166,30 @@ impl GetAggregateConfigRuleComplianceSummaryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
-                        GetAggregateConfigRuleComplianceSummaryError::InvalidLimit(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAggregateConfigRuleComplianceSummaryError::InvalidLimit,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        GetAggregateConfigRuleComplianceSummaryError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAggregateConfigRuleComplianceSummaryError::InvalidNextToken,
                     )
                 }
                 "NoSuchConfigurationAggregatorException" => {
-                    return RusotoError::Service(
-                        GetAggregateConfigRuleComplianceSummaryError::NoSuchConfigurationAggregator(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAggregateConfigRuleComplianceSummaryError::NoSuchConfigurationAggregator,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5965,23 +6231,30 @@ impl GetAggregateDiscoveredResourceCountsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
-                        GetAggregateDiscoveredResourceCountsError::InvalidLimit(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAggregateDiscoveredResourceCountsError::InvalidLimit,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        GetAggregateDiscoveredResourceCountsError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAggregateDiscoveredResourceCountsError::InvalidNextToken,
                     )
                 }
                 "NoSuchConfigurationAggregatorException" => {
-                    return RusotoError::Service(
-                        GetAggregateDiscoveredResourceCountsError::NoSuchConfigurationAggregator(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAggregateDiscoveredResourceCountsError::NoSuchConfigurationAggregator,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6023,21 +6296,30 @@ impl GetAggregateResourceConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchConfigurationAggregatorException" => {
-                    return RusotoError::Service(
-                        GetAggregateResourceConfigError::NoSuchConfigurationAggregator(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAggregateResourceConfigError::NoSuchConfigurationAggregator,
                     )
                 }
                 "OversizedConfigurationItemException" => {
-                    return RusotoError::Service(
-                        GetAggregateResourceConfigError::OversizedConfigurationItem(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAggregateResourceConfigError::OversizedConfigurationItem,
                     )
                 }
                 "ResourceNotDiscoveredException" => {
-                    return RusotoError::Service(
-                        GetAggregateResourceConfigError::ResourceNotDiscovered(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAggregateResourceConfigError::ResourceNotDiscovered,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6079,21 +6361,30 @@ impl GetComplianceDetailsByConfigRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        GetComplianceDetailsByConfigRuleError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetComplianceDetailsByConfigRuleError::InvalidNextToken,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        GetComplianceDetailsByConfigRuleError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetComplianceDetailsByConfigRuleError::InvalidParameterValue,
                     )
                 }
                 "NoSuchConfigRuleException" => {
-                    return RusotoError::Service(
-                        GetComplianceDetailsByConfigRuleError::NoSuchConfigRule(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetComplianceDetailsByConfigRuleError::NoSuchConfigRule,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6131,11 +6422,14 @@ impl GetComplianceDetailsByResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        GetComplianceDetailsByResourceError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetComplianceDetailsByResourceError::InvalidParameterValue,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6163,7 +6457,7 @@ impl GetComplianceSummaryByConfigRuleError {
     ) -> RusotoError<GetComplianceSummaryByConfigRuleError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6191,11 +6485,14 @@ impl GetComplianceSummaryByResourceTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        GetComplianceSummaryByResourceTypeError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetComplianceSummaryByResourceTypeError::InvalidParameterValue,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6235,33 +6532,46 @@ impl GetConformancePackComplianceDetailsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
-                        GetConformancePackComplianceDetailsError::InvalidLimit(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConformancePackComplianceDetailsError::InvalidLimit,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        GetConformancePackComplianceDetailsError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConformancePackComplianceDetailsError::InvalidNextToken,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        GetConformancePackComplianceDetailsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConformancePackComplianceDetailsError::InvalidParameterValue,
                     )
                 }
                 "NoSuchConfigRuleInConformancePackException" => {
-                    return RusotoError::Service(
-                        GetConformancePackComplianceDetailsError::NoSuchConfigRuleInConformancePack(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConformancePackComplianceDetailsError::NoSuchConfigRuleInConformancePack,
                     )
                 }
                 "NoSuchConformancePackException" => {
-                    return RusotoError::Service(
-                        GetConformancePackComplianceDetailsError::NoSuchConformancePack(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConformancePackComplianceDetailsError::NoSuchConformancePack,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6309,21 +6619,30 @@ impl GetConformancePackComplianceSummaryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
-                        GetConformancePackComplianceSummaryError::InvalidLimit(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConformancePackComplianceSummaryError::InvalidLimit,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        GetConformancePackComplianceSummaryError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConformancePackComplianceSummaryError::InvalidNextToken,
                     )
                 }
                 "NoSuchConformancePackException" => {
-                    return RusotoError::Service(
-                        GetConformancePackComplianceSummaryError::NoSuchConformancePack(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConformancePackComplianceSummaryError::NoSuchConformancePack,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6363,16 +6682,22 @@ impl GetDiscoveredResourceCountsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(GetDiscoveredResourceCountsError::InvalidLimit(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDiscoveredResourceCountsError::InvalidLimit,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        GetDiscoveredResourceCountsError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDiscoveredResourceCountsError::InvalidNextToken,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6409,30 +6734,38 @@ impl GetOrganizationConfigRuleDetailedStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
-                        GetOrganizationConfigRuleDetailedStatusError::InvalidLimit(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOrganizationConfigRuleDetailedStatusError::InvalidLimit,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        GetOrganizationConfigRuleDetailedStatusError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOrganizationConfigRuleDetailedStatusError::InvalidNextToken,
                     )
                 }
                 "NoSuchOrganizationConfigRuleException" => {
-                    return RusotoError::Service(
-                        GetOrganizationConfigRuleDetailedStatusError::NoSuchOrganizationConfigRule(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOrganizationConfigRuleDetailedStatusError::NoSuchOrganizationConfigRule,
                     )
                 }
                 "OrganizationAccessDeniedException" => {
-                    return RusotoError::Service(
-                        GetOrganizationConfigRuleDetailedStatusError::OrganizationAccessDenied(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOrganizationConfigRuleDetailedStatusError::OrganizationAccessDenied,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6478,11 +6811,11 @@ impl GetOrganizationConformancePackDetailedStatusError {
     ) -> RusotoError<GetOrganizationConformancePackDetailedStatusError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                                "InvalidLimitException" => return RusotoError::Service(GetOrganizationConformancePackDetailedStatusError::InvalidLimit(err.msg)),
-"InvalidNextTokenException" => return RusotoError::Service(GetOrganizationConformancePackDetailedStatusError::InvalidNextToken(err.msg)),
-"NoSuchOrganizationConformancePackException" => return RusotoError::Service(GetOrganizationConformancePackDetailedStatusError::NoSuchOrganizationConformancePack(err.msg)),
-"OrganizationAccessDeniedException" => return RusotoError::Service(GetOrganizationConformancePackDetailedStatusError::OrganizationAccessDenied(err.msg)),
-"ValidationException" => return RusotoError::Validation(err.msg),
+                                "InvalidLimitException" => return RusotoError::service_error(&res, &err.typ, err.msg, GetOrganizationConformancePackDetailedStatusError::InvalidLimit),
+"InvalidNextTokenException" => return RusotoError::service_error(&res, &err.typ, err.msg, GetOrganizationConformancePackDetailedStatusError::InvalidNextToken),
+"NoSuchOrganizationConformancePackException" => return RusotoError::service_error(&res, &err.typ, err.msg, GetOrganizationConformancePackDetailedStatusError::NoSuchOrganizationConformancePack),
+"OrganizationAccessDeniedException" => return RusotoError::service_error(&res, &err.typ, err.msg, GetOrganizationConformancePackDetailedStatusError::OrganizationAccessDenied),
+"ValidationException" => return RusotoError::validation_error(&res, err.msg),
 _ => {}
                             }
         }
@@ -6521,31 +6854,46 @@ impl GetResourceConfigHistoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(GetResourceConfigHistoryError::InvalidLimit(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetResourceConfigHistoryError::InvalidLimit,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(GetResourceConfigHistoryError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetResourceConfigHistoryError::InvalidNextToken,
+                    )
                 }
                 "InvalidTimeRangeException" => {
-                    return RusotoError::Service(GetResourceConfigHistoryError::InvalidTimeRange(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetResourceConfigHistoryError::InvalidTimeRange,
+                    )
                 }
                 "NoAvailableConfigurationRecorderException" => {
-                    return RusotoError::Service(
-                        GetResourceConfigHistoryError::NoAvailableConfigurationRecorder(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourceConfigHistoryError::NoAvailableConfigurationRecorder,
                     )
                 }
                 "ResourceNotDiscoveredException" => {
-                    return RusotoError::Service(
-                        GetResourceConfigHistoryError::ResourceNotDiscovered(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourceConfigHistoryError::ResourceNotDiscovered,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6581,9 +6929,14 @@ impl GetStoredQueryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetStoredQueryError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetStoredQueryError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6617,23 +6970,30 @@ impl ListAggregateDiscoveredResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(
-                        ListAggregateDiscoveredResourcesError::InvalidLimit(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAggregateDiscoveredResourcesError::InvalidLimit,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        ListAggregateDiscoveredResourcesError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAggregateDiscoveredResourcesError::InvalidNextToken,
                     )
                 }
                 "NoSuchConfigurationAggregatorException" => {
-                    return RusotoError::Service(
-                        ListAggregateDiscoveredResourcesError::NoSuchConfigurationAggregator(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAggregateDiscoveredResourcesError::NoSuchConfigurationAggregator,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6673,21 +7033,30 @@ impl ListDiscoveredResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(ListDiscoveredResourcesError::InvalidLimit(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDiscoveredResourcesError::InvalidLimit,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDiscoveredResourcesError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDiscoveredResourcesError::InvalidNextToken,
+                    )
                 }
                 "NoAvailableConfigurationRecorderException" => {
-                    return RusotoError::Service(
-                        ListDiscoveredResourcesError::NoAvailableConfigurationRecorder(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDiscoveredResourcesError::NoAvailableConfigurationRecorder,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6719,9 +7088,14 @@ impl ListStoredQueriesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListStoredQueriesError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListStoredQueriesError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6753,19 +7127,30 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLimitException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidLimit(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InvalidLimit,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::InvalidNextToken,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6797,11 +7182,14 @@ impl PutAggregationAuthorizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        PutAggregationAuthorizationError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAggregationAuthorizationError::InvalidParameterValue,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6839,27 +7227,46 @@ impl PutConfigRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InsufficientPermissionsException" => {
-                    return RusotoError::Service(PutConfigRuleError::InsufficientPermissions(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutConfigRuleError::InsufficientPermissions,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(PutConfigRuleError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutConfigRuleError::InvalidParameterValue,
+                    )
                 }
                 "MaxNumberOfConfigRulesExceededException" => {
-                    return RusotoError::Service(
-                        PutConfigRuleError::MaxNumberOfConfigRulesExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutConfigRuleError::MaxNumberOfConfigRulesExceeded,
                     )
                 }
                 "NoAvailableConfigurationRecorderException" => {
-                    return RusotoError::Service(
-                        PutConfigRuleError::NoAvailableConfigurationRecorder(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutConfigRuleError::NoAvailableConfigurationRecorder,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(PutConfigRuleError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutConfigRuleError::ResourceInUse,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6905,36 +7312,54 @@ impl PutConfigurationAggregatorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        PutConfigurationAggregatorError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutConfigurationAggregatorError::InvalidParameterValue,
                     )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(PutConfigurationAggregatorError::InvalidRole(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutConfigurationAggregatorError::InvalidRole,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutConfigurationAggregatorError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutConfigurationAggregatorError::LimitExceeded,
+                    )
                 }
                 "NoAvailableOrganizationException" => {
-                    return RusotoError::Service(
-                        PutConfigurationAggregatorError::NoAvailableOrganization(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutConfigurationAggregatorError::NoAvailableOrganization,
                     )
                 }
                 "OrganizationAccessDeniedException" => {
-                    return RusotoError::Service(
-                        PutConfigurationAggregatorError::OrganizationAccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutConfigurationAggregatorError::OrganizationAccessDenied,
                     )
                 }
                 "OrganizationAllFeaturesNotEnabledException" => {
-                    return RusotoError::Service(
-                        PutConfigurationAggregatorError::OrganizationAllFeaturesNotEnabled(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutConfigurationAggregatorError::OrganizationAllFeaturesNotEnabled,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6981,28 +7406,38 @@ impl PutConfigurationRecorderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidConfigurationRecorderNameException" => {
-                    return RusotoError::Service(
-                        PutConfigurationRecorderError::InvalidConfigurationRecorderName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutConfigurationRecorderError::InvalidConfigurationRecorderName,
                     )
                 }
                 "InvalidRecordingGroupException" => {
-                    return RusotoError::Service(
-                        PutConfigurationRecorderError::InvalidRecordingGroup(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutConfigurationRecorderError::InvalidRecordingGroup,
                     )
                 }
                 "InvalidRoleException" => {
-                    return RusotoError::Service(PutConfigurationRecorderError::InvalidRole(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutConfigurationRecorderError::InvalidRole,
+                    )
                 }
                 "MaxNumberOfConfigurationRecordersExceededException" => {
-                    return RusotoError::Service(
-                        PutConfigurationRecorderError::MaxNumberOfConfigurationRecordersExceeded(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutConfigurationRecorderError::MaxNumberOfConfigurationRecordersExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7047,29 +7482,46 @@ impl PutConformancePackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConformancePackTemplateValidationException" => {
-                    return RusotoError::Service(
-                        PutConformancePackError::ConformancePackTemplateValidation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutConformancePackError::ConformancePackTemplateValidation,
                     )
                 }
                 "InsufficientPermissionsException" => {
-                    return RusotoError::Service(PutConformancePackError::InsufficientPermissions(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutConformancePackError::InsufficientPermissions,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(PutConformancePackError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutConformancePackError::InvalidParameterValue,
+                    )
                 }
                 "MaxNumberOfConformancePacksExceededException" => {
-                    return RusotoError::Service(
-                        PutConformancePackError::MaxNumberOfConformancePacksExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutConformancePackError::MaxNumberOfConformancePacksExceeded,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(PutConformancePackError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutConformancePackError::ResourceInUse,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7117,39 +7569,62 @@ impl PutDeliveryChannelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InsufficientDeliveryPolicyException" => {
-                    return RusotoError::Service(
-                        PutDeliveryChannelError::InsufficientDeliveryPolicy(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutDeliveryChannelError::InsufficientDeliveryPolicy,
                     )
                 }
                 "InvalidDeliveryChannelNameException" => {
-                    return RusotoError::Service(
-                        PutDeliveryChannelError::InvalidDeliveryChannelName(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutDeliveryChannelError::InvalidDeliveryChannelName,
                     )
                 }
                 "InvalidS3KeyPrefixException" => {
-                    return RusotoError::Service(PutDeliveryChannelError::InvalidS3KeyPrefix(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutDeliveryChannelError::InvalidS3KeyPrefix,
+                    )
                 }
                 "InvalidSNSTopicARNException" => {
-                    return RusotoError::Service(PutDeliveryChannelError::InvalidSNSTopicARN(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutDeliveryChannelError::InvalidSNSTopicARN,
+                    )
                 }
                 "MaxNumberOfDeliveryChannelsExceededException" => {
-                    return RusotoError::Service(
-                        PutDeliveryChannelError::MaxNumberOfDeliveryChannelsExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutDeliveryChannelError::MaxNumberOfDeliveryChannelsExceeded,
                     )
                 }
                 "NoAvailableConfigurationRecorderException" => {
-                    return RusotoError::Service(
-                        PutDeliveryChannelError::NoAvailableConfigurationRecorder(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutDeliveryChannelError::NoAvailableConfigurationRecorder,
                     )
                 }
                 "NoSuchBucketException" => {
-                    return RusotoError::Service(PutDeliveryChannelError::NoSuchBucket(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutDeliveryChannelError::NoSuchBucket,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7195,17 +7670,30 @@ impl PutEvaluationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(PutEvaluationsError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutEvaluationsError::InvalidParameterValue,
+                    )
                 }
                 "InvalidResultTokenException" => {
-                    return RusotoError::Service(PutEvaluationsError::InvalidResultToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutEvaluationsError::InvalidResultToken,
+                    )
                 }
                 "NoSuchConfigRuleException" => {
-                    return RusotoError::Service(PutEvaluationsError::NoSuchConfigRule(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutEvaluationsError::NoSuchConfigRule,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7237,16 +7725,22 @@ impl PutExternalEvaluationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(PutExternalEvaluationError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutExternalEvaluationError::InvalidParameterValue,
+                    )
                 }
                 "NoSuchConfigRuleException" => {
-                    return RusotoError::Service(PutExternalEvaluationError::NoSuchConfigRule(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutExternalEvaluationError::NoSuchConfigRule,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7287,43 +7781,62 @@ impl PutOrganizationConfigRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InsufficientPermissionsException" => {
-                    return RusotoError::Service(
-                        PutOrganizationConfigRuleError::InsufficientPermissions(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutOrganizationConfigRuleError::InsufficientPermissions,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        PutOrganizationConfigRuleError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutOrganizationConfigRuleError::InvalidParameterValue,
                     )
                 }
                 "MaxNumberOfOrganizationConfigRulesExceededException" => {
-                    return RusotoError::Service(
-                        PutOrganizationConfigRuleError::MaxNumberOfOrganizationConfigRulesExceeded(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutOrganizationConfigRuleError::MaxNumberOfOrganizationConfigRulesExceeded,
                     )
                 }
                 "NoAvailableOrganizationException" => {
-                    return RusotoError::Service(
-                        PutOrganizationConfigRuleError::NoAvailableOrganization(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutOrganizationConfigRuleError::NoAvailableOrganization,
                     )
                 }
                 "OrganizationAccessDeniedException" => {
-                    return RusotoError::Service(
-                        PutOrganizationConfigRuleError::OrganizationAccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutOrganizationConfigRuleError::OrganizationAccessDenied,
                     )
                 }
                 "OrganizationAllFeaturesNotEnabledException" => {
-                    return RusotoError::Service(
-                        PutOrganizationConfigRuleError::OrganizationAllFeaturesNotEnabled(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutOrganizationConfigRuleError::OrganizationAllFeaturesNotEnabled,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(PutOrganizationConfigRuleError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutOrganizationConfigRuleError::ResourceInUse,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7382,14 +7895,14 @@ impl PutOrganizationConformancePackError {
     ) -> RusotoError<PutOrganizationConformancePackError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                                "InsufficientPermissionsException" => return RusotoError::Service(PutOrganizationConformancePackError::InsufficientPermissions(err.msg)),
-"MaxNumberOfOrganizationConformancePacksExceededException" => return RusotoError::Service(PutOrganizationConformancePackError::MaxNumberOfOrganizationConformancePacksExceeded(err.msg)),
-"NoAvailableOrganizationException" => return RusotoError::Service(PutOrganizationConformancePackError::NoAvailableOrganization(err.msg)),
-"OrganizationAccessDeniedException" => return RusotoError::Service(PutOrganizationConformancePackError::OrganizationAccessDenied(err.msg)),
-"OrganizationAllFeaturesNotEnabledException" => return RusotoError::Service(PutOrganizationConformancePackError::OrganizationAllFeaturesNotEnabled(err.msg)),
-"OrganizationConformancePackTemplateValidationException" => return RusotoError::Service(PutOrganizationConformancePackError::OrganizationConformancePackTemplateValidation(err.msg)),
-"ResourceInUseException" => return RusotoError::Service(PutOrganizationConformancePackError::ResourceInUse(err.msg)),
-"ValidationException" => return RusotoError::Validation(err.msg),
+                                "InsufficientPermissionsException" => return RusotoError::service_error(&res, &err.typ, err.msg, PutOrganizationConformancePackError::InsufficientPermissions),
+"MaxNumberOfOrganizationConformancePacksExceededException" => return RusotoError::service_error(&res, &err.typ, err.msg, PutOrganizationConformancePackError::MaxNumberOfOrganizationConformancePacksExceeded),
+"NoAvailableOrganizationException" => return RusotoError::service_error(&res, &err.typ, err.msg, PutOrganizationConformancePackError::NoAvailableOrganization),
+"OrganizationAccessDeniedException" => return RusotoError::service_error(&res, &err.typ, err.msg, PutOrganizationConformancePackError::OrganizationAccessDenied),
+"OrganizationAllFeaturesNotEnabledException" => return RusotoError::service_error(&res, &err.typ, err.msg, PutOrganizationConformancePackError::OrganizationAllFeaturesNotEnabled),
+"OrganizationConformancePackTemplateValidationException" => return RusotoError::service_error(&res, &err.typ, err.msg, PutOrganizationConformancePackError::OrganizationConformancePackTemplateValidation),
+"ResourceInUseException" => return RusotoError::service_error(&res, &err.typ, err.msg, PutOrganizationConformancePackError::ResourceInUse),
+"ValidationException" => return RusotoError::validation_error(&res, err.msg),
 _ => {}
                             }
         }
@@ -7427,16 +7940,22 @@ impl PutRemediationConfigurationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InsufficientPermissionsException" => {
-                    return RusotoError::Service(
-                        PutRemediationConfigurationsError::InsufficientPermissions(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRemediationConfigurationsError::InsufficientPermissions,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        PutRemediationConfigurationsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRemediationConfigurationsError::InvalidParameterValue,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7471,16 +7990,22 @@ impl PutRemediationExceptionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InsufficientPermissionsException" => {
-                    return RusotoError::Service(
-                        PutRemediationExceptionsError::InsufficientPermissions(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRemediationExceptionsError::InsufficientPermissions,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        PutRemediationExceptionsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRemediationExceptionsError::InvalidParameterValue,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7517,21 +8042,30 @@ impl PutResourceConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InsufficientPermissionsException" => {
-                    return RusotoError::Service(PutResourceConfigError::InsufficientPermissions(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutResourceConfigError::InsufficientPermissions,
+                    )
                 }
                 "MaxActiveResourcesExceededException" => {
-                    return RusotoError::Service(
-                        PutResourceConfigError::MaxActiveResourcesExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutResourceConfigError::MaxActiveResourcesExceeded,
                     )
                 }
                 "NoRunningConfigurationRecorderException" => {
-                    return RusotoError::Service(
-                        PutResourceConfigError::NoRunningConfigurationRecorder(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutResourceConfigError::NoRunningConfigurationRecorder,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7565,18 +8099,22 @@ impl PutRetentionConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        PutRetentionConfigurationError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRetentionConfigurationError::InvalidParameterValue,
                     )
                 }
                 "MaxNumberOfRetentionConfigurationsExceededException" => {
-                    return RusotoError::Service(
-                        PutRetentionConfigurationError::MaxNumberOfRetentionConfigurationsExceeded(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRetentionConfigurationError::MaxNumberOfRetentionConfigurationsExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7611,14 +8149,22 @@ impl PutStoredQueryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        PutStoredQueryError::ResourceConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutStoredQueryError::ResourceConcurrentModification,
                     )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(PutStoredQueryError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutStoredQueryError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7657,26 +8203,38 @@ impl SelectAggregateResourceConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidExpressionException" => {
-                    return RusotoError::Service(
-                        SelectAggregateResourceConfigError::InvalidExpression(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SelectAggregateResourceConfigError::InvalidExpression,
                     )
                 }
                 "InvalidLimitException" => {
-                    return RusotoError::Service(SelectAggregateResourceConfigError::InvalidLimit(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SelectAggregateResourceConfigError::InvalidLimit,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        SelectAggregateResourceConfigError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SelectAggregateResourceConfigError::InvalidNextToken,
                     )
                 }
                 "NoSuchConfigurationAggregatorException" => {
-                    return RusotoError::Service(
-                        SelectAggregateResourceConfigError::NoSuchConfigurationAggregator(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SelectAggregateResourceConfigError::NoSuchConfigurationAggregator,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7717,19 +8275,30 @@ impl SelectResourceConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidExpressionException" => {
-                    return RusotoError::Service(SelectResourceConfigError::InvalidExpression(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SelectResourceConfigError::InvalidExpression,
+                    )
                 }
                 "InvalidLimitException" => {
-                    return RusotoError::Service(SelectResourceConfigError::InvalidLimit(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SelectResourceConfigError::InvalidLimit,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(SelectResourceConfigError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SelectResourceConfigError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7767,26 +8336,38 @@ impl StartConfigRulesEvaluationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        StartConfigRulesEvaluationError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartConfigRulesEvaluationError::InvalidParameterValue,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartConfigRulesEvaluationError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartConfigRulesEvaluationError::LimitExceeded,
+                    )
                 }
                 "NoSuchConfigRuleException" => {
-                    return RusotoError::Service(StartConfigRulesEvaluationError::NoSuchConfigRule(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartConfigRulesEvaluationError::NoSuchConfigRule,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StartConfigRulesEvaluationError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartConfigRulesEvaluationError::ResourceInUse,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7823,16 +8404,22 @@ impl StartConfigurationRecorderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoAvailableDeliveryChannelException" => {
-                    return RusotoError::Service(
-                        StartConfigurationRecorderError::NoAvailableDeliveryChannel(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartConfigurationRecorderError::NoAvailableDeliveryChannel,
                     )
                 }
                 "NoSuchConfigurationRecorderException" => {
-                    return RusotoError::Service(
-                        StartConfigurationRecorderError::NoSuchConfigurationRecorder(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartConfigurationRecorderError::NoSuchConfigurationRecorder,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7869,21 +8456,30 @@ impl StartRemediationExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InsufficientPermissionsException" => {
-                    return RusotoError::Service(
-                        StartRemediationExecutionError::InsufficientPermissions(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartRemediationExecutionError::InsufficientPermissions,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        StartRemediationExecutionError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartRemediationExecutionError::InvalidParameterValue,
                     )
                 }
                 "NoSuchRemediationConfigurationException" => {
-                    return RusotoError::Service(
-                        StartRemediationExecutionError::NoSuchRemediationConfiguration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartRemediationExecutionError::NoSuchRemediationConfiguration,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7919,11 +8515,14 @@ impl StopConfigurationRecorderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "NoSuchConfigurationRecorderException" => {
-                    return RusotoError::Service(
-                        StopConfigurationRecorderError::NoSuchConfigurationRecorder(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopConfigurationRecorderError::NoSuchConfigurationRecorder,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7955,12 +8554,22 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7989,9 +8598,14 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8630,11 +9244,12 @@ pub struct ConfigServiceClient {
 impl ConfigServiceClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> ConfigServiceClient {
         ConfigServiceClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Config Service"),
         }
     }
 
@@ -8649,12 +9264,32 @@ impl ConfigServiceClient {
     {
         ConfigServiceClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Config Service"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ConfigServiceClient {
-        ConfigServiceClient { client, region }
+        ConfigServiceClient {
+            client,
+            region: region_for_service(region, "Config Service"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> ConfigServiceClient {
+        ConfigServiceClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Config Service"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ConfigServiceClient {
+        ConfigServiceClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -8669,6 +9304,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<BatchGetAggregateResourceConfigError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchGetAggregateResourceConfig");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.BatchGetAggregateResourceConfig",
@@ -8691,6 +9327,7 @@ impl ConfigService for ConfigServiceClient {
         input: BatchGetResourceConfigRequest,
     ) -> Result<BatchGetResourceConfigResponse, RusotoError<BatchGetResourceConfigError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchGetResourceConfig");
         request.add_header("x-amz-target", "StarlingDoveService.BatchGetResourceConfig");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8710,6 +9347,7 @@ impl ConfigService for ConfigServiceClient {
         input: DeleteAggregationAuthorizationRequest,
     ) -> Result<(), RusotoError<DeleteAggregationAuthorizationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteAggregationAuthorization");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DeleteAggregationAuthorization",
@@ -8730,6 +9368,7 @@ impl ConfigService for ConfigServiceClient {
         input: DeleteConfigRuleRequest,
     ) -> Result<(), RusotoError<DeleteConfigRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteConfigRule");
         request.add_header("x-amz-target", "StarlingDoveService.DeleteConfigRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8747,6 +9386,7 @@ impl ConfigService for ConfigServiceClient {
         input: DeleteConfigurationAggregatorRequest,
     ) -> Result<(), RusotoError<DeleteConfigurationAggregatorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteConfigurationAggregator");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DeleteConfigurationAggregator",
@@ -8767,6 +9407,7 @@ impl ConfigService for ConfigServiceClient {
         input: DeleteConfigurationRecorderRequest,
     ) -> Result<(), RusotoError<DeleteConfigurationRecorderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteConfigurationRecorder");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DeleteConfigurationRecorder",
@@ -8787,6 +9428,7 @@ impl ConfigService for ConfigServiceClient {
         input: DeleteConformancePackRequest,
     ) -> Result<(), RusotoError<DeleteConformancePackError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteConformancePack");
         request.add_header("x-amz-target", "StarlingDoveService.DeleteConformancePack");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8804,6 +9446,7 @@ impl ConfigService for ConfigServiceClient {
         input: DeleteDeliveryChannelRequest,
     ) -> Result<(), RusotoError<DeleteDeliveryChannelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteDeliveryChannel");
         request.add_header("x-amz-target", "StarlingDoveService.DeleteDeliveryChannel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8821,6 +9464,7 @@ impl ConfigService for ConfigServiceClient {
         input: DeleteEvaluationResultsRequest,
     ) -> Result<DeleteEvaluationResultsResponse, RusotoError<DeleteEvaluationResultsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteEvaluationResults");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DeleteEvaluationResults",
@@ -8843,6 +9487,7 @@ impl ConfigService for ConfigServiceClient {
         input: DeleteOrganizationConfigRuleRequest,
     ) -> Result<(), RusotoError<DeleteOrganizationConfigRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteOrganizationConfigRule");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DeleteOrganizationConfigRule",
@@ -8863,6 +9508,7 @@ impl ConfigService for ConfigServiceClient {
         input: DeleteOrganizationConformancePackRequest,
     ) -> Result<(), RusotoError<DeleteOrganizationConformancePackError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteOrganizationConformancePack");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DeleteOrganizationConformancePack",
@@ -8886,6 +9532,7 @@ impl ConfigService for ConfigServiceClient {
         input: DeletePendingAggregationRequestRequest,
     ) -> Result<(), RusotoError<DeletePendingAggregationRequestError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeletePendingAggregationRequest");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DeletePendingAggregationRequest",
@@ -8909,6 +9556,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DeleteRemediationConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteRemediationConfiguration");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DeleteRemediationConfiguration",
@@ -8932,6 +9580,7 @@ impl ConfigService for ConfigServiceClient {
     ) -> Result<DeleteRemediationExceptionsResponse, RusotoError<DeleteRemediationExceptionsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteRemediationExceptions");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DeleteRemediationExceptions",
@@ -8954,6 +9603,7 @@ impl ConfigService for ConfigServiceClient {
         input: DeleteResourceConfigRequest,
     ) -> Result<(), RusotoError<DeleteResourceConfigError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteResourceConfig");
         request.add_header("x-amz-target", "StarlingDoveService.DeleteResourceConfig");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8971,6 +9621,7 @@ impl ConfigService for ConfigServiceClient {
         input: DeleteRetentionConfigurationRequest,
     ) -> Result<(), RusotoError<DeleteRetentionConfigurationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteRetentionConfiguration");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DeleteRetentionConfiguration",
@@ -8991,6 +9642,7 @@ impl ConfigService for ConfigServiceClient {
         input: DeleteStoredQueryRequest,
     ) -> Result<DeleteStoredQueryResponse, RusotoError<DeleteStoredQueryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteStoredQuery");
         request.add_header("x-amz-target", "StarlingDoveService.DeleteStoredQuery");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9009,6 +9661,7 @@ impl ConfigService for ConfigServiceClient {
         input: DeliverConfigSnapshotRequest,
     ) -> Result<DeliverConfigSnapshotResponse, RusotoError<DeliverConfigSnapshotError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeliverConfigSnapshot");
         request.add_header("x-amz-target", "StarlingDoveService.DeliverConfigSnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9031,6 +9684,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeAggregateComplianceByConfigRulesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeAggregateComplianceByConfigRules");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeAggregateComplianceByConfigRules",
@@ -9059,6 +9713,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeAggregationAuthorizationsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeAggregationAuthorizations");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeAggregationAuthorizations",
@@ -9087,6 +9742,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeComplianceByConfigRuleError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeComplianceByConfigRule");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeComplianceByConfigRule",
@@ -9110,6 +9766,7 @@ impl ConfigService for ConfigServiceClient {
     ) -> Result<DescribeComplianceByResourceResponse, RusotoError<DescribeComplianceByResourceError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeComplianceByResource");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeComplianceByResource",
@@ -9135,6 +9792,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeConfigRuleEvaluationStatusError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeConfigRuleEvaluationStatus");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeConfigRuleEvaluationStatus",
@@ -9160,6 +9818,7 @@ impl ConfigService for ConfigServiceClient {
         input: DescribeConfigRulesRequest,
     ) -> Result<DescribeConfigRulesResponse, RusotoError<DescribeConfigRulesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeConfigRules");
         request.add_header("x-amz-target", "StarlingDoveService.DescribeConfigRules");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9181,6 +9840,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeConfigurationAggregatorSourcesStatusError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeConfigurationAggregatorSourcesStatus");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeConfigurationAggregatorSourcesStatus",
@@ -9209,6 +9869,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeConfigurationAggregatorsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeConfigurationAggregators");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeConfigurationAggregators",
@@ -9237,6 +9898,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeConfigurationRecorderStatusError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeConfigurationRecorderStatus");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeConfigurationRecorderStatus",
@@ -9265,6 +9927,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeConfigurationRecordersError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeConfigurationRecorders");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeConfigurationRecorders",
@@ -9290,6 +9953,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeConformancePackComplianceError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeConformancePackCompliance");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeConformancePackCompliance",
@@ -9318,6 +9982,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeConformancePackStatusError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeConformancePackStatus");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeConformancePackStatus",
@@ -9340,6 +10005,7 @@ impl ConfigService for ConfigServiceClient {
         input: DescribeConformancePacksRequest,
     ) -> Result<DescribeConformancePacksResponse, RusotoError<DescribeConformancePacksError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeConformancePacks");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeConformancePacks",
@@ -9365,6 +10031,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeDeliveryChannelStatusError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeDeliveryChannelStatus");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeDeliveryChannelStatus",
@@ -9387,6 +10054,7 @@ impl ConfigService for ConfigServiceClient {
         input: DescribeDeliveryChannelsRequest,
     ) -> Result<DescribeDeliveryChannelsResponse, RusotoError<DescribeDeliveryChannelsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeDeliveryChannels");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeDeliveryChannels",
@@ -9412,6 +10080,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeOrganizationConfigRuleStatusesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeOrganizationConfigRuleStatuses");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeOrganizationConfigRuleStatuses",
@@ -9440,6 +10109,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeOrganizationConfigRulesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeOrganizationConfigRules");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeOrganizationConfigRules",
@@ -9465,6 +10135,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeOrganizationConformancePackStatusesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeOrganizationConformancePackStatuses");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeOrganizationConformancePackStatuses",
@@ -9493,6 +10164,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeOrganizationConformancePacksError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeOrganizationConformancePacks");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeOrganizationConformancePacks",
@@ -9521,6 +10193,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribePendingAggregationRequestsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribePendingAggregationRequests");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribePendingAggregationRequests",
@@ -9549,6 +10222,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeRemediationConfigurationsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeRemediationConfigurations");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeRemediationConfigurations",
@@ -9577,6 +10251,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeRemediationExceptionsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeRemediationExceptions");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeRemediationExceptions",
@@ -9602,6 +10277,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeRemediationExecutionStatusError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeRemediationExecutionStatus");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeRemediationExecutionStatus",
@@ -9630,6 +10306,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<DescribeRetentionConfigurationsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeRetentionConfigurations");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.DescribeRetentionConfigurations",
@@ -9655,6 +10332,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<GetAggregateComplianceDetailsByConfigRuleError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetAggregateComplianceDetailsByConfigRule");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.GetAggregateComplianceDetailsByConfigRule",
@@ -9683,6 +10361,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<GetAggregateConfigRuleComplianceSummaryError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetAggregateConfigRuleComplianceSummary");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.GetAggregateConfigRuleComplianceSummary",
@@ -9711,6 +10390,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<GetAggregateDiscoveredResourceCountsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetAggregateDiscoveredResourceCounts");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.GetAggregateDiscoveredResourceCounts",
@@ -9737,6 +10417,7 @@ impl ConfigService for ConfigServiceClient {
     ) -> Result<GetAggregateResourceConfigResponse, RusotoError<GetAggregateResourceConfigError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetAggregateResourceConfig");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.GetAggregateResourceConfig",
@@ -9762,6 +10443,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<GetComplianceDetailsByConfigRuleError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetComplianceDetailsByConfigRule");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.GetComplianceDetailsByConfigRule",
@@ -9790,6 +10472,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<GetComplianceDetailsByResourceError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetComplianceDetailsByResource");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.GetComplianceDetailsByResource",
@@ -9814,6 +10497,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<GetComplianceSummaryByConfigRuleError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetComplianceSummaryByConfigRule");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.GetComplianceSummaryByConfigRule",
@@ -9841,6 +10525,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<GetComplianceSummaryByResourceTypeError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetComplianceSummaryByResourceType");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.GetComplianceSummaryByResourceType",
@@ -9869,6 +10554,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<GetConformancePackComplianceDetailsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetConformancePackComplianceDetails");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.GetConformancePackComplianceDetails",
@@ -9897,6 +10583,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<GetConformancePackComplianceSummaryError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetConformancePackComplianceSummary");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.GetConformancePackComplianceSummary",
@@ -9923,6 +10610,7 @@ impl ConfigService for ConfigServiceClient {
     ) -> Result<GetDiscoveredResourceCountsResponse, RusotoError<GetDiscoveredResourceCountsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDiscoveredResourceCounts");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.GetDiscoveredResourceCounts",
@@ -9948,6 +10636,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<GetOrganizationConfigRuleDetailedStatusError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetOrganizationConfigRuleDetailedStatus");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.GetOrganizationConfigRuleDetailedStatus",
@@ -9976,6 +10665,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<GetOrganizationConformancePackDetailedStatusError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetOrganizationConformancePackDetailedStatus");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.GetOrganizationConformancePackDetailedStatus",
@@ -10001,6 +10691,7 @@ impl ConfigService for ConfigServiceClient {
         input: GetResourceConfigHistoryRequest,
     ) -> Result<GetResourceConfigHistoryResponse, RusotoError<GetResourceConfigHistoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetResourceConfigHistory");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.GetResourceConfigHistory",
@@ -10023,6 +10714,7 @@ impl ConfigService for ConfigServiceClient {
         input: GetStoredQueryRequest,
     ) -> Result<GetStoredQueryResponse, RusotoError<GetStoredQueryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetStoredQuery");
         request.add_header("x-amz-target", "StarlingDoveService.GetStoredQuery");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10044,6 +10736,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<ListAggregateDiscoveredResourcesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListAggregateDiscoveredResources");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.ListAggregateDiscoveredResources",
@@ -10069,6 +10762,7 @@ impl ConfigService for ConfigServiceClient {
         input: ListDiscoveredResourcesRequest,
     ) -> Result<ListDiscoveredResourcesResponse, RusotoError<ListDiscoveredResourcesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListDiscoveredResources");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.ListDiscoveredResources",
@@ -10091,6 +10785,7 @@ impl ConfigService for ConfigServiceClient {
         input: ListStoredQueriesRequest,
     ) -> Result<ListStoredQueriesResponse, RusotoError<ListStoredQueriesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListStoredQueries");
         request.add_header("x-amz-target", "StarlingDoveService.ListStoredQueries");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10109,6 +10804,7 @@ impl ConfigService for ConfigServiceClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header("x-amz-target", "StarlingDoveService.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10128,6 +10824,7 @@ impl ConfigService for ConfigServiceClient {
     ) -> Result<PutAggregationAuthorizationResponse, RusotoError<PutAggregationAuthorizationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutAggregationAuthorization");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.PutAggregationAuthorization",
@@ -10150,6 +10847,7 @@ impl ConfigService for ConfigServiceClient {
         input: PutConfigRuleRequest,
     ) -> Result<(), RusotoError<PutConfigRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutConfigRule");
         request.add_header("x-amz-target", "StarlingDoveService.PutConfigRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10168,6 +10866,7 @@ impl ConfigService for ConfigServiceClient {
     ) -> Result<PutConfigurationAggregatorResponse, RusotoError<PutConfigurationAggregatorError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutConfigurationAggregator");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.PutConfigurationAggregator",
@@ -10190,6 +10889,7 @@ impl ConfigService for ConfigServiceClient {
         input: PutConfigurationRecorderRequest,
     ) -> Result<(), RusotoError<PutConfigurationRecorderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutConfigurationRecorder");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.PutConfigurationRecorder",
@@ -10210,6 +10910,7 @@ impl ConfigService for ConfigServiceClient {
         input: PutConformancePackRequest,
     ) -> Result<PutConformancePackResponse, RusotoError<PutConformancePackError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutConformancePack");
         request.add_header("x-amz-target", "StarlingDoveService.PutConformancePack");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10228,6 +10929,7 @@ impl ConfigService for ConfigServiceClient {
         input: PutDeliveryChannelRequest,
     ) -> Result<(), RusotoError<PutDeliveryChannelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutDeliveryChannel");
         request.add_header("x-amz-target", "StarlingDoveService.PutDeliveryChannel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10245,6 +10947,7 @@ impl ConfigService for ConfigServiceClient {
         input: PutEvaluationsRequest,
     ) -> Result<PutEvaluationsResponse, RusotoError<PutEvaluationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutEvaluations");
         request.add_header("x-amz-target", "StarlingDoveService.PutEvaluations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10262,6 +10965,7 @@ impl ConfigService for ConfigServiceClient {
         input: PutExternalEvaluationRequest,
     ) -> Result<PutExternalEvaluationResponse, RusotoError<PutExternalEvaluationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutExternalEvaluation");
         request.add_header("x-amz-target", "StarlingDoveService.PutExternalEvaluation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10282,6 +10986,7 @@ impl ConfigService for ConfigServiceClient {
     ) -> Result<PutOrganizationConfigRuleResponse, RusotoError<PutOrganizationConfigRuleError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutOrganizationConfigRule");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.PutOrganizationConfigRule",
@@ -10307,6 +11012,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<PutOrganizationConformancePackError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutOrganizationConformancePack");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.PutOrganizationConformancePack",
@@ -10330,6 +11036,7 @@ impl ConfigService for ConfigServiceClient {
     ) -> Result<PutRemediationConfigurationsResponse, RusotoError<PutRemediationConfigurationsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutRemediationConfigurations");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.PutRemediationConfigurations",
@@ -10352,6 +11059,7 @@ impl ConfigService for ConfigServiceClient {
         input: PutRemediationExceptionsRequest,
     ) -> Result<PutRemediationExceptionsResponse, RusotoError<PutRemediationExceptionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutRemediationExceptions");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.PutRemediationExceptions",
@@ -10374,6 +11082,7 @@ impl ConfigService for ConfigServiceClient {
         input: PutResourceConfigRequest,
     ) -> Result<(), RusotoError<PutResourceConfigError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutResourceConfig");
         request.add_header("x-amz-target", "StarlingDoveService.PutResourceConfig");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10392,6 +11101,7 @@ impl ConfigService for ConfigServiceClient {
     ) -> Result<PutRetentionConfigurationResponse, RusotoError<PutRetentionConfigurationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutRetentionConfiguration");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.PutRetentionConfiguration",
@@ -10414,6 +11124,7 @@ impl ConfigService for ConfigServiceClient {
         input: PutStoredQueryRequest,
     ) -> Result<PutStoredQueryResponse, RusotoError<PutStoredQueryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutStoredQuery");
         request.add_header("x-amz-target", "StarlingDoveService.PutStoredQuery");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10435,6 +11146,7 @@ impl ConfigService for ConfigServiceClient {
         RusotoError<SelectAggregateResourceConfigError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SelectAggregateResourceConfig");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.SelectAggregateResourceConfig",
@@ -10457,6 +11169,7 @@ impl ConfigService for ConfigServiceClient {
         input: SelectResourceConfigRequest,
     ) -> Result<SelectResourceConfigResponse, RusotoError<SelectResourceConfigError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SelectResourceConfig");
         request.add_header("x-amz-target", "StarlingDoveService.SelectResourceConfig");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10477,6 +11190,7 @@ impl ConfigService for ConfigServiceClient {
     ) -> Result<StartConfigRulesEvaluationResponse, RusotoError<StartConfigRulesEvaluationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartConfigRulesEvaluation");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.StartConfigRulesEvaluation",
@@ -10499,6 +11213,7 @@ impl ConfigService for ConfigServiceClient {
         input: StartConfigurationRecorderRequest,
     ) -> Result<(), RusotoError<StartConfigurationRecorderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartConfigurationRecorder");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.StartConfigurationRecorder",
@@ -10520,6 +11235,7 @@ impl ConfigService for ConfigServiceClient {
     ) -> Result<StartRemediationExecutionResponse, RusotoError<StartRemediationExecutionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartRemediationExecution");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.StartRemediationExecution",
@@ -10542,6 +11258,7 @@ impl ConfigService for ConfigServiceClient {
         input: StopConfigurationRecorderRequest,
     ) -> Result<(), RusotoError<StopConfigurationRecorderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopConfigurationRecorder");
         request.add_header(
             "x-amz-target",
             "StarlingDoveService.StopConfigurationRecorder",
@@ -10562,6 +11279,7 @@ impl ConfigService for ConfigServiceClient {
         input: TagResourceRequest,
     ) -> Result<(), RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "StarlingDoveService.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10579,6 +11297,7 @@ impl ConfigService for ConfigServiceClient {
         input: UntagResourceRequest,
     ) -> Result<(), RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "StarlingDoveService.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10590,3 +11309,966 @@ impl ConfigService for ConfigServiceClient {
         Ok(())
     }
 }
+
+/// A blocking client for the Config Service API, running the calls of a `ConfigServiceClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `ConfigServiceClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ConfigServiceBlockingClient {
+    client: ConfigServiceClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ConfigServiceBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> ConfigServiceBlockingClient {
+        ConfigServiceBlockingClient::from_client(ConfigServiceClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ConfigServiceBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ConfigServiceBlockingClient::from_client(ConfigServiceClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> ConfigServiceBlockingClient {
+        ConfigServiceBlockingClient::from_client(ConfigServiceClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> ConfigServiceBlockingClient {
+        ConfigServiceBlockingClient::from_client(ConfigServiceClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: ConfigServiceClient) -> ConfigServiceBlockingClient {
+        ConfigServiceBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> ConfigServiceBlockingClient {
+        ConfigServiceBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ConfigServiceBlockingClient {
+        ConfigServiceBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`ConfigService::batch_get_aggregate_resource_config`].
+    pub fn batch_get_aggregate_resource_config(
+        &self,
+        input: BatchGetAggregateResourceConfigRequest,
+    ) -> Result<
+        BatchGetAggregateResourceConfigResponse,
+        RusotoError<BatchGetAggregateResourceConfigError>,
+    > {
+        self.runtime
+            .block_on(self.client.batch_get_aggregate_resource_config(input))
+    }
+
+    /// Blocking version of [`ConfigService::batch_get_resource_config`].
+    pub fn batch_get_resource_config(
+        &self,
+        input: BatchGetResourceConfigRequest,
+    ) -> Result<BatchGetResourceConfigResponse, RusotoError<BatchGetResourceConfigError>> {
+        self.runtime
+            .block_on(self.client.batch_get_resource_config(input))
+    }
+
+    /// Blocking version of [`ConfigService::delete_aggregation_authorization`].
+    pub fn delete_aggregation_authorization(
+        &self,
+        input: DeleteAggregationAuthorizationRequest,
+    ) -> Result<(), RusotoError<DeleteAggregationAuthorizationError>> {
+        self.runtime
+            .block_on(self.client.delete_aggregation_authorization(input))
+    }
+
+    /// Blocking version of [`ConfigService::delete_config_rule`].
+    pub fn delete_config_rule(
+        &self,
+        input: DeleteConfigRuleRequest,
+    ) -> Result<(), RusotoError<DeleteConfigRuleError>> {
+        self.runtime.block_on(self.client.delete_config_rule(input))
+    }
+
+    /// Blocking version of [`ConfigService::delete_configuration_aggregator`].
+    pub fn delete_configuration_aggregator(
+        &self,
+        input: DeleteConfigurationAggregatorRequest,
+    ) -> Result<(), RusotoError<DeleteConfigurationAggregatorError>> {
+        self.runtime
+            .block_on(self.client.delete_configuration_aggregator(input))
+    }
+
+    /// Blocking version of [`ConfigService::delete_configuration_recorder`].
+    pub fn delete_configuration_recorder(
+        &self,
+        input: DeleteConfigurationRecorderRequest,
+    ) -> Result<(), RusotoError<DeleteConfigurationRecorderError>> {
+        self.runtime
+            .block_on(self.client.delete_configuration_recorder(input))
+    }
+
+    /// Blocking version of [`ConfigService::delete_conformance_pack`].
+    pub fn delete_conformance_pack(
+        &self,
+        input: DeleteConformancePackRequest,
+    ) -> Result<(), RusotoError<DeleteConformancePackError>> {
+        self.runtime
+            .block_on(self.client.delete_conformance_pack(input))
+    }
+
+    /// Blocking version of [`ConfigService::delete_delivery_channel`].
+    pub fn delete_delivery_channel(
+        &self,
+        input: DeleteDeliveryChannelRequest,
+    ) -> Result<(), RusotoError<DeleteDeliveryChannelError>> {
+        self.runtime
+            .block_on(self.client.delete_delivery_channel(input))
+    }
+
+    /// Blocking version of [`ConfigService::delete_evaluation_results`].
+    pub fn delete_evaluation_results(
+        &self,
+        input: DeleteEvaluationResultsRequest,
+    ) -> Result<DeleteEvaluationResultsResponse, RusotoError<DeleteEvaluationResultsError>> {
+        self.runtime
+            .block_on(self.client.delete_evaluation_results(input))
+    }
+
+    /// Blocking version of [`ConfigService::delete_organization_config_rule`].
+    pub fn delete_organization_config_rule(
+        &self,
+        input: DeleteOrganizationConfigRuleRequest,
+    ) -> Result<(), RusotoError<DeleteOrganizationConfigRuleError>> {
+        self.runtime
+            .block_on(self.client.delete_organization_config_rule(input))
+    }
+
+    /// Blocking version of [`ConfigService::delete_organization_conformance_pack`].
+    pub fn delete_organization_conformance_pack(
+        &self,
+        input: DeleteOrganizationConformancePackRequest,
+    ) -> Result<(), RusotoError<DeleteOrganizationConformancePackError>> {
+        self.runtime
+            .block_on(self.client.delete_organization_conformance_pack(input))
+    }
+
+    /// Blocking version of [`ConfigService::delete_pending_aggregation_request`].
+    pub fn delete_pending_aggregation_request(
+        &self,
+        input: DeletePendingAggregationRequestRequest,
+    ) -> Result<(), RusotoError<DeletePendingAggregationRequestError>> {
+        self.runtime
+            .block_on(self.client.delete_pending_aggregation_request(input))
+    }
+
+    /// Blocking version of [`ConfigService::delete_remediation_configuration`].
+    pub fn delete_remediation_configuration(
+        &self,
+        input: DeleteRemediationConfigurationRequest,
+    ) -> Result<
+        DeleteRemediationConfigurationResponse,
+        RusotoError<DeleteRemediationConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_remediation_configuration(input))
+    }
+
+    /// Blocking version of [`ConfigService::delete_remediation_exceptions`].
+    pub fn delete_remediation_exceptions(
+        &self,
+        input: DeleteRemediationExceptionsRequest,
+    ) -> Result<DeleteRemediationExceptionsResponse, RusotoError<DeleteRemediationExceptionsError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_remediation_exceptions(input))
+    }
+
+    /// Blocking version of [`ConfigService::delete_resource_config`].
+    pub fn delete_resource_config(
+        &self,
+        input: DeleteResourceConfigRequest,
+    ) -> Result<(), RusotoError<DeleteResourceConfigError>> {
+        self.runtime
+            .block_on(self.client.delete_resource_config(input))
+    }
+
+    /// Blocking version of [`ConfigService::delete_retention_configuration`].
+    pub fn delete_retention_configuration(
+        &self,
+        input: DeleteRetentionConfigurationRequest,
+    ) -> Result<(), RusotoError<DeleteRetentionConfigurationError>> {
+        self.runtime
+            .block_on(self.client.delete_retention_configuration(input))
+    }
+
+    /// Blocking version of [`ConfigService::delete_stored_query`].
+    pub fn delete_stored_query(
+        &self,
+        input: DeleteStoredQueryRequest,
+    ) -> Result<DeleteStoredQueryResponse, RusotoError<DeleteStoredQueryError>> {
+        self.runtime
+            .block_on(self.client.delete_stored_query(input))
+    }
+
+    /// Blocking version of [`ConfigService::deliver_config_snapshot`].
+    pub fn deliver_config_snapshot(
+        &self,
+        input: DeliverConfigSnapshotRequest,
+    ) -> Result<DeliverConfigSnapshotResponse, RusotoError<DeliverConfigSnapshotError>> {
+        self.runtime
+            .block_on(self.client.deliver_config_snapshot(input))
+    }
+
+    /// Blocking version of [`ConfigService::describe_aggregate_compliance_by_config_rules`].
+    pub fn describe_aggregate_compliance_by_config_rules(
+        &self,
+        input: DescribeAggregateComplianceByConfigRulesRequest,
+    ) -> Result<
+        DescribeAggregateComplianceByConfigRulesResponse,
+        RusotoError<DescribeAggregateComplianceByConfigRulesError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_aggregate_compliance_by_config_rules(input),
+        )
+    }
+
+    /// Blocking version of [`ConfigService::describe_aggregation_authorizations`].
+    pub fn describe_aggregation_authorizations(
+        &self,
+        input: DescribeAggregationAuthorizationsRequest,
+    ) -> Result<
+        DescribeAggregationAuthorizationsResponse,
+        RusotoError<DescribeAggregationAuthorizationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_aggregation_authorizations(input))
+    }
+
+    /// Blocking version of [`ConfigService::describe_compliance_by_config_rule`].
+    pub fn describe_compliance_by_config_rule(
+        &self,
+        input: DescribeComplianceByConfigRuleRequest,
+    ) -> Result<
+        DescribeComplianceByConfigRuleResponse,
+        RusotoError<DescribeComplianceByConfigRuleError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_compliance_by_config_rule(input))
+    }
+
+    /// Blocking version of [`ConfigService::describe_compliance_by_resource`].
+    pub fn describe_compliance_by_resource(
+        &self,
+        input: DescribeComplianceByResourceRequest,
+    ) -> Result<DescribeComplianceByResourceResponse, RusotoError<DescribeComplianceByResourceError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_compliance_by_resource(input))
+    }
+
+    /// Blocking version of [`ConfigService::describe_config_rule_evaluation_status`].
+    pub fn describe_config_rule_evaluation_status(
+        &self,
+        input: DescribeConfigRuleEvaluationStatusRequest,
+    ) -> Result<
+        DescribeConfigRuleEvaluationStatusResponse,
+        RusotoError<DescribeConfigRuleEvaluationStatusError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_config_rule_evaluation_status(input))
+    }
+
+    /// Blocking version of [`ConfigService::describe_config_rules`].
+    pub fn describe_config_rules(
+        &self,
+        input: DescribeConfigRulesRequest,
+    ) -> Result<DescribeConfigRulesResponse, RusotoError<DescribeConfigRulesError>> {
+        self.runtime
+            .block_on(self.client.describe_config_rules(input))
+    }
+
+    /// Blocking version of [`ConfigService::describe_configuration_aggregator_sources_status`].
+    pub fn describe_configuration_aggregator_sources_status(
+        &self,
+        input: DescribeConfigurationAggregatorSourcesStatusRequest,
+    ) -> Result<
+        DescribeConfigurationAggregatorSourcesStatusResponse,
+        RusotoError<DescribeConfigurationAggregatorSourcesStatusError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_configuration_aggregator_sources_status(input),
+        )
+    }
+
+    /// Blocking version of [`ConfigService::describe_configuration_aggregators`].
+    pub fn describe_configuration_aggregators(
+        &self,
+        input: DescribeConfigurationAggregatorsRequest,
+    ) -> Result<
+        DescribeConfigurationAggregatorsResponse,
+        RusotoError<DescribeConfigurationAggregatorsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_configuration_aggregators(input))
+    }
+
+    /// Blocking version of [`ConfigService::describe_configuration_recorder_status`].
+    pub fn describe_configuration_recorder_status(
+        &self,
+        input: DescribeConfigurationRecorderStatusRequest,
+    ) -> Result<
+        DescribeConfigurationRecorderStatusResponse,
+        RusotoError<DescribeConfigurationRecorderStatusError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_configuration_recorder_status(input))
+    }
+
+    /// Blocking version of [`ConfigService::describe_configuration_recorders`].
+    pub fn describe_configuration_recorders(
+        &self,
+        input: DescribeConfigurationRecordersRequest,
+    ) -> Result<
+        DescribeConfigurationRecordersResponse,
+        RusotoError<DescribeConfigurationRecordersError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_configuration_recorders(input))
+    }
+
+    /// Blocking version of [`ConfigService::describe_conformance_pack_compliance`].
+    pub fn describe_conformance_pack_compliance(
+        &self,
+        input: DescribeConformancePackComplianceRequest,
+    ) -> Result<
+        DescribeConformancePackComplianceResponse,
+        RusotoError<DescribeConformancePackComplianceError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_conformance_pack_compliance(input))
+    }
+
+    /// Blocking version of [`ConfigService::describe_conformance_pack_status`].
+    pub fn describe_conformance_pack_status(
+        &self,
+        input: DescribeConformancePackStatusRequest,
+    ) -> Result<
+        DescribeConformancePackStatusResponse,
+        RusotoError<DescribeConformancePackStatusError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_conformance_pack_status(input))
+    }
+
+    /// Blocking version of [`ConfigService::describe_conformance_packs`].
+    pub fn describe_conformance_packs(
+        &self,
+        input: DescribeConformancePacksRequest,
+    ) -> Result<DescribeConformancePacksResponse, RusotoError<DescribeConformancePacksError>> {
+        self.runtime
+            .block_on(self.client.describe_conformance_packs(input))
+    }
+
+    /// Blocking version of [`ConfigService::describe_delivery_channel_status`].
+    pub fn describe_delivery_channel_status(
+        &self,
+        input: DescribeDeliveryChannelStatusRequest,
+    ) -> Result<
+        DescribeDeliveryChannelStatusResponse,
+        RusotoError<DescribeDeliveryChannelStatusError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_delivery_channel_status(input))
+    }
+
+    /// Blocking version of [`ConfigService::describe_delivery_channels`].
+    pub fn describe_delivery_channels(
+        &self,
+        input: DescribeDeliveryChannelsRequest,
+    ) -> Result<DescribeDeliveryChannelsResponse, RusotoError<DescribeDeliveryChannelsError>> {
+        self.runtime
+            .block_on(self.client.describe_delivery_channels(input))
+    }
+
+    /// Blocking version of [`ConfigService::describe_organization_config_rule_statuses`].
+    pub fn describe_organization_config_rule_statuses(
+        &self,
+        input: DescribeOrganizationConfigRuleStatusesRequest,
+    ) -> Result<
+        DescribeOrganizationConfigRuleStatusesResponse,
+        RusotoError<DescribeOrganizationConfigRuleStatusesError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_organization_config_rule_statuses(input),
+        )
+    }
+
+    /// Blocking version of [`ConfigService::describe_organization_config_rules`].
+    pub fn describe_organization_config_rules(
+        &self,
+        input: DescribeOrganizationConfigRulesRequest,
+    ) -> Result<
+        DescribeOrganizationConfigRulesResponse,
+        RusotoError<DescribeOrganizationConfigRulesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_organization_config_rules(input))
+    }
+
+    /// Blocking version of [`ConfigService::describe_organization_conformance_pack_statuses`].
+    pub fn describe_organization_conformance_pack_statuses(
+        &self,
+        input: DescribeOrganizationConformancePackStatusesRequest,
+    ) -> Result<
+        DescribeOrganizationConformancePackStatusesResponse,
+        RusotoError<DescribeOrganizationConformancePackStatusesError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_organization_conformance_pack_statuses(input),
+        )
+    }
+
+    /// Blocking version of [`ConfigService::describe_organization_conformance_packs`].
+    pub fn describe_organization_conformance_packs(
+        &self,
+        input: DescribeOrganizationConformancePacksRequest,
+    ) -> Result<
+        DescribeOrganizationConformancePacksResponse,
+        RusotoError<DescribeOrganizationConformancePacksError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_organization_conformance_packs(input))
+    }
+
+    /// Blocking version of [`ConfigService::describe_pending_aggregation_requests`].
+    pub fn describe_pending_aggregation_requests(
+        &self,
+        input: DescribePendingAggregationRequestsRequest,
+    ) -> Result<
+        DescribePendingAggregationRequestsResponse,
+        RusotoError<DescribePendingAggregationRequestsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_pending_aggregation_requests(input))
+    }
+
+    /// Blocking version of [`ConfigService::describe_remediation_configurations`].
+    pub fn describe_remediation_configurations(
+        &self,
+        input: DescribeRemediationConfigurationsRequest,
+    ) -> Result<
+        DescribeRemediationConfigurationsResponse,
+        RusotoError<DescribeRemediationConfigurationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_remediation_configurations(input))
+    }
+
+    /// Blocking version of [`ConfigService::describe_remediation_exceptions`].
+    pub fn describe_remediation_exceptions(
+        &self,
+        input: DescribeRemediationExceptionsRequest,
+    ) -> Result<
+        DescribeRemediationExceptionsResponse,
+        RusotoError<DescribeRemediationExceptionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_remediation_exceptions(input))
+    }
+
+    /// Blocking version of [`ConfigService::describe_remediation_execution_status`].
+    pub fn describe_remediation_execution_status(
+        &self,
+        input: DescribeRemediationExecutionStatusRequest,
+    ) -> Result<
+        DescribeRemediationExecutionStatusResponse,
+        RusotoError<DescribeRemediationExecutionStatusError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_remediation_execution_status(input))
+    }
+
+    /// Blocking version of [`ConfigService::describe_retention_configurations`].
+    pub fn describe_retention_configurations(
+        &self,
+        input: DescribeRetentionConfigurationsRequest,
+    ) -> Result<
+        DescribeRetentionConfigurationsResponse,
+        RusotoError<DescribeRetentionConfigurationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_retention_configurations(input))
+    }
+
+    /// Blocking version of [`ConfigService::get_aggregate_compliance_details_by_config_rule`].
+    pub fn get_aggregate_compliance_details_by_config_rule(
+        &self,
+        input: GetAggregateComplianceDetailsByConfigRuleRequest,
+    ) -> Result<
+        GetAggregateComplianceDetailsByConfigRuleResponse,
+        RusotoError<GetAggregateComplianceDetailsByConfigRuleError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .get_aggregate_compliance_details_by_config_rule(input),
+        )
+    }
+
+    /// Blocking version of [`ConfigService::get_aggregate_config_rule_compliance_summary`].
+    pub fn get_aggregate_config_rule_compliance_summary(
+        &self,
+        input: GetAggregateConfigRuleComplianceSummaryRequest,
+    ) -> Result<
+        GetAggregateConfigRuleComplianceSummaryResponse,
+        RusotoError<GetAggregateConfigRuleComplianceSummaryError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .get_aggregate_config_rule_compliance_summary(input),
+        )
+    }
+
+    /// Blocking version of [`ConfigService::get_aggregate_discovered_resource_counts`].
+    pub fn get_aggregate_discovered_resource_counts(
+        &self,
+        input: GetAggregateDiscoveredResourceCountsRequest,
+    ) -> Result<
+        GetAggregateDiscoveredResourceCountsResponse,
+        RusotoError<GetAggregateDiscoveredResourceCountsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_aggregate_discovered_resource_counts(input))
+    }
+
+    /// Blocking version of [`ConfigService::get_aggregate_resource_config`].
+    pub fn get_aggregate_resource_config(
+        &self,
+        input: GetAggregateResourceConfigRequest,
+    ) -> Result<GetAggregateResourceConfigResponse, RusotoError<GetAggregateResourceConfigError>>
+    {
+        self.runtime
+            .block_on(self.client.get_aggregate_resource_config(input))
+    }
+
+    /// Blocking version of [`ConfigService::get_compliance_details_by_config_rule`].
+    pub fn get_compliance_details_by_config_rule(
+        &self,
+        input: GetComplianceDetailsByConfigRuleRequest,
+    ) -> Result<
+        GetComplianceDetailsByConfigRuleResponse,
+        RusotoError<GetComplianceDetailsByConfigRuleError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_compliance_details_by_config_rule(input))
+    }
+
+    /// Blocking version of [`ConfigService::get_compliance_details_by_resource`].
+    pub fn get_compliance_details_by_resource(
+        &self,
+        input: GetComplianceDetailsByResourceRequest,
+    ) -> Result<
+        GetComplianceDetailsByResourceResponse,
+        RusotoError<GetComplianceDetailsByResourceError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_compliance_details_by_resource(input))
+    }
+
+    /// Blocking version of [`ConfigService::get_compliance_summary_by_config_rule`].
+    pub fn get_compliance_summary_by_config_rule(
+        &self,
+    ) -> Result<
+        GetComplianceSummaryByConfigRuleResponse,
+        RusotoError<GetComplianceSummaryByConfigRuleError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_compliance_summary_by_config_rule())
+    }
+
+    /// Blocking version of [`ConfigService::get_compliance_summary_by_resource_type`].
+    pub fn get_compliance_summary_by_resource_type(
+        &self,
+        input: GetComplianceSummaryByResourceTypeRequest,
+    ) -> Result<
+        GetComplianceSummaryByResourceTypeResponse,
+        RusotoError<GetComplianceSummaryByResourceTypeError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_compliance_summary_by_resource_type(input))
+    }
+
+    /// Blocking version of [`ConfigService::get_conformance_pack_compliance_details`].
+    pub fn get_conformance_pack_compliance_details(
+        &self,
+        input: GetConformancePackComplianceDetailsRequest,
+    ) -> Result<
+        GetConformancePackComplianceDetailsResponse,
+        RusotoError<GetConformancePackComplianceDetailsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_conformance_pack_compliance_details(input))
+    }
+
+    /// Blocking version of [`ConfigService::get_conformance_pack_compliance_summary`].
+    pub fn get_conformance_pack_compliance_summary(
+        &self,
+        input: GetConformancePackComplianceSummaryRequest,
+    ) -> Result<
+        GetConformancePackComplianceSummaryResponse,
+        RusotoError<GetConformancePackComplianceSummaryError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_conformance_pack_compliance_summary(input))
+    }
+
+    /// Blocking version of [`ConfigService::get_discovered_resource_counts`].
+    pub fn get_discovered_resource_counts(
+        &self,
+        input: GetDiscoveredResourceCountsRequest,
+    ) -> Result<GetDiscoveredResourceCountsResponse, RusotoError<GetDiscoveredResourceCountsError>>
+    {
+        self.runtime
+            .block_on(self.client.get_discovered_resource_counts(input))
+    }
+
+    /// Blocking version of [`ConfigService::get_organization_config_rule_detailed_status`].
+    pub fn get_organization_config_rule_detailed_status(
+        &self,
+        input: GetOrganizationConfigRuleDetailedStatusRequest,
+    ) -> Result<
+        GetOrganizationConfigRuleDetailedStatusResponse,
+        RusotoError<GetOrganizationConfigRuleDetailedStatusError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .get_organization_config_rule_detailed_status(input),
+        )
+    }
+
+    /// Blocking version of [`ConfigService::get_organization_conformance_pack_detailed_status`].
+    pub fn get_organization_conformance_pack_detailed_status(
+        &self,
+        input: GetOrganizationConformancePackDetailedStatusRequest,
+    ) -> Result<
+        GetOrganizationConformancePackDetailedStatusResponse,
+        RusotoError<GetOrganizationConformancePackDetailedStatusError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .get_organization_conformance_pack_detailed_status(input),
+        )
+    }
+
+    /// Blocking version of [`ConfigService::get_resource_config_history`].
+    pub fn get_resource_config_history(
+        &self,
+        input: GetResourceConfigHistoryRequest,
+    ) -> Result<GetResourceConfigHistoryResponse, RusotoError<GetResourceConfigHistoryError>> {
+        self.runtime
+            .block_on(self.client.get_resource_config_history(input))
+    }
+
+    /// Blocking version of [`ConfigService::get_stored_query`].
+    pub fn get_stored_query(
+        &self,
+        input: GetStoredQueryRequest,
+    ) -> Result<GetStoredQueryResponse, RusotoError<GetStoredQueryError>> {
+        self.runtime.block_on(self.client.get_stored_query(input))
+    }
+
+    /// Blocking version of [`ConfigService::list_aggregate_discovered_resources`].
+    pub fn list_aggregate_discovered_resources(
+        &self,
+        input: ListAggregateDiscoveredResourcesRequest,
+    ) -> Result<
+        ListAggregateDiscoveredResourcesResponse,
+        RusotoError<ListAggregateDiscoveredResourcesError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_aggregate_discovered_resources(input))
+    }
+
+    /// Blocking version of [`ConfigService::list_discovered_resources`].
+    pub fn list_discovered_resources(
+        &self,
+        input: ListDiscoveredResourcesRequest,
+    ) -> Result<ListDiscoveredResourcesResponse, RusotoError<ListDiscoveredResourcesError>> {
+        self.runtime
+            .block_on(self.client.list_discovered_resources(input))
+    }
+
+    /// Blocking version of [`ConfigService::list_stored_queries`].
+    pub fn list_stored_queries(
+        &self,
+        input: ListStoredQueriesRequest,
+    ) -> Result<ListStoredQueriesResponse, RusotoError<ListStoredQueriesError>> {
+        self.runtime
+            .block_on(self.client.list_stored_queries(input))
+    }
+
+    /// Blocking version of [`ConfigService::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`ConfigService::put_aggregation_authorization`].
+    pub fn put_aggregation_authorization(
+        &self,
+        input: PutAggregationAuthorizationRequest,
+    ) -> Result<PutAggregationAuthorizationResponse, RusotoError<PutAggregationAuthorizationError>>
+    {
+        self.runtime
+            .block_on(self.client.put_aggregation_authorization(input))
+    }
+
+    /// Blocking version of [`ConfigService::put_config_rule`].
+    pub fn put_config_rule(
+        &self,
+        input: PutConfigRuleRequest,
+    ) -> Result<(), RusotoError<PutConfigRuleError>> {
+        self.runtime.block_on(self.client.put_config_rule(input))
+    }
+
+    /// Blocking version of [`ConfigService::put_configuration_aggregator`].
+    pub fn put_configuration_aggregator(
+        &self,
+        input: PutConfigurationAggregatorRequest,
+    ) -> Result<PutConfigurationAggregatorResponse, RusotoError<PutConfigurationAggregatorError>>
+    {
+        self.runtime
+            .block_on(self.client.put_configuration_aggregator(input))
+    }
+
+    /// Blocking version of [`ConfigService::put_configuration_recorder`].
+    pub fn put_configuration_recorder(
+        &self,
+        input: PutConfigurationRecorderRequest,
+    ) -> Result<(), RusotoError<PutConfigurationRecorderError>> {
+        self.runtime
+            .block_on(self.client.put_configuration_recorder(input))
+    }
+
+    /// Blocking version of [`ConfigService::put_conformance_pack`].
+    pub fn put_conformance_pack(
+        &self,
+        input: PutConformancePackRequest,
+    ) -> Result<PutConformancePackResponse, RusotoError<PutConformancePackError>> {
+        self.runtime
+            .block_on(self.client.put_conformance_pack(input))
+    }
+
+    /// Blocking version of [`ConfigService::put_delivery_channel`].
+    pub fn put_delivery_channel(
+        &self,
+        input: PutDeliveryChannelRequest,
+    ) -> Result<(), RusotoError<PutDeliveryChannelError>> {
+        self.runtime
+            .block_on(self.client.put_delivery_channel(input))
+    }
+
+    /// Blocking version of [`ConfigService::put_evaluations`].
+    pub fn put_evaluations(
+        &self,
+        input: PutEvaluationsRequest,
+    ) -> Result<PutEvaluationsResponse, RusotoError<PutEvaluationsError>> {
+        self.runtime.block_on(self.client.put_evaluations(input))
+    }
+
+    /// Blocking version of [`ConfigService::put_external_evaluation`].
+    pub fn put_external_evaluation(
+        &self,
+        input: PutExternalEvaluationRequest,
+    ) -> Result<PutExternalEvaluationResponse, RusotoError<PutExternalEvaluationError>> {
+        self.runtime
+            .block_on(self.client.put_external_evaluation(input))
+    }
+
+    /// Blocking version of [`ConfigService::put_organization_config_rule`].
+    pub fn put_organization_config_rule(
+        &self,
+        input: PutOrganizationConfigRuleRequest,
+    ) -> Result<PutOrganizationConfigRuleResponse, RusotoError<PutOrganizationConfigRuleError>>
+    {
+        self.runtime
+            .block_on(self.client.put_organization_config_rule(input))
+    }
+
+    /// Blocking version of [`ConfigService::put_organization_conformance_pack`].
+    pub fn put_organization_conformance_pack(
+        &self,
+        input: PutOrganizationConformancePackRequest,
+    ) -> Result<
+        PutOrganizationConformancePackResponse,
+        RusotoError<PutOrganizationConformancePackError>,
+    > {
+        self.runtime
+            .block_on(self.client.put_organization_conformance_pack(input))
+    }
+
+    /// Blocking version of [`ConfigService::put_remediation_configurations`].
+    pub fn put_remediation_configurations(
+        &self,
+        input: PutRemediationConfigurationsRequest,
+    ) -> Result<PutRemediationConfigurationsResponse, RusotoError<PutRemediationConfigurationsError>>
+    {
+        self.runtime
+            .block_on(self.client.put_remediation_configurations(input))
+    }
+
+    /// Blocking version of [`ConfigService::put_remediation_exceptions`].
+    pub fn put_remediation_exceptions(
+        &self,
+        input: PutRemediationExceptionsRequest,
+    ) -> Result<PutRemediationExceptionsResponse, RusotoError<PutRemediationExceptionsError>> {
+        self.runtime
+            .block_on(self.client.put_remediation_exceptions(input))
+    }
+
+    /// Blocking version of [`ConfigService::put_resource_config`].
+    pub fn put_resource_config(
+        &self,
+        input: PutResourceConfigRequest,
+    ) -> Result<(), RusotoError<PutResourceConfigError>> {
+        self.runtime
+            .block_on(self.client.put_resource_config(input))
+    }
+
+    /// Blocking version of [`ConfigService::put_retention_configuration`].
+    pub fn put_retention_configuration(
+        &self,
+        input: PutRetentionConfigurationRequest,
+    ) -> Result<PutRetentionConfigurationResponse, RusotoError<PutRetentionConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.put_retention_configuration(input))
+    }
+
+    /// Blocking version of [`ConfigService::put_stored_query`].
+    pub fn put_stored_query(
+        &self,
+        input: PutStoredQueryRequest,
+    ) -> Result<PutStoredQueryResponse, RusotoError<PutStoredQueryError>> {
+        self.runtime.block_on(self.client.put_stored_query(input))
+    }
+
+    /// Blocking version of [`ConfigService::select_aggregate_resource_config`].
+    pub fn select_aggregate_resource_config(
+        &self,
+        input: SelectAggregateResourceConfigRequest,
+    ) -> Result<
+        SelectAggregateResourceConfigResponse,
+        RusotoError<SelectAggregateResourceConfigError>,
+    > {
+        self.runtime
+            .block_on(self.client.select_aggregate_resource_config(input))
+    }
+
+    /// Blocking version of [`ConfigService::select_resource_config`].
+    pub fn select_resource_config(
+        &self,
+        input: SelectResourceConfigRequest,
+    ) -> Result<SelectResourceConfigResponse, RusotoError<SelectResourceConfigError>> {
+        self.runtime
+            .block_on(self.client.select_resource_config(input))
+    }
+
+    /// Blocking version of [`ConfigService::start_config_rules_evaluation`].
+    pub fn start_config_rules_evaluation(
+        &self,
+        input: StartConfigRulesEvaluationRequest,
+    ) -> Result<StartConfigRulesEvaluationResponse, RusotoError<StartConfigRulesEvaluationError>>
+    {
+        self.runtime
+            .block_on(self.client.start_config_rules_evaluation(input))
+    }
+
+    /// Blocking version of [`ConfigService::start_configuration_recorder`].
+    pub fn start_configuration_recorder(
+        &self,
+        input: StartConfigurationRecorderRequest,
+    ) -> Result<(), RusotoError<StartConfigurationRecorderError>> {
+        self.runtime
+            .block_on(self.client.start_configuration_recorder(input))
+    }
+
+    /// Blocking version of [`ConfigService::start_remediation_execution`].
+    pub fn start_remediation_execution(
+        &self,
+        input: StartRemediationExecutionRequest,
+    ) -> Result<StartRemediationExecutionResponse, RusotoError<StartRemediationExecutionError>>
+    {
+        self.runtime
+            .block_on(self.client.start_remediation_execution(input))
+    }
+
+    /// Blocking version of [`ConfigService::stop_configuration_recorder`].
+    pub fn stop_configuration_recorder(
+        &self,
+        input: StopConfigurationRecorderRequest,
+    ) -> Result<(), RusotoError<StopConfigurationRecorderError>> {
+        self.runtime
+            .block_on(self.client.stop_configuration_recorder(input))
+    }
+
+    /// Blocking version of [`ConfigService::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`ConfigService::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+}
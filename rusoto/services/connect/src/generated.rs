@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -3139,39 +3140,62 @@ impl AssociateApprovedOriginError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(AssociateApprovedOriginError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateApprovedOriginError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AssociateApprovedOriginError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateApprovedOriginError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AssociateApprovedOriginError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateApprovedOriginError::InvalidRequest,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(AssociateApprovedOriginError::ResourceConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateApprovedOriginError::ResourceConflict,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateApprovedOriginError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateApprovedOriginError::ResourceNotFound,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(
-                        AssociateApprovedOriginError::ServiceQuotaExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateApprovedOriginError::ServiceQuotaExceeded,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AssociateApprovedOriginError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateApprovedOriginError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3217,36 +3241,54 @@ impl AssociateInstanceStorageConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
-                        AssociateInstanceStorageConfigError::InternalService(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateInstanceStorageConfigError::InternalService,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        AssociateInstanceStorageConfigError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateInstanceStorageConfigError::InvalidParameter,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        AssociateInstanceStorageConfigError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateInstanceStorageConfigError::InvalidRequest,
                     )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(
-                        AssociateInstanceStorageConfigError::ResourceConflict(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateInstanceStorageConfigError::ResourceConflict,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        AssociateInstanceStorageConfigError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateInstanceStorageConfigError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AssociateInstanceStorageConfigError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateInstanceStorageConfigError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3301,39 +3343,62 @@ impl AssociateLambdaFunctionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(AssociateLambdaFunctionError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateLambdaFunctionError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AssociateLambdaFunctionError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateLambdaFunctionError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AssociateLambdaFunctionError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateLambdaFunctionError::InvalidRequest,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(AssociateLambdaFunctionError::ResourceConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateLambdaFunctionError::ResourceConflict,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateLambdaFunctionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateLambdaFunctionError::ResourceNotFound,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(
-                        AssociateLambdaFunctionError::ServiceQuotaExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateLambdaFunctionError::ServiceQuotaExceeded,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AssociateLambdaFunctionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateLambdaFunctionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3379,29 +3444,62 @@ impl AssociateLexBotError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(AssociateLexBotError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateLexBotError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AssociateLexBotError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateLexBotError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AssociateLexBotError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateLexBotError::InvalidRequest,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(AssociateLexBotError::ResourceConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateLexBotError::ResourceConflict,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateLexBotError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateLexBotError::ResourceNotFound,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(AssociateLexBotError::ServiceQuotaExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateLexBotError::ServiceQuotaExceeded,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AssociateLexBotError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateLexBotError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3445,31 +3543,46 @@ impl AssociateRoutingProfileQueuesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
-                        AssociateRoutingProfileQueuesError::InternalService(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateRoutingProfileQueuesError::InternalService,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        AssociateRoutingProfileQueuesError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateRoutingProfileQueuesError::InvalidParameter,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        AssociateRoutingProfileQueuesError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateRoutingProfileQueuesError::InvalidRequest,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        AssociateRoutingProfileQueuesError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateRoutingProfileQueuesError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AssociateRoutingProfileQueuesError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateRoutingProfileQueuesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3519,37 +3632,62 @@ impl AssociateSecurityKeyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(AssociateSecurityKeyError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateSecurityKeyError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AssociateSecurityKeyError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateSecurityKeyError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AssociateSecurityKeyError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateSecurityKeyError::InvalidRequest,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(AssociateSecurityKeyError::ResourceConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateSecurityKeyError::ResourceConflict,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateSecurityKeyError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateSecurityKeyError::ResourceNotFound,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(AssociateSecurityKeyError::ServiceQuotaExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateSecurityKeyError::ServiceQuotaExceeded,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AssociateSecurityKeyError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateSecurityKeyError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3597,32 +3735,70 @@ impl CreateContactFlowError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DuplicateResourceException" => {
-                    return RusotoError::Service(CreateContactFlowError::DuplicateResource(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateContactFlowError::DuplicateResource,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateContactFlowError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateContactFlowError::InternalService,
+                    )
                 }
                 "InvalidContactFlowException" => {
-                    return RusotoError::Service(CreateContactFlowError::InvalidContactFlow(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateContactFlowError::InvalidContactFlow,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateContactFlowError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateContactFlowError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateContactFlowError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateContactFlowError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateContactFlowError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateContactFlowError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateContactFlowError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateContactFlowError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateContactFlowError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateContactFlowError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3665,21 +3841,46 @@ impl CreateInstanceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateInstanceError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInstanceError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateInstanceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInstanceError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateInstanceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInstanceError::ResourceNotFound,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateInstanceError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInstanceError::ServiceQuotaExceeded,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateInstanceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInstanceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3721,31 +3922,46 @@ impl CreateIntegrationAssociationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DuplicateResourceException" => {
-                    return RusotoError::Service(
-                        CreateIntegrationAssociationError::DuplicateResource(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIntegrationAssociationError::DuplicateResource,
                     )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(
-                        CreateIntegrationAssociationError::InternalService(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIntegrationAssociationError::InternalService,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateIntegrationAssociationError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateIntegrationAssociationError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        CreateIntegrationAssociationError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIntegrationAssociationError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateIntegrationAssociationError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateIntegrationAssociationError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3793,29 +4009,62 @@ impl CreateQuickConnectError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DuplicateResourceException" => {
-                    return RusotoError::Service(CreateQuickConnectError::DuplicateResource(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateQuickConnectError::DuplicateResource,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateQuickConnectError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateQuickConnectError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateQuickConnectError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateQuickConnectError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateQuickConnectError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateQuickConnectError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateQuickConnectError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateQuickConnectError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateQuickConnectError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateQuickConnectError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateQuickConnectError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateQuickConnectError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3861,35 +4110,62 @@ impl CreateRoutingProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DuplicateResourceException" => {
-                    return RusotoError::Service(CreateRoutingProfileError::DuplicateResource(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRoutingProfileError::DuplicateResource,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateRoutingProfileError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRoutingProfileError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateRoutingProfileError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRoutingProfileError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateRoutingProfileError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRoutingProfileError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateRoutingProfileError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRoutingProfileError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateRoutingProfileError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRoutingProfileError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateRoutingProfileError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRoutingProfileError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3931,21 +4207,46 @@ impl CreateUseCaseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DuplicateResourceException" => {
-                    return RusotoError::Service(CreateUseCaseError::DuplicateResource(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUseCaseError::DuplicateResource,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateUseCaseError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUseCaseError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateUseCaseError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUseCaseError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateUseCaseError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUseCaseError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateUseCaseError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUseCaseError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3989,27 +4290,62 @@ impl CreateUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DuplicateResourceException" => {
-                    return RusotoError::Service(CreateUserError::DuplicateResource(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserError::DuplicateResource,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateUserError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateUserError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateUserError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateUserError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateUserError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4055,39 +4391,62 @@ impl CreateUserHierarchyGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DuplicateResourceException" => {
-                    return RusotoError::Service(CreateUserHierarchyGroupError::DuplicateResource(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateUserHierarchyGroupError::DuplicateResource,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateUserHierarchyGroupError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateUserHierarchyGroupError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateUserHierarchyGroupError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateUserHierarchyGroupError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateUserHierarchyGroupError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateUserHierarchyGroupError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateUserHierarchyGroupError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateUserHierarchyGroupError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateUserHierarchyGroupError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateUserHierarchyGroupError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateUserHierarchyGroupError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserHierarchyGroupError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4125,15 +4484,30 @@ impl DeleteInstanceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteInstanceError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInstanceError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteInstanceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInstanceError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteInstanceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInstanceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4171,26 +4545,38 @@ impl DeleteIntegrationAssociationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
-                        DeleteIntegrationAssociationError::InternalService(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIntegrationAssociationError::InternalService,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteIntegrationAssociationError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteIntegrationAssociationError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteIntegrationAssociationError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIntegrationAssociationError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteIntegrationAssociationError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteIntegrationAssociationError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4231,21 +4617,46 @@ impl DeleteQuickConnectError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteQuickConnectError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteQuickConnectError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteQuickConnectError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteQuickConnectError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteQuickConnectError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteQuickConnectError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteQuickConnectError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteQuickConnectError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteQuickConnectError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteQuickConnectError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4283,18 +4694,38 @@ impl DeleteUseCaseError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteUseCaseError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUseCaseError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteUseCaseError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUseCaseError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteUseCaseError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUseCaseError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteUseCaseError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUseCaseError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4333,21 +4764,46 @@ impl DeleteUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteUserError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteUserError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteUserError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteUserError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4389,34 +4845,54 @@ impl DeleteUserHierarchyGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteUserHierarchyGroupError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteUserHierarchyGroupError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteUserHierarchyGroupError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteUserHierarchyGroupError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteUserHierarchyGroupError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteUserHierarchyGroupError::InvalidRequest,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteUserHierarchyGroupError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteUserHierarchyGroupError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteUserHierarchyGroupError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteUserHierarchyGroupError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteUserHierarchyGroupError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserHierarchyGroupError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4459,30 +4935,54 @@ impl DescribeContactFlowError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ContactFlowNotPublishedException" => {
-                    return RusotoError::Service(DescribeContactFlowError::ContactFlowNotPublished(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeContactFlowError::ContactFlowNotPublished,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeContactFlowError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeContactFlowError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeContactFlowError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeContactFlowError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeContactFlowError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeContactFlowError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeContactFlowError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeContactFlowError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeContactFlowError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeContactFlowError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4519,15 +5019,30 @@ impl DescribeInstanceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeInstanceError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInstanceError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeInstanceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInstanceError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeInstanceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInstanceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4565,31 +5080,46 @@ impl DescribeInstanceAttributeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeInstanceAttributeError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeInstanceAttributeError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeInstanceAttributeError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeInstanceAttributeError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeInstanceAttributeError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeInstanceAttributeError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeInstanceAttributeError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeInstanceAttributeError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeInstanceAttributeError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeInstanceAttributeError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4631,31 +5161,46 @@ impl DescribeInstanceStorageConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
-                        DescribeInstanceStorageConfigError::InternalService(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInstanceStorageConfigError::InternalService,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        DescribeInstanceStorageConfigError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInstanceStorageConfigError::InvalidParameter,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        DescribeInstanceStorageConfigError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInstanceStorageConfigError::InvalidRequest,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeInstanceStorageConfigError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInstanceStorageConfigError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeInstanceStorageConfigError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeInstanceStorageConfigError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4701,27 +5246,46 @@ impl DescribeQuickConnectError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeQuickConnectError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeQuickConnectError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeQuickConnectError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeQuickConnectError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeQuickConnectError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeQuickConnectError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeQuickConnectError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeQuickConnectError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeQuickConnectError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeQuickConnectError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4761,29 +5325,46 @@ impl DescribeRoutingProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeRoutingProfileError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRoutingProfileError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeRoutingProfileError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRoutingProfileError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeRoutingProfileError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRoutingProfileError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeRoutingProfileError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRoutingProfileError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeRoutingProfileError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRoutingProfileError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4823,21 +5404,46 @@ impl DescribeUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeUserError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUserError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeUserError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUserError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeUserError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUserError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUserError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeUserError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUserError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4879,31 +5485,46 @@ impl DescribeUserHierarchyGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeUserHierarchyGroupError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeUserHierarchyGroupError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeUserHierarchyGroupError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeUserHierarchyGroupError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeUserHierarchyGroupError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeUserHierarchyGroupError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeUserHierarchyGroupError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeUserHierarchyGroupError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeUserHierarchyGroupError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeUserHierarchyGroupError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4945,31 +5566,46 @@ impl DescribeUserHierarchyStructureError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
-                        DescribeUserHierarchyStructureError::InternalService(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUserHierarchyStructureError::InternalService,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        DescribeUserHierarchyStructureError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUserHierarchyStructureError::InvalidParameter,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        DescribeUserHierarchyStructureError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUserHierarchyStructureError::InvalidRequest,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeUserHierarchyStructureError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUserHierarchyStructureError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeUserHierarchyStructureError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeUserHierarchyStructureError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5019,31 +5655,46 @@ impl DisassociateApprovedOriginError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DisassociateApprovedOriginError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateApprovedOriginError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DisassociateApprovedOriginError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateApprovedOriginError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DisassociateApprovedOriginError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateApprovedOriginError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateApprovedOriginError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateApprovedOriginError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DisassociateApprovedOriginError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateApprovedOriginError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5085,31 +5736,46 @@ impl DisassociateInstanceStorageConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
-                        DisassociateInstanceStorageConfigError::InternalService(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateInstanceStorageConfigError::InternalService,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        DisassociateInstanceStorageConfigError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateInstanceStorageConfigError::InvalidParameter,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        DisassociateInstanceStorageConfigError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateInstanceStorageConfigError::InvalidRequest,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DisassociateInstanceStorageConfigError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateInstanceStorageConfigError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
-                        DisassociateInstanceStorageConfigError::Throttling(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateInstanceStorageConfigError::Throttling,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5159,31 +5825,46 @@ impl DisassociateLambdaFunctionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DisassociateLambdaFunctionError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateLambdaFunctionError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DisassociateLambdaFunctionError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateLambdaFunctionError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DisassociateLambdaFunctionError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateLambdaFunctionError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateLambdaFunctionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateLambdaFunctionError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DisassociateLambdaFunctionError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateLambdaFunctionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5223,21 +5904,46 @@ impl DisassociateLexBotError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DisassociateLexBotError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateLexBotError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DisassociateLexBotError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateLexBotError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DisassociateLexBotError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateLexBotError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateLexBotError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateLexBotError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DisassociateLexBotError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateLexBotError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5279,31 +5985,46 @@ impl DisassociateRoutingProfileQueuesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
-                        DisassociateRoutingProfileQueuesError::InternalService(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateRoutingProfileQueuesError::InternalService,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        DisassociateRoutingProfileQueuesError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateRoutingProfileQueuesError::InvalidParameter,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        DisassociateRoutingProfileQueuesError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateRoutingProfileQueuesError::InvalidRequest,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DisassociateRoutingProfileQueuesError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateRoutingProfileQueuesError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DisassociateRoutingProfileQueuesError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateRoutingProfileQueuesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5351,29 +6072,46 @@ impl DisassociateSecurityKeyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DisassociateSecurityKeyError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateSecurityKeyError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DisassociateSecurityKeyError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateSecurityKeyError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DisassociateSecurityKeyError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateSecurityKeyError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateSecurityKeyError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateSecurityKeyError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DisassociateSecurityKeyError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateSecurityKeyError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5409,19 +6147,30 @@ impl GetContactAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetContactAttributesError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetContactAttributesError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetContactAttributesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetContactAttributesError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetContactAttributesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetContactAttributesError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5459,27 +6208,46 @@ impl GetCurrentMetricDataError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetCurrentMetricDataError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCurrentMetricDataError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetCurrentMetricDataError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCurrentMetricDataError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetCurrentMetricDataError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCurrentMetricDataError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetCurrentMetricDataError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCurrentMetricDataError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetCurrentMetricDataError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCurrentMetricDataError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5521,26 +6289,54 @@ impl GetFederationTokenError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DuplicateResourceException" => {
-                    return RusotoError::Service(GetFederationTokenError::DuplicateResource(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetFederationTokenError::DuplicateResource,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetFederationTokenError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFederationTokenError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetFederationTokenError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFederationTokenError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetFederationTokenError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFederationTokenError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetFederationTokenError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFederationTokenError::ResourceNotFound,
+                    )
                 }
                 "UserNotFoundException" => {
-                    return RusotoError::Service(GetFederationTokenError::UserNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFederationTokenError::UserNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5581,21 +6377,46 @@ impl GetMetricDataError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetMetricDataError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMetricDataError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetMetricDataError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMetricDataError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetMetricDataError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMetricDataError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetMetricDataError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMetricDataError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetMetricDataError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMetricDataError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5635,25 +6456,46 @@ impl ListApprovedOriginsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListApprovedOriginsError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListApprovedOriginsError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListApprovedOriginsError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListApprovedOriginsError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListApprovedOriginsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListApprovedOriginsError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListApprovedOriginsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListApprovedOriginsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListApprovedOriginsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListApprovedOriginsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5693,21 +6535,46 @@ impl ListContactFlowsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListContactFlowsError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListContactFlowsError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListContactFlowsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListContactFlowsError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListContactFlowsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListContactFlowsError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListContactFlowsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListContactFlowsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListContactFlowsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListContactFlowsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5747,29 +6614,46 @@ impl ListHoursOfOperationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListHoursOfOperationsError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListHoursOfOperationsError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListHoursOfOperationsError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListHoursOfOperationsError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListHoursOfOperationsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListHoursOfOperationsError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListHoursOfOperationsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListHoursOfOperationsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListHoursOfOperationsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListHoursOfOperationsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5809,29 +6693,46 @@ impl ListInstanceAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListInstanceAttributesError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListInstanceAttributesError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListInstanceAttributesError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListInstanceAttributesError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListInstanceAttributesError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListInstanceAttributesError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListInstanceAttributesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListInstanceAttributesError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListInstanceAttributesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInstanceAttributesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5873,31 +6774,46 @@ impl ListInstanceStorageConfigsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListInstanceStorageConfigsError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListInstanceStorageConfigsError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListInstanceStorageConfigsError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListInstanceStorageConfigsError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListInstanceStorageConfigsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListInstanceStorageConfigsError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListInstanceStorageConfigsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListInstanceStorageConfigsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListInstanceStorageConfigsError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListInstanceStorageConfigsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5931,12 +6847,22 @@ impl ListInstancesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListInstancesError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInstancesError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListInstancesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInstancesError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5973,26 +6899,38 @@ impl ListIntegrationAssociationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListIntegrationAssociationsError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListIntegrationAssociationsError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListIntegrationAssociationsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListIntegrationAssociationsError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        ListIntegrationAssociationsError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIntegrationAssociationsError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListIntegrationAssociationsError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListIntegrationAssociationsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6031,25 +6969,46 @@ impl ListLambdaFunctionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListLambdaFunctionsError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLambdaFunctionsError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListLambdaFunctionsError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListLambdaFunctionsError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListLambdaFunctionsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLambdaFunctionsError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListLambdaFunctionsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListLambdaFunctionsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListLambdaFunctionsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLambdaFunctionsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6089,21 +7048,46 @@ impl ListLexBotsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListLexBotsError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLexBotsError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListLexBotsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLexBotsError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListLexBotsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLexBotsError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListLexBotsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLexBotsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListLexBotsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLexBotsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6143,21 +7127,46 @@ impl ListPhoneNumbersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListPhoneNumbersError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPhoneNumbersError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListPhoneNumbersError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPhoneNumbersError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListPhoneNumbersError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPhoneNumbersError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListPhoneNumbersError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPhoneNumbersError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListPhoneNumbersError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPhoneNumbersError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6197,21 +7206,46 @@ impl ListPromptsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListPromptsError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPromptsError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListPromptsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPromptsError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListPromptsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPromptsError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListPromptsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPromptsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListPromptsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPromptsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6251,21 +7285,46 @@ impl ListQueuesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListQueuesError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListQueuesError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListQueuesError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListQueuesError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListQueuesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListQueuesError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListQueuesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListQueuesError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListQueuesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListQueuesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6305,21 +7364,46 @@ impl ListQuickConnectsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListQuickConnectsError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListQuickConnectsError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListQuickConnectsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListQuickConnectsError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListQuickConnectsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListQuickConnectsError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListQuickConnectsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListQuickConnectsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListQuickConnectsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListQuickConnectsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6359,29 +7443,46 @@ impl ListRoutingProfileQueuesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListRoutingProfileQueuesError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListRoutingProfileQueuesError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListRoutingProfileQueuesError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListRoutingProfileQueuesError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListRoutingProfileQueuesError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListRoutingProfileQueuesError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListRoutingProfileQueuesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListRoutingProfileQueuesError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListRoutingProfileQueuesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRoutingProfileQueuesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6421,25 +7522,46 @@ impl ListRoutingProfilesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListRoutingProfilesError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRoutingProfilesError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListRoutingProfilesError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListRoutingProfilesError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListRoutingProfilesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRoutingProfilesError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListRoutingProfilesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListRoutingProfilesError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListRoutingProfilesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRoutingProfilesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6479,21 +7601,46 @@ impl ListSecurityKeysError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListSecurityKeysError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSecurityKeysError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListSecurityKeysError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSecurityKeysError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListSecurityKeysError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSecurityKeysError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListSecurityKeysError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSecurityKeysError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListSecurityKeysError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSecurityKeysError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6533,27 +7680,46 @@ impl ListSecurityProfilesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListSecurityProfilesError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSecurityProfilesError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListSecurityProfilesError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSecurityProfilesError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListSecurityProfilesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSecurityProfilesError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListSecurityProfilesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSecurityProfilesError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListSecurityProfilesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSecurityProfilesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6593,25 +7759,46 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6649,18 +7836,38 @@ impl ListUseCasesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListUseCasesError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUseCasesError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListUseCasesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUseCasesError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListUseCasesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUseCasesError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListUseCasesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUseCasesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6699,29 +7906,46 @@ impl ListUserHierarchyGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListUserHierarchyGroupsError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListUserHierarchyGroupsError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListUserHierarchyGroupsError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListUserHierarchyGroupsError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListUserHierarchyGroupsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListUserHierarchyGroupsError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListUserHierarchyGroupsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListUserHierarchyGroupsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListUserHierarchyGroupsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUserHierarchyGroupsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6761,21 +7985,46 @@ impl ListUsersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListUsersError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUsersError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListUsersError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUsersError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListUsersError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUsersError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListUsersError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUsersError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListUsersError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUsersError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6811,21 +8060,30 @@ impl ResumeContactRecordingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ResumeContactRecordingError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ResumeContactRecordingError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ResumeContactRecordingError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ResumeContactRecordingError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ResumeContactRecordingError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ResumeContactRecordingError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6863,21 +8121,46 @@ impl StartChatContactError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartChatContactError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartChatContactError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartChatContactError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartChatContactError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartChatContactError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartChatContactError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartChatContactError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartChatContactError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartChatContactError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartChatContactError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6915,26 +8198,38 @@ impl StartContactRecordingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartContactRecordingError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartContactRecordingError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartContactRecordingError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartContactRecordingError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartContactRecordingError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartContactRecordingError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartContactRecordingError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartContactRecordingError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6977,41 +8272,62 @@ impl StartOutboundVoiceContactError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DestinationNotAllowedException" => {
-                    return RusotoError::Service(
-                        StartOutboundVoiceContactError::DestinationNotAllowed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartOutboundVoiceContactError::DestinationNotAllowed,
                     )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartOutboundVoiceContactError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartOutboundVoiceContactError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartOutboundVoiceContactError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartOutboundVoiceContactError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartOutboundVoiceContactError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartOutboundVoiceContactError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartOutboundVoiceContactError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartOutboundVoiceContactError::LimitExceeded,
+                    )
                 }
                 "OutboundContactNotPermittedException" => {
-                    return RusotoError::Service(
-                        StartOutboundVoiceContactError::OutboundContactNotPermitted(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartOutboundVoiceContactError::OutboundContactNotPermitted,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartOutboundVoiceContactError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartOutboundVoiceContactError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7059,26 +8375,54 @@ impl StartTaskContactError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartTaskContactError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartTaskContactError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartTaskContactError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartTaskContactError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartTaskContactError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartTaskContactError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartTaskContactError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartTaskContactError::ResourceNotFound,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(StartTaskContactError::ServiceQuotaExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartTaskContactError::ServiceQuotaExceeded,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartTaskContactError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartTaskContactError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7119,21 +8463,46 @@ impl StopContactError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ContactNotFoundException" => {
-                    return RusotoError::Service(StopContactError::ContactNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopContactError::ContactNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(StopContactError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopContactError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StopContactError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopContactError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopContactError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopContactError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopContactError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopContactError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7169,19 +8538,30 @@ impl StopContactRecordingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(StopContactRecordingError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopContactRecordingError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopContactRecordingError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopContactRecordingError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopContactRecordingError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopContactRecordingError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7215,21 +8595,30 @@ impl SuspendContactRecordingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(SuspendContactRecordingError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SuspendContactRecordingError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SuspendContactRecordingError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SuspendContactRecordingError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SuspendContactRecordingError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SuspendContactRecordingError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7267,21 +8656,46 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(TagResourceError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(TagResourceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7321,21 +8735,46 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UntagResourceError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UntagResourceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7373,26 +8812,38 @@ impl UpdateContactAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateContactAttributesError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateContactAttributesError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateContactAttributesError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateContactAttributesError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateContactAttributesError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateContactAttributesError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateContactAttributesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateContactAttributesError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7433,34 +8884,54 @@ impl UpdateContactFlowContentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateContactFlowContentError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateContactFlowContentError::InternalService,
+                    )
                 }
                 "InvalidContactFlowException" => {
-                    return RusotoError::Service(UpdateContactFlowContentError::InvalidContactFlow(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateContactFlowContentError::InvalidContactFlow,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateContactFlowContentError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateContactFlowContentError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateContactFlowContentError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateContactFlowContentError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateContactFlowContentError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateContactFlowContentError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateContactFlowContentError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateContactFlowContentError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7503,34 +8974,54 @@ impl UpdateContactFlowNameError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DuplicateResourceException" => {
-                    return RusotoError::Service(UpdateContactFlowNameError::DuplicateResource(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateContactFlowNameError::DuplicateResource,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateContactFlowNameError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateContactFlowNameError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateContactFlowNameError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateContactFlowNameError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateContactFlowNameError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateContactFlowNameError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateContactFlowNameError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateContactFlowNameError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateContactFlowNameError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateContactFlowNameError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7571,29 +9062,46 @@ impl UpdateInstanceAttributeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateInstanceAttributeError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateInstanceAttributeError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateInstanceAttributeError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateInstanceAttributeError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateInstanceAttributeError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateInstanceAttributeError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateInstanceAttributeError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateInstanceAttributeError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateInstanceAttributeError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateInstanceAttributeError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7635,31 +9143,46 @@ impl UpdateInstanceStorageConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateInstanceStorageConfigError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateInstanceStorageConfigError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        UpdateInstanceStorageConfigError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateInstanceStorageConfigError::InvalidParameter,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateInstanceStorageConfigError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateInstanceStorageConfigError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateInstanceStorageConfigError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateInstanceStorageConfigError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateInstanceStorageConfigError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateInstanceStorageConfigError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7699,29 +9222,46 @@ impl UpdateQuickConnectConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateQuickConnectConfigError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateQuickConnectConfigError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateQuickConnectConfigError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateQuickConnectConfigError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateQuickConnectConfigError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateQuickConnectConfigError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateQuickConnectConfigError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateQuickConnectConfigError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateQuickConnectConfigError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateQuickConnectConfigError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7761,29 +9301,46 @@ impl UpdateQuickConnectNameError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateQuickConnectNameError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateQuickConnectNameError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateQuickConnectNameError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateQuickConnectNameError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateQuickConnectNameError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateQuickConnectNameError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateQuickConnectNameError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateQuickConnectNameError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateQuickConnectNameError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateQuickConnectNameError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7825,31 +9382,46 @@ impl UpdateRoutingProfileConcurrencyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
-                        UpdateRoutingProfileConcurrencyError::InternalService(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRoutingProfileConcurrencyError::InternalService,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        UpdateRoutingProfileConcurrencyError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRoutingProfileConcurrencyError::InvalidParameter,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        UpdateRoutingProfileConcurrencyError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRoutingProfileConcurrencyError::InvalidRequest,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateRoutingProfileConcurrencyError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRoutingProfileConcurrencyError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateRoutingProfileConcurrencyError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRoutingProfileConcurrencyError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7899,31 +9471,46 @@ impl UpdateRoutingProfileDefaultOutboundQueueError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
-                        UpdateRoutingProfileDefaultOutboundQueueError::InternalService(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRoutingProfileDefaultOutboundQueueError::InternalService,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        UpdateRoutingProfileDefaultOutboundQueueError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRoutingProfileDefaultOutboundQueueError::InvalidParameter,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        UpdateRoutingProfileDefaultOutboundQueueError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRoutingProfileDefaultOutboundQueueError::InvalidRequest,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateRoutingProfileDefaultOutboundQueueError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRoutingProfileDefaultOutboundQueueError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
-                        UpdateRoutingProfileDefaultOutboundQueueError::Throttling(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRoutingProfileDefaultOutboundQueueError::Throttling,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7975,34 +9562,54 @@ impl UpdateRoutingProfileNameError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DuplicateResourceException" => {
-                    return RusotoError::Service(UpdateRoutingProfileNameError::DuplicateResource(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRoutingProfileNameError::DuplicateResource,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateRoutingProfileNameError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRoutingProfileNameError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateRoutingProfileNameError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRoutingProfileNameError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateRoutingProfileNameError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRoutingProfileNameError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateRoutingProfileNameError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRoutingProfileNameError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateRoutingProfileNameError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRoutingProfileNameError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8045,31 +9652,46 @@ impl UpdateRoutingProfileQueuesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateRoutingProfileQueuesError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRoutingProfileQueuesError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateRoutingProfileQueuesError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRoutingProfileQueuesError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateRoutingProfileQueuesError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRoutingProfileQueuesError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateRoutingProfileQueuesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRoutingProfileQueuesError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateRoutingProfileQueuesError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRoutingProfileQueuesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8109,25 +9731,46 @@ impl UpdateUserHierarchyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateUserHierarchyError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserHierarchyError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateUserHierarchyError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserHierarchyError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateUserHierarchyError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserHierarchyError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateUserHierarchyError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserHierarchyError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateUserHierarchyError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserHierarchyError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8171,36 +9814,54 @@ impl UpdateUserHierarchyGroupNameError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DuplicateResourceException" => {
-                    return RusotoError::Service(
-                        UpdateUserHierarchyGroupNameError::DuplicateResource(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserHierarchyGroupNameError::DuplicateResource,
                     )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(
-                        UpdateUserHierarchyGroupNameError::InternalService(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserHierarchyGroupNameError::InternalService,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        UpdateUserHierarchyGroupNameError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserHierarchyGroupNameError::InvalidParameter,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateUserHierarchyGroupNameError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserHierarchyGroupNameError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateUserHierarchyGroupNameError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserHierarchyGroupNameError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateUserHierarchyGroupNameError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserHierarchyGroupNameError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8251,36 +9912,54 @@ impl UpdateUserHierarchyStructureError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
-                        UpdateUserHierarchyStructureError::InternalService(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserHierarchyStructureError::InternalService,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        UpdateUserHierarchyStructureError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserHierarchyStructureError::InvalidParameter,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateUserHierarchyStructureError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserHierarchyStructureError::InvalidRequest,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateUserHierarchyStructureError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserHierarchyStructureError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateUserHierarchyStructureError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserHierarchyStructureError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateUserHierarchyStructureError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserHierarchyStructureError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8325,29 +10004,46 @@ impl UpdateUserIdentityInfoError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateUserIdentityInfoError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserIdentityInfoError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateUserIdentityInfoError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserIdentityInfoError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateUserIdentityInfoError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserIdentityInfoError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateUserIdentityInfoError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserIdentityInfoError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateUserIdentityInfoError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserIdentityInfoError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8387,29 +10083,46 @@ impl UpdateUserPhoneConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateUserPhoneConfigError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserPhoneConfigError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateUserPhoneConfigError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserPhoneConfigError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateUserPhoneConfigError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserPhoneConfigError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateUserPhoneConfigError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserPhoneConfigError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateUserPhoneConfigError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserPhoneConfigError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8449,29 +10162,46 @@ impl UpdateUserRoutingProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateUserRoutingProfileError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserRoutingProfileError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateUserRoutingProfileError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserRoutingProfileError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateUserRoutingProfileError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserRoutingProfileError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateUserRoutingProfileError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserRoutingProfileError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateUserRoutingProfileError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserRoutingProfileError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8513,31 +10243,46 @@ impl UpdateUserSecurityProfilesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateUserSecurityProfilesError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserSecurityProfilesError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateUserSecurityProfilesError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserSecurityProfilesError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateUserSecurityProfilesError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserSecurityProfilesError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateUserSecurityProfilesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserSecurityProfilesError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateUserSecurityProfilesError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserSecurityProfilesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9107,11 +10852,12 @@ pub struct ConnectClient {
 impl ConnectClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> ConnectClient {
         ConnectClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Connect"),
         }
     }
 
@@ -9126,12 +10872,32 @@ impl ConnectClient {
     {
         ConnectClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Connect"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ConnectClient {
-        ConnectClient { client, region }
+        ConnectClient {
+            client,
+            region: region_for_service(region, "Connect"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> ConnectClient {
+        ConnectClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Connect"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ConnectClient {
+        ConnectClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -9149,6 +10915,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("PUT", "connect", &self.region, &request_uri);
+        request.set_operation("AssociateApprovedOrigin");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9185,6 +10952,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("PUT", "connect", &self.region, &request_uri);
+        request.set_operation("AssociateInstanceStorageConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9219,6 +10987,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("PUT", "connect", &self.region, &request_uri);
+        request.set_operation("AssociateLambdaFunction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9252,6 +11021,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("PUT", "connect", &self.region, &request_uri);
+        request.set_operation("AssociateLexBot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9286,6 +11056,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("AssociateRoutingProfileQueues");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9319,6 +11090,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("PUT", "connect", &self.region, &request_uri);
+        request.set_operation("AssociateSecurityKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9353,6 +11125,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("PUT", "connect", &self.region, &request_uri);
+        request.set_operation("CreateContactFlow");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9384,6 +11157,7 @@ impl Connect for ConnectClient {
         let request_uri = "/instance";
 
         let mut request = SignedRequest::new("PUT", "connect", &self.region, &request_uri);
+        request.set_operation("CreateInstance");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9419,6 +11193,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("PUT", "connect", &self.region, &request_uri);
+        request.set_operation("CreateIntegrationAssociation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9453,6 +11228,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("PUT", "connect", &self.region, &request_uri);
+        request.set_operation("CreateQuickConnect");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9487,6 +11263,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("PUT", "connect", &self.region, &request_uri);
+        request.set_operation("CreateRoutingProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9518,6 +11295,7 @@ impl Connect for ConnectClient {
         let request_uri = format!("/instance/{instance_id}/integration-associations/{integration_association_id}/use-cases", instance_id = input.instance_id, integration_association_id = input.integration_association_id);
 
         let mut request = SignedRequest::new("PUT", "connect", &self.region, &request_uri);
+        request.set_operation("CreateUseCase");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9549,6 +11327,7 @@ impl Connect for ConnectClient {
         let request_uri = format!("/users/{instance_id}", instance_id = input.instance_id);
 
         let mut request = SignedRequest::new("PUT", "connect", &self.region, &request_uri);
+        request.set_operation("CreateUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9583,6 +11362,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("PUT", "connect", &self.region, &request_uri);
+        request.set_operation("CreateUserHierarchyGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9614,6 +11394,7 @@ impl Connect for ConnectClient {
         let request_uri = format!("/instance/{instance_id}", instance_id = input.instance_id);
 
         let mut request = SignedRequest::new("DELETE", "connect", &self.region, &request_uri);
+        request.set_operation("DeleteInstance");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9645,6 +11426,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "connect", &self.region, &request_uri);
+        request.set_operation("DeleteIntegrationAssociation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9676,6 +11458,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "connect", &self.region, &request_uri);
+        request.set_operation("DeleteQuickConnect");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9703,6 +11486,7 @@ impl Connect for ConnectClient {
         let request_uri = format!("/instance/{instance_id}/integration-associations/{integration_association_id}/use-cases/{use_case_id}", instance_id = input.instance_id, integration_association_id = input.integration_association_id, use_case_id = input.use_case_id);
 
         let mut request = SignedRequest::new("DELETE", "connect", &self.region, &request_uri);
+        request.set_operation("DeleteUseCase");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9734,6 +11518,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "connect", &self.region, &request_uri);
+        request.set_operation("DeleteUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9765,6 +11550,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "connect", &self.region, &request_uri);
+        request.set_operation("DeleteUserHierarchyGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9796,6 +11582,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("DescribeContactFlow");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9824,6 +11611,7 @@ impl Connect for ConnectClient {
         let request_uri = format!("/instance/{instance_id}", instance_id = input.instance_id);
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("DescribeInstance");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9857,6 +11645,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("DescribeInstanceAttribute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9892,6 +11681,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("DescribeInstanceStorageConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9928,6 +11718,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("DescribeQuickConnect");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9960,6 +11751,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("DescribeRoutingProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9992,6 +11784,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("DescribeUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10025,6 +11818,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("DescribeUserHierarchyGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10059,6 +11853,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("DescribeUserHierarchyStructure");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10090,6 +11885,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "connect", &self.region, &request_uri);
+        request.set_operation("DisassociateApprovedOrigin");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10125,6 +11921,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "connect", &self.region, &request_uri);
+        request.set_operation("DisassociateInstanceStorageConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10161,6 +11958,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "connect", &self.region, &request_uri);
+        request.set_operation("DisassociateLambdaFunction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10195,6 +11993,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "connect", &self.region, &request_uri);
+        request.set_operation("DisassociateLexBot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10231,6 +12030,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("DisassociateRoutingProfileQueues");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10267,6 +12067,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "connect", &self.region, &request_uri);
+        request.set_operation("DisassociateSecurityKey");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10298,6 +12099,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("GetContactAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10329,6 +12131,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("GetCurrentMetricData");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10363,6 +12166,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("GetFederationToken");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -10394,6 +12198,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("GetMetricData");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10428,6 +12233,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListApprovedOrigins");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10468,6 +12274,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListContactFlows");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10513,6 +12320,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListHoursOfOperations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10553,6 +12361,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListInstanceAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10594,6 +12403,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListInstanceStorageConfigs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10632,6 +12442,7 @@ impl Connect for ConnectClient {
         let request_uri = "/instance";
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListInstances");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10673,6 +12484,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListIntegrationAssociations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10713,6 +12525,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListLambdaFunctions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10753,6 +12566,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListLexBots");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10793,6 +12607,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListPhoneNumbers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10843,6 +12658,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListPrompts");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10883,6 +12699,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListQueues");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10928,6 +12745,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListQuickConnects");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -10974,6 +12792,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListRoutingProfileQueues");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11014,6 +12833,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListRoutingProfiles");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11054,6 +12874,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListSecurityKeys");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11094,6 +12915,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListSecurityProfiles");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11131,6 +12953,7 @@ impl Connect for ConnectClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11159,6 +12982,7 @@ impl Connect for ConnectClient {
         let request_uri = format!("/instance/{instance_id}/integration-associations/{integration_association_id}/use-cases", instance_id = input.instance_id, integration_association_id = input.integration_association_id);
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListUseCases");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11199,6 +13023,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListUserHierarchyGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11239,6 +13064,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "connect", &self.region, &request_uri);
+        request.set_operation("ListUsers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11276,6 +13102,7 @@ impl Connect for ConnectClient {
         let request_uri = "/contact/resume-recording";
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("ResumeContactRecording");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11307,6 +13134,7 @@ impl Connect for ConnectClient {
         let request_uri = "/contact/chat";
 
         let mut request = SignedRequest::new("PUT", "connect", &self.region, &request_uri);
+        request.set_operation("StartChatContact");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11338,6 +13166,7 @@ impl Connect for ConnectClient {
         let request_uri = "/contact/start-recording";
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("StartContactRecording");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11370,6 +13199,7 @@ impl Connect for ConnectClient {
         let request_uri = "/contact/outbound-voice";
 
         let mut request = SignedRequest::new("PUT", "connect", &self.region, &request_uri);
+        request.set_operation("StartOutboundVoiceContact");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11401,6 +13231,7 @@ impl Connect for ConnectClient {
         let request_uri = "/contact/task";
 
         let mut request = SignedRequest::new("PUT", "connect", &self.region, &request_uri);
+        request.set_operation("StartTaskContact");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11432,6 +13263,7 @@ impl Connect for ConnectClient {
         let request_uri = "/contact/stop";
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("StopContact");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11463,6 +13295,7 @@ impl Connect for ConnectClient {
         let request_uri = "/contact/stop-recording";
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("StopContactRecording");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11494,6 +13327,7 @@ impl Connect for ConnectClient {
         let request_uri = "/contact/suspend-recording";
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("SuspendContactRecording");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11525,6 +13359,7 @@ impl Connect for ConnectClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11555,6 +13390,7 @@ impl Connect for ConnectClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "connect", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11588,6 +13424,7 @@ impl Connect for ConnectClient {
         let request_uri = "/contact/attributes";
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("UpdateContactAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11623,6 +13460,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("UpdateContactFlowContent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11657,6 +13495,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("UpdateContactFlowName");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11691,6 +13530,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("UpdateInstanceAttribute");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11725,6 +13565,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("UpdateInstanceStorageConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11763,6 +13604,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("UpdateQuickConnectConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11797,6 +13639,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("UpdateQuickConnectName");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11831,6 +13674,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("UpdateRoutingProfileConcurrency");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11867,6 +13711,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("UpdateRoutingProfileDefaultOutboundQueue");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11901,6 +13746,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("UpdateRoutingProfileName");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11935,6 +13781,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("UpdateRoutingProfileQueues");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11969,6 +13816,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("UpdateUserHierarchy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -12003,6 +13851,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("UpdateUserHierarchyGroupName");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -12036,6 +13885,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("UpdateUserHierarchyStructure");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -12070,6 +13920,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("UpdateUserIdentityInfo");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -12104,6 +13955,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("UpdateUserPhoneConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -12138,6 +13990,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("UpdateUserRoutingProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -12172,6 +14025,7 @@ impl Connect for ConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "connect", &self.region, &request_uri);
+        request.set_operation("UpdateUserSecurityProfiles");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -12193,3 +14047,863 @@ impl Connect for ConnectClient {
         }
     }
 }
+
+/// A blocking client for the Amazon Connect API, running the calls of a `ConnectClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `ConnectClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ConnectBlockingClient {
+    client: ConnectClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ConnectBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> ConnectBlockingClient {
+        ConnectBlockingClient::from_client(ConnectClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ConnectBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ConnectBlockingClient::from_client(ConnectClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> ConnectBlockingClient {
+        ConnectBlockingClient::from_client(ConnectClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> ConnectBlockingClient {
+        ConnectBlockingClient::from_client(ConnectClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: ConnectClient) -> ConnectBlockingClient {
+        ConnectBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> ConnectBlockingClient {
+        ConnectBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ConnectBlockingClient {
+        ConnectBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Connect::associate_approved_origin`].
+    pub fn associate_approved_origin(
+        &self,
+        input: AssociateApprovedOriginRequest,
+    ) -> Result<(), RusotoError<AssociateApprovedOriginError>> {
+        self.runtime
+            .block_on(self.client.associate_approved_origin(input))
+    }
+
+    /// Blocking version of [`Connect::associate_instance_storage_config`].
+    pub fn associate_instance_storage_config(
+        &self,
+        input: AssociateInstanceStorageConfigRequest,
+    ) -> Result<
+        AssociateInstanceStorageConfigResponse,
+        RusotoError<AssociateInstanceStorageConfigError>,
+    > {
+        self.runtime
+            .block_on(self.client.associate_instance_storage_config(input))
+    }
+
+    /// Blocking version of [`Connect::associate_lambda_function`].
+    pub fn associate_lambda_function(
+        &self,
+        input: AssociateLambdaFunctionRequest,
+    ) -> Result<(), RusotoError<AssociateLambdaFunctionError>> {
+        self.runtime
+            .block_on(self.client.associate_lambda_function(input))
+    }
+
+    /// Blocking version of [`Connect::associate_lex_bot`].
+    pub fn associate_lex_bot(
+        &self,
+        input: AssociateLexBotRequest,
+    ) -> Result<(), RusotoError<AssociateLexBotError>> {
+        self.runtime.block_on(self.client.associate_lex_bot(input))
+    }
+
+    /// Blocking version of [`Connect::associate_routing_profile_queues`].
+    pub fn associate_routing_profile_queues(
+        &self,
+        input: AssociateRoutingProfileQueuesRequest,
+    ) -> Result<(), RusotoError<AssociateRoutingProfileQueuesError>> {
+        self.runtime
+            .block_on(self.client.associate_routing_profile_queues(input))
+    }
+
+    /// Blocking version of [`Connect::associate_security_key`].
+    pub fn associate_security_key(
+        &self,
+        input: AssociateSecurityKeyRequest,
+    ) -> Result<AssociateSecurityKeyResponse, RusotoError<AssociateSecurityKeyError>> {
+        self.runtime
+            .block_on(self.client.associate_security_key(input))
+    }
+
+    /// Blocking version of [`Connect::create_contact_flow`].
+    pub fn create_contact_flow(
+        &self,
+        input: CreateContactFlowRequest,
+    ) -> Result<CreateContactFlowResponse, RusotoError<CreateContactFlowError>> {
+        self.runtime
+            .block_on(self.client.create_contact_flow(input))
+    }
+
+    /// Blocking version of [`Connect::create_instance`].
+    pub fn create_instance(
+        &self,
+        input: CreateInstanceRequest,
+    ) -> Result<CreateInstanceResponse, RusotoError<CreateInstanceError>> {
+        self.runtime.block_on(self.client.create_instance(input))
+    }
+
+    /// Blocking version of [`Connect::create_integration_association`].
+    pub fn create_integration_association(
+        &self,
+        input: CreateIntegrationAssociationRequest,
+    ) -> Result<CreateIntegrationAssociationResponse, RusotoError<CreateIntegrationAssociationError>>
+    {
+        self.runtime
+            .block_on(self.client.create_integration_association(input))
+    }
+
+    /// Blocking version of [`Connect::create_quick_connect`].
+    pub fn create_quick_connect(
+        &self,
+        input: CreateQuickConnectRequest,
+    ) -> Result<CreateQuickConnectResponse, RusotoError<CreateQuickConnectError>> {
+        self.runtime
+            .block_on(self.client.create_quick_connect(input))
+    }
+
+    /// Blocking version of [`Connect::create_routing_profile`].
+    pub fn create_routing_profile(
+        &self,
+        input: CreateRoutingProfileRequest,
+    ) -> Result<CreateRoutingProfileResponse, RusotoError<CreateRoutingProfileError>> {
+        self.runtime
+            .block_on(self.client.create_routing_profile(input))
+    }
+
+    /// Blocking version of [`Connect::create_use_case`].
+    pub fn create_use_case(
+        &self,
+        input: CreateUseCaseRequest,
+    ) -> Result<CreateUseCaseResponse, RusotoError<CreateUseCaseError>> {
+        self.runtime.block_on(self.client.create_use_case(input))
+    }
+
+    /// Blocking version of [`Connect::create_user`].
+    pub fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> Result<CreateUserResponse, RusotoError<CreateUserError>> {
+        self.runtime.block_on(self.client.create_user(input))
+    }
+
+    /// Blocking version of [`Connect::create_user_hierarchy_group`].
+    pub fn create_user_hierarchy_group(
+        &self,
+        input: CreateUserHierarchyGroupRequest,
+    ) -> Result<CreateUserHierarchyGroupResponse, RusotoError<CreateUserHierarchyGroupError>> {
+        self.runtime
+            .block_on(self.client.create_user_hierarchy_group(input))
+    }
+
+    /// Blocking version of [`Connect::delete_instance`].
+    pub fn delete_instance(
+        &self,
+        input: DeleteInstanceRequest,
+    ) -> Result<(), RusotoError<DeleteInstanceError>> {
+        self.runtime.block_on(self.client.delete_instance(input))
+    }
+
+    /// Blocking version of [`Connect::delete_integration_association`].
+    pub fn delete_integration_association(
+        &self,
+        input: DeleteIntegrationAssociationRequest,
+    ) -> Result<(), RusotoError<DeleteIntegrationAssociationError>> {
+        self.runtime
+            .block_on(self.client.delete_integration_association(input))
+    }
+
+    /// Blocking version of [`Connect::delete_quick_connect`].
+    pub fn delete_quick_connect(
+        &self,
+        input: DeleteQuickConnectRequest,
+    ) -> Result<(), RusotoError<DeleteQuickConnectError>> {
+        self.runtime
+            .block_on(self.client.delete_quick_connect(input))
+    }
+
+    /// Blocking version of [`Connect::delete_use_case`].
+    pub fn delete_use_case(
+        &self,
+        input: DeleteUseCaseRequest,
+    ) -> Result<(), RusotoError<DeleteUseCaseError>> {
+        self.runtime.block_on(self.client.delete_use_case(input))
+    }
+
+    /// Blocking version of [`Connect::delete_user`].
+    pub fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> Result<(), RusotoError<DeleteUserError>> {
+        self.runtime.block_on(self.client.delete_user(input))
+    }
+
+    /// Blocking version of [`Connect::delete_user_hierarchy_group`].
+    pub fn delete_user_hierarchy_group(
+        &self,
+        input: DeleteUserHierarchyGroupRequest,
+    ) -> Result<(), RusotoError<DeleteUserHierarchyGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_user_hierarchy_group(input))
+    }
+
+    /// Blocking version of [`Connect::describe_contact_flow`].
+    pub fn describe_contact_flow(
+        &self,
+        input: DescribeContactFlowRequest,
+    ) -> Result<DescribeContactFlowResponse, RusotoError<DescribeContactFlowError>> {
+        self.runtime
+            .block_on(self.client.describe_contact_flow(input))
+    }
+
+    /// Blocking version of [`Connect::describe_instance`].
+    pub fn describe_instance(
+        &self,
+        input: DescribeInstanceRequest,
+    ) -> Result<DescribeInstanceResponse, RusotoError<DescribeInstanceError>> {
+        self.runtime.block_on(self.client.describe_instance(input))
+    }
+
+    /// Blocking version of [`Connect::describe_instance_attribute`].
+    pub fn describe_instance_attribute(
+        &self,
+        input: DescribeInstanceAttributeRequest,
+    ) -> Result<DescribeInstanceAttributeResponse, RusotoError<DescribeInstanceAttributeError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_instance_attribute(input))
+    }
+
+    /// Blocking version of [`Connect::describe_instance_storage_config`].
+    pub fn describe_instance_storage_config(
+        &self,
+        input: DescribeInstanceStorageConfigRequest,
+    ) -> Result<
+        DescribeInstanceStorageConfigResponse,
+        RusotoError<DescribeInstanceStorageConfigError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_instance_storage_config(input))
+    }
+
+    /// Blocking version of [`Connect::describe_quick_connect`].
+    pub fn describe_quick_connect(
+        &self,
+        input: DescribeQuickConnectRequest,
+    ) -> Result<DescribeQuickConnectResponse, RusotoError<DescribeQuickConnectError>> {
+        self.runtime
+            .block_on(self.client.describe_quick_connect(input))
+    }
+
+    /// Blocking version of [`Connect::describe_routing_profile`].
+    pub fn describe_routing_profile(
+        &self,
+        input: DescribeRoutingProfileRequest,
+    ) -> Result<DescribeRoutingProfileResponse, RusotoError<DescribeRoutingProfileError>> {
+        self.runtime
+            .block_on(self.client.describe_routing_profile(input))
+    }
+
+    /// Blocking version of [`Connect::describe_user`].
+    pub fn describe_user(
+        &self,
+        input: DescribeUserRequest,
+    ) -> Result<DescribeUserResponse, RusotoError<DescribeUserError>> {
+        self.runtime.block_on(self.client.describe_user(input))
+    }
+
+    /// Blocking version of [`Connect::describe_user_hierarchy_group`].
+    pub fn describe_user_hierarchy_group(
+        &self,
+        input: DescribeUserHierarchyGroupRequest,
+    ) -> Result<DescribeUserHierarchyGroupResponse, RusotoError<DescribeUserHierarchyGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_user_hierarchy_group(input))
+    }
+
+    /// Blocking version of [`Connect::describe_user_hierarchy_structure`].
+    pub fn describe_user_hierarchy_structure(
+        &self,
+        input: DescribeUserHierarchyStructureRequest,
+    ) -> Result<
+        DescribeUserHierarchyStructureResponse,
+        RusotoError<DescribeUserHierarchyStructureError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_user_hierarchy_structure(input))
+    }
+
+    /// Blocking version of [`Connect::disassociate_approved_origin`].
+    pub fn disassociate_approved_origin(
+        &self,
+        input: DisassociateApprovedOriginRequest,
+    ) -> Result<(), RusotoError<DisassociateApprovedOriginError>> {
+        self.runtime
+            .block_on(self.client.disassociate_approved_origin(input))
+    }
+
+    /// Blocking version of [`Connect::disassociate_instance_storage_config`].
+    pub fn disassociate_instance_storage_config(
+        &self,
+        input: DisassociateInstanceStorageConfigRequest,
+    ) -> Result<(), RusotoError<DisassociateInstanceStorageConfigError>> {
+        self.runtime
+            .block_on(self.client.disassociate_instance_storage_config(input))
+    }
+
+    /// Blocking version of [`Connect::disassociate_lambda_function`].
+    pub fn disassociate_lambda_function(
+        &self,
+        input: DisassociateLambdaFunctionRequest,
+    ) -> Result<(), RusotoError<DisassociateLambdaFunctionError>> {
+        self.runtime
+            .block_on(self.client.disassociate_lambda_function(input))
+    }
+
+    /// Blocking version of [`Connect::disassociate_lex_bot`].
+    pub fn disassociate_lex_bot(
+        &self,
+        input: DisassociateLexBotRequest,
+    ) -> Result<(), RusotoError<DisassociateLexBotError>> {
+        self.runtime
+            .block_on(self.client.disassociate_lex_bot(input))
+    }
+
+    /// Blocking version of [`Connect::disassociate_routing_profile_queues`].
+    pub fn disassociate_routing_profile_queues(
+        &self,
+        input: DisassociateRoutingProfileQueuesRequest,
+    ) -> Result<(), RusotoError<DisassociateRoutingProfileQueuesError>> {
+        self.runtime
+            .block_on(self.client.disassociate_routing_profile_queues(input))
+    }
+
+    /// Blocking version of [`Connect::disassociate_security_key`].
+    pub fn disassociate_security_key(
+        &self,
+        input: DisassociateSecurityKeyRequest,
+    ) -> Result<(), RusotoError<DisassociateSecurityKeyError>> {
+        self.runtime
+            .block_on(self.client.disassociate_security_key(input))
+    }
+
+    /// Blocking version of [`Connect::get_contact_attributes`].
+    pub fn get_contact_attributes(
+        &self,
+        input: GetContactAttributesRequest,
+    ) -> Result<GetContactAttributesResponse, RusotoError<GetContactAttributesError>> {
+        self.runtime
+            .block_on(self.client.get_contact_attributes(input))
+    }
+
+    /// Blocking version of [`Connect::get_current_metric_data`].
+    pub fn get_current_metric_data(
+        &self,
+        input: GetCurrentMetricDataRequest,
+    ) -> Result<GetCurrentMetricDataResponse, RusotoError<GetCurrentMetricDataError>> {
+        self.runtime
+            .block_on(self.client.get_current_metric_data(input))
+    }
+
+    /// Blocking version of [`Connect::get_federation_token`].
+    pub fn get_federation_token(
+        &self,
+        input: GetFederationTokenRequest,
+    ) -> Result<GetFederationTokenResponse, RusotoError<GetFederationTokenError>> {
+        self.runtime
+            .block_on(self.client.get_federation_token(input))
+    }
+
+    /// Blocking version of [`Connect::get_metric_data`].
+    pub fn get_metric_data(
+        &self,
+        input: GetMetricDataRequest,
+    ) -> Result<GetMetricDataResponse, RusotoError<GetMetricDataError>> {
+        self.runtime.block_on(self.client.get_metric_data(input))
+    }
+
+    /// Blocking version of [`Connect::list_approved_origins`].
+    pub fn list_approved_origins(
+        &self,
+        input: ListApprovedOriginsRequest,
+    ) -> Result<ListApprovedOriginsResponse, RusotoError<ListApprovedOriginsError>> {
+        self.runtime
+            .block_on(self.client.list_approved_origins(input))
+    }
+
+    /// Blocking version of [`Connect::list_contact_flows`].
+    pub fn list_contact_flows(
+        &self,
+        input: ListContactFlowsRequest,
+    ) -> Result<ListContactFlowsResponse, RusotoError<ListContactFlowsError>> {
+        self.runtime.block_on(self.client.list_contact_flows(input))
+    }
+
+    /// Blocking version of [`Connect::list_hours_of_operations`].
+    pub fn list_hours_of_operations(
+        &self,
+        input: ListHoursOfOperationsRequest,
+    ) -> Result<ListHoursOfOperationsResponse, RusotoError<ListHoursOfOperationsError>> {
+        self.runtime
+            .block_on(self.client.list_hours_of_operations(input))
+    }
+
+    /// Blocking version of [`Connect::list_instance_attributes`].
+    pub fn list_instance_attributes(
+        &self,
+        input: ListInstanceAttributesRequest,
+    ) -> Result<ListInstanceAttributesResponse, RusotoError<ListInstanceAttributesError>> {
+        self.runtime
+            .block_on(self.client.list_instance_attributes(input))
+    }
+
+    /// Blocking version of [`Connect::list_instance_storage_configs`].
+    pub fn list_instance_storage_configs(
+        &self,
+        input: ListInstanceStorageConfigsRequest,
+    ) -> Result<ListInstanceStorageConfigsResponse, RusotoError<ListInstanceStorageConfigsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_instance_storage_configs(input))
+    }
+
+    /// Blocking version of [`Connect::list_instances`].
+    pub fn list_instances(
+        &self,
+        input: ListInstancesRequest,
+    ) -> Result<ListInstancesResponse, RusotoError<ListInstancesError>> {
+        self.runtime.block_on(self.client.list_instances(input))
+    }
+
+    /// Blocking version of [`Connect::list_integration_associations`].
+    pub fn list_integration_associations(
+        &self,
+        input: ListIntegrationAssociationsRequest,
+    ) -> Result<ListIntegrationAssociationsResponse, RusotoError<ListIntegrationAssociationsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_integration_associations(input))
+    }
+
+    /// Blocking version of [`Connect::list_lambda_functions`].
+    pub fn list_lambda_functions(
+        &self,
+        input: ListLambdaFunctionsRequest,
+    ) -> Result<ListLambdaFunctionsResponse, RusotoError<ListLambdaFunctionsError>> {
+        self.runtime
+            .block_on(self.client.list_lambda_functions(input))
+    }
+
+    /// Blocking version of [`Connect::list_lex_bots`].
+    pub fn list_lex_bots(
+        &self,
+        input: ListLexBotsRequest,
+    ) -> Result<ListLexBotsResponse, RusotoError<ListLexBotsError>> {
+        self.runtime.block_on(self.client.list_lex_bots(input))
+    }
+
+    /// Blocking version of [`Connect::list_phone_numbers`].
+    pub fn list_phone_numbers(
+        &self,
+        input: ListPhoneNumbersRequest,
+    ) -> Result<ListPhoneNumbersResponse, RusotoError<ListPhoneNumbersError>> {
+        self.runtime.block_on(self.client.list_phone_numbers(input))
+    }
+
+    /// Blocking version of [`Connect::list_prompts`].
+    pub fn list_prompts(
+        &self,
+        input: ListPromptsRequest,
+    ) -> Result<ListPromptsResponse, RusotoError<ListPromptsError>> {
+        self.runtime.block_on(self.client.list_prompts(input))
+    }
+
+    /// Blocking version of [`Connect::list_queues`].
+    pub fn list_queues(
+        &self,
+        input: ListQueuesRequest,
+    ) -> Result<ListQueuesResponse, RusotoError<ListQueuesError>> {
+        self.runtime.block_on(self.client.list_queues(input))
+    }
+
+    /// Blocking version of [`Connect::list_quick_connects`].
+    pub fn list_quick_connects(
+        &self,
+        input: ListQuickConnectsRequest,
+    ) -> Result<ListQuickConnectsResponse, RusotoError<ListQuickConnectsError>> {
+        self.runtime
+            .block_on(self.client.list_quick_connects(input))
+    }
+
+    /// Blocking version of [`Connect::list_routing_profile_queues`].
+    pub fn list_routing_profile_queues(
+        &self,
+        input: ListRoutingProfileQueuesRequest,
+    ) -> Result<ListRoutingProfileQueuesResponse, RusotoError<ListRoutingProfileQueuesError>> {
+        self.runtime
+            .block_on(self.client.list_routing_profile_queues(input))
+    }
+
+    /// Blocking version of [`Connect::list_routing_profiles`].
+    pub fn list_routing_profiles(
+        &self,
+        input: ListRoutingProfilesRequest,
+    ) -> Result<ListRoutingProfilesResponse, RusotoError<ListRoutingProfilesError>> {
+        self.runtime
+            .block_on(self.client.list_routing_profiles(input))
+    }
+
+    /// Blocking version of [`Connect::list_security_keys`].
+    pub fn list_security_keys(
+        &self,
+        input: ListSecurityKeysRequest,
+    ) -> Result<ListSecurityKeysResponse, RusotoError<ListSecurityKeysError>> {
+        self.runtime.block_on(self.client.list_security_keys(input))
+    }
+
+    /// Blocking version of [`Connect::list_security_profiles`].
+    pub fn list_security_profiles(
+        &self,
+        input: ListSecurityProfilesRequest,
+    ) -> Result<ListSecurityProfilesResponse, RusotoError<ListSecurityProfilesError>> {
+        self.runtime
+            .block_on(self.client.list_security_profiles(input))
+    }
+
+    /// Blocking version of [`Connect::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`Connect::list_use_cases`].
+    pub fn list_use_cases(
+        &self,
+        input: ListUseCasesRequest,
+    ) -> Result<ListUseCasesResponse, RusotoError<ListUseCasesError>> {
+        self.runtime.block_on(self.client.list_use_cases(input))
+    }
+
+    /// Blocking version of [`Connect::list_user_hierarchy_groups`].
+    pub fn list_user_hierarchy_groups(
+        &self,
+        input: ListUserHierarchyGroupsRequest,
+    ) -> Result<ListUserHierarchyGroupsResponse, RusotoError<ListUserHierarchyGroupsError>> {
+        self.runtime
+            .block_on(self.client.list_user_hierarchy_groups(input))
+    }
+
+    /// Blocking version of [`Connect::list_users`].
+    pub fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> Result<ListUsersResponse, RusotoError<ListUsersError>> {
+        self.runtime.block_on(self.client.list_users(input))
+    }
+
+    /// Blocking version of [`Connect::resume_contact_recording`].
+    pub fn resume_contact_recording(
+        &self,
+        input: ResumeContactRecordingRequest,
+    ) -> Result<ResumeContactRecordingResponse, RusotoError<ResumeContactRecordingError>> {
+        self.runtime
+            .block_on(self.client.resume_contact_recording(input))
+    }
+
+    /// Blocking version of [`Connect::start_chat_contact`].
+    pub fn start_chat_contact(
+        &self,
+        input: StartChatContactRequest,
+    ) -> Result<StartChatContactResponse, RusotoError<StartChatContactError>> {
+        self.runtime.block_on(self.client.start_chat_contact(input))
+    }
+
+    /// Blocking version of [`Connect::start_contact_recording`].
+    pub fn start_contact_recording(
+        &self,
+        input: StartContactRecordingRequest,
+    ) -> Result<StartContactRecordingResponse, RusotoError<StartContactRecordingError>> {
+        self.runtime
+            .block_on(self.client.start_contact_recording(input))
+    }
+
+    /// Blocking version of [`Connect::start_outbound_voice_contact`].
+    pub fn start_outbound_voice_contact(
+        &self,
+        input: StartOutboundVoiceContactRequest,
+    ) -> Result<StartOutboundVoiceContactResponse, RusotoError<StartOutboundVoiceContactError>>
+    {
+        self.runtime
+            .block_on(self.client.start_outbound_voice_contact(input))
+    }
+
+    /// Blocking version of [`Connect::start_task_contact`].
+    pub fn start_task_contact(
+        &self,
+        input: StartTaskContactRequest,
+    ) -> Result<StartTaskContactResponse, RusotoError<StartTaskContactError>> {
+        self.runtime.block_on(self.client.start_task_contact(input))
+    }
+
+    /// Blocking version of [`Connect::stop_contact`].
+    pub fn stop_contact(
+        &self,
+        input: StopContactRequest,
+    ) -> Result<StopContactResponse, RusotoError<StopContactError>> {
+        self.runtime.block_on(self.client.stop_contact(input))
+    }
+
+    /// Blocking version of [`Connect::stop_contact_recording`].
+    pub fn stop_contact_recording(
+        &self,
+        input: StopContactRecordingRequest,
+    ) -> Result<StopContactRecordingResponse, RusotoError<StopContactRecordingError>> {
+        self.runtime
+            .block_on(self.client.stop_contact_recording(input))
+    }
+
+    /// Blocking version of [`Connect::suspend_contact_recording`].
+    pub fn suspend_contact_recording(
+        &self,
+        input: SuspendContactRecordingRequest,
+    ) -> Result<SuspendContactRecordingResponse, RusotoError<SuspendContactRecordingError>> {
+        self.runtime
+            .block_on(self.client.suspend_contact_recording(input))
+    }
+
+    /// Blocking version of [`Connect::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`Connect::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`Connect::update_contact_attributes`].
+    pub fn update_contact_attributes(
+        &self,
+        input: UpdateContactAttributesRequest,
+    ) -> Result<UpdateContactAttributesResponse, RusotoError<UpdateContactAttributesError>> {
+        self.runtime
+            .block_on(self.client.update_contact_attributes(input))
+    }
+
+    /// Blocking version of [`Connect::update_contact_flow_content`].
+    pub fn update_contact_flow_content(
+        &self,
+        input: UpdateContactFlowContentRequest,
+    ) -> Result<(), RusotoError<UpdateContactFlowContentError>> {
+        self.runtime
+            .block_on(self.client.update_contact_flow_content(input))
+    }
+
+    /// Blocking version of [`Connect::update_contact_flow_name`].
+    pub fn update_contact_flow_name(
+        &self,
+        input: UpdateContactFlowNameRequest,
+    ) -> Result<(), RusotoError<UpdateContactFlowNameError>> {
+        self.runtime
+            .block_on(self.client.update_contact_flow_name(input))
+    }
+
+    /// Blocking version of [`Connect::update_instance_attribute`].
+    pub fn update_instance_attribute(
+        &self,
+        input: UpdateInstanceAttributeRequest,
+    ) -> Result<(), RusotoError<UpdateInstanceAttributeError>> {
+        self.runtime
+            .block_on(self.client.update_instance_attribute(input))
+    }
+
+    /// Blocking version of [`Connect::update_instance_storage_config`].
+    pub fn update_instance_storage_config(
+        &self,
+        input: UpdateInstanceStorageConfigRequest,
+    ) -> Result<(), RusotoError<UpdateInstanceStorageConfigError>> {
+        self.runtime
+            .block_on(self.client.update_instance_storage_config(input))
+    }
+
+    /// Blocking version of [`Connect::update_quick_connect_config`].
+    pub fn update_quick_connect_config(
+        &self,
+        input: UpdateQuickConnectConfigRequest,
+    ) -> Result<(), RusotoError<UpdateQuickConnectConfigError>> {
+        self.runtime
+            .block_on(self.client.update_quick_connect_config(input))
+    }
+
+    /// Blocking version of [`Connect::update_quick_connect_name`].
+    pub fn update_quick_connect_name(
+        &self,
+        input: UpdateQuickConnectNameRequest,
+    ) -> Result<(), RusotoError<UpdateQuickConnectNameError>> {
+        self.runtime
+            .block_on(self.client.update_quick_connect_name(input))
+    }
+
+    /// Blocking version of [`Connect::update_routing_profile_concurrency`].
+    pub fn update_routing_profile_concurrency(
+        &self,
+        input: UpdateRoutingProfileConcurrencyRequest,
+    ) -> Result<(), RusotoError<UpdateRoutingProfileConcurrencyError>> {
+        self.runtime
+            .block_on(self.client.update_routing_profile_concurrency(input))
+    }
+
+    /// Blocking version of [`Connect::update_routing_profile_default_outbound_queue`].
+    pub fn update_routing_profile_default_outbound_queue(
+        &self,
+        input: UpdateRoutingProfileDefaultOutboundQueueRequest,
+    ) -> Result<(), RusotoError<UpdateRoutingProfileDefaultOutboundQueueError>> {
+        self.runtime.block_on(
+            self.client
+                .update_routing_profile_default_outbound_queue(input),
+        )
+    }
+
+    /// Blocking version of [`Connect::update_routing_profile_name`].
+    pub fn update_routing_profile_name(
+        &self,
+        input: UpdateRoutingProfileNameRequest,
+    ) -> Result<(), RusotoError<UpdateRoutingProfileNameError>> {
+        self.runtime
+            .block_on(self.client.update_routing_profile_name(input))
+    }
+
+    /// Blocking version of [`Connect::update_routing_profile_queues`].
+    pub fn update_routing_profile_queues(
+        &self,
+        input: UpdateRoutingProfileQueuesRequest,
+    ) -> Result<(), RusotoError<UpdateRoutingProfileQueuesError>> {
+        self.runtime
+            .block_on(self.client.update_routing_profile_queues(input))
+    }
+
+    /// Blocking version of [`Connect::update_user_hierarchy`].
+    pub fn update_user_hierarchy(
+        &self,
+        input: UpdateUserHierarchyRequest,
+    ) -> Result<(), RusotoError<UpdateUserHierarchyError>> {
+        self.runtime
+            .block_on(self.client.update_user_hierarchy(input))
+    }
+
+    /// Blocking version of [`Connect::update_user_hierarchy_group_name`].
+    pub fn update_user_hierarchy_group_name(
+        &self,
+        input: UpdateUserHierarchyGroupNameRequest,
+    ) -> Result<(), RusotoError<UpdateUserHierarchyGroupNameError>> {
+        self.runtime
+            .block_on(self.client.update_user_hierarchy_group_name(input))
+    }
+
+    /// Blocking version of [`Connect::update_user_hierarchy_structure`].
+    pub fn update_user_hierarchy_structure(
+        &self,
+        input: UpdateUserHierarchyStructureRequest,
+    ) -> Result<(), RusotoError<UpdateUserHierarchyStructureError>> {
+        self.runtime
+            .block_on(self.client.update_user_hierarchy_structure(input))
+    }
+
+    /// Blocking version of [`Connect::update_user_identity_info`].
+    pub fn update_user_identity_info(
+        &self,
+        input: UpdateUserIdentityInfoRequest,
+    ) -> Result<(), RusotoError<UpdateUserIdentityInfoError>> {
+        self.runtime
+            .block_on(self.client.update_user_identity_info(input))
+    }
+
+    /// Blocking version of [`Connect::update_user_phone_config`].
+    pub fn update_user_phone_config(
+        &self,
+        input: UpdateUserPhoneConfigRequest,
+    ) -> Result<(), RusotoError<UpdateUserPhoneConfigError>> {
+        self.runtime
+            .block_on(self.client.update_user_phone_config(input))
+    }
+
+    /// Blocking version of [`Connect::update_user_routing_profile`].
+    pub fn update_user_routing_profile(
+        &self,
+        input: UpdateUserRoutingProfileRequest,
+    ) -> Result<(), RusotoError<UpdateUserRoutingProfileError>> {
+        self.runtime
+            .block_on(self.client.update_user_routing_profile(input))
+    }
+
+    /// Blocking version of [`Connect::update_user_security_profiles`].
+    pub fn update_user_security_profiles(
+        &self,
+        input: UpdateUserSecurityProfilesRequest,
+    ) -> Result<(), RusotoError<UpdateUserSecurityProfilesError>> {
+        self.runtime
+            .block_on(self.client.update_user_security_profiles(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -399,27 +400,46 @@ impl CompleteAttachmentUploadError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CompleteAttachmentUploadError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CompleteAttachmentUploadError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CompleteAttachmentUploadError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CompleteAttachmentUploadError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CompleteAttachmentUploadError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CompleteAttachmentUploadError::InternalServer,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(
-                        CompleteAttachmentUploadError::ServiceQuotaExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CompleteAttachmentUploadError::ServiceQuotaExceeded,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CompleteAttachmentUploadError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CompleteAttachmentUploadError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -459,21 +479,30 @@ impl CreateParticipantConnectionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateParticipantConnectionError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateParticipantConnectionError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateParticipantConnectionError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateParticipantConnectionError::InternalServer,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateParticipantConnectionError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateParticipantConnectionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -507,17 +536,30 @@ impl DisconnectParticipantError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DisconnectParticipantError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisconnectParticipantError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DisconnectParticipantError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisconnectParticipantError::InternalServer,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DisconnectParticipantError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisconnectParticipantError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -551,15 +593,30 @@ impl GetAttachmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetAttachmentError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAttachmentError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetAttachmentError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAttachmentError::InternalServer,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetAttachmentError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAttachmentError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -593,15 +650,30 @@ impl GetTranscriptError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetTranscriptError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTranscriptError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetTranscriptError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTranscriptError::InternalServer,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetTranscriptError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTranscriptError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -635,15 +707,30 @@ impl SendEventError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(SendEventError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendEventError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(SendEventError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendEventError::InternalServer,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SendEventError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendEventError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -677,15 +764,30 @@ impl SendMessageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(SendMessageError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendMessageError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(SendMessageError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendMessageError::InternalServer,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SendMessageError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendMessageError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -721,22 +823,38 @@ impl StartAttachmentUploadError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartAttachmentUploadError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartAttachmentUploadError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(StartAttachmentUploadError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartAttachmentUploadError::InternalServer,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(StartAttachmentUploadError::ServiceQuotaExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartAttachmentUploadError::ServiceQuotaExceeded,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartAttachmentUploadError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartAttachmentUploadError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -816,11 +934,12 @@ pub struct ConnectParticipantClient {
 impl ConnectParticipantClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> ConnectParticipantClient {
         ConnectParticipantClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "ConnectParticipant"),
         }
     }
 
@@ -835,12 +954,32 @@ impl ConnectParticipantClient {
     {
         ConnectParticipantClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "ConnectParticipant"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ConnectParticipantClient {
-        ConnectParticipantClient { client, region }
+        ConnectParticipantClient {
+            client,
+            region: region_for_service(region, "ConnectParticipant"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> ConnectParticipantClient {
+        ConnectParticipantClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("ConnectParticipant"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ConnectParticipantClient {
+        ConnectParticipantClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -855,6 +994,7 @@ impl ConnectParticipant for ConnectParticipantClient {
         let request_uri = "/participant/complete-attachment-upload";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CompleteAttachmentUpload");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("participant.connect".to_string());
@@ -889,6 +1029,7 @@ impl ConnectParticipant for ConnectParticipantClient {
         let request_uri = "/participant/connection";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateParticipantConnection");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("participant.connect".to_string());
@@ -922,6 +1063,7 @@ impl ConnectParticipant for ConnectParticipantClient {
         let request_uri = "/participant/disconnect";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("DisconnectParticipant");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("participant.connect".to_string());
@@ -955,6 +1097,7 @@ impl ConnectParticipant for ConnectParticipantClient {
         let request_uri = "/participant/attachment";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("GetAttachment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("participant.connect".to_string());
@@ -988,6 +1131,7 @@ impl ConnectParticipant for ConnectParticipantClient {
         let request_uri = "/participant/transcript";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("GetTranscript");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("participant.connect".to_string());
@@ -1021,6 +1165,7 @@ impl ConnectParticipant for ConnectParticipantClient {
         let request_uri = "/participant/event";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("SendEvent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("participant.connect".to_string());
@@ -1054,6 +1199,7 @@ impl ConnectParticipant for ConnectParticipantClient {
         let request_uri = "/participant/message";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("SendMessage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("participant.connect".to_string());
@@ -1087,6 +1233,7 @@ impl ConnectParticipant for ConnectParticipantClient {
         let request_uri = "/participant/start-attachment-upload";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("StartAttachmentUpload");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("participant.connect".to_string());
@@ -1111,3 +1258,157 @@ impl ConnectParticipant for ConnectParticipantClient {
         }
     }
 }
+
+/// A blocking client for the Amazon Connect Participant API, running the calls of a `ConnectParticipantClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `ConnectParticipantClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ConnectParticipantBlockingClient {
+    client: ConnectParticipantClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ConnectParticipantBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> ConnectParticipantBlockingClient {
+        ConnectParticipantBlockingClient::from_client(ConnectParticipantClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ConnectParticipantBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ConnectParticipantBlockingClient::from_client(ConnectParticipantClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> ConnectParticipantBlockingClient {
+        ConnectParticipantBlockingClient::from_client(ConnectParticipantClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> ConnectParticipantBlockingClient {
+        ConnectParticipantBlockingClient::from_client(ConnectParticipantClient::new_with_config(
+            config,
+        ))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: ConnectParticipantClient) -> ConnectParticipantBlockingClient {
+        ConnectParticipantBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> ConnectParticipantBlockingClient {
+        ConnectParticipantBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ConnectParticipantBlockingClient {
+        ConnectParticipantBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`ConnectParticipant::complete_attachment_upload`].
+    pub fn complete_attachment_upload(
+        &self,
+        input: CompleteAttachmentUploadRequest,
+    ) -> Result<CompleteAttachmentUploadResponse, RusotoError<CompleteAttachmentUploadError>> {
+        self.runtime
+            .block_on(self.client.complete_attachment_upload(input))
+    }
+
+    /// Blocking version of [`ConnectParticipant::create_participant_connection`].
+    pub fn create_participant_connection(
+        &self,
+        input: CreateParticipantConnectionRequest,
+    ) -> Result<CreateParticipantConnectionResponse, RusotoError<CreateParticipantConnectionError>>
+    {
+        self.runtime
+            .block_on(self.client.create_participant_connection(input))
+    }
+
+    /// Blocking version of [`ConnectParticipant::disconnect_participant`].
+    pub fn disconnect_participant(
+        &self,
+        input: DisconnectParticipantRequest,
+    ) -> Result<DisconnectParticipantResponse, RusotoError<DisconnectParticipantError>> {
+        self.runtime
+            .block_on(self.client.disconnect_participant(input))
+    }
+
+    /// Blocking version of [`ConnectParticipant::get_attachment`].
+    pub fn get_attachment(
+        &self,
+        input: GetAttachmentRequest,
+    ) -> Result<GetAttachmentResponse, RusotoError<GetAttachmentError>> {
+        self.runtime.block_on(self.client.get_attachment(input))
+    }
+
+    /// Blocking version of [`ConnectParticipant::get_transcript`].
+    pub fn get_transcript(
+        &self,
+        input: GetTranscriptRequest,
+    ) -> Result<GetTranscriptResponse, RusotoError<GetTranscriptError>> {
+        self.runtime.block_on(self.client.get_transcript(input))
+    }
+
+    /// Blocking version of [`ConnectParticipant::send_event`].
+    pub fn send_event(
+        &self,
+        input: SendEventRequest,
+    ) -> Result<SendEventResponse, RusotoError<SendEventError>> {
+        self.runtime.block_on(self.client.send_event(input))
+    }
+
+    /// Blocking version of [`ConnectParticipant::send_message`].
+    pub fn send_message(
+        &self,
+        input: SendMessageRequest,
+    ) -> Result<SendMessageResponse, RusotoError<SendMessageError>> {
+        self.runtime.block_on(self.client.send_message(input))
+    }
+
+    /// Blocking version of [`ConnectParticipant::start_attachment_upload`].
+    pub fn start_attachment_upload(
+        &self,
+        input: StartAttachmentUploadRequest,
+    ) -> Result<StartAttachmentUploadResponse, RusotoError<StartAttachmentUploadError>> {
+        self.runtime
+            .block_on(self.client.start_attachment_upload(input))
+    }
+}
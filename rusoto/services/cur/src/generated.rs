@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -167,11 +168,14 @@ impl DeleteReportDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteReportDefinitionError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteReportDefinitionError::InternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -199,11 +203,14 @@ impl DescribeReportDefinitionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeReportDefinitionsError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeReportDefinitionsError::InternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -231,11 +238,14 @@ impl ModifyReportDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ModifyReportDefinitionError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ModifyReportDefinitionError::InternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -267,19 +277,30 @@ impl PutReportDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateReportNameException" => {
-                    return RusotoError::Service(PutReportDefinitionError::DuplicateReportName(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutReportDefinitionError::DuplicateReportName,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(PutReportDefinitionError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutReportDefinitionError::InternalError,
+                    )
                 }
                 "ReportLimitReachedException" => {
-                    return RusotoError::Service(PutReportDefinitionError::ReportLimitReached(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutReportDefinitionError::ReportLimitReached,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -334,11 +355,12 @@ pub struct CostAndUsageReportClient {
 impl CostAndUsageReportClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> CostAndUsageReportClient {
         CostAndUsageReportClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Cost and Usage Report Service"),
         }
     }
 
@@ -353,12 +375,32 @@ impl CostAndUsageReportClient {
     {
         CostAndUsageReportClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Cost and Usage Report Service"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CostAndUsageReportClient {
-        CostAndUsageReportClient { client, region }
+        CostAndUsageReportClient {
+            client,
+            region: region_for_service(region, "Cost and Usage Report Service"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> CostAndUsageReportClient {
+        CostAndUsageReportClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Cost and Usage Report Service"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CostAndUsageReportClient {
+        CostAndUsageReportClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -370,6 +412,7 @@ impl CostAndUsageReport for CostAndUsageReportClient {
         input: DeleteReportDefinitionRequest,
     ) -> Result<DeleteReportDefinitionResponse, RusotoError<DeleteReportDefinitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteReportDefinition");
         request.add_header(
             "x-amz-target",
             "AWSOrigamiServiceGatewayService.DeleteReportDefinition",
@@ -393,6 +436,7 @@ impl CostAndUsageReport for CostAndUsageReportClient {
     ) -> Result<DescribeReportDefinitionsResponse, RusotoError<DescribeReportDefinitionsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeReportDefinitions");
         request.add_header(
             "x-amz-target",
             "AWSOrigamiServiceGatewayService.DescribeReportDefinitions",
@@ -415,6 +459,7 @@ impl CostAndUsageReport for CostAndUsageReportClient {
         input: ModifyReportDefinitionRequest,
     ) -> Result<ModifyReportDefinitionResponse, RusotoError<ModifyReportDefinitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ModifyReportDefinition");
         request.add_header(
             "x-amz-target",
             "AWSOrigamiServiceGatewayService.ModifyReportDefinition",
@@ -437,6 +482,7 @@ impl CostAndUsageReport for CostAndUsageReportClient {
         input: PutReportDefinitionRequest,
     ) -> Result<PutReportDefinitionResponse, RusotoError<PutReportDefinitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutReportDefinition");
         request.add_header(
             "x-amz-target",
             "AWSOrigamiServiceGatewayService.PutReportDefinition",
@@ -452,3 +498,125 @@ impl CostAndUsageReport for CostAndUsageReportClient {
         proto::json::ResponsePayload::new(&response).deserialize::<PutReportDefinitionResponse, _>()
     }
 }
+
+/// A blocking client for the AWS Cost and Usage Report Service API, running the calls of a `CostAndUsageReportClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `CostAndUsageReportClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CostAndUsageReportBlockingClient {
+    client: CostAndUsageReportClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CostAndUsageReportBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> CostAndUsageReportBlockingClient {
+        CostAndUsageReportBlockingClient::from_client(CostAndUsageReportClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CostAndUsageReportBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CostAndUsageReportBlockingClient::from_client(CostAndUsageReportClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> CostAndUsageReportBlockingClient {
+        CostAndUsageReportBlockingClient::from_client(CostAndUsageReportClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> CostAndUsageReportBlockingClient {
+        CostAndUsageReportBlockingClient::from_client(CostAndUsageReportClient::new_with_config(
+            config,
+        ))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: CostAndUsageReportClient) -> CostAndUsageReportBlockingClient {
+        CostAndUsageReportBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> CostAndUsageReportBlockingClient {
+        CostAndUsageReportBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CostAndUsageReportBlockingClient {
+        CostAndUsageReportBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`CostAndUsageReport::delete_report_definition`].
+    pub fn delete_report_definition(
+        &self,
+        input: DeleteReportDefinitionRequest,
+    ) -> Result<DeleteReportDefinitionResponse, RusotoError<DeleteReportDefinitionError>> {
+        self.runtime
+            .block_on(self.client.delete_report_definition(input))
+    }
+
+    /// Blocking version of [`CostAndUsageReport::describe_report_definitions`].
+    pub fn describe_report_definitions(
+        &self,
+        input: DescribeReportDefinitionsRequest,
+    ) -> Result<DescribeReportDefinitionsResponse, RusotoError<DescribeReportDefinitionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_report_definitions(input))
+    }
+
+    /// Blocking version of [`CostAndUsageReport::modify_report_definition`].
+    pub fn modify_report_definition(
+        &self,
+        input: ModifyReportDefinitionRequest,
+    ) -> Result<ModifyReportDefinitionResponse, RusotoError<ModifyReportDefinitionError>> {
+        self.runtime
+            .block_on(self.client.modify_report_definition(input))
+    }
+
+    /// Blocking version of [`CostAndUsageReport::put_report_definition`].
+    pub fn put_report_definition(
+        &self,
+        input: PutReportDefinitionRequest,
+    ) -> Result<PutReportDefinitionResponse, RusotoError<PutReportDefinitionError>> {
+        self.runtime
+            .block_on(self.client.put_report_definition(input))
+    }
+}
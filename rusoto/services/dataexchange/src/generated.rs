@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1277,18 +1278,38 @@ impl CancelJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(CancelJobError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelJobError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CancelJobError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelJobError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelJobError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CancelJobError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelJobError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1325,18 +1346,38 @@ impl CreateDataSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDataSetError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataSetError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateDataSetError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataSetError::InternalServer,
+                    )
                 }
                 "ServiceLimitExceededException" => {
-                    return RusotoError::Service(CreateDataSetError::ServiceLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataSetError::ServiceLimitExceeded,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDataSetError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataSetError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1373,18 +1414,38 @@ impl CreateJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateJobError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateJobError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateJobError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateJobError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateJobError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateJobError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateJobError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1421,18 +1482,38 @@ impl CreateRevisionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateRevisionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRevisionError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateRevisionError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRevisionError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateRevisionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRevisionError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateRevisionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRevisionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1471,21 +1552,46 @@ impl DeleteAssetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteAssetError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAssetError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteAssetError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAssetError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteAssetError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAssetError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteAssetError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAssetError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteAssetError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAssetError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1525,21 +1631,46 @@ impl DeleteDataSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteDataSetError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDataSetError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteDataSetError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDataSetError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteDataSetError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDataSetError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDataSetError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDataSetError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteDataSetError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDataSetError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1579,21 +1710,46 @@ impl DeleteRevisionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteRevisionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRevisionError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteRevisionError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRevisionError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteRevisionError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRevisionError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteRevisionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRevisionError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteRevisionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRevisionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1629,15 +1785,30 @@ impl GetAssetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetAssetError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAssetError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetAssetError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAssetError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetAssetError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAssetError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1671,15 +1842,30 @@ impl GetDataSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetDataSetError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDataSetError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDataSetError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDataSetError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetDataSetError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDataSetError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1713,15 +1899,30 @@ impl GetJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetJobError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetJobError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1755,15 +1956,30 @@ impl GetRevisionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetRevisionError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRevisionError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetRevisionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRevisionError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetRevisionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRevisionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1797,17 +2013,30 @@ impl ListDataSetRevisionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListDataSetRevisionsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDataSetRevisionsError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDataSetRevisionsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDataSetRevisionsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDataSetRevisionsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDataSetRevisionsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1841,15 +2070,30 @@ impl ListDataSetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListDataSetsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDataSetsError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDataSetsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDataSetsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDataSetsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDataSetsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1883,15 +2127,30 @@ impl ListJobsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListJobsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListJobsError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListJobsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListJobsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListJobsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListJobsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1925,15 +2184,30 @@ impl ListRevisionAssetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListRevisionAssetsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRevisionAssetsError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListRevisionAssetsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRevisionAssetsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListRevisionAssetsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRevisionAssetsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1959,7 +2233,7 @@ impl ListTagsForResourceError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListTagsForResourceError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1993,21 +2267,46 @@ impl StartJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartJobError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartJobError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(StartJobError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartJobError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(StartJobError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartJobError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartJobError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartJobError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartJobError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2035,7 +2334,7 @@ impl TagResourceError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<TagResourceError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2057,7 +2356,7 @@ impl UntagResourceError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UntagResourceError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2091,21 +2390,46 @@ impl UpdateAssetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateAssetError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAssetError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateAssetError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAssetError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateAssetError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAssetError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateAssetError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAssetError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateAssetError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAssetError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2143,18 +2467,38 @@ impl UpdateDataSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateDataSetError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDataSetError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateDataSetError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDataSetError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDataSetError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDataSetError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDataSetError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDataSetError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2193,21 +2537,46 @@ impl UpdateRevisionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateRevisionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRevisionError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateRevisionError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRevisionError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateRevisionError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRevisionError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateRevisionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRevisionError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateRevisionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRevisionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2369,11 +2738,12 @@ pub struct DataExchangeClient {
 impl DataExchangeClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> DataExchangeClient {
         DataExchangeClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "DataExchange"),
         }
     }
 
@@ -2388,12 +2758,32 @@ impl DataExchangeClient {
     {
         DataExchangeClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "DataExchange"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> DataExchangeClient {
-        DataExchangeClient { client, region }
+        DataExchangeClient {
+            client,
+            region: region_for_service(region, "DataExchange"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> DataExchangeClient {
+        DataExchangeClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("DataExchange"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> DataExchangeClient {
+        DataExchangeClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -2405,6 +2795,7 @@ impl DataExchange for DataExchangeClient {
         let request_uri = format!("/v1/jobs/{job_id}", job_id = input.job_id);
 
         let mut request = SignedRequest::new("DELETE", "dataexchange", &self.region, &request_uri);
+        request.set_operation("CancelJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2432,6 +2823,7 @@ impl DataExchange for DataExchangeClient {
         let request_uri = "/v1/data-sets";
 
         let mut request = SignedRequest::new("POST", "dataexchange", &self.region, &request_uri);
+        request.set_operation("CreateDataSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2463,6 +2855,7 @@ impl DataExchange for DataExchangeClient {
         let request_uri = "/v1/jobs";
 
         let mut request = SignedRequest::new("POST", "dataexchange", &self.region, &request_uri);
+        request.set_operation("CreateJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2497,6 +2890,7 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("POST", "dataexchange", &self.region, &request_uri);
+        request.set_operation("CreateRevision");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2533,6 +2927,7 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "dataexchange", &self.region, &request_uri);
+        request.set_operation("DeleteAsset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2563,6 +2958,7 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "dataexchange", &self.region, &request_uri);
+        request.set_operation("DeleteDataSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2594,6 +2990,7 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "dataexchange", &self.region, &request_uri);
+        request.set_operation("DeleteRevision");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2626,6 +3023,7 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation("GetAsset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2657,6 +3055,7 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation("GetDataSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2685,6 +3084,7 @@ impl DataExchange for DataExchangeClient {
         let request_uri = format!("/v1/jobs/{job_id}", job_id = input.job_id);
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation("GetJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2717,6 +3117,7 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation("GetRevision");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2748,6 +3149,7 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation("ListDataSetRevisions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2785,6 +3187,7 @@ impl DataExchange for DataExchangeClient {
         let request_uri = "/v1/data-sets";
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation("ListDataSets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2825,6 +3228,7 @@ impl DataExchange for DataExchangeClient {
         let request_uri = "/v1/jobs";
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation("ListJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2872,6 +3276,7 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation("ListRevisionAssets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2909,6 +3314,7 @@ impl DataExchange for DataExchangeClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "dataexchange", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2937,6 +3343,7 @@ impl DataExchange for DataExchangeClient {
         let request_uri = format!("/v1/jobs/{job_id}", job_id = input.job_id);
 
         let mut request = SignedRequest::new("PATCH", "dataexchange", &self.region, &request_uri);
+        request.set_operation("StartJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2965,6 +3372,7 @@ impl DataExchange for DataExchangeClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "dataexchange", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2995,6 +3403,7 @@ impl DataExchange for DataExchangeClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "dataexchange", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3033,6 +3442,7 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "dataexchange", &self.region, &request_uri);
+        request.set_operation("UpdateAsset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3067,6 +3477,7 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "dataexchange", &self.region, &request_uri);
+        request.set_operation("UpdateDataSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3102,6 +3513,7 @@ impl DataExchange for DataExchangeClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "dataexchange", &self.region, &request_uri);
+        request.set_operation("UpdateRevision");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3124,3 +3536,257 @@ impl DataExchange for DataExchangeClient {
         }
     }
 }
+
+/// A blocking client for the AWS Data Exchange API, running the calls of a `DataExchangeClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `DataExchangeClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct DataExchangeBlockingClient {
+    client: DataExchangeClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl DataExchangeBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> DataExchangeBlockingClient {
+        DataExchangeBlockingClient::from_client(DataExchangeClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> DataExchangeBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        DataExchangeBlockingClient::from_client(DataExchangeClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> DataExchangeBlockingClient {
+        DataExchangeBlockingClient::from_client(DataExchangeClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> DataExchangeBlockingClient {
+        DataExchangeBlockingClient::from_client(DataExchangeClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: DataExchangeClient) -> DataExchangeBlockingClient {
+        DataExchangeBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> DataExchangeBlockingClient {
+        DataExchangeBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> DataExchangeBlockingClient {
+        DataExchangeBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`DataExchange::cancel_job`].
+    pub fn cancel_job(&self, input: CancelJobRequest) -> Result<(), RusotoError<CancelJobError>> {
+        self.runtime.block_on(self.client.cancel_job(input))
+    }
+
+    /// Blocking version of [`DataExchange::create_data_set`].
+    pub fn create_data_set(
+        &self,
+        input: CreateDataSetRequest,
+    ) -> Result<CreateDataSetResponse, RusotoError<CreateDataSetError>> {
+        self.runtime.block_on(self.client.create_data_set(input))
+    }
+
+    /// Blocking version of [`DataExchange::create_job`].
+    pub fn create_job(
+        &self,
+        input: CreateJobRequest,
+    ) -> Result<CreateJobResponse, RusotoError<CreateJobError>> {
+        self.runtime.block_on(self.client.create_job(input))
+    }
+
+    /// Blocking version of [`DataExchange::create_revision`].
+    pub fn create_revision(
+        &self,
+        input: CreateRevisionRequest,
+    ) -> Result<CreateRevisionResponse, RusotoError<CreateRevisionError>> {
+        self.runtime.block_on(self.client.create_revision(input))
+    }
+
+    /// Blocking version of [`DataExchange::delete_asset`].
+    pub fn delete_asset(
+        &self,
+        input: DeleteAssetRequest,
+    ) -> Result<(), RusotoError<DeleteAssetError>> {
+        self.runtime.block_on(self.client.delete_asset(input))
+    }
+
+    /// Blocking version of [`DataExchange::delete_data_set`].
+    pub fn delete_data_set(
+        &self,
+        input: DeleteDataSetRequest,
+    ) -> Result<(), RusotoError<DeleteDataSetError>> {
+        self.runtime.block_on(self.client.delete_data_set(input))
+    }
+
+    /// Blocking version of [`DataExchange::delete_revision`].
+    pub fn delete_revision(
+        &self,
+        input: DeleteRevisionRequest,
+    ) -> Result<(), RusotoError<DeleteRevisionError>> {
+        self.runtime.block_on(self.client.delete_revision(input))
+    }
+
+    /// Blocking version of [`DataExchange::get_asset`].
+    pub fn get_asset(
+        &self,
+        input: GetAssetRequest,
+    ) -> Result<GetAssetResponse, RusotoError<GetAssetError>> {
+        self.runtime.block_on(self.client.get_asset(input))
+    }
+
+    /// Blocking version of [`DataExchange::get_data_set`].
+    pub fn get_data_set(
+        &self,
+        input: GetDataSetRequest,
+    ) -> Result<GetDataSetResponse, RusotoError<GetDataSetError>> {
+        self.runtime.block_on(self.client.get_data_set(input))
+    }
+
+    /// Blocking version of [`DataExchange::get_job`].
+    pub fn get_job(
+        &self,
+        input: GetJobRequest,
+    ) -> Result<GetJobResponse, RusotoError<GetJobError>> {
+        self.runtime.block_on(self.client.get_job(input))
+    }
+
+    /// Blocking version of [`DataExchange::get_revision`].
+    pub fn get_revision(
+        &self,
+        input: GetRevisionRequest,
+    ) -> Result<GetRevisionResponse, RusotoError<GetRevisionError>> {
+        self.runtime.block_on(self.client.get_revision(input))
+    }
+
+    /// Blocking version of [`DataExchange::list_data_set_revisions`].
+    pub fn list_data_set_revisions(
+        &self,
+        input: ListDataSetRevisionsRequest,
+    ) -> Result<ListDataSetRevisionsResponse, RusotoError<ListDataSetRevisionsError>> {
+        self.runtime
+            .block_on(self.client.list_data_set_revisions(input))
+    }
+
+    /// Blocking version of [`DataExchange::list_data_sets`].
+    pub fn list_data_sets(
+        &self,
+        input: ListDataSetsRequest,
+    ) -> Result<ListDataSetsResponse, RusotoError<ListDataSetsError>> {
+        self.runtime.block_on(self.client.list_data_sets(input))
+    }
+
+    /// Blocking version of [`DataExchange::list_jobs`].
+    pub fn list_jobs(
+        &self,
+        input: ListJobsRequest,
+    ) -> Result<ListJobsResponse, RusotoError<ListJobsError>> {
+        self.runtime.block_on(self.client.list_jobs(input))
+    }
+
+    /// Blocking version of [`DataExchange::list_revision_assets`].
+    pub fn list_revision_assets(
+        &self,
+        input: ListRevisionAssetsRequest,
+    ) -> Result<ListRevisionAssetsResponse, RusotoError<ListRevisionAssetsError>> {
+        self.runtime
+            .block_on(self.client.list_revision_assets(input))
+    }
+
+    /// Blocking version of [`DataExchange::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`DataExchange::start_job`].
+    pub fn start_job(
+        &self,
+        input: StartJobRequest,
+    ) -> Result<StartJobResponse, RusotoError<StartJobError>> {
+        self.runtime.block_on(self.client.start_job(input))
+    }
+
+    /// Blocking version of [`DataExchange::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`DataExchange::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`DataExchange::update_asset`].
+    pub fn update_asset(
+        &self,
+        input: UpdateAssetRequest,
+    ) -> Result<UpdateAssetResponse, RusotoError<UpdateAssetError>> {
+        self.runtime.block_on(self.client.update_asset(input))
+    }
+
+    /// Blocking version of [`DataExchange::update_data_set`].
+    pub fn update_data_set(
+        &self,
+        input: UpdateDataSetRequest,
+    ) -> Result<UpdateDataSetResponse, RusotoError<UpdateDataSetError>> {
+        self.runtime.block_on(self.client.update_data_set(input))
+    }
+
+    /// Blocking version of [`DataExchange::update_revision`].
+    pub fn update_revision(
+        &self,
+        input: UpdateRevisionRequest,
+    ) -> Result<UpdateRevisionResponse, RusotoError<UpdateRevisionError>> {
+        self.runtime.block_on(self.client.update_revision(input))
+    }
+}
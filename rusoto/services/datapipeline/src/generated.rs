@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -765,20 +766,38 @@ impl ActivatePipelineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(ActivatePipelineError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ActivatePipelineError::InternalServiceError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ActivatePipelineError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ActivatePipelineError::InvalidRequest,
+                    )
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(ActivatePipelineError::PipelineDeleted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ActivatePipelineError::PipelineDeleted,
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(ActivatePipelineError::PipelineNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ActivatePipelineError::PipelineNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -815,18 +834,38 @@ impl AddTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(AddTagsError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsError::InternalServiceError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AddTagsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsError::InvalidRequest,
+                    )
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(AddTagsError::PipelineDeleted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsError::PipelineDeleted,
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(AddTagsError::PipelineNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsError::PipelineNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -859,12 +898,22 @@ impl CreatePipelineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(CreatePipelineError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePipelineError::InternalServiceError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreatePipelineError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePipelineError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -899,20 +948,38 @@ impl DeactivatePipelineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(DeactivatePipelineError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeactivatePipelineError::InternalServiceError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeactivatePipelineError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeactivatePipelineError::InvalidRequest,
+                    )
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(DeactivatePipelineError::PipelineDeleted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeactivatePipelineError::PipelineDeleted,
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(DeactivatePipelineError::PipelineNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeactivatePipelineError::PipelineNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -947,15 +1014,30 @@ impl DeletePipelineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(DeletePipelineError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePipelineError::InternalServiceError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeletePipelineError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePipelineError::InvalidRequest,
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(DeletePipelineError::PipelineNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePipelineError::PipelineNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -991,20 +1073,38 @@ impl DescribeObjectsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(DescribeObjectsError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeObjectsError::InternalServiceError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeObjectsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeObjectsError::InvalidRequest,
+                    )
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(DescribeObjectsError::PipelineDeleted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeObjectsError::PipelineDeleted,
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(DescribeObjectsError::PipelineNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeObjectsError::PipelineNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1041,20 +1141,38 @@ impl DescribePipelinesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(DescribePipelinesError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribePipelinesError::InternalServiceError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribePipelinesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePipelinesError::InvalidRequest,
+                    )
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(DescribePipelinesError::PipelineDeleted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePipelinesError::PipelineDeleted,
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(DescribePipelinesError::PipelineNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePipelinesError::PipelineNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1093,23 +1211,46 @@ impl EvaluateExpressionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(EvaluateExpressionError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        EvaluateExpressionError::InternalServiceError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(EvaluateExpressionError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EvaluateExpressionError::InvalidRequest,
+                    )
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(EvaluateExpressionError::PipelineDeleted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EvaluateExpressionError::PipelineDeleted,
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(EvaluateExpressionError::PipelineNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EvaluateExpressionError::PipelineNotFound,
+                    )
                 }
                 "TaskNotFoundException" => {
-                    return RusotoError::Service(EvaluateExpressionError::TaskNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EvaluateExpressionError::TaskNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1147,26 +1288,38 @@ impl GetPipelineDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(GetPipelineDefinitionError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetPipelineDefinitionError::InternalServiceError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetPipelineDefinitionError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetPipelineDefinitionError::InvalidRequest,
+                    )
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(GetPipelineDefinitionError::PipelineDeleted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetPipelineDefinitionError::PipelineDeleted,
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(GetPipelineDefinitionError::PipelineNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetPipelineDefinitionError::PipelineNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1199,12 +1352,22 @@ impl ListPipelinesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(ListPipelinesError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPipelinesError::InternalServiceError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListPipelinesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPipelinesError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1237,15 +1400,30 @@ impl PollForTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(PollForTaskError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PollForTaskError::InternalServiceError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(PollForTaskError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PollForTaskError::InvalidRequest,
+                    )
                 }
                 "TaskNotFoundException" => {
-                    return RusotoError::Service(PollForTaskError::TaskNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PollForTaskError::TaskNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1281,26 +1459,38 @@ impl PutPipelineDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(PutPipelineDefinitionError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutPipelineDefinitionError::InternalServiceError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(PutPipelineDefinitionError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutPipelineDefinitionError::InvalidRequest,
+                    )
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(PutPipelineDefinitionError::PipelineDeleted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutPipelineDefinitionError::PipelineDeleted,
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(PutPipelineDefinitionError::PipelineNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutPipelineDefinitionError::PipelineNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1337,18 +1527,38 @@ impl QueryObjectsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(QueryObjectsError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        QueryObjectsError::InternalServiceError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(QueryObjectsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        QueryObjectsError::InvalidRequest,
+                    )
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(QueryObjectsError::PipelineDeleted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        QueryObjectsError::PipelineDeleted,
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(QueryObjectsError::PipelineNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        QueryObjectsError::PipelineNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1385,18 +1595,38 @@ impl RemoveTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(RemoveTagsError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveTagsError::InternalServiceError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RemoveTagsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveTagsError::InvalidRequest,
+                    )
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(RemoveTagsError::PipelineDeleted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveTagsError::PipelineDeleted,
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(RemoveTagsError::PipelineNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveTagsError::PipelineNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1435,23 +1665,46 @@ impl ReportTaskProgressError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(ReportTaskProgressError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ReportTaskProgressError::InternalServiceError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ReportTaskProgressError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReportTaskProgressError::InvalidRequest,
+                    )
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(ReportTaskProgressError::PipelineDeleted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReportTaskProgressError::PipelineDeleted,
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(ReportTaskProgressError::PipelineNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReportTaskProgressError::PipelineNotFound,
+                    )
                 }
                 "TaskNotFoundException" => {
-                    return RusotoError::Service(ReportTaskProgressError::TaskNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReportTaskProgressError::TaskNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1485,16 +1738,22 @@ impl ReportTaskRunnerHeartbeatError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(
-                        ReportTaskRunnerHeartbeatError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReportTaskRunnerHeartbeatError::InternalServiceError,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ReportTaskRunnerHeartbeatError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ReportTaskRunnerHeartbeatError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1531,18 +1790,38 @@ impl SetStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(SetStatusError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetStatusError::InternalServiceError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SetStatusError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetStatusError::InvalidRequest,
+                    )
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(SetStatusError::PipelineDeleted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetStatusError::PipelineDeleted,
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(SetStatusError::PipelineNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetStatusError::PipelineNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1581,21 +1860,46 @@ impl SetTaskStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(SetTaskStatusError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetTaskStatusError::InternalServiceError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SetTaskStatusError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetTaskStatusError::InvalidRequest,
+                    )
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(SetTaskStatusError::PipelineDeleted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetTaskStatusError::PipelineDeleted,
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(SetTaskStatusError::PipelineNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetTaskStatusError::PipelineNotFound,
+                    )
                 }
                 "TaskNotFoundException" => {
-                    return RusotoError::Service(SetTaskStatusError::TaskNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetTaskStatusError::TaskNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1635,26 +1939,38 @@ impl ValidatePipelineDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(
-                        ValidatePipelineDefinitionError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ValidatePipelineDefinitionError::InternalServiceError,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ValidatePipelineDefinitionError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ValidatePipelineDefinitionError::InvalidRequest,
+                    )
                 }
                 "PipelineDeletedException" => {
-                    return RusotoError::Service(ValidatePipelineDefinitionError::PipelineDeleted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ValidatePipelineDefinitionError::PipelineDeleted,
+                    )
                 }
                 "PipelineNotFoundException" => {
-                    return RusotoError::Service(ValidatePipelineDefinitionError::PipelineNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ValidatePipelineDefinitionError::PipelineNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1799,11 +2115,12 @@ pub struct DataPipelineClient {
 impl DataPipelineClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> DataPipelineClient {
         DataPipelineClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Data Pipeline"),
         }
     }
 
@@ -1818,12 +2135,32 @@ impl DataPipelineClient {
     {
         DataPipelineClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Data Pipeline"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> DataPipelineClient {
-        DataPipelineClient { client, region }
+        DataPipelineClient {
+            client,
+            region: region_for_service(region, "Data Pipeline"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> DataPipelineClient {
+        DataPipelineClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Data Pipeline"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> DataPipelineClient {
+        DataPipelineClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -1835,6 +2172,7 @@ impl DataPipeline for DataPipelineClient {
         input: ActivatePipelineInput,
     ) -> Result<ActivatePipelineOutput, RusotoError<ActivatePipelineError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ActivatePipeline");
         request.add_header("x-amz-target", "DataPipeline.ActivatePipeline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1853,6 +2191,7 @@ impl DataPipeline for DataPipelineClient {
         input: AddTagsInput,
     ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AddTags");
         request.add_header("x-amz-target", "DataPipeline.AddTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1871,6 +2210,7 @@ impl DataPipeline for DataPipelineClient {
         input: CreatePipelineInput,
     ) -> Result<CreatePipelineOutput, RusotoError<CreatePipelineError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreatePipeline");
         request.add_header("x-amz-target", "DataPipeline.CreatePipeline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1889,6 +2229,7 @@ impl DataPipeline for DataPipelineClient {
         input: DeactivatePipelineInput,
     ) -> Result<DeactivatePipelineOutput, RusotoError<DeactivatePipelineError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeactivatePipeline");
         request.add_header("x-amz-target", "DataPipeline.DeactivatePipeline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1907,6 +2248,7 @@ impl DataPipeline for DataPipelineClient {
         input: DeletePipelineInput,
     ) -> Result<(), RusotoError<DeletePipelineError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeletePipeline");
         request.add_header("x-amz-target", "DataPipeline.DeletePipeline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1924,6 +2266,7 @@ impl DataPipeline for DataPipelineClient {
         input: DescribeObjectsInput,
     ) -> Result<DescribeObjectsOutput, RusotoError<DescribeObjectsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeObjects");
         request.add_header("x-amz-target", "DataPipeline.DescribeObjects");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1942,6 +2285,7 @@ impl DataPipeline for DataPipelineClient {
         input: DescribePipelinesInput,
     ) -> Result<DescribePipelinesOutput, RusotoError<DescribePipelinesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribePipelines");
         request.add_header("x-amz-target", "DataPipeline.DescribePipelines");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1960,6 +2304,7 @@ impl DataPipeline for DataPipelineClient {
         input: EvaluateExpressionInput,
     ) -> Result<EvaluateExpressionOutput, RusotoError<EvaluateExpressionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("EvaluateExpression");
         request.add_header("x-amz-target", "DataPipeline.EvaluateExpression");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1978,6 +2323,7 @@ impl DataPipeline for DataPipelineClient {
         input: GetPipelineDefinitionInput,
     ) -> Result<GetPipelineDefinitionOutput, RusotoError<GetPipelineDefinitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetPipelineDefinition");
         request.add_header("x-amz-target", "DataPipeline.GetPipelineDefinition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1996,6 +2342,7 @@ impl DataPipeline for DataPipelineClient {
         input: ListPipelinesInput,
     ) -> Result<ListPipelinesOutput, RusotoError<ListPipelinesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListPipelines");
         request.add_header("x-amz-target", "DataPipeline.ListPipelines");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2014,6 +2361,7 @@ impl DataPipeline for DataPipelineClient {
         input: PollForTaskInput,
     ) -> Result<PollForTaskOutput, RusotoError<PollForTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PollForTask");
         request.add_header("x-amz-target", "DataPipeline.PollForTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2032,6 +2380,7 @@ impl DataPipeline for DataPipelineClient {
         input: PutPipelineDefinitionInput,
     ) -> Result<PutPipelineDefinitionOutput, RusotoError<PutPipelineDefinitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutPipelineDefinition");
         request.add_header("x-amz-target", "DataPipeline.PutPipelineDefinition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2050,6 +2399,7 @@ impl DataPipeline for DataPipelineClient {
         input: QueryObjectsInput,
     ) -> Result<QueryObjectsOutput, RusotoError<QueryObjectsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("QueryObjects");
         request.add_header("x-amz-target", "DataPipeline.QueryObjects");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2068,6 +2418,7 @@ impl DataPipeline for DataPipelineClient {
         input: RemoveTagsInput,
     ) -> Result<RemoveTagsOutput, RusotoError<RemoveTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RemoveTags");
         request.add_header("x-amz-target", "DataPipeline.RemoveTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2086,6 +2437,7 @@ impl DataPipeline for DataPipelineClient {
         input: ReportTaskProgressInput,
     ) -> Result<ReportTaskProgressOutput, RusotoError<ReportTaskProgressError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ReportTaskProgress");
         request.add_header("x-amz-target", "DataPipeline.ReportTaskProgress");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2104,6 +2456,7 @@ impl DataPipeline for DataPipelineClient {
         input: ReportTaskRunnerHeartbeatInput,
     ) -> Result<ReportTaskRunnerHeartbeatOutput, RusotoError<ReportTaskRunnerHeartbeatError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ReportTaskRunnerHeartbeat");
         request.add_header("x-amz-target", "DataPipeline.ReportTaskRunnerHeartbeat");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2120,6 +2473,7 @@ impl DataPipeline for DataPipelineClient {
     /// <p>Requests that the status of the specified physical or logical pipeline objects be updated in the specified pipeline. This update might not occur immediately, but is eventually consistent. The status that can be set depends on the type of object (for example, DataNode or Activity). You cannot perform this operation on <code>FINISHED</code> pipelines and attempting to do so returns <code>InvalidRequestException</code>.</p>
     async fn set_status(&self, input: SetStatusInput) -> Result<(), RusotoError<SetStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SetStatus");
         request.add_header("x-amz-target", "DataPipeline.SetStatus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2137,6 +2491,7 @@ impl DataPipeline for DataPipelineClient {
         input: SetTaskStatusInput,
     ) -> Result<SetTaskStatusOutput, RusotoError<SetTaskStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SetTaskStatus");
         request.add_header("x-amz-target", "DataPipeline.SetTaskStatus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2156,6 +2511,7 @@ impl DataPipeline for DataPipelineClient {
     ) -> Result<ValidatePipelineDefinitionOutput, RusotoError<ValidatePipelineDefinitionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ValidatePipelineDefinition");
         request.add_header("x-amz-target", "DataPipeline.ValidatePipelineDefinition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2169,3 +2525,238 @@ impl DataPipeline for DataPipelineClient {
             .deserialize::<ValidatePipelineDefinitionOutput, _>()
     }
 }
+
+/// A blocking client for the AWS Data Pipeline API, running the calls of a `DataPipelineClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `DataPipelineClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct DataPipelineBlockingClient {
+    client: DataPipelineClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl DataPipelineBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> DataPipelineBlockingClient {
+        DataPipelineBlockingClient::from_client(DataPipelineClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> DataPipelineBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        DataPipelineBlockingClient::from_client(DataPipelineClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> DataPipelineBlockingClient {
+        DataPipelineBlockingClient::from_client(DataPipelineClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> DataPipelineBlockingClient {
+        DataPipelineBlockingClient::from_client(DataPipelineClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: DataPipelineClient) -> DataPipelineBlockingClient {
+        DataPipelineBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> DataPipelineBlockingClient {
+        DataPipelineBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> DataPipelineBlockingClient {
+        DataPipelineBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`DataPipeline::activate_pipeline`].
+    pub fn activate_pipeline(
+        &self,
+        input: ActivatePipelineInput,
+    ) -> Result<ActivatePipelineOutput, RusotoError<ActivatePipelineError>> {
+        self.runtime.block_on(self.client.activate_pipeline(input))
+    }
+
+    /// Blocking version of [`DataPipeline::add_tags`].
+    pub fn add_tags(
+        &self,
+        input: AddTagsInput,
+    ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
+        self.runtime.block_on(self.client.add_tags(input))
+    }
+
+    /// Blocking version of [`DataPipeline::create_pipeline`].
+    pub fn create_pipeline(
+        &self,
+        input: CreatePipelineInput,
+    ) -> Result<CreatePipelineOutput, RusotoError<CreatePipelineError>> {
+        self.runtime.block_on(self.client.create_pipeline(input))
+    }
+
+    /// Blocking version of [`DataPipeline::deactivate_pipeline`].
+    pub fn deactivate_pipeline(
+        &self,
+        input: DeactivatePipelineInput,
+    ) -> Result<DeactivatePipelineOutput, RusotoError<DeactivatePipelineError>> {
+        self.runtime
+            .block_on(self.client.deactivate_pipeline(input))
+    }
+
+    /// Blocking version of [`DataPipeline::delete_pipeline`].
+    pub fn delete_pipeline(
+        &self,
+        input: DeletePipelineInput,
+    ) -> Result<(), RusotoError<DeletePipelineError>> {
+        self.runtime.block_on(self.client.delete_pipeline(input))
+    }
+
+    /// Blocking version of [`DataPipeline::describe_objects`].
+    pub fn describe_objects(
+        &self,
+        input: DescribeObjectsInput,
+    ) -> Result<DescribeObjectsOutput, RusotoError<DescribeObjectsError>> {
+        self.runtime.block_on(self.client.describe_objects(input))
+    }
+
+    /// Blocking version of [`DataPipeline::describe_pipelines`].
+    pub fn describe_pipelines(
+        &self,
+        input: DescribePipelinesInput,
+    ) -> Result<DescribePipelinesOutput, RusotoError<DescribePipelinesError>> {
+        self.runtime.block_on(self.client.describe_pipelines(input))
+    }
+
+    /// Blocking version of [`DataPipeline::evaluate_expression`].
+    pub fn evaluate_expression(
+        &self,
+        input: EvaluateExpressionInput,
+    ) -> Result<EvaluateExpressionOutput, RusotoError<EvaluateExpressionError>> {
+        self.runtime
+            .block_on(self.client.evaluate_expression(input))
+    }
+
+    /// Blocking version of [`DataPipeline::get_pipeline_definition`].
+    pub fn get_pipeline_definition(
+        &self,
+        input: GetPipelineDefinitionInput,
+    ) -> Result<GetPipelineDefinitionOutput, RusotoError<GetPipelineDefinitionError>> {
+        self.runtime
+            .block_on(self.client.get_pipeline_definition(input))
+    }
+
+    /// Blocking version of [`DataPipeline::list_pipelines`].
+    pub fn list_pipelines(
+        &self,
+        input: ListPipelinesInput,
+    ) -> Result<ListPipelinesOutput, RusotoError<ListPipelinesError>> {
+        self.runtime.block_on(self.client.list_pipelines(input))
+    }
+
+    /// Blocking version of [`DataPipeline::poll_for_task`].
+    pub fn poll_for_task(
+        &self,
+        input: PollForTaskInput,
+    ) -> Result<PollForTaskOutput, RusotoError<PollForTaskError>> {
+        self.runtime.block_on(self.client.poll_for_task(input))
+    }
+
+    /// Blocking version of [`DataPipeline::put_pipeline_definition`].
+    pub fn put_pipeline_definition(
+        &self,
+        input: PutPipelineDefinitionInput,
+    ) -> Result<PutPipelineDefinitionOutput, RusotoError<PutPipelineDefinitionError>> {
+        self.runtime
+            .block_on(self.client.put_pipeline_definition(input))
+    }
+
+    /// Blocking version of [`DataPipeline::query_objects`].
+    pub fn query_objects(
+        &self,
+        input: QueryObjectsInput,
+    ) -> Result<QueryObjectsOutput, RusotoError<QueryObjectsError>> {
+        self.runtime.block_on(self.client.query_objects(input))
+    }
+
+    /// Blocking version of [`DataPipeline::remove_tags`].
+    pub fn remove_tags(
+        &self,
+        input: RemoveTagsInput,
+    ) -> Result<RemoveTagsOutput, RusotoError<RemoveTagsError>> {
+        self.runtime.block_on(self.client.remove_tags(input))
+    }
+
+    /// Blocking version of [`DataPipeline::report_task_progress`].
+    pub fn report_task_progress(
+        &self,
+        input: ReportTaskProgressInput,
+    ) -> Result<ReportTaskProgressOutput, RusotoError<ReportTaskProgressError>> {
+        self.runtime
+            .block_on(self.client.report_task_progress(input))
+    }
+
+    /// Blocking version of [`DataPipeline::report_task_runner_heartbeat`].
+    pub fn report_task_runner_heartbeat(
+        &self,
+        input: ReportTaskRunnerHeartbeatInput,
+    ) -> Result<ReportTaskRunnerHeartbeatOutput, RusotoError<ReportTaskRunnerHeartbeatError>> {
+        self.runtime
+            .block_on(self.client.report_task_runner_heartbeat(input))
+    }
+
+    /// Blocking version of [`DataPipeline::set_status`].
+    pub fn set_status(&self, input: SetStatusInput) -> Result<(), RusotoError<SetStatusError>> {
+        self.runtime.block_on(self.client.set_status(input))
+    }
+
+    /// Blocking version of [`DataPipeline::set_task_status`].
+    pub fn set_task_status(
+        &self,
+        input: SetTaskStatusInput,
+    ) -> Result<SetTaskStatusOutput, RusotoError<SetTaskStatusError>> {
+        self.runtime.block_on(self.client.set_task_status(input))
+    }
+
+    /// Blocking version of [`DataPipeline::validate_pipeline_definition`].
+    pub fn validate_pipeline_definition(
+        &self,
+        input: ValidatePipelineDefinitionInput,
+    ) -> Result<ValidatePipelineDefinitionOutput, RusotoError<ValidatePipelineDefinitionError>>
+    {
+        self.runtime
+            .block_on(self.client.validate_pipeline_definition(input))
+    }
+}
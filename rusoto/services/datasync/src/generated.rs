@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1405,12 +1406,22 @@ impl CancelTaskExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(CancelTaskExecutionError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelTaskExecutionError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CancelTaskExecutionError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelTaskExecutionError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1441,12 +1452,22 @@ impl CreateAgentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(CreateAgentError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAgentError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateAgentError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAgentError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1477,12 +1498,22 @@ impl CreateLocationEfsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(CreateLocationEfsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLocationEfsError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateLocationEfsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLocationEfsError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1513,14 +1544,22 @@ impl CreateLocationFsxWindowsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(CreateLocationFsxWindowsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLocationFsxWindowsError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateLocationFsxWindowsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateLocationFsxWindowsError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1551,12 +1590,22 @@ impl CreateLocationNfsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(CreateLocationNfsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLocationNfsError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateLocationNfsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLocationNfsError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1589,16 +1638,22 @@ impl CreateLocationObjectStorageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(CreateLocationObjectStorageError::Internal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateLocationObjectStorageError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateLocationObjectStorageError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateLocationObjectStorageError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1629,12 +1684,22 @@ impl CreateLocationS3Error {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(CreateLocationS3Error::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLocationS3Error::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateLocationS3Error::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLocationS3Error::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1665,12 +1730,22 @@ impl CreateLocationSmbError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(CreateLocationSmbError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLocationSmbError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateLocationSmbError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLocationSmbError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1701,12 +1776,22 @@ impl CreateTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(CreateTaskError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTaskError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateTaskError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTaskError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1737,12 +1822,22 @@ impl DeleteAgentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DeleteAgentError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAgentError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteAgentError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAgentError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1773,12 +1868,22 @@ impl DeleteLocationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DeleteLocationError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLocationError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteLocationError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLocationError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1809,12 +1914,22 @@ impl DeleteTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DeleteTaskError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTaskError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteTaskError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTaskError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1845,12 +1960,22 @@ impl DescribeAgentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeAgentError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAgentError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeAgentError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAgentError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1881,12 +2006,22 @@ impl DescribeLocationEfsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeLocationEfsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeLocationEfsError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeLocationEfsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeLocationEfsError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1919,14 +2054,22 @@ impl DescribeLocationFsxWindowsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeLocationFsxWindowsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeLocationFsxWindowsError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeLocationFsxWindowsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeLocationFsxWindowsError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1957,12 +2100,22 @@ impl DescribeLocationNfsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeLocationNfsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeLocationNfsError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeLocationNfsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeLocationNfsError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1995,16 +2148,22 @@ impl DescribeLocationObjectStorageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeLocationObjectStorageError::Internal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeLocationObjectStorageError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        DescribeLocationObjectStorageError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeLocationObjectStorageError::InvalidRequest,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2035,12 +2194,22 @@ impl DescribeLocationS3Error {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeLocationS3Error::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeLocationS3Error::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeLocationS3Error::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeLocationS3Error::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2071,12 +2240,22 @@ impl DescribeLocationSmbError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeLocationSmbError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeLocationSmbError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeLocationSmbError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeLocationSmbError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2107,12 +2286,22 @@ impl DescribeTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeTaskError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTaskError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeTaskError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTaskError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2143,14 +2332,22 @@ impl DescribeTaskExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeTaskExecutionError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTaskExecutionError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeTaskExecutionError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeTaskExecutionError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2181,12 +2378,22 @@ impl ListAgentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListAgentsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAgentsError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListAgentsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAgentsError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2217,12 +2424,22 @@ impl ListLocationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListLocationsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLocationsError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListLocationsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLocationsError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2253,12 +2470,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2289,12 +2516,22 @@ impl ListTaskExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListTaskExecutionsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTaskExecutionsError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTaskExecutionsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTaskExecutionsError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2325,12 +2562,22 @@ impl ListTasksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListTasksError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTasksError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTasksError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTasksError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2361,12 +2608,22 @@ impl StartTaskExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(StartTaskExecutionError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartTaskExecutionError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartTaskExecutionError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartTaskExecutionError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2397,12 +2654,22 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(TagResourceError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2433,12 +2700,22 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(UntagResourceError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2469,12 +2746,22 @@ impl UpdateAgentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(UpdateAgentError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAgentError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateAgentError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAgentError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2505,12 +2792,22 @@ impl UpdateTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(UpdateTaskError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTaskError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateTaskError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTaskError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2541,12 +2838,22 @@ impl UpdateTaskExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(UpdateTaskExecutionError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTaskExecutionError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateTaskExecutionError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTaskExecutionError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2771,11 +3078,12 @@ pub struct DataSyncClient {
 impl DataSyncClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> DataSyncClient {
         DataSyncClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "DataSync"),
         }
     }
 
@@ -2790,12 +3098,32 @@ impl DataSyncClient {
     {
         DataSyncClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "DataSync"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> DataSyncClient {
-        DataSyncClient { client, region }
+        DataSyncClient {
+            client,
+            region: region_for_service(region, "DataSync"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> DataSyncClient {
+        DataSyncClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("DataSync"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> DataSyncClient {
+        DataSyncClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -2807,6 +3135,7 @@ impl DataSync for DataSyncClient {
         input: CancelTaskExecutionRequest,
     ) -> Result<CancelTaskExecutionResponse, RusotoError<CancelTaskExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CancelTaskExecution");
         request.add_header("x-amz-target", "FmrsService.CancelTaskExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2825,6 +3154,7 @@ impl DataSync for DataSyncClient {
         input: CreateAgentRequest,
     ) -> Result<CreateAgentResponse, RusotoError<CreateAgentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateAgent");
         request.add_header("x-amz-target", "FmrsService.CreateAgent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2843,6 +3173,7 @@ impl DataSync for DataSyncClient {
         input: CreateLocationEfsRequest,
     ) -> Result<CreateLocationEfsResponse, RusotoError<CreateLocationEfsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateLocationEfs");
         request.add_header("x-amz-target", "FmrsService.CreateLocationEfs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2861,6 +3192,7 @@ impl DataSync for DataSyncClient {
         input: CreateLocationFsxWindowsRequest,
     ) -> Result<CreateLocationFsxWindowsResponse, RusotoError<CreateLocationFsxWindowsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateLocationFsxWindows");
         request.add_header("x-amz-target", "FmrsService.CreateLocationFsxWindows");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2880,6 +3212,7 @@ impl DataSync for DataSyncClient {
         input: CreateLocationNfsRequest,
     ) -> Result<CreateLocationNfsResponse, RusotoError<CreateLocationNfsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateLocationNfs");
         request.add_header("x-amz-target", "FmrsService.CreateLocationNfs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2899,6 +3232,7 @@ impl DataSync for DataSyncClient {
     ) -> Result<CreateLocationObjectStorageResponse, RusotoError<CreateLocationObjectStorageError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateLocationObjectStorage");
         request.add_header("x-amz-target", "FmrsService.CreateLocationObjectStorage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2918,6 +3252,7 @@ impl DataSync for DataSyncClient {
         input: CreateLocationS3Request,
     ) -> Result<CreateLocationS3Response, RusotoError<CreateLocationS3Error>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateLocationS3");
         request.add_header("x-amz-target", "FmrsService.CreateLocationS3");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2936,6 +3271,7 @@ impl DataSync for DataSyncClient {
         input: CreateLocationSmbRequest,
     ) -> Result<CreateLocationSmbResponse, RusotoError<CreateLocationSmbError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateLocationSmb");
         request.add_header("x-amz-target", "FmrsService.CreateLocationSmb");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2954,6 +3290,7 @@ impl DataSync for DataSyncClient {
         input: CreateTaskRequest,
     ) -> Result<CreateTaskResponse, RusotoError<CreateTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateTask");
         request.add_header("x-amz-target", "FmrsService.CreateTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2972,6 +3309,7 @@ impl DataSync for DataSyncClient {
         input: DeleteAgentRequest,
     ) -> Result<DeleteAgentResponse, RusotoError<DeleteAgentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteAgent");
         request.add_header("x-amz-target", "FmrsService.DeleteAgent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2990,6 +3328,7 @@ impl DataSync for DataSyncClient {
         input: DeleteLocationRequest,
     ) -> Result<DeleteLocationResponse, RusotoError<DeleteLocationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteLocation");
         request.add_header("x-amz-target", "FmrsService.DeleteLocation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3008,6 +3347,7 @@ impl DataSync for DataSyncClient {
         input: DeleteTaskRequest,
     ) -> Result<DeleteTaskResponse, RusotoError<DeleteTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteTask");
         request.add_header("x-amz-target", "FmrsService.DeleteTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3026,6 +3366,7 @@ impl DataSync for DataSyncClient {
         input: DescribeAgentRequest,
     ) -> Result<DescribeAgentResponse, RusotoError<DescribeAgentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeAgent");
         request.add_header("x-amz-target", "FmrsService.DescribeAgent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3044,6 +3385,7 @@ impl DataSync for DataSyncClient {
         input: DescribeLocationEfsRequest,
     ) -> Result<DescribeLocationEfsResponse, RusotoError<DescribeLocationEfsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeLocationEfs");
         request.add_header("x-amz-target", "FmrsService.DescribeLocationEfs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3063,6 +3405,7 @@ impl DataSync for DataSyncClient {
     ) -> Result<DescribeLocationFsxWindowsResponse, RusotoError<DescribeLocationFsxWindowsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeLocationFsxWindows");
         request.add_header("x-amz-target", "FmrsService.DescribeLocationFsxWindows");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3082,6 +3425,7 @@ impl DataSync for DataSyncClient {
         input: DescribeLocationNfsRequest,
     ) -> Result<DescribeLocationNfsResponse, RusotoError<DescribeLocationNfsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeLocationNfs");
         request.add_header("x-amz-target", "FmrsService.DescribeLocationNfs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3103,6 +3447,7 @@ impl DataSync for DataSyncClient {
         RusotoError<DescribeLocationObjectStorageError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeLocationObjectStorage");
         request.add_header("x-amz-target", "FmrsService.DescribeLocationObjectStorage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3122,6 +3467,7 @@ impl DataSync for DataSyncClient {
         input: DescribeLocationS3Request,
     ) -> Result<DescribeLocationS3Response, RusotoError<DescribeLocationS3Error>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeLocationS3");
         request.add_header("x-amz-target", "FmrsService.DescribeLocationS3");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3140,6 +3486,7 @@ impl DataSync for DataSyncClient {
         input: DescribeLocationSmbRequest,
     ) -> Result<DescribeLocationSmbResponse, RusotoError<DescribeLocationSmbError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeLocationSmb");
         request.add_header("x-amz-target", "FmrsService.DescribeLocationSmb");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3158,6 +3505,7 @@ impl DataSync for DataSyncClient {
         input: DescribeTaskRequest,
     ) -> Result<DescribeTaskResponse, RusotoError<DescribeTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeTask");
         request.add_header("x-amz-target", "FmrsService.DescribeTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3176,6 +3524,7 @@ impl DataSync for DataSyncClient {
         input: DescribeTaskExecutionRequest,
     ) -> Result<DescribeTaskExecutionResponse, RusotoError<DescribeTaskExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeTaskExecution");
         request.add_header("x-amz-target", "FmrsService.DescribeTaskExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3195,6 +3544,7 @@ impl DataSync for DataSyncClient {
         input: ListAgentsRequest,
     ) -> Result<ListAgentsResponse, RusotoError<ListAgentsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListAgents");
         request.add_header("x-amz-target", "FmrsService.ListAgents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3213,6 +3563,7 @@ impl DataSync for DataSyncClient {
         input: ListLocationsRequest,
     ) -> Result<ListLocationsResponse, RusotoError<ListLocationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListLocations");
         request.add_header("x-amz-target", "FmrsService.ListLocations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3231,6 +3582,7 @@ impl DataSync for DataSyncClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header("x-amz-target", "FmrsService.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3249,6 +3601,7 @@ impl DataSync for DataSyncClient {
         input: ListTaskExecutionsRequest,
     ) -> Result<ListTaskExecutionsResponse, RusotoError<ListTaskExecutionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTaskExecutions");
         request.add_header("x-amz-target", "FmrsService.ListTaskExecutions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3267,6 +3620,7 @@ impl DataSync for DataSyncClient {
         input: ListTasksRequest,
     ) -> Result<ListTasksResponse, RusotoError<ListTasksError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTasks");
         request.add_header("x-amz-target", "FmrsService.ListTasks");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3285,6 +3639,7 @@ impl DataSync for DataSyncClient {
         input: StartTaskExecutionRequest,
     ) -> Result<StartTaskExecutionResponse, RusotoError<StartTaskExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartTaskExecution");
         request.add_header("x-amz-target", "FmrsService.StartTaskExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3303,6 +3658,7 @@ impl DataSync for DataSyncClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "FmrsService.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3321,6 +3677,7 @@ impl DataSync for DataSyncClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "FmrsService.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3339,6 +3696,7 @@ impl DataSync for DataSyncClient {
         input: UpdateAgentRequest,
     ) -> Result<UpdateAgentResponse, RusotoError<UpdateAgentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateAgent");
         request.add_header("x-amz-target", "FmrsService.UpdateAgent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3357,6 +3715,7 @@ impl DataSync for DataSyncClient {
         input: UpdateTaskRequest,
     ) -> Result<UpdateTaskResponse, RusotoError<UpdateTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateTask");
         request.add_header("x-amz-target", "FmrsService.UpdateTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3375,6 +3734,7 @@ impl DataSync for DataSyncClient {
         input: UpdateTaskExecutionRequest,
     ) -> Result<UpdateTaskExecutionResponse, RusotoError<UpdateTaskExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateTaskExecution");
         request.add_header("x-amz-target", "FmrsService.UpdateTaskExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3387,3 +3747,356 @@ impl DataSync for DataSyncClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateTaskExecutionResponse, _>()
     }
 }
+
+/// A blocking client for the DataSync API, running the calls of a `DataSyncClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `DataSyncClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct DataSyncBlockingClient {
+    client: DataSyncClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl DataSyncBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> DataSyncBlockingClient {
+        DataSyncBlockingClient::from_client(DataSyncClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> DataSyncBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        DataSyncBlockingClient::from_client(DataSyncClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> DataSyncBlockingClient {
+        DataSyncBlockingClient::from_client(DataSyncClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> DataSyncBlockingClient {
+        DataSyncBlockingClient::from_client(DataSyncClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: DataSyncClient) -> DataSyncBlockingClient {
+        DataSyncBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> DataSyncBlockingClient {
+        DataSyncBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> DataSyncBlockingClient {
+        DataSyncBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`DataSync::cancel_task_execution`].
+    pub fn cancel_task_execution(
+        &self,
+        input: CancelTaskExecutionRequest,
+    ) -> Result<CancelTaskExecutionResponse, RusotoError<CancelTaskExecutionError>> {
+        self.runtime
+            .block_on(self.client.cancel_task_execution(input))
+    }
+
+    /// Blocking version of [`DataSync::create_agent`].
+    pub fn create_agent(
+        &self,
+        input: CreateAgentRequest,
+    ) -> Result<CreateAgentResponse, RusotoError<CreateAgentError>> {
+        self.runtime.block_on(self.client.create_agent(input))
+    }
+
+    /// Blocking version of [`DataSync::create_location_efs`].
+    pub fn create_location_efs(
+        &self,
+        input: CreateLocationEfsRequest,
+    ) -> Result<CreateLocationEfsResponse, RusotoError<CreateLocationEfsError>> {
+        self.runtime
+            .block_on(self.client.create_location_efs(input))
+    }
+
+    /// Blocking version of [`DataSync::create_location_fsx_windows`].
+    pub fn create_location_fsx_windows(
+        &self,
+        input: CreateLocationFsxWindowsRequest,
+    ) -> Result<CreateLocationFsxWindowsResponse, RusotoError<CreateLocationFsxWindowsError>> {
+        self.runtime
+            .block_on(self.client.create_location_fsx_windows(input))
+    }
+
+    /// Blocking version of [`DataSync::create_location_nfs`].
+    pub fn create_location_nfs(
+        &self,
+        input: CreateLocationNfsRequest,
+    ) -> Result<CreateLocationNfsResponse, RusotoError<CreateLocationNfsError>> {
+        self.runtime
+            .block_on(self.client.create_location_nfs(input))
+    }
+
+    /// Blocking version of [`DataSync::create_location_object_storage`].
+    pub fn create_location_object_storage(
+        &self,
+        input: CreateLocationObjectStorageRequest,
+    ) -> Result<CreateLocationObjectStorageResponse, RusotoError<CreateLocationObjectStorageError>>
+    {
+        self.runtime
+            .block_on(self.client.create_location_object_storage(input))
+    }
+
+    /// Blocking version of [`DataSync::create_location_s3`].
+    pub fn create_location_s3(
+        &self,
+        input: CreateLocationS3Request,
+    ) -> Result<CreateLocationS3Response, RusotoError<CreateLocationS3Error>> {
+        self.runtime.block_on(self.client.create_location_s3(input))
+    }
+
+    /// Blocking version of [`DataSync::create_location_smb`].
+    pub fn create_location_smb(
+        &self,
+        input: CreateLocationSmbRequest,
+    ) -> Result<CreateLocationSmbResponse, RusotoError<CreateLocationSmbError>> {
+        self.runtime
+            .block_on(self.client.create_location_smb(input))
+    }
+
+    /// Blocking version of [`DataSync::create_task`].
+    pub fn create_task(
+        &self,
+        input: CreateTaskRequest,
+    ) -> Result<CreateTaskResponse, RusotoError<CreateTaskError>> {
+        self.runtime.block_on(self.client.create_task(input))
+    }
+
+    /// Blocking version of [`DataSync::delete_agent`].
+    pub fn delete_agent(
+        &self,
+        input: DeleteAgentRequest,
+    ) -> Result<DeleteAgentResponse, RusotoError<DeleteAgentError>> {
+        self.runtime.block_on(self.client.delete_agent(input))
+    }
+
+    /// Blocking version of [`DataSync::delete_location`].
+    pub fn delete_location(
+        &self,
+        input: DeleteLocationRequest,
+    ) -> Result<DeleteLocationResponse, RusotoError<DeleteLocationError>> {
+        self.runtime.block_on(self.client.delete_location(input))
+    }
+
+    /// Blocking version of [`DataSync::delete_task`].
+    pub fn delete_task(
+        &self,
+        input: DeleteTaskRequest,
+    ) -> Result<DeleteTaskResponse, RusotoError<DeleteTaskError>> {
+        self.runtime.block_on(self.client.delete_task(input))
+    }
+
+    /// Blocking version of [`DataSync::describe_agent`].
+    pub fn describe_agent(
+        &self,
+        input: DescribeAgentRequest,
+    ) -> Result<DescribeAgentResponse, RusotoError<DescribeAgentError>> {
+        self.runtime.block_on(self.client.describe_agent(input))
+    }
+
+    /// Blocking version of [`DataSync::describe_location_efs`].
+    pub fn describe_location_efs(
+        &self,
+        input: DescribeLocationEfsRequest,
+    ) -> Result<DescribeLocationEfsResponse, RusotoError<DescribeLocationEfsError>> {
+        self.runtime
+            .block_on(self.client.describe_location_efs(input))
+    }
+
+    /// Blocking version of [`DataSync::describe_location_fsx_windows`].
+    pub fn describe_location_fsx_windows(
+        &self,
+        input: DescribeLocationFsxWindowsRequest,
+    ) -> Result<DescribeLocationFsxWindowsResponse, RusotoError<DescribeLocationFsxWindowsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_location_fsx_windows(input))
+    }
+
+    /// Blocking version of [`DataSync::describe_location_nfs`].
+    pub fn describe_location_nfs(
+        &self,
+        input: DescribeLocationNfsRequest,
+    ) -> Result<DescribeLocationNfsResponse, RusotoError<DescribeLocationNfsError>> {
+        self.runtime
+            .block_on(self.client.describe_location_nfs(input))
+    }
+
+    /// Blocking version of [`DataSync::describe_location_object_storage`].
+    pub fn describe_location_object_storage(
+        &self,
+        input: DescribeLocationObjectStorageRequest,
+    ) -> Result<
+        DescribeLocationObjectStorageResponse,
+        RusotoError<DescribeLocationObjectStorageError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_location_object_storage(input))
+    }
+
+    /// Blocking version of [`DataSync::describe_location_s3`].
+    pub fn describe_location_s3(
+        &self,
+        input: DescribeLocationS3Request,
+    ) -> Result<DescribeLocationS3Response, RusotoError<DescribeLocationS3Error>> {
+        self.runtime
+            .block_on(self.client.describe_location_s3(input))
+    }
+
+    /// Blocking version of [`DataSync::describe_location_smb`].
+    pub fn describe_location_smb(
+        &self,
+        input: DescribeLocationSmbRequest,
+    ) -> Result<DescribeLocationSmbResponse, RusotoError<DescribeLocationSmbError>> {
+        self.runtime
+            .block_on(self.client.describe_location_smb(input))
+    }
+
+    /// Blocking version of [`DataSync::describe_task`].
+    pub fn describe_task(
+        &self,
+        input: DescribeTaskRequest,
+    ) -> Result<DescribeTaskResponse, RusotoError<DescribeTaskError>> {
+        self.runtime.block_on(self.client.describe_task(input))
+    }
+
+    /// Blocking version of [`DataSync::describe_task_execution`].
+    pub fn describe_task_execution(
+        &self,
+        input: DescribeTaskExecutionRequest,
+    ) -> Result<DescribeTaskExecutionResponse, RusotoError<DescribeTaskExecutionError>> {
+        self.runtime
+            .block_on(self.client.describe_task_execution(input))
+    }
+
+    /// Blocking version of [`DataSync::list_agents`].
+    pub fn list_agents(
+        &self,
+        input: ListAgentsRequest,
+    ) -> Result<ListAgentsResponse, RusotoError<ListAgentsError>> {
+        self.runtime.block_on(self.client.list_agents(input))
+    }
+
+    /// Blocking version of [`DataSync::list_locations`].
+    pub fn list_locations(
+        &self,
+        input: ListLocationsRequest,
+    ) -> Result<ListLocationsResponse, RusotoError<ListLocationsError>> {
+        self.runtime.block_on(self.client.list_locations(input))
+    }
+
+    /// Blocking version of [`DataSync::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`DataSync::list_task_executions`].
+    pub fn list_task_executions(
+        &self,
+        input: ListTaskExecutionsRequest,
+    ) -> Result<ListTaskExecutionsResponse, RusotoError<ListTaskExecutionsError>> {
+        self.runtime
+            .block_on(self.client.list_task_executions(input))
+    }
+
+    /// Blocking version of [`DataSync::list_tasks`].
+    pub fn list_tasks(
+        &self,
+        input: ListTasksRequest,
+    ) -> Result<ListTasksResponse, RusotoError<ListTasksError>> {
+        self.runtime.block_on(self.client.list_tasks(input))
+    }
+
+    /// Blocking version of [`DataSync::start_task_execution`].
+    pub fn start_task_execution(
+        &self,
+        input: StartTaskExecutionRequest,
+    ) -> Result<StartTaskExecutionResponse, RusotoError<StartTaskExecutionError>> {
+        self.runtime
+            .block_on(self.client.start_task_execution(input))
+    }
+
+    /// Blocking version of [`DataSync::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`DataSync::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`DataSync::update_agent`].
+    pub fn update_agent(
+        &self,
+        input: UpdateAgentRequest,
+    ) -> Result<UpdateAgentResponse, RusotoError<UpdateAgentError>> {
+        self.runtime.block_on(self.client.update_agent(input))
+    }
+
+    /// Blocking version of [`DataSync::update_task`].
+    pub fn update_task(
+        &self,
+        input: UpdateTaskRequest,
+    ) -> Result<UpdateTaskResponse, RusotoError<UpdateTaskError>> {
+        self.runtime.block_on(self.client.update_task(input))
+    }
+
+    /// Blocking version of [`DataSync::update_task_execution`].
+    pub fn update_task_execution(
+        &self,
+        input: UpdateTaskExecutionRequest,
+    ) -> Result<UpdateTaskExecutionResponse, RusotoError<UpdateTaskExecutionError>> {
+        self.runtime
+            .block_on(self.client.update_task_execution(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1006,74 +1007,118 @@ impl CreateClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterAlreadyExistsFault" => {
-                    return RusotoError::Service(CreateClusterError::ClusterAlreadyExistsFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateClusterError::ClusterAlreadyExistsFault,
+                    )
                 }
                 "ClusterQuotaForCustomerExceededFault" => {
-                    return RusotoError::Service(
-                        CreateClusterError::ClusterQuotaForCustomerExceededFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateClusterError::ClusterQuotaForCustomerExceededFault,
                     )
                 }
                 "InsufficientClusterCapacityFault" => {
-                    return RusotoError::Service(
-                        CreateClusterError::InsufficientClusterCapacityFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateClusterError::InsufficientClusterCapacityFault,
                     )
                 }
                 "InvalidClusterStateFault" => {
-                    return RusotoError::Service(CreateClusterError::InvalidClusterStateFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateClusterError::InvalidClusterStateFault,
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(CreateClusterError::InvalidParameterCombination(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateClusterError::InvalidParameterCombination,
+                    )
                 }
                 "InvalidParameterGroupStateFault" => {
-                    return RusotoError::Service(
-                        CreateClusterError::InvalidParameterGroupStateFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateClusterError::InvalidParameterGroupStateFault,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateClusterError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateClusterError::InvalidParameterValue,
+                    )
                 }
                 "InvalidVPCNetworkStateFault" => {
-                    return RusotoError::Service(CreateClusterError::InvalidVPCNetworkStateFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateClusterError::InvalidVPCNetworkStateFault,
+                    )
                 }
                 "NodeQuotaForClusterExceededFault" => {
-                    return RusotoError::Service(
-                        CreateClusterError::NodeQuotaForClusterExceededFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateClusterError::NodeQuotaForClusterExceededFault,
                     )
                 }
                 "NodeQuotaForCustomerExceededFault" => {
-                    return RusotoError::Service(
-                        CreateClusterError::NodeQuotaForCustomerExceededFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateClusterError::NodeQuotaForCustomerExceededFault,
                     )
                 }
                 "ParameterGroupNotFoundFault" => {
-                    return RusotoError::Service(CreateClusterError::ParameterGroupNotFoundFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateClusterError::ParameterGroupNotFoundFault,
+                    )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
-                        CreateClusterError::ServiceLinkedRoleNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateClusterError::ServiceLinkedRoleNotFoundFault,
                     )
                 }
                 "SubnetGroupNotFoundFault" => {
-                    return RusotoError::Service(CreateClusterError::SubnetGroupNotFoundFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateClusterError::SubnetGroupNotFoundFault,
+                    )
                 }
                 "TagQuotaPerResourceExceeded" => {
-                    return RusotoError::Service(CreateClusterError::TagQuotaPerResourceExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateClusterError::TagQuotaPerResourceExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1134,36 +1179,54 @@ impl CreateParameterGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
-                        CreateParameterGroupError::InvalidParameterCombination(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateParameterGroupError::InvalidParameterCombination,
                     )
                 }
                 "InvalidParameterGroupStateFault" => {
-                    return RusotoError::Service(
-                        CreateParameterGroupError::InvalidParameterGroupStateFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateParameterGroupError::InvalidParameterGroupStateFault,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateParameterGroupError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateParameterGroupError::InvalidParameterValue,
+                    )
                 }
                 "ParameterGroupAlreadyExistsFault" => {
-                    return RusotoError::Service(
-                        CreateParameterGroupError::ParameterGroupAlreadyExistsFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateParameterGroupError::ParameterGroupAlreadyExistsFault,
                     )
                 }
                 "ParameterGroupQuotaExceededFault" => {
-                    return RusotoError::Service(
-                        CreateParameterGroupError::ParameterGroupQuotaExceededFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateParameterGroupError::ParameterGroupQuotaExceededFault,
                     )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
-                        CreateParameterGroupError::ServiceLinkedRoleNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateParameterGroupError::ServiceLinkedRoleNotFoundFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1214,29 +1277,46 @@ impl CreateSubnetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidSubnet" => {
-                    return RusotoError::Service(CreateSubnetGroupError::InvalidSubnet(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSubnetGroupError::InvalidSubnet,
+                    )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
-                        CreateSubnetGroupError::ServiceLinkedRoleNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSubnetGroupError::ServiceLinkedRoleNotFoundFault,
                     )
                 }
                 "SubnetGroupAlreadyExistsFault" => {
-                    return RusotoError::Service(
-                        CreateSubnetGroupError::SubnetGroupAlreadyExistsFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSubnetGroupError::SubnetGroupAlreadyExistsFault,
                     )
                 }
                 "SubnetGroupQuotaExceededFault" => {
-                    return RusotoError::Service(
-                        CreateSubnetGroupError::SubnetGroupQuotaExceededFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSubnetGroupError::SubnetGroupQuotaExceededFault,
                     )
                 }
                 "SubnetQuotaExceededFault" => {
-                    return RusotoError::Service(CreateSubnetGroupError::SubnetQuotaExceededFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSubnetGroupError::SubnetQuotaExceededFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1284,36 +1364,54 @@ impl DecreaseReplicationFactorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterNotFoundFault" => {
-                    return RusotoError::Service(
-                        DecreaseReplicationFactorError::ClusterNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DecreaseReplicationFactorError::ClusterNotFoundFault,
                     )
                 }
                 "InvalidClusterStateFault" => {
-                    return RusotoError::Service(
-                        DecreaseReplicationFactorError::InvalidClusterStateFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DecreaseReplicationFactorError::InvalidClusterStateFault,
                     )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
-                        DecreaseReplicationFactorError::InvalidParameterCombination(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DecreaseReplicationFactorError::InvalidParameterCombination,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DecreaseReplicationFactorError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DecreaseReplicationFactorError::InvalidParameterValue,
                     )
                 }
                 "NodeNotFoundFault" => {
-                    return RusotoError::Service(DecreaseReplicationFactorError::NodeNotFoundFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DecreaseReplicationFactorError::NodeNotFoundFault,
+                    )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
-                        DecreaseReplicationFactorError::ServiceLinkedRoleNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DecreaseReplicationFactorError::ServiceLinkedRoleNotFoundFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1364,27 +1462,46 @@ impl DeleteClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterNotFoundFault" => {
-                    return RusotoError::Service(DeleteClusterError::ClusterNotFoundFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteClusterError::ClusterNotFoundFault,
+                    )
                 }
                 "InvalidClusterStateFault" => {
-                    return RusotoError::Service(DeleteClusterError::InvalidClusterStateFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteClusterError::InvalidClusterStateFault,
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(DeleteClusterError::InvalidParameterCombination(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteClusterError::InvalidParameterCombination,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteClusterError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteClusterError::InvalidParameterValue,
+                    )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
-                        DeleteClusterError::ServiceLinkedRoleNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteClusterError::ServiceLinkedRoleNotFoundFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1424,31 +1541,46 @@ impl DeleteParameterGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
-                        DeleteParameterGroupError::InvalidParameterCombination(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteParameterGroupError::InvalidParameterCombination,
                     )
                 }
                 "InvalidParameterGroupStateFault" => {
-                    return RusotoError::Service(
-                        DeleteParameterGroupError::InvalidParameterGroupStateFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteParameterGroupError::InvalidParameterGroupStateFault,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteParameterGroupError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteParameterGroupError::InvalidParameterValue,
+                    )
                 }
                 "ParameterGroupNotFoundFault" => {
-                    return RusotoError::Service(
-                        DeleteParameterGroupError::ParameterGroupNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteParameterGroupError::ParameterGroupNotFoundFault,
                     )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
-                        DeleteParameterGroupError::ServiceLinkedRoleNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteParameterGroupError::ServiceLinkedRoleNotFoundFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1492,21 +1624,30 @@ impl DeleteSubnetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
-                        DeleteSubnetGroupError::ServiceLinkedRoleNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSubnetGroupError::ServiceLinkedRoleNotFoundFault,
                     )
                 }
                 "SubnetGroupInUseFault" => {
-                    return RusotoError::Service(DeleteSubnetGroupError::SubnetGroupInUseFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSubnetGroupError::SubnetGroupInUseFault,
+                    )
                 }
                 "SubnetGroupNotFoundFault" => {
-                    return RusotoError::Service(DeleteSubnetGroupError::SubnetGroupNotFoundFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSubnetGroupError::SubnetGroupNotFoundFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1544,26 +1685,38 @@ impl DescribeClustersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterNotFoundFault" => {
-                    return RusotoError::Service(DescribeClustersError::ClusterNotFoundFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeClustersError::ClusterNotFoundFault,
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
-                        DescribeClustersError::InvalidParameterCombination(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeClustersError::InvalidParameterCombination,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeClustersError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeClustersError::InvalidParameterValue,
+                    )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
-                        DescribeClustersError::ServiceLinkedRoleNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeClustersError::ServiceLinkedRoleNotFoundFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1600,21 +1753,30 @@ impl DescribeDefaultParametersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
-                        DescribeDefaultParametersError::InvalidParameterCombination(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDefaultParametersError::InvalidParameterCombination,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DescribeDefaultParametersError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDefaultParametersError::InvalidParameterValue,
                     )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
-                        DescribeDefaultParametersError::ServiceLinkedRoleNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDefaultParametersError::ServiceLinkedRoleNotFoundFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1654,21 +1816,30 @@ impl DescribeEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(DescribeEventsError::InvalidParameterCombination(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEventsError::InvalidParameterCombination,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeEventsError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEventsError::InvalidParameterValue,
+                    )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
-                        DescribeEventsError::ServiceLinkedRoleNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEventsError::ServiceLinkedRoleNotFoundFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1706,26 +1877,38 @@ impl DescribeParameterGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
-                        DescribeParameterGroupsError::InvalidParameterCombination(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeParameterGroupsError::InvalidParameterCombination,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DescribeParameterGroupsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeParameterGroupsError::InvalidParameterValue,
                     )
                 }
                 "ParameterGroupNotFoundFault" => {
-                    return RusotoError::Service(
-                        DescribeParameterGroupsError::ParameterGroupNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeParameterGroupsError::ParameterGroupNotFoundFault,
                     )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
-                        DescribeParameterGroupsError::ServiceLinkedRoleNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeParameterGroupsError::ServiceLinkedRoleNotFoundFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1770,26 +1953,38 @@ impl DescribeParametersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
-                        DescribeParametersError::InvalidParameterCombination(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeParametersError::InvalidParameterCombination,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeParametersError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeParametersError::InvalidParameterValue,
+                    )
                 }
                 "ParameterGroupNotFoundFault" => {
-                    return RusotoError::Service(
-                        DescribeParametersError::ParameterGroupNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeParametersError::ParameterGroupNotFoundFault,
                     )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
-                        DescribeParametersError::ServiceLinkedRoleNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeParametersError::ServiceLinkedRoleNotFoundFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1828,16 +2023,22 @@ impl DescribeSubnetGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
-                        DescribeSubnetGroupsError::ServiceLinkedRoleNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSubnetGroupsError::ServiceLinkedRoleNotFoundFault,
                     )
                 }
                 "SubnetGroupNotFoundFault" => {
-                    return RusotoError::Service(
-                        DescribeSubnetGroupsError::SubnetGroupNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSubnetGroupsError::SubnetGroupNotFoundFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1886,51 +2087,78 @@ impl IncreaseReplicationFactorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterNotFoundFault" => {
-                    return RusotoError::Service(
-                        IncreaseReplicationFactorError::ClusterNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        IncreaseReplicationFactorError::ClusterNotFoundFault,
                     )
                 }
                 "InsufficientClusterCapacityFault" => {
-                    return RusotoError::Service(
-                        IncreaseReplicationFactorError::InsufficientClusterCapacityFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        IncreaseReplicationFactorError::InsufficientClusterCapacityFault,
                     )
                 }
                 "InvalidClusterStateFault" => {
-                    return RusotoError::Service(
-                        IncreaseReplicationFactorError::InvalidClusterStateFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        IncreaseReplicationFactorError::InvalidClusterStateFault,
                     )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
-                        IncreaseReplicationFactorError::InvalidParameterCombination(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        IncreaseReplicationFactorError::InvalidParameterCombination,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        IncreaseReplicationFactorError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        IncreaseReplicationFactorError::InvalidParameterValue,
                     )
                 }
                 "InvalidVPCNetworkStateFault" => {
-                    return RusotoError::Service(
-                        IncreaseReplicationFactorError::InvalidVPCNetworkStateFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        IncreaseReplicationFactorError::InvalidVPCNetworkStateFault,
                     )
                 }
                 "NodeQuotaForClusterExceededFault" => {
-                    return RusotoError::Service(
-                        IncreaseReplicationFactorError::NodeQuotaForClusterExceededFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        IncreaseReplicationFactorError::NodeQuotaForClusterExceededFault,
                     )
                 }
                 "NodeQuotaForCustomerExceededFault" => {
-                    return RusotoError::Service(
-                        IncreaseReplicationFactorError::NodeQuotaForCustomerExceededFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        IncreaseReplicationFactorError::NodeQuotaForCustomerExceededFault,
                     )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
-                        IncreaseReplicationFactorError::ServiceLinkedRoleNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        IncreaseReplicationFactorError::ServiceLinkedRoleNotFoundFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1994,28 +2222,54 @@ impl ListTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterNotFoundFault" => {
-                    return RusotoError::Service(ListTagsError::ClusterNotFoundFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsError::ClusterNotFoundFault,
+                    )
                 }
                 "InvalidARNFault" => {
-                    return RusotoError::Service(ListTagsError::InvalidARNFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsError::InvalidARNFault,
+                    )
                 }
                 "InvalidClusterStateFault" => {
-                    return RusotoError::Service(ListTagsError::InvalidClusterStateFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsError::InvalidClusterStateFault,
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(ListTagsError::InvalidParameterCombination(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsError::InvalidParameterCombination,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListTagsError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsError::InvalidParameterValue,
+                    )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(ListTagsError::ServiceLinkedRoleNotFoundFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsError::ServiceLinkedRoleNotFoundFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2058,28 +2312,54 @@ impl RebootNodeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterNotFoundFault" => {
-                    return RusotoError::Service(RebootNodeError::ClusterNotFoundFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RebootNodeError::ClusterNotFoundFault,
+                    )
                 }
                 "InvalidClusterStateFault" => {
-                    return RusotoError::Service(RebootNodeError::InvalidClusterStateFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RebootNodeError::InvalidClusterStateFault,
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(RebootNodeError::InvalidParameterCombination(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RebootNodeError::InvalidParameterCombination,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(RebootNodeError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RebootNodeError::InvalidParameterValue,
+                    )
                 }
                 "NodeNotFoundFault" => {
-                    return RusotoError::Service(RebootNodeError::NodeNotFoundFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RebootNodeError::NodeNotFoundFault,
+                    )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(RebootNodeError::ServiceLinkedRoleNotFoundFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RebootNodeError::ServiceLinkedRoleNotFoundFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2124,35 +2404,62 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterNotFoundFault" => {
-                    return RusotoError::Service(TagResourceError::ClusterNotFoundFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ClusterNotFoundFault,
+                    )
                 }
                 "InvalidARNFault" => {
-                    return RusotoError::Service(TagResourceError::InvalidARNFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidARNFault,
+                    )
                 }
                 "InvalidClusterStateFault" => {
-                    return RusotoError::Service(TagResourceError::InvalidClusterStateFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TagResourceError::InvalidClusterStateFault,
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameterCombination(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TagResourceError::InvalidParameterCombination,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidParameterValue,
+                    )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(TagResourceError::ServiceLinkedRoleNotFoundFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TagResourceError::ServiceLinkedRoleNotFoundFault,
+                    )
                 }
                 "TagQuotaPerResourceExceeded" => {
-                    return RusotoError::Service(TagResourceError::TagQuotaPerResourceExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TagResourceError::TagQuotaPerResourceExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2198,33 +2505,62 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterNotFoundFault" => {
-                    return RusotoError::Service(UntagResourceError::ClusterNotFoundFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ClusterNotFoundFault,
+                    )
                 }
                 "InvalidARNFault" => {
-                    return RusotoError::Service(UntagResourceError::InvalidARNFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidARNFault,
+                    )
                 }
                 "InvalidClusterStateFault" => {
-                    return RusotoError::Service(UntagResourceError::InvalidClusterStateFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UntagResourceError::InvalidClusterStateFault,
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameterCombination(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UntagResourceError::InvalidParameterCombination,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidParameterValue,
+                    )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
-                        UntagResourceError::ServiceLinkedRoleNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ServiceLinkedRoleNotFoundFault,
                     )
                 }
                 "TagNotFoundFault" => {
-                    return RusotoError::Service(UntagResourceError::TagNotFoundFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::TagNotFoundFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2270,37 +2606,62 @@ impl UpdateClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterNotFoundFault" => {
-                    return RusotoError::Service(UpdateClusterError::ClusterNotFoundFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateClusterError::ClusterNotFoundFault,
+                    )
                 }
                 "InvalidClusterStateFault" => {
-                    return RusotoError::Service(UpdateClusterError::InvalidClusterStateFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateClusterError::InvalidClusterStateFault,
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(UpdateClusterError::InvalidParameterCombination(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateClusterError::InvalidParameterCombination,
+                    )
                 }
                 "InvalidParameterGroupStateFault" => {
-                    return RusotoError::Service(
-                        UpdateClusterError::InvalidParameterGroupStateFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateClusterError::InvalidParameterGroupStateFault,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateClusterError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateClusterError::InvalidParameterValue,
+                    )
                 }
                 "ParameterGroupNotFoundFault" => {
-                    return RusotoError::Service(UpdateClusterError::ParameterGroupNotFoundFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateClusterError::ParameterGroupNotFoundFault,
+                    )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
-                        UpdateClusterError::ServiceLinkedRoleNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateClusterError::ServiceLinkedRoleNotFoundFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2344,31 +2705,46 @@ impl UpdateParameterGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
-                        UpdateParameterGroupError::InvalidParameterCombination(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateParameterGroupError::InvalidParameterCombination,
                     )
                 }
                 "InvalidParameterGroupStateFault" => {
-                    return RusotoError::Service(
-                        UpdateParameterGroupError::InvalidParameterGroupStateFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateParameterGroupError::InvalidParameterGroupStateFault,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateParameterGroupError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateParameterGroupError::InvalidParameterValue,
+                    )
                 }
                 "ParameterGroupNotFoundFault" => {
-                    return RusotoError::Service(
-                        UpdateParameterGroupError::ParameterGroupNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateParameterGroupError::ParameterGroupNotFoundFault,
                     )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
-                        UpdateParameterGroupError::ServiceLinkedRoleNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateParameterGroupError::ServiceLinkedRoleNotFoundFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2416,27 +2792,46 @@ impl UpdateSubnetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidSubnet" => {
-                    return RusotoError::Service(UpdateSubnetGroupError::InvalidSubnet(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSubnetGroupError::InvalidSubnet,
+                    )
                 }
                 "ServiceLinkedRoleNotFoundFault" => {
-                    return RusotoError::Service(
-                        UpdateSubnetGroupError::ServiceLinkedRoleNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSubnetGroupError::ServiceLinkedRoleNotFoundFault,
                     )
                 }
                 "SubnetGroupNotFoundFault" => {
-                    return RusotoError::Service(UpdateSubnetGroupError::SubnetGroupNotFoundFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSubnetGroupError::SubnetGroupNotFoundFault,
+                    )
                 }
                 "SubnetInUse" => {
-                    return RusotoError::Service(UpdateSubnetGroupError::SubnetInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSubnetGroupError::SubnetInUse,
+                    )
                 }
                 "SubnetQuotaExceededFault" => {
-                    return RusotoError::Service(UpdateSubnetGroupError::SubnetQuotaExceededFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSubnetGroupError::SubnetQuotaExceededFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2597,11 +2992,12 @@ pub struct DynamodbAcceleratorClient {
 impl DynamodbAcceleratorClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> DynamodbAcceleratorClient {
         DynamodbAcceleratorClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "DAX"),
         }
     }
 
@@ -2616,12 +3012,32 @@ impl DynamodbAcceleratorClient {
     {
         DynamodbAcceleratorClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "DAX"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> DynamodbAcceleratorClient {
-        DynamodbAcceleratorClient { client, region }
+        DynamodbAcceleratorClient {
+            client,
+            region: region_for_service(region, "DAX"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> DynamodbAcceleratorClient {
+        DynamodbAcceleratorClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("DAX"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> DynamodbAcceleratorClient {
+        DynamodbAcceleratorClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -2633,6 +3049,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: CreateClusterRequest,
     ) -> Result<CreateClusterResponse, RusotoError<CreateClusterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateCluster");
         request.add_header("x-amz-target", "AmazonDAXV3.CreateCluster");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2651,6 +3068,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: CreateParameterGroupRequest,
     ) -> Result<CreateParameterGroupResponse, RusotoError<CreateParameterGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateParameterGroup");
         request.add_header("x-amz-target", "AmazonDAXV3.CreateParameterGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2670,6 +3088,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: CreateSubnetGroupRequest,
     ) -> Result<CreateSubnetGroupResponse, RusotoError<CreateSubnetGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateSubnetGroup");
         request.add_header("x-amz-target", "AmazonDAXV3.CreateSubnetGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2689,6 +3108,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
     ) -> Result<DecreaseReplicationFactorResponse, RusotoError<DecreaseReplicationFactorError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DecreaseReplicationFactor");
         request.add_header("x-amz-target", "AmazonDAXV3.DecreaseReplicationFactor");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2708,6 +3128,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: DeleteClusterRequest,
     ) -> Result<DeleteClusterResponse, RusotoError<DeleteClusterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteCluster");
         request.add_header("x-amz-target", "AmazonDAXV3.DeleteCluster");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2726,6 +3147,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: DeleteParameterGroupRequest,
     ) -> Result<DeleteParameterGroupResponse, RusotoError<DeleteParameterGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteParameterGroup");
         request.add_header("x-amz-target", "AmazonDAXV3.DeleteParameterGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2745,6 +3167,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: DeleteSubnetGroupRequest,
     ) -> Result<DeleteSubnetGroupResponse, RusotoError<DeleteSubnetGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteSubnetGroup");
         request.add_header("x-amz-target", "AmazonDAXV3.DeleteSubnetGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2763,6 +3186,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: DescribeClustersRequest,
     ) -> Result<DescribeClustersResponse, RusotoError<DescribeClustersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeClusters");
         request.add_header("x-amz-target", "AmazonDAXV3.DescribeClusters");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2782,6 +3206,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
     ) -> Result<DescribeDefaultParametersResponse, RusotoError<DescribeDefaultParametersError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeDefaultParameters");
         request.add_header("x-amz-target", "AmazonDAXV3.DescribeDefaultParameters");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2801,6 +3226,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: DescribeEventsRequest,
     ) -> Result<DescribeEventsResponse, RusotoError<DescribeEventsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeEvents");
         request.add_header("x-amz-target", "AmazonDAXV3.DescribeEvents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2819,6 +3245,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: DescribeParameterGroupsRequest,
     ) -> Result<DescribeParameterGroupsResponse, RusotoError<DescribeParameterGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeParameterGroups");
         request.add_header("x-amz-target", "AmazonDAXV3.DescribeParameterGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2838,6 +3265,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: DescribeParametersRequest,
     ) -> Result<DescribeParametersResponse, RusotoError<DescribeParametersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeParameters");
         request.add_header("x-amz-target", "AmazonDAXV3.DescribeParameters");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2856,6 +3284,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: DescribeSubnetGroupsRequest,
     ) -> Result<DescribeSubnetGroupsResponse, RusotoError<DescribeSubnetGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeSubnetGroups");
         request.add_header("x-amz-target", "AmazonDAXV3.DescribeSubnetGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2876,6 +3305,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
     ) -> Result<IncreaseReplicationFactorResponse, RusotoError<IncreaseReplicationFactorError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("IncreaseReplicationFactor");
         request.add_header("x-amz-target", "AmazonDAXV3.IncreaseReplicationFactor");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2895,6 +3325,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: ListTagsRequest,
     ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTags");
         request.add_header("x-amz-target", "AmazonDAXV3.ListTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2913,6 +3344,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: RebootNodeRequest,
     ) -> Result<RebootNodeResponse, RusotoError<RebootNodeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RebootNode");
         request.add_header("x-amz-target", "AmazonDAXV3.RebootNode");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2931,6 +3363,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "AmazonDAXV3.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2949,6 +3382,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "AmazonDAXV3.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2967,6 +3401,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: UpdateClusterRequest,
     ) -> Result<UpdateClusterResponse, RusotoError<UpdateClusterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateCluster");
         request.add_header("x-amz-target", "AmazonDAXV3.UpdateCluster");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2985,6 +3420,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: UpdateParameterGroupRequest,
     ) -> Result<UpdateParameterGroupResponse, RusotoError<UpdateParameterGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateParameterGroup");
         request.add_header("x-amz-target", "AmazonDAXV3.UpdateParameterGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3004,6 +3440,7 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         input: UpdateSubnetGroupRequest,
     ) -> Result<UpdateSubnetGroupResponse, RusotoError<UpdateSubnetGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateSubnetGroup");
         request.add_header("x-amz-target", "AmazonDAXV3.UpdateSubnetGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3016,3 +3453,271 @@ impl DynamodbAccelerator for DynamodbAcceleratorClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateSubnetGroupResponse, _>()
     }
 }
+
+/// A blocking client for the Amazon DAX API, running the calls of a `DynamodbAcceleratorClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `DynamodbAcceleratorClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct DynamodbAcceleratorBlockingClient {
+    client: DynamodbAcceleratorClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl DynamodbAcceleratorBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> DynamodbAcceleratorBlockingClient {
+        DynamodbAcceleratorBlockingClient::from_client(DynamodbAcceleratorClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> DynamodbAcceleratorBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        DynamodbAcceleratorBlockingClient::from_client(DynamodbAcceleratorClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> DynamodbAcceleratorBlockingClient {
+        DynamodbAcceleratorBlockingClient::from_client(DynamodbAcceleratorClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> DynamodbAcceleratorBlockingClient {
+        DynamodbAcceleratorBlockingClient::from_client(DynamodbAcceleratorClient::new_with_config(
+            config,
+        ))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: DynamodbAcceleratorClient) -> DynamodbAcceleratorBlockingClient {
+        DynamodbAcceleratorBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> DynamodbAcceleratorBlockingClient {
+        DynamodbAcceleratorBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> DynamodbAcceleratorBlockingClient {
+        DynamodbAcceleratorBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`DynamodbAccelerator::create_cluster`].
+    pub fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> Result<CreateClusterResponse, RusotoError<CreateClusterError>> {
+        self.runtime.block_on(self.client.create_cluster(input))
+    }
+
+    /// Blocking version of [`DynamodbAccelerator::create_parameter_group`].
+    pub fn create_parameter_group(
+        &self,
+        input: CreateParameterGroupRequest,
+    ) -> Result<CreateParameterGroupResponse, RusotoError<CreateParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.create_parameter_group(input))
+    }
+
+    /// Blocking version of [`DynamodbAccelerator::create_subnet_group`].
+    pub fn create_subnet_group(
+        &self,
+        input: CreateSubnetGroupRequest,
+    ) -> Result<CreateSubnetGroupResponse, RusotoError<CreateSubnetGroupError>> {
+        self.runtime
+            .block_on(self.client.create_subnet_group(input))
+    }
+
+    /// Blocking version of [`DynamodbAccelerator::decrease_replication_factor`].
+    pub fn decrease_replication_factor(
+        &self,
+        input: DecreaseReplicationFactorRequest,
+    ) -> Result<DecreaseReplicationFactorResponse, RusotoError<DecreaseReplicationFactorError>>
+    {
+        self.runtime
+            .block_on(self.client.decrease_replication_factor(input))
+    }
+
+    /// Blocking version of [`DynamodbAccelerator::delete_cluster`].
+    pub fn delete_cluster(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> Result<DeleteClusterResponse, RusotoError<DeleteClusterError>> {
+        self.runtime.block_on(self.client.delete_cluster(input))
+    }
+
+    /// Blocking version of [`DynamodbAccelerator::delete_parameter_group`].
+    pub fn delete_parameter_group(
+        &self,
+        input: DeleteParameterGroupRequest,
+    ) -> Result<DeleteParameterGroupResponse, RusotoError<DeleteParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_parameter_group(input))
+    }
+
+    /// Blocking version of [`DynamodbAccelerator::delete_subnet_group`].
+    pub fn delete_subnet_group(
+        &self,
+        input: DeleteSubnetGroupRequest,
+    ) -> Result<DeleteSubnetGroupResponse, RusotoError<DeleteSubnetGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_subnet_group(input))
+    }
+
+    /// Blocking version of [`DynamodbAccelerator::describe_clusters`].
+    pub fn describe_clusters(
+        &self,
+        input: DescribeClustersRequest,
+    ) -> Result<DescribeClustersResponse, RusotoError<DescribeClustersError>> {
+        self.runtime.block_on(self.client.describe_clusters(input))
+    }
+
+    /// Blocking version of [`DynamodbAccelerator::describe_default_parameters`].
+    pub fn describe_default_parameters(
+        &self,
+        input: DescribeDefaultParametersRequest,
+    ) -> Result<DescribeDefaultParametersResponse, RusotoError<DescribeDefaultParametersError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_default_parameters(input))
+    }
+
+    /// Blocking version of [`DynamodbAccelerator::describe_events`].
+    pub fn describe_events(
+        &self,
+        input: DescribeEventsRequest,
+    ) -> Result<DescribeEventsResponse, RusotoError<DescribeEventsError>> {
+        self.runtime.block_on(self.client.describe_events(input))
+    }
+
+    /// Blocking version of [`DynamodbAccelerator::describe_parameter_groups`].
+    pub fn describe_parameter_groups(
+        &self,
+        input: DescribeParameterGroupsRequest,
+    ) -> Result<DescribeParameterGroupsResponse, RusotoError<DescribeParameterGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_parameter_groups(input))
+    }
+
+    /// Blocking version of [`DynamodbAccelerator::describe_parameters`].
+    pub fn describe_parameters(
+        &self,
+        input: DescribeParametersRequest,
+    ) -> Result<DescribeParametersResponse, RusotoError<DescribeParametersError>> {
+        self.runtime
+            .block_on(self.client.describe_parameters(input))
+    }
+
+    /// Blocking version of [`DynamodbAccelerator::describe_subnet_groups`].
+    pub fn describe_subnet_groups(
+        &self,
+        input: DescribeSubnetGroupsRequest,
+    ) -> Result<DescribeSubnetGroupsResponse, RusotoError<DescribeSubnetGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_subnet_groups(input))
+    }
+
+    /// Blocking version of [`DynamodbAccelerator::increase_replication_factor`].
+    pub fn increase_replication_factor(
+        &self,
+        input: IncreaseReplicationFactorRequest,
+    ) -> Result<IncreaseReplicationFactorResponse, RusotoError<IncreaseReplicationFactorError>>
+    {
+        self.runtime
+            .block_on(self.client.increase_replication_factor(input))
+    }
+
+    /// Blocking version of [`DynamodbAccelerator::list_tags`].
+    pub fn list_tags(
+        &self,
+        input: ListTagsRequest,
+    ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
+        self.runtime.block_on(self.client.list_tags(input))
+    }
+
+    /// Blocking version of [`DynamodbAccelerator::reboot_node`].
+    pub fn reboot_node(
+        &self,
+        input: RebootNodeRequest,
+    ) -> Result<RebootNodeResponse, RusotoError<RebootNodeError>> {
+        self.runtime.block_on(self.client.reboot_node(input))
+    }
+
+    /// Blocking version of [`DynamodbAccelerator::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`DynamodbAccelerator::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`DynamodbAccelerator::update_cluster`].
+    pub fn update_cluster(
+        &self,
+        input: UpdateClusterRequest,
+    ) -> Result<UpdateClusterResponse, RusotoError<UpdateClusterError>> {
+        self.runtime.block_on(self.client.update_cluster(input))
+    }
+
+    /// Blocking version of [`DynamodbAccelerator::update_parameter_group`].
+    pub fn update_parameter_group(
+        &self,
+        input: UpdateParameterGroupRequest,
+    ) -> Result<UpdateParameterGroupResponse, RusotoError<UpdateParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.update_parameter_group(input))
+    }
+
+    /// Blocking version of [`DynamodbAccelerator::update_subnet_group`].
+    pub fn update_subnet_group(
+        &self,
+        input: UpdateSubnetGroupRequest,
+    ) -> Result<UpdateSubnetGroupResponse, RusotoError<UpdateSubnetGroupError>> {
+        self.runtime
+            .block_on(self.client.update_subnet_group(input))
+    }
+}
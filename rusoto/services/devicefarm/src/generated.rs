@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -3446,18 +3447,38 @@ impl CreateDevicePoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(CreateDevicePoolError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDevicePoolError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDevicePoolError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDevicePoolError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDevicePoolError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDevicePoolError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(CreateDevicePoolError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDevicePoolError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3494,20 +3515,38 @@ impl CreateInstanceProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(CreateInstanceProfileError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInstanceProfileError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateInstanceProfileError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInstanceProfileError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateInstanceProfileError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInstanceProfileError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(CreateInstanceProfileError::ServiceAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateInstanceProfileError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3544,18 +3583,38 @@ impl CreateNetworkProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(CreateNetworkProfileError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNetworkProfileError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateNetworkProfileError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNetworkProfileError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateNetworkProfileError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNetworkProfileError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(CreateNetworkProfileError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNetworkProfileError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3594,21 +3653,46 @@ impl CreateProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(CreateProjectError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProjectError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateProjectError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProjectError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateProjectError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProjectError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(CreateProjectError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProjectError::ServiceAccount,
+                    )
                 }
                 "TagOperationException" => {
-                    return RusotoError::Service(CreateProjectError::TagOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProjectError::TagOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3646,22 +3730,38 @@ impl CreateRemoteAccessSessionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(CreateRemoteAccessSessionError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRemoteAccessSessionError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateRemoteAccessSessionError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRemoteAccessSessionError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateRemoteAccessSessionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRemoteAccessSessionError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(CreateRemoteAccessSessionError::ServiceAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRemoteAccessSessionError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3692,11 +3792,14 @@ impl CreateTestGridProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateTestGridProjectError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateTestGridProjectError::InternalService,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3728,15 +3831,30 @@ impl CreateTestGridUrlError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(CreateTestGridUrlError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTestGridUrlError::Argument,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateTestGridUrlError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTestGridUrlError::InternalService,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateTestGridUrlError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTestGridUrlError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3772,18 +3890,38 @@ impl CreateUploadError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(CreateUploadError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUploadError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateUploadError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUploadError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateUploadError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUploadError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(CreateUploadError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUploadError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3818,19 +3956,30 @@ impl CreateVPCEConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(CreateVPCEConfigurationError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVPCEConfigurationError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateVPCEConfigurationError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateVPCEConfigurationError::LimitExceeded,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(CreateVPCEConfigurationError::ServiceAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateVPCEConfigurationError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3866,18 +4015,38 @@ impl DeleteDevicePoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(DeleteDevicePoolError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDevicePoolError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteDevicePoolError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDevicePoolError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDevicePoolError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDevicePoolError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(DeleteDevicePoolError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDevicePoolError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3914,20 +4083,38 @@ impl DeleteInstanceProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(DeleteInstanceProfileError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInstanceProfileError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteInstanceProfileError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInstanceProfileError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteInstanceProfileError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInstanceProfileError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(DeleteInstanceProfileError::ServiceAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteInstanceProfileError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3964,18 +4151,38 @@ impl DeleteNetworkProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(DeleteNetworkProfileError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNetworkProfileError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteNetworkProfileError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNetworkProfileError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteNetworkProfileError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNetworkProfileError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(DeleteNetworkProfileError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNetworkProfileError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4012,18 +4219,38 @@ impl DeleteProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(DeleteProjectError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProjectError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteProjectError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProjectError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteProjectError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProjectError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(DeleteProjectError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProjectError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4060,22 +4287,38 @@ impl DeleteRemoteAccessSessionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(DeleteRemoteAccessSessionError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRemoteAccessSessionError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteRemoteAccessSessionError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRemoteAccessSessionError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRemoteAccessSessionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRemoteAccessSessionError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(DeleteRemoteAccessSessionError::ServiceAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRemoteAccessSessionError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4112,18 +4355,38 @@ impl DeleteRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(DeleteRunError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRunError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteRunError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRunError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRunError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRunError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(DeleteRunError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRunError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4160,20 +4423,38 @@ impl DeleteTestGridProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(DeleteTestGridProjectError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTestGridProjectError::Argument,
+                    )
                 }
                 "CannotDeleteException" => {
-                    return RusotoError::Service(DeleteTestGridProjectError::CannotDelete(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTestGridProjectError::CannotDelete,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteTestGridProjectError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteTestGridProjectError::InternalService,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteTestGridProjectError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTestGridProjectError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4210,18 +4491,38 @@ impl DeleteUploadError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(DeleteUploadError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUploadError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteUploadError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUploadError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteUploadError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUploadError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(DeleteUploadError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUploadError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4258,22 +4559,38 @@ impl DeleteVPCEConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(DeleteVPCEConfigurationError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVPCEConfigurationError::Argument,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(DeleteVPCEConfigurationError::InvalidOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteVPCEConfigurationError::InvalidOperation,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVPCEConfigurationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVPCEConfigurationError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(DeleteVPCEConfigurationError::ServiceAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteVPCEConfigurationError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4310,18 +4627,38 @@ impl GetAccountSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetAccountSettingsError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAccountSettingsError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetAccountSettingsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAccountSettingsError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetAccountSettingsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAccountSettingsError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetAccountSettingsError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAccountSettingsError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4358,18 +4695,38 @@ impl GetDeviceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetDeviceError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeviceError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetDeviceError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeviceError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDeviceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeviceError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetDeviceError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeviceError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4406,18 +4763,38 @@ impl GetDeviceInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetDeviceInstanceError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeviceInstanceError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetDeviceInstanceError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeviceInstanceError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDeviceInstanceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeviceInstanceError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetDeviceInstanceError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeviceInstanceError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4454,18 +4831,38 @@ impl GetDevicePoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetDevicePoolError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDevicePoolError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetDevicePoolError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDevicePoolError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDevicePoolError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDevicePoolError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetDevicePoolError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDevicePoolError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4504,22 +4901,38 @@ impl GetDevicePoolCompatibilityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetDevicePoolCompatibilityError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDevicePoolCompatibilityError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetDevicePoolCompatibilityError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDevicePoolCompatibilityError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDevicePoolCompatibilityError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDevicePoolCompatibilityError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetDevicePoolCompatibilityError::ServiceAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDevicePoolCompatibilityError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4556,18 +4969,38 @@ impl GetInstanceProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetInstanceProfileError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceProfileError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetInstanceProfileError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceProfileError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetInstanceProfileError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceProfileError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetInstanceProfileError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceProfileError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4603,15 +5036,39 @@ impl GetJobError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetJobError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ArgumentException" => return RusotoError::Service(GetJobError::Argument(err.msg)),
+                "ArgumentException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobError::Argument,
+                    )
+                }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetJobError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobError::LimitExceeded,
+                    )
+                }
+                "NotFoundException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobError::NotFound,
+                    )
                 }
-                "NotFoundException" => return RusotoError::Service(GetJobError::NotFound(err.msg)),
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetJobError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4648,18 +5105,38 @@ impl GetNetworkProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetNetworkProfileError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetNetworkProfileError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetNetworkProfileError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetNetworkProfileError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetNetworkProfileError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetNetworkProfileError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetNetworkProfileError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetNetworkProfileError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4698,21 +5175,46 @@ impl GetOfferingStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetOfferingStatusError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOfferingStatusError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetOfferingStatusError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOfferingStatusError::LimitExceeded,
+                    )
                 }
                 "NotEligibleException" => {
-                    return RusotoError::Service(GetOfferingStatusError::NotEligible(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOfferingStatusError::NotEligible,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetOfferingStatusError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOfferingStatusError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetOfferingStatusError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOfferingStatusError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4750,18 +5252,38 @@ impl GetProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetProjectError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetProjectError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetProjectError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetProjectError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetProjectError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetProjectError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetProjectError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetProjectError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4798,22 +5320,38 @@ impl GetRemoteAccessSessionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetRemoteAccessSessionError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRemoteAccessSessionError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetRemoteAccessSessionError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRemoteAccessSessionError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRemoteAccessSessionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRemoteAccessSessionError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetRemoteAccessSessionError::ServiceAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRemoteAccessSessionError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4849,15 +5387,39 @@ impl GetRunError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetRunError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ArgumentException" => return RusotoError::Service(GetRunError::Argument(err.msg)),
+                "ArgumentException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRunError::Argument,
+                    )
+                }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetRunError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRunError::LimitExceeded,
+                    )
+                }
+                "NotFoundException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRunError::NotFound,
+                    )
                 }
-                "NotFoundException" => return RusotoError::Service(GetRunError::NotFound(err.msg)),
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetRunError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRunError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4894,18 +5456,38 @@ impl GetSuiteError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetSuiteError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSuiteError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetSuiteError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSuiteError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetSuiteError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSuiteError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetSuiteError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSuiteError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4942,18 +5524,38 @@ impl GetTestError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetTestError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTestError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetTestError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTestError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetTestError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTestError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetTestError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTestError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4988,15 +5590,30 @@ impl GetTestGridProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetTestGridProjectError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTestGridProjectError::Argument,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetTestGridProjectError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTestGridProjectError::InternalService,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetTestGridProjectError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTestGridProjectError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5030,15 +5647,30 @@ impl GetTestGridSessionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetTestGridSessionError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTestGridSessionError::Argument,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetTestGridSessionError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTestGridSessionError::InternalService,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetTestGridSessionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTestGridSessionError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5074,18 +5706,38 @@ impl GetUploadError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetUploadError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUploadError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetUploadError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUploadError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetUploadError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUploadError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetUploadError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUploadError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5120,15 +5772,30 @@ impl GetVPCEConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(GetVPCEConfigurationError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVPCEConfigurationError::Argument,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetVPCEConfigurationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVPCEConfigurationError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(GetVPCEConfigurationError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVPCEConfigurationError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5166,26 +5833,38 @@ impl InstallToRemoteAccessSessionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(InstallToRemoteAccessSessionError::Argument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        InstallToRemoteAccessSessionError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(InstallToRemoteAccessSessionError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        InstallToRemoteAccessSessionError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(InstallToRemoteAccessSessionError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        InstallToRemoteAccessSessionError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(InstallToRemoteAccessSessionError::ServiceAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        InstallToRemoteAccessSessionError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5222,18 +5901,38 @@ impl ListArtifactsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListArtifactsError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListArtifactsError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListArtifactsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListArtifactsError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListArtifactsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListArtifactsError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListArtifactsError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListArtifactsError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5270,18 +5969,38 @@ impl ListDeviceInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListDeviceInstancesError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDeviceInstancesError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListDeviceInstancesError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDeviceInstancesError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListDeviceInstancesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDeviceInstancesError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListDeviceInstancesError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDeviceInstancesError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5318,18 +6037,38 @@ impl ListDevicePoolsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListDevicePoolsError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDevicePoolsError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListDevicePoolsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDevicePoolsError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListDevicePoolsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDevicePoolsError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListDevicePoolsError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDevicePoolsError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5366,18 +6105,38 @@ impl ListDevicesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListDevicesError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDevicesError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListDevicesError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDevicesError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListDevicesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDevicesError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListDevicesError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDevicesError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5414,18 +6173,38 @@ impl ListInstanceProfilesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListInstanceProfilesError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInstanceProfilesError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListInstanceProfilesError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInstanceProfilesError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListInstanceProfilesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInstanceProfilesError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListInstanceProfilesError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInstanceProfilesError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5462,18 +6241,38 @@ impl ListJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListJobsError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListJobsError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListJobsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListJobsError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListJobsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListJobsError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListJobsError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListJobsError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5510,18 +6309,38 @@ impl ListNetworkProfilesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListNetworkProfilesError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListNetworkProfilesError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListNetworkProfilesError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListNetworkProfilesError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListNetworkProfilesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListNetworkProfilesError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListNetworkProfilesError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListNetworkProfilesError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5560,25 +6379,46 @@ impl ListOfferingPromotionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListOfferingPromotionsError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOfferingPromotionsError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListOfferingPromotionsError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListOfferingPromotionsError::LimitExceeded,
+                    )
                 }
                 "NotEligibleException" => {
-                    return RusotoError::Service(ListOfferingPromotionsError::NotEligible(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOfferingPromotionsError::NotEligible,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListOfferingPromotionsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOfferingPromotionsError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListOfferingPromotionsError::ServiceAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListOfferingPromotionsError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5618,27 +6458,46 @@ impl ListOfferingTransactionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListOfferingTransactionsError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOfferingTransactionsError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListOfferingTransactionsError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListOfferingTransactionsError::LimitExceeded,
+                    )
                 }
                 "NotEligibleException" => {
-                    return RusotoError::Service(ListOfferingTransactionsError::NotEligible(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListOfferingTransactionsError::NotEligible,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListOfferingTransactionsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOfferingTransactionsError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListOfferingTransactionsError::ServiceAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListOfferingTransactionsError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5678,21 +6537,46 @@ impl ListOfferingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListOfferingsError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOfferingsError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListOfferingsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOfferingsError::LimitExceeded,
+                    )
                 }
                 "NotEligibleException" => {
-                    return RusotoError::Service(ListOfferingsError::NotEligible(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOfferingsError::NotEligible,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListOfferingsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOfferingsError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListOfferingsError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOfferingsError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5730,18 +6614,38 @@ impl ListProjectsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListProjectsError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProjectsError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListProjectsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProjectsError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListProjectsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProjectsError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListProjectsError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProjectsError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5778,22 +6682,38 @@ impl ListRemoteAccessSessionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListRemoteAccessSessionsError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRemoteAccessSessionsError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListRemoteAccessSessionsError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListRemoteAccessSessionsError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListRemoteAccessSessionsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRemoteAccessSessionsError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListRemoteAccessSessionsError::ServiceAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListRemoteAccessSessionsError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5830,18 +6750,38 @@ impl ListRunsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListRunsError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRunsError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListRunsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRunsError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListRunsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRunsError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListRunsError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRunsError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5878,18 +6818,38 @@ impl ListSamplesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListSamplesError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSamplesError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListSamplesError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSamplesError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListSamplesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSamplesError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListSamplesError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSamplesError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5926,18 +6886,38 @@ impl ListSuitesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListSuitesError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSuitesError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListSuitesError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSuitesError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListSuitesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSuitesError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListSuitesError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSuitesError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5972,15 +6952,30 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::Argument,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::NotFound,
+                    )
                 }
                 "TagOperationException" => {
-                    return RusotoError::Service(ListTagsForResourceError::TagOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::TagOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6012,14 +7007,22 @@ impl ListTestGridProjectsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListTestGridProjectsError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTestGridProjectsError::Argument,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListTestGridProjectsError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTestGridProjectsError::InternalService,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6054,17 +7057,30 @@ impl ListTestGridSessionActionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListTestGridSessionActionsError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTestGridSessionActionsError::Argument,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListTestGridSessionActionsError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTestGridSessionActionsError::InternalService,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTestGridSessionActionsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTestGridSessionActionsError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6100,21 +7116,30 @@ impl ListTestGridSessionArtifactsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListTestGridSessionArtifactsError::Argument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTestGridSessionArtifactsError::Argument,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(
-                        ListTestGridSessionArtifactsError::InternalService(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTestGridSessionArtifactsError::InternalService,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTestGridSessionArtifactsError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTestGridSessionArtifactsError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6148,17 +7173,30 @@ impl ListTestGridSessionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListTestGridSessionsError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTestGridSessionsError::Argument,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListTestGridSessionsError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTestGridSessionsError::InternalService,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTestGridSessionsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTestGridSessionsError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6194,18 +7232,38 @@ impl ListTestsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListTestsError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTestsError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListTestsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTestsError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTestsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTestsError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListTestsError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTestsError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6242,18 +7300,38 @@ impl ListUniqueProblemsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListUniqueProblemsError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUniqueProblemsError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListUniqueProblemsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUniqueProblemsError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListUniqueProblemsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUniqueProblemsError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListUniqueProblemsError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUniqueProblemsError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6290,18 +7368,38 @@ impl ListUploadsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListUploadsError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUploadsError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListUploadsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUploadsError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListUploadsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUploadsError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListUploadsError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUploadsError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6334,14 +7432,22 @@ impl ListVPCEConfigurationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ListVPCEConfigurationsError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVPCEConfigurationsError::Argument,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ListVPCEConfigurationsError::ServiceAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListVPCEConfigurationsError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6378,21 +7484,46 @@ impl PurchaseOfferingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(PurchaseOfferingError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PurchaseOfferingError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PurchaseOfferingError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PurchaseOfferingError::LimitExceeded,
+                    )
                 }
                 "NotEligibleException" => {
-                    return RusotoError::Service(PurchaseOfferingError::NotEligible(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PurchaseOfferingError::NotEligible,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PurchaseOfferingError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PurchaseOfferingError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(PurchaseOfferingError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PurchaseOfferingError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6432,21 +7563,46 @@ impl RenewOfferingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(RenewOfferingError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RenewOfferingError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RenewOfferingError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RenewOfferingError::LimitExceeded,
+                    )
                 }
                 "NotEligibleException" => {
-                    return RusotoError::Service(RenewOfferingError::NotEligible(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RenewOfferingError::NotEligible,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RenewOfferingError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RenewOfferingError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(RenewOfferingError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RenewOfferingError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6486,21 +7642,46 @@ impl ScheduleRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(ScheduleRunError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ScheduleRunError::Argument,
+                    )
                 }
                 "IdempotencyException" => {
-                    return RusotoError::Service(ScheduleRunError::Idempotency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ScheduleRunError::Idempotency,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ScheduleRunError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ScheduleRunError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ScheduleRunError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ScheduleRunError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(ScheduleRunError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ScheduleRunError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6538,18 +7719,38 @@ impl StopJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(StopJobError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopJobError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StopJobError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopJobError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StopJobError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopJobError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(StopJobError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopJobError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6586,22 +7787,38 @@ impl StopRemoteAccessSessionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(StopRemoteAccessSessionError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopRemoteAccessSessionError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StopRemoteAccessSessionError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopRemoteAccessSessionError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StopRemoteAccessSessionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopRemoteAccessSessionError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(StopRemoteAccessSessionError::ServiceAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopRemoteAccessSessionError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6638,18 +7855,38 @@ impl StopRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(StopRunError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopRunError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StopRunError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopRunError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StopRunError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopRunError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(StopRunError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopRunError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6688,21 +7925,46 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(TagResourceError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::Argument,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::NotFound,
+                    )
                 }
                 "TagOperationException" => {
-                    return RusotoError::Service(TagResourceError::TagOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::TagOperation,
+                    )
                 }
                 "TagPolicyException" => {
-                    return RusotoError::Service(TagResourceError::TagPolicy(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::TagPolicy,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6738,15 +8000,30 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(UntagResourceError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::Argument,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::NotFound,
+                    )
                 }
                 "TagOperationException" => {
-                    return RusotoError::Service(UntagResourceError::TagOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::TagOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6782,18 +8059,38 @@ impl UpdateDeviceInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(UpdateDeviceInstanceError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeviceInstanceError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateDeviceInstanceError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeviceInstanceError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateDeviceInstanceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeviceInstanceError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(UpdateDeviceInstanceError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeviceInstanceError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6830,18 +8127,38 @@ impl UpdateDevicePoolError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(UpdateDevicePoolError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDevicePoolError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateDevicePoolError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDevicePoolError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateDevicePoolError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDevicePoolError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(UpdateDevicePoolError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDevicePoolError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6878,20 +8195,38 @@ impl UpdateInstanceProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(UpdateInstanceProfileError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateInstanceProfileError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateInstanceProfileError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateInstanceProfileError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateInstanceProfileError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateInstanceProfileError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(UpdateInstanceProfileError::ServiceAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateInstanceProfileError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6928,18 +8263,38 @@ impl UpdateNetworkProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(UpdateNetworkProfileError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateNetworkProfileError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateNetworkProfileError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateNetworkProfileError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateNetworkProfileError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateNetworkProfileError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(UpdateNetworkProfileError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateNetworkProfileError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6976,18 +8331,38 @@ impl UpdateProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(UpdateProjectError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateProjectError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateProjectError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateProjectError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateProjectError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateProjectError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(UpdateProjectError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateProjectError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7022,17 +8397,30 @@ impl UpdateTestGridProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(UpdateTestGridProjectError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTestGridProjectError::Argument,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateTestGridProjectError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateTestGridProjectError::InternalService,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateTestGridProjectError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTestGridProjectError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7068,18 +8456,38 @@ impl UpdateUploadError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(UpdateUploadError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUploadError::Argument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateUploadError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUploadError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateUploadError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUploadError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(UpdateUploadError::ServiceAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUploadError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7116,22 +8524,38 @@ impl UpdateVPCEConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ArgumentException" => {
-                    return RusotoError::Service(UpdateVPCEConfigurationError::Argument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVPCEConfigurationError::Argument,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(UpdateVPCEConfigurationError::InvalidOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateVPCEConfigurationError::InvalidOperation,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateVPCEConfigurationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVPCEConfigurationError::NotFound,
+                    )
                 }
                 "ServiceAccountException" => {
-                    return RusotoError::Service(UpdateVPCEConfigurationError::ServiceAccount(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateVPCEConfigurationError::ServiceAccount,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7620,11 +9044,12 @@ pub struct DeviceFarmClient {
 impl DeviceFarmClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> DeviceFarmClient {
         DeviceFarmClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Device Farm"),
         }
     }
 
@@ -7639,12 +9064,32 @@ impl DeviceFarmClient {
     {
         DeviceFarmClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Device Farm"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> DeviceFarmClient {
-        DeviceFarmClient { client, region }
+        DeviceFarmClient {
+            client,
+            region: region_for_service(region, "Device Farm"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> DeviceFarmClient {
+        DeviceFarmClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Device Farm"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> DeviceFarmClient {
+        DeviceFarmClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -7656,6 +9101,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: CreateDevicePoolRequest,
     ) -> Result<CreateDevicePoolResult, RusotoError<CreateDevicePoolError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateDevicePool");
         request.add_header("x-amz-target", "DeviceFarm_20150623.CreateDevicePool");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7674,6 +9120,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: CreateInstanceProfileRequest,
     ) -> Result<CreateInstanceProfileResult, RusotoError<CreateInstanceProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateInstanceProfile");
         request.add_header("x-amz-target", "DeviceFarm_20150623.CreateInstanceProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7692,6 +9139,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: CreateNetworkProfileRequest,
     ) -> Result<CreateNetworkProfileResult, RusotoError<CreateNetworkProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateNetworkProfile");
         request.add_header("x-amz-target", "DeviceFarm_20150623.CreateNetworkProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7710,6 +9158,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: CreateProjectRequest,
     ) -> Result<CreateProjectResult, RusotoError<CreateProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateProject");
         request.add_header("x-amz-target", "DeviceFarm_20150623.CreateProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7728,6 +9177,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: CreateRemoteAccessSessionRequest,
     ) -> Result<CreateRemoteAccessSessionResult, RusotoError<CreateRemoteAccessSessionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateRemoteAccessSession");
         request.add_header(
             "x-amz-target",
             "DeviceFarm_20150623.CreateRemoteAccessSession",
@@ -7750,6 +9200,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: CreateTestGridProjectRequest,
     ) -> Result<CreateTestGridProjectResult, RusotoError<CreateTestGridProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateTestGridProject");
         request.add_header("x-amz-target", "DeviceFarm_20150623.CreateTestGridProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7768,6 +9219,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: CreateTestGridUrlRequest,
     ) -> Result<CreateTestGridUrlResult, RusotoError<CreateTestGridUrlError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateTestGridUrl");
         request.add_header("x-amz-target", "DeviceFarm_20150623.CreateTestGridUrl");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7786,6 +9238,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: CreateUploadRequest,
     ) -> Result<CreateUploadResult, RusotoError<CreateUploadError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateUpload");
         request.add_header("x-amz-target", "DeviceFarm_20150623.CreateUpload");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7804,6 +9257,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: CreateVPCEConfigurationRequest,
     ) -> Result<CreateVPCEConfigurationResult, RusotoError<CreateVPCEConfigurationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateVPCEConfiguration");
         request.add_header(
             "x-amz-target",
             "DeviceFarm_20150623.CreateVPCEConfiguration",
@@ -7826,6 +9280,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: DeleteDevicePoolRequest,
     ) -> Result<DeleteDevicePoolResult, RusotoError<DeleteDevicePoolError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteDevicePool");
         request.add_header("x-amz-target", "DeviceFarm_20150623.DeleteDevicePool");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7844,6 +9299,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: DeleteInstanceProfileRequest,
     ) -> Result<DeleteInstanceProfileResult, RusotoError<DeleteInstanceProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteInstanceProfile");
         request.add_header("x-amz-target", "DeviceFarm_20150623.DeleteInstanceProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7862,6 +9318,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: DeleteNetworkProfileRequest,
     ) -> Result<DeleteNetworkProfileResult, RusotoError<DeleteNetworkProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteNetworkProfile");
         request.add_header("x-amz-target", "DeviceFarm_20150623.DeleteNetworkProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7880,6 +9337,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: DeleteProjectRequest,
     ) -> Result<DeleteProjectResult, RusotoError<DeleteProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteProject");
         request.add_header("x-amz-target", "DeviceFarm_20150623.DeleteProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7898,6 +9356,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: DeleteRemoteAccessSessionRequest,
     ) -> Result<DeleteRemoteAccessSessionResult, RusotoError<DeleteRemoteAccessSessionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteRemoteAccessSession");
         request.add_header(
             "x-amz-target",
             "DeviceFarm_20150623.DeleteRemoteAccessSession",
@@ -7920,6 +9379,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: DeleteRunRequest,
     ) -> Result<DeleteRunResult, RusotoError<DeleteRunError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteRun");
         request.add_header("x-amz-target", "DeviceFarm_20150623.DeleteRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7938,6 +9398,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: DeleteTestGridProjectRequest,
     ) -> Result<DeleteTestGridProjectResult, RusotoError<DeleteTestGridProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteTestGridProject");
         request.add_header("x-amz-target", "DeviceFarm_20150623.DeleteTestGridProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7956,6 +9417,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: DeleteUploadRequest,
     ) -> Result<DeleteUploadResult, RusotoError<DeleteUploadError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteUpload");
         request.add_header("x-amz-target", "DeviceFarm_20150623.DeleteUpload");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7974,6 +9436,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: DeleteVPCEConfigurationRequest,
     ) -> Result<DeleteVPCEConfigurationResult, RusotoError<DeleteVPCEConfigurationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteVPCEConfiguration");
         request.add_header(
             "x-amz-target",
             "DeviceFarm_20150623.DeleteVPCEConfiguration",
@@ -7995,6 +9458,7 @@ impl DeviceFarm for DeviceFarmClient {
         &self,
     ) -> Result<GetAccountSettingsResult, RusotoError<GetAccountSettingsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetAccountSettings");
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetAccountSettings");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -8012,6 +9476,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetDeviceRequest,
     ) -> Result<GetDeviceResult, RusotoError<GetDeviceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDevice");
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetDevice");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8030,6 +9495,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetDeviceInstanceRequest,
     ) -> Result<GetDeviceInstanceResult, RusotoError<GetDeviceInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDeviceInstance");
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetDeviceInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8048,6 +9514,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetDevicePoolRequest,
     ) -> Result<GetDevicePoolResult, RusotoError<GetDevicePoolError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDevicePool");
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetDevicePool");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8067,6 +9534,7 @@ impl DeviceFarm for DeviceFarmClient {
     ) -> Result<GetDevicePoolCompatibilityResult, RusotoError<GetDevicePoolCompatibilityError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDevicePoolCompatibility");
         request.add_header(
             "x-amz-target",
             "DeviceFarm_20150623.GetDevicePoolCompatibility",
@@ -8089,6 +9557,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetInstanceProfileRequest,
     ) -> Result<GetInstanceProfileResult, RusotoError<GetInstanceProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetInstanceProfile");
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetInstanceProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8107,6 +9576,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetJobRequest,
     ) -> Result<GetJobResult, RusotoError<GetJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetJob");
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8125,6 +9595,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetNetworkProfileRequest,
     ) -> Result<GetNetworkProfileResult, RusotoError<GetNetworkProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetNetworkProfile");
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetNetworkProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8143,6 +9614,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetOfferingStatusRequest,
     ) -> Result<GetOfferingStatusResult, RusotoError<GetOfferingStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetOfferingStatus");
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetOfferingStatus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8161,6 +9633,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetProjectRequest,
     ) -> Result<GetProjectResult, RusotoError<GetProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetProject");
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8179,6 +9652,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetRemoteAccessSessionRequest,
     ) -> Result<GetRemoteAccessSessionResult, RusotoError<GetRemoteAccessSessionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRemoteAccessSession");
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetRemoteAccessSession");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8198,6 +9672,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetRunRequest,
     ) -> Result<GetRunResult, RusotoError<GetRunError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRun");
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8216,6 +9691,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetSuiteRequest,
     ) -> Result<GetSuiteResult, RusotoError<GetSuiteError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetSuite");
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetSuite");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8234,6 +9710,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetTestRequest,
     ) -> Result<GetTestResult, RusotoError<GetTestError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetTest");
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetTest");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8252,6 +9729,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetTestGridProjectRequest,
     ) -> Result<GetTestGridProjectResult, RusotoError<GetTestGridProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetTestGridProject");
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetTestGridProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8270,6 +9748,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetTestGridSessionRequest,
     ) -> Result<GetTestGridSessionResult, RusotoError<GetTestGridSessionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetTestGridSession");
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetTestGridSession");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8288,6 +9767,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetUploadRequest,
     ) -> Result<GetUploadResult, RusotoError<GetUploadError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetUpload");
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetUpload");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8306,6 +9786,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: GetVPCEConfigurationRequest,
     ) -> Result<GetVPCEConfigurationResult, RusotoError<GetVPCEConfigurationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetVPCEConfiguration");
         request.add_header("x-amz-target", "DeviceFarm_20150623.GetVPCEConfiguration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8325,6 +9806,7 @@ impl DeviceFarm for DeviceFarmClient {
     ) -> Result<InstallToRemoteAccessSessionResult, RusotoError<InstallToRemoteAccessSessionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("InstallToRemoteAccessSession");
         request.add_header(
             "x-amz-target",
             "DeviceFarm_20150623.InstallToRemoteAccessSession",
@@ -8347,6 +9829,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListArtifactsRequest,
     ) -> Result<ListArtifactsResult, RusotoError<ListArtifactsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListArtifacts");
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListArtifacts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8365,6 +9848,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListDeviceInstancesRequest,
     ) -> Result<ListDeviceInstancesResult, RusotoError<ListDeviceInstancesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListDeviceInstances");
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListDeviceInstances");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8383,6 +9867,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListDevicePoolsRequest,
     ) -> Result<ListDevicePoolsResult, RusotoError<ListDevicePoolsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListDevicePools");
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListDevicePools");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8401,6 +9886,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListDevicesRequest,
     ) -> Result<ListDevicesResult, RusotoError<ListDevicesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListDevices");
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListDevices");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8419,6 +9905,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListInstanceProfilesRequest,
     ) -> Result<ListInstanceProfilesResult, RusotoError<ListInstanceProfilesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListInstanceProfiles");
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListInstanceProfiles");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8437,6 +9924,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListJobsRequest,
     ) -> Result<ListJobsResult, RusotoError<ListJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListJobs");
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8455,6 +9943,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListNetworkProfilesRequest,
     ) -> Result<ListNetworkProfilesResult, RusotoError<ListNetworkProfilesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListNetworkProfiles");
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListNetworkProfiles");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8473,6 +9962,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListOfferingPromotionsRequest,
     ) -> Result<ListOfferingPromotionsResult, RusotoError<ListOfferingPromotionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListOfferingPromotions");
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListOfferingPromotions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8492,6 +9982,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListOfferingTransactionsRequest,
     ) -> Result<ListOfferingTransactionsResult, RusotoError<ListOfferingTransactionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListOfferingTransactions");
         request.add_header(
             "x-amz-target",
             "DeviceFarm_20150623.ListOfferingTransactions",
@@ -8514,6 +10005,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListOfferingsRequest,
     ) -> Result<ListOfferingsResult, RusotoError<ListOfferingsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListOfferings");
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListOfferings");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8532,6 +10024,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListProjectsRequest,
     ) -> Result<ListProjectsResult, RusotoError<ListProjectsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListProjects");
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListProjects");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8550,6 +10043,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListRemoteAccessSessionsRequest,
     ) -> Result<ListRemoteAccessSessionsResult, RusotoError<ListRemoteAccessSessionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListRemoteAccessSessions");
         request.add_header(
             "x-amz-target",
             "DeviceFarm_20150623.ListRemoteAccessSessions",
@@ -8572,6 +10066,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListRunsRequest,
     ) -> Result<ListRunsResult, RusotoError<ListRunsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListRuns");
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListRuns");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8590,6 +10085,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListSamplesRequest,
     ) -> Result<ListSamplesResult, RusotoError<ListSamplesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListSamples");
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListSamples");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8608,6 +10104,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListSuitesRequest,
     ) -> Result<ListSuitesResult, RusotoError<ListSuitesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListSuites");
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListSuites");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8626,6 +10123,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8644,6 +10142,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListTestGridProjectsRequest,
     ) -> Result<ListTestGridProjectsResult, RusotoError<ListTestGridProjectsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTestGridProjects");
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListTestGridProjects");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8663,6 +10162,7 @@ impl DeviceFarm for DeviceFarmClient {
     ) -> Result<ListTestGridSessionActionsResult, RusotoError<ListTestGridSessionActionsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTestGridSessionActions");
         request.add_header(
             "x-amz-target",
             "DeviceFarm_20150623.ListTestGridSessionActions",
@@ -8686,6 +10186,7 @@ impl DeviceFarm for DeviceFarmClient {
     ) -> Result<ListTestGridSessionArtifactsResult, RusotoError<ListTestGridSessionArtifactsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTestGridSessionArtifacts");
         request.add_header(
             "x-amz-target",
             "DeviceFarm_20150623.ListTestGridSessionArtifacts",
@@ -8708,6 +10209,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListTestGridSessionsRequest,
     ) -> Result<ListTestGridSessionsResult, RusotoError<ListTestGridSessionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTestGridSessions");
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListTestGridSessions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8726,6 +10228,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListTestsRequest,
     ) -> Result<ListTestsResult, RusotoError<ListTestsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTests");
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListTests");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8744,6 +10247,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListUniqueProblemsRequest,
     ) -> Result<ListUniqueProblemsResult, RusotoError<ListUniqueProblemsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListUniqueProblems");
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListUniqueProblems");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8762,6 +10266,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListUploadsRequest,
     ) -> Result<ListUploadsResult, RusotoError<ListUploadsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListUploads");
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListUploads");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8780,6 +10285,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ListVPCEConfigurationsRequest,
     ) -> Result<ListVPCEConfigurationsResult, RusotoError<ListVPCEConfigurationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListVPCEConfigurations");
         request.add_header("x-amz-target", "DeviceFarm_20150623.ListVPCEConfigurations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8799,6 +10305,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: PurchaseOfferingRequest,
     ) -> Result<PurchaseOfferingResult, RusotoError<PurchaseOfferingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PurchaseOffering");
         request.add_header("x-amz-target", "DeviceFarm_20150623.PurchaseOffering");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8817,6 +10324,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: RenewOfferingRequest,
     ) -> Result<RenewOfferingResult, RusotoError<RenewOfferingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RenewOffering");
         request.add_header("x-amz-target", "DeviceFarm_20150623.RenewOffering");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8835,6 +10343,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: ScheduleRunRequest,
     ) -> Result<ScheduleRunResult, RusotoError<ScheduleRunError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ScheduleRun");
         request.add_header("x-amz-target", "DeviceFarm_20150623.ScheduleRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8853,6 +10362,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: StopJobRequest,
     ) -> Result<StopJobResult, RusotoError<StopJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopJob");
         request.add_header("x-amz-target", "DeviceFarm_20150623.StopJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8871,6 +10381,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: StopRemoteAccessSessionRequest,
     ) -> Result<StopRemoteAccessSessionResult, RusotoError<StopRemoteAccessSessionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopRemoteAccessSession");
         request.add_header(
             "x-amz-target",
             "DeviceFarm_20150623.StopRemoteAccessSession",
@@ -8893,6 +10404,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: StopRunRequest,
     ) -> Result<StopRunResult, RusotoError<StopRunError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopRun");
         request.add_header("x-amz-target", "DeviceFarm_20150623.StopRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8911,6 +10423,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "DeviceFarm_20150623.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8929,6 +10442,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "DeviceFarm_20150623.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8947,6 +10461,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: UpdateDeviceInstanceRequest,
     ) -> Result<UpdateDeviceInstanceResult, RusotoError<UpdateDeviceInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateDeviceInstance");
         request.add_header("x-amz-target", "DeviceFarm_20150623.UpdateDeviceInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8965,6 +10480,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: UpdateDevicePoolRequest,
     ) -> Result<UpdateDevicePoolResult, RusotoError<UpdateDevicePoolError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateDevicePool");
         request.add_header("x-amz-target", "DeviceFarm_20150623.UpdateDevicePool");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8983,6 +10499,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: UpdateInstanceProfileRequest,
     ) -> Result<UpdateInstanceProfileResult, RusotoError<UpdateInstanceProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateInstanceProfile");
         request.add_header("x-amz-target", "DeviceFarm_20150623.UpdateInstanceProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9001,6 +10518,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: UpdateNetworkProfileRequest,
     ) -> Result<UpdateNetworkProfileResult, RusotoError<UpdateNetworkProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateNetworkProfile");
         request.add_header("x-amz-target", "DeviceFarm_20150623.UpdateNetworkProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9019,6 +10537,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: UpdateProjectRequest,
     ) -> Result<UpdateProjectResult, RusotoError<UpdateProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateProject");
         request.add_header("x-amz-target", "DeviceFarm_20150623.UpdateProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9037,6 +10556,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: UpdateTestGridProjectRequest,
     ) -> Result<UpdateTestGridProjectResult, RusotoError<UpdateTestGridProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateTestGridProject");
         request.add_header("x-amz-target", "DeviceFarm_20150623.UpdateTestGridProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9055,6 +10575,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: UpdateUploadRequest,
     ) -> Result<UpdateUploadResult, RusotoError<UpdateUploadError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateUpload");
         request.add_header("x-amz-target", "DeviceFarm_20150623.UpdateUpload");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9073,6 +10594,7 @@ impl DeviceFarm for DeviceFarmClient {
         input: UpdateVPCEConfigurationRequest,
     ) -> Result<UpdateVPCEConfigurationResult, RusotoError<UpdateVPCEConfigurationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateVPCEConfiguration");
         request.add_header(
             "x-amz-target",
             "DeviceFarm_20150623.UpdateVPCEConfiguration",
@@ -9089,3 +10611,731 @@ impl DeviceFarm for DeviceFarmClient {
             .deserialize::<UpdateVPCEConfigurationResult, _>()
     }
 }
+
+/// A blocking client for the AWS Device Farm API, running the calls of a `DeviceFarmClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `DeviceFarmClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct DeviceFarmBlockingClient {
+    client: DeviceFarmClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl DeviceFarmBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> DeviceFarmBlockingClient {
+        DeviceFarmBlockingClient::from_client(DeviceFarmClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> DeviceFarmBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        DeviceFarmBlockingClient::from_client(DeviceFarmClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> DeviceFarmBlockingClient {
+        DeviceFarmBlockingClient::from_client(DeviceFarmClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> DeviceFarmBlockingClient {
+        DeviceFarmBlockingClient::from_client(DeviceFarmClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: DeviceFarmClient) -> DeviceFarmBlockingClient {
+        DeviceFarmBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> DeviceFarmBlockingClient {
+        DeviceFarmBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> DeviceFarmBlockingClient {
+        DeviceFarmBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`DeviceFarm::create_device_pool`].
+    pub fn create_device_pool(
+        &self,
+        input: CreateDevicePoolRequest,
+    ) -> Result<CreateDevicePoolResult, RusotoError<CreateDevicePoolError>> {
+        self.runtime.block_on(self.client.create_device_pool(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::create_instance_profile`].
+    pub fn create_instance_profile(
+        &self,
+        input: CreateInstanceProfileRequest,
+    ) -> Result<CreateInstanceProfileResult, RusotoError<CreateInstanceProfileError>> {
+        self.runtime
+            .block_on(self.client.create_instance_profile(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::create_network_profile`].
+    pub fn create_network_profile(
+        &self,
+        input: CreateNetworkProfileRequest,
+    ) -> Result<CreateNetworkProfileResult, RusotoError<CreateNetworkProfileError>> {
+        self.runtime
+            .block_on(self.client.create_network_profile(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::create_project`].
+    pub fn create_project(
+        &self,
+        input: CreateProjectRequest,
+    ) -> Result<CreateProjectResult, RusotoError<CreateProjectError>> {
+        self.runtime.block_on(self.client.create_project(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::create_remote_access_session`].
+    pub fn create_remote_access_session(
+        &self,
+        input: CreateRemoteAccessSessionRequest,
+    ) -> Result<CreateRemoteAccessSessionResult, RusotoError<CreateRemoteAccessSessionError>> {
+        self.runtime
+            .block_on(self.client.create_remote_access_session(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::create_test_grid_project`].
+    pub fn create_test_grid_project(
+        &self,
+        input: CreateTestGridProjectRequest,
+    ) -> Result<CreateTestGridProjectResult, RusotoError<CreateTestGridProjectError>> {
+        self.runtime
+            .block_on(self.client.create_test_grid_project(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::create_test_grid_url`].
+    pub fn create_test_grid_url(
+        &self,
+        input: CreateTestGridUrlRequest,
+    ) -> Result<CreateTestGridUrlResult, RusotoError<CreateTestGridUrlError>> {
+        self.runtime
+            .block_on(self.client.create_test_grid_url(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::create_upload`].
+    pub fn create_upload(
+        &self,
+        input: CreateUploadRequest,
+    ) -> Result<CreateUploadResult, RusotoError<CreateUploadError>> {
+        self.runtime.block_on(self.client.create_upload(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::create_vpce_configuration`].
+    pub fn create_vpce_configuration(
+        &self,
+        input: CreateVPCEConfigurationRequest,
+    ) -> Result<CreateVPCEConfigurationResult, RusotoError<CreateVPCEConfigurationError>> {
+        self.runtime
+            .block_on(self.client.create_vpce_configuration(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::delete_device_pool`].
+    pub fn delete_device_pool(
+        &self,
+        input: DeleteDevicePoolRequest,
+    ) -> Result<DeleteDevicePoolResult, RusotoError<DeleteDevicePoolError>> {
+        self.runtime.block_on(self.client.delete_device_pool(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::delete_instance_profile`].
+    pub fn delete_instance_profile(
+        &self,
+        input: DeleteInstanceProfileRequest,
+    ) -> Result<DeleteInstanceProfileResult, RusotoError<DeleteInstanceProfileError>> {
+        self.runtime
+            .block_on(self.client.delete_instance_profile(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::delete_network_profile`].
+    pub fn delete_network_profile(
+        &self,
+        input: DeleteNetworkProfileRequest,
+    ) -> Result<DeleteNetworkProfileResult, RusotoError<DeleteNetworkProfileError>> {
+        self.runtime
+            .block_on(self.client.delete_network_profile(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::delete_project`].
+    pub fn delete_project(
+        &self,
+        input: DeleteProjectRequest,
+    ) -> Result<DeleteProjectResult, RusotoError<DeleteProjectError>> {
+        self.runtime.block_on(self.client.delete_project(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::delete_remote_access_session`].
+    pub fn delete_remote_access_session(
+        &self,
+        input: DeleteRemoteAccessSessionRequest,
+    ) -> Result<DeleteRemoteAccessSessionResult, RusotoError<DeleteRemoteAccessSessionError>> {
+        self.runtime
+            .block_on(self.client.delete_remote_access_session(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::delete_run`].
+    pub fn delete_run(
+        &self,
+        input: DeleteRunRequest,
+    ) -> Result<DeleteRunResult, RusotoError<DeleteRunError>> {
+        self.runtime.block_on(self.client.delete_run(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::delete_test_grid_project`].
+    pub fn delete_test_grid_project(
+        &self,
+        input: DeleteTestGridProjectRequest,
+    ) -> Result<DeleteTestGridProjectResult, RusotoError<DeleteTestGridProjectError>> {
+        self.runtime
+            .block_on(self.client.delete_test_grid_project(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::delete_upload`].
+    pub fn delete_upload(
+        &self,
+        input: DeleteUploadRequest,
+    ) -> Result<DeleteUploadResult, RusotoError<DeleteUploadError>> {
+        self.runtime.block_on(self.client.delete_upload(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::delete_vpce_configuration`].
+    pub fn delete_vpce_configuration(
+        &self,
+        input: DeleteVPCEConfigurationRequest,
+    ) -> Result<DeleteVPCEConfigurationResult, RusotoError<DeleteVPCEConfigurationError>> {
+        self.runtime
+            .block_on(self.client.delete_vpce_configuration(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::get_account_settings`].
+    pub fn get_account_settings(
+        &self,
+    ) -> Result<GetAccountSettingsResult, RusotoError<GetAccountSettingsError>> {
+        self.runtime.block_on(self.client.get_account_settings())
+    }
+
+    /// Blocking version of [`DeviceFarm::get_device`].
+    pub fn get_device(
+        &self,
+        input: GetDeviceRequest,
+    ) -> Result<GetDeviceResult, RusotoError<GetDeviceError>> {
+        self.runtime.block_on(self.client.get_device(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::get_device_instance`].
+    pub fn get_device_instance(
+        &self,
+        input: GetDeviceInstanceRequest,
+    ) -> Result<GetDeviceInstanceResult, RusotoError<GetDeviceInstanceError>> {
+        self.runtime
+            .block_on(self.client.get_device_instance(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::get_device_pool`].
+    pub fn get_device_pool(
+        &self,
+        input: GetDevicePoolRequest,
+    ) -> Result<GetDevicePoolResult, RusotoError<GetDevicePoolError>> {
+        self.runtime.block_on(self.client.get_device_pool(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::get_device_pool_compatibility`].
+    pub fn get_device_pool_compatibility(
+        &self,
+        input: GetDevicePoolCompatibilityRequest,
+    ) -> Result<GetDevicePoolCompatibilityResult, RusotoError<GetDevicePoolCompatibilityError>>
+    {
+        self.runtime
+            .block_on(self.client.get_device_pool_compatibility(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::get_instance_profile`].
+    pub fn get_instance_profile(
+        &self,
+        input: GetInstanceProfileRequest,
+    ) -> Result<GetInstanceProfileResult, RusotoError<GetInstanceProfileError>> {
+        self.runtime
+            .block_on(self.client.get_instance_profile(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::get_job`].
+    pub fn get_job(&self, input: GetJobRequest) -> Result<GetJobResult, RusotoError<GetJobError>> {
+        self.runtime.block_on(self.client.get_job(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::get_network_profile`].
+    pub fn get_network_profile(
+        &self,
+        input: GetNetworkProfileRequest,
+    ) -> Result<GetNetworkProfileResult, RusotoError<GetNetworkProfileError>> {
+        self.runtime
+            .block_on(self.client.get_network_profile(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::get_offering_status`].
+    pub fn get_offering_status(
+        &self,
+        input: GetOfferingStatusRequest,
+    ) -> Result<GetOfferingStatusResult, RusotoError<GetOfferingStatusError>> {
+        self.runtime
+            .block_on(self.client.get_offering_status(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::get_project`].
+    pub fn get_project(
+        &self,
+        input: GetProjectRequest,
+    ) -> Result<GetProjectResult, RusotoError<GetProjectError>> {
+        self.runtime.block_on(self.client.get_project(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::get_remote_access_session`].
+    pub fn get_remote_access_session(
+        &self,
+        input: GetRemoteAccessSessionRequest,
+    ) -> Result<GetRemoteAccessSessionResult, RusotoError<GetRemoteAccessSessionError>> {
+        self.runtime
+            .block_on(self.client.get_remote_access_session(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::get_run`].
+    pub fn get_run(&self, input: GetRunRequest) -> Result<GetRunResult, RusotoError<GetRunError>> {
+        self.runtime.block_on(self.client.get_run(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::get_suite`].
+    pub fn get_suite(
+        &self,
+        input: GetSuiteRequest,
+    ) -> Result<GetSuiteResult, RusotoError<GetSuiteError>> {
+        self.runtime.block_on(self.client.get_suite(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::get_test`].
+    pub fn get_test(
+        &self,
+        input: GetTestRequest,
+    ) -> Result<GetTestResult, RusotoError<GetTestError>> {
+        self.runtime.block_on(self.client.get_test(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::get_test_grid_project`].
+    pub fn get_test_grid_project(
+        &self,
+        input: GetTestGridProjectRequest,
+    ) -> Result<GetTestGridProjectResult, RusotoError<GetTestGridProjectError>> {
+        self.runtime
+            .block_on(self.client.get_test_grid_project(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::get_test_grid_session`].
+    pub fn get_test_grid_session(
+        &self,
+        input: GetTestGridSessionRequest,
+    ) -> Result<GetTestGridSessionResult, RusotoError<GetTestGridSessionError>> {
+        self.runtime
+            .block_on(self.client.get_test_grid_session(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::get_upload`].
+    pub fn get_upload(
+        &self,
+        input: GetUploadRequest,
+    ) -> Result<GetUploadResult, RusotoError<GetUploadError>> {
+        self.runtime.block_on(self.client.get_upload(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::get_vpce_configuration`].
+    pub fn get_vpce_configuration(
+        &self,
+        input: GetVPCEConfigurationRequest,
+    ) -> Result<GetVPCEConfigurationResult, RusotoError<GetVPCEConfigurationError>> {
+        self.runtime
+            .block_on(self.client.get_vpce_configuration(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::install_to_remote_access_session`].
+    pub fn install_to_remote_access_session(
+        &self,
+        input: InstallToRemoteAccessSessionRequest,
+    ) -> Result<InstallToRemoteAccessSessionResult, RusotoError<InstallToRemoteAccessSessionError>>
+    {
+        self.runtime
+            .block_on(self.client.install_to_remote_access_session(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::list_artifacts`].
+    pub fn list_artifacts(
+        &self,
+        input: ListArtifactsRequest,
+    ) -> Result<ListArtifactsResult, RusotoError<ListArtifactsError>> {
+        self.runtime.block_on(self.client.list_artifacts(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::list_device_instances`].
+    pub fn list_device_instances(
+        &self,
+        input: ListDeviceInstancesRequest,
+    ) -> Result<ListDeviceInstancesResult, RusotoError<ListDeviceInstancesError>> {
+        self.runtime
+            .block_on(self.client.list_device_instances(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::list_device_pools`].
+    pub fn list_device_pools(
+        &self,
+        input: ListDevicePoolsRequest,
+    ) -> Result<ListDevicePoolsResult, RusotoError<ListDevicePoolsError>> {
+        self.runtime.block_on(self.client.list_device_pools(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::list_devices`].
+    pub fn list_devices(
+        &self,
+        input: ListDevicesRequest,
+    ) -> Result<ListDevicesResult, RusotoError<ListDevicesError>> {
+        self.runtime.block_on(self.client.list_devices(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::list_instance_profiles`].
+    pub fn list_instance_profiles(
+        &self,
+        input: ListInstanceProfilesRequest,
+    ) -> Result<ListInstanceProfilesResult, RusotoError<ListInstanceProfilesError>> {
+        self.runtime
+            .block_on(self.client.list_instance_profiles(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::list_jobs`].
+    pub fn list_jobs(
+        &self,
+        input: ListJobsRequest,
+    ) -> Result<ListJobsResult, RusotoError<ListJobsError>> {
+        self.runtime.block_on(self.client.list_jobs(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::list_network_profiles`].
+    pub fn list_network_profiles(
+        &self,
+        input: ListNetworkProfilesRequest,
+    ) -> Result<ListNetworkProfilesResult, RusotoError<ListNetworkProfilesError>> {
+        self.runtime
+            .block_on(self.client.list_network_profiles(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::list_offering_promotions`].
+    pub fn list_offering_promotions(
+        &self,
+        input: ListOfferingPromotionsRequest,
+    ) -> Result<ListOfferingPromotionsResult, RusotoError<ListOfferingPromotionsError>> {
+        self.runtime
+            .block_on(self.client.list_offering_promotions(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::list_offering_transactions`].
+    pub fn list_offering_transactions(
+        &self,
+        input: ListOfferingTransactionsRequest,
+    ) -> Result<ListOfferingTransactionsResult, RusotoError<ListOfferingTransactionsError>> {
+        self.runtime
+            .block_on(self.client.list_offering_transactions(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::list_offerings`].
+    pub fn list_offerings(
+        &self,
+        input: ListOfferingsRequest,
+    ) -> Result<ListOfferingsResult, RusotoError<ListOfferingsError>> {
+        self.runtime.block_on(self.client.list_offerings(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::list_projects`].
+    pub fn list_projects(
+        &self,
+        input: ListProjectsRequest,
+    ) -> Result<ListProjectsResult, RusotoError<ListProjectsError>> {
+        self.runtime.block_on(self.client.list_projects(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::list_remote_access_sessions`].
+    pub fn list_remote_access_sessions(
+        &self,
+        input: ListRemoteAccessSessionsRequest,
+    ) -> Result<ListRemoteAccessSessionsResult, RusotoError<ListRemoteAccessSessionsError>> {
+        self.runtime
+            .block_on(self.client.list_remote_access_sessions(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::list_runs`].
+    pub fn list_runs(
+        &self,
+        input: ListRunsRequest,
+    ) -> Result<ListRunsResult, RusotoError<ListRunsError>> {
+        self.runtime.block_on(self.client.list_runs(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::list_samples`].
+    pub fn list_samples(
+        &self,
+        input: ListSamplesRequest,
+    ) -> Result<ListSamplesResult, RusotoError<ListSamplesError>> {
+        self.runtime.block_on(self.client.list_samples(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::list_suites`].
+    pub fn list_suites(
+        &self,
+        input: ListSuitesRequest,
+    ) -> Result<ListSuitesResult, RusotoError<ListSuitesError>> {
+        self.runtime.block_on(self.client.list_suites(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::list_test_grid_projects`].
+    pub fn list_test_grid_projects(
+        &self,
+        input: ListTestGridProjectsRequest,
+    ) -> Result<ListTestGridProjectsResult, RusotoError<ListTestGridProjectsError>> {
+        self.runtime
+            .block_on(self.client.list_test_grid_projects(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::list_test_grid_session_actions`].
+    pub fn list_test_grid_session_actions(
+        &self,
+        input: ListTestGridSessionActionsRequest,
+    ) -> Result<ListTestGridSessionActionsResult, RusotoError<ListTestGridSessionActionsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_test_grid_session_actions(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::list_test_grid_session_artifacts`].
+    pub fn list_test_grid_session_artifacts(
+        &self,
+        input: ListTestGridSessionArtifactsRequest,
+    ) -> Result<ListTestGridSessionArtifactsResult, RusotoError<ListTestGridSessionArtifactsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_test_grid_session_artifacts(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::list_test_grid_sessions`].
+    pub fn list_test_grid_sessions(
+        &self,
+        input: ListTestGridSessionsRequest,
+    ) -> Result<ListTestGridSessionsResult, RusotoError<ListTestGridSessionsError>> {
+        self.runtime
+            .block_on(self.client.list_test_grid_sessions(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::list_tests`].
+    pub fn list_tests(
+        &self,
+        input: ListTestsRequest,
+    ) -> Result<ListTestsResult, RusotoError<ListTestsError>> {
+        self.runtime.block_on(self.client.list_tests(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::list_unique_problems`].
+    pub fn list_unique_problems(
+        &self,
+        input: ListUniqueProblemsRequest,
+    ) -> Result<ListUniqueProblemsResult, RusotoError<ListUniqueProblemsError>> {
+        self.runtime
+            .block_on(self.client.list_unique_problems(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::list_uploads`].
+    pub fn list_uploads(
+        &self,
+        input: ListUploadsRequest,
+    ) -> Result<ListUploadsResult, RusotoError<ListUploadsError>> {
+        self.runtime.block_on(self.client.list_uploads(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::list_vpce_configurations`].
+    pub fn list_vpce_configurations(
+        &self,
+        input: ListVPCEConfigurationsRequest,
+    ) -> Result<ListVPCEConfigurationsResult, RusotoError<ListVPCEConfigurationsError>> {
+        self.runtime
+            .block_on(self.client.list_vpce_configurations(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::purchase_offering`].
+    pub fn purchase_offering(
+        &self,
+        input: PurchaseOfferingRequest,
+    ) -> Result<PurchaseOfferingResult, RusotoError<PurchaseOfferingError>> {
+        self.runtime.block_on(self.client.purchase_offering(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::renew_offering`].
+    pub fn renew_offering(
+        &self,
+        input: RenewOfferingRequest,
+    ) -> Result<RenewOfferingResult, RusotoError<RenewOfferingError>> {
+        self.runtime.block_on(self.client.renew_offering(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::schedule_run`].
+    pub fn schedule_run(
+        &self,
+        input: ScheduleRunRequest,
+    ) -> Result<ScheduleRunResult, RusotoError<ScheduleRunError>> {
+        self.runtime.block_on(self.client.schedule_run(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::stop_job`].
+    pub fn stop_job(
+        &self,
+        input: StopJobRequest,
+    ) -> Result<StopJobResult, RusotoError<StopJobError>> {
+        self.runtime.block_on(self.client.stop_job(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::stop_remote_access_session`].
+    pub fn stop_remote_access_session(
+        &self,
+        input: StopRemoteAccessSessionRequest,
+    ) -> Result<StopRemoteAccessSessionResult, RusotoError<StopRemoteAccessSessionError>> {
+        self.runtime
+            .block_on(self.client.stop_remote_access_session(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::stop_run`].
+    pub fn stop_run(
+        &self,
+        input: StopRunRequest,
+    ) -> Result<StopRunResult, RusotoError<StopRunError>> {
+        self.runtime.block_on(self.client.stop_run(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::update_device_instance`].
+    pub fn update_device_instance(
+        &self,
+        input: UpdateDeviceInstanceRequest,
+    ) -> Result<UpdateDeviceInstanceResult, RusotoError<UpdateDeviceInstanceError>> {
+        self.runtime
+            .block_on(self.client.update_device_instance(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::update_device_pool`].
+    pub fn update_device_pool(
+        &self,
+        input: UpdateDevicePoolRequest,
+    ) -> Result<UpdateDevicePoolResult, RusotoError<UpdateDevicePoolError>> {
+        self.runtime.block_on(self.client.update_device_pool(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::update_instance_profile`].
+    pub fn update_instance_profile(
+        &self,
+        input: UpdateInstanceProfileRequest,
+    ) -> Result<UpdateInstanceProfileResult, RusotoError<UpdateInstanceProfileError>> {
+        self.runtime
+            .block_on(self.client.update_instance_profile(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::update_network_profile`].
+    pub fn update_network_profile(
+        &self,
+        input: UpdateNetworkProfileRequest,
+    ) -> Result<UpdateNetworkProfileResult, RusotoError<UpdateNetworkProfileError>> {
+        self.runtime
+            .block_on(self.client.update_network_profile(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::update_project`].
+    pub fn update_project(
+        &self,
+        input: UpdateProjectRequest,
+    ) -> Result<UpdateProjectResult, RusotoError<UpdateProjectError>> {
+        self.runtime.block_on(self.client.update_project(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::update_test_grid_project`].
+    pub fn update_test_grid_project(
+        &self,
+        input: UpdateTestGridProjectRequest,
+    ) -> Result<UpdateTestGridProjectResult, RusotoError<UpdateTestGridProjectError>> {
+        self.runtime
+            .block_on(self.client.update_test_grid_project(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::update_upload`].
+    pub fn update_upload(
+        &self,
+        input: UpdateUploadRequest,
+    ) -> Result<UpdateUploadResult, RusotoError<UpdateUploadError>> {
+        self.runtime.block_on(self.client.update_upload(input))
+    }
+
+    /// Blocking version of [`DeviceFarm::update_vpce_configuration`].
+    pub fn update_vpce_configuration(
+        &self,
+        input: UpdateVPCEConfigurationRequest,
+    ) -> Result<UpdateVPCEConfigurationResult, RusotoError<UpdateVPCEConfigurationError>> {
+        self.runtime
+            .block_on(self.client.update_vpce_configuration(input))
+    }
+}
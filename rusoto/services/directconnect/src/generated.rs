@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -2116,20 +2117,22 @@ impl AcceptDirectConnectGatewayAssociationProposalError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
-                        AcceptDirectConnectGatewayAssociationProposalError::DirectConnectClient(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptDirectConnectGatewayAssociationProposalError::DirectConnectClient,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
-                        AcceptDirectConnectGatewayAssociationProposalError::DirectConnectServer(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptDirectConnectGatewayAssociationProposalError::DirectConnectServer,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2166,16 +2169,22 @@ impl AllocateConnectionOnInterconnectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
-                        AllocateConnectionOnInterconnectError::DirectConnectClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AllocateConnectionOnInterconnectError::DirectConnectClient,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
-                        AllocateConnectionOnInterconnectError::DirectConnectServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AllocateConnectionOnInterconnectError::DirectConnectServer,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2214,26 +2223,38 @@ impl AllocateHostedConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
-                        AllocateHostedConnectionError::DirectConnectClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AllocateHostedConnectionError::DirectConnectClient,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
-                        AllocateHostedConnectionError::DirectConnectServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AllocateHostedConnectionError::DirectConnectServer,
                     )
                 }
                 "DuplicateTagKeysException" => {
-                    return RusotoError::Service(AllocateHostedConnectionError::DuplicateTagKeys(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AllocateHostedConnectionError::DuplicateTagKeys,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(AllocateHostedConnectionError::TooManyTags(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AllocateHostedConnectionError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2272,26 +2293,38 @@ impl AllocatePrivateVirtualInterfaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
-                        AllocatePrivateVirtualInterfaceError::DirectConnectClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AllocatePrivateVirtualInterfaceError::DirectConnectClient,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
-                        AllocatePrivateVirtualInterfaceError::DirectConnectServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AllocatePrivateVirtualInterfaceError::DirectConnectServer,
                     )
                 }
                 "DuplicateTagKeysException" => {
-                    return RusotoError::Service(
-                        AllocatePrivateVirtualInterfaceError::DuplicateTagKeys(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AllocatePrivateVirtualInterfaceError::DuplicateTagKeys,
                     )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(AllocatePrivateVirtualInterfaceError::TooManyTags(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AllocatePrivateVirtualInterfaceError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2336,26 +2369,38 @@ impl AllocatePublicVirtualInterfaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
-                        AllocatePublicVirtualInterfaceError::DirectConnectClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AllocatePublicVirtualInterfaceError::DirectConnectClient,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
-                        AllocatePublicVirtualInterfaceError::DirectConnectServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AllocatePublicVirtualInterfaceError::DirectConnectServer,
                     )
                 }
                 "DuplicateTagKeysException" => {
-                    return RusotoError::Service(
-                        AllocatePublicVirtualInterfaceError::DuplicateTagKeys(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AllocatePublicVirtualInterfaceError::DuplicateTagKeys,
                     )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(AllocatePublicVirtualInterfaceError::TooManyTags(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AllocatePublicVirtualInterfaceError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2400,26 +2445,38 @@ impl AllocateTransitVirtualInterfaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
-                        AllocateTransitVirtualInterfaceError::DirectConnectClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AllocateTransitVirtualInterfaceError::DirectConnectClient,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
-                        AllocateTransitVirtualInterfaceError::DirectConnectServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AllocateTransitVirtualInterfaceError::DirectConnectServer,
                     )
                 }
                 "DuplicateTagKeysException" => {
-                    return RusotoError::Service(
-                        AllocateTransitVirtualInterfaceError::DuplicateTagKeys(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AllocateTransitVirtualInterfaceError::DuplicateTagKeys,
                     )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(AllocateTransitVirtualInterfaceError::TooManyTags(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AllocateTransitVirtualInterfaceError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2460,16 +2517,22 @@ impl AssociateConnectionWithLagError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
-                        AssociateConnectionWithLagError::DirectConnectClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateConnectionWithLagError::DirectConnectClient,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
-                        AssociateConnectionWithLagError::DirectConnectServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateConnectionWithLagError::DirectConnectServer,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2504,16 +2567,22 @@ impl AssociateHostedConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
-                        AssociateHostedConnectionError::DirectConnectClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateHostedConnectionError::DirectConnectClient,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
-                        AssociateHostedConnectionError::DirectConnectServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateHostedConnectionError::DirectConnectServer,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2548,16 +2617,22 @@ impl AssociateVirtualInterfaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
-                        AssociateVirtualInterfaceError::DirectConnectClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateVirtualInterfaceError::DirectConnectClient,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
-                        AssociateVirtualInterfaceError::DirectConnectServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateVirtualInterfaceError::DirectConnectServer,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2592,16 +2667,22 @@ impl ConfirmConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(ConfirmConnectionError::DirectConnectClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ConfirmConnectionError::DirectConnectClient,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(ConfirmConnectionError::DirectConnectServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ConfirmConnectionError::DirectConnectServer,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2634,16 +2715,22 @@ impl ConfirmPrivateVirtualInterfaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
-                        ConfirmPrivateVirtualInterfaceError::DirectConnectClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfirmPrivateVirtualInterfaceError::DirectConnectClient,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
-                        ConfirmPrivateVirtualInterfaceError::DirectConnectServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfirmPrivateVirtualInterfaceError::DirectConnectServer,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2680,16 +2767,22 @@ impl ConfirmPublicVirtualInterfaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
-                        ConfirmPublicVirtualInterfaceError::DirectConnectClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfirmPublicVirtualInterfaceError::DirectConnectClient,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
-                        ConfirmPublicVirtualInterfaceError::DirectConnectServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfirmPublicVirtualInterfaceError::DirectConnectServer,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2726,16 +2819,22 @@ impl ConfirmTransitVirtualInterfaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
-                        ConfirmTransitVirtualInterfaceError::DirectConnectClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfirmTransitVirtualInterfaceError::DirectConnectClient,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
-                        ConfirmTransitVirtualInterfaceError::DirectConnectServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfirmTransitVirtualInterfaceError::DirectConnectServer,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2770,12 +2869,22 @@ impl CreateBGPPeerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(CreateBGPPeerError::DirectConnectClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBGPPeerError::DirectConnectClient,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(CreateBGPPeerError::DirectConnectServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBGPPeerError::DirectConnectServer,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2810,22 +2919,38 @@ impl CreateConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(CreateConnectionError::DirectConnectClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateConnectionError::DirectConnectClient,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(CreateConnectionError::DirectConnectServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateConnectionError::DirectConnectServer,
+                    )
                 }
                 "DuplicateTagKeysException" => {
-                    return RusotoError::Service(CreateConnectionError::DuplicateTagKeys(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConnectionError::DuplicateTagKeys,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreateConnectionError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConnectionError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2860,16 +2985,22 @@ impl CreateDirectConnectGatewayError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
-                        CreateDirectConnectGatewayError::DirectConnectClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDirectConnectGatewayError::DirectConnectClient,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
-                        CreateDirectConnectGatewayError::DirectConnectServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDirectConnectGatewayError::DirectConnectServer,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2906,16 +3037,22 @@ impl CreateDirectConnectGatewayAssociationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
-                        CreateDirectConnectGatewayAssociationError::DirectConnectClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDirectConnectGatewayAssociationError::DirectConnectClient,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
-                        CreateDirectConnectGatewayAssociationError::DirectConnectServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDirectConnectGatewayAssociationError::DirectConnectServer,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2952,20 +3089,22 @@ impl CreateDirectConnectGatewayAssociationProposalError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
-                        CreateDirectConnectGatewayAssociationProposalError::DirectConnectClient(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDirectConnectGatewayAssociationProposalError::DirectConnectClient,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
-                        CreateDirectConnectGatewayAssociationProposalError::DirectConnectServer(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDirectConnectGatewayAssociationProposalError::DirectConnectServer,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3004,22 +3143,38 @@ impl CreateInterconnectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(CreateInterconnectError::DirectConnectClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateInterconnectError::DirectConnectClient,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(CreateInterconnectError::DirectConnectServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateInterconnectError::DirectConnectServer,
+                    )
                 }
                 "DuplicateTagKeysException" => {
-                    return RusotoError::Service(CreateInterconnectError::DuplicateTagKeys(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInterconnectError::DuplicateTagKeys,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreateInterconnectError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInterconnectError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3056,18 +3211,38 @@ impl CreateLagError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(CreateLagError::DirectConnectClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLagError::DirectConnectClient,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(CreateLagError::DirectConnectServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLagError::DirectConnectServer,
+                    )
                 }
                 "DuplicateTagKeysException" => {
-                    return RusotoError::Service(CreateLagError::DuplicateTagKeys(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLagError::DuplicateTagKeys,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreateLagError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLagError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3106,26 +3281,38 @@ impl CreatePrivateVirtualInterfaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
-                        CreatePrivateVirtualInterfaceError::DirectConnectClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePrivateVirtualInterfaceError::DirectConnectClient,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
-                        CreatePrivateVirtualInterfaceError::DirectConnectServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePrivateVirtualInterfaceError::DirectConnectServer,
                     )
                 }
                 "DuplicateTagKeysException" => {
-                    return RusotoError::Service(
-                        CreatePrivateVirtualInterfaceError::DuplicateTagKeys(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePrivateVirtualInterfaceError::DuplicateTagKeys,
                     )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreatePrivateVirtualInterfaceError::TooManyTags(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePrivateVirtualInterfaceError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3170,26 +3357,38 @@ impl CreatePublicVirtualInterfaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
-                        CreatePublicVirtualInterfaceError::DirectConnectClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePublicVirtualInterfaceError::DirectConnectClient,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
-                        CreatePublicVirtualInterfaceError::DirectConnectServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePublicVirtualInterfaceError::DirectConnectServer,
                     )
                 }
                 "DuplicateTagKeysException" => {
-                    return RusotoError::Service(
-                        CreatePublicVirtualInterfaceError::DuplicateTagKeys(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePublicVirtualInterfaceError::DuplicateTagKeys,
                     )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreatePublicVirtualInterfaceError::TooManyTags(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePublicVirtualInterfaceError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3234,26 +3433,38 @@ impl CreateTransitVirtualInterfaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
-                        CreateTransitVirtualInterfaceError::DirectConnectClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTransitVirtualInterfaceError::DirectConnectClient,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
-                        CreateTransitVirtualInterfaceError::DirectConnectServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTransitVirtualInterfaceError::DirectConnectServer,
                     )
                 }
                 "DuplicateTagKeysException" => {
-                    return RusotoError::Service(
-                        CreateTransitVirtualInterfaceError::DuplicateTagKeys(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTransitVirtualInterfaceError::DuplicateTagKeys,
                     )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreateTransitVirtualInterfaceError::TooManyTags(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateTransitVirtualInterfaceError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3292,12 +3503,22 @@ impl DeleteBGPPeerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DeleteBGPPeerError::DirectConnectClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBGPPeerError::DirectConnectClient,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DeleteBGPPeerError::DirectConnectServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBGPPeerError::DirectConnectServer,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3328,16 +3549,22 @@ impl DeleteConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DeleteConnectionError::DirectConnectClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteConnectionError::DirectConnectClient,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DeleteConnectionError::DirectConnectServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteConnectionError::DirectConnectServer,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3370,16 +3597,22 @@ impl DeleteDirectConnectGatewayError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
-                        DeleteDirectConnectGatewayError::DirectConnectClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDirectConnectGatewayError::DirectConnectClient,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
-                        DeleteDirectConnectGatewayError::DirectConnectServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDirectConnectGatewayError::DirectConnectServer,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3416,16 +3649,22 @@ impl DeleteDirectConnectGatewayAssociationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
-                        DeleteDirectConnectGatewayAssociationError::DirectConnectClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDirectConnectGatewayAssociationError::DirectConnectClient,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
-                        DeleteDirectConnectGatewayAssociationError::DirectConnectServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDirectConnectGatewayAssociationError::DirectConnectServer,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3462,20 +3701,22 @@ impl DeleteDirectConnectGatewayAssociationProposalError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
-                        DeleteDirectConnectGatewayAssociationProposalError::DirectConnectClient(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDirectConnectGatewayAssociationProposalError::DirectConnectClient,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
-                        DeleteDirectConnectGatewayAssociationProposalError::DirectConnectServer(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDirectConnectGatewayAssociationProposalError::DirectConnectServer,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3510,16 +3751,22 @@ impl DeleteInterconnectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DeleteInterconnectError::DirectConnectClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteInterconnectError::DirectConnectClient,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DeleteInterconnectError::DirectConnectServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteInterconnectError::DirectConnectServer,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3550,12 +3797,22 @@ impl DeleteLagError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DeleteLagError::DirectConnectClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLagError::DirectConnectClient,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DeleteLagError::DirectConnectServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLagError::DirectConnectServer,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3586,16 +3843,22 @@ impl DeleteVirtualInterfaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DeleteVirtualInterfaceError::DirectConnectClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteVirtualInterfaceError::DirectConnectClient,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DeleteVirtualInterfaceError::DirectConnectServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteVirtualInterfaceError::DirectConnectServer,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3626,16 +3889,22 @@ impl DescribeConnectionLoaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DescribeConnectionLoaError::DirectConnectClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeConnectionLoaError::DirectConnectClient,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DescribeConnectionLoaError::DirectConnectServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeConnectionLoaError::DirectConnectServer,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3666,16 +3935,22 @@ impl DescribeConnectionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DescribeConnectionsError::DirectConnectClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeConnectionsError::DirectConnectClient,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DescribeConnectionsError::DirectConnectServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeConnectionsError::DirectConnectServer,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3708,16 +3983,22 @@ impl DescribeConnectionsOnInterconnectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
-                        DescribeConnectionsOnInterconnectError::DirectConnectClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeConnectionsOnInterconnectError::DirectConnectClient,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
-                        DescribeConnectionsOnInterconnectError::DirectConnectServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeConnectionsOnInterconnectError::DirectConnectServer,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3754,20 +4035,22 @@ impl DescribeDirectConnectGatewayAssociationProposalsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
-                        DescribeDirectConnectGatewayAssociationProposalsError::DirectConnectClient(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDirectConnectGatewayAssociationProposalsError::DirectConnectClient,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
-                        DescribeDirectConnectGatewayAssociationProposalsError::DirectConnectServer(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDirectConnectGatewayAssociationProposalsError::DirectConnectServer,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3804,16 +4087,22 @@ impl DescribeDirectConnectGatewayAssociationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
-                        DescribeDirectConnectGatewayAssociationsError::DirectConnectClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDirectConnectGatewayAssociationsError::DirectConnectClient,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
-                        DescribeDirectConnectGatewayAssociationsError::DirectConnectServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDirectConnectGatewayAssociationsError::DirectConnectServer,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3850,16 +4139,22 @@ impl DescribeDirectConnectGatewayAttachmentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
-                        DescribeDirectConnectGatewayAttachmentsError::DirectConnectClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDirectConnectGatewayAttachmentsError::DirectConnectClient,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
-                        DescribeDirectConnectGatewayAttachmentsError::DirectConnectServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDirectConnectGatewayAttachmentsError::DirectConnectServer,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3896,16 +4191,22 @@ impl DescribeDirectConnectGatewaysError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
-                        DescribeDirectConnectGatewaysError::DirectConnectClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDirectConnectGatewaysError::DirectConnectClient,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
-                        DescribeDirectConnectGatewaysError::DirectConnectServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDirectConnectGatewaysError::DirectConnectServer,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3940,16 +4241,22 @@ impl DescribeHostedConnectionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
-                        DescribeHostedConnectionsError::DirectConnectClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeHostedConnectionsError::DirectConnectClient,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
-                        DescribeHostedConnectionsError::DirectConnectServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeHostedConnectionsError::DirectConnectServer,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3984,16 +4291,22 @@ impl DescribeInterconnectLoaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DescribeInterconnectLoaError::DirectConnectClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeInterconnectLoaError::DirectConnectClient,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DescribeInterconnectLoaError::DirectConnectServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeInterconnectLoaError::DirectConnectServer,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4024,16 +4337,22 @@ impl DescribeInterconnectsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DescribeInterconnectsError::DirectConnectClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeInterconnectsError::DirectConnectClient,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DescribeInterconnectsError::DirectConnectServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeInterconnectsError::DirectConnectServer,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4064,12 +4383,22 @@ impl DescribeLagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DescribeLagsError::DirectConnectClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeLagsError::DirectConnectClient,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DescribeLagsError::DirectConnectServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeLagsError::DirectConnectServer,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4100,12 +4429,22 @@ impl DescribeLoaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DescribeLoaError::DirectConnectClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeLoaError::DirectConnectClient,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DescribeLoaError::DirectConnectServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeLoaError::DirectConnectServer,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4136,16 +4475,22 @@ impl DescribeLocationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DescribeLocationsError::DirectConnectClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeLocationsError::DirectConnectClient,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DescribeLocationsError::DirectConnectServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeLocationsError::DirectConnectServer,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4176,12 +4521,22 @@ impl DescribeTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DescribeTagsError::DirectConnectClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTagsError::DirectConnectClient,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DescribeTagsError::DirectConnectServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTagsError::DirectConnectServer,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4212,16 +4567,22 @@ impl DescribeVirtualGatewaysError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(DescribeVirtualGatewaysError::DirectConnectClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeVirtualGatewaysError::DirectConnectClient,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(DescribeVirtualGatewaysError::DirectConnectServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeVirtualGatewaysError::DirectConnectServer,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4252,16 +4613,22 @@ impl DescribeVirtualInterfacesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
-                        DescribeVirtualInterfacesError::DirectConnectClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeVirtualInterfacesError::DirectConnectClient,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
-                        DescribeVirtualInterfacesError::DirectConnectServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeVirtualInterfacesError::DirectConnectServer,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4298,16 +4665,22 @@ impl DisassociateConnectionFromLagError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
-                        DisassociateConnectionFromLagError::DirectConnectClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateConnectionFromLagError::DirectConnectClient,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
-                        DisassociateConnectionFromLagError::DirectConnectServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateConnectionFromLagError::DirectConnectServer,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4344,16 +4717,22 @@ impl ListVirtualInterfaceTestHistoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
-                        ListVirtualInterfaceTestHistoryError::DirectConnectClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVirtualInterfaceTestHistoryError::DirectConnectClient,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
-                        ListVirtualInterfaceTestHistoryError::DirectConnectServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVirtualInterfaceTestHistoryError::DirectConnectServer,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4388,16 +4767,22 @@ impl StartBgpFailoverTestError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(StartBgpFailoverTestError::DirectConnectClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartBgpFailoverTestError::DirectConnectClient,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(StartBgpFailoverTestError::DirectConnectServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartBgpFailoverTestError::DirectConnectServer,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4428,16 +4813,22 @@ impl StopBgpFailoverTestError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(StopBgpFailoverTestError::DirectConnectClient(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopBgpFailoverTestError::DirectConnectClient,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(StopBgpFailoverTestError::DirectConnectServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopBgpFailoverTestError::DirectConnectServer,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4472,18 +4863,38 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(TagResourceError::DirectConnectClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::DirectConnectClient,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(TagResourceError::DirectConnectServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::DirectConnectServer,
+                    )
                 }
                 "DuplicateTagKeysException" => {
-                    return RusotoError::Service(TagResourceError::DuplicateTagKeys(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::DuplicateTagKeys,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4516,12 +4927,22 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(UntagResourceError::DirectConnectClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::DirectConnectClient,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(UntagResourceError::DirectConnectServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::DirectConnectServer,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4554,16 +4975,22 @@ impl UpdateDirectConnectGatewayAssociationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
-                        UpdateDirectConnectGatewayAssociationError::DirectConnectClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDirectConnectGatewayAssociationError::DirectConnectClient,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
-                        UpdateDirectConnectGatewayAssociationError::DirectConnectServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDirectConnectGatewayAssociationError::DirectConnectServer,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4598,12 +5025,22 @@ impl UpdateLagError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(UpdateLagError::DirectConnectClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateLagError::DirectConnectClient,
+                    )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(UpdateLagError::DirectConnectServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateLagError::DirectConnectServer,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4636,16 +5073,22 @@ impl UpdateVirtualInterfaceAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DirectConnectClientException" => {
-                    return RusotoError::Service(
-                        UpdateVirtualInterfaceAttributesError::DirectConnectClient(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVirtualInterfaceAttributesError::DirectConnectClient,
                     )
                 }
                 "DirectConnectServerException" => {
-                    return RusotoError::Service(
-                        UpdateVirtualInterfaceAttributesError::DirectConnectServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVirtualInterfaceAttributesError::DirectConnectServer,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5047,11 +5490,12 @@ pub struct DirectConnectClient {
 impl DirectConnectClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> DirectConnectClient {
         DirectConnectClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Direct Connect"),
         }
     }
 
@@ -5066,12 +5510,32 @@ impl DirectConnectClient {
     {
         DirectConnectClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Direct Connect"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> DirectConnectClient {
-        DirectConnectClient { client, region }
+        DirectConnectClient {
+            client,
+            region: region_for_service(region, "Direct Connect"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> DirectConnectClient {
+        DirectConnectClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Direct Connect"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> DirectConnectClient {
+        DirectConnectClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -5086,6 +5550,7 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<AcceptDirectConnectGatewayAssociationProposalError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AcceptDirectConnectGatewayAssociationProposal");
         request.add_header(
             "x-amz-target",
             "OvertureService.AcceptDirectConnectGatewayAssociationProposal",
@@ -5111,6 +5576,7 @@ impl DirectConnect for DirectConnectClient {
         input: AllocateConnectionOnInterconnectRequest,
     ) -> Result<Connection, RusotoError<AllocateConnectionOnInterconnectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AllocateConnectionOnInterconnect");
         request.add_header(
             "x-amz-target",
             "OvertureService.AllocateConnectionOnInterconnect",
@@ -5135,6 +5601,7 @@ impl DirectConnect for DirectConnectClient {
         input: AllocateHostedConnectionRequest,
     ) -> Result<Connection, RusotoError<AllocateHostedConnectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AllocateHostedConnection");
         request.add_header("x-amz-target", "OvertureService.AllocateHostedConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5153,6 +5620,7 @@ impl DirectConnect for DirectConnectClient {
         input: AllocatePrivateVirtualInterfaceRequest,
     ) -> Result<VirtualInterface, RusotoError<AllocatePrivateVirtualInterfaceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AllocatePrivateVirtualInterface");
         request.add_header(
             "x-amz-target",
             "OvertureService.AllocatePrivateVirtualInterface",
@@ -5174,6 +5642,7 @@ impl DirectConnect for DirectConnectClient {
         input: AllocatePublicVirtualInterfaceRequest,
     ) -> Result<VirtualInterface, RusotoError<AllocatePublicVirtualInterfaceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AllocatePublicVirtualInterface");
         request.add_header(
             "x-amz-target",
             "OvertureService.AllocatePublicVirtualInterface",
@@ -5198,6 +5667,7 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<AllocateTransitVirtualInterfaceError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AllocateTransitVirtualInterface");
         request.add_header(
             "x-amz-target",
             "OvertureService.AllocateTransitVirtualInterface",
@@ -5220,6 +5690,7 @@ impl DirectConnect for DirectConnectClient {
         input: AssociateConnectionWithLagRequest,
     ) -> Result<Connection, RusotoError<AssociateConnectionWithLagError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateConnectionWithLag");
         request.add_header("x-amz-target", "OvertureService.AssociateConnectionWithLag");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5238,6 +5709,7 @@ impl DirectConnect for DirectConnectClient {
         input: AssociateHostedConnectionRequest,
     ) -> Result<Connection, RusotoError<AssociateHostedConnectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateHostedConnection");
         request.add_header("x-amz-target", "OvertureService.AssociateHostedConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5256,6 +5728,7 @@ impl DirectConnect for DirectConnectClient {
         input: AssociateVirtualInterfaceRequest,
     ) -> Result<VirtualInterface, RusotoError<AssociateVirtualInterfaceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateVirtualInterface");
         request.add_header("x-amz-target", "OvertureService.AssociateVirtualInterface");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5274,6 +5747,7 @@ impl DirectConnect for DirectConnectClient {
         input: ConfirmConnectionRequest,
     ) -> Result<ConfirmConnectionResponse, RusotoError<ConfirmConnectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ConfirmConnection");
         request.add_header("x-amz-target", "OvertureService.ConfirmConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5295,6 +5769,7 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<ConfirmPrivateVirtualInterfaceError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ConfirmPrivateVirtualInterface");
         request.add_header(
             "x-amz-target",
             "OvertureService.ConfirmPrivateVirtualInterface",
@@ -5320,6 +5795,7 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<ConfirmPublicVirtualInterfaceError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ConfirmPublicVirtualInterface");
         request.add_header(
             "x-amz-target",
             "OvertureService.ConfirmPublicVirtualInterface",
@@ -5345,6 +5821,7 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<ConfirmTransitVirtualInterfaceError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ConfirmTransitVirtualInterface");
         request.add_header(
             "x-amz-target",
             "OvertureService.ConfirmTransitVirtualInterface",
@@ -5367,6 +5844,7 @@ impl DirectConnect for DirectConnectClient {
         input: CreateBGPPeerRequest,
     ) -> Result<CreateBGPPeerResponse, RusotoError<CreateBGPPeerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateBGPPeer");
         request.add_header("x-amz-target", "OvertureService.CreateBGPPeer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5385,6 +5863,7 @@ impl DirectConnect for DirectConnectClient {
         input: CreateConnectionRequest,
     ) -> Result<Connection, RusotoError<CreateConnectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateConnection");
         request.add_header("x-amz-target", "OvertureService.CreateConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5404,6 +5883,7 @@ impl DirectConnect for DirectConnectClient {
     ) -> Result<CreateDirectConnectGatewayResult, RusotoError<CreateDirectConnectGatewayError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateDirectConnectGateway");
         request.add_header("x-amz-target", "OvertureService.CreateDirectConnectGateway");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5426,6 +5906,7 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<CreateDirectConnectGatewayAssociationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateDirectConnectGatewayAssociation");
         request.add_header(
             "x-amz-target",
             "OvertureService.CreateDirectConnectGatewayAssociation",
@@ -5454,6 +5935,7 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<CreateDirectConnectGatewayAssociationProposalError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateDirectConnectGatewayAssociationProposal");
         request.add_header(
             "x-amz-target",
             "OvertureService.CreateDirectConnectGatewayAssociationProposal",
@@ -5479,6 +5961,7 @@ impl DirectConnect for DirectConnectClient {
         input: CreateInterconnectRequest,
     ) -> Result<Interconnect, RusotoError<CreateInterconnectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateInterconnect");
         request.add_header("x-amz-target", "OvertureService.CreateInterconnect");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5497,6 +5980,7 @@ impl DirectConnect for DirectConnectClient {
         input: CreateLagRequest,
     ) -> Result<Lag, RusotoError<CreateLagError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateLag");
         request.add_header("x-amz-target", "OvertureService.CreateLag");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5515,6 +5999,7 @@ impl DirectConnect for DirectConnectClient {
         input: CreatePrivateVirtualInterfaceRequest,
     ) -> Result<VirtualInterface, RusotoError<CreatePrivateVirtualInterfaceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreatePrivateVirtualInterface");
         request.add_header(
             "x-amz-target",
             "OvertureService.CreatePrivateVirtualInterface",
@@ -5536,6 +6021,7 @@ impl DirectConnect for DirectConnectClient {
         input: CreatePublicVirtualInterfaceRequest,
     ) -> Result<VirtualInterface, RusotoError<CreatePublicVirtualInterfaceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreatePublicVirtualInterface");
         request.add_header(
             "x-amz-target",
             "OvertureService.CreatePublicVirtualInterface",
@@ -5558,6 +6044,7 @@ impl DirectConnect for DirectConnectClient {
     ) -> Result<CreateTransitVirtualInterfaceResult, RusotoError<CreateTransitVirtualInterfaceError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateTransitVirtualInterface");
         request.add_header(
             "x-amz-target",
             "OvertureService.CreateTransitVirtualInterface",
@@ -5580,6 +6067,7 @@ impl DirectConnect for DirectConnectClient {
         input: DeleteBGPPeerRequest,
     ) -> Result<DeleteBGPPeerResponse, RusotoError<DeleteBGPPeerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteBGPPeer");
         request.add_header("x-amz-target", "OvertureService.DeleteBGPPeer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5598,6 +6086,7 @@ impl DirectConnect for DirectConnectClient {
         input: DeleteConnectionRequest,
     ) -> Result<Connection, RusotoError<DeleteConnectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteConnection");
         request.add_header("x-amz-target", "OvertureService.DeleteConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5617,6 +6106,7 @@ impl DirectConnect for DirectConnectClient {
     ) -> Result<DeleteDirectConnectGatewayResult, RusotoError<DeleteDirectConnectGatewayError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteDirectConnectGateway");
         request.add_header("x-amz-target", "OvertureService.DeleteDirectConnectGateway");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5639,6 +6129,7 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<DeleteDirectConnectGatewayAssociationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteDirectConnectGatewayAssociation");
         request.add_header(
             "x-amz-target",
             "OvertureService.DeleteDirectConnectGatewayAssociation",
@@ -5667,6 +6158,7 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<DeleteDirectConnectGatewayAssociationProposalError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteDirectConnectGatewayAssociationProposal");
         request.add_header(
             "x-amz-target",
             "OvertureService.DeleteDirectConnectGatewayAssociationProposal",
@@ -5692,6 +6184,7 @@ impl DirectConnect for DirectConnectClient {
         input: DeleteInterconnectRequest,
     ) -> Result<DeleteInterconnectResponse, RusotoError<DeleteInterconnectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteInterconnect");
         request.add_header("x-amz-target", "OvertureService.DeleteInterconnect");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5710,6 +6203,7 @@ impl DirectConnect for DirectConnectClient {
         input: DeleteLagRequest,
     ) -> Result<Lag, RusotoError<DeleteLagError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteLag");
         request.add_header("x-amz-target", "OvertureService.DeleteLag");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5728,6 +6222,7 @@ impl DirectConnect for DirectConnectClient {
         input: DeleteVirtualInterfaceRequest,
     ) -> Result<DeleteVirtualInterfaceResponse, RusotoError<DeleteVirtualInterfaceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteVirtualInterface");
         request.add_header("x-amz-target", "OvertureService.DeleteVirtualInterface");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5747,6 +6242,7 @@ impl DirectConnect for DirectConnectClient {
         input: DescribeConnectionLoaRequest,
     ) -> Result<DescribeConnectionLoaResponse, RusotoError<DescribeConnectionLoaError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeConnectionLoa");
         request.add_header("x-amz-target", "OvertureService.DescribeConnectionLoa");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5766,6 +6262,7 @@ impl DirectConnect for DirectConnectClient {
         input: DescribeConnectionsRequest,
     ) -> Result<Connections, RusotoError<DescribeConnectionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeConnections");
         request.add_header("x-amz-target", "OvertureService.DescribeConnections");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5784,6 +6281,7 @@ impl DirectConnect for DirectConnectClient {
         input: DescribeConnectionsOnInterconnectRequest,
     ) -> Result<Connections, RusotoError<DescribeConnectionsOnInterconnectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeConnectionsOnInterconnect");
         request.add_header(
             "x-amz-target",
             "OvertureService.DescribeConnectionsOnInterconnect",
@@ -5811,6 +6309,7 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<DescribeDirectConnectGatewayAssociationProposalsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeDirectConnectGatewayAssociationProposals");
         request.add_header(
             "x-amz-target",
             "OvertureService.DescribeDirectConnectGatewayAssociationProposals",
@@ -5839,6 +6338,7 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<DescribeDirectConnectGatewayAssociationsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeDirectConnectGatewayAssociations");
         request.add_header(
             "x-amz-target",
             "OvertureService.DescribeDirectConnectGatewayAssociations",
@@ -5867,6 +6367,7 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<DescribeDirectConnectGatewayAttachmentsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeDirectConnectGatewayAttachments");
         request.add_header(
             "x-amz-target",
             "OvertureService.DescribeDirectConnectGatewayAttachments",
@@ -5893,6 +6394,7 @@ impl DirectConnect for DirectConnectClient {
     ) -> Result<DescribeDirectConnectGatewaysResult, RusotoError<DescribeDirectConnectGatewaysError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeDirectConnectGateways");
         request.add_header(
             "x-amz-target",
             "OvertureService.DescribeDirectConnectGateways",
@@ -5915,6 +6417,7 @@ impl DirectConnect for DirectConnectClient {
         input: DescribeHostedConnectionsRequest,
     ) -> Result<Connections, RusotoError<DescribeHostedConnectionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeHostedConnections");
         request.add_header("x-amz-target", "OvertureService.DescribeHostedConnections");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5933,6 +6436,7 @@ impl DirectConnect for DirectConnectClient {
         input: DescribeInterconnectLoaRequest,
     ) -> Result<DescribeInterconnectLoaResponse, RusotoError<DescribeInterconnectLoaError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeInterconnectLoa");
         request.add_header("x-amz-target", "OvertureService.DescribeInterconnectLoa");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5952,6 +6456,7 @@ impl DirectConnect for DirectConnectClient {
         input: DescribeInterconnectsRequest,
     ) -> Result<Interconnects, RusotoError<DescribeInterconnectsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeInterconnects");
         request.add_header("x-amz-target", "OvertureService.DescribeInterconnects");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5970,6 +6475,7 @@ impl DirectConnect for DirectConnectClient {
         input: DescribeLagsRequest,
     ) -> Result<Lags, RusotoError<DescribeLagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeLags");
         request.add_header("x-amz-target", "OvertureService.DescribeLags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5988,6 +6494,7 @@ impl DirectConnect for DirectConnectClient {
         input: DescribeLoaRequest,
     ) -> Result<Loa, RusotoError<DescribeLoaError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeLoa");
         request.add_header("x-amz-target", "OvertureService.DescribeLoa");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6003,6 +6510,7 @@ impl DirectConnect for DirectConnectClient {
     /// <p>Lists the AWS Direct Connect locations in the current AWS Region. These are the locations that can be selected when calling <a>CreateConnection</a> or <a>CreateInterconnect</a>.</p>
     async fn describe_locations(&self) -> Result<Locations, RusotoError<DescribeLocationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeLocations");
         request.add_header("x-amz-target", "OvertureService.DescribeLocations");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -6020,6 +6528,7 @@ impl DirectConnect for DirectConnectClient {
         input: DescribeTagsRequest,
     ) -> Result<DescribeTagsResponse, RusotoError<DescribeTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeTags");
         request.add_header("x-amz-target", "OvertureService.DescribeTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6037,6 +6546,7 @@ impl DirectConnect for DirectConnectClient {
         &self,
     ) -> Result<VirtualGateways, RusotoError<DescribeVirtualGatewaysError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeVirtualGateways");
         request.add_header("x-amz-target", "OvertureService.DescribeVirtualGateways");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -6054,6 +6564,7 @@ impl DirectConnect for DirectConnectClient {
         input: DescribeVirtualInterfacesRequest,
     ) -> Result<VirtualInterfaces, RusotoError<DescribeVirtualInterfacesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeVirtualInterfaces");
         request.add_header("x-amz-target", "OvertureService.DescribeVirtualInterfaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6072,6 +6583,7 @@ impl DirectConnect for DirectConnectClient {
         input: DisassociateConnectionFromLagRequest,
     ) -> Result<Connection, RusotoError<DisassociateConnectionFromLagError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisassociateConnectionFromLag");
         request.add_header(
             "x-amz-target",
             "OvertureService.DisassociateConnectionFromLag",
@@ -6096,6 +6608,7 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<ListVirtualInterfaceTestHistoryError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListVirtualInterfaceTestHistory");
         request.add_header(
             "x-amz-target",
             "OvertureService.ListVirtualInterfaceTestHistory",
@@ -6118,6 +6631,7 @@ impl DirectConnect for DirectConnectClient {
         input: StartBgpFailoverTestRequest,
     ) -> Result<StartBgpFailoverTestResponse, RusotoError<StartBgpFailoverTestError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartBgpFailoverTest");
         request.add_header("x-amz-target", "OvertureService.StartBgpFailoverTest");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6137,6 +6651,7 @@ impl DirectConnect for DirectConnectClient {
         input: StopBgpFailoverTestRequest,
     ) -> Result<StopBgpFailoverTestResponse, RusotoError<StopBgpFailoverTestError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopBgpFailoverTest");
         request.add_header("x-amz-target", "OvertureService.StopBgpFailoverTest");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6155,6 +6670,7 @@ impl DirectConnect for DirectConnectClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "OvertureService.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6173,6 +6689,7 @@ impl DirectConnect for DirectConnectClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "OvertureService.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6194,6 +6711,7 @@ impl DirectConnect for DirectConnectClient {
         RusotoError<UpdateDirectConnectGatewayAssociationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateDirectConnectGatewayAssociation");
         request.add_header(
             "x-amz-target",
             "OvertureService.UpdateDirectConnectGatewayAssociation",
@@ -6219,6 +6737,7 @@ impl DirectConnect for DirectConnectClient {
         input: UpdateLagRequest,
     ) -> Result<Lag, RusotoError<UpdateLagError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateLag");
         request.add_header("x-amz-target", "OvertureService.UpdateLag");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6237,6 +6756,7 @@ impl DirectConnect for DirectConnectClient {
         input: UpdateVirtualInterfaceAttributesRequest,
     ) -> Result<VirtualInterface, RusotoError<UpdateVirtualInterfaceAttributesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateVirtualInterfaceAttributes");
         request.add_header(
             "x-amz-target",
             "OvertureService.UpdateVirtualInterfaceAttributes",
@@ -6255,3 +6775,618 @@ impl DirectConnect for DirectConnectClient {
         proto::json::ResponsePayload::new(&response).deserialize::<VirtualInterface, _>()
     }
 }
+
+/// A blocking client for the AWS Direct Connect API, running the calls of a `DirectConnectClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `DirectConnectClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct DirectConnectBlockingClient {
+    client: DirectConnectClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl DirectConnectBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> DirectConnectBlockingClient {
+        DirectConnectBlockingClient::from_client(DirectConnectClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> DirectConnectBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        DirectConnectBlockingClient::from_client(DirectConnectClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> DirectConnectBlockingClient {
+        DirectConnectBlockingClient::from_client(DirectConnectClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> DirectConnectBlockingClient {
+        DirectConnectBlockingClient::from_client(DirectConnectClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: DirectConnectClient) -> DirectConnectBlockingClient {
+        DirectConnectBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> DirectConnectBlockingClient {
+        DirectConnectBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> DirectConnectBlockingClient {
+        DirectConnectBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`DirectConnect::accept_direct_connect_gateway_association_proposal`].
+    pub fn accept_direct_connect_gateway_association_proposal(
+        &self,
+        input: AcceptDirectConnectGatewayAssociationProposalRequest,
+    ) -> Result<
+        AcceptDirectConnectGatewayAssociationProposalResult,
+        RusotoError<AcceptDirectConnectGatewayAssociationProposalError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .accept_direct_connect_gateway_association_proposal(input),
+        )
+    }
+
+    /// Blocking version of [`DirectConnect::allocate_connection_on_interconnect`].
+    pub fn allocate_connection_on_interconnect(
+        &self,
+        input: AllocateConnectionOnInterconnectRequest,
+    ) -> Result<Connection, RusotoError<AllocateConnectionOnInterconnectError>> {
+        self.runtime
+            .block_on(self.client.allocate_connection_on_interconnect(input))
+    }
+
+    /// Blocking version of [`DirectConnect::allocate_hosted_connection`].
+    pub fn allocate_hosted_connection(
+        &self,
+        input: AllocateHostedConnectionRequest,
+    ) -> Result<Connection, RusotoError<AllocateHostedConnectionError>> {
+        self.runtime
+            .block_on(self.client.allocate_hosted_connection(input))
+    }
+
+    /// Blocking version of [`DirectConnect::allocate_private_virtual_interface`].
+    pub fn allocate_private_virtual_interface(
+        &self,
+        input: AllocatePrivateVirtualInterfaceRequest,
+    ) -> Result<VirtualInterface, RusotoError<AllocatePrivateVirtualInterfaceError>> {
+        self.runtime
+            .block_on(self.client.allocate_private_virtual_interface(input))
+    }
+
+    /// Blocking version of [`DirectConnect::allocate_public_virtual_interface`].
+    pub fn allocate_public_virtual_interface(
+        &self,
+        input: AllocatePublicVirtualInterfaceRequest,
+    ) -> Result<VirtualInterface, RusotoError<AllocatePublicVirtualInterfaceError>> {
+        self.runtime
+            .block_on(self.client.allocate_public_virtual_interface(input))
+    }
+
+    /// Blocking version of [`DirectConnect::allocate_transit_virtual_interface`].
+    pub fn allocate_transit_virtual_interface(
+        &self,
+        input: AllocateTransitVirtualInterfaceRequest,
+    ) -> Result<
+        AllocateTransitVirtualInterfaceResult,
+        RusotoError<AllocateTransitVirtualInterfaceError>,
+    > {
+        self.runtime
+            .block_on(self.client.allocate_transit_virtual_interface(input))
+    }
+
+    /// Blocking version of [`DirectConnect::associate_connection_with_lag`].
+    pub fn associate_connection_with_lag(
+        &self,
+        input: AssociateConnectionWithLagRequest,
+    ) -> Result<Connection, RusotoError<AssociateConnectionWithLagError>> {
+        self.runtime
+            .block_on(self.client.associate_connection_with_lag(input))
+    }
+
+    /// Blocking version of [`DirectConnect::associate_hosted_connection`].
+    pub fn associate_hosted_connection(
+        &self,
+        input: AssociateHostedConnectionRequest,
+    ) -> Result<Connection, RusotoError<AssociateHostedConnectionError>> {
+        self.runtime
+            .block_on(self.client.associate_hosted_connection(input))
+    }
+
+    /// Blocking version of [`DirectConnect::associate_virtual_interface`].
+    pub fn associate_virtual_interface(
+        &self,
+        input: AssociateVirtualInterfaceRequest,
+    ) -> Result<VirtualInterface, RusotoError<AssociateVirtualInterfaceError>> {
+        self.runtime
+            .block_on(self.client.associate_virtual_interface(input))
+    }
+
+    /// Blocking version of [`DirectConnect::confirm_connection`].
+    pub fn confirm_connection(
+        &self,
+        input: ConfirmConnectionRequest,
+    ) -> Result<ConfirmConnectionResponse, RusotoError<ConfirmConnectionError>> {
+        self.runtime.block_on(self.client.confirm_connection(input))
+    }
+
+    /// Blocking version of [`DirectConnect::confirm_private_virtual_interface`].
+    pub fn confirm_private_virtual_interface(
+        &self,
+        input: ConfirmPrivateVirtualInterfaceRequest,
+    ) -> Result<
+        ConfirmPrivateVirtualInterfaceResponse,
+        RusotoError<ConfirmPrivateVirtualInterfaceError>,
+    > {
+        self.runtime
+            .block_on(self.client.confirm_private_virtual_interface(input))
+    }
+
+    /// Blocking version of [`DirectConnect::confirm_public_virtual_interface`].
+    pub fn confirm_public_virtual_interface(
+        &self,
+        input: ConfirmPublicVirtualInterfaceRequest,
+    ) -> Result<
+        ConfirmPublicVirtualInterfaceResponse,
+        RusotoError<ConfirmPublicVirtualInterfaceError>,
+    > {
+        self.runtime
+            .block_on(self.client.confirm_public_virtual_interface(input))
+    }
+
+    /// Blocking version of [`DirectConnect::confirm_transit_virtual_interface`].
+    pub fn confirm_transit_virtual_interface(
+        &self,
+        input: ConfirmTransitVirtualInterfaceRequest,
+    ) -> Result<
+        ConfirmTransitVirtualInterfaceResponse,
+        RusotoError<ConfirmTransitVirtualInterfaceError>,
+    > {
+        self.runtime
+            .block_on(self.client.confirm_transit_virtual_interface(input))
+    }
+
+    /// Blocking version of [`DirectConnect::create_bgp_peer`].
+    pub fn create_bgp_peer(
+        &self,
+        input: CreateBGPPeerRequest,
+    ) -> Result<CreateBGPPeerResponse, RusotoError<CreateBGPPeerError>> {
+        self.runtime.block_on(self.client.create_bgp_peer(input))
+    }
+
+    /// Blocking version of [`DirectConnect::create_connection`].
+    pub fn create_connection(
+        &self,
+        input: CreateConnectionRequest,
+    ) -> Result<Connection, RusotoError<CreateConnectionError>> {
+        self.runtime.block_on(self.client.create_connection(input))
+    }
+
+    /// Blocking version of [`DirectConnect::create_direct_connect_gateway`].
+    pub fn create_direct_connect_gateway(
+        &self,
+        input: CreateDirectConnectGatewayRequest,
+    ) -> Result<CreateDirectConnectGatewayResult, RusotoError<CreateDirectConnectGatewayError>>
+    {
+        self.runtime
+            .block_on(self.client.create_direct_connect_gateway(input))
+    }
+
+    /// Blocking version of [`DirectConnect::create_direct_connect_gateway_association`].
+    pub fn create_direct_connect_gateway_association(
+        &self,
+        input: CreateDirectConnectGatewayAssociationRequest,
+    ) -> Result<
+        CreateDirectConnectGatewayAssociationResult,
+        RusotoError<CreateDirectConnectGatewayAssociationError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_direct_connect_gateway_association(input))
+    }
+
+    /// Blocking version of [`DirectConnect::create_direct_connect_gateway_association_proposal`].
+    pub fn create_direct_connect_gateway_association_proposal(
+        &self,
+        input: CreateDirectConnectGatewayAssociationProposalRequest,
+    ) -> Result<
+        CreateDirectConnectGatewayAssociationProposalResult,
+        RusotoError<CreateDirectConnectGatewayAssociationProposalError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .create_direct_connect_gateway_association_proposal(input),
+        )
+    }
+
+    /// Blocking version of [`DirectConnect::create_interconnect`].
+    pub fn create_interconnect(
+        &self,
+        input: CreateInterconnectRequest,
+    ) -> Result<Interconnect, RusotoError<CreateInterconnectError>> {
+        self.runtime
+            .block_on(self.client.create_interconnect(input))
+    }
+
+    /// Blocking version of [`DirectConnect::create_lag`].
+    pub fn create_lag(&self, input: CreateLagRequest) -> Result<Lag, RusotoError<CreateLagError>> {
+        self.runtime.block_on(self.client.create_lag(input))
+    }
+
+    /// Blocking version of [`DirectConnect::create_private_virtual_interface`].
+    pub fn create_private_virtual_interface(
+        &self,
+        input: CreatePrivateVirtualInterfaceRequest,
+    ) -> Result<VirtualInterface, RusotoError<CreatePrivateVirtualInterfaceError>> {
+        self.runtime
+            .block_on(self.client.create_private_virtual_interface(input))
+    }
+
+    /// Blocking version of [`DirectConnect::create_public_virtual_interface`].
+    pub fn create_public_virtual_interface(
+        &self,
+        input: CreatePublicVirtualInterfaceRequest,
+    ) -> Result<VirtualInterface, RusotoError<CreatePublicVirtualInterfaceError>> {
+        self.runtime
+            .block_on(self.client.create_public_virtual_interface(input))
+    }
+
+    /// Blocking version of [`DirectConnect::create_transit_virtual_interface`].
+    pub fn create_transit_virtual_interface(
+        &self,
+        input: CreateTransitVirtualInterfaceRequest,
+    ) -> Result<CreateTransitVirtualInterfaceResult, RusotoError<CreateTransitVirtualInterfaceError>>
+    {
+        self.runtime
+            .block_on(self.client.create_transit_virtual_interface(input))
+    }
+
+    /// Blocking version of [`DirectConnect::delete_bgp_peer`].
+    pub fn delete_bgp_peer(
+        &self,
+        input: DeleteBGPPeerRequest,
+    ) -> Result<DeleteBGPPeerResponse, RusotoError<DeleteBGPPeerError>> {
+        self.runtime.block_on(self.client.delete_bgp_peer(input))
+    }
+
+    /// Blocking version of [`DirectConnect::delete_connection`].
+    pub fn delete_connection(
+        &self,
+        input: DeleteConnectionRequest,
+    ) -> Result<Connection, RusotoError<DeleteConnectionError>> {
+        self.runtime.block_on(self.client.delete_connection(input))
+    }
+
+    /// Blocking version of [`DirectConnect::delete_direct_connect_gateway`].
+    pub fn delete_direct_connect_gateway(
+        &self,
+        input: DeleteDirectConnectGatewayRequest,
+    ) -> Result<DeleteDirectConnectGatewayResult, RusotoError<DeleteDirectConnectGatewayError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_direct_connect_gateway(input))
+    }
+
+    /// Blocking version of [`DirectConnect::delete_direct_connect_gateway_association`].
+    pub fn delete_direct_connect_gateway_association(
+        &self,
+        input: DeleteDirectConnectGatewayAssociationRequest,
+    ) -> Result<
+        DeleteDirectConnectGatewayAssociationResult,
+        RusotoError<DeleteDirectConnectGatewayAssociationError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_direct_connect_gateway_association(input))
+    }
+
+    /// Blocking version of [`DirectConnect::delete_direct_connect_gateway_association_proposal`].
+    pub fn delete_direct_connect_gateway_association_proposal(
+        &self,
+        input: DeleteDirectConnectGatewayAssociationProposalRequest,
+    ) -> Result<
+        DeleteDirectConnectGatewayAssociationProposalResult,
+        RusotoError<DeleteDirectConnectGatewayAssociationProposalError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .delete_direct_connect_gateway_association_proposal(input),
+        )
+    }
+
+    /// Blocking version of [`DirectConnect::delete_interconnect`].
+    pub fn delete_interconnect(
+        &self,
+        input: DeleteInterconnectRequest,
+    ) -> Result<DeleteInterconnectResponse, RusotoError<DeleteInterconnectError>> {
+        self.runtime
+            .block_on(self.client.delete_interconnect(input))
+    }
+
+    /// Blocking version of [`DirectConnect::delete_lag`].
+    pub fn delete_lag(&self, input: DeleteLagRequest) -> Result<Lag, RusotoError<DeleteLagError>> {
+        self.runtime.block_on(self.client.delete_lag(input))
+    }
+
+    /// Blocking version of [`DirectConnect::delete_virtual_interface`].
+    pub fn delete_virtual_interface(
+        &self,
+        input: DeleteVirtualInterfaceRequest,
+    ) -> Result<DeleteVirtualInterfaceResponse, RusotoError<DeleteVirtualInterfaceError>> {
+        self.runtime
+            .block_on(self.client.delete_virtual_interface(input))
+    }
+
+    /// Blocking version of [`DirectConnect::describe_connection_loa`].
+    pub fn describe_connection_loa(
+        &self,
+        input: DescribeConnectionLoaRequest,
+    ) -> Result<DescribeConnectionLoaResponse, RusotoError<DescribeConnectionLoaError>> {
+        self.runtime
+            .block_on(self.client.describe_connection_loa(input))
+    }
+
+    /// Blocking version of [`DirectConnect::describe_connections`].
+    pub fn describe_connections(
+        &self,
+        input: DescribeConnectionsRequest,
+    ) -> Result<Connections, RusotoError<DescribeConnectionsError>> {
+        self.runtime
+            .block_on(self.client.describe_connections(input))
+    }
+
+    /// Blocking version of [`DirectConnect::describe_connections_on_interconnect`].
+    pub fn describe_connections_on_interconnect(
+        &self,
+        input: DescribeConnectionsOnInterconnectRequest,
+    ) -> Result<Connections, RusotoError<DescribeConnectionsOnInterconnectError>> {
+        self.runtime
+            .block_on(self.client.describe_connections_on_interconnect(input))
+    }
+
+    /// Blocking version of [`DirectConnect::describe_direct_connect_gateway_association_proposals`].
+    pub fn describe_direct_connect_gateway_association_proposals(
+        &self,
+        input: DescribeDirectConnectGatewayAssociationProposalsRequest,
+    ) -> Result<
+        DescribeDirectConnectGatewayAssociationProposalsResult,
+        RusotoError<DescribeDirectConnectGatewayAssociationProposalsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_direct_connect_gateway_association_proposals(input),
+        )
+    }
+
+    /// Blocking version of [`DirectConnect::describe_direct_connect_gateway_associations`].
+    pub fn describe_direct_connect_gateway_associations(
+        &self,
+        input: DescribeDirectConnectGatewayAssociationsRequest,
+    ) -> Result<
+        DescribeDirectConnectGatewayAssociationsResult,
+        RusotoError<DescribeDirectConnectGatewayAssociationsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_direct_connect_gateway_associations(input),
+        )
+    }
+
+    /// Blocking version of [`DirectConnect::describe_direct_connect_gateway_attachments`].
+    pub fn describe_direct_connect_gateway_attachments(
+        &self,
+        input: DescribeDirectConnectGatewayAttachmentsRequest,
+    ) -> Result<
+        DescribeDirectConnectGatewayAttachmentsResult,
+        RusotoError<DescribeDirectConnectGatewayAttachmentsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_direct_connect_gateway_attachments(input),
+        )
+    }
+
+    /// Blocking version of [`DirectConnect::describe_direct_connect_gateways`].
+    pub fn describe_direct_connect_gateways(
+        &self,
+        input: DescribeDirectConnectGatewaysRequest,
+    ) -> Result<DescribeDirectConnectGatewaysResult, RusotoError<DescribeDirectConnectGatewaysError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_direct_connect_gateways(input))
+    }
+
+    /// Blocking version of [`DirectConnect::describe_hosted_connections`].
+    pub fn describe_hosted_connections(
+        &self,
+        input: DescribeHostedConnectionsRequest,
+    ) -> Result<Connections, RusotoError<DescribeHostedConnectionsError>> {
+        self.runtime
+            .block_on(self.client.describe_hosted_connections(input))
+    }
+
+    /// Blocking version of [`DirectConnect::describe_interconnect_loa`].
+    pub fn describe_interconnect_loa(
+        &self,
+        input: DescribeInterconnectLoaRequest,
+    ) -> Result<DescribeInterconnectLoaResponse, RusotoError<DescribeInterconnectLoaError>> {
+        self.runtime
+            .block_on(self.client.describe_interconnect_loa(input))
+    }
+
+    /// Blocking version of [`DirectConnect::describe_interconnects`].
+    pub fn describe_interconnects(
+        &self,
+        input: DescribeInterconnectsRequest,
+    ) -> Result<Interconnects, RusotoError<DescribeInterconnectsError>> {
+        self.runtime
+            .block_on(self.client.describe_interconnects(input))
+    }
+
+    /// Blocking version of [`DirectConnect::describe_lags`].
+    pub fn describe_lags(
+        &self,
+        input: DescribeLagsRequest,
+    ) -> Result<Lags, RusotoError<DescribeLagsError>> {
+        self.runtime.block_on(self.client.describe_lags(input))
+    }
+
+    /// Blocking version of [`DirectConnect::describe_loa`].
+    pub fn describe_loa(
+        &self,
+        input: DescribeLoaRequest,
+    ) -> Result<Loa, RusotoError<DescribeLoaError>> {
+        self.runtime.block_on(self.client.describe_loa(input))
+    }
+
+    /// Blocking version of [`DirectConnect::describe_locations`].
+    pub fn describe_locations(&self) -> Result<Locations, RusotoError<DescribeLocationsError>> {
+        self.runtime.block_on(self.client.describe_locations())
+    }
+
+    /// Blocking version of [`DirectConnect::describe_tags`].
+    pub fn describe_tags(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> Result<DescribeTagsResponse, RusotoError<DescribeTagsError>> {
+        self.runtime.block_on(self.client.describe_tags(input))
+    }
+
+    /// Blocking version of [`DirectConnect::describe_virtual_gateways`].
+    pub fn describe_virtual_gateways(
+        &self,
+    ) -> Result<VirtualGateways, RusotoError<DescribeVirtualGatewaysError>> {
+        self.runtime
+            .block_on(self.client.describe_virtual_gateways())
+    }
+
+    /// Blocking version of [`DirectConnect::describe_virtual_interfaces`].
+    pub fn describe_virtual_interfaces(
+        &self,
+        input: DescribeVirtualInterfacesRequest,
+    ) -> Result<VirtualInterfaces, RusotoError<DescribeVirtualInterfacesError>> {
+        self.runtime
+            .block_on(self.client.describe_virtual_interfaces(input))
+    }
+
+    /// Blocking version of [`DirectConnect::disassociate_connection_from_lag`].
+    pub fn disassociate_connection_from_lag(
+        &self,
+        input: DisassociateConnectionFromLagRequest,
+    ) -> Result<Connection, RusotoError<DisassociateConnectionFromLagError>> {
+        self.runtime
+            .block_on(self.client.disassociate_connection_from_lag(input))
+    }
+
+    /// Blocking version of [`DirectConnect::list_virtual_interface_test_history`].
+    pub fn list_virtual_interface_test_history(
+        &self,
+        input: ListVirtualInterfaceTestHistoryRequest,
+    ) -> Result<
+        ListVirtualInterfaceTestHistoryResponse,
+        RusotoError<ListVirtualInterfaceTestHistoryError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_virtual_interface_test_history(input))
+    }
+
+    /// Blocking version of [`DirectConnect::start_bgp_failover_test`].
+    pub fn start_bgp_failover_test(
+        &self,
+        input: StartBgpFailoverTestRequest,
+    ) -> Result<StartBgpFailoverTestResponse, RusotoError<StartBgpFailoverTestError>> {
+        self.runtime
+            .block_on(self.client.start_bgp_failover_test(input))
+    }
+
+    /// Blocking version of [`DirectConnect::stop_bgp_failover_test`].
+    pub fn stop_bgp_failover_test(
+        &self,
+        input: StopBgpFailoverTestRequest,
+    ) -> Result<StopBgpFailoverTestResponse, RusotoError<StopBgpFailoverTestError>> {
+        self.runtime
+            .block_on(self.client.stop_bgp_failover_test(input))
+    }
+
+    /// Blocking version of [`DirectConnect::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`DirectConnect::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`DirectConnect::update_direct_connect_gateway_association`].
+    pub fn update_direct_connect_gateway_association(
+        &self,
+        input: UpdateDirectConnectGatewayAssociationRequest,
+    ) -> Result<
+        UpdateDirectConnectGatewayAssociationResult,
+        RusotoError<UpdateDirectConnectGatewayAssociationError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_direct_connect_gateway_association(input))
+    }
+
+    /// Blocking version of [`DirectConnect::update_lag`].
+    pub fn update_lag(&self, input: UpdateLagRequest) -> Result<Lag, RusotoError<UpdateLagError>> {
+        self.runtime.block_on(self.client.update_lag(input))
+    }
+
+    /// Blocking version of [`DirectConnect::update_virtual_interface_attributes`].
+    pub fn update_virtual_interface_attributes(
+        &self,
+        input: UpdateVirtualInterfaceAttributesRequest,
+    ) -> Result<VirtualInterface, RusotoError<UpdateVirtualInterfaceAttributesError>> {
+        self.runtime
+            .block_on(self.client.update_virtual_interface_attributes(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1075,33 +1076,46 @@ impl AssociateConfigurationItemsToApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(
-                        AssociateConfigurationItemsToApplicationError::AuthorizationError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateConfigurationItemsToApplicationError::AuthorizationError,
                     )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(
-                        AssociateConfigurationItemsToApplicationError::HomeRegionNotSet(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateConfigurationItemsToApplicationError::HomeRegionNotSet,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        AssociateConfigurationItemsToApplicationError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateConfigurationItemsToApplicationError::InvalidParameter,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        AssociateConfigurationItemsToApplicationError::InvalidParameterValue(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateConfigurationItemsToApplicationError::InvalidParameterValue,
                     )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(
-                        AssociateConfigurationItemsToApplicationError::ServerInternalError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateConfigurationItemsToApplicationError::ServerInternalError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1151,31 +1165,46 @@ impl BatchDeleteImportDataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(BatchDeleteImportDataError::AuthorizationError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDeleteImportDataError::AuthorizationError,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(BatchDeleteImportDataError::HomeRegionNotSet(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDeleteImportDataError::HomeRegionNotSet,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(BatchDeleteImportDataError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDeleteImportDataError::InvalidParameter,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(BatchDeleteImportDataError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDeleteImportDataError::InvalidParameterValue,
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(BatchDeleteImportDataError::ServerInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDeleteImportDataError::ServerInternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1215,27 +1244,46 @@ impl CreateApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(CreateApplicationError::AuthorizationError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateApplicationError::AuthorizationError,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(CreateApplicationError::HomeRegionNotSet(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApplicationError::HomeRegionNotSet,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateApplicationError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApplicationError::InvalidParameter,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateApplicationError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateApplicationError::InvalidParameterValue,
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(CreateApplicationError::ServerInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateApplicationError::ServerInternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1277,24 +1325,54 @@ impl CreateTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(CreateTagsError::AuthorizationError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTagsError::AuthorizationError,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(CreateTagsError::HomeRegionNotSet(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTagsError::HomeRegionNotSet,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateTagsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTagsError::InvalidParameter,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateTagsError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTagsError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTagsError::ResourceNotFound,
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(CreateTagsError::ServerInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTagsError::ServerInternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1335,27 +1413,46 @@ impl DeleteApplicationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(DeleteApplicationsError::AuthorizationError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteApplicationsError::AuthorizationError,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(DeleteApplicationsError::HomeRegionNotSet(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationsError::HomeRegionNotSet,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteApplicationsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationsError::InvalidParameter,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteApplicationsError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteApplicationsError::InvalidParameterValue,
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(DeleteApplicationsError::ServerInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteApplicationsError::ServerInternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1397,24 +1494,54 @@ impl DeleteTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(DeleteTagsError::AuthorizationError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTagsError::AuthorizationError,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(DeleteTagsError::HomeRegionNotSet(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTagsError::HomeRegionNotSet,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteTagsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTagsError::InvalidParameter,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteTagsError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTagsError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTagsError::ResourceNotFound,
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(DeleteTagsError::ServerInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTagsError::ServerInternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1455,23 +1582,46 @@ impl DescribeAgentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(DescribeAgentsError::AuthorizationError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAgentsError::AuthorizationError,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(DescribeAgentsError::HomeRegionNotSet(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAgentsError::HomeRegionNotSet,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeAgentsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAgentsError::InvalidParameter,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeAgentsError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAgentsError::InvalidParameterValue,
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(DescribeAgentsError::ServerInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAgentsError::ServerInternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1511,31 +1661,46 @@ impl DescribeConfigurationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(DescribeConfigurationsError::AuthorizationError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeConfigurationsError::AuthorizationError,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(DescribeConfigurationsError::HomeRegionNotSet(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeConfigurationsError::HomeRegionNotSet,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeConfigurationsError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeConfigurationsError::InvalidParameter,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DescribeConfigurationsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeConfigurationsError::InvalidParameterValue,
                     )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(DescribeConfigurationsError::ServerInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeConfigurationsError::ServerInternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1579,41 +1744,62 @@ impl DescribeContinuousExportsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(
-                        DescribeContinuousExportsError::AuthorizationError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeContinuousExportsError::AuthorizationError,
                     )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(DescribeContinuousExportsError::HomeRegionNotSet(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeContinuousExportsError::HomeRegionNotSet,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeContinuousExportsError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeContinuousExportsError::InvalidParameter,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DescribeContinuousExportsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeContinuousExportsError::InvalidParameterValue,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
-                        DescribeContinuousExportsError::OperationNotPermitted(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeContinuousExportsError::OperationNotPermitted,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeContinuousExportsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeContinuousExportsError::ResourceNotFound,
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(
-                        DescribeContinuousExportsError::ServerInternalError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeContinuousExportsError::ServerInternalError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1665,36 +1851,54 @@ impl DescribeExportConfigurationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(
-                        DescribeExportConfigurationsError::AuthorizationError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeExportConfigurationsError::AuthorizationError,
                     )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(
-                        DescribeExportConfigurationsError::HomeRegionNotSet(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeExportConfigurationsError::HomeRegionNotSet,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        DescribeExportConfigurationsError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeExportConfigurationsError::InvalidParameter,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DescribeExportConfigurationsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeExportConfigurationsError::InvalidParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeExportConfigurationsError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeExportConfigurationsError::ResourceNotFound,
                     )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(
-                        DescribeExportConfigurationsError::ServerInternalError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeExportConfigurationsError::ServerInternalError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1747,31 +1951,46 @@ impl DescribeExportTasksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(DescribeExportTasksError::AuthorizationError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeExportTasksError::AuthorizationError,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(DescribeExportTasksError::HomeRegionNotSet(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeExportTasksError::HomeRegionNotSet,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeExportTasksError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeExportTasksError::InvalidParameter,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeExportTasksError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeExportTasksError::InvalidParameterValue,
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(DescribeExportTasksError::ServerInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeExportTasksError::ServerInternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1811,31 +2030,46 @@ impl DescribeImportTasksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(DescribeImportTasksError::AuthorizationError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeImportTasksError::AuthorizationError,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(DescribeImportTasksError::HomeRegionNotSet(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeImportTasksError::HomeRegionNotSet,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeImportTasksError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeImportTasksError::InvalidParameter,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeImportTasksError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeImportTasksError::InvalidParameterValue,
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(DescribeImportTasksError::ServerInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeImportTasksError::ServerInternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1877,24 +2111,54 @@ impl DescribeTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(DescribeTagsError::AuthorizationError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTagsError::AuthorizationError,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(DescribeTagsError::HomeRegionNotSet(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTagsError::HomeRegionNotSet,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeTagsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTagsError::InvalidParameter,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeTagsError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTagsError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTagsError::ResourceNotFound,
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(DescribeTagsError::ServerInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTagsError::ServerInternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1937,41 +2201,46 @@ impl DisassociateConfigurationItemsFromApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(
-                        DisassociateConfigurationItemsFromApplicationError::AuthorizationError(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateConfigurationItemsFromApplicationError::AuthorizationError,
                     )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(
-                        DisassociateConfigurationItemsFromApplicationError::HomeRegionNotSet(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateConfigurationItemsFromApplicationError::HomeRegionNotSet,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        DisassociateConfigurationItemsFromApplicationError::InvalidParameter(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateConfigurationItemsFromApplicationError::InvalidParameter,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DisassociateConfigurationItemsFromApplicationError::InvalidParameterValue(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateConfigurationItemsFromApplicationError::InvalidParameterValue,
                     )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(
-                        DisassociateConfigurationItemsFromApplicationError::ServerInternalError(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateConfigurationItemsFromApplicationError::ServerInternalError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2023,36 +2292,54 @@ impl ExportConfigurationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(ExportConfigurationsError::AuthorizationError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ExportConfigurationsError::AuthorizationError,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(ExportConfigurationsError::HomeRegionNotSet(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ExportConfigurationsError::HomeRegionNotSet,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ExportConfigurationsError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ExportConfigurationsError::InvalidParameter,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ExportConfigurationsError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ExportConfigurationsError::InvalidParameterValue,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(ExportConfigurationsError::OperationNotPermitted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ExportConfigurationsError::OperationNotPermitted,
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(ExportConfigurationsError::ServerInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ExportConfigurationsError::ServerInternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2093,31 +2380,46 @@ impl GetDiscoverySummaryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(GetDiscoverySummaryError::AuthorizationError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDiscoverySummaryError::AuthorizationError,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(GetDiscoverySummaryError::HomeRegionNotSet(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDiscoverySummaryError::HomeRegionNotSet,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetDiscoverySummaryError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDiscoverySummaryError::InvalidParameter,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetDiscoverySummaryError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDiscoverySummaryError::InvalidParameterValue,
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(GetDiscoverySummaryError::ServerInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDiscoverySummaryError::ServerInternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2159,30 +2461,54 @@ impl ListConfigurationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(ListConfigurationsError::AuthorizationError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListConfigurationsError::AuthorizationError,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(ListConfigurationsError::HomeRegionNotSet(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListConfigurationsError::HomeRegionNotSet,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListConfigurationsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListConfigurationsError::InvalidParameter,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListConfigurationsError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListConfigurationsError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListConfigurationsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListConfigurationsError::ResourceNotFound,
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(ListConfigurationsError::ServerInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListConfigurationsError::ServerInternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2223,31 +2549,46 @@ impl ListServerNeighborsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(ListServerNeighborsError::AuthorizationError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListServerNeighborsError::AuthorizationError,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(ListServerNeighborsError::HomeRegionNotSet(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListServerNeighborsError::HomeRegionNotSet,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListServerNeighborsError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListServerNeighborsError::InvalidParameter,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListServerNeighborsError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListServerNeighborsError::InvalidParameterValue,
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(ListServerNeighborsError::ServerInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListServerNeighborsError::ServerInternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2293,42 +2634,70 @@ impl StartContinuousExportError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(StartContinuousExportError::AuthorizationError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartContinuousExportError::AuthorizationError,
+                    )
                 }
                 "ConflictErrorException" => {
-                    return RusotoError::Service(StartContinuousExportError::ConflictError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartContinuousExportError::ConflictError,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(StartContinuousExportError::HomeRegionNotSet(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartContinuousExportError::HomeRegionNotSet,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartContinuousExportError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartContinuousExportError::InvalidParameter,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(StartContinuousExportError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartContinuousExportError::InvalidParameterValue,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(StartContinuousExportError::OperationNotPermitted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartContinuousExportError::OperationNotPermitted,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StartContinuousExportError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartContinuousExportError::ResourceInUse,
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(StartContinuousExportError::ServerInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartContinuousExportError::ServerInternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2373,31 +2742,46 @@ impl StartDataCollectionByAgentIdsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(
-                        StartDataCollectionByAgentIdsError::AuthorizationError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDataCollectionByAgentIdsError::AuthorizationError,
                     )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(
-                        StartDataCollectionByAgentIdsError::HomeRegionNotSet(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDataCollectionByAgentIdsError::HomeRegionNotSet,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        StartDataCollectionByAgentIdsError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDataCollectionByAgentIdsError::InvalidParameter,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        StartDataCollectionByAgentIdsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDataCollectionByAgentIdsError::InvalidParameterValue,
                     )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(
-                        StartDataCollectionByAgentIdsError::ServerInternalError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDataCollectionByAgentIdsError::ServerInternalError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2449,28 +2833,54 @@ impl StartExportTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(StartExportTaskError::AuthorizationError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartExportTaskError::AuthorizationError,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(StartExportTaskError::HomeRegionNotSet(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartExportTaskError::HomeRegionNotSet,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartExportTaskError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartExportTaskError::InvalidParameter,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(StartExportTaskError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartExportTaskError::InvalidParameterValue,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(StartExportTaskError::OperationNotPermitted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartExportTaskError::OperationNotPermitted,
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(StartExportTaskError::ServerInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartExportTaskError::ServerInternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2513,26 +2923,54 @@ impl StartImportTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(StartImportTaskError::AuthorizationError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartImportTaskError::AuthorizationError,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(StartImportTaskError::HomeRegionNotSet(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartImportTaskError::HomeRegionNotSet,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartImportTaskError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartImportTaskError::InvalidParameter,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(StartImportTaskError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartImportTaskError::InvalidParameterValue,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StartImportTaskError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartImportTaskError::ResourceInUse,
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(StartImportTaskError::ServerInternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartImportTaskError::ServerInternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2579,44 +3017,70 @@ impl StopContinuousExportError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(StopContinuousExportError::AuthorizationError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopContinuousExportError::AuthorizationError,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(StopContinuousExportError::HomeRegionNotSet(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopContinuousExportError::HomeRegionNotSet,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StopContinuousExportError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopContinuousExportError::InvalidParameter,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(StopContinuousExportError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopContinuousExportError::InvalidParameterValue,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(StopContinuousExportError::OperationNotPermitted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopContinuousExportError::OperationNotPermitted,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StopContinuousExportError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopContinuousExportError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopContinuousExportError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopContinuousExportError::ResourceNotFound,
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(StopContinuousExportError::ServerInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopContinuousExportError::ServerInternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2661,31 +3125,46 @@ impl StopDataCollectionByAgentIdsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(
-                        StopDataCollectionByAgentIdsError::AuthorizationError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopDataCollectionByAgentIdsError::AuthorizationError,
                     )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(
-                        StopDataCollectionByAgentIdsError::HomeRegionNotSet(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopDataCollectionByAgentIdsError::HomeRegionNotSet,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        StopDataCollectionByAgentIdsError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopDataCollectionByAgentIdsError::InvalidParameter,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        StopDataCollectionByAgentIdsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopDataCollectionByAgentIdsError::InvalidParameterValue,
                     )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(
-                        StopDataCollectionByAgentIdsError::ServerInternalError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopDataCollectionByAgentIdsError::ServerInternalError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2735,27 +3214,46 @@ impl UpdateApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthorizationErrorException" => {
-                    return RusotoError::Service(UpdateApplicationError::AuthorizationError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateApplicationError::AuthorizationError,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(UpdateApplicationError::HomeRegionNotSet(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApplicationError::HomeRegionNotSet,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateApplicationError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApplicationError::InvalidParameter,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateApplicationError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateApplicationError::InvalidParameterValue,
+                    )
                 }
                 "ServerInternalErrorException" => {
-                    return RusotoError::Service(UpdateApplicationError::ServerInternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateApplicationError::ServerInternalError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2944,11 +3442,12 @@ pub struct DiscoveryClient {
 impl DiscoveryClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> DiscoveryClient {
         DiscoveryClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Application Discovery Service"),
         }
     }
 
@@ -2963,12 +3462,32 @@ impl DiscoveryClient {
     {
         DiscoveryClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Application Discovery Service"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> DiscoveryClient {
-        DiscoveryClient { client, region }
+        DiscoveryClient {
+            client,
+            region: region_for_service(region, "Application Discovery Service"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> DiscoveryClient {
+        DiscoveryClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Application Discovery Service"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> DiscoveryClient {
+        DiscoveryClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -2983,6 +3502,7 @@ impl Discovery for DiscoveryClient {
         RusotoError<AssociateConfigurationItemsToApplicationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateConfigurationItemsToApplication");
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.AssociateConfigurationItemsToApplication",
@@ -3008,6 +3528,7 @@ impl Discovery for DiscoveryClient {
         input: BatchDeleteImportDataRequest,
     ) -> Result<BatchDeleteImportDataResponse, RusotoError<BatchDeleteImportDataError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchDeleteImportData");
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.BatchDeleteImportData",
@@ -3030,6 +3551,7 @@ impl Discovery for DiscoveryClient {
         input: CreateApplicationRequest,
     ) -> Result<CreateApplicationResponse, RusotoError<CreateApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateApplication");
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.CreateApplication",
@@ -3051,6 +3573,7 @@ impl Discovery for DiscoveryClient {
         input: CreateTagsRequest,
     ) -> Result<CreateTagsResponse, RusotoError<CreateTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateTags");
         request.add_header("x-amz-target", "AWSPoseidonService_V2015_11_01.CreateTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3069,6 +3592,7 @@ impl Discovery for DiscoveryClient {
         input: DeleteApplicationsRequest,
     ) -> Result<DeleteApplicationsResponse, RusotoError<DeleteApplicationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteApplications");
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.DeleteApplications",
@@ -3090,6 +3614,7 @@ impl Discovery for DiscoveryClient {
         input: DeleteTagsRequest,
     ) -> Result<DeleteTagsResponse, RusotoError<DeleteTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteTags");
         request.add_header("x-amz-target", "AWSPoseidonService_V2015_11_01.DeleteTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3108,6 +3633,7 @@ impl Discovery for DiscoveryClient {
         input: DescribeAgentsRequest,
     ) -> Result<DescribeAgentsResponse, RusotoError<DescribeAgentsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeAgents");
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.DescribeAgents",
@@ -3129,6 +3655,7 @@ impl Discovery for DiscoveryClient {
         input: DescribeConfigurationsRequest,
     ) -> Result<DescribeConfigurationsResponse, RusotoError<DescribeConfigurationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeConfigurations");
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.DescribeConfigurations",
@@ -3152,6 +3679,7 @@ impl Discovery for DiscoveryClient {
     ) -> Result<DescribeContinuousExportsResponse, RusotoError<DescribeContinuousExportsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeContinuousExports");
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.DescribeContinuousExports",
@@ -3175,6 +3703,7 @@ impl Discovery for DiscoveryClient {
     ) -> Result<DescribeExportConfigurationsResponse, RusotoError<DescribeExportConfigurationsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeExportConfigurations");
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.DescribeExportConfigurations",
@@ -3197,6 +3726,7 @@ impl Discovery for DiscoveryClient {
         input: DescribeExportTasksRequest,
     ) -> Result<DescribeExportTasksResponse, RusotoError<DescribeExportTasksError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeExportTasks");
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.DescribeExportTasks",
@@ -3218,6 +3748,7 @@ impl Discovery for DiscoveryClient {
         input: DescribeImportTasksRequest,
     ) -> Result<DescribeImportTasksResponse, RusotoError<DescribeImportTasksError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeImportTasks");
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.DescribeImportTasks",
@@ -3239,6 +3770,7 @@ impl Discovery for DiscoveryClient {
         input: DescribeTagsRequest,
     ) -> Result<DescribeTagsResponse, RusotoError<DescribeTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeTags");
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.DescribeTags",
@@ -3263,6 +3795,7 @@ impl Discovery for DiscoveryClient {
         RusotoError<DisassociateConfigurationItemsFromApplicationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisassociateConfigurationItemsFromApplication");
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.DisassociateConfigurationItemsFromApplication",
@@ -3287,6 +3820,7 @@ impl Discovery for DiscoveryClient {
         &self,
     ) -> Result<ExportConfigurationsResponse, RusotoError<ExportConfigurationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ExportConfigurations");
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.ExportConfigurations",
@@ -3307,6 +3841,7 @@ impl Discovery for DiscoveryClient {
         &self,
     ) -> Result<GetDiscoverySummaryResponse, RusotoError<GetDiscoverySummaryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDiscoverySummary");
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.GetDiscoverySummary",
@@ -3327,6 +3862,7 @@ impl Discovery for DiscoveryClient {
         input: ListConfigurationsRequest,
     ) -> Result<ListConfigurationsResponse, RusotoError<ListConfigurationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListConfigurations");
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.ListConfigurations",
@@ -3348,6 +3884,7 @@ impl Discovery for DiscoveryClient {
         input: ListServerNeighborsRequest,
     ) -> Result<ListServerNeighborsResponse, RusotoError<ListServerNeighborsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListServerNeighbors");
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.ListServerNeighbors",
@@ -3368,6 +3905,7 @@ impl Discovery for DiscoveryClient {
         &self,
     ) -> Result<StartContinuousExportResponse, RusotoError<StartContinuousExportError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartContinuousExport");
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.StartContinuousExport",
@@ -3392,6 +3930,7 @@ impl Discovery for DiscoveryClient {
         RusotoError<StartDataCollectionByAgentIdsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartDataCollectionByAgentIds");
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.StartDataCollectionByAgentIds",
@@ -3414,6 +3953,7 @@ impl Discovery for DiscoveryClient {
         input: StartExportTaskRequest,
     ) -> Result<StartExportTaskResponse, RusotoError<StartExportTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartExportTask");
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.StartExportTask",
@@ -3435,6 +3975,7 @@ impl Discovery for DiscoveryClient {
         input: StartImportTaskRequest,
     ) -> Result<StartImportTaskResponse, RusotoError<StartImportTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartImportTask");
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.StartImportTask",
@@ -3456,6 +3997,7 @@ impl Discovery for DiscoveryClient {
         input: StopContinuousExportRequest,
     ) -> Result<StopContinuousExportResponse, RusotoError<StopContinuousExportError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopContinuousExport");
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.StopContinuousExport",
@@ -3479,6 +4021,7 @@ impl Discovery for DiscoveryClient {
     ) -> Result<StopDataCollectionByAgentIdsResponse, RusotoError<StopDataCollectionByAgentIdsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopDataCollectionByAgentIds");
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.StopDataCollectionByAgentIds",
@@ -3501,6 +4044,7 @@ impl Discovery for DiscoveryClient {
         input: UpdateApplicationRequest,
     ) -> Result<UpdateApplicationResponse, RusotoError<UpdateApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateApplication");
         request.add_header(
             "x-amz-target",
             "AWSPoseidonService_V2015_11_01.UpdateApplication",
@@ -3516,3 +4060,305 @@ impl Discovery for DiscoveryClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateApplicationResponse, _>()
     }
 }
+
+/// A blocking client for the AWS Application Discovery Service API, running the calls of a `DiscoveryClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `DiscoveryClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct DiscoveryBlockingClient {
+    client: DiscoveryClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl DiscoveryBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> DiscoveryBlockingClient {
+        DiscoveryBlockingClient::from_client(DiscoveryClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> DiscoveryBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        DiscoveryBlockingClient::from_client(DiscoveryClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> DiscoveryBlockingClient {
+        DiscoveryBlockingClient::from_client(DiscoveryClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> DiscoveryBlockingClient {
+        DiscoveryBlockingClient::from_client(DiscoveryClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: DiscoveryClient) -> DiscoveryBlockingClient {
+        DiscoveryBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> DiscoveryBlockingClient {
+        DiscoveryBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> DiscoveryBlockingClient {
+        DiscoveryBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Discovery::associate_configuration_items_to_application`].
+    pub fn associate_configuration_items_to_application(
+        &self,
+        input: AssociateConfigurationItemsToApplicationRequest,
+    ) -> Result<
+        AssociateConfigurationItemsToApplicationResponse,
+        RusotoError<AssociateConfigurationItemsToApplicationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .associate_configuration_items_to_application(input),
+        )
+    }
+
+    /// Blocking version of [`Discovery::batch_delete_import_data`].
+    pub fn batch_delete_import_data(
+        &self,
+        input: BatchDeleteImportDataRequest,
+    ) -> Result<BatchDeleteImportDataResponse, RusotoError<BatchDeleteImportDataError>> {
+        self.runtime
+            .block_on(self.client.batch_delete_import_data(input))
+    }
+
+    /// Blocking version of [`Discovery::create_application`].
+    pub fn create_application(
+        &self,
+        input: CreateApplicationRequest,
+    ) -> Result<CreateApplicationResponse, RusotoError<CreateApplicationError>> {
+        self.runtime.block_on(self.client.create_application(input))
+    }
+
+    /// Blocking version of [`Discovery::create_tags`].
+    pub fn create_tags(
+        &self,
+        input: CreateTagsRequest,
+    ) -> Result<CreateTagsResponse, RusotoError<CreateTagsError>> {
+        self.runtime.block_on(self.client.create_tags(input))
+    }
+
+    /// Blocking version of [`Discovery::delete_applications`].
+    pub fn delete_applications(
+        &self,
+        input: DeleteApplicationsRequest,
+    ) -> Result<DeleteApplicationsResponse, RusotoError<DeleteApplicationsError>> {
+        self.runtime
+            .block_on(self.client.delete_applications(input))
+    }
+
+    /// Blocking version of [`Discovery::delete_tags`].
+    pub fn delete_tags(
+        &self,
+        input: DeleteTagsRequest,
+    ) -> Result<DeleteTagsResponse, RusotoError<DeleteTagsError>> {
+        self.runtime.block_on(self.client.delete_tags(input))
+    }
+
+    /// Blocking version of [`Discovery::describe_agents`].
+    pub fn describe_agents(
+        &self,
+        input: DescribeAgentsRequest,
+    ) -> Result<DescribeAgentsResponse, RusotoError<DescribeAgentsError>> {
+        self.runtime.block_on(self.client.describe_agents(input))
+    }
+
+    /// Blocking version of [`Discovery::describe_configurations`].
+    pub fn describe_configurations(
+        &self,
+        input: DescribeConfigurationsRequest,
+    ) -> Result<DescribeConfigurationsResponse, RusotoError<DescribeConfigurationsError>> {
+        self.runtime
+            .block_on(self.client.describe_configurations(input))
+    }
+
+    /// Blocking version of [`Discovery::describe_continuous_exports`].
+    pub fn describe_continuous_exports(
+        &self,
+        input: DescribeContinuousExportsRequest,
+    ) -> Result<DescribeContinuousExportsResponse, RusotoError<DescribeContinuousExportsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_continuous_exports(input))
+    }
+
+    /// Blocking version of [`Discovery::describe_export_configurations`].
+    pub fn describe_export_configurations(
+        &self,
+        input: DescribeExportConfigurationsRequest,
+    ) -> Result<DescribeExportConfigurationsResponse, RusotoError<DescribeExportConfigurationsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_export_configurations(input))
+    }
+
+    /// Blocking version of [`Discovery::describe_export_tasks`].
+    pub fn describe_export_tasks(
+        &self,
+        input: DescribeExportTasksRequest,
+    ) -> Result<DescribeExportTasksResponse, RusotoError<DescribeExportTasksError>> {
+        self.runtime
+            .block_on(self.client.describe_export_tasks(input))
+    }
+
+    /// Blocking version of [`Discovery::describe_import_tasks`].
+    pub fn describe_import_tasks(
+        &self,
+        input: DescribeImportTasksRequest,
+    ) -> Result<DescribeImportTasksResponse, RusotoError<DescribeImportTasksError>> {
+        self.runtime
+            .block_on(self.client.describe_import_tasks(input))
+    }
+
+    /// Blocking version of [`Discovery::describe_tags`].
+    pub fn describe_tags(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> Result<DescribeTagsResponse, RusotoError<DescribeTagsError>> {
+        self.runtime.block_on(self.client.describe_tags(input))
+    }
+
+    /// Blocking version of [`Discovery::disassociate_configuration_items_from_application`].
+    pub fn disassociate_configuration_items_from_application(
+        &self,
+        input: DisassociateConfigurationItemsFromApplicationRequest,
+    ) -> Result<
+        DisassociateConfigurationItemsFromApplicationResponse,
+        RusotoError<DisassociateConfigurationItemsFromApplicationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .disassociate_configuration_items_from_application(input),
+        )
+    }
+
+    /// Blocking version of [`Discovery::export_configurations`].
+    pub fn export_configurations(
+        &self,
+    ) -> Result<ExportConfigurationsResponse, RusotoError<ExportConfigurationsError>> {
+        self.runtime.block_on(self.client.export_configurations())
+    }
+
+    /// Blocking version of [`Discovery::get_discovery_summary`].
+    pub fn get_discovery_summary(
+        &self,
+    ) -> Result<GetDiscoverySummaryResponse, RusotoError<GetDiscoverySummaryError>> {
+        self.runtime.block_on(self.client.get_discovery_summary())
+    }
+
+    /// Blocking version of [`Discovery::list_configurations`].
+    pub fn list_configurations(
+        &self,
+        input: ListConfigurationsRequest,
+    ) -> Result<ListConfigurationsResponse, RusotoError<ListConfigurationsError>> {
+        self.runtime
+            .block_on(self.client.list_configurations(input))
+    }
+
+    /// Blocking version of [`Discovery::list_server_neighbors`].
+    pub fn list_server_neighbors(
+        &self,
+        input: ListServerNeighborsRequest,
+    ) -> Result<ListServerNeighborsResponse, RusotoError<ListServerNeighborsError>> {
+        self.runtime
+            .block_on(self.client.list_server_neighbors(input))
+    }
+
+    /// Blocking version of [`Discovery::start_continuous_export`].
+    pub fn start_continuous_export(
+        &self,
+    ) -> Result<StartContinuousExportResponse, RusotoError<StartContinuousExportError>> {
+        self.runtime.block_on(self.client.start_continuous_export())
+    }
+
+    /// Blocking version of [`Discovery::start_data_collection_by_agent_ids`].
+    pub fn start_data_collection_by_agent_ids(
+        &self,
+        input: StartDataCollectionByAgentIdsRequest,
+    ) -> Result<
+        StartDataCollectionByAgentIdsResponse,
+        RusotoError<StartDataCollectionByAgentIdsError>,
+    > {
+        self.runtime
+            .block_on(self.client.start_data_collection_by_agent_ids(input))
+    }
+
+    /// Blocking version of [`Discovery::start_export_task`].
+    pub fn start_export_task(
+        &self,
+        input: StartExportTaskRequest,
+    ) -> Result<StartExportTaskResponse, RusotoError<StartExportTaskError>> {
+        self.runtime.block_on(self.client.start_export_task(input))
+    }
+
+    /// Blocking version of [`Discovery::start_import_task`].
+    pub fn start_import_task(
+        &self,
+        input: StartImportTaskRequest,
+    ) -> Result<StartImportTaskResponse, RusotoError<StartImportTaskError>> {
+        self.runtime.block_on(self.client.start_import_task(input))
+    }
+
+    /// Blocking version of [`Discovery::stop_continuous_export`].
+    pub fn stop_continuous_export(
+        &self,
+        input: StopContinuousExportRequest,
+    ) -> Result<StopContinuousExportResponse, RusotoError<StopContinuousExportError>> {
+        self.runtime
+            .block_on(self.client.stop_continuous_export(input))
+    }
+
+    /// Blocking version of [`Discovery::stop_data_collection_by_agent_ids`].
+    pub fn stop_data_collection_by_agent_ids(
+        &self,
+        input: StopDataCollectionByAgentIdsRequest,
+    ) -> Result<StopDataCollectionByAgentIdsResponse, RusotoError<StopDataCollectionByAgentIdsError>>
+    {
+        self.runtime
+            .block_on(self.client.stop_data_collection_by_agent_ids(input))
+    }
+
+    /// Blocking version of [`Discovery::update_application`].
+    pub fn update_application(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> Result<UpdateApplicationResponse, RusotoError<UpdateApplicationError>> {
+        self.runtime.block_on(self.client.update_application(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -3842,11 +3843,14 @@ impl AddTagsToResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(AddTagsToResourceError::ResourceNotFoundFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddTagsToResourceError::ResourceNotFoundFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3876,11 +3880,14 @@ impl ApplyPendingMaintenanceActionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
-                        ApplyPendingMaintenanceActionError::ResourceNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ApplyPendingMaintenanceActionError::ResourceNotFoundFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3916,21 +3923,30 @@ impl CancelReplicationTaskAssessmentRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedFault" => {
-                    return RusotoError::Service(
-                        CancelReplicationTaskAssessmentRunError::AccessDeniedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelReplicationTaskAssessmentRunError::AccessDeniedFault,
                     )
                 }
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
-                        CancelReplicationTaskAssessmentRunError::InvalidResourceStateFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelReplicationTaskAssessmentRunError::InvalidResourceStateFault,
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
-                        CancelReplicationTaskAssessmentRunError::ResourceNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelReplicationTaskAssessmentRunError::ResourceNotFoundFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3978,37 +3994,62 @@ impl CreateEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedFault" => {
-                    return RusotoError::Service(CreateEndpointError::AccessDeniedFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEndpointError::AccessDeniedFault,
+                    )
                 }
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(CreateEndpointError::InvalidResourceStateFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateEndpointError::InvalidResourceStateFault,
+                    )
                 }
                 "KMSKeyNotAccessibleFault" => {
-                    return RusotoError::Service(CreateEndpointError::KMSKeyNotAccessibleFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateEndpointError::KMSKeyNotAccessibleFault,
+                    )
                 }
                 "ResourceAlreadyExistsFault" => {
-                    return RusotoError::Service(CreateEndpointError::ResourceAlreadyExistsFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateEndpointError::ResourceAlreadyExistsFault,
+                    )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(CreateEndpointError::ResourceNotFoundFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateEndpointError::ResourceNotFoundFault,
+                    )
                 }
                 "ResourceQuotaExceededFault" => {
-                    return RusotoError::Service(CreateEndpointError::ResourceQuotaExceededFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateEndpointError::ResourceQuotaExceededFault,
+                    )
                 }
                 "S3AccessDeniedFault" => {
-                    return RusotoError::Service(CreateEndpointError::S3AccessDeniedFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEndpointError::S3AccessDeniedFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4060,56 +4101,86 @@ impl CreateEventSubscriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "KMSAccessDeniedFault" => {
-                    return RusotoError::Service(
-                        CreateEventSubscriptionError::KMSAccessDeniedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEventSubscriptionError::KMSAccessDeniedFault,
                     )
                 }
                 "KMSDisabledFault" => {
-                    return RusotoError::Service(CreateEventSubscriptionError::KMSDisabledFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateEventSubscriptionError::KMSDisabledFault,
+                    )
                 }
                 "KMSInvalidStateFault" => {
-                    return RusotoError::Service(
-                        CreateEventSubscriptionError::KMSInvalidStateFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEventSubscriptionError::KMSInvalidStateFault,
                     )
                 }
                 "KMSNotFoundFault" => {
-                    return RusotoError::Service(CreateEventSubscriptionError::KMSNotFoundFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateEventSubscriptionError::KMSNotFoundFault,
+                    )
                 }
                 "KMSThrottlingFault" => {
-                    return RusotoError::Service(CreateEventSubscriptionError::KMSThrottlingFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateEventSubscriptionError::KMSThrottlingFault,
+                    )
                 }
                 "ResourceAlreadyExistsFault" => {
-                    return RusotoError::Service(
-                        CreateEventSubscriptionError::ResourceAlreadyExistsFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEventSubscriptionError::ResourceAlreadyExistsFault,
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
-                        CreateEventSubscriptionError::ResourceNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEventSubscriptionError::ResourceNotFoundFault,
                     )
                 }
                 "ResourceQuotaExceededFault" => {
-                    return RusotoError::Service(
-                        CreateEventSubscriptionError::ResourceQuotaExceededFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEventSubscriptionError::ResourceQuotaExceededFault,
                     )
                 }
                 "SNSInvalidTopicFault" => {
-                    return RusotoError::Service(
-                        CreateEventSubscriptionError::SNSInvalidTopicFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEventSubscriptionError::SNSInvalidTopicFault,
                     )
                 }
                 "SNSNoAuthorizationFault" => {
-                    return RusotoError::Service(
-                        CreateEventSubscriptionError::SNSNoAuthorizationFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEventSubscriptionError::SNSNoAuthorizationFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4172,58 +4243,86 @@ impl CreateReplicationInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedFault" => {
-                    return RusotoError::Service(CreateReplicationInstanceError::AccessDeniedFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateReplicationInstanceError::AccessDeniedFault,
+                    )
                 }
                 "InsufficientResourceCapacityFault" => {
-                    return RusotoError::Service(
-                        CreateReplicationInstanceError::InsufficientResourceCapacityFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateReplicationInstanceError::InsufficientResourceCapacityFault,
                     )
                 }
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
-                        CreateReplicationInstanceError::InvalidResourceStateFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateReplicationInstanceError::InvalidResourceStateFault,
                     )
                 }
                 "InvalidSubnet" => {
-                    return RusotoError::Service(CreateReplicationInstanceError::InvalidSubnet(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateReplicationInstanceError::InvalidSubnet,
+                    )
                 }
                 "KMSKeyNotAccessibleFault" => {
-                    return RusotoError::Service(
-                        CreateReplicationInstanceError::KMSKeyNotAccessibleFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateReplicationInstanceError::KMSKeyNotAccessibleFault,
                     )
                 }
                 "ReplicationSubnetGroupDoesNotCoverEnoughAZs" => {
-                    return RusotoError::Service(
-                        CreateReplicationInstanceError::ReplicationSubnetGroupDoesNotCoverEnoughAZs(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateReplicationInstanceError::ReplicationSubnetGroupDoesNotCoverEnoughAZs,
                     )
                 }
                 "ResourceAlreadyExistsFault" => {
-                    return RusotoError::Service(
-                        CreateReplicationInstanceError::ResourceAlreadyExistsFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateReplicationInstanceError::ResourceAlreadyExistsFault,
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
-                        CreateReplicationInstanceError::ResourceNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateReplicationInstanceError::ResourceNotFoundFault,
                     )
                 }
                 "ResourceQuotaExceededFault" => {
-                    return RusotoError::Service(
-                        CreateReplicationInstanceError::ResourceQuotaExceededFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateReplicationInstanceError::ResourceQuotaExceededFault,
                     )
                 }
                 "StorageQuotaExceededFault" => {
-                    return RusotoError::Service(
-                        CreateReplicationInstanceError::StorageQuotaExceededFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateReplicationInstanceError::StorageQuotaExceededFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4288,36 +4387,52 @@ impl CreateReplicationSubnetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedFault" => {
-                    return RusotoError::Service(
-                        CreateReplicationSubnetGroupError::AccessDeniedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateReplicationSubnetGroupError::AccessDeniedFault,
                     )
                 }
                 "InvalidSubnet" => {
-                    return RusotoError::Service(CreateReplicationSubnetGroupError::InvalidSubnet(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateReplicationSubnetGroupError::InvalidSubnet,
+                    )
                 }
-                "ReplicationSubnetGroupDoesNotCoverEnoughAZs" => return RusotoError::Service(
-                    CreateReplicationSubnetGroupError::ReplicationSubnetGroupDoesNotCoverEnoughAZs(
-                        err.msg,
-                    ),
+                "ReplicationSubnetGroupDoesNotCoverEnoughAZs" => return RusotoError::service_error(
+                    &res,
+                    &err.typ,
+                    err.msg,
+                    CreateReplicationSubnetGroupError::ReplicationSubnetGroupDoesNotCoverEnoughAZs,
                 ),
                 "ResourceAlreadyExistsFault" => {
-                    return RusotoError::Service(
-                        CreateReplicationSubnetGroupError::ResourceAlreadyExistsFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateReplicationSubnetGroupError::ResourceAlreadyExistsFault,
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
-                        CreateReplicationSubnetGroupError::ResourceNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateReplicationSubnetGroupError::ResourceNotFoundFault,
                     )
                 }
                 "ResourceQuotaExceededFault" => {
-                    return RusotoError::Service(
-                        CreateReplicationSubnetGroupError::ResourceQuotaExceededFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateReplicationSubnetGroupError::ResourceQuotaExceededFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4370,36 +4485,54 @@ impl CreateReplicationTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedFault" => {
-                    return RusotoError::Service(CreateReplicationTaskError::AccessDeniedFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateReplicationTaskError::AccessDeniedFault,
+                    )
                 }
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
-                        CreateReplicationTaskError::InvalidResourceStateFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateReplicationTaskError::InvalidResourceStateFault,
                     )
                 }
                 "KMSKeyNotAccessibleFault" => {
-                    return RusotoError::Service(
-                        CreateReplicationTaskError::KMSKeyNotAccessibleFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateReplicationTaskError::KMSKeyNotAccessibleFault,
                     )
                 }
                 "ResourceAlreadyExistsFault" => {
-                    return RusotoError::Service(
-                        CreateReplicationTaskError::ResourceAlreadyExistsFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateReplicationTaskError::ResourceAlreadyExistsFault,
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(CreateReplicationTaskError::ResourceNotFoundFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateReplicationTaskError::ResourceNotFoundFault,
+                    )
                 }
                 "ResourceQuotaExceededFault" => {
-                    return RusotoError::Service(
-                        CreateReplicationTaskError::ResourceQuotaExceededFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateReplicationTaskError::ResourceQuotaExceededFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4442,16 +4575,22 @@ impl DeleteCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(DeleteCertificateError::InvalidResourceStateFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteCertificateError::InvalidResourceStateFault,
+                    )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(DeleteCertificateError::ResourceNotFoundFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteCertificateError::ResourceNotFoundFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4484,19 +4623,30 @@ impl DeleteConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedFault" => {
-                    return RusotoError::Service(DeleteConnectionError::AccessDeniedFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteConnectionError::AccessDeniedFault,
+                    )
                 }
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(DeleteConnectionError::InvalidResourceStateFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteConnectionError::InvalidResourceStateFault,
+                    )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(DeleteConnectionError::ResourceNotFoundFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteConnectionError::ResourceNotFoundFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4528,16 +4678,22 @@ impl DeleteEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(DeleteEndpointError::InvalidResourceStateFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteEndpointError::InvalidResourceStateFault,
+                    )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(DeleteEndpointError::ResourceNotFoundFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteEndpointError::ResourceNotFoundFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4568,16 +4724,22 @@ impl DeleteEventSubscriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
-                        DeleteEventSubscriptionError::InvalidResourceStateFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEventSubscriptionError::InvalidResourceStateFault,
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
-                        DeleteEventSubscriptionError::ResourceNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEventSubscriptionError::ResourceNotFoundFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4612,16 +4774,22 @@ impl DeleteReplicationInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
-                        DeleteReplicationInstanceError::InvalidResourceStateFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteReplicationInstanceError::InvalidResourceStateFault,
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
-                        DeleteReplicationInstanceError::ResourceNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteReplicationInstanceError::ResourceNotFoundFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4658,16 +4826,22 @@ impl DeleteReplicationSubnetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
-                        DeleteReplicationSubnetGroupError::InvalidResourceStateFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteReplicationSubnetGroupError::InvalidResourceStateFault,
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
-                        DeleteReplicationSubnetGroupError::ResourceNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteReplicationSubnetGroupError::ResourceNotFoundFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4702,16 +4876,22 @@ impl DeleteReplicationTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
-                        DeleteReplicationTaskError::InvalidResourceStateFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteReplicationTaskError::InvalidResourceStateFault,
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(DeleteReplicationTaskError::ResourceNotFoundFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteReplicationTaskError::ResourceNotFoundFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4748,21 +4928,30 @@ impl DeleteReplicationTaskAssessmentRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedFault" => {
-                    return RusotoError::Service(
-                        DeleteReplicationTaskAssessmentRunError::AccessDeniedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteReplicationTaskAssessmentRunError::AccessDeniedFault,
                     )
                 }
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
-                        DeleteReplicationTaskAssessmentRunError::InvalidResourceStateFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteReplicationTaskAssessmentRunError::InvalidResourceStateFault,
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
-                        DeleteReplicationTaskAssessmentRunError::ResourceNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteReplicationTaskAssessmentRunError::ResourceNotFoundFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4794,7 +4983,7 @@ impl DescribeAccountAttributesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeAccountAttributesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4826,25 +5015,30 @@ impl DescribeApplicableIndividualAssessmentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedFault" => {
-                    return RusotoError::Service(
-                        DescribeApplicableIndividualAssessmentsError::AccessDeniedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeApplicableIndividualAssessmentsError::AccessDeniedFault,
                     )
                 }
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
-                        DescribeApplicableIndividualAssessmentsError::InvalidResourceStateFault(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeApplicableIndividualAssessmentsError::InvalidResourceStateFault,
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
-                        DescribeApplicableIndividualAssessmentsError::ResourceNotFoundFault(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeApplicableIndividualAssessmentsError::ResourceNotFoundFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4880,11 +5074,14 @@ impl DescribeCertificatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(DescribeCertificatesError::ResourceNotFoundFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeCertificatesError::ResourceNotFoundFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4912,11 +5109,14 @@ impl DescribeConnectionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(DescribeConnectionsError::ResourceNotFoundFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeConnectionsError::ResourceNotFoundFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4940,7 +5140,7 @@ impl DescribeEndpointTypesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeEndpointTypesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4966,11 +5166,14 @@ impl DescribeEndpointsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(DescribeEndpointsError::ResourceNotFoundFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEndpointsError::ResourceNotFoundFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4994,7 +5197,7 @@ impl DescribeEventCategoriesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeEventCategoriesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5022,11 +5225,14 @@ impl DescribeEventSubscriptionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
-                        DescribeEventSubscriptionsError::ResourceNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEventSubscriptionsError::ResourceNotFoundFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5052,7 +5258,7 @@ impl DescribeEventsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeEventsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5076,7 +5282,7 @@ impl DescribeOrderableReplicationInstancesError {
     ) -> RusotoError<DescribeOrderableReplicationInstancesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5104,11 +5310,14 @@ impl DescribePendingMaintenanceActionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
-                        DescribePendingMaintenanceActionsError::ResourceNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePendingMaintenanceActionsError::ResourceNotFoundFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5142,16 +5351,22 @@ impl DescribeRefreshSchemasStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
-                        DescribeRefreshSchemasStatusError::InvalidResourceStateFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRefreshSchemasStatusError::InvalidResourceStateFault,
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
-                        DescribeRefreshSchemasStatusError::ResourceNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRefreshSchemasStatusError::ResourceNotFoundFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5188,18 +5403,22 @@ impl DescribeReplicationInstanceTaskLogsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
-                        DescribeReplicationInstanceTaskLogsError::InvalidResourceStateFault(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeReplicationInstanceTaskLogsError::InvalidResourceStateFault,
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
-                        DescribeReplicationInstanceTaskLogsError::ResourceNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeReplicationInstanceTaskLogsError::ResourceNotFoundFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5234,11 +5453,14 @@ impl DescribeReplicationInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
-                        DescribeReplicationInstancesError::ResourceNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeReplicationInstancesError::ResourceNotFoundFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5270,11 +5492,14 @@ impl DescribeReplicationSubnetGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
-                        DescribeReplicationSubnetGroupsError::ResourceNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeReplicationSubnetGroupsError::ResourceNotFoundFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5306,13 +5531,14 @@ impl DescribeReplicationTaskAssessmentResultsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
-                        DescribeReplicationTaskAssessmentResultsError::ResourceNotFoundFault(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeReplicationTaskAssessmentResultsError::ResourceNotFoundFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5344,11 +5570,14 @@ impl DescribeReplicationTaskAssessmentRunsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
-                        DescribeReplicationTaskAssessmentRunsError::ResourceNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeReplicationTaskAssessmentRunsError::ResourceNotFoundFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5380,13 +5609,14 @@ impl DescribeReplicationTaskIndividualAssessmentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
-                        DescribeReplicationTaskIndividualAssessmentsError::ResourceNotFoundFault(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeReplicationTaskIndividualAssessmentsError::ResourceNotFoundFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5416,11 +5646,14 @@ impl DescribeReplicationTasksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
-                        DescribeReplicationTasksError::ResourceNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeReplicationTasksError::ResourceNotFoundFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5452,16 +5685,22 @@ impl DescribeSchemasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(DescribeSchemasError::InvalidResourceStateFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeSchemasError::InvalidResourceStateFault,
+                    )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(DescribeSchemasError::ResourceNotFoundFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeSchemasError::ResourceNotFoundFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5492,16 +5731,22 @@ impl DescribeTableStatisticsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
-                        DescribeTableStatisticsError::InvalidResourceStateFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTableStatisticsError::InvalidResourceStateFault,
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
-                        DescribeTableStatisticsError::ResourceNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTableStatisticsError::ResourceNotFoundFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5538,21 +5783,30 @@ impl ImportCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidCertificateFault" => {
-                    return RusotoError::Service(ImportCertificateError::InvalidCertificateFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ImportCertificateError::InvalidCertificateFault,
+                    )
                 }
                 "ResourceAlreadyExistsFault" => {
-                    return RusotoError::Service(
-                        ImportCertificateError::ResourceAlreadyExistsFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportCertificateError::ResourceAlreadyExistsFault,
                     )
                 }
                 "ResourceQuotaExceededFault" => {
-                    return RusotoError::Service(
-                        ImportCertificateError::ResourceQuotaExceededFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportCertificateError::ResourceQuotaExceededFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5582,11 +5836,14 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFoundFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFoundFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5622,29 +5879,46 @@ impl ModifyEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedFault" => {
-                    return RusotoError::Service(ModifyEndpointError::AccessDeniedFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyEndpointError::AccessDeniedFault,
+                    )
                 }
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(ModifyEndpointError::InvalidResourceStateFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ModifyEndpointError::InvalidResourceStateFault,
+                    )
                 }
                 "KMSKeyNotAccessibleFault" => {
-                    return RusotoError::Service(ModifyEndpointError::KMSKeyNotAccessibleFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ModifyEndpointError::KMSKeyNotAccessibleFault,
+                    )
                 }
                 "ResourceAlreadyExistsFault" => {
-                    return RusotoError::Service(ModifyEndpointError::ResourceAlreadyExistsFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ModifyEndpointError::ResourceAlreadyExistsFault,
+                    )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(ModifyEndpointError::ResourceNotFoundFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ModifyEndpointError::ResourceNotFoundFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5692,51 +5966,78 @@ impl ModifyEventSubscriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "KMSAccessDeniedFault" => {
-                    return RusotoError::Service(
-                        ModifyEventSubscriptionError::KMSAccessDeniedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyEventSubscriptionError::KMSAccessDeniedFault,
                     )
                 }
                 "KMSDisabledFault" => {
-                    return RusotoError::Service(ModifyEventSubscriptionError::KMSDisabledFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ModifyEventSubscriptionError::KMSDisabledFault,
+                    )
                 }
                 "KMSInvalidStateFault" => {
-                    return RusotoError::Service(
-                        ModifyEventSubscriptionError::KMSInvalidStateFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyEventSubscriptionError::KMSInvalidStateFault,
                     )
                 }
                 "KMSNotFoundFault" => {
-                    return RusotoError::Service(ModifyEventSubscriptionError::KMSNotFoundFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ModifyEventSubscriptionError::KMSNotFoundFault,
+                    )
                 }
                 "KMSThrottlingFault" => {
-                    return RusotoError::Service(ModifyEventSubscriptionError::KMSThrottlingFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ModifyEventSubscriptionError::KMSThrottlingFault,
+                    )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
-                        ModifyEventSubscriptionError::ResourceNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyEventSubscriptionError::ResourceNotFoundFault,
                     )
                 }
                 "ResourceQuotaExceededFault" => {
-                    return RusotoError::Service(
-                        ModifyEventSubscriptionError::ResourceQuotaExceededFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyEventSubscriptionError::ResourceQuotaExceededFault,
                     )
                 }
                 "SNSInvalidTopicFault" => {
-                    return RusotoError::Service(
-                        ModifyEventSubscriptionError::SNSInvalidTopicFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyEventSubscriptionError::SNSInvalidTopicFault,
                     )
                 }
                 "SNSNoAuthorizationFault" => {
-                    return RusotoError::Service(
-                        ModifyEventSubscriptionError::SNSNoAuthorizationFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyEventSubscriptionError::SNSNoAuthorizationFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5790,41 +6091,62 @@ impl ModifyReplicationInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedFault" => {
-                    return RusotoError::Service(ModifyReplicationInstanceError::AccessDeniedFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ModifyReplicationInstanceError::AccessDeniedFault,
+                    )
                 }
                 "InsufficientResourceCapacityFault" => {
-                    return RusotoError::Service(
-                        ModifyReplicationInstanceError::InsufficientResourceCapacityFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyReplicationInstanceError::InsufficientResourceCapacityFault,
                     )
                 }
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
-                        ModifyReplicationInstanceError::InvalidResourceStateFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyReplicationInstanceError::InvalidResourceStateFault,
                     )
                 }
                 "ResourceAlreadyExistsFault" => {
-                    return RusotoError::Service(
-                        ModifyReplicationInstanceError::ResourceAlreadyExistsFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyReplicationInstanceError::ResourceAlreadyExistsFault,
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
-                        ModifyReplicationInstanceError::ResourceNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyReplicationInstanceError::ResourceNotFoundFault,
                     )
                 }
                 "StorageQuotaExceededFault" => {
-                    return RusotoError::Service(
-                        ModifyReplicationInstanceError::StorageQuotaExceededFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyReplicationInstanceError::StorageQuotaExceededFault,
                     )
                 }
                 "UpgradeDependencyFailureFault" => {
-                    return RusotoError::Service(
-                        ModifyReplicationInstanceError::UpgradeDependencyFailureFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyReplicationInstanceError::UpgradeDependencyFailureFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5882,36 +6204,52 @@ impl ModifyReplicationSubnetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedFault" => {
-                    return RusotoError::Service(
-                        ModifyReplicationSubnetGroupError::AccessDeniedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyReplicationSubnetGroupError::AccessDeniedFault,
                     )
                 }
                 "InvalidSubnet" => {
-                    return RusotoError::Service(ModifyReplicationSubnetGroupError::InvalidSubnet(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ModifyReplicationSubnetGroupError::InvalidSubnet,
+                    )
                 }
-                "ReplicationSubnetGroupDoesNotCoverEnoughAZs" => return RusotoError::Service(
-                    ModifyReplicationSubnetGroupError::ReplicationSubnetGroupDoesNotCoverEnoughAZs(
-                        err.msg,
-                    ),
+                "ReplicationSubnetGroupDoesNotCoverEnoughAZs" => return RusotoError::service_error(
+                    &res,
+                    &err.typ,
+                    err.msg,
+                    ModifyReplicationSubnetGroupError::ReplicationSubnetGroupDoesNotCoverEnoughAZs,
                 ),
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
-                        ModifyReplicationSubnetGroupError::ResourceNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyReplicationSubnetGroupError::ResourceNotFoundFault,
                     )
                 }
                 "ResourceQuotaExceededFault" => {
-                    return RusotoError::Service(
-                        ModifyReplicationSubnetGroupError::ResourceQuotaExceededFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyReplicationSubnetGroupError::ResourceQuotaExceededFault,
                     )
                 }
                 "SubnetAlreadyInUse" => {
-                    return RusotoError::Service(
-                        ModifyReplicationSubnetGroupError::SubnetAlreadyInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyReplicationSubnetGroupError::SubnetAlreadyInUse,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5960,26 +6298,38 @@ impl ModifyReplicationTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
-                        ModifyReplicationTaskError::InvalidResourceStateFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyReplicationTaskError::InvalidResourceStateFault,
                     )
                 }
                 "KMSKeyNotAccessibleFault" => {
-                    return RusotoError::Service(
-                        ModifyReplicationTaskError::KMSKeyNotAccessibleFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyReplicationTaskError::KMSKeyNotAccessibleFault,
                     )
                 }
                 "ResourceAlreadyExistsFault" => {
-                    return RusotoError::Service(
-                        ModifyReplicationTaskError::ResourceAlreadyExistsFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyReplicationTaskError::ResourceAlreadyExistsFault,
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(ModifyReplicationTaskError::ResourceNotFoundFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ModifyReplicationTaskError::ResourceNotFoundFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6020,21 +6370,30 @@ impl MoveReplicationTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedFault" => {
-                    return RusotoError::Service(MoveReplicationTaskError::AccessDeniedFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MoveReplicationTaskError::AccessDeniedFault,
+                    )
                 }
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
-                        MoveReplicationTaskError::InvalidResourceStateFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MoveReplicationTaskError::InvalidResourceStateFault,
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(MoveReplicationTaskError::ResourceNotFoundFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MoveReplicationTaskError::ResourceNotFoundFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6068,16 +6427,22 @@ impl RebootReplicationInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
-                        RebootReplicationInstanceError::InvalidResourceStateFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RebootReplicationInstanceError::InvalidResourceStateFault,
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
-                        RebootReplicationInstanceError::ResourceNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RebootReplicationInstanceError::ResourceNotFoundFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6116,26 +6481,38 @@ impl RefreshSchemasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(RefreshSchemasError::InvalidResourceStateFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RefreshSchemasError::InvalidResourceStateFault,
+                    )
                 }
                 "KMSKeyNotAccessibleFault" => {
-                    return RusotoError::Service(RefreshSchemasError::KMSKeyNotAccessibleFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RefreshSchemasError::KMSKeyNotAccessibleFault,
+                    )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(RefreshSchemasError::ResourceNotFoundFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RefreshSchemasError::ResourceNotFoundFault,
+                    )
                 }
                 "ResourceQuotaExceededFault" => {
-                    return RusotoError::Service(RefreshSchemasError::ResourceQuotaExceededFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RefreshSchemasError::ResourceQuotaExceededFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6168,14 +6545,22 @@ impl ReloadTablesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(ReloadTablesError::InvalidResourceStateFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ReloadTablesError::InvalidResourceStateFault,
+                    )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(ReloadTablesError::ResourceNotFoundFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReloadTablesError::ResourceNotFoundFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6204,11 +6589,14 @@ impl RemoveTagsFromResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
-                        RemoveTagsFromResourceError::ResourceNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveTagsFromResourceError::ResourceNotFoundFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6240,21 +6628,30 @@ impl StartReplicationTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedFault" => {
-                    return RusotoError::Service(StartReplicationTaskError::AccessDeniedFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartReplicationTaskError::AccessDeniedFault,
+                    )
                 }
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
-                        StartReplicationTaskError::InvalidResourceStateFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartReplicationTaskError::InvalidResourceStateFault,
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(StartReplicationTaskError::ResourceNotFoundFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartReplicationTaskError::ResourceNotFoundFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6290,16 +6687,22 @@ impl StartReplicationTaskAssessmentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
-                        StartReplicationTaskAssessmentError::InvalidResourceStateFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartReplicationTaskAssessmentError::InvalidResourceStateFault,
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
-                        StartReplicationTaskAssessmentError::ResourceNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartReplicationTaskAssessmentError::ResourceNotFoundFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6356,66 +6759,102 @@ impl StartReplicationTaskAssessmentRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedFault" => {
-                    return RusotoError::Service(
-                        StartReplicationTaskAssessmentRunError::AccessDeniedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartReplicationTaskAssessmentRunError::AccessDeniedFault,
                     )
                 }
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
-                        StartReplicationTaskAssessmentRunError::InvalidResourceStateFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartReplicationTaskAssessmentRunError::InvalidResourceStateFault,
                     )
                 }
                 "KMSAccessDeniedFault" => {
-                    return RusotoError::Service(
-                        StartReplicationTaskAssessmentRunError::KMSAccessDeniedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartReplicationTaskAssessmentRunError::KMSAccessDeniedFault,
                     )
                 }
                 "KMSDisabledFault" => {
-                    return RusotoError::Service(
-                        StartReplicationTaskAssessmentRunError::KMSDisabledFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartReplicationTaskAssessmentRunError::KMSDisabledFault,
                     )
                 }
                 "KMSFault" => {
-                    return RusotoError::Service(StartReplicationTaskAssessmentRunError::KMSFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartReplicationTaskAssessmentRunError::KMSFault,
+                    )
                 }
                 "KMSInvalidStateFault" => {
-                    return RusotoError::Service(
-                        StartReplicationTaskAssessmentRunError::KMSInvalidStateFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartReplicationTaskAssessmentRunError::KMSInvalidStateFault,
                     )
                 }
                 "KMSKeyNotAccessibleFault" => {
-                    return RusotoError::Service(
-                        StartReplicationTaskAssessmentRunError::KMSKeyNotAccessibleFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartReplicationTaskAssessmentRunError::KMSKeyNotAccessibleFault,
                     )
                 }
                 "KMSNotFoundFault" => {
-                    return RusotoError::Service(
-                        StartReplicationTaskAssessmentRunError::KMSNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartReplicationTaskAssessmentRunError::KMSNotFoundFault,
                     )
                 }
                 "ResourceAlreadyExistsFault" => {
-                    return RusotoError::Service(
-                        StartReplicationTaskAssessmentRunError::ResourceAlreadyExistsFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartReplicationTaskAssessmentRunError::ResourceAlreadyExistsFault,
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(
-                        StartReplicationTaskAssessmentRunError::ResourceNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartReplicationTaskAssessmentRunError::ResourceNotFoundFault,
                     )
                 }
                 "S3AccessDeniedFault" => {
-                    return RusotoError::Service(
-                        StartReplicationTaskAssessmentRunError::S3AccessDeniedFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartReplicationTaskAssessmentRunError::S3AccessDeniedFault,
                     )
                 }
                 "S3ResourceNotFoundFault" => {
-                    return RusotoError::Service(
-                        StartReplicationTaskAssessmentRunError::S3ResourceNotFoundFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartReplicationTaskAssessmentRunError::S3ResourceNotFoundFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6478,16 +6917,22 @@ impl StopReplicationTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(
-                        StopReplicationTaskError::InvalidResourceStateFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopReplicationTaskError::InvalidResourceStateFault,
                     )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(StopReplicationTaskError::ResourceNotFoundFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopReplicationTaskError::ResourceNotFoundFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6524,26 +6969,38 @@ impl TestConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidResourceStateFault" => {
-                    return RusotoError::Service(TestConnectionError::InvalidResourceStateFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TestConnectionError::InvalidResourceStateFault,
+                    )
                 }
                 "KMSKeyNotAccessibleFault" => {
-                    return RusotoError::Service(TestConnectionError::KMSKeyNotAccessibleFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TestConnectionError::KMSKeyNotAccessibleFault,
+                    )
                 }
                 "ResourceNotFoundFault" => {
-                    return RusotoError::Service(TestConnectionError::ResourceNotFoundFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TestConnectionError::ResourceNotFoundFault,
+                    )
                 }
                 "ResourceQuotaExceededFault" => {
-                    return RusotoError::Service(TestConnectionError::ResourceQuotaExceededFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TestConnectionError::ResourceQuotaExceededFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6937,11 +7394,12 @@ pub struct DatabaseMigrationServiceClient {
 impl DatabaseMigrationServiceClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> DatabaseMigrationServiceClient {
         DatabaseMigrationServiceClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Database Migration Service"),
         }
     }
 
@@ -6956,7 +7414,7 @@ impl DatabaseMigrationServiceClient {
     {
         DatabaseMigrationServiceClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Database Migration Service"),
         }
     }
 
@@ -6964,7 +7422,27 @@ impl DatabaseMigrationServiceClient {
         client: Client,
         region: region::Region,
     ) -> DatabaseMigrationServiceClient {
-        DatabaseMigrationServiceClient { client, region }
+        DatabaseMigrationServiceClient {
+            client,
+            region: region_for_service(region, "Database Migration Service"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> DatabaseMigrationServiceClient {
+        DatabaseMigrationServiceClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Database Migration Service"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> DatabaseMigrationServiceClient {
+        DatabaseMigrationServiceClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -6976,6 +7454,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: AddTagsToResourceMessage,
     ) -> Result<AddTagsToResourceResponse, RusotoError<AddTagsToResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AddTagsToResource");
         request.add_header("x-amz-target", "AmazonDMSv20160101.AddTagsToResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6997,6 +7476,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         RusotoError<ApplyPendingMaintenanceActionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ApplyPendingMaintenanceAction");
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.ApplyPendingMaintenanceAction",
@@ -7022,6 +7502,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         RusotoError<CancelReplicationTaskAssessmentRunError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CancelReplicationTaskAssessmentRun");
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.CancelReplicationTaskAssessmentRun",
@@ -7047,6 +7528,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: CreateEndpointMessage,
     ) -> Result<CreateEndpointResponse, RusotoError<CreateEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateEndpoint");
         request.add_header("x-amz-target", "AmazonDMSv20160101.CreateEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7065,6 +7547,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: CreateEventSubscriptionMessage,
     ) -> Result<CreateEventSubscriptionResponse, RusotoError<CreateEventSubscriptionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateEventSubscription");
         request.add_header("x-amz-target", "AmazonDMSv20160101.CreateEventSubscription");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7085,6 +7568,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
     ) -> Result<CreateReplicationInstanceResponse, RusotoError<CreateReplicationInstanceError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateReplicationInstance");
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.CreateReplicationInstance",
@@ -7108,6 +7592,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
     ) -> Result<CreateReplicationSubnetGroupResponse, RusotoError<CreateReplicationSubnetGroupError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateReplicationSubnetGroup");
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.CreateReplicationSubnetGroup",
@@ -7130,6 +7615,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: CreateReplicationTaskMessage,
     ) -> Result<CreateReplicationTaskResponse, RusotoError<CreateReplicationTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateReplicationTask");
         request.add_header("x-amz-target", "AmazonDMSv20160101.CreateReplicationTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7149,6 +7635,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DeleteCertificateMessage,
     ) -> Result<DeleteCertificateResponse, RusotoError<DeleteCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteCertificate");
         request.add_header("x-amz-target", "AmazonDMSv20160101.DeleteCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7167,6 +7654,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DeleteConnectionMessage,
     ) -> Result<DeleteConnectionResponse, RusotoError<DeleteConnectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteConnection");
         request.add_header("x-amz-target", "AmazonDMSv20160101.DeleteConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7185,6 +7673,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DeleteEndpointMessage,
     ) -> Result<DeleteEndpointResponse, RusotoError<DeleteEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteEndpoint");
         request.add_header("x-amz-target", "AmazonDMSv20160101.DeleteEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7203,6 +7692,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DeleteEventSubscriptionMessage,
     ) -> Result<DeleteEventSubscriptionResponse, RusotoError<DeleteEventSubscriptionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteEventSubscription");
         request.add_header("x-amz-target", "AmazonDMSv20160101.DeleteEventSubscription");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7223,6 +7713,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
     ) -> Result<DeleteReplicationInstanceResponse, RusotoError<DeleteReplicationInstanceError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteReplicationInstance");
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.DeleteReplicationInstance",
@@ -7246,6 +7737,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
     ) -> Result<DeleteReplicationSubnetGroupResponse, RusotoError<DeleteReplicationSubnetGroupError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteReplicationSubnetGroup");
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.DeleteReplicationSubnetGroup",
@@ -7268,6 +7760,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DeleteReplicationTaskMessage,
     ) -> Result<DeleteReplicationTaskResponse, RusotoError<DeleteReplicationTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteReplicationTask");
         request.add_header("x-amz-target", "AmazonDMSv20160101.DeleteReplicationTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7290,6 +7783,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         RusotoError<DeleteReplicationTaskAssessmentRunError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteReplicationTaskAssessmentRun");
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.DeleteReplicationTaskAssessmentRun",
@@ -7315,6 +7809,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
     ) -> Result<DescribeAccountAttributesResponse, RusotoError<DescribeAccountAttributesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeAccountAttributes");
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.DescribeAccountAttributes",
@@ -7339,6 +7834,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         RusotoError<DescribeApplicableIndividualAssessmentsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeApplicableIndividualAssessments");
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.DescribeApplicableIndividualAssessments",
@@ -7364,6 +7860,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DescribeCertificatesMessage,
     ) -> Result<DescribeCertificatesResponse, RusotoError<DescribeCertificatesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeCertificates");
         request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeCertificates");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7383,6 +7880,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DescribeConnectionsMessage,
     ) -> Result<DescribeConnectionsResponse, RusotoError<DescribeConnectionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeConnections");
         request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeConnections");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7401,6 +7899,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DescribeEndpointTypesMessage,
     ) -> Result<DescribeEndpointTypesResponse, RusotoError<DescribeEndpointTypesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeEndpointTypes");
         request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeEndpointTypes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7420,6 +7919,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DescribeEndpointsMessage,
     ) -> Result<DescribeEndpointsResponse, RusotoError<DescribeEndpointsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeEndpoints");
         request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeEndpoints");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7438,6 +7938,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DescribeEventCategoriesMessage,
     ) -> Result<DescribeEventCategoriesResponse, RusotoError<DescribeEventCategoriesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeEventCategories");
         request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeEventCategories");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7458,6 +7959,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
     ) -> Result<DescribeEventSubscriptionsResponse, RusotoError<DescribeEventSubscriptionsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeEventSubscriptions");
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.DescribeEventSubscriptions",
@@ -7480,6 +7982,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DescribeEventsMessage,
     ) -> Result<DescribeEventsResponse, RusotoError<DescribeEventsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeEvents");
         request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeEvents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7501,6 +8004,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         RusotoError<DescribeOrderableReplicationInstancesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeOrderableReplicationInstances");
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.DescribeOrderableReplicationInstances",
@@ -7529,6 +8033,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         RusotoError<DescribePendingMaintenanceActionsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribePendingMaintenanceActions");
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.DescribePendingMaintenanceActions",
@@ -7555,6 +8060,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
     ) -> Result<DescribeRefreshSchemasStatusResponse, RusotoError<DescribeRefreshSchemasStatusError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeRefreshSchemasStatus");
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.DescribeRefreshSchemasStatus",
@@ -7580,6 +8086,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         RusotoError<DescribeReplicationInstanceTaskLogsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeReplicationInstanceTaskLogs");
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.DescribeReplicationInstanceTaskLogs",
@@ -7606,6 +8113,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
     ) -> Result<DescribeReplicationInstancesResponse, RusotoError<DescribeReplicationInstancesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeReplicationInstances");
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.DescribeReplicationInstances",
@@ -7631,6 +8139,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         RusotoError<DescribeReplicationSubnetGroupsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeReplicationSubnetGroups");
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.DescribeReplicationSubnetGroups",
@@ -7656,6 +8165,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         RusotoError<DescribeReplicationTaskAssessmentResultsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeReplicationTaskAssessmentResults");
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.DescribeReplicationTaskAssessmentResults",
@@ -7684,6 +8194,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         RusotoError<DescribeReplicationTaskAssessmentRunsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeReplicationTaskAssessmentRuns");
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.DescribeReplicationTaskAssessmentRuns",
@@ -7712,6 +8223,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         RusotoError<DescribeReplicationTaskIndividualAssessmentsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeReplicationTaskIndividualAssessments");
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.DescribeReplicationTaskIndividualAssessments",
@@ -7737,6 +8249,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DescribeReplicationTasksMessage,
     ) -> Result<DescribeReplicationTasksResponse, RusotoError<DescribeReplicationTasksError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeReplicationTasks");
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.DescribeReplicationTasks",
@@ -7759,6 +8272,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DescribeSchemasMessage,
     ) -> Result<DescribeSchemasResponse, RusotoError<DescribeSchemasError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeSchemas");
         request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeSchemas");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7777,6 +8291,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: DescribeTableStatisticsMessage,
     ) -> Result<DescribeTableStatisticsResponse, RusotoError<DescribeTableStatisticsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeTableStatistics");
         request.add_header("x-amz-target", "AmazonDMSv20160101.DescribeTableStatistics");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7796,6 +8311,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: ImportCertificateMessage,
     ) -> Result<ImportCertificateResponse, RusotoError<ImportCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ImportCertificate");
         request.add_header("x-amz-target", "AmazonDMSv20160101.ImportCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7814,6 +8330,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: ListTagsForResourceMessage,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header("x-amz-target", "AmazonDMSv20160101.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7832,6 +8349,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: ModifyEndpointMessage,
     ) -> Result<ModifyEndpointResponse, RusotoError<ModifyEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ModifyEndpoint");
         request.add_header("x-amz-target", "AmazonDMSv20160101.ModifyEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7850,6 +8368,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: ModifyEventSubscriptionMessage,
     ) -> Result<ModifyEventSubscriptionResponse, RusotoError<ModifyEventSubscriptionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ModifyEventSubscription");
         request.add_header("x-amz-target", "AmazonDMSv20160101.ModifyEventSubscription");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7870,6 +8389,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
     ) -> Result<ModifyReplicationInstanceResponse, RusotoError<ModifyReplicationInstanceError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ModifyReplicationInstance");
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.ModifyReplicationInstance",
@@ -7893,6 +8413,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
     ) -> Result<ModifyReplicationSubnetGroupResponse, RusotoError<ModifyReplicationSubnetGroupError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ModifyReplicationSubnetGroup");
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.ModifyReplicationSubnetGroup",
@@ -7915,6 +8436,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: ModifyReplicationTaskMessage,
     ) -> Result<ModifyReplicationTaskResponse, RusotoError<ModifyReplicationTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ModifyReplicationTask");
         request.add_header("x-amz-target", "AmazonDMSv20160101.ModifyReplicationTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7934,6 +8456,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: MoveReplicationTaskMessage,
     ) -> Result<MoveReplicationTaskResponse, RusotoError<MoveReplicationTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("MoveReplicationTask");
         request.add_header("x-amz-target", "AmazonDMSv20160101.MoveReplicationTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7953,6 +8476,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
     ) -> Result<RebootReplicationInstanceResponse, RusotoError<RebootReplicationInstanceError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RebootReplicationInstance");
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.RebootReplicationInstance",
@@ -7975,6 +8499,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: RefreshSchemasMessage,
     ) -> Result<RefreshSchemasResponse, RusotoError<RefreshSchemasError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RefreshSchemas");
         request.add_header("x-amz-target", "AmazonDMSv20160101.RefreshSchemas");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7993,6 +8518,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: ReloadTablesMessage,
     ) -> Result<ReloadTablesResponse, RusotoError<ReloadTablesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ReloadTables");
         request.add_header("x-amz-target", "AmazonDMSv20160101.ReloadTables");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8011,6 +8537,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: RemoveTagsFromResourceMessage,
     ) -> Result<RemoveTagsFromResourceResponse, RusotoError<RemoveTagsFromResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RemoveTagsFromResource");
         request.add_header("x-amz-target", "AmazonDMSv20160101.RemoveTagsFromResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8030,6 +8557,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: StartReplicationTaskMessage,
     ) -> Result<StartReplicationTaskResponse, RusotoError<StartReplicationTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartReplicationTask");
         request.add_header("x-amz-target", "AmazonDMSv20160101.StartReplicationTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8052,6 +8580,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         RusotoError<StartReplicationTaskAssessmentError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartReplicationTaskAssessment");
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.StartReplicationTaskAssessment",
@@ -8077,6 +8606,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         RusotoError<StartReplicationTaskAssessmentRunError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartReplicationTaskAssessmentRun");
         request.add_header(
             "x-amz-target",
             "AmazonDMSv20160101.StartReplicationTaskAssessmentRun",
@@ -8102,6 +8632,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: StopReplicationTaskMessage,
     ) -> Result<StopReplicationTaskResponse, RusotoError<StopReplicationTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopReplicationTask");
         request.add_header("x-amz-target", "AmazonDMSv20160101.StopReplicationTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8120,6 +8651,7 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         input: TestConnectionMessage,
     ) -> Result<TestConnectionResponse, RusotoError<TestConnectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TestConnection");
         request.add_header("x-amz-target", "AmazonDMSv20160101.TestConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -8132,3 +8664,623 @@ impl DatabaseMigrationService for DatabaseMigrationServiceClient {
         proto::json::ResponsePayload::new(&response).deserialize::<TestConnectionResponse, _>()
     }
 }
+
+/// A blocking client for the AWS Database Migration Service API, running the calls of a `DatabaseMigrationServiceClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `DatabaseMigrationServiceClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct DatabaseMigrationServiceBlockingClient {
+    client: DatabaseMigrationServiceClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl DatabaseMigrationServiceBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> DatabaseMigrationServiceBlockingClient {
+        DatabaseMigrationServiceBlockingClient::from_client(DatabaseMigrationServiceClient::new(
+            region,
+        ))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> DatabaseMigrationServiceBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        DatabaseMigrationServiceBlockingClient::from_client(
+            DatabaseMigrationServiceClient::new_with(
+                request_dispatcher,
+                credentials_provider,
+                region,
+            ),
+        )
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> DatabaseMigrationServiceBlockingClient {
+        DatabaseMigrationServiceBlockingClient::from_client(
+            DatabaseMigrationServiceClient::new_with_client(client, region),
+        )
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> DatabaseMigrationServiceBlockingClient {
+        DatabaseMigrationServiceBlockingClient::from_client(
+            DatabaseMigrationServiceClient::new_with_config(config),
+        )
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(
+        client: DatabaseMigrationServiceClient,
+    ) -> DatabaseMigrationServiceBlockingClient {
+        DatabaseMigrationServiceBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> DatabaseMigrationServiceBlockingClient {
+        DatabaseMigrationServiceBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> DatabaseMigrationServiceBlockingClient {
+        DatabaseMigrationServiceBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::add_tags_to_resource`].
+    pub fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceMessage,
+    ) -> Result<AddTagsToResourceResponse, RusotoError<AddTagsToResourceError>> {
+        self.runtime
+            .block_on(self.client.add_tags_to_resource(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::apply_pending_maintenance_action`].
+    pub fn apply_pending_maintenance_action(
+        &self,
+        input: ApplyPendingMaintenanceActionMessage,
+    ) -> Result<
+        ApplyPendingMaintenanceActionResponse,
+        RusotoError<ApplyPendingMaintenanceActionError>,
+    > {
+        self.runtime
+            .block_on(self.client.apply_pending_maintenance_action(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::cancel_replication_task_assessment_run`].
+    pub fn cancel_replication_task_assessment_run(
+        &self,
+        input: CancelReplicationTaskAssessmentRunMessage,
+    ) -> Result<
+        CancelReplicationTaskAssessmentRunResponse,
+        RusotoError<CancelReplicationTaskAssessmentRunError>,
+    > {
+        self.runtime
+            .block_on(self.client.cancel_replication_task_assessment_run(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::create_endpoint`].
+    pub fn create_endpoint(
+        &self,
+        input: CreateEndpointMessage,
+    ) -> Result<CreateEndpointResponse, RusotoError<CreateEndpointError>> {
+        self.runtime.block_on(self.client.create_endpoint(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::create_event_subscription`].
+    pub fn create_event_subscription(
+        &self,
+        input: CreateEventSubscriptionMessage,
+    ) -> Result<CreateEventSubscriptionResponse, RusotoError<CreateEventSubscriptionError>> {
+        self.runtime
+            .block_on(self.client.create_event_subscription(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::create_replication_instance`].
+    pub fn create_replication_instance(
+        &self,
+        input: CreateReplicationInstanceMessage,
+    ) -> Result<CreateReplicationInstanceResponse, RusotoError<CreateReplicationInstanceError>>
+    {
+        self.runtime
+            .block_on(self.client.create_replication_instance(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::create_replication_subnet_group`].
+    pub fn create_replication_subnet_group(
+        &self,
+        input: CreateReplicationSubnetGroupMessage,
+    ) -> Result<CreateReplicationSubnetGroupResponse, RusotoError<CreateReplicationSubnetGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.create_replication_subnet_group(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::create_replication_task`].
+    pub fn create_replication_task(
+        &self,
+        input: CreateReplicationTaskMessage,
+    ) -> Result<CreateReplicationTaskResponse, RusotoError<CreateReplicationTaskError>> {
+        self.runtime
+            .block_on(self.client.create_replication_task(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::delete_certificate`].
+    pub fn delete_certificate(
+        &self,
+        input: DeleteCertificateMessage,
+    ) -> Result<DeleteCertificateResponse, RusotoError<DeleteCertificateError>> {
+        self.runtime.block_on(self.client.delete_certificate(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::delete_connection`].
+    pub fn delete_connection(
+        &self,
+        input: DeleteConnectionMessage,
+    ) -> Result<DeleteConnectionResponse, RusotoError<DeleteConnectionError>> {
+        self.runtime.block_on(self.client.delete_connection(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::delete_endpoint`].
+    pub fn delete_endpoint(
+        &self,
+        input: DeleteEndpointMessage,
+    ) -> Result<DeleteEndpointResponse, RusotoError<DeleteEndpointError>> {
+        self.runtime.block_on(self.client.delete_endpoint(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::delete_event_subscription`].
+    pub fn delete_event_subscription(
+        &self,
+        input: DeleteEventSubscriptionMessage,
+    ) -> Result<DeleteEventSubscriptionResponse, RusotoError<DeleteEventSubscriptionError>> {
+        self.runtime
+            .block_on(self.client.delete_event_subscription(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::delete_replication_instance`].
+    pub fn delete_replication_instance(
+        &self,
+        input: DeleteReplicationInstanceMessage,
+    ) -> Result<DeleteReplicationInstanceResponse, RusotoError<DeleteReplicationInstanceError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_replication_instance(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::delete_replication_subnet_group`].
+    pub fn delete_replication_subnet_group(
+        &self,
+        input: DeleteReplicationSubnetGroupMessage,
+    ) -> Result<DeleteReplicationSubnetGroupResponse, RusotoError<DeleteReplicationSubnetGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_replication_subnet_group(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::delete_replication_task`].
+    pub fn delete_replication_task(
+        &self,
+        input: DeleteReplicationTaskMessage,
+    ) -> Result<DeleteReplicationTaskResponse, RusotoError<DeleteReplicationTaskError>> {
+        self.runtime
+            .block_on(self.client.delete_replication_task(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::delete_replication_task_assessment_run`].
+    pub fn delete_replication_task_assessment_run(
+        &self,
+        input: DeleteReplicationTaskAssessmentRunMessage,
+    ) -> Result<
+        DeleteReplicationTaskAssessmentRunResponse,
+        RusotoError<DeleteReplicationTaskAssessmentRunError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_replication_task_assessment_run(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::describe_account_attributes`].
+    pub fn describe_account_attributes(
+        &self,
+    ) -> Result<DescribeAccountAttributesResponse, RusotoError<DescribeAccountAttributesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_account_attributes())
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::describe_applicable_individual_assessments`].
+    pub fn describe_applicable_individual_assessments(
+        &self,
+        input: DescribeApplicableIndividualAssessmentsMessage,
+    ) -> Result<
+        DescribeApplicableIndividualAssessmentsResponse,
+        RusotoError<DescribeApplicableIndividualAssessmentsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_applicable_individual_assessments(input),
+        )
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::describe_certificates`].
+    pub fn describe_certificates(
+        &self,
+        input: DescribeCertificatesMessage,
+    ) -> Result<DescribeCertificatesResponse, RusotoError<DescribeCertificatesError>> {
+        self.runtime
+            .block_on(self.client.describe_certificates(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::describe_connections`].
+    pub fn describe_connections(
+        &self,
+        input: DescribeConnectionsMessage,
+    ) -> Result<DescribeConnectionsResponse, RusotoError<DescribeConnectionsError>> {
+        self.runtime
+            .block_on(self.client.describe_connections(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::describe_endpoint_types`].
+    pub fn describe_endpoint_types(
+        &self,
+        input: DescribeEndpointTypesMessage,
+    ) -> Result<DescribeEndpointTypesResponse, RusotoError<DescribeEndpointTypesError>> {
+        self.runtime
+            .block_on(self.client.describe_endpoint_types(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::describe_endpoints`].
+    pub fn describe_endpoints(
+        &self,
+        input: DescribeEndpointsMessage,
+    ) -> Result<DescribeEndpointsResponse, RusotoError<DescribeEndpointsError>> {
+        self.runtime.block_on(self.client.describe_endpoints(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::describe_event_categories`].
+    pub fn describe_event_categories(
+        &self,
+        input: DescribeEventCategoriesMessage,
+    ) -> Result<DescribeEventCategoriesResponse, RusotoError<DescribeEventCategoriesError>> {
+        self.runtime
+            .block_on(self.client.describe_event_categories(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::describe_event_subscriptions`].
+    pub fn describe_event_subscriptions(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> Result<DescribeEventSubscriptionsResponse, RusotoError<DescribeEventSubscriptionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_event_subscriptions(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::describe_events`].
+    pub fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> Result<DescribeEventsResponse, RusotoError<DescribeEventsError>> {
+        self.runtime.block_on(self.client.describe_events(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::describe_orderable_replication_instances`].
+    pub fn describe_orderable_replication_instances(
+        &self,
+        input: DescribeOrderableReplicationInstancesMessage,
+    ) -> Result<
+        DescribeOrderableReplicationInstancesResponse,
+        RusotoError<DescribeOrderableReplicationInstancesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_orderable_replication_instances(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::describe_pending_maintenance_actions`].
+    pub fn describe_pending_maintenance_actions(
+        &self,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> Result<
+        DescribePendingMaintenanceActionsResponse,
+        RusotoError<DescribePendingMaintenanceActionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_pending_maintenance_actions(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::describe_refresh_schemas_status`].
+    pub fn describe_refresh_schemas_status(
+        &self,
+        input: DescribeRefreshSchemasStatusMessage,
+    ) -> Result<DescribeRefreshSchemasStatusResponse, RusotoError<DescribeRefreshSchemasStatusError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_refresh_schemas_status(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::describe_replication_instance_task_logs`].
+    pub fn describe_replication_instance_task_logs(
+        &self,
+        input: DescribeReplicationInstanceTaskLogsMessage,
+    ) -> Result<
+        DescribeReplicationInstanceTaskLogsResponse,
+        RusotoError<DescribeReplicationInstanceTaskLogsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_replication_instance_task_logs(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::describe_replication_instances`].
+    pub fn describe_replication_instances(
+        &self,
+        input: DescribeReplicationInstancesMessage,
+    ) -> Result<DescribeReplicationInstancesResponse, RusotoError<DescribeReplicationInstancesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_replication_instances(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::describe_replication_subnet_groups`].
+    pub fn describe_replication_subnet_groups(
+        &self,
+        input: DescribeReplicationSubnetGroupsMessage,
+    ) -> Result<
+        DescribeReplicationSubnetGroupsResponse,
+        RusotoError<DescribeReplicationSubnetGroupsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_replication_subnet_groups(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::describe_replication_task_assessment_results`].
+    pub fn describe_replication_task_assessment_results(
+        &self,
+        input: DescribeReplicationTaskAssessmentResultsMessage,
+    ) -> Result<
+        DescribeReplicationTaskAssessmentResultsResponse,
+        RusotoError<DescribeReplicationTaskAssessmentResultsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_replication_task_assessment_results(input),
+        )
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::describe_replication_task_assessment_runs`].
+    pub fn describe_replication_task_assessment_runs(
+        &self,
+        input: DescribeReplicationTaskAssessmentRunsMessage,
+    ) -> Result<
+        DescribeReplicationTaskAssessmentRunsResponse,
+        RusotoError<DescribeReplicationTaskAssessmentRunsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_replication_task_assessment_runs(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::describe_replication_task_individual_assessments`].
+    pub fn describe_replication_task_individual_assessments(
+        &self,
+        input: DescribeReplicationTaskIndividualAssessmentsMessage,
+    ) -> Result<
+        DescribeReplicationTaskIndividualAssessmentsResponse,
+        RusotoError<DescribeReplicationTaskIndividualAssessmentsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_replication_task_individual_assessments(input),
+        )
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::describe_replication_tasks`].
+    pub fn describe_replication_tasks(
+        &self,
+        input: DescribeReplicationTasksMessage,
+    ) -> Result<DescribeReplicationTasksResponse, RusotoError<DescribeReplicationTasksError>> {
+        self.runtime
+            .block_on(self.client.describe_replication_tasks(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::describe_schemas`].
+    pub fn describe_schemas(
+        &self,
+        input: DescribeSchemasMessage,
+    ) -> Result<DescribeSchemasResponse, RusotoError<DescribeSchemasError>> {
+        self.runtime.block_on(self.client.describe_schemas(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::describe_table_statistics`].
+    pub fn describe_table_statistics(
+        &self,
+        input: DescribeTableStatisticsMessage,
+    ) -> Result<DescribeTableStatisticsResponse, RusotoError<DescribeTableStatisticsError>> {
+        self.runtime
+            .block_on(self.client.describe_table_statistics(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::import_certificate`].
+    pub fn import_certificate(
+        &self,
+        input: ImportCertificateMessage,
+    ) -> Result<ImportCertificateResponse, RusotoError<ImportCertificateError>> {
+        self.runtime.block_on(self.client.import_certificate(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::modify_endpoint`].
+    pub fn modify_endpoint(
+        &self,
+        input: ModifyEndpointMessage,
+    ) -> Result<ModifyEndpointResponse, RusotoError<ModifyEndpointError>> {
+        self.runtime.block_on(self.client.modify_endpoint(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::modify_event_subscription`].
+    pub fn modify_event_subscription(
+        &self,
+        input: ModifyEventSubscriptionMessage,
+    ) -> Result<ModifyEventSubscriptionResponse, RusotoError<ModifyEventSubscriptionError>> {
+        self.runtime
+            .block_on(self.client.modify_event_subscription(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::modify_replication_instance`].
+    pub fn modify_replication_instance(
+        &self,
+        input: ModifyReplicationInstanceMessage,
+    ) -> Result<ModifyReplicationInstanceResponse, RusotoError<ModifyReplicationInstanceError>>
+    {
+        self.runtime
+            .block_on(self.client.modify_replication_instance(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::modify_replication_subnet_group`].
+    pub fn modify_replication_subnet_group(
+        &self,
+        input: ModifyReplicationSubnetGroupMessage,
+    ) -> Result<ModifyReplicationSubnetGroupResponse, RusotoError<ModifyReplicationSubnetGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.modify_replication_subnet_group(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::modify_replication_task`].
+    pub fn modify_replication_task(
+        &self,
+        input: ModifyReplicationTaskMessage,
+    ) -> Result<ModifyReplicationTaskResponse, RusotoError<ModifyReplicationTaskError>> {
+        self.runtime
+            .block_on(self.client.modify_replication_task(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::move_replication_task`].
+    pub fn move_replication_task(
+        &self,
+        input: MoveReplicationTaskMessage,
+    ) -> Result<MoveReplicationTaskResponse, RusotoError<MoveReplicationTaskError>> {
+        self.runtime
+            .block_on(self.client.move_replication_task(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::reboot_replication_instance`].
+    pub fn reboot_replication_instance(
+        &self,
+        input: RebootReplicationInstanceMessage,
+    ) -> Result<RebootReplicationInstanceResponse, RusotoError<RebootReplicationInstanceError>>
+    {
+        self.runtime
+            .block_on(self.client.reboot_replication_instance(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::refresh_schemas`].
+    pub fn refresh_schemas(
+        &self,
+        input: RefreshSchemasMessage,
+    ) -> Result<RefreshSchemasResponse, RusotoError<RefreshSchemasError>> {
+        self.runtime.block_on(self.client.refresh_schemas(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::reload_tables`].
+    pub fn reload_tables(
+        &self,
+        input: ReloadTablesMessage,
+    ) -> Result<ReloadTablesResponse, RusotoError<ReloadTablesError>> {
+        self.runtime.block_on(self.client.reload_tables(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::remove_tags_from_resource`].
+    pub fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceMessage,
+    ) -> Result<RemoveTagsFromResourceResponse, RusotoError<RemoveTagsFromResourceError>> {
+        self.runtime
+            .block_on(self.client.remove_tags_from_resource(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::start_replication_task`].
+    pub fn start_replication_task(
+        &self,
+        input: StartReplicationTaskMessage,
+    ) -> Result<StartReplicationTaskResponse, RusotoError<StartReplicationTaskError>> {
+        self.runtime
+            .block_on(self.client.start_replication_task(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::start_replication_task_assessment`].
+    pub fn start_replication_task_assessment(
+        &self,
+        input: StartReplicationTaskAssessmentMessage,
+    ) -> Result<
+        StartReplicationTaskAssessmentResponse,
+        RusotoError<StartReplicationTaskAssessmentError>,
+    > {
+        self.runtime
+            .block_on(self.client.start_replication_task_assessment(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::start_replication_task_assessment_run`].
+    pub fn start_replication_task_assessment_run(
+        &self,
+        input: StartReplicationTaskAssessmentRunMessage,
+    ) -> Result<
+        StartReplicationTaskAssessmentRunResponse,
+        RusotoError<StartReplicationTaskAssessmentRunError>,
+    > {
+        self.runtime
+            .block_on(self.client.start_replication_task_assessment_run(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::stop_replication_task`].
+    pub fn stop_replication_task(
+        &self,
+        input: StopReplicationTaskMessage,
+    ) -> Result<StopReplicationTaskResponse, RusotoError<StopReplicationTaskError>> {
+        self.runtime
+            .block_on(self.client.stop_replication_task(input))
+    }
+
+    /// Blocking version of [`DatabaseMigrationService::test_connection`].
+    pub fn test_connection(
+        &self,
+        input: TestConnectionMessage,
+    ) -> Result<TestConnectionResponse, RusotoError<TestConnectionError>> {
+        self.runtime.block_on(self.client.test_connection(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -5844,18 +5845,27 @@ impl AddTagsToResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            AddTagsToResourceError::DBClusterNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AddTagsToResourceError::DBClusterNotFoundFault,
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
-                            AddTagsToResourceError::DBInstanceNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AddTagsToResourceError::DBInstanceNotFoundFault,
                         )
                     }
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
-                            AddTagsToResourceError::DBSnapshotNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AddTagsToResourceError::DBSnapshotNotFoundFault,
                         )
                     }
                     _ => {}
@@ -5906,24 +5916,27 @@ impl ApplyPendingMaintenanceActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            ApplyPendingMaintenanceActionError::InvalidDBClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ApplyPendingMaintenanceActionError::InvalidDBClusterStateFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            ApplyPendingMaintenanceActionError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ApplyPendingMaintenanceActionError::InvalidDBInstanceStateFault,
                         )
                     }
                     "ResourceNotFoundFault" => {
-                        return RusotoError::Service(
-                            ApplyPendingMaintenanceActionError::ResourceNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ApplyPendingMaintenanceActionError::ResourceNotFoundFault,
                         )
                     }
                     _ => {}
@@ -5980,24 +5993,27 @@ impl CopyDBClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupAlreadyExists" => {
-                        return RusotoError::Service(
-                            CopyDBClusterParameterGroupError::DBParameterGroupAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyDBClusterParameterGroupError::DBParameterGroupAlreadyExistsFault,
                         )
                     }
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            CopyDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyDBClusterParameterGroupError::DBParameterGroupNotFoundFault,
                         )
                     }
                     "DBParameterGroupQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CopyDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -6058,45 +6074,51 @@ impl CopyDBClusterSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterSnapshotAlreadyExistsFault" => {
-                        return RusotoError::Service(
-                            CopyDBClusterSnapshotError::DBClusterSnapshotAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyDBClusterSnapshotError::DBClusterSnapshotAlreadyExistsFault,
                         )
                     }
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
-                            CopyDBClusterSnapshotError::DBClusterSnapshotNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyDBClusterSnapshotError::DBClusterSnapshotNotFoundFault,
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
-                            CopyDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            CopyDBClusterSnapshotError::InvalidDBClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyDBClusterSnapshotError::InvalidDBClusterStateFault,
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
-                            CopyDBClusterSnapshotError::KMSKeyNotAccessibleFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyDBClusterSnapshotError::KMSKeyNotAccessibleFault,
                         )
                     }
                     "SnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CopyDBClusterSnapshotError::SnapshotQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyDBClusterSnapshotError::SnapshotQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -6184,86 +6206,123 @@ impl CreateDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterAlreadyExistsFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterError::DBClusterAlreadyExistsFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterError::DBClusterAlreadyExistsFault,
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(CreateDBClusterError::DBClusterNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateDBClusterError::DBClusterNotFoundFault,
+                        )
                     }
                     "DBClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            CreateDBClusterError::DBClusterParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterError::DBClusterParameterGroupNotFoundFault,
                         )
                     }
                     "DBClusterQuotaExceededFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterError::DBClusterQuotaExceededFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterError::DBClusterQuotaExceededFault,
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(CreateDBClusterError::DBInstanceNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateDBClusterError::DBInstanceNotFoundFault,
+                        )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
-                        return RusotoError::Service(
-                            CreateDBClusterError::DBSubnetGroupDoesNotCoverEnoughAZs(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterError::DBSubnetGroupDoesNotCoverEnoughAZs,
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterError::DBSubnetGroupNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterError::DBSubnetGroupNotFoundFault,
                         )
                     }
                     "InsufficientStorageClusterCapacity" => {
-                        return RusotoError::Service(
-                            CreateDBClusterError::InsufficientStorageClusterCapacityFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterError::InsufficientStorageClusterCapacityFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterError::InvalidDBClusterStateFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            CreateDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterError::InvalidDBInstanceStateFault,
                         )
                     }
                     "InvalidDBSubnetGroupStateFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterError::InvalidDBSubnetGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterError::InvalidDBSubnetGroupStateFault,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateDBClusterError::InvalidSubnet(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateDBClusterError::InvalidSubnet,
+                        )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterError::InvalidVPCNetworkStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterError::InvalidVPCNetworkStateFault,
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterError::KMSKeyNotAccessibleFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterError::KMSKeyNotAccessibleFault,
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CreateDBClusterError::StorageQuotaExceededFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterError::StorageQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -6332,17 +6391,19 @@ impl CreateDBClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupAlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateDBClusterParameterGroupError::DBParameterGroupAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterParameterGroupError::DBParameterGroupAlreadyExistsFault,
                         )
                     }
                     "DBParameterGroupQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CreateDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -6398,38 +6459,43 @@ impl CreateDBClusterSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterSnapshotError::DBClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterSnapshotError::DBClusterNotFoundFault,
                         )
                     }
                     "DBClusterSnapshotAlreadyExistsFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterSnapshotError::DBClusterSnapshotAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterSnapshotError::DBClusterSnapshotAlreadyExistsFault,
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterSnapshotError::InvalidDBClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterSnapshotError::InvalidDBClusterStateFault,
                         )
                     }
                     "SnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CreateDBClusterSnapshotError::SnapshotQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterSnapshotError::SnapshotQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -6514,92 +6580,123 @@ impl CreateDBInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationNotFound" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::AuthorizationNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::AuthorizationNotFoundFault,
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(CreateDBInstanceError::DBClusterNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateDBInstanceError::DBClusterNotFoundFault,
+                        )
                     }
                     "DBInstanceAlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::DBInstanceAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::DBInstanceAlreadyExistsFault,
                         )
                     }
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::DBParameterGroupNotFoundFault,
                         )
                     }
                     "DBSecurityGroupNotFound" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::DBSecurityGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::DBSecurityGroupNotFoundFault,
                         )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::DBSubnetGroupDoesNotCoverEnoughAZs(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::DBSubnetGroupDoesNotCoverEnoughAZs,
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::DBSubnetGroupNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::DBSubnetGroupNotFoundFault,
                         )
                     }
                     "InstanceQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::InstanceQuotaExceededFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::InstanceQuotaExceededFault,
                         )
                     }
                     "InsufficientDBInstanceCapacity" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::InsufficientDBInstanceCapacityFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::InsufficientDBInstanceCapacityFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::InvalidDBClusterStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::InvalidDBClusterStateFault,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateDBInstanceError::InvalidSubnet(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateDBInstanceError::InvalidSubnet,
+                        )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::InvalidVPCNetworkStateFault,
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::KMSKeyNotAccessibleFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::KMSKeyNotAccessibleFault,
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::StorageQuotaExceededFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::StorageQuotaExceededFault,
                         )
                     }
                     "StorageTypeNotSupported" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::StorageTypeNotSupportedFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::StorageTypeNotSupportedFault,
                         )
                     }
                     _ => {}
@@ -6676,37 +6773,44 @@ impl CreateDBSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSubnetGroupAlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateDBSubnetGroupError::DBSubnetGroupAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBSubnetGroupError::DBSubnetGroupAlreadyExistsFault,
                         )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
-                        return RusotoError::Service(
-                            CreateDBSubnetGroupError::DBSubnetGroupDoesNotCoverEnoughAZs(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBSubnetGroupError::DBSubnetGroupDoesNotCoverEnoughAZs,
                         )
                     }
                     "DBSubnetGroupQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CreateDBSubnetGroupError::DBSubnetGroupQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBSubnetGroupError::DBSubnetGroupQuotaExceededFault,
                         )
                     }
                     "DBSubnetQuotaExceededFault" => {
-                        return RusotoError::Service(
-                            CreateDBSubnetGroupError::DBSubnetQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBSubnetGroupError::DBSubnetQuotaExceededFault,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateDBSubnetGroupError::InvalidSubnet(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateDBSubnetGroupError::InvalidSubnet,
+                        )
                     }
                     _ => {}
                 }
@@ -6768,32 +6872,43 @@ impl DeleteDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(DeleteDBClusterError::DBClusterNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteDBClusterError::DBClusterNotFoundFault,
+                        )
                     }
                     "DBClusterSnapshotAlreadyExistsFault" => {
-                        return RusotoError::Service(
-                            DeleteDBClusterError::DBClusterSnapshotAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBClusterError::DBClusterSnapshotAlreadyExistsFault,
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
-                            DeleteDBClusterError::InvalidDBClusterSnapshotStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBClusterError::InvalidDBClusterSnapshotStateFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            DeleteDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBClusterError::InvalidDBClusterStateFault,
                         )
                     }
                     "SnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
-                            DeleteDBClusterError::SnapshotQuotaExceededFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBClusterError::SnapshotQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -6848,17 +6963,19 @@ impl DeleteDBClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            DeleteDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBClusterParameterGroupError::DBParameterGroupNotFoundFault,
                         )
                     }
                     "InvalidDBParameterGroupState" => {
-                        return RusotoError::Service(
-                            DeleteDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBClusterParameterGroupError::InvalidDBParameterGroupStateFault,
                         )
                     }
                     _ => {}
@@ -6908,17 +7025,19 @@ impl DeleteDBClusterSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
-                            DeleteDBClusterSnapshotError::DBClusterSnapshotNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBClusterSnapshotError::DBClusterSnapshotNotFoundFault,
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
-                            DeleteDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault,
                         )
                     }
                     _ => {}
@@ -6974,32 +7093,43 @@ impl DeleteDBInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
-                            DeleteDBInstanceError::DBInstanceNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBInstanceError::DBInstanceNotFoundFault,
                         )
                     }
                     "DBSnapshotAlreadyExists" => {
-                        return RusotoError::Service(
-                            DeleteDBInstanceError::DBSnapshotAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBInstanceError::DBSnapshotAlreadyExistsFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            DeleteDBInstanceError::InvalidDBClusterStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBInstanceError::InvalidDBClusterStateFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            DeleteDBInstanceError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBInstanceError::InvalidDBInstanceStateFault,
                         )
                     }
                     "SnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
-                            DeleteDBInstanceError::SnapshotQuotaExceededFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBInstanceError::SnapshotQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -7052,24 +7182,27 @@ impl DeleteDBSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            DeleteDBSubnetGroupError::DBSubnetGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBSubnetGroupError::DBSubnetGroupNotFoundFault,
                         )
                     }
                     "InvalidDBSubnetGroupStateFault" => {
-                        return RusotoError::Service(
-                            DeleteDBSubnetGroupError::InvalidDBSubnetGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBSubnetGroupError::InvalidDBSubnetGroupStateFault,
                         )
                     }
                     "InvalidDBSubnetStateFault" => {
-                        return RusotoError::Service(
-                            DeleteDBSubnetGroupError::InvalidDBSubnetStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBSubnetGroupError::InvalidDBSubnetStateFault,
                         )
                     }
                     _ => {}
@@ -7120,10 +7253,11 @@ impl DescribeCertificatesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CertificateNotFound" => {
-                        return RusotoError::Service(
-                            DescribeCertificatesError::CertificateNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeCertificatesError::CertificateNotFoundFault,
                         )
                     }
                     _ => {}
@@ -7170,10 +7304,11 @@ impl DescribeDBClusterParameterGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            DescribeDBClusterParameterGroupsError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeDBClusterParameterGroupsError::DBParameterGroupNotFoundFault,
                         )
                     }
                     _ => {}
@@ -7220,10 +7355,11 @@ impl DescribeDBClusterParametersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            DescribeDBClusterParametersError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeDBClusterParametersError::DBParameterGroupNotFoundFault,
                         )
                     }
                     _ => {}
@@ -7269,10 +7405,11 @@ impl DescribeDBClusterSnapshotAttributesError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                    "DBClusterSnapshotNotFoundFault" => return RusotoError::Service(
-                        DescribeDBClusterSnapshotAttributesError::DBClusterSnapshotNotFoundFault(
-                            parsed_error.message,
-                        ),
+                    "DBClusterSnapshotNotFoundFault" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        DescribeDBClusterSnapshotAttributesError::DBClusterSnapshotNotFoundFault,
                     ),
                     _ => {}
                 }
@@ -7318,10 +7455,11 @@ impl DescribeDBClusterSnapshotsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
-                            DescribeDBClusterSnapshotsError::DBClusterSnapshotNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeDBClusterSnapshotsError::DBClusterSnapshotNotFoundFault,
                         )
                     }
                     _ => {}
@@ -7366,8 +7504,11 @@ impl DescribeDBClustersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            DescribeDBClustersError::DBClusterNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeDBClustersError::DBClusterNotFoundFault,
                         )
                     }
                     _ => {}
@@ -7444,8 +7585,11 @@ impl DescribeDBInstancesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
-                            DescribeDBInstancesError::DBInstanceNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeDBInstancesError::DBInstanceNotFoundFault,
                         )
                     }
                     _ => {}
@@ -7488,10 +7632,11 @@ impl DescribeDBSubnetGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            DescribeDBSubnetGroupsError::DBSubnetGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeDBSubnetGroupsError::DBSubnetGroupNotFoundFault,
                         )
                     }
                     _ => {}
@@ -7678,10 +7823,11 @@ impl DescribePendingMaintenanceActionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceNotFoundFault" => {
-                        return RusotoError::Service(
-                            DescribePendingMaintenanceActionsError::ResourceNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribePendingMaintenanceActionsError::ResourceNotFoundFault,
                         )
                     }
                     _ => {}
@@ -7730,22 +7876,27 @@ impl FailoverDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            FailoverDBClusterError::DBClusterNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            FailoverDBClusterError::DBClusterNotFoundFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            FailoverDBClusterError::InvalidDBClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            FailoverDBClusterError::InvalidDBClusterStateFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            FailoverDBClusterError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            FailoverDBClusterError::InvalidDBInstanceStateFault,
                         )
                     }
                     _ => {}
@@ -7796,18 +7947,27 @@ impl ListTagsForResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            ListTagsForResourceError::DBClusterNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListTagsForResourceError::DBClusterNotFoundFault,
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
-                            ListTagsForResourceError::DBInstanceNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListTagsForResourceError::DBInstanceNotFoundFault,
                         )
                     }
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
-                            ListTagsForResourceError::DBSnapshotNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListTagsForResourceError::DBSnapshotNotFoundFault,
                         )
                     }
                     _ => {}
@@ -7872,64 +8032,91 @@ impl ModifyDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterAlreadyExistsFault" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterError::DBClusterAlreadyExistsFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterError::DBClusterAlreadyExistsFault,
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(ModifyDBClusterError::DBClusterNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyDBClusterError::DBClusterNotFoundFault,
+                        )
                     }
                     "DBClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterError::DBClusterParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterError::DBClusterParameterGroupNotFoundFault,
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterError::DBSubnetGroupNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterError::DBSubnetGroupNotFoundFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterError::InvalidDBClusterStateFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterError::InvalidDBInstanceStateFault,
                         )
                     }
                     "InvalidDBSecurityGroupState" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterError::InvalidDBSecurityGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterError::InvalidDBSecurityGroupStateFault,
                         )
                     }
                     "InvalidDBSubnetGroupStateFault" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterError::InvalidDBSubnetGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterError::InvalidDBSubnetGroupStateFault,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(ModifyDBClusterError::InvalidSubnet(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyDBClusterError::InvalidSubnet,
+                        )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterError::InvalidVPCNetworkStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterError::InvalidVPCNetworkStateFault,
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterError::StorageQuotaExceededFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterError::StorageQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -7992,17 +8179,19 @@ impl ModifyDBClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterParameterGroupError::DBParameterGroupNotFoundFault,
                         )
                     }
                     "InvalidDBParameterGroupState" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterParameterGroupError::InvalidDBParameterGroupStateFault,
                         )
                     }
                     _ => {}
@@ -8056,22 +8245,25 @@ impl ModifyDBClusterSnapshotAttributeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterSnapshotAttributeError::DBClusterSnapshotNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterSnapshotAttributeError::DBClusterSnapshotNotFoundFault,
                         )
                     }
-                    "InvalidDBClusterSnapshotStateFault" => return RusotoError::Service(
-                        ModifyDBClusterSnapshotAttributeError::InvalidDBClusterSnapshotStateFault(
-                            parsed_error.message,
-                        ),
+                    "InvalidDBClusterSnapshotStateFault" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        ModifyDBClusterSnapshotAttributeError::InvalidDBClusterSnapshotStateFault,
                     ),
                     "SharedSnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterSnapshotAttributeError::SharedSnapshotQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterSnapshotAttributeError::SharedSnapshotQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -8146,86 +8338,107 @@ impl ModifyDBInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationNotFound" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::AuthorizationNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::AuthorizationNotFoundFault,
                         )
                     }
                     "CertificateNotFound" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::CertificateNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::CertificateNotFoundFault,
                         )
                     }
                     "DBInstanceAlreadyExists" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::DBInstanceAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::DBInstanceAlreadyExistsFault,
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::DBInstanceNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::DBInstanceNotFoundFault,
                         )
                     }
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::DBParameterGroupNotFoundFault,
                         )
                     }
                     "DBSecurityGroupNotFound" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::DBSecurityGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::DBSecurityGroupNotFoundFault,
                         )
                     }
                     "DBUpgradeDependencyFailure" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::DBUpgradeDependencyFailureFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::DBUpgradeDependencyFailureFault,
                         )
                     }
                     "InsufficientDBInstanceCapacity" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::InsufficientDBInstanceCapacityFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::InsufficientDBInstanceCapacityFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::InvalidDBInstanceStateFault,
                         )
                     }
                     "InvalidDBSecurityGroupState" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::InvalidDBSecurityGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::InvalidDBSecurityGroupStateFault,
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::InvalidVPCNetworkStateFault,
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::StorageQuotaExceededFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::StorageQuotaExceededFault,
                         )
                     }
                     "StorageTypeNotSupported" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::StorageTypeNotSupportedFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::StorageTypeNotSupportedFault,
                         )
                     }
                     _ => {}
@@ -8302,35 +8515,44 @@ impl ModifyDBSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
-                        return RusotoError::Service(
-                            ModifyDBSubnetGroupError::DBSubnetGroupDoesNotCoverEnoughAZs(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBSubnetGroupError::DBSubnetGroupDoesNotCoverEnoughAZs,
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            ModifyDBSubnetGroupError::DBSubnetGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBSubnetGroupError::DBSubnetGroupNotFoundFault,
                         )
                     }
                     "DBSubnetQuotaExceededFault" => {
-                        return RusotoError::Service(
-                            ModifyDBSubnetGroupError::DBSubnetQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBSubnetGroupError::DBSubnetQuotaExceededFault,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(ModifyDBSubnetGroupError::InvalidSubnet(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyDBSubnetGroupError::InvalidSubnet,
+                        )
                     }
                     "SubnetAlreadyInUse" => {
-                        return RusotoError::Service(ModifyDBSubnetGroupError::SubnetAlreadyInUse(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyDBSubnetGroupError::SubnetAlreadyInUse,
+                        )
                     }
                     _ => {}
                 }
@@ -8384,15 +8606,19 @@ impl RebootDBInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
-                            RebootDBInstanceError::DBInstanceNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RebootDBInstanceError::DBInstanceNotFoundFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            RebootDBInstanceError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RebootDBInstanceError::InvalidDBInstanceStateFault,
                         )
                     }
                     _ => {}
@@ -8440,24 +8666,27 @@ impl RemoveTagsFromResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            RemoveTagsFromResourceError::DBClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RemoveTagsFromResourceError::DBClusterNotFoundFault,
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
-                            RemoveTagsFromResourceError::DBInstanceNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RemoveTagsFromResourceError::DBInstanceNotFoundFault,
                         )
                     }
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
-                            RemoveTagsFromResourceError::DBSnapshotNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RemoveTagsFromResourceError::DBSnapshotNotFoundFault,
                         )
                     }
                     _ => {}
@@ -8512,17 +8741,19 @@ impl ResetDBClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            ResetDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ResetDBClusterParameterGroupError::DBParameterGroupNotFoundFault,
                         )
                     }
                     "InvalidDBParameterGroupState" => {
-                        return RusotoError::Service(
-                            ResetDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ResetDBClusterParameterGroupError::InvalidDBParameterGroupStateFault,
                         )
                     }
                     _ => {}
@@ -8598,97 +8829,113 @@ impl RestoreDBClusterFromSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterAlreadyExistsFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::DBClusterAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::DBClusterAlreadyExistsFault,
                         )
                     }
                     "DBClusterQuotaExceededFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::DBClusterQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::DBClusterQuotaExceededFault,
                         )
                     }
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::DBClusterSnapshotNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::DBClusterSnapshotNotFoundFault,
                         )
                     }
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::DBSnapshotNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::DBSnapshotNotFoundFault,
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::DBSubnetGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::DBSubnetGroupNotFoundFault,
                         )
                     }
                     "InsufficientDBClusterCapacityFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::InsufficientDBClusterCapacityFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::InsufficientDBClusterCapacityFault,
                         )
                     }
-                    "InsufficientStorageClusterCapacity" => return RusotoError::Service(
-                        RestoreDBClusterFromSnapshotError::InsufficientStorageClusterCapacityFault(
-                            parsed_error.message,
-                        ),
+                    "InsufficientStorageClusterCapacity" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        RestoreDBClusterFromSnapshotError::InsufficientStorageClusterCapacityFault,
                     ),
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::InvalidDBClusterSnapshotStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::InvalidDBClusterSnapshotStateFault,
                         )
                     }
                     "InvalidDBSnapshotState" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::InvalidDBSnapshotStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::InvalidDBSnapshotStateFault,
                         )
                     }
                     "InvalidRestoreFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::InvalidRestoreFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::InvalidRestoreFault,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::InvalidSubnet(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::InvalidSubnet,
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::InvalidVPCNetworkStateFault,
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::KMSKeyNotAccessibleFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::KMSKeyNotAccessibleFault,
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::StorageQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::StorageQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -8800,104 +9047,121 @@ impl RestoreDBClusterToPointInTimeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterAlreadyExistsFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::DBClusterAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::DBClusterAlreadyExistsFault,
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::DBClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::DBClusterNotFoundFault,
                         )
                     }
                     "DBClusterQuotaExceededFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::DBClusterQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::DBClusterQuotaExceededFault,
                         )
                     }
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::DBClusterSnapshotNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::DBClusterSnapshotNotFoundFault,
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::DBSubnetGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::DBSubnetGroupNotFoundFault,
                         )
                     }
                     "InsufficientDBClusterCapacityFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::InsufficientDBClusterCapacityFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::InsufficientDBClusterCapacityFault,
                         )
                     }
-                    "InsufficientStorageClusterCapacity" => return RusotoError::Service(
-                        RestoreDBClusterToPointInTimeError::InsufficientStorageClusterCapacityFault(
-                            parsed_error.message,
-                        ),
+                    "InsufficientStorageClusterCapacity" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        RestoreDBClusterToPointInTimeError::InsufficientStorageClusterCapacityFault,
                     ),
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::InvalidDBClusterSnapshotStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::InvalidDBClusterSnapshotStateFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::InvalidDBClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::InvalidDBClusterStateFault,
                         )
                     }
                     "InvalidDBSnapshotState" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::InvalidDBSnapshotStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::InvalidDBSnapshotStateFault,
                         )
                     }
                     "InvalidRestoreFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::InvalidRestoreFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::InvalidRestoreFault,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::InvalidSubnet(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::InvalidSubnet,
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::InvalidVPCNetworkStateFault,
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::KMSKeyNotAccessibleFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::KMSKeyNotAccessibleFault,
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::StorageQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::StorageQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -8986,18 +9250,27 @@ impl StartDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(StartDBClusterError::DBClusterNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            StartDBClusterError::DBClusterNotFoundFault,
+                        )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            StartDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StartDBClusterError::InvalidDBClusterStateFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            StartDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StartDBClusterError::InvalidDBInstanceStateFault,
                         )
                     }
                     _ => {}
@@ -9046,18 +9319,27 @@ impl StopDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(StopDBClusterError::DBClusterNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            StopDBClusterError::DBClusterNotFoundFault,
+                        )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            StopDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StopDBClusterError::InvalidDBClusterStateFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            StopDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StopDBClusterError::InvalidDBInstanceStateFault,
                         )
                     }
                     _ => {}
@@ -9363,11 +9645,12 @@ pub struct DocdbClient {
 impl DocdbClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> DocdbClient {
         DocdbClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "DocDB"),
         }
     }
 
@@ -9382,12 +9665,32 @@ impl DocdbClient {
     {
         DocdbClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "DocDB"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> DocdbClient {
-        DocdbClient { client, region }
+        DocdbClient {
+            client,
+            region: region_for_service(region, "DocDB"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> DocdbClient {
+        DocdbClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("DocDB"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> DocdbClient {
+        DocdbClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -9399,6 +9702,7 @@ impl Docdb for DocdbClient {
         input: AddTagsToResourceMessage,
     ) -> Result<(), RusotoError<AddTagsToResourceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("AddTagsToResource");
         let params = self.new_params("AddTagsToResource");
         let mut params = params;
         AddTagsToResourceMessageSerializer::serialize(&mut params, "", &input);
@@ -9420,6 +9724,7 @@ impl Docdb for DocdbClient {
     ) -> Result<ApplyPendingMaintenanceActionResult, RusotoError<ApplyPendingMaintenanceActionError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ApplyPendingMaintenanceAction");
         let params = self.new_params("ApplyPendingMaintenanceAction");
         let mut params = params;
         ApplyPendingMaintenanceActionMessageSerializer::serialize(&mut params, "", &input);
@@ -9454,6 +9759,7 @@ impl Docdb for DocdbClient {
     ) -> Result<CopyDBClusterParameterGroupResult, RusotoError<CopyDBClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CopyDBClusterParameterGroup");
         let params = self.new_params("CopyDBClusterParameterGroup");
         let mut params = params;
         CopyDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -9487,6 +9793,7 @@ impl Docdb for DocdbClient {
         input: CopyDBClusterSnapshotMessage,
     ) -> Result<CopyDBClusterSnapshotResult, RusotoError<CopyDBClusterSnapshotError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CopyDBClusterSnapshot");
         let params = self.new_params("CopyDBClusterSnapshot");
         let mut params = params;
         CopyDBClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -9520,6 +9827,7 @@ impl Docdb for DocdbClient {
         input: CreateDBClusterMessage,
     ) -> Result<CreateDBClusterResult, RusotoError<CreateDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBCluster");
         let params = self.new_params("CreateDBCluster");
         let mut params = params;
         CreateDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -9552,6 +9860,7 @@ impl Docdb for DocdbClient {
     ) -> Result<CreateDBClusterParameterGroupResult, RusotoError<CreateDBClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBClusterParameterGroup");
         let params = self.new_params("CreateDBClusterParameterGroup");
         let mut params = params;
         CreateDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -9585,6 +9894,7 @@ impl Docdb for DocdbClient {
         input: CreateDBClusterSnapshotMessage,
     ) -> Result<CreateDBClusterSnapshotResult, RusotoError<CreateDBClusterSnapshotError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBClusterSnapshot");
         let params = self.new_params("CreateDBClusterSnapshot");
         let mut params = params;
         CreateDBClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -9618,6 +9928,7 @@ impl Docdb for DocdbClient {
         input: CreateDBInstanceMessage,
     ) -> Result<CreateDBInstanceResult, RusotoError<CreateDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBInstance");
         let params = self.new_params("CreateDBInstance");
         let mut params = params;
         CreateDBInstanceMessageSerializer::serialize(&mut params, "", &input);
@@ -9649,6 +9960,7 @@ impl Docdb for DocdbClient {
         input: CreateDBSubnetGroupMessage,
     ) -> Result<CreateDBSubnetGroupResult, RusotoError<CreateDBSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBSubnetGroup");
         let params = self.new_params("CreateDBSubnetGroup");
         let mut params = params;
         CreateDBSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -9682,6 +9994,7 @@ impl Docdb for DocdbClient {
         input: DeleteDBClusterMessage,
     ) -> Result<DeleteDBClusterResult, RusotoError<DeleteDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBCluster");
         let params = self.new_params("DeleteDBCluster");
         let mut params = params;
         DeleteDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -9713,6 +10026,7 @@ impl Docdb for DocdbClient {
         input: DeleteDBClusterParameterGroupMessage,
     ) -> Result<(), RusotoError<DeleteDBClusterParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBClusterParameterGroup");
         let params = self.new_params("DeleteDBClusterParameterGroup");
         let mut params = params;
         DeleteDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -9733,6 +10047,7 @@ impl Docdb for DocdbClient {
         input: DeleteDBClusterSnapshotMessage,
     ) -> Result<DeleteDBClusterSnapshotResult, RusotoError<DeleteDBClusterSnapshotError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBClusterSnapshot");
         let params = self.new_params("DeleteDBClusterSnapshot");
         let mut params = params;
         DeleteDBClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -9766,6 +10081,7 @@ impl Docdb for DocdbClient {
         input: DeleteDBInstanceMessage,
     ) -> Result<DeleteDBInstanceResult, RusotoError<DeleteDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBInstance");
         let params = self.new_params("DeleteDBInstance");
         let mut params = params;
         DeleteDBInstanceMessageSerializer::serialize(&mut params, "", &input);
@@ -9797,6 +10113,7 @@ impl Docdb for DocdbClient {
         input: DeleteDBSubnetGroupMessage,
     ) -> Result<(), RusotoError<DeleteDBSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBSubnetGroup");
         let params = self.new_params("DeleteDBSubnetGroup");
         let mut params = params;
         DeleteDBSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -9817,6 +10134,7 @@ impl Docdb for DocdbClient {
         input: DescribeCertificatesMessage,
     ) -> Result<CertificateMessage, RusotoError<DescribeCertificatesError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeCertificates");
         let params = self.new_params("DescribeCertificates");
         let mut params = params;
         DescribeCertificatesMessageSerializer::serialize(&mut params, "", &input);
@@ -9849,6 +10167,7 @@ impl Docdb for DocdbClient {
     ) -> Result<DBClusterParameterGroupsMessage, RusotoError<DescribeDBClusterParameterGroupsError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterParameterGroups");
         let params = self.new_params("DescribeDBClusterParameterGroups");
         let mut params = params;
         DescribeDBClusterParameterGroupsMessageSerializer::serialize(&mut params, "", &input);
@@ -9885,6 +10204,7 @@ impl Docdb for DocdbClient {
         input: DescribeDBClusterParametersMessage,
     ) -> Result<DBClusterParameterGroupDetails, RusotoError<DescribeDBClusterParametersError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterParameters");
         let params = self.new_params("DescribeDBClusterParameters");
         let mut params = params;
         DescribeDBClusterParametersMessageSerializer::serialize(&mut params, "", &input);
@@ -9921,6 +10241,7 @@ impl Docdb for DocdbClient {
         RusotoError<DescribeDBClusterSnapshotAttributesError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterSnapshotAttributes");
         let params = self.new_params("DescribeDBClusterSnapshotAttributes");
         let mut params = params;
         DescribeDBClusterSnapshotAttributesMessageSerializer::serialize(&mut params, "", &input);
@@ -9957,6 +10278,7 @@ impl Docdb for DocdbClient {
         input: DescribeDBClusterSnapshotsMessage,
     ) -> Result<DBClusterSnapshotMessage, RusotoError<DescribeDBClusterSnapshotsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterSnapshots");
         let params = self.new_params("DescribeDBClusterSnapshots");
         let mut params = params;
         DescribeDBClusterSnapshotsMessageSerializer::serialize(&mut params, "", &input);
@@ -9990,6 +10312,7 @@ impl Docdb for DocdbClient {
         input: DescribeDBClustersMessage,
     ) -> Result<DBClusterMessage, RusotoError<DescribeDBClustersError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusters");
         let params = self.new_params("DescribeDBClusters");
         let mut params = params;
         DescribeDBClustersMessageSerializer::serialize(&mut params, "", &input);
@@ -10021,6 +10344,7 @@ impl Docdb for DocdbClient {
         input: DescribeDBEngineVersionsMessage,
     ) -> Result<DBEngineVersionMessage, RusotoError<DescribeDBEngineVersionsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBEngineVersions");
         let params = self.new_params("DescribeDBEngineVersions");
         let mut params = params;
         DescribeDBEngineVersionsMessageSerializer::serialize(&mut params, "", &input);
@@ -10054,6 +10378,7 @@ impl Docdb for DocdbClient {
         input: DescribeDBInstancesMessage,
     ) -> Result<DBInstanceMessage, RusotoError<DescribeDBInstancesError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBInstances");
         let params = self.new_params("DescribeDBInstances");
         let mut params = params;
         DescribeDBInstancesMessageSerializer::serialize(&mut params, "", &input);
@@ -10085,6 +10410,7 @@ impl Docdb for DocdbClient {
         input: DescribeDBSubnetGroupsMessage,
     ) -> Result<DBSubnetGroupMessage, RusotoError<DescribeDBSubnetGroupsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBSubnetGroups");
         let params = self.new_params("DescribeDBSubnetGroups");
         let mut params = params;
         DescribeDBSubnetGroupsMessageSerializer::serialize(&mut params, "", &input);
@@ -10121,6 +10447,7 @@ impl Docdb for DocdbClient {
         RusotoError<DescribeEngineDefaultClusterParametersError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeEngineDefaultClusterParameters");
         let params = self.new_params("DescribeEngineDefaultClusterParameters");
         let mut params = params;
         DescribeEngineDefaultClusterParametersMessageSerializer::serialize(&mut params, "", &input);
@@ -10157,6 +10484,7 @@ impl Docdb for DocdbClient {
         input: DescribeEventCategoriesMessage,
     ) -> Result<EventCategoriesMessage, RusotoError<DescribeEventCategoriesError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeEventCategories");
         let params = self.new_params("DescribeEventCategories");
         let mut params = params;
         DescribeEventCategoriesMessageSerializer::serialize(&mut params, "", &input);
@@ -10190,6 +10518,7 @@ impl Docdb for DocdbClient {
         input: DescribeEventsMessage,
     ) -> Result<EventsMessage, RusotoError<DescribeEventsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeEvents");
         let params = self.new_params("DescribeEvents");
         let mut params = params;
         DescribeEventsMessageSerializer::serialize(&mut params, "", &input);
@@ -10223,6 +10552,7 @@ impl Docdb for DocdbClient {
         RusotoError<DescribeOrderableDBInstanceOptionsError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeOrderableDBInstanceOptions");
         let params = self.new_params("DescribeOrderableDBInstanceOptions");
         let mut params = params;
         DescribeOrderableDBInstanceOptionsMessageSerializer::serialize(&mut params, "", &input);
@@ -10260,6 +10590,7 @@ impl Docdb for DocdbClient {
     ) -> Result<PendingMaintenanceActionsMessage, RusotoError<DescribePendingMaintenanceActionsError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribePendingMaintenanceActions");
         let params = self.new_params("DescribePendingMaintenanceActions");
         let mut params = params;
         DescribePendingMaintenanceActionsMessageSerializer::serialize(&mut params, "", &input);
@@ -10296,6 +10627,7 @@ impl Docdb for DocdbClient {
         input: FailoverDBClusterMessage,
     ) -> Result<FailoverDBClusterResult, RusotoError<FailoverDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("FailoverDBCluster");
         let params = self.new_params("FailoverDBCluster");
         let mut params = params;
         FailoverDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -10327,6 +10659,7 @@ impl Docdb for DocdbClient {
         input: ListTagsForResourceMessage,
     ) -> Result<TagListMessage, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ListTagsForResource");
         let params = self.new_params("ListTagsForResource");
         let mut params = params;
         ListTagsForResourceMessageSerializer::serialize(&mut params, "", &input);
@@ -10358,6 +10691,7 @@ impl Docdb for DocdbClient {
         input: ModifyDBClusterMessage,
     ) -> Result<ModifyDBClusterResult, RusotoError<ModifyDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBCluster");
         let params = self.new_params("ModifyDBCluster");
         let mut params = params;
         ModifyDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -10390,6 +10724,7 @@ impl Docdb for DocdbClient {
     ) -> Result<DBClusterParameterGroupNameMessage, RusotoError<ModifyDBClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBClusterParameterGroup");
         let params = self.new_params("ModifyDBClusterParameterGroup");
         let mut params = params;
         ModifyDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -10426,6 +10761,7 @@ impl Docdb for DocdbClient {
         RusotoError<ModifyDBClusterSnapshotAttributeError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBClusterSnapshotAttribute");
         let params = self.new_params("ModifyDBClusterSnapshotAttribute");
         let mut params = params;
         ModifyDBClusterSnapshotAttributeMessageSerializer::serialize(&mut params, "", &input);
@@ -10462,6 +10798,7 @@ impl Docdb for DocdbClient {
         input: ModifyDBInstanceMessage,
     ) -> Result<ModifyDBInstanceResult, RusotoError<ModifyDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBInstance");
         let params = self.new_params("ModifyDBInstance");
         let mut params = params;
         ModifyDBInstanceMessageSerializer::serialize(&mut params, "", &input);
@@ -10493,6 +10830,7 @@ impl Docdb for DocdbClient {
         input: ModifyDBSubnetGroupMessage,
     ) -> Result<ModifyDBSubnetGroupResult, RusotoError<ModifyDBSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBSubnetGroup");
         let params = self.new_params("ModifyDBSubnetGroup");
         let mut params = params;
         ModifyDBSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -10526,6 +10864,7 @@ impl Docdb for DocdbClient {
         input: RebootDBInstanceMessage,
     ) -> Result<RebootDBInstanceResult, RusotoError<RebootDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RebootDBInstance");
         let params = self.new_params("RebootDBInstance");
         let mut params = params;
         RebootDBInstanceMessageSerializer::serialize(&mut params, "", &input);
@@ -10557,6 +10896,7 @@ impl Docdb for DocdbClient {
         input: RemoveTagsFromResourceMessage,
     ) -> Result<(), RusotoError<RemoveTagsFromResourceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RemoveTagsFromResource");
         let params = self.new_params("RemoveTagsFromResource");
         let mut params = params;
         RemoveTagsFromResourceMessageSerializer::serialize(&mut params, "", &input);
@@ -10578,6 +10918,7 @@ impl Docdb for DocdbClient {
     ) -> Result<DBClusterParameterGroupNameMessage, RusotoError<ResetDBClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ResetDBClusterParameterGroup");
         let params = self.new_params("ResetDBClusterParameterGroup");
         let mut params = params;
         ResetDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -10612,6 +10953,7 @@ impl Docdb for DocdbClient {
     ) -> Result<RestoreDBClusterFromSnapshotResult, RusotoError<RestoreDBClusterFromSnapshotError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RestoreDBClusterFromSnapshot");
         let params = self.new_params("RestoreDBClusterFromSnapshot");
         let mut params = params;
         RestoreDBClusterFromSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -10646,6 +10988,7 @@ impl Docdb for DocdbClient {
     ) -> Result<RestoreDBClusterToPointInTimeResult, RusotoError<RestoreDBClusterToPointInTimeError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RestoreDBClusterToPointInTime");
         let params = self.new_params("RestoreDBClusterToPointInTime");
         let mut params = params;
         RestoreDBClusterToPointInTimeMessageSerializer::serialize(&mut params, "", &input);
@@ -10679,6 +11022,7 @@ impl Docdb for DocdbClient {
         input: StartDBClusterMessage,
     ) -> Result<StartDBClusterResult, RusotoError<StartDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("StartDBCluster");
         let params = self.new_params("StartDBCluster");
         let mut params = params;
         StartDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -10710,6 +11054,7 @@ impl Docdb for DocdbClient {
         input: StopDBClusterMessage,
     ) -> Result<StopDBClusterResult, RusotoError<StopDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("StopDBCluster");
         let params = self.new_params("StopDBCluster");
         let mut params = params;
         StopDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -10735,3 +11080,469 @@ impl Docdb for DocdbClient {
         Ok(result)
     }
 }
+
+/// A blocking client for the Amazon DocDB API, running the calls of a `DocdbClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `DocdbClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct DocdbBlockingClient {
+    client: DocdbClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl DocdbBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> DocdbBlockingClient {
+        DocdbBlockingClient::from_client(DocdbClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> DocdbBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        DocdbBlockingClient::from_client(DocdbClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> DocdbBlockingClient {
+        DocdbBlockingClient::from_client(DocdbClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> DocdbBlockingClient {
+        DocdbBlockingClient::from_client(DocdbClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: DocdbClient) -> DocdbBlockingClient {
+        DocdbBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> DocdbBlockingClient {
+        DocdbBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> DocdbBlockingClient {
+        DocdbBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Docdb::add_tags_to_resource`].
+    pub fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceMessage,
+    ) -> Result<(), RusotoError<AddTagsToResourceError>> {
+        self.runtime
+            .block_on(self.client.add_tags_to_resource(input))
+    }
+
+    /// Blocking version of [`Docdb::apply_pending_maintenance_action`].
+    pub fn apply_pending_maintenance_action(
+        &self,
+        input: ApplyPendingMaintenanceActionMessage,
+    ) -> Result<ApplyPendingMaintenanceActionResult, RusotoError<ApplyPendingMaintenanceActionError>>
+    {
+        self.runtime
+            .block_on(self.client.apply_pending_maintenance_action(input))
+    }
+
+    /// Blocking version of [`Docdb::copy_db_cluster_parameter_group`].
+    pub fn copy_db_cluster_parameter_group(
+        &self,
+        input: CopyDBClusterParameterGroupMessage,
+    ) -> Result<CopyDBClusterParameterGroupResult, RusotoError<CopyDBClusterParameterGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.copy_db_cluster_parameter_group(input))
+    }
+
+    /// Blocking version of [`Docdb::copy_db_cluster_snapshot`].
+    pub fn copy_db_cluster_snapshot(
+        &self,
+        input: CopyDBClusterSnapshotMessage,
+    ) -> Result<CopyDBClusterSnapshotResult, RusotoError<CopyDBClusterSnapshotError>> {
+        self.runtime
+            .block_on(self.client.copy_db_cluster_snapshot(input))
+    }
+
+    /// Blocking version of [`Docdb::create_db_cluster`].
+    pub fn create_db_cluster(
+        &self,
+        input: CreateDBClusterMessage,
+    ) -> Result<CreateDBClusterResult, RusotoError<CreateDBClusterError>> {
+        self.runtime.block_on(self.client.create_db_cluster(input))
+    }
+
+    /// Blocking version of [`Docdb::create_db_cluster_parameter_group`].
+    pub fn create_db_cluster_parameter_group(
+        &self,
+        input: CreateDBClusterParameterGroupMessage,
+    ) -> Result<CreateDBClusterParameterGroupResult, RusotoError<CreateDBClusterParameterGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.create_db_cluster_parameter_group(input))
+    }
+
+    /// Blocking version of [`Docdb::create_db_cluster_snapshot`].
+    pub fn create_db_cluster_snapshot(
+        &self,
+        input: CreateDBClusterSnapshotMessage,
+    ) -> Result<CreateDBClusterSnapshotResult, RusotoError<CreateDBClusterSnapshotError>> {
+        self.runtime
+            .block_on(self.client.create_db_cluster_snapshot(input))
+    }
+
+    /// Blocking version of [`Docdb::create_db_instance`].
+    pub fn create_db_instance(
+        &self,
+        input: CreateDBInstanceMessage,
+    ) -> Result<CreateDBInstanceResult, RusotoError<CreateDBInstanceError>> {
+        self.runtime.block_on(self.client.create_db_instance(input))
+    }
+
+    /// Blocking version of [`Docdb::create_db_subnet_group`].
+    pub fn create_db_subnet_group(
+        &self,
+        input: CreateDBSubnetGroupMessage,
+    ) -> Result<CreateDBSubnetGroupResult, RusotoError<CreateDBSubnetGroupError>> {
+        self.runtime
+            .block_on(self.client.create_db_subnet_group(input))
+    }
+
+    /// Blocking version of [`Docdb::delete_db_cluster`].
+    pub fn delete_db_cluster(
+        &self,
+        input: DeleteDBClusterMessage,
+    ) -> Result<DeleteDBClusterResult, RusotoError<DeleteDBClusterError>> {
+        self.runtime.block_on(self.client.delete_db_cluster(input))
+    }
+
+    /// Blocking version of [`Docdb::delete_db_cluster_parameter_group`].
+    pub fn delete_db_cluster_parameter_group(
+        &self,
+        input: DeleteDBClusterParameterGroupMessage,
+    ) -> Result<(), RusotoError<DeleteDBClusterParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_db_cluster_parameter_group(input))
+    }
+
+    /// Blocking version of [`Docdb::delete_db_cluster_snapshot`].
+    pub fn delete_db_cluster_snapshot(
+        &self,
+        input: DeleteDBClusterSnapshotMessage,
+    ) -> Result<DeleteDBClusterSnapshotResult, RusotoError<DeleteDBClusterSnapshotError>> {
+        self.runtime
+            .block_on(self.client.delete_db_cluster_snapshot(input))
+    }
+
+    /// Blocking version of [`Docdb::delete_db_instance`].
+    pub fn delete_db_instance(
+        &self,
+        input: DeleteDBInstanceMessage,
+    ) -> Result<DeleteDBInstanceResult, RusotoError<DeleteDBInstanceError>> {
+        self.runtime.block_on(self.client.delete_db_instance(input))
+    }
+
+    /// Blocking version of [`Docdb::delete_db_subnet_group`].
+    pub fn delete_db_subnet_group(
+        &self,
+        input: DeleteDBSubnetGroupMessage,
+    ) -> Result<(), RusotoError<DeleteDBSubnetGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_db_subnet_group(input))
+    }
+
+    /// Blocking version of [`Docdb::describe_certificates`].
+    pub fn describe_certificates(
+        &self,
+        input: DescribeCertificatesMessage,
+    ) -> Result<CertificateMessage, RusotoError<DescribeCertificatesError>> {
+        self.runtime
+            .block_on(self.client.describe_certificates(input))
+    }
+
+    /// Blocking version of [`Docdb::describe_db_cluster_parameter_groups`].
+    pub fn describe_db_cluster_parameter_groups(
+        &self,
+        input: DescribeDBClusterParameterGroupsMessage,
+    ) -> Result<DBClusterParameterGroupsMessage, RusotoError<DescribeDBClusterParameterGroupsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_db_cluster_parameter_groups(input))
+    }
+
+    /// Blocking version of [`Docdb::describe_db_cluster_parameters`].
+    pub fn describe_db_cluster_parameters(
+        &self,
+        input: DescribeDBClusterParametersMessage,
+    ) -> Result<DBClusterParameterGroupDetails, RusotoError<DescribeDBClusterParametersError>> {
+        self.runtime
+            .block_on(self.client.describe_db_cluster_parameters(input))
+    }
+
+    /// Blocking version of [`Docdb::describe_db_cluster_snapshot_attributes`].
+    pub fn describe_db_cluster_snapshot_attributes(
+        &self,
+        input: DescribeDBClusterSnapshotAttributesMessage,
+    ) -> Result<
+        DescribeDBClusterSnapshotAttributesResult,
+        RusotoError<DescribeDBClusterSnapshotAttributesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_db_cluster_snapshot_attributes(input))
+    }
+
+    /// Blocking version of [`Docdb::describe_db_cluster_snapshots`].
+    pub fn describe_db_cluster_snapshots(
+        &self,
+        input: DescribeDBClusterSnapshotsMessage,
+    ) -> Result<DBClusterSnapshotMessage, RusotoError<DescribeDBClusterSnapshotsError>> {
+        self.runtime
+            .block_on(self.client.describe_db_cluster_snapshots(input))
+    }
+
+    /// Blocking version of [`Docdb::describe_db_clusters`].
+    pub fn describe_db_clusters(
+        &self,
+        input: DescribeDBClustersMessage,
+    ) -> Result<DBClusterMessage, RusotoError<DescribeDBClustersError>> {
+        self.runtime
+            .block_on(self.client.describe_db_clusters(input))
+    }
+
+    /// Blocking version of [`Docdb::describe_db_engine_versions`].
+    pub fn describe_db_engine_versions(
+        &self,
+        input: DescribeDBEngineVersionsMessage,
+    ) -> Result<DBEngineVersionMessage, RusotoError<DescribeDBEngineVersionsError>> {
+        self.runtime
+            .block_on(self.client.describe_db_engine_versions(input))
+    }
+
+    /// Blocking version of [`Docdb::describe_db_instances`].
+    pub fn describe_db_instances(
+        &self,
+        input: DescribeDBInstancesMessage,
+    ) -> Result<DBInstanceMessage, RusotoError<DescribeDBInstancesError>> {
+        self.runtime
+            .block_on(self.client.describe_db_instances(input))
+    }
+
+    /// Blocking version of [`Docdb::describe_db_subnet_groups`].
+    pub fn describe_db_subnet_groups(
+        &self,
+        input: DescribeDBSubnetGroupsMessage,
+    ) -> Result<DBSubnetGroupMessage, RusotoError<DescribeDBSubnetGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_db_subnet_groups(input))
+    }
+
+    /// Blocking version of [`Docdb::describe_engine_default_cluster_parameters`].
+    pub fn describe_engine_default_cluster_parameters(
+        &self,
+        input: DescribeEngineDefaultClusterParametersMessage,
+    ) -> Result<
+        DescribeEngineDefaultClusterParametersResult,
+        RusotoError<DescribeEngineDefaultClusterParametersError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_engine_default_cluster_parameters(input),
+        )
+    }
+
+    /// Blocking version of [`Docdb::describe_event_categories`].
+    pub fn describe_event_categories(
+        &self,
+        input: DescribeEventCategoriesMessage,
+    ) -> Result<EventCategoriesMessage, RusotoError<DescribeEventCategoriesError>> {
+        self.runtime
+            .block_on(self.client.describe_event_categories(input))
+    }
+
+    /// Blocking version of [`Docdb::describe_events`].
+    pub fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> Result<EventsMessage, RusotoError<DescribeEventsError>> {
+        self.runtime.block_on(self.client.describe_events(input))
+    }
+
+    /// Blocking version of [`Docdb::describe_orderable_db_instance_options`].
+    pub fn describe_orderable_db_instance_options(
+        &self,
+        input: DescribeOrderableDBInstanceOptionsMessage,
+    ) -> Result<
+        OrderableDBInstanceOptionsMessage,
+        RusotoError<DescribeOrderableDBInstanceOptionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_orderable_db_instance_options(input))
+    }
+
+    /// Blocking version of [`Docdb::describe_pending_maintenance_actions`].
+    pub fn describe_pending_maintenance_actions(
+        &self,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> Result<PendingMaintenanceActionsMessage, RusotoError<DescribePendingMaintenanceActionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_pending_maintenance_actions(input))
+    }
+
+    /// Blocking version of [`Docdb::failover_db_cluster`].
+    pub fn failover_db_cluster(
+        &self,
+        input: FailoverDBClusterMessage,
+    ) -> Result<FailoverDBClusterResult, RusotoError<FailoverDBClusterError>> {
+        self.runtime
+            .block_on(self.client.failover_db_cluster(input))
+    }
+
+    /// Blocking version of [`Docdb::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> Result<TagListMessage, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`Docdb::modify_db_cluster`].
+    pub fn modify_db_cluster(
+        &self,
+        input: ModifyDBClusterMessage,
+    ) -> Result<ModifyDBClusterResult, RusotoError<ModifyDBClusterError>> {
+        self.runtime.block_on(self.client.modify_db_cluster(input))
+    }
+
+    /// Blocking version of [`Docdb::modify_db_cluster_parameter_group`].
+    pub fn modify_db_cluster_parameter_group(
+        &self,
+        input: ModifyDBClusterParameterGroupMessage,
+    ) -> Result<DBClusterParameterGroupNameMessage, RusotoError<ModifyDBClusterParameterGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.modify_db_cluster_parameter_group(input))
+    }
+
+    /// Blocking version of [`Docdb::modify_db_cluster_snapshot_attribute`].
+    pub fn modify_db_cluster_snapshot_attribute(
+        &self,
+        input: ModifyDBClusterSnapshotAttributeMessage,
+    ) -> Result<
+        ModifyDBClusterSnapshotAttributeResult,
+        RusotoError<ModifyDBClusterSnapshotAttributeError>,
+    > {
+        self.runtime
+            .block_on(self.client.modify_db_cluster_snapshot_attribute(input))
+    }
+
+    /// Blocking version of [`Docdb::modify_db_instance`].
+    pub fn modify_db_instance(
+        &self,
+        input: ModifyDBInstanceMessage,
+    ) -> Result<ModifyDBInstanceResult, RusotoError<ModifyDBInstanceError>> {
+        self.runtime.block_on(self.client.modify_db_instance(input))
+    }
+
+    /// Blocking version of [`Docdb::modify_db_subnet_group`].
+    pub fn modify_db_subnet_group(
+        &self,
+        input: ModifyDBSubnetGroupMessage,
+    ) -> Result<ModifyDBSubnetGroupResult, RusotoError<ModifyDBSubnetGroupError>> {
+        self.runtime
+            .block_on(self.client.modify_db_subnet_group(input))
+    }
+
+    /// Blocking version of [`Docdb::reboot_db_instance`].
+    pub fn reboot_db_instance(
+        &self,
+        input: RebootDBInstanceMessage,
+    ) -> Result<RebootDBInstanceResult, RusotoError<RebootDBInstanceError>> {
+        self.runtime.block_on(self.client.reboot_db_instance(input))
+    }
+
+    /// Blocking version of [`Docdb::remove_tags_from_resource`].
+    pub fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceMessage,
+    ) -> Result<(), RusotoError<RemoveTagsFromResourceError>> {
+        self.runtime
+            .block_on(self.client.remove_tags_from_resource(input))
+    }
+
+    /// Blocking version of [`Docdb::reset_db_cluster_parameter_group`].
+    pub fn reset_db_cluster_parameter_group(
+        &self,
+        input: ResetDBClusterParameterGroupMessage,
+    ) -> Result<DBClusterParameterGroupNameMessage, RusotoError<ResetDBClusterParameterGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.reset_db_cluster_parameter_group(input))
+    }
+
+    /// Blocking version of [`Docdb::restore_db_cluster_from_snapshot`].
+    pub fn restore_db_cluster_from_snapshot(
+        &self,
+        input: RestoreDBClusterFromSnapshotMessage,
+    ) -> Result<RestoreDBClusterFromSnapshotResult, RusotoError<RestoreDBClusterFromSnapshotError>>
+    {
+        self.runtime
+            .block_on(self.client.restore_db_cluster_from_snapshot(input))
+    }
+
+    /// Blocking version of [`Docdb::restore_db_cluster_to_point_in_time`].
+    pub fn restore_db_cluster_to_point_in_time(
+        &self,
+        input: RestoreDBClusterToPointInTimeMessage,
+    ) -> Result<RestoreDBClusterToPointInTimeResult, RusotoError<RestoreDBClusterToPointInTimeError>>
+    {
+        self.runtime
+            .block_on(self.client.restore_db_cluster_to_point_in_time(input))
+    }
+
+    /// Blocking version of [`Docdb::start_db_cluster`].
+    pub fn start_db_cluster(
+        &self,
+        input: StartDBClusterMessage,
+    ) -> Result<StartDBClusterResult, RusotoError<StartDBClusterError>> {
+        self.runtime.block_on(self.client.start_db_cluster(input))
+    }
+
+    /// Blocking version of [`Docdb::stop_db_cluster`].
+    pub fn stop_db_cluster(
+        &self,
+        input: StopDBClusterMessage,
+    ) -> Result<StopDBClusterResult, RusotoError<StopDBClusterError>> {
+        self.runtime.block_on(self.client.stop_db_cluster(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -2306,27 +2307,46 @@ impl AcceptSharedDirectoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(AcceptSharedDirectoryError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptSharedDirectoryError::Client,
+                    )
                 }
                 "DirectoryAlreadySharedException" => {
-                    return RusotoError::Service(
-                        AcceptSharedDirectoryError::DirectoryAlreadyShared(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptSharedDirectoryError::DirectoryAlreadyShared,
                     )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(AcceptSharedDirectoryError::EntityDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AcceptSharedDirectoryError::EntityDoesNotExist,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AcceptSharedDirectoryError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AcceptSharedDirectoryError::InvalidParameter,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AcceptSharedDirectoryError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptSharedDirectoryError::Service,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2370,27 +2390,62 @@ impl AddIpRoutesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(AddIpRoutesError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddIpRoutesError::Client,
+                    )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(AddIpRoutesError::DirectoryUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddIpRoutesError::DirectoryUnavailable,
+                    )
                 }
                 "EntityAlreadyExistsException" => {
-                    return RusotoError::Service(AddIpRoutesError::EntityAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddIpRoutesError::EntityAlreadyExists,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(AddIpRoutesError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddIpRoutesError::EntityDoesNotExist,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AddIpRoutesError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddIpRoutesError::InvalidParameter,
+                    )
                 }
                 "IpRouteLimitExceededException" => {
-                    return RusotoError::Service(AddIpRoutesError::IpRouteLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddIpRoutesError::IpRouteLimitExceeded,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AddIpRoutesError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddIpRoutesError::Service,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2442,34 +2497,86 @@ impl AddRegionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AddRegionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddRegionError::AccessDenied,
+                    )
+                }
+                "ClientException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddRegionError::Client,
+                    )
                 }
-                "ClientException" => return RusotoError::Service(AddRegionError::Client(err.msg)),
                 "DirectoryAlreadyInRegionException" => {
-                    return RusotoError::Service(AddRegionError::DirectoryAlreadyInRegion(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddRegionError::DirectoryAlreadyInRegion,
+                    )
                 }
                 "DirectoryDoesNotExistException" => {
-                    return RusotoError::Service(AddRegionError::DirectoryDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddRegionError::DirectoryDoesNotExist,
+                    )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(AddRegionError::DirectoryUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddRegionError::DirectoryUnavailable,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(AddRegionError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddRegionError::EntityDoesNotExist,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AddRegionError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddRegionError::InvalidParameter,
+                    )
                 }
                 "RegionLimitExceededException" => {
-                    return RusotoError::Service(AddRegionError::RegionLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddRegionError::RegionLimitExceeded,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AddRegionError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddRegionError::Service,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(AddRegionError::UnsupportedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddRegionError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2514,23 +2621,46 @@ impl AddTagsToResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(AddTagsToResourceError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsToResourceError::Client,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(AddTagsToResourceError::EntityDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddTagsToResourceError::EntityDoesNotExist,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AddTagsToResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsToResourceError::InvalidParameter,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AddTagsToResourceError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsToResourceError::Service,
+                    )
                 }
                 "TagLimitExceededException" => {
-                    return RusotoError::Service(AddTagsToResourceError::TagLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsToResourceError::TagLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2566,17 +2696,30 @@ impl CancelSchemaExtensionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CancelSchemaExtensionError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelSchemaExtensionError::Client,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(CancelSchemaExtensionError::EntityDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelSchemaExtensionError::EntityDoesNotExist,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CancelSchemaExtensionError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelSchemaExtensionError::Service,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2612,20 +2755,38 @@ impl ConnectDirectoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ConnectDirectoryError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConnectDirectoryError::Client,
+                    )
                 }
                 "DirectoryLimitExceededException" => {
-                    return RusotoError::Service(ConnectDirectoryError::DirectoryLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ConnectDirectoryError::DirectoryLimitExceeded,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ConnectDirectoryError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConnectDirectoryError::InvalidParameter,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ConnectDirectoryError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConnectDirectoryError::Service,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2664,21 +2825,46 @@ impl CreateAliasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateAliasError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAliasError::Client,
+                    )
                 }
                 "EntityAlreadyExistsException" => {
-                    return RusotoError::Service(CreateAliasError::EntityAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAliasError::EntityAlreadyExists,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(CreateAliasError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAliasError::EntityDoesNotExist,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateAliasError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAliasError::InvalidParameter,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateAliasError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAliasError::Service,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2724,30 +2910,70 @@ impl CreateComputerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthenticationFailedException" => {
-                    return RusotoError::Service(CreateComputerError::AuthenticationFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateComputerError::AuthenticationFailed,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(CreateComputerError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateComputerError::Client,
+                    )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(CreateComputerError::DirectoryUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateComputerError::DirectoryUnavailable,
+                    )
                 }
                 "EntityAlreadyExistsException" => {
-                    return RusotoError::Service(CreateComputerError::EntityAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateComputerError::EntityAlreadyExists,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(CreateComputerError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateComputerError::EntityDoesNotExist,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateComputerError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateComputerError::InvalidParameter,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateComputerError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateComputerError::Service,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(CreateComputerError::UnsupportedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateComputerError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2796,37 +3022,62 @@ impl CreateConditionalForwarderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateConditionalForwarderError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConditionalForwarderError::Client,
+                    )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(
-                        CreateConditionalForwarderError::DirectoryUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConditionalForwarderError::DirectoryUnavailable,
                     )
                 }
                 "EntityAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        CreateConditionalForwarderError::EntityAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConditionalForwarderError::EntityAlreadyExists,
                     )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(
-                        CreateConditionalForwarderError::EntityDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConditionalForwarderError::EntityDoesNotExist,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateConditionalForwarderError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateConditionalForwarderError::InvalidParameter,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateConditionalForwarderError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConditionalForwarderError::Service,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
-                        CreateConditionalForwarderError::UnsupportedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConditionalForwarderError::UnsupportedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2874,20 +3125,38 @@ impl CreateDirectoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateDirectoryError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDirectoryError::Client,
+                    )
                 }
                 "DirectoryLimitExceededException" => {
-                    return RusotoError::Service(CreateDirectoryError::DirectoryLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDirectoryError::DirectoryLimitExceeded,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateDirectoryError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDirectoryError::InvalidParameter,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateDirectoryError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDirectoryError::Service,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2928,32 +3197,54 @@ impl CreateLogSubscriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateLogSubscriptionError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLogSubscriptionError::Client,
+                    )
                 }
                 "EntityAlreadyExistsException" => {
-                    return RusotoError::Service(CreateLogSubscriptionError::EntityAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateLogSubscriptionError::EntityAlreadyExists,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(CreateLogSubscriptionError::EntityDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateLogSubscriptionError::EntityDoesNotExist,
+                    )
                 }
                 "InsufficientPermissionsException" => {
-                    return RusotoError::Service(
-                        CreateLogSubscriptionError::InsufficientPermissions(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLogSubscriptionError::InsufficientPermissions,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateLogSubscriptionError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLogSubscriptionError::Service,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(CreateLogSubscriptionError::UnsupportedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateLogSubscriptionError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2996,25 +3287,46 @@ impl CreateMicrosoftADError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateMicrosoftADError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMicrosoftADError::Client,
+                    )
                 }
                 "DirectoryLimitExceededException" => {
-                    return RusotoError::Service(CreateMicrosoftADError::DirectoryLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateMicrosoftADError::DirectoryLimitExceeded,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateMicrosoftADError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMicrosoftADError::InvalidParameter,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateMicrosoftADError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMicrosoftADError::Service,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(CreateMicrosoftADError::UnsupportedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateMicrosoftADError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3054,23 +3366,46 @@ impl CreateSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateSnapshotError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSnapshotError::Client,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(CreateSnapshotError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSnapshotError::EntityDoesNotExist,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateSnapshotError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSnapshotError::InvalidParameter,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateSnapshotError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSnapshotError::Service,
+                    )
                 }
                 "SnapshotLimitExceededException" => {
-                    return RusotoError::Service(CreateSnapshotError::SnapshotLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSnapshotError::SnapshotLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3112,24 +3447,54 @@ impl CreateTrustError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateTrustError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTrustError::Client,
+                    )
                 }
                 "EntityAlreadyExistsException" => {
-                    return RusotoError::Service(CreateTrustError::EntityAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTrustError::EntityAlreadyExists,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(CreateTrustError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTrustError::EntityDoesNotExist,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateTrustError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTrustError::InvalidParameter,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateTrustError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTrustError::Service,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(CreateTrustError::UnsupportedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTrustError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3174,32 +3539,54 @@ impl DeleteConditionalForwarderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteConditionalForwarderError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteConditionalForwarderError::Client,
+                    )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(
-                        DeleteConditionalForwarderError::DirectoryUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteConditionalForwarderError::DirectoryUnavailable,
                     )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(
-                        DeleteConditionalForwarderError::EntityDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteConditionalForwarderError::EntityDoesNotExist,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteConditionalForwarderError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteConditionalForwarderError::InvalidParameter,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteConditionalForwarderError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteConditionalForwarderError::Service,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
-                        DeleteConditionalForwarderError::UnsupportedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteConditionalForwarderError::UnsupportedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3242,15 +3629,30 @@ impl DeleteDirectoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteDirectoryError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDirectoryError::Client,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(DeleteDirectoryError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDirectoryError::EntityDoesNotExist,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteDirectoryError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDirectoryError::Service,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3286,22 +3688,38 @@ impl DeleteLogSubscriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteLogSubscriptionError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLogSubscriptionError::Client,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(DeleteLogSubscriptionError::EntityDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteLogSubscriptionError::EntityDoesNotExist,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteLogSubscriptionError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLogSubscriptionError::Service,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DeleteLogSubscriptionError::UnsupportedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteLogSubscriptionError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3338,18 +3756,38 @@ impl DeleteSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteSnapshotError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSnapshotError::Client,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(DeleteSnapshotError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSnapshotError::EntityDoesNotExist,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteSnapshotError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSnapshotError::InvalidParameter,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteSnapshotError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSnapshotError::Service,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3388,21 +3826,46 @@ impl DeleteTrustError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteTrustError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTrustError::Client,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(DeleteTrustError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTrustError::EntityDoesNotExist,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteTrustError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTrustError::InvalidParameter,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteTrustError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTrustError::Service,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DeleteTrustError::UnsupportedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTrustError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3448,42 +3911,70 @@ impl DeregisterCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CertificateDoesNotExistException" => {
-                    return RusotoError::Service(
-                        DeregisterCertificateError::CertificateDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterCertificateError::CertificateDoesNotExist,
                     )
                 }
                 "CertificateInUseException" => {
-                    return RusotoError::Service(DeregisterCertificateError::CertificateInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeregisterCertificateError::CertificateInUse,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(DeregisterCertificateError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterCertificateError::Client,
+                    )
                 }
                 "DirectoryDoesNotExistException" => {
-                    return RusotoError::Service(DeregisterCertificateError::DirectoryDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeregisterCertificateError::DirectoryDoesNotExist,
+                    )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(DeregisterCertificateError::DirectoryUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeregisterCertificateError::DirectoryUnavailable,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeregisterCertificateError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeregisterCertificateError::InvalidParameter,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeregisterCertificateError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterCertificateError::Service,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DeregisterCertificateError::UnsupportedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeregisterCertificateError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3526,22 +4017,38 @@ impl DeregisterEventTopicError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeregisterEventTopicError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterEventTopicError::Client,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(DeregisterEventTopicError::EntityDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeregisterEventTopicError::EntityDoesNotExist,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeregisterEventTopicError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeregisterEventTopicError::InvalidParameter,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeregisterEventTopicError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterEventTopicError::Service,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3582,32 +4089,54 @@ impl DescribeCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CertificateDoesNotExistException" => {
-                    return RusotoError::Service(DescribeCertificateError::CertificateDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeCertificateError::CertificateDoesNotExist,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(DescribeCertificateError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCertificateError::Client,
+                    )
                 }
                 "DirectoryDoesNotExistException" => {
-                    return RusotoError::Service(DescribeCertificateError::DirectoryDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeCertificateError::DirectoryDoesNotExist,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeCertificateError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeCertificateError::InvalidParameter,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeCertificateError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCertificateError::Service,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DescribeCertificateError::UnsupportedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeCertificateError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3652,36 +4181,54 @@ impl DescribeConditionalForwardersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeConditionalForwardersError::Client(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeConditionalForwardersError::Client,
+                    )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(
-                        DescribeConditionalForwardersError::DirectoryUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeConditionalForwardersError::DirectoryUnavailable,
                     )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(
-                        DescribeConditionalForwardersError::EntityDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeConditionalForwardersError::EntityDoesNotExist,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        DescribeConditionalForwardersError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeConditionalForwardersError::InvalidParameter,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeConditionalForwardersError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeConditionalForwardersError::Service,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
-                        DescribeConditionalForwardersError::UnsupportedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeConditionalForwardersError::UnsupportedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3730,27 +4277,46 @@ impl DescribeDirectoriesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeDirectoriesError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDirectoriesError::Client,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(DescribeDirectoriesError::EntityDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDirectoriesError::EntityDoesNotExist,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeDirectoriesError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDirectoriesError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeDirectoriesError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDirectoriesError::InvalidParameter,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeDirectoriesError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDirectoriesError::Service,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3792,32 +4358,54 @@ impl DescribeDomainControllersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeDomainControllersError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDomainControllersError::Client,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(
-                        DescribeDomainControllersError::EntityDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDomainControllersError::EntityDoesNotExist,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeDomainControllersError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDomainControllersError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeDomainControllersError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDomainControllersError::InvalidParameter,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeDomainControllersError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDomainControllersError::Service,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
-                        DescribeDomainControllersError::UnsupportedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDomainControllersError::UnsupportedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3858,22 +4446,38 @@ impl DescribeEventTopicsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeEventTopicsError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEventTopicsError::Client,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(DescribeEventTopicsError::EntityDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEventTopicsError::EntityDoesNotExist,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeEventTopicsError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEventTopicsError::InvalidParameter,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeEventTopicsError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEventTopicsError::Service,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3914,32 +4518,54 @@ impl DescribeLDAPSSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeLDAPSSettingsError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeLDAPSSettingsError::Client,
+                    )
                 }
                 "DirectoryDoesNotExistException" => {
-                    return RusotoError::Service(DescribeLDAPSSettingsError::DirectoryDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeLDAPSSettingsError::DirectoryDoesNotExist,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeLDAPSSettingsError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeLDAPSSettingsError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeLDAPSSettingsError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeLDAPSSettingsError::InvalidParameter,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeLDAPSSettingsError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeLDAPSSettingsError::Service,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DescribeLDAPSSettingsError::UnsupportedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeLDAPSSettingsError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3984,31 +4610,62 @@ impl DescribeRegionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeRegionsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRegionsError::AccessDenied,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(DescribeRegionsError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRegionsError::Client,
+                    )
                 }
                 "DirectoryDoesNotExistException" => {
-                    return RusotoError::Service(DescribeRegionsError::DirectoryDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRegionsError::DirectoryDoesNotExist,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeRegionsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRegionsError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeRegionsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRegionsError::InvalidParameter,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeRegionsError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRegionsError::Service,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DescribeRegionsError::UnsupportedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRegionsError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4052,32 +4709,54 @@ impl DescribeSharedDirectoriesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeSharedDirectoriesError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSharedDirectoriesError::Client,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(
-                        DescribeSharedDirectoriesError::EntityDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSharedDirectoriesError::EntityDoesNotExist,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeSharedDirectoriesError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeSharedDirectoriesError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeSharedDirectoriesError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeSharedDirectoriesError::InvalidParameter,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeSharedDirectoriesError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSharedDirectoriesError::Service,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
-                        DescribeSharedDirectoriesError::UnsupportedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSharedDirectoriesError::UnsupportedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4120,23 +4799,46 @@ impl DescribeSnapshotsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeSnapshotsError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSnapshotsError::Client,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(DescribeSnapshotsError::EntityDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeSnapshotsError::EntityDoesNotExist,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeSnapshotsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSnapshotsError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeSnapshotsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSnapshotsError::InvalidParameter,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeSnapshotsError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSnapshotsError::Service,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4178,24 +4880,54 @@ impl DescribeTrustsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeTrustsError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTrustsError::Client,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(DescribeTrustsError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTrustsError::EntityDoesNotExist,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeTrustsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTrustsError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeTrustsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTrustsError::InvalidParameter,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeTrustsError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTrustsError::Service,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DescribeTrustsError::UnsupportedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTrustsError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4240,32 +4972,54 @@ impl DisableClientAuthenticationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DisableClientAuthenticationError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisableClientAuthenticationError::AccessDenied,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(DisableClientAuthenticationError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableClientAuthenticationError::Client,
+                    )
                 }
                 "DirectoryDoesNotExistException" => {
-                    return RusotoError::Service(
-                        DisableClientAuthenticationError::DirectoryDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableClientAuthenticationError::DirectoryDoesNotExist,
                     )
                 }
                 "InvalidClientAuthStatusException" => {
-                    return RusotoError::Service(
-                        DisableClientAuthenticationError::InvalidClientAuthStatus(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableClientAuthenticationError::InvalidClientAuthStatus,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DisableClientAuthenticationError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableClientAuthenticationError::Service,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
-                        DisableClientAuthenticationError::UnsupportedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableClientAuthenticationError::UnsupportedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4316,27 +5070,62 @@ impl DisableLDAPSError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DisableLDAPSError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableLDAPSError::Client,
+                    )
                 }
                 "DirectoryDoesNotExistException" => {
-                    return RusotoError::Service(DisableLDAPSError::DirectoryDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableLDAPSError::DirectoryDoesNotExist,
+                    )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(DisableLDAPSError::DirectoryUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableLDAPSError::DirectoryUnavailable,
+                    )
                 }
                 "InvalidLDAPSStatusException" => {
-                    return RusotoError::Service(DisableLDAPSError::InvalidLDAPSStatus(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableLDAPSError::InvalidLDAPSStatus,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DisableLDAPSError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableLDAPSError::InvalidParameter,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DisableLDAPSError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableLDAPSError::Service,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DisableLDAPSError::UnsupportedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableLDAPSError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4374,15 +5163,30 @@ impl DisableRadiusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DisableRadiusError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableRadiusError::Client,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(DisableRadiusError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableRadiusError::EntityDoesNotExist,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DisableRadiusError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableRadiusError::Service,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4420,19 +5224,46 @@ impl DisableSsoError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthenticationFailedException" => {
-                    return RusotoError::Service(DisableSsoError::AuthenticationFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableSsoError::AuthenticationFailed,
+                    )
+                }
+                "ClientException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableSsoError::Client,
+                    )
                 }
-                "ClientException" => return RusotoError::Service(DisableSsoError::Client(err.msg)),
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(DisableSsoError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableSsoError::EntityDoesNotExist,
+                    )
                 }
                 "InsufficientPermissionsException" => {
-                    return RusotoError::Service(DisableSsoError::InsufficientPermissions(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableSsoError::InsufficientPermissions,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DisableSsoError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableSsoError::Service,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4478,37 +5309,62 @@ impl EnableClientAuthenticationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(EnableClientAuthenticationError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        EnableClientAuthenticationError::AccessDenied,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(EnableClientAuthenticationError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableClientAuthenticationError::Client,
+                    )
                 }
                 "DirectoryDoesNotExistException" => {
-                    return RusotoError::Service(
-                        EnableClientAuthenticationError::DirectoryDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableClientAuthenticationError::DirectoryDoesNotExist,
                     )
                 }
                 "InvalidClientAuthStatusException" => {
-                    return RusotoError::Service(
-                        EnableClientAuthenticationError::InvalidClientAuthStatus(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableClientAuthenticationError::InvalidClientAuthStatus,
                     )
                 }
                 "NoAvailableCertificateException" => {
-                    return RusotoError::Service(
-                        EnableClientAuthenticationError::NoAvailableCertificate(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableClientAuthenticationError::NoAvailableCertificate,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(EnableClientAuthenticationError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableClientAuthenticationError::Service,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
-                        EnableClientAuthenticationError::UnsupportedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableClientAuthenticationError::UnsupportedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4564,30 +5420,70 @@ impl EnableLDAPSError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(EnableLDAPSError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableLDAPSError::Client,
+                    )
                 }
                 "DirectoryDoesNotExistException" => {
-                    return RusotoError::Service(EnableLDAPSError::DirectoryDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableLDAPSError::DirectoryDoesNotExist,
+                    )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(EnableLDAPSError::DirectoryUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableLDAPSError::DirectoryUnavailable,
+                    )
                 }
                 "InvalidLDAPSStatusException" => {
-                    return RusotoError::Service(EnableLDAPSError::InvalidLDAPSStatus(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableLDAPSError::InvalidLDAPSStatus,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(EnableLDAPSError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableLDAPSError::InvalidParameter,
+                    )
                 }
                 "NoAvailableCertificateException" => {
-                    return RusotoError::Service(EnableLDAPSError::NoAvailableCertificate(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableLDAPSError::NoAvailableCertificate,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(EnableLDAPSError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableLDAPSError::Service,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(EnableLDAPSError::UnsupportedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableLDAPSError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4630,21 +5526,46 @@ impl EnableRadiusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(EnableRadiusError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableRadiusError::Client,
+                    )
                 }
                 "EntityAlreadyExistsException" => {
-                    return RusotoError::Service(EnableRadiusError::EntityAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableRadiusError::EntityAlreadyExists,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(EnableRadiusError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableRadiusError::EntityDoesNotExist,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(EnableRadiusError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableRadiusError::InvalidParameter,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(EnableRadiusError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableRadiusError::Service,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4684,19 +5605,46 @@ impl EnableSsoError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthenticationFailedException" => {
-                    return RusotoError::Service(EnableSsoError::AuthenticationFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableSsoError::AuthenticationFailed,
+                    )
+                }
+                "ClientException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableSsoError::Client,
+                    )
                 }
-                "ClientException" => return RusotoError::Service(EnableSsoError::Client(err.msg)),
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(EnableSsoError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableSsoError::EntityDoesNotExist,
+                    )
                 }
                 "InsufficientPermissionsException" => {
-                    return RusotoError::Service(EnableSsoError::InsufficientPermissions(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableSsoError::InsufficientPermissions,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(EnableSsoError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableSsoError::Service,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4732,17 +5680,30 @@ impl GetDirectoryLimitsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(GetDirectoryLimitsError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDirectoryLimitsError::Client,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(GetDirectoryLimitsError::EntityDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDirectoryLimitsError::EntityDoesNotExist,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetDirectoryLimitsError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDirectoryLimitsError::Service,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4776,17 +5737,30 @@ impl GetSnapshotLimitsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(GetSnapshotLimitsError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSnapshotLimitsError::Client,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(GetSnapshotLimitsError::EntityDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSnapshotLimitsError::EntityDoesNotExist,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetSnapshotLimitsError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSnapshotLimitsError::Service,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4826,28 +5800,54 @@ impl ListCertificatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListCertificatesError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCertificatesError::Client,
+                    )
                 }
                 "DirectoryDoesNotExistException" => {
-                    return RusotoError::Service(ListCertificatesError::DirectoryDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListCertificatesError::DirectoryDoesNotExist,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListCertificatesError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCertificatesError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListCertificatesError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCertificatesError::InvalidParameter,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListCertificatesError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCertificatesError::Service,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(ListCertificatesError::UnsupportedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListCertificatesError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4888,21 +5888,46 @@ impl ListIpRoutesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListIpRoutesError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIpRoutesError::Client,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(ListIpRoutesError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIpRoutesError::EntityDoesNotExist,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListIpRoutesError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIpRoutesError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListIpRoutesError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIpRoutesError::InvalidParameter,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListIpRoutesError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIpRoutesError::Service,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4940,22 +5965,38 @@ impl ListLogSubscriptionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListLogSubscriptionsError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLogSubscriptionsError::Client,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(ListLogSubscriptionsError::EntityDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListLogSubscriptionsError::EntityDoesNotExist,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListLogSubscriptionsError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListLogSubscriptionsError::InvalidNextToken,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListLogSubscriptionsError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLogSubscriptionsError::Service,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4992,22 +6033,38 @@ impl ListSchemaExtensionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListSchemaExtensionsError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSchemaExtensionsError::Client,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(ListSchemaExtensionsError::EntityDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSchemaExtensionsError::EntityDoesNotExist,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListSchemaExtensionsError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSchemaExtensionsError::InvalidNextToken,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListSchemaExtensionsError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSchemaExtensionsError::Service,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5046,27 +6103,46 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::Client,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(ListTagsForResourceError::EntityDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::EntityDoesNotExist,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::InvalidParameter,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::Service,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5114,47 +6190,78 @@ impl RegisterCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CertificateAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        RegisterCertificateError::CertificateAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterCertificateError::CertificateAlreadyExists,
                     )
                 }
                 "CertificateLimitExceededException" => {
-                    return RusotoError::Service(
-                        RegisterCertificateError::CertificateLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterCertificateError::CertificateLimitExceeded,
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(RegisterCertificateError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterCertificateError::Client,
+                    )
                 }
                 "DirectoryDoesNotExistException" => {
-                    return RusotoError::Service(RegisterCertificateError::DirectoryDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterCertificateError::DirectoryDoesNotExist,
+                    )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(RegisterCertificateError::DirectoryUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterCertificateError::DirectoryUnavailable,
+                    )
                 }
                 "InvalidCertificateException" => {
-                    return RusotoError::Service(RegisterCertificateError::InvalidCertificate(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterCertificateError::InvalidCertificate,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RegisterCertificateError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterCertificateError::InvalidParameter,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(RegisterCertificateError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterCertificateError::Service,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(RegisterCertificateError::UnsupportedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterCertificateError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5196,20 +6303,38 @@ impl RegisterEventTopicError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(RegisterEventTopicError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterEventTopicError::Client,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(RegisterEventTopicError::EntityDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterEventTopicError::EntityDoesNotExist,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RegisterEventTopicError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterEventTopicError::InvalidParameter,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(RegisterEventTopicError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterEventTopicError::Service,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5248,27 +6373,46 @@ impl RejectSharedDirectoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(RejectSharedDirectoryError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RejectSharedDirectoryError::Client,
+                    )
                 }
                 "DirectoryAlreadySharedException" => {
-                    return RusotoError::Service(
-                        RejectSharedDirectoryError::DirectoryAlreadyShared(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RejectSharedDirectoryError::DirectoryAlreadyShared,
                     )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(RejectSharedDirectoryError::EntityDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RejectSharedDirectoryError::EntityDoesNotExist,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RejectSharedDirectoryError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RejectSharedDirectoryError::InvalidParameter,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(RejectSharedDirectoryError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RejectSharedDirectoryError::Service,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5308,21 +6452,46 @@ impl RemoveIpRoutesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(RemoveIpRoutesError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveIpRoutesError::Client,
+                    )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(RemoveIpRoutesError::DirectoryUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveIpRoutesError::DirectoryUnavailable,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(RemoveIpRoutesError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveIpRoutesError::EntityDoesNotExist,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RemoveIpRoutesError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveIpRoutesError::InvalidParameter,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(RemoveIpRoutesError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveIpRoutesError::Service,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5364,24 +6533,54 @@ impl RemoveRegionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RemoveRegionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveRegionError::AccessDenied,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(RemoveRegionError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveRegionError::Client,
+                    )
                 }
                 "DirectoryDoesNotExistException" => {
-                    return RusotoError::Service(RemoveRegionError::DirectoryDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveRegionError::DirectoryDoesNotExist,
+                    )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(RemoveRegionError::DirectoryUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveRegionError::DirectoryUnavailable,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(RemoveRegionError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveRegionError::Service,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(RemoveRegionError::UnsupportedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveRegionError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5420,22 +6619,38 @@ impl RemoveTagsFromResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(RemoveTagsFromResourceError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveTagsFromResourceError::Client,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(RemoveTagsFromResourceError::EntityDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveTagsFromResourceError::EntityDoesNotExist,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RemoveTagsFromResourceError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveTagsFromResourceError::InvalidParameter,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(RemoveTagsFromResourceError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveTagsFromResourceError::Service,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5478,33 +6693,62 @@ impl ResetUserPasswordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ResetUserPasswordError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResetUserPasswordError::Client,
+                    )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(ResetUserPasswordError::DirectoryUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ResetUserPasswordError::DirectoryUnavailable,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(ResetUserPasswordError::EntityDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ResetUserPasswordError::EntityDoesNotExist,
+                    )
                 }
                 "InvalidPasswordException" => {
-                    return RusotoError::Service(ResetUserPasswordError::InvalidPassword(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResetUserPasswordError::InvalidPassword,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ResetUserPasswordError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResetUserPasswordError::Service,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(ResetUserPasswordError::UnsupportedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ResetUserPasswordError::UnsupportedOperation,
+                    )
                 }
                 "UserDoesNotExistException" => {
-                    return RusotoError::Service(ResetUserPasswordError::UserDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResetUserPasswordError::UserDoesNotExist,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5544,22 +6788,38 @@ impl RestoreFromSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(RestoreFromSnapshotError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RestoreFromSnapshotError::Client,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(RestoreFromSnapshotError::EntityDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RestoreFromSnapshotError::EntityDoesNotExist,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RestoreFromSnapshotError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RestoreFromSnapshotError::InvalidParameter,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(RestoreFromSnapshotError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RestoreFromSnapshotError::Service,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5608,38 +6868,86 @@ impl ShareDirectoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ShareDirectoryError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ShareDirectoryError::AccessDenied,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(ShareDirectoryError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ShareDirectoryError::Client,
+                    )
                 }
                 "DirectoryAlreadySharedException" => {
-                    return RusotoError::Service(ShareDirectoryError::DirectoryAlreadyShared(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ShareDirectoryError::DirectoryAlreadyShared,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(ShareDirectoryError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ShareDirectoryError::EntityDoesNotExist,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ShareDirectoryError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ShareDirectoryError::InvalidParameter,
+                    )
                 }
                 "InvalidTargetException" => {
-                    return RusotoError::Service(ShareDirectoryError::InvalidTarget(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ShareDirectoryError::InvalidTarget,
+                    )
                 }
                 "OrganizationsException" => {
-                    return RusotoError::Service(ShareDirectoryError::Organizations(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ShareDirectoryError::Organizations,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ShareDirectoryError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ShareDirectoryError::Service,
+                    )
                 }
                 "ShareLimitExceededException" => {
-                    return RusotoError::Service(ShareDirectoryError::ShareLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ShareDirectoryError::ShareLimitExceeded,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(ShareDirectoryError::UnsupportedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ShareDirectoryError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5686,32 +6994,54 @@ impl StartSchemaExtensionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(StartSchemaExtensionError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartSchemaExtensionError::Client,
+                    )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(StartSchemaExtensionError::DirectoryUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartSchemaExtensionError::DirectoryUnavailable,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(StartSchemaExtensionError::EntityDoesNotExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartSchemaExtensionError::EntityDoesNotExist,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartSchemaExtensionError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartSchemaExtensionError::InvalidParameter,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(StartSchemaExtensionError::Service(err.msg))
-                }
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartSchemaExtensionError::Service,
+                    )
+                }
                 "SnapshotLimitExceededException" => {
-                    return RusotoError::Service(StartSchemaExtensionError::SnapshotLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartSchemaExtensionError::SnapshotLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5752,21 +7082,46 @@ impl UnshareDirectoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UnshareDirectoryError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UnshareDirectoryError::Client,
+                    )
                 }
                 "DirectoryNotSharedException" => {
-                    return RusotoError::Service(UnshareDirectoryError::DirectoryNotShared(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UnshareDirectoryError::DirectoryNotShared,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(UnshareDirectoryError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UnshareDirectoryError::EntityDoesNotExist,
+                    )
                 }
                 "InvalidTargetException" => {
-                    return RusotoError::Service(UnshareDirectoryError::InvalidTarget(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UnshareDirectoryError::InvalidTarget,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UnshareDirectoryError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UnshareDirectoryError::Service,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5810,32 +7165,54 @@ impl UpdateConditionalForwarderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateConditionalForwarderError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateConditionalForwarderError::Client,
+                    )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(
-                        UpdateConditionalForwarderError::DirectoryUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateConditionalForwarderError::DirectoryUnavailable,
                     )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(
-                        UpdateConditionalForwarderError::EntityDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateConditionalForwarderError::EntityDoesNotExist,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateConditionalForwarderError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateConditionalForwarderError::InvalidParameter,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateConditionalForwarderError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateConditionalForwarderError::Service,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
-                        UpdateConditionalForwarderError::UnsupportedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateConditionalForwarderError::UnsupportedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5888,43 +7265,62 @@ impl UpdateNumberOfDomainControllersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateNumberOfDomainControllersError::Client(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateNumberOfDomainControllersError::Client,
+                    )
                 }
                 "DirectoryUnavailableException" => {
-                    return RusotoError::Service(
-                        UpdateNumberOfDomainControllersError::DirectoryUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateNumberOfDomainControllersError::DirectoryUnavailable,
                     )
                 }
                 "DomainControllerLimitExceededException" => {
-                    return RusotoError::Service(
-                        UpdateNumberOfDomainControllersError::DomainControllerLimitExceeded(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateNumberOfDomainControllersError::DomainControllerLimitExceeded,
                     )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(
-                        UpdateNumberOfDomainControllersError::EntityDoesNotExist(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateNumberOfDomainControllersError::EntityDoesNotExist,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        UpdateNumberOfDomainControllersError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateNumberOfDomainControllersError::InvalidParameter,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateNumberOfDomainControllersError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateNumberOfDomainControllersError::Service,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
-                        UpdateNumberOfDomainControllersError::UnsupportedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateNumberOfDomainControllersError::UnsupportedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5974,18 +7370,38 @@ impl UpdateRadiusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateRadiusError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRadiusError::Client,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(UpdateRadiusError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRadiusError::EntityDoesNotExist,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateRadiusError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRadiusError::InvalidParameter,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateRadiusError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRadiusError::Service,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6022,18 +7438,38 @@ impl UpdateTrustError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateTrustError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTrustError::Client,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(UpdateTrustError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTrustError::EntityDoesNotExist,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateTrustError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTrustError::InvalidParameter,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateTrustError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTrustError::Service,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6072,21 +7508,46 @@ impl VerifyTrustError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(VerifyTrustError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        VerifyTrustError::Client,
+                    )
                 }
                 "EntityDoesNotExistException" => {
-                    return RusotoError::Service(VerifyTrustError::EntityDoesNotExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        VerifyTrustError::EntityDoesNotExist,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(VerifyTrustError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        VerifyTrustError::InvalidParameter,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(VerifyTrustError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        VerifyTrustError::Service,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(VerifyTrustError::UnsupportedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        VerifyTrustError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6493,11 +7954,12 @@ pub struct DirectoryServiceClient {
 impl DirectoryServiceClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> DirectoryServiceClient {
         DirectoryServiceClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Directory Service"),
         }
     }
 
@@ -6512,12 +7974,32 @@ impl DirectoryServiceClient {
     {
         DirectoryServiceClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Directory Service"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> DirectoryServiceClient {
-        DirectoryServiceClient { client, region }
+        DirectoryServiceClient {
+            client,
+            region: region_for_service(region, "Directory Service"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> DirectoryServiceClient {
+        DirectoryServiceClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Directory Service"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> DirectoryServiceClient {
+        DirectoryServiceClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -6529,6 +8011,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: AcceptSharedDirectoryRequest,
     ) -> Result<AcceptSharedDirectoryResult, RusotoError<AcceptSharedDirectoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AcceptSharedDirectory");
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.AcceptSharedDirectory",
@@ -6550,6 +8033,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: AddIpRoutesRequest,
     ) -> Result<AddIpRoutesResult, RusotoError<AddIpRoutesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AddIpRoutes");
         request.add_header("x-amz-target", "DirectoryService_20150416.AddIpRoutes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6568,6 +8052,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: AddRegionRequest,
     ) -> Result<AddRegionResult, RusotoError<AddRegionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AddRegion");
         request.add_header("x-amz-target", "DirectoryService_20150416.AddRegion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6586,6 +8071,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: AddTagsToResourceRequest,
     ) -> Result<AddTagsToResourceResult, RusotoError<AddTagsToResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AddTagsToResource");
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.AddTagsToResource",
@@ -6607,6 +8093,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: CancelSchemaExtensionRequest,
     ) -> Result<CancelSchemaExtensionResult, RusotoError<CancelSchemaExtensionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CancelSchemaExtension");
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.CancelSchemaExtension",
@@ -6628,6 +8115,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: ConnectDirectoryRequest,
     ) -> Result<ConnectDirectoryResult, RusotoError<ConnectDirectoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ConnectDirectory");
         request.add_header("x-amz-target", "DirectoryService_20150416.ConnectDirectory");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6646,6 +8134,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: CreateAliasRequest,
     ) -> Result<CreateAliasResult, RusotoError<CreateAliasError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateAlias");
         request.add_header("x-amz-target", "DirectoryService_20150416.CreateAlias");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6664,6 +8153,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: CreateComputerRequest,
     ) -> Result<CreateComputerResult, RusotoError<CreateComputerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateComputer");
         request.add_header("x-amz-target", "DirectoryService_20150416.CreateComputer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6683,6 +8173,7 @@ impl DirectoryService for DirectoryServiceClient {
     ) -> Result<CreateConditionalForwarderResult, RusotoError<CreateConditionalForwarderError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateConditionalForwarder");
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.CreateConditionalForwarder",
@@ -6705,6 +8196,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: CreateDirectoryRequest,
     ) -> Result<CreateDirectoryResult, RusotoError<CreateDirectoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateDirectory");
         request.add_header("x-amz-target", "DirectoryService_20150416.CreateDirectory");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6723,6 +8215,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: CreateLogSubscriptionRequest,
     ) -> Result<CreateLogSubscriptionResult, RusotoError<CreateLogSubscriptionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateLogSubscription");
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.CreateLogSubscription",
@@ -6744,6 +8237,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: CreateMicrosoftADRequest,
     ) -> Result<CreateMicrosoftADResult, RusotoError<CreateMicrosoftADError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateMicrosoftAD");
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.CreateMicrosoftAD",
@@ -6765,6 +8259,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: CreateSnapshotRequest,
     ) -> Result<CreateSnapshotResult, RusotoError<CreateSnapshotError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateSnapshot");
         request.add_header("x-amz-target", "DirectoryService_20150416.CreateSnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6783,6 +8278,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: CreateTrustRequest,
     ) -> Result<CreateTrustResult, RusotoError<CreateTrustError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateTrust");
         request.add_header("x-amz-target", "DirectoryService_20150416.CreateTrust");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6802,6 +8298,7 @@ impl DirectoryService for DirectoryServiceClient {
     ) -> Result<DeleteConditionalForwarderResult, RusotoError<DeleteConditionalForwarderError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteConditionalForwarder");
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.DeleteConditionalForwarder",
@@ -6824,6 +8321,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: DeleteDirectoryRequest,
     ) -> Result<DeleteDirectoryResult, RusotoError<DeleteDirectoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteDirectory");
         request.add_header("x-amz-target", "DirectoryService_20150416.DeleteDirectory");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6842,6 +8340,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: DeleteLogSubscriptionRequest,
     ) -> Result<DeleteLogSubscriptionResult, RusotoError<DeleteLogSubscriptionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteLogSubscription");
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.DeleteLogSubscription",
@@ -6863,6 +8362,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: DeleteSnapshotRequest,
     ) -> Result<DeleteSnapshotResult, RusotoError<DeleteSnapshotError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteSnapshot");
         request.add_header("x-amz-target", "DirectoryService_20150416.DeleteSnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6881,6 +8381,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: DeleteTrustRequest,
     ) -> Result<DeleteTrustResult, RusotoError<DeleteTrustError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteTrust");
         request.add_header("x-amz-target", "DirectoryService_20150416.DeleteTrust");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6899,6 +8400,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: DeregisterCertificateRequest,
     ) -> Result<DeregisterCertificateResult, RusotoError<DeregisterCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeregisterCertificate");
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.DeregisterCertificate",
@@ -6920,6 +8422,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: DeregisterEventTopicRequest,
     ) -> Result<DeregisterEventTopicResult, RusotoError<DeregisterEventTopicError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeregisterEventTopic");
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.DeregisterEventTopic",
@@ -6941,6 +8444,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: DescribeCertificateRequest,
     ) -> Result<DescribeCertificateResult, RusotoError<DescribeCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeCertificate");
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.DescribeCertificate",
@@ -6963,6 +8467,7 @@ impl DirectoryService for DirectoryServiceClient {
     ) -> Result<DescribeConditionalForwardersResult, RusotoError<DescribeConditionalForwardersError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeConditionalForwarders");
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.DescribeConditionalForwarders",
@@ -6985,6 +8490,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: DescribeDirectoriesRequest,
     ) -> Result<DescribeDirectoriesResult, RusotoError<DescribeDirectoriesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeDirectories");
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.DescribeDirectories",
@@ -7006,6 +8512,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: DescribeDomainControllersRequest,
     ) -> Result<DescribeDomainControllersResult, RusotoError<DescribeDomainControllersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeDomainControllers");
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.DescribeDomainControllers",
@@ -7028,6 +8535,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: DescribeEventTopicsRequest,
     ) -> Result<DescribeEventTopicsResult, RusotoError<DescribeEventTopicsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeEventTopics");
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.DescribeEventTopics",
@@ -7049,6 +8557,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: DescribeLDAPSSettingsRequest,
     ) -> Result<DescribeLDAPSSettingsResult, RusotoError<DescribeLDAPSSettingsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeLDAPSSettings");
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.DescribeLDAPSSettings",
@@ -7070,6 +8579,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: DescribeRegionsRequest,
     ) -> Result<DescribeRegionsResult, RusotoError<DescribeRegionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeRegions");
         request.add_header("x-amz-target", "DirectoryService_20150416.DescribeRegions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7088,6 +8598,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: DescribeSharedDirectoriesRequest,
     ) -> Result<DescribeSharedDirectoriesResult, RusotoError<DescribeSharedDirectoriesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeSharedDirectories");
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.DescribeSharedDirectories",
@@ -7110,6 +8621,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: DescribeSnapshotsRequest,
     ) -> Result<DescribeSnapshotsResult, RusotoError<DescribeSnapshotsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeSnapshots");
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.DescribeSnapshots",
@@ -7131,6 +8643,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: DescribeTrustsRequest,
     ) -> Result<DescribeTrustsResult, RusotoError<DescribeTrustsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeTrusts");
         request.add_header("x-amz-target", "DirectoryService_20150416.DescribeTrusts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7150,6 +8663,7 @@ impl DirectoryService for DirectoryServiceClient {
     ) -> Result<DisableClientAuthenticationResult, RusotoError<DisableClientAuthenticationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisableClientAuthentication");
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.DisableClientAuthentication",
@@ -7172,6 +8686,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: DisableLDAPSRequest,
     ) -> Result<DisableLDAPSResult, RusotoError<DisableLDAPSError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisableLDAPS");
         request.add_header("x-amz-target", "DirectoryService_20150416.DisableLDAPS");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7190,6 +8705,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: DisableRadiusRequest,
     ) -> Result<DisableRadiusResult, RusotoError<DisableRadiusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisableRadius");
         request.add_header("x-amz-target", "DirectoryService_20150416.DisableRadius");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7208,6 +8724,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: DisableSsoRequest,
     ) -> Result<DisableSsoResult, RusotoError<DisableSsoError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisableSso");
         request.add_header("x-amz-target", "DirectoryService_20150416.DisableSso");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7227,6 +8744,7 @@ impl DirectoryService for DirectoryServiceClient {
     ) -> Result<EnableClientAuthenticationResult, RusotoError<EnableClientAuthenticationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("EnableClientAuthentication");
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.EnableClientAuthentication",
@@ -7249,6 +8767,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: EnableLDAPSRequest,
     ) -> Result<EnableLDAPSResult, RusotoError<EnableLDAPSError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("EnableLDAPS");
         request.add_header("x-amz-target", "DirectoryService_20150416.EnableLDAPS");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7267,6 +8786,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: EnableRadiusRequest,
     ) -> Result<EnableRadiusResult, RusotoError<EnableRadiusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("EnableRadius");
         request.add_header("x-amz-target", "DirectoryService_20150416.EnableRadius");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7285,6 +8805,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: EnableSsoRequest,
     ) -> Result<EnableSsoResult, RusotoError<EnableSsoError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("EnableSso");
         request.add_header("x-amz-target", "DirectoryService_20150416.EnableSso");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7302,6 +8823,7 @@ impl DirectoryService for DirectoryServiceClient {
         &self,
     ) -> Result<GetDirectoryLimitsResult, RusotoError<GetDirectoryLimitsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDirectoryLimits");
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.GetDirectoryLimits",
@@ -7322,6 +8844,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: GetSnapshotLimitsRequest,
     ) -> Result<GetSnapshotLimitsResult, RusotoError<GetSnapshotLimitsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetSnapshotLimits");
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.GetSnapshotLimits",
@@ -7343,6 +8866,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: ListCertificatesRequest,
     ) -> Result<ListCertificatesResult, RusotoError<ListCertificatesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListCertificates");
         request.add_header("x-amz-target", "DirectoryService_20150416.ListCertificates");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7361,6 +8885,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: ListIpRoutesRequest,
     ) -> Result<ListIpRoutesResult, RusotoError<ListIpRoutesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListIpRoutes");
         request.add_header("x-amz-target", "DirectoryService_20150416.ListIpRoutes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7379,6 +8904,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: ListLogSubscriptionsRequest,
     ) -> Result<ListLogSubscriptionsResult, RusotoError<ListLogSubscriptionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListLogSubscriptions");
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.ListLogSubscriptions",
@@ -7400,6 +8926,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: ListSchemaExtensionsRequest,
     ) -> Result<ListSchemaExtensionsResult, RusotoError<ListSchemaExtensionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListSchemaExtensions");
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.ListSchemaExtensions",
@@ -7421,6 +8948,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResult, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.ListTagsForResource",
@@ -7442,6 +8970,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: RegisterCertificateRequest,
     ) -> Result<RegisterCertificateResult, RusotoError<RegisterCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RegisterCertificate");
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.RegisterCertificate",
@@ -7463,6 +8992,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: RegisterEventTopicRequest,
     ) -> Result<RegisterEventTopicResult, RusotoError<RegisterEventTopicError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RegisterEventTopic");
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.RegisterEventTopic",
@@ -7484,6 +9014,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: RejectSharedDirectoryRequest,
     ) -> Result<RejectSharedDirectoryResult, RusotoError<RejectSharedDirectoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RejectSharedDirectory");
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.RejectSharedDirectory",
@@ -7505,6 +9036,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: RemoveIpRoutesRequest,
     ) -> Result<RemoveIpRoutesResult, RusotoError<RemoveIpRoutesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RemoveIpRoutes");
         request.add_header("x-amz-target", "DirectoryService_20150416.RemoveIpRoutes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7523,6 +9055,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: RemoveRegionRequest,
     ) -> Result<RemoveRegionResult, RusotoError<RemoveRegionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RemoveRegion");
         request.add_header("x-amz-target", "DirectoryService_20150416.RemoveRegion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7541,6 +9074,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: RemoveTagsFromResourceRequest,
     ) -> Result<RemoveTagsFromResourceResult, RusotoError<RemoveTagsFromResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RemoveTagsFromResource");
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.RemoveTagsFromResource",
@@ -7563,6 +9097,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: ResetUserPasswordRequest,
     ) -> Result<ResetUserPasswordResult, RusotoError<ResetUserPasswordError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ResetUserPassword");
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.ResetUserPassword",
@@ -7584,6 +9119,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: RestoreFromSnapshotRequest,
     ) -> Result<RestoreFromSnapshotResult, RusotoError<RestoreFromSnapshotError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RestoreFromSnapshot");
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.RestoreFromSnapshot",
@@ -7605,6 +9141,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: ShareDirectoryRequest,
     ) -> Result<ShareDirectoryResult, RusotoError<ShareDirectoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ShareDirectory");
         request.add_header("x-amz-target", "DirectoryService_20150416.ShareDirectory");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7623,6 +9160,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: StartSchemaExtensionRequest,
     ) -> Result<StartSchemaExtensionResult, RusotoError<StartSchemaExtensionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartSchemaExtension");
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.StartSchemaExtension",
@@ -7644,6 +9182,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: UnshareDirectoryRequest,
     ) -> Result<UnshareDirectoryResult, RusotoError<UnshareDirectoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UnshareDirectory");
         request.add_header("x-amz-target", "DirectoryService_20150416.UnshareDirectory");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7663,6 +9202,7 @@ impl DirectoryService for DirectoryServiceClient {
     ) -> Result<UpdateConditionalForwarderResult, RusotoError<UpdateConditionalForwarderError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateConditionalForwarder");
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.UpdateConditionalForwarder",
@@ -7688,6 +9228,7 @@ impl DirectoryService for DirectoryServiceClient {
         RusotoError<UpdateNumberOfDomainControllersError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateNumberOfDomainControllers");
         request.add_header(
             "x-amz-target",
             "DirectoryService_20150416.UpdateNumberOfDomainControllers",
@@ -7710,6 +9251,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: UpdateRadiusRequest,
     ) -> Result<UpdateRadiusResult, RusotoError<UpdateRadiusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateRadius");
         request.add_header("x-amz-target", "DirectoryService_20150416.UpdateRadius");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7728,6 +9270,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: UpdateTrustRequest,
     ) -> Result<UpdateTrustResult, RusotoError<UpdateTrustError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateTrust");
         request.add_header("x-amz-target", "DirectoryService_20150416.UpdateTrust");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7746,6 +9289,7 @@ impl DirectoryService for DirectoryServiceClient {
         input: VerifyTrustRequest,
     ) -> Result<VerifyTrustResult, RusotoError<VerifyTrustError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("VerifyTrust");
         request.add_header("x-amz-target", "DirectoryService_20150416.VerifyTrust");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7758,3 +9302,622 @@ impl DirectoryService for DirectoryServiceClient {
         proto::json::ResponsePayload::new(&response).deserialize::<VerifyTrustResult, _>()
     }
 }
+
+/// A blocking client for the Directory Service API, running the calls of a `DirectoryServiceClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `DirectoryServiceClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct DirectoryServiceBlockingClient {
+    client: DirectoryServiceClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl DirectoryServiceBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> DirectoryServiceBlockingClient {
+        DirectoryServiceBlockingClient::from_client(DirectoryServiceClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> DirectoryServiceBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        DirectoryServiceBlockingClient::from_client(DirectoryServiceClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> DirectoryServiceBlockingClient {
+        DirectoryServiceBlockingClient::from_client(DirectoryServiceClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> DirectoryServiceBlockingClient {
+        DirectoryServiceBlockingClient::from_client(DirectoryServiceClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: DirectoryServiceClient) -> DirectoryServiceBlockingClient {
+        DirectoryServiceBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> DirectoryServiceBlockingClient {
+        DirectoryServiceBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> DirectoryServiceBlockingClient {
+        DirectoryServiceBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`DirectoryService::accept_shared_directory`].
+    pub fn accept_shared_directory(
+        &self,
+        input: AcceptSharedDirectoryRequest,
+    ) -> Result<AcceptSharedDirectoryResult, RusotoError<AcceptSharedDirectoryError>> {
+        self.runtime
+            .block_on(self.client.accept_shared_directory(input))
+    }
+
+    /// Blocking version of [`DirectoryService::add_ip_routes`].
+    pub fn add_ip_routes(
+        &self,
+        input: AddIpRoutesRequest,
+    ) -> Result<AddIpRoutesResult, RusotoError<AddIpRoutesError>> {
+        self.runtime.block_on(self.client.add_ip_routes(input))
+    }
+
+    /// Blocking version of [`DirectoryService::add_region`].
+    pub fn add_region(
+        &self,
+        input: AddRegionRequest,
+    ) -> Result<AddRegionResult, RusotoError<AddRegionError>> {
+        self.runtime.block_on(self.client.add_region(input))
+    }
+
+    /// Blocking version of [`DirectoryService::add_tags_to_resource`].
+    pub fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceRequest,
+    ) -> Result<AddTagsToResourceResult, RusotoError<AddTagsToResourceError>> {
+        self.runtime
+            .block_on(self.client.add_tags_to_resource(input))
+    }
+
+    /// Blocking version of [`DirectoryService::cancel_schema_extension`].
+    pub fn cancel_schema_extension(
+        &self,
+        input: CancelSchemaExtensionRequest,
+    ) -> Result<CancelSchemaExtensionResult, RusotoError<CancelSchemaExtensionError>> {
+        self.runtime
+            .block_on(self.client.cancel_schema_extension(input))
+    }
+
+    /// Blocking version of [`DirectoryService::connect_directory`].
+    pub fn connect_directory(
+        &self,
+        input: ConnectDirectoryRequest,
+    ) -> Result<ConnectDirectoryResult, RusotoError<ConnectDirectoryError>> {
+        self.runtime.block_on(self.client.connect_directory(input))
+    }
+
+    /// Blocking version of [`DirectoryService::create_alias`].
+    pub fn create_alias(
+        &self,
+        input: CreateAliasRequest,
+    ) -> Result<CreateAliasResult, RusotoError<CreateAliasError>> {
+        self.runtime.block_on(self.client.create_alias(input))
+    }
+
+    /// Blocking version of [`DirectoryService::create_computer`].
+    pub fn create_computer(
+        &self,
+        input: CreateComputerRequest,
+    ) -> Result<CreateComputerResult, RusotoError<CreateComputerError>> {
+        self.runtime.block_on(self.client.create_computer(input))
+    }
+
+    /// Blocking version of [`DirectoryService::create_conditional_forwarder`].
+    pub fn create_conditional_forwarder(
+        &self,
+        input: CreateConditionalForwarderRequest,
+    ) -> Result<CreateConditionalForwarderResult, RusotoError<CreateConditionalForwarderError>>
+    {
+        self.runtime
+            .block_on(self.client.create_conditional_forwarder(input))
+    }
+
+    /// Blocking version of [`DirectoryService::create_directory`].
+    pub fn create_directory(
+        &self,
+        input: CreateDirectoryRequest,
+    ) -> Result<CreateDirectoryResult, RusotoError<CreateDirectoryError>> {
+        self.runtime.block_on(self.client.create_directory(input))
+    }
+
+    /// Blocking version of [`DirectoryService::create_log_subscription`].
+    pub fn create_log_subscription(
+        &self,
+        input: CreateLogSubscriptionRequest,
+    ) -> Result<CreateLogSubscriptionResult, RusotoError<CreateLogSubscriptionError>> {
+        self.runtime
+            .block_on(self.client.create_log_subscription(input))
+    }
+
+    /// Blocking version of [`DirectoryService::create_microsoft_ad`].
+    pub fn create_microsoft_ad(
+        &self,
+        input: CreateMicrosoftADRequest,
+    ) -> Result<CreateMicrosoftADResult, RusotoError<CreateMicrosoftADError>> {
+        self.runtime
+            .block_on(self.client.create_microsoft_ad(input))
+    }
+
+    /// Blocking version of [`DirectoryService::create_snapshot`].
+    pub fn create_snapshot(
+        &self,
+        input: CreateSnapshotRequest,
+    ) -> Result<CreateSnapshotResult, RusotoError<CreateSnapshotError>> {
+        self.runtime.block_on(self.client.create_snapshot(input))
+    }
+
+    /// Blocking version of [`DirectoryService::create_trust`].
+    pub fn create_trust(
+        &self,
+        input: CreateTrustRequest,
+    ) -> Result<CreateTrustResult, RusotoError<CreateTrustError>> {
+        self.runtime.block_on(self.client.create_trust(input))
+    }
+
+    /// Blocking version of [`DirectoryService::delete_conditional_forwarder`].
+    pub fn delete_conditional_forwarder(
+        &self,
+        input: DeleteConditionalForwarderRequest,
+    ) -> Result<DeleteConditionalForwarderResult, RusotoError<DeleteConditionalForwarderError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_conditional_forwarder(input))
+    }
+
+    /// Blocking version of [`DirectoryService::delete_directory`].
+    pub fn delete_directory(
+        &self,
+        input: DeleteDirectoryRequest,
+    ) -> Result<DeleteDirectoryResult, RusotoError<DeleteDirectoryError>> {
+        self.runtime.block_on(self.client.delete_directory(input))
+    }
+
+    /// Blocking version of [`DirectoryService::delete_log_subscription`].
+    pub fn delete_log_subscription(
+        &self,
+        input: DeleteLogSubscriptionRequest,
+    ) -> Result<DeleteLogSubscriptionResult, RusotoError<DeleteLogSubscriptionError>> {
+        self.runtime
+            .block_on(self.client.delete_log_subscription(input))
+    }
+
+    /// Blocking version of [`DirectoryService::delete_snapshot`].
+    pub fn delete_snapshot(
+        &self,
+        input: DeleteSnapshotRequest,
+    ) -> Result<DeleteSnapshotResult, RusotoError<DeleteSnapshotError>> {
+        self.runtime.block_on(self.client.delete_snapshot(input))
+    }
+
+    /// Blocking version of [`DirectoryService::delete_trust`].
+    pub fn delete_trust(
+        &self,
+        input: DeleteTrustRequest,
+    ) -> Result<DeleteTrustResult, RusotoError<DeleteTrustError>> {
+        self.runtime.block_on(self.client.delete_trust(input))
+    }
+
+    /// Blocking version of [`DirectoryService::deregister_certificate`].
+    pub fn deregister_certificate(
+        &self,
+        input: DeregisterCertificateRequest,
+    ) -> Result<DeregisterCertificateResult, RusotoError<DeregisterCertificateError>> {
+        self.runtime
+            .block_on(self.client.deregister_certificate(input))
+    }
+
+    /// Blocking version of [`DirectoryService::deregister_event_topic`].
+    pub fn deregister_event_topic(
+        &self,
+        input: DeregisterEventTopicRequest,
+    ) -> Result<DeregisterEventTopicResult, RusotoError<DeregisterEventTopicError>> {
+        self.runtime
+            .block_on(self.client.deregister_event_topic(input))
+    }
+
+    /// Blocking version of [`DirectoryService::describe_certificate`].
+    pub fn describe_certificate(
+        &self,
+        input: DescribeCertificateRequest,
+    ) -> Result<DescribeCertificateResult, RusotoError<DescribeCertificateError>> {
+        self.runtime
+            .block_on(self.client.describe_certificate(input))
+    }
+
+    /// Blocking version of [`DirectoryService::describe_conditional_forwarders`].
+    pub fn describe_conditional_forwarders(
+        &self,
+        input: DescribeConditionalForwardersRequest,
+    ) -> Result<DescribeConditionalForwardersResult, RusotoError<DescribeConditionalForwardersError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_conditional_forwarders(input))
+    }
+
+    /// Blocking version of [`DirectoryService::describe_directories`].
+    pub fn describe_directories(
+        &self,
+        input: DescribeDirectoriesRequest,
+    ) -> Result<DescribeDirectoriesResult, RusotoError<DescribeDirectoriesError>> {
+        self.runtime
+            .block_on(self.client.describe_directories(input))
+    }
+
+    /// Blocking version of [`DirectoryService::describe_domain_controllers`].
+    pub fn describe_domain_controllers(
+        &self,
+        input: DescribeDomainControllersRequest,
+    ) -> Result<DescribeDomainControllersResult, RusotoError<DescribeDomainControllersError>> {
+        self.runtime
+            .block_on(self.client.describe_domain_controllers(input))
+    }
+
+    /// Blocking version of [`DirectoryService::describe_event_topics`].
+    pub fn describe_event_topics(
+        &self,
+        input: DescribeEventTopicsRequest,
+    ) -> Result<DescribeEventTopicsResult, RusotoError<DescribeEventTopicsError>> {
+        self.runtime
+            .block_on(self.client.describe_event_topics(input))
+    }
+
+    /// Blocking version of [`DirectoryService::describe_ldaps_settings`].
+    pub fn describe_ldaps_settings(
+        &self,
+        input: DescribeLDAPSSettingsRequest,
+    ) -> Result<DescribeLDAPSSettingsResult, RusotoError<DescribeLDAPSSettingsError>> {
+        self.runtime
+            .block_on(self.client.describe_ldaps_settings(input))
+    }
+
+    /// Blocking version of [`DirectoryService::describe_regions`].
+    pub fn describe_regions(
+        &self,
+        input: DescribeRegionsRequest,
+    ) -> Result<DescribeRegionsResult, RusotoError<DescribeRegionsError>> {
+        self.runtime.block_on(self.client.describe_regions(input))
+    }
+
+    /// Blocking version of [`DirectoryService::describe_shared_directories`].
+    pub fn describe_shared_directories(
+        &self,
+        input: DescribeSharedDirectoriesRequest,
+    ) -> Result<DescribeSharedDirectoriesResult, RusotoError<DescribeSharedDirectoriesError>> {
+        self.runtime
+            .block_on(self.client.describe_shared_directories(input))
+    }
+
+    /// Blocking version of [`DirectoryService::describe_snapshots`].
+    pub fn describe_snapshots(
+        &self,
+        input: DescribeSnapshotsRequest,
+    ) -> Result<DescribeSnapshotsResult, RusotoError<DescribeSnapshotsError>> {
+        self.runtime.block_on(self.client.describe_snapshots(input))
+    }
+
+    /// Blocking version of [`DirectoryService::describe_trusts`].
+    pub fn describe_trusts(
+        &self,
+        input: DescribeTrustsRequest,
+    ) -> Result<DescribeTrustsResult, RusotoError<DescribeTrustsError>> {
+        self.runtime.block_on(self.client.describe_trusts(input))
+    }
+
+    /// Blocking version of [`DirectoryService::disable_client_authentication`].
+    pub fn disable_client_authentication(
+        &self,
+        input: DisableClientAuthenticationRequest,
+    ) -> Result<DisableClientAuthenticationResult, RusotoError<DisableClientAuthenticationError>>
+    {
+        self.runtime
+            .block_on(self.client.disable_client_authentication(input))
+    }
+
+    /// Blocking version of [`DirectoryService::disable_ldaps`].
+    pub fn disable_ldaps(
+        &self,
+        input: DisableLDAPSRequest,
+    ) -> Result<DisableLDAPSResult, RusotoError<DisableLDAPSError>> {
+        self.runtime.block_on(self.client.disable_ldaps(input))
+    }
+
+    /// Blocking version of [`DirectoryService::disable_radius`].
+    pub fn disable_radius(
+        &self,
+        input: DisableRadiusRequest,
+    ) -> Result<DisableRadiusResult, RusotoError<DisableRadiusError>> {
+        self.runtime.block_on(self.client.disable_radius(input))
+    }
+
+    /// Blocking version of [`DirectoryService::disable_sso`].
+    pub fn disable_sso(
+        &self,
+        input: DisableSsoRequest,
+    ) -> Result<DisableSsoResult, RusotoError<DisableSsoError>> {
+        self.runtime.block_on(self.client.disable_sso(input))
+    }
+
+    /// Blocking version of [`DirectoryService::enable_client_authentication`].
+    pub fn enable_client_authentication(
+        &self,
+        input: EnableClientAuthenticationRequest,
+    ) -> Result<EnableClientAuthenticationResult, RusotoError<EnableClientAuthenticationError>>
+    {
+        self.runtime
+            .block_on(self.client.enable_client_authentication(input))
+    }
+
+    /// Blocking version of [`DirectoryService::enable_ldaps`].
+    pub fn enable_ldaps(
+        &self,
+        input: EnableLDAPSRequest,
+    ) -> Result<EnableLDAPSResult, RusotoError<EnableLDAPSError>> {
+        self.runtime.block_on(self.client.enable_ldaps(input))
+    }
+
+    /// Blocking version of [`DirectoryService::enable_radius`].
+    pub fn enable_radius(
+        &self,
+        input: EnableRadiusRequest,
+    ) -> Result<EnableRadiusResult, RusotoError<EnableRadiusError>> {
+        self.runtime.block_on(self.client.enable_radius(input))
+    }
+
+    /// Blocking version of [`DirectoryService::enable_sso`].
+    pub fn enable_sso(
+        &self,
+        input: EnableSsoRequest,
+    ) -> Result<EnableSsoResult, RusotoError<EnableSsoError>> {
+        self.runtime.block_on(self.client.enable_sso(input))
+    }
+
+    /// Blocking version of [`DirectoryService::get_directory_limits`].
+    pub fn get_directory_limits(
+        &self,
+    ) -> Result<GetDirectoryLimitsResult, RusotoError<GetDirectoryLimitsError>> {
+        self.runtime.block_on(self.client.get_directory_limits())
+    }
+
+    /// Blocking version of [`DirectoryService::get_snapshot_limits`].
+    pub fn get_snapshot_limits(
+        &self,
+        input: GetSnapshotLimitsRequest,
+    ) -> Result<GetSnapshotLimitsResult, RusotoError<GetSnapshotLimitsError>> {
+        self.runtime
+            .block_on(self.client.get_snapshot_limits(input))
+    }
+
+    /// Blocking version of [`DirectoryService::list_certificates`].
+    pub fn list_certificates(
+        &self,
+        input: ListCertificatesRequest,
+    ) -> Result<ListCertificatesResult, RusotoError<ListCertificatesError>> {
+        self.runtime.block_on(self.client.list_certificates(input))
+    }
+
+    /// Blocking version of [`DirectoryService::list_ip_routes`].
+    pub fn list_ip_routes(
+        &self,
+        input: ListIpRoutesRequest,
+    ) -> Result<ListIpRoutesResult, RusotoError<ListIpRoutesError>> {
+        self.runtime.block_on(self.client.list_ip_routes(input))
+    }
+
+    /// Blocking version of [`DirectoryService::list_log_subscriptions`].
+    pub fn list_log_subscriptions(
+        &self,
+        input: ListLogSubscriptionsRequest,
+    ) -> Result<ListLogSubscriptionsResult, RusotoError<ListLogSubscriptionsError>> {
+        self.runtime
+            .block_on(self.client.list_log_subscriptions(input))
+    }
+
+    /// Blocking version of [`DirectoryService::list_schema_extensions`].
+    pub fn list_schema_extensions(
+        &self,
+        input: ListSchemaExtensionsRequest,
+    ) -> Result<ListSchemaExtensionsResult, RusotoError<ListSchemaExtensionsError>> {
+        self.runtime
+            .block_on(self.client.list_schema_extensions(input))
+    }
+
+    /// Blocking version of [`DirectoryService::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResult, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`DirectoryService::register_certificate`].
+    pub fn register_certificate(
+        &self,
+        input: RegisterCertificateRequest,
+    ) -> Result<RegisterCertificateResult, RusotoError<RegisterCertificateError>> {
+        self.runtime
+            .block_on(self.client.register_certificate(input))
+    }
+
+    /// Blocking version of [`DirectoryService::register_event_topic`].
+    pub fn register_event_topic(
+        &self,
+        input: RegisterEventTopicRequest,
+    ) -> Result<RegisterEventTopicResult, RusotoError<RegisterEventTopicError>> {
+        self.runtime
+            .block_on(self.client.register_event_topic(input))
+    }
+
+    /// Blocking version of [`DirectoryService::reject_shared_directory`].
+    pub fn reject_shared_directory(
+        &self,
+        input: RejectSharedDirectoryRequest,
+    ) -> Result<RejectSharedDirectoryResult, RusotoError<RejectSharedDirectoryError>> {
+        self.runtime
+            .block_on(self.client.reject_shared_directory(input))
+    }
+
+    /// Blocking version of [`DirectoryService::remove_ip_routes`].
+    pub fn remove_ip_routes(
+        &self,
+        input: RemoveIpRoutesRequest,
+    ) -> Result<RemoveIpRoutesResult, RusotoError<RemoveIpRoutesError>> {
+        self.runtime.block_on(self.client.remove_ip_routes(input))
+    }
+
+    /// Blocking version of [`DirectoryService::remove_region`].
+    pub fn remove_region(
+        &self,
+        input: RemoveRegionRequest,
+    ) -> Result<RemoveRegionResult, RusotoError<RemoveRegionError>> {
+        self.runtime.block_on(self.client.remove_region(input))
+    }
+
+    /// Blocking version of [`DirectoryService::remove_tags_from_resource`].
+    pub fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceRequest,
+    ) -> Result<RemoveTagsFromResourceResult, RusotoError<RemoveTagsFromResourceError>> {
+        self.runtime
+            .block_on(self.client.remove_tags_from_resource(input))
+    }
+
+    /// Blocking version of [`DirectoryService::reset_user_password`].
+    pub fn reset_user_password(
+        &self,
+        input: ResetUserPasswordRequest,
+    ) -> Result<ResetUserPasswordResult, RusotoError<ResetUserPasswordError>> {
+        self.runtime
+            .block_on(self.client.reset_user_password(input))
+    }
+
+    /// Blocking version of [`DirectoryService::restore_from_snapshot`].
+    pub fn restore_from_snapshot(
+        &self,
+        input: RestoreFromSnapshotRequest,
+    ) -> Result<RestoreFromSnapshotResult, RusotoError<RestoreFromSnapshotError>> {
+        self.runtime
+            .block_on(self.client.restore_from_snapshot(input))
+    }
+
+    /// Blocking version of [`DirectoryService::share_directory`].
+    pub fn share_directory(
+        &self,
+        input: ShareDirectoryRequest,
+    ) -> Result<ShareDirectoryResult, RusotoError<ShareDirectoryError>> {
+        self.runtime.block_on(self.client.share_directory(input))
+    }
+
+    /// Blocking version of [`DirectoryService::start_schema_extension`].
+    pub fn start_schema_extension(
+        &self,
+        input: StartSchemaExtensionRequest,
+    ) -> Result<StartSchemaExtensionResult, RusotoError<StartSchemaExtensionError>> {
+        self.runtime
+            .block_on(self.client.start_schema_extension(input))
+    }
+
+    /// Blocking version of [`DirectoryService::unshare_directory`].
+    pub fn unshare_directory(
+        &self,
+        input: UnshareDirectoryRequest,
+    ) -> Result<UnshareDirectoryResult, RusotoError<UnshareDirectoryError>> {
+        self.runtime.block_on(self.client.unshare_directory(input))
+    }
+
+    /// Blocking version of [`DirectoryService::update_conditional_forwarder`].
+    pub fn update_conditional_forwarder(
+        &self,
+        input: UpdateConditionalForwarderRequest,
+    ) -> Result<UpdateConditionalForwarderResult, RusotoError<UpdateConditionalForwarderError>>
+    {
+        self.runtime
+            .block_on(self.client.update_conditional_forwarder(input))
+    }
+
+    /// Blocking version of [`DirectoryService::update_number_of_domain_controllers`].
+    pub fn update_number_of_domain_controllers(
+        &self,
+        input: UpdateNumberOfDomainControllersRequest,
+    ) -> Result<
+        UpdateNumberOfDomainControllersResult,
+        RusotoError<UpdateNumberOfDomainControllersError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_number_of_domain_controllers(input))
+    }
+
+    /// Blocking version of [`DirectoryService::update_radius`].
+    pub fn update_radius(
+        &self,
+        input: UpdateRadiusRequest,
+    ) -> Result<UpdateRadiusResult, RusotoError<UpdateRadiusError>> {
+        self.runtime.block_on(self.client.update_radius(input))
+    }
+
+    /// Blocking version of [`DirectoryService::update_trust`].
+    pub fn update_trust(
+        &self,
+        input: UpdateTrustRequest,
+    ) -> Result<UpdateTrustResult, RusotoError<UpdateTrustError>> {
+        self.runtime.block_on(self.client.update_trust(input))
+    }
+
+    /// Blocking version of [`DirectoryService::verify_trust`].
+    pub fn verify_trust(
+        &self,
+        input: VerifyTrustRequest,
+    ) -> Result<VerifyTrustResult, RusotoError<VerifyTrustError>> {
+        self.runtime.block_on(self.client.verify_trust(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -3561,16 +3562,22 @@ impl BatchExecuteStatementError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(BatchExecuteStatementError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchExecuteStatementError::InternalServerError,
+                    )
                 }
                 "RequestLimitExceeded" => {
-                    return RusotoError::Service(BatchExecuteStatementError::RequestLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchExecuteStatementError::RequestLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3605,20 +3612,38 @@ impl BatchGetItemError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(BatchGetItemError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetItemError::InternalServerError,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(BatchGetItemError::ProvisionedThroughputExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchGetItemError::ProvisionedThroughputExceeded,
+                    )
                 }
                 "RequestLimitExceeded" => {
-                    return RusotoError::Service(BatchGetItemError::RequestLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetItemError::RequestLimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(BatchGetItemError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetItemError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3657,25 +3682,46 @@ impl BatchWriteItemError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(BatchWriteItemError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchWriteItemError::InternalServerError,
+                    )
                 }
                 "ItemCollectionSizeLimitExceededException" => {
-                    return RusotoError::Service(
-                        BatchWriteItemError::ItemCollectionSizeLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchWriteItemError::ItemCollectionSizeLimitExceeded,
                     )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        BatchWriteItemError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchWriteItemError::ProvisionedThroughputExceeded,
                     )
                 }
                 "RequestLimitExceeded" => {
-                    return RusotoError::Service(BatchWriteItemError::RequestLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchWriteItemError::RequestLimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(BatchWriteItemError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchWriteItemError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3719,26 +3765,54 @@ impl CreateBackupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BackupInUseException" => {
-                    return RusotoError::Service(CreateBackupError::BackupInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBackupError::BackupInUse,
+                    )
                 }
                 "ContinuousBackupsUnavailableException" => {
-                    return RusotoError::Service(CreateBackupError::ContinuousBackupsUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateBackupError::ContinuousBackupsUnavailable,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateBackupError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBackupError::InternalServerError,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateBackupError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBackupError::LimitExceeded,
+                    )
                 }
                 "TableInUseException" => {
-                    return RusotoError::Service(CreateBackupError::TableInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBackupError::TableInUse,
+                    )
                 }
                 "TableNotFoundException" => {
-                    return RusotoError::Service(CreateBackupError::TableNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBackupError::TableNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3777,22 +3851,38 @@ impl CreateGlobalTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "GlobalTableAlreadyExistsException" => {
-                    return RusotoError::Service(CreateGlobalTableError::GlobalTableAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateGlobalTableError::GlobalTableAlreadyExists,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateGlobalTableError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateGlobalTableError::InternalServerError,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateGlobalTableError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGlobalTableError::LimitExceeded,
+                    )
                 }
                 "TableNotFoundException" => {
-                    return RusotoError::Service(CreateGlobalTableError::TableNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGlobalTableError::TableNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3827,15 +3917,30 @@ impl CreateTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateTableError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTableError::InternalServerError,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateTableError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTableError::LimitExceeded,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateTableError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTableError::ResourceInUse,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3871,18 +3976,38 @@ impl DeleteBackupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BackupInUseException" => {
-                    return RusotoError::Service(DeleteBackupError::BackupInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBackupError::BackupInUse,
+                    )
                 }
                 "BackupNotFoundException" => {
-                    return RusotoError::Service(DeleteBackupError::BackupNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBackupError::BackupNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteBackupError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBackupError::InternalServerError,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteBackupError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBackupError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3925,31 +4050,62 @@ impl DeleteItemError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConditionalCheckFailedException" => {
-                    return RusotoError::Service(DeleteItemError::ConditionalCheckFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteItemError::ConditionalCheckFailed,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteItemError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteItemError::InternalServerError,
+                    )
                 }
                 "ItemCollectionSizeLimitExceededException" => {
-                    return RusotoError::Service(DeleteItemError::ItemCollectionSizeLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteItemError::ItemCollectionSizeLimitExceeded,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(DeleteItemError::ProvisionedThroughputExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteItemError::ProvisionedThroughputExceeded,
+                    )
                 }
                 "RequestLimitExceeded" => {
-                    return RusotoError::Service(DeleteItemError::RequestLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteItemError::RequestLimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteItemError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteItemError::ResourceNotFound,
+                    )
                 }
                 "TransactionConflictException" => {
-                    return RusotoError::Service(DeleteItemError::TransactionConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteItemError::TransactionConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3989,18 +4145,38 @@ impl DeleteTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteTableError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTableError::InternalServerError,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteTableError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTableError::LimitExceeded,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteTableError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTableError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteTableError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTableError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4033,12 +4209,22 @@ impl DescribeBackupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BackupNotFoundException" => {
-                    return RusotoError::Service(DescribeBackupError::BackupNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBackupError::BackupNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeBackupError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBackupError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4069,16 +4255,22 @@ impl DescribeContinuousBackupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeContinuousBackupsError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeContinuousBackupsError::InternalServerError,
                     )
                 }
                 "TableNotFoundException" => {
-                    return RusotoError::Service(DescribeContinuousBackupsError::TableNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeContinuousBackupsError::TableNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4113,16 +4305,22 @@ impl DescribeContributorInsightsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeContributorInsightsError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeContributorInsightsError::InternalServerError,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeContributorInsightsError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeContributorInsightsError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4149,7 +4347,7 @@ impl DescribeEndpointsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeEndpointsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4179,15 +4377,30 @@ impl DescribeExportError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExportNotFoundException" => {
-                    return RusotoError::Service(DescribeExportError::ExportNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeExportError::ExportNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeExportError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeExportError::InternalServerError,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DescribeExportError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeExportError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4219,16 +4432,22 @@ impl DescribeGlobalTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "GlobalTableNotFoundException" => {
-                    return RusotoError::Service(DescribeGlobalTableError::GlobalTableNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeGlobalTableError::GlobalTableNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeGlobalTableError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeGlobalTableError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4261,16 +4480,22 @@ impl DescribeGlobalTableSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "GlobalTableNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeGlobalTableSettingsError::GlobalTableNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeGlobalTableSettingsError::GlobalTableNotFound,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeGlobalTableSettingsError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeGlobalTableSettingsError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4307,16 +4532,22 @@ impl DescribeKinesisStreamingDestinationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeKinesisStreamingDestinationError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeKinesisStreamingDestinationError::InternalServerError,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeKinesisStreamingDestinationError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeKinesisStreamingDestinationError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4349,9 +4580,14 @@ impl DescribeLimitsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeLimitsError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeLimitsError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4381,12 +4617,22 @@ impl DescribeTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeTableError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTableError::InternalServerError,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeTableError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTableError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4419,16 +4665,22 @@ impl DescribeTableReplicaAutoScalingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeTableReplicaAutoScalingError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTableReplicaAutoScalingError::InternalServerError,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeTableReplicaAutoScalingError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTableReplicaAutoScalingError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4463,14 +4715,22 @@ impl DescribeTimeToLiveError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeTimeToLiveError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeTimeToLiveError::InternalServerError,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeTimeToLiveError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTimeToLiveError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4507,26 +4767,38 @@ impl DisableKinesisStreamingDestinationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DisableKinesisStreamingDestinationError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableKinesisStreamingDestinationError::InternalServerError,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
-                        DisableKinesisStreamingDestinationError::LimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableKinesisStreamingDestinationError::LimitExceeded,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
-                        DisableKinesisStreamingDestinationError::ResourceInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableKinesisStreamingDestinationError::ResourceInUse,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DisableKinesisStreamingDestinationError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableKinesisStreamingDestinationError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4573,26 +4845,38 @@ impl EnableKinesisStreamingDestinationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        EnableKinesisStreamingDestinationError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableKinesisStreamingDestinationError::InternalServerError,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
-                        EnableKinesisStreamingDestinationError::LimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableKinesisStreamingDestinationError::LimitExceeded,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
-                        EnableKinesisStreamingDestinationError::ResourceInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableKinesisStreamingDestinationError::ResourceInUse,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        EnableKinesisStreamingDestinationError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableKinesisStreamingDestinationError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4645,42 +4929,70 @@ impl ExecuteStatementError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConditionalCheckFailedException" => {
-                    return RusotoError::Service(ExecuteStatementError::ConditionalCheckFailed(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ExecuteStatementError::ConditionalCheckFailed,
+                    )
                 }
                 "DuplicateItemException" => {
-                    return RusotoError::Service(ExecuteStatementError::DuplicateItem(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExecuteStatementError::DuplicateItem,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ExecuteStatementError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ExecuteStatementError::InternalServerError,
+                    )
                 }
                 "ItemCollectionSizeLimitExceededException" => {
-                    return RusotoError::Service(
-                        ExecuteStatementError::ItemCollectionSizeLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExecuteStatementError::ItemCollectionSizeLimitExceeded,
                     )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        ExecuteStatementError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExecuteStatementError::ProvisionedThroughputExceeded,
                     )
                 }
                 "RequestLimitExceeded" => {
-                    return RusotoError::Service(ExecuteStatementError::RequestLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ExecuteStatementError::RequestLimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ExecuteStatementError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExecuteStatementError::ResourceNotFound,
+                    )
                 }
                 "TransactionConflictException" => {
-                    return RusotoError::Service(ExecuteStatementError::TransactionConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ExecuteStatementError::TransactionConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4731,39 +5043,62 @@ impl ExecuteTransactionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        ExecuteTransactionError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExecuteTransactionError::IdempotentParameterMismatch,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ExecuteTransactionError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ExecuteTransactionError::InternalServerError,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        ExecuteTransactionError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExecuteTransactionError::ProvisionedThroughputExceeded,
                     )
                 }
                 "RequestLimitExceeded" => {
-                    return RusotoError::Service(ExecuteTransactionError::RequestLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ExecuteTransactionError::RequestLimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ExecuteTransactionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExecuteTransactionError::ResourceNotFound,
+                    )
                 }
                 "TransactionCanceledException" => {
-                    return RusotoError::Service(ExecuteTransactionError::TransactionCanceled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ExecuteTransactionError::TransactionCanceled,
+                    )
                 }
                 "TransactionInProgressException" => {
-                    return RusotoError::Service(ExecuteTransactionError::TransactionInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ExecuteTransactionError::TransactionInProgress,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4811,36 +5146,54 @@ impl ExportTableToPointInTimeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExportConflictException" => {
-                    return RusotoError::Service(ExportTableToPointInTimeError::ExportConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ExportTableToPointInTimeError::ExportConflict,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        ExportTableToPointInTimeError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExportTableToPointInTimeError::InternalServerError,
                     )
                 }
                 "InvalidExportTimeException" => {
-                    return RusotoError::Service(ExportTableToPointInTimeError::InvalidExportTime(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ExportTableToPointInTimeError::InvalidExportTime,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ExportTableToPointInTimeError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ExportTableToPointInTimeError::LimitExceeded,
+                    )
                 }
                 "PointInTimeRecoveryUnavailableException" => {
-                    return RusotoError::Service(
-                        ExportTableToPointInTimeError::PointInTimeRecoveryUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExportTableToPointInTimeError::PointInTimeRecoveryUnavailable,
                     )
                 }
                 "TableNotFoundException" => {
-                    return RusotoError::Service(ExportTableToPointInTimeError::TableNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ExportTableToPointInTimeError::TableNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4881,20 +5234,38 @@ impl GetItemError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetItemError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetItemError::InternalServerError,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(GetItemError::ProvisionedThroughputExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetItemError::ProvisionedThroughputExceeded,
+                    )
                 }
                 "RequestLimitExceeded" => {
-                    return RusotoError::Service(GetItemError::RequestLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetItemError::RequestLimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetItemError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetItemError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4925,9 +5296,14 @@ impl ListBackupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListBackupsError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBackupsError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4957,16 +5333,22 @@ impl ListContributorInsightsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListContributorInsightsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListContributorInsightsError::InternalServerError,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListContributorInsightsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListContributorInsightsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4997,12 +5379,22 @@ impl ListExportsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListExportsError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListExportsError::InternalServerError,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListExportsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListExportsError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5031,11 +5423,14 @@ impl ListGlobalTablesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListGlobalTablesError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListGlobalTablesError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5063,9 +5458,14 @@ impl ListTablesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListTablesError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTablesError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5095,14 +5495,22 @@ impl ListTagsOfResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListTagsOfResourceError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsOfResourceError::InternalServerError,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsOfResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsOfResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5143,31 +5551,62 @@ impl PutItemError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConditionalCheckFailedException" => {
-                    return RusotoError::Service(PutItemError::ConditionalCheckFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutItemError::ConditionalCheckFailed,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(PutItemError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutItemError::InternalServerError,
+                    )
                 }
                 "ItemCollectionSizeLimitExceededException" => {
-                    return RusotoError::Service(PutItemError::ItemCollectionSizeLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutItemError::ItemCollectionSizeLimitExceeded,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(PutItemError::ProvisionedThroughputExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutItemError::ProvisionedThroughputExceeded,
+                    )
                 }
                 "RequestLimitExceeded" => {
-                    return RusotoError::Service(PutItemError::RequestLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutItemError::RequestLimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutItemError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutItemError::ResourceNotFound,
+                    )
                 }
                 "TransactionConflictException" => {
-                    return RusotoError::Service(PutItemError::TransactionConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutItemError::TransactionConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5207,18 +5646,38 @@ impl QueryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(QueryError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        QueryError::InternalServerError,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(QueryError::ProvisionedThroughputExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        QueryError::ProvisionedThroughputExceeded,
+                    )
                 }
                 "RequestLimitExceeded" => {
-                    return RusotoError::Service(QueryError::RequestLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        QueryError::RequestLimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(QueryError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        QueryError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5259,32 +5718,54 @@ impl RestoreTableFromBackupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BackupInUseException" => {
-                    return RusotoError::Service(RestoreTableFromBackupError::BackupInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RestoreTableFromBackupError::BackupInUse,
+                    )
                 }
                 "BackupNotFoundException" => {
-                    return RusotoError::Service(RestoreTableFromBackupError::BackupNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RestoreTableFromBackupError::BackupNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(RestoreTableFromBackupError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RestoreTableFromBackupError::InternalServerError,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RestoreTableFromBackupError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RestoreTableFromBackupError::LimitExceeded,
+                    )
                 }
                 "TableAlreadyExistsException" => {
-                    return RusotoError::Service(RestoreTableFromBackupError::TableAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RestoreTableFromBackupError::TableAlreadyExists,
+                    )
                 }
                 "TableInUseException" => {
-                    return RusotoError::Service(RestoreTableFromBackupError::TableInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RestoreTableFromBackupError::TableInUse,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5329,41 +5810,62 @@ impl RestoreTableToPointInTimeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        RestoreTableToPointInTimeError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RestoreTableToPointInTimeError::InternalServerError,
                     )
                 }
                 "InvalidRestoreTimeException" => {
-                    return RusotoError::Service(
-                        RestoreTableToPointInTimeError::InvalidRestoreTime(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RestoreTableToPointInTimeError::InvalidRestoreTime,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RestoreTableToPointInTimeError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RestoreTableToPointInTimeError::LimitExceeded,
+                    )
                 }
                 "PointInTimeRecoveryUnavailableException" => {
-                    return RusotoError::Service(
-                        RestoreTableToPointInTimeError::PointInTimeRecoveryUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RestoreTableToPointInTimeError::PointInTimeRecoveryUnavailable,
                     )
                 }
                 "TableAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        RestoreTableToPointInTimeError::TableAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RestoreTableToPointInTimeError::TableAlreadyExists,
                     )
                 }
                 "TableInUseException" => {
-                    return RusotoError::Service(RestoreTableToPointInTimeError::TableInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RestoreTableToPointInTimeError::TableInUse,
+                    )
                 }
                 "TableNotFoundException" => {
-                    return RusotoError::Service(RestoreTableToPointInTimeError::TableNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RestoreTableToPointInTimeError::TableNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5407,18 +5909,38 @@ impl ScanError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ScanError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ScanError::InternalServerError,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(ScanError::ProvisionedThroughputExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ScanError::ProvisionedThroughputExceeded,
+                    )
                 }
                 "RequestLimitExceeded" => {
-                    return RusotoError::Service(ScanError::RequestLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ScanError::RequestLimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ScanError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ScanError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5455,18 +5977,38 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(TagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InternalServerError,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::LimitExceeded,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(TagResourceError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5505,29 +6047,46 @@ impl TransactGetItemsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(TransactGetItemsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TransactGetItemsError::InternalServerError,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        TransactGetItemsError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TransactGetItemsError::ProvisionedThroughputExceeded,
                     )
                 }
                 "RequestLimitExceeded" => {
-                    return RusotoError::Service(TransactGetItemsError::RequestLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TransactGetItemsError::RequestLimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TransactGetItemsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TransactGetItemsError::ResourceNotFound,
+                    )
                 }
                 "TransactionCanceledException" => {
-                    return RusotoError::Service(TransactGetItemsError::TransactionCanceled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TransactGetItemsError::TransactionCanceled,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5573,39 +6132,62 @@ impl TransactWriteItemsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        TransactWriteItemsError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TransactWriteItemsError::IdempotentParameterMismatch,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(TransactWriteItemsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TransactWriteItemsError::InternalServerError,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        TransactWriteItemsError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TransactWriteItemsError::ProvisionedThroughputExceeded,
                     )
                 }
                 "RequestLimitExceeded" => {
-                    return RusotoError::Service(TransactWriteItemsError::RequestLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TransactWriteItemsError::RequestLimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TransactWriteItemsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TransactWriteItemsError::ResourceNotFound,
+                    )
                 }
                 "TransactionCanceledException" => {
-                    return RusotoError::Service(TransactWriteItemsError::TransactionCanceled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TransactWriteItemsError::TransactionCanceled,
+                    )
                 }
                 "TransactionInProgressException" => {
-                    return RusotoError::Service(TransactWriteItemsError::TransactionInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TransactWriteItemsError::TransactionInProgress,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5649,18 +6231,38 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(UntagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InternalServerError,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UntagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::LimitExceeded,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5695,21 +6297,30 @@ impl UpdateContinuousBackupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContinuousBackupsUnavailableException" => {
-                    return RusotoError::Service(
-                        UpdateContinuousBackupsError::ContinuousBackupsUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateContinuousBackupsError::ContinuousBackupsUnavailable,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateContinuousBackupsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateContinuousBackupsError::InternalServerError,
+                    )
                 }
                 "TableNotFoundException" => {
-                    return RusotoError::Service(UpdateContinuousBackupsError::TableNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateContinuousBackupsError::TableNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5743,16 +6354,22 @@ impl UpdateContributorInsightsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        UpdateContributorInsightsError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateContributorInsightsError::InternalServerError,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateContributorInsightsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateContributorInsightsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5791,27 +6408,46 @@ impl UpdateGlobalTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "GlobalTableNotFoundException" => {
-                    return RusotoError::Service(UpdateGlobalTableError::GlobalTableNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateGlobalTableError::GlobalTableNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateGlobalTableError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateGlobalTableError::InternalServerError,
+                    )
                 }
                 "ReplicaAlreadyExistsException" => {
-                    return RusotoError::Service(UpdateGlobalTableError::ReplicaAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateGlobalTableError::ReplicaAlreadyExists,
+                    )
                 }
                 "ReplicaNotFoundException" => {
-                    return RusotoError::Service(UpdateGlobalTableError::ReplicaNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGlobalTableError::ReplicaNotFound,
+                    )
                 }
                 "TableNotFoundException" => {
-                    return RusotoError::Service(UpdateGlobalTableError::TableNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGlobalTableError::TableNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5853,36 +6489,54 @@ impl UpdateGlobalTableSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "GlobalTableNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateGlobalTableSettingsError::GlobalTableNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGlobalTableSettingsError::GlobalTableNotFound,
                     )
                 }
                 "IndexNotFoundException" => {
-                    return RusotoError::Service(UpdateGlobalTableSettingsError::IndexNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateGlobalTableSettingsError::IndexNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        UpdateGlobalTableSettingsError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGlobalTableSettingsError::InternalServerError,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateGlobalTableSettingsError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateGlobalTableSettingsError::LimitExceeded,
+                    )
                 }
                 "ReplicaNotFoundException" => {
-                    return RusotoError::Service(UpdateGlobalTableSettingsError::ReplicaNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateGlobalTableSettingsError::ReplicaNotFound,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateGlobalTableSettingsError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateGlobalTableSettingsError::ResourceInUse,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5931,31 +6585,62 @@ impl UpdateItemError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConditionalCheckFailedException" => {
-                    return RusotoError::Service(UpdateItemError::ConditionalCheckFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateItemError::ConditionalCheckFailed,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateItemError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateItemError::InternalServerError,
+                    )
                 }
                 "ItemCollectionSizeLimitExceededException" => {
-                    return RusotoError::Service(UpdateItemError::ItemCollectionSizeLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateItemError::ItemCollectionSizeLimitExceeded,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(UpdateItemError::ProvisionedThroughputExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateItemError::ProvisionedThroughputExceeded,
+                    )
                 }
                 "RequestLimitExceeded" => {
-                    return RusotoError::Service(UpdateItemError::RequestLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateItemError::RequestLimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateItemError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateItemError::ResourceNotFound,
+                    )
                 }
                 "TransactionConflictException" => {
-                    return RusotoError::Service(UpdateItemError::TransactionConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateItemError::TransactionConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5995,18 +6680,38 @@ impl UpdateTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateTableError::InternalServerError(err.msg))
-                }
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTableError::InternalServerError,
+                    )
+                }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateTableError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTableError::LimitExceeded,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateTableError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTableError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateTableError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTableError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6045,26 +6750,38 @@ impl UpdateTableReplicaAutoScalingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        UpdateTableReplicaAutoScalingError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTableReplicaAutoScalingError::InternalServerError,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateTableReplicaAutoScalingError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateTableReplicaAutoScalingError::LimitExceeded,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateTableReplicaAutoScalingError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateTableReplicaAutoScalingError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateTableReplicaAutoScalingError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTableReplicaAutoScalingError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6105,20 +6822,38 @@ impl UpdateTimeToLiveError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateTimeToLiveError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateTimeToLiveError::InternalServerError,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateTimeToLiveError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTimeToLiveError::LimitExceeded,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateTimeToLiveError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTimeToLiveError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateTimeToLiveError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTimeToLiveError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6454,11 +7189,12 @@ pub struct DynamoDbClient {
 impl DynamoDbClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> DynamoDbClient {
         DynamoDbClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "DynamoDB"),
         }
     }
 
@@ -6473,12 +7209,32 @@ impl DynamoDbClient {
     {
         DynamoDbClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "DynamoDB"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> DynamoDbClient {
-        DynamoDbClient { client, region }
+        DynamoDbClient {
+            client,
+            region: region_for_service(region, "DynamoDB"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> DynamoDbClient {
+        DynamoDbClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("DynamoDB"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> DynamoDbClient {
+        DynamoDbClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -6490,6 +7246,7 @@ impl DynamoDb for DynamoDbClient {
         input: BatchExecuteStatementInput,
     ) -> Result<BatchExecuteStatementOutput, RusotoError<BatchExecuteStatementError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchExecuteStatement");
         request.add_header("x-amz-target", "DynamoDB_20120810.BatchExecuteStatement");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6508,6 +7265,7 @@ impl DynamoDb for DynamoDbClient {
         input: BatchGetItemInput,
     ) -> Result<BatchGetItemOutput, RusotoError<BatchGetItemError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchGetItem");
         request.add_header("x-amz-target", "DynamoDB_20120810.BatchGetItem");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6526,6 +7284,7 @@ impl DynamoDb for DynamoDbClient {
         input: BatchWriteItemInput,
     ) -> Result<BatchWriteItemOutput, RusotoError<BatchWriteItemError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchWriteItem");
         request.add_header("x-amz-target", "DynamoDB_20120810.BatchWriteItem");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6544,6 +7303,7 @@ impl DynamoDb for DynamoDbClient {
         input: CreateBackupInput,
     ) -> Result<CreateBackupOutput, RusotoError<CreateBackupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateBackup");
         request.add_header("x-amz-target", "DynamoDB_20120810.CreateBackup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6562,6 +7322,7 @@ impl DynamoDb for DynamoDbClient {
         input: CreateGlobalTableInput,
     ) -> Result<CreateGlobalTableOutput, RusotoError<CreateGlobalTableError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateGlobalTable");
         request.add_header("x-amz-target", "DynamoDB_20120810.CreateGlobalTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6580,6 +7341,7 @@ impl DynamoDb for DynamoDbClient {
         input: CreateTableInput,
     ) -> Result<CreateTableOutput, RusotoError<CreateTableError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateTable");
         request.add_header("x-amz-target", "DynamoDB_20120810.CreateTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6598,6 +7360,7 @@ impl DynamoDb for DynamoDbClient {
         input: DeleteBackupInput,
     ) -> Result<DeleteBackupOutput, RusotoError<DeleteBackupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteBackup");
         request.add_header("x-amz-target", "DynamoDB_20120810.DeleteBackup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6616,6 +7379,7 @@ impl DynamoDb for DynamoDbClient {
         input: DeleteItemInput,
     ) -> Result<DeleteItemOutput, RusotoError<DeleteItemError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteItem");
         request.add_header("x-amz-target", "DynamoDB_20120810.DeleteItem");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6634,6 +7398,7 @@ impl DynamoDb for DynamoDbClient {
         input: DeleteTableInput,
     ) -> Result<DeleteTableOutput, RusotoError<DeleteTableError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteTable");
         request.add_header("x-amz-target", "DynamoDB_20120810.DeleteTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6652,6 +7417,7 @@ impl DynamoDb for DynamoDbClient {
         input: DescribeBackupInput,
     ) -> Result<DescribeBackupOutput, RusotoError<DescribeBackupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeBackup");
         request.add_header("x-amz-target", "DynamoDB_20120810.DescribeBackup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6670,6 +7436,7 @@ impl DynamoDb for DynamoDbClient {
         input: DescribeContinuousBackupsInput,
     ) -> Result<DescribeContinuousBackupsOutput, RusotoError<DescribeContinuousBackupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeContinuousBackups");
         request.add_header(
             "x-amz-target",
             "DynamoDB_20120810.DescribeContinuousBackups",
@@ -6693,6 +7460,7 @@ impl DynamoDb for DynamoDbClient {
     ) -> Result<DescribeContributorInsightsOutput, RusotoError<DescribeContributorInsightsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeContributorInsights");
         request.add_header(
             "x-amz-target",
             "DynamoDB_20120810.DescribeContributorInsights",
@@ -6714,6 +7482,7 @@ impl DynamoDb for DynamoDbClient {
         &self,
     ) -> Result<DescribeEndpointsResponse, RusotoError<DescribeEndpointsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeEndpoints");
         request.add_header("x-amz-target", "DynamoDB_20120810.DescribeEndpoints");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -6731,6 +7500,7 @@ impl DynamoDb for DynamoDbClient {
         input: DescribeExportInput,
     ) -> Result<DescribeExportOutput, RusotoError<DescribeExportError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeExport");
         request.add_header("x-amz-target", "DynamoDB_20120810.DescribeExport");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6749,6 +7519,7 @@ impl DynamoDb for DynamoDbClient {
         input: DescribeGlobalTableInput,
     ) -> Result<DescribeGlobalTableOutput, RusotoError<DescribeGlobalTableError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeGlobalTable");
         request.add_header("x-amz-target", "DynamoDB_20120810.DescribeGlobalTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6768,6 +7539,7 @@ impl DynamoDb for DynamoDbClient {
     ) -> Result<DescribeGlobalTableSettingsOutput, RusotoError<DescribeGlobalTableSettingsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeGlobalTableSettings");
         request.add_header(
             "x-amz-target",
             "DynamoDB_20120810.DescribeGlobalTableSettings",
@@ -6793,6 +7565,7 @@ impl DynamoDb for DynamoDbClient {
         RusotoError<DescribeKinesisStreamingDestinationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeKinesisStreamingDestination");
         request.add_header(
             "x-amz-target",
             "DynamoDB_20120810.DescribeKinesisStreamingDestination",
@@ -6817,6 +7590,7 @@ impl DynamoDb for DynamoDbClient {
         &self,
     ) -> Result<DescribeLimitsOutput, RusotoError<DescribeLimitsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeLimits");
         request.add_header("x-amz-target", "DynamoDB_20120810.DescribeLimits");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -6834,6 +7608,7 @@ impl DynamoDb for DynamoDbClient {
         input: DescribeTableInput,
     ) -> Result<DescribeTableOutput, RusotoError<DescribeTableError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeTable");
         request.add_header("x-amz-target", "DynamoDB_20120810.DescribeTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6855,6 +7630,7 @@ impl DynamoDb for DynamoDbClient {
         RusotoError<DescribeTableReplicaAutoScalingError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeTableReplicaAutoScaling");
         request.add_header(
             "x-amz-target",
             "DynamoDB_20120810.DescribeTableReplicaAutoScaling",
@@ -6877,6 +7653,7 @@ impl DynamoDb for DynamoDbClient {
         input: DescribeTimeToLiveInput,
     ) -> Result<DescribeTimeToLiveOutput, RusotoError<DescribeTimeToLiveError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeTimeToLive");
         request.add_header("x-amz-target", "DynamoDB_20120810.DescribeTimeToLive");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6898,6 +7675,7 @@ impl DynamoDb for DynamoDbClient {
         RusotoError<DisableKinesisStreamingDestinationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisableKinesisStreamingDestination");
         request.add_header(
             "x-amz-target",
             "DynamoDB_20120810.DisableKinesisStreamingDestination",
@@ -6926,6 +7704,7 @@ impl DynamoDb for DynamoDbClient {
         RusotoError<EnableKinesisStreamingDestinationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("EnableKinesisStreamingDestination");
         request.add_header(
             "x-amz-target",
             "DynamoDB_20120810.EnableKinesisStreamingDestination",
@@ -6951,6 +7730,7 @@ impl DynamoDb for DynamoDbClient {
         input: ExecuteStatementInput,
     ) -> Result<ExecuteStatementOutput, RusotoError<ExecuteStatementError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ExecuteStatement");
         request.add_header("x-amz-target", "DynamoDB_20120810.ExecuteStatement");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6969,6 +7749,7 @@ impl DynamoDb for DynamoDbClient {
         input: ExecuteTransactionInput,
     ) -> Result<ExecuteTransactionOutput, RusotoError<ExecuteTransactionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ExecuteTransaction");
         request.add_header("x-amz-target", "DynamoDB_20120810.ExecuteTransaction");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6987,6 +7768,7 @@ impl DynamoDb for DynamoDbClient {
         input: ExportTableToPointInTimeInput,
     ) -> Result<ExportTableToPointInTimeOutput, RusotoError<ExportTableToPointInTimeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ExportTableToPointInTime");
         request.add_header("x-amz-target", "DynamoDB_20120810.ExportTableToPointInTime");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7006,6 +7788,7 @@ impl DynamoDb for DynamoDbClient {
         input: GetItemInput,
     ) -> Result<GetItemOutput, RusotoError<GetItemError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetItem");
         request.add_header("x-amz-target", "DynamoDB_20120810.GetItem");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7024,6 +7807,7 @@ impl DynamoDb for DynamoDbClient {
         input: ListBackupsInput,
     ) -> Result<ListBackupsOutput, RusotoError<ListBackupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListBackups");
         request.add_header("x-amz-target", "DynamoDB_20120810.ListBackups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7042,6 +7826,7 @@ impl DynamoDb for DynamoDbClient {
         input: ListContributorInsightsInput,
     ) -> Result<ListContributorInsightsOutput, RusotoError<ListContributorInsightsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListContributorInsights");
         request.add_header("x-amz-target", "DynamoDB_20120810.ListContributorInsights");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7061,6 +7846,7 @@ impl DynamoDb for DynamoDbClient {
         input: ListExportsInput,
     ) -> Result<ListExportsOutput, RusotoError<ListExportsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListExports");
         request.add_header("x-amz-target", "DynamoDB_20120810.ListExports");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7079,6 +7865,7 @@ impl DynamoDb for DynamoDbClient {
         input: ListGlobalTablesInput,
     ) -> Result<ListGlobalTablesOutput, RusotoError<ListGlobalTablesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListGlobalTables");
         request.add_header("x-amz-target", "DynamoDB_20120810.ListGlobalTables");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7097,6 +7884,7 @@ impl DynamoDb for DynamoDbClient {
         input: ListTablesInput,
     ) -> Result<ListTablesOutput, RusotoError<ListTablesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTables");
         request.add_header("x-amz-target", "DynamoDB_20120810.ListTables");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7115,6 +7903,7 @@ impl DynamoDb for DynamoDbClient {
         input: ListTagsOfResourceInput,
     ) -> Result<ListTagsOfResourceOutput, RusotoError<ListTagsOfResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsOfResource");
         request.add_header("x-amz-target", "DynamoDB_20120810.ListTagsOfResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7133,6 +7922,7 @@ impl DynamoDb for DynamoDbClient {
         input: PutItemInput,
     ) -> Result<PutItemOutput, RusotoError<PutItemError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutItem");
         request.add_header("x-amz-target", "DynamoDB_20120810.PutItem");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7148,6 +7938,7 @@ impl DynamoDb for DynamoDbClient {
     /// <p>The <code>Query</code> operation finds items based on primary key values. You can query any table or secondary index that has a composite primary key (a partition key and a sort key). </p> <p>Use the <code>KeyConditionExpression</code> parameter to provide a specific value for the partition key. The <code>Query</code> operation will return all of the items from the table or index with that partition key value. You can optionally narrow the scope of the <code>Query</code> operation by specifying a sort key value and a comparison operator in <code>KeyConditionExpression</code>. To further refine the <code>Query</code> results, you can optionally provide a <code>FilterExpression</code>. A <code>FilterExpression</code> determines which items within the results should be returned to you. All of the other results are discarded. </p> <p> A <code>Query</code> operation always returns a result set. If no matching items are found, the result set will be empty. Queries that do not return results consume the minimum number of read capacity units for that type of read operation. </p> <note> <p> DynamoDB calculates the number of read capacity units consumed based on item size, not on the amount of data that is returned to an application. The number of capacity units consumed will be the same whether you request all of the attributes (the default behavior) or just some of them (using a projection expression). The number will also be the same whether or not you use a <code>FilterExpression</code>. </p> </note> <p> <code>Query</code> results are always sorted by the sort key value. If the data type of the sort key is Number, the results are returned in numeric order; otherwise, the results are returned in order of UTF-8 bytes. By default, the sort order is ascending. To reverse the order, set the <code>ScanIndexForward</code> parameter to false. </p> <p> A single <code>Query</code> operation will read up to the maximum number of items set (if using the <code>Limit</code> parameter) or a maximum of 1 MB of data and then apply any filtering to the results using <code>FilterExpression</code>. If <code>LastEvaluatedKey</code> is present in the response, you will need to paginate the result set. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Query.html#Query.Pagination">Paginating the Results</a> in the <i>Amazon DynamoDB Developer Guide</i>. </p> <p> <code>FilterExpression</code> is applied after a <code>Query</code> finishes, but before the results are returned. A <code>FilterExpression</code> cannot contain partition key or sort key attributes. You need to specify those attributes in the <code>KeyConditionExpression</code>. </p> <note> <p> A <code>Query</code> operation can return an empty result set and a <code>LastEvaluatedKey</code> if all the items read for the page of results are filtered out. </p> </note> <p>You can query a table, a local secondary index, or a global secondary index. For a query on a table or on a local secondary index, you can set the <code>ConsistentRead</code> parameter to <code>true</code> and obtain a strongly consistent result. Global secondary indexes support eventually consistent reads only, so do not specify <code>ConsistentRead</code> when querying a global secondary index.</p>
     async fn query(&self, input: QueryInput) -> Result<QueryOutput, RusotoError<QueryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("Query");
         request.add_header("x-amz-target", "DynamoDB_20120810.Query");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7166,6 +7957,7 @@ impl DynamoDb for DynamoDbClient {
         input: RestoreTableFromBackupInput,
     ) -> Result<RestoreTableFromBackupOutput, RusotoError<RestoreTableFromBackupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RestoreTableFromBackup");
         request.add_header("x-amz-target", "DynamoDB_20120810.RestoreTableFromBackup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7185,6 +7977,7 @@ impl DynamoDb for DynamoDbClient {
         input: RestoreTableToPointInTimeInput,
     ) -> Result<RestoreTableToPointInTimeOutput, RusotoError<RestoreTableToPointInTimeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RestoreTableToPointInTime");
         request.add_header(
             "x-amz-target",
             "DynamoDB_20120810.RestoreTableToPointInTime",
@@ -7204,6 +7997,7 @@ impl DynamoDb for DynamoDbClient {
     /// <p>The <code>Scan</code> operation returns one or more items and item attributes by accessing every item in a table or a secondary index. To have DynamoDB return fewer items, you can provide a <code>FilterExpression</code> operation.</p> <p>If the total number of scanned items exceeds the maximum dataset size limit of 1 MB, the scan stops and results are returned to the user as a <code>LastEvaluatedKey</code> value to continue the scan in a subsequent operation. The results also include the number of items exceeding the limit. A scan can result in no table data meeting the filter criteria. </p> <p>A single <code>Scan</code> operation reads up to the maximum number of items set (if using the <code>Limit</code> parameter) or a maximum of 1 MB of data and then apply any filtering to the results using <code>FilterExpression</code>. If <code>LastEvaluatedKey</code> is present in the response, you need to paginate the result set. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Scan.html#Scan.Pagination">Paginating the Results</a> in the <i>Amazon DynamoDB Developer Guide</i>. </p> <p> <code>Scan</code> operations proceed sequentially; however, for faster performance on a large table or secondary index, applications can request a parallel <code>Scan</code> operation by providing the <code>Segment</code> and <code>TotalSegments</code> parameters. For more information, see <a href="https://docs.aws.amazon.com/amazondynamodb/latest/developerguide/Scan.html#Scan.ParallelScan">Parallel Scan</a> in the <i>Amazon DynamoDB Developer Guide</i>.</p> <p> <code>Scan</code> uses eventually consistent reads when accessing the data in a table; therefore, the result set might not include the changes to data in the table immediately before the operation began. If you need a consistent copy of the data, as of the time that the <code>Scan</code> begins, you can set the <code>ConsistentRead</code> parameter to <code>true</code>.</p>
     async fn scan(&self, input: ScanInput) -> Result<ScanOutput, RusotoError<ScanError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("Scan");
         request.add_header("x-amz-target", "DynamoDB_20120810.Scan");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7222,6 +8016,7 @@ impl DynamoDb for DynamoDbClient {
         input: TagResourceInput,
     ) -> Result<(), RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "DynamoDB_20120810.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7239,6 +8034,7 @@ impl DynamoDb for DynamoDbClient {
         input: TransactGetItemsInput,
     ) -> Result<TransactGetItemsOutput, RusotoError<TransactGetItemsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TransactGetItems");
         request.add_header("x-amz-target", "DynamoDB_20120810.TransactGetItems");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7257,6 +8053,7 @@ impl DynamoDb for DynamoDbClient {
         input: TransactWriteItemsInput,
     ) -> Result<TransactWriteItemsOutput, RusotoError<TransactWriteItemsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TransactWriteItems");
         request.add_header("x-amz-target", "DynamoDB_20120810.TransactWriteItems");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7275,6 +8072,7 @@ impl DynamoDb for DynamoDbClient {
         input: UntagResourceInput,
     ) -> Result<(), RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "DynamoDB_20120810.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7292,6 +8090,7 @@ impl DynamoDb for DynamoDbClient {
         input: UpdateContinuousBackupsInput,
     ) -> Result<UpdateContinuousBackupsOutput, RusotoError<UpdateContinuousBackupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateContinuousBackups");
         request.add_header("x-amz-target", "DynamoDB_20120810.UpdateContinuousBackups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7311,6 +8110,7 @@ impl DynamoDb for DynamoDbClient {
         input: UpdateContributorInsightsInput,
     ) -> Result<UpdateContributorInsightsOutput, RusotoError<UpdateContributorInsightsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateContributorInsights");
         request.add_header(
             "x-amz-target",
             "DynamoDB_20120810.UpdateContributorInsights",
@@ -7333,6 +8133,7 @@ impl DynamoDb for DynamoDbClient {
         input: UpdateGlobalTableInput,
     ) -> Result<UpdateGlobalTableOutput, RusotoError<UpdateGlobalTableError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateGlobalTable");
         request.add_header("x-amz-target", "DynamoDB_20120810.UpdateGlobalTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7351,6 +8152,7 @@ impl DynamoDb for DynamoDbClient {
         input: UpdateGlobalTableSettingsInput,
     ) -> Result<UpdateGlobalTableSettingsOutput, RusotoError<UpdateGlobalTableSettingsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateGlobalTableSettings");
         request.add_header(
             "x-amz-target",
             "DynamoDB_20120810.UpdateGlobalTableSettings",
@@ -7373,6 +8175,7 @@ impl DynamoDb for DynamoDbClient {
         input: UpdateItemInput,
     ) -> Result<UpdateItemOutput, RusotoError<UpdateItemError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateItem");
         request.add_header("x-amz-target", "DynamoDB_20120810.UpdateItem");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7391,6 +8194,7 @@ impl DynamoDb for DynamoDbClient {
         input: UpdateTableInput,
     ) -> Result<UpdateTableOutput, RusotoError<UpdateTableError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateTable");
         request.add_header("x-amz-target", "DynamoDB_20120810.UpdateTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7410,6 +8214,7 @@ impl DynamoDb for DynamoDbClient {
     ) -> Result<UpdateTableReplicaAutoScalingOutput, RusotoError<UpdateTableReplicaAutoScalingError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateTableReplicaAutoScaling");
         request.add_header(
             "x-amz-target",
             "DynamoDB_20120810.UpdateTableReplicaAutoScaling",
@@ -7432,6 +8237,7 @@ impl DynamoDb for DynamoDbClient {
         input: UpdateTimeToLiveInput,
     ) -> Result<UpdateTimeToLiveOutput, RusotoError<UpdateTimeToLiveError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateTimeToLive");
         request.add_header("x-amz-target", "DynamoDB_20120810.UpdateTimeToLive");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7444,3 +8250,509 @@ impl DynamoDb for DynamoDbClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateTimeToLiveOutput, _>()
     }
 }
+
+/// A blocking client for the DynamoDB API, running the calls of a `DynamoDbClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `DynamoDbClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct DynamoDbBlockingClient {
+    client: DynamoDbClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl DynamoDbBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> DynamoDbBlockingClient {
+        DynamoDbBlockingClient::from_client(DynamoDbClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> DynamoDbBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        DynamoDbBlockingClient::from_client(DynamoDbClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> DynamoDbBlockingClient {
+        DynamoDbBlockingClient::from_client(DynamoDbClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> DynamoDbBlockingClient {
+        DynamoDbBlockingClient::from_client(DynamoDbClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: DynamoDbClient) -> DynamoDbBlockingClient {
+        DynamoDbBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> DynamoDbBlockingClient {
+        DynamoDbBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> DynamoDbBlockingClient {
+        DynamoDbBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`DynamoDb::batch_execute_statement`].
+    pub fn batch_execute_statement(
+        &self,
+        input: BatchExecuteStatementInput,
+    ) -> Result<BatchExecuteStatementOutput, RusotoError<BatchExecuteStatementError>> {
+        self.runtime
+            .block_on(self.client.batch_execute_statement(input))
+    }
+
+    /// Blocking version of [`DynamoDb::batch_get_item`].
+    pub fn batch_get_item(
+        &self,
+        input: BatchGetItemInput,
+    ) -> Result<BatchGetItemOutput, RusotoError<BatchGetItemError>> {
+        self.runtime.block_on(self.client.batch_get_item(input))
+    }
+
+    /// Blocking version of [`DynamoDb::batch_write_item`].
+    pub fn batch_write_item(
+        &self,
+        input: BatchWriteItemInput,
+    ) -> Result<BatchWriteItemOutput, RusotoError<BatchWriteItemError>> {
+        self.runtime.block_on(self.client.batch_write_item(input))
+    }
+
+    /// Blocking version of [`DynamoDb::create_backup`].
+    pub fn create_backup(
+        &self,
+        input: CreateBackupInput,
+    ) -> Result<CreateBackupOutput, RusotoError<CreateBackupError>> {
+        self.runtime.block_on(self.client.create_backup(input))
+    }
+
+    /// Blocking version of [`DynamoDb::create_global_table`].
+    pub fn create_global_table(
+        &self,
+        input: CreateGlobalTableInput,
+    ) -> Result<CreateGlobalTableOutput, RusotoError<CreateGlobalTableError>> {
+        self.runtime
+            .block_on(self.client.create_global_table(input))
+    }
+
+    /// Blocking version of [`DynamoDb::create_table`].
+    pub fn create_table(
+        &self,
+        input: CreateTableInput,
+    ) -> Result<CreateTableOutput, RusotoError<CreateTableError>> {
+        self.runtime.block_on(self.client.create_table(input))
+    }
+
+    /// Blocking version of [`DynamoDb::delete_backup`].
+    pub fn delete_backup(
+        &self,
+        input: DeleteBackupInput,
+    ) -> Result<DeleteBackupOutput, RusotoError<DeleteBackupError>> {
+        self.runtime.block_on(self.client.delete_backup(input))
+    }
+
+    /// Blocking version of [`DynamoDb::delete_item`].
+    pub fn delete_item(
+        &self,
+        input: DeleteItemInput,
+    ) -> Result<DeleteItemOutput, RusotoError<DeleteItemError>> {
+        self.runtime.block_on(self.client.delete_item(input))
+    }
+
+    /// Blocking version of [`DynamoDb::delete_table`].
+    pub fn delete_table(
+        &self,
+        input: DeleteTableInput,
+    ) -> Result<DeleteTableOutput, RusotoError<DeleteTableError>> {
+        self.runtime.block_on(self.client.delete_table(input))
+    }
+
+    /// Blocking version of [`DynamoDb::describe_backup`].
+    pub fn describe_backup(
+        &self,
+        input: DescribeBackupInput,
+    ) -> Result<DescribeBackupOutput, RusotoError<DescribeBackupError>> {
+        self.runtime.block_on(self.client.describe_backup(input))
+    }
+
+    /// Blocking version of [`DynamoDb::describe_continuous_backups`].
+    pub fn describe_continuous_backups(
+        &self,
+        input: DescribeContinuousBackupsInput,
+    ) -> Result<DescribeContinuousBackupsOutput, RusotoError<DescribeContinuousBackupsError>> {
+        self.runtime
+            .block_on(self.client.describe_continuous_backups(input))
+    }
+
+    /// Blocking version of [`DynamoDb::describe_contributor_insights`].
+    pub fn describe_contributor_insights(
+        &self,
+        input: DescribeContributorInsightsInput,
+    ) -> Result<DescribeContributorInsightsOutput, RusotoError<DescribeContributorInsightsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_contributor_insights(input))
+    }
+
+    /// Blocking version of [`DynamoDb::describe_endpoints`].
+    pub fn describe_endpoints(
+        &self,
+    ) -> Result<DescribeEndpointsResponse, RusotoError<DescribeEndpointsError>> {
+        self.runtime.block_on(self.client.describe_endpoints())
+    }
+
+    /// Blocking version of [`DynamoDb::describe_export`].
+    pub fn describe_export(
+        &self,
+        input: DescribeExportInput,
+    ) -> Result<DescribeExportOutput, RusotoError<DescribeExportError>> {
+        self.runtime.block_on(self.client.describe_export(input))
+    }
+
+    /// Blocking version of [`DynamoDb::describe_global_table`].
+    pub fn describe_global_table(
+        &self,
+        input: DescribeGlobalTableInput,
+    ) -> Result<DescribeGlobalTableOutput, RusotoError<DescribeGlobalTableError>> {
+        self.runtime
+            .block_on(self.client.describe_global_table(input))
+    }
+
+    /// Blocking version of [`DynamoDb::describe_global_table_settings`].
+    pub fn describe_global_table_settings(
+        &self,
+        input: DescribeGlobalTableSettingsInput,
+    ) -> Result<DescribeGlobalTableSettingsOutput, RusotoError<DescribeGlobalTableSettingsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_global_table_settings(input))
+    }
+
+    /// Blocking version of [`DynamoDb::describe_kinesis_streaming_destination`].
+    pub fn describe_kinesis_streaming_destination(
+        &self,
+        input: DescribeKinesisStreamingDestinationInput,
+    ) -> Result<
+        DescribeKinesisStreamingDestinationOutput,
+        RusotoError<DescribeKinesisStreamingDestinationError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_kinesis_streaming_destination(input))
+    }
+
+    /// Blocking version of [`DynamoDb::describe_limits`].
+    pub fn describe_limits(
+        &self,
+    ) -> Result<DescribeLimitsOutput, RusotoError<DescribeLimitsError>> {
+        self.runtime.block_on(self.client.describe_limits())
+    }
+
+    /// Blocking version of [`DynamoDb::describe_table`].
+    pub fn describe_table(
+        &self,
+        input: DescribeTableInput,
+    ) -> Result<DescribeTableOutput, RusotoError<DescribeTableError>> {
+        self.runtime.block_on(self.client.describe_table(input))
+    }
+
+    /// Blocking version of [`DynamoDb::describe_table_replica_auto_scaling`].
+    pub fn describe_table_replica_auto_scaling(
+        &self,
+        input: DescribeTableReplicaAutoScalingInput,
+    ) -> Result<
+        DescribeTableReplicaAutoScalingOutput,
+        RusotoError<DescribeTableReplicaAutoScalingError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_table_replica_auto_scaling(input))
+    }
+
+    /// Blocking version of [`DynamoDb::describe_time_to_live`].
+    pub fn describe_time_to_live(
+        &self,
+        input: DescribeTimeToLiveInput,
+    ) -> Result<DescribeTimeToLiveOutput, RusotoError<DescribeTimeToLiveError>> {
+        self.runtime
+            .block_on(self.client.describe_time_to_live(input))
+    }
+
+    /// Blocking version of [`DynamoDb::disable_kinesis_streaming_destination`].
+    pub fn disable_kinesis_streaming_destination(
+        &self,
+        input: KinesisStreamingDestinationInput,
+    ) -> Result<
+        KinesisStreamingDestinationOutput,
+        RusotoError<DisableKinesisStreamingDestinationError>,
+    > {
+        self.runtime
+            .block_on(self.client.disable_kinesis_streaming_destination(input))
+    }
+
+    /// Blocking version of [`DynamoDb::enable_kinesis_streaming_destination`].
+    pub fn enable_kinesis_streaming_destination(
+        &self,
+        input: KinesisStreamingDestinationInput,
+    ) -> Result<
+        KinesisStreamingDestinationOutput,
+        RusotoError<EnableKinesisStreamingDestinationError>,
+    > {
+        self.runtime
+            .block_on(self.client.enable_kinesis_streaming_destination(input))
+    }
+
+    /// Blocking version of [`DynamoDb::execute_statement`].
+    pub fn execute_statement(
+        &self,
+        input: ExecuteStatementInput,
+    ) -> Result<ExecuteStatementOutput, RusotoError<ExecuteStatementError>> {
+        self.runtime.block_on(self.client.execute_statement(input))
+    }
+
+    /// Blocking version of [`DynamoDb::execute_transaction`].
+    pub fn execute_transaction(
+        &self,
+        input: ExecuteTransactionInput,
+    ) -> Result<ExecuteTransactionOutput, RusotoError<ExecuteTransactionError>> {
+        self.runtime
+            .block_on(self.client.execute_transaction(input))
+    }
+
+    /// Blocking version of [`DynamoDb::export_table_to_point_in_time`].
+    pub fn export_table_to_point_in_time(
+        &self,
+        input: ExportTableToPointInTimeInput,
+    ) -> Result<ExportTableToPointInTimeOutput, RusotoError<ExportTableToPointInTimeError>> {
+        self.runtime
+            .block_on(self.client.export_table_to_point_in_time(input))
+    }
+
+    /// Blocking version of [`DynamoDb::get_item`].
+    pub fn get_item(
+        &self,
+        input: GetItemInput,
+    ) -> Result<GetItemOutput, RusotoError<GetItemError>> {
+        self.runtime.block_on(self.client.get_item(input))
+    }
+
+    /// Blocking version of [`DynamoDb::list_backups`].
+    pub fn list_backups(
+        &self,
+        input: ListBackupsInput,
+    ) -> Result<ListBackupsOutput, RusotoError<ListBackupsError>> {
+        self.runtime.block_on(self.client.list_backups(input))
+    }
+
+    /// Blocking version of [`DynamoDb::list_contributor_insights`].
+    pub fn list_contributor_insights(
+        &self,
+        input: ListContributorInsightsInput,
+    ) -> Result<ListContributorInsightsOutput, RusotoError<ListContributorInsightsError>> {
+        self.runtime
+            .block_on(self.client.list_contributor_insights(input))
+    }
+
+    /// Blocking version of [`DynamoDb::list_exports`].
+    pub fn list_exports(
+        &self,
+        input: ListExportsInput,
+    ) -> Result<ListExportsOutput, RusotoError<ListExportsError>> {
+        self.runtime.block_on(self.client.list_exports(input))
+    }
+
+    /// Blocking version of [`DynamoDb::list_global_tables`].
+    pub fn list_global_tables(
+        &self,
+        input: ListGlobalTablesInput,
+    ) -> Result<ListGlobalTablesOutput, RusotoError<ListGlobalTablesError>> {
+        self.runtime.block_on(self.client.list_global_tables(input))
+    }
+
+    /// Blocking version of [`DynamoDb::list_tables`].
+    pub fn list_tables(
+        &self,
+        input: ListTablesInput,
+    ) -> Result<ListTablesOutput, RusotoError<ListTablesError>> {
+        self.runtime.block_on(self.client.list_tables(input))
+    }
+
+    /// Blocking version of [`DynamoDb::list_tags_of_resource`].
+    pub fn list_tags_of_resource(
+        &self,
+        input: ListTagsOfResourceInput,
+    ) -> Result<ListTagsOfResourceOutput, RusotoError<ListTagsOfResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_of_resource(input))
+    }
+
+    /// Blocking version of [`DynamoDb::put_item`].
+    pub fn put_item(
+        &self,
+        input: PutItemInput,
+    ) -> Result<PutItemOutput, RusotoError<PutItemError>> {
+        self.runtime.block_on(self.client.put_item(input))
+    }
+
+    /// Blocking version of [`DynamoDb::query`].
+    pub fn query(&self, input: QueryInput) -> Result<QueryOutput, RusotoError<QueryError>> {
+        self.runtime.block_on(self.client.query(input))
+    }
+
+    /// Blocking version of [`DynamoDb::restore_table_from_backup`].
+    pub fn restore_table_from_backup(
+        &self,
+        input: RestoreTableFromBackupInput,
+    ) -> Result<RestoreTableFromBackupOutput, RusotoError<RestoreTableFromBackupError>> {
+        self.runtime
+            .block_on(self.client.restore_table_from_backup(input))
+    }
+
+    /// Blocking version of [`DynamoDb::restore_table_to_point_in_time`].
+    pub fn restore_table_to_point_in_time(
+        &self,
+        input: RestoreTableToPointInTimeInput,
+    ) -> Result<RestoreTableToPointInTimeOutput, RusotoError<RestoreTableToPointInTimeError>> {
+        self.runtime
+            .block_on(self.client.restore_table_to_point_in_time(input))
+    }
+
+    /// Blocking version of [`DynamoDb::scan`].
+    pub fn scan(&self, input: ScanInput) -> Result<ScanOutput, RusotoError<ScanError>> {
+        self.runtime.block_on(self.client.scan(input))
+    }
+
+    /// Blocking version of [`DynamoDb::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`DynamoDb::transact_get_items`].
+    pub fn transact_get_items(
+        &self,
+        input: TransactGetItemsInput,
+    ) -> Result<TransactGetItemsOutput, RusotoError<TransactGetItemsError>> {
+        self.runtime.block_on(self.client.transact_get_items(input))
+    }
+
+    /// Blocking version of [`DynamoDb::transact_write_items`].
+    pub fn transact_write_items(
+        &self,
+        input: TransactWriteItemsInput,
+    ) -> Result<TransactWriteItemsOutput, RusotoError<TransactWriteItemsError>> {
+        self.runtime
+            .block_on(self.client.transact_write_items(input))
+    }
+
+    /// Blocking version of [`DynamoDb::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`DynamoDb::update_continuous_backups`].
+    pub fn update_continuous_backups(
+        &self,
+        input: UpdateContinuousBackupsInput,
+    ) -> Result<UpdateContinuousBackupsOutput, RusotoError<UpdateContinuousBackupsError>> {
+        self.runtime
+            .block_on(self.client.update_continuous_backups(input))
+    }
+
+    /// Blocking version of [`DynamoDb::update_contributor_insights`].
+    pub fn update_contributor_insights(
+        &self,
+        input: UpdateContributorInsightsInput,
+    ) -> Result<UpdateContributorInsightsOutput, RusotoError<UpdateContributorInsightsError>> {
+        self.runtime
+            .block_on(self.client.update_contributor_insights(input))
+    }
+
+    /// Blocking version of [`DynamoDb::update_global_table`].
+    pub fn update_global_table(
+        &self,
+        input: UpdateGlobalTableInput,
+    ) -> Result<UpdateGlobalTableOutput, RusotoError<UpdateGlobalTableError>> {
+        self.runtime
+            .block_on(self.client.update_global_table(input))
+    }
+
+    /// Blocking version of [`DynamoDb::update_global_table_settings`].
+    pub fn update_global_table_settings(
+        &self,
+        input: UpdateGlobalTableSettingsInput,
+    ) -> Result<UpdateGlobalTableSettingsOutput, RusotoError<UpdateGlobalTableSettingsError>> {
+        self.runtime
+            .block_on(self.client.update_global_table_settings(input))
+    }
+
+    /// Blocking version of [`DynamoDb::update_item`].
+    pub fn update_item(
+        &self,
+        input: UpdateItemInput,
+    ) -> Result<UpdateItemOutput, RusotoError<UpdateItemError>> {
+        self.runtime.block_on(self.client.update_item(input))
+    }
+
+    /// Blocking version of [`DynamoDb::update_table`].
+    pub fn update_table(
+        &self,
+        input: UpdateTableInput,
+    ) -> Result<UpdateTableOutput, RusotoError<UpdateTableError>> {
+        self.runtime.block_on(self.client.update_table(input))
+    }
+
+    /// Blocking version of [`DynamoDb::update_table_replica_auto_scaling`].
+    pub fn update_table_replica_auto_scaling(
+        &self,
+        input: UpdateTableReplicaAutoScalingInput,
+    ) -> Result<UpdateTableReplicaAutoScalingOutput, RusotoError<UpdateTableReplicaAutoScalingError>>
+    {
+        self.runtime
+            .block_on(self.client.update_table_replica_auto_scaling(input))
+    }
+
+    /// Blocking version of [`DynamoDb::update_time_to_live`].
+    pub fn update_time_to_live(
+        &self,
+        input: UpdateTimeToLiveInput,
+    ) -> Result<UpdateTimeToLiveOutput, RusotoError<UpdateTimeToLiveError>> {
+        self.runtime
+            .block_on(self.client.update_time_to_live(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -422,12 +423,22 @@ impl DescribeStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeStreamError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeStreamError::InternalServerError,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeStreamError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -464,21 +475,46 @@ impl GetRecordsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExpiredIteratorException" => {
-                    return RusotoError::Service(GetRecordsError::ExpiredIterator(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRecordsError::ExpiredIterator,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetRecordsError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRecordsError::InternalServerError,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetRecordsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRecordsError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetRecordsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRecordsError::ResourceNotFound,
+                    )
                 }
                 "TrimmedDataAccessException" => {
-                    return RusotoError::Service(GetRecordsError::TrimmedDataAccess(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRecordsError::TrimmedDataAccess,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -514,17 +550,30 @@ impl GetShardIteratorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetShardIteratorError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetShardIteratorError::InternalServerError,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetShardIteratorError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetShardIteratorError::ResourceNotFound,
+                    )
                 }
                 "TrimmedDataAccessException" => {
-                    return RusotoError::Service(GetShardIteratorError::TrimmedDataAccess(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetShardIteratorError::TrimmedDataAccess,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -556,12 +605,22 @@ impl ListStreamsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListStreamsError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListStreamsError::InternalServerError,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListStreamsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListStreamsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -615,11 +674,12 @@ pub struct DynamoDbStreamsClient {
 impl DynamoDbStreamsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> DynamoDbStreamsClient {
         DynamoDbStreamsClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "DynamoDB Streams"),
         }
     }
 
@@ -634,12 +694,32 @@ impl DynamoDbStreamsClient {
     {
         DynamoDbStreamsClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "DynamoDB Streams"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> DynamoDbStreamsClient {
-        DynamoDbStreamsClient { client, region }
+        DynamoDbStreamsClient {
+            client,
+            region: region_for_service(region, "DynamoDB Streams"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> DynamoDbStreamsClient {
+        DynamoDbStreamsClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("DynamoDB Streams"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> DynamoDbStreamsClient {
+        DynamoDbStreamsClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -651,6 +731,7 @@ impl DynamoDbStreams for DynamoDbStreamsClient {
         input: DescribeStreamInput,
     ) -> Result<DescribeStreamOutput, RusotoError<DescribeStreamError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeStream");
         request.add_header("x-amz-target", "DynamoDBStreams_20120810.DescribeStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -669,6 +750,7 @@ impl DynamoDbStreams for DynamoDbStreamsClient {
         input: GetRecordsInput,
     ) -> Result<GetRecordsOutput, RusotoError<GetRecordsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRecords");
         request.add_header("x-amz-target", "DynamoDBStreams_20120810.GetRecords");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -687,6 +769,7 @@ impl DynamoDbStreams for DynamoDbStreamsClient {
         input: GetShardIteratorInput,
     ) -> Result<GetShardIteratorOutput, RusotoError<GetShardIteratorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetShardIterator");
         request.add_header("x-amz-target", "DynamoDBStreams_20120810.GetShardIterator");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -705,6 +788,7 @@ impl DynamoDbStreams for DynamoDbStreamsClient {
         input: ListStreamsInput,
     ) -> Result<ListStreamsOutput, RusotoError<ListStreamsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListStreams");
         request.add_header("x-amz-target", "DynamoDBStreams_20120810.ListStreams");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -717,3 +801,118 @@ impl DynamoDbStreams for DynamoDbStreamsClient {
         proto::json::ResponsePayload::new(&response).deserialize::<ListStreamsOutput, _>()
     }
 }
+
+/// A blocking client for the Amazon DynamoDB Streams API, running the calls of a `DynamoDbStreamsClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `DynamoDbStreamsClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct DynamoDbStreamsBlockingClient {
+    client: DynamoDbStreamsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl DynamoDbStreamsBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> DynamoDbStreamsBlockingClient {
+        DynamoDbStreamsBlockingClient::from_client(DynamoDbStreamsClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> DynamoDbStreamsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        DynamoDbStreamsBlockingClient::from_client(DynamoDbStreamsClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> DynamoDbStreamsBlockingClient {
+        DynamoDbStreamsBlockingClient::from_client(DynamoDbStreamsClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> DynamoDbStreamsBlockingClient {
+        DynamoDbStreamsBlockingClient::from_client(DynamoDbStreamsClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: DynamoDbStreamsClient) -> DynamoDbStreamsBlockingClient {
+        DynamoDbStreamsBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> DynamoDbStreamsBlockingClient {
+        DynamoDbStreamsBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> DynamoDbStreamsBlockingClient {
+        DynamoDbStreamsBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`DynamoDbStreams::describe_stream`].
+    pub fn describe_stream(
+        &self,
+        input: DescribeStreamInput,
+    ) -> Result<DescribeStreamOutput, RusotoError<DescribeStreamError>> {
+        self.runtime.block_on(self.client.describe_stream(input))
+    }
+
+    /// Blocking version of [`DynamoDbStreams::get_records`].
+    pub fn get_records(
+        &self,
+        input: GetRecordsInput,
+    ) -> Result<GetRecordsOutput, RusotoError<GetRecordsError>> {
+        self.runtime.block_on(self.client.get_records(input))
+    }
+
+    /// Blocking version of [`DynamoDbStreams::get_shard_iterator`].
+    pub fn get_shard_iterator(
+        &self,
+        input: GetShardIteratorInput,
+    ) -> Result<GetShardIteratorOutput, RusotoError<GetShardIteratorError>> {
+        self.runtime.block_on(self.client.get_shard_iterator(input))
+    }
+
+    /// Blocking version of [`DynamoDbStreams::list_streams`].
+    pub fn list_streams(
+        &self,
+        input: ListStreamsInput,
+    ) -> Result<ListStreamsOutput, RusotoError<ListStreamsError>> {
+        self.runtime.block_on(self.client.list_streams(input))
+    }
+}
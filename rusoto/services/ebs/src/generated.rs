@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -368,23 +369,46 @@ impl CompleteSnapshotError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CompleteSnapshotError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CompleteSnapshotError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CompleteSnapshotError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CompleteSnapshotError::InternalServer,
+                    )
                 }
                 "RequestThrottledException" => {
-                    return RusotoError::Service(CompleteSnapshotError::RequestThrottled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CompleteSnapshotError::RequestThrottled,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CompleteSnapshotError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CompleteSnapshotError::ResourceNotFound,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CompleteSnapshotError::ServiceQuotaExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CompleteSnapshotError::ServiceQuotaExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -424,23 +448,46 @@ impl GetSnapshotBlockError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetSnapshotBlockError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSnapshotBlockError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetSnapshotBlockError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSnapshotBlockError::InternalServer,
+                    )
                 }
                 "RequestThrottledException" => {
-                    return RusotoError::Service(GetSnapshotBlockError::RequestThrottled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSnapshotBlockError::RequestThrottled,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetSnapshotBlockError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSnapshotBlockError::ResourceNotFound,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(GetSnapshotBlockError::ServiceQuotaExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSnapshotBlockError::ServiceQuotaExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -480,23 +527,46 @@ impl ListChangedBlocksError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListChangedBlocksError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChangedBlocksError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListChangedBlocksError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChangedBlocksError::InternalServer,
+                    )
                 }
                 "RequestThrottledException" => {
-                    return RusotoError::Service(ListChangedBlocksError::RequestThrottled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChangedBlocksError::RequestThrottled,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListChangedBlocksError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChangedBlocksError::ResourceNotFound,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(ListChangedBlocksError::ServiceQuotaExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListChangedBlocksError::ServiceQuotaExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -536,23 +606,46 @@ impl ListSnapshotBlocksError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListSnapshotBlocksError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSnapshotBlocksError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListSnapshotBlocksError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSnapshotBlocksError::InternalServer,
+                    )
                 }
                 "RequestThrottledException" => {
-                    return RusotoError::Service(ListSnapshotBlocksError::RequestThrottled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSnapshotBlocksError::RequestThrottled,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListSnapshotBlocksError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSnapshotBlocksError::ResourceNotFound,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(ListSnapshotBlocksError::ServiceQuotaExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSnapshotBlocksError::ServiceQuotaExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -592,23 +685,46 @@ impl PutSnapshotBlockError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(PutSnapshotBlockError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSnapshotBlockError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(PutSnapshotBlockError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSnapshotBlockError::InternalServer,
+                    )
                 }
                 "RequestThrottledException" => {
-                    return RusotoError::Service(PutSnapshotBlockError::RequestThrottled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSnapshotBlockError::RequestThrottled,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutSnapshotBlockError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSnapshotBlockError::ResourceNotFound,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(PutSnapshotBlockError::ServiceQuotaExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutSnapshotBlockError::ServiceQuotaExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -652,29 +768,62 @@ impl StartSnapshotError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartSnapshotError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartSnapshotError::AccessDenied,
+                    )
                 }
                 "ConcurrentLimitExceededException" => {
-                    return RusotoError::Service(StartSnapshotError::ConcurrentLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartSnapshotError::ConcurrentLimitExceeded,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(StartSnapshotError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartSnapshotError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(StartSnapshotError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartSnapshotError::InternalServer,
+                    )
                 }
                 "RequestThrottledException" => {
-                    return RusotoError::Service(StartSnapshotError::RequestThrottled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartSnapshotError::RequestThrottled,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartSnapshotError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartSnapshotError::ResourceNotFound,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(StartSnapshotError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartSnapshotError::ServiceQuotaExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -745,11 +894,12 @@ pub struct EbsClient {
 impl EbsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> EbsClient {
         EbsClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "EBS"),
         }
     }
 
@@ -764,12 +914,32 @@ impl EbsClient {
     {
         EbsClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "EBS"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> EbsClient {
-        EbsClient { client, region }
+        EbsClient {
+            client,
+            region: region_for_service(region, "EBS"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> EbsClient {
+        EbsClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("EBS"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> EbsClient {
+        EbsClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -787,6 +957,7 @@ impl Ebs for EbsClient {
         );
 
         let mut request = SignedRequest::new("POST", "ebs", &self.region, &request_uri);
+        request.set_operation("CompleteSnapshot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_header(
@@ -833,6 +1004,8 @@ impl Ebs for EbsClient {
         );
 
         let mut request = SignedRequest::new("GET", "ebs", &self.region, &request_uri);
+        request.set_operation("GetSnapshotBlock");
+        request.set_raw_response(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -876,6 +1049,7 @@ impl Ebs for EbsClient {
         );
 
         let mut request = SignedRequest::new("GET", "ebs", &self.region, &request_uri);
+        request.set_operation("ListChangedBlocks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -922,6 +1096,7 @@ impl Ebs for EbsClient {
         );
 
         let mut request = SignedRequest::new("GET", "ebs", &self.region, &request_uri);
+        request.set_operation("ListSnapshotBlocks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -966,6 +1141,7 @@ impl Ebs for EbsClient {
         );
 
         let mut request = SignedRequest::new("PUT", "ebs", &self.region, &request_uri);
+        request.set_operation("PutSnapshotBlock");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.block_data.to_owned());
@@ -1006,6 +1182,7 @@ impl Ebs for EbsClient {
         let request_uri = "/snapshots";
 
         let mut request = SignedRequest::new("POST", "ebs", &self.region, &request_uri);
+        request.set_operation("StartSnapshot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1028,3 +1205,128 @@ impl Ebs for EbsClient {
         }
     }
 }
+
+/// A blocking client for the Amazon EBS API, running the calls of a `EbsClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `EbsClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct EbsBlockingClient {
+    client: EbsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl EbsBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> EbsBlockingClient {
+        EbsBlockingClient::from_client(EbsClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> EbsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        EbsBlockingClient::from_client(EbsClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> EbsBlockingClient {
+        EbsBlockingClient::from_client(EbsClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> EbsBlockingClient {
+        EbsBlockingClient::from_client(EbsClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: EbsClient) -> EbsBlockingClient {
+        EbsBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> EbsBlockingClient {
+        EbsBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> EbsBlockingClient {
+        EbsBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Ebs::complete_snapshot`].
+    pub fn complete_snapshot(
+        &self,
+        input: CompleteSnapshotRequest,
+    ) -> Result<CompleteSnapshotResponse, RusotoError<CompleteSnapshotError>> {
+        self.runtime.block_on(self.client.complete_snapshot(input))
+    }
+
+    /// Blocking version of [`Ebs::get_snapshot_block`].
+    pub fn get_snapshot_block(
+        &self,
+        input: GetSnapshotBlockRequest,
+    ) -> Result<GetSnapshotBlockResponse, RusotoError<GetSnapshotBlockError>> {
+        self.runtime.block_on(self.client.get_snapshot_block(input))
+    }
+
+    /// Blocking version of [`Ebs::list_changed_blocks`].
+    pub fn list_changed_blocks(
+        &self,
+        input: ListChangedBlocksRequest,
+    ) -> Result<ListChangedBlocksResponse, RusotoError<ListChangedBlocksError>> {
+        self.runtime
+            .block_on(self.client.list_changed_blocks(input))
+    }
+
+    /// Blocking version of [`Ebs::list_snapshot_blocks`].
+    pub fn list_snapshot_blocks(
+        &self,
+        input: ListSnapshotBlocksRequest,
+    ) -> Result<ListSnapshotBlocksResponse, RusotoError<ListSnapshotBlocksError>> {
+        self.runtime
+            .block_on(self.client.list_snapshot_blocks(input))
+    }
+
+    /// Blocking version of [`Ebs::put_snapshot_block`].
+    pub fn put_snapshot_block(
+        &self,
+        input: PutSnapshotBlockRequest,
+    ) -> Result<PutSnapshotBlockResponse, RusotoError<PutSnapshotBlockError>> {
+        self.runtime.block_on(self.client.put_snapshot_block(input))
+    }
+
+    /// Blocking version of [`Ebs::start_snapshot`].
+    pub fn start_snapshot(
+        &self,
+        input: StartSnapshotRequest,
+    ) -> Result<StartSnapshotResponse, RusotoError<StartSnapshotError>> {
+        self.runtime.block_on(self.client.start_snapshot(input))
+    }
+}
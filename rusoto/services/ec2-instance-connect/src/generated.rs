@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -105,23 +106,46 @@ impl SendSSHPublicKeyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AuthException" => {
-                    return RusotoError::Service(SendSSHPublicKeyError::Auth(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendSSHPublicKeyError::Auth,
+                    )
                 }
                 "EC2InstanceNotFoundException" => {
-                    return RusotoError::Service(SendSSHPublicKeyError::EC2InstanceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SendSSHPublicKeyError::EC2InstanceNotFound,
+                    )
                 }
                 "InvalidArgsException" => {
-                    return RusotoError::Service(SendSSHPublicKeyError::InvalidArgs(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendSSHPublicKeyError::InvalidArgs,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(SendSSHPublicKeyError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendSSHPublicKeyError::Service,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SendSSHPublicKeyError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendSSHPublicKeyError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -160,11 +184,12 @@ pub struct Ec2InstanceConnectClient {
 impl Ec2InstanceConnectClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> Ec2InstanceConnectClient {
         Ec2InstanceConnectClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "EC2 Instance Connect"),
         }
     }
 
@@ -179,12 +204,32 @@ impl Ec2InstanceConnectClient {
     {
         Ec2InstanceConnectClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "EC2 Instance Connect"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> Ec2InstanceConnectClient {
-        Ec2InstanceConnectClient { client, region }
+        Ec2InstanceConnectClient {
+            client,
+            region: region_for_service(region, "EC2 Instance Connect"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> Ec2InstanceConnectClient {
+        Ec2InstanceConnectClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("EC2 Instance Connect"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> Ec2InstanceConnectClient {
+        Ec2InstanceConnectClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -196,6 +241,7 @@ impl Ec2InstanceConnect for Ec2InstanceConnectClient {
         input: SendSSHPublicKeyRequest,
     ) -> Result<SendSSHPublicKeyResponse, RusotoError<SendSSHPublicKeyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SendSSHPublicKey");
         request.add_header(
             "x-amz-target",
             "AWSEC2InstanceConnectService.SendSSHPublicKey",
@@ -211,3 +257,97 @@ impl Ec2InstanceConnect for Ec2InstanceConnectClient {
         proto::json::ResponsePayload::new(&response).deserialize::<SendSSHPublicKeyResponse, _>()
     }
 }
+
+/// A blocking client for the EC2 Instance Connect API, running the calls of a `Ec2InstanceConnectClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `Ec2InstanceConnectClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct Ec2InstanceConnectBlockingClient {
+    client: Ec2InstanceConnectClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl Ec2InstanceConnectBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> Ec2InstanceConnectBlockingClient {
+        Ec2InstanceConnectBlockingClient::from_client(Ec2InstanceConnectClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> Ec2InstanceConnectBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        Ec2InstanceConnectBlockingClient::from_client(Ec2InstanceConnectClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> Ec2InstanceConnectBlockingClient {
+        Ec2InstanceConnectBlockingClient::from_client(Ec2InstanceConnectClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> Ec2InstanceConnectBlockingClient {
+        Ec2InstanceConnectBlockingClient::from_client(Ec2InstanceConnectClient::new_with_config(
+            config,
+        ))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: Ec2InstanceConnectClient) -> Ec2InstanceConnectBlockingClient {
+        Ec2InstanceConnectBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> Ec2InstanceConnectBlockingClient {
+        Ec2InstanceConnectBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> Ec2InstanceConnectBlockingClient {
+        Ec2InstanceConnectBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Ec2InstanceConnect::send_ssh_public_key`].
+    pub fn send_ssh_public_key(
+        &self,
+        input: SendSSHPublicKeyRequest,
+    ) -> Result<SendSSHPublicKeyResponse, RusotoError<SendSSHPublicKeyError>> {
+        self.runtime
+            .block_on(self.client.send_ssh_public_key(input))
+    }
+}
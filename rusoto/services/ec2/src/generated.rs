@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -91236,11 +91237,12 @@ pub struct Ec2Client {
 impl Ec2Client {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> Ec2Client {
         Ec2Client {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "EC2"),
         }
     }
 
@@ -91255,12 +91257,32 @@ impl Ec2Client {
     {
         Ec2Client {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "EC2"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> Ec2Client {
-        Ec2Client { client, region }
+        Ec2Client {
+            client,
+            region: region_for_service(region, "EC2"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> Ec2Client {
+        Ec2Client {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("EC2"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> Ec2Client {
+        Ec2Client {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -91275,6 +91297,7 @@ impl Ec2 for Ec2Client {
         RusotoError<AcceptReservedInstancesExchangeQuoteError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("AcceptReservedInstancesExchangeQuote");
         let params = self.new_params("AcceptReservedInstancesExchangeQuote");
         let mut params = params;
         AcceptReservedInstancesExchangeQuoteRequestSerializer::serialize(&mut params, "", &input);
@@ -91310,6 +91333,7 @@ impl Ec2 for Ec2Client {
         RusotoError<AcceptTransitGatewayMulticastDomainAssociationsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("AcceptTransitGatewayMulticastDomainAssociations");
         let params = self.new_params("AcceptTransitGatewayMulticastDomainAssociations");
         let mut params = params;
         AcceptTransitGatewayMulticastDomainAssociationsRequestSerializer::serialize(
@@ -91349,6 +91373,7 @@ impl Ec2 for Ec2Client {
         RusotoError<AcceptTransitGatewayPeeringAttachmentError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("AcceptTransitGatewayPeeringAttachment");
         let params = self.new_params("AcceptTransitGatewayPeeringAttachment");
         let mut params = params;
         AcceptTransitGatewayPeeringAttachmentRequestSerializer::serialize(&mut params, "", &input);
@@ -91384,6 +91409,7 @@ impl Ec2 for Ec2Client {
         RusotoError<AcceptTransitGatewayVpcAttachmentError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("AcceptTransitGatewayVpcAttachment");
         let params = self.new_params("AcceptTransitGatewayVpcAttachment");
         let mut params = params;
         AcceptTransitGatewayVpcAttachmentRequestSerializer::serialize(&mut params, "", &input);
@@ -91414,6 +91440,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<AcceptVpcEndpointConnectionsResult, RusotoError<AcceptVpcEndpointConnectionsError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("AcceptVpcEndpointConnections");
         let params = self.new_params("AcceptVpcEndpointConnections");
         let mut params = params;
         AcceptVpcEndpointConnectionsRequestSerializer::serialize(&mut params, "", &input);
@@ -91441,6 +91468,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<AcceptVpcPeeringConnectionResult, RusotoError<AcceptVpcPeeringConnectionError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("AcceptVpcPeeringConnection");
         let params = self.new_params("AcceptVpcPeeringConnection");
         let mut params = params;
         AcceptVpcPeeringConnectionRequestSerializer::serialize(&mut params, "", &input);
@@ -91467,6 +91495,7 @@ impl Ec2 for Ec2Client {
         input: AdvertiseByoipCidrRequest,
     ) -> Result<AdvertiseByoipCidrResult, RusotoError<AdvertiseByoipCidrError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("AdvertiseByoipCidr");
         let params = self.new_params("AdvertiseByoipCidr");
         let mut params = params;
         AdvertiseByoipCidrRequestSerializer::serialize(&mut params, "", &input);
@@ -91493,6 +91522,7 @@ impl Ec2 for Ec2Client {
         input: AllocateAddressRequest,
     ) -> Result<AllocateAddressResult, RusotoError<AllocateAddressError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("AllocateAddress");
         let params = self.new_params("AllocateAddress");
         let mut params = params;
         AllocateAddressRequestSerializer::serialize(&mut params, "", &input);
@@ -91519,6 +91549,7 @@ impl Ec2 for Ec2Client {
         input: AllocateHostsRequest,
     ) -> Result<AllocateHostsResult, RusotoError<AllocateHostsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("AllocateHosts");
         let params = self.new_params("AllocateHosts");
         let mut params = params;
         AllocateHostsRequestSerializer::serialize(&mut params, "", &input);
@@ -91548,6 +91579,7 @@ impl Ec2 for Ec2Client {
         RusotoError<ApplySecurityGroupsToClientVpnTargetNetworkError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ApplySecurityGroupsToClientVpnTargetNetwork");
         let params = self.new_params("ApplySecurityGroupsToClientVpnTargetNetwork");
         let mut params = params;
         ApplySecurityGroupsToClientVpnTargetNetworkRequestSerializer::serialize(
@@ -91584,6 +91616,7 @@ impl Ec2 for Ec2Client {
         input: AssignIpv6AddressesRequest,
     ) -> Result<AssignIpv6AddressesResult, RusotoError<AssignIpv6AddressesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("AssignIpv6Addresses");
         let params = self.new_params("AssignIpv6Addresses");
         let mut params = params;
         AssignIpv6AddressesRequestSerializer::serialize(&mut params, "", &input);
@@ -91610,6 +91643,7 @@ impl Ec2 for Ec2Client {
         input: AssignPrivateIpAddressesRequest,
     ) -> Result<AssignPrivateIpAddressesResult, RusotoError<AssignPrivateIpAddressesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("AssignPrivateIpAddresses");
         let params = self.new_params("AssignPrivateIpAddresses");
         let mut params = params;
         AssignPrivateIpAddressesRequestSerializer::serialize(&mut params, "", &input);
@@ -91636,6 +91670,7 @@ impl Ec2 for Ec2Client {
         input: AssociateAddressRequest,
     ) -> Result<AssociateAddressResult, RusotoError<AssociateAddressError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("AssociateAddress");
         let params = self.new_params("AssociateAddress");
         let mut params = params;
         AssociateAddressRequestSerializer::serialize(&mut params, "", &input);
@@ -91665,6 +91700,7 @@ impl Ec2 for Ec2Client {
         RusotoError<AssociateClientVpnTargetNetworkError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("AssociateClientVpnTargetNetwork");
         let params = self.new_params("AssociateClientVpnTargetNetwork");
         let mut params = params;
         AssociateClientVpnTargetNetworkRequestSerializer::serialize(&mut params, "", &input);
@@ -91691,6 +91727,7 @@ impl Ec2 for Ec2Client {
         input: AssociateDhcpOptionsRequest,
     ) -> Result<(), RusotoError<AssociateDhcpOptionsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("AssociateDhcpOptions");
         let params = self.new_params("AssociateDhcpOptions");
         let mut params = params;
         AssociateDhcpOptionsRequestSerializer::serialize(&mut params, "", &input);
@@ -91714,6 +91751,7 @@ impl Ec2 for Ec2Client {
         RusotoError<AssociateEnclaveCertificateIamRoleError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("AssociateEnclaveCertificateIamRole");
         let params = self.new_params("AssociateEnclaveCertificateIamRole");
         let mut params = params;
         AssociateEnclaveCertificateIamRoleRequestSerializer::serialize(&mut params, "", &input);
@@ -91747,6 +91785,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<AssociateIamInstanceProfileResult, RusotoError<AssociateIamInstanceProfileError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("AssociateIamInstanceProfile");
         let params = self.new_params("AssociateIamInstanceProfile");
         let mut params = params;
         AssociateIamInstanceProfileRequestSerializer::serialize(&mut params, "", &input);
@@ -91773,6 +91812,7 @@ impl Ec2 for Ec2Client {
         input: AssociateRouteTableRequest,
     ) -> Result<AssociateRouteTableResult, RusotoError<AssociateRouteTableError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("AssociateRouteTable");
         let params = self.new_params("AssociateRouteTable");
         let mut params = params;
         AssociateRouteTableRequestSerializer::serialize(&mut params, "", &input);
@@ -91799,6 +91839,7 @@ impl Ec2 for Ec2Client {
         input: AssociateSubnetCidrBlockRequest,
     ) -> Result<AssociateSubnetCidrBlockResult, RusotoError<AssociateSubnetCidrBlockError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("AssociateSubnetCidrBlock");
         let params = self.new_params("AssociateSubnetCidrBlock");
         let mut params = params;
         AssociateSubnetCidrBlockRequestSerializer::serialize(&mut params, "", &input);
@@ -91828,6 +91869,7 @@ impl Ec2 for Ec2Client {
         RusotoError<AssociateTransitGatewayMulticastDomainError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("AssociateTransitGatewayMulticastDomain");
         let params = self.new_params("AssociateTransitGatewayMulticastDomain");
         let mut params = params;
         AssociateTransitGatewayMulticastDomainRequestSerializer::serialize(&mut params, "", &input);
@@ -91863,6 +91905,7 @@ impl Ec2 for Ec2Client {
         RusotoError<AssociateTransitGatewayRouteTableError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("AssociateTransitGatewayRouteTable");
         let params = self.new_params("AssociateTransitGatewayRouteTable");
         let mut params = params;
         AssociateTransitGatewayRouteTableRequestSerializer::serialize(&mut params, "", &input);
@@ -91892,6 +91935,7 @@ impl Ec2 for Ec2Client {
         input: AssociateVpcCidrBlockRequest,
     ) -> Result<AssociateVpcCidrBlockResult, RusotoError<AssociateVpcCidrBlockError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("AssociateVpcCidrBlock");
         let params = self.new_params("AssociateVpcCidrBlock");
         let mut params = params;
         AssociateVpcCidrBlockRequestSerializer::serialize(&mut params, "", &input);
@@ -91918,6 +91962,7 @@ impl Ec2 for Ec2Client {
         input: AttachClassicLinkVpcRequest,
     ) -> Result<AttachClassicLinkVpcResult, RusotoError<AttachClassicLinkVpcError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("AttachClassicLinkVpc");
         let params = self.new_params("AttachClassicLinkVpc");
         let mut params = params;
         AttachClassicLinkVpcRequestSerializer::serialize(&mut params, "", &input);
@@ -91944,6 +91989,7 @@ impl Ec2 for Ec2Client {
         input: AttachInternetGatewayRequest,
     ) -> Result<(), RusotoError<AttachInternetGatewayError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("AttachInternetGateway");
         let params = self.new_params("AttachInternetGateway");
         let mut params = params;
         AttachInternetGatewayRequestSerializer::serialize(&mut params, "", &input);
@@ -91964,6 +92010,7 @@ impl Ec2 for Ec2Client {
         input: AttachNetworkInterfaceRequest,
     ) -> Result<AttachNetworkInterfaceResult, RusotoError<AttachNetworkInterfaceError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("AttachNetworkInterface");
         let params = self.new_params("AttachNetworkInterface");
         let mut params = params;
         AttachNetworkInterfaceRequestSerializer::serialize(&mut params, "", &input);
@@ -91990,6 +92037,7 @@ impl Ec2 for Ec2Client {
         input: AttachVolumeRequest,
     ) -> Result<VolumeAttachment, RusotoError<AttachVolumeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("AttachVolume");
         let params = self.new_params("AttachVolume");
         let mut params = params;
         AttachVolumeRequestSerializer::serialize(&mut params, "", &input);
@@ -92016,6 +92064,7 @@ impl Ec2 for Ec2Client {
         input: AttachVpnGatewayRequest,
     ) -> Result<AttachVpnGatewayResult, RusotoError<AttachVpnGatewayError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("AttachVpnGateway");
         let params = self.new_params("AttachVpnGateway");
         let mut params = params;
         AttachVpnGatewayRequestSerializer::serialize(&mut params, "", &input);
@@ -92042,6 +92091,7 @@ impl Ec2 for Ec2Client {
         input: AuthorizeClientVpnIngressRequest,
     ) -> Result<AuthorizeClientVpnIngressResult, RusotoError<AuthorizeClientVpnIngressError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("AuthorizeClientVpnIngress");
         let params = self.new_params("AuthorizeClientVpnIngress");
         let mut params = params;
         AuthorizeClientVpnIngressRequestSerializer::serialize(&mut params, "", &input);
@@ -92068,6 +92118,7 @@ impl Ec2 for Ec2Client {
         input: AuthorizeSecurityGroupEgressRequest,
     ) -> Result<(), RusotoError<AuthorizeSecurityGroupEgressError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("AuthorizeSecurityGroupEgress");
         let params = self.new_params("AuthorizeSecurityGroupEgress");
         let mut params = params;
         AuthorizeSecurityGroupEgressRequestSerializer::serialize(&mut params, "", &input);
@@ -92088,6 +92139,7 @@ impl Ec2 for Ec2Client {
         input: AuthorizeSecurityGroupIngressRequest,
     ) -> Result<(), RusotoError<AuthorizeSecurityGroupIngressError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("AuthorizeSecurityGroupIngress");
         let params = self.new_params("AuthorizeSecurityGroupIngress");
         let mut params = params;
         AuthorizeSecurityGroupIngressRequestSerializer::serialize(&mut params, "", &input);
@@ -92108,6 +92160,7 @@ impl Ec2 for Ec2Client {
         input: BundleInstanceRequest,
     ) -> Result<BundleInstanceResult, RusotoError<BundleInstanceError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("BundleInstance");
         let params = self.new_params("BundleInstance");
         let mut params = params;
         BundleInstanceRequestSerializer::serialize(&mut params, "", &input);
@@ -92134,6 +92187,7 @@ impl Ec2 for Ec2Client {
         input: CancelBundleTaskRequest,
     ) -> Result<CancelBundleTaskResult, RusotoError<CancelBundleTaskError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CancelBundleTask");
         let params = self.new_params("CancelBundleTask");
         let mut params = params;
         CancelBundleTaskRequestSerializer::serialize(&mut params, "", &input);
@@ -92160,6 +92214,7 @@ impl Ec2 for Ec2Client {
         input: CancelCapacityReservationRequest,
     ) -> Result<CancelCapacityReservationResult, RusotoError<CancelCapacityReservationError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CancelCapacityReservation");
         let params = self.new_params("CancelCapacityReservation");
         let mut params = params;
         CancelCapacityReservationRequestSerializer::serialize(&mut params, "", &input);
@@ -92186,6 +92241,7 @@ impl Ec2 for Ec2Client {
         input: CancelConversionRequest,
     ) -> Result<(), RusotoError<CancelConversionTaskError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CancelConversionTask");
         let params = self.new_params("CancelConversionTask");
         let mut params = params;
         CancelConversionRequestSerializer::serialize(&mut params, "", &input);
@@ -92206,6 +92262,7 @@ impl Ec2 for Ec2Client {
         input: CancelExportTaskRequest,
     ) -> Result<(), RusotoError<CancelExportTaskError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CancelExportTask");
         let params = self.new_params("CancelExportTask");
         let mut params = params;
         CancelExportTaskRequestSerializer::serialize(&mut params, "", &input);
@@ -92226,6 +92283,7 @@ impl Ec2 for Ec2Client {
         input: CancelImportTaskRequest,
     ) -> Result<CancelImportTaskResult, RusotoError<CancelImportTaskError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CancelImportTask");
         let params = self.new_params("CancelImportTask");
         let mut params = params;
         CancelImportTaskRequestSerializer::serialize(&mut params, "", &input);
@@ -92255,6 +92313,7 @@ impl Ec2 for Ec2Client {
         RusotoError<CancelReservedInstancesListingError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CancelReservedInstancesListing");
         let params = self.new_params("CancelReservedInstancesListing");
         let mut params = params;
         CancelReservedInstancesListingRequestSerializer::serialize(&mut params, "", &input);
@@ -92281,6 +92340,7 @@ impl Ec2 for Ec2Client {
         input: CancelSpotFleetRequestsRequest,
     ) -> Result<CancelSpotFleetRequestsResponse, RusotoError<EC2CancelSpotFleetRequestsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CancelSpotFleetRequests");
         let params = self.new_params("CancelSpotFleetRequests");
         let mut params = params;
         CancelSpotFleetRequestsRequestSerializer::serialize(&mut params, "", &input);
@@ -92308,6 +92368,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<CancelSpotInstanceRequestsResult, RusotoError<CancelSpotInstanceRequestsError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CancelSpotInstanceRequests");
         let params = self.new_params("CancelSpotInstanceRequests");
         let mut params = params;
         CancelSpotInstanceRequestsRequestSerializer::serialize(&mut params, "", &input);
@@ -92334,6 +92395,7 @@ impl Ec2 for Ec2Client {
         input: ConfirmProductInstanceRequest,
     ) -> Result<ConfirmProductInstanceResult, RusotoError<ConfirmProductInstanceError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ConfirmProductInstance");
         let params = self.new_params("ConfirmProductInstance");
         let mut params = params;
         ConfirmProductInstanceRequestSerializer::serialize(&mut params, "", &input);
@@ -92360,6 +92422,7 @@ impl Ec2 for Ec2Client {
         input: CopyFpgaImageRequest,
     ) -> Result<CopyFpgaImageResult, RusotoError<CopyFpgaImageError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CopyFpgaImage");
         let params = self.new_params("CopyFpgaImage");
         let mut params = params;
         CopyFpgaImageRequestSerializer::serialize(&mut params, "", &input);
@@ -92386,6 +92449,7 @@ impl Ec2 for Ec2Client {
         input: CopyImageRequest,
     ) -> Result<CopyImageResult, RusotoError<CopyImageError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CopyImage");
         let params = self.new_params("CopyImage");
         let mut params = params;
         CopyImageRequestSerializer::serialize(&mut params, "", &input);
@@ -92412,6 +92476,7 @@ impl Ec2 for Ec2Client {
         input: CopySnapshotRequest,
     ) -> Result<CopySnapshotResult, RusotoError<CopySnapshotError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CopySnapshot");
         let params = self.new_params("CopySnapshot");
         let mut params = params;
         CopySnapshotRequestSerializer::serialize(&mut params, "", &input);
@@ -92438,6 +92503,7 @@ impl Ec2 for Ec2Client {
         input: CreateCapacityReservationRequest,
     ) -> Result<CreateCapacityReservationResult, RusotoError<CreateCapacityReservationError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateCapacityReservation");
         let params = self.new_params("CreateCapacityReservation");
         let mut params = params;
         CreateCapacityReservationRequestSerializer::serialize(&mut params, "", &input);
@@ -92464,6 +92530,7 @@ impl Ec2 for Ec2Client {
         input: CreateCarrierGatewayRequest,
     ) -> Result<CreateCarrierGatewayResult, RusotoError<CreateCarrierGatewayError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateCarrierGateway");
         let params = self.new_params("CreateCarrierGateway");
         let mut params = params;
         CreateCarrierGatewayRequestSerializer::serialize(&mut params, "", &input);
@@ -92490,6 +92557,7 @@ impl Ec2 for Ec2Client {
         input: CreateClientVpnEndpointRequest,
     ) -> Result<CreateClientVpnEndpointResult, RusotoError<CreateClientVpnEndpointError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateClientVpnEndpoint");
         let params = self.new_params("CreateClientVpnEndpoint");
         let mut params = params;
         CreateClientVpnEndpointRequestSerializer::serialize(&mut params, "", &input);
@@ -92516,6 +92584,7 @@ impl Ec2 for Ec2Client {
         input: CreateClientVpnRouteRequest,
     ) -> Result<CreateClientVpnRouteResult, RusotoError<CreateClientVpnRouteError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateClientVpnRoute");
         let params = self.new_params("CreateClientVpnRoute");
         let mut params = params;
         CreateClientVpnRouteRequestSerializer::serialize(&mut params, "", &input);
@@ -92542,6 +92611,7 @@ impl Ec2 for Ec2Client {
         input: CreateCustomerGatewayRequest,
     ) -> Result<CreateCustomerGatewayResult, RusotoError<CreateCustomerGatewayError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateCustomerGateway");
         let params = self.new_params("CreateCustomerGateway");
         let mut params = params;
         CreateCustomerGatewayRequestSerializer::serialize(&mut params, "", &input);
@@ -92568,6 +92638,7 @@ impl Ec2 for Ec2Client {
         input: CreateDefaultSubnetRequest,
     ) -> Result<CreateDefaultSubnetResult, RusotoError<CreateDefaultSubnetError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateDefaultSubnet");
         let params = self.new_params("CreateDefaultSubnet");
         let mut params = params;
         CreateDefaultSubnetRequestSerializer::serialize(&mut params, "", &input);
@@ -92594,6 +92665,7 @@ impl Ec2 for Ec2Client {
         input: CreateDefaultVpcRequest,
     ) -> Result<CreateDefaultVpcResult, RusotoError<CreateDefaultVpcError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateDefaultVpc");
         let params = self.new_params("CreateDefaultVpc");
         let mut params = params;
         CreateDefaultVpcRequestSerializer::serialize(&mut params, "", &input);
@@ -92620,6 +92692,7 @@ impl Ec2 for Ec2Client {
         input: CreateDhcpOptionsRequest,
     ) -> Result<CreateDhcpOptionsResult, RusotoError<CreateDhcpOptionsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateDhcpOptions");
         let params = self.new_params("CreateDhcpOptions");
         let mut params = params;
         CreateDhcpOptionsRequestSerializer::serialize(&mut params, "", &input);
@@ -92649,6 +92722,7 @@ impl Ec2 for Ec2Client {
         RusotoError<CreateEgressOnlyInternetGatewayError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateEgressOnlyInternetGateway");
         let params = self.new_params("CreateEgressOnlyInternetGateway");
         let mut params = params;
         CreateEgressOnlyInternetGatewayRequestSerializer::serialize(&mut params, "", &input);
@@ -92675,6 +92749,7 @@ impl Ec2 for Ec2Client {
         input: CreateFleetRequest,
     ) -> Result<CreateFleetResult, RusotoError<CreateFleetError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateFleet");
         let params = self.new_params("CreateFleet");
         let mut params = params;
         CreateFleetRequestSerializer::serialize(&mut params, "", &input);
@@ -92701,6 +92776,7 @@ impl Ec2 for Ec2Client {
         input: CreateFlowLogsRequest,
     ) -> Result<CreateFlowLogsResult, RusotoError<CreateFlowLogsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateFlowLogs");
         let params = self.new_params("CreateFlowLogs");
         let mut params = params;
         CreateFlowLogsRequestSerializer::serialize(&mut params, "", &input);
@@ -92727,6 +92803,7 @@ impl Ec2 for Ec2Client {
         input: CreateFpgaImageRequest,
     ) -> Result<CreateFpgaImageResult, RusotoError<CreateFpgaImageError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateFpgaImage");
         let params = self.new_params("CreateFpgaImage");
         let mut params = params;
         CreateFpgaImageRequestSerializer::serialize(&mut params, "", &input);
@@ -92753,6 +92830,7 @@ impl Ec2 for Ec2Client {
         input: CreateImageRequest,
     ) -> Result<CreateImageResult, RusotoError<CreateImageError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateImage");
         let params = self.new_params("CreateImage");
         let mut params = params;
         CreateImageRequestSerializer::serialize(&mut params, "", &input);
@@ -92779,6 +92857,7 @@ impl Ec2 for Ec2Client {
         input: CreateInstanceExportTaskRequest,
     ) -> Result<CreateInstanceExportTaskResult, RusotoError<CreateInstanceExportTaskError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateInstanceExportTask");
         let params = self.new_params("CreateInstanceExportTask");
         let mut params = params;
         CreateInstanceExportTaskRequestSerializer::serialize(&mut params, "", &input);
@@ -92805,6 +92884,7 @@ impl Ec2 for Ec2Client {
         input: CreateInternetGatewayRequest,
     ) -> Result<CreateInternetGatewayResult, RusotoError<CreateInternetGatewayError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateInternetGateway");
         let params = self.new_params("CreateInternetGateway");
         let mut params = params;
         CreateInternetGatewayRequestSerializer::serialize(&mut params, "", &input);
@@ -92831,6 +92911,7 @@ impl Ec2 for Ec2Client {
         input: CreateKeyPairRequest,
     ) -> Result<KeyPair, RusotoError<CreateKeyPairError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateKeyPair");
         let params = self.new_params("CreateKeyPair");
         let mut params = params;
         CreateKeyPairRequestSerializer::serialize(&mut params, "", &input);
@@ -92857,6 +92938,7 @@ impl Ec2 for Ec2Client {
         input: CreateLaunchTemplateRequest,
     ) -> Result<CreateLaunchTemplateResult, RusotoError<CreateLaunchTemplateError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateLaunchTemplate");
         let params = self.new_params("CreateLaunchTemplate");
         let mut params = params;
         CreateLaunchTemplateRequestSerializer::serialize(&mut params, "", &input);
@@ -92884,6 +92966,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<CreateLaunchTemplateVersionResult, RusotoError<CreateLaunchTemplateVersionError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateLaunchTemplateVersion");
         let params = self.new_params("CreateLaunchTemplateVersion");
         let mut params = params;
         CreateLaunchTemplateVersionRequestSerializer::serialize(&mut params, "", &input);
@@ -92910,6 +92993,7 @@ impl Ec2 for Ec2Client {
         input: CreateLocalGatewayRouteRequest,
     ) -> Result<CreateLocalGatewayRouteResult, RusotoError<CreateLocalGatewayRouteError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateLocalGatewayRoute");
         let params = self.new_params("CreateLocalGatewayRoute");
         let mut params = params;
         CreateLocalGatewayRouteRequestSerializer::serialize(&mut params, "", &input);
@@ -92939,6 +93023,7 @@ impl Ec2 for Ec2Client {
         RusotoError<CreateLocalGatewayRouteTableVpcAssociationError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateLocalGatewayRouteTableVpcAssociation");
         let params = self.new_params("CreateLocalGatewayRouteTableVpcAssociation");
         let mut params = params;
         CreateLocalGatewayRouteTableVpcAssociationRequestSerializer::serialize(
@@ -92975,6 +93060,7 @@ impl Ec2 for Ec2Client {
         input: CreateManagedPrefixListRequest,
     ) -> Result<CreateManagedPrefixListResult, RusotoError<CreateManagedPrefixListError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateManagedPrefixList");
         let params = self.new_params("CreateManagedPrefixList");
         let mut params = params;
         CreateManagedPrefixListRequestSerializer::serialize(&mut params, "", &input);
@@ -93001,6 +93087,7 @@ impl Ec2 for Ec2Client {
         input: CreateNatGatewayRequest,
     ) -> Result<CreateNatGatewayResult, RusotoError<CreateNatGatewayError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateNatGateway");
         let params = self.new_params("CreateNatGateway");
         let mut params = params;
         CreateNatGatewayRequestSerializer::serialize(&mut params, "", &input);
@@ -93027,6 +93114,7 @@ impl Ec2 for Ec2Client {
         input: CreateNetworkAclRequest,
     ) -> Result<CreateNetworkAclResult, RusotoError<CreateNetworkAclError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateNetworkAcl");
         let params = self.new_params("CreateNetworkAcl");
         let mut params = params;
         CreateNetworkAclRequestSerializer::serialize(&mut params, "", &input);
@@ -93053,6 +93141,7 @@ impl Ec2 for Ec2Client {
         input: CreateNetworkAclEntryRequest,
     ) -> Result<(), RusotoError<CreateNetworkAclEntryError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateNetworkAclEntry");
         let params = self.new_params("CreateNetworkAclEntry");
         let mut params = params;
         CreateNetworkAclEntryRequestSerializer::serialize(&mut params, "", &input);
@@ -93073,6 +93162,7 @@ impl Ec2 for Ec2Client {
         input: CreateNetworkInsightsPathRequest,
     ) -> Result<CreateNetworkInsightsPathResult, RusotoError<CreateNetworkInsightsPathError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateNetworkInsightsPath");
         let params = self.new_params("CreateNetworkInsightsPath");
         let mut params = params;
         CreateNetworkInsightsPathRequestSerializer::serialize(&mut params, "", &input);
@@ -93099,6 +93189,7 @@ impl Ec2 for Ec2Client {
         input: CreateNetworkInterfaceRequest,
     ) -> Result<CreateNetworkInterfaceResult, RusotoError<CreateNetworkInterfaceError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateNetworkInterface");
         let params = self.new_params("CreateNetworkInterface");
         let mut params = params;
         CreateNetworkInterfaceRequestSerializer::serialize(&mut params, "", &input);
@@ -93128,6 +93219,7 @@ impl Ec2 for Ec2Client {
         RusotoError<CreateNetworkInterfacePermissionError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateNetworkInterfacePermission");
         let params = self.new_params("CreateNetworkInterfacePermission");
         let mut params = params;
         CreateNetworkInterfacePermissionRequestSerializer::serialize(&mut params, "", &input);
@@ -93157,6 +93249,7 @@ impl Ec2 for Ec2Client {
         input: CreatePlacementGroupRequest,
     ) -> Result<CreatePlacementGroupResult, RusotoError<CreatePlacementGroupError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreatePlacementGroup");
         let params = self.new_params("CreatePlacementGroup");
         let mut params = params;
         CreatePlacementGroupRequestSerializer::serialize(&mut params, "", &input);
@@ -93186,6 +93279,7 @@ impl Ec2 for Ec2Client {
         RusotoError<CreateReservedInstancesListingError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateReservedInstancesListing");
         let params = self.new_params("CreateReservedInstancesListing");
         let mut params = params;
         CreateReservedInstancesListingRequestSerializer::serialize(&mut params, "", &input);
@@ -93212,6 +93306,7 @@ impl Ec2 for Ec2Client {
         input: CreateRouteRequest,
     ) -> Result<CreateRouteResult, RusotoError<CreateRouteError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateRoute");
         let params = self.new_params("CreateRoute");
         let mut params = params;
         CreateRouteRequestSerializer::serialize(&mut params, "", &input);
@@ -93238,6 +93333,7 @@ impl Ec2 for Ec2Client {
         input: CreateRouteTableRequest,
     ) -> Result<CreateRouteTableResult, RusotoError<CreateRouteTableError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateRouteTable");
         let params = self.new_params("CreateRouteTable");
         let mut params = params;
         CreateRouteTableRequestSerializer::serialize(&mut params, "", &input);
@@ -93264,6 +93360,7 @@ impl Ec2 for Ec2Client {
         input: CreateSecurityGroupRequest,
     ) -> Result<CreateSecurityGroupResult, RusotoError<CreateSecurityGroupError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateSecurityGroup");
         let params = self.new_params("CreateSecurityGroup");
         let mut params = params;
         CreateSecurityGroupRequestSerializer::serialize(&mut params, "", &input);
@@ -93290,6 +93387,7 @@ impl Ec2 for Ec2Client {
         input: CreateSnapshotRequest,
     ) -> Result<Snapshot, RusotoError<CreateSnapshotError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateSnapshot");
         let params = self.new_params("CreateSnapshot");
         let mut params = params;
         CreateSnapshotRequestSerializer::serialize(&mut params, "", &input);
@@ -93316,6 +93414,7 @@ impl Ec2 for Ec2Client {
         input: CreateSnapshotsRequest,
     ) -> Result<CreateSnapshotsResult, RusotoError<CreateSnapshotsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateSnapshots");
         let params = self.new_params("CreateSnapshots");
         let mut params = params;
         CreateSnapshotsRequestSerializer::serialize(&mut params, "", &input);
@@ -93345,6 +93444,7 @@ impl Ec2 for Ec2Client {
         RusotoError<CreateSpotDatafeedSubscriptionError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateSpotDatafeedSubscription");
         let params = self.new_params("CreateSpotDatafeedSubscription");
         let mut params = params;
         CreateSpotDatafeedSubscriptionRequestSerializer::serialize(&mut params, "", &input);
@@ -93371,6 +93471,7 @@ impl Ec2 for Ec2Client {
         input: CreateSubnetRequest,
     ) -> Result<CreateSubnetResult, RusotoError<CreateSubnetError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateSubnet");
         let params = self.new_params("CreateSubnet");
         let mut params = params;
         CreateSubnetRequestSerializer::serialize(&mut params, "", &input);
@@ -93397,6 +93498,7 @@ impl Ec2 for Ec2Client {
         input: CreateTagsRequest,
     ) -> Result<(), RusotoError<CreateTagsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateTags");
         let params = self.new_params("CreateTags");
         let mut params = params;
         CreateTagsRequestSerializer::serialize(&mut params, "", &input);
@@ -93417,6 +93519,7 @@ impl Ec2 for Ec2Client {
         input: CreateTrafficMirrorFilterRequest,
     ) -> Result<CreateTrafficMirrorFilterResult, RusotoError<CreateTrafficMirrorFilterError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateTrafficMirrorFilter");
         let params = self.new_params("CreateTrafficMirrorFilter");
         let mut params = params;
         CreateTrafficMirrorFilterRequestSerializer::serialize(&mut params, "", &input);
@@ -93444,6 +93547,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<CreateTrafficMirrorFilterRuleResult, RusotoError<CreateTrafficMirrorFilterRuleError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateTrafficMirrorFilterRule");
         let params = self.new_params("CreateTrafficMirrorFilterRule");
         let mut params = params;
         CreateTrafficMirrorFilterRuleRequestSerializer::serialize(&mut params, "", &input);
@@ -93471,6 +93575,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<CreateTrafficMirrorSessionResult, RusotoError<CreateTrafficMirrorSessionError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateTrafficMirrorSession");
         let params = self.new_params("CreateTrafficMirrorSession");
         let mut params = params;
         CreateTrafficMirrorSessionRequestSerializer::serialize(&mut params, "", &input);
@@ -93497,6 +93602,7 @@ impl Ec2 for Ec2Client {
         input: CreateTrafficMirrorTargetRequest,
     ) -> Result<CreateTrafficMirrorTargetResult, RusotoError<CreateTrafficMirrorTargetError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateTrafficMirrorTarget");
         let params = self.new_params("CreateTrafficMirrorTarget");
         let mut params = params;
         CreateTrafficMirrorTargetRequestSerializer::serialize(&mut params, "", &input);
@@ -93523,6 +93629,7 @@ impl Ec2 for Ec2Client {
         input: CreateTransitGatewayRequest,
     ) -> Result<CreateTransitGatewayResult, RusotoError<CreateTransitGatewayError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateTransitGateway");
         let params = self.new_params("CreateTransitGateway");
         let mut params = params;
         CreateTransitGatewayRequestSerializer::serialize(&mut params, "", &input);
@@ -93550,6 +93657,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<CreateTransitGatewayConnectResult, RusotoError<CreateTransitGatewayConnectError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateTransitGatewayConnect");
         let params = self.new_params("CreateTransitGatewayConnect");
         let mut params = params;
         CreateTransitGatewayConnectRequestSerializer::serialize(&mut params, "", &input);
@@ -93579,6 +93687,7 @@ impl Ec2 for Ec2Client {
         RusotoError<CreateTransitGatewayConnectPeerError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateTransitGatewayConnectPeer");
         let params = self.new_params("CreateTransitGatewayConnectPeer");
         let mut params = params;
         CreateTransitGatewayConnectPeerRequestSerializer::serialize(&mut params, "", &input);
@@ -93608,6 +93717,7 @@ impl Ec2 for Ec2Client {
         RusotoError<CreateTransitGatewayMulticastDomainError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateTransitGatewayMulticastDomain");
         let params = self.new_params("CreateTransitGatewayMulticastDomain");
         let mut params = params;
         CreateTransitGatewayMulticastDomainRequestSerializer::serialize(&mut params, "", &input);
@@ -93643,6 +93753,7 @@ impl Ec2 for Ec2Client {
         RusotoError<CreateTransitGatewayPeeringAttachmentError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateTransitGatewayPeeringAttachment");
         let params = self.new_params("CreateTransitGatewayPeeringAttachment");
         let mut params = params;
         CreateTransitGatewayPeeringAttachmentRequestSerializer::serialize(&mut params, "", &input);
@@ -93678,6 +93789,7 @@ impl Ec2 for Ec2Client {
         RusotoError<CreateTransitGatewayPrefixListReferenceError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateTransitGatewayPrefixListReference");
         let params = self.new_params("CreateTransitGatewayPrefixListReference");
         let mut params = params;
         CreateTransitGatewayPrefixListReferenceRequestSerializer::serialize(
@@ -93714,6 +93826,7 @@ impl Ec2 for Ec2Client {
         input: CreateTransitGatewayRouteRequest,
     ) -> Result<CreateTransitGatewayRouteResult, RusotoError<CreateTransitGatewayRouteError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateTransitGatewayRoute");
         let params = self.new_params("CreateTransitGatewayRoute");
         let mut params = params;
         CreateTransitGatewayRouteRequestSerializer::serialize(&mut params, "", &input);
@@ -93743,6 +93856,7 @@ impl Ec2 for Ec2Client {
         RusotoError<CreateTransitGatewayRouteTableError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateTransitGatewayRouteTable");
         let params = self.new_params("CreateTransitGatewayRouteTable");
         let mut params = params;
         CreateTransitGatewayRouteTableRequestSerializer::serialize(&mut params, "", &input);
@@ -93772,6 +93886,7 @@ impl Ec2 for Ec2Client {
         RusotoError<CreateTransitGatewayVpcAttachmentError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateTransitGatewayVpcAttachment");
         let params = self.new_params("CreateTransitGatewayVpcAttachment");
         let mut params = params;
         CreateTransitGatewayVpcAttachmentRequestSerializer::serialize(&mut params, "", &input);
@@ -93801,6 +93916,7 @@ impl Ec2 for Ec2Client {
         input: CreateVolumeRequest,
     ) -> Result<Volume, RusotoError<CreateVolumeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateVolume");
         let params = self.new_params("CreateVolume");
         let mut params = params;
         CreateVolumeRequestSerializer::serialize(&mut params, "", &input);
@@ -93827,6 +93943,7 @@ impl Ec2 for Ec2Client {
         input: CreateVpcRequest,
     ) -> Result<CreateVpcResult, RusotoError<CreateVpcError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateVpc");
         let params = self.new_params("CreateVpc");
         let mut params = params;
         CreateVpcRequestSerializer::serialize(&mut params, "", &input);
@@ -93853,6 +93970,7 @@ impl Ec2 for Ec2Client {
         input: CreateVpcEndpointRequest,
     ) -> Result<CreateVpcEndpointResult, RusotoError<CreateVpcEndpointError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateVpcEndpoint");
         let params = self.new_params("CreateVpcEndpoint");
         let mut params = params;
         CreateVpcEndpointRequestSerializer::serialize(&mut params, "", &input);
@@ -93882,6 +94000,7 @@ impl Ec2 for Ec2Client {
         RusotoError<CreateVpcEndpointConnectionNotificationError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateVpcEndpointConnectionNotification");
         let params = self.new_params("CreateVpcEndpointConnectionNotification");
         let mut params = params;
         CreateVpcEndpointConnectionNotificationRequestSerializer::serialize(
@@ -93921,6 +94040,7 @@ impl Ec2 for Ec2Client {
         RusotoError<CreateVpcEndpointServiceConfigurationError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateVpcEndpointServiceConfiguration");
         let params = self.new_params("CreateVpcEndpointServiceConfiguration");
         let mut params = params;
         CreateVpcEndpointServiceConfigurationRequestSerializer::serialize(&mut params, "", &input);
@@ -93954,6 +94074,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<CreateVpcPeeringConnectionResult, RusotoError<CreateVpcPeeringConnectionError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateVpcPeeringConnection");
         let params = self.new_params("CreateVpcPeeringConnection");
         let mut params = params;
         CreateVpcPeeringConnectionRequestSerializer::serialize(&mut params, "", &input);
@@ -93980,6 +94101,7 @@ impl Ec2 for Ec2Client {
         input: CreateVpnConnectionRequest,
     ) -> Result<CreateVpnConnectionResult, RusotoError<CreateVpnConnectionError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateVpnConnection");
         let params = self.new_params("CreateVpnConnection");
         let mut params = params;
         CreateVpnConnectionRequestSerializer::serialize(&mut params, "", &input);
@@ -94006,6 +94128,7 @@ impl Ec2 for Ec2Client {
         input: CreateVpnConnectionRouteRequest,
     ) -> Result<(), RusotoError<CreateVpnConnectionRouteError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateVpnConnectionRoute");
         let params = self.new_params("CreateVpnConnectionRoute");
         let mut params = params;
         CreateVpnConnectionRouteRequestSerializer::serialize(&mut params, "", &input);
@@ -94026,6 +94149,7 @@ impl Ec2 for Ec2Client {
         input: CreateVpnGatewayRequest,
     ) -> Result<CreateVpnGatewayResult, RusotoError<CreateVpnGatewayError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("CreateVpnGateway");
         let params = self.new_params("CreateVpnGateway");
         let mut params = params;
         CreateVpnGatewayRequestSerializer::serialize(&mut params, "", &input);
@@ -94052,6 +94176,7 @@ impl Ec2 for Ec2Client {
         input: DeleteCarrierGatewayRequest,
     ) -> Result<DeleteCarrierGatewayResult, RusotoError<DeleteCarrierGatewayError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteCarrierGateway");
         let params = self.new_params("DeleteCarrierGateway");
         let mut params = params;
         DeleteCarrierGatewayRequestSerializer::serialize(&mut params, "", &input);
@@ -94078,6 +94203,7 @@ impl Ec2 for Ec2Client {
         input: DeleteClientVpnEndpointRequest,
     ) -> Result<DeleteClientVpnEndpointResult, RusotoError<DeleteClientVpnEndpointError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteClientVpnEndpoint");
         let params = self.new_params("DeleteClientVpnEndpoint");
         let mut params = params;
         DeleteClientVpnEndpointRequestSerializer::serialize(&mut params, "", &input);
@@ -94104,6 +94230,7 @@ impl Ec2 for Ec2Client {
         input: DeleteClientVpnRouteRequest,
     ) -> Result<DeleteClientVpnRouteResult, RusotoError<DeleteClientVpnRouteError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteClientVpnRoute");
         let params = self.new_params("DeleteClientVpnRoute");
         let mut params = params;
         DeleteClientVpnRouteRequestSerializer::serialize(&mut params, "", &input);
@@ -94130,6 +94257,7 @@ impl Ec2 for Ec2Client {
         input: DeleteCustomerGatewayRequest,
     ) -> Result<(), RusotoError<DeleteCustomerGatewayError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteCustomerGateway");
         let params = self.new_params("DeleteCustomerGateway");
         let mut params = params;
         DeleteCustomerGatewayRequestSerializer::serialize(&mut params, "", &input);
@@ -94150,6 +94278,7 @@ impl Ec2 for Ec2Client {
         input: DeleteDhcpOptionsRequest,
     ) -> Result<(), RusotoError<DeleteDhcpOptionsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteDhcpOptions");
         let params = self.new_params("DeleteDhcpOptions");
         let mut params = params;
         DeleteDhcpOptionsRequestSerializer::serialize(&mut params, "", &input);
@@ -94173,6 +94302,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DeleteEgressOnlyInternetGatewayError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteEgressOnlyInternetGateway");
         let params = self.new_params("DeleteEgressOnlyInternetGateway");
         let mut params = params;
         DeleteEgressOnlyInternetGatewayRequestSerializer::serialize(&mut params, "", &input);
@@ -94199,6 +94329,7 @@ impl Ec2 for Ec2Client {
         input: DeleteFleetsRequest,
     ) -> Result<DeleteFleetsResult, RusotoError<DeleteFleetsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteFleets");
         let params = self.new_params("DeleteFleets");
         let mut params = params;
         DeleteFleetsRequestSerializer::serialize(&mut params, "", &input);
@@ -94225,6 +94356,7 @@ impl Ec2 for Ec2Client {
         input: DeleteFlowLogsRequest,
     ) -> Result<DeleteFlowLogsResult, RusotoError<DeleteFlowLogsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteFlowLogs");
         let params = self.new_params("DeleteFlowLogs");
         let mut params = params;
         DeleteFlowLogsRequestSerializer::serialize(&mut params, "", &input);
@@ -94251,6 +94383,7 @@ impl Ec2 for Ec2Client {
         input: DeleteFpgaImageRequest,
     ) -> Result<DeleteFpgaImageResult, RusotoError<DeleteFpgaImageError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteFpgaImage");
         let params = self.new_params("DeleteFpgaImage");
         let mut params = params;
         DeleteFpgaImageRequestSerializer::serialize(&mut params, "", &input);
@@ -94277,6 +94410,7 @@ impl Ec2 for Ec2Client {
         input: DeleteInternetGatewayRequest,
     ) -> Result<(), RusotoError<DeleteInternetGatewayError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteInternetGateway");
         let params = self.new_params("DeleteInternetGateway");
         let mut params = params;
         DeleteInternetGatewayRequestSerializer::serialize(&mut params, "", &input);
@@ -94297,6 +94431,7 @@ impl Ec2 for Ec2Client {
         input: DeleteKeyPairRequest,
     ) -> Result<(), RusotoError<DeleteKeyPairError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteKeyPair");
         let params = self.new_params("DeleteKeyPair");
         let mut params = params;
         DeleteKeyPairRequestSerializer::serialize(&mut params, "", &input);
@@ -94317,6 +94452,7 @@ impl Ec2 for Ec2Client {
         input: DeleteLaunchTemplateRequest,
     ) -> Result<DeleteLaunchTemplateResult, RusotoError<DeleteLaunchTemplateError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteLaunchTemplate");
         let params = self.new_params("DeleteLaunchTemplate");
         let mut params = params;
         DeleteLaunchTemplateRequestSerializer::serialize(&mut params, "", &input);
@@ -94344,6 +94480,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<DeleteLaunchTemplateVersionsResult, RusotoError<DeleteLaunchTemplateVersionsError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteLaunchTemplateVersions");
         let params = self.new_params("DeleteLaunchTemplateVersions");
         let mut params = params;
         DeleteLaunchTemplateVersionsRequestSerializer::serialize(&mut params, "", &input);
@@ -94370,6 +94507,7 @@ impl Ec2 for Ec2Client {
         input: DeleteLocalGatewayRouteRequest,
     ) -> Result<DeleteLocalGatewayRouteResult, RusotoError<DeleteLocalGatewayRouteError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteLocalGatewayRoute");
         let params = self.new_params("DeleteLocalGatewayRoute");
         let mut params = params;
         DeleteLocalGatewayRouteRequestSerializer::serialize(&mut params, "", &input);
@@ -94399,6 +94537,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DeleteLocalGatewayRouteTableVpcAssociationError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteLocalGatewayRouteTableVpcAssociation");
         let params = self.new_params("DeleteLocalGatewayRouteTableVpcAssociation");
         let mut params = params;
         DeleteLocalGatewayRouteTableVpcAssociationRequestSerializer::serialize(
@@ -94435,6 +94574,7 @@ impl Ec2 for Ec2Client {
         input: DeleteManagedPrefixListRequest,
     ) -> Result<DeleteManagedPrefixListResult, RusotoError<DeleteManagedPrefixListError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteManagedPrefixList");
         let params = self.new_params("DeleteManagedPrefixList");
         let mut params = params;
         DeleteManagedPrefixListRequestSerializer::serialize(&mut params, "", &input);
@@ -94461,6 +94601,7 @@ impl Ec2 for Ec2Client {
         input: DeleteNatGatewayRequest,
     ) -> Result<DeleteNatGatewayResult, RusotoError<DeleteNatGatewayError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteNatGateway");
         let params = self.new_params("DeleteNatGateway");
         let mut params = params;
         DeleteNatGatewayRequestSerializer::serialize(&mut params, "", &input);
@@ -94487,6 +94628,7 @@ impl Ec2 for Ec2Client {
         input: DeleteNetworkAclRequest,
     ) -> Result<(), RusotoError<DeleteNetworkAclError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteNetworkAcl");
         let params = self.new_params("DeleteNetworkAcl");
         let mut params = params;
         DeleteNetworkAclRequestSerializer::serialize(&mut params, "", &input);
@@ -94507,6 +94649,7 @@ impl Ec2 for Ec2Client {
         input: DeleteNetworkAclEntryRequest,
     ) -> Result<(), RusotoError<DeleteNetworkAclEntryError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteNetworkAclEntry");
         let params = self.new_params("DeleteNetworkAclEntry");
         let mut params = params;
         DeleteNetworkAclEntryRequestSerializer::serialize(&mut params, "", &input);
@@ -94528,6 +94671,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<DeleteNetworkInsightsAnalysisResult, RusotoError<DeleteNetworkInsightsAnalysisError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteNetworkInsightsAnalysis");
         let params = self.new_params("DeleteNetworkInsightsAnalysis");
         let mut params = params;
         DeleteNetworkInsightsAnalysisRequestSerializer::serialize(&mut params, "", &input);
@@ -94554,6 +94698,7 @@ impl Ec2 for Ec2Client {
         input: DeleteNetworkInsightsPathRequest,
     ) -> Result<DeleteNetworkInsightsPathResult, RusotoError<DeleteNetworkInsightsPathError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteNetworkInsightsPath");
         let params = self.new_params("DeleteNetworkInsightsPath");
         let mut params = params;
         DeleteNetworkInsightsPathRequestSerializer::serialize(&mut params, "", &input);
@@ -94580,6 +94725,7 @@ impl Ec2 for Ec2Client {
         input: DeleteNetworkInterfaceRequest,
     ) -> Result<(), RusotoError<DeleteNetworkInterfaceError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteNetworkInterface");
         let params = self.new_params("DeleteNetworkInterface");
         let mut params = params;
         DeleteNetworkInterfaceRequestSerializer::serialize(&mut params, "", &input);
@@ -94603,6 +94749,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DeleteNetworkInterfacePermissionError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteNetworkInterfacePermission");
         let params = self.new_params("DeleteNetworkInterfacePermission");
         let mut params = params;
         DeleteNetworkInterfacePermissionRequestSerializer::serialize(&mut params, "", &input);
@@ -94632,6 +94779,7 @@ impl Ec2 for Ec2Client {
         input: DeletePlacementGroupRequest,
     ) -> Result<(), RusotoError<DeletePlacementGroupError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeletePlacementGroup");
         let params = self.new_params("DeletePlacementGroup");
         let mut params = params;
         DeletePlacementGroupRequestSerializer::serialize(&mut params, "", &input);
@@ -94653,6 +94801,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<DeleteQueuedReservedInstancesResult, RusotoError<DeleteQueuedReservedInstancesError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteQueuedReservedInstances");
         let params = self.new_params("DeleteQueuedReservedInstances");
         let mut params = params;
         DeleteQueuedReservedInstancesRequestSerializer::serialize(&mut params, "", &input);
@@ -94679,6 +94828,7 @@ impl Ec2 for Ec2Client {
         input: DeleteRouteRequest,
     ) -> Result<(), RusotoError<DeleteRouteError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteRoute");
         let params = self.new_params("DeleteRoute");
         let mut params = params;
         DeleteRouteRequestSerializer::serialize(&mut params, "", &input);
@@ -94699,6 +94849,7 @@ impl Ec2 for Ec2Client {
         input: DeleteRouteTableRequest,
     ) -> Result<(), RusotoError<DeleteRouteTableError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteRouteTable");
         let params = self.new_params("DeleteRouteTable");
         let mut params = params;
         DeleteRouteTableRequestSerializer::serialize(&mut params, "", &input);
@@ -94719,6 +94870,7 @@ impl Ec2 for Ec2Client {
         input: DeleteSecurityGroupRequest,
     ) -> Result<(), RusotoError<DeleteSecurityGroupError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteSecurityGroup");
         let params = self.new_params("DeleteSecurityGroup");
         let mut params = params;
         DeleteSecurityGroupRequestSerializer::serialize(&mut params, "", &input);
@@ -94739,6 +94891,7 @@ impl Ec2 for Ec2Client {
         input: DeleteSnapshotRequest,
     ) -> Result<(), RusotoError<DeleteSnapshotError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteSnapshot");
         let params = self.new_params("DeleteSnapshot");
         let mut params = params;
         DeleteSnapshotRequestSerializer::serialize(&mut params, "", &input);
@@ -94759,6 +94912,7 @@ impl Ec2 for Ec2Client {
         input: DeleteSpotDatafeedSubscriptionRequest,
     ) -> Result<(), RusotoError<DeleteSpotDatafeedSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteSpotDatafeedSubscription");
         let params = self.new_params("DeleteSpotDatafeedSubscription");
         let mut params = params;
         DeleteSpotDatafeedSubscriptionRequestSerializer::serialize(&mut params, "", &input);
@@ -94779,6 +94933,7 @@ impl Ec2 for Ec2Client {
         input: DeleteSubnetRequest,
     ) -> Result<(), RusotoError<DeleteSubnetError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteSubnet");
         let params = self.new_params("DeleteSubnet");
         let mut params = params;
         DeleteSubnetRequestSerializer::serialize(&mut params, "", &input);
@@ -94799,6 +94954,7 @@ impl Ec2 for Ec2Client {
         input: DeleteTagsRequest,
     ) -> Result<(), RusotoError<DeleteTagsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteTags");
         let params = self.new_params("DeleteTags");
         let mut params = params;
         DeleteTagsRequestSerializer::serialize(&mut params, "", &input);
@@ -94819,6 +94975,7 @@ impl Ec2 for Ec2Client {
         input: DeleteTrafficMirrorFilterRequest,
     ) -> Result<DeleteTrafficMirrorFilterResult, RusotoError<DeleteTrafficMirrorFilterError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteTrafficMirrorFilter");
         let params = self.new_params("DeleteTrafficMirrorFilter");
         let mut params = params;
         DeleteTrafficMirrorFilterRequestSerializer::serialize(&mut params, "", &input);
@@ -94846,6 +95003,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<DeleteTrafficMirrorFilterRuleResult, RusotoError<DeleteTrafficMirrorFilterRuleError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteTrafficMirrorFilterRule");
         let params = self.new_params("DeleteTrafficMirrorFilterRule");
         let mut params = params;
         DeleteTrafficMirrorFilterRuleRequestSerializer::serialize(&mut params, "", &input);
@@ -94873,6 +95031,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<DeleteTrafficMirrorSessionResult, RusotoError<DeleteTrafficMirrorSessionError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteTrafficMirrorSession");
         let params = self.new_params("DeleteTrafficMirrorSession");
         let mut params = params;
         DeleteTrafficMirrorSessionRequestSerializer::serialize(&mut params, "", &input);
@@ -94899,6 +95058,7 @@ impl Ec2 for Ec2Client {
         input: DeleteTrafficMirrorTargetRequest,
     ) -> Result<DeleteTrafficMirrorTargetResult, RusotoError<DeleteTrafficMirrorTargetError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteTrafficMirrorTarget");
         let params = self.new_params("DeleteTrafficMirrorTarget");
         let mut params = params;
         DeleteTrafficMirrorTargetRequestSerializer::serialize(&mut params, "", &input);
@@ -94925,6 +95085,7 @@ impl Ec2 for Ec2Client {
         input: DeleteTransitGatewayRequest,
     ) -> Result<DeleteTransitGatewayResult, RusotoError<DeleteTransitGatewayError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteTransitGateway");
         let params = self.new_params("DeleteTransitGateway");
         let mut params = params;
         DeleteTransitGatewayRequestSerializer::serialize(&mut params, "", &input);
@@ -94952,6 +95113,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<DeleteTransitGatewayConnectResult, RusotoError<DeleteTransitGatewayConnectError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteTransitGatewayConnect");
         let params = self.new_params("DeleteTransitGatewayConnect");
         let mut params = params;
         DeleteTransitGatewayConnectRequestSerializer::serialize(&mut params, "", &input);
@@ -94981,6 +95143,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DeleteTransitGatewayConnectPeerError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteTransitGatewayConnectPeer");
         let params = self.new_params("DeleteTransitGatewayConnectPeer");
         let mut params = params;
         DeleteTransitGatewayConnectPeerRequestSerializer::serialize(&mut params, "", &input);
@@ -95010,6 +95173,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DeleteTransitGatewayMulticastDomainError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteTransitGatewayMulticastDomain");
         let params = self.new_params("DeleteTransitGatewayMulticastDomain");
         let mut params = params;
         DeleteTransitGatewayMulticastDomainRequestSerializer::serialize(&mut params, "", &input);
@@ -95045,6 +95209,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DeleteTransitGatewayPeeringAttachmentError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteTransitGatewayPeeringAttachment");
         let params = self.new_params("DeleteTransitGatewayPeeringAttachment");
         let mut params = params;
         DeleteTransitGatewayPeeringAttachmentRequestSerializer::serialize(&mut params, "", &input);
@@ -95080,6 +95245,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DeleteTransitGatewayPrefixListReferenceError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteTransitGatewayPrefixListReference");
         let params = self.new_params("DeleteTransitGatewayPrefixListReference");
         let mut params = params;
         DeleteTransitGatewayPrefixListReferenceRequestSerializer::serialize(
@@ -95116,6 +95282,7 @@ impl Ec2 for Ec2Client {
         input: DeleteTransitGatewayRouteRequest,
     ) -> Result<DeleteTransitGatewayRouteResult, RusotoError<DeleteTransitGatewayRouteError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteTransitGatewayRoute");
         let params = self.new_params("DeleteTransitGatewayRoute");
         let mut params = params;
         DeleteTransitGatewayRouteRequestSerializer::serialize(&mut params, "", &input);
@@ -95145,6 +95312,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DeleteTransitGatewayRouteTableError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteTransitGatewayRouteTable");
         let params = self.new_params("DeleteTransitGatewayRouteTable");
         let mut params = params;
         DeleteTransitGatewayRouteTableRequestSerializer::serialize(&mut params, "", &input);
@@ -95174,6 +95342,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DeleteTransitGatewayVpcAttachmentError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteTransitGatewayVpcAttachment");
         let params = self.new_params("DeleteTransitGatewayVpcAttachment");
         let mut params = params;
         DeleteTransitGatewayVpcAttachmentRequestSerializer::serialize(&mut params, "", &input);
@@ -95203,6 +95372,7 @@ impl Ec2 for Ec2Client {
         input: DeleteVolumeRequest,
     ) -> Result<(), RusotoError<DeleteVolumeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteVolume");
         let params = self.new_params("DeleteVolume");
         let mut params = params;
         DeleteVolumeRequestSerializer::serialize(&mut params, "", &input);
@@ -95220,6 +95390,7 @@ impl Ec2 for Ec2Client {
     /// <p>Deletes the specified VPC. You must detach or delete all gateways and resources that are associated with the VPC before you can delete it. For example, you must terminate all instances running in the VPC, delete all security groups associated with the VPC (except the default one), delete all route tables associated with the VPC (except the default one), and so on.</p>
     async fn delete_vpc(&self, input: DeleteVpcRequest) -> Result<(), RusotoError<DeleteVpcError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteVpc");
         let params = self.new_params("DeleteVpc");
         let mut params = params;
         DeleteVpcRequestSerializer::serialize(&mut params, "", &input);
@@ -95243,6 +95414,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DeleteVpcEndpointConnectionNotificationsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteVpcEndpointConnectionNotifications");
         let params = self.new_params("DeleteVpcEndpointConnectionNotifications");
         let mut params = params;
         DeleteVpcEndpointConnectionNotificationsRequestSerializer::serialize(
@@ -95282,6 +95454,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DeleteVpcEndpointServiceConfigurationsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteVpcEndpointServiceConfigurations");
         let params = self.new_params("DeleteVpcEndpointServiceConfigurations");
         let mut params = params;
         DeleteVpcEndpointServiceConfigurationsRequestSerializer::serialize(&mut params, "", &input);
@@ -95314,6 +95487,7 @@ impl Ec2 for Ec2Client {
         input: DeleteVpcEndpointsRequest,
     ) -> Result<DeleteVpcEndpointsResult, RusotoError<DeleteVpcEndpointsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteVpcEndpoints");
         let params = self.new_params("DeleteVpcEndpoints");
         let mut params = params;
         DeleteVpcEndpointsRequestSerializer::serialize(&mut params, "", &input);
@@ -95341,6 +95515,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<DeleteVpcPeeringConnectionResult, RusotoError<DeleteVpcPeeringConnectionError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteVpcPeeringConnection");
         let params = self.new_params("DeleteVpcPeeringConnection");
         let mut params = params;
         DeleteVpcPeeringConnectionRequestSerializer::serialize(&mut params, "", &input);
@@ -95367,6 +95542,7 @@ impl Ec2 for Ec2Client {
         input: DeleteVpnConnectionRequest,
     ) -> Result<(), RusotoError<DeleteVpnConnectionError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteVpnConnection");
         let params = self.new_params("DeleteVpnConnection");
         let mut params = params;
         DeleteVpnConnectionRequestSerializer::serialize(&mut params, "", &input);
@@ -95387,6 +95563,7 @@ impl Ec2 for Ec2Client {
         input: DeleteVpnConnectionRouteRequest,
     ) -> Result<(), RusotoError<DeleteVpnConnectionRouteError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteVpnConnectionRoute");
         let params = self.new_params("DeleteVpnConnectionRoute");
         let mut params = params;
         DeleteVpnConnectionRouteRequestSerializer::serialize(&mut params, "", &input);
@@ -95407,6 +95584,7 @@ impl Ec2 for Ec2Client {
         input: DeleteVpnGatewayRequest,
     ) -> Result<(), RusotoError<DeleteVpnGatewayError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeleteVpnGateway");
         let params = self.new_params("DeleteVpnGateway");
         let mut params = params;
         DeleteVpnGatewayRequestSerializer::serialize(&mut params, "", &input);
@@ -95427,6 +95605,7 @@ impl Ec2 for Ec2Client {
         input: DeprovisionByoipCidrRequest,
     ) -> Result<DeprovisionByoipCidrResult, RusotoError<DeprovisionByoipCidrError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeprovisionByoipCidr");
         let params = self.new_params("DeprovisionByoipCidr");
         let mut params = params;
         DeprovisionByoipCidrRequestSerializer::serialize(&mut params, "", &input);
@@ -95453,6 +95632,7 @@ impl Ec2 for Ec2Client {
         input: DeregisterImageRequest,
     ) -> Result<(), RusotoError<DeregisterImageError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeregisterImage");
         let params = self.new_params("DeregisterImage");
         let mut params = params;
         DeregisterImageRequestSerializer::serialize(&mut params, "", &input);
@@ -95476,6 +95656,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DeregisterInstanceEventNotificationAttributesError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeregisterInstanceEventNotificationAttributes");
         let params = self.new_params("DeregisterInstanceEventNotificationAttributes");
         let mut params = params;
         DeregisterInstanceEventNotificationAttributesRequestSerializer::serialize(
@@ -95515,6 +95696,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DeregisterTransitGatewayMulticastGroupMembersError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeregisterTransitGatewayMulticastGroupMembers");
         let params = self.new_params("DeregisterTransitGatewayMulticastGroupMembers");
         let mut params = params;
         DeregisterTransitGatewayMulticastGroupMembersRequestSerializer::serialize(
@@ -95554,6 +95736,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DeregisterTransitGatewayMulticastGroupSourcesError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DeregisterTransitGatewayMulticastGroupSources");
         let params = self.new_params("DeregisterTransitGatewayMulticastGroupSources");
         let mut params = params;
         DeregisterTransitGatewayMulticastGroupSourcesRequestSerializer::serialize(
@@ -95590,6 +95773,7 @@ impl Ec2 for Ec2Client {
         input: DescribeAccountAttributesRequest,
     ) -> Result<DescribeAccountAttributesResult, RusotoError<DescribeAccountAttributesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeAccountAttributes");
         let params = self.new_params("DescribeAccountAttributes");
         let mut params = params;
         DescribeAccountAttributesRequestSerializer::serialize(&mut params, "", &input);
@@ -95616,6 +95800,7 @@ impl Ec2 for Ec2Client {
         input: DescribeAddressesRequest,
     ) -> Result<DescribeAddressesResult, RusotoError<DescribeAddressesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeAddresses");
         let params = self.new_params("DescribeAddresses");
         let mut params = params;
         DescribeAddressesRequestSerializer::serialize(&mut params, "", &input);
@@ -95642,6 +95827,7 @@ impl Ec2 for Ec2Client {
         input: DescribeAggregateIdFormatRequest,
     ) -> Result<DescribeAggregateIdFormatResult, RusotoError<DescribeAggregateIdFormatError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeAggregateIdFormat");
         let params = self.new_params("DescribeAggregateIdFormat");
         let mut params = params;
         DescribeAggregateIdFormatRequestSerializer::serialize(&mut params, "", &input);
@@ -95668,6 +95854,7 @@ impl Ec2 for Ec2Client {
         input: DescribeAvailabilityZonesRequest,
     ) -> Result<DescribeAvailabilityZonesResult, RusotoError<DescribeAvailabilityZonesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeAvailabilityZones");
         let params = self.new_params("DescribeAvailabilityZones");
         let mut params = params;
         DescribeAvailabilityZonesRequestSerializer::serialize(&mut params, "", &input);
@@ -95694,6 +95881,7 @@ impl Ec2 for Ec2Client {
         input: DescribeBundleTasksRequest,
     ) -> Result<DescribeBundleTasksResult, RusotoError<DescribeBundleTasksError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeBundleTasks");
         let params = self.new_params("DescribeBundleTasks");
         let mut params = params;
         DescribeBundleTasksRequestSerializer::serialize(&mut params, "", &input);
@@ -95720,6 +95908,7 @@ impl Ec2 for Ec2Client {
         input: DescribeByoipCidrsRequest,
     ) -> Result<DescribeByoipCidrsResult, RusotoError<DescribeByoipCidrsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeByoipCidrs");
         let params = self.new_params("DescribeByoipCidrs");
         let mut params = params;
         DescribeByoipCidrsRequestSerializer::serialize(&mut params, "", &input);
@@ -95747,6 +95936,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeCapacityReservationsResult, RusotoError<DescribeCapacityReservationsError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeCapacityReservations");
         let params = self.new_params("DescribeCapacityReservations");
         let mut params = params;
         DescribeCapacityReservationsRequestSerializer::serialize(&mut params, "", &input);
@@ -95773,6 +95963,7 @@ impl Ec2 for Ec2Client {
         input: DescribeCarrierGatewaysRequest,
     ) -> Result<DescribeCarrierGatewaysResult, RusotoError<DescribeCarrierGatewaysError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeCarrierGateways");
         let params = self.new_params("DescribeCarrierGateways");
         let mut params = params;
         DescribeCarrierGatewaysRequestSerializer::serialize(&mut params, "", &input);
@@ -95800,6 +95991,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeClassicLinkInstancesResult, RusotoError<DescribeClassicLinkInstancesError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeClassicLinkInstances");
         let params = self.new_params("DescribeClassicLinkInstances");
         let mut params = params;
         DescribeClassicLinkInstancesRequestSerializer::serialize(&mut params, "", &input);
@@ -95829,6 +96021,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeClientVpnAuthorizationRulesError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeClientVpnAuthorizationRules");
         let params = self.new_params("DescribeClientVpnAuthorizationRules");
         let mut params = params;
         DescribeClientVpnAuthorizationRulesRequestSerializer::serialize(&mut params, "", &input);
@@ -95862,6 +96055,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeClientVpnConnectionsResult, RusotoError<DescribeClientVpnConnectionsError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeClientVpnConnections");
         let params = self.new_params("DescribeClientVpnConnections");
         let mut params = params;
         DescribeClientVpnConnectionsRequestSerializer::serialize(&mut params, "", &input);
@@ -95889,6 +96083,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeClientVpnEndpointsResult, RusotoError<DescribeClientVpnEndpointsError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeClientVpnEndpoints");
         let params = self.new_params("DescribeClientVpnEndpoints");
         let mut params = params;
         DescribeClientVpnEndpointsRequestSerializer::serialize(&mut params, "", &input);
@@ -95915,6 +96110,7 @@ impl Ec2 for Ec2Client {
         input: DescribeClientVpnRoutesRequest,
     ) -> Result<DescribeClientVpnRoutesResult, RusotoError<DescribeClientVpnRoutesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeClientVpnRoutes");
         let params = self.new_params("DescribeClientVpnRoutes");
         let mut params = params;
         DescribeClientVpnRoutesRequestSerializer::serialize(&mut params, "", &input);
@@ -95944,6 +96140,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeClientVpnTargetNetworksError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeClientVpnTargetNetworks");
         let params = self.new_params("DescribeClientVpnTargetNetworks");
         let mut params = params;
         DescribeClientVpnTargetNetworksRequestSerializer::serialize(&mut params, "", &input);
@@ -95970,6 +96167,7 @@ impl Ec2 for Ec2Client {
         input: DescribeCoipPoolsRequest,
     ) -> Result<DescribeCoipPoolsResult, RusotoError<DescribeCoipPoolsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeCoipPools");
         let params = self.new_params("DescribeCoipPools");
         let mut params = params;
         DescribeCoipPoolsRequestSerializer::serialize(&mut params, "", &input);
@@ -95996,6 +96194,7 @@ impl Ec2 for Ec2Client {
         input: DescribeConversionTasksRequest,
     ) -> Result<DescribeConversionTasksResult, RusotoError<DescribeConversionTasksError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeConversionTasks");
         let params = self.new_params("DescribeConversionTasks");
         let mut params = params;
         DescribeConversionTasksRequestSerializer::serialize(&mut params, "", &input);
@@ -96022,6 +96221,7 @@ impl Ec2 for Ec2Client {
         input: DescribeCustomerGatewaysRequest,
     ) -> Result<DescribeCustomerGatewaysResult, RusotoError<DescribeCustomerGatewaysError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeCustomerGateways");
         let params = self.new_params("DescribeCustomerGateways");
         let mut params = params;
         DescribeCustomerGatewaysRequestSerializer::serialize(&mut params, "", &input);
@@ -96048,6 +96248,7 @@ impl Ec2 for Ec2Client {
         input: DescribeDhcpOptionsRequest,
     ) -> Result<DescribeDhcpOptionsResult, RusotoError<DescribeDhcpOptionsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeDhcpOptions");
         let params = self.new_params("DescribeDhcpOptions");
         let mut params = params;
         DescribeDhcpOptionsRequestSerializer::serialize(&mut params, "", &input);
@@ -96077,6 +96278,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeEgressOnlyInternetGatewaysError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeEgressOnlyInternetGateways");
         let params = self.new_params("DescribeEgressOnlyInternetGateways");
         let mut params = params;
         DescribeEgressOnlyInternetGatewaysRequestSerializer::serialize(&mut params, "", &input);
@@ -96109,6 +96311,7 @@ impl Ec2 for Ec2Client {
         input: DescribeElasticGpusRequest,
     ) -> Result<DescribeElasticGpusResult, RusotoError<DescribeElasticGpusError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeElasticGpus");
         let params = self.new_params("DescribeElasticGpus");
         let mut params = params;
         DescribeElasticGpusRequestSerializer::serialize(&mut params, "", &input);
@@ -96135,6 +96338,7 @@ impl Ec2 for Ec2Client {
         input: DescribeExportImageTasksRequest,
     ) -> Result<DescribeExportImageTasksResult, RusotoError<DescribeExportImageTasksError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeExportImageTasks");
         let params = self.new_params("DescribeExportImageTasks");
         let mut params = params;
         DescribeExportImageTasksRequestSerializer::serialize(&mut params, "", &input);
@@ -96161,6 +96365,7 @@ impl Ec2 for Ec2Client {
         input: DescribeExportTasksRequest,
     ) -> Result<DescribeExportTasksResult, RusotoError<DescribeExportTasksError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeExportTasks");
         let params = self.new_params("DescribeExportTasks");
         let mut params = params;
         DescribeExportTasksRequestSerializer::serialize(&mut params, "", &input);
@@ -96188,6 +96393,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeFastSnapshotRestoresResult, RusotoError<DescribeFastSnapshotRestoresError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeFastSnapshotRestores");
         let params = self.new_params("DescribeFastSnapshotRestores");
         let mut params = params;
         DescribeFastSnapshotRestoresRequestSerializer::serialize(&mut params, "", &input);
@@ -96214,6 +96420,7 @@ impl Ec2 for Ec2Client {
         input: DescribeFleetHistoryRequest,
     ) -> Result<DescribeFleetHistoryResult, RusotoError<DescribeFleetHistoryError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeFleetHistory");
         let params = self.new_params("DescribeFleetHistory");
         let mut params = params;
         DescribeFleetHistoryRequestSerializer::serialize(&mut params, "", &input);
@@ -96240,6 +96447,7 @@ impl Ec2 for Ec2Client {
         input: DescribeFleetInstancesRequest,
     ) -> Result<DescribeFleetInstancesResult, RusotoError<DescribeFleetInstancesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeFleetInstances");
         let params = self.new_params("DescribeFleetInstances");
         let mut params = params;
         DescribeFleetInstancesRequestSerializer::serialize(&mut params, "", &input);
@@ -96266,6 +96474,7 @@ impl Ec2 for Ec2Client {
         input: DescribeFleetsRequest,
     ) -> Result<DescribeFleetsResult, RusotoError<DescribeFleetsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeFleets");
         let params = self.new_params("DescribeFleets");
         let mut params = params;
         DescribeFleetsRequestSerializer::serialize(&mut params, "", &input);
@@ -96292,6 +96501,7 @@ impl Ec2 for Ec2Client {
         input: DescribeFlowLogsRequest,
     ) -> Result<DescribeFlowLogsResult, RusotoError<DescribeFlowLogsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeFlowLogs");
         let params = self.new_params("DescribeFlowLogs");
         let mut params = params;
         DescribeFlowLogsRequestSerializer::serialize(&mut params, "", &input);
@@ -96319,6 +96529,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeFpgaImageAttributeResult, RusotoError<DescribeFpgaImageAttributeError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeFpgaImageAttribute");
         let params = self.new_params("DescribeFpgaImageAttribute");
         let mut params = params;
         DescribeFpgaImageAttributeRequestSerializer::serialize(&mut params, "", &input);
@@ -96345,6 +96556,7 @@ impl Ec2 for Ec2Client {
         input: DescribeFpgaImagesRequest,
     ) -> Result<DescribeFpgaImagesResult, RusotoError<DescribeFpgaImagesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeFpgaImages");
         let params = self.new_params("DescribeFpgaImages");
         let mut params = params;
         DescribeFpgaImagesRequestSerializer::serialize(&mut params, "", &input);
@@ -96374,6 +96586,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeHostReservationOfferingsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeHostReservationOfferings");
         let params = self.new_params("DescribeHostReservationOfferings");
         let mut params = params;
         DescribeHostReservationOfferingsRequestSerializer::serialize(&mut params, "", &input);
@@ -96403,6 +96616,7 @@ impl Ec2 for Ec2Client {
         input: DescribeHostReservationsRequest,
     ) -> Result<DescribeHostReservationsResult, RusotoError<DescribeHostReservationsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeHostReservations");
         let params = self.new_params("DescribeHostReservations");
         let mut params = params;
         DescribeHostReservationsRequestSerializer::serialize(&mut params, "", &input);
@@ -96429,6 +96643,7 @@ impl Ec2 for Ec2Client {
         input: DescribeHostsRequest,
     ) -> Result<DescribeHostsResult, RusotoError<DescribeHostsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeHosts");
         let params = self.new_params("DescribeHosts");
         let mut params = params;
         DescribeHostsRequestSerializer::serialize(&mut params, "", &input);
@@ -96458,6 +96673,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeIamInstanceProfileAssociationsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeIamInstanceProfileAssociations");
         let params = self.new_params("DescribeIamInstanceProfileAssociations");
         let mut params = params;
         DescribeIamInstanceProfileAssociationsRequestSerializer::serialize(&mut params, "", &input);
@@ -96490,6 +96706,7 @@ impl Ec2 for Ec2Client {
         input: DescribeIdFormatRequest,
     ) -> Result<DescribeIdFormatResult, RusotoError<DescribeIdFormatError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeIdFormat");
         let params = self.new_params("DescribeIdFormat");
         let mut params = params;
         DescribeIdFormatRequestSerializer::serialize(&mut params, "", &input);
@@ -96516,6 +96733,7 @@ impl Ec2 for Ec2Client {
         input: DescribeIdentityIdFormatRequest,
     ) -> Result<DescribeIdentityIdFormatResult, RusotoError<DescribeIdentityIdFormatError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeIdentityIdFormat");
         let params = self.new_params("DescribeIdentityIdFormat");
         let mut params = params;
         DescribeIdentityIdFormatRequestSerializer::serialize(&mut params, "", &input);
@@ -96542,6 +96760,7 @@ impl Ec2 for Ec2Client {
         input: DescribeImageAttributeRequest,
     ) -> Result<ImageAttribute, RusotoError<DescribeImageAttributeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeImageAttribute");
         let params = self.new_params("DescribeImageAttribute");
         let mut params = params;
         DescribeImageAttributeRequestSerializer::serialize(&mut params, "", &input);
@@ -96568,6 +96787,7 @@ impl Ec2 for Ec2Client {
         input: DescribeImagesRequest,
     ) -> Result<DescribeImagesResult, RusotoError<DescribeImagesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeImages");
         let params = self.new_params("DescribeImages");
         let mut params = params;
         DescribeImagesRequestSerializer::serialize(&mut params, "", &input);
@@ -96594,6 +96814,7 @@ impl Ec2 for Ec2Client {
         input: DescribeImportImageTasksRequest,
     ) -> Result<DescribeImportImageTasksResult, RusotoError<DescribeImportImageTasksError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeImportImageTasks");
         let params = self.new_params("DescribeImportImageTasks");
         let mut params = params;
         DescribeImportImageTasksRequestSerializer::serialize(&mut params, "", &input);
@@ -96621,6 +96842,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeImportSnapshotTasksResult, RusotoError<DescribeImportSnapshotTasksError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeImportSnapshotTasks");
         let params = self.new_params("DescribeImportSnapshotTasks");
         let mut params = params;
         DescribeImportSnapshotTasksRequestSerializer::serialize(&mut params, "", &input);
@@ -96647,6 +96869,7 @@ impl Ec2 for Ec2Client {
         input: DescribeInstanceAttributeRequest,
     ) -> Result<InstanceAttribute, RusotoError<DescribeInstanceAttributeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeInstanceAttribute");
         let params = self.new_params("DescribeInstanceAttribute");
         let mut params = params;
         DescribeInstanceAttributeRequestSerializer::serialize(&mut params, "", &input);
@@ -96676,6 +96899,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeInstanceCreditSpecificationsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeInstanceCreditSpecifications");
         let params = self.new_params("DescribeInstanceCreditSpecifications");
         let mut params = params;
         DescribeInstanceCreditSpecificationsRequestSerializer::serialize(&mut params, "", &input);
@@ -96711,6 +96935,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeInstanceEventNotificationAttributesError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeInstanceEventNotificationAttributes");
         let params = self.new_params("DescribeInstanceEventNotificationAttributes");
         let mut params = params;
         DescribeInstanceEventNotificationAttributesRequestSerializer::serialize(
@@ -96747,6 +96972,7 @@ impl Ec2 for Ec2Client {
         input: DescribeInstanceStatusRequest,
     ) -> Result<DescribeInstanceStatusResult, RusotoError<DescribeInstanceStatusError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeInstanceStatus");
         let params = self.new_params("DescribeInstanceStatus");
         let mut params = params;
         DescribeInstanceStatusRequestSerializer::serialize(&mut params, "", &input);
@@ -96774,6 +97000,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeInstanceTypeOfferingsResult, RusotoError<DescribeInstanceTypeOfferingsError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeInstanceTypeOfferings");
         let params = self.new_params("DescribeInstanceTypeOfferings");
         let mut params = params;
         DescribeInstanceTypeOfferingsRequestSerializer::serialize(&mut params, "", &input);
@@ -96800,6 +97027,7 @@ impl Ec2 for Ec2Client {
         input: DescribeInstanceTypesRequest,
     ) -> Result<DescribeInstanceTypesResult, RusotoError<DescribeInstanceTypesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeInstanceTypes");
         let params = self.new_params("DescribeInstanceTypes");
         let mut params = params;
         DescribeInstanceTypesRequestSerializer::serialize(&mut params, "", &input);
@@ -96826,6 +97054,7 @@ impl Ec2 for Ec2Client {
         input: DescribeInstancesRequest,
     ) -> Result<DescribeInstancesResult, RusotoError<DescribeInstancesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeInstances");
         let params = self.new_params("DescribeInstances");
         let mut params = params;
         DescribeInstancesRequestSerializer::serialize(&mut params, "", &input);
@@ -96852,6 +97081,7 @@ impl Ec2 for Ec2Client {
         input: DescribeInternetGatewaysRequest,
     ) -> Result<DescribeInternetGatewaysResult, RusotoError<DescribeInternetGatewaysError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeInternetGateways");
         let params = self.new_params("DescribeInternetGateways");
         let mut params = params;
         DescribeInternetGatewaysRequestSerializer::serialize(&mut params, "", &input);
@@ -96878,6 +97108,7 @@ impl Ec2 for Ec2Client {
         input: DescribeIpv6PoolsRequest,
     ) -> Result<DescribeIpv6PoolsResult, RusotoError<DescribeIpv6PoolsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeIpv6Pools");
         let params = self.new_params("DescribeIpv6Pools");
         let mut params = params;
         DescribeIpv6PoolsRequestSerializer::serialize(&mut params, "", &input);
@@ -96904,6 +97135,7 @@ impl Ec2 for Ec2Client {
         input: DescribeKeyPairsRequest,
     ) -> Result<DescribeKeyPairsResult, RusotoError<DescribeKeyPairsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeKeyPairs");
         let params = self.new_params("DescribeKeyPairs");
         let mut params = params;
         DescribeKeyPairsRequestSerializer::serialize(&mut params, "", &input);
@@ -96933,6 +97165,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeLaunchTemplateVersionsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeLaunchTemplateVersions");
         let params = self.new_params("DescribeLaunchTemplateVersions");
         let mut params = params;
         DescribeLaunchTemplateVersionsRequestSerializer::serialize(&mut params, "", &input);
@@ -96959,6 +97192,7 @@ impl Ec2 for Ec2Client {
         input: DescribeLaunchTemplatesRequest,
     ) -> Result<DescribeLaunchTemplatesResult, RusotoError<DescribeLaunchTemplatesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeLaunchTemplates");
         let params = self.new_params("DescribeLaunchTemplates");
         let mut params = params;
         DescribeLaunchTemplatesRequestSerializer::serialize(&mut params, "", &input);
@@ -96988,6 +97222,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeLocalGatewayRouteTableVirtualInterfaceGroupAssociationsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeLocalGatewayRouteTableVirtualInterfaceGroupAssociations");
         let params =
             self.new_params("DescribeLocalGatewayRouteTableVirtualInterfaceGroupAssociations");
         let mut params = params;
@@ -97022,6 +97257,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeLocalGatewayRouteTableVpcAssociationsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeLocalGatewayRouteTableVpcAssociations");
         let params = self.new_params("DescribeLocalGatewayRouteTableVpcAssociations");
         let mut params = params;
         DescribeLocalGatewayRouteTableVpcAssociationsRequestSerializer::serialize(
@@ -97061,6 +97297,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeLocalGatewayRouteTablesError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeLocalGatewayRouteTables");
         let params = self.new_params("DescribeLocalGatewayRouteTables");
         let mut params = params;
         DescribeLocalGatewayRouteTablesRequestSerializer::serialize(&mut params, "", &input);
@@ -97090,6 +97327,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeLocalGatewayVirtualInterfaceGroupsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeLocalGatewayVirtualInterfaceGroups");
         let params = self.new_params("DescribeLocalGatewayVirtualInterfaceGroups");
         let mut params = params;
         DescribeLocalGatewayVirtualInterfaceGroupsRequestSerializer::serialize(
@@ -97129,6 +97367,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeLocalGatewayVirtualInterfacesError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeLocalGatewayVirtualInterfaces");
         let params = self.new_params("DescribeLocalGatewayVirtualInterfaces");
         let mut params = params;
         DescribeLocalGatewayVirtualInterfacesRequestSerializer::serialize(&mut params, "", &input);
@@ -97161,6 +97400,7 @@ impl Ec2 for Ec2Client {
         input: DescribeLocalGatewaysRequest,
     ) -> Result<DescribeLocalGatewaysResult, RusotoError<DescribeLocalGatewaysError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeLocalGateways");
         let params = self.new_params("DescribeLocalGateways");
         let mut params = params;
         DescribeLocalGatewaysRequestSerializer::serialize(&mut params, "", &input);
@@ -97188,6 +97428,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeManagedPrefixListsResult, RusotoError<DescribeManagedPrefixListsError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeManagedPrefixLists");
         let params = self.new_params("DescribeManagedPrefixLists");
         let mut params = params;
         DescribeManagedPrefixListsRequestSerializer::serialize(&mut params, "", &input);
@@ -97214,6 +97455,7 @@ impl Ec2 for Ec2Client {
         input: DescribeMovingAddressesRequest,
     ) -> Result<DescribeMovingAddressesResult, RusotoError<DescribeMovingAddressesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeMovingAddresses");
         let params = self.new_params("DescribeMovingAddresses");
         let mut params = params;
         DescribeMovingAddressesRequestSerializer::serialize(&mut params, "", &input);
@@ -97240,6 +97482,7 @@ impl Ec2 for Ec2Client {
         input: DescribeNatGatewaysRequest,
     ) -> Result<DescribeNatGatewaysResult, RusotoError<DescribeNatGatewaysError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeNatGateways");
         let params = self.new_params("DescribeNatGateways");
         let mut params = params;
         DescribeNatGatewaysRequestSerializer::serialize(&mut params, "", &input);
@@ -97266,6 +97509,7 @@ impl Ec2 for Ec2Client {
         input: DescribeNetworkAclsRequest,
     ) -> Result<DescribeNetworkAclsResult, RusotoError<DescribeNetworkAclsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeNetworkAcls");
         let params = self.new_params("DescribeNetworkAcls");
         let mut params = params;
         DescribeNetworkAclsRequestSerializer::serialize(&mut params, "", &input);
@@ -97295,6 +97539,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeNetworkInsightsAnalysesError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeNetworkInsightsAnalyses");
         let params = self.new_params("DescribeNetworkInsightsAnalyses");
         let mut params = params;
         DescribeNetworkInsightsAnalysesRequestSerializer::serialize(&mut params, "", &input);
@@ -97322,6 +97567,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeNetworkInsightsPathsResult, RusotoError<DescribeNetworkInsightsPathsError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeNetworkInsightsPaths");
         let params = self.new_params("DescribeNetworkInsightsPaths");
         let mut params = params;
         DescribeNetworkInsightsPathsRequestSerializer::serialize(&mut params, "", &input);
@@ -97351,6 +97597,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeNetworkInterfaceAttributeError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeNetworkInterfaceAttribute");
         let params = self.new_params("DescribeNetworkInterfaceAttribute");
         let mut params = params;
         DescribeNetworkInterfaceAttributeRequestSerializer::serialize(&mut params, "", &input);
@@ -97383,6 +97630,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeNetworkInterfacePermissionsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeNetworkInterfacePermissions");
         let params = self.new_params("DescribeNetworkInterfacePermissions");
         let mut params = params;
         DescribeNetworkInterfacePermissionsRequestSerializer::serialize(&mut params, "", &input);
@@ -97415,6 +97663,7 @@ impl Ec2 for Ec2Client {
         input: DescribeNetworkInterfacesRequest,
     ) -> Result<DescribeNetworkInterfacesResult, RusotoError<DescribeNetworkInterfacesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeNetworkInterfaces");
         let params = self.new_params("DescribeNetworkInterfaces");
         let mut params = params;
         DescribeNetworkInterfacesRequestSerializer::serialize(&mut params, "", &input);
@@ -97441,6 +97690,7 @@ impl Ec2 for Ec2Client {
         input: DescribePlacementGroupsRequest,
     ) -> Result<DescribePlacementGroupsResult, RusotoError<DescribePlacementGroupsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribePlacementGroups");
         let params = self.new_params("DescribePlacementGroups");
         let mut params = params;
         DescribePlacementGroupsRequestSerializer::serialize(&mut params, "", &input);
@@ -97467,6 +97717,7 @@ impl Ec2 for Ec2Client {
         input: DescribePrefixListsRequest,
     ) -> Result<DescribePrefixListsResult, RusotoError<DescribePrefixListsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribePrefixLists");
         let params = self.new_params("DescribePrefixLists");
         let mut params = params;
         DescribePrefixListsRequestSerializer::serialize(&mut params, "", &input);
@@ -97493,6 +97744,7 @@ impl Ec2 for Ec2Client {
         input: DescribePrincipalIdFormatRequest,
     ) -> Result<DescribePrincipalIdFormatResult, RusotoError<DescribePrincipalIdFormatError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribePrincipalIdFormat");
         let params = self.new_params("DescribePrincipalIdFormat");
         let mut params = params;
         DescribePrincipalIdFormatRequestSerializer::serialize(&mut params, "", &input);
@@ -97519,6 +97771,7 @@ impl Ec2 for Ec2Client {
         input: DescribePublicIpv4PoolsRequest,
     ) -> Result<DescribePublicIpv4PoolsResult, RusotoError<DescribePublicIpv4PoolsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribePublicIpv4Pools");
         let params = self.new_params("DescribePublicIpv4Pools");
         let mut params = params;
         DescribePublicIpv4PoolsRequestSerializer::serialize(&mut params, "", &input);
@@ -97545,6 +97798,7 @@ impl Ec2 for Ec2Client {
         input: DescribeRegionsRequest,
     ) -> Result<DescribeRegionsResult, RusotoError<DescribeRegionsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeRegions");
         let params = self.new_params("DescribeRegions");
         let mut params = params;
         DescribeRegionsRequestSerializer::serialize(&mut params, "", &input);
@@ -97571,6 +97825,7 @@ impl Ec2 for Ec2Client {
         input: DescribeReservedInstancesRequest,
     ) -> Result<DescribeReservedInstancesResult, RusotoError<DescribeReservedInstancesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeReservedInstances");
         let params = self.new_params("DescribeReservedInstances");
         let mut params = params;
         DescribeReservedInstancesRequestSerializer::serialize(&mut params, "", &input);
@@ -97600,6 +97855,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeReservedInstancesListingsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeReservedInstancesListings");
         let params = self.new_params("DescribeReservedInstancesListings");
         let mut params = params;
         DescribeReservedInstancesListingsRequestSerializer::serialize(&mut params, "", &input);
@@ -97632,6 +97888,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeReservedInstancesModificationsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeReservedInstancesModifications");
         let params = self.new_params("DescribeReservedInstancesModifications");
         let mut params = params;
         DescribeReservedInstancesModificationsRequestSerializer::serialize(&mut params, "", &input);
@@ -97667,6 +97924,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeReservedInstancesOfferingsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeReservedInstancesOfferings");
         let params = self.new_params("DescribeReservedInstancesOfferings");
         let mut params = params;
         DescribeReservedInstancesOfferingsRequestSerializer::serialize(&mut params, "", &input);
@@ -97699,6 +97957,7 @@ impl Ec2 for Ec2Client {
         input: DescribeRouteTablesRequest,
     ) -> Result<DescribeRouteTablesResult, RusotoError<DescribeRouteTablesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeRouteTables");
         let params = self.new_params("DescribeRouteTables");
         let mut params = params;
         DescribeRouteTablesRequestSerializer::serialize(&mut params, "", &input);
@@ -97728,6 +97987,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeScheduledInstanceAvailabilityError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeScheduledInstanceAvailability");
         let params = self.new_params("DescribeScheduledInstanceAvailability");
         let mut params = params;
         DescribeScheduledInstanceAvailabilityRequestSerializer::serialize(&mut params, "", &input);
@@ -97761,6 +98021,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeScheduledInstancesResult, RusotoError<DescribeScheduledInstancesError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeScheduledInstances");
         let params = self.new_params("DescribeScheduledInstances");
         let mut params = params;
         DescribeScheduledInstancesRequestSerializer::serialize(&mut params, "", &input);
@@ -97790,6 +98051,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeSecurityGroupReferencesError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeSecurityGroupReferences");
         let params = self.new_params("DescribeSecurityGroupReferences");
         let mut params = params;
         DescribeSecurityGroupReferencesRequestSerializer::serialize(&mut params, "", &input);
@@ -97816,6 +98078,7 @@ impl Ec2 for Ec2Client {
         input: DescribeSecurityGroupsRequest,
     ) -> Result<DescribeSecurityGroupsResult, RusotoError<DescribeSecurityGroupsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeSecurityGroups");
         let params = self.new_params("DescribeSecurityGroups");
         let mut params = params;
         DescribeSecurityGroupsRequestSerializer::serialize(&mut params, "", &input);
@@ -97842,6 +98105,7 @@ impl Ec2 for Ec2Client {
         input: DescribeSnapshotAttributeRequest,
     ) -> Result<DescribeSnapshotAttributeResult, RusotoError<DescribeSnapshotAttributeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeSnapshotAttribute");
         let params = self.new_params("DescribeSnapshotAttribute");
         let mut params = params;
         DescribeSnapshotAttributeRequestSerializer::serialize(&mut params, "", &input);
@@ -97868,6 +98132,7 @@ impl Ec2 for Ec2Client {
         input: DescribeSnapshotsRequest,
     ) -> Result<DescribeSnapshotsResult, RusotoError<DescribeSnapshotsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeSnapshots");
         let params = self.new_params("DescribeSnapshots");
         let mut params = params;
         DescribeSnapshotsRequestSerializer::serialize(&mut params, "", &input);
@@ -97897,6 +98162,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeSpotDatafeedSubscriptionError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeSpotDatafeedSubscription");
         let params = self.new_params("DescribeSpotDatafeedSubscription");
         let mut params = params;
         DescribeSpotDatafeedSubscriptionRequestSerializer::serialize(&mut params, "", &input);
@@ -97927,6 +98193,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeSpotFleetInstancesResponse, RusotoError<DescribeSpotFleetInstancesError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeSpotFleetInstances");
         let params = self.new_params("DescribeSpotFleetInstances");
         let mut params = params;
         DescribeSpotFleetInstancesRequestSerializer::serialize(&mut params, "", &input);
@@ -97956,6 +98223,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeSpotFleetRequestHistoryError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeSpotFleetRequestHistory");
         let params = self.new_params("DescribeSpotFleetRequestHistory");
         let mut params = params;
         DescribeSpotFleetRequestHistoryRequestSerializer::serialize(&mut params, "", &input);
@@ -97983,6 +98251,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeSpotFleetRequestsResponse, RusotoError<DescribeSpotFleetRequestsError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeSpotFleetRequests");
         let params = self.new_params("DescribeSpotFleetRequests");
         let mut params = params;
         DescribeSpotFleetRequestsRequestSerializer::serialize(&mut params, "", &input);
@@ -98010,6 +98279,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeSpotInstanceRequestsResult, RusotoError<DescribeSpotInstanceRequestsError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeSpotInstanceRequests");
         let params = self.new_params("DescribeSpotInstanceRequests");
         let mut params = params;
         DescribeSpotInstanceRequestsRequestSerializer::serialize(&mut params, "", &input);
@@ -98036,6 +98306,7 @@ impl Ec2 for Ec2Client {
         input: DescribeSpotPriceHistoryRequest,
     ) -> Result<DescribeSpotPriceHistoryResult, RusotoError<DescribeSpotPriceHistoryError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeSpotPriceHistory");
         let params = self.new_params("DescribeSpotPriceHistory");
         let mut params = params;
         DescribeSpotPriceHistoryRequestSerializer::serialize(&mut params, "", &input);
@@ -98063,6 +98334,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeStaleSecurityGroupsResult, RusotoError<DescribeStaleSecurityGroupsError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeStaleSecurityGroups");
         let params = self.new_params("DescribeStaleSecurityGroups");
         let mut params = params;
         DescribeStaleSecurityGroupsRequestSerializer::serialize(&mut params, "", &input);
@@ -98089,6 +98361,7 @@ impl Ec2 for Ec2Client {
         input: DescribeSubnetsRequest,
     ) -> Result<DescribeSubnetsResult, RusotoError<DescribeSubnetsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeSubnets");
         let params = self.new_params("DescribeSubnets");
         let mut params = params;
         DescribeSubnetsRequestSerializer::serialize(&mut params, "", &input);
@@ -98115,6 +98388,7 @@ impl Ec2 for Ec2Client {
         input: DescribeTagsRequest,
     ) -> Result<DescribeTagsResult, RusotoError<DescribeTagsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeTags");
         let params = self.new_params("DescribeTags");
         let mut params = params;
         DescribeTagsRequestSerializer::serialize(&mut params, "", &input);
@@ -98142,6 +98416,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeTrafficMirrorFiltersResult, RusotoError<DescribeTrafficMirrorFiltersError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeTrafficMirrorFilters");
         let params = self.new_params("DescribeTrafficMirrorFilters");
         let mut params = params;
         DescribeTrafficMirrorFiltersRequestSerializer::serialize(&mut params, "", &input);
@@ -98169,6 +98444,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeTrafficMirrorSessionsResult, RusotoError<DescribeTrafficMirrorSessionsError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeTrafficMirrorSessions");
         let params = self.new_params("DescribeTrafficMirrorSessions");
         let mut params = params;
         DescribeTrafficMirrorSessionsRequestSerializer::serialize(&mut params, "", &input);
@@ -98196,6 +98472,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeTrafficMirrorTargetsResult, RusotoError<DescribeTrafficMirrorTargetsError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeTrafficMirrorTargets");
         let params = self.new_params("DescribeTrafficMirrorTargets");
         let mut params = params;
         DescribeTrafficMirrorTargetsRequestSerializer::serialize(&mut params, "", &input);
@@ -98225,6 +98502,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeTransitGatewayAttachmentsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeTransitGatewayAttachments");
         let params = self.new_params("DescribeTransitGatewayAttachments");
         let mut params = params;
         DescribeTransitGatewayAttachmentsRequestSerializer::serialize(&mut params, "", &input);
@@ -98257,6 +98535,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeTransitGatewayConnectPeersError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeTransitGatewayConnectPeers");
         let params = self.new_params("DescribeTransitGatewayConnectPeers");
         let mut params = params;
         DescribeTransitGatewayConnectPeersRequestSerializer::serialize(&mut params, "", &input);
@@ -98292,6 +98571,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeTransitGatewayConnectsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeTransitGatewayConnects");
         let params = self.new_params("DescribeTransitGatewayConnects");
         let mut params = params;
         DescribeTransitGatewayConnectsRequestSerializer::serialize(&mut params, "", &input);
@@ -98321,6 +98601,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeTransitGatewayMulticastDomainsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeTransitGatewayMulticastDomains");
         let params = self.new_params("DescribeTransitGatewayMulticastDomains");
         let mut params = params;
         DescribeTransitGatewayMulticastDomainsRequestSerializer::serialize(&mut params, "", &input);
@@ -98356,6 +98637,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeTransitGatewayPeeringAttachmentsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeTransitGatewayPeeringAttachments");
         let params = self.new_params("DescribeTransitGatewayPeeringAttachments");
         let mut params = params;
         DescribeTransitGatewayPeeringAttachmentsRequestSerializer::serialize(
@@ -98395,6 +98677,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeTransitGatewayRouteTablesError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeTransitGatewayRouteTables");
         let params = self.new_params("DescribeTransitGatewayRouteTables");
         let mut params = params;
         DescribeTransitGatewayRouteTablesRequestSerializer::serialize(&mut params, "", &input);
@@ -98427,6 +98710,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeTransitGatewayVpcAttachmentsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeTransitGatewayVpcAttachments");
         let params = self.new_params("DescribeTransitGatewayVpcAttachments");
         let mut params = params;
         DescribeTransitGatewayVpcAttachmentsRequestSerializer::serialize(&mut params, "", &input);
@@ -98459,6 +98743,7 @@ impl Ec2 for Ec2Client {
         input: DescribeTransitGatewaysRequest,
     ) -> Result<DescribeTransitGatewaysResult, RusotoError<DescribeTransitGatewaysError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeTransitGateways");
         let params = self.new_params("DescribeTransitGateways");
         let mut params = params;
         DescribeTransitGatewaysRequestSerializer::serialize(&mut params, "", &input);
@@ -98485,6 +98770,7 @@ impl Ec2 for Ec2Client {
         input: DescribeVolumeAttributeRequest,
     ) -> Result<DescribeVolumeAttributeResult, RusotoError<DescribeVolumeAttributeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeVolumeAttribute");
         let params = self.new_params("DescribeVolumeAttribute");
         let mut params = params;
         DescribeVolumeAttributeRequestSerializer::serialize(&mut params, "", &input);
@@ -98511,6 +98797,7 @@ impl Ec2 for Ec2Client {
         input: DescribeVolumeStatusRequest,
     ) -> Result<DescribeVolumeStatusResult, RusotoError<DescribeVolumeStatusError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeVolumeStatus");
         let params = self.new_params("DescribeVolumeStatus");
         let mut params = params;
         DescribeVolumeStatusRequestSerializer::serialize(&mut params, "", &input);
@@ -98537,6 +98824,7 @@ impl Ec2 for Ec2Client {
         input: DescribeVolumesRequest,
     ) -> Result<DescribeVolumesResult, RusotoError<DescribeVolumesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeVolumes");
         let params = self.new_params("DescribeVolumes");
         let mut params = params;
         DescribeVolumesRequestSerializer::serialize(&mut params, "", &input);
@@ -98564,6 +98852,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeVolumesModificationsResult, RusotoError<DescribeVolumesModificationsError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeVolumesModifications");
         let params = self.new_params("DescribeVolumesModifications");
         let mut params = params;
         DescribeVolumesModificationsRequestSerializer::serialize(&mut params, "", &input);
@@ -98590,6 +98879,7 @@ impl Ec2 for Ec2Client {
         input: DescribeVpcAttributeRequest,
     ) -> Result<DescribeVpcAttributeResult, RusotoError<DescribeVpcAttributeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeVpcAttribute");
         let params = self.new_params("DescribeVpcAttribute");
         let mut params = params;
         DescribeVpcAttributeRequestSerializer::serialize(&mut params, "", &input);
@@ -98616,6 +98906,7 @@ impl Ec2 for Ec2Client {
         input: DescribeVpcClassicLinkRequest,
     ) -> Result<DescribeVpcClassicLinkResult, RusotoError<DescribeVpcClassicLinkError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeVpcClassicLink");
         let params = self.new_params("DescribeVpcClassicLink");
         let mut params = params;
         DescribeVpcClassicLinkRequestSerializer::serialize(&mut params, "", &input);
@@ -98645,6 +98936,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeVpcClassicLinkDnsSupportError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeVpcClassicLinkDnsSupport");
         let params = self.new_params("DescribeVpcClassicLinkDnsSupport");
         let mut params = params;
         DescribeVpcClassicLinkDnsSupportRequestSerializer::serialize(&mut params, "", &input);
@@ -98677,6 +98969,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeVpcEndpointConnectionNotificationsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeVpcEndpointConnectionNotifications");
         let params = self.new_params("DescribeVpcEndpointConnectionNotifications");
         let mut params = params;
         DescribeVpcEndpointConnectionNotificationsRequestSerializer::serialize(
@@ -98716,6 +99009,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeVpcEndpointConnectionsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeVpcEndpointConnections");
         let params = self.new_params("DescribeVpcEndpointConnections");
         let mut params = params;
         DescribeVpcEndpointConnectionsRequestSerializer::serialize(&mut params, "", &input);
@@ -98745,6 +99039,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeVpcEndpointServiceConfigurationsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeVpcEndpointServiceConfigurations");
         let params = self.new_params("DescribeVpcEndpointServiceConfigurations");
         let mut params = params;
         DescribeVpcEndpointServiceConfigurationsRequestSerializer::serialize(
@@ -98784,6 +99079,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DescribeVpcEndpointServicePermissionsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeVpcEndpointServicePermissions");
         let params = self.new_params("DescribeVpcEndpointServicePermissions");
         let mut params = params;
         DescribeVpcEndpointServicePermissionsRequestSerializer::serialize(&mut params, "", &input);
@@ -98817,6 +99113,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeVpcEndpointServicesResult, RusotoError<DescribeVpcEndpointServicesError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeVpcEndpointServices");
         let params = self.new_params("DescribeVpcEndpointServices");
         let mut params = params;
         DescribeVpcEndpointServicesRequestSerializer::serialize(&mut params, "", &input);
@@ -98843,6 +99140,7 @@ impl Ec2 for Ec2Client {
         input: DescribeVpcEndpointsRequest,
     ) -> Result<DescribeVpcEndpointsResult, RusotoError<DescribeVpcEndpointsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeVpcEndpoints");
         let params = self.new_params("DescribeVpcEndpoints");
         let mut params = params;
         DescribeVpcEndpointsRequestSerializer::serialize(&mut params, "", &input);
@@ -98870,6 +99168,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<DescribeVpcPeeringConnectionsResult, RusotoError<DescribeVpcPeeringConnectionsError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeVpcPeeringConnections");
         let params = self.new_params("DescribeVpcPeeringConnections");
         let mut params = params;
         DescribeVpcPeeringConnectionsRequestSerializer::serialize(&mut params, "", &input);
@@ -98896,6 +99195,7 @@ impl Ec2 for Ec2Client {
         input: DescribeVpcsRequest,
     ) -> Result<DescribeVpcsResult, RusotoError<DescribeVpcsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeVpcs");
         let params = self.new_params("DescribeVpcs");
         let mut params = params;
         DescribeVpcsRequestSerializer::serialize(&mut params, "", &input);
@@ -98922,6 +99222,7 @@ impl Ec2 for Ec2Client {
         input: DescribeVpnConnectionsRequest,
     ) -> Result<DescribeVpnConnectionsResult, RusotoError<DescribeVpnConnectionsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeVpnConnections");
         let params = self.new_params("DescribeVpnConnections");
         let mut params = params;
         DescribeVpnConnectionsRequestSerializer::serialize(&mut params, "", &input);
@@ -98948,6 +99249,7 @@ impl Ec2 for Ec2Client {
         input: DescribeVpnGatewaysRequest,
     ) -> Result<DescribeVpnGatewaysResult, RusotoError<DescribeVpnGatewaysError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DescribeVpnGateways");
         let params = self.new_params("DescribeVpnGateways");
         let mut params = params;
         DescribeVpnGatewaysRequestSerializer::serialize(&mut params, "", &input);
@@ -98974,6 +99276,7 @@ impl Ec2 for Ec2Client {
         input: DetachClassicLinkVpcRequest,
     ) -> Result<DetachClassicLinkVpcResult, RusotoError<DetachClassicLinkVpcError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DetachClassicLinkVpc");
         let params = self.new_params("DetachClassicLinkVpc");
         let mut params = params;
         DetachClassicLinkVpcRequestSerializer::serialize(&mut params, "", &input);
@@ -99000,6 +99303,7 @@ impl Ec2 for Ec2Client {
         input: DetachInternetGatewayRequest,
     ) -> Result<(), RusotoError<DetachInternetGatewayError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DetachInternetGateway");
         let params = self.new_params("DetachInternetGateway");
         let mut params = params;
         DetachInternetGatewayRequestSerializer::serialize(&mut params, "", &input);
@@ -99020,6 +99324,7 @@ impl Ec2 for Ec2Client {
         input: DetachNetworkInterfaceRequest,
     ) -> Result<(), RusotoError<DetachNetworkInterfaceError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DetachNetworkInterface");
         let params = self.new_params("DetachNetworkInterface");
         let mut params = params;
         DetachNetworkInterfaceRequestSerializer::serialize(&mut params, "", &input);
@@ -99040,6 +99345,7 @@ impl Ec2 for Ec2Client {
         input: DetachVolumeRequest,
     ) -> Result<VolumeAttachment, RusotoError<DetachVolumeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DetachVolume");
         let params = self.new_params("DetachVolume");
         let mut params = params;
         DetachVolumeRequestSerializer::serialize(&mut params, "", &input);
@@ -99066,6 +99372,7 @@ impl Ec2 for Ec2Client {
         input: DetachVpnGatewayRequest,
     ) -> Result<(), RusotoError<DetachVpnGatewayError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DetachVpnGateway");
         let params = self.new_params("DetachVpnGateway");
         let mut params = params;
         DetachVpnGatewayRequestSerializer::serialize(&mut params, "", &input);
@@ -99087,6 +99394,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<DisableEbsEncryptionByDefaultResult, RusotoError<DisableEbsEncryptionByDefaultError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DisableEbsEncryptionByDefault");
         let params = self.new_params("DisableEbsEncryptionByDefault");
         let mut params = params;
         DisableEbsEncryptionByDefaultRequestSerializer::serialize(&mut params, "", &input);
@@ -99114,6 +99422,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<DisableFastSnapshotRestoresResult, RusotoError<DisableFastSnapshotRestoresError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DisableFastSnapshotRestores");
         let params = self.new_params("DisableFastSnapshotRestores");
         let mut params = params;
         DisableFastSnapshotRestoresRequestSerializer::serialize(&mut params, "", &input);
@@ -99143,6 +99452,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DisableTransitGatewayRouteTablePropagationError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DisableTransitGatewayRouteTablePropagation");
         let params = self.new_params("DisableTransitGatewayRouteTablePropagation");
         let mut params = params;
         DisableTransitGatewayRouteTablePropagationRequestSerializer::serialize(
@@ -99179,6 +99489,7 @@ impl Ec2 for Ec2Client {
         input: DisableVgwRoutePropagationRequest,
     ) -> Result<(), RusotoError<DisableVgwRoutePropagationError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DisableVgwRoutePropagation");
         let params = self.new_params("DisableVgwRoutePropagation");
         let mut params = params;
         DisableVgwRoutePropagationRequestSerializer::serialize(&mut params, "", &input);
@@ -99199,6 +99510,7 @@ impl Ec2 for Ec2Client {
         input: DisableVpcClassicLinkRequest,
     ) -> Result<DisableVpcClassicLinkResult, RusotoError<DisableVpcClassicLinkError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DisableVpcClassicLink");
         let params = self.new_params("DisableVpcClassicLink");
         let mut params = params;
         DisableVpcClassicLinkRequestSerializer::serialize(&mut params, "", &input);
@@ -99228,6 +99540,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DisableVpcClassicLinkDnsSupportError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DisableVpcClassicLinkDnsSupport");
         let params = self.new_params("DisableVpcClassicLinkDnsSupport");
         let mut params = params;
         DisableVpcClassicLinkDnsSupportRequestSerializer::serialize(&mut params, "", &input);
@@ -99254,6 +99567,7 @@ impl Ec2 for Ec2Client {
         input: DisassociateAddressRequest,
     ) -> Result<(), RusotoError<DisassociateAddressError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DisassociateAddress");
         let params = self.new_params("DisassociateAddress");
         let mut params = params;
         DisassociateAddressRequestSerializer::serialize(&mut params, "", &input);
@@ -99277,6 +99591,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DisassociateClientVpnTargetNetworkError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DisassociateClientVpnTargetNetwork");
         let params = self.new_params("DisassociateClientVpnTargetNetwork");
         let mut params = params;
         DisassociateClientVpnTargetNetworkRequestSerializer::serialize(&mut params, "", &input);
@@ -99312,6 +99627,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DisassociateEnclaveCertificateIamRoleError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DisassociateEnclaveCertificateIamRole");
         let params = self.new_params("DisassociateEnclaveCertificateIamRole");
         let mut params = params;
         DisassociateEnclaveCertificateIamRoleRequestSerializer::serialize(&mut params, "", &input);
@@ -99347,6 +99663,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DisassociateIamInstanceProfileError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DisassociateIamInstanceProfile");
         let params = self.new_params("DisassociateIamInstanceProfile");
         let mut params = params;
         DisassociateIamInstanceProfileRequestSerializer::serialize(&mut params, "", &input);
@@ -99373,6 +99690,7 @@ impl Ec2 for Ec2Client {
         input: DisassociateRouteTableRequest,
     ) -> Result<(), RusotoError<DisassociateRouteTableError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DisassociateRouteTable");
         let params = self.new_params("DisassociateRouteTable");
         let mut params = params;
         DisassociateRouteTableRequestSerializer::serialize(&mut params, "", &input);
@@ -99394,6 +99712,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<DisassociateSubnetCidrBlockResult, RusotoError<DisassociateSubnetCidrBlockError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DisassociateSubnetCidrBlock");
         let params = self.new_params("DisassociateSubnetCidrBlock");
         let mut params = params;
         DisassociateSubnetCidrBlockRequestSerializer::serialize(&mut params, "", &input);
@@ -99423,6 +99742,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DisassociateTransitGatewayMulticastDomainError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DisassociateTransitGatewayMulticastDomain");
         let params = self.new_params("DisassociateTransitGatewayMulticastDomain");
         let mut params = params;
         DisassociateTransitGatewayMulticastDomainRequestSerializer::serialize(
@@ -99462,6 +99782,7 @@ impl Ec2 for Ec2Client {
         RusotoError<DisassociateTransitGatewayRouteTableError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DisassociateTransitGatewayRouteTable");
         let params = self.new_params("DisassociateTransitGatewayRouteTable");
         let mut params = params;
         DisassociateTransitGatewayRouteTableRequestSerializer::serialize(&mut params, "", &input);
@@ -99494,6 +99815,7 @@ impl Ec2 for Ec2Client {
         input: DisassociateVpcCidrBlockRequest,
     ) -> Result<DisassociateVpcCidrBlockResult, RusotoError<DisassociateVpcCidrBlockError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("DisassociateVpcCidrBlock");
         let params = self.new_params("DisassociateVpcCidrBlock");
         let mut params = params;
         DisassociateVpcCidrBlockRequestSerializer::serialize(&mut params, "", &input);
@@ -99521,6 +99843,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<EnableEbsEncryptionByDefaultResult, RusotoError<EnableEbsEncryptionByDefaultError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("EnableEbsEncryptionByDefault");
         let params = self.new_params("EnableEbsEncryptionByDefault");
         let mut params = params;
         EnableEbsEncryptionByDefaultRequestSerializer::serialize(&mut params, "", &input);
@@ -99548,6 +99871,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<EnableFastSnapshotRestoresResult, RusotoError<EnableFastSnapshotRestoresError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("EnableFastSnapshotRestores");
         let params = self.new_params("EnableFastSnapshotRestores");
         let mut params = params;
         EnableFastSnapshotRestoresRequestSerializer::serialize(&mut params, "", &input);
@@ -99577,6 +99901,7 @@ impl Ec2 for Ec2Client {
         RusotoError<EnableTransitGatewayRouteTablePropagationError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("EnableTransitGatewayRouteTablePropagation");
         let params = self.new_params("EnableTransitGatewayRouteTablePropagation");
         let mut params = params;
         EnableTransitGatewayRouteTablePropagationRequestSerializer::serialize(
@@ -99613,6 +99938,7 @@ impl Ec2 for Ec2Client {
         input: EnableVgwRoutePropagationRequest,
     ) -> Result<(), RusotoError<EnableVgwRoutePropagationError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("EnableVgwRoutePropagation");
         let params = self.new_params("EnableVgwRoutePropagation");
         let mut params = params;
         EnableVgwRoutePropagationRequestSerializer::serialize(&mut params, "", &input);
@@ -99633,6 +99959,7 @@ impl Ec2 for Ec2Client {
         input: EnableVolumeIORequest,
     ) -> Result<(), RusotoError<EnableVolumeIOError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("EnableVolumeIO");
         let params = self.new_params("EnableVolumeIO");
         let mut params = params;
         EnableVolumeIORequestSerializer::serialize(&mut params, "", &input);
@@ -99653,6 +99980,7 @@ impl Ec2 for Ec2Client {
         input: EnableVpcClassicLinkRequest,
     ) -> Result<EnableVpcClassicLinkResult, RusotoError<EnableVpcClassicLinkError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("EnableVpcClassicLink");
         let params = self.new_params("EnableVpcClassicLink");
         let mut params = params;
         EnableVpcClassicLinkRequestSerializer::serialize(&mut params, "", &input);
@@ -99682,6 +100010,7 @@ impl Ec2 for Ec2Client {
         RusotoError<EnableVpcClassicLinkDnsSupportError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("EnableVpcClassicLinkDnsSupport");
         let params = self.new_params("EnableVpcClassicLinkDnsSupport");
         let mut params = params;
         EnableVpcClassicLinkDnsSupportRequestSerializer::serialize(&mut params, "", &input);
@@ -99711,6 +100040,7 @@ impl Ec2 for Ec2Client {
         RusotoError<ExportClientVpnClientCertificateRevocationListError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ExportClientVpnClientCertificateRevocationList");
         let params = self.new_params("ExportClientVpnClientCertificateRevocationList");
         let mut params = params;
         ExportClientVpnClientCertificateRevocationListRequestSerializer::serialize(
@@ -99750,6 +100080,7 @@ impl Ec2 for Ec2Client {
         RusotoError<ExportClientVpnClientConfigurationError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ExportClientVpnClientConfiguration");
         let params = self.new_params("ExportClientVpnClientConfiguration");
         let mut params = params;
         ExportClientVpnClientConfigurationRequestSerializer::serialize(&mut params, "", &input);
@@ -99782,6 +100113,7 @@ impl Ec2 for Ec2Client {
         input: ExportImageRequest,
     ) -> Result<ExportImageResult, RusotoError<ExportImageError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ExportImage");
         let params = self.new_params("ExportImage");
         let mut params = params;
         ExportImageRequestSerializer::serialize(&mut params, "", &input);
@@ -99809,6 +100141,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<ExportTransitGatewayRoutesResult, RusotoError<ExportTransitGatewayRoutesError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ExportTransitGatewayRoutes");
         let params = self.new_params("ExportTransitGatewayRoutes");
         let mut params = params;
         ExportTransitGatewayRoutesRequestSerializer::serialize(&mut params, "", &input);
@@ -99838,6 +100171,7 @@ impl Ec2 for Ec2Client {
         RusotoError<GetAssociatedEnclaveCertificateIamRolesError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("GetAssociatedEnclaveCertificateIamRoles");
         let params = self.new_params("GetAssociatedEnclaveCertificateIamRoles");
         let mut params = params;
         GetAssociatedEnclaveCertificateIamRolesRequestSerializer::serialize(
@@ -99875,6 +100209,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<GetAssociatedIpv6PoolCidrsResult, RusotoError<GetAssociatedIpv6PoolCidrsError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("GetAssociatedIpv6PoolCidrs");
         let params = self.new_params("GetAssociatedIpv6PoolCidrs");
         let mut params = params;
         GetAssociatedIpv6PoolCidrsRequestSerializer::serialize(&mut params, "", &input);
@@ -99902,6 +100237,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<GetCapacityReservationUsageResult, RusotoError<GetCapacityReservationUsageError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("GetCapacityReservationUsage");
         let params = self.new_params("GetCapacityReservationUsage");
         let mut params = params;
         GetCapacityReservationUsageRequestSerializer::serialize(&mut params, "", &input);
@@ -99928,6 +100264,7 @@ impl Ec2 for Ec2Client {
         input: GetCoipPoolUsageRequest,
     ) -> Result<GetCoipPoolUsageResult, RusotoError<GetCoipPoolUsageError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("GetCoipPoolUsage");
         let params = self.new_params("GetCoipPoolUsage");
         let mut params = params;
         GetCoipPoolUsageRequestSerializer::serialize(&mut params, "", &input);
@@ -99954,6 +100291,7 @@ impl Ec2 for Ec2Client {
         input: GetConsoleOutputRequest,
     ) -> Result<GetConsoleOutputResult, RusotoError<GetConsoleOutputError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("GetConsoleOutput");
         let params = self.new_params("GetConsoleOutput");
         let mut params = params;
         GetConsoleOutputRequestSerializer::serialize(&mut params, "", &input);
@@ -99980,6 +100318,7 @@ impl Ec2 for Ec2Client {
         input: GetConsoleScreenshotRequest,
     ) -> Result<GetConsoleScreenshotResult, RusotoError<GetConsoleScreenshotError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("GetConsoleScreenshot");
         let params = self.new_params("GetConsoleScreenshot");
         let mut params = params;
         GetConsoleScreenshotRequestSerializer::serialize(&mut params, "", &input);
@@ -100007,6 +100346,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<GetDefaultCreditSpecificationResult, RusotoError<GetDefaultCreditSpecificationError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("GetDefaultCreditSpecification");
         let params = self.new_params("GetDefaultCreditSpecification");
         let mut params = params;
         GetDefaultCreditSpecificationRequestSerializer::serialize(&mut params, "", &input);
@@ -100033,6 +100373,7 @@ impl Ec2 for Ec2Client {
         input: GetEbsDefaultKmsKeyIdRequest,
     ) -> Result<GetEbsDefaultKmsKeyIdResult, RusotoError<GetEbsDefaultKmsKeyIdError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("GetEbsDefaultKmsKeyId");
         let params = self.new_params("GetEbsDefaultKmsKeyId");
         let mut params = params;
         GetEbsDefaultKmsKeyIdRequestSerializer::serialize(&mut params, "", &input);
@@ -100059,6 +100400,7 @@ impl Ec2 for Ec2Client {
         input: GetEbsEncryptionByDefaultRequest,
     ) -> Result<GetEbsEncryptionByDefaultResult, RusotoError<GetEbsEncryptionByDefaultError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("GetEbsEncryptionByDefault");
         let params = self.new_params("GetEbsEncryptionByDefault");
         let mut params = params;
         GetEbsEncryptionByDefaultRequestSerializer::serialize(&mut params, "", &input);
@@ -100088,6 +100430,7 @@ impl Ec2 for Ec2Client {
         RusotoError<GetGroupsForCapacityReservationError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("GetGroupsForCapacityReservation");
         let params = self.new_params("GetGroupsForCapacityReservation");
         let mut params = params;
         GetGroupsForCapacityReservationRequestSerializer::serialize(&mut params, "", &input);
@@ -100117,6 +100460,7 @@ impl Ec2 for Ec2Client {
         RusotoError<GetHostReservationPurchasePreviewError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("GetHostReservationPurchasePreview");
         let params = self.new_params("GetHostReservationPurchasePreview");
         let mut params = params;
         GetHostReservationPurchasePreviewRequestSerializer::serialize(&mut params, "", &input);
@@ -100146,6 +100490,7 @@ impl Ec2 for Ec2Client {
         input: GetLaunchTemplateDataRequest,
     ) -> Result<GetLaunchTemplateDataResult, RusotoError<GetLaunchTemplateDataError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("GetLaunchTemplateData");
         let params = self.new_params("GetLaunchTemplateData");
         let mut params = params;
         GetLaunchTemplateDataRequestSerializer::serialize(&mut params, "", &input);
@@ -100175,6 +100520,7 @@ impl Ec2 for Ec2Client {
         RusotoError<GetManagedPrefixListAssociationsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("GetManagedPrefixListAssociations");
         let params = self.new_params("GetManagedPrefixListAssociations");
         let mut params = params;
         GetManagedPrefixListAssociationsRequestSerializer::serialize(&mut params, "", &input);
@@ -100205,6 +100551,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<GetManagedPrefixListEntriesResult, RusotoError<GetManagedPrefixListEntriesError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("GetManagedPrefixListEntries");
         let params = self.new_params("GetManagedPrefixListEntries");
         let mut params = params;
         GetManagedPrefixListEntriesRequestSerializer::serialize(&mut params, "", &input);
@@ -100231,6 +100578,7 @@ impl Ec2 for Ec2Client {
         input: GetPasswordDataRequest,
     ) -> Result<GetPasswordDataResult, RusotoError<GetPasswordDataError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("GetPasswordData");
         let params = self.new_params("GetPasswordData");
         let mut params = params;
         GetPasswordDataRequestSerializer::serialize(&mut params, "", &input);
@@ -100260,6 +100608,7 @@ impl Ec2 for Ec2Client {
         RusotoError<GetReservedInstancesExchangeQuoteError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("GetReservedInstancesExchangeQuote");
         let params = self.new_params("GetReservedInstancesExchangeQuote");
         let mut params = params;
         GetReservedInstancesExchangeQuoteRequestSerializer::serialize(&mut params, "", &input);
@@ -100292,6 +100641,7 @@ impl Ec2 for Ec2Client {
         RusotoError<GetTransitGatewayAttachmentPropagationsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("GetTransitGatewayAttachmentPropagations");
         let params = self.new_params("GetTransitGatewayAttachmentPropagations");
         let mut params = params;
         GetTransitGatewayAttachmentPropagationsRequestSerializer::serialize(
@@ -100331,6 +100681,7 @@ impl Ec2 for Ec2Client {
         RusotoError<GetTransitGatewayMulticastDomainAssociationsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("GetTransitGatewayMulticastDomainAssociations");
         let params = self.new_params("GetTransitGatewayMulticastDomainAssociations");
         let mut params = params;
         GetTransitGatewayMulticastDomainAssociationsRequestSerializer::serialize(
@@ -100370,6 +100721,7 @@ impl Ec2 for Ec2Client {
         RusotoError<GetTransitGatewayPrefixListReferencesError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("GetTransitGatewayPrefixListReferences");
         let params = self.new_params("GetTransitGatewayPrefixListReferences");
         let mut params = params;
         GetTransitGatewayPrefixListReferencesRequestSerializer::serialize(&mut params, "", &input);
@@ -100405,6 +100757,7 @@ impl Ec2 for Ec2Client {
         RusotoError<GetTransitGatewayRouteTableAssociationsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("GetTransitGatewayRouteTableAssociations");
         let params = self.new_params("GetTransitGatewayRouteTableAssociations");
         let mut params = params;
         GetTransitGatewayRouteTableAssociationsRequestSerializer::serialize(
@@ -100444,6 +100797,7 @@ impl Ec2 for Ec2Client {
         RusotoError<GetTransitGatewayRouteTablePropagationsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("GetTransitGatewayRouteTablePropagations");
         let params = self.new_params("GetTransitGatewayRouteTablePropagations");
         let mut params = params;
         GetTransitGatewayRouteTablePropagationsRequestSerializer::serialize(
@@ -100483,6 +100837,7 @@ impl Ec2 for Ec2Client {
         RusotoError<ImportClientVpnClientCertificateRevocationListError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ImportClientVpnClientCertificateRevocationList");
         let params = self.new_params("ImportClientVpnClientCertificateRevocationList");
         let mut params = params;
         ImportClientVpnClientCertificateRevocationListRequestSerializer::serialize(
@@ -100519,6 +100874,7 @@ impl Ec2 for Ec2Client {
         input: ImportImageRequest,
     ) -> Result<ImportImageResult, RusotoError<ImportImageError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ImportImage");
         let params = self.new_params("ImportImage");
         let mut params = params;
         ImportImageRequestSerializer::serialize(&mut params, "", &input);
@@ -100545,6 +100901,7 @@ impl Ec2 for Ec2Client {
         input: ImportInstanceRequest,
     ) -> Result<ImportInstanceResult, RusotoError<ImportInstanceError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ImportInstance");
         let params = self.new_params("ImportInstance");
         let mut params = params;
         ImportInstanceRequestSerializer::serialize(&mut params, "", &input);
@@ -100571,6 +100928,7 @@ impl Ec2 for Ec2Client {
         input: ImportKeyPairRequest,
     ) -> Result<ImportKeyPairResult, RusotoError<ImportKeyPairError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ImportKeyPair");
         let params = self.new_params("ImportKeyPair");
         let mut params = params;
         ImportKeyPairRequestSerializer::serialize(&mut params, "", &input);
@@ -100597,6 +100955,7 @@ impl Ec2 for Ec2Client {
         input: ImportSnapshotRequest,
     ) -> Result<ImportSnapshotResult, RusotoError<ImportSnapshotError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ImportSnapshot");
         let params = self.new_params("ImportSnapshot");
         let mut params = params;
         ImportSnapshotRequestSerializer::serialize(&mut params, "", &input);
@@ -100623,6 +100982,7 @@ impl Ec2 for Ec2Client {
         input: ImportVolumeRequest,
     ) -> Result<ImportVolumeResult, RusotoError<ImportVolumeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ImportVolume");
         let params = self.new_params("ImportVolume");
         let mut params = params;
         ImportVolumeRequestSerializer::serialize(&mut params, "", &input);
@@ -100650,6 +101010,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<ModifyAvailabilityZoneGroupResult, RusotoError<ModifyAvailabilityZoneGroupError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifyAvailabilityZoneGroup");
         let params = self.new_params("ModifyAvailabilityZoneGroup");
         let mut params = params;
         ModifyAvailabilityZoneGroupRequestSerializer::serialize(&mut params, "", &input);
@@ -100676,6 +101037,7 @@ impl Ec2 for Ec2Client {
         input: ModifyCapacityReservationRequest,
     ) -> Result<ModifyCapacityReservationResult, RusotoError<ModifyCapacityReservationError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifyCapacityReservation");
         let params = self.new_params("ModifyCapacityReservation");
         let mut params = params;
         ModifyCapacityReservationRequestSerializer::serialize(&mut params, "", &input);
@@ -100702,6 +101064,7 @@ impl Ec2 for Ec2Client {
         input: ModifyClientVpnEndpointRequest,
     ) -> Result<ModifyClientVpnEndpointResult, RusotoError<ModifyClientVpnEndpointError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifyClientVpnEndpoint");
         let params = self.new_params("ModifyClientVpnEndpoint");
         let mut params = params;
         ModifyClientVpnEndpointRequestSerializer::serialize(&mut params, "", &input);
@@ -100731,6 +101094,7 @@ impl Ec2 for Ec2Client {
         RusotoError<ModifyDefaultCreditSpecificationError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifyDefaultCreditSpecification");
         let params = self.new_params("ModifyDefaultCreditSpecification");
         let mut params = params;
         ModifyDefaultCreditSpecificationRequestSerializer::serialize(&mut params, "", &input);
@@ -100760,6 +101124,7 @@ impl Ec2 for Ec2Client {
         input: ModifyEbsDefaultKmsKeyIdRequest,
     ) -> Result<ModifyEbsDefaultKmsKeyIdResult, RusotoError<ModifyEbsDefaultKmsKeyIdError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifyEbsDefaultKmsKeyId");
         let params = self.new_params("ModifyEbsDefaultKmsKeyId");
         let mut params = params;
         ModifyEbsDefaultKmsKeyIdRequestSerializer::serialize(&mut params, "", &input);
@@ -100786,6 +101151,7 @@ impl Ec2 for Ec2Client {
         input: ModifyFleetRequest,
     ) -> Result<ModifyFleetResult, RusotoError<ModifyFleetError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifyFleet");
         let params = self.new_params("ModifyFleet");
         let mut params = params;
         ModifyFleetRequestSerializer::serialize(&mut params, "", &input);
@@ -100812,6 +101178,7 @@ impl Ec2 for Ec2Client {
         input: ModifyFpgaImageAttributeRequest,
     ) -> Result<ModifyFpgaImageAttributeResult, RusotoError<ModifyFpgaImageAttributeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifyFpgaImageAttribute");
         let params = self.new_params("ModifyFpgaImageAttribute");
         let mut params = params;
         ModifyFpgaImageAttributeRequestSerializer::serialize(&mut params, "", &input);
@@ -100838,6 +101205,7 @@ impl Ec2 for Ec2Client {
         input: ModifyHostsRequest,
     ) -> Result<ModifyHostsResult, RusotoError<ModifyHostsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifyHosts");
         let params = self.new_params("ModifyHosts");
         let mut params = params;
         ModifyHostsRequestSerializer::serialize(&mut params, "", &input);
@@ -100864,6 +101232,7 @@ impl Ec2 for Ec2Client {
         input: ModifyIdFormatRequest,
     ) -> Result<(), RusotoError<ModifyIdFormatError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifyIdFormat");
         let params = self.new_params("ModifyIdFormat");
         let mut params = params;
         ModifyIdFormatRequestSerializer::serialize(&mut params, "", &input);
@@ -100884,6 +101253,7 @@ impl Ec2 for Ec2Client {
         input: ModifyIdentityIdFormatRequest,
     ) -> Result<(), RusotoError<ModifyIdentityIdFormatError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifyIdentityIdFormat");
         let params = self.new_params("ModifyIdentityIdFormat");
         let mut params = params;
         ModifyIdentityIdFormatRequestSerializer::serialize(&mut params, "", &input);
@@ -100904,6 +101274,7 @@ impl Ec2 for Ec2Client {
         input: ModifyImageAttributeRequest,
     ) -> Result<(), RusotoError<ModifyImageAttributeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifyImageAttribute");
         let params = self.new_params("ModifyImageAttribute");
         let mut params = params;
         ModifyImageAttributeRequestSerializer::serialize(&mut params, "", &input);
@@ -100924,6 +101295,7 @@ impl Ec2 for Ec2Client {
         input: ModifyInstanceAttributeRequest,
     ) -> Result<(), RusotoError<ModifyInstanceAttributeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifyInstanceAttribute");
         let params = self.new_params("ModifyInstanceAttribute");
         let mut params = params;
         ModifyInstanceAttributeRequestSerializer::serialize(&mut params, "", &input);
@@ -100947,6 +101319,7 @@ impl Ec2 for Ec2Client {
         RusotoError<ModifyInstanceCapacityReservationAttributesError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifyInstanceCapacityReservationAttributes");
         let params = self.new_params("ModifyInstanceCapacityReservationAttributes");
         let mut params = params;
         ModifyInstanceCapacityReservationAttributesRequestSerializer::serialize(
@@ -100986,6 +101359,7 @@ impl Ec2 for Ec2Client {
         RusotoError<ModifyInstanceCreditSpecificationError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifyInstanceCreditSpecification");
         let params = self.new_params("ModifyInstanceCreditSpecification");
         let mut params = params;
         ModifyInstanceCreditSpecificationRequestSerializer::serialize(&mut params, "", &input);
@@ -101016,6 +101390,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<ModifyInstanceEventStartTimeResult, RusotoError<ModifyInstanceEventStartTimeError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifyInstanceEventStartTime");
         let params = self.new_params("ModifyInstanceEventStartTime");
         let mut params = params;
         ModifyInstanceEventStartTimeRequestSerializer::serialize(&mut params, "", &input);
@@ -101043,6 +101418,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<ModifyInstanceMetadataOptionsResult, RusotoError<ModifyInstanceMetadataOptionsError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifyInstanceMetadataOptions");
         let params = self.new_params("ModifyInstanceMetadataOptions");
         let mut params = params;
         ModifyInstanceMetadataOptionsRequestSerializer::serialize(&mut params, "", &input);
@@ -101069,6 +101445,7 @@ impl Ec2 for Ec2Client {
         input: ModifyInstancePlacementRequest,
     ) -> Result<ModifyInstancePlacementResult, RusotoError<ModifyInstancePlacementError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifyInstancePlacement");
         let params = self.new_params("ModifyInstancePlacement");
         let mut params = params;
         ModifyInstancePlacementRequestSerializer::serialize(&mut params, "", &input);
@@ -101095,6 +101472,7 @@ impl Ec2 for Ec2Client {
         input: ModifyLaunchTemplateRequest,
     ) -> Result<ModifyLaunchTemplateResult, RusotoError<ModifyLaunchTemplateError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifyLaunchTemplate");
         let params = self.new_params("ModifyLaunchTemplate");
         let mut params = params;
         ModifyLaunchTemplateRequestSerializer::serialize(&mut params, "", &input);
@@ -101121,6 +101499,7 @@ impl Ec2 for Ec2Client {
         input: ModifyManagedPrefixListRequest,
     ) -> Result<ModifyManagedPrefixListResult, RusotoError<ModifyManagedPrefixListError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifyManagedPrefixList");
         let params = self.new_params("ModifyManagedPrefixList");
         let mut params = params;
         ModifyManagedPrefixListRequestSerializer::serialize(&mut params, "", &input);
@@ -101147,6 +101526,7 @@ impl Ec2 for Ec2Client {
         input: ModifyNetworkInterfaceAttributeRequest,
     ) -> Result<(), RusotoError<ModifyNetworkInterfaceAttributeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifyNetworkInterfaceAttribute");
         let params = self.new_params("ModifyNetworkInterfaceAttribute");
         let mut params = params;
         ModifyNetworkInterfaceAttributeRequestSerializer::serialize(&mut params, "", &input);
@@ -101167,6 +101547,7 @@ impl Ec2 for Ec2Client {
         input: ModifyReservedInstancesRequest,
     ) -> Result<ModifyReservedInstancesResult, RusotoError<ModifyReservedInstancesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifyReservedInstances");
         let params = self.new_params("ModifyReservedInstances");
         let mut params = params;
         ModifyReservedInstancesRequestSerializer::serialize(&mut params, "", &input);
@@ -101193,6 +101574,7 @@ impl Ec2 for Ec2Client {
         input: ModifySnapshotAttributeRequest,
     ) -> Result<(), RusotoError<ModifySnapshotAttributeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifySnapshotAttribute");
         let params = self.new_params("ModifySnapshotAttribute");
         let mut params = params;
         ModifySnapshotAttributeRequestSerializer::serialize(&mut params, "", &input);
@@ -101213,6 +101595,7 @@ impl Ec2 for Ec2Client {
         input: ModifySpotFleetRequestRequest,
     ) -> Result<ModifySpotFleetRequestResponse, RusotoError<ModifySpotFleetRequestError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifySpotFleetRequest");
         let params = self.new_params("ModifySpotFleetRequest");
         let mut params = params;
         ModifySpotFleetRequestRequestSerializer::serialize(&mut params, "", &input);
@@ -101239,6 +101622,7 @@ impl Ec2 for Ec2Client {
         input: ModifySubnetAttributeRequest,
     ) -> Result<(), RusotoError<ModifySubnetAttributeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifySubnetAttribute");
         let params = self.new_params("ModifySubnetAttribute");
         let mut params = params;
         ModifySubnetAttributeRequestSerializer::serialize(&mut params, "", &input);
@@ -101262,6 +101646,7 @@ impl Ec2 for Ec2Client {
         RusotoError<ModifyTrafficMirrorFilterNetworkServicesError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifyTrafficMirrorFilterNetworkServices");
         let params = self.new_params("ModifyTrafficMirrorFilterNetworkServices");
         let mut params = params;
         ModifyTrafficMirrorFilterNetworkServicesRequestSerializer::serialize(
@@ -101299,6 +101684,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<ModifyTrafficMirrorFilterRuleResult, RusotoError<ModifyTrafficMirrorFilterRuleError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifyTrafficMirrorFilterRule");
         let params = self.new_params("ModifyTrafficMirrorFilterRule");
         let mut params = params;
         ModifyTrafficMirrorFilterRuleRequestSerializer::serialize(&mut params, "", &input);
@@ -101326,6 +101712,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<ModifyTrafficMirrorSessionResult, RusotoError<ModifyTrafficMirrorSessionError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifyTrafficMirrorSession");
         let params = self.new_params("ModifyTrafficMirrorSession");
         let mut params = params;
         ModifyTrafficMirrorSessionRequestSerializer::serialize(&mut params, "", &input);
@@ -101352,6 +101739,7 @@ impl Ec2 for Ec2Client {
         input: ModifyTransitGatewayRequest,
     ) -> Result<ModifyTransitGatewayResult, RusotoError<ModifyTransitGatewayError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifyTransitGateway");
         let params = self.new_params("ModifyTransitGateway");
         let mut params = params;
         ModifyTransitGatewayRequestSerializer::serialize(&mut params, "", &input);
@@ -101381,6 +101769,7 @@ impl Ec2 for Ec2Client {
         RusotoError<ModifyTransitGatewayPrefixListReferenceError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifyTransitGatewayPrefixListReference");
         let params = self.new_params("ModifyTransitGatewayPrefixListReference");
         let mut params = params;
         ModifyTransitGatewayPrefixListReferenceRequestSerializer::serialize(
@@ -101420,6 +101809,7 @@ impl Ec2 for Ec2Client {
         RusotoError<ModifyTransitGatewayVpcAttachmentError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifyTransitGatewayVpcAttachment");
         let params = self.new_params("ModifyTransitGatewayVpcAttachment");
         let mut params = params;
         ModifyTransitGatewayVpcAttachmentRequestSerializer::serialize(&mut params, "", &input);
@@ -101449,6 +101839,7 @@ impl Ec2 for Ec2Client {
         input: ModifyVolumeRequest,
     ) -> Result<ModifyVolumeResult, RusotoError<ModifyVolumeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifyVolume");
         let params = self.new_params("ModifyVolume");
         let mut params = params;
         ModifyVolumeRequestSerializer::serialize(&mut params, "", &input);
@@ -101475,6 +101866,7 @@ impl Ec2 for Ec2Client {
         input: ModifyVolumeAttributeRequest,
     ) -> Result<(), RusotoError<ModifyVolumeAttributeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifyVolumeAttribute");
         let params = self.new_params("ModifyVolumeAttribute");
         let mut params = params;
         ModifyVolumeAttributeRequestSerializer::serialize(&mut params, "", &input);
@@ -101495,6 +101887,7 @@ impl Ec2 for Ec2Client {
         input: ModifyVpcAttributeRequest,
     ) -> Result<(), RusotoError<ModifyVpcAttributeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifyVpcAttribute");
         let params = self.new_params("ModifyVpcAttribute");
         let mut params = params;
         ModifyVpcAttributeRequestSerializer::serialize(&mut params, "", &input);
@@ -101515,6 +101908,7 @@ impl Ec2 for Ec2Client {
         input: ModifyVpcEndpointRequest,
     ) -> Result<ModifyVpcEndpointResult, RusotoError<ModifyVpcEndpointError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifyVpcEndpoint");
         let params = self.new_params("ModifyVpcEndpoint");
         let mut params = params;
         ModifyVpcEndpointRequestSerializer::serialize(&mut params, "", &input);
@@ -101544,6 +101938,7 @@ impl Ec2 for Ec2Client {
         RusotoError<ModifyVpcEndpointConnectionNotificationError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifyVpcEndpointConnectionNotification");
         let params = self.new_params("ModifyVpcEndpointConnectionNotification");
         let mut params = params;
         ModifyVpcEndpointConnectionNotificationRequestSerializer::serialize(
@@ -101583,6 +101978,7 @@ impl Ec2 for Ec2Client {
         RusotoError<ModifyVpcEndpointServiceConfigurationError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifyVpcEndpointServiceConfiguration");
         let params = self.new_params("ModifyVpcEndpointServiceConfiguration");
         let mut params = params;
         ModifyVpcEndpointServiceConfigurationRequestSerializer::serialize(&mut params, "", &input);
@@ -101618,6 +102014,7 @@ impl Ec2 for Ec2Client {
         RusotoError<ModifyVpcEndpointServicePermissionsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifyVpcEndpointServicePermissions");
         let params = self.new_params("ModifyVpcEndpointServicePermissions");
         let mut params = params;
         ModifyVpcEndpointServicePermissionsRequestSerializer::serialize(&mut params, "", &input);
@@ -101653,6 +102050,7 @@ impl Ec2 for Ec2Client {
         RusotoError<ModifyVpcPeeringConnectionOptionsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifyVpcPeeringConnectionOptions");
         let params = self.new_params("ModifyVpcPeeringConnectionOptions");
         let mut params = params;
         ModifyVpcPeeringConnectionOptionsRequestSerializer::serialize(&mut params, "", &input);
@@ -101682,6 +102080,7 @@ impl Ec2 for Ec2Client {
         input: ModifyVpcTenancyRequest,
     ) -> Result<ModifyVpcTenancyResult, RusotoError<ModifyVpcTenancyError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifyVpcTenancy");
         let params = self.new_params("ModifyVpcTenancy");
         let mut params = params;
         ModifyVpcTenancyRequestSerializer::serialize(&mut params, "", &input);
@@ -101708,6 +102107,7 @@ impl Ec2 for Ec2Client {
         input: ModifyVpnConnectionRequest,
     ) -> Result<ModifyVpnConnectionResult, RusotoError<ModifyVpnConnectionError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifyVpnConnection");
         let params = self.new_params("ModifyVpnConnection");
         let mut params = params;
         ModifyVpnConnectionRequestSerializer::serialize(&mut params, "", &input);
@@ -101735,6 +102135,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<ModifyVpnConnectionOptionsResult, RusotoError<ModifyVpnConnectionOptionsError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifyVpnConnectionOptions");
         let params = self.new_params("ModifyVpnConnectionOptions");
         let mut params = params;
         ModifyVpnConnectionOptionsRequestSerializer::serialize(&mut params, "", &input);
@@ -101762,6 +102163,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<ModifyVpnTunnelCertificateResult, RusotoError<ModifyVpnTunnelCertificateError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifyVpnTunnelCertificate");
         let params = self.new_params("ModifyVpnTunnelCertificate");
         let mut params = params;
         ModifyVpnTunnelCertificateRequestSerializer::serialize(&mut params, "", &input);
@@ -101788,6 +102190,7 @@ impl Ec2 for Ec2Client {
         input: ModifyVpnTunnelOptionsRequest,
     ) -> Result<ModifyVpnTunnelOptionsResult, RusotoError<ModifyVpnTunnelOptionsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ModifyVpnTunnelOptions");
         let params = self.new_params("ModifyVpnTunnelOptions");
         let mut params = params;
         ModifyVpnTunnelOptionsRequestSerializer::serialize(&mut params, "", &input);
@@ -101814,6 +102217,7 @@ impl Ec2 for Ec2Client {
         input: MonitorInstancesRequest,
     ) -> Result<MonitorInstancesResult, RusotoError<MonitorInstancesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("MonitorInstances");
         let params = self.new_params("MonitorInstances");
         let mut params = params;
         MonitorInstancesRequestSerializer::serialize(&mut params, "", &input);
@@ -101840,6 +102244,7 @@ impl Ec2 for Ec2Client {
         input: MoveAddressToVpcRequest,
     ) -> Result<MoveAddressToVpcResult, RusotoError<MoveAddressToVpcError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("MoveAddressToVpc");
         let params = self.new_params("MoveAddressToVpc");
         let mut params = params;
         MoveAddressToVpcRequestSerializer::serialize(&mut params, "", &input);
@@ -101866,6 +102271,7 @@ impl Ec2 for Ec2Client {
         input: ProvisionByoipCidrRequest,
     ) -> Result<ProvisionByoipCidrResult, RusotoError<ProvisionByoipCidrError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ProvisionByoipCidr");
         let params = self.new_params("ProvisionByoipCidr");
         let mut params = params;
         ProvisionByoipCidrRequestSerializer::serialize(&mut params, "", &input);
@@ -101892,6 +102298,7 @@ impl Ec2 for Ec2Client {
         input: PurchaseHostReservationRequest,
     ) -> Result<PurchaseHostReservationResult, RusotoError<PurchaseHostReservationError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("PurchaseHostReservation");
         let params = self.new_params("PurchaseHostReservation");
         let mut params = params;
         PurchaseHostReservationRequestSerializer::serialize(&mut params, "", &input);
@@ -101921,6 +102328,7 @@ impl Ec2 for Ec2Client {
         RusotoError<PurchaseReservedInstancesOfferingError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("PurchaseReservedInstancesOffering");
         let params = self.new_params("PurchaseReservedInstancesOffering");
         let mut params = params;
         PurchaseReservedInstancesOfferingRequestSerializer::serialize(&mut params, "", &input);
@@ -101951,6 +102359,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<PurchaseScheduledInstancesResult, RusotoError<PurchaseScheduledInstancesError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("PurchaseScheduledInstances");
         let params = self.new_params("PurchaseScheduledInstances");
         let mut params = params;
         PurchaseScheduledInstancesRequestSerializer::serialize(&mut params, "", &input);
@@ -101977,6 +102386,7 @@ impl Ec2 for Ec2Client {
         input: RebootInstancesRequest,
     ) -> Result<(), RusotoError<RebootInstancesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("RebootInstances");
         let params = self.new_params("RebootInstances");
         let mut params = params;
         RebootInstancesRequestSerializer::serialize(&mut params, "", &input);
@@ -101997,6 +102407,7 @@ impl Ec2 for Ec2Client {
         input: RegisterImageRequest,
     ) -> Result<RegisterImageResult, RusotoError<RegisterImageError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("RegisterImage");
         let params = self.new_params("RegisterImage");
         let mut params = params;
         RegisterImageRequestSerializer::serialize(&mut params, "", &input);
@@ -102026,6 +102437,7 @@ impl Ec2 for Ec2Client {
         RusotoError<RegisterInstanceEventNotificationAttributesError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("RegisterInstanceEventNotificationAttributes");
         let params = self.new_params("RegisterInstanceEventNotificationAttributes");
         let mut params = params;
         RegisterInstanceEventNotificationAttributesRequestSerializer::serialize(
@@ -102065,6 +102477,7 @@ impl Ec2 for Ec2Client {
         RusotoError<RegisterTransitGatewayMulticastGroupMembersError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("RegisterTransitGatewayMulticastGroupMembers");
         let params = self.new_params("RegisterTransitGatewayMulticastGroupMembers");
         let mut params = params;
         RegisterTransitGatewayMulticastGroupMembersRequestSerializer::serialize(
@@ -102104,6 +102517,7 @@ impl Ec2 for Ec2Client {
         RusotoError<RegisterTransitGatewayMulticastGroupSourcesError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("RegisterTransitGatewayMulticastGroupSources");
         let params = self.new_params("RegisterTransitGatewayMulticastGroupSources");
         let mut params = params;
         RegisterTransitGatewayMulticastGroupSourcesRequestSerializer::serialize(
@@ -102143,6 +102557,7 @@ impl Ec2 for Ec2Client {
         RusotoError<RejectTransitGatewayMulticastDomainAssociationsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("RejectTransitGatewayMulticastDomainAssociations");
         let params = self.new_params("RejectTransitGatewayMulticastDomainAssociations");
         let mut params = params;
         RejectTransitGatewayMulticastDomainAssociationsRequestSerializer::serialize(
@@ -102182,6 +102597,7 @@ impl Ec2 for Ec2Client {
         RusotoError<RejectTransitGatewayPeeringAttachmentError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("RejectTransitGatewayPeeringAttachment");
         let params = self.new_params("RejectTransitGatewayPeeringAttachment");
         let mut params = params;
         RejectTransitGatewayPeeringAttachmentRequestSerializer::serialize(&mut params, "", &input);
@@ -102217,6 +102633,7 @@ impl Ec2 for Ec2Client {
         RusotoError<RejectTransitGatewayVpcAttachmentError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("RejectTransitGatewayVpcAttachment");
         let params = self.new_params("RejectTransitGatewayVpcAttachment");
         let mut params = params;
         RejectTransitGatewayVpcAttachmentRequestSerializer::serialize(&mut params, "", &input);
@@ -102247,6 +102664,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<RejectVpcEndpointConnectionsResult, RusotoError<RejectVpcEndpointConnectionsError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("RejectVpcEndpointConnections");
         let params = self.new_params("RejectVpcEndpointConnections");
         let mut params = params;
         RejectVpcEndpointConnectionsRequestSerializer::serialize(&mut params, "", &input);
@@ -102274,6 +102692,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<RejectVpcPeeringConnectionResult, RusotoError<RejectVpcPeeringConnectionError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("RejectVpcPeeringConnection");
         let params = self.new_params("RejectVpcPeeringConnection");
         let mut params = params;
         RejectVpcPeeringConnectionRequestSerializer::serialize(&mut params, "", &input);
@@ -102300,6 +102719,7 @@ impl Ec2 for Ec2Client {
         input: ReleaseAddressRequest,
     ) -> Result<(), RusotoError<ReleaseAddressError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ReleaseAddress");
         let params = self.new_params("ReleaseAddress");
         let mut params = params;
         ReleaseAddressRequestSerializer::serialize(&mut params, "", &input);
@@ -102320,6 +102740,7 @@ impl Ec2 for Ec2Client {
         input: ReleaseHostsRequest,
     ) -> Result<ReleaseHostsResult, RusotoError<ReleaseHostsError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ReleaseHosts");
         let params = self.new_params("ReleaseHosts");
         let mut params = params;
         ReleaseHostsRequestSerializer::serialize(&mut params, "", &input);
@@ -102349,6 +102770,7 @@ impl Ec2 for Ec2Client {
         RusotoError<ReplaceIamInstanceProfileAssociationError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ReplaceIamInstanceProfileAssociation");
         let params = self.new_params("ReplaceIamInstanceProfileAssociation");
         let mut params = params;
         ReplaceIamInstanceProfileAssociationRequestSerializer::serialize(&mut params, "", &input);
@@ -102382,6 +102804,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<ReplaceNetworkAclAssociationResult, RusotoError<ReplaceNetworkAclAssociationError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ReplaceNetworkAclAssociation");
         let params = self.new_params("ReplaceNetworkAclAssociation");
         let mut params = params;
         ReplaceNetworkAclAssociationRequestSerializer::serialize(&mut params, "", &input);
@@ -102408,6 +102831,7 @@ impl Ec2 for Ec2Client {
         input: ReplaceNetworkAclEntryRequest,
     ) -> Result<(), RusotoError<ReplaceNetworkAclEntryError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ReplaceNetworkAclEntry");
         let params = self.new_params("ReplaceNetworkAclEntry");
         let mut params = params;
         ReplaceNetworkAclEntryRequestSerializer::serialize(&mut params, "", &input);
@@ -102428,6 +102852,7 @@ impl Ec2 for Ec2Client {
         input: ReplaceRouteRequest,
     ) -> Result<(), RusotoError<ReplaceRouteError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ReplaceRoute");
         let params = self.new_params("ReplaceRoute");
         let mut params = params;
         ReplaceRouteRequestSerializer::serialize(&mut params, "", &input);
@@ -102449,6 +102874,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<ReplaceRouteTableAssociationResult, RusotoError<ReplaceRouteTableAssociationError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ReplaceRouteTableAssociation");
         let params = self.new_params("ReplaceRouteTableAssociation");
         let mut params = params;
         ReplaceRouteTableAssociationRequestSerializer::serialize(&mut params, "", &input);
@@ -102476,6 +102902,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<ReplaceTransitGatewayRouteResult, RusotoError<ReplaceTransitGatewayRouteError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ReplaceTransitGatewayRoute");
         let params = self.new_params("ReplaceTransitGatewayRoute");
         let mut params = params;
         ReplaceTransitGatewayRouteRequestSerializer::serialize(&mut params, "", &input);
@@ -102502,6 +102929,7 @@ impl Ec2 for Ec2Client {
         input: ReportInstanceStatusRequest,
     ) -> Result<(), RusotoError<ReportInstanceStatusError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ReportInstanceStatus");
         let params = self.new_params("ReportInstanceStatus");
         let mut params = params;
         ReportInstanceStatusRequestSerializer::serialize(&mut params, "", &input);
@@ -102522,6 +102950,7 @@ impl Ec2 for Ec2Client {
         input: RequestSpotFleetRequest,
     ) -> Result<RequestSpotFleetResponse, RusotoError<RequestSpotFleetError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("RequestSpotFleet");
         let params = self.new_params("RequestSpotFleet");
         let mut params = params;
         RequestSpotFleetRequestSerializer::serialize(&mut params, "", &input);
@@ -102548,6 +102977,7 @@ impl Ec2 for Ec2Client {
         input: RequestSpotInstancesRequest,
     ) -> Result<RequestSpotInstancesResult, RusotoError<RequestSpotInstancesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("RequestSpotInstances");
         let params = self.new_params("RequestSpotInstances");
         let mut params = params;
         RequestSpotInstancesRequestSerializer::serialize(&mut params, "", &input);
@@ -102574,6 +103004,7 @@ impl Ec2 for Ec2Client {
         input: ResetEbsDefaultKmsKeyIdRequest,
     ) -> Result<ResetEbsDefaultKmsKeyIdResult, RusotoError<ResetEbsDefaultKmsKeyIdError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ResetEbsDefaultKmsKeyId");
         let params = self.new_params("ResetEbsDefaultKmsKeyId");
         let mut params = params;
         ResetEbsDefaultKmsKeyIdRequestSerializer::serialize(&mut params, "", &input);
@@ -102600,6 +103031,7 @@ impl Ec2 for Ec2Client {
         input: ResetFpgaImageAttributeRequest,
     ) -> Result<ResetFpgaImageAttributeResult, RusotoError<ResetFpgaImageAttributeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ResetFpgaImageAttribute");
         let params = self.new_params("ResetFpgaImageAttribute");
         let mut params = params;
         ResetFpgaImageAttributeRequestSerializer::serialize(&mut params, "", &input);
@@ -102626,6 +103058,7 @@ impl Ec2 for Ec2Client {
         input: ResetImageAttributeRequest,
     ) -> Result<(), RusotoError<ResetImageAttributeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ResetImageAttribute");
         let params = self.new_params("ResetImageAttribute");
         let mut params = params;
         ResetImageAttributeRequestSerializer::serialize(&mut params, "", &input);
@@ -102646,6 +103079,7 @@ impl Ec2 for Ec2Client {
         input: ResetInstanceAttributeRequest,
     ) -> Result<(), RusotoError<ResetInstanceAttributeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ResetInstanceAttribute");
         let params = self.new_params("ResetInstanceAttribute");
         let mut params = params;
         ResetInstanceAttributeRequestSerializer::serialize(&mut params, "", &input);
@@ -102666,6 +103100,7 @@ impl Ec2 for Ec2Client {
         input: ResetNetworkInterfaceAttributeRequest,
     ) -> Result<(), RusotoError<ResetNetworkInterfaceAttributeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ResetNetworkInterfaceAttribute");
         let params = self.new_params("ResetNetworkInterfaceAttribute");
         let mut params = params;
         ResetNetworkInterfaceAttributeRequestSerializer::serialize(&mut params, "", &input);
@@ -102686,6 +103121,7 @@ impl Ec2 for Ec2Client {
         input: ResetSnapshotAttributeRequest,
     ) -> Result<(), RusotoError<ResetSnapshotAttributeError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("ResetSnapshotAttribute");
         let params = self.new_params("ResetSnapshotAttribute");
         let mut params = params;
         ResetSnapshotAttributeRequestSerializer::serialize(&mut params, "", &input);
@@ -102706,6 +103142,7 @@ impl Ec2 for Ec2Client {
         input: RestoreAddressToClassicRequest,
     ) -> Result<RestoreAddressToClassicResult, RusotoError<RestoreAddressToClassicError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("RestoreAddressToClassic");
         let params = self.new_params("RestoreAddressToClassic");
         let mut params = params;
         RestoreAddressToClassicRequestSerializer::serialize(&mut params, "", &input);
@@ -102735,6 +103172,7 @@ impl Ec2 for Ec2Client {
         RusotoError<RestoreManagedPrefixListVersionError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("RestoreManagedPrefixListVersion");
         let params = self.new_params("RestoreManagedPrefixListVersion");
         let mut params = params;
         RestoreManagedPrefixListVersionRequestSerializer::serialize(&mut params, "", &input);
@@ -102761,6 +103199,7 @@ impl Ec2 for Ec2Client {
         input: RevokeClientVpnIngressRequest,
     ) -> Result<RevokeClientVpnIngressResult, RusotoError<RevokeClientVpnIngressError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("RevokeClientVpnIngress");
         let params = self.new_params("RevokeClientVpnIngress");
         let mut params = params;
         RevokeClientVpnIngressRequestSerializer::serialize(&mut params, "", &input);
@@ -102787,6 +103226,7 @@ impl Ec2 for Ec2Client {
         input: RevokeSecurityGroupEgressRequest,
     ) -> Result<RevokeSecurityGroupEgressResult, RusotoError<RevokeSecurityGroupEgressError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("RevokeSecurityGroupEgress");
         let params = self.new_params("RevokeSecurityGroupEgress");
         let mut params = params;
         RevokeSecurityGroupEgressRequestSerializer::serialize(&mut params, "", &input);
@@ -102814,6 +103254,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<RevokeSecurityGroupIngressResult, RusotoError<RevokeSecurityGroupIngressError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("RevokeSecurityGroupIngress");
         let params = self.new_params("RevokeSecurityGroupIngress");
         let mut params = params;
         RevokeSecurityGroupIngressRequestSerializer::serialize(&mut params, "", &input);
@@ -102840,6 +103281,7 @@ impl Ec2 for Ec2Client {
         input: RunInstancesRequest,
     ) -> Result<Reservation, RusotoError<RunInstancesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("RunInstances");
         let params = self.new_params("RunInstances");
         let mut params = params;
         RunInstancesRequestSerializer::serialize(&mut params, "", &input);
@@ -102866,6 +103308,7 @@ impl Ec2 for Ec2Client {
         input: RunScheduledInstancesRequest,
     ) -> Result<RunScheduledInstancesResult, RusotoError<RunScheduledInstancesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("RunScheduledInstances");
         let params = self.new_params("RunScheduledInstances");
         let mut params = params;
         RunScheduledInstancesRequestSerializer::serialize(&mut params, "", &input);
@@ -102892,6 +103335,7 @@ impl Ec2 for Ec2Client {
         input: SearchLocalGatewayRoutesRequest,
     ) -> Result<SearchLocalGatewayRoutesResult, RusotoError<SearchLocalGatewayRoutesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("SearchLocalGatewayRoutes");
         let params = self.new_params("SearchLocalGatewayRoutes");
         let mut params = params;
         SearchLocalGatewayRoutesRequestSerializer::serialize(&mut params, "", &input);
@@ -102921,6 +103365,7 @@ impl Ec2 for Ec2Client {
         RusotoError<SearchTransitGatewayMulticastGroupsError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("SearchTransitGatewayMulticastGroups");
         let params = self.new_params("SearchTransitGatewayMulticastGroups");
         let mut params = params;
         SearchTransitGatewayMulticastGroupsRequestSerializer::serialize(&mut params, "", &input);
@@ -102954,6 +103399,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<SearchTransitGatewayRoutesResult, RusotoError<SearchTransitGatewayRoutesError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("SearchTransitGatewayRoutes");
         let params = self.new_params("SearchTransitGatewayRoutes");
         let mut params = params;
         SearchTransitGatewayRoutesRequestSerializer::serialize(&mut params, "", &input);
@@ -102980,6 +103426,7 @@ impl Ec2 for Ec2Client {
         input: SendDiagnosticInterruptRequest,
     ) -> Result<(), RusotoError<SendDiagnosticInterruptError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("SendDiagnosticInterrupt");
         let params = self.new_params("SendDiagnosticInterrupt");
         let mut params = params;
         SendDiagnosticInterruptRequestSerializer::serialize(&mut params, "", &input);
@@ -103000,6 +103447,7 @@ impl Ec2 for Ec2Client {
         input: StartInstancesRequest,
     ) -> Result<StartInstancesResult, RusotoError<StartInstancesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("StartInstances");
         let params = self.new_params("StartInstances");
         let mut params = params;
         StartInstancesRequestSerializer::serialize(&mut params, "", &input);
@@ -103027,6 +103475,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<StartNetworkInsightsAnalysisResult, RusotoError<StartNetworkInsightsAnalysisError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("StartNetworkInsightsAnalysis");
         let params = self.new_params("StartNetworkInsightsAnalysis");
         let mut params = params;
         StartNetworkInsightsAnalysisRequestSerializer::serialize(&mut params, "", &input);
@@ -103056,6 +103505,7 @@ impl Ec2 for Ec2Client {
         RusotoError<StartVpcEndpointServicePrivateDnsVerificationError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("StartVpcEndpointServicePrivateDnsVerification");
         let params = self.new_params("StartVpcEndpointServicePrivateDnsVerification");
         let mut params = params;
         StartVpcEndpointServicePrivateDnsVerificationRequestSerializer::serialize(
@@ -103092,6 +103542,7 @@ impl Ec2 for Ec2Client {
         input: StopInstancesRequest,
     ) -> Result<StopInstancesResult, RusotoError<StopInstancesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("StopInstances");
         let params = self.new_params("StopInstances");
         let mut params = params;
         StopInstancesRequestSerializer::serialize(&mut params, "", &input);
@@ -103119,6 +103570,7 @@ impl Ec2 for Ec2Client {
     ) -> Result<TerminateClientVpnConnectionsResult, RusotoError<TerminateClientVpnConnectionsError>>
     {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("TerminateClientVpnConnections");
         let params = self.new_params("TerminateClientVpnConnections");
         let mut params = params;
         TerminateClientVpnConnectionsRequestSerializer::serialize(&mut params, "", &input);
@@ -103145,6 +103597,7 @@ impl Ec2 for Ec2Client {
         input: TerminateInstancesRequest,
     ) -> Result<TerminateInstancesResult, RusotoError<TerminateInstancesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("TerminateInstances");
         let params = self.new_params("TerminateInstances");
         let mut params = params;
         TerminateInstancesRequestSerializer::serialize(&mut params, "", &input);
@@ -103171,6 +103624,7 @@ impl Ec2 for Ec2Client {
         input: UnassignIpv6AddressesRequest,
     ) -> Result<UnassignIpv6AddressesResult, RusotoError<UnassignIpv6AddressesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("UnassignIpv6Addresses");
         let params = self.new_params("UnassignIpv6Addresses");
         let mut params = params;
         UnassignIpv6AddressesRequestSerializer::serialize(&mut params, "", &input);
@@ -103197,6 +103651,7 @@ impl Ec2 for Ec2Client {
         input: UnassignPrivateIpAddressesRequest,
     ) -> Result<(), RusotoError<UnassignPrivateIpAddressesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("UnassignPrivateIpAddresses");
         let params = self.new_params("UnassignPrivateIpAddresses");
         let mut params = params;
         UnassignPrivateIpAddressesRequestSerializer::serialize(&mut params, "", &input);
@@ -103217,6 +103672,7 @@ impl Ec2 for Ec2Client {
         input: UnmonitorInstancesRequest,
     ) -> Result<UnmonitorInstancesResult, RusotoError<UnmonitorInstancesError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("UnmonitorInstances");
         let params = self.new_params("UnmonitorInstances");
         let mut params = params;
         UnmonitorInstancesRequestSerializer::serialize(&mut params, "", &input);
@@ -103246,6 +103702,7 @@ impl Ec2 for Ec2Client {
         RusotoError<UpdateSecurityGroupRuleDescriptionsEgressError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("UpdateSecurityGroupRuleDescriptionsEgress");
         let params = self.new_params("UpdateSecurityGroupRuleDescriptionsEgress");
         let mut params = params;
         UpdateSecurityGroupRuleDescriptionsEgressRequestSerializer::serialize(
@@ -103285,6 +103742,7 @@ impl Ec2 for Ec2Client {
         RusotoError<UpdateSecurityGroupRuleDescriptionsIngressError>,
     > {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("UpdateSecurityGroupRuleDescriptionsIngress");
         let params = self.new_params("UpdateSecurityGroupRuleDescriptionsIngress");
         let mut params = params;
         UpdateSecurityGroupRuleDescriptionsIngressRequestSerializer::serialize(
@@ -103321,6 +103779,7 @@ impl Ec2 for Ec2Client {
         input: WithdrawByoipCidrRequest,
     ) -> Result<WithdrawByoipCidrResult, RusotoError<WithdrawByoipCidrError>> {
         let mut request = SignedRequest::new("POST", "ec2", &self.region, "/");
+        request.set_operation("WithdrawByoipCidr");
         let params = self.new_params("WithdrawByoipCidr");
         let mut params = params;
         WithdrawByoipCidrRequestSerializer::serialize(&mut params, "", &input);
@@ -103342,6 +103801,4430 @@ impl Ec2 for Ec2Client {
     }
 }
 
+/// A blocking client for the Amazon EC2 API, running the calls of a `Ec2Client`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `Ec2Client`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct Ec2BlockingClient {
+    client: Ec2Client,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl Ec2BlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> Ec2BlockingClient {
+        Ec2BlockingClient::from_client(Ec2Client::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> Ec2BlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        Ec2BlockingClient::from_client(Ec2Client::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> Ec2BlockingClient {
+        Ec2BlockingClient::from_client(Ec2Client::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> Ec2BlockingClient {
+        Ec2BlockingClient::from_client(Ec2Client::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: Ec2Client) -> Ec2BlockingClient {
+        Ec2BlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> Ec2BlockingClient {
+        Ec2BlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> Ec2BlockingClient {
+        Ec2BlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Ec2::accept_reserved_instances_exchange_quote`].
+    pub fn accept_reserved_instances_exchange_quote(
+        &self,
+        input: AcceptReservedInstancesExchangeQuoteRequest,
+    ) -> Result<
+        AcceptReservedInstancesExchangeQuoteResult,
+        RusotoError<AcceptReservedInstancesExchangeQuoteError>,
+    > {
+        self.runtime
+            .block_on(self.client.accept_reserved_instances_exchange_quote(input))
+    }
+
+    /// Blocking version of [`Ec2::accept_transit_gateway_multicast_domain_associations`].
+    pub fn accept_transit_gateway_multicast_domain_associations(
+        &self,
+        input: AcceptTransitGatewayMulticastDomainAssociationsRequest,
+    ) -> Result<
+        AcceptTransitGatewayMulticastDomainAssociationsResult,
+        RusotoError<AcceptTransitGatewayMulticastDomainAssociationsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .accept_transit_gateway_multicast_domain_associations(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::accept_transit_gateway_peering_attachment`].
+    pub fn accept_transit_gateway_peering_attachment(
+        &self,
+        input: AcceptTransitGatewayPeeringAttachmentRequest,
+    ) -> Result<
+        AcceptTransitGatewayPeeringAttachmentResult,
+        RusotoError<AcceptTransitGatewayPeeringAttachmentError>,
+    > {
+        self.runtime
+            .block_on(self.client.accept_transit_gateway_peering_attachment(input))
+    }
+
+    /// Blocking version of [`Ec2::accept_transit_gateway_vpc_attachment`].
+    pub fn accept_transit_gateway_vpc_attachment(
+        &self,
+        input: AcceptTransitGatewayVpcAttachmentRequest,
+    ) -> Result<
+        AcceptTransitGatewayVpcAttachmentResult,
+        RusotoError<AcceptTransitGatewayVpcAttachmentError>,
+    > {
+        self.runtime
+            .block_on(self.client.accept_transit_gateway_vpc_attachment(input))
+    }
+
+    /// Blocking version of [`Ec2::accept_vpc_endpoint_connections`].
+    pub fn accept_vpc_endpoint_connections(
+        &self,
+        input: AcceptVpcEndpointConnectionsRequest,
+    ) -> Result<AcceptVpcEndpointConnectionsResult, RusotoError<AcceptVpcEndpointConnectionsError>>
+    {
+        self.runtime
+            .block_on(self.client.accept_vpc_endpoint_connections(input))
+    }
+
+    /// Blocking version of [`Ec2::accept_vpc_peering_connection`].
+    pub fn accept_vpc_peering_connection(
+        &self,
+        input: AcceptVpcPeeringConnectionRequest,
+    ) -> Result<AcceptVpcPeeringConnectionResult, RusotoError<AcceptVpcPeeringConnectionError>>
+    {
+        self.runtime
+            .block_on(self.client.accept_vpc_peering_connection(input))
+    }
+
+    /// Blocking version of [`Ec2::advertise_byoip_cidr`].
+    pub fn advertise_byoip_cidr(
+        &self,
+        input: AdvertiseByoipCidrRequest,
+    ) -> Result<AdvertiseByoipCidrResult, RusotoError<AdvertiseByoipCidrError>> {
+        self.runtime
+            .block_on(self.client.advertise_byoip_cidr(input))
+    }
+
+    /// Blocking version of [`Ec2::allocate_address`].
+    pub fn allocate_address(
+        &self,
+        input: AllocateAddressRequest,
+    ) -> Result<AllocateAddressResult, RusotoError<AllocateAddressError>> {
+        self.runtime.block_on(self.client.allocate_address(input))
+    }
+
+    /// Blocking version of [`Ec2::allocate_hosts`].
+    pub fn allocate_hosts(
+        &self,
+        input: AllocateHostsRequest,
+    ) -> Result<AllocateHostsResult, RusotoError<AllocateHostsError>> {
+        self.runtime.block_on(self.client.allocate_hosts(input))
+    }
+
+    /// Blocking version of [`Ec2::apply_security_groups_to_client_vpn_target_network`].
+    pub fn apply_security_groups_to_client_vpn_target_network(
+        &self,
+        input: ApplySecurityGroupsToClientVpnTargetNetworkRequest,
+    ) -> Result<
+        ApplySecurityGroupsToClientVpnTargetNetworkResult,
+        RusotoError<ApplySecurityGroupsToClientVpnTargetNetworkError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .apply_security_groups_to_client_vpn_target_network(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::assign_ipv_6_addresses`].
+    pub fn assign_ipv_6_addresses(
+        &self,
+        input: AssignIpv6AddressesRequest,
+    ) -> Result<AssignIpv6AddressesResult, RusotoError<AssignIpv6AddressesError>> {
+        self.runtime
+            .block_on(self.client.assign_ipv_6_addresses(input))
+    }
+
+    /// Blocking version of [`Ec2::assign_private_ip_addresses`].
+    pub fn assign_private_ip_addresses(
+        &self,
+        input: AssignPrivateIpAddressesRequest,
+    ) -> Result<AssignPrivateIpAddressesResult, RusotoError<AssignPrivateIpAddressesError>> {
+        self.runtime
+            .block_on(self.client.assign_private_ip_addresses(input))
+    }
+
+    /// Blocking version of [`Ec2::associate_address`].
+    pub fn associate_address(
+        &self,
+        input: AssociateAddressRequest,
+    ) -> Result<AssociateAddressResult, RusotoError<AssociateAddressError>> {
+        self.runtime.block_on(self.client.associate_address(input))
+    }
+
+    /// Blocking version of [`Ec2::associate_client_vpn_target_network`].
+    pub fn associate_client_vpn_target_network(
+        &self,
+        input: AssociateClientVpnTargetNetworkRequest,
+    ) -> Result<
+        AssociateClientVpnTargetNetworkResult,
+        RusotoError<AssociateClientVpnTargetNetworkError>,
+    > {
+        self.runtime
+            .block_on(self.client.associate_client_vpn_target_network(input))
+    }
+
+    /// Blocking version of [`Ec2::associate_dhcp_options`].
+    pub fn associate_dhcp_options(
+        &self,
+        input: AssociateDhcpOptionsRequest,
+    ) -> Result<(), RusotoError<AssociateDhcpOptionsError>> {
+        self.runtime
+            .block_on(self.client.associate_dhcp_options(input))
+    }
+
+    /// Blocking version of [`Ec2::associate_enclave_certificate_iam_role`].
+    pub fn associate_enclave_certificate_iam_role(
+        &self,
+        input: AssociateEnclaveCertificateIamRoleRequest,
+    ) -> Result<
+        AssociateEnclaveCertificateIamRoleResult,
+        RusotoError<AssociateEnclaveCertificateIamRoleError>,
+    > {
+        self.runtime
+            .block_on(self.client.associate_enclave_certificate_iam_role(input))
+    }
+
+    /// Blocking version of [`Ec2::associate_iam_instance_profile`].
+    pub fn associate_iam_instance_profile(
+        &self,
+        input: AssociateIamInstanceProfileRequest,
+    ) -> Result<AssociateIamInstanceProfileResult, RusotoError<AssociateIamInstanceProfileError>>
+    {
+        self.runtime
+            .block_on(self.client.associate_iam_instance_profile(input))
+    }
+
+    /// Blocking version of [`Ec2::associate_route_table`].
+    pub fn associate_route_table(
+        &self,
+        input: AssociateRouteTableRequest,
+    ) -> Result<AssociateRouteTableResult, RusotoError<AssociateRouteTableError>> {
+        self.runtime
+            .block_on(self.client.associate_route_table(input))
+    }
+
+    /// Blocking version of [`Ec2::associate_subnet_cidr_block`].
+    pub fn associate_subnet_cidr_block(
+        &self,
+        input: AssociateSubnetCidrBlockRequest,
+    ) -> Result<AssociateSubnetCidrBlockResult, RusotoError<AssociateSubnetCidrBlockError>> {
+        self.runtime
+            .block_on(self.client.associate_subnet_cidr_block(input))
+    }
+
+    /// Blocking version of [`Ec2::associate_transit_gateway_multicast_domain`].
+    pub fn associate_transit_gateway_multicast_domain(
+        &self,
+        input: AssociateTransitGatewayMulticastDomainRequest,
+    ) -> Result<
+        AssociateTransitGatewayMulticastDomainResult,
+        RusotoError<AssociateTransitGatewayMulticastDomainError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .associate_transit_gateway_multicast_domain(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::associate_transit_gateway_route_table`].
+    pub fn associate_transit_gateway_route_table(
+        &self,
+        input: AssociateTransitGatewayRouteTableRequest,
+    ) -> Result<
+        AssociateTransitGatewayRouteTableResult,
+        RusotoError<AssociateTransitGatewayRouteTableError>,
+    > {
+        self.runtime
+            .block_on(self.client.associate_transit_gateway_route_table(input))
+    }
+
+    /// Blocking version of [`Ec2::associate_vpc_cidr_block`].
+    pub fn associate_vpc_cidr_block(
+        &self,
+        input: AssociateVpcCidrBlockRequest,
+    ) -> Result<AssociateVpcCidrBlockResult, RusotoError<AssociateVpcCidrBlockError>> {
+        self.runtime
+            .block_on(self.client.associate_vpc_cidr_block(input))
+    }
+
+    /// Blocking version of [`Ec2::attach_classic_link_vpc`].
+    pub fn attach_classic_link_vpc(
+        &self,
+        input: AttachClassicLinkVpcRequest,
+    ) -> Result<AttachClassicLinkVpcResult, RusotoError<AttachClassicLinkVpcError>> {
+        self.runtime
+            .block_on(self.client.attach_classic_link_vpc(input))
+    }
+
+    /// Blocking version of [`Ec2::attach_internet_gateway`].
+    pub fn attach_internet_gateway(
+        &self,
+        input: AttachInternetGatewayRequest,
+    ) -> Result<(), RusotoError<AttachInternetGatewayError>> {
+        self.runtime
+            .block_on(self.client.attach_internet_gateway(input))
+    }
+
+    /// Blocking version of [`Ec2::attach_network_interface`].
+    pub fn attach_network_interface(
+        &self,
+        input: AttachNetworkInterfaceRequest,
+    ) -> Result<AttachNetworkInterfaceResult, RusotoError<AttachNetworkInterfaceError>> {
+        self.runtime
+            .block_on(self.client.attach_network_interface(input))
+    }
+
+    /// Blocking version of [`Ec2::attach_volume`].
+    pub fn attach_volume(
+        &self,
+        input: AttachVolumeRequest,
+    ) -> Result<VolumeAttachment, RusotoError<AttachVolumeError>> {
+        self.runtime.block_on(self.client.attach_volume(input))
+    }
+
+    /// Blocking version of [`Ec2::attach_vpn_gateway`].
+    pub fn attach_vpn_gateway(
+        &self,
+        input: AttachVpnGatewayRequest,
+    ) -> Result<AttachVpnGatewayResult, RusotoError<AttachVpnGatewayError>> {
+        self.runtime.block_on(self.client.attach_vpn_gateway(input))
+    }
+
+    /// Blocking version of [`Ec2::authorize_client_vpn_ingress`].
+    pub fn authorize_client_vpn_ingress(
+        &self,
+        input: AuthorizeClientVpnIngressRequest,
+    ) -> Result<AuthorizeClientVpnIngressResult, RusotoError<AuthorizeClientVpnIngressError>> {
+        self.runtime
+            .block_on(self.client.authorize_client_vpn_ingress(input))
+    }
+
+    /// Blocking version of [`Ec2::authorize_security_group_egress`].
+    pub fn authorize_security_group_egress(
+        &self,
+        input: AuthorizeSecurityGroupEgressRequest,
+    ) -> Result<(), RusotoError<AuthorizeSecurityGroupEgressError>> {
+        self.runtime
+            .block_on(self.client.authorize_security_group_egress(input))
+    }
+
+    /// Blocking version of [`Ec2::authorize_security_group_ingress`].
+    pub fn authorize_security_group_ingress(
+        &self,
+        input: AuthorizeSecurityGroupIngressRequest,
+    ) -> Result<(), RusotoError<AuthorizeSecurityGroupIngressError>> {
+        self.runtime
+            .block_on(self.client.authorize_security_group_ingress(input))
+    }
+
+    /// Blocking version of [`Ec2::bundle_instance`].
+    pub fn bundle_instance(
+        &self,
+        input: BundleInstanceRequest,
+    ) -> Result<BundleInstanceResult, RusotoError<BundleInstanceError>> {
+        self.runtime.block_on(self.client.bundle_instance(input))
+    }
+
+    /// Blocking version of [`Ec2::cancel_bundle_task`].
+    pub fn cancel_bundle_task(
+        &self,
+        input: CancelBundleTaskRequest,
+    ) -> Result<CancelBundleTaskResult, RusotoError<CancelBundleTaskError>> {
+        self.runtime.block_on(self.client.cancel_bundle_task(input))
+    }
+
+    /// Blocking version of [`Ec2::cancel_capacity_reservation`].
+    pub fn cancel_capacity_reservation(
+        &self,
+        input: CancelCapacityReservationRequest,
+    ) -> Result<CancelCapacityReservationResult, RusotoError<CancelCapacityReservationError>> {
+        self.runtime
+            .block_on(self.client.cancel_capacity_reservation(input))
+    }
+
+    /// Blocking version of [`Ec2::cancel_conversion_task`].
+    pub fn cancel_conversion_task(
+        &self,
+        input: CancelConversionRequest,
+    ) -> Result<(), RusotoError<CancelConversionTaskError>> {
+        self.runtime
+            .block_on(self.client.cancel_conversion_task(input))
+    }
+
+    /// Blocking version of [`Ec2::cancel_export_task`].
+    pub fn cancel_export_task(
+        &self,
+        input: CancelExportTaskRequest,
+    ) -> Result<(), RusotoError<CancelExportTaskError>> {
+        self.runtime.block_on(self.client.cancel_export_task(input))
+    }
+
+    /// Blocking version of [`Ec2::cancel_import_task`].
+    pub fn cancel_import_task(
+        &self,
+        input: CancelImportTaskRequest,
+    ) -> Result<CancelImportTaskResult, RusotoError<CancelImportTaskError>> {
+        self.runtime.block_on(self.client.cancel_import_task(input))
+    }
+
+    /// Blocking version of [`Ec2::cancel_reserved_instances_listing`].
+    pub fn cancel_reserved_instances_listing(
+        &self,
+        input: CancelReservedInstancesListingRequest,
+    ) -> Result<
+        CancelReservedInstancesListingResult,
+        RusotoError<CancelReservedInstancesListingError>,
+    > {
+        self.runtime
+            .block_on(self.client.cancel_reserved_instances_listing(input))
+    }
+
+    /// Blocking version of [`Ec2::cancel_spot_fleet_requests`].
+    pub fn cancel_spot_fleet_requests(
+        &self,
+        input: CancelSpotFleetRequestsRequest,
+    ) -> Result<CancelSpotFleetRequestsResponse, RusotoError<EC2CancelSpotFleetRequestsError>> {
+        self.runtime
+            .block_on(self.client.cancel_spot_fleet_requests(input))
+    }
+
+    /// Blocking version of [`Ec2::cancel_spot_instance_requests`].
+    pub fn cancel_spot_instance_requests(
+        &self,
+        input: CancelSpotInstanceRequestsRequest,
+    ) -> Result<CancelSpotInstanceRequestsResult, RusotoError<CancelSpotInstanceRequestsError>>
+    {
+        self.runtime
+            .block_on(self.client.cancel_spot_instance_requests(input))
+    }
+
+    /// Blocking version of [`Ec2::confirm_product_instance`].
+    pub fn confirm_product_instance(
+        &self,
+        input: ConfirmProductInstanceRequest,
+    ) -> Result<ConfirmProductInstanceResult, RusotoError<ConfirmProductInstanceError>> {
+        self.runtime
+            .block_on(self.client.confirm_product_instance(input))
+    }
+
+    /// Blocking version of [`Ec2::copy_fpga_image`].
+    pub fn copy_fpga_image(
+        &self,
+        input: CopyFpgaImageRequest,
+    ) -> Result<CopyFpgaImageResult, RusotoError<CopyFpgaImageError>> {
+        self.runtime.block_on(self.client.copy_fpga_image(input))
+    }
+
+    /// Blocking version of [`Ec2::copy_image`].
+    pub fn copy_image(
+        &self,
+        input: CopyImageRequest,
+    ) -> Result<CopyImageResult, RusotoError<CopyImageError>> {
+        self.runtime.block_on(self.client.copy_image(input))
+    }
+
+    /// Blocking version of [`Ec2::copy_snapshot`].
+    pub fn copy_snapshot(
+        &self,
+        input: CopySnapshotRequest,
+    ) -> Result<CopySnapshotResult, RusotoError<CopySnapshotError>> {
+        self.runtime.block_on(self.client.copy_snapshot(input))
+    }
+
+    /// Blocking version of [`Ec2::create_capacity_reservation`].
+    pub fn create_capacity_reservation(
+        &self,
+        input: CreateCapacityReservationRequest,
+    ) -> Result<CreateCapacityReservationResult, RusotoError<CreateCapacityReservationError>> {
+        self.runtime
+            .block_on(self.client.create_capacity_reservation(input))
+    }
+
+    /// Blocking version of [`Ec2::create_carrier_gateway`].
+    pub fn create_carrier_gateway(
+        &self,
+        input: CreateCarrierGatewayRequest,
+    ) -> Result<CreateCarrierGatewayResult, RusotoError<CreateCarrierGatewayError>> {
+        self.runtime
+            .block_on(self.client.create_carrier_gateway(input))
+    }
+
+    /// Blocking version of [`Ec2::create_client_vpn_endpoint`].
+    pub fn create_client_vpn_endpoint(
+        &self,
+        input: CreateClientVpnEndpointRequest,
+    ) -> Result<CreateClientVpnEndpointResult, RusotoError<CreateClientVpnEndpointError>> {
+        self.runtime
+            .block_on(self.client.create_client_vpn_endpoint(input))
+    }
+
+    /// Blocking version of [`Ec2::create_client_vpn_route`].
+    pub fn create_client_vpn_route(
+        &self,
+        input: CreateClientVpnRouteRequest,
+    ) -> Result<CreateClientVpnRouteResult, RusotoError<CreateClientVpnRouteError>> {
+        self.runtime
+            .block_on(self.client.create_client_vpn_route(input))
+    }
+
+    /// Blocking version of [`Ec2::create_customer_gateway`].
+    pub fn create_customer_gateway(
+        &self,
+        input: CreateCustomerGatewayRequest,
+    ) -> Result<CreateCustomerGatewayResult, RusotoError<CreateCustomerGatewayError>> {
+        self.runtime
+            .block_on(self.client.create_customer_gateway(input))
+    }
+
+    /// Blocking version of [`Ec2::create_default_subnet`].
+    pub fn create_default_subnet(
+        &self,
+        input: CreateDefaultSubnetRequest,
+    ) -> Result<CreateDefaultSubnetResult, RusotoError<CreateDefaultSubnetError>> {
+        self.runtime
+            .block_on(self.client.create_default_subnet(input))
+    }
+
+    /// Blocking version of [`Ec2::create_default_vpc`].
+    pub fn create_default_vpc(
+        &self,
+        input: CreateDefaultVpcRequest,
+    ) -> Result<CreateDefaultVpcResult, RusotoError<CreateDefaultVpcError>> {
+        self.runtime.block_on(self.client.create_default_vpc(input))
+    }
+
+    /// Blocking version of [`Ec2::create_dhcp_options`].
+    pub fn create_dhcp_options(
+        &self,
+        input: CreateDhcpOptionsRequest,
+    ) -> Result<CreateDhcpOptionsResult, RusotoError<CreateDhcpOptionsError>> {
+        self.runtime
+            .block_on(self.client.create_dhcp_options(input))
+    }
+
+    /// Blocking version of [`Ec2::create_egress_only_internet_gateway`].
+    pub fn create_egress_only_internet_gateway(
+        &self,
+        input: CreateEgressOnlyInternetGatewayRequest,
+    ) -> Result<
+        CreateEgressOnlyInternetGatewayResult,
+        RusotoError<CreateEgressOnlyInternetGatewayError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_egress_only_internet_gateway(input))
+    }
+
+    /// Blocking version of [`Ec2::create_fleet`].
+    pub fn create_fleet(
+        &self,
+        input: CreateFleetRequest,
+    ) -> Result<CreateFleetResult, RusotoError<CreateFleetError>> {
+        self.runtime.block_on(self.client.create_fleet(input))
+    }
+
+    /// Blocking version of [`Ec2::create_flow_logs`].
+    pub fn create_flow_logs(
+        &self,
+        input: CreateFlowLogsRequest,
+    ) -> Result<CreateFlowLogsResult, RusotoError<CreateFlowLogsError>> {
+        self.runtime.block_on(self.client.create_flow_logs(input))
+    }
+
+    /// Blocking version of [`Ec2::create_fpga_image`].
+    pub fn create_fpga_image(
+        &self,
+        input: CreateFpgaImageRequest,
+    ) -> Result<CreateFpgaImageResult, RusotoError<CreateFpgaImageError>> {
+        self.runtime.block_on(self.client.create_fpga_image(input))
+    }
+
+    /// Blocking version of [`Ec2::create_image`].
+    pub fn create_image(
+        &self,
+        input: CreateImageRequest,
+    ) -> Result<CreateImageResult, RusotoError<CreateImageError>> {
+        self.runtime.block_on(self.client.create_image(input))
+    }
+
+    /// Blocking version of [`Ec2::create_instance_export_task`].
+    pub fn create_instance_export_task(
+        &self,
+        input: CreateInstanceExportTaskRequest,
+    ) -> Result<CreateInstanceExportTaskResult, RusotoError<CreateInstanceExportTaskError>> {
+        self.runtime
+            .block_on(self.client.create_instance_export_task(input))
+    }
+
+    /// Blocking version of [`Ec2::create_internet_gateway`].
+    pub fn create_internet_gateway(
+        &self,
+        input: CreateInternetGatewayRequest,
+    ) -> Result<CreateInternetGatewayResult, RusotoError<CreateInternetGatewayError>> {
+        self.runtime
+            .block_on(self.client.create_internet_gateway(input))
+    }
+
+    /// Blocking version of [`Ec2::create_key_pair`].
+    pub fn create_key_pair(
+        &self,
+        input: CreateKeyPairRequest,
+    ) -> Result<KeyPair, RusotoError<CreateKeyPairError>> {
+        self.runtime.block_on(self.client.create_key_pair(input))
+    }
+
+    /// Blocking version of [`Ec2::create_launch_template`].
+    pub fn create_launch_template(
+        &self,
+        input: CreateLaunchTemplateRequest,
+    ) -> Result<CreateLaunchTemplateResult, RusotoError<CreateLaunchTemplateError>> {
+        self.runtime
+            .block_on(self.client.create_launch_template(input))
+    }
+
+    /// Blocking version of [`Ec2::create_launch_template_version`].
+    pub fn create_launch_template_version(
+        &self,
+        input: CreateLaunchTemplateVersionRequest,
+    ) -> Result<CreateLaunchTemplateVersionResult, RusotoError<CreateLaunchTemplateVersionError>>
+    {
+        self.runtime
+            .block_on(self.client.create_launch_template_version(input))
+    }
+
+    /// Blocking version of [`Ec2::create_local_gateway_route`].
+    pub fn create_local_gateway_route(
+        &self,
+        input: CreateLocalGatewayRouteRequest,
+    ) -> Result<CreateLocalGatewayRouteResult, RusotoError<CreateLocalGatewayRouteError>> {
+        self.runtime
+            .block_on(self.client.create_local_gateway_route(input))
+    }
+
+    /// Blocking version of [`Ec2::create_local_gateway_route_table_vpc_association`].
+    pub fn create_local_gateway_route_table_vpc_association(
+        &self,
+        input: CreateLocalGatewayRouteTableVpcAssociationRequest,
+    ) -> Result<
+        CreateLocalGatewayRouteTableVpcAssociationResult,
+        RusotoError<CreateLocalGatewayRouteTableVpcAssociationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .create_local_gateway_route_table_vpc_association(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::create_managed_prefix_list`].
+    pub fn create_managed_prefix_list(
+        &self,
+        input: CreateManagedPrefixListRequest,
+    ) -> Result<CreateManagedPrefixListResult, RusotoError<CreateManagedPrefixListError>> {
+        self.runtime
+            .block_on(self.client.create_managed_prefix_list(input))
+    }
+
+    /// Blocking version of [`Ec2::create_nat_gateway`].
+    pub fn create_nat_gateway(
+        &self,
+        input: CreateNatGatewayRequest,
+    ) -> Result<CreateNatGatewayResult, RusotoError<CreateNatGatewayError>> {
+        self.runtime.block_on(self.client.create_nat_gateway(input))
+    }
+
+    /// Blocking version of [`Ec2::create_network_acl`].
+    pub fn create_network_acl(
+        &self,
+        input: CreateNetworkAclRequest,
+    ) -> Result<CreateNetworkAclResult, RusotoError<CreateNetworkAclError>> {
+        self.runtime.block_on(self.client.create_network_acl(input))
+    }
+
+    /// Blocking version of [`Ec2::create_network_acl_entry`].
+    pub fn create_network_acl_entry(
+        &self,
+        input: CreateNetworkAclEntryRequest,
+    ) -> Result<(), RusotoError<CreateNetworkAclEntryError>> {
+        self.runtime
+            .block_on(self.client.create_network_acl_entry(input))
+    }
+
+    /// Blocking version of [`Ec2::create_network_insights_path`].
+    pub fn create_network_insights_path(
+        &self,
+        input: CreateNetworkInsightsPathRequest,
+    ) -> Result<CreateNetworkInsightsPathResult, RusotoError<CreateNetworkInsightsPathError>> {
+        self.runtime
+            .block_on(self.client.create_network_insights_path(input))
+    }
+
+    /// Blocking version of [`Ec2::create_network_interface`].
+    pub fn create_network_interface(
+        &self,
+        input: CreateNetworkInterfaceRequest,
+    ) -> Result<CreateNetworkInterfaceResult, RusotoError<CreateNetworkInterfaceError>> {
+        self.runtime
+            .block_on(self.client.create_network_interface(input))
+    }
+
+    /// Blocking version of [`Ec2::create_network_interface_permission`].
+    pub fn create_network_interface_permission(
+        &self,
+        input: CreateNetworkInterfacePermissionRequest,
+    ) -> Result<
+        CreateNetworkInterfacePermissionResult,
+        RusotoError<CreateNetworkInterfacePermissionError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_network_interface_permission(input))
+    }
+
+    /// Blocking version of [`Ec2::create_placement_group`].
+    pub fn create_placement_group(
+        &self,
+        input: CreatePlacementGroupRequest,
+    ) -> Result<CreatePlacementGroupResult, RusotoError<CreatePlacementGroupError>> {
+        self.runtime
+            .block_on(self.client.create_placement_group(input))
+    }
+
+    /// Blocking version of [`Ec2::create_reserved_instances_listing`].
+    pub fn create_reserved_instances_listing(
+        &self,
+        input: CreateReservedInstancesListingRequest,
+    ) -> Result<
+        CreateReservedInstancesListingResult,
+        RusotoError<CreateReservedInstancesListingError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_reserved_instances_listing(input))
+    }
+
+    /// Blocking version of [`Ec2::create_route`].
+    pub fn create_route(
+        &self,
+        input: CreateRouteRequest,
+    ) -> Result<CreateRouteResult, RusotoError<CreateRouteError>> {
+        self.runtime.block_on(self.client.create_route(input))
+    }
+
+    /// Blocking version of [`Ec2::create_route_table`].
+    pub fn create_route_table(
+        &self,
+        input: CreateRouteTableRequest,
+    ) -> Result<CreateRouteTableResult, RusotoError<CreateRouteTableError>> {
+        self.runtime.block_on(self.client.create_route_table(input))
+    }
+
+    /// Blocking version of [`Ec2::create_security_group`].
+    pub fn create_security_group(
+        &self,
+        input: CreateSecurityGroupRequest,
+    ) -> Result<CreateSecurityGroupResult, RusotoError<CreateSecurityGroupError>> {
+        self.runtime
+            .block_on(self.client.create_security_group(input))
+    }
+
+    /// Blocking version of [`Ec2::create_snapshot`].
+    pub fn create_snapshot(
+        &self,
+        input: CreateSnapshotRequest,
+    ) -> Result<Snapshot, RusotoError<CreateSnapshotError>> {
+        self.runtime.block_on(self.client.create_snapshot(input))
+    }
+
+    /// Blocking version of [`Ec2::create_snapshots`].
+    pub fn create_snapshots(
+        &self,
+        input: CreateSnapshotsRequest,
+    ) -> Result<CreateSnapshotsResult, RusotoError<CreateSnapshotsError>> {
+        self.runtime.block_on(self.client.create_snapshots(input))
+    }
+
+    /// Blocking version of [`Ec2::create_spot_datafeed_subscription`].
+    pub fn create_spot_datafeed_subscription(
+        &self,
+        input: CreateSpotDatafeedSubscriptionRequest,
+    ) -> Result<
+        CreateSpotDatafeedSubscriptionResult,
+        RusotoError<CreateSpotDatafeedSubscriptionError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_spot_datafeed_subscription(input))
+    }
+
+    /// Blocking version of [`Ec2::create_subnet`].
+    pub fn create_subnet(
+        &self,
+        input: CreateSubnetRequest,
+    ) -> Result<CreateSubnetResult, RusotoError<CreateSubnetError>> {
+        self.runtime.block_on(self.client.create_subnet(input))
+    }
+
+    /// Blocking version of [`Ec2::create_tags`].
+    pub fn create_tags(
+        &self,
+        input: CreateTagsRequest,
+    ) -> Result<(), RusotoError<CreateTagsError>> {
+        self.runtime.block_on(self.client.create_tags(input))
+    }
+
+    /// Blocking version of [`Ec2::create_traffic_mirror_filter`].
+    pub fn create_traffic_mirror_filter(
+        &self,
+        input: CreateTrafficMirrorFilterRequest,
+    ) -> Result<CreateTrafficMirrorFilterResult, RusotoError<CreateTrafficMirrorFilterError>> {
+        self.runtime
+            .block_on(self.client.create_traffic_mirror_filter(input))
+    }
+
+    /// Blocking version of [`Ec2::create_traffic_mirror_filter_rule`].
+    pub fn create_traffic_mirror_filter_rule(
+        &self,
+        input: CreateTrafficMirrorFilterRuleRequest,
+    ) -> Result<CreateTrafficMirrorFilterRuleResult, RusotoError<CreateTrafficMirrorFilterRuleError>>
+    {
+        self.runtime
+            .block_on(self.client.create_traffic_mirror_filter_rule(input))
+    }
+
+    /// Blocking version of [`Ec2::create_traffic_mirror_session`].
+    pub fn create_traffic_mirror_session(
+        &self,
+        input: CreateTrafficMirrorSessionRequest,
+    ) -> Result<CreateTrafficMirrorSessionResult, RusotoError<CreateTrafficMirrorSessionError>>
+    {
+        self.runtime
+            .block_on(self.client.create_traffic_mirror_session(input))
+    }
+
+    /// Blocking version of [`Ec2::create_traffic_mirror_target`].
+    pub fn create_traffic_mirror_target(
+        &self,
+        input: CreateTrafficMirrorTargetRequest,
+    ) -> Result<CreateTrafficMirrorTargetResult, RusotoError<CreateTrafficMirrorTargetError>> {
+        self.runtime
+            .block_on(self.client.create_traffic_mirror_target(input))
+    }
+
+    /// Blocking version of [`Ec2::create_transit_gateway`].
+    pub fn create_transit_gateway(
+        &self,
+        input: CreateTransitGatewayRequest,
+    ) -> Result<CreateTransitGatewayResult, RusotoError<CreateTransitGatewayError>> {
+        self.runtime
+            .block_on(self.client.create_transit_gateway(input))
+    }
+
+    /// Blocking version of [`Ec2::create_transit_gateway_connect`].
+    pub fn create_transit_gateway_connect(
+        &self,
+        input: CreateTransitGatewayConnectRequest,
+    ) -> Result<CreateTransitGatewayConnectResult, RusotoError<CreateTransitGatewayConnectError>>
+    {
+        self.runtime
+            .block_on(self.client.create_transit_gateway_connect(input))
+    }
+
+    /// Blocking version of [`Ec2::create_transit_gateway_connect_peer`].
+    pub fn create_transit_gateway_connect_peer(
+        &self,
+        input: CreateTransitGatewayConnectPeerRequest,
+    ) -> Result<
+        CreateTransitGatewayConnectPeerResult,
+        RusotoError<CreateTransitGatewayConnectPeerError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_transit_gateway_connect_peer(input))
+    }
+
+    /// Blocking version of [`Ec2::create_transit_gateway_multicast_domain`].
+    pub fn create_transit_gateway_multicast_domain(
+        &self,
+        input: CreateTransitGatewayMulticastDomainRequest,
+    ) -> Result<
+        CreateTransitGatewayMulticastDomainResult,
+        RusotoError<CreateTransitGatewayMulticastDomainError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_transit_gateway_multicast_domain(input))
+    }
+
+    /// Blocking version of [`Ec2::create_transit_gateway_peering_attachment`].
+    pub fn create_transit_gateway_peering_attachment(
+        &self,
+        input: CreateTransitGatewayPeeringAttachmentRequest,
+    ) -> Result<
+        CreateTransitGatewayPeeringAttachmentResult,
+        RusotoError<CreateTransitGatewayPeeringAttachmentError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_transit_gateway_peering_attachment(input))
+    }
+
+    /// Blocking version of [`Ec2::create_transit_gateway_prefix_list_reference`].
+    pub fn create_transit_gateway_prefix_list_reference(
+        &self,
+        input: CreateTransitGatewayPrefixListReferenceRequest,
+    ) -> Result<
+        CreateTransitGatewayPrefixListReferenceResult,
+        RusotoError<CreateTransitGatewayPrefixListReferenceError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .create_transit_gateway_prefix_list_reference(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::create_transit_gateway_route`].
+    pub fn create_transit_gateway_route(
+        &self,
+        input: CreateTransitGatewayRouteRequest,
+    ) -> Result<CreateTransitGatewayRouteResult, RusotoError<CreateTransitGatewayRouteError>> {
+        self.runtime
+            .block_on(self.client.create_transit_gateway_route(input))
+    }
+
+    /// Blocking version of [`Ec2::create_transit_gateway_route_table`].
+    pub fn create_transit_gateway_route_table(
+        &self,
+        input: CreateTransitGatewayRouteTableRequest,
+    ) -> Result<
+        CreateTransitGatewayRouteTableResult,
+        RusotoError<CreateTransitGatewayRouteTableError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_transit_gateway_route_table(input))
+    }
+
+    /// Blocking version of [`Ec2::create_transit_gateway_vpc_attachment`].
+    pub fn create_transit_gateway_vpc_attachment(
+        &self,
+        input: CreateTransitGatewayVpcAttachmentRequest,
+    ) -> Result<
+        CreateTransitGatewayVpcAttachmentResult,
+        RusotoError<CreateTransitGatewayVpcAttachmentError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_transit_gateway_vpc_attachment(input))
+    }
+
+    /// Blocking version of [`Ec2::create_volume`].
+    pub fn create_volume(
+        &self,
+        input: CreateVolumeRequest,
+    ) -> Result<Volume, RusotoError<CreateVolumeError>> {
+        self.runtime.block_on(self.client.create_volume(input))
+    }
+
+    /// Blocking version of [`Ec2::create_vpc`].
+    pub fn create_vpc(
+        &self,
+        input: CreateVpcRequest,
+    ) -> Result<CreateVpcResult, RusotoError<CreateVpcError>> {
+        self.runtime.block_on(self.client.create_vpc(input))
+    }
+
+    /// Blocking version of [`Ec2::create_vpc_endpoint`].
+    pub fn create_vpc_endpoint(
+        &self,
+        input: CreateVpcEndpointRequest,
+    ) -> Result<CreateVpcEndpointResult, RusotoError<CreateVpcEndpointError>> {
+        self.runtime
+            .block_on(self.client.create_vpc_endpoint(input))
+    }
+
+    /// Blocking version of [`Ec2::create_vpc_endpoint_connection_notification`].
+    pub fn create_vpc_endpoint_connection_notification(
+        &self,
+        input: CreateVpcEndpointConnectionNotificationRequest,
+    ) -> Result<
+        CreateVpcEndpointConnectionNotificationResult,
+        RusotoError<CreateVpcEndpointConnectionNotificationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .create_vpc_endpoint_connection_notification(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::create_vpc_endpoint_service_configuration`].
+    pub fn create_vpc_endpoint_service_configuration(
+        &self,
+        input: CreateVpcEndpointServiceConfigurationRequest,
+    ) -> Result<
+        CreateVpcEndpointServiceConfigurationResult,
+        RusotoError<CreateVpcEndpointServiceConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_vpc_endpoint_service_configuration(input))
+    }
+
+    /// Blocking version of [`Ec2::create_vpc_peering_connection`].
+    pub fn create_vpc_peering_connection(
+        &self,
+        input: CreateVpcPeeringConnectionRequest,
+    ) -> Result<CreateVpcPeeringConnectionResult, RusotoError<CreateVpcPeeringConnectionError>>
+    {
+        self.runtime
+            .block_on(self.client.create_vpc_peering_connection(input))
+    }
+
+    /// Blocking version of [`Ec2::create_vpn_connection`].
+    pub fn create_vpn_connection(
+        &self,
+        input: CreateVpnConnectionRequest,
+    ) -> Result<CreateVpnConnectionResult, RusotoError<CreateVpnConnectionError>> {
+        self.runtime
+            .block_on(self.client.create_vpn_connection(input))
+    }
+
+    /// Blocking version of [`Ec2::create_vpn_connection_route`].
+    pub fn create_vpn_connection_route(
+        &self,
+        input: CreateVpnConnectionRouteRequest,
+    ) -> Result<(), RusotoError<CreateVpnConnectionRouteError>> {
+        self.runtime
+            .block_on(self.client.create_vpn_connection_route(input))
+    }
+
+    /// Blocking version of [`Ec2::create_vpn_gateway`].
+    pub fn create_vpn_gateway(
+        &self,
+        input: CreateVpnGatewayRequest,
+    ) -> Result<CreateVpnGatewayResult, RusotoError<CreateVpnGatewayError>> {
+        self.runtime.block_on(self.client.create_vpn_gateway(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_carrier_gateway`].
+    pub fn delete_carrier_gateway(
+        &self,
+        input: DeleteCarrierGatewayRequest,
+    ) -> Result<DeleteCarrierGatewayResult, RusotoError<DeleteCarrierGatewayError>> {
+        self.runtime
+            .block_on(self.client.delete_carrier_gateway(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_client_vpn_endpoint`].
+    pub fn delete_client_vpn_endpoint(
+        &self,
+        input: DeleteClientVpnEndpointRequest,
+    ) -> Result<DeleteClientVpnEndpointResult, RusotoError<DeleteClientVpnEndpointError>> {
+        self.runtime
+            .block_on(self.client.delete_client_vpn_endpoint(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_client_vpn_route`].
+    pub fn delete_client_vpn_route(
+        &self,
+        input: DeleteClientVpnRouteRequest,
+    ) -> Result<DeleteClientVpnRouteResult, RusotoError<DeleteClientVpnRouteError>> {
+        self.runtime
+            .block_on(self.client.delete_client_vpn_route(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_customer_gateway`].
+    pub fn delete_customer_gateway(
+        &self,
+        input: DeleteCustomerGatewayRequest,
+    ) -> Result<(), RusotoError<DeleteCustomerGatewayError>> {
+        self.runtime
+            .block_on(self.client.delete_customer_gateway(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_dhcp_options`].
+    pub fn delete_dhcp_options(
+        &self,
+        input: DeleteDhcpOptionsRequest,
+    ) -> Result<(), RusotoError<DeleteDhcpOptionsError>> {
+        self.runtime
+            .block_on(self.client.delete_dhcp_options(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_egress_only_internet_gateway`].
+    pub fn delete_egress_only_internet_gateway(
+        &self,
+        input: DeleteEgressOnlyInternetGatewayRequest,
+    ) -> Result<
+        DeleteEgressOnlyInternetGatewayResult,
+        RusotoError<DeleteEgressOnlyInternetGatewayError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_egress_only_internet_gateway(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_fleets`].
+    pub fn delete_fleets(
+        &self,
+        input: DeleteFleetsRequest,
+    ) -> Result<DeleteFleetsResult, RusotoError<DeleteFleetsError>> {
+        self.runtime.block_on(self.client.delete_fleets(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_flow_logs`].
+    pub fn delete_flow_logs(
+        &self,
+        input: DeleteFlowLogsRequest,
+    ) -> Result<DeleteFlowLogsResult, RusotoError<DeleteFlowLogsError>> {
+        self.runtime.block_on(self.client.delete_flow_logs(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_fpga_image`].
+    pub fn delete_fpga_image(
+        &self,
+        input: DeleteFpgaImageRequest,
+    ) -> Result<DeleteFpgaImageResult, RusotoError<DeleteFpgaImageError>> {
+        self.runtime.block_on(self.client.delete_fpga_image(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_internet_gateway`].
+    pub fn delete_internet_gateway(
+        &self,
+        input: DeleteInternetGatewayRequest,
+    ) -> Result<(), RusotoError<DeleteInternetGatewayError>> {
+        self.runtime
+            .block_on(self.client.delete_internet_gateway(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_key_pair`].
+    pub fn delete_key_pair(
+        &self,
+        input: DeleteKeyPairRequest,
+    ) -> Result<(), RusotoError<DeleteKeyPairError>> {
+        self.runtime.block_on(self.client.delete_key_pair(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_launch_template`].
+    pub fn delete_launch_template(
+        &self,
+        input: DeleteLaunchTemplateRequest,
+    ) -> Result<DeleteLaunchTemplateResult, RusotoError<DeleteLaunchTemplateError>> {
+        self.runtime
+            .block_on(self.client.delete_launch_template(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_launch_template_versions`].
+    pub fn delete_launch_template_versions(
+        &self,
+        input: DeleteLaunchTemplateVersionsRequest,
+    ) -> Result<DeleteLaunchTemplateVersionsResult, RusotoError<DeleteLaunchTemplateVersionsError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_launch_template_versions(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_local_gateway_route`].
+    pub fn delete_local_gateway_route(
+        &self,
+        input: DeleteLocalGatewayRouteRequest,
+    ) -> Result<DeleteLocalGatewayRouteResult, RusotoError<DeleteLocalGatewayRouteError>> {
+        self.runtime
+            .block_on(self.client.delete_local_gateway_route(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_local_gateway_route_table_vpc_association`].
+    pub fn delete_local_gateway_route_table_vpc_association(
+        &self,
+        input: DeleteLocalGatewayRouteTableVpcAssociationRequest,
+    ) -> Result<
+        DeleteLocalGatewayRouteTableVpcAssociationResult,
+        RusotoError<DeleteLocalGatewayRouteTableVpcAssociationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .delete_local_gateway_route_table_vpc_association(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::delete_managed_prefix_list`].
+    pub fn delete_managed_prefix_list(
+        &self,
+        input: DeleteManagedPrefixListRequest,
+    ) -> Result<DeleteManagedPrefixListResult, RusotoError<DeleteManagedPrefixListError>> {
+        self.runtime
+            .block_on(self.client.delete_managed_prefix_list(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_nat_gateway`].
+    pub fn delete_nat_gateway(
+        &self,
+        input: DeleteNatGatewayRequest,
+    ) -> Result<DeleteNatGatewayResult, RusotoError<DeleteNatGatewayError>> {
+        self.runtime.block_on(self.client.delete_nat_gateway(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_network_acl`].
+    pub fn delete_network_acl(
+        &self,
+        input: DeleteNetworkAclRequest,
+    ) -> Result<(), RusotoError<DeleteNetworkAclError>> {
+        self.runtime.block_on(self.client.delete_network_acl(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_network_acl_entry`].
+    pub fn delete_network_acl_entry(
+        &self,
+        input: DeleteNetworkAclEntryRequest,
+    ) -> Result<(), RusotoError<DeleteNetworkAclEntryError>> {
+        self.runtime
+            .block_on(self.client.delete_network_acl_entry(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_network_insights_analysis`].
+    pub fn delete_network_insights_analysis(
+        &self,
+        input: DeleteNetworkInsightsAnalysisRequest,
+    ) -> Result<DeleteNetworkInsightsAnalysisResult, RusotoError<DeleteNetworkInsightsAnalysisError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_network_insights_analysis(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_network_insights_path`].
+    pub fn delete_network_insights_path(
+        &self,
+        input: DeleteNetworkInsightsPathRequest,
+    ) -> Result<DeleteNetworkInsightsPathResult, RusotoError<DeleteNetworkInsightsPathError>> {
+        self.runtime
+            .block_on(self.client.delete_network_insights_path(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_network_interface`].
+    pub fn delete_network_interface(
+        &self,
+        input: DeleteNetworkInterfaceRequest,
+    ) -> Result<(), RusotoError<DeleteNetworkInterfaceError>> {
+        self.runtime
+            .block_on(self.client.delete_network_interface(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_network_interface_permission`].
+    pub fn delete_network_interface_permission(
+        &self,
+        input: DeleteNetworkInterfacePermissionRequest,
+    ) -> Result<
+        DeleteNetworkInterfacePermissionResult,
+        RusotoError<DeleteNetworkInterfacePermissionError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_network_interface_permission(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_placement_group`].
+    pub fn delete_placement_group(
+        &self,
+        input: DeletePlacementGroupRequest,
+    ) -> Result<(), RusotoError<DeletePlacementGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_placement_group(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_queued_reserved_instances`].
+    pub fn delete_queued_reserved_instances(
+        &self,
+        input: DeleteQueuedReservedInstancesRequest,
+    ) -> Result<DeleteQueuedReservedInstancesResult, RusotoError<DeleteQueuedReservedInstancesError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_queued_reserved_instances(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_route`].
+    pub fn delete_route(
+        &self,
+        input: DeleteRouteRequest,
+    ) -> Result<(), RusotoError<DeleteRouteError>> {
+        self.runtime.block_on(self.client.delete_route(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_route_table`].
+    pub fn delete_route_table(
+        &self,
+        input: DeleteRouteTableRequest,
+    ) -> Result<(), RusotoError<DeleteRouteTableError>> {
+        self.runtime.block_on(self.client.delete_route_table(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_security_group`].
+    pub fn delete_security_group(
+        &self,
+        input: DeleteSecurityGroupRequest,
+    ) -> Result<(), RusotoError<DeleteSecurityGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_security_group(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_snapshot`].
+    pub fn delete_snapshot(
+        &self,
+        input: DeleteSnapshotRequest,
+    ) -> Result<(), RusotoError<DeleteSnapshotError>> {
+        self.runtime.block_on(self.client.delete_snapshot(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_spot_datafeed_subscription`].
+    pub fn delete_spot_datafeed_subscription(
+        &self,
+        input: DeleteSpotDatafeedSubscriptionRequest,
+    ) -> Result<(), RusotoError<DeleteSpotDatafeedSubscriptionError>> {
+        self.runtime
+            .block_on(self.client.delete_spot_datafeed_subscription(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_subnet`].
+    pub fn delete_subnet(
+        &self,
+        input: DeleteSubnetRequest,
+    ) -> Result<(), RusotoError<DeleteSubnetError>> {
+        self.runtime.block_on(self.client.delete_subnet(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_tags`].
+    pub fn delete_tags(
+        &self,
+        input: DeleteTagsRequest,
+    ) -> Result<(), RusotoError<DeleteTagsError>> {
+        self.runtime.block_on(self.client.delete_tags(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_traffic_mirror_filter`].
+    pub fn delete_traffic_mirror_filter(
+        &self,
+        input: DeleteTrafficMirrorFilterRequest,
+    ) -> Result<DeleteTrafficMirrorFilterResult, RusotoError<DeleteTrafficMirrorFilterError>> {
+        self.runtime
+            .block_on(self.client.delete_traffic_mirror_filter(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_traffic_mirror_filter_rule`].
+    pub fn delete_traffic_mirror_filter_rule(
+        &self,
+        input: DeleteTrafficMirrorFilterRuleRequest,
+    ) -> Result<DeleteTrafficMirrorFilterRuleResult, RusotoError<DeleteTrafficMirrorFilterRuleError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_traffic_mirror_filter_rule(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_traffic_mirror_session`].
+    pub fn delete_traffic_mirror_session(
+        &self,
+        input: DeleteTrafficMirrorSessionRequest,
+    ) -> Result<DeleteTrafficMirrorSessionResult, RusotoError<DeleteTrafficMirrorSessionError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_traffic_mirror_session(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_traffic_mirror_target`].
+    pub fn delete_traffic_mirror_target(
+        &self,
+        input: DeleteTrafficMirrorTargetRequest,
+    ) -> Result<DeleteTrafficMirrorTargetResult, RusotoError<DeleteTrafficMirrorTargetError>> {
+        self.runtime
+            .block_on(self.client.delete_traffic_mirror_target(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_transit_gateway`].
+    pub fn delete_transit_gateway(
+        &self,
+        input: DeleteTransitGatewayRequest,
+    ) -> Result<DeleteTransitGatewayResult, RusotoError<DeleteTransitGatewayError>> {
+        self.runtime
+            .block_on(self.client.delete_transit_gateway(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_transit_gateway_connect`].
+    pub fn delete_transit_gateway_connect(
+        &self,
+        input: DeleteTransitGatewayConnectRequest,
+    ) -> Result<DeleteTransitGatewayConnectResult, RusotoError<DeleteTransitGatewayConnectError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_transit_gateway_connect(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_transit_gateway_connect_peer`].
+    pub fn delete_transit_gateway_connect_peer(
+        &self,
+        input: DeleteTransitGatewayConnectPeerRequest,
+    ) -> Result<
+        DeleteTransitGatewayConnectPeerResult,
+        RusotoError<DeleteTransitGatewayConnectPeerError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_transit_gateway_connect_peer(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_transit_gateway_multicast_domain`].
+    pub fn delete_transit_gateway_multicast_domain(
+        &self,
+        input: DeleteTransitGatewayMulticastDomainRequest,
+    ) -> Result<
+        DeleteTransitGatewayMulticastDomainResult,
+        RusotoError<DeleteTransitGatewayMulticastDomainError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_transit_gateway_multicast_domain(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_transit_gateway_peering_attachment`].
+    pub fn delete_transit_gateway_peering_attachment(
+        &self,
+        input: DeleteTransitGatewayPeeringAttachmentRequest,
+    ) -> Result<
+        DeleteTransitGatewayPeeringAttachmentResult,
+        RusotoError<DeleteTransitGatewayPeeringAttachmentError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_transit_gateway_peering_attachment(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_transit_gateway_prefix_list_reference`].
+    pub fn delete_transit_gateway_prefix_list_reference(
+        &self,
+        input: DeleteTransitGatewayPrefixListReferenceRequest,
+    ) -> Result<
+        DeleteTransitGatewayPrefixListReferenceResult,
+        RusotoError<DeleteTransitGatewayPrefixListReferenceError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .delete_transit_gateway_prefix_list_reference(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::delete_transit_gateway_route`].
+    pub fn delete_transit_gateway_route(
+        &self,
+        input: DeleteTransitGatewayRouteRequest,
+    ) -> Result<DeleteTransitGatewayRouteResult, RusotoError<DeleteTransitGatewayRouteError>> {
+        self.runtime
+            .block_on(self.client.delete_transit_gateway_route(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_transit_gateway_route_table`].
+    pub fn delete_transit_gateway_route_table(
+        &self,
+        input: DeleteTransitGatewayRouteTableRequest,
+    ) -> Result<
+        DeleteTransitGatewayRouteTableResult,
+        RusotoError<DeleteTransitGatewayRouteTableError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_transit_gateway_route_table(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_transit_gateway_vpc_attachment`].
+    pub fn delete_transit_gateway_vpc_attachment(
+        &self,
+        input: DeleteTransitGatewayVpcAttachmentRequest,
+    ) -> Result<
+        DeleteTransitGatewayVpcAttachmentResult,
+        RusotoError<DeleteTransitGatewayVpcAttachmentError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_transit_gateway_vpc_attachment(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_volume`].
+    pub fn delete_volume(
+        &self,
+        input: DeleteVolumeRequest,
+    ) -> Result<(), RusotoError<DeleteVolumeError>> {
+        self.runtime.block_on(self.client.delete_volume(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_vpc`].
+    pub fn delete_vpc(&self, input: DeleteVpcRequest) -> Result<(), RusotoError<DeleteVpcError>> {
+        self.runtime.block_on(self.client.delete_vpc(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_vpc_endpoint_connection_notifications`].
+    pub fn delete_vpc_endpoint_connection_notifications(
+        &self,
+        input: DeleteVpcEndpointConnectionNotificationsRequest,
+    ) -> Result<
+        DeleteVpcEndpointConnectionNotificationsResult,
+        RusotoError<DeleteVpcEndpointConnectionNotificationsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .delete_vpc_endpoint_connection_notifications(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::delete_vpc_endpoint_service_configurations`].
+    pub fn delete_vpc_endpoint_service_configurations(
+        &self,
+        input: DeleteVpcEndpointServiceConfigurationsRequest,
+    ) -> Result<
+        DeleteVpcEndpointServiceConfigurationsResult,
+        RusotoError<DeleteVpcEndpointServiceConfigurationsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .delete_vpc_endpoint_service_configurations(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::delete_vpc_endpoints`].
+    pub fn delete_vpc_endpoints(
+        &self,
+        input: DeleteVpcEndpointsRequest,
+    ) -> Result<DeleteVpcEndpointsResult, RusotoError<DeleteVpcEndpointsError>> {
+        self.runtime
+            .block_on(self.client.delete_vpc_endpoints(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_vpc_peering_connection`].
+    pub fn delete_vpc_peering_connection(
+        &self,
+        input: DeleteVpcPeeringConnectionRequest,
+    ) -> Result<DeleteVpcPeeringConnectionResult, RusotoError<DeleteVpcPeeringConnectionError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_vpc_peering_connection(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_vpn_connection`].
+    pub fn delete_vpn_connection(
+        &self,
+        input: DeleteVpnConnectionRequest,
+    ) -> Result<(), RusotoError<DeleteVpnConnectionError>> {
+        self.runtime
+            .block_on(self.client.delete_vpn_connection(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_vpn_connection_route`].
+    pub fn delete_vpn_connection_route(
+        &self,
+        input: DeleteVpnConnectionRouteRequest,
+    ) -> Result<(), RusotoError<DeleteVpnConnectionRouteError>> {
+        self.runtime
+            .block_on(self.client.delete_vpn_connection_route(input))
+    }
+
+    /// Blocking version of [`Ec2::delete_vpn_gateway`].
+    pub fn delete_vpn_gateway(
+        &self,
+        input: DeleteVpnGatewayRequest,
+    ) -> Result<(), RusotoError<DeleteVpnGatewayError>> {
+        self.runtime.block_on(self.client.delete_vpn_gateway(input))
+    }
+
+    /// Blocking version of [`Ec2::deprovision_byoip_cidr`].
+    pub fn deprovision_byoip_cidr(
+        &self,
+        input: DeprovisionByoipCidrRequest,
+    ) -> Result<DeprovisionByoipCidrResult, RusotoError<DeprovisionByoipCidrError>> {
+        self.runtime
+            .block_on(self.client.deprovision_byoip_cidr(input))
+    }
+
+    /// Blocking version of [`Ec2::deregister_image`].
+    pub fn deregister_image(
+        &self,
+        input: DeregisterImageRequest,
+    ) -> Result<(), RusotoError<DeregisterImageError>> {
+        self.runtime.block_on(self.client.deregister_image(input))
+    }
+
+    /// Blocking version of [`Ec2::deregister_instance_event_notification_attributes`].
+    pub fn deregister_instance_event_notification_attributes(
+        &self,
+        input: DeregisterInstanceEventNotificationAttributesRequest,
+    ) -> Result<
+        DeregisterInstanceEventNotificationAttributesResult,
+        RusotoError<DeregisterInstanceEventNotificationAttributesError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .deregister_instance_event_notification_attributes(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::deregister_transit_gateway_multicast_group_members`].
+    pub fn deregister_transit_gateway_multicast_group_members(
+        &self,
+        input: DeregisterTransitGatewayMulticastGroupMembersRequest,
+    ) -> Result<
+        DeregisterTransitGatewayMulticastGroupMembersResult,
+        RusotoError<DeregisterTransitGatewayMulticastGroupMembersError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .deregister_transit_gateway_multicast_group_members(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::deregister_transit_gateway_multicast_group_sources`].
+    pub fn deregister_transit_gateway_multicast_group_sources(
+        &self,
+        input: DeregisterTransitGatewayMulticastGroupSourcesRequest,
+    ) -> Result<
+        DeregisterTransitGatewayMulticastGroupSourcesResult,
+        RusotoError<DeregisterTransitGatewayMulticastGroupSourcesError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .deregister_transit_gateway_multicast_group_sources(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::describe_account_attributes`].
+    pub fn describe_account_attributes(
+        &self,
+        input: DescribeAccountAttributesRequest,
+    ) -> Result<DescribeAccountAttributesResult, RusotoError<DescribeAccountAttributesError>> {
+        self.runtime
+            .block_on(self.client.describe_account_attributes(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_addresses`].
+    pub fn describe_addresses(
+        &self,
+        input: DescribeAddressesRequest,
+    ) -> Result<DescribeAddressesResult, RusotoError<DescribeAddressesError>> {
+        self.runtime.block_on(self.client.describe_addresses(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_aggregate_id_format`].
+    pub fn describe_aggregate_id_format(
+        &self,
+        input: DescribeAggregateIdFormatRequest,
+    ) -> Result<DescribeAggregateIdFormatResult, RusotoError<DescribeAggregateIdFormatError>> {
+        self.runtime
+            .block_on(self.client.describe_aggregate_id_format(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_availability_zones`].
+    pub fn describe_availability_zones(
+        &self,
+        input: DescribeAvailabilityZonesRequest,
+    ) -> Result<DescribeAvailabilityZonesResult, RusotoError<DescribeAvailabilityZonesError>> {
+        self.runtime
+            .block_on(self.client.describe_availability_zones(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_bundle_tasks`].
+    pub fn describe_bundle_tasks(
+        &self,
+        input: DescribeBundleTasksRequest,
+    ) -> Result<DescribeBundleTasksResult, RusotoError<DescribeBundleTasksError>> {
+        self.runtime
+            .block_on(self.client.describe_bundle_tasks(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_byoip_cidrs`].
+    pub fn describe_byoip_cidrs(
+        &self,
+        input: DescribeByoipCidrsRequest,
+    ) -> Result<DescribeByoipCidrsResult, RusotoError<DescribeByoipCidrsError>> {
+        self.runtime
+            .block_on(self.client.describe_byoip_cidrs(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_capacity_reservations`].
+    pub fn describe_capacity_reservations(
+        &self,
+        input: DescribeCapacityReservationsRequest,
+    ) -> Result<DescribeCapacityReservationsResult, RusotoError<DescribeCapacityReservationsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_capacity_reservations(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_carrier_gateways`].
+    pub fn describe_carrier_gateways(
+        &self,
+        input: DescribeCarrierGatewaysRequest,
+    ) -> Result<DescribeCarrierGatewaysResult, RusotoError<DescribeCarrierGatewaysError>> {
+        self.runtime
+            .block_on(self.client.describe_carrier_gateways(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_classic_link_instances`].
+    pub fn describe_classic_link_instances(
+        &self,
+        input: DescribeClassicLinkInstancesRequest,
+    ) -> Result<DescribeClassicLinkInstancesResult, RusotoError<DescribeClassicLinkInstancesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_classic_link_instances(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_client_vpn_authorization_rules`].
+    pub fn describe_client_vpn_authorization_rules(
+        &self,
+        input: DescribeClientVpnAuthorizationRulesRequest,
+    ) -> Result<
+        DescribeClientVpnAuthorizationRulesResult,
+        RusotoError<DescribeClientVpnAuthorizationRulesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_client_vpn_authorization_rules(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_client_vpn_connections`].
+    pub fn describe_client_vpn_connections(
+        &self,
+        input: DescribeClientVpnConnectionsRequest,
+    ) -> Result<DescribeClientVpnConnectionsResult, RusotoError<DescribeClientVpnConnectionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_client_vpn_connections(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_client_vpn_endpoints`].
+    pub fn describe_client_vpn_endpoints(
+        &self,
+        input: DescribeClientVpnEndpointsRequest,
+    ) -> Result<DescribeClientVpnEndpointsResult, RusotoError<DescribeClientVpnEndpointsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_client_vpn_endpoints(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_client_vpn_routes`].
+    pub fn describe_client_vpn_routes(
+        &self,
+        input: DescribeClientVpnRoutesRequest,
+    ) -> Result<DescribeClientVpnRoutesResult, RusotoError<DescribeClientVpnRoutesError>> {
+        self.runtime
+            .block_on(self.client.describe_client_vpn_routes(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_client_vpn_target_networks`].
+    pub fn describe_client_vpn_target_networks(
+        &self,
+        input: DescribeClientVpnTargetNetworksRequest,
+    ) -> Result<
+        DescribeClientVpnTargetNetworksResult,
+        RusotoError<DescribeClientVpnTargetNetworksError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_client_vpn_target_networks(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_coip_pools`].
+    pub fn describe_coip_pools(
+        &self,
+        input: DescribeCoipPoolsRequest,
+    ) -> Result<DescribeCoipPoolsResult, RusotoError<DescribeCoipPoolsError>> {
+        self.runtime
+            .block_on(self.client.describe_coip_pools(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_conversion_tasks`].
+    pub fn describe_conversion_tasks(
+        &self,
+        input: DescribeConversionTasksRequest,
+    ) -> Result<DescribeConversionTasksResult, RusotoError<DescribeConversionTasksError>> {
+        self.runtime
+            .block_on(self.client.describe_conversion_tasks(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_customer_gateways`].
+    pub fn describe_customer_gateways(
+        &self,
+        input: DescribeCustomerGatewaysRequest,
+    ) -> Result<DescribeCustomerGatewaysResult, RusotoError<DescribeCustomerGatewaysError>> {
+        self.runtime
+            .block_on(self.client.describe_customer_gateways(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_dhcp_options`].
+    pub fn describe_dhcp_options(
+        &self,
+        input: DescribeDhcpOptionsRequest,
+    ) -> Result<DescribeDhcpOptionsResult, RusotoError<DescribeDhcpOptionsError>> {
+        self.runtime
+            .block_on(self.client.describe_dhcp_options(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_egress_only_internet_gateways`].
+    pub fn describe_egress_only_internet_gateways(
+        &self,
+        input: DescribeEgressOnlyInternetGatewaysRequest,
+    ) -> Result<
+        DescribeEgressOnlyInternetGatewaysResult,
+        RusotoError<DescribeEgressOnlyInternetGatewaysError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_egress_only_internet_gateways(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_elastic_gpus`].
+    pub fn describe_elastic_gpus(
+        &self,
+        input: DescribeElasticGpusRequest,
+    ) -> Result<DescribeElasticGpusResult, RusotoError<DescribeElasticGpusError>> {
+        self.runtime
+            .block_on(self.client.describe_elastic_gpus(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_export_image_tasks`].
+    pub fn describe_export_image_tasks(
+        &self,
+        input: DescribeExportImageTasksRequest,
+    ) -> Result<DescribeExportImageTasksResult, RusotoError<DescribeExportImageTasksError>> {
+        self.runtime
+            .block_on(self.client.describe_export_image_tasks(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_export_tasks`].
+    pub fn describe_export_tasks(
+        &self,
+        input: DescribeExportTasksRequest,
+    ) -> Result<DescribeExportTasksResult, RusotoError<DescribeExportTasksError>> {
+        self.runtime
+            .block_on(self.client.describe_export_tasks(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_fast_snapshot_restores`].
+    pub fn describe_fast_snapshot_restores(
+        &self,
+        input: DescribeFastSnapshotRestoresRequest,
+    ) -> Result<DescribeFastSnapshotRestoresResult, RusotoError<DescribeFastSnapshotRestoresError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_fast_snapshot_restores(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_fleet_history`].
+    pub fn describe_fleet_history(
+        &self,
+        input: DescribeFleetHistoryRequest,
+    ) -> Result<DescribeFleetHistoryResult, RusotoError<DescribeFleetHistoryError>> {
+        self.runtime
+            .block_on(self.client.describe_fleet_history(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_fleet_instances`].
+    pub fn describe_fleet_instances(
+        &self,
+        input: DescribeFleetInstancesRequest,
+    ) -> Result<DescribeFleetInstancesResult, RusotoError<DescribeFleetInstancesError>> {
+        self.runtime
+            .block_on(self.client.describe_fleet_instances(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_fleets`].
+    pub fn describe_fleets(
+        &self,
+        input: DescribeFleetsRequest,
+    ) -> Result<DescribeFleetsResult, RusotoError<DescribeFleetsError>> {
+        self.runtime.block_on(self.client.describe_fleets(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_flow_logs`].
+    pub fn describe_flow_logs(
+        &self,
+        input: DescribeFlowLogsRequest,
+    ) -> Result<DescribeFlowLogsResult, RusotoError<DescribeFlowLogsError>> {
+        self.runtime.block_on(self.client.describe_flow_logs(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_fpga_image_attribute`].
+    pub fn describe_fpga_image_attribute(
+        &self,
+        input: DescribeFpgaImageAttributeRequest,
+    ) -> Result<DescribeFpgaImageAttributeResult, RusotoError<DescribeFpgaImageAttributeError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_fpga_image_attribute(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_fpga_images`].
+    pub fn describe_fpga_images(
+        &self,
+        input: DescribeFpgaImagesRequest,
+    ) -> Result<DescribeFpgaImagesResult, RusotoError<DescribeFpgaImagesError>> {
+        self.runtime
+            .block_on(self.client.describe_fpga_images(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_host_reservation_offerings`].
+    pub fn describe_host_reservation_offerings(
+        &self,
+        input: DescribeHostReservationOfferingsRequest,
+    ) -> Result<
+        DescribeHostReservationOfferingsResult,
+        RusotoError<DescribeHostReservationOfferingsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_host_reservation_offerings(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_host_reservations`].
+    pub fn describe_host_reservations(
+        &self,
+        input: DescribeHostReservationsRequest,
+    ) -> Result<DescribeHostReservationsResult, RusotoError<DescribeHostReservationsError>> {
+        self.runtime
+            .block_on(self.client.describe_host_reservations(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_hosts`].
+    pub fn describe_hosts(
+        &self,
+        input: DescribeHostsRequest,
+    ) -> Result<DescribeHostsResult, RusotoError<DescribeHostsError>> {
+        self.runtime.block_on(self.client.describe_hosts(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_iam_instance_profile_associations`].
+    pub fn describe_iam_instance_profile_associations(
+        &self,
+        input: DescribeIamInstanceProfileAssociationsRequest,
+    ) -> Result<
+        DescribeIamInstanceProfileAssociationsResult,
+        RusotoError<DescribeIamInstanceProfileAssociationsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_iam_instance_profile_associations(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::describe_id_format`].
+    pub fn describe_id_format(
+        &self,
+        input: DescribeIdFormatRequest,
+    ) -> Result<DescribeIdFormatResult, RusotoError<DescribeIdFormatError>> {
+        self.runtime.block_on(self.client.describe_id_format(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_identity_id_format`].
+    pub fn describe_identity_id_format(
+        &self,
+        input: DescribeIdentityIdFormatRequest,
+    ) -> Result<DescribeIdentityIdFormatResult, RusotoError<DescribeIdentityIdFormatError>> {
+        self.runtime
+            .block_on(self.client.describe_identity_id_format(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_image_attribute`].
+    pub fn describe_image_attribute(
+        &self,
+        input: DescribeImageAttributeRequest,
+    ) -> Result<ImageAttribute, RusotoError<DescribeImageAttributeError>> {
+        self.runtime
+            .block_on(self.client.describe_image_attribute(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_images`].
+    pub fn describe_images(
+        &self,
+        input: DescribeImagesRequest,
+    ) -> Result<DescribeImagesResult, RusotoError<DescribeImagesError>> {
+        self.runtime.block_on(self.client.describe_images(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_import_image_tasks`].
+    pub fn describe_import_image_tasks(
+        &self,
+        input: DescribeImportImageTasksRequest,
+    ) -> Result<DescribeImportImageTasksResult, RusotoError<DescribeImportImageTasksError>> {
+        self.runtime
+            .block_on(self.client.describe_import_image_tasks(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_import_snapshot_tasks`].
+    pub fn describe_import_snapshot_tasks(
+        &self,
+        input: DescribeImportSnapshotTasksRequest,
+    ) -> Result<DescribeImportSnapshotTasksResult, RusotoError<DescribeImportSnapshotTasksError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_import_snapshot_tasks(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_instance_attribute`].
+    pub fn describe_instance_attribute(
+        &self,
+        input: DescribeInstanceAttributeRequest,
+    ) -> Result<InstanceAttribute, RusotoError<DescribeInstanceAttributeError>> {
+        self.runtime
+            .block_on(self.client.describe_instance_attribute(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_instance_credit_specifications`].
+    pub fn describe_instance_credit_specifications(
+        &self,
+        input: DescribeInstanceCreditSpecificationsRequest,
+    ) -> Result<
+        DescribeInstanceCreditSpecificationsResult,
+        RusotoError<DescribeInstanceCreditSpecificationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_instance_credit_specifications(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_instance_event_notification_attributes`].
+    pub fn describe_instance_event_notification_attributes(
+        &self,
+        input: DescribeInstanceEventNotificationAttributesRequest,
+    ) -> Result<
+        DescribeInstanceEventNotificationAttributesResult,
+        RusotoError<DescribeInstanceEventNotificationAttributesError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_instance_event_notification_attributes(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::describe_instance_status`].
+    pub fn describe_instance_status(
+        &self,
+        input: DescribeInstanceStatusRequest,
+    ) -> Result<DescribeInstanceStatusResult, RusotoError<DescribeInstanceStatusError>> {
+        self.runtime
+            .block_on(self.client.describe_instance_status(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_instance_type_offerings`].
+    pub fn describe_instance_type_offerings(
+        &self,
+        input: DescribeInstanceTypeOfferingsRequest,
+    ) -> Result<DescribeInstanceTypeOfferingsResult, RusotoError<DescribeInstanceTypeOfferingsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_instance_type_offerings(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_instance_types`].
+    pub fn describe_instance_types(
+        &self,
+        input: DescribeInstanceTypesRequest,
+    ) -> Result<DescribeInstanceTypesResult, RusotoError<DescribeInstanceTypesError>> {
+        self.runtime
+            .block_on(self.client.describe_instance_types(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_instances`].
+    pub fn describe_instances(
+        &self,
+        input: DescribeInstancesRequest,
+    ) -> Result<DescribeInstancesResult, RusotoError<DescribeInstancesError>> {
+        self.runtime.block_on(self.client.describe_instances(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_internet_gateways`].
+    pub fn describe_internet_gateways(
+        &self,
+        input: DescribeInternetGatewaysRequest,
+    ) -> Result<DescribeInternetGatewaysResult, RusotoError<DescribeInternetGatewaysError>> {
+        self.runtime
+            .block_on(self.client.describe_internet_gateways(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_ipv_6_pools`].
+    pub fn describe_ipv_6_pools(
+        &self,
+        input: DescribeIpv6PoolsRequest,
+    ) -> Result<DescribeIpv6PoolsResult, RusotoError<DescribeIpv6PoolsError>> {
+        self.runtime
+            .block_on(self.client.describe_ipv_6_pools(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_key_pairs`].
+    pub fn describe_key_pairs(
+        &self,
+        input: DescribeKeyPairsRequest,
+    ) -> Result<DescribeKeyPairsResult, RusotoError<DescribeKeyPairsError>> {
+        self.runtime.block_on(self.client.describe_key_pairs(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_launch_template_versions`].
+    pub fn describe_launch_template_versions(
+        &self,
+        input: DescribeLaunchTemplateVersionsRequest,
+    ) -> Result<
+        DescribeLaunchTemplateVersionsResult,
+        RusotoError<DescribeLaunchTemplateVersionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_launch_template_versions(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_launch_templates`].
+    pub fn describe_launch_templates(
+        &self,
+        input: DescribeLaunchTemplatesRequest,
+    ) -> Result<DescribeLaunchTemplatesResult, RusotoError<DescribeLaunchTemplatesError>> {
+        self.runtime
+            .block_on(self.client.describe_launch_templates(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_local_gateway_route_table_virtual_interface_group_associations`].
+    pub fn describe_local_gateway_route_table_virtual_interface_group_associations(
+        &self,
+        input: DescribeLocalGatewayRouteTableVirtualInterfaceGroupAssociationsRequest,
+    ) -> Result<
+        DescribeLocalGatewayRouteTableVirtualInterfaceGroupAssociationsResult,
+        RusotoError<DescribeLocalGatewayRouteTableVirtualInterfaceGroupAssociationsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_local_gateway_route_table_virtual_interface_group_associations(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::describe_local_gateway_route_table_vpc_associations`].
+    pub fn describe_local_gateway_route_table_vpc_associations(
+        &self,
+        input: DescribeLocalGatewayRouteTableVpcAssociationsRequest,
+    ) -> Result<
+        DescribeLocalGatewayRouteTableVpcAssociationsResult,
+        RusotoError<DescribeLocalGatewayRouteTableVpcAssociationsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_local_gateway_route_table_vpc_associations(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::describe_local_gateway_route_tables`].
+    pub fn describe_local_gateway_route_tables(
+        &self,
+        input: DescribeLocalGatewayRouteTablesRequest,
+    ) -> Result<
+        DescribeLocalGatewayRouteTablesResult,
+        RusotoError<DescribeLocalGatewayRouteTablesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_local_gateway_route_tables(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_local_gateway_virtual_interface_groups`].
+    pub fn describe_local_gateway_virtual_interface_groups(
+        &self,
+        input: DescribeLocalGatewayVirtualInterfaceGroupsRequest,
+    ) -> Result<
+        DescribeLocalGatewayVirtualInterfaceGroupsResult,
+        RusotoError<DescribeLocalGatewayVirtualInterfaceGroupsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_local_gateway_virtual_interface_groups(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::describe_local_gateway_virtual_interfaces`].
+    pub fn describe_local_gateway_virtual_interfaces(
+        &self,
+        input: DescribeLocalGatewayVirtualInterfacesRequest,
+    ) -> Result<
+        DescribeLocalGatewayVirtualInterfacesResult,
+        RusotoError<DescribeLocalGatewayVirtualInterfacesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_local_gateway_virtual_interfaces(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_local_gateways`].
+    pub fn describe_local_gateways(
+        &self,
+        input: DescribeLocalGatewaysRequest,
+    ) -> Result<DescribeLocalGatewaysResult, RusotoError<DescribeLocalGatewaysError>> {
+        self.runtime
+            .block_on(self.client.describe_local_gateways(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_managed_prefix_lists`].
+    pub fn describe_managed_prefix_lists(
+        &self,
+        input: DescribeManagedPrefixListsRequest,
+    ) -> Result<DescribeManagedPrefixListsResult, RusotoError<DescribeManagedPrefixListsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_managed_prefix_lists(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_moving_addresses`].
+    pub fn describe_moving_addresses(
+        &self,
+        input: DescribeMovingAddressesRequest,
+    ) -> Result<DescribeMovingAddressesResult, RusotoError<DescribeMovingAddressesError>> {
+        self.runtime
+            .block_on(self.client.describe_moving_addresses(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_nat_gateways`].
+    pub fn describe_nat_gateways(
+        &self,
+        input: DescribeNatGatewaysRequest,
+    ) -> Result<DescribeNatGatewaysResult, RusotoError<DescribeNatGatewaysError>> {
+        self.runtime
+            .block_on(self.client.describe_nat_gateways(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_network_acls`].
+    pub fn describe_network_acls(
+        &self,
+        input: DescribeNetworkAclsRequest,
+    ) -> Result<DescribeNetworkAclsResult, RusotoError<DescribeNetworkAclsError>> {
+        self.runtime
+            .block_on(self.client.describe_network_acls(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_network_insights_analyses`].
+    pub fn describe_network_insights_analyses(
+        &self,
+        input: DescribeNetworkInsightsAnalysesRequest,
+    ) -> Result<
+        DescribeNetworkInsightsAnalysesResult,
+        RusotoError<DescribeNetworkInsightsAnalysesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_network_insights_analyses(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_network_insights_paths`].
+    pub fn describe_network_insights_paths(
+        &self,
+        input: DescribeNetworkInsightsPathsRequest,
+    ) -> Result<DescribeNetworkInsightsPathsResult, RusotoError<DescribeNetworkInsightsPathsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_network_insights_paths(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_network_interface_attribute`].
+    pub fn describe_network_interface_attribute(
+        &self,
+        input: DescribeNetworkInterfaceAttributeRequest,
+    ) -> Result<
+        DescribeNetworkInterfaceAttributeResult,
+        RusotoError<DescribeNetworkInterfaceAttributeError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_network_interface_attribute(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_network_interface_permissions`].
+    pub fn describe_network_interface_permissions(
+        &self,
+        input: DescribeNetworkInterfacePermissionsRequest,
+    ) -> Result<
+        DescribeNetworkInterfacePermissionsResult,
+        RusotoError<DescribeNetworkInterfacePermissionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_network_interface_permissions(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_network_interfaces`].
+    pub fn describe_network_interfaces(
+        &self,
+        input: DescribeNetworkInterfacesRequest,
+    ) -> Result<DescribeNetworkInterfacesResult, RusotoError<DescribeNetworkInterfacesError>> {
+        self.runtime
+            .block_on(self.client.describe_network_interfaces(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_placement_groups`].
+    pub fn describe_placement_groups(
+        &self,
+        input: DescribePlacementGroupsRequest,
+    ) -> Result<DescribePlacementGroupsResult, RusotoError<DescribePlacementGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_placement_groups(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_prefix_lists`].
+    pub fn describe_prefix_lists(
+        &self,
+        input: DescribePrefixListsRequest,
+    ) -> Result<DescribePrefixListsResult, RusotoError<DescribePrefixListsError>> {
+        self.runtime
+            .block_on(self.client.describe_prefix_lists(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_principal_id_format`].
+    pub fn describe_principal_id_format(
+        &self,
+        input: DescribePrincipalIdFormatRequest,
+    ) -> Result<DescribePrincipalIdFormatResult, RusotoError<DescribePrincipalIdFormatError>> {
+        self.runtime
+            .block_on(self.client.describe_principal_id_format(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_public_ipv_4_pools`].
+    pub fn describe_public_ipv_4_pools(
+        &self,
+        input: DescribePublicIpv4PoolsRequest,
+    ) -> Result<DescribePublicIpv4PoolsResult, RusotoError<DescribePublicIpv4PoolsError>> {
+        self.runtime
+            .block_on(self.client.describe_public_ipv_4_pools(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_regions`].
+    pub fn describe_regions(
+        &self,
+        input: DescribeRegionsRequest,
+    ) -> Result<DescribeRegionsResult, RusotoError<DescribeRegionsError>> {
+        self.runtime.block_on(self.client.describe_regions(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_reserved_instances`].
+    pub fn describe_reserved_instances(
+        &self,
+        input: DescribeReservedInstancesRequest,
+    ) -> Result<DescribeReservedInstancesResult, RusotoError<DescribeReservedInstancesError>> {
+        self.runtime
+            .block_on(self.client.describe_reserved_instances(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_reserved_instances_listings`].
+    pub fn describe_reserved_instances_listings(
+        &self,
+        input: DescribeReservedInstancesListingsRequest,
+    ) -> Result<
+        DescribeReservedInstancesListingsResult,
+        RusotoError<DescribeReservedInstancesListingsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_reserved_instances_listings(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_reserved_instances_modifications`].
+    pub fn describe_reserved_instances_modifications(
+        &self,
+        input: DescribeReservedInstancesModificationsRequest,
+    ) -> Result<
+        DescribeReservedInstancesModificationsResult,
+        RusotoError<DescribeReservedInstancesModificationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_reserved_instances_modifications(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_reserved_instances_offerings`].
+    pub fn describe_reserved_instances_offerings(
+        &self,
+        input: DescribeReservedInstancesOfferingsRequest,
+    ) -> Result<
+        DescribeReservedInstancesOfferingsResult,
+        RusotoError<DescribeReservedInstancesOfferingsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_reserved_instances_offerings(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_route_tables`].
+    pub fn describe_route_tables(
+        &self,
+        input: DescribeRouteTablesRequest,
+    ) -> Result<DescribeRouteTablesResult, RusotoError<DescribeRouteTablesError>> {
+        self.runtime
+            .block_on(self.client.describe_route_tables(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_scheduled_instance_availability`].
+    pub fn describe_scheduled_instance_availability(
+        &self,
+        input: DescribeScheduledInstanceAvailabilityRequest,
+    ) -> Result<
+        DescribeScheduledInstanceAvailabilityResult,
+        RusotoError<DescribeScheduledInstanceAvailabilityError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_scheduled_instance_availability(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_scheduled_instances`].
+    pub fn describe_scheduled_instances(
+        &self,
+        input: DescribeScheduledInstancesRequest,
+    ) -> Result<DescribeScheduledInstancesResult, RusotoError<DescribeScheduledInstancesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_scheduled_instances(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_security_group_references`].
+    pub fn describe_security_group_references(
+        &self,
+        input: DescribeSecurityGroupReferencesRequest,
+    ) -> Result<
+        DescribeSecurityGroupReferencesResult,
+        RusotoError<DescribeSecurityGroupReferencesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_security_group_references(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_security_groups`].
+    pub fn describe_security_groups(
+        &self,
+        input: DescribeSecurityGroupsRequest,
+    ) -> Result<DescribeSecurityGroupsResult, RusotoError<DescribeSecurityGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_security_groups(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_snapshot_attribute`].
+    pub fn describe_snapshot_attribute(
+        &self,
+        input: DescribeSnapshotAttributeRequest,
+    ) -> Result<DescribeSnapshotAttributeResult, RusotoError<DescribeSnapshotAttributeError>> {
+        self.runtime
+            .block_on(self.client.describe_snapshot_attribute(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_snapshots`].
+    pub fn describe_snapshots(
+        &self,
+        input: DescribeSnapshotsRequest,
+    ) -> Result<DescribeSnapshotsResult, RusotoError<DescribeSnapshotsError>> {
+        self.runtime.block_on(self.client.describe_snapshots(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_spot_datafeed_subscription`].
+    pub fn describe_spot_datafeed_subscription(
+        &self,
+        input: DescribeSpotDatafeedSubscriptionRequest,
+    ) -> Result<
+        DescribeSpotDatafeedSubscriptionResult,
+        RusotoError<DescribeSpotDatafeedSubscriptionError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_spot_datafeed_subscription(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_spot_fleet_instances`].
+    pub fn describe_spot_fleet_instances(
+        &self,
+        input: DescribeSpotFleetInstancesRequest,
+    ) -> Result<DescribeSpotFleetInstancesResponse, RusotoError<DescribeSpotFleetInstancesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_spot_fleet_instances(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_spot_fleet_request_history`].
+    pub fn describe_spot_fleet_request_history(
+        &self,
+        input: DescribeSpotFleetRequestHistoryRequest,
+    ) -> Result<
+        DescribeSpotFleetRequestHistoryResponse,
+        RusotoError<DescribeSpotFleetRequestHistoryError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_spot_fleet_request_history(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_spot_fleet_requests`].
+    pub fn describe_spot_fleet_requests(
+        &self,
+        input: DescribeSpotFleetRequestsRequest,
+    ) -> Result<DescribeSpotFleetRequestsResponse, RusotoError<DescribeSpotFleetRequestsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_spot_fleet_requests(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_spot_instance_requests`].
+    pub fn describe_spot_instance_requests(
+        &self,
+        input: DescribeSpotInstanceRequestsRequest,
+    ) -> Result<DescribeSpotInstanceRequestsResult, RusotoError<DescribeSpotInstanceRequestsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_spot_instance_requests(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_spot_price_history`].
+    pub fn describe_spot_price_history(
+        &self,
+        input: DescribeSpotPriceHistoryRequest,
+    ) -> Result<DescribeSpotPriceHistoryResult, RusotoError<DescribeSpotPriceHistoryError>> {
+        self.runtime
+            .block_on(self.client.describe_spot_price_history(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_stale_security_groups`].
+    pub fn describe_stale_security_groups(
+        &self,
+        input: DescribeStaleSecurityGroupsRequest,
+    ) -> Result<DescribeStaleSecurityGroupsResult, RusotoError<DescribeStaleSecurityGroupsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_stale_security_groups(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_subnets`].
+    pub fn describe_subnets(
+        &self,
+        input: DescribeSubnetsRequest,
+    ) -> Result<DescribeSubnetsResult, RusotoError<DescribeSubnetsError>> {
+        self.runtime.block_on(self.client.describe_subnets(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_tags`].
+    pub fn describe_tags(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> Result<DescribeTagsResult, RusotoError<DescribeTagsError>> {
+        self.runtime.block_on(self.client.describe_tags(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_traffic_mirror_filters`].
+    pub fn describe_traffic_mirror_filters(
+        &self,
+        input: DescribeTrafficMirrorFiltersRequest,
+    ) -> Result<DescribeTrafficMirrorFiltersResult, RusotoError<DescribeTrafficMirrorFiltersError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_traffic_mirror_filters(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_traffic_mirror_sessions`].
+    pub fn describe_traffic_mirror_sessions(
+        &self,
+        input: DescribeTrafficMirrorSessionsRequest,
+    ) -> Result<DescribeTrafficMirrorSessionsResult, RusotoError<DescribeTrafficMirrorSessionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_traffic_mirror_sessions(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_traffic_mirror_targets`].
+    pub fn describe_traffic_mirror_targets(
+        &self,
+        input: DescribeTrafficMirrorTargetsRequest,
+    ) -> Result<DescribeTrafficMirrorTargetsResult, RusotoError<DescribeTrafficMirrorTargetsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_traffic_mirror_targets(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_transit_gateway_attachments`].
+    pub fn describe_transit_gateway_attachments(
+        &self,
+        input: DescribeTransitGatewayAttachmentsRequest,
+    ) -> Result<
+        DescribeTransitGatewayAttachmentsResult,
+        RusotoError<DescribeTransitGatewayAttachmentsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_transit_gateway_attachments(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_transit_gateway_connect_peers`].
+    pub fn describe_transit_gateway_connect_peers(
+        &self,
+        input: DescribeTransitGatewayConnectPeersRequest,
+    ) -> Result<
+        DescribeTransitGatewayConnectPeersResult,
+        RusotoError<DescribeTransitGatewayConnectPeersError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_transit_gateway_connect_peers(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_transit_gateway_connects`].
+    pub fn describe_transit_gateway_connects(
+        &self,
+        input: DescribeTransitGatewayConnectsRequest,
+    ) -> Result<
+        DescribeTransitGatewayConnectsResult,
+        RusotoError<DescribeTransitGatewayConnectsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_transit_gateway_connects(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_transit_gateway_multicast_domains`].
+    pub fn describe_transit_gateway_multicast_domains(
+        &self,
+        input: DescribeTransitGatewayMulticastDomainsRequest,
+    ) -> Result<
+        DescribeTransitGatewayMulticastDomainsResult,
+        RusotoError<DescribeTransitGatewayMulticastDomainsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_transit_gateway_multicast_domains(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::describe_transit_gateway_peering_attachments`].
+    pub fn describe_transit_gateway_peering_attachments(
+        &self,
+        input: DescribeTransitGatewayPeeringAttachmentsRequest,
+    ) -> Result<
+        DescribeTransitGatewayPeeringAttachmentsResult,
+        RusotoError<DescribeTransitGatewayPeeringAttachmentsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_transit_gateway_peering_attachments(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::describe_transit_gateway_route_tables`].
+    pub fn describe_transit_gateway_route_tables(
+        &self,
+        input: DescribeTransitGatewayRouteTablesRequest,
+    ) -> Result<
+        DescribeTransitGatewayRouteTablesResult,
+        RusotoError<DescribeTransitGatewayRouteTablesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_transit_gateway_route_tables(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_transit_gateway_vpc_attachments`].
+    pub fn describe_transit_gateway_vpc_attachments(
+        &self,
+        input: DescribeTransitGatewayVpcAttachmentsRequest,
+    ) -> Result<
+        DescribeTransitGatewayVpcAttachmentsResult,
+        RusotoError<DescribeTransitGatewayVpcAttachmentsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_transit_gateway_vpc_attachments(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_transit_gateways`].
+    pub fn describe_transit_gateways(
+        &self,
+        input: DescribeTransitGatewaysRequest,
+    ) -> Result<DescribeTransitGatewaysResult, RusotoError<DescribeTransitGatewaysError>> {
+        self.runtime
+            .block_on(self.client.describe_transit_gateways(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_volume_attribute`].
+    pub fn describe_volume_attribute(
+        &self,
+        input: DescribeVolumeAttributeRequest,
+    ) -> Result<DescribeVolumeAttributeResult, RusotoError<DescribeVolumeAttributeError>> {
+        self.runtime
+            .block_on(self.client.describe_volume_attribute(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_volume_status`].
+    pub fn describe_volume_status(
+        &self,
+        input: DescribeVolumeStatusRequest,
+    ) -> Result<DescribeVolumeStatusResult, RusotoError<DescribeVolumeStatusError>> {
+        self.runtime
+            .block_on(self.client.describe_volume_status(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_volumes`].
+    pub fn describe_volumes(
+        &self,
+        input: DescribeVolumesRequest,
+    ) -> Result<DescribeVolumesResult, RusotoError<DescribeVolumesError>> {
+        self.runtime.block_on(self.client.describe_volumes(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_volumes_modifications`].
+    pub fn describe_volumes_modifications(
+        &self,
+        input: DescribeVolumesModificationsRequest,
+    ) -> Result<DescribeVolumesModificationsResult, RusotoError<DescribeVolumesModificationsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_volumes_modifications(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_vpc_attribute`].
+    pub fn describe_vpc_attribute(
+        &self,
+        input: DescribeVpcAttributeRequest,
+    ) -> Result<DescribeVpcAttributeResult, RusotoError<DescribeVpcAttributeError>> {
+        self.runtime
+            .block_on(self.client.describe_vpc_attribute(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_vpc_classic_link`].
+    pub fn describe_vpc_classic_link(
+        &self,
+        input: DescribeVpcClassicLinkRequest,
+    ) -> Result<DescribeVpcClassicLinkResult, RusotoError<DescribeVpcClassicLinkError>> {
+        self.runtime
+            .block_on(self.client.describe_vpc_classic_link(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_vpc_classic_link_dns_support`].
+    pub fn describe_vpc_classic_link_dns_support(
+        &self,
+        input: DescribeVpcClassicLinkDnsSupportRequest,
+    ) -> Result<
+        DescribeVpcClassicLinkDnsSupportResult,
+        RusotoError<DescribeVpcClassicLinkDnsSupportError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_vpc_classic_link_dns_support(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_vpc_endpoint_connection_notifications`].
+    pub fn describe_vpc_endpoint_connection_notifications(
+        &self,
+        input: DescribeVpcEndpointConnectionNotificationsRequest,
+    ) -> Result<
+        DescribeVpcEndpointConnectionNotificationsResult,
+        RusotoError<DescribeVpcEndpointConnectionNotificationsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_vpc_endpoint_connection_notifications(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::describe_vpc_endpoint_connections`].
+    pub fn describe_vpc_endpoint_connections(
+        &self,
+        input: DescribeVpcEndpointConnectionsRequest,
+    ) -> Result<
+        DescribeVpcEndpointConnectionsResult,
+        RusotoError<DescribeVpcEndpointConnectionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_vpc_endpoint_connections(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_vpc_endpoint_service_configurations`].
+    pub fn describe_vpc_endpoint_service_configurations(
+        &self,
+        input: DescribeVpcEndpointServiceConfigurationsRequest,
+    ) -> Result<
+        DescribeVpcEndpointServiceConfigurationsResult,
+        RusotoError<DescribeVpcEndpointServiceConfigurationsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_vpc_endpoint_service_configurations(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::describe_vpc_endpoint_service_permissions`].
+    pub fn describe_vpc_endpoint_service_permissions(
+        &self,
+        input: DescribeVpcEndpointServicePermissionsRequest,
+    ) -> Result<
+        DescribeVpcEndpointServicePermissionsResult,
+        RusotoError<DescribeVpcEndpointServicePermissionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_vpc_endpoint_service_permissions(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_vpc_endpoint_services`].
+    pub fn describe_vpc_endpoint_services(
+        &self,
+        input: DescribeVpcEndpointServicesRequest,
+    ) -> Result<DescribeVpcEndpointServicesResult, RusotoError<DescribeVpcEndpointServicesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_vpc_endpoint_services(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_vpc_endpoints`].
+    pub fn describe_vpc_endpoints(
+        &self,
+        input: DescribeVpcEndpointsRequest,
+    ) -> Result<DescribeVpcEndpointsResult, RusotoError<DescribeVpcEndpointsError>> {
+        self.runtime
+            .block_on(self.client.describe_vpc_endpoints(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_vpc_peering_connections`].
+    pub fn describe_vpc_peering_connections(
+        &self,
+        input: DescribeVpcPeeringConnectionsRequest,
+    ) -> Result<DescribeVpcPeeringConnectionsResult, RusotoError<DescribeVpcPeeringConnectionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_vpc_peering_connections(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_vpcs`].
+    pub fn describe_vpcs(
+        &self,
+        input: DescribeVpcsRequest,
+    ) -> Result<DescribeVpcsResult, RusotoError<DescribeVpcsError>> {
+        self.runtime.block_on(self.client.describe_vpcs(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_vpn_connections`].
+    pub fn describe_vpn_connections(
+        &self,
+        input: DescribeVpnConnectionsRequest,
+    ) -> Result<DescribeVpnConnectionsResult, RusotoError<DescribeVpnConnectionsError>> {
+        self.runtime
+            .block_on(self.client.describe_vpn_connections(input))
+    }
+
+    /// Blocking version of [`Ec2::describe_vpn_gateways`].
+    pub fn describe_vpn_gateways(
+        &self,
+        input: DescribeVpnGatewaysRequest,
+    ) -> Result<DescribeVpnGatewaysResult, RusotoError<DescribeVpnGatewaysError>> {
+        self.runtime
+            .block_on(self.client.describe_vpn_gateways(input))
+    }
+
+    /// Blocking version of [`Ec2::detach_classic_link_vpc`].
+    pub fn detach_classic_link_vpc(
+        &self,
+        input: DetachClassicLinkVpcRequest,
+    ) -> Result<DetachClassicLinkVpcResult, RusotoError<DetachClassicLinkVpcError>> {
+        self.runtime
+            .block_on(self.client.detach_classic_link_vpc(input))
+    }
+
+    /// Blocking version of [`Ec2::detach_internet_gateway`].
+    pub fn detach_internet_gateway(
+        &self,
+        input: DetachInternetGatewayRequest,
+    ) -> Result<(), RusotoError<DetachInternetGatewayError>> {
+        self.runtime
+            .block_on(self.client.detach_internet_gateway(input))
+    }
+
+    /// Blocking version of [`Ec2::detach_network_interface`].
+    pub fn detach_network_interface(
+        &self,
+        input: DetachNetworkInterfaceRequest,
+    ) -> Result<(), RusotoError<DetachNetworkInterfaceError>> {
+        self.runtime
+            .block_on(self.client.detach_network_interface(input))
+    }
+
+    /// Blocking version of [`Ec2::detach_volume`].
+    pub fn detach_volume(
+        &self,
+        input: DetachVolumeRequest,
+    ) -> Result<VolumeAttachment, RusotoError<DetachVolumeError>> {
+        self.runtime.block_on(self.client.detach_volume(input))
+    }
+
+    /// Blocking version of [`Ec2::detach_vpn_gateway`].
+    pub fn detach_vpn_gateway(
+        &self,
+        input: DetachVpnGatewayRequest,
+    ) -> Result<(), RusotoError<DetachVpnGatewayError>> {
+        self.runtime.block_on(self.client.detach_vpn_gateway(input))
+    }
+
+    /// Blocking version of [`Ec2::disable_ebs_encryption_by_default`].
+    pub fn disable_ebs_encryption_by_default(
+        &self,
+        input: DisableEbsEncryptionByDefaultRequest,
+    ) -> Result<DisableEbsEncryptionByDefaultResult, RusotoError<DisableEbsEncryptionByDefaultError>>
+    {
+        self.runtime
+            .block_on(self.client.disable_ebs_encryption_by_default(input))
+    }
+
+    /// Blocking version of [`Ec2::disable_fast_snapshot_restores`].
+    pub fn disable_fast_snapshot_restores(
+        &self,
+        input: DisableFastSnapshotRestoresRequest,
+    ) -> Result<DisableFastSnapshotRestoresResult, RusotoError<DisableFastSnapshotRestoresError>>
+    {
+        self.runtime
+            .block_on(self.client.disable_fast_snapshot_restores(input))
+    }
+
+    /// Blocking version of [`Ec2::disable_transit_gateway_route_table_propagation`].
+    pub fn disable_transit_gateway_route_table_propagation(
+        &self,
+        input: DisableTransitGatewayRouteTablePropagationRequest,
+    ) -> Result<
+        DisableTransitGatewayRouteTablePropagationResult,
+        RusotoError<DisableTransitGatewayRouteTablePropagationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .disable_transit_gateway_route_table_propagation(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::disable_vgw_route_propagation`].
+    pub fn disable_vgw_route_propagation(
+        &self,
+        input: DisableVgwRoutePropagationRequest,
+    ) -> Result<(), RusotoError<DisableVgwRoutePropagationError>> {
+        self.runtime
+            .block_on(self.client.disable_vgw_route_propagation(input))
+    }
+
+    /// Blocking version of [`Ec2::disable_vpc_classic_link`].
+    pub fn disable_vpc_classic_link(
+        &self,
+        input: DisableVpcClassicLinkRequest,
+    ) -> Result<DisableVpcClassicLinkResult, RusotoError<DisableVpcClassicLinkError>> {
+        self.runtime
+            .block_on(self.client.disable_vpc_classic_link(input))
+    }
+
+    /// Blocking version of [`Ec2::disable_vpc_classic_link_dns_support`].
+    pub fn disable_vpc_classic_link_dns_support(
+        &self,
+        input: DisableVpcClassicLinkDnsSupportRequest,
+    ) -> Result<
+        DisableVpcClassicLinkDnsSupportResult,
+        RusotoError<DisableVpcClassicLinkDnsSupportError>,
+    > {
+        self.runtime
+            .block_on(self.client.disable_vpc_classic_link_dns_support(input))
+    }
+
+    /// Blocking version of [`Ec2::disassociate_address`].
+    pub fn disassociate_address(
+        &self,
+        input: DisassociateAddressRequest,
+    ) -> Result<(), RusotoError<DisassociateAddressError>> {
+        self.runtime
+            .block_on(self.client.disassociate_address(input))
+    }
+
+    /// Blocking version of [`Ec2::disassociate_client_vpn_target_network`].
+    pub fn disassociate_client_vpn_target_network(
+        &self,
+        input: DisassociateClientVpnTargetNetworkRequest,
+    ) -> Result<
+        DisassociateClientVpnTargetNetworkResult,
+        RusotoError<DisassociateClientVpnTargetNetworkError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_client_vpn_target_network(input))
+    }
+
+    /// Blocking version of [`Ec2::disassociate_enclave_certificate_iam_role`].
+    pub fn disassociate_enclave_certificate_iam_role(
+        &self,
+        input: DisassociateEnclaveCertificateIamRoleRequest,
+    ) -> Result<
+        DisassociateEnclaveCertificateIamRoleResult,
+        RusotoError<DisassociateEnclaveCertificateIamRoleError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_enclave_certificate_iam_role(input))
+    }
+
+    /// Blocking version of [`Ec2::disassociate_iam_instance_profile`].
+    pub fn disassociate_iam_instance_profile(
+        &self,
+        input: DisassociateIamInstanceProfileRequest,
+    ) -> Result<
+        DisassociateIamInstanceProfileResult,
+        RusotoError<DisassociateIamInstanceProfileError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_iam_instance_profile(input))
+    }
+
+    /// Blocking version of [`Ec2::disassociate_route_table`].
+    pub fn disassociate_route_table(
+        &self,
+        input: DisassociateRouteTableRequest,
+    ) -> Result<(), RusotoError<DisassociateRouteTableError>> {
+        self.runtime
+            .block_on(self.client.disassociate_route_table(input))
+    }
+
+    /// Blocking version of [`Ec2::disassociate_subnet_cidr_block`].
+    pub fn disassociate_subnet_cidr_block(
+        &self,
+        input: DisassociateSubnetCidrBlockRequest,
+    ) -> Result<DisassociateSubnetCidrBlockResult, RusotoError<DisassociateSubnetCidrBlockError>>
+    {
+        self.runtime
+            .block_on(self.client.disassociate_subnet_cidr_block(input))
+    }
+
+    /// Blocking version of [`Ec2::disassociate_transit_gateway_multicast_domain`].
+    pub fn disassociate_transit_gateway_multicast_domain(
+        &self,
+        input: DisassociateTransitGatewayMulticastDomainRequest,
+    ) -> Result<
+        DisassociateTransitGatewayMulticastDomainResult,
+        RusotoError<DisassociateTransitGatewayMulticastDomainError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .disassociate_transit_gateway_multicast_domain(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::disassociate_transit_gateway_route_table`].
+    pub fn disassociate_transit_gateway_route_table(
+        &self,
+        input: DisassociateTransitGatewayRouteTableRequest,
+    ) -> Result<
+        DisassociateTransitGatewayRouteTableResult,
+        RusotoError<DisassociateTransitGatewayRouteTableError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_transit_gateway_route_table(input))
+    }
+
+    /// Blocking version of [`Ec2::disassociate_vpc_cidr_block`].
+    pub fn disassociate_vpc_cidr_block(
+        &self,
+        input: DisassociateVpcCidrBlockRequest,
+    ) -> Result<DisassociateVpcCidrBlockResult, RusotoError<DisassociateVpcCidrBlockError>> {
+        self.runtime
+            .block_on(self.client.disassociate_vpc_cidr_block(input))
+    }
+
+    /// Blocking version of [`Ec2::enable_ebs_encryption_by_default`].
+    pub fn enable_ebs_encryption_by_default(
+        &self,
+        input: EnableEbsEncryptionByDefaultRequest,
+    ) -> Result<EnableEbsEncryptionByDefaultResult, RusotoError<EnableEbsEncryptionByDefaultError>>
+    {
+        self.runtime
+            .block_on(self.client.enable_ebs_encryption_by_default(input))
+    }
+
+    /// Blocking version of [`Ec2::enable_fast_snapshot_restores`].
+    pub fn enable_fast_snapshot_restores(
+        &self,
+        input: EnableFastSnapshotRestoresRequest,
+    ) -> Result<EnableFastSnapshotRestoresResult, RusotoError<EnableFastSnapshotRestoresError>>
+    {
+        self.runtime
+            .block_on(self.client.enable_fast_snapshot_restores(input))
+    }
+
+    /// Blocking version of [`Ec2::enable_transit_gateway_route_table_propagation`].
+    pub fn enable_transit_gateway_route_table_propagation(
+        &self,
+        input: EnableTransitGatewayRouteTablePropagationRequest,
+    ) -> Result<
+        EnableTransitGatewayRouteTablePropagationResult,
+        RusotoError<EnableTransitGatewayRouteTablePropagationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .enable_transit_gateway_route_table_propagation(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::enable_vgw_route_propagation`].
+    pub fn enable_vgw_route_propagation(
+        &self,
+        input: EnableVgwRoutePropagationRequest,
+    ) -> Result<(), RusotoError<EnableVgwRoutePropagationError>> {
+        self.runtime
+            .block_on(self.client.enable_vgw_route_propagation(input))
+    }
+
+    /// Blocking version of [`Ec2::enable_volume_io`].
+    pub fn enable_volume_io(
+        &self,
+        input: EnableVolumeIORequest,
+    ) -> Result<(), RusotoError<EnableVolumeIOError>> {
+        self.runtime.block_on(self.client.enable_volume_io(input))
+    }
+
+    /// Blocking version of [`Ec2::enable_vpc_classic_link`].
+    pub fn enable_vpc_classic_link(
+        &self,
+        input: EnableVpcClassicLinkRequest,
+    ) -> Result<EnableVpcClassicLinkResult, RusotoError<EnableVpcClassicLinkError>> {
+        self.runtime
+            .block_on(self.client.enable_vpc_classic_link(input))
+    }
+
+    /// Blocking version of [`Ec2::enable_vpc_classic_link_dns_support`].
+    pub fn enable_vpc_classic_link_dns_support(
+        &self,
+        input: EnableVpcClassicLinkDnsSupportRequest,
+    ) -> Result<
+        EnableVpcClassicLinkDnsSupportResult,
+        RusotoError<EnableVpcClassicLinkDnsSupportError>,
+    > {
+        self.runtime
+            .block_on(self.client.enable_vpc_classic_link_dns_support(input))
+    }
+
+    /// Blocking version of [`Ec2::export_client_vpn_client_certificate_revocation_list`].
+    pub fn export_client_vpn_client_certificate_revocation_list(
+        &self,
+        input: ExportClientVpnClientCertificateRevocationListRequest,
+    ) -> Result<
+        ExportClientVpnClientCertificateRevocationListResult,
+        RusotoError<ExportClientVpnClientCertificateRevocationListError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .export_client_vpn_client_certificate_revocation_list(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::export_client_vpn_client_configuration`].
+    pub fn export_client_vpn_client_configuration(
+        &self,
+        input: ExportClientVpnClientConfigurationRequest,
+    ) -> Result<
+        ExportClientVpnClientConfigurationResult,
+        RusotoError<ExportClientVpnClientConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.export_client_vpn_client_configuration(input))
+    }
+
+    /// Blocking version of [`Ec2::export_image`].
+    pub fn export_image(
+        &self,
+        input: ExportImageRequest,
+    ) -> Result<ExportImageResult, RusotoError<ExportImageError>> {
+        self.runtime.block_on(self.client.export_image(input))
+    }
+
+    /// Blocking version of [`Ec2::export_transit_gateway_routes`].
+    pub fn export_transit_gateway_routes(
+        &self,
+        input: ExportTransitGatewayRoutesRequest,
+    ) -> Result<ExportTransitGatewayRoutesResult, RusotoError<ExportTransitGatewayRoutesError>>
+    {
+        self.runtime
+            .block_on(self.client.export_transit_gateway_routes(input))
+    }
+
+    /// Blocking version of [`Ec2::get_associated_enclave_certificate_iam_roles`].
+    pub fn get_associated_enclave_certificate_iam_roles(
+        &self,
+        input: GetAssociatedEnclaveCertificateIamRolesRequest,
+    ) -> Result<
+        GetAssociatedEnclaveCertificateIamRolesResult,
+        RusotoError<GetAssociatedEnclaveCertificateIamRolesError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .get_associated_enclave_certificate_iam_roles(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::get_associated_ipv_6_pool_cidrs`].
+    pub fn get_associated_ipv_6_pool_cidrs(
+        &self,
+        input: GetAssociatedIpv6PoolCidrsRequest,
+    ) -> Result<GetAssociatedIpv6PoolCidrsResult, RusotoError<GetAssociatedIpv6PoolCidrsError>>
+    {
+        self.runtime
+            .block_on(self.client.get_associated_ipv_6_pool_cidrs(input))
+    }
+
+    /// Blocking version of [`Ec2::get_capacity_reservation_usage`].
+    pub fn get_capacity_reservation_usage(
+        &self,
+        input: GetCapacityReservationUsageRequest,
+    ) -> Result<GetCapacityReservationUsageResult, RusotoError<GetCapacityReservationUsageError>>
+    {
+        self.runtime
+            .block_on(self.client.get_capacity_reservation_usage(input))
+    }
+
+    /// Blocking version of [`Ec2::get_coip_pool_usage`].
+    pub fn get_coip_pool_usage(
+        &self,
+        input: GetCoipPoolUsageRequest,
+    ) -> Result<GetCoipPoolUsageResult, RusotoError<GetCoipPoolUsageError>> {
+        self.runtime
+            .block_on(self.client.get_coip_pool_usage(input))
+    }
+
+    /// Blocking version of [`Ec2::get_console_output`].
+    pub fn get_console_output(
+        &self,
+        input: GetConsoleOutputRequest,
+    ) -> Result<GetConsoleOutputResult, RusotoError<GetConsoleOutputError>> {
+        self.runtime.block_on(self.client.get_console_output(input))
+    }
+
+    /// Blocking version of [`Ec2::get_console_screenshot`].
+    pub fn get_console_screenshot(
+        &self,
+        input: GetConsoleScreenshotRequest,
+    ) -> Result<GetConsoleScreenshotResult, RusotoError<GetConsoleScreenshotError>> {
+        self.runtime
+            .block_on(self.client.get_console_screenshot(input))
+    }
+
+    /// Blocking version of [`Ec2::get_default_credit_specification`].
+    pub fn get_default_credit_specification(
+        &self,
+        input: GetDefaultCreditSpecificationRequest,
+    ) -> Result<GetDefaultCreditSpecificationResult, RusotoError<GetDefaultCreditSpecificationError>>
+    {
+        self.runtime
+            .block_on(self.client.get_default_credit_specification(input))
+    }
+
+    /// Blocking version of [`Ec2::get_ebs_default_kms_key_id`].
+    pub fn get_ebs_default_kms_key_id(
+        &self,
+        input: GetEbsDefaultKmsKeyIdRequest,
+    ) -> Result<GetEbsDefaultKmsKeyIdResult, RusotoError<GetEbsDefaultKmsKeyIdError>> {
+        self.runtime
+            .block_on(self.client.get_ebs_default_kms_key_id(input))
+    }
+
+    /// Blocking version of [`Ec2::get_ebs_encryption_by_default`].
+    pub fn get_ebs_encryption_by_default(
+        &self,
+        input: GetEbsEncryptionByDefaultRequest,
+    ) -> Result<GetEbsEncryptionByDefaultResult, RusotoError<GetEbsEncryptionByDefaultError>> {
+        self.runtime
+            .block_on(self.client.get_ebs_encryption_by_default(input))
+    }
+
+    /// Blocking version of [`Ec2::get_groups_for_capacity_reservation`].
+    pub fn get_groups_for_capacity_reservation(
+        &self,
+        input: GetGroupsForCapacityReservationRequest,
+    ) -> Result<
+        GetGroupsForCapacityReservationResult,
+        RusotoError<GetGroupsForCapacityReservationError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_groups_for_capacity_reservation(input))
+    }
+
+    /// Blocking version of [`Ec2::get_host_reservation_purchase_preview`].
+    pub fn get_host_reservation_purchase_preview(
+        &self,
+        input: GetHostReservationPurchasePreviewRequest,
+    ) -> Result<
+        GetHostReservationPurchasePreviewResult,
+        RusotoError<GetHostReservationPurchasePreviewError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_host_reservation_purchase_preview(input))
+    }
+
+    /// Blocking version of [`Ec2::get_launch_template_data`].
+    pub fn get_launch_template_data(
+        &self,
+        input: GetLaunchTemplateDataRequest,
+    ) -> Result<GetLaunchTemplateDataResult, RusotoError<GetLaunchTemplateDataError>> {
+        self.runtime
+            .block_on(self.client.get_launch_template_data(input))
+    }
+
+    /// Blocking version of [`Ec2::get_managed_prefix_list_associations`].
+    pub fn get_managed_prefix_list_associations(
+        &self,
+        input: GetManagedPrefixListAssociationsRequest,
+    ) -> Result<
+        GetManagedPrefixListAssociationsResult,
+        RusotoError<GetManagedPrefixListAssociationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_managed_prefix_list_associations(input))
+    }
+
+    /// Blocking version of [`Ec2::get_managed_prefix_list_entries`].
+    pub fn get_managed_prefix_list_entries(
+        &self,
+        input: GetManagedPrefixListEntriesRequest,
+    ) -> Result<GetManagedPrefixListEntriesResult, RusotoError<GetManagedPrefixListEntriesError>>
+    {
+        self.runtime
+            .block_on(self.client.get_managed_prefix_list_entries(input))
+    }
+
+    /// Blocking version of [`Ec2::get_password_data`].
+    pub fn get_password_data(
+        &self,
+        input: GetPasswordDataRequest,
+    ) -> Result<GetPasswordDataResult, RusotoError<GetPasswordDataError>> {
+        self.runtime.block_on(self.client.get_password_data(input))
+    }
+
+    /// Blocking version of [`Ec2::get_reserved_instances_exchange_quote`].
+    pub fn get_reserved_instances_exchange_quote(
+        &self,
+        input: GetReservedInstancesExchangeQuoteRequest,
+    ) -> Result<
+        GetReservedInstancesExchangeQuoteResult,
+        RusotoError<GetReservedInstancesExchangeQuoteError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_reserved_instances_exchange_quote(input))
+    }
+
+    /// Blocking version of [`Ec2::get_transit_gateway_attachment_propagations`].
+    pub fn get_transit_gateway_attachment_propagations(
+        &self,
+        input: GetTransitGatewayAttachmentPropagationsRequest,
+    ) -> Result<
+        GetTransitGatewayAttachmentPropagationsResult,
+        RusotoError<GetTransitGatewayAttachmentPropagationsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .get_transit_gateway_attachment_propagations(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::get_transit_gateway_multicast_domain_associations`].
+    pub fn get_transit_gateway_multicast_domain_associations(
+        &self,
+        input: GetTransitGatewayMulticastDomainAssociationsRequest,
+    ) -> Result<
+        GetTransitGatewayMulticastDomainAssociationsResult,
+        RusotoError<GetTransitGatewayMulticastDomainAssociationsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .get_transit_gateway_multicast_domain_associations(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::get_transit_gateway_prefix_list_references`].
+    pub fn get_transit_gateway_prefix_list_references(
+        &self,
+        input: GetTransitGatewayPrefixListReferencesRequest,
+    ) -> Result<
+        GetTransitGatewayPrefixListReferencesResult,
+        RusotoError<GetTransitGatewayPrefixListReferencesError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .get_transit_gateway_prefix_list_references(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::get_transit_gateway_route_table_associations`].
+    pub fn get_transit_gateway_route_table_associations(
+        &self,
+        input: GetTransitGatewayRouteTableAssociationsRequest,
+    ) -> Result<
+        GetTransitGatewayRouteTableAssociationsResult,
+        RusotoError<GetTransitGatewayRouteTableAssociationsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .get_transit_gateway_route_table_associations(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::get_transit_gateway_route_table_propagations`].
+    pub fn get_transit_gateway_route_table_propagations(
+        &self,
+        input: GetTransitGatewayRouteTablePropagationsRequest,
+    ) -> Result<
+        GetTransitGatewayRouteTablePropagationsResult,
+        RusotoError<GetTransitGatewayRouteTablePropagationsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .get_transit_gateway_route_table_propagations(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::import_client_vpn_client_certificate_revocation_list`].
+    pub fn import_client_vpn_client_certificate_revocation_list(
+        &self,
+        input: ImportClientVpnClientCertificateRevocationListRequest,
+    ) -> Result<
+        ImportClientVpnClientCertificateRevocationListResult,
+        RusotoError<ImportClientVpnClientCertificateRevocationListError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .import_client_vpn_client_certificate_revocation_list(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::import_image`].
+    pub fn import_image(
+        &self,
+        input: ImportImageRequest,
+    ) -> Result<ImportImageResult, RusotoError<ImportImageError>> {
+        self.runtime.block_on(self.client.import_image(input))
+    }
+
+    /// Blocking version of [`Ec2::import_instance`].
+    pub fn import_instance(
+        &self,
+        input: ImportInstanceRequest,
+    ) -> Result<ImportInstanceResult, RusotoError<ImportInstanceError>> {
+        self.runtime.block_on(self.client.import_instance(input))
+    }
+
+    /// Blocking version of [`Ec2::import_key_pair`].
+    pub fn import_key_pair(
+        &self,
+        input: ImportKeyPairRequest,
+    ) -> Result<ImportKeyPairResult, RusotoError<ImportKeyPairError>> {
+        self.runtime.block_on(self.client.import_key_pair(input))
+    }
+
+    /// Blocking version of [`Ec2::import_snapshot`].
+    pub fn import_snapshot(
+        &self,
+        input: ImportSnapshotRequest,
+    ) -> Result<ImportSnapshotResult, RusotoError<ImportSnapshotError>> {
+        self.runtime.block_on(self.client.import_snapshot(input))
+    }
+
+    /// Blocking version of [`Ec2::import_volume`].
+    pub fn import_volume(
+        &self,
+        input: ImportVolumeRequest,
+    ) -> Result<ImportVolumeResult, RusotoError<ImportVolumeError>> {
+        self.runtime.block_on(self.client.import_volume(input))
+    }
+
+    /// Blocking version of [`Ec2::modify_availability_zone_group`].
+    pub fn modify_availability_zone_group(
+        &self,
+        input: ModifyAvailabilityZoneGroupRequest,
+    ) -> Result<ModifyAvailabilityZoneGroupResult, RusotoError<ModifyAvailabilityZoneGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.modify_availability_zone_group(input))
+    }
+
+    /// Blocking version of [`Ec2::modify_capacity_reservation`].
+    pub fn modify_capacity_reservation(
+        &self,
+        input: ModifyCapacityReservationRequest,
+    ) -> Result<ModifyCapacityReservationResult, RusotoError<ModifyCapacityReservationError>> {
+        self.runtime
+            .block_on(self.client.modify_capacity_reservation(input))
+    }
+
+    /// Blocking version of [`Ec2::modify_client_vpn_endpoint`].
+    pub fn modify_client_vpn_endpoint(
+        &self,
+        input: ModifyClientVpnEndpointRequest,
+    ) -> Result<ModifyClientVpnEndpointResult, RusotoError<ModifyClientVpnEndpointError>> {
+        self.runtime
+            .block_on(self.client.modify_client_vpn_endpoint(input))
+    }
+
+    /// Blocking version of [`Ec2::modify_default_credit_specification`].
+    pub fn modify_default_credit_specification(
+        &self,
+        input: ModifyDefaultCreditSpecificationRequest,
+    ) -> Result<
+        ModifyDefaultCreditSpecificationResult,
+        RusotoError<ModifyDefaultCreditSpecificationError>,
+    > {
+        self.runtime
+            .block_on(self.client.modify_default_credit_specification(input))
+    }
+
+    /// Blocking version of [`Ec2::modify_ebs_default_kms_key_id`].
+    pub fn modify_ebs_default_kms_key_id(
+        &self,
+        input: ModifyEbsDefaultKmsKeyIdRequest,
+    ) -> Result<ModifyEbsDefaultKmsKeyIdResult, RusotoError<ModifyEbsDefaultKmsKeyIdError>> {
+        self.runtime
+            .block_on(self.client.modify_ebs_default_kms_key_id(input))
+    }
+
+    /// Blocking version of [`Ec2::modify_fleet`].
+    pub fn modify_fleet(
+        &self,
+        input: ModifyFleetRequest,
+    ) -> Result<ModifyFleetResult, RusotoError<ModifyFleetError>> {
+        self.runtime.block_on(self.client.modify_fleet(input))
+    }
+
+    /// Blocking version of [`Ec2::modify_fpga_image_attribute`].
+    pub fn modify_fpga_image_attribute(
+        &self,
+        input: ModifyFpgaImageAttributeRequest,
+    ) -> Result<ModifyFpgaImageAttributeResult, RusotoError<ModifyFpgaImageAttributeError>> {
+        self.runtime
+            .block_on(self.client.modify_fpga_image_attribute(input))
+    }
+
+    /// Blocking version of [`Ec2::modify_hosts`].
+    pub fn modify_hosts(
+        &self,
+        input: ModifyHostsRequest,
+    ) -> Result<ModifyHostsResult, RusotoError<ModifyHostsError>> {
+        self.runtime.block_on(self.client.modify_hosts(input))
+    }
+
+    /// Blocking version of [`Ec2::modify_id_format`].
+    pub fn modify_id_format(
+        &self,
+        input: ModifyIdFormatRequest,
+    ) -> Result<(), RusotoError<ModifyIdFormatError>> {
+        self.runtime.block_on(self.client.modify_id_format(input))
+    }
+
+    /// Blocking version of [`Ec2::modify_identity_id_format`].
+    pub fn modify_identity_id_format(
+        &self,
+        input: ModifyIdentityIdFormatRequest,
+    ) -> Result<(), RusotoError<ModifyIdentityIdFormatError>> {
+        self.runtime
+            .block_on(self.client.modify_identity_id_format(input))
+    }
+
+    /// Blocking version of [`Ec2::modify_image_attribute`].
+    pub fn modify_image_attribute(
+        &self,
+        input: ModifyImageAttributeRequest,
+    ) -> Result<(), RusotoError<ModifyImageAttributeError>> {
+        self.runtime
+            .block_on(self.client.modify_image_attribute(input))
+    }
+
+    /// Blocking version of [`Ec2::modify_instance_attribute`].
+    pub fn modify_instance_attribute(
+        &self,
+        input: ModifyInstanceAttributeRequest,
+    ) -> Result<(), RusotoError<ModifyInstanceAttributeError>> {
+        self.runtime
+            .block_on(self.client.modify_instance_attribute(input))
+    }
+
+    /// Blocking version of [`Ec2::modify_instance_capacity_reservation_attributes`].
+    pub fn modify_instance_capacity_reservation_attributes(
+        &self,
+        input: ModifyInstanceCapacityReservationAttributesRequest,
+    ) -> Result<
+        ModifyInstanceCapacityReservationAttributesResult,
+        RusotoError<ModifyInstanceCapacityReservationAttributesError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .modify_instance_capacity_reservation_attributes(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::modify_instance_credit_specification`].
+    pub fn modify_instance_credit_specification(
+        &self,
+        input: ModifyInstanceCreditSpecificationRequest,
+    ) -> Result<
+        ModifyInstanceCreditSpecificationResult,
+        RusotoError<ModifyInstanceCreditSpecificationError>,
+    > {
+        self.runtime
+            .block_on(self.client.modify_instance_credit_specification(input))
+    }
+
+    /// Blocking version of [`Ec2::modify_instance_event_start_time`].
+    pub fn modify_instance_event_start_time(
+        &self,
+        input: ModifyInstanceEventStartTimeRequest,
+    ) -> Result<ModifyInstanceEventStartTimeResult, RusotoError<ModifyInstanceEventStartTimeError>>
+    {
+        self.runtime
+            .block_on(self.client.modify_instance_event_start_time(input))
+    }
+
+    /// Blocking version of [`Ec2::modify_instance_metadata_options`].
+    pub fn modify_instance_metadata_options(
+        &self,
+        input: ModifyInstanceMetadataOptionsRequest,
+    ) -> Result<ModifyInstanceMetadataOptionsResult, RusotoError<ModifyInstanceMetadataOptionsError>>
+    {
+        self.runtime
+            .block_on(self.client.modify_instance_metadata_options(input))
+    }
+
+    /// Blocking version of [`Ec2::modify_instance_placement`].
+    pub fn modify_instance_placement(
+        &self,
+        input: ModifyInstancePlacementRequest,
+    ) -> Result<ModifyInstancePlacementResult, RusotoError<ModifyInstancePlacementError>> {
+        self.runtime
+            .block_on(self.client.modify_instance_placement(input))
+    }
+
+    /// Blocking version of [`Ec2::modify_launch_template`].
+    pub fn modify_launch_template(
+        &self,
+        input: ModifyLaunchTemplateRequest,
+    ) -> Result<ModifyLaunchTemplateResult, RusotoError<ModifyLaunchTemplateError>> {
+        self.runtime
+            .block_on(self.client.modify_launch_template(input))
+    }
+
+    /// Blocking version of [`Ec2::modify_managed_prefix_list`].
+    pub fn modify_managed_prefix_list(
+        &self,
+        input: ModifyManagedPrefixListRequest,
+    ) -> Result<ModifyManagedPrefixListResult, RusotoError<ModifyManagedPrefixListError>> {
+        self.runtime
+            .block_on(self.client.modify_managed_prefix_list(input))
+    }
+
+    /// Blocking version of [`Ec2::modify_network_interface_attribute`].
+    pub fn modify_network_interface_attribute(
+        &self,
+        input: ModifyNetworkInterfaceAttributeRequest,
+    ) -> Result<(), RusotoError<ModifyNetworkInterfaceAttributeError>> {
+        self.runtime
+            .block_on(self.client.modify_network_interface_attribute(input))
+    }
+
+    /// Blocking version of [`Ec2::modify_reserved_instances`].
+    pub fn modify_reserved_instances(
+        &self,
+        input: ModifyReservedInstancesRequest,
+    ) -> Result<ModifyReservedInstancesResult, RusotoError<ModifyReservedInstancesError>> {
+        self.runtime
+            .block_on(self.client.modify_reserved_instances(input))
+    }
+
+    /// Blocking version of [`Ec2::modify_snapshot_attribute`].
+    pub fn modify_snapshot_attribute(
+        &self,
+        input: ModifySnapshotAttributeRequest,
+    ) -> Result<(), RusotoError<ModifySnapshotAttributeError>> {
+        self.runtime
+            .block_on(self.client.modify_snapshot_attribute(input))
+    }
+
+    /// Blocking version of [`Ec2::modify_spot_fleet_request`].
+    pub fn modify_spot_fleet_request(
+        &self,
+        input: ModifySpotFleetRequestRequest,
+    ) -> Result<ModifySpotFleetRequestResponse, RusotoError<ModifySpotFleetRequestError>> {
+        self.runtime
+            .block_on(self.client.modify_spot_fleet_request(input))
+    }
+
+    /// Blocking version of [`Ec2::modify_subnet_attribute`].
+    pub fn modify_subnet_attribute(
+        &self,
+        input: ModifySubnetAttributeRequest,
+    ) -> Result<(), RusotoError<ModifySubnetAttributeError>> {
+        self.runtime
+            .block_on(self.client.modify_subnet_attribute(input))
+    }
+
+    /// Blocking version of [`Ec2::modify_traffic_mirror_filter_network_services`].
+    pub fn modify_traffic_mirror_filter_network_services(
+        &self,
+        input: ModifyTrafficMirrorFilterNetworkServicesRequest,
+    ) -> Result<
+        ModifyTrafficMirrorFilterNetworkServicesResult,
+        RusotoError<ModifyTrafficMirrorFilterNetworkServicesError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .modify_traffic_mirror_filter_network_services(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::modify_traffic_mirror_filter_rule`].
+    pub fn modify_traffic_mirror_filter_rule(
+        &self,
+        input: ModifyTrafficMirrorFilterRuleRequest,
+    ) -> Result<ModifyTrafficMirrorFilterRuleResult, RusotoError<ModifyTrafficMirrorFilterRuleError>>
+    {
+        self.runtime
+            .block_on(self.client.modify_traffic_mirror_filter_rule(input))
+    }
+
+    /// Blocking version of [`Ec2::modify_traffic_mirror_session`].
+    pub fn modify_traffic_mirror_session(
+        &self,
+        input: ModifyTrafficMirrorSessionRequest,
+    ) -> Result<ModifyTrafficMirrorSessionResult, RusotoError<ModifyTrafficMirrorSessionError>>
+    {
+        self.runtime
+            .block_on(self.client.modify_traffic_mirror_session(input))
+    }
+
+    /// Blocking version of [`Ec2::modify_transit_gateway`].
+    pub fn modify_transit_gateway(
+        &self,
+        input: ModifyTransitGatewayRequest,
+    ) -> Result<ModifyTransitGatewayResult, RusotoError<ModifyTransitGatewayError>> {
+        self.runtime
+            .block_on(self.client.modify_transit_gateway(input))
+    }
+
+    /// Blocking version of [`Ec2::modify_transit_gateway_prefix_list_reference`].
+    pub fn modify_transit_gateway_prefix_list_reference(
+        &self,
+        input: ModifyTransitGatewayPrefixListReferenceRequest,
+    ) -> Result<
+        ModifyTransitGatewayPrefixListReferenceResult,
+        RusotoError<ModifyTransitGatewayPrefixListReferenceError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .modify_transit_gateway_prefix_list_reference(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::modify_transit_gateway_vpc_attachment`].
+    pub fn modify_transit_gateway_vpc_attachment(
+        &self,
+        input: ModifyTransitGatewayVpcAttachmentRequest,
+    ) -> Result<
+        ModifyTransitGatewayVpcAttachmentResult,
+        RusotoError<ModifyTransitGatewayVpcAttachmentError>,
+    > {
+        self.runtime
+            .block_on(self.client.modify_transit_gateway_vpc_attachment(input))
+    }
+
+    /// Blocking version of [`Ec2::modify_volume`].
+    pub fn modify_volume(
+        &self,
+        input: ModifyVolumeRequest,
+    ) -> Result<ModifyVolumeResult, RusotoError<ModifyVolumeError>> {
+        self.runtime.block_on(self.client.modify_volume(input))
+    }
+
+    /// Blocking version of [`Ec2::modify_volume_attribute`].
+    pub fn modify_volume_attribute(
+        &self,
+        input: ModifyVolumeAttributeRequest,
+    ) -> Result<(), RusotoError<ModifyVolumeAttributeError>> {
+        self.runtime
+            .block_on(self.client.modify_volume_attribute(input))
+    }
+
+    /// Blocking version of [`Ec2::modify_vpc_attribute`].
+    pub fn modify_vpc_attribute(
+        &self,
+        input: ModifyVpcAttributeRequest,
+    ) -> Result<(), RusotoError<ModifyVpcAttributeError>> {
+        self.runtime
+            .block_on(self.client.modify_vpc_attribute(input))
+    }
+
+    /// Blocking version of [`Ec2::modify_vpc_endpoint`].
+    pub fn modify_vpc_endpoint(
+        &self,
+        input: ModifyVpcEndpointRequest,
+    ) -> Result<ModifyVpcEndpointResult, RusotoError<ModifyVpcEndpointError>> {
+        self.runtime
+            .block_on(self.client.modify_vpc_endpoint(input))
+    }
+
+    /// Blocking version of [`Ec2::modify_vpc_endpoint_connection_notification`].
+    pub fn modify_vpc_endpoint_connection_notification(
+        &self,
+        input: ModifyVpcEndpointConnectionNotificationRequest,
+    ) -> Result<
+        ModifyVpcEndpointConnectionNotificationResult,
+        RusotoError<ModifyVpcEndpointConnectionNotificationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .modify_vpc_endpoint_connection_notification(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::modify_vpc_endpoint_service_configuration`].
+    pub fn modify_vpc_endpoint_service_configuration(
+        &self,
+        input: ModifyVpcEndpointServiceConfigurationRequest,
+    ) -> Result<
+        ModifyVpcEndpointServiceConfigurationResult,
+        RusotoError<ModifyVpcEndpointServiceConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.modify_vpc_endpoint_service_configuration(input))
+    }
+
+    /// Blocking version of [`Ec2::modify_vpc_endpoint_service_permissions`].
+    pub fn modify_vpc_endpoint_service_permissions(
+        &self,
+        input: ModifyVpcEndpointServicePermissionsRequest,
+    ) -> Result<
+        ModifyVpcEndpointServicePermissionsResult,
+        RusotoError<ModifyVpcEndpointServicePermissionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.modify_vpc_endpoint_service_permissions(input))
+    }
+
+    /// Blocking version of [`Ec2::modify_vpc_peering_connection_options`].
+    pub fn modify_vpc_peering_connection_options(
+        &self,
+        input: ModifyVpcPeeringConnectionOptionsRequest,
+    ) -> Result<
+        ModifyVpcPeeringConnectionOptionsResult,
+        RusotoError<ModifyVpcPeeringConnectionOptionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.modify_vpc_peering_connection_options(input))
+    }
+
+    /// Blocking version of [`Ec2::modify_vpc_tenancy`].
+    pub fn modify_vpc_tenancy(
+        &self,
+        input: ModifyVpcTenancyRequest,
+    ) -> Result<ModifyVpcTenancyResult, RusotoError<ModifyVpcTenancyError>> {
+        self.runtime.block_on(self.client.modify_vpc_tenancy(input))
+    }
+
+    /// Blocking version of [`Ec2::modify_vpn_connection`].
+    pub fn modify_vpn_connection(
+        &self,
+        input: ModifyVpnConnectionRequest,
+    ) -> Result<ModifyVpnConnectionResult, RusotoError<ModifyVpnConnectionError>> {
+        self.runtime
+            .block_on(self.client.modify_vpn_connection(input))
+    }
+
+    /// Blocking version of [`Ec2::modify_vpn_connection_options`].
+    pub fn modify_vpn_connection_options(
+        &self,
+        input: ModifyVpnConnectionOptionsRequest,
+    ) -> Result<ModifyVpnConnectionOptionsResult, RusotoError<ModifyVpnConnectionOptionsError>>
+    {
+        self.runtime
+            .block_on(self.client.modify_vpn_connection_options(input))
+    }
+
+    /// Blocking version of [`Ec2::modify_vpn_tunnel_certificate`].
+    pub fn modify_vpn_tunnel_certificate(
+        &self,
+        input: ModifyVpnTunnelCertificateRequest,
+    ) -> Result<ModifyVpnTunnelCertificateResult, RusotoError<ModifyVpnTunnelCertificateError>>
+    {
+        self.runtime
+            .block_on(self.client.modify_vpn_tunnel_certificate(input))
+    }
+
+    /// Blocking version of [`Ec2::modify_vpn_tunnel_options`].
+    pub fn modify_vpn_tunnel_options(
+        &self,
+        input: ModifyVpnTunnelOptionsRequest,
+    ) -> Result<ModifyVpnTunnelOptionsResult, RusotoError<ModifyVpnTunnelOptionsError>> {
+        self.runtime
+            .block_on(self.client.modify_vpn_tunnel_options(input))
+    }
+
+    /// Blocking version of [`Ec2::monitor_instances`].
+    pub fn monitor_instances(
+        &self,
+        input: MonitorInstancesRequest,
+    ) -> Result<MonitorInstancesResult, RusotoError<MonitorInstancesError>> {
+        self.runtime.block_on(self.client.monitor_instances(input))
+    }
+
+    /// Blocking version of [`Ec2::move_address_to_vpc`].
+    pub fn move_address_to_vpc(
+        &self,
+        input: MoveAddressToVpcRequest,
+    ) -> Result<MoveAddressToVpcResult, RusotoError<MoveAddressToVpcError>> {
+        self.runtime
+            .block_on(self.client.move_address_to_vpc(input))
+    }
+
+    /// Blocking version of [`Ec2::provision_byoip_cidr`].
+    pub fn provision_byoip_cidr(
+        &self,
+        input: ProvisionByoipCidrRequest,
+    ) -> Result<ProvisionByoipCidrResult, RusotoError<ProvisionByoipCidrError>> {
+        self.runtime
+            .block_on(self.client.provision_byoip_cidr(input))
+    }
+
+    /// Blocking version of [`Ec2::purchase_host_reservation`].
+    pub fn purchase_host_reservation(
+        &self,
+        input: PurchaseHostReservationRequest,
+    ) -> Result<PurchaseHostReservationResult, RusotoError<PurchaseHostReservationError>> {
+        self.runtime
+            .block_on(self.client.purchase_host_reservation(input))
+    }
+
+    /// Blocking version of [`Ec2::purchase_reserved_instances_offering`].
+    pub fn purchase_reserved_instances_offering(
+        &self,
+        input: PurchaseReservedInstancesOfferingRequest,
+    ) -> Result<
+        PurchaseReservedInstancesOfferingResult,
+        RusotoError<PurchaseReservedInstancesOfferingError>,
+    > {
+        self.runtime
+            .block_on(self.client.purchase_reserved_instances_offering(input))
+    }
+
+    /// Blocking version of [`Ec2::purchase_scheduled_instances`].
+    pub fn purchase_scheduled_instances(
+        &self,
+        input: PurchaseScheduledInstancesRequest,
+    ) -> Result<PurchaseScheduledInstancesResult, RusotoError<PurchaseScheduledInstancesError>>
+    {
+        self.runtime
+            .block_on(self.client.purchase_scheduled_instances(input))
+    }
+
+    /// Blocking version of [`Ec2::reboot_instances`].
+    pub fn reboot_instances(
+        &self,
+        input: RebootInstancesRequest,
+    ) -> Result<(), RusotoError<RebootInstancesError>> {
+        self.runtime.block_on(self.client.reboot_instances(input))
+    }
+
+    /// Blocking version of [`Ec2::register_image`].
+    pub fn register_image(
+        &self,
+        input: RegisterImageRequest,
+    ) -> Result<RegisterImageResult, RusotoError<RegisterImageError>> {
+        self.runtime.block_on(self.client.register_image(input))
+    }
+
+    /// Blocking version of [`Ec2::register_instance_event_notification_attributes`].
+    pub fn register_instance_event_notification_attributes(
+        &self,
+        input: RegisterInstanceEventNotificationAttributesRequest,
+    ) -> Result<
+        RegisterInstanceEventNotificationAttributesResult,
+        RusotoError<RegisterInstanceEventNotificationAttributesError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .register_instance_event_notification_attributes(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::register_transit_gateway_multicast_group_members`].
+    pub fn register_transit_gateway_multicast_group_members(
+        &self,
+        input: RegisterTransitGatewayMulticastGroupMembersRequest,
+    ) -> Result<
+        RegisterTransitGatewayMulticastGroupMembersResult,
+        RusotoError<RegisterTransitGatewayMulticastGroupMembersError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .register_transit_gateway_multicast_group_members(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::register_transit_gateway_multicast_group_sources`].
+    pub fn register_transit_gateway_multicast_group_sources(
+        &self,
+        input: RegisterTransitGatewayMulticastGroupSourcesRequest,
+    ) -> Result<
+        RegisterTransitGatewayMulticastGroupSourcesResult,
+        RusotoError<RegisterTransitGatewayMulticastGroupSourcesError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .register_transit_gateway_multicast_group_sources(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::reject_transit_gateway_multicast_domain_associations`].
+    pub fn reject_transit_gateway_multicast_domain_associations(
+        &self,
+        input: RejectTransitGatewayMulticastDomainAssociationsRequest,
+    ) -> Result<
+        RejectTransitGatewayMulticastDomainAssociationsResult,
+        RusotoError<RejectTransitGatewayMulticastDomainAssociationsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .reject_transit_gateway_multicast_domain_associations(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::reject_transit_gateway_peering_attachment`].
+    pub fn reject_transit_gateway_peering_attachment(
+        &self,
+        input: RejectTransitGatewayPeeringAttachmentRequest,
+    ) -> Result<
+        RejectTransitGatewayPeeringAttachmentResult,
+        RusotoError<RejectTransitGatewayPeeringAttachmentError>,
+    > {
+        self.runtime
+            .block_on(self.client.reject_transit_gateway_peering_attachment(input))
+    }
+
+    /// Blocking version of [`Ec2::reject_transit_gateway_vpc_attachment`].
+    pub fn reject_transit_gateway_vpc_attachment(
+        &self,
+        input: RejectTransitGatewayVpcAttachmentRequest,
+    ) -> Result<
+        RejectTransitGatewayVpcAttachmentResult,
+        RusotoError<RejectTransitGatewayVpcAttachmentError>,
+    > {
+        self.runtime
+            .block_on(self.client.reject_transit_gateway_vpc_attachment(input))
+    }
+
+    /// Blocking version of [`Ec2::reject_vpc_endpoint_connections`].
+    pub fn reject_vpc_endpoint_connections(
+        &self,
+        input: RejectVpcEndpointConnectionsRequest,
+    ) -> Result<RejectVpcEndpointConnectionsResult, RusotoError<RejectVpcEndpointConnectionsError>>
+    {
+        self.runtime
+            .block_on(self.client.reject_vpc_endpoint_connections(input))
+    }
+
+    /// Blocking version of [`Ec2::reject_vpc_peering_connection`].
+    pub fn reject_vpc_peering_connection(
+        &self,
+        input: RejectVpcPeeringConnectionRequest,
+    ) -> Result<RejectVpcPeeringConnectionResult, RusotoError<RejectVpcPeeringConnectionError>>
+    {
+        self.runtime
+            .block_on(self.client.reject_vpc_peering_connection(input))
+    }
+
+    /// Blocking version of [`Ec2::release_address`].
+    pub fn release_address(
+        &self,
+        input: ReleaseAddressRequest,
+    ) -> Result<(), RusotoError<ReleaseAddressError>> {
+        self.runtime.block_on(self.client.release_address(input))
+    }
+
+    /// Blocking version of [`Ec2::release_hosts`].
+    pub fn release_hosts(
+        &self,
+        input: ReleaseHostsRequest,
+    ) -> Result<ReleaseHostsResult, RusotoError<ReleaseHostsError>> {
+        self.runtime.block_on(self.client.release_hosts(input))
+    }
+
+    /// Blocking version of [`Ec2::replace_iam_instance_profile_association`].
+    pub fn replace_iam_instance_profile_association(
+        &self,
+        input: ReplaceIamInstanceProfileAssociationRequest,
+    ) -> Result<
+        ReplaceIamInstanceProfileAssociationResult,
+        RusotoError<ReplaceIamInstanceProfileAssociationError>,
+    > {
+        self.runtime
+            .block_on(self.client.replace_iam_instance_profile_association(input))
+    }
+
+    /// Blocking version of [`Ec2::replace_network_acl_association`].
+    pub fn replace_network_acl_association(
+        &self,
+        input: ReplaceNetworkAclAssociationRequest,
+    ) -> Result<ReplaceNetworkAclAssociationResult, RusotoError<ReplaceNetworkAclAssociationError>>
+    {
+        self.runtime
+            .block_on(self.client.replace_network_acl_association(input))
+    }
+
+    /// Blocking version of [`Ec2::replace_network_acl_entry`].
+    pub fn replace_network_acl_entry(
+        &self,
+        input: ReplaceNetworkAclEntryRequest,
+    ) -> Result<(), RusotoError<ReplaceNetworkAclEntryError>> {
+        self.runtime
+            .block_on(self.client.replace_network_acl_entry(input))
+    }
+
+    /// Blocking version of [`Ec2::replace_route`].
+    pub fn replace_route(
+        &self,
+        input: ReplaceRouteRequest,
+    ) -> Result<(), RusotoError<ReplaceRouteError>> {
+        self.runtime.block_on(self.client.replace_route(input))
+    }
+
+    /// Blocking version of [`Ec2::replace_route_table_association`].
+    pub fn replace_route_table_association(
+        &self,
+        input: ReplaceRouteTableAssociationRequest,
+    ) -> Result<ReplaceRouteTableAssociationResult, RusotoError<ReplaceRouteTableAssociationError>>
+    {
+        self.runtime
+            .block_on(self.client.replace_route_table_association(input))
+    }
+
+    /// Blocking version of [`Ec2::replace_transit_gateway_route`].
+    pub fn replace_transit_gateway_route(
+        &self,
+        input: ReplaceTransitGatewayRouteRequest,
+    ) -> Result<ReplaceTransitGatewayRouteResult, RusotoError<ReplaceTransitGatewayRouteError>>
+    {
+        self.runtime
+            .block_on(self.client.replace_transit_gateway_route(input))
+    }
+
+    /// Blocking version of [`Ec2::report_instance_status`].
+    pub fn report_instance_status(
+        &self,
+        input: ReportInstanceStatusRequest,
+    ) -> Result<(), RusotoError<ReportInstanceStatusError>> {
+        self.runtime
+            .block_on(self.client.report_instance_status(input))
+    }
+
+    /// Blocking version of [`Ec2::request_spot_fleet`].
+    pub fn request_spot_fleet(
+        &self,
+        input: RequestSpotFleetRequest,
+    ) -> Result<RequestSpotFleetResponse, RusotoError<RequestSpotFleetError>> {
+        self.runtime.block_on(self.client.request_spot_fleet(input))
+    }
+
+    /// Blocking version of [`Ec2::request_spot_instances`].
+    pub fn request_spot_instances(
+        &self,
+        input: RequestSpotInstancesRequest,
+    ) -> Result<RequestSpotInstancesResult, RusotoError<RequestSpotInstancesError>> {
+        self.runtime
+            .block_on(self.client.request_spot_instances(input))
+    }
+
+    /// Blocking version of [`Ec2::reset_ebs_default_kms_key_id`].
+    pub fn reset_ebs_default_kms_key_id(
+        &self,
+        input: ResetEbsDefaultKmsKeyIdRequest,
+    ) -> Result<ResetEbsDefaultKmsKeyIdResult, RusotoError<ResetEbsDefaultKmsKeyIdError>> {
+        self.runtime
+            .block_on(self.client.reset_ebs_default_kms_key_id(input))
+    }
+
+    /// Blocking version of [`Ec2::reset_fpga_image_attribute`].
+    pub fn reset_fpga_image_attribute(
+        &self,
+        input: ResetFpgaImageAttributeRequest,
+    ) -> Result<ResetFpgaImageAttributeResult, RusotoError<ResetFpgaImageAttributeError>> {
+        self.runtime
+            .block_on(self.client.reset_fpga_image_attribute(input))
+    }
+
+    /// Blocking version of [`Ec2::reset_image_attribute`].
+    pub fn reset_image_attribute(
+        &self,
+        input: ResetImageAttributeRequest,
+    ) -> Result<(), RusotoError<ResetImageAttributeError>> {
+        self.runtime
+            .block_on(self.client.reset_image_attribute(input))
+    }
+
+    /// Blocking version of [`Ec2::reset_instance_attribute`].
+    pub fn reset_instance_attribute(
+        &self,
+        input: ResetInstanceAttributeRequest,
+    ) -> Result<(), RusotoError<ResetInstanceAttributeError>> {
+        self.runtime
+            .block_on(self.client.reset_instance_attribute(input))
+    }
+
+    /// Blocking version of [`Ec2::reset_network_interface_attribute`].
+    pub fn reset_network_interface_attribute(
+        &self,
+        input: ResetNetworkInterfaceAttributeRequest,
+    ) -> Result<(), RusotoError<ResetNetworkInterfaceAttributeError>> {
+        self.runtime
+            .block_on(self.client.reset_network_interface_attribute(input))
+    }
+
+    /// Blocking version of [`Ec2::reset_snapshot_attribute`].
+    pub fn reset_snapshot_attribute(
+        &self,
+        input: ResetSnapshotAttributeRequest,
+    ) -> Result<(), RusotoError<ResetSnapshotAttributeError>> {
+        self.runtime
+            .block_on(self.client.reset_snapshot_attribute(input))
+    }
+
+    /// Blocking version of [`Ec2::restore_address_to_classic`].
+    pub fn restore_address_to_classic(
+        &self,
+        input: RestoreAddressToClassicRequest,
+    ) -> Result<RestoreAddressToClassicResult, RusotoError<RestoreAddressToClassicError>> {
+        self.runtime
+            .block_on(self.client.restore_address_to_classic(input))
+    }
+
+    /// Blocking version of [`Ec2::restore_managed_prefix_list_version`].
+    pub fn restore_managed_prefix_list_version(
+        &self,
+        input: RestoreManagedPrefixListVersionRequest,
+    ) -> Result<
+        RestoreManagedPrefixListVersionResult,
+        RusotoError<RestoreManagedPrefixListVersionError>,
+    > {
+        self.runtime
+            .block_on(self.client.restore_managed_prefix_list_version(input))
+    }
+
+    /// Blocking version of [`Ec2::revoke_client_vpn_ingress`].
+    pub fn revoke_client_vpn_ingress(
+        &self,
+        input: RevokeClientVpnIngressRequest,
+    ) -> Result<RevokeClientVpnIngressResult, RusotoError<RevokeClientVpnIngressError>> {
+        self.runtime
+            .block_on(self.client.revoke_client_vpn_ingress(input))
+    }
+
+    /// Blocking version of [`Ec2::revoke_security_group_egress`].
+    pub fn revoke_security_group_egress(
+        &self,
+        input: RevokeSecurityGroupEgressRequest,
+    ) -> Result<RevokeSecurityGroupEgressResult, RusotoError<RevokeSecurityGroupEgressError>> {
+        self.runtime
+            .block_on(self.client.revoke_security_group_egress(input))
+    }
+
+    /// Blocking version of [`Ec2::revoke_security_group_ingress`].
+    pub fn revoke_security_group_ingress(
+        &self,
+        input: RevokeSecurityGroupIngressRequest,
+    ) -> Result<RevokeSecurityGroupIngressResult, RusotoError<RevokeSecurityGroupIngressError>>
+    {
+        self.runtime
+            .block_on(self.client.revoke_security_group_ingress(input))
+    }
+
+    /// Blocking version of [`Ec2::run_instances`].
+    pub fn run_instances(
+        &self,
+        input: RunInstancesRequest,
+    ) -> Result<Reservation, RusotoError<RunInstancesError>> {
+        self.runtime.block_on(self.client.run_instances(input))
+    }
+
+    /// Blocking version of [`Ec2::run_scheduled_instances`].
+    pub fn run_scheduled_instances(
+        &self,
+        input: RunScheduledInstancesRequest,
+    ) -> Result<RunScheduledInstancesResult, RusotoError<RunScheduledInstancesError>> {
+        self.runtime
+            .block_on(self.client.run_scheduled_instances(input))
+    }
+
+    /// Blocking version of [`Ec2::search_local_gateway_routes`].
+    pub fn search_local_gateway_routes(
+        &self,
+        input: SearchLocalGatewayRoutesRequest,
+    ) -> Result<SearchLocalGatewayRoutesResult, RusotoError<SearchLocalGatewayRoutesError>> {
+        self.runtime
+            .block_on(self.client.search_local_gateway_routes(input))
+    }
+
+    /// Blocking version of [`Ec2::search_transit_gateway_multicast_groups`].
+    pub fn search_transit_gateway_multicast_groups(
+        &self,
+        input: SearchTransitGatewayMulticastGroupsRequest,
+    ) -> Result<
+        SearchTransitGatewayMulticastGroupsResult,
+        RusotoError<SearchTransitGatewayMulticastGroupsError>,
+    > {
+        self.runtime
+            .block_on(self.client.search_transit_gateway_multicast_groups(input))
+    }
+
+    /// Blocking version of [`Ec2::search_transit_gateway_routes`].
+    pub fn search_transit_gateway_routes(
+        &self,
+        input: SearchTransitGatewayRoutesRequest,
+    ) -> Result<SearchTransitGatewayRoutesResult, RusotoError<SearchTransitGatewayRoutesError>>
+    {
+        self.runtime
+            .block_on(self.client.search_transit_gateway_routes(input))
+    }
+
+    /// Blocking version of [`Ec2::send_diagnostic_interrupt`].
+    pub fn send_diagnostic_interrupt(
+        &self,
+        input: SendDiagnosticInterruptRequest,
+    ) -> Result<(), RusotoError<SendDiagnosticInterruptError>> {
+        self.runtime
+            .block_on(self.client.send_diagnostic_interrupt(input))
+    }
+
+    /// Blocking version of [`Ec2::start_instances`].
+    pub fn start_instances(
+        &self,
+        input: StartInstancesRequest,
+    ) -> Result<StartInstancesResult, RusotoError<StartInstancesError>> {
+        self.runtime.block_on(self.client.start_instances(input))
+    }
+
+    /// Blocking version of [`Ec2::start_network_insights_analysis`].
+    pub fn start_network_insights_analysis(
+        &self,
+        input: StartNetworkInsightsAnalysisRequest,
+    ) -> Result<StartNetworkInsightsAnalysisResult, RusotoError<StartNetworkInsightsAnalysisError>>
+    {
+        self.runtime
+            .block_on(self.client.start_network_insights_analysis(input))
+    }
+
+    /// Blocking version of [`Ec2::start_vpc_endpoint_service_private_dns_verification`].
+    pub fn start_vpc_endpoint_service_private_dns_verification(
+        &self,
+        input: StartVpcEndpointServicePrivateDnsVerificationRequest,
+    ) -> Result<
+        StartVpcEndpointServicePrivateDnsVerificationResult,
+        RusotoError<StartVpcEndpointServicePrivateDnsVerificationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .start_vpc_endpoint_service_private_dns_verification(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::stop_instances`].
+    pub fn stop_instances(
+        &self,
+        input: StopInstancesRequest,
+    ) -> Result<StopInstancesResult, RusotoError<StopInstancesError>> {
+        self.runtime.block_on(self.client.stop_instances(input))
+    }
+
+    /// Blocking version of [`Ec2::terminate_client_vpn_connections`].
+    pub fn terminate_client_vpn_connections(
+        &self,
+        input: TerminateClientVpnConnectionsRequest,
+    ) -> Result<TerminateClientVpnConnectionsResult, RusotoError<TerminateClientVpnConnectionsError>>
+    {
+        self.runtime
+            .block_on(self.client.terminate_client_vpn_connections(input))
+    }
+
+    /// Blocking version of [`Ec2::terminate_instances`].
+    pub fn terminate_instances(
+        &self,
+        input: TerminateInstancesRequest,
+    ) -> Result<TerminateInstancesResult, RusotoError<TerminateInstancesError>> {
+        self.runtime
+            .block_on(self.client.terminate_instances(input))
+    }
+
+    /// Blocking version of [`Ec2::unassign_ipv_6_addresses`].
+    pub fn unassign_ipv_6_addresses(
+        &self,
+        input: UnassignIpv6AddressesRequest,
+    ) -> Result<UnassignIpv6AddressesResult, RusotoError<UnassignIpv6AddressesError>> {
+        self.runtime
+            .block_on(self.client.unassign_ipv_6_addresses(input))
+    }
+
+    /// Blocking version of [`Ec2::unassign_private_ip_addresses`].
+    pub fn unassign_private_ip_addresses(
+        &self,
+        input: UnassignPrivateIpAddressesRequest,
+    ) -> Result<(), RusotoError<UnassignPrivateIpAddressesError>> {
+        self.runtime
+            .block_on(self.client.unassign_private_ip_addresses(input))
+    }
+
+    /// Blocking version of [`Ec2::unmonitor_instances`].
+    pub fn unmonitor_instances(
+        &self,
+        input: UnmonitorInstancesRequest,
+    ) -> Result<UnmonitorInstancesResult, RusotoError<UnmonitorInstancesError>> {
+        self.runtime
+            .block_on(self.client.unmonitor_instances(input))
+    }
+
+    /// Blocking version of [`Ec2::update_security_group_rule_descriptions_egress`].
+    pub fn update_security_group_rule_descriptions_egress(
+        &self,
+        input: UpdateSecurityGroupRuleDescriptionsEgressRequest,
+    ) -> Result<
+        UpdateSecurityGroupRuleDescriptionsEgressResult,
+        RusotoError<UpdateSecurityGroupRuleDescriptionsEgressError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .update_security_group_rule_descriptions_egress(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::update_security_group_rule_descriptions_ingress`].
+    pub fn update_security_group_rule_descriptions_ingress(
+        &self,
+        input: UpdateSecurityGroupRuleDescriptionsIngressRequest,
+    ) -> Result<
+        UpdateSecurityGroupRuleDescriptionsIngressResult,
+        RusotoError<UpdateSecurityGroupRuleDescriptionsIngressError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .update_security_group_rule_descriptions_ingress(input),
+        )
+    }
+
+    /// Blocking version of [`Ec2::withdraw_byoip_cidr`].
+    pub fn withdraw_byoip_cidr(
+        &self,
+        input: WithdrawByoipCidrRequest,
+    ) -> Result<WithdrawByoipCidrResult, RusotoError<WithdrawByoipCidrError>> {
+        self.runtime
+            .block_on(self.client.withdraw_byoip_cidr(input))
+    }
+}
+
 #[cfg(test)]
 mod protocol_tests {
 
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1521,19 +1522,30 @@ impl BatchCheckLayerAvailabilityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        BatchCheckLayerAvailabilityError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchCheckLayerAvailabilityError::InvalidParameter,
                     )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(
-                        BatchCheckLayerAvailabilityError::RepositoryNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchCheckLayerAvailabilityError::RepositoryNotFound,
                     )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(BatchCheckLayerAvailabilityError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchCheckLayerAvailabilityError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1569,15 +1581,30 @@ impl BatchDeleteImageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(BatchDeleteImageError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDeleteImageError::InvalidParameter,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(BatchDeleteImageError::RepositoryNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDeleteImageError::RepositoryNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(BatchDeleteImageError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDeleteImageError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1611,15 +1638,30 @@ impl BatchGetImageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(BatchGetImageError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetImageError::InvalidParameter,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(BatchGetImageError::RepositoryNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetImageError::RepositoryNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(BatchGetImageError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetImageError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1665,41 +1707,78 @@ impl CompleteLayerUploadError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EmptyUploadException" => {
-                    return RusotoError::Service(CompleteLayerUploadError::EmptyUpload(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CompleteLayerUploadError::EmptyUpload,
+                    )
                 }
                 "InvalidLayerException" => {
-                    return RusotoError::Service(CompleteLayerUploadError::InvalidLayer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CompleteLayerUploadError::InvalidLayer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CompleteLayerUploadError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CompleteLayerUploadError::InvalidParameter,
+                    )
                 }
                 "KmsException" => {
-                    return RusotoError::Service(CompleteLayerUploadError::Kms(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CompleteLayerUploadError::Kms,
+                    )
                 }
                 "LayerAlreadyExistsException" => {
-                    return RusotoError::Service(CompleteLayerUploadError::LayerAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CompleteLayerUploadError::LayerAlreadyExists,
+                    )
                 }
                 "LayerPartTooSmallException" => {
-                    return RusotoError::Service(CompleteLayerUploadError::LayerPartTooSmall(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CompleteLayerUploadError::LayerPartTooSmall,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(CompleteLayerUploadError::RepositoryNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CompleteLayerUploadError::RepositoryNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(CompleteLayerUploadError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CompleteLayerUploadError::Server,
+                    )
                 }
                 "UploadNotFoundException" => {
-                    return RusotoError::Service(CompleteLayerUploadError::UploadNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CompleteLayerUploadError::UploadNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1747,29 +1826,62 @@ impl CreateRepositoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateRepositoryError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRepositoryError::InvalidParameter,
+                    )
                 }
                 "InvalidTagParameterException" => {
-                    return RusotoError::Service(CreateRepositoryError::InvalidTagParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRepositoryError::InvalidTagParameter,
+                    )
+                }
+                "KmsException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRepositoryError::Kms,
+                    )
                 }
-                "KmsException" => return RusotoError::Service(CreateRepositoryError::Kms(err.msg)),
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateRepositoryError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRepositoryError::LimitExceeded,
+                    )
                 }
                 "RepositoryAlreadyExistsException" => {
-                    return RusotoError::Service(CreateRepositoryError::RepositoryAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRepositoryError::RepositoryAlreadyExists,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(CreateRepositoryError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRepositoryError::Server,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreateRepositoryError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRepositoryError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1809,24 +1921,38 @@ impl DeleteLifecyclePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteLifecyclePolicyError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteLifecyclePolicyError::InvalidParameter,
+                    )
                 }
                 "LifecyclePolicyNotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteLifecyclePolicyError::LifecyclePolicyNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLifecyclePolicyError::LifecyclePolicyNotFound,
                     )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(DeleteLifecyclePolicyError::RepositoryNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteLifecyclePolicyError::RepositoryNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeleteLifecyclePolicyError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLifecyclePolicyError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1863,19 +1989,30 @@ impl DeleteRegistryPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteRegistryPolicyError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRegistryPolicyError::InvalidParameter,
+                    )
                 }
                 "RegistryPolicyNotFoundException" => {
-                    return RusotoError::Service(DeleteRegistryPolicyError::RegistryPolicyNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRegistryPolicyError::RegistryPolicyNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeleteRegistryPolicyError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRegistryPolicyError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1913,19 +2050,46 @@ impl DeleteRepositoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteRepositoryError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRepositoryError::InvalidParameter,
+                    )
+                }
+                "KmsException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRepositoryError::Kms,
+                    )
                 }
-                "KmsException" => return RusotoError::Service(DeleteRepositoryError::Kms(err.msg)),
                 "RepositoryNotEmptyException" => {
-                    return RusotoError::Service(DeleteRepositoryError::RepositoryNotEmpty(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRepositoryError::RepositoryNotEmpty,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(DeleteRepositoryError::RepositoryNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRepositoryError::RepositoryNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeleteRepositoryError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRepositoryError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1963,24 +2127,38 @@ impl DeleteRepositoryPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteRepositoryPolicyError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRepositoryPolicyError::InvalidParameter,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(DeleteRepositoryPolicyError::RepositoryNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRepositoryPolicyError::RepositoryNotFound,
+                    )
                 }
                 "RepositoryPolicyNotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteRepositoryPolicyError::RepositoryPolicyNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRepositoryPolicyError::RepositoryPolicyNotFound,
                     )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeleteRepositoryPolicyError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRepositoryPolicyError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2021,29 +2199,46 @@ impl DescribeImageScanFindingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ImageNotFoundException" => {
-                    return RusotoError::Service(DescribeImageScanFindingsError::ImageNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeImageScanFindingsError::ImageNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeImageScanFindingsError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeImageScanFindingsError::InvalidParameter,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeImageScanFindingsError::RepositoryNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeImageScanFindingsError::RepositoryNotFound,
                     )
                 }
                 "ScanNotFoundException" => {
-                    return RusotoError::Service(DescribeImageScanFindingsError::ScanNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeImageScanFindingsError::ScanNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeImageScanFindingsError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeImageScanFindingsError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2081,18 +2276,38 @@ impl DescribeImagesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ImageNotFoundException" => {
-                    return RusotoError::Service(DescribeImagesError::ImageNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeImagesError::ImageNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeImagesError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeImagesError::InvalidParameter,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(DescribeImagesError::RepositoryNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeImagesError::RepositoryNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeImagesError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeImagesError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2125,12 +2340,22 @@ impl DescribeRegistryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeRegistryError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRegistryError::InvalidParameter,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeRegistryError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRegistryError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2163,19 +2388,30 @@ impl DescribeRepositoriesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeRepositoriesError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRepositoriesError::InvalidParameter,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(DescribeRepositoriesError::RepositoryNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRepositoriesError::RepositoryNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeRepositoriesError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRepositoriesError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2207,14 +2443,22 @@ impl GetAuthorizationTokenError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetAuthorizationTokenError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetAuthorizationTokenError::InvalidParameter,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(GetAuthorizationTokenError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAuthorizationTokenError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2251,29 +2495,46 @@ impl GetDownloadUrlForLayerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetDownloadUrlForLayerError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDownloadUrlForLayerError::InvalidParameter,
+                    )
                 }
                 "LayerInaccessibleException" => {
-                    return RusotoError::Service(GetDownloadUrlForLayerError::LayerInaccessible(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDownloadUrlForLayerError::LayerInaccessible,
+                    )
                 }
                 "LayersNotFoundException" => {
-                    return RusotoError::Service(GetDownloadUrlForLayerError::LayersNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDownloadUrlForLayerError::LayersNotFound,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(GetDownloadUrlForLayerError::RepositoryNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDownloadUrlForLayerError::RepositoryNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(GetDownloadUrlForLayerError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDownloadUrlForLayerError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2311,22 +2572,38 @@ impl GetLifecyclePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetLifecyclePolicyError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLifecyclePolicyError::InvalidParameter,
+                    )
                 }
                 "LifecyclePolicyNotFoundException" => {
-                    return RusotoError::Service(GetLifecyclePolicyError::LifecyclePolicyNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetLifecyclePolicyError::LifecyclePolicyNotFound,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(GetLifecyclePolicyError::RepositoryNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetLifecyclePolicyError::RepositoryNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(GetLifecyclePolicyError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLifecyclePolicyError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2363,24 +2640,38 @@ impl GetLifecyclePolicyPreviewError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetLifecyclePolicyPreviewError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetLifecyclePolicyPreviewError::InvalidParameter,
+                    )
                 }
                 "LifecyclePolicyPreviewNotFoundException" => {
-                    return RusotoError::Service(
-                        GetLifecyclePolicyPreviewError::LifecyclePolicyPreviewNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLifecyclePolicyPreviewError::LifecyclePolicyPreviewNotFound,
                     )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(
-                        GetLifecyclePolicyPreviewError::RepositoryNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLifecyclePolicyPreviewError::RepositoryNotFound,
                     )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(GetLifecyclePolicyPreviewError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLifecyclePolicyPreviewError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2417,17 +2708,30 @@ impl GetRegistryPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetRegistryPolicyError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRegistryPolicyError::InvalidParameter,
+                    )
                 }
                 "RegistryPolicyNotFoundException" => {
-                    return RusotoError::Service(GetRegistryPolicyError::RegistryPolicyNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRegistryPolicyError::RegistryPolicyNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(GetRegistryPolicyError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRegistryPolicyError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2463,24 +2767,38 @@ impl GetRepositoryPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetRepositoryPolicyError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRepositoryPolicyError::InvalidParameter,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(GetRepositoryPolicyError::RepositoryNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRepositoryPolicyError::RepositoryNotFound,
+                    )
                 }
                 "RepositoryPolicyNotFoundException" => {
-                    return RusotoError::Service(
-                        GetRepositoryPolicyError::RepositoryPolicyNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRepositoryPolicyError::RepositoryPolicyNotFound,
                     )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(GetRepositoryPolicyError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRepositoryPolicyError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2517,22 +2835,38 @@ impl InitiateLayerUploadError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(InitiateLayerUploadError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        InitiateLayerUploadError::InvalidParameter,
+                    )
                 }
                 "KmsException" => {
-                    return RusotoError::Service(InitiateLayerUploadError::Kms(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InitiateLayerUploadError::Kms,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(InitiateLayerUploadError::RepositoryNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        InitiateLayerUploadError::RepositoryNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(InitiateLayerUploadError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InitiateLayerUploadError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2567,13 +2901,30 @@ impl ListImagesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListImagesError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListImagesError::InvalidParameter,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(ListImagesError::RepositoryNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListImagesError::RepositoryNotFound,
+                    )
+                }
+                "ServerException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListImagesError::Server,
+                    )
                 }
-                "ServerException" => return RusotoError::Service(ListImagesError::Server(err.msg)),
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2607,19 +2958,30 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::InvalidParameter,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::RepositoryNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::RepositoryNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2667,32 +3029,81 @@ impl PutImageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ImageAlreadyExistsException" => {
-                    return RusotoError::Service(PutImageError::ImageAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutImageError::ImageAlreadyExists,
+                    )
                 }
                 "ImageDigestDoesNotMatchException" => {
-                    return RusotoError::Service(PutImageError::ImageDigestDoesNotMatch(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutImageError::ImageDigestDoesNotMatch,
+                    )
                 }
                 "ImageTagAlreadyExistsException" => {
-                    return RusotoError::Service(PutImageError::ImageTagAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutImageError::ImageTagAlreadyExists,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutImageError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutImageError::InvalidParameter,
+                    )
+                }
+                "KmsException" => {
+                    return RusotoError::service_error(&res, &err.typ, err.msg, PutImageError::Kms)
                 }
-                "KmsException" => return RusotoError::Service(PutImageError::Kms(err.msg)),
                 "LayersNotFoundException" => {
-                    return RusotoError::Service(PutImageError::LayersNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutImageError::LayersNotFound,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutImageError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutImageError::LimitExceeded,
+                    )
                 }
                 "ReferencedImagesNotFoundException" => {
-                    return RusotoError::Service(PutImageError::ReferencedImagesNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutImageError::ReferencedImagesNotFound,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(PutImageError::RepositoryNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutImageError::RepositoryNotFound,
+                    )
                 }
-                "ServerException" => return RusotoError::Service(PutImageError::Server(err.msg)),
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ServerException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutImageError::Server,
+                    )
+                }
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2735,21 +3146,30 @@ impl PutImageScanningConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        PutImageScanningConfigurationError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutImageScanningConfigurationError::InvalidParameter,
                     )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(
-                        PutImageScanningConfigurationError::RepositoryNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutImageScanningConfigurationError::RepositoryNotFound,
                     )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(PutImageScanningConfigurationError::Server(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutImageScanningConfigurationError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2787,19 +3207,30 @@ impl PutImageTagMutabilityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutImageTagMutabilityError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutImageTagMutabilityError::InvalidParameter,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(PutImageTagMutabilityError::RepositoryNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutImageTagMutabilityError::RepositoryNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(PutImageTagMutabilityError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutImageTagMutabilityError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2833,17 +3264,30 @@ impl PutLifecyclePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutLifecyclePolicyError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutLifecyclePolicyError::InvalidParameter,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(PutLifecyclePolicyError::RepositoryNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutLifecyclePolicyError::RepositoryNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(PutLifecyclePolicyError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutLifecyclePolicyError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2875,12 +3319,22 @@ impl PutRegistryPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutRegistryPolicyError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRegistryPolicyError::InvalidParameter,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(PutRegistryPolicyError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRegistryPolicyError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2913,14 +3367,22 @@ impl PutReplicationConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        PutReplicationConfigurationError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutReplicationConfigurationError::InvalidParameter,
                     )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(PutReplicationConfigurationError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutReplicationConfigurationError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2953,19 +3415,30 @@ impl SetRepositoryPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SetRepositoryPolicyError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetRepositoryPolicyError::InvalidParameter,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(SetRepositoryPolicyError::RepositoryNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetRepositoryPolicyError::RepositoryNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(SetRepositoryPolicyError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetRepositoryPolicyError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3005,24 +3478,54 @@ impl StartImageScanError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ImageNotFoundException" => {
-                    return RusotoError::Service(StartImageScanError::ImageNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartImageScanError::ImageNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartImageScanError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartImageScanError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartImageScanError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartImageScanError::LimitExceeded,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(StartImageScanError::RepositoryNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartImageScanError::RepositoryNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(StartImageScanError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartImageScanError::Server,
+                    )
                 }
                 "UnsupportedImageTypeException" => {
-                    return RusotoError::Service(StartImageScanError::UnsupportedImageType(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartImageScanError::UnsupportedImageType,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3065,29 +3568,46 @@ impl StartLifecyclePolicyPreviewError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        StartLifecyclePolicyPreviewError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartLifecyclePolicyPreviewError::InvalidParameter,
                     )
                 }
                 "LifecyclePolicyNotFoundException" => {
-                    return RusotoError::Service(
-                        StartLifecyclePolicyPreviewError::LifecyclePolicyNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartLifecyclePolicyPreviewError::LifecyclePolicyNotFound,
                     )
                 }
                 "LifecyclePolicyPreviewInProgressException" => {
-                    return RusotoError::Service(
-                        StartLifecyclePolicyPreviewError::LifecyclePolicyPreviewInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartLifecyclePolicyPreviewError::LifecyclePolicyPreviewInProgress,
                     )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(
-                        StartLifecyclePolicyPreviewError::RepositoryNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartLifecyclePolicyPreviewError::RepositoryNotFound,
                     )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(StartLifecyclePolicyPreviewError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartLifecyclePolicyPreviewError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3133,21 +3653,46 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidParameter,
+                    )
                 }
                 "InvalidTagParameterException" => {
-                    return RusotoError::Service(TagResourceError::InvalidTagParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidTagParameter,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::RepositoryNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::RepositoryNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(TagResourceError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::Server,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3187,21 +3732,46 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidParameter,
+                    )
                 }
                 "InvalidTagParameterException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidTagParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidTagParameter,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::RepositoryNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::RepositoryNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UntagResourceError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::Server,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3245,25 +3815,62 @@ impl UploadLayerPartError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidLayerPartException" => {
-                    return RusotoError::Service(UploadLayerPartError::InvalidLayerPart(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UploadLayerPartError::InvalidLayerPart,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UploadLayerPartError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UploadLayerPartError::InvalidParameter,
+                    )
+                }
+                "KmsException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UploadLayerPartError::Kms,
+                    )
                 }
-                "KmsException" => return RusotoError::Service(UploadLayerPartError::Kms(err.msg)),
                 "LimitExceededException" => {
-                    return RusotoError::Service(UploadLayerPartError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UploadLayerPartError::LimitExceeded,
+                    )
                 }
                 "RepositoryNotFoundException" => {
-                    return RusotoError::Service(UploadLayerPartError::RepositoryNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UploadLayerPartError::RepositoryNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UploadLayerPartError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UploadLayerPartError::Server,
+                    )
                 }
                 "UploadNotFoundException" => {
-                    return RusotoError::Service(UploadLayerPartError::UploadNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UploadLayerPartError::UploadNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3502,11 +4109,12 @@ pub struct EcrClient {
 impl EcrClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> EcrClient {
         EcrClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "ECR"),
         }
     }
 
@@ -3521,12 +4129,32 @@ impl EcrClient {
     {
         EcrClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "ECR"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> EcrClient {
-        EcrClient { client, region }
+        EcrClient {
+            client,
+            region: region_for_service(region, "ECR"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> EcrClient {
+        EcrClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("ECR"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> EcrClient {
+        EcrClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -3539,6 +4167,7 @@ impl Ecr for EcrClient {
     ) -> Result<BatchCheckLayerAvailabilityResponse, RusotoError<BatchCheckLayerAvailabilityError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchCheckLayerAvailability");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.BatchCheckLayerAvailability",
@@ -3561,6 +4190,7 @@ impl Ecr for EcrClient {
         input: BatchDeleteImageRequest,
     ) -> Result<BatchDeleteImageResponse, RusotoError<BatchDeleteImageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchDeleteImage");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.BatchDeleteImage",
@@ -3582,6 +4212,7 @@ impl Ecr for EcrClient {
         input: BatchGetImageRequest,
     ) -> Result<BatchGetImageResponse, RusotoError<BatchGetImageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchGetImage");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.BatchGetImage",
@@ -3603,6 +4234,7 @@ impl Ecr for EcrClient {
         input: CompleteLayerUploadRequest,
     ) -> Result<CompleteLayerUploadResponse, RusotoError<CompleteLayerUploadError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CompleteLayerUpload");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.CompleteLayerUpload",
@@ -3624,6 +4256,7 @@ impl Ecr for EcrClient {
         input: CreateRepositoryRequest,
     ) -> Result<CreateRepositoryResponse, RusotoError<CreateRepositoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateRepository");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.CreateRepository",
@@ -3645,6 +4278,7 @@ impl Ecr for EcrClient {
         input: DeleteLifecyclePolicyRequest,
     ) -> Result<DeleteLifecyclePolicyResponse, RusotoError<DeleteLifecyclePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteLifecyclePolicy");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.DeleteLifecyclePolicy",
@@ -3666,6 +4300,7 @@ impl Ecr for EcrClient {
         &self,
     ) -> Result<DeleteRegistryPolicyResponse, RusotoError<DeleteRegistryPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteRegistryPolicy");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.DeleteRegistryPolicy",
@@ -3687,6 +4322,7 @@ impl Ecr for EcrClient {
         input: DeleteRepositoryRequest,
     ) -> Result<DeleteRepositoryResponse, RusotoError<DeleteRepositoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteRepository");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.DeleteRepository",
@@ -3708,6 +4344,7 @@ impl Ecr for EcrClient {
         input: DeleteRepositoryPolicyRequest,
     ) -> Result<DeleteRepositoryPolicyResponse, RusotoError<DeleteRepositoryPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteRepositoryPolicy");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.DeleteRepositoryPolicy",
@@ -3731,6 +4368,7 @@ impl Ecr for EcrClient {
     ) -> Result<DescribeImageScanFindingsResponse, RusotoError<DescribeImageScanFindingsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeImageScanFindings");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.DescribeImageScanFindings",
@@ -3753,6 +4391,7 @@ impl Ecr for EcrClient {
         input: DescribeImagesRequest,
     ) -> Result<DescribeImagesResponse, RusotoError<DescribeImagesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeImages");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.DescribeImages",
@@ -3773,6 +4412,7 @@ impl Ecr for EcrClient {
         &self,
     ) -> Result<DescribeRegistryResponse, RusotoError<DescribeRegistryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeRegistry");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.DescribeRegistry",
@@ -3793,6 +4433,7 @@ impl Ecr for EcrClient {
         input: DescribeRepositoriesRequest,
     ) -> Result<DescribeRepositoriesResponse, RusotoError<DescribeRepositoriesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeRepositories");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.DescribeRepositories",
@@ -3815,6 +4456,7 @@ impl Ecr for EcrClient {
         input: GetAuthorizationTokenRequest,
     ) -> Result<GetAuthorizationTokenResponse, RusotoError<GetAuthorizationTokenError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetAuthorizationToken");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.GetAuthorizationToken",
@@ -3837,6 +4479,7 @@ impl Ecr for EcrClient {
         input: GetDownloadUrlForLayerRequest,
     ) -> Result<GetDownloadUrlForLayerResponse, RusotoError<GetDownloadUrlForLayerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDownloadUrlForLayer");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.GetDownloadUrlForLayer",
@@ -3859,6 +4502,7 @@ impl Ecr for EcrClient {
         input: GetLifecyclePolicyRequest,
     ) -> Result<GetLifecyclePolicyResponse, RusotoError<GetLifecyclePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetLifecyclePolicy");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.GetLifecyclePolicy",
@@ -3881,6 +4525,7 @@ impl Ecr for EcrClient {
     ) -> Result<GetLifecyclePolicyPreviewResponse, RusotoError<GetLifecyclePolicyPreviewError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetLifecyclePolicyPreview");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.GetLifecyclePolicyPreview",
@@ -3902,6 +4547,7 @@ impl Ecr for EcrClient {
         &self,
     ) -> Result<GetRegistryPolicyResponse, RusotoError<GetRegistryPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRegistryPolicy");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.GetRegistryPolicy",
@@ -3922,6 +4568,7 @@ impl Ecr for EcrClient {
         input: GetRepositoryPolicyRequest,
     ) -> Result<GetRepositoryPolicyResponse, RusotoError<GetRepositoryPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRepositoryPolicy");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.GetRepositoryPolicy",
@@ -3943,6 +4590,7 @@ impl Ecr for EcrClient {
         input: InitiateLayerUploadRequest,
     ) -> Result<InitiateLayerUploadResponse, RusotoError<InitiateLayerUploadError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("InitiateLayerUpload");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.InitiateLayerUpload",
@@ -3964,6 +4612,7 @@ impl Ecr for EcrClient {
         input: ListImagesRequest,
     ) -> Result<ListImagesResponse, RusotoError<ListImagesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListImages");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.ListImages",
@@ -3985,6 +4634,7 @@ impl Ecr for EcrClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.ListTagsForResource",
@@ -4006,6 +4656,7 @@ impl Ecr for EcrClient {
         input: PutImageRequest,
     ) -> Result<PutImageResponse, RusotoError<PutImageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutImage");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.PutImage",
@@ -4030,6 +4681,7 @@ impl Ecr for EcrClient {
         RusotoError<PutImageScanningConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutImageScanningConfiguration");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.PutImageScanningConfiguration",
@@ -4052,6 +4704,7 @@ impl Ecr for EcrClient {
         input: PutImageTagMutabilityRequest,
     ) -> Result<PutImageTagMutabilityResponse, RusotoError<PutImageTagMutabilityError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutImageTagMutability");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.PutImageTagMutability",
@@ -4074,6 +4727,7 @@ impl Ecr for EcrClient {
         input: PutLifecyclePolicyRequest,
     ) -> Result<PutLifecyclePolicyResponse, RusotoError<PutLifecyclePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutLifecyclePolicy");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.PutLifecyclePolicy",
@@ -4095,6 +4749,7 @@ impl Ecr for EcrClient {
         input: PutRegistryPolicyRequest,
     ) -> Result<PutRegistryPolicyResponse, RusotoError<PutRegistryPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutRegistryPolicy");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.PutRegistryPolicy",
@@ -4117,6 +4772,7 @@ impl Ecr for EcrClient {
     ) -> Result<PutReplicationConfigurationResponse, RusotoError<PutReplicationConfigurationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutReplicationConfiguration");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.PutReplicationConfiguration",
@@ -4139,6 +4795,7 @@ impl Ecr for EcrClient {
         input: SetRepositoryPolicyRequest,
     ) -> Result<SetRepositoryPolicyResponse, RusotoError<SetRepositoryPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SetRepositoryPolicy");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.SetRepositoryPolicy",
@@ -4160,6 +4817,7 @@ impl Ecr for EcrClient {
         input: StartImageScanRequest,
     ) -> Result<StartImageScanResponse, RusotoError<StartImageScanError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartImageScan");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.StartImageScan",
@@ -4182,6 +4840,7 @@ impl Ecr for EcrClient {
     ) -> Result<StartLifecyclePolicyPreviewResponse, RusotoError<StartLifecyclePolicyPreviewError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartLifecyclePolicyPreview");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.StartLifecyclePolicyPreview",
@@ -4204,6 +4863,7 @@ impl Ecr for EcrClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.TagResource",
@@ -4225,6 +4885,7 @@ impl Ecr for EcrClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.UntagResource",
@@ -4246,6 +4907,7 @@ impl Ecr for EcrClient {
         input: UploadLayerPartRequest,
     ) -> Result<UploadLayerPartResponse, RusotoError<UploadLayerPartError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UploadLayerPart");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerRegistry_V20150921.UploadLayerPart",
@@ -4261,3 +4923,375 @@ impl Ecr for EcrClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UploadLayerPartResponse, _>()
     }
 }
+
+/// A blocking client for the Amazon ECR API, running the calls of a `EcrClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `EcrClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct EcrBlockingClient {
+    client: EcrClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl EcrBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> EcrBlockingClient {
+        EcrBlockingClient::from_client(EcrClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> EcrBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        EcrBlockingClient::from_client(EcrClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> EcrBlockingClient {
+        EcrBlockingClient::from_client(EcrClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> EcrBlockingClient {
+        EcrBlockingClient::from_client(EcrClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: EcrClient) -> EcrBlockingClient {
+        EcrBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> EcrBlockingClient {
+        EcrBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> EcrBlockingClient {
+        EcrBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Ecr::batch_check_layer_availability`].
+    pub fn batch_check_layer_availability(
+        &self,
+        input: BatchCheckLayerAvailabilityRequest,
+    ) -> Result<BatchCheckLayerAvailabilityResponse, RusotoError<BatchCheckLayerAvailabilityError>>
+    {
+        self.runtime
+            .block_on(self.client.batch_check_layer_availability(input))
+    }
+
+    /// Blocking version of [`Ecr::batch_delete_image`].
+    pub fn batch_delete_image(
+        &self,
+        input: BatchDeleteImageRequest,
+    ) -> Result<BatchDeleteImageResponse, RusotoError<BatchDeleteImageError>> {
+        self.runtime.block_on(self.client.batch_delete_image(input))
+    }
+
+    /// Blocking version of [`Ecr::batch_get_image`].
+    pub fn batch_get_image(
+        &self,
+        input: BatchGetImageRequest,
+    ) -> Result<BatchGetImageResponse, RusotoError<BatchGetImageError>> {
+        self.runtime.block_on(self.client.batch_get_image(input))
+    }
+
+    /// Blocking version of [`Ecr::complete_layer_upload`].
+    pub fn complete_layer_upload(
+        &self,
+        input: CompleteLayerUploadRequest,
+    ) -> Result<CompleteLayerUploadResponse, RusotoError<CompleteLayerUploadError>> {
+        self.runtime
+            .block_on(self.client.complete_layer_upload(input))
+    }
+
+    /// Blocking version of [`Ecr::create_repository`].
+    pub fn create_repository(
+        &self,
+        input: CreateRepositoryRequest,
+    ) -> Result<CreateRepositoryResponse, RusotoError<CreateRepositoryError>> {
+        self.runtime.block_on(self.client.create_repository(input))
+    }
+
+    /// Blocking version of [`Ecr::delete_lifecycle_policy`].
+    pub fn delete_lifecycle_policy(
+        &self,
+        input: DeleteLifecyclePolicyRequest,
+    ) -> Result<DeleteLifecyclePolicyResponse, RusotoError<DeleteLifecyclePolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_lifecycle_policy(input))
+    }
+
+    /// Blocking version of [`Ecr::delete_registry_policy`].
+    pub fn delete_registry_policy(
+        &self,
+    ) -> Result<DeleteRegistryPolicyResponse, RusotoError<DeleteRegistryPolicyError>> {
+        self.runtime.block_on(self.client.delete_registry_policy())
+    }
+
+    /// Blocking version of [`Ecr::delete_repository`].
+    pub fn delete_repository(
+        &self,
+        input: DeleteRepositoryRequest,
+    ) -> Result<DeleteRepositoryResponse, RusotoError<DeleteRepositoryError>> {
+        self.runtime.block_on(self.client.delete_repository(input))
+    }
+
+    /// Blocking version of [`Ecr::delete_repository_policy`].
+    pub fn delete_repository_policy(
+        &self,
+        input: DeleteRepositoryPolicyRequest,
+    ) -> Result<DeleteRepositoryPolicyResponse, RusotoError<DeleteRepositoryPolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_repository_policy(input))
+    }
+
+    /// Blocking version of [`Ecr::describe_image_scan_findings`].
+    pub fn describe_image_scan_findings(
+        &self,
+        input: DescribeImageScanFindingsRequest,
+    ) -> Result<DescribeImageScanFindingsResponse, RusotoError<DescribeImageScanFindingsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_image_scan_findings(input))
+    }
+
+    /// Blocking version of [`Ecr::describe_images`].
+    pub fn describe_images(
+        &self,
+        input: DescribeImagesRequest,
+    ) -> Result<DescribeImagesResponse, RusotoError<DescribeImagesError>> {
+        self.runtime.block_on(self.client.describe_images(input))
+    }
+
+    /// Blocking version of [`Ecr::describe_registry`].
+    pub fn describe_registry(
+        &self,
+    ) -> Result<DescribeRegistryResponse, RusotoError<DescribeRegistryError>> {
+        self.runtime.block_on(self.client.describe_registry())
+    }
+
+    /// Blocking version of [`Ecr::describe_repositories`].
+    pub fn describe_repositories(
+        &self,
+        input: DescribeRepositoriesRequest,
+    ) -> Result<DescribeRepositoriesResponse, RusotoError<DescribeRepositoriesError>> {
+        self.runtime
+            .block_on(self.client.describe_repositories(input))
+    }
+
+    /// Blocking version of [`Ecr::get_authorization_token`].
+    pub fn get_authorization_token(
+        &self,
+        input: GetAuthorizationTokenRequest,
+    ) -> Result<GetAuthorizationTokenResponse, RusotoError<GetAuthorizationTokenError>> {
+        self.runtime
+            .block_on(self.client.get_authorization_token(input))
+    }
+
+    /// Blocking version of [`Ecr::get_download_url_for_layer`].
+    pub fn get_download_url_for_layer(
+        &self,
+        input: GetDownloadUrlForLayerRequest,
+    ) -> Result<GetDownloadUrlForLayerResponse, RusotoError<GetDownloadUrlForLayerError>> {
+        self.runtime
+            .block_on(self.client.get_download_url_for_layer(input))
+    }
+
+    /// Blocking version of [`Ecr::get_lifecycle_policy`].
+    pub fn get_lifecycle_policy(
+        &self,
+        input: GetLifecyclePolicyRequest,
+    ) -> Result<GetLifecyclePolicyResponse, RusotoError<GetLifecyclePolicyError>> {
+        self.runtime
+            .block_on(self.client.get_lifecycle_policy(input))
+    }
+
+    /// Blocking version of [`Ecr::get_lifecycle_policy_preview`].
+    pub fn get_lifecycle_policy_preview(
+        &self,
+        input: GetLifecyclePolicyPreviewRequest,
+    ) -> Result<GetLifecyclePolicyPreviewResponse, RusotoError<GetLifecyclePolicyPreviewError>>
+    {
+        self.runtime
+            .block_on(self.client.get_lifecycle_policy_preview(input))
+    }
+
+    /// Blocking version of [`Ecr::get_registry_policy`].
+    pub fn get_registry_policy(
+        &self,
+    ) -> Result<GetRegistryPolicyResponse, RusotoError<GetRegistryPolicyError>> {
+        self.runtime.block_on(self.client.get_registry_policy())
+    }
+
+    /// Blocking version of [`Ecr::get_repository_policy`].
+    pub fn get_repository_policy(
+        &self,
+        input: GetRepositoryPolicyRequest,
+    ) -> Result<GetRepositoryPolicyResponse, RusotoError<GetRepositoryPolicyError>> {
+        self.runtime
+            .block_on(self.client.get_repository_policy(input))
+    }
+
+    /// Blocking version of [`Ecr::initiate_layer_upload`].
+    pub fn initiate_layer_upload(
+        &self,
+        input: InitiateLayerUploadRequest,
+    ) -> Result<InitiateLayerUploadResponse, RusotoError<InitiateLayerUploadError>> {
+        self.runtime
+            .block_on(self.client.initiate_layer_upload(input))
+    }
+
+    /// Blocking version of [`Ecr::list_images`].
+    pub fn list_images(
+        &self,
+        input: ListImagesRequest,
+    ) -> Result<ListImagesResponse, RusotoError<ListImagesError>> {
+        self.runtime.block_on(self.client.list_images(input))
+    }
+
+    /// Blocking version of [`Ecr::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`Ecr::put_image`].
+    pub fn put_image(
+        &self,
+        input: PutImageRequest,
+    ) -> Result<PutImageResponse, RusotoError<PutImageError>> {
+        self.runtime.block_on(self.client.put_image(input))
+    }
+
+    /// Blocking version of [`Ecr::put_image_scanning_configuration`].
+    pub fn put_image_scanning_configuration(
+        &self,
+        input: PutImageScanningConfigurationRequest,
+    ) -> Result<
+        PutImageScanningConfigurationResponse,
+        RusotoError<PutImageScanningConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.put_image_scanning_configuration(input))
+    }
+
+    /// Blocking version of [`Ecr::put_image_tag_mutability`].
+    pub fn put_image_tag_mutability(
+        &self,
+        input: PutImageTagMutabilityRequest,
+    ) -> Result<PutImageTagMutabilityResponse, RusotoError<PutImageTagMutabilityError>> {
+        self.runtime
+            .block_on(self.client.put_image_tag_mutability(input))
+    }
+
+    /// Blocking version of [`Ecr::put_lifecycle_policy`].
+    pub fn put_lifecycle_policy(
+        &self,
+        input: PutLifecyclePolicyRequest,
+    ) -> Result<PutLifecyclePolicyResponse, RusotoError<PutLifecyclePolicyError>> {
+        self.runtime
+            .block_on(self.client.put_lifecycle_policy(input))
+    }
+
+    /// Blocking version of [`Ecr::put_registry_policy`].
+    pub fn put_registry_policy(
+        &self,
+        input: PutRegistryPolicyRequest,
+    ) -> Result<PutRegistryPolicyResponse, RusotoError<PutRegistryPolicyError>> {
+        self.runtime
+            .block_on(self.client.put_registry_policy(input))
+    }
+
+    /// Blocking version of [`Ecr::put_replication_configuration`].
+    pub fn put_replication_configuration(
+        &self,
+        input: PutReplicationConfigurationRequest,
+    ) -> Result<PutReplicationConfigurationResponse, RusotoError<PutReplicationConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.put_replication_configuration(input))
+    }
+
+    /// Blocking version of [`Ecr::set_repository_policy`].
+    pub fn set_repository_policy(
+        &self,
+        input: SetRepositoryPolicyRequest,
+    ) -> Result<SetRepositoryPolicyResponse, RusotoError<SetRepositoryPolicyError>> {
+        self.runtime
+            .block_on(self.client.set_repository_policy(input))
+    }
+
+    /// Blocking version of [`Ecr::start_image_scan`].
+    pub fn start_image_scan(
+        &self,
+        input: StartImageScanRequest,
+    ) -> Result<StartImageScanResponse, RusotoError<StartImageScanError>> {
+        self.runtime.block_on(self.client.start_image_scan(input))
+    }
+
+    /// Blocking version of [`Ecr::start_lifecycle_policy_preview`].
+    pub fn start_lifecycle_policy_preview(
+        &self,
+        input: StartLifecyclePolicyPreviewRequest,
+    ) -> Result<StartLifecyclePolicyPreviewResponse, RusotoError<StartLifecyclePolicyPreviewError>>
+    {
+        self.runtime
+            .block_on(self.client.start_lifecycle_policy_preview(input))
+    }
+
+    /// Blocking version of [`Ecr::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`Ecr::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`Ecr::upload_layer_part`].
+    pub fn upload_layer_part(
+        &self,
+        input: UploadLayerPartRequest,
+    ) -> Result<UploadLayerPartResponse, RusotoError<UploadLayerPartError>> {
+        self.runtime.block_on(self.client.upload_layer_part(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -3636,27 +3637,46 @@ impl CreateCapacityProviderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateCapacityProviderError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCapacityProviderError::Client,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateCapacityProviderError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCapacityProviderError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateCapacityProviderError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCapacityProviderError::LimitExceeded,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(CreateCapacityProviderError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCapacityProviderError::Server,
+                    )
                 }
                 "UpdateInProgressException" => {
-                    return RusotoError::Service(CreateCapacityProviderError::UpdateInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCapacityProviderError::UpdateInProgress,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3692,15 +3712,30 @@ impl CreateClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateClusterError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateClusterError::Client,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateClusterError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateClusterError::InvalidParameter,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(CreateClusterError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateClusterError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3744,32 +3779,70 @@ impl CreateServiceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateServiceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateServiceError::AccessDenied,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(CreateServiceError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateServiceError::Client,
+                    )
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(CreateServiceError::ClusterNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateServiceError::ClusterNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateServiceError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateServiceError::InvalidParameter,
+                    )
                 }
                 "PlatformTaskDefinitionIncompatibilityException" => {
-                    return RusotoError::Service(
-                        CreateServiceError::PlatformTaskDefinitionIncompatibility(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateServiceError::PlatformTaskDefinitionIncompatibility,
                     )
                 }
                 "PlatformUnknownException" => {
-                    return RusotoError::Service(CreateServiceError::PlatformUnknown(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateServiceError::PlatformUnknown,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(CreateServiceError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateServiceError::Server,
+                    )
                 }
                 "UnsupportedFeatureException" => {
-                    return RusotoError::Service(CreateServiceError::UnsupportedFeature(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateServiceError::UnsupportedFeature,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3824,38 +3897,86 @@ impl CreateTaskSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateTaskSetError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTaskSetError::AccessDenied,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(CreateTaskSetError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTaskSetError::Client,
+                    )
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(CreateTaskSetError::ClusterNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTaskSetError::ClusterNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateTaskSetError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTaskSetError::InvalidParameter,
+                    )
                 }
                 "PlatformTaskDefinitionIncompatibilityException" => {
-                    return RusotoError::Service(
-                        CreateTaskSetError::PlatformTaskDefinitionIncompatibility(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTaskSetError::PlatformTaskDefinitionIncompatibility,
                     )
                 }
                 "PlatformUnknownException" => {
-                    return RusotoError::Service(CreateTaskSetError::PlatformUnknown(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTaskSetError::PlatformUnknown,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(CreateTaskSetError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTaskSetError::Server,
+                    )
                 }
                 "ServiceNotActiveException" => {
-                    return RusotoError::Service(CreateTaskSetError::ServiceNotActive(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTaskSetError::ServiceNotActive,
+                    )
                 }
                 "ServiceNotFoundException" => {
-                    return RusotoError::Service(CreateTaskSetError::ServiceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTaskSetError::ServiceNotFound,
+                    )
                 }
                 "UnsupportedFeatureException" => {
-                    return RusotoError::Service(CreateTaskSetError::UnsupportedFeature(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTaskSetError::UnsupportedFeature,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3898,17 +4019,30 @@ impl DeleteAccountSettingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteAccountSettingError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAccountSettingError::Client,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteAccountSettingError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteAccountSettingError::InvalidParameter,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeleteAccountSettingError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAccountSettingError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3942,15 +4076,30 @@ impl DeleteAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(DeleteAttributesError::ClusterNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAttributesError::ClusterNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteAttributesError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAttributesError::InvalidParameter,
+                    )
                 }
                 "TargetNotFoundException" => {
-                    return RusotoError::Service(DeleteAttributesError::TargetNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAttributesError::TargetNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3984,17 +4133,30 @@ impl DeleteCapacityProviderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteCapacityProviderError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCapacityProviderError::Client,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteCapacityProviderError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteCapacityProviderError::InvalidParameter,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeleteCapacityProviderError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCapacityProviderError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4038,34 +4200,70 @@ impl DeleteClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteClusterError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteClusterError::Client,
+                    )
                 }
                 "ClusterContainsContainerInstancesException" => {
-                    return RusotoError::Service(
-                        DeleteClusterError::ClusterContainsContainerInstances(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteClusterError::ClusterContainsContainerInstances,
                     )
                 }
                 "ClusterContainsServicesException" => {
-                    return RusotoError::Service(DeleteClusterError::ClusterContainsServices(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteClusterError::ClusterContainsServices,
+                    )
                 }
                 "ClusterContainsTasksException" => {
-                    return RusotoError::Service(DeleteClusterError::ClusterContainsTasks(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteClusterError::ClusterContainsTasks,
+                    )
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(DeleteClusterError::ClusterNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteClusterError::ClusterNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteClusterError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteClusterError::InvalidParameter,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeleteClusterError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteClusterError::Server,
+                    )
                 }
                 "UpdateInProgressException" => {
-                    return RusotoError::Service(DeleteClusterError::UpdateInProgress(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteClusterError::UpdateInProgress,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4110,21 +4308,46 @@ impl DeleteServiceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteServiceError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteServiceError::Client,
+                    )
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(DeleteServiceError::ClusterNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteServiceError::ClusterNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteServiceError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteServiceError::InvalidParameter,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeleteServiceError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteServiceError::Server,
+                    )
                 }
                 "ServiceNotFoundException" => {
-                    return RusotoError::Service(DeleteServiceError::ServiceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteServiceError::ServiceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4172,33 +4395,78 @@ impl DeleteTaskSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteTaskSetError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTaskSetError::AccessDenied,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(DeleteTaskSetError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTaskSetError::Client,
+                    )
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(DeleteTaskSetError::ClusterNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTaskSetError::ClusterNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteTaskSetError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTaskSetError::InvalidParameter,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeleteTaskSetError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTaskSetError::Server,
+                    )
                 }
                 "ServiceNotActiveException" => {
-                    return RusotoError::Service(DeleteTaskSetError::ServiceNotActive(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTaskSetError::ServiceNotActive,
+                    )
                 }
                 "ServiceNotFoundException" => {
-                    return RusotoError::Service(DeleteTaskSetError::ServiceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTaskSetError::ServiceNotFound,
+                    )
                 }
                 "TaskSetNotFoundException" => {
-                    return RusotoError::Service(DeleteTaskSetError::TaskSetNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTaskSetError::TaskSetNotFound,
+                    )
                 }
                 "UnsupportedFeatureException" => {
-                    return RusotoError::Service(DeleteTaskSetError::UnsupportedFeature(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTaskSetError::UnsupportedFeature,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4242,22 +4510,38 @@ impl DeregisterContainerInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeregisterContainerInstanceError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterContainerInstanceError::Client,
+                    )
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(DeregisterContainerInstanceError::ClusterNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeregisterContainerInstanceError::ClusterNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        DeregisterContainerInstanceError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterContainerInstanceError::InvalidParameter,
                     )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeregisterContainerInstanceError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterContainerInstanceError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4292,17 +4576,30 @@ impl DeregisterTaskDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeregisterTaskDefinitionError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterTaskDefinitionError::Client,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeregisterTaskDefinitionError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeregisterTaskDefinitionError::InvalidParameter,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeregisterTaskDefinitionError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterTaskDefinitionError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4336,17 +4633,30 @@ impl DescribeCapacityProvidersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeCapacityProvidersError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCapacityProvidersError::Client,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeCapacityProvidersError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeCapacityProvidersError::InvalidParameter,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeCapacityProvidersError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCapacityProvidersError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4380,15 +4690,30 @@ impl DescribeClustersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeClustersError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeClustersError::Client,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeClustersError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeClustersError::InvalidParameter,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeClustersError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeClustersError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4426,22 +4751,38 @@ impl DescribeContainerInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeContainerInstancesError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeContainerInstancesError::Client,
+                    )
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(DescribeContainerInstancesError::ClusterNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeContainerInstancesError::ClusterNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeContainerInstancesError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeContainerInstancesError::InvalidParameter,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeContainerInstancesError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeContainerInstancesError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4478,18 +4819,38 @@ impl DescribeServicesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeServicesError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeServicesError::Client,
+                    )
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(DescribeServicesError::ClusterNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeServicesError::ClusterNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeServicesError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeServicesError::InvalidParameter,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeServicesError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeServicesError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4524,17 +4885,30 @@ impl DescribeTaskDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeTaskDefinitionError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTaskDefinitionError::Client,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeTaskDefinitionError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeTaskDefinitionError::InvalidParameter,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeTaskDefinitionError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTaskDefinitionError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4578,30 +4952,70 @@ impl DescribeTaskSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeTaskSetsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTaskSetsError::AccessDenied,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(DescribeTaskSetsError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTaskSetsError::Client,
+                    )
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(DescribeTaskSetsError::ClusterNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTaskSetsError::ClusterNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeTaskSetsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTaskSetsError::InvalidParameter,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeTaskSetsError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTaskSetsError::Server,
+                    )
                 }
                 "ServiceNotActiveException" => {
-                    return RusotoError::Service(DescribeTaskSetsError::ServiceNotActive(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTaskSetsError::ServiceNotActive,
+                    )
                 }
                 "ServiceNotFoundException" => {
-                    return RusotoError::Service(DescribeTaskSetsError::ServiceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTaskSetsError::ServiceNotFound,
+                    )
                 }
                 "UnsupportedFeatureException" => {
-                    return RusotoError::Service(DescribeTaskSetsError::UnsupportedFeature(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTaskSetsError::UnsupportedFeature,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4642,18 +5056,38 @@ impl DescribeTasksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeTasksError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTasksError::Client,
+                    )
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(DescribeTasksError::ClusterNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTasksError::ClusterNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeTasksError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTasksError::InvalidParameter,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeTasksError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTasksError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4686,12 +5120,22 @@ impl DiscoverPollEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DiscoverPollEndpointError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DiscoverPollEndpointError::Client,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DiscoverPollEndpointError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DiscoverPollEndpointError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4724,17 +5168,30 @@ impl ListAccountSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListAccountSettingsError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAccountSettingsError::Client,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListAccountSettingsError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAccountSettingsError::InvalidParameter,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(ListAccountSettingsError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAccountSettingsError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4766,12 +5223,22 @@ impl ListAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(ListAttributesError::ClusterNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAttributesError::ClusterNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListAttributesError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAttributesError::InvalidParameter,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4804,15 +5271,30 @@ impl ListClustersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListClustersError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListClustersError::Client,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListClustersError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListClustersError::InvalidParameter,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(ListClustersError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListClustersError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4848,22 +5330,38 @@ impl ListContainerInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListContainerInstancesError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListContainerInstancesError::Client,
+                    )
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(ListContainerInstancesError::ClusterNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListContainerInstancesError::ClusterNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListContainerInstancesError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListContainerInstancesError::InvalidParameter,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(ListContainerInstancesError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListContainerInstancesError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4900,18 +5398,38 @@ impl ListServicesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListServicesError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListServicesError::Client,
+                    )
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(ListServicesError::ClusterNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListServicesError::ClusterNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListServicesError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListServicesError::InvalidParameter,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(ListServicesError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListServicesError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4948,20 +5466,38 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::Client,
+                    )
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ClusterNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::ClusterNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::InvalidParameter,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4998,17 +5534,30 @@ impl ListTaskDefinitionFamiliesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListTaskDefinitionFamiliesError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTaskDefinitionFamiliesError::Client,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTaskDefinitionFamiliesError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTaskDefinitionFamiliesError::InvalidParameter,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(ListTaskDefinitionFamiliesError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTaskDefinitionFamiliesError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5042,17 +5591,30 @@ impl ListTaskDefinitionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListTaskDefinitionsError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTaskDefinitionsError::Client,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTaskDefinitionsError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTaskDefinitionsError::InvalidParameter,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(ListTaskDefinitionsError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTaskDefinitionsError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5089,18 +5651,47 @@ impl ListTasksError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListTasksError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ClientException" => return RusotoError::Service(ListTasksError::Client(err.msg)),
+                "ClientException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTasksError::Client,
+                    )
+                }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(ListTasksError::ClusterNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTasksError::ClusterNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTasksError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTasksError::InvalidParameter,
+                    )
+                }
+                "ServerException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTasksError::Server,
+                    )
                 }
-                "ServerException" => return RusotoError::Service(ListTasksError::Server(err.msg)),
                 "ServiceNotFoundException" => {
-                    return RusotoError::Service(ListTasksError::ServiceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTasksError::ServiceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5136,15 +5727,30 @@ impl PutAccountSettingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(PutAccountSettingError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAccountSettingError::Client,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutAccountSettingError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAccountSettingError::InvalidParameter,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(PutAccountSettingError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAccountSettingError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5178,17 +5784,30 @@ impl PutAccountSettingDefaultError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(PutAccountSettingDefaultError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAccountSettingDefaultError::Client,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutAccountSettingDefaultError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutAccountSettingDefaultError::InvalidParameter,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(PutAccountSettingDefaultError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAccountSettingDefaultError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5224,20 +5843,38 @@ impl PutAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AttributeLimitExceededException" => {
-                    return RusotoError::Service(PutAttributesError::AttributeLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutAttributesError::AttributeLimitExceeded,
+                    )
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(PutAttributesError::ClusterNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAttributesError::ClusterNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutAttributesError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAttributesError::InvalidParameter,
+                    )
                 }
                 "TargetNotFoundException" => {
-                    return RusotoError::Service(PutAttributesError::TargetNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAttributesError::TargetNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5280,32 +5917,54 @@ impl PutClusterCapacityProvidersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(PutClusterCapacityProvidersError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutClusterCapacityProvidersError::Client,
+                    )
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(PutClusterCapacityProvidersError::ClusterNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutClusterCapacityProvidersError::ClusterNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        PutClusterCapacityProvidersError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutClusterCapacityProvidersError::InvalidParameter,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(PutClusterCapacityProvidersError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutClusterCapacityProvidersError::ResourceInUse,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(PutClusterCapacityProvidersError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutClusterCapacityProvidersError::Server,
+                    )
                 }
                 "UpdateInProgressException" => {
-                    return RusotoError::Service(
-                        PutClusterCapacityProvidersError::UpdateInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutClusterCapacityProvidersError::UpdateInProgress,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5342,17 +6001,30 @@ impl RegisterContainerInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(RegisterContainerInstanceError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterContainerInstanceError::Client,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RegisterContainerInstanceError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterContainerInstanceError::InvalidParameter,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(RegisterContainerInstanceError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterContainerInstanceError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5386,17 +6058,30 @@ impl RegisterTaskDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(RegisterTaskDefinitionError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterTaskDefinitionError::Client,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RegisterTaskDefinitionError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterTaskDefinitionError::InvalidParameter,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(RegisterTaskDefinitionError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterTaskDefinitionError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5442,29 +6127,78 @@ impl RunTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RunTaskError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RunTaskError::AccessDenied,
+                    )
+                }
+                "BlockedException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RunTaskError::Blocked,
+                    )
+                }
+                "ClientException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RunTaskError::Client,
+                    )
                 }
-                "BlockedException" => return RusotoError::Service(RunTaskError::Blocked(err.msg)),
-                "ClientException" => return RusotoError::Service(RunTaskError::Client(err.msg)),
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(RunTaskError::ClusterNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RunTaskError::ClusterNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RunTaskError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RunTaskError::InvalidParameter,
+                    )
                 }
                 "PlatformTaskDefinitionIncompatibilityException" => {
-                    return RusotoError::Service(
-                        RunTaskError::PlatformTaskDefinitionIncompatibility(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RunTaskError::PlatformTaskDefinitionIncompatibility,
                     )
                 }
                 "PlatformUnknownException" => {
-                    return RusotoError::Service(RunTaskError::PlatformUnknown(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RunTaskError::PlatformUnknown,
+                    )
+                }
+                "ServerException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RunTaskError::Server,
+                    )
                 }
-                "ServerException" => return RusotoError::Service(RunTaskError::Server(err.msg)),
                 "UnsupportedFeatureException" => {
-                    return RusotoError::Service(RunTaskError::UnsupportedFeature(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RunTaskError::UnsupportedFeature,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5507,15 +6241,39 @@ impl StartTaskError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<StartTaskError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ClientException" => return RusotoError::Service(StartTaskError::Client(err.msg)),
+                "ClientException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartTaskError::Client,
+                    )
+                }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(StartTaskError::ClusterNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartTaskError::ClusterNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartTaskError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartTaskError::InvalidParameter,
+                    )
+                }
+                "ServerException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartTaskError::Server,
+                    )
                 }
-                "ServerException" => return RusotoError::Service(StartTaskError::Server(err.msg)),
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5551,15 +6309,39 @@ impl StopTaskError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<StopTaskError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ClientException" => return RusotoError::Service(StopTaskError::Client(err.msg)),
+                "ClientException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopTaskError::Client,
+                    )
+                }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(StopTaskError::ClusterNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopTaskError::ClusterNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StopTaskError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopTaskError::InvalidParameter,
+                    )
+                }
+                "ServerException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopTaskError::Server,
+                    )
                 }
-                "ServerException" => return RusotoError::Service(StopTaskError::Server(err.msg)),
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5598,22 +6380,38 @@ impl SubmitAttachmentStateChangesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(SubmitAttachmentStateChangesError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SubmitAttachmentStateChangesError::AccessDenied,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(SubmitAttachmentStateChangesError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SubmitAttachmentStateChangesError::Client,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        SubmitAttachmentStateChangesError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SubmitAttachmentStateChangesError::InvalidParameter,
                     )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(SubmitAttachmentStateChangesError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SubmitAttachmentStateChangesError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5652,17 +6450,30 @@ impl SubmitContainerStateChangeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(SubmitContainerStateChangeError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SubmitContainerStateChangeError::AccessDenied,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(SubmitContainerStateChangeError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SubmitContainerStateChangeError::Client,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(SubmitContainerStateChangeError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SubmitContainerStateChangeError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5698,20 +6509,38 @@ impl SubmitTaskStateChangeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(SubmitTaskStateChangeError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SubmitTaskStateChangeError::AccessDenied,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(SubmitTaskStateChangeError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SubmitTaskStateChangeError::Client,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SubmitTaskStateChangeError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SubmitTaskStateChangeError::InvalidParameter,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(SubmitTaskStateChangeError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SubmitTaskStateChangeError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5750,21 +6579,46 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(TagResourceError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::Client,
+                    )
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ClusterNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ClusterNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(TagResourceError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5804,21 +6658,46 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UntagResourceError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::Client,
+                    )
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ClusterNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ClusterNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UntagResourceError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5854,17 +6733,30 @@ impl UpdateCapacityProviderError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateCapacityProviderError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCapacityProviderError::Client,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateCapacityProviderError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateCapacityProviderError::InvalidParameter,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UpdateCapacityProviderError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCapacityProviderError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5900,22 +6792,38 @@ impl UpdateClusterSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateClusterSettingsError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateClusterSettingsError::Client,
+                    )
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(UpdateClusterSettingsError::ClusterNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateClusterSettingsError::ClusterNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateClusterSettingsError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateClusterSettingsError::InvalidParameter,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UpdateClusterSettingsError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateClusterSettingsError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5958,35 +6866,62 @@ impl UpdateContainerAgentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateContainerAgentError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateContainerAgentError::Client,
+                    )
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(UpdateContainerAgentError::ClusterNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateContainerAgentError::ClusterNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateContainerAgentError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateContainerAgentError::InvalidParameter,
+                    )
                 }
                 "MissingVersionException" => {
-                    return RusotoError::Service(UpdateContainerAgentError::MissingVersion(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateContainerAgentError::MissingVersion,
+                    )
                 }
                 "NoUpdateAvailableException" => {
-                    return RusotoError::Service(UpdateContainerAgentError::NoUpdateAvailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateContainerAgentError::NoUpdateAvailable,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UpdateContainerAgentError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateContainerAgentError::Server,
+                    )
                 }
                 "UpdateInProgressException" => {
-                    return RusotoError::Service(UpdateContainerAgentError::UpdateInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateContainerAgentError::UpdateInProgress,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6028,26 +6963,38 @@ impl UpdateContainerInstancesStateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateContainerInstancesStateError::Client(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateContainerInstancesStateError::Client,
+                    )
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateContainerInstancesStateError::ClusterNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateContainerInstancesStateError::ClusterNotFound,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        UpdateContainerInstancesStateError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateContainerInstancesStateError::InvalidParameter,
                     )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UpdateContainerInstancesStateError::Server(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateContainerInstancesStateError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6098,35 +7045,78 @@ impl UpdateServiceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateServiceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateServiceError::AccessDenied,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(UpdateServiceError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateServiceError::Client,
+                    )
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(UpdateServiceError::ClusterNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateServiceError::ClusterNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateServiceError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateServiceError::InvalidParameter,
+                    )
                 }
                 "PlatformTaskDefinitionIncompatibilityException" => {
-                    return RusotoError::Service(
-                        UpdateServiceError::PlatformTaskDefinitionIncompatibility(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateServiceError::PlatformTaskDefinitionIncompatibility,
                     )
                 }
                 "PlatformUnknownException" => {
-                    return RusotoError::Service(UpdateServiceError::PlatformUnknown(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateServiceError::PlatformUnknown,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UpdateServiceError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateServiceError::Server,
+                    )
                 }
                 "ServiceNotActiveException" => {
-                    return RusotoError::Service(UpdateServiceError::ServiceNotActive(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateServiceError::ServiceNotActive,
+                    )
                 }
                 "ServiceNotFoundException" => {
-                    return RusotoError::Service(UpdateServiceError::ServiceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateServiceError::ServiceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6182,47 +7172,78 @@ impl UpdateServicePrimaryTaskSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateServicePrimaryTaskSetError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateServicePrimaryTaskSetError::AccessDenied,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(UpdateServicePrimaryTaskSetError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateServicePrimaryTaskSetError::Client,
+                    )
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(UpdateServicePrimaryTaskSetError::ClusterNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateServicePrimaryTaskSetError::ClusterNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        UpdateServicePrimaryTaskSetError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateServicePrimaryTaskSetError::InvalidParameter,
                     )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UpdateServicePrimaryTaskSetError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateServicePrimaryTaskSetError::Server,
+                    )
                 }
                 "ServiceNotActiveException" => {
-                    return RusotoError::Service(
-                        UpdateServicePrimaryTaskSetError::ServiceNotActive(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateServicePrimaryTaskSetError::ServiceNotActive,
                     )
                 }
                 "ServiceNotFoundException" => {
-                    return RusotoError::Service(UpdateServicePrimaryTaskSetError::ServiceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateServicePrimaryTaskSetError::ServiceNotFound,
+                    )
                 }
                 "TaskSetNotFoundException" => {
-                    return RusotoError::Service(UpdateServicePrimaryTaskSetError::TaskSetNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateServicePrimaryTaskSetError::TaskSetNotFound,
+                    )
                 }
                 "UnsupportedFeatureException" => {
-                    return RusotoError::Service(
-                        UpdateServicePrimaryTaskSetError::UnsupportedFeature(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateServicePrimaryTaskSetError::UnsupportedFeature,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6276,33 +7297,78 @@ impl UpdateTaskSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateTaskSetError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTaskSetError::AccessDenied,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(UpdateTaskSetError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTaskSetError::Client,
+                    )
                 }
                 "ClusterNotFoundException" => {
-                    return RusotoError::Service(UpdateTaskSetError::ClusterNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTaskSetError::ClusterNotFound,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateTaskSetError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTaskSetError::InvalidParameter,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UpdateTaskSetError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTaskSetError::Server,
+                    )
                 }
                 "ServiceNotActiveException" => {
-                    return RusotoError::Service(UpdateTaskSetError::ServiceNotActive(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTaskSetError::ServiceNotActive,
+                    )
                 }
                 "ServiceNotFoundException" => {
-                    return RusotoError::Service(UpdateTaskSetError::ServiceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTaskSetError::ServiceNotFound,
+                    )
                 }
                 "TaskSetNotFoundException" => {
-                    return RusotoError::Service(UpdateTaskSetError::TaskSetNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTaskSetError::TaskSetNotFound,
+                    )
                 }
                 "UnsupportedFeatureException" => {
-                    return RusotoError::Service(UpdateTaskSetError::UnsupportedFeature(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTaskSetError::UnsupportedFeature,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6642,11 +7708,12 @@ pub struct EcsClient {
 impl EcsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> EcsClient {
         EcsClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "ECS"),
         }
     }
 
@@ -6661,12 +7728,32 @@ impl EcsClient {
     {
         EcsClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "ECS"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> EcsClient {
-        EcsClient { client, region }
+        EcsClient {
+            client,
+            region: region_for_service(region, "ECS"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> EcsClient {
+        EcsClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("ECS"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> EcsClient {
+        EcsClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -6678,6 +7765,7 @@ impl Ecs for EcsClient {
         input: CreateCapacityProviderRequest,
     ) -> Result<CreateCapacityProviderResponse, RusotoError<CreateCapacityProviderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateCapacityProvider");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.CreateCapacityProvider",
@@ -6700,6 +7788,7 @@ impl Ecs for EcsClient {
         input: CreateClusterRequest,
     ) -> Result<CreateClusterResponse, RusotoError<CreateClusterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateCluster");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.CreateCluster",
@@ -6721,6 +7810,7 @@ impl Ecs for EcsClient {
         input: CreateServiceRequest,
     ) -> Result<CreateServiceResponse, RusotoError<CreateServiceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateService");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.CreateService",
@@ -6742,6 +7832,7 @@ impl Ecs for EcsClient {
         input: CreateTaskSetRequest,
     ) -> Result<CreateTaskSetResponse, RusotoError<CreateTaskSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateTaskSet");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.CreateTaskSet",
@@ -6763,6 +7854,7 @@ impl Ecs for EcsClient {
         input: DeleteAccountSettingRequest,
     ) -> Result<DeleteAccountSettingResponse, RusotoError<DeleteAccountSettingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteAccountSetting");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.DeleteAccountSetting",
@@ -6785,6 +7877,7 @@ impl Ecs for EcsClient {
         input: DeleteAttributesRequest,
     ) -> Result<DeleteAttributesResponse, RusotoError<DeleteAttributesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteAttributes");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.DeleteAttributes",
@@ -6806,6 +7899,7 @@ impl Ecs for EcsClient {
         input: DeleteCapacityProviderRequest,
     ) -> Result<DeleteCapacityProviderResponse, RusotoError<DeleteCapacityProviderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteCapacityProvider");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.DeleteCapacityProvider",
@@ -6828,6 +7922,7 @@ impl Ecs for EcsClient {
         input: DeleteClusterRequest,
     ) -> Result<DeleteClusterResponse, RusotoError<DeleteClusterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteCluster");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.DeleteCluster",
@@ -6849,6 +7944,7 @@ impl Ecs for EcsClient {
         input: DeleteServiceRequest,
     ) -> Result<DeleteServiceResponse, RusotoError<DeleteServiceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteService");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.DeleteService",
@@ -6870,6 +7966,7 @@ impl Ecs for EcsClient {
         input: DeleteTaskSetRequest,
     ) -> Result<DeleteTaskSetResponse, RusotoError<DeleteTaskSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteTaskSet");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.DeleteTaskSet",
@@ -6892,6 +7989,7 @@ impl Ecs for EcsClient {
     ) -> Result<DeregisterContainerInstanceResponse, RusotoError<DeregisterContainerInstanceError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeregisterContainerInstance");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.DeregisterContainerInstance",
@@ -6914,6 +8012,7 @@ impl Ecs for EcsClient {
         input: DeregisterTaskDefinitionRequest,
     ) -> Result<DeregisterTaskDefinitionResponse, RusotoError<DeregisterTaskDefinitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeregisterTaskDefinition");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.DeregisterTaskDefinition",
@@ -6937,6 +8036,7 @@ impl Ecs for EcsClient {
     ) -> Result<DescribeCapacityProvidersResponse, RusotoError<DescribeCapacityProvidersError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeCapacityProviders");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.DescribeCapacityProviders",
@@ -6959,6 +8059,7 @@ impl Ecs for EcsClient {
         input: DescribeClustersRequest,
     ) -> Result<DescribeClustersResponse, RusotoError<DescribeClustersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeClusters");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.DescribeClusters",
@@ -6981,6 +8082,7 @@ impl Ecs for EcsClient {
     ) -> Result<DescribeContainerInstancesResponse, RusotoError<DescribeContainerInstancesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeContainerInstances");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.DescribeContainerInstances",
@@ -7003,6 +8105,7 @@ impl Ecs for EcsClient {
         input: DescribeServicesRequest,
     ) -> Result<DescribeServicesResponse, RusotoError<DescribeServicesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeServices");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.DescribeServices",
@@ -7024,6 +8127,7 @@ impl Ecs for EcsClient {
         input: DescribeTaskDefinitionRequest,
     ) -> Result<DescribeTaskDefinitionResponse, RusotoError<DescribeTaskDefinitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeTaskDefinition");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.DescribeTaskDefinition",
@@ -7046,6 +8150,7 @@ impl Ecs for EcsClient {
         input: DescribeTaskSetsRequest,
     ) -> Result<DescribeTaskSetsResponse, RusotoError<DescribeTaskSetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeTaskSets");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.DescribeTaskSets",
@@ -7067,6 +8172,7 @@ impl Ecs for EcsClient {
         input: DescribeTasksRequest,
     ) -> Result<DescribeTasksResponse, RusotoError<DescribeTasksError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeTasks");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.DescribeTasks",
@@ -7088,6 +8194,7 @@ impl Ecs for EcsClient {
         input: DiscoverPollEndpointRequest,
     ) -> Result<DiscoverPollEndpointResponse, RusotoError<DiscoverPollEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DiscoverPollEndpoint");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.DiscoverPollEndpoint",
@@ -7110,6 +8217,7 @@ impl Ecs for EcsClient {
         input: ListAccountSettingsRequest,
     ) -> Result<ListAccountSettingsResponse, RusotoError<ListAccountSettingsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListAccountSettings");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.ListAccountSettings",
@@ -7131,6 +8239,7 @@ impl Ecs for EcsClient {
         input: ListAttributesRequest,
     ) -> Result<ListAttributesResponse, RusotoError<ListAttributesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListAttributes");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.ListAttributes",
@@ -7152,6 +8261,7 @@ impl Ecs for EcsClient {
         input: ListClustersRequest,
     ) -> Result<ListClustersResponse, RusotoError<ListClustersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListClusters");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.ListClusters",
@@ -7173,6 +8283,7 @@ impl Ecs for EcsClient {
         input: ListContainerInstancesRequest,
     ) -> Result<ListContainerInstancesResponse, RusotoError<ListContainerInstancesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListContainerInstances");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.ListContainerInstances",
@@ -7195,6 +8306,7 @@ impl Ecs for EcsClient {
         input: ListServicesRequest,
     ) -> Result<ListServicesResponse, RusotoError<ListServicesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListServices");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.ListServices",
@@ -7216,6 +8328,7 @@ impl Ecs for EcsClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.ListTagsForResource",
@@ -7238,6 +8351,7 @@ impl Ecs for EcsClient {
     ) -> Result<ListTaskDefinitionFamiliesResponse, RusotoError<ListTaskDefinitionFamiliesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTaskDefinitionFamilies");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.ListTaskDefinitionFamilies",
@@ -7260,6 +8374,7 @@ impl Ecs for EcsClient {
         input: ListTaskDefinitionsRequest,
     ) -> Result<ListTaskDefinitionsResponse, RusotoError<ListTaskDefinitionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTaskDefinitions");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.ListTaskDefinitions",
@@ -7281,6 +8396,7 @@ impl Ecs for EcsClient {
         input: ListTasksRequest,
     ) -> Result<ListTasksResponse, RusotoError<ListTasksError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTasks");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.ListTasks",
@@ -7302,6 +8418,7 @@ impl Ecs for EcsClient {
         input: PutAccountSettingRequest,
     ) -> Result<PutAccountSettingResponse, RusotoError<PutAccountSettingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutAccountSetting");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.PutAccountSetting",
@@ -7323,6 +8440,7 @@ impl Ecs for EcsClient {
         input: PutAccountSettingDefaultRequest,
     ) -> Result<PutAccountSettingDefaultResponse, RusotoError<PutAccountSettingDefaultError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutAccountSettingDefault");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.PutAccountSettingDefault",
@@ -7345,6 +8463,7 @@ impl Ecs for EcsClient {
         input: PutAttributesRequest,
     ) -> Result<PutAttributesResponse, RusotoError<PutAttributesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutAttributes");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.PutAttributes",
@@ -7367,6 +8486,7 @@ impl Ecs for EcsClient {
     ) -> Result<PutClusterCapacityProvidersResponse, RusotoError<PutClusterCapacityProvidersError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutClusterCapacityProviders");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.PutClusterCapacityProviders",
@@ -7390,6 +8510,7 @@ impl Ecs for EcsClient {
     ) -> Result<RegisterContainerInstanceResponse, RusotoError<RegisterContainerInstanceError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RegisterContainerInstance");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.RegisterContainerInstance",
@@ -7412,6 +8533,7 @@ impl Ecs for EcsClient {
         input: RegisterTaskDefinitionRequest,
     ) -> Result<RegisterTaskDefinitionResponse, RusotoError<RegisterTaskDefinitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RegisterTaskDefinition");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.RegisterTaskDefinition",
@@ -7434,6 +8556,7 @@ impl Ecs for EcsClient {
         input: RunTaskRequest,
     ) -> Result<RunTaskResponse, RusotoError<RunTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RunTask");
         request.add_header("x-amz-target", "AmazonEC2ContainerServiceV20141113.RunTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7452,6 +8575,7 @@ impl Ecs for EcsClient {
         input: StartTaskRequest,
     ) -> Result<StartTaskResponse, RusotoError<StartTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartTask");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.StartTask",
@@ -7473,6 +8597,7 @@ impl Ecs for EcsClient {
         input: StopTaskRequest,
     ) -> Result<StopTaskResponse, RusotoError<StopTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopTask");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.StopTask",
@@ -7495,6 +8620,7 @@ impl Ecs for EcsClient {
     ) -> Result<SubmitAttachmentStateChangesResponse, RusotoError<SubmitAttachmentStateChangesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SubmitAttachmentStateChanges");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.SubmitAttachmentStateChanges",
@@ -7518,6 +8644,7 @@ impl Ecs for EcsClient {
     ) -> Result<SubmitContainerStateChangeResponse, RusotoError<SubmitContainerStateChangeError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SubmitContainerStateChange");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.SubmitContainerStateChange",
@@ -7540,6 +8667,7 @@ impl Ecs for EcsClient {
         input: SubmitTaskStateChangeRequest,
     ) -> Result<SubmitTaskStateChangeResponse, RusotoError<SubmitTaskStateChangeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SubmitTaskStateChange");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.SubmitTaskStateChange",
@@ -7562,6 +8690,7 @@ impl Ecs for EcsClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.TagResource",
@@ -7583,6 +8712,7 @@ impl Ecs for EcsClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.UntagResource",
@@ -7604,6 +8734,7 @@ impl Ecs for EcsClient {
         input: UpdateCapacityProviderRequest,
     ) -> Result<UpdateCapacityProviderResponse, RusotoError<UpdateCapacityProviderError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateCapacityProvider");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.UpdateCapacityProvider",
@@ -7626,6 +8757,7 @@ impl Ecs for EcsClient {
         input: UpdateClusterSettingsRequest,
     ) -> Result<UpdateClusterSettingsResponse, RusotoError<UpdateClusterSettingsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateClusterSettings");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.UpdateClusterSettings",
@@ -7648,6 +8780,7 @@ impl Ecs for EcsClient {
         input: UpdateContainerAgentRequest,
     ) -> Result<UpdateContainerAgentResponse, RusotoError<UpdateContainerAgentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateContainerAgent");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.UpdateContainerAgent",
@@ -7673,6 +8806,7 @@ impl Ecs for EcsClient {
         RusotoError<UpdateContainerInstancesStateError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateContainerInstancesState");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.UpdateContainerInstancesState",
@@ -7695,6 +8829,7 @@ impl Ecs for EcsClient {
         input: UpdateServiceRequest,
     ) -> Result<UpdateServiceResponse, RusotoError<UpdateServiceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateService");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.UpdateService",
@@ -7717,6 +8852,7 @@ impl Ecs for EcsClient {
     ) -> Result<UpdateServicePrimaryTaskSetResponse, RusotoError<UpdateServicePrimaryTaskSetError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateServicePrimaryTaskSet");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.UpdateServicePrimaryTaskSet",
@@ -7739,6 +8875,7 @@ impl Ecs for EcsClient {
         input: UpdateTaskSetRequest,
     ) -> Result<UpdateTaskSetResponse, RusotoError<UpdateTaskSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateTaskSet");
         request.add_header(
             "x-amz-target",
             "AmazonEC2ContainerServiceV20141113.UpdateTaskSet",
@@ -7754,3 +8891,517 @@ impl Ecs for EcsClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateTaskSetResponse, _>()
     }
 }
+
+/// A blocking client for the Amazon ECS API, running the calls of a `EcsClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `EcsClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct EcsBlockingClient {
+    client: EcsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl EcsBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> EcsBlockingClient {
+        EcsBlockingClient::from_client(EcsClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> EcsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        EcsBlockingClient::from_client(EcsClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> EcsBlockingClient {
+        EcsBlockingClient::from_client(EcsClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> EcsBlockingClient {
+        EcsBlockingClient::from_client(EcsClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: EcsClient) -> EcsBlockingClient {
+        EcsBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> EcsBlockingClient {
+        EcsBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> EcsBlockingClient {
+        EcsBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Ecs::create_capacity_provider`].
+    pub fn create_capacity_provider(
+        &self,
+        input: CreateCapacityProviderRequest,
+    ) -> Result<CreateCapacityProviderResponse, RusotoError<CreateCapacityProviderError>> {
+        self.runtime
+            .block_on(self.client.create_capacity_provider(input))
+    }
+
+    /// Blocking version of [`Ecs::create_cluster`].
+    pub fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> Result<CreateClusterResponse, RusotoError<CreateClusterError>> {
+        self.runtime.block_on(self.client.create_cluster(input))
+    }
+
+    /// Blocking version of [`Ecs::create_service`].
+    pub fn create_service(
+        &self,
+        input: CreateServiceRequest,
+    ) -> Result<CreateServiceResponse, RusotoError<CreateServiceError>> {
+        self.runtime.block_on(self.client.create_service(input))
+    }
+
+    /// Blocking version of [`Ecs::create_task_set`].
+    pub fn create_task_set(
+        &self,
+        input: CreateTaskSetRequest,
+    ) -> Result<CreateTaskSetResponse, RusotoError<CreateTaskSetError>> {
+        self.runtime.block_on(self.client.create_task_set(input))
+    }
+
+    /// Blocking version of [`Ecs::delete_account_setting`].
+    pub fn delete_account_setting(
+        &self,
+        input: DeleteAccountSettingRequest,
+    ) -> Result<DeleteAccountSettingResponse, RusotoError<DeleteAccountSettingError>> {
+        self.runtime
+            .block_on(self.client.delete_account_setting(input))
+    }
+
+    /// Blocking version of [`Ecs::delete_attributes`].
+    pub fn delete_attributes(
+        &self,
+        input: DeleteAttributesRequest,
+    ) -> Result<DeleteAttributesResponse, RusotoError<DeleteAttributesError>> {
+        self.runtime.block_on(self.client.delete_attributes(input))
+    }
+
+    /// Blocking version of [`Ecs::delete_capacity_provider`].
+    pub fn delete_capacity_provider(
+        &self,
+        input: DeleteCapacityProviderRequest,
+    ) -> Result<DeleteCapacityProviderResponse, RusotoError<DeleteCapacityProviderError>> {
+        self.runtime
+            .block_on(self.client.delete_capacity_provider(input))
+    }
+
+    /// Blocking version of [`Ecs::delete_cluster`].
+    pub fn delete_cluster(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> Result<DeleteClusterResponse, RusotoError<DeleteClusterError>> {
+        self.runtime.block_on(self.client.delete_cluster(input))
+    }
+
+    /// Blocking version of [`Ecs::delete_service`].
+    pub fn delete_service(
+        &self,
+        input: DeleteServiceRequest,
+    ) -> Result<DeleteServiceResponse, RusotoError<DeleteServiceError>> {
+        self.runtime.block_on(self.client.delete_service(input))
+    }
+
+    /// Blocking version of [`Ecs::delete_task_set`].
+    pub fn delete_task_set(
+        &self,
+        input: DeleteTaskSetRequest,
+    ) -> Result<DeleteTaskSetResponse, RusotoError<DeleteTaskSetError>> {
+        self.runtime.block_on(self.client.delete_task_set(input))
+    }
+
+    /// Blocking version of [`Ecs::deregister_container_instance`].
+    pub fn deregister_container_instance(
+        &self,
+        input: DeregisterContainerInstanceRequest,
+    ) -> Result<DeregisterContainerInstanceResponse, RusotoError<DeregisterContainerInstanceError>>
+    {
+        self.runtime
+            .block_on(self.client.deregister_container_instance(input))
+    }
+
+    /// Blocking version of [`Ecs::deregister_task_definition`].
+    pub fn deregister_task_definition(
+        &self,
+        input: DeregisterTaskDefinitionRequest,
+    ) -> Result<DeregisterTaskDefinitionResponse, RusotoError<DeregisterTaskDefinitionError>> {
+        self.runtime
+            .block_on(self.client.deregister_task_definition(input))
+    }
+
+    /// Blocking version of [`Ecs::describe_capacity_providers`].
+    pub fn describe_capacity_providers(
+        &self,
+        input: DescribeCapacityProvidersRequest,
+    ) -> Result<DescribeCapacityProvidersResponse, RusotoError<DescribeCapacityProvidersError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_capacity_providers(input))
+    }
+
+    /// Blocking version of [`Ecs::describe_clusters`].
+    pub fn describe_clusters(
+        &self,
+        input: DescribeClustersRequest,
+    ) -> Result<DescribeClustersResponse, RusotoError<DescribeClustersError>> {
+        self.runtime.block_on(self.client.describe_clusters(input))
+    }
+
+    /// Blocking version of [`Ecs::describe_container_instances`].
+    pub fn describe_container_instances(
+        &self,
+        input: DescribeContainerInstancesRequest,
+    ) -> Result<DescribeContainerInstancesResponse, RusotoError<DescribeContainerInstancesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_container_instances(input))
+    }
+
+    /// Blocking version of [`Ecs::describe_services`].
+    pub fn describe_services(
+        &self,
+        input: DescribeServicesRequest,
+    ) -> Result<DescribeServicesResponse, RusotoError<DescribeServicesError>> {
+        self.runtime.block_on(self.client.describe_services(input))
+    }
+
+    /// Blocking version of [`Ecs::describe_task_definition`].
+    pub fn describe_task_definition(
+        &self,
+        input: DescribeTaskDefinitionRequest,
+    ) -> Result<DescribeTaskDefinitionResponse, RusotoError<DescribeTaskDefinitionError>> {
+        self.runtime
+            .block_on(self.client.describe_task_definition(input))
+    }
+
+    /// Blocking version of [`Ecs::describe_task_sets`].
+    pub fn describe_task_sets(
+        &self,
+        input: DescribeTaskSetsRequest,
+    ) -> Result<DescribeTaskSetsResponse, RusotoError<DescribeTaskSetsError>> {
+        self.runtime.block_on(self.client.describe_task_sets(input))
+    }
+
+    /// Blocking version of [`Ecs::describe_tasks`].
+    pub fn describe_tasks(
+        &self,
+        input: DescribeTasksRequest,
+    ) -> Result<DescribeTasksResponse, RusotoError<DescribeTasksError>> {
+        self.runtime.block_on(self.client.describe_tasks(input))
+    }
+
+    /// Blocking version of [`Ecs::discover_poll_endpoint`].
+    pub fn discover_poll_endpoint(
+        &self,
+        input: DiscoverPollEndpointRequest,
+    ) -> Result<DiscoverPollEndpointResponse, RusotoError<DiscoverPollEndpointError>> {
+        self.runtime
+            .block_on(self.client.discover_poll_endpoint(input))
+    }
+
+    /// Blocking version of [`Ecs::list_account_settings`].
+    pub fn list_account_settings(
+        &self,
+        input: ListAccountSettingsRequest,
+    ) -> Result<ListAccountSettingsResponse, RusotoError<ListAccountSettingsError>> {
+        self.runtime
+            .block_on(self.client.list_account_settings(input))
+    }
+
+    /// Blocking version of [`Ecs::list_attributes`].
+    pub fn list_attributes(
+        &self,
+        input: ListAttributesRequest,
+    ) -> Result<ListAttributesResponse, RusotoError<ListAttributesError>> {
+        self.runtime.block_on(self.client.list_attributes(input))
+    }
+
+    /// Blocking version of [`Ecs::list_clusters`].
+    pub fn list_clusters(
+        &self,
+        input: ListClustersRequest,
+    ) -> Result<ListClustersResponse, RusotoError<ListClustersError>> {
+        self.runtime.block_on(self.client.list_clusters(input))
+    }
+
+    /// Blocking version of [`Ecs::list_container_instances`].
+    pub fn list_container_instances(
+        &self,
+        input: ListContainerInstancesRequest,
+    ) -> Result<ListContainerInstancesResponse, RusotoError<ListContainerInstancesError>> {
+        self.runtime
+            .block_on(self.client.list_container_instances(input))
+    }
+
+    /// Blocking version of [`Ecs::list_services`].
+    pub fn list_services(
+        &self,
+        input: ListServicesRequest,
+    ) -> Result<ListServicesResponse, RusotoError<ListServicesError>> {
+        self.runtime.block_on(self.client.list_services(input))
+    }
+
+    /// Blocking version of [`Ecs::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`Ecs::list_task_definition_families`].
+    pub fn list_task_definition_families(
+        &self,
+        input: ListTaskDefinitionFamiliesRequest,
+    ) -> Result<ListTaskDefinitionFamiliesResponse, RusotoError<ListTaskDefinitionFamiliesError>>
+    {
+        self.runtime
+            .block_on(self.client.list_task_definition_families(input))
+    }
+
+    /// Blocking version of [`Ecs::list_task_definitions`].
+    pub fn list_task_definitions(
+        &self,
+        input: ListTaskDefinitionsRequest,
+    ) -> Result<ListTaskDefinitionsResponse, RusotoError<ListTaskDefinitionsError>> {
+        self.runtime
+            .block_on(self.client.list_task_definitions(input))
+    }
+
+    /// Blocking version of [`Ecs::list_tasks`].
+    pub fn list_tasks(
+        &self,
+        input: ListTasksRequest,
+    ) -> Result<ListTasksResponse, RusotoError<ListTasksError>> {
+        self.runtime.block_on(self.client.list_tasks(input))
+    }
+
+    /// Blocking version of [`Ecs::put_account_setting`].
+    pub fn put_account_setting(
+        &self,
+        input: PutAccountSettingRequest,
+    ) -> Result<PutAccountSettingResponse, RusotoError<PutAccountSettingError>> {
+        self.runtime
+            .block_on(self.client.put_account_setting(input))
+    }
+
+    /// Blocking version of [`Ecs::put_account_setting_default`].
+    pub fn put_account_setting_default(
+        &self,
+        input: PutAccountSettingDefaultRequest,
+    ) -> Result<PutAccountSettingDefaultResponse, RusotoError<PutAccountSettingDefaultError>> {
+        self.runtime
+            .block_on(self.client.put_account_setting_default(input))
+    }
+
+    /// Blocking version of [`Ecs::put_attributes`].
+    pub fn put_attributes(
+        &self,
+        input: PutAttributesRequest,
+    ) -> Result<PutAttributesResponse, RusotoError<PutAttributesError>> {
+        self.runtime.block_on(self.client.put_attributes(input))
+    }
+
+    /// Blocking version of [`Ecs::put_cluster_capacity_providers`].
+    pub fn put_cluster_capacity_providers(
+        &self,
+        input: PutClusterCapacityProvidersRequest,
+    ) -> Result<PutClusterCapacityProvidersResponse, RusotoError<PutClusterCapacityProvidersError>>
+    {
+        self.runtime
+            .block_on(self.client.put_cluster_capacity_providers(input))
+    }
+
+    /// Blocking version of [`Ecs::register_container_instance`].
+    pub fn register_container_instance(
+        &self,
+        input: RegisterContainerInstanceRequest,
+    ) -> Result<RegisterContainerInstanceResponse, RusotoError<RegisterContainerInstanceError>>
+    {
+        self.runtime
+            .block_on(self.client.register_container_instance(input))
+    }
+
+    /// Blocking version of [`Ecs::register_task_definition`].
+    pub fn register_task_definition(
+        &self,
+        input: RegisterTaskDefinitionRequest,
+    ) -> Result<RegisterTaskDefinitionResponse, RusotoError<RegisterTaskDefinitionError>> {
+        self.runtime
+            .block_on(self.client.register_task_definition(input))
+    }
+
+    /// Blocking version of [`Ecs::run_task`].
+    pub fn run_task(
+        &self,
+        input: RunTaskRequest,
+    ) -> Result<RunTaskResponse, RusotoError<RunTaskError>> {
+        self.runtime.block_on(self.client.run_task(input))
+    }
+
+    /// Blocking version of [`Ecs::start_task`].
+    pub fn start_task(
+        &self,
+        input: StartTaskRequest,
+    ) -> Result<StartTaskResponse, RusotoError<StartTaskError>> {
+        self.runtime.block_on(self.client.start_task(input))
+    }
+
+    /// Blocking version of [`Ecs::stop_task`].
+    pub fn stop_task(
+        &self,
+        input: StopTaskRequest,
+    ) -> Result<StopTaskResponse, RusotoError<StopTaskError>> {
+        self.runtime.block_on(self.client.stop_task(input))
+    }
+
+    /// Blocking version of [`Ecs::submit_attachment_state_changes`].
+    pub fn submit_attachment_state_changes(
+        &self,
+        input: SubmitAttachmentStateChangesRequest,
+    ) -> Result<SubmitAttachmentStateChangesResponse, RusotoError<SubmitAttachmentStateChangesError>>
+    {
+        self.runtime
+            .block_on(self.client.submit_attachment_state_changes(input))
+    }
+
+    /// Blocking version of [`Ecs::submit_container_state_change`].
+    pub fn submit_container_state_change(
+        &self,
+        input: SubmitContainerStateChangeRequest,
+    ) -> Result<SubmitContainerStateChangeResponse, RusotoError<SubmitContainerStateChangeError>>
+    {
+        self.runtime
+            .block_on(self.client.submit_container_state_change(input))
+    }
+
+    /// Blocking version of [`Ecs::submit_task_state_change`].
+    pub fn submit_task_state_change(
+        &self,
+        input: SubmitTaskStateChangeRequest,
+    ) -> Result<SubmitTaskStateChangeResponse, RusotoError<SubmitTaskStateChangeError>> {
+        self.runtime
+            .block_on(self.client.submit_task_state_change(input))
+    }
+
+    /// Blocking version of [`Ecs::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`Ecs::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`Ecs::update_capacity_provider`].
+    pub fn update_capacity_provider(
+        &self,
+        input: UpdateCapacityProviderRequest,
+    ) -> Result<UpdateCapacityProviderResponse, RusotoError<UpdateCapacityProviderError>> {
+        self.runtime
+            .block_on(self.client.update_capacity_provider(input))
+    }
+
+    /// Blocking version of [`Ecs::update_cluster_settings`].
+    pub fn update_cluster_settings(
+        &self,
+        input: UpdateClusterSettingsRequest,
+    ) -> Result<UpdateClusterSettingsResponse, RusotoError<UpdateClusterSettingsError>> {
+        self.runtime
+            .block_on(self.client.update_cluster_settings(input))
+    }
+
+    /// Blocking version of [`Ecs::update_container_agent`].
+    pub fn update_container_agent(
+        &self,
+        input: UpdateContainerAgentRequest,
+    ) -> Result<UpdateContainerAgentResponse, RusotoError<UpdateContainerAgentError>> {
+        self.runtime
+            .block_on(self.client.update_container_agent(input))
+    }
+
+    /// Blocking version of [`Ecs::update_container_instances_state`].
+    pub fn update_container_instances_state(
+        &self,
+        input: UpdateContainerInstancesStateRequest,
+    ) -> Result<
+        UpdateContainerInstancesStateResponse,
+        RusotoError<UpdateContainerInstancesStateError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_container_instances_state(input))
+    }
+
+    /// Blocking version of [`Ecs::update_service`].
+    pub fn update_service(
+        &self,
+        input: UpdateServiceRequest,
+    ) -> Result<UpdateServiceResponse, RusotoError<UpdateServiceError>> {
+        self.runtime.block_on(self.client.update_service(input))
+    }
+
+    /// Blocking version of [`Ecs::update_service_primary_task_set`].
+    pub fn update_service_primary_task_set(
+        &self,
+        input: UpdateServicePrimaryTaskSetRequest,
+    ) -> Result<UpdateServicePrimaryTaskSetResponse, RusotoError<UpdateServicePrimaryTaskSetError>>
+    {
+        self.runtime
+            .block_on(self.client.update_service_primary_task_set(input))
+    }
+
+    /// Blocking version of [`Ecs::update_task_set`].
+    pub fn update_task_set(
+        &self,
+        input: UpdateTaskSetRequest,
+    ) -> Result<UpdateTaskSetResponse, RusotoError<UpdateTaskSetError>> {
+        self.runtime.block_on(self.client.update_task_set(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -756,34 +757,54 @@ impl CreateAccessPointError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessPointAlreadyExists" => {
-                    return RusotoError::Service(CreateAccessPointError::AccessPointAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAccessPointError::AccessPointAlreadyExists,
+                    )
                 }
                 "AccessPointLimitExceeded" => {
-                    return RusotoError::Service(CreateAccessPointError::AccessPointLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAccessPointError::AccessPointLimitExceeded,
+                    )
                 }
                 "BadRequest" => {
-                    return RusotoError::Service(CreateAccessPointError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAccessPointError::BadRequest,
+                    )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(CreateAccessPointError::FileSystemNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAccessPointError::FileSystemNotFound,
+                    )
                 }
                 "IncorrectFileSystemLifeCycleState" => {
-                    return RusotoError::Service(
-                        CreateAccessPointError::IncorrectFileSystemLifeCycleState(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAccessPointError::IncorrectFileSystemLifeCycleState,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateAccessPointError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAccessPointError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -828,34 +849,54 @@ impl CreateFileSystemError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(CreateFileSystemError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFileSystemError::BadRequest,
+                    )
                 }
                 "FileSystemAlreadyExists" => {
-                    return RusotoError::Service(CreateFileSystemError::FileSystemAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateFileSystemError::FileSystemAlreadyExists,
+                    )
                 }
                 "FileSystemLimitExceeded" => {
-                    return RusotoError::Service(CreateFileSystemError::FileSystemLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateFileSystemError::FileSystemLimitExceeded,
+                    )
                 }
                 "InsufficientThroughputCapacity" => {
-                    return RusotoError::Service(
-                        CreateFileSystemError::InsufficientThroughputCapacity(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFileSystemError::InsufficientThroughputCapacity,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateFileSystemError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateFileSystemError::InternalServerError,
+                    )
                 }
                 "ThroughputLimitExceeded" => {
-                    return RusotoError::Service(CreateFileSystemError::ThroughputLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateFileSystemError::ThroughputLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -912,60 +953,102 @@ impl CreateMountTargetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(CreateMountTargetError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMountTargetError::BadRequest,
+                    )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(CreateMountTargetError::FileSystemNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateMountTargetError::FileSystemNotFound,
+                    )
                 }
                 "IncorrectFileSystemLifeCycleState" => {
-                    return RusotoError::Service(
-                        CreateMountTargetError::IncorrectFileSystemLifeCycleState(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMountTargetError::IncorrectFileSystemLifeCycleState,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateMountTargetError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateMountTargetError::InternalServerError,
+                    )
                 }
                 "IpAddressInUse" => {
-                    return RusotoError::Service(CreateMountTargetError::IpAddressInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMountTargetError::IpAddressInUse,
+                    )
                 }
                 "MountTargetConflict" => {
-                    return RusotoError::Service(CreateMountTargetError::MountTargetConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateMountTargetError::MountTargetConflict,
+                    )
                 }
                 "NetworkInterfaceLimitExceeded" => {
-                    return RusotoError::Service(
-                        CreateMountTargetError::NetworkInterfaceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMountTargetError::NetworkInterfaceLimitExceeded,
                     )
                 }
                 "NoFreeAddressesInSubnet" => {
-                    return RusotoError::Service(CreateMountTargetError::NoFreeAddressesInSubnet(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateMountTargetError::NoFreeAddressesInSubnet,
+                    )
                 }
                 "SecurityGroupLimitExceeded" => {
-                    return RusotoError::Service(
-                        CreateMountTargetError::SecurityGroupLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMountTargetError::SecurityGroupLimitExceeded,
                     )
                 }
                 "SecurityGroupNotFound" => {
-                    return RusotoError::Service(CreateMountTargetError::SecurityGroupNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateMountTargetError::SecurityGroupNotFound,
+                    )
                 }
                 "SubnetNotFound" => {
-                    return RusotoError::Service(CreateMountTargetError::SubnetNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMountTargetError::SubnetNotFound,
+                    )
                 }
                 "UnsupportedAvailabilityZone" => {
-                    return RusotoError::Service(
-                        CreateMountTargetError::UnsupportedAvailabilityZone(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMountTargetError::UnsupportedAvailabilityZone,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1013,14 +1096,31 @@ impl CreateTagsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CreateTagsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "BadRequest" => return RusotoError::Service(CreateTagsError::BadRequest(err.msg)),
+                "BadRequest" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTagsError::BadRequest,
+                    )
+                }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(CreateTagsError::FileSystemNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTagsError::FileSystemNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateTagsError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTagsError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1054,19 +1154,30 @@ impl DeleteAccessPointError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessPointNotFound" => {
-                    return RusotoError::Service(DeleteAccessPointError::AccessPointNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteAccessPointError::AccessPointNotFound,
+                    )
                 }
                 "BadRequest" => {
-                    return RusotoError::Service(DeleteAccessPointError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAccessPointError::BadRequest,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteAccessPointError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteAccessPointError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1102,20 +1213,38 @@ impl DeleteFileSystemError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(DeleteFileSystemError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFileSystemError::BadRequest,
+                    )
                 }
                 "FileSystemInUse" => {
-                    return RusotoError::Service(DeleteFileSystemError::FileSystemInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFileSystemError::FileSystemInUse,
+                    )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(DeleteFileSystemError::FileSystemNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFileSystemError::FileSystemNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteFileSystemError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteFileSystemError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1150,21 +1279,30 @@ impl DeleteFileSystemPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(DeleteFileSystemPolicyError::FileSystemNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteFileSystemPolicyError::FileSystemNotFound,
+                    )
                 }
                 "IncorrectFileSystemLifeCycleState" => {
-                    return RusotoError::Service(
-                        DeleteFileSystemPolicyError::IncorrectFileSystemLifeCycleState(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFileSystemPolicyError::IncorrectFileSystemLifeCycleState,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteFileSystemPolicyError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteFileSystemPolicyError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1202,22 +1340,38 @@ impl DeleteMountTargetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(DeleteMountTargetError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMountTargetError::BadRequest,
+                    )
                 }
                 "DependencyTimeout" => {
-                    return RusotoError::Service(DeleteMountTargetError::DependencyTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMountTargetError::DependencyTimeout,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteMountTargetError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteMountTargetError::InternalServerError,
+                    )
                 }
                 "MountTargetNotFound" => {
-                    return RusotoError::Service(DeleteMountTargetError::MountTargetNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteMountTargetError::MountTargetNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1251,14 +1405,31 @@ impl DeleteTagsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteTagsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "BadRequest" => return RusotoError::Service(DeleteTagsError::BadRequest(err.msg)),
+                "BadRequest" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTagsError::BadRequest,
+                    )
+                }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(DeleteTagsError::FileSystemNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTagsError::FileSystemNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteTagsError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTagsError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1294,24 +1465,38 @@ impl DescribeAccessPointsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessPointNotFound" => {
-                    return RusotoError::Service(DescribeAccessPointsError::AccessPointNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAccessPointsError::AccessPointNotFound,
+                    )
                 }
                 "BadRequest" => {
-                    return RusotoError::Service(DescribeAccessPointsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAccessPointsError::BadRequest,
+                    )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(DescribeAccessPointsError::FileSystemNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAccessPointsError::FileSystemNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeAccessPointsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAccessPointsError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1348,22 +1533,38 @@ impl DescribeBackupPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(DescribeBackupPolicyError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBackupPolicyError::BadRequest,
+                    )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(DescribeBackupPolicyError::FileSystemNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeBackupPolicyError::FileSystemNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeBackupPolicyError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeBackupPolicyError::InternalServerError,
+                    )
                 }
                 "PolicyNotFound" => {
-                    return RusotoError::Service(DescribeBackupPolicyError::PolicyNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBackupPolicyError::PolicyNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1398,21 +1599,30 @@ impl DescribeFileSystemPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(DescribeFileSystemPolicyError::FileSystemNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeFileSystemPolicyError::FileSystemNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeFileSystemPolicyError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeFileSystemPolicyError::InternalServerError,
                     )
                 }
                 "PolicyNotFound" => {
-                    return RusotoError::Service(DescribeFileSystemPolicyError::PolicyNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeFileSystemPolicyError::PolicyNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1446,19 +1656,30 @@ impl DescribeFileSystemsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(DescribeFileSystemsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeFileSystemsError::BadRequest,
+                    )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(DescribeFileSystemsError::FileSystemNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeFileSystemsError::FileSystemNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeFileSystemsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeFileSystemsError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1494,21 +1715,30 @@ impl DescribeLifecycleConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(DescribeLifecycleConfigurationError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeLifecycleConfigurationError::BadRequest,
+                    )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(
-                        DescribeLifecycleConfigurationError::FileSystemNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeLifecycleConfigurationError::FileSystemNotFound,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeLifecycleConfigurationError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeLifecycleConfigurationError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1550,26 +1780,38 @@ impl DescribeMountTargetSecurityGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(
-                        DescribeMountTargetSecurityGroupsError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMountTargetSecurityGroupsError::BadRequest,
                     )
                 }
                 "IncorrectMountTargetState" => {
-                    return RusotoError::Service(
-                        DescribeMountTargetSecurityGroupsError::IncorrectMountTargetState(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMountTargetSecurityGroupsError::IncorrectMountTargetState,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeMountTargetSecurityGroupsError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMountTargetSecurityGroupsError::InternalServerError,
                     )
                 }
                 "MountTargetNotFound" => {
-                    return RusotoError::Service(
-                        DescribeMountTargetSecurityGroupsError::MountTargetNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMountTargetSecurityGroupsError::MountTargetNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1614,29 +1856,46 @@ impl DescribeMountTargetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessPointNotFound" => {
-                    return RusotoError::Service(DescribeMountTargetsError::AccessPointNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeMountTargetsError::AccessPointNotFound,
+                    )
                 }
                 "BadRequest" => {
-                    return RusotoError::Service(DescribeMountTargetsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMountTargetsError::BadRequest,
+                    )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(DescribeMountTargetsError::FileSystemNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeMountTargetsError::FileSystemNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeMountTargetsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeMountTargetsError::InternalServerError,
+                    )
                 }
                 "MountTargetNotFound" => {
-                    return RusotoError::Service(DescribeMountTargetsError::MountTargetNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeMountTargetsError::MountTargetNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1672,15 +1931,30 @@ impl DescribeTagsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(DescribeTagsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTagsError::BadRequest,
+                    )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(DescribeTagsError::FileSystemNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTagsError::FileSystemNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeTagsError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTagsError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1716,24 +1990,38 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessPointNotFound" => {
-                    return RusotoError::Service(ListTagsForResourceError::AccessPointNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::AccessPointNotFound,
+                    )
                 }
                 "BadRequest" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::BadRequest,
+                    )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(ListTagsForResourceError::FileSystemNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::FileSystemNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1776,36 +2064,54 @@ impl ModifyMountTargetSecurityGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(ModifyMountTargetSecurityGroupsError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ModifyMountTargetSecurityGroupsError::BadRequest,
+                    )
                 }
                 "IncorrectMountTargetState" => {
-                    return RusotoError::Service(
-                        ModifyMountTargetSecurityGroupsError::IncorrectMountTargetState(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyMountTargetSecurityGroupsError::IncorrectMountTargetState,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        ModifyMountTargetSecurityGroupsError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyMountTargetSecurityGroupsError::InternalServerError,
                     )
                 }
                 "MountTargetNotFound" => {
-                    return RusotoError::Service(
-                        ModifyMountTargetSecurityGroupsError::MountTargetNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyMountTargetSecurityGroupsError::MountTargetNotFound,
                     )
                 }
                 "SecurityGroupLimitExceeded" => {
-                    return RusotoError::Service(
-                        ModifyMountTargetSecurityGroupsError::SecurityGroupLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyMountTargetSecurityGroupsError::SecurityGroupLimitExceeded,
                     )
                 }
                 "SecurityGroupNotFound" => {
-                    return RusotoError::Service(
-                        ModifyMountTargetSecurityGroupsError::SecurityGroupNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyMountTargetSecurityGroupsError::SecurityGroupNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1854,20 +2160,38 @@ impl PutBackupPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(PutBackupPolicyError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutBackupPolicyError::BadRequest,
+                    )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(PutBackupPolicyError::FileSystemNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutBackupPolicyError::FileSystemNotFound,
+                    )
                 }
                 "IncorrectFileSystemLifeCycleState" => {
-                    return RusotoError::Service(
-                        PutBackupPolicyError::IncorrectFileSystemLifeCycleState(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutBackupPolicyError::IncorrectFileSystemLifeCycleState,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(PutBackupPolicyError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutBackupPolicyError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1906,24 +2230,38 @@ impl PutFileSystemPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(PutFileSystemPolicyError::FileSystemNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutFileSystemPolicyError::FileSystemNotFound,
+                    )
                 }
                 "IncorrectFileSystemLifeCycleState" => {
-                    return RusotoError::Service(
-                        PutFileSystemPolicyError::IncorrectFileSystemLifeCycleState(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutFileSystemPolicyError::IncorrectFileSystemLifeCycleState,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(PutFileSystemPolicyError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutFileSystemPolicyError::InternalServerError,
+                    )
                 }
                 "InvalidPolicyException" => {
-                    return RusotoError::Service(PutFileSystemPolicyError::InvalidPolicy(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutFileSystemPolicyError::InvalidPolicy,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1962,26 +2300,38 @@ impl PutLifecycleConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(PutLifecycleConfigurationError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutLifecycleConfigurationError::BadRequest,
+                    )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(
-                        PutLifecycleConfigurationError::FileSystemNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutLifecycleConfigurationError::FileSystemNotFound,
                     )
                 }
                 "IncorrectFileSystemLifeCycleState" => {
-                    return RusotoError::Service(
-                        PutLifecycleConfigurationError::IncorrectFileSystemLifeCycleState(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutLifecycleConfigurationError::IncorrectFileSystemLifeCycleState,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        PutLifecycleConfigurationError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutLifecycleConfigurationError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2022,16 +2372,38 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessPointNotFound" => {
-                    return RusotoError::Service(TagResourceError::AccessPointNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::AccessPointNotFound,
+                    )
+                }
+                "BadRequest" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::BadRequest,
+                    )
                 }
-                "BadRequest" => return RusotoError::Service(TagResourceError::BadRequest(err.msg)),
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(TagResourceError::FileSystemNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::FileSystemNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(TagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2068,18 +2440,38 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessPointNotFound" => {
-                    return RusotoError::Service(UntagResourceError::AccessPointNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::AccessPointNotFound,
+                    )
                 }
                 "BadRequest" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::BadRequest,
+                    )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(UntagResourceError::FileSystemNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::FileSystemNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(UntagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2122,35 +2514,62 @@ impl UpdateFileSystemError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(UpdateFileSystemError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFileSystemError::BadRequest,
+                    )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(UpdateFileSystemError::FileSystemNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFileSystemError::FileSystemNotFound,
+                    )
                 }
                 "IncorrectFileSystemLifeCycleState" => {
-                    return RusotoError::Service(
-                        UpdateFileSystemError::IncorrectFileSystemLifeCycleState(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFileSystemError::IncorrectFileSystemLifeCycleState,
                     )
                 }
                 "InsufficientThroughputCapacity" => {
-                    return RusotoError::Service(
-                        UpdateFileSystemError::InsufficientThroughputCapacity(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFileSystemError::InsufficientThroughputCapacity,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateFileSystemError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateFileSystemError::InternalServerError,
+                    )
                 }
                 "ThroughputLimitExceeded" => {
-                    return RusotoError::Service(UpdateFileSystemError::ThroughputLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateFileSystemError::ThroughputLimitExceeded,
+                    )
                 }
                 "TooManyRequests" => {
-                    return RusotoError::Service(UpdateFileSystemError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFileSystemError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2342,11 +2761,12 @@ pub struct EfsClient {
 impl EfsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> EfsClient {
         EfsClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "EFS"),
         }
     }
 
@@ -2361,12 +2781,32 @@ impl EfsClient {
     {
         EfsClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "EFS"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> EfsClient {
-        EfsClient { client, region }
+        EfsClient {
+            client,
+            region: region_for_service(region, "EFS"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> EfsClient {
+        EfsClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("EFS"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> EfsClient {
+        EfsClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -2382,6 +2822,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("POST", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("CreateAccessPoint");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2414,6 +2855,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("POST", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("CreateFileSystem");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2446,6 +2888,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("POST", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("CreateMountTarget");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2481,6 +2924,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("POST", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("CreateTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2515,6 +2959,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DeleteAccessPoint");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2546,6 +2991,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DeleteFileSystem");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2577,6 +3023,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DeleteFileSystemPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2608,6 +3055,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DeleteMountTarget");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2639,6 +3087,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("POST", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DeleteTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2670,6 +3119,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DescribeAccessPoints");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2717,6 +3167,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DescribeBackupPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2749,6 +3200,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DescribeFileSystemPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2778,6 +3230,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DescribeFileSystems");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2826,6 +3279,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DescribeLifecycleConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2861,6 +3315,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DescribeMountTargetSecurityGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2892,6 +3347,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DescribeMountTargets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2942,6 +3398,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("DescribeTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2983,6 +3440,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("GET", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3024,6 +3482,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("PUT", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("ModifyMountTargetSecurityGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3060,6 +3519,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("PUT", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("PutBackupPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3095,6 +3555,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("PUT", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("PutFileSystemPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3131,6 +3592,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("PUT", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("PutLifecycleConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3166,6 +3628,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("POST", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3200,6 +3663,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3237,6 +3701,7 @@ impl Efs for EfsClient {
 
         let mut request =
             SignedRequest::new("PUT", "elasticfilesystem", &self.region, &request_uri);
+        request.set_operation("UpdateFileSystem");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3259,3 +3724,299 @@ impl Efs for EfsClient {
         }
     }
 }
+
+/// A blocking client for the EFS API, running the calls of a `EfsClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `EfsClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct EfsBlockingClient {
+    client: EfsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl EfsBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> EfsBlockingClient {
+        EfsBlockingClient::from_client(EfsClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> EfsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        EfsBlockingClient::from_client(EfsClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> EfsBlockingClient {
+        EfsBlockingClient::from_client(EfsClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> EfsBlockingClient {
+        EfsBlockingClient::from_client(EfsClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: EfsClient) -> EfsBlockingClient {
+        EfsBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> EfsBlockingClient {
+        EfsBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> EfsBlockingClient {
+        EfsBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Efs::create_access_point`].
+    pub fn create_access_point(
+        &self,
+        input: CreateAccessPointRequest,
+    ) -> Result<AccessPointDescription, RusotoError<CreateAccessPointError>> {
+        self.runtime
+            .block_on(self.client.create_access_point(input))
+    }
+
+    /// Blocking version of [`Efs::create_file_system`].
+    pub fn create_file_system(
+        &self,
+        input: CreateFileSystemRequest,
+    ) -> Result<FileSystemDescription, RusotoError<CreateFileSystemError>> {
+        self.runtime.block_on(self.client.create_file_system(input))
+    }
+
+    /// Blocking version of [`Efs::create_mount_target`].
+    pub fn create_mount_target(
+        &self,
+        input: CreateMountTargetRequest,
+    ) -> Result<MountTargetDescription, RusotoError<CreateMountTargetError>> {
+        self.runtime
+            .block_on(self.client.create_mount_target(input))
+    }
+
+    /// Blocking version of [`Efs::create_tags`].
+    pub fn create_tags(
+        &self,
+        input: CreateTagsRequest,
+    ) -> Result<(), RusotoError<CreateTagsError>> {
+        self.runtime.block_on(self.client.create_tags(input))
+    }
+
+    /// Blocking version of [`Efs::delete_access_point`].
+    pub fn delete_access_point(
+        &self,
+        input: DeleteAccessPointRequest,
+    ) -> Result<(), RusotoError<DeleteAccessPointError>> {
+        self.runtime
+            .block_on(self.client.delete_access_point(input))
+    }
+
+    /// Blocking version of [`Efs::delete_file_system`].
+    pub fn delete_file_system(
+        &self,
+        input: DeleteFileSystemRequest,
+    ) -> Result<(), RusotoError<DeleteFileSystemError>> {
+        self.runtime.block_on(self.client.delete_file_system(input))
+    }
+
+    /// Blocking version of [`Efs::delete_file_system_policy`].
+    pub fn delete_file_system_policy(
+        &self,
+        input: DeleteFileSystemPolicyRequest,
+    ) -> Result<(), RusotoError<DeleteFileSystemPolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_file_system_policy(input))
+    }
+
+    /// Blocking version of [`Efs::delete_mount_target`].
+    pub fn delete_mount_target(
+        &self,
+        input: DeleteMountTargetRequest,
+    ) -> Result<(), RusotoError<DeleteMountTargetError>> {
+        self.runtime
+            .block_on(self.client.delete_mount_target(input))
+    }
+
+    /// Blocking version of [`Efs::delete_tags`].
+    pub fn delete_tags(
+        &self,
+        input: DeleteTagsRequest,
+    ) -> Result<(), RusotoError<DeleteTagsError>> {
+        self.runtime.block_on(self.client.delete_tags(input))
+    }
+
+    /// Blocking version of [`Efs::describe_access_points`].
+    pub fn describe_access_points(
+        &self,
+        input: DescribeAccessPointsRequest,
+    ) -> Result<DescribeAccessPointsResponse, RusotoError<DescribeAccessPointsError>> {
+        self.runtime
+            .block_on(self.client.describe_access_points(input))
+    }
+
+    /// Blocking version of [`Efs::describe_backup_policy`].
+    pub fn describe_backup_policy(
+        &self,
+        input: DescribeBackupPolicyRequest,
+    ) -> Result<BackupPolicyDescription, RusotoError<DescribeBackupPolicyError>> {
+        self.runtime
+            .block_on(self.client.describe_backup_policy(input))
+    }
+
+    /// Blocking version of [`Efs::describe_file_system_policy`].
+    pub fn describe_file_system_policy(
+        &self,
+        input: DescribeFileSystemPolicyRequest,
+    ) -> Result<FileSystemPolicyDescription, RusotoError<DescribeFileSystemPolicyError>> {
+        self.runtime
+            .block_on(self.client.describe_file_system_policy(input))
+    }
+
+    /// Blocking version of [`Efs::describe_file_systems`].
+    pub fn describe_file_systems(
+        &self,
+        input: DescribeFileSystemsRequest,
+    ) -> Result<DescribeFileSystemsResponse, RusotoError<DescribeFileSystemsError>> {
+        self.runtime
+            .block_on(self.client.describe_file_systems(input))
+    }
+
+    /// Blocking version of [`Efs::describe_lifecycle_configuration`].
+    pub fn describe_lifecycle_configuration(
+        &self,
+        input: DescribeLifecycleConfigurationRequest,
+    ) -> Result<LifecycleConfigurationDescription, RusotoError<DescribeLifecycleConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_lifecycle_configuration(input))
+    }
+
+    /// Blocking version of [`Efs::describe_mount_target_security_groups`].
+    pub fn describe_mount_target_security_groups(
+        &self,
+        input: DescribeMountTargetSecurityGroupsRequest,
+    ) -> Result<
+        DescribeMountTargetSecurityGroupsResponse,
+        RusotoError<DescribeMountTargetSecurityGroupsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_mount_target_security_groups(input))
+    }
+
+    /// Blocking version of [`Efs::describe_mount_targets`].
+    pub fn describe_mount_targets(
+        &self,
+        input: DescribeMountTargetsRequest,
+    ) -> Result<DescribeMountTargetsResponse, RusotoError<DescribeMountTargetsError>> {
+        self.runtime
+            .block_on(self.client.describe_mount_targets(input))
+    }
+
+    /// Blocking version of [`Efs::describe_tags`].
+    pub fn describe_tags(
+        &self,
+        input: DescribeTagsRequest,
+    ) -> Result<DescribeTagsResponse, RusotoError<DescribeTagsError>> {
+        self.runtime.block_on(self.client.describe_tags(input))
+    }
+
+    /// Blocking version of [`Efs::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`Efs::modify_mount_target_security_groups`].
+    pub fn modify_mount_target_security_groups(
+        &self,
+        input: ModifyMountTargetSecurityGroupsRequest,
+    ) -> Result<(), RusotoError<ModifyMountTargetSecurityGroupsError>> {
+        self.runtime
+            .block_on(self.client.modify_mount_target_security_groups(input))
+    }
+
+    /// Blocking version of [`Efs::put_backup_policy`].
+    pub fn put_backup_policy(
+        &self,
+        input: PutBackupPolicyRequest,
+    ) -> Result<BackupPolicyDescription, RusotoError<PutBackupPolicyError>> {
+        self.runtime.block_on(self.client.put_backup_policy(input))
+    }
+
+    /// Blocking version of [`Efs::put_file_system_policy`].
+    pub fn put_file_system_policy(
+        &self,
+        input: PutFileSystemPolicyRequest,
+    ) -> Result<FileSystemPolicyDescription, RusotoError<PutFileSystemPolicyError>> {
+        self.runtime
+            .block_on(self.client.put_file_system_policy(input))
+    }
+
+    /// Blocking version of [`Efs::put_lifecycle_configuration`].
+    pub fn put_lifecycle_configuration(
+        &self,
+        input: PutLifecycleConfigurationRequest,
+    ) -> Result<LifecycleConfigurationDescription, RusotoError<PutLifecycleConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.put_lifecycle_configuration(input))
+    }
+
+    /// Blocking version of [`Efs::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`Efs::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`Efs::update_file_system`].
+    pub fn update_file_system(
+        &self,
+        input: UpdateFileSystemRequest,
+    ) -> Result<FileSystemDescription, RusotoError<UpdateFileSystemError>> {
+        self.runtime.block_on(self.client.update_file_system(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1485,24 +1486,54 @@ impl CreateAddonError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateAddonError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAddonError::Client,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateAddonError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAddonError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateAddonError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAddonError::InvalidRequest,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateAddonError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAddonError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateAddonError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAddonError::ResourceNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(CreateAddonError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAddonError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1547,29 +1578,62 @@ impl CreateClusterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateClusterError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateClusterError::Client,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateClusterError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateClusterError::InvalidParameter,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateClusterError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateClusterError::ResourceInUse,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateClusterError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateClusterError::ResourceLimitExceeded,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(CreateClusterError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateClusterError::Server,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateClusterError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateClusterError::ServiceUnavailable,
+                    )
                 }
                 "UnsupportedAvailabilityZoneException" => {
-                    return RusotoError::Service(CreateClusterError::UnsupportedAvailabilityZone(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateClusterError::UnsupportedAvailabilityZone,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1613,30 +1677,54 @@ impl CreateFargateProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateFargateProfileError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFargateProfileError::Client,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateFargateProfileError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateFargateProfileError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateFargateProfileError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFargateProfileError::InvalidRequest,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateFargateProfileError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateFargateProfileError::ResourceLimitExceeded,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(CreateFargateProfileError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFargateProfileError::Server,
+                    )
                 }
                 "UnsupportedAvailabilityZoneException" => {
-                    return RusotoError::Service(
-                        CreateFargateProfileError::UnsupportedAvailabilityZone(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFargateProfileError::UnsupportedAvailabilityZone,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1683,29 +1771,62 @@ impl CreateNodegroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(CreateNodegroupError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNodegroupError::Client,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateNodegroupError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNodegroupError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateNodegroupError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNodegroupError::InvalidRequest,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateNodegroupError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNodegroupError::ResourceInUse,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateNodegroupError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateNodegroupError::ResourceLimitExceeded,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(CreateNodegroupError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNodegroupError::Server,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateNodegroupError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNodegroupError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1747,21 +1868,46 @@ impl DeleteAddonError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteAddonError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAddonError::Client,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteAddonError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAddonError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteAddonError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAddonError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteAddonError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAddonError::ResourceNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeleteAddonError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAddonError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1801,21 +1947,46 @@ impl DeleteClusterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteClusterError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteClusterError::Client,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteClusterError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteClusterError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteClusterError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteClusterError::ResourceNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeleteClusterError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteClusterError::Server,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteClusterError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteClusterError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1853,22 +2024,38 @@ impl DeleteFargateProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteFargateProfileError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFargateProfileError::Client,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteFargateProfileError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteFargateProfileError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteFargateProfileError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteFargateProfileError::ResourceNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeleteFargateProfileError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFargateProfileError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1909,24 +2096,54 @@ impl DeleteNodegroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DeleteNodegroupError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNodegroupError::Client,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteNodegroupError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNodegroupError::InvalidParameter,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteNodegroupError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNodegroupError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteNodegroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNodegroupError::ResourceNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DeleteNodegroupError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNodegroupError::Server,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteNodegroupError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNodegroupError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1967,21 +2184,46 @@ impl DescribeAddonError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeAddonError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAddonError::Client,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeAddonError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAddonError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeAddonError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAddonError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeAddonError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAddonError::ResourceNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeAddonError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAddonError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2017,19 +2259,30 @@ impl DescribeAddonVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeAddonVersionsError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAddonVersionsError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeAddonVersionsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAddonVersionsError::ResourceNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeAddonVersionsError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAddonVersionsError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2065,18 +2318,38 @@ impl DescribeClusterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeClusterError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeClusterError::Client,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeClusterError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeClusterError::ResourceNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeClusterError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeClusterError::Server,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeClusterError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeClusterError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2113,22 +2386,38 @@ impl DescribeFargateProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeFargateProfileError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeFargateProfileError::Client,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeFargateProfileError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeFargateProfileError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeFargateProfileError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeFargateProfileError::ResourceNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeFargateProfileError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeFargateProfileError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2167,23 +2456,46 @@ impl DescribeNodegroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeNodegroupError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeNodegroupError::Client,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeNodegroupError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeNodegroupError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeNodegroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeNodegroupError::ResourceNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeNodegroupError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeNodegroupError::Server,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeNodegroupError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeNodegroupError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2221,18 +2533,38 @@ impl DescribeUpdateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(DescribeUpdateError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUpdateError::Client,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeUpdateError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUpdateError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeUpdateError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUpdateError::ResourceNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(DescribeUpdateError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUpdateError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2270,18 +2602,47 @@ impl ListAddonsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListAddonsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ClientException" => return RusotoError::Service(ListAddonsError::Client(err.msg)),
+                "ClientException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAddonsError::Client,
+                    )
+                }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListAddonsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAddonsError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListAddonsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAddonsError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListAddonsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAddonsError::ResourceNotFound,
+                    )
+                }
+                "ServerException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAddonsError::Server,
+                    )
                 }
-                "ServerException" => return RusotoError::Service(ListAddonsError::Server(err.msg)),
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2319,18 +2680,38 @@ impl ListClustersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListClustersError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListClustersError::Client,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListClustersError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListClustersError::InvalidParameter,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(ListClustersError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListClustersError::Server,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListClustersError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListClustersError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2367,22 +2748,38 @@ impl ListFargateProfilesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListFargateProfilesError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFargateProfilesError::Client,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListFargateProfilesError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListFargateProfilesError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListFargateProfilesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListFargateProfilesError::ResourceNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(ListFargateProfilesError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFargateProfilesError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2421,21 +2818,46 @@ impl ListNodegroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListNodegroupsError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListNodegroupsError::Client,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListNodegroupsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListNodegroupsError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListNodegroupsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListNodegroupsError::ResourceNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(ListNodegroupsError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListNodegroupsError::Server,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListNodegroupsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListNodegroupsError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2469,12 +2891,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2509,18 +2941,38 @@ impl ListUpdatesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(ListUpdatesError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUpdatesError::Client,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListUpdatesError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUpdatesError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListUpdatesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUpdatesError::ResourceNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(ListUpdatesError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUpdatesError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2553,12 +3005,22 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2589,12 +3051,22 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2633,24 +3105,54 @@ impl UpdateAddonError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateAddonError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAddonError::Client,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateAddonError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAddonError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateAddonError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAddonError::InvalidRequest,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateAddonError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAddonError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateAddonError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAddonError::ResourceNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UpdateAddonError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAddonError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2693,28 +3195,54 @@ impl UpdateClusterConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateClusterConfigError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateClusterConfigError::Client,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateClusterConfigError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateClusterConfigError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateClusterConfigError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateClusterConfigError::InvalidRequest,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateClusterConfigError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateClusterConfigError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateClusterConfigError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateClusterConfigError::ResourceNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UpdateClusterConfigError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateClusterConfigError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2757,28 +3285,54 @@ impl UpdateClusterVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateClusterVersionError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateClusterVersionError::Client,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateClusterVersionError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateClusterVersionError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateClusterVersionError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateClusterVersionError::InvalidRequest,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateClusterVersionError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateClusterVersionError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateClusterVersionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateClusterVersionError::ResourceNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UpdateClusterVersionError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateClusterVersionError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2821,30 +3375,54 @@ impl UpdateNodegroupConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateNodegroupConfigError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateNodegroupConfigError::Client,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateNodegroupConfigError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateNodegroupConfigError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateNodegroupConfigError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateNodegroupConfigError::InvalidRequest,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateNodegroupConfigError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateNodegroupConfigError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateNodegroupConfigError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateNodegroupConfigError::ResourceNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UpdateNodegroupConfigError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateNodegroupConfigError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2887,32 +3465,54 @@ impl UpdateNodegroupVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientException" => {
-                    return RusotoError::Service(UpdateNodegroupVersionError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateNodegroupVersionError::Client,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateNodegroupVersionError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateNodegroupVersionError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateNodegroupVersionError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateNodegroupVersionError::InvalidRequest,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateNodegroupVersionError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateNodegroupVersionError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateNodegroupVersionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateNodegroupVersionError::ResourceNotFound,
+                    )
                 }
                 "ServerException" => {
-                    return RusotoError::Service(UpdateNodegroupVersionError::Server(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateNodegroupVersionError::Server,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3108,11 +3708,12 @@ pub struct EksClient {
 impl EksClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> EksClient {
         EksClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "EKS"),
         }
     }
 
@@ -3127,12 +3728,32 @@ impl EksClient {
     {
         EksClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "EKS"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> EksClient {
-        EksClient { client, region }
+        EksClient {
+            client,
+            region: region_for_service(region, "EKS"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> EksClient {
+        EksClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("EKS"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> EksClient {
+        EksClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -3147,6 +3768,7 @@ impl Eks for EksClient {
         let request_uri = format!("/clusters/{name}/addons", name = input.cluster_name);
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
+        request.set_operation("CreateAddon");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3178,6 +3800,7 @@ impl Eks for EksClient {
         let request_uri = "/clusters";
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
+        request.set_operation("CreateCluster");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3212,6 +3835,7 @@ impl Eks for EksClient {
         );
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
+        request.set_operation("CreateFargateProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3243,6 +3867,7 @@ impl Eks for EksClient {
         let request_uri = format!("/clusters/{name}/node-groups", name = input.cluster_name);
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
+        request.set_operation("CreateNodegroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3278,6 +3903,7 @@ impl Eks for EksClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "eks", &self.region, &request_uri);
+        request.set_operation("DeleteAddon");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3306,6 +3932,7 @@ impl Eks for EksClient {
         let request_uri = format!("/clusters/{name}", name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "eks", &self.region, &request_uri);
+        request.set_operation("DeleteCluster");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3338,6 +3965,7 @@ impl Eks for EksClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "eks", &self.region, &request_uri);
+        request.set_operation("DeleteFargateProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3370,6 +3998,7 @@ impl Eks for EksClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "eks", &self.region, &request_uri);
+        request.set_operation("DeleteNodegroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3402,6 +4031,7 @@ impl Eks for EksClient {
         );
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation("DescribeAddon");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3430,6 +4060,7 @@ impl Eks for EksClient {
         let request_uri = "/addons/supported-versions";
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation("DescribeAddonVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3473,6 +4104,7 @@ impl Eks for EksClient {
         let request_uri = format!("/clusters/{name}", name = input.name);
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation("DescribeCluster");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3505,6 +4137,7 @@ impl Eks for EksClient {
         );
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation("DescribeFargateProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3537,6 +4170,7 @@ impl Eks for EksClient {
         );
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation("DescribeNodegroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3569,6 +4203,7 @@ impl Eks for EksClient {
         );
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation("DescribeUpdate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3606,6 +4241,7 @@ impl Eks for EksClient {
         let request_uri = format!("/clusters/{name}/addons", name = input.cluster_name);
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation("ListAddons");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3643,6 +4279,7 @@ impl Eks for EksClient {
         let request_uri = "/clusters";
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation("ListClusters");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3683,6 +4320,7 @@ impl Eks for EksClient {
         );
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation("ListFargateProfiles");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3720,6 +4358,7 @@ impl Eks for EksClient {
         let request_uri = format!("/clusters/{name}/node-groups", name = input.cluster_name);
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation("ListNodegroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3757,6 +4396,7 @@ impl Eks for EksClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3785,6 +4425,7 @@ impl Eks for EksClient {
         let request_uri = format!("/clusters/{name}/updates", name = input.name);
 
         let mut request = SignedRequest::new("GET", "eks", &self.region, &request_uri);
+        request.set_operation("ListUpdates");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3828,6 +4469,7 @@ impl Eks for EksClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3859,6 +4501,7 @@ impl Eks for EksClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "eks", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3897,6 +4540,7 @@ impl Eks for EksClient {
         );
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
+        request.set_operation("UpdateAddon");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3928,6 +4572,7 @@ impl Eks for EksClient {
         let request_uri = format!("/clusters/{name}/update-config", name = input.name);
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
+        request.set_operation("UpdateClusterConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3959,6 +4604,7 @@ impl Eks for EksClient {
         let request_uri = format!("/clusters/{name}/updates", name = input.name);
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
+        request.set_operation("UpdateClusterVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3994,6 +4640,7 @@ impl Eks for EksClient {
         );
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
+        request.set_operation("UpdateNodegroupConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4029,6 +4676,7 @@ impl Eks for EksClient {
         );
 
         let mut request = SignedRequest::new("POST", "eks", &self.region, &request_uri);
+        request.set_operation("UpdateNodegroupVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4051,3 +4699,304 @@ impl Eks for EksClient {
         }
     }
 }
+
+/// A blocking client for the Amazon EKS API, running the calls of a `EksClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `EksClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct EksBlockingClient {
+    client: EksClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl EksBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> EksBlockingClient {
+        EksBlockingClient::from_client(EksClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> EksBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        EksBlockingClient::from_client(EksClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> EksBlockingClient {
+        EksBlockingClient::from_client(EksClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> EksBlockingClient {
+        EksBlockingClient::from_client(EksClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: EksClient) -> EksBlockingClient {
+        EksBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> EksBlockingClient {
+        EksBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> EksBlockingClient {
+        EksBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Eks::create_addon`].
+    pub fn create_addon(
+        &self,
+        input: CreateAddonRequest,
+    ) -> Result<CreateAddonResponse, RusotoError<CreateAddonError>> {
+        self.runtime.block_on(self.client.create_addon(input))
+    }
+
+    /// Blocking version of [`Eks::create_cluster`].
+    pub fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> Result<CreateClusterResponse, RusotoError<CreateClusterError>> {
+        self.runtime.block_on(self.client.create_cluster(input))
+    }
+
+    /// Blocking version of [`Eks::create_fargate_profile`].
+    pub fn create_fargate_profile(
+        &self,
+        input: CreateFargateProfileRequest,
+    ) -> Result<CreateFargateProfileResponse, RusotoError<CreateFargateProfileError>> {
+        self.runtime
+            .block_on(self.client.create_fargate_profile(input))
+    }
+
+    /// Blocking version of [`Eks::create_nodegroup`].
+    pub fn create_nodegroup(
+        &self,
+        input: CreateNodegroupRequest,
+    ) -> Result<CreateNodegroupResponse, RusotoError<CreateNodegroupError>> {
+        self.runtime.block_on(self.client.create_nodegroup(input))
+    }
+
+    /// Blocking version of [`Eks::delete_addon`].
+    pub fn delete_addon(
+        &self,
+        input: DeleteAddonRequest,
+    ) -> Result<DeleteAddonResponse, RusotoError<DeleteAddonError>> {
+        self.runtime.block_on(self.client.delete_addon(input))
+    }
+
+    /// Blocking version of [`Eks::delete_cluster`].
+    pub fn delete_cluster(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> Result<DeleteClusterResponse, RusotoError<DeleteClusterError>> {
+        self.runtime.block_on(self.client.delete_cluster(input))
+    }
+
+    /// Blocking version of [`Eks::delete_fargate_profile`].
+    pub fn delete_fargate_profile(
+        &self,
+        input: DeleteFargateProfileRequest,
+    ) -> Result<DeleteFargateProfileResponse, RusotoError<DeleteFargateProfileError>> {
+        self.runtime
+            .block_on(self.client.delete_fargate_profile(input))
+    }
+
+    /// Blocking version of [`Eks::delete_nodegroup`].
+    pub fn delete_nodegroup(
+        &self,
+        input: DeleteNodegroupRequest,
+    ) -> Result<DeleteNodegroupResponse, RusotoError<DeleteNodegroupError>> {
+        self.runtime.block_on(self.client.delete_nodegroup(input))
+    }
+
+    /// Blocking version of [`Eks::describe_addon`].
+    pub fn describe_addon(
+        &self,
+        input: DescribeAddonRequest,
+    ) -> Result<DescribeAddonResponse, RusotoError<DescribeAddonError>> {
+        self.runtime.block_on(self.client.describe_addon(input))
+    }
+
+    /// Blocking version of [`Eks::describe_addon_versions`].
+    pub fn describe_addon_versions(
+        &self,
+        input: DescribeAddonVersionsRequest,
+    ) -> Result<DescribeAddonVersionsResponse, RusotoError<DescribeAddonVersionsError>> {
+        self.runtime
+            .block_on(self.client.describe_addon_versions(input))
+    }
+
+    /// Blocking version of [`Eks::describe_cluster`].
+    pub fn describe_cluster(
+        &self,
+        input: DescribeClusterRequest,
+    ) -> Result<DescribeClusterResponse, RusotoError<DescribeClusterError>> {
+        self.runtime.block_on(self.client.describe_cluster(input))
+    }
+
+    /// Blocking version of [`Eks::describe_fargate_profile`].
+    pub fn describe_fargate_profile(
+        &self,
+        input: DescribeFargateProfileRequest,
+    ) -> Result<DescribeFargateProfileResponse, RusotoError<DescribeFargateProfileError>> {
+        self.runtime
+            .block_on(self.client.describe_fargate_profile(input))
+    }
+
+    /// Blocking version of [`Eks::describe_nodegroup`].
+    pub fn describe_nodegroup(
+        &self,
+        input: DescribeNodegroupRequest,
+    ) -> Result<DescribeNodegroupResponse, RusotoError<DescribeNodegroupError>> {
+        self.runtime.block_on(self.client.describe_nodegroup(input))
+    }
+
+    /// Blocking version of [`Eks::describe_update`].
+    pub fn describe_update(
+        &self,
+        input: DescribeUpdateRequest,
+    ) -> Result<DescribeUpdateResponse, RusotoError<DescribeUpdateError>> {
+        self.runtime.block_on(self.client.describe_update(input))
+    }
+
+    /// Blocking version of [`Eks::list_addons`].
+    pub fn list_addons(
+        &self,
+        input: ListAddonsRequest,
+    ) -> Result<ListAddonsResponse, RusotoError<ListAddonsError>> {
+        self.runtime.block_on(self.client.list_addons(input))
+    }
+
+    /// Blocking version of [`Eks::list_clusters`].
+    pub fn list_clusters(
+        &self,
+        input: ListClustersRequest,
+    ) -> Result<ListClustersResponse, RusotoError<ListClustersError>> {
+        self.runtime.block_on(self.client.list_clusters(input))
+    }
+
+    /// Blocking version of [`Eks::list_fargate_profiles`].
+    pub fn list_fargate_profiles(
+        &self,
+        input: ListFargateProfilesRequest,
+    ) -> Result<ListFargateProfilesResponse, RusotoError<ListFargateProfilesError>> {
+        self.runtime
+            .block_on(self.client.list_fargate_profiles(input))
+    }
+
+    /// Blocking version of [`Eks::list_nodegroups`].
+    pub fn list_nodegroups(
+        &self,
+        input: ListNodegroupsRequest,
+    ) -> Result<ListNodegroupsResponse, RusotoError<ListNodegroupsError>> {
+        self.runtime.block_on(self.client.list_nodegroups(input))
+    }
+
+    /// Blocking version of [`Eks::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`Eks::list_updates`].
+    pub fn list_updates(
+        &self,
+        input: ListUpdatesRequest,
+    ) -> Result<ListUpdatesResponse, RusotoError<ListUpdatesError>> {
+        self.runtime.block_on(self.client.list_updates(input))
+    }
+
+    /// Blocking version of [`Eks::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`Eks::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`Eks::update_addon`].
+    pub fn update_addon(
+        &self,
+        input: UpdateAddonRequest,
+    ) -> Result<UpdateAddonResponse, RusotoError<UpdateAddonError>> {
+        self.runtime.block_on(self.client.update_addon(input))
+    }
+
+    /// Blocking version of [`Eks::update_cluster_config`].
+    pub fn update_cluster_config(
+        &self,
+        input: UpdateClusterConfigRequest,
+    ) -> Result<UpdateClusterConfigResponse, RusotoError<UpdateClusterConfigError>> {
+        self.runtime
+            .block_on(self.client.update_cluster_config(input))
+    }
+
+    /// Blocking version of [`Eks::update_cluster_version`].
+    pub fn update_cluster_version(
+        &self,
+        input: UpdateClusterVersionRequest,
+    ) -> Result<UpdateClusterVersionResponse, RusotoError<UpdateClusterVersionError>> {
+        self.runtime
+            .block_on(self.client.update_cluster_version(input))
+    }
+
+    /// Blocking version of [`Eks::update_nodegroup_config`].
+    pub fn update_nodegroup_config(
+        &self,
+        input: UpdateNodegroupConfigRequest,
+    ) -> Result<UpdateNodegroupConfigResponse, RusotoError<UpdateNodegroupConfigError>> {
+        self.runtime
+            .block_on(self.client.update_nodegroup_config(input))
+    }
+
+    /// Blocking version of [`Eks::update_nodegroup_version`].
+    pub fn update_nodegroup_version(
+        &self,
+        input: UpdateNodegroupVersionRequest,
+    ) -> Result<UpdateNodegroupVersionResponse, RusotoError<UpdateNodegroupVersionError>> {
+        self.runtime
+            .block_on(self.client.update_nodegroup_version(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -9994,25 +9995,35 @@ impl AddTagsToResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheClusterNotFound" => {
-                        return RusotoError::Service(
-                            AddTagsToResourceError::CacheClusterNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AddTagsToResourceError::CacheClusterNotFoundFault,
                         )
                     }
                     "InvalidARN" => {
-                        return RusotoError::Service(AddTagsToResourceError::InvalidARNFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AddTagsToResourceError::InvalidARNFault,
+                        )
                     }
                     "SnapshotNotFoundFault" => {
-                        return RusotoError::Service(AddTagsToResourceError::SnapshotNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AddTagsToResourceError::SnapshotNotFoundFault,
+                        )
                     }
                     "TagQuotaPerResourceExceeded" => {
-                        return RusotoError::Service(
-                            AddTagsToResourceError::TagQuotaPerResourceExceeded(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AddTagsToResourceError::TagQuotaPerResourceExceeded,
                         )
                     }
                     _ => {}
@@ -10069,7 +10080,7 @@ impl AuthorizeCacheSecurityGroupIngressError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AuthorizationAlreadyExists" => return RusotoError::Service(AuthorizeCacheSecurityGroupIngressError::AuthorizationAlreadyExistsFault(parsed_error.message)),"CacheSecurityGroupNotFound" => return RusotoError::Service(AuthorizeCacheSecurityGroupIngressError::CacheSecurityGroupNotFoundFault(parsed_error.message)),"InvalidCacheSecurityGroupState" => return RusotoError::Service(AuthorizeCacheSecurityGroupIngressError::InvalidCacheSecurityGroupStateFault(parsed_error.message)),"InvalidParameterCombination" => return RusotoError::Service(AuthorizeCacheSecurityGroupIngressError::InvalidParameterCombination(parsed_error.message)),"InvalidParameterValue" => return RusotoError::Service(AuthorizeCacheSecurityGroupIngressError::InvalidParameterValue(parsed_error.message)),_ => {}
+                                    "AuthorizationAlreadyExists" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, AuthorizeCacheSecurityGroupIngressError::AuthorizationAlreadyExistsFault),"CacheSecurityGroupNotFound" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, AuthorizeCacheSecurityGroupIngressError::CacheSecurityGroupNotFoundFault),"InvalidCacheSecurityGroupState" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, AuthorizeCacheSecurityGroupIngressError::InvalidCacheSecurityGroupStateFault),"InvalidParameterCombination" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, AuthorizeCacheSecurityGroupIngressError::InvalidParameterCombination),"InvalidParameterValue" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, AuthorizeCacheSecurityGroupIngressError::InvalidParameterValue),_ => {}
                                 }
             }
         }
@@ -10125,17 +10136,19 @@ impl BatchApplyUpdateActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
-                            BatchApplyUpdateActionError::InvalidParameterValue(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            BatchApplyUpdateActionError::InvalidParameterValue,
                         )
                     }
                     "ServiceUpdateNotFoundFault" => {
-                        return RusotoError::Service(
-                            BatchApplyUpdateActionError::ServiceUpdateNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            BatchApplyUpdateActionError::ServiceUpdateNotFoundFault,
                         )
                     }
                     _ => {}
@@ -10183,15 +10196,19 @@ impl BatchStopUpdateActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
-                            BatchStopUpdateActionError::InvalidParameterValue(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            BatchStopUpdateActionError::InvalidParameterValue,
                         )
                     }
                     "ServiceUpdateNotFoundFault" => {
-                        return RusotoError::Service(
-                            BatchStopUpdateActionError::ServiceUpdateNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            BatchStopUpdateActionError::ServiceUpdateNotFoundFault,
                         )
                     }
                     _ => {}
@@ -10241,24 +10258,27 @@ impl CompleteMigrationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidReplicationGroupState" => {
-                        return RusotoError::Service(
-                            CompleteMigrationError::InvalidReplicationGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CompleteMigrationError::InvalidReplicationGroupStateFault,
                         )
                     }
                     "ReplicationGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            CompleteMigrationError::ReplicationGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CompleteMigrationError::ReplicationGroupNotFoundFault,
                         )
                     }
                     "ReplicationGroupNotUnderMigrationFault" => {
-                        return RusotoError::Service(
-                            CompleteMigrationError::ReplicationGroupNotUnderMigrationFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CompleteMigrationError::ReplicationGroupNotUnderMigrationFault,
                         )
                     }
                     _ => {}
@@ -10319,34 +10339,52 @@ impl CopySnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
-                            CopySnapshotError::InvalidParameterCombination(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopySnapshotError::InvalidParameterCombination,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(CopySnapshotError::InvalidParameterValue(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CopySnapshotError::InvalidParameterValue,
+                        )
                     }
                     "InvalidSnapshotState" => {
-                        return RusotoError::Service(CopySnapshotError::InvalidSnapshotStateFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CopySnapshotError::InvalidSnapshotStateFault,
+                        )
                     }
                     "SnapshotAlreadyExistsFault" => {
-                        return RusotoError::Service(CopySnapshotError::SnapshotAlreadyExistsFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CopySnapshotError::SnapshotAlreadyExistsFault,
+                        )
                     }
                     "SnapshotNotFoundFault" => {
-                        return RusotoError::Service(CopySnapshotError::SnapshotNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CopySnapshotError::SnapshotNotFoundFault,
+                        )
                     }
                     "SnapshotQuotaExceededFault" => {
-                        return RusotoError::Service(CopySnapshotError::SnapshotQuotaExceededFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CopySnapshotError::SnapshotQuotaExceededFault,
+                        )
                     }
                     _ => {}
                 }
@@ -10419,99 +10457,115 @@ impl CreateCacheClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheClusterAlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateCacheClusterError::CacheClusterAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateCacheClusterError::CacheClusterAlreadyExistsFault,
                         )
                     }
                     "CacheParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            CreateCacheClusterError::CacheParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateCacheClusterError::CacheParameterGroupNotFoundFault,
                         )
                     }
                     "CacheSecurityGroupNotFound" => {
-                        return RusotoError::Service(
-                            CreateCacheClusterError::CacheSecurityGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateCacheClusterError::CacheSecurityGroupNotFoundFault,
                         )
                     }
                     "CacheSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            CreateCacheClusterError::CacheSubnetGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateCacheClusterError::CacheSubnetGroupNotFoundFault,
                         )
                     }
                     "ClusterQuotaForCustomerExceeded" => {
-                        return RusotoError::Service(
-                            CreateCacheClusterError::ClusterQuotaForCustomerExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateCacheClusterError::ClusterQuotaForCustomerExceededFault,
                         )
                     }
                     "InsufficientCacheClusterCapacity" => {
-                        return RusotoError::Service(
-                            CreateCacheClusterError::InsufficientCacheClusterCapacityFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateCacheClusterError::InsufficientCacheClusterCapacityFault,
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
-                            CreateCacheClusterError::InvalidParameterCombination(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateCacheClusterError::InvalidParameterCombination,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
-                            CreateCacheClusterError::InvalidParameterValue(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateCacheClusterError::InvalidParameterValue,
                         )
                     }
                     "InvalidReplicationGroupState" => {
-                        return RusotoError::Service(
-                            CreateCacheClusterError::InvalidReplicationGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateCacheClusterError::InvalidReplicationGroupStateFault,
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
-                            CreateCacheClusterError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateCacheClusterError::InvalidVPCNetworkStateFault,
                         )
                     }
                     "NodeQuotaForClusterExceeded" => {
-                        return RusotoError::Service(
-                            CreateCacheClusterError::NodeQuotaForClusterExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateCacheClusterError::NodeQuotaForClusterExceededFault,
                         )
                     }
                     "NodeQuotaForCustomerExceeded" => {
-                        return RusotoError::Service(
-                            CreateCacheClusterError::NodeQuotaForCustomerExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateCacheClusterError::NodeQuotaForCustomerExceededFault,
                         )
                     }
                     "ReplicationGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            CreateCacheClusterError::ReplicationGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateCacheClusterError::ReplicationGroupNotFoundFault,
                         )
                     }
                     "TagQuotaPerResourceExceeded" => {
-                        return RusotoError::Service(
-                            CreateCacheClusterError::TagQuotaPerResourceExceeded(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateCacheClusterError::TagQuotaPerResourceExceeded,
                         )
                     }
                     _ => {}
@@ -10601,38 +10655,43 @@ impl CreateCacheParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheParameterGroupAlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateCacheParameterGroupError::CacheParameterGroupAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateCacheParameterGroupError::CacheParameterGroupAlreadyExistsFault,
                         )
                     }
                     "CacheParameterGroupQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CreateCacheParameterGroupError::CacheParameterGroupQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateCacheParameterGroupError::CacheParameterGroupQuotaExceededFault,
                         )
                     }
                     "InvalidCacheParameterGroupState" => {
-                        return RusotoError::Service(
-                            CreateCacheParameterGroupError::InvalidCacheParameterGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateCacheParameterGroupError::InvalidCacheParameterGroupStateFault,
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
-                            CreateCacheParameterGroupError::InvalidParameterCombination(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateCacheParameterGroupError::InvalidParameterCombination,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
-                            CreateCacheParameterGroupError::InvalidParameterValue(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateCacheParameterGroupError::InvalidParameterValue,
                         )
                     }
                     _ => {}
@@ -10695,31 +10754,35 @@ impl CreateCacheSecurityGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheSecurityGroupAlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateCacheSecurityGroupError::CacheSecurityGroupAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateCacheSecurityGroupError::CacheSecurityGroupAlreadyExistsFault,
                         )
                     }
                     "QuotaExceeded.CacheSecurityGroup" => {
-                        return RusotoError::Service(
-                            CreateCacheSecurityGroupError::CacheSecurityGroupQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateCacheSecurityGroupError::CacheSecurityGroupQuotaExceededFault,
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
-                            CreateCacheSecurityGroupError::InvalidParameterCombination(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateCacheSecurityGroupError::InvalidParameterCombination,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
-                            CreateCacheSecurityGroupError::InvalidParameterValue(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateCacheSecurityGroupError::InvalidParameterValue,
                         )
                     }
                     _ => {}
@@ -10781,36 +10844,43 @@ impl CreateCacheSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheSubnetGroupAlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateCacheSubnetGroupError::CacheSubnetGroupAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateCacheSubnetGroupError::CacheSubnetGroupAlreadyExistsFault,
                         )
                     }
                     "CacheSubnetGroupQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CreateCacheSubnetGroupError::CacheSubnetGroupQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateCacheSubnetGroupError::CacheSubnetGroupQuotaExceededFault,
                         )
                     }
                     "CacheSubnetQuotaExceededFault" => {
-                        return RusotoError::Service(
-                            CreateCacheSubnetGroupError::CacheSubnetQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateCacheSubnetGroupError::CacheSubnetQuotaExceededFault,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateCacheSubnetGroupError::InvalidSubnet(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateCacheSubnetGroupError::InvalidSubnet,
+                        )
                     }
                     "SubnetNotAllowedFault" => {
-                        return RusotoError::Service(
-                            CreateCacheSubnetGroupError::SubnetNotAllowedFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateCacheSubnetGroupError::SubnetNotAllowedFault,
                         )
                     }
                     _ => {}
@@ -10872,38 +10942,18 @@ impl CreateGlobalReplicationGroupError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                    "GlobalReplicationGroupAlreadyExistsFault" => return RusotoError::Service(
-                        CreateGlobalReplicationGroupError::GlobalReplicationGroupAlreadyExistsFault(
-                            parsed_error.message,
-                        ),
-                    ),
+                    "GlobalReplicationGroupAlreadyExistsFault" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateGlobalReplicationGroupError::GlobalReplicationGroupAlreadyExistsFault),
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
-                            CreateGlobalReplicationGroupError::InvalidParameterValue(
-                                parsed_error.message,
-                            ),
-                        )
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateGlobalReplicationGroupError::InvalidParameterValue)
                     }
                     "InvalidReplicationGroupState" => {
-                        return RusotoError::Service(
-                            CreateGlobalReplicationGroupError::InvalidReplicationGroupStateFault(
-                                parsed_error.message,
-                            ),
-                        )
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateGlobalReplicationGroupError::InvalidReplicationGroupStateFault)
                     }
                     "ReplicationGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            CreateGlobalReplicationGroupError::ReplicationGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
-                        )
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateGlobalReplicationGroupError::ReplicationGroupNotFoundFault)
                     }
                     "ServiceLinkedRoleNotFoundFault" => {
-                        return RusotoError::Service(
-                            CreateGlobalReplicationGroupError::ServiceLinkedRoleNotFoundFault(
-                                parsed_error.message,
-                            ),
-                        )
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateGlobalReplicationGroupError::ServiceLinkedRoleNotFoundFault)
                     }
                     _ => {}
                 }
@@ -10994,7 +11044,7 @@ impl CreateReplicationGroupError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "CacheClusterNotFound" => return RusotoError::Service(CreateReplicationGroupError::CacheClusterNotFoundFault(parsed_error.message)),"CacheParameterGroupNotFound" => return RusotoError::Service(CreateReplicationGroupError::CacheParameterGroupNotFoundFault(parsed_error.message)),"CacheSecurityGroupNotFound" => return RusotoError::Service(CreateReplicationGroupError::CacheSecurityGroupNotFoundFault(parsed_error.message)),"CacheSubnetGroupNotFoundFault" => return RusotoError::Service(CreateReplicationGroupError::CacheSubnetGroupNotFoundFault(parsed_error.message)),"ClusterQuotaForCustomerExceeded" => return RusotoError::Service(CreateReplicationGroupError::ClusterQuotaForCustomerExceededFault(parsed_error.message)),"GlobalReplicationGroupNotFoundFault" => return RusotoError::Service(CreateReplicationGroupError::GlobalReplicationGroupNotFoundFault(parsed_error.message)),"InsufficientCacheClusterCapacity" => return RusotoError::Service(CreateReplicationGroupError::InsufficientCacheClusterCapacityFault(parsed_error.message)),"InvalidCacheClusterState" => return RusotoError::Service(CreateReplicationGroupError::InvalidCacheClusterStateFault(parsed_error.message)),"InvalidGlobalReplicationGroupState" => return RusotoError::Service(CreateReplicationGroupError::InvalidGlobalReplicationGroupStateFault(parsed_error.message)),"InvalidParameterCombination" => return RusotoError::Service(CreateReplicationGroupError::InvalidParameterCombination(parsed_error.message)),"InvalidParameterValue" => return RusotoError::Service(CreateReplicationGroupError::InvalidParameterValue(parsed_error.message)),"InvalidUserGroupState" => return RusotoError::Service(CreateReplicationGroupError::InvalidUserGroupStateFault(parsed_error.message)),"InvalidVPCNetworkStateFault" => return RusotoError::Service(CreateReplicationGroupError::InvalidVPCNetworkStateFault(parsed_error.message)),"NodeGroupsPerReplicationGroupQuotaExceeded" => return RusotoError::Service(CreateReplicationGroupError::NodeGroupsPerReplicationGroupQuotaExceededFault(parsed_error.message)),"NodeQuotaForClusterExceeded" => return RusotoError::Service(CreateReplicationGroupError::NodeQuotaForClusterExceededFault(parsed_error.message)),"NodeQuotaForCustomerExceeded" => return RusotoError::Service(CreateReplicationGroupError::NodeQuotaForCustomerExceededFault(parsed_error.message)),"ReplicationGroupAlreadyExists" => return RusotoError::Service(CreateReplicationGroupError::ReplicationGroupAlreadyExistsFault(parsed_error.message)),"TagQuotaPerResourceExceeded" => return RusotoError::Service(CreateReplicationGroupError::TagQuotaPerResourceExceeded(parsed_error.message)),"UserGroupNotFound" => return RusotoError::Service(CreateReplicationGroupError::UserGroupNotFoundFault(parsed_error.message)),_ => {}
+                                    "CacheClusterNotFound" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateReplicationGroupError::CacheClusterNotFoundFault),"CacheParameterGroupNotFound" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateReplicationGroupError::CacheParameterGroupNotFoundFault),"CacheSecurityGroupNotFound" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateReplicationGroupError::CacheSecurityGroupNotFoundFault),"CacheSubnetGroupNotFoundFault" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateReplicationGroupError::CacheSubnetGroupNotFoundFault),"ClusterQuotaForCustomerExceeded" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateReplicationGroupError::ClusterQuotaForCustomerExceededFault),"GlobalReplicationGroupNotFoundFault" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateReplicationGroupError::GlobalReplicationGroupNotFoundFault),"InsufficientCacheClusterCapacity" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateReplicationGroupError::InsufficientCacheClusterCapacityFault),"InvalidCacheClusterState" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateReplicationGroupError::InvalidCacheClusterStateFault),"InvalidGlobalReplicationGroupState" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateReplicationGroupError::InvalidGlobalReplicationGroupStateFault),"InvalidParameterCombination" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateReplicationGroupError::InvalidParameterCombination),"InvalidParameterValue" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateReplicationGroupError::InvalidParameterValue),"InvalidUserGroupState" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateReplicationGroupError::InvalidUserGroupStateFault),"InvalidVPCNetworkStateFault" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateReplicationGroupError::InvalidVPCNetworkStateFault),"NodeGroupsPerReplicationGroupQuotaExceeded" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateReplicationGroupError::NodeGroupsPerReplicationGroupQuotaExceededFault),"NodeQuotaForClusterExceeded" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateReplicationGroupError::NodeQuotaForClusterExceededFault),"NodeQuotaForCustomerExceeded" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateReplicationGroupError::NodeQuotaForCustomerExceededFault),"ReplicationGroupAlreadyExists" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateReplicationGroupError::ReplicationGroupAlreadyExistsFault),"TagQuotaPerResourceExceeded" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateReplicationGroupError::TagQuotaPerResourceExceeded),"UserGroupNotFound" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateReplicationGroupError::UserGroupNotFoundFault),_ => {}
                                 }
             }
         }
@@ -11104,56 +11154,75 @@ impl CreateSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheClusterNotFound" => {
-                        return RusotoError::Service(
-                            CreateSnapshotError::CacheClusterNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateSnapshotError::CacheClusterNotFoundFault,
                         )
                     }
                     "InvalidCacheClusterState" => {
-                        return RusotoError::Service(
-                            CreateSnapshotError::InvalidCacheClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateSnapshotError::InvalidCacheClusterStateFault,
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
-                            CreateSnapshotError::InvalidParameterCombination(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateSnapshotError::InvalidParameterCombination,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(CreateSnapshotError::InvalidParameterValue(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateSnapshotError::InvalidParameterValue,
+                        )
                     }
                     "InvalidReplicationGroupState" => {
-                        return RusotoError::Service(
-                            CreateSnapshotError::InvalidReplicationGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateSnapshotError::InvalidReplicationGroupStateFault,
                         )
                     }
                     "ReplicationGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            CreateSnapshotError::ReplicationGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateSnapshotError::ReplicationGroupNotFoundFault,
                         )
                     }
                     "SnapshotAlreadyExistsFault" => {
-                        return RusotoError::Service(
-                            CreateSnapshotError::SnapshotAlreadyExistsFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateSnapshotError::SnapshotAlreadyExistsFault,
                         )
                     }
                     "SnapshotFeatureNotSupportedFault" => {
-                        return RusotoError::Service(
-                            CreateSnapshotError::SnapshotFeatureNotSupportedFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateSnapshotError::SnapshotFeatureNotSupportedFault,
                         )
                     }
                     "SnapshotQuotaExceededFault" => {
-                        return RusotoError::Service(
-                            CreateSnapshotError::SnapshotQuotaExceededFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateSnapshotError::SnapshotQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -11216,29 +11285,44 @@ impl CreateUserError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DuplicateUserName" => {
-                        return RusotoError::Service(CreateUserError::DuplicateUserNameFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateUserError::DuplicateUserNameFault,
+                        )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(CreateUserError::InvalidParameterCombination(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateUserError::InvalidParameterCombination,
+                        )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(CreateUserError::InvalidParameterValue(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateUserError::InvalidParameterValue,
+                        )
                     }
                     "UserAlreadyExists" => {
-                        return RusotoError::Service(CreateUserError::UserAlreadyExistsFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateUserError::UserAlreadyExistsFault,
+                        )
                     }
                     "UserQuotaExceeded" => {
-                        return RusotoError::Service(CreateUserError::UserQuotaExceededFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateUserError::UserQuotaExceededFault,
+                        )
                     }
                     _ => {}
                 }
@@ -11294,34 +11378,52 @@ impl CreateUserGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DefaultUserRequired" => {
-                        return RusotoError::Service(CreateUserGroupError::DefaultUserRequired(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateUserGroupError::DefaultUserRequired,
+                        )
                     }
                     "DuplicateUserName" => {
-                        return RusotoError::Service(CreateUserGroupError::DuplicateUserNameFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateUserGroupError::DuplicateUserNameFault,
+                        )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(CreateUserGroupError::InvalidParameterValue(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateUserGroupError::InvalidParameterValue,
+                        )
                     }
                     "UserGroupAlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateUserGroupError::UserGroupAlreadyExistsFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateUserGroupError::UserGroupAlreadyExistsFault,
                         )
                     }
                     "UserGroupQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CreateUserGroupError::UserGroupQuotaExceededFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateUserGroupError::UserGroupQuotaExceededFault,
                         )
                     }
                     "UserNotFound" => {
-                        return RusotoError::Service(CreateUserGroupError::UserNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateUserGroupError::UserNotFoundFault,
+                        )
                     }
                     _ => {}
                 }
@@ -11375,7 +11477,7 @@ impl DecreaseNodeGroupsInGlobalReplicationGroupError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "GlobalReplicationGroupNotFoundFault" => return RusotoError::Service(DecreaseNodeGroupsInGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(parsed_error.message)),"InvalidGlobalReplicationGroupState" => return RusotoError::Service(DecreaseNodeGroupsInGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(parsed_error.message)),"InvalidParameterCombination" => return RusotoError::Service(DecreaseNodeGroupsInGlobalReplicationGroupError::InvalidParameterCombination(parsed_error.message)),"InvalidParameterValue" => return RusotoError::Service(DecreaseNodeGroupsInGlobalReplicationGroupError::InvalidParameterValue(parsed_error.message)),_ => {}
+                                    "GlobalReplicationGroupNotFoundFault" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, DecreaseNodeGroupsInGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault),"InvalidGlobalReplicationGroupState" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, DecreaseNodeGroupsInGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault),"InvalidParameterCombination" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, DecreaseNodeGroupsInGlobalReplicationGroupError::InvalidParameterCombination),"InvalidParameterValue" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, DecreaseNodeGroupsInGlobalReplicationGroupError::InvalidParameterValue),_ => {}
                                 }
             }
         }
@@ -11440,82 +11542,38 @@ impl DecreaseReplicaCountError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterQuotaForCustomerExceeded" => {
-                        return RusotoError::Service(
-                            DecreaseReplicaCountError::ClusterQuotaForCustomerExceededFault(
-                                parsed_error.message,
-                            ),
-                        )
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, DecreaseReplicaCountError::ClusterQuotaForCustomerExceededFault)
                     }
                     "InsufficientCacheClusterCapacity" => {
-                        return RusotoError::Service(
-                            DecreaseReplicaCountError::InsufficientCacheClusterCapacityFault(
-                                parsed_error.message,
-                            ),
-                        )
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, DecreaseReplicaCountError::InsufficientCacheClusterCapacityFault)
                     }
                     "InvalidCacheClusterState" => {
-                        return RusotoError::Service(
-                            DecreaseReplicaCountError::InvalidCacheClusterStateFault(
-                                parsed_error.message,
-                            ),
-                        )
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, DecreaseReplicaCountError::InvalidCacheClusterStateFault)
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
-                            DecreaseReplicaCountError::InvalidParameterCombination(
-                                parsed_error.message,
-                            ),
-                        )
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, DecreaseReplicaCountError::InvalidParameterCombination)
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
-                            DecreaseReplicaCountError::InvalidParameterValue(parsed_error.message),
-                        )
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, DecreaseReplicaCountError::InvalidParameterValue)
                     }
                     "InvalidReplicationGroupState" => {
-                        return RusotoError::Service(
-                            DecreaseReplicaCountError::InvalidReplicationGroupStateFault(
-                                parsed_error.message,
-                            ),
-                        )
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, DecreaseReplicaCountError::InvalidReplicationGroupStateFault)
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
-                            DecreaseReplicaCountError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
-                            ),
-                        )
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, DecreaseReplicaCountError::InvalidVPCNetworkStateFault)
                     }
                     "NoOperationFault" => {
-                        return RusotoError::Service(DecreaseReplicaCountError::NoOperationFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, DecreaseReplicaCountError::NoOperationFault)
                     }
-                    "NodeGroupsPerReplicationGroupQuotaExceeded" => return RusotoError::Service(
-                        DecreaseReplicaCountError::NodeGroupsPerReplicationGroupQuotaExceededFault(
-                            parsed_error.message,
-                        ),
-                    ),
+                    "NodeGroupsPerReplicationGroupQuotaExceeded" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, DecreaseReplicaCountError::NodeGroupsPerReplicationGroupQuotaExceededFault),
                     "NodeQuotaForCustomerExceeded" => {
-                        return RusotoError::Service(
-                            DecreaseReplicaCountError::NodeQuotaForCustomerExceededFault(
-                                parsed_error.message,
-                            ),
-                        )
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, DecreaseReplicaCountError::NodeQuotaForCustomerExceededFault)
                     }
                     "ReplicationGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            DecreaseReplicaCountError::ReplicationGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
-                        )
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, DecreaseReplicaCountError::ReplicationGroupNotFoundFault)
                     }
                     "ServiceLinkedRoleNotFoundFault" => {
-                        return RusotoError::Service(
-                            DecreaseReplicaCountError::ServiceLinkedRoleNotFoundFault(
-                                parsed_error.message,
-                            ),
-                        )
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, DecreaseReplicaCountError::ServiceLinkedRoleNotFoundFault)
                     }
                     _ => {}
                 }
@@ -11600,50 +11658,59 @@ impl DeleteCacheClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheClusterNotFound" => {
-                        return RusotoError::Service(
-                            DeleteCacheClusterError::CacheClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteCacheClusterError::CacheClusterNotFoundFault,
                         )
                     }
                     "InvalidCacheClusterState" => {
-                        return RusotoError::Service(
-                            DeleteCacheClusterError::InvalidCacheClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteCacheClusterError::InvalidCacheClusterStateFault,
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
-                            DeleteCacheClusterError::InvalidParameterCombination(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteCacheClusterError::InvalidParameterCombination,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
-                            DeleteCacheClusterError::InvalidParameterValue(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteCacheClusterError::InvalidParameterValue,
                         )
                     }
                     "SnapshotAlreadyExistsFault" => {
-                        return RusotoError::Service(
-                            DeleteCacheClusterError::SnapshotAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteCacheClusterError::SnapshotAlreadyExistsFault,
                         )
                     }
                     "SnapshotFeatureNotSupportedFault" => {
-                        return RusotoError::Service(
-                            DeleteCacheClusterError::SnapshotFeatureNotSupportedFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteCacheClusterError::SnapshotFeatureNotSupportedFault,
                         )
                     }
                     "SnapshotQuotaExceededFault" => {
-                        return RusotoError::Service(
-                            DeleteCacheClusterError::SnapshotQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteCacheClusterError::SnapshotQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -11708,31 +11775,35 @@ impl DeleteCacheParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            DeleteCacheParameterGroupError::CacheParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteCacheParameterGroupError::CacheParameterGroupNotFoundFault,
                         )
                     }
                     "InvalidCacheParameterGroupState" => {
-                        return RusotoError::Service(
-                            DeleteCacheParameterGroupError::InvalidCacheParameterGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteCacheParameterGroupError::InvalidCacheParameterGroupStateFault,
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
-                            DeleteCacheParameterGroupError::InvalidParameterCombination(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteCacheParameterGroupError::InvalidParameterCombination,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
-                            DeleteCacheParameterGroupError::InvalidParameterValue(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteCacheParameterGroupError::InvalidParameterValue,
                         )
                     }
                     _ => {}
@@ -11792,31 +11863,35 @@ impl DeleteCacheSecurityGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheSecurityGroupNotFound" => {
-                        return RusotoError::Service(
-                            DeleteCacheSecurityGroupError::CacheSecurityGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteCacheSecurityGroupError::CacheSecurityGroupNotFoundFault,
                         )
                     }
                     "InvalidCacheSecurityGroupState" => {
-                        return RusotoError::Service(
-                            DeleteCacheSecurityGroupError::InvalidCacheSecurityGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteCacheSecurityGroupError::InvalidCacheSecurityGroupStateFault,
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
-                            DeleteCacheSecurityGroupError::InvalidParameterCombination(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteCacheSecurityGroupError::InvalidParameterCombination,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
-                            DeleteCacheSecurityGroupError::InvalidParameterValue(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteCacheSecurityGroupError::InvalidParameterValue,
                         )
                     }
                     _ => {}
@@ -11872,17 +11947,19 @@ impl DeleteCacheSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheSubnetGroupInUse" => {
-                        return RusotoError::Service(
-                            DeleteCacheSubnetGroupError::CacheSubnetGroupInUse(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteCacheSubnetGroupError::CacheSubnetGroupInUse,
                         )
                     }
                     "CacheSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            DeleteCacheSubnetGroupError::CacheSubnetGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteCacheSubnetGroupError::CacheSubnetGroupNotFoundFault,
                         )
                     }
                     _ => {}
@@ -11934,22 +12011,25 @@ impl DeleteGlobalReplicationGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "GlobalReplicationGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            DeleteGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault,
                         )
                     }
-                    "InvalidGlobalReplicationGroupState" => return RusotoError::Service(
-                        DeleteGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(
-                            parsed_error.message,
-                        ),
+                    "InvalidGlobalReplicationGroupState" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        DeleteGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault,
                     ),
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
-                            DeleteGlobalReplicationGroupError::InvalidParameterValue(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteGlobalReplicationGroupError::InvalidParameterValue,
                         )
                     }
                     _ => {}
@@ -12012,52 +12092,59 @@ impl DeleteReplicationGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
-                            DeleteReplicationGroupError::InvalidParameterCombination(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteReplicationGroupError::InvalidParameterCombination,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
-                            DeleteReplicationGroupError::InvalidParameterValue(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteReplicationGroupError::InvalidParameterValue,
                         )
                     }
                     "InvalidReplicationGroupState" => {
-                        return RusotoError::Service(
-                            DeleteReplicationGroupError::InvalidReplicationGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteReplicationGroupError::InvalidReplicationGroupStateFault,
                         )
                     }
                     "ReplicationGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            DeleteReplicationGroupError::ReplicationGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteReplicationGroupError::ReplicationGroupNotFoundFault,
                         )
                     }
                     "SnapshotAlreadyExistsFault" => {
-                        return RusotoError::Service(
-                            DeleteReplicationGroupError::SnapshotAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteReplicationGroupError::SnapshotAlreadyExistsFault,
                         )
                     }
                     "SnapshotFeatureNotSupportedFault" => {
-                        return RusotoError::Service(
-                            DeleteReplicationGroupError::SnapshotFeatureNotSupportedFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteReplicationGroupError::SnapshotFeatureNotSupportedFault,
                         )
                     }
                     "SnapshotQuotaExceededFault" => {
-                        return RusotoError::Service(
-                            DeleteReplicationGroupError::SnapshotQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteReplicationGroupError::SnapshotQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -12124,24 +12211,36 @@ impl DeleteSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
-                            DeleteSnapshotError::InvalidParameterCombination(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteSnapshotError::InvalidParameterCombination,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(DeleteSnapshotError::InvalidParameterValue(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteSnapshotError::InvalidParameterValue,
+                        )
                     }
                     "InvalidSnapshotState" => {
-                        return RusotoError::Service(
-                            DeleteSnapshotError::InvalidSnapshotStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteSnapshotError::InvalidSnapshotStateFault,
                         )
                     }
                     "SnapshotNotFoundFault" => {
-                        return RusotoError::Service(DeleteSnapshotError::SnapshotNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteSnapshotError::SnapshotNotFoundFault,
+                        )
                     }
                     _ => {}
                 }
@@ -12192,26 +12291,36 @@ impl DeleteUserError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DefaultUserAssociatedToUserGroup" => {
-                        return RusotoError::Service(
-                            DeleteUserError::DefaultUserAssociatedToUserGroupFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteUserError::DefaultUserAssociatedToUserGroupFault,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(DeleteUserError::InvalidParameterValue(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteUserError::InvalidParameterValue,
+                        )
                     }
                     "InvalidUserState" => {
-                        return RusotoError::Service(DeleteUserError::InvalidUserStateFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteUserError::InvalidUserStateFault,
+                        )
                     }
                     "UserNotFound" => {
-                        return RusotoError::Service(DeleteUserError::UserNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteUserError::UserNotFoundFault,
+                        )
                     }
                     _ => {}
                 }
@@ -12262,19 +12371,28 @@ impl DeleteUserGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(DeleteUserGroupError::InvalidParameterValue(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteUserGroupError::InvalidParameterValue,
+                        )
                     }
                     "InvalidUserGroupState" => {
-                        return RusotoError::Service(
-                            DeleteUserGroupError::InvalidUserGroupStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteUserGroupError::InvalidUserGroupStateFault,
                         )
                     }
                     "UserGroupNotFound" => {
-                        return RusotoError::Service(DeleteUserGroupError::UserGroupNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteUserGroupError::UserGroupNotFoundFault,
+                        )
                     }
                     _ => {}
                 }
@@ -12322,22 +12440,27 @@ impl DescribeCacheClustersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheClusterNotFound" => {
-                        return RusotoError::Service(
-                            DescribeCacheClustersError::CacheClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeCacheClustersError::CacheClusterNotFoundFault,
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
-                            DescribeCacheClustersError::InvalidParameterCombination(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeCacheClustersError::InvalidParameterCombination,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
-                            DescribeCacheClustersError::InvalidParameterValue(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeCacheClustersError::InvalidParameterValue,
                         )
                     }
                     _ => {}
@@ -12428,24 +12551,27 @@ impl DescribeCacheParameterGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            DescribeCacheParameterGroupsError::CacheParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeCacheParameterGroupsError::CacheParameterGroupNotFoundFault,
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
-                            DescribeCacheParameterGroupsError::InvalidParameterCombination(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeCacheParameterGroupsError::InvalidParameterCombination,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
-                            DescribeCacheParameterGroupsError::InvalidParameterValue(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeCacheParameterGroupsError::InvalidParameterValue,
                         )
                     }
                     _ => {}
@@ -12500,24 +12626,27 @@ impl DescribeCacheParametersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            DescribeCacheParametersError::CacheParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeCacheParametersError::CacheParameterGroupNotFoundFault,
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
-                            DescribeCacheParametersError::InvalidParameterCombination(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeCacheParametersError::InvalidParameterCombination,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
-                            DescribeCacheParametersError::InvalidParameterValue(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeCacheParametersError::InvalidParameterValue,
                         )
                     }
                     _ => {}
@@ -12574,24 +12703,27 @@ impl DescribeCacheSecurityGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheSecurityGroupNotFound" => {
-                        return RusotoError::Service(
-                            DescribeCacheSecurityGroupsError::CacheSecurityGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeCacheSecurityGroupsError::CacheSecurityGroupNotFoundFault,
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
-                            DescribeCacheSecurityGroupsError::InvalidParameterCombination(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeCacheSecurityGroupsError::InvalidParameterCombination,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
-                            DescribeCacheSecurityGroupsError::InvalidParameterValue(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeCacheSecurityGroupsError::InvalidParameterValue,
                         )
                     }
                     _ => {}
@@ -12642,10 +12774,11 @@ impl DescribeCacheSubnetGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            DescribeCacheSubnetGroupsError::CacheSubnetGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeCacheSubnetGroupsError::CacheSubnetGroupNotFoundFault,
                         )
                     }
                     _ => {}
@@ -12694,17 +12827,19 @@ impl DescribeEngineDefaultParametersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
-                            DescribeEngineDefaultParametersError::InvalidParameterCombination(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeEngineDefaultParametersError::InvalidParameterCombination,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
-                            DescribeEngineDefaultParametersError::InvalidParameterValue(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeEngineDefaultParametersError::InvalidParameterValue,
                         )
                     }
                     _ => {}
@@ -12754,14 +12889,20 @@ impl DescribeEventsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
-                            DescribeEventsError::InvalidParameterCombination(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeEventsError::InvalidParameterCombination,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(DescribeEventsError::InvalidParameterValue(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeEventsError::InvalidParameterValue,
+                        )
                     }
                     _ => {}
                 }
@@ -12809,23 +12950,26 @@ impl DescribeGlobalReplicationGroupsError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                    "GlobalReplicationGroupNotFoundFault" => return RusotoError::Service(
-                        DescribeGlobalReplicationGroupsError::GlobalReplicationGroupNotFoundFault(
-                            parsed_error.message,
-                        ),
+                    "GlobalReplicationGroupNotFoundFault" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        DescribeGlobalReplicationGroupsError::GlobalReplicationGroupNotFoundFault,
                     ),
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
-                            DescribeGlobalReplicationGroupsError::InvalidParameterCombination(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeGlobalReplicationGroupsError::InvalidParameterCombination,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
-                            DescribeGlobalReplicationGroupsError::InvalidParameterValue(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeGlobalReplicationGroupsError::InvalidParameterValue,
                         )
                     }
                     _ => {}
@@ -12880,24 +13024,27 @@ impl DescribeReplicationGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
-                            DescribeReplicationGroupsError::InvalidParameterCombination(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeReplicationGroupsError::InvalidParameterCombination,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
-                            DescribeReplicationGroupsError::InvalidParameterValue(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeReplicationGroupsError::InvalidParameterValue,
                         )
                     }
                     "ReplicationGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            DescribeReplicationGroupsError::ReplicationGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeReplicationGroupsError::ReplicationGroupNotFoundFault,
                         )
                     }
                     _ => {}
@@ -12954,24 +13101,27 @@ impl DescribeReservedCacheNodesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
-                            DescribeReservedCacheNodesError::InvalidParameterCombination(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeReservedCacheNodesError::InvalidParameterCombination,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
-                            DescribeReservedCacheNodesError::InvalidParameterValue(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeReservedCacheNodesError::InvalidParameterValue,
                         )
                     }
                     "ReservedCacheNodeNotFound" => {
-                        return RusotoError::Service(
-                            DescribeReservedCacheNodesError::ReservedCacheNodeNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeReservedCacheNodesError::ReservedCacheNodeNotFoundFault,
                         )
                     }
                     _ => {}
@@ -13027,7 +13177,7 @@ impl DescribeReservedCacheNodesOfferingsError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "InvalidParameterCombination" => return RusotoError::Service(DescribeReservedCacheNodesOfferingsError::InvalidParameterCombination(parsed_error.message)),"InvalidParameterValue" => return RusotoError::Service(DescribeReservedCacheNodesOfferingsError::InvalidParameterValue(parsed_error.message)),"ReservedCacheNodesOfferingNotFound" => return RusotoError::Service(DescribeReservedCacheNodesOfferingsError::ReservedCacheNodesOfferingNotFoundFault(parsed_error.message)),_ => {}
+                                    "InvalidParameterCombination" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, DescribeReservedCacheNodesOfferingsError::InvalidParameterCombination),"InvalidParameterValue" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, DescribeReservedCacheNodesOfferingsError::InvalidParameterValue),"ReservedCacheNodesOfferingNotFound" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, DescribeReservedCacheNodesOfferingsError::ReservedCacheNodesOfferingNotFoundFault),_ => {}
                                 }
             }
         }
@@ -13079,24 +13229,27 @@ impl DescribeServiceUpdatesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
-                            DescribeServiceUpdatesError::InvalidParameterCombination(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeServiceUpdatesError::InvalidParameterCombination,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
-                            DescribeServiceUpdatesError::InvalidParameterValue(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeServiceUpdatesError::InvalidParameterValue,
                         )
                     }
                     "ServiceUpdateNotFoundFault" => {
-                        return RusotoError::Service(
-                            DescribeServiceUpdatesError::ServiceUpdateNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeServiceUpdatesError::ServiceUpdateNotFoundFault,
                         )
                     }
                     _ => {}
@@ -13151,26 +13304,36 @@ impl DescribeSnapshotsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheClusterNotFound" => {
-                        return RusotoError::Service(
-                            DescribeSnapshotsError::CacheClusterNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeSnapshotsError::CacheClusterNotFoundFault,
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
-                            DescribeSnapshotsError::InvalidParameterCombination(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeSnapshotsError::InvalidParameterCombination,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(DescribeSnapshotsError::InvalidParameterValue(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeSnapshotsError::InvalidParameterValue,
+                        )
                     }
                     "SnapshotNotFoundFault" => {
-                        return RusotoError::Service(DescribeSnapshotsError::SnapshotNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeSnapshotsError::SnapshotNotFoundFault,
+                        )
                     }
                     _ => {}
                 }
@@ -13219,15 +13382,19 @@ impl DescribeUpdateActionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
-                            DescribeUpdateActionsError::InvalidParameterCombination(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeUpdateActionsError::InvalidParameterCombination,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
-                            DescribeUpdateActionsError::InvalidParameterValue(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeUpdateActionsError::InvalidParameterValue,
                         )
                     }
                     _ => {}
@@ -13275,15 +13442,19 @@ impl DescribeUserGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
-                            DescribeUserGroupsError::InvalidParameterCombination(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeUserGroupsError::InvalidParameterCombination,
                         )
                     }
                     "UserGroupNotFound" => {
-                        return RusotoError::Service(
-                            DescribeUserGroupsError::UserGroupNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeUserGroupsError::UserGroupNotFoundFault,
                         )
                     }
                     _ => {}
@@ -13331,14 +13502,20 @@ impl DescribeUsersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
-                            DescribeUsersError::InvalidParameterCombination(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeUsersError::InvalidParameterCombination,
                         )
                     }
                     "UserNotFound" => {
-                        return RusotoError::Service(DescribeUsersError::UserNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeUsersError::UserNotFoundFault,
+                        )
                     }
                     _ => {}
                 }
@@ -13388,7 +13565,7 @@ impl DisassociateGlobalReplicationGroupError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "GlobalReplicationGroupNotFoundFault" => return RusotoError::Service(DisassociateGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(parsed_error.message)),"InvalidGlobalReplicationGroupState" => return RusotoError::Service(DisassociateGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(parsed_error.message)),"InvalidParameterCombination" => return RusotoError::Service(DisassociateGlobalReplicationGroupError::InvalidParameterCombination(parsed_error.message)),"InvalidParameterValue" => return RusotoError::Service(DisassociateGlobalReplicationGroupError::InvalidParameterValue(parsed_error.message)),_ => {}
+                                    "GlobalReplicationGroupNotFoundFault" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, DisassociateGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault),"InvalidGlobalReplicationGroupState" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, DisassociateGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault),"InvalidParameterCombination" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, DisassociateGlobalReplicationGroupError::InvalidParameterCombination),"InvalidParameterValue" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, DisassociateGlobalReplicationGroupError::InvalidParameterValue),_ => {}
                                 }
             }
         }
@@ -13446,7 +13623,7 @@ impl FailoverGlobalReplicationGroupError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "GlobalReplicationGroupNotFoundFault" => return RusotoError::Service(FailoverGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(parsed_error.message)),"InvalidGlobalReplicationGroupState" => return RusotoError::Service(FailoverGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(parsed_error.message)),"InvalidParameterCombination" => return RusotoError::Service(FailoverGlobalReplicationGroupError::InvalidParameterCombination(parsed_error.message)),"InvalidParameterValue" => return RusotoError::Service(FailoverGlobalReplicationGroupError::InvalidParameterValue(parsed_error.message)),_ => {}
+                                    "GlobalReplicationGroupNotFoundFault" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, FailoverGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault),"InvalidGlobalReplicationGroupState" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, FailoverGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault),"InvalidParameterCombination" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, FailoverGlobalReplicationGroupError::InvalidParameterCombination),"InvalidParameterValue" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, FailoverGlobalReplicationGroupError::InvalidParameterValue),_ => {}
                                 }
             }
         }
@@ -13502,7 +13679,7 @@ impl IncreaseNodeGroupsInGlobalReplicationGroupError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "GlobalReplicationGroupNotFoundFault" => return RusotoError::Service(IncreaseNodeGroupsInGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(parsed_error.message)),"InvalidGlobalReplicationGroupState" => return RusotoError::Service(IncreaseNodeGroupsInGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(parsed_error.message)),"InvalidParameterValue" => return RusotoError::Service(IncreaseNodeGroupsInGlobalReplicationGroupError::InvalidParameterValue(parsed_error.message)),_ => {}
+                                    "GlobalReplicationGroupNotFoundFault" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, IncreaseNodeGroupsInGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault),"InvalidGlobalReplicationGroupState" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, IncreaseNodeGroupsInGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault),"InvalidParameterValue" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, IncreaseNodeGroupsInGlobalReplicationGroupError::InvalidParameterValue),_ => {}
                                 }
             }
         }
@@ -13566,80 +13743,38 @@ impl IncreaseReplicaCountError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterQuotaForCustomerExceeded" => {
-                        return RusotoError::Service(
-                            IncreaseReplicaCountError::ClusterQuotaForCustomerExceededFault(
-                                parsed_error.message,
-                            ),
-                        )
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, IncreaseReplicaCountError::ClusterQuotaForCustomerExceededFault)
                     }
                     "InsufficientCacheClusterCapacity" => {
-                        return RusotoError::Service(
-                            IncreaseReplicaCountError::InsufficientCacheClusterCapacityFault(
-                                parsed_error.message,
-                            ),
-                        )
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, IncreaseReplicaCountError::InsufficientCacheClusterCapacityFault)
                     }
                     "InvalidCacheClusterState" => {
-                        return RusotoError::Service(
-                            IncreaseReplicaCountError::InvalidCacheClusterStateFault(
-                                parsed_error.message,
-                            ),
-                        )
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, IncreaseReplicaCountError::InvalidCacheClusterStateFault)
                     }
                     "InvalidKMSKeyFault" => {
-                        return RusotoError::Service(IncreaseReplicaCountError::InvalidKMSKeyFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, IncreaseReplicaCountError::InvalidKMSKeyFault)
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
-                            IncreaseReplicaCountError::InvalidParameterCombination(
-                                parsed_error.message,
-                            ),
-                        )
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, IncreaseReplicaCountError::InvalidParameterCombination)
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
-                            IncreaseReplicaCountError::InvalidParameterValue(parsed_error.message),
-                        )
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, IncreaseReplicaCountError::InvalidParameterValue)
                     }
                     "InvalidReplicationGroupState" => {
-                        return RusotoError::Service(
-                            IncreaseReplicaCountError::InvalidReplicationGroupStateFault(
-                                parsed_error.message,
-                            ),
-                        )
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, IncreaseReplicaCountError::InvalidReplicationGroupStateFault)
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
-                            IncreaseReplicaCountError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
-                            ),
-                        )
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, IncreaseReplicaCountError::InvalidVPCNetworkStateFault)
                     }
                     "NoOperationFault" => {
-                        return RusotoError::Service(IncreaseReplicaCountError::NoOperationFault(
-                            parsed_error.message,
-                        ))
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, IncreaseReplicaCountError::NoOperationFault)
                     }
-                    "NodeGroupsPerReplicationGroupQuotaExceeded" => return RusotoError::Service(
-                        IncreaseReplicaCountError::NodeGroupsPerReplicationGroupQuotaExceededFault(
-                            parsed_error.message,
-                        ),
-                    ),
+                    "NodeGroupsPerReplicationGroupQuotaExceeded" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, IncreaseReplicaCountError::NodeGroupsPerReplicationGroupQuotaExceededFault),
                     "NodeQuotaForCustomerExceeded" => {
-                        return RusotoError::Service(
-                            IncreaseReplicaCountError::NodeQuotaForCustomerExceededFault(
-                                parsed_error.message,
-                            ),
-                        )
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, IncreaseReplicaCountError::NodeQuotaForCustomerExceededFault)
                     }
                     "ReplicationGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            IncreaseReplicaCountError::ReplicationGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
-                        )
+                        return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, IncreaseReplicaCountError::ReplicationGroupNotFoundFault)
                     }
                     _ => {}
                 }
@@ -13718,31 +13853,35 @@ impl ListAllowedNodeTypeModificationsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheClusterNotFound" => {
-                        return RusotoError::Service(
-                            ListAllowedNodeTypeModificationsError::CacheClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListAllowedNodeTypeModificationsError::CacheClusterNotFoundFault,
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
-                            ListAllowedNodeTypeModificationsError::InvalidParameterCombination(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListAllowedNodeTypeModificationsError::InvalidParameterCombination,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
-                            ListAllowedNodeTypeModificationsError::InvalidParameterValue(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListAllowedNodeTypeModificationsError::InvalidParameterValue,
                         )
                     }
                     "ReplicationGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            ListAllowedNodeTypeModificationsError::ReplicationGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListAllowedNodeTypeModificationsError::ReplicationGroupNotFoundFault,
                         )
                     }
                     _ => {}
@@ -13800,20 +13939,27 @@ impl ListTagsForResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheClusterNotFound" => {
-                        return RusotoError::Service(
-                            ListTagsForResourceError::CacheClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListTagsForResourceError::CacheClusterNotFoundFault,
                         )
                     }
                     "InvalidARN" => {
-                        return RusotoError::Service(ListTagsForResourceError::InvalidARNFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListTagsForResourceError::InvalidARNFault,
+                        )
                     }
                     "SnapshotNotFoundFault" => {
-                        return RusotoError::Service(
-                            ListTagsForResourceError::SnapshotNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListTagsForResourceError::SnapshotNotFoundFault,
                         )
                     }
                     _ => {}
@@ -13880,78 +14026,91 @@ impl ModifyCacheClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheClusterNotFound" => {
-                        return RusotoError::Service(
-                            ModifyCacheClusterError::CacheClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyCacheClusterError::CacheClusterNotFoundFault,
                         )
                     }
                     "CacheParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            ModifyCacheClusterError::CacheParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyCacheClusterError::CacheParameterGroupNotFoundFault,
                         )
                     }
                     "CacheSecurityGroupNotFound" => {
-                        return RusotoError::Service(
-                            ModifyCacheClusterError::CacheSecurityGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyCacheClusterError::CacheSecurityGroupNotFoundFault,
                         )
                     }
                     "InsufficientCacheClusterCapacity" => {
-                        return RusotoError::Service(
-                            ModifyCacheClusterError::InsufficientCacheClusterCapacityFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyCacheClusterError::InsufficientCacheClusterCapacityFault,
                         )
                     }
                     "InvalidCacheClusterState" => {
-                        return RusotoError::Service(
-                            ModifyCacheClusterError::InvalidCacheClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyCacheClusterError::InvalidCacheClusterStateFault,
                         )
                     }
                     "InvalidCacheSecurityGroupState" => {
-                        return RusotoError::Service(
-                            ModifyCacheClusterError::InvalidCacheSecurityGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyCacheClusterError::InvalidCacheSecurityGroupStateFault,
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
-                            ModifyCacheClusterError::InvalidParameterCombination(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyCacheClusterError::InvalidParameterCombination,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
-                            ModifyCacheClusterError::InvalidParameterValue(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyCacheClusterError::InvalidParameterValue,
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
-                            ModifyCacheClusterError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyCacheClusterError::InvalidVPCNetworkStateFault,
                         )
                     }
                     "NodeQuotaForClusterExceeded" => {
-                        return RusotoError::Service(
-                            ModifyCacheClusterError::NodeQuotaForClusterExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyCacheClusterError::NodeQuotaForClusterExceededFault,
                         )
                     }
                     "NodeQuotaForCustomerExceeded" => {
-                        return RusotoError::Service(
-                            ModifyCacheClusterError::NodeQuotaForCustomerExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyCacheClusterError::NodeQuotaForCustomerExceededFault,
                         )
                     }
                     _ => {}
@@ -14030,38 +14189,43 @@ impl ModifyCacheParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            ModifyCacheParameterGroupError::CacheParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyCacheParameterGroupError::CacheParameterGroupNotFoundFault,
                         )
                     }
                     "InvalidCacheParameterGroupState" => {
-                        return RusotoError::Service(
-                            ModifyCacheParameterGroupError::InvalidCacheParameterGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyCacheParameterGroupError::InvalidCacheParameterGroupStateFault,
                         )
                     }
                     "InvalidGlobalReplicationGroupState" => {
-                        return RusotoError::Service(
-                            ModifyCacheParameterGroupError::InvalidGlobalReplicationGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyCacheParameterGroupError::InvalidGlobalReplicationGroupStateFault,
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
-                            ModifyCacheParameterGroupError::InvalidParameterCombination(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyCacheParameterGroupError::InvalidParameterCombination,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
-                            ModifyCacheParameterGroupError::InvalidParameterValue(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyCacheParameterGroupError::InvalidParameterValue,
                         )
                     }
                     _ => {}
@@ -14126,34 +14290,43 @@ impl ModifyCacheSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            ModifyCacheSubnetGroupError::CacheSubnetGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyCacheSubnetGroupError::CacheSubnetGroupNotFoundFault,
                         )
                     }
                     "CacheSubnetQuotaExceededFault" => {
-                        return RusotoError::Service(
-                            ModifyCacheSubnetGroupError::CacheSubnetQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyCacheSubnetGroupError::CacheSubnetQuotaExceededFault,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(ModifyCacheSubnetGroupError::InvalidSubnet(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyCacheSubnetGroupError::InvalidSubnet,
+                        )
                     }
                     "SubnetInUse" => {
-                        return RusotoError::Service(ModifyCacheSubnetGroupError::SubnetInUse(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyCacheSubnetGroupError::SubnetInUse,
+                        )
                     }
                     "SubnetNotAllowedFault" => {
-                        return RusotoError::Service(
-                            ModifyCacheSubnetGroupError::SubnetNotAllowedFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyCacheSubnetGroupError::SubnetNotAllowedFault,
                         )
                     }
                     _ => {}
@@ -14210,22 +14383,25 @@ impl ModifyGlobalReplicationGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "GlobalReplicationGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            ModifyGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault,
                         )
                     }
-                    "InvalidGlobalReplicationGroupState" => return RusotoError::Service(
-                        ModifyGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(
-                            parsed_error.message,
-                        ),
+                    "InvalidGlobalReplicationGroupState" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        ModifyGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault,
                     ),
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
-                            ModifyGlobalReplicationGroupError::InvalidParameterValue(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyGlobalReplicationGroupError::InvalidParameterValue,
                         )
                     }
                     _ => {}
@@ -14306,113 +14482,131 @@ impl ModifyReplicationGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheClusterNotFound" => {
-                        return RusotoError::Service(
-                            ModifyReplicationGroupError::CacheClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyReplicationGroupError::CacheClusterNotFoundFault,
                         )
                     }
                     "CacheParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            ModifyReplicationGroupError::CacheParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyReplicationGroupError::CacheParameterGroupNotFoundFault,
                         )
                     }
                     "CacheSecurityGroupNotFound" => {
-                        return RusotoError::Service(
-                            ModifyReplicationGroupError::CacheSecurityGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyReplicationGroupError::CacheSecurityGroupNotFoundFault,
                         )
                     }
                     "InsufficientCacheClusterCapacity" => {
-                        return RusotoError::Service(
-                            ModifyReplicationGroupError::InsufficientCacheClusterCapacityFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyReplicationGroupError::InsufficientCacheClusterCapacityFault,
                         )
                     }
                     "InvalidCacheClusterState" => {
-                        return RusotoError::Service(
-                            ModifyReplicationGroupError::InvalidCacheClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyReplicationGroupError::InvalidCacheClusterStateFault,
                         )
                     }
                     "InvalidCacheSecurityGroupState" => {
-                        return RusotoError::Service(
-                            ModifyReplicationGroupError::InvalidCacheSecurityGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyReplicationGroupError::InvalidCacheSecurityGroupStateFault,
                         )
                     }
                     "InvalidKMSKeyFault" => {
-                        return RusotoError::Service(
-                            ModifyReplicationGroupError::InvalidKMSKeyFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyReplicationGroupError::InvalidKMSKeyFault,
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
-                            ModifyReplicationGroupError::InvalidParameterCombination(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyReplicationGroupError::InvalidParameterCombination,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
-                            ModifyReplicationGroupError::InvalidParameterValue(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyReplicationGroupError::InvalidParameterValue,
                         )
                     }
                     "InvalidReplicationGroupState" => {
-                        return RusotoError::Service(
-                            ModifyReplicationGroupError::InvalidReplicationGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyReplicationGroupError::InvalidReplicationGroupStateFault,
                         )
                     }
                     "InvalidUserGroupState" => {
-                        return RusotoError::Service(
-                            ModifyReplicationGroupError::InvalidUserGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyReplicationGroupError::InvalidUserGroupStateFault,
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
-                            ModifyReplicationGroupError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyReplicationGroupError::InvalidVPCNetworkStateFault,
                         )
                     }
                     "NodeQuotaForClusterExceeded" => {
-                        return RusotoError::Service(
-                            ModifyReplicationGroupError::NodeQuotaForClusterExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyReplicationGroupError::NodeQuotaForClusterExceededFault,
                         )
                     }
                     "NodeQuotaForCustomerExceeded" => {
-                        return RusotoError::Service(
-                            ModifyReplicationGroupError::NodeQuotaForCustomerExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyReplicationGroupError::NodeQuotaForCustomerExceededFault,
                         )
                     }
                     "ReplicationGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            ModifyReplicationGroupError::ReplicationGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyReplicationGroupError::ReplicationGroupNotFoundFault,
                         )
                     }
                     "UserGroupNotFound" => {
-                        return RusotoError::Service(
-                            ModifyReplicationGroupError::UserGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyReplicationGroupError::UserGroupNotFoundFault,
                         )
                     }
                     _ => {}
@@ -14517,7 +14711,7 @@ impl ModifyReplicationGroupShardConfigurationError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "InsufficientCacheClusterCapacity" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::InsufficientCacheClusterCapacityFault(parsed_error.message)),"InvalidCacheClusterState" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::InvalidCacheClusterStateFault(parsed_error.message)),"InvalidKMSKeyFault" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::InvalidKMSKeyFault(parsed_error.message)),"InvalidParameterCombination" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::InvalidParameterCombination(parsed_error.message)),"InvalidParameterValue" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::InvalidParameterValue(parsed_error.message)),"InvalidReplicationGroupState" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::InvalidReplicationGroupStateFault(parsed_error.message)),"InvalidVPCNetworkStateFault" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::InvalidVPCNetworkStateFault(parsed_error.message)),"NodeGroupsPerReplicationGroupQuotaExceeded" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::NodeGroupsPerReplicationGroupQuotaExceededFault(parsed_error.message)),"NodeQuotaForCustomerExceeded" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::NodeQuotaForCustomerExceededFault(parsed_error.message)),"ReplicationGroupNotFoundFault" => return RusotoError::Service(ModifyReplicationGroupShardConfigurationError::ReplicationGroupNotFoundFault(parsed_error.message)),_ => {}
+                                    "InsufficientCacheClusterCapacity" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, ModifyReplicationGroupShardConfigurationError::InsufficientCacheClusterCapacityFault),"InvalidCacheClusterState" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, ModifyReplicationGroupShardConfigurationError::InvalidCacheClusterStateFault),"InvalidKMSKeyFault" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, ModifyReplicationGroupShardConfigurationError::InvalidKMSKeyFault),"InvalidParameterCombination" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, ModifyReplicationGroupShardConfigurationError::InvalidParameterCombination),"InvalidParameterValue" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, ModifyReplicationGroupShardConfigurationError::InvalidParameterValue),"InvalidReplicationGroupState" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, ModifyReplicationGroupShardConfigurationError::InvalidReplicationGroupStateFault),"InvalidVPCNetworkStateFault" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, ModifyReplicationGroupShardConfigurationError::InvalidVPCNetworkStateFault),"NodeGroupsPerReplicationGroupQuotaExceeded" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, ModifyReplicationGroupShardConfigurationError::NodeGroupsPerReplicationGroupQuotaExceededFault),"NodeQuotaForCustomerExceeded" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, ModifyReplicationGroupShardConfigurationError::NodeQuotaForCustomerExceededFault),"ReplicationGroupNotFoundFault" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, ModifyReplicationGroupShardConfigurationError::ReplicationGroupNotFoundFault),_ => {}
                                 }
             }
         }
@@ -14572,24 +14766,36 @@ impl ModifyUserError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(ModifyUserError::InvalidParameterCombination(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyUserError::InvalidParameterCombination,
+                        )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(ModifyUserError::InvalidParameterValue(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyUserError::InvalidParameterValue,
+                        )
                     }
                     "InvalidUserState" => {
-                        return RusotoError::Service(ModifyUserError::InvalidUserStateFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyUserError::InvalidUserStateFault,
+                        )
                     }
                     "UserNotFound" => {
-                        return RusotoError::Service(ModifyUserError::UserNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyUserError::UserNotFoundFault,
+                        )
                     }
                     _ => {}
                 }
@@ -14646,39 +14852,60 @@ impl ModifyUserGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DefaultUserRequired" => {
-                        return RusotoError::Service(ModifyUserGroupError::DefaultUserRequired(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyUserGroupError::DefaultUserRequired,
+                        )
                     }
                     "DuplicateUserName" => {
-                        return RusotoError::Service(ModifyUserGroupError::DuplicateUserNameFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyUserGroupError::DuplicateUserNameFault,
+                        )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
-                            ModifyUserGroupError::InvalidParameterCombination(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyUserGroupError::InvalidParameterCombination,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(ModifyUserGroupError::InvalidParameterValue(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyUserGroupError::InvalidParameterValue,
+                        )
                     }
                     "InvalidUserGroupState" => {
-                        return RusotoError::Service(
-                            ModifyUserGroupError::InvalidUserGroupStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyUserGroupError::InvalidUserGroupStateFault,
                         )
                     }
                     "UserGroupNotFound" => {
-                        return RusotoError::Service(ModifyUserGroupError::UserGroupNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyUserGroupError::UserGroupNotFoundFault,
+                        )
                     }
                     "UserNotFound" => {
-                        return RusotoError::Service(ModifyUserGroupError::UserNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyUserGroupError::UserNotFoundFault,
+                        )
                     }
                     _ => {}
                 }
@@ -14735,7 +14962,7 @@ impl PurchaseReservedCacheNodesOfferingError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "InvalidParameterCombination" => return RusotoError::Service(PurchaseReservedCacheNodesOfferingError::InvalidParameterCombination(parsed_error.message)),"InvalidParameterValue" => return RusotoError::Service(PurchaseReservedCacheNodesOfferingError::InvalidParameterValue(parsed_error.message)),"ReservedCacheNodeAlreadyExists" => return RusotoError::Service(PurchaseReservedCacheNodesOfferingError::ReservedCacheNodeAlreadyExistsFault(parsed_error.message)),"ReservedCacheNodeQuotaExceeded" => return RusotoError::Service(PurchaseReservedCacheNodesOfferingError::ReservedCacheNodeQuotaExceededFault(parsed_error.message)),"ReservedCacheNodesOfferingNotFound" => return RusotoError::Service(PurchaseReservedCacheNodesOfferingError::ReservedCacheNodesOfferingNotFoundFault(parsed_error.message)),_ => {}
+                                    "InvalidParameterCombination" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, PurchaseReservedCacheNodesOfferingError::InvalidParameterCombination),"InvalidParameterValue" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, PurchaseReservedCacheNodesOfferingError::InvalidParameterValue),"ReservedCacheNodeAlreadyExists" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, PurchaseReservedCacheNodesOfferingError::ReservedCacheNodeAlreadyExistsFault),"ReservedCacheNodeQuotaExceeded" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, PurchaseReservedCacheNodesOfferingError::ReservedCacheNodeQuotaExceededFault),"ReservedCacheNodesOfferingNotFound" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, PurchaseReservedCacheNodesOfferingError::ReservedCacheNodesOfferingNotFoundFault),_ => {}
                                 }
             }
         }
@@ -14794,7 +15021,7 @@ impl RebalanceSlotsInGlobalReplicationGroupError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "GlobalReplicationGroupNotFoundFault" => return RusotoError::Service(RebalanceSlotsInGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault(parsed_error.message)),"InvalidGlobalReplicationGroupState" => return RusotoError::Service(RebalanceSlotsInGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault(parsed_error.message)),"InvalidParameterValue" => return RusotoError::Service(RebalanceSlotsInGlobalReplicationGroupError::InvalidParameterValue(parsed_error.message)),_ => {}
+                                    "GlobalReplicationGroupNotFoundFault" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, RebalanceSlotsInGlobalReplicationGroupError::GlobalReplicationGroupNotFoundFault),"InvalidGlobalReplicationGroupState" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, RebalanceSlotsInGlobalReplicationGroupError::InvalidGlobalReplicationGroupStateFault),"InvalidParameterValue" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, RebalanceSlotsInGlobalReplicationGroupError::InvalidParameterValue),_ => {}
                                 }
             }
         }
@@ -14838,17 +15065,19 @@ impl RebootCacheClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheClusterNotFound" => {
-                        return RusotoError::Service(
-                            RebootCacheClusterError::CacheClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RebootCacheClusterError::CacheClusterNotFoundFault,
                         )
                     }
                     "InvalidCacheClusterState" => {
-                        return RusotoError::Service(
-                            RebootCacheClusterError::InvalidCacheClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RebootCacheClusterError::InvalidCacheClusterStateFault,
                         )
                     }
                     _ => {}
@@ -14900,28 +15129,36 @@ impl RemoveTagsFromResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheClusterNotFound" => {
-                        return RusotoError::Service(
-                            RemoveTagsFromResourceError::CacheClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RemoveTagsFromResourceError::CacheClusterNotFoundFault,
                         )
                     }
                     "InvalidARN" => {
-                        return RusotoError::Service(RemoveTagsFromResourceError::InvalidARNFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            RemoveTagsFromResourceError::InvalidARNFault,
+                        )
                     }
                     "SnapshotNotFoundFault" => {
-                        return RusotoError::Service(
-                            RemoveTagsFromResourceError::SnapshotNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RemoveTagsFromResourceError::SnapshotNotFoundFault,
                         )
                     }
                     "TagNotFound" => {
-                        return RusotoError::Service(RemoveTagsFromResourceError::TagNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            RemoveTagsFromResourceError::TagNotFoundFault,
+                        )
                     }
                     _ => {}
                 }
@@ -14976,38 +15213,43 @@ impl ResetCacheParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CacheParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            ResetCacheParameterGroupError::CacheParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ResetCacheParameterGroupError::CacheParameterGroupNotFoundFault,
                         )
                     }
                     "InvalidCacheParameterGroupState" => {
-                        return RusotoError::Service(
-                            ResetCacheParameterGroupError::InvalidCacheParameterGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ResetCacheParameterGroupError::InvalidCacheParameterGroupStateFault,
                         )
                     }
                     "InvalidGlobalReplicationGroupState" => {
-                        return RusotoError::Service(
-                            ResetCacheParameterGroupError::InvalidGlobalReplicationGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ResetCacheParameterGroupError::InvalidGlobalReplicationGroupStateFault,
                         )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
-                            ResetCacheParameterGroupError::InvalidParameterCombination(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ResetCacheParameterGroupError::InvalidParameterCombination,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
-                            ResetCacheParameterGroupError::InvalidParameterValue(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ResetCacheParameterGroupError::InvalidParameterValue,
                         )
                     }
                     _ => {}
@@ -15074,36 +15316,41 @@ impl RevokeCacheSecurityGroupIngressError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationNotFound" => {
-                        return RusotoError::Service(
-                            RevokeCacheSecurityGroupIngressError::AuthorizationNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RevokeCacheSecurityGroupIngressError::AuthorizationNotFoundFault,
                         )
                     }
                     "CacheSecurityGroupNotFound" => {
-                        return RusotoError::Service(
-                            RevokeCacheSecurityGroupIngressError::CacheSecurityGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RevokeCacheSecurityGroupIngressError::CacheSecurityGroupNotFoundFault,
                         )
                     }
-                    "InvalidCacheSecurityGroupState" => return RusotoError::Service(
-                        RevokeCacheSecurityGroupIngressError::InvalidCacheSecurityGroupStateFault(
-                            parsed_error.message,
-                        ),
+                    "InvalidCacheSecurityGroupState" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        RevokeCacheSecurityGroupIngressError::InvalidCacheSecurityGroupStateFault,
                     ),
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
-                            RevokeCacheSecurityGroupIngressError::InvalidParameterCombination(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RevokeCacheSecurityGroupIngressError::InvalidParameterCombination,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(
-                            RevokeCacheSecurityGroupIngressError::InvalidParameterValue(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RevokeCacheSecurityGroupIngressError::InvalidParameterValue,
                         )
                     }
                     _ => {}
@@ -15166,29 +15413,35 @@ impl StartMigrationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(StartMigrationError::InvalidParameterValue(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            StartMigrationError::InvalidParameterValue,
+                        )
                     }
                     "InvalidReplicationGroupState" => {
-                        return RusotoError::Service(
-                            StartMigrationError::InvalidReplicationGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StartMigrationError::InvalidReplicationGroupStateFault,
                         )
                     }
                     "ReplicationGroupAlreadyUnderMigrationFault" => {
-                        return RusotoError::Service(
-                            StartMigrationError::ReplicationGroupAlreadyUnderMigrationFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StartMigrationError::ReplicationGroupAlreadyUnderMigrationFault,
                         )
                     }
                     "ReplicationGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            StartMigrationError::ReplicationGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StartMigrationError::ReplicationGroupNotFoundFault,
                         )
                     }
                     _ => {}
@@ -15254,52 +15507,75 @@ impl TestFailoverError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "APICallRateForCustomerExceeded" => {
-                        return RusotoError::Service(
-                            TestFailoverError::APICallRateForCustomerExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            TestFailoverError::APICallRateForCustomerExceededFault,
                         )
                     }
                     "InvalidCacheClusterState" => {
-                        return RusotoError::Service(
-                            TestFailoverError::InvalidCacheClusterStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            TestFailoverError::InvalidCacheClusterStateFault,
                         )
                     }
                     "InvalidKMSKeyFault" => {
-                        return RusotoError::Service(TestFailoverError::InvalidKMSKeyFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            TestFailoverError::InvalidKMSKeyFault,
+                        )
                     }
                     "InvalidParameterCombination" => {
-                        return RusotoError::Service(
-                            TestFailoverError::InvalidParameterCombination(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            TestFailoverError::InvalidParameterCombination,
                         )
                     }
                     "InvalidParameterValue" => {
-                        return RusotoError::Service(TestFailoverError::InvalidParameterValue(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            TestFailoverError::InvalidParameterValue,
+                        )
                     }
                     "InvalidReplicationGroupState" => {
-                        return RusotoError::Service(
-                            TestFailoverError::InvalidReplicationGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            TestFailoverError::InvalidReplicationGroupStateFault,
                         )
                     }
                     "NodeGroupNotFoundFault" => {
-                        return RusotoError::Service(TestFailoverError::NodeGroupNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            TestFailoverError::NodeGroupNotFoundFault,
+                        )
                     }
                     "ReplicationGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            TestFailoverError::ReplicationGroupNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            TestFailoverError::ReplicationGroupNotFoundFault,
                         )
                     }
                     "TestFailoverNotAvailableFault" => {
-                        return RusotoError::Service(
-                            TestFailoverError::TestFailoverNotAvailableFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            TestFailoverError::TestFailoverNotAvailableFault,
                         )
                     }
                     _ => {}
@@ -15780,11 +16056,12 @@ pub struct ElastiCacheClient {
 impl ElastiCacheClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> ElastiCacheClient {
         ElastiCacheClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "ElastiCache"),
         }
     }
 
@@ -15799,12 +16076,32 @@ impl ElastiCacheClient {
     {
         ElastiCacheClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "ElastiCache"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ElastiCacheClient {
-        ElastiCacheClient { client, region }
+        ElastiCacheClient {
+            client,
+            region: region_for_service(region, "ElastiCache"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> ElastiCacheClient {
+        ElastiCacheClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("ElastiCache"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ElastiCacheClient {
+        ElastiCacheClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -15816,6 +16113,7 @@ impl ElastiCache for ElastiCacheClient {
         input: AddTagsToResourceMessage,
     ) -> Result<TagListMessage, RusotoError<AddTagsToResourceError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("AddTagsToResource");
         let params = self.new_params("AddTagsToResource");
         let mut params = params;
         AddTagsToResourceMessageSerializer::serialize(&mut params, "", &input);
@@ -15849,6 +16147,7 @@ impl ElastiCache for ElastiCacheClient {
         RusotoError<AuthorizeCacheSecurityGroupIngressError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("AuthorizeCacheSecurityGroupIngress");
         let params = self.new_params("AuthorizeCacheSecurityGroupIngress");
         let mut params = params;
         AuthorizeCacheSecurityGroupIngressMessageSerializer::serialize(&mut params, "", &input);
@@ -15885,6 +16184,7 @@ impl ElastiCache for ElastiCacheClient {
         input: BatchApplyUpdateActionMessage,
     ) -> Result<UpdateActionResultsMessage, RusotoError<BatchApplyUpdateActionError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("BatchApplyUpdateAction");
         let params = self.new_params("BatchApplyUpdateAction");
         let mut params = params;
         BatchApplyUpdateActionMessageSerializer::serialize(&mut params, "", &input);
@@ -15918,6 +16218,7 @@ impl ElastiCache for ElastiCacheClient {
         input: BatchStopUpdateActionMessage,
     ) -> Result<UpdateActionResultsMessage, RusotoError<BatchStopUpdateActionError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("BatchStopUpdateAction");
         let params = self.new_params("BatchStopUpdateAction");
         let mut params = params;
         BatchStopUpdateActionMessageSerializer::serialize(&mut params, "", &input);
@@ -15951,6 +16252,7 @@ impl ElastiCache for ElastiCacheClient {
         input: CompleteMigrationMessage,
     ) -> Result<CompleteMigrationResponse, RusotoError<CompleteMigrationError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("CompleteMigration");
         let params = self.new_params("CompleteMigration");
         let mut params = params;
         CompleteMigrationMessageSerializer::serialize(&mut params, "", &input);
@@ -15984,6 +16286,7 @@ impl ElastiCache for ElastiCacheClient {
         input: CopySnapshotMessage,
     ) -> Result<CopySnapshotResult, RusotoError<CopySnapshotError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("CopySnapshot");
         let params = self.new_params("CopySnapshot");
         let mut params = params;
         CopySnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -16014,6 +16317,7 @@ impl ElastiCache for ElastiCacheClient {
         input: CreateCacheClusterMessage,
     ) -> Result<CreateCacheClusterResult, RusotoError<CreateCacheClusterError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("CreateCacheCluster");
         let params = self.new_params("CreateCacheCluster");
         let mut params = params;
         CreateCacheClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -16047,6 +16351,7 @@ impl ElastiCache for ElastiCacheClient {
         input: CreateCacheParameterGroupMessage,
     ) -> Result<CreateCacheParameterGroupResult, RusotoError<CreateCacheParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("CreateCacheParameterGroup");
         let params = self.new_params("CreateCacheParameterGroup");
         let mut params = params;
         CreateCacheParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -16080,6 +16385,7 @@ impl ElastiCache for ElastiCacheClient {
         input: CreateCacheSecurityGroupMessage,
     ) -> Result<CreateCacheSecurityGroupResult, RusotoError<CreateCacheSecurityGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("CreateCacheSecurityGroup");
         let params = self.new_params("CreateCacheSecurityGroup");
         let mut params = params;
         CreateCacheSecurityGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -16113,6 +16419,7 @@ impl ElastiCache for ElastiCacheClient {
         input: CreateCacheSubnetGroupMessage,
     ) -> Result<CreateCacheSubnetGroupResult, RusotoError<CreateCacheSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("CreateCacheSubnetGroup");
         let params = self.new_params("CreateCacheSubnetGroup");
         let mut params = params;
         CreateCacheSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -16147,6 +16454,7 @@ impl ElastiCache for ElastiCacheClient {
     ) -> Result<CreateGlobalReplicationGroupResult, RusotoError<CreateGlobalReplicationGroupError>>
     {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("CreateGlobalReplicationGroup");
         let params = self.new_params("CreateGlobalReplicationGroup");
         let mut params = params;
         CreateGlobalReplicationGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -16180,6 +16488,7 @@ impl ElastiCache for ElastiCacheClient {
         input: CreateReplicationGroupMessage,
     ) -> Result<CreateReplicationGroupResult, RusotoError<CreateReplicationGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("CreateReplicationGroup");
         let params = self.new_params("CreateReplicationGroup");
         let mut params = params;
         CreateReplicationGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -16213,6 +16522,7 @@ impl ElastiCache for ElastiCacheClient {
         input: CreateSnapshotMessage,
     ) -> Result<CreateSnapshotResult, RusotoError<CreateSnapshotError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("CreateSnapshot");
         let params = self.new_params("CreateSnapshot");
         let mut params = params;
         CreateSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -16244,6 +16554,7 @@ impl ElastiCache for ElastiCacheClient {
         input: CreateUserMessage,
     ) -> Result<User, RusotoError<CreateUserError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("CreateUser");
         let params = self.new_params("CreateUser");
         let mut params = params;
         CreateUserMessageSerializer::serialize(&mut params, "", &input);
@@ -16274,6 +16585,7 @@ impl ElastiCache for ElastiCacheClient {
         input: CreateUserGroupMessage,
     ) -> Result<UserGroup, RusotoError<CreateUserGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("CreateUserGroup");
         let params = self.new_params("CreateUserGroup");
         let mut params = params;
         CreateUserGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -16307,6 +16619,7 @@ impl ElastiCache for ElastiCacheClient {
         RusotoError<DecreaseNodeGroupsInGlobalReplicationGroupError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DecreaseNodeGroupsInGlobalReplicationGroup");
         let params = self.new_params("DecreaseNodeGroupsInGlobalReplicationGroup");
         let mut params = params;
         DecreaseNodeGroupsInGlobalReplicationGroupMessageSerializer::serialize(
@@ -16347,6 +16660,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DecreaseReplicaCountMessage,
     ) -> Result<DecreaseReplicaCountResult, RusotoError<DecreaseReplicaCountError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DecreaseReplicaCount");
         let params = self.new_params("DecreaseReplicaCount");
         let mut params = params;
         DecreaseReplicaCountMessageSerializer::serialize(&mut params, "", &input);
@@ -16380,6 +16694,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DeleteCacheClusterMessage,
     ) -> Result<DeleteCacheClusterResult, RusotoError<DeleteCacheClusterError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DeleteCacheCluster");
         let params = self.new_params("DeleteCacheCluster");
         let mut params = params;
         DeleteCacheClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -16413,6 +16728,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DeleteCacheParameterGroupMessage,
     ) -> Result<(), RusotoError<DeleteCacheParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DeleteCacheParameterGroup");
         let params = self.new_params("DeleteCacheParameterGroup");
         let mut params = params;
         DeleteCacheParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -16433,6 +16749,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DeleteCacheSecurityGroupMessage,
     ) -> Result<(), RusotoError<DeleteCacheSecurityGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DeleteCacheSecurityGroup");
         let params = self.new_params("DeleteCacheSecurityGroup");
         let mut params = params;
         DeleteCacheSecurityGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -16453,6 +16770,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DeleteCacheSubnetGroupMessage,
     ) -> Result<(), RusotoError<DeleteCacheSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DeleteCacheSubnetGroup");
         let params = self.new_params("DeleteCacheSubnetGroup");
         let mut params = params;
         DeleteCacheSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -16474,6 +16792,7 @@ impl ElastiCache for ElastiCacheClient {
     ) -> Result<DeleteGlobalReplicationGroupResult, RusotoError<DeleteGlobalReplicationGroupError>>
     {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DeleteGlobalReplicationGroup");
         let params = self.new_params("DeleteGlobalReplicationGroup");
         let mut params = params;
         DeleteGlobalReplicationGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -16507,6 +16826,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DeleteReplicationGroupMessage,
     ) -> Result<DeleteReplicationGroupResult, RusotoError<DeleteReplicationGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DeleteReplicationGroup");
         let params = self.new_params("DeleteReplicationGroup");
         let mut params = params;
         DeleteReplicationGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -16540,6 +16860,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DeleteSnapshotMessage,
     ) -> Result<DeleteSnapshotResult, RusotoError<DeleteSnapshotError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DeleteSnapshot");
         let params = self.new_params("DeleteSnapshot");
         let mut params = params;
         DeleteSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -16571,6 +16892,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DeleteUserMessage,
     ) -> Result<User, RusotoError<DeleteUserError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DeleteUser");
         let params = self.new_params("DeleteUser");
         let mut params = params;
         DeleteUserMessageSerializer::serialize(&mut params, "", &input);
@@ -16601,6 +16923,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DeleteUserGroupMessage,
     ) -> Result<UserGroup, RusotoError<DeleteUserGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DeleteUserGroup");
         let params = self.new_params("DeleteUserGroup");
         let mut params = params;
         DeleteUserGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -16631,6 +16954,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DescribeCacheClustersMessage,
     ) -> Result<CacheClusterMessage, RusotoError<DescribeCacheClustersError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeCacheClusters");
         let params = self.new_params("DescribeCacheClusters");
         let mut params = params;
         DescribeCacheClustersMessageSerializer::serialize(&mut params, "", &input);
@@ -16662,6 +16986,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DescribeCacheEngineVersionsMessage,
     ) -> Result<CacheEngineVersionMessage, RusotoError<DescribeCacheEngineVersionsError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeCacheEngineVersions");
         let params = self.new_params("DescribeCacheEngineVersions");
         let mut params = params;
         DescribeCacheEngineVersionsMessageSerializer::serialize(&mut params, "", &input);
@@ -16695,6 +17020,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DescribeCacheParameterGroupsMessage,
     ) -> Result<CacheParameterGroupsMessage, RusotoError<DescribeCacheParameterGroupsError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeCacheParameterGroups");
         let params = self.new_params("DescribeCacheParameterGroups");
         let mut params = params;
         DescribeCacheParameterGroupsMessageSerializer::serialize(&mut params, "", &input);
@@ -16728,6 +17054,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DescribeCacheParametersMessage,
     ) -> Result<CacheParameterGroupDetails, RusotoError<DescribeCacheParametersError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeCacheParameters");
         let params = self.new_params("DescribeCacheParameters");
         let mut params = params;
         DescribeCacheParametersMessageSerializer::serialize(&mut params, "", &input);
@@ -16761,6 +17088,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DescribeCacheSecurityGroupsMessage,
     ) -> Result<CacheSecurityGroupMessage, RusotoError<DescribeCacheSecurityGroupsError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeCacheSecurityGroups");
         let params = self.new_params("DescribeCacheSecurityGroups");
         let mut params = params;
         DescribeCacheSecurityGroupsMessageSerializer::serialize(&mut params, "", &input);
@@ -16794,6 +17122,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DescribeCacheSubnetGroupsMessage,
     ) -> Result<CacheSubnetGroupMessage, RusotoError<DescribeCacheSubnetGroupsError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeCacheSubnetGroups");
         let params = self.new_params("DescribeCacheSubnetGroups");
         let mut params = params;
         DescribeCacheSubnetGroupsMessageSerializer::serialize(&mut params, "", &input);
@@ -16830,6 +17159,7 @@ impl ElastiCache for ElastiCacheClient {
         RusotoError<DescribeEngineDefaultParametersError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeEngineDefaultParameters");
         let params = self.new_params("DescribeEngineDefaultParameters");
         let mut params = params;
         DescribeEngineDefaultParametersMessageSerializer::serialize(&mut params, "", &input);
@@ -16863,6 +17193,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DescribeEventsMessage,
     ) -> Result<EventsMessage, RusotoError<DescribeEventsError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeEvents");
         let params = self.new_params("DescribeEvents");
         let mut params = params;
         DescribeEventsMessageSerializer::serialize(&mut params, "", &input);
@@ -16896,6 +17227,7 @@ impl ElastiCache for ElastiCacheClient {
         RusotoError<DescribeGlobalReplicationGroupsError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeGlobalReplicationGroups");
         let params = self.new_params("DescribeGlobalReplicationGroups");
         let mut params = params;
         DescribeGlobalReplicationGroupsMessageSerializer::serialize(&mut params, "", &input);
@@ -16929,6 +17261,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DescribeReplicationGroupsMessage,
     ) -> Result<ReplicationGroupMessage, RusotoError<DescribeReplicationGroupsError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeReplicationGroups");
         let params = self.new_params("DescribeReplicationGroups");
         let mut params = params;
         DescribeReplicationGroupsMessageSerializer::serialize(&mut params, "", &input);
@@ -16962,6 +17295,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DescribeReservedCacheNodesMessage,
     ) -> Result<ReservedCacheNodeMessage, RusotoError<DescribeReservedCacheNodesError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeReservedCacheNodes");
         let params = self.new_params("DescribeReservedCacheNodes");
         let mut params = params;
         DescribeReservedCacheNodesMessageSerializer::serialize(&mut params, "", &input);
@@ -16998,6 +17332,7 @@ impl ElastiCache for ElastiCacheClient {
         RusotoError<DescribeReservedCacheNodesOfferingsError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeReservedCacheNodesOfferings");
         let params = self.new_params("DescribeReservedCacheNodesOfferings");
         let mut params = params;
         DescribeReservedCacheNodesOfferingsMessageSerializer::serialize(&mut params, "", &input);
@@ -17034,6 +17369,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DescribeServiceUpdatesMessage,
     ) -> Result<ServiceUpdatesMessage, RusotoError<DescribeServiceUpdatesError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeServiceUpdates");
         let params = self.new_params("DescribeServiceUpdates");
         let mut params = params;
         DescribeServiceUpdatesMessageSerializer::serialize(&mut params, "", &input);
@@ -17067,6 +17403,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DescribeSnapshotsMessage,
     ) -> Result<DescribeSnapshotsListMessage, RusotoError<DescribeSnapshotsError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeSnapshots");
         let params = self.new_params("DescribeSnapshots");
         let mut params = params;
         DescribeSnapshotsMessageSerializer::serialize(&mut params, "", &input);
@@ -17100,6 +17437,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DescribeUpdateActionsMessage,
     ) -> Result<UpdateActionsMessage, RusotoError<DescribeUpdateActionsError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeUpdateActions");
         let params = self.new_params("DescribeUpdateActions");
         let mut params = params;
         DescribeUpdateActionsMessageSerializer::serialize(&mut params, "", &input);
@@ -17133,6 +17471,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DescribeUserGroupsMessage,
     ) -> Result<DescribeUserGroupsResult, RusotoError<DescribeUserGroupsError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeUserGroups");
         let params = self.new_params("DescribeUserGroups");
         let mut params = params;
         DescribeUserGroupsMessageSerializer::serialize(&mut params, "", &input);
@@ -17166,6 +17505,7 @@ impl ElastiCache for ElastiCacheClient {
         input: DescribeUsersMessage,
     ) -> Result<DescribeUsersResult, RusotoError<DescribeUsersError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DescribeUsers");
         let params = self.new_params("DescribeUsers");
         let mut params = params;
         DescribeUsersMessageSerializer::serialize(&mut params, "", &input);
@@ -17200,6 +17540,7 @@ impl ElastiCache for ElastiCacheClient {
         RusotoError<DisassociateGlobalReplicationGroupError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("DisassociateGlobalReplicationGroup");
         let params = self.new_params("DisassociateGlobalReplicationGroup");
         let mut params = params;
         DisassociateGlobalReplicationGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -17239,6 +17580,7 @@ impl ElastiCache for ElastiCacheClient {
         RusotoError<FailoverGlobalReplicationGroupError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("FailoverGlobalReplicationGroup");
         let params = self.new_params("FailoverGlobalReplicationGroup");
         let mut params = params;
         FailoverGlobalReplicationGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -17275,6 +17617,7 @@ impl ElastiCache for ElastiCacheClient {
         RusotoError<IncreaseNodeGroupsInGlobalReplicationGroupError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("IncreaseNodeGroupsInGlobalReplicationGroup");
         let params = self.new_params("IncreaseNodeGroupsInGlobalReplicationGroup");
         let mut params = params;
         IncreaseNodeGroupsInGlobalReplicationGroupMessageSerializer::serialize(
@@ -17315,6 +17658,7 @@ impl ElastiCache for ElastiCacheClient {
         input: IncreaseReplicaCountMessage,
     ) -> Result<IncreaseReplicaCountResult, RusotoError<IncreaseReplicaCountError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("IncreaseReplicaCount");
         let params = self.new_params("IncreaseReplicaCount");
         let mut params = params;
         IncreaseReplicaCountMessageSerializer::serialize(&mut params, "", &input);
@@ -17351,6 +17695,7 @@ impl ElastiCache for ElastiCacheClient {
         RusotoError<ListAllowedNodeTypeModificationsError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("ListAllowedNodeTypeModifications");
         let params = self.new_params("ListAllowedNodeTypeModifications");
         let mut params = params;
         ListAllowedNodeTypeModificationsMessageSerializer::serialize(&mut params, "", &input);
@@ -17387,6 +17732,7 @@ impl ElastiCache for ElastiCacheClient {
         input: ListTagsForResourceMessage,
     ) -> Result<TagListMessage, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("ListTagsForResource");
         let params = self.new_params("ListTagsForResource");
         let mut params = params;
         ListTagsForResourceMessageSerializer::serialize(&mut params, "", &input);
@@ -17418,6 +17764,7 @@ impl ElastiCache for ElastiCacheClient {
         input: ModifyCacheClusterMessage,
     ) -> Result<ModifyCacheClusterResult, RusotoError<ModifyCacheClusterError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("ModifyCacheCluster");
         let params = self.new_params("ModifyCacheCluster");
         let mut params = params;
         ModifyCacheClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -17451,6 +17798,7 @@ impl ElastiCache for ElastiCacheClient {
         input: ModifyCacheParameterGroupMessage,
     ) -> Result<CacheParameterGroupNameMessage, RusotoError<ModifyCacheParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("ModifyCacheParameterGroup");
         let params = self.new_params("ModifyCacheParameterGroup");
         let mut params = params;
         ModifyCacheParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -17484,6 +17832,7 @@ impl ElastiCache for ElastiCacheClient {
         input: ModifyCacheSubnetGroupMessage,
     ) -> Result<ModifyCacheSubnetGroupResult, RusotoError<ModifyCacheSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("ModifyCacheSubnetGroup");
         let params = self.new_params("ModifyCacheSubnetGroup");
         let mut params = params;
         ModifyCacheSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -17518,6 +17867,7 @@ impl ElastiCache for ElastiCacheClient {
     ) -> Result<ModifyGlobalReplicationGroupResult, RusotoError<ModifyGlobalReplicationGroupError>>
     {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("ModifyGlobalReplicationGroup");
         let params = self.new_params("ModifyGlobalReplicationGroup");
         let mut params = params;
         ModifyGlobalReplicationGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -17551,6 +17901,7 @@ impl ElastiCache for ElastiCacheClient {
         input: ModifyReplicationGroupMessage,
     ) -> Result<ModifyReplicationGroupResult, RusotoError<ModifyReplicationGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("ModifyReplicationGroup");
         let params = self.new_params("ModifyReplicationGroup");
         let mut params = params;
         ModifyReplicationGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -17587,6 +17938,7 @@ impl ElastiCache for ElastiCacheClient {
         RusotoError<ModifyReplicationGroupShardConfigurationError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("ModifyReplicationGroupShardConfiguration");
         let params = self.new_params("ModifyReplicationGroupShardConfiguration");
         let mut params = params;
         ModifyReplicationGroupShardConfigurationMessageSerializer::serialize(
@@ -17627,6 +17979,7 @@ impl ElastiCache for ElastiCacheClient {
         input: ModifyUserMessage,
     ) -> Result<User, RusotoError<ModifyUserError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("ModifyUser");
         let params = self.new_params("ModifyUser");
         let mut params = params;
         ModifyUserMessageSerializer::serialize(&mut params, "", &input);
@@ -17657,6 +18010,7 @@ impl ElastiCache for ElastiCacheClient {
         input: ModifyUserGroupMessage,
     ) -> Result<UserGroup, RusotoError<ModifyUserGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("ModifyUserGroup");
         let params = self.new_params("ModifyUserGroup");
         let mut params = params;
         ModifyUserGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -17690,6 +18044,7 @@ impl ElastiCache for ElastiCacheClient {
         RusotoError<PurchaseReservedCacheNodesOfferingError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("PurchaseReservedCacheNodesOffering");
         let params = self.new_params("PurchaseReservedCacheNodesOffering");
         let mut params = params;
         PurchaseReservedCacheNodesOfferingMessageSerializer::serialize(&mut params, "", &input);
@@ -17729,6 +18084,7 @@ impl ElastiCache for ElastiCacheClient {
         RusotoError<RebalanceSlotsInGlobalReplicationGroupError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("RebalanceSlotsInGlobalReplicationGroup");
         let params = self.new_params("RebalanceSlotsInGlobalReplicationGroup");
         let mut params = params;
         RebalanceSlotsInGlobalReplicationGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -17765,6 +18121,7 @@ impl ElastiCache for ElastiCacheClient {
         input: RebootCacheClusterMessage,
     ) -> Result<RebootCacheClusterResult, RusotoError<RebootCacheClusterError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("RebootCacheCluster");
         let params = self.new_params("RebootCacheCluster");
         let mut params = params;
         RebootCacheClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -17798,6 +18155,7 @@ impl ElastiCache for ElastiCacheClient {
         input: RemoveTagsFromResourceMessage,
     ) -> Result<TagListMessage, RusotoError<RemoveTagsFromResourceError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("RemoveTagsFromResource");
         let params = self.new_params("RemoveTagsFromResource");
         let mut params = params;
         RemoveTagsFromResourceMessageSerializer::serialize(&mut params, "", &input);
@@ -17829,6 +18187,7 @@ impl ElastiCache for ElastiCacheClient {
         input: ResetCacheParameterGroupMessage,
     ) -> Result<CacheParameterGroupNameMessage, RusotoError<ResetCacheParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("ResetCacheParameterGroup");
         let params = self.new_params("ResetCacheParameterGroup");
         let mut params = params;
         ResetCacheParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -17865,6 +18224,7 @@ impl ElastiCache for ElastiCacheClient {
         RusotoError<RevokeCacheSecurityGroupIngressError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("RevokeCacheSecurityGroupIngress");
         let params = self.new_params("RevokeCacheSecurityGroupIngress");
         let mut params = params;
         RevokeCacheSecurityGroupIngressMessageSerializer::serialize(&mut params, "", &input);
@@ -17898,6 +18258,7 @@ impl ElastiCache for ElastiCacheClient {
         input: StartMigrationMessage,
     ) -> Result<StartMigrationResponse, RusotoError<StartMigrationError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("StartMigration");
         let params = self.new_params("StartMigration");
         let mut params = params;
         StartMigrationMessageSerializer::serialize(&mut params, "", &input);
@@ -17929,6 +18290,7 @@ impl ElastiCache for ElastiCacheClient {
         input: TestFailoverMessage,
     ) -> Result<TestFailoverResult, RusotoError<TestFailoverError>> {
         let mut request = SignedRequest::new("POST", "elasticache", &self.region, "/");
+        request.set_operation("TestFailover");
         let params = self.new_params("TestFailover");
         let mut params = params;
         TestFailoverMessageSerializer::serialize(&mut params, "", &input);
@@ -17953,3 +18315,698 @@ impl ElastiCache for ElastiCacheClient {
         Ok(result)
     }
 }
+
+/// A blocking client for the Amazon ElastiCache API, running the calls of a `ElastiCacheClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `ElastiCacheClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ElastiCacheBlockingClient {
+    client: ElastiCacheClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ElastiCacheBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> ElastiCacheBlockingClient {
+        ElastiCacheBlockingClient::from_client(ElastiCacheClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ElastiCacheBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ElastiCacheBlockingClient::from_client(ElastiCacheClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> ElastiCacheBlockingClient {
+        ElastiCacheBlockingClient::from_client(ElastiCacheClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> ElastiCacheBlockingClient {
+        ElastiCacheBlockingClient::from_client(ElastiCacheClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: ElastiCacheClient) -> ElastiCacheBlockingClient {
+        ElastiCacheBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> ElastiCacheBlockingClient {
+        ElastiCacheBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ElastiCacheBlockingClient {
+        ElastiCacheBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`ElastiCache::add_tags_to_resource`].
+    pub fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceMessage,
+    ) -> Result<TagListMessage, RusotoError<AddTagsToResourceError>> {
+        self.runtime
+            .block_on(self.client.add_tags_to_resource(input))
+    }
+
+    /// Blocking version of [`ElastiCache::authorize_cache_security_group_ingress`].
+    pub fn authorize_cache_security_group_ingress(
+        &self,
+        input: AuthorizeCacheSecurityGroupIngressMessage,
+    ) -> Result<
+        AuthorizeCacheSecurityGroupIngressResult,
+        RusotoError<AuthorizeCacheSecurityGroupIngressError>,
+    > {
+        self.runtime
+            .block_on(self.client.authorize_cache_security_group_ingress(input))
+    }
+
+    /// Blocking version of [`ElastiCache::batch_apply_update_action`].
+    pub fn batch_apply_update_action(
+        &self,
+        input: BatchApplyUpdateActionMessage,
+    ) -> Result<UpdateActionResultsMessage, RusotoError<BatchApplyUpdateActionError>> {
+        self.runtime
+            .block_on(self.client.batch_apply_update_action(input))
+    }
+
+    /// Blocking version of [`ElastiCache::batch_stop_update_action`].
+    pub fn batch_stop_update_action(
+        &self,
+        input: BatchStopUpdateActionMessage,
+    ) -> Result<UpdateActionResultsMessage, RusotoError<BatchStopUpdateActionError>> {
+        self.runtime
+            .block_on(self.client.batch_stop_update_action(input))
+    }
+
+    /// Blocking version of [`ElastiCache::complete_migration`].
+    pub fn complete_migration(
+        &self,
+        input: CompleteMigrationMessage,
+    ) -> Result<CompleteMigrationResponse, RusotoError<CompleteMigrationError>> {
+        self.runtime.block_on(self.client.complete_migration(input))
+    }
+
+    /// Blocking version of [`ElastiCache::copy_snapshot`].
+    pub fn copy_snapshot(
+        &self,
+        input: CopySnapshotMessage,
+    ) -> Result<CopySnapshotResult, RusotoError<CopySnapshotError>> {
+        self.runtime.block_on(self.client.copy_snapshot(input))
+    }
+
+    /// Blocking version of [`ElastiCache::create_cache_cluster`].
+    pub fn create_cache_cluster(
+        &self,
+        input: CreateCacheClusterMessage,
+    ) -> Result<CreateCacheClusterResult, RusotoError<CreateCacheClusterError>> {
+        self.runtime
+            .block_on(self.client.create_cache_cluster(input))
+    }
+
+    /// Blocking version of [`ElastiCache::create_cache_parameter_group`].
+    pub fn create_cache_parameter_group(
+        &self,
+        input: CreateCacheParameterGroupMessage,
+    ) -> Result<CreateCacheParameterGroupResult, RusotoError<CreateCacheParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.create_cache_parameter_group(input))
+    }
+
+    /// Blocking version of [`ElastiCache::create_cache_security_group`].
+    pub fn create_cache_security_group(
+        &self,
+        input: CreateCacheSecurityGroupMessage,
+    ) -> Result<CreateCacheSecurityGroupResult, RusotoError<CreateCacheSecurityGroupError>> {
+        self.runtime
+            .block_on(self.client.create_cache_security_group(input))
+    }
+
+    /// Blocking version of [`ElastiCache::create_cache_subnet_group`].
+    pub fn create_cache_subnet_group(
+        &self,
+        input: CreateCacheSubnetGroupMessage,
+    ) -> Result<CreateCacheSubnetGroupResult, RusotoError<CreateCacheSubnetGroupError>> {
+        self.runtime
+            .block_on(self.client.create_cache_subnet_group(input))
+    }
+
+    /// Blocking version of [`ElastiCache::create_global_replication_group`].
+    pub fn create_global_replication_group(
+        &self,
+        input: CreateGlobalReplicationGroupMessage,
+    ) -> Result<CreateGlobalReplicationGroupResult, RusotoError<CreateGlobalReplicationGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.create_global_replication_group(input))
+    }
+
+    /// Blocking version of [`ElastiCache::create_replication_group`].
+    pub fn create_replication_group(
+        &self,
+        input: CreateReplicationGroupMessage,
+    ) -> Result<CreateReplicationGroupResult, RusotoError<CreateReplicationGroupError>> {
+        self.runtime
+            .block_on(self.client.create_replication_group(input))
+    }
+
+    /// Blocking version of [`ElastiCache::create_snapshot`].
+    pub fn create_snapshot(
+        &self,
+        input: CreateSnapshotMessage,
+    ) -> Result<CreateSnapshotResult, RusotoError<CreateSnapshotError>> {
+        self.runtime.block_on(self.client.create_snapshot(input))
+    }
+
+    /// Blocking version of [`ElastiCache::create_user`].
+    pub fn create_user(
+        &self,
+        input: CreateUserMessage,
+    ) -> Result<User, RusotoError<CreateUserError>> {
+        self.runtime.block_on(self.client.create_user(input))
+    }
+
+    /// Blocking version of [`ElastiCache::create_user_group`].
+    pub fn create_user_group(
+        &self,
+        input: CreateUserGroupMessage,
+    ) -> Result<UserGroup, RusotoError<CreateUserGroupError>> {
+        self.runtime.block_on(self.client.create_user_group(input))
+    }
+
+    /// Blocking version of [`ElastiCache::decrease_node_groups_in_global_replication_group`].
+    pub fn decrease_node_groups_in_global_replication_group(
+        &self,
+        input: DecreaseNodeGroupsInGlobalReplicationGroupMessage,
+    ) -> Result<
+        DecreaseNodeGroupsInGlobalReplicationGroupResult,
+        RusotoError<DecreaseNodeGroupsInGlobalReplicationGroupError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .decrease_node_groups_in_global_replication_group(input),
+        )
+    }
+
+    /// Blocking version of [`ElastiCache::decrease_replica_count`].
+    pub fn decrease_replica_count(
+        &self,
+        input: DecreaseReplicaCountMessage,
+    ) -> Result<DecreaseReplicaCountResult, RusotoError<DecreaseReplicaCountError>> {
+        self.runtime
+            .block_on(self.client.decrease_replica_count(input))
+    }
+
+    /// Blocking version of [`ElastiCache::delete_cache_cluster`].
+    pub fn delete_cache_cluster(
+        &self,
+        input: DeleteCacheClusterMessage,
+    ) -> Result<DeleteCacheClusterResult, RusotoError<DeleteCacheClusterError>> {
+        self.runtime
+            .block_on(self.client.delete_cache_cluster(input))
+    }
+
+    /// Blocking version of [`ElastiCache::delete_cache_parameter_group`].
+    pub fn delete_cache_parameter_group(
+        &self,
+        input: DeleteCacheParameterGroupMessage,
+    ) -> Result<(), RusotoError<DeleteCacheParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_cache_parameter_group(input))
+    }
+
+    /// Blocking version of [`ElastiCache::delete_cache_security_group`].
+    pub fn delete_cache_security_group(
+        &self,
+        input: DeleteCacheSecurityGroupMessage,
+    ) -> Result<(), RusotoError<DeleteCacheSecurityGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_cache_security_group(input))
+    }
+
+    /// Blocking version of [`ElastiCache::delete_cache_subnet_group`].
+    pub fn delete_cache_subnet_group(
+        &self,
+        input: DeleteCacheSubnetGroupMessage,
+    ) -> Result<(), RusotoError<DeleteCacheSubnetGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_cache_subnet_group(input))
+    }
+
+    /// Blocking version of [`ElastiCache::delete_global_replication_group`].
+    pub fn delete_global_replication_group(
+        &self,
+        input: DeleteGlobalReplicationGroupMessage,
+    ) -> Result<DeleteGlobalReplicationGroupResult, RusotoError<DeleteGlobalReplicationGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_global_replication_group(input))
+    }
+
+    /// Blocking version of [`ElastiCache::delete_replication_group`].
+    pub fn delete_replication_group(
+        &self,
+        input: DeleteReplicationGroupMessage,
+    ) -> Result<DeleteReplicationGroupResult, RusotoError<DeleteReplicationGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_replication_group(input))
+    }
+
+    /// Blocking version of [`ElastiCache::delete_snapshot`].
+    pub fn delete_snapshot(
+        &self,
+        input: DeleteSnapshotMessage,
+    ) -> Result<DeleteSnapshotResult, RusotoError<DeleteSnapshotError>> {
+        self.runtime.block_on(self.client.delete_snapshot(input))
+    }
+
+    /// Blocking version of [`ElastiCache::delete_user`].
+    pub fn delete_user(
+        &self,
+        input: DeleteUserMessage,
+    ) -> Result<User, RusotoError<DeleteUserError>> {
+        self.runtime.block_on(self.client.delete_user(input))
+    }
+
+    /// Blocking version of [`ElastiCache::delete_user_group`].
+    pub fn delete_user_group(
+        &self,
+        input: DeleteUserGroupMessage,
+    ) -> Result<UserGroup, RusotoError<DeleteUserGroupError>> {
+        self.runtime.block_on(self.client.delete_user_group(input))
+    }
+
+    /// Blocking version of [`ElastiCache::describe_cache_clusters`].
+    pub fn describe_cache_clusters(
+        &self,
+        input: DescribeCacheClustersMessage,
+    ) -> Result<CacheClusterMessage, RusotoError<DescribeCacheClustersError>> {
+        self.runtime
+            .block_on(self.client.describe_cache_clusters(input))
+    }
+
+    /// Blocking version of [`ElastiCache::describe_cache_engine_versions`].
+    pub fn describe_cache_engine_versions(
+        &self,
+        input: DescribeCacheEngineVersionsMessage,
+    ) -> Result<CacheEngineVersionMessage, RusotoError<DescribeCacheEngineVersionsError>> {
+        self.runtime
+            .block_on(self.client.describe_cache_engine_versions(input))
+    }
+
+    /// Blocking version of [`ElastiCache::describe_cache_parameter_groups`].
+    pub fn describe_cache_parameter_groups(
+        &self,
+        input: DescribeCacheParameterGroupsMessage,
+    ) -> Result<CacheParameterGroupsMessage, RusotoError<DescribeCacheParameterGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_cache_parameter_groups(input))
+    }
+
+    /// Blocking version of [`ElastiCache::describe_cache_parameters`].
+    pub fn describe_cache_parameters(
+        &self,
+        input: DescribeCacheParametersMessage,
+    ) -> Result<CacheParameterGroupDetails, RusotoError<DescribeCacheParametersError>> {
+        self.runtime
+            .block_on(self.client.describe_cache_parameters(input))
+    }
+
+    /// Blocking version of [`ElastiCache::describe_cache_security_groups`].
+    pub fn describe_cache_security_groups(
+        &self,
+        input: DescribeCacheSecurityGroupsMessage,
+    ) -> Result<CacheSecurityGroupMessage, RusotoError<DescribeCacheSecurityGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_cache_security_groups(input))
+    }
+
+    /// Blocking version of [`ElastiCache::describe_cache_subnet_groups`].
+    pub fn describe_cache_subnet_groups(
+        &self,
+        input: DescribeCacheSubnetGroupsMessage,
+    ) -> Result<CacheSubnetGroupMessage, RusotoError<DescribeCacheSubnetGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_cache_subnet_groups(input))
+    }
+
+    /// Blocking version of [`ElastiCache::describe_engine_default_parameters`].
+    pub fn describe_engine_default_parameters(
+        &self,
+        input: DescribeEngineDefaultParametersMessage,
+    ) -> Result<
+        DescribeEngineDefaultParametersResult,
+        RusotoError<DescribeEngineDefaultParametersError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_engine_default_parameters(input))
+    }
+
+    /// Blocking version of [`ElastiCache::describe_events`].
+    pub fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> Result<EventsMessage, RusotoError<DescribeEventsError>> {
+        self.runtime.block_on(self.client.describe_events(input))
+    }
+
+    /// Blocking version of [`ElastiCache::describe_global_replication_groups`].
+    pub fn describe_global_replication_groups(
+        &self,
+        input: DescribeGlobalReplicationGroupsMessage,
+    ) -> Result<
+        DescribeGlobalReplicationGroupsResult,
+        RusotoError<DescribeGlobalReplicationGroupsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_global_replication_groups(input))
+    }
+
+    /// Blocking version of [`ElastiCache::describe_replication_groups`].
+    pub fn describe_replication_groups(
+        &self,
+        input: DescribeReplicationGroupsMessage,
+    ) -> Result<ReplicationGroupMessage, RusotoError<DescribeReplicationGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_replication_groups(input))
+    }
+
+    /// Blocking version of [`ElastiCache::describe_reserved_cache_nodes`].
+    pub fn describe_reserved_cache_nodes(
+        &self,
+        input: DescribeReservedCacheNodesMessage,
+    ) -> Result<ReservedCacheNodeMessage, RusotoError<DescribeReservedCacheNodesError>> {
+        self.runtime
+            .block_on(self.client.describe_reserved_cache_nodes(input))
+    }
+
+    /// Blocking version of [`ElastiCache::describe_reserved_cache_nodes_offerings`].
+    pub fn describe_reserved_cache_nodes_offerings(
+        &self,
+        input: DescribeReservedCacheNodesOfferingsMessage,
+    ) -> Result<
+        ReservedCacheNodesOfferingMessage,
+        RusotoError<DescribeReservedCacheNodesOfferingsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_reserved_cache_nodes_offerings(input))
+    }
+
+    /// Blocking version of [`ElastiCache::describe_service_updates`].
+    pub fn describe_service_updates(
+        &self,
+        input: DescribeServiceUpdatesMessage,
+    ) -> Result<ServiceUpdatesMessage, RusotoError<DescribeServiceUpdatesError>> {
+        self.runtime
+            .block_on(self.client.describe_service_updates(input))
+    }
+
+    /// Blocking version of [`ElastiCache::describe_snapshots`].
+    pub fn describe_snapshots(
+        &self,
+        input: DescribeSnapshotsMessage,
+    ) -> Result<DescribeSnapshotsListMessage, RusotoError<DescribeSnapshotsError>> {
+        self.runtime.block_on(self.client.describe_snapshots(input))
+    }
+
+    /// Blocking version of [`ElastiCache::describe_update_actions`].
+    pub fn describe_update_actions(
+        &self,
+        input: DescribeUpdateActionsMessage,
+    ) -> Result<UpdateActionsMessage, RusotoError<DescribeUpdateActionsError>> {
+        self.runtime
+            .block_on(self.client.describe_update_actions(input))
+    }
+
+    /// Blocking version of [`ElastiCache::describe_user_groups`].
+    pub fn describe_user_groups(
+        &self,
+        input: DescribeUserGroupsMessage,
+    ) -> Result<DescribeUserGroupsResult, RusotoError<DescribeUserGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_user_groups(input))
+    }
+
+    /// Blocking version of [`ElastiCache::describe_users`].
+    pub fn describe_users(
+        &self,
+        input: DescribeUsersMessage,
+    ) -> Result<DescribeUsersResult, RusotoError<DescribeUsersError>> {
+        self.runtime.block_on(self.client.describe_users(input))
+    }
+
+    /// Blocking version of [`ElastiCache::disassociate_global_replication_group`].
+    pub fn disassociate_global_replication_group(
+        &self,
+        input: DisassociateGlobalReplicationGroupMessage,
+    ) -> Result<
+        DisassociateGlobalReplicationGroupResult,
+        RusotoError<DisassociateGlobalReplicationGroupError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_global_replication_group(input))
+    }
+
+    /// Blocking version of [`ElastiCache::failover_global_replication_group`].
+    pub fn failover_global_replication_group(
+        &self,
+        input: FailoverGlobalReplicationGroupMessage,
+    ) -> Result<
+        FailoverGlobalReplicationGroupResult,
+        RusotoError<FailoverGlobalReplicationGroupError>,
+    > {
+        self.runtime
+            .block_on(self.client.failover_global_replication_group(input))
+    }
+
+    /// Blocking version of [`ElastiCache::increase_node_groups_in_global_replication_group`].
+    pub fn increase_node_groups_in_global_replication_group(
+        &self,
+        input: IncreaseNodeGroupsInGlobalReplicationGroupMessage,
+    ) -> Result<
+        IncreaseNodeGroupsInGlobalReplicationGroupResult,
+        RusotoError<IncreaseNodeGroupsInGlobalReplicationGroupError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .increase_node_groups_in_global_replication_group(input),
+        )
+    }
+
+    /// Blocking version of [`ElastiCache::increase_replica_count`].
+    pub fn increase_replica_count(
+        &self,
+        input: IncreaseReplicaCountMessage,
+    ) -> Result<IncreaseReplicaCountResult, RusotoError<IncreaseReplicaCountError>> {
+        self.runtime
+            .block_on(self.client.increase_replica_count(input))
+    }
+
+    /// Blocking version of [`ElastiCache::list_allowed_node_type_modifications`].
+    pub fn list_allowed_node_type_modifications(
+        &self,
+        input: ListAllowedNodeTypeModificationsMessage,
+    ) -> Result<
+        AllowedNodeTypeModificationsMessage,
+        RusotoError<ListAllowedNodeTypeModificationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_allowed_node_type_modifications(input))
+    }
+
+    /// Blocking version of [`ElastiCache::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> Result<TagListMessage, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`ElastiCache::modify_cache_cluster`].
+    pub fn modify_cache_cluster(
+        &self,
+        input: ModifyCacheClusterMessage,
+    ) -> Result<ModifyCacheClusterResult, RusotoError<ModifyCacheClusterError>> {
+        self.runtime
+            .block_on(self.client.modify_cache_cluster(input))
+    }
+
+    /// Blocking version of [`ElastiCache::modify_cache_parameter_group`].
+    pub fn modify_cache_parameter_group(
+        &self,
+        input: ModifyCacheParameterGroupMessage,
+    ) -> Result<CacheParameterGroupNameMessage, RusotoError<ModifyCacheParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.modify_cache_parameter_group(input))
+    }
+
+    /// Blocking version of [`ElastiCache::modify_cache_subnet_group`].
+    pub fn modify_cache_subnet_group(
+        &self,
+        input: ModifyCacheSubnetGroupMessage,
+    ) -> Result<ModifyCacheSubnetGroupResult, RusotoError<ModifyCacheSubnetGroupError>> {
+        self.runtime
+            .block_on(self.client.modify_cache_subnet_group(input))
+    }
+
+    /// Blocking version of [`ElastiCache::modify_global_replication_group`].
+    pub fn modify_global_replication_group(
+        &self,
+        input: ModifyGlobalReplicationGroupMessage,
+    ) -> Result<ModifyGlobalReplicationGroupResult, RusotoError<ModifyGlobalReplicationGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.modify_global_replication_group(input))
+    }
+
+    /// Blocking version of [`ElastiCache::modify_replication_group`].
+    pub fn modify_replication_group(
+        &self,
+        input: ModifyReplicationGroupMessage,
+    ) -> Result<ModifyReplicationGroupResult, RusotoError<ModifyReplicationGroupError>> {
+        self.runtime
+            .block_on(self.client.modify_replication_group(input))
+    }
+
+    /// Blocking version of [`ElastiCache::modify_replication_group_shard_configuration`].
+    pub fn modify_replication_group_shard_configuration(
+        &self,
+        input: ModifyReplicationGroupShardConfigurationMessage,
+    ) -> Result<
+        ModifyReplicationGroupShardConfigurationResult,
+        RusotoError<ModifyReplicationGroupShardConfigurationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .modify_replication_group_shard_configuration(input),
+        )
+    }
+
+    /// Blocking version of [`ElastiCache::modify_user`].
+    pub fn modify_user(
+        &self,
+        input: ModifyUserMessage,
+    ) -> Result<User, RusotoError<ModifyUserError>> {
+        self.runtime.block_on(self.client.modify_user(input))
+    }
+
+    /// Blocking version of [`ElastiCache::modify_user_group`].
+    pub fn modify_user_group(
+        &self,
+        input: ModifyUserGroupMessage,
+    ) -> Result<UserGroup, RusotoError<ModifyUserGroupError>> {
+        self.runtime.block_on(self.client.modify_user_group(input))
+    }
+
+    /// Blocking version of [`ElastiCache::purchase_reserved_cache_nodes_offering`].
+    pub fn purchase_reserved_cache_nodes_offering(
+        &self,
+        input: PurchaseReservedCacheNodesOfferingMessage,
+    ) -> Result<
+        PurchaseReservedCacheNodesOfferingResult,
+        RusotoError<PurchaseReservedCacheNodesOfferingError>,
+    > {
+        self.runtime
+            .block_on(self.client.purchase_reserved_cache_nodes_offering(input))
+    }
+
+    /// Blocking version of [`ElastiCache::rebalance_slots_in_global_replication_group`].
+    pub fn rebalance_slots_in_global_replication_group(
+        &self,
+        input: RebalanceSlotsInGlobalReplicationGroupMessage,
+    ) -> Result<
+        RebalanceSlotsInGlobalReplicationGroupResult,
+        RusotoError<RebalanceSlotsInGlobalReplicationGroupError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .rebalance_slots_in_global_replication_group(input),
+        )
+    }
+
+    /// Blocking version of [`ElastiCache::reboot_cache_cluster`].
+    pub fn reboot_cache_cluster(
+        &self,
+        input: RebootCacheClusterMessage,
+    ) -> Result<RebootCacheClusterResult, RusotoError<RebootCacheClusterError>> {
+        self.runtime
+            .block_on(self.client.reboot_cache_cluster(input))
+    }
+
+    /// Blocking version of [`ElastiCache::remove_tags_from_resource`].
+    pub fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceMessage,
+    ) -> Result<TagListMessage, RusotoError<RemoveTagsFromResourceError>> {
+        self.runtime
+            .block_on(self.client.remove_tags_from_resource(input))
+    }
+
+    /// Blocking version of [`ElastiCache::reset_cache_parameter_group`].
+    pub fn reset_cache_parameter_group(
+        &self,
+        input: ResetCacheParameterGroupMessage,
+    ) -> Result<CacheParameterGroupNameMessage, RusotoError<ResetCacheParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.reset_cache_parameter_group(input))
+    }
+
+    /// Blocking version of [`ElastiCache::revoke_cache_security_group_ingress`].
+    pub fn revoke_cache_security_group_ingress(
+        &self,
+        input: RevokeCacheSecurityGroupIngressMessage,
+    ) -> Result<
+        RevokeCacheSecurityGroupIngressResult,
+        RusotoError<RevokeCacheSecurityGroupIngressError>,
+    > {
+        self.runtime
+            .block_on(self.client.revoke_cache_security_group_ingress(input))
+    }
+
+    /// Blocking version of [`ElastiCache::start_migration`].
+    pub fn start_migration(
+        &self,
+        input: StartMigrationMessage,
+    ) -> Result<StartMigrationResponse, RusotoError<StartMigrationError>> {
+        self.runtime.block_on(self.client.start_migration(input))
+    }
+
+    /// Blocking version of [`ElastiCache::test_failover`].
+    pub fn test_failover(
+        &self,
+        input: TestFailoverMessage,
+    ) -> Result<TestFailoverResult, RusotoError<TestFailoverError>> {
+        self.runtime.block_on(self.client.test_failover(input))
+    }
+}
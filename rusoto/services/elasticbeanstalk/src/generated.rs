@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -7732,10 +7733,11 @@ impl AbortEnvironmentUpdateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
-                            AbortEnvironmentUpdateError::InsufficientPrivileges(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AbortEnvironmentUpdateError::InsufficientPrivileges,
                         )
                     }
                     _ => {}
@@ -7784,17 +7786,19 @@ impl ApplyEnvironmentManagedActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ElasticBeanstalkServiceException" => {
-                        return RusotoError::Service(
-                            ApplyEnvironmentManagedActionError::ElasticBeanstalkService(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ApplyEnvironmentManagedActionError::ElasticBeanstalkService,
                         )
                     }
                     "ManagedActionInvalidStateException" => {
-                        return RusotoError::Service(
-                            ApplyEnvironmentManagedActionError::ManagedActionInvalidState(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ApplyEnvironmentManagedActionError::ManagedActionInvalidState,
                         )
                     }
                     _ => {}
@@ -7844,10 +7848,11 @@ impl AssociateEnvironmentOperationsRoleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
-                            AssociateEnvironmentOperationsRoleError::InsufficientPrivileges(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AssociateEnvironmentOperationsRoleError::InsufficientPrivileges,
                         )
                     }
                     _ => {}
@@ -7928,14 +7933,20 @@ impl ComposeEnvironmentsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
-                            ComposeEnvironmentsError::InsufficientPrivileges(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ComposeEnvironmentsError::InsufficientPrivileges,
                         )
                     }
                     "TooManyEnvironmentsException" => {
-                        return RusotoError::Service(ComposeEnvironmentsError::TooManyEnvironments(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ComposeEnvironmentsError::TooManyEnvironments,
+                        )
                     }
                     _ => {}
                 }
@@ -7978,9 +7989,12 @@ impl CreateApplicationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "TooManyApplicationsException" => {
-                        return RusotoError::Service(CreateApplicationError::TooManyApplications(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateApplicationError::TooManyApplications,
+                        )
                     }
                     _ => {}
                 }
@@ -8030,38 +8044,43 @@ impl CreateApplicationVersionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CodeBuildNotInServiceRegionException" => {
-                        return RusotoError::Service(
-                            CreateApplicationVersionError::CodeBuildNotInServiceRegion(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateApplicationVersionError::CodeBuildNotInServiceRegion,
                         )
                     }
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
-                            CreateApplicationVersionError::InsufficientPrivileges(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateApplicationVersionError::InsufficientPrivileges,
                         )
                     }
                     "S3LocationNotInServiceRegionException" => {
-                        return RusotoError::Service(
-                            CreateApplicationVersionError::S3LocationNotInServiceRegion(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateApplicationVersionError::S3LocationNotInServiceRegion,
                         )
                     }
                     "TooManyApplicationVersionsException" => {
-                        return RusotoError::Service(
-                            CreateApplicationVersionError::TooManyApplicationVersions(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateApplicationVersionError::TooManyApplicationVersions,
                         )
                     }
                     "TooManyApplicationsException" => {
-                        return RusotoError::Service(
-                            CreateApplicationVersionError::TooManyApplications(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateApplicationVersionError::TooManyApplications,
                         )
                     }
                     _ => {}
@@ -8122,22 +8141,27 @@ impl CreateConfigurationTemplateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
-                            CreateConfigurationTemplateError::InsufficientPrivileges(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateConfigurationTemplateError::InsufficientPrivileges,
                         )
                     }
                     "TooManyBucketsException" => {
-                        return RusotoError::Service(
-                            CreateConfigurationTemplateError::TooManyBuckets(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateConfigurationTemplateError::TooManyBuckets,
                         )
                     }
                     "TooManyConfigurationTemplatesException" => {
-                        return RusotoError::Service(
-                            CreateConfigurationTemplateError::TooManyConfigurationTemplates(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateConfigurationTemplateError::TooManyConfigurationTemplates,
                         )
                     }
                     _ => {}
@@ -8188,14 +8212,20 @@ impl CreateEnvironmentError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
-                            CreateEnvironmentError::InsufficientPrivileges(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateEnvironmentError::InsufficientPrivileges,
                         )
                     }
                     "TooManyEnvironmentsException" => {
-                        return RusotoError::Service(CreateEnvironmentError::TooManyEnvironments(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateEnvironmentError::TooManyEnvironments,
+                        )
                     }
                     _ => {}
                 }
@@ -8242,23 +8272,28 @@ impl CreatePlatformVersionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ElasticBeanstalkServiceException" => {
-                        return RusotoError::Service(
-                            CreatePlatformVersionError::ElasticBeanstalkService(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreatePlatformVersionError::ElasticBeanstalkService,
                         )
                     }
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
-                            CreatePlatformVersionError::InsufficientPrivileges(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreatePlatformVersionError::InsufficientPrivileges,
                         )
                     }
                     "TooManyPlatformsException" => {
-                        return RusotoError::Service(CreatePlatformVersionError::TooManyPlatforms(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreatePlatformVersionError::TooManyPlatforms,
+                        )
                     }
                     _ => {}
                 }
@@ -8308,23 +8343,28 @@ impl CreateStorageLocationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
-                            CreateStorageLocationError::InsufficientPrivileges(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateStorageLocationError::InsufficientPrivileges,
                         )
                     }
                     "S3SubscriptionRequiredException" => {
-                        return RusotoError::Service(
-                            CreateStorageLocationError::S3SubscriptionRequired(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateStorageLocationError::S3SubscriptionRequired,
                         )
                     }
                     "TooManyBucketsException" => {
-                        return RusotoError::Service(CreateStorageLocationError::TooManyBuckets(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateStorageLocationError::TooManyBuckets,
+                        )
                     }
                     _ => {}
                 }
@@ -8368,9 +8408,12 @@ impl DeleteApplicationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "OperationInProgressFailure" => {
-                        return RusotoError::Service(DeleteApplicationError::OperationInProgress(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteApplicationError::OperationInProgress,
+                        )
                     }
                     _ => {}
                 }
@@ -8418,31 +8461,35 @@ impl DeleteApplicationVersionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
-                            DeleteApplicationVersionError::InsufficientPrivileges(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteApplicationVersionError::InsufficientPrivileges,
                         )
                     }
                     "OperationInProgressFailure" => {
-                        return RusotoError::Service(
-                            DeleteApplicationVersionError::OperationInProgress(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteApplicationVersionError::OperationInProgress,
                         )
                     }
                     "S3LocationNotInServiceRegionException" => {
-                        return RusotoError::Service(
-                            DeleteApplicationVersionError::S3LocationNotInServiceRegion(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteApplicationVersionError::S3LocationNotInServiceRegion,
                         )
                     }
                     "SourceBundleDeletionFailure" => {
-                        return RusotoError::Service(
-                            DeleteApplicationVersionError::SourceBundleDeletion(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteApplicationVersionError::SourceBundleDeletion,
                         )
                     }
                     _ => {}
@@ -8496,10 +8543,11 @@ impl DeleteConfigurationTemplateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "OperationInProgressFailure" => {
-                        return RusotoError::Service(
-                            DeleteConfigurationTemplateError::OperationInProgress(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteConfigurationTemplateError::OperationInProgress,
                         )
                     }
                     _ => {}
@@ -8586,29 +8634,35 @@ impl DeletePlatformVersionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ElasticBeanstalkServiceException" => {
-                        return RusotoError::Service(
-                            DeletePlatformVersionError::ElasticBeanstalkService(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeletePlatformVersionError::ElasticBeanstalkService,
                         )
                     }
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
-                            DeletePlatformVersionError::InsufficientPrivileges(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeletePlatformVersionError::InsufficientPrivileges,
                         )
                     }
                     "OperationInProgressFailure" => {
-                        return RusotoError::Service(
-                            DeletePlatformVersionError::OperationInProgress(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeletePlatformVersionError::OperationInProgress,
                         )
                     }
                     "PlatformVersionStillReferencedException" => {
-                        return RusotoError::Service(
-                            DeletePlatformVersionError::PlatformVersionStillReferenced(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeletePlatformVersionError::PlatformVersionStillReferenced,
                         )
                     }
                     _ => {}
@@ -8658,10 +8712,11 @@ impl DescribeAccountAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
-                            DescribeAccountAttributesError::InsufficientPrivileges(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeAccountAttributesError::InsufficientPrivileges,
                         )
                     }
                     _ => {}
@@ -8778,8 +8833,11 @@ impl DescribeConfigurationOptionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "TooManyBucketsException" => {
-                        return RusotoError::Service(
-                            DescribeConfigurationOptionsError::TooManyBuckets(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeConfigurationOptionsError::TooManyBuckets,
                         )
                     }
                     _ => {}
@@ -8824,10 +8882,11 @@ impl DescribeConfigurationSettingsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "TooManyBucketsException" => {
-                        return RusotoError::Service(
-                            DescribeConfigurationSettingsError::TooManyBuckets(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeConfigurationSettingsError::TooManyBuckets,
                         )
                     }
                     _ => {}
@@ -8872,15 +8931,19 @@ impl DescribeEnvironmentHealthError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ElasticBeanstalkServiceException" => {
-                        return RusotoError::Service(
-                            DescribeEnvironmentHealthError::ElasticBeanstalkService(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeEnvironmentHealthError::ElasticBeanstalkService,
                         )
                     }
                     "InvalidRequestException" => {
-                        return RusotoError::Service(
-                            DescribeEnvironmentHealthError::InvalidRequest(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeEnvironmentHealthError::InvalidRequest,
                         )
                     }
                     _ => {}
@@ -8928,10 +8991,11 @@ impl DescribeEnvironmentManagedActionHistoryError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ElasticBeanstalkServiceException" => {
-                        return RusotoError::Service(
-                            DescribeEnvironmentManagedActionHistoryError::ElasticBeanstalkService(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeEnvironmentManagedActionHistoryError::ElasticBeanstalkService,
                         )
                     }
                     _ => {}
@@ -8978,10 +9042,11 @@ impl DescribeEnvironmentManagedActionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ElasticBeanstalkServiceException" => {
-                        return RusotoError::Service(
-                            DescribeEnvironmentManagedActionsError::ElasticBeanstalkService(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeEnvironmentManagedActionsError::ElasticBeanstalkService,
                         )
                     }
                     _ => {}
@@ -9028,10 +9093,11 @@ impl DescribeEnvironmentResourcesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
-                            DescribeEnvironmentResourcesError::InsufficientPrivileges(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeEnvironmentResourcesError::InsufficientPrivileges,
                         )
                     }
                     _ => {}
@@ -9146,16 +9212,20 @@ impl DescribeInstancesHealthError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ElasticBeanstalkServiceException" => {
-                        return RusotoError::Service(
-                            DescribeInstancesHealthError::ElasticBeanstalkService(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeInstancesHealthError::ElasticBeanstalkService,
                         )
                     }
                     "InvalidRequestException" => {
-                        return RusotoError::Service(DescribeInstancesHealthError::InvalidRequest(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeInstancesHealthError::InvalidRequest,
+                        )
                     }
                     _ => {}
                 }
@@ -9202,17 +9272,19 @@ impl DescribePlatformVersionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ElasticBeanstalkServiceException" => {
-                        return RusotoError::Service(
-                            DescribePlatformVersionError::ElasticBeanstalkService(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribePlatformVersionError::ElasticBeanstalkService,
                         )
                     }
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
-                            DescribePlatformVersionError::InsufficientPrivileges(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribePlatformVersionError::InsufficientPrivileges,
                         )
                     }
                     _ => {}
@@ -9262,10 +9334,11 @@ impl DisassociateEnvironmentOperationsRoleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
-                            DisassociateEnvironmentOperationsRoleError::InsufficientPrivileges(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DisassociateEnvironmentOperationsRoleError::InsufficientPrivileges,
                         )
                     }
                     _ => {}
@@ -9382,15 +9455,19 @@ impl ListPlatformVersionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ElasticBeanstalkServiceException" => {
-                        return RusotoError::Service(
-                            ListPlatformVersionsError::ElasticBeanstalkService(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListPlatformVersionsError::ElasticBeanstalkService,
                         )
                     }
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
-                            ListPlatformVersionsError::InsufficientPrivileges(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListPlatformVersionsError::InsufficientPrivileges,
                         )
                     }
                     _ => {}
@@ -9438,20 +9515,27 @@ impl ListTagsForResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
-                            ListTagsForResourceError::InsufficientPrivileges(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListTagsForResourceError::InsufficientPrivileges,
                         )
                     }
                     "ResourceNotFoundException" => {
-                        return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListTagsForResourceError::ResourceNotFound,
+                        )
                     }
                     "ResourceTypeNotSupportedException" => {
-                        return RusotoError::Service(
-                            ListTagsForResourceError::ResourceTypeNotSupported(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListTagsForResourceError::ResourceTypeNotSupported,
                         )
                     }
                     _ => {}
@@ -9496,8 +9580,11 @@ impl RebuildEnvironmentError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
-                            RebuildEnvironmentError::InsufficientPrivileges(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RebuildEnvironmentError::InsufficientPrivileges,
                         )
                     }
                     _ => {}
@@ -9676,8 +9763,11 @@ impl TerminateEnvironmentError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
-                            TerminateEnvironmentError::InsufficientPrivileges(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            TerminateEnvironmentError::InsufficientPrivileges,
                         )
                     }
                     _ => {}
@@ -9756,10 +9846,11 @@ impl UpdateApplicationResourceLifecycleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
-                            UpdateApplicationResourceLifecycleError::InsufficientPrivileges(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateApplicationResourceLifecycleError::InsufficientPrivileges,
                         )
                     }
                     _ => {}
@@ -9842,15 +9933,19 @@ impl UpdateConfigurationTemplateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
-                            UpdateConfigurationTemplateError::InsufficientPrivileges(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateConfigurationTemplateError::InsufficientPrivileges,
                         )
                     }
                     "TooManyBucketsException" => {
-                        return RusotoError::Service(
-                            UpdateConfigurationTemplateError::TooManyBuckets(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateConfigurationTemplateError::TooManyBuckets,
                         )
                     }
                     _ => {}
@@ -9898,14 +9993,20 @@ impl UpdateEnvironmentError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
-                            UpdateEnvironmentError::InsufficientPrivileges(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateEnvironmentError::InsufficientPrivileges,
                         )
                     }
                     "TooManyBucketsException" => {
-                        return RusotoError::Service(UpdateEnvironmentError::TooManyBuckets(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateEnvironmentError::TooManyBuckets,
+                        )
                     }
                     _ => {}
                 }
@@ -9956,33 +10057,44 @@ impl UpdateTagsForResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
-                            UpdateTagsForResourceError::InsufficientPrivileges(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateTagsForResourceError::InsufficientPrivileges,
                         )
                     }
                     "OperationInProgressFailure" => {
-                        return RusotoError::Service(
-                            UpdateTagsForResourceError::OperationInProgress(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateTagsForResourceError::OperationInProgress,
                         )
                     }
                     "ResourceNotFoundException" => {
-                        return RusotoError::Service(UpdateTagsForResourceError::ResourceNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateTagsForResourceError::ResourceNotFound,
+                        )
                     }
                     "ResourceTypeNotSupportedException" => {
-                        return RusotoError::Service(
-                            UpdateTagsForResourceError::ResourceTypeNotSupported(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateTagsForResourceError::ResourceTypeNotSupported,
                         )
                     }
                     "TooManyTagsException" => {
-                        return RusotoError::Service(UpdateTagsForResourceError::TooManyTags(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateTagsForResourceError::TooManyTags,
+                        )
                     }
                     _ => {}
                 }
@@ -10034,17 +10146,19 @@ impl ValidateConfigurationSettingsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InsufficientPrivilegesException" => {
-                        return RusotoError::Service(
-                            ValidateConfigurationSettingsError::InsufficientPrivileges(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ValidateConfigurationSettingsError::InsufficientPrivileges,
                         )
                     }
                     "TooManyBucketsException" => {
-                        return RusotoError::Service(
-                            ValidateConfigurationSettingsError::TooManyBuckets(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ValidateConfigurationSettingsError::TooManyBuckets,
                         )
                     }
                     _ => {}
@@ -10384,11 +10498,12 @@ pub struct ElasticBeanstalkClient {
 impl ElasticBeanstalkClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> ElasticBeanstalkClient {
         ElasticBeanstalkClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Elastic Beanstalk"),
         }
     }
 
@@ -10403,12 +10518,32 @@ impl ElasticBeanstalkClient {
     {
         ElasticBeanstalkClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Elastic Beanstalk"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ElasticBeanstalkClient {
-        ElasticBeanstalkClient { client, region }
+        ElasticBeanstalkClient {
+            client,
+            region: region_for_service(region, "Elastic Beanstalk"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> ElasticBeanstalkClient {
+        ElasticBeanstalkClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Elastic Beanstalk"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ElasticBeanstalkClient {
+        ElasticBeanstalkClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -10420,6 +10555,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: AbortEnvironmentUpdateMessage,
     ) -> Result<(), RusotoError<AbortEnvironmentUpdateError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("AbortEnvironmentUpdate");
         let params = self.new_params("AbortEnvironmentUpdate");
         let mut params = params;
         AbortEnvironmentUpdateMessageSerializer::serialize(&mut params, "", &input);
@@ -10441,6 +10577,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
     ) -> Result<ApplyEnvironmentManagedActionResult, RusotoError<ApplyEnvironmentManagedActionError>>
     {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("ApplyEnvironmentManagedAction");
         let params = self.new_params("ApplyEnvironmentManagedAction");
         let mut params = params;
         ApplyEnvironmentManagedActionRequestSerializer::serialize(&mut params, "", &input);
@@ -10474,6 +10611,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: AssociateEnvironmentOperationsRoleMessage,
     ) -> Result<(), RusotoError<AssociateEnvironmentOperationsRoleError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("AssociateEnvironmentOperationsRole");
         let params = self.new_params("AssociateEnvironmentOperationsRole");
         let mut params = params;
         AssociateEnvironmentOperationsRoleMessageSerializer::serialize(&mut params, "", &input);
@@ -10497,6 +10635,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: CheckDNSAvailabilityMessage,
     ) -> Result<CheckDNSAvailabilityResultMessage, RusotoError<CheckDNSAvailabilityError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("CheckDNSAvailability");
         let params = self.new_params("CheckDNSAvailability");
         let mut params = params;
         CheckDNSAvailabilityMessageSerializer::serialize(&mut params, "", &input);
@@ -10530,6 +10669,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: ComposeEnvironmentsMessage,
     ) -> Result<EnvironmentDescriptionsMessage, RusotoError<ComposeEnvironmentsError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("ComposeEnvironments");
         let params = self.new_params("ComposeEnvironments");
         let mut params = params;
         ComposeEnvironmentsMessageSerializer::serialize(&mut params, "", &input);
@@ -10563,6 +10703,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: CreateApplicationMessage,
     ) -> Result<ApplicationDescriptionMessage, RusotoError<CreateApplicationError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("CreateApplication");
         let params = self.new_params("CreateApplication");
         let mut params = params;
         CreateApplicationMessageSerializer::serialize(&mut params, "", &input);
@@ -10597,6 +10738,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
     ) -> Result<ApplicationVersionDescriptionMessage, RusotoError<CreateApplicationVersionError>>
     {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("CreateApplicationVersion");
         let params = self.new_params("CreateApplicationVersion");
         let mut params = params;
         CreateApplicationVersionMessageSerializer::serialize(&mut params, "", &input);
@@ -10631,6 +10773,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
     ) -> Result<ConfigurationSettingsDescription, RusotoError<CreateConfigurationTemplateError>>
     {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("CreateConfigurationTemplate");
         let params = self.new_params("CreateConfigurationTemplate");
         let mut params = params;
         CreateConfigurationTemplateMessageSerializer::serialize(&mut params, "", &input);
@@ -10664,6 +10807,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: CreateEnvironmentMessage,
     ) -> Result<EnvironmentDescription, RusotoError<CreateEnvironmentError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("CreateEnvironment");
         let params = self.new_params("CreateEnvironment");
         let mut params = params;
         CreateEnvironmentMessageSerializer::serialize(&mut params, "", &input);
@@ -10695,6 +10839,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: CreatePlatformVersionRequest,
     ) -> Result<CreatePlatformVersionResult, RusotoError<CreatePlatformVersionError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("CreatePlatformVersion");
         let params = self.new_params("CreatePlatformVersion");
         let mut params = params;
         CreatePlatformVersionRequestSerializer::serialize(&mut params, "", &input);
@@ -10727,6 +10872,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         &self,
     ) -> Result<CreateStorageLocationResultMessage, RusotoError<CreateStorageLocationError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("CreateStorageLocation");
         let params = self.new_params("CreateStorageLocation");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -10759,6 +10905,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: DeleteApplicationMessage,
     ) -> Result<(), RusotoError<DeleteApplicationError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DeleteApplication");
         let params = self.new_params("DeleteApplication");
         let mut params = params;
         DeleteApplicationMessageSerializer::serialize(&mut params, "", &input);
@@ -10779,6 +10926,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: DeleteApplicationVersionMessage,
     ) -> Result<(), RusotoError<DeleteApplicationVersionError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DeleteApplicationVersion");
         let params = self.new_params("DeleteApplicationVersion");
         let mut params = params;
         DeleteApplicationVersionMessageSerializer::serialize(&mut params, "", &input);
@@ -10799,6 +10947,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: DeleteConfigurationTemplateMessage,
     ) -> Result<(), RusotoError<DeleteConfigurationTemplateError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DeleteConfigurationTemplate");
         let params = self.new_params("DeleteConfigurationTemplate");
         let mut params = params;
         DeleteConfigurationTemplateMessageSerializer::serialize(&mut params, "", &input);
@@ -10819,6 +10968,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: DeleteEnvironmentConfigurationMessage,
     ) -> Result<(), RusotoError<DeleteEnvironmentConfigurationError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DeleteEnvironmentConfiguration");
         let params = self.new_params("DeleteEnvironmentConfiguration");
         let mut params = params;
         DeleteEnvironmentConfigurationMessageSerializer::serialize(&mut params, "", &input);
@@ -10839,6 +10989,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: DeletePlatformVersionRequest,
     ) -> Result<DeletePlatformVersionResult, RusotoError<DeletePlatformVersionError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DeletePlatformVersion");
         let params = self.new_params("DeletePlatformVersion");
         let mut params = params;
         DeletePlatformVersionRequestSerializer::serialize(&mut params, "", &input);
@@ -10871,6 +11022,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         &self,
     ) -> Result<DescribeAccountAttributesResult, RusotoError<DescribeAccountAttributesError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeAccountAttributes");
         let params = self.new_params("DescribeAccountAttributes");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -10904,6 +11056,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
     ) -> Result<ApplicationVersionDescriptionsMessage, RusotoError<DescribeApplicationVersionsError>>
     {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeApplicationVersions");
         let params = self.new_params("DescribeApplicationVersions");
         let mut params = params;
         DescribeApplicationVersionsMessageSerializer::serialize(&mut params, "", &input);
@@ -10937,6 +11090,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: DescribeApplicationsMessage,
     ) -> Result<ApplicationDescriptionsMessage, RusotoError<DescribeApplicationsError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeApplications");
         let params = self.new_params("DescribeApplications");
         let mut params = params;
         DescribeApplicationsMessageSerializer::serialize(&mut params, "", &input);
@@ -10971,6 +11125,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
     ) -> Result<ConfigurationOptionsDescription, RusotoError<DescribeConfigurationOptionsError>>
     {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeConfigurationOptions");
         let params = self.new_params("DescribeConfigurationOptions");
         let mut params = params;
         DescribeConfigurationOptionsMessageSerializer::serialize(&mut params, "", &input);
@@ -11005,6 +11160,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
     ) -> Result<ConfigurationSettingsDescriptions, RusotoError<DescribeConfigurationSettingsError>>
     {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeConfigurationSettings");
         let params = self.new_params("DescribeConfigurationSettings");
         let mut params = params;
         DescribeConfigurationSettingsMessageSerializer::serialize(&mut params, "", &input);
@@ -11038,6 +11194,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: DescribeEnvironmentHealthRequest,
     ) -> Result<DescribeEnvironmentHealthResult, RusotoError<DescribeEnvironmentHealthError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeEnvironmentHealth");
         let params = self.new_params("DescribeEnvironmentHealth");
         let mut params = params;
         DescribeEnvironmentHealthRequestSerializer::serialize(&mut params, "", &input);
@@ -11074,6 +11231,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         RusotoError<DescribeEnvironmentManagedActionHistoryError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeEnvironmentManagedActionHistory");
         let params = self.new_params("DescribeEnvironmentManagedActionHistory");
         let mut params = params;
         DescribeEnvironmentManagedActionHistoryRequestSerializer::serialize(
@@ -11117,6 +11275,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         RusotoError<DescribeEnvironmentManagedActionsError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeEnvironmentManagedActions");
         let params = self.new_params("DescribeEnvironmentManagedActions");
         let mut params = params;
         DescribeEnvironmentManagedActionsRequestSerializer::serialize(&mut params, "", &input);
@@ -11156,6 +11315,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         RusotoError<DescribeEnvironmentResourcesError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeEnvironmentResources");
         let params = self.new_params("DescribeEnvironmentResources");
         let mut params = params;
         DescribeEnvironmentResourcesMessageSerializer::serialize(&mut params, "", &input);
@@ -11189,6 +11349,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: DescribeEnvironmentsMessage,
     ) -> Result<EnvironmentDescriptionsMessage, RusotoError<DescribeEnvironmentsError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeEnvironments");
         let params = self.new_params("DescribeEnvironments");
         let mut params = params;
         DescribeEnvironmentsMessageSerializer::serialize(&mut params, "", &input);
@@ -11222,6 +11383,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: DescribeEventsMessage,
     ) -> Result<EventDescriptionsMessage, RusotoError<DescribeEventsError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeEvents");
         let params = self.new_params("DescribeEvents");
         let mut params = params;
         DescribeEventsMessageSerializer::serialize(&mut params, "", &input);
@@ -11253,6 +11415,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: DescribeInstancesHealthRequest,
     ) -> Result<DescribeInstancesHealthResult, RusotoError<DescribeInstancesHealthError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribeInstancesHealth");
         let params = self.new_params("DescribeInstancesHealth");
         let mut params = params;
         DescribeInstancesHealthRequestSerializer::serialize(&mut params, "", &input);
@@ -11286,6 +11449,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: DescribePlatformVersionRequest,
     ) -> Result<DescribePlatformVersionResult, RusotoError<DescribePlatformVersionError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DescribePlatformVersion");
         let params = self.new_params("DescribePlatformVersion");
         let mut params = params;
         DescribePlatformVersionRequestSerializer::serialize(&mut params, "", &input);
@@ -11319,6 +11483,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: DisassociateEnvironmentOperationsRoleMessage,
     ) -> Result<(), RusotoError<DisassociateEnvironmentOperationsRoleError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("DisassociateEnvironmentOperationsRole");
         let params = self.new_params("DisassociateEnvironmentOperationsRole");
         let mut params = params;
         DisassociateEnvironmentOperationsRoleMessageSerializer::serialize(&mut params, "", &input);
@@ -11344,6 +11509,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         RusotoError<ListAvailableSolutionStacksError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("ListAvailableSolutionStacks");
         let params = self.new_params("ListAvailableSolutionStacks");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -11376,6 +11542,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: ListPlatformBranchesRequest,
     ) -> Result<ListPlatformBranchesResult, RusotoError<ListPlatformBranchesError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("ListPlatformBranches");
         let params = self.new_params("ListPlatformBranches");
         let mut params = params;
         ListPlatformBranchesRequestSerializer::serialize(&mut params, "", &input);
@@ -11409,6 +11576,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: ListPlatformVersionsRequest,
     ) -> Result<ListPlatformVersionsResult, RusotoError<ListPlatformVersionsError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("ListPlatformVersions");
         let params = self.new_params("ListPlatformVersions");
         let mut params = params;
         ListPlatformVersionsRequestSerializer::serialize(&mut params, "", &input);
@@ -11442,6 +11610,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: ListTagsForResourceMessage,
     ) -> Result<ResourceTagsDescriptionMessage, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("ListTagsForResource");
         let params = self.new_params("ListTagsForResource");
         let mut params = params;
         ListTagsForResourceMessageSerializer::serialize(&mut params, "", &input);
@@ -11475,6 +11644,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: RebuildEnvironmentMessage,
     ) -> Result<(), RusotoError<RebuildEnvironmentError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("RebuildEnvironment");
         let params = self.new_params("RebuildEnvironment");
         let mut params = params;
         RebuildEnvironmentMessageSerializer::serialize(&mut params, "", &input);
@@ -11495,6 +11665,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: RequestEnvironmentInfoMessage,
     ) -> Result<(), RusotoError<RequestEnvironmentInfoError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("RequestEnvironmentInfo");
         let params = self.new_params("RequestEnvironmentInfo");
         let mut params = params;
         RequestEnvironmentInfoMessageSerializer::serialize(&mut params, "", &input);
@@ -11515,6 +11686,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: RestartAppServerMessage,
     ) -> Result<(), RusotoError<RestartAppServerError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("RestartAppServer");
         let params = self.new_params("RestartAppServer");
         let mut params = params;
         RestartAppServerMessageSerializer::serialize(&mut params, "", &input);
@@ -11536,6 +11708,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
     ) -> Result<RetrieveEnvironmentInfoResultMessage, RusotoError<RetrieveEnvironmentInfoError>>
     {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("RetrieveEnvironmentInfo");
         let params = self.new_params("RetrieveEnvironmentInfo");
         let mut params = params;
         RetrieveEnvironmentInfoMessageSerializer::serialize(&mut params, "", &input);
@@ -11569,6 +11742,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: SwapEnvironmentCNAMEsMessage,
     ) -> Result<(), RusotoError<SwapEnvironmentCNAMEsError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("SwapEnvironmentCNAMEs");
         let params = self.new_params("SwapEnvironmentCNAMEs");
         let mut params = params;
         SwapEnvironmentCNAMEsMessageSerializer::serialize(&mut params, "", &input);
@@ -11589,6 +11763,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: TerminateEnvironmentMessage,
     ) -> Result<EnvironmentDescription, RusotoError<TerminateEnvironmentError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("TerminateEnvironment");
         let params = self.new_params("TerminateEnvironment");
         let mut params = params;
         TerminateEnvironmentMessageSerializer::serialize(&mut params, "", &input);
@@ -11622,6 +11797,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: UpdateApplicationMessage,
     ) -> Result<ApplicationDescriptionMessage, RusotoError<UpdateApplicationError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("UpdateApplication");
         let params = self.new_params("UpdateApplication");
         let mut params = params;
         UpdateApplicationMessageSerializer::serialize(&mut params, "", &input);
@@ -11658,6 +11834,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         RusotoError<UpdateApplicationResourceLifecycleError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("UpdateApplicationResourceLifecycle");
         let params = self.new_params("UpdateApplicationResourceLifecycle");
         let mut params = params;
         UpdateApplicationResourceLifecycleMessageSerializer::serialize(&mut params, "", &input);
@@ -11695,6 +11872,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
     ) -> Result<ApplicationVersionDescriptionMessage, RusotoError<UpdateApplicationVersionError>>
     {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("UpdateApplicationVersion");
         let params = self.new_params("UpdateApplicationVersion");
         let mut params = params;
         UpdateApplicationVersionMessageSerializer::serialize(&mut params, "", &input);
@@ -11729,6 +11907,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
     ) -> Result<ConfigurationSettingsDescription, RusotoError<UpdateConfigurationTemplateError>>
     {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("UpdateConfigurationTemplate");
         let params = self.new_params("UpdateConfigurationTemplate");
         let mut params = params;
         UpdateConfigurationTemplateMessageSerializer::serialize(&mut params, "", &input);
@@ -11762,6 +11941,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: UpdateEnvironmentMessage,
     ) -> Result<EnvironmentDescription, RusotoError<UpdateEnvironmentError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("UpdateEnvironment");
         let params = self.new_params("UpdateEnvironment");
         let mut params = params;
         UpdateEnvironmentMessageSerializer::serialize(&mut params, "", &input);
@@ -11793,6 +11973,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         input: UpdateTagsForResourceMessage,
     ) -> Result<(), RusotoError<UpdateTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("UpdateTagsForResource");
         let params = self.new_params("UpdateTagsForResource");
         let mut params = params;
         UpdateTagsForResourceMessageSerializer::serialize(&mut params, "", &input);
@@ -11816,6 +11997,7 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
         RusotoError<ValidateConfigurationSettingsError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticbeanstalk", &self.region, "/");
+        request.set_operation("ValidateConfigurationSettings");
         let params = self.new_params("ValidateConfigurationSettings");
         let mut params = params;
         ValidateConfigurationSettingsMessageSerializer::serialize(&mut params, "", &input);
@@ -11844,6 +12026,530 @@ impl ElasticBeanstalk for ElasticBeanstalkClient {
     }
 }
 
+/// A blocking client for the Elastic Beanstalk API, running the calls of a `ElasticBeanstalkClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `ElasticBeanstalkClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ElasticBeanstalkBlockingClient {
+    client: ElasticBeanstalkClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ElasticBeanstalkBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> ElasticBeanstalkBlockingClient {
+        ElasticBeanstalkBlockingClient::from_client(ElasticBeanstalkClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ElasticBeanstalkBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ElasticBeanstalkBlockingClient::from_client(ElasticBeanstalkClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> ElasticBeanstalkBlockingClient {
+        ElasticBeanstalkBlockingClient::from_client(ElasticBeanstalkClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> ElasticBeanstalkBlockingClient {
+        ElasticBeanstalkBlockingClient::from_client(ElasticBeanstalkClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: ElasticBeanstalkClient) -> ElasticBeanstalkBlockingClient {
+        ElasticBeanstalkBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> ElasticBeanstalkBlockingClient {
+        ElasticBeanstalkBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ElasticBeanstalkBlockingClient {
+        ElasticBeanstalkBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::abort_environment_update`].
+    pub fn abort_environment_update(
+        &self,
+        input: AbortEnvironmentUpdateMessage,
+    ) -> Result<(), RusotoError<AbortEnvironmentUpdateError>> {
+        self.runtime
+            .block_on(self.client.abort_environment_update(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::apply_environment_managed_action`].
+    pub fn apply_environment_managed_action(
+        &self,
+        input: ApplyEnvironmentManagedActionRequest,
+    ) -> Result<ApplyEnvironmentManagedActionResult, RusotoError<ApplyEnvironmentManagedActionError>>
+    {
+        self.runtime
+            .block_on(self.client.apply_environment_managed_action(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::associate_environment_operations_role`].
+    pub fn associate_environment_operations_role(
+        &self,
+        input: AssociateEnvironmentOperationsRoleMessage,
+    ) -> Result<(), RusotoError<AssociateEnvironmentOperationsRoleError>> {
+        self.runtime
+            .block_on(self.client.associate_environment_operations_role(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::check_dns_availability`].
+    pub fn check_dns_availability(
+        &self,
+        input: CheckDNSAvailabilityMessage,
+    ) -> Result<CheckDNSAvailabilityResultMessage, RusotoError<CheckDNSAvailabilityError>> {
+        self.runtime
+            .block_on(self.client.check_dns_availability(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::compose_environments`].
+    pub fn compose_environments(
+        &self,
+        input: ComposeEnvironmentsMessage,
+    ) -> Result<EnvironmentDescriptionsMessage, RusotoError<ComposeEnvironmentsError>> {
+        self.runtime
+            .block_on(self.client.compose_environments(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::create_application`].
+    pub fn create_application(
+        &self,
+        input: CreateApplicationMessage,
+    ) -> Result<ApplicationDescriptionMessage, RusotoError<CreateApplicationError>> {
+        self.runtime.block_on(self.client.create_application(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::create_application_version`].
+    pub fn create_application_version(
+        &self,
+        input: CreateApplicationVersionMessage,
+    ) -> Result<ApplicationVersionDescriptionMessage, RusotoError<CreateApplicationVersionError>>
+    {
+        self.runtime
+            .block_on(self.client.create_application_version(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::create_configuration_template`].
+    pub fn create_configuration_template(
+        &self,
+        input: CreateConfigurationTemplateMessage,
+    ) -> Result<ConfigurationSettingsDescription, RusotoError<CreateConfigurationTemplateError>>
+    {
+        self.runtime
+            .block_on(self.client.create_configuration_template(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::create_environment`].
+    pub fn create_environment(
+        &self,
+        input: CreateEnvironmentMessage,
+    ) -> Result<EnvironmentDescription, RusotoError<CreateEnvironmentError>> {
+        self.runtime.block_on(self.client.create_environment(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::create_platform_version`].
+    pub fn create_platform_version(
+        &self,
+        input: CreatePlatformVersionRequest,
+    ) -> Result<CreatePlatformVersionResult, RusotoError<CreatePlatformVersionError>> {
+        self.runtime
+            .block_on(self.client.create_platform_version(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::create_storage_location`].
+    pub fn create_storage_location(
+        &self,
+    ) -> Result<CreateStorageLocationResultMessage, RusotoError<CreateStorageLocationError>> {
+        self.runtime.block_on(self.client.create_storage_location())
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::delete_application`].
+    pub fn delete_application(
+        &self,
+        input: DeleteApplicationMessage,
+    ) -> Result<(), RusotoError<DeleteApplicationError>> {
+        self.runtime.block_on(self.client.delete_application(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::delete_application_version`].
+    pub fn delete_application_version(
+        &self,
+        input: DeleteApplicationVersionMessage,
+    ) -> Result<(), RusotoError<DeleteApplicationVersionError>> {
+        self.runtime
+            .block_on(self.client.delete_application_version(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::delete_configuration_template`].
+    pub fn delete_configuration_template(
+        &self,
+        input: DeleteConfigurationTemplateMessage,
+    ) -> Result<(), RusotoError<DeleteConfigurationTemplateError>> {
+        self.runtime
+            .block_on(self.client.delete_configuration_template(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::delete_environment_configuration`].
+    pub fn delete_environment_configuration(
+        &self,
+        input: DeleteEnvironmentConfigurationMessage,
+    ) -> Result<(), RusotoError<DeleteEnvironmentConfigurationError>> {
+        self.runtime
+            .block_on(self.client.delete_environment_configuration(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::delete_platform_version`].
+    pub fn delete_platform_version(
+        &self,
+        input: DeletePlatformVersionRequest,
+    ) -> Result<DeletePlatformVersionResult, RusotoError<DeletePlatformVersionError>> {
+        self.runtime
+            .block_on(self.client.delete_platform_version(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::describe_account_attributes`].
+    pub fn describe_account_attributes(
+        &self,
+    ) -> Result<DescribeAccountAttributesResult, RusotoError<DescribeAccountAttributesError>> {
+        self.runtime
+            .block_on(self.client.describe_account_attributes())
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::describe_application_versions`].
+    pub fn describe_application_versions(
+        &self,
+        input: DescribeApplicationVersionsMessage,
+    ) -> Result<ApplicationVersionDescriptionsMessage, RusotoError<DescribeApplicationVersionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_application_versions(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::describe_applications`].
+    pub fn describe_applications(
+        &self,
+        input: DescribeApplicationsMessage,
+    ) -> Result<ApplicationDescriptionsMessage, RusotoError<DescribeApplicationsError>> {
+        self.runtime
+            .block_on(self.client.describe_applications(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::describe_configuration_options`].
+    pub fn describe_configuration_options(
+        &self,
+        input: DescribeConfigurationOptionsMessage,
+    ) -> Result<ConfigurationOptionsDescription, RusotoError<DescribeConfigurationOptionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_configuration_options(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::describe_configuration_settings`].
+    pub fn describe_configuration_settings(
+        &self,
+        input: DescribeConfigurationSettingsMessage,
+    ) -> Result<ConfigurationSettingsDescriptions, RusotoError<DescribeConfigurationSettingsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_configuration_settings(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::describe_environment_health`].
+    pub fn describe_environment_health(
+        &self,
+        input: DescribeEnvironmentHealthRequest,
+    ) -> Result<DescribeEnvironmentHealthResult, RusotoError<DescribeEnvironmentHealthError>> {
+        self.runtime
+            .block_on(self.client.describe_environment_health(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::describe_environment_managed_action_history`].
+    pub fn describe_environment_managed_action_history(
+        &self,
+        input: DescribeEnvironmentManagedActionHistoryRequest,
+    ) -> Result<
+        DescribeEnvironmentManagedActionHistoryResult,
+        RusotoError<DescribeEnvironmentManagedActionHistoryError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_environment_managed_action_history(input),
+        )
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::describe_environment_managed_actions`].
+    pub fn describe_environment_managed_actions(
+        &self,
+        input: DescribeEnvironmentManagedActionsRequest,
+    ) -> Result<
+        DescribeEnvironmentManagedActionsResult,
+        RusotoError<DescribeEnvironmentManagedActionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_environment_managed_actions(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::describe_environment_resources`].
+    pub fn describe_environment_resources(
+        &self,
+        input: DescribeEnvironmentResourcesMessage,
+    ) -> Result<
+        EnvironmentResourceDescriptionsMessage,
+        RusotoError<DescribeEnvironmentResourcesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_environment_resources(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::describe_environments`].
+    pub fn describe_environments(
+        &self,
+        input: DescribeEnvironmentsMessage,
+    ) -> Result<EnvironmentDescriptionsMessage, RusotoError<DescribeEnvironmentsError>> {
+        self.runtime
+            .block_on(self.client.describe_environments(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::describe_events`].
+    pub fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> Result<EventDescriptionsMessage, RusotoError<DescribeEventsError>> {
+        self.runtime.block_on(self.client.describe_events(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::describe_instances_health`].
+    pub fn describe_instances_health(
+        &self,
+        input: DescribeInstancesHealthRequest,
+    ) -> Result<DescribeInstancesHealthResult, RusotoError<DescribeInstancesHealthError>> {
+        self.runtime
+            .block_on(self.client.describe_instances_health(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::describe_platform_version`].
+    pub fn describe_platform_version(
+        &self,
+        input: DescribePlatformVersionRequest,
+    ) -> Result<DescribePlatformVersionResult, RusotoError<DescribePlatformVersionError>> {
+        self.runtime
+            .block_on(self.client.describe_platform_version(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::disassociate_environment_operations_role`].
+    pub fn disassociate_environment_operations_role(
+        &self,
+        input: DisassociateEnvironmentOperationsRoleMessage,
+    ) -> Result<(), RusotoError<DisassociateEnvironmentOperationsRoleError>> {
+        self.runtime
+            .block_on(self.client.disassociate_environment_operations_role(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::list_available_solution_stacks`].
+    pub fn list_available_solution_stacks(
+        &self,
+    ) -> Result<
+        ListAvailableSolutionStacksResultMessage,
+        RusotoError<ListAvailableSolutionStacksError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_available_solution_stacks())
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::list_platform_branches`].
+    pub fn list_platform_branches(
+        &self,
+        input: ListPlatformBranchesRequest,
+    ) -> Result<ListPlatformBranchesResult, RusotoError<ListPlatformBranchesError>> {
+        self.runtime
+            .block_on(self.client.list_platform_branches(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::list_platform_versions`].
+    pub fn list_platform_versions(
+        &self,
+        input: ListPlatformVersionsRequest,
+    ) -> Result<ListPlatformVersionsResult, RusotoError<ListPlatformVersionsError>> {
+        self.runtime
+            .block_on(self.client.list_platform_versions(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> Result<ResourceTagsDescriptionMessage, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::rebuild_environment`].
+    pub fn rebuild_environment(
+        &self,
+        input: RebuildEnvironmentMessage,
+    ) -> Result<(), RusotoError<RebuildEnvironmentError>> {
+        self.runtime
+            .block_on(self.client.rebuild_environment(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::request_environment_info`].
+    pub fn request_environment_info(
+        &self,
+        input: RequestEnvironmentInfoMessage,
+    ) -> Result<(), RusotoError<RequestEnvironmentInfoError>> {
+        self.runtime
+            .block_on(self.client.request_environment_info(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::restart_app_server`].
+    pub fn restart_app_server(
+        &self,
+        input: RestartAppServerMessage,
+    ) -> Result<(), RusotoError<RestartAppServerError>> {
+        self.runtime.block_on(self.client.restart_app_server(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::retrieve_environment_info`].
+    pub fn retrieve_environment_info(
+        &self,
+        input: RetrieveEnvironmentInfoMessage,
+    ) -> Result<RetrieveEnvironmentInfoResultMessage, RusotoError<RetrieveEnvironmentInfoError>>
+    {
+        self.runtime
+            .block_on(self.client.retrieve_environment_info(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::swap_environment_cnam_es`].
+    pub fn swap_environment_cnam_es(
+        &self,
+        input: SwapEnvironmentCNAMEsMessage,
+    ) -> Result<(), RusotoError<SwapEnvironmentCNAMEsError>> {
+        self.runtime
+            .block_on(self.client.swap_environment_cnam_es(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::terminate_environment`].
+    pub fn terminate_environment(
+        &self,
+        input: TerminateEnvironmentMessage,
+    ) -> Result<EnvironmentDescription, RusotoError<TerminateEnvironmentError>> {
+        self.runtime
+            .block_on(self.client.terminate_environment(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::update_application`].
+    pub fn update_application(
+        &self,
+        input: UpdateApplicationMessage,
+    ) -> Result<ApplicationDescriptionMessage, RusotoError<UpdateApplicationError>> {
+        self.runtime.block_on(self.client.update_application(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::update_application_resource_lifecycle`].
+    pub fn update_application_resource_lifecycle(
+        &self,
+        input: UpdateApplicationResourceLifecycleMessage,
+    ) -> Result<
+        ApplicationResourceLifecycleDescriptionMessage,
+        RusotoError<UpdateApplicationResourceLifecycleError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_application_resource_lifecycle(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::update_application_version`].
+    pub fn update_application_version(
+        &self,
+        input: UpdateApplicationVersionMessage,
+    ) -> Result<ApplicationVersionDescriptionMessage, RusotoError<UpdateApplicationVersionError>>
+    {
+        self.runtime
+            .block_on(self.client.update_application_version(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::update_configuration_template`].
+    pub fn update_configuration_template(
+        &self,
+        input: UpdateConfigurationTemplateMessage,
+    ) -> Result<ConfigurationSettingsDescription, RusotoError<UpdateConfigurationTemplateError>>
+    {
+        self.runtime
+            .block_on(self.client.update_configuration_template(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::update_environment`].
+    pub fn update_environment(
+        &self,
+        input: UpdateEnvironmentMessage,
+    ) -> Result<EnvironmentDescription, RusotoError<UpdateEnvironmentError>> {
+        self.runtime.block_on(self.client.update_environment(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::update_tags_for_resource`].
+    pub fn update_tags_for_resource(
+        &self,
+        input: UpdateTagsForResourceMessage,
+    ) -> Result<(), RusotoError<UpdateTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.update_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`ElasticBeanstalk::validate_configuration_settings`].
+    pub fn validate_configuration_settings(
+        &self,
+        input: ValidateConfigurationSettingsMessage,
+    ) -> Result<
+        ConfigurationSettingsValidationMessages,
+        RusotoError<ValidateConfigurationSettingsError>,
+    > {
+        self.runtime
+            .block_on(self.client.validate_configuration_settings(input))
+    }
+}
+
 #[cfg(test)]
 mod protocol_tests {
 
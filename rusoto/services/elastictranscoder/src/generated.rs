@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1449,21 +1450,46 @@ impl CancelJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CancelJobError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelJobError::AccessDenied,
+                    )
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(CancelJobError::IncompatibleVersion(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelJobError::IncompatibleVersion,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CancelJobError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelJobError::InternalService,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CancelJobError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelJobError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelJobError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1503,21 +1529,46 @@ impl CreateJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateJobError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateJobError::AccessDenied,
+                    )
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(CreateJobError::IncompatibleVersion(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateJobError::IncompatibleVersion,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateJobError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateJobError::InternalService,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateJobError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateJobError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateJobError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1557,21 +1608,46 @@ impl CreatePipelineError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreatePipelineError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePipelineError::AccessDenied,
+                    )
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(CreatePipelineError::IncompatibleVersion(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePipelineError::IncompatibleVersion,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreatePipelineError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePipelineError::InternalService,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreatePipelineError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePipelineError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreatePipelineError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePipelineError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1609,18 +1685,38 @@ impl CreatePresetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreatePresetError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePresetError::AccessDenied,
+                    )
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(CreatePresetError::IncompatibleVersion(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePresetError::IncompatibleVersion,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreatePresetError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePresetError::InternalService,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreatePresetError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePresetError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1659,21 +1755,46 @@ impl DeletePipelineError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeletePipelineError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePipelineError::AccessDenied,
+                    )
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(DeletePipelineError::IncompatibleVersion(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePipelineError::IncompatibleVersion,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeletePipelineError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePipelineError::InternalService,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeletePipelineError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePipelineError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeletePipelineError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePipelineError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1711,18 +1832,38 @@ impl DeletePresetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeletePresetError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePresetError::AccessDenied,
+                    )
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(DeletePresetError::IncompatibleVersion(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePresetError::IncompatibleVersion,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeletePresetError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePresetError::InternalService,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeletePresetError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePresetError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1759,20 +1900,38 @@ impl ListJobsByPipelineError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListJobsByPipelineError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListJobsByPipelineError::AccessDenied,
+                    )
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(ListJobsByPipelineError::IncompatibleVersion(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListJobsByPipelineError::IncompatibleVersion,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListJobsByPipelineError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListJobsByPipelineError::InternalService,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListJobsByPipelineError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListJobsByPipelineError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1809,20 +1968,38 @@ impl ListJobsByStatusError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListJobsByStatusError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListJobsByStatusError::AccessDenied,
+                    )
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(ListJobsByStatusError::IncompatibleVersion(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListJobsByStatusError::IncompatibleVersion,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListJobsByStatusError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListJobsByStatusError::InternalService,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListJobsByStatusError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListJobsByStatusError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1857,15 +2034,30 @@ impl ListPipelinesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListPipelinesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPipelinesError::AccessDenied,
+                    )
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(ListPipelinesError::IncompatibleVersion(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPipelinesError::IncompatibleVersion,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListPipelinesError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPipelinesError::InternalService,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1899,15 +2091,30 @@ impl ListPresetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListPresetsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPresetsError::AccessDenied,
+                    )
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(ListPresetsError::IncompatibleVersion(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPresetsError::IncompatibleVersion,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListPresetsError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPresetsError::InternalService,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1943,18 +2150,38 @@ impl ReadJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ReadJobError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReadJobError::AccessDenied,
+                    )
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(ReadJobError::IncompatibleVersion(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReadJobError::IncompatibleVersion,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ReadJobError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReadJobError::InternalService,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ReadJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReadJobError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1991,18 +2218,38 @@ impl ReadPipelineError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ReadPipelineError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReadPipelineError::AccessDenied,
+                    )
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(ReadPipelineError::IncompatibleVersion(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReadPipelineError::IncompatibleVersion,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ReadPipelineError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReadPipelineError::InternalService,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ReadPipelineError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReadPipelineError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2039,18 +2286,38 @@ impl ReadPresetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ReadPresetError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReadPresetError::AccessDenied,
+                    )
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(ReadPresetError::IncompatibleVersion(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReadPresetError::IncompatibleVersion,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ReadPresetError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReadPresetError::InternalService,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ReadPresetError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReadPresetError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2087,18 +2354,38 @@ impl TestRoleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(TestRoleError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestRoleError::AccessDenied,
+                    )
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(TestRoleError::IncompatibleVersion(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestRoleError::IncompatibleVersion,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(TestRoleError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestRoleError::InternalService,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TestRoleError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestRoleError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2137,21 +2424,46 @@ impl UpdatePipelineError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdatePipelineError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePipelineError::AccessDenied,
+                    )
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(UpdatePipelineError::IncompatibleVersion(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePipelineError::IncompatibleVersion,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdatePipelineError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePipelineError::InternalService,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdatePipelineError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePipelineError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdatePipelineError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePipelineError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2193,31 +2505,46 @@ impl UpdatePipelineNotificationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdatePipelineNotificationsError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdatePipelineNotificationsError::AccessDenied,
+                    )
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(
-                        UpdatePipelineNotificationsError::IncompatibleVersion(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePipelineNotificationsError::IncompatibleVersion,
                     )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdatePipelineNotificationsError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdatePipelineNotificationsError::InternalService,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdatePipelineNotificationsError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdatePipelineNotificationsError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdatePipelineNotificationsError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePipelineNotificationsError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2259,27 +2586,46 @@ impl UpdatePipelineStatusError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdatePipelineStatusError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePipelineStatusError::AccessDenied,
+                    )
                 }
                 "IncompatibleVersionException" => {
-                    return RusotoError::Service(UpdatePipelineStatusError::IncompatibleVersion(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdatePipelineStatusError::IncompatibleVersion,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdatePipelineStatusError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdatePipelineStatusError::InternalService,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdatePipelineStatusError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePipelineStatusError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdatePipelineStatusError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdatePipelineStatusError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2414,11 +2760,12 @@ pub struct EtsClient {
 impl EtsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> EtsClient {
         EtsClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Elastic Transcoder"),
         }
     }
 
@@ -2433,12 +2780,32 @@ impl EtsClient {
     {
         EtsClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Elastic Transcoder"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> EtsClient {
-        EtsClient { client, region }
+        EtsClient {
+            client,
+            region: region_for_service(region, "Elastic Transcoder"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> EtsClient {
+        EtsClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Elastic Transcoder"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> EtsClient {
+        EtsClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -2454,6 +2821,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("CancelJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2483,6 +2851,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("POST", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("CreateJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2515,6 +2884,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("POST", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("CreatePipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2547,6 +2917,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("POST", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("CreatePreset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2579,6 +2950,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("DeletePipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2608,6 +2980,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("DELETE", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("DeletePreset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2640,6 +3013,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("ListJobsByPipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2678,6 +3052,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("ListJobsByStatus");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2716,6 +3091,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("ListPipelines");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2754,6 +3130,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("ListPresets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2792,6 +3169,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("ReadJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2821,6 +3199,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("ReadPipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2850,6 +3229,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("GET", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("ReadPreset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2879,6 +3259,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("POST", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("TestRole");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2911,6 +3292,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("PUT", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("UpdatePipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2944,6 +3326,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("POST", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("UpdatePipelineNotifications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2976,6 +3359,7 @@ impl Ets for EtsClient {
 
         let mut request =
             SignedRequest::new("POST", "elastictranscoder", &self.region, &request_uri);
+        request.set_operation("UpdatePipelineStatus");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2998,3 +3382,219 @@ impl Ets for EtsClient {
         }
     }
 }
+
+/// A blocking client for the Amazon Elastic Transcoder API, running the calls of a `EtsClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `EtsClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct EtsBlockingClient {
+    client: EtsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl EtsBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> EtsBlockingClient {
+        EtsBlockingClient::from_client(EtsClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> EtsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        EtsBlockingClient::from_client(EtsClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> EtsBlockingClient {
+        EtsBlockingClient::from_client(EtsClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> EtsBlockingClient {
+        EtsBlockingClient::from_client(EtsClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: EtsClient) -> EtsBlockingClient {
+        EtsBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> EtsBlockingClient {
+        EtsBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> EtsBlockingClient {
+        EtsBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Ets::cancel_job`].
+    pub fn cancel_job(
+        &self,
+        input: CancelJobRequest,
+    ) -> Result<CancelJobResponse, RusotoError<CancelJobError>> {
+        self.runtime.block_on(self.client.cancel_job(input))
+    }
+
+    /// Blocking version of [`Ets::create_job`].
+    pub fn create_job(
+        &self,
+        input: CreateJobRequest,
+    ) -> Result<CreateJobResponse, RusotoError<CreateJobError>> {
+        self.runtime.block_on(self.client.create_job(input))
+    }
+
+    /// Blocking version of [`Ets::create_pipeline`].
+    pub fn create_pipeline(
+        &self,
+        input: CreatePipelineRequest,
+    ) -> Result<CreatePipelineResponse, RusotoError<CreatePipelineError>> {
+        self.runtime.block_on(self.client.create_pipeline(input))
+    }
+
+    /// Blocking version of [`Ets::create_preset`].
+    pub fn create_preset(
+        &self,
+        input: CreatePresetRequest,
+    ) -> Result<CreatePresetResponse, RusotoError<CreatePresetError>> {
+        self.runtime.block_on(self.client.create_preset(input))
+    }
+
+    /// Blocking version of [`Ets::delete_pipeline`].
+    pub fn delete_pipeline(
+        &self,
+        input: DeletePipelineRequest,
+    ) -> Result<DeletePipelineResponse, RusotoError<DeletePipelineError>> {
+        self.runtime.block_on(self.client.delete_pipeline(input))
+    }
+
+    /// Blocking version of [`Ets::delete_preset`].
+    pub fn delete_preset(
+        &self,
+        input: DeletePresetRequest,
+    ) -> Result<DeletePresetResponse, RusotoError<DeletePresetError>> {
+        self.runtime.block_on(self.client.delete_preset(input))
+    }
+
+    /// Blocking version of [`Ets::list_jobs_by_pipeline`].
+    pub fn list_jobs_by_pipeline(
+        &self,
+        input: ListJobsByPipelineRequest,
+    ) -> Result<ListJobsByPipelineResponse, RusotoError<ListJobsByPipelineError>> {
+        self.runtime
+            .block_on(self.client.list_jobs_by_pipeline(input))
+    }
+
+    /// Blocking version of [`Ets::list_jobs_by_status`].
+    pub fn list_jobs_by_status(
+        &self,
+        input: ListJobsByStatusRequest,
+    ) -> Result<ListJobsByStatusResponse, RusotoError<ListJobsByStatusError>> {
+        self.runtime
+            .block_on(self.client.list_jobs_by_status(input))
+    }
+
+    /// Blocking version of [`Ets::list_pipelines`].
+    pub fn list_pipelines(
+        &self,
+        input: ListPipelinesRequest,
+    ) -> Result<ListPipelinesResponse, RusotoError<ListPipelinesError>> {
+        self.runtime.block_on(self.client.list_pipelines(input))
+    }
+
+    /// Blocking version of [`Ets::list_presets`].
+    pub fn list_presets(
+        &self,
+        input: ListPresetsRequest,
+    ) -> Result<ListPresetsResponse, RusotoError<ListPresetsError>> {
+        self.runtime.block_on(self.client.list_presets(input))
+    }
+
+    /// Blocking version of [`Ets::read_job`].
+    pub fn read_job(
+        &self,
+        input: ReadJobRequest,
+    ) -> Result<ReadJobResponse, RusotoError<ReadJobError>> {
+        self.runtime.block_on(self.client.read_job(input))
+    }
+
+    /// Blocking version of [`Ets::read_pipeline`].
+    pub fn read_pipeline(
+        &self,
+        input: ReadPipelineRequest,
+    ) -> Result<ReadPipelineResponse, RusotoError<ReadPipelineError>> {
+        self.runtime.block_on(self.client.read_pipeline(input))
+    }
+
+    /// Blocking version of [`Ets::read_preset`].
+    pub fn read_preset(
+        &self,
+        input: ReadPresetRequest,
+    ) -> Result<ReadPresetResponse, RusotoError<ReadPresetError>> {
+        self.runtime.block_on(self.client.read_preset(input))
+    }
+
+    /// Blocking version of [`Ets::test_role`].
+    pub fn test_role(
+        &self,
+        input: TestRoleRequest,
+    ) -> Result<TestRoleResponse, RusotoError<TestRoleError>> {
+        self.runtime.block_on(self.client.test_role(input))
+    }
+
+    /// Blocking version of [`Ets::update_pipeline`].
+    pub fn update_pipeline(
+        &self,
+        input: UpdatePipelineRequest,
+    ) -> Result<UpdatePipelineResponse, RusotoError<UpdatePipelineError>> {
+        self.runtime.block_on(self.client.update_pipeline(input))
+    }
+
+    /// Blocking version of [`Ets::update_pipeline_notifications`].
+    pub fn update_pipeline_notifications(
+        &self,
+        input: UpdatePipelineNotificationsRequest,
+    ) -> Result<UpdatePipelineNotificationsResponse, RusotoError<UpdatePipelineNotificationsError>>
+    {
+        self.runtime
+            .block_on(self.client.update_pipeline_notifications(input))
+    }
+
+    /// Blocking version of [`Ets::update_pipeline_status`].
+    pub fn update_pipeline_status(
+        &self,
+        input: UpdatePipelineStatusRequest,
+    ) -> Result<UpdatePipelineStatusResponse, RusotoError<UpdatePipelineStatusError>> {
+        self.runtime
+            .block_on(self.client.update_pipeline_status(input))
+    }
+}
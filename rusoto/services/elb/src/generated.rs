@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -4130,19 +4131,28 @@ impl AddTagsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(AddTagsError::AccessPointNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AddTagsError::AccessPointNotFound,
+                        )
                     }
                     "DuplicateTagKeys" => {
-                        return RusotoError::Service(AddTagsError::DuplicateTagKeys(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AddTagsError::DuplicateTagKeys,
+                        )
                     }
                     "TooManyTags" => {
-                        return RusotoError::Service(AddTagsError::TooManyTags(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AddTagsError::TooManyTags,
+                        )
                     }
                     _ => {}
                 }
@@ -4192,24 +4202,27 @@ impl ApplySecurityGroupsToLoadBalancerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
-                            ApplySecurityGroupsToLoadBalancerError::AccessPointNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ApplySecurityGroupsToLoadBalancerError::AccessPointNotFound,
                         )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
-                            ApplySecurityGroupsToLoadBalancerError::InvalidConfigurationRequest(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ApplySecurityGroupsToLoadBalancerError::InvalidConfigurationRequest,
                         )
                     }
                     "InvalidSecurityGroup" => {
-                        return RusotoError::Service(
-                            ApplySecurityGroupsToLoadBalancerError::InvalidSecurityGroup(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ApplySecurityGroupsToLoadBalancerError::InvalidSecurityGroup,
                         )
                     }
                     _ => {}
@@ -4268,27 +4281,35 @@ impl AttachLoadBalancerToSubnetsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
-                            AttachLoadBalancerToSubnetsError::AccessPointNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AttachLoadBalancerToSubnetsError::AccessPointNotFound,
                         )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
-                            AttachLoadBalancerToSubnetsError::InvalidConfigurationRequest(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AttachLoadBalancerToSubnetsError::InvalidConfigurationRequest,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(
-                            AttachLoadBalancerToSubnetsError::InvalidSubnet(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AttachLoadBalancerToSubnetsError::InvalidSubnet,
                         )
                     }
                     "SubnetNotFound" => {
-                        return RusotoError::Service(
-                            AttachLoadBalancerToSubnetsError::SubnetNotFound(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AttachLoadBalancerToSubnetsError::SubnetNotFound,
                         )
                     }
                     _ => {}
@@ -4338,8 +4359,11 @@ impl ConfigureHealthCheckError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
-                            ConfigureHealthCheckError::AccessPointNotFound(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ConfigureHealthCheckError::AccessPointNotFound,
                         )
                     }
                     _ => {}
@@ -4390,31 +4414,35 @@ impl CreateAppCookieStickinessPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
-                            CreateAppCookieStickinessPolicyError::AccessPointNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateAppCookieStickinessPolicyError::AccessPointNotFound,
                         )
                     }
                     "DuplicatePolicyName" => {
-                        return RusotoError::Service(
-                            CreateAppCookieStickinessPolicyError::DuplicatePolicyName(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateAppCookieStickinessPolicyError::DuplicatePolicyName,
                         )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
-                            CreateAppCookieStickinessPolicyError::InvalidConfigurationRequest(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateAppCookieStickinessPolicyError::InvalidConfigurationRequest,
                         )
                     }
                     "TooManyPolicies" => {
-                        return RusotoError::Service(
-                            CreateAppCookieStickinessPolicyError::TooManyPolicies(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateAppCookieStickinessPolicyError::TooManyPolicies,
                         )
                     }
                     _ => {}
@@ -4476,31 +4504,35 @@ impl CreateLBCookieStickinessPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
-                            CreateLBCookieStickinessPolicyError::AccessPointNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateLBCookieStickinessPolicyError::AccessPointNotFound,
                         )
                     }
                     "DuplicatePolicyName" => {
-                        return RusotoError::Service(
-                            CreateLBCookieStickinessPolicyError::DuplicatePolicyName(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateLBCookieStickinessPolicyError::DuplicatePolicyName,
                         )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
-                            CreateLBCookieStickinessPolicyError::InvalidConfigurationRequest(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateLBCookieStickinessPolicyError::InvalidConfigurationRequest,
                         )
                     }
                     "TooManyPolicies" => {
-                        return RusotoError::Service(
-                            CreateLBCookieStickinessPolicyError::TooManyPolicies(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateLBCookieStickinessPolicyError::TooManyPolicies,
                         )
                     }
                     _ => {}
@@ -4576,66 +4608,100 @@ impl CreateLoadBalancerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CertificateNotFound" => {
-                        return RusotoError::Service(CreateLoadBalancerError::CertificateNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateLoadBalancerError::CertificateNotFound,
+                        )
                     }
                     "DuplicateLoadBalancerName" => {
-                        return RusotoError::Service(
-                            CreateLoadBalancerError::DuplicateAccessPointName(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateLoadBalancerError::DuplicateAccessPointName,
                         )
                     }
                     "DuplicateTagKeys" => {
-                        return RusotoError::Service(CreateLoadBalancerError::DuplicateTagKeys(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateLoadBalancerError::DuplicateTagKeys,
+                        )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
-                            CreateLoadBalancerError::InvalidConfigurationRequest(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateLoadBalancerError::InvalidConfigurationRequest,
                         )
                     }
                     "InvalidScheme" => {
-                        return RusotoError::Service(CreateLoadBalancerError::InvalidScheme(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateLoadBalancerError::InvalidScheme,
+                        )
                     }
                     "InvalidSecurityGroup" => {
-                        return RusotoError::Service(CreateLoadBalancerError::InvalidSecurityGroup(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateLoadBalancerError::InvalidSecurityGroup,
+                        )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateLoadBalancerError::InvalidSubnet(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateLoadBalancerError::InvalidSubnet,
+                        )
                     }
                     "OperationNotPermitted" => {
-                        return RusotoError::Service(
-                            CreateLoadBalancerError::OperationNotPermitted(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateLoadBalancerError::OperationNotPermitted,
                         )
                     }
                     "SubnetNotFound" => {
-                        return RusotoError::Service(CreateLoadBalancerError::SubnetNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateLoadBalancerError::SubnetNotFound,
+                        )
                     }
                     "TooManyLoadBalancers" => {
-                        return RusotoError::Service(CreateLoadBalancerError::TooManyAccessPoints(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateLoadBalancerError::TooManyAccessPoints,
+                        )
                     }
                     "TooManyTags" => {
-                        return RusotoError::Service(CreateLoadBalancerError::TooManyTags(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateLoadBalancerError::TooManyTags,
+                        )
                     }
                     "UnsupportedProtocol" => {
-                        return RusotoError::Service(CreateLoadBalancerError::UnsupportedProtocol(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateLoadBalancerError::UnsupportedProtocol,
+                        )
                     }
                     _ => {}
                 }
@@ -4700,38 +4766,43 @@ impl CreateLoadBalancerListenersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
-                            CreateLoadBalancerListenersError::AccessPointNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateLoadBalancerListenersError::AccessPointNotFound,
                         )
                     }
                     "CertificateNotFound" => {
-                        return RusotoError::Service(
-                            CreateLoadBalancerListenersError::CertificateNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateLoadBalancerListenersError::CertificateNotFound,
                         )
                     }
                     "DuplicateListener" => {
-                        return RusotoError::Service(
-                            CreateLoadBalancerListenersError::DuplicateListener(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateLoadBalancerListenersError::DuplicateListener,
                         )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
-                            CreateLoadBalancerListenersError::InvalidConfigurationRequest(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateLoadBalancerListenersError::InvalidConfigurationRequest,
                         )
                     }
                     "UnsupportedProtocol" => {
-                        return RusotoError::Service(
-                            CreateLoadBalancerListenersError::UnsupportedProtocol(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateLoadBalancerListenersError::UnsupportedProtocol,
                         )
                     }
                     _ => {}
@@ -4796,34 +4867,43 @@ impl CreateLoadBalancerPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
-                            CreateLoadBalancerPolicyError::AccessPointNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateLoadBalancerPolicyError::AccessPointNotFound,
                         )
                     }
                     "DuplicatePolicyName" => {
-                        return RusotoError::Service(
-                            CreateLoadBalancerPolicyError::DuplicatePolicyName(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateLoadBalancerPolicyError::DuplicatePolicyName,
                         )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
-                            CreateLoadBalancerPolicyError::InvalidConfigurationRequest(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateLoadBalancerPolicyError::InvalidConfigurationRequest,
                         )
                     }
                     "PolicyTypeNotFound" => {
-                        return RusotoError::Service(
-                            CreateLoadBalancerPolicyError::PolicyTypeNotFound(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateLoadBalancerPolicyError::PolicyTypeNotFound,
                         )
                     }
                     "TooManyPolicies" => {
-                        return RusotoError::Service(
-                            CreateLoadBalancerPolicyError::TooManyPolicies(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateLoadBalancerPolicyError::TooManyPolicies,
                         )
                     }
                     _ => {}
@@ -4908,10 +4988,11 @@ impl DeleteLoadBalancerListenersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
-                            DeleteLoadBalancerListenersError::AccessPointNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteLoadBalancerListenersError::AccessPointNotFound,
                         )
                     }
                     _ => {}
@@ -4958,17 +5039,19 @@ impl DeleteLoadBalancerPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
-                            DeleteLoadBalancerPolicyError::AccessPointNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteLoadBalancerPolicyError::AccessPointNotFound,
                         )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
-                            DeleteLoadBalancerPolicyError::InvalidConfigurationRequest(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteLoadBalancerPolicyError::InvalidConfigurationRequest,
                         )
                     }
                     _ => {}
@@ -5018,17 +5101,19 @@ impl DeregisterInstancesFromLoadBalancerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
-                            DeregisterInstancesFromLoadBalancerError::AccessPointNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeregisterInstancesFromLoadBalancerError::AccessPointNotFound,
                         )
                     }
                     "InvalidInstance" => {
-                        return RusotoError::Service(
-                            DeregisterInstancesFromLoadBalancerError::InvalidEndPoint(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeregisterInstancesFromLoadBalancerError::InvalidEndPoint,
                         )
                     }
                     _ => {}
@@ -5112,14 +5197,20 @@ impl DescribeInstanceHealthError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
-                            DescribeInstanceHealthError::AccessPointNotFound(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeInstanceHealthError::AccessPointNotFound,
                         )
                     }
                     "InvalidInstance" => {
-                        return RusotoError::Service(DescribeInstanceHealthError::InvalidEndPoint(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeInstanceHealthError::InvalidEndPoint,
+                        )
                     }
                     _ => {}
                 }
@@ -5166,17 +5257,19 @@ impl DescribeLoadBalancerAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
-                            DescribeLoadBalancerAttributesError::AccessPointNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeLoadBalancerAttributesError::AccessPointNotFound,
                         )
                     }
                     "LoadBalancerAttributeNotFound" => {
-                        return RusotoError::Service(
-                            DescribeLoadBalancerAttributesError::LoadBalancerAttributeNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeLoadBalancerAttributesError::LoadBalancerAttributeNotFound,
                         )
                     }
                     _ => {}
@@ -5228,15 +5321,19 @@ impl DescribeLoadBalancerPoliciesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
-                            DescribeLoadBalancerPoliciesError::AccessPointNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeLoadBalancerPoliciesError::AccessPointNotFound,
                         )
                     }
                     "PolicyNotFound" => {
-                        return RusotoError::Service(
-                            DescribeLoadBalancerPoliciesError::PolicyNotFound(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeLoadBalancerPoliciesError::PolicyNotFound,
                         )
                     }
                     _ => {}
@@ -5284,10 +5381,11 @@ impl DescribeLoadBalancerPolicyTypesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "PolicyTypeNotFound" => {
-                        return RusotoError::Service(
-                            DescribeLoadBalancerPolicyTypesError::PolicyTypeNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeLoadBalancerPolicyTypesError::PolicyTypeNotFound,
                         )
                     }
                     _ => {}
@@ -5334,13 +5432,19 @@ impl DescribeLoadBalancersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
-                            DescribeLoadBalancersError::AccessPointNotFound(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeLoadBalancersError::AccessPointNotFound,
                         )
                     }
                     "DependencyThrottle" => {
-                        return RusotoError::Service(
-                            DescribeLoadBalancersError::DependencyThrottle(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeLoadBalancersError::DependencyThrottle,
                         )
                     }
                     _ => {}
@@ -5384,9 +5488,12 @@ impl DescribeTagsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(DescribeTagsError::AccessPointNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeTagsError::AccessPointNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -5432,17 +5539,19 @@ impl DetachLoadBalancerFromSubnetsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
-                            DetachLoadBalancerFromSubnetsError::AccessPointNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DetachLoadBalancerFromSubnetsError::AccessPointNotFound,
                         )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
-                            DetachLoadBalancerFromSubnetsError::InvalidConfigurationRequest(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DetachLoadBalancerFromSubnetsError::InvalidConfigurationRequest,
                         )
                     }
                     _ => {}
@@ -5494,16 +5603,18 @@ impl DisableAvailabilityZonesForLoadBalancerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
-                            DisableAvailabilityZonesForLoadBalancerError::AccessPointNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DisableAvailabilityZonesForLoadBalancerError::AccessPointNotFound,
                         )
                     }
-                    "InvalidConfigurationRequest" => return RusotoError::Service(
-                        DisableAvailabilityZonesForLoadBalancerError::InvalidConfigurationRequest(
-                            parsed_error.message,
-                        ),
+                    "InvalidConfigurationRequest" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        DisableAvailabilityZonesForLoadBalancerError::InvalidConfigurationRequest,
                     ),
                     _ => {}
                 }
@@ -5552,10 +5663,11 @@ impl EnableAvailabilityZonesForLoadBalancerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
-                            EnableAvailabilityZonesForLoadBalancerError::AccessPointNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            EnableAvailabilityZonesForLoadBalancerError::AccessPointNotFound,
                         )
                     }
                     _ => {}
@@ -5606,24 +5718,27 @@ impl ModifyLoadBalancerAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
-                            ModifyLoadBalancerAttributesError::AccessPointNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyLoadBalancerAttributesError::AccessPointNotFound,
                         )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
-                            ModifyLoadBalancerAttributesError::InvalidConfigurationRequest(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyLoadBalancerAttributesError::InvalidConfigurationRequest,
                         )
                     }
                     "LoadBalancerAttributeNotFound" => {
-                        return RusotoError::Service(
-                            ModifyLoadBalancerAttributesError::LoadBalancerAttributeNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyLoadBalancerAttributesError::LoadBalancerAttributeNotFound,
                         )
                     }
                     _ => {}
@@ -5678,17 +5793,19 @@ impl RegisterInstancesWithLoadBalancerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
-                            RegisterInstancesWithLoadBalancerError::AccessPointNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RegisterInstancesWithLoadBalancerError::AccessPointNotFound,
                         )
                     }
                     "InvalidInstance" => {
-                        return RusotoError::Service(
-                            RegisterInstancesWithLoadBalancerError::InvalidEndPoint(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RegisterInstancesWithLoadBalancerError::InvalidEndPoint,
                         )
                     }
                     _ => {}
@@ -5736,9 +5853,12 @@ impl RemoveTagsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(RemoveTagsError::AccessPointNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            RemoveTagsError::AccessPointNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -5790,38 +5910,43 @@ impl SetLoadBalancerListenerSSLCertificateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
-                            SetLoadBalancerListenerSSLCertificateError::AccessPointNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SetLoadBalancerListenerSSLCertificateError::AccessPointNotFound,
                         )
                     }
                     "CertificateNotFound" => {
-                        return RusotoError::Service(
-                            SetLoadBalancerListenerSSLCertificateError::CertificateNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SetLoadBalancerListenerSSLCertificateError::CertificateNotFound,
                         )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
-                            SetLoadBalancerListenerSSLCertificateError::InvalidConfigurationRequest(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SetLoadBalancerListenerSSLCertificateError::InvalidConfigurationRequest,
                         )
                     }
                     "ListenerNotFound" => {
-                        return RusotoError::Service(
-                            SetLoadBalancerListenerSSLCertificateError::ListenerNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SetLoadBalancerListenerSSLCertificateError::ListenerNotFound,
                         )
                     }
                     "UnsupportedProtocol" => {
-                        return RusotoError::Service(
-                            SetLoadBalancerListenerSSLCertificateError::UnsupportedProtocol(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SetLoadBalancerListenerSSLCertificateError::UnsupportedProtocol,
                         )
                     }
                     _ => {}
@@ -5884,22 +6009,25 @@ impl SetLoadBalancerPoliciesForBackendServerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
-                            SetLoadBalancerPoliciesForBackendServerError::AccessPointNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SetLoadBalancerPoliciesForBackendServerError::AccessPointNotFound,
                         )
                     }
-                    "InvalidConfigurationRequest" => return RusotoError::Service(
-                        SetLoadBalancerPoliciesForBackendServerError::InvalidConfigurationRequest(
-                            parsed_error.message,
-                        ),
+                    "InvalidConfigurationRequest" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        SetLoadBalancerPoliciesForBackendServerError::InvalidConfigurationRequest,
                     ),
                     "PolicyNotFound" => {
-                        return RusotoError::Service(
-                            SetLoadBalancerPoliciesForBackendServerError::PolicyNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SetLoadBalancerPoliciesForBackendServerError::PolicyNotFound,
                         )
                     }
                     _ => {}
@@ -5958,31 +6086,35 @@ impl SetLoadBalancerPoliciesOfListenerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
-                            SetLoadBalancerPoliciesOfListenerError::AccessPointNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SetLoadBalancerPoliciesOfListenerError::AccessPointNotFound,
                         )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
-                            SetLoadBalancerPoliciesOfListenerError::InvalidConfigurationRequest(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SetLoadBalancerPoliciesOfListenerError::InvalidConfigurationRequest,
                         )
                     }
                     "ListenerNotFound" => {
-                        return RusotoError::Service(
-                            SetLoadBalancerPoliciesOfListenerError::ListenerNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SetLoadBalancerPoliciesOfListenerError::ListenerNotFound,
                         )
                     }
                     "PolicyNotFound" => {
-                        return RusotoError::Service(
-                            SetLoadBalancerPoliciesOfListenerError::PolicyNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SetLoadBalancerPoliciesOfListenerError::PolicyNotFound,
                         )
                     }
                     _ => {}
@@ -6234,11 +6366,12 @@ pub struct ElbClient {
 impl ElbClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> ElbClient {
         ElbClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Elastic Load Balancing"),
         }
     }
 
@@ -6253,12 +6386,32 @@ impl ElbClient {
     {
         ElbClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Elastic Load Balancing"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ElbClient {
-        ElbClient { client, region }
+        ElbClient {
+            client,
+            region: region_for_service(region, "Elastic Load Balancing"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> ElbClient {
+        ElbClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Elastic Load Balancing"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ElbClient {
+        ElbClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -6270,6 +6423,7 @@ impl Elb for ElbClient {
         input: AddTagsInput,
     ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("AddTags");
         let params = self.new_params("AddTags");
         let mut params = params;
         AddTagsInputSerializer::serialize(&mut params, "", &input);
@@ -6295,6 +6449,7 @@ impl Elb for ElbClient {
         RusotoError<ApplySecurityGroupsToLoadBalancerError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("ApplySecurityGroupsToLoadBalancer");
         let params = self.new_params("ApplySecurityGroupsToLoadBalancer");
         let mut params = params;
         ApplySecurityGroupsToLoadBalancerInputSerializer::serialize(&mut params, "", &input);
@@ -6332,6 +6487,7 @@ impl Elb for ElbClient {
     ) -> Result<AttachLoadBalancerToSubnetsOutput, RusotoError<AttachLoadBalancerToSubnetsError>>
     {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("AttachLoadBalancerToSubnets");
         let params = self.new_params("AttachLoadBalancerToSubnets");
         let mut params = params;
         AttachLoadBalancerToSubnetsInputSerializer::serialize(&mut params, "", &input);
@@ -6365,6 +6521,7 @@ impl Elb for ElbClient {
         input: ConfigureHealthCheckInput,
     ) -> Result<ConfigureHealthCheckOutput, RusotoError<ConfigureHealthCheckError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("ConfigureHealthCheck");
         let params = self.new_params("ConfigureHealthCheck");
         let mut params = params;
         ConfigureHealthCheckInputSerializer::serialize(&mut params, "", &input);
@@ -6401,6 +6558,7 @@ impl Elb for ElbClient {
         RusotoError<CreateAppCookieStickinessPolicyError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("CreateAppCookieStickinessPolicy");
         let params = self.new_params("CreateAppCookieStickinessPolicy");
         let mut params = params;
         CreateAppCookieStickinessPolicyInputSerializer::serialize(&mut params, "", &input);
@@ -6426,6 +6584,7 @@ impl Elb for ElbClient {
         RusotoError<CreateLBCookieStickinessPolicyError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("CreateLBCookieStickinessPolicy");
         let params = self.new_params("CreateLBCookieStickinessPolicy");
         let mut params = params;
         CreateLBCookieStickinessPolicyInputSerializer::serialize(&mut params, "", &input);
@@ -6448,6 +6607,7 @@ impl Elb for ElbClient {
         input: CreateAccessPointInput,
     ) -> Result<CreateAccessPointOutput, RusotoError<CreateLoadBalancerError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("CreateLoadBalancer");
         let params = self.new_params("CreateLoadBalancer");
         let mut params = params;
         CreateAccessPointInputSerializer::serialize(&mut params, "", &input);
@@ -6482,6 +6642,7 @@ impl Elb for ElbClient {
     ) -> Result<CreateLoadBalancerListenerOutput, RusotoError<CreateLoadBalancerListenersError>>
     {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("CreateLoadBalancerListeners");
         let params = self.new_params("CreateLoadBalancerListeners");
         let mut params = params;
         CreateLoadBalancerListenerInputSerializer::serialize(&mut params, "", &input);
@@ -6504,6 +6665,7 @@ impl Elb for ElbClient {
         input: CreateLoadBalancerPolicyInput,
     ) -> Result<CreateLoadBalancerPolicyOutput, RusotoError<CreateLoadBalancerPolicyError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("CreateLoadBalancerPolicy");
         let params = self.new_params("CreateLoadBalancerPolicy");
         let mut params = params;
         CreateLoadBalancerPolicyInputSerializer::serialize(&mut params, "", &input);
@@ -6526,6 +6688,7 @@ impl Elb for ElbClient {
         input: DeleteAccessPointInput,
     ) -> Result<DeleteAccessPointOutput, RusotoError<DeleteLoadBalancerError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DeleteLoadBalancer");
         let params = self.new_params("DeleteLoadBalancer");
         let mut params = params;
         DeleteAccessPointInputSerializer::serialize(&mut params, "", &input);
@@ -6549,6 +6712,7 @@ impl Elb for ElbClient {
     ) -> Result<DeleteLoadBalancerListenerOutput, RusotoError<DeleteLoadBalancerListenersError>>
     {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DeleteLoadBalancerListeners");
         let params = self.new_params("DeleteLoadBalancerListeners");
         let mut params = params;
         DeleteLoadBalancerListenerInputSerializer::serialize(&mut params, "", &input);
@@ -6571,6 +6735,7 @@ impl Elb for ElbClient {
         input: DeleteLoadBalancerPolicyInput,
     ) -> Result<DeleteLoadBalancerPolicyOutput, RusotoError<DeleteLoadBalancerPolicyError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DeleteLoadBalancerPolicy");
         let params = self.new_params("DeleteLoadBalancerPolicy");
         let mut params = params;
         DeleteLoadBalancerPolicyInputSerializer::serialize(&mut params, "", &input);
@@ -6594,6 +6759,7 @@ impl Elb for ElbClient {
     ) -> Result<DeregisterEndPointsOutput, RusotoError<DeregisterInstancesFromLoadBalancerError>>
     {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DeregisterInstancesFromLoadBalancer");
         let params = self.new_params("DeregisterInstancesFromLoadBalancer");
         let mut params = params;
         DeregisterEndPointsInputSerializer::serialize(&mut params, "", &input);
@@ -6630,6 +6796,7 @@ impl Elb for ElbClient {
         input: DescribeAccountLimitsInput,
     ) -> Result<DescribeAccountLimitsOutput, RusotoError<DescribeAccountLimitsError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeAccountLimits");
         let params = self.new_params("DescribeAccountLimits");
         let mut params = params;
         DescribeAccountLimitsInputSerializer::serialize(&mut params, "", &input);
@@ -6663,6 +6830,7 @@ impl Elb for ElbClient {
         input: DescribeEndPointStateInput,
     ) -> Result<DescribeEndPointStateOutput, RusotoError<DescribeInstanceHealthError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeInstanceHealth");
         let params = self.new_params("DescribeInstanceHealth");
         let mut params = params;
         DescribeEndPointStateInputSerializer::serialize(&mut params, "", &input);
@@ -6699,6 +6867,7 @@ impl Elb for ElbClient {
         RusotoError<DescribeLoadBalancerAttributesError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeLoadBalancerAttributes");
         let params = self.new_params("DescribeLoadBalancerAttributes");
         let mut params = params;
         DescribeLoadBalancerAttributesInputSerializer::serialize(&mut params, "", &input);
@@ -6733,6 +6902,7 @@ impl Elb for ElbClient {
     ) -> Result<DescribeLoadBalancerPoliciesOutput, RusotoError<DescribeLoadBalancerPoliciesError>>
     {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeLoadBalancerPolicies");
         let params = self.new_params("DescribeLoadBalancerPolicies");
         let mut params = params;
         DescribeLoadBalancerPoliciesInputSerializer::serialize(&mut params, "", &input);
@@ -6769,6 +6939,7 @@ impl Elb for ElbClient {
         RusotoError<DescribeLoadBalancerPolicyTypesError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeLoadBalancerPolicyTypes");
         let params = self.new_params("DescribeLoadBalancerPolicyTypes");
         let mut params = params;
         DescribeLoadBalancerPolicyTypesInputSerializer::serialize(&mut params, "", &input);
@@ -6802,6 +6973,7 @@ impl Elb for ElbClient {
         input: DescribeAccessPointsInput,
     ) -> Result<DescribeAccessPointsOutput, RusotoError<DescribeLoadBalancersError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeLoadBalancers");
         let params = self.new_params("DescribeLoadBalancers");
         let mut params = params;
         DescribeAccessPointsInputSerializer::serialize(&mut params, "", &input);
@@ -6835,6 +7007,7 @@ impl Elb for ElbClient {
         input: DescribeTagsInput,
     ) -> Result<DescribeTagsOutput, RusotoError<DescribeTagsError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeTags");
         let params = self.new_params("DescribeTags");
         let mut params = params;
         DescribeTagsInputSerializer::serialize(&mut params, "", &input);
@@ -6866,6 +7039,7 @@ impl Elb for ElbClient {
     ) -> Result<DetachLoadBalancerFromSubnetsOutput, RusotoError<DetachLoadBalancerFromSubnetsError>>
     {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DetachLoadBalancerFromSubnets");
         let params = self.new_params("DetachLoadBalancerFromSubnets");
         let mut params = params;
         DetachLoadBalancerFromSubnetsInputSerializer::serialize(&mut params, "", &input);
@@ -6902,6 +7076,7 @@ impl Elb for ElbClient {
         RusotoError<DisableAvailabilityZonesForLoadBalancerError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DisableAvailabilityZonesForLoadBalancer");
         let params = self.new_params("DisableAvailabilityZonesForLoadBalancer");
         let mut params = params;
         RemoveAvailabilityZonesInputSerializer::serialize(&mut params, "", &input);
@@ -6939,6 +7114,7 @@ impl Elb for ElbClient {
     ) -> Result<AddAvailabilityZonesOutput, RusotoError<EnableAvailabilityZonesForLoadBalancerError>>
     {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("EnableAvailabilityZonesForLoadBalancer");
         let params = self.new_params("EnableAvailabilityZonesForLoadBalancer");
         let mut params = params;
         AddAvailabilityZonesInputSerializer::serialize(&mut params, "", &input);
@@ -6976,6 +7152,7 @@ impl Elb for ElbClient {
     ) -> Result<ModifyLoadBalancerAttributesOutput, RusotoError<ModifyLoadBalancerAttributesError>>
     {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("ModifyLoadBalancerAttributes");
         let params = self.new_params("ModifyLoadBalancerAttributes");
         let mut params = params;
         ModifyLoadBalancerAttributesInputSerializer::serialize(&mut params, "", &input);
@@ -7009,6 +7186,7 @@ impl Elb for ElbClient {
         input: RegisterEndPointsInput,
     ) -> Result<RegisterEndPointsOutput, RusotoError<RegisterInstancesWithLoadBalancerError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("RegisterInstancesWithLoadBalancer");
         let params = self.new_params("RegisterInstancesWithLoadBalancer");
         let mut params = params;
         RegisterEndPointsInputSerializer::serialize(&mut params, "", &input);
@@ -7045,6 +7223,7 @@ impl Elb for ElbClient {
         input: RemoveTagsInput,
     ) -> Result<RemoveTagsOutput, RusotoError<RemoveTagsError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("RemoveTags");
         let params = self.new_params("RemoveTags");
         let mut params = params;
         RemoveTagsInputSerializer::serialize(&mut params, "", &input);
@@ -7070,6 +7249,7 @@ impl Elb for ElbClient {
         RusotoError<SetLoadBalancerListenerSSLCertificateError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("SetLoadBalancerListenerSSLCertificate");
         let params = self.new_params("SetLoadBalancerListenerSSLCertificate");
         let mut params = params;
         SetLoadBalancerListenerSSLCertificateInputSerializer::serialize(&mut params, "", &input);
@@ -7098,6 +7278,7 @@ impl Elb for ElbClient {
         RusotoError<SetLoadBalancerPoliciesForBackendServerError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("SetLoadBalancerPoliciesForBackendServer");
         let params = self.new_params("SetLoadBalancerPoliciesForBackendServer");
         let mut params = params;
         SetLoadBalancerPoliciesForBackendServerInputSerializer::serialize(&mut params, "", &input);
@@ -7126,6 +7307,7 @@ impl Elb for ElbClient {
         RusotoError<SetLoadBalancerPoliciesOfListenerError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("SetLoadBalancerPoliciesOfListener");
         let params = self.new_params("SetLoadBalancerPoliciesOfListener");
         let mut params = params;
         SetLoadBalancerPoliciesOfListenerInputSerializer::serialize(&mut params, "", &input);
@@ -7146,6 +7328,382 @@ impl Elb for ElbClient {
     }
 }
 
+/// A blocking client for the Elastic Load Balancing API, running the calls of a `ElbClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `ElbClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ElbBlockingClient {
+    client: ElbClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ElbBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> ElbBlockingClient {
+        ElbBlockingClient::from_client(ElbClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ElbBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ElbBlockingClient::from_client(ElbClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> ElbBlockingClient {
+        ElbBlockingClient::from_client(ElbClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> ElbBlockingClient {
+        ElbBlockingClient::from_client(ElbClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: ElbClient) -> ElbBlockingClient {
+        ElbBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> ElbBlockingClient {
+        ElbBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ElbBlockingClient {
+        ElbBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Elb::add_tags`].
+    pub fn add_tags(
+        &self,
+        input: AddTagsInput,
+    ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
+        self.runtime.block_on(self.client.add_tags(input))
+    }
+
+    /// Blocking version of [`Elb::apply_security_groups_to_load_balancer`].
+    pub fn apply_security_groups_to_load_balancer(
+        &self,
+        input: ApplySecurityGroupsToLoadBalancerInput,
+    ) -> Result<
+        ApplySecurityGroupsToLoadBalancerOutput,
+        RusotoError<ApplySecurityGroupsToLoadBalancerError>,
+    > {
+        self.runtime
+            .block_on(self.client.apply_security_groups_to_load_balancer(input))
+    }
+
+    /// Blocking version of [`Elb::attach_load_balancer_to_subnets`].
+    pub fn attach_load_balancer_to_subnets(
+        &self,
+        input: AttachLoadBalancerToSubnetsInput,
+    ) -> Result<AttachLoadBalancerToSubnetsOutput, RusotoError<AttachLoadBalancerToSubnetsError>>
+    {
+        self.runtime
+            .block_on(self.client.attach_load_balancer_to_subnets(input))
+    }
+
+    /// Blocking version of [`Elb::configure_health_check`].
+    pub fn configure_health_check(
+        &self,
+        input: ConfigureHealthCheckInput,
+    ) -> Result<ConfigureHealthCheckOutput, RusotoError<ConfigureHealthCheckError>> {
+        self.runtime
+            .block_on(self.client.configure_health_check(input))
+    }
+
+    /// Blocking version of [`Elb::create_app_cookie_stickiness_policy`].
+    pub fn create_app_cookie_stickiness_policy(
+        &self,
+        input: CreateAppCookieStickinessPolicyInput,
+    ) -> Result<
+        CreateAppCookieStickinessPolicyOutput,
+        RusotoError<CreateAppCookieStickinessPolicyError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_app_cookie_stickiness_policy(input))
+    }
+
+    /// Blocking version of [`Elb::create_lb_cookie_stickiness_policy`].
+    pub fn create_lb_cookie_stickiness_policy(
+        &self,
+        input: CreateLBCookieStickinessPolicyInput,
+    ) -> Result<
+        CreateLBCookieStickinessPolicyOutput,
+        RusotoError<CreateLBCookieStickinessPolicyError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_lb_cookie_stickiness_policy(input))
+    }
+
+    /// Blocking version of [`Elb::create_load_balancer`].
+    pub fn create_load_balancer(
+        &self,
+        input: CreateAccessPointInput,
+    ) -> Result<CreateAccessPointOutput, RusotoError<CreateLoadBalancerError>> {
+        self.runtime
+            .block_on(self.client.create_load_balancer(input))
+    }
+
+    /// Blocking version of [`Elb::create_load_balancer_listeners`].
+    pub fn create_load_balancer_listeners(
+        &self,
+        input: CreateLoadBalancerListenerInput,
+    ) -> Result<CreateLoadBalancerListenerOutput, RusotoError<CreateLoadBalancerListenersError>>
+    {
+        self.runtime
+            .block_on(self.client.create_load_balancer_listeners(input))
+    }
+
+    /// Blocking version of [`Elb::create_load_balancer_policy`].
+    pub fn create_load_balancer_policy(
+        &self,
+        input: CreateLoadBalancerPolicyInput,
+    ) -> Result<CreateLoadBalancerPolicyOutput, RusotoError<CreateLoadBalancerPolicyError>> {
+        self.runtime
+            .block_on(self.client.create_load_balancer_policy(input))
+    }
+
+    /// Blocking version of [`Elb::delete_load_balancer`].
+    pub fn delete_load_balancer(
+        &self,
+        input: DeleteAccessPointInput,
+    ) -> Result<DeleteAccessPointOutput, RusotoError<DeleteLoadBalancerError>> {
+        self.runtime
+            .block_on(self.client.delete_load_balancer(input))
+    }
+
+    /// Blocking version of [`Elb::delete_load_balancer_listeners`].
+    pub fn delete_load_balancer_listeners(
+        &self,
+        input: DeleteLoadBalancerListenerInput,
+    ) -> Result<DeleteLoadBalancerListenerOutput, RusotoError<DeleteLoadBalancerListenersError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_load_balancer_listeners(input))
+    }
+
+    /// Blocking version of [`Elb::delete_load_balancer_policy`].
+    pub fn delete_load_balancer_policy(
+        &self,
+        input: DeleteLoadBalancerPolicyInput,
+    ) -> Result<DeleteLoadBalancerPolicyOutput, RusotoError<DeleteLoadBalancerPolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_load_balancer_policy(input))
+    }
+
+    /// Blocking version of [`Elb::deregister_instances_from_load_balancer`].
+    pub fn deregister_instances_from_load_balancer(
+        &self,
+        input: DeregisterEndPointsInput,
+    ) -> Result<DeregisterEndPointsOutput, RusotoError<DeregisterInstancesFromLoadBalancerError>>
+    {
+        self.runtime
+            .block_on(self.client.deregister_instances_from_load_balancer(input))
+    }
+
+    /// Blocking version of [`Elb::describe_account_limits`].
+    pub fn describe_account_limits(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> Result<DescribeAccountLimitsOutput, RusotoError<DescribeAccountLimitsError>> {
+        self.runtime
+            .block_on(self.client.describe_account_limits(input))
+    }
+
+    /// Blocking version of [`Elb::describe_instance_health`].
+    pub fn describe_instance_health(
+        &self,
+        input: DescribeEndPointStateInput,
+    ) -> Result<DescribeEndPointStateOutput, RusotoError<DescribeInstanceHealthError>> {
+        self.runtime
+            .block_on(self.client.describe_instance_health(input))
+    }
+
+    /// Blocking version of [`Elb::describe_load_balancer_attributes`].
+    pub fn describe_load_balancer_attributes(
+        &self,
+        input: DescribeLoadBalancerAttributesInput,
+    ) -> Result<
+        DescribeLoadBalancerAttributesOutput,
+        RusotoError<DescribeLoadBalancerAttributesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_load_balancer_attributes(input))
+    }
+
+    /// Blocking version of [`Elb::describe_load_balancer_policies`].
+    pub fn describe_load_balancer_policies(
+        &self,
+        input: DescribeLoadBalancerPoliciesInput,
+    ) -> Result<DescribeLoadBalancerPoliciesOutput, RusotoError<DescribeLoadBalancerPoliciesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_load_balancer_policies(input))
+    }
+
+    /// Blocking version of [`Elb::describe_load_balancer_policy_types`].
+    pub fn describe_load_balancer_policy_types(
+        &self,
+        input: DescribeLoadBalancerPolicyTypesInput,
+    ) -> Result<
+        DescribeLoadBalancerPolicyTypesOutput,
+        RusotoError<DescribeLoadBalancerPolicyTypesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_load_balancer_policy_types(input))
+    }
+
+    /// Blocking version of [`Elb::describe_load_balancers`].
+    pub fn describe_load_balancers(
+        &self,
+        input: DescribeAccessPointsInput,
+    ) -> Result<DescribeAccessPointsOutput, RusotoError<DescribeLoadBalancersError>> {
+        self.runtime
+            .block_on(self.client.describe_load_balancers(input))
+    }
+
+    /// Blocking version of [`Elb::describe_tags`].
+    pub fn describe_tags(
+        &self,
+        input: DescribeTagsInput,
+    ) -> Result<DescribeTagsOutput, RusotoError<DescribeTagsError>> {
+        self.runtime.block_on(self.client.describe_tags(input))
+    }
+
+    /// Blocking version of [`Elb::detach_load_balancer_from_subnets`].
+    pub fn detach_load_balancer_from_subnets(
+        &self,
+        input: DetachLoadBalancerFromSubnetsInput,
+    ) -> Result<DetachLoadBalancerFromSubnetsOutput, RusotoError<DetachLoadBalancerFromSubnetsError>>
+    {
+        self.runtime
+            .block_on(self.client.detach_load_balancer_from_subnets(input))
+    }
+
+    /// Blocking version of [`Elb::disable_availability_zones_for_load_balancer`].
+    pub fn disable_availability_zones_for_load_balancer(
+        &self,
+        input: RemoveAvailabilityZonesInput,
+    ) -> Result<
+        RemoveAvailabilityZonesOutput,
+        RusotoError<DisableAvailabilityZonesForLoadBalancerError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .disable_availability_zones_for_load_balancer(input),
+        )
+    }
+
+    /// Blocking version of [`Elb::enable_availability_zones_for_load_balancer`].
+    pub fn enable_availability_zones_for_load_balancer(
+        &self,
+        input: AddAvailabilityZonesInput,
+    ) -> Result<AddAvailabilityZonesOutput, RusotoError<EnableAvailabilityZonesForLoadBalancerError>>
+    {
+        self.runtime.block_on(
+            self.client
+                .enable_availability_zones_for_load_balancer(input),
+        )
+    }
+
+    /// Blocking version of [`Elb::modify_load_balancer_attributes`].
+    pub fn modify_load_balancer_attributes(
+        &self,
+        input: ModifyLoadBalancerAttributesInput,
+    ) -> Result<ModifyLoadBalancerAttributesOutput, RusotoError<ModifyLoadBalancerAttributesError>>
+    {
+        self.runtime
+            .block_on(self.client.modify_load_balancer_attributes(input))
+    }
+
+    /// Blocking version of [`Elb::register_instances_with_load_balancer`].
+    pub fn register_instances_with_load_balancer(
+        &self,
+        input: RegisterEndPointsInput,
+    ) -> Result<RegisterEndPointsOutput, RusotoError<RegisterInstancesWithLoadBalancerError>> {
+        self.runtime
+            .block_on(self.client.register_instances_with_load_balancer(input))
+    }
+
+    /// Blocking version of [`Elb::remove_tags`].
+    pub fn remove_tags(
+        &self,
+        input: RemoveTagsInput,
+    ) -> Result<RemoveTagsOutput, RusotoError<RemoveTagsError>> {
+        self.runtime.block_on(self.client.remove_tags(input))
+    }
+
+    /// Blocking version of [`Elb::set_load_balancer_listener_ssl_certificate`].
+    pub fn set_load_balancer_listener_ssl_certificate(
+        &self,
+        input: SetLoadBalancerListenerSSLCertificateInput,
+    ) -> Result<
+        SetLoadBalancerListenerSSLCertificateOutput,
+        RusotoError<SetLoadBalancerListenerSSLCertificateError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .set_load_balancer_listener_ssl_certificate(input),
+        )
+    }
+
+    /// Blocking version of [`Elb::set_load_balancer_policies_for_backend_server`].
+    pub fn set_load_balancer_policies_for_backend_server(
+        &self,
+        input: SetLoadBalancerPoliciesForBackendServerInput,
+    ) -> Result<
+        SetLoadBalancerPoliciesForBackendServerOutput,
+        RusotoError<SetLoadBalancerPoliciesForBackendServerError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .set_load_balancer_policies_for_backend_server(input),
+        )
+    }
+
+    /// Blocking version of [`Elb::set_load_balancer_policies_of_listener`].
+    pub fn set_load_balancer_policies_of_listener(
+        &self,
+        input: SetLoadBalancerPoliciesOfListenerInput,
+    ) -> Result<
+        SetLoadBalancerPoliciesOfListenerOutput,
+        RusotoError<SetLoadBalancerPoliciesOfListenerError>,
+    > {
+        self.runtime
+            .block_on(self.client.set_load_balancer_policies_of_listener(input))
+    }
+}
+
 #[cfg(test)]
 mod protocol_tests {
 
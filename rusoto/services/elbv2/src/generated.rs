@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -6446,18 +6447,27 @@ impl AddListenerCertificatesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CertificateNotFound" => {
-                        return RusotoError::Service(
-                            AddListenerCertificatesError::CertificateNotFound(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AddListenerCertificatesError::CertificateNotFound,
                         )
                     }
                     "ListenerNotFound" => {
-                        return RusotoError::Service(
-                            AddListenerCertificatesError::ListenerNotFound(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AddListenerCertificatesError::ListenerNotFound,
                         )
                     }
                     "TooManyCertificates" => {
-                        return RusotoError::Service(
-                            AddListenerCertificatesError::TooManyCertificates(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AddListenerCertificatesError::TooManyCertificates,
                         )
                     }
                     _ => {}
@@ -6508,24 +6518,36 @@ impl AddTagsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DuplicateTagKeys" => {
-                        return RusotoError::Service(AddTagsError::DuplicateTagKeys(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AddTagsError::DuplicateTagKeys,
+                        )
                     }
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(AddTagsError::LoadBalancerNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AddTagsError::LoadBalancerNotFound,
+                        )
                     }
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(AddTagsError::TargetGroupNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AddTagsError::TargetGroupNotFound,
+                        )
                     }
                     "TooManyTags" => {
-                        return RusotoError::Service(AddTagsError::TooManyTags(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AddTagsError::TooManyTags,
+                        )
                     }
                     _ => {}
                 }
@@ -6604,98 +6626,148 @@ impl CreateListenerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ALPNPolicyNotFound" => {
-                        return RusotoError::Service(CreateListenerError::ALPNPolicyNotSupported(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateListenerError::ALPNPolicyNotSupported,
+                        )
                     }
                     "CertificateNotFound" => {
-                        return RusotoError::Service(CreateListenerError::CertificateNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateListenerError::CertificateNotFound,
+                        )
                     }
                     "DuplicateListener" => {
-                        return RusotoError::Service(CreateListenerError::DuplicateListener(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateListenerError::DuplicateListener,
+                        )
                     }
                     "IncompatibleProtocols" => {
-                        return RusotoError::Service(CreateListenerError::IncompatibleProtocols(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateListenerError::IncompatibleProtocols,
+                        )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
-                            CreateListenerError::InvalidConfigurationRequest(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateListenerError::InvalidConfigurationRequest,
                         )
                     }
                     "InvalidLoadBalancerAction" => {
-                        return RusotoError::Service(
-                            CreateListenerError::InvalidLoadBalancerAction(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateListenerError::InvalidLoadBalancerAction,
                         )
                     }
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(CreateListenerError::LoadBalancerNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateListenerError::LoadBalancerNotFound,
+                        )
                     }
                     "SSLPolicyNotFound" => {
-                        return RusotoError::Service(CreateListenerError::SSLPolicyNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateListenerError::SSLPolicyNotFound,
+                        )
                     }
                     "TargetGroupAssociationLimit" => {
-                        return RusotoError::Service(
-                            CreateListenerError::TargetGroupAssociationLimit(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateListenerError::TargetGroupAssociationLimit,
                         )
                     }
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(CreateListenerError::TargetGroupNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateListenerError::TargetGroupNotFound,
+                        )
                     }
                     "TooManyActions" => {
-                        return RusotoError::Service(CreateListenerError::TooManyActions(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateListenerError::TooManyActions,
+                        )
                     }
                     "TooManyCertificates" => {
-                        return RusotoError::Service(CreateListenerError::TooManyCertificates(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateListenerError::TooManyCertificates,
+                        )
                     }
                     "TooManyListeners" => {
-                        return RusotoError::Service(CreateListenerError::TooManyListeners(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateListenerError::TooManyListeners,
+                        )
                     }
                     "TooManyRegistrationsForTargetId" => {
-                        return RusotoError::Service(
-                            CreateListenerError::TooManyRegistrationsForTargetId(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateListenerError::TooManyRegistrationsForTargetId,
                         )
                     }
                     "TooManyTags" => {
-                        return RusotoError::Service(CreateListenerError::TooManyTags(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateListenerError::TooManyTags,
+                        )
                     }
                     "TooManyTargets" => {
-                        return RusotoError::Service(CreateListenerError::TooManyTargets(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateListenerError::TooManyTargets,
+                        )
                     }
                     "TooManyUniqueTargetGroupsPerLoadBalancer" => {
-                        return RusotoError::Service(
-                            CreateListenerError::TooManyUniqueTargetGroupsPerLoadBalancer(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateListenerError::TooManyUniqueTargetGroupsPerLoadBalancer,
                         )
                     }
                     "UnsupportedProtocol" => {
-                        return RusotoError::Service(CreateListenerError::UnsupportedProtocol(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateListenerError::UnsupportedProtocol,
+                        )
                     }
                     _ => {}
                 }
@@ -6782,75 +6854,108 @@ impl CreateLoadBalancerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AllocationIdNotFound" => {
-                        return RusotoError::Service(CreateLoadBalancerError::AllocationIdNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateLoadBalancerError::AllocationIdNotFound,
+                        )
                     }
                     "AvailabilityZoneNotSupported" => {
-                        return RusotoError::Service(
-                            CreateLoadBalancerError::AvailabilityZoneNotSupported(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateLoadBalancerError::AvailabilityZoneNotSupported,
                         )
                     }
                     "DuplicateLoadBalancerName" => {
-                        return RusotoError::Service(
-                            CreateLoadBalancerError::DuplicateLoadBalancerName(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateLoadBalancerError::DuplicateLoadBalancerName,
                         )
                     }
                     "DuplicateTagKeys" => {
-                        return RusotoError::Service(CreateLoadBalancerError::DuplicateTagKeys(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateLoadBalancerError::DuplicateTagKeys,
+                        )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
-                            CreateLoadBalancerError::InvalidConfigurationRequest(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateLoadBalancerError::InvalidConfigurationRequest,
                         )
                     }
                     "InvalidScheme" => {
-                        return RusotoError::Service(CreateLoadBalancerError::InvalidScheme(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateLoadBalancerError::InvalidScheme,
+                        )
                     }
                     "InvalidSecurityGroup" => {
-                        return RusotoError::Service(CreateLoadBalancerError::InvalidSecurityGroup(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateLoadBalancerError::InvalidSecurityGroup,
+                        )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateLoadBalancerError::InvalidSubnet(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateLoadBalancerError::InvalidSubnet,
+                        )
                     }
                     "OperationNotPermitted" => {
-                        return RusotoError::Service(
-                            CreateLoadBalancerError::OperationNotPermitted(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateLoadBalancerError::OperationNotPermitted,
                         )
                     }
                     "ResourceInUse" => {
-                        return RusotoError::Service(CreateLoadBalancerError::ResourceInUse(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateLoadBalancerError::ResourceInUse,
+                        )
                     }
                     "SubnetNotFound" => {
-                        return RusotoError::Service(CreateLoadBalancerError::SubnetNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateLoadBalancerError::SubnetNotFound,
+                        )
                     }
                     "TooManyLoadBalancers" => {
-                        return RusotoError::Service(CreateLoadBalancerError::TooManyLoadBalancers(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateLoadBalancerError::TooManyLoadBalancers,
+                        )
                     }
                     "TooManyTags" => {
-                        return RusotoError::Service(CreateLoadBalancerError::TooManyTags(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateLoadBalancerError::TooManyTags,
+                        )
                     }
                     _ => {}
                 }
@@ -6936,81 +7041,124 @@ impl CreateRuleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "IncompatibleProtocols" => {
-                        return RusotoError::Service(CreateRuleError::IncompatibleProtocols(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateRuleError::IncompatibleProtocols,
+                        )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(CreateRuleError::InvalidConfigurationRequest(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateRuleError::InvalidConfigurationRequest,
+                        )
                     }
                     "InvalidLoadBalancerAction" => {
-                        return RusotoError::Service(CreateRuleError::InvalidLoadBalancerAction(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateRuleError::InvalidLoadBalancerAction,
+                        )
                     }
                     "ListenerNotFound" => {
-                        return RusotoError::Service(CreateRuleError::ListenerNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateRuleError::ListenerNotFound,
+                        )
                     }
                     "PriorityInUse" => {
-                        return RusotoError::Service(CreateRuleError::PriorityInUse(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateRuleError::PriorityInUse,
+                        )
                     }
                     "TargetGroupAssociationLimit" => {
-                        return RusotoError::Service(CreateRuleError::TargetGroupAssociationLimit(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateRuleError::TargetGroupAssociationLimit,
+                        )
                     }
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(CreateRuleError::TargetGroupNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateRuleError::TargetGroupNotFound,
+                        )
                     }
                     "TooManyActions" => {
-                        return RusotoError::Service(CreateRuleError::TooManyActions(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateRuleError::TooManyActions,
+                        )
                     }
                     "TooManyRegistrationsForTargetId" => {
-                        return RusotoError::Service(
-                            CreateRuleError::TooManyRegistrationsForTargetId(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateRuleError::TooManyRegistrationsForTargetId,
                         )
                     }
                     "TooManyRules" => {
-                        return RusotoError::Service(CreateRuleError::TooManyRules(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateRuleError::TooManyRules,
+                        )
                     }
                     "TooManyTags" => {
-                        return RusotoError::Service(CreateRuleError::TooManyTags(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateRuleError::TooManyTags,
+                        )
                     }
                     "TooManyTargetGroups" => {
-                        return RusotoError::Service(CreateRuleError::TooManyTargetGroups(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateRuleError::TooManyTargetGroups,
+                        )
                     }
                     "TooManyTargets" => {
-                        return RusotoError::Service(CreateRuleError::TooManyTargets(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateRuleError::TooManyTargets,
+                        )
                     }
                     "TooManyUniqueTargetGroupsPerLoadBalancer" => {
-                        return RusotoError::Service(
-                            CreateRuleError::TooManyUniqueTargetGroupsPerLoadBalancer(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateRuleError::TooManyUniqueTargetGroupsPerLoadBalancer,
                         )
                     }
                     "UnsupportedProtocol" => {
-                        return RusotoError::Service(CreateRuleError::UnsupportedProtocol(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateRuleError::UnsupportedProtocol,
+                        )
                     }
                     _ => {}
                 }
@@ -7074,26 +7222,36 @@ impl CreateTargetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DuplicateTargetGroupName" => {
-                        return RusotoError::Service(
-                            CreateTargetGroupError::DuplicateTargetGroupName(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateTargetGroupError::DuplicateTargetGroupName,
                         )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
-                            CreateTargetGroupError::InvalidConfigurationRequest(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateTargetGroupError::InvalidConfigurationRequest,
                         )
                     }
                     "TooManyTags" => {
-                        return RusotoError::Service(CreateTargetGroupError::TooManyTags(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateTargetGroupError::TooManyTags,
+                        )
                     }
                     "TooManyTargetGroups" => {
-                        return RusotoError::Service(CreateTargetGroupError::TooManyTargetGroups(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateTargetGroupError::TooManyTargetGroups,
+                        )
                     }
                     _ => {}
                 }
@@ -7140,9 +7298,12 @@ impl DeleteListenerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ListenerNotFound" => {
-                        return RusotoError::Service(DeleteListenerError::ListenerNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteListenerError::ListenerNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -7188,19 +7349,28 @@ impl DeleteLoadBalancerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(DeleteLoadBalancerError::LoadBalancerNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteLoadBalancerError::LoadBalancerNotFound,
+                        )
                     }
                     "OperationNotPermitted" => {
-                        return RusotoError::Service(
-                            DeleteLoadBalancerError::OperationNotPermitted(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteLoadBalancerError::OperationNotPermitted,
                         )
                     }
                     "ResourceInUse" => {
-                        return RusotoError::Service(DeleteLoadBalancerError::ResourceInUse(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteLoadBalancerError::ResourceInUse,
+                        )
                     }
                     _ => {}
                 }
@@ -7246,14 +7416,20 @@ impl DeleteRuleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "OperationNotPermitted" => {
-                        return RusotoError::Service(DeleteRuleError::OperationNotPermitted(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteRuleError::OperationNotPermitted,
+                        )
                     }
                     "RuleNotFound" => {
-                        return RusotoError::Service(DeleteRuleError::RuleNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteRuleError::RuleNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -7296,9 +7472,12 @@ impl DeleteTargetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceInUse" => {
-                        return RusotoError::Service(DeleteTargetGroupError::ResourceInUse(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteTargetGroupError::ResourceInUse,
+                        )
                     }
                     _ => {}
                 }
@@ -7342,14 +7521,20 @@ impl DeregisterTargetsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidTarget" => {
-                        return RusotoError::Service(DeregisterTargetsError::InvalidTarget(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeregisterTargetsError::InvalidTarget,
+                        )
                     }
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(DeregisterTargetsError::TargetGroupNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeregisterTargetsError::TargetGroupNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -7428,10 +7613,11 @@ impl DescribeListenerCertificatesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ListenerNotFound" => {
-                        return RusotoError::Service(
-                            DescribeListenerCertificatesError::ListenerNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeListenerCertificatesError::ListenerNotFound,
                         )
                     }
                     _ => {}
@@ -7480,19 +7666,28 @@ impl DescribeListenersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ListenerNotFound" => {
-                        return RusotoError::Service(DescribeListenersError::ListenerNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeListenersError::ListenerNotFound,
+                        )
                     }
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(DescribeListenersError::LoadBalancerNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeListenersError::LoadBalancerNotFound,
+                        )
                     }
                     "UnsupportedProtocol" => {
-                        return RusotoError::Service(DescribeListenersError::UnsupportedProtocol(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeListenersError::UnsupportedProtocol,
+                        )
                     }
                     _ => {}
                 }
@@ -7538,10 +7733,11 @@ impl DescribeLoadBalancerAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
-                            DescribeLoadBalancerAttributesError::LoadBalancerNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeLoadBalancerAttributesError::LoadBalancerNotFound,
                         )
                     }
                     _ => {}
@@ -7586,8 +7782,11 @@ impl DescribeLoadBalancersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
-                            DescribeLoadBalancersError::LoadBalancerNotFound(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeLoadBalancersError::LoadBalancerNotFound,
                         )
                     }
                     _ => {}
@@ -7634,19 +7833,28 @@ impl DescribeRulesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ListenerNotFound" => {
-                        return RusotoError::Service(DescribeRulesError::ListenerNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeRulesError::ListenerNotFound,
+                        )
                     }
                     "RuleNotFound" => {
-                        return RusotoError::Service(DescribeRulesError::RuleNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeRulesError::RuleNotFound,
+                        )
                     }
                     "UnsupportedProtocol" => {
-                        return RusotoError::Service(DescribeRulesError::UnsupportedProtocol(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeRulesError::UnsupportedProtocol,
+                        )
                     }
                     _ => {}
                 }
@@ -7690,9 +7898,12 @@ impl DescribeSSLPoliciesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "SSLPolicyNotFound" => {
-                        return RusotoError::Service(DescribeSSLPoliciesError::SSLPolicyNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeSSLPoliciesError::SSLPolicyNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -7740,24 +7951,36 @@ impl DescribeTagsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ListenerNotFound" => {
-                        return RusotoError::Service(DescribeTagsError::ListenerNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeTagsError::ListenerNotFound,
+                        )
                     }
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(DescribeTagsError::LoadBalancerNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeTagsError::LoadBalancerNotFound,
+                        )
                     }
                     "RuleNotFound" => {
-                        return RusotoError::Service(DescribeTagsError::RuleNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeTagsError::RuleNotFound,
+                        )
                     }
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(DescribeTagsError::TargetGroupNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeTagsError::TargetGroupNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -7804,10 +8027,11 @@ impl DescribeTargetGroupAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(
-                            DescribeTargetGroupAttributesError::TargetGroupNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeTargetGroupAttributesError::TargetGroupNotFound,
                         )
                     }
                     _ => {}
@@ -7854,13 +8078,19 @@ impl DescribeTargetGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
-                            DescribeTargetGroupsError::LoadBalancerNotFound(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeTargetGroupsError::LoadBalancerNotFound,
                         )
                     }
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(
-                            DescribeTargetGroupsError::TargetGroupNotFound(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeTargetGroupsError::TargetGroupNotFound,
                         )
                     }
                     _ => {}
@@ -7908,18 +8138,27 @@ impl DescribeTargetHealthError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "HealthUnavailable" => {
-                        return RusotoError::Service(DescribeTargetHealthError::HealthUnavailable(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeTargetHealthError::HealthUnavailable,
+                        )
                     }
                     "InvalidTarget" => {
-                        return RusotoError::Service(DescribeTargetHealthError::InvalidTarget(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeTargetHealthError::InvalidTarget,
+                        )
                     }
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(
-                            DescribeTargetHealthError::TargetGroupNotFound(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeTargetHealthError::TargetGroupNotFound,
                         )
                     }
                     _ => {}
@@ -7996,93 +8235,140 @@ impl ModifyListenerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ALPNPolicyNotFound" => {
-                        return RusotoError::Service(ModifyListenerError::ALPNPolicyNotSupported(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyListenerError::ALPNPolicyNotSupported,
+                        )
                     }
                     "CertificateNotFound" => {
-                        return RusotoError::Service(ModifyListenerError::CertificateNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyListenerError::CertificateNotFound,
+                        )
                     }
                     "DuplicateListener" => {
-                        return RusotoError::Service(ModifyListenerError::DuplicateListener(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyListenerError::DuplicateListener,
+                        )
                     }
                     "IncompatibleProtocols" => {
-                        return RusotoError::Service(ModifyListenerError::IncompatibleProtocols(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyListenerError::IncompatibleProtocols,
+                        )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
-                            ModifyListenerError::InvalidConfigurationRequest(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyListenerError::InvalidConfigurationRequest,
                         )
                     }
                     "InvalidLoadBalancerAction" => {
-                        return RusotoError::Service(
-                            ModifyListenerError::InvalidLoadBalancerAction(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyListenerError::InvalidLoadBalancerAction,
                         )
                     }
                     "ListenerNotFound" => {
-                        return RusotoError::Service(ModifyListenerError::ListenerNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyListenerError::ListenerNotFound,
+                        )
                     }
                     "SSLPolicyNotFound" => {
-                        return RusotoError::Service(ModifyListenerError::SSLPolicyNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyListenerError::SSLPolicyNotFound,
+                        )
                     }
                     "TargetGroupAssociationLimit" => {
-                        return RusotoError::Service(
-                            ModifyListenerError::TargetGroupAssociationLimit(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyListenerError::TargetGroupAssociationLimit,
                         )
                     }
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(ModifyListenerError::TargetGroupNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyListenerError::TargetGroupNotFound,
+                        )
                     }
                     "TooManyActions" => {
-                        return RusotoError::Service(ModifyListenerError::TooManyActions(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyListenerError::TooManyActions,
+                        )
                     }
                     "TooManyCertificates" => {
-                        return RusotoError::Service(ModifyListenerError::TooManyCertificates(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyListenerError::TooManyCertificates,
+                        )
                     }
                     "TooManyListeners" => {
-                        return RusotoError::Service(ModifyListenerError::TooManyListeners(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyListenerError::TooManyListeners,
+                        )
                     }
                     "TooManyRegistrationsForTargetId" => {
-                        return RusotoError::Service(
-                            ModifyListenerError::TooManyRegistrationsForTargetId(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyListenerError::TooManyRegistrationsForTargetId,
                         )
                     }
                     "TooManyTargets" => {
-                        return RusotoError::Service(ModifyListenerError::TooManyTargets(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyListenerError::TooManyTargets,
+                        )
                     }
                     "TooManyUniqueTargetGroupsPerLoadBalancer" => {
-                        return RusotoError::Service(
-                            ModifyListenerError::TooManyUniqueTargetGroupsPerLoadBalancer(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyListenerError::TooManyUniqueTargetGroupsPerLoadBalancer,
                         )
                     }
                     "UnsupportedProtocol" => {
-                        return RusotoError::Service(ModifyListenerError::UnsupportedProtocol(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyListenerError::UnsupportedProtocol,
+                        )
                     }
                     _ => {}
                 }
@@ -8148,17 +8434,19 @@ impl ModifyLoadBalancerAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
-                            ModifyLoadBalancerAttributesError::InvalidConfigurationRequest(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyLoadBalancerAttributesError::InvalidConfigurationRequest,
                         )
                     }
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(
-                            ModifyLoadBalancerAttributesError::LoadBalancerNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyLoadBalancerAttributesError::LoadBalancerNotFound,
                         )
                     }
                     _ => {}
@@ -8226,61 +8514,92 @@ impl ModifyRuleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "IncompatibleProtocols" => {
-                        return RusotoError::Service(ModifyRuleError::IncompatibleProtocols(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyRuleError::IncompatibleProtocols,
+                        )
                     }
                     "InvalidLoadBalancerAction" => {
-                        return RusotoError::Service(ModifyRuleError::InvalidLoadBalancerAction(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyRuleError::InvalidLoadBalancerAction,
+                        )
                     }
                     "OperationNotPermitted" => {
-                        return RusotoError::Service(ModifyRuleError::OperationNotPermitted(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyRuleError::OperationNotPermitted,
+                        )
                     }
                     "RuleNotFound" => {
-                        return RusotoError::Service(ModifyRuleError::RuleNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyRuleError::RuleNotFound,
+                        )
                     }
                     "TargetGroupAssociationLimit" => {
-                        return RusotoError::Service(ModifyRuleError::TargetGroupAssociationLimit(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyRuleError::TargetGroupAssociationLimit,
+                        )
                     }
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(ModifyRuleError::TargetGroupNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyRuleError::TargetGroupNotFound,
+                        )
                     }
                     "TooManyActions" => {
-                        return RusotoError::Service(ModifyRuleError::TooManyActions(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyRuleError::TooManyActions,
+                        )
                     }
                     "TooManyRegistrationsForTargetId" => {
-                        return RusotoError::Service(
-                            ModifyRuleError::TooManyRegistrationsForTargetId(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyRuleError::TooManyRegistrationsForTargetId,
                         )
                     }
                     "TooManyTargets" => {
-                        return RusotoError::Service(ModifyRuleError::TooManyTargets(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyRuleError::TooManyTargets,
+                        )
                     }
                     "TooManyUniqueTargetGroupsPerLoadBalancer" => {
-                        return RusotoError::Service(
-                            ModifyRuleError::TooManyUniqueTargetGroupsPerLoadBalancer(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyRuleError::TooManyUniqueTargetGroupsPerLoadBalancer,
                         )
                     }
                     "UnsupportedProtocol" => {
-                        return RusotoError::Service(ModifyRuleError::UnsupportedProtocol(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyRuleError::UnsupportedProtocol,
+                        )
                     }
                     _ => {}
                 }
@@ -8336,16 +8655,20 @@ impl ModifyTargetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
-                            ModifyTargetGroupError::InvalidConfigurationRequest(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyTargetGroupError::InvalidConfigurationRequest,
                         )
                     }
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(ModifyTargetGroupError::TargetGroupNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyTargetGroupError::TargetGroupNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -8394,17 +8717,19 @@ impl ModifyTargetGroupAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
-                            ModifyTargetGroupAttributesError::InvalidConfigurationRequest(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyTargetGroupAttributesError::InvalidConfigurationRequest,
                         )
                     }
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(
-                            ModifyTargetGroupAttributesError::TargetGroupNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyTargetGroupAttributesError::TargetGroupNotFound,
                         )
                     }
                     _ => {}
@@ -8458,26 +8783,36 @@ impl RegisterTargetsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidTarget" => {
-                        return RusotoError::Service(RegisterTargetsError::InvalidTarget(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            RegisterTargetsError::InvalidTarget,
+                        )
                     }
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(RegisterTargetsError::TargetGroupNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            RegisterTargetsError::TargetGroupNotFound,
+                        )
                     }
                     "TooManyRegistrationsForTargetId" => {
-                        return RusotoError::Service(
-                            RegisterTargetsError::TooManyRegistrationsForTargetId(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RegisterTargetsError::TooManyRegistrationsForTargetId,
                         )
                     }
                     "TooManyTargets" => {
-                        return RusotoError::Service(RegisterTargetsError::TooManyTargets(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            RegisterTargetsError::TooManyTargets,
+                        )
                     }
                     _ => {}
                 }
@@ -8528,15 +8863,19 @@ impl RemoveListenerCertificatesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ListenerNotFound" => {
-                        return RusotoError::Service(
-                            RemoveListenerCertificatesError::ListenerNotFound(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RemoveListenerCertificatesError::ListenerNotFound,
                         )
                     }
                     "OperationNotPermitted" => {
-                        return RusotoError::Service(
-                            RemoveListenerCertificatesError::OperationNotPermitted(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RemoveListenerCertificatesError::OperationNotPermitted,
                         )
                     }
                     _ => {}
@@ -8590,29 +8929,44 @@ impl RemoveTagsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ListenerNotFound" => {
-                        return RusotoError::Service(RemoveTagsError::ListenerNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            RemoveTagsError::ListenerNotFound,
+                        )
                     }
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(RemoveTagsError::LoadBalancerNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            RemoveTagsError::LoadBalancerNotFound,
+                        )
                     }
                     "RuleNotFound" => {
-                        return RusotoError::Service(RemoveTagsError::RuleNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            RemoveTagsError::RuleNotFound,
+                        )
                     }
                     "TargetGroupNotFound" => {
-                        return RusotoError::Service(RemoveTagsError::TargetGroupNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            RemoveTagsError::TargetGroupNotFound,
+                        )
                     }
                     "TooManyTags" => {
-                        return RusotoError::Service(RemoveTagsError::TooManyTags(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            RemoveTagsError::TooManyTags,
+                        )
                     }
                     _ => {}
                 }
@@ -8662,21 +9016,28 @@ impl SetIpAddressTypeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
-                            SetIpAddressTypeError::InvalidConfigurationRequest(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SetIpAddressTypeError::InvalidConfigurationRequest,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(SetIpAddressTypeError::InvalidSubnet(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SetIpAddressTypeError::InvalidSubnet,
+                        )
                     }
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(SetIpAddressTypeError::LoadBalancerNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SetIpAddressTypeError::LoadBalancerNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -8724,19 +9085,28 @@ impl SetRulePrioritiesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "OperationNotPermitted" => {
-                        return RusotoError::Service(SetRulePrioritiesError::OperationNotPermitted(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SetRulePrioritiesError::OperationNotPermitted,
+                        )
                     }
                     "PriorityInUse" => {
-                        return RusotoError::Service(SetRulePrioritiesError::PriorityInUse(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SetRulePrioritiesError::PriorityInUse,
+                        )
                     }
                     "RuleNotFound" => {
-                        return RusotoError::Service(SetRulePrioritiesError::RuleNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SetRulePrioritiesError::RuleNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -8784,21 +9154,28 @@ impl SetSecurityGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(
-                            SetSecurityGroupsError::InvalidConfigurationRequest(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SetSecurityGroupsError::InvalidConfigurationRequest,
                         )
                     }
                     "InvalidSecurityGroup" => {
-                        return RusotoError::Service(SetSecurityGroupsError::InvalidSecurityGroup(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SetSecurityGroupsError::InvalidSecurityGroup,
+                        )
                     }
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(SetSecurityGroupsError::LoadBalancerNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SetSecurityGroupsError::LoadBalancerNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -8854,34 +9231,52 @@ impl SetSubnetsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AllocationIdNotFound" => {
-                        return RusotoError::Service(SetSubnetsError::AllocationIdNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SetSubnetsError::AllocationIdNotFound,
+                        )
                     }
                     "AvailabilityZoneNotSupported" => {
-                        return RusotoError::Service(SetSubnetsError::AvailabilityZoneNotSupported(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SetSubnetsError::AvailabilityZoneNotSupported,
+                        )
                     }
                     "InvalidConfigurationRequest" => {
-                        return RusotoError::Service(SetSubnetsError::InvalidConfigurationRequest(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SetSubnetsError::InvalidConfigurationRequest,
+                        )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(SetSubnetsError::InvalidSubnet(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SetSubnetsError::InvalidSubnet,
+                        )
                     }
                     "LoadBalancerNotFound" => {
-                        return RusotoError::Service(SetSubnetsError::LoadBalancerNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SetSubnetsError::LoadBalancerNotFound,
+                        )
                     }
                     "SubnetNotFound" => {
-                        return RusotoError::Service(SetSubnetsError::SubnetNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SetSubnetsError::SubnetNotFound,
+                        )
                     }
                     _ => {}
                 }
@@ -9132,11 +9527,12 @@ pub struct ElbClient {
 impl ElbClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> ElbClient {
         ElbClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Elastic Load Balancing v2"),
         }
     }
 
@@ -9151,12 +9547,32 @@ impl ElbClient {
     {
         ElbClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Elastic Load Balancing v2"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ElbClient {
-        ElbClient { client, region }
+        ElbClient {
+            client,
+            region: region_for_service(region, "Elastic Load Balancing v2"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> ElbClient {
+        ElbClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Elastic Load Balancing v2"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ElbClient {
+        ElbClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -9168,6 +9584,7 @@ impl Elb for ElbClient {
         input: AddListenerCertificatesInput,
     ) -> Result<AddListenerCertificatesOutput, RusotoError<AddListenerCertificatesError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("AddListenerCertificates");
         let params = self.new_params("AddListenerCertificates");
         let mut params = params;
         AddListenerCertificatesInputSerializer::serialize(&mut params, "", &input);
@@ -9201,6 +9618,7 @@ impl Elb for ElbClient {
         input: AddTagsInput,
     ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("AddTags");
         let params = self.new_params("AddTags");
         let mut params = params;
         AddTagsInputSerializer::serialize(&mut params, "", &input);
@@ -9223,6 +9641,7 @@ impl Elb for ElbClient {
         input: CreateListenerInput,
     ) -> Result<CreateListenerOutput, RusotoError<CreateListenerError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("CreateListener");
         let params = self.new_params("CreateListener");
         let mut params = params;
         CreateListenerInputSerializer::serialize(&mut params, "", &input);
@@ -9254,6 +9673,7 @@ impl Elb for ElbClient {
         input: CreateLoadBalancerInput,
     ) -> Result<CreateLoadBalancerOutput, RusotoError<CreateLoadBalancerError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("CreateLoadBalancer");
         let params = self.new_params("CreateLoadBalancer");
         let mut params = params;
         CreateLoadBalancerInputSerializer::serialize(&mut params, "", &input);
@@ -9287,6 +9707,7 @@ impl Elb for ElbClient {
         input: CreateRuleInput,
     ) -> Result<CreateRuleOutput, RusotoError<CreateRuleError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("CreateRule");
         let params = self.new_params("CreateRule");
         let mut params = params;
         CreateRuleInputSerializer::serialize(&mut params, "", &input);
@@ -9317,6 +9738,7 @@ impl Elb for ElbClient {
         input: CreateTargetGroupInput,
     ) -> Result<CreateTargetGroupOutput, RusotoError<CreateTargetGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("CreateTargetGroup");
         let params = self.new_params("CreateTargetGroup");
         let mut params = params;
         CreateTargetGroupInputSerializer::serialize(&mut params, "", &input);
@@ -9348,6 +9770,7 @@ impl Elb for ElbClient {
         input: DeleteListenerInput,
     ) -> Result<DeleteListenerOutput, RusotoError<DeleteListenerError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DeleteListener");
         let params = self.new_params("DeleteListener");
         let mut params = params;
         DeleteListenerInputSerializer::serialize(&mut params, "", &input);
@@ -9370,6 +9793,7 @@ impl Elb for ElbClient {
         input: DeleteLoadBalancerInput,
     ) -> Result<DeleteLoadBalancerOutput, RusotoError<DeleteLoadBalancerError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DeleteLoadBalancer");
         let params = self.new_params("DeleteLoadBalancer");
         let mut params = params;
         DeleteLoadBalancerInputSerializer::serialize(&mut params, "", &input);
@@ -9392,6 +9816,7 @@ impl Elb for ElbClient {
         input: DeleteRuleInput,
     ) -> Result<DeleteRuleOutput, RusotoError<DeleteRuleError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DeleteRule");
         let params = self.new_params("DeleteRule");
         let mut params = params;
         DeleteRuleInputSerializer::serialize(&mut params, "", &input);
@@ -9414,6 +9839,7 @@ impl Elb for ElbClient {
         input: DeleteTargetGroupInput,
     ) -> Result<DeleteTargetGroupOutput, RusotoError<DeleteTargetGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DeleteTargetGroup");
         let params = self.new_params("DeleteTargetGroup");
         let mut params = params;
         DeleteTargetGroupInputSerializer::serialize(&mut params, "", &input);
@@ -9436,6 +9862,7 @@ impl Elb for ElbClient {
         input: DeregisterTargetsInput,
     ) -> Result<DeregisterTargetsOutput, RusotoError<DeregisterTargetsError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DeregisterTargets");
         let params = self.new_params("DeregisterTargets");
         let mut params = params;
         DeregisterTargetsInputSerializer::serialize(&mut params, "", &input);
@@ -9458,6 +9885,7 @@ impl Elb for ElbClient {
         input: DescribeAccountLimitsInput,
     ) -> Result<DescribeAccountLimitsOutput, RusotoError<DescribeAccountLimitsError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeAccountLimits");
         let params = self.new_params("DescribeAccountLimits");
         let mut params = params;
         DescribeAccountLimitsInputSerializer::serialize(&mut params, "", &input);
@@ -9492,6 +9920,7 @@ impl Elb for ElbClient {
     ) -> Result<DescribeListenerCertificatesOutput, RusotoError<DescribeListenerCertificatesError>>
     {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeListenerCertificates");
         let params = self.new_params("DescribeListenerCertificates");
         let mut params = params;
         DescribeListenerCertificatesInputSerializer::serialize(&mut params, "", &input);
@@ -9525,6 +9954,7 @@ impl Elb for ElbClient {
         input: DescribeListenersInput,
     ) -> Result<DescribeListenersOutput, RusotoError<DescribeListenersError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeListeners");
         let params = self.new_params("DescribeListeners");
         let mut params = params;
         DescribeListenersInputSerializer::serialize(&mut params, "", &input);
@@ -9559,6 +9989,7 @@ impl Elb for ElbClient {
         RusotoError<DescribeLoadBalancerAttributesError>,
     > {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeLoadBalancerAttributes");
         let params = self.new_params("DescribeLoadBalancerAttributes");
         let mut params = params;
         DescribeLoadBalancerAttributesInputSerializer::serialize(&mut params, "", &input);
@@ -9592,6 +10023,7 @@ impl Elb for ElbClient {
         input: DescribeLoadBalancersInput,
     ) -> Result<DescribeLoadBalancersOutput, RusotoError<DescribeLoadBalancersError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeLoadBalancers");
         let params = self.new_params("DescribeLoadBalancers");
         let mut params = params;
         DescribeLoadBalancersInputSerializer::serialize(&mut params, "", &input);
@@ -9625,6 +10057,7 @@ impl Elb for ElbClient {
         input: DescribeRulesInput,
     ) -> Result<DescribeRulesOutput, RusotoError<DescribeRulesError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeRules");
         let params = self.new_params("DescribeRules");
         let mut params = params;
         DescribeRulesInputSerializer::serialize(&mut params, "", &input);
@@ -9656,6 +10089,7 @@ impl Elb for ElbClient {
         input: DescribeSSLPoliciesInput,
     ) -> Result<DescribeSSLPoliciesOutput, RusotoError<DescribeSSLPoliciesError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeSSLPolicies");
         let params = self.new_params("DescribeSSLPolicies");
         let mut params = params;
         DescribeSSLPoliciesInputSerializer::serialize(&mut params, "", &input);
@@ -9689,6 +10123,7 @@ impl Elb for ElbClient {
         input: DescribeTagsInput,
     ) -> Result<DescribeTagsOutput, RusotoError<DescribeTagsError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeTags");
         let params = self.new_params("DescribeTags");
         let mut params = params;
         DescribeTagsInputSerializer::serialize(&mut params, "", &input);
@@ -9720,6 +10155,7 @@ impl Elb for ElbClient {
     ) -> Result<DescribeTargetGroupAttributesOutput, RusotoError<DescribeTargetGroupAttributesError>>
     {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeTargetGroupAttributes");
         let params = self.new_params("DescribeTargetGroupAttributes");
         let mut params = params;
         DescribeTargetGroupAttributesInputSerializer::serialize(&mut params, "", &input);
@@ -9753,6 +10189,7 @@ impl Elb for ElbClient {
         input: DescribeTargetGroupsInput,
     ) -> Result<DescribeTargetGroupsOutput, RusotoError<DescribeTargetGroupsError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeTargetGroups");
         let params = self.new_params("DescribeTargetGroups");
         let mut params = params;
         DescribeTargetGroupsInputSerializer::serialize(&mut params, "", &input);
@@ -9786,6 +10223,7 @@ impl Elb for ElbClient {
         input: DescribeTargetHealthInput,
     ) -> Result<DescribeTargetHealthOutput, RusotoError<DescribeTargetHealthError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("DescribeTargetHealth");
         let params = self.new_params("DescribeTargetHealth");
         let mut params = params;
         DescribeTargetHealthInputSerializer::serialize(&mut params, "", &input);
@@ -9819,6 +10257,7 @@ impl Elb for ElbClient {
         input: ModifyListenerInput,
     ) -> Result<ModifyListenerOutput, RusotoError<ModifyListenerError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("ModifyListener");
         let params = self.new_params("ModifyListener");
         let mut params = params;
         ModifyListenerInputSerializer::serialize(&mut params, "", &input);
@@ -9851,6 +10290,7 @@ impl Elb for ElbClient {
     ) -> Result<ModifyLoadBalancerAttributesOutput, RusotoError<ModifyLoadBalancerAttributesError>>
     {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("ModifyLoadBalancerAttributes");
         let params = self.new_params("ModifyLoadBalancerAttributes");
         let mut params = params;
         ModifyLoadBalancerAttributesInputSerializer::serialize(&mut params, "", &input);
@@ -9884,6 +10324,7 @@ impl Elb for ElbClient {
         input: ModifyRuleInput,
     ) -> Result<ModifyRuleOutput, RusotoError<ModifyRuleError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("ModifyRule");
         let params = self.new_params("ModifyRule");
         let mut params = params;
         ModifyRuleInputSerializer::serialize(&mut params, "", &input);
@@ -9914,6 +10355,7 @@ impl Elb for ElbClient {
         input: ModifyTargetGroupInput,
     ) -> Result<ModifyTargetGroupOutput, RusotoError<ModifyTargetGroupError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("ModifyTargetGroup");
         let params = self.new_params("ModifyTargetGroup");
         let mut params = params;
         ModifyTargetGroupInputSerializer::serialize(&mut params, "", &input);
@@ -9946,6 +10388,7 @@ impl Elb for ElbClient {
     ) -> Result<ModifyTargetGroupAttributesOutput, RusotoError<ModifyTargetGroupAttributesError>>
     {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("ModifyTargetGroupAttributes");
         let params = self.new_params("ModifyTargetGroupAttributes");
         let mut params = params;
         ModifyTargetGroupAttributesInputSerializer::serialize(&mut params, "", &input);
@@ -9979,6 +10422,7 @@ impl Elb for ElbClient {
         input: RegisterTargetsInput,
     ) -> Result<RegisterTargetsOutput, RusotoError<RegisterTargetsError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("RegisterTargets");
         let params = self.new_params("RegisterTargets");
         let mut params = params;
         RegisterTargetsInputSerializer::serialize(&mut params, "", &input);
@@ -10002,6 +10446,7 @@ impl Elb for ElbClient {
     ) -> Result<RemoveListenerCertificatesOutput, RusotoError<RemoveListenerCertificatesError>>
     {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("RemoveListenerCertificates");
         let params = self.new_params("RemoveListenerCertificates");
         let mut params = params;
         RemoveListenerCertificatesInputSerializer::serialize(&mut params, "", &input);
@@ -10024,6 +10469,7 @@ impl Elb for ElbClient {
         input: RemoveTagsInput,
     ) -> Result<RemoveTagsOutput, RusotoError<RemoveTagsError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("RemoveTags");
         let params = self.new_params("RemoveTags");
         let mut params = params;
         RemoveTagsInputSerializer::serialize(&mut params, "", &input);
@@ -10046,6 +10492,7 @@ impl Elb for ElbClient {
         input: SetIpAddressTypeInput,
     ) -> Result<SetIpAddressTypeOutput, RusotoError<SetIpAddressTypeError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("SetIpAddressType");
         let params = self.new_params("SetIpAddressType");
         let mut params = params;
         SetIpAddressTypeInputSerializer::serialize(&mut params, "", &input);
@@ -10077,6 +10524,7 @@ impl Elb for ElbClient {
         input: SetRulePrioritiesInput,
     ) -> Result<SetRulePrioritiesOutput, RusotoError<SetRulePrioritiesError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("SetRulePriorities");
         let params = self.new_params("SetRulePriorities");
         let mut params = params;
         SetRulePrioritiesInputSerializer::serialize(&mut params, "", &input);
@@ -10108,6 +10556,7 @@ impl Elb for ElbClient {
         input: SetSecurityGroupsInput,
     ) -> Result<SetSecurityGroupsOutput, RusotoError<SetSecurityGroupsError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("SetSecurityGroups");
         let params = self.new_params("SetSecurityGroups");
         let mut params = params;
         SetSecurityGroupsInputSerializer::serialize(&mut params, "", &input);
@@ -10139,6 +10588,7 @@ impl Elb for ElbClient {
         input: SetSubnetsInput,
     ) -> Result<SetSubnetsOutput, RusotoError<SetSubnetsError>> {
         let mut request = SignedRequest::new("POST", "elasticloadbalancing", &self.region, "/");
+        request.set_operation("SetSubnets");
         let params = self.new_params("SetSubnets");
         let mut params = params;
         SetSubnetsInputSerializer::serialize(&mut params, "", &input);
@@ -10164,6 +10614,381 @@ impl Elb for ElbClient {
     }
 }
 
+/// A blocking client for the Elastic Load Balancing v2 API, running the calls of a `ElbClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `ElbClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ElbBlockingClient {
+    client: ElbClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ElbBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> ElbBlockingClient {
+        ElbBlockingClient::from_client(ElbClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ElbBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ElbBlockingClient::from_client(ElbClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> ElbBlockingClient {
+        ElbBlockingClient::from_client(ElbClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> ElbBlockingClient {
+        ElbBlockingClient::from_client(ElbClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: ElbClient) -> ElbBlockingClient {
+        ElbBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> ElbBlockingClient {
+        ElbBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ElbBlockingClient {
+        ElbBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Elb::add_listener_certificates`].
+    pub fn add_listener_certificates(
+        &self,
+        input: AddListenerCertificatesInput,
+    ) -> Result<AddListenerCertificatesOutput, RusotoError<AddListenerCertificatesError>> {
+        self.runtime
+            .block_on(self.client.add_listener_certificates(input))
+    }
+
+    /// Blocking version of [`Elb::add_tags`].
+    pub fn add_tags(
+        &self,
+        input: AddTagsInput,
+    ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
+        self.runtime.block_on(self.client.add_tags(input))
+    }
+
+    /// Blocking version of [`Elb::create_listener`].
+    pub fn create_listener(
+        &self,
+        input: CreateListenerInput,
+    ) -> Result<CreateListenerOutput, RusotoError<CreateListenerError>> {
+        self.runtime.block_on(self.client.create_listener(input))
+    }
+
+    /// Blocking version of [`Elb::create_load_balancer`].
+    pub fn create_load_balancer(
+        &self,
+        input: CreateLoadBalancerInput,
+    ) -> Result<CreateLoadBalancerOutput, RusotoError<CreateLoadBalancerError>> {
+        self.runtime
+            .block_on(self.client.create_load_balancer(input))
+    }
+
+    /// Blocking version of [`Elb::create_rule`].
+    pub fn create_rule(
+        &self,
+        input: CreateRuleInput,
+    ) -> Result<CreateRuleOutput, RusotoError<CreateRuleError>> {
+        self.runtime.block_on(self.client.create_rule(input))
+    }
+
+    /// Blocking version of [`Elb::create_target_group`].
+    pub fn create_target_group(
+        &self,
+        input: CreateTargetGroupInput,
+    ) -> Result<CreateTargetGroupOutput, RusotoError<CreateTargetGroupError>> {
+        self.runtime
+            .block_on(self.client.create_target_group(input))
+    }
+
+    /// Blocking version of [`Elb::delete_listener`].
+    pub fn delete_listener(
+        &self,
+        input: DeleteListenerInput,
+    ) -> Result<DeleteListenerOutput, RusotoError<DeleteListenerError>> {
+        self.runtime.block_on(self.client.delete_listener(input))
+    }
+
+    /// Blocking version of [`Elb::delete_load_balancer`].
+    pub fn delete_load_balancer(
+        &self,
+        input: DeleteLoadBalancerInput,
+    ) -> Result<DeleteLoadBalancerOutput, RusotoError<DeleteLoadBalancerError>> {
+        self.runtime
+            .block_on(self.client.delete_load_balancer(input))
+    }
+
+    /// Blocking version of [`Elb::delete_rule`].
+    pub fn delete_rule(
+        &self,
+        input: DeleteRuleInput,
+    ) -> Result<DeleteRuleOutput, RusotoError<DeleteRuleError>> {
+        self.runtime.block_on(self.client.delete_rule(input))
+    }
+
+    /// Blocking version of [`Elb::delete_target_group`].
+    pub fn delete_target_group(
+        &self,
+        input: DeleteTargetGroupInput,
+    ) -> Result<DeleteTargetGroupOutput, RusotoError<DeleteTargetGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_target_group(input))
+    }
+
+    /// Blocking version of [`Elb::deregister_targets`].
+    pub fn deregister_targets(
+        &self,
+        input: DeregisterTargetsInput,
+    ) -> Result<DeregisterTargetsOutput, RusotoError<DeregisterTargetsError>> {
+        self.runtime.block_on(self.client.deregister_targets(input))
+    }
+
+    /// Blocking version of [`Elb::describe_account_limits`].
+    pub fn describe_account_limits(
+        &self,
+        input: DescribeAccountLimitsInput,
+    ) -> Result<DescribeAccountLimitsOutput, RusotoError<DescribeAccountLimitsError>> {
+        self.runtime
+            .block_on(self.client.describe_account_limits(input))
+    }
+
+    /// Blocking version of [`Elb::describe_listener_certificates`].
+    pub fn describe_listener_certificates(
+        &self,
+        input: DescribeListenerCertificatesInput,
+    ) -> Result<DescribeListenerCertificatesOutput, RusotoError<DescribeListenerCertificatesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_listener_certificates(input))
+    }
+
+    /// Blocking version of [`Elb::describe_listeners`].
+    pub fn describe_listeners(
+        &self,
+        input: DescribeListenersInput,
+    ) -> Result<DescribeListenersOutput, RusotoError<DescribeListenersError>> {
+        self.runtime.block_on(self.client.describe_listeners(input))
+    }
+
+    /// Blocking version of [`Elb::describe_load_balancer_attributes`].
+    pub fn describe_load_balancer_attributes(
+        &self,
+        input: DescribeLoadBalancerAttributesInput,
+    ) -> Result<
+        DescribeLoadBalancerAttributesOutput,
+        RusotoError<DescribeLoadBalancerAttributesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_load_balancer_attributes(input))
+    }
+
+    /// Blocking version of [`Elb::describe_load_balancers`].
+    pub fn describe_load_balancers(
+        &self,
+        input: DescribeLoadBalancersInput,
+    ) -> Result<DescribeLoadBalancersOutput, RusotoError<DescribeLoadBalancersError>> {
+        self.runtime
+            .block_on(self.client.describe_load_balancers(input))
+    }
+
+    /// Blocking version of [`Elb::describe_rules`].
+    pub fn describe_rules(
+        &self,
+        input: DescribeRulesInput,
+    ) -> Result<DescribeRulesOutput, RusotoError<DescribeRulesError>> {
+        self.runtime.block_on(self.client.describe_rules(input))
+    }
+
+    /// Blocking version of [`Elb::describe_ssl_policies`].
+    pub fn describe_ssl_policies(
+        &self,
+        input: DescribeSSLPoliciesInput,
+    ) -> Result<DescribeSSLPoliciesOutput, RusotoError<DescribeSSLPoliciesError>> {
+        self.runtime
+            .block_on(self.client.describe_ssl_policies(input))
+    }
+
+    /// Blocking version of [`Elb::describe_tags`].
+    pub fn describe_tags(
+        &self,
+        input: DescribeTagsInput,
+    ) -> Result<DescribeTagsOutput, RusotoError<DescribeTagsError>> {
+        self.runtime.block_on(self.client.describe_tags(input))
+    }
+
+    /// Blocking version of [`Elb::describe_target_group_attributes`].
+    pub fn describe_target_group_attributes(
+        &self,
+        input: DescribeTargetGroupAttributesInput,
+    ) -> Result<DescribeTargetGroupAttributesOutput, RusotoError<DescribeTargetGroupAttributesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_target_group_attributes(input))
+    }
+
+    /// Blocking version of [`Elb::describe_target_groups`].
+    pub fn describe_target_groups(
+        &self,
+        input: DescribeTargetGroupsInput,
+    ) -> Result<DescribeTargetGroupsOutput, RusotoError<DescribeTargetGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_target_groups(input))
+    }
+
+    /// Blocking version of [`Elb::describe_target_health`].
+    pub fn describe_target_health(
+        &self,
+        input: DescribeTargetHealthInput,
+    ) -> Result<DescribeTargetHealthOutput, RusotoError<DescribeTargetHealthError>> {
+        self.runtime
+            .block_on(self.client.describe_target_health(input))
+    }
+
+    /// Blocking version of [`Elb::modify_listener`].
+    pub fn modify_listener(
+        &self,
+        input: ModifyListenerInput,
+    ) -> Result<ModifyListenerOutput, RusotoError<ModifyListenerError>> {
+        self.runtime.block_on(self.client.modify_listener(input))
+    }
+
+    /// Blocking version of [`Elb::modify_load_balancer_attributes`].
+    pub fn modify_load_balancer_attributes(
+        &self,
+        input: ModifyLoadBalancerAttributesInput,
+    ) -> Result<ModifyLoadBalancerAttributesOutput, RusotoError<ModifyLoadBalancerAttributesError>>
+    {
+        self.runtime
+            .block_on(self.client.modify_load_balancer_attributes(input))
+    }
+
+    /// Blocking version of [`Elb::modify_rule`].
+    pub fn modify_rule(
+        &self,
+        input: ModifyRuleInput,
+    ) -> Result<ModifyRuleOutput, RusotoError<ModifyRuleError>> {
+        self.runtime.block_on(self.client.modify_rule(input))
+    }
+
+    /// Blocking version of [`Elb::modify_target_group`].
+    pub fn modify_target_group(
+        &self,
+        input: ModifyTargetGroupInput,
+    ) -> Result<ModifyTargetGroupOutput, RusotoError<ModifyTargetGroupError>> {
+        self.runtime
+            .block_on(self.client.modify_target_group(input))
+    }
+
+    /// Blocking version of [`Elb::modify_target_group_attributes`].
+    pub fn modify_target_group_attributes(
+        &self,
+        input: ModifyTargetGroupAttributesInput,
+    ) -> Result<ModifyTargetGroupAttributesOutput, RusotoError<ModifyTargetGroupAttributesError>>
+    {
+        self.runtime
+            .block_on(self.client.modify_target_group_attributes(input))
+    }
+
+    /// Blocking version of [`Elb::register_targets`].
+    pub fn register_targets(
+        &self,
+        input: RegisterTargetsInput,
+    ) -> Result<RegisterTargetsOutput, RusotoError<RegisterTargetsError>> {
+        self.runtime.block_on(self.client.register_targets(input))
+    }
+
+    /// Blocking version of [`Elb::remove_listener_certificates`].
+    pub fn remove_listener_certificates(
+        &self,
+        input: RemoveListenerCertificatesInput,
+    ) -> Result<RemoveListenerCertificatesOutput, RusotoError<RemoveListenerCertificatesError>>
+    {
+        self.runtime
+            .block_on(self.client.remove_listener_certificates(input))
+    }
+
+    /// Blocking version of [`Elb::remove_tags`].
+    pub fn remove_tags(
+        &self,
+        input: RemoveTagsInput,
+    ) -> Result<RemoveTagsOutput, RusotoError<RemoveTagsError>> {
+        self.runtime.block_on(self.client.remove_tags(input))
+    }
+
+    /// Blocking version of [`Elb::set_ip_address_type`].
+    pub fn set_ip_address_type(
+        &self,
+        input: SetIpAddressTypeInput,
+    ) -> Result<SetIpAddressTypeOutput, RusotoError<SetIpAddressTypeError>> {
+        self.runtime
+            .block_on(self.client.set_ip_address_type(input))
+    }
+
+    /// Blocking version of [`Elb::set_rule_priorities`].
+    pub fn set_rule_priorities(
+        &self,
+        input: SetRulePrioritiesInput,
+    ) -> Result<SetRulePrioritiesOutput, RusotoError<SetRulePrioritiesError>> {
+        self.runtime
+            .block_on(self.client.set_rule_priorities(input))
+    }
+
+    /// Blocking version of [`Elb::set_security_groups`].
+    pub fn set_security_groups(
+        &self,
+        input: SetSecurityGroupsInput,
+    ) -> Result<SetSecurityGroupsOutput, RusotoError<SetSecurityGroupsError>> {
+        self.runtime
+            .block_on(self.client.set_security_groups(input))
+    }
+
+    /// Blocking version of [`Elb::set_subnets`].
+    pub fn set_subnets(
+        &self,
+        input: SetSubnetsInput,
+    ) -> Result<SetSubnetsOutput, RusotoError<SetSubnetsError>> {
+        self.runtime.block_on(self.client.set_subnets(input))
+    }
+}
+
 #[cfg(test)]
 mod protocol_tests {
 
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -3306,12 +3307,22 @@ impl AddInstanceFleetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(AddInstanceFleetError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddInstanceFleetError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AddInstanceFleetError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddInstanceFleetError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3340,11 +3351,14 @@ impl AddInstanceGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(AddInstanceGroupsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddInstanceGroupsError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3372,9 +3386,14 @@ impl AddJobFlowStepsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(AddJobFlowStepsError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddJobFlowStepsError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3404,12 +3423,22 @@ impl AddTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(AddTagsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AddTagsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3440,12 +3469,22 @@ impl CancelStepsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(CancelStepsError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelStepsError::InternalServerError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CancelStepsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelStepsError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3478,16 +3517,22 @@ impl CreateSecurityConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateSecurityConfigurationError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSecurityConfigurationError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateSecurityConfigurationError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSecurityConfigurationError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3518,12 +3563,22 @@ impl CreateStudioError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateStudioError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStudioError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateStudioError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStudioError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3556,16 +3611,22 @@ impl CreateStudioSessionMappingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        CreateStudioSessionMappingError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStudioSessionMappingError::InternalServerError,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateStudioSessionMappingError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateStudioSessionMappingError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3600,16 +3661,22 @@ impl DeleteSecurityConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteSecurityConfigurationError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSecurityConfigurationError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteSecurityConfigurationError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSecurityConfigurationError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3640,12 +3707,22 @@ impl DeleteStudioError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteStudioError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteStudioError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteStudioError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteStudioError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3678,16 +3755,22 @@ impl DeleteStudioSessionMappingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DeleteStudioSessionMappingError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteStudioSessionMappingError::InternalServerError,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteStudioSessionMappingError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteStudioSessionMappingError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3720,12 +3803,22 @@ impl DescribeClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeClusterError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeClusterError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeClusterError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeClusterError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3754,11 +3847,14 @@ impl DescribeJobFlowsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeJobFlowsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeJobFlowsError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3788,16 +3884,22 @@ impl DescribeNotebookExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeNotebookExecutionError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeNotebookExecutionError::InternalServerError,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeNotebookExecutionError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeNotebookExecutionError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3832,16 +3934,22 @@ impl DescribeSecurityConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
-                        DescribeSecurityConfigurationError::InternalServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSecurityConfigurationError::InternalServer,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        DescribeSecurityConfigurationError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSecurityConfigurationError::InvalidRequest,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3872,12 +3980,22 @@ impl DescribeStepError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeStepError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeStepError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeStepError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeStepError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3908,12 +4026,22 @@ impl DescribeStudioError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeStudioError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeStudioError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeStudioError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeStudioError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3946,16 +4074,22 @@ impl GetBlockPublicAccessConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
-                        GetBlockPublicAccessConfigurationError::InternalServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBlockPublicAccessConfigurationError::InternalServer,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        GetBlockPublicAccessConfigurationError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBlockPublicAccessConfigurationError::InvalidRequest,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3984,7 +4118,7 @@ impl GetManagedScalingPolicyError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetManagedScalingPolicyError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4012,16 +4146,22 @@ impl GetStudioSessionMappingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(GetStudioSessionMappingError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetStudioSessionMappingError::InternalServerError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetStudioSessionMappingError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetStudioSessionMappingError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4052,12 +4192,22 @@ impl ListBootstrapActionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListBootstrapActionsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBootstrapActionsError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListBootstrapActionsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBootstrapActionsError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4088,12 +4238,22 @@ impl ListClustersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListClustersError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListClustersError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListClustersError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListClustersError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4124,12 +4284,22 @@ impl ListInstanceFleetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListInstanceFleetsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInstanceFleetsError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListInstanceFleetsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInstanceFleetsError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4160,12 +4330,22 @@ impl ListInstanceGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListInstanceGroupsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInstanceGroupsError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListInstanceGroupsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInstanceGroupsError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4196,12 +4376,22 @@ impl ListInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListInstancesError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInstancesError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListInstancesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInstancesError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4232,16 +4422,22 @@ impl ListNotebookExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListNotebookExecutionsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListNotebookExecutionsError::InternalServerError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListNotebookExecutionsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListNotebookExecutionsError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4274,16 +4470,22 @@ impl ListSecurityConfigurationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListSecurityConfigurationsError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSecurityConfigurationsError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListSecurityConfigurationsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSecurityConfigurationsError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4314,12 +4516,22 @@ impl ListStepsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListStepsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListStepsError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListStepsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListStepsError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4350,16 +4562,22 @@ impl ListStudioSessionMappingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        ListStudioSessionMappingsError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListStudioSessionMappingsError::InternalServerError,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListStudioSessionMappingsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListStudioSessionMappingsError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4392,12 +4610,22 @@ impl ListStudiosError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListStudiosError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListStudiosError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListStudiosError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListStudiosError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4428,12 +4656,22 @@ impl ModifyClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ModifyClusterError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyClusterError::InternalServerError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ModifyClusterError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyClusterError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4464,12 +4702,22 @@ impl ModifyInstanceFleetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ModifyInstanceFleetError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyInstanceFleetError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ModifyInstanceFleetError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ModifyInstanceFleetError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4498,11 +4746,14 @@ impl ModifyInstanceGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ModifyInstanceGroupsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ModifyInstanceGroupsError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4526,7 +4777,7 @@ impl PutAutoScalingPolicyError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutAutoScalingPolicyError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4556,16 +4807,22 @@ impl PutBlockPublicAccessConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
-                        PutBlockPublicAccessConfigurationError::InternalServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutBlockPublicAccessConfigurationError::InternalServer,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        PutBlockPublicAccessConfigurationError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutBlockPublicAccessConfigurationError::InvalidRequest,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4594,7 +4851,7 @@ impl PutManagedScalingPolicyError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<PutManagedScalingPolicyError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4616,7 +4873,7 @@ impl RemoveAutoScalingPolicyError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<RemoveAutoScalingPolicyError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4640,7 +4897,7 @@ impl RemoveManagedScalingPolicyError {
     ) -> RusotoError<RemoveManagedScalingPolicyError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4668,12 +4925,22 @@ impl RemoveTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(RemoveTagsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveTagsError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RemoveTagsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveTagsError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4702,9 +4969,14 @@ impl RunJobFlowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(RunJobFlowError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RunJobFlowError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4732,11 +5004,14 @@ impl SetTerminationProtectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        SetTerminationProtectionError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetTerminationProtectionError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4764,11 +5039,14 @@ impl SetVisibleToAllUsersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(SetVisibleToAllUsersError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetVisibleToAllUsersError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4798,16 +5076,22 @@ impl StartNotebookExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StartNotebookExecutionError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartNotebookExecutionError::InternalServer,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartNotebookExecutionError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartNotebookExecutionError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4838,16 +5122,22 @@ impl StopNotebookExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(StopNotebookExecutionError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopNotebookExecutionError::InternalServerError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopNotebookExecutionError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopNotebookExecutionError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4876,11 +5166,14 @@ impl TerminateJobFlowsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(TerminateJobFlowsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TerminateJobFlowsError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4912,16 +5205,22 @@ impl UpdateStudioSessionMappingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        UpdateStudioSessionMappingError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateStudioSessionMappingError::InternalServerError,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateStudioSessionMappingError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateStudioSessionMappingError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5234,11 +5533,12 @@ pub struct EmrClient {
 impl EmrClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> EmrClient {
         EmrClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "EMR"),
         }
     }
 
@@ -5253,12 +5553,32 @@ impl EmrClient {
     {
         EmrClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "EMR"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> EmrClient {
-        EmrClient { client, region }
+        EmrClient {
+            client,
+            region: region_for_service(region, "EMR"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> EmrClient {
+        EmrClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("EMR"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> EmrClient {
+        EmrClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -5270,6 +5590,7 @@ impl Emr for EmrClient {
         input: AddInstanceFleetInput,
     ) -> Result<AddInstanceFleetOutput, RusotoError<AddInstanceFleetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AddInstanceFleet");
         request.add_header("x-amz-target", "ElasticMapReduce.AddInstanceFleet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5288,6 +5609,7 @@ impl Emr for EmrClient {
         input: AddInstanceGroupsInput,
     ) -> Result<AddInstanceGroupsOutput, RusotoError<AddInstanceGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AddInstanceGroups");
         request.add_header("x-amz-target", "ElasticMapReduce.AddInstanceGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5306,6 +5628,7 @@ impl Emr for EmrClient {
         input: AddJobFlowStepsInput,
     ) -> Result<AddJobFlowStepsOutput, RusotoError<AddJobFlowStepsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AddJobFlowSteps");
         request.add_header("x-amz-target", "ElasticMapReduce.AddJobFlowSteps");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5324,6 +5647,7 @@ impl Emr for EmrClient {
         input: AddTagsInput,
     ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AddTags");
         request.add_header("x-amz-target", "ElasticMapReduce.AddTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5342,6 +5666,7 @@ impl Emr for EmrClient {
         input: CancelStepsInput,
     ) -> Result<CancelStepsOutput, RusotoError<CancelStepsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CancelSteps");
         request.add_header("x-amz-target", "ElasticMapReduce.CancelSteps");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5361,6 +5686,7 @@ impl Emr for EmrClient {
     ) -> Result<CreateSecurityConfigurationOutput, RusotoError<CreateSecurityConfigurationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateSecurityConfiguration");
         request.add_header(
             "x-amz-target",
             "ElasticMapReduce.CreateSecurityConfiguration",
@@ -5383,6 +5709,7 @@ impl Emr for EmrClient {
         input: CreateStudioInput,
     ) -> Result<CreateStudioOutput, RusotoError<CreateStudioError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateStudio");
         request.add_header("x-amz-target", "ElasticMapReduce.CreateStudio");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5401,6 +5728,7 @@ impl Emr for EmrClient {
         input: CreateStudioSessionMappingInput,
     ) -> Result<(), RusotoError<CreateStudioSessionMappingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateStudioSessionMapping");
         request.add_header(
             "x-amz-target",
             "ElasticMapReduce.CreateStudioSessionMapping",
@@ -5422,6 +5750,7 @@ impl Emr for EmrClient {
     ) -> Result<DeleteSecurityConfigurationOutput, RusotoError<DeleteSecurityConfigurationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteSecurityConfiguration");
         request.add_header(
             "x-amz-target",
             "ElasticMapReduce.DeleteSecurityConfiguration",
@@ -5444,6 +5773,7 @@ impl Emr for EmrClient {
         input: DeleteStudioInput,
     ) -> Result<(), RusotoError<DeleteStudioError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteStudio");
         request.add_header("x-amz-target", "ElasticMapReduce.DeleteStudio");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5461,6 +5791,7 @@ impl Emr for EmrClient {
         input: DeleteStudioSessionMappingInput,
     ) -> Result<(), RusotoError<DeleteStudioSessionMappingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteStudioSessionMapping");
         request.add_header(
             "x-amz-target",
             "ElasticMapReduce.DeleteStudioSessionMapping",
@@ -5481,6 +5812,7 @@ impl Emr for EmrClient {
         input: DescribeClusterInput,
     ) -> Result<DescribeClusterOutput, RusotoError<DescribeClusterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeCluster");
         request.add_header("x-amz-target", "ElasticMapReduce.DescribeCluster");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5499,6 +5831,7 @@ impl Emr for EmrClient {
         input: DescribeJobFlowsInput,
     ) -> Result<DescribeJobFlowsOutput, RusotoError<DescribeJobFlowsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeJobFlows");
         request.add_header("x-amz-target", "ElasticMapReduce.DescribeJobFlows");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5517,6 +5850,7 @@ impl Emr for EmrClient {
         input: DescribeNotebookExecutionInput,
     ) -> Result<DescribeNotebookExecutionOutput, RusotoError<DescribeNotebookExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeNotebookExecution");
         request.add_header("x-amz-target", "ElasticMapReduce.DescribeNotebookExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5537,6 +5871,7 @@ impl Emr for EmrClient {
     ) -> Result<DescribeSecurityConfigurationOutput, RusotoError<DescribeSecurityConfigurationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeSecurityConfiguration");
         request.add_header(
             "x-amz-target",
             "ElasticMapReduce.DescribeSecurityConfiguration",
@@ -5559,6 +5894,7 @@ impl Emr for EmrClient {
         input: DescribeStepInput,
     ) -> Result<DescribeStepOutput, RusotoError<DescribeStepError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeStep");
         request.add_header("x-amz-target", "ElasticMapReduce.DescribeStep");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5577,6 +5913,7 @@ impl Emr for EmrClient {
         input: DescribeStudioInput,
     ) -> Result<DescribeStudioOutput, RusotoError<DescribeStudioError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeStudio");
         request.add_header("x-amz-target", "ElasticMapReduce.DescribeStudio");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5597,6 +5934,7 @@ impl Emr for EmrClient {
         RusotoError<GetBlockPublicAccessConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetBlockPublicAccessConfiguration");
         request.add_header(
             "x-amz-target",
             "ElasticMapReduce.GetBlockPublicAccessConfiguration",
@@ -5621,6 +5959,7 @@ impl Emr for EmrClient {
         input: GetManagedScalingPolicyInput,
     ) -> Result<GetManagedScalingPolicyOutput, RusotoError<GetManagedScalingPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetManagedScalingPolicy");
         request.add_header("x-amz-target", "ElasticMapReduce.GetManagedScalingPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5640,6 +5979,7 @@ impl Emr for EmrClient {
         input: GetStudioSessionMappingInput,
     ) -> Result<GetStudioSessionMappingOutput, RusotoError<GetStudioSessionMappingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetStudioSessionMapping");
         request.add_header("x-amz-target", "ElasticMapReduce.GetStudioSessionMapping");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5659,6 +5999,7 @@ impl Emr for EmrClient {
         input: ListBootstrapActionsInput,
     ) -> Result<ListBootstrapActionsOutput, RusotoError<ListBootstrapActionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListBootstrapActions");
         request.add_header("x-amz-target", "ElasticMapReduce.ListBootstrapActions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5677,6 +6018,7 @@ impl Emr for EmrClient {
         input: ListClustersInput,
     ) -> Result<ListClustersOutput, RusotoError<ListClustersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListClusters");
         request.add_header("x-amz-target", "ElasticMapReduce.ListClusters");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5695,6 +6037,7 @@ impl Emr for EmrClient {
         input: ListInstanceFleetsInput,
     ) -> Result<ListInstanceFleetsOutput, RusotoError<ListInstanceFleetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListInstanceFleets");
         request.add_header("x-amz-target", "ElasticMapReduce.ListInstanceFleets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5713,6 +6056,7 @@ impl Emr for EmrClient {
         input: ListInstanceGroupsInput,
     ) -> Result<ListInstanceGroupsOutput, RusotoError<ListInstanceGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListInstanceGroups");
         request.add_header("x-amz-target", "ElasticMapReduce.ListInstanceGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5731,6 +6075,7 @@ impl Emr for EmrClient {
         input: ListInstancesInput,
     ) -> Result<ListInstancesOutput, RusotoError<ListInstancesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListInstances");
         request.add_header("x-amz-target", "ElasticMapReduce.ListInstances");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5749,6 +6094,7 @@ impl Emr for EmrClient {
         input: ListNotebookExecutionsInput,
     ) -> Result<ListNotebookExecutionsOutput, RusotoError<ListNotebookExecutionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListNotebookExecutions");
         request.add_header("x-amz-target", "ElasticMapReduce.ListNotebookExecutions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5769,6 +6115,7 @@ impl Emr for EmrClient {
     ) -> Result<ListSecurityConfigurationsOutput, RusotoError<ListSecurityConfigurationsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListSecurityConfigurations");
         request.add_header(
             "x-amz-target",
             "ElasticMapReduce.ListSecurityConfigurations",
@@ -5791,6 +6138,7 @@ impl Emr for EmrClient {
         input: ListStepsInput,
     ) -> Result<ListStepsOutput, RusotoError<ListStepsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListSteps");
         request.add_header("x-amz-target", "ElasticMapReduce.ListSteps");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5809,6 +6157,7 @@ impl Emr for EmrClient {
         input: ListStudioSessionMappingsInput,
     ) -> Result<ListStudioSessionMappingsOutput, RusotoError<ListStudioSessionMappingsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListStudioSessionMappings");
         request.add_header("x-amz-target", "ElasticMapReduce.ListStudioSessionMappings");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5828,6 +6177,7 @@ impl Emr for EmrClient {
         input: ListStudiosInput,
     ) -> Result<ListStudiosOutput, RusotoError<ListStudiosError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListStudios");
         request.add_header("x-amz-target", "ElasticMapReduce.ListStudios");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5846,6 +6196,7 @@ impl Emr for EmrClient {
         input: ModifyClusterInput,
     ) -> Result<ModifyClusterOutput, RusotoError<ModifyClusterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ModifyCluster");
         request.add_header("x-amz-target", "ElasticMapReduce.ModifyCluster");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5864,6 +6215,7 @@ impl Emr for EmrClient {
         input: ModifyInstanceFleetInput,
     ) -> Result<(), RusotoError<ModifyInstanceFleetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ModifyInstanceFleet");
         request.add_header("x-amz-target", "ElasticMapReduce.ModifyInstanceFleet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5881,6 +6233,7 @@ impl Emr for EmrClient {
         input: ModifyInstanceGroupsInput,
     ) -> Result<(), RusotoError<ModifyInstanceGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ModifyInstanceGroups");
         request.add_header("x-amz-target", "ElasticMapReduce.ModifyInstanceGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5898,6 +6251,7 @@ impl Emr for EmrClient {
         input: PutAutoScalingPolicyInput,
     ) -> Result<PutAutoScalingPolicyOutput, RusotoError<PutAutoScalingPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutAutoScalingPolicy");
         request.add_header("x-amz-target", "ElasticMapReduce.PutAutoScalingPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5919,6 +6273,7 @@ impl Emr for EmrClient {
         RusotoError<PutBlockPublicAccessConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutBlockPublicAccessConfiguration");
         request.add_header(
             "x-amz-target",
             "ElasticMapReduce.PutBlockPublicAccessConfiguration",
@@ -5944,6 +6299,7 @@ impl Emr for EmrClient {
         input: PutManagedScalingPolicyInput,
     ) -> Result<PutManagedScalingPolicyOutput, RusotoError<PutManagedScalingPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutManagedScalingPolicy");
         request.add_header("x-amz-target", "ElasticMapReduce.PutManagedScalingPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5963,6 +6319,7 @@ impl Emr for EmrClient {
         input: RemoveAutoScalingPolicyInput,
     ) -> Result<RemoveAutoScalingPolicyOutput, RusotoError<RemoveAutoScalingPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RemoveAutoScalingPolicy");
         request.add_header("x-amz-target", "ElasticMapReduce.RemoveAutoScalingPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5983,6 +6340,7 @@ impl Emr for EmrClient {
     ) -> Result<RemoveManagedScalingPolicyOutput, RusotoError<RemoveManagedScalingPolicyError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RemoveManagedScalingPolicy");
         request.add_header(
             "x-amz-target",
             "ElasticMapReduce.RemoveManagedScalingPolicy",
@@ -6005,6 +6363,7 @@ impl Emr for EmrClient {
         input: RemoveTagsInput,
     ) -> Result<RemoveTagsOutput, RusotoError<RemoveTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RemoveTags");
         request.add_header("x-amz-target", "ElasticMapReduce.RemoveTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6023,6 +6382,7 @@ impl Emr for EmrClient {
         input: RunJobFlowInput,
     ) -> Result<RunJobFlowOutput, RusotoError<RunJobFlowError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RunJobFlow");
         request.add_header("x-amz-target", "ElasticMapReduce.RunJobFlow");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6041,6 +6401,7 @@ impl Emr for EmrClient {
         input: SetTerminationProtectionInput,
     ) -> Result<(), RusotoError<SetTerminationProtectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SetTerminationProtection");
         request.add_header("x-amz-target", "ElasticMapReduce.SetTerminationProtection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6058,6 +6419,7 @@ impl Emr for EmrClient {
         input: SetVisibleToAllUsersInput,
     ) -> Result<(), RusotoError<SetVisibleToAllUsersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SetVisibleToAllUsers");
         request.add_header("x-amz-target", "ElasticMapReduce.SetVisibleToAllUsers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6075,6 +6437,7 @@ impl Emr for EmrClient {
         input: StartNotebookExecutionInput,
     ) -> Result<StartNotebookExecutionOutput, RusotoError<StartNotebookExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartNotebookExecution");
         request.add_header("x-amz-target", "ElasticMapReduce.StartNotebookExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6094,6 +6457,7 @@ impl Emr for EmrClient {
         input: StopNotebookExecutionInput,
     ) -> Result<(), RusotoError<StopNotebookExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopNotebookExecution");
         request.add_header("x-amz-target", "ElasticMapReduce.StopNotebookExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6111,6 +6475,7 @@ impl Emr for EmrClient {
         input: TerminateJobFlowsInput,
     ) -> Result<(), RusotoError<TerminateJobFlowsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TerminateJobFlows");
         request.add_header("x-amz-target", "ElasticMapReduce.TerminateJobFlows");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6128,6 +6493,7 @@ impl Emr for EmrClient {
         input: UpdateStudioSessionMappingInput,
     ) -> Result<(), RusotoError<UpdateStudioSessionMappingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateStudioSessionMapping");
         request.add_header(
             "x-amz-target",
             "ElasticMapReduce.UpdateStudioSessionMapping",
@@ -6142,3 +6508,485 @@ impl Emr for EmrClient {
         Ok(())
     }
 }
+
+/// A blocking client for the Amazon EMR API, running the calls of a `EmrClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `EmrClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct EmrBlockingClient {
+    client: EmrClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl EmrBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> EmrBlockingClient {
+        EmrBlockingClient::from_client(EmrClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> EmrBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        EmrBlockingClient::from_client(EmrClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> EmrBlockingClient {
+        EmrBlockingClient::from_client(EmrClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> EmrBlockingClient {
+        EmrBlockingClient::from_client(EmrClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: EmrClient) -> EmrBlockingClient {
+        EmrBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> EmrBlockingClient {
+        EmrBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> EmrBlockingClient {
+        EmrBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Emr::add_instance_fleet`].
+    pub fn add_instance_fleet(
+        &self,
+        input: AddInstanceFleetInput,
+    ) -> Result<AddInstanceFleetOutput, RusotoError<AddInstanceFleetError>> {
+        self.runtime.block_on(self.client.add_instance_fleet(input))
+    }
+
+    /// Blocking version of [`Emr::add_instance_groups`].
+    pub fn add_instance_groups(
+        &self,
+        input: AddInstanceGroupsInput,
+    ) -> Result<AddInstanceGroupsOutput, RusotoError<AddInstanceGroupsError>> {
+        self.runtime
+            .block_on(self.client.add_instance_groups(input))
+    }
+
+    /// Blocking version of [`Emr::add_job_flow_steps`].
+    pub fn add_job_flow_steps(
+        &self,
+        input: AddJobFlowStepsInput,
+    ) -> Result<AddJobFlowStepsOutput, RusotoError<AddJobFlowStepsError>> {
+        self.runtime.block_on(self.client.add_job_flow_steps(input))
+    }
+
+    /// Blocking version of [`Emr::add_tags`].
+    pub fn add_tags(
+        &self,
+        input: AddTagsInput,
+    ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
+        self.runtime.block_on(self.client.add_tags(input))
+    }
+
+    /// Blocking version of [`Emr::cancel_steps`].
+    pub fn cancel_steps(
+        &self,
+        input: CancelStepsInput,
+    ) -> Result<CancelStepsOutput, RusotoError<CancelStepsError>> {
+        self.runtime.block_on(self.client.cancel_steps(input))
+    }
+
+    /// Blocking version of [`Emr::create_security_configuration`].
+    pub fn create_security_configuration(
+        &self,
+        input: CreateSecurityConfigurationInput,
+    ) -> Result<CreateSecurityConfigurationOutput, RusotoError<CreateSecurityConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.create_security_configuration(input))
+    }
+
+    /// Blocking version of [`Emr::create_studio`].
+    pub fn create_studio(
+        &self,
+        input: CreateStudioInput,
+    ) -> Result<CreateStudioOutput, RusotoError<CreateStudioError>> {
+        self.runtime.block_on(self.client.create_studio(input))
+    }
+
+    /// Blocking version of [`Emr::create_studio_session_mapping`].
+    pub fn create_studio_session_mapping(
+        &self,
+        input: CreateStudioSessionMappingInput,
+    ) -> Result<(), RusotoError<CreateStudioSessionMappingError>> {
+        self.runtime
+            .block_on(self.client.create_studio_session_mapping(input))
+    }
+
+    /// Blocking version of [`Emr::delete_security_configuration`].
+    pub fn delete_security_configuration(
+        &self,
+        input: DeleteSecurityConfigurationInput,
+    ) -> Result<DeleteSecurityConfigurationOutput, RusotoError<DeleteSecurityConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_security_configuration(input))
+    }
+
+    /// Blocking version of [`Emr::delete_studio`].
+    pub fn delete_studio(
+        &self,
+        input: DeleteStudioInput,
+    ) -> Result<(), RusotoError<DeleteStudioError>> {
+        self.runtime.block_on(self.client.delete_studio(input))
+    }
+
+    /// Blocking version of [`Emr::delete_studio_session_mapping`].
+    pub fn delete_studio_session_mapping(
+        &self,
+        input: DeleteStudioSessionMappingInput,
+    ) -> Result<(), RusotoError<DeleteStudioSessionMappingError>> {
+        self.runtime
+            .block_on(self.client.delete_studio_session_mapping(input))
+    }
+
+    /// Blocking version of [`Emr::describe_cluster`].
+    pub fn describe_cluster(
+        &self,
+        input: DescribeClusterInput,
+    ) -> Result<DescribeClusterOutput, RusotoError<DescribeClusterError>> {
+        self.runtime.block_on(self.client.describe_cluster(input))
+    }
+
+    /// Blocking version of [`Emr::describe_job_flows`].
+    pub fn describe_job_flows(
+        &self,
+        input: DescribeJobFlowsInput,
+    ) -> Result<DescribeJobFlowsOutput, RusotoError<DescribeJobFlowsError>> {
+        self.runtime.block_on(self.client.describe_job_flows(input))
+    }
+
+    /// Blocking version of [`Emr::describe_notebook_execution`].
+    pub fn describe_notebook_execution(
+        &self,
+        input: DescribeNotebookExecutionInput,
+    ) -> Result<DescribeNotebookExecutionOutput, RusotoError<DescribeNotebookExecutionError>> {
+        self.runtime
+            .block_on(self.client.describe_notebook_execution(input))
+    }
+
+    /// Blocking version of [`Emr::describe_security_configuration`].
+    pub fn describe_security_configuration(
+        &self,
+        input: DescribeSecurityConfigurationInput,
+    ) -> Result<DescribeSecurityConfigurationOutput, RusotoError<DescribeSecurityConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_security_configuration(input))
+    }
+
+    /// Blocking version of [`Emr::describe_step`].
+    pub fn describe_step(
+        &self,
+        input: DescribeStepInput,
+    ) -> Result<DescribeStepOutput, RusotoError<DescribeStepError>> {
+        self.runtime.block_on(self.client.describe_step(input))
+    }
+
+    /// Blocking version of [`Emr::describe_studio`].
+    pub fn describe_studio(
+        &self,
+        input: DescribeStudioInput,
+    ) -> Result<DescribeStudioOutput, RusotoError<DescribeStudioError>> {
+        self.runtime.block_on(self.client.describe_studio(input))
+    }
+
+    /// Blocking version of [`Emr::get_block_public_access_configuration`].
+    pub fn get_block_public_access_configuration(
+        &self,
+    ) -> Result<
+        GetBlockPublicAccessConfigurationOutput,
+        RusotoError<GetBlockPublicAccessConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_block_public_access_configuration())
+    }
+
+    /// Blocking version of [`Emr::get_managed_scaling_policy`].
+    pub fn get_managed_scaling_policy(
+        &self,
+        input: GetManagedScalingPolicyInput,
+    ) -> Result<GetManagedScalingPolicyOutput, RusotoError<GetManagedScalingPolicyError>> {
+        self.runtime
+            .block_on(self.client.get_managed_scaling_policy(input))
+    }
+
+    /// Blocking version of [`Emr::get_studio_session_mapping`].
+    pub fn get_studio_session_mapping(
+        &self,
+        input: GetStudioSessionMappingInput,
+    ) -> Result<GetStudioSessionMappingOutput, RusotoError<GetStudioSessionMappingError>> {
+        self.runtime
+            .block_on(self.client.get_studio_session_mapping(input))
+    }
+
+    /// Blocking version of [`Emr::list_bootstrap_actions`].
+    pub fn list_bootstrap_actions(
+        &self,
+        input: ListBootstrapActionsInput,
+    ) -> Result<ListBootstrapActionsOutput, RusotoError<ListBootstrapActionsError>> {
+        self.runtime
+            .block_on(self.client.list_bootstrap_actions(input))
+    }
+
+    /// Blocking version of [`Emr::list_clusters`].
+    pub fn list_clusters(
+        &self,
+        input: ListClustersInput,
+    ) -> Result<ListClustersOutput, RusotoError<ListClustersError>> {
+        self.runtime.block_on(self.client.list_clusters(input))
+    }
+
+    /// Blocking version of [`Emr::list_instance_fleets`].
+    pub fn list_instance_fleets(
+        &self,
+        input: ListInstanceFleetsInput,
+    ) -> Result<ListInstanceFleetsOutput, RusotoError<ListInstanceFleetsError>> {
+        self.runtime
+            .block_on(self.client.list_instance_fleets(input))
+    }
+
+    /// Blocking version of [`Emr::list_instance_groups`].
+    pub fn list_instance_groups(
+        &self,
+        input: ListInstanceGroupsInput,
+    ) -> Result<ListInstanceGroupsOutput, RusotoError<ListInstanceGroupsError>> {
+        self.runtime
+            .block_on(self.client.list_instance_groups(input))
+    }
+
+    /// Blocking version of [`Emr::list_instances`].
+    pub fn list_instances(
+        &self,
+        input: ListInstancesInput,
+    ) -> Result<ListInstancesOutput, RusotoError<ListInstancesError>> {
+        self.runtime.block_on(self.client.list_instances(input))
+    }
+
+    /// Blocking version of [`Emr::list_notebook_executions`].
+    pub fn list_notebook_executions(
+        &self,
+        input: ListNotebookExecutionsInput,
+    ) -> Result<ListNotebookExecutionsOutput, RusotoError<ListNotebookExecutionsError>> {
+        self.runtime
+            .block_on(self.client.list_notebook_executions(input))
+    }
+
+    /// Blocking version of [`Emr::list_security_configurations`].
+    pub fn list_security_configurations(
+        &self,
+        input: ListSecurityConfigurationsInput,
+    ) -> Result<ListSecurityConfigurationsOutput, RusotoError<ListSecurityConfigurationsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_security_configurations(input))
+    }
+
+    /// Blocking version of [`Emr::list_steps`].
+    pub fn list_steps(
+        &self,
+        input: ListStepsInput,
+    ) -> Result<ListStepsOutput, RusotoError<ListStepsError>> {
+        self.runtime.block_on(self.client.list_steps(input))
+    }
+
+    /// Blocking version of [`Emr::list_studio_session_mappings`].
+    pub fn list_studio_session_mappings(
+        &self,
+        input: ListStudioSessionMappingsInput,
+    ) -> Result<ListStudioSessionMappingsOutput, RusotoError<ListStudioSessionMappingsError>> {
+        self.runtime
+            .block_on(self.client.list_studio_session_mappings(input))
+    }
+
+    /// Blocking version of [`Emr::list_studios`].
+    pub fn list_studios(
+        &self,
+        input: ListStudiosInput,
+    ) -> Result<ListStudiosOutput, RusotoError<ListStudiosError>> {
+        self.runtime.block_on(self.client.list_studios(input))
+    }
+
+    /// Blocking version of [`Emr::modify_cluster`].
+    pub fn modify_cluster(
+        &self,
+        input: ModifyClusterInput,
+    ) -> Result<ModifyClusterOutput, RusotoError<ModifyClusterError>> {
+        self.runtime.block_on(self.client.modify_cluster(input))
+    }
+
+    /// Blocking version of [`Emr::modify_instance_fleet`].
+    pub fn modify_instance_fleet(
+        &self,
+        input: ModifyInstanceFleetInput,
+    ) -> Result<(), RusotoError<ModifyInstanceFleetError>> {
+        self.runtime
+            .block_on(self.client.modify_instance_fleet(input))
+    }
+
+    /// Blocking version of [`Emr::modify_instance_groups`].
+    pub fn modify_instance_groups(
+        &self,
+        input: ModifyInstanceGroupsInput,
+    ) -> Result<(), RusotoError<ModifyInstanceGroupsError>> {
+        self.runtime
+            .block_on(self.client.modify_instance_groups(input))
+    }
+
+    /// Blocking version of [`Emr::put_auto_scaling_policy`].
+    pub fn put_auto_scaling_policy(
+        &self,
+        input: PutAutoScalingPolicyInput,
+    ) -> Result<PutAutoScalingPolicyOutput, RusotoError<PutAutoScalingPolicyError>> {
+        self.runtime
+            .block_on(self.client.put_auto_scaling_policy(input))
+    }
+
+    /// Blocking version of [`Emr::put_block_public_access_configuration`].
+    pub fn put_block_public_access_configuration(
+        &self,
+        input: PutBlockPublicAccessConfigurationInput,
+    ) -> Result<
+        PutBlockPublicAccessConfigurationOutput,
+        RusotoError<PutBlockPublicAccessConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.put_block_public_access_configuration(input))
+    }
+
+    /// Blocking version of [`Emr::put_managed_scaling_policy`].
+    pub fn put_managed_scaling_policy(
+        &self,
+        input: PutManagedScalingPolicyInput,
+    ) -> Result<PutManagedScalingPolicyOutput, RusotoError<PutManagedScalingPolicyError>> {
+        self.runtime
+            .block_on(self.client.put_managed_scaling_policy(input))
+    }
+
+    /// Blocking version of [`Emr::remove_auto_scaling_policy`].
+    pub fn remove_auto_scaling_policy(
+        &self,
+        input: RemoveAutoScalingPolicyInput,
+    ) -> Result<RemoveAutoScalingPolicyOutput, RusotoError<RemoveAutoScalingPolicyError>> {
+        self.runtime
+            .block_on(self.client.remove_auto_scaling_policy(input))
+    }
+
+    /// Blocking version of [`Emr::remove_managed_scaling_policy`].
+    pub fn remove_managed_scaling_policy(
+        &self,
+        input: RemoveManagedScalingPolicyInput,
+    ) -> Result<RemoveManagedScalingPolicyOutput, RusotoError<RemoveManagedScalingPolicyError>>
+    {
+        self.runtime
+            .block_on(self.client.remove_managed_scaling_policy(input))
+    }
+
+    /// Blocking version of [`Emr::remove_tags`].
+    pub fn remove_tags(
+        &self,
+        input: RemoveTagsInput,
+    ) -> Result<RemoveTagsOutput, RusotoError<RemoveTagsError>> {
+        self.runtime.block_on(self.client.remove_tags(input))
+    }
+
+    /// Blocking version of [`Emr::run_job_flow`].
+    pub fn run_job_flow(
+        &self,
+        input: RunJobFlowInput,
+    ) -> Result<RunJobFlowOutput, RusotoError<RunJobFlowError>> {
+        self.runtime.block_on(self.client.run_job_flow(input))
+    }
+
+    /// Blocking version of [`Emr::set_termination_protection`].
+    pub fn set_termination_protection(
+        &self,
+        input: SetTerminationProtectionInput,
+    ) -> Result<(), RusotoError<SetTerminationProtectionError>> {
+        self.runtime
+            .block_on(self.client.set_termination_protection(input))
+    }
+
+    /// Blocking version of [`Emr::set_visible_to_all_users`].
+    pub fn set_visible_to_all_users(
+        &self,
+        input: SetVisibleToAllUsersInput,
+    ) -> Result<(), RusotoError<SetVisibleToAllUsersError>> {
+        self.runtime
+            .block_on(self.client.set_visible_to_all_users(input))
+    }
+
+    /// Blocking version of [`Emr::start_notebook_execution`].
+    pub fn start_notebook_execution(
+        &self,
+        input: StartNotebookExecutionInput,
+    ) -> Result<StartNotebookExecutionOutput, RusotoError<StartNotebookExecutionError>> {
+        self.runtime
+            .block_on(self.client.start_notebook_execution(input))
+    }
+
+    /// Blocking version of [`Emr::stop_notebook_execution`].
+    pub fn stop_notebook_execution(
+        &self,
+        input: StopNotebookExecutionInput,
+    ) -> Result<(), RusotoError<StopNotebookExecutionError>> {
+        self.runtime
+            .block_on(self.client.stop_notebook_execution(input))
+    }
+
+    /// Blocking version of [`Emr::terminate_job_flows`].
+    pub fn terminate_job_flows(
+        &self,
+        input: TerminateJobFlowsInput,
+    ) -> Result<(), RusotoError<TerminateJobFlowsError>> {
+        self.runtime
+            .block_on(self.client.terminate_job_flows(input))
+    }
+
+    /// Blocking version of [`Emr::update_studio_session_mapping`].
+    pub fn update_studio_session_mapping(
+        &self,
+        input: UpdateStudioSessionMappingInput,
+    ) -> Result<(), RusotoError<UpdateStudioSessionMappingError>> {
+        self.runtime
+            .block_on(self.client.update_studio_session_mapping(input))
+    }
+}
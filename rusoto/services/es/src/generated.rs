@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -2255,21 +2256,30 @@ impl AcceptInboundCrossClusterSearchConnectionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DisabledOperationException" => {
-                    return RusotoError::Service(
-                        AcceptInboundCrossClusterSearchConnectionError::DisabledOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptInboundCrossClusterSearchConnectionError::DisabledOperation,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
-                        AcceptInboundCrossClusterSearchConnectionError::LimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptInboundCrossClusterSearchConnectionError::LimitExceeded,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        AcceptInboundCrossClusterSearchConnectionError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptInboundCrossClusterSearchConnectionError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2308,14 +2318,26 @@ impl AddTagsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<AddTagsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "BaseException" => return RusotoError::Service(AddTagsError::Base(err.msg)),
+                "BaseException" => {
+                    return RusotoError::service_error(&res, &err.typ, err.msg, AddTagsError::Base)
+                }
                 "InternalException" => {
-                    return RusotoError::Service(AddTagsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsError::Internal,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AddTagsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2353,21 +2375,46 @@ impl AssociatePackageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AssociatePackageError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociatePackageError::AccessDenied,
+                    )
                 }
                 "BaseException" => {
-                    return RusotoError::Service(AssociatePackageError::Base(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociatePackageError::Base,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(AssociatePackageError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociatePackageError::Conflict,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(AssociatePackageError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociatePackageError::Internal,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociatePackageError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociatePackageError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2405,21 +2452,30 @@ impl CancelElasticsearchServiceSoftwareUpdateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(
-                        CancelElasticsearchServiceSoftwareUpdateError::Base(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelElasticsearchServiceSoftwareUpdateError::Base,
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(
-                        CancelElasticsearchServiceSoftwareUpdateError::Internal(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelElasticsearchServiceSoftwareUpdateError::Internal,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        CancelElasticsearchServiceSoftwareUpdateError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelElasticsearchServiceSoftwareUpdateError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2465,32 +2521,54 @@ impl CreateElasticsearchDomainError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(CreateElasticsearchDomainError::Base(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateElasticsearchDomainError::Base,
+                    )
                 }
                 "DisabledOperationException" => {
-                    return RusotoError::Service(CreateElasticsearchDomainError::DisabledOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateElasticsearchDomainError::DisabledOperation,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(CreateElasticsearchDomainError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateElasticsearchDomainError::Internal,
+                    )
                 }
                 "InvalidTypeException" => {
-                    return RusotoError::Service(CreateElasticsearchDomainError::InvalidType(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateElasticsearchDomainError::InvalidType,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateElasticsearchDomainError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateElasticsearchDomainError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        CreateElasticsearchDomainError::ResourceAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateElasticsearchDomainError::ResourceAlreadyExists,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2533,28 +2611,38 @@ impl CreateOutboundCrossClusterSearchConnectionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DisabledOperationException" => {
-                    return RusotoError::Service(
-                        CreateOutboundCrossClusterSearchConnectionError::DisabledOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateOutboundCrossClusterSearchConnectionError::DisabledOperation,
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(
-                        CreateOutboundCrossClusterSearchConnectionError::Internal(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateOutboundCrossClusterSearchConnectionError::Internal,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
-                        CreateOutboundCrossClusterSearchConnectionError::LimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateOutboundCrossClusterSearchConnectionError::LimitExceeded,
                     )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        CreateOutboundCrossClusterSearchConnectionError::ResourceAlreadyExists(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateOutboundCrossClusterSearchConnectionError::ResourceAlreadyExists,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2603,22 +2691,54 @@ impl CreatePackageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreatePackageError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePackageError::AccessDenied,
+                    )
+                }
+                "BaseException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePackageError::Base,
+                    )
                 }
-                "BaseException" => return RusotoError::Service(CreatePackageError::Base(err.msg)),
                 "InternalException" => {
-                    return RusotoError::Service(CreatePackageError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePackageError::Internal,
+                    )
                 }
                 "InvalidTypeException" => {
-                    return RusotoError::Service(CreatePackageError::InvalidType(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePackageError::InvalidType,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreatePackageError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePackageError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreatePackageError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePackageError::ResourceAlreadyExists,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2655,17 +2775,30 @@ impl DeleteElasticsearchDomainError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(DeleteElasticsearchDomainError::Base(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteElasticsearchDomainError::Base,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DeleteElasticsearchDomainError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteElasticsearchDomainError::Internal,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteElasticsearchDomainError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteElasticsearchDomainError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2699,14 +2832,22 @@ impl DeleteElasticsearchServiceRoleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(DeleteElasticsearchServiceRoleError::Base(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteElasticsearchServiceRoleError::Base,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DeleteElasticsearchServiceRoleError::Internal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteElasticsearchServiceRoleError::Internal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2739,16 +2880,22 @@ impl DeleteInboundCrossClusterSearchConnectionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DisabledOperationException" => {
-                    return RusotoError::Service(
-                        DeleteInboundCrossClusterSearchConnectionError::DisabledOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInboundCrossClusterSearchConnectionError::DisabledOperation,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteInboundCrossClusterSearchConnectionError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInboundCrossClusterSearchConnectionError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2785,16 +2932,22 @@ impl DeleteOutboundCrossClusterSearchConnectionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DisabledOperationException" => {
-                    return RusotoError::Service(
-                        DeleteOutboundCrossClusterSearchConnectionError::DisabledOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteOutboundCrossClusterSearchConnectionError::DisabledOperation,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteOutboundCrossClusterSearchConnectionError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteOutboundCrossClusterSearchConnectionError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2835,19 +2988,46 @@ impl DeletePackageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeletePackageError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePackageError::AccessDenied,
+                    )
+                }
+                "BaseException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePackageError::Base,
+                    )
                 }
-                "BaseException" => return RusotoError::Service(DeletePackageError::Base(err.msg)),
                 "ConflictException" => {
-                    return RusotoError::Service(DeletePackageError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePackageError::Conflict,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DeletePackageError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePackageError::Internal,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeletePackageError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePackageError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2885,19 +3065,30 @@ impl DescribeElasticsearchDomainError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(DescribeElasticsearchDomainError::Base(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeElasticsearchDomainError::Base,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DescribeElasticsearchDomainError::Internal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeElasticsearchDomainError::Internal,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeElasticsearchDomainError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeElasticsearchDomainError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2933,21 +3124,30 @@ impl DescribeElasticsearchDomainConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(DescribeElasticsearchDomainConfigError::Base(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeElasticsearchDomainConfigError::Base,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DescribeElasticsearchDomainConfigError::Internal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeElasticsearchDomainConfigError::Internal,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeElasticsearchDomainConfigError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeElasticsearchDomainConfigError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2983,14 +3183,22 @@ impl DescribeElasticsearchDomainsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(DescribeElasticsearchDomainsError::Base(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeElasticsearchDomainsError::Base,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DescribeElasticsearchDomainsError::Internal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeElasticsearchDomainsError::Internal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3029,31 +3237,46 @@ impl DescribeElasticsearchInstanceTypeLimitsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(
-                        DescribeElasticsearchInstanceTypeLimitsError::Base(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeElasticsearchInstanceTypeLimitsError::Base,
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(
-                        DescribeElasticsearchInstanceTypeLimitsError::Internal(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeElasticsearchInstanceTypeLimitsError::Internal,
                     )
                 }
                 "InvalidTypeException" => {
-                    return RusotoError::Service(
-                        DescribeElasticsearchInstanceTypeLimitsError::InvalidType(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeElasticsearchInstanceTypeLimitsError::InvalidType,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
-                        DescribeElasticsearchInstanceTypeLimitsError::LimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeElasticsearchInstanceTypeLimitsError::LimitExceeded,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeElasticsearchInstanceTypeLimitsError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeElasticsearchInstanceTypeLimitsError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3097,20 +3320,22 @@ impl DescribeInboundCrossClusterSearchConnectionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DisabledOperationException" => {
-                    return RusotoError::Service(
-                        DescribeInboundCrossClusterSearchConnectionsError::DisabledOperation(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInboundCrossClusterSearchConnectionsError::DisabledOperation,
                     )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(
-                        DescribeInboundCrossClusterSearchConnectionsError::InvalidPaginationToken(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInboundCrossClusterSearchConnectionsError::InvalidPaginationToken,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3147,20 +3372,22 @@ impl DescribeOutboundCrossClusterSearchConnectionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DisabledOperationException" => {
-                    return RusotoError::Service(
-                        DescribeOutboundCrossClusterSearchConnectionsError::DisabledOperation(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeOutboundCrossClusterSearchConnectionsError::DisabledOperation,
                     )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(
-                        DescribeOutboundCrossClusterSearchConnectionsError::InvalidPaginationToken(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeOutboundCrossClusterSearchConnectionsError::InvalidPaginationToken,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3199,18 +3426,38 @@ impl DescribePackagesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribePackagesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePackagesError::AccessDenied,
+                    )
                 }
                 "BaseException" => {
-                    return RusotoError::Service(DescribePackagesError::Base(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePackagesError::Base,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DescribePackagesError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePackagesError::Internal,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribePackagesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePackagesError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3247,25 +3494,30 @@ impl DescribeReservedElasticsearchInstanceOfferingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DisabledOperationException" => {
-                    return RusotoError::Service(
-                        DescribeReservedElasticsearchInstanceOfferingsError::DisabledOperation(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeReservedElasticsearchInstanceOfferingsError::DisabledOperation,
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(
-                        DescribeReservedElasticsearchInstanceOfferingsError::Internal(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeReservedElasticsearchInstanceOfferingsError::Internal,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeReservedElasticsearchInstanceOfferingsError::ResourceNotFound(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeReservedElasticsearchInstanceOfferingsError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3307,21 +3559,30 @@ impl DescribeReservedElasticsearchInstancesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DisabledOperationException" => {
-                    return RusotoError::Service(
-                        DescribeReservedElasticsearchInstancesError::DisabledOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeReservedElasticsearchInstancesError::DisabledOperation,
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(
-                        DescribeReservedElasticsearchInstancesError::Internal(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeReservedElasticsearchInstancesError::Internal,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeReservedElasticsearchInstancesError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeReservedElasticsearchInstancesError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3365,21 +3626,46 @@ impl DissociatePackageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DissociatePackageError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DissociatePackageError::AccessDenied,
+                    )
                 }
                 "BaseException" => {
-                    return RusotoError::Service(DissociatePackageError::Base(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DissociatePackageError::Base,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DissociatePackageError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DissociatePackageError::Conflict,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DissociatePackageError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DissociatePackageError::Internal,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DissociatePackageError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DissociatePackageError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3419,26 +3705,38 @@ impl GetCompatibleElasticsearchVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(GetCompatibleElasticsearchVersionsError::Base(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCompatibleElasticsearchVersionsError::Base,
+                    )
                 }
                 "DisabledOperationException" => {
-                    return RusotoError::Service(
-                        GetCompatibleElasticsearchVersionsError::DisabledOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCompatibleElasticsearchVersionsError::DisabledOperation,
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(GetCompatibleElasticsearchVersionsError::Internal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCompatibleElasticsearchVersionsError::Internal,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        GetCompatibleElasticsearchVersionsError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCompatibleElasticsearchVersionsError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3479,22 +3777,38 @@ impl GetPackageVersionHistoryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetPackageVersionHistoryError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetPackageVersionHistoryError::AccessDenied,
+                    )
                 }
                 "BaseException" => {
-                    return RusotoError::Service(GetPackageVersionHistoryError::Base(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPackageVersionHistoryError::Base,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(GetPackageVersionHistoryError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPackageVersionHistoryError::Internal,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetPackageVersionHistoryError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetPackageVersionHistoryError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3531,18 +3845,38 @@ impl GetUpgradeHistoryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(GetUpgradeHistoryError::Base(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUpgradeHistoryError::Base,
+                    )
                 }
                 "DisabledOperationException" => {
-                    return RusotoError::Service(GetUpgradeHistoryError::DisabledOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUpgradeHistoryError::DisabledOperation,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(GetUpgradeHistoryError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUpgradeHistoryError::Internal,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetUpgradeHistoryError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUpgradeHistoryError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3579,18 +3913,38 @@ impl GetUpgradeStatusError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(GetUpgradeStatusError::Base(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUpgradeStatusError::Base,
+                    )
                 }
                 "DisabledOperationException" => {
-                    return RusotoError::Service(GetUpgradeStatusError::DisabledOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUpgradeStatusError::DisabledOperation,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(GetUpgradeStatusError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUpgradeStatusError::Internal,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetUpgradeStatusError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUpgradeStatusError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3621,9 +3975,14 @@ impl ListDomainNamesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(ListDomainNamesError::Base(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDomainNamesError::Base,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3657,20 +4016,38 @@ impl ListDomainsForPackageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListDomainsForPackageError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDomainsForPackageError::AccessDenied,
+                    )
                 }
                 "BaseException" => {
-                    return RusotoError::Service(ListDomainsForPackageError::Base(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDomainsForPackageError::Base,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ListDomainsForPackageError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDomainsForPackageError::Internal,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDomainsForPackageError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDomainsForPackageError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3707,19 +4084,30 @@ impl ListElasticsearchInstanceTypesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(ListElasticsearchInstanceTypesError::Base(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListElasticsearchInstanceTypesError::Base,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ListElasticsearchInstanceTypesError::Internal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListElasticsearchInstanceTypesError::Internal,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        ListElasticsearchInstanceTypesError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListElasticsearchInstanceTypesError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3755,17 +4143,30 @@ impl ListElasticsearchVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(ListElasticsearchVersionsError::Base(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListElasticsearchVersionsError::Base,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ListElasticsearchVersionsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListElasticsearchVersionsError::Internal,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListElasticsearchVersionsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListElasticsearchVersionsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3801,20 +4202,38 @@ impl ListPackagesForDomainError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListPackagesForDomainError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPackagesForDomainError::AccessDenied,
+                    )
                 }
                 "BaseException" => {
-                    return RusotoError::Service(ListPackagesForDomainError::Base(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPackagesForDomainError::Base,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ListPackagesForDomainError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPackagesForDomainError::Internal,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListPackagesForDomainError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPackagesForDomainError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3848,14 +4267,26 @@ impl ListTagsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListTagsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "BaseException" => return RusotoError::Service(ListTagsError::Base(err.msg)),
+                "BaseException" => {
+                    return RusotoError::service_error(&res, &err.typ, err.msg, ListTagsError::Base)
+                }
                 "InternalException" => {
-                    return RusotoError::Service(ListTagsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsError::Internal,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3895,37 +4326,46 @@ impl PurchaseReservedElasticsearchInstanceOfferingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DisabledOperationException" => {
-                    return RusotoError::Service(
-                        PurchaseReservedElasticsearchInstanceOfferingError::DisabledOperation(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PurchaseReservedElasticsearchInstanceOfferingError::DisabledOperation,
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(
-                        PurchaseReservedElasticsearchInstanceOfferingError::Internal(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PurchaseReservedElasticsearchInstanceOfferingError::Internal,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
-                        PurchaseReservedElasticsearchInstanceOfferingError::LimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PurchaseReservedElasticsearchInstanceOfferingError::LimitExceeded,
                     )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        PurchaseReservedElasticsearchInstanceOfferingError::ResourceAlreadyExists(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PurchaseReservedElasticsearchInstanceOfferingError::ResourceAlreadyExists,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        PurchaseReservedElasticsearchInstanceOfferingError::ResourceNotFound(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PurchaseReservedElasticsearchInstanceOfferingError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3971,16 +4411,22 @@ impl RejectInboundCrossClusterSearchConnectionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DisabledOperationException" => {
-                    return RusotoError::Service(
-                        RejectInboundCrossClusterSearchConnectionError::DisabledOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RejectInboundCrossClusterSearchConnectionError::DisabledOperation,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        RejectInboundCrossClusterSearchConnectionError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RejectInboundCrossClusterSearchConnectionError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4014,11 +4460,23 @@ impl RemoveTagsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<RemoveTagsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "BaseException" => return RusotoError::Service(RemoveTagsError::Base(err.msg)),
+                "BaseException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveTagsError::Base,
+                    )
+                }
                 "InternalException" => {
-                    return RusotoError::Service(RemoveTagsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveTagsError::Internal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4053,21 +4511,30 @@ impl StartElasticsearchServiceSoftwareUpdateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(
-                        StartElasticsearchServiceSoftwareUpdateError::Base(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartElasticsearchServiceSoftwareUpdateError::Base,
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(
-                        StartElasticsearchServiceSoftwareUpdateError::Internal(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartElasticsearchServiceSoftwareUpdateError::Internal,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        StartElasticsearchServiceSoftwareUpdateError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartElasticsearchServiceSoftwareUpdateError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4111,31 +4578,46 @@ impl UpdateElasticsearchDomainConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(UpdateElasticsearchDomainConfigError::Base(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateElasticsearchDomainConfigError::Base,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(UpdateElasticsearchDomainConfigError::Internal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateElasticsearchDomainConfigError::Internal,
+                    )
                 }
                 "InvalidTypeException" => {
-                    return RusotoError::Service(UpdateElasticsearchDomainConfigError::InvalidType(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateElasticsearchDomainConfigError::InvalidType,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
-                        UpdateElasticsearchDomainConfigError::LimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateElasticsearchDomainConfigError::LimitExceeded,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateElasticsearchDomainConfigError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateElasticsearchDomainConfigError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4179,19 +4661,46 @@ impl UpdatePackageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdatePackageError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePackageError::AccessDenied,
+                    )
+                }
+                "BaseException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePackageError::Base,
+                    )
                 }
-                "BaseException" => return RusotoError::Service(UpdatePackageError::Base(err.msg)),
                 "InternalException" => {
-                    return RusotoError::Service(UpdatePackageError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePackageError::Internal,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdatePackageError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePackageError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdatePackageError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePackageError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4233,27 +4742,46 @@ impl UpgradeElasticsearchDomainError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BaseException" => {
-                    return RusotoError::Service(UpgradeElasticsearchDomainError::Base(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpgradeElasticsearchDomainError::Base,
+                    )
                 }
                 "DisabledOperationException" => {
-                    return RusotoError::Service(
-                        UpgradeElasticsearchDomainError::DisabledOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpgradeElasticsearchDomainError::DisabledOperation,
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(UpgradeElasticsearchDomainError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpgradeElasticsearchDomainError::Internal,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        UpgradeElasticsearchDomainError::ResourceAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpgradeElasticsearchDomainError::ResourceAlreadyExists,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpgradeElasticsearchDomainError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpgradeElasticsearchDomainError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4568,11 +5096,12 @@ pub struct EsClient {
 impl EsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> EsClient {
         EsClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Elasticsearch Service"),
         }
     }
 
@@ -4587,12 +5116,32 @@ impl EsClient {
     {
         EsClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Elasticsearch Service"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> EsClient {
-        EsClient { client, region }
+        EsClient {
+            client,
+            region: region_for_service(region, "Elasticsearch Service"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> EsClient {
+        EsClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Elasticsearch Service"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> EsClient {
+        EsClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -4613,6 +5162,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("PUT", "es", &self.region, &request_uri);
+        request.set_operation("AcceptInboundCrossClusterSearchConnection");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4638,6 +5188,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/tags";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("AddTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4672,6 +5223,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("AssociatePackage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4703,6 +5255,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/serviceSoftwareUpdate/cancel";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("CancelElasticsearchServiceSoftwareUpdate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4735,6 +5288,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/domain";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("CreateElasticsearchDomain");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4769,6 +5323,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/ccs/outboundConnection";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("CreateOutboundCrossClusterSearchConnection");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4800,6 +5355,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/packages";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("CreatePackage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4835,6 +5391,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "es", &self.region, &request_uri);
+        request.set_operation("DeleteElasticsearchDomain");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4862,6 +5419,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/role";
 
         let mut request = SignedRequest::new("DELETE", "es", &self.region, &request_uri);
+        request.set_operation("DeleteElasticsearchServiceRole");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4895,6 +5453,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "es", &self.region, &request_uri);
+        request.set_operation("DeleteInboundCrossClusterSearchConnection");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4929,6 +5488,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "es", &self.region, &request_uri);
+        request.set_operation("DeleteOutboundCrossClusterSearchConnection");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4960,6 +5520,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "es", &self.region, &request_uri);
+        request.set_operation("DeletePackage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4992,6 +5553,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("DescribeElasticsearchDomain");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5026,6 +5588,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("DescribeElasticsearchDomainConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5057,6 +5620,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/domain-info";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("DescribeElasticsearchDomains");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5095,6 +5659,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("DescribeElasticsearchInstanceTypeLimits");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5134,6 +5699,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/ccs/inboundConnection/search";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("DescribeInboundCrossClusterSearchConnections");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5169,6 +5735,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/ccs/outboundConnection/search";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("DescribeOutboundCrossClusterSearchConnections");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5201,6 +5768,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/packages/describe";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("DescribePackages");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5235,6 +5803,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/reservedInstanceOfferings";
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("DescribeReservedElasticsearchInstanceOfferings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5279,6 +5848,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/reservedInstances";
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("DescribeReservedElasticsearchInstances");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5325,6 +5895,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("DissociatePackage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5356,6 +5927,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/compatibleVersions";
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("GetCompatibleElasticsearchVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5395,6 +5967,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("GetPackageVersionHistory");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5435,6 +6008,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("GetUpgradeHistory");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5475,6 +6049,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("GetUpgradeStatus");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5502,6 +6077,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/domain";
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("ListDomainNames");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5533,6 +6109,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("ListDomainsForPackage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5576,6 +6153,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("ListElasticsearchInstanceTypes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5617,6 +6195,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/versions";
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("ListElasticsearchVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5657,6 +6236,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("ListPackagesForDomain");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5694,6 +6274,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/tags/";
 
         let mut request = SignedRequest::new("GET", "es", &self.region, &request_uri);
+        request.set_operation("ListTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -5729,6 +6310,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/purchaseReservedInstanceOffering";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("PurchaseReservedElasticsearchInstanceOffering");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5767,6 +6349,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("PUT", "es", &self.region, &request_uri);
+        request.set_operation("RejectInboundCrossClusterSearchConnection");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5795,6 +6378,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/tags-removal";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("RemoveTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5828,6 +6412,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/serviceSoftwareUpdate/start";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("StartElasticsearchServiceSoftwareUpdate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5867,6 +6452,7 @@ impl Es for EsClient {
         );
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("UpdateElasticsearchDomainConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5900,6 +6486,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/packages/update";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("UpdatePackage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5932,6 +6519,7 @@ impl Es for EsClient {
         let request_uri = "/2015-01-01/es/upgradeDomain";
 
         let mut request = SignedRequest::new("POST", "es", &self.region, &request_uri);
+        request.set_operation("UpgradeElasticsearchDomain");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5954,3 +6542,494 @@ impl Es for EsClient {
         }
     }
 }
+
+/// A blocking client for the Amazon Elasticsearch Service API, running the calls of a `EsClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `EsClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct EsBlockingClient {
+    client: EsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl EsBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> EsBlockingClient {
+        EsBlockingClient::from_client(EsClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> EsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        EsBlockingClient::from_client(EsClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> EsBlockingClient {
+        EsBlockingClient::from_client(EsClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> EsBlockingClient {
+        EsBlockingClient::from_client(EsClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: EsClient) -> EsBlockingClient {
+        EsBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> EsBlockingClient {
+        EsBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> EsBlockingClient {
+        EsBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Es::accept_inbound_cross_cluster_search_connection`].
+    pub fn accept_inbound_cross_cluster_search_connection(
+        &self,
+        input: AcceptInboundCrossClusterSearchConnectionRequest,
+    ) -> Result<
+        AcceptInboundCrossClusterSearchConnectionResponse,
+        RusotoError<AcceptInboundCrossClusterSearchConnectionError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .accept_inbound_cross_cluster_search_connection(input),
+        )
+    }
+
+    /// Blocking version of [`Es::add_tags`].
+    pub fn add_tags(&self, input: AddTagsRequest) -> Result<(), RusotoError<AddTagsError>> {
+        self.runtime.block_on(self.client.add_tags(input))
+    }
+
+    /// Blocking version of [`Es::associate_package`].
+    pub fn associate_package(
+        &self,
+        input: AssociatePackageRequest,
+    ) -> Result<AssociatePackageResponse, RusotoError<AssociatePackageError>> {
+        self.runtime.block_on(self.client.associate_package(input))
+    }
+
+    /// Blocking version of [`Es::cancel_elasticsearch_service_software_update`].
+    pub fn cancel_elasticsearch_service_software_update(
+        &self,
+        input: CancelElasticsearchServiceSoftwareUpdateRequest,
+    ) -> Result<
+        CancelElasticsearchServiceSoftwareUpdateResponse,
+        RusotoError<CancelElasticsearchServiceSoftwareUpdateError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .cancel_elasticsearch_service_software_update(input),
+        )
+    }
+
+    /// Blocking version of [`Es::create_elasticsearch_domain`].
+    pub fn create_elasticsearch_domain(
+        &self,
+        input: CreateElasticsearchDomainRequest,
+    ) -> Result<CreateElasticsearchDomainResponse, RusotoError<CreateElasticsearchDomainError>>
+    {
+        self.runtime
+            .block_on(self.client.create_elasticsearch_domain(input))
+    }
+
+    /// Blocking version of [`Es::create_outbound_cross_cluster_search_connection`].
+    pub fn create_outbound_cross_cluster_search_connection(
+        &self,
+        input: CreateOutboundCrossClusterSearchConnectionRequest,
+    ) -> Result<
+        CreateOutboundCrossClusterSearchConnectionResponse,
+        RusotoError<CreateOutboundCrossClusterSearchConnectionError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .create_outbound_cross_cluster_search_connection(input),
+        )
+    }
+
+    /// Blocking version of [`Es::create_package`].
+    pub fn create_package(
+        &self,
+        input: CreatePackageRequest,
+    ) -> Result<CreatePackageResponse, RusotoError<CreatePackageError>> {
+        self.runtime.block_on(self.client.create_package(input))
+    }
+
+    /// Blocking version of [`Es::delete_elasticsearch_domain`].
+    pub fn delete_elasticsearch_domain(
+        &self,
+        input: DeleteElasticsearchDomainRequest,
+    ) -> Result<DeleteElasticsearchDomainResponse, RusotoError<DeleteElasticsearchDomainError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_elasticsearch_domain(input))
+    }
+
+    /// Blocking version of [`Es::delete_elasticsearch_service_role`].
+    pub fn delete_elasticsearch_service_role(
+        &self,
+    ) -> Result<(), RusotoError<DeleteElasticsearchServiceRoleError>> {
+        self.runtime
+            .block_on(self.client.delete_elasticsearch_service_role())
+    }
+
+    /// Blocking version of [`Es::delete_inbound_cross_cluster_search_connection`].
+    pub fn delete_inbound_cross_cluster_search_connection(
+        &self,
+        input: DeleteInboundCrossClusterSearchConnectionRequest,
+    ) -> Result<
+        DeleteInboundCrossClusterSearchConnectionResponse,
+        RusotoError<DeleteInboundCrossClusterSearchConnectionError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .delete_inbound_cross_cluster_search_connection(input),
+        )
+    }
+
+    /// Blocking version of [`Es::delete_outbound_cross_cluster_search_connection`].
+    pub fn delete_outbound_cross_cluster_search_connection(
+        &self,
+        input: DeleteOutboundCrossClusterSearchConnectionRequest,
+    ) -> Result<
+        DeleteOutboundCrossClusterSearchConnectionResponse,
+        RusotoError<DeleteOutboundCrossClusterSearchConnectionError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .delete_outbound_cross_cluster_search_connection(input),
+        )
+    }
+
+    /// Blocking version of [`Es::delete_package`].
+    pub fn delete_package(
+        &self,
+        input: DeletePackageRequest,
+    ) -> Result<DeletePackageResponse, RusotoError<DeletePackageError>> {
+        self.runtime.block_on(self.client.delete_package(input))
+    }
+
+    /// Blocking version of [`Es::describe_elasticsearch_domain`].
+    pub fn describe_elasticsearch_domain(
+        &self,
+        input: DescribeElasticsearchDomainRequest,
+    ) -> Result<DescribeElasticsearchDomainResponse, RusotoError<DescribeElasticsearchDomainError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_elasticsearch_domain(input))
+    }
+
+    /// Blocking version of [`Es::describe_elasticsearch_domain_config`].
+    pub fn describe_elasticsearch_domain_config(
+        &self,
+        input: DescribeElasticsearchDomainConfigRequest,
+    ) -> Result<
+        DescribeElasticsearchDomainConfigResponse,
+        RusotoError<DescribeElasticsearchDomainConfigError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_elasticsearch_domain_config(input))
+    }
+
+    /// Blocking version of [`Es::describe_elasticsearch_domains`].
+    pub fn describe_elasticsearch_domains(
+        &self,
+        input: DescribeElasticsearchDomainsRequest,
+    ) -> Result<DescribeElasticsearchDomainsResponse, RusotoError<DescribeElasticsearchDomainsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_elasticsearch_domains(input))
+    }
+
+    /// Blocking version of [`Es::describe_elasticsearch_instance_type_limits`].
+    pub fn describe_elasticsearch_instance_type_limits(
+        &self,
+        input: DescribeElasticsearchInstanceTypeLimitsRequest,
+    ) -> Result<
+        DescribeElasticsearchInstanceTypeLimitsResponse,
+        RusotoError<DescribeElasticsearchInstanceTypeLimitsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_elasticsearch_instance_type_limits(input),
+        )
+    }
+
+    /// Blocking version of [`Es::describe_inbound_cross_cluster_search_connections`].
+    pub fn describe_inbound_cross_cluster_search_connections(
+        &self,
+        input: DescribeInboundCrossClusterSearchConnectionsRequest,
+    ) -> Result<
+        DescribeInboundCrossClusterSearchConnectionsResponse,
+        RusotoError<DescribeInboundCrossClusterSearchConnectionsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_inbound_cross_cluster_search_connections(input),
+        )
+    }
+
+    /// Blocking version of [`Es::describe_outbound_cross_cluster_search_connections`].
+    pub fn describe_outbound_cross_cluster_search_connections(
+        &self,
+        input: DescribeOutboundCrossClusterSearchConnectionsRequest,
+    ) -> Result<
+        DescribeOutboundCrossClusterSearchConnectionsResponse,
+        RusotoError<DescribeOutboundCrossClusterSearchConnectionsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_outbound_cross_cluster_search_connections(input),
+        )
+    }
+
+    /// Blocking version of [`Es::describe_packages`].
+    pub fn describe_packages(
+        &self,
+        input: DescribePackagesRequest,
+    ) -> Result<DescribePackagesResponse, RusotoError<DescribePackagesError>> {
+        self.runtime.block_on(self.client.describe_packages(input))
+    }
+
+    /// Blocking version of [`Es::describe_reserved_elasticsearch_instance_offerings`].
+    pub fn describe_reserved_elasticsearch_instance_offerings(
+        &self,
+        input: DescribeReservedElasticsearchInstanceOfferingsRequest,
+    ) -> Result<
+        DescribeReservedElasticsearchInstanceOfferingsResponse,
+        RusotoError<DescribeReservedElasticsearchInstanceOfferingsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_reserved_elasticsearch_instance_offerings(input),
+        )
+    }
+
+    /// Blocking version of [`Es::describe_reserved_elasticsearch_instances`].
+    pub fn describe_reserved_elasticsearch_instances(
+        &self,
+        input: DescribeReservedElasticsearchInstancesRequest,
+    ) -> Result<
+        DescribeReservedElasticsearchInstancesResponse,
+        RusotoError<DescribeReservedElasticsearchInstancesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_reserved_elasticsearch_instances(input))
+    }
+
+    /// Blocking version of [`Es::dissociate_package`].
+    pub fn dissociate_package(
+        &self,
+        input: DissociatePackageRequest,
+    ) -> Result<DissociatePackageResponse, RusotoError<DissociatePackageError>> {
+        self.runtime.block_on(self.client.dissociate_package(input))
+    }
+
+    /// Blocking version of [`Es::get_compatible_elasticsearch_versions`].
+    pub fn get_compatible_elasticsearch_versions(
+        &self,
+        input: GetCompatibleElasticsearchVersionsRequest,
+    ) -> Result<
+        GetCompatibleElasticsearchVersionsResponse,
+        RusotoError<GetCompatibleElasticsearchVersionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_compatible_elasticsearch_versions(input))
+    }
+
+    /// Blocking version of [`Es::get_package_version_history`].
+    pub fn get_package_version_history(
+        &self,
+        input: GetPackageVersionHistoryRequest,
+    ) -> Result<GetPackageVersionHistoryResponse, RusotoError<GetPackageVersionHistoryError>> {
+        self.runtime
+            .block_on(self.client.get_package_version_history(input))
+    }
+
+    /// Blocking version of [`Es::get_upgrade_history`].
+    pub fn get_upgrade_history(
+        &self,
+        input: GetUpgradeHistoryRequest,
+    ) -> Result<GetUpgradeHistoryResponse, RusotoError<GetUpgradeHistoryError>> {
+        self.runtime
+            .block_on(self.client.get_upgrade_history(input))
+    }
+
+    /// Blocking version of [`Es::get_upgrade_status`].
+    pub fn get_upgrade_status(
+        &self,
+        input: GetUpgradeStatusRequest,
+    ) -> Result<GetUpgradeStatusResponse, RusotoError<GetUpgradeStatusError>> {
+        self.runtime.block_on(self.client.get_upgrade_status(input))
+    }
+
+    /// Blocking version of [`Es::list_domain_names`].
+    pub fn list_domain_names(
+        &self,
+    ) -> Result<ListDomainNamesResponse, RusotoError<ListDomainNamesError>> {
+        self.runtime.block_on(self.client.list_domain_names())
+    }
+
+    /// Blocking version of [`Es::list_domains_for_package`].
+    pub fn list_domains_for_package(
+        &self,
+        input: ListDomainsForPackageRequest,
+    ) -> Result<ListDomainsForPackageResponse, RusotoError<ListDomainsForPackageError>> {
+        self.runtime
+            .block_on(self.client.list_domains_for_package(input))
+    }
+
+    /// Blocking version of [`Es::list_elasticsearch_instance_types`].
+    pub fn list_elasticsearch_instance_types(
+        &self,
+        input: ListElasticsearchInstanceTypesRequest,
+    ) -> Result<
+        ListElasticsearchInstanceTypesResponse,
+        RusotoError<ListElasticsearchInstanceTypesError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_elasticsearch_instance_types(input))
+    }
+
+    /// Blocking version of [`Es::list_elasticsearch_versions`].
+    pub fn list_elasticsearch_versions(
+        &self,
+        input: ListElasticsearchVersionsRequest,
+    ) -> Result<ListElasticsearchVersionsResponse, RusotoError<ListElasticsearchVersionsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_elasticsearch_versions(input))
+    }
+
+    /// Blocking version of [`Es::list_packages_for_domain`].
+    pub fn list_packages_for_domain(
+        &self,
+        input: ListPackagesForDomainRequest,
+    ) -> Result<ListPackagesForDomainResponse, RusotoError<ListPackagesForDomainError>> {
+        self.runtime
+            .block_on(self.client.list_packages_for_domain(input))
+    }
+
+    /// Blocking version of [`Es::list_tags`].
+    pub fn list_tags(
+        &self,
+        input: ListTagsRequest,
+    ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
+        self.runtime.block_on(self.client.list_tags(input))
+    }
+
+    /// Blocking version of [`Es::purchase_reserved_elasticsearch_instance_offering`].
+    pub fn purchase_reserved_elasticsearch_instance_offering(
+        &self,
+        input: PurchaseReservedElasticsearchInstanceOfferingRequest,
+    ) -> Result<
+        PurchaseReservedElasticsearchInstanceOfferingResponse,
+        RusotoError<PurchaseReservedElasticsearchInstanceOfferingError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .purchase_reserved_elasticsearch_instance_offering(input),
+        )
+    }
+
+    /// Blocking version of [`Es::reject_inbound_cross_cluster_search_connection`].
+    pub fn reject_inbound_cross_cluster_search_connection(
+        &self,
+        input: RejectInboundCrossClusterSearchConnectionRequest,
+    ) -> Result<
+        RejectInboundCrossClusterSearchConnectionResponse,
+        RusotoError<RejectInboundCrossClusterSearchConnectionError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .reject_inbound_cross_cluster_search_connection(input),
+        )
+    }
+
+    /// Blocking version of [`Es::remove_tags`].
+    pub fn remove_tags(
+        &self,
+        input: RemoveTagsRequest,
+    ) -> Result<(), RusotoError<RemoveTagsError>> {
+        self.runtime.block_on(self.client.remove_tags(input))
+    }
+
+    /// Blocking version of [`Es::start_elasticsearch_service_software_update`].
+    pub fn start_elasticsearch_service_software_update(
+        &self,
+        input: StartElasticsearchServiceSoftwareUpdateRequest,
+    ) -> Result<
+        StartElasticsearchServiceSoftwareUpdateResponse,
+        RusotoError<StartElasticsearchServiceSoftwareUpdateError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .start_elasticsearch_service_software_update(input),
+        )
+    }
+
+    /// Blocking version of [`Es::update_elasticsearch_domain_config`].
+    pub fn update_elasticsearch_domain_config(
+        &self,
+        input: UpdateElasticsearchDomainConfigRequest,
+    ) -> Result<
+        UpdateElasticsearchDomainConfigResponse,
+        RusotoError<UpdateElasticsearchDomainConfigError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_elasticsearch_domain_config(input))
+    }
+
+    /// Blocking version of [`Es::update_package`].
+    pub fn update_package(
+        &self,
+        input: UpdatePackageRequest,
+    ) -> Result<UpdatePackageResponse, RusotoError<UpdatePackageError>> {
+        self.runtime.block_on(self.client.update_package(input))
+    }
+
+    /// Blocking version of [`Es::upgrade_elasticsearch_domain`].
+    pub fn upgrade_elasticsearch_domain(
+        &self,
+        input: UpgradeElasticsearchDomainRequest,
+    ) -> Result<UpgradeElasticsearchDomainResponse, RusotoError<UpgradeElasticsearchDomainError>>
+    {
+        self.runtime
+            .block_on(self.client.upgrade_elasticsearch_domain(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1809,27 +1810,46 @@ impl ActivateEventSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(ActivateEventSourceError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ActivateEventSourceError::ConcurrentModification,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ActivateEventSourceError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ActivateEventSourceError::Internal,
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(ActivateEventSourceError::InvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ActivateEventSourceError::InvalidState,
+                    )
                 }
                 "OperationDisabledException" => {
-                    return RusotoError::Service(ActivateEventSourceError::OperationDisabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ActivateEventSourceError::OperationDisabled,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ActivateEventSourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ActivateEventSourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1867,18 +1887,38 @@ impl CancelReplayError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CancelReplayError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelReplayError::ConcurrentModification,
+                    )
                 }
                 "IllegalStatusException" => {
-                    return RusotoError::Service(CancelReplayError::IllegalStatus(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelReplayError::IllegalStatus,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(CancelReplayError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelReplayError::Internal,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelReplayError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelReplayError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1919,26 +1959,54 @@ impl CreateArchiveError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateArchiveError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateArchiveError::ConcurrentModification,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(CreateArchiveError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateArchiveError::Internal,
+                    )
                 }
                 "InvalidEventPatternException" => {
-                    return RusotoError::Service(CreateArchiveError::InvalidEventPattern(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateArchiveError::InvalidEventPattern,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateArchiveError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateArchiveError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateArchiveError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateArchiveError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateArchiveError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateArchiveError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1983,31 +2051,62 @@ impl CreateEventBusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateEventBusError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateEventBusError::ConcurrentModification,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(CreateEventBusError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEventBusError::Internal,
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(CreateEventBusError::InvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEventBusError::InvalidState,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateEventBusError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEventBusError::LimitExceeded,
+                    )
                 }
                 "OperationDisabledException" => {
-                    return RusotoError::Service(CreateEventBusError::OperationDisabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEventBusError::OperationDisabled,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateEventBusError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateEventBusError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateEventBusError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEventBusError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2049,29 +2148,46 @@ impl CreatePartnerEventSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        CreatePartnerEventSourceError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePartnerEventSourceError::ConcurrentModification,
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(CreatePartnerEventSourceError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePartnerEventSourceError::Internal,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreatePartnerEventSourceError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePartnerEventSourceError::LimitExceeded,
+                    )
                 }
                 "OperationDisabledException" => {
-                    return RusotoError::Service(CreatePartnerEventSourceError::OperationDisabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePartnerEventSourceError::OperationDisabled,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        CreatePartnerEventSourceError::ResourceAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePartnerEventSourceError::ResourceAlreadyExists,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2115,27 +2231,46 @@ impl DeactivateEventSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        DeactivateEventSourceError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeactivateEventSourceError::ConcurrentModification,
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DeactivateEventSourceError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeactivateEventSourceError::Internal,
+                    )
                 }
                 "InvalidStateException" => {
-                    return RusotoError::Service(DeactivateEventSourceError::InvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeactivateEventSourceError::InvalidState,
+                    )
                 }
                 "OperationDisabledException" => {
-                    return RusotoError::Service(DeactivateEventSourceError::OperationDisabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeactivateEventSourceError::OperationDisabled,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeactivateEventSourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeactivateEventSourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2171,17 +2306,30 @@ impl DeleteArchiveError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteArchiveError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteArchiveError::ConcurrentModification,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DeleteArchiveError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteArchiveError::Internal,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteArchiveError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteArchiveError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2213,14 +2361,22 @@ impl DeleteEventBusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteEventBusError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteEventBusError::ConcurrentModification,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DeleteEventBusError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEventBusError::Internal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2253,19 +2409,30 @@ impl DeletePartnerEventSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        DeletePartnerEventSourceError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePartnerEventSourceError::ConcurrentModification,
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DeletePartnerEventSourceError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePartnerEventSourceError::Internal,
+                    )
                 }
                 "OperationDisabledException" => {
-                    return RusotoError::Service(DeletePartnerEventSourceError::OperationDisabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeletePartnerEventSourceError::OperationDisabled,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2303,18 +2470,38 @@ impl DeleteRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteRuleError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRuleError::ConcurrentModification,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DeleteRuleError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRuleError::Internal,
+                    )
                 }
                 "ManagedRuleException" => {
-                    return RusotoError::Service(DeleteRuleError::ManagedRule(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRuleError::ManagedRule,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRuleError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2349,17 +2536,30 @@ impl DescribeArchiveError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeArchiveError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeArchiveError::Internal,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(DescribeArchiveError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeArchiveError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeArchiveError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeArchiveError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2391,12 +2591,22 @@ impl DescribeEventBusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeEventBusError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEventBusError::Internal,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeEventBusError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEventBusError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2429,19 +2639,30 @@ impl DescribeEventSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeEventSourceError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEventSourceError::Internal,
+                    )
                 }
                 "OperationDisabledException" => {
-                    return RusotoError::Service(DescribeEventSourceError::OperationDisabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEventSourceError::OperationDisabled,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeEventSourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEventSourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2477,19 +2698,30 @@ impl DescribePartnerEventSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribePartnerEventSourceError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePartnerEventSourceError::Internal,
+                    )
                 }
                 "OperationDisabledException" => {
-                    return RusotoError::Service(
-                        DescribePartnerEventSourceError::OperationDisabled(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePartnerEventSourceError::OperationDisabled,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribePartnerEventSourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribePartnerEventSourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2521,12 +2753,22 @@ impl DescribeReplayError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeReplayError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeReplayError::Internal,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeReplayError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeReplayError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2557,12 +2799,22 @@ impl DescribeRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeRuleError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRuleError::Internal,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRuleError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2597,18 +2849,38 @@ impl DisableRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DisableRuleError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableRuleError::ConcurrentModification,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DisableRuleError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableRuleError::Internal,
+                    )
                 }
                 "ManagedRuleException" => {
-                    return RusotoError::Service(DisableRuleError::ManagedRule(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableRuleError::ManagedRule,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisableRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableRuleError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2645,18 +2917,38 @@ impl EnableRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(EnableRuleError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableRuleError::ConcurrentModification,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(EnableRuleError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableRuleError::Internal,
+                    )
                 }
                 "ManagedRuleException" => {
-                    return RusotoError::Service(EnableRuleError::ManagedRule(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableRuleError::ManagedRule,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(EnableRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableRuleError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2689,12 +2981,22 @@ impl ListArchivesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListArchivesError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListArchivesError::Internal,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListArchivesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListArchivesError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2723,9 +3025,14 @@ impl ListEventBusesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListEventBusesError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListEventBusesError::Internal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2755,12 +3062,22 @@ impl ListEventSourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListEventSourcesError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListEventSourcesError::Internal,
+                    )
                 }
                 "OperationDisabledException" => {
-                    return RusotoError::Service(ListEventSourcesError::OperationDisabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListEventSourcesError::OperationDisabled,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2795,21 +3112,30 @@ impl ListPartnerEventSourceAccountsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListPartnerEventSourceAccountsError::Internal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPartnerEventSourceAccountsError::Internal,
+                    )
                 }
                 "OperationDisabledException" => {
-                    return RusotoError::Service(
-                        ListPartnerEventSourceAccountsError::OperationDisabled(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPartnerEventSourceAccountsError::OperationDisabled,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        ListPartnerEventSourceAccountsError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPartnerEventSourceAccountsError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2845,14 +3171,22 @@ impl ListPartnerEventSourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListPartnerEventSourcesError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPartnerEventSourcesError::Internal,
+                    )
                 }
                 "OperationDisabledException" => {
-                    return RusotoError::Service(ListPartnerEventSourcesError::OperationDisabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPartnerEventSourcesError::OperationDisabled,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2881,9 +3215,14 @@ impl ListReplaysError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListReplaysError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListReplaysError::Internal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2913,14 +3252,22 @@ impl ListRuleNamesByTargetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListRuleNamesByTargetError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRuleNamesByTargetError::Internal,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListRuleNamesByTargetError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListRuleNamesByTargetError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2951,12 +3298,22 @@ impl ListRulesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListRulesError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRulesError::Internal,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListRulesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRulesError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2987,14 +3344,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::Internal,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3025,12 +3390,22 @@ impl ListTargetsByRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListTargetsByRuleError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTargetsByRuleError::Internal,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTargetsByRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTargetsByRuleError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3059,9 +3434,14 @@ impl PutEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(PutEventsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutEventsError::Internal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3091,12 +3471,22 @@ impl PutPartnerEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(PutPartnerEventsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutPartnerEventsError::Internal,
+                    )
                 }
                 "OperationDisabledException" => {
-                    return RusotoError::Service(PutPartnerEventsError::OperationDisabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutPartnerEventsError::OperationDisabled,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3133,23 +3523,46 @@ impl PutPermissionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(PutPermissionError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutPermissionError::ConcurrentModification,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(PutPermissionError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutPermissionError::Internal,
+                    )
                 }
                 "OperationDisabledException" => {
-                    return RusotoError::Service(PutPermissionError::OperationDisabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutPermissionError::OperationDisabled,
+                    )
                 }
                 "PolicyLengthExceededException" => {
-                    return RusotoError::Service(PutPermissionError::PolicyLengthExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutPermissionError::PolicyLengthExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutPermissionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutPermissionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3191,24 +3604,54 @@ impl PutRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(PutRuleError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRuleError::ConcurrentModification,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(PutRuleError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRuleError::Internal,
+                    )
                 }
                 "InvalidEventPatternException" => {
-                    return RusotoError::Service(PutRuleError::InvalidEventPattern(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRuleError::InvalidEventPattern,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutRuleError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRuleError::LimitExceeded,
+                    )
                 }
                 "ManagedRuleException" => {
-                    return RusotoError::Service(PutRuleError::ManagedRule(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRuleError::ManagedRule,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRuleError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3249,21 +3692,46 @@ impl PutTargetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(PutTargetsError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutTargetsError::ConcurrentModification,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(PutTargetsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutTargetsError::Internal,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutTargetsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutTargetsError::LimitExceeded,
+                    )
                 }
                 "ManagedRuleException" => {
-                    return RusotoError::Service(PutTargetsError::ManagedRule(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutTargetsError::ManagedRule,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutTargetsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutTargetsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3301,20 +3769,38 @@ impl RemovePermissionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(RemovePermissionError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemovePermissionError::ConcurrentModification,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(RemovePermissionError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemovePermissionError::Internal,
+                    )
                 }
                 "OperationDisabledException" => {
-                    return RusotoError::Service(RemovePermissionError::OperationDisabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemovePermissionError::OperationDisabled,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RemovePermissionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemovePermissionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3351,20 +3837,38 @@ impl RemoveTargetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(RemoveTargetsError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveTargetsError::ConcurrentModification,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(RemoveTargetsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveTargetsError::Internal,
+                    )
                 }
                 "ManagedRuleException" => {
-                    return RusotoError::Service(RemoveTargetsError::ManagedRule(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveTargetsError::ManagedRule,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RemoveTargetsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveTargetsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3403,21 +3907,46 @@ impl StartReplayError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(StartReplayError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartReplayError::Internal,
+                    )
                 }
                 "InvalidEventPatternException" => {
-                    return RusotoError::Service(StartReplayError::InvalidEventPattern(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartReplayError::InvalidEventPattern,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartReplayError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartReplayError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(StartReplayError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartReplayError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartReplayError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartReplayError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3455,18 +3984,38 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(TagResourceError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ConcurrentModification,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(TagResourceError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::Internal,
+                    )
                 }
                 "ManagedRuleException" => {
-                    return RusotoError::Service(TagResourceError::ManagedRule(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ManagedRule,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3499,14 +4048,22 @@ impl TestEventPatternError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(TestEventPatternError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestEventPatternError::Internal,
+                    )
                 }
                 "InvalidEventPatternException" => {
-                    return RusotoError::Service(TestEventPatternError::InvalidEventPattern(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TestEventPatternError::InvalidEventPattern,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3541,20 +4098,38 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UntagResourceError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UntagResourceError::ConcurrentModification,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(UntagResourceError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::Internal,
+                    )
                 }
                 "ManagedRuleException" => {
-                    return RusotoError::Service(UntagResourceError::ManagedRule(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ManagedRule,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3593,23 +4168,46 @@ impl UpdateArchiveError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateArchiveError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateArchiveError::ConcurrentModification,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(UpdateArchiveError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateArchiveError::Internal,
+                    )
                 }
                 "InvalidEventPatternException" => {
-                    return RusotoError::Service(UpdateArchiveError::InvalidEventPattern(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateArchiveError::InvalidEventPattern,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateArchiveError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateArchiveError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateArchiveError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateArchiveError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3885,11 +4483,12 @@ pub struct EventBridgeClient {
 impl EventBridgeClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> EventBridgeClient {
         EventBridgeClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "EventBridge"),
         }
     }
 
@@ -3904,12 +4503,32 @@ impl EventBridgeClient {
     {
         EventBridgeClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "EventBridge"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> EventBridgeClient {
-        EventBridgeClient { client, region }
+        EventBridgeClient {
+            client,
+            region: region_for_service(region, "EventBridge"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> EventBridgeClient {
+        EventBridgeClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("EventBridge"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> EventBridgeClient {
+        EventBridgeClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -3921,6 +4540,7 @@ impl EventBridge for EventBridgeClient {
         input: ActivateEventSourceRequest,
     ) -> Result<(), RusotoError<ActivateEventSourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ActivateEventSource");
         request.add_header("x-amz-target", "AWSEvents.ActivateEventSource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3938,6 +4558,7 @@ impl EventBridge for EventBridgeClient {
         input: CancelReplayRequest,
     ) -> Result<CancelReplayResponse, RusotoError<CancelReplayError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CancelReplay");
         request.add_header("x-amz-target", "AWSEvents.CancelReplay");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3956,6 +4577,7 @@ impl EventBridge for EventBridgeClient {
         input: CreateArchiveRequest,
     ) -> Result<CreateArchiveResponse, RusotoError<CreateArchiveError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateArchive");
         request.add_header("x-amz-target", "AWSEvents.CreateArchive");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3974,6 +4596,7 @@ impl EventBridge for EventBridgeClient {
         input: CreateEventBusRequest,
     ) -> Result<CreateEventBusResponse, RusotoError<CreateEventBusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateEventBus");
         request.add_header("x-amz-target", "AWSEvents.CreateEventBus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3992,6 +4615,7 @@ impl EventBridge for EventBridgeClient {
         input: CreatePartnerEventSourceRequest,
     ) -> Result<CreatePartnerEventSourceResponse, RusotoError<CreatePartnerEventSourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreatePartnerEventSource");
         request.add_header("x-amz-target", "AWSEvents.CreatePartnerEventSource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4011,6 +4635,7 @@ impl EventBridge for EventBridgeClient {
         input: DeactivateEventSourceRequest,
     ) -> Result<(), RusotoError<DeactivateEventSourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeactivateEventSource");
         request.add_header("x-amz-target", "AWSEvents.DeactivateEventSource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4028,6 +4653,7 @@ impl EventBridge for EventBridgeClient {
         input: DeleteArchiveRequest,
     ) -> Result<DeleteArchiveResponse, RusotoError<DeleteArchiveError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteArchive");
         request.add_header("x-amz-target", "AWSEvents.DeleteArchive");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4046,6 +4672,7 @@ impl EventBridge for EventBridgeClient {
         input: DeleteEventBusRequest,
     ) -> Result<(), RusotoError<DeleteEventBusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteEventBus");
         request.add_header("x-amz-target", "AWSEvents.DeleteEventBus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4063,6 +4690,7 @@ impl EventBridge for EventBridgeClient {
         input: DeletePartnerEventSourceRequest,
     ) -> Result<(), RusotoError<DeletePartnerEventSourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeletePartnerEventSource");
         request.add_header("x-amz-target", "AWSEvents.DeletePartnerEventSource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4080,6 +4708,7 @@ impl EventBridge for EventBridgeClient {
         input: DeleteRuleRequest,
     ) -> Result<(), RusotoError<DeleteRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteRule");
         request.add_header("x-amz-target", "AWSEvents.DeleteRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4097,6 +4726,7 @@ impl EventBridge for EventBridgeClient {
         input: DescribeArchiveRequest,
     ) -> Result<DescribeArchiveResponse, RusotoError<DescribeArchiveError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeArchive");
         request.add_header("x-amz-target", "AWSEvents.DescribeArchive");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4115,6 +4745,7 @@ impl EventBridge for EventBridgeClient {
         input: DescribeEventBusRequest,
     ) -> Result<DescribeEventBusResponse, RusotoError<DescribeEventBusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeEventBus");
         request.add_header("x-amz-target", "AWSEvents.DescribeEventBus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4133,6 +4764,7 @@ impl EventBridge for EventBridgeClient {
         input: DescribeEventSourceRequest,
     ) -> Result<DescribeEventSourceResponse, RusotoError<DescribeEventSourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeEventSource");
         request.add_header("x-amz-target", "AWSEvents.DescribeEventSource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4152,6 +4784,7 @@ impl EventBridge for EventBridgeClient {
     ) -> Result<DescribePartnerEventSourceResponse, RusotoError<DescribePartnerEventSourceError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribePartnerEventSource");
         request.add_header("x-amz-target", "AWSEvents.DescribePartnerEventSource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4171,6 +4804,7 @@ impl EventBridge for EventBridgeClient {
         input: DescribeReplayRequest,
     ) -> Result<DescribeReplayResponse, RusotoError<DescribeReplayError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeReplay");
         request.add_header("x-amz-target", "AWSEvents.DescribeReplay");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4189,6 +4823,7 @@ impl EventBridge for EventBridgeClient {
         input: DescribeRuleRequest,
     ) -> Result<DescribeRuleResponse, RusotoError<DescribeRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeRule");
         request.add_header("x-amz-target", "AWSEvents.DescribeRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4207,6 +4842,7 @@ impl EventBridge for EventBridgeClient {
         input: DisableRuleRequest,
     ) -> Result<(), RusotoError<DisableRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisableRule");
         request.add_header("x-amz-target", "AWSEvents.DisableRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4224,6 +4860,7 @@ impl EventBridge for EventBridgeClient {
         input: EnableRuleRequest,
     ) -> Result<(), RusotoError<EnableRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("EnableRule");
         request.add_header("x-amz-target", "AWSEvents.EnableRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4241,6 +4878,7 @@ impl EventBridge for EventBridgeClient {
         input: ListArchivesRequest,
     ) -> Result<ListArchivesResponse, RusotoError<ListArchivesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListArchives");
         request.add_header("x-amz-target", "AWSEvents.ListArchives");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4259,6 +4897,7 @@ impl EventBridge for EventBridgeClient {
         input: ListEventBusesRequest,
     ) -> Result<ListEventBusesResponse, RusotoError<ListEventBusesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListEventBuses");
         request.add_header("x-amz-target", "AWSEvents.ListEventBuses");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4277,6 +4916,7 @@ impl EventBridge for EventBridgeClient {
         input: ListEventSourcesRequest,
     ) -> Result<ListEventSourcesResponse, RusotoError<ListEventSourcesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListEventSources");
         request.add_header("x-amz-target", "AWSEvents.ListEventSources");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4298,6 +4938,7 @@ impl EventBridge for EventBridgeClient {
         RusotoError<ListPartnerEventSourceAccountsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListPartnerEventSourceAccounts");
         request.add_header("x-amz-target", "AWSEvents.ListPartnerEventSourceAccounts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4317,6 +4958,7 @@ impl EventBridge for EventBridgeClient {
         input: ListPartnerEventSourcesRequest,
     ) -> Result<ListPartnerEventSourcesResponse, RusotoError<ListPartnerEventSourcesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListPartnerEventSources");
         request.add_header("x-amz-target", "AWSEvents.ListPartnerEventSources");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4336,6 +4978,7 @@ impl EventBridge for EventBridgeClient {
         input: ListReplaysRequest,
     ) -> Result<ListReplaysResponse, RusotoError<ListReplaysError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListReplays");
         request.add_header("x-amz-target", "AWSEvents.ListReplays");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4354,6 +4997,7 @@ impl EventBridge for EventBridgeClient {
         input: ListRuleNamesByTargetRequest,
     ) -> Result<ListRuleNamesByTargetResponse, RusotoError<ListRuleNamesByTargetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListRuleNamesByTarget");
         request.add_header("x-amz-target", "AWSEvents.ListRuleNamesByTarget");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4373,6 +5017,7 @@ impl EventBridge for EventBridgeClient {
         input: ListRulesRequest,
     ) -> Result<ListRulesResponse, RusotoError<ListRulesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListRules");
         request.add_header("x-amz-target", "AWSEvents.ListRules");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4391,6 +5036,7 @@ impl EventBridge for EventBridgeClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header("x-amz-target", "AWSEvents.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4409,6 +5055,7 @@ impl EventBridge for EventBridgeClient {
         input: ListTargetsByRuleRequest,
     ) -> Result<ListTargetsByRuleResponse, RusotoError<ListTargetsByRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTargetsByRule");
         request.add_header("x-amz-target", "AWSEvents.ListTargetsByRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4427,6 +5074,7 @@ impl EventBridge for EventBridgeClient {
         input: PutEventsRequest,
     ) -> Result<PutEventsResponse, RusotoError<PutEventsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutEvents");
         request.add_header("x-amz-target", "AWSEvents.PutEvents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4445,6 +5093,7 @@ impl EventBridge for EventBridgeClient {
         input: PutPartnerEventsRequest,
     ) -> Result<PutPartnerEventsResponse, RusotoError<PutPartnerEventsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutPartnerEvents");
         request.add_header("x-amz-target", "AWSEvents.PutPartnerEvents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4463,6 +5112,7 @@ impl EventBridge for EventBridgeClient {
         input: PutPermissionRequest,
     ) -> Result<(), RusotoError<PutPermissionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutPermission");
         request.add_header("x-amz-target", "AWSEvents.PutPermission");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4480,6 +5130,7 @@ impl EventBridge for EventBridgeClient {
         input: PutRuleRequest,
     ) -> Result<PutRuleResponse, RusotoError<PutRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutRule");
         request.add_header("x-amz-target", "AWSEvents.PutRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4498,6 +5149,7 @@ impl EventBridge for EventBridgeClient {
         input: PutTargetsRequest,
     ) -> Result<PutTargetsResponse, RusotoError<PutTargetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutTargets");
         request.add_header("x-amz-target", "AWSEvents.PutTargets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4516,6 +5168,7 @@ impl EventBridge for EventBridgeClient {
         input: RemovePermissionRequest,
     ) -> Result<(), RusotoError<RemovePermissionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RemovePermission");
         request.add_header("x-amz-target", "AWSEvents.RemovePermission");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4533,6 +5186,7 @@ impl EventBridge for EventBridgeClient {
         input: RemoveTargetsRequest,
     ) -> Result<RemoveTargetsResponse, RusotoError<RemoveTargetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RemoveTargets");
         request.add_header("x-amz-target", "AWSEvents.RemoveTargets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4551,6 +5205,7 @@ impl EventBridge for EventBridgeClient {
         input: StartReplayRequest,
     ) -> Result<StartReplayResponse, RusotoError<StartReplayError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartReplay");
         request.add_header("x-amz-target", "AWSEvents.StartReplay");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4569,6 +5224,7 @@ impl EventBridge for EventBridgeClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "AWSEvents.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4587,6 +5243,7 @@ impl EventBridge for EventBridgeClient {
         input: TestEventPatternRequest,
     ) -> Result<TestEventPatternResponse, RusotoError<TestEventPatternError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TestEventPattern");
         request.add_header("x-amz-target", "AWSEvents.TestEventPattern");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4605,6 +5262,7 @@ impl EventBridge for EventBridgeClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "AWSEvents.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4623,6 +5281,7 @@ impl EventBridge for EventBridgeClient {
         input: UpdateArchiveRequest,
     ) -> Result<UpdateArchiveResponse, RusotoError<UpdateArchiveError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateArchive");
         request.add_header("x-amz-target", "AWSEvents.UpdateArchive");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4635,3 +5294,413 @@ impl EventBridge for EventBridgeClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateArchiveResponse, _>()
     }
 }
+
+/// A blocking client for the Amazon EventBridge API, running the calls of a `EventBridgeClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `EventBridgeClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct EventBridgeBlockingClient {
+    client: EventBridgeClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl EventBridgeBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> EventBridgeBlockingClient {
+        EventBridgeBlockingClient::from_client(EventBridgeClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> EventBridgeBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        EventBridgeBlockingClient::from_client(EventBridgeClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> EventBridgeBlockingClient {
+        EventBridgeBlockingClient::from_client(EventBridgeClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> EventBridgeBlockingClient {
+        EventBridgeBlockingClient::from_client(EventBridgeClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: EventBridgeClient) -> EventBridgeBlockingClient {
+        EventBridgeBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> EventBridgeBlockingClient {
+        EventBridgeBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> EventBridgeBlockingClient {
+        EventBridgeBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`EventBridge::activate_event_source`].
+    pub fn activate_event_source(
+        &self,
+        input: ActivateEventSourceRequest,
+    ) -> Result<(), RusotoError<ActivateEventSourceError>> {
+        self.runtime
+            .block_on(self.client.activate_event_source(input))
+    }
+
+    /// Blocking version of [`EventBridge::cancel_replay`].
+    pub fn cancel_replay(
+        &self,
+        input: CancelReplayRequest,
+    ) -> Result<CancelReplayResponse, RusotoError<CancelReplayError>> {
+        self.runtime.block_on(self.client.cancel_replay(input))
+    }
+
+    /// Blocking version of [`EventBridge::create_archive`].
+    pub fn create_archive(
+        &self,
+        input: CreateArchiveRequest,
+    ) -> Result<CreateArchiveResponse, RusotoError<CreateArchiveError>> {
+        self.runtime.block_on(self.client.create_archive(input))
+    }
+
+    /// Blocking version of [`EventBridge::create_event_bus`].
+    pub fn create_event_bus(
+        &self,
+        input: CreateEventBusRequest,
+    ) -> Result<CreateEventBusResponse, RusotoError<CreateEventBusError>> {
+        self.runtime.block_on(self.client.create_event_bus(input))
+    }
+
+    /// Blocking version of [`EventBridge::create_partner_event_source`].
+    pub fn create_partner_event_source(
+        &self,
+        input: CreatePartnerEventSourceRequest,
+    ) -> Result<CreatePartnerEventSourceResponse, RusotoError<CreatePartnerEventSourceError>> {
+        self.runtime
+            .block_on(self.client.create_partner_event_source(input))
+    }
+
+    /// Blocking version of [`EventBridge::deactivate_event_source`].
+    pub fn deactivate_event_source(
+        &self,
+        input: DeactivateEventSourceRequest,
+    ) -> Result<(), RusotoError<DeactivateEventSourceError>> {
+        self.runtime
+            .block_on(self.client.deactivate_event_source(input))
+    }
+
+    /// Blocking version of [`EventBridge::delete_archive`].
+    pub fn delete_archive(
+        &self,
+        input: DeleteArchiveRequest,
+    ) -> Result<DeleteArchiveResponse, RusotoError<DeleteArchiveError>> {
+        self.runtime.block_on(self.client.delete_archive(input))
+    }
+
+    /// Blocking version of [`EventBridge::delete_event_bus`].
+    pub fn delete_event_bus(
+        &self,
+        input: DeleteEventBusRequest,
+    ) -> Result<(), RusotoError<DeleteEventBusError>> {
+        self.runtime.block_on(self.client.delete_event_bus(input))
+    }
+
+    /// Blocking version of [`EventBridge::delete_partner_event_source`].
+    pub fn delete_partner_event_source(
+        &self,
+        input: DeletePartnerEventSourceRequest,
+    ) -> Result<(), RusotoError<DeletePartnerEventSourceError>> {
+        self.runtime
+            .block_on(self.client.delete_partner_event_source(input))
+    }
+
+    /// Blocking version of [`EventBridge::delete_rule`].
+    pub fn delete_rule(
+        &self,
+        input: DeleteRuleRequest,
+    ) -> Result<(), RusotoError<DeleteRuleError>> {
+        self.runtime.block_on(self.client.delete_rule(input))
+    }
+
+    /// Blocking version of [`EventBridge::describe_archive`].
+    pub fn describe_archive(
+        &self,
+        input: DescribeArchiveRequest,
+    ) -> Result<DescribeArchiveResponse, RusotoError<DescribeArchiveError>> {
+        self.runtime.block_on(self.client.describe_archive(input))
+    }
+
+    /// Blocking version of [`EventBridge::describe_event_bus`].
+    pub fn describe_event_bus(
+        &self,
+        input: DescribeEventBusRequest,
+    ) -> Result<DescribeEventBusResponse, RusotoError<DescribeEventBusError>> {
+        self.runtime.block_on(self.client.describe_event_bus(input))
+    }
+
+    /// Blocking version of [`EventBridge::describe_event_source`].
+    pub fn describe_event_source(
+        &self,
+        input: DescribeEventSourceRequest,
+    ) -> Result<DescribeEventSourceResponse, RusotoError<DescribeEventSourceError>> {
+        self.runtime
+            .block_on(self.client.describe_event_source(input))
+    }
+
+    /// Blocking version of [`EventBridge::describe_partner_event_source`].
+    pub fn describe_partner_event_source(
+        &self,
+        input: DescribePartnerEventSourceRequest,
+    ) -> Result<DescribePartnerEventSourceResponse, RusotoError<DescribePartnerEventSourceError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_partner_event_source(input))
+    }
+
+    /// Blocking version of [`EventBridge::describe_replay`].
+    pub fn describe_replay(
+        &self,
+        input: DescribeReplayRequest,
+    ) -> Result<DescribeReplayResponse, RusotoError<DescribeReplayError>> {
+        self.runtime.block_on(self.client.describe_replay(input))
+    }
+
+    /// Blocking version of [`EventBridge::describe_rule`].
+    pub fn describe_rule(
+        &self,
+        input: DescribeRuleRequest,
+    ) -> Result<DescribeRuleResponse, RusotoError<DescribeRuleError>> {
+        self.runtime.block_on(self.client.describe_rule(input))
+    }
+
+    /// Blocking version of [`EventBridge::disable_rule`].
+    pub fn disable_rule(
+        &self,
+        input: DisableRuleRequest,
+    ) -> Result<(), RusotoError<DisableRuleError>> {
+        self.runtime.block_on(self.client.disable_rule(input))
+    }
+
+    /// Blocking version of [`EventBridge::enable_rule`].
+    pub fn enable_rule(
+        &self,
+        input: EnableRuleRequest,
+    ) -> Result<(), RusotoError<EnableRuleError>> {
+        self.runtime.block_on(self.client.enable_rule(input))
+    }
+
+    /// Blocking version of [`EventBridge::list_archives`].
+    pub fn list_archives(
+        &self,
+        input: ListArchivesRequest,
+    ) -> Result<ListArchivesResponse, RusotoError<ListArchivesError>> {
+        self.runtime.block_on(self.client.list_archives(input))
+    }
+
+    /// Blocking version of [`EventBridge::list_event_buses`].
+    pub fn list_event_buses(
+        &self,
+        input: ListEventBusesRequest,
+    ) -> Result<ListEventBusesResponse, RusotoError<ListEventBusesError>> {
+        self.runtime.block_on(self.client.list_event_buses(input))
+    }
+
+    /// Blocking version of [`EventBridge::list_event_sources`].
+    pub fn list_event_sources(
+        &self,
+        input: ListEventSourcesRequest,
+    ) -> Result<ListEventSourcesResponse, RusotoError<ListEventSourcesError>> {
+        self.runtime.block_on(self.client.list_event_sources(input))
+    }
+
+    /// Blocking version of [`EventBridge::list_partner_event_source_accounts`].
+    pub fn list_partner_event_source_accounts(
+        &self,
+        input: ListPartnerEventSourceAccountsRequest,
+    ) -> Result<
+        ListPartnerEventSourceAccountsResponse,
+        RusotoError<ListPartnerEventSourceAccountsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_partner_event_source_accounts(input))
+    }
+
+    /// Blocking version of [`EventBridge::list_partner_event_sources`].
+    pub fn list_partner_event_sources(
+        &self,
+        input: ListPartnerEventSourcesRequest,
+    ) -> Result<ListPartnerEventSourcesResponse, RusotoError<ListPartnerEventSourcesError>> {
+        self.runtime
+            .block_on(self.client.list_partner_event_sources(input))
+    }
+
+    /// Blocking version of [`EventBridge::list_replays`].
+    pub fn list_replays(
+        &self,
+        input: ListReplaysRequest,
+    ) -> Result<ListReplaysResponse, RusotoError<ListReplaysError>> {
+        self.runtime.block_on(self.client.list_replays(input))
+    }
+
+    /// Blocking version of [`EventBridge::list_rule_names_by_target`].
+    pub fn list_rule_names_by_target(
+        &self,
+        input: ListRuleNamesByTargetRequest,
+    ) -> Result<ListRuleNamesByTargetResponse, RusotoError<ListRuleNamesByTargetError>> {
+        self.runtime
+            .block_on(self.client.list_rule_names_by_target(input))
+    }
+
+    /// Blocking version of [`EventBridge::list_rules`].
+    pub fn list_rules(
+        &self,
+        input: ListRulesRequest,
+    ) -> Result<ListRulesResponse, RusotoError<ListRulesError>> {
+        self.runtime.block_on(self.client.list_rules(input))
+    }
+
+    /// Blocking version of [`EventBridge::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`EventBridge::list_targets_by_rule`].
+    pub fn list_targets_by_rule(
+        &self,
+        input: ListTargetsByRuleRequest,
+    ) -> Result<ListTargetsByRuleResponse, RusotoError<ListTargetsByRuleError>> {
+        self.runtime
+            .block_on(self.client.list_targets_by_rule(input))
+    }
+
+    /// Blocking version of [`EventBridge::put_events`].
+    pub fn put_events(
+        &self,
+        input: PutEventsRequest,
+    ) -> Result<PutEventsResponse, RusotoError<PutEventsError>> {
+        self.runtime.block_on(self.client.put_events(input))
+    }
+
+    /// Blocking version of [`EventBridge::put_partner_events`].
+    pub fn put_partner_events(
+        &self,
+        input: PutPartnerEventsRequest,
+    ) -> Result<PutPartnerEventsResponse, RusotoError<PutPartnerEventsError>> {
+        self.runtime.block_on(self.client.put_partner_events(input))
+    }
+
+    /// Blocking version of [`EventBridge::put_permission`].
+    pub fn put_permission(
+        &self,
+        input: PutPermissionRequest,
+    ) -> Result<(), RusotoError<PutPermissionError>> {
+        self.runtime.block_on(self.client.put_permission(input))
+    }
+
+    /// Blocking version of [`EventBridge::put_rule`].
+    pub fn put_rule(
+        &self,
+        input: PutRuleRequest,
+    ) -> Result<PutRuleResponse, RusotoError<PutRuleError>> {
+        self.runtime.block_on(self.client.put_rule(input))
+    }
+
+    /// Blocking version of [`EventBridge::put_targets`].
+    pub fn put_targets(
+        &self,
+        input: PutTargetsRequest,
+    ) -> Result<PutTargetsResponse, RusotoError<PutTargetsError>> {
+        self.runtime.block_on(self.client.put_targets(input))
+    }
+
+    /// Blocking version of [`EventBridge::remove_permission`].
+    pub fn remove_permission(
+        &self,
+        input: RemovePermissionRequest,
+    ) -> Result<(), RusotoError<RemovePermissionError>> {
+        self.runtime.block_on(self.client.remove_permission(input))
+    }
+
+    /// Blocking version of [`EventBridge::remove_targets`].
+    pub fn remove_targets(
+        &self,
+        input: RemoveTargetsRequest,
+    ) -> Result<RemoveTargetsResponse, RusotoError<RemoveTargetsError>> {
+        self.runtime.block_on(self.client.remove_targets(input))
+    }
+
+    /// Blocking version of [`EventBridge::start_replay`].
+    pub fn start_replay(
+        &self,
+        input: StartReplayRequest,
+    ) -> Result<StartReplayResponse, RusotoError<StartReplayError>> {
+        self.runtime.block_on(self.client.start_replay(input))
+    }
+
+    /// Blocking version of [`EventBridge::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`EventBridge::test_event_pattern`].
+    pub fn test_event_pattern(
+        &self,
+        input: TestEventPatternRequest,
+    ) -> Result<TestEventPatternResponse, RusotoError<TestEventPatternError>> {
+        self.runtime.block_on(self.client.test_event_pattern(input))
+    }
+
+    /// Blocking version of [`EventBridge::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`EventBridge::update_archive`].
+    pub fn update_archive(
+        &self,
+        input: UpdateArchiveRequest,
+    ) -> Result<UpdateArchiveResponse, RusotoError<UpdateArchiveError>> {
+        self.runtime.block_on(self.client.update_archive(input))
+    }
+}
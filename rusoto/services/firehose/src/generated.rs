@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1818,22 +1819,38 @@ impl CreateDeliveryStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(CreateDeliveryStreamError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDeliveryStreamError::InvalidArgument,
+                    )
                 }
                 "InvalidKMSResourceException" => {
-                    return RusotoError::Service(CreateDeliveryStreamError::InvalidKMSResource(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDeliveryStreamError::InvalidKMSResource,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDeliveryStreamError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeliveryStreamError::LimitExceeded,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateDeliveryStreamError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeliveryStreamError::ResourceInUse,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1866,14 +1883,22 @@ impl DeleteDeliveryStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteDeliveryStreamError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDeliveryStreamError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDeliveryStreamError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDeliveryStreamError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1902,11 +1927,14 @@ impl DescribeDeliveryStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDeliveryStreamError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDeliveryStreamError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1930,7 +1958,7 @@ impl ListDeliveryStreamsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListDeliveryStreamsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1960,21 +1988,30 @@ impl ListTagsForDeliveryStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListTagsForDeliveryStreamError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForDeliveryStreamError::InvalidArgument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListTagsForDeliveryStreamError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForDeliveryStreamError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForDeliveryStreamError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForDeliveryStreamError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2010,18 +2047,38 @@ impl PutRecordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(PutRecordError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRecordError::InvalidArgument,
+                    )
                 }
                 "InvalidKMSResourceException" => {
-                    return RusotoError::Service(PutRecordError::InvalidKMSResource(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRecordError::InvalidKMSResource,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutRecordError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRecordError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutRecordError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRecordError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2058,18 +2115,38 @@ impl PutRecordBatchError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(PutRecordBatchError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRecordBatchError::InvalidArgument,
+                    )
                 }
                 "InvalidKMSResourceException" => {
-                    return RusotoError::Service(PutRecordBatchError::InvalidKMSResource(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRecordBatchError::InvalidKMSResource,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutRecordBatchError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRecordBatchError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutRecordBatchError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRecordBatchError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2110,31 +2187,46 @@ impl StartDeliveryStreamEncryptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
-                        StartDeliveryStreamEncryptionError::InvalidArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDeliveryStreamEncryptionError::InvalidArgument,
                     )
                 }
                 "InvalidKMSResourceException" => {
-                    return RusotoError::Service(
-                        StartDeliveryStreamEncryptionError::InvalidKMSResource(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDeliveryStreamEncryptionError::InvalidKMSResource,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartDeliveryStreamEncryptionError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartDeliveryStreamEncryptionError::LimitExceeded,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StartDeliveryStreamEncryptionError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartDeliveryStreamEncryptionError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        StartDeliveryStreamEncryptionError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDeliveryStreamEncryptionError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2180,26 +2272,38 @@ impl StopDeliveryStreamEncryptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
-                        StopDeliveryStreamEncryptionError::InvalidArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopDeliveryStreamEncryptionError::InvalidArgument,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StopDeliveryStreamEncryptionError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopDeliveryStreamEncryptionError::LimitExceeded,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StopDeliveryStreamEncryptionError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopDeliveryStreamEncryptionError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        StopDeliveryStreamEncryptionError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopDeliveryStreamEncryptionError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2238,18 +2342,38 @@ impl TagDeliveryStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(TagDeliveryStreamError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagDeliveryStreamError::InvalidArgument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagDeliveryStreamError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagDeliveryStreamError::LimitExceeded,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(TagDeliveryStreamError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagDeliveryStreamError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagDeliveryStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagDeliveryStreamError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2286,20 +2410,38 @@ impl UntagDeliveryStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UntagDeliveryStreamError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagDeliveryStreamError::InvalidArgument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UntagDeliveryStreamError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagDeliveryStreamError::LimitExceeded,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UntagDeliveryStreamError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagDeliveryStreamError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagDeliveryStreamError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UntagDeliveryStreamError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2336,20 +2478,38 @@ impl UpdateDestinationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateDestinationError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDestinationError::ConcurrentModification,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UpdateDestinationError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDestinationError::InvalidArgument,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateDestinationError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDestinationError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDestinationError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDestinationError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2453,11 +2613,12 @@ pub struct KinesisFirehoseClient {
 impl KinesisFirehoseClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> KinesisFirehoseClient {
         KinesisFirehoseClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Firehose"),
         }
     }
 
@@ -2472,12 +2633,32 @@ impl KinesisFirehoseClient {
     {
         KinesisFirehoseClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Firehose"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> KinesisFirehoseClient {
-        KinesisFirehoseClient { client, region }
+        KinesisFirehoseClient {
+            client,
+            region: region_for_service(region, "Firehose"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> KinesisFirehoseClient {
+        KinesisFirehoseClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Firehose"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> KinesisFirehoseClient {
+        KinesisFirehoseClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -2489,6 +2670,7 @@ impl KinesisFirehose for KinesisFirehoseClient {
         input: CreateDeliveryStreamInput,
     ) -> Result<CreateDeliveryStreamOutput, RusotoError<CreateDeliveryStreamError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateDeliveryStream");
         request.add_header("x-amz-target", "Firehose_20150804.CreateDeliveryStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2507,6 +2689,7 @@ impl KinesisFirehose for KinesisFirehoseClient {
         input: DeleteDeliveryStreamInput,
     ) -> Result<DeleteDeliveryStreamOutput, RusotoError<DeleteDeliveryStreamError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteDeliveryStream");
         request.add_header("x-amz-target", "Firehose_20150804.DeleteDeliveryStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2525,6 +2708,7 @@ impl KinesisFirehose for KinesisFirehoseClient {
         input: DescribeDeliveryStreamInput,
     ) -> Result<DescribeDeliveryStreamOutput, RusotoError<DescribeDeliveryStreamError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeDeliveryStream");
         request.add_header("x-amz-target", "Firehose_20150804.DescribeDeliveryStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2544,6 +2728,7 @@ impl KinesisFirehose for KinesisFirehoseClient {
         input: ListDeliveryStreamsInput,
     ) -> Result<ListDeliveryStreamsOutput, RusotoError<ListDeliveryStreamsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListDeliveryStreams");
         request.add_header("x-amz-target", "Firehose_20150804.ListDeliveryStreams");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2562,6 +2747,7 @@ impl KinesisFirehose for KinesisFirehoseClient {
         input: ListTagsForDeliveryStreamInput,
     ) -> Result<ListTagsForDeliveryStreamOutput, RusotoError<ListTagsForDeliveryStreamError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForDeliveryStream");
         request.add_header(
             "x-amz-target",
             "Firehose_20150804.ListTagsForDeliveryStream",
@@ -2584,6 +2770,7 @@ impl KinesisFirehose for KinesisFirehoseClient {
         input: PutRecordInput,
     ) -> Result<PutRecordOutput, RusotoError<PutRecordError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutRecord");
         request.add_header("x-amz-target", "Firehose_20150804.PutRecord");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2602,6 +2789,7 @@ impl KinesisFirehose for KinesisFirehoseClient {
         input: PutRecordBatchInput,
     ) -> Result<PutRecordBatchOutput, RusotoError<PutRecordBatchError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutRecordBatch");
         request.add_header("x-amz-target", "Firehose_20150804.PutRecordBatch");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2621,6 +2809,7 @@ impl KinesisFirehose for KinesisFirehoseClient {
     ) -> Result<StartDeliveryStreamEncryptionOutput, RusotoError<StartDeliveryStreamEncryptionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartDeliveryStreamEncryption");
         request.add_header(
             "x-amz-target",
             "Firehose_20150804.StartDeliveryStreamEncryption",
@@ -2644,6 +2833,7 @@ impl KinesisFirehose for KinesisFirehoseClient {
     ) -> Result<StopDeliveryStreamEncryptionOutput, RusotoError<StopDeliveryStreamEncryptionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopDeliveryStreamEncryption");
         request.add_header(
             "x-amz-target",
             "Firehose_20150804.StopDeliveryStreamEncryption",
@@ -2666,6 +2856,7 @@ impl KinesisFirehose for KinesisFirehoseClient {
         input: TagDeliveryStreamInput,
     ) -> Result<TagDeliveryStreamOutput, RusotoError<TagDeliveryStreamError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagDeliveryStream");
         request.add_header("x-amz-target", "Firehose_20150804.TagDeliveryStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2684,6 +2875,7 @@ impl KinesisFirehose for KinesisFirehoseClient {
         input: UntagDeliveryStreamInput,
     ) -> Result<UntagDeliveryStreamOutput, RusotoError<UntagDeliveryStreamError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagDeliveryStream");
         request.add_header("x-amz-target", "Firehose_20150804.UntagDeliveryStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2702,6 +2894,7 @@ impl KinesisFirehose for KinesisFirehoseClient {
         input: UpdateDestinationInput,
     ) -> Result<UpdateDestinationOutput, RusotoError<UpdateDestinationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateDestination");
         request.add_header("x-amz-target", "Firehose_20150804.UpdateDestination");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2714,3 +2907,193 @@ impl KinesisFirehose for KinesisFirehoseClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateDestinationOutput, _>()
     }
 }
+
+/// A blocking client for the Firehose API, running the calls of a `KinesisFirehoseClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `KinesisFirehoseClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct KinesisFirehoseBlockingClient {
+    client: KinesisFirehoseClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl KinesisFirehoseBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> KinesisFirehoseBlockingClient {
+        KinesisFirehoseBlockingClient::from_client(KinesisFirehoseClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> KinesisFirehoseBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        KinesisFirehoseBlockingClient::from_client(KinesisFirehoseClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> KinesisFirehoseBlockingClient {
+        KinesisFirehoseBlockingClient::from_client(KinesisFirehoseClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> KinesisFirehoseBlockingClient {
+        KinesisFirehoseBlockingClient::from_client(KinesisFirehoseClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: KinesisFirehoseClient) -> KinesisFirehoseBlockingClient {
+        KinesisFirehoseBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> KinesisFirehoseBlockingClient {
+        KinesisFirehoseBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> KinesisFirehoseBlockingClient {
+        KinesisFirehoseBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`KinesisFirehose::create_delivery_stream`].
+    pub fn create_delivery_stream(
+        &self,
+        input: CreateDeliveryStreamInput,
+    ) -> Result<CreateDeliveryStreamOutput, RusotoError<CreateDeliveryStreamError>> {
+        self.runtime
+            .block_on(self.client.create_delivery_stream(input))
+    }
+
+    /// Blocking version of [`KinesisFirehose::delete_delivery_stream`].
+    pub fn delete_delivery_stream(
+        &self,
+        input: DeleteDeliveryStreamInput,
+    ) -> Result<DeleteDeliveryStreamOutput, RusotoError<DeleteDeliveryStreamError>> {
+        self.runtime
+            .block_on(self.client.delete_delivery_stream(input))
+    }
+
+    /// Blocking version of [`KinesisFirehose::describe_delivery_stream`].
+    pub fn describe_delivery_stream(
+        &self,
+        input: DescribeDeliveryStreamInput,
+    ) -> Result<DescribeDeliveryStreamOutput, RusotoError<DescribeDeliveryStreamError>> {
+        self.runtime
+            .block_on(self.client.describe_delivery_stream(input))
+    }
+
+    /// Blocking version of [`KinesisFirehose::list_delivery_streams`].
+    pub fn list_delivery_streams(
+        &self,
+        input: ListDeliveryStreamsInput,
+    ) -> Result<ListDeliveryStreamsOutput, RusotoError<ListDeliveryStreamsError>> {
+        self.runtime
+            .block_on(self.client.list_delivery_streams(input))
+    }
+
+    /// Blocking version of [`KinesisFirehose::list_tags_for_delivery_stream`].
+    pub fn list_tags_for_delivery_stream(
+        &self,
+        input: ListTagsForDeliveryStreamInput,
+    ) -> Result<ListTagsForDeliveryStreamOutput, RusotoError<ListTagsForDeliveryStreamError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_delivery_stream(input))
+    }
+
+    /// Blocking version of [`KinesisFirehose::put_record`].
+    pub fn put_record(
+        &self,
+        input: PutRecordInput,
+    ) -> Result<PutRecordOutput, RusotoError<PutRecordError>> {
+        self.runtime.block_on(self.client.put_record(input))
+    }
+
+    /// Blocking version of [`KinesisFirehose::put_record_batch`].
+    pub fn put_record_batch(
+        &self,
+        input: PutRecordBatchInput,
+    ) -> Result<PutRecordBatchOutput, RusotoError<PutRecordBatchError>> {
+        self.runtime.block_on(self.client.put_record_batch(input))
+    }
+
+    /// Blocking version of [`KinesisFirehose::start_delivery_stream_encryption`].
+    pub fn start_delivery_stream_encryption(
+        &self,
+        input: StartDeliveryStreamEncryptionInput,
+    ) -> Result<StartDeliveryStreamEncryptionOutput, RusotoError<StartDeliveryStreamEncryptionError>>
+    {
+        self.runtime
+            .block_on(self.client.start_delivery_stream_encryption(input))
+    }
+
+    /// Blocking version of [`KinesisFirehose::stop_delivery_stream_encryption`].
+    pub fn stop_delivery_stream_encryption(
+        &self,
+        input: StopDeliveryStreamEncryptionInput,
+    ) -> Result<StopDeliveryStreamEncryptionOutput, RusotoError<StopDeliveryStreamEncryptionError>>
+    {
+        self.runtime
+            .block_on(self.client.stop_delivery_stream_encryption(input))
+    }
+
+    /// Blocking version of [`KinesisFirehose::tag_delivery_stream`].
+    pub fn tag_delivery_stream(
+        &self,
+        input: TagDeliveryStreamInput,
+    ) -> Result<TagDeliveryStreamOutput, RusotoError<TagDeliveryStreamError>> {
+        self.runtime
+            .block_on(self.client.tag_delivery_stream(input))
+    }
+
+    /// Blocking version of [`KinesisFirehose::untag_delivery_stream`].
+    pub fn untag_delivery_stream(
+        &self,
+        input: UntagDeliveryStreamInput,
+    ) -> Result<UntagDeliveryStreamOutput, RusotoError<UntagDeliveryStreamError>> {
+        self.runtime
+            .block_on(self.client.untag_delivery_stream(input))
+    }
+
+    /// Blocking version of [`KinesisFirehose::update_destination`].
+    pub fn update_destination(
+        &self,
+        input: UpdateDestinationInput,
+    ) -> Result<UpdateDestinationOutput, RusotoError<UpdateDestinationError>> {
+        self.runtime.block_on(self.client.update_destination(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1244,22 +1245,38 @@ impl AssociateAdminAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(AssociateAdminAccountError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateAdminAccountError::InternalError,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AssociateAdminAccountError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateAdminAccountError::InvalidInput,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(AssociateAdminAccountError::InvalidOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateAdminAccountError::InvalidOperation,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateAdminAccountError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateAdminAccountError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1294,15 +1311,30 @@ impl DeleteAppsListError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteAppsListError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppsListError::InternalError,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(DeleteAppsListError::InvalidOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppsListError::InvalidOperation,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteAppsListError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppsListError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1336,21 +1368,30 @@ impl DeleteNotificationChannelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteNotificationChannelError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteNotificationChannelError::InternalError,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(DeleteNotificationChannelError::InvalidOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteNotificationChannelError::InvalidOperation,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteNotificationChannelError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteNotificationChannelError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1388,21 +1429,46 @@ impl DeletePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeletePolicyError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePolicyError::InternalError,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeletePolicyError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePolicyError::InvalidInput,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(DeletePolicyError::InvalidOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePolicyError::InvalidOperation,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeletePolicyError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePolicyError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeletePolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePolicyError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1438,19 +1504,30 @@ impl DeleteProtocolsListError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DeleteProtocolsListError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProtocolsListError::InternalError,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(DeleteProtocolsListError::InvalidOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteProtocolsListError::InvalidOperation,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteProtocolsListError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteProtocolsListError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1484,21 +1561,30 @@ impl DisassociateAdminAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(DisassociateAdminAccountError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateAdminAccountError::InternalError,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(DisassociateAdminAccountError::InvalidOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateAdminAccountError::InvalidOperation,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateAdminAccountError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateAdminAccountError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1532,15 +1618,30 @@ impl GetAdminAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetAdminAccountError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAdminAccountError::InternalError,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(GetAdminAccountError::InvalidOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAdminAccountError::InvalidOperation,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetAdminAccountError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAdminAccountError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1574,15 +1675,30 @@ impl GetAppsListError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetAppsListError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAppsListError::InternalError,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(GetAppsListError::InvalidOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAppsListError::InvalidOperation,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetAppsListError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAppsListError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1618,22 +1734,38 @@ impl GetComplianceDetailError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetComplianceDetailError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetComplianceDetailError::InternalError,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetComplianceDetailError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetComplianceDetailError::InvalidInput,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(GetComplianceDetailError::InvalidOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetComplianceDetailError::InvalidOperation,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetComplianceDetailError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetComplianceDetailError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1668,21 +1800,30 @@ impl GetNotificationChannelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetNotificationChannelError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetNotificationChannelError::InternalError,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(GetNotificationChannelError::InvalidOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetNotificationChannelError::InvalidOperation,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetNotificationChannelError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetNotificationChannelError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1718,18 +1859,38 @@ impl GetPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetPolicyError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPolicyError::InternalError,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(GetPolicyError::InvalidOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPolicyError::InvalidOperation,
+                    )
                 }
                 "InvalidTypeException" => {
-                    return RusotoError::Service(GetPolicyError::InvalidType(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPolicyError::InvalidType,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetPolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPolicyError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1764,17 +1925,30 @@ impl GetProtectionStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetProtectionStatusError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetProtectionStatusError::InternalError,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetProtectionStatusError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetProtectionStatusError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetProtectionStatusError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetProtectionStatusError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1808,15 +1982,30 @@ impl GetProtocolsListError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetProtocolsListError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetProtocolsListError::InternalError,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(GetProtocolsListError::InvalidOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetProtocolsListError::InvalidOperation,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetProtocolsListError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetProtocolsListError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1850,17 +2039,30 @@ impl GetViolationDetailsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetViolationDetailsError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetViolationDetailsError::InternalError,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetViolationDetailsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetViolationDetailsError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetViolationDetailsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetViolationDetailsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1896,18 +2098,38 @@ impl ListAppsListsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListAppsListsError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAppsListsError::InternalError,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(ListAppsListsError::InvalidOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAppsListsError::InvalidOperation,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListAppsListsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAppsListsError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListAppsListsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAppsListsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1940,14 +2162,22 @@ impl ListComplianceStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListComplianceStatusError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListComplianceStatusError::InternalError,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListComplianceStatusError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListComplianceStatusError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1978,12 +2208,22 @@ impl ListMemberAccountsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListMemberAccountsError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMemberAccountsError::InternalError,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListMemberAccountsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMemberAccountsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2018,18 +2258,38 @@ impl ListPoliciesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListPoliciesError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPoliciesError::InternalError,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(ListPoliciesError::InvalidOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPoliciesError::InvalidOperation,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListPoliciesError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPoliciesError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListPoliciesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPoliciesError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2064,15 +2324,30 @@ impl ListProtocolsListsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListProtocolsListsError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProtocolsListsError::InternalError,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(ListProtocolsListsError::InvalidOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProtocolsListsError::InvalidOperation,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListProtocolsListsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProtocolsListsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2108,22 +2383,38 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InternalError,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InvalidInput,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::InvalidOperation,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2162,21 +2453,46 @@ impl PutAppsListError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(PutAppsListError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAppsListError::InternalError,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(PutAppsListError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAppsListError::InvalidInput,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(PutAppsListError::InvalidOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAppsListError::InvalidOperation,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutAppsListError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAppsListError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutAppsListError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAppsListError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2212,21 +2528,30 @@ impl PutNotificationChannelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(PutNotificationChannelError::InternalError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutNotificationChannelError::InternalError,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(PutNotificationChannelError::InvalidOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutNotificationChannelError::InvalidOperation,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutNotificationChannelError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutNotificationChannelError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2266,24 +2591,54 @@ impl PutPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(PutPolicyError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutPolicyError::InternalError,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(PutPolicyError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutPolicyError::InvalidInput,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(PutPolicyError::InvalidOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutPolicyError::InvalidOperation,
+                    )
                 }
                 "InvalidTypeException" => {
-                    return RusotoError::Service(PutPolicyError::InvalidType(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutPolicyError::InvalidType,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutPolicyError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutPolicyError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutPolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutPolicyError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2324,21 +2679,46 @@ impl PutProtocolsListError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(PutProtocolsListError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutProtocolsListError::InternalError,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(PutProtocolsListError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutProtocolsListError::InvalidInput,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(PutProtocolsListError::InvalidOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutProtocolsListError::InvalidOperation,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutProtocolsListError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutProtocolsListError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutProtocolsListError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutProtocolsListError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2378,21 +2758,46 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(TagResourceError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InternalError,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(TagResourceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidInput,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(TagResourceError::InvalidOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidOperation,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2430,18 +2835,38 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalErrorException" => {
-                    return RusotoError::Service(UntagResourceError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InternalError,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidInput,
+                    )
                 }
                 "InvalidOperationException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidOperation,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2625,11 +3050,12 @@ pub struct FmsClient {
 impl FmsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> FmsClient {
         FmsClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "FMS"),
         }
     }
 
@@ -2644,12 +3070,32 @@ impl FmsClient {
     {
         FmsClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "FMS"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> FmsClient {
-        FmsClient { client, region }
+        FmsClient {
+            client,
+            region: region_for_service(region, "FMS"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> FmsClient {
+        FmsClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("FMS"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> FmsClient {
+        FmsClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -2661,6 +3107,7 @@ impl Fms for FmsClient {
         input: AssociateAdminAccountRequest,
     ) -> Result<(), RusotoError<AssociateAdminAccountError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateAdminAccount");
         request.add_header("x-amz-target", "AWSFMS_20180101.AssociateAdminAccount");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2678,6 +3125,7 @@ impl Fms for FmsClient {
         input: DeleteAppsListRequest,
     ) -> Result<(), RusotoError<DeleteAppsListError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteAppsList");
         request.add_header("x-amz-target", "AWSFMS_20180101.DeleteAppsList");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2694,6 +3142,7 @@ impl Fms for FmsClient {
         &self,
     ) -> Result<(), RusotoError<DeleteNotificationChannelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteNotificationChannel");
         request.add_header("x-amz-target", "AWSFMS_20180101.DeleteNotificationChannel");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -2710,6 +3159,7 @@ impl Fms for FmsClient {
         input: DeletePolicyRequest,
     ) -> Result<(), RusotoError<DeletePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeletePolicy");
         request.add_header("x-amz-target", "AWSFMS_20180101.DeletePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2727,6 +3177,7 @@ impl Fms for FmsClient {
         input: DeleteProtocolsListRequest,
     ) -> Result<(), RusotoError<DeleteProtocolsListError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteProtocolsList");
         request.add_header("x-amz-target", "AWSFMS_20180101.DeleteProtocolsList");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2743,6 +3194,7 @@ impl Fms for FmsClient {
         &self,
     ) -> Result<(), RusotoError<DisassociateAdminAccountError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisassociateAdminAccount");
         request.add_header("x-amz-target", "AWSFMS_20180101.DisassociateAdminAccount");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -2758,6 +3210,7 @@ impl Fms for FmsClient {
         &self,
     ) -> Result<GetAdminAccountResponse, RusotoError<GetAdminAccountError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetAdminAccount");
         request.add_header("x-amz-target", "AWSFMS_20180101.GetAdminAccount");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -2775,6 +3228,7 @@ impl Fms for FmsClient {
         input: GetAppsListRequest,
     ) -> Result<GetAppsListResponse, RusotoError<GetAppsListError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetAppsList");
         request.add_header("x-amz-target", "AWSFMS_20180101.GetAppsList");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2793,6 +3247,7 @@ impl Fms for FmsClient {
         input: GetComplianceDetailRequest,
     ) -> Result<GetComplianceDetailResponse, RusotoError<GetComplianceDetailError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetComplianceDetail");
         request.add_header("x-amz-target", "AWSFMS_20180101.GetComplianceDetail");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2810,6 +3265,7 @@ impl Fms for FmsClient {
         &self,
     ) -> Result<GetNotificationChannelResponse, RusotoError<GetNotificationChannelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetNotificationChannel");
         request.add_header("x-amz-target", "AWSFMS_20180101.GetNotificationChannel");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -2828,6 +3284,7 @@ impl Fms for FmsClient {
         input: GetPolicyRequest,
     ) -> Result<GetPolicyResponse, RusotoError<GetPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetPolicy");
         request.add_header("x-amz-target", "AWSFMS_20180101.GetPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2846,6 +3303,7 @@ impl Fms for FmsClient {
         input: GetProtectionStatusRequest,
     ) -> Result<GetProtectionStatusResponse, RusotoError<GetProtectionStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetProtectionStatus");
         request.add_header("x-amz-target", "AWSFMS_20180101.GetProtectionStatus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2864,6 +3322,7 @@ impl Fms for FmsClient {
         input: GetProtocolsListRequest,
     ) -> Result<GetProtocolsListResponse, RusotoError<GetProtocolsListError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetProtocolsList");
         request.add_header("x-amz-target", "AWSFMS_20180101.GetProtocolsList");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2882,6 +3341,7 @@ impl Fms for FmsClient {
         input: GetViolationDetailsRequest,
     ) -> Result<GetViolationDetailsResponse, RusotoError<GetViolationDetailsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetViolationDetails");
         request.add_header("x-amz-target", "AWSFMS_20180101.GetViolationDetails");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2900,6 +3360,7 @@ impl Fms for FmsClient {
         input: ListAppsListsRequest,
     ) -> Result<ListAppsListsResponse, RusotoError<ListAppsListsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListAppsLists");
         request.add_header("x-amz-target", "AWSFMS_20180101.ListAppsLists");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2918,6 +3379,7 @@ impl Fms for FmsClient {
         input: ListComplianceStatusRequest,
     ) -> Result<ListComplianceStatusResponse, RusotoError<ListComplianceStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListComplianceStatus");
         request.add_header("x-amz-target", "AWSFMS_20180101.ListComplianceStatus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2937,6 +3399,7 @@ impl Fms for FmsClient {
         input: ListMemberAccountsRequest,
     ) -> Result<ListMemberAccountsResponse, RusotoError<ListMemberAccountsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListMemberAccounts");
         request.add_header("x-amz-target", "AWSFMS_20180101.ListMemberAccounts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2955,6 +3418,7 @@ impl Fms for FmsClient {
         input: ListPoliciesRequest,
     ) -> Result<ListPoliciesResponse, RusotoError<ListPoliciesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListPolicies");
         request.add_header("x-amz-target", "AWSFMS_20180101.ListPolicies");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2973,6 +3437,7 @@ impl Fms for FmsClient {
         input: ListProtocolsListsRequest,
     ) -> Result<ListProtocolsListsResponse, RusotoError<ListProtocolsListsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListProtocolsLists");
         request.add_header("x-amz-target", "AWSFMS_20180101.ListProtocolsLists");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2991,6 +3456,7 @@ impl Fms for FmsClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header("x-amz-target", "AWSFMS_20180101.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3009,6 +3475,7 @@ impl Fms for FmsClient {
         input: PutAppsListRequest,
     ) -> Result<PutAppsListResponse, RusotoError<PutAppsListError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutAppsList");
         request.add_header("x-amz-target", "AWSFMS_20180101.PutAppsList");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3027,6 +3494,7 @@ impl Fms for FmsClient {
         input: PutNotificationChannelRequest,
     ) -> Result<(), RusotoError<PutNotificationChannelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutNotificationChannel");
         request.add_header("x-amz-target", "AWSFMS_20180101.PutNotificationChannel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3044,6 +3512,7 @@ impl Fms for FmsClient {
         input: PutPolicyRequest,
     ) -> Result<PutPolicyResponse, RusotoError<PutPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutPolicy");
         request.add_header("x-amz-target", "AWSFMS_20180101.PutPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3062,6 +3531,7 @@ impl Fms for FmsClient {
         input: PutProtocolsListRequest,
     ) -> Result<PutProtocolsListResponse, RusotoError<PutProtocolsListError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutProtocolsList");
         request.add_header("x-amz-target", "AWSFMS_20180101.PutProtocolsList");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3080,6 +3550,7 @@ impl Fms for FmsClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "AWSFMS_20180101.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3098,6 +3569,7 @@ impl Fms for FmsClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "AWSFMS_20180101.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3110,3 +3582,295 @@ impl Fms for FmsClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 }
+
+/// A blocking client for the FMS API, running the calls of a `FmsClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `FmsClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct FmsBlockingClient {
+    client: FmsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl FmsBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> FmsBlockingClient {
+        FmsBlockingClient::from_client(FmsClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> FmsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        FmsBlockingClient::from_client(FmsClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> FmsBlockingClient {
+        FmsBlockingClient::from_client(FmsClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> FmsBlockingClient {
+        FmsBlockingClient::from_client(FmsClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: FmsClient) -> FmsBlockingClient {
+        FmsBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> FmsBlockingClient {
+        FmsBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> FmsBlockingClient {
+        FmsBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Fms::associate_admin_account`].
+    pub fn associate_admin_account(
+        &self,
+        input: AssociateAdminAccountRequest,
+    ) -> Result<(), RusotoError<AssociateAdminAccountError>> {
+        self.runtime
+            .block_on(self.client.associate_admin_account(input))
+    }
+
+    /// Blocking version of [`Fms::delete_apps_list`].
+    pub fn delete_apps_list(
+        &self,
+        input: DeleteAppsListRequest,
+    ) -> Result<(), RusotoError<DeleteAppsListError>> {
+        self.runtime.block_on(self.client.delete_apps_list(input))
+    }
+
+    /// Blocking version of [`Fms::delete_notification_channel`].
+    pub fn delete_notification_channel(
+        &self,
+    ) -> Result<(), RusotoError<DeleteNotificationChannelError>> {
+        self.runtime
+            .block_on(self.client.delete_notification_channel())
+    }
+
+    /// Blocking version of [`Fms::delete_policy`].
+    pub fn delete_policy(
+        &self,
+        input: DeletePolicyRequest,
+    ) -> Result<(), RusotoError<DeletePolicyError>> {
+        self.runtime.block_on(self.client.delete_policy(input))
+    }
+
+    /// Blocking version of [`Fms::delete_protocols_list`].
+    pub fn delete_protocols_list(
+        &self,
+        input: DeleteProtocolsListRequest,
+    ) -> Result<(), RusotoError<DeleteProtocolsListError>> {
+        self.runtime
+            .block_on(self.client.delete_protocols_list(input))
+    }
+
+    /// Blocking version of [`Fms::disassociate_admin_account`].
+    pub fn disassociate_admin_account(
+        &self,
+    ) -> Result<(), RusotoError<DisassociateAdminAccountError>> {
+        self.runtime
+            .block_on(self.client.disassociate_admin_account())
+    }
+
+    /// Blocking version of [`Fms::get_admin_account`].
+    pub fn get_admin_account(
+        &self,
+    ) -> Result<GetAdminAccountResponse, RusotoError<GetAdminAccountError>> {
+        self.runtime.block_on(self.client.get_admin_account())
+    }
+
+    /// Blocking version of [`Fms::get_apps_list`].
+    pub fn get_apps_list(
+        &self,
+        input: GetAppsListRequest,
+    ) -> Result<GetAppsListResponse, RusotoError<GetAppsListError>> {
+        self.runtime.block_on(self.client.get_apps_list(input))
+    }
+
+    /// Blocking version of [`Fms::get_compliance_detail`].
+    pub fn get_compliance_detail(
+        &self,
+        input: GetComplianceDetailRequest,
+    ) -> Result<GetComplianceDetailResponse, RusotoError<GetComplianceDetailError>> {
+        self.runtime
+            .block_on(self.client.get_compliance_detail(input))
+    }
+
+    /// Blocking version of [`Fms::get_notification_channel`].
+    pub fn get_notification_channel(
+        &self,
+    ) -> Result<GetNotificationChannelResponse, RusotoError<GetNotificationChannelError>> {
+        self.runtime
+            .block_on(self.client.get_notification_channel())
+    }
+
+    /// Blocking version of [`Fms::get_policy`].
+    pub fn get_policy(
+        &self,
+        input: GetPolicyRequest,
+    ) -> Result<GetPolicyResponse, RusotoError<GetPolicyError>> {
+        self.runtime.block_on(self.client.get_policy(input))
+    }
+
+    /// Blocking version of [`Fms::get_protection_status`].
+    pub fn get_protection_status(
+        &self,
+        input: GetProtectionStatusRequest,
+    ) -> Result<GetProtectionStatusResponse, RusotoError<GetProtectionStatusError>> {
+        self.runtime
+            .block_on(self.client.get_protection_status(input))
+    }
+
+    /// Blocking version of [`Fms::get_protocols_list`].
+    pub fn get_protocols_list(
+        &self,
+        input: GetProtocolsListRequest,
+    ) -> Result<GetProtocolsListResponse, RusotoError<GetProtocolsListError>> {
+        self.runtime.block_on(self.client.get_protocols_list(input))
+    }
+
+    /// Blocking version of [`Fms::get_violation_details`].
+    pub fn get_violation_details(
+        &self,
+        input: GetViolationDetailsRequest,
+    ) -> Result<GetViolationDetailsResponse, RusotoError<GetViolationDetailsError>> {
+        self.runtime
+            .block_on(self.client.get_violation_details(input))
+    }
+
+    /// Blocking version of [`Fms::list_apps_lists`].
+    pub fn list_apps_lists(
+        &self,
+        input: ListAppsListsRequest,
+    ) -> Result<ListAppsListsResponse, RusotoError<ListAppsListsError>> {
+        self.runtime.block_on(self.client.list_apps_lists(input))
+    }
+
+    /// Blocking version of [`Fms::list_compliance_status`].
+    pub fn list_compliance_status(
+        &self,
+        input: ListComplianceStatusRequest,
+    ) -> Result<ListComplianceStatusResponse, RusotoError<ListComplianceStatusError>> {
+        self.runtime
+            .block_on(self.client.list_compliance_status(input))
+    }
+
+    /// Blocking version of [`Fms::list_member_accounts`].
+    pub fn list_member_accounts(
+        &self,
+        input: ListMemberAccountsRequest,
+    ) -> Result<ListMemberAccountsResponse, RusotoError<ListMemberAccountsError>> {
+        self.runtime
+            .block_on(self.client.list_member_accounts(input))
+    }
+
+    /// Blocking version of [`Fms::list_policies`].
+    pub fn list_policies(
+        &self,
+        input: ListPoliciesRequest,
+    ) -> Result<ListPoliciesResponse, RusotoError<ListPoliciesError>> {
+        self.runtime.block_on(self.client.list_policies(input))
+    }
+
+    /// Blocking version of [`Fms::list_protocols_lists`].
+    pub fn list_protocols_lists(
+        &self,
+        input: ListProtocolsListsRequest,
+    ) -> Result<ListProtocolsListsResponse, RusotoError<ListProtocolsListsError>> {
+        self.runtime
+            .block_on(self.client.list_protocols_lists(input))
+    }
+
+    /// Blocking version of [`Fms::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`Fms::put_apps_list`].
+    pub fn put_apps_list(
+        &self,
+        input: PutAppsListRequest,
+    ) -> Result<PutAppsListResponse, RusotoError<PutAppsListError>> {
+        self.runtime.block_on(self.client.put_apps_list(input))
+    }
+
+    /// Blocking version of [`Fms::put_notification_channel`].
+    pub fn put_notification_channel(
+        &self,
+        input: PutNotificationChannelRequest,
+    ) -> Result<(), RusotoError<PutNotificationChannelError>> {
+        self.runtime
+            .block_on(self.client.put_notification_channel(input))
+    }
+
+    /// Blocking version of [`Fms::put_policy`].
+    pub fn put_policy(
+        &self,
+        input: PutPolicyRequest,
+    ) -> Result<PutPolicyResponse, RusotoError<PutPolicyError>> {
+        self.runtime.block_on(self.client.put_policy(input))
+    }
+
+    /// Blocking version of [`Fms::put_protocols_list`].
+    pub fn put_protocols_list(
+        &self,
+        input: PutProtocolsListRequest,
+    ) -> Result<PutProtocolsListResponse, RusotoError<PutProtocolsListError>> {
+        self.runtime.block_on(self.client.put_protocols_list(input))
+    }
+
+    /// Blocking version of [`Fms::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`Fms::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+}
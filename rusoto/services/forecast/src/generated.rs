@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1683,15 +1684,30 @@ impl CreateDatasetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDatasetError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDatasetError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDatasetError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDatasetError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateDatasetError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDatasetError::ResourceAlreadyExists,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1729,23 +1745,46 @@ impl CreateDatasetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDatasetGroupError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDatasetGroupError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDatasetGroupError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDatasetGroupError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateDatasetGroupError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDatasetGroupError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateDatasetGroupError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDatasetGroupError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDatasetGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDatasetGroupError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1785,29 +1824,46 @@ impl CreateDatasetImportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDatasetImportJobError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDatasetImportJobError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDatasetImportJobError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDatasetImportJobError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        CreateDatasetImportJobError::ResourceAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDatasetImportJobError::ResourceAlreadyExists,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateDatasetImportJobError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDatasetImportJobError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDatasetImportJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDatasetImportJobError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1847,23 +1903,46 @@ impl CreateForecastError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateForecastError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateForecastError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateForecastError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateForecastError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateForecastError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateForecastError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateForecastError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateForecastError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateForecastError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateForecastError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1903,31 +1982,46 @@ impl CreateForecastExportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateForecastExportJobError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateForecastExportJobError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateForecastExportJobError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateForecastExportJobError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        CreateForecastExportJobError::ResourceAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateForecastExportJobError::ResourceAlreadyExists,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateForecastExportJobError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateForecastExportJobError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateForecastExportJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateForecastExportJobError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1969,23 +2063,46 @@ impl CreatePredictorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreatePredictorError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePredictorError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreatePredictorError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePredictorError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreatePredictorError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePredictorError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreatePredictorError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePredictorError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreatePredictorError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePredictorError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2027,31 +2144,46 @@ impl CreatePredictorBacktestExportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(
-                        CreatePredictorBacktestExportJobError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePredictorBacktestExportJobError::InvalidInput,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
-                        CreatePredictorBacktestExportJobError::LimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePredictorBacktestExportJobError::LimitExceeded,
                     )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        CreatePredictorBacktestExportJobError::ResourceAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePredictorBacktestExportJobError::ResourceAlreadyExists,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
-                        CreatePredictorBacktestExportJobError::ResourceInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePredictorBacktestExportJobError::ResourceInUse,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        CreatePredictorBacktestExportJobError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePredictorBacktestExportJobError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2097,15 +2229,30 @@ impl DeleteDatasetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteDatasetError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDatasetError::InvalidInput,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteDatasetError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDatasetError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDatasetError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDatasetError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2139,15 +2286,30 @@ impl DeleteDatasetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteDatasetGroupError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDatasetGroupError::InvalidInput,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteDatasetGroupError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDatasetGroupError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDatasetGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDatasetGroupError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2181,19 +2343,30 @@ impl DeleteDatasetImportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteDatasetImportJobError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDatasetImportJobError::InvalidInput,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteDatasetImportJobError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDatasetImportJobError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDatasetImportJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDatasetImportJobError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2227,15 +2400,30 @@ impl DeleteForecastError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteForecastError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteForecastError::InvalidInput,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteForecastError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteForecastError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteForecastError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteForecastError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2269,21 +2457,30 @@ impl DeleteForecastExportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteForecastExportJobError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteForecastExportJobError::InvalidInput,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteForecastExportJobError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteForecastExportJobError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteForecastExportJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteForecastExportJobError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2317,15 +2514,30 @@ impl DeletePredictorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeletePredictorError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePredictorError::InvalidInput,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeletePredictorError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePredictorError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeletePredictorError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePredictorError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2361,21 +2573,30 @@ impl DeletePredictorBacktestExportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(
-                        DeletePredictorBacktestExportJobError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePredictorBacktestExportJobError::InvalidInput,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
-                        DeletePredictorBacktestExportJobError::ResourceInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePredictorBacktestExportJobError::ResourceInUse,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DeletePredictorBacktestExportJobError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePredictorBacktestExportJobError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2413,12 +2634,22 @@ impl DescribeDatasetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeDatasetError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDatasetError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDatasetError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDatasetError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2449,14 +2680,22 @@ impl DescribeDatasetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeDatasetGroupError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDatasetGroupError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDatasetGroupError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDatasetGroupError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2487,16 +2726,22 @@ impl DescribeDatasetImportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeDatasetImportJobError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDatasetImportJobError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDatasetImportJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDatasetImportJobError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2527,12 +2772,22 @@ impl DescribeForecastError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeForecastError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeForecastError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeForecastError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeForecastError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2563,16 +2818,22 @@ impl DescribeForecastExportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeForecastExportJobError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeForecastExportJobError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeForecastExportJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeForecastExportJobError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2603,12 +2864,22 @@ impl DescribePredictorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribePredictorError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePredictorError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribePredictorError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePredictorError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2641,16 +2912,22 @@ impl DescribePredictorBacktestExportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(
-                        DescribePredictorBacktestExportJobError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePredictorBacktestExportJobError::InvalidInput,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribePredictorBacktestExportJobError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePredictorBacktestExportJobError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2687,15 +2964,30 @@ impl GetAccuracyMetricsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetAccuracyMetricsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAccuracyMetricsError::InvalidInput,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(GetAccuracyMetricsError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAccuracyMetricsError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetAccuracyMetricsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAccuracyMetricsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2725,9 +3017,14 @@ impl ListDatasetGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDatasetGroupsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDatasetGroupsError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2757,14 +3054,22 @@ impl ListDatasetImportJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListDatasetImportJobsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDatasetImportJobsError::InvalidInput,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDatasetImportJobsError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDatasetImportJobsError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2793,9 +3098,14 @@ impl ListDatasetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDatasetsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDatasetsError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2825,14 +3135,22 @@ impl ListForecastExportJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListForecastExportJobsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListForecastExportJobsError::InvalidInput,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListForecastExportJobsError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListForecastExportJobsError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2863,12 +3181,22 @@ impl ListForecastsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListForecastsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListForecastsError::InvalidInput,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListForecastsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListForecastsError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2901,16 +3229,22 @@ impl ListPredictorBacktestExportJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(
-                        ListPredictorBacktestExportJobsError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPredictorBacktestExportJobsError::InvalidInput,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        ListPredictorBacktestExportJobsError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPredictorBacktestExportJobsError::InvalidNextToken,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2943,12 +3277,22 @@ impl ListPredictorsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListPredictorsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPredictorsError::InvalidInput,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListPredictorsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPredictorsError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2979,14 +3323,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3019,15 +3371,30 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(TagResourceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3059,12 +3426,22 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3097,15 +3474,30 @@ impl UpdateDatasetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateDatasetGroupError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDatasetGroupError::InvalidInput,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateDatasetGroupError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDatasetGroupError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDatasetGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDatasetGroupError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3343,11 +3735,12 @@ pub struct ForecastClient {
 impl ForecastClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> ForecastClient {
         ForecastClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "forecast"),
         }
     }
 
@@ -3362,12 +3755,32 @@ impl ForecastClient {
     {
         ForecastClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "forecast"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ForecastClient {
-        ForecastClient { client, region }
+        ForecastClient {
+            client,
+            region: region_for_service(region, "forecast"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> ForecastClient {
+        ForecastClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("forecast"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ForecastClient {
+        ForecastClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -3379,6 +3792,7 @@ impl Forecast for ForecastClient {
         input: CreateDatasetRequest,
     ) -> Result<CreateDatasetResponse, RusotoError<CreateDatasetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateDataset");
         request.add_header("x-amz-target", "AmazonForecast.CreateDataset");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3397,6 +3811,7 @@ impl Forecast for ForecastClient {
         input: CreateDatasetGroupRequest,
     ) -> Result<CreateDatasetGroupResponse, RusotoError<CreateDatasetGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateDatasetGroup");
         request.add_header("x-amz-target", "AmazonForecast.CreateDatasetGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3415,6 +3830,7 @@ impl Forecast for ForecastClient {
         input: CreateDatasetImportJobRequest,
     ) -> Result<CreateDatasetImportJobResponse, RusotoError<CreateDatasetImportJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateDatasetImportJob");
         request.add_header("x-amz-target", "AmazonForecast.CreateDatasetImportJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3434,6 +3850,7 @@ impl Forecast for ForecastClient {
         input: CreateForecastRequest,
     ) -> Result<CreateForecastResponse, RusotoError<CreateForecastError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateForecast");
         request.add_header("x-amz-target", "AmazonForecast.CreateForecast");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3452,6 +3869,7 @@ impl Forecast for ForecastClient {
         input: CreateForecastExportJobRequest,
     ) -> Result<CreateForecastExportJobResponse, RusotoError<CreateForecastExportJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateForecastExportJob");
         request.add_header("x-amz-target", "AmazonForecast.CreateForecastExportJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3471,6 +3889,7 @@ impl Forecast for ForecastClient {
         input: CreatePredictorRequest,
     ) -> Result<CreatePredictorResponse, RusotoError<CreatePredictorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreatePredictor");
         request.add_header("x-amz-target", "AmazonForecast.CreatePredictor");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3492,6 +3911,7 @@ impl Forecast for ForecastClient {
         RusotoError<CreatePredictorBacktestExportJobError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreatePredictorBacktestExportJob");
         request.add_header(
             "x-amz-target",
             "AmazonForecast.CreatePredictorBacktestExportJob",
@@ -3517,6 +3937,7 @@ impl Forecast for ForecastClient {
         input: DeleteDatasetRequest,
     ) -> Result<(), RusotoError<DeleteDatasetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteDataset");
         request.add_header("x-amz-target", "AmazonForecast.DeleteDataset");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3534,6 +3955,7 @@ impl Forecast for ForecastClient {
         input: DeleteDatasetGroupRequest,
     ) -> Result<(), RusotoError<DeleteDatasetGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteDatasetGroup");
         request.add_header("x-amz-target", "AmazonForecast.DeleteDatasetGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3551,6 +3973,7 @@ impl Forecast for ForecastClient {
         input: DeleteDatasetImportJobRequest,
     ) -> Result<(), RusotoError<DeleteDatasetImportJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteDatasetImportJob");
         request.add_header("x-amz-target", "AmazonForecast.DeleteDatasetImportJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3568,6 +3991,7 @@ impl Forecast for ForecastClient {
         input: DeleteForecastRequest,
     ) -> Result<(), RusotoError<DeleteForecastError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteForecast");
         request.add_header("x-amz-target", "AmazonForecast.DeleteForecast");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3585,6 +4009,7 @@ impl Forecast for ForecastClient {
         input: DeleteForecastExportJobRequest,
     ) -> Result<(), RusotoError<DeleteForecastExportJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteForecastExportJob");
         request.add_header("x-amz-target", "AmazonForecast.DeleteForecastExportJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3602,6 +4027,7 @@ impl Forecast for ForecastClient {
         input: DeletePredictorRequest,
     ) -> Result<(), RusotoError<DeletePredictorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeletePredictor");
         request.add_header("x-amz-target", "AmazonForecast.DeletePredictor");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3619,6 +4045,7 @@ impl Forecast for ForecastClient {
         input: DeletePredictorBacktestExportJobRequest,
     ) -> Result<(), RusotoError<DeletePredictorBacktestExportJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeletePredictorBacktestExportJob");
         request.add_header(
             "x-amz-target",
             "AmazonForecast.DeletePredictorBacktestExportJob",
@@ -3642,6 +4069,7 @@ impl Forecast for ForecastClient {
         input: DescribeDatasetRequest,
     ) -> Result<DescribeDatasetResponse, RusotoError<DescribeDatasetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeDataset");
         request.add_header("x-amz-target", "AmazonForecast.DescribeDataset");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3660,6 +4088,7 @@ impl Forecast for ForecastClient {
         input: DescribeDatasetGroupRequest,
     ) -> Result<DescribeDatasetGroupResponse, RusotoError<DescribeDatasetGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeDatasetGroup");
         request.add_header("x-amz-target", "AmazonForecast.DescribeDatasetGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3679,6 +4108,7 @@ impl Forecast for ForecastClient {
         input: DescribeDatasetImportJobRequest,
     ) -> Result<DescribeDatasetImportJobResponse, RusotoError<DescribeDatasetImportJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeDatasetImportJob");
         request.add_header("x-amz-target", "AmazonForecast.DescribeDatasetImportJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3698,6 +4128,7 @@ impl Forecast for ForecastClient {
         input: DescribeForecastRequest,
     ) -> Result<DescribeForecastResponse, RusotoError<DescribeForecastError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeForecast");
         request.add_header("x-amz-target", "AmazonForecast.DescribeForecast");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3717,6 +4148,7 @@ impl Forecast for ForecastClient {
     ) -> Result<DescribeForecastExportJobResponse, RusotoError<DescribeForecastExportJobError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeForecastExportJob");
         request.add_header("x-amz-target", "AmazonForecast.DescribeForecastExportJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3736,6 +4168,7 @@ impl Forecast for ForecastClient {
         input: DescribePredictorRequest,
     ) -> Result<DescribePredictorResponse, RusotoError<DescribePredictorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribePredictor");
         request.add_header("x-amz-target", "AmazonForecast.DescribePredictor");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3757,6 +4190,7 @@ impl Forecast for ForecastClient {
         RusotoError<DescribePredictorBacktestExportJobError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribePredictorBacktestExportJob");
         request.add_header(
             "x-amz-target",
             "AmazonForecast.DescribePredictorBacktestExportJob",
@@ -3782,6 +4216,7 @@ impl Forecast for ForecastClient {
         input: GetAccuracyMetricsRequest,
     ) -> Result<GetAccuracyMetricsResponse, RusotoError<GetAccuracyMetricsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetAccuracyMetrics");
         request.add_header("x-amz-target", "AmazonForecast.GetAccuracyMetrics");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3800,6 +4235,7 @@ impl Forecast for ForecastClient {
         input: ListDatasetGroupsRequest,
     ) -> Result<ListDatasetGroupsResponse, RusotoError<ListDatasetGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListDatasetGroups");
         request.add_header("x-amz-target", "AmazonForecast.ListDatasetGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3818,6 +4254,7 @@ impl Forecast for ForecastClient {
         input: ListDatasetImportJobsRequest,
     ) -> Result<ListDatasetImportJobsResponse, RusotoError<ListDatasetImportJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListDatasetImportJobs");
         request.add_header("x-amz-target", "AmazonForecast.ListDatasetImportJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3837,6 +4274,7 @@ impl Forecast for ForecastClient {
         input: ListDatasetsRequest,
     ) -> Result<ListDatasetsResponse, RusotoError<ListDatasetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListDatasets");
         request.add_header("x-amz-target", "AmazonForecast.ListDatasets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3855,6 +4293,7 @@ impl Forecast for ForecastClient {
         input: ListForecastExportJobsRequest,
     ) -> Result<ListForecastExportJobsResponse, RusotoError<ListForecastExportJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListForecastExportJobs");
         request.add_header("x-amz-target", "AmazonForecast.ListForecastExportJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3874,6 +4313,7 @@ impl Forecast for ForecastClient {
         input: ListForecastsRequest,
     ) -> Result<ListForecastsResponse, RusotoError<ListForecastsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListForecasts");
         request.add_header("x-amz-target", "AmazonForecast.ListForecasts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3895,6 +4335,7 @@ impl Forecast for ForecastClient {
         RusotoError<ListPredictorBacktestExportJobsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListPredictorBacktestExportJobs");
         request.add_header(
             "x-amz-target",
             "AmazonForecast.ListPredictorBacktestExportJobs",
@@ -3917,6 +4358,7 @@ impl Forecast for ForecastClient {
         input: ListPredictorsRequest,
     ) -> Result<ListPredictorsResponse, RusotoError<ListPredictorsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListPredictors");
         request.add_header("x-amz-target", "AmazonForecast.ListPredictors");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3935,6 +4377,7 @@ impl Forecast for ForecastClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header("x-amz-target", "AmazonForecast.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3953,6 +4396,7 @@ impl Forecast for ForecastClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "AmazonForecast.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3971,6 +4415,7 @@ impl Forecast for ForecastClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "AmazonForecast.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3989,6 +4434,7 @@ impl Forecast for ForecastClient {
         input: UpdateDatasetGroupRequest,
     ) -> Result<UpdateDatasetGroupResponse, RusotoError<UpdateDatasetGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateDatasetGroup");
         request.add_header("x-amz-target", "AmazonForecast.UpdateDatasetGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4001,3 +4447,371 @@ impl Forecast for ForecastClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateDatasetGroupResponse, _>()
     }
 }
+
+/// A blocking client for the Amazon Forecast Service API, running the calls of a `ForecastClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `ForecastClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ForecastBlockingClient {
+    client: ForecastClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ForecastBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> ForecastBlockingClient {
+        ForecastBlockingClient::from_client(ForecastClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ForecastBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ForecastBlockingClient::from_client(ForecastClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> ForecastBlockingClient {
+        ForecastBlockingClient::from_client(ForecastClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> ForecastBlockingClient {
+        ForecastBlockingClient::from_client(ForecastClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: ForecastClient) -> ForecastBlockingClient {
+        ForecastBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> ForecastBlockingClient {
+        ForecastBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ForecastBlockingClient {
+        ForecastBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Forecast::create_dataset`].
+    pub fn create_dataset(
+        &self,
+        input: CreateDatasetRequest,
+    ) -> Result<CreateDatasetResponse, RusotoError<CreateDatasetError>> {
+        self.runtime.block_on(self.client.create_dataset(input))
+    }
+
+    /// Blocking version of [`Forecast::create_dataset_group`].
+    pub fn create_dataset_group(
+        &self,
+        input: CreateDatasetGroupRequest,
+    ) -> Result<CreateDatasetGroupResponse, RusotoError<CreateDatasetGroupError>> {
+        self.runtime
+            .block_on(self.client.create_dataset_group(input))
+    }
+
+    /// Blocking version of [`Forecast::create_dataset_import_job`].
+    pub fn create_dataset_import_job(
+        &self,
+        input: CreateDatasetImportJobRequest,
+    ) -> Result<CreateDatasetImportJobResponse, RusotoError<CreateDatasetImportJobError>> {
+        self.runtime
+            .block_on(self.client.create_dataset_import_job(input))
+    }
+
+    /// Blocking version of [`Forecast::create_forecast`].
+    pub fn create_forecast(
+        &self,
+        input: CreateForecastRequest,
+    ) -> Result<CreateForecastResponse, RusotoError<CreateForecastError>> {
+        self.runtime.block_on(self.client.create_forecast(input))
+    }
+
+    /// Blocking version of [`Forecast::create_forecast_export_job`].
+    pub fn create_forecast_export_job(
+        &self,
+        input: CreateForecastExportJobRequest,
+    ) -> Result<CreateForecastExportJobResponse, RusotoError<CreateForecastExportJobError>> {
+        self.runtime
+            .block_on(self.client.create_forecast_export_job(input))
+    }
+
+    /// Blocking version of [`Forecast::create_predictor`].
+    pub fn create_predictor(
+        &self,
+        input: CreatePredictorRequest,
+    ) -> Result<CreatePredictorResponse, RusotoError<CreatePredictorError>> {
+        self.runtime.block_on(self.client.create_predictor(input))
+    }
+
+    /// Blocking version of [`Forecast::create_predictor_backtest_export_job`].
+    pub fn create_predictor_backtest_export_job(
+        &self,
+        input: CreatePredictorBacktestExportJobRequest,
+    ) -> Result<
+        CreatePredictorBacktestExportJobResponse,
+        RusotoError<CreatePredictorBacktestExportJobError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_predictor_backtest_export_job(input))
+    }
+
+    /// Blocking version of [`Forecast::delete_dataset`].
+    pub fn delete_dataset(
+        &self,
+        input: DeleteDatasetRequest,
+    ) -> Result<(), RusotoError<DeleteDatasetError>> {
+        self.runtime.block_on(self.client.delete_dataset(input))
+    }
+
+    /// Blocking version of [`Forecast::delete_dataset_group`].
+    pub fn delete_dataset_group(
+        &self,
+        input: DeleteDatasetGroupRequest,
+    ) -> Result<(), RusotoError<DeleteDatasetGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_dataset_group(input))
+    }
+
+    /// Blocking version of [`Forecast::delete_dataset_import_job`].
+    pub fn delete_dataset_import_job(
+        &self,
+        input: DeleteDatasetImportJobRequest,
+    ) -> Result<(), RusotoError<DeleteDatasetImportJobError>> {
+        self.runtime
+            .block_on(self.client.delete_dataset_import_job(input))
+    }
+
+    /// Blocking version of [`Forecast::delete_forecast`].
+    pub fn delete_forecast(
+        &self,
+        input: DeleteForecastRequest,
+    ) -> Result<(), RusotoError<DeleteForecastError>> {
+        self.runtime.block_on(self.client.delete_forecast(input))
+    }
+
+    /// Blocking version of [`Forecast::delete_forecast_export_job`].
+    pub fn delete_forecast_export_job(
+        &self,
+        input: DeleteForecastExportJobRequest,
+    ) -> Result<(), RusotoError<DeleteForecastExportJobError>> {
+        self.runtime
+            .block_on(self.client.delete_forecast_export_job(input))
+    }
+
+    /// Blocking version of [`Forecast::delete_predictor`].
+    pub fn delete_predictor(
+        &self,
+        input: DeletePredictorRequest,
+    ) -> Result<(), RusotoError<DeletePredictorError>> {
+        self.runtime.block_on(self.client.delete_predictor(input))
+    }
+
+    /// Blocking version of [`Forecast::delete_predictor_backtest_export_job`].
+    pub fn delete_predictor_backtest_export_job(
+        &self,
+        input: DeletePredictorBacktestExportJobRequest,
+    ) -> Result<(), RusotoError<DeletePredictorBacktestExportJobError>> {
+        self.runtime
+            .block_on(self.client.delete_predictor_backtest_export_job(input))
+    }
+
+    /// Blocking version of [`Forecast::describe_dataset`].
+    pub fn describe_dataset(
+        &self,
+        input: DescribeDatasetRequest,
+    ) -> Result<DescribeDatasetResponse, RusotoError<DescribeDatasetError>> {
+        self.runtime.block_on(self.client.describe_dataset(input))
+    }
+
+    /// Blocking version of [`Forecast::describe_dataset_group`].
+    pub fn describe_dataset_group(
+        &self,
+        input: DescribeDatasetGroupRequest,
+    ) -> Result<DescribeDatasetGroupResponse, RusotoError<DescribeDatasetGroupError>> {
+        self.runtime
+            .block_on(self.client.describe_dataset_group(input))
+    }
+
+    /// Blocking version of [`Forecast::describe_dataset_import_job`].
+    pub fn describe_dataset_import_job(
+        &self,
+        input: DescribeDatasetImportJobRequest,
+    ) -> Result<DescribeDatasetImportJobResponse, RusotoError<DescribeDatasetImportJobError>> {
+        self.runtime
+            .block_on(self.client.describe_dataset_import_job(input))
+    }
+
+    /// Blocking version of [`Forecast::describe_forecast`].
+    pub fn describe_forecast(
+        &self,
+        input: DescribeForecastRequest,
+    ) -> Result<DescribeForecastResponse, RusotoError<DescribeForecastError>> {
+        self.runtime.block_on(self.client.describe_forecast(input))
+    }
+
+    /// Blocking version of [`Forecast::describe_forecast_export_job`].
+    pub fn describe_forecast_export_job(
+        &self,
+        input: DescribeForecastExportJobRequest,
+    ) -> Result<DescribeForecastExportJobResponse, RusotoError<DescribeForecastExportJobError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_forecast_export_job(input))
+    }
+
+    /// Blocking version of [`Forecast::describe_predictor`].
+    pub fn describe_predictor(
+        &self,
+        input: DescribePredictorRequest,
+    ) -> Result<DescribePredictorResponse, RusotoError<DescribePredictorError>> {
+        self.runtime.block_on(self.client.describe_predictor(input))
+    }
+
+    /// Blocking version of [`Forecast::describe_predictor_backtest_export_job`].
+    pub fn describe_predictor_backtest_export_job(
+        &self,
+        input: DescribePredictorBacktestExportJobRequest,
+    ) -> Result<
+        DescribePredictorBacktestExportJobResponse,
+        RusotoError<DescribePredictorBacktestExportJobError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_predictor_backtest_export_job(input))
+    }
+
+    /// Blocking version of [`Forecast::get_accuracy_metrics`].
+    pub fn get_accuracy_metrics(
+        &self,
+        input: GetAccuracyMetricsRequest,
+    ) -> Result<GetAccuracyMetricsResponse, RusotoError<GetAccuracyMetricsError>> {
+        self.runtime
+            .block_on(self.client.get_accuracy_metrics(input))
+    }
+
+    /// Blocking version of [`Forecast::list_dataset_groups`].
+    pub fn list_dataset_groups(
+        &self,
+        input: ListDatasetGroupsRequest,
+    ) -> Result<ListDatasetGroupsResponse, RusotoError<ListDatasetGroupsError>> {
+        self.runtime
+            .block_on(self.client.list_dataset_groups(input))
+    }
+
+    /// Blocking version of [`Forecast::list_dataset_import_jobs`].
+    pub fn list_dataset_import_jobs(
+        &self,
+        input: ListDatasetImportJobsRequest,
+    ) -> Result<ListDatasetImportJobsResponse, RusotoError<ListDatasetImportJobsError>> {
+        self.runtime
+            .block_on(self.client.list_dataset_import_jobs(input))
+    }
+
+    /// Blocking version of [`Forecast::list_datasets`].
+    pub fn list_datasets(
+        &self,
+        input: ListDatasetsRequest,
+    ) -> Result<ListDatasetsResponse, RusotoError<ListDatasetsError>> {
+        self.runtime.block_on(self.client.list_datasets(input))
+    }
+
+    /// Blocking version of [`Forecast::list_forecast_export_jobs`].
+    pub fn list_forecast_export_jobs(
+        &self,
+        input: ListForecastExportJobsRequest,
+    ) -> Result<ListForecastExportJobsResponse, RusotoError<ListForecastExportJobsError>> {
+        self.runtime
+            .block_on(self.client.list_forecast_export_jobs(input))
+    }
+
+    /// Blocking version of [`Forecast::list_forecasts`].
+    pub fn list_forecasts(
+        &self,
+        input: ListForecastsRequest,
+    ) -> Result<ListForecastsResponse, RusotoError<ListForecastsError>> {
+        self.runtime.block_on(self.client.list_forecasts(input))
+    }
+
+    /// Blocking version of [`Forecast::list_predictor_backtest_export_jobs`].
+    pub fn list_predictor_backtest_export_jobs(
+        &self,
+        input: ListPredictorBacktestExportJobsRequest,
+    ) -> Result<
+        ListPredictorBacktestExportJobsResponse,
+        RusotoError<ListPredictorBacktestExportJobsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_predictor_backtest_export_jobs(input))
+    }
+
+    /// Blocking version of [`Forecast::list_predictors`].
+    pub fn list_predictors(
+        &self,
+        input: ListPredictorsRequest,
+    ) -> Result<ListPredictorsResponse, RusotoError<ListPredictorsError>> {
+        self.runtime.block_on(self.client.list_predictors(input))
+    }
+
+    /// Blocking version of [`Forecast::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`Forecast::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`Forecast::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`Forecast::update_dataset_group`].
+    pub fn update_dataset_group(
+        &self,
+        input: UpdateDatasetGroupRequest,
+    ) -> Result<UpdateDatasetGroupResponse, RusotoError<UpdateDatasetGroupError>> {
+        self.runtime
+            .block_on(self.client.update_dataset_group(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -127,21 +128,46 @@ impl QueryForecastError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(QueryForecastError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        QueryForecastError::InvalidInput,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(QueryForecastError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        QueryForecastError::InvalidNextToken,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(QueryForecastError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        QueryForecastError::LimitExceeded,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(QueryForecastError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        QueryForecastError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(QueryForecastError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        QueryForecastError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -180,11 +206,12 @@ pub struct ForecastQueryClient {
 impl ForecastQueryClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> ForecastQueryClient {
         ForecastQueryClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "forecastquery"),
         }
     }
 
@@ -199,12 +226,32 @@ impl ForecastQueryClient {
     {
         ForecastQueryClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "forecastquery"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ForecastQueryClient {
-        ForecastQueryClient { client, region }
+        ForecastQueryClient {
+            client,
+            region: region_for_service(region, "forecastquery"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> ForecastQueryClient {
+        ForecastQueryClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("forecastquery"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ForecastQueryClient {
+        ForecastQueryClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -216,6 +263,7 @@ impl ForecastQuery for ForecastQueryClient {
         input: QueryForecastRequest,
     ) -> Result<QueryForecastResponse, RusotoError<QueryForecastError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("QueryForecast");
         request.add_header("x-amz-target", "AmazonForecastRuntime.QueryForecast");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -228,3 +276,91 @@ impl ForecastQuery for ForecastQueryClient {
         proto::json::ResponsePayload::new(&response).deserialize::<QueryForecastResponse, _>()
     }
 }
+
+/// A blocking client for the Amazon Forecast Query Service API, running the calls of a `ForecastQueryClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `ForecastQueryClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ForecastQueryBlockingClient {
+    client: ForecastQueryClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ForecastQueryBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> ForecastQueryBlockingClient {
+        ForecastQueryBlockingClient::from_client(ForecastQueryClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ForecastQueryBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ForecastQueryBlockingClient::from_client(ForecastQueryClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> ForecastQueryBlockingClient {
+        ForecastQueryBlockingClient::from_client(ForecastQueryClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> ForecastQueryBlockingClient {
+        ForecastQueryBlockingClient::from_client(ForecastQueryClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: ForecastQueryClient) -> ForecastQueryBlockingClient {
+        ForecastQueryBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> ForecastQueryBlockingClient {
+        ForecastQueryBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ForecastQueryBlockingClient {
+        ForecastQueryBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`ForecastQuery::query_forecast`].
+    pub fn query_forecast(
+        &self,
+        input: QueryForecastRequest,
+    ) -> Result<QueryForecastResponse, RusotoError<QueryForecastError>> {
+        self.runtime.block_on(self.client.query_forecast(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -2155,15 +2156,30 @@ impl BatchCreateVariableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(BatchCreateVariableError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchCreateVariableError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(BatchCreateVariableError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchCreateVariableError::InternalServer,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(BatchCreateVariableError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchCreateVariableError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2197,15 +2213,30 @@ impl BatchGetVariableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(BatchGetVariableError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetVariableError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(BatchGetVariableError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetVariableError::InternalServer,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(BatchGetVariableError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetVariableError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2241,22 +2272,38 @@ impl CreateDetectorVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDetectorVersionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDetectorVersionError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateDetectorVersionError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDetectorVersionError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDetectorVersionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDetectorVersionError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDetectorVersionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDetectorVersionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2289,12 +2336,22 @@ impl CreateModelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateModelError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateModelError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateModelError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateModelError::InternalServer,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2327,15 +2384,30 @@ impl CreateModelVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateModelVersionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateModelVersionError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateModelVersionError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateModelVersionError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateModelVersionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateModelVersionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2369,15 +2441,30 @@ impl CreateRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateRuleError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRuleError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateRuleError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRuleError::InternalServer,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateRuleError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRuleError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2411,15 +2498,30 @@ impl CreateVariableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateVariableError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVariableError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateVariableError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVariableError::InternalServer,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateVariableError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVariableError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2455,18 +2557,38 @@ impl DeleteDetectorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteDetectorError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDetectorError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteDetectorError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDetectorError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteDetectorError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDetectorError::InternalServer,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteDetectorError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDetectorError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2505,25 +2627,46 @@ impl DeleteDetectorVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteDetectorVersionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDetectorVersionError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteDetectorVersionError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDetectorVersionError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteDetectorVersionError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDetectorVersionError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDetectorVersionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDetectorVersionError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteDetectorVersionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDetectorVersionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2559,15 +2702,30 @@ impl DeleteEntityTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteEntityTypeError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEntityTypeError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteEntityTypeError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEntityTypeError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteEntityTypeError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEntityTypeError::InternalServer,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2601,15 +2759,30 @@ impl DeleteEventError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteEventError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEventError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteEventError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEventError::InternalServer,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteEventError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEventError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2643,15 +2816,30 @@ impl DeleteEventTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteEventTypeError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEventTypeError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteEventTypeError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEventTypeError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteEventTypeError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEventTypeError::InternalServer,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2687,18 +2875,38 @@ impl DeleteExternalModelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteExternalModelError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteExternalModelError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteExternalModelError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteExternalModelError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteExternalModelError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteExternalModelError::InternalServer,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteExternalModelError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteExternalModelError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2731,12 +2939,22 @@ impl DeleteLabelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteLabelError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLabelError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteLabelError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLabelError::InternalServer,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2769,15 +2987,30 @@ impl DeleteModelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteModelError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteModelError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteModelError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteModelError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteModelError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteModelError::InternalServer,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2811,15 +3044,30 @@ impl DeleteModelVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteModelVersionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteModelVersionError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteModelVersionError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteModelVersionError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteModelVersionError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteModelVersionError::InternalServer,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2855,18 +3103,38 @@ impl DeleteOutcomeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteOutcomeError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteOutcomeError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteOutcomeError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteOutcomeError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteOutcomeError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteOutcomeError::InternalServer,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteOutcomeError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteOutcomeError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2903,18 +3171,38 @@ impl DeleteRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteRuleError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRuleError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteRuleError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRuleError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteRuleError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRuleError::InternalServer,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteRuleError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRuleError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2951,18 +3239,38 @@ impl DeleteVariableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteVariableError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVariableError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteVariableError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVariableError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteVariableError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVariableError::InternalServer,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteVariableError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVariableError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2999,18 +3307,38 @@ impl DescribeDetectorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeDetectorError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDetectorError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeDetectorError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDetectorError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDetectorError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDetectorError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDetectorError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDetectorError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3045,19 +3373,30 @@ impl DescribeModelVersionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeModelVersionsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeModelVersionsError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeModelVersionsError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeModelVersionsError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeModelVersionsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeModelVersionsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3093,18 +3432,38 @@ impl GetDetectorVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetDetectorVersionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDetectorVersionError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetDetectorVersionError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDetectorVersionError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDetectorVersionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDetectorVersionError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetDetectorVersionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDetectorVersionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3141,18 +3500,38 @@ impl GetDetectorsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetDetectorsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDetectorsError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetDetectorsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDetectorsError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDetectorsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDetectorsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetDetectorsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDetectorsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3187,15 +3566,30 @@ impl GetEntityTypesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetEntityTypesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEntityTypesError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetEntityTypesError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEntityTypesError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetEntityTypesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEntityTypesError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3233,21 +3627,46 @@ impl GetEventPredictionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetEventPredictionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEventPredictionError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(GetEventPredictionError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEventPredictionError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetEventPredictionError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEventPredictionError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetEventPredictionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEventPredictionError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetEventPredictionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEventPredictionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3283,15 +3702,30 @@ impl GetEventTypesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetEventTypesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEventTypesError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetEventTypesError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEventTypesError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetEventTypesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEventTypesError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3327,18 +3761,38 @@ impl GetExternalModelsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetExternalModelsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetExternalModelsError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetExternalModelsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetExternalModelsError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetExternalModelsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetExternalModelsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetExternalModelsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetExternalModelsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3373,17 +3827,30 @@ impl GetKMSEncryptionKeyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetKMSEncryptionKeyError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetKMSEncryptionKeyError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetKMSEncryptionKeyError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetKMSEncryptionKeyError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetKMSEncryptionKeyError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetKMSEncryptionKeyError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3417,15 +3884,30 @@ impl GetLabelsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetLabelsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLabelsError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetLabelsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLabelsError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetLabelsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLabelsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3459,15 +3941,30 @@ impl GetModelVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetModelVersionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetModelVersionError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetModelVersionError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetModelVersionError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetModelVersionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetModelVersionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3501,15 +3998,30 @@ impl GetModelsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetModelsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetModelsError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetModelsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetModelsError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetModelsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetModelsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3545,18 +4057,38 @@ impl GetOutcomesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetOutcomesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOutcomesError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetOutcomesError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOutcomesError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetOutcomesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOutcomesError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetOutcomesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOutcomesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3593,18 +4125,38 @@ impl GetRulesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetRulesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRulesError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetRulesError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRulesError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetRulesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRulesError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetRulesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRulesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3641,18 +4193,38 @@ impl GetVariablesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetVariablesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVariablesError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetVariablesError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVariablesError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetVariablesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVariablesError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetVariablesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVariablesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3685,14 +4257,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::AccessDenied,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3725,15 +4305,30 @@ impl PutDetectorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(PutDetectorError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutDetectorError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(PutDetectorError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutDetectorError::InternalServer,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(PutDetectorError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutDetectorError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3765,12 +4360,22 @@ impl PutEntityTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(PutEntityTypeError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutEntityTypeError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(PutEntityTypeError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutEntityTypeError::InternalServer,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3801,12 +4406,22 @@ impl PutEventTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(PutEventTypeError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutEventTypeError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(PutEventTypeError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutEventTypeError::InternalServer,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3839,15 +4454,30 @@ impl PutExternalModelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(PutExternalModelError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutExternalModelError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(PutExternalModelError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutExternalModelError::InternalServer,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(PutExternalModelError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutExternalModelError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3881,17 +4511,30 @@ impl PutKMSEncryptionKeyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(PutKMSEncryptionKeyError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutKMSEncryptionKeyError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(PutKMSEncryptionKeyError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutKMSEncryptionKeyError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutKMSEncryptionKeyError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutKMSEncryptionKeyError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3923,12 +4566,22 @@ impl PutLabelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(PutLabelError::AccessDenied(err.msg))
-                }
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutLabelError::AccessDenied,
+                    )
+                }
                 "InternalServerException" => {
-                    return RusotoError::Service(PutLabelError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutLabelError::InternalServer,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3961,15 +4614,30 @@ impl PutOutcomeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(PutOutcomeError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutOutcomeError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(PutOutcomeError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutOutcomeError::InternalServer,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(PutOutcomeError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutOutcomeError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4001,12 +4669,22 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(TagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::AccessDenied,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4037,12 +4715,22 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UntagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::AccessDenied,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4077,22 +4765,38 @@ impl UpdateDetectorVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateDetectorVersionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDetectorVersionError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateDetectorVersionError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDetectorVersionError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDetectorVersionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDetectorVersionError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDetectorVersionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDetectorVersionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4129,21 +4833,30 @@ impl UpdateDetectorVersionMetadataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateDetectorVersionMetadataError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDetectorVersionMetadataError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
-                        UpdateDetectorVersionMetadataError::InternalServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDetectorVersionMetadataError::InternalServer,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDetectorVersionMetadataError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDetectorVersionMetadataError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4181,26 +4894,38 @@ impl UpdateDetectorVersionStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateDetectorVersionStatusError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDetectorVersionStatusError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateDetectorVersionStatusError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDetectorVersionStatusError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateDetectorVersionStatusError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDetectorVersionStatusError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDetectorVersionStatusError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDetectorVersionStatusError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4235,15 +4960,30 @@ impl UpdateModelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateModelError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateModelError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateModelError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateModelError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateModelError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateModelError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4277,15 +5017,30 @@ impl UpdateModelVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateModelVersionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateModelVersionError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateModelVersionError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateModelVersionError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateModelVersionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateModelVersionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4319,21 +5074,30 @@ impl UpdateModelVersionStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateModelVersionStatusError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateModelVersionStatusError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateModelVersionStatusError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateModelVersionStatusError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateModelVersionStatusError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateModelVersionStatusError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4369,18 +5133,38 @@ impl UpdateRuleMetadataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateRuleMetadataError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRuleMetadataError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateRuleMetadataError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRuleMetadataError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateRuleMetadataError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRuleMetadataError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateRuleMetadataError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRuleMetadataError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4417,18 +5201,38 @@ impl UpdateRuleVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateRuleVersionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRuleVersionError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateRuleVersionError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRuleVersionError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateRuleVersionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRuleVersionError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateRuleVersionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRuleVersionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4465,18 +5269,38 @@ impl UpdateVariableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateVariableError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVariableError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateVariableError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVariableError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateVariableError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVariableError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateVariableError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVariableError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4825,11 +5649,12 @@ pub struct FraudDetectorClient {
 impl FraudDetectorClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> FraudDetectorClient {
         FraudDetectorClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "FraudDetector"),
         }
     }
 
@@ -4844,12 +5669,32 @@ impl FraudDetectorClient {
     {
         FraudDetectorClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "FraudDetector"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> FraudDetectorClient {
-        FraudDetectorClient { client, region }
+        FraudDetectorClient {
+            client,
+            region: region_for_service(region, "FraudDetector"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> FraudDetectorClient {
+        FraudDetectorClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("FraudDetector"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> FraudDetectorClient {
+        FraudDetectorClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -4861,6 +5706,7 @@ impl FraudDetector for FraudDetectorClient {
         input: BatchCreateVariableRequest,
     ) -> Result<BatchCreateVariableResult, RusotoError<BatchCreateVariableError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchCreateVariable");
         request.add_header(
             "x-amz-target",
             "AWSHawksNestServiceFacade.BatchCreateVariable",
@@ -4882,6 +5728,7 @@ impl FraudDetector for FraudDetectorClient {
         input: BatchGetVariableRequest,
     ) -> Result<BatchGetVariableResult, RusotoError<BatchGetVariableError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchGetVariable");
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.BatchGetVariable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4900,6 +5747,7 @@ impl FraudDetector for FraudDetectorClient {
         input: CreateDetectorVersionRequest,
     ) -> Result<CreateDetectorVersionResult, RusotoError<CreateDetectorVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateDetectorVersion");
         request.add_header(
             "x-amz-target",
             "AWSHawksNestServiceFacade.CreateDetectorVersion",
@@ -4921,6 +5769,7 @@ impl FraudDetector for FraudDetectorClient {
         input: CreateModelRequest,
     ) -> Result<CreateModelResult, RusotoError<CreateModelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateModel");
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.CreateModel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4939,6 +5788,7 @@ impl FraudDetector for FraudDetectorClient {
         input: CreateModelVersionRequest,
     ) -> Result<CreateModelVersionResult, RusotoError<CreateModelVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateModelVersion");
         request.add_header(
             "x-amz-target",
             "AWSHawksNestServiceFacade.CreateModelVersion",
@@ -4960,6 +5810,7 @@ impl FraudDetector for FraudDetectorClient {
         input: CreateRuleRequest,
     ) -> Result<CreateRuleResult, RusotoError<CreateRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateRule");
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.CreateRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4978,6 +5829,7 @@ impl FraudDetector for FraudDetectorClient {
         input: CreateVariableRequest,
     ) -> Result<CreateVariableResult, RusotoError<CreateVariableError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateVariable");
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.CreateVariable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4996,6 +5848,7 @@ impl FraudDetector for FraudDetectorClient {
         input: DeleteDetectorRequest,
     ) -> Result<DeleteDetectorResult, RusotoError<DeleteDetectorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteDetector");
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.DeleteDetector");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5014,6 +5867,7 @@ impl FraudDetector for FraudDetectorClient {
         input: DeleteDetectorVersionRequest,
     ) -> Result<DeleteDetectorVersionResult, RusotoError<DeleteDetectorVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteDetectorVersion");
         request.add_header(
             "x-amz-target",
             "AWSHawksNestServiceFacade.DeleteDetectorVersion",
@@ -5035,6 +5889,7 @@ impl FraudDetector for FraudDetectorClient {
         input: DeleteEntityTypeRequest,
     ) -> Result<DeleteEntityTypeResult, RusotoError<DeleteEntityTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteEntityType");
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.DeleteEntityType");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5053,6 +5908,7 @@ impl FraudDetector for FraudDetectorClient {
         input: DeleteEventRequest,
     ) -> Result<DeleteEventResult, RusotoError<DeleteEventError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteEvent");
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.DeleteEvent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5071,6 +5927,7 @@ impl FraudDetector for FraudDetectorClient {
         input: DeleteEventTypeRequest,
     ) -> Result<DeleteEventTypeResult, RusotoError<DeleteEventTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteEventType");
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.DeleteEventType");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5089,6 +5946,7 @@ impl FraudDetector for FraudDetectorClient {
         input: DeleteExternalModelRequest,
     ) -> Result<DeleteExternalModelResult, RusotoError<DeleteExternalModelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteExternalModel");
         request.add_header(
             "x-amz-target",
             "AWSHawksNestServiceFacade.DeleteExternalModel",
@@ -5110,6 +5968,7 @@ impl FraudDetector for FraudDetectorClient {
         input: DeleteLabelRequest,
     ) -> Result<DeleteLabelResult, RusotoError<DeleteLabelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteLabel");
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.DeleteLabel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5128,6 +5987,7 @@ impl FraudDetector for FraudDetectorClient {
         input: DeleteModelRequest,
     ) -> Result<DeleteModelResult, RusotoError<DeleteModelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteModel");
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.DeleteModel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5146,6 +6006,7 @@ impl FraudDetector for FraudDetectorClient {
         input: DeleteModelVersionRequest,
     ) -> Result<DeleteModelVersionResult, RusotoError<DeleteModelVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteModelVersion");
         request.add_header(
             "x-amz-target",
             "AWSHawksNestServiceFacade.DeleteModelVersion",
@@ -5167,6 +6028,7 @@ impl FraudDetector for FraudDetectorClient {
         input: DeleteOutcomeRequest,
     ) -> Result<DeleteOutcomeResult, RusotoError<DeleteOutcomeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteOutcome");
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.DeleteOutcome");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5185,6 +6047,7 @@ impl FraudDetector for FraudDetectorClient {
         input: DeleteRuleRequest,
     ) -> Result<DeleteRuleResult, RusotoError<DeleteRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteRule");
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.DeleteRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5203,6 +6066,7 @@ impl FraudDetector for FraudDetectorClient {
         input: DeleteVariableRequest,
     ) -> Result<DeleteVariableResult, RusotoError<DeleteVariableError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteVariable");
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.DeleteVariable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5221,6 +6085,7 @@ impl FraudDetector for FraudDetectorClient {
         input: DescribeDetectorRequest,
     ) -> Result<DescribeDetectorResult, RusotoError<DescribeDetectorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeDetector");
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.DescribeDetector");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5239,6 +6104,7 @@ impl FraudDetector for FraudDetectorClient {
         input: DescribeModelVersionsRequest,
     ) -> Result<DescribeModelVersionsResult, RusotoError<DescribeModelVersionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeModelVersions");
         request.add_header(
             "x-amz-target",
             "AWSHawksNestServiceFacade.DescribeModelVersions",
@@ -5260,6 +6126,7 @@ impl FraudDetector for FraudDetectorClient {
         input: GetDetectorVersionRequest,
     ) -> Result<GetDetectorVersionResult, RusotoError<GetDetectorVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDetectorVersion");
         request.add_header(
             "x-amz-target",
             "AWSHawksNestServiceFacade.GetDetectorVersion",
@@ -5281,6 +6148,7 @@ impl FraudDetector for FraudDetectorClient {
         input: GetDetectorsRequest,
     ) -> Result<GetDetectorsResult, RusotoError<GetDetectorsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDetectors");
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.GetDetectors");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5299,6 +6167,7 @@ impl FraudDetector for FraudDetectorClient {
         input: GetEntityTypesRequest,
     ) -> Result<GetEntityTypesResult, RusotoError<GetEntityTypesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetEntityTypes");
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.GetEntityTypes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5317,6 +6186,7 @@ impl FraudDetector for FraudDetectorClient {
         input: GetEventPredictionRequest,
     ) -> Result<GetEventPredictionResult, RusotoError<GetEventPredictionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetEventPrediction");
         request.add_header(
             "x-amz-target",
             "AWSHawksNestServiceFacade.GetEventPrediction",
@@ -5338,6 +6208,7 @@ impl FraudDetector for FraudDetectorClient {
         input: GetEventTypesRequest,
     ) -> Result<GetEventTypesResult, RusotoError<GetEventTypesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetEventTypes");
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.GetEventTypes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5356,6 +6227,7 @@ impl FraudDetector for FraudDetectorClient {
         input: GetExternalModelsRequest,
     ) -> Result<GetExternalModelsResult, RusotoError<GetExternalModelsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetExternalModels");
         request.add_header(
             "x-amz-target",
             "AWSHawksNestServiceFacade.GetExternalModels",
@@ -5376,6 +6248,7 @@ impl FraudDetector for FraudDetectorClient {
         &self,
     ) -> Result<GetKMSEncryptionKeyResult, RusotoError<GetKMSEncryptionKeyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetKMSEncryptionKey");
         request.add_header(
             "x-amz-target",
             "AWSHawksNestServiceFacade.GetKMSEncryptionKey",
@@ -5396,6 +6269,7 @@ impl FraudDetector for FraudDetectorClient {
         input: GetLabelsRequest,
     ) -> Result<GetLabelsResult, RusotoError<GetLabelsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetLabels");
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.GetLabels");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5414,6 +6288,7 @@ impl FraudDetector for FraudDetectorClient {
         input: GetModelVersionRequest,
     ) -> Result<GetModelVersionResult, RusotoError<GetModelVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetModelVersion");
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.GetModelVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5432,6 +6307,7 @@ impl FraudDetector for FraudDetectorClient {
         input: GetModelsRequest,
     ) -> Result<GetModelsResult, RusotoError<GetModelsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetModels");
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.GetModels");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5450,6 +6326,7 @@ impl FraudDetector for FraudDetectorClient {
         input: GetOutcomesRequest,
     ) -> Result<GetOutcomesResult, RusotoError<GetOutcomesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetOutcomes");
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.GetOutcomes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5468,6 +6345,7 @@ impl FraudDetector for FraudDetectorClient {
         input: GetRulesRequest,
     ) -> Result<GetRulesResult, RusotoError<GetRulesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRules");
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.GetRules");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5486,6 +6364,7 @@ impl FraudDetector for FraudDetectorClient {
         input: GetVariablesRequest,
     ) -> Result<GetVariablesResult, RusotoError<GetVariablesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetVariables");
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.GetVariables");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5504,6 +6383,7 @@ impl FraudDetector for FraudDetectorClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResult, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header(
             "x-amz-target",
             "AWSHawksNestServiceFacade.ListTagsForResource",
@@ -5525,6 +6405,7 @@ impl FraudDetector for FraudDetectorClient {
         input: PutDetectorRequest,
     ) -> Result<PutDetectorResult, RusotoError<PutDetectorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutDetector");
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.PutDetector");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5543,6 +6424,7 @@ impl FraudDetector for FraudDetectorClient {
         input: PutEntityTypeRequest,
     ) -> Result<PutEntityTypeResult, RusotoError<PutEntityTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutEntityType");
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.PutEntityType");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5561,6 +6443,7 @@ impl FraudDetector for FraudDetectorClient {
         input: PutEventTypeRequest,
     ) -> Result<PutEventTypeResult, RusotoError<PutEventTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutEventType");
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.PutEventType");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5579,6 +6462,7 @@ impl FraudDetector for FraudDetectorClient {
         input: PutExternalModelRequest,
     ) -> Result<PutExternalModelResult, RusotoError<PutExternalModelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutExternalModel");
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.PutExternalModel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5597,6 +6481,7 @@ impl FraudDetector for FraudDetectorClient {
         input: PutKMSEncryptionKeyRequest,
     ) -> Result<PutKMSEncryptionKeyResult, RusotoError<PutKMSEncryptionKeyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutKMSEncryptionKey");
         request.add_header(
             "x-amz-target",
             "AWSHawksNestServiceFacade.PutKMSEncryptionKey",
@@ -5618,6 +6503,7 @@ impl FraudDetector for FraudDetectorClient {
         input: PutLabelRequest,
     ) -> Result<PutLabelResult, RusotoError<PutLabelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutLabel");
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.PutLabel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5636,6 +6522,7 @@ impl FraudDetector for FraudDetectorClient {
         input: PutOutcomeRequest,
     ) -> Result<PutOutcomeResult, RusotoError<PutOutcomeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutOutcome");
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.PutOutcome");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5654,6 +6541,7 @@ impl FraudDetector for FraudDetectorClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResult, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5672,6 +6560,7 @@ impl FraudDetector for FraudDetectorClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResult, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5690,6 +6579,7 @@ impl FraudDetector for FraudDetectorClient {
         input: UpdateDetectorVersionRequest,
     ) -> Result<UpdateDetectorVersionResult, RusotoError<UpdateDetectorVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateDetectorVersion");
         request.add_header(
             "x-amz-target",
             "AWSHawksNestServiceFacade.UpdateDetectorVersion",
@@ -5712,6 +6602,7 @@ impl FraudDetector for FraudDetectorClient {
     ) -> Result<UpdateDetectorVersionMetadataResult, RusotoError<UpdateDetectorVersionMetadataError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateDetectorVersionMetadata");
         request.add_header(
             "x-amz-target",
             "AWSHawksNestServiceFacade.UpdateDetectorVersionMetadata",
@@ -5735,6 +6626,7 @@ impl FraudDetector for FraudDetectorClient {
     ) -> Result<UpdateDetectorVersionStatusResult, RusotoError<UpdateDetectorVersionStatusError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateDetectorVersionStatus");
         request.add_header(
             "x-amz-target",
             "AWSHawksNestServiceFacade.UpdateDetectorVersionStatus",
@@ -5757,6 +6649,7 @@ impl FraudDetector for FraudDetectorClient {
         input: UpdateModelRequest,
     ) -> Result<UpdateModelResult, RusotoError<UpdateModelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateModel");
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.UpdateModel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5775,6 +6668,7 @@ impl FraudDetector for FraudDetectorClient {
         input: UpdateModelVersionRequest,
     ) -> Result<UpdateModelVersionResult, RusotoError<UpdateModelVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateModelVersion");
         request.add_header(
             "x-amz-target",
             "AWSHawksNestServiceFacade.UpdateModelVersion",
@@ -5796,6 +6690,7 @@ impl FraudDetector for FraudDetectorClient {
         input: UpdateModelVersionStatusRequest,
     ) -> Result<UpdateModelVersionStatusResult, RusotoError<UpdateModelVersionStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateModelVersionStatus");
         request.add_header(
             "x-amz-target",
             "AWSHawksNestServiceFacade.UpdateModelVersionStatus",
@@ -5818,6 +6713,7 @@ impl FraudDetector for FraudDetectorClient {
         input: UpdateRuleMetadataRequest,
     ) -> Result<UpdateRuleMetadataResult, RusotoError<UpdateRuleMetadataError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateRuleMetadata");
         request.add_header(
             "x-amz-target",
             "AWSHawksNestServiceFacade.UpdateRuleMetadata",
@@ -5839,6 +6735,7 @@ impl FraudDetector for FraudDetectorClient {
         input: UpdateRuleVersionRequest,
     ) -> Result<UpdateRuleVersionResult, RusotoError<UpdateRuleVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateRuleVersion");
         request.add_header(
             "x-amz-target",
             "AWSHawksNestServiceFacade.UpdateRuleVersion",
@@ -5860,6 +6757,7 @@ impl FraudDetector for FraudDetectorClient {
         input: UpdateVariableRequest,
     ) -> Result<UpdateVariableResult, RusotoError<UpdateVariableError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateVariable");
         request.add_header("x-amz-target", "AWSHawksNestServiceFacade.UpdateVariable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5872,3 +6770,527 @@ impl FraudDetector for FraudDetectorClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateVariableResult, _>()
     }
 }
+
+/// A blocking client for the Amazon Fraud Detector API, running the calls of a `FraudDetectorClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `FraudDetectorClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct FraudDetectorBlockingClient {
+    client: FraudDetectorClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl FraudDetectorBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> FraudDetectorBlockingClient {
+        FraudDetectorBlockingClient::from_client(FraudDetectorClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> FraudDetectorBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        FraudDetectorBlockingClient::from_client(FraudDetectorClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> FraudDetectorBlockingClient {
+        FraudDetectorBlockingClient::from_client(FraudDetectorClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> FraudDetectorBlockingClient {
+        FraudDetectorBlockingClient::from_client(FraudDetectorClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: FraudDetectorClient) -> FraudDetectorBlockingClient {
+        FraudDetectorBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> FraudDetectorBlockingClient {
+        FraudDetectorBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> FraudDetectorBlockingClient {
+        FraudDetectorBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`FraudDetector::batch_create_variable`].
+    pub fn batch_create_variable(
+        &self,
+        input: BatchCreateVariableRequest,
+    ) -> Result<BatchCreateVariableResult, RusotoError<BatchCreateVariableError>> {
+        self.runtime
+            .block_on(self.client.batch_create_variable(input))
+    }
+
+    /// Blocking version of [`FraudDetector::batch_get_variable`].
+    pub fn batch_get_variable(
+        &self,
+        input: BatchGetVariableRequest,
+    ) -> Result<BatchGetVariableResult, RusotoError<BatchGetVariableError>> {
+        self.runtime.block_on(self.client.batch_get_variable(input))
+    }
+
+    /// Blocking version of [`FraudDetector::create_detector_version`].
+    pub fn create_detector_version(
+        &self,
+        input: CreateDetectorVersionRequest,
+    ) -> Result<CreateDetectorVersionResult, RusotoError<CreateDetectorVersionError>> {
+        self.runtime
+            .block_on(self.client.create_detector_version(input))
+    }
+
+    /// Blocking version of [`FraudDetector::create_model`].
+    pub fn create_model(
+        &self,
+        input: CreateModelRequest,
+    ) -> Result<CreateModelResult, RusotoError<CreateModelError>> {
+        self.runtime.block_on(self.client.create_model(input))
+    }
+
+    /// Blocking version of [`FraudDetector::create_model_version`].
+    pub fn create_model_version(
+        &self,
+        input: CreateModelVersionRequest,
+    ) -> Result<CreateModelVersionResult, RusotoError<CreateModelVersionError>> {
+        self.runtime
+            .block_on(self.client.create_model_version(input))
+    }
+
+    /// Blocking version of [`FraudDetector::create_rule`].
+    pub fn create_rule(
+        &self,
+        input: CreateRuleRequest,
+    ) -> Result<CreateRuleResult, RusotoError<CreateRuleError>> {
+        self.runtime.block_on(self.client.create_rule(input))
+    }
+
+    /// Blocking version of [`FraudDetector::create_variable`].
+    pub fn create_variable(
+        &self,
+        input: CreateVariableRequest,
+    ) -> Result<CreateVariableResult, RusotoError<CreateVariableError>> {
+        self.runtime.block_on(self.client.create_variable(input))
+    }
+
+    /// Blocking version of [`FraudDetector::delete_detector`].
+    pub fn delete_detector(
+        &self,
+        input: DeleteDetectorRequest,
+    ) -> Result<DeleteDetectorResult, RusotoError<DeleteDetectorError>> {
+        self.runtime.block_on(self.client.delete_detector(input))
+    }
+
+    /// Blocking version of [`FraudDetector::delete_detector_version`].
+    pub fn delete_detector_version(
+        &self,
+        input: DeleteDetectorVersionRequest,
+    ) -> Result<DeleteDetectorVersionResult, RusotoError<DeleteDetectorVersionError>> {
+        self.runtime
+            .block_on(self.client.delete_detector_version(input))
+    }
+
+    /// Blocking version of [`FraudDetector::delete_entity_type`].
+    pub fn delete_entity_type(
+        &self,
+        input: DeleteEntityTypeRequest,
+    ) -> Result<DeleteEntityTypeResult, RusotoError<DeleteEntityTypeError>> {
+        self.runtime.block_on(self.client.delete_entity_type(input))
+    }
+
+    /// Blocking version of [`FraudDetector::delete_event`].
+    pub fn delete_event(
+        &self,
+        input: DeleteEventRequest,
+    ) -> Result<DeleteEventResult, RusotoError<DeleteEventError>> {
+        self.runtime.block_on(self.client.delete_event(input))
+    }
+
+    /// Blocking version of [`FraudDetector::delete_event_type`].
+    pub fn delete_event_type(
+        &self,
+        input: DeleteEventTypeRequest,
+    ) -> Result<DeleteEventTypeResult, RusotoError<DeleteEventTypeError>> {
+        self.runtime.block_on(self.client.delete_event_type(input))
+    }
+
+    /// Blocking version of [`FraudDetector::delete_external_model`].
+    pub fn delete_external_model(
+        &self,
+        input: DeleteExternalModelRequest,
+    ) -> Result<DeleteExternalModelResult, RusotoError<DeleteExternalModelError>> {
+        self.runtime
+            .block_on(self.client.delete_external_model(input))
+    }
+
+    /// Blocking version of [`FraudDetector::delete_label`].
+    pub fn delete_label(
+        &self,
+        input: DeleteLabelRequest,
+    ) -> Result<DeleteLabelResult, RusotoError<DeleteLabelError>> {
+        self.runtime.block_on(self.client.delete_label(input))
+    }
+
+    /// Blocking version of [`FraudDetector::delete_model`].
+    pub fn delete_model(
+        &self,
+        input: DeleteModelRequest,
+    ) -> Result<DeleteModelResult, RusotoError<DeleteModelError>> {
+        self.runtime.block_on(self.client.delete_model(input))
+    }
+
+    /// Blocking version of [`FraudDetector::delete_model_version`].
+    pub fn delete_model_version(
+        &self,
+        input: DeleteModelVersionRequest,
+    ) -> Result<DeleteModelVersionResult, RusotoError<DeleteModelVersionError>> {
+        self.runtime
+            .block_on(self.client.delete_model_version(input))
+    }
+
+    /// Blocking version of [`FraudDetector::delete_outcome`].
+    pub fn delete_outcome(
+        &self,
+        input: DeleteOutcomeRequest,
+    ) -> Result<DeleteOutcomeResult, RusotoError<DeleteOutcomeError>> {
+        self.runtime.block_on(self.client.delete_outcome(input))
+    }
+
+    /// Blocking version of [`FraudDetector::delete_rule`].
+    pub fn delete_rule(
+        &self,
+        input: DeleteRuleRequest,
+    ) -> Result<DeleteRuleResult, RusotoError<DeleteRuleError>> {
+        self.runtime.block_on(self.client.delete_rule(input))
+    }
+
+    /// Blocking version of [`FraudDetector::delete_variable`].
+    pub fn delete_variable(
+        &self,
+        input: DeleteVariableRequest,
+    ) -> Result<DeleteVariableResult, RusotoError<DeleteVariableError>> {
+        self.runtime.block_on(self.client.delete_variable(input))
+    }
+
+    /// Blocking version of [`FraudDetector::describe_detector`].
+    pub fn describe_detector(
+        &self,
+        input: DescribeDetectorRequest,
+    ) -> Result<DescribeDetectorResult, RusotoError<DescribeDetectorError>> {
+        self.runtime.block_on(self.client.describe_detector(input))
+    }
+
+    /// Blocking version of [`FraudDetector::describe_model_versions`].
+    pub fn describe_model_versions(
+        &self,
+        input: DescribeModelVersionsRequest,
+    ) -> Result<DescribeModelVersionsResult, RusotoError<DescribeModelVersionsError>> {
+        self.runtime
+            .block_on(self.client.describe_model_versions(input))
+    }
+
+    /// Blocking version of [`FraudDetector::get_detector_version`].
+    pub fn get_detector_version(
+        &self,
+        input: GetDetectorVersionRequest,
+    ) -> Result<GetDetectorVersionResult, RusotoError<GetDetectorVersionError>> {
+        self.runtime
+            .block_on(self.client.get_detector_version(input))
+    }
+
+    /// Blocking version of [`FraudDetector::get_detectors`].
+    pub fn get_detectors(
+        &self,
+        input: GetDetectorsRequest,
+    ) -> Result<GetDetectorsResult, RusotoError<GetDetectorsError>> {
+        self.runtime.block_on(self.client.get_detectors(input))
+    }
+
+    /// Blocking version of [`FraudDetector::get_entity_types`].
+    pub fn get_entity_types(
+        &self,
+        input: GetEntityTypesRequest,
+    ) -> Result<GetEntityTypesResult, RusotoError<GetEntityTypesError>> {
+        self.runtime.block_on(self.client.get_entity_types(input))
+    }
+
+    /// Blocking version of [`FraudDetector::get_event_prediction`].
+    pub fn get_event_prediction(
+        &self,
+        input: GetEventPredictionRequest,
+    ) -> Result<GetEventPredictionResult, RusotoError<GetEventPredictionError>> {
+        self.runtime
+            .block_on(self.client.get_event_prediction(input))
+    }
+
+    /// Blocking version of [`FraudDetector::get_event_types`].
+    pub fn get_event_types(
+        &self,
+        input: GetEventTypesRequest,
+    ) -> Result<GetEventTypesResult, RusotoError<GetEventTypesError>> {
+        self.runtime.block_on(self.client.get_event_types(input))
+    }
+
+    /// Blocking version of [`FraudDetector::get_external_models`].
+    pub fn get_external_models(
+        &self,
+        input: GetExternalModelsRequest,
+    ) -> Result<GetExternalModelsResult, RusotoError<GetExternalModelsError>> {
+        self.runtime
+            .block_on(self.client.get_external_models(input))
+    }
+
+    /// Blocking version of [`FraudDetector::get_kms_encryption_key`].
+    pub fn get_kms_encryption_key(
+        &self,
+    ) -> Result<GetKMSEncryptionKeyResult, RusotoError<GetKMSEncryptionKeyError>> {
+        self.runtime.block_on(self.client.get_kms_encryption_key())
+    }
+
+    /// Blocking version of [`FraudDetector::get_labels`].
+    pub fn get_labels(
+        &self,
+        input: GetLabelsRequest,
+    ) -> Result<GetLabelsResult, RusotoError<GetLabelsError>> {
+        self.runtime.block_on(self.client.get_labels(input))
+    }
+
+    /// Blocking version of [`FraudDetector::get_model_version`].
+    pub fn get_model_version(
+        &self,
+        input: GetModelVersionRequest,
+    ) -> Result<GetModelVersionResult, RusotoError<GetModelVersionError>> {
+        self.runtime.block_on(self.client.get_model_version(input))
+    }
+
+    /// Blocking version of [`FraudDetector::get_models`].
+    pub fn get_models(
+        &self,
+        input: GetModelsRequest,
+    ) -> Result<GetModelsResult, RusotoError<GetModelsError>> {
+        self.runtime.block_on(self.client.get_models(input))
+    }
+
+    /// Blocking version of [`FraudDetector::get_outcomes`].
+    pub fn get_outcomes(
+        &self,
+        input: GetOutcomesRequest,
+    ) -> Result<GetOutcomesResult, RusotoError<GetOutcomesError>> {
+        self.runtime.block_on(self.client.get_outcomes(input))
+    }
+
+    /// Blocking version of [`FraudDetector::get_rules`].
+    pub fn get_rules(
+        &self,
+        input: GetRulesRequest,
+    ) -> Result<GetRulesResult, RusotoError<GetRulesError>> {
+        self.runtime.block_on(self.client.get_rules(input))
+    }
+
+    /// Blocking version of [`FraudDetector::get_variables`].
+    pub fn get_variables(
+        &self,
+        input: GetVariablesRequest,
+    ) -> Result<GetVariablesResult, RusotoError<GetVariablesError>> {
+        self.runtime.block_on(self.client.get_variables(input))
+    }
+
+    /// Blocking version of [`FraudDetector::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResult, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`FraudDetector::put_detector`].
+    pub fn put_detector(
+        &self,
+        input: PutDetectorRequest,
+    ) -> Result<PutDetectorResult, RusotoError<PutDetectorError>> {
+        self.runtime.block_on(self.client.put_detector(input))
+    }
+
+    /// Blocking version of [`FraudDetector::put_entity_type`].
+    pub fn put_entity_type(
+        &self,
+        input: PutEntityTypeRequest,
+    ) -> Result<PutEntityTypeResult, RusotoError<PutEntityTypeError>> {
+        self.runtime.block_on(self.client.put_entity_type(input))
+    }
+
+    /// Blocking version of [`FraudDetector::put_event_type`].
+    pub fn put_event_type(
+        &self,
+        input: PutEventTypeRequest,
+    ) -> Result<PutEventTypeResult, RusotoError<PutEventTypeError>> {
+        self.runtime.block_on(self.client.put_event_type(input))
+    }
+
+    /// Blocking version of [`FraudDetector::put_external_model`].
+    pub fn put_external_model(
+        &self,
+        input: PutExternalModelRequest,
+    ) -> Result<PutExternalModelResult, RusotoError<PutExternalModelError>> {
+        self.runtime.block_on(self.client.put_external_model(input))
+    }
+
+    /// Blocking version of [`FraudDetector::put_kms_encryption_key`].
+    pub fn put_kms_encryption_key(
+        &self,
+        input: PutKMSEncryptionKeyRequest,
+    ) -> Result<PutKMSEncryptionKeyResult, RusotoError<PutKMSEncryptionKeyError>> {
+        self.runtime
+            .block_on(self.client.put_kms_encryption_key(input))
+    }
+
+    /// Blocking version of [`FraudDetector::put_label`].
+    pub fn put_label(
+        &self,
+        input: PutLabelRequest,
+    ) -> Result<PutLabelResult, RusotoError<PutLabelError>> {
+        self.runtime.block_on(self.client.put_label(input))
+    }
+
+    /// Blocking version of [`FraudDetector::put_outcome`].
+    pub fn put_outcome(
+        &self,
+        input: PutOutcomeRequest,
+    ) -> Result<PutOutcomeResult, RusotoError<PutOutcomeError>> {
+        self.runtime.block_on(self.client.put_outcome(input))
+    }
+
+    /// Blocking version of [`FraudDetector::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResult, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`FraudDetector::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResult, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`FraudDetector::update_detector_version`].
+    pub fn update_detector_version(
+        &self,
+        input: UpdateDetectorVersionRequest,
+    ) -> Result<UpdateDetectorVersionResult, RusotoError<UpdateDetectorVersionError>> {
+        self.runtime
+            .block_on(self.client.update_detector_version(input))
+    }
+
+    /// Blocking version of [`FraudDetector::update_detector_version_metadata`].
+    pub fn update_detector_version_metadata(
+        &self,
+        input: UpdateDetectorVersionMetadataRequest,
+    ) -> Result<UpdateDetectorVersionMetadataResult, RusotoError<UpdateDetectorVersionMetadataError>>
+    {
+        self.runtime
+            .block_on(self.client.update_detector_version_metadata(input))
+    }
+
+    /// Blocking version of [`FraudDetector::update_detector_version_status`].
+    pub fn update_detector_version_status(
+        &self,
+        input: UpdateDetectorVersionStatusRequest,
+    ) -> Result<UpdateDetectorVersionStatusResult, RusotoError<UpdateDetectorVersionStatusError>>
+    {
+        self.runtime
+            .block_on(self.client.update_detector_version_status(input))
+    }
+
+    /// Blocking version of [`FraudDetector::update_model`].
+    pub fn update_model(
+        &self,
+        input: UpdateModelRequest,
+    ) -> Result<UpdateModelResult, RusotoError<UpdateModelError>> {
+        self.runtime.block_on(self.client.update_model(input))
+    }
+
+    /// Blocking version of [`FraudDetector::update_model_version`].
+    pub fn update_model_version(
+        &self,
+        input: UpdateModelVersionRequest,
+    ) -> Result<UpdateModelVersionResult, RusotoError<UpdateModelVersionError>> {
+        self.runtime
+            .block_on(self.client.update_model_version(input))
+    }
+
+    /// Blocking version of [`FraudDetector::update_model_version_status`].
+    pub fn update_model_version_status(
+        &self,
+        input: UpdateModelVersionStatusRequest,
+    ) -> Result<UpdateModelVersionStatusResult, RusotoError<UpdateModelVersionStatusError>> {
+        self.runtime
+            .block_on(self.client.update_model_version_status(input))
+    }
+
+    /// Blocking version of [`FraudDetector::update_rule_metadata`].
+    pub fn update_rule_metadata(
+        &self,
+        input: UpdateRuleMetadataRequest,
+    ) -> Result<UpdateRuleMetadataResult, RusotoError<UpdateRuleMetadataError>> {
+        self.runtime
+            .block_on(self.client.update_rule_metadata(input))
+    }
+
+    /// Blocking version of [`FraudDetector::update_rule_version`].
+    pub fn update_rule_version(
+        &self,
+        input: UpdateRuleVersionRequest,
+    ) -> Result<UpdateRuleVersionResult, RusotoError<UpdateRuleVersionError>> {
+        self.runtime
+            .block_on(self.client.update_rule_version(input))
+    }
+
+    /// Blocking version of [`FraudDetector::update_variable`].
+    pub fn update_variable(
+        &self,
+        input: UpdateVariableRequest,
+    ) -> Result<UpdateVariableResult, RusotoError<UpdateVariableError>> {
+        self.runtime.block_on(self.client.update_variable(input))
+    }
+}
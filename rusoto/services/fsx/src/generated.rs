@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1335,21 +1336,30 @@ impl AssociateFileSystemAliasesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(AssociateFileSystemAliasesError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateFileSystemAliasesError::BadRequest,
+                    )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(
-                        AssociateFileSystemAliasesError::FileSystemNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateFileSystemAliasesError::FileSystemNotFound,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        AssociateFileSystemAliasesError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateFileSystemAliasesError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1391,29 +1401,46 @@ impl CancelDataRepositoryTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(CancelDataRepositoryTaskError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelDataRepositoryTaskError::BadRequest,
+                    )
                 }
                 "DataRepositoryTaskEnded" => {
-                    return RusotoError::Service(
-                        CancelDataRepositoryTaskError::DataRepositoryTaskEnded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelDataRepositoryTaskError::DataRepositoryTaskEnded,
                     )
                 }
                 "DataRepositoryTaskNotFound" => {
-                    return RusotoError::Service(
-                        CancelDataRepositoryTaskError::DataRepositoryTaskNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelDataRepositoryTaskError::DataRepositoryTaskNotFound,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        CancelDataRepositoryTaskError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelDataRepositoryTaskError::InternalServerError,
                     )
                 }
                 "UnsupportedOperation" => {
-                    return RusotoError::Service(
-                        CancelDataRepositoryTaskError::UnsupportedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelDataRepositoryTaskError::UnsupportedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1463,29 +1490,62 @@ impl CreateBackupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BackupInProgress" => {
-                    return RusotoError::Service(CreateBackupError::BackupInProgress(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBackupError::BackupInProgress,
+                    )
                 }
                 "BadRequest" => {
-                    return RusotoError::Service(CreateBackupError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBackupError::BadRequest,
+                    )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(CreateBackupError::FileSystemNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBackupError::FileSystemNotFound,
+                    )
                 }
                 "IncompatibleParameterError" => {
-                    return RusotoError::Service(CreateBackupError::IncompatibleParameterError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateBackupError::IncompatibleParameterError,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateBackupError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBackupError::InternalServerError,
+                    )
                 }
                 "ServiceLimitExceeded" => {
-                    return RusotoError::Service(CreateBackupError::ServiceLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBackupError::ServiceLimitExceeded,
+                    )
                 }
                 "UnsupportedOperation" => {
-                    return RusotoError::Service(CreateBackupError::UnsupportedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBackupError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1531,39 +1591,62 @@ impl CreateDataRepositoryTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(CreateDataRepositoryTaskError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataRepositoryTaskError::BadRequest,
+                    )
                 }
                 "DataRepositoryTaskExecuting" => {
-                    return RusotoError::Service(
-                        CreateDataRepositoryTaskError::DataRepositoryTaskExecuting(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataRepositoryTaskError::DataRepositoryTaskExecuting,
                     )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(CreateDataRepositoryTaskError::FileSystemNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDataRepositoryTaskError::FileSystemNotFound,
+                    )
                 }
                 "IncompatibleParameterError" => {
-                    return RusotoError::Service(
-                        CreateDataRepositoryTaskError::IncompatibleParameterError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataRepositoryTaskError::IncompatibleParameterError,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        CreateDataRepositoryTaskError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataRepositoryTaskError::InternalServerError,
                     )
                 }
                 "ServiceLimitExceeded" => {
-                    return RusotoError::Service(
-                        CreateDataRepositoryTaskError::ServiceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataRepositoryTaskError::ServiceLimitExceeded,
                     )
                 }
                 "UnsupportedOperation" => {
-                    return RusotoError::Service(
-                        CreateDataRepositoryTaskError::UnsupportedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataRepositoryTaskError::UnsupportedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1623,50 +1706,86 @@ impl CreateFileSystemError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ActiveDirectoryError" => {
-                    return RusotoError::Service(CreateFileSystemError::ActiveDirectoryError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateFileSystemError::ActiveDirectoryError,
+                    )
                 }
                 "BadRequest" => {
-                    return RusotoError::Service(CreateFileSystemError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFileSystemError::BadRequest,
+                    )
                 }
                 "IncompatibleParameterError" => {
-                    return RusotoError::Service(CreateFileSystemError::IncompatibleParameterError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateFileSystemError::IncompatibleParameterError,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateFileSystemError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateFileSystemError::InternalServerError,
+                    )
                 }
                 "InvalidExportPath" => {
-                    return RusotoError::Service(CreateFileSystemError::InvalidExportPath(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFileSystemError::InvalidExportPath,
+                    )
                 }
                 "InvalidImportPath" => {
-                    return RusotoError::Service(CreateFileSystemError::InvalidImportPath(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFileSystemError::InvalidImportPath,
+                    )
                 }
                 "InvalidNetworkSettings" => {
-                    return RusotoError::Service(CreateFileSystemError::InvalidNetworkSettings(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateFileSystemError::InvalidNetworkSettings,
+                    )
                 }
                 "InvalidPerUnitStorageThroughput" => {
-                    return RusotoError::Service(
-                        CreateFileSystemError::InvalidPerUnitStorageThroughput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFileSystemError::InvalidPerUnitStorageThroughput,
                     )
                 }
                 "MissingFileSystemConfiguration" => {
-                    return RusotoError::Service(
-                        CreateFileSystemError::MissingFileSystemConfiguration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFileSystemError::MissingFileSystemConfiguration,
                     )
                 }
                 "ServiceLimitExceeded" => {
-                    return RusotoError::Service(CreateFileSystemError::ServiceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateFileSystemError::ServiceLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1725,51 +1844,78 @@ impl CreateFileSystemFromBackupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ActiveDirectoryError" => {
-                    return RusotoError::Service(
-                        CreateFileSystemFromBackupError::ActiveDirectoryError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFileSystemFromBackupError::ActiveDirectoryError,
                     )
                 }
                 "BackupNotFound" => {
-                    return RusotoError::Service(CreateFileSystemFromBackupError::BackupNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateFileSystemFromBackupError::BackupNotFound,
+                    )
                 }
                 "BadRequest" => {
-                    return RusotoError::Service(CreateFileSystemFromBackupError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateFileSystemFromBackupError::BadRequest,
+                    )
                 }
                 "IncompatibleParameterError" => {
-                    return RusotoError::Service(
-                        CreateFileSystemFromBackupError::IncompatibleParameterError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFileSystemFromBackupError::IncompatibleParameterError,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        CreateFileSystemFromBackupError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFileSystemFromBackupError::InternalServerError,
                     )
                 }
                 "InvalidNetworkSettings" => {
-                    return RusotoError::Service(
-                        CreateFileSystemFromBackupError::InvalidNetworkSettings(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFileSystemFromBackupError::InvalidNetworkSettings,
                     )
                 }
                 "InvalidPerUnitStorageThroughput" => {
-                    return RusotoError::Service(
-                        CreateFileSystemFromBackupError::InvalidPerUnitStorageThroughput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFileSystemFromBackupError::InvalidPerUnitStorageThroughput,
                     )
                 }
                 "MissingFileSystemConfiguration" => {
-                    return RusotoError::Service(
-                        CreateFileSystemFromBackupError::MissingFileSystemConfiguration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFileSystemFromBackupError::MissingFileSystemConfiguration,
                     )
                 }
                 "ServiceLimitExceeded" => {
-                    return RusotoError::Service(
-                        CreateFileSystemFromBackupError::ServiceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFileSystemFromBackupError::ServiceLimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1829,26 +1975,54 @@ impl DeleteBackupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BackupInProgress" => {
-                    return RusotoError::Service(DeleteBackupError::BackupInProgress(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBackupError::BackupInProgress,
+                    )
                 }
                 "BackupNotFound" => {
-                    return RusotoError::Service(DeleteBackupError::BackupNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBackupError::BackupNotFound,
+                    )
                 }
                 "BackupRestoring" => {
-                    return RusotoError::Service(DeleteBackupError::BackupRestoring(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBackupError::BackupRestoring,
+                    )
                 }
                 "BadRequest" => {
-                    return RusotoError::Service(DeleteBackupError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBackupError::BadRequest,
+                    )
                 }
                 "IncompatibleParameterError" => {
-                    return RusotoError::Service(DeleteBackupError::IncompatibleParameterError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteBackupError::IncompatibleParameterError,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteBackupError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBackupError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1889,27 +2063,46 @@ impl DeleteFileSystemError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(DeleteFileSystemError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFileSystemError::BadRequest,
+                    )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(DeleteFileSystemError::FileSystemNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFileSystemError::FileSystemNotFound,
+                    )
                 }
                 "IncompatibleParameterError" => {
-                    return RusotoError::Service(DeleteFileSystemError::IncompatibleParameterError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteFileSystemError::IncompatibleParameterError,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteFileSystemError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteFileSystemError::InternalServerError,
+                    )
                 }
                 "ServiceLimitExceeded" => {
-                    return RusotoError::Service(DeleteFileSystemError::ServiceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteFileSystemError::ServiceLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1947,18 +2140,38 @@ impl DescribeBackupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BackupNotFound" => {
-                    return RusotoError::Service(DescribeBackupsError::BackupNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBackupsError::BackupNotFound,
+                    )
                 }
                 "BadRequest" => {
-                    return RusotoError::Service(DescribeBackupsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBackupsError::BadRequest,
+                    )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(DescribeBackupsError::FileSystemNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBackupsError::FileSystemNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeBackupsError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBackupsError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1997,26 +2210,38 @@ impl DescribeDataRepositoryTasksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(DescribeDataRepositoryTasksError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDataRepositoryTasksError::BadRequest,
+                    )
                 }
                 "DataRepositoryTaskNotFound" => {
-                    return RusotoError::Service(
-                        DescribeDataRepositoryTasksError::DataRepositoryTaskNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDataRepositoryTasksError::DataRepositoryTaskNotFound,
                     )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(
-                        DescribeDataRepositoryTasksError::FileSystemNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDataRepositoryTasksError::FileSystemNotFound,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeDataRepositoryTasksError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDataRepositoryTasksError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2057,21 +2282,30 @@ impl DescribeFileSystemAliasesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(DescribeFileSystemAliasesError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeFileSystemAliasesError::BadRequest,
+                    )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(
-                        DescribeFileSystemAliasesError::FileSystemNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeFileSystemAliasesError::FileSystemNotFound,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeFileSystemAliasesError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeFileSystemAliasesError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2107,19 +2341,30 @@ impl DescribeFileSystemsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(DescribeFileSystemsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeFileSystemsError::BadRequest,
+                    )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(DescribeFileSystemsError::FileSystemNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeFileSystemsError::FileSystemNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeFileSystemsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeFileSystemsError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2155,21 +2400,30 @@ impl DisassociateFileSystemAliasesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(DisassociateFileSystemAliasesError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateFileSystemAliasesError::BadRequest,
+                    )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(
-                        DisassociateFileSystemAliasesError::FileSystemNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateFileSystemAliasesError::FileSystemNotFound,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DisassociateFileSystemAliasesError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateFileSystemAliasesError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2211,29 +2465,46 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::BadRequest,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::InternalServerError,
+                    )
                 }
                 "NotServiceResourceError" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotServiceResourceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::NotServiceResourceError,
+                    )
                 }
                 "ResourceDoesNotSupportTagging" => {
-                    return RusotoError::Service(
-                        ListTagsForResourceError::ResourceDoesNotSupportTagging(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::ResourceDoesNotSupportTagging,
                     )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2274,22 +2545,47 @@ impl TagResourceError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<TagResourceError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "BadRequest" => return RusotoError::Service(TagResourceError::BadRequest(err.msg)),
+                "BadRequest" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::BadRequest,
+                    )
+                }
                 "InternalServerError" => {
-                    return RusotoError::Service(TagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InternalServerError,
+                    )
                 }
                 "NotServiceResourceError" => {
-                    return RusotoError::Service(TagResourceError::NotServiceResourceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::NotServiceResourceError,
+                    )
                 }
                 "ResourceDoesNotSupportTagging" => {
-                    return RusotoError::Service(TagResourceError::ResourceDoesNotSupportTagging(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TagResourceError::ResourceDoesNotSupportTagging,
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2329,25 +2625,46 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::BadRequest,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(UntagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InternalServerError,
+                    )
                 }
                 "NotServiceResourceError" => {
-                    return RusotoError::Service(UntagResourceError::NotServiceResourceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UntagResourceError::NotServiceResourceError,
+                    )
                 }
                 "ResourceDoesNotSupportTagging" => {
-                    return RusotoError::Service(UntagResourceError::ResourceDoesNotSupportTagging(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UntagResourceError::ResourceDoesNotSupportTagging,
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2391,37 +2708,62 @@ impl UpdateFileSystemError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequest" => {
-                    return RusotoError::Service(UpdateFileSystemError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFileSystemError::BadRequest,
+                    )
                 }
                 "FileSystemNotFound" => {
-                    return RusotoError::Service(UpdateFileSystemError::FileSystemNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFileSystemError::FileSystemNotFound,
+                    )
                 }
                 "IncompatibleParameterError" => {
-                    return RusotoError::Service(UpdateFileSystemError::IncompatibleParameterError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateFileSystemError::IncompatibleParameterError,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(UpdateFileSystemError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateFileSystemError::InternalServerError,
+                    )
                 }
                 "MissingFileSystemConfiguration" => {
-                    return RusotoError::Service(
-                        UpdateFileSystemError::MissingFileSystemConfiguration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFileSystemError::MissingFileSystemConfiguration,
                     )
                 }
                 "ServiceLimitExceeded" => {
-                    return RusotoError::Service(UpdateFileSystemError::ServiceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateFileSystemError::ServiceLimitExceeded,
+                    )
                 }
                 "UnsupportedOperation" => {
-                    return RusotoError::Service(UpdateFileSystemError::UnsupportedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateFileSystemError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2563,11 +2905,12 @@ pub struct FsxClient {
 impl FsxClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> FsxClient {
         FsxClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "FSx"),
         }
     }
 
@@ -2582,12 +2925,32 @@ impl FsxClient {
     {
         FsxClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "FSx"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> FsxClient {
-        FsxClient { client, region }
+        FsxClient {
+            client,
+            region: region_for_service(region, "FSx"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> FsxClient {
+        FsxClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("FSx"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> FsxClient {
+        FsxClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -2600,6 +2963,7 @@ impl Fsx for FsxClient {
     ) -> Result<AssociateFileSystemAliasesResponse, RusotoError<AssociateFileSystemAliasesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateFileSystemAliases");
         request.add_header(
             "x-amz-target",
             "AWSSimbaAPIService_v20180301.AssociateFileSystemAliases",
@@ -2622,6 +2986,7 @@ impl Fsx for FsxClient {
         input: CancelDataRepositoryTaskRequest,
     ) -> Result<CancelDataRepositoryTaskResponse, RusotoError<CancelDataRepositoryTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CancelDataRepositoryTask");
         request.add_header(
             "x-amz-target",
             "AWSSimbaAPIService_v20180301.CancelDataRepositoryTask",
@@ -2644,6 +3009,7 @@ impl Fsx for FsxClient {
         input: CreateBackupRequest,
     ) -> Result<CreateBackupResponse, RusotoError<CreateBackupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateBackup");
         request.add_header("x-amz-target", "AWSSimbaAPIService_v20180301.CreateBackup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2662,6 +3028,7 @@ impl Fsx for FsxClient {
         input: CreateDataRepositoryTaskRequest,
     ) -> Result<CreateDataRepositoryTaskResponse, RusotoError<CreateDataRepositoryTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateDataRepositoryTask");
         request.add_header(
             "x-amz-target",
             "AWSSimbaAPIService_v20180301.CreateDataRepositoryTask",
@@ -2684,6 +3051,7 @@ impl Fsx for FsxClient {
         input: CreateFileSystemRequest,
     ) -> Result<CreateFileSystemResponse, RusotoError<CreateFileSystemError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateFileSystem");
         request.add_header(
             "x-amz-target",
             "AWSSimbaAPIService_v20180301.CreateFileSystem",
@@ -2706,6 +3074,7 @@ impl Fsx for FsxClient {
     ) -> Result<CreateFileSystemFromBackupResponse, RusotoError<CreateFileSystemFromBackupError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateFileSystemFromBackup");
         request.add_header(
             "x-amz-target",
             "AWSSimbaAPIService_v20180301.CreateFileSystemFromBackup",
@@ -2728,6 +3097,7 @@ impl Fsx for FsxClient {
         input: DeleteBackupRequest,
     ) -> Result<DeleteBackupResponse, RusotoError<DeleteBackupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteBackup");
         request.add_header("x-amz-target", "AWSSimbaAPIService_v20180301.DeleteBackup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2746,6 +3116,7 @@ impl Fsx for FsxClient {
         input: DeleteFileSystemRequest,
     ) -> Result<DeleteFileSystemResponse, RusotoError<DeleteFileSystemError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteFileSystem");
         request.add_header(
             "x-amz-target",
             "AWSSimbaAPIService_v20180301.DeleteFileSystem",
@@ -2767,6 +3138,7 @@ impl Fsx for FsxClient {
         input: DescribeBackupsRequest,
     ) -> Result<DescribeBackupsResponse, RusotoError<DescribeBackupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeBackups");
         request.add_header(
             "x-amz-target",
             "AWSSimbaAPIService_v20180301.DescribeBackups",
@@ -2789,6 +3161,7 @@ impl Fsx for FsxClient {
     ) -> Result<DescribeDataRepositoryTasksResponse, RusotoError<DescribeDataRepositoryTasksError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeDataRepositoryTasks");
         request.add_header(
             "x-amz-target",
             "AWSSimbaAPIService_v20180301.DescribeDataRepositoryTasks",
@@ -2812,6 +3185,7 @@ impl Fsx for FsxClient {
     ) -> Result<DescribeFileSystemAliasesResponse, RusotoError<DescribeFileSystemAliasesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeFileSystemAliases");
         request.add_header(
             "x-amz-target",
             "AWSSimbaAPIService_v20180301.DescribeFileSystemAliases",
@@ -2834,6 +3208,7 @@ impl Fsx for FsxClient {
         input: DescribeFileSystemsRequest,
     ) -> Result<DescribeFileSystemsResponse, RusotoError<DescribeFileSystemsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeFileSystems");
         request.add_header(
             "x-amz-target",
             "AWSSimbaAPIService_v20180301.DescribeFileSystems",
@@ -2858,6 +3233,7 @@ impl Fsx for FsxClient {
         RusotoError<DisassociateFileSystemAliasesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisassociateFileSystemAliases");
         request.add_header(
             "x-amz-target",
             "AWSSimbaAPIService_v20180301.DisassociateFileSystemAliases",
@@ -2880,6 +3256,7 @@ impl Fsx for FsxClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header(
             "x-amz-target",
             "AWSSimbaAPIService_v20180301.ListTagsForResource",
@@ -2901,6 +3278,7 @@ impl Fsx for FsxClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "AWSSimbaAPIService_v20180301.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2919,6 +3297,7 @@ impl Fsx for FsxClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "AWSSimbaAPIService_v20180301.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2937,6 +3316,7 @@ impl Fsx for FsxClient {
         input: UpdateFileSystemRequest,
     ) -> Result<UpdateFileSystemResponse, RusotoError<UpdateFileSystemError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateFileSystem");
         request.add_header(
             "x-amz-target",
             "AWSSimbaAPIService_v20180301.UpdateFileSystem",
@@ -2952,3 +3332,230 @@ impl Fsx for FsxClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateFileSystemResponse, _>()
     }
 }
+
+/// A blocking client for the Amazon FSx API, running the calls of a `FsxClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `FsxClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct FsxBlockingClient {
+    client: FsxClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl FsxBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> FsxBlockingClient {
+        FsxBlockingClient::from_client(FsxClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> FsxBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        FsxBlockingClient::from_client(FsxClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> FsxBlockingClient {
+        FsxBlockingClient::from_client(FsxClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> FsxBlockingClient {
+        FsxBlockingClient::from_client(FsxClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: FsxClient) -> FsxBlockingClient {
+        FsxBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> FsxBlockingClient {
+        FsxBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> FsxBlockingClient {
+        FsxBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Fsx::associate_file_system_aliases`].
+    pub fn associate_file_system_aliases(
+        &self,
+        input: AssociateFileSystemAliasesRequest,
+    ) -> Result<AssociateFileSystemAliasesResponse, RusotoError<AssociateFileSystemAliasesError>>
+    {
+        self.runtime
+            .block_on(self.client.associate_file_system_aliases(input))
+    }
+
+    /// Blocking version of [`Fsx::cancel_data_repository_task`].
+    pub fn cancel_data_repository_task(
+        &self,
+        input: CancelDataRepositoryTaskRequest,
+    ) -> Result<CancelDataRepositoryTaskResponse, RusotoError<CancelDataRepositoryTaskError>> {
+        self.runtime
+            .block_on(self.client.cancel_data_repository_task(input))
+    }
+
+    /// Blocking version of [`Fsx::create_backup`].
+    pub fn create_backup(
+        &self,
+        input: CreateBackupRequest,
+    ) -> Result<CreateBackupResponse, RusotoError<CreateBackupError>> {
+        self.runtime.block_on(self.client.create_backup(input))
+    }
+
+    /// Blocking version of [`Fsx::create_data_repository_task`].
+    pub fn create_data_repository_task(
+        &self,
+        input: CreateDataRepositoryTaskRequest,
+    ) -> Result<CreateDataRepositoryTaskResponse, RusotoError<CreateDataRepositoryTaskError>> {
+        self.runtime
+            .block_on(self.client.create_data_repository_task(input))
+    }
+
+    /// Blocking version of [`Fsx::create_file_system`].
+    pub fn create_file_system(
+        &self,
+        input: CreateFileSystemRequest,
+    ) -> Result<CreateFileSystemResponse, RusotoError<CreateFileSystemError>> {
+        self.runtime.block_on(self.client.create_file_system(input))
+    }
+
+    /// Blocking version of [`Fsx::create_file_system_from_backup`].
+    pub fn create_file_system_from_backup(
+        &self,
+        input: CreateFileSystemFromBackupRequest,
+    ) -> Result<CreateFileSystemFromBackupResponse, RusotoError<CreateFileSystemFromBackupError>>
+    {
+        self.runtime
+            .block_on(self.client.create_file_system_from_backup(input))
+    }
+
+    /// Blocking version of [`Fsx::delete_backup`].
+    pub fn delete_backup(
+        &self,
+        input: DeleteBackupRequest,
+    ) -> Result<DeleteBackupResponse, RusotoError<DeleteBackupError>> {
+        self.runtime.block_on(self.client.delete_backup(input))
+    }
+
+    /// Blocking version of [`Fsx::delete_file_system`].
+    pub fn delete_file_system(
+        &self,
+        input: DeleteFileSystemRequest,
+    ) -> Result<DeleteFileSystemResponse, RusotoError<DeleteFileSystemError>> {
+        self.runtime.block_on(self.client.delete_file_system(input))
+    }
+
+    /// Blocking version of [`Fsx::describe_backups`].
+    pub fn describe_backups(
+        &self,
+        input: DescribeBackupsRequest,
+    ) -> Result<DescribeBackupsResponse, RusotoError<DescribeBackupsError>> {
+        self.runtime.block_on(self.client.describe_backups(input))
+    }
+
+    /// Blocking version of [`Fsx::describe_data_repository_tasks`].
+    pub fn describe_data_repository_tasks(
+        &self,
+        input: DescribeDataRepositoryTasksRequest,
+    ) -> Result<DescribeDataRepositoryTasksResponse, RusotoError<DescribeDataRepositoryTasksError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_data_repository_tasks(input))
+    }
+
+    /// Blocking version of [`Fsx::describe_file_system_aliases`].
+    pub fn describe_file_system_aliases(
+        &self,
+        input: DescribeFileSystemAliasesRequest,
+    ) -> Result<DescribeFileSystemAliasesResponse, RusotoError<DescribeFileSystemAliasesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_file_system_aliases(input))
+    }
+
+    /// Blocking version of [`Fsx::describe_file_systems`].
+    pub fn describe_file_systems(
+        &self,
+        input: DescribeFileSystemsRequest,
+    ) -> Result<DescribeFileSystemsResponse, RusotoError<DescribeFileSystemsError>> {
+        self.runtime
+            .block_on(self.client.describe_file_systems(input))
+    }
+
+    /// Blocking version of [`Fsx::disassociate_file_system_aliases`].
+    pub fn disassociate_file_system_aliases(
+        &self,
+        input: DisassociateFileSystemAliasesRequest,
+    ) -> Result<
+        DisassociateFileSystemAliasesResponse,
+        RusotoError<DisassociateFileSystemAliasesError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_file_system_aliases(input))
+    }
+
+    /// Blocking version of [`Fsx::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`Fsx::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`Fsx::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`Fsx::update_file_system`].
+    pub fn update_file_system(
+        &self,
+        input: UpdateFileSystemRequest,
+    ) -> Result<UpdateFileSystemResponse, RusotoError<UpdateFileSystemError>> {
+        self.runtime.block_on(self.client.update_file_system(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -3983,18 +3984,38 @@ impl AcceptMatchError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(AcceptMatchError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptMatchError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AcceptMatchError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptMatchError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AcceptMatchError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptMatchError::NotFound,
+                    )
                 }
                 "UnsupportedRegionException" => {
-                    return RusotoError::Service(AcceptMatchError::UnsupportedRegion(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptMatchError::UnsupportedRegion,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4035,24 +4056,54 @@ impl ClaimGameServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(ClaimGameServerError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ClaimGameServerError::Conflict,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ClaimGameServerError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ClaimGameServerError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ClaimGameServerError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ClaimGameServerError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ClaimGameServerError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ClaimGameServerError::NotFound,
+                    )
                 }
                 "OutOfCapacityException" => {
-                    return RusotoError::Service(ClaimGameServerError::OutOfCapacity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ClaimGameServerError::OutOfCapacity,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ClaimGameServerError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ClaimGameServerError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4095,24 +4146,54 @@ impl CreateAliasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(CreateAliasError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAliasError::Conflict,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateAliasError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAliasError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateAliasError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAliasError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateAliasError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAliasError::LimitExceeded,
+                    )
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(CreateAliasError::TaggingFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAliasError::TaggingFailed,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateAliasError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAliasError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4153,21 +4234,46 @@ impl CreateBuildError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(CreateBuildError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBuildError::Conflict,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateBuildError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBuildError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateBuildError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBuildError::InvalidRequest,
+                    )
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(CreateBuildError::TaggingFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBuildError::TaggingFailed,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateBuildError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBuildError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4211,27 +4317,62 @@ impl CreateFleetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(CreateFleetError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFleetError::Conflict,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateFleetError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFleetError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateFleetError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFleetError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateFleetError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFleetError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateFleetError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFleetError::NotFound,
+                    )
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(CreateFleetError::TaggingFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFleetError::TaggingFailed,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateFleetError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFleetError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4273,25 +4414,46 @@ impl CreateGameServerGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(CreateGameServerGroupError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGameServerGroupError::Conflict,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateGameServerGroupError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateGameServerGroupError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateGameServerGroupError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateGameServerGroupError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateGameServerGroupError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGameServerGroupError::LimitExceeded,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateGameServerGroupError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGameServerGroupError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4341,44 +4503,86 @@ impl CreateGameSessionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(CreateGameSessionError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGameSessionError::Conflict,
+                    )
                 }
                 "FleetCapacityExceededException" => {
-                    return RusotoError::Service(CreateGameSessionError::FleetCapacityExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateGameSessionError::FleetCapacityExceeded,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        CreateGameSessionError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGameSessionError::IdempotentParameterMismatch,
                     )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateGameSessionError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGameSessionError::InternalService,
+                    )
                 }
                 "InvalidFleetStatusException" => {
-                    return RusotoError::Service(CreateGameSessionError::InvalidFleetStatus(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateGameSessionError::InvalidFleetStatus,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateGameSessionError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGameSessionError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateGameSessionError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGameSessionError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateGameSessionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGameSessionError::NotFound,
+                    )
                 }
                 "TerminalRoutingStrategyException" => {
-                    return RusotoError::Service(CreateGameSessionError::TerminalRoutingStrategy(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateGameSessionError::TerminalRoutingStrategy,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateGameSessionError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGameSessionError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4425,29 +4629,46 @@ impl CreateGameSessionQueueError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateGameSessionQueueError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateGameSessionQueueError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateGameSessionQueueError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateGameSessionQueueError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateGameSessionQueueError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateGameSessionQueueError::LimitExceeded,
+                    )
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(CreateGameSessionQueueError::TaggingFailed(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateGameSessionQueueError::TaggingFailed,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateGameSessionQueueError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGameSessionQueueError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4491,36 +4712,54 @@ impl CreateMatchmakingConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
-                        CreateMatchmakingConfigurationError::InternalService(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMatchmakingConfigurationError::InternalService,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        CreateMatchmakingConfigurationError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMatchmakingConfigurationError::InvalidRequest,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
-                        CreateMatchmakingConfigurationError::LimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMatchmakingConfigurationError::LimitExceeded,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateMatchmakingConfigurationError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateMatchmakingConfigurationError::NotFound,
+                    )
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(
-                        CreateMatchmakingConfigurationError::TaggingFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMatchmakingConfigurationError::TaggingFailed,
                     )
                 }
                 "UnsupportedRegionException" => {
-                    return RusotoError::Service(
-                        CreateMatchmakingConfigurationError::UnsupportedRegion(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMatchmakingConfigurationError::UnsupportedRegion,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4565,26 +4804,38 @@ impl CreateMatchmakingRuleSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateMatchmakingRuleSetError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateMatchmakingRuleSetError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateMatchmakingRuleSetError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateMatchmakingRuleSetError::InvalidRequest,
+                    )
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(CreateMatchmakingRuleSetError::TaggingFailed(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateMatchmakingRuleSetError::TaggingFailed,
+                    )
                 }
                 "UnsupportedRegionException" => {
-                    return RusotoError::Service(CreateMatchmakingRuleSetError::UnsupportedRegion(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateMatchmakingRuleSetError::UnsupportedRegion,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4627,31 +4878,62 @@ impl CreatePlayerSessionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "GameSessionFullException" => {
-                    return RusotoError::Service(CreatePlayerSessionError::GameSessionFull(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePlayerSessionError::GameSessionFull,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreatePlayerSessionError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePlayerSessionError::InternalService,
+                    )
                 }
                 "InvalidGameSessionStatusException" => {
-                    return RusotoError::Service(
-                        CreatePlayerSessionError::InvalidGameSessionStatus(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePlayerSessionError::InvalidGameSessionStatus,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreatePlayerSessionError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePlayerSessionError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreatePlayerSessionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePlayerSessionError::NotFound,
+                    )
                 }
                 "TerminalRoutingStrategyException" => {
-                    return RusotoError::Service(CreatePlayerSessionError::TerminalRoutingStrategy(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePlayerSessionError::TerminalRoutingStrategy,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreatePlayerSessionError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePlayerSessionError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4697,35 +4979,62 @@ impl CreatePlayerSessionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "GameSessionFullException" => {
-                    return RusotoError::Service(CreatePlayerSessionsError::GameSessionFull(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePlayerSessionsError::GameSessionFull,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreatePlayerSessionsError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePlayerSessionsError::InternalService,
+                    )
                 }
                 "InvalidGameSessionStatusException" => {
-                    return RusotoError::Service(
-                        CreatePlayerSessionsError::InvalidGameSessionStatus(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePlayerSessionsError::InvalidGameSessionStatus,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreatePlayerSessionsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePlayerSessionsError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreatePlayerSessionsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePlayerSessionsError::NotFound,
+                    )
                 }
                 "TerminalRoutingStrategyException" => {
-                    return RusotoError::Service(
-                        CreatePlayerSessionsError::TerminalRoutingStrategy(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePlayerSessionsError::TerminalRoutingStrategy,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreatePlayerSessionsError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePlayerSessionsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4769,21 +5078,46 @@ impl CreateScriptError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(CreateScriptError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateScriptError::Conflict,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateScriptError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateScriptError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateScriptError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateScriptError::InvalidRequest,
+                    )
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(CreateScriptError::TaggingFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateScriptError::TaggingFailed,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateScriptError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateScriptError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4823,26 +5157,38 @@ impl CreateVpcPeeringAuthorizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
-                        CreateVpcPeeringAuthorizationError::InternalService(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVpcPeeringAuthorizationError::InternalService,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        CreateVpcPeeringAuthorizationError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVpcPeeringAuthorizationError::InvalidRequest,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateVpcPeeringAuthorizationError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateVpcPeeringAuthorizationError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateVpcPeeringAuthorizationError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateVpcPeeringAuthorizationError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4883,24 +5229,38 @@ impl CreateVpcPeeringConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateVpcPeeringConnectionError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateVpcPeeringConnectionError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateVpcPeeringConnectionError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateVpcPeeringConnectionError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateVpcPeeringConnectionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVpcPeeringConnectionError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateVpcPeeringConnectionError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateVpcPeeringConnectionError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4939,21 +5299,46 @@ impl DeleteAliasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteAliasError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAliasError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteAliasError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAliasError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteAliasError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAliasError::NotFound,
+                    )
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(DeleteAliasError::TaggingFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAliasError::TaggingFailed,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteAliasError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAliasError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4993,21 +5378,46 @@ impl DeleteBuildError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteBuildError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBuildError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteBuildError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBuildError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteBuildError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBuildError::NotFound,
+                    )
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(DeleteBuildError::TaggingFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBuildError::TaggingFailed,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteBuildError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBuildError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5049,24 +5459,54 @@ impl DeleteFleetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteFleetError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFleetError::InternalService,
+                    )
                 }
                 "InvalidFleetStatusException" => {
-                    return RusotoError::Service(DeleteFleetError::InvalidFleetStatus(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFleetError::InvalidFleetStatus,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteFleetError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFleetError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteFleetError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFleetError::NotFound,
+                    )
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(DeleteFleetError::TaggingFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFleetError::TaggingFailed,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteFleetError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFleetError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5105,22 +5545,38 @@ impl DeleteGameServerGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteGameServerGroupError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteGameServerGroupError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteGameServerGroupError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteGameServerGroupError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteGameServerGroupError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGameServerGroupError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteGameServerGroupError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGameServerGroupError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5159,27 +5615,46 @@ impl DeleteGameSessionQueueError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteGameSessionQueueError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteGameSessionQueueError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteGameSessionQueueError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteGameSessionQueueError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteGameSessionQueueError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGameSessionQueueError::NotFound,
+                    )
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(DeleteGameSessionQueueError::TaggingFailed(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteGameSessionQueueError::TaggingFailed,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteGameSessionQueueError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGameSessionQueueError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5221,31 +5696,46 @@ impl DeleteMatchmakingConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
-                        DeleteMatchmakingConfigurationError::InternalService(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMatchmakingConfigurationError::InternalService,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        DeleteMatchmakingConfigurationError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMatchmakingConfigurationError::InvalidRequest,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteMatchmakingConfigurationError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteMatchmakingConfigurationError::NotFound,
+                    )
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(
-                        DeleteMatchmakingConfigurationError::TaggingFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMatchmakingConfigurationError::TaggingFailed,
                     )
                 }
                 "UnsupportedRegionException" => {
-                    return RusotoError::Service(
-                        DeleteMatchmakingConfigurationError::UnsupportedRegion(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMatchmakingConfigurationError::UnsupportedRegion,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5291,29 +5781,46 @@ impl DeleteMatchmakingRuleSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteMatchmakingRuleSetError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteMatchmakingRuleSetError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteMatchmakingRuleSetError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteMatchmakingRuleSetError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteMatchmakingRuleSetError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMatchmakingRuleSetError::NotFound,
+                    )
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(DeleteMatchmakingRuleSetError::TaggingFailed(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteMatchmakingRuleSetError::TaggingFailed,
+                    )
                 }
                 "UnsupportedRegionException" => {
-                    return RusotoError::Service(DeleteMatchmakingRuleSetError::UnsupportedRegion(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteMatchmakingRuleSetError::UnsupportedRegion,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5351,18 +5858,38 @@ impl DeleteScalingPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteScalingPolicyError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteScalingPolicyError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteScalingPolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteScalingPolicyError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteScalingPolicyError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteScalingPolicyError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteScalingPolicyError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteScalingPolicyError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5401,21 +5928,46 @@ impl DeleteScriptError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteScriptError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteScriptError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteScriptError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteScriptError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteScriptError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteScriptError::NotFound,
+                    )
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(DeleteScriptError::TaggingFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteScriptError::TaggingFailed,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteScriptError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteScriptError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5455,26 +6007,38 @@ impl DeleteVpcPeeringAuthorizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
-                        DeleteVpcPeeringAuthorizationError::InternalService(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVpcPeeringAuthorizationError::InternalService,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        DeleteVpcPeeringAuthorizationError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVpcPeeringAuthorizationError::InvalidRequest,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVpcPeeringAuthorizationError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteVpcPeeringAuthorizationError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteVpcPeeringAuthorizationError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteVpcPeeringAuthorizationError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5515,24 +6079,38 @@ impl DeleteVpcPeeringConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteVpcPeeringConnectionError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteVpcPeeringConnectionError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteVpcPeeringConnectionError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteVpcPeeringConnectionError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteVpcPeeringConnectionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVpcPeeringConnectionError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteVpcPeeringConnectionError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteVpcPeeringConnectionError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5569,20 +6147,38 @@ impl DeregisterGameServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeregisterGameServerError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeregisterGameServerError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeregisterGameServerError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterGameServerError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeregisterGameServerError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterGameServerError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeregisterGameServerError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterGameServerError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5619,18 +6215,38 @@ impl DescribeAliasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeAliasError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAliasError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeAliasError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAliasError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeAliasError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAliasError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeAliasError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAliasError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5667,18 +6283,38 @@ impl DescribeBuildError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeBuildError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBuildError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeBuildError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBuildError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeBuildError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBuildError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeBuildError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBuildError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5713,21 +6349,30 @@ impl DescribeEC2InstanceLimitsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeEC2InstanceLimitsError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEC2InstanceLimitsError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeEC2InstanceLimitsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEC2InstanceLimitsError::InvalidRequest,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeEC2InstanceLimitsError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEC2InstanceLimitsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5763,24 +6408,38 @@ impl DescribeFleetAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeFleetAttributesError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeFleetAttributesError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeFleetAttributesError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeFleetAttributesError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeFleetAttributesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeFleetAttributesError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeFleetAttributesError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeFleetAttributesError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5817,22 +6476,38 @@ impl DescribeFleetCapacityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeFleetCapacityError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeFleetCapacityError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeFleetCapacityError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeFleetCapacityError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeFleetCapacityError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeFleetCapacityError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeFleetCapacityError::Unauthorized(err.msg))
-                }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeFleetCapacityError::Unauthorized,
+                    )
+                }
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5869,18 +6544,38 @@ impl DescribeFleetEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeFleetEventsError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeFleetEventsError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeFleetEventsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeFleetEventsError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeFleetEventsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeFleetEventsError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeFleetEventsError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeFleetEventsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5917,24 +6612,38 @@ impl DescribeFleetPortSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeFleetPortSettingsError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeFleetPortSettingsError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeFleetPortSettingsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeFleetPortSettingsError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeFleetPortSettingsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeFleetPortSettingsError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeFleetPortSettingsError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeFleetPortSettingsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5971,24 +6680,38 @@ impl DescribeFleetUtilizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeFleetUtilizationError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeFleetUtilizationError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeFleetUtilizationError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeFleetUtilizationError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeFleetUtilizationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeFleetUtilizationError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeFleetUtilizationError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeFleetUtilizationError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6025,18 +6748,38 @@ impl DescribeGameServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeGameServerError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeGameServerError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeGameServerError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeGameServerError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeGameServerError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeGameServerError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeGameServerError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeGameServerError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6073,24 +6816,38 @@ impl DescribeGameServerGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeGameServerGroupError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeGameServerGroupError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeGameServerGroupError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeGameServerGroupError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeGameServerGroupError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeGameServerGroupError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeGameServerGroupError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeGameServerGroupError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6129,26 +6886,38 @@ impl DescribeGameServerInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeGameServerInstancesError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeGameServerInstancesError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeGameServerInstancesError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeGameServerInstancesError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeGameServerInstancesError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeGameServerInstancesError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeGameServerInstancesError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeGameServerInstancesError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6189,29 +6958,46 @@ impl DescribeGameSessionDetailsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeGameSessionDetailsError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeGameSessionDetailsError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeGameSessionDetailsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeGameSessionDetailsError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeGameSessionDetailsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeGameSessionDetailsError::NotFound,
+                    )
                 }
                 "TerminalRoutingStrategyException" => {
-                    return RusotoError::Service(
-                        DescribeGameSessionDetailsError::TerminalRoutingStrategy(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeGameSessionDetailsError::TerminalRoutingStrategy,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeGameSessionDetailsError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeGameSessionDetailsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6253,26 +7039,38 @@ impl DescribeGameSessionPlacementError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
-                        DescribeGameSessionPlacementError::InternalService(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeGameSessionPlacementError::InternalService,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeGameSessionPlacementError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeGameSessionPlacementError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeGameSessionPlacementError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeGameSessionPlacementError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeGameSessionPlacementError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeGameSessionPlacementError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6309,24 +7107,38 @@ impl DescribeGameSessionQueuesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeGameSessionQueuesError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeGameSessionQueuesError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeGameSessionQueuesError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeGameSessionQueuesError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeGameSessionQueuesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeGameSessionQueuesError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeGameSessionQueuesError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeGameSessionQueuesError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6365,25 +7177,46 @@ impl DescribeGameSessionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeGameSessionsError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeGameSessionsError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeGameSessionsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeGameSessionsError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeGameSessionsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeGameSessionsError::NotFound,
+                    )
                 }
                 "TerminalRoutingStrategyException" => {
-                    return RusotoError::Service(
-                        DescribeGameSessionsError::TerminalRoutingStrategy(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeGameSessionsError::TerminalRoutingStrategy,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeGameSessionsError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeGameSessionsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6421,18 +7254,38 @@ impl DescribeInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeInstancesError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInstancesError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeInstancesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInstancesError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeInstancesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInstancesError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeInstancesError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInstancesError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6467,17 +7320,30 @@ impl DescribeMatchmakingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeMatchmakingError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMatchmakingError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeMatchmakingError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMatchmakingError::InvalidRequest,
+                    )
                 }
                 "UnsupportedRegionException" => {
-                    return RusotoError::Service(DescribeMatchmakingError::UnsupportedRegion(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeMatchmakingError::UnsupportedRegion,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6513,21 +7379,30 @@ impl DescribeMatchmakingConfigurationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
-                        DescribeMatchmakingConfigurationsError::InternalService(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMatchmakingConfigurationsError::InternalService,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        DescribeMatchmakingConfigurationsError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMatchmakingConfigurationsError::InvalidRequest,
                     )
                 }
                 "UnsupportedRegionException" => {
-                    return RusotoError::Service(
-                        DescribeMatchmakingConfigurationsError::UnsupportedRegion(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMatchmakingConfigurationsError::UnsupportedRegion,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6571,26 +7446,38 @@ impl DescribeMatchmakingRuleSetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeMatchmakingRuleSetsError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeMatchmakingRuleSetsError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeMatchmakingRuleSetsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeMatchmakingRuleSetsError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeMatchmakingRuleSetsError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeMatchmakingRuleSetsError::NotFound,
+                    )
                 }
                 "UnsupportedRegionException" => {
-                    return RusotoError::Service(
-                        DescribeMatchmakingRuleSetsError::UnsupportedRegion(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMatchmakingRuleSetsError::UnsupportedRegion,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6629,22 +7516,38 @@ impl DescribePlayerSessionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribePlayerSessionsError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribePlayerSessionsError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribePlayerSessionsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribePlayerSessionsError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribePlayerSessionsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePlayerSessionsError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribePlayerSessionsError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePlayerSessionsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6683,26 +7586,38 @@ impl DescribeRuntimeConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
-                        DescribeRuntimeConfigurationError::InternalService(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRuntimeConfigurationError::InternalService,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeRuntimeConfigurationError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRuntimeConfigurationError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeRuntimeConfigurationError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRuntimeConfigurationError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeRuntimeConfigurationError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRuntimeConfigurationError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6739,24 +7654,38 @@ impl DescribeScalingPoliciesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeScalingPoliciesError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeScalingPoliciesError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeScalingPoliciesError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeScalingPoliciesError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeScalingPoliciesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeScalingPoliciesError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeScalingPoliciesError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeScalingPoliciesError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6793,18 +7722,38 @@ impl DescribeScriptError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeScriptError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeScriptError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeScriptError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeScriptError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeScriptError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeScriptError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeScriptError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeScriptError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6841,21 +7790,30 @@ impl DescribeVpcPeeringAuthorizationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
-                        DescribeVpcPeeringAuthorizationsError::InternalService(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeVpcPeeringAuthorizationsError::InternalService,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        DescribeVpcPeeringAuthorizationsError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeVpcPeeringAuthorizationsError::InvalidRequest,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
-                        DescribeVpcPeeringAuthorizationsError::Unauthorized(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeVpcPeeringAuthorizationsError::Unauthorized,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6899,26 +7857,38 @@ impl DescribeVpcPeeringConnectionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
-                        DescribeVpcPeeringConnectionsError::InternalService(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeVpcPeeringConnectionsError::InternalService,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        DescribeVpcPeeringConnectionsError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeVpcPeeringConnectionsError::InvalidRequest,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeVpcPeeringConnectionsError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeVpcPeeringConnectionsError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeVpcPeeringConnectionsError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeVpcPeeringConnectionsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6957,20 +7927,38 @@ impl GetGameSessionLogUrlError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetGameSessionLogUrlError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetGameSessionLogUrlError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetGameSessionLogUrlError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGameSessionLogUrlError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetGameSessionLogUrlError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGameSessionLogUrlError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetGameSessionLogUrlError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGameSessionLogUrlError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7007,18 +7995,38 @@ impl GetInstanceAccessError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetInstanceAccessError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceAccessError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetInstanceAccessError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceAccessError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetInstanceAccessError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceAccessError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetInstanceAccessError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceAccessError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7053,15 +8061,30 @@ impl ListAliasesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListAliasesError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAliasesError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListAliasesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAliasesError::InvalidRequest,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListAliasesError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAliasesError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7095,15 +8118,30 @@ impl ListBuildsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListBuildsError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBuildsError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListBuildsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBuildsError::InvalidRequest,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListBuildsError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBuildsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7139,18 +8177,38 @@ impl ListFleetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListFleetsError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFleetsError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListFleetsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFleetsError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListFleetsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFleetsError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListFleetsError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFleetsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7185,17 +8243,30 @@ impl ListGameServerGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListGameServerGroupsError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListGameServerGroupsError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListGameServerGroupsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGameServerGroupsError::InvalidRequest,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListGameServerGroupsError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGameServerGroupsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7229,15 +8300,30 @@ impl ListGameServersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListGameServersError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGameServersError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListGameServersError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGameServersError::InvalidRequest,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListGameServersError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGameServersError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7271,15 +8357,30 @@ impl ListScriptsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListScriptsError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListScriptsError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListScriptsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListScriptsError::InvalidRequest,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListScriptsError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListScriptsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7315,18 +8416,38 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::NotFound,
+                    )
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::TaggingFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::TaggingFailed,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7363,18 +8484,38 @@ impl PutScalingPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(PutScalingPolicyError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutScalingPolicyError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(PutScalingPolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutScalingPolicyError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutScalingPolicyError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutScalingPolicyError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(PutScalingPolicyError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutScalingPolicyError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7413,21 +8554,46 @@ impl RegisterGameServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(RegisterGameServerError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterGameServerError::Conflict,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(RegisterGameServerError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterGameServerError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RegisterGameServerError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterGameServerError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RegisterGameServerError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterGameServerError::LimitExceeded,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(RegisterGameServerError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterGameServerError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7465,24 +8631,38 @@ impl RequestUploadCredentialsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(RequestUploadCredentialsError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RequestUploadCredentialsError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RequestUploadCredentialsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RequestUploadCredentialsError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RequestUploadCredentialsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RequestUploadCredentialsError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(RequestUploadCredentialsError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RequestUploadCredentialsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7521,23 +8701,46 @@ impl ResolveAliasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ResolveAliasError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResolveAliasError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ResolveAliasError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResolveAliasError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ResolveAliasError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResolveAliasError::NotFound,
+                    )
                 }
                 "TerminalRoutingStrategyException" => {
-                    return RusotoError::Service(ResolveAliasError::TerminalRoutingStrategy(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ResolveAliasError::TerminalRoutingStrategy,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ResolveAliasError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResolveAliasError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7575,22 +8778,38 @@ impl ResumeGameServerGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ResumeGameServerGroupError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ResumeGameServerGroupError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ResumeGameServerGroupError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ResumeGameServerGroupError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ResumeGameServerGroupError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResumeGameServerGroupError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ResumeGameServerGroupError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResumeGameServerGroupError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7629,23 +8848,46 @@ impl SearchGameSessionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(SearchGameSessionsError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchGameSessionsError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SearchGameSessionsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchGameSessionsError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(SearchGameSessionsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchGameSessionsError::NotFound,
+                    )
                 }
                 "TerminalRoutingStrategyException" => {
-                    return RusotoError::Service(SearchGameSessionsError::TerminalRoutingStrategy(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SearchGameSessionsError::TerminalRoutingStrategy,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(SearchGameSessionsError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchGameSessionsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7683,18 +8925,38 @@ impl StartFleetActionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartFleetActionsError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartFleetActionsError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartFleetActionsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartFleetActionsError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StartFleetActionsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartFleetActionsError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(StartFleetActionsError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartFleetActionsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7731,24 +8993,38 @@ impl StartGameSessionPlacementError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartGameSessionPlacementError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartGameSessionPlacementError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartGameSessionPlacementError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartGameSessionPlacementError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StartGameSessionPlacementError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartGameSessionPlacementError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(StartGameSessionPlacementError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartGameSessionPlacementError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7785,20 +9061,38 @@ impl StartMatchBackfillError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartMatchBackfillError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartMatchBackfillError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartMatchBackfillError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartMatchBackfillError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StartMatchBackfillError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartMatchBackfillError::NotFound,
+                    )
                 }
                 "UnsupportedRegionException" => {
-                    return RusotoError::Service(StartMatchBackfillError::UnsupportedRegion(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartMatchBackfillError::UnsupportedRegion,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7835,18 +9129,38 @@ impl StartMatchmakingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartMatchmakingError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartMatchmakingError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartMatchmakingError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartMatchmakingError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StartMatchmakingError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartMatchmakingError::NotFound,
+                    )
                 }
                 "UnsupportedRegionException" => {
-                    return RusotoError::Service(StartMatchmakingError::UnsupportedRegion(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartMatchmakingError::UnsupportedRegion,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7883,18 +9197,38 @@ impl StopFleetActionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(StopFleetActionsError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopFleetActionsError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopFleetActionsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopFleetActionsError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StopFleetActionsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopFleetActionsError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(StopFleetActionsError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopFleetActionsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7931,24 +9265,38 @@ impl StopGameSessionPlacementError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(StopGameSessionPlacementError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopGameSessionPlacementError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopGameSessionPlacementError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopGameSessionPlacementError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StopGameSessionPlacementError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopGameSessionPlacementError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(StopGameSessionPlacementError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopGameSessionPlacementError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7985,18 +9333,38 @@ impl StopMatchmakingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(StopMatchmakingError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopMatchmakingError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopMatchmakingError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopMatchmakingError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StopMatchmakingError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopMatchmakingError::NotFound,
+                    )
                 }
                 "UnsupportedRegionException" => {
-                    return RusotoError::Service(StopMatchmakingError::UnsupportedRegion(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopMatchmakingError::UnsupportedRegion,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8033,22 +9401,38 @@ impl SuspendGameServerGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(SuspendGameServerGroupError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SuspendGameServerGroupError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SuspendGameServerGroupError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SuspendGameServerGroupError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(SuspendGameServerGroupError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SuspendGameServerGroupError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(SuspendGameServerGroupError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SuspendGameServerGroupError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8085,18 +9469,38 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(TagResourceError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::NotFound,
+                    )
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(TagResourceError::TaggingFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::TaggingFailed,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8133,18 +9537,38 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UntagResourceError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::NotFound,
+                    )
                 }
                 "TaggingFailedException" => {
-                    return RusotoError::Service(UntagResourceError::TaggingFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::TaggingFailed,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8181,18 +9605,38 @@ impl UpdateAliasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateAliasError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAliasError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateAliasError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAliasError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateAliasError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAliasError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateAliasError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAliasError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8229,18 +9673,38 @@ impl UpdateBuildError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateBuildError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBuildError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateBuildError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBuildError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateBuildError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBuildError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateBuildError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBuildError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8283,33 +9747,62 @@ impl UpdateFleetAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateFleetAttributesError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFleetAttributesError::Conflict,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateFleetAttributesError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateFleetAttributesError::InternalService,
+                    )
                 }
                 "InvalidFleetStatusException" => {
-                    return RusotoError::Service(UpdateFleetAttributesError::InvalidFleetStatus(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateFleetAttributesError::InvalidFleetStatus,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateFleetAttributesError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateFleetAttributesError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateFleetAttributesError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFleetAttributesError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateFleetAttributesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFleetAttributesError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateFleetAttributesError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFleetAttributesError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8355,29 +9848,62 @@ impl UpdateFleetCapacityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateFleetCapacityError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFleetCapacityError::Conflict,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateFleetCapacityError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFleetCapacityError::InternalService,
+                    )
                 }
                 "InvalidFleetStatusException" => {
-                    return RusotoError::Service(UpdateFleetCapacityError::InvalidFleetStatus(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateFleetCapacityError::InvalidFleetStatus,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateFleetCapacityError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFleetCapacityError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateFleetCapacityError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFleetCapacityError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateFleetCapacityError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFleetCapacityError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateFleetCapacityError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFleetCapacityError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8423,37 +9949,62 @@ impl UpdateFleetPortSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateFleetPortSettingsError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFleetPortSettingsError::Conflict,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateFleetPortSettingsError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateFleetPortSettingsError::InternalService,
+                    )
                 }
                 "InvalidFleetStatusException" => {
-                    return RusotoError::Service(UpdateFleetPortSettingsError::InvalidFleetStatus(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateFleetPortSettingsError::InvalidFleetStatus,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateFleetPortSettingsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateFleetPortSettingsError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateFleetPortSettingsError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateFleetPortSettingsError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateFleetPortSettingsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFleetPortSettingsError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateFleetPortSettingsError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateFleetPortSettingsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8493,18 +10044,38 @@ impl UpdateGameServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateGameServerError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGameServerError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateGameServerError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGameServerError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateGameServerError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGameServerError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateGameServerError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGameServerError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8541,22 +10112,38 @@ impl UpdateGameServerGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateGameServerGroupError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateGameServerGroupError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateGameServerGroupError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateGameServerGroupError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateGameServerGroupError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGameServerGroupError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateGameServerGroupError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGameServerGroupError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8597,26 +10184,54 @@ impl UpdateGameSessionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateGameSessionError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGameSessionError::Conflict,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateGameSessionError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGameSessionError::InternalService,
+                    )
                 }
                 "InvalidGameSessionStatusException" => {
-                    return RusotoError::Service(UpdateGameSessionError::InvalidGameSessionStatus(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateGameSessionError::InvalidGameSessionStatus,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateGameSessionError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGameSessionError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateGameSessionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGameSessionError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateGameSessionError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGameSessionError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8655,22 +10270,38 @@ impl UpdateGameSessionQueueError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateGameSessionQueueError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateGameSessionQueueError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateGameSessionQueueError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateGameSessionQueueError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateGameSessionQueueError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGameSessionQueueError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateGameSessionQueueError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGameSessionQueueError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8709,26 +10340,38 @@ impl UpdateMatchmakingConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
-                        UpdateMatchmakingConfigurationError::InternalService(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMatchmakingConfigurationError::InternalService,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        UpdateMatchmakingConfigurationError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMatchmakingConfigurationError::InvalidRequest,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateMatchmakingConfigurationError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateMatchmakingConfigurationError::NotFound,
+                    )
                 }
                 "UnsupportedRegionException" => {
-                    return RusotoError::Service(
-                        UpdateMatchmakingConfigurationError::UnsupportedRegion(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMatchmakingConfigurationError::UnsupportedRegion,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8775,29 +10418,46 @@ impl UpdateRuntimeConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateRuntimeConfigurationError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRuntimeConfigurationError::InternalService,
+                    )
                 }
                 "InvalidFleetStatusException" => {
-                    return RusotoError::Service(
-                        UpdateRuntimeConfigurationError::InvalidFleetStatus(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRuntimeConfigurationError::InvalidFleetStatus,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateRuntimeConfigurationError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRuntimeConfigurationError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateRuntimeConfigurationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRuntimeConfigurationError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateRuntimeConfigurationError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRuntimeConfigurationError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8837,18 +10497,38 @@ impl UpdateScriptError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateScriptError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateScriptError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateScriptError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateScriptError::InvalidRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateScriptError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateScriptError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateScriptError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateScriptError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8885,21 +10565,30 @@ impl ValidateMatchmakingRuleSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ValidateMatchmakingRuleSetError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ValidateMatchmakingRuleSetError::InternalService,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ValidateMatchmakingRuleSetError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ValidateMatchmakingRuleSetError::InvalidRequest,
+                    )
                 }
                 "UnsupportedRegionException" => {
-                    return RusotoError::Service(
-                        ValidateMatchmakingRuleSetError::UnsupportedRegion(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ValidateMatchmakingRuleSetError::UnsupportedRegion,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9484,11 +11173,12 @@ pub struct GameLiftClient {
 impl GameLiftClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> GameLiftClient {
         GameLiftClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "GameLift"),
         }
     }
 
@@ -9503,12 +11193,32 @@ impl GameLiftClient {
     {
         GameLiftClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "GameLift"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> GameLiftClient {
-        GameLiftClient { client, region }
+        GameLiftClient {
+            client,
+            region: region_for_service(region, "GameLift"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> GameLiftClient {
+        GameLiftClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("GameLift"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> GameLiftClient {
+        GameLiftClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -9520,6 +11230,7 @@ impl GameLift for GameLiftClient {
         input: AcceptMatchInput,
     ) -> Result<AcceptMatchOutput, RusotoError<AcceptMatchError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AcceptMatch");
         request.add_header("x-amz-target", "GameLift.AcceptMatch");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9538,6 +11249,7 @@ impl GameLift for GameLiftClient {
         input: ClaimGameServerInput,
     ) -> Result<ClaimGameServerOutput, RusotoError<ClaimGameServerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ClaimGameServer");
         request.add_header("x-amz-target", "GameLift.ClaimGameServer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9556,6 +11268,7 @@ impl GameLift for GameLiftClient {
         input: CreateAliasInput,
     ) -> Result<CreateAliasOutput, RusotoError<CreateAliasError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateAlias");
         request.add_header("x-amz-target", "GameLift.CreateAlias");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9574,6 +11287,7 @@ impl GameLift for GameLiftClient {
         input: CreateBuildInput,
     ) -> Result<CreateBuildOutput, RusotoError<CreateBuildError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateBuild");
         request.add_header("x-amz-target", "GameLift.CreateBuild");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9592,6 +11306,7 @@ impl GameLift for GameLiftClient {
         input: CreateFleetInput,
     ) -> Result<CreateFleetOutput, RusotoError<CreateFleetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateFleet");
         request.add_header("x-amz-target", "GameLift.CreateFleet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9610,6 +11325,7 @@ impl GameLift for GameLiftClient {
         input: CreateGameServerGroupInput,
     ) -> Result<CreateGameServerGroupOutput, RusotoError<CreateGameServerGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateGameServerGroup");
         request.add_header("x-amz-target", "GameLift.CreateGameServerGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9628,6 +11344,7 @@ impl GameLift for GameLiftClient {
         input: CreateGameSessionInput,
     ) -> Result<CreateGameSessionOutput, RusotoError<CreateGameSessionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateGameSession");
         request.add_header("x-amz-target", "GameLift.CreateGameSession");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9646,6 +11363,7 @@ impl GameLift for GameLiftClient {
         input: CreateGameSessionQueueInput,
     ) -> Result<CreateGameSessionQueueOutput, RusotoError<CreateGameSessionQueueError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateGameSessionQueue");
         request.add_header("x-amz-target", "GameLift.CreateGameSessionQueue");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9668,6 +11386,7 @@ impl GameLift for GameLiftClient {
         RusotoError<CreateMatchmakingConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateMatchmakingConfiguration");
         request.add_header("x-amz-target", "GameLift.CreateMatchmakingConfiguration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9687,6 +11406,7 @@ impl GameLift for GameLiftClient {
         input: CreateMatchmakingRuleSetInput,
     ) -> Result<CreateMatchmakingRuleSetOutput, RusotoError<CreateMatchmakingRuleSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateMatchmakingRuleSet");
         request.add_header("x-amz-target", "GameLift.CreateMatchmakingRuleSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9706,6 +11426,7 @@ impl GameLift for GameLiftClient {
         input: CreatePlayerSessionInput,
     ) -> Result<CreatePlayerSessionOutput, RusotoError<CreatePlayerSessionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreatePlayerSession");
         request.add_header("x-amz-target", "GameLift.CreatePlayerSession");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9724,6 +11445,7 @@ impl GameLift for GameLiftClient {
         input: CreatePlayerSessionsInput,
     ) -> Result<CreatePlayerSessionsOutput, RusotoError<CreatePlayerSessionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreatePlayerSessions");
         request.add_header("x-amz-target", "GameLift.CreatePlayerSessions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9742,6 +11464,7 @@ impl GameLift for GameLiftClient {
         input: CreateScriptInput,
     ) -> Result<CreateScriptOutput, RusotoError<CreateScriptError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateScript");
         request.add_header("x-amz-target", "GameLift.CreateScript");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9761,6 +11484,7 @@ impl GameLift for GameLiftClient {
     ) -> Result<CreateVpcPeeringAuthorizationOutput, RusotoError<CreateVpcPeeringAuthorizationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateVpcPeeringAuthorization");
         request.add_header("x-amz-target", "GameLift.CreateVpcPeeringAuthorization");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9781,6 +11505,7 @@ impl GameLift for GameLiftClient {
     ) -> Result<CreateVpcPeeringConnectionOutput, RusotoError<CreateVpcPeeringConnectionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateVpcPeeringConnection");
         request.add_header("x-amz-target", "GameLift.CreateVpcPeeringConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9800,6 +11525,7 @@ impl GameLift for GameLiftClient {
         input: DeleteAliasInput,
     ) -> Result<(), RusotoError<DeleteAliasError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteAlias");
         request.add_header("x-amz-target", "GameLift.DeleteAlias");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9817,6 +11543,7 @@ impl GameLift for GameLiftClient {
         input: DeleteBuildInput,
     ) -> Result<(), RusotoError<DeleteBuildError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteBuild");
         request.add_header("x-amz-target", "GameLift.DeleteBuild");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9834,6 +11561,7 @@ impl GameLift for GameLiftClient {
         input: DeleteFleetInput,
     ) -> Result<(), RusotoError<DeleteFleetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteFleet");
         request.add_header("x-amz-target", "GameLift.DeleteFleet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9851,6 +11579,7 @@ impl GameLift for GameLiftClient {
         input: DeleteGameServerGroupInput,
     ) -> Result<DeleteGameServerGroupOutput, RusotoError<DeleteGameServerGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteGameServerGroup");
         request.add_header("x-amz-target", "GameLift.DeleteGameServerGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9869,6 +11598,7 @@ impl GameLift for GameLiftClient {
         input: DeleteGameSessionQueueInput,
     ) -> Result<DeleteGameSessionQueueOutput, RusotoError<DeleteGameSessionQueueError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteGameSessionQueue");
         request.add_header("x-amz-target", "GameLift.DeleteGameSessionQueue");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9891,6 +11621,7 @@ impl GameLift for GameLiftClient {
         RusotoError<DeleteMatchmakingConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteMatchmakingConfiguration");
         request.add_header("x-amz-target", "GameLift.DeleteMatchmakingConfiguration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9910,6 +11641,7 @@ impl GameLift for GameLiftClient {
         input: DeleteMatchmakingRuleSetInput,
     ) -> Result<DeleteMatchmakingRuleSetOutput, RusotoError<DeleteMatchmakingRuleSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteMatchmakingRuleSet");
         request.add_header("x-amz-target", "GameLift.DeleteMatchmakingRuleSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9929,6 +11661,7 @@ impl GameLift for GameLiftClient {
         input: DeleteScalingPolicyInput,
     ) -> Result<(), RusotoError<DeleteScalingPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteScalingPolicy");
         request.add_header("x-amz-target", "GameLift.DeleteScalingPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9946,6 +11679,7 @@ impl GameLift for GameLiftClient {
         input: DeleteScriptInput,
     ) -> Result<(), RusotoError<DeleteScriptError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteScript");
         request.add_header("x-amz-target", "GameLift.DeleteScript");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9964,6 +11698,7 @@ impl GameLift for GameLiftClient {
     ) -> Result<DeleteVpcPeeringAuthorizationOutput, RusotoError<DeleteVpcPeeringAuthorizationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteVpcPeeringAuthorization");
         request.add_header("x-amz-target", "GameLift.DeleteVpcPeeringAuthorization");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -9984,6 +11719,7 @@ impl GameLift for GameLiftClient {
     ) -> Result<DeleteVpcPeeringConnectionOutput, RusotoError<DeleteVpcPeeringConnectionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteVpcPeeringConnection");
         request.add_header("x-amz-target", "GameLift.DeleteVpcPeeringConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10003,6 +11739,7 @@ impl GameLift for GameLiftClient {
         input: DeregisterGameServerInput,
     ) -> Result<(), RusotoError<DeregisterGameServerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeregisterGameServer");
         request.add_header("x-amz-target", "GameLift.DeregisterGameServer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10020,6 +11757,7 @@ impl GameLift for GameLiftClient {
         input: DescribeAliasInput,
     ) -> Result<DescribeAliasOutput, RusotoError<DescribeAliasError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeAlias");
         request.add_header("x-amz-target", "GameLift.DescribeAlias");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10038,6 +11776,7 @@ impl GameLift for GameLiftClient {
         input: DescribeBuildInput,
     ) -> Result<DescribeBuildOutput, RusotoError<DescribeBuildError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeBuild");
         request.add_header("x-amz-target", "GameLift.DescribeBuild");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10056,6 +11795,7 @@ impl GameLift for GameLiftClient {
         input: DescribeEC2InstanceLimitsInput,
     ) -> Result<DescribeEC2InstanceLimitsOutput, RusotoError<DescribeEC2InstanceLimitsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeEC2InstanceLimits");
         request.add_header("x-amz-target", "GameLift.DescribeEC2InstanceLimits");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10075,6 +11815,7 @@ impl GameLift for GameLiftClient {
         input: DescribeFleetAttributesInput,
     ) -> Result<DescribeFleetAttributesOutput, RusotoError<DescribeFleetAttributesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeFleetAttributes");
         request.add_header("x-amz-target", "GameLift.DescribeFleetAttributes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10094,6 +11835,7 @@ impl GameLift for GameLiftClient {
         input: DescribeFleetCapacityInput,
     ) -> Result<DescribeFleetCapacityOutput, RusotoError<DescribeFleetCapacityError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeFleetCapacity");
         request.add_header("x-amz-target", "GameLift.DescribeFleetCapacity");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10112,6 +11854,7 @@ impl GameLift for GameLiftClient {
         input: DescribeFleetEventsInput,
     ) -> Result<DescribeFleetEventsOutput, RusotoError<DescribeFleetEventsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeFleetEvents");
         request.add_header("x-amz-target", "GameLift.DescribeFleetEvents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10130,6 +11873,7 @@ impl GameLift for GameLiftClient {
         input: DescribeFleetPortSettingsInput,
     ) -> Result<DescribeFleetPortSettingsOutput, RusotoError<DescribeFleetPortSettingsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeFleetPortSettings");
         request.add_header("x-amz-target", "GameLift.DescribeFleetPortSettings");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10149,6 +11893,7 @@ impl GameLift for GameLiftClient {
         input: DescribeFleetUtilizationInput,
     ) -> Result<DescribeFleetUtilizationOutput, RusotoError<DescribeFleetUtilizationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeFleetUtilization");
         request.add_header("x-amz-target", "GameLift.DescribeFleetUtilization");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10168,6 +11913,7 @@ impl GameLift for GameLiftClient {
         input: DescribeGameServerInput,
     ) -> Result<DescribeGameServerOutput, RusotoError<DescribeGameServerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeGameServer");
         request.add_header("x-amz-target", "GameLift.DescribeGameServer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10186,6 +11932,7 @@ impl GameLift for GameLiftClient {
         input: DescribeGameServerGroupInput,
     ) -> Result<DescribeGameServerGroupOutput, RusotoError<DescribeGameServerGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeGameServerGroup");
         request.add_header("x-amz-target", "GameLift.DescribeGameServerGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10206,6 +11953,7 @@ impl GameLift for GameLiftClient {
     ) -> Result<DescribeGameServerInstancesOutput, RusotoError<DescribeGameServerInstancesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeGameServerInstances");
         request.add_header("x-amz-target", "GameLift.DescribeGameServerInstances");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10226,6 +11974,7 @@ impl GameLift for GameLiftClient {
     ) -> Result<DescribeGameSessionDetailsOutput, RusotoError<DescribeGameSessionDetailsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeGameSessionDetails");
         request.add_header("x-amz-target", "GameLift.DescribeGameSessionDetails");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10246,6 +11995,7 @@ impl GameLift for GameLiftClient {
     ) -> Result<DescribeGameSessionPlacementOutput, RusotoError<DescribeGameSessionPlacementError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeGameSessionPlacement");
         request.add_header("x-amz-target", "GameLift.DescribeGameSessionPlacement");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10265,6 +12015,7 @@ impl GameLift for GameLiftClient {
         input: DescribeGameSessionQueuesInput,
     ) -> Result<DescribeGameSessionQueuesOutput, RusotoError<DescribeGameSessionQueuesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeGameSessionQueues");
         request.add_header("x-amz-target", "GameLift.DescribeGameSessionQueues");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10284,6 +12035,7 @@ impl GameLift for GameLiftClient {
         input: DescribeGameSessionsInput,
     ) -> Result<DescribeGameSessionsOutput, RusotoError<DescribeGameSessionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeGameSessions");
         request.add_header("x-amz-target", "GameLift.DescribeGameSessions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10302,6 +12054,7 @@ impl GameLift for GameLiftClient {
         input: DescribeInstancesInput,
     ) -> Result<DescribeInstancesOutput, RusotoError<DescribeInstancesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeInstances");
         request.add_header("x-amz-target", "GameLift.DescribeInstances");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10320,6 +12073,7 @@ impl GameLift for GameLiftClient {
         input: DescribeMatchmakingInput,
     ) -> Result<DescribeMatchmakingOutput, RusotoError<DescribeMatchmakingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeMatchmaking");
         request.add_header("x-amz-target", "GameLift.DescribeMatchmaking");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10341,6 +12095,7 @@ impl GameLift for GameLiftClient {
         RusotoError<DescribeMatchmakingConfigurationsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeMatchmakingConfigurations");
         request.add_header("x-amz-target", "GameLift.DescribeMatchmakingConfigurations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10364,6 +12119,7 @@ impl GameLift for GameLiftClient {
     ) -> Result<DescribeMatchmakingRuleSetsOutput, RusotoError<DescribeMatchmakingRuleSetsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeMatchmakingRuleSets");
         request.add_header("x-amz-target", "GameLift.DescribeMatchmakingRuleSets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10383,6 +12139,7 @@ impl GameLift for GameLiftClient {
         input: DescribePlayerSessionsInput,
     ) -> Result<DescribePlayerSessionsOutput, RusotoError<DescribePlayerSessionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribePlayerSessions");
         request.add_header("x-amz-target", "GameLift.DescribePlayerSessions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10403,6 +12160,7 @@ impl GameLift for GameLiftClient {
     ) -> Result<DescribeRuntimeConfigurationOutput, RusotoError<DescribeRuntimeConfigurationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeRuntimeConfiguration");
         request.add_header("x-amz-target", "GameLift.DescribeRuntimeConfiguration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10422,6 +12180,7 @@ impl GameLift for GameLiftClient {
         input: DescribeScalingPoliciesInput,
     ) -> Result<DescribeScalingPoliciesOutput, RusotoError<DescribeScalingPoliciesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeScalingPolicies");
         request.add_header("x-amz-target", "GameLift.DescribeScalingPolicies");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10441,6 +12200,7 @@ impl GameLift for GameLiftClient {
         input: DescribeScriptInput,
     ) -> Result<DescribeScriptOutput, RusotoError<DescribeScriptError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeScript");
         request.add_header("x-amz-target", "GameLift.DescribeScript");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10461,6 +12221,7 @@ impl GameLift for GameLiftClient {
         RusotoError<DescribeVpcPeeringAuthorizationsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeVpcPeeringAuthorizations");
         request.add_header("x-amz-target", "GameLift.DescribeVpcPeeringAuthorizations");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -10483,6 +12244,7 @@ impl GameLift for GameLiftClient {
     ) -> Result<DescribeVpcPeeringConnectionsOutput, RusotoError<DescribeVpcPeeringConnectionsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeVpcPeeringConnections");
         request.add_header("x-amz-target", "GameLift.DescribeVpcPeeringConnections");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10502,6 +12264,7 @@ impl GameLift for GameLiftClient {
         input: GetGameSessionLogUrlInput,
     ) -> Result<GetGameSessionLogUrlOutput, RusotoError<GetGameSessionLogUrlError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetGameSessionLogUrl");
         request.add_header("x-amz-target", "GameLift.GetGameSessionLogUrl");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10520,6 +12283,7 @@ impl GameLift for GameLiftClient {
         input: GetInstanceAccessInput,
     ) -> Result<GetInstanceAccessOutput, RusotoError<GetInstanceAccessError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetInstanceAccess");
         request.add_header("x-amz-target", "GameLift.GetInstanceAccess");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10538,6 +12302,7 @@ impl GameLift for GameLiftClient {
         input: ListAliasesInput,
     ) -> Result<ListAliasesOutput, RusotoError<ListAliasesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListAliases");
         request.add_header("x-amz-target", "GameLift.ListAliases");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10556,6 +12321,7 @@ impl GameLift for GameLiftClient {
         input: ListBuildsInput,
     ) -> Result<ListBuildsOutput, RusotoError<ListBuildsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListBuilds");
         request.add_header("x-amz-target", "GameLift.ListBuilds");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10574,6 +12340,7 @@ impl GameLift for GameLiftClient {
         input: ListFleetsInput,
     ) -> Result<ListFleetsOutput, RusotoError<ListFleetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListFleets");
         request.add_header("x-amz-target", "GameLift.ListFleets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10592,6 +12359,7 @@ impl GameLift for GameLiftClient {
         input: ListGameServerGroupsInput,
     ) -> Result<ListGameServerGroupsOutput, RusotoError<ListGameServerGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListGameServerGroups");
         request.add_header("x-amz-target", "GameLift.ListGameServerGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10610,6 +12378,7 @@ impl GameLift for GameLiftClient {
         input: ListGameServersInput,
     ) -> Result<ListGameServersOutput, RusotoError<ListGameServersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListGameServers");
         request.add_header("x-amz-target", "GameLift.ListGameServers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10628,6 +12397,7 @@ impl GameLift for GameLiftClient {
         input: ListScriptsInput,
     ) -> Result<ListScriptsOutput, RusotoError<ListScriptsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListScripts");
         request.add_header("x-amz-target", "GameLift.ListScripts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10646,6 +12416,7 @@ impl GameLift for GameLiftClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header("x-amz-target", "GameLift.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10664,6 +12435,7 @@ impl GameLift for GameLiftClient {
         input: PutScalingPolicyInput,
     ) -> Result<PutScalingPolicyOutput, RusotoError<PutScalingPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutScalingPolicy");
         request.add_header("x-amz-target", "GameLift.PutScalingPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10682,6 +12454,7 @@ impl GameLift for GameLiftClient {
         input: RegisterGameServerInput,
     ) -> Result<RegisterGameServerOutput, RusotoError<RegisterGameServerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RegisterGameServer");
         request.add_header("x-amz-target", "GameLift.RegisterGameServer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10700,6 +12473,7 @@ impl GameLift for GameLiftClient {
         input: RequestUploadCredentialsInput,
     ) -> Result<RequestUploadCredentialsOutput, RusotoError<RequestUploadCredentialsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RequestUploadCredentials");
         request.add_header("x-amz-target", "GameLift.RequestUploadCredentials");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10719,6 +12493,7 @@ impl GameLift for GameLiftClient {
         input: ResolveAliasInput,
     ) -> Result<ResolveAliasOutput, RusotoError<ResolveAliasError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ResolveAlias");
         request.add_header("x-amz-target", "GameLift.ResolveAlias");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10737,6 +12512,7 @@ impl GameLift for GameLiftClient {
         input: ResumeGameServerGroupInput,
     ) -> Result<ResumeGameServerGroupOutput, RusotoError<ResumeGameServerGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ResumeGameServerGroup");
         request.add_header("x-amz-target", "GameLift.ResumeGameServerGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10755,6 +12531,7 @@ impl GameLift for GameLiftClient {
         input: SearchGameSessionsInput,
     ) -> Result<SearchGameSessionsOutput, RusotoError<SearchGameSessionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SearchGameSessions");
         request.add_header("x-amz-target", "GameLift.SearchGameSessions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10773,6 +12550,7 @@ impl GameLift for GameLiftClient {
         input: StartFleetActionsInput,
     ) -> Result<StartFleetActionsOutput, RusotoError<StartFleetActionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartFleetActions");
         request.add_header("x-amz-target", "GameLift.StartFleetActions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10791,6 +12569,7 @@ impl GameLift for GameLiftClient {
         input: StartGameSessionPlacementInput,
     ) -> Result<StartGameSessionPlacementOutput, RusotoError<StartGameSessionPlacementError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartGameSessionPlacement");
         request.add_header("x-amz-target", "GameLift.StartGameSessionPlacement");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10810,6 +12589,7 @@ impl GameLift for GameLiftClient {
         input: StartMatchBackfillInput,
     ) -> Result<StartMatchBackfillOutput, RusotoError<StartMatchBackfillError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartMatchBackfill");
         request.add_header("x-amz-target", "GameLift.StartMatchBackfill");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10828,6 +12608,7 @@ impl GameLift for GameLiftClient {
         input: StartMatchmakingInput,
     ) -> Result<StartMatchmakingOutput, RusotoError<StartMatchmakingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartMatchmaking");
         request.add_header("x-amz-target", "GameLift.StartMatchmaking");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10846,6 +12627,7 @@ impl GameLift for GameLiftClient {
         input: StopFleetActionsInput,
     ) -> Result<StopFleetActionsOutput, RusotoError<StopFleetActionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopFleetActions");
         request.add_header("x-amz-target", "GameLift.StopFleetActions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10864,6 +12646,7 @@ impl GameLift for GameLiftClient {
         input: StopGameSessionPlacementInput,
     ) -> Result<StopGameSessionPlacementOutput, RusotoError<StopGameSessionPlacementError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopGameSessionPlacement");
         request.add_header("x-amz-target", "GameLift.StopGameSessionPlacement");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10883,6 +12666,7 @@ impl GameLift for GameLiftClient {
         input: StopMatchmakingInput,
     ) -> Result<StopMatchmakingOutput, RusotoError<StopMatchmakingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopMatchmaking");
         request.add_header("x-amz-target", "GameLift.StopMatchmaking");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10901,6 +12685,7 @@ impl GameLift for GameLiftClient {
         input: SuspendGameServerGroupInput,
     ) -> Result<SuspendGameServerGroupOutput, RusotoError<SuspendGameServerGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SuspendGameServerGroup");
         request.add_header("x-amz-target", "GameLift.SuspendGameServerGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10920,6 +12705,7 @@ impl GameLift for GameLiftClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "GameLift.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10938,6 +12724,7 @@ impl GameLift for GameLiftClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "GameLift.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10956,6 +12743,7 @@ impl GameLift for GameLiftClient {
         input: UpdateAliasInput,
     ) -> Result<UpdateAliasOutput, RusotoError<UpdateAliasError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateAlias");
         request.add_header("x-amz-target", "GameLift.UpdateAlias");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10974,6 +12762,7 @@ impl GameLift for GameLiftClient {
         input: UpdateBuildInput,
     ) -> Result<UpdateBuildOutput, RusotoError<UpdateBuildError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateBuild");
         request.add_header("x-amz-target", "GameLift.UpdateBuild");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -10992,6 +12781,7 @@ impl GameLift for GameLiftClient {
         input: UpdateFleetAttributesInput,
     ) -> Result<UpdateFleetAttributesOutput, RusotoError<UpdateFleetAttributesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateFleetAttributes");
         request.add_header("x-amz-target", "GameLift.UpdateFleetAttributes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -11010,6 +12800,7 @@ impl GameLift for GameLiftClient {
         input: UpdateFleetCapacityInput,
     ) -> Result<UpdateFleetCapacityOutput, RusotoError<UpdateFleetCapacityError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateFleetCapacity");
         request.add_header("x-amz-target", "GameLift.UpdateFleetCapacity");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -11028,6 +12819,7 @@ impl GameLift for GameLiftClient {
         input: UpdateFleetPortSettingsInput,
     ) -> Result<UpdateFleetPortSettingsOutput, RusotoError<UpdateFleetPortSettingsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateFleetPortSettings");
         request.add_header("x-amz-target", "GameLift.UpdateFleetPortSettings");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -11047,6 +12839,7 @@ impl GameLift for GameLiftClient {
         input: UpdateGameServerInput,
     ) -> Result<UpdateGameServerOutput, RusotoError<UpdateGameServerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateGameServer");
         request.add_header("x-amz-target", "GameLift.UpdateGameServer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -11065,6 +12858,7 @@ impl GameLift for GameLiftClient {
         input: UpdateGameServerGroupInput,
     ) -> Result<UpdateGameServerGroupOutput, RusotoError<UpdateGameServerGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateGameServerGroup");
         request.add_header("x-amz-target", "GameLift.UpdateGameServerGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -11083,6 +12877,7 @@ impl GameLift for GameLiftClient {
         input: UpdateGameSessionInput,
     ) -> Result<UpdateGameSessionOutput, RusotoError<UpdateGameSessionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateGameSession");
         request.add_header("x-amz-target", "GameLift.UpdateGameSession");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -11101,6 +12896,7 @@ impl GameLift for GameLiftClient {
         input: UpdateGameSessionQueueInput,
     ) -> Result<UpdateGameSessionQueueOutput, RusotoError<UpdateGameSessionQueueError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateGameSessionQueue");
         request.add_header("x-amz-target", "GameLift.UpdateGameSessionQueue");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -11123,6 +12919,7 @@ impl GameLift for GameLiftClient {
         RusotoError<UpdateMatchmakingConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateMatchmakingConfiguration");
         request.add_header("x-amz-target", "GameLift.UpdateMatchmakingConfiguration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -11143,6 +12940,7 @@ impl GameLift for GameLiftClient {
     ) -> Result<UpdateRuntimeConfigurationOutput, RusotoError<UpdateRuntimeConfigurationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateRuntimeConfiguration");
         request.add_header("x-amz-target", "GameLift.UpdateRuntimeConfiguration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -11162,6 +12960,7 @@ impl GameLift for GameLiftClient {
         input: UpdateScriptInput,
     ) -> Result<UpdateScriptOutput, RusotoError<UpdateScriptError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateScript");
         request.add_header("x-amz-target", "GameLift.UpdateScript");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -11181,6 +12980,7 @@ impl GameLift for GameLiftClient {
     ) -> Result<ValidateMatchmakingRuleSetOutput, RusotoError<ValidateMatchmakingRuleSetError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ValidateMatchmakingRuleSet");
         request.add_header("x-amz-target", "GameLift.ValidateMatchmakingRuleSet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -11194,3 +12994,884 @@ impl GameLift for GameLiftClient {
             .deserialize::<ValidateMatchmakingRuleSetOutput, _>()
     }
 }
+
+/// A blocking client for the Amazon GameLift API, running the calls of a `GameLiftClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `GameLiftClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct GameLiftBlockingClient {
+    client: GameLiftClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl GameLiftBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> GameLiftBlockingClient {
+        GameLiftBlockingClient::from_client(GameLiftClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> GameLiftBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        GameLiftBlockingClient::from_client(GameLiftClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> GameLiftBlockingClient {
+        GameLiftBlockingClient::from_client(GameLiftClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> GameLiftBlockingClient {
+        GameLiftBlockingClient::from_client(GameLiftClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: GameLiftClient) -> GameLiftBlockingClient {
+        GameLiftBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> GameLiftBlockingClient {
+        GameLiftBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> GameLiftBlockingClient {
+        GameLiftBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`GameLift::accept_match`].
+    pub fn accept_match(
+        &self,
+        input: AcceptMatchInput,
+    ) -> Result<AcceptMatchOutput, RusotoError<AcceptMatchError>> {
+        self.runtime.block_on(self.client.accept_match(input))
+    }
+
+    /// Blocking version of [`GameLift::claim_game_server`].
+    pub fn claim_game_server(
+        &self,
+        input: ClaimGameServerInput,
+    ) -> Result<ClaimGameServerOutput, RusotoError<ClaimGameServerError>> {
+        self.runtime.block_on(self.client.claim_game_server(input))
+    }
+
+    /// Blocking version of [`GameLift::create_alias`].
+    pub fn create_alias(
+        &self,
+        input: CreateAliasInput,
+    ) -> Result<CreateAliasOutput, RusotoError<CreateAliasError>> {
+        self.runtime.block_on(self.client.create_alias(input))
+    }
+
+    /// Blocking version of [`GameLift::create_build`].
+    pub fn create_build(
+        &self,
+        input: CreateBuildInput,
+    ) -> Result<CreateBuildOutput, RusotoError<CreateBuildError>> {
+        self.runtime.block_on(self.client.create_build(input))
+    }
+
+    /// Blocking version of [`GameLift::create_fleet`].
+    pub fn create_fleet(
+        &self,
+        input: CreateFleetInput,
+    ) -> Result<CreateFleetOutput, RusotoError<CreateFleetError>> {
+        self.runtime.block_on(self.client.create_fleet(input))
+    }
+
+    /// Blocking version of [`GameLift::create_game_server_group`].
+    pub fn create_game_server_group(
+        &self,
+        input: CreateGameServerGroupInput,
+    ) -> Result<CreateGameServerGroupOutput, RusotoError<CreateGameServerGroupError>> {
+        self.runtime
+            .block_on(self.client.create_game_server_group(input))
+    }
+
+    /// Blocking version of [`GameLift::create_game_session`].
+    pub fn create_game_session(
+        &self,
+        input: CreateGameSessionInput,
+    ) -> Result<CreateGameSessionOutput, RusotoError<CreateGameSessionError>> {
+        self.runtime
+            .block_on(self.client.create_game_session(input))
+    }
+
+    /// Blocking version of [`GameLift::create_game_session_queue`].
+    pub fn create_game_session_queue(
+        &self,
+        input: CreateGameSessionQueueInput,
+    ) -> Result<CreateGameSessionQueueOutput, RusotoError<CreateGameSessionQueueError>> {
+        self.runtime
+            .block_on(self.client.create_game_session_queue(input))
+    }
+
+    /// Blocking version of [`GameLift::create_matchmaking_configuration`].
+    pub fn create_matchmaking_configuration(
+        &self,
+        input: CreateMatchmakingConfigurationInput,
+    ) -> Result<
+        CreateMatchmakingConfigurationOutput,
+        RusotoError<CreateMatchmakingConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_matchmaking_configuration(input))
+    }
+
+    /// Blocking version of [`GameLift::create_matchmaking_rule_set`].
+    pub fn create_matchmaking_rule_set(
+        &self,
+        input: CreateMatchmakingRuleSetInput,
+    ) -> Result<CreateMatchmakingRuleSetOutput, RusotoError<CreateMatchmakingRuleSetError>> {
+        self.runtime
+            .block_on(self.client.create_matchmaking_rule_set(input))
+    }
+
+    /// Blocking version of [`GameLift::create_player_session`].
+    pub fn create_player_session(
+        &self,
+        input: CreatePlayerSessionInput,
+    ) -> Result<CreatePlayerSessionOutput, RusotoError<CreatePlayerSessionError>> {
+        self.runtime
+            .block_on(self.client.create_player_session(input))
+    }
+
+    /// Blocking version of [`GameLift::create_player_sessions`].
+    pub fn create_player_sessions(
+        &self,
+        input: CreatePlayerSessionsInput,
+    ) -> Result<CreatePlayerSessionsOutput, RusotoError<CreatePlayerSessionsError>> {
+        self.runtime
+            .block_on(self.client.create_player_sessions(input))
+    }
+
+    /// Blocking version of [`GameLift::create_script`].
+    pub fn create_script(
+        &self,
+        input: CreateScriptInput,
+    ) -> Result<CreateScriptOutput, RusotoError<CreateScriptError>> {
+        self.runtime.block_on(self.client.create_script(input))
+    }
+
+    /// Blocking version of [`GameLift::create_vpc_peering_authorization`].
+    pub fn create_vpc_peering_authorization(
+        &self,
+        input: CreateVpcPeeringAuthorizationInput,
+    ) -> Result<CreateVpcPeeringAuthorizationOutput, RusotoError<CreateVpcPeeringAuthorizationError>>
+    {
+        self.runtime
+            .block_on(self.client.create_vpc_peering_authorization(input))
+    }
+
+    /// Blocking version of [`GameLift::create_vpc_peering_connection`].
+    pub fn create_vpc_peering_connection(
+        &self,
+        input: CreateVpcPeeringConnectionInput,
+    ) -> Result<CreateVpcPeeringConnectionOutput, RusotoError<CreateVpcPeeringConnectionError>>
+    {
+        self.runtime
+            .block_on(self.client.create_vpc_peering_connection(input))
+    }
+
+    /// Blocking version of [`GameLift::delete_alias`].
+    pub fn delete_alias(
+        &self,
+        input: DeleteAliasInput,
+    ) -> Result<(), RusotoError<DeleteAliasError>> {
+        self.runtime.block_on(self.client.delete_alias(input))
+    }
+
+    /// Blocking version of [`GameLift::delete_build`].
+    pub fn delete_build(
+        &self,
+        input: DeleteBuildInput,
+    ) -> Result<(), RusotoError<DeleteBuildError>> {
+        self.runtime.block_on(self.client.delete_build(input))
+    }
+
+    /// Blocking version of [`GameLift::delete_fleet`].
+    pub fn delete_fleet(
+        &self,
+        input: DeleteFleetInput,
+    ) -> Result<(), RusotoError<DeleteFleetError>> {
+        self.runtime.block_on(self.client.delete_fleet(input))
+    }
+
+    /// Blocking version of [`GameLift::delete_game_server_group`].
+    pub fn delete_game_server_group(
+        &self,
+        input: DeleteGameServerGroupInput,
+    ) -> Result<DeleteGameServerGroupOutput, RusotoError<DeleteGameServerGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_game_server_group(input))
+    }
+
+    /// Blocking version of [`GameLift::delete_game_session_queue`].
+    pub fn delete_game_session_queue(
+        &self,
+        input: DeleteGameSessionQueueInput,
+    ) -> Result<DeleteGameSessionQueueOutput, RusotoError<DeleteGameSessionQueueError>> {
+        self.runtime
+            .block_on(self.client.delete_game_session_queue(input))
+    }
+
+    /// Blocking version of [`GameLift::delete_matchmaking_configuration`].
+    pub fn delete_matchmaking_configuration(
+        &self,
+        input: DeleteMatchmakingConfigurationInput,
+    ) -> Result<
+        DeleteMatchmakingConfigurationOutput,
+        RusotoError<DeleteMatchmakingConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_matchmaking_configuration(input))
+    }
+
+    /// Blocking version of [`GameLift::delete_matchmaking_rule_set`].
+    pub fn delete_matchmaking_rule_set(
+        &self,
+        input: DeleteMatchmakingRuleSetInput,
+    ) -> Result<DeleteMatchmakingRuleSetOutput, RusotoError<DeleteMatchmakingRuleSetError>> {
+        self.runtime
+            .block_on(self.client.delete_matchmaking_rule_set(input))
+    }
+
+    /// Blocking version of [`GameLift::delete_scaling_policy`].
+    pub fn delete_scaling_policy(
+        &self,
+        input: DeleteScalingPolicyInput,
+    ) -> Result<(), RusotoError<DeleteScalingPolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_scaling_policy(input))
+    }
+
+    /// Blocking version of [`GameLift::delete_script`].
+    pub fn delete_script(
+        &self,
+        input: DeleteScriptInput,
+    ) -> Result<(), RusotoError<DeleteScriptError>> {
+        self.runtime.block_on(self.client.delete_script(input))
+    }
+
+    /// Blocking version of [`GameLift::delete_vpc_peering_authorization`].
+    pub fn delete_vpc_peering_authorization(
+        &self,
+        input: DeleteVpcPeeringAuthorizationInput,
+    ) -> Result<DeleteVpcPeeringAuthorizationOutput, RusotoError<DeleteVpcPeeringAuthorizationError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_vpc_peering_authorization(input))
+    }
+
+    /// Blocking version of [`GameLift::delete_vpc_peering_connection`].
+    pub fn delete_vpc_peering_connection(
+        &self,
+        input: DeleteVpcPeeringConnectionInput,
+    ) -> Result<DeleteVpcPeeringConnectionOutput, RusotoError<DeleteVpcPeeringConnectionError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_vpc_peering_connection(input))
+    }
+
+    /// Blocking version of [`GameLift::deregister_game_server`].
+    pub fn deregister_game_server(
+        &self,
+        input: DeregisterGameServerInput,
+    ) -> Result<(), RusotoError<DeregisterGameServerError>> {
+        self.runtime
+            .block_on(self.client.deregister_game_server(input))
+    }
+
+    /// Blocking version of [`GameLift::describe_alias`].
+    pub fn describe_alias(
+        &self,
+        input: DescribeAliasInput,
+    ) -> Result<DescribeAliasOutput, RusotoError<DescribeAliasError>> {
+        self.runtime.block_on(self.client.describe_alias(input))
+    }
+
+    /// Blocking version of [`GameLift::describe_build`].
+    pub fn describe_build(
+        &self,
+        input: DescribeBuildInput,
+    ) -> Result<DescribeBuildOutput, RusotoError<DescribeBuildError>> {
+        self.runtime.block_on(self.client.describe_build(input))
+    }
+
+    /// Blocking version of [`GameLift::describe_ec2_instance_limits`].
+    pub fn describe_ec2_instance_limits(
+        &self,
+        input: DescribeEC2InstanceLimitsInput,
+    ) -> Result<DescribeEC2InstanceLimitsOutput, RusotoError<DescribeEC2InstanceLimitsError>> {
+        self.runtime
+            .block_on(self.client.describe_ec2_instance_limits(input))
+    }
+
+    /// Blocking version of [`GameLift::describe_fleet_attributes`].
+    pub fn describe_fleet_attributes(
+        &self,
+        input: DescribeFleetAttributesInput,
+    ) -> Result<DescribeFleetAttributesOutput, RusotoError<DescribeFleetAttributesError>> {
+        self.runtime
+            .block_on(self.client.describe_fleet_attributes(input))
+    }
+
+    /// Blocking version of [`GameLift::describe_fleet_capacity`].
+    pub fn describe_fleet_capacity(
+        &self,
+        input: DescribeFleetCapacityInput,
+    ) -> Result<DescribeFleetCapacityOutput, RusotoError<DescribeFleetCapacityError>> {
+        self.runtime
+            .block_on(self.client.describe_fleet_capacity(input))
+    }
+
+    /// Blocking version of [`GameLift::describe_fleet_events`].
+    pub fn describe_fleet_events(
+        &self,
+        input: DescribeFleetEventsInput,
+    ) -> Result<DescribeFleetEventsOutput, RusotoError<DescribeFleetEventsError>> {
+        self.runtime
+            .block_on(self.client.describe_fleet_events(input))
+    }
+
+    /// Blocking version of [`GameLift::describe_fleet_port_settings`].
+    pub fn describe_fleet_port_settings(
+        &self,
+        input: DescribeFleetPortSettingsInput,
+    ) -> Result<DescribeFleetPortSettingsOutput, RusotoError<DescribeFleetPortSettingsError>> {
+        self.runtime
+            .block_on(self.client.describe_fleet_port_settings(input))
+    }
+
+    /// Blocking version of [`GameLift::describe_fleet_utilization`].
+    pub fn describe_fleet_utilization(
+        &self,
+        input: DescribeFleetUtilizationInput,
+    ) -> Result<DescribeFleetUtilizationOutput, RusotoError<DescribeFleetUtilizationError>> {
+        self.runtime
+            .block_on(self.client.describe_fleet_utilization(input))
+    }
+
+    /// Blocking version of [`GameLift::describe_game_server`].
+    pub fn describe_game_server(
+        &self,
+        input: DescribeGameServerInput,
+    ) -> Result<DescribeGameServerOutput, RusotoError<DescribeGameServerError>> {
+        self.runtime
+            .block_on(self.client.describe_game_server(input))
+    }
+
+    /// Blocking version of [`GameLift::describe_game_server_group`].
+    pub fn describe_game_server_group(
+        &self,
+        input: DescribeGameServerGroupInput,
+    ) -> Result<DescribeGameServerGroupOutput, RusotoError<DescribeGameServerGroupError>> {
+        self.runtime
+            .block_on(self.client.describe_game_server_group(input))
+    }
+
+    /// Blocking version of [`GameLift::describe_game_server_instances`].
+    pub fn describe_game_server_instances(
+        &self,
+        input: DescribeGameServerInstancesInput,
+    ) -> Result<DescribeGameServerInstancesOutput, RusotoError<DescribeGameServerInstancesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_game_server_instances(input))
+    }
+
+    /// Blocking version of [`GameLift::describe_game_session_details`].
+    pub fn describe_game_session_details(
+        &self,
+        input: DescribeGameSessionDetailsInput,
+    ) -> Result<DescribeGameSessionDetailsOutput, RusotoError<DescribeGameSessionDetailsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_game_session_details(input))
+    }
+
+    /// Blocking version of [`GameLift::describe_game_session_placement`].
+    pub fn describe_game_session_placement(
+        &self,
+        input: DescribeGameSessionPlacementInput,
+    ) -> Result<DescribeGameSessionPlacementOutput, RusotoError<DescribeGameSessionPlacementError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_game_session_placement(input))
+    }
+
+    /// Blocking version of [`GameLift::describe_game_session_queues`].
+    pub fn describe_game_session_queues(
+        &self,
+        input: DescribeGameSessionQueuesInput,
+    ) -> Result<DescribeGameSessionQueuesOutput, RusotoError<DescribeGameSessionQueuesError>> {
+        self.runtime
+            .block_on(self.client.describe_game_session_queues(input))
+    }
+
+    /// Blocking version of [`GameLift::describe_game_sessions`].
+    pub fn describe_game_sessions(
+        &self,
+        input: DescribeGameSessionsInput,
+    ) -> Result<DescribeGameSessionsOutput, RusotoError<DescribeGameSessionsError>> {
+        self.runtime
+            .block_on(self.client.describe_game_sessions(input))
+    }
+
+    /// Blocking version of [`GameLift::describe_instances`].
+    pub fn describe_instances(
+        &self,
+        input: DescribeInstancesInput,
+    ) -> Result<DescribeInstancesOutput, RusotoError<DescribeInstancesError>> {
+        self.runtime.block_on(self.client.describe_instances(input))
+    }
+
+    /// Blocking version of [`GameLift::describe_matchmaking`].
+    pub fn describe_matchmaking(
+        &self,
+        input: DescribeMatchmakingInput,
+    ) -> Result<DescribeMatchmakingOutput, RusotoError<DescribeMatchmakingError>> {
+        self.runtime
+            .block_on(self.client.describe_matchmaking(input))
+    }
+
+    /// Blocking version of [`GameLift::describe_matchmaking_configurations`].
+    pub fn describe_matchmaking_configurations(
+        &self,
+        input: DescribeMatchmakingConfigurationsInput,
+    ) -> Result<
+        DescribeMatchmakingConfigurationsOutput,
+        RusotoError<DescribeMatchmakingConfigurationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_matchmaking_configurations(input))
+    }
+
+    /// Blocking version of [`GameLift::describe_matchmaking_rule_sets`].
+    pub fn describe_matchmaking_rule_sets(
+        &self,
+        input: DescribeMatchmakingRuleSetsInput,
+    ) -> Result<DescribeMatchmakingRuleSetsOutput, RusotoError<DescribeMatchmakingRuleSetsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_matchmaking_rule_sets(input))
+    }
+
+    /// Blocking version of [`GameLift::describe_player_sessions`].
+    pub fn describe_player_sessions(
+        &self,
+        input: DescribePlayerSessionsInput,
+    ) -> Result<DescribePlayerSessionsOutput, RusotoError<DescribePlayerSessionsError>> {
+        self.runtime
+            .block_on(self.client.describe_player_sessions(input))
+    }
+
+    /// Blocking version of [`GameLift::describe_runtime_configuration`].
+    pub fn describe_runtime_configuration(
+        &self,
+        input: DescribeRuntimeConfigurationInput,
+    ) -> Result<DescribeRuntimeConfigurationOutput, RusotoError<DescribeRuntimeConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_runtime_configuration(input))
+    }
+
+    /// Blocking version of [`GameLift::describe_scaling_policies`].
+    pub fn describe_scaling_policies(
+        &self,
+        input: DescribeScalingPoliciesInput,
+    ) -> Result<DescribeScalingPoliciesOutput, RusotoError<DescribeScalingPoliciesError>> {
+        self.runtime
+            .block_on(self.client.describe_scaling_policies(input))
+    }
+
+    /// Blocking version of [`GameLift::describe_script`].
+    pub fn describe_script(
+        &self,
+        input: DescribeScriptInput,
+    ) -> Result<DescribeScriptOutput, RusotoError<DescribeScriptError>> {
+        self.runtime.block_on(self.client.describe_script(input))
+    }
+
+    /// Blocking version of [`GameLift::describe_vpc_peering_authorizations`].
+    pub fn describe_vpc_peering_authorizations(
+        &self,
+    ) -> Result<
+        DescribeVpcPeeringAuthorizationsOutput,
+        RusotoError<DescribeVpcPeeringAuthorizationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_vpc_peering_authorizations())
+    }
+
+    /// Blocking version of [`GameLift::describe_vpc_peering_connections`].
+    pub fn describe_vpc_peering_connections(
+        &self,
+        input: DescribeVpcPeeringConnectionsInput,
+    ) -> Result<DescribeVpcPeeringConnectionsOutput, RusotoError<DescribeVpcPeeringConnectionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_vpc_peering_connections(input))
+    }
+
+    /// Blocking version of [`GameLift::get_game_session_log_url`].
+    pub fn get_game_session_log_url(
+        &self,
+        input: GetGameSessionLogUrlInput,
+    ) -> Result<GetGameSessionLogUrlOutput, RusotoError<GetGameSessionLogUrlError>> {
+        self.runtime
+            .block_on(self.client.get_game_session_log_url(input))
+    }
+
+    /// Blocking version of [`GameLift::get_instance_access`].
+    pub fn get_instance_access(
+        &self,
+        input: GetInstanceAccessInput,
+    ) -> Result<GetInstanceAccessOutput, RusotoError<GetInstanceAccessError>> {
+        self.runtime
+            .block_on(self.client.get_instance_access(input))
+    }
+
+    /// Blocking version of [`GameLift::list_aliases`].
+    pub fn list_aliases(
+        &self,
+        input: ListAliasesInput,
+    ) -> Result<ListAliasesOutput, RusotoError<ListAliasesError>> {
+        self.runtime.block_on(self.client.list_aliases(input))
+    }
+
+    /// Blocking version of [`GameLift::list_builds`].
+    pub fn list_builds(
+        &self,
+        input: ListBuildsInput,
+    ) -> Result<ListBuildsOutput, RusotoError<ListBuildsError>> {
+        self.runtime.block_on(self.client.list_builds(input))
+    }
+
+    /// Blocking version of [`GameLift::list_fleets`].
+    pub fn list_fleets(
+        &self,
+        input: ListFleetsInput,
+    ) -> Result<ListFleetsOutput, RusotoError<ListFleetsError>> {
+        self.runtime.block_on(self.client.list_fleets(input))
+    }
+
+    /// Blocking version of [`GameLift::list_game_server_groups`].
+    pub fn list_game_server_groups(
+        &self,
+        input: ListGameServerGroupsInput,
+    ) -> Result<ListGameServerGroupsOutput, RusotoError<ListGameServerGroupsError>> {
+        self.runtime
+            .block_on(self.client.list_game_server_groups(input))
+    }
+
+    /// Blocking version of [`GameLift::list_game_servers`].
+    pub fn list_game_servers(
+        &self,
+        input: ListGameServersInput,
+    ) -> Result<ListGameServersOutput, RusotoError<ListGameServersError>> {
+        self.runtime.block_on(self.client.list_game_servers(input))
+    }
+
+    /// Blocking version of [`GameLift::list_scripts`].
+    pub fn list_scripts(
+        &self,
+        input: ListScriptsInput,
+    ) -> Result<ListScriptsOutput, RusotoError<ListScriptsError>> {
+        self.runtime.block_on(self.client.list_scripts(input))
+    }
+
+    /// Blocking version of [`GameLift::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`GameLift::put_scaling_policy`].
+    pub fn put_scaling_policy(
+        &self,
+        input: PutScalingPolicyInput,
+    ) -> Result<PutScalingPolicyOutput, RusotoError<PutScalingPolicyError>> {
+        self.runtime.block_on(self.client.put_scaling_policy(input))
+    }
+
+    /// Blocking version of [`GameLift::register_game_server`].
+    pub fn register_game_server(
+        &self,
+        input: RegisterGameServerInput,
+    ) -> Result<RegisterGameServerOutput, RusotoError<RegisterGameServerError>> {
+        self.runtime
+            .block_on(self.client.register_game_server(input))
+    }
+
+    /// Blocking version of [`GameLift::request_upload_credentials`].
+    pub fn request_upload_credentials(
+        &self,
+        input: RequestUploadCredentialsInput,
+    ) -> Result<RequestUploadCredentialsOutput, RusotoError<RequestUploadCredentialsError>> {
+        self.runtime
+            .block_on(self.client.request_upload_credentials(input))
+    }
+
+    /// Blocking version of [`GameLift::resolve_alias`].
+    pub fn resolve_alias(
+        &self,
+        input: ResolveAliasInput,
+    ) -> Result<ResolveAliasOutput, RusotoError<ResolveAliasError>> {
+        self.runtime.block_on(self.client.resolve_alias(input))
+    }
+
+    /// Blocking version of [`GameLift::resume_game_server_group`].
+    pub fn resume_game_server_group(
+        &self,
+        input: ResumeGameServerGroupInput,
+    ) -> Result<ResumeGameServerGroupOutput, RusotoError<ResumeGameServerGroupError>> {
+        self.runtime
+            .block_on(self.client.resume_game_server_group(input))
+    }
+
+    /// Blocking version of [`GameLift::search_game_sessions`].
+    pub fn search_game_sessions(
+        &self,
+        input: SearchGameSessionsInput,
+    ) -> Result<SearchGameSessionsOutput, RusotoError<SearchGameSessionsError>> {
+        self.runtime
+            .block_on(self.client.search_game_sessions(input))
+    }
+
+    /// Blocking version of [`GameLift::start_fleet_actions`].
+    pub fn start_fleet_actions(
+        &self,
+        input: StartFleetActionsInput,
+    ) -> Result<StartFleetActionsOutput, RusotoError<StartFleetActionsError>> {
+        self.runtime
+            .block_on(self.client.start_fleet_actions(input))
+    }
+
+    /// Blocking version of [`GameLift::start_game_session_placement`].
+    pub fn start_game_session_placement(
+        &self,
+        input: StartGameSessionPlacementInput,
+    ) -> Result<StartGameSessionPlacementOutput, RusotoError<StartGameSessionPlacementError>> {
+        self.runtime
+            .block_on(self.client.start_game_session_placement(input))
+    }
+
+    /// Blocking version of [`GameLift::start_match_backfill`].
+    pub fn start_match_backfill(
+        &self,
+        input: StartMatchBackfillInput,
+    ) -> Result<StartMatchBackfillOutput, RusotoError<StartMatchBackfillError>> {
+        self.runtime
+            .block_on(self.client.start_match_backfill(input))
+    }
+
+    /// Blocking version of [`GameLift::start_matchmaking`].
+    pub fn start_matchmaking(
+        &self,
+        input: StartMatchmakingInput,
+    ) -> Result<StartMatchmakingOutput, RusotoError<StartMatchmakingError>> {
+        self.runtime.block_on(self.client.start_matchmaking(input))
+    }
+
+    /// Blocking version of [`GameLift::stop_fleet_actions`].
+    pub fn stop_fleet_actions(
+        &self,
+        input: StopFleetActionsInput,
+    ) -> Result<StopFleetActionsOutput, RusotoError<StopFleetActionsError>> {
+        self.runtime.block_on(self.client.stop_fleet_actions(input))
+    }
+
+    /// Blocking version of [`GameLift::stop_game_session_placement`].
+    pub fn stop_game_session_placement(
+        &self,
+        input: StopGameSessionPlacementInput,
+    ) -> Result<StopGameSessionPlacementOutput, RusotoError<StopGameSessionPlacementError>> {
+        self.runtime
+            .block_on(self.client.stop_game_session_placement(input))
+    }
+
+    /// Blocking version of [`GameLift::stop_matchmaking`].
+    pub fn stop_matchmaking(
+        &self,
+        input: StopMatchmakingInput,
+    ) -> Result<StopMatchmakingOutput, RusotoError<StopMatchmakingError>> {
+        self.runtime.block_on(self.client.stop_matchmaking(input))
+    }
+
+    /// Blocking version of [`GameLift::suspend_game_server_group`].
+    pub fn suspend_game_server_group(
+        &self,
+        input: SuspendGameServerGroupInput,
+    ) -> Result<SuspendGameServerGroupOutput, RusotoError<SuspendGameServerGroupError>> {
+        self.runtime
+            .block_on(self.client.suspend_game_server_group(input))
+    }
+
+    /// Blocking version of [`GameLift::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`GameLift::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`GameLift::update_alias`].
+    pub fn update_alias(
+        &self,
+        input: UpdateAliasInput,
+    ) -> Result<UpdateAliasOutput, RusotoError<UpdateAliasError>> {
+        self.runtime.block_on(self.client.update_alias(input))
+    }
+
+    /// Blocking version of [`GameLift::update_build`].
+    pub fn update_build(
+        &self,
+        input: UpdateBuildInput,
+    ) -> Result<UpdateBuildOutput, RusotoError<UpdateBuildError>> {
+        self.runtime.block_on(self.client.update_build(input))
+    }
+
+    /// Blocking version of [`GameLift::update_fleet_attributes`].
+    pub fn update_fleet_attributes(
+        &self,
+        input: UpdateFleetAttributesInput,
+    ) -> Result<UpdateFleetAttributesOutput, RusotoError<UpdateFleetAttributesError>> {
+        self.runtime
+            .block_on(self.client.update_fleet_attributes(input))
+    }
+
+    /// Blocking version of [`GameLift::update_fleet_capacity`].
+    pub fn update_fleet_capacity(
+        &self,
+        input: UpdateFleetCapacityInput,
+    ) -> Result<UpdateFleetCapacityOutput, RusotoError<UpdateFleetCapacityError>> {
+        self.runtime
+            .block_on(self.client.update_fleet_capacity(input))
+    }
+
+    /// Blocking version of [`GameLift::update_fleet_port_settings`].
+    pub fn update_fleet_port_settings(
+        &self,
+        input: UpdateFleetPortSettingsInput,
+    ) -> Result<UpdateFleetPortSettingsOutput, RusotoError<UpdateFleetPortSettingsError>> {
+        self.runtime
+            .block_on(self.client.update_fleet_port_settings(input))
+    }
+
+    /// Blocking version of [`GameLift::update_game_server`].
+    pub fn update_game_server(
+        &self,
+        input: UpdateGameServerInput,
+    ) -> Result<UpdateGameServerOutput, RusotoError<UpdateGameServerError>> {
+        self.runtime.block_on(self.client.update_game_server(input))
+    }
+
+    /// Blocking version of [`GameLift::update_game_server_group`].
+    pub fn update_game_server_group(
+        &self,
+        input: UpdateGameServerGroupInput,
+    ) -> Result<UpdateGameServerGroupOutput, RusotoError<UpdateGameServerGroupError>> {
+        self.runtime
+            .block_on(self.client.update_game_server_group(input))
+    }
+
+    /// Blocking version of [`GameLift::update_game_session`].
+    pub fn update_game_session(
+        &self,
+        input: UpdateGameSessionInput,
+    ) -> Result<UpdateGameSessionOutput, RusotoError<UpdateGameSessionError>> {
+        self.runtime
+            .block_on(self.client.update_game_session(input))
+    }
+
+    /// Blocking version of [`GameLift::update_game_session_queue`].
+    pub fn update_game_session_queue(
+        &self,
+        input: UpdateGameSessionQueueInput,
+    ) -> Result<UpdateGameSessionQueueOutput, RusotoError<UpdateGameSessionQueueError>> {
+        self.runtime
+            .block_on(self.client.update_game_session_queue(input))
+    }
+
+    /// Blocking version of [`GameLift::update_matchmaking_configuration`].
+    pub fn update_matchmaking_configuration(
+        &self,
+        input: UpdateMatchmakingConfigurationInput,
+    ) -> Result<
+        UpdateMatchmakingConfigurationOutput,
+        RusotoError<UpdateMatchmakingConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_matchmaking_configuration(input))
+    }
+
+    /// Blocking version of [`GameLift::update_runtime_configuration`].
+    pub fn update_runtime_configuration(
+        &self,
+        input: UpdateRuntimeConfigurationInput,
+    ) -> Result<UpdateRuntimeConfigurationOutput, RusotoError<UpdateRuntimeConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.update_runtime_configuration(input))
+    }
+
+    /// Blocking version of [`GameLift::update_script`].
+    pub fn update_script(
+        &self,
+        input: UpdateScriptInput,
+    ) -> Result<UpdateScriptOutput, RusotoError<UpdateScriptError>> {
+        self.runtime.block_on(self.client.update_script(input))
+    }
+
+    /// Blocking version of [`GameLift::validate_matchmaking_rule_set`].
+    pub fn validate_matchmaking_rule_set(
+        &self,
+        input: ValidateMatchmakingRuleSetInput,
+    ) -> Result<ValidateMatchmakingRuleSetOutput, RusotoError<ValidateMatchmakingRuleSetError>>
+    {
+        self.runtime
+            .block_on(self.client.validate_matchmaking_rule_set(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1342,26 +1343,38 @@ impl AbortMultipartUploadError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(AbortMultipartUploadError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AbortMultipartUploadError::InvalidParameterValue,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(AbortMultipartUploadError::MissingParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AbortMultipartUploadError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AbortMultipartUploadError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AbortMultipartUploadError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(AbortMultipartUploadError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AbortMultipartUploadError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1398,22 +1411,38 @@ impl AbortVaultLockError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(AbortVaultLockError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AbortVaultLockError::InvalidParameterValue,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(AbortVaultLockError::MissingParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AbortVaultLockError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AbortVaultLockError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AbortVaultLockError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(AbortVaultLockError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AbortVaultLockError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1452,25 +1481,46 @@ impl AddTagsToVaultError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(AddTagsToVaultError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddTagsToVaultError::InvalidParameterValue,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AddTagsToVaultError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsToVaultError::LimitExceeded,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(AddTagsToVaultError::MissingParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddTagsToVaultError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddTagsToVaultError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsToVaultError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(AddTagsToVaultError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsToVaultError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1508,26 +1558,38 @@ impl CompleteMultipartUploadError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        CompleteMultipartUploadError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CompleteMultipartUploadError::InvalidParameterValue,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
-                        CompleteMultipartUploadError::MissingParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CompleteMultipartUploadError::MissingParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CompleteMultipartUploadError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CompleteMultipartUploadError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CompleteMultipartUploadError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CompleteMultipartUploadError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1568,24 +1630,38 @@ impl CompleteVaultLockError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CompleteVaultLockError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CompleteVaultLockError::InvalidParameterValue,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(CompleteVaultLockError::MissingParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CompleteVaultLockError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CompleteVaultLockError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CompleteVaultLockError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CompleteVaultLockError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CompleteVaultLockError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1622,18 +1698,38 @@ impl CreateVaultError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateVaultError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVaultError::InvalidParameterValue,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateVaultError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVaultError::LimitExceeded,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(CreateVaultError::MissingParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVaultError::MissingParameterValue,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateVaultError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateVaultError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1670,18 +1766,38 @@ impl DeleteArchiveError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteArchiveError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteArchiveError::InvalidParameterValue,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(DeleteArchiveError::MissingParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteArchiveError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteArchiveError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteArchiveError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteArchiveError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteArchiveError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1718,18 +1834,38 @@ impl DeleteVaultError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteVaultError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVaultError::InvalidParameterValue,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(DeleteVaultError::MissingParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVaultError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteVaultError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVaultError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteVaultError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVaultError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1766,26 +1902,38 @@ impl DeleteVaultAccessPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DeleteVaultAccessPolicyError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVaultAccessPolicyError::InvalidParameterValue,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
-                        DeleteVaultAccessPolicyError::MissingParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVaultAccessPolicyError::MissingParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteVaultAccessPolicyError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteVaultAccessPolicyError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteVaultAccessPolicyError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteVaultAccessPolicyError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1826,26 +1974,38 @@ impl DeleteVaultNotificationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DeleteVaultNotificationsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVaultNotificationsError::InvalidParameterValue,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
-                        DeleteVaultNotificationsError::MissingParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteVaultNotificationsError::MissingParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteVaultNotificationsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteVaultNotificationsError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteVaultNotificationsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteVaultNotificationsError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1886,18 +2046,38 @@ impl DescribeJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeJobError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeJobError::InvalidParameterValue,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(DescribeJobError::MissingParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeJobError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeJobError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeJobError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeJobError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1934,18 +2114,38 @@ impl DescribeVaultError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeVaultError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeVaultError::InvalidParameterValue,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(DescribeVaultError::MissingParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeVaultError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeVaultError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeVaultError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeVaultError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeVaultError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1980,21 +2180,30 @@ impl GetDataRetrievalPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        GetDataRetrievalPolicyError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDataRetrievalPolicyError::InvalidParameterValue,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
-                        GetDataRetrievalPolicyError::MissingParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDataRetrievalPolicyError::MissingParameterValue,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetDataRetrievalPolicyError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDataRetrievalPolicyError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2030,18 +2239,38 @@ impl GetJobOutputError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetJobOutputError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobOutputError::InvalidParameterValue,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(GetJobOutputError::MissingParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobOutputError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetJobOutputError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobOutputError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetJobOutputError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobOutputError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2078,26 +2307,38 @@ impl GetVaultAccessPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetVaultAccessPolicyError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetVaultAccessPolicyError::InvalidParameterValue,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(GetVaultAccessPolicyError::MissingParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetVaultAccessPolicyError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetVaultAccessPolicyError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetVaultAccessPolicyError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetVaultAccessPolicyError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetVaultAccessPolicyError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2134,18 +2375,38 @@ impl GetVaultLockError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetVaultLockError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVaultLockError::InvalidParameterValue,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(GetVaultLockError::MissingParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVaultLockError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetVaultLockError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVaultLockError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetVaultLockError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetVaultLockError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2182,26 +2443,38 @@ impl GetVaultNotificationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetVaultNotificationsError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetVaultNotificationsError::InvalidParameterValue,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(GetVaultNotificationsError::MissingParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetVaultNotificationsError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetVaultNotificationsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetVaultNotificationsError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetVaultNotificationsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetVaultNotificationsError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2242,24 +2515,54 @@ impl InitiateJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InsufficientCapacityException" => {
-                    return RusotoError::Service(InitiateJobError::InsufficientCapacity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InitiateJobError::InsufficientCapacity,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(InitiateJobError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InitiateJobError::InvalidParameterValue,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(InitiateJobError::MissingParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InitiateJobError::MissingParameterValue,
+                    )
                 }
                 "PolicyEnforcedException" => {
-                    return RusotoError::Service(InitiateJobError::PolicyEnforced(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InitiateJobError::PolicyEnforced,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(InitiateJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InitiateJobError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(InitiateJobError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InitiateJobError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2298,26 +2601,38 @@ impl InitiateMultipartUploadError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        InitiateMultipartUploadError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InitiateMultipartUploadError::InvalidParameterValue,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
-                        InitiateMultipartUploadError::MissingParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InitiateMultipartUploadError::MissingParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(InitiateMultipartUploadError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        InitiateMultipartUploadError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(InitiateMultipartUploadError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        InitiateMultipartUploadError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2358,24 +2673,38 @@ impl InitiateVaultLockError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(InitiateVaultLockError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        InitiateVaultLockError::InvalidParameterValue,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(InitiateVaultLockError::MissingParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        InitiateVaultLockError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(InitiateVaultLockError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InitiateVaultLockError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(InitiateVaultLockError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        InitiateVaultLockError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2412,18 +2741,38 @@ impl ListJobsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListJobsError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListJobsError::InvalidParameterValue,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(ListJobsError::MissingParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListJobsError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListJobsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListJobsError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListJobsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListJobsError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2460,26 +2809,38 @@ impl ListMultipartUploadsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListMultipartUploadsError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListMultipartUploadsError::InvalidParameterValue,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(ListMultipartUploadsError::MissingParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListMultipartUploadsError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListMultipartUploadsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListMultipartUploadsError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListMultipartUploadsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListMultipartUploadsError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2516,18 +2877,38 @@ impl ListPartsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListPartsError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPartsError::InvalidParameterValue,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(ListPartsError::MissingParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPartsError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListPartsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPartsError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListPartsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPartsError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2562,21 +2943,30 @@ impl ListProvisionedCapacityError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        ListProvisionedCapacityError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProvisionedCapacityError::InvalidParameterValue,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
-                        ListProvisionedCapacityError::MissingParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProvisionedCapacityError::MissingParameterValue,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListProvisionedCapacityError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListProvisionedCapacityError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2616,22 +3006,38 @@ impl ListTagsForVaultError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListTagsForVaultError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForVaultError::InvalidParameterValue,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(ListTagsForVaultError::MissingParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForVaultError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForVaultError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForVaultError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListTagsForVaultError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForVaultError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2668,18 +3074,38 @@ impl ListVaultsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListVaultsError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVaultsError::InvalidParameterValue,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(ListVaultsError::MissingParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVaultsError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListVaultsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVaultsError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListVaultsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVaultsError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2718,26 +3144,38 @@ impl PurchaseProvisionedCapacityError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        PurchaseProvisionedCapacityError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PurchaseProvisionedCapacityError::InvalidParameterValue,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PurchaseProvisionedCapacityError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PurchaseProvisionedCapacityError::LimitExceeded,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
-                        PurchaseProvisionedCapacityError::MissingParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PurchaseProvisionedCapacityError::MissingParameterValue,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        PurchaseProvisionedCapacityError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PurchaseProvisionedCapacityError::ServiceUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2780,26 +3218,38 @@ impl RemoveTagsFromVaultError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(RemoveTagsFromVaultError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveTagsFromVaultError::InvalidParameterValue,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(RemoveTagsFromVaultError::MissingParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveTagsFromVaultError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RemoveTagsFromVaultError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveTagsFromVaultError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(RemoveTagsFromVaultError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveTagsFromVaultError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2834,21 +3284,30 @@ impl SetDataRetrievalPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        SetDataRetrievalPolicyError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetDataRetrievalPolicyError::InvalidParameterValue,
                     )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(
-                        SetDataRetrievalPolicyError::MissingParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetDataRetrievalPolicyError::MissingParameterValue,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(SetDataRetrievalPolicyError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetDataRetrievalPolicyError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2884,26 +3343,38 @@ impl SetVaultAccessPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(SetVaultAccessPolicyError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetVaultAccessPolicyError::InvalidParameterValue,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(SetVaultAccessPolicyError::MissingParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetVaultAccessPolicyError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SetVaultAccessPolicyError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetVaultAccessPolicyError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(SetVaultAccessPolicyError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetVaultAccessPolicyError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2940,26 +3411,38 @@ impl SetVaultNotificationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(SetVaultNotificationsError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetVaultNotificationsError::InvalidParameterValue,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(SetVaultNotificationsError::MissingParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetVaultNotificationsError::MissingParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SetVaultNotificationsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetVaultNotificationsError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(SetVaultNotificationsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetVaultNotificationsError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2998,21 +3481,46 @@ impl UploadArchiveError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UploadArchiveError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UploadArchiveError::InvalidParameterValue,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(UploadArchiveError::MissingParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UploadArchiveError::MissingParameterValue,
+                    )
                 }
                 "RequestTimeoutException" => {
-                    return RusotoError::Service(UploadArchiveError::RequestTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UploadArchiveError::RequestTimeout,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UploadArchiveError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UploadArchiveError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UploadArchiveError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UploadArchiveError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3052,29 +3560,46 @@ impl UploadMultipartPartError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UploadMultipartPartError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UploadMultipartPartError::InvalidParameterValue,
+                    )
                 }
                 "MissingParameterValueException" => {
-                    return RusotoError::Service(UploadMultipartPartError::MissingParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UploadMultipartPartError::MissingParameterValue,
+                    )
                 }
                 "RequestTimeoutException" => {
-                    return RusotoError::Service(UploadMultipartPartError::RequestTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UploadMultipartPartError::RequestTimeout,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UploadMultipartPartError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UploadMultipartPartError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UploadMultipartPartError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UploadMultipartPartError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3305,11 +3830,12 @@ pub struct GlacierClient {
 impl GlacierClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> GlacierClient {
         GlacierClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Glacier"),
         }
     }
 
@@ -3324,12 +3850,32 @@ impl GlacierClient {
     {
         GlacierClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Glacier"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> GlacierClient {
-        GlacierClient { client, region }
+        GlacierClient {
+            client,
+            region: region_for_service(region, "Glacier"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> GlacierClient {
+        GlacierClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Glacier"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> GlacierClient {
+        GlacierClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -3349,6 +3895,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
+        request.set_operation("AbortMultipartUpload");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3381,6 +3928,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
+        request.set_operation("AbortVaultLock");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3413,6 +3961,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("AddTagsToVault");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3453,6 +4002,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("CompleteMultipartUpload");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3493,6 +4043,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("CompleteVaultLock");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3525,6 +4076,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("PUT", "glacier", &self.region, &request_uri);
+        request.set_operation("CreateVault");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3560,6 +4112,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
+        request.set_operation("DeleteArchive");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3592,6 +4145,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
+        request.set_operation("DeleteVault");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3624,6 +4178,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
+        request.set_operation("DeleteVaultAccessPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3656,6 +4211,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "glacier", &self.region, &request_uri);
+        request.set_operation("DeleteVaultNotifications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3689,6 +4245,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("DescribeJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3722,6 +4279,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("DescribeVault");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3754,6 +4312,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("GetDataRetrievalPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3788,6 +4347,8 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("GetJobOutput");
+        request.set_raw_response(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3830,6 +4391,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("GetVaultAccessPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3863,6 +4425,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("GetVaultLock");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3896,6 +4459,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("GetVaultNotifications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3929,6 +4493,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("InitiateJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -3968,6 +4533,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("InitiateMultipartUpload");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4009,6 +4575,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("InitiateVaultLock");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4046,6 +4613,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("ListJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4094,6 +4662,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("ListMultipartUploads");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4137,6 +4706,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("ListParts");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4178,6 +4748,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("ListProvisionedCapacity");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4211,6 +4782,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("ListTagsForVault");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4240,6 +4812,7 @@ impl Glacier for GlacierClient {
         let request_uri = format!("/{account_id}/vaults", account_id = input.account_id);
 
         let mut request = SignedRequest::new("GET", "glacier", &self.region, &request_uri);
+        request.set_operation("ListVaults");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4282,6 +4855,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("PurchaseProvisionedCapacity");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4316,6 +4890,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("RemoveTagsFromVault");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4354,6 +4929,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("PUT", "glacier", &self.region, &request_uri);
+        request.set_operation("SetDataRetrievalPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4389,6 +4965,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("PUT", "glacier", &self.region, &request_uri);
+        request.set_operation("SetVaultAccessPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4424,6 +5001,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("PUT", "glacier", &self.region, &request_uri);
+        request.set_operation("SetVaultNotifications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4459,6 +5037,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("POST", "glacier", &self.region, &request_uri);
+        request.set_operation("UploadArchive");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4508,6 +5087,7 @@ impl Glacier for GlacierClient {
         );
 
         let mut request = SignedRequest::new("PUT", "glacier", &self.region, &request_uri);
+        request.set_operation("UploadMultipartPart");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
         request.add_header("x-amz-glacier-version", "2012-06-01");
 
@@ -4538,3 +5118,362 @@ impl Glacier for GlacierClient {
         }
     }
 }
+
+/// A blocking client for the Amazon Glacier API, running the calls of a `GlacierClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `GlacierClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct GlacierBlockingClient {
+    client: GlacierClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl GlacierBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> GlacierBlockingClient {
+        GlacierBlockingClient::from_client(GlacierClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> GlacierBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        GlacierBlockingClient::from_client(GlacierClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> GlacierBlockingClient {
+        GlacierBlockingClient::from_client(GlacierClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> GlacierBlockingClient {
+        GlacierBlockingClient::from_client(GlacierClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: GlacierClient) -> GlacierBlockingClient {
+        GlacierBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> GlacierBlockingClient {
+        GlacierBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> GlacierBlockingClient {
+        GlacierBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Glacier::abort_multipart_upload`].
+    pub fn abort_multipart_upload(
+        &self,
+        input: AbortMultipartUploadInput,
+    ) -> Result<(), RusotoError<AbortMultipartUploadError>> {
+        self.runtime
+            .block_on(self.client.abort_multipart_upload(input))
+    }
+
+    /// Blocking version of [`Glacier::abort_vault_lock`].
+    pub fn abort_vault_lock(
+        &self,
+        input: AbortVaultLockInput,
+    ) -> Result<(), RusotoError<AbortVaultLockError>> {
+        self.runtime.block_on(self.client.abort_vault_lock(input))
+    }
+
+    /// Blocking version of [`Glacier::add_tags_to_vault`].
+    pub fn add_tags_to_vault(
+        &self,
+        input: AddTagsToVaultInput,
+    ) -> Result<(), RusotoError<AddTagsToVaultError>> {
+        self.runtime.block_on(self.client.add_tags_to_vault(input))
+    }
+
+    /// Blocking version of [`Glacier::complete_multipart_upload`].
+    pub fn complete_multipart_upload(
+        &self,
+        input: CompleteMultipartUploadInput,
+    ) -> Result<ArchiveCreationOutput, RusotoError<CompleteMultipartUploadError>> {
+        self.runtime
+            .block_on(self.client.complete_multipart_upload(input))
+    }
+
+    /// Blocking version of [`Glacier::complete_vault_lock`].
+    pub fn complete_vault_lock(
+        &self,
+        input: CompleteVaultLockInput,
+    ) -> Result<(), RusotoError<CompleteVaultLockError>> {
+        self.runtime
+            .block_on(self.client.complete_vault_lock(input))
+    }
+
+    /// Blocking version of [`Glacier::create_vault`].
+    pub fn create_vault(
+        &self,
+        input: CreateVaultInput,
+    ) -> Result<CreateVaultOutput, RusotoError<CreateVaultError>> {
+        self.runtime.block_on(self.client.create_vault(input))
+    }
+
+    /// Blocking version of [`Glacier::delete_archive`].
+    pub fn delete_archive(
+        &self,
+        input: DeleteArchiveInput,
+    ) -> Result<(), RusotoError<DeleteArchiveError>> {
+        self.runtime.block_on(self.client.delete_archive(input))
+    }
+
+    /// Blocking version of [`Glacier::delete_vault`].
+    pub fn delete_vault(
+        &self,
+        input: DeleteVaultInput,
+    ) -> Result<(), RusotoError<DeleteVaultError>> {
+        self.runtime.block_on(self.client.delete_vault(input))
+    }
+
+    /// Blocking version of [`Glacier::delete_vault_access_policy`].
+    pub fn delete_vault_access_policy(
+        &self,
+        input: DeleteVaultAccessPolicyInput,
+    ) -> Result<(), RusotoError<DeleteVaultAccessPolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_vault_access_policy(input))
+    }
+
+    /// Blocking version of [`Glacier::delete_vault_notifications`].
+    pub fn delete_vault_notifications(
+        &self,
+        input: DeleteVaultNotificationsInput,
+    ) -> Result<(), RusotoError<DeleteVaultNotificationsError>> {
+        self.runtime
+            .block_on(self.client.delete_vault_notifications(input))
+    }
+
+    /// Blocking version of [`Glacier::describe_job`].
+    pub fn describe_job(
+        &self,
+        input: DescribeJobInput,
+    ) -> Result<GlacierJobDescription, RusotoError<DescribeJobError>> {
+        self.runtime.block_on(self.client.describe_job(input))
+    }
+
+    /// Blocking version of [`Glacier::describe_vault`].
+    pub fn describe_vault(
+        &self,
+        input: DescribeVaultInput,
+    ) -> Result<DescribeVaultOutput, RusotoError<DescribeVaultError>> {
+        self.runtime.block_on(self.client.describe_vault(input))
+    }
+
+    /// Blocking version of [`Glacier::get_data_retrieval_policy`].
+    pub fn get_data_retrieval_policy(
+        &self,
+        input: GetDataRetrievalPolicyInput,
+    ) -> Result<GetDataRetrievalPolicyOutput, RusotoError<GetDataRetrievalPolicyError>> {
+        self.runtime
+            .block_on(self.client.get_data_retrieval_policy(input))
+    }
+
+    /// Blocking version of [`Glacier::get_job_output`].
+    pub fn get_job_output(
+        &self,
+        input: GetJobOutputInput,
+    ) -> Result<GetJobOutputOutput, RusotoError<GetJobOutputError>> {
+        self.runtime.block_on(self.client.get_job_output(input))
+    }
+
+    /// Blocking version of [`Glacier::get_vault_access_policy`].
+    pub fn get_vault_access_policy(
+        &self,
+        input: GetVaultAccessPolicyInput,
+    ) -> Result<GetVaultAccessPolicyOutput, RusotoError<GetVaultAccessPolicyError>> {
+        self.runtime
+            .block_on(self.client.get_vault_access_policy(input))
+    }
+
+    /// Blocking version of [`Glacier::get_vault_lock`].
+    pub fn get_vault_lock(
+        &self,
+        input: GetVaultLockInput,
+    ) -> Result<GetVaultLockOutput, RusotoError<GetVaultLockError>> {
+        self.runtime.block_on(self.client.get_vault_lock(input))
+    }
+
+    /// Blocking version of [`Glacier::get_vault_notifications`].
+    pub fn get_vault_notifications(
+        &self,
+        input: GetVaultNotificationsInput,
+    ) -> Result<GetVaultNotificationsOutput, RusotoError<GetVaultNotificationsError>> {
+        self.runtime
+            .block_on(self.client.get_vault_notifications(input))
+    }
+
+    /// Blocking version of [`Glacier::initiate_job`].
+    pub fn initiate_job(
+        &self,
+        input: InitiateJobInput,
+    ) -> Result<InitiateJobOutput, RusotoError<InitiateJobError>> {
+        self.runtime.block_on(self.client.initiate_job(input))
+    }
+
+    /// Blocking version of [`Glacier::initiate_multipart_upload`].
+    pub fn initiate_multipart_upload(
+        &self,
+        input: InitiateMultipartUploadInput,
+    ) -> Result<InitiateMultipartUploadOutput, RusotoError<InitiateMultipartUploadError>> {
+        self.runtime
+            .block_on(self.client.initiate_multipart_upload(input))
+    }
+
+    /// Blocking version of [`Glacier::initiate_vault_lock`].
+    pub fn initiate_vault_lock(
+        &self,
+        input: InitiateVaultLockInput,
+    ) -> Result<InitiateVaultLockOutput, RusotoError<InitiateVaultLockError>> {
+        self.runtime
+            .block_on(self.client.initiate_vault_lock(input))
+    }
+
+    /// Blocking version of [`Glacier::list_jobs`].
+    pub fn list_jobs(
+        &self,
+        input: ListJobsInput,
+    ) -> Result<ListJobsOutput, RusotoError<ListJobsError>> {
+        self.runtime.block_on(self.client.list_jobs(input))
+    }
+
+    /// Blocking version of [`Glacier::list_multipart_uploads`].
+    pub fn list_multipart_uploads(
+        &self,
+        input: ListMultipartUploadsInput,
+    ) -> Result<ListMultipartUploadsOutput, RusotoError<ListMultipartUploadsError>> {
+        self.runtime
+            .block_on(self.client.list_multipart_uploads(input))
+    }
+
+    /// Blocking version of [`Glacier::list_parts`].
+    pub fn list_parts(
+        &self,
+        input: ListPartsInput,
+    ) -> Result<ListPartsOutput, RusotoError<ListPartsError>> {
+        self.runtime.block_on(self.client.list_parts(input))
+    }
+
+    /// Blocking version of [`Glacier::list_provisioned_capacity`].
+    pub fn list_provisioned_capacity(
+        &self,
+        input: ListProvisionedCapacityInput,
+    ) -> Result<ListProvisionedCapacityOutput, RusotoError<ListProvisionedCapacityError>> {
+        self.runtime
+            .block_on(self.client.list_provisioned_capacity(input))
+    }
+
+    /// Blocking version of [`Glacier::list_tags_for_vault`].
+    pub fn list_tags_for_vault(
+        &self,
+        input: ListTagsForVaultInput,
+    ) -> Result<ListTagsForVaultOutput, RusotoError<ListTagsForVaultError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_vault(input))
+    }
+
+    /// Blocking version of [`Glacier::list_vaults`].
+    pub fn list_vaults(
+        &self,
+        input: ListVaultsInput,
+    ) -> Result<ListVaultsOutput, RusotoError<ListVaultsError>> {
+        self.runtime.block_on(self.client.list_vaults(input))
+    }
+
+    /// Blocking version of [`Glacier::purchase_provisioned_capacity`].
+    pub fn purchase_provisioned_capacity(
+        &self,
+        input: PurchaseProvisionedCapacityInput,
+    ) -> Result<PurchaseProvisionedCapacityOutput, RusotoError<PurchaseProvisionedCapacityError>>
+    {
+        self.runtime
+            .block_on(self.client.purchase_provisioned_capacity(input))
+    }
+
+    /// Blocking version of [`Glacier::remove_tags_from_vault`].
+    pub fn remove_tags_from_vault(
+        &self,
+        input: RemoveTagsFromVaultInput,
+    ) -> Result<(), RusotoError<RemoveTagsFromVaultError>> {
+        self.runtime
+            .block_on(self.client.remove_tags_from_vault(input))
+    }
+
+    /// Blocking version of [`Glacier::set_data_retrieval_policy`].
+    pub fn set_data_retrieval_policy(
+        &self,
+        input: SetDataRetrievalPolicyInput,
+    ) -> Result<(), RusotoError<SetDataRetrievalPolicyError>> {
+        self.runtime
+            .block_on(self.client.set_data_retrieval_policy(input))
+    }
+
+    /// Blocking version of [`Glacier::set_vault_access_policy`].
+    pub fn set_vault_access_policy(
+        &self,
+        input: SetVaultAccessPolicyInput,
+    ) -> Result<(), RusotoError<SetVaultAccessPolicyError>> {
+        self.runtime
+            .block_on(self.client.set_vault_access_policy(input))
+    }
+
+    /// Blocking version of [`Glacier::set_vault_notifications`].
+    pub fn set_vault_notifications(
+        &self,
+        input: SetVaultNotificationsInput,
+    ) -> Result<(), RusotoError<SetVaultNotificationsError>> {
+        self.runtime
+            .block_on(self.client.set_vault_notifications(input))
+    }
+
+    /// Blocking version of [`Glacier::upload_archive`].
+    pub fn upload_archive(
+        &self,
+        input: UploadArchiveInput,
+    ) -> Result<ArchiveCreationOutput, RusotoError<UploadArchiveError>> {
+        self.runtime.block_on(self.client.upload_archive(input))
+    }
+
+    /// Blocking version of [`Glacier::upload_multipart_part`].
+    pub fn upload_multipart_part(
+        &self,
+        input: UploadMultipartPartInput,
+    ) -> Result<UploadMultipartPartOutput, RusotoError<UploadMultipartPartError>> {
+        self.runtime
+            .block_on(self.client.upload_multipart_part(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1631,39 +1632,62 @@ impl AddCustomRoutingEndpointsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AddCustomRoutingEndpointsError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddCustomRoutingEndpointsError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(AddCustomRoutingEndpointsError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddCustomRoutingEndpointsError::Conflict,
+                    )
                 }
                 "EndpointAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        AddCustomRoutingEndpointsError::EndpointAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddCustomRoutingEndpointsError::EndpointAlreadyExists,
                     )
                 }
                 "EndpointGroupNotFoundException" => {
-                    return RusotoError::Service(
-                        AddCustomRoutingEndpointsError::EndpointGroupNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddCustomRoutingEndpointsError::EndpointGroupNotFound,
                     )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        AddCustomRoutingEndpointsError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddCustomRoutingEndpointsError::InternalServiceError,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(AddCustomRoutingEndpointsError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddCustomRoutingEndpointsError::InvalidArgument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AddCustomRoutingEndpointsError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddCustomRoutingEndpointsError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1711,27 +1735,46 @@ impl AdvertiseByoipCidrError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AdvertiseByoipCidrError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdvertiseByoipCidrError::AccessDenied,
+                    )
                 }
                 "ByoipCidrNotFoundException" => {
-                    return RusotoError::Service(AdvertiseByoipCidrError::ByoipCidrNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdvertiseByoipCidrError::ByoipCidrNotFound,
+                    )
                 }
                 "IncorrectCidrStateException" => {
-                    return RusotoError::Service(AdvertiseByoipCidrError::IncorrectCidrState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdvertiseByoipCidrError::IncorrectCidrState,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(AdvertiseByoipCidrError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AdvertiseByoipCidrError::InternalServiceError,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(AdvertiseByoipCidrError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AdvertiseByoipCidrError::InvalidArgument,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1765,16 +1808,22 @@ impl AllowCustomRoutingTrafficError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        AllowCustomRoutingTrafficError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AllowCustomRoutingTrafficError::InternalServiceError,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(AllowCustomRoutingTrafficError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AllowCustomRoutingTrafficError::InvalidArgument,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1809,17 +1858,30 @@ impl CreateAcceleratorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(CreateAcceleratorError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAcceleratorError::InternalServiceError,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(CreateAcceleratorError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAcceleratorError::InvalidArgument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateAcceleratorError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAcceleratorError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1857,26 +1919,38 @@ impl CreateCustomRoutingAcceleratorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateCustomRoutingAcceleratorError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCustomRoutingAcceleratorError::AccessDenied,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        CreateCustomRoutingAcceleratorError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCustomRoutingAcceleratorError::InternalServiceError,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
-                        CreateCustomRoutingAcceleratorError::InvalidArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCustomRoutingAcceleratorError::InvalidArgument,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
-                        CreateCustomRoutingAcceleratorError::LimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCustomRoutingAcceleratorError::LimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1927,46 +2001,70 @@ impl CreateCustomRoutingEndpointGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AcceleratorNotFoundException" => {
-                    return RusotoError::Service(
-                        CreateCustomRoutingEndpointGroupError::AcceleratorNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCustomRoutingEndpointGroupError::AcceleratorNotFound,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        CreateCustomRoutingEndpointGroupError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCustomRoutingEndpointGroupError::AccessDenied,
                     )
                 }
                 "EndpointGroupAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        CreateCustomRoutingEndpointGroupError::EndpointGroupAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCustomRoutingEndpointGroupError::EndpointGroupAlreadyExists,
                     )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        CreateCustomRoutingEndpointGroupError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCustomRoutingEndpointGroupError::InternalServiceError,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
-                        CreateCustomRoutingEndpointGroupError::InvalidArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCustomRoutingEndpointGroupError::InvalidArgument,
                     )
                 }
                 "InvalidPortRangeException" => {
-                    return RusotoError::Service(
-                        CreateCustomRoutingEndpointGroupError::InvalidPortRange(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCustomRoutingEndpointGroupError::InvalidPortRange,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
-                        CreateCustomRoutingEndpointGroupError::LimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCustomRoutingEndpointGroupError::LimitExceeded,
                     )
                 }
                 "ListenerNotFoundException" => {
-                    return RusotoError::Service(
-                        CreateCustomRoutingEndpointGroupError::ListenerNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCustomRoutingEndpointGroupError::ListenerNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2027,31 +2125,46 @@ impl CreateCustomRoutingListenerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AcceleratorNotFoundException" => {
-                    return RusotoError::Service(
-                        CreateCustomRoutingListenerError::AcceleratorNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCustomRoutingListenerError::AcceleratorNotFound,
                     )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        CreateCustomRoutingListenerError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCustomRoutingListenerError::InternalServiceError,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(CreateCustomRoutingListenerError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCustomRoutingListenerError::InvalidArgument,
+                    )
                 }
                 "InvalidPortRangeException" => {
-                    return RusotoError::Service(
-                        CreateCustomRoutingListenerError::InvalidPortRange(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCustomRoutingListenerError::InvalidPortRange,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateCustomRoutingListenerError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCustomRoutingListenerError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2099,35 +2212,62 @@ impl CreateEndpointGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AcceleratorNotFoundException" => {
-                    return RusotoError::Service(CreateEndpointGroupError::AcceleratorNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateEndpointGroupError::AcceleratorNotFound,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateEndpointGroupError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEndpointGroupError::AccessDenied,
+                    )
                 }
                 "EndpointGroupAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        CreateEndpointGroupError::EndpointGroupAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEndpointGroupError::EndpointGroupAlreadyExists,
                     )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(CreateEndpointGroupError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateEndpointGroupError::InternalServiceError,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(CreateEndpointGroupError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEndpointGroupError::InvalidArgument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateEndpointGroupError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEndpointGroupError::LimitExceeded,
+                    )
                 }
                 "ListenerNotFoundException" => {
-                    return RusotoError::Service(CreateEndpointGroupError::ListenerNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateEndpointGroupError::ListenerNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2171,21 +2311,46 @@ impl CreateListenerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AcceleratorNotFoundException" => {
-                    return RusotoError::Service(CreateListenerError::AcceleratorNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateListenerError::AcceleratorNotFound,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(CreateListenerError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateListenerError::InternalServiceError,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(CreateListenerError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateListenerError::InvalidArgument,
+                    )
                 }
                 "InvalidPortRangeException" => {
-                    return RusotoError::Service(CreateListenerError::InvalidPortRange(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateListenerError::InvalidPortRange,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateListenerError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateListenerError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2225,29 +2390,46 @@ impl DeleteAcceleratorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AcceleratorNotDisabledException" => {
-                    return RusotoError::Service(DeleteAcceleratorError::AcceleratorNotDisabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteAcceleratorError::AcceleratorNotDisabled,
+                    )
                 }
                 "AcceleratorNotFoundException" => {
-                    return RusotoError::Service(DeleteAcceleratorError::AcceleratorNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteAcceleratorError::AcceleratorNotFound,
+                    )
                 }
                 "AssociatedListenerFoundException" => {
-                    return RusotoError::Service(DeleteAcceleratorError::AssociatedListenerFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteAcceleratorError::AssociatedListenerFound,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(DeleteAcceleratorError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteAcceleratorError::InternalServiceError,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DeleteAcceleratorError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAcceleratorError::InvalidArgument,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2289,31 +2471,46 @@ impl DeleteCustomRoutingAcceleratorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AcceleratorNotDisabledException" => {
-                    return RusotoError::Service(
-                        DeleteCustomRoutingAcceleratorError::AcceleratorNotDisabled(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCustomRoutingAcceleratorError::AcceleratorNotDisabled,
                     )
                 }
                 "AcceleratorNotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteCustomRoutingAcceleratorError::AcceleratorNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCustomRoutingAcceleratorError::AcceleratorNotFound,
                     )
                 }
                 "AssociatedListenerFoundException" => {
-                    return RusotoError::Service(
-                        DeleteCustomRoutingAcceleratorError::AssociatedListenerFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCustomRoutingAcceleratorError::AssociatedListenerFound,
                     )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        DeleteCustomRoutingAcceleratorError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCustomRoutingAcceleratorError::InternalServiceError,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
-                        DeleteCustomRoutingAcceleratorError::InvalidArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCustomRoutingAcceleratorError::InvalidArgument,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2361,21 +2558,30 @@ impl DeleteCustomRoutingEndpointGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EndpointGroupNotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteCustomRoutingEndpointGroupError::EndpointGroupNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCustomRoutingEndpointGroupError::EndpointGroupNotFound,
                     )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        DeleteCustomRoutingEndpointGroupError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCustomRoutingEndpointGroupError::InternalServiceError,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
-                        DeleteCustomRoutingEndpointGroupError::InvalidArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCustomRoutingEndpointGroupError::InvalidArgument,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2419,26 +2625,38 @@ impl DeleteCustomRoutingListenerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AssociatedEndpointGroupFoundException" => {
-                    return RusotoError::Service(
-                        DeleteCustomRoutingListenerError::AssociatedEndpointGroupFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCustomRoutingListenerError::AssociatedEndpointGroupFound,
                     )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        DeleteCustomRoutingListenerError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCustomRoutingListenerError::InternalServiceError,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DeleteCustomRoutingListenerError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteCustomRoutingListenerError::InvalidArgument,
+                    )
                 }
                 "ListenerNotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteCustomRoutingListenerError::ListenerNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCustomRoutingListenerError::ListenerNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2477,19 +2695,30 @@ impl DeleteEndpointGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EndpointGroupNotFoundException" => {
-                    return RusotoError::Service(DeleteEndpointGroupError::EndpointGroupNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteEndpointGroupError::EndpointGroupNotFound,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(DeleteEndpointGroupError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteEndpointGroupError::InternalServiceError,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DeleteEndpointGroupError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEndpointGroupError::InvalidArgument,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2525,20 +2754,38 @@ impl DeleteListenerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AssociatedEndpointGroupFoundException" => {
-                    return RusotoError::Service(DeleteListenerError::AssociatedEndpointGroupFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteListenerError::AssociatedEndpointGroupFound,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(DeleteListenerError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteListenerError::InternalServiceError,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DeleteListenerError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteListenerError::InvalidArgument,
+                    )
                 }
                 "ListenerNotFoundException" => {
-                    return RusotoError::Service(DeleteListenerError::ListenerNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteListenerError::ListenerNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2571,16 +2818,22 @@ impl DenyCustomRoutingTrafficError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        DenyCustomRoutingTrafficError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DenyCustomRoutingTrafficError::InternalServiceError,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DenyCustomRoutingTrafficError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DenyCustomRoutingTrafficError::InvalidArgument,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2619,29 +2872,46 @@ impl DeprovisionByoipCidrError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeprovisionByoipCidrError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeprovisionByoipCidrError::AccessDenied,
+                    )
                 }
                 "ByoipCidrNotFoundException" => {
-                    return RusotoError::Service(DeprovisionByoipCidrError::ByoipCidrNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeprovisionByoipCidrError::ByoipCidrNotFound,
+                    )
                 }
                 "IncorrectCidrStateException" => {
-                    return RusotoError::Service(DeprovisionByoipCidrError::IncorrectCidrState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeprovisionByoipCidrError::IncorrectCidrState,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(DeprovisionByoipCidrError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeprovisionByoipCidrError::InternalServiceError,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DeprovisionByoipCidrError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeprovisionByoipCidrError::InvalidArgument,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2677,19 +2947,30 @@ impl DescribeAcceleratorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AcceleratorNotFoundException" => {
-                    return RusotoError::Service(DescribeAcceleratorError::AcceleratorNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAcceleratorError::AcceleratorNotFound,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(DescribeAcceleratorError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAcceleratorError::InternalServiceError,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DescribeAcceleratorError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAcceleratorError::InvalidArgument,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2725,21 +3006,30 @@ impl DescribeAcceleratorAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AcceleratorNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeAcceleratorAttributesError::AcceleratorNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAcceleratorAttributesError::AcceleratorNotFound,
                     )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        DescribeAcceleratorAttributesError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAcceleratorAttributesError::InternalServiceError,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
-                        DescribeAcceleratorAttributesError::InvalidArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAcceleratorAttributesError::InvalidArgument,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2781,21 +3071,30 @@ impl DescribeCustomRoutingAcceleratorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AcceleratorNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeCustomRoutingAcceleratorError::AcceleratorNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCustomRoutingAcceleratorError::AcceleratorNotFound,
                     )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        DescribeCustomRoutingAcceleratorError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCustomRoutingAcceleratorError::InternalServiceError,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
-                        DescribeCustomRoutingAcceleratorError::InvalidArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCustomRoutingAcceleratorError::InvalidArgument,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2837,25 +3136,30 @@ impl DescribeCustomRoutingAcceleratorAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AcceleratorNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeCustomRoutingAcceleratorAttributesError::AcceleratorNotFound(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCustomRoutingAcceleratorAttributesError::AcceleratorNotFound,
                     )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        DescribeCustomRoutingAcceleratorAttributesError::InternalServiceError(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCustomRoutingAcceleratorAttributesError::InternalServiceError,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
-                        DescribeCustomRoutingAcceleratorAttributesError::InvalidArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCustomRoutingAcceleratorAttributesError::InvalidArgument,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2897,21 +3201,30 @@ impl DescribeCustomRoutingEndpointGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EndpointGroupNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeCustomRoutingEndpointGroupError::EndpointGroupNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCustomRoutingEndpointGroupError::EndpointGroupNotFound,
                     )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        DescribeCustomRoutingEndpointGroupError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCustomRoutingEndpointGroupError::InternalServiceError,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
-                        DescribeCustomRoutingEndpointGroupError::InvalidArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCustomRoutingEndpointGroupError::InvalidArgument,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2953,21 +3266,30 @@ impl DescribeCustomRoutingListenerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        DescribeCustomRoutingListenerError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCustomRoutingListenerError::InternalServiceError,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
-                        DescribeCustomRoutingListenerError::InvalidArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCustomRoutingListenerError::InvalidArgument,
                     )
                 }
                 "ListenerNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeCustomRoutingListenerError::ListenerNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCustomRoutingListenerError::ListenerNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3007,21 +3329,30 @@ impl DescribeEndpointGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EndpointGroupNotFoundException" => {
-                    return RusotoError::Service(DescribeEndpointGroupError::EndpointGroupNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEndpointGroupError::EndpointGroupNotFound,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(DescribeEndpointGroupError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEndpointGroupError::InternalServiceError,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DescribeEndpointGroupError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEndpointGroupError::InvalidArgument,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3055,17 +3386,30 @@ impl DescribeListenerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(DescribeListenerError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeListenerError::InternalServiceError,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DescribeListenerError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeListenerError::InvalidArgument,
+                    )
                 }
                 "ListenerNotFoundException" => {
-                    return RusotoError::Service(DescribeListenerError::ListenerNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeListenerError::ListenerNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3099,17 +3443,30 @@ impl ListAcceleratorsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListAcceleratorsError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAcceleratorsError::InternalServiceError,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListAcceleratorsError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAcceleratorsError::InvalidArgument,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListAcceleratorsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAcceleratorsError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3145,18 +3502,38 @@ impl ListByoipCidrsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListByoipCidrsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListByoipCidrsError::AccessDenied,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListByoipCidrsError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListByoipCidrsError::InternalServiceError,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListByoipCidrsError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListByoipCidrsError::InvalidArgument,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListByoipCidrsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListByoipCidrsError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3193,21 +3570,30 @@ impl ListCustomRoutingAcceleratorsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        ListCustomRoutingAcceleratorsError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCustomRoutingAcceleratorsError::InternalServiceError,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
-                        ListCustomRoutingAcceleratorsError::InvalidArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCustomRoutingAcceleratorsError::InvalidArgument,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        ListCustomRoutingAcceleratorsError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCustomRoutingAcceleratorsError::InvalidNextToken,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3251,26 +3637,38 @@ impl ListCustomRoutingEndpointGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        ListCustomRoutingEndpointGroupsError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCustomRoutingEndpointGroupsError::InternalServiceError,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
-                        ListCustomRoutingEndpointGroupsError::InvalidArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCustomRoutingEndpointGroupsError::InvalidArgument,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        ListCustomRoutingEndpointGroupsError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCustomRoutingEndpointGroupsError::InvalidNextToken,
                     )
                 }
                 "ListenerNotFoundException" => {
-                    return RusotoError::Service(
-                        ListCustomRoutingEndpointGroupsError::ListenerNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCustomRoutingEndpointGroupsError::ListenerNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3317,26 +3715,38 @@ impl ListCustomRoutingListenersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AcceleratorNotFoundException" => {
-                    return RusotoError::Service(
-                        ListCustomRoutingListenersError::AcceleratorNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCustomRoutingListenersError::AcceleratorNotFound,
                     )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        ListCustomRoutingListenersError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCustomRoutingListenersError::InternalServiceError,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListCustomRoutingListenersError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListCustomRoutingListenersError::InvalidArgument,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListCustomRoutingListenersError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListCustomRoutingListenersError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3381,31 +3791,46 @@ impl ListCustomRoutingPortMappingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AcceleratorNotFoundException" => {
-                    return RusotoError::Service(
-                        ListCustomRoutingPortMappingsError::AcceleratorNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCustomRoutingPortMappingsError::AcceleratorNotFound,
                     )
                 }
                 "EndpointGroupNotFoundException" => {
-                    return RusotoError::Service(
-                        ListCustomRoutingPortMappingsError::EndpointGroupNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCustomRoutingPortMappingsError::EndpointGroupNotFound,
                     )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        ListCustomRoutingPortMappingsError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCustomRoutingPortMappingsError::InternalServiceError,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
-                        ListCustomRoutingPortMappingsError::InvalidArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCustomRoutingPortMappingsError::InvalidArgument,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        ListCustomRoutingPortMappingsError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCustomRoutingPortMappingsError::InvalidNextToken,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3455,28 +3880,38 @@ impl ListCustomRoutingPortMappingsByDestinationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EndpointNotFoundException" => {
-                    return RusotoError::Service(
-                        ListCustomRoutingPortMappingsByDestinationError::EndpointNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCustomRoutingPortMappingsByDestinationError::EndpointNotFound,
                     )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        ListCustomRoutingPortMappingsByDestinationError::InternalServiceError(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCustomRoutingPortMappingsByDestinationError::InternalServiceError,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
-                        ListCustomRoutingPortMappingsByDestinationError::InvalidArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCustomRoutingPortMappingsByDestinationError::InvalidArgument,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        ListCustomRoutingPortMappingsByDestinationError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCustomRoutingPortMappingsByDestinationError::InvalidNextToken,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3521,20 +3956,38 @@ impl ListEndpointGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListEndpointGroupsError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListEndpointGroupsError::InternalServiceError,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListEndpointGroupsError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListEndpointGroupsError::InvalidArgument,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListEndpointGroupsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListEndpointGroupsError::InvalidNextToken,
+                    )
                 }
                 "ListenerNotFoundException" => {
-                    return RusotoError::Service(ListEndpointGroupsError::ListenerNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListEndpointGroupsError::ListenerNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3571,18 +4024,38 @@ impl ListListenersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AcceleratorNotFoundException" => {
-                    return RusotoError::Service(ListListenersError::AcceleratorNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListListenersError::AcceleratorNotFound,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListListenersError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListListenersError::InternalServiceError,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListListenersError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListListenersError::InvalidArgument,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListListenersError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListListenersError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3617,19 +4090,30 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AcceleratorNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::AcceleratorNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::AcceleratorNotFound,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::InternalServiceError,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InvalidArgument,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3667,25 +4151,46 @@ impl ProvisionByoipCidrError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ProvisionByoipCidrError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ProvisionByoipCidrError::AccessDenied,
+                    )
                 }
                 "IncorrectCidrStateException" => {
-                    return RusotoError::Service(ProvisionByoipCidrError::IncorrectCidrState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ProvisionByoipCidrError::IncorrectCidrState,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ProvisionByoipCidrError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ProvisionByoipCidrError::InternalServiceError,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ProvisionByoipCidrError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ProvisionByoipCidrError::InvalidArgument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ProvisionByoipCidrError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ProvisionByoipCidrError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3729,36 +4234,54 @@ impl RemoveCustomRoutingEndpointsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RemoveCustomRoutingEndpointsError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveCustomRoutingEndpointsError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(RemoveCustomRoutingEndpointsError::Conflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveCustomRoutingEndpointsError::Conflict,
+                    )
                 }
                 "EndpointGroupNotFoundException" => {
-                    return RusotoError::Service(
-                        RemoveCustomRoutingEndpointsError::EndpointGroupNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveCustomRoutingEndpointsError::EndpointGroupNotFound,
                     )
                 }
                 "EndpointNotFoundException" => {
-                    return RusotoError::Service(
-                        RemoveCustomRoutingEndpointsError::EndpointNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveCustomRoutingEndpointsError::EndpointNotFound,
                     )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        RemoveCustomRoutingEndpointsError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveCustomRoutingEndpointsError::InternalServiceError,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
-                        RemoveCustomRoutingEndpointsError::InvalidArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveCustomRoutingEndpointsError::InvalidArgument,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3801,15 +4324,30 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AcceleratorNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::AcceleratorNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::AcceleratorNotFound,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(TagResourceError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InternalServiceError,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(TagResourceError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidArgument,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3843,15 +4381,30 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AcceleratorNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::AcceleratorNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::AcceleratorNotFound,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InternalServiceError,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidArgument,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3885,19 +4438,30 @@ impl UpdateAcceleratorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AcceleratorNotFoundException" => {
-                    return RusotoError::Service(UpdateAcceleratorError::AcceleratorNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAcceleratorError::AcceleratorNotFound,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(UpdateAcceleratorError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAcceleratorError::InternalServiceError,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UpdateAcceleratorError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAcceleratorError::InvalidArgument,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3935,26 +4499,38 @@ impl UpdateAcceleratorAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AcceleratorNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateAcceleratorAttributesError::AcceleratorNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAcceleratorAttributesError::AcceleratorNotFound,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateAcceleratorAttributesError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAcceleratorAttributesError::AccessDenied,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        UpdateAcceleratorAttributesError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAcceleratorAttributesError::InternalServiceError,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UpdateAcceleratorAttributesError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAcceleratorAttributesError::InvalidArgument,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3995,21 +4571,30 @@ impl UpdateCustomRoutingAcceleratorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AcceleratorNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateCustomRoutingAcceleratorError::AcceleratorNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCustomRoutingAcceleratorError::AcceleratorNotFound,
                     )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        UpdateCustomRoutingAcceleratorError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCustomRoutingAcceleratorError::InternalServiceError,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
-                        UpdateCustomRoutingAcceleratorError::InvalidArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCustomRoutingAcceleratorError::InvalidArgument,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4053,28 +4638,38 @@ impl UpdateCustomRoutingAcceleratorAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AcceleratorNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateCustomRoutingAcceleratorAttributesError::AcceleratorNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCustomRoutingAcceleratorAttributesError::AcceleratorNotFound,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        UpdateCustomRoutingAcceleratorAttributesError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCustomRoutingAcceleratorAttributesError::AccessDenied,
                     )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        UpdateCustomRoutingAcceleratorAttributesError::InternalServiceError(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCustomRoutingAcceleratorAttributesError::InternalServiceError,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
-                        UpdateCustomRoutingAcceleratorAttributesError::InvalidArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCustomRoutingAcceleratorAttributesError::InvalidArgument,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4123,31 +4718,46 @@ impl UpdateCustomRoutingListenerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        UpdateCustomRoutingListenerError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCustomRoutingListenerError::InternalServiceError,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UpdateCustomRoutingListenerError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateCustomRoutingListenerError::InvalidArgument,
+                    )
                 }
                 "InvalidPortRangeException" => {
-                    return RusotoError::Service(
-                        UpdateCustomRoutingListenerError::InvalidPortRange(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCustomRoutingListenerError::InvalidPortRange,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateCustomRoutingListenerError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateCustomRoutingListenerError::LimitExceeded,
+                    )
                 }
                 "ListenerNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateCustomRoutingListenerError::ListenerNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCustomRoutingListenerError::ListenerNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4189,25 +4799,46 @@ impl UpdateEndpointGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateEndpointGroupError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateEndpointGroupError::AccessDenied,
+                    )
                 }
                 "EndpointGroupNotFoundException" => {
-                    return RusotoError::Service(UpdateEndpointGroupError::EndpointGroupNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateEndpointGroupError::EndpointGroupNotFound,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(UpdateEndpointGroupError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateEndpointGroupError::InternalServiceError,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UpdateEndpointGroupError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateEndpointGroupError::InvalidArgument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateEndpointGroupError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateEndpointGroupError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4247,21 +4878,46 @@ impl UpdateListenerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(UpdateListenerError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateListenerError::InternalServiceError,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UpdateListenerError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateListenerError::InvalidArgument,
+                    )
                 }
                 "InvalidPortRangeException" => {
-                    return RusotoError::Service(UpdateListenerError::InvalidPortRange(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateListenerError::InvalidPortRange,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateListenerError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateListenerError::LimitExceeded,
+                    )
                 }
                 "ListenerNotFoundException" => {
-                    return RusotoError::Service(UpdateListenerError::ListenerNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateListenerError::ListenerNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4301,25 +4957,46 @@ impl WithdrawByoipCidrError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(WithdrawByoipCidrError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        WithdrawByoipCidrError::AccessDenied,
+                    )
                 }
                 "ByoipCidrNotFoundException" => {
-                    return RusotoError::Service(WithdrawByoipCidrError::ByoipCidrNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        WithdrawByoipCidrError::ByoipCidrNotFound,
+                    )
                 }
                 "IncorrectCidrStateException" => {
-                    return RusotoError::Service(WithdrawByoipCidrError::IncorrectCidrState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        WithdrawByoipCidrError::IncorrectCidrState,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(WithdrawByoipCidrError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        WithdrawByoipCidrError::InternalServiceError,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(WithdrawByoipCidrError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        WithdrawByoipCidrError::InvalidArgument,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4673,11 +5350,12 @@ pub struct GlobalAcceleratorClient {
 impl GlobalAcceleratorClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> GlobalAcceleratorClient {
         GlobalAcceleratorClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Global Accelerator"),
         }
     }
 
@@ -4692,24 +5370,45 @@ impl GlobalAcceleratorClient {
     {
         GlobalAcceleratorClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Global Accelerator"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> GlobalAcceleratorClient {
-        GlobalAcceleratorClient { client, region }
+        GlobalAcceleratorClient {
+            client,
+            region: region_for_service(region, "Global Accelerator"),
+        }
     }
-}
 
-#[async_trait]
-impl GlobalAccelerator for GlobalAcceleratorClient {
-    /// <p>Associate a virtual private cloud (VPC) subnet endpoint with your custom routing accelerator.</p> <p>The listener port range must be large enough to support the number of IP addresses that can be specified in your subnet. The number of ports required is: subnet size times the number of ports per destination EC2 instances. For example, a subnet defined as /24 requires a listener port range of at least 255 ports. </p> <p>Note: You must have enough remaining listener ports available to map to the subnet ports, or the call will fail with a LimitExceededException.</p> <p>By default, all destinations in a subnet in a custom routing accelerator cannot receive traffic. To enable all destinations to receive traffic, or to specify individual port mappings that can receive traffic, see the <a href="https://docs.aws.amazon.com/global-accelerator/latest/api/API_AllowCustomRoutingTraffic.html"> AllowCustomRoutingTraffic</a> operation.</p>
-    async fn add_custom_routing_endpoints(
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> GlobalAcceleratorClient {
+        GlobalAcceleratorClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Global Accelerator"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> GlobalAcceleratorClient {
+        GlobalAcceleratorClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl GlobalAccelerator for GlobalAcceleratorClient {
+    /// <p>Associate a virtual private cloud (VPC) subnet endpoint with your custom routing accelerator.</p> <p>The listener port range must be large enough to support the number of IP addresses that can be specified in your subnet. The number of ports required is: subnet size times the number of ports per destination EC2 instances. For example, a subnet defined as /24 requires a listener port range of at least 255 ports. </p> <p>Note: You must have enough remaining listener ports available to map to the subnet ports, or the call will fail with a LimitExceededException.</p> <p>By default, all destinations in a subnet in a custom routing accelerator cannot receive traffic. To enable all destinations to receive traffic, or to specify individual port mappings that can receive traffic, see the <a href="https://docs.aws.amazon.com/global-accelerator/latest/api/API_AllowCustomRoutingTraffic.html"> AllowCustomRoutingTraffic</a> operation.</p>
+    async fn add_custom_routing_endpoints(
         &self,
         input: AddCustomRoutingEndpointsRequest,
     ) -> Result<AddCustomRoutingEndpointsResponse, RusotoError<AddCustomRoutingEndpointsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AddCustomRoutingEndpoints");
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.AddCustomRoutingEndpoints",
@@ -4732,6 +5431,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: AdvertiseByoipCidrRequest,
     ) -> Result<AdvertiseByoipCidrResponse, RusotoError<AdvertiseByoipCidrError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AdvertiseByoipCidr");
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.AdvertiseByoipCidr",
@@ -4753,6 +5453,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: AllowCustomRoutingTrafficRequest,
     ) -> Result<(), RusotoError<AllowCustomRoutingTrafficError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AllowCustomRoutingTraffic");
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.AllowCustomRoutingTraffic",
@@ -4773,6 +5474,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: CreateAcceleratorRequest,
     ) -> Result<CreateAcceleratorResponse, RusotoError<CreateAcceleratorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateAccelerator");
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.CreateAccelerator",
@@ -4797,6 +5499,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         RusotoError<CreateCustomRoutingAcceleratorError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateCustomRoutingAccelerator");
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.CreateCustomRoutingAccelerator",
@@ -4822,6 +5525,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         RusotoError<CreateCustomRoutingEndpointGroupError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateCustomRoutingEndpointGroup");
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.CreateCustomRoutingEndpointGroup",
@@ -4848,6 +5552,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
     ) -> Result<CreateCustomRoutingListenerResponse, RusotoError<CreateCustomRoutingListenerError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateCustomRoutingListener");
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.CreateCustomRoutingListener",
@@ -4870,6 +5575,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: CreateEndpointGroupRequest,
     ) -> Result<CreateEndpointGroupResponse, RusotoError<CreateEndpointGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateEndpointGroup");
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.CreateEndpointGroup",
@@ -4891,6 +5597,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: CreateListenerRequest,
     ) -> Result<CreateListenerResponse, RusotoError<CreateListenerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateListener");
         request.add_header("x-amz-target", "GlobalAccelerator_V20180706.CreateListener");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4909,6 +5616,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: DeleteAcceleratorRequest,
     ) -> Result<(), RusotoError<DeleteAcceleratorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteAccelerator");
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.DeleteAccelerator",
@@ -4929,6 +5637,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: DeleteCustomRoutingAcceleratorRequest,
     ) -> Result<(), RusotoError<DeleteCustomRoutingAcceleratorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteCustomRoutingAccelerator");
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.DeleteCustomRoutingAccelerator",
@@ -4949,6 +5658,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: DeleteCustomRoutingEndpointGroupRequest,
     ) -> Result<(), RusotoError<DeleteCustomRoutingEndpointGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteCustomRoutingEndpointGroup");
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.DeleteCustomRoutingEndpointGroup",
@@ -4972,6 +5682,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: DeleteCustomRoutingListenerRequest,
     ) -> Result<(), RusotoError<DeleteCustomRoutingListenerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteCustomRoutingListener");
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.DeleteCustomRoutingListener",
@@ -4992,6 +5703,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: DeleteEndpointGroupRequest,
     ) -> Result<(), RusotoError<DeleteEndpointGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteEndpointGroup");
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.DeleteEndpointGroup",
@@ -5012,6 +5724,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: DeleteListenerRequest,
     ) -> Result<(), RusotoError<DeleteListenerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteListener");
         request.add_header("x-amz-target", "GlobalAccelerator_V20180706.DeleteListener");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5029,6 +5742,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: DenyCustomRoutingTrafficRequest,
     ) -> Result<(), RusotoError<DenyCustomRoutingTrafficError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DenyCustomRoutingTraffic");
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.DenyCustomRoutingTraffic",
@@ -5049,6 +5763,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: DeprovisionByoipCidrRequest,
     ) -> Result<DeprovisionByoipCidrResponse, RusotoError<DeprovisionByoipCidrError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeprovisionByoipCidr");
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.DeprovisionByoipCidr",
@@ -5071,6 +5786,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: DescribeAcceleratorRequest,
     ) -> Result<DescribeAcceleratorResponse, RusotoError<DescribeAcceleratorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeAccelerator");
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.DescribeAccelerator",
@@ -5095,6 +5811,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         RusotoError<DescribeAcceleratorAttributesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeAcceleratorAttributes");
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.DescribeAcceleratorAttributes",
@@ -5120,6 +5837,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         RusotoError<DescribeCustomRoutingAcceleratorError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeCustomRoutingAccelerator");
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.DescribeCustomRoutingAccelerator",
@@ -5148,6 +5866,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         RusotoError<DescribeCustomRoutingAcceleratorAttributesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeCustomRoutingAcceleratorAttributes");
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.DescribeCustomRoutingAcceleratorAttributes",
@@ -5176,6 +5895,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         RusotoError<DescribeCustomRoutingEndpointGroupError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeCustomRoutingEndpointGroup");
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.DescribeCustomRoutingEndpointGroup",
@@ -5204,6 +5924,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         RusotoError<DescribeCustomRoutingListenerError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeCustomRoutingListener");
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.DescribeCustomRoutingListener",
@@ -5226,6 +5947,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: DescribeEndpointGroupRequest,
     ) -> Result<DescribeEndpointGroupResponse, RusotoError<DescribeEndpointGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeEndpointGroup");
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.DescribeEndpointGroup",
@@ -5248,6 +5970,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: DescribeListenerRequest,
     ) -> Result<DescribeListenerResponse, RusotoError<DescribeListenerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeListener");
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.DescribeListener",
@@ -5269,6 +5992,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: ListAcceleratorsRequest,
     ) -> Result<ListAcceleratorsResponse, RusotoError<ListAcceleratorsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListAccelerators");
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.ListAccelerators",
@@ -5290,6 +6014,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: ListByoipCidrsRequest,
     ) -> Result<ListByoipCidrsResponse, RusotoError<ListByoipCidrsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListByoipCidrs");
         request.add_header("x-amz-target", "GlobalAccelerator_V20180706.ListByoipCidrs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5311,6 +6036,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         RusotoError<ListCustomRoutingAcceleratorsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListCustomRoutingAccelerators");
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.ListCustomRoutingAccelerators",
@@ -5336,6 +6062,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         RusotoError<ListCustomRoutingEndpointGroupsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListCustomRoutingEndpointGroups");
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.ListCustomRoutingEndpointGroups",
@@ -5359,6 +6086,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
     ) -> Result<ListCustomRoutingListenersResponse, RusotoError<ListCustomRoutingListenersError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListCustomRoutingListeners");
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.ListCustomRoutingListeners",
@@ -5384,6 +6112,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         RusotoError<ListCustomRoutingPortMappingsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListCustomRoutingPortMappings");
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.ListCustomRoutingPortMappings",
@@ -5409,6 +6138,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         RusotoError<ListCustomRoutingPortMappingsByDestinationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListCustomRoutingPortMappingsByDestination");
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.ListCustomRoutingPortMappingsByDestination",
@@ -5434,6 +6164,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: ListEndpointGroupsRequest,
     ) -> Result<ListEndpointGroupsResponse, RusotoError<ListEndpointGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListEndpointGroups");
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.ListEndpointGroups",
@@ -5455,6 +6186,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: ListListenersRequest,
     ) -> Result<ListListenersResponse, RusotoError<ListListenersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListListeners");
         request.add_header("x-amz-target", "GlobalAccelerator_V20180706.ListListeners");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5473,6 +6205,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.ListTagsForResource",
@@ -5494,6 +6227,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: ProvisionByoipCidrRequest,
     ) -> Result<ProvisionByoipCidrResponse, RusotoError<ProvisionByoipCidrError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ProvisionByoipCidr");
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.ProvisionByoipCidr",
@@ -5515,6 +6249,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: RemoveCustomRoutingEndpointsRequest,
     ) -> Result<(), RusotoError<RemoveCustomRoutingEndpointsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RemoveCustomRoutingEndpoints");
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.RemoveCustomRoutingEndpoints",
@@ -5535,6 +6270,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "GlobalAccelerator_V20180706.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5553,6 +6289,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "GlobalAccelerator_V20180706.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5571,6 +6308,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: UpdateAcceleratorRequest,
     ) -> Result<UpdateAcceleratorResponse, RusotoError<UpdateAcceleratorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateAccelerator");
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.UpdateAccelerator",
@@ -5593,6 +6331,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
     ) -> Result<UpdateAcceleratorAttributesResponse, RusotoError<UpdateAcceleratorAttributesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateAcceleratorAttributes");
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.UpdateAcceleratorAttributes",
@@ -5618,6 +6357,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         RusotoError<UpdateCustomRoutingAcceleratorError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateCustomRoutingAccelerator");
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.UpdateCustomRoutingAccelerator",
@@ -5643,6 +6383,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         RusotoError<UpdateCustomRoutingAcceleratorAttributesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateCustomRoutingAcceleratorAttributes");
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.UpdateCustomRoutingAcceleratorAttributes",
@@ -5669,6 +6410,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
     ) -> Result<UpdateCustomRoutingListenerResponse, RusotoError<UpdateCustomRoutingListenerError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateCustomRoutingListener");
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.UpdateCustomRoutingListener",
@@ -5691,6 +6433,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: UpdateEndpointGroupRequest,
     ) -> Result<UpdateEndpointGroupResponse, RusotoError<UpdateEndpointGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateEndpointGroup");
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.UpdateEndpointGroup",
@@ -5712,6 +6455,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: UpdateListenerRequest,
     ) -> Result<UpdateListenerResponse, RusotoError<UpdateListenerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateListener");
         request.add_header("x-amz-target", "GlobalAccelerator_V20180706.UpdateListener");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5730,6 +6474,7 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         input: WithdrawByoipCidrRequest,
     ) -> Result<WithdrawByoipCidrResponse, RusotoError<WithdrawByoipCidrError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("WithdrawByoipCidr");
         request.add_header(
             "x-amz-target",
             "GlobalAccelerator_V20180706.WithdrawByoipCidr",
@@ -5745,3 +6490,549 @@ impl GlobalAccelerator for GlobalAcceleratorClient {
         proto::json::ResponsePayload::new(&response).deserialize::<WithdrawByoipCidrResponse, _>()
     }
 }
+
+/// A blocking client for the AWS Global Accelerator API, running the calls of a `GlobalAcceleratorClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `GlobalAcceleratorClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct GlobalAcceleratorBlockingClient {
+    client: GlobalAcceleratorClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl GlobalAcceleratorBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> GlobalAcceleratorBlockingClient {
+        GlobalAcceleratorBlockingClient::from_client(GlobalAcceleratorClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> GlobalAcceleratorBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        GlobalAcceleratorBlockingClient::from_client(GlobalAcceleratorClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> GlobalAcceleratorBlockingClient {
+        GlobalAcceleratorBlockingClient::from_client(GlobalAcceleratorClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> GlobalAcceleratorBlockingClient {
+        GlobalAcceleratorBlockingClient::from_client(GlobalAcceleratorClient::new_with_config(
+            config,
+        ))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: GlobalAcceleratorClient) -> GlobalAcceleratorBlockingClient {
+        GlobalAcceleratorBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> GlobalAcceleratorBlockingClient {
+        GlobalAcceleratorBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> GlobalAcceleratorBlockingClient {
+        GlobalAcceleratorBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`GlobalAccelerator::add_custom_routing_endpoints`].
+    pub fn add_custom_routing_endpoints(
+        &self,
+        input: AddCustomRoutingEndpointsRequest,
+    ) -> Result<AddCustomRoutingEndpointsResponse, RusotoError<AddCustomRoutingEndpointsError>>
+    {
+        self.runtime
+            .block_on(self.client.add_custom_routing_endpoints(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::advertise_byoip_cidr`].
+    pub fn advertise_byoip_cidr(
+        &self,
+        input: AdvertiseByoipCidrRequest,
+    ) -> Result<AdvertiseByoipCidrResponse, RusotoError<AdvertiseByoipCidrError>> {
+        self.runtime
+            .block_on(self.client.advertise_byoip_cidr(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::allow_custom_routing_traffic`].
+    pub fn allow_custom_routing_traffic(
+        &self,
+        input: AllowCustomRoutingTrafficRequest,
+    ) -> Result<(), RusotoError<AllowCustomRoutingTrafficError>> {
+        self.runtime
+            .block_on(self.client.allow_custom_routing_traffic(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::create_accelerator`].
+    pub fn create_accelerator(
+        &self,
+        input: CreateAcceleratorRequest,
+    ) -> Result<CreateAcceleratorResponse, RusotoError<CreateAcceleratorError>> {
+        self.runtime.block_on(self.client.create_accelerator(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::create_custom_routing_accelerator`].
+    pub fn create_custom_routing_accelerator(
+        &self,
+        input: CreateCustomRoutingAcceleratorRequest,
+    ) -> Result<
+        CreateCustomRoutingAcceleratorResponse,
+        RusotoError<CreateCustomRoutingAcceleratorError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_custom_routing_accelerator(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::create_custom_routing_endpoint_group`].
+    pub fn create_custom_routing_endpoint_group(
+        &self,
+        input: CreateCustomRoutingEndpointGroupRequest,
+    ) -> Result<
+        CreateCustomRoutingEndpointGroupResponse,
+        RusotoError<CreateCustomRoutingEndpointGroupError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_custom_routing_endpoint_group(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::create_custom_routing_listener`].
+    pub fn create_custom_routing_listener(
+        &self,
+        input: CreateCustomRoutingListenerRequest,
+    ) -> Result<CreateCustomRoutingListenerResponse, RusotoError<CreateCustomRoutingListenerError>>
+    {
+        self.runtime
+            .block_on(self.client.create_custom_routing_listener(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::create_endpoint_group`].
+    pub fn create_endpoint_group(
+        &self,
+        input: CreateEndpointGroupRequest,
+    ) -> Result<CreateEndpointGroupResponse, RusotoError<CreateEndpointGroupError>> {
+        self.runtime
+            .block_on(self.client.create_endpoint_group(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::create_listener`].
+    pub fn create_listener(
+        &self,
+        input: CreateListenerRequest,
+    ) -> Result<CreateListenerResponse, RusotoError<CreateListenerError>> {
+        self.runtime.block_on(self.client.create_listener(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::delete_accelerator`].
+    pub fn delete_accelerator(
+        &self,
+        input: DeleteAcceleratorRequest,
+    ) -> Result<(), RusotoError<DeleteAcceleratorError>> {
+        self.runtime.block_on(self.client.delete_accelerator(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::delete_custom_routing_accelerator`].
+    pub fn delete_custom_routing_accelerator(
+        &self,
+        input: DeleteCustomRoutingAcceleratorRequest,
+    ) -> Result<(), RusotoError<DeleteCustomRoutingAcceleratorError>> {
+        self.runtime
+            .block_on(self.client.delete_custom_routing_accelerator(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::delete_custom_routing_endpoint_group`].
+    pub fn delete_custom_routing_endpoint_group(
+        &self,
+        input: DeleteCustomRoutingEndpointGroupRequest,
+    ) -> Result<(), RusotoError<DeleteCustomRoutingEndpointGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_custom_routing_endpoint_group(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::delete_custom_routing_listener`].
+    pub fn delete_custom_routing_listener(
+        &self,
+        input: DeleteCustomRoutingListenerRequest,
+    ) -> Result<(), RusotoError<DeleteCustomRoutingListenerError>> {
+        self.runtime
+            .block_on(self.client.delete_custom_routing_listener(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::delete_endpoint_group`].
+    pub fn delete_endpoint_group(
+        &self,
+        input: DeleteEndpointGroupRequest,
+    ) -> Result<(), RusotoError<DeleteEndpointGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_endpoint_group(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::delete_listener`].
+    pub fn delete_listener(
+        &self,
+        input: DeleteListenerRequest,
+    ) -> Result<(), RusotoError<DeleteListenerError>> {
+        self.runtime.block_on(self.client.delete_listener(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::deny_custom_routing_traffic`].
+    pub fn deny_custom_routing_traffic(
+        &self,
+        input: DenyCustomRoutingTrafficRequest,
+    ) -> Result<(), RusotoError<DenyCustomRoutingTrafficError>> {
+        self.runtime
+            .block_on(self.client.deny_custom_routing_traffic(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::deprovision_byoip_cidr`].
+    pub fn deprovision_byoip_cidr(
+        &self,
+        input: DeprovisionByoipCidrRequest,
+    ) -> Result<DeprovisionByoipCidrResponse, RusotoError<DeprovisionByoipCidrError>> {
+        self.runtime
+            .block_on(self.client.deprovision_byoip_cidr(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::describe_accelerator`].
+    pub fn describe_accelerator(
+        &self,
+        input: DescribeAcceleratorRequest,
+    ) -> Result<DescribeAcceleratorResponse, RusotoError<DescribeAcceleratorError>> {
+        self.runtime
+            .block_on(self.client.describe_accelerator(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::describe_accelerator_attributes`].
+    pub fn describe_accelerator_attributes(
+        &self,
+        input: DescribeAcceleratorAttributesRequest,
+    ) -> Result<
+        DescribeAcceleratorAttributesResponse,
+        RusotoError<DescribeAcceleratorAttributesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_accelerator_attributes(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::describe_custom_routing_accelerator`].
+    pub fn describe_custom_routing_accelerator(
+        &self,
+        input: DescribeCustomRoutingAcceleratorRequest,
+    ) -> Result<
+        DescribeCustomRoutingAcceleratorResponse,
+        RusotoError<DescribeCustomRoutingAcceleratorError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_custom_routing_accelerator(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::describe_custom_routing_accelerator_attributes`].
+    pub fn describe_custom_routing_accelerator_attributes(
+        &self,
+        input: DescribeCustomRoutingAcceleratorAttributesRequest,
+    ) -> Result<
+        DescribeCustomRoutingAcceleratorAttributesResponse,
+        RusotoError<DescribeCustomRoutingAcceleratorAttributesError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_custom_routing_accelerator_attributes(input),
+        )
+    }
+
+    /// Blocking version of [`GlobalAccelerator::describe_custom_routing_endpoint_group`].
+    pub fn describe_custom_routing_endpoint_group(
+        &self,
+        input: DescribeCustomRoutingEndpointGroupRequest,
+    ) -> Result<
+        DescribeCustomRoutingEndpointGroupResponse,
+        RusotoError<DescribeCustomRoutingEndpointGroupError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_custom_routing_endpoint_group(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::describe_custom_routing_listener`].
+    pub fn describe_custom_routing_listener(
+        &self,
+        input: DescribeCustomRoutingListenerRequest,
+    ) -> Result<
+        DescribeCustomRoutingListenerResponse,
+        RusotoError<DescribeCustomRoutingListenerError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_custom_routing_listener(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::describe_endpoint_group`].
+    pub fn describe_endpoint_group(
+        &self,
+        input: DescribeEndpointGroupRequest,
+    ) -> Result<DescribeEndpointGroupResponse, RusotoError<DescribeEndpointGroupError>> {
+        self.runtime
+            .block_on(self.client.describe_endpoint_group(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::describe_listener`].
+    pub fn describe_listener(
+        &self,
+        input: DescribeListenerRequest,
+    ) -> Result<DescribeListenerResponse, RusotoError<DescribeListenerError>> {
+        self.runtime.block_on(self.client.describe_listener(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::list_accelerators`].
+    pub fn list_accelerators(
+        &self,
+        input: ListAcceleratorsRequest,
+    ) -> Result<ListAcceleratorsResponse, RusotoError<ListAcceleratorsError>> {
+        self.runtime.block_on(self.client.list_accelerators(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::list_byoip_cidrs`].
+    pub fn list_byoip_cidrs(
+        &self,
+        input: ListByoipCidrsRequest,
+    ) -> Result<ListByoipCidrsResponse, RusotoError<ListByoipCidrsError>> {
+        self.runtime.block_on(self.client.list_byoip_cidrs(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::list_custom_routing_accelerators`].
+    pub fn list_custom_routing_accelerators(
+        &self,
+        input: ListCustomRoutingAcceleratorsRequest,
+    ) -> Result<
+        ListCustomRoutingAcceleratorsResponse,
+        RusotoError<ListCustomRoutingAcceleratorsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_custom_routing_accelerators(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::list_custom_routing_endpoint_groups`].
+    pub fn list_custom_routing_endpoint_groups(
+        &self,
+        input: ListCustomRoutingEndpointGroupsRequest,
+    ) -> Result<
+        ListCustomRoutingEndpointGroupsResponse,
+        RusotoError<ListCustomRoutingEndpointGroupsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_custom_routing_endpoint_groups(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::list_custom_routing_listeners`].
+    pub fn list_custom_routing_listeners(
+        &self,
+        input: ListCustomRoutingListenersRequest,
+    ) -> Result<ListCustomRoutingListenersResponse, RusotoError<ListCustomRoutingListenersError>>
+    {
+        self.runtime
+            .block_on(self.client.list_custom_routing_listeners(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::list_custom_routing_port_mappings`].
+    pub fn list_custom_routing_port_mappings(
+        &self,
+        input: ListCustomRoutingPortMappingsRequest,
+    ) -> Result<
+        ListCustomRoutingPortMappingsResponse,
+        RusotoError<ListCustomRoutingPortMappingsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_custom_routing_port_mappings(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::list_custom_routing_port_mappings_by_destination`].
+    pub fn list_custom_routing_port_mappings_by_destination(
+        &self,
+        input: ListCustomRoutingPortMappingsByDestinationRequest,
+    ) -> Result<
+        ListCustomRoutingPortMappingsByDestinationResponse,
+        RusotoError<ListCustomRoutingPortMappingsByDestinationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .list_custom_routing_port_mappings_by_destination(input),
+        )
+    }
+
+    /// Blocking version of [`GlobalAccelerator::list_endpoint_groups`].
+    pub fn list_endpoint_groups(
+        &self,
+        input: ListEndpointGroupsRequest,
+    ) -> Result<ListEndpointGroupsResponse, RusotoError<ListEndpointGroupsError>> {
+        self.runtime
+            .block_on(self.client.list_endpoint_groups(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::list_listeners`].
+    pub fn list_listeners(
+        &self,
+        input: ListListenersRequest,
+    ) -> Result<ListListenersResponse, RusotoError<ListListenersError>> {
+        self.runtime.block_on(self.client.list_listeners(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::provision_byoip_cidr`].
+    pub fn provision_byoip_cidr(
+        &self,
+        input: ProvisionByoipCidrRequest,
+    ) -> Result<ProvisionByoipCidrResponse, RusotoError<ProvisionByoipCidrError>> {
+        self.runtime
+            .block_on(self.client.provision_byoip_cidr(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::remove_custom_routing_endpoints`].
+    pub fn remove_custom_routing_endpoints(
+        &self,
+        input: RemoveCustomRoutingEndpointsRequest,
+    ) -> Result<(), RusotoError<RemoveCustomRoutingEndpointsError>> {
+        self.runtime
+            .block_on(self.client.remove_custom_routing_endpoints(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::update_accelerator`].
+    pub fn update_accelerator(
+        &self,
+        input: UpdateAcceleratorRequest,
+    ) -> Result<UpdateAcceleratorResponse, RusotoError<UpdateAcceleratorError>> {
+        self.runtime.block_on(self.client.update_accelerator(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::update_accelerator_attributes`].
+    pub fn update_accelerator_attributes(
+        &self,
+        input: UpdateAcceleratorAttributesRequest,
+    ) -> Result<UpdateAcceleratorAttributesResponse, RusotoError<UpdateAcceleratorAttributesError>>
+    {
+        self.runtime
+            .block_on(self.client.update_accelerator_attributes(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::update_custom_routing_accelerator`].
+    pub fn update_custom_routing_accelerator(
+        &self,
+        input: UpdateCustomRoutingAcceleratorRequest,
+    ) -> Result<
+        UpdateCustomRoutingAcceleratorResponse,
+        RusotoError<UpdateCustomRoutingAcceleratorError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_custom_routing_accelerator(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::update_custom_routing_accelerator_attributes`].
+    pub fn update_custom_routing_accelerator_attributes(
+        &self,
+        input: UpdateCustomRoutingAcceleratorAttributesRequest,
+    ) -> Result<
+        UpdateCustomRoutingAcceleratorAttributesResponse,
+        RusotoError<UpdateCustomRoutingAcceleratorAttributesError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .update_custom_routing_accelerator_attributes(input),
+        )
+    }
+
+    /// Blocking version of [`GlobalAccelerator::update_custom_routing_listener`].
+    pub fn update_custom_routing_listener(
+        &self,
+        input: UpdateCustomRoutingListenerRequest,
+    ) -> Result<UpdateCustomRoutingListenerResponse, RusotoError<UpdateCustomRoutingListenerError>>
+    {
+        self.runtime
+            .block_on(self.client.update_custom_routing_listener(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::update_endpoint_group`].
+    pub fn update_endpoint_group(
+        &self,
+        input: UpdateEndpointGroupRequest,
+    ) -> Result<UpdateEndpointGroupResponse, RusotoError<UpdateEndpointGroupError>> {
+        self.runtime
+            .block_on(self.client.update_endpoint_group(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::update_listener`].
+    pub fn update_listener(
+        &self,
+        input: UpdateListenerRequest,
+    ) -> Result<UpdateListenerResponse, RusotoError<UpdateListenerError>> {
+        self.runtime.block_on(self.client.update_listener(input))
+    }
+
+    /// Blocking version of [`GlobalAccelerator::withdraw_byoip_cidr`].
+    pub fn withdraw_byoip_cidr(
+        &self,
+        input: WithdrawByoipCidrRequest,
+    ) -> Result<WithdrawByoipCidrResponse, RusotoError<WithdrawByoipCidrError>> {
+        self.runtime
+            .block_on(self.client.withdraw_byoip_cidr(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -7995,33 +7996,62 @@ impl BatchCreatePartitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(BatchCreatePartitionError::AlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchCreatePartitionError::AlreadyExists,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(BatchCreatePartitionError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchCreatePartitionError::EntityNotFound,
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(BatchCreatePartitionError::GlueEncryption(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchCreatePartitionError::GlueEncryption,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(BatchCreatePartitionError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchCreatePartitionError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchCreatePartitionError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchCreatePartitionError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(BatchCreatePartitionError::OperationTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchCreatePartitionError::OperationTimeout,
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(
-                        BatchCreatePartitionError::ResourceNumberLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchCreatePartitionError::ResourceNumberLimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8059,16 +8089,22 @@ impl BatchDeleteConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(BatchDeleteConnectionError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDeleteConnectionError::InternalService,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(BatchDeleteConnectionError::OperationTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDeleteConnectionError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8103,22 +8139,38 @@ impl BatchDeletePartitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(BatchDeletePartitionError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDeletePartitionError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(BatchDeletePartitionError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDeletePartitionError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchDeletePartitionError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDeletePartitionError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(BatchDeletePartitionError::OperationTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDeletePartitionError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8155,18 +8207,38 @@ impl BatchDeleteTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(BatchDeleteTableError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDeleteTableError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(BatchDeleteTableError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDeleteTableError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchDeleteTableError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDeleteTableError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(BatchDeleteTableError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDeleteTableError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8203,26 +8275,38 @@ impl BatchDeleteTableVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(BatchDeleteTableVersionError::EntityNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDeleteTableVersionError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(BatchDeleteTableVersionError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDeleteTableVersionError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchDeleteTableVersionError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDeleteTableVersionError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(BatchDeleteTableVersionError::OperationTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDeleteTableVersionError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8255,12 +8339,22 @@ impl BatchGetCrawlersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchGetCrawlersError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetCrawlersError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(BatchGetCrawlersError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetCrawlersError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8295,22 +8389,38 @@ impl BatchGetDevEndpointsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(BatchGetDevEndpointsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetDevEndpointsError::AccessDenied,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(BatchGetDevEndpointsError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchGetDevEndpointsError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchGetDevEndpointsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetDevEndpointsError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(BatchGetDevEndpointsError::OperationTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchGetDevEndpointsError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8345,15 +8455,30 @@ impl BatchGetJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(BatchGetJobsError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetJobsError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchGetJobsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetJobsError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(BatchGetJobsError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetJobsError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8391,21 +8516,46 @@ impl BatchGetPartitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(BatchGetPartitionError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetPartitionError::EntityNotFound,
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(BatchGetPartitionError::GlueEncryption(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetPartitionError::GlueEncryption,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(BatchGetPartitionError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetPartitionError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchGetPartitionError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetPartitionError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(BatchGetPartitionError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetPartitionError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8441,15 +8591,30 @@ impl BatchGetTriggersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(BatchGetTriggersError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetTriggersError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchGetTriggersError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetTriggersError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(BatchGetTriggersError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetTriggersError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8483,15 +8648,30 @@ impl BatchGetWorkflowsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(BatchGetWorkflowsError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetWorkflowsError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchGetWorkflowsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetWorkflowsError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(BatchGetWorkflowsError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGetWorkflowsError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8525,17 +8705,30 @@ impl GlueBatchStopJobRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(GlueBatchStopJobRunError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GlueBatchStopJobRunError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GlueBatchStopJobRunError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GlueBatchStopJobRunError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GlueBatchStopJobRunError::OperationTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GlueBatchStopJobRunError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8573,25 +8766,46 @@ impl BatchUpdatePartitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(BatchUpdatePartitionError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchUpdatePartitionError::EntityNotFound,
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(BatchUpdatePartitionError::GlueEncryption(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchUpdatePartitionError::GlueEncryption,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(BatchUpdatePartitionError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchUpdatePartitionError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchUpdatePartitionError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchUpdatePartitionError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(BatchUpdatePartitionError::OperationTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchUpdatePartitionError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8629,18 +8843,38 @@ impl CancelMLTaskRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(CancelMLTaskRunError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelMLTaskRunError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CancelMLTaskRunError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelMLTaskRunError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CancelMLTaskRunError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelMLTaskRunError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CancelMLTaskRunError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelMLTaskRunError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8677,21 +8911,30 @@ impl CheckSchemaVersionValidityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CheckSchemaVersionValidityError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CheckSchemaVersionValidityError::AccessDenied,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CheckSchemaVersionValidityError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CheckSchemaVersionValidityError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CheckSchemaVersionValidityError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CheckSchemaVersionValidityError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8725,15 +8968,30 @@ impl CreateClassifierError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateClassifierError::AlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateClassifierError::AlreadyExists,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateClassifierError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateClassifierError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CreateClassifierError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateClassifierError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8771,23 +9029,46 @@ impl CreateConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateConnectionError::AlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConnectionError::AlreadyExists,
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(CreateConnectionError::GlueEncryption(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConnectionError::GlueEncryption,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateConnectionError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConnectionError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CreateConnectionError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConnectionError::OperationTimeout,
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(
-                        CreateConnectionError::ResourceNumberLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConnectionError::ResourceNumberLimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8825,20 +9106,38 @@ impl CreateCrawlerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateCrawlerError::AlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCrawlerError::AlreadyExists,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateCrawlerError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCrawlerError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CreateCrawlerError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCrawlerError::OperationTimeout,
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(CreateCrawlerError::ResourceNumberLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCrawlerError::ResourceNumberLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8879,26 +9178,54 @@ impl CreateDatabaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateDatabaseError::AlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDatabaseError::AlreadyExists,
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(CreateDatabaseError::GlueEncryption(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDatabaseError::GlueEncryption,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateDatabaseError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDatabaseError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDatabaseError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDatabaseError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CreateDatabaseError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDatabaseError::OperationTimeout,
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(CreateDatabaseError::ResourceNumberLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDatabaseError::ResourceNumberLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8943,31 +9270,62 @@ impl CreateDevEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDevEndpointError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDevEndpointError::AccessDenied,
+                    )
                 }
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateDevEndpointError::AlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDevEndpointError::AlreadyExists,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        CreateDevEndpointError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDevEndpointError::IdempotentParameterMismatch,
                     )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateDevEndpointError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDevEndpointError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDevEndpointError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDevEndpointError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CreateDevEndpointError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDevEndpointError::OperationTimeout,
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(
-                        CreateDevEndpointError::ResourceNumberLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDevEndpointError::ResourceNumberLimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9017,31 +9375,62 @@ impl CreateJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateJobError::AlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateJobError::AlreadyExists,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateJobError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateJobError::ConcurrentModification,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(CreateJobError::IdempotentParameterMismatch(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateJobError::IdempotentParameterMismatch,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateJobError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateJobError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateJobError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateJobError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CreateJobError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateJobError::OperationTimeout,
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(CreateJobError::ResourceNumberLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateJobError::ResourceNumberLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9087,31 +9476,62 @@ impl CreateMLTransformError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateMLTransformError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMLTransformError::AccessDenied,
+                    )
                 }
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateMLTransformError::AlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMLTransformError::AlreadyExists,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        CreateMLTransformError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMLTransformError::IdempotentParameterMismatch,
                     )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateMLTransformError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMLTransformError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateMLTransformError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMLTransformError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CreateMLTransformError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMLTransformError::OperationTimeout,
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(
-                        CreateMLTransformError::ResourceNumberLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMLTransformError::ResourceNumberLimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9161,29 +9581,62 @@ impl CreatePartitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreatePartitionError::AlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePartitionError::AlreadyExists,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(CreatePartitionError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePartitionError::EntityNotFound,
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(CreatePartitionError::GlueEncryption(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePartitionError::GlueEncryption,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreatePartitionError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePartitionError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreatePartitionError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePartitionError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CreatePartitionError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePartitionError::OperationTimeout,
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(CreatePartitionError::ResourceNumberLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePartitionError::ResourceNumberLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9229,33 +9682,62 @@ impl CreatePartitionIndexError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreatePartitionIndexError::AlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePartitionIndexError::AlreadyExists,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(CreatePartitionIndexError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePartitionIndexError::EntityNotFound,
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(CreatePartitionIndexError::GlueEncryption(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePartitionIndexError::GlueEncryption,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreatePartitionIndexError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePartitionIndexError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreatePartitionIndexError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePartitionIndexError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CreatePartitionIndexError::OperationTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePartitionIndexError::OperationTimeout,
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(
-                        CreatePartitionIndexError::ResourceNumberLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePartitionIndexError::ResourceNumberLimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9299,23 +9781,46 @@ impl CreateRegistryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateRegistryError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRegistryError::AccessDenied,
+                    )
                 }
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateRegistryError::AlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRegistryError::AlreadyExists,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateRegistryError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRegistryError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateRegistryError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRegistryError::InvalidInput,
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(CreateRegistryError::ResourceNumberLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRegistryError::ResourceNumberLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9357,26 +9862,54 @@ impl CreateSchemaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateSchemaError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSchemaError::AccessDenied,
+                    )
                 }
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateSchemaError::AlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSchemaError::AlreadyExists,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(CreateSchemaError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSchemaError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateSchemaError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSchemaError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateSchemaError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSchemaError::InvalidInput,
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(CreateSchemaError::ResourceNumberLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSchemaError::ResourceNumberLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9413,15 +9946,30 @@ impl CreateScriptError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateScriptError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateScriptError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateScriptError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateScriptError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CreateScriptError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateScriptError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9461,31 +10009,46 @@ impl CreateSecurityConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateSecurityConfigurationError::AlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSecurityConfigurationError::AlreadyExists,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateSecurityConfigurationError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSecurityConfigurationError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateSecurityConfigurationError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSecurityConfigurationError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(
-                        CreateSecurityConfigurationError::OperationTimeout(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSecurityConfigurationError::OperationTimeout,
                     )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(
-                        CreateSecurityConfigurationError::ResourceNumberLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSecurityConfigurationError::ResourceNumberLimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9531,29 +10094,62 @@ impl CreateTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateTableError::AlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTableError::AlreadyExists,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(CreateTableError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTableError::EntityNotFound,
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(CreateTableError::GlueEncryption(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTableError::GlueEncryption,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateTableError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTableError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateTableError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTableError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CreateTableError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTableError::OperationTimeout,
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(CreateTableError::ResourceNumberLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateTableError::ResourceNumberLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9601,36 +10197,70 @@ impl CreateTriggerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateTriggerError::AlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTriggerError::AlreadyExists,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateTriggerError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateTriggerError::ConcurrentModification,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(CreateTriggerError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTriggerError::EntityNotFound,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(CreateTriggerError::IdempotentParameterMismatch(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateTriggerError::IdempotentParameterMismatch,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateTriggerError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTriggerError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateTriggerError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTriggerError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CreateTriggerError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTriggerError::OperationTimeout,
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(CreateTriggerError::ResourceNumberLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateTriggerError::ResourceNumberLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9677,41 +10307,62 @@ impl CreateUserDefinedFunctionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateUserDefinedFunctionError::AlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateUserDefinedFunctionError::AlreadyExists,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(CreateUserDefinedFunctionError::EntityNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateUserDefinedFunctionError::EntityNotFound,
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(CreateUserDefinedFunctionError::GlueEncryption(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateUserDefinedFunctionError::GlueEncryption,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateUserDefinedFunctionError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateUserDefinedFunctionError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateUserDefinedFunctionError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateUserDefinedFunctionError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CreateUserDefinedFunctionError::OperationTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateUserDefinedFunctionError::OperationTimeout,
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(
-                        CreateUserDefinedFunctionError::ResourceNumberLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserDefinedFunctionError::ResourceNumberLimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9757,28 +10408,54 @@ impl CreateWorkflowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateWorkflowError::AlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWorkflowError::AlreadyExists,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateWorkflowError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateWorkflowError::ConcurrentModification,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CreateWorkflowError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWorkflowError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateWorkflowError::InvalidInput(err.msg))
-                }
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWorkflowError::InvalidInput,
+                    )
+                }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(CreateWorkflowError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWorkflowError::OperationTimeout,
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(CreateWorkflowError::ResourceNumberLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateWorkflowError::ResourceNumberLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9813,12 +10490,22 @@ impl DeleteClassifierError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeleteClassifierError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteClassifierError::EntityNotFound,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeleteClassifierError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteClassifierError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9857,31 +10544,46 @@ impl DeleteColumnStatisticsForPartitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteColumnStatisticsForPartitionError::EntityNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteColumnStatisticsForPartitionError::EntityNotFound,
                     )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(
-                        DeleteColumnStatisticsForPartitionError::GlueEncryption(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteColumnStatisticsForPartitionError::GlueEncryption,
                     )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(
-                        DeleteColumnStatisticsForPartitionError::InternalService(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteColumnStatisticsForPartitionError::InternalService,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
-                        DeleteColumnStatisticsForPartitionError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteColumnStatisticsForPartitionError::InvalidInput,
                     )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(
-                        DeleteColumnStatisticsForPartitionError::OperationTimeout(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteColumnStatisticsForPartitionError::OperationTimeout,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9933,31 +10635,46 @@ impl DeleteColumnStatisticsForTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteColumnStatisticsForTableError::EntityNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteColumnStatisticsForTableError::EntityNotFound,
                     )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(
-                        DeleteColumnStatisticsForTableError::GlueEncryption(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteColumnStatisticsForTableError::GlueEncryption,
                     )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(
-                        DeleteColumnStatisticsForTableError::InternalService(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteColumnStatisticsForTableError::InternalService,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteColumnStatisticsForTableError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteColumnStatisticsForTableError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(
-                        DeleteColumnStatisticsForTableError::OperationTimeout(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteColumnStatisticsForTableError::OperationTimeout,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9999,12 +10716,22 @@ impl DeleteConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeleteConnectionError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteConnectionError::EntityNotFound,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeleteConnectionError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteConnectionError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10039,20 +10766,38 @@ impl DeleteCrawlerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CrawlerRunningException" => {
-                    return RusotoError::Service(DeleteCrawlerError::CrawlerRunning(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCrawlerError::CrawlerRunning,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeleteCrawlerError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCrawlerError::EntityNotFound,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeleteCrawlerError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCrawlerError::OperationTimeout,
+                    )
                 }
                 "SchedulerTransitioningException" => {
-                    return RusotoError::Service(DeleteCrawlerError::SchedulerTransitioning(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteCrawlerError::SchedulerTransitioning,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10089,18 +10834,38 @@ impl DeleteDatabaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeleteDatabaseError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDatabaseError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteDatabaseError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDatabaseError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteDatabaseError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDatabaseError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeleteDatabaseError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDatabaseError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10137,18 +10902,38 @@ impl DeleteDevEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeleteDevEndpointError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDevEndpointError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteDevEndpointError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDevEndpointError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteDevEndpointError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDevEndpointError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeleteDevEndpointError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDevEndpointError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10183,15 +10968,30 @@ impl DeleteJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteJobError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteJobError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteJobError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteJobError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeleteJobError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteJobError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10227,18 +11027,38 @@ impl DeleteMLTransformError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeleteMLTransformError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMLTransformError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteMLTransformError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMLTransformError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteMLTransformError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMLTransformError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeleteMLTransformError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMLTransformError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10275,18 +11095,38 @@ impl DeletePartitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeletePartitionError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePartitionError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeletePartitionError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePartitionError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeletePartitionError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePartitionError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeletePartitionError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePartitionError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10327,28 +11167,54 @@ impl DeletePartitionIndexError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(DeletePartitionIndexError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePartitionIndexError::Conflict,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeletePartitionIndexError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePartitionIndexError::EntityNotFound,
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(DeletePartitionIndexError::GlueEncryption(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePartitionIndexError::GlueEncryption,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeletePartitionIndexError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeletePartitionIndexError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeletePartitionIndexError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePartitionIndexError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeletePartitionIndexError::OperationTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeletePartitionIndexError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10387,20 +11253,38 @@ impl DeleteRegistryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteRegistryError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRegistryError::AccessDenied,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteRegistryError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRegistryError::ConcurrentModification,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeleteRegistryError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRegistryError::EntityNotFound,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteRegistryError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRegistryError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10439,27 +11323,46 @@ impl DeleteResourcePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConditionCheckFailureException" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::ConditionCheckFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteResourcePolicyError::ConditionCheckFailure,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteResourcePolicyError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteResourcePolicyError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteResourcePolicyError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::OperationTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteResourcePolicyError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10497,18 +11400,38 @@ impl DeleteSchemaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteSchemaError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSchemaError::AccessDenied,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteSchemaError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSchemaError::ConcurrentModification,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeleteSchemaError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSchemaError::EntityNotFound,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteSchemaError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSchemaError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10545,20 +11468,38 @@ impl DeleteSchemaVersionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteSchemaVersionsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSchemaVersionsError::AccessDenied,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteSchemaVersionsError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSchemaVersionsError::ConcurrentModification,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeleteSchemaVersionsError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSchemaVersionsError::EntityNotFound,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteSchemaVersionsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSchemaVersionsError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10597,26 +11538,38 @@ impl DeleteSecurityConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeleteSecurityConfigurationError::EntityNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSecurityConfigurationError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteSecurityConfigurationError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSecurityConfigurationError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteSecurityConfigurationError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSecurityConfigurationError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(
-                        DeleteSecurityConfigurationError::OperationTimeout(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSecurityConfigurationError::OperationTimeout,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10653,18 +11606,38 @@ impl DeleteTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeleteTableError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTableError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteTableError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTableError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteTableError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTableError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeleteTableError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTableError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10701,18 +11674,38 @@ impl DeleteTableVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeleteTableVersionError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTableVersionError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteTableVersionError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTableVersionError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteTableVersionError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTableVersionError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeleteTableVersionError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTableVersionError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10749,20 +11742,38 @@ impl DeleteTriggerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteTriggerError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteTriggerError::ConcurrentModification,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteTriggerError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTriggerError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteTriggerError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTriggerError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeleteTriggerError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTriggerError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10799,26 +11810,38 @@ impl DeleteUserDefinedFunctionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeleteUserDefinedFunctionError::EntityNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteUserDefinedFunctionError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteUserDefinedFunctionError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteUserDefinedFunctionError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteUserDefinedFunctionError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteUserDefinedFunctionError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeleteUserDefinedFunctionError::OperationTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteUserDefinedFunctionError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10855,20 +11878,38 @@ impl DeleteWorkflowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteWorkflowError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteWorkflowError::ConcurrentModification,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeleteWorkflowError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteWorkflowError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteWorkflowError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteWorkflowError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeleteWorkflowError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteWorkflowError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10901,16 +11942,22 @@ impl GetCatalogImportStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetCatalogImportStatusError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCatalogImportStatusError::InternalService,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetCatalogImportStatusError::OperationTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCatalogImportStatusError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10941,12 +11988,22 @@ impl GetClassifierError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetClassifierError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetClassifierError::EntityNotFound,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetClassifierError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetClassifierError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10975,9 +12032,14 @@ impl GetClassifiersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetClassifiersError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetClassifiersError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11015,31 +12077,46 @@ impl GetColumnStatisticsForPartitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(
-                        GetColumnStatisticsForPartitionError::EntityNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetColumnStatisticsForPartitionError::EntityNotFound,
                     )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(
-                        GetColumnStatisticsForPartitionError::GlueEncryption(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetColumnStatisticsForPartitionError::GlueEncryption,
                     )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(
-                        GetColumnStatisticsForPartitionError::InternalService(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetColumnStatisticsForPartitionError::InternalService,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
-                        GetColumnStatisticsForPartitionError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetColumnStatisticsForPartitionError::InvalidInput,
                     )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(
-                        GetColumnStatisticsForPartitionError::OperationTimeout(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetColumnStatisticsForPartitionError::OperationTimeout,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11089,31 +12166,46 @@ impl GetColumnStatisticsForTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetColumnStatisticsForTableError::EntityNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetColumnStatisticsForTableError::EntityNotFound,
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(GetColumnStatisticsForTableError::GlueEncryption(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetColumnStatisticsForTableError::GlueEncryption,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetColumnStatisticsForTableError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetColumnStatisticsForTableError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetColumnStatisticsForTableError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetColumnStatisticsForTableError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(
-                        GetColumnStatisticsForTableError::OperationTimeout(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetColumnStatisticsForTableError::OperationTimeout,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11151,18 +12243,38 @@ impl GetConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetConnectionError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConnectionError::EntityNotFound,
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(GetConnectionError::GlueEncryption(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConnectionError::GlueEncryption,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetConnectionError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConnectionError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetConnectionError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConnectionError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11199,18 +12311,38 @@ impl GetConnectionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetConnectionsError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConnectionsError::EntityNotFound,
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(GetConnectionsError::GlueEncryption(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConnectionsError::GlueEncryption,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetConnectionsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConnectionsError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetConnectionsError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConnectionsError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11243,12 +12375,22 @@ impl GetCrawlerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetCrawlerError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCrawlerError::EntityNotFound,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetCrawlerError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCrawlerError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11277,9 +12419,14 @@ impl GetCrawlerMetricsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetCrawlerMetricsError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCrawlerMetricsError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11307,9 +12454,14 @@ impl GetCrawlersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetCrawlersError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCrawlersError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11343,21 +12495,30 @@ impl GetDataCatalogEncryptionSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
-                        GetDataCatalogEncryptionSettingsError::InternalService(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDataCatalogEncryptionSettingsError::InternalService,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
-                        GetDataCatalogEncryptionSettingsError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDataCatalogEncryptionSettingsError::InvalidInput,
                     )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(
-                        GetDataCatalogEncryptionSettingsError::OperationTimeout(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDataCatalogEncryptionSettingsError::OperationTimeout,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11401,21 +12562,46 @@ impl GetDatabaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetDatabaseError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDatabaseError::EntityNotFound,
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(GetDatabaseError::GlueEncryption(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDatabaseError::GlueEncryption,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetDatabaseError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDatabaseError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDatabaseError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDatabaseError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetDatabaseError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDatabaseError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11453,18 +12639,38 @@ impl GetDatabasesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(GetDatabasesError::GlueEncryption(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDatabasesError::GlueEncryption,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetDatabasesError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDatabasesError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDatabasesError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDatabasesError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetDatabasesError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDatabasesError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11499,15 +12705,30 @@ impl GetDataflowGraphError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetDataflowGraphError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDataflowGraphError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDataflowGraphError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDataflowGraphError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetDataflowGraphError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDataflowGraphError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11543,18 +12764,38 @@ impl GetDevEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetDevEndpointError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDevEndpointError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetDevEndpointError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDevEndpointError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDevEndpointError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDevEndpointError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetDevEndpointError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDevEndpointError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11591,18 +12832,38 @@ impl GetDevEndpointsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetDevEndpointsError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDevEndpointsError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetDevEndpointsError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDevEndpointsError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDevEndpointsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDevEndpointsError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetDevEndpointsError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDevEndpointsError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11639,18 +12900,38 @@ impl GetJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetJobError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetJobError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetJobError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetJobError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11687,18 +12968,38 @@ impl GetJobBookmarkError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetJobBookmarkError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobBookmarkError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetJobBookmarkError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobBookmarkError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetJobBookmarkError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobBookmarkError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetJobBookmarkError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobBookmarkError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11735,18 +13036,38 @@ impl GetJobRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetJobRunError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobRunError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetJobRunError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobRunError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetJobRunError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobRunError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetJobRunError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobRunError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11783,18 +13104,38 @@ impl GetJobRunsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetJobRunsError::EntityNotFound(err.msg))
-                }
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobRunsError::EntityNotFound,
+                    )
+                }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetJobRunsError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobRunsError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetJobRunsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobRunsError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetJobRunsError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobRunsError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11831,18 +13172,38 @@ impl GetJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetJobsError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobsError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetJobsError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobsError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetJobsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobsError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetJobsError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobsError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11879,18 +13240,38 @@ impl GetMLTaskRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetMLTaskRunError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMLTaskRunError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetMLTaskRunError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMLTaskRunError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetMLTaskRunError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMLTaskRunError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetMLTaskRunError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMLTaskRunError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11927,18 +13308,38 @@ impl GetMLTaskRunsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetMLTaskRunsError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMLTaskRunsError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetMLTaskRunsError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMLTaskRunsError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetMLTaskRunsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMLTaskRunsError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetMLTaskRunsError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMLTaskRunsError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11975,18 +13376,38 @@ impl GetMLTransformError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetMLTransformError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMLTransformError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetMLTransformError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMLTransformError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetMLTransformError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMLTransformError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetMLTransformError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMLTransformError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12023,18 +13444,38 @@ impl GetMLTransformsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetMLTransformsError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMLTransformsError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetMLTransformsError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMLTransformsError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetMLTransformsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMLTransformsError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetMLTransformsError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMLTransformsError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12071,18 +13512,38 @@ impl GetMappingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetMappingError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMappingError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetMappingError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMappingError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetMappingError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMappingError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetMappingError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMappingError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12121,21 +13582,46 @@ impl GetPartitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetPartitionError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPartitionError::EntityNotFound,
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(GetPartitionError::GlueEncryption(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPartitionError::GlueEncryption,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetPartitionError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPartitionError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetPartitionError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPartitionError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetPartitionError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPartitionError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12175,23 +13661,46 @@ impl GetPartitionIndexesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(GetPartitionIndexesError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPartitionIndexesError::Conflict,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetPartitionIndexesError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPartitionIndexesError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetPartitionIndexesError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPartitionIndexesError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetPartitionIndexesError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPartitionIndexesError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetPartitionIndexesError::OperationTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetPartitionIndexesError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12231,21 +13740,46 @@ impl GetPartitionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetPartitionsError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPartitionsError::EntityNotFound,
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(GetPartitionsError::GlueEncryption(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPartitionsError::GlueEncryption,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetPartitionsError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPartitionsError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetPartitionsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPartitionsError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetPartitionsError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPartitionsError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12281,15 +13815,30 @@ impl GetPlanError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetPlanError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPlanError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetPlanError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPlanError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetPlanError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPlanError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12325,18 +13874,38 @@ impl GetRegistryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetRegistryError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRegistryError::AccessDenied,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetRegistryError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRegistryError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetRegistryError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRegistryError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetRegistryError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRegistryError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12373,20 +13942,38 @@ impl GetResourcePoliciesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(GetResourcePoliciesError::GlueEncryption(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourcePoliciesError::GlueEncryption,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetResourcePoliciesError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourcePoliciesError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetResourcePoliciesError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourcePoliciesError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetResourcePoliciesError::OperationTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetResourcePoliciesError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12423,18 +14010,38 @@ impl GetResourcePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetResourcePolicyError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourcePolicyError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetResourcePolicyError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourcePolicyError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetResourcePolicyError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourcePolicyError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetResourcePolicyError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourcePolicyError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12471,18 +14078,38 @@ impl GetSchemaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetSchemaError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSchemaError::AccessDenied,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetSchemaError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSchemaError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetSchemaError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSchemaError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetSchemaError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSchemaError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12519,22 +14146,38 @@ impl GetSchemaByDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetSchemaByDefinitionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSchemaByDefinitionError::AccessDenied,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetSchemaByDefinitionError::EntityNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSchemaByDefinitionError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetSchemaByDefinitionError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSchemaByDefinitionError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetSchemaByDefinitionError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSchemaByDefinitionError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12571,18 +14214,38 @@ impl GetSchemaVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetSchemaVersionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSchemaVersionError::AccessDenied,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetSchemaVersionError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSchemaVersionError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetSchemaVersionError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSchemaVersionError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetSchemaVersionError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSchemaVersionError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12619,22 +14282,38 @@ impl GetSchemaVersionsDiffError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetSchemaVersionsDiffError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSchemaVersionsDiffError::AccessDenied,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetSchemaVersionsDiffError::EntityNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSchemaVersionsDiffError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetSchemaVersionsDiffError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSchemaVersionsDiffError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetSchemaVersionsDiffError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSchemaVersionsDiffError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12671,26 +14350,38 @@ impl GetSecurityConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetSecurityConfigurationError::EntityNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSecurityConfigurationError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetSecurityConfigurationError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSecurityConfigurationError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetSecurityConfigurationError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSecurityConfigurationError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetSecurityConfigurationError::OperationTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSecurityConfigurationError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12727,26 +14418,38 @@ impl GetSecurityConfigurationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetSecurityConfigurationsError::EntityNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSecurityConfigurationsError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetSecurityConfigurationsError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSecurityConfigurationsError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetSecurityConfigurationsError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSecurityConfigurationsError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetSecurityConfigurationsError::OperationTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSecurityConfigurationsError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12785,21 +14488,46 @@ impl GetTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetTableError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTableError::EntityNotFound,
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(GetTableError::GlueEncryption(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTableError::GlueEncryption,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetTableError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTableError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetTableError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTableError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetTableError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTableError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12839,21 +14567,46 @@ impl GetTableVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetTableVersionError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTableVersionError::EntityNotFound,
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(GetTableVersionError::GlueEncryption(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTableVersionError::GlueEncryption,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetTableVersionError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTableVersionError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetTableVersionError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTableVersionError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetTableVersionError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTableVersionError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12893,21 +14646,46 @@ impl GetTableVersionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetTableVersionsError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTableVersionsError::EntityNotFound,
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(GetTableVersionsError::GlueEncryption(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTableVersionsError::GlueEncryption,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetTableVersionsError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTableVersionsError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetTableVersionsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTableVersionsError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetTableVersionsError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTableVersionsError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12947,21 +14725,46 @@ impl GetTablesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetTablesError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTablesError::EntityNotFound,
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(GetTablesError::GlueEncryption(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTablesError::GlueEncryption,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetTablesError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTablesError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetTablesError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTablesError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetTablesError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTablesError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12999,18 +14802,38 @@ impl GetTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetTagsError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTagsError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetTagsError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTagsError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetTagsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTagsError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetTagsError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTagsError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13047,18 +14870,38 @@ impl GetTriggerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetTriggerError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTriggerError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetTriggerError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTriggerError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetTriggerError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTriggerError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetTriggerError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTriggerError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13095,18 +14938,38 @@ impl GetTriggersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetTriggersError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTriggersError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetTriggersError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTriggersError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetTriggersError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTriggersError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetTriggersError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTriggersError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13145,29 +15008,46 @@ impl GetUserDefinedFunctionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetUserDefinedFunctionError::EntityNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetUserDefinedFunctionError::EntityNotFound,
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(GetUserDefinedFunctionError::GlueEncryption(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetUserDefinedFunctionError::GlueEncryption,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetUserDefinedFunctionError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetUserDefinedFunctionError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetUserDefinedFunctionError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUserDefinedFunctionError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetUserDefinedFunctionError::OperationTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetUserDefinedFunctionError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13207,31 +15087,46 @@ impl GetUserDefinedFunctionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetUserDefinedFunctionsError::EntityNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetUserDefinedFunctionsError::EntityNotFound,
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(GetUserDefinedFunctionsError::GlueEncryption(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetUserDefinedFunctionsError::GlueEncryption,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetUserDefinedFunctionsError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetUserDefinedFunctionsError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetUserDefinedFunctionsError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetUserDefinedFunctionsError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetUserDefinedFunctionsError::OperationTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetUserDefinedFunctionsError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13269,18 +15164,38 @@ impl GetWorkflowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetWorkflowError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetWorkflowError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetWorkflowError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetWorkflowError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetWorkflowError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetWorkflowError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetWorkflowError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetWorkflowError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13317,18 +15232,38 @@ impl GetWorkflowRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetWorkflowRunError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetWorkflowRunError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetWorkflowRunError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetWorkflowRunError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetWorkflowRunError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetWorkflowRunError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetWorkflowRunError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetWorkflowRunError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13365,26 +15300,38 @@ impl GetWorkflowRunPropertiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetWorkflowRunPropertiesError::EntityNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetWorkflowRunPropertiesError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetWorkflowRunPropertiesError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetWorkflowRunPropertiesError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetWorkflowRunPropertiesError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetWorkflowRunPropertiesError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetWorkflowRunPropertiesError::OperationTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetWorkflowRunPropertiesError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13421,18 +15368,38 @@ impl GetWorkflowRunsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetWorkflowRunsError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetWorkflowRunsError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetWorkflowRunsError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetWorkflowRunsError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetWorkflowRunsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetWorkflowRunsError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(GetWorkflowRunsError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetWorkflowRunsError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13465,14 +15432,22 @@ impl ImportCatalogToGlueError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ImportCatalogToGlueError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportCatalogToGlueError::InternalService,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(ImportCatalogToGlueError::OperationTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ImportCatalogToGlueError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13501,9 +15476,14 @@ impl ListCrawlersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(ListCrawlersError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCrawlersError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13537,18 +15517,38 @@ impl ListDevEndpointsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(ListDevEndpointsError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDevEndpointsError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListDevEndpointsError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDevEndpointsError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListDevEndpointsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDevEndpointsError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(ListDevEndpointsError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDevEndpointsError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13585,18 +15585,38 @@ impl ListJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(ListJobsError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListJobsError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListJobsError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListJobsError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListJobsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListJobsError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(ListJobsError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListJobsError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13633,18 +15653,38 @@ impl ListMLTransformsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(ListMLTransformsError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMLTransformsError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListMLTransformsError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMLTransformsError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListMLTransformsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMLTransformsError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(ListMLTransformsError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMLTransformsError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13679,15 +15719,30 @@ impl ListRegistriesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListRegistriesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRegistriesError::AccessDenied,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListRegistriesError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRegistriesError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListRegistriesError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRegistriesError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13723,18 +15778,38 @@ impl ListSchemaVersionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListSchemaVersionsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSchemaVersionsError::AccessDenied,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(ListSchemaVersionsError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSchemaVersionsError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListSchemaVersionsError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSchemaVersionsError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListSchemaVersionsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSchemaVersionsError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13771,18 +15846,38 @@ impl ListSchemasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListSchemasError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSchemasError::AccessDenied,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(ListSchemasError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSchemasError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListSchemasError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSchemasError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListSchemasError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSchemasError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13819,18 +15914,38 @@ impl ListTriggersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(ListTriggersError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTriggersError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListTriggersError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTriggersError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListTriggersError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTriggersError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(ListTriggersError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTriggersError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13865,15 +15980,30 @@ impl ListWorkflowsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListWorkflowsError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListWorkflowsError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListWorkflowsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListWorkflowsError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(ListWorkflowsError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListWorkflowsError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13909,21 +16039,30 @@ impl PutDataCatalogEncryptionSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(
-                        PutDataCatalogEncryptionSettingsError::InternalService(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutDataCatalogEncryptionSettingsError::InternalService,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
-                        PutDataCatalogEncryptionSettingsError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutDataCatalogEncryptionSettingsError::InvalidInput,
                     )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(
-                        PutDataCatalogEncryptionSettingsError::OperationTimeout(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutDataCatalogEncryptionSettingsError::OperationTimeout,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13967,23 +16106,46 @@ impl PutResourcePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConditionCheckFailureException" => {
-                    return RusotoError::Service(PutResourcePolicyError::ConditionCheckFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutResourcePolicyError::ConditionCheckFailure,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(PutResourcePolicyError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutResourcePolicyError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(PutResourcePolicyError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutResourcePolicyError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(PutResourcePolicyError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutResourcePolicyError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(PutResourcePolicyError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutResourcePolicyError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14023,31 +16185,46 @@ impl PutSchemaVersionMetadataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(PutSchemaVersionMetadataError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutSchemaVersionMetadataError::AccessDenied,
+                    )
                 }
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(PutSchemaVersionMetadataError::AlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutSchemaVersionMetadataError::AlreadyExists,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(PutSchemaVersionMetadataError::EntityNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutSchemaVersionMetadataError::EntityNotFound,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(PutSchemaVersionMetadataError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutSchemaVersionMetadataError::InvalidInput,
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(
-                        PutSchemaVersionMetadataError::ResourceNumberLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSchemaVersionMetadataError::ResourceNumberLimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14093,41 +16270,62 @@ impl PutWorkflowRunPropertiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(PutWorkflowRunPropertiesError::AlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutWorkflowRunPropertiesError::AlreadyExists,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        PutWorkflowRunPropertiesError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutWorkflowRunPropertiesError::ConcurrentModification,
                     )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(PutWorkflowRunPropertiesError::EntityNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutWorkflowRunPropertiesError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(PutWorkflowRunPropertiesError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutWorkflowRunPropertiesError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(PutWorkflowRunPropertiesError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutWorkflowRunPropertiesError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(PutWorkflowRunPropertiesError::OperationTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutWorkflowRunPropertiesError::OperationTimeout,
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(
-                        PutWorkflowRunPropertiesError::ResourceNumberLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutWorkflowRunPropertiesError::ResourceNumberLimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14171,21 +16369,30 @@ impl QuerySchemaVersionMetadataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(QuerySchemaVersionMetadataError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        QuerySchemaVersionMetadataError::AccessDenied,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(QuerySchemaVersionMetadataError::EntityNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        QuerySchemaVersionMetadataError::EntityNotFound,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(QuerySchemaVersionMetadataError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        QuerySchemaVersionMetadataError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14225,32 +16432,54 @@ impl RegisterSchemaVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RegisterSchemaVersionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterSchemaVersionError::AccessDenied,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        RegisterSchemaVersionError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterSchemaVersionError::ConcurrentModification,
                     )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(RegisterSchemaVersionError::EntityNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterSchemaVersionError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(RegisterSchemaVersionError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterSchemaVersionError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(RegisterSchemaVersionError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterSchemaVersionError::InvalidInput,
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(
-                        RegisterSchemaVersionError::ResourceNumberLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterSchemaVersionError::ResourceNumberLimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14291,21 +16520,30 @@ impl RemoveSchemaVersionMetadataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RemoveSchemaVersionMetadataError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveSchemaVersionMetadataError::AccessDenied,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(RemoveSchemaVersionMetadataError::EntityNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveSchemaVersionMetadataError::EntityNotFound,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(RemoveSchemaVersionMetadataError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveSchemaVersionMetadataError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14341,18 +16579,38 @@ impl ResetJobBookmarkError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(ResetJobBookmarkError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResetJobBookmarkError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ResetJobBookmarkError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResetJobBookmarkError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ResetJobBookmarkError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResetJobBookmarkError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(ResetJobBookmarkError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResetJobBookmarkError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14393,28 +16651,54 @@ impl ResumeWorkflowRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentRunsExceededException" => {
-                    return RusotoError::Service(ResumeWorkflowRunError::ConcurrentRunsExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ResumeWorkflowRunError::ConcurrentRunsExceeded,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(ResumeWorkflowRunError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResumeWorkflowRunError::EntityNotFound,
+                    )
                 }
                 "IllegalWorkflowStateException" => {
-                    return RusotoError::Service(ResumeWorkflowRunError::IllegalWorkflowState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ResumeWorkflowRunError::IllegalWorkflowState,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ResumeWorkflowRunError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResumeWorkflowRunError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ResumeWorkflowRunError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResumeWorkflowRunError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(ResumeWorkflowRunError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResumeWorkflowRunError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14451,15 +16735,30 @@ impl SearchTablesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(SearchTablesError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchTablesError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(SearchTablesError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchTablesError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(SearchTablesError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchTablesError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14493,15 +16792,30 @@ impl StartCrawlerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CrawlerRunningException" => {
-                    return RusotoError::Service(StartCrawlerError::CrawlerRunning(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartCrawlerError::CrawlerRunning,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(StartCrawlerError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartCrawlerError::EntityNotFound,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(StartCrawlerError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartCrawlerError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14539,27 +16853,46 @@ impl StartCrawlerScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(StartCrawlerScheduleError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartCrawlerScheduleError::EntityNotFound,
+                    )
                 }
                 "NoScheduleException" => {
-                    return RusotoError::Service(StartCrawlerScheduleError::NoSchedule(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartCrawlerScheduleError::NoSchedule,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(StartCrawlerScheduleError::OperationTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartCrawlerScheduleError::OperationTimeout,
+                    )
                 }
                 "SchedulerRunningException" => {
-                    return RusotoError::Service(StartCrawlerScheduleError::SchedulerRunning(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartCrawlerScheduleError::SchedulerRunning,
+                    )
                 }
                 "SchedulerTransitioningException" => {
-                    return RusotoError::Service(StartCrawlerScheduleError::SchedulerTransitioning(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartCrawlerScheduleError::SchedulerTransitioning,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14597,26 +16930,38 @@ impl StartExportLabelsTaskRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(StartExportLabelsTaskRunError::EntityNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartExportLabelsTaskRunError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartExportLabelsTaskRunError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartExportLabelsTaskRunError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StartExportLabelsTaskRunError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartExportLabelsTaskRunError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(StartExportLabelsTaskRunError::OperationTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartExportLabelsTaskRunError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14655,31 +17000,46 @@ impl StartImportLabelsTaskRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(StartImportLabelsTaskRunError::EntityNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartImportLabelsTaskRunError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartImportLabelsTaskRunError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartImportLabelsTaskRunError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StartImportLabelsTaskRunError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartImportLabelsTaskRunError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(StartImportLabelsTaskRunError::OperationTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartImportLabelsTaskRunError::OperationTimeout,
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(
-                        StartImportLabelsTaskRunError::ResourceNumberLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartImportLabelsTaskRunError::ResourceNumberLimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14723,26 +17083,54 @@ impl StartJobRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentRunsExceededException" => {
-                    return RusotoError::Service(StartJobRunError::ConcurrentRunsExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartJobRunError::ConcurrentRunsExceeded,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(StartJobRunError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartJobRunError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartJobRunError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartJobRunError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StartJobRunError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartJobRunError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(StartJobRunError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartJobRunError::OperationTimeout,
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(StartJobRunError::ResourceNumberLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartJobRunError::ResourceNumberLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14785,36 +17173,54 @@ impl StartMLEvaluationTaskRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentRunsExceededException" => {
-                    return RusotoError::Service(
-                        StartMLEvaluationTaskRunError::ConcurrentRunsExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartMLEvaluationTaskRunError::ConcurrentRunsExceeded,
                     )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(StartMLEvaluationTaskRunError::EntityNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartMLEvaluationTaskRunError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartMLEvaluationTaskRunError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartMLEvaluationTaskRunError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StartMLEvaluationTaskRunError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartMLEvaluationTaskRunError::InvalidInput,
+                    )
                 }
                 "MLTransformNotReadyException" => {
-                    return RusotoError::Service(
-                        StartMLEvaluationTaskRunError::MLTransformNotReady(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartMLEvaluationTaskRunError::MLTransformNotReady,
                     )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(StartMLEvaluationTaskRunError::OperationTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartMLEvaluationTaskRunError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14859,31 +17265,46 @@ impl StartMLLabelingSetGenerationTaskRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentRunsExceededException" => {
-                    return RusotoError::Service(
-                        StartMLLabelingSetGenerationTaskRunError::ConcurrentRunsExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartMLLabelingSetGenerationTaskRunError::ConcurrentRunsExceeded,
                     )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(
-                        StartMLLabelingSetGenerationTaskRunError::EntityNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartMLLabelingSetGenerationTaskRunError::EntityNotFound,
                     )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(
-                        StartMLLabelingSetGenerationTaskRunError::InternalService(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartMLLabelingSetGenerationTaskRunError::InternalService,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
-                        StartMLLabelingSetGenerationTaskRunError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartMLLabelingSetGenerationTaskRunError::InvalidInput,
                     )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(
-                        StartMLLabelingSetGenerationTaskRunError::OperationTimeout(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartMLLabelingSetGenerationTaskRunError::OperationTimeout,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14935,26 +17356,54 @@ impl StartTriggerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentRunsExceededException" => {
-                    return RusotoError::Service(StartTriggerError::ConcurrentRunsExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartTriggerError::ConcurrentRunsExceeded,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(StartTriggerError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartTriggerError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartTriggerError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartTriggerError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StartTriggerError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartTriggerError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(StartTriggerError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartTriggerError::OperationTimeout,
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(StartTriggerError::ResourceNumberLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartTriggerError::ResourceNumberLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14997,28 +17446,54 @@ impl StartWorkflowRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentRunsExceededException" => {
-                    return RusotoError::Service(StartWorkflowRunError::ConcurrentRunsExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartWorkflowRunError::ConcurrentRunsExceeded,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(StartWorkflowRunError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartWorkflowRunError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartWorkflowRunError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartWorkflowRunError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StartWorkflowRunError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartWorkflowRunError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(StartWorkflowRunError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartWorkflowRunError::OperationTimeout,
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(
-                        StartWorkflowRunError::ResourceNumberLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartWorkflowRunError::ResourceNumberLimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15057,18 +17532,38 @@ impl StopCrawlerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CrawlerNotRunningException" => {
-                    return RusotoError::Service(StopCrawlerError::CrawlerNotRunning(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopCrawlerError::CrawlerNotRunning,
+                    )
                 }
                 "CrawlerStoppingException" => {
-                    return RusotoError::Service(StopCrawlerError::CrawlerStopping(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopCrawlerError::CrawlerStopping,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(StopCrawlerError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopCrawlerError::EntityNotFound,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(StopCrawlerError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopCrawlerError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15105,24 +17600,38 @@ impl StopCrawlerScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(StopCrawlerScheduleError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopCrawlerScheduleError::EntityNotFound,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(StopCrawlerScheduleError::OperationTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopCrawlerScheduleError::OperationTimeout,
+                    )
                 }
                 "SchedulerNotRunningException" => {
-                    return RusotoError::Service(StopCrawlerScheduleError::SchedulerNotRunning(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopCrawlerScheduleError::SchedulerNotRunning,
+                    )
                 }
                 "SchedulerTransitioningException" => {
-                    return RusotoError::Service(StopCrawlerScheduleError::SchedulerTransitioning(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopCrawlerScheduleError::SchedulerTransitioning,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15161,21 +17670,46 @@ impl StopTriggerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(StopTriggerError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopTriggerError::ConcurrentModification,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(StopTriggerError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopTriggerError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(StopTriggerError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopTriggerError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StopTriggerError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopTriggerError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(StopTriggerError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopTriggerError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15215,23 +17749,46 @@ impl StopWorkflowRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(StopWorkflowRunError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopWorkflowRunError::EntityNotFound,
+                    )
                 }
                 "IllegalWorkflowStateException" => {
-                    return RusotoError::Service(StopWorkflowRunError::IllegalWorkflowState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopWorkflowRunError::IllegalWorkflowState,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(StopWorkflowRunError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopWorkflowRunError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StopWorkflowRunError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopWorkflowRunError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(StopWorkflowRunError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopWorkflowRunError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15269,18 +17826,38 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(TagResourceError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(TagResourceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(TagResourceError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15317,18 +17894,38 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UntagResourceError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UntagResourceError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15365,18 +17962,38 @@ impl UpdateClassifierError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateClassifierError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateClassifierError::EntityNotFound,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateClassifierError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateClassifierError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdateClassifierError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateClassifierError::OperationTimeout,
+                    )
                 }
                 "VersionMismatchException" => {
-                    return RusotoError::Service(UpdateClassifierError::VersionMismatch(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateClassifierError::VersionMismatch,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15417,31 +18034,46 @@ impl UpdateColumnStatisticsForPartitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateColumnStatisticsForPartitionError::EntityNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateColumnStatisticsForPartitionError::EntityNotFound,
                     )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(
-                        UpdateColumnStatisticsForPartitionError::GlueEncryption(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateColumnStatisticsForPartitionError::GlueEncryption,
                     )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(
-                        UpdateColumnStatisticsForPartitionError::InternalService(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateColumnStatisticsForPartitionError::InternalService,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
-                        UpdateColumnStatisticsForPartitionError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateColumnStatisticsForPartitionError::InvalidInput,
                     )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(
-                        UpdateColumnStatisticsForPartitionError::OperationTimeout(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateColumnStatisticsForPartitionError::OperationTimeout,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15493,31 +18125,46 @@ impl UpdateColumnStatisticsForTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateColumnStatisticsForTableError::EntityNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateColumnStatisticsForTableError::EntityNotFound,
                     )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(
-                        UpdateColumnStatisticsForTableError::GlueEncryption(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateColumnStatisticsForTableError::GlueEncryption,
                     )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(
-                        UpdateColumnStatisticsForTableError::InternalService(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateColumnStatisticsForTableError::InternalService,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateColumnStatisticsForTableError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateColumnStatisticsForTableError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(
-                        UpdateColumnStatisticsForTableError::OperationTimeout(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateColumnStatisticsForTableError::OperationTimeout,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15563,18 +18210,38 @@ impl UpdateConnectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateConnectionError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateConnectionError::EntityNotFound,
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(UpdateConnectionError::GlueEncryption(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateConnectionError::GlueEncryption,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateConnectionError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateConnectionError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdateConnectionError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateConnectionError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15613,21 +18280,46 @@ impl UpdateCrawlerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CrawlerRunningException" => {
-                    return RusotoError::Service(UpdateCrawlerError::CrawlerRunning(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCrawlerError::CrawlerRunning,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateCrawlerError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCrawlerError::EntityNotFound,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateCrawlerError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCrawlerError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdateCrawlerError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCrawlerError::OperationTimeout,
+                    )
                 }
                 "VersionMismatchException" => {
-                    return RusotoError::Service(UpdateCrawlerError::VersionMismatch(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCrawlerError::VersionMismatch,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15667,29 +18359,46 @@ impl UpdateCrawlerScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateCrawlerScheduleError::EntityNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateCrawlerScheduleError::EntityNotFound,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateCrawlerScheduleError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCrawlerScheduleError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdateCrawlerScheduleError::OperationTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateCrawlerScheduleError::OperationTimeout,
+                    )
                 }
                 "SchedulerTransitioningException" => {
-                    return RusotoError::Service(
-                        UpdateCrawlerScheduleError::SchedulerTransitioning(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCrawlerScheduleError::SchedulerTransitioning,
                     )
                 }
                 "VersionMismatchException" => {
-                    return RusotoError::Service(UpdateCrawlerScheduleError::VersionMismatch(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateCrawlerScheduleError::VersionMismatch,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15729,21 +18438,46 @@ impl UpdateDatabaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateDatabaseError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDatabaseError::EntityNotFound,
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(UpdateDatabaseError::GlueEncryption(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDatabaseError::GlueEncryption,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateDatabaseError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDatabaseError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateDatabaseError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDatabaseError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdateDatabaseError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDatabaseError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15781,18 +18515,38 @@ impl UpdateDevEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateDevEndpointError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDevEndpointError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateDevEndpointError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDevEndpointError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateDevEndpointError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDevEndpointError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdateDevEndpointError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDevEndpointError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15831,21 +18585,46 @@ impl UpdateJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateJobError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateJobError::ConcurrentModification,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateJobError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateJobError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateJobError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateJobError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateJobError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateJobError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdateJobError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateJobError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15885,21 +18664,46 @@ impl UpdateMLTransformError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateMLTransformError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMLTransformError::AccessDenied,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateMLTransformError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMLTransformError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateMLTransformError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMLTransformError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateMLTransformError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMLTransformError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdateMLTransformError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMLTransformError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15939,21 +18743,46 @@ impl UpdatePartitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdatePartitionError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePartitionError::EntityNotFound,
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(UpdatePartitionError::GlueEncryption(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePartitionError::GlueEncryption,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdatePartitionError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePartitionError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdatePartitionError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePartitionError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdatePartitionError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePartitionError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15993,23 +18822,46 @@ impl UpdateRegistryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateRegistryError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRegistryError::AccessDenied,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateRegistryError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRegistryError::ConcurrentModification,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateRegistryError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRegistryError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateRegistryError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRegistryError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateRegistryError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRegistryError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16049,21 +18901,46 @@ impl UpdateSchemaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateSchemaError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSchemaError::AccessDenied,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateSchemaError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSchemaError::ConcurrentModification,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateSchemaError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSchemaError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateSchemaError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSchemaError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateSchemaError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSchemaError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16107,29 +18984,62 @@ impl UpdateTableError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateTableError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTableError::ConcurrentModification,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateTableError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTableError::EntityNotFound,
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(UpdateTableError::GlueEncryption(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTableError::GlueEncryption,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateTableError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTableError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateTableError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTableError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdateTableError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTableError::OperationTimeout,
+                    )
                 }
                 "ResourceNumberLimitExceededException" => {
-                    return RusotoError::Service(UpdateTableError::ResourceNumberLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateTableError::ResourceNumberLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16171,23 +19081,46 @@ impl UpdateTriggerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateTriggerError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateTriggerError::ConcurrentModification,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateTriggerError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTriggerError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateTriggerError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTriggerError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateTriggerError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTriggerError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdateTriggerError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTriggerError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16227,31 +19160,46 @@ impl UpdateUserDefinedFunctionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateUserDefinedFunctionError::EntityNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserDefinedFunctionError::EntityNotFound,
+                    )
                 }
                 "GlueEncryptionException" => {
-                    return RusotoError::Service(UpdateUserDefinedFunctionError::GlueEncryption(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserDefinedFunctionError::GlueEncryption,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateUserDefinedFunctionError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserDefinedFunctionError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateUserDefinedFunctionError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserDefinedFunctionError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdateUserDefinedFunctionError::OperationTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserDefinedFunctionError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16291,23 +19239,46 @@ impl UpdateWorkflowError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateWorkflowError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateWorkflowError::ConcurrentModification,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateWorkflowError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateWorkflowError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateWorkflowError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateWorkflowError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateWorkflowError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateWorkflowError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdateWorkflowError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateWorkflowError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17306,11 +20277,12 @@ pub struct GlueClient {
 impl GlueClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> GlueClient {
         GlueClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Glue"),
         }
     }
 
@@ -17325,12 +20297,32 @@ impl GlueClient {
     {
         GlueClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Glue"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> GlueClient {
-        GlueClient { client, region }
+        GlueClient {
+            client,
+            region: region_for_service(region, "Glue"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> GlueClient {
+        GlueClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Glue"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> GlueClient {
+        GlueClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -17342,6 +20334,7 @@ impl Glue for GlueClient {
         input: BatchCreatePartitionRequest,
     ) -> Result<BatchCreatePartitionResponse, RusotoError<BatchCreatePartitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchCreatePartition");
         request.add_header("x-amz-target", "AWSGlue.BatchCreatePartition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17361,6 +20354,7 @@ impl Glue for GlueClient {
         input: BatchDeleteConnectionRequest,
     ) -> Result<BatchDeleteConnectionResponse, RusotoError<BatchDeleteConnectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchDeleteConnection");
         request.add_header("x-amz-target", "AWSGlue.BatchDeleteConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17380,6 +20374,7 @@ impl Glue for GlueClient {
         input: BatchDeletePartitionRequest,
     ) -> Result<BatchDeletePartitionResponse, RusotoError<BatchDeletePartitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchDeletePartition");
         request.add_header("x-amz-target", "AWSGlue.BatchDeletePartition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17399,6 +20394,7 @@ impl Glue for GlueClient {
         input: BatchDeleteTableRequest,
     ) -> Result<BatchDeleteTableResponse, RusotoError<BatchDeleteTableError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchDeleteTable");
         request.add_header("x-amz-target", "AWSGlue.BatchDeleteTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17417,6 +20413,7 @@ impl Glue for GlueClient {
         input: BatchDeleteTableVersionRequest,
     ) -> Result<BatchDeleteTableVersionResponse, RusotoError<BatchDeleteTableVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchDeleteTableVersion");
         request.add_header("x-amz-target", "AWSGlue.BatchDeleteTableVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17436,6 +20433,7 @@ impl Glue for GlueClient {
         input: BatchGetCrawlersRequest,
     ) -> Result<BatchGetCrawlersResponse, RusotoError<BatchGetCrawlersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchGetCrawlers");
         request.add_header("x-amz-target", "AWSGlue.BatchGetCrawlers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17454,6 +20452,7 @@ impl Glue for GlueClient {
         input: BatchGetDevEndpointsRequest,
     ) -> Result<BatchGetDevEndpointsResponse, RusotoError<BatchGetDevEndpointsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchGetDevEndpoints");
         request.add_header("x-amz-target", "AWSGlue.BatchGetDevEndpoints");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17473,6 +20472,7 @@ impl Glue for GlueClient {
         input: BatchGetJobsRequest,
     ) -> Result<BatchGetJobsResponse, RusotoError<BatchGetJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchGetJobs");
         request.add_header("x-amz-target", "AWSGlue.BatchGetJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17491,6 +20491,7 @@ impl Glue for GlueClient {
         input: BatchGetPartitionRequest,
     ) -> Result<BatchGetPartitionResponse, RusotoError<BatchGetPartitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchGetPartition");
         request.add_header("x-amz-target", "AWSGlue.BatchGetPartition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17509,6 +20510,7 @@ impl Glue for GlueClient {
         input: BatchGetTriggersRequest,
     ) -> Result<BatchGetTriggersResponse, RusotoError<BatchGetTriggersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchGetTriggers");
         request.add_header("x-amz-target", "AWSGlue.BatchGetTriggers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17527,6 +20529,7 @@ impl Glue for GlueClient {
         input: BatchGetWorkflowsRequest,
     ) -> Result<BatchGetWorkflowsResponse, RusotoError<BatchGetWorkflowsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchGetWorkflows");
         request.add_header("x-amz-target", "AWSGlue.BatchGetWorkflows");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17545,6 +20548,7 @@ impl Glue for GlueClient {
         input: BatchStopJobRunRequest,
     ) -> Result<BatchStopJobRunResponse, RusotoError<GlueBatchStopJobRunError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchStopJobRun");
         request.add_header("x-amz-target", "AWSGlue.BatchStopJobRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17563,6 +20567,7 @@ impl Glue for GlueClient {
         input: BatchUpdatePartitionRequest,
     ) -> Result<BatchUpdatePartitionResponse, RusotoError<BatchUpdatePartitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchUpdatePartition");
         request.add_header("x-amz-target", "AWSGlue.BatchUpdatePartition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17582,6 +20587,7 @@ impl Glue for GlueClient {
         input: CancelMLTaskRunRequest,
     ) -> Result<CancelMLTaskRunResponse, RusotoError<CancelMLTaskRunError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CancelMLTaskRun");
         request.add_header("x-amz-target", "AWSGlue.CancelMLTaskRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17601,6 +20607,7 @@ impl Glue for GlueClient {
     ) -> Result<CheckSchemaVersionValidityResponse, RusotoError<CheckSchemaVersionValidityError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CheckSchemaVersionValidity");
         request.add_header("x-amz-target", "AWSGlue.CheckSchemaVersionValidity");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17620,6 +20627,7 @@ impl Glue for GlueClient {
         input: CreateClassifierRequest,
     ) -> Result<CreateClassifierResponse, RusotoError<CreateClassifierError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateClassifier");
         request.add_header("x-amz-target", "AWSGlue.CreateClassifier");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17638,6 +20646,7 @@ impl Glue for GlueClient {
         input: CreateConnectionRequest,
     ) -> Result<CreateConnectionResponse, RusotoError<CreateConnectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateConnection");
         request.add_header("x-amz-target", "AWSGlue.CreateConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17656,6 +20665,7 @@ impl Glue for GlueClient {
         input: CreateCrawlerRequest,
     ) -> Result<CreateCrawlerResponse, RusotoError<CreateCrawlerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateCrawler");
         request.add_header("x-amz-target", "AWSGlue.CreateCrawler");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17674,6 +20684,7 @@ impl Glue for GlueClient {
         input: CreateDatabaseRequest,
     ) -> Result<CreateDatabaseResponse, RusotoError<CreateDatabaseError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateDatabase");
         request.add_header("x-amz-target", "AWSGlue.CreateDatabase");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17692,6 +20703,7 @@ impl Glue for GlueClient {
         input: CreateDevEndpointRequest,
     ) -> Result<CreateDevEndpointResponse, RusotoError<CreateDevEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateDevEndpoint");
         request.add_header("x-amz-target", "AWSGlue.CreateDevEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17710,6 +20722,7 @@ impl Glue for GlueClient {
         input: CreateJobRequest,
     ) -> Result<CreateJobResponse, RusotoError<CreateJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateJob");
         request.add_header("x-amz-target", "AWSGlue.CreateJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17728,6 +20741,7 @@ impl Glue for GlueClient {
         input: CreateMLTransformRequest,
     ) -> Result<CreateMLTransformResponse, RusotoError<CreateMLTransformError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateMLTransform");
         request.add_header("x-amz-target", "AWSGlue.CreateMLTransform");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17746,6 +20760,7 @@ impl Glue for GlueClient {
         input: CreatePartitionRequest,
     ) -> Result<CreatePartitionResponse, RusotoError<CreatePartitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreatePartition");
         request.add_header("x-amz-target", "AWSGlue.CreatePartition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17764,6 +20779,7 @@ impl Glue for GlueClient {
         input: CreatePartitionIndexRequest,
     ) -> Result<CreatePartitionIndexResponse, RusotoError<CreatePartitionIndexError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreatePartitionIndex");
         request.add_header("x-amz-target", "AWSGlue.CreatePartitionIndex");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17783,6 +20799,7 @@ impl Glue for GlueClient {
         input: CreateRegistryInput,
     ) -> Result<CreateRegistryResponse, RusotoError<CreateRegistryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateRegistry");
         request.add_header("x-amz-target", "AWSGlue.CreateRegistry");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17801,6 +20818,7 @@ impl Glue for GlueClient {
         input: CreateSchemaInput,
     ) -> Result<CreateSchemaResponse, RusotoError<CreateSchemaError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateSchema");
         request.add_header("x-amz-target", "AWSGlue.CreateSchema");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17819,6 +20837,7 @@ impl Glue for GlueClient {
         input: CreateScriptRequest,
     ) -> Result<CreateScriptResponse, RusotoError<CreateScriptError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateScript");
         request.add_header("x-amz-target", "AWSGlue.CreateScript");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17838,6 +20857,7 @@ impl Glue for GlueClient {
     ) -> Result<CreateSecurityConfigurationResponse, RusotoError<CreateSecurityConfigurationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateSecurityConfiguration");
         request.add_header("x-amz-target", "AWSGlue.CreateSecurityConfiguration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17857,6 +20877,7 @@ impl Glue for GlueClient {
         input: CreateTableRequest,
     ) -> Result<CreateTableResponse, RusotoError<CreateTableError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateTable");
         request.add_header("x-amz-target", "AWSGlue.CreateTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17875,6 +20896,7 @@ impl Glue for GlueClient {
         input: CreateTriggerRequest,
     ) -> Result<CreateTriggerResponse, RusotoError<CreateTriggerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateTrigger");
         request.add_header("x-amz-target", "AWSGlue.CreateTrigger");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17894,6 +20916,7 @@ impl Glue for GlueClient {
     ) -> Result<CreateUserDefinedFunctionResponse, RusotoError<CreateUserDefinedFunctionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateUserDefinedFunction");
         request.add_header("x-amz-target", "AWSGlue.CreateUserDefinedFunction");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17913,6 +20936,7 @@ impl Glue for GlueClient {
         input: CreateWorkflowRequest,
     ) -> Result<CreateWorkflowResponse, RusotoError<CreateWorkflowError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateWorkflow");
         request.add_header("x-amz-target", "AWSGlue.CreateWorkflow");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17931,6 +20955,7 @@ impl Glue for GlueClient {
         input: DeleteClassifierRequest,
     ) -> Result<DeleteClassifierResponse, RusotoError<DeleteClassifierError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteClassifier");
         request.add_header("x-amz-target", "AWSGlue.DeleteClassifier");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17952,6 +20977,7 @@ impl Glue for GlueClient {
         RusotoError<DeleteColumnStatisticsForPartitionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteColumnStatisticsForPartition");
         request.add_header("x-amz-target", "AWSGlue.DeleteColumnStatisticsForPartition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17977,6 +21003,7 @@ impl Glue for GlueClient {
         RusotoError<DeleteColumnStatisticsForTableError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteColumnStatisticsForTable");
         request.add_header("x-amz-target", "AWSGlue.DeleteColumnStatisticsForTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17996,6 +21023,7 @@ impl Glue for GlueClient {
         input: DeleteConnectionRequest,
     ) -> Result<DeleteConnectionResponse, RusotoError<DeleteConnectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteConnection");
         request.add_header("x-amz-target", "AWSGlue.DeleteConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18014,6 +21042,7 @@ impl Glue for GlueClient {
         input: DeleteCrawlerRequest,
     ) -> Result<DeleteCrawlerResponse, RusotoError<DeleteCrawlerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteCrawler");
         request.add_header("x-amz-target", "AWSGlue.DeleteCrawler");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18032,6 +21061,7 @@ impl Glue for GlueClient {
         input: DeleteDatabaseRequest,
     ) -> Result<DeleteDatabaseResponse, RusotoError<DeleteDatabaseError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteDatabase");
         request.add_header("x-amz-target", "AWSGlue.DeleteDatabase");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18050,6 +21080,7 @@ impl Glue for GlueClient {
         input: DeleteDevEndpointRequest,
     ) -> Result<DeleteDevEndpointResponse, RusotoError<DeleteDevEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteDevEndpoint");
         request.add_header("x-amz-target", "AWSGlue.DeleteDevEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18068,6 +21099,7 @@ impl Glue for GlueClient {
         input: DeleteJobRequest,
     ) -> Result<DeleteJobResponse, RusotoError<DeleteJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteJob");
         request.add_header("x-amz-target", "AWSGlue.DeleteJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18086,6 +21118,7 @@ impl Glue for GlueClient {
         input: DeleteMLTransformRequest,
     ) -> Result<DeleteMLTransformResponse, RusotoError<DeleteMLTransformError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteMLTransform");
         request.add_header("x-amz-target", "AWSGlue.DeleteMLTransform");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18104,6 +21137,7 @@ impl Glue for GlueClient {
         input: DeletePartitionRequest,
     ) -> Result<DeletePartitionResponse, RusotoError<DeletePartitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeletePartition");
         request.add_header("x-amz-target", "AWSGlue.DeletePartition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18122,6 +21156,7 @@ impl Glue for GlueClient {
         input: DeletePartitionIndexRequest,
     ) -> Result<DeletePartitionIndexResponse, RusotoError<DeletePartitionIndexError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeletePartitionIndex");
         request.add_header("x-amz-target", "AWSGlue.DeletePartitionIndex");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18141,6 +21176,7 @@ impl Glue for GlueClient {
         input: DeleteRegistryInput,
     ) -> Result<DeleteRegistryResponse, RusotoError<DeleteRegistryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteRegistry");
         request.add_header("x-amz-target", "AWSGlue.DeleteRegistry");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18159,6 +21195,7 @@ impl Glue for GlueClient {
         input: DeleteResourcePolicyRequest,
     ) -> Result<DeleteResourcePolicyResponse, RusotoError<DeleteResourcePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteResourcePolicy");
         request.add_header("x-amz-target", "AWSGlue.DeleteResourcePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18178,6 +21215,7 @@ impl Glue for GlueClient {
         input: DeleteSchemaInput,
     ) -> Result<DeleteSchemaResponse, RusotoError<DeleteSchemaError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteSchema");
         request.add_header("x-amz-target", "AWSGlue.DeleteSchema");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18196,6 +21234,7 @@ impl Glue for GlueClient {
         input: DeleteSchemaVersionsInput,
     ) -> Result<DeleteSchemaVersionsResponse, RusotoError<DeleteSchemaVersionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteSchemaVersions");
         request.add_header("x-amz-target", "AWSGlue.DeleteSchemaVersions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18216,6 +21255,7 @@ impl Glue for GlueClient {
     ) -> Result<DeleteSecurityConfigurationResponse, RusotoError<DeleteSecurityConfigurationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteSecurityConfiguration");
         request.add_header("x-amz-target", "AWSGlue.DeleteSecurityConfiguration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18235,6 +21275,7 @@ impl Glue for GlueClient {
         input: DeleteTableRequest,
     ) -> Result<DeleteTableResponse, RusotoError<DeleteTableError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteTable");
         request.add_header("x-amz-target", "AWSGlue.DeleteTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18253,6 +21294,7 @@ impl Glue for GlueClient {
         input: DeleteTableVersionRequest,
     ) -> Result<DeleteTableVersionResponse, RusotoError<DeleteTableVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteTableVersion");
         request.add_header("x-amz-target", "AWSGlue.DeleteTableVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18271,6 +21313,7 @@ impl Glue for GlueClient {
         input: DeleteTriggerRequest,
     ) -> Result<DeleteTriggerResponse, RusotoError<DeleteTriggerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteTrigger");
         request.add_header("x-amz-target", "AWSGlue.DeleteTrigger");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18290,6 +21333,7 @@ impl Glue for GlueClient {
     ) -> Result<DeleteUserDefinedFunctionResponse, RusotoError<DeleteUserDefinedFunctionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteUserDefinedFunction");
         request.add_header("x-amz-target", "AWSGlue.DeleteUserDefinedFunction");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18309,6 +21353,7 @@ impl Glue for GlueClient {
         input: DeleteWorkflowRequest,
     ) -> Result<DeleteWorkflowResponse, RusotoError<DeleteWorkflowError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteWorkflow");
         request.add_header("x-amz-target", "AWSGlue.DeleteWorkflow");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18327,6 +21372,7 @@ impl Glue for GlueClient {
         input: GetCatalogImportStatusRequest,
     ) -> Result<GetCatalogImportStatusResponse, RusotoError<GetCatalogImportStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetCatalogImportStatus");
         request.add_header("x-amz-target", "AWSGlue.GetCatalogImportStatus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18346,6 +21392,7 @@ impl Glue for GlueClient {
         input: GetClassifierRequest,
     ) -> Result<GetClassifierResponse, RusotoError<GetClassifierError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetClassifier");
         request.add_header("x-amz-target", "AWSGlue.GetClassifier");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18364,6 +21411,7 @@ impl Glue for GlueClient {
         input: GetClassifiersRequest,
     ) -> Result<GetClassifiersResponse, RusotoError<GetClassifiersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetClassifiers");
         request.add_header("x-amz-target", "AWSGlue.GetClassifiers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18385,6 +21433,7 @@ impl Glue for GlueClient {
         RusotoError<GetColumnStatisticsForPartitionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetColumnStatisticsForPartition");
         request.add_header("x-amz-target", "AWSGlue.GetColumnStatisticsForPartition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18405,6 +21454,7 @@ impl Glue for GlueClient {
     ) -> Result<GetColumnStatisticsForTableResponse, RusotoError<GetColumnStatisticsForTableError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetColumnStatisticsForTable");
         request.add_header("x-amz-target", "AWSGlue.GetColumnStatisticsForTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18424,6 +21474,7 @@ impl Glue for GlueClient {
         input: GetConnectionRequest,
     ) -> Result<GetConnectionResponse, RusotoError<GetConnectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetConnection");
         request.add_header("x-amz-target", "AWSGlue.GetConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18442,6 +21493,7 @@ impl Glue for GlueClient {
         input: GetConnectionsRequest,
     ) -> Result<GetConnectionsResponse, RusotoError<GetConnectionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetConnections");
         request.add_header("x-amz-target", "AWSGlue.GetConnections");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18460,6 +21512,7 @@ impl Glue for GlueClient {
         input: GetCrawlerRequest,
     ) -> Result<GetCrawlerResponse, RusotoError<GetCrawlerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetCrawler");
         request.add_header("x-amz-target", "AWSGlue.GetCrawler");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18478,6 +21531,7 @@ impl Glue for GlueClient {
         input: GetCrawlerMetricsRequest,
     ) -> Result<GetCrawlerMetricsResponse, RusotoError<GetCrawlerMetricsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetCrawlerMetrics");
         request.add_header("x-amz-target", "AWSGlue.GetCrawlerMetrics");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18496,6 +21550,7 @@ impl Glue for GlueClient {
         input: GetCrawlersRequest,
     ) -> Result<GetCrawlersResponse, RusotoError<GetCrawlersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetCrawlers");
         request.add_header("x-amz-target", "AWSGlue.GetCrawlers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18517,6 +21572,7 @@ impl Glue for GlueClient {
         RusotoError<GetDataCatalogEncryptionSettingsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDataCatalogEncryptionSettings");
         request.add_header("x-amz-target", "AWSGlue.GetDataCatalogEncryptionSettings");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18539,6 +21595,7 @@ impl Glue for GlueClient {
         input: GetDatabaseRequest,
     ) -> Result<GetDatabaseResponse, RusotoError<GetDatabaseError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDatabase");
         request.add_header("x-amz-target", "AWSGlue.GetDatabase");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18557,6 +21614,7 @@ impl Glue for GlueClient {
         input: GetDatabasesRequest,
     ) -> Result<GetDatabasesResponse, RusotoError<GetDatabasesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDatabases");
         request.add_header("x-amz-target", "AWSGlue.GetDatabases");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18575,6 +21633,7 @@ impl Glue for GlueClient {
         input: GetDataflowGraphRequest,
     ) -> Result<GetDataflowGraphResponse, RusotoError<GetDataflowGraphError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDataflowGraph");
         request.add_header("x-amz-target", "AWSGlue.GetDataflowGraph");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18593,6 +21652,7 @@ impl Glue for GlueClient {
         input: GetDevEndpointRequest,
     ) -> Result<GetDevEndpointResponse, RusotoError<GetDevEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDevEndpoint");
         request.add_header("x-amz-target", "AWSGlue.GetDevEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18611,6 +21671,7 @@ impl Glue for GlueClient {
         input: GetDevEndpointsRequest,
     ) -> Result<GetDevEndpointsResponse, RusotoError<GetDevEndpointsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDevEndpoints");
         request.add_header("x-amz-target", "AWSGlue.GetDevEndpoints");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18629,6 +21690,7 @@ impl Glue for GlueClient {
         input: GetJobRequest,
     ) -> Result<GetJobResponse, RusotoError<GetJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetJob");
         request.add_header("x-amz-target", "AWSGlue.GetJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18647,6 +21709,7 @@ impl Glue for GlueClient {
         input: GetJobBookmarkRequest,
     ) -> Result<GetJobBookmarkResponse, RusotoError<GetJobBookmarkError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetJobBookmark");
         request.add_header("x-amz-target", "AWSGlue.GetJobBookmark");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18665,6 +21728,7 @@ impl Glue for GlueClient {
         input: GetJobRunRequest,
     ) -> Result<GetJobRunResponse, RusotoError<GetJobRunError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetJobRun");
         request.add_header("x-amz-target", "AWSGlue.GetJobRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18683,6 +21747,7 @@ impl Glue for GlueClient {
         input: GetJobRunsRequest,
     ) -> Result<GetJobRunsResponse, RusotoError<GetJobRunsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetJobRuns");
         request.add_header("x-amz-target", "AWSGlue.GetJobRuns");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18701,6 +21766,7 @@ impl Glue for GlueClient {
         input: GetJobsRequest,
     ) -> Result<GetJobsResponse, RusotoError<GetJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetJobs");
         request.add_header("x-amz-target", "AWSGlue.GetJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18719,6 +21785,7 @@ impl Glue for GlueClient {
         input: GetMLTaskRunRequest,
     ) -> Result<GetMLTaskRunResponse, RusotoError<GetMLTaskRunError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetMLTaskRun");
         request.add_header("x-amz-target", "AWSGlue.GetMLTaskRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18737,6 +21804,7 @@ impl Glue for GlueClient {
         input: GetMLTaskRunsRequest,
     ) -> Result<GetMLTaskRunsResponse, RusotoError<GetMLTaskRunsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetMLTaskRuns");
         request.add_header("x-amz-target", "AWSGlue.GetMLTaskRuns");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18755,6 +21823,7 @@ impl Glue for GlueClient {
         input: GetMLTransformRequest,
     ) -> Result<GetMLTransformResponse, RusotoError<GetMLTransformError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetMLTransform");
         request.add_header("x-amz-target", "AWSGlue.GetMLTransform");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18773,6 +21842,7 @@ impl Glue for GlueClient {
         input: GetMLTransformsRequest,
     ) -> Result<GetMLTransformsResponse, RusotoError<GetMLTransformsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetMLTransforms");
         request.add_header("x-amz-target", "AWSGlue.GetMLTransforms");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18791,6 +21861,7 @@ impl Glue for GlueClient {
         input: GetMappingRequest,
     ) -> Result<GetMappingResponse, RusotoError<GetMappingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetMapping");
         request.add_header("x-amz-target", "AWSGlue.GetMapping");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18809,6 +21880,7 @@ impl Glue for GlueClient {
         input: GetPartitionRequest,
     ) -> Result<GetPartitionResponse, RusotoError<GetPartitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetPartition");
         request.add_header("x-amz-target", "AWSGlue.GetPartition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18827,6 +21899,7 @@ impl Glue for GlueClient {
         input: GetPartitionIndexesRequest,
     ) -> Result<GetPartitionIndexesResponse, RusotoError<GetPartitionIndexesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetPartitionIndexes");
         request.add_header("x-amz-target", "AWSGlue.GetPartitionIndexes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18845,6 +21918,7 @@ impl Glue for GlueClient {
         input: GetPartitionsRequest,
     ) -> Result<GetPartitionsResponse, RusotoError<GetPartitionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetPartitions");
         request.add_header("x-amz-target", "AWSGlue.GetPartitions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18863,6 +21937,7 @@ impl Glue for GlueClient {
         input: GetPlanRequest,
     ) -> Result<GetPlanResponse, RusotoError<GetPlanError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetPlan");
         request.add_header("x-amz-target", "AWSGlue.GetPlan");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18881,6 +21956,7 @@ impl Glue for GlueClient {
         input: GetRegistryInput,
     ) -> Result<GetRegistryResponse, RusotoError<GetRegistryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRegistry");
         request.add_header("x-amz-target", "AWSGlue.GetRegistry");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18899,6 +21975,7 @@ impl Glue for GlueClient {
         input: GetResourcePoliciesRequest,
     ) -> Result<GetResourcePoliciesResponse, RusotoError<GetResourcePoliciesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetResourcePolicies");
         request.add_header("x-amz-target", "AWSGlue.GetResourcePolicies");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18917,6 +21994,7 @@ impl Glue for GlueClient {
         input: GetResourcePolicyRequest,
     ) -> Result<GetResourcePolicyResponse, RusotoError<GetResourcePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetResourcePolicy");
         request.add_header("x-amz-target", "AWSGlue.GetResourcePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18935,6 +22013,7 @@ impl Glue for GlueClient {
         input: GetSchemaInput,
     ) -> Result<GetSchemaResponse, RusotoError<GetSchemaError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetSchema");
         request.add_header("x-amz-target", "AWSGlue.GetSchema");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18953,6 +22032,7 @@ impl Glue for GlueClient {
         input: GetSchemaByDefinitionInput,
     ) -> Result<GetSchemaByDefinitionResponse, RusotoError<GetSchemaByDefinitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetSchemaByDefinition");
         request.add_header("x-amz-target", "AWSGlue.GetSchemaByDefinition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18972,6 +22052,7 @@ impl Glue for GlueClient {
         input: GetSchemaVersionInput,
     ) -> Result<GetSchemaVersionResponse, RusotoError<GetSchemaVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetSchemaVersion");
         request.add_header("x-amz-target", "AWSGlue.GetSchemaVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18990,6 +22071,7 @@ impl Glue for GlueClient {
         input: GetSchemaVersionsDiffInput,
     ) -> Result<GetSchemaVersionsDiffResponse, RusotoError<GetSchemaVersionsDiffError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetSchemaVersionsDiff");
         request.add_header("x-amz-target", "AWSGlue.GetSchemaVersionsDiff");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19009,6 +22091,7 @@ impl Glue for GlueClient {
         input: GetSecurityConfigurationRequest,
     ) -> Result<GetSecurityConfigurationResponse, RusotoError<GetSecurityConfigurationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetSecurityConfiguration");
         request.add_header("x-amz-target", "AWSGlue.GetSecurityConfiguration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19029,6 +22112,7 @@ impl Glue for GlueClient {
     ) -> Result<GetSecurityConfigurationsResponse, RusotoError<GetSecurityConfigurationsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetSecurityConfigurations");
         request.add_header("x-amz-target", "AWSGlue.GetSecurityConfigurations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19048,6 +22132,7 @@ impl Glue for GlueClient {
         input: GetTableRequest,
     ) -> Result<GetTableResponse, RusotoError<GetTableError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetTable");
         request.add_header("x-amz-target", "AWSGlue.GetTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19066,6 +22151,7 @@ impl Glue for GlueClient {
         input: GetTableVersionRequest,
     ) -> Result<GetTableVersionResponse, RusotoError<GetTableVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetTableVersion");
         request.add_header("x-amz-target", "AWSGlue.GetTableVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19084,6 +22170,7 @@ impl Glue for GlueClient {
         input: GetTableVersionsRequest,
     ) -> Result<GetTableVersionsResponse, RusotoError<GetTableVersionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetTableVersions");
         request.add_header("x-amz-target", "AWSGlue.GetTableVersions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19102,6 +22189,7 @@ impl Glue for GlueClient {
         input: GetTablesRequest,
     ) -> Result<GetTablesResponse, RusotoError<GetTablesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetTables");
         request.add_header("x-amz-target", "AWSGlue.GetTables");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19120,6 +22208,7 @@ impl Glue for GlueClient {
         input: GetTagsRequest,
     ) -> Result<GetTagsResponse, RusotoError<GetTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetTags");
         request.add_header("x-amz-target", "AWSGlue.GetTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19138,6 +22227,7 @@ impl Glue for GlueClient {
         input: GetTriggerRequest,
     ) -> Result<GetTriggerResponse, RusotoError<GetTriggerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetTrigger");
         request.add_header("x-amz-target", "AWSGlue.GetTrigger");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19156,6 +22246,7 @@ impl Glue for GlueClient {
         input: GetTriggersRequest,
     ) -> Result<GetTriggersResponse, RusotoError<GetTriggersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetTriggers");
         request.add_header("x-amz-target", "AWSGlue.GetTriggers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19174,6 +22265,7 @@ impl Glue for GlueClient {
         input: GetUserDefinedFunctionRequest,
     ) -> Result<GetUserDefinedFunctionResponse, RusotoError<GetUserDefinedFunctionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetUserDefinedFunction");
         request.add_header("x-amz-target", "AWSGlue.GetUserDefinedFunction");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19193,6 +22285,7 @@ impl Glue for GlueClient {
         input: GetUserDefinedFunctionsRequest,
     ) -> Result<GetUserDefinedFunctionsResponse, RusotoError<GetUserDefinedFunctionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetUserDefinedFunctions");
         request.add_header("x-amz-target", "AWSGlue.GetUserDefinedFunctions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19212,6 +22305,7 @@ impl Glue for GlueClient {
         input: GetWorkflowRequest,
     ) -> Result<GetWorkflowResponse, RusotoError<GetWorkflowError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetWorkflow");
         request.add_header("x-amz-target", "AWSGlue.GetWorkflow");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19230,6 +22324,7 @@ impl Glue for GlueClient {
         input: GetWorkflowRunRequest,
     ) -> Result<GetWorkflowRunResponse, RusotoError<GetWorkflowRunError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetWorkflowRun");
         request.add_header("x-amz-target", "AWSGlue.GetWorkflowRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19248,6 +22343,7 @@ impl Glue for GlueClient {
         input: GetWorkflowRunPropertiesRequest,
     ) -> Result<GetWorkflowRunPropertiesResponse, RusotoError<GetWorkflowRunPropertiesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetWorkflowRunProperties");
         request.add_header("x-amz-target", "AWSGlue.GetWorkflowRunProperties");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19267,6 +22363,7 @@ impl Glue for GlueClient {
         input: GetWorkflowRunsRequest,
     ) -> Result<GetWorkflowRunsResponse, RusotoError<GetWorkflowRunsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetWorkflowRuns");
         request.add_header("x-amz-target", "AWSGlue.GetWorkflowRuns");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19285,6 +22382,7 @@ impl Glue for GlueClient {
         input: ImportCatalogToGlueRequest,
     ) -> Result<ImportCatalogToGlueResponse, RusotoError<ImportCatalogToGlueError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ImportCatalogToGlue");
         request.add_header("x-amz-target", "AWSGlue.ImportCatalogToGlue");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19303,6 +22401,7 @@ impl Glue for GlueClient {
         input: ListCrawlersRequest,
     ) -> Result<ListCrawlersResponse, RusotoError<ListCrawlersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListCrawlers");
         request.add_header("x-amz-target", "AWSGlue.ListCrawlers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19321,6 +22420,7 @@ impl Glue for GlueClient {
         input: ListDevEndpointsRequest,
     ) -> Result<ListDevEndpointsResponse, RusotoError<ListDevEndpointsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListDevEndpoints");
         request.add_header("x-amz-target", "AWSGlue.ListDevEndpoints");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19339,6 +22439,7 @@ impl Glue for GlueClient {
         input: ListJobsRequest,
     ) -> Result<ListJobsResponse, RusotoError<ListJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListJobs");
         request.add_header("x-amz-target", "AWSGlue.ListJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19357,6 +22458,7 @@ impl Glue for GlueClient {
         input: ListMLTransformsRequest,
     ) -> Result<ListMLTransformsResponse, RusotoError<ListMLTransformsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListMLTransforms");
         request.add_header("x-amz-target", "AWSGlue.ListMLTransforms");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19375,6 +22477,7 @@ impl Glue for GlueClient {
         input: ListRegistriesInput,
     ) -> Result<ListRegistriesResponse, RusotoError<ListRegistriesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListRegistries");
         request.add_header("x-amz-target", "AWSGlue.ListRegistries");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19393,6 +22496,7 @@ impl Glue for GlueClient {
         input: ListSchemaVersionsInput,
     ) -> Result<ListSchemaVersionsResponse, RusotoError<ListSchemaVersionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListSchemaVersions");
         request.add_header("x-amz-target", "AWSGlue.ListSchemaVersions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19411,6 +22515,7 @@ impl Glue for GlueClient {
         input: ListSchemasInput,
     ) -> Result<ListSchemasResponse, RusotoError<ListSchemasError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListSchemas");
         request.add_header("x-amz-target", "AWSGlue.ListSchemas");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19429,6 +22534,7 @@ impl Glue for GlueClient {
         input: ListTriggersRequest,
     ) -> Result<ListTriggersResponse, RusotoError<ListTriggersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTriggers");
         request.add_header("x-amz-target", "AWSGlue.ListTriggers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19447,6 +22553,7 @@ impl Glue for GlueClient {
         input: ListWorkflowsRequest,
     ) -> Result<ListWorkflowsResponse, RusotoError<ListWorkflowsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListWorkflows");
         request.add_header("x-amz-target", "AWSGlue.ListWorkflows");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19468,6 +22575,7 @@ impl Glue for GlueClient {
         RusotoError<PutDataCatalogEncryptionSettingsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutDataCatalogEncryptionSettings");
         request.add_header("x-amz-target", "AWSGlue.PutDataCatalogEncryptionSettings");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19490,6 +22598,7 @@ impl Glue for GlueClient {
         input: PutResourcePolicyRequest,
     ) -> Result<PutResourcePolicyResponse, RusotoError<PutResourcePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutResourcePolicy");
         request.add_header("x-amz-target", "AWSGlue.PutResourcePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19508,6 +22617,7 @@ impl Glue for GlueClient {
         input: PutSchemaVersionMetadataInput,
     ) -> Result<PutSchemaVersionMetadataResponse, RusotoError<PutSchemaVersionMetadataError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutSchemaVersionMetadata");
         request.add_header("x-amz-target", "AWSGlue.PutSchemaVersionMetadata");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19527,6 +22637,7 @@ impl Glue for GlueClient {
         input: PutWorkflowRunPropertiesRequest,
     ) -> Result<PutWorkflowRunPropertiesResponse, RusotoError<PutWorkflowRunPropertiesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutWorkflowRunProperties");
         request.add_header("x-amz-target", "AWSGlue.PutWorkflowRunProperties");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19547,6 +22658,7 @@ impl Glue for GlueClient {
     ) -> Result<QuerySchemaVersionMetadataResponse, RusotoError<QuerySchemaVersionMetadataError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("QuerySchemaVersionMetadata");
         request.add_header("x-amz-target", "AWSGlue.QuerySchemaVersionMetadata");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19566,6 +22678,7 @@ impl Glue for GlueClient {
         input: RegisterSchemaVersionInput,
     ) -> Result<RegisterSchemaVersionResponse, RusotoError<RegisterSchemaVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RegisterSchemaVersion");
         request.add_header("x-amz-target", "AWSGlue.RegisterSchemaVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19586,6 +22699,7 @@ impl Glue for GlueClient {
     ) -> Result<RemoveSchemaVersionMetadataResponse, RusotoError<RemoveSchemaVersionMetadataError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RemoveSchemaVersionMetadata");
         request.add_header("x-amz-target", "AWSGlue.RemoveSchemaVersionMetadata");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19605,6 +22719,7 @@ impl Glue for GlueClient {
         input: ResetJobBookmarkRequest,
     ) -> Result<ResetJobBookmarkResponse, RusotoError<ResetJobBookmarkError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ResetJobBookmark");
         request.add_header("x-amz-target", "AWSGlue.ResetJobBookmark");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19623,6 +22738,7 @@ impl Glue for GlueClient {
         input: ResumeWorkflowRunRequest,
     ) -> Result<ResumeWorkflowRunResponse, RusotoError<ResumeWorkflowRunError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ResumeWorkflowRun");
         request.add_header("x-amz-target", "AWSGlue.ResumeWorkflowRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19641,6 +22757,7 @@ impl Glue for GlueClient {
         input: SearchTablesRequest,
     ) -> Result<SearchTablesResponse, RusotoError<SearchTablesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SearchTables");
         request.add_header("x-amz-target", "AWSGlue.SearchTables");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19659,6 +22776,7 @@ impl Glue for GlueClient {
         input: StartCrawlerRequest,
     ) -> Result<StartCrawlerResponse, RusotoError<StartCrawlerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartCrawler");
         request.add_header("x-amz-target", "AWSGlue.StartCrawler");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19677,6 +22795,7 @@ impl Glue for GlueClient {
         input: StartCrawlerScheduleRequest,
     ) -> Result<StartCrawlerScheduleResponse, RusotoError<StartCrawlerScheduleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartCrawlerSchedule");
         request.add_header("x-amz-target", "AWSGlue.StartCrawlerSchedule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19696,6 +22815,7 @@ impl Glue for GlueClient {
         input: StartExportLabelsTaskRunRequest,
     ) -> Result<StartExportLabelsTaskRunResponse, RusotoError<StartExportLabelsTaskRunError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartExportLabelsTaskRun");
         request.add_header("x-amz-target", "AWSGlue.StartExportLabelsTaskRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19715,6 +22835,7 @@ impl Glue for GlueClient {
         input: StartImportLabelsTaskRunRequest,
     ) -> Result<StartImportLabelsTaskRunResponse, RusotoError<StartImportLabelsTaskRunError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartImportLabelsTaskRun");
         request.add_header("x-amz-target", "AWSGlue.StartImportLabelsTaskRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19734,6 +22855,7 @@ impl Glue for GlueClient {
         input: StartJobRunRequest,
     ) -> Result<StartJobRunResponse, RusotoError<StartJobRunError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartJobRun");
         request.add_header("x-amz-target", "AWSGlue.StartJobRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19752,6 +22874,7 @@ impl Glue for GlueClient {
         input: StartMLEvaluationTaskRunRequest,
     ) -> Result<StartMLEvaluationTaskRunResponse, RusotoError<StartMLEvaluationTaskRunError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartMLEvaluationTaskRun");
         request.add_header("x-amz-target", "AWSGlue.StartMLEvaluationTaskRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19774,6 +22897,7 @@ impl Glue for GlueClient {
         RusotoError<StartMLLabelingSetGenerationTaskRunError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartMLLabelingSetGenerationTaskRun");
         request.add_header(
             "x-amz-target",
             "AWSGlue.StartMLLabelingSetGenerationTaskRun",
@@ -19799,6 +22923,7 @@ impl Glue for GlueClient {
         input: StartTriggerRequest,
     ) -> Result<StartTriggerResponse, RusotoError<StartTriggerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartTrigger");
         request.add_header("x-amz-target", "AWSGlue.StartTrigger");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19817,6 +22942,7 @@ impl Glue for GlueClient {
         input: StartWorkflowRunRequest,
     ) -> Result<StartWorkflowRunResponse, RusotoError<StartWorkflowRunError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartWorkflowRun");
         request.add_header("x-amz-target", "AWSGlue.StartWorkflowRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19835,6 +22961,7 @@ impl Glue for GlueClient {
         input: StopCrawlerRequest,
     ) -> Result<StopCrawlerResponse, RusotoError<StopCrawlerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopCrawler");
         request.add_header("x-amz-target", "AWSGlue.StopCrawler");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19853,6 +22980,7 @@ impl Glue for GlueClient {
         input: StopCrawlerScheduleRequest,
     ) -> Result<StopCrawlerScheduleResponse, RusotoError<StopCrawlerScheduleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopCrawlerSchedule");
         request.add_header("x-amz-target", "AWSGlue.StopCrawlerSchedule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19871,6 +22999,7 @@ impl Glue for GlueClient {
         input: StopTriggerRequest,
     ) -> Result<StopTriggerResponse, RusotoError<StopTriggerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopTrigger");
         request.add_header("x-amz-target", "AWSGlue.StopTrigger");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19889,6 +23018,7 @@ impl Glue for GlueClient {
         input: StopWorkflowRunRequest,
     ) -> Result<StopWorkflowRunResponse, RusotoError<StopWorkflowRunError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopWorkflowRun");
         request.add_header("x-amz-target", "AWSGlue.StopWorkflowRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19907,6 +23037,7 @@ impl Glue for GlueClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "AWSGlue.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19925,6 +23056,7 @@ impl Glue for GlueClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "AWSGlue.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19943,6 +23075,7 @@ impl Glue for GlueClient {
         input: UpdateClassifierRequest,
     ) -> Result<UpdateClassifierResponse, RusotoError<UpdateClassifierError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateClassifier");
         request.add_header("x-amz-target", "AWSGlue.UpdateClassifier");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19964,6 +23097,7 @@ impl Glue for GlueClient {
         RusotoError<UpdateColumnStatisticsForPartitionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateColumnStatisticsForPartition");
         request.add_header("x-amz-target", "AWSGlue.UpdateColumnStatisticsForPartition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19989,6 +23123,7 @@ impl Glue for GlueClient {
         RusotoError<UpdateColumnStatisticsForTableError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateColumnStatisticsForTable");
         request.add_header("x-amz-target", "AWSGlue.UpdateColumnStatisticsForTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -20008,6 +23143,7 @@ impl Glue for GlueClient {
         input: UpdateConnectionRequest,
     ) -> Result<UpdateConnectionResponse, RusotoError<UpdateConnectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateConnection");
         request.add_header("x-amz-target", "AWSGlue.UpdateConnection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -20026,6 +23162,7 @@ impl Glue for GlueClient {
         input: UpdateCrawlerRequest,
     ) -> Result<UpdateCrawlerResponse, RusotoError<UpdateCrawlerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateCrawler");
         request.add_header("x-amz-target", "AWSGlue.UpdateCrawler");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -20044,6 +23181,7 @@ impl Glue for GlueClient {
         input: UpdateCrawlerScheduleRequest,
     ) -> Result<UpdateCrawlerScheduleResponse, RusotoError<UpdateCrawlerScheduleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateCrawlerSchedule");
         request.add_header("x-amz-target", "AWSGlue.UpdateCrawlerSchedule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -20063,6 +23201,7 @@ impl Glue for GlueClient {
         input: UpdateDatabaseRequest,
     ) -> Result<UpdateDatabaseResponse, RusotoError<UpdateDatabaseError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateDatabase");
         request.add_header("x-amz-target", "AWSGlue.UpdateDatabase");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -20081,6 +23220,7 @@ impl Glue for GlueClient {
         input: UpdateDevEndpointRequest,
     ) -> Result<UpdateDevEndpointResponse, RusotoError<UpdateDevEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateDevEndpoint");
         request.add_header("x-amz-target", "AWSGlue.UpdateDevEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -20099,6 +23239,7 @@ impl Glue for GlueClient {
         input: UpdateJobRequest,
     ) -> Result<UpdateJobResponse, RusotoError<UpdateJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateJob");
         request.add_header("x-amz-target", "AWSGlue.UpdateJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -20117,6 +23258,7 @@ impl Glue for GlueClient {
         input: UpdateMLTransformRequest,
     ) -> Result<UpdateMLTransformResponse, RusotoError<UpdateMLTransformError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateMLTransform");
         request.add_header("x-amz-target", "AWSGlue.UpdateMLTransform");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -20135,6 +23277,7 @@ impl Glue for GlueClient {
         input: UpdatePartitionRequest,
     ) -> Result<UpdatePartitionResponse, RusotoError<UpdatePartitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdatePartition");
         request.add_header("x-amz-target", "AWSGlue.UpdatePartition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -20153,6 +23296,7 @@ impl Glue for GlueClient {
         input: UpdateRegistryInput,
     ) -> Result<UpdateRegistryResponse, RusotoError<UpdateRegistryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateRegistry");
         request.add_header("x-amz-target", "AWSGlue.UpdateRegistry");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -20171,6 +23315,7 @@ impl Glue for GlueClient {
         input: UpdateSchemaInput,
     ) -> Result<UpdateSchemaResponse, RusotoError<UpdateSchemaError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateSchema");
         request.add_header("x-amz-target", "AWSGlue.UpdateSchema");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -20189,6 +23334,7 @@ impl Glue for GlueClient {
         input: UpdateTableRequest,
     ) -> Result<UpdateTableResponse, RusotoError<UpdateTableError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateTable");
         request.add_header("x-amz-target", "AWSGlue.UpdateTable");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -20207,6 +23353,7 @@ impl Glue for GlueClient {
         input: UpdateTriggerRequest,
     ) -> Result<UpdateTriggerResponse, RusotoError<UpdateTriggerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateTrigger");
         request.add_header("x-amz-target", "AWSGlue.UpdateTrigger");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -20226,6 +23373,7 @@ impl Glue for GlueClient {
     ) -> Result<UpdateUserDefinedFunctionResponse, RusotoError<UpdateUserDefinedFunctionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateUserDefinedFunction");
         request.add_header("x-amz-target", "AWSGlue.UpdateUserDefinedFunction");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -20245,6 +23393,7 @@ impl Glue for GlueClient {
         input: UpdateWorkflowRequest,
     ) -> Result<UpdateWorkflowResponse, RusotoError<UpdateWorkflowError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateWorkflow");
         request.add_header("x-amz-target", "AWSGlue.UpdateWorkflow");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -20257,3 +23406,1429 @@ impl Glue for GlueClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateWorkflowResponse, _>()
     }
 }
+
+/// A blocking client for the AWS Glue API, running the calls of a `GlueClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `GlueClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct GlueBlockingClient {
+    client: GlueClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl GlueBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> GlueBlockingClient {
+        GlueBlockingClient::from_client(GlueClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> GlueBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        GlueBlockingClient::from_client(GlueClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> GlueBlockingClient {
+        GlueBlockingClient::from_client(GlueClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> GlueBlockingClient {
+        GlueBlockingClient::from_client(GlueClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: GlueClient) -> GlueBlockingClient {
+        GlueBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> GlueBlockingClient {
+        GlueBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> GlueBlockingClient {
+        GlueBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Glue::batch_create_partition`].
+    pub fn batch_create_partition(
+        &self,
+        input: BatchCreatePartitionRequest,
+    ) -> Result<BatchCreatePartitionResponse, RusotoError<BatchCreatePartitionError>> {
+        self.runtime
+            .block_on(self.client.batch_create_partition(input))
+    }
+
+    /// Blocking version of [`Glue::batch_delete_connection`].
+    pub fn batch_delete_connection(
+        &self,
+        input: BatchDeleteConnectionRequest,
+    ) -> Result<BatchDeleteConnectionResponse, RusotoError<BatchDeleteConnectionError>> {
+        self.runtime
+            .block_on(self.client.batch_delete_connection(input))
+    }
+
+    /// Blocking version of [`Glue::batch_delete_partition`].
+    pub fn batch_delete_partition(
+        &self,
+        input: BatchDeletePartitionRequest,
+    ) -> Result<BatchDeletePartitionResponse, RusotoError<BatchDeletePartitionError>> {
+        self.runtime
+            .block_on(self.client.batch_delete_partition(input))
+    }
+
+    /// Blocking version of [`Glue::batch_delete_table`].
+    pub fn batch_delete_table(
+        &self,
+        input: BatchDeleteTableRequest,
+    ) -> Result<BatchDeleteTableResponse, RusotoError<BatchDeleteTableError>> {
+        self.runtime.block_on(self.client.batch_delete_table(input))
+    }
+
+    /// Blocking version of [`Glue::batch_delete_table_version`].
+    pub fn batch_delete_table_version(
+        &self,
+        input: BatchDeleteTableVersionRequest,
+    ) -> Result<BatchDeleteTableVersionResponse, RusotoError<BatchDeleteTableVersionError>> {
+        self.runtime
+            .block_on(self.client.batch_delete_table_version(input))
+    }
+
+    /// Blocking version of [`Glue::batch_get_crawlers`].
+    pub fn batch_get_crawlers(
+        &self,
+        input: BatchGetCrawlersRequest,
+    ) -> Result<BatchGetCrawlersResponse, RusotoError<BatchGetCrawlersError>> {
+        self.runtime.block_on(self.client.batch_get_crawlers(input))
+    }
+
+    /// Blocking version of [`Glue::batch_get_dev_endpoints`].
+    pub fn batch_get_dev_endpoints(
+        &self,
+        input: BatchGetDevEndpointsRequest,
+    ) -> Result<BatchGetDevEndpointsResponse, RusotoError<BatchGetDevEndpointsError>> {
+        self.runtime
+            .block_on(self.client.batch_get_dev_endpoints(input))
+    }
+
+    /// Blocking version of [`Glue::batch_get_jobs`].
+    pub fn batch_get_jobs(
+        &self,
+        input: BatchGetJobsRequest,
+    ) -> Result<BatchGetJobsResponse, RusotoError<BatchGetJobsError>> {
+        self.runtime.block_on(self.client.batch_get_jobs(input))
+    }
+
+    /// Blocking version of [`Glue::batch_get_partition`].
+    pub fn batch_get_partition(
+        &self,
+        input: BatchGetPartitionRequest,
+    ) -> Result<BatchGetPartitionResponse, RusotoError<BatchGetPartitionError>> {
+        self.runtime
+            .block_on(self.client.batch_get_partition(input))
+    }
+
+    /// Blocking version of [`Glue::batch_get_triggers`].
+    pub fn batch_get_triggers(
+        &self,
+        input: BatchGetTriggersRequest,
+    ) -> Result<BatchGetTriggersResponse, RusotoError<BatchGetTriggersError>> {
+        self.runtime.block_on(self.client.batch_get_triggers(input))
+    }
+
+    /// Blocking version of [`Glue::batch_get_workflows`].
+    pub fn batch_get_workflows(
+        &self,
+        input: BatchGetWorkflowsRequest,
+    ) -> Result<BatchGetWorkflowsResponse, RusotoError<BatchGetWorkflowsError>> {
+        self.runtime
+            .block_on(self.client.batch_get_workflows(input))
+    }
+
+    /// Blocking version of [`Glue::batch_stop_job_run`].
+    pub fn batch_stop_job_run(
+        &self,
+        input: BatchStopJobRunRequest,
+    ) -> Result<BatchStopJobRunResponse, RusotoError<GlueBatchStopJobRunError>> {
+        self.runtime.block_on(self.client.batch_stop_job_run(input))
+    }
+
+    /// Blocking version of [`Glue::batch_update_partition`].
+    pub fn batch_update_partition(
+        &self,
+        input: BatchUpdatePartitionRequest,
+    ) -> Result<BatchUpdatePartitionResponse, RusotoError<BatchUpdatePartitionError>> {
+        self.runtime
+            .block_on(self.client.batch_update_partition(input))
+    }
+
+    /// Blocking version of [`Glue::cancel_ml_task_run`].
+    pub fn cancel_ml_task_run(
+        &self,
+        input: CancelMLTaskRunRequest,
+    ) -> Result<CancelMLTaskRunResponse, RusotoError<CancelMLTaskRunError>> {
+        self.runtime.block_on(self.client.cancel_ml_task_run(input))
+    }
+
+    /// Blocking version of [`Glue::check_schema_version_validity`].
+    pub fn check_schema_version_validity(
+        &self,
+        input: CheckSchemaVersionValidityInput,
+    ) -> Result<CheckSchemaVersionValidityResponse, RusotoError<CheckSchemaVersionValidityError>>
+    {
+        self.runtime
+            .block_on(self.client.check_schema_version_validity(input))
+    }
+
+    /// Blocking version of [`Glue::create_classifier`].
+    pub fn create_classifier(
+        &self,
+        input: CreateClassifierRequest,
+    ) -> Result<CreateClassifierResponse, RusotoError<CreateClassifierError>> {
+        self.runtime.block_on(self.client.create_classifier(input))
+    }
+
+    /// Blocking version of [`Glue::create_connection`].
+    pub fn create_connection(
+        &self,
+        input: CreateConnectionRequest,
+    ) -> Result<CreateConnectionResponse, RusotoError<CreateConnectionError>> {
+        self.runtime.block_on(self.client.create_connection(input))
+    }
+
+    /// Blocking version of [`Glue::create_crawler`].
+    pub fn create_crawler(
+        &self,
+        input: CreateCrawlerRequest,
+    ) -> Result<CreateCrawlerResponse, RusotoError<CreateCrawlerError>> {
+        self.runtime.block_on(self.client.create_crawler(input))
+    }
+
+    /// Blocking version of [`Glue::create_database`].
+    pub fn create_database(
+        &self,
+        input: CreateDatabaseRequest,
+    ) -> Result<CreateDatabaseResponse, RusotoError<CreateDatabaseError>> {
+        self.runtime.block_on(self.client.create_database(input))
+    }
+
+    /// Blocking version of [`Glue::create_dev_endpoint`].
+    pub fn create_dev_endpoint(
+        &self,
+        input: CreateDevEndpointRequest,
+    ) -> Result<CreateDevEndpointResponse, RusotoError<CreateDevEndpointError>> {
+        self.runtime
+            .block_on(self.client.create_dev_endpoint(input))
+    }
+
+    /// Blocking version of [`Glue::create_job`].
+    pub fn create_job(
+        &self,
+        input: CreateJobRequest,
+    ) -> Result<CreateJobResponse, RusotoError<CreateJobError>> {
+        self.runtime.block_on(self.client.create_job(input))
+    }
+
+    /// Blocking version of [`Glue::create_ml_transform`].
+    pub fn create_ml_transform(
+        &self,
+        input: CreateMLTransformRequest,
+    ) -> Result<CreateMLTransformResponse, RusotoError<CreateMLTransformError>> {
+        self.runtime
+            .block_on(self.client.create_ml_transform(input))
+    }
+
+    /// Blocking version of [`Glue::create_partition`].
+    pub fn create_partition(
+        &self,
+        input: CreatePartitionRequest,
+    ) -> Result<CreatePartitionResponse, RusotoError<CreatePartitionError>> {
+        self.runtime.block_on(self.client.create_partition(input))
+    }
+
+    /// Blocking version of [`Glue::create_partition_index`].
+    pub fn create_partition_index(
+        &self,
+        input: CreatePartitionIndexRequest,
+    ) -> Result<CreatePartitionIndexResponse, RusotoError<CreatePartitionIndexError>> {
+        self.runtime
+            .block_on(self.client.create_partition_index(input))
+    }
+
+    /// Blocking version of [`Glue::create_registry`].
+    pub fn create_registry(
+        &self,
+        input: CreateRegistryInput,
+    ) -> Result<CreateRegistryResponse, RusotoError<CreateRegistryError>> {
+        self.runtime.block_on(self.client.create_registry(input))
+    }
+
+    /// Blocking version of [`Glue::create_schema`].
+    pub fn create_schema(
+        &self,
+        input: CreateSchemaInput,
+    ) -> Result<CreateSchemaResponse, RusotoError<CreateSchemaError>> {
+        self.runtime.block_on(self.client.create_schema(input))
+    }
+
+    /// Blocking version of [`Glue::create_script`].
+    pub fn create_script(
+        &self,
+        input: CreateScriptRequest,
+    ) -> Result<CreateScriptResponse, RusotoError<CreateScriptError>> {
+        self.runtime.block_on(self.client.create_script(input))
+    }
+
+    /// Blocking version of [`Glue::create_security_configuration`].
+    pub fn create_security_configuration(
+        &self,
+        input: CreateSecurityConfigurationRequest,
+    ) -> Result<CreateSecurityConfigurationResponse, RusotoError<CreateSecurityConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.create_security_configuration(input))
+    }
+
+    /// Blocking version of [`Glue::create_table`].
+    pub fn create_table(
+        &self,
+        input: CreateTableRequest,
+    ) -> Result<CreateTableResponse, RusotoError<CreateTableError>> {
+        self.runtime.block_on(self.client.create_table(input))
+    }
+
+    /// Blocking version of [`Glue::create_trigger`].
+    pub fn create_trigger(
+        &self,
+        input: CreateTriggerRequest,
+    ) -> Result<CreateTriggerResponse, RusotoError<CreateTriggerError>> {
+        self.runtime.block_on(self.client.create_trigger(input))
+    }
+
+    /// Blocking version of [`Glue::create_user_defined_function`].
+    pub fn create_user_defined_function(
+        &self,
+        input: CreateUserDefinedFunctionRequest,
+    ) -> Result<CreateUserDefinedFunctionResponse, RusotoError<CreateUserDefinedFunctionError>>
+    {
+        self.runtime
+            .block_on(self.client.create_user_defined_function(input))
+    }
+
+    /// Blocking version of [`Glue::create_workflow`].
+    pub fn create_workflow(
+        &self,
+        input: CreateWorkflowRequest,
+    ) -> Result<CreateWorkflowResponse, RusotoError<CreateWorkflowError>> {
+        self.runtime.block_on(self.client.create_workflow(input))
+    }
+
+    /// Blocking version of [`Glue::delete_classifier`].
+    pub fn delete_classifier(
+        &self,
+        input: DeleteClassifierRequest,
+    ) -> Result<DeleteClassifierResponse, RusotoError<DeleteClassifierError>> {
+        self.runtime.block_on(self.client.delete_classifier(input))
+    }
+
+    /// Blocking version of [`Glue::delete_column_statistics_for_partition`].
+    pub fn delete_column_statistics_for_partition(
+        &self,
+        input: DeleteColumnStatisticsForPartitionRequest,
+    ) -> Result<
+        DeleteColumnStatisticsForPartitionResponse,
+        RusotoError<DeleteColumnStatisticsForPartitionError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_column_statistics_for_partition(input))
+    }
+
+    /// Blocking version of [`Glue::delete_column_statistics_for_table`].
+    pub fn delete_column_statistics_for_table(
+        &self,
+        input: DeleteColumnStatisticsForTableRequest,
+    ) -> Result<
+        DeleteColumnStatisticsForTableResponse,
+        RusotoError<DeleteColumnStatisticsForTableError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_column_statistics_for_table(input))
+    }
+
+    /// Blocking version of [`Glue::delete_connection`].
+    pub fn delete_connection(
+        &self,
+        input: DeleteConnectionRequest,
+    ) -> Result<DeleteConnectionResponse, RusotoError<DeleteConnectionError>> {
+        self.runtime.block_on(self.client.delete_connection(input))
+    }
+
+    /// Blocking version of [`Glue::delete_crawler`].
+    pub fn delete_crawler(
+        &self,
+        input: DeleteCrawlerRequest,
+    ) -> Result<DeleteCrawlerResponse, RusotoError<DeleteCrawlerError>> {
+        self.runtime.block_on(self.client.delete_crawler(input))
+    }
+
+    /// Blocking version of [`Glue::delete_database`].
+    pub fn delete_database(
+        &self,
+        input: DeleteDatabaseRequest,
+    ) -> Result<DeleteDatabaseResponse, RusotoError<DeleteDatabaseError>> {
+        self.runtime.block_on(self.client.delete_database(input))
+    }
+
+    /// Blocking version of [`Glue::delete_dev_endpoint`].
+    pub fn delete_dev_endpoint(
+        &self,
+        input: DeleteDevEndpointRequest,
+    ) -> Result<DeleteDevEndpointResponse, RusotoError<DeleteDevEndpointError>> {
+        self.runtime
+            .block_on(self.client.delete_dev_endpoint(input))
+    }
+
+    /// Blocking version of [`Glue::delete_job`].
+    pub fn delete_job(
+        &self,
+        input: DeleteJobRequest,
+    ) -> Result<DeleteJobResponse, RusotoError<DeleteJobError>> {
+        self.runtime.block_on(self.client.delete_job(input))
+    }
+
+    /// Blocking version of [`Glue::delete_ml_transform`].
+    pub fn delete_ml_transform(
+        &self,
+        input: DeleteMLTransformRequest,
+    ) -> Result<DeleteMLTransformResponse, RusotoError<DeleteMLTransformError>> {
+        self.runtime
+            .block_on(self.client.delete_ml_transform(input))
+    }
+
+    /// Blocking version of [`Glue::delete_partition`].
+    pub fn delete_partition(
+        &self,
+        input: DeletePartitionRequest,
+    ) -> Result<DeletePartitionResponse, RusotoError<DeletePartitionError>> {
+        self.runtime.block_on(self.client.delete_partition(input))
+    }
+
+    /// Blocking version of [`Glue::delete_partition_index`].
+    pub fn delete_partition_index(
+        &self,
+        input: DeletePartitionIndexRequest,
+    ) -> Result<DeletePartitionIndexResponse, RusotoError<DeletePartitionIndexError>> {
+        self.runtime
+            .block_on(self.client.delete_partition_index(input))
+    }
+
+    /// Blocking version of [`Glue::delete_registry`].
+    pub fn delete_registry(
+        &self,
+        input: DeleteRegistryInput,
+    ) -> Result<DeleteRegistryResponse, RusotoError<DeleteRegistryError>> {
+        self.runtime.block_on(self.client.delete_registry(input))
+    }
+
+    /// Blocking version of [`Glue::delete_resource_policy`].
+    pub fn delete_resource_policy(
+        &self,
+        input: DeleteResourcePolicyRequest,
+    ) -> Result<DeleteResourcePolicyResponse, RusotoError<DeleteResourcePolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_resource_policy(input))
+    }
+
+    /// Blocking version of [`Glue::delete_schema`].
+    pub fn delete_schema(
+        &self,
+        input: DeleteSchemaInput,
+    ) -> Result<DeleteSchemaResponse, RusotoError<DeleteSchemaError>> {
+        self.runtime.block_on(self.client.delete_schema(input))
+    }
+
+    /// Blocking version of [`Glue::delete_schema_versions`].
+    pub fn delete_schema_versions(
+        &self,
+        input: DeleteSchemaVersionsInput,
+    ) -> Result<DeleteSchemaVersionsResponse, RusotoError<DeleteSchemaVersionsError>> {
+        self.runtime
+            .block_on(self.client.delete_schema_versions(input))
+    }
+
+    /// Blocking version of [`Glue::delete_security_configuration`].
+    pub fn delete_security_configuration(
+        &self,
+        input: DeleteSecurityConfigurationRequest,
+    ) -> Result<DeleteSecurityConfigurationResponse, RusotoError<DeleteSecurityConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_security_configuration(input))
+    }
+
+    /// Blocking version of [`Glue::delete_table`].
+    pub fn delete_table(
+        &self,
+        input: DeleteTableRequest,
+    ) -> Result<DeleteTableResponse, RusotoError<DeleteTableError>> {
+        self.runtime.block_on(self.client.delete_table(input))
+    }
+
+    /// Blocking version of [`Glue::delete_table_version`].
+    pub fn delete_table_version(
+        &self,
+        input: DeleteTableVersionRequest,
+    ) -> Result<DeleteTableVersionResponse, RusotoError<DeleteTableVersionError>> {
+        self.runtime
+            .block_on(self.client.delete_table_version(input))
+    }
+
+    /// Blocking version of [`Glue::delete_trigger`].
+    pub fn delete_trigger(
+        &self,
+        input: DeleteTriggerRequest,
+    ) -> Result<DeleteTriggerResponse, RusotoError<DeleteTriggerError>> {
+        self.runtime.block_on(self.client.delete_trigger(input))
+    }
+
+    /// Blocking version of [`Glue::delete_user_defined_function`].
+    pub fn delete_user_defined_function(
+        &self,
+        input: DeleteUserDefinedFunctionRequest,
+    ) -> Result<DeleteUserDefinedFunctionResponse, RusotoError<DeleteUserDefinedFunctionError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_user_defined_function(input))
+    }
+
+    /// Blocking version of [`Glue::delete_workflow`].
+    pub fn delete_workflow(
+        &self,
+        input: DeleteWorkflowRequest,
+    ) -> Result<DeleteWorkflowResponse, RusotoError<DeleteWorkflowError>> {
+        self.runtime.block_on(self.client.delete_workflow(input))
+    }
+
+    /// Blocking version of [`Glue::get_catalog_import_status`].
+    pub fn get_catalog_import_status(
+        &self,
+        input: GetCatalogImportStatusRequest,
+    ) -> Result<GetCatalogImportStatusResponse, RusotoError<GetCatalogImportStatusError>> {
+        self.runtime
+            .block_on(self.client.get_catalog_import_status(input))
+    }
+
+    /// Blocking version of [`Glue::get_classifier`].
+    pub fn get_classifier(
+        &self,
+        input: GetClassifierRequest,
+    ) -> Result<GetClassifierResponse, RusotoError<GetClassifierError>> {
+        self.runtime.block_on(self.client.get_classifier(input))
+    }
+
+    /// Blocking version of [`Glue::get_classifiers`].
+    pub fn get_classifiers(
+        &self,
+        input: GetClassifiersRequest,
+    ) -> Result<GetClassifiersResponse, RusotoError<GetClassifiersError>> {
+        self.runtime.block_on(self.client.get_classifiers(input))
+    }
+
+    /// Blocking version of [`Glue::get_column_statistics_for_partition`].
+    pub fn get_column_statistics_for_partition(
+        &self,
+        input: GetColumnStatisticsForPartitionRequest,
+    ) -> Result<
+        GetColumnStatisticsForPartitionResponse,
+        RusotoError<GetColumnStatisticsForPartitionError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_column_statistics_for_partition(input))
+    }
+
+    /// Blocking version of [`Glue::get_column_statistics_for_table`].
+    pub fn get_column_statistics_for_table(
+        &self,
+        input: GetColumnStatisticsForTableRequest,
+    ) -> Result<GetColumnStatisticsForTableResponse, RusotoError<GetColumnStatisticsForTableError>>
+    {
+        self.runtime
+            .block_on(self.client.get_column_statistics_for_table(input))
+    }
+
+    /// Blocking version of [`Glue::get_connection`].
+    pub fn get_connection(
+        &self,
+        input: GetConnectionRequest,
+    ) -> Result<GetConnectionResponse, RusotoError<GetConnectionError>> {
+        self.runtime.block_on(self.client.get_connection(input))
+    }
+
+    /// Blocking version of [`Glue::get_connections`].
+    pub fn get_connections(
+        &self,
+        input: GetConnectionsRequest,
+    ) -> Result<GetConnectionsResponse, RusotoError<GetConnectionsError>> {
+        self.runtime.block_on(self.client.get_connections(input))
+    }
+
+    /// Blocking version of [`Glue::get_crawler`].
+    pub fn get_crawler(
+        &self,
+        input: GetCrawlerRequest,
+    ) -> Result<GetCrawlerResponse, RusotoError<GetCrawlerError>> {
+        self.runtime.block_on(self.client.get_crawler(input))
+    }
+
+    /// Blocking version of [`Glue::get_crawler_metrics`].
+    pub fn get_crawler_metrics(
+        &self,
+        input: GetCrawlerMetricsRequest,
+    ) -> Result<GetCrawlerMetricsResponse, RusotoError<GetCrawlerMetricsError>> {
+        self.runtime
+            .block_on(self.client.get_crawler_metrics(input))
+    }
+
+    /// Blocking version of [`Glue::get_crawlers`].
+    pub fn get_crawlers(
+        &self,
+        input: GetCrawlersRequest,
+    ) -> Result<GetCrawlersResponse, RusotoError<GetCrawlersError>> {
+        self.runtime.block_on(self.client.get_crawlers(input))
+    }
+
+    /// Blocking version of [`Glue::get_data_catalog_encryption_settings`].
+    pub fn get_data_catalog_encryption_settings(
+        &self,
+        input: GetDataCatalogEncryptionSettingsRequest,
+    ) -> Result<
+        GetDataCatalogEncryptionSettingsResponse,
+        RusotoError<GetDataCatalogEncryptionSettingsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_data_catalog_encryption_settings(input))
+    }
+
+    /// Blocking version of [`Glue::get_database`].
+    pub fn get_database(
+        &self,
+        input: GetDatabaseRequest,
+    ) -> Result<GetDatabaseResponse, RusotoError<GetDatabaseError>> {
+        self.runtime.block_on(self.client.get_database(input))
+    }
+
+    /// Blocking version of [`Glue::get_databases`].
+    pub fn get_databases(
+        &self,
+        input: GetDatabasesRequest,
+    ) -> Result<GetDatabasesResponse, RusotoError<GetDatabasesError>> {
+        self.runtime.block_on(self.client.get_databases(input))
+    }
+
+    /// Blocking version of [`Glue::get_dataflow_graph`].
+    pub fn get_dataflow_graph(
+        &self,
+        input: GetDataflowGraphRequest,
+    ) -> Result<GetDataflowGraphResponse, RusotoError<GetDataflowGraphError>> {
+        self.runtime.block_on(self.client.get_dataflow_graph(input))
+    }
+
+    /// Blocking version of [`Glue::get_dev_endpoint`].
+    pub fn get_dev_endpoint(
+        &self,
+        input: GetDevEndpointRequest,
+    ) -> Result<GetDevEndpointResponse, RusotoError<GetDevEndpointError>> {
+        self.runtime.block_on(self.client.get_dev_endpoint(input))
+    }
+
+    /// Blocking version of [`Glue::get_dev_endpoints`].
+    pub fn get_dev_endpoints(
+        &self,
+        input: GetDevEndpointsRequest,
+    ) -> Result<GetDevEndpointsResponse, RusotoError<GetDevEndpointsError>> {
+        self.runtime.block_on(self.client.get_dev_endpoints(input))
+    }
+
+    /// Blocking version of [`Glue::get_job`].
+    pub fn get_job(
+        &self,
+        input: GetJobRequest,
+    ) -> Result<GetJobResponse, RusotoError<GetJobError>> {
+        self.runtime.block_on(self.client.get_job(input))
+    }
+
+    /// Blocking version of [`Glue::get_job_bookmark`].
+    pub fn get_job_bookmark(
+        &self,
+        input: GetJobBookmarkRequest,
+    ) -> Result<GetJobBookmarkResponse, RusotoError<GetJobBookmarkError>> {
+        self.runtime.block_on(self.client.get_job_bookmark(input))
+    }
+
+    /// Blocking version of [`Glue::get_job_run`].
+    pub fn get_job_run(
+        &self,
+        input: GetJobRunRequest,
+    ) -> Result<GetJobRunResponse, RusotoError<GetJobRunError>> {
+        self.runtime.block_on(self.client.get_job_run(input))
+    }
+
+    /// Blocking version of [`Glue::get_job_runs`].
+    pub fn get_job_runs(
+        &self,
+        input: GetJobRunsRequest,
+    ) -> Result<GetJobRunsResponse, RusotoError<GetJobRunsError>> {
+        self.runtime.block_on(self.client.get_job_runs(input))
+    }
+
+    /// Blocking version of [`Glue::get_jobs`].
+    pub fn get_jobs(
+        &self,
+        input: GetJobsRequest,
+    ) -> Result<GetJobsResponse, RusotoError<GetJobsError>> {
+        self.runtime.block_on(self.client.get_jobs(input))
+    }
+
+    /// Blocking version of [`Glue::get_ml_task_run`].
+    pub fn get_ml_task_run(
+        &self,
+        input: GetMLTaskRunRequest,
+    ) -> Result<GetMLTaskRunResponse, RusotoError<GetMLTaskRunError>> {
+        self.runtime.block_on(self.client.get_ml_task_run(input))
+    }
+
+    /// Blocking version of [`Glue::get_ml_task_runs`].
+    pub fn get_ml_task_runs(
+        &self,
+        input: GetMLTaskRunsRequest,
+    ) -> Result<GetMLTaskRunsResponse, RusotoError<GetMLTaskRunsError>> {
+        self.runtime.block_on(self.client.get_ml_task_runs(input))
+    }
+
+    /// Blocking version of [`Glue::get_ml_transform`].
+    pub fn get_ml_transform(
+        &self,
+        input: GetMLTransformRequest,
+    ) -> Result<GetMLTransformResponse, RusotoError<GetMLTransformError>> {
+        self.runtime.block_on(self.client.get_ml_transform(input))
+    }
+
+    /// Blocking version of [`Glue::get_ml_transforms`].
+    pub fn get_ml_transforms(
+        &self,
+        input: GetMLTransformsRequest,
+    ) -> Result<GetMLTransformsResponse, RusotoError<GetMLTransformsError>> {
+        self.runtime.block_on(self.client.get_ml_transforms(input))
+    }
+
+    /// Blocking version of [`Glue::get_mapping`].
+    pub fn get_mapping(
+        &self,
+        input: GetMappingRequest,
+    ) -> Result<GetMappingResponse, RusotoError<GetMappingError>> {
+        self.runtime.block_on(self.client.get_mapping(input))
+    }
+
+    /// Blocking version of [`Glue::get_partition`].
+    pub fn get_partition(
+        &self,
+        input: GetPartitionRequest,
+    ) -> Result<GetPartitionResponse, RusotoError<GetPartitionError>> {
+        self.runtime.block_on(self.client.get_partition(input))
+    }
+
+    /// Blocking version of [`Glue::get_partition_indexes`].
+    pub fn get_partition_indexes(
+        &self,
+        input: GetPartitionIndexesRequest,
+    ) -> Result<GetPartitionIndexesResponse, RusotoError<GetPartitionIndexesError>> {
+        self.runtime
+            .block_on(self.client.get_partition_indexes(input))
+    }
+
+    /// Blocking version of [`Glue::get_partitions`].
+    pub fn get_partitions(
+        &self,
+        input: GetPartitionsRequest,
+    ) -> Result<GetPartitionsResponse, RusotoError<GetPartitionsError>> {
+        self.runtime.block_on(self.client.get_partitions(input))
+    }
+
+    /// Blocking version of [`Glue::get_plan`].
+    pub fn get_plan(
+        &self,
+        input: GetPlanRequest,
+    ) -> Result<GetPlanResponse, RusotoError<GetPlanError>> {
+        self.runtime.block_on(self.client.get_plan(input))
+    }
+
+    /// Blocking version of [`Glue::get_registry`].
+    pub fn get_registry(
+        &self,
+        input: GetRegistryInput,
+    ) -> Result<GetRegistryResponse, RusotoError<GetRegistryError>> {
+        self.runtime.block_on(self.client.get_registry(input))
+    }
+
+    /// Blocking version of [`Glue::get_resource_policies`].
+    pub fn get_resource_policies(
+        &self,
+        input: GetResourcePoliciesRequest,
+    ) -> Result<GetResourcePoliciesResponse, RusotoError<GetResourcePoliciesError>> {
+        self.runtime
+            .block_on(self.client.get_resource_policies(input))
+    }
+
+    /// Blocking version of [`Glue::get_resource_policy`].
+    pub fn get_resource_policy(
+        &self,
+        input: GetResourcePolicyRequest,
+    ) -> Result<GetResourcePolicyResponse, RusotoError<GetResourcePolicyError>> {
+        self.runtime
+            .block_on(self.client.get_resource_policy(input))
+    }
+
+    /// Blocking version of [`Glue::get_schema`].
+    pub fn get_schema(
+        &self,
+        input: GetSchemaInput,
+    ) -> Result<GetSchemaResponse, RusotoError<GetSchemaError>> {
+        self.runtime.block_on(self.client.get_schema(input))
+    }
+
+    /// Blocking version of [`Glue::get_schema_by_definition`].
+    pub fn get_schema_by_definition(
+        &self,
+        input: GetSchemaByDefinitionInput,
+    ) -> Result<GetSchemaByDefinitionResponse, RusotoError<GetSchemaByDefinitionError>> {
+        self.runtime
+            .block_on(self.client.get_schema_by_definition(input))
+    }
+
+    /// Blocking version of [`Glue::get_schema_version`].
+    pub fn get_schema_version(
+        &self,
+        input: GetSchemaVersionInput,
+    ) -> Result<GetSchemaVersionResponse, RusotoError<GetSchemaVersionError>> {
+        self.runtime.block_on(self.client.get_schema_version(input))
+    }
+
+    /// Blocking version of [`Glue::get_schema_versions_diff`].
+    pub fn get_schema_versions_diff(
+        &self,
+        input: GetSchemaVersionsDiffInput,
+    ) -> Result<GetSchemaVersionsDiffResponse, RusotoError<GetSchemaVersionsDiffError>> {
+        self.runtime
+            .block_on(self.client.get_schema_versions_diff(input))
+    }
+
+    /// Blocking version of [`Glue::get_security_configuration`].
+    pub fn get_security_configuration(
+        &self,
+        input: GetSecurityConfigurationRequest,
+    ) -> Result<GetSecurityConfigurationResponse, RusotoError<GetSecurityConfigurationError>> {
+        self.runtime
+            .block_on(self.client.get_security_configuration(input))
+    }
+
+    /// Blocking version of [`Glue::get_security_configurations`].
+    pub fn get_security_configurations(
+        &self,
+        input: GetSecurityConfigurationsRequest,
+    ) -> Result<GetSecurityConfigurationsResponse, RusotoError<GetSecurityConfigurationsError>>
+    {
+        self.runtime
+            .block_on(self.client.get_security_configurations(input))
+    }
+
+    /// Blocking version of [`Glue::get_table`].
+    pub fn get_table(
+        &self,
+        input: GetTableRequest,
+    ) -> Result<GetTableResponse, RusotoError<GetTableError>> {
+        self.runtime.block_on(self.client.get_table(input))
+    }
+
+    /// Blocking version of [`Glue::get_table_version`].
+    pub fn get_table_version(
+        &self,
+        input: GetTableVersionRequest,
+    ) -> Result<GetTableVersionResponse, RusotoError<GetTableVersionError>> {
+        self.runtime.block_on(self.client.get_table_version(input))
+    }
+
+    /// Blocking version of [`Glue::get_table_versions`].
+    pub fn get_table_versions(
+        &self,
+        input: GetTableVersionsRequest,
+    ) -> Result<GetTableVersionsResponse, RusotoError<GetTableVersionsError>> {
+        self.runtime.block_on(self.client.get_table_versions(input))
+    }
+
+    /// Blocking version of [`Glue::get_tables`].
+    pub fn get_tables(
+        &self,
+        input: GetTablesRequest,
+    ) -> Result<GetTablesResponse, RusotoError<GetTablesError>> {
+        self.runtime.block_on(self.client.get_tables(input))
+    }
+
+    /// Blocking version of [`Glue::get_tags`].
+    pub fn get_tags(
+        &self,
+        input: GetTagsRequest,
+    ) -> Result<GetTagsResponse, RusotoError<GetTagsError>> {
+        self.runtime.block_on(self.client.get_tags(input))
+    }
+
+    /// Blocking version of [`Glue::get_trigger`].
+    pub fn get_trigger(
+        &self,
+        input: GetTriggerRequest,
+    ) -> Result<GetTriggerResponse, RusotoError<GetTriggerError>> {
+        self.runtime.block_on(self.client.get_trigger(input))
+    }
+
+    /// Blocking version of [`Glue::get_triggers`].
+    pub fn get_triggers(
+        &self,
+        input: GetTriggersRequest,
+    ) -> Result<GetTriggersResponse, RusotoError<GetTriggersError>> {
+        self.runtime.block_on(self.client.get_triggers(input))
+    }
+
+    /// Blocking version of [`Glue::get_user_defined_function`].
+    pub fn get_user_defined_function(
+        &self,
+        input: GetUserDefinedFunctionRequest,
+    ) -> Result<GetUserDefinedFunctionResponse, RusotoError<GetUserDefinedFunctionError>> {
+        self.runtime
+            .block_on(self.client.get_user_defined_function(input))
+    }
+
+    /// Blocking version of [`Glue::get_user_defined_functions`].
+    pub fn get_user_defined_functions(
+        &self,
+        input: GetUserDefinedFunctionsRequest,
+    ) -> Result<GetUserDefinedFunctionsResponse, RusotoError<GetUserDefinedFunctionsError>> {
+        self.runtime
+            .block_on(self.client.get_user_defined_functions(input))
+    }
+
+    /// Blocking version of [`Glue::get_workflow`].
+    pub fn get_workflow(
+        &self,
+        input: GetWorkflowRequest,
+    ) -> Result<GetWorkflowResponse, RusotoError<GetWorkflowError>> {
+        self.runtime.block_on(self.client.get_workflow(input))
+    }
+
+    /// Blocking version of [`Glue::get_workflow_run`].
+    pub fn get_workflow_run(
+        &self,
+        input: GetWorkflowRunRequest,
+    ) -> Result<GetWorkflowRunResponse, RusotoError<GetWorkflowRunError>> {
+        self.runtime.block_on(self.client.get_workflow_run(input))
+    }
+
+    /// Blocking version of [`Glue::get_workflow_run_properties`].
+    pub fn get_workflow_run_properties(
+        &self,
+        input: GetWorkflowRunPropertiesRequest,
+    ) -> Result<GetWorkflowRunPropertiesResponse, RusotoError<GetWorkflowRunPropertiesError>> {
+        self.runtime
+            .block_on(self.client.get_workflow_run_properties(input))
+    }
+
+    /// Blocking version of [`Glue::get_workflow_runs`].
+    pub fn get_workflow_runs(
+        &self,
+        input: GetWorkflowRunsRequest,
+    ) -> Result<GetWorkflowRunsResponse, RusotoError<GetWorkflowRunsError>> {
+        self.runtime.block_on(self.client.get_workflow_runs(input))
+    }
+
+    /// Blocking version of [`Glue::import_catalog_to_glue`].
+    pub fn import_catalog_to_glue(
+        &self,
+        input: ImportCatalogToGlueRequest,
+    ) -> Result<ImportCatalogToGlueResponse, RusotoError<ImportCatalogToGlueError>> {
+        self.runtime
+            .block_on(self.client.import_catalog_to_glue(input))
+    }
+
+    /// Blocking version of [`Glue::list_crawlers`].
+    pub fn list_crawlers(
+        &self,
+        input: ListCrawlersRequest,
+    ) -> Result<ListCrawlersResponse, RusotoError<ListCrawlersError>> {
+        self.runtime.block_on(self.client.list_crawlers(input))
+    }
+
+    /// Blocking version of [`Glue::list_dev_endpoints`].
+    pub fn list_dev_endpoints(
+        &self,
+        input: ListDevEndpointsRequest,
+    ) -> Result<ListDevEndpointsResponse, RusotoError<ListDevEndpointsError>> {
+        self.runtime.block_on(self.client.list_dev_endpoints(input))
+    }
+
+    /// Blocking version of [`Glue::list_jobs`].
+    pub fn list_jobs(
+        &self,
+        input: ListJobsRequest,
+    ) -> Result<ListJobsResponse, RusotoError<ListJobsError>> {
+        self.runtime.block_on(self.client.list_jobs(input))
+    }
+
+    /// Blocking version of [`Glue::list_ml_transforms`].
+    pub fn list_ml_transforms(
+        &self,
+        input: ListMLTransformsRequest,
+    ) -> Result<ListMLTransformsResponse, RusotoError<ListMLTransformsError>> {
+        self.runtime.block_on(self.client.list_ml_transforms(input))
+    }
+
+    /// Blocking version of [`Glue::list_registries`].
+    pub fn list_registries(
+        &self,
+        input: ListRegistriesInput,
+    ) -> Result<ListRegistriesResponse, RusotoError<ListRegistriesError>> {
+        self.runtime.block_on(self.client.list_registries(input))
+    }
+
+    /// Blocking version of [`Glue::list_schema_versions`].
+    pub fn list_schema_versions(
+        &self,
+        input: ListSchemaVersionsInput,
+    ) -> Result<ListSchemaVersionsResponse, RusotoError<ListSchemaVersionsError>> {
+        self.runtime
+            .block_on(self.client.list_schema_versions(input))
+    }
+
+    /// Blocking version of [`Glue::list_schemas`].
+    pub fn list_schemas(
+        &self,
+        input: ListSchemasInput,
+    ) -> Result<ListSchemasResponse, RusotoError<ListSchemasError>> {
+        self.runtime.block_on(self.client.list_schemas(input))
+    }
+
+    /// Blocking version of [`Glue::list_triggers`].
+    pub fn list_triggers(
+        &self,
+        input: ListTriggersRequest,
+    ) -> Result<ListTriggersResponse, RusotoError<ListTriggersError>> {
+        self.runtime.block_on(self.client.list_triggers(input))
+    }
+
+    /// Blocking version of [`Glue::list_workflows`].
+    pub fn list_workflows(
+        &self,
+        input: ListWorkflowsRequest,
+    ) -> Result<ListWorkflowsResponse, RusotoError<ListWorkflowsError>> {
+        self.runtime.block_on(self.client.list_workflows(input))
+    }
+
+    /// Blocking version of [`Glue::put_data_catalog_encryption_settings`].
+    pub fn put_data_catalog_encryption_settings(
+        &self,
+        input: PutDataCatalogEncryptionSettingsRequest,
+    ) -> Result<
+        PutDataCatalogEncryptionSettingsResponse,
+        RusotoError<PutDataCatalogEncryptionSettingsError>,
+    > {
+        self.runtime
+            .block_on(self.client.put_data_catalog_encryption_settings(input))
+    }
+
+    /// Blocking version of [`Glue::put_resource_policy`].
+    pub fn put_resource_policy(
+        &self,
+        input: PutResourcePolicyRequest,
+    ) -> Result<PutResourcePolicyResponse, RusotoError<PutResourcePolicyError>> {
+        self.runtime
+            .block_on(self.client.put_resource_policy(input))
+    }
+
+    /// Blocking version of [`Glue::put_schema_version_metadata`].
+    pub fn put_schema_version_metadata(
+        &self,
+        input: PutSchemaVersionMetadataInput,
+    ) -> Result<PutSchemaVersionMetadataResponse, RusotoError<PutSchemaVersionMetadataError>> {
+        self.runtime
+            .block_on(self.client.put_schema_version_metadata(input))
+    }
+
+    /// Blocking version of [`Glue::put_workflow_run_properties`].
+    pub fn put_workflow_run_properties(
+        &self,
+        input: PutWorkflowRunPropertiesRequest,
+    ) -> Result<PutWorkflowRunPropertiesResponse, RusotoError<PutWorkflowRunPropertiesError>> {
+        self.runtime
+            .block_on(self.client.put_workflow_run_properties(input))
+    }
+
+    /// Blocking version of [`Glue::query_schema_version_metadata`].
+    pub fn query_schema_version_metadata(
+        &self,
+        input: QuerySchemaVersionMetadataInput,
+    ) -> Result<QuerySchemaVersionMetadataResponse, RusotoError<QuerySchemaVersionMetadataError>>
+    {
+        self.runtime
+            .block_on(self.client.query_schema_version_metadata(input))
+    }
+
+    /// Blocking version of [`Glue::register_schema_version`].
+    pub fn register_schema_version(
+        &self,
+        input: RegisterSchemaVersionInput,
+    ) -> Result<RegisterSchemaVersionResponse, RusotoError<RegisterSchemaVersionError>> {
+        self.runtime
+            .block_on(self.client.register_schema_version(input))
+    }
+
+    /// Blocking version of [`Glue::remove_schema_version_metadata`].
+    pub fn remove_schema_version_metadata(
+        &self,
+        input: RemoveSchemaVersionMetadataInput,
+    ) -> Result<RemoveSchemaVersionMetadataResponse, RusotoError<RemoveSchemaVersionMetadataError>>
+    {
+        self.runtime
+            .block_on(self.client.remove_schema_version_metadata(input))
+    }
+
+    /// Blocking version of [`Glue::reset_job_bookmark`].
+    pub fn reset_job_bookmark(
+        &self,
+        input: ResetJobBookmarkRequest,
+    ) -> Result<ResetJobBookmarkResponse, RusotoError<ResetJobBookmarkError>> {
+        self.runtime.block_on(self.client.reset_job_bookmark(input))
+    }
+
+    /// Blocking version of [`Glue::resume_workflow_run`].
+    pub fn resume_workflow_run(
+        &self,
+        input: ResumeWorkflowRunRequest,
+    ) -> Result<ResumeWorkflowRunResponse, RusotoError<ResumeWorkflowRunError>> {
+        self.runtime
+            .block_on(self.client.resume_workflow_run(input))
+    }
+
+    /// Blocking version of [`Glue::search_tables`].
+    pub fn search_tables(
+        &self,
+        input: SearchTablesRequest,
+    ) -> Result<SearchTablesResponse, RusotoError<SearchTablesError>> {
+        self.runtime.block_on(self.client.search_tables(input))
+    }
+
+    /// Blocking version of [`Glue::start_crawler`].
+    pub fn start_crawler(
+        &self,
+        input: StartCrawlerRequest,
+    ) -> Result<StartCrawlerResponse, RusotoError<StartCrawlerError>> {
+        self.runtime.block_on(self.client.start_crawler(input))
+    }
+
+    /// Blocking version of [`Glue::start_crawler_schedule`].
+    pub fn start_crawler_schedule(
+        &self,
+        input: StartCrawlerScheduleRequest,
+    ) -> Result<StartCrawlerScheduleResponse, RusotoError<StartCrawlerScheduleError>> {
+        self.runtime
+            .block_on(self.client.start_crawler_schedule(input))
+    }
+
+    /// Blocking version of [`Glue::start_export_labels_task_run`].
+    pub fn start_export_labels_task_run(
+        &self,
+        input: StartExportLabelsTaskRunRequest,
+    ) -> Result<StartExportLabelsTaskRunResponse, RusotoError<StartExportLabelsTaskRunError>> {
+        self.runtime
+            .block_on(self.client.start_export_labels_task_run(input))
+    }
+
+    /// Blocking version of [`Glue::start_import_labels_task_run`].
+    pub fn start_import_labels_task_run(
+        &self,
+        input: StartImportLabelsTaskRunRequest,
+    ) -> Result<StartImportLabelsTaskRunResponse, RusotoError<StartImportLabelsTaskRunError>> {
+        self.runtime
+            .block_on(self.client.start_import_labels_task_run(input))
+    }
+
+    /// Blocking version of [`Glue::start_job_run`].
+    pub fn start_job_run(
+        &self,
+        input: StartJobRunRequest,
+    ) -> Result<StartJobRunResponse, RusotoError<StartJobRunError>> {
+        self.runtime.block_on(self.client.start_job_run(input))
+    }
+
+    /// Blocking version of [`Glue::start_ml_evaluation_task_run`].
+    pub fn start_ml_evaluation_task_run(
+        &self,
+        input: StartMLEvaluationTaskRunRequest,
+    ) -> Result<StartMLEvaluationTaskRunResponse, RusotoError<StartMLEvaluationTaskRunError>> {
+        self.runtime
+            .block_on(self.client.start_ml_evaluation_task_run(input))
+    }
+
+    /// Blocking version of [`Glue::start_ml_labeling_set_generation_task_run`].
+    pub fn start_ml_labeling_set_generation_task_run(
+        &self,
+        input: StartMLLabelingSetGenerationTaskRunRequest,
+    ) -> Result<
+        StartMLLabelingSetGenerationTaskRunResponse,
+        RusotoError<StartMLLabelingSetGenerationTaskRunError>,
+    > {
+        self.runtime
+            .block_on(self.client.start_ml_labeling_set_generation_task_run(input))
+    }
+
+    /// Blocking version of [`Glue::start_trigger`].
+    pub fn start_trigger(
+        &self,
+        input: StartTriggerRequest,
+    ) -> Result<StartTriggerResponse, RusotoError<StartTriggerError>> {
+        self.runtime.block_on(self.client.start_trigger(input))
+    }
+
+    /// Blocking version of [`Glue::start_workflow_run`].
+    pub fn start_workflow_run(
+        &self,
+        input: StartWorkflowRunRequest,
+    ) -> Result<StartWorkflowRunResponse, RusotoError<StartWorkflowRunError>> {
+        self.runtime.block_on(self.client.start_workflow_run(input))
+    }
+
+    /// Blocking version of [`Glue::stop_crawler`].
+    pub fn stop_crawler(
+        &self,
+        input: StopCrawlerRequest,
+    ) -> Result<StopCrawlerResponse, RusotoError<StopCrawlerError>> {
+        self.runtime.block_on(self.client.stop_crawler(input))
+    }
+
+    /// Blocking version of [`Glue::stop_crawler_schedule`].
+    pub fn stop_crawler_schedule(
+        &self,
+        input: StopCrawlerScheduleRequest,
+    ) -> Result<StopCrawlerScheduleResponse, RusotoError<StopCrawlerScheduleError>> {
+        self.runtime
+            .block_on(self.client.stop_crawler_schedule(input))
+    }
+
+    /// Blocking version of [`Glue::stop_trigger`].
+    pub fn stop_trigger(
+        &self,
+        input: StopTriggerRequest,
+    ) -> Result<StopTriggerResponse, RusotoError<StopTriggerError>> {
+        self.runtime.block_on(self.client.stop_trigger(input))
+    }
+
+    /// Blocking version of [`Glue::stop_workflow_run`].
+    pub fn stop_workflow_run(
+        &self,
+        input: StopWorkflowRunRequest,
+    ) -> Result<StopWorkflowRunResponse, RusotoError<StopWorkflowRunError>> {
+        self.runtime.block_on(self.client.stop_workflow_run(input))
+    }
+
+    /// Blocking version of [`Glue::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`Glue::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`Glue::update_classifier`].
+    pub fn update_classifier(
+        &self,
+        input: UpdateClassifierRequest,
+    ) -> Result<UpdateClassifierResponse, RusotoError<UpdateClassifierError>> {
+        self.runtime.block_on(self.client.update_classifier(input))
+    }
+
+    /// Blocking version of [`Glue::update_column_statistics_for_partition`].
+    pub fn update_column_statistics_for_partition(
+        &self,
+        input: UpdateColumnStatisticsForPartitionRequest,
+    ) -> Result<
+        UpdateColumnStatisticsForPartitionResponse,
+        RusotoError<UpdateColumnStatisticsForPartitionError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_column_statistics_for_partition(input))
+    }
+
+    /// Blocking version of [`Glue::update_column_statistics_for_table`].
+    pub fn update_column_statistics_for_table(
+        &self,
+        input: UpdateColumnStatisticsForTableRequest,
+    ) -> Result<
+        UpdateColumnStatisticsForTableResponse,
+        RusotoError<UpdateColumnStatisticsForTableError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_column_statistics_for_table(input))
+    }
+
+    /// Blocking version of [`Glue::update_connection`].
+    pub fn update_connection(
+        &self,
+        input: UpdateConnectionRequest,
+    ) -> Result<UpdateConnectionResponse, RusotoError<UpdateConnectionError>> {
+        self.runtime.block_on(self.client.update_connection(input))
+    }
+
+    /// Blocking version of [`Glue::update_crawler`].
+    pub fn update_crawler(
+        &self,
+        input: UpdateCrawlerRequest,
+    ) -> Result<UpdateCrawlerResponse, RusotoError<UpdateCrawlerError>> {
+        self.runtime.block_on(self.client.update_crawler(input))
+    }
+
+    /// Blocking version of [`Glue::update_crawler_schedule`].
+    pub fn update_crawler_schedule(
+        &self,
+        input: UpdateCrawlerScheduleRequest,
+    ) -> Result<UpdateCrawlerScheduleResponse, RusotoError<UpdateCrawlerScheduleError>> {
+        self.runtime
+            .block_on(self.client.update_crawler_schedule(input))
+    }
+
+    /// Blocking version of [`Glue::update_database`].
+    pub fn update_database(
+        &self,
+        input: UpdateDatabaseRequest,
+    ) -> Result<UpdateDatabaseResponse, RusotoError<UpdateDatabaseError>> {
+        self.runtime.block_on(self.client.update_database(input))
+    }
+
+    /// Blocking version of [`Glue::update_dev_endpoint`].
+    pub fn update_dev_endpoint(
+        &self,
+        input: UpdateDevEndpointRequest,
+    ) -> Result<UpdateDevEndpointResponse, RusotoError<UpdateDevEndpointError>> {
+        self.runtime
+            .block_on(self.client.update_dev_endpoint(input))
+    }
+
+    /// Blocking version of [`Glue::update_job`].
+    pub fn update_job(
+        &self,
+        input: UpdateJobRequest,
+    ) -> Result<UpdateJobResponse, RusotoError<UpdateJobError>> {
+        self.runtime.block_on(self.client.update_job(input))
+    }
+
+    /// Blocking version of [`Glue::update_ml_transform`].
+    pub fn update_ml_transform(
+        &self,
+        input: UpdateMLTransformRequest,
+    ) -> Result<UpdateMLTransformResponse, RusotoError<UpdateMLTransformError>> {
+        self.runtime
+            .block_on(self.client.update_ml_transform(input))
+    }
+
+    /// Blocking version of [`Glue::update_partition`].
+    pub fn update_partition(
+        &self,
+        input: UpdatePartitionRequest,
+    ) -> Result<UpdatePartitionResponse, RusotoError<UpdatePartitionError>> {
+        self.runtime.block_on(self.client.update_partition(input))
+    }
+
+    /// Blocking version of [`Glue::update_registry`].
+    pub fn update_registry(
+        &self,
+        input: UpdateRegistryInput,
+    ) -> Result<UpdateRegistryResponse, RusotoError<UpdateRegistryError>> {
+        self.runtime.block_on(self.client.update_registry(input))
+    }
+
+    /// Blocking version of [`Glue::update_schema`].
+    pub fn update_schema(
+        &self,
+        input: UpdateSchemaInput,
+    ) -> Result<UpdateSchemaResponse, RusotoError<UpdateSchemaError>> {
+        self.runtime.block_on(self.client.update_schema(input))
+    }
+
+    /// Blocking version of [`Glue::update_table`].
+    pub fn update_table(
+        &self,
+        input: UpdateTableRequest,
+    ) -> Result<UpdateTableResponse, RusotoError<UpdateTableError>> {
+        self.runtime.block_on(self.client.update_table(input))
+    }
+
+    /// Blocking version of [`Glue::update_trigger`].
+    pub fn update_trigger(
+        &self,
+        input: UpdateTriggerRequest,
+    ) -> Result<UpdateTriggerResponse, RusotoError<UpdateTriggerError>> {
+        self.runtime.block_on(self.client.update_trigger(input))
+    }
+
+    /// Blocking version of [`Glue::update_user_defined_function`].
+    pub fn update_user_defined_function(
+        &self,
+        input: UpdateUserDefinedFunctionRequest,
+    ) -> Result<UpdateUserDefinedFunctionResponse, RusotoError<UpdateUserDefinedFunctionError>>
+    {
+        self.runtime
+            .block_on(self.client.update_user_defined_function(input))
+    }
+
+    /// Blocking version of [`Glue::update_workflow`].
+    pub fn update_workflow(
+        &self,
+        input: UpdateWorkflowRequest,
+    ) -> Result<UpdateWorkflowResponse, RusotoError<UpdateWorkflowError>> {
+        self.runtime.block_on(self.client.update_workflow(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -3527,14 +3528,22 @@ impl AssociateRoleToGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(AssociateRoleToGroupError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateRoleToGroupError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(AssociateRoleToGroupError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateRoleToGroupError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3567,16 +3576,22 @@ impl AssociateServiceRoleToAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(AssociateServiceRoleToAccountError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateServiceRoleToAccountError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        AssociateServiceRoleToAccountError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateServiceRoleToAccountError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3607,11 +3622,14 @@ impl CreateConnectorDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateConnectorDefinitionError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateConnectorDefinitionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3641,11 +3659,14 @@ impl CreateConnectorDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateConnectorDefinitionVersionError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateConnectorDefinitionVersionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3673,9 +3694,14 @@ impl CreateCoreDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateCoreDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCoreDefinitionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3705,11 +3731,14 @@ impl CreateCoreDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateCoreDefinitionVersionError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCoreDefinitionVersionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3737,9 +3766,14 @@ impl CreateDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDeploymentError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3767,9 +3801,14 @@ impl CreateDeviceDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDeviceDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeviceDefinitionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3799,11 +3838,14 @@ impl CreateDeviceDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDeviceDefinitionVersionError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDeviceDefinitionVersionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3831,9 +3873,14 @@ impl CreateFunctionDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateFunctionDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFunctionDefinitionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3863,11 +3910,14 @@ impl CreateFunctionDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateFunctionDefinitionVersionError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateFunctionDefinitionVersionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3895,9 +3945,14 @@ impl CreateGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateGroupError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGroupError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3929,16 +3984,22 @@ impl CreateGroupCertificateAuthorityError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateGroupCertificateAuthorityError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateGroupCertificateAuthorityError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        CreateGroupCertificateAuthorityError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGroupCertificateAuthorityError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3969,9 +4030,14 @@ impl CreateGroupVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateGroupVersionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGroupVersionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3999,9 +4065,14 @@ impl CreateLoggerDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateLoggerDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLoggerDefinitionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4031,11 +4102,14 @@ impl CreateLoggerDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateLoggerDefinitionVersionError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateLoggerDefinitionVersionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4063,9 +4137,14 @@ impl CreateResourceDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateResourceDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResourceDefinitionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4095,11 +4174,14 @@ impl CreateResourceDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateResourceDefinitionVersionError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateResourceDefinitionVersionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4129,14 +4211,22 @@ impl CreateSoftwareUpdateJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateSoftwareUpdateJobError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSoftwareUpdateJobError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateSoftwareUpdateJobError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSoftwareUpdateJobError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4167,11 +4257,14 @@ impl CreateSubscriptionDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateSubscriptionDefinitionError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSubscriptionDefinitionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4201,11 +4294,14 @@ impl CreateSubscriptionDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        CreateSubscriptionDefinitionVersionError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSubscriptionDefinitionVersionError::BadRequest,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4235,11 +4331,14 @@ impl DeleteConnectorDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteConnectorDefinitionError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteConnectorDefinitionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4267,9 +4366,14 @@ impl DeleteCoreDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteCoreDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCoreDefinitionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4297,9 +4401,14 @@ impl DeleteDeviceDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteDeviceDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDeviceDefinitionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4327,9 +4436,14 @@ impl DeleteFunctionDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteFunctionDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFunctionDefinitionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4357,9 +4471,14 @@ impl DeleteGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteGroupError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGroupError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4387,9 +4506,14 @@ impl DeleteLoggerDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteLoggerDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLoggerDefinitionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4417,9 +4541,14 @@ impl DeleteResourceDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteResourceDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteResourceDefinitionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4449,11 +4578,14 @@ impl DeleteSubscriptionDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteSubscriptionDefinitionError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSubscriptionDefinitionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4483,16 +4615,22 @@ impl DisassociateRoleFromGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DisassociateRoleFromGroupError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateRoleFromGroupError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        DisassociateRoleFromGroupError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateRoleFromGroupError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4525,11 +4663,14 @@ impl DisassociateServiceRoleFromAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        DisassociateServiceRoleFromAccountError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateServiceRoleFromAccountError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4561,14 +4702,22 @@ impl GetAssociatedRoleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetAssociatedRoleError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAssociatedRoleError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetAssociatedRoleError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetAssociatedRoleError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4597,9 +4746,14 @@ impl GetBulkDeploymentStatusError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBulkDeploymentStatusError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBulkDeploymentStatusError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4629,14 +4783,22 @@ impl GetConnectivityInfoError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetConnectivityInfoError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConnectivityInfoError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetConnectivityInfoError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetConnectivityInfoError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4665,9 +4827,14 @@ impl GetConnectorDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetConnectorDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConnectorDefinitionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4697,11 +4864,14 @@ impl GetConnectorDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetConnectorDefinitionVersionError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetConnectorDefinitionVersionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4729,9 +4899,14 @@ impl GetCoreDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetCoreDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCoreDefinitionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4759,9 +4934,14 @@ impl GetCoreDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetCoreDefinitionVersionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCoreDefinitionVersionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4789,9 +4969,14 @@ impl GetDeploymentStatusError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDeploymentStatusError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeploymentStatusError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4819,9 +5004,14 @@ impl GetDeviceDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDeviceDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeviceDefinitionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4851,11 +5041,14 @@ impl GetDeviceDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDeviceDefinitionVersionError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDeviceDefinitionVersionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4883,9 +5076,14 @@ impl GetFunctionDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetFunctionDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFunctionDefinitionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4915,11 +5113,14 @@ impl GetFunctionDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetFunctionDefinitionVersionError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetFunctionDefinitionVersionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4947,9 +5148,14 @@ impl GetGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetGroupError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGroupError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4981,16 +5187,22 @@ impl GetGroupCertificateAuthorityError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetGroupCertificateAuthorityError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetGroupCertificateAuthorityError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        GetGroupCertificateAuthorityError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGroupCertificateAuthorityError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5025,16 +5237,22 @@ impl GetGroupCertificateConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetGroupCertificateConfigurationError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetGroupCertificateConfigurationError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        GetGroupCertificateConfigurationError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGroupCertificateConfigurationError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5065,9 +5283,14 @@ impl GetGroupVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetGroupVersionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGroupVersionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5095,9 +5318,14 @@ impl GetLoggerDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetLoggerDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLoggerDefinitionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5127,11 +5355,14 @@ impl GetLoggerDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetLoggerDefinitionVersionError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetLoggerDefinitionVersionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5159,9 +5390,14 @@ impl GetResourceDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetResourceDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourceDefinitionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5191,11 +5427,14 @@ impl GetResourceDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetResourceDefinitionVersionError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetResourceDefinitionVersionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5223,11 +5462,14 @@ impl GetServiceRoleForAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        GetServiceRoleForAccountError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetServiceRoleForAccountError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5255,11 +5497,14 @@ impl GetSubscriptionDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetSubscriptionDefinitionError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSubscriptionDefinitionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5289,11 +5534,14 @@ impl GetSubscriptionDefinitionVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetSubscriptionDefinitionVersionError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSubscriptionDefinitionVersionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5325,16 +5573,22 @@ impl GetThingRuntimeConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetThingRuntimeConfigurationError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetThingRuntimeConfigurationError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        GetThingRuntimeConfigurationError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetThingRuntimeConfigurationError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5367,11 +5621,14 @@ impl ListBulkDeploymentDetailedReportsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        ListBulkDeploymentDetailedReportsError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBulkDeploymentDetailedReportsError::BadRequest,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5399,9 +5656,14 @@ impl ListBulkDeploymentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListBulkDeploymentsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBulkDeploymentsError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5431,11 +5693,14 @@ impl ListConnectorDefinitionVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListConnectorDefinitionVersionsError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListConnectorDefinitionVersionsError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5459,7 +5724,7 @@ impl ListConnectorDefinitionsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListConnectorDefinitionsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5487,11 +5752,14 @@ impl ListCoreDefinitionVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListCoreDefinitionVersionsError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListCoreDefinitionVersionsError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5515,7 +5783,7 @@ impl ListCoreDefinitionsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListCoreDefinitionsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5541,9 +5809,14 @@ impl ListDeploymentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListDeploymentsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDeploymentsError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5573,11 +5846,14 @@ impl ListDeviceDefinitionVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListDeviceDefinitionVersionsError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDeviceDefinitionVersionsError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5601,7 +5877,7 @@ impl ListDeviceDefinitionsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListDeviceDefinitionsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5629,11 +5905,14 @@ impl ListFunctionDefinitionVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListFunctionDefinitionVersionsError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListFunctionDefinitionVersionsError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5657,7 +5936,7 @@ impl ListFunctionDefinitionsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListFunctionDefinitionsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5687,16 +5966,22 @@ impl ListGroupCertificateAuthoritiesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListGroupCertificateAuthoritiesError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListGroupCertificateAuthoritiesError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        ListGroupCertificateAuthoritiesError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGroupCertificateAuthoritiesError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5727,9 +6012,14 @@ impl ListGroupVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListGroupVersionsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGroupVersionsError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5753,7 +6043,7 @@ impl ListGroupsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListGroupsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5781,11 +6071,14 @@ impl ListLoggerDefinitionVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListLoggerDefinitionVersionsError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListLoggerDefinitionVersionsError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5809,7 +6102,7 @@ impl ListLoggerDefinitionsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListLoggerDefinitionsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5837,11 +6130,14 @@ impl ListResourceDefinitionVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListResourceDefinitionVersionsError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResourceDefinitionVersionsError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5865,7 +6161,7 @@ impl ListResourceDefinitionsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListResourceDefinitionsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5893,11 +6189,14 @@ impl ListSubscriptionDefinitionVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        ListSubscriptionDefinitionVersionsError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSubscriptionDefinitionVersionsError::BadRequest,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5925,7 +6224,7 @@ impl ListSubscriptionDefinitionsError {
     ) -> RusotoError<ListSubscriptionDefinitionsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5951,9 +6250,14 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5981,9 +6285,14 @@ impl ResetDeploymentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ResetDeploymentsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResetDeploymentsError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6011,9 +6320,14 @@ impl StartBulkDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StartBulkDeploymentError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartBulkDeploymentError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6041,9 +6355,14 @@ impl StopBulkDeploymentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StopBulkDeploymentError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopBulkDeploymentError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6071,9 +6390,14 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6101,9 +6425,14 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6133,14 +6462,22 @@ impl UpdateConnectivityInfoError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateConnectivityInfoError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateConnectivityInfoError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateConnectivityInfoError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateConnectivityInfoError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6169,11 +6506,14 @@ impl UpdateConnectorDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateConnectorDefinitionError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateConnectorDefinitionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6201,9 +6541,14 @@ impl UpdateCoreDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateCoreDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCoreDefinitionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6231,9 +6576,14 @@ impl UpdateDeviceDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateDeviceDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeviceDefinitionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6261,9 +6611,14 @@ impl UpdateFunctionDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateFunctionDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFunctionDefinitionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6291,9 +6646,14 @@ impl UpdateGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateGroupError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGroupError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6325,16 +6685,22 @@ impl UpdateGroupCertificateConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        UpdateGroupCertificateConfigurationError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGroupCertificateConfigurationError::BadRequest,
                     )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        UpdateGroupCertificateConfigurationError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGroupCertificateConfigurationError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6367,9 +6733,14 @@ impl UpdateLoggerDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateLoggerDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateLoggerDefinitionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6397,9 +6768,14 @@ impl UpdateResourceDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateResourceDefinitionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateResourceDefinitionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6429,11 +6805,14 @@ impl UpdateSubscriptionDefinitionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateSubscriptionDefinitionError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSubscriptionDefinitionError::BadRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6465,16 +6844,22 @@ impl UpdateThingRuntimeConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateThingRuntimeConfigurationError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateThingRuntimeConfigurationError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        UpdateThingRuntimeConfigurationError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThingRuntimeConfigurationError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7116,11 +7501,12 @@ pub struct GreenGrassClient {
 impl GreenGrassClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> GreenGrassClient {
         GreenGrassClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Greengrass"),
         }
     }
 
@@ -7135,12 +7521,32 @@ impl GreenGrassClient {
     {
         GreenGrassClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Greengrass"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> GreenGrassClient {
-        GreenGrassClient { client, region }
+        GreenGrassClient {
+            client,
+            region: region_for_service(region, "Greengrass"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> GreenGrassClient {
+        GreenGrassClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Greengrass"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> GreenGrassClient {
+        GreenGrassClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -7158,6 +7564,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("AssociateRoleToGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7192,6 +7599,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/servicerole";
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("AssociateServiceRoleToAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7224,6 +7632,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/connectors";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateConnectorDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7262,6 +7671,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateConnectorDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7296,6 +7706,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/cores";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateCoreDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7332,6 +7743,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateCoreDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7367,6 +7779,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7399,6 +7812,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/devices";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateDeviceDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7437,6 +7851,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateDeviceDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7469,6 +7884,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/functions";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateFunctionDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7507,6 +7923,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateFunctionDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7541,6 +7958,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/groups";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7579,6 +7997,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateGroupCertificateAuthority");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_optional_header("X-Amzn-Client-Token", input.amzn_client_token.as_ref());
@@ -7614,6 +8033,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateGroupVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7646,6 +8066,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/loggers";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateLoggerDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7684,6 +8105,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateLoggerDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7716,6 +8138,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/resources";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateResourceDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7754,6 +8177,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateResourceDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7788,6 +8212,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/updates";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateSoftwareUpdateJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7821,6 +8246,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/subscriptions";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateSubscriptionDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7859,6 +8285,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("CreateSubscriptionDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7897,6 +8324,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DeleteConnectorDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7928,6 +8356,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DeleteCoreDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7959,6 +8388,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DeleteDeviceDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7990,6 +8420,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DeleteFunctionDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8018,6 +8449,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/greengrass/groups/{group_id}", group_id = input.group_id);
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DeleteGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8049,6 +8481,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DeleteLoggerDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8080,6 +8513,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DeleteResourceDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8112,6 +8546,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DeleteSubscriptionDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8144,6 +8579,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DisassociateRoleFromGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8174,6 +8610,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/servicerole";
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("DisassociateServiceRoleFromAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8207,6 +8644,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetAssociatedRole");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8238,6 +8676,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetBulkDeploymentStatus");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8269,6 +8708,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetConnectivityInfo");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8300,6 +8740,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetConnectorDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8331,6 +8772,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/greengrass/definition/connectors/{connector_definition_id}/versions/{connector_definition_version_id}", connector_definition_id = input.connector_definition_id, connector_definition_version_id = input.connector_definition_version_id);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetConnectorDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8368,6 +8810,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetCoreDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8396,6 +8839,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/greengrass/definition/cores/{core_definition_id}/versions/{core_definition_version_id}", core_definition_id = input.core_definition_id, core_definition_version_id = input.core_definition_version_id);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetCoreDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8428,6 +8872,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetDeploymentStatus");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8459,6 +8904,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetDeviceDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8488,6 +8934,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/greengrass/definition/devices/{device_definition_id}/versions/{device_definition_version_id}", device_definition_id = input.device_definition_id, device_definition_version_id = input.device_definition_version_id);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetDeviceDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8525,6 +8972,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetFunctionDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8554,6 +9002,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/greengrass/definition/functions/{function_definition_id}/versions/{function_definition_version_id}", function_definition_id = input.function_definition_id, function_definition_version_id = input.function_definition_version_id);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetFunctionDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8588,6 +9037,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/greengrass/groups/{group_id}", group_id = input.group_id);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8621,6 +9071,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetGroupCertificateAuthority");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8655,6 +9106,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetGroupCertificateConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8689,6 +9141,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetGroupVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8720,6 +9173,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetLoggerDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8749,6 +9203,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/greengrass/definition/loggers/{logger_definition_id}/versions/{logger_definition_version_id}", logger_definition_id = input.logger_definition_id, logger_definition_version_id = input.logger_definition_version_id);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetLoggerDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8786,6 +9241,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetResourceDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8815,6 +9271,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/greengrass/definition/resources/{resource_definition_id}/versions/{resource_definition_version_id}", resource_definition_id = input.resource_definition_id, resource_definition_version_id = input.resource_definition_version_id);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetResourceDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8842,6 +9299,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/servicerole";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetServiceRoleForAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8874,6 +9332,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetSubscriptionDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8905,6 +9364,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/greengrass/definition/subscriptions/{subscription_definition_id}/versions/{subscription_definition_version_id}", subscription_definition_id = input.subscription_definition_id, subscription_definition_version_id = input.subscription_definition_version_id);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetSubscriptionDefinitionVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8945,6 +9405,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("GetThingRuntimeConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8979,6 +9440,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListBulkDeploymentDetailedReports");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9018,6 +9480,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/bulk/deployments";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListBulkDeployments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9061,6 +9524,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListConnectorDefinitionVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9100,6 +9564,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/connectors";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListConnectorDefinitions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9141,6 +9606,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListCoreDefinitionVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9178,6 +9644,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/cores";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListCoreDefinitions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9218,6 +9685,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListDeployments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9259,6 +9727,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListDeviceDefinitionVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9296,6 +9765,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/devices";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListDeviceDefinitions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9339,6 +9809,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListFunctionDefinitionVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9376,6 +9847,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/functions";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListFunctionDefinitions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9419,6 +9891,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListGroupCertificateAuthorities");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9452,6 +9925,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListGroupVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9489,6 +9963,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/groups";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9530,6 +10005,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListLoggerDefinitionVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9567,6 +10043,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/loggers";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListLoggerDefinitions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9610,6 +10087,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListResourceDefinitionVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9647,6 +10125,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/resources";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListResourceDefinitions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9690,6 +10169,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListSubscriptionDefinitionVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9730,6 +10210,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/definition/subscriptions";
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListSubscriptionDefinitions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9767,6 +10248,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "greengrass", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9798,6 +10280,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("ResetDeployments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9830,6 +10313,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = "/greengrass/bulk/deployments";
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("StartBulkDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9865,6 +10349,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("StopBulkDeployment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -9893,6 +10378,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "greengrass", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9923,6 +10409,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "greengrass", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -9959,6 +10446,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateConnectivityInfo");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -9994,6 +10482,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateConnectorDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10028,6 +10517,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateCoreDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10062,6 +10552,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateDeviceDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10096,6 +10587,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateFunctionDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10127,6 +10619,7 @@ impl GreenGrass for GreenGrassClient {
         let request_uri = format!("/greengrass/groups/{group_id}", group_id = input.group_id);
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10164,6 +10657,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateGroupCertificateConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10200,6 +10694,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateLoggerDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10234,6 +10729,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateResourceDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10269,6 +10765,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateSubscriptionDefinition");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10306,6 +10803,7 @@ impl GreenGrass for GreenGrassClient {
         );
 
         let mut request = SignedRequest::new("PUT", "greengrass", &self.region, &request_uri);
+        request.set_operation("UpdateThingRuntimeConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -10330,3 +10828,972 @@ impl GreenGrass for GreenGrassClient {
         }
     }
 }
+
+/// A blocking client for the AWS Greengrass API, running the calls of a `GreenGrassClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `GreenGrassClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct GreenGrassBlockingClient {
+    client: GreenGrassClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl GreenGrassBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> GreenGrassBlockingClient {
+        GreenGrassBlockingClient::from_client(GreenGrassClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> GreenGrassBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        GreenGrassBlockingClient::from_client(GreenGrassClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> GreenGrassBlockingClient {
+        GreenGrassBlockingClient::from_client(GreenGrassClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> GreenGrassBlockingClient {
+        GreenGrassBlockingClient::from_client(GreenGrassClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: GreenGrassClient) -> GreenGrassBlockingClient {
+        GreenGrassBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> GreenGrassBlockingClient {
+        GreenGrassBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> GreenGrassBlockingClient {
+        GreenGrassBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`GreenGrass::associate_role_to_group`].
+    pub fn associate_role_to_group(
+        &self,
+        input: AssociateRoleToGroupRequest,
+    ) -> Result<AssociateRoleToGroupResponse, RusotoError<AssociateRoleToGroupError>> {
+        self.runtime
+            .block_on(self.client.associate_role_to_group(input))
+    }
+
+    /// Blocking version of [`GreenGrass::associate_service_role_to_account`].
+    pub fn associate_service_role_to_account(
+        &self,
+        input: AssociateServiceRoleToAccountRequest,
+    ) -> Result<
+        AssociateServiceRoleToAccountResponse,
+        RusotoError<AssociateServiceRoleToAccountError>,
+    > {
+        self.runtime
+            .block_on(self.client.associate_service_role_to_account(input))
+    }
+
+    /// Blocking version of [`GreenGrass::create_connector_definition`].
+    pub fn create_connector_definition(
+        &self,
+        input: CreateConnectorDefinitionRequest,
+    ) -> Result<CreateConnectorDefinitionResponse, RusotoError<CreateConnectorDefinitionError>>
+    {
+        self.runtime
+            .block_on(self.client.create_connector_definition(input))
+    }
+
+    /// Blocking version of [`GreenGrass::create_connector_definition_version`].
+    pub fn create_connector_definition_version(
+        &self,
+        input: CreateConnectorDefinitionVersionRequest,
+    ) -> Result<
+        CreateConnectorDefinitionVersionResponse,
+        RusotoError<CreateConnectorDefinitionVersionError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_connector_definition_version(input))
+    }
+
+    /// Blocking version of [`GreenGrass::create_core_definition`].
+    pub fn create_core_definition(
+        &self,
+        input: CreateCoreDefinitionRequest,
+    ) -> Result<CreateCoreDefinitionResponse, RusotoError<CreateCoreDefinitionError>> {
+        self.runtime
+            .block_on(self.client.create_core_definition(input))
+    }
+
+    /// Blocking version of [`GreenGrass::create_core_definition_version`].
+    pub fn create_core_definition_version(
+        &self,
+        input: CreateCoreDefinitionVersionRequest,
+    ) -> Result<CreateCoreDefinitionVersionResponse, RusotoError<CreateCoreDefinitionVersionError>>
+    {
+        self.runtime
+            .block_on(self.client.create_core_definition_version(input))
+    }
+
+    /// Blocking version of [`GreenGrass::create_deployment`].
+    pub fn create_deployment(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> Result<CreateDeploymentResponse, RusotoError<CreateDeploymentError>> {
+        self.runtime.block_on(self.client.create_deployment(input))
+    }
+
+    /// Blocking version of [`GreenGrass::create_device_definition`].
+    pub fn create_device_definition(
+        &self,
+        input: CreateDeviceDefinitionRequest,
+    ) -> Result<CreateDeviceDefinitionResponse, RusotoError<CreateDeviceDefinitionError>> {
+        self.runtime
+            .block_on(self.client.create_device_definition(input))
+    }
+
+    /// Blocking version of [`GreenGrass::create_device_definition_version`].
+    pub fn create_device_definition_version(
+        &self,
+        input: CreateDeviceDefinitionVersionRequest,
+    ) -> Result<
+        CreateDeviceDefinitionVersionResponse,
+        RusotoError<CreateDeviceDefinitionVersionError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_device_definition_version(input))
+    }
+
+    /// Blocking version of [`GreenGrass::create_function_definition`].
+    pub fn create_function_definition(
+        &self,
+        input: CreateFunctionDefinitionRequest,
+    ) -> Result<CreateFunctionDefinitionResponse, RusotoError<CreateFunctionDefinitionError>> {
+        self.runtime
+            .block_on(self.client.create_function_definition(input))
+    }
+
+    /// Blocking version of [`GreenGrass::create_function_definition_version`].
+    pub fn create_function_definition_version(
+        &self,
+        input: CreateFunctionDefinitionVersionRequest,
+    ) -> Result<
+        CreateFunctionDefinitionVersionResponse,
+        RusotoError<CreateFunctionDefinitionVersionError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_function_definition_version(input))
+    }
+
+    /// Blocking version of [`GreenGrass::create_group`].
+    pub fn create_group(
+        &self,
+        input: CreateGroupRequest,
+    ) -> Result<CreateGroupResponse, RusotoError<CreateGroupError>> {
+        self.runtime.block_on(self.client.create_group(input))
+    }
+
+    /// Blocking version of [`GreenGrass::create_group_certificate_authority`].
+    pub fn create_group_certificate_authority(
+        &self,
+        input: CreateGroupCertificateAuthorityRequest,
+    ) -> Result<
+        CreateGroupCertificateAuthorityResponse,
+        RusotoError<CreateGroupCertificateAuthorityError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_group_certificate_authority(input))
+    }
+
+    /// Blocking version of [`GreenGrass::create_group_version`].
+    pub fn create_group_version(
+        &self,
+        input: CreateGroupVersionRequest,
+    ) -> Result<CreateGroupVersionResponse, RusotoError<CreateGroupVersionError>> {
+        self.runtime
+            .block_on(self.client.create_group_version(input))
+    }
+
+    /// Blocking version of [`GreenGrass::create_logger_definition`].
+    pub fn create_logger_definition(
+        &self,
+        input: CreateLoggerDefinitionRequest,
+    ) -> Result<CreateLoggerDefinitionResponse, RusotoError<CreateLoggerDefinitionError>> {
+        self.runtime
+            .block_on(self.client.create_logger_definition(input))
+    }
+
+    /// Blocking version of [`GreenGrass::create_logger_definition_version`].
+    pub fn create_logger_definition_version(
+        &self,
+        input: CreateLoggerDefinitionVersionRequest,
+    ) -> Result<
+        CreateLoggerDefinitionVersionResponse,
+        RusotoError<CreateLoggerDefinitionVersionError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_logger_definition_version(input))
+    }
+
+    /// Blocking version of [`GreenGrass::create_resource_definition`].
+    pub fn create_resource_definition(
+        &self,
+        input: CreateResourceDefinitionRequest,
+    ) -> Result<CreateResourceDefinitionResponse, RusotoError<CreateResourceDefinitionError>> {
+        self.runtime
+            .block_on(self.client.create_resource_definition(input))
+    }
+
+    /// Blocking version of [`GreenGrass::create_resource_definition_version`].
+    pub fn create_resource_definition_version(
+        &self,
+        input: CreateResourceDefinitionVersionRequest,
+    ) -> Result<
+        CreateResourceDefinitionVersionResponse,
+        RusotoError<CreateResourceDefinitionVersionError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_resource_definition_version(input))
+    }
+
+    /// Blocking version of [`GreenGrass::create_software_update_job`].
+    pub fn create_software_update_job(
+        &self,
+        input: CreateSoftwareUpdateJobRequest,
+    ) -> Result<CreateSoftwareUpdateJobResponse, RusotoError<CreateSoftwareUpdateJobError>> {
+        self.runtime
+            .block_on(self.client.create_software_update_job(input))
+    }
+
+    /// Blocking version of [`GreenGrass::create_subscription_definition`].
+    pub fn create_subscription_definition(
+        &self,
+        input: CreateSubscriptionDefinitionRequest,
+    ) -> Result<CreateSubscriptionDefinitionResponse, RusotoError<CreateSubscriptionDefinitionError>>
+    {
+        self.runtime
+            .block_on(self.client.create_subscription_definition(input))
+    }
+
+    /// Blocking version of [`GreenGrass::create_subscription_definition_version`].
+    pub fn create_subscription_definition_version(
+        &self,
+        input: CreateSubscriptionDefinitionVersionRequest,
+    ) -> Result<
+        CreateSubscriptionDefinitionVersionResponse,
+        RusotoError<CreateSubscriptionDefinitionVersionError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_subscription_definition_version(input))
+    }
+
+    /// Blocking version of [`GreenGrass::delete_connector_definition`].
+    pub fn delete_connector_definition(
+        &self,
+        input: DeleteConnectorDefinitionRequest,
+    ) -> Result<DeleteConnectorDefinitionResponse, RusotoError<DeleteConnectorDefinitionError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_connector_definition(input))
+    }
+
+    /// Blocking version of [`GreenGrass::delete_core_definition`].
+    pub fn delete_core_definition(
+        &self,
+        input: DeleteCoreDefinitionRequest,
+    ) -> Result<DeleteCoreDefinitionResponse, RusotoError<DeleteCoreDefinitionError>> {
+        self.runtime
+            .block_on(self.client.delete_core_definition(input))
+    }
+
+    /// Blocking version of [`GreenGrass::delete_device_definition`].
+    pub fn delete_device_definition(
+        &self,
+        input: DeleteDeviceDefinitionRequest,
+    ) -> Result<DeleteDeviceDefinitionResponse, RusotoError<DeleteDeviceDefinitionError>> {
+        self.runtime
+            .block_on(self.client.delete_device_definition(input))
+    }
+
+    /// Blocking version of [`GreenGrass::delete_function_definition`].
+    pub fn delete_function_definition(
+        &self,
+        input: DeleteFunctionDefinitionRequest,
+    ) -> Result<DeleteFunctionDefinitionResponse, RusotoError<DeleteFunctionDefinitionError>> {
+        self.runtime
+            .block_on(self.client.delete_function_definition(input))
+    }
+
+    /// Blocking version of [`GreenGrass::delete_group`].
+    pub fn delete_group(
+        &self,
+        input: DeleteGroupRequest,
+    ) -> Result<DeleteGroupResponse, RusotoError<DeleteGroupError>> {
+        self.runtime.block_on(self.client.delete_group(input))
+    }
+
+    /// Blocking version of [`GreenGrass::delete_logger_definition`].
+    pub fn delete_logger_definition(
+        &self,
+        input: DeleteLoggerDefinitionRequest,
+    ) -> Result<DeleteLoggerDefinitionResponse, RusotoError<DeleteLoggerDefinitionError>> {
+        self.runtime
+            .block_on(self.client.delete_logger_definition(input))
+    }
+
+    /// Blocking version of [`GreenGrass::delete_resource_definition`].
+    pub fn delete_resource_definition(
+        &self,
+        input: DeleteResourceDefinitionRequest,
+    ) -> Result<DeleteResourceDefinitionResponse, RusotoError<DeleteResourceDefinitionError>> {
+        self.runtime
+            .block_on(self.client.delete_resource_definition(input))
+    }
+
+    /// Blocking version of [`GreenGrass::delete_subscription_definition`].
+    pub fn delete_subscription_definition(
+        &self,
+        input: DeleteSubscriptionDefinitionRequest,
+    ) -> Result<DeleteSubscriptionDefinitionResponse, RusotoError<DeleteSubscriptionDefinitionError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_subscription_definition(input))
+    }
+
+    /// Blocking version of [`GreenGrass::disassociate_role_from_group`].
+    pub fn disassociate_role_from_group(
+        &self,
+        input: DisassociateRoleFromGroupRequest,
+    ) -> Result<DisassociateRoleFromGroupResponse, RusotoError<DisassociateRoleFromGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.disassociate_role_from_group(input))
+    }
+
+    /// Blocking version of [`GreenGrass::disassociate_service_role_from_account`].
+    pub fn disassociate_service_role_from_account(
+        &self,
+    ) -> Result<
+        DisassociateServiceRoleFromAccountResponse,
+        RusotoError<DisassociateServiceRoleFromAccountError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_service_role_from_account())
+    }
+
+    /// Blocking version of [`GreenGrass::get_associated_role`].
+    pub fn get_associated_role(
+        &self,
+        input: GetAssociatedRoleRequest,
+    ) -> Result<GetAssociatedRoleResponse, RusotoError<GetAssociatedRoleError>> {
+        self.runtime
+            .block_on(self.client.get_associated_role(input))
+    }
+
+    /// Blocking version of [`GreenGrass::get_bulk_deployment_status`].
+    pub fn get_bulk_deployment_status(
+        &self,
+        input: GetBulkDeploymentStatusRequest,
+    ) -> Result<GetBulkDeploymentStatusResponse, RusotoError<GetBulkDeploymentStatusError>> {
+        self.runtime
+            .block_on(self.client.get_bulk_deployment_status(input))
+    }
+
+    /// Blocking version of [`GreenGrass::get_connectivity_info`].
+    pub fn get_connectivity_info(
+        &self,
+        input: GetConnectivityInfoRequest,
+    ) -> Result<GetConnectivityInfoResponse, RusotoError<GetConnectivityInfoError>> {
+        self.runtime
+            .block_on(self.client.get_connectivity_info(input))
+    }
+
+    /// Blocking version of [`GreenGrass::get_connector_definition`].
+    pub fn get_connector_definition(
+        &self,
+        input: GetConnectorDefinitionRequest,
+    ) -> Result<GetConnectorDefinitionResponse, RusotoError<GetConnectorDefinitionError>> {
+        self.runtime
+            .block_on(self.client.get_connector_definition(input))
+    }
+
+    /// Blocking version of [`GreenGrass::get_connector_definition_version`].
+    pub fn get_connector_definition_version(
+        &self,
+        input: GetConnectorDefinitionVersionRequest,
+    ) -> Result<
+        GetConnectorDefinitionVersionResponse,
+        RusotoError<GetConnectorDefinitionVersionError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_connector_definition_version(input))
+    }
+
+    /// Blocking version of [`GreenGrass::get_core_definition`].
+    pub fn get_core_definition(
+        &self,
+        input: GetCoreDefinitionRequest,
+    ) -> Result<GetCoreDefinitionResponse, RusotoError<GetCoreDefinitionError>> {
+        self.runtime
+            .block_on(self.client.get_core_definition(input))
+    }
+
+    /// Blocking version of [`GreenGrass::get_core_definition_version`].
+    pub fn get_core_definition_version(
+        &self,
+        input: GetCoreDefinitionVersionRequest,
+    ) -> Result<GetCoreDefinitionVersionResponse, RusotoError<GetCoreDefinitionVersionError>> {
+        self.runtime
+            .block_on(self.client.get_core_definition_version(input))
+    }
+
+    /// Blocking version of [`GreenGrass::get_deployment_status`].
+    pub fn get_deployment_status(
+        &self,
+        input: GetDeploymentStatusRequest,
+    ) -> Result<GetDeploymentStatusResponse, RusotoError<GetDeploymentStatusError>> {
+        self.runtime
+            .block_on(self.client.get_deployment_status(input))
+    }
+
+    /// Blocking version of [`GreenGrass::get_device_definition`].
+    pub fn get_device_definition(
+        &self,
+        input: GetDeviceDefinitionRequest,
+    ) -> Result<GetDeviceDefinitionResponse, RusotoError<GetDeviceDefinitionError>> {
+        self.runtime
+            .block_on(self.client.get_device_definition(input))
+    }
+
+    /// Blocking version of [`GreenGrass::get_device_definition_version`].
+    pub fn get_device_definition_version(
+        &self,
+        input: GetDeviceDefinitionVersionRequest,
+    ) -> Result<GetDeviceDefinitionVersionResponse, RusotoError<GetDeviceDefinitionVersionError>>
+    {
+        self.runtime
+            .block_on(self.client.get_device_definition_version(input))
+    }
+
+    /// Blocking version of [`GreenGrass::get_function_definition`].
+    pub fn get_function_definition(
+        &self,
+        input: GetFunctionDefinitionRequest,
+    ) -> Result<GetFunctionDefinitionResponse, RusotoError<GetFunctionDefinitionError>> {
+        self.runtime
+            .block_on(self.client.get_function_definition(input))
+    }
+
+    /// Blocking version of [`GreenGrass::get_function_definition_version`].
+    pub fn get_function_definition_version(
+        &self,
+        input: GetFunctionDefinitionVersionRequest,
+    ) -> Result<GetFunctionDefinitionVersionResponse, RusotoError<GetFunctionDefinitionVersionError>>
+    {
+        self.runtime
+            .block_on(self.client.get_function_definition_version(input))
+    }
+
+    /// Blocking version of [`GreenGrass::get_group`].
+    pub fn get_group(
+        &self,
+        input: GetGroupRequest,
+    ) -> Result<GetGroupResponse, RusotoError<GetGroupError>> {
+        self.runtime.block_on(self.client.get_group(input))
+    }
+
+    /// Blocking version of [`GreenGrass::get_group_certificate_authority`].
+    pub fn get_group_certificate_authority(
+        &self,
+        input: GetGroupCertificateAuthorityRequest,
+    ) -> Result<GetGroupCertificateAuthorityResponse, RusotoError<GetGroupCertificateAuthorityError>>
+    {
+        self.runtime
+            .block_on(self.client.get_group_certificate_authority(input))
+    }
+
+    /// Blocking version of [`GreenGrass::get_group_certificate_configuration`].
+    pub fn get_group_certificate_configuration(
+        &self,
+        input: GetGroupCertificateConfigurationRequest,
+    ) -> Result<
+        GetGroupCertificateConfigurationResponse,
+        RusotoError<GetGroupCertificateConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_group_certificate_configuration(input))
+    }
+
+    /// Blocking version of [`GreenGrass::get_group_version`].
+    pub fn get_group_version(
+        &self,
+        input: GetGroupVersionRequest,
+    ) -> Result<GetGroupVersionResponse, RusotoError<GetGroupVersionError>> {
+        self.runtime.block_on(self.client.get_group_version(input))
+    }
+
+    /// Blocking version of [`GreenGrass::get_logger_definition`].
+    pub fn get_logger_definition(
+        &self,
+        input: GetLoggerDefinitionRequest,
+    ) -> Result<GetLoggerDefinitionResponse, RusotoError<GetLoggerDefinitionError>> {
+        self.runtime
+            .block_on(self.client.get_logger_definition(input))
+    }
+
+    /// Blocking version of [`GreenGrass::get_logger_definition_version`].
+    pub fn get_logger_definition_version(
+        &self,
+        input: GetLoggerDefinitionVersionRequest,
+    ) -> Result<GetLoggerDefinitionVersionResponse, RusotoError<GetLoggerDefinitionVersionError>>
+    {
+        self.runtime
+            .block_on(self.client.get_logger_definition_version(input))
+    }
+
+    /// Blocking version of [`GreenGrass::get_resource_definition`].
+    pub fn get_resource_definition(
+        &self,
+        input: GetResourceDefinitionRequest,
+    ) -> Result<GetResourceDefinitionResponse, RusotoError<GetResourceDefinitionError>> {
+        self.runtime
+            .block_on(self.client.get_resource_definition(input))
+    }
+
+    /// Blocking version of [`GreenGrass::get_resource_definition_version`].
+    pub fn get_resource_definition_version(
+        &self,
+        input: GetResourceDefinitionVersionRequest,
+    ) -> Result<GetResourceDefinitionVersionResponse, RusotoError<GetResourceDefinitionVersionError>>
+    {
+        self.runtime
+            .block_on(self.client.get_resource_definition_version(input))
+    }
+
+    /// Blocking version of [`GreenGrass::get_service_role_for_account`].
+    pub fn get_service_role_for_account(
+        &self,
+    ) -> Result<GetServiceRoleForAccountResponse, RusotoError<GetServiceRoleForAccountError>> {
+        self.runtime
+            .block_on(self.client.get_service_role_for_account())
+    }
+
+    /// Blocking version of [`GreenGrass::get_subscription_definition`].
+    pub fn get_subscription_definition(
+        &self,
+        input: GetSubscriptionDefinitionRequest,
+    ) -> Result<GetSubscriptionDefinitionResponse, RusotoError<GetSubscriptionDefinitionError>>
+    {
+        self.runtime
+            .block_on(self.client.get_subscription_definition(input))
+    }
+
+    /// Blocking version of [`GreenGrass::get_subscription_definition_version`].
+    pub fn get_subscription_definition_version(
+        &self,
+        input: GetSubscriptionDefinitionVersionRequest,
+    ) -> Result<
+        GetSubscriptionDefinitionVersionResponse,
+        RusotoError<GetSubscriptionDefinitionVersionError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_subscription_definition_version(input))
+    }
+
+    /// Blocking version of [`GreenGrass::get_thing_runtime_configuration`].
+    pub fn get_thing_runtime_configuration(
+        &self,
+        input: GetThingRuntimeConfigurationRequest,
+    ) -> Result<GetThingRuntimeConfigurationResponse, RusotoError<GetThingRuntimeConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.get_thing_runtime_configuration(input))
+    }
+
+    /// Blocking version of [`GreenGrass::list_bulk_deployment_detailed_reports`].
+    pub fn list_bulk_deployment_detailed_reports(
+        &self,
+        input: ListBulkDeploymentDetailedReportsRequest,
+    ) -> Result<
+        ListBulkDeploymentDetailedReportsResponse,
+        RusotoError<ListBulkDeploymentDetailedReportsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_bulk_deployment_detailed_reports(input))
+    }
+
+    /// Blocking version of [`GreenGrass::list_bulk_deployments`].
+    pub fn list_bulk_deployments(
+        &self,
+        input: ListBulkDeploymentsRequest,
+    ) -> Result<ListBulkDeploymentsResponse, RusotoError<ListBulkDeploymentsError>> {
+        self.runtime
+            .block_on(self.client.list_bulk_deployments(input))
+    }
+
+    /// Blocking version of [`GreenGrass::list_connector_definition_versions`].
+    pub fn list_connector_definition_versions(
+        &self,
+        input: ListConnectorDefinitionVersionsRequest,
+    ) -> Result<
+        ListConnectorDefinitionVersionsResponse,
+        RusotoError<ListConnectorDefinitionVersionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_connector_definition_versions(input))
+    }
+
+    /// Blocking version of [`GreenGrass::list_connector_definitions`].
+    pub fn list_connector_definitions(
+        &self,
+        input: ListConnectorDefinitionsRequest,
+    ) -> Result<ListConnectorDefinitionsResponse, RusotoError<ListConnectorDefinitionsError>> {
+        self.runtime
+            .block_on(self.client.list_connector_definitions(input))
+    }
+
+    /// Blocking version of [`GreenGrass::list_core_definition_versions`].
+    pub fn list_core_definition_versions(
+        &self,
+        input: ListCoreDefinitionVersionsRequest,
+    ) -> Result<ListCoreDefinitionVersionsResponse, RusotoError<ListCoreDefinitionVersionsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_core_definition_versions(input))
+    }
+
+    /// Blocking version of [`GreenGrass::list_core_definitions`].
+    pub fn list_core_definitions(
+        &self,
+        input: ListCoreDefinitionsRequest,
+    ) -> Result<ListCoreDefinitionsResponse, RusotoError<ListCoreDefinitionsError>> {
+        self.runtime
+            .block_on(self.client.list_core_definitions(input))
+    }
+
+    /// Blocking version of [`GreenGrass::list_deployments`].
+    pub fn list_deployments(
+        &self,
+        input: ListDeploymentsRequest,
+    ) -> Result<ListDeploymentsResponse, RusotoError<ListDeploymentsError>> {
+        self.runtime.block_on(self.client.list_deployments(input))
+    }
+
+    /// Blocking version of [`GreenGrass::list_device_definition_versions`].
+    pub fn list_device_definition_versions(
+        &self,
+        input: ListDeviceDefinitionVersionsRequest,
+    ) -> Result<ListDeviceDefinitionVersionsResponse, RusotoError<ListDeviceDefinitionVersionsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_device_definition_versions(input))
+    }
+
+    /// Blocking version of [`GreenGrass::list_device_definitions`].
+    pub fn list_device_definitions(
+        &self,
+        input: ListDeviceDefinitionsRequest,
+    ) -> Result<ListDeviceDefinitionsResponse, RusotoError<ListDeviceDefinitionsError>> {
+        self.runtime
+            .block_on(self.client.list_device_definitions(input))
+    }
+
+    /// Blocking version of [`GreenGrass::list_function_definition_versions`].
+    pub fn list_function_definition_versions(
+        &self,
+        input: ListFunctionDefinitionVersionsRequest,
+    ) -> Result<
+        ListFunctionDefinitionVersionsResponse,
+        RusotoError<ListFunctionDefinitionVersionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_function_definition_versions(input))
+    }
+
+    /// Blocking version of [`GreenGrass::list_function_definitions`].
+    pub fn list_function_definitions(
+        &self,
+        input: ListFunctionDefinitionsRequest,
+    ) -> Result<ListFunctionDefinitionsResponse, RusotoError<ListFunctionDefinitionsError>> {
+        self.runtime
+            .block_on(self.client.list_function_definitions(input))
+    }
+
+    /// Blocking version of [`GreenGrass::list_group_certificate_authorities`].
+    pub fn list_group_certificate_authorities(
+        &self,
+        input: ListGroupCertificateAuthoritiesRequest,
+    ) -> Result<
+        ListGroupCertificateAuthoritiesResponse,
+        RusotoError<ListGroupCertificateAuthoritiesError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_group_certificate_authorities(input))
+    }
+
+    /// Blocking version of [`GreenGrass::list_group_versions`].
+    pub fn list_group_versions(
+        &self,
+        input: ListGroupVersionsRequest,
+    ) -> Result<ListGroupVersionsResponse, RusotoError<ListGroupVersionsError>> {
+        self.runtime
+            .block_on(self.client.list_group_versions(input))
+    }
+
+    /// Blocking version of [`GreenGrass::list_groups`].
+    pub fn list_groups(
+        &self,
+        input: ListGroupsRequest,
+    ) -> Result<ListGroupsResponse, RusotoError<ListGroupsError>> {
+        self.runtime.block_on(self.client.list_groups(input))
+    }
+
+    /// Blocking version of [`GreenGrass::list_logger_definition_versions`].
+    pub fn list_logger_definition_versions(
+        &self,
+        input: ListLoggerDefinitionVersionsRequest,
+    ) -> Result<ListLoggerDefinitionVersionsResponse, RusotoError<ListLoggerDefinitionVersionsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_logger_definition_versions(input))
+    }
+
+    /// Blocking version of [`GreenGrass::list_logger_definitions`].
+    pub fn list_logger_definitions(
+        &self,
+        input: ListLoggerDefinitionsRequest,
+    ) -> Result<ListLoggerDefinitionsResponse, RusotoError<ListLoggerDefinitionsError>> {
+        self.runtime
+            .block_on(self.client.list_logger_definitions(input))
+    }
+
+    /// Blocking version of [`GreenGrass::list_resource_definition_versions`].
+    pub fn list_resource_definition_versions(
+        &self,
+        input: ListResourceDefinitionVersionsRequest,
+    ) -> Result<
+        ListResourceDefinitionVersionsResponse,
+        RusotoError<ListResourceDefinitionVersionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_resource_definition_versions(input))
+    }
+
+    /// Blocking version of [`GreenGrass::list_resource_definitions`].
+    pub fn list_resource_definitions(
+        &self,
+        input: ListResourceDefinitionsRequest,
+    ) -> Result<ListResourceDefinitionsResponse, RusotoError<ListResourceDefinitionsError>> {
+        self.runtime
+            .block_on(self.client.list_resource_definitions(input))
+    }
+
+    /// Blocking version of [`GreenGrass::list_subscription_definition_versions`].
+    pub fn list_subscription_definition_versions(
+        &self,
+        input: ListSubscriptionDefinitionVersionsRequest,
+    ) -> Result<
+        ListSubscriptionDefinitionVersionsResponse,
+        RusotoError<ListSubscriptionDefinitionVersionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_subscription_definition_versions(input))
+    }
+
+    /// Blocking version of [`GreenGrass::list_subscription_definitions`].
+    pub fn list_subscription_definitions(
+        &self,
+        input: ListSubscriptionDefinitionsRequest,
+    ) -> Result<ListSubscriptionDefinitionsResponse, RusotoError<ListSubscriptionDefinitionsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_subscription_definitions(input))
+    }
+
+    /// Blocking version of [`GreenGrass::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`GreenGrass::reset_deployments`].
+    pub fn reset_deployments(
+        &self,
+        input: ResetDeploymentsRequest,
+    ) -> Result<ResetDeploymentsResponse, RusotoError<ResetDeploymentsError>> {
+        self.runtime.block_on(self.client.reset_deployments(input))
+    }
+
+    /// Blocking version of [`GreenGrass::start_bulk_deployment`].
+    pub fn start_bulk_deployment(
+        &self,
+        input: StartBulkDeploymentRequest,
+    ) -> Result<StartBulkDeploymentResponse, RusotoError<StartBulkDeploymentError>> {
+        self.runtime
+            .block_on(self.client.start_bulk_deployment(input))
+    }
+
+    /// Blocking version of [`GreenGrass::stop_bulk_deployment`].
+    pub fn stop_bulk_deployment(
+        &self,
+        input: StopBulkDeploymentRequest,
+    ) -> Result<StopBulkDeploymentResponse, RusotoError<StopBulkDeploymentError>> {
+        self.runtime
+            .block_on(self.client.stop_bulk_deployment(input))
+    }
+
+    /// Blocking version of [`GreenGrass::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`GreenGrass::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`GreenGrass::update_connectivity_info`].
+    pub fn update_connectivity_info(
+        &self,
+        input: UpdateConnectivityInfoRequest,
+    ) -> Result<UpdateConnectivityInfoResponse, RusotoError<UpdateConnectivityInfoError>> {
+        self.runtime
+            .block_on(self.client.update_connectivity_info(input))
+    }
+
+    /// Blocking version of [`GreenGrass::update_connector_definition`].
+    pub fn update_connector_definition(
+        &self,
+        input: UpdateConnectorDefinitionRequest,
+    ) -> Result<UpdateConnectorDefinitionResponse, RusotoError<UpdateConnectorDefinitionError>>
+    {
+        self.runtime
+            .block_on(self.client.update_connector_definition(input))
+    }
+
+    /// Blocking version of [`GreenGrass::update_core_definition`].
+    pub fn update_core_definition(
+        &self,
+        input: UpdateCoreDefinitionRequest,
+    ) -> Result<UpdateCoreDefinitionResponse, RusotoError<UpdateCoreDefinitionError>> {
+        self.runtime
+            .block_on(self.client.update_core_definition(input))
+    }
+
+    /// Blocking version of [`GreenGrass::update_device_definition`].
+    pub fn update_device_definition(
+        &self,
+        input: UpdateDeviceDefinitionRequest,
+    ) -> Result<UpdateDeviceDefinitionResponse, RusotoError<UpdateDeviceDefinitionError>> {
+        self.runtime
+            .block_on(self.client.update_device_definition(input))
+    }
+
+    /// Blocking version of [`GreenGrass::update_function_definition`].
+    pub fn update_function_definition(
+        &self,
+        input: UpdateFunctionDefinitionRequest,
+    ) -> Result<UpdateFunctionDefinitionResponse, RusotoError<UpdateFunctionDefinitionError>> {
+        self.runtime
+            .block_on(self.client.update_function_definition(input))
+    }
+
+    /// Blocking version of [`GreenGrass::update_group`].
+    pub fn update_group(
+        &self,
+        input: UpdateGroupRequest,
+    ) -> Result<UpdateGroupResponse, RusotoError<UpdateGroupError>> {
+        self.runtime.block_on(self.client.update_group(input))
+    }
+
+    /// Blocking version of [`GreenGrass::update_group_certificate_configuration`].
+    pub fn update_group_certificate_configuration(
+        &self,
+        input: UpdateGroupCertificateConfigurationRequest,
+    ) -> Result<
+        UpdateGroupCertificateConfigurationResponse,
+        RusotoError<UpdateGroupCertificateConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_group_certificate_configuration(input))
+    }
+
+    /// Blocking version of [`GreenGrass::update_logger_definition`].
+    pub fn update_logger_definition(
+        &self,
+        input: UpdateLoggerDefinitionRequest,
+    ) -> Result<UpdateLoggerDefinitionResponse, RusotoError<UpdateLoggerDefinitionError>> {
+        self.runtime
+            .block_on(self.client.update_logger_definition(input))
+    }
+
+    /// Blocking version of [`GreenGrass::update_resource_definition`].
+    pub fn update_resource_definition(
+        &self,
+        input: UpdateResourceDefinitionRequest,
+    ) -> Result<UpdateResourceDefinitionResponse, RusotoError<UpdateResourceDefinitionError>> {
+        self.runtime
+            .block_on(self.client.update_resource_definition(input))
+    }
+
+    /// Blocking version of [`GreenGrass::update_subscription_definition`].
+    pub fn update_subscription_definition(
+        &self,
+        input: UpdateSubscriptionDefinitionRequest,
+    ) -> Result<UpdateSubscriptionDefinitionResponse, RusotoError<UpdateSubscriptionDefinitionError>>
+    {
+        self.runtime
+            .block_on(self.client.update_subscription_definition(input))
+    }
+
+    /// Blocking version of [`GreenGrass::update_thing_runtime_configuration`].
+    pub fn update_thing_runtime_configuration(
+        &self,
+        input: UpdateThingRuntimeConfigurationRequest,
+    ) -> Result<
+        UpdateThingRuntimeConfigurationResponse,
+        RusotoError<UpdateThingRuntimeConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_thing_runtime_configuration(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1256,15 +1257,30 @@ impl CancelContactError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(CancelContactError::Dependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelContactError::Dependency,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CancelContactError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelContactError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelContactError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelContactError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1300,18 +1316,38 @@ impl CreateConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(CreateConfigError::Dependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConfigError::Dependency,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateConfigError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConfigError::InvalidParameter,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateConfigError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConfigError::ResourceLimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateConfigError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConfigError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1348,21 +1384,30 @@ impl CreateDataflowEndpointGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(CreateDataflowEndpointGroupError::Dependency(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDataflowEndpointGroupError::Dependency,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        CreateDataflowEndpointGroupError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataflowEndpointGroupError::InvalidParameter,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        CreateDataflowEndpointGroupError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataflowEndpointGroupError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1396,19 +1441,30 @@ impl CreateMissionProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(CreateMissionProfileError::Dependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMissionProfileError::Dependency,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateMissionProfileError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateMissionProfileError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateMissionProfileError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateMissionProfileError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1442,15 +1498,30 @@ impl DeleteConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(DeleteConfigError::Dependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteConfigError::Dependency,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteConfigError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteConfigError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteConfigError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteConfigError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1486,21 +1557,30 @@ impl DeleteDataflowEndpointGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(DeleteDataflowEndpointGroupError::Dependency(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDataflowEndpointGroupError::Dependency,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        DeleteDataflowEndpointGroupError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDataflowEndpointGroupError::InvalidParameter,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteDataflowEndpointGroupError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDataflowEndpointGroupError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1534,19 +1614,30 @@ impl DeleteMissionProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(DeleteMissionProfileError::Dependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMissionProfileError::Dependency,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteMissionProfileError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteMissionProfileError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteMissionProfileError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteMissionProfileError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1580,15 +1671,30 @@ impl DescribeContactError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(DescribeContactError::Dependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeContactError::Dependency,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeContactError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeContactError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeContactError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeContactError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1622,15 +1728,30 @@ impl GetConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(GetConfigError::Dependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConfigError::Dependency,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetConfigError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConfigError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetConfigError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConfigError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1664,19 +1785,30 @@ impl GetDataflowEndpointGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(GetDataflowEndpointGroupError::Dependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDataflowEndpointGroupError::Dependency,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetDataflowEndpointGroupError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDataflowEndpointGroupError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDataflowEndpointGroupError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDataflowEndpointGroupError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1710,15 +1842,30 @@ impl GetMinuteUsageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(GetMinuteUsageError::Dependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMinuteUsageError::Dependency,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetMinuteUsageError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMinuteUsageError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetMinuteUsageError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMinuteUsageError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1752,15 +1899,30 @@ impl GetMissionProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(GetMissionProfileError::Dependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMissionProfileError::Dependency,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetMissionProfileError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMissionProfileError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetMissionProfileError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMissionProfileError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1794,15 +1956,30 @@ impl GetSatelliteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(GetSatelliteError::Dependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSatelliteError::Dependency,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetSatelliteError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSatelliteError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetSatelliteError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSatelliteError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1836,15 +2013,30 @@ impl ListConfigsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(ListConfigsError::Dependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListConfigsError::Dependency,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListConfigsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListConfigsError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListConfigsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListConfigsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1878,15 +2070,30 @@ impl ListContactsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(ListContactsError::Dependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListContactsError::Dependency,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListContactsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListContactsError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListContactsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListContactsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1922,21 +2129,30 @@ impl ListDataflowEndpointGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(ListDataflowEndpointGroupsError::Dependency(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDataflowEndpointGroupsError::Dependency,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListDataflowEndpointGroupsError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDataflowEndpointGroupsError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDataflowEndpointGroupsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDataflowEndpointGroupsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1970,15 +2186,30 @@ impl ListGroundStationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(ListGroundStationsError::Dependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGroundStationsError::Dependency,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListGroundStationsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGroundStationsError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListGroundStationsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGroundStationsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2012,19 +2243,30 @@ impl ListMissionProfilesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(ListMissionProfilesError::Dependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMissionProfilesError::Dependency,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListMissionProfilesError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListMissionProfilesError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListMissionProfilesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListMissionProfilesError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2058,15 +2300,30 @@ impl ListSatellitesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(ListSatellitesError::Dependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSatellitesError::Dependency,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListSatellitesError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSatellitesError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListSatellitesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSatellitesError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2100,19 +2357,30 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Dependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::Dependency,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2146,15 +2414,30 @@ impl ReserveContactError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(ReserveContactError::Dependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReserveContactError::Dependency,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ReserveContactError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReserveContactError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ReserveContactError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReserveContactError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2188,15 +2471,30 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(TagResourceError::Dependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::Dependency,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2230,15 +2528,30 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(UntagResourceError::Dependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::Dependency,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2272,15 +2585,30 @@ impl UpdateConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(UpdateConfigError::Dependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateConfigError::Dependency,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateConfigError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateConfigError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateConfigError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateConfigError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2314,19 +2642,30 @@ impl UpdateMissionProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DependencyException" => {
-                    return RusotoError::Service(UpdateMissionProfileError::Dependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMissionProfileError::Dependency,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateMissionProfileError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateMissionProfileError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateMissionProfileError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateMissionProfileError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2507,11 +2846,12 @@ pub struct GroundStationClient {
 impl GroundStationClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> GroundStationClient {
         GroundStationClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "GroundStation"),
         }
     }
 
@@ -2526,12 +2866,32 @@ impl GroundStationClient {
     {
         GroundStationClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "GroundStation"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> GroundStationClient {
-        GroundStationClient { client, region }
+        GroundStationClient {
+            client,
+            region: region_for_service(region, "GroundStation"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> GroundStationClient {
+        GroundStationClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("GroundStation"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> GroundStationClient {
+        GroundStationClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -2546,6 +2906,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = format!("/contact/{contact_id}", contact_id = input.contact_id);
 
         let mut request = SignedRequest::new("DELETE", "groundstation", &self.region, &request_uri);
+        request.set_operation("CancelContact");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2574,6 +2935,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/config";
 
         let mut request = SignedRequest::new("POST", "groundstation", &self.region, &request_uri);
+        request.set_operation("CreateConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2606,6 +2968,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/dataflowEndpointGroup";
 
         let mut request = SignedRequest::new("POST", "groundstation", &self.region, &request_uri);
+        request.set_operation("CreateDataflowEndpointGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2637,6 +3000,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/missionprofile";
 
         let mut request = SignedRequest::new("POST", "groundstation", &self.region, &request_uri);
+        request.set_operation("CreateMissionProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2672,6 +3036,7 @@ impl GroundStation for GroundStationClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "groundstation", &self.region, &request_uri);
+        request.set_operation("DeleteConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2704,6 +3069,7 @@ impl GroundStation for GroundStationClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "groundstation", &self.region, &request_uri);
+        request.set_operation("DeleteDataflowEndpointGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2735,6 +3101,7 @@ impl GroundStation for GroundStationClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "groundstation", &self.region, &request_uri);
+        request.set_operation("DeleteMissionProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2763,6 +3130,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = format!("/contact/{contact_id}", contact_id = input.contact_id);
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("DescribeContact");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2795,6 +3163,7 @@ impl GroundStation for GroundStationClient {
         );
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("GetConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2826,6 +3195,7 @@ impl GroundStation for GroundStationClient {
         );
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("GetDataflowEndpointGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2854,6 +3224,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/minute-usage";
 
         let mut request = SignedRequest::new("POST", "groundstation", &self.region, &request_uri);
+        request.set_operation("GetMinuteUsage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2888,6 +3259,7 @@ impl GroundStation for GroundStationClient {
         );
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("GetMissionProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2919,6 +3291,7 @@ impl GroundStation for GroundStationClient {
         );
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("GetSatellite");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2947,6 +3320,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/config";
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("ListConfigs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2984,6 +3358,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/contacts";
 
         let mut request = SignedRequest::new("POST", "groundstation", &self.region, &request_uri);
+        request.set_operation("ListContacts");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3016,6 +3391,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/dataflowEndpointGroup";
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("ListDataflowEndpointGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3053,6 +3429,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/groundstation";
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("ListGroundStations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3093,6 +3470,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/missionprofile";
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("ListMissionProfiles");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3130,6 +3508,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/satellite";
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("ListSatellites");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3167,6 +3546,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "groundstation", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3195,6 +3575,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = "/contact";
 
         let mut request = SignedRequest::new("POST", "groundstation", &self.region, &request_uri);
+        request.set_operation("ReserveContact");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3226,6 +3607,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "groundstation", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3257,6 +3639,7 @@ impl GroundStation for GroundStationClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "groundstation", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3295,6 +3678,7 @@ impl GroundStation for GroundStationClient {
         );
 
         let mut request = SignedRequest::new("PUT", "groundstation", &self.region, &request_uri);
+        request.set_operation("UpdateConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3329,6 +3713,7 @@ impl GroundStation for GroundStationClient {
         );
 
         let mut request = SignedRequest::new("PUT", "groundstation", &self.region, &request_uri);
+        request.set_operation("UpdateMissionProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3351,3 +3736,297 @@ impl GroundStation for GroundStationClient {
         }
     }
 }
+
+/// A blocking client for the AWS Ground Station API, running the calls of a `GroundStationClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `GroundStationClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct GroundStationBlockingClient {
+    client: GroundStationClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl GroundStationBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> GroundStationBlockingClient {
+        GroundStationBlockingClient::from_client(GroundStationClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> GroundStationBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        GroundStationBlockingClient::from_client(GroundStationClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> GroundStationBlockingClient {
+        GroundStationBlockingClient::from_client(GroundStationClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> GroundStationBlockingClient {
+        GroundStationBlockingClient::from_client(GroundStationClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: GroundStationClient) -> GroundStationBlockingClient {
+        GroundStationBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> GroundStationBlockingClient {
+        GroundStationBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> GroundStationBlockingClient {
+        GroundStationBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`GroundStation::cancel_contact`].
+    pub fn cancel_contact(
+        &self,
+        input: CancelContactRequest,
+    ) -> Result<ContactIdResponse, RusotoError<CancelContactError>> {
+        self.runtime.block_on(self.client.cancel_contact(input))
+    }
+
+    /// Blocking version of [`GroundStation::create_config`].
+    pub fn create_config(
+        &self,
+        input: CreateConfigRequest,
+    ) -> Result<ConfigIdResponse, RusotoError<CreateConfigError>> {
+        self.runtime.block_on(self.client.create_config(input))
+    }
+
+    /// Blocking version of [`GroundStation::create_dataflow_endpoint_group`].
+    pub fn create_dataflow_endpoint_group(
+        &self,
+        input: CreateDataflowEndpointGroupRequest,
+    ) -> Result<DataflowEndpointGroupIdResponse, RusotoError<CreateDataflowEndpointGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.create_dataflow_endpoint_group(input))
+    }
+
+    /// Blocking version of [`GroundStation::create_mission_profile`].
+    pub fn create_mission_profile(
+        &self,
+        input: CreateMissionProfileRequest,
+    ) -> Result<MissionProfileIdResponse, RusotoError<CreateMissionProfileError>> {
+        self.runtime
+            .block_on(self.client.create_mission_profile(input))
+    }
+
+    /// Blocking version of [`GroundStation::delete_config`].
+    pub fn delete_config(
+        &self,
+        input: DeleteConfigRequest,
+    ) -> Result<ConfigIdResponse, RusotoError<DeleteConfigError>> {
+        self.runtime.block_on(self.client.delete_config(input))
+    }
+
+    /// Blocking version of [`GroundStation::delete_dataflow_endpoint_group`].
+    pub fn delete_dataflow_endpoint_group(
+        &self,
+        input: DeleteDataflowEndpointGroupRequest,
+    ) -> Result<DataflowEndpointGroupIdResponse, RusotoError<DeleteDataflowEndpointGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_dataflow_endpoint_group(input))
+    }
+
+    /// Blocking version of [`GroundStation::delete_mission_profile`].
+    pub fn delete_mission_profile(
+        &self,
+        input: DeleteMissionProfileRequest,
+    ) -> Result<MissionProfileIdResponse, RusotoError<DeleteMissionProfileError>> {
+        self.runtime
+            .block_on(self.client.delete_mission_profile(input))
+    }
+
+    /// Blocking version of [`GroundStation::describe_contact`].
+    pub fn describe_contact(
+        &self,
+        input: DescribeContactRequest,
+    ) -> Result<DescribeContactResponse, RusotoError<DescribeContactError>> {
+        self.runtime.block_on(self.client.describe_contact(input))
+    }
+
+    /// Blocking version of [`GroundStation::get_config`].
+    pub fn get_config(
+        &self,
+        input: GetConfigRequest,
+    ) -> Result<GetConfigResponse, RusotoError<GetConfigError>> {
+        self.runtime.block_on(self.client.get_config(input))
+    }
+
+    /// Blocking version of [`GroundStation::get_dataflow_endpoint_group`].
+    pub fn get_dataflow_endpoint_group(
+        &self,
+        input: GetDataflowEndpointGroupRequest,
+    ) -> Result<GetDataflowEndpointGroupResponse, RusotoError<GetDataflowEndpointGroupError>> {
+        self.runtime
+            .block_on(self.client.get_dataflow_endpoint_group(input))
+    }
+
+    /// Blocking version of [`GroundStation::get_minute_usage`].
+    pub fn get_minute_usage(
+        &self,
+        input: GetMinuteUsageRequest,
+    ) -> Result<GetMinuteUsageResponse, RusotoError<GetMinuteUsageError>> {
+        self.runtime.block_on(self.client.get_minute_usage(input))
+    }
+
+    /// Blocking version of [`GroundStation::get_mission_profile`].
+    pub fn get_mission_profile(
+        &self,
+        input: GetMissionProfileRequest,
+    ) -> Result<GetMissionProfileResponse, RusotoError<GetMissionProfileError>> {
+        self.runtime
+            .block_on(self.client.get_mission_profile(input))
+    }
+
+    /// Blocking version of [`GroundStation::get_satellite`].
+    pub fn get_satellite(
+        &self,
+        input: GetSatelliteRequest,
+    ) -> Result<GetSatelliteResponse, RusotoError<GetSatelliteError>> {
+        self.runtime.block_on(self.client.get_satellite(input))
+    }
+
+    /// Blocking version of [`GroundStation::list_configs`].
+    pub fn list_configs(
+        &self,
+        input: ListConfigsRequest,
+    ) -> Result<ListConfigsResponse, RusotoError<ListConfigsError>> {
+        self.runtime.block_on(self.client.list_configs(input))
+    }
+
+    /// Blocking version of [`GroundStation::list_contacts`].
+    pub fn list_contacts(
+        &self,
+        input: ListContactsRequest,
+    ) -> Result<ListContactsResponse, RusotoError<ListContactsError>> {
+        self.runtime.block_on(self.client.list_contacts(input))
+    }
+
+    /// Blocking version of [`GroundStation::list_dataflow_endpoint_groups`].
+    pub fn list_dataflow_endpoint_groups(
+        &self,
+        input: ListDataflowEndpointGroupsRequest,
+    ) -> Result<ListDataflowEndpointGroupsResponse, RusotoError<ListDataflowEndpointGroupsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_dataflow_endpoint_groups(input))
+    }
+
+    /// Blocking version of [`GroundStation::list_ground_stations`].
+    pub fn list_ground_stations(
+        &self,
+        input: ListGroundStationsRequest,
+    ) -> Result<ListGroundStationsResponse, RusotoError<ListGroundStationsError>> {
+        self.runtime
+            .block_on(self.client.list_ground_stations(input))
+    }
+
+    /// Blocking version of [`GroundStation::list_mission_profiles`].
+    pub fn list_mission_profiles(
+        &self,
+        input: ListMissionProfilesRequest,
+    ) -> Result<ListMissionProfilesResponse, RusotoError<ListMissionProfilesError>> {
+        self.runtime
+            .block_on(self.client.list_mission_profiles(input))
+    }
+
+    /// Blocking version of [`GroundStation::list_satellites`].
+    pub fn list_satellites(
+        &self,
+        input: ListSatellitesRequest,
+    ) -> Result<ListSatellitesResponse, RusotoError<ListSatellitesError>> {
+        self.runtime.block_on(self.client.list_satellites(input))
+    }
+
+    /// Blocking version of [`GroundStation::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`GroundStation::reserve_contact`].
+    pub fn reserve_contact(
+        &self,
+        input: ReserveContactRequest,
+    ) -> Result<ContactIdResponse, RusotoError<ReserveContactError>> {
+        self.runtime.block_on(self.client.reserve_contact(input))
+    }
+
+    /// Blocking version of [`GroundStation::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`GroundStation::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`GroundStation::update_config`].
+    pub fn update_config(
+        &self,
+        input: UpdateConfigRequest,
+    ) -> Result<ConfigIdResponse, RusotoError<UpdateConfigError>> {
+        self.runtime.block_on(self.client.update_config(input))
+    }
+
+    /// Blocking version of [`GroundStation::update_mission_profile`].
+    pub fn update_mission_profile(
+        &self,
+        input: UpdateMissionProfileRequest,
+    ) -> Result<MissionProfileIdResponse, RusotoError<UpdateMissionProfileError>> {
+        self.runtime
+            .block_on(self.client.update_mission_profile(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -2585,14 +2586,22 @@ impl AcceptInvitationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(AcceptInvitationError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptInvitationError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(AcceptInvitationError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AcceptInvitationError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2623,12 +2632,22 @@ impl ArchiveFindingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ArchiveFindingsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ArchiveFindingsError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ArchiveFindingsError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ArchiveFindingsError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2659,12 +2678,22 @@ impl CreateDetectorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDetectorError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDetectorError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateDetectorError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDetectorError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2695,12 +2724,22 @@ impl CreateFilterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateFilterError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFilterError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateFilterError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFilterError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2731,12 +2770,22 @@ impl CreateIPSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateIPSetError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIPSetError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateIPSetError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIPSetError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2767,12 +2816,22 @@ impl CreateMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateMembersError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMembersError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateMembersError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMembersError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2805,16 +2864,22 @@ impl CreatePublishingDestinationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreatePublishingDestinationError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePublishingDestinationError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        CreatePublishingDestinationError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePublishingDestinationError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2847,14 +2912,22 @@ impl CreateSampleFindingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateSampleFindingsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSampleFindingsError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateSampleFindingsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSampleFindingsError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2885,14 +2958,22 @@ impl CreateThreatIntelSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateThreatIntelSetError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateThreatIntelSetError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateThreatIntelSetError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateThreatIntelSetError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2923,14 +3004,22 @@ impl DeclineInvitationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeclineInvitationsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeclineInvitationsError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeclineInvitationsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeclineInvitationsError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2961,12 +3050,22 @@ impl DeleteDetectorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteDetectorError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDetectorError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteDetectorError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDetectorError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2997,12 +3096,22 @@ impl DeleteFilterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteFilterError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFilterError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteFilterError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFilterError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3033,12 +3142,22 @@ impl DeleteIPSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteIPSetError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIPSetError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteIPSetError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIPSetError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3069,14 +3188,22 @@ impl DeleteInvitationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteInvitationsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInvitationsError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteInvitationsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteInvitationsError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3107,12 +3234,22 @@ impl DeleteMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteMembersError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMembersError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteMembersError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMembersError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3145,16 +3282,22 @@ impl DeletePublishingDestinationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeletePublishingDestinationError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeletePublishingDestinationError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        DeletePublishingDestinationError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePublishingDestinationError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3187,14 +3330,22 @@ impl DeleteThreatIntelSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteThreatIntelSetError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThreatIntelSetError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteThreatIntelSetError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteThreatIntelSetError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3227,16 +3378,22 @@ impl DescribeOrganizationConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        DescribeOrganizationConfigurationError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeOrganizationConfigurationError::BadRequest,
                     )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        DescribeOrganizationConfigurationError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeOrganizationConfigurationError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3271,16 +3428,22 @@ impl DescribePublishingDestinationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribePublishingDestinationError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribePublishingDestinationError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        DescribePublishingDestinationError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePublishingDestinationError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3315,16 +3478,22 @@ impl DisableOrganizationAdminAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DisableOrganizationAdminAccountError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisableOrganizationAdminAccountError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        DisableOrganizationAdminAccountError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableOrganizationAdminAccountError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3359,16 +3528,22 @@ impl DisassociateFromMasterAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DisassociateFromMasterAccountError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateFromMasterAccountError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        DisassociateFromMasterAccountError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateFromMasterAccountError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3401,14 +3576,22 @@ impl DisassociateMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DisassociateMembersError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateMembersError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DisassociateMembersError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateMembersError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3441,16 +3624,22 @@ impl EnableOrganizationAdminAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(EnableOrganizationAdminAccountError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        EnableOrganizationAdminAccountError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        EnableOrganizationAdminAccountError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableOrganizationAdminAccountError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3483,12 +3672,22 @@ impl GetDetectorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDetectorError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDetectorError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetDetectorError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDetectorError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3519,12 +3718,22 @@ impl GetFilterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetFilterError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFilterError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetFilterError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFilterError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3555,12 +3764,22 @@ impl GetFindingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetFindingsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFindingsError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetFindingsError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFindingsError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3591,14 +3810,22 @@ impl GetFindingsStatisticsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetFindingsStatisticsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFindingsStatisticsError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetFindingsStatisticsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetFindingsStatisticsError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3629,12 +3856,22 @@ impl GetIPSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetIPSetError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIPSetError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetIPSetError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIPSetError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3665,14 +3902,22 @@ impl GetInvitationsCountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetInvitationsCountError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInvitationsCountError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetInvitationsCountError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetInvitationsCountError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3703,14 +3948,22 @@ impl GetMasterAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetMasterAccountError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMasterAccountError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetMasterAccountError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMasterAccountError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3741,14 +3994,22 @@ impl GetMemberDetectorsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetMemberDetectorsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMemberDetectorsError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetMemberDetectorsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMemberDetectorsError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3779,12 +4040,22 @@ impl GetMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetMembersError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMembersError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetMembersError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMembersError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3815,14 +4086,22 @@ impl GetThreatIntelSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetThreatIntelSetError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetThreatIntelSetError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetThreatIntelSetError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetThreatIntelSetError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3853,14 +4132,22 @@ impl GetUsageStatisticsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetUsageStatisticsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUsageStatisticsError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetUsageStatisticsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetUsageStatisticsError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3891,12 +4178,22 @@ impl InviteMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(InviteMembersError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InviteMembersError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(InviteMembersError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InviteMembersError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3927,12 +4224,22 @@ impl ListDetectorsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListDetectorsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDetectorsError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListDetectorsError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDetectorsError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3963,12 +4270,22 @@ impl ListFiltersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListFiltersError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFiltersError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListFiltersError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFiltersError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3999,12 +4316,22 @@ impl ListFindingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListFindingsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFindingsError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListFindingsError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFindingsError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4035,12 +4362,22 @@ impl ListIPSetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListIPSetsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIPSetsError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListIPSetsError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIPSetsError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4071,12 +4408,22 @@ impl ListInvitationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListInvitationsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInvitationsError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListInvitationsError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInvitationsError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4107,12 +4454,22 @@ impl ListMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListMembersError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMembersError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListMembersError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMembersError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4145,16 +4502,22 @@ impl ListOrganizationAdminAccountsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListOrganizationAdminAccountsError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListOrganizationAdminAccountsError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        ListOrganizationAdminAccountsError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOrganizationAdminAccountsError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4189,16 +4552,22 @@ impl ListPublishingDestinationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListPublishingDestinationsError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPublishingDestinationsError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        ListPublishingDestinationsError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPublishingDestinationsError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4231,14 +4600,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4269,14 +4646,22 @@ impl ListThreatIntelSetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListThreatIntelSetsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThreatIntelSetsError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListThreatIntelSetsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListThreatIntelSetsError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4307,14 +4692,22 @@ impl StartMonitoringMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StartMonitoringMembersError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartMonitoringMembersError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(StartMonitoringMembersError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartMonitoringMembersError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4345,14 +4738,22 @@ impl StopMonitoringMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StopMonitoringMembersError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopMonitoringMembersError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(StopMonitoringMembersError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopMonitoringMembersError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4383,12 +4784,22 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(TagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4419,14 +4830,22 @@ impl UnarchiveFindingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UnarchiveFindingsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UnarchiveFindingsError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UnarchiveFindingsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UnarchiveFindingsError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4457,12 +4876,22 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4493,12 +4922,22 @@ impl UpdateDetectorError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateDetectorError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDetectorError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateDetectorError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDetectorError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4529,12 +4968,22 @@ impl UpdateFilterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateFilterError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFilterError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateFilterError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFilterError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4565,14 +5014,22 @@ impl UpdateFindingsFeedbackError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateFindingsFeedbackError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFindingsFeedbackError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateFindingsFeedbackError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateFindingsFeedbackError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4603,12 +5060,22 @@ impl UpdateIPSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateIPSetError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIPSetError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateIPSetError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIPSetError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4639,14 +5106,22 @@ impl UpdateMemberDetectorsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateMemberDetectorsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMemberDetectorsError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateMemberDetectorsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateMemberDetectorsError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4679,16 +5154,22 @@ impl UpdateOrganizationConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateOrganizationConfigurationError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateOrganizationConfigurationError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        UpdateOrganizationConfigurationError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateOrganizationConfigurationError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4723,16 +5204,22 @@ impl UpdatePublishingDestinationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdatePublishingDestinationError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdatePublishingDestinationError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        UpdatePublishingDestinationError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePublishingDestinationError::InternalServerError,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4765,14 +5252,22 @@ impl UpdateThreatIntelSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateThreatIntelSetError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThreatIntelSetError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateThreatIntelSetError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateThreatIntelSetError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5170,11 +5665,12 @@ pub struct GuardDutyClient {
 impl GuardDutyClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> GuardDutyClient {
         GuardDutyClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "GuardDuty"),
         }
     }
 
@@ -5189,12 +5685,32 @@ impl GuardDutyClient {
     {
         GuardDutyClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "GuardDuty"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> GuardDutyClient {
-        GuardDutyClient { client, region }
+        GuardDutyClient {
+            client,
+            region: region_for_service(region, "GuardDuty"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> GuardDutyClient {
+        GuardDutyClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("GuardDuty"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> GuardDutyClient {
+        GuardDutyClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -5212,6 +5728,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("AcceptInvitation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5246,6 +5763,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("ArchiveFindings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5277,6 +5795,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = "/detector";
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("CreateDetector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5311,6 +5830,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("CreateFilter");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5345,6 +5865,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("CreateIPSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5379,6 +5900,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("CreateMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5414,6 +5936,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("CreatePublishingDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5448,6 +5971,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("CreateSampleFindings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5482,6 +6006,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("CreateThreatIntelSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5513,6 +6038,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = "/invitation/decline";
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("DeclineInvitations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5544,6 +6070,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = format!("/detector/{detector_id}", detector_id = input.detector_id);
 
         let mut request = SignedRequest::new("DELETE", "guardduty", &self.region, &request_uri);
+        request.set_operation("DeleteDetector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5576,6 +6103,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "guardduty", &self.region, &request_uri);
+        request.set_operation("DeleteFilter");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5608,6 +6136,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "guardduty", &self.region, &request_uri);
+        request.set_operation("DeleteIPSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5636,6 +6165,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = "/invitation/delete";
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("DeleteInvitations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5670,6 +6200,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("DeleteMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5706,6 +6237,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "guardduty", &self.region, &request_uri);
+        request.set_operation("DeletePublishingDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5738,6 +6270,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "guardduty", &self.region, &request_uri);
+        request.set_operation("DeleteThreatIntelSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5772,6 +6305,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("DescribeOrganizationConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5809,6 +6343,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("DescribePublishingDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5840,6 +6375,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = "/admin/disable";
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("DisableOrganizationAdminAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5879,6 +6415,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("DisassociateFromMasterAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -5910,6 +6447,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("DisassociateMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5944,6 +6482,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = "/admin/enable";
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("EnableOrganizationAdminAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5975,6 +6514,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = format!("/detector/{detector_id}", detector_id = input.detector_id);
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetDetector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6007,6 +6547,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetFilter");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6038,6 +6579,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetFindings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6072,6 +6614,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetFindingsStatistics");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6107,6 +6650,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetIPSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6134,6 +6678,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = "/invitation/count";
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetInvitationsCount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6165,6 +6710,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetMasterAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6196,6 +6742,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetMemberDetectors");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6230,6 +6777,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6265,6 +6813,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetThreatIntelSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6296,6 +6845,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("GetUsageStatistics");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6330,6 +6880,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("InviteMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6361,6 +6912,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = "/detector";
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListDetectors");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6401,6 +6953,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListFilters");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6441,6 +6994,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListFindings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6475,6 +7029,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListIPSets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6512,6 +7067,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = "/invitation";
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListInvitations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6552,6 +7108,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6595,6 +7152,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = "/admin";
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListOrganizationAdminAccounts");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6636,6 +7194,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListPublishingDestinations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6673,6 +7232,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6704,6 +7264,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("GET", "guardduty", &self.region, &request_uri);
+        request.set_operation("ListThreatIntelSets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6744,6 +7305,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("StartMonitoringMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6778,6 +7340,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("StopMonitoringMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6809,6 +7372,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6843,6 +7407,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("UnarchiveFindings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6874,6 +7439,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "guardduty", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6908,6 +7474,7 @@ impl GuardDuty for GuardDutyClient {
         let request_uri = format!("/detector/{detector_id}", detector_id = input.detector_id);
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("UpdateDetector");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6943,6 +7510,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("UpdateFilter");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6977,6 +7545,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("UpdateFindingsFeedback");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7012,6 +7581,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("UpdateIPSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7046,6 +7616,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("UpdateMemberDetectors");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7083,6 +7654,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("UpdateOrganizationConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7121,6 +7693,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("UpdatePublishingDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7156,6 +7729,7 @@ impl GuardDuty for GuardDutyClient {
         );
 
         let mut request = SignedRequest::new("POST", "guardduty", &self.region, &request_uri);
+        request.set_operation("UpdateThreatIntelSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7178,3 +7752,593 @@ impl GuardDuty for GuardDutyClient {
         }
     }
 }
+
+/// A blocking client for the Amazon GuardDuty API, running the calls of a `GuardDutyClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `GuardDutyClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct GuardDutyBlockingClient {
+    client: GuardDutyClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl GuardDutyBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> GuardDutyBlockingClient {
+        GuardDutyBlockingClient::from_client(GuardDutyClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> GuardDutyBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        GuardDutyBlockingClient::from_client(GuardDutyClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> GuardDutyBlockingClient {
+        GuardDutyBlockingClient::from_client(GuardDutyClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> GuardDutyBlockingClient {
+        GuardDutyBlockingClient::from_client(GuardDutyClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: GuardDutyClient) -> GuardDutyBlockingClient {
+        GuardDutyBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> GuardDutyBlockingClient {
+        GuardDutyBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> GuardDutyBlockingClient {
+        GuardDutyBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`GuardDuty::accept_invitation`].
+    pub fn accept_invitation(
+        &self,
+        input: AcceptInvitationRequest,
+    ) -> Result<AcceptInvitationResponse, RusotoError<AcceptInvitationError>> {
+        self.runtime.block_on(self.client.accept_invitation(input))
+    }
+
+    /// Blocking version of [`GuardDuty::archive_findings`].
+    pub fn archive_findings(
+        &self,
+        input: ArchiveFindingsRequest,
+    ) -> Result<ArchiveFindingsResponse, RusotoError<ArchiveFindingsError>> {
+        self.runtime.block_on(self.client.archive_findings(input))
+    }
+
+    /// Blocking version of [`GuardDuty::create_detector`].
+    pub fn create_detector(
+        &self,
+        input: CreateDetectorRequest,
+    ) -> Result<CreateDetectorResponse, RusotoError<CreateDetectorError>> {
+        self.runtime.block_on(self.client.create_detector(input))
+    }
+
+    /// Blocking version of [`GuardDuty::create_filter`].
+    pub fn create_filter(
+        &self,
+        input: CreateFilterRequest,
+    ) -> Result<CreateFilterResponse, RusotoError<CreateFilterError>> {
+        self.runtime.block_on(self.client.create_filter(input))
+    }
+
+    /// Blocking version of [`GuardDuty::create_ip_set`].
+    pub fn create_ip_set(
+        &self,
+        input: CreateIPSetRequest,
+    ) -> Result<CreateIPSetResponse, RusotoError<CreateIPSetError>> {
+        self.runtime.block_on(self.client.create_ip_set(input))
+    }
+
+    /// Blocking version of [`GuardDuty::create_members`].
+    pub fn create_members(
+        &self,
+        input: CreateMembersRequest,
+    ) -> Result<CreateMembersResponse, RusotoError<CreateMembersError>> {
+        self.runtime.block_on(self.client.create_members(input))
+    }
+
+    /// Blocking version of [`GuardDuty::create_publishing_destination`].
+    pub fn create_publishing_destination(
+        &self,
+        input: CreatePublishingDestinationRequest,
+    ) -> Result<CreatePublishingDestinationResponse, RusotoError<CreatePublishingDestinationError>>
+    {
+        self.runtime
+            .block_on(self.client.create_publishing_destination(input))
+    }
+
+    /// Blocking version of [`GuardDuty::create_sample_findings`].
+    pub fn create_sample_findings(
+        &self,
+        input: CreateSampleFindingsRequest,
+    ) -> Result<CreateSampleFindingsResponse, RusotoError<CreateSampleFindingsError>> {
+        self.runtime
+            .block_on(self.client.create_sample_findings(input))
+    }
+
+    /// Blocking version of [`GuardDuty::create_threat_intel_set`].
+    pub fn create_threat_intel_set(
+        &self,
+        input: CreateThreatIntelSetRequest,
+    ) -> Result<CreateThreatIntelSetResponse, RusotoError<CreateThreatIntelSetError>> {
+        self.runtime
+            .block_on(self.client.create_threat_intel_set(input))
+    }
+
+    /// Blocking version of [`GuardDuty::decline_invitations`].
+    pub fn decline_invitations(
+        &self,
+        input: DeclineInvitationsRequest,
+    ) -> Result<DeclineInvitationsResponse, RusotoError<DeclineInvitationsError>> {
+        self.runtime
+            .block_on(self.client.decline_invitations(input))
+    }
+
+    /// Blocking version of [`GuardDuty::delete_detector`].
+    pub fn delete_detector(
+        &self,
+        input: DeleteDetectorRequest,
+    ) -> Result<DeleteDetectorResponse, RusotoError<DeleteDetectorError>> {
+        self.runtime.block_on(self.client.delete_detector(input))
+    }
+
+    /// Blocking version of [`GuardDuty::delete_filter`].
+    pub fn delete_filter(
+        &self,
+        input: DeleteFilterRequest,
+    ) -> Result<DeleteFilterResponse, RusotoError<DeleteFilterError>> {
+        self.runtime.block_on(self.client.delete_filter(input))
+    }
+
+    /// Blocking version of [`GuardDuty::delete_ip_set`].
+    pub fn delete_ip_set(
+        &self,
+        input: DeleteIPSetRequest,
+    ) -> Result<DeleteIPSetResponse, RusotoError<DeleteIPSetError>> {
+        self.runtime.block_on(self.client.delete_ip_set(input))
+    }
+
+    /// Blocking version of [`GuardDuty::delete_invitations`].
+    pub fn delete_invitations(
+        &self,
+        input: DeleteInvitationsRequest,
+    ) -> Result<DeleteInvitationsResponse, RusotoError<DeleteInvitationsError>> {
+        self.runtime.block_on(self.client.delete_invitations(input))
+    }
+
+    /// Blocking version of [`GuardDuty::delete_members`].
+    pub fn delete_members(
+        &self,
+        input: DeleteMembersRequest,
+    ) -> Result<DeleteMembersResponse, RusotoError<DeleteMembersError>> {
+        self.runtime.block_on(self.client.delete_members(input))
+    }
+
+    /// Blocking version of [`GuardDuty::delete_publishing_destination`].
+    pub fn delete_publishing_destination(
+        &self,
+        input: DeletePublishingDestinationRequest,
+    ) -> Result<DeletePublishingDestinationResponse, RusotoError<DeletePublishingDestinationError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_publishing_destination(input))
+    }
+
+    /// Blocking version of [`GuardDuty::delete_threat_intel_set`].
+    pub fn delete_threat_intel_set(
+        &self,
+        input: DeleteThreatIntelSetRequest,
+    ) -> Result<DeleteThreatIntelSetResponse, RusotoError<DeleteThreatIntelSetError>> {
+        self.runtime
+            .block_on(self.client.delete_threat_intel_set(input))
+    }
+
+    /// Blocking version of [`GuardDuty::describe_organization_configuration`].
+    pub fn describe_organization_configuration(
+        &self,
+        input: DescribeOrganizationConfigurationRequest,
+    ) -> Result<
+        DescribeOrganizationConfigurationResponse,
+        RusotoError<DescribeOrganizationConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_organization_configuration(input))
+    }
+
+    /// Blocking version of [`GuardDuty::describe_publishing_destination`].
+    pub fn describe_publishing_destination(
+        &self,
+        input: DescribePublishingDestinationRequest,
+    ) -> Result<
+        DescribePublishingDestinationResponse,
+        RusotoError<DescribePublishingDestinationError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_publishing_destination(input))
+    }
+
+    /// Blocking version of [`GuardDuty::disable_organization_admin_account`].
+    pub fn disable_organization_admin_account(
+        &self,
+        input: DisableOrganizationAdminAccountRequest,
+    ) -> Result<
+        DisableOrganizationAdminAccountResponse,
+        RusotoError<DisableOrganizationAdminAccountError>,
+    > {
+        self.runtime
+            .block_on(self.client.disable_organization_admin_account(input))
+    }
+
+    /// Blocking version of [`GuardDuty::disassociate_from_master_account`].
+    pub fn disassociate_from_master_account(
+        &self,
+        input: DisassociateFromMasterAccountRequest,
+    ) -> Result<
+        DisassociateFromMasterAccountResponse,
+        RusotoError<DisassociateFromMasterAccountError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_from_master_account(input))
+    }
+
+    /// Blocking version of [`GuardDuty::disassociate_members`].
+    pub fn disassociate_members(
+        &self,
+        input: DisassociateMembersRequest,
+    ) -> Result<DisassociateMembersResponse, RusotoError<DisassociateMembersError>> {
+        self.runtime
+            .block_on(self.client.disassociate_members(input))
+    }
+
+    /// Blocking version of [`GuardDuty::enable_organization_admin_account`].
+    pub fn enable_organization_admin_account(
+        &self,
+        input: EnableOrganizationAdminAccountRequest,
+    ) -> Result<
+        EnableOrganizationAdminAccountResponse,
+        RusotoError<EnableOrganizationAdminAccountError>,
+    > {
+        self.runtime
+            .block_on(self.client.enable_organization_admin_account(input))
+    }
+
+    /// Blocking version of [`GuardDuty::get_detector`].
+    pub fn get_detector(
+        &self,
+        input: GetDetectorRequest,
+    ) -> Result<GetDetectorResponse, RusotoError<GetDetectorError>> {
+        self.runtime.block_on(self.client.get_detector(input))
+    }
+
+    /// Blocking version of [`GuardDuty::get_filter`].
+    pub fn get_filter(
+        &self,
+        input: GetFilterRequest,
+    ) -> Result<GetFilterResponse, RusotoError<GetFilterError>> {
+        self.runtime.block_on(self.client.get_filter(input))
+    }
+
+    /// Blocking version of [`GuardDuty::get_findings`].
+    pub fn get_findings(
+        &self,
+        input: GetFindingsRequest,
+    ) -> Result<GetFindingsResponse, RusotoError<GetFindingsError>> {
+        self.runtime.block_on(self.client.get_findings(input))
+    }
+
+    /// Blocking version of [`GuardDuty::get_findings_statistics`].
+    pub fn get_findings_statistics(
+        &self,
+        input: GetFindingsStatisticsRequest,
+    ) -> Result<GetFindingsStatisticsResponse, RusotoError<GetFindingsStatisticsError>> {
+        self.runtime
+            .block_on(self.client.get_findings_statistics(input))
+    }
+
+    /// Blocking version of [`GuardDuty::get_ip_set`].
+    pub fn get_ip_set(
+        &self,
+        input: GetIPSetRequest,
+    ) -> Result<GetIPSetResponse, RusotoError<GetIPSetError>> {
+        self.runtime.block_on(self.client.get_ip_set(input))
+    }
+
+    /// Blocking version of [`GuardDuty::get_invitations_count`].
+    pub fn get_invitations_count(
+        &self,
+    ) -> Result<GetInvitationsCountResponse, RusotoError<GetInvitationsCountError>> {
+        self.runtime.block_on(self.client.get_invitations_count())
+    }
+
+    /// Blocking version of [`GuardDuty::get_master_account`].
+    pub fn get_master_account(
+        &self,
+        input: GetMasterAccountRequest,
+    ) -> Result<GetMasterAccountResponse, RusotoError<GetMasterAccountError>> {
+        self.runtime.block_on(self.client.get_master_account(input))
+    }
+
+    /// Blocking version of [`GuardDuty::get_member_detectors`].
+    pub fn get_member_detectors(
+        &self,
+        input: GetMemberDetectorsRequest,
+    ) -> Result<GetMemberDetectorsResponse, RusotoError<GetMemberDetectorsError>> {
+        self.runtime
+            .block_on(self.client.get_member_detectors(input))
+    }
+
+    /// Blocking version of [`GuardDuty::get_members`].
+    pub fn get_members(
+        &self,
+        input: GetMembersRequest,
+    ) -> Result<GetMembersResponse, RusotoError<GetMembersError>> {
+        self.runtime.block_on(self.client.get_members(input))
+    }
+
+    /// Blocking version of [`GuardDuty::get_threat_intel_set`].
+    pub fn get_threat_intel_set(
+        &self,
+        input: GetThreatIntelSetRequest,
+    ) -> Result<GetThreatIntelSetResponse, RusotoError<GetThreatIntelSetError>> {
+        self.runtime
+            .block_on(self.client.get_threat_intel_set(input))
+    }
+
+    /// Blocking version of [`GuardDuty::get_usage_statistics`].
+    pub fn get_usage_statistics(
+        &self,
+        input: GetUsageStatisticsRequest,
+    ) -> Result<GetUsageStatisticsResponse, RusotoError<GetUsageStatisticsError>> {
+        self.runtime
+            .block_on(self.client.get_usage_statistics(input))
+    }
+
+    /// Blocking version of [`GuardDuty::invite_members`].
+    pub fn invite_members(
+        &self,
+        input: InviteMembersRequest,
+    ) -> Result<InviteMembersResponse, RusotoError<InviteMembersError>> {
+        self.runtime.block_on(self.client.invite_members(input))
+    }
+
+    /// Blocking version of [`GuardDuty::list_detectors`].
+    pub fn list_detectors(
+        &self,
+        input: ListDetectorsRequest,
+    ) -> Result<ListDetectorsResponse, RusotoError<ListDetectorsError>> {
+        self.runtime.block_on(self.client.list_detectors(input))
+    }
+
+    /// Blocking version of [`GuardDuty::list_filters`].
+    pub fn list_filters(
+        &self,
+        input: ListFiltersRequest,
+    ) -> Result<ListFiltersResponse, RusotoError<ListFiltersError>> {
+        self.runtime.block_on(self.client.list_filters(input))
+    }
+
+    /// Blocking version of [`GuardDuty::list_findings`].
+    pub fn list_findings(
+        &self,
+        input: ListFindingsRequest,
+    ) -> Result<ListFindingsResponse, RusotoError<ListFindingsError>> {
+        self.runtime.block_on(self.client.list_findings(input))
+    }
+
+    /// Blocking version of [`GuardDuty::list_ip_sets`].
+    pub fn list_ip_sets(
+        &self,
+        input: ListIPSetsRequest,
+    ) -> Result<ListIPSetsResponse, RusotoError<ListIPSetsError>> {
+        self.runtime.block_on(self.client.list_ip_sets(input))
+    }
+
+    /// Blocking version of [`GuardDuty::list_invitations`].
+    pub fn list_invitations(
+        &self,
+        input: ListInvitationsRequest,
+    ) -> Result<ListInvitationsResponse, RusotoError<ListInvitationsError>> {
+        self.runtime.block_on(self.client.list_invitations(input))
+    }
+
+    /// Blocking version of [`GuardDuty::list_members`].
+    pub fn list_members(
+        &self,
+        input: ListMembersRequest,
+    ) -> Result<ListMembersResponse, RusotoError<ListMembersError>> {
+        self.runtime.block_on(self.client.list_members(input))
+    }
+
+    /// Blocking version of [`GuardDuty::list_organization_admin_accounts`].
+    pub fn list_organization_admin_accounts(
+        &self,
+        input: ListOrganizationAdminAccountsRequest,
+    ) -> Result<
+        ListOrganizationAdminAccountsResponse,
+        RusotoError<ListOrganizationAdminAccountsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_organization_admin_accounts(input))
+    }
+
+    /// Blocking version of [`GuardDuty::list_publishing_destinations`].
+    pub fn list_publishing_destinations(
+        &self,
+        input: ListPublishingDestinationsRequest,
+    ) -> Result<ListPublishingDestinationsResponse, RusotoError<ListPublishingDestinationsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_publishing_destinations(input))
+    }
+
+    /// Blocking version of [`GuardDuty::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`GuardDuty::list_threat_intel_sets`].
+    pub fn list_threat_intel_sets(
+        &self,
+        input: ListThreatIntelSetsRequest,
+    ) -> Result<ListThreatIntelSetsResponse, RusotoError<ListThreatIntelSetsError>> {
+        self.runtime
+            .block_on(self.client.list_threat_intel_sets(input))
+    }
+
+    /// Blocking version of [`GuardDuty::start_monitoring_members`].
+    pub fn start_monitoring_members(
+        &self,
+        input: StartMonitoringMembersRequest,
+    ) -> Result<StartMonitoringMembersResponse, RusotoError<StartMonitoringMembersError>> {
+        self.runtime
+            .block_on(self.client.start_monitoring_members(input))
+    }
+
+    /// Blocking version of [`GuardDuty::stop_monitoring_members`].
+    pub fn stop_monitoring_members(
+        &self,
+        input: StopMonitoringMembersRequest,
+    ) -> Result<StopMonitoringMembersResponse, RusotoError<StopMonitoringMembersError>> {
+        self.runtime
+            .block_on(self.client.stop_monitoring_members(input))
+    }
+
+    /// Blocking version of [`GuardDuty::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`GuardDuty::unarchive_findings`].
+    pub fn unarchive_findings(
+        &self,
+        input: UnarchiveFindingsRequest,
+    ) -> Result<UnarchiveFindingsResponse, RusotoError<UnarchiveFindingsError>> {
+        self.runtime.block_on(self.client.unarchive_findings(input))
+    }
+
+    /// Blocking version of [`GuardDuty::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`GuardDuty::update_detector`].
+    pub fn update_detector(
+        &self,
+        input: UpdateDetectorRequest,
+    ) -> Result<UpdateDetectorResponse, RusotoError<UpdateDetectorError>> {
+        self.runtime.block_on(self.client.update_detector(input))
+    }
+
+    /// Blocking version of [`GuardDuty::update_filter`].
+    pub fn update_filter(
+        &self,
+        input: UpdateFilterRequest,
+    ) -> Result<UpdateFilterResponse, RusotoError<UpdateFilterError>> {
+        self.runtime.block_on(self.client.update_filter(input))
+    }
+
+    /// Blocking version of [`GuardDuty::update_findings_feedback`].
+    pub fn update_findings_feedback(
+        &self,
+        input: UpdateFindingsFeedbackRequest,
+    ) -> Result<UpdateFindingsFeedbackResponse, RusotoError<UpdateFindingsFeedbackError>> {
+        self.runtime
+            .block_on(self.client.update_findings_feedback(input))
+    }
+
+    /// Blocking version of [`GuardDuty::update_ip_set`].
+    pub fn update_ip_set(
+        &self,
+        input: UpdateIPSetRequest,
+    ) -> Result<UpdateIPSetResponse, RusotoError<UpdateIPSetError>> {
+        self.runtime.block_on(self.client.update_ip_set(input))
+    }
+
+    /// Blocking version of [`GuardDuty::update_member_detectors`].
+    pub fn update_member_detectors(
+        &self,
+        input: UpdateMemberDetectorsRequest,
+    ) -> Result<UpdateMemberDetectorsResponse, RusotoError<UpdateMemberDetectorsError>> {
+        self.runtime
+            .block_on(self.client.update_member_detectors(input))
+    }
+
+    /// Blocking version of [`GuardDuty::update_organization_configuration`].
+    pub fn update_organization_configuration(
+        &self,
+        input: UpdateOrganizationConfigurationRequest,
+    ) -> Result<
+        UpdateOrganizationConfigurationResponse,
+        RusotoError<UpdateOrganizationConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_organization_configuration(input))
+    }
+
+    /// Blocking version of [`GuardDuty::update_publishing_destination`].
+    pub fn update_publishing_destination(
+        &self,
+        input: UpdatePublishingDestinationRequest,
+    ) -> Result<UpdatePublishingDestinationResponse, RusotoError<UpdatePublishingDestinationError>>
+    {
+        self.runtime
+            .block_on(self.client.update_publishing_destination(input))
+    }
+
+    /// Blocking version of [`GuardDuty::update_threat_intel_set`].
+    pub fn update_threat_intel_set(
+        &self,
+        input: UpdateThreatIntelSetRequest,
+    ) -> Result<UpdateThreatIntelSetResponse, RusotoError<UpdateThreatIntelSetError>> {
+        self.runtime
+            .block_on(self.client.update_threat_intel_set(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -820,13 +821,14 @@ impl DescribeAffectedAccountsForOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidPaginationToken" => {
-                    return RusotoError::Service(
-                        DescribeAffectedAccountsForOrganizationError::InvalidPaginationToken(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAffectedAccountsForOrganizationError::InvalidPaginationToken,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -858,16 +860,22 @@ impl DescribeAffectedEntitiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidPaginationToken" => {
-                    return RusotoError::Service(
-                        DescribeAffectedEntitiesError::InvalidPaginationToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAffectedEntitiesError::InvalidPaginationToken,
                     )
                 }
                 "UnsupportedLocale" => {
-                    return RusotoError::Service(DescribeAffectedEntitiesError::UnsupportedLocale(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAffectedEntitiesError::UnsupportedLocale,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -902,18 +910,22 @@ impl DescribeAffectedEntitiesForOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidPaginationToken" => {
-                    return RusotoError::Service(
-                        DescribeAffectedEntitiesForOrganizationError::InvalidPaginationToken(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAffectedEntitiesForOrganizationError::InvalidPaginationToken,
                     )
                 }
                 "UnsupportedLocale" => {
-                    return RusotoError::Service(
-                        DescribeAffectedEntitiesForOrganizationError::UnsupportedLocale(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAffectedEntitiesForOrganizationError::UnsupportedLocale,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -942,7 +954,7 @@ impl DescribeEntityAggregatesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeEntityAggregatesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -968,11 +980,14 @@ impl DescribeEventAggregatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidPaginationToken" => {
-                    return RusotoError::Service(
-                        DescribeEventAggregatesError::InvalidPaginationToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEventAggregatesError::InvalidPaginationToken,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1002,11 +1017,14 @@ impl DescribeEventDetailsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "UnsupportedLocale" => {
-                    return RusotoError::Service(DescribeEventDetailsError::UnsupportedLocale(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEventDetailsError::UnsupportedLocale,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1036,11 +1054,14 @@ impl DescribeEventDetailsForOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "UnsupportedLocale" => {
-                    return RusotoError::Service(
-                        DescribeEventDetailsForOrganizationError::UnsupportedLocale(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEventDetailsForOrganizationError::UnsupportedLocale,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1072,16 +1093,22 @@ impl DescribeEventTypesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidPaginationToken" => {
-                    return RusotoError::Service(DescribeEventTypesError::InvalidPaginationToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEventTypesError::InvalidPaginationToken,
+                    )
                 }
                 "UnsupportedLocale" => {
-                    return RusotoError::Service(DescribeEventTypesError::UnsupportedLocale(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEventTypesError::UnsupportedLocale,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1112,14 +1139,22 @@ impl DescribeEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidPaginationToken" => {
-                    return RusotoError::Service(DescribeEventsError::InvalidPaginationToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEventsError::InvalidPaginationToken,
+                    )
                 }
                 "UnsupportedLocale" => {
-                    return RusotoError::Service(DescribeEventsError::UnsupportedLocale(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEventsError::UnsupportedLocale,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1152,16 +1187,22 @@ impl DescribeEventsForOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidPaginationToken" => {
-                    return RusotoError::Service(
-                        DescribeEventsForOrganizationError::InvalidPaginationToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEventsForOrganizationError::InvalidPaginationToken,
                     )
                 }
                 "UnsupportedLocale" => {
-                    return RusotoError::Service(
-                        DescribeEventsForOrganizationError::UnsupportedLocale(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEventsForOrganizationError::UnsupportedLocale,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1192,7 +1233,7 @@ impl DescribeHealthServiceStatusForOrganizationError {
     ) -> RusotoError<DescribeHealthServiceStatusForOrganizationError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1220,13 +1261,14 @@ impl DisableHealthServiceAccessForOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        DisableHealthServiceAccessForOrganizationError::ConcurrentModification(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableHealthServiceAccessForOrganizationError::ConcurrentModification,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1258,13 +1300,14 @@ impl EnableHealthServiceAccessForOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        EnableHealthServiceAccessForOrganizationError::ConcurrentModification(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableHealthServiceAccessForOrganizationError::ConcurrentModification,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1385,11 +1428,12 @@ pub struct AWSHealthClient {
 impl AWSHealthClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> AWSHealthClient {
         AWSHealthClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Health"),
         }
     }
 
@@ -1404,12 +1448,32 @@ impl AWSHealthClient {
     {
         AWSHealthClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Health"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> AWSHealthClient {
-        AWSHealthClient { client, region }
+        AWSHealthClient {
+            client,
+            region: region_for_service(region, "Health"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> AWSHealthClient {
+        AWSHealthClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Health"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> AWSHealthClient {
+        AWSHealthClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -1424,6 +1488,7 @@ impl AWSHealth for AWSHealthClient {
         RusotoError<DescribeAffectedAccountsForOrganizationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeAffectedAccountsForOrganization");
         request.add_header(
             "x-amz-target",
             "AWSHealth_20160804.DescribeAffectedAccountsForOrganization",
@@ -1449,6 +1514,7 @@ impl AWSHealth for AWSHealthClient {
         input: DescribeAffectedEntitiesRequest,
     ) -> Result<DescribeAffectedEntitiesResponse, RusotoError<DescribeAffectedEntitiesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeAffectedEntities");
         request.add_header(
             "x-amz-target",
             "AWSHealth_20160804.DescribeAffectedEntities",
@@ -1474,6 +1540,7 @@ impl AWSHealth for AWSHealthClient {
         RusotoError<DescribeAffectedEntitiesForOrganizationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeAffectedEntitiesForOrganization");
         request.add_header(
             "x-amz-target",
             "AWSHealth_20160804.DescribeAffectedEntitiesForOrganization",
@@ -1499,6 +1566,7 @@ impl AWSHealth for AWSHealthClient {
         input: DescribeEntityAggregatesRequest,
     ) -> Result<DescribeEntityAggregatesResponse, RusotoError<DescribeEntityAggregatesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeEntityAggregates");
         request.add_header(
             "x-amz-target",
             "AWSHealth_20160804.DescribeEntityAggregates",
@@ -1521,6 +1589,7 @@ impl AWSHealth for AWSHealthClient {
         input: DescribeEventAggregatesRequest,
     ) -> Result<DescribeEventAggregatesResponse, RusotoError<DescribeEventAggregatesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeEventAggregates");
         request.add_header("x-amz-target", "AWSHealth_20160804.DescribeEventAggregates");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1540,6 +1609,7 @@ impl AWSHealth for AWSHealthClient {
         input: DescribeEventDetailsRequest,
     ) -> Result<DescribeEventDetailsResponse, RusotoError<DescribeEventDetailsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeEventDetails");
         request.add_header("x-amz-target", "AWSHealth_20160804.DescribeEventDetails");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1562,6 +1632,7 @@ impl AWSHealth for AWSHealthClient {
         RusotoError<DescribeEventDetailsForOrganizationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeEventDetailsForOrganization");
         request.add_header(
             "x-amz-target",
             "AWSHealth_20160804.DescribeEventDetailsForOrganization",
@@ -1587,6 +1658,7 @@ impl AWSHealth for AWSHealthClient {
         input: DescribeEventTypesRequest,
     ) -> Result<DescribeEventTypesResponse, RusotoError<DescribeEventTypesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeEventTypes");
         request.add_header("x-amz-target", "AWSHealth_20160804.DescribeEventTypes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1605,6 +1677,7 @@ impl AWSHealth for AWSHealthClient {
         input: DescribeEventsRequest,
     ) -> Result<DescribeEventsResponse, RusotoError<DescribeEventsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeEvents");
         request.add_header("x-amz-target", "AWSHealth_20160804.DescribeEvents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1626,6 +1699,7 @@ impl AWSHealth for AWSHealthClient {
         RusotoError<DescribeEventsForOrganizationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeEventsForOrganization");
         request.add_header(
             "x-amz-target",
             "AWSHealth_20160804.DescribeEventsForOrganization",
@@ -1650,6 +1724,7 @@ impl AWSHealth for AWSHealthClient {
         RusotoError<DescribeHealthServiceStatusForOrganizationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeHealthServiceStatusForOrganization");
         request.add_header(
             "x-amz-target",
             "AWSHealth_20160804.DescribeHealthServiceStatusForOrganization",
@@ -1673,6 +1748,7 @@ impl AWSHealth for AWSHealthClient {
         &self,
     ) -> Result<(), RusotoError<DisableHealthServiceAccessForOrganizationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisableHealthServiceAccessForOrganization");
         request.add_header(
             "x-amz-target",
             "AWSHealth_20160804.DisableHealthServiceAccessForOrganization",
@@ -1694,6 +1770,7 @@ impl AWSHealth for AWSHealthClient {
         &self,
     ) -> Result<(), RusotoError<EnableHealthServiceAccessForOrganizationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("EnableHealthServiceAccessForOrganization");
         request.add_header(
             "x-amz-target",
             "AWSHealth_20160804.EnableHealthServiceAccessForOrganization",
@@ -1710,3 +1787,212 @@ impl AWSHealth for AWSHealthClient {
         Ok(())
     }
 }
+
+/// A blocking client for the AWSHealth API, running the calls of a `AWSHealthClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `AWSHealthClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct AWSHealthBlockingClient {
+    client: AWSHealthClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl AWSHealthBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> AWSHealthBlockingClient {
+        AWSHealthBlockingClient::from_client(AWSHealthClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> AWSHealthBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        AWSHealthBlockingClient::from_client(AWSHealthClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> AWSHealthBlockingClient {
+        AWSHealthBlockingClient::from_client(AWSHealthClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> AWSHealthBlockingClient {
+        AWSHealthBlockingClient::from_client(AWSHealthClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: AWSHealthClient) -> AWSHealthBlockingClient {
+        AWSHealthBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> AWSHealthBlockingClient {
+        AWSHealthBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> AWSHealthBlockingClient {
+        AWSHealthBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`AWSHealth::describe_affected_accounts_for_organization`].
+    pub fn describe_affected_accounts_for_organization(
+        &self,
+        input: DescribeAffectedAccountsForOrganizationRequest,
+    ) -> Result<
+        DescribeAffectedAccountsForOrganizationResponse,
+        RusotoError<DescribeAffectedAccountsForOrganizationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_affected_accounts_for_organization(input),
+        )
+    }
+
+    /// Blocking version of [`AWSHealth::describe_affected_entities`].
+    pub fn describe_affected_entities(
+        &self,
+        input: DescribeAffectedEntitiesRequest,
+    ) -> Result<DescribeAffectedEntitiesResponse, RusotoError<DescribeAffectedEntitiesError>> {
+        self.runtime
+            .block_on(self.client.describe_affected_entities(input))
+    }
+
+    /// Blocking version of [`AWSHealth::describe_affected_entities_for_organization`].
+    pub fn describe_affected_entities_for_organization(
+        &self,
+        input: DescribeAffectedEntitiesForOrganizationRequest,
+    ) -> Result<
+        DescribeAffectedEntitiesForOrganizationResponse,
+        RusotoError<DescribeAffectedEntitiesForOrganizationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_affected_entities_for_organization(input),
+        )
+    }
+
+    /// Blocking version of [`AWSHealth::describe_entity_aggregates`].
+    pub fn describe_entity_aggregates(
+        &self,
+        input: DescribeEntityAggregatesRequest,
+    ) -> Result<DescribeEntityAggregatesResponse, RusotoError<DescribeEntityAggregatesError>> {
+        self.runtime
+            .block_on(self.client.describe_entity_aggregates(input))
+    }
+
+    /// Blocking version of [`AWSHealth::describe_event_aggregates`].
+    pub fn describe_event_aggregates(
+        &self,
+        input: DescribeEventAggregatesRequest,
+    ) -> Result<DescribeEventAggregatesResponse, RusotoError<DescribeEventAggregatesError>> {
+        self.runtime
+            .block_on(self.client.describe_event_aggregates(input))
+    }
+
+    /// Blocking version of [`AWSHealth::describe_event_details`].
+    pub fn describe_event_details(
+        &self,
+        input: DescribeEventDetailsRequest,
+    ) -> Result<DescribeEventDetailsResponse, RusotoError<DescribeEventDetailsError>> {
+        self.runtime
+            .block_on(self.client.describe_event_details(input))
+    }
+
+    /// Blocking version of [`AWSHealth::describe_event_details_for_organization`].
+    pub fn describe_event_details_for_organization(
+        &self,
+        input: DescribeEventDetailsForOrganizationRequest,
+    ) -> Result<
+        DescribeEventDetailsForOrganizationResponse,
+        RusotoError<DescribeEventDetailsForOrganizationError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_event_details_for_organization(input))
+    }
+
+    /// Blocking version of [`AWSHealth::describe_event_types`].
+    pub fn describe_event_types(
+        &self,
+        input: DescribeEventTypesRequest,
+    ) -> Result<DescribeEventTypesResponse, RusotoError<DescribeEventTypesError>> {
+        self.runtime
+            .block_on(self.client.describe_event_types(input))
+    }
+
+    /// Blocking version of [`AWSHealth::describe_events`].
+    pub fn describe_events(
+        &self,
+        input: DescribeEventsRequest,
+    ) -> Result<DescribeEventsResponse, RusotoError<DescribeEventsError>> {
+        self.runtime.block_on(self.client.describe_events(input))
+    }
+
+    /// Blocking version of [`AWSHealth::describe_events_for_organization`].
+    pub fn describe_events_for_organization(
+        &self,
+        input: DescribeEventsForOrganizationRequest,
+    ) -> Result<
+        DescribeEventsForOrganizationResponse,
+        RusotoError<DescribeEventsForOrganizationError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_events_for_organization(input))
+    }
+
+    /// Blocking version of [`AWSHealth::describe_health_service_status_for_organization`].
+    pub fn describe_health_service_status_for_organization(
+        &self,
+    ) -> Result<
+        DescribeHealthServiceStatusForOrganizationResponse,
+        RusotoError<DescribeHealthServiceStatusForOrganizationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_health_service_status_for_organization(),
+        )
+    }
+
+    /// Blocking version of [`AWSHealth::disable_health_service_access_for_organization`].
+    pub fn disable_health_service_access_for_organization(
+        &self,
+    ) -> Result<(), RusotoError<DisableHealthServiceAccessForOrganizationError>> {
+        self.runtime
+            .block_on(self.client.disable_health_service_access_for_organization())
+    }
+
+    /// Blocking version of [`AWSHealth::enable_health_service_access_for_organization`].
+    pub fn enable_health_service_access_for_organization(
+        &self,
+    ) -> Result<(), RusotoError<EnableHealthServiceAccessForOrganizationError>> {
+        self.runtime
+            .block_on(self.client.enable_health_service_access_for_organization())
+    }
+}
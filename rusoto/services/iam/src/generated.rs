@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -11370,31 +11371,35 @@ impl AddClientIDToOpenIDConnectProviderError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
-                            AddClientIDToOpenIDConnectProviderError::InvalidInput(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AddClientIDToOpenIDConnectProviderError::InvalidInput,
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(
-                            AddClientIDToOpenIDConnectProviderError::LimitExceeded(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AddClientIDToOpenIDConnectProviderError::LimitExceeded,
                         )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
-                            AddClientIDToOpenIDConnectProviderError::NoSuchEntity(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AddClientIDToOpenIDConnectProviderError::NoSuchEntity,
                         )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(
-                            AddClientIDToOpenIDConnectProviderError::ServiceFailure(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AddClientIDToOpenIDConnectProviderError::ServiceFailure,
                         )
                     }
                     _ => {}
@@ -11456,30 +11461,43 @@ impl AddRoleToInstanceProfileError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EntityAlreadyExists" => {
-                        return RusotoError::Service(
-                            AddRoleToInstanceProfileError::EntityAlreadyExists(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AddRoleToInstanceProfileError::EntityAlreadyExists,
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(AddRoleToInstanceProfileError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AddRoleToInstanceProfileError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(AddRoleToInstanceProfileError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AddRoleToInstanceProfileError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(AddRoleToInstanceProfileError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AddRoleToInstanceProfileError::ServiceFailure,
+                        )
                     }
                     "UnmodifiableEntity" => {
-                        return RusotoError::Service(
-                            AddRoleToInstanceProfileError::UnmodifiableEntity(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AddRoleToInstanceProfileError::UnmodifiableEntity,
                         )
                     }
                     _ => {}
@@ -11530,19 +11548,28 @@ impl AddUserToGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(AddUserToGroupError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AddUserToGroupError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(AddUserToGroupError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AddUserToGroupError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(AddUserToGroupError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AddUserToGroupError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -11594,29 +11621,44 @@ impl AttachGroupPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(AttachGroupPolicyError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AttachGroupPolicyError::InvalidInput,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(AttachGroupPolicyError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AttachGroupPolicyError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(AttachGroupPolicyError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AttachGroupPolicyError::NoSuchEntity,
+                        )
                     }
                     "PolicyNotAttachable" => {
-                        return RusotoError::Service(AttachGroupPolicyError::PolicyNotAttachable(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AttachGroupPolicyError::PolicyNotAttachable,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(AttachGroupPolicyError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AttachGroupPolicyError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -11672,34 +11714,52 @@ impl AttachRolePolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(AttachRolePolicyError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AttachRolePolicyError::InvalidInput,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(AttachRolePolicyError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AttachRolePolicyError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(AttachRolePolicyError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AttachRolePolicyError::NoSuchEntity,
+                        )
                     }
                     "PolicyNotAttachable" => {
-                        return RusotoError::Service(AttachRolePolicyError::PolicyNotAttachable(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AttachRolePolicyError::PolicyNotAttachable,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(AttachRolePolicyError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AttachRolePolicyError::ServiceFailure,
+                        )
                     }
                     "UnmodifiableEntity" => {
-                        return RusotoError::Service(AttachRolePolicyError::UnmodifiableEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AttachRolePolicyError::UnmodifiableEntity,
+                        )
                     }
                     _ => {}
                 }
@@ -11754,29 +11814,44 @@ impl AttachUserPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(AttachUserPolicyError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AttachUserPolicyError::InvalidInput,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(AttachUserPolicyError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AttachUserPolicyError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(AttachUserPolicyError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AttachUserPolicyError::NoSuchEntity,
+                        )
                     }
                     "PolicyNotAttachable" => {
-                        return RusotoError::Service(AttachUserPolicyError::PolicyNotAttachable(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AttachUserPolicyError::PolicyNotAttachable,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(AttachUserPolicyError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AttachUserPolicyError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -11832,36 +11907,52 @@ impl ChangePasswordError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EntityTemporarilyUnmodifiable" => {
-                        return RusotoError::Service(
-                            ChangePasswordError::EntityTemporarilyUnmodifiable(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ChangePasswordError::EntityTemporarilyUnmodifiable,
                         )
                     }
                     "InvalidUserType" => {
-                        return RusotoError::Service(ChangePasswordError::InvalidUserType(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ChangePasswordError::InvalidUserType,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(ChangePasswordError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ChangePasswordError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(ChangePasswordError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ChangePasswordError::NoSuchEntity,
+                        )
                     }
                     "PasswordPolicyViolation" => {
-                        return RusotoError::Service(ChangePasswordError::PasswordPolicyViolation(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ChangePasswordError::PasswordPolicyViolation,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(ChangePasswordError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ChangePasswordError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -11912,19 +12003,28 @@ impl CreateAccessKeyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateAccessKeyError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateAccessKeyError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(CreateAccessKeyError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateAccessKeyError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(CreateAccessKeyError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateAccessKeyError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -11972,19 +12072,28 @@ impl CreateAccountAliasError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EntityAlreadyExists" => {
-                        return RusotoError::Service(CreateAccountAliasError::EntityAlreadyExists(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateAccountAliasError::EntityAlreadyExists,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateAccountAliasError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateAccountAliasError::LimitExceeded,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(CreateAccountAliasError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateAccountAliasError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -12034,24 +12143,36 @@ impl CreateGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EntityAlreadyExists" => {
-                        return RusotoError::Service(CreateGroupError::EntityAlreadyExists(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateGroupError::EntityAlreadyExists,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateGroupError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateGroupError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(CreateGroupError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateGroupError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(CreateGroupError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateGroupError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -12100,19 +12221,28 @@ impl CreateInstanceProfileError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EntityAlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateInstanceProfileError::EntityAlreadyExists(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateInstanceProfileError::EntityAlreadyExists,
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateInstanceProfileError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateInstanceProfileError::LimitExceeded,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(CreateInstanceProfileError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateInstanceProfileError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -12164,29 +12294,44 @@ impl CreateLoginProfileError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EntityAlreadyExists" => {
-                        return RusotoError::Service(CreateLoginProfileError::EntityAlreadyExists(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateLoginProfileError::EntityAlreadyExists,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateLoginProfileError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateLoginProfileError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(CreateLoginProfileError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateLoginProfileError::NoSuchEntity,
+                        )
                     }
                     "PasswordPolicyViolation" => {
-                        return RusotoError::Service(
-                            CreateLoginProfileError::PasswordPolicyViolation(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateLoginProfileError::PasswordPolicyViolation,
                         )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(CreateLoginProfileError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateLoginProfileError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -12240,25 +12385,35 @@ impl CreateOpenIDConnectProviderError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EntityAlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateOpenIDConnectProviderError::EntityAlreadyExists(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateOpenIDConnectProviderError::EntityAlreadyExists,
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(
-                            CreateOpenIDConnectProviderError::InvalidInput(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateOpenIDConnectProviderError::InvalidInput,
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(
-                            CreateOpenIDConnectProviderError::LimitExceeded(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateOpenIDConnectProviderError::LimitExceeded,
                         )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(
-                            CreateOpenIDConnectProviderError::ServiceFailure(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateOpenIDConnectProviderError::ServiceFailure,
                         )
                     }
                     _ => {}
@@ -12314,29 +12469,44 @@ impl CreatePolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EntityAlreadyExists" => {
-                        return RusotoError::Service(CreatePolicyError::EntityAlreadyExists(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreatePolicyError::EntityAlreadyExists,
+                        )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(CreatePolicyError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreatePolicyError::InvalidInput,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreatePolicyError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreatePolicyError::LimitExceeded,
+                        )
                     }
                     "MalformedPolicyDocument" => {
-                        return RusotoError::Service(CreatePolicyError::MalformedPolicyDocument(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreatePolicyError::MalformedPolicyDocument,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(CreatePolicyError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreatePolicyError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -12390,29 +12560,44 @@ impl CreatePolicyVersionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(CreatePolicyVersionError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreatePolicyVersionError::InvalidInput,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreatePolicyVersionError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreatePolicyVersionError::LimitExceeded,
+                        )
                     }
                     "MalformedPolicyDocument" => {
-                        return RusotoError::Service(
-                            CreatePolicyVersionError::MalformedPolicyDocument(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreatePolicyVersionError::MalformedPolicyDocument,
                         )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(CreatePolicyVersionError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreatePolicyVersionError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(CreatePolicyVersionError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreatePolicyVersionError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -12468,34 +12653,52 @@ impl CreateRoleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(CreateRoleError::ConcurrentModification(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateRoleError::ConcurrentModification,
+                        )
                     }
                     "EntityAlreadyExists" => {
-                        return RusotoError::Service(CreateRoleError::EntityAlreadyExists(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateRoleError::EntityAlreadyExists,
+                        )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(CreateRoleError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateRoleError::InvalidInput,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateRoleError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateRoleError::LimitExceeded,
+                        )
                     }
                     "MalformedPolicyDocument" => {
-                        return RusotoError::Service(CreateRoleError::MalformedPolicyDocument(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateRoleError::MalformedPolicyDocument,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(CreateRoleError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateRoleError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -12548,24 +12751,36 @@ impl CreateSAMLProviderError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EntityAlreadyExists" => {
-                        return RusotoError::Service(CreateSAMLProviderError::EntityAlreadyExists(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateSAMLProviderError::EntityAlreadyExists,
+                        )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(CreateSAMLProviderError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateSAMLProviderError::InvalidInput,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateSAMLProviderError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateSAMLProviderError::LimitExceeded,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(CreateSAMLProviderError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateSAMLProviderError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -12616,24 +12831,36 @@ impl CreateServiceLinkedRoleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(CreateServiceLinkedRoleError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateServiceLinkedRoleError::InvalidInput,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateServiceLinkedRoleError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateServiceLinkedRoleError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(CreateServiceLinkedRoleError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateServiceLinkedRoleError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(CreateServiceLinkedRoleError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateServiceLinkedRoleError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -12684,24 +12911,27 @@ impl CreateServiceSpecificCredentialError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(
-                            CreateServiceSpecificCredentialError::LimitExceeded(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateServiceSpecificCredentialError::LimitExceeded,
                         )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
-                            CreateServiceSpecificCredentialError::NoSuchEntity(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateServiceSpecificCredentialError::NoSuchEntity,
                         )
                     }
                     "NotSupportedService" => {
-                        return RusotoError::Service(
-                            CreateServiceSpecificCredentialError::ServiceNotSupported(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateServiceSpecificCredentialError::ServiceNotSupported,
                         )
                     }
                     _ => {}
@@ -12760,34 +12990,52 @@ impl CreateUserError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(CreateUserError::ConcurrentModification(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateUserError::ConcurrentModification,
+                        )
                     }
                     "EntityAlreadyExists" => {
-                        return RusotoError::Service(CreateUserError::EntityAlreadyExists(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateUserError::EntityAlreadyExists,
+                        )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(CreateUserError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateUserError::InvalidInput,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateUserError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateUserError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(CreateUserError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateUserError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(CreateUserError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateUserError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -12838,19 +13086,28 @@ impl CreateVirtualMFADeviceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EntityAlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateVirtualMFADeviceError::EntityAlreadyExists(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateVirtualMFADeviceError::EntityAlreadyExists,
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(CreateVirtualMFADeviceError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateVirtualMFADeviceError::LimitExceeded,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(CreateVirtualMFADeviceError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateVirtualMFADeviceError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -12900,26 +13157,36 @@ impl DeactivateMFADeviceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EntityTemporarilyUnmodifiable" => {
-                        return RusotoError::Service(
-                            DeactivateMFADeviceError::EntityTemporarilyUnmodifiable(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeactivateMFADeviceError::EntityTemporarilyUnmodifiable,
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(DeactivateMFADeviceError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeactivateMFADeviceError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DeactivateMFADeviceError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeactivateMFADeviceError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DeactivateMFADeviceError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeactivateMFADeviceError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -12970,19 +13237,28 @@ impl DeleteAccessKeyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(DeleteAccessKeyError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteAccessKeyError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DeleteAccessKeyError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteAccessKeyError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DeleteAccessKeyError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteAccessKeyError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -13030,19 +13306,28 @@ impl DeleteAccountAliasError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(DeleteAccountAliasError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteAccountAliasError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DeleteAccountAliasError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteAccountAliasError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DeleteAccountAliasError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteAccountAliasError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -13092,18 +13377,27 @@ impl DeleteAccountPasswordPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(
-                            DeleteAccountPasswordPolicyError::LimitExceeded(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteAccountPasswordPolicyError::LimitExceeded,
                         )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
-                            DeleteAccountPasswordPolicyError::NoSuchEntity(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteAccountPasswordPolicyError::NoSuchEntity,
                         )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(
-                            DeleteAccountPasswordPolicyError::ServiceFailure(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteAccountPasswordPolicyError::ServiceFailure,
                         )
                     }
                     _ => {}
@@ -13154,24 +13448,36 @@ impl DeleteGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DeleteConflict" => {
-                        return RusotoError::Service(DeleteGroupError::DeleteConflict(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteGroupError::DeleteConflict,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(DeleteGroupError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteGroupError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DeleteGroupError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteGroupError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DeleteGroupError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteGroupError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -13220,19 +13526,28 @@ impl DeleteGroupPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(DeleteGroupPolicyError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteGroupPolicyError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DeleteGroupPolicyError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteGroupPolicyError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DeleteGroupPolicyError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteGroupPolicyError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -13282,24 +13597,36 @@ impl DeleteInstanceProfileError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DeleteConflict" => {
-                        return RusotoError::Service(DeleteInstanceProfileError::DeleteConflict(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteInstanceProfileError::DeleteConflict,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(DeleteInstanceProfileError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteInstanceProfileError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DeleteInstanceProfileError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteInstanceProfileError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DeleteInstanceProfileError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteInstanceProfileError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -13350,26 +13677,36 @@ impl DeleteLoginProfileError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EntityTemporarilyUnmodifiable" => {
-                        return RusotoError::Service(
-                            DeleteLoginProfileError::EntityTemporarilyUnmodifiable(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteLoginProfileError::EntityTemporarilyUnmodifiable,
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(DeleteLoginProfileError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteLoginProfileError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DeleteLoginProfileError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteLoginProfileError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DeleteLoginProfileError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteLoginProfileError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -13422,18 +13759,27 @@ impl DeleteOpenIDConnectProviderError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
-                            DeleteOpenIDConnectProviderError::InvalidInput(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteOpenIDConnectProviderError::InvalidInput,
                         )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
-                            DeleteOpenIDConnectProviderError::NoSuchEntity(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteOpenIDConnectProviderError::NoSuchEntity,
                         )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(
-                            DeleteOpenIDConnectProviderError::ServiceFailure(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteOpenIDConnectProviderError::ServiceFailure,
                         )
                     }
                     _ => {}
@@ -13486,29 +13832,44 @@ impl DeletePolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DeleteConflict" => {
-                        return RusotoError::Service(DeletePolicyError::DeleteConflict(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeletePolicyError::DeleteConflict,
+                        )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(DeletePolicyError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeletePolicyError::InvalidInput,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(DeletePolicyError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeletePolicyError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DeletePolicyError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeletePolicyError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DeletePolicyError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeletePolicyError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -13562,29 +13923,44 @@ impl DeletePolicyVersionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DeleteConflict" => {
-                        return RusotoError::Service(DeletePolicyVersionError::DeleteConflict(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeletePolicyVersionError::DeleteConflict,
+                        )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(DeletePolicyVersionError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeletePolicyVersionError::InvalidInput,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(DeletePolicyVersionError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeletePolicyVersionError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DeletePolicyVersionError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeletePolicyVersionError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DeletePolicyVersionError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeletePolicyVersionError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -13640,34 +14016,52 @@ impl DeleteRoleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(DeleteRoleError::ConcurrentModification(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteRoleError::ConcurrentModification,
+                        )
                     }
                     "DeleteConflict" => {
-                        return RusotoError::Service(DeleteRoleError::DeleteConflict(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteRoleError::DeleteConflict,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(DeleteRoleError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteRoleError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DeleteRoleError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteRoleError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DeleteRoleError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteRoleError::ServiceFailure,
+                        )
                     }
                     "UnmodifiableEntity" => {
-                        return RusotoError::Service(DeleteRoleError::UnmodifiableEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteRoleError::UnmodifiableEntity,
+                        )
                     }
                     _ => {}
                 }
@@ -13720,22 +14114,27 @@ impl DeleteRolePermissionsBoundaryError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
-                            DeleteRolePermissionsBoundaryError::NoSuchEntity(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteRolePermissionsBoundaryError::NoSuchEntity,
                         )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(
-                            DeleteRolePermissionsBoundaryError::ServiceFailure(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteRolePermissionsBoundaryError::ServiceFailure,
                         )
                     }
                     "UnmodifiableEntity" => {
-                        return RusotoError::Service(
-                            DeleteRolePermissionsBoundaryError::UnmodifiableEntity(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteRolePermissionsBoundaryError::UnmodifiableEntity,
                         )
                     }
                     _ => {}
@@ -13788,24 +14187,36 @@ impl DeleteRolePolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(DeleteRolePolicyError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteRolePolicyError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DeleteRolePolicyError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteRolePolicyError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DeleteRolePolicyError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteRolePolicyError::ServiceFailure,
+                        )
                     }
                     "UnmodifiableEntity" => {
-                        return RusotoError::Service(DeleteRolePolicyError::UnmodifiableEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteRolePolicyError::UnmodifiableEntity,
+                        )
                     }
                     _ => {}
                 }
@@ -13856,24 +14267,36 @@ impl DeleteSAMLProviderError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(DeleteSAMLProviderError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteSAMLProviderError::InvalidInput,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(DeleteSAMLProviderError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteSAMLProviderError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DeleteSAMLProviderError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteSAMLProviderError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DeleteSAMLProviderError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteSAMLProviderError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -13918,9 +14341,12 @@ impl DeleteSSHPublicKeyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DeleteSSHPublicKeyError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteSSHPublicKeyError::NoSuchEntity,
+                        )
                     }
                     _ => {}
                 }
@@ -13968,24 +14394,36 @@ impl DeleteServerCertificateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DeleteConflict" => {
-                        return RusotoError::Service(DeleteServerCertificateError::DeleteConflict(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteServerCertificateError::DeleteConflict,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(DeleteServerCertificateError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteServerCertificateError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DeleteServerCertificateError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteServerCertificateError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DeleteServerCertificateError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteServerCertificateError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -14034,19 +14472,28 @@ impl DeleteServiceLinkedRoleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(DeleteServiceLinkedRoleError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteServiceLinkedRoleError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DeleteServiceLinkedRoleError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteServiceLinkedRoleError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DeleteServiceLinkedRoleError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteServiceLinkedRoleError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -14092,10 +14539,11 @@ impl DeleteServiceSpecificCredentialError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
-                            DeleteServiceSpecificCredentialError::NoSuchEntity(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteServiceSpecificCredentialError::NoSuchEntity,
                         )
                     }
                     _ => {}
@@ -14142,19 +14590,28 @@ impl DeleteSigningCertificateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(DeleteSigningCertificateError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteSigningCertificateError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DeleteSigningCertificateError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteSigningCertificateError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DeleteSigningCertificateError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteSigningCertificateError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -14206,29 +14663,44 @@ impl DeleteUserError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(DeleteUserError::ConcurrentModification(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteUserError::ConcurrentModification,
+                        )
                     }
                     "DeleteConflict" => {
-                        return RusotoError::Service(DeleteUserError::DeleteConflict(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteUserError::DeleteConflict,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(DeleteUserError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteUserError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DeleteUserError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteUserError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DeleteUserError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteUserError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -14278,15 +14750,19 @@ impl DeleteUserPermissionsBoundaryError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
-                            DeleteUserPermissionsBoundaryError::NoSuchEntity(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteUserPermissionsBoundaryError::NoSuchEntity,
                         )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(
-                            DeleteUserPermissionsBoundaryError::ServiceFailure(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteUserPermissionsBoundaryError::ServiceFailure,
                         )
                     }
                     _ => {}
@@ -14334,19 +14810,28 @@ impl DeleteUserPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(DeleteUserPolicyError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteUserPolicyError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DeleteUserPolicyError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteUserPolicyError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DeleteUserPolicyError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteUserPolicyError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -14396,24 +14881,36 @@ impl DeleteVirtualMFADeviceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DeleteConflict" => {
-                        return RusotoError::Service(DeleteVirtualMFADeviceError::DeleteConflict(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteVirtualMFADeviceError::DeleteConflict,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(DeleteVirtualMFADeviceError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteVirtualMFADeviceError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DeleteVirtualMFADeviceError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteVirtualMFADeviceError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DeleteVirtualMFADeviceError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteVirtualMFADeviceError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -14464,24 +14961,36 @@ impl DetachGroupPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(DetachGroupPolicyError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DetachGroupPolicyError::InvalidInput,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(DetachGroupPolicyError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DetachGroupPolicyError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DetachGroupPolicyError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DetachGroupPolicyError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DetachGroupPolicyError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DetachGroupPolicyError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -14534,29 +15043,44 @@ impl DetachRolePolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(DetachRolePolicyError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DetachRolePolicyError::InvalidInput,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(DetachRolePolicyError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DetachRolePolicyError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DetachRolePolicyError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DetachRolePolicyError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DetachRolePolicyError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DetachRolePolicyError::ServiceFailure,
+                        )
                     }
                     "UnmodifiableEntity" => {
-                        return RusotoError::Service(DetachRolePolicyError::UnmodifiableEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DetachRolePolicyError::UnmodifiableEntity,
+                        )
                     }
                     _ => {}
                 }
@@ -14608,24 +15132,36 @@ impl DetachUserPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(DetachUserPolicyError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DetachUserPolicyError::InvalidInput,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(DetachUserPolicyError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DetachUserPolicyError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(DetachUserPolicyError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DetachUserPolicyError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(DetachUserPolicyError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DetachUserPolicyError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -14680,36 +15216,52 @@ impl EnableMFADeviceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EntityAlreadyExists" => {
-                        return RusotoError::Service(EnableMFADeviceError::EntityAlreadyExists(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            EnableMFADeviceError::EntityAlreadyExists,
+                        )
                     }
                     "EntityTemporarilyUnmodifiable" => {
-                        return RusotoError::Service(
-                            EnableMFADeviceError::EntityTemporarilyUnmodifiable(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            EnableMFADeviceError::EntityTemporarilyUnmodifiable,
                         )
                     }
                     "InvalidAuthenticationCode" => {
-                        return RusotoError::Service(
-                            EnableMFADeviceError::InvalidAuthenticationCode(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            EnableMFADeviceError::InvalidAuthenticationCode,
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(EnableMFADeviceError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            EnableMFADeviceError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(EnableMFADeviceError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            EnableMFADeviceError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(EnableMFADeviceError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            EnableMFADeviceError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -14760,14 +15312,20 @@ impl GenerateCredentialReportError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(GenerateCredentialReportError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GenerateCredentialReportError::LimitExceeded,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(GenerateCredentialReportError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GenerateCredentialReportError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -14812,10 +15370,11 @@ impl GenerateOrganizationsAccessReportError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ReportGenerationLimitExceeded" => {
-                        return RusotoError::Service(
-                            GenerateOrganizationsAccessReportError::ReportGenerationLimitExceeded(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GenerateOrganizationsAccessReportError::ReportGenerationLimitExceeded,
                         )
                     }
                     _ => {}
@@ -14864,17 +15423,19 @@ impl GenerateServiceLastAccessedDetailsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
-                            GenerateServiceLastAccessedDetailsError::InvalidInput(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GenerateServiceLastAccessedDetailsError::InvalidInput,
                         )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
-                            GenerateServiceLastAccessedDetailsError::NoSuchEntity(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GenerateServiceLastAccessedDetailsError::NoSuchEntity,
                         )
                     }
                     _ => {}
@@ -14958,10 +15519,11 @@ impl GetAccountAuthorizationDetailsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ServiceFailure" => {
-                        return RusotoError::Service(
-                            GetAccountAuthorizationDetailsError::ServiceFailure(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetAccountAuthorizationDetailsError::ServiceFailure,
                         )
                     }
                     _ => {}
@@ -15008,14 +15570,20 @@ impl GetAccountPasswordPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(GetAccountPasswordPolicyError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetAccountPasswordPolicyError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(GetAccountPasswordPolicyError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetAccountPasswordPolicyError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -15058,9 +15626,12 @@ impl GetAccountSummaryError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ServiceFailure" => {
-                        return RusotoError::Service(GetAccountSummaryError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetAccountSummaryError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -15104,8 +15675,11 @@ impl GetContextKeysForCustomPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
-                            GetContextKeysForCustomPolicyError::InvalidInput(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetContextKeysForCustomPolicyError::InvalidInput,
                         )
                     }
                     _ => {}
@@ -15152,17 +15726,19 @@ impl GetContextKeysForPrincipalPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
-                            GetContextKeysForPrincipalPolicyError::InvalidInput(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetContextKeysForPrincipalPolicyError::InvalidInput,
                         )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
-                            GetContextKeysForPrincipalPolicyError::NoSuchEntity(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetContextKeysForPrincipalPolicyError::NoSuchEntity,
                         )
                     }
                     _ => {}
@@ -15216,28 +15792,36 @@ impl GetCredentialReportError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ReportExpired" => {
-                        return RusotoError::Service(
-                            GetCredentialReportError::CredentialReportExpired(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetCredentialReportError::CredentialReportExpired,
                         )
                     }
                     "ReportNotPresent" => {
-                        return RusotoError::Service(
-                            GetCredentialReportError::CredentialReportNotPresent(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetCredentialReportError::CredentialReportNotPresent,
                         )
                     }
                     "ReportInProgress" => {
-                        return RusotoError::Service(
-                            GetCredentialReportError::CredentialReportNotReady(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetCredentialReportError::CredentialReportNotReady,
                         )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(GetCredentialReportError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetCredentialReportError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -15286,14 +15870,20 @@ impl GetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(GetGroupError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetGroupError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(GetGroupError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetGroupError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -15338,14 +15928,20 @@ impl GetGroupPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(GetGroupPolicyError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetGroupPolicyError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(GetGroupPolicyError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetGroupPolicyError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -15390,14 +15986,20 @@ impl GetInstanceProfileError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(GetInstanceProfileError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetInstanceProfileError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(GetInstanceProfileError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetInstanceProfileError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -15442,14 +16044,20 @@ impl GetLoginProfileError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(GetLoginProfileError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetLoginProfileError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(GetLoginProfileError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetLoginProfileError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -15496,19 +16104,28 @@ impl GetOpenIDConnectProviderError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(GetOpenIDConnectProviderError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetOpenIDConnectProviderError::InvalidInput,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(GetOpenIDConnectProviderError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetOpenIDConnectProviderError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(GetOpenIDConnectProviderError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetOpenIDConnectProviderError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -15554,8 +16171,11 @@ impl GetOrganizationsAccessReportError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
-                            GetOrganizationsAccessReportError::NoSuchEntity(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetOrganizationsAccessReportError::NoSuchEntity,
                         )
                     }
                     _ => {}
@@ -15602,19 +16222,28 @@ impl GetPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(GetPolicyError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetPolicyError::InvalidInput,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(GetPolicyError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetPolicyError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(GetPolicyError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetPolicyError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -15662,19 +16291,28 @@ impl GetPolicyVersionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(GetPolicyVersionError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetPolicyVersionError::InvalidInput,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(GetPolicyVersionError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetPolicyVersionError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(GetPolicyVersionError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetPolicyVersionError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -15720,14 +16358,20 @@ impl GetRoleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(GetRoleError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetRoleError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(GetRoleError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetRoleError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -15772,14 +16416,20 @@ impl GetRolePolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(GetRolePolicyError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetRolePolicyError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(GetRolePolicyError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetRolePolicyError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -15826,19 +16476,28 @@ impl GetSAMLProviderError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(GetSAMLProviderError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetSAMLProviderError::InvalidInput,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(GetSAMLProviderError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetSAMLProviderError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(GetSAMLProviderError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetSAMLProviderError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -15884,15 +16543,19 @@ impl GetSSHPublicKeyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(GetSSHPublicKeyError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetSSHPublicKeyError::NoSuchEntity,
+                        )
                     }
                     "UnrecognizedPublicKeyEncoding" => {
-                        return RusotoError::Service(
-                            GetSSHPublicKeyError::UnrecognizedPublicKeyEncoding(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetSSHPublicKeyError::UnrecognizedPublicKeyEncoding,
                         )
                     }
                     _ => {}
@@ -15940,14 +16603,20 @@ impl GetServerCertificateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(GetServerCertificateError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetServerCertificateError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(GetServerCertificateError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetServerCertificateError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -15994,13 +16663,19 @@ impl GetServiceLastAccessedDetailsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
-                            GetServiceLastAccessedDetailsError::InvalidInput(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetServiceLastAccessedDetailsError::InvalidInput,
                         )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
-                            GetServiceLastAccessedDetailsError::NoSuchEntity(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetServiceLastAccessedDetailsError::NoSuchEntity,
                         )
                     }
                     _ => {}
@@ -16048,17 +16723,19 @@ impl GetServiceLastAccessedDetailsWithEntitiesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
-                            GetServiceLastAccessedDetailsWithEntitiesError::InvalidInput(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetServiceLastAccessedDetailsWithEntitiesError::InvalidInput,
                         )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
-                            GetServiceLastAccessedDetailsWithEntitiesError::NoSuchEntity(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetServiceLastAccessedDetailsWithEntitiesError::NoSuchEntity,
                         )
                     }
                     _ => {}
@@ -16112,24 +16789,27 @@ impl GetServiceLinkedRoleDeletionStatusError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
-                            GetServiceLinkedRoleDeletionStatusError::InvalidInput(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetServiceLinkedRoleDeletionStatusError::InvalidInput,
                         )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
-                            GetServiceLinkedRoleDeletionStatusError::NoSuchEntity(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetServiceLinkedRoleDeletionStatusError::NoSuchEntity,
                         )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(
-                            GetServiceLinkedRoleDeletionStatusError::ServiceFailure(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetServiceLinkedRoleDeletionStatusError::ServiceFailure,
                         )
                     }
                     _ => {}
@@ -16182,14 +16862,20 @@ impl GetUserError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(GetUserError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetUserError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(GetUserError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetUserError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -16234,14 +16920,20 @@ impl GetUserPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(GetUserPolicyError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetUserPolicyError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(GetUserPolicyError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetUserPolicyError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -16286,14 +16978,20 @@ impl ListAccessKeysError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(ListAccessKeysError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListAccessKeysError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListAccessKeysError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListAccessKeysError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -16336,9 +17034,12 @@ impl ListAccountAliasesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListAccountAliasesError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListAccountAliasesError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -16384,18 +17085,27 @@ impl ListAttachedGroupPoliciesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ListAttachedGroupPoliciesError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListAttachedGroupPoliciesError::InvalidInput,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(ListAttachedGroupPoliciesError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListAttachedGroupPoliciesError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(
-                            ListAttachedGroupPoliciesError::ServiceFailure(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListAttachedGroupPoliciesError::ServiceFailure,
                         )
                     }
                     _ => {}
@@ -16444,19 +17154,28 @@ impl ListAttachedRolePoliciesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ListAttachedRolePoliciesError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListAttachedRolePoliciesError::InvalidInput,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(ListAttachedRolePoliciesError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListAttachedRolePoliciesError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListAttachedRolePoliciesError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListAttachedRolePoliciesError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -16504,19 +17223,28 @@ impl ListAttachedUserPoliciesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ListAttachedUserPoliciesError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListAttachedUserPoliciesError::InvalidInput,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(ListAttachedUserPoliciesError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListAttachedUserPoliciesError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListAttachedUserPoliciesError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListAttachedUserPoliciesError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -16564,19 +17292,28 @@ impl ListEntitiesForPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ListEntitiesForPolicyError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListEntitiesForPolicyError::InvalidInput,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(ListEntitiesForPolicyError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListEntitiesForPolicyError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListEntitiesForPolicyError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListEntitiesForPolicyError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -16622,14 +17359,20 @@ impl ListGroupPoliciesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(ListGroupPoliciesError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListGroupPoliciesError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListGroupPoliciesError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListGroupPoliciesError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -16672,9 +17415,12 @@ impl ListGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListGroupsError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListGroupsError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -16718,14 +17464,20 @@ impl ListGroupsForUserError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(ListGroupsForUserError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListGroupsForUserError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListGroupsForUserError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListGroupsForUserError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -16768,9 +17520,12 @@ impl ListInstanceProfilesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListInstanceProfilesError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListInstanceProfilesError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -16816,13 +17571,19 @@ impl ListInstanceProfilesForRoleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
-                            ListInstanceProfilesForRoleError::NoSuchEntity(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListInstanceProfilesForRoleError::NoSuchEntity,
                         )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(
-                            ListInstanceProfilesForRoleError::ServiceFailure(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListInstanceProfilesForRoleError::ServiceFailure,
                         )
                     }
                     _ => {}
@@ -16868,14 +17629,20 @@ impl ListMFADevicesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(ListMFADevicesError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListMFADevicesError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListMFADevicesError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListMFADevicesError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -16920,8 +17687,11 @@ impl ListOpenIDConnectProvidersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ServiceFailure" => {
-                        return RusotoError::Service(
-                            ListOpenIDConnectProvidersError::ServiceFailure(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListOpenIDConnectProvidersError::ServiceFailure,
                         )
                     }
                     _ => {}
@@ -16964,9 +17734,12 @@ impl ListPoliciesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListPoliciesError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListPoliciesError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -17012,17 +17785,19 @@ impl ListPoliciesGrantingServiceAccessError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
-                            ListPoliciesGrantingServiceAccessError::InvalidInput(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListPoliciesGrantingServiceAccessError::InvalidInput,
                         )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
-                            ListPoliciesGrantingServiceAccessError::NoSuchEntity(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListPoliciesGrantingServiceAccessError::NoSuchEntity,
                         )
                     }
                     _ => {}
@@ -17074,19 +17849,28 @@ impl ListPolicyVersionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ListPolicyVersionsError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListPolicyVersionsError::InvalidInput,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(ListPolicyVersionsError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListPolicyVersionsError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListPolicyVersionsError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListPolicyVersionsError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -17132,14 +17916,20 @@ impl ListRolePoliciesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(ListRolePoliciesError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListRolePoliciesError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListRolePoliciesError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListRolePoliciesError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -17184,14 +17974,20 @@ impl ListRoleTagsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(ListRoleTagsError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListRoleTagsError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListRoleTagsError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListRoleTagsError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -17234,9 +18030,12 @@ impl ListRolesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListRolesError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListRolesError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -17278,9 +18077,12 @@ impl ListSAMLProvidersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListSAMLProvidersError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListSAMLProvidersError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -17322,9 +18124,12 @@ impl ListSSHPublicKeysError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(ListSSHPublicKeysError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListSSHPublicKeysError::NoSuchEntity,
+                        )
                     }
                     _ => {}
                 }
@@ -17366,9 +18171,12 @@ impl ListServerCertificatesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListServerCertificatesError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListServerCertificatesError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -17414,15 +18222,19 @@ impl ListServiceSpecificCredentialsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
-                            ListServiceSpecificCredentialsError::NoSuchEntity(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListServiceSpecificCredentialsError::NoSuchEntity,
                         )
                     }
                     "NotSupportedService" => {
-                        return RusotoError::Service(
-                            ListServiceSpecificCredentialsError::ServiceNotSupported(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListServiceSpecificCredentialsError::ServiceNotSupported,
                         )
                     }
                     _ => {}
@@ -17470,14 +18282,20 @@ impl ListSigningCertificatesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(ListSigningCertificatesError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListSigningCertificatesError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListSigningCertificatesError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListSigningCertificatesError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -17522,14 +18340,20 @@ impl ListUserPoliciesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(ListUserPoliciesError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListUserPoliciesError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListUserPoliciesError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListUserPoliciesError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -17574,14 +18398,20 @@ impl ListUserTagsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(ListUserTagsError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListUserTagsError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListUserTagsError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListUserTagsError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -17624,9 +18454,12 @@ impl ListUsersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ServiceFailure" => {
-                        return RusotoError::Service(ListUsersError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListUsersError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -17708,24 +18541,36 @@ impl PutGroupPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(PutGroupPolicyError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PutGroupPolicyError::LimitExceeded,
+                        )
                     }
                     "MalformedPolicyDocument" => {
-                        return RusotoError::Service(PutGroupPolicyError::MalformedPolicyDocument(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PutGroupPolicyError::MalformedPolicyDocument,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(PutGroupPolicyError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PutGroupPolicyError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(PutGroupPolicyError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PutGroupPolicyError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -17780,32 +18625,43 @@ impl PutRolePermissionsBoundaryError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(PutRolePermissionsBoundaryError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PutRolePermissionsBoundaryError::InvalidInput,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(PutRolePermissionsBoundaryError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PutRolePermissionsBoundaryError::NoSuchEntity,
+                        )
                     }
                     "PolicyNotAttachable" => {
-                        return RusotoError::Service(
-                            PutRolePermissionsBoundaryError::PolicyNotAttachable(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            PutRolePermissionsBoundaryError::PolicyNotAttachable,
                         )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(
-                            PutRolePermissionsBoundaryError::ServiceFailure(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            PutRolePermissionsBoundaryError::ServiceFailure,
                         )
                     }
                     "UnmodifiableEntity" => {
-                        return RusotoError::Service(
-                            PutRolePermissionsBoundaryError::UnmodifiableEntity(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            PutRolePermissionsBoundaryError::UnmodifiableEntity,
                         )
                     }
                     _ => {}
@@ -17864,29 +18720,44 @@ impl PutRolePolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(PutRolePolicyError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PutRolePolicyError::LimitExceeded,
+                        )
                     }
                     "MalformedPolicyDocument" => {
-                        return RusotoError::Service(PutRolePolicyError::MalformedPolicyDocument(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PutRolePolicyError::MalformedPolicyDocument,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(PutRolePolicyError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PutRolePolicyError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(PutRolePolicyError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PutRolePolicyError::ServiceFailure,
+                        )
                     }
                     "UnmodifiableEntity" => {
-                        return RusotoError::Service(PutRolePolicyError::UnmodifiableEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PutRolePolicyError::UnmodifiableEntity,
+                        )
                     }
                     _ => {}
                 }
@@ -17940,25 +18811,35 @@ impl PutUserPermissionsBoundaryError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(PutUserPermissionsBoundaryError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PutUserPermissionsBoundaryError::InvalidInput,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(PutUserPermissionsBoundaryError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PutUserPermissionsBoundaryError::NoSuchEntity,
+                        )
                     }
                     "PolicyNotAttachable" => {
-                        return RusotoError::Service(
-                            PutUserPermissionsBoundaryError::PolicyNotAttachable(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            PutUserPermissionsBoundaryError::PolicyNotAttachable,
                         )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(
-                            PutUserPermissionsBoundaryError::ServiceFailure(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            PutUserPermissionsBoundaryError::ServiceFailure,
                         )
                     }
                     _ => {}
@@ -18012,24 +18893,36 @@ impl PutUserPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(PutUserPolicyError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PutUserPolicyError::LimitExceeded,
+                        )
                     }
                     "MalformedPolicyDocument" => {
-                        return RusotoError::Service(PutUserPolicyError::MalformedPolicyDocument(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PutUserPolicyError::MalformedPolicyDocument,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(PutUserPolicyError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PutUserPolicyError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(PutUserPolicyError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PutUserPolicyError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -18080,24 +18973,27 @@ impl RemoveClientIDFromOpenIDConnectProviderError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
-                            RemoveClientIDFromOpenIDConnectProviderError::InvalidInput(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RemoveClientIDFromOpenIDConnectProviderError::InvalidInput,
                         )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
-                            RemoveClientIDFromOpenIDConnectProviderError::NoSuchEntity(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RemoveClientIDFromOpenIDConnectProviderError::NoSuchEntity,
                         )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(
-                            RemoveClientIDFromOpenIDConnectProviderError::ServiceFailure(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RemoveClientIDFromOpenIDConnectProviderError::ServiceFailure,
                         )
                     }
                     _ => {}
@@ -18156,27 +19052,35 @@ impl RemoveRoleFromInstanceProfileError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(
-                            RemoveRoleFromInstanceProfileError::LimitExceeded(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RemoveRoleFromInstanceProfileError::LimitExceeded,
                         )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
-                            RemoveRoleFromInstanceProfileError::NoSuchEntity(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RemoveRoleFromInstanceProfileError::NoSuchEntity,
                         )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(
-                            RemoveRoleFromInstanceProfileError::ServiceFailure(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RemoveRoleFromInstanceProfileError::ServiceFailure,
                         )
                     }
                     "UnmodifiableEntity" => {
-                        return RusotoError::Service(
-                            RemoveRoleFromInstanceProfileError::UnmodifiableEntity(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RemoveRoleFromInstanceProfileError::UnmodifiableEntity,
                         )
                     }
                     _ => {}
@@ -18228,19 +19132,28 @@ impl RemoveUserFromGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(RemoveUserFromGroupError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            RemoveUserFromGroupError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(RemoveUserFromGroupError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            RemoveUserFromGroupError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(RemoveUserFromGroupError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            RemoveUserFromGroupError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -18286,8 +19199,11 @@ impl ResetServiceSpecificCredentialError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
-                            ResetServiceSpecificCredentialError::NoSuchEntity(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ResetServiceSpecificCredentialError::NoSuchEntity,
                         )
                     }
                     _ => {}
@@ -18336,24 +19252,36 @@ impl ResyncMFADeviceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidAuthenticationCode" => {
-                        return RusotoError::Service(
-                            ResyncMFADeviceError::InvalidAuthenticationCode(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ResyncMFADeviceError::InvalidAuthenticationCode,
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(ResyncMFADeviceError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ResyncMFADeviceError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(ResyncMFADeviceError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ResyncMFADeviceError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(ResyncMFADeviceError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ResyncMFADeviceError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -18404,24 +19332,36 @@ impl SetDefaultPolicyVersionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(SetDefaultPolicyVersionError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SetDefaultPolicyVersionError::InvalidInput,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(SetDefaultPolicyVersionError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SetDefaultPolicyVersionError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(SetDefaultPolicyVersionError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SetDefaultPolicyVersionError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(SetDefaultPolicyVersionError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SetDefaultPolicyVersionError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -18468,10 +19408,11 @@ impl SetSecurityTokenServicePreferencesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ServiceFailure" => {
-                        return RusotoError::Service(
-                            SetSecurityTokenServicePreferencesError::ServiceFailure(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SetSecurityTokenServicePreferencesError::ServiceFailure,
                         )
                     }
                     _ => {}
@@ -18518,14 +19459,20 @@ impl SimulateCustomPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(SimulateCustomPolicyError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SimulateCustomPolicyError::InvalidInput,
+                        )
                     }
                     "PolicyEvaluation" => {
-                        return RusotoError::Service(SimulateCustomPolicyError::PolicyEvaluation(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SimulateCustomPolicyError::PolicyEvaluation,
+                        )
                     }
                     _ => {}
                 }
@@ -18572,18 +19519,27 @@ impl SimulatePrincipalPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(SimulatePrincipalPolicyError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SimulatePrincipalPolicyError::InvalidInput,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(SimulatePrincipalPolicyError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            SimulatePrincipalPolicyError::NoSuchEntity,
+                        )
                     }
                     "PolicyEvaluation" => {
-                        return RusotoError::Service(
-                            SimulatePrincipalPolicyError::PolicyEvaluation(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            SimulatePrincipalPolicyError::PolicyEvaluation,
                         )
                     }
                     _ => {}
@@ -18636,29 +19592,44 @@ impl TagRoleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(TagRoleError::ConcurrentModification(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            TagRoleError::ConcurrentModification,
+                        )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(TagRoleError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            TagRoleError::InvalidInput,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(TagRoleError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            TagRoleError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(TagRoleError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            TagRoleError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(TagRoleError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            TagRoleError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -18712,29 +19683,44 @@ impl TagUserError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(TagUserError::ConcurrentModification(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            TagUserError::ConcurrentModification,
+                        )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(TagUserError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            TagUserError::InvalidInput,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(TagUserError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            TagUserError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(TagUserError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            TagUserError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(TagUserError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            TagUserError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -18784,19 +19770,28 @@ impl UntagRoleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(UntagRoleError::ConcurrentModification(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UntagRoleError::ConcurrentModification,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(UntagRoleError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UntagRoleError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(UntagRoleError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UntagRoleError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -18844,19 +19839,28 @@ impl UntagUserError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(UntagUserError::ConcurrentModification(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UntagUserError::ConcurrentModification,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(UntagUserError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UntagUserError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(UntagUserError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UntagUserError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -18904,19 +19908,28 @@ impl UpdateAccessKeyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(UpdateAccessKeyError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateAccessKeyError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(UpdateAccessKeyError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateAccessKeyError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(UpdateAccessKeyError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateAccessKeyError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -18968,25 +19981,35 @@ impl UpdateAccountPasswordPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(
-                            UpdateAccountPasswordPolicyError::LimitExceeded(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateAccountPasswordPolicyError::LimitExceeded,
                         )
                     }
                     "MalformedPolicyDocument" => {
-                        return RusotoError::Service(
-                            UpdateAccountPasswordPolicyError::MalformedPolicyDocument(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateAccountPasswordPolicyError::MalformedPolicyDocument,
                         )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
-                            UpdateAccountPasswordPolicyError::NoSuchEntity(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateAccountPasswordPolicyError::NoSuchEntity,
                         )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(
-                            UpdateAccountPasswordPolicyError::ServiceFailure(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateAccountPasswordPolicyError::ServiceFailure,
                         )
                     }
                     _ => {}
@@ -19042,30 +20065,43 @@ impl UpdateAssumeRolePolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(UpdateAssumeRolePolicyError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateAssumeRolePolicyError::LimitExceeded,
+                        )
                     }
                     "MalformedPolicyDocument" => {
-                        return RusotoError::Service(
-                            UpdateAssumeRolePolicyError::MalformedPolicyDocument(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateAssumeRolePolicyError::MalformedPolicyDocument,
                         )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(UpdateAssumeRolePolicyError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateAssumeRolePolicyError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(UpdateAssumeRolePolicyError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateAssumeRolePolicyError::ServiceFailure,
+                        )
                     }
                     "UnmodifiableEntity" => {
-                        return RusotoError::Service(
-                            UpdateAssumeRolePolicyError::UnmodifiableEntity(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateAssumeRolePolicyError::UnmodifiableEntity,
                         )
                     }
                     _ => {}
@@ -19120,24 +20156,36 @@ impl UpdateGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EntityAlreadyExists" => {
-                        return RusotoError::Service(UpdateGroupError::EntityAlreadyExists(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateGroupError::EntityAlreadyExists,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(UpdateGroupError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateGroupError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(UpdateGroupError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateGroupError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(UpdateGroupError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateGroupError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -19190,31 +20238,44 @@ impl UpdateLoginProfileError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EntityTemporarilyUnmodifiable" => {
-                        return RusotoError::Service(
-                            UpdateLoginProfileError::EntityTemporarilyUnmodifiable(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateLoginProfileError::EntityTemporarilyUnmodifiable,
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(UpdateLoginProfileError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateLoginProfileError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(UpdateLoginProfileError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateLoginProfileError::NoSuchEntity,
+                        )
                     }
                     "PasswordPolicyViolation" => {
-                        return RusotoError::Service(
-                            UpdateLoginProfileError::PasswordPolicyViolation(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateLoginProfileError::PasswordPolicyViolation,
                         )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(UpdateLoginProfileError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateLoginProfileError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -19268,24 +20329,27 @@ impl UpdateOpenIDConnectProviderThumbprintError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
-                            UpdateOpenIDConnectProviderThumbprintError::InvalidInput(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateOpenIDConnectProviderThumbprintError::InvalidInput,
                         )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
-                            UpdateOpenIDConnectProviderThumbprintError::NoSuchEntity(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateOpenIDConnectProviderThumbprintError::NoSuchEntity,
                         )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(
-                            UpdateOpenIDConnectProviderThumbprintError::ServiceFailure(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateOpenIDConnectProviderThumbprintError::ServiceFailure,
                         )
                     }
                     _ => {}
@@ -19340,19 +20404,28 @@ impl UpdateRoleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(UpdateRoleError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateRoleError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(UpdateRoleError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateRoleError::ServiceFailure,
+                        )
                     }
                     "UnmodifiableEntity" => {
-                        return RusotoError::Service(UpdateRoleError::UnmodifiableEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateRoleError::UnmodifiableEntity,
+                        )
                     }
                     _ => {}
                 }
@@ -19400,18 +20473,27 @@ impl UpdateRoleDescriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(UpdateRoleDescriptionError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateRoleDescriptionError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(UpdateRoleDescriptionError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateRoleDescriptionError::ServiceFailure,
+                        )
                     }
                     "UnmodifiableEntity" => {
-                        return RusotoError::Service(
-                            UpdateRoleDescriptionError::UnmodifiableEntity(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateRoleDescriptionError::UnmodifiableEntity,
                         )
                     }
                     _ => {}
@@ -19462,24 +20544,36 @@ impl UpdateSAMLProviderError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(UpdateSAMLProviderError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateSAMLProviderError::InvalidInput,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(UpdateSAMLProviderError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateSAMLProviderError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(UpdateSAMLProviderError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateSAMLProviderError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(UpdateSAMLProviderError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateSAMLProviderError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -19524,9 +20618,12 @@ impl UpdateSSHPublicKeyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(UpdateSSHPublicKeyError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateSSHPublicKeyError::NoSuchEntity,
+                        )
                     }
                     _ => {}
                 }
@@ -19574,24 +20671,36 @@ impl UpdateServerCertificateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EntityAlreadyExists" => {
-                        return RusotoError::Service(
-                            UpdateServerCertificateError::EntityAlreadyExists(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateServerCertificateError::EntityAlreadyExists,
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(UpdateServerCertificateError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateServerCertificateError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(UpdateServerCertificateError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateServerCertificateError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(UpdateServerCertificateError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateServerCertificateError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -19638,10 +20747,11 @@ impl UpdateServiceSpecificCredentialError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchEntity" => {
-                        return RusotoError::Service(
-                            UpdateServiceSpecificCredentialError::NoSuchEntity(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateServiceSpecificCredentialError::NoSuchEntity,
                         )
                     }
                     _ => {}
@@ -19688,19 +20798,28 @@ impl UpdateSigningCertificateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "LimitExceeded" => {
-                        return RusotoError::Service(UpdateSigningCertificateError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateSigningCertificateError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(UpdateSigningCertificateError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateSigningCertificateError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(UpdateSigningCertificateError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateSigningCertificateError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -19754,34 +20873,52 @@ impl UpdateUserError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(UpdateUserError::ConcurrentModification(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateUserError::ConcurrentModification,
+                        )
                     }
                     "EntityAlreadyExists" => {
-                        return RusotoError::Service(UpdateUserError::EntityAlreadyExists(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateUserError::EntityAlreadyExists,
+                        )
                     }
                     "EntityTemporarilyUnmodifiable" => {
-                        return RusotoError::Service(
-                            UpdateUserError::EntityTemporarilyUnmodifiable(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateUserError::EntityTemporarilyUnmodifiable,
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(UpdateUserError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateUserError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(UpdateUserError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateUserError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(UpdateUserError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateUserError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -19836,30 +20973,43 @@ impl UploadSSHPublicKeyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DuplicateSSHPublicKey" => {
-                        return RusotoError::Service(
-                            UploadSSHPublicKeyError::DuplicateSSHPublicKey(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UploadSSHPublicKeyError::DuplicateSSHPublicKey,
                         )
                     }
                     "InvalidPublicKey" => {
-                        return RusotoError::Service(UploadSSHPublicKeyError::InvalidPublicKey(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UploadSSHPublicKeyError::InvalidPublicKey,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(UploadSSHPublicKeyError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UploadSSHPublicKeyError::LimitExceeded,
+                        )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(UploadSSHPublicKeyError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UploadSSHPublicKeyError::NoSuchEntity,
+                        )
                     }
                     "UnrecognizedPublicKeyEncoding" => {
-                        return RusotoError::Service(
-                            UploadSSHPublicKeyError::UnrecognizedPublicKeyEncoding(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UploadSSHPublicKeyError::UnrecognizedPublicKeyEncoding,
                         )
                     }
                     _ => {}
@@ -19916,31 +21066,44 @@ impl UploadServerCertificateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EntityAlreadyExists" => {
-                        return RusotoError::Service(
-                            UploadServerCertificateError::EntityAlreadyExists(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UploadServerCertificateError::EntityAlreadyExists,
                         )
                     }
                     "KeyPairMismatch" => {
-                        return RusotoError::Service(UploadServerCertificateError::KeyPairMismatch(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UploadServerCertificateError::KeyPairMismatch,
+                        )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(UploadServerCertificateError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UploadServerCertificateError::LimitExceeded,
+                        )
                     }
                     "MalformedCertificate" => {
-                        return RusotoError::Service(
-                            UploadServerCertificateError::MalformedCertificate(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UploadServerCertificateError::MalformedCertificate,
                         )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(UploadServerCertificateError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UploadServerCertificateError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -19998,45 +21161,60 @@ impl UploadSigningCertificateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DuplicateCertificate" => {
-                        return RusotoError::Service(
-                            UploadSigningCertificateError::DuplicateCertificate(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UploadSigningCertificateError::DuplicateCertificate,
                         )
                     }
                     "EntityAlreadyExists" => {
-                        return RusotoError::Service(
-                            UploadSigningCertificateError::EntityAlreadyExists(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UploadSigningCertificateError::EntityAlreadyExists,
                         )
                     }
                     "InvalidCertificate" => {
-                        return RusotoError::Service(
-                            UploadSigningCertificateError::InvalidCertificate(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UploadSigningCertificateError::InvalidCertificate,
                         )
                     }
                     "LimitExceeded" => {
-                        return RusotoError::Service(UploadSigningCertificateError::LimitExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UploadSigningCertificateError::LimitExceeded,
+                        )
                     }
                     "MalformedCertificate" => {
-                        return RusotoError::Service(
-                            UploadSigningCertificateError::MalformedCertificate(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UploadSigningCertificateError::MalformedCertificate,
                         )
                     }
                     "NoSuchEntity" => {
-                        return RusotoError::Service(UploadSigningCertificateError::NoSuchEntity(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UploadSigningCertificateError::NoSuchEntity,
+                        )
                     }
                     "ServiceFailure" => {
-                        return RusotoError::Service(UploadSigningCertificateError::ServiceFailure(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UploadSigningCertificateError::ServiceFailure,
+                        )
                     }
                     _ => {}
                 }
@@ -20938,11 +22116,12 @@ pub struct IamClient {
 impl IamClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> IamClient {
         IamClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "IAM"),
         }
     }
 
@@ -20957,12 +22136,32 @@ impl IamClient {
     {
         IamClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "IAM"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> IamClient {
-        IamClient { client, region }
+        IamClient {
+            client,
+            region: region_for_service(region, "IAM"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> IamClient {
+        IamClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("IAM"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> IamClient {
+        IamClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -20974,6 +22173,7 @@ impl Iam for IamClient {
         input: AddClientIDToOpenIDConnectProviderRequest,
     ) -> Result<(), RusotoError<AddClientIDToOpenIDConnectProviderError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("AddClientIDToOpenIDConnectProvider");
         let params = self.new_params("AddClientIDToOpenIDConnectProvider");
         let mut params = params;
         AddClientIDToOpenIDConnectProviderRequestSerializer::serialize(&mut params, "", &input);
@@ -20997,6 +22197,7 @@ impl Iam for IamClient {
         input: AddRoleToInstanceProfileRequest,
     ) -> Result<(), RusotoError<AddRoleToInstanceProfileError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("AddRoleToInstanceProfile");
         let params = self.new_params("AddRoleToInstanceProfile");
         let mut params = params;
         AddRoleToInstanceProfileRequestSerializer::serialize(&mut params, "", &input);
@@ -21017,6 +22218,7 @@ impl Iam for IamClient {
         input: AddUserToGroupRequest,
     ) -> Result<(), RusotoError<AddUserToGroupError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("AddUserToGroup");
         let params = self.new_params("AddUserToGroup");
         let mut params = params;
         AddUserToGroupRequestSerializer::serialize(&mut params, "", &input);
@@ -21037,6 +22239,7 @@ impl Iam for IamClient {
         input: AttachGroupPolicyRequest,
     ) -> Result<(), RusotoError<AttachGroupPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("AttachGroupPolicy");
         let params = self.new_params("AttachGroupPolicy");
         let mut params = params;
         AttachGroupPolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -21057,6 +22260,7 @@ impl Iam for IamClient {
         input: AttachRolePolicyRequest,
     ) -> Result<(), RusotoError<AttachRolePolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("AttachRolePolicy");
         let params = self.new_params("AttachRolePolicy");
         let mut params = params;
         AttachRolePolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -21077,6 +22281,7 @@ impl Iam for IamClient {
         input: AttachUserPolicyRequest,
     ) -> Result<(), RusotoError<AttachUserPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("AttachUserPolicy");
         let params = self.new_params("AttachUserPolicy");
         let mut params = params;
         AttachUserPolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -21097,6 +22302,7 @@ impl Iam for IamClient {
         input: ChangePasswordRequest,
     ) -> Result<(), RusotoError<ChangePasswordError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ChangePassword");
         let params = self.new_params("ChangePassword");
         let mut params = params;
         ChangePasswordRequestSerializer::serialize(&mut params, "", &input);
@@ -21117,6 +22323,7 @@ impl Iam for IamClient {
         input: CreateAccessKeyRequest,
     ) -> Result<CreateAccessKeyResponse, RusotoError<CreateAccessKeyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("CreateAccessKey");
         let params = self.new_params("CreateAccessKey");
         let mut params = params;
         CreateAccessKeyRequestSerializer::serialize(&mut params, "", &input);
@@ -21148,6 +22355,7 @@ impl Iam for IamClient {
         input: CreateAccountAliasRequest,
     ) -> Result<(), RusotoError<CreateAccountAliasError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("CreateAccountAlias");
         let params = self.new_params("CreateAccountAlias");
         let mut params = params;
         CreateAccountAliasRequestSerializer::serialize(&mut params, "", &input);
@@ -21168,6 +22376,7 @@ impl Iam for IamClient {
         input: CreateGroupRequest,
     ) -> Result<CreateGroupResponse, RusotoError<CreateGroupError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("CreateGroup");
         let params = self.new_params("CreateGroup");
         let mut params = params;
         CreateGroupRequestSerializer::serialize(&mut params, "", &input);
@@ -21198,6 +22407,7 @@ impl Iam for IamClient {
         input: CreateInstanceProfileRequest,
     ) -> Result<CreateInstanceProfileResponse, RusotoError<CreateInstanceProfileError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("CreateInstanceProfile");
         let params = self.new_params("CreateInstanceProfile");
         let mut params = params;
         CreateInstanceProfileRequestSerializer::serialize(&mut params, "", &input);
@@ -21231,6 +22441,7 @@ impl Iam for IamClient {
         input: CreateLoginProfileRequest,
     ) -> Result<CreateLoginProfileResponse, RusotoError<CreateLoginProfileError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("CreateLoginProfile");
         let params = self.new_params("CreateLoginProfile");
         let mut params = params;
         CreateLoginProfileRequestSerializer::serialize(&mut params, "", &input);
@@ -21265,6 +22476,7 @@ impl Iam for IamClient {
     ) -> Result<CreateOpenIDConnectProviderResponse, RusotoError<CreateOpenIDConnectProviderError>>
     {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("CreateOpenIDConnectProvider");
         let params = self.new_params("CreateOpenIDConnectProvider");
         let mut params = params;
         CreateOpenIDConnectProviderRequestSerializer::serialize(&mut params, "", &input);
@@ -21298,6 +22510,7 @@ impl Iam for IamClient {
         input: CreatePolicyRequest,
     ) -> Result<CreatePolicyResponse, RusotoError<CreatePolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("CreatePolicy");
         let params = self.new_params("CreatePolicy");
         let mut params = params;
         CreatePolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -21329,6 +22542,7 @@ impl Iam for IamClient {
         input: CreatePolicyVersionRequest,
     ) -> Result<CreatePolicyVersionResponse, RusotoError<CreatePolicyVersionError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("CreatePolicyVersion");
         let params = self.new_params("CreatePolicyVersion");
         let mut params = params;
         CreatePolicyVersionRequestSerializer::serialize(&mut params, "", &input);
@@ -21362,6 +22576,7 @@ impl Iam for IamClient {
         input: CreateRoleRequest,
     ) -> Result<CreateRoleResponse, RusotoError<CreateRoleError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("CreateRole");
         let params = self.new_params("CreateRole");
         let mut params = params;
         CreateRoleRequestSerializer::serialize(&mut params, "", &input);
@@ -21392,6 +22607,7 @@ impl Iam for IamClient {
         input: CreateSAMLProviderRequest,
     ) -> Result<CreateSAMLProviderResponse, RusotoError<CreateSAMLProviderError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("CreateSAMLProvider");
         let params = self.new_params("CreateSAMLProvider");
         let mut params = params;
         CreateSAMLProviderRequestSerializer::serialize(&mut params, "", &input);
@@ -21425,6 +22641,7 @@ impl Iam for IamClient {
         input: CreateServiceLinkedRoleRequest,
     ) -> Result<CreateServiceLinkedRoleResponse, RusotoError<CreateServiceLinkedRoleError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("CreateServiceLinkedRole");
         let params = self.new_params("CreateServiceLinkedRole");
         let mut params = params;
         CreateServiceLinkedRoleRequestSerializer::serialize(&mut params, "", &input);
@@ -21461,6 +22678,7 @@ impl Iam for IamClient {
         RusotoError<CreateServiceSpecificCredentialError>,
     > {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("CreateServiceSpecificCredential");
         let params = self.new_params("CreateServiceSpecificCredential");
         let mut params = params;
         CreateServiceSpecificCredentialRequestSerializer::serialize(&mut params, "", &input);
@@ -21494,6 +22712,7 @@ impl Iam for IamClient {
         input: CreateUserRequest,
     ) -> Result<CreateUserResponse, RusotoError<CreateUserError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("CreateUser");
         let params = self.new_params("CreateUser");
         let mut params = params;
         CreateUserRequestSerializer::serialize(&mut params, "", &input);
@@ -21524,6 +22743,7 @@ impl Iam for IamClient {
         input: CreateVirtualMFADeviceRequest,
     ) -> Result<CreateVirtualMFADeviceResponse, RusotoError<CreateVirtualMFADeviceError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("CreateVirtualMFADevice");
         let params = self.new_params("CreateVirtualMFADevice");
         let mut params = params;
         CreateVirtualMFADeviceRequestSerializer::serialize(&mut params, "", &input);
@@ -21557,6 +22777,7 @@ impl Iam for IamClient {
         input: DeactivateMFADeviceRequest,
     ) -> Result<(), RusotoError<DeactivateMFADeviceError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeactivateMFADevice");
         let params = self.new_params("DeactivateMFADevice");
         let mut params = params;
         DeactivateMFADeviceRequestSerializer::serialize(&mut params, "", &input);
@@ -21577,6 +22798,7 @@ impl Iam for IamClient {
         input: DeleteAccessKeyRequest,
     ) -> Result<(), RusotoError<DeleteAccessKeyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteAccessKey");
         let params = self.new_params("DeleteAccessKey");
         let mut params = params;
         DeleteAccessKeyRequestSerializer::serialize(&mut params, "", &input);
@@ -21597,6 +22819,7 @@ impl Iam for IamClient {
         input: DeleteAccountAliasRequest,
     ) -> Result<(), RusotoError<DeleteAccountAliasError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteAccountAlias");
         let params = self.new_params("DeleteAccountAlias");
         let mut params = params;
         DeleteAccountAliasRequestSerializer::serialize(&mut params, "", &input);
@@ -21616,6 +22839,7 @@ impl Iam for IamClient {
         &self,
     ) -> Result<(), RusotoError<DeleteAccountPasswordPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteAccountPasswordPolicy");
         let params = self.new_params("DeleteAccountPasswordPolicy");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -21635,6 +22859,7 @@ impl Iam for IamClient {
         input: DeleteGroupRequest,
     ) -> Result<(), RusotoError<DeleteGroupError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteGroup");
         let params = self.new_params("DeleteGroup");
         let mut params = params;
         DeleteGroupRequestSerializer::serialize(&mut params, "", &input);
@@ -21655,6 +22880,7 @@ impl Iam for IamClient {
         input: DeleteGroupPolicyRequest,
     ) -> Result<(), RusotoError<DeleteGroupPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteGroupPolicy");
         let params = self.new_params("DeleteGroupPolicy");
         let mut params = params;
         DeleteGroupPolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -21675,6 +22901,7 @@ impl Iam for IamClient {
         input: DeleteInstanceProfileRequest,
     ) -> Result<(), RusotoError<DeleteInstanceProfileError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteInstanceProfile");
         let params = self.new_params("DeleteInstanceProfile");
         let mut params = params;
         DeleteInstanceProfileRequestSerializer::serialize(&mut params, "", &input);
@@ -21695,6 +22922,7 @@ impl Iam for IamClient {
         input: DeleteLoginProfileRequest,
     ) -> Result<(), RusotoError<DeleteLoginProfileError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteLoginProfile");
         let params = self.new_params("DeleteLoginProfile");
         let mut params = params;
         DeleteLoginProfileRequestSerializer::serialize(&mut params, "", &input);
@@ -21715,6 +22943,7 @@ impl Iam for IamClient {
         input: DeleteOpenIDConnectProviderRequest,
     ) -> Result<(), RusotoError<DeleteOpenIDConnectProviderError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteOpenIDConnectProvider");
         let params = self.new_params("DeleteOpenIDConnectProvider");
         let mut params = params;
         DeleteOpenIDConnectProviderRequestSerializer::serialize(&mut params, "", &input);
@@ -21735,6 +22964,7 @@ impl Iam for IamClient {
         input: DeletePolicyRequest,
     ) -> Result<(), RusotoError<DeletePolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeletePolicy");
         let params = self.new_params("DeletePolicy");
         let mut params = params;
         DeletePolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -21755,6 +22985,7 @@ impl Iam for IamClient {
         input: DeletePolicyVersionRequest,
     ) -> Result<(), RusotoError<DeletePolicyVersionError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeletePolicyVersion");
         let params = self.new_params("DeletePolicyVersion");
         let mut params = params;
         DeletePolicyVersionRequestSerializer::serialize(&mut params, "", &input);
@@ -21775,6 +23006,7 @@ impl Iam for IamClient {
         input: DeleteRoleRequest,
     ) -> Result<(), RusotoError<DeleteRoleError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteRole");
         let params = self.new_params("DeleteRole");
         let mut params = params;
         DeleteRoleRequestSerializer::serialize(&mut params, "", &input);
@@ -21795,6 +23027,7 @@ impl Iam for IamClient {
         input: DeleteRolePermissionsBoundaryRequest,
     ) -> Result<(), RusotoError<DeleteRolePermissionsBoundaryError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteRolePermissionsBoundary");
         let params = self.new_params("DeleteRolePermissionsBoundary");
         let mut params = params;
         DeleteRolePermissionsBoundaryRequestSerializer::serialize(&mut params, "", &input);
@@ -21815,6 +23048,7 @@ impl Iam for IamClient {
         input: DeleteRolePolicyRequest,
     ) -> Result<(), RusotoError<DeleteRolePolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteRolePolicy");
         let params = self.new_params("DeleteRolePolicy");
         let mut params = params;
         DeleteRolePolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -21835,6 +23069,7 @@ impl Iam for IamClient {
         input: DeleteSAMLProviderRequest,
     ) -> Result<(), RusotoError<DeleteSAMLProviderError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteSAMLProvider");
         let params = self.new_params("DeleteSAMLProvider");
         let mut params = params;
         DeleteSAMLProviderRequestSerializer::serialize(&mut params, "", &input);
@@ -21855,6 +23090,7 @@ impl Iam for IamClient {
         input: DeleteSSHPublicKeyRequest,
     ) -> Result<(), RusotoError<DeleteSSHPublicKeyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteSSHPublicKey");
         let params = self.new_params("DeleteSSHPublicKey");
         let mut params = params;
         DeleteSSHPublicKeyRequestSerializer::serialize(&mut params, "", &input);
@@ -21875,6 +23111,7 @@ impl Iam for IamClient {
         input: DeleteServerCertificateRequest,
     ) -> Result<(), RusotoError<DeleteServerCertificateError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteServerCertificate");
         let params = self.new_params("DeleteServerCertificate");
         let mut params = params;
         DeleteServerCertificateRequestSerializer::serialize(&mut params, "", &input);
@@ -21895,6 +23132,7 @@ impl Iam for IamClient {
         input: DeleteServiceLinkedRoleRequest,
     ) -> Result<DeleteServiceLinkedRoleResponse, RusotoError<DeleteServiceLinkedRoleError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteServiceLinkedRole");
         let params = self.new_params("DeleteServiceLinkedRole");
         let mut params = params;
         DeleteServiceLinkedRoleRequestSerializer::serialize(&mut params, "", &input);
@@ -21928,6 +23166,7 @@ impl Iam for IamClient {
         input: DeleteServiceSpecificCredentialRequest,
     ) -> Result<(), RusotoError<DeleteServiceSpecificCredentialError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteServiceSpecificCredential");
         let params = self.new_params("DeleteServiceSpecificCredential");
         let mut params = params;
         DeleteServiceSpecificCredentialRequestSerializer::serialize(&mut params, "", &input);
@@ -21948,6 +23187,7 @@ impl Iam for IamClient {
         input: DeleteSigningCertificateRequest,
     ) -> Result<(), RusotoError<DeleteSigningCertificateError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteSigningCertificate");
         let params = self.new_params("DeleteSigningCertificate");
         let mut params = params;
         DeleteSigningCertificateRequestSerializer::serialize(&mut params, "", &input);
@@ -21968,6 +23208,7 @@ impl Iam for IamClient {
         input: DeleteUserRequest,
     ) -> Result<(), RusotoError<DeleteUserError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteUser");
         let params = self.new_params("DeleteUser");
         let mut params = params;
         DeleteUserRequestSerializer::serialize(&mut params, "", &input);
@@ -21988,6 +23229,7 @@ impl Iam for IamClient {
         input: DeleteUserPermissionsBoundaryRequest,
     ) -> Result<(), RusotoError<DeleteUserPermissionsBoundaryError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteUserPermissionsBoundary");
         let params = self.new_params("DeleteUserPermissionsBoundary");
         let mut params = params;
         DeleteUserPermissionsBoundaryRequestSerializer::serialize(&mut params, "", &input);
@@ -22008,6 +23250,7 @@ impl Iam for IamClient {
         input: DeleteUserPolicyRequest,
     ) -> Result<(), RusotoError<DeleteUserPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteUserPolicy");
         let params = self.new_params("DeleteUserPolicy");
         let mut params = params;
         DeleteUserPolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -22028,6 +23271,7 @@ impl Iam for IamClient {
         input: DeleteVirtualMFADeviceRequest,
     ) -> Result<(), RusotoError<DeleteVirtualMFADeviceError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DeleteVirtualMFADevice");
         let params = self.new_params("DeleteVirtualMFADevice");
         let mut params = params;
         DeleteVirtualMFADeviceRequestSerializer::serialize(&mut params, "", &input);
@@ -22048,6 +23292,7 @@ impl Iam for IamClient {
         input: DetachGroupPolicyRequest,
     ) -> Result<(), RusotoError<DetachGroupPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DetachGroupPolicy");
         let params = self.new_params("DetachGroupPolicy");
         let mut params = params;
         DetachGroupPolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -22068,6 +23313,7 @@ impl Iam for IamClient {
         input: DetachRolePolicyRequest,
     ) -> Result<(), RusotoError<DetachRolePolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DetachRolePolicy");
         let params = self.new_params("DetachRolePolicy");
         let mut params = params;
         DetachRolePolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -22088,6 +23334,7 @@ impl Iam for IamClient {
         input: DetachUserPolicyRequest,
     ) -> Result<(), RusotoError<DetachUserPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("DetachUserPolicy");
         let params = self.new_params("DetachUserPolicy");
         let mut params = params;
         DetachUserPolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -22108,6 +23355,7 @@ impl Iam for IamClient {
         input: EnableMFADeviceRequest,
     ) -> Result<(), RusotoError<EnableMFADeviceError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("EnableMFADevice");
         let params = self.new_params("EnableMFADevice");
         let mut params = params;
         EnableMFADeviceRequestSerializer::serialize(&mut params, "", &input);
@@ -22127,6 +23375,7 @@ impl Iam for IamClient {
         &self,
     ) -> Result<GenerateCredentialReportResponse, RusotoError<GenerateCredentialReportError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GenerateCredentialReport");
         let params = self.new_params("GenerateCredentialReport");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -22162,6 +23411,7 @@ impl Iam for IamClient {
         RusotoError<GenerateOrganizationsAccessReportError>,
     > {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GenerateOrganizationsAccessReport");
         let params = self.new_params("GenerateOrganizationsAccessReport");
         let mut params = params;
         GenerateOrganizationsAccessReportRequestSerializer::serialize(&mut params, "", &input);
@@ -22201,6 +23451,7 @@ impl Iam for IamClient {
         RusotoError<GenerateServiceLastAccessedDetailsError>,
     > {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GenerateServiceLastAccessedDetails");
         let params = self.new_params("GenerateServiceLastAccessedDetails");
         let mut params = params;
         GenerateServiceLastAccessedDetailsRequestSerializer::serialize(&mut params, "", &input);
@@ -22237,6 +23488,7 @@ impl Iam for IamClient {
         input: GetAccessKeyLastUsedRequest,
     ) -> Result<GetAccessKeyLastUsedResponse, RusotoError<GetAccessKeyLastUsedError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetAccessKeyLastUsed");
         let params = self.new_params("GetAccessKeyLastUsed");
         let mut params = params;
         GetAccessKeyLastUsedRequestSerializer::serialize(&mut params, "", &input);
@@ -22273,6 +23525,7 @@ impl Iam for IamClient {
         RusotoError<GetAccountAuthorizationDetailsError>,
     > {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetAccountAuthorizationDetails");
         let params = self.new_params("GetAccountAuthorizationDetails");
         let mut params = params;
         GetAccountAuthorizationDetailsRequestSerializer::serialize(&mut params, "", &input);
@@ -22305,6 +23558,7 @@ impl Iam for IamClient {
         &self,
     ) -> Result<GetAccountPasswordPolicyResponse, RusotoError<GetAccountPasswordPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetAccountPasswordPolicy");
         let params = self.new_params("GetAccountPasswordPolicy");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -22336,6 +23590,7 @@ impl Iam for IamClient {
         &self,
     ) -> Result<GetAccountSummaryResponse, RusotoError<GetAccountSummaryError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetAccountSummary");
         let params = self.new_params("GetAccountSummary");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -22369,6 +23624,7 @@ impl Iam for IamClient {
     ) -> Result<GetContextKeysForPolicyResponse, RusotoError<GetContextKeysForCustomPolicyError>>
     {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetContextKeysForCustomPolicy");
         let params = self.new_params("GetContextKeysForCustomPolicy");
         let mut params = params;
         GetContextKeysForCustomPolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -22403,6 +23659,7 @@ impl Iam for IamClient {
     ) -> Result<GetContextKeysForPolicyResponse, RusotoError<GetContextKeysForPrincipalPolicyError>>
     {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetContextKeysForPrincipalPolicy");
         let params = self.new_params("GetContextKeysForPrincipalPolicy");
         let mut params = params;
         GetContextKeysForPrincipalPolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -22438,6 +23695,7 @@ impl Iam for IamClient {
         &self,
     ) -> Result<GetCredentialReportResponse, RusotoError<GetCredentialReportError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetCredentialReport");
         let params = self.new_params("GetCredentialReport");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -22470,6 +23728,7 @@ impl Iam for IamClient {
         input: GetGroupRequest,
     ) -> Result<GetGroupResponse, RusotoError<GetGroupError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetGroup");
         let params = self.new_params("GetGroup");
         let mut params = params;
         GetGroupRequestSerializer::serialize(&mut params, "", &input);
@@ -22500,6 +23759,7 @@ impl Iam for IamClient {
         input: GetGroupPolicyRequest,
     ) -> Result<GetGroupPolicyResponse, RusotoError<GetGroupPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetGroupPolicy");
         let params = self.new_params("GetGroupPolicy");
         let mut params = params;
         GetGroupPolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -22531,6 +23791,7 @@ impl Iam for IamClient {
         input: GetInstanceProfileRequest,
     ) -> Result<GetInstanceProfileResponse, RusotoError<GetInstanceProfileError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetInstanceProfile");
         let params = self.new_params("GetInstanceProfile");
         let mut params = params;
         GetInstanceProfileRequestSerializer::serialize(&mut params, "", &input);
@@ -22564,6 +23825,7 @@ impl Iam for IamClient {
         input: GetLoginProfileRequest,
     ) -> Result<GetLoginProfileResponse, RusotoError<GetLoginProfileError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetLoginProfile");
         let params = self.new_params("GetLoginProfile");
         let mut params = params;
         GetLoginProfileRequestSerializer::serialize(&mut params, "", &input);
@@ -22595,6 +23857,7 @@ impl Iam for IamClient {
         input: GetOpenIDConnectProviderRequest,
     ) -> Result<GetOpenIDConnectProviderResponse, RusotoError<GetOpenIDConnectProviderError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetOpenIDConnectProvider");
         let params = self.new_params("GetOpenIDConnectProvider");
         let mut params = params;
         GetOpenIDConnectProviderRequestSerializer::serialize(&mut params, "", &input);
@@ -22629,6 +23892,7 @@ impl Iam for IamClient {
     ) -> Result<GetOrganizationsAccessReportResponse, RusotoError<GetOrganizationsAccessReportError>>
     {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetOrganizationsAccessReport");
         let params = self.new_params("GetOrganizationsAccessReport");
         let mut params = params;
         GetOrganizationsAccessReportRequestSerializer::serialize(&mut params, "", &input);
@@ -22662,6 +23926,7 @@ impl Iam for IamClient {
         input: GetPolicyRequest,
     ) -> Result<GetPolicyResponse, RusotoError<GetPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetPolicy");
         let params = self.new_params("GetPolicy");
         let mut params = params;
         GetPolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -22692,6 +23957,7 @@ impl Iam for IamClient {
         input: GetPolicyVersionRequest,
     ) -> Result<GetPolicyVersionResponse, RusotoError<GetPolicyVersionError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetPolicyVersion");
         let params = self.new_params("GetPolicyVersion");
         let mut params = params;
         GetPolicyVersionRequestSerializer::serialize(&mut params, "", &input);
@@ -22723,6 +23989,7 @@ impl Iam for IamClient {
         input: GetRoleRequest,
     ) -> Result<GetRoleResponse, RusotoError<GetRoleError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetRole");
         let params = self.new_params("GetRole");
         let mut params = params;
         GetRoleRequestSerializer::serialize(&mut params, "", &input);
@@ -22753,6 +24020,7 @@ impl Iam for IamClient {
         input: GetRolePolicyRequest,
     ) -> Result<GetRolePolicyResponse, RusotoError<GetRolePolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetRolePolicy");
         let params = self.new_params("GetRolePolicy");
         let mut params = params;
         GetRolePolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -22784,6 +24052,7 @@ impl Iam for IamClient {
         input: GetSAMLProviderRequest,
     ) -> Result<GetSAMLProviderResponse, RusotoError<GetSAMLProviderError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetSAMLProvider");
         let params = self.new_params("GetSAMLProvider");
         let mut params = params;
         GetSAMLProviderRequestSerializer::serialize(&mut params, "", &input);
@@ -22815,6 +24084,7 @@ impl Iam for IamClient {
         input: GetSSHPublicKeyRequest,
     ) -> Result<GetSSHPublicKeyResponse, RusotoError<GetSSHPublicKeyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetSSHPublicKey");
         let params = self.new_params("GetSSHPublicKey");
         let mut params = params;
         GetSSHPublicKeyRequestSerializer::serialize(&mut params, "", &input);
@@ -22846,6 +24116,7 @@ impl Iam for IamClient {
         input: GetServerCertificateRequest,
     ) -> Result<GetServerCertificateResponse, RusotoError<GetServerCertificateError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetServerCertificate");
         let params = self.new_params("GetServerCertificate");
         let mut params = params;
         GetServerCertificateRequestSerializer::serialize(&mut params, "", &input);
@@ -22882,6 +24153,7 @@ impl Iam for IamClient {
         RusotoError<GetServiceLastAccessedDetailsError>,
     > {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetServiceLastAccessedDetails");
         let params = self.new_params("GetServiceLastAccessedDetails");
         let mut params = params;
         GetServiceLastAccessedDetailsRequestSerializer::serialize(&mut params, "", &input);
@@ -22918,6 +24190,7 @@ impl Iam for IamClient {
         RusotoError<GetServiceLastAccessedDetailsWithEntitiesError>,
     > {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetServiceLastAccessedDetailsWithEntities");
         let params = self.new_params("GetServiceLastAccessedDetailsWithEntities");
         let mut params = params;
         GetServiceLastAccessedDetailsWithEntitiesRequestSerializer::serialize(
@@ -22962,6 +24235,7 @@ impl Iam for IamClient {
         RusotoError<GetServiceLinkedRoleDeletionStatusError>,
     > {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetServiceLinkedRoleDeletionStatus");
         let params = self.new_params("GetServiceLinkedRoleDeletionStatus");
         let mut params = params;
         GetServiceLinkedRoleDeletionStatusRequestSerializer::serialize(&mut params, "", &input);
@@ -22998,6 +24272,7 @@ impl Iam for IamClient {
         input: GetUserRequest,
     ) -> Result<GetUserResponse, RusotoError<GetUserError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetUser");
         let params = self.new_params("GetUser");
         let mut params = params;
         GetUserRequestSerializer::serialize(&mut params, "", &input);
@@ -23028,6 +24303,7 @@ impl Iam for IamClient {
         input: GetUserPolicyRequest,
     ) -> Result<GetUserPolicyResponse, RusotoError<GetUserPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("GetUserPolicy");
         let params = self.new_params("GetUserPolicy");
         let mut params = params;
         GetUserPolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -23059,6 +24335,7 @@ impl Iam for IamClient {
         input: ListAccessKeysRequest,
     ) -> Result<ListAccessKeysResponse, RusotoError<ListAccessKeysError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListAccessKeys");
         let params = self.new_params("ListAccessKeys");
         let mut params = params;
         ListAccessKeysRequestSerializer::serialize(&mut params, "", &input);
@@ -23090,6 +24367,7 @@ impl Iam for IamClient {
         input: ListAccountAliasesRequest,
     ) -> Result<ListAccountAliasesResponse, RusotoError<ListAccountAliasesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListAccountAliases");
         let params = self.new_params("ListAccountAliases");
         let mut params = params;
         ListAccountAliasesRequestSerializer::serialize(&mut params, "", &input);
@@ -23124,6 +24402,7 @@ impl Iam for IamClient {
     ) -> Result<ListAttachedGroupPoliciesResponse, RusotoError<ListAttachedGroupPoliciesError>>
     {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListAttachedGroupPolicies");
         let params = self.new_params("ListAttachedGroupPolicies");
         let mut params = params;
         ListAttachedGroupPoliciesRequestSerializer::serialize(&mut params, "", &input);
@@ -23157,6 +24436,7 @@ impl Iam for IamClient {
         input: ListAttachedRolePoliciesRequest,
     ) -> Result<ListAttachedRolePoliciesResponse, RusotoError<ListAttachedRolePoliciesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListAttachedRolePolicies");
         let params = self.new_params("ListAttachedRolePolicies");
         let mut params = params;
         ListAttachedRolePoliciesRequestSerializer::serialize(&mut params, "", &input);
@@ -23190,6 +24470,7 @@ impl Iam for IamClient {
         input: ListAttachedUserPoliciesRequest,
     ) -> Result<ListAttachedUserPoliciesResponse, RusotoError<ListAttachedUserPoliciesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListAttachedUserPolicies");
         let params = self.new_params("ListAttachedUserPolicies");
         let mut params = params;
         ListAttachedUserPoliciesRequestSerializer::serialize(&mut params, "", &input);
@@ -23223,6 +24504,7 @@ impl Iam for IamClient {
         input: ListEntitiesForPolicyRequest,
     ) -> Result<ListEntitiesForPolicyResponse, RusotoError<ListEntitiesForPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListEntitiesForPolicy");
         let params = self.new_params("ListEntitiesForPolicy");
         let mut params = params;
         ListEntitiesForPolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -23256,6 +24538,7 @@ impl Iam for IamClient {
         input: ListGroupPoliciesRequest,
     ) -> Result<ListGroupPoliciesResponse, RusotoError<ListGroupPoliciesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListGroupPolicies");
         let params = self.new_params("ListGroupPolicies");
         let mut params = params;
         ListGroupPoliciesRequestSerializer::serialize(&mut params, "", &input);
@@ -23289,6 +24572,7 @@ impl Iam for IamClient {
         input: ListGroupsRequest,
     ) -> Result<ListGroupsResponse, RusotoError<ListGroupsError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListGroups");
         let params = self.new_params("ListGroups");
         let mut params = params;
         ListGroupsRequestSerializer::serialize(&mut params, "", &input);
@@ -23319,6 +24603,7 @@ impl Iam for IamClient {
         input: ListGroupsForUserRequest,
     ) -> Result<ListGroupsForUserResponse, RusotoError<ListGroupsForUserError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListGroupsForUser");
         let params = self.new_params("ListGroupsForUser");
         let mut params = params;
         ListGroupsForUserRequestSerializer::serialize(&mut params, "", &input);
@@ -23352,6 +24637,7 @@ impl Iam for IamClient {
         input: ListInstanceProfilesRequest,
     ) -> Result<ListInstanceProfilesResponse, RusotoError<ListInstanceProfilesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListInstanceProfiles");
         let params = self.new_params("ListInstanceProfiles");
         let mut params = params;
         ListInstanceProfilesRequestSerializer::serialize(&mut params, "", &input);
@@ -23386,6 +24672,7 @@ impl Iam for IamClient {
     ) -> Result<ListInstanceProfilesForRoleResponse, RusotoError<ListInstanceProfilesForRoleError>>
     {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListInstanceProfilesForRole");
         let params = self.new_params("ListInstanceProfilesForRole");
         let mut params = params;
         ListInstanceProfilesForRoleRequestSerializer::serialize(&mut params, "", &input);
@@ -23419,6 +24706,7 @@ impl Iam for IamClient {
         input: ListMFADevicesRequest,
     ) -> Result<ListMFADevicesResponse, RusotoError<ListMFADevicesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListMFADevices");
         let params = self.new_params("ListMFADevices");
         let mut params = params;
         ListMFADevicesRequestSerializer::serialize(&mut params, "", &input);
@@ -23451,6 +24739,7 @@ impl Iam for IamClient {
     ) -> Result<ListOpenIDConnectProvidersResponse, RusotoError<ListOpenIDConnectProvidersError>>
     {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListOpenIDConnectProviders");
         let params = self.new_params("ListOpenIDConnectProviders");
         let mut params = params;
         ListOpenIDConnectProvidersRequestSerializer::serialize(&mut params, "", &input);
@@ -23484,6 +24773,7 @@ impl Iam for IamClient {
         input: ListPoliciesRequest,
     ) -> Result<ListPoliciesResponse, RusotoError<ListPoliciesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListPolicies");
         let params = self.new_params("ListPolicies");
         let mut params = params;
         ListPoliciesRequestSerializer::serialize(&mut params, "", &input);
@@ -23518,6 +24808,7 @@ impl Iam for IamClient {
         RusotoError<ListPoliciesGrantingServiceAccessError>,
     > {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListPoliciesGrantingServiceAccess");
         let params = self.new_params("ListPoliciesGrantingServiceAccess");
         let mut params = params;
         ListPoliciesGrantingServiceAccessRequestSerializer::serialize(&mut params, "", &input);
@@ -23554,6 +24845,7 @@ impl Iam for IamClient {
         input: ListPolicyVersionsRequest,
     ) -> Result<ListPolicyVersionsResponse, RusotoError<ListPolicyVersionsError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListPolicyVersions");
         let params = self.new_params("ListPolicyVersions");
         let mut params = params;
         ListPolicyVersionsRequestSerializer::serialize(&mut params, "", &input);
@@ -23587,6 +24879,7 @@ impl Iam for IamClient {
         input: ListRolePoliciesRequest,
     ) -> Result<ListRolePoliciesResponse, RusotoError<ListRolePoliciesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListRolePolicies");
         let params = self.new_params("ListRolePolicies");
         let mut params = params;
         ListRolePoliciesRequestSerializer::serialize(&mut params, "", &input);
@@ -23618,6 +24911,7 @@ impl Iam for IamClient {
         input: ListRoleTagsRequest,
     ) -> Result<ListRoleTagsResponse, RusotoError<ListRoleTagsError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListRoleTags");
         let params = self.new_params("ListRoleTags");
         let mut params = params;
         ListRoleTagsRequestSerializer::serialize(&mut params, "", &input);
@@ -23649,6 +24943,7 @@ impl Iam for IamClient {
         input: ListRolesRequest,
     ) -> Result<ListRolesResponse, RusotoError<ListRolesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListRoles");
         let params = self.new_params("ListRoles");
         let mut params = params;
         ListRolesRequestSerializer::serialize(&mut params, "", &input);
@@ -23679,6 +24974,7 @@ impl Iam for IamClient {
         input: ListSAMLProvidersRequest,
     ) -> Result<ListSAMLProvidersResponse, RusotoError<ListSAMLProvidersError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListSAMLProviders");
         let params = self.new_params("ListSAMLProviders");
         let mut params = params;
         ListSAMLProvidersRequestSerializer::serialize(&mut params, "", &input);
@@ -23712,6 +25008,7 @@ impl Iam for IamClient {
         input: ListSSHPublicKeysRequest,
     ) -> Result<ListSSHPublicKeysResponse, RusotoError<ListSSHPublicKeysError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListSSHPublicKeys");
         let params = self.new_params("ListSSHPublicKeys");
         let mut params = params;
         ListSSHPublicKeysRequestSerializer::serialize(&mut params, "", &input);
@@ -23745,6 +25042,7 @@ impl Iam for IamClient {
         input: ListServerCertificatesRequest,
     ) -> Result<ListServerCertificatesResponse, RusotoError<ListServerCertificatesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListServerCertificates");
         let params = self.new_params("ListServerCertificates");
         let mut params = params;
         ListServerCertificatesRequestSerializer::serialize(&mut params, "", &input);
@@ -23781,6 +25079,7 @@ impl Iam for IamClient {
         RusotoError<ListServiceSpecificCredentialsError>,
     > {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListServiceSpecificCredentials");
         let params = self.new_params("ListServiceSpecificCredentials");
         let mut params = params;
         ListServiceSpecificCredentialsRequestSerializer::serialize(&mut params, "", &input);
@@ -23814,6 +25113,7 @@ impl Iam for IamClient {
         input: ListSigningCertificatesRequest,
     ) -> Result<ListSigningCertificatesResponse, RusotoError<ListSigningCertificatesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListSigningCertificates");
         let params = self.new_params("ListSigningCertificates");
         let mut params = params;
         ListSigningCertificatesRequestSerializer::serialize(&mut params, "", &input);
@@ -23847,6 +25147,7 @@ impl Iam for IamClient {
         input: ListUserPoliciesRequest,
     ) -> Result<ListUserPoliciesResponse, RusotoError<ListUserPoliciesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListUserPolicies");
         let params = self.new_params("ListUserPolicies");
         let mut params = params;
         ListUserPoliciesRequestSerializer::serialize(&mut params, "", &input);
@@ -23878,6 +25179,7 @@ impl Iam for IamClient {
         input: ListUserTagsRequest,
     ) -> Result<ListUserTagsResponse, RusotoError<ListUserTagsError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListUserTags");
         let params = self.new_params("ListUserTags");
         let mut params = params;
         ListUserTagsRequestSerializer::serialize(&mut params, "", &input);
@@ -23909,6 +25211,7 @@ impl Iam for IamClient {
         input: ListUsersRequest,
     ) -> Result<ListUsersResponse, RusotoError<ListUsersError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListUsers");
         let params = self.new_params("ListUsers");
         let mut params = params;
         ListUsersRequestSerializer::serialize(&mut params, "", &input);
@@ -23939,6 +25242,7 @@ impl Iam for IamClient {
         input: ListVirtualMFADevicesRequest,
     ) -> Result<ListVirtualMFADevicesResponse, RusotoError<ListVirtualMFADevicesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ListVirtualMFADevices");
         let params = self.new_params("ListVirtualMFADevices");
         let mut params = params;
         ListVirtualMFADevicesRequestSerializer::serialize(&mut params, "", &input);
@@ -23972,6 +25276,7 @@ impl Iam for IamClient {
         input: PutGroupPolicyRequest,
     ) -> Result<(), RusotoError<PutGroupPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("PutGroupPolicy");
         let params = self.new_params("PutGroupPolicy");
         let mut params = params;
         PutGroupPolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -23992,6 +25297,7 @@ impl Iam for IamClient {
         input: PutRolePermissionsBoundaryRequest,
     ) -> Result<(), RusotoError<PutRolePermissionsBoundaryError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("PutRolePermissionsBoundary");
         let params = self.new_params("PutRolePermissionsBoundary");
         let mut params = params;
         PutRolePermissionsBoundaryRequestSerializer::serialize(&mut params, "", &input);
@@ -24012,6 +25318,7 @@ impl Iam for IamClient {
         input: PutRolePolicyRequest,
     ) -> Result<(), RusotoError<PutRolePolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("PutRolePolicy");
         let params = self.new_params("PutRolePolicy");
         let mut params = params;
         PutRolePolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -24032,6 +25339,7 @@ impl Iam for IamClient {
         input: PutUserPermissionsBoundaryRequest,
     ) -> Result<(), RusotoError<PutUserPermissionsBoundaryError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("PutUserPermissionsBoundary");
         let params = self.new_params("PutUserPermissionsBoundary");
         let mut params = params;
         PutUserPermissionsBoundaryRequestSerializer::serialize(&mut params, "", &input);
@@ -24052,6 +25360,7 @@ impl Iam for IamClient {
         input: PutUserPolicyRequest,
     ) -> Result<(), RusotoError<PutUserPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("PutUserPolicy");
         let params = self.new_params("PutUserPolicy");
         let mut params = params;
         PutUserPolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -24072,6 +25381,7 @@ impl Iam for IamClient {
         input: RemoveClientIDFromOpenIDConnectProviderRequest,
     ) -> Result<(), RusotoError<RemoveClientIDFromOpenIDConnectProviderError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("RemoveClientIDFromOpenIDConnectProvider");
         let params = self.new_params("RemoveClientIDFromOpenIDConnectProvider");
         let mut params = params;
         RemoveClientIDFromOpenIDConnectProviderRequestSerializer::serialize(
@@ -24099,6 +25409,7 @@ impl Iam for IamClient {
         input: RemoveRoleFromInstanceProfileRequest,
     ) -> Result<(), RusotoError<RemoveRoleFromInstanceProfileError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("RemoveRoleFromInstanceProfile");
         let params = self.new_params("RemoveRoleFromInstanceProfile");
         let mut params = params;
         RemoveRoleFromInstanceProfileRequestSerializer::serialize(&mut params, "", &input);
@@ -24119,6 +25430,7 @@ impl Iam for IamClient {
         input: RemoveUserFromGroupRequest,
     ) -> Result<(), RusotoError<RemoveUserFromGroupError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("RemoveUserFromGroup");
         let params = self.new_params("RemoveUserFromGroup");
         let mut params = params;
         RemoveUserFromGroupRequestSerializer::serialize(&mut params, "", &input);
@@ -24142,6 +25454,7 @@ impl Iam for IamClient {
         RusotoError<ResetServiceSpecificCredentialError>,
     > {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ResetServiceSpecificCredential");
         let params = self.new_params("ResetServiceSpecificCredential");
         let mut params = params;
         ResetServiceSpecificCredentialRequestSerializer::serialize(&mut params, "", &input);
@@ -24175,6 +25488,7 @@ impl Iam for IamClient {
         input: ResyncMFADeviceRequest,
     ) -> Result<(), RusotoError<ResyncMFADeviceError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("ResyncMFADevice");
         let params = self.new_params("ResyncMFADevice");
         let mut params = params;
         ResyncMFADeviceRequestSerializer::serialize(&mut params, "", &input);
@@ -24195,6 +25509,7 @@ impl Iam for IamClient {
         input: SetDefaultPolicyVersionRequest,
     ) -> Result<(), RusotoError<SetDefaultPolicyVersionError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("SetDefaultPolicyVersion");
         let params = self.new_params("SetDefaultPolicyVersion");
         let mut params = params;
         SetDefaultPolicyVersionRequestSerializer::serialize(&mut params, "", &input);
@@ -24215,6 +25530,7 @@ impl Iam for IamClient {
         input: SetSecurityTokenServicePreferencesRequest,
     ) -> Result<(), RusotoError<SetSecurityTokenServicePreferencesError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("SetSecurityTokenServicePreferences");
         let params = self.new_params("SetSecurityTokenServicePreferences");
         let mut params = params;
         SetSecurityTokenServicePreferencesRequestSerializer::serialize(&mut params, "", &input);
@@ -24238,6 +25554,7 @@ impl Iam for IamClient {
         input: SimulateCustomPolicyRequest,
     ) -> Result<SimulatePolicyResponse, RusotoError<SimulateCustomPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("SimulateCustomPolicy");
         let params = self.new_params("SimulateCustomPolicy");
         let mut params = params;
         SimulateCustomPolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -24271,6 +25588,7 @@ impl Iam for IamClient {
         input: SimulatePrincipalPolicyRequest,
     ) -> Result<SimulatePolicyResponse, RusotoError<SimulatePrincipalPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("SimulatePrincipalPolicy");
         let params = self.new_params("SimulatePrincipalPolicy");
         let mut params = params;
         SimulatePrincipalPolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -24301,6 +25619,7 @@ impl Iam for IamClient {
     /// <p>Adds one or more tags to an IAM role. The role can be a regular role or a service-linked role. If a tag with the same key name already exists, then that tag is overwritten with the new value.</p> <p>A tag consists of a key name and an associated value. By assigning tags to your resources, you can do the following:</p> <ul> <li> <p> <b>Administrative grouping and discovery</b> - Attach tags to resources to aid in organization and search. For example, you could search for all resources with the key name <i>Project</i> and the value <i>MyImportantProject</i>. Or search for all resources with the key name <i>Cost Center</i> and the value <i>41200</i>. </p> </li> <li> <p> <b>Access control</b> - Reference tags in IAM user-based and resource-based policies. You can use tags to restrict access to only an IAM user or role that has a specified tag attached. You can also restrict access to only those resources that have a certain tag attached. For examples of policies that show how to use tags to control access, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_tags.html">Control Access Using IAM Tags</a> in the <i>IAM User Guide</i>.</p> </li> <li> <p> <b>Cost allocation</b> - Use tags to help track which individuals and teams are using which AWS resources.</p> </li> </ul> <note> <ul> <li> <p>Make sure that you have no invalid tags and that you do not exceed the allowed number of tags per role. In either case, the entire request fails and <i>no</i> tags are added to the role.</p> </li> <li> <p>AWS always interprets the tag <code>Value</code> as a single string. If you need to store an array, you can store comma-separated values in the string. However, you must interpret the value in your code.</p> </li> </ul> </note> <p>For more information about tagging, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_tags.html">Tagging IAM Identities</a> in the <i>IAM User Guide</i>.</p>
     async fn tag_role(&self, input: TagRoleRequest) -> Result<(), RusotoError<TagRoleError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("TagRole");
         let params = self.new_params("TagRole");
         let mut params = params;
         TagRoleRequestSerializer::serialize(&mut params, "", &input);
@@ -24318,6 +25637,7 @@ impl Iam for IamClient {
     /// <p>Adds one or more tags to an IAM user. If a tag with the same key name already exists, then that tag is overwritten with the new value.</p> <p>A tag consists of a key name and an associated value. By assigning tags to your resources, you can do the following:</p> <ul> <li> <p> <b>Administrative grouping and discovery</b> - Attach tags to resources to aid in organization and search. For example, you could search for all resources with the key name <i>Project</i> and the value <i>MyImportantProject</i>. Or search for all resources with the key name <i>Cost Center</i> and the value <i>41200</i>. </p> </li> <li> <p> <b>Access control</b> - Reference tags in IAM user-based and resource-based policies. You can use tags to restrict access to only an IAM requesting user or to a role that has a specified tag attached. You can also restrict access to only those resources that have a certain tag attached. For examples of policies that show how to use tags to control access, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/access_tags.html">Control Access Using IAM Tags</a> in the <i>IAM User Guide</i>.</p> </li> <li> <p> <b>Cost allocation</b> - Use tags to help track which individuals and teams are using which AWS resources.</p> </li> </ul> <note> <ul> <li> <p>Make sure that you have no invalid tags and that you do not exceed the allowed number of tags per role. In either case, the entire request fails and <i>no</i> tags are added to the role.</p> </li> <li> <p>AWS always interprets the tag <code>Value</code> as a single string. If you need to store an array, you can store comma-separated values in the string. However, you must interpret the value in your code.</p> </li> </ul> </note> <p>For more information about tagging, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_tags.html">Tagging IAM Identities</a> in the <i>IAM User Guide</i>.</p>
     async fn tag_user(&self, input: TagUserRequest) -> Result<(), RusotoError<TagUserError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("TagUser");
         let params = self.new_params("TagUser");
         let mut params = params;
         TagUserRequestSerializer::serialize(&mut params, "", &input);
@@ -24335,6 +25655,7 @@ impl Iam for IamClient {
     /// <p>Removes the specified tags from the role. For more information about tagging, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_tags.html">Tagging IAM Identities</a> in the <i>IAM User Guide</i>.</p>
     async fn untag_role(&self, input: UntagRoleRequest) -> Result<(), RusotoError<UntagRoleError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("UntagRole");
         let params = self.new_params("UntagRole");
         let mut params = params;
         UntagRoleRequestSerializer::serialize(&mut params, "", &input);
@@ -24352,6 +25673,7 @@ impl Iam for IamClient {
     /// <p>Removes the specified tags from the user. For more information about tagging, see <a href="https://docs.aws.amazon.com/IAM/latest/UserGuide/id_tags.html">Tagging IAM Identities</a> in the <i>IAM User Guide</i>.</p>
     async fn untag_user(&self, input: UntagUserRequest) -> Result<(), RusotoError<UntagUserError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("UntagUser");
         let params = self.new_params("UntagUser");
         let mut params = params;
         UntagUserRequestSerializer::serialize(&mut params, "", &input);
@@ -24372,6 +25694,7 @@ impl Iam for IamClient {
         input: UpdateAccessKeyRequest,
     ) -> Result<(), RusotoError<UpdateAccessKeyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("UpdateAccessKey");
         let params = self.new_params("UpdateAccessKey");
         let mut params = params;
         UpdateAccessKeyRequestSerializer::serialize(&mut params, "", &input);
@@ -24392,6 +25715,7 @@ impl Iam for IamClient {
         input: UpdateAccountPasswordPolicyRequest,
     ) -> Result<(), RusotoError<UpdateAccountPasswordPolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("UpdateAccountPasswordPolicy");
         let params = self.new_params("UpdateAccountPasswordPolicy");
         let mut params = params;
         UpdateAccountPasswordPolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -24412,6 +25736,7 @@ impl Iam for IamClient {
         input: UpdateAssumeRolePolicyRequest,
     ) -> Result<(), RusotoError<UpdateAssumeRolePolicyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("UpdateAssumeRolePolicy");
         let params = self.new_params("UpdateAssumeRolePolicy");
         let mut params = params;
         UpdateAssumeRolePolicyRequestSerializer::serialize(&mut params, "", &input);
@@ -24432,6 +25757,7 @@ impl Iam for IamClient {
         input: UpdateGroupRequest,
     ) -> Result<(), RusotoError<UpdateGroupError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("UpdateGroup");
         let params = self.new_params("UpdateGroup");
         let mut params = params;
         UpdateGroupRequestSerializer::serialize(&mut params, "", &input);
@@ -24452,6 +25778,7 @@ impl Iam for IamClient {
         input: UpdateLoginProfileRequest,
     ) -> Result<(), RusotoError<UpdateLoginProfileError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("UpdateLoginProfile");
         let params = self.new_params("UpdateLoginProfile");
         let mut params = params;
         UpdateLoginProfileRequestSerializer::serialize(&mut params, "", &input);
@@ -24472,6 +25799,7 @@ impl Iam for IamClient {
         input: UpdateOpenIDConnectProviderThumbprintRequest,
     ) -> Result<(), RusotoError<UpdateOpenIDConnectProviderThumbprintError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("UpdateOpenIDConnectProviderThumbprint");
         let params = self.new_params("UpdateOpenIDConnectProviderThumbprint");
         let mut params = params;
         UpdateOpenIDConnectProviderThumbprintRequestSerializer::serialize(&mut params, "", &input);
@@ -24495,6 +25823,7 @@ impl Iam for IamClient {
         input: UpdateRoleRequest,
     ) -> Result<UpdateRoleResponse, RusotoError<UpdateRoleError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("UpdateRole");
         let params = self.new_params("UpdateRole");
         let mut params = params;
         UpdateRoleRequestSerializer::serialize(&mut params, "", &input);
@@ -24517,6 +25846,7 @@ impl Iam for IamClient {
         input: UpdateRoleDescriptionRequest,
     ) -> Result<UpdateRoleDescriptionResponse, RusotoError<UpdateRoleDescriptionError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("UpdateRoleDescription");
         let params = self.new_params("UpdateRoleDescription");
         let mut params = params;
         UpdateRoleDescriptionRequestSerializer::serialize(&mut params, "", &input);
@@ -24550,6 +25880,7 @@ impl Iam for IamClient {
         input: UpdateSAMLProviderRequest,
     ) -> Result<UpdateSAMLProviderResponse, RusotoError<UpdateSAMLProviderError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("UpdateSAMLProvider");
         let params = self.new_params("UpdateSAMLProvider");
         let mut params = params;
         UpdateSAMLProviderRequestSerializer::serialize(&mut params, "", &input);
@@ -24583,6 +25914,7 @@ impl Iam for IamClient {
         input: UpdateSSHPublicKeyRequest,
     ) -> Result<(), RusotoError<UpdateSSHPublicKeyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("UpdateSSHPublicKey");
         let params = self.new_params("UpdateSSHPublicKey");
         let mut params = params;
         UpdateSSHPublicKeyRequestSerializer::serialize(&mut params, "", &input);
@@ -24603,6 +25935,7 @@ impl Iam for IamClient {
         input: UpdateServerCertificateRequest,
     ) -> Result<(), RusotoError<UpdateServerCertificateError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("UpdateServerCertificate");
         let params = self.new_params("UpdateServerCertificate");
         let mut params = params;
         UpdateServerCertificateRequestSerializer::serialize(&mut params, "", &input);
@@ -24623,6 +25956,7 @@ impl Iam for IamClient {
         input: UpdateServiceSpecificCredentialRequest,
     ) -> Result<(), RusotoError<UpdateServiceSpecificCredentialError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("UpdateServiceSpecificCredential");
         let params = self.new_params("UpdateServiceSpecificCredential");
         let mut params = params;
         UpdateServiceSpecificCredentialRequestSerializer::serialize(&mut params, "", &input);
@@ -24643,6 +25977,7 @@ impl Iam for IamClient {
         input: UpdateSigningCertificateRequest,
     ) -> Result<(), RusotoError<UpdateSigningCertificateError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("UpdateSigningCertificate");
         let params = self.new_params("UpdateSigningCertificate");
         let mut params = params;
         UpdateSigningCertificateRequestSerializer::serialize(&mut params, "", &input);
@@ -24663,6 +25998,7 @@ impl Iam for IamClient {
         input: UpdateUserRequest,
     ) -> Result<(), RusotoError<UpdateUserError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("UpdateUser");
         let params = self.new_params("UpdateUser");
         let mut params = params;
         UpdateUserRequestSerializer::serialize(&mut params, "", &input);
@@ -24683,6 +26019,7 @@ impl Iam for IamClient {
         input: UploadSSHPublicKeyRequest,
     ) -> Result<UploadSSHPublicKeyResponse, RusotoError<UploadSSHPublicKeyError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("UploadSSHPublicKey");
         let params = self.new_params("UploadSSHPublicKey");
         let mut params = params;
         UploadSSHPublicKeyRequestSerializer::serialize(&mut params, "", &input);
@@ -24716,6 +26053,7 @@ impl Iam for IamClient {
         input: UploadServerCertificateRequest,
     ) -> Result<UploadServerCertificateResponse, RusotoError<UploadServerCertificateError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("UploadServerCertificate");
         let params = self.new_params("UploadServerCertificate");
         let mut params = params;
         UploadServerCertificateRequestSerializer::serialize(&mut params, "", &input);
@@ -24749,6 +26087,7 @@ impl Iam for IamClient {
         input: UploadSigningCertificateRequest,
     ) -> Result<UploadSigningCertificateResponse, RusotoError<UploadSigningCertificateError>> {
         let mut request = SignedRequest::new("POST", "iam", &self.region, "/");
+        request.set_operation("UploadSigningCertificate");
         let params = self.new_params("UploadSigningCertificate");
         let mut params = params;
         UploadSigningCertificateRequestSerializer::serialize(&mut params, "", &input);
@@ -24777,6 +26116,1313 @@ impl Iam for IamClient {
     }
 }
 
+/// A blocking client for the IAM API, running the calls of a `IamClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `IamClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct IamBlockingClient {
+    client: IamClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl IamBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> IamBlockingClient {
+        IamBlockingClient::from_client(IamClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> IamBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        IamBlockingClient::from_client(IamClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> IamBlockingClient {
+        IamBlockingClient::from_client(IamClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> IamBlockingClient {
+        IamBlockingClient::from_client(IamClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: IamClient) -> IamBlockingClient {
+        IamBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> IamBlockingClient {
+        IamBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> IamBlockingClient {
+        IamBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Iam::add_client_id_to_open_id_connect_provider`].
+    pub fn add_client_id_to_open_id_connect_provider(
+        &self,
+        input: AddClientIDToOpenIDConnectProviderRequest,
+    ) -> Result<(), RusotoError<AddClientIDToOpenIDConnectProviderError>> {
+        self.runtime
+            .block_on(self.client.add_client_id_to_open_id_connect_provider(input))
+    }
+
+    /// Blocking version of [`Iam::add_role_to_instance_profile`].
+    pub fn add_role_to_instance_profile(
+        &self,
+        input: AddRoleToInstanceProfileRequest,
+    ) -> Result<(), RusotoError<AddRoleToInstanceProfileError>> {
+        self.runtime
+            .block_on(self.client.add_role_to_instance_profile(input))
+    }
+
+    /// Blocking version of [`Iam::add_user_to_group`].
+    pub fn add_user_to_group(
+        &self,
+        input: AddUserToGroupRequest,
+    ) -> Result<(), RusotoError<AddUserToGroupError>> {
+        self.runtime.block_on(self.client.add_user_to_group(input))
+    }
+
+    /// Blocking version of [`Iam::attach_group_policy`].
+    pub fn attach_group_policy(
+        &self,
+        input: AttachGroupPolicyRequest,
+    ) -> Result<(), RusotoError<AttachGroupPolicyError>> {
+        self.runtime
+            .block_on(self.client.attach_group_policy(input))
+    }
+
+    /// Blocking version of [`Iam::attach_role_policy`].
+    pub fn attach_role_policy(
+        &self,
+        input: AttachRolePolicyRequest,
+    ) -> Result<(), RusotoError<AttachRolePolicyError>> {
+        self.runtime.block_on(self.client.attach_role_policy(input))
+    }
+
+    /// Blocking version of [`Iam::attach_user_policy`].
+    pub fn attach_user_policy(
+        &self,
+        input: AttachUserPolicyRequest,
+    ) -> Result<(), RusotoError<AttachUserPolicyError>> {
+        self.runtime.block_on(self.client.attach_user_policy(input))
+    }
+
+    /// Blocking version of [`Iam::change_password`].
+    pub fn change_password(
+        &self,
+        input: ChangePasswordRequest,
+    ) -> Result<(), RusotoError<ChangePasswordError>> {
+        self.runtime.block_on(self.client.change_password(input))
+    }
+
+    /// Blocking version of [`Iam::create_access_key`].
+    pub fn create_access_key(
+        &self,
+        input: CreateAccessKeyRequest,
+    ) -> Result<CreateAccessKeyResponse, RusotoError<CreateAccessKeyError>> {
+        self.runtime.block_on(self.client.create_access_key(input))
+    }
+
+    /// Blocking version of [`Iam::create_account_alias`].
+    pub fn create_account_alias(
+        &self,
+        input: CreateAccountAliasRequest,
+    ) -> Result<(), RusotoError<CreateAccountAliasError>> {
+        self.runtime
+            .block_on(self.client.create_account_alias(input))
+    }
+
+    /// Blocking version of [`Iam::create_group`].
+    pub fn create_group(
+        &self,
+        input: CreateGroupRequest,
+    ) -> Result<CreateGroupResponse, RusotoError<CreateGroupError>> {
+        self.runtime.block_on(self.client.create_group(input))
+    }
+
+    /// Blocking version of [`Iam::create_instance_profile`].
+    pub fn create_instance_profile(
+        &self,
+        input: CreateInstanceProfileRequest,
+    ) -> Result<CreateInstanceProfileResponse, RusotoError<CreateInstanceProfileError>> {
+        self.runtime
+            .block_on(self.client.create_instance_profile(input))
+    }
+
+    /// Blocking version of [`Iam::create_login_profile`].
+    pub fn create_login_profile(
+        &self,
+        input: CreateLoginProfileRequest,
+    ) -> Result<CreateLoginProfileResponse, RusotoError<CreateLoginProfileError>> {
+        self.runtime
+            .block_on(self.client.create_login_profile(input))
+    }
+
+    /// Blocking version of [`Iam::create_open_id_connect_provider`].
+    pub fn create_open_id_connect_provider(
+        &self,
+        input: CreateOpenIDConnectProviderRequest,
+    ) -> Result<CreateOpenIDConnectProviderResponse, RusotoError<CreateOpenIDConnectProviderError>>
+    {
+        self.runtime
+            .block_on(self.client.create_open_id_connect_provider(input))
+    }
+
+    /// Blocking version of [`Iam::create_policy`].
+    pub fn create_policy(
+        &self,
+        input: CreatePolicyRequest,
+    ) -> Result<CreatePolicyResponse, RusotoError<CreatePolicyError>> {
+        self.runtime.block_on(self.client.create_policy(input))
+    }
+
+    /// Blocking version of [`Iam::create_policy_version`].
+    pub fn create_policy_version(
+        &self,
+        input: CreatePolicyVersionRequest,
+    ) -> Result<CreatePolicyVersionResponse, RusotoError<CreatePolicyVersionError>> {
+        self.runtime
+            .block_on(self.client.create_policy_version(input))
+    }
+
+    /// Blocking version of [`Iam::create_role`].
+    pub fn create_role(
+        &self,
+        input: CreateRoleRequest,
+    ) -> Result<CreateRoleResponse, RusotoError<CreateRoleError>> {
+        self.runtime.block_on(self.client.create_role(input))
+    }
+
+    /// Blocking version of [`Iam::create_saml_provider`].
+    pub fn create_saml_provider(
+        &self,
+        input: CreateSAMLProviderRequest,
+    ) -> Result<CreateSAMLProviderResponse, RusotoError<CreateSAMLProviderError>> {
+        self.runtime
+            .block_on(self.client.create_saml_provider(input))
+    }
+
+    /// Blocking version of [`Iam::create_service_linked_role`].
+    pub fn create_service_linked_role(
+        &self,
+        input: CreateServiceLinkedRoleRequest,
+    ) -> Result<CreateServiceLinkedRoleResponse, RusotoError<CreateServiceLinkedRoleError>> {
+        self.runtime
+            .block_on(self.client.create_service_linked_role(input))
+    }
+
+    /// Blocking version of [`Iam::create_service_specific_credential`].
+    pub fn create_service_specific_credential(
+        &self,
+        input: CreateServiceSpecificCredentialRequest,
+    ) -> Result<
+        CreateServiceSpecificCredentialResponse,
+        RusotoError<CreateServiceSpecificCredentialError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_service_specific_credential(input))
+    }
+
+    /// Blocking version of [`Iam::create_user`].
+    pub fn create_user(
+        &self,
+        input: CreateUserRequest,
+    ) -> Result<CreateUserResponse, RusotoError<CreateUserError>> {
+        self.runtime.block_on(self.client.create_user(input))
+    }
+
+    /// Blocking version of [`Iam::create_virtual_mfa_device`].
+    pub fn create_virtual_mfa_device(
+        &self,
+        input: CreateVirtualMFADeviceRequest,
+    ) -> Result<CreateVirtualMFADeviceResponse, RusotoError<CreateVirtualMFADeviceError>> {
+        self.runtime
+            .block_on(self.client.create_virtual_mfa_device(input))
+    }
+
+    /// Blocking version of [`Iam::deactivate_mfa_device`].
+    pub fn deactivate_mfa_device(
+        &self,
+        input: DeactivateMFADeviceRequest,
+    ) -> Result<(), RusotoError<DeactivateMFADeviceError>> {
+        self.runtime
+            .block_on(self.client.deactivate_mfa_device(input))
+    }
+
+    /// Blocking version of [`Iam::delete_access_key`].
+    pub fn delete_access_key(
+        &self,
+        input: DeleteAccessKeyRequest,
+    ) -> Result<(), RusotoError<DeleteAccessKeyError>> {
+        self.runtime.block_on(self.client.delete_access_key(input))
+    }
+
+    /// Blocking version of [`Iam::delete_account_alias`].
+    pub fn delete_account_alias(
+        &self,
+        input: DeleteAccountAliasRequest,
+    ) -> Result<(), RusotoError<DeleteAccountAliasError>> {
+        self.runtime
+            .block_on(self.client.delete_account_alias(input))
+    }
+
+    /// Blocking version of [`Iam::delete_account_password_policy`].
+    pub fn delete_account_password_policy(
+        &self,
+    ) -> Result<(), RusotoError<DeleteAccountPasswordPolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_account_password_policy())
+    }
+
+    /// Blocking version of [`Iam::delete_group`].
+    pub fn delete_group(
+        &self,
+        input: DeleteGroupRequest,
+    ) -> Result<(), RusotoError<DeleteGroupError>> {
+        self.runtime.block_on(self.client.delete_group(input))
+    }
+
+    /// Blocking version of [`Iam::delete_group_policy`].
+    pub fn delete_group_policy(
+        &self,
+        input: DeleteGroupPolicyRequest,
+    ) -> Result<(), RusotoError<DeleteGroupPolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_group_policy(input))
+    }
+
+    /// Blocking version of [`Iam::delete_instance_profile`].
+    pub fn delete_instance_profile(
+        &self,
+        input: DeleteInstanceProfileRequest,
+    ) -> Result<(), RusotoError<DeleteInstanceProfileError>> {
+        self.runtime
+            .block_on(self.client.delete_instance_profile(input))
+    }
+
+    /// Blocking version of [`Iam::delete_login_profile`].
+    pub fn delete_login_profile(
+        &self,
+        input: DeleteLoginProfileRequest,
+    ) -> Result<(), RusotoError<DeleteLoginProfileError>> {
+        self.runtime
+            .block_on(self.client.delete_login_profile(input))
+    }
+
+    /// Blocking version of [`Iam::delete_open_id_connect_provider`].
+    pub fn delete_open_id_connect_provider(
+        &self,
+        input: DeleteOpenIDConnectProviderRequest,
+    ) -> Result<(), RusotoError<DeleteOpenIDConnectProviderError>> {
+        self.runtime
+            .block_on(self.client.delete_open_id_connect_provider(input))
+    }
+
+    /// Blocking version of [`Iam::delete_policy`].
+    pub fn delete_policy(
+        &self,
+        input: DeletePolicyRequest,
+    ) -> Result<(), RusotoError<DeletePolicyError>> {
+        self.runtime.block_on(self.client.delete_policy(input))
+    }
+
+    /// Blocking version of [`Iam::delete_policy_version`].
+    pub fn delete_policy_version(
+        &self,
+        input: DeletePolicyVersionRequest,
+    ) -> Result<(), RusotoError<DeletePolicyVersionError>> {
+        self.runtime
+            .block_on(self.client.delete_policy_version(input))
+    }
+
+    /// Blocking version of [`Iam::delete_role`].
+    pub fn delete_role(
+        &self,
+        input: DeleteRoleRequest,
+    ) -> Result<(), RusotoError<DeleteRoleError>> {
+        self.runtime.block_on(self.client.delete_role(input))
+    }
+
+    /// Blocking version of [`Iam::delete_role_permissions_boundary`].
+    pub fn delete_role_permissions_boundary(
+        &self,
+        input: DeleteRolePermissionsBoundaryRequest,
+    ) -> Result<(), RusotoError<DeleteRolePermissionsBoundaryError>> {
+        self.runtime
+            .block_on(self.client.delete_role_permissions_boundary(input))
+    }
+
+    /// Blocking version of [`Iam::delete_role_policy`].
+    pub fn delete_role_policy(
+        &self,
+        input: DeleteRolePolicyRequest,
+    ) -> Result<(), RusotoError<DeleteRolePolicyError>> {
+        self.runtime.block_on(self.client.delete_role_policy(input))
+    }
+
+    /// Blocking version of [`Iam::delete_saml_provider`].
+    pub fn delete_saml_provider(
+        &self,
+        input: DeleteSAMLProviderRequest,
+    ) -> Result<(), RusotoError<DeleteSAMLProviderError>> {
+        self.runtime
+            .block_on(self.client.delete_saml_provider(input))
+    }
+
+    /// Blocking version of [`Iam::delete_ssh_public_key`].
+    pub fn delete_ssh_public_key(
+        &self,
+        input: DeleteSSHPublicKeyRequest,
+    ) -> Result<(), RusotoError<DeleteSSHPublicKeyError>> {
+        self.runtime
+            .block_on(self.client.delete_ssh_public_key(input))
+    }
+
+    /// Blocking version of [`Iam::delete_server_certificate`].
+    pub fn delete_server_certificate(
+        &self,
+        input: DeleteServerCertificateRequest,
+    ) -> Result<(), RusotoError<DeleteServerCertificateError>> {
+        self.runtime
+            .block_on(self.client.delete_server_certificate(input))
+    }
+
+    /// Blocking version of [`Iam::delete_service_linked_role`].
+    pub fn delete_service_linked_role(
+        &self,
+        input: DeleteServiceLinkedRoleRequest,
+    ) -> Result<DeleteServiceLinkedRoleResponse, RusotoError<DeleteServiceLinkedRoleError>> {
+        self.runtime
+            .block_on(self.client.delete_service_linked_role(input))
+    }
+
+    /// Blocking version of [`Iam::delete_service_specific_credential`].
+    pub fn delete_service_specific_credential(
+        &self,
+        input: DeleteServiceSpecificCredentialRequest,
+    ) -> Result<(), RusotoError<DeleteServiceSpecificCredentialError>> {
+        self.runtime
+            .block_on(self.client.delete_service_specific_credential(input))
+    }
+
+    /// Blocking version of [`Iam::delete_signing_certificate`].
+    pub fn delete_signing_certificate(
+        &self,
+        input: DeleteSigningCertificateRequest,
+    ) -> Result<(), RusotoError<DeleteSigningCertificateError>> {
+        self.runtime
+            .block_on(self.client.delete_signing_certificate(input))
+    }
+
+    /// Blocking version of [`Iam::delete_user`].
+    pub fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> Result<(), RusotoError<DeleteUserError>> {
+        self.runtime.block_on(self.client.delete_user(input))
+    }
+
+    /// Blocking version of [`Iam::delete_user_permissions_boundary`].
+    pub fn delete_user_permissions_boundary(
+        &self,
+        input: DeleteUserPermissionsBoundaryRequest,
+    ) -> Result<(), RusotoError<DeleteUserPermissionsBoundaryError>> {
+        self.runtime
+            .block_on(self.client.delete_user_permissions_boundary(input))
+    }
+
+    /// Blocking version of [`Iam::delete_user_policy`].
+    pub fn delete_user_policy(
+        &self,
+        input: DeleteUserPolicyRequest,
+    ) -> Result<(), RusotoError<DeleteUserPolicyError>> {
+        self.runtime.block_on(self.client.delete_user_policy(input))
+    }
+
+    /// Blocking version of [`Iam::delete_virtual_mfa_device`].
+    pub fn delete_virtual_mfa_device(
+        &self,
+        input: DeleteVirtualMFADeviceRequest,
+    ) -> Result<(), RusotoError<DeleteVirtualMFADeviceError>> {
+        self.runtime
+            .block_on(self.client.delete_virtual_mfa_device(input))
+    }
+
+    /// Blocking version of [`Iam::detach_group_policy`].
+    pub fn detach_group_policy(
+        &self,
+        input: DetachGroupPolicyRequest,
+    ) -> Result<(), RusotoError<DetachGroupPolicyError>> {
+        self.runtime
+            .block_on(self.client.detach_group_policy(input))
+    }
+
+    /// Blocking version of [`Iam::detach_role_policy`].
+    pub fn detach_role_policy(
+        &self,
+        input: DetachRolePolicyRequest,
+    ) -> Result<(), RusotoError<DetachRolePolicyError>> {
+        self.runtime.block_on(self.client.detach_role_policy(input))
+    }
+
+    /// Blocking version of [`Iam::detach_user_policy`].
+    pub fn detach_user_policy(
+        &self,
+        input: DetachUserPolicyRequest,
+    ) -> Result<(), RusotoError<DetachUserPolicyError>> {
+        self.runtime.block_on(self.client.detach_user_policy(input))
+    }
+
+    /// Blocking version of [`Iam::enable_mfa_device`].
+    pub fn enable_mfa_device(
+        &self,
+        input: EnableMFADeviceRequest,
+    ) -> Result<(), RusotoError<EnableMFADeviceError>> {
+        self.runtime.block_on(self.client.enable_mfa_device(input))
+    }
+
+    /// Blocking version of [`Iam::generate_credential_report`].
+    pub fn generate_credential_report(
+        &self,
+    ) -> Result<GenerateCredentialReportResponse, RusotoError<GenerateCredentialReportError>> {
+        self.runtime
+            .block_on(self.client.generate_credential_report())
+    }
+
+    /// Blocking version of [`Iam::generate_organizations_access_report`].
+    pub fn generate_organizations_access_report(
+        &self,
+        input: GenerateOrganizationsAccessReportRequest,
+    ) -> Result<
+        GenerateOrganizationsAccessReportResponse,
+        RusotoError<GenerateOrganizationsAccessReportError>,
+    > {
+        self.runtime
+            .block_on(self.client.generate_organizations_access_report(input))
+    }
+
+    /// Blocking version of [`Iam::generate_service_last_accessed_details`].
+    pub fn generate_service_last_accessed_details(
+        &self,
+        input: GenerateServiceLastAccessedDetailsRequest,
+    ) -> Result<
+        GenerateServiceLastAccessedDetailsResponse,
+        RusotoError<GenerateServiceLastAccessedDetailsError>,
+    > {
+        self.runtime
+            .block_on(self.client.generate_service_last_accessed_details(input))
+    }
+
+    /// Blocking version of [`Iam::get_access_key_last_used`].
+    pub fn get_access_key_last_used(
+        &self,
+        input: GetAccessKeyLastUsedRequest,
+    ) -> Result<GetAccessKeyLastUsedResponse, RusotoError<GetAccessKeyLastUsedError>> {
+        self.runtime
+            .block_on(self.client.get_access_key_last_used(input))
+    }
+
+    /// Blocking version of [`Iam::get_account_authorization_details`].
+    pub fn get_account_authorization_details(
+        &self,
+        input: GetAccountAuthorizationDetailsRequest,
+    ) -> Result<
+        GetAccountAuthorizationDetailsResponse,
+        RusotoError<GetAccountAuthorizationDetailsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_account_authorization_details(input))
+    }
+
+    /// Blocking version of [`Iam::get_account_password_policy`].
+    pub fn get_account_password_policy(
+        &self,
+    ) -> Result<GetAccountPasswordPolicyResponse, RusotoError<GetAccountPasswordPolicyError>> {
+        self.runtime
+            .block_on(self.client.get_account_password_policy())
+    }
+
+    /// Blocking version of [`Iam::get_account_summary`].
+    pub fn get_account_summary(
+        &self,
+    ) -> Result<GetAccountSummaryResponse, RusotoError<GetAccountSummaryError>> {
+        self.runtime.block_on(self.client.get_account_summary())
+    }
+
+    /// Blocking version of [`Iam::get_context_keys_for_custom_policy`].
+    pub fn get_context_keys_for_custom_policy(
+        &self,
+        input: GetContextKeysForCustomPolicyRequest,
+    ) -> Result<GetContextKeysForPolicyResponse, RusotoError<GetContextKeysForCustomPolicyError>>
+    {
+        self.runtime
+            .block_on(self.client.get_context_keys_for_custom_policy(input))
+    }
+
+    /// Blocking version of [`Iam::get_context_keys_for_principal_policy`].
+    pub fn get_context_keys_for_principal_policy(
+        &self,
+        input: GetContextKeysForPrincipalPolicyRequest,
+    ) -> Result<GetContextKeysForPolicyResponse, RusotoError<GetContextKeysForPrincipalPolicyError>>
+    {
+        self.runtime
+            .block_on(self.client.get_context_keys_for_principal_policy(input))
+    }
+
+    /// Blocking version of [`Iam::get_credential_report`].
+    pub fn get_credential_report(
+        &self,
+    ) -> Result<GetCredentialReportResponse, RusotoError<GetCredentialReportError>> {
+        self.runtime.block_on(self.client.get_credential_report())
+    }
+
+    /// Blocking version of [`Iam::get_group`].
+    pub fn get_group(
+        &self,
+        input: GetGroupRequest,
+    ) -> Result<GetGroupResponse, RusotoError<GetGroupError>> {
+        self.runtime.block_on(self.client.get_group(input))
+    }
+
+    /// Blocking version of [`Iam::get_group_policy`].
+    pub fn get_group_policy(
+        &self,
+        input: GetGroupPolicyRequest,
+    ) -> Result<GetGroupPolicyResponse, RusotoError<GetGroupPolicyError>> {
+        self.runtime.block_on(self.client.get_group_policy(input))
+    }
+
+    /// Blocking version of [`Iam::get_instance_profile`].
+    pub fn get_instance_profile(
+        &self,
+        input: GetInstanceProfileRequest,
+    ) -> Result<GetInstanceProfileResponse, RusotoError<GetInstanceProfileError>> {
+        self.runtime
+            .block_on(self.client.get_instance_profile(input))
+    }
+
+    /// Blocking version of [`Iam::get_login_profile`].
+    pub fn get_login_profile(
+        &self,
+        input: GetLoginProfileRequest,
+    ) -> Result<GetLoginProfileResponse, RusotoError<GetLoginProfileError>> {
+        self.runtime.block_on(self.client.get_login_profile(input))
+    }
+
+    /// Blocking version of [`Iam::get_open_id_connect_provider`].
+    pub fn get_open_id_connect_provider(
+        &self,
+        input: GetOpenIDConnectProviderRequest,
+    ) -> Result<GetOpenIDConnectProviderResponse, RusotoError<GetOpenIDConnectProviderError>> {
+        self.runtime
+            .block_on(self.client.get_open_id_connect_provider(input))
+    }
+
+    /// Blocking version of [`Iam::get_organizations_access_report`].
+    pub fn get_organizations_access_report(
+        &self,
+        input: GetOrganizationsAccessReportRequest,
+    ) -> Result<GetOrganizationsAccessReportResponse, RusotoError<GetOrganizationsAccessReportError>>
+    {
+        self.runtime
+            .block_on(self.client.get_organizations_access_report(input))
+    }
+
+    /// Blocking version of [`Iam::get_policy`].
+    pub fn get_policy(
+        &self,
+        input: GetPolicyRequest,
+    ) -> Result<GetPolicyResponse, RusotoError<GetPolicyError>> {
+        self.runtime.block_on(self.client.get_policy(input))
+    }
+
+    /// Blocking version of [`Iam::get_policy_version`].
+    pub fn get_policy_version(
+        &self,
+        input: GetPolicyVersionRequest,
+    ) -> Result<GetPolicyVersionResponse, RusotoError<GetPolicyVersionError>> {
+        self.runtime.block_on(self.client.get_policy_version(input))
+    }
+
+    /// Blocking version of [`Iam::get_role`].
+    pub fn get_role(
+        &self,
+        input: GetRoleRequest,
+    ) -> Result<GetRoleResponse, RusotoError<GetRoleError>> {
+        self.runtime.block_on(self.client.get_role(input))
+    }
+
+    /// Blocking version of [`Iam::get_role_policy`].
+    pub fn get_role_policy(
+        &self,
+        input: GetRolePolicyRequest,
+    ) -> Result<GetRolePolicyResponse, RusotoError<GetRolePolicyError>> {
+        self.runtime.block_on(self.client.get_role_policy(input))
+    }
+
+    /// Blocking version of [`Iam::get_saml_provider`].
+    pub fn get_saml_provider(
+        &self,
+        input: GetSAMLProviderRequest,
+    ) -> Result<GetSAMLProviderResponse, RusotoError<GetSAMLProviderError>> {
+        self.runtime.block_on(self.client.get_saml_provider(input))
+    }
+
+    /// Blocking version of [`Iam::get_ssh_public_key`].
+    pub fn get_ssh_public_key(
+        &self,
+        input: GetSSHPublicKeyRequest,
+    ) -> Result<GetSSHPublicKeyResponse, RusotoError<GetSSHPublicKeyError>> {
+        self.runtime.block_on(self.client.get_ssh_public_key(input))
+    }
+
+    /// Blocking version of [`Iam::get_server_certificate`].
+    pub fn get_server_certificate(
+        &self,
+        input: GetServerCertificateRequest,
+    ) -> Result<GetServerCertificateResponse, RusotoError<GetServerCertificateError>> {
+        self.runtime
+            .block_on(self.client.get_server_certificate(input))
+    }
+
+    /// Blocking version of [`Iam::get_service_last_accessed_details`].
+    pub fn get_service_last_accessed_details(
+        &self,
+        input: GetServiceLastAccessedDetailsRequest,
+    ) -> Result<
+        GetServiceLastAccessedDetailsResponse,
+        RusotoError<GetServiceLastAccessedDetailsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_service_last_accessed_details(input))
+    }
+
+    /// Blocking version of [`Iam::get_service_last_accessed_details_with_entities`].
+    pub fn get_service_last_accessed_details_with_entities(
+        &self,
+        input: GetServiceLastAccessedDetailsWithEntitiesRequest,
+    ) -> Result<
+        GetServiceLastAccessedDetailsWithEntitiesResponse,
+        RusotoError<GetServiceLastAccessedDetailsWithEntitiesError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .get_service_last_accessed_details_with_entities(input),
+        )
+    }
+
+    /// Blocking version of [`Iam::get_service_linked_role_deletion_status`].
+    pub fn get_service_linked_role_deletion_status(
+        &self,
+        input: GetServiceLinkedRoleDeletionStatusRequest,
+    ) -> Result<
+        GetServiceLinkedRoleDeletionStatusResponse,
+        RusotoError<GetServiceLinkedRoleDeletionStatusError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_service_linked_role_deletion_status(input))
+    }
+
+    /// Blocking version of [`Iam::get_user`].
+    pub fn get_user(
+        &self,
+        input: GetUserRequest,
+    ) -> Result<GetUserResponse, RusotoError<GetUserError>> {
+        self.runtime.block_on(self.client.get_user(input))
+    }
+
+    /// Blocking version of [`Iam::get_user_policy`].
+    pub fn get_user_policy(
+        &self,
+        input: GetUserPolicyRequest,
+    ) -> Result<GetUserPolicyResponse, RusotoError<GetUserPolicyError>> {
+        self.runtime.block_on(self.client.get_user_policy(input))
+    }
+
+    /// Blocking version of [`Iam::list_access_keys`].
+    pub fn list_access_keys(
+        &self,
+        input: ListAccessKeysRequest,
+    ) -> Result<ListAccessKeysResponse, RusotoError<ListAccessKeysError>> {
+        self.runtime.block_on(self.client.list_access_keys(input))
+    }
+
+    /// Blocking version of [`Iam::list_account_aliases`].
+    pub fn list_account_aliases(
+        &self,
+        input: ListAccountAliasesRequest,
+    ) -> Result<ListAccountAliasesResponse, RusotoError<ListAccountAliasesError>> {
+        self.runtime
+            .block_on(self.client.list_account_aliases(input))
+    }
+
+    /// Blocking version of [`Iam::list_attached_group_policies`].
+    pub fn list_attached_group_policies(
+        &self,
+        input: ListAttachedGroupPoliciesRequest,
+    ) -> Result<ListAttachedGroupPoliciesResponse, RusotoError<ListAttachedGroupPoliciesError>>
+    {
+        self.runtime
+            .block_on(self.client.list_attached_group_policies(input))
+    }
+
+    /// Blocking version of [`Iam::list_attached_role_policies`].
+    pub fn list_attached_role_policies(
+        &self,
+        input: ListAttachedRolePoliciesRequest,
+    ) -> Result<ListAttachedRolePoliciesResponse, RusotoError<ListAttachedRolePoliciesError>> {
+        self.runtime
+            .block_on(self.client.list_attached_role_policies(input))
+    }
+
+    /// Blocking version of [`Iam::list_attached_user_policies`].
+    pub fn list_attached_user_policies(
+        &self,
+        input: ListAttachedUserPoliciesRequest,
+    ) -> Result<ListAttachedUserPoliciesResponse, RusotoError<ListAttachedUserPoliciesError>> {
+        self.runtime
+            .block_on(self.client.list_attached_user_policies(input))
+    }
+
+    /// Blocking version of [`Iam::list_entities_for_policy`].
+    pub fn list_entities_for_policy(
+        &self,
+        input: ListEntitiesForPolicyRequest,
+    ) -> Result<ListEntitiesForPolicyResponse, RusotoError<ListEntitiesForPolicyError>> {
+        self.runtime
+            .block_on(self.client.list_entities_for_policy(input))
+    }
+
+    /// Blocking version of [`Iam::list_group_policies`].
+    pub fn list_group_policies(
+        &self,
+        input: ListGroupPoliciesRequest,
+    ) -> Result<ListGroupPoliciesResponse, RusotoError<ListGroupPoliciesError>> {
+        self.runtime
+            .block_on(self.client.list_group_policies(input))
+    }
+
+    /// Blocking version of [`Iam::list_groups`].
+    pub fn list_groups(
+        &self,
+        input: ListGroupsRequest,
+    ) -> Result<ListGroupsResponse, RusotoError<ListGroupsError>> {
+        self.runtime.block_on(self.client.list_groups(input))
+    }
+
+    /// Blocking version of [`Iam::list_groups_for_user`].
+    pub fn list_groups_for_user(
+        &self,
+        input: ListGroupsForUserRequest,
+    ) -> Result<ListGroupsForUserResponse, RusotoError<ListGroupsForUserError>> {
+        self.runtime
+            .block_on(self.client.list_groups_for_user(input))
+    }
+
+    /// Blocking version of [`Iam::list_instance_profiles`].
+    pub fn list_instance_profiles(
+        &self,
+        input: ListInstanceProfilesRequest,
+    ) -> Result<ListInstanceProfilesResponse, RusotoError<ListInstanceProfilesError>> {
+        self.runtime
+            .block_on(self.client.list_instance_profiles(input))
+    }
+
+    /// Blocking version of [`Iam::list_instance_profiles_for_role`].
+    pub fn list_instance_profiles_for_role(
+        &self,
+        input: ListInstanceProfilesForRoleRequest,
+    ) -> Result<ListInstanceProfilesForRoleResponse, RusotoError<ListInstanceProfilesForRoleError>>
+    {
+        self.runtime
+            .block_on(self.client.list_instance_profiles_for_role(input))
+    }
+
+    /// Blocking version of [`Iam::list_mfa_devices`].
+    pub fn list_mfa_devices(
+        &self,
+        input: ListMFADevicesRequest,
+    ) -> Result<ListMFADevicesResponse, RusotoError<ListMFADevicesError>> {
+        self.runtime.block_on(self.client.list_mfa_devices(input))
+    }
+
+    /// Blocking version of [`Iam::list_open_id_connect_providers`].
+    pub fn list_open_id_connect_providers(
+        &self,
+        input: ListOpenIDConnectProvidersRequest,
+    ) -> Result<ListOpenIDConnectProvidersResponse, RusotoError<ListOpenIDConnectProvidersError>>
+    {
+        self.runtime
+            .block_on(self.client.list_open_id_connect_providers(input))
+    }
+
+    /// Blocking version of [`Iam::list_policies`].
+    pub fn list_policies(
+        &self,
+        input: ListPoliciesRequest,
+    ) -> Result<ListPoliciesResponse, RusotoError<ListPoliciesError>> {
+        self.runtime.block_on(self.client.list_policies(input))
+    }
+
+    /// Blocking version of [`Iam::list_policies_granting_service_access`].
+    pub fn list_policies_granting_service_access(
+        &self,
+        input: ListPoliciesGrantingServiceAccessRequest,
+    ) -> Result<
+        ListPoliciesGrantingServiceAccessResponse,
+        RusotoError<ListPoliciesGrantingServiceAccessError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_policies_granting_service_access(input))
+    }
+
+    /// Blocking version of [`Iam::list_policy_versions`].
+    pub fn list_policy_versions(
+        &self,
+        input: ListPolicyVersionsRequest,
+    ) -> Result<ListPolicyVersionsResponse, RusotoError<ListPolicyVersionsError>> {
+        self.runtime
+            .block_on(self.client.list_policy_versions(input))
+    }
+
+    /// Blocking version of [`Iam::list_role_policies`].
+    pub fn list_role_policies(
+        &self,
+        input: ListRolePoliciesRequest,
+    ) -> Result<ListRolePoliciesResponse, RusotoError<ListRolePoliciesError>> {
+        self.runtime.block_on(self.client.list_role_policies(input))
+    }
+
+    /// Blocking version of [`Iam::list_role_tags`].
+    pub fn list_role_tags(
+        &self,
+        input: ListRoleTagsRequest,
+    ) -> Result<ListRoleTagsResponse, RusotoError<ListRoleTagsError>> {
+        self.runtime.block_on(self.client.list_role_tags(input))
+    }
+
+    /// Blocking version of [`Iam::list_roles`].
+    pub fn list_roles(
+        &self,
+        input: ListRolesRequest,
+    ) -> Result<ListRolesResponse, RusotoError<ListRolesError>> {
+        self.runtime.block_on(self.client.list_roles(input))
+    }
+
+    /// Blocking version of [`Iam::list_saml_providers`].
+    pub fn list_saml_providers(
+        &self,
+        input: ListSAMLProvidersRequest,
+    ) -> Result<ListSAMLProvidersResponse, RusotoError<ListSAMLProvidersError>> {
+        self.runtime
+            .block_on(self.client.list_saml_providers(input))
+    }
+
+    /// Blocking version of [`Iam::list_ssh_public_keys`].
+    pub fn list_ssh_public_keys(
+        &self,
+        input: ListSSHPublicKeysRequest,
+    ) -> Result<ListSSHPublicKeysResponse, RusotoError<ListSSHPublicKeysError>> {
+        self.runtime
+            .block_on(self.client.list_ssh_public_keys(input))
+    }
+
+    /// Blocking version of [`Iam::list_server_certificates`].
+    pub fn list_server_certificates(
+        &self,
+        input: ListServerCertificatesRequest,
+    ) -> Result<ListServerCertificatesResponse, RusotoError<ListServerCertificatesError>> {
+        self.runtime
+            .block_on(self.client.list_server_certificates(input))
+    }
+
+    /// Blocking version of [`Iam::list_service_specific_credentials`].
+    pub fn list_service_specific_credentials(
+        &self,
+        input: ListServiceSpecificCredentialsRequest,
+    ) -> Result<
+        ListServiceSpecificCredentialsResponse,
+        RusotoError<ListServiceSpecificCredentialsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_service_specific_credentials(input))
+    }
+
+    /// Blocking version of [`Iam::list_signing_certificates`].
+    pub fn list_signing_certificates(
+        &self,
+        input: ListSigningCertificatesRequest,
+    ) -> Result<ListSigningCertificatesResponse, RusotoError<ListSigningCertificatesError>> {
+        self.runtime
+            .block_on(self.client.list_signing_certificates(input))
+    }
+
+    /// Blocking version of [`Iam::list_user_policies`].
+    pub fn list_user_policies(
+        &self,
+        input: ListUserPoliciesRequest,
+    ) -> Result<ListUserPoliciesResponse, RusotoError<ListUserPoliciesError>> {
+        self.runtime.block_on(self.client.list_user_policies(input))
+    }
+
+    /// Blocking version of [`Iam::list_user_tags`].
+    pub fn list_user_tags(
+        &self,
+        input: ListUserTagsRequest,
+    ) -> Result<ListUserTagsResponse, RusotoError<ListUserTagsError>> {
+        self.runtime.block_on(self.client.list_user_tags(input))
+    }
+
+    /// Blocking version of [`Iam::list_users`].
+    pub fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> Result<ListUsersResponse, RusotoError<ListUsersError>> {
+        self.runtime.block_on(self.client.list_users(input))
+    }
+
+    /// Blocking version of [`Iam::list_virtual_mfa_devices`].
+    pub fn list_virtual_mfa_devices(
+        &self,
+        input: ListVirtualMFADevicesRequest,
+    ) -> Result<ListVirtualMFADevicesResponse, RusotoError<ListVirtualMFADevicesError>> {
+        self.runtime
+            .block_on(self.client.list_virtual_mfa_devices(input))
+    }
+
+    /// Blocking version of [`Iam::put_group_policy`].
+    pub fn put_group_policy(
+        &self,
+        input: PutGroupPolicyRequest,
+    ) -> Result<(), RusotoError<PutGroupPolicyError>> {
+        self.runtime.block_on(self.client.put_group_policy(input))
+    }
+
+    /// Blocking version of [`Iam::put_role_permissions_boundary`].
+    pub fn put_role_permissions_boundary(
+        &self,
+        input: PutRolePermissionsBoundaryRequest,
+    ) -> Result<(), RusotoError<PutRolePermissionsBoundaryError>> {
+        self.runtime
+            .block_on(self.client.put_role_permissions_boundary(input))
+    }
+
+    /// Blocking version of [`Iam::put_role_policy`].
+    pub fn put_role_policy(
+        &self,
+        input: PutRolePolicyRequest,
+    ) -> Result<(), RusotoError<PutRolePolicyError>> {
+        self.runtime.block_on(self.client.put_role_policy(input))
+    }
+
+    /// Blocking version of [`Iam::put_user_permissions_boundary`].
+    pub fn put_user_permissions_boundary(
+        &self,
+        input: PutUserPermissionsBoundaryRequest,
+    ) -> Result<(), RusotoError<PutUserPermissionsBoundaryError>> {
+        self.runtime
+            .block_on(self.client.put_user_permissions_boundary(input))
+    }
+
+    /// Blocking version of [`Iam::put_user_policy`].
+    pub fn put_user_policy(
+        &self,
+        input: PutUserPolicyRequest,
+    ) -> Result<(), RusotoError<PutUserPolicyError>> {
+        self.runtime.block_on(self.client.put_user_policy(input))
+    }
+
+    /// Blocking version of [`Iam::remove_client_id_from_open_id_connect_provider`].
+    pub fn remove_client_id_from_open_id_connect_provider(
+        &self,
+        input: RemoveClientIDFromOpenIDConnectProviderRequest,
+    ) -> Result<(), RusotoError<RemoveClientIDFromOpenIDConnectProviderError>> {
+        self.runtime.block_on(
+            self.client
+                .remove_client_id_from_open_id_connect_provider(input),
+        )
+    }
+
+    /// Blocking version of [`Iam::remove_role_from_instance_profile`].
+    pub fn remove_role_from_instance_profile(
+        &self,
+        input: RemoveRoleFromInstanceProfileRequest,
+    ) -> Result<(), RusotoError<RemoveRoleFromInstanceProfileError>> {
+        self.runtime
+            .block_on(self.client.remove_role_from_instance_profile(input))
+    }
+
+    /// Blocking version of [`Iam::remove_user_from_group`].
+    pub fn remove_user_from_group(
+        &self,
+        input: RemoveUserFromGroupRequest,
+    ) -> Result<(), RusotoError<RemoveUserFromGroupError>> {
+        self.runtime
+            .block_on(self.client.remove_user_from_group(input))
+    }
+
+    /// Blocking version of [`Iam::reset_service_specific_credential`].
+    pub fn reset_service_specific_credential(
+        &self,
+        input: ResetServiceSpecificCredentialRequest,
+    ) -> Result<
+        ResetServiceSpecificCredentialResponse,
+        RusotoError<ResetServiceSpecificCredentialError>,
+    > {
+        self.runtime
+            .block_on(self.client.reset_service_specific_credential(input))
+    }
+
+    /// Blocking version of [`Iam::resync_mfa_device`].
+    pub fn resync_mfa_device(
+        &self,
+        input: ResyncMFADeviceRequest,
+    ) -> Result<(), RusotoError<ResyncMFADeviceError>> {
+        self.runtime.block_on(self.client.resync_mfa_device(input))
+    }
+
+    /// Blocking version of [`Iam::set_default_policy_version`].
+    pub fn set_default_policy_version(
+        &self,
+        input: SetDefaultPolicyVersionRequest,
+    ) -> Result<(), RusotoError<SetDefaultPolicyVersionError>> {
+        self.runtime
+            .block_on(self.client.set_default_policy_version(input))
+    }
+
+    /// Blocking version of [`Iam::set_security_token_service_preferences`].
+    pub fn set_security_token_service_preferences(
+        &self,
+        input: SetSecurityTokenServicePreferencesRequest,
+    ) -> Result<(), RusotoError<SetSecurityTokenServicePreferencesError>> {
+        self.runtime
+            .block_on(self.client.set_security_token_service_preferences(input))
+    }
+
+    /// Blocking version of [`Iam::simulate_custom_policy`].
+    pub fn simulate_custom_policy(
+        &self,
+        input: SimulateCustomPolicyRequest,
+    ) -> Result<SimulatePolicyResponse, RusotoError<SimulateCustomPolicyError>> {
+        self.runtime
+            .block_on(self.client.simulate_custom_policy(input))
+    }
+
+    /// Blocking version of [`Iam::simulate_principal_policy`].
+    pub fn simulate_principal_policy(
+        &self,
+        input: SimulatePrincipalPolicyRequest,
+    ) -> Result<SimulatePolicyResponse, RusotoError<SimulatePrincipalPolicyError>> {
+        self.runtime
+            .block_on(self.client.simulate_principal_policy(input))
+    }
+
+    /// Blocking version of [`Iam::tag_role`].
+    pub fn tag_role(&self, input: TagRoleRequest) -> Result<(), RusotoError<TagRoleError>> {
+        self.runtime.block_on(self.client.tag_role(input))
+    }
+
+    /// Blocking version of [`Iam::tag_user`].
+    pub fn tag_user(&self, input: TagUserRequest) -> Result<(), RusotoError<TagUserError>> {
+        self.runtime.block_on(self.client.tag_user(input))
+    }
+
+    /// Blocking version of [`Iam::untag_role`].
+    pub fn untag_role(&self, input: UntagRoleRequest) -> Result<(), RusotoError<UntagRoleError>> {
+        self.runtime.block_on(self.client.untag_role(input))
+    }
+
+    /// Blocking version of [`Iam::untag_user`].
+    pub fn untag_user(&self, input: UntagUserRequest) -> Result<(), RusotoError<UntagUserError>> {
+        self.runtime.block_on(self.client.untag_user(input))
+    }
+
+    /// Blocking version of [`Iam::update_access_key`].
+    pub fn update_access_key(
+        &self,
+        input: UpdateAccessKeyRequest,
+    ) -> Result<(), RusotoError<UpdateAccessKeyError>> {
+        self.runtime.block_on(self.client.update_access_key(input))
+    }
+
+    /// Blocking version of [`Iam::update_account_password_policy`].
+    pub fn update_account_password_policy(
+        &self,
+        input: UpdateAccountPasswordPolicyRequest,
+    ) -> Result<(), RusotoError<UpdateAccountPasswordPolicyError>> {
+        self.runtime
+            .block_on(self.client.update_account_password_policy(input))
+    }
+
+    /// Blocking version of [`Iam::update_assume_role_policy`].
+    pub fn update_assume_role_policy(
+        &self,
+        input: UpdateAssumeRolePolicyRequest,
+    ) -> Result<(), RusotoError<UpdateAssumeRolePolicyError>> {
+        self.runtime
+            .block_on(self.client.update_assume_role_policy(input))
+    }
+
+    /// Blocking version of [`Iam::update_group`].
+    pub fn update_group(
+        &self,
+        input: UpdateGroupRequest,
+    ) -> Result<(), RusotoError<UpdateGroupError>> {
+        self.runtime.block_on(self.client.update_group(input))
+    }
+
+    /// Blocking version of [`Iam::update_login_profile`].
+    pub fn update_login_profile(
+        &self,
+        input: UpdateLoginProfileRequest,
+    ) -> Result<(), RusotoError<UpdateLoginProfileError>> {
+        self.runtime
+            .block_on(self.client.update_login_profile(input))
+    }
+
+    /// Blocking version of [`Iam::update_open_id_connect_provider_thumbprint`].
+    pub fn update_open_id_connect_provider_thumbprint(
+        &self,
+        input: UpdateOpenIDConnectProviderThumbprintRequest,
+    ) -> Result<(), RusotoError<UpdateOpenIDConnectProviderThumbprintError>> {
+        self.runtime.block_on(
+            self.client
+                .update_open_id_connect_provider_thumbprint(input),
+        )
+    }
+
+    /// Blocking version of [`Iam::update_role`].
+    pub fn update_role(
+        &self,
+        input: UpdateRoleRequest,
+    ) -> Result<UpdateRoleResponse, RusotoError<UpdateRoleError>> {
+        self.runtime.block_on(self.client.update_role(input))
+    }
+
+    /// Blocking version of [`Iam::update_role_description`].
+    pub fn update_role_description(
+        &self,
+        input: UpdateRoleDescriptionRequest,
+    ) -> Result<UpdateRoleDescriptionResponse, RusotoError<UpdateRoleDescriptionError>> {
+        self.runtime
+            .block_on(self.client.update_role_description(input))
+    }
+
+    /// Blocking version of [`Iam::update_saml_provider`].
+    pub fn update_saml_provider(
+        &self,
+        input: UpdateSAMLProviderRequest,
+    ) -> Result<UpdateSAMLProviderResponse, RusotoError<UpdateSAMLProviderError>> {
+        self.runtime
+            .block_on(self.client.update_saml_provider(input))
+    }
+
+    /// Blocking version of [`Iam::update_ssh_public_key`].
+    pub fn update_ssh_public_key(
+        &self,
+        input: UpdateSSHPublicKeyRequest,
+    ) -> Result<(), RusotoError<UpdateSSHPublicKeyError>> {
+        self.runtime
+            .block_on(self.client.update_ssh_public_key(input))
+    }
+
+    /// Blocking version of [`Iam::update_server_certificate`].
+    pub fn update_server_certificate(
+        &self,
+        input: UpdateServerCertificateRequest,
+    ) -> Result<(), RusotoError<UpdateServerCertificateError>> {
+        self.runtime
+            .block_on(self.client.update_server_certificate(input))
+    }
+
+    /// Blocking version of [`Iam::update_service_specific_credential`].
+    pub fn update_service_specific_credential(
+        &self,
+        input: UpdateServiceSpecificCredentialRequest,
+    ) -> Result<(), RusotoError<UpdateServiceSpecificCredentialError>> {
+        self.runtime
+            .block_on(self.client.update_service_specific_credential(input))
+    }
+
+    /// Blocking version of [`Iam::update_signing_certificate`].
+    pub fn update_signing_certificate(
+        &self,
+        input: UpdateSigningCertificateRequest,
+    ) -> Result<(), RusotoError<UpdateSigningCertificateError>> {
+        self.runtime
+            .block_on(self.client.update_signing_certificate(input))
+    }
+
+    /// Blocking version of [`Iam::update_user`].
+    pub fn update_user(
+        &self,
+        input: UpdateUserRequest,
+    ) -> Result<(), RusotoError<UpdateUserError>> {
+        self.runtime.block_on(self.client.update_user(input))
+    }
+
+    /// Blocking version of [`Iam::upload_ssh_public_key`].
+    pub fn upload_ssh_public_key(
+        &self,
+        input: UploadSSHPublicKeyRequest,
+    ) -> Result<UploadSSHPublicKeyResponse, RusotoError<UploadSSHPublicKeyError>> {
+        self.runtime
+            .block_on(self.client.upload_ssh_public_key(input))
+    }
+
+    /// Blocking version of [`Iam::upload_server_certificate`].
+    pub fn upload_server_certificate(
+        &self,
+        input: UploadServerCertificateRequest,
+    ) -> Result<UploadServerCertificateResponse, RusotoError<UploadServerCertificateError>> {
+        self.runtime
+            .block_on(self.client.upload_server_certificate(input))
+    }
+
+    /// Blocking version of [`Iam::upload_signing_certificate`].
+    pub fn upload_signing_certificate(
+        &self,
+        input: UploadSigningCertificateRequest,
+    ) -> Result<UploadSigningCertificateResponse, RusotoError<UploadSigningCertificateError>> {
+        self.runtime
+            .block_on(self.client.upload_signing_certificate(input))
+    }
+}
+
 #[cfg(test)]
 mod protocol_tests {
 
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -2699,36 +2700,70 @@ impl CancelImageCreationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(CancelImageCreationError::CallRateLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelImageCreationError::CallRateLimitExceeded,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(CancelImageCreationError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelImageCreationError::Client,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CancelImageCreationError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelImageCreationError::Forbidden,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        CancelImageCreationError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelImageCreationError::IdempotentParameterMismatch,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CancelImageCreationError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelImageCreationError::InvalidRequest,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CancelImageCreationError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelImageCreationError::ResourceInUse,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CancelImageCreationError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelImageCreationError::Service,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CancelImageCreationError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelImageCreationError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2785,49 +2820,94 @@ impl CreateComponentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(CreateComponentError::CallRateLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateComponentError::CallRateLimitExceeded,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(CreateComponentError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateComponentError::Client,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateComponentError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateComponentError::Forbidden,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(CreateComponentError::IdempotentParameterMismatch(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateComponentError::IdempotentParameterMismatch,
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(CreateComponentError::InvalidParameterCombination(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateComponentError::InvalidParameterCombination,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateComponentError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateComponentError::InvalidRequest,
+                    )
                 }
                 "InvalidVersionNumberException" => {
-                    return RusotoError::Service(CreateComponentError::InvalidVersionNumber(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateComponentError::InvalidVersionNumber,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateComponentError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateComponentError::ResourceInUse,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateComponentError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateComponentError::Service,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateComponentError::ServiceQuotaExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateComponentError::ServiceQuotaExceeded,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateComponentError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateComponentError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2885,53 +2965,94 @@ impl CreateContainerRecipeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(CreateContainerRecipeError::CallRateLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateContainerRecipeError::CallRateLimitExceeded,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(CreateContainerRecipeError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateContainerRecipeError::Client,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateContainerRecipeError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateContainerRecipeError::Forbidden,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        CreateContainerRecipeError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateContainerRecipeError::IdempotentParameterMismatch,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateContainerRecipeError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateContainerRecipeError::InvalidRequest,
+                    )
                 }
                 "InvalidVersionNumberException" => {
-                    return RusotoError::Service(CreateContainerRecipeError::InvalidVersionNumber(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateContainerRecipeError::InvalidVersionNumber,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateContainerRecipeError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateContainerRecipeError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateContainerRecipeError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateContainerRecipeError::ResourceInUse,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateContainerRecipeError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateContainerRecipeError::Service,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateContainerRecipeError::ServiceQuotaExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateContainerRecipeError::ServiceQuotaExceeded,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateContainerRecipeError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateContainerRecipeError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2993,61 +3114,94 @@ impl CreateDistributionConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
-                        CreateDistributionConfigurationError::CallRateLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDistributionConfigurationError::CallRateLimitExceeded,
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(CreateDistributionConfigurationError::Client(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDistributionConfigurationError::Client,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateDistributionConfigurationError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDistributionConfigurationError::Forbidden,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        CreateDistributionConfigurationError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDistributionConfigurationError::IdempotentParameterMismatch,
                     )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
-                        CreateDistributionConfigurationError::InvalidParameterCombination(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDistributionConfigurationError::InvalidParameterCombination,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        CreateDistributionConfigurationError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDistributionConfigurationError::InvalidRequest,
                     )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        CreateDistributionConfigurationError::ResourceAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDistributionConfigurationError::ResourceAlreadyExists,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
-                        CreateDistributionConfigurationError::ResourceInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDistributionConfigurationError::ResourceInUse,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateDistributionConfigurationError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDistributionConfigurationError::Service,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(
-                        CreateDistributionConfigurationError::ServiceQuotaExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDistributionConfigurationError::ServiceQuotaExceeded,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        CreateDistributionConfigurationError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDistributionConfigurationError::ServiceUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3117,35 +3271,78 @@ impl CreateImageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(CreateImageError::CallRateLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateImageError::CallRateLimitExceeded,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(CreateImageError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateImageError::Client,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateImageError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateImageError::Forbidden,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(CreateImageError::IdempotentParameterMismatch(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateImageError::IdempotentParameterMismatch,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateImageError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateImageError::InvalidRequest,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateImageError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateImageError::ResourceInUse,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateImageError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateImageError::Service,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateImageError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateImageError::ServiceQuotaExceeded,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateImageError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateImageError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3199,46 +3396,86 @@ impl CreateImagePipelineError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(CreateImagePipelineError::CallRateLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateImagePipelineError::CallRateLimitExceeded,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(CreateImagePipelineError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateImagePipelineError::Client,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateImagePipelineError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateImagePipelineError::Forbidden,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        CreateImagePipelineError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateImagePipelineError::IdempotentParameterMismatch,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateImagePipelineError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateImagePipelineError::InvalidRequest,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateImagePipelineError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateImagePipelineError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateImagePipelineError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateImagePipelineError::ResourceInUse,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateImagePipelineError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateImagePipelineError::Service,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateImagePipelineError::ServiceQuotaExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateImagePipelineError::ServiceQuotaExceeded,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateImagePipelineError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateImagePipelineError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3297,51 +3534,94 @@ impl CreateImageRecipeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(CreateImageRecipeError::CallRateLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateImageRecipeError::CallRateLimitExceeded,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(CreateImageRecipeError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateImageRecipeError::Client,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateImageRecipeError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateImageRecipeError::Forbidden,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        CreateImageRecipeError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateImageRecipeError::IdempotentParameterMismatch,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateImageRecipeError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateImageRecipeError::InvalidRequest,
+                    )
                 }
                 "InvalidVersionNumberException" => {
-                    return RusotoError::Service(CreateImageRecipeError::InvalidVersionNumber(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateImageRecipeError::InvalidVersionNumber,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateImageRecipeError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateImageRecipeError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateImageRecipeError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateImageRecipeError::ResourceInUse,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateImageRecipeError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateImageRecipeError::Service,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateImageRecipeError::ServiceQuotaExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateImageRecipeError::ServiceQuotaExceeded,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateImageRecipeError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateImageRecipeError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3401,58 +3681,86 @@ impl CreateInfrastructureConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
-                        CreateInfrastructureConfigurationError::CallRateLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInfrastructureConfigurationError::CallRateLimitExceeded,
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(CreateInfrastructureConfigurationError::Client(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateInfrastructureConfigurationError::Client,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateInfrastructureConfigurationError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateInfrastructureConfigurationError::Forbidden,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        CreateInfrastructureConfigurationError::IdempotentParameterMismatch(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInfrastructureConfigurationError::IdempotentParameterMismatch,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        CreateInfrastructureConfigurationError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInfrastructureConfigurationError::InvalidRequest,
                     )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        CreateInfrastructureConfigurationError::ResourceAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInfrastructureConfigurationError::ResourceAlreadyExists,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
-                        CreateInfrastructureConfigurationError::ResourceInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInfrastructureConfigurationError::ResourceInUse,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateInfrastructureConfigurationError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateInfrastructureConfigurationError::Service,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(
-                        CreateInfrastructureConfigurationError::ServiceQuotaExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInfrastructureConfigurationError::ServiceQuotaExceeded,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        CreateInfrastructureConfigurationError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInfrastructureConfigurationError::ServiceUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3515,29 +3823,62 @@ impl DeleteComponentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(DeleteComponentError::CallRateLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteComponentError::CallRateLimitExceeded,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(DeleteComponentError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteComponentError::Client,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteComponentError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteComponentError::Forbidden,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteComponentError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteComponentError::InvalidRequest,
+                    )
                 }
                 "ResourceDependencyException" => {
-                    return RusotoError::Service(DeleteComponentError::ResourceDependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteComponentError::ResourceDependency,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteComponentError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteComponentError::Service,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteComponentError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteComponentError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3583,35 +3924,62 @@ impl DeleteContainerRecipeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(DeleteContainerRecipeError::CallRateLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteContainerRecipeError::CallRateLimitExceeded,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(DeleteContainerRecipeError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteContainerRecipeError::Client,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteContainerRecipeError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteContainerRecipeError::Forbidden,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteContainerRecipeError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteContainerRecipeError::InvalidRequest,
+                    )
                 }
                 "ResourceDependencyException" => {
-                    return RusotoError::Service(DeleteContainerRecipeError::ResourceDependency(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteContainerRecipeError::ResourceDependency,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteContainerRecipeError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteContainerRecipeError::Service,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteContainerRecipeError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteContainerRecipeError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3659,41 +4027,62 @@ impl DeleteDistributionConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
-                        DeleteDistributionConfigurationError::CallRateLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDistributionConfigurationError::CallRateLimitExceeded,
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(DeleteDistributionConfigurationError::Client(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDistributionConfigurationError::Client,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteDistributionConfigurationError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDistributionConfigurationError::Forbidden,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        DeleteDistributionConfigurationError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDistributionConfigurationError::InvalidRequest,
                     )
                 }
                 "ResourceDependencyException" => {
-                    return RusotoError::Service(
-                        DeleteDistributionConfigurationError::ResourceDependency(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDistributionConfigurationError::ResourceDependency,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteDistributionConfigurationError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDistributionConfigurationError::Service,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DeleteDistributionConfigurationError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDistributionConfigurationError::ServiceUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3747,27 +4136,62 @@ impl DeleteImageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(DeleteImageError::CallRateLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteImageError::CallRateLimitExceeded,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(DeleteImageError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteImageError::Client,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteImageError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteImageError::Forbidden,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteImageError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteImageError::InvalidRequest,
+                    )
                 }
                 "ResourceDependencyException" => {
-                    return RusotoError::Service(DeleteImageError::ResourceDependency(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteImageError::ResourceDependency,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteImageError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteImageError::Service,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteImageError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteImageError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3813,33 +4237,62 @@ impl DeleteImagePipelineError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(DeleteImagePipelineError::CallRateLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteImagePipelineError::CallRateLimitExceeded,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(DeleteImagePipelineError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteImagePipelineError::Client,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteImagePipelineError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteImagePipelineError::Forbidden,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteImagePipelineError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteImagePipelineError::InvalidRequest,
+                    )
                 }
                 "ResourceDependencyException" => {
-                    return RusotoError::Service(DeleteImagePipelineError::ResourceDependency(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteImagePipelineError::ResourceDependency,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteImagePipelineError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteImagePipelineError::Service,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteImagePipelineError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteImagePipelineError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3885,33 +4338,62 @@ impl DeleteImageRecipeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(DeleteImageRecipeError::CallRateLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteImageRecipeError::CallRateLimitExceeded,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(DeleteImageRecipeError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteImageRecipeError::Client,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteImageRecipeError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteImageRecipeError::Forbidden,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteImageRecipeError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteImageRecipeError::InvalidRequest,
+                    )
                 }
                 "ResourceDependencyException" => {
-                    return RusotoError::Service(DeleteImageRecipeError::ResourceDependency(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteImageRecipeError::ResourceDependency,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteImageRecipeError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteImageRecipeError::Service,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteImageRecipeError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteImageRecipeError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3959,41 +4441,62 @@ impl DeleteInfrastructureConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
-                        DeleteInfrastructureConfigurationError::CallRateLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInfrastructureConfigurationError::CallRateLimitExceeded,
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(DeleteInfrastructureConfigurationError::Client(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteInfrastructureConfigurationError::Client,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteInfrastructureConfigurationError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteInfrastructureConfigurationError::Forbidden,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        DeleteInfrastructureConfigurationError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInfrastructureConfigurationError::InvalidRequest,
                     )
                 }
                 "ResourceDependencyException" => {
-                    return RusotoError::Service(
-                        DeleteInfrastructureConfigurationError::ResourceDependency(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInfrastructureConfigurationError::ResourceDependency,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteInfrastructureConfigurationError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteInfrastructureConfigurationError::Service,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DeleteInfrastructureConfigurationError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInfrastructureConfigurationError::ServiceUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4045,24 +4548,54 @@ impl GetComponentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(GetComponentError::CallRateLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetComponentError::CallRateLimitExceeded,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(GetComponentError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetComponentError::Client,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetComponentError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetComponentError::Forbidden,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetComponentError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetComponentError::InvalidRequest,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetComponentError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetComponentError::Service,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetComponentError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetComponentError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4105,28 +4638,54 @@ impl GetComponentPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(GetComponentPolicyError::CallRateLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetComponentPolicyError::CallRateLimitExceeded,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetComponentPolicyError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetComponentPolicyError::Forbidden,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetComponentPolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetComponentPolicyError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetComponentPolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetComponentPolicyError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetComponentPolicyError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetComponentPolicyError::Service,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetComponentPolicyError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetComponentPolicyError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4169,28 +4728,54 @@ impl GetContainerRecipeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(GetContainerRecipeError::CallRateLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetContainerRecipeError::CallRateLimitExceeded,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(GetContainerRecipeError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetContainerRecipeError::Client,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetContainerRecipeError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetContainerRecipeError::Forbidden,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetContainerRecipeError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetContainerRecipeError::InvalidRequest,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetContainerRecipeError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetContainerRecipeError::Service,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetContainerRecipeError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetContainerRecipeError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4233,32 +4818,54 @@ impl GetContainerRecipePolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
-                        GetContainerRecipePolicyError::CallRateLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetContainerRecipePolicyError::CallRateLimitExceeded,
                     )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetContainerRecipePolicyError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetContainerRecipePolicyError::Forbidden,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetContainerRecipePolicyError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetContainerRecipePolicyError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetContainerRecipePolicyError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetContainerRecipePolicyError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetContainerRecipePolicyError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetContainerRecipePolicyError::Service,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetContainerRecipePolicyError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetContainerRecipePolicyError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4305,34 +4912,54 @@ impl GetDistributionConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
-                        GetDistributionConfigurationError::CallRateLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDistributionConfigurationError::CallRateLimitExceeded,
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(GetDistributionConfigurationError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDistributionConfigurationError::Client,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetDistributionConfigurationError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDistributionConfigurationError::Forbidden,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetDistributionConfigurationError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDistributionConfigurationError::InvalidRequest,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetDistributionConfigurationError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDistributionConfigurationError::Service,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        GetDistributionConfigurationError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDistributionConfigurationError::ServiceUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4379,20 +5006,54 @@ impl GetImageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(GetImageError::CallRateLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetImageError::CallRateLimitExceeded,
+                    )
+                }
+                "ClientException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetImageError::Client,
+                    )
                 }
-                "ClientException" => return RusotoError::Service(GetImageError::Client(err.msg)),
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetImageError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetImageError::Forbidden,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetImageError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetImageError::InvalidRequest,
+                    )
+                }
+                "ServiceException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetImageError::Service,
+                    )
                 }
-                "ServiceException" => return RusotoError::Service(GetImageError::Service(err.msg)),
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetImageError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetImageError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4435,26 +5096,54 @@ impl GetImagePipelineError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(GetImagePipelineError::CallRateLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetImagePipelineError::CallRateLimitExceeded,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(GetImagePipelineError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetImagePipelineError::Client,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetImagePipelineError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetImagePipelineError::Forbidden,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetImagePipelineError::InvalidRequest(err.msg))
-                }
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetImagePipelineError::InvalidRequest,
+                    )
+                }
                 "ServiceException" => {
-                    return RusotoError::Service(GetImagePipelineError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetImagePipelineError::Service,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetImagePipelineError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetImagePipelineError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4497,26 +5186,54 @@ impl GetImagePolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(GetImagePolicyError::CallRateLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetImagePolicyError::CallRateLimitExceeded,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetImagePolicyError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetImagePolicyError::Forbidden,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetImagePolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetImagePolicyError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetImagePolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetImagePolicyError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetImagePolicyError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetImagePolicyError::Service,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetImagePolicyError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetImagePolicyError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4559,26 +5276,54 @@ impl GetImageRecipeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(GetImageRecipeError::CallRateLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetImageRecipeError::CallRateLimitExceeded,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(GetImageRecipeError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetImageRecipeError::Client,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetImageRecipeError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetImageRecipeError::Forbidden,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetImageRecipeError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetImageRecipeError::InvalidRequest,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetImageRecipeError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetImageRecipeError::Service,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetImageRecipeError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetImageRecipeError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4621,30 +5366,54 @@ impl GetImageRecipePolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(GetImageRecipePolicyError::CallRateLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetImageRecipePolicyError::CallRateLimitExceeded,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetImageRecipePolicyError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetImageRecipePolicyError::Forbidden,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetImageRecipePolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetImageRecipePolicyError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetImageRecipePolicyError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetImageRecipePolicyError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetImageRecipePolicyError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetImageRecipePolicyError::Service,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetImageRecipePolicyError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetImageRecipePolicyError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4689,36 +5458,54 @@ impl GetInfrastructureConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
-                        GetInfrastructureConfigurationError::CallRateLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInfrastructureConfigurationError::CallRateLimitExceeded,
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(GetInfrastructureConfigurationError::Client(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetInfrastructureConfigurationError::Client,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetInfrastructureConfigurationError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetInfrastructureConfigurationError::Forbidden,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        GetInfrastructureConfigurationError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInfrastructureConfigurationError::InvalidRequest,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetInfrastructureConfigurationError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetInfrastructureConfigurationError::Service,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        GetInfrastructureConfigurationError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInfrastructureConfigurationError::ServiceUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4775,44 +5562,86 @@ impl ImportComponentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(ImportComponentError::CallRateLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ImportComponentError::CallRateLimitExceeded,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(ImportComponentError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportComponentError::Client,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ImportComponentError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportComponentError::Forbidden,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(ImportComponentError::IdempotentParameterMismatch(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ImportComponentError::IdempotentParameterMismatch,
+                    )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(ImportComponentError::InvalidParameterCombination(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ImportComponentError::InvalidParameterCombination,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ImportComponentError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportComponentError::InvalidRequest,
+                    )
                 }
                 "InvalidVersionNumberException" => {
-                    return RusotoError::Service(ImportComponentError::InvalidVersionNumber(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ImportComponentError::InvalidVersionNumber,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(ImportComponentError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportComponentError::ResourceInUse,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ImportComponentError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportComponentError::Service,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ImportComponentError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportComponentError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4863,37 +5692,62 @@ impl ListComponentBuildVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
-                        ListComponentBuildVersionsError::CallRateLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListComponentBuildVersionsError::CallRateLimitExceeded,
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(ListComponentBuildVersionsError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListComponentBuildVersionsError::Client,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListComponentBuildVersionsError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListComponentBuildVersionsError::Forbidden,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(
-                        ListComponentBuildVersionsError::InvalidPaginationToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListComponentBuildVersionsError::InvalidPaginationToken,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListComponentBuildVersionsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListComponentBuildVersionsError::InvalidRequest,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListComponentBuildVersionsError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListComponentBuildVersionsError::Service,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        ListComponentBuildVersionsError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListComponentBuildVersionsError::ServiceUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4945,31 +5799,62 @@ impl ListComponentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(ListComponentsError::CallRateLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListComponentsError::CallRateLimitExceeded,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(ListComponentsError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListComponentsError::Client,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListComponentsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListComponentsError::Forbidden,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(ListComponentsError::InvalidPaginationToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListComponentsError::InvalidPaginationToken,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListComponentsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListComponentsError::InvalidRequest,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListComponentsError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListComponentsError::Service,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListComponentsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListComponentsError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5015,33 +5900,62 @@ impl ListContainerRecipesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(ListContainerRecipesError::CallRateLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListContainerRecipesError::CallRateLimitExceeded,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(ListContainerRecipesError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListContainerRecipesError::Client,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListContainerRecipesError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListContainerRecipesError::Forbidden,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(ListContainerRecipesError::InvalidPaginationToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListContainerRecipesError::InvalidPaginationToken,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListContainerRecipesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListContainerRecipesError::InvalidRequest,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListContainerRecipesError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListContainerRecipesError::Service,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListContainerRecipesError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListContainerRecipesError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5089,41 +6003,62 @@ impl ListDistributionConfigurationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
-                        ListDistributionConfigurationsError::CallRateLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDistributionConfigurationsError::CallRateLimitExceeded,
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(ListDistributionConfigurationsError::Client(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDistributionConfigurationsError::Client,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListDistributionConfigurationsError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDistributionConfigurationsError::Forbidden,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(
-                        ListDistributionConfigurationsError::InvalidPaginationToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDistributionConfigurationsError::InvalidPaginationToken,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        ListDistributionConfigurationsError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDistributionConfigurationsError::InvalidRequest,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListDistributionConfigurationsError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDistributionConfigurationsError::Service,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        ListDistributionConfigurationsError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDistributionConfigurationsError::ServiceUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5177,35 +6112,62 @@ impl ListImageBuildVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
-                        ListImageBuildVersionsError::CallRateLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListImageBuildVersionsError::CallRateLimitExceeded,
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(ListImageBuildVersionsError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListImageBuildVersionsError::Client,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListImageBuildVersionsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListImageBuildVersionsError::Forbidden,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(
-                        ListImageBuildVersionsError::InvalidPaginationToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListImageBuildVersionsError::InvalidPaginationToken,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListImageBuildVersionsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListImageBuildVersionsError::InvalidRequest,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListImageBuildVersionsError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListImageBuildVersionsError::Service,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListImageBuildVersionsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListImageBuildVersionsError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5255,40 +6217,70 @@ impl ListImagePipelineImagesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
-                        ListImagePipelineImagesError::CallRateLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListImagePipelineImagesError::CallRateLimitExceeded,
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(ListImagePipelineImagesError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListImagePipelineImagesError::Client,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListImagePipelineImagesError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListImagePipelineImagesError::Forbidden,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(
-                        ListImagePipelineImagesError::InvalidPaginationToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListImagePipelineImagesError::InvalidPaginationToken,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListImagePipelineImagesError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListImagePipelineImagesError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListImagePipelineImagesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListImagePipelineImagesError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListImagePipelineImagesError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListImagePipelineImagesError::Service,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListImagePipelineImagesError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListImagePipelineImagesError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5339,33 +6331,62 @@ impl ListImagePipelinesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(ListImagePipelinesError::CallRateLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListImagePipelinesError::CallRateLimitExceeded,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(ListImagePipelinesError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListImagePipelinesError::Client,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListImagePipelinesError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListImagePipelinesError::Forbidden,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(ListImagePipelinesError::InvalidPaginationToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListImagePipelinesError::InvalidPaginationToken,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListImagePipelinesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListImagePipelinesError::InvalidRequest,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListImagePipelinesError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListImagePipelinesError::Service,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListImagePipelinesError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListImagePipelinesError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5411,31 +6432,62 @@ impl ListImageRecipesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(ListImageRecipesError::CallRateLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListImageRecipesError::CallRateLimitExceeded,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(ListImageRecipesError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListImageRecipesError::Client,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListImageRecipesError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListImageRecipesError::Forbidden,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(ListImageRecipesError::InvalidPaginationToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListImageRecipesError::InvalidPaginationToken,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListImageRecipesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListImageRecipesError::InvalidRequest,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListImageRecipesError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListImageRecipesError::Service,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListImageRecipesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListImageRecipesError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5481,25 +6533,62 @@ impl ListImagesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(ListImagesError::CallRateLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListImagesError::CallRateLimitExceeded,
+                    )
+                }
+                "ClientException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListImagesError::Client,
+                    )
                 }
-                "ClientException" => return RusotoError::Service(ListImagesError::Client(err.msg)),
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListImagesError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListImagesError::Forbidden,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(ListImagesError::InvalidPaginationToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListImagesError::InvalidPaginationToken,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListImagesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListImagesError::InvalidRequest,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListImagesError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListImagesError::Service,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListImagesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListImagesError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5547,41 +6636,62 @@ impl ListInfrastructureConfigurationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
-                        ListInfrastructureConfigurationsError::CallRateLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInfrastructureConfigurationsError::CallRateLimitExceeded,
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(ListInfrastructureConfigurationsError::Client(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListInfrastructureConfigurationsError::Client,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListInfrastructureConfigurationsError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListInfrastructureConfigurationsError::Forbidden,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(
-                        ListInfrastructureConfigurationsError::InvalidPaginationToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInfrastructureConfigurationsError::InvalidPaginationToken,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        ListInfrastructureConfigurationsError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInfrastructureConfigurationsError::InvalidRequest,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListInfrastructureConfigurationsError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListInfrastructureConfigurationsError::Service,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        ListInfrastructureConfigurationsError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInfrastructureConfigurationsError::ServiceUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5627,19 +6737,30 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::Service,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5683,36 +6804,70 @@ impl PutComponentPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(PutComponentPolicyError::CallRateLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutComponentPolicyError::CallRateLimitExceeded,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(PutComponentPolicyError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutComponentPolicyError::Client,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(PutComponentPolicyError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutComponentPolicyError::Forbidden,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(PutComponentPolicyError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutComponentPolicyError::InvalidParameterValue,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(PutComponentPolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutComponentPolicyError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutComponentPolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutComponentPolicyError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(PutComponentPolicyError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutComponentPolicyError::Service,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutComponentPolicyError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutComponentPolicyError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5761,40 +6916,70 @@ impl PutContainerRecipePolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
-                        PutContainerRecipePolicyError::CallRateLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutContainerRecipePolicyError::CallRateLimitExceeded,
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(PutContainerRecipePolicyError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutContainerRecipePolicyError::Client,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(PutContainerRecipePolicyError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutContainerRecipePolicyError::Forbidden,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        PutContainerRecipePolicyError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutContainerRecipePolicyError::InvalidParameterValue,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(PutContainerRecipePolicyError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutContainerRecipePolicyError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutContainerRecipePolicyError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutContainerRecipePolicyError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(PutContainerRecipePolicyError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutContainerRecipePolicyError::Service,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutContainerRecipePolicyError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutContainerRecipePolicyError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5847,34 +7032,70 @@ impl PutImagePolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(PutImagePolicyError::CallRateLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutImagePolicyError::CallRateLimitExceeded,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(PutImagePolicyError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutImagePolicyError::Client,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(PutImagePolicyError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutImagePolicyError::Forbidden,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(PutImagePolicyError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutImagePolicyError::InvalidParameterValue,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(PutImagePolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutImagePolicyError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutImagePolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutImagePolicyError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(PutImagePolicyError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutImagePolicyError::Service,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutImagePolicyError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutImagePolicyError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5923,38 +7144,70 @@ impl PutImageRecipePolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(PutImageRecipePolicyError::CallRateLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutImageRecipePolicyError::CallRateLimitExceeded,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(PutImageRecipePolicyError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutImageRecipePolicyError::Client,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(PutImageRecipePolicyError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutImageRecipePolicyError::Forbidden,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(PutImageRecipePolicyError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutImageRecipePolicyError::InvalidParameterValue,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(PutImageRecipePolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutImageRecipePolicyError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutImageRecipePolicyError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutImageRecipePolicyError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(PutImageRecipePolicyError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutImageRecipePolicyError::Service,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutImageRecipePolicyError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutImageRecipePolicyError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6007,47 +7260,78 @@ impl StartImagePipelineExecutionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
-                        StartImagePipelineExecutionError::CallRateLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartImagePipelineExecutionError::CallRateLimitExceeded,
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(StartImagePipelineExecutionError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartImagePipelineExecutionError::Client,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(StartImagePipelineExecutionError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartImagePipelineExecutionError::Forbidden,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        StartImagePipelineExecutionError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartImagePipelineExecutionError::IdempotentParameterMismatch,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartImagePipelineExecutionError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartImagePipelineExecutionError::InvalidRequest,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StartImagePipelineExecutionError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartImagePipelineExecutionError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        StartImagePipelineExecutionError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartImagePipelineExecutionError::ResourceNotFound,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(StartImagePipelineExecutionError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartImagePipelineExecutionError::Service,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        StartImagePipelineExecutionError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartImagePipelineExecutionError::ServiceUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6093,15 +7377,30 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(TagResourceError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::Service,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6135,15 +7434,30 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UntagResourceError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::Service,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6191,51 +7505,78 @@ impl UpdateDistributionConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
-                        UpdateDistributionConfigurationError::CallRateLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDistributionConfigurationError::CallRateLimitExceeded,
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(UpdateDistributionConfigurationError::Client(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDistributionConfigurationError::Client,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateDistributionConfigurationError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDistributionConfigurationError::Forbidden,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        UpdateDistributionConfigurationError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDistributionConfigurationError::IdempotentParameterMismatch,
                     )
                 }
                 "InvalidParameterCombinationException" => {
-                    return RusotoError::Service(
-                        UpdateDistributionConfigurationError::InvalidParameterCombination(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDistributionConfigurationError::InvalidParameterCombination,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        UpdateDistributionConfigurationError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDistributionConfigurationError::InvalidRequest,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
-                        UpdateDistributionConfigurationError::ResourceInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDistributionConfigurationError::ResourceInUse,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateDistributionConfigurationError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDistributionConfigurationError::Service,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        UpdateDistributionConfigurationError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDistributionConfigurationError::ServiceUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6297,36 +7638,70 @@ impl UpdateImagePipelineError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(UpdateImagePipelineError::CallRateLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateImagePipelineError::CallRateLimitExceeded,
+                    )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(UpdateImagePipelineError::Client(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateImagePipelineError::Client,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateImagePipelineError::Forbidden(err.msg))
-                }
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateImagePipelineError::Forbidden,
+                    )
+                }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        UpdateImagePipelineError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateImagePipelineError::IdempotentParameterMismatch,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateImagePipelineError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateImagePipelineError::InvalidRequest,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateImagePipelineError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateImagePipelineError::ResourceInUse,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateImagePipelineError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateImagePipelineError::Service,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateImagePipelineError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateImagePipelineError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6379,48 +7754,70 @@ impl UpdateInfrastructureConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CallRateLimitExceededException" => {
-                    return RusotoError::Service(
-                        UpdateInfrastructureConfigurationError::CallRateLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateInfrastructureConfigurationError::CallRateLimitExceeded,
                     )
                 }
                 "ClientException" => {
-                    return RusotoError::Service(UpdateInfrastructureConfigurationError::Client(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateInfrastructureConfigurationError::Client,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateInfrastructureConfigurationError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateInfrastructureConfigurationError::Forbidden,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        UpdateInfrastructureConfigurationError::IdempotentParameterMismatch(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateInfrastructureConfigurationError::IdempotentParameterMismatch,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        UpdateInfrastructureConfigurationError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateInfrastructureConfigurationError::InvalidRequest,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
-                        UpdateInfrastructureConfigurationError::ResourceInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateInfrastructureConfigurationError::ResourceInUse,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateInfrastructureConfigurationError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateInfrastructureConfigurationError::Service,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        UpdateInfrastructureConfigurationError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateInfrastructureConfigurationError::ServiceUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6781,11 +8178,12 @@ pub struct ImageBuilderClient {
 impl ImageBuilderClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> ImageBuilderClient {
         ImageBuilderClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "imagebuilder"),
         }
     }
 
@@ -6800,12 +8198,32 @@ impl ImageBuilderClient {
     {
         ImageBuilderClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "imagebuilder"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ImageBuilderClient {
-        ImageBuilderClient { client, region }
+        ImageBuilderClient {
+            client,
+            region: region_for_service(region, "imagebuilder"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> ImageBuilderClient {
+        ImageBuilderClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("imagebuilder"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ImageBuilderClient {
+        ImageBuilderClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -6820,6 +8238,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/CancelImageCreation";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("CancelImageCreation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6851,6 +8270,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/CreateComponent";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("CreateComponent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6882,6 +8302,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/CreateContainerRecipe";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("CreateContainerRecipe");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6916,6 +8337,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/CreateDistributionConfiguration";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("CreateDistributionConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6949,6 +8371,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/CreateImage";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("CreateImage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6980,6 +8403,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/CreateImagePipeline";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("CreateImagePipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7011,6 +8435,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/CreateImageRecipe";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("CreateImageRecipe");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7045,6 +8470,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/CreateInfrastructureConfiguration";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("CreateInfrastructureConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7078,6 +8504,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/DeleteComponent";
 
         let mut request = SignedRequest::new("DELETE", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("DeleteComponent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7113,6 +8540,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/DeleteContainerRecipe";
 
         let mut request = SignedRequest::new("DELETE", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("DeleteContainerRecipe");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7148,6 +8576,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/DeleteDistributionConfiguration";
 
         let mut request = SignedRequest::new("DELETE", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("DeleteDistributionConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7185,6 +8614,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/DeleteImage";
 
         let mut request = SignedRequest::new("DELETE", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("DeleteImage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7217,6 +8647,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/DeleteImagePipeline";
 
         let mut request = SignedRequest::new("DELETE", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("DeleteImagePipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7249,6 +8680,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/DeleteImageRecipe";
 
         let mut request = SignedRequest::new("DELETE", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("DeleteImageRecipe");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7284,6 +8716,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/DeleteInfrastructureConfiguration";
 
         let mut request = SignedRequest::new("DELETE", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("DeleteInfrastructureConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7321,6 +8754,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/GetComponent";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("GetComponent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7356,6 +8790,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/GetComponentPolicy";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("GetComponentPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7388,6 +8823,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/GetContainerRecipe";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("GetContainerRecipe");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7420,6 +8856,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/GetContainerRecipePolicy";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("GetContainerRecipePolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7453,6 +8890,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/GetDistributionConfiguration";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("GetDistributionConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7488,6 +8926,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/GetImage";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("GetImage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7520,6 +8959,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/GetImagePipeline";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("GetImagePipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7552,6 +8992,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/GetImagePolicy";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("GetImagePolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7584,6 +9025,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/GetImageRecipe";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("GetImageRecipe");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7616,6 +9058,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/GetImageRecipePolicy";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("GetImageRecipePolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7651,6 +9094,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/GetInfrastructureConfiguration";
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("GetInfrastructureConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7686,6 +9130,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ImportComponent";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ImportComponent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7718,6 +9163,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ListComponentBuildVersions";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListComponentBuildVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7749,6 +9195,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ListComponents";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListComponents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7780,6 +9227,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ListContainerRecipes";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListContainerRecipes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7814,6 +9262,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ListDistributionConfigurations";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListDistributionConfigurations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7845,6 +9294,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ListImageBuildVersions";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListImageBuildVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7876,6 +9326,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ListImagePipelineImages";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListImagePipelineImages");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7907,6 +9358,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ListImagePipelines";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListImagePipelines");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7938,6 +9390,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ListImageRecipes";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListImageRecipes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7969,6 +9422,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ListImages";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListImages");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8003,6 +9457,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/ListInfrastructureConfigurations";
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListInfrastructureConfigurations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8036,6 +9491,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8064,6 +9520,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/PutComponentPolicy";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("PutComponentPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8095,6 +9552,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/PutContainerRecipePolicy";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("PutContainerRecipePolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8126,6 +9584,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/PutImagePolicy";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("PutImagePolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8157,6 +9616,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/PutImageRecipePolicy";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("PutImageRecipePolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8189,6 +9649,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/StartImagePipelineExecution";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("StartImagePipelineExecution");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8220,6 +9681,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8251,6 +9713,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8288,6 +9751,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/UpdateDistributionConfiguration";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("UpdateDistributionConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8321,6 +9785,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/UpdateImagePipeline";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("UpdateImagePipeline");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8355,6 +9820,7 @@ impl ImageBuilder for ImageBuilderClient {
         let request_uri = "/UpdateInfrastructureConfiguration";
 
         let mut request = SignedRequest::new("PUT", "imagebuilder", &self.region, &request_uri);
+        request.set_operation("UpdateInfrastructureConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8379,3 +9845,527 @@ impl ImageBuilder for ImageBuilderClient {
         }
     }
 }
+
+/// A blocking client for the imagebuilder API, running the calls of a `ImageBuilderClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `ImageBuilderClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ImageBuilderBlockingClient {
+    client: ImageBuilderClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ImageBuilderBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> ImageBuilderBlockingClient {
+        ImageBuilderBlockingClient::from_client(ImageBuilderClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ImageBuilderBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ImageBuilderBlockingClient::from_client(ImageBuilderClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> ImageBuilderBlockingClient {
+        ImageBuilderBlockingClient::from_client(ImageBuilderClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> ImageBuilderBlockingClient {
+        ImageBuilderBlockingClient::from_client(ImageBuilderClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: ImageBuilderClient) -> ImageBuilderBlockingClient {
+        ImageBuilderBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> ImageBuilderBlockingClient {
+        ImageBuilderBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ImageBuilderBlockingClient {
+        ImageBuilderBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`ImageBuilder::cancel_image_creation`].
+    pub fn cancel_image_creation(
+        &self,
+        input: CancelImageCreationRequest,
+    ) -> Result<CancelImageCreationResponse, RusotoError<CancelImageCreationError>> {
+        self.runtime
+            .block_on(self.client.cancel_image_creation(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::create_component`].
+    pub fn create_component(
+        &self,
+        input: CreateComponentRequest,
+    ) -> Result<CreateComponentResponse, RusotoError<CreateComponentError>> {
+        self.runtime.block_on(self.client.create_component(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::create_container_recipe`].
+    pub fn create_container_recipe(
+        &self,
+        input: CreateContainerRecipeRequest,
+    ) -> Result<CreateContainerRecipeResponse, RusotoError<CreateContainerRecipeError>> {
+        self.runtime
+            .block_on(self.client.create_container_recipe(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::create_distribution_configuration`].
+    pub fn create_distribution_configuration(
+        &self,
+        input: CreateDistributionConfigurationRequest,
+    ) -> Result<
+        CreateDistributionConfigurationResponse,
+        RusotoError<CreateDistributionConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_distribution_configuration(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::create_image`].
+    pub fn create_image(
+        &self,
+        input: CreateImageRequest,
+    ) -> Result<CreateImageResponse, RusotoError<CreateImageError>> {
+        self.runtime.block_on(self.client.create_image(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::create_image_pipeline`].
+    pub fn create_image_pipeline(
+        &self,
+        input: CreateImagePipelineRequest,
+    ) -> Result<CreateImagePipelineResponse, RusotoError<CreateImagePipelineError>> {
+        self.runtime
+            .block_on(self.client.create_image_pipeline(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::create_image_recipe`].
+    pub fn create_image_recipe(
+        &self,
+        input: CreateImageRecipeRequest,
+    ) -> Result<CreateImageRecipeResponse, RusotoError<CreateImageRecipeError>> {
+        self.runtime
+            .block_on(self.client.create_image_recipe(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::create_infrastructure_configuration`].
+    pub fn create_infrastructure_configuration(
+        &self,
+        input: CreateInfrastructureConfigurationRequest,
+    ) -> Result<
+        CreateInfrastructureConfigurationResponse,
+        RusotoError<CreateInfrastructureConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_infrastructure_configuration(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::delete_component`].
+    pub fn delete_component(
+        &self,
+        input: DeleteComponentRequest,
+    ) -> Result<DeleteComponentResponse, RusotoError<DeleteComponentError>> {
+        self.runtime.block_on(self.client.delete_component(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::delete_container_recipe`].
+    pub fn delete_container_recipe(
+        &self,
+        input: DeleteContainerRecipeRequest,
+    ) -> Result<DeleteContainerRecipeResponse, RusotoError<DeleteContainerRecipeError>> {
+        self.runtime
+            .block_on(self.client.delete_container_recipe(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::delete_distribution_configuration`].
+    pub fn delete_distribution_configuration(
+        &self,
+        input: DeleteDistributionConfigurationRequest,
+    ) -> Result<
+        DeleteDistributionConfigurationResponse,
+        RusotoError<DeleteDistributionConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_distribution_configuration(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::delete_image`].
+    pub fn delete_image(
+        &self,
+        input: DeleteImageRequest,
+    ) -> Result<DeleteImageResponse, RusotoError<DeleteImageError>> {
+        self.runtime.block_on(self.client.delete_image(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::delete_image_pipeline`].
+    pub fn delete_image_pipeline(
+        &self,
+        input: DeleteImagePipelineRequest,
+    ) -> Result<DeleteImagePipelineResponse, RusotoError<DeleteImagePipelineError>> {
+        self.runtime
+            .block_on(self.client.delete_image_pipeline(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::delete_image_recipe`].
+    pub fn delete_image_recipe(
+        &self,
+        input: DeleteImageRecipeRequest,
+    ) -> Result<DeleteImageRecipeResponse, RusotoError<DeleteImageRecipeError>> {
+        self.runtime
+            .block_on(self.client.delete_image_recipe(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::delete_infrastructure_configuration`].
+    pub fn delete_infrastructure_configuration(
+        &self,
+        input: DeleteInfrastructureConfigurationRequest,
+    ) -> Result<
+        DeleteInfrastructureConfigurationResponse,
+        RusotoError<DeleteInfrastructureConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_infrastructure_configuration(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::get_component`].
+    pub fn get_component(
+        &self,
+        input: GetComponentRequest,
+    ) -> Result<GetComponentResponse, RusotoError<GetComponentError>> {
+        self.runtime.block_on(self.client.get_component(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::get_component_policy`].
+    pub fn get_component_policy(
+        &self,
+        input: GetComponentPolicyRequest,
+    ) -> Result<GetComponentPolicyResponse, RusotoError<GetComponentPolicyError>> {
+        self.runtime
+            .block_on(self.client.get_component_policy(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::get_container_recipe`].
+    pub fn get_container_recipe(
+        &self,
+        input: GetContainerRecipeRequest,
+    ) -> Result<GetContainerRecipeResponse, RusotoError<GetContainerRecipeError>> {
+        self.runtime
+            .block_on(self.client.get_container_recipe(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::get_container_recipe_policy`].
+    pub fn get_container_recipe_policy(
+        &self,
+        input: GetContainerRecipePolicyRequest,
+    ) -> Result<GetContainerRecipePolicyResponse, RusotoError<GetContainerRecipePolicyError>> {
+        self.runtime
+            .block_on(self.client.get_container_recipe_policy(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::get_distribution_configuration`].
+    pub fn get_distribution_configuration(
+        &self,
+        input: GetDistributionConfigurationRequest,
+    ) -> Result<GetDistributionConfigurationResponse, RusotoError<GetDistributionConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.get_distribution_configuration(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::get_image`].
+    pub fn get_image(
+        &self,
+        input: GetImageRequest,
+    ) -> Result<GetImageResponse, RusotoError<GetImageError>> {
+        self.runtime.block_on(self.client.get_image(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::get_image_pipeline`].
+    pub fn get_image_pipeline(
+        &self,
+        input: GetImagePipelineRequest,
+    ) -> Result<GetImagePipelineResponse, RusotoError<GetImagePipelineError>> {
+        self.runtime.block_on(self.client.get_image_pipeline(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::get_image_policy`].
+    pub fn get_image_policy(
+        &self,
+        input: GetImagePolicyRequest,
+    ) -> Result<GetImagePolicyResponse, RusotoError<GetImagePolicyError>> {
+        self.runtime.block_on(self.client.get_image_policy(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::get_image_recipe`].
+    pub fn get_image_recipe(
+        &self,
+        input: GetImageRecipeRequest,
+    ) -> Result<GetImageRecipeResponse, RusotoError<GetImageRecipeError>> {
+        self.runtime.block_on(self.client.get_image_recipe(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::get_image_recipe_policy`].
+    pub fn get_image_recipe_policy(
+        &self,
+        input: GetImageRecipePolicyRequest,
+    ) -> Result<GetImageRecipePolicyResponse, RusotoError<GetImageRecipePolicyError>> {
+        self.runtime
+            .block_on(self.client.get_image_recipe_policy(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::get_infrastructure_configuration`].
+    pub fn get_infrastructure_configuration(
+        &self,
+        input: GetInfrastructureConfigurationRequest,
+    ) -> Result<
+        GetInfrastructureConfigurationResponse,
+        RusotoError<GetInfrastructureConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_infrastructure_configuration(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::import_component`].
+    pub fn import_component(
+        &self,
+        input: ImportComponentRequest,
+    ) -> Result<ImportComponentResponse, RusotoError<ImportComponentError>> {
+        self.runtime.block_on(self.client.import_component(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::list_component_build_versions`].
+    pub fn list_component_build_versions(
+        &self,
+        input: ListComponentBuildVersionsRequest,
+    ) -> Result<ListComponentBuildVersionsResponse, RusotoError<ListComponentBuildVersionsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_component_build_versions(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::list_components`].
+    pub fn list_components(
+        &self,
+        input: ListComponentsRequest,
+    ) -> Result<ListComponentsResponse, RusotoError<ListComponentsError>> {
+        self.runtime.block_on(self.client.list_components(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::list_container_recipes`].
+    pub fn list_container_recipes(
+        &self,
+        input: ListContainerRecipesRequest,
+    ) -> Result<ListContainerRecipesResponse, RusotoError<ListContainerRecipesError>> {
+        self.runtime
+            .block_on(self.client.list_container_recipes(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::list_distribution_configurations`].
+    pub fn list_distribution_configurations(
+        &self,
+        input: ListDistributionConfigurationsRequest,
+    ) -> Result<
+        ListDistributionConfigurationsResponse,
+        RusotoError<ListDistributionConfigurationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_distribution_configurations(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::list_image_build_versions`].
+    pub fn list_image_build_versions(
+        &self,
+        input: ListImageBuildVersionsRequest,
+    ) -> Result<ListImageBuildVersionsResponse, RusotoError<ListImageBuildVersionsError>> {
+        self.runtime
+            .block_on(self.client.list_image_build_versions(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::list_image_pipeline_images`].
+    pub fn list_image_pipeline_images(
+        &self,
+        input: ListImagePipelineImagesRequest,
+    ) -> Result<ListImagePipelineImagesResponse, RusotoError<ListImagePipelineImagesError>> {
+        self.runtime
+            .block_on(self.client.list_image_pipeline_images(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::list_image_pipelines`].
+    pub fn list_image_pipelines(
+        &self,
+        input: ListImagePipelinesRequest,
+    ) -> Result<ListImagePipelinesResponse, RusotoError<ListImagePipelinesError>> {
+        self.runtime
+            .block_on(self.client.list_image_pipelines(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::list_image_recipes`].
+    pub fn list_image_recipes(
+        &self,
+        input: ListImageRecipesRequest,
+    ) -> Result<ListImageRecipesResponse, RusotoError<ListImageRecipesError>> {
+        self.runtime.block_on(self.client.list_image_recipes(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::list_images`].
+    pub fn list_images(
+        &self,
+        input: ListImagesRequest,
+    ) -> Result<ListImagesResponse, RusotoError<ListImagesError>> {
+        self.runtime.block_on(self.client.list_images(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::list_infrastructure_configurations`].
+    pub fn list_infrastructure_configurations(
+        &self,
+        input: ListInfrastructureConfigurationsRequest,
+    ) -> Result<
+        ListInfrastructureConfigurationsResponse,
+        RusotoError<ListInfrastructureConfigurationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_infrastructure_configurations(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::put_component_policy`].
+    pub fn put_component_policy(
+        &self,
+        input: PutComponentPolicyRequest,
+    ) -> Result<PutComponentPolicyResponse, RusotoError<PutComponentPolicyError>> {
+        self.runtime
+            .block_on(self.client.put_component_policy(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::put_container_recipe_policy`].
+    pub fn put_container_recipe_policy(
+        &self,
+        input: PutContainerRecipePolicyRequest,
+    ) -> Result<PutContainerRecipePolicyResponse, RusotoError<PutContainerRecipePolicyError>> {
+        self.runtime
+            .block_on(self.client.put_container_recipe_policy(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::put_image_policy`].
+    pub fn put_image_policy(
+        &self,
+        input: PutImagePolicyRequest,
+    ) -> Result<PutImagePolicyResponse, RusotoError<PutImagePolicyError>> {
+        self.runtime.block_on(self.client.put_image_policy(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::put_image_recipe_policy`].
+    pub fn put_image_recipe_policy(
+        &self,
+        input: PutImageRecipePolicyRequest,
+    ) -> Result<PutImageRecipePolicyResponse, RusotoError<PutImageRecipePolicyError>> {
+        self.runtime
+            .block_on(self.client.put_image_recipe_policy(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::start_image_pipeline_execution`].
+    pub fn start_image_pipeline_execution(
+        &self,
+        input: StartImagePipelineExecutionRequest,
+    ) -> Result<StartImagePipelineExecutionResponse, RusotoError<StartImagePipelineExecutionError>>
+    {
+        self.runtime
+            .block_on(self.client.start_image_pipeline_execution(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::update_distribution_configuration`].
+    pub fn update_distribution_configuration(
+        &self,
+        input: UpdateDistributionConfigurationRequest,
+    ) -> Result<
+        UpdateDistributionConfigurationResponse,
+        RusotoError<UpdateDistributionConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_distribution_configuration(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::update_image_pipeline`].
+    pub fn update_image_pipeline(
+        &self,
+        input: UpdateImagePipelineRequest,
+    ) -> Result<UpdateImagePipelineResponse, RusotoError<UpdateImagePipelineError>> {
+        self.runtime
+            .block_on(self.client.update_image_pipeline(input))
+    }
+
+    /// Blocking version of [`ImageBuilder::update_infrastructure_configuration`].
+    pub fn update_infrastructure_configuration(
+        &self,
+        input: UpdateInfrastructureConfigurationRequest,
+    ) -> Result<
+        UpdateInfrastructureConfigurationResponse,
+        RusotoError<UpdateInfrastructureConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_infrastructure_configuration(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -881,34 +882,52 @@ impl CancelJobError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CanceledJobIdException" => {
-                        return RusotoError::Service(CancelJobError::CanceledJobId(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CancelJobError::CanceledJobId,
+                        )
                     }
                     "ExpiredJobIdException" => {
-                        return RusotoError::Service(CancelJobError::ExpiredJobId(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CancelJobError::ExpiredJobId,
+                        )
                     }
                     "InvalidAccessKeyIdException" => {
-                        return RusotoError::Service(CancelJobError::InvalidAccessKeyId(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CancelJobError::InvalidAccessKeyId,
+                        )
                     }
                     "InvalidJobIdException" => {
-                        return RusotoError::Service(CancelJobError::InvalidJobId(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CancelJobError::InvalidJobId,
+                        )
                     }
                     "InvalidVersionException" => {
-                        return RusotoError::Service(CancelJobError::InvalidVersion(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CancelJobError::InvalidVersion,
+                        )
                     }
                     "UnableToCancelJobIdException" => {
-                        return RusotoError::Service(CancelJobError::UnableToCancelJobId(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CancelJobError::UnableToCancelJobId,
+                        )
                     }
                     _ => {}
                 }
@@ -985,84 +1004,132 @@ impl CreateJobError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BucketPermissionException" => {
-                        return RusotoError::Service(CreateJobError::BucketPermission(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateJobError::BucketPermission,
+                        )
                     }
                     "CreateJobQuotaExceededException" => {
-                        return RusotoError::Service(CreateJobError::CreateJobQuotaExceeded(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateJobError::CreateJobQuotaExceeded,
+                        )
                     }
                     "InvalidAccessKeyIdException" => {
-                        return RusotoError::Service(CreateJobError::InvalidAccessKeyId(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateJobError::InvalidAccessKeyId,
+                        )
                     }
                     "InvalidAddressException" => {
-                        return RusotoError::Service(CreateJobError::InvalidAddress(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateJobError::InvalidAddress,
+                        )
                     }
                     "InvalidCustomsException" => {
-                        return RusotoError::Service(CreateJobError::InvalidCustoms(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateJobError::InvalidCustoms,
+                        )
                     }
                     "InvalidFileSystemException" => {
-                        return RusotoError::Service(CreateJobError::InvalidFileSystem(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateJobError::InvalidFileSystem,
+                        )
                     }
                     "InvalidJobIdException" => {
-                        return RusotoError::Service(CreateJobError::InvalidJobId(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateJobError::InvalidJobId,
+                        )
                     }
                     "InvalidManifestFieldException" => {
-                        return RusotoError::Service(CreateJobError::InvalidManifestField(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateJobError::InvalidManifestField,
+                        )
                     }
                     "InvalidParameterException" => {
-                        return RusotoError::Service(CreateJobError::InvalidParameter(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateJobError::InvalidParameter,
+                        )
                     }
                     "InvalidVersionException" => {
-                        return RusotoError::Service(CreateJobError::InvalidVersion(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateJobError::InvalidVersion,
+                        )
                     }
                     "MalformedManifestException" => {
-                        return RusotoError::Service(CreateJobError::MalformedManifest(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateJobError::MalformedManifest,
+                        )
                     }
                     "MissingCustomsException" => {
-                        return RusotoError::Service(CreateJobError::MissingCustoms(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateJobError::MissingCustoms,
+                        )
                     }
                     "MissingManifestFieldException" => {
-                        return RusotoError::Service(CreateJobError::MissingManifestField(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateJobError::MissingManifestField,
+                        )
                     }
                     "MissingParameterException" => {
-                        return RusotoError::Service(CreateJobError::MissingParameter(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateJobError::MissingParameter,
+                        )
                     }
                     "MultipleRegionsException" => {
-                        return RusotoError::Service(CreateJobError::MultipleRegions(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateJobError::MultipleRegions,
+                        )
                     }
                     "NoSuchBucketException" => {
-                        return RusotoError::Service(CreateJobError::NoSuchBucket(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateJobError::NoSuchBucket,
+                        )
                     }
                     _ => {}
                 }
@@ -1131,39 +1198,60 @@ impl GetShippingLabelError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CanceledJobIdException" => {
-                        return RusotoError::Service(GetShippingLabelError::CanceledJobId(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetShippingLabelError::CanceledJobId,
+                        )
                     }
                     "ExpiredJobIdException" => {
-                        return RusotoError::Service(GetShippingLabelError::ExpiredJobId(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetShippingLabelError::ExpiredJobId,
+                        )
                     }
                     "InvalidAccessKeyIdException" => {
-                        return RusotoError::Service(GetShippingLabelError::InvalidAccessKeyId(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetShippingLabelError::InvalidAccessKeyId,
+                        )
                     }
                     "InvalidAddressException" => {
-                        return RusotoError::Service(GetShippingLabelError::InvalidAddress(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetShippingLabelError::InvalidAddress,
+                        )
                     }
                     "InvalidJobIdException" => {
-                        return RusotoError::Service(GetShippingLabelError::InvalidJobId(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetShippingLabelError::InvalidJobId,
+                        )
                     }
                     "InvalidParameterException" => {
-                        return RusotoError::Service(GetShippingLabelError::InvalidParameter(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetShippingLabelError::InvalidParameter,
+                        )
                     }
                     "InvalidVersionException" => {
-                        return RusotoError::Service(GetShippingLabelError::InvalidVersion(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetShippingLabelError::InvalidVersion,
+                        )
                     }
                     _ => {}
                 }
@@ -1219,29 +1307,44 @@ impl GetStatusError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CanceledJobIdException" => {
-                        return RusotoError::Service(GetStatusError::CanceledJobId(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetStatusError::CanceledJobId,
+                        )
                     }
                     "ExpiredJobIdException" => {
-                        return RusotoError::Service(GetStatusError::ExpiredJobId(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetStatusError::ExpiredJobId,
+                        )
                     }
                     "InvalidAccessKeyIdException" => {
-                        return RusotoError::Service(GetStatusError::InvalidAccessKeyId(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetStatusError::InvalidAccessKeyId,
+                        )
                     }
                     "InvalidJobIdException" => {
-                        return RusotoError::Service(GetStatusError::InvalidJobId(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetStatusError::InvalidJobId,
+                        )
                     }
                     "InvalidVersionException" => {
-                        return RusotoError::Service(GetStatusError::InvalidVersion(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetStatusError::InvalidVersion,
+                        )
                     }
                     _ => {}
                 }
@@ -1291,19 +1394,28 @@ impl ListJobsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidAccessKeyIdException" => {
-                        return RusotoError::Service(ListJobsError::InvalidAccessKeyId(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListJobsError::InvalidAccessKeyId,
+                        )
                     }
                     "InvalidParameterException" => {
-                        return RusotoError::Service(ListJobsError::InvalidParameter(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListJobsError::InvalidParameter,
+                        )
                     }
                     "InvalidVersionException" => {
-                        return RusotoError::Service(ListJobsError::InvalidVersion(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListJobsError::InvalidVersion,
+                        )
                     }
                     _ => {}
                 }
@@ -1381,94 +1493,148 @@ impl UpdateJobError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BucketPermissionException" => {
-                        return RusotoError::Service(UpdateJobError::BucketPermission(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateJobError::BucketPermission,
+                        )
                     }
                     "CanceledJobIdException" => {
-                        return RusotoError::Service(UpdateJobError::CanceledJobId(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateJobError::CanceledJobId,
+                        )
                     }
                     "ExpiredJobIdException" => {
-                        return RusotoError::Service(UpdateJobError::ExpiredJobId(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateJobError::ExpiredJobId,
+                        )
                     }
                     "InvalidAccessKeyIdException" => {
-                        return RusotoError::Service(UpdateJobError::InvalidAccessKeyId(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateJobError::InvalidAccessKeyId,
+                        )
                     }
                     "InvalidAddressException" => {
-                        return RusotoError::Service(UpdateJobError::InvalidAddress(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateJobError::InvalidAddress,
+                        )
                     }
                     "InvalidCustomsException" => {
-                        return RusotoError::Service(UpdateJobError::InvalidCustoms(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateJobError::InvalidCustoms,
+                        )
                     }
                     "InvalidFileSystemException" => {
-                        return RusotoError::Service(UpdateJobError::InvalidFileSystem(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateJobError::InvalidFileSystem,
+                        )
                     }
                     "InvalidJobIdException" => {
-                        return RusotoError::Service(UpdateJobError::InvalidJobId(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateJobError::InvalidJobId,
+                        )
                     }
                     "InvalidManifestFieldException" => {
-                        return RusotoError::Service(UpdateJobError::InvalidManifestField(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateJobError::InvalidManifestField,
+                        )
                     }
                     "InvalidParameterException" => {
-                        return RusotoError::Service(UpdateJobError::InvalidParameter(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateJobError::InvalidParameter,
+                        )
                     }
                     "InvalidVersionException" => {
-                        return RusotoError::Service(UpdateJobError::InvalidVersion(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateJobError::InvalidVersion,
+                        )
                     }
                     "MalformedManifestException" => {
-                        return RusotoError::Service(UpdateJobError::MalformedManifest(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateJobError::MalformedManifest,
+                        )
                     }
                     "MissingCustomsException" => {
-                        return RusotoError::Service(UpdateJobError::MissingCustoms(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateJobError::MissingCustoms,
+                        )
                     }
                     "MissingManifestFieldException" => {
-                        return RusotoError::Service(UpdateJobError::MissingManifestField(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateJobError::MissingManifestField,
+                        )
                     }
                     "MissingParameterException" => {
-                        return RusotoError::Service(UpdateJobError::MissingParameter(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateJobError::MissingParameter,
+                        )
                     }
                     "MultipleRegionsException" => {
-                        return RusotoError::Service(UpdateJobError::MultipleRegions(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateJobError::MultipleRegions,
+                        )
                     }
                     "NoSuchBucketException" => {
-                        return RusotoError::Service(UpdateJobError::NoSuchBucket(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateJobError::NoSuchBucket,
+                        )
                     }
                     "UnableToUpdateJobIdException" => {
-                        return RusotoError::Service(UpdateJobError::UnableToUpdateJobId(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateJobError::UnableToUpdateJobId,
+                        )
                     }
                     _ => {}
                 }
@@ -1560,11 +1726,12 @@ pub struct ImportExportClient {
 impl ImportExportClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> ImportExportClient {
         ImportExportClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "ImportExport"),
         }
     }
 
@@ -1579,12 +1746,32 @@ impl ImportExportClient {
     {
         ImportExportClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "ImportExport"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ImportExportClient {
-        ImportExportClient { client, region }
+        ImportExportClient {
+            client,
+            region: region_for_service(region, "ImportExport"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> ImportExportClient {
+        ImportExportClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("ImportExport"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ImportExportClient {
+        ImportExportClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -1601,6 +1788,8 @@ impl ImportExport for ImportExportClient {
             &self.region,
             "/?Operation=CancelJob",
         );
+        request.set_operation("CancelJob");
+        request.set_signing_algorithm(rusoto_core::signature::SigningAlgorithm::SigV2);
         let params = self.new_params("CancelJob");
         let mut params = params;
         CancelJobInputSerializer::serialize(&mut params, "", &input);
@@ -1636,6 +1825,8 @@ impl ImportExport for ImportExportClient {
             &self.region,
             "/?Operation=CreateJob",
         );
+        request.set_operation("CreateJob");
+        request.set_signing_algorithm(rusoto_core::signature::SigningAlgorithm::SigV2);
         let params = self.new_params("CreateJob");
         let mut params = params;
         CreateJobInputSerializer::serialize(&mut params, "", &input);
@@ -1671,6 +1862,8 @@ impl ImportExport for ImportExportClient {
             &self.region,
             "/?Operation=GetShippingLabel",
         );
+        request.set_operation("GetShippingLabel");
+        request.set_signing_algorithm(rusoto_core::signature::SigningAlgorithm::SigV2);
         let params = self.new_params("GetShippingLabel");
         let mut params = params;
         GetShippingLabelInputSerializer::serialize(&mut params, "", &input);
@@ -1707,6 +1900,8 @@ impl ImportExport for ImportExportClient {
             &self.region,
             "/?Operation=GetStatus",
         );
+        request.set_operation("GetStatus");
+        request.set_signing_algorithm(rusoto_core::signature::SigningAlgorithm::SigV2);
         let params = self.new_params("GetStatus");
         let mut params = params;
         GetStatusInputSerializer::serialize(&mut params, "", &input);
@@ -1738,6 +1933,8 @@ impl ImportExport for ImportExportClient {
     ) -> Result<ListJobsOutput, RusotoError<ListJobsError>> {
         let mut request =
             SignedRequest::new("POST", "importexport", &self.region, "/?Operation=ListJobs");
+        request.set_operation("ListJobs");
+        request.set_signing_algorithm(rusoto_core::signature::SigningAlgorithm::SigV2);
         let params = self.new_params("ListJobs");
         let mut params = params;
         ListJobsInputSerializer::serialize(&mut params, "", &input);
@@ -1773,6 +1970,8 @@ impl ImportExport for ImportExportClient {
             &self.region,
             "/?Operation=UpdateJob",
         );
+        request.set_operation("UpdateJob");
+        request.set_signing_algorithm(rusoto_core::signature::SigningAlgorithm::SigV2);
         let params = self.new_params("UpdateJob");
         let mut params = params;
         UpdateJobInputSerializer::serialize(&mut params, "", &input);
@@ -1798,6 +1997,132 @@ impl ImportExport for ImportExportClient {
     }
 }
 
+/// A blocking client for the AWS Import/Export API, running the calls of a `ImportExportClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `ImportExportClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ImportExportBlockingClient {
+    client: ImportExportClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ImportExportBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> ImportExportBlockingClient {
+        ImportExportBlockingClient::from_client(ImportExportClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ImportExportBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ImportExportBlockingClient::from_client(ImportExportClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> ImportExportBlockingClient {
+        ImportExportBlockingClient::from_client(ImportExportClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> ImportExportBlockingClient {
+        ImportExportBlockingClient::from_client(ImportExportClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: ImportExportClient) -> ImportExportBlockingClient {
+        ImportExportBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> ImportExportBlockingClient {
+        ImportExportBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ImportExportBlockingClient {
+        ImportExportBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`ImportExport::cancel_job`].
+    pub fn cancel_job(
+        &self,
+        input: CancelJobInput,
+    ) -> Result<CancelJobOutput, RusotoError<CancelJobError>> {
+        self.runtime.block_on(self.client.cancel_job(input))
+    }
+
+    /// Blocking version of [`ImportExport::create_job`].
+    pub fn create_job(
+        &self,
+        input: CreateJobInput,
+    ) -> Result<CreateJobOutput, RusotoError<CreateJobError>> {
+        self.runtime.block_on(self.client.create_job(input))
+    }
+
+    /// Blocking version of [`ImportExport::get_shipping_label`].
+    pub fn get_shipping_label(
+        &self,
+        input: GetShippingLabelInput,
+    ) -> Result<GetShippingLabelOutput, RusotoError<GetShippingLabelError>> {
+        self.runtime.block_on(self.client.get_shipping_label(input))
+    }
+
+    /// Blocking version of [`ImportExport::get_status`].
+    pub fn get_status(
+        &self,
+        input: GetStatusInput,
+    ) -> Result<GetStatusOutput, RusotoError<GetStatusError>> {
+        self.runtime.block_on(self.client.get_status(input))
+    }
+
+    /// Blocking version of [`ImportExport::list_jobs`].
+    pub fn list_jobs(
+        &self,
+        input: ListJobsInput,
+    ) -> Result<ListJobsOutput, RusotoError<ListJobsError>> {
+        self.runtime.block_on(self.client.list_jobs(input))
+    }
+
+    /// Blocking version of [`ImportExport::update_job`].
+    pub fn update_job(
+        &self,
+        input: UpdateJobInput,
+    ) -> Result<UpdateJobOutput, RusotoError<UpdateJobError>> {
+        self.runtime.block_on(self.client.update_job(input))
+    }
+}
+
 #[cfg(test)]
 mod protocol_tests {
 
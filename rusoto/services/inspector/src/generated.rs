@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1586,29 +1587,46 @@ impl AddAttributesToFindingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AddAttributesToFindingsError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddAttributesToFindingsError::AccessDenied,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(AddAttributesToFindingsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddAttributesToFindingsError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AddAttributesToFindingsError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddAttributesToFindingsError::InvalidInput,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(AddAttributesToFindingsError::NoSuchEntity(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddAttributesToFindingsError::NoSuchEntity,
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
-                        AddAttributesToFindingsError::ServiceTemporarilyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddAttributesToFindingsError::ServiceTemporarilyUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1654,33 +1672,62 @@ impl CreateAssessmentTargetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateAssessmentTargetError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAssessmentTargetError::AccessDenied,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(CreateAssessmentTargetError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAssessmentTargetError::Internal,
+                    )
                 }
                 "InvalidCrossAccountRoleException" => {
-                    return RusotoError::Service(
-                        CreateAssessmentTargetError::InvalidCrossAccountRole(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAssessmentTargetError::InvalidCrossAccountRole,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateAssessmentTargetError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAssessmentTargetError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateAssessmentTargetError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAssessmentTargetError::LimitExceeded,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(CreateAssessmentTargetError::NoSuchEntity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAssessmentTargetError::NoSuchEntity,
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
-                        CreateAssessmentTargetError::ServiceTemporarilyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAssessmentTargetError::ServiceTemporarilyUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1728,34 +1775,54 @@ impl CreateAssessmentTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateAssessmentTemplateError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAssessmentTemplateError::AccessDenied,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(CreateAssessmentTemplateError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAssessmentTemplateError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateAssessmentTemplateError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAssessmentTemplateError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateAssessmentTemplateError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAssessmentTemplateError::LimitExceeded,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(CreateAssessmentTemplateError::NoSuchEntity(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAssessmentTemplateError::NoSuchEntity,
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
-                        CreateAssessmentTemplateError::ServiceTemporarilyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAssessmentTemplateError::ServiceTemporarilyUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1800,34 +1867,54 @@ impl CreateExclusionsPreviewError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateExclusionsPreviewError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateExclusionsPreviewError::AccessDenied,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(CreateExclusionsPreviewError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateExclusionsPreviewError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateExclusionsPreviewError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateExclusionsPreviewError::InvalidInput,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(CreateExclusionsPreviewError::NoSuchEntity(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateExclusionsPreviewError::NoSuchEntity,
+                    )
                 }
                 "PreviewGenerationInProgressException" => {
-                    return RusotoError::Service(
-                        CreateExclusionsPreviewError::PreviewGenerationInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateExclusionsPreviewError::PreviewGenerationInProgress,
                     )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
-                        CreateExclusionsPreviewError::ServiceTemporarilyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateExclusionsPreviewError::ServiceTemporarilyUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1872,23 +1959,46 @@ impl CreateResourceGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateResourceGroupError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResourceGroupError::AccessDenied,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(CreateResourceGroupError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResourceGroupError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateResourceGroupError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResourceGroupError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateResourceGroupError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResourceGroupError::LimitExceeded,
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
-                        CreateResourceGroupError::ServiceTemporarilyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResourceGroupError::ServiceTemporarilyUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1932,28 +2042,54 @@ impl DeleteAssessmentRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteAssessmentRunError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAssessmentRunError::AccessDenied,
+                    )
                 }
                 "AssessmentRunInProgressException" => {
-                    return RusotoError::Service(DeleteAssessmentRunError::AssessmentRunInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteAssessmentRunError::AssessmentRunInProgress,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DeleteAssessmentRunError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAssessmentRunError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteAssessmentRunError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAssessmentRunError::InvalidInput,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(DeleteAssessmentRunError::NoSuchEntity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAssessmentRunError::NoSuchEntity,
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
-                        DeleteAssessmentRunError::ServiceTemporarilyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAssessmentRunError::ServiceTemporarilyUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1998,28 +2134,54 @@ impl DeleteAssessmentTargetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteAssessmentTargetError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAssessmentTargetError::AccessDenied,
+                    )
                 }
                 "AssessmentRunInProgressException" => {
-                    return RusotoError::Service(
-                        DeleteAssessmentTargetError::AssessmentRunInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAssessmentTargetError::AssessmentRunInProgress,
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DeleteAssessmentTargetError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAssessmentTargetError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteAssessmentTargetError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAssessmentTargetError::InvalidInput,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(DeleteAssessmentTargetError::NoSuchEntity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAssessmentTargetError::NoSuchEntity,
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
-                        DeleteAssessmentTargetError::ServiceTemporarilyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAssessmentTargetError::ServiceTemporarilyUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2066,34 +2228,54 @@ impl DeleteAssessmentTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteAssessmentTemplateError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteAssessmentTemplateError::AccessDenied,
+                    )
                 }
                 "AssessmentRunInProgressException" => {
-                    return RusotoError::Service(
-                        DeleteAssessmentTemplateError::AssessmentRunInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAssessmentTemplateError::AssessmentRunInProgress,
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DeleteAssessmentTemplateError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAssessmentTemplateError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteAssessmentTemplateError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteAssessmentTemplateError::InvalidInput,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(DeleteAssessmentTemplateError::NoSuchEntity(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteAssessmentTemplateError::NoSuchEntity,
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
-                        DeleteAssessmentTemplateError::ServiceTemporarilyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAssessmentTemplateError::ServiceTemporarilyUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2132,12 +2314,22 @@ impl DescribeAssessmentRunsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeAssessmentRunsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAssessmentRunsError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeAssessmentRunsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAssessmentRunsError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2168,14 +2360,22 @@ impl DescribeAssessmentTargetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeAssessmentTargetsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAssessmentTargetsError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeAssessmentTargetsError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAssessmentTargetsError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2208,16 +2408,22 @@ impl DescribeAssessmentTemplatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeAssessmentTemplatesError::Internal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAssessmentTemplatesError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeAssessmentTemplatesError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAssessmentTemplatesError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2248,11 +2454,14 @@ impl DescribeCrossAccountAccessRoleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeCrossAccountAccessRoleError::Internal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeCrossAccountAccessRoleError::Internal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2282,12 +2491,22 @@ impl DescribeExclusionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeExclusionsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeExclusionsError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeExclusionsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeExclusionsError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2318,12 +2537,22 @@ impl DescribeFindingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeFindingsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeFindingsError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeFindingsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeFindingsError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2354,12 +2583,22 @@ impl DescribeResourceGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeResourceGroupsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeResourceGroupsError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeResourceGroupsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeResourceGroupsError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2390,12 +2629,22 @@ impl DescribeRulesPackagesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DescribeRulesPackagesError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRulesPackagesError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeRulesPackagesError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRulesPackagesError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2436,33 +2685,62 @@ impl GetAssessmentReportError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetAssessmentReportError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAssessmentReportError::AccessDenied,
+                    )
                 }
                 "AssessmentRunInProgressException" => {
-                    return RusotoError::Service(GetAssessmentReportError::AssessmentRunInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetAssessmentReportError::AssessmentRunInProgress,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(GetAssessmentReportError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAssessmentReportError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetAssessmentReportError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAssessmentReportError::InvalidInput,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(GetAssessmentReportError::NoSuchEntity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAssessmentReportError::NoSuchEntity,
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
-                        GetAssessmentReportError::ServiceTemporarilyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAssessmentReportError::ServiceTemporarilyUnavailable,
                     )
                 }
                 "UnsupportedFeatureException" => {
-                    return RusotoError::Service(GetAssessmentReportError::UnsupportedFeature(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetAssessmentReportError::UnsupportedFeature,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2504,18 +2782,38 @@ impl GetExclusionsPreviewError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetExclusionsPreviewError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetExclusionsPreviewError::AccessDenied,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(GetExclusionsPreviewError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetExclusionsPreviewError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetExclusionsPreviewError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetExclusionsPreviewError::InvalidInput,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(GetExclusionsPreviewError::NoSuchEntity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetExclusionsPreviewError::NoSuchEntity,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2552,18 +2850,38 @@ impl GetTelemetryMetadataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetTelemetryMetadataError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTelemetryMetadataError::AccessDenied,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(GetTelemetryMetadataError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTelemetryMetadataError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetTelemetryMetadataError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTelemetryMetadataError::InvalidInput,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(GetTelemetryMetadataError::NoSuchEntity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTelemetryMetadataError::NoSuchEntity,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2600,24 +2918,38 @@ impl ListAssessmentRunAgentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListAssessmentRunAgentsError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAssessmentRunAgentsError::AccessDenied,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ListAssessmentRunAgentsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAssessmentRunAgentsError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListAssessmentRunAgentsError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAssessmentRunAgentsError::InvalidInput,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(ListAssessmentRunAgentsError::NoSuchEntity(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAssessmentRunAgentsError::NoSuchEntity,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2654,18 +2986,38 @@ impl ListAssessmentRunsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListAssessmentRunsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAssessmentRunsError::AccessDenied,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ListAssessmentRunsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAssessmentRunsError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListAssessmentRunsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAssessmentRunsError::InvalidInput,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(ListAssessmentRunsError::NoSuchEntity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAssessmentRunsError::NoSuchEntity,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2700,15 +3052,30 @@ impl ListAssessmentTargetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListAssessmentTargetsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAssessmentTargetsError::AccessDenied,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ListAssessmentTargetsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAssessmentTargetsError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListAssessmentTargetsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAssessmentTargetsError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2744,24 +3111,38 @@ impl ListAssessmentTemplatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListAssessmentTemplatesError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAssessmentTemplatesError::AccessDenied,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ListAssessmentTemplatesError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAssessmentTemplatesError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListAssessmentTemplatesError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAssessmentTemplatesError::InvalidInput,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(ListAssessmentTemplatesError::NoSuchEntity(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAssessmentTemplatesError::NoSuchEntity,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2798,18 +3179,38 @@ impl ListEventSubscriptionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListEventSubscriptionsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListEventSubscriptionsError::AccessDenied,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ListEventSubscriptionsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListEventSubscriptionsError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListEventSubscriptionsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListEventSubscriptionsError::InvalidInput,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(ListEventSubscriptionsError::NoSuchEntity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListEventSubscriptionsError::NoSuchEntity,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2846,18 +3247,38 @@ impl ListExclusionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListExclusionsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListExclusionsError::AccessDenied,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ListExclusionsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListExclusionsError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListExclusionsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListExclusionsError::InvalidInput,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(ListExclusionsError::NoSuchEntity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListExclusionsError::NoSuchEntity,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2894,18 +3315,38 @@ impl ListFindingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListFindingsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFindingsError::AccessDenied,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ListFindingsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFindingsError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListFindingsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFindingsError::InvalidInput,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(ListFindingsError::NoSuchEntity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFindingsError::NoSuchEntity,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2940,15 +3381,30 @@ impl ListRulesPackagesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListRulesPackagesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRulesPackagesError::AccessDenied,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ListRulesPackagesError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRulesPackagesError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListRulesPackagesError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRulesPackagesError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2984,18 +3440,38 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::AccessDenied,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InvalidInput,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NoSuchEntity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::NoSuchEntity,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3034,23 +3510,46 @@ impl PreviewAgentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(PreviewAgentsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PreviewAgentsError::AccessDenied,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(PreviewAgentsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PreviewAgentsError::Internal,
+                    )
                 }
                 "InvalidCrossAccountRoleException" => {
-                    return RusotoError::Service(PreviewAgentsError::InvalidCrossAccountRole(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PreviewAgentsError::InvalidCrossAccountRole,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(PreviewAgentsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PreviewAgentsError::InvalidInput,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(PreviewAgentsError::NoSuchEntity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PreviewAgentsError::NoSuchEntity,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3092,31 +3591,46 @@ impl RegisterCrossAccountAccessRoleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RegisterCrossAccountAccessRoleError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterCrossAccountAccessRoleError::AccessDenied,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(RegisterCrossAccountAccessRoleError::Internal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterCrossAccountAccessRoleError::Internal,
+                    )
                 }
                 "InvalidCrossAccountRoleException" => {
-                    return RusotoError::Service(
-                        RegisterCrossAccountAccessRoleError::InvalidCrossAccountRole(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterCrossAccountAccessRoleError::InvalidCrossAccountRole,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(RegisterCrossAccountAccessRoleError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterCrossAccountAccessRoleError::InvalidInput,
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
-                        RegisterCrossAccountAccessRoleError::ServiceTemporarilyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterCrossAccountAccessRoleError::ServiceTemporarilyUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3162,31 +3676,46 @@ impl RemoveAttributesFromFindingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RemoveAttributesFromFindingsError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveAttributesFromFindingsError::AccessDenied,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(RemoveAttributesFromFindingsError::Internal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveAttributesFromFindingsError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(RemoveAttributesFromFindingsError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveAttributesFromFindingsError::InvalidInput,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(RemoveAttributesFromFindingsError::NoSuchEntity(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveAttributesFromFindingsError::NoSuchEntity,
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
-                        RemoveAttributesFromFindingsError::ServiceTemporarilyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveAttributesFromFindingsError::ServiceTemporarilyUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3228,23 +3757,46 @@ impl SetTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(SetTagsForResourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetTagsForResourceError::AccessDenied,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(SetTagsForResourceError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetTagsForResourceError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(SetTagsForResourceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetTagsForResourceError::InvalidInput,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(SetTagsForResourceError::NoSuchEntity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetTagsForResourceError::NoSuchEntity,
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
-                        SetTagsForResourceError::ServiceTemporarilyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetTagsForResourceError::ServiceTemporarilyUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3292,36 +3844,70 @@ impl StartAssessmentRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartAssessmentRunError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartAssessmentRunError::AccessDenied,
+                    )
                 }
                 "AgentsAlreadyRunningAssessmentException" => {
-                    return RusotoError::Service(
-                        StartAssessmentRunError::AgentsAlreadyRunningAssessment(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartAssessmentRunError::AgentsAlreadyRunningAssessment,
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(StartAssessmentRunError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartAssessmentRunError::Internal,
+                    )
                 }
                 "InvalidCrossAccountRoleException" => {
-                    return RusotoError::Service(StartAssessmentRunError::InvalidCrossAccountRole(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartAssessmentRunError::InvalidCrossAccountRole,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StartAssessmentRunError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartAssessmentRunError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartAssessmentRunError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartAssessmentRunError::LimitExceeded,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(StartAssessmentRunError::NoSuchEntity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartAssessmentRunError::NoSuchEntity,
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
-                        StartAssessmentRunError::ServiceTemporarilyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartAssessmentRunError::ServiceTemporarilyUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3368,23 +3954,46 @@ impl StopAssessmentRunError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StopAssessmentRunError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopAssessmentRunError::AccessDenied,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(StopAssessmentRunError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopAssessmentRunError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StopAssessmentRunError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopAssessmentRunError::InvalidInput,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(StopAssessmentRunError::NoSuchEntity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopAssessmentRunError::NoSuchEntity,
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
-                        StopAssessmentRunError::ServiceTemporarilyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopAssessmentRunError::ServiceTemporarilyUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3428,26 +4037,54 @@ impl SubscribeToEventError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(SubscribeToEventError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SubscribeToEventError::AccessDenied,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(SubscribeToEventError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SubscribeToEventError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(SubscribeToEventError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SubscribeToEventError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(SubscribeToEventError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SubscribeToEventError::LimitExceeded,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(SubscribeToEventError::NoSuchEntity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SubscribeToEventError::NoSuchEntity,
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
-                        SubscribeToEventError::ServiceTemporarilyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SubscribeToEventError::ServiceTemporarilyUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3490,23 +4127,46 @@ impl UnsubscribeFromEventError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UnsubscribeFromEventError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UnsubscribeFromEventError::AccessDenied,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(UnsubscribeFromEventError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UnsubscribeFromEventError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UnsubscribeFromEventError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UnsubscribeFromEventError::InvalidInput,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(UnsubscribeFromEventError::NoSuchEntity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UnsubscribeFromEventError::NoSuchEntity,
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
-                        UnsubscribeFromEventError::ServiceTemporarilyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UnsubscribeFromEventError::ServiceTemporarilyUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3548,23 +4208,46 @@ impl UpdateAssessmentTargetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateAssessmentTargetError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAssessmentTargetError::AccessDenied,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(UpdateAssessmentTargetError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAssessmentTargetError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateAssessmentTargetError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAssessmentTargetError::InvalidInput,
+                    )
                 }
                 "NoSuchEntityException" => {
-                    return RusotoError::Service(UpdateAssessmentTargetError::NoSuchEntity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAssessmentTargetError::NoSuchEntity,
+                    )
                 }
                 "ServiceTemporarilyUnavailableException" => {
-                    return RusotoError::Service(
-                        UpdateAssessmentTargetError::ServiceTemporarilyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAssessmentTargetError::ServiceTemporarilyUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3823,11 +4506,12 @@ pub struct InspectorClient {
 impl InspectorClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> InspectorClient {
         InspectorClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Inspector"),
         }
     }
 
@@ -3842,12 +4526,32 @@ impl InspectorClient {
     {
         InspectorClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Inspector"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> InspectorClient {
-        InspectorClient { client, region }
+        InspectorClient {
+            client,
+            region: region_for_service(region, "Inspector"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> InspectorClient {
+        InspectorClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Inspector"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> InspectorClient {
+        InspectorClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -3859,6 +4563,7 @@ impl Inspector for InspectorClient {
         input: AddAttributesToFindingsRequest,
     ) -> Result<AddAttributesToFindingsResponse, RusotoError<AddAttributesToFindingsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AddAttributesToFindings");
         request.add_header("x-amz-target", "InspectorService.AddAttributesToFindings");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3878,6 +4583,7 @@ impl Inspector for InspectorClient {
         input: CreateAssessmentTargetRequest,
     ) -> Result<CreateAssessmentTargetResponse, RusotoError<CreateAssessmentTargetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateAssessmentTarget");
         request.add_header("x-amz-target", "InspectorService.CreateAssessmentTarget");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3897,6 +4603,7 @@ impl Inspector for InspectorClient {
         input: CreateAssessmentTemplateRequest,
     ) -> Result<CreateAssessmentTemplateResponse, RusotoError<CreateAssessmentTemplateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateAssessmentTemplate");
         request.add_header("x-amz-target", "InspectorService.CreateAssessmentTemplate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3916,6 +4623,7 @@ impl Inspector for InspectorClient {
         input: CreateExclusionsPreviewRequest,
     ) -> Result<CreateExclusionsPreviewResponse, RusotoError<CreateExclusionsPreviewError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateExclusionsPreview");
         request.add_header("x-amz-target", "InspectorService.CreateExclusionsPreview");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3935,6 +4643,7 @@ impl Inspector for InspectorClient {
         input: CreateResourceGroupRequest,
     ) -> Result<CreateResourceGroupResponse, RusotoError<CreateResourceGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateResourceGroup");
         request.add_header("x-amz-target", "InspectorService.CreateResourceGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3953,6 +4662,7 @@ impl Inspector for InspectorClient {
         input: DeleteAssessmentRunRequest,
     ) -> Result<(), RusotoError<DeleteAssessmentRunError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteAssessmentRun");
         request.add_header("x-amz-target", "InspectorService.DeleteAssessmentRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3970,6 +4680,7 @@ impl Inspector for InspectorClient {
         input: DeleteAssessmentTargetRequest,
     ) -> Result<(), RusotoError<DeleteAssessmentTargetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteAssessmentTarget");
         request.add_header("x-amz-target", "InspectorService.DeleteAssessmentTarget");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3987,6 +4698,7 @@ impl Inspector for InspectorClient {
         input: DeleteAssessmentTemplateRequest,
     ) -> Result<(), RusotoError<DeleteAssessmentTemplateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteAssessmentTemplate");
         request.add_header("x-amz-target", "InspectorService.DeleteAssessmentTemplate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4004,6 +4716,7 @@ impl Inspector for InspectorClient {
         input: DescribeAssessmentRunsRequest,
     ) -> Result<DescribeAssessmentRunsResponse, RusotoError<DescribeAssessmentRunsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeAssessmentRuns");
         request.add_header("x-amz-target", "InspectorService.DescribeAssessmentRuns");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4024,6 +4737,7 @@ impl Inspector for InspectorClient {
     ) -> Result<DescribeAssessmentTargetsResponse, RusotoError<DescribeAssessmentTargetsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeAssessmentTargets");
         request.add_header("x-amz-target", "InspectorService.DescribeAssessmentTargets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4044,6 +4758,7 @@ impl Inspector for InspectorClient {
     ) -> Result<DescribeAssessmentTemplatesResponse, RusotoError<DescribeAssessmentTemplatesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeAssessmentTemplates");
         request.add_header(
             "x-amz-target",
             "InspectorService.DescribeAssessmentTemplates",
@@ -4068,6 +4783,7 @@ impl Inspector for InspectorClient {
         RusotoError<DescribeCrossAccountAccessRoleError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeCrossAccountAccessRole");
         request.add_header(
             "x-amz-target",
             "InspectorService.DescribeCrossAccountAccessRole",
@@ -4089,6 +4805,7 @@ impl Inspector for InspectorClient {
         input: DescribeExclusionsRequest,
     ) -> Result<DescribeExclusionsResponse, RusotoError<DescribeExclusionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeExclusions");
         request.add_header("x-amz-target", "InspectorService.DescribeExclusions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4107,6 +4824,7 @@ impl Inspector for InspectorClient {
         input: DescribeFindingsRequest,
     ) -> Result<DescribeFindingsResponse, RusotoError<DescribeFindingsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeFindings");
         request.add_header("x-amz-target", "InspectorService.DescribeFindings");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4125,6 +4843,7 @@ impl Inspector for InspectorClient {
         input: DescribeResourceGroupsRequest,
     ) -> Result<DescribeResourceGroupsResponse, RusotoError<DescribeResourceGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeResourceGroups");
         request.add_header("x-amz-target", "InspectorService.DescribeResourceGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4144,6 +4863,7 @@ impl Inspector for InspectorClient {
         input: DescribeRulesPackagesRequest,
     ) -> Result<DescribeRulesPackagesResponse, RusotoError<DescribeRulesPackagesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeRulesPackages");
         request.add_header("x-amz-target", "InspectorService.DescribeRulesPackages");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4163,6 +4883,7 @@ impl Inspector for InspectorClient {
         input: GetAssessmentReportRequest,
     ) -> Result<GetAssessmentReportResponse, RusotoError<GetAssessmentReportError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetAssessmentReport");
         request.add_header("x-amz-target", "InspectorService.GetAssessmentReport");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4181,6 +4902,7 @@ impl Inspector for InspectorClient {
         input: GetExclusionsPreviewRequest,
     ) -> Result<GetExclusionsPreviewResponse, RusotoError<GetExclusionsPreviewError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetExclusionsPreview");
         request.add_header("x-amz-target", "InspectorService.GetExclusionsPreview");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4200,6 +4922,7 @@ impl Inspector for InspectorClient {
         input: GetTelemetryMetadataRequest,
     ) -> Result<GetTelemetryMetadataResponse, RusotoError<GetTelemetryMetadataError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetTelemetryMetadata");
         request.add_header("x-amz-target", "InspectorService.GetTelemetryMetadata");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4219,6 +4942,7 @@ impl Inspector for InspectorClient {
         input: ListAssessmentRunAgentsRequest,
     ) -> Result<ListAssessmentRunAgentsResponse, RusotoError<ListAssessmentRunAgentsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListAssessmentRunAgents");
         request.add_header("x-amz-target", "InspectorService.ListAssessmentRunAgents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4238,6 +4962,7 @@ impl Inspector for InspectorClient {
         input: ListAssessmentRunsRequest,
     ) -> Result<ListAssessmentRunsResponse, RusotoError<ListAssessmentRunsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListAssessmentRuns");
         request.add_header("x-amz-target", "InspectorService.ListAssessmentRuns");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4256,6 +4981,7 @@ impl Inspector for InspectorClient {
         input: ListAssessmentTargetsRequest,
     ) -> Result<ListAssessmentTargetsResponse, RusotoError<ListAssessmentTargetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListAssessmentTargets");
         request.add_header("x-amz-target", "InspectorService.ListAssessmentTargets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4275,6 +5001,7 @@ impl Inspector for InspectorClient {
         input: ListAssessmentTemplatesRequest,
     ) -> Result<ListAssessmentTemplatesResponse, RusotoError<ListAssessmentTemplatesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListAssessmentTemplates");
         request.add_header("x-amz-target", "InspectorService.ListAssessmentTemplates");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4294,6 +5021,7 @@ impl Inspector for InspectorClient {
         input: ListEventSubscriptionsRequest,
     ) -> Result<ListEventSubscriptionsResponse, RusotoError<ListEventSubscriptionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListEventSubscriptions");
         request.add_header("x-amz-target", "InspectorService.ListEventSubscriptions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4313,6 +5041,7 @@ impl Inspector for InspectorClient {
         input: ListExclusionsRequest,
     ) -> Result<ListExclusionsResponse, RusotoError<ListExclusionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListExclusions");
         request.add_header("x-amz-target", "InspectorService.ListExclusions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4331,6 +5060,7 @@ impl Inspector for InspectorClient {
         input: ListFindingsRequest,
     ) -> Result<ListFindingsResponse, RusotoError<ListFindingsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListFindings");
         request.add_header("x-amz-target", "InspectorService.ListFindings");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4349,6 +5079,7 @@ impl Inspector for InspectorClient {
         input: ListRulesPackagesRequest,
     ) -> Result<ListRulesPackagesResponse, RusotoError<ListRulesPackagesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListRulesPackages");
         request.add_header("x-amz-target", "InspectorService.ListRulesPackages");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4367,6 +5098,7 @@ impl Inspector for InspectorClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header("x-amz-target", "InspectorService.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4385,6 +5117,7 @@ impl Inspector for InspectorClient {
         input: PreviewAgentsRequest,
     ) -> Result<PreviewAgentsResponse, RusotoError<PreviewAgentsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PreviewAgents");
         request.add_header("x-amz-target", "InspectorService.PreviewAgents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4403,6 +5136,7 @@ impl Inspector for InspectorClient {
         input: RegisterCrossAccountAccessRoleRequest,
     ) -> Result<(), RusotoError<RegisterCrossAccountAccessRoleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RegisterCrossAccountAccessRole");
         request.add_header(
             "x-amz-target",
             "InspectorService.RegisterCrossAccountAccessRole",
@@ -4424,6 +5158,7 @@ impl Inspector for InspectorClient {
     ) -> Result<RemoveAttributesFromFindingsResponse, RusotoError<RemoveAttributesFromFindingsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RemoveAttributesFromFindings");
         request.add_header(
             "x-amz-target",
             "InspectorService.RemoveAttributesFromFindings",
@@ -4446,6 +5181,7 @@ impl Inspector for InspectorClient {
         input: SetTagsForResourceRequest,
     ) -> Result<(), RusotoError<SetTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SetTagsForResource");
         request.add_header("x-amz-target", "InspectorService.SetTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4463,6 +5199,7 @@ impl Inspector for InspectorClient {
         input: StartAssessmentRunRequest,
     ) -> Result<StartAssessmentRunResponse, RusotoError<StartAssessmentRunError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartAssessmentRun");
         request.add_header("x-amz-target", "InspectorService.StartAssessmentRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4481,6 +5218,7 @@ impl Inspector for InspectorClient {
         input: StopAssessmentRunRequest,
     ) -> Result<(), RusotoError<StopAssessmentRunError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopAssessmentRun");
         request.add_header("x-amz-target", "InspectorService.StopAssessmentRun");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4498,6 +5236,7 @@ impl Inspector for InspectorClient {
         input: SubscribeToEventRequest,
     ) -> Result<(), RusotoError<SubscribeToEventError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SubscribeToEvent");
         request.add_header("x-amz-target", "InspectorService.SubscribeToEvent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4515,6 +5254,7 @@ impl Inspector for InspectorClient {
         input: UnsubscribeFromEventRequest,
     ) -> Result<(), RusotoError<UnsubscribeFromEventError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UnsubscribeFromEvent");
         request.add_header("x-amz-target", "InspectorService.UnsubscribeFromEvent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4532,6 +5272,7 @@ impl Inspector for InspectorClient {
         input: UpdateAssessmentTargetRequest,
     ) -> Result<(), RusotoError<UpdateAssessmentTargetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateAssessmentTarget");
         request.add_header("x-amz-target", "InspectorService.UpdateAssessmentTarget");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4543,3 +5284,411 @@ impl Inspector for InspectorClient {
         Ok(())
     }
 }
+
+/// A blocking client for the Amazon Inspector API, running the calls of a `InspectorClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `InspectorClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct InspectorBlockingClient {
+    client: InspectorClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl InspectorBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> InspectorBlockingClient {
+        InspectorBlockingClient::from_client(InspectorClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> InspectorBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        InspectorBlockingClient::from_client(InspectorClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> InspectorBlockingClient {
+        InspectorBlockingClient::from_client(InspectorClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> InspectorBlockingClient {
+        InspectorBlockingClient::from_client(InspectorClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: InspectorClient) -> InspectorBlockingClient {
+        InspectorBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> InspectorBlockingClient {
+        InspectorBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> InspectorBlockingClient {
+        InspectorBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Inspector::add_attributes_to_findings`].
+    pub fn add_attributes_to_findings(
+        &self,
+        input: AddAttributesToFindingsRequest,
+    ) -> Result<AddAttributesToFindingsResponse, RusotoError<AddAttributesToFindingsError>> {
+        self.runtime
+            .block_on(self.client.add_attributes_to_findings(input))
+    }
+
+    /// Blocking version of [`Inspector::create_assessment_target`].
+    pub fn create_assessment_target(
+        &self,
+        input: CreateAssessmentTargetRequest,
+    ) -> Result<CreateAssessmentTargetResponse, RusotoError<CreateAssessmentTargetError>> {
+        self.runtime
+            .block_on(self.client.create_assessment_target(input))
+    }
+
+    /// Blocking version of [`Inspector::create_assessment_template`].
+    pub fn create_assessment_template(
+        &self,
+        input: CreateAssessmentTemplateRequest,
+    ) -> Result<CreateAssessmentTemplateResponse, RusotoError<CreateAssessmentTemplateError>> {
+        self.runtime
+            .block_on(self.client.create_assessment_template(input))
+    }
+
+    /// Blocking version of [`Inspector::create_exclusions_preview`].
+    pub fn create_exclusions_preview(
+        &self,
+        input: CreateExclusionsPreviewRequest,
+    ) -> Result<CreateExclusionsPreviewResponse, RusotoError<CreateExclusionsPreviewError>> {
+        self.runtime
+            .block_on(self.client.create_exclusions_preview(input))
+    }
+
+    /// Blocking version of [`Inspector::create_resource_group`].
+    pub fn create_resource_group(
+        &self,
+        input: CreateResourceGroupRequest,
+    ) -> Result<CreateResourceGroupResponse, RusotoError<CreateResourceGroupError>> {
+        self.runtime
+            .block_on(self.client.create_resource_group(input))
+    }
+
+    /// Blocking version of [`Inspector::delete_assessment_run`].
+    pub fn delete_assessment_run(
+        &self,
+        input: DeleteAssessmentRunRequest,
+    ) -> Result<(), RusotoError<DeleteAssessmentRunError>> {
+        self.runtime
+            .block_on(self.client.delete_assessment_run(input))
+    }
+
+    /// Blocking version of [`Inspector::delete_assessment_target`].
+    pub fn delete_assessment_target(
+        &self,
+        input: DeleteAssessmentTargetRequest,
+    ) -> Result<(), RusotoError<DeleteAssessmentTargetError>> {
+        self.runtime
+            .block_on(self.client.delete_assessment_target(input))
+    }
+
+    /// Blocking version of [`Inspector::delete_assessment_template`].
+    pub fn delete_assessment_template(
+        &self,
+        input: DeleteAssessmentTemplateRequest,
+    ) -> Result<(), RusotoError<DeleteAssessmentTemplateError>> {
+        self.runtime
+            .block_on(self.client.delete_assessment_template(input))
+    }
+
+    /// Blocking version of [`Inspector::describe_assessment_runs`].
+    pub fn describe_assessment_runs(
+        &self,
+        input: DescribeAssessmentRunsRequest,
+    ) -> Result<DescribeAssessmentRunsResponse, RusotoError<DescribeAssessmentRunsError>> {
+        self.runtime
+            .block_on(self.client.describe_assessment_runs(input))
+    }
+
+    /// Blocking version of [`Inspector::describe_assessment_targets`].
+    pub fn describe_assessment_targets(
+        &self,
+        input: DescribeAssessmentTargetsRequest,
+    ) -> Result<DescribeAssessmentTargetsResponse, RusotoError<DescribeAssessmentTargetsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_assessment_targets(input))
+    }
+
+    /// Blocking version of [`Inspector::describe_assessment_templates`].
+    pub fn describe_assessment_templates(
+        &self,
+        input: DescribeAssessmentTemplatesRequest,
+    ) -> Result<DescribeAssessmentTemplatesResponse, RusotoError<DescribeAssessmentTemplatesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_assessment_templates(input))
+    }
+
+    /// Blocking version of [`Inspector::describe_cross_account_access_role`].
+    pub fn describe_cross_account_access_role(
+        &self,
+    ) -> Result<
+        DescribeCrossAccountAccessRoleResponse,
+        RusotoError<DescribeCrossAccountAccessRoleError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_cross_account_access_role())
+    }
+
+    /// Blocking version of [`Inspector::describe_exclusions`].
+    pub fn describe_exclusions(
+        &self,
+        input: DescribeExclusionsRequest,
+    ) -> Result<DescribeExclusionsResponse, RusotoError<DescribeExclusionsError>> {
+        self.runtime
+            .block_on(self.client.describe_exclusions(input))
+    }
+
+    /// Blocking version of [`Inspector::describe_findings`].
+    pub fn describe_findings(
+        &self,
+        input: DescribeFindingsRequest,
+    ) -> Result<DescribeFindingsResponse, RusotoError<DescribeFindingsError>> {
+        self.runtime.block_on(self.client.describe_findings(input))
+    }
+
+    /// Blocking version of [`Inspector::describe_resource_groups`].
+    pub fn describe_resource_groups(
+        &self,
+        input: DescribeResourceGroupsRequest,
+    ) -> Result<DescribeResourceGroupsResponse, RusotoError<DescribeResourceGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_resource_groups(input))
+    }
+
+    /// Blocking version of [`Inspector::describe_rules_packages`].
+    pub fn describe_rules_packages(
+        &self,
+        input: DescribeRulesPackagesRequest,
+    ) -> Result<DescribeRulesPackagesResponse, RusotoError<DescribeRulesPackagesError>> {
+        self.runtime
+            .block_on(self.client.describe_rules_packages(input))
+    }
+
+    /// Blocking version of [`Inspector::get_assessment_report`].
+    pub fn get_assessment_report(
+        &self,
+        input: GetAssessmentReportRequest,
+    ) -> Result<GetAssessmentReportResponse, RusotoError<GetAssessmentReportError>> {
+        self.runtime
+            .block_on(self.client.get_assessment_report(input))
+    }
+
+    /// Blocking version of [`Inspector::get_exclusions_preview`].
+    pub fn get_exclusions_preview(
+        &self,
+        input: GetExclusionsPreviewRequest,
+    ) -> Result<GetExclusionsPreviewResponse, RusotoError<GetExclusionsPreviewError>> {
+        self.runtime
+            .block_on(self.client.get_exclusions_preview(input))
+    }
+
+    /// Blocking version of [`Inspector::get_telemetry_metadata`].
+    pub fn get_telemetry_metadata(
+        &self,
+        input: GetTelemetryMetadataRequest,
+    ) -> Result<GetTelemetryMetadataResponse, RusotoError<GetTelemetryMetadataError>> {
+        self.runtime
+            .block_on(self.client.get_telemetry_metadata(input))
+    }
+
+    /// Blocking version of [`Inspector::list_assessment_run_agents`].
+    pub fn list_assessment_run_agents(
+        &self,
+        input: ListAssessmentRunAgentsRequest,
+    ) -> Result<ListAssessmentRunAgentsResponse, RusotoError<ListAssessmentRunAgentsError>> {
+        self.runtime
+            .block_on(self.client.list_assessment_run_agents(input))
+    }
+
+    /// Blocking version of [`Inspector::list_assessment_runs`].
+    pub fn list_assessment_runs(
+        &self,
+        input: ListAssessmentRunsRequest,
+    ) -> Result<ListAssessmentRunsResponse, RusotoError<ListAssessmentRunsError>> {
+        self.runtime
+            .block_on(self.client.list_assessment_runs(input))
+    }
+
+    /// Blocking version of [`Inspector::list_assessment_targets`].
+    pub fn list_assessment_targets(
+        &self,
+        input: ListAssessmentTargetsRequest,
+    ) -> Result<ListAssessmentTargetsResponse, RusotoError<ListAssessmentTargetsError>> {
+        self.runtime
+            .block_on(self.client.list_assessment_targets(input))
+    }
+
+    /// Blocking version of [`Inspector::list_assessment_templates`].
+    pub fn list_assessment_templates(
+        &self,
+        input: ListAssessmentTemplatesRequest,
+    ) -> Result<ListAssessmentTemplatesResponse, RusotoError<ListAssessmentTemplatesError>> {
+        self.runtime
+            .block_on(self.client.list_assessment_templates(input))
+    }
+
+    /// Blocking version of [`Inspector::list_event_subscriptions`].
+    pub fn list_event_subscriptions(
+        &self,
+        input: ListEventSubscriptionsRequest,
+    ) -> Result<ListEventSubscriptionsResponse, RusotoError<ListEventSubscriptionsError>> {
+        self.runtime
+            .block_on(self.client.list_event_subscriptions(input))
+    }
+
+    /// Blocking version of [`Inspector::list_exclusions`].
+    pub fn list_exclusions(
+        &self,
+        input: ListExclusionsRequest,
+    ) -> Result<ListExclusionsResponse, RusotoError<ListExclusionsError>> {
+        self.runtime.block_on(self.client.list_exclusions(input))
+    }
+
+    /// Blocking version of [`Inspector::list_findings`].
+    pub fn list_findings(
+        &self,
+        input: ListFindingsRequest,
+    ) -> Result<ListFindingsResponse, RusotoError<ListFindingsError>> {
+        self.runtime.block_on(self.client.list_findings(input))
+    }
+
+    /// Blocking version of [`Inspector::list_rules_packages`].
+    pub fn list_rules_packages(
+        &self,
+        input: ListRulesPackagesRequest,
+    ) -> Result<ListRulesPackagesResponse, RusotoError<ListRulesPackagesError>> {
+        self.runtime
+            .block_on(self.client.list_rules_packages(input))
+    }
+
+    /// Blocking version of [`Inspector::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`Inspector::preview_agents`].
+    pub fn preview_agents(
+        &self,
+        input: PreviewAgentsRequest,
+    ) -> Result<PreviewAgentsResponse, RusotoError<PreviewAgentsError>> {
+        self.runtime.block_on(self.client.preview_agents(input))
+    }
+
+    /// Blocking version of [`Inspector::register_cross_account_access_role`].
+    pub fn register_cross_account_access_role(
+        &self,
+        input: RegisterCrossAccountAccessRoleRequest,
+    ) -> Result<(), RusotoError<RegisterCrossAccountAccessRoleError>> {
+        self.runtime
+            .block_on(self.client.register_cross_account_access_role(input))
+    }
+
+    /// Blocking version of [`Inspector::remove_attributes_from_findings`].
+    pub fn remove_attributes_from_findings(
+        &self,
+        input: RemoveAttributesFromFindingsRequest,
+    ) -> Result<RemoveAttributesFromFindingsResponse, RusotoError<RemoveAttributesFromFindingsError>>
+    {
+        self.runtime
+            .block_on(self.client.remove_attributes_from_findings(input))
+    }
+
+    /// Blocking version of [`Inspector::set_tags_for_resource`].
+    pub fn set_tags_for_resource(
+        &self,
+        input: SetTagsForResourceRequest,
+    ) -> Result<(), RusotoError<SetTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.set_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`Inspector::start_assessment_run`].
+    pub fn start_assessment_run(
+        &self,
+        input: StartAssessmentRunRequest,
+    ) -> Result<StartAssessmentRunResponse, RusotoError<StartAssessmentRunError>> {
+        self.runtime
+            .block_on(self.client.start_assessment_run(input))
+    }
+
+    /// Blocking version of [`Inspector::stop_assessment_run`].
+    pub fn stop_assessment_run(
+        &self,
+        input: StopAssessmentRunRequest,
+    ) -> Result<(), RusotoError<StopAssessmentRunError>> {
+        self.runtime
+            .block_on(self.client.stop_assessment_run(input))
+    }
+
+    /// Blocking version of [`Inspector::subscribe_to_event`].
+    pub fn subscribe_to_event(
+        &self,
+        input: SubscribeToEventRequest,
+    ) -> Result<(), RusotoError<SubscribeToEventError>> {
+        self.runtime.block_on(self.client.subscribe_to_event(input))
+    }
+
+    /// Blocking version of [`Inspector::unsubscribe_from_event`].
+    pub fn unsubscribe_from_event(
+        &self,
+        input: UnsubscribeFromEventRequest,
+    ) -> Result<(), RusotoError<UnsubscribeFromEventError>> {
+        self.runtime
+            .block_on(self.client.unsubscribe_from_event(input))
+    }
+
+    /// Blocking version of [`Inspector::update_assessment_target`].
+    pub fn update_assessment_target(
+        &self,
+        input: UpdateAssessmentTargetRequest,
+    ) -> Result<(), RusotoError<UpdateAssessmentTargetError>> {
+        self.runtime
+            .block_on(self.client.update_assessment_target(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -173,34 +174,70 @@ impl DeleteThingShadowError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteThingShadowError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThingShadowError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteThingShadowError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThingShadowError::InvalidRequest,
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(DeleteThingShadowError::MethodNotAllowed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThingShadowError::MethodNotAllowed,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteThingShadowError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThingShadowError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteThingShadowError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteThingShadowError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteThingShadowError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThingShadowError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteThingShadowError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThingShadowError::Unauthorized,
+                    )
                 }
                 "UnsupportedDocumentEncodingException" => {
-                    return RusotoError::Service(
-                        DeleteThingShadowError::UnsupportedDocumentEncoding(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThingShadowError::UnsupportedDocumentEncoding,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -251,32 +288,70 @@ impl GetThingShadowError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetThingShadowError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetThingShadowError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetThingShadowError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetThingShadowError::InvalidRequest,
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(GetThingShadowError::MethodNotAllowed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetThingShadowError::MethodNotAllowed,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetThingShadowError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetThingShadowError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetThingShadowError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetThingShadowError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetThingShadowError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetThingShadowError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetThingShadowError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetThingShadowError::Unauthorized,
+                    )
                 }
                 "UnsupportedDocumentEncodingException" => {
-                    return RusotoError::Service(GetThingShadowError::UnsupportedDocumentEncoding(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetThingShadowError::UnsupportedDocumentEncoding,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -323,39 +398,62 @@ impl ListNamedShadowsForThingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListNamedShadowsForThingError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListNamedShadowsForThingError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListNamedShadowsForThingError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListNamedShadowsForThingError::InvalidRequest,
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(ListNamedShadowsForThingError::MethodNotAllowed(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListNamedShadowsForThingError::MethodNotAllowed,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListNamedShadowsForThingError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListNamedShadowsForThingError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListNamedShadowsForThingError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListNamedShadowsForThingError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListNamedShadowsForThingError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListNamedShadowsForThingError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListNamedShadowsForThingError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListNamedShadowsForThingError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -395,18 +493,38 @@ impl PublishError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(PublishError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PublishError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(PublishError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PublishError::InvalidRequest,
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(PublishError::MethodNotAllowed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PublishError::MethodNotAllowed,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(PublishError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PublishError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -453,39 +571,78 @@ impl UpdateThingShadowError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateThingShadowError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThingShadowError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateThingShadowError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThingShadowError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateThingShadowError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThingShadowError::InvalidRequest,
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(UpdateThingShadowError::MethodNotAllowed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThingShadowError::MethodNotAllowed,
+                    )
                 }
                 "RequestEntityTooLargeException" => {
-                    return RusotoError::Service(UpdateThingShadowError::RequestEntityTooLarge(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateThingShadowError::RequestEntityTooLarge,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateThingShadowError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateThingShadowError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateThingShadowError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThingShadowError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateThingShadowError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThingShadowError::Unauthorized,
+                    )
                 }
                 "UnsupportedDocumentEncodingException" => {
-                    return RusotoError::Service(
-                        UpdateThingShadowError::UnsupportedDocumentEncoding(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThingShadowError::UnsupportedDocumentEncoding,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -551,11 +708,12 @@ pub struct IotDataClient {
 impl IotDataClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> IotDataClient {
         IotDataClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "IoT Data Plane"),
         }
     }
 
@@ -570,12 +728,32 @@ impl IotDataClient {
     {
         IotDataClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "IoT Data Plane"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> IotDataClient {
-        IotDataClient { client, region }
+        IotDataClient {
+            client,
+            region: region_for_service(region, "IoT Data Plane"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> IotDataClient {
+        IotDataClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("IoT Data Plane"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> IotDataClient {
+        IotDataClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -590,6 +768,8 @@ impl IotData for IotDataClient {
         let request_uri = format!("/things/{thing_name}/shadow", thing_name = input.thing_name);
 
         let mut request = SignedRequest::new("DELETE", "iotdata", &self.region, &request_uri);
+        request.set_operation("DeleteThingShadow");
+        request.set_raw_response(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.iot".to_string());
@@ -627,6 +807,8 @@ impl IotData for IotDataClient {
         let request_uri = format!("/things/{thing_name}/shadow", thing_name = input.thing_name);
 
         let mut request = SignedRequest::new("GET", "iotdata", &self.region, &request_uri);
+        request.set_operation("GetThingShadow");
+        request.set_raw_response(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.iot".to_string());
@@ -667,6 +849,7 @@ impl IotData for IotDataClient {
         );
 
         let mut request = SignedRequest::new("GET", "iotdata", &self.region, &request_uri);
+        request.set_operation("ListNamedShadowsForThing");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.iot".to_string());
@@ -703,6 +886,7 @@ impl IotData for IotDataClient {
         let request_uri = format!("/topics/{topic}", topic = input.topic);
 
         let mut request = SignedRequest::new("POST", "iotdata", &self.region, &request_uri);
+        request.set_operation("Publish");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.iot".to_string());
@@ -744,6 +928,8 @@ impl IotData for IotDataClient {
         let request_uri = format!("/things/{thing_name}/shadow", thing_name = input.thing_name);
 
         let mut request = SignedRequest::new("POST", "iotdata", &self.region, &request_uri);
+        request.set_operation("UpdateThingShadow");
+        request.set_raw_response(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.iot".to_string());
@@ -774,3 +960,118 @@ impl IotData for IotDataClient {
         }
     }
 }
+
+/// A blocking client for the AWS IoT Data Plane API, running the calls of a `IotDataClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `IotDataClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct IotDataBlockingClient {
+    client: IotDataClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl IotDataBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> IotDataBlockingClient {
+        IotDataBlockingClient::from_client(IotDataClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> IotDataBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        IotDataBlockingClient::from_client(IotDataClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> IotDataBlockingClient {
+        IotDataBlockingClient::from_client(IotDataClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> IotDataBlockingClient {
+        IotDataBlockingClient::from_client(IotDataClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: IotDataClient) -> IotDataBlockingClient {
+        IotDataBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> IotDataBlockingClient {
+        IotDataBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> IotDataBlockingClient {
+        IotDataBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`IotData::delete_thing_shadow`].
+    pub fn delete_thing_shadow(
+        &self,
+        input: DeleteThingShadowRequest,
+    ) -> Result<DeleteThingShadowResponse, RusotoError<DeleteThingShadowError>> {
+        self.runtime
+            .block_on(self.client.delete_thing_shadow(input))
+    }
+
+    /// Blocking version of [`IotData::get_thing_shadow`].
+    pub fn get_thing_shadow(
+        &self,
+        input: GetThingShadowRequest,
+    ) -> Result<GetThingShadowResponse, RusotoError<GetThingShadowError>> {
+        self.runtime.block_on(self.client.get_thing_shadow(input))
+    }
+
+    /// Blocking version of [`IotData::list_named_shadows_for_thing`].
+    pub fn list_named_shadows_for_thing(
+        &self,
+        input: ListNamedShadowsForThingRequest,
+    ) -> Result<ListNamedShadowsForThingResponse, RusotoError<ListNamedShadowsForThingError>> {
+        self.runtime
+            .block_on(self.client.list_named_shadows_for_thing(input))
+    }
+
+    /// Blocking version of [`IotData::publish`].
+    pub fn publish(&self, input: PublishRequest) -> Result<(), RusotoError<PublishError>> {
+        self.runtime.block_on(self.client.publish(input))
+    }
+
+    /// Blocking version of [`IotData::update_thing_shadow`].
+    pub fn update_thing_shadow(
+        &self,
+        input: UpdateThingShadowRequest,
+    ) -> Result<UpdateThingShadowResponse, RusotoError<UpdateThingShadowError>> {
+        self.runtime
+            .block_on(self.client.update_thing_shadow(input))
+    }
+}
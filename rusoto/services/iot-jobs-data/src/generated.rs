@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -270,30 +271,54 @@ impl DescribeJobExecutionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CertificateValidationException" => {
-                    return RusotoError::Service(DescribeJobExecutionError::CertificateValidation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeJobExecutionError::CertificateValidation,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeJobExecutionError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeJobExecutionError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeJobExecutionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeJobExecutionError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeJobExecutionError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeJobExecutionError::ServiceUnavailable,
+                    )
                 }
                 "TerminalStateException" => {
-                    return RusotoError::Service(DescribeJobExecutionError::TerminalState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeJobExecutionError::TerminalState,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeJobExecutionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeJobExecutionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -334,29 +359,46 @@ impl GetPendingJobExecutionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CertificateValidationException" => {
-                    return RusotoError::Service(
-                        GetPendingJobExecutionsError::CertificateValidation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPendingJobExecutionsError::CertificateValidation,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetPendingJobExecutionsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetPendingJobExecutionsError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetPendingJobExecutionsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetPendingJobExecutionsError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetPendingJobExecutionsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetPendingJobExecutionsError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetPendingJobExecutionsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPendingJobExecutionsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -400,31 +442,46 @@ impl StartNextPendingJobExecutionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CertificateValidationException" => {
-                    return RusotoError::Service(
-                        StartNextPendingJobExecutionError::CertificateValidation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartNextPendingJobExecutionError::CertificateValidation,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartNextPendingJobExecutionError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartNextPendingJobExecutionError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        StartNextPendingJobExecutionError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartNextPendingJobExecutionError::ResourceNotFound,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        StartNextPendingJobExecutionError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartNextPendingJobExecutionError::ServiceUnavailable,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartNextPendingJobExecutionError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartNextPendingJobExecutionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -472,30 +529,54 @@ impl UpdateJobExecutionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CertificateValidationException" => {
-                    return RusotoError::Service(UpdateJobExecutionError::CertificateValidation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateJobExecutionError::CertificateValidation,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateJobExecutionError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateJobExecutionError::InvalidRequest,
+                    )
                 }
                 "InvalidStateTransitionException" => {
-                    return RusotoError::Service(UpdateJobExecutionError::InvalidStateTransition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateJobExecutionError::InvalidStateTransition,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateJobExecutionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateJobExecutionError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateJobExecutionError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateJobExecutionError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateJobExecutionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateJobExecutionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -553,11 +634,12 @@ pub struct IotJobsDataClient {
 impl IotJobsDataClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> IotJobsDataClient {
         IotJobsDataClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "IoT Jobs Data Plane"),
         }
     }
 
@@ -572,12 +654,32 @@ impl IotJobsDataClient {
     {
         IotJobsDataClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "IoT Jobs Data Plane"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> IotJobsDataClient {
-        IotJobsDataClient { client, region }
+        IotJobsDataClient {
+            client,
+            region: region_for_service(region, "IoT Jobs Data Plane"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> IotJobsDataClient {
+        IotJobsDataClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("IoT Jobs Data Plane"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> IotJobsDataClient {
+        IotJobsDataClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -596,6 +698,7 @@ impl IotJobsData for IotJobsDataClient {
         );
 
         let mut request = SignedRequest::new("GET", "iot-jobs-data", &self.region, &request_uri);
+        request.set_operation("DescribeJobExecution");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.jobs.iot".to_string());
@@ -635,6 +738,7 @@ impl IotJobsData for IotJobsDataClient {
         let request_uri = format!("/things/{thing_name}/jobs", thing_name = input.thing_name);
 
         let mut request = SignedRequest::new("GET", "iot-jobs-data", &self.region, &request_uri);
+        request.set_operation("GetPendingJobExecutions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.jobs.iot".to_string());
@@ -669,6 +773,7 @@ impl IotJobsData for IotJobsDataClient {
         );
 
         let mut request = SignedRequest::new("PUT", "iot-jobs-data", &self.region, &request_uri);
+        request.set_operation("StartNextPendingJobExecution");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.jobs.iot".to_string());
@@ -705,6 +810,7 @@ impl IotJobsData for IotJobsDataClient {
         );
 
         let mut request = SignedRequest::new("POST", "iot-jobs-data", &self.region, &request_uri);
+        request.set_operation("UpdateJobExecution");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("data.jobs.iot".to_string());
@@ -728,3 +834,115 @@ impl IotJobsData for IotJobsDataClient {
         }
     }
 }
+
+/// A blocking client for the AWS IoT Jobs Data Plane API, running the calls of a `IotJobsDataClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `IotJobsDataClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct IotJobsDataBlockingClient {
+    client: IotJobsDataClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl IotJobsDataBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> IotJobsDataBlockingClient {
+        IotJobsDataBlockingClient::from_client(IotJobsDataClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> IotJobsDataBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        IotJobsDataBlockingClient::from_client(IotJobsDataClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> IotJobsDataBlockingClient {
+        IotJobsDataBlockingClient::from_client(IotJobsDataClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> IotJobsDataBlockingClient {
+        IotJobsDataBlockingClient::from_client(IotJobsDataClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: IotJobsDataClient) -> IotJobsDataBlockingClient {
+        IotJobsDataBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> IotJobsDataBlockingClient {
+        IotJobsDataBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> IotJobsDataBlockingClient {
+        IotJobsDataBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`IotJobsData::describe_job_execution`].
+    pub fn describe_job_execution(
+        &self,
+        input: DescribeJobExecutionRequest,
+    ) -> Result<DescribeJobExecutionResponse, RusotoError<DescribeJobExecutionError>> {
+        self.runtime
+            .block_on(self.client.describe_job_execution(input))
+    }
+
+    /// Blocking version of [`IotJobsData::get_pending_job_executions`].
+    pub fn get_pending_job_executions(
+        &self,
+        input: GetPendingJobExecutionsRequest,
+    ) -> Result<GetPendingJobExecutionsResponse, RusotoError<GetPendingJobExecutionsError>> {
+        self.runtime
+            .block_on(self.client.get_pending_job_executions(input))
+    }
+
+    /// Blocking version of [`IotJobsData::start_next_pending_job_execution`].
+    pub fn start_next_pending_job_execution(
+        &self,
+        input: StartNextPendingJobExecutionRequest,
+    ) -> Result<StartNextPendingJobExecutionResponse, RusotoError<StartNextPendingJobExecutionError>>
+    {
+        self.runtime
+            .block_on(self.client.start_next_pending_job_execution(input))
+    }
+
+    /// Blocking version of [`IotJobsData::update_job_execution`].
+    pub fn update_job_execution(
+        &self,
+        input: UpdateJobExecutionRequest,
+    ) -> Result<UpdateJobExecutionResponse, RusotoError<UpdateJobExecutionError>> {
+        self.runtime
+            .block_on(self.client.update_job_execution(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -9824,41 +9825,62 @@ impl AcceptCertificateTransferError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(AcceptCertificateTransferError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AcceptCertificateTransferError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AcceptCertificateTransferError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AcceptCertificateTransferError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AcceptCertificateTransferError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AcceptCertificateTransferError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        AcceptCertificateTransferError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptCertificateTransferError::ServiceUnavailable,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AcceptCertificateTransferError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AcceptCertificateTransferError::Throttling,
+                    )
                 }
                 "TransferAlreadyCompletedException" => {
-                    return RusotoError::Service(
-                        AcceptCertificateTransferError::TransferAlreadyCompleted(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptCertificateTransferError::TransferAlreadyCompleted,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(AcceptCertificateTransferError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AcceptCertificateTransferError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9900,24 +9922,38 @@ impl AddThingToBillingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(AddThingToBillingGroupError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddThingToBillingGroupError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AddThingToBillingGroupError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddThingToBillingGroupError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddThingToBillingGroupError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddThingToBillingGroupError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AddThingToBillingGroupError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddThingToBillingGroupError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9954,22 +9990,38 @@ impl AddThingToThingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(AddThingToThingGroupError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddThingToThingGroupError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AddThingToThingGroupError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddThingToThingGroupError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddThingToThingGroupError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddThingToThingGroupError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AddThingToThingGroupError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddThingToThingGroupError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10008,29 +10060,46 @@ impl AssociateTargetsWithJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AssociateTargetsWithJobError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateTargetsWithJobError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AssociateTargetsWithJobError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateTargetsWithJobError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateTargetsWithJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateTargetsWithJobError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(AssociateTargetsWithJobError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateTargetsWithJobError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AssociateTargetsWithJobError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateTargetsWithJobError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10074,27 +10143,62 @@ impl AttachPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(AttachPolicyError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachPolicyError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AttachPolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachPolicyError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AttachPolicyError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachPolicyError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AttachPolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachPolicyError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(AttachPolicyError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachPolicyError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AttachPolicyError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachPolicyError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(AttachPolicyError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachPolicyError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10140,35 +10244,62 @@ impl AttachPrincipalPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(AttachPrincipalPolicyError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AttachPrincipalPolicyError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AttachPrincipalPolicyError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AttachPrincipalPolicyError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AttachPrincipalPolicyError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachPrincipalPolicyError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AttachPrincipalPolicyError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AttachPrincipalPolicyError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(AttachPrincipalPolicyError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AttachPrincipalPolicyError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AttachPrincipalPolicyError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachPrincipalPolicyError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(AttachPrincipalPolicyError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachPrincipalPolicyError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10212,32 +10343,54 @@ impl AttachSecurityProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(AttachSecurityProfileError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AttachSecurityProfileError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AttachSecurityProfileError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AttachSecurityProfileError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AttachSecurityProfileError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachSecurityProfileError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AttachSecurityProfileError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AttachSecurityProfileError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AttachSecurityProfileError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachSecurityProfileError::Throttling,
+                    )
                 }
                 "VersionConflictException" => {
-                    return RusotoError::Service(AttachSecurityProfileError::VersionConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AttachSecurityProfileError::VersionConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10280,30 +10433,54 @@ impl AttachThingPrincipalError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(AttachThingPrincipalError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AttachThingPrincipalError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AttachThingPrincipalError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachThingPrincipalError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AttachThingPrincipalError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AttachThingPrincipalError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(AttachThingPrincipalError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AttachThingPrincipalError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AttachThingPrincipalError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachThingPrincipalError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(AttachThingPrincipalError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachThingPrincipalError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10344,26 +10521,38 @@ impl CancelAuditMitigationActionsTaskError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
-                        CancelAuditMitigationActionsTaskError::InternalFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelAuditMitigationActionsTaskError::InternalFailure,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        CancelAuditMitigationActionsTaskError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelAuditMitigationActionsTaskError::InvalidRequest,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        CancelAuditMitigationActionsTaskError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelAuditMitigationActionsTaskError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CancelAuditMitigationActionsTaskError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelAuditMitigationActionsTaskError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10406,18 +10595,38 @@ impl CancelAuditTaskError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CancelAuditTaskError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelAuditTaskError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CancelAuditTaskError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelAuditTaskError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelAuditTaskError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelAuditTaskError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CancelAuditTaskError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelAuditTaskError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10460,41 +10669,62 @@ impl CancelCertificateTransferError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CancelCertificateTransferError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelCertificateTransferError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CancelCertificateTransferError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelCertificateTransferError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelCertificateTransferError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelCertificateTransferError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        CancelCertificateTransferError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelCertificateTransferError::ServiceUnavailable,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CancelCertificateTransferError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelCertificateTransferError::Throttling,
+                    )
                 }
                 "TransferAlreadyCompletedException" => {
-                    return RusotoError::Service(
-                        CancelCertificateTransferError::TransferAlreadyCompleted(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelCertificateTransferError::TransferAlreadyCompleted,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CancelCertificateTransferError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelCertificateTransferError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10538,26 +10768,38 @@ impl CancelDetectMitigationActionsTaskError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
-                        CancelDetectMitigationActionsTaskError::InternalFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelDetectMitigationActionsTaskError::InternalFailure,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        CancelDetectMitigationActionsTaskError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelDetectMitigationActionsTaskError::InvalidRequest,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        CancelDetectMitigationActionsTaskError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelDetectMitigationActionsTaskError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
-                        CancelDetectMitigationActionsTaskError::Throttling(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelDetectMitigationActionsTaskError::Throttling,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10600,18 +10842,38 @@ impl CancelJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CancelJobError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelJobError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelJobError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CancelJobError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelJobError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CancelJobError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelJobError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10652,28 +10914,54 @@ impl CancelJobExecutionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CancelJobExecutionError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelJobExecutionError::InvalidRequest,
+                    )
                 }
                 "InvalidStateTransitionException" => {
-                    return RusotoError::Service(CancelJobExecutionError::InvalidStateTransition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelJobExecutionError::InvalidStateTransition,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelJobExecutionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelJobExecutionError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CancelJobExecutionError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelJobExecutionError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CancelJobExecutionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelJobExecutionError::Throttling,
+                    )
                 }
                 "VersionConflictException" => {
-                    return RusotoError::Service(CancelJobExecutionError::VersionConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelJobExecutionError::VersionConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10716,32 +11004,54 @@ impl ClearDefaultAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ClearDefaultAuthorizerError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ClearDefaultAuthorizerError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ClearDefaultAuthorizerError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ClearDefaultAuthorizerError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ClearDefaultAuthorizerError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ClearDefaultAuthorizerError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ClearDefaultAuthorizerError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ClearDefaultAuthorizerError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ClearDefaultAuthorizerError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ClearDefaultAuthorizerError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ClearDefaultAuthorizerError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ClearDefaultAuthorizerError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10784,31 +11094,46 @@ impl ConfirmTopicRuleDestinationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictingResourceUpdateException" => {
-                    return RusotoError::Service(
-                        ConfirmTopicRuleDestinationError::ConflictingResourceUpdate(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfirmTopicRuleDestinationError::ConflictingResourceUpdate,
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ConfirmTopicRuleDestinationError::Internal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ConfirmTopicRuleDestinationError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ConfirmTopicRuleDestinationError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ConfirmTopicRuleDestinationError::InvalidRequest,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        ConfirmTopicRuleDestinationError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConfirmTopicRuleDestinationError::ServiceUnavailable,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ConfirmTopicRuleDestinationError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ConfirmTopicRuleDestinationError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10852,29 +11177,46 @@ impl CreateAuditSuppressionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateAuditSuppressionError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAuditSuppressionError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateAuditSuppressionError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAuditSuppressionError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateAuditSuppressionError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAuditSuppressionError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        CreateAuditSuppressionError::ResourceAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAuditSuppressionError::ResourceAlreadyExists,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateAuditSuppressionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAuditSuppressionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10918,29 +11260,62 @@ impl CreateAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateAuthorizerError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAuthorizerError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateAuthorizerError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAuthorizerError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateAuthorizerError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAuthorizerError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateAuthorizerError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAuthorizerError::ResourceAlreadyExists,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateAuthorizerError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAuthorizerError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateAuthorizerError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAuthorizerError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateAuthorizerError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAuthorizerError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10980,20 +11355,38 @@ impl CreateBillingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateBillingGroupError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBillingGroupError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateBillingGroupError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBillingGroupError::InvalidRequest,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateBillingGroupError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateBillingGroupError::ResourceAlreadyExists,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateBillingGroupError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBillingGroupError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11032,29 +11425,46 @@ impl CreateCertificateFromCsrError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateCertificateFromCsrError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCertificateFromCsrError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateCertificateFromCsrError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCertificateFromCsrError::InvalidRequest,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateCertificateFromCsrError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCertificateFromCsrError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateCertificateFromCsrError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCertificateFromCsrError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateCertificateFromCsrError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCertificateFromCsrError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11094,23 +11504,46 @@ impl CreateCustomMetricError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateCustomMetricError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCustomMetricError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateCustomMetricError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCustomMetricError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateCustomMetricError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCustomMetricError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateCustomMetricError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCustomMetricError::ResourceAlreadyExists,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateCustomMetricError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCustomMetricError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11150,23 +11583,46 @@ impl CreateDimensionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateDimensionError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDimensionError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateDimensionError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDimensionError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDimensionError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDimensionError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateDimensionError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDimensionError::ResourceAlreadyExists,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDimensionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDimensionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11212,46 +11668,70 @@ impl CreateDomainConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CertificateValidationException" => {
-                    return RusotoError::Service(
-                        CreateDomainConfigurationError::CertificateValidation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDomainConfigurationError::CertificateValidation,
                     )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateDomainConfigurationError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDomainConfigurationError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateDomainConfigurationError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDomainConfigurationError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDomainConfigurationError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDomainConfigurationError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        CreateDomainConfigurationError::ResourceAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDomainConfigurationError::ResourceAlreadyExists,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        CreateDomainConfigurationError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDomainConfigurationError::ServiceUnavailable,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDomainConfigurationError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDomainConfigurationError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateDomainConfigurationError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDomainConfigurationError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11302,39 +11782,62 @@ impl CreateDynamicThingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateDynamicThingGroupError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDynamicThingGroupError::InternalFailure,
+                    )
                 }
                 "InvalidQueryException" => {
-                    return RusotoError::Service(CreateDynamicThingGroupError::InvalidQuery(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDynamicThingGroupError::InvalidQuery,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateDynamicThingGroupError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDynamicThingGroupError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDynamicThingGroupError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDynamicThingGroupError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        CreateDynamicThingGroupError::ResourceAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDynamicThingGroupError::ResourceAlreadyExists,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDynamicThingGroupError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDynamicThingGroupError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDynamicThingGroupError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDynamicThingGroupError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11380,24 +11883,54 @@ impl CreateJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateJobError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateJobError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateJobError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateJobError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateJobError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateJobError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateJobError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateJobError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateJobError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateJobError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateJobError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11438,29 +11971,46 @@ impl CreateKeysAndCertificateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateKeysAndCertificateError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateKeysAndCertificateError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateKeysAndCertificateError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateKeysAndCertificateError::InvalidRequest,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateKeysAndCertificateError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateKeysAndCertificateError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateKeysAndCertificateError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateKeysAndCertificateError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateKeysAndCertificateError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateKeysAndCertificateError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11500,29 +12050,46 @@ impl CreateMitigationActionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateMitigationActionError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateMitigationActionError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateMitigationActionError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateMitigationActionError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateMitigationActionError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateMitigationActionError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        CreateMitigationActionError::ResourceAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMitigationActionError::ResourceAlreadyExists,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateMitigationActionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMitigationActionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11568,32 +12135,70 @@ impl CreateOTAUpdateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateOTAUpdateError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateOTAUpdateError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateOTAUpdateError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateOTAUpdateError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateOTAUpdateError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateOTAUpdateError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateOTAUpdateError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateOTAUpdateError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateOTAUpdateError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateOTAUpdateError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateOTAUpdateError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateOTAUpdateError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateOTAUpdateError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateOTAUpdateError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateOTAUpdateError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateOTAUpdateError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11640,27 +12245,62 @@ impl CreatePolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreatePolicyError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePolicyError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreatePolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePolicyError::InvalidRequest,
+                    )
                 }
                 "MalformedPolicyException" => {
-                    return RusotoError::Service(CreatePolicyError::MalformedPolicy(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePolicyError::MalformedPolicy,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreatePolicyError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePolicyError::ResourceAlreadyExists,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreatePolicyError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePolicyError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreatePolicyError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePolicyError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreatePolicyError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePolicyError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11708,36 +12348,70 @@ impl CreatePolicyVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreatePolicyVersionError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePolicyVersionError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreatePolicyVersionError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePolicyVersionError::InvalidRequest,
+                    )
                 }
                 "MalformedPolicyException" => {
-                    return RusotoError::Service(CreatePolicyVersionError::MalformedPolicy(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePolicyVersionError::MalformedPolicy,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreatePolicyVersionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePolicyVersionError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreatePolicyVersionError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePolicyVersionError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreatePolicyVersionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePolicyVersionError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreatePolicyVersionError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePolicyVersionError::Unauthorized,
+                    )
                 }
                 "VersionsLimitExceededException" => {
-                    return RusotoError::Service(CreatePolicyVersionError::VersionsLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePolicyVersionError::VersionsLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11782,34 +12456,54 @@ impl CreateProvisioningClaimError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateProvisioningClaimError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateProvisioningClaimError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateProvisioningClaimError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateProvisioningClaimError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateProvisioningClaimError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateProvisioningClaimError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateProvisioningClaimError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateProvisioningClaimError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateProvisioningClaimError::Throttling(err.msg))
-                }
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProvisioningClaimError::Throttling,
+                    )
+                }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateProvisioningClaimError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateProvisioningClaimError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11854,36 +12548,54 @@ impl CreateProvisioningTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateProvisioningTemplateError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateProvisioningTemplateError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateProvisioningTemplateError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateProvisioningTemplateError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateProvisioningTemplateError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateProvisioningTemplateError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        CreateProvisioningTemplateError::ResourceAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProvisioningTemplateError::ResourceAlreadyExists,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateProvisioningTemplateError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateProvisioningTemplateError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateProvisioningTemplateError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateProvisioningTemplateError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11932,41 +12644,62 @@ impl CreateProvisioningTemplateVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictingResourceUpdateException" => {
-                    return RusotoError::Service(
-                        CreateProvisioningTemplateVersionError::ConflictingResourceUpdate(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProvisioningTemplateVersionError::ConflictingResourceUpdate,
                     )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(
-                        CreateProvisioningTemplateVersionError::InternalFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProvisioningTemplateVersionError::InternalFailure,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        CreateProvisioningTemplateVersionError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProvisioningTemplateVersionError::InvalidRequest,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        CreateProvisioningTemplateVersionError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProvisioningTemplateVersionError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
-                        CreateProvisioningTemplateVersionError::Throttling(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProvisioningTemplateVersionError::Throttling,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
-                        CreateProvisioningTemplateVersionError::Unauthorized(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProvisioningTemplateVersionError::Unauthorized,
                     )
                 }
                 "VersionsLimitExceededException" => {
-                    return RusotoError::Service(
-                        CreateProvisioningTemplateVersionError::VersionsLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProvisioningTemplateVersionError::VersionsLimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12024,29 +12757,62 @@ impl CreateRoleAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateRoleAliasError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRoleAliasError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateRoleAliasError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRoleAliasError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateRoleAliasError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRoleAliasError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateRoleAliasError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRoleAliasError::ResourceAlreadyExists,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateRoleAliasError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRoleAliasError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateRoleAliasError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRoleAliasError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateRoleAliasError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRoleAliasError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12088,25 +12854,46 @@ impl CreateScheduledAuditError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateScheduledAuditError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateScheduledAuditError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateScheduledAuditError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateScheduledAuditError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateScheduledAuditError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateScheduledAuditError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateScheduledAuditError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateScheduledAuditError::ResourceAlreadyExists,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateScheduledAuditError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateScheduledAuditError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12144,24 +12931,38 @@ impl CreateSecurityProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateSecurityProfileError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSecurityProfileError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateSecurityProfileError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSecurityProfileError::InvalidRequest,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateSecurityProfileError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSecurityProfileError::ResourceAlreadyExists,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateSecurityProfileError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSecurityProfileError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12206,30 +13007,70 @@ impl CreateStreamError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateStreamError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStreamError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateStreamError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStreamError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateStreamError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStreamError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateStreamError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStreamError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStreamError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateStreamError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStreamError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateStreamError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStreamError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateStreamError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStreamError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12276,27 +13117,62 @@ impl CreateThingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateThingError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateThingError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateThingError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateThingError::InvalidRequest,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateThingError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateThingError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateThingError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateThingError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateThingError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateThingError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateThingError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateThingError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateThingError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateThingError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12336,20 +13212,38 @@ impl CreateThingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateThingGroupError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateThingGroupError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateThingGroupError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateThingGroupError::InvalidRequest,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateThingGroupError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateThingGroupError::ResourceAlreadyExists,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateThingGroupError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateThingGroupError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12390,26 +13284,54 @@ impl CreateThingTypeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateThingTypeError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateThingTypeError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateThingTypeError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateThingTypeError::InvalidRequest,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateThingTypeError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateThingTypeError::ResourceAlreadyExists,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateThingTypeError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateThingTypeError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateThingTypeError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateThingTypeError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateThingTypeError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateThingTypeError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12452,28 +13374,54 @@ impl CreateTopicRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictingResourceUpdateException" => {
-                    return RusotoError::Service(CreateTopicRuleError::ConflictingResourceUpdate(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateTopicRuleError::ConflictingResourceUpdate,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(CreateTopicRuleError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTopicRuleError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateTopicRuleError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTopicRuleError::InvalidRequest,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateTopicRuleError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateTopicRuleError::ResourceAlreadyExists,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateTopicRuleError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTopicRuleError::ServiceUnavailable,
+                    )
                 }
                 "SqlParseException" => {
-                    return RusotoError::Service(CreateTopicRuleError::SqlParse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTopicRuleError::SqlParse,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12516,29 +13464,46 @@ impl CreateTopicRuleDestinationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictingResourceUpdateException" => {
-                    return RusotoError::Service(
-                        CreateTopicRuleDestinationError::ConflictingResourceUpdate(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTopicRuleDestinationError::ConflictingResourceUpdate,
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(CreateTopicRuleDestinationError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTopicRuleDestinationError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateTopicRuleDestinationError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateTopicRuleDestinationError::InvalidRequest,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        CreateTopicRuleDestinationError::ResourceAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTopicRuleDestinationError::ResourceAlreadyExists,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        CreateTopicRuleDestinationError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTopicRuleDestinationError::ServiceUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12584,26 +13549,38 @@ impl DeleteAccountAuditConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
-                        DeleteAccountAuditConfigurationError::InternalFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAccountAuditConfigurationError::InternalFailure,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        DeleteAccountAuditConfigurationError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAccountAuditConfigurationError::InvalidRequest,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteAccountAuditConfigurationError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAccountAuditConfigurationError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteAccountAuditConfigurationError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteAccountAuditConfigurationError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12644,19 +13621,30 @@ impl DeleteAuditSuppressionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteAuditSuppressionError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteAuditSuppressionError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteAuditSuppressionError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteAuditSuppressionError::InvalidRequest,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteAuditSuppressionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAuditSuppressionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12698,27 +13686,62 @@ impl DeleteAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DeleteConflictException" => {
-                    return RusotoError::Service(DeleteAuthorizerError::DeleteConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAuthorizerError::DeleteConflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteAuthorizerError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAuthorizerError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteAuthorizerError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAuthorizerError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteAuthorizerError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAuthorizerError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteAuthorizerError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAuthorizerError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteAuthorizerError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAuthorizerError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteAuthorizerError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAuthorizerError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12758,18 +13781,38 @@ impl DeleteBillingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteBillingGroupError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBillingGroupError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteBillingGroupError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBillingGroupError::InvalidRequest,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteBillingGroupError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBillingGroupError::Throttling,
+                    )
                 }
                 "VersionConflictException" => {
-                    return RusotoError::Service(DeleteBillingGroupError::VersionConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBillingGroupError::VersionConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12812,33 +13855,62 @@ impl DeleteCACertificateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CertificateStateException" => {
-                    return RusotoError::Service(DeleteCACertificateError::CertificateState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteCACertificateError::CertificateState,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteCACertificateError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCACertificateError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteCACertificateError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCACertificateError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteCACertificateError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteCACertificateError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteCACertificateError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteCACertificateError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteCACertificateError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCACertificateError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteCACertificateError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCACertificateError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12886,32 +13958,70 @@ impl DeleteCertificateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CertificateStateException" => {
-                    return RusotoError::Service(DeleteCertificateError::CertificateState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCertificateError::CertificateState,
+                    )
                 }
                 "DeleteConflictException" => {
-                    return RusotoError::Service(DeleteCertificateError::DeleteConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCertificateError::DeleteConflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteCertificateError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCertificateError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteCertificateError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCertificateError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteCertificateError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCertificateError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteCertificateError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteCertificateError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteCertificateError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCertificateError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteCertificateError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCertificateError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12950,15 +14060,30 @@ impl DeleteCustomMetricError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteCustomMetricError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCustomMetricError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteCustomMetricError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCustomMetricError::InvalidRequest,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteCustomMetricError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCustomMetricError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12992,15 +14117,30 @@ impl DeleteDimensionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteDimensionError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDimensionError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteDimensionError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDimensionError::InvalidRequest,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteDimensionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDimensionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13040,36 +14180,54 @@ impl DeleteDomainConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteDomainConfigurationError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDomainConfigurationError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteDomainConfigurationError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDomainConfigurationError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDomainConfigurationError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDomainConfigurationError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DeleteDomainConfigurationError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDomainConfigurationError::ServiceUnavailable,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteDomainConfigurationError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDomainConfigurationError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteDomainConfigurationError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDomainConfigurationError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13108,24 +14266,38 @@ impl DeleteDynamicThingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteDynamicThingGroupError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDynamicThingGroupError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteDynamicThingGroupError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDynamicThingGroupError::InvalidRequest,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteDynamicThingGroupError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDynamicThingGroupError::Throttling,
+                    )
                 }
                 "VersionConflictException" => {
-                    return RusotoError::Service(DeleteDynamicThingGroupError::VersionConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDynamicThingGroupError::VersionConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13166,24 +14338,54 @@ impl DeleteJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteJobError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteJobError::InvalidRequest,
+                    )
                 }
                 "InvalidStateTransitionException" => {
-                    return RusotoError::Service(DeleteJobError::InvalidStateTransition(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteJobError::InvalidStateTransition,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteJobError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteJobError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteJobError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteJobError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteJobError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteJobError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteJobError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13224,25 +14426,46 @@ impl DeleteJobExecutionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteJobExecutionError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteJobExecutionError::InvalidRequest,
+                    )
                 }
                 "InvalidStateTransitionException" => {
-                    return RusotoError::Service(DeleteJobExecutionError::InvalidStateTransition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteJobExecutionError::InvalidStateTransition,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteJobExecutionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteJobExecutionError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteJobExecutionError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteJobExecutionError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteJobExecutionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteJobExecutionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13278,19 +14501,30 @@ impl DeleteMitigationActionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteMitigationActionError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteMitigationActionError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteMitigationActionError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteMitigationActionError::InvalidRequest,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteMitigationActionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMitigationActionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13332,27 +14566,62 @@ impl DeleteOTAUpdateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteOTAUpdateError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteOTAUpdateError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteOTAUpdateError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteOTAUpdateError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteOTAUpdateError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteOTAUpdateError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteOTAUpdateError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteOTAUpdateError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteOTAUpdateError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteOTAUpdateError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteOTAUpdateError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteOTAUpdateError::Unauthorized,
+                    )
                 }
                 "VersionConflictException" => {
-                    return RusotoError::Service(DeleteOTAUpdateError::VersionConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteOTAUpdateError::VersionConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13398,27 +14667,62 @@ impl DeletePolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DeleteConflictException" => {
-                    return RusotoError::Service(DeletePolicyError::DeleteConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePolicyError::DeleteConflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeletePolicyError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePolicyError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeletePolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePolicyError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeletePolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePolicyError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeletePolicyError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePolicyError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeletePolicyError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePolicyError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeletePolicyError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePolicyError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13464,31 +14768,62 @@ impl DeletePolicyVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DeleteConflictException" => {
-                    return RusotoError::Service(DeletePolicyVersionError::DeleteConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePolicyVersionError::DeleteConflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeletePolicyVersionError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePolicyVersionError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeletePolicyVersionError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePolicyVersionError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeletePolicyVersionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeletePolicyVersionError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeletePolicyVersionError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeletePolicyVersionError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeletePolicyVersionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePolicyVersionError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeletePolicyVersionError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePolicyVersionError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13536,41 +14871,62 @@ impl DeleteProvisioningTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictingResourceUpdateException" => {
-                    return RusotoError::Service(
-                        DeleteProvisioningTemplateError::ConflictingResourceUpdate(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProvisioningTemplateError::ConflictingResourceUpdate,
                     )
                 }
                 "DeleteConflictException" => {
-                    return RusotoError::Service(DeleteProvisioningTemplateError::DeleteConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteProvisioningTemplateError::DeleteConflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteProvisioningTemplateError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteProvisioningTemplateError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteProvisioningTemplateError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteProvisioningTemplateError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteProvisioningTemplateError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteProvisioningTemplateError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteProvisioningTemplateError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteProvisioningTemplateError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteProvisioningTemplateError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteProvisioningTemplateError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13620,41 +14976,62 @@ impl DeleteProvisioningTemplateVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictingResourceUpdateException" => {
-                    return RusotoError::Service(
-                        DeleteProvisioningTemplateVersionError::ConflictingResourceUpdate(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProvisioningTemplateVersionError::ConflictingResourceUpdate,
                     )
                 }
                 "DeleteConflictException" => {
-                    return RusotoError::Service(
-                        DeleteProvisioningTemplateVersionError::DeleteConflict(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProvisioningTemplateVersionError::DeleteConflict,
                     )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(
-                        DeleteProvisioningTemplateVersionError::InternalFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProvisioningTemplateVersionError::InternalFailure,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        DeleteProvisioningTemplateVersionError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProvisioningTemplateVersionError::InvalidRequest,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteProvisioningTemplateVersionError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProvisioningTemplateVersionError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
-                        DeleteProvisioningTemplateVersionError::Throttling(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProvisioningTemplateVersionError::Throttling,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
-                        DeleteProvisioningTemplateVersionError::Unauthorized(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProvisioningTemplateVersionError::Unauthorized,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13708,27 +15085,46 @@ impl DeleteRegistrationCodeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteRegistrationCodeError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRegistrationCodeError::InternalFailure,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteRegistrationCodeError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRegistrationCodeError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteRegistrationCodeError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRegistrationCodeError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteRegistrationCodeError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRegistrationCodeError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteRegistrationCodeError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRegistrationCodeError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13772,27 +15168,62 @@ impl DeleteRoleAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DeleteConflictException" => {
-                    return RusotoError::Service(DeleteRoleAliasError::DeleteConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRoleAliasError::DeleteConflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteRoleAliasError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRoleAliasError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteRoleAliasError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRoleAliasError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteRoleAliasError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRoleAliasError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteRoleAliasError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRoleAliasError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteRoleAliasError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRoleAliasError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteRoleAliasError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRoleAliasError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13832,22 +15263,38 @@ impl DeleteScheduledAuditError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteScheduledAuditError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteScheduledAuditError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteScheduledAuditError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteScheduledAuditError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteScheduledAuditError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteScheduledAuditError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteScheduledAuditError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteScheduledAuditError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13884,24 +15331,38 @@ impl DeleteSecurityProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteSecurityProfileError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSecurityProfileError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteSecurityProfileError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSecurityProfileError::InvalidRequest,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteSecurityProfileError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSecurityProfileError::Throttling,
+                    )
                 }
                 "VersionConflictException" => {
-                    return RusotoError::Service(DeleteSecurityProfileError::VersionConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSecurityProfileError::VersionConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13944,27 +15405,62 @@ impl DeleteStreamError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "DeleteConflictException" => {
-                    return RusotoError::Service(DeleteStreamError::DeleteConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteStreamError::DeleteConflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteStreamError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteStreamError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteStreamError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteStreamError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteStreamError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteStreamError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteStreamError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteStreamError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteStreamError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteStreamError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteStreamError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14010,27 +15506,62 @@ impl DeleteThingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteThingError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThingError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteThingError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThingError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteThingError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThingError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteThingError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThingError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteThingError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThingError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteThingError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThingError::Unauthorized,
+                    )
                 }
                 "VersionConflictException" => {
-                    return RusotoError::Service(DeleteThingError::VersionConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThingError::VersionConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14070,18 +15601,38 @@ impl DeleteThingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteThingGroupError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThingGroupError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteThingGroupError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThingGroupError::InvalidRequest,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteThingGroupError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThingGroupError::Throttling,
+                    )
                 }
                 "VersionConflictException" => {
-                    return RusotoError::Service(DeleteThingGroupError::VersionConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThingGroupError::VersionConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14122,24 +15673,54 @@ impl DeleteThingTypeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteThingTypeError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThingTypeError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteThingTypeError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThingTypeError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteThingTypeError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThingTypeError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteThingTypeError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThingTypeError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteThingTypeError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThingTypeError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteThingTypeError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThingTypeError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14180,23 +15761,46 @@ impl DeleteTopicRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictingResourceUpdateException" => {
-                    return RusotoError::Service(DeleteTopicRuleError::ConflictingResourceUpdate(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteTopicRuleError::ConflictingResourceUpdate,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DeleteTopicRuleError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTopicRuleError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteTopicRuleError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTopicRuleError::InvalidRequest,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteTopicRuleError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTopicRuleError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteTopicRuleError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTopicRuleError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14238,29 +15842,46 @@ impl DeleteTopicRuleDestinationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictingResourceUpdateException" => {
-                    return RusotoError::Service(
-                        DeleteTopicRuleDestinationError::ConflictingResourceUpdate(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTopicRuleDestinationError::ConflictingResourceUpdate,
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DeleteTopicRuleDestinationError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTopicRuleDestinationError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteTopicRuleDestinationError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteTopicRuleDestinationError::InvalidRequest,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DeleteTopicRuleDestinationError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTopicRuleDestinationError::ServiceUnavailable,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeleteTopicRuleDestinationError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteTopicRuleDestinationError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14300,17 +15921,30 @@ impl DeleteV2LoggingLevelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DeleteV2LoggingLevelError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteV2LoggingLevelError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteV2LoggingLevelError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteV2LoggingLevelError::InvalidRequest,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteV2LoggingLevelError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteV2LoggingLevelError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14350,26 +15984,54 @@ impl DeprecateThingTypeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeprecateThingTypeError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeprecateThingTypeError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeprecateThingTypeError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeprecateThingTypeError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeprecateThingTypeError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeprecateThingTypeError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeprecateThingTypeError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeprecateThingTypeError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeprecateThingTypeError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeprecateThingTypeError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DeprecateThingTypeError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeprecateThingTypeError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14406,16 +16068,22 @@ impl DescribeAccountAuditConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
-                        DescribeAccountAuditConfigurationError::InternalFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAccountAuditConfigurationError::InternalFailure,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
-                        DescribeAccountAuditConfigurationError::Throttling(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAccountAuditConfigurationError::Throttling,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14452,22 +16120,38 @@ impl DescribeAuditFindingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeAuditFindingError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAuditFindingError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeAuditFindingError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAuditFindingError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeAuditFindingError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAuditFindingError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeAuditFindingError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAuditFindingError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14506,26 +16190,38 @@ impl DescribeAuditMitigationActionsTaskError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
-                        DescribeAuditMitigationActionsTaskError::InternalFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAuditMitigationActionsTaskError::InternalFailure,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        DescribeAuditMitigationActionsTaskError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAuditMitigationActionsTaskError::InvalidRequest,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeAuditMitigationActionsTaskError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAuditMitigationActionsTaskError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
-                        DescribeAuditMitigationActionsTaskError::Throttling(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAuditMitigationActionsTaskError::Throttling,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14570,24 +16266,38 @@ impl DescribeAuditSuppressionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeAuditSuppressionError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAuditSuppressionError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeAuditSuppressionError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAuditSuppressionError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeAuditSuppressionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAuditSuppressionError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeAuditSuppressionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAuditSuppressionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14624,18 +16334,38 @@ impl DescribeAuditTaskError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeAuditTaskError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAuditTaskError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeAuditTaskError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAuditTaskError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeAuditTaskError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAuditTaskError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeAuditTaskError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAuditTaskError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14676,26 +16406,54 @@ impl DescribeAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeAuthorizerError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAuthorizerError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeAuthorizerError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAuthorizerError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeAuthorizerError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAuthorizerError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeAuthorizerError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAuthorizerError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeAuthorizerError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAuthorizerError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeAuthorizerError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAuthorizerError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14734,22 +16492,38 @@ impl DescribeBillingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeBillingGroupError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeBillingGroupError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeBillingGroupError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBillingGroupError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeBillingGroupError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeBillingGroupError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeBillingGroupError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBillingGroupError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14790,32 +16564,54 @@ impl DescribeCACertificateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeCACertificateError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeCACertificateError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeCACertificateError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeCACertificateError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeCACertificateError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeCACertificateError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeCACertificateError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeCACertificateError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeCACertificateError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCACertificateError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeCACertificateError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCACertificateError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14858,28 +16654,54 @@ impl DescribeCertificateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeCertificateError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCertificateError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeCertificateError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCertificateError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeCertificateError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeCertificateError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeCertificateError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeCertificateError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeCertificateError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCertificateError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeCertificateError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCertificateError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14918,22 +16740,38 @@ impl DescribeCustomMetricError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeCustomMetricError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeCustomMetricError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeCustomMetricError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCustomMetricError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeCustomMetricError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeCustomMetricError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeCustomMetricError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCustomMetricError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14974,36 +16812,54 @@ impl DescribeDefaultAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeDefaultAuthorizerError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDefaultAuthorizerError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeDefaultAuthorizerError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDefaultAuthorizerError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDefaultAuthorizerError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDefaultAuthorizerError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DescribeDefaultAuthorizerError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDefaultAuthorizerError::ServiceUnavailable,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDefaultAuthorizerError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDefaultAuthorizerError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeDefaultAuthorizerError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDefaultAuthorizerError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15044,26 +16900,38 @@ impl DescribeDetectMitigationActionsTaskError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
-                        DescribeDetectMitigationActionsTaskError::InternalFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDetectMitigationActionsTaskError::InternalFailure,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        DescribeDetectMitigationActionsTaskError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDetectMitigationActionsTaskError::InvalidRequest,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeDetectMitigationActionsTaskError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDetectMitigationActionsTaskError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
-                        DescribeDetectMitigationActionsTaskError::Throttling(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDetectMitigationActionsTaskError::Throttling,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15108,18 +16976,38 @@ impl DescribeDimensionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeDimensionError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDimensionError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeDimensionError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDimensionError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDimensionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDimensionError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDimensionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDimensionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15162,36 +17050,54 @@ impl DescribeDomainConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeDomainConfigurationError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDomainConfigurationError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeDomainConfigurationError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDomainConfigurationError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeDomainConfigurationError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDomainConfigurationError::ResourceNotFound,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DescribeDomainConfigurationError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDomainConfigurationError::ServiceUnavailable,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDomainConfigurationError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDomainConfigurationError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeDomainConfigurationError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDomainConfigurationError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15232,18 +17138,38 @@ impl DescribeEndpointError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeEndpointError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEndpointError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeEndpointError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEndpointError::InvalidRequest,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeEndpointError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEndpointError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeEndpointError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEndpointError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15278,16 +17204,22 @@ impl DescribeEventConfigurationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeEventConfigurationsError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEventConfigurationsError::InternalFailure,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeEventConfigurationsError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEventConfigurationsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15326,24 +17258,54 @@ impl DescribeIndexError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeIndexError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeIndexError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeIndexError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeIndexError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeIndexError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeIndexError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeIndexError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeIndexError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeIndexError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeIndexError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeIndexError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeIndexError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15382,18 +17344,38 @@ impl DescribeJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeJobError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeJobError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeJobError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeJobError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeJobError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeJobError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeJobError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15430,22 +17412,38 @@ impl DescribeJobExecutionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeJobExecutionError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeJobExecutionError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeJobExecutionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeJobExecutionError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeJobExecutionError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeJobExecutionError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeJobExecutionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeJobExecutionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15482,24 +17480,38 @@ impl DescribeMitigationActionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeMitigationActionError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeMitigationActionError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeMitigationActionError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeMitigationActionError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeMitigationActionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeMitigationActionError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeMitigationActionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMitigationActionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15540,31 +17552,46 @@ impl DescribeProvisioningTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
-                        DescribeProvisioningTemplateError::InternalFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeProvisioningTemplateError::InternalFailure,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeProvisioningTemplateError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeProvisioningTemplateError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeProvisioningTemplateError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeProvisioningTemplateError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeProvisioningTemplateError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeProvisioningTemplateError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeProvisioningTemplateError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeProvisioningTemplateError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15608,31 +17635,46 @@ impl DescribeProvisioningTemplateVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
-                        DescribeProvisioningTemplateVersionError::InternalFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeProvisioningTemplateVersionError::InternalFailure,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        DescribeProvisioningTemplateVersionError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeProvisioningTemplateVersionError::InvalidRequest,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeProvisioningTemplateVersionError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeProvisioningTemplateVersionError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
-                        DescribeProvisioningTemplateVersionError::Throttling(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeProvisioningTemplateVersionError::Throttling,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
-                        DescribeProvisioningTemplateVersionError::Unauthorized(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeProvisioningTemplateVersionError::Unauthorized,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15684,26 +17726,54 @@ impl DescribeRoleAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeRoleAliasError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRoleAliasError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeRoleAliasError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRoleAliasError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeRoleAliasError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRoleAliasError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeRoleAliasError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRoleAliasError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeRoleAliasError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRoleAliasError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeRoleAliasError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRoleAliasError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15742,24 +17812,38 @@ impl DescribeScheduledAuditError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeScheduledAuditError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeScheduledAuditError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeScheduledAuditError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeScheduledAuditError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeScheduledAuditError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeScheduledAuditError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeScheduledAuditError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeScheduledAuditError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15796,24 +17880,38 @@ impl DescribeSecurityProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeSecurityProfileError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeSecurityProfileError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeSecurityProfileError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeSecurityProfileError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeSecurityProfileError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeSecurityProfileError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeSecurityProfileError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSecurityProfileError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15854,24 +17952,54 @@ impl DescribeStreamError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeStreamError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeStreamError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeStreamError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeStreamError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeStreamError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeStreamError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeStreamError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeStreamError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeStreamError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeStreamError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeStreamError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15914,24 +18042,54 @@ impl DescribeThingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeThingError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeThingError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeThingError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeThingError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeThingError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeThingError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeThingError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeThingError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeThingError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeThingError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeThingError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeThingError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15970,18 +18128,38 @@ impl DescribeThingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeThingGroupError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeThingGroupError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeThingGroupError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeThingGroupError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeThingGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeThingGroupError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeThingGroupError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeThingGroupError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16022,31 +18200,46 @@ impl DescribeThingRegistrationTaskError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
-                        DescribeThingRegistrationTaskError::InternalFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeThingRegistrationTaskError::InternalFailure,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        DescribeThingRegistrationTaskError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeThingRegistrationTaskError::InvalidRequest,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeThingRegistrationTaskError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeThingRegistrationTaskError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeThingRegistrationTaskError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeThingRegistrationTaskError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeThingRegistrationTaskError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeThingRegistrationTaskError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16092,26 +18285,54 @@ impl DescribeThingTypeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeThingTypeError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeThingTypeError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeThingTypeError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeThingTypeError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeThingTypeError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeThingTypeError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeThingTypeError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeThingTypeError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeThingTypeError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeThingTypeError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeThingTypeError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeThingTypeError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16154,24 +18375,54 @@ impl DetachPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DetachPolicyError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachPolicyError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DetachPolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachPolicyError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DetachPolicyError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachPolicyError::LimitExceeded,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DetachPolicyError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachPolicyError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DetachPolicyError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachPolicyError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DetachPolicyError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachPolicyError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16214,32 +18465,54 @@ impl DetachPrincipalPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DetachPrincipalPolicyError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetachPrincipalPolicyError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DetachPrincipalPolicyError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetachPrincipalPolicyError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DetachPrincipalPolicyError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetachPrincipalPolicyError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DetachPrincipalPolicyError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetachPrincipalPolicyError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DetachPrincipalPolicyError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachPrincipalPolicyError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DetachPrincipalPolicyError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachPrincipalPolicyError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16278,24 +18551,38 @@ impl DetachSecurityProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DetachSecurityProfileError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetachSecurityProfileError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DetachSecurityProfileError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetachSecurityProfileError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DetachSecurityProfileError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetachSecurityProfileError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DetachSecurityProfileError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachSecurityProfileError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16336,30 +18623,54 @@ impl DetachThingPrincipalError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DetachThingPrincipalError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetachThingPrincipalError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DetachThingPrincipalError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachThingPrincipalError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DetachThingPrincipalError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetachThingPrincipalError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DetachThingPrincipalError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetachThingPrincipalError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DetachThingPrincipalError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachThingPrincipalError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DetachThingPrincipalError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachThingPrincipalError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16400,23 +18711,46 @@ impl DisableTopicRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictingResourceUpdateException" => {
-                    return RusotoError::Service(DisableTopicRuleError::ConflictingResourceUpdate(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisableTopicRuleError::ConflictingResourceUpdate,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DisableTopicRuleError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableTopicRuleError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DisableTopicRuleError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableTopicRuleError::InvalidRequest,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DisableTopicRuleError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableTopicRuleError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DisableTopicRuleError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableTopicRuleError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16456,23 +18790,46 @@ impl EnableTopicRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictingResourceUpdateException" => {
-                    return RusotoError::Service(EnableTopicRuleError::ConflictingResourceUpdate(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        EnableTopicRuleError::ConflictingResourceUpdate,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(EnableTopicRuleError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableTopicRuleError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(EnableTopicRuleError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableTopicRuleError::InvalidRequest,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(EnableTopicRuleError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableTopicRuleError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(EnableTopicRuleError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableTopicRuleError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16512,26 +18869,38 @@ impl GetBehaviorModelTrainingSummariesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
-                        GetBehaviorModelTrainingSummariesError::InternalFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBehaviorModelTrainingSummariesError::InternalFailure,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        GetBehaviorModelTrainingSummariesError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBehaviorModelTrainingSummariesError::InvalidRequest,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        GetBehaviorModelTrainingSummariesError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBehaviorModelTrainingSummariesError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
-                        GetBehaviorModelTrainingSummariesError::Throttling(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBehaviorModelTrainingSummariesError::Throttling,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16584,33 +18953,78 @@ impl GetCardinalityError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IndexNotReadyException" => {
-                    return RusotoError::Service(GetCardinalityError::IndexNotReady(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCardinalityError::IndexNotReady,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetCardinalityError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCardinalityError::InternalFailure,
+                    )
                 }
                 "InvalidAggregationException" => {
-                    return RusotoError::Service(GetCardinalityError::InvalidAggregation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCardinalityError::InvalidAggregation,
+                    )
                 }
                 "InvalidQueryException" => {
-                    return RusotoError::Service(GetCardinalityError::InvalidQuery(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCardinalityError::InvalidQuery,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetCardinalityError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCardinalityError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetCardinalityError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCardinalityError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetCardinalityError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCardinalityError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetCardinalityError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCardinalityError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetCardinalityError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCardinalityError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16658,33 +19072,62 @@ impl GetEffectivePoliciesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetEffectivePoliciesError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetEffectivePoliciesError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetEffectivePoliciesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEffectivePoliciesError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetEffectivePoliciesError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEffectivePoliciesError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetEffectivePoliciesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetEffectivePoliciesError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetEffectivePoliciesError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetEffectivePoliciesError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetEffectivePoliciesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEffectivePoliciesError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetEffectivePoliciesError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEffectivePoliciesError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16726,29 +19169,46 @@ impl GetIndexingConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetIndexingConfigurationError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetIndexingConfigurationError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetIndexingConfigurationError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetIndexingConfigurationError::InvalidRequest,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetIndexingConfigurationError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetIndexingConfigurationError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetIndexingConfigurationError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIndexingConfigurationError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetIndexingConfigurationError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetIndexingConfigurationError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16786,18 +19246,38 @@ impl GetJobDocumentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetJobDocumentError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobDocumentError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetJobDocumentError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobDocumentError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetJobDocumentError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobDocumentError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetJobDocumentError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetJobDocumentError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16832,17 +19312,30 @@ impl GetLoggingOptionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(GetLoggingOptionsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLoggingOptionsError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetLoggingOptionsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLoggingOptionsError::InvalidRequest,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetLoggingOptionsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetLoggingOptionsError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16882,24 +19375,54 @@ impl GetOTAUpdateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetOTAUpdateError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOTAUpdateError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetOTAUpdateError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOTAUpdateError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetOTAUpdateError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOTAUpdateError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetOTAUpdateError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOTAUpdateError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetOTAUpdateError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOTAUpdateError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetOTAUpdateError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOTAUpdateError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16948,33 +19471,78 @@ impl GetPercentilesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IndexNotReadyException" => {
-                    return RusotoError::Service(GetPercentilesError::IndexNotReady(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPercentilesError::IndexNotReady,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetPercentilesError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPercentilesError::InternalFailure,
+                    )
                 }
                 "InvalidAggregationException" => {
-                    return RusotoError::Service(GetPercentilesError::InvalidAggregation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPercentilesError::InvalidAggregation,
+                    )
                 }
                 "InvalidQueryException" => {
-                    return RusotoError::Service(GetPercentilesError::InvalidQuery(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPercentilesError::InvalidQuery,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetPercentilesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPercentilesError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetPercentilesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPercentilesError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetPercentilesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPercentilesError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetPercentilesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPercentilesError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetPercentilesError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPercentilesError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17020,24 +19588,54 @@ impl GetPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetPolicyError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPolicyError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetPolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPolicyError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetPolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPolicyError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetPolicyError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPolicyError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetPolicyError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPolicyError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetPolicyError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPolicyError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17080,24 +19678,54 @@ impl GetPolicyVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetPolicyVersionError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPolicyVersionError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetPolicyVersionError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPolicyVersionError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetPolicyVersionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPolicyVersionError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetPolicyVersionError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPolicyVersionError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetPolicyVersionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPolicyVersionError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetPolicyVersionError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPolicyVersionError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17138,23 +19766,46 @@ impl GetRegistrationCodeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetRegistrationCodeError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRegistrationCodeError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetRegistrationCodeError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRegistrationCodeError::InvalidRequest,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetRegistrationCodeError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRegistrationCodeError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetRegistrationCodeError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRegistrationCodeError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetRegistrationCodeError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRegistrationCodeError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17202,33 +19853,78 @@ impl GetStatisticsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IndexNotReadyException" => {
-                    return RusotoError::Service(GetStatisticsError::IndexNotReady(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetStatisticsError::IndexNotReady,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetStatisticsError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetStatisticsError::InternalFailure,
+                    )
                 }
                 "InvalidAggregationException" => {
-                    return RusotoError::Service(GetStatisticsError::InvalidAggregation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetStatisticsError::InvalidAggregation,
+                    )
                 }
                 "InvalidQueryException" => {
-                    return RusotoError::Service(GetStatisticsError::InvalidQuery(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetStatisticsError::InvalidQuery,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetStatisticsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetStatisticsError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetStatisticsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetStatisticsError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetStatisticsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetStatisticsError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetStatisticsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetStatisticsError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetStatisticsError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetStatisticsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17270,18 +19966,38 @@ impl GetTopicRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(GetTopicRuleError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTopicRuleError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetTopicRuleError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTopicRuleError::InvalidRequest,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetTopicRuleError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTopicRuleError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetTopicRuleError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTopicRuleError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17318,24 +20034,38 @@ impl GetTopicRuleDestinationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(GetTopicRuleDestinationError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTopicRuleDestinationError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetTopicRuleDestinationError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetTopicRuleDestinationError::InvalidRequest,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetTopicRuleDestinationError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetTopicRuleDestinationError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetTopicRuleDestinationError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetTopicRuleDestinationError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17370,17 +20100,30 @@ impl GetV2LoggingOptionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(GetV2LoggingOptionsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetV2LoggingOptionsError::Internal,
+                    )
                 }
                 "NotConfiguredException" => {
-                    return RusotoError::Service(GetV2LoggingOptionsError::NotConfigured(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetV2LoggingOptionsError::NotConfigured,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetV2LoggingOptionsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetV2LoggingOptionsError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17416,22 +20159,38 @@ impl ListActiveViolationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListActiveViolationsError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListActiveViolationsError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListActiveViolationsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListActiveViolationsError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListActiveViolationsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListActiveViolationsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListActiveViolationsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListActiveViolationsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17474,33 +20233,62 @@ impl ListAttachedPoliciesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListAttachedPoliciesError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAttachedPoliciesError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListAttachedPoliciesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAttachedPoliciesError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListAttachedPoliciesError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAttachedPoliciesError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListAttachedPoliciesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAttachedPoliciesError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListAttachedPoliciesError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAttachedPoliciesError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListAttachedPoliciesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAttachedPoliciesError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListAttachedPoliciesError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAttachedPoliciesError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17538,15 +20326,30 @@ impl ListAuditFindingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListAuditFindingsError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAuditFindingsError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListAuditFindingsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAuditFindingsError::InvalidRequest,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListAuditFindingsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAuditFindingsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17582,21 +20385,30 @@ impl ListAuditMitigationActionsExecutionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
-                        ListAuditMitigationActionsExecutionsError::InternalFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAuditMitigationActionsExecutionsError::InternalFailure,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        ListAuditMitigationActionsExecutionsError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAuditMitigationActionsExecutionsError::InvalidRequest,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
-                        ListAuditMitigationActionsExecutionsError::Throttling(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAuditMitigationActionsExecutionsError::Throttling,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17638,21 +20450,30 @@ impl ListAuditMitigationActionsTasksError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
-                        ListAuditMitigationActionsTasksError::InternalFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAuditMitigationActionsTasksError::InternalFailure,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        ListAuditMitigationActionsTasksError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAuditMitigationActionsTasksError::InvalidRequest,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListAuditMitigationActionsTasksError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAuditMitigationActionsTasksError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17690,19 +20511,30 @@ impl ListAuditSuppressionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListAuditSuppressionsError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAuditSuppressionsError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListAuditSuppressionsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAuditSuppressionsError::InvalidRequest,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListAuditSuppressionsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAuditSuppressionsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17736,15 +20568,30 @@ impl ListAuditTasksError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListAuditTasksError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAuditTasksError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListAuditTasksError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAuditTasksError::InvalidRequest,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListAuditTasksError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAuditTasksError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17782,21 +20629,46 @@ impl ListAuthorizersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListAuthorizersError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAuthorizersError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListAuthorizersError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAuthorizersError::InvalidRequest,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListAuthorizersError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAuthorizersError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListAuthorizersError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAuthorizersError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListAuthorizersError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAuthorizersError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17834,18 +20706,38 @@ impl ListBillingGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListBillingGroupsError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBillingGroupsError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListBillingGroupsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBillingGroupsError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListBillingGroupsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBillingGroupsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListBillingGroupsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBillingGroupsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17884,23 +20776,46 @@ impl ListCACertificatesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListCACertificatesError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCACertificatesError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListCACertificatesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCACertificatesError::InvalidRequest,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListCACertificatesError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListCACertificatesError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListCACertificatesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCACertificatesError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListCACertificatesError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCACertificatesError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17940,21 +20855,46 @@ impl ListCertificatesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListCertificatesError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCertificatesError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListCertificatesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCertificatesError::InvalidRequest,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListCertificatesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCertificatesError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListCertificatesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCertificatesError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListCertificatesError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCertificatesError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17994,25 +20934,46 @@ impl ListCertificatesByCAError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListCertificatesByCAError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListCertificatesByCAError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListCertificatesByCAError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCertificatesByCAError::InvalidRequest,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListCertificatesByCAError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListCertificatesByCAError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListCertificatesByCAError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCertificatesByCAError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListCertificatesByCAError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCertificatesByCAError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18048,15 +21009,30 @@ impl ListCustomMetricsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListCustomMetricsError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCustomMetricsError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListCustomMetricsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCustomMetricsError::InvalidRequest,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListCustomMetricsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCustomMetricsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18092,21 +21068,30 @@ impl ListDetectMitigationActionsExecutionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
-                        ListDetectMitigationActionsExecutionsError::InternalFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDetectMitigationActionsExecutionsError::InternalFailure,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        ListDetectMitigationActionsExecutionsError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDetectMitigationActionsExecutionsError::InvalidRequest,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
-                        ListDetectMitigationActionsExecutionsError::Throttling(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDetectMitigationActionsExecutionsError::Throttling,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18148,21 +21133,30 @@ impl ListDetectMitigationActionsTasksError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
-                        ListDetectMitigationActionsTasksError::InternalFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDetectMitigationActionsTasksError::InternalFailure,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        ListDetectMitigationActionsTasksError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDetectMitigationActionsTasksError::InvalidRequest,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDetectMitigationActionsTasksError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDetectMitigationActionsTasksError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18200,15 +21194,30 @@ impl ListDimensionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListDimensionsError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDimensionsError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListDimensionsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDimensionsError::InvalidRequest,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDimensionsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDimensionsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18246,29 +21255,46 @@ impl ListDomainConfigurationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListDomainConfigurationsError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDomainConfigurationsError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListDomainConfigurationsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDomainConfigurationsError::InvalidRequest,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListDomainConfigurationsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDomainConfigurationsError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDomainConfigurationsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDomainConfigurationsError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListDomainConfigurationsError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDomainConfigurationsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18308,21 +21334,46 @@ impl ListIndicesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListIndicesError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIndicesError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListIndicesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIndicesError::InvalidRequest,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListIndicesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIndicesError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListIndicesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIndicesError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListIndicesError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIndicesError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18360,24 +21411,38 @@ impl ListJobExecutionsForJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListJobExecutionsForJobError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListJobExecutionsForJobError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListJobExecutionsForJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListJobExecutionsForJobError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListJobExecutionsForJobError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListJobExecutionsForJobError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListJobExecutionsForJobError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListJobExecutionsForJobError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18414,26 +21479,38 @@ impl ListJobExecutionsForThingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListJobExecutionsForThingError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListJobExecutionsForThingError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListJobExecutionsForThingError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListJobExecutionsForThingError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        ListJobExecutionsForThingError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListJobExecutionsForThingError::ServiceUnavailable,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListJobExecutionsForThingError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListJobExecutionsForThingError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18470,18 +21547,38 @@ impl ListJobsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListJobsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListJobsError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListJobsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListJobsError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListJobsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListJobsError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListJobsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListJobsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18516,19 +21613,30 @@ impl ListMitigationActionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListMitigationActionsError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListMitigationActionsError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListMitigationActionsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListMitigationActionsError::InvalidRequest,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListMitigationActionsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMitigationActionsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18566,21 +21674,46 @@ impl ListOTAUpdatesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListOTAUpdatesError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOTAUpdatesError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListOTAUpdatesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOTAUpdatesError::InvalidRequest,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListOTAUpdatesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOTAUpdatesError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListOTAUpdatesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOTAUpdatesError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListOTAUpdatesError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOTAUpdatesError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18620,29 +21753,46 @@ impl ListOutgoingCertificatesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListOutgoingCertificatesError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListOutgoingCertificatesError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListOutgoingCertificatesError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListOutgoingCertificatesError::InvalidRequest,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListOutgoingCertificatesError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListOutgoingCertificatesError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListOutgoingCertificatesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOutgoingCertificatesError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListOutgoingCertificatesError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListOutgoingCertificatesError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18682,21 +21832,46 @@ impl ListPoliciesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListPoliciesError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPoliciesError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListPoliciesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPoliciesError::InvalidRequest,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListPoliciesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPoliciesError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListPoliciesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPoliciesError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListPoliciesError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPoliciesError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18738,30 +21913,54 @@ impl ListPolicyPrincipalsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListPolicyPrincipalsError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPolicyPrincipalsError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListPolicyPrincipalsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPolicyPrincipalsError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListPolicyPrincipalsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPolicyPrincipalsError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListPolicyPrincipalsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPolicyPrincipalsError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListPolicyPrincipalsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPolicyPrincipalsError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListPolicyPrincipalsError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPolicyPrincipalsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18804,26 +22003,54 @@ impl ListPolicyVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListPolicyVersionsError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPolicyVersionsError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListPolicyVersionsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPolicyVersionsError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListPolicyVersionsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPolicyVersionsError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListPolicyVersionsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPolicyVersionsError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListPolicyVersionsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPolicyVersionsError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListPolicyVersionsError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPolicyVersionsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18866,32 +22093,54 @@ impl ListPrincipalPoliciesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListPrincipalPoliciesError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPrincipalPoliciesError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListPrincipalPoliciesError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPrincipalPoliciesError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListPrincipalPoliciesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPrincipalPoliciesError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListPrincipalPoliciesError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPrincipalPoliciesError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListPrincipalPoliciesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPrincipalPoliciesError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListPrincipalPoliciesError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPrincipalPoliciesError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18934,28 +22183,54 @@ impl ListPrincipalThingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListPrincipalThingsError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPrincipalThingsError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListPrincipalThingsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPrincipalThingsError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListPrincipalThingsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPrincipalThingsError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListPrincipalThingsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPrincipalThingsError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListPrincipalThingsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPrincipalThingsError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListPrincipalThingsError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPrincipalThingsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18998,31 +22273,46 @@ impl ListProvisioningTemplateVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
-                        ListProvisioningTemplateVersionsError::InternalFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProvisioningTemplateVersionsError::InternalFailure,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        ListProvisioningTemplateVersionsError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProvisioningTemplateVersionsError::InvalidRequest,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        ListProvisioningTemplateVersionsError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProvisioningTemplateVersionsError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListProvisioningTemplateVersionsError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListProvisioningTemplateVersionsError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
-                        ListProvisioningTemplateVersionsError::Unauthorized(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProvisioningTemplateVersionsError::Unauthorized,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19068,26 +22358,38 @@ impl ListProvisioningTemplatesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListProvisioningTemplatesError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListProvisioningTemplatesError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListProvisioningTemplatesError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListProvisioningTemplatesError::InvalidRequest,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListProvisioningTemplatesError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListProvisioningTemplatesError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListProvisioningTemplatesError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListProvisioningTemplatesError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19126,21 +22428,46 @@ impl ListRoleAliasesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListRoleAliasesError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRoleAliasesError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListRoleAliasesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRoleAliasesError::InvalidRequest,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListRoleAliasesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRoleAliasesError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListRoleAliasesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRoleAliasesError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListRoleAliasesError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRoleAliasesError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19176,15 +22503,30 @@ impl ListScheduledAuditsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListScheduledAuditsError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListScheduledAuditsError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListScheduledAuditsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListScheduledAuditsError::InvalidRequest,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListScheduledAuditsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListScheduledAuditsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19220,22 +22562,38 @@ impl ListSecurityProfilesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListSecurityProfilesError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSecurityProfilesError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListSecurityProfilesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSecurityProfilesError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListSecurityProfilesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSecurityProfilesError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListSecurityProfilesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSecurityProfilesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19274,26 +22632,38 @@ impl ListSecurityProfilesForTargetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
-                        ListSecurityProfilesForTargetError::InternalFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSecurityProfilesForTargetError::InternalFailure,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        ListSecurityProfilesForTargetError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSecurityProfilesForTargetError::InvalidRequest,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        ListSecurityProfilesForTargetError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSecurityProfilesForTargetError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListSecurityProfilesForTargetError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSecurityProfilesForTargetError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19336,21 +22706,46 @@ impl ListStreamsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListStreamsError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListStreamsError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListStreamsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListStreamsError::InvalidRequest,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListStreamsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListStreamsError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListStreamsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListStreamsError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListStreamsError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListStreamsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19388,20 +22783,38 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19444,33 +22857,62 @@ impl ListTargetsForPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTargetsForPolicyError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTargetsForPolicyError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTargetsForPolicyError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTargetsForPolicyError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListTargetsForPolicyError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTargetsForPolicyError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTargetsForPolicyError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTargetsForPolicyError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListTargetsForPolicyError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTargetsForPolicyError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTargetsForPolicyError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTargetsForPolicyError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListTargetsForPolicyError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTargetsForPolicyError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19512,26 +22954,38 @@ impl ListTargetsForSecurityProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
-                        ListTargetsForSecurityProfileError::InternalFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTargetsForSecurityProfileError::InternalFailure,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        ListTargetsForSecurityProfileError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTargetsForSecurityProfileError::InvalidRequest,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        ListTargetsForSecurityProfileError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTargetsForSecurityProfileError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTargetsForSecurityProfileError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTargetsForSecurityProfileError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19572,18 +23026,38 @@ impl ListThingGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListThingGroupsError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThingGroupsError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListThingGroupsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThingGroupsError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListThingGroupsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThingGroupsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListThingGroupsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThingGroupsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19620,24 +23094,38 @@ impl ListThingGroupsForThingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListThingGroupsForThingError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListThingGroupsForThingError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListThingGroupsForThingError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListThingGroupsForThingError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListThingGroupsForThingError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListThingGroupsForThingError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListThingGroupsForThingError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThingGroupsForThingError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19678,28 +23166,54 @@ impl ListThingPrincipalsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListThingPrincipalsError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThingPrincipalsError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListThingPrincipalsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThingPrincipalsError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListThingPrincipalsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListThingPrincipalsError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListThingPrincipalsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListThingPrincipalsError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListThingPrincipalsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThingPrincipalsError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListThingPrincipalsError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThingPrincipalsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19740,26 +23254,38 @@ impl ListThingRegistrationTaskReportsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
-                        ListThingRegistrationTaskReportsError::InternalFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThingRegistrationTaskReportsError::InternalFailure,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        ListThingRegistrationTaskReportsError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThingRegistrationTaskReportsError::InvalidRequest,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListThingRegistrationTaskReportsError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListThingRegistrationTaskReportsError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(
-                        ListThingRegistrationTaskReportsError::Unauthorized(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThingRegistrationTaskReportsError::Unauthorized,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19804,26 +23330,38 @@ impl ListThingRegistrationTasksError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListThingRegistrationTasksError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListThingRegistrationTasksError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListThingRegistrationTasksError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListThingRegistrationTasksError::InvalidRequest,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListThingRegistrationTasksError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListThingRegistrationTasksError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListThingRegistrationTasksError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListThingRegistrationTasksError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19862,21 +23400,46 @@ impl ListThingTypesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListThingTypesError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThingTypesError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListThingTypesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThingTypesError::InvalidRequest,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListThingTypesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThingTypesError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListThingTypesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThingTypesError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListThingTypesError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThingTypesError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19916,21 +23479,46 @@ impl ListThingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListThingsError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThingsError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListThingsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThingsError::InvalidRequest,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListThingsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThingsError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListThingsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThingsError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListThingsError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThingsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19968,24 +23556,38 @@ impl ListThingsInBillingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListThingsInBillingGroupError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListThingsInBillingGroupError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListThingsInBillingGroupError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListThingsInBillingGroupError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListThingsInBillingGroupError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListThingsInBillingGroupError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListThingsInBillingGroupError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThingsInBillingGroupError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20022,24 +23624,38 @@ impl ListThingsInThingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListThingsInThingGroupError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListThingsInThingGroupError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListThingsInThingGroupError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListThingsInThingGroupError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListThingsInThingGroupError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListThingsInThingGroupError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListThingsInThingGroupError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThingsInThingGroupError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20076,24 +23692,38 @@ impl ListTopicRuleDestinationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListTopicRuleDestinationsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTopicRuleDestinationsError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTopicRuleDestinationsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTopicRuleDestinationsError::InvalidRequest,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        ListTopicRuleDestinationsError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTopicRuleDestinationsError::ServiceUnavailable,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListTopicRuleDestinationsError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTopicRuleDestinationsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20128,15 +23758,30 @@ impl ListTopicRulesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListTopicRulesError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTopicRulesError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTopicRulesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTopicRulesError::InvalidRequest,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListTopicRulesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTopicRulesError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20172,20 +23817,38 @@ impl ListV2LoggingLevelsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListV2LoggingLevelsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListV2LoggingLevelsError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListV2LoggingLevelsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListV2LoggingLevelsError::InvalidRequest,
+                    )
                 }
                 "NotConfiguredException" => {
-                    return RusotoError::Service(ListV2LoggingLevelsError::NotConfigured(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListV2LoggingLevelsError::NotConfigured,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListV2LoggingLevelsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListV2LoggingLevelsError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20220,15 +23883,30 @@ impl ListViolationEventsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListViolationEventsError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListViolationEventsError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListViolationEventsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListViolationEventsError::InvalidRequest,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListViolationEventsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListViolationEventsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20274,45 +23952,78 @@ impl RegisterCACertificateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CertificateValidationException" => {
-                    return RusotoError::Service(RegisterCACertificateError::CertificateValidation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterCACertificateError::CertificateValidation,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(RegisterCACertificateError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterCACertificateError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RegisterCACertificateError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterCACertificateError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RegisterCACertificateError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterCACertificateError::LimitExceeded,
+                    )
                 }
                 "RegistrationCodeValidationException" => {
-                    return RusotoError::Service(
-                        RegisterCACertificateError::RegistrationCodeValidation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterCACertificateError::RegistrationCodeValidation,
                     )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(RegisterCACertificateError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterCACertificateError::ResourceAlreadyExists,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(RegisterCACertificateError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterCACertificateError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RegisterCACertificateError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterCACertificateError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(RegisterCACertificateError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterCACertificateError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20366,43 +24077,78 @@ impl RegisterCertificateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CertificateConflictException" => {
-                    return RusotoError::Service(RegisterCertificateError::CertificateConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterCertificateError::CertificateConflict,
+                    )
                 }
                 "CertificateStateException" => {
-                    return RusotoError::Service(RegisterCertificateError::CertificateState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterCertificateError::CertificateState,
+                    )
                 }
                 "CertificateValidationException" => {
-                    return RusotoError::Service(RegisterCertificateError::CertificateValidation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterCertificateError::CertificateValidation,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(RegisterCertificateError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterCertificateError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RegisterCertificateError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterCertificateError::InvalidRequest,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(RegisterCertificateError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterCertificateError::ResourceAlreadyExists,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(RegisterCertificateError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterCertificateError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RegisterCertificateError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterCertificateError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(RegisterCertificateError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterCertificateError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20454,46 +24200,70 @@ impl RegisterCertificateWithoutCAError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CertificateStateException" => {
-                    return RusotoError::Service(
-                        RegisterCertificateWithoutCAError::CertificateState(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterCertificateWithoutCAError::CertificateState,
                     )
                 }
                 "CertificateValidationException" => {
-                    return RusotoError::Service(
-                        RegisterCertificateWithoutCAError::CertificateValidation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterCertificateWithoutCAError::CertificateValidation,
                     )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(
-                        RegisterCertificateWithoutCAError::InternalFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterCertificateWithoutCAError::InternalFailure,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RegisterCertificateWithoutCAError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterCertificateWithoutCAError::InvalidRequest,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        RegisterCertificateWithoutCAError::ResourceAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterCertificateWithoutCAError::ResourceAlreadyExists,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        RegisterCertificateWithoutCAError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterCertificateWithoutCAError::ServiceUnavailable,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RegisterCertificateWithoutCAError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterCertificateWithoutCAError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(RegisterCertificateWithoutCAError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterCertificateWithoutCAError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20548,31 +24318,62 @@ impl RegisterThingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictingResourceUpdateException" => {
-                    return RusotoError::Service(RegisterThingError::ConflictingResourceUpdate(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterThingError::ConflictingResourceUpdate,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(RegisterThingError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterThingError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RegisterThingError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterThingError::InvalidRequest,
+                    )
                 }
                 "ResourceRegistrationFailureException" => {
-                    return RusotoError::Service(RegisterThingError::ResourceRegistrationFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterThingError::ResourceRegistrationFailure,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(RegisterThingError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterThingError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RegisterThingError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterThingError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(RegisterThingError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterThingError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20618,41 +24419,62 @@ impl RejectCertificateTransferError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(RejectCertificateTransferError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RejectCertificateTransferError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RejectCertificateTransferError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RejectCertificateTransferError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RejectCertificateTransferError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RejectCertificateTransferError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        RejectCertificateTransferError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RejectCertificateTransferError::ServiceUnavailable,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RejectCertificateTransferError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RejectCertificateTransferError::Throttling,
+                    )
                 }
                 "TransferAlreadyCompletedException" => {
-                    return RusotoError::Service(
-                        RejectCertificateTransferError::TransferAlreadyCompleted(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RejectCertificateTransferError::TransferAlreadyCompleted,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(RejectCertificateTransferError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RejectCertificateTransferError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20696,26 +24518,38 @@ impl RemoveThingFromBillingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(RemoveThingFromBillingGroupError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveThingFromBillingGroupError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RemoveThingFromBillingGroupError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveThingFromBillingGroupError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        RemoveThingFromBillingGroupError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveThingFromBillingGroupError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RemoveThingFromBillingGroupError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveThingFromBillingGroupError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20752,26 +24586,38 @@ impl RemoveThingFromThingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(RemoveThingFromThingGroupError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveThingFromThingGroupError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RemoveThingFromThingGroupError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveThingFromThingGroupError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RemoveThingFromThingGroupError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveThingFromThingGroupError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RemoveThingFromThingGroupError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveThingFromThingGroupError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20812,26 +24658,54 @@ impl ReplaceTopicRuleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictingResourceUpdateException" => {
-                    return RusotoError::Service(ReplaceTopicRuleError::ConflictingResourceUpdate(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ReplaceTopicRuleError::ConflictingResourceUpdate,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ReplaceTopicRuleError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReplaceTopicRuleError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ReplaceTopicRuleError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReplaceTopicRuleError::InvalidRequest,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ReplaceTopicRuleError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReplaceTopicRuleError::ServiceUnavailable,
+                    )
                 }
                 "SqlParseException" => {
-                    return RusotoError::Service(ReplaceTopicRuleError::SqlParse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReplaceTopicRuleError::SqlParse,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ReplaceTopicRuleError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReplaceTopicRuleError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20878,30 +24752,70 @@ impl SearchIndexError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IndexNotReadyException" => {
-                    return RusotoError::Service(SearchIndexError::IndexNotReady(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchIndexError::IndexNotReady,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(SearchIndexError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchIndexError::InternalFailure,
+                    )
                 }
                 "InvalidQueryException" => {
-                    return RusotoError::Service(SearchIndexError::InvalidQuery(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchIndexError::InvalidQuery,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SearchIndexError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchIndexError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SearchIndexError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchIndexError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(SearchIndexError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchIndexError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SearchIndexError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchIndexError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(SearchIndexError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchIndexError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20948,35 +24862,62 @@ impl SetDefaultAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(SetDefaultAuthorizerError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetDefaultAuthorizerError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SetDefaultAuthorizerError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetDefaultAuthorizerError::InvalidRequest,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(SetDefaultAuthorizerError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetDefaultAuthorizerError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SetDefaultAuthorizerError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetDefaultAuthorizerError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(SetDefaultAuthorizerError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetDefaultAuthorizerError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SetDefaultAuthorizerError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetDefaultAuthorizerError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(SetDefaultAuthorizerError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetDefaultAuthorizerError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -21020,34 +24961,54 @@ impl SetDefaultPolicyVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(SetDefaultPolicyVersionError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetDefaultPolicyVersionError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SetDefaultPolicyVersionError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetDefaultPolicyVersionError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SetDefaultPolicyVersionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetDefaultPolicyVersionError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(SetDefaultPolicyVersionError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetDefaultPolicyVersionError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SetDefaultPolicyVersionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetDefaultPolicyVersionError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(SetDefaultPolicyVersionError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetDefaultPolicyVersionError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -21084,17 +25045,30 @@ impl SetLoggingOptionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(SetLoggingOptionsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetLoggingOptionsError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SetLoggingOptionsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetLoggingOptionsError::InvalidRequest,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(SetLoggingOptionsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetLoggingOptionsError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -21132,23 +25106,46 @@ impl SetV2LoggingLevelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(SetV2LoggingLevelError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetV2LoggingLevelError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SetV2LoggingLevelError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetV2LoggingLevelError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(SetV2LoggingLevelError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetV2LoggingLevelError::LimitExceeded,
+                    )
                 }
                 "NotConfiguredException" => {
-                    return RusotoError::Service(SetV2LoggingLevelError::NotConfigured(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetV2LoggingLevelError::NotConfigured,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(SetV2LoggingLevelError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetV2LoggingLevelError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -21184,17 +25181,30 @@ impl SetV2LoggingOptionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(SetV2LoggingOptionsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetV2LoggingOptionsError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SetV2LoggingOptionsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetV2LoggingOptionsError::InvalidRequest,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(SetV2LoggingOptionsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetV2LoggingOptionsError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -21234,31 +25244,46 @@ impl StartAuditMitigationActionsTaskError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
-                        StartAuditMitigationActionsTaskError::InternalFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartAuditMitigationActionsTaskError::InternalFailure,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        StartAuditMitigationActionsTaskError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartAuditMitigationActionsTaskError::InvalidRequest,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
-                        StartAuditMitigationActionsTaskError::LimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartAuditMitigationActionsTaskError::LimitExceeded,
                     )
                 }
                 "TaskAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        StartAuditMitigationActionsTaskError::TaskAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartAuditMitigationActionsTaskError::TaskAlreadyExists,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartAuditMitigationActionsTaskError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartAuditMitigationActionsTaskError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -21308,31 +25333,46 @@ impl StartDetectMitigationActionsTaskError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
-                        StartDetectMitigationActionsTaskError::InternalFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDetectMitigationActionsTaskError::InternalFailure,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        StartDetectMitigationActionsTaskError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDetectMitigationActionsTaskError::InvalidRequest,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
-                        StartDetectMitigationActionsTaskError::LimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDetectMitigationActionsTaskError::LimitExceeded,
                     )
                 }
                 "TaskAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        StartDetectMitigationActionsTaskError::TaskAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDetectMitigationActionsTaskError::TaskAlreadyExists,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartDetectMitigationActionsTaskError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartDetectMitigationActionsTaskError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -21378,24 +25418,38 @@ impl StartOnDemandAuditTaskError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(StartOnDemandAuditTaskError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartOnDemandAuditTaskError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartOnDemandAuditTaskError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartOnDemandAuditTaskError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartOnDemandAuditTaskError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartOnDemandAuditTaskError::LimitExceeded,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartOnDemandAuditTaskError::Throttling(err.msg))
-                }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartOnDemandAuditTaskError::Throttling,
+                    )
+                }
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -21434,26 +25488,38 @@ impl StartThingRegistrationTaskError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(StartThingRegistrationTaskError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartThingRegistrationTaskError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartThingRegistrationTaskError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartThingRegistrationTaskError::InvalidRequest,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartThingRegistrationTaskError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartThingRegistrationTaskError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(StartThingRegistrationTaskError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartThingRegistrationTaskError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -21492,31 +25558,46 @@ impl StopThingRegistrationTaskError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(StopThingRegistrationTaskError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopThingRegistrationTaskError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopThingRegistrationTaskError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopThingRegistrationTaskError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopThingRegistrationTaskError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopThingRegistrationTaskError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StopThingRegistrationTaskError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopThingRegistrationTaskError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(StopThingRegistrationTaskError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopThingRegistrationTaskError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -21556,21 +25637,46 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(TagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(TagResourceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -21614,29 +25720,62 @@ impl TestAuthorizationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(TestAuthorizationError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestAuthorizationError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TestAuthorizationError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestAuthorizationError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TestAuthorizationError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestAuthorizationError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TestAuthorizationError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestAuthorizationError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(TestAuthorizationError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TestAuthorizationError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(TestAuthorizationError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestAuthorizationError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(TestAuthorizationError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestAuthorizationError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -21682,35 +25821,62 @@ impl TestInvokeAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(TestInvokeAuthorizerError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TestInvokeAuthorizerError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TestInvokeAuthorizerError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestInvokeAuthorizerError::InvalidRequest,
+                    )
                 }
                 "InvalidResponseException" => {
-                    return RusotoError::Service(TestInvokeAuthorizerError::InvalidResponse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TestInvokeAuthorizerError::InvalidResponse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TestInvokeAuthorizerError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TestInvokeAuthorizerError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(TestInvokeAuthorizerError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TestInvokeAuthorizerError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(TestInvokeAuthorizerError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestInvokeAuthorizerError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(TestInvokeAuthorizerError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestInvokeAuthorizerError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -21758,38 +25924,70 @@ impl TransferCertificateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CertificateStateException" => {
-                    return RusotoError::Service(TransferCertificateError::CertificateState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TransferCertificateError::CertificateState,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(TransferCertificateError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TransferCertificateError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TransferCertificateError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TransferCertificateError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TransferCertificateError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TransferCertificateError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(TransferCertificateError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TransferCertificateError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(TransferCertificateError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TransferCertificateError::Throttling,
+                    )
                 }
                 "TransferConflictException" => {
-                    return RusotoError::Service(TransferCertificateError::TransferConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TransferCertificateError::TransferConflict,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(TransferCertificateError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TransferCertificateError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -21830,18 +26028,38 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UntagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UntagResourceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -21878,21 +26096,30 @@ impl UpdateAccountAuditConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
-                        UpdateAccountAuditConfigurationError::InternalFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAccountAuditConfigurationError::InternalFailure,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        UpdateAccountAuditConfigurationError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAccountAuditConfigurationError::InvalidRequest,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateAccountAuditConfigurationError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAccountAuditConfigurationError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -21932,24 +26159,38 @@ impl UpdateAuditSuppressionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateAuditSuppressionError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAuditSuppressionError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateAuditSuppressionError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAuditSuppressionError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateAuditSuppressionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAuditSuppressionError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateAuditSuppressionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAuditSuppressionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -21992,27 +26233,62 @@ impl UpdateAuthorizerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateAuthorizerError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAuthorizerError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateAuthorizerError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAuthorizerError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateAuthorizerError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAuthorizerError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateAuthorizerError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAuthorizerError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateAuthorizerError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAuthorizerError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateAuthorizerError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAuthorizerError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateAuthorizerError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAuthorizerError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -22054,21 +26330,46 @@ impl UpdateBillingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateBillingGroupError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBillingGroupError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateBillingGroupError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBillingGroupError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateBillingGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBillingGroupError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateBillingGroupError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBillingGroupError::Throttling,
+                    )
                 }
                 "VersionConflictException" => {
-                    return RusotoError::Service(UpdateBillingGroupError::VersionConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBillingGroupError::VersionConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -22110,28 +26411,54 @@ impl UpdateCACertificateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateCACertificateError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCACertificateError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateCACertificateError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCACertificateError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateCACertificateError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateCACertificateError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateCACertificateError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateCACertificateError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateCACertificateError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCACertificateError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateCACertificateError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCACertificateError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -22176,29 +26503,62 @@ impl UpdateCertificateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CertificateStateException" => {
-                    return RusotoError::Service(UpdateCertificateError::CertificateState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCertificateError::CertificateState,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateCertificateError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCertificateError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateCertificateError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCertificateError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateCertificateError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCertificateError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateCertificateError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateCertificateError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateCertificateError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCertificateError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateCertificateError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCertificateError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -22238,18 +26598,38 @@ impl UpdateCustomMetricError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateCustomMetricError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCustomMetricError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateCustomMetricError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCustomMetricError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateCustomMetricError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCustomMetricError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateCustomMetricError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCustomMetricError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -22286,18 +26666,38 @@ impl UpdateDimensionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateDimensionError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDimensionError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateDimensionError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDimensionError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDimensionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDimensionError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDimensionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDimensionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -22340,41 +26740,62 @@ impl UpdateDomainConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CertificateValidationException" => {
-                    return RusotoError::Service(
-                        UpdateDomainConfigurationError::CertificateValidation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDomainConfigurationError::CertificateValidation,
                     )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateDomainConfigurationError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDomainConfigurationError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateDomainConfigurationError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDomainConfigurationError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDomainConfigurationError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDomainConfigurationError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        UpdateDomainConfigurationError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDomainConfigurationError::ServiceUnavailable,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDomainConfigurationError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDomainConfigurationError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateDomainConfigurationError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDomainConfigurationError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -22420,34 +26841,54 @@ impl UpdateDynamicThingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateDynamicThingGroupError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDynamicThingGroupError::InternalFailure,
+                    )
                 }
                 "InvalidQueryException" => {
-                    return RusotoError::Service(UpdateDynamicThingGroupError::InvalidQuery(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDynamicThingGroupError::InvalidQuery,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateDynamicThingGroupError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDynamicThingGroupError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDynamicThingGroupError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDynamicThingGroupError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDynamicThingGroupError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDynamicThingGroupError::Throttling,
+                    )
                 }
                 "VersionConflictException" => {
-                    return RusotoError::Service(UpdateDynamicThingGroupError::VersionConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDynamicThingGroupError::VersionConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -22484,21 +26925,30 @@ impl UpdateEventConfigurationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateEventConfigurationsError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateEventConfigurationsError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateEventConfigurationsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateEventConfigurationsError::InvalidRequest,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateEventConfigurationsError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateEventConfigurationsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -22538,31 +26988,46 @@ impl UpdateIndexingConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateIndexingConfigurationError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateIndexingConfigurationError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateIndexingConfigurationError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateIndexingConfigurationError::InvalidRequest,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        UpdateIndexingConfigurationError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIndexingConfigurationError::ServiceUnavailable,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateIndexingConfigurationError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateIndexingConfigurationError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateIndexingConfigurationError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateIndexingConfigurationError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -22602,18 +27067,38 @@ impl UpdateJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateJobError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateJobError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateJobError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateJobError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateJobError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateJobError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateJobError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -22650,24 +27135,38 @@ impl UpdateMitigationActionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateMitigationActionError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateMitigationActionError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateMitigationActionError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateMitigationActionError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateMitigationActionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateMitigationActionError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateMitigationActionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMitigationActionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -22708,31 +27207,46 @@ impl UpdateProvisioningTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictingResourceUpdateException" => {
-                    return RusotoError::Service(
-                        UpdateProvisioningTemplateError::ConflictingResourceUpdate(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateProvisioningTemplateError::ConflictingResourceUpdate,
                     )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateProvisioningTemplateError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateProvisioningTemplateError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateProvisioningTemplateError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateProvisioningTemplateError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateProvisioningTemplateError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateProvisioningTemplateError::ResourceNotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateProvisioningTemplateError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateProvisioningTemplateError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -22776,24 +27290,54 @@ impl UpdateRoleAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateRoleAliasError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRoleAliasError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateRoleAliasError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRoleAliasError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateRoleAliasError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRoleAliasError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateRoleAliasError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRoleAliasError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateRoleAliasError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRoleAliasError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateRoleAliasError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRoleAliasError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -22832,22 +27376,38 @@ impl UpdateScheduledAuditError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateScheduledAuditError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateScheduledAuditError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateScheduledAuditError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateScheduledAuditError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateScheduledAuditError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateScheduledAuditError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateScheduledAuditError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateScheduledAuditError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -22886,29 +27446,46 @@ impl UpdateSecurityProfileError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateSecurityProfileError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSecurityProfileError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateSecurityProfileError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSecurityProfileError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateSecurityProfileError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSecurityProfileError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateSecurityProfileError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSecurityProfileError::Throttling,
+                    )
                 }
                 "VersionConflictException" => {
-                    return RusotoError::Service(UpdateSecurityProfileError::VersionConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSecurityProfileError::VersionConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -22950,24 +27527,54 @@ impl UpdateStreamError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateStreamError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateStreamError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateStreamError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateStreamError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateStreamError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateStreamError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateStreamError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateStreamError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateStreamError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateStreamError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateStreamError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -23012,27 +27619,62 @@ impl UpdateThingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateThingError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThingError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateThingError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThingError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateThingError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThingError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateThingError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThingError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateThingError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThingError::Throttling,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateThingError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThingError::Unauthorized,
+                    )
                 }
                 "VersionConflictException" => {
-                    return RusotoError::Service(UpdateThingError::VersionConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThingError::VersionConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -23074,21 +27716,46 @@ impl UpdateThingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateThingGroupError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThingGroupError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateThingGroupError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThingGroupError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateThingGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThingGroupError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateThingGroupError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThingGroupError::Throttling,
+                    )
                 }
                 "VersionConflictException" => {
-                    return RusotoError::Service(UpdateThingGroupError::VersionConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThingGroupError::VersionConflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -23126,26 +27793,38 @@ impl UpdateThingGroupsForThingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateThingGroupsForThingError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateThingGroupsForThingError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateThingGroupsForThingError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateThingGroupsForThingError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateThingGroupsForThingError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateThingGroupsForThingError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateThingGroupsForThingError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateThingGroupsForThingError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -23186,29 +27865,46 @@ impl UpdateTopicRuleDestinationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictingResourceUpdateException" => {
-                    return RusotoError::Service(
-                        UpdateTopicRuleDestinationError::ConflictingResourceUpdate(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTopicRuleDestinationError::ConflictingResourceUpdate,
                     )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(UpdateTopicRuleDestinationError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTopicRuleDestinationError::Internal,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateTopicRuleDestinationError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateTopicRuleDestinationError::InvalidRequest,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        UpdateTopicRuleDestinationError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTopicRuleDestinationError::ServiceUnavailable,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateTopicRuleDestinationError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateTopicRuleDestinationError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -23250,21 +27946,30 @@ impl ValidateSecurityProfileBehaviorsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
-                        ValidateSecurityProfileBehaviorsError::InternalFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ValidateSecurityProfileBehaviorsError::InternalFailure,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        ValidateSecurityProfileBehaviorsError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ValidateSecurityProfileBehaviorsError::InvalidRequest,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ValidateSecurityProfileBehaviorsError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ValidateSecurityProfileBehaviorsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -24691,11 +29396,12 @@ pub struct IotClient {
 impl IotClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> IotClient {
         IotClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "IoT"),
         }
     }
 
@@ -24710,17 +29416,37 @@ impl IotClient {
     {
         IotClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "IoT"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> IotClient {
-        IotClient { client, region }
+        IotClient {
+            client,
+            region: region_for_service(region, "IoT"),
+        }
     }
-}
 
-#[async_trait]
-impl Iot for IotClient {
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> IotClient {
+        IotClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("IoT"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> IotClient {
+        IotClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
+    }
+}
+
+#[async_trait]
+impl Iot for IotClient {
     /// <p>Accepts a pending certificate transfer. The default state of the certificate is INACTIVE.</p> <p>To check for pending certificate transfers, call <a>ListCertificates</a> to enumerate your certificates.</p>
     #[allow(unused_mut)]
     async fn accept_certificate_transfer(
@@ -24733,6 +29459,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation("AcceptCertificateTransfer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24768,6 +29495,7 @@ impl Iot for IotClient {
         let request_uri = "/billing-groups/addThingToBillingGroup";
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("AddThingToBillingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24800,6 +29528,7 @@ impl Iot for IotClient {
         let request_uri = "/thing-groups/addThingToThingGroup";
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("AddThingToThingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24832,6 +29561,7 @@ impl Iot for IotClient {
         let request_uri = format!("/jobs/{job_id}/targets", job_id = input.job_id);
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("AssociateTargetsWithJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24873,6 +29603,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("AttachPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24907,6 +29638,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("AttachPrincipalPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24941,6 +29673,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("AttachSecurityProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -24981,6 +29714,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("AttachThingPrincipal");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25019,6 +29753,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("CancelAuditMitigationActionsTask");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25051,6 +29786,7 @@ impl Iot for IotClient {
         let request_uri = format!("/audit/tasks/{task_id}/cancel", task_id = input.task_id);
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("CancelAuditTask");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25084,6 +29820,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation("CancelCertificateTransfer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25119,6 +29856,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("CancelDetectMitigationActionsTask");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25151,6 +29889,7 @@ impl Iot for IotClient {
         let request_uri = format!("/jobs/{job_id}/cancel", job_id = input.job_id);
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("CancelJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25193,6 +29932,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("CancelJobExecution");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25229,6 +29969,7 @@ impl Iot for IotClient {
         let request_uri = "/default-authorizer";
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("ClearDefaultAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25263,6 +30004,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ConfirmTopicRuleDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25293,6 +30035,7 @@ impl Iot for IotClient {
         let request_uri = "/audit/suppressions/create";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateAuditSuppression");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25328,6 +30071,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25363,6 +30107,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateBillingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25395,6 +30140,7 @@ impl Iot for IotClient {
         let request_uri = "/certificates";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateCertificateFromCsr");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25436,6 +30182,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateCustomMetric");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25468,6 +30215,7 @@ impl Iot for IotClient {
         let request_uri = format!("/dimensions/{name}", name = input.name);
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateDimension");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25504,6 +30252,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateDomainConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25539,6 +30288,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateDynamicThingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25571,6 +30321,7 @@ impl Iot for IotClient {
         let request_uri = format!("/jobs/{job_id}", job_id = input.job_id);
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25603,6 +30354,7 @@ impl Iot for IotClient {
         let request_uri = "/keys-and-certificate";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateKeysAndCertificate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25642,6 +30394,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateMitigationAction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25677,6 +30430,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateOTAUpdate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25709,6 +30463,7 @@ impl Iot for IotClient {
         let request_uri = format!("/policies/{policy_name}", policy_name = input.policy_name);
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreatePolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25744,6 +30499,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreatePolicyVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25785,6 +30541,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateProvisioningClaim");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25816,6 +30573,7 @@ impl Iot for IotClient {
         let request_uri = "/provisioning-templates";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateProvisioningTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25854,6 +30612,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateProvisioningTemplateVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25894,6 +30653,7 @@ impl Iot for IotClient {
         let request_uri = format!("/role-aliases/{role_alias}", role_alias = input.role_alias);
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateRoleAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25929,6 +30689,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateScheduledAudit");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25964,6 +30725,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateSecurityProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -25996,6 +30758,7 @@ impl Iot for IotClient {
         let request_uri = format!("/streams/{stream_id}", stream_id = input.stream_id);
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateStream");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26028,6 +30791,7 @@ impl Iot for IotClient {
         let request_uri = format!("/things/{thing_name}", thing_name = input.thing_name);
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateThing");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26063,6 +30827,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateThingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26098,6 +30863,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateThingType");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26130,6 +30896,7 @@ impl Iot for IotClient {
         let request_uri = format!("/rules/{rule_name}", rule_name = input.rule_name);
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateTopicRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26163,6 +30930,7 @@ impl Iot for IotClient {
         let request_uri = "/destinations";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("CreateTopicRuleDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26198,6 +30966,7 @@ impl Iot for IotClient {
         let request_uri = "/audit/configuration";
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteAccountAuditConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26236,6 +31005,7 @@ impl Iot for IotClient {
         let request_uri = "/audit/suppressions/delete";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteAuditSuppression");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26271,6 +31041,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26304,6 +31075,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteBillingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26343,6 +31115,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteCACertificate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26376,6 +31149,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteCertificate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26414,6 +31188,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteCustomMetric");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26444,6 +31219,7 @@ impl Iot for IotClient {
         let request_uri = format!("/dimensions/{name}", name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteDimension");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26478,6 +31254,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteDomainConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26511,6 +31288,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteDynamicThingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26544,6 +31322,7 @@ impl Iot for IotClient {
         let request_uri = format!("/jobs/{job_id}", job_id = input.job_id);
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26587,6 +31366,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteJobExecution");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26628,6 +31408,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteMitigationAction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26661,6 +31442,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteOTAUpdate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26700,6 +31482,7 @@ impl Iot for IotClient {
         let request_uri = format!("/policies/{policy_name}", policy_name = input.policy_name);
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeletePolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26733,6 +31516,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeletePolicyVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26766,6 +31550,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteProvisioningTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26803,6 +31588,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteProvisioningTemplateVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26834,6 +31620,7 @@ impl Iot for IotClient {
         let request_uri = "/registrationcode";
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteRegistrationCode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26864,6 +31651,7 @@ impl Iot for IotClient {
         let request_uri = format!("/role-aliases/{role_alias}", role_alias = input.role_alias);
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteRoleAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26897,6 +31685,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteScheduledAudit");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26930,6 +31719,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteSecurityProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26966,6 +31756,7 @@ impl Iot for IotClient {
         let request_uri = format!("/streams/{stream_id}", stream_id = input.stream_id);
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteStream");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -26996,6 +31787,7 @@ impl Iot for IotClient {
         let request_uri = format!("/things/{thing_name}", thing_name = input.thing_name);
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteThing");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27035,6 +31827,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteThingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27074,6 +31867,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteThingType");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27104,6 +31898,7 @@ impl Iot for IotClient {
         let request_uri = format!("/rules/{rule_name}", rule_name = input.rule_name);
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteTopicRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27134,6 +31929,7 @@ impl Iot for IotClient {
         let request_uri = format!("/destinations/{arn}", arn = input.arn);
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteTopicRuleDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27164,6 +31960,7 @@ impl Iot for IotClient {
         let request_uri = "/v2LoggingLevel";
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeleteV2LoggingLevel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27201,6 +31998,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("DeprecateThingType");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27235,6 +32033,7 @@ impl Iot for IotClient {
         let request_uri = "/audit/configuration";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeAccountAuditConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27270,6 +32069,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeAuditFinding");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27306,6 +32106,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeAuditMitigationActionsTask");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27338,6 +32139,7 @@ impl Iot for IotClient {
         let request_uri = "/audit/suppressions/describe";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeAuditSuppression");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27370,6 +32172,7 @@ impl Iot for IotClient {
         let request_uri = format!("/audit/tasks/{task_id}", task_id = input.task_id);
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeAuditTask");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27403,6 +32206,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27436,6 +32240,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeBillingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27469,6 +32274,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeCACertificate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27502,6 +32308,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeCertificate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27535,6 +32342,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeCustomMetric");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27565,6 +32373,7 @@ impl Iot for IotClient {
         let request_uri = "/default-authorizer";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeDefaultAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27601,6 +32410,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeDetectMitigationActionsTask");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27633,6 +32443,7 @@ impl Iot for IotClient {
         let request_uri = format!("/dimensions/{name}", name = input.name);
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeDimension");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27667,6 +32478,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeDomainConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27697,6 +32509,7 @@ impl Iot for IotClient {
         let request_uri = "/endpoint";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeEndpoint");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27733,6 +32546,7 @@ impl Iot for IotClient {
         let request_uri = "/event-configurations";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeEventConfigurations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27763,6 +32577,7 @@ impl Iot for IotClient {
         let request_uri = format!("/indices/{index_name}", index_name = input.index_name);
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeIndex");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27793,6 +32608,7 @@ impl Iot for IotClient {
         let request_uri = format!("/jobs/{job_id}", job_id = input.job_id);
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27827,6 +32643,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeJobExecution");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27866,6 +32683,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeMitigationAction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27900,6 +32718,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeProvisioningTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27937,6 +32756,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeProvisioningTemplateVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -27969,6 +32789,7 @@ impl Iot for IotClient {
         let request_uri = format!("/role-aliases/{role_alias}", role_alias = input.role_alias);
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeRoleAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28002,6 +32823,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeScheduledAudit");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28035,6 +32857,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeSecurityProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28065,6 +32888,7 @@ impl Iot for IotClient {
         let request_uri = format!("/streams/{stream_id}", stream_id = input.stream_id);
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeStream");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28095,6 +32919,7 @@ impl Iot for IotClient {
         let request_uri = format!("/things/{thing_name}", thing_name = input.thing_name);
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeThing");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28128,6 +32953,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeThingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28164,6 +32990,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeThingRegistrationTask");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28197,6 +33024,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("DescribeThingType");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28230,6 +33058,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("DetachPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28264,6 +33093,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DetachPrincipalPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28298,6 +33128,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DetachSecurityProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28338,6 +33169,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "execute-api", &self.region, &request_uri);
+        request.set_operation("DetachThingPrincipal");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28370,6 +33202,7 @@ impl Iot for IotClient {
         let request_uri = format!("/rules/{rule_name}/disable", rule_name = input.rule_name);
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("DisableTopicRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28399,6 +33232,7 @@ impl Iot for IotClient {
         let request_uri = format!("/rules/{rule_name}/enable", rule_name = input.rule_name);
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("EnableTopicRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28431,6 +33265,7 @@ impl Iot for IotClient {
         let request_uri = "/behavior-model-training/summaries";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("GetBehaviorModelTrainingSummaries");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28475,6 +33310,7 @@ impl Iot for IotClient {
         let request_uri = "/indices/cardinality";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("GetCardinality");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28507,6 +33343,7 @@ impl Iot for IotClient {
         let request_uri = "/effective-policies";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("GetEffectivePolicies");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28544,6 +33381,7 @@ impl Iot for IotClient {
         let request_uri = "/indexing/config";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("GetIndexingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28574,6 +33412,7 @@ impl Iot for IotClient {
         let request_uri = format!("/jobs/{job_id}/job-document", job_id = input.job_id);
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("GetJobDocument");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28603,6 +33442,7 @@ impl Iot for IotClient {
         let request_uri = "/loggingOptions";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("GetLoggingOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28636,6 +33476,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("GetOTAUpdate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28666,6 +33507,7 @@ impl Iot for IotClient {
         let request_uri = "/indices/percentiles";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("GetPercentiles");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28698,6 +33540,7 @@ impl Iot for IotClient {
         let request_uri = format!("/policies/{policy_name}", policy_name = input.policy_name);
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("GetPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28732,6 +33575,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("GetPolicyVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28761,6 +33605,7 @@ impl Iot for IotClient {
         let request_uri = "/registrationcode";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("GetRegistrationCode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28791,6 +33636,7 @@ impl Iot for IotClient {
         let request_uri = "/indices/statistics";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("GetStatistics");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28823,6 +33669,7 @@ impl Iot for IotClient {
         let request_uri = format!("/rules/{rule_name}", rule_name = input.rule_name);
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("GetTopicRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28853,6 +33700,7 @@ impl Iot for IotClient {
         let request_uri = format!("/destinations/{arn}", arn = input.arn);
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("GetTopicRuleDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28882,6 +33730,7 @@ impl Iot for IotClient {
         let request_uri = "/v2LoggingOptions";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("GetV2LoggingOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28912,6 +33761,7 @@ impl Iot for IotClient {
         let request_uri = "/active-violations";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListActiveViolations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -28963,6 +33813,7 @@ impl Iot for IotClient {
         let request_uri = format!("/attached-policies/{target}", target = input.target);
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListAttachedPolicies");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29005,6 +33856,7 @@ impl Iot for IotClient {
         let request_uri = "/audit/findings";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListAuditFindings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29040,6 +33892,7 @@ impl Iot for IotClient {
         let request_uri = "/audit/mitigationactions/executions";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListAuditMitigationActionsExecutions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29089,6 +33942,7 @@ impl Iot for IotClient {
         let request_uri = "/audit/mitigationactions/tasks";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListAuditMitigationActionsTasks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29141,6 +33995,7 @@ impl Iot for IotClient {
         let request_uri = "/audit/suppressions/list";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListAuditSuppressions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29173,6 +34028,7 @@ impl Iot for IotClient {
         let request_uri = "/audit/tasks";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListAuditTasks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29220,6 +34076,7 @@ impl Iot for IotClient {
         let request_uri = "/authorizers/";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListAuthorizers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29265,6 +34122,7 @@ impl Iot for IotClient {
         let request_uri = "/billing-groups";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListBillingGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29307,6 +34165,7 @@ impl Iot for IotClient {
         let request_uri = "/cacertificates";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListCACertificates");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29349,6 +34208,7 @@ impl Iot for IotClient {
         let request_uri = "/certificates";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListCertificates");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29394,6 +34254,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListCertificatesByCA");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29436,6 +34297,7 @@ impl Iot for IotClient {
         let request_uri = "/custom-metrics";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListCustomMetrics");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29478,6 +34340,7 @@ impl Iot for IotClient {
         let request_uri = "/detect/mitigationactions/executions";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListDetectMitigationActionsExecutions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29537,6 +34400,7 @@ impl Iot for IotClient {
         let request_uri = "/detect/mitigationactions/tasks";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListDetectMitigationActionsTasks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29580,6 +34444,7 @@ impl Iot for IotClient {
         let request_uri = "/dimensions";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListDimensions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29619,6 +34484,7 @@ impl Iot for IotClient {
         let request_uri = "/domainConfigurations";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListDomainConfigurations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29661,6 +34527,7 @@ impl Iot for IotClient {
         let request_uri = "/indices";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListIndices");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29700,6 +34567,7 @@ impl Iot for IotClient {
         let request_uri = format!("/jobs/{job_id}/things", job_id = input.job_id);
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListJobExecutionsForJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29743,6 +34611,7 @@ impl Iot for IotClient {
         let request_uri = format!("/things/{thing_name}/jobs", thing_name = input.thing_name);
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListJobExecutionsForThing");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29788,6 +34657,7 @@ impl Iot for IotClient {
         let request_uri = "/jobs";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29842,6 +34712,7 @@ impl Iot for IotClient {
         let request_uri = "/mitigationactions/actions";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListMitigationActions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29884,6 +34755,7 @@ impl Iot for IotClient {
         let request_uri = "/otaUpdates";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListOTAUpdates");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29926,6 +34798,7 @@ impl Iot for IotClient {
         let request_uri = "/certificates-out-going";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListOutgoingCertificates");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -29968,6 +34841,7 @@ impl Iot for IotClient {
         let request_uri = "/policies";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListPolicies");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -30010,6 +34884,7 @@ impl Iot for IotClient {
         let request_uri = "/policy-principals";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListPolicyPrincipals");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -30056,6 +34931,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListPolicyVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -30086,6 +34962,7 @@ impl Iot for IotClient {
         let request_uri = "/principal-policies";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListPrincipalPolicies");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -30129,6 +35006,7 @@ impl Iot for IotClient {
         let request_uri = "/principals/things";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListPrincipalThings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -30175,6 +35053,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListProvisioningTemplateVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -30217,6 +35096,7 @@ impl Iot for IotClient {
         let request_uri = "/provisioning-templates";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListProvisioningTemplates");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -30256,6 +35136,7 @@ impl Iot for IotClient {
         let request_uri = "/role-aliases";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListRoleAliases");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -30298,6 +35179,7 @@ impl Iot for IotClient {
         let request_uri = "/audit/scheduledaudits";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListScheduledAudits");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -30337,6 +35219,7 @@ impl Iot for IotClient {
         let request_uri = "/security-profiles";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListSecurityProfiles");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -30385,6 +35268,7 @@ impl Iot for IotClient {
         let request_uri = "/security-profiles-for-target";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListSecurityProfilesForTarget");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -30431,6 +35315,7 @@ impl Iot for IotClient {
         let request_uri = "/streams";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListStreams");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -30473,6 +35358,7 @@ impl Iot for IotClient {
         let request_uri = "/tags";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -30513,6 +35399,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListTargetsForPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -30558,6 +35445,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListTargetsForSecurityProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -30597,6 +35485,7 @@ impl Iot for IotClient {
         let request_uri = "/thing-groups";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListThingGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -30648,6 +35537,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListThingGroupsForThing");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -30690,6 +35580,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListThingPrincipals");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -30735,6 +35626,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListThingRegistrationTaskReports");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -30778,6 +35670,7 @@ impl Iot for IotClient {
         let request_uri = "/thing-registration-tasks";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListThingRegistrationTasks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -30820,6 +35713,7 @@ impl Iot for IotClient {
         let request_uri = "/thing-types";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListThingTypes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -30862,6 +35756,7 @@ impl Iot for IotClient {
         let request_uri = "/things";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListThings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -30913,6 +35808,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListThingsInBillingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -30955,6 +35851,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListThingsInThingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -30998,6 +35895,7 @@ impl Iot for IotClient {
         let request_uri = "/destinations";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListTopicRuleDestinations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -31037,6 +35935,7 @@ impl Iot for IotClient {
         let request_uri = "/rules";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListTopicRules");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -31082,6 +35981,7 @@ impl Iot for IotClient {
         let request_uri = "/v2LoggingLevel";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListV2LoggingLevels");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -31124,6 +36024,7 @@ impl Iot for IotClient {
         let request_uri = "/violation-events";
 
         let mut request = SignedRequest::new("GET", "execute-api", &self.region, &request_uri);
+        request.set_operation("ListViolationEvents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -31177,6 +36078,7 @@ impl Iot for IotClient {
         let request_uri = "/cacertificate";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("RegisterCACertificate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -31218,6 +36120,7 @@ impl Iot for IotClient {
         let request_uri = "/certificate/register";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("RegisterCertificate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -31251,6 +36154,7 @@ impl Iot for IotClient {
         let request_uri = "/certificate/register-no-ca";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("RegisterCertificateWithoutCA");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -31283,6 +36187,7 @@ impl Iot for IotClient {
         let request_uri = "/things";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("RegisterThing");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -31318,6 +36223,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation("RejectCertificateTransfer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -31350,6 +36256,7 @@ impl Iot for IotClient {
         let request_uri = "/billing-groups/removeThingFromBillingGroup";
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("RemoveThingFromBillingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -31383,6 +36290,7 @@ impl Iot for IotClient {
         let request_uri = "/thing-groups/removeThingFromThingGroup";
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("RemoveThingFromThingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -31415,6 +36323,7 @@ impl Iot for IotClient {
         let request_uri = format!("/rules/{rule_name}", rule_name = input.rule_name);
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation("ReplaceTopicRule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -31446,6 +36355,7 @@ impl Iot for IotClient {
         let request_uri = "/indices/search";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("SearchIndex");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -31478,6 +36388,7 @@ impl Iot for IotClient {
         let request_uri = "/default-authorizer";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("SetDefaultAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -31514,6 +36425,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation("SetDefaultPolicyVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -31543,6 +36455,7 @@ impl Iot for IotClient {
         let request_uri = "/loggingOptions";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("SetLoggingOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -31574,6 +36487,7 @@ impl Iot for IotClient {
         let request_uri = "/v2LoggingLevel";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("SetV2LoggingLevel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -31605,6 +36519,7 @@ impl Iot for IotClient {
         let request_uri = "/v2LoggingOptions";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("SetV2LoggingOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -31642,6 +36557,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("StartAuditMitigationActionsTask");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -31682,6 +36598,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("StartDetectMitigationActionsTask");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -31716,6 +36633,7 @@ impl Iot for IotClient {
         let request_uri = "/audit/tasks";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("StartOnDemandAuditTask");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -31749,6 +36667,7 @@ impl Iot for IotClient {
         let request_uri = "/thing-registration-tasks";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("StartThingRegistrationTask");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -31785,6 +36704,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("StopThingRegistrationTask");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -31815,6 +36735,7 @@ impl Iot for IotClient {
         let request_uri = "/tags";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -31847,6 +36768,7 @@ impl Iot for IotClient {
         let request_uri = "/test-authorization";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("TestAuthorization");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -31888,6 +36810,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("TestInvokeAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -31923,6 +36846,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation("TransferCertificate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -31959,6 +36883,7 @@ impl Iot for IotClient {
         let request_uri = "/untag";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -31994,6 +36919,7 @@ impl Iot for IotClient {
         let request_uri = "/audit/configuration";
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateAccountAuditConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -32028,6 +36954,7 @@ impl Iot for IotClient {
         let request_uri = "/audit/suppressions/update";
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateAuditSuppression");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -32063,6 +36990,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateAuthorizer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -32098,6 +37026,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateBillingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -32133,6 +37062,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateCACertificate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -32176,6 +37106,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateCertificate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -32212,6 +37143,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateCustomMetric");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -32244,6 +37176,7 @@ impl Iot for IotClient {
         let request_uri = format!("/dimensions/{name}", name = input.name);
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateDimension");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -32280,6 +37213,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateDomainConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -32315,6 +37249,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateDynamicThingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -32348,6 +37283,7 @@ impl Iot for IotClient {
         let request_uri = "/event-configurations";
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateEventConfigurations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -32381,6 +37317,7 @@ impl Iot for IotClient {
         let request_uri = "/indexing/config";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateIndexingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -32410,6 +37347,7 @@ impl Iot for IotClient {
         let request_uri = format!("/jobs/{job_id}", job_id = input.job_id);
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -32450,6 +37388,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateMitigationAction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -32486,6 +37425,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateProvisioningTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -32518,6 +37458,7 @@ impl Iot for IotClient {
         let request_uri = format!("/role-aliases/{role_alias}", role_alias = input.role_alias);
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateRoleAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -32553,6 +37494,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateScheduledAudit");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -32588,6 +37530,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateSecurityProfile");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -32626,6 +37569,7 @@ impl Iot for IotClient {
         let request_uri = format!("/streams/{stream_id}", stream_id = input.stream_id);
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateStream");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -32658,6 +37602,7 @@ impl Iot for IotClient {
         let request_uri = format!("/things/{thing_name}", thing_name = input.thing_name);
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateThing");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -32693,6 +37638,7 @@ impl Iot for IotClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateThingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -32726,6 +37672,7 @@ impl Iot for IotClient {
         let request_uri = "/thing-groups/updateThingGroupsForThing";
 
         let mut request = SignedRequest::new("PUT", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateThingGroupsForThing");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -32759,6 +37706,7 @@ impl Iot for IotClient {
         let request_uri = "/destinations";
 
         let mut request = SignedRequest::new("PATCH", "execute-api", &self.region, &request_uri);
+        request.set_operation("UpdateTopicRuleDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -32794,6 +37742,7 @@ impl Iot for IotClient {
         let request_uri = "/security-profile-behaviors/validate";
 
         let mut request = SignedRequest::new("POST", "execute-api", &self.region, &request_uri);
+        request.set_operation("ValidateSecurityProfileBehaviors");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("iot".to_string());
@@ -32819,3 +37768,2091 @@ impl Iot for IotClient {
         }
     }
 }
+
+/// A blocking client for the AWS IoT API, running the calls of a `IotClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `IotClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct IotBlockingClient {
+    client: IotClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl IotBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> IotBlockingClient {
+        IotBlockingClient::from_client(IotClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> IotBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        IotBlockingClient::from_client(IotClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> IotBlockingClient {
+        IotBlockingClient::from_client(IotClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> IotBlockingClient {
+        IotBlockingClient::from_client(IotClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: IotClient) -> IotBlockingClient {
+        IotBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> IotBlockingClient {
+        IotBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> IotBlockingClient {
+        IotBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Iot::accept_certificate_transfer`].
+    pub fn accept_certificate_transfer(
+        &self,
+        input: AcceptCertificateTransferRequest,
+    ) -> Result<(), RusotoError<AcceptCertificateTransferError>> {
+        self.runtime
+            .block_on(self.client.accept_certificate_transfer(input))
+    }
+
+    /// Blocking version of [`Iot::add_thing_to_billing_group`].
+    pub fn add_thing_to_billing_group(
+        &self,
+        input: AddThingToBillingGroupRequest,
+    ) -> Result<AddThingToBillingGroupResponse, RusotoError<AddThingToBillingGroupError>> {
+        self.runtime
+            .block_on(self.client.add_thing_to_billing_group(input))
+    }
+
+    /// Blocking version of [`Iot::add_thing_to_thing_group`].
+    pub fn add_thing_to_thing_group(
+        &self,
+        input: AddThingToThingGroupRequest,
+    ) -> Result<AddThingToThingGroupResponse, RusotoError<AddThingToThingGroupError>> {
+        self.runtime
+            .block_on(self.client.add_thing_to_thing_group(input))
+    }
+
+    /// Blocking version of [`Iot::associate_targets_with_job`].
+    pub fn associate_targets_with_job(
+        &self,
+        input: AssociateTargetsWithJobRequest,
+    ) -> Result<AssociateTargetsWithJobResponse, RusotoError<AssociateTargetsWithJobError>> {
+        self.runtime
+            .block_on(self.client.associate_targets_with_job(input))
+    }
+
+    /// Blocking version of [`Iot::attach_policy`].
+    pub fn attach_policy(
+        &self,
+        input: AttachPolicyRequest,
+    ) -> Result<(), RusotoError<AttachPolicyError>> {
+        self.runtime.block_on(self.client.attach_policy(input))
+    }
+
+    /// Blocking version of [`Iot::attach_principal_policy`].
+    pub fn attach_principal_policy(
+        &self,
+        input: AttachPrincipalPolicyRequest,
+    ) -> Result<(), RusotoError<AttachPrincipalPolicyError>> {
+        self.runtime
+            .block_on(self.client.attach_principal_policy(input))
+    }
+
+    /// Blocking version of [`Iot::attach_security_profile`].
+    pub fn attach_security_profile(
+        &self,
+        input: AttachSecurityProfileRequest,
+    ) -> Result<AttachSecurityProfileResponse, RusotoError<AttachSecurityProfileError>> {
+        self.runtime
+            .block_on(self.client.attach_security_profile(input))
+    }
+
+    /// Blocking version of [`Iot::attach_thing_principal`].
+    pub fn attach_thing_principal(
+        &self,
+        input: AttachThingPrincipalRequest,
+    ) -> Result<AttachThingPrincipalResponse, RusotoError<AttachThingPrincipalError>> {
+        self.runtime
+            .block_on(self.client.attach_thing_principal(input))
+    }
+
+    /// Blocking version of [`Iot::cancel_audit_mitigation_actions_task`].
+    pub fn cancel_audit_mitigation_actions_task(
+        &self,
+        input: CancelAuditMitigationActionsTaskRequest,
+    ) -> Result<
+        CancelAuditMitigationActionsTaskResponse,
+        RusotoError<CancelAuditMitigationActionsTaskError>,
+    > {
+        self.runtime
+            .block_on(self.client.cancel_audit_mitigation_actions_task(input))
+    }
+
+    /// Blocking version of [`Iot::cancel_audit_task`].
+    pub fn cancel_audit_task(
+        &self,
+        input: CancelAuditTaskRequest,
+    ) -> Result<CancelAuditTaskResponse, RusotoError<CancelAuditTaskError>> {
+        self.runtime.block_on(self.client.cancel_audit_task(input))
+    }
+
+    /// Blocking version of [`Iot::cancel_certificate_transfer`].
+    pub fn cancel_certificate_transfer(
+        &self,
+        input: CancelCertificateTransferRequest,
+    ) -> Result<(), RusotoError<CancelCertificateTransferError>> {
+        self.runtime
+            .block_on(self.client.cancel_certificate_transfer(input))
+    }
+
+    /// Blocking version of [`Iot::cancel_detect_mitigation_actions_task`].
+    pub fn cancel_detect_mitigation_actions_task(
+        &self,
+        input: CancelDetectMitigationActionsTaskRequest,
+    ) -> Result<
+        CancelDetectMitigationActionsTaskResponse,
+        RusotoError<CancelDetectMitigationActionsTaskError>,
+    > {
+        self.runtime
+            .block_on(self.client.cancel_detect_mitigation_actions_task(input))
+    }
+
+    /// Blocking version of [`Iot::cancel_job`].
+    pub fn cancel_job(
+        &self,
+        input: CancelJobRequest,
+    ) -> Result<CancelJobResponse, RusotoError<CancelJobError>> {
+        self.runtime.block_on(self.client.cancel_job(input))
+    }
+
+    /// Blocking version of [`Iot::cancel_job_execution`].
+    pub fn cancel_job_execution(
+        &self,
+        input: CancelJobExecutionRequest,
+    ) -> Result<(), RusotoError<CancelJobExecutionError>> {
+        self.runtime
+            .block_on(self.client.cancel_job_execution(input))
+    }
+
+    /// Blocking version of [`Iot::clear_default_authorizer`].
+    pub fn clear_default_authorizer(
+        &self,
+    ) -> Result<ClearDefaultAuthorizerResponse, RusotoError<ClearDefaultAuthorizerError>> {
+        self.runtime
+            .block_on(self.client.clear_default_authorizer())
+    }
+
+    /// Blocking version of [`Iot::confirm_topic_rule_destination`].
+    pub fn confirm_topic_rule_destination(
+        &self,
+        input: ConfirmTopicRuleDestinationRequest,
+    ) -> Result<ConfirmTopicRuleDestinationResponse, RusotoError<ConfirmTopicRuleDestinationError>>
+    {
+        self.runtime
+            .block_on(self.client.confirm_topic_rule_destination(input))
+    }
+
+    /// Blocking version of [`Iot::create_audit_suppression`].
+    pub fn create_audit_suppression(
+        &self,
+        input: CreateAuditSuppressionRequest,
+    ) -> Result<CreateAuditSuppressionResponse, RusotoError<CreateAuditSuppressionError>> {
+        self.runtime
+            .block_on(self.client.create_audit_suppression(input))
+    }
+
+    /// Blocking version of [`Iot::create_authorizer`].
+    pub fn create_authorizer(
+        &self,
+        input: CreateAuthorizerRequest,
+    ) -> Result<CreateAuthorizerResponse, RusotoError<CreateAuthorizerError>> {
+        self.runtime.block_on(self.client.create_authorizer(input))
+    }
+
+    /// Blocking version of [`Iot::create_billing_group`].
+    pub fn create_billing_group(
+        &self,
+        input: CreateBillingGroupRequest,
+    ) -> Result<CreateBillingGroupResponse, RusotoError<CreateBillingGroupError>> {
+        self.runtime
+            .block_on(self.client.create_billing_group(input))
+    }
+
+    /// Blocking version of [`Iot::create_certificate_from_csr`].
+    pub fn create_certificate_from_csr(
+        &self,
+        input: CreateCertificateFromCsrRequest,
+    ) -> Result<CreateCertificateFromCsrResponse, RusotoError<CreateCertificateFromCsrError>> {
+        self.runtime
+            .block_on(self.client.create_certificate_from_csr(input))
+    }
+
+    /// Blocking version of [`Iot::create_custom_metric`].
+    pub fn create_custom_metric(
+        &self,
+        input: CreateCustomMetricRequest,
+    ) -> Result<CreateCustomMetricResponse, RusotoError<CreateCustomMetricError>> {
+        self.runtime
+            .block_on(self.client.create_custom_metric(input))
+    }
+
+    /// Blocking version of [`Iot::create_dimension`].
+    pub fn create_dimension(
+        &self,
+        input: CreateDimensionRequest,
+    ) -> Result<CreateDimensionResponse, RusotoError<CreateDimensionError>> {
+        self.runtime.block_on(self.client.create_dimension(input))
+    }
+
+    /// Blocking version of [`Iot::create_domain_configuration`].
+    pub fn create_domain_configuration(
+        &self,
+        input: CreateDomainConfigurationRequest,
+    ) -> Result<CreateDomainConfigurationResponse, RusotoError<CreateDomainConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.create_domain_configuration(input))
+    }
+
+    /// Blocking version of [`Iot::create_dynamic_thing_group`].
+    pub fn create_dynamic_thing_group(
+        &self,
+        input: CreateDynamicThingGroupRequest,
+    ) -> Result<CreateDynamicThingGroupResponse, RusotoError<CreateDynamicThingGroupError>> {
+        self.runtime
+            .block_on(self.client.create_dynamic_thing_group(input))
+    }
+
+    /// Blocking version of [`Iot::create_job`].
+    pub fn create_job(
+        &self,
+        input: CreateJobRequest,
+    ) -> Result<CreateJobResponse, RusotoError<CreateJobError>> {
+        self.runtime.block_on(self.client.create_job(input))
+    }
+
+    /// Blocking version of [`Iot::create_keys_and_certificate`].
+    pub fn create_keys_and_certificate(
+        &self,
+        input: CreateKeysAndCertificateRequest,
+    ) -> Result<CreateKeysAndCertificateResponse, RusotoError<CreateKeysAndCertificateError>> {
+        self.runtime
+            .block_on(self.client.create_keys_and_certificate(input))
+    }
+
+    /// Blocking version of [`Iot::create_mitigation_action`].
+    pub fn create_mitigation_action(
+        &self,
+        input: CreateMitigationActionRequest,
+    ) -> Result<CreateMitigationActionResponse, RusotoError<CreateMitigationActionError>> {
+        self.runtime
+            .block_on(self.client.create_mitigation_action(input))
+    }
+
+    /// Blocking version of [`Iot::create_ota_update`].
+    pub fn create_ota_update(
+        &self,
+        input: CreateOTAUpdateRequest,
+    ) -> Result<CreateOTAUpdateResponse, RusotoError<CreateOTAUpdateError>> {
+        self.runtime.block_on(self.client.create_ota_update(input))
+    }
+
+    /// Blocking version of [`Iot::create_policy`].
+    pub fn create_policy(
+        &self,
+        input: CreatePolicyRequest,
+    ) -> Result<CreatePolicyResponse, RusotoError<CreatePolicyError>> {
+        self.runtime.block_on(self.client.create_policy(input))
+    }
+
+    /// Blocking version of [`Iot::create_policy_version`].
+    pub fn create_policy_version(
+        &self,
+        input: CreatePolicyVersionRequest,
+    ) -> Result<CreatePolicyVersionResponse, RusotoError<CreatePolicyVersionError>> {
+        self.runtime
+            .block_on(self.client.create_policy_version(input))
+    }
+
+    /// Blocking version of [`Iot::create_provisioning_claim`].
+    pub fn create_provisioning_claim(
+        &self,
+        input: CreateProvisioningClaimRequest,
+    ) -> Result<CreateProvisioningClaimResponse, RusotoError<CreateProvisioningClaimError>> {
+        self.runtime
+            .block_on(self.client.create_provisioning_claim(input))
+    }
+
+    /// Blocking version of [`Iot::create_provisioning_template`].
+    pub fn create_provisioning_template(
+        &self,
+        input: CreateProvisioningTemplateRequest,
+    ) -> Result<CreateProvisioningTemplateResponse, RusotoError<CreateProvisioningTemplateError>>
+    {
+        self.runtime
+            .block_on(self.client.create_provisioning_template(input))
+    }
+
+    /// Blocking version of [`Iot::create_provisioning_template_version`].
+    pub fn create_provisioning_template_version(
+        &self,
+        input: CreateProvisioningTemplateVersionRequest,
+    ) -> Result<
+        CreateProvisioningTemplateVersionResponse,
+        RusotoError<CreateProvisioningTemplateVersionError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_provisioning_template_version(input))
+    }
+
+    /// Blocking version of [`Iot::create_role_alias`].
+    pub fn create_role_alias(
+        &self,
+        input: CreateRoleAliasRequest,
+    ) -> Result<CreateRoleAliasResponse, RusotoError<CreateRoleAliasError>> {
+        self.runtime.block_on(self.client.create_role_alias(input))
+    }
+
+    /// Blocking version of [`Iot::create_scheduled_audit`].
+    pub fn create_scheduled_audit(
+        &self,
+        input: CreateScheduledAuditRequest,
+    ) -> Result<CreateScheduledAuditResponse, RusotoError<CreateScheduledAuditError>> {
+        self.runtime
+            .block_on(self.client.create_scheduled_audit(input))
+    }
+
+    /// Blocking version of [`Iot::create_security_profile`].
+    pub fn create_security_profile(
+        &self,
+        input: CreateSecurityProfileRequest,
+    ) -> Result<CreateSecurityProfileResponse, RusotoError<CreateSecurityProfileError>> {
+        self.runtime
+            .block_on(self.client.create_security_profile(input))
+    }
+
+    /// Blocking version of [`Iot::create_stream`].
+    pub fn create_stream(
+        &self,
+        input: CreateStreamRequest,
+    ) -> Result<CreateStreamResponse, RusotoError<CreateStreamError>> {
+        self.runtime.block_on(self.client.create_stream(input))
+    }
+
+    /// Blocking version of [`Iot::create_thing`].
+    pub fn create_thing(
+        &self,
+        input: CreateThingRequest,
+    ) -> Result<CreateThingResponse, RusotoError<CreateThingError>> {
+        self.runtime.block_on(self.client.create_thing(input))
+    }
+
+    /// Blocking version of [`Iot::create_thing_group`].
+    pub fn create_thing_group(
+        &self,
+        input: CreateThingGroupRequest,
+    ) -> Result<CreateThingGroupResponse, RusotoError<CreateThingGroupError>> {
+        self.runtime.block_on(self.client.create_thing_group(input))
+    }
+
+    /// Blocking version of [`Iot::create_thing_type`].
+    pub fn create_thing_type(
+        &self,
+        input: CreateThingTypeRequest,
+    ) -> Result<CreateThingTypeResponse, RusotoError<CreateThingTypeError>> {
+        self.runtime.block_on(self.client.create_thing_type(input))
+    }
+
+    /// Blocking version of [`Iot::create_topic_rule`].
+    pub fn create_topic_rule(
+        &self,
+        input: CreateTopicRuleRequest,
+    ) -> Result<(), RusotoError<CreateTopicRuleError>> {
+        self.runtime.block_on(self.client.create_topic_rule(input))
+    }
+
+    /// Blocking version of [`Iot::create_topic_rule_destination`].
+    pub fn create_topic_rule_destination(
+        &self,
+        input: CreateTopicRuleDestinationRequest,
+    ) -> Result<CreateTopicRuleDestinationResponse, RusotoError<CreateTopicRuleDestinationError>>
+    {
+        self.runtime
+            .block_on(self.client.create_topic_rule_destination(input))
+    }
+
+    /// Blocking version of [`Iot::delete_account_audit_configuration`].
+    pub fn delete_account_audit_configuration(
+        &self,
+        input: DeleteAccountAuditConfigurationRequest,
+    ) -> Result<
+        DeleteAccountAuditConfigurationResponse,
+        RusotoError<DeleteAccountAuditConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_account_audit_configuration(input))
+    }
+
+    /// Blocking version of [`Iot::delete_audit_suppression`].
+    pub fn delete_audit_suppression(
+        &self,
+        input: DeleteAuditSuppressionRequest,
+    ) -> Result<DeleteAuditSuppressionResponse, RusotoError<DeleteAuditSuppressionError>> {
+        self.runtime
+            .block_on(self.client.delete_audit_suppression(input))
+    }
+
+    /// Blocking version of [`Iot::delete_authorizer`].
+    pub fn delete_authorizer(
+        &self,
+        input: DeleteAuthorizerRequest,
+    ) -> Result<DeleteAuthorizerResponse, RusotoError<DeleteAuthorizerError>> {
+        self.runtime.block_on(self.client.delete_authorizer(input))
+    }
+
+    /// Blocking version of [`Iot::delete_billing_group`].
+    pub fn delete_billing_group(
+        &self,
+        input: DeleteBillingGroupRequest,
+    ) -> Result<DeleteBillingGroupResponse, RusotoError<DeleteBillingGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_billing_group(input))
+    }
+
+    /// Blocking version of [`Iot::delete_ca_certificate`].
+    pub fn delete_ca_certificate(
+        &self,
+        input: DeleteCACertificateRequest,
+    ) -> Result<DeleteCACertificateResponse, RusotoError<DeleteCACertificateError>> {
+        self.runtime
+            .block_on(self.client.delete_ca_certificate(input))
+    }
+
+    /// Blocking version of [`Iot::delete_certificate`].
+    pub fn delete_certificate(
+        &self,
+        input: DeleteCertificateRequest,
+    ) -> Result<(), RusotoError<DeleteCertificateError>> {
+        self.runtime.block_on(self.client.delete_certificate(input))
+    }
+
+    /// Blocking version of [`Iot::delete_custom_metric`].
+    pub fn delete_custom_metric(
+        &self,
+        input: DeleteCustomMetricRequest,
+    ) -> Result<DeleteCustomMetricResponse, RusotoError<DeleteCustomMetricError>> {
+        self.runtime
+            .block_on(self.client.delete_custom_metric(input))
+    }
+
+    /// Blocking version of [`Iot::delete_dimension`].
+    pub fn delete_dimension(
+        &self,
+        input: DeleteDimensionRequest,
+    ) -> Result<DeleteDimensionResponse, RusotoError<DeleteDimensionError>> {
+        self.runtime.block_on(self.client.delete_dimension(input))
+    }
+
+    /// Blocking version of [`Iot::delete_domain_configuration`].
+    pub fn delete_domain_configuration(
+        &self,
+        input: DeleteDomainConfigurationRequest,
+    ) -> Result<DeleteDomainConfigurationResponse, RusotoError<DeleteDomainConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_domain_configuration(input))
+    }
+
+    /// Blocking version of [`Iot::delete_dynamic_thing_group`].
+    pub fn delete_dynamic_thing_group(
+        &self,
+        input: DeleteDynamicThingGroupRequest,
+    ) -> Result<DeleteDynamicThingGroupResponse, RusotoError<DeleteDynamicThingGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_dynamic_thing_group(input))
+    }
+
+    /// Blocking version of [`Iot::delete_job`].
+    pub fn delete_job(&self, input: DeleteJobRequest) -> Result<(), RusotoError<DeleteJobError>> {
+        self.runtime.block_on(self.client.delete_job(input))
+    }
+
+    /// Blocking version of [`Iot::delete_job_execution`].
+    pub fn delete_job_execution(
+        &self,
+        input: DeleteJobExecutionRequest,
+    ) -> Result<(), RusotoError<DeleteJobExecutionError>> {
+        self.runtime
+            .block_on(self.client.delete_job_execution(input))
+    }
+
+    /// Blocking version of [`Iot::delete_mitigation_action`].
+    pub fn delete_mitigation_action(
+        &self,
+        input: DeleteMitigationActionRequest,
+    ) -> Result<DeleteMitigationActionResponse, RusotoError<DeleteMitigationActionError>> {
+        self.runtime
+            .block_on(self.client.delete_mitigation_action(input))
+    }
+
+    /// Blocking version of [`Iot::delete_ota_update`].
+    pub fn delete_ota_update(
+        &self,
+        input: DeleteOTAUpdateRequest,
+    ) -> Result<DeleteOTAUpdateResponse, RusotoError<DeleteOTAUpdateError>> {
+        self.runtime.block_on(self.client.delete_ota_update(input))
+    }
+
+    /// Blocking version of [`Iot::delete_policy`].
+    pub fn delete_policy(
+        &self,
+        input: DeletePolicyRequest,
+    ) -> Result<(), RusotoError<DeletePolicyError>> {
+        self.runtime.block_on(self.client.delete_policy(input))
+    }
+
+    /// Blocking version of [`Iot::delete_policy_version`].
+    pub fn delete_policy_version(
+        &self,
+        input: DeletePolicyVersionRequest,
+    ) -> Result<(), RusotoError<DeletePolicyVersionError>> {
+        self.runtime
+            .block_on(self.client.delete_policy_version(input))
+    }
+
+    /// Blocking version of [`Iot::delete_provisioning_template`].
+    pub fn delete_provisioning_template(
+        &self,
+        input: DeleteProvisioningTemplateRequest,
+    ) -> Result<DeleteProvisioningTemplateResponse, RusotoError<DeleteProvisioningTemplateError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_provisioning_template(input))
+    }
+
+    /// Blocking version of [`Iot::delete_provisioning_template_version`].
+    pub fn delete_provisioning_template_version(
+        &self,
+        input: DeleteProvisioningTemplateVersionRequest,
+    ) -> Result<
+        DeleteProvisioningTemplateVersionResponse,
+        RusotoError<DeleteProvisioningTemplateVersionError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_provisioning_template_version(input))
+    }
+
+    /// Blocking version of [`Iot::delete_registration_code`].
+    pub fn delete_registration_code(
+        &self,
+    ) -> Result<DeleteRegistrationCodeResponse, RusotoError<DeleteRegistrationCodeError>> {
+        self.runtime
+            .block_on(self.client.delete_registration_code())
+    }
+
+    /// Blocking version of [`Iot::delete_role_alias`].
+    pub fn delete_role_alias(
+        &self,
+        input: DeleteRoleAliasRequest,
+    ) -> Result<DeleteRoleAliasResponse, RusotoError<DeleteRoleAliasError>> {
+        self.runtime.block_on(self.client.delete_role_alias(input))
+    }
+
+    /// Blocking version of [`Iot::delete_scheduled_audit`].
+    pub fn delete_scheduled_audit(
+        &self,
+        input: DeleteScheduledAuditRequest,
+    ) -> Result<DeleteScheduledAuditResponse, RusotoError<DeleteScheduledAuditError>> {
+        self.runtime
+            .block_on(self.client.delete_scheduled_audit(input))
+    }
+
+    /// Blocking version of [`Iot::delete_security_profile`].
+    pub fn delete_security_profile(
+        &self,
+        input: DeleteSecurityProfileRequest,
+    ) -> Result<DeleteSecurityProfileResponse, RusotoError<DeleteSecurityProfileError>> {
+        self.runtime
+            .block_on(self.client.delete_security_profile(input))
+    }
+
+    /// Blocking version of [`Iot::delete_stream`].
+    pub fn delete_stream(
+        &self,
+        input: DeleteStreamRequest,
+    ) -> Result<DeleteStreamResponse, RusotoError<DeleteStreamError>> {
+        self.runtime.block_on(self.client.delete_stream(input))
+    }
+
+    /// Blocking version of [`Iot::delete_thing`].
+    pub fn delete_thing(
+        &self,
+        input: DeleteThingRequest,
+    ) -> Result<DeleteThingResponse, RusotoError<DeleteThingError>> {
+        self.runtime.block_on(self.client.delete_thing(input))
+    }
+
+    /// Blocking version of [`Iot::delete_thing_group`].
+    pub fn delete_thing_group(
+        &self,
+        input: DeleteThingGroupRequest,
+    ) -> Result<DeleteThingGroupResponse, RusotoError<DeleteThingGroupError>> {
+        self.runtime.block_on(self.client.delete_thing_group(input))
+    }
+
+    /// Blocking version of [`Iot::delete_thing_type`].
+    pub fn delete_thing_type(
+        &self,
+        input: DeleteThingTypeRequest,
+    ) -> Result<DeleteThingTypeResponse, RusotoError<DeleteThingTypeError>> {
+        self.runtime.block_on(self.client.delete_thing_type(input))
+    }
+
+    /// Blocking version of [`Iot::delete_topic_rule`].
+    pub fn delete_topic_rule(
+        &self,
+        input: DeleteTopicRuleRequest,
+    ) -> Result<(), RusotoError<DeleteTopicRuleError>> {
+        self.runtime.block_on(self.client.delete_topic_rule(input))
+    }
+
+    /// Blocking version of [`Iot::delete_topic_rule_destination`].
+    pub fn delete_topic_rule_destination(
+        &self,
+        input: DeleteTopicRuleDestinationRequest,
+    ) -> Result<DeleteTopicRuleDestinationResponse, RusotoError<DeleteTopicRuleDestinationError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_topic_rule_destination(input))
+    }
+
+    /// Blocking version of [`Iot::delete_v2_logging_level`].
+    pub fn delete_v2_logging_level(
+        &self,
+        input: DeleteV2LoggingLevelRequest,
+    ) -> Result<(), RusotoError<DeleteV2LoggingLevelError>> {
+        self.runtime
+            .block_on(self.client.delete_v2_logging_level(input))
+    }
+
+    /// Blocking version of [`Iot::deprecate_thing_type`].
+    pub fn deprecate_thing_type(
+        &self,
+        input: DeprecateThingTypeRequest,
+    ) -> Result<DeprecateThingTypeResponse, RusotoError<DeprecateThingTypeError>> {
+        self.runtime
+            .block_on(self.client.deprecate_thing_type(input))
+    }
+
+    /// Blocking version of [`Iot::describe_account_audit_configuration`].
+    pub fn describe_account_audit_configuration(
+        &self,
+    ) -> Result<
+        DescribeAccountAuditConfigurationResponse,
+        RusotoError<DescribeAccountAuditConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_account_audit_configuration())
+    }
+
+    /// Blocking version of [`Iot::describe_audit_finding`].
+    pub fn describe_audit_finding(
+        &self,
+        input: DescribeAuditFindingRequest,
+    ) -> Result<DescribeAuditFindingResponse, RusotoError<DescribeAuditFindingError>> {
+        self.runtime
+            .block_on(self.client.describe_audit_finding(input))
+    }
+
+    /// Blocking version of [`Iot::describe_audit_mitigation_actions_task`].
+    pub fn describe_audit_mitigation_actions_task(
+        &self,
+        input: DescribeAuditMitigationActionsTaskRequest,
+    ) -> Result<
+        DescribeAuditMitigationActionsTaskResponse,
+        RusotoError<DescribeAuditMitigationActionsTaskError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_audit_mitigation_actions_task(input))
+    }
+
+    /// Blocking version of [`Iot::describe_audit_suppression`].
+    pub fn describe_audit_suppression(
+        &self,
+        input: DescribeAuditSuppressionRequest,
+    ) -> Result<DescribeAuditSuppressionResponse, RusotoError<DescribeAuditSuppressionError>> {
+        self.runtime
+            .block_on(self.client.describe_audit_suppression(input))
+    }
+
+    /// Blocking version of [`Iot::describe_audit_task`].
+    pub fn describe_audit_task(
+        &self,
+        input: DescribeAuditTaskRequest,
+    ) -> Result<DescribeAuditTaskResponse, RusotoError<DescribeAuditTaskError>> {
+        self.runtime
+            .block_on(self.client.describe_audit_task(input))
+    }
+
+    /// Blocking version of [`Iot::describe_authorizer`].
+    pub fn describe_authorizer(
+        &self,
+        input: DescribeAuthorizerRequest,
+    ) -> Result<DescribeAuthorizerResponse, RusotoError<DescribeAuthorizerError>> {
+        self.runtime
+            .block_on(self.client.describe_authorizer(input))
+    }
+
+    /// Blocking version of [`Iot::describe_billing_group`].
+    pub fn describe_billing_group(
+        &self,
+        input: DescribeBillingGroupRequest,
+    ) -> Result<DescribeBillingGroupResponse, RusotoError<DescribeBillingGroupError>> {
+        self.runtime
+            .block_on(self.client.describe_billing_group(input))
+    }
+
+    /// Blocking version of [`Iot::describe_ca_certificate`].
+    pub fn describe_ca_certificate(
+        &self,
+        input: DescribeCACertificateRequest,
+    ) -> Result<DescribeCACertificateResponse, RusotoError<DescribeCACertificateError>> {
+        self.runtime
+            .block_on(self.client.describe_ca_certificate(input))
+    }
+
+    /// Blocking version of [`Iot::describe_certificate`].
+    pub fn describe_certificate(
+        &self,
+        input: DescribeCertificateRequest,
+    ) -> Result<DescribeCertificateResponse, RusotoError<DescribeCertificateError>> {
+        self.runtime
+            .block_on(self.client.describe_certificate(input))
+    }
+
+    /// Blocking version of [`Iot::describe_custom_metric`].
+    pub fn describe_custom_metric(
+        &self,
+        input: DescribeCustomMetricRequest,
+    ) -> Result<DescribeCustomMetricResponse, RusotoError<DescribeCustomMetricError>> {
+        self.runtime
+            .block_on(self.client.describe_custom_metric(input))
+    }
+
+    /// Blocking version of [`Iot::describe_default_authorizer`].
+    pub fn describe_default_authorizer(
+        &self,
+    ) -> Result<DescribeDefaultAuthorizerResponse, RusotoError<DescribeDefaultAuthorizerError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_default_authorizer())
+    }
+
+    /// Blocking version of [`Iot::describe_detect_mitigation_actions_task`].
+    pub fn describe_detect_mitigation_actions_task(
+        &self,
+        input: DescribeDetectMitigationActionsTaskRequest,
+    ) -> Result<
+        DescribeDetectMitigationActionsTaskResponse,
+        RusotoError<DescribeDetectMitigationActionsTaskError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_detect_mitigation_actions_task(input))
+    }
+
+    /// Blocking version of [`Iot::describe_dimension`].
+    pub fn describe_dimension(
+        &self,
+        input: DescribeDimensionRequest,
+    ) -> Result<DescribeDimensionResponse, RusotoError<DescribeDimensionError>> {
+        self.runtime.block_on(self.client.describe_dimension(input))
+    }
+
+    /// Blocking version of [`Iot::describe_domain_configuration`].
+    pub fn describe_domain_configuration(
+        &self,
+        input: DescribeDomainConfigurationRequest,
+    ) -> Result<DescribeDomainConfigurationResponse, RusotoError<DescribeDomainConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_domain_configuration(input))
+    }
+
+    /// Blocking version of [`Iot::describe_endpoint`].
+    pub fn describe_endpoint(
+        &self,
+        input: DescribeEndpointRequest,
+    ) -> Result<DescribeEndpointResponse, RusotoError<DescribeEndpointError>> {
+        self.runtime.block_on(self.client.describe_endpoint(input))
+    }
+
+    /// Blocking version of [`Iot::describe_event_configurations`].
+    pub fn describe_event_configurations(
+        &self,
+    ) -> Result<DescribeEventConfigurationsResponse, RusotoError<DescribeEventConfigurationsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_event_configurations())
+    }
+
+    /// Blocking version of [`Iot::describe_index`].
+    pub fn describe_index(
+        &self,
+        input: DescribeIndexRequest,
+    ) -> Result<DescribeIndexResponse, RusotoError<DescribeIndexError>> {
+        self.runtime.block_on(self.client.describe_index(input))
+    }
+
+    /// Blocking version of [`Iot::describe_job`].
+    pub fn describe_job(
+        &self,
+        input: DescribeJobRequest,
+    ) -> Result<DescribeJobResponse, RusotoError<DescribeJobError>> {
+        self.runtime.block_on(self.client.describe_job(input))
+    }
+
+    /// Blocking version of [`Iot::describe_job_execution`].
+    pub fn describe_job_execution(
+        &self,
+        input: DescribeJobExecutionRequest,
+    ) -> Result<DescribeJobExecutionResponse, RusotoError<DescribeJobExecutionError>> {
+        self.runtime
+            .block_on(self.client.describe_job_execution(input))
+    }
+
+    /// Blocking version of [`Iot::describe_mitigation_action`].
+    pub fn describe_mitigation_action(
+        &self,
+        input: DescribeMitigationActionRequest,
+    ) -> Result<DescribeMitigationActionResponse, RusotoError<DescribeMitigationActionError>> {
+        self.runtime
+            .block_on(self.client.describe_mitigation_action(input))
+    }
+
+    /// Blocking version of [`Iot::describe_provisioning_template`].
+    pub fn describe_provisioning_template(
+        &self,
+        input: DescribeProvisioningTemplateRequest,
+    ) -> Result<DescribeProvisioningTemplateResponse, RusotoError<DescribeProvisioningTemplateError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_provisioning_template(input))
+    }
+
+    /// Blocking version of [`Iot::describe_provisioning_template_version`].
+    pub fn describe_provisioning_template_version(
+        &self,
+        input: DescribeProvisioningTemplateVersionRequest,
+    ) -> Result<
+        DescribeProvisioningTemplateVersionResponse,
+        RusotoError<DescribeProvisioningTemplateVersionError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_provisioning_template_version(input))
+    }
+
+    /// Blocking version of [`Iot::describe_role_alias`].
+    pub fn describe_role_alias(
+        &self,
+        input: DescribeRoleAliasRequest,
+    ) -> Result<DescribeRoleAliasResponse, RusotoError<DescribeRoleAliasError>> {
+        self.runtime
+            .block_on(self.client.describe_role_alias(input))
+    }
+
+    /// Blocking version of [`Iot::describe_scheduled_audit`].
+    pub fn describe_scheduled_audit(
+        &self,
+        input: DescribeScheduledAuditRequest,
+    ) -> Result<DescribeScheduledAuditResponse, RusotoError<DescribeScheduledAuditError>> {
+        self.runtime
+            .block_on(self.client.describe_scheduled_audit(input))
+    }
+
+    /// Blocking version of [`Iot::describe_security_profile`].
+    pub fn describe_security_profile(
+        &self,
+        input: DescribeSecurityProfileRequest,
+    ) -> Result<DescribeSecurityProfileResponse, RusotoError<DescribeSecurityProfileError>> {
+        self.runtime
+            .block_on(self.client.describe_security_profile(input))
+    }
+
+    /// Blocking version of [`Iot::describe_stream`].
+    pub fn describe_stream(
+        &self,
+        input: DescribeStreamRequest,
+    ) -> Result<DescribeStreamResponse, RusotoError<DescribeStreamError>> {
+        self.runtime.block_on(self.client.describe_stream(input))
+    }
+
+    /// Blocking version of [`Iot::describe_thing`].
+    pub fn describe_thing(
+        &self,
+        input: DescribeThingRequest,
+    ) -> Result<DescribeThingResponse, RusotoError<DescribeThingError>> {
+        self.runtime.block_on(self.client.describe_thing(input))
+    }
+
+    /// Blocking version of [`Iot::describe_thing_group`].
+    pub fn describe_thing_group(
+        &self,
+        input: DescribeThingGroupRequest,
+    ) -> Result<DescribeThingGroupResponse, RusotoError<DescribeThingGroupError>> {
+        self.runtime
+            .block_on(self.client.describe_thing_group(input))
+    }
+
+    /// Blocking version of [`Iot::describe_thing_registration_task`].
+    pub fn describe_thing_registration_task(
+        &self,
+        input: DescribeThingRegistrationTaskRequest,
+    ) -> Result<
+        DescribeThingRegistrationTaskResponse,
+        RusotoError<DescribeThingRegistrationTaskError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_thing_registration_task(input))
+    }
+
+    /// Blocking version of [`Iot::describe_thing_type`].
+    pub fn describe_thing_type(
+        &self,
+        input: DescribeThingTypeRequest,
+    ) -> Result<DescribeThingTypeResponse, RusotoError<DescribeThingTypeError>> {
+        self.runtime
+            .block_on(self.client.describe_thing_type(input))
+    }
+
+    /// Blocking version of [`Iot::detach_policy`].
+    pub fn detach_policy(
+        &self,
+        input: DetachPolicyRequest,
+    ) -> Result<(), RusotoError<DetachPolicyError>> {
+        self.runtime.block_on(self.client.detach_policy(input))
+    }
+
+    /// Blocking version of [`Iot::detach_principal_policy`].
+    pub fn detach_principal_policy(
+        &self,
+        input: DetachPrincipalPolicyRequest,
+    ) -> Result<(), RusotoError<DetachPrincipalPolicyError>> {
+        self.runtime
+            .block_on(self.client.detach_principal_policy(input))
+    }
+
+    /// Blocking version of [`Iot::detach_security_profile`].
+    pub fn detach_security_profile(
+        &self,
+        input: DetachSecurityProfileRequest,
+    ) -> Result<DetachSecurityProfileResponse, RusotoError<DetachSecurityProfileError>> {
+        self.runtime
+            .block_on(self.client.detach_security_profile(input))
+    }
+
+    /// Blocking version of [`Iot::detach_thing_principal`].
+    pub fn detach_thing_principal(
+        &self,
+        input: DetachThingPrincipalRequest,
+    ) -> Result<DetachThingPrincipalResponse, RusotoError<DetachThingPrincipalError>> {
+        self.runtime
+            .block_on(self.client.detach_thing_principal(input))
+    }
+
+    /// Blocking version of [`Iot::disable_topic_rule`].
+    pub fn disable_topic_rule(
+        &self,
+        input: DisableTopicRuleRequest,
+    ) -> Result<(), RusotoError<DisableTopicRuleError>> {
+        self.runtime.block_on(self.client.disable_topic_rule(input))
+    }
+
+    /// Blocking version of [`Iot::enable_topic_rule`].
+    pub fn enable_topic_rule(
+        &self,
+        input: EnableTopicRuleRequest,
+    ) -> Result<(), RusotoError<EnableTopicRuleError>> {
+        self.runtime.block_on(self.client.enable_topic_rule(input))
+    }
+
+    /// Blocking version of [`Iot::get_behavior_model_training_summaries`].
+    pub fn get_behavior_model_training_summaries(
+        &self,
+        input: GetBehaviorModelTrainingSummariesRequest,
+    ) -> Result<
+        GetBehaviorModelTrainingSummariesResponse,
+        RusotoError<GetBehaviorModelTrainingSummariesError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_behavior_model_training_summaries(input))
+    }
+
+    /// Blocking version of [`Iot::get_cardinality`].
+    pub fn get_cardinality(
+        &self,
+        input: GetCardinalityRequest,
+    ) -> Result<GetCardinalityResponse, RusotoError<GetCardinalityError>> {
+        self.runtime.block_on(self.client.get_cardinality(input))
+    }
+
+    /// Blocking version of [`Iot::get_effective_policies`].
+    pub fn get_effective_policies(
+        &self,
+        input: GetEffectivePoliciesRequest,
+    ) -> Result<GetEffectivePoliciesResponse, RusotoError<GetEffectivePoliciesError>> {
+        self.runtime
+            .block_on(self.client.get_effective_policies(input))
+    }
+
+    /// Blocking version of [`Iot::get_indexing_configuration`].
+    pub fn get_indexing_configuration(
+        &self,
+    ) -> Result<GetIndexingConfigurationResponse, RusotoError<GetIndexingConfigurationError>> {
+        self.runtime
+            .block_on(self.client.get_indexing_configuration())
+    }
+
+    /// Blocking version of [`Iot::get_job_document`].
+    pub fn get_job_document(
+        &self,
+        input: GetJobDocumentRequest,
+    ) -> Result<GetJobDocumentResponse, RusotoError<GetJobDocumentError>> {
+        self.runtime.block_on(self.client.get_job_document(input))
+    }
+
+    /// Blocking version of [`Iot::get_logging_options`].
+    pub fn get_logging_options(
+        &self,
+    ) -> Result<GetLoggingOptionsResponse, RusotoError<GetLoggingOptionsError>> {
+        self.runtime.block_on(self.client.get_logging_options())
+    }
+
+    /// Blocking version of [`Iot::get_ota_update`].
+    pub fn get_ota_update(
+        &self,
+        input: GetOTAUpdateRequest,
+    ) -> Result<GetOTAUpdateResponse, RusotoError<GetOTAUpdateError>> {
+        self.runtime.block_on(self.client.get_ota_update(input))
+    }
+
+    /// Blocking version of [`Iot::get_percentiles`].
+    pub fn get_percentiles(
+        &self,
+        input: GetPercentilesRequest,
+    ) -> Result<GetPercentilesResponse, RusotoError<GetPercentilesError>> {
+        self.runtime.block_on(self.client.get_percentiles(input))
+    }
+
+    /// Blocking version of [`Iot::get_policy`].
+    pub fn get_policy(
+        &self,
+        input: GetPolicyRequest,
+    ) -> Result<GetPolicyResponse, RusotoError<GetPolicyError>> {
+        self.runtime.block_on(self.client.get_policy(input))
+    }
+
+    /// Blocking version of [`Iot::get_policy_version`].
+    pub fn get_policy_version(
+        &self,
+        input: GetPolicyVersionRequest,
+    ) -> Result<GetPolicyVersionResponse, RusotoError<GetPolicyVersionError>> {
+        self.runtime.block_on(self.client.get_policy_version(input))
+    }
+
+    /// Blocking version of [`Iot::get_registration_code`].
+    pub fn get_registration_code(
+        &self,
+    ) -> Result<GetRegistrationCodeResponse, RusotoError<GetRegistrationCodeError>> {
+        self.runtime.block_on(self.client.get_registration_code())
+    }
+
+    /// Blocking version of [`Iot::get_statistics`].
+    pub fn get_statistics(
+        &self,
+        input: GetStatisticsRequest,
+    ) -> Result<GetStatisticsResponse, RusotoError<GetStatisticsError>> {
+        self.runtime.block_on(self.client.get_statistics(input))
+    }
+
+    /// Blocking version of [`Iot::get_topic_rule`].
+    pub fn get_topic_rule(
+        &self,
+        input: GetTopicRuleRequest,
+    ) -> Result<GetTopicRuleResponse, RusotoError<GetTopicRuleError>> {
+        self.runtime.block_on(self.client.get_topic_rule(input))
+    }
+
+    /// Blocking version of [`Iot::get_topic_rule_destination`].
+    pub fn get_topic_rule_destination(
+        &self,
+        input: GetTopicRuleDestinationRequest,
+    ) -> Result<GetTopicRuleDestinationResponse, RusotoError<GetTopicRuleDestinationError>> {
+        self.runtime
+            .block_on(self.client.get_topic_rule_destination(input))
+    }
+
+    /// Blocking version of [`Iot::get_v2_logging_options`].
+    pub fn get_v2_logging_options(
+        &self,
+    ) -> Result<GetV2LoggingOptionsResponse, RusotoError<GetV2LoggingOptionsError>> {
+        self.runtime.block_on(self.client.get_v2_logging_options())
+    }
+
+    /// Blocking version of [`Iot::list_active_violations`].
+    pub fn list_active_violations(
+        &self,
+        input: ListActiveViolationsRequest,
+    ) -> Result<ListActiveViolationsResponse, RusotoError<ListActiveViolationsError>> {
+        self.runtime
+            .block_on(self.client.list_active_violations(input))
+    }
+
+    /// Blocking version of [`Iot::list_attached_policies`].
+    pub fn list_attached_policies(
+        &self,
+        input: ListAttachedPoliciesRequest,
+    ) -> Result<ListAttachedPoliciesResponse, RusotoError<ListAttachedPoliciesError>> {
+        self.runtime
+            .block_on(self.client.list_attached_policies(input))
+    }
+
+    /// Blocking version of [`Iot::list_audit_findings`].
+    pub fn list_audit_findings(
+        &self,
+        input: ListAuditFindingsRequest,
+    ) -> Result<ListAuditFindingsResponse, RusotoError<ListAuditFindingsError>> {
+        self.runtime
+            .block_on(self.client.list_audit_findings(input))
+    }
+
+    /// Blocking version of [`Iot::list_audit_mitigation_actions_executions`].
+    pub fn list_audit_mitigation_actions_executions(
+        &self,
+        input: ListAuditMitigationActionsExecutionsRequest,
+    ) -> Result<
+        ListAuditMitigationActionsExecutionsResponse,
+        RusotoError<ListAuditMitigationActionsExecutionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_audit_mitigation_actions_executions(input))
+    }
+
+    /// Blocking version of [`Iot::list_audit_mitigation_actions_tasks`].
+    pub fn list_audit_mitigation_actions_tasks(
+        &self,
+        input: ListAuditMitigationActionsTasksRequest,
+    ) -> Result<
+        ListAuditMitigationActionsTasksResponse,
+        RusotoError<ListAuditMitigationActionsTasksError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_audit_mitigation_actions_tasks(input))
+    }
+
+    /// Blocking version of [`Iot::list_audit_suppressions`].
+    pub fn list_audit_suppressions(
+        &self,
+        input: ListAuditSuppressionsRequest,
+    ) -> Result<ListAuditSuppressionsResponse, RusotoError<ListAuditSuppressionsError>> {
+        self.runtime
+            .block_on(self.client.list_audit_suppressions(input))
+    }
+
+    /// Blocking version of [`Iot::list_audit_tasks`].
+    pub fn list_audit_tasks(
+        &self,
+        input: ListAuditTasksRequest,
+    ) -> Result<ListAuditTasksResponse, RusotoError<ListAuditTasksError>> {
+        self.runtime.block_on(self.client.list_audit_tasks(input))
+    }
+
+    /// Blocking version of [`Iot::list_authorizers`].
+    pub fn list_authorizers(
+        &self,
+        input: ListAuthorizersRequest,
+    ) -> Result<ListAuthorizersResponse, RusotoError<ListAuthorizersError>> {
+        self.runtime.block_on(self.client.list_authorizers(input))
+    }
+
+    /// Blocking version of [`Iot::list_billing_groups`].
+    pub fn list_billing_groups(
+        &self,
+        input: ListBillingGroupsRequest,
+    ) -> Result<ListBillingGroupsResponse, RusotoError<ListBillingGroupsError>> {
+        self.runtime
+            .block_on(self.client.list_billing_groups(input))
+    }
+
+    /// Blocking version of [`Iot::list_ca_certificates`].
+    pub fn list_ca_certificates(
+        &self,
+        input: ListCACertificatesRequest,
+    ) -> Result<ListCACertificatesResponse, RusotoError<ListCACertificatesError>> {
+        self.runtime
+            .block_on(self.client.list_ca_certificates(input))
+    }
+
+    /// Blocking version of [`Iot::list_certificates`].
+    pub fn list_certificates(
+        &self,
+        input: ListCertificatesRequest,
+    ) -> Result<ListCertificatesResponse, RusotoError<ListCertificatesError>> {
+        self.runtime.block_on(self.client.list_certificates(input))
+    }
+
+    /// Blocking version of [`Iot::list_certificates_by_ca`].
+    pub fn list_certificates_by_ca(
+        &self,
+        input: ListCertificatesByCARequest,
+    ) -> Result<ListCertificatesByCAResponse, RusotoError<ListCertificatesByCAError>> {
+        self.runtime
+            .block_on(self.client.list_certificates_by_ca(input))
+    }
+
+    /// Blocking version of [`Iot::list_custom_metrics`].
+    pub fn list_custom_metrics(
+        &self,
+        input: ListCustomMetricsRequest,
+    ) -> Result<ListCustomMetricsResponse, RusotoError<ListCustomMetricsError>> {
+        self.runtime
+            .block_on(self.client.list_custom_metrics(input))
+    }
+
+    /// Blocking version of [`Iot::list_detect_mitigation_actions_executions`].
+    pub fn list_detect_mitigation_actions_executions(
+        &self,
+        input: ListDetectMitigationActionsExecutionsRequest,
+    ) -> Result<
+        ListDetectMitigationActionsExecutionsResponse,
+        RusotoError<ListDetectMitigationActionsExecutionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_detect_mitigation_actions_executions(input))
+    }
+
+    /// Blocking version of [`Iot::list_detect_mitigation_actions_tasks`].
+    pub fn list_detect_mitigation_actions_tasks(
+        &self,
+        input: ListDetectMitigationActionsTasksRequest,
+    ) -> Result<
+        ListDetectMitigationActionsTasksResponse,
+        RusotoError<ListDetectMitigationActionsTasksError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_detect_mitigation_actions_tasks(input))
+    }
+
+    /// Blocking version of [`Iot::list_dimensions`].
+    pub fn list_dimensions(
+        &self,
+        input: ListDimensionsRequest,
+    ) -> Result<ListDimensionsResponse, RusotoError<ListDimensionsError>> {
+        self.runtime.block_on(self.client.list_dimensions(input))
+    }
+
+    /// Blocking version of [`Iot::list_domain_configurations`].
+    pub fn list_domain_configurations(
+        &self,
+        input: ListDomainConfigurationsRequest,
+    ) -> Result<ListDomainConfigurationsResponse, RusotoError<ListDomainConfigurationsError>> {
+        self.runtime
+            .block_on(self.client.list_domain_configurations(input))
+    }
+
+    /// Blocking version of [`Iot::list_indices`].
+    pub fn list_indices(
+        &self,
+        input: ListIndicesRequest,
+    ) -> Result<ListIndicesResponse, RusotoError<ListIndicesError>> {
+        self.runtime.block_on(self.client.list_indices(input))
+    }
+
+    /// Blocking version of [`Iot::list_job_executions_for_job`].
+    pub fn list_job_executions_for_job(
+        &self,
+        input: ListJobExecutionsForJobRequest,
+    ) -> Result<ListJobExecutionsForJobResponse, RusotoError<ListJobExecutionsForJobError>> {
+        self.runtime
+            .block_on(self.client.list_job_executions_for_job(input))
+    }
+
+    /// Blocking version of [`Iot::list_job_executions_for_thing`].
+    pub fn list_job_executions_for_thing(
+        &self,
+        input: ListJobExecutionsForThingRequest,
+    ) -> Result<ListJobExecutionsForThingResponse, RusotoError<ListJobExecutionsForThingError>>
+    {
+        self.runtime
+            .block_on(self.client.list_job_executions_for_thing(input))
+    }
+
+    /// Blocking version of [`Iot::list_jobs`].
+    pub fn list_jobs(
+        &self,
+        input: ListJobsRequest,
+    ) -> Result<ListJobsResponse, RusotoError<ListJobsError>> {
+        self.runtime.block_on(self.client.list_jobs(input))
+    }
+
+    /// Blocking version of [`Iot::list_mitigation_actions`].
+    pub fn list_mitigation_actions(
+        &self,
+        input: ListMitigationActionsRequest,
+    ) -> Result<ListMitigationActionsResponse, RusotoError<ListMitigationActionsError>> {
+        self.runtime
+            .block_on(self.client.list_mitigation_actions(input))
+    }
+
+    /// Blocking version of [`Iot::list_ota_updates`].
+    pub fn list_ota_updates(
+        &self,
+        input: ListOTAUpdatesRequest,
+    ) -> Result<ListOTAUpdatesResponse, RusotoError<ListOTAUpdatesError>> {
+        self.runtime.block_on(self.client.list_ota_updates(input))
+    }
+
+    /// Blocking version of [`Iot::list_outgoing_certificates`].
+    pub fn list_outgoing_certificates(
+        &self,
+        input: ListOutgoingCertificatesRequest,
+    ) -> Result<ListOutgoingCertificatesResponse, RusotoError<ListOutgoingCertificatesError>> {
+        self.runtime
+            .block_on(self.client.list_outgoing_certificates(input))
+    }
+
+    /// Blocking version of [`Iot::list_policies`].
+    pub fn list_policies(
+        &self,
+        input: ListPoliciesRequest,
+    ) -> Result<ListPoliciesResponse, RusotoError<ListPoliciesError>> {
+        self.runtime.block_on(self.client.list_policies(input))
+    }
+
+    /// Blocking version of [`Iot::list_policy_principals`].
+    pub fn list_policy_principals(
+        &self,
+        input: ListPolicyPrincipalsRequest,
+    ) -> Result<ListPolicyPrincipalsResponse, RusotoError<ListPolicyPrincipalsError>> {
+        self.runtime
+            .block_on(self.client.list_policy_principals(input))
+    }
+
+    /// Blocking version of [`Iot::list_policy_versions`].
+    pub fn list_policy_versions(
+        &self,
+        input: ListPolicyVersionsRequest,
+    ) -> Result<ListPolicyVersionsResponse, RusotoError<ListPolicyVersionsError>> {
+        self.runtime
+            .block_on(self.client.list_policy_versions(input))
+    }
+
+    /// Blocking version of [`Iot::list_principal_policies`].
+    pub fn list_principal_policies(
+        &self,
+        input: ListPrincipalPoliciesRequest,
+    ) -> Result<ListPrincipalPoliciesResponse, RusotoError<ListPrincipalPoliciesError>> {
+        self.runtime
+            .block_on(self.client.list_principal_policies(input))
+    }
+
+    /// Blocking version of [`Iot::list_principal_things`].
+    pub fn list_principal_things(
+        &self,
+        input: ListPrincipalThingsRequest,
+    ) -> Result<ListPrincipalThingsResponse, RusotoError<ListPrincipalThingsError>> {
+        self.runtime
+            .block_on(self.client.list_principal_things(input))
+    }
+
+    /// Blocking version of [`Iot::list_provisioning_template_versions`].
+    pub fn list_provisioning_template_versions(
+        &self,
+        input: ListProvisioningTemplateVersionsRequest,
+    ) -> Result<
+        ListProvisioningTemplateVersionsResponse,
+        RusotoError<ListProvisioningTemplateVersionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_provisioning_template_versions(input))
+    }
+
+    /// Blocking version of [`Iot::list_provisioning_templates`].
+    pub fn list_provisioning_templates(
+        &self,
+        input: ListProvisioningTemplatesRequest,
+    ) -> Result<ListProvisioningTemplatesResponse, RusotoError<ListProvisioningTemplatesError>>
+    {
+        self.runtime
+            .block_on(self.client.list_provisioning_templates(input))
+    }
+
+    /// Blocking version of [`Iot::list_role_aliases`].
+    pub fn list_role_aliases(
+        &self,
+        input: ListRoleAliasesRequest,
+    ) -> Result<ListRoleAliasesResponse, RusotoError<ListRoleAliasesError>> {
+        self.runtime.block_on(self.client.list_role_aliases(input))
+    }
+
+    /// Blocking version of [`Iot::list_scheduled_audits`].
+    pub fn list_scheduled_audits(
+        &self,
+        input: ListScheduledAuditsRequest,
+    ) -> Result<ListScheduledAuditsResponse, RusotoError<ListScheduledAuditsError>> {
+        self.runtime
+            .block_on(self.client.list_scheduled_audits(input))
+    }
+
+    /// Blocking version of [`Iot::list_security_profiles`].
+    pub fn list_security_profiles(
+        &self,
+        input: ListSecurityProfilesRequest,
+    ) -> Result<ListSecurityProfilesResponse, RusotoError<ListSecurityProfilesError>> {
+        self.runtime
+            .block_on(self.client.list_security_profiles(input))
+    }
+
+    /// Blocking version of [`Iot::list_security_profiles_for_target`].
+    pub fn list_security_profiles_for_target(
+        &self,
+        input: ListSecurityProfilesForTargetRequest,
+    ) -> Result<
+        ListSecurityProfilesForTargetResponse,
+        RusotoError<ListSecurityProfilesForTargetError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_security_profiles_for_target(input))
+    }
+
+    /// Blocking version of [`Iot::list_streams`].
+    pub fn list_streams(
+        &self,
+        input: ListStreamsRequest,
+    ) -> Result<ListStreamsResponse, RusotoError<ListStreamsError>> {
+        self.runtime.block_on(self.client.list_streams(input))
+    }
+
+    /// Blocking version of [`Iot::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`Iot::list_targets_for_policy`].
+    pub fn list_targets_for_policy(
+        &self,
+        input: ListTargetsForPolicyRequest,
+    ) -> Result<ListTargetsForPolicyResponse, RusotoError<ListTargetsForPolicyError>> {
+        self.runtime
+            .block_on(self.client.list_targets_for_policy(input))
+    }
+
+    /// Blocking version of [`Iot::list_targets_for_security_profile`].
+    pub fn list_targets_for_security_profile(
+        &self,
+        input: ListTargetsForSecurityProfileRequest,
+    ) -> Result<
+        ListTargetsForSecurityProfileResponse,
+        RusotoError<ListTargetsForSecurityProfileError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_targets_for_security_profile(input))
+    }
+
+    /// Blocking version of [`Iot::list_thing_groups`].
+    pub fn list_thing_groups(
+        &self,
+        input: ListThingGroupsRequest,
+    ) -> Result<ListThingGroupsResponse, RusotoError<ListThingGroupsError>> {
+        self.runtime.block_on(self.client.list_thing_groups(input))
+    }
+
+    /// Blocking version of [`Iot::list_thing_groups_for_thing`].
+    pub fn list_thing_groups_for_thing(
+        &self,
+        input: ListThingGroupsForThingRequest,
+    ) -> Result<ListThingGroupsForThingResponse, RusotoError<ListThingGroupsForThingError>> {
+        self.runtime
+            .block_on(self.client.list_thing_groups_for_thing(input))
+    }
+
+    /// Blocking version of [`Iot::list_thing_principals`].
+    pub fn list_thing_principals(
+        &self,
+        input: ListThingPrincipalsRequest,
+    ) -> Result<ListThingPrincipalsResponse, RusotoError<ListThingPrincipalsError>> {
+        self.runtime
+            .block_on(self.client.list_thing_principals(input))
+    }
+
+    /// Blocking version of [`Iot::list_thing_registration_task_reports`].
+    pub fn list_thing_registration_task_reports(
+        &self,
+        input: ListThingRegistrationTaskReportsRequest,
+    ) -> Result<
+        ListThingRegistrationTaskReportsResponse,
+        RusotoError<ListThingRegistrationTaskReportsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_thing_registration_task_reports(input))
+    }
+
+    /// Blocking version of [`Iot::list_thing_registration_tasks`].
+    pub fn list_thing_registration_tasks(
+        &self,
+        input: ListThingRegistrationTasksRequest,
+    ) -> Result<ListThingRegistrationTasksResponse, RusotoError<ListThingRegistrationTasksError>>
+    {
+        self.runtime
+            .block_on(self.client.list_thing_registration_tasks(input))
+    }
+
+    /// Blocking version of [`Iot::list_thing_types`].
+    pub fn list_thing_types(
+        &self,
+        input: ListThingTypesRequest,
+    ) -> Result<ListThingTypesResponse, RusotoError<ListThingTypesError>> {
+        self.runtime.block_on(self.client.list_thing_types(input))
+    }
+
+    /// Blocking version of [`Iot::list_things`].
+    pub fn list_things(
+        &self,
+        input: ListThingsRequest,
+    ) -> Result<ListThingsResponse, RusotoError<ListThingsError>> {
+        self.runtime.block_on(self.client.list_things(input))
+    }
+
+    /// Blocking version of [`Iot::list_things_in_billing_group`].
+    pub fn list_things_in_billing_group(
+        &self,
+        input: ListThingsInBillingGroupRequest,
+    ) -> Result<ListThingsInBillingGroupResponse, RusotoError<ListThingsInBillingGroupError>> {
+        self.runtime
+            .block_on(self.client.list_things_in_billing_group(input))
+    }
+
+    /// Blocking version of [`Iot::list_things_in_thing_group`].
+    pub fn list_things_in_thing_group(
+        &self,
+        input: ListThingsInThingGroupRequest,
+    ) -> Result<ListThingsInThingGroupResponse, RusotoError<ListThingsInThingGroupError>> {
+        self.runtime
+            .block_on(self.client.list_things_in_thing_group(input))
+    }
+
+    /// Blocking version of [`Iot::list_topic_rule_destinations`].
+    pub fn list_topic_rule_destinations(
+        &self,
+        input: ListTopicRuleDestinationsRequest,
+    ) -> Result<ListTopicRuleDestinationsResponse, RusotoError<ListTopicRuleDestinationsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_topic_rule_destinations(input))
+    }
+
+    /// Blocking version of [`Iot::list_topic_rules`].
+    pub fn list_topic_rules(
+        &self,
+        input: ListTopicRulesRequest,
+    ) -> Result<ListTopicRulesResponse, RusotoError<ListTopicRulesError>> {
+        self.runtime.block_on(self.client.list_topic_rules(input))
+    }
+
+    /// Blocking version of [`Iot::list_v2_logging_levels`].
+    pub fn list_v2_logging_levels(
+        &self,
+        input: ListV2LoggingLevelsRequest,
+    ) -> Result<ListV2LoggingLevelsResponse, RusotoError<ListV2LoggingLevelsError>> {
+        self.runtime
+            .block_on(self.client.list_v2_logging_levels(input))
+    }
+
+    /// Blocking version of [`Iot::list_violation_events`].
+    pub fn list_violation_events(
+        &self,
+        input: ListViolationEventsRequest,
+    ) -> Result<ListViolationEventsResponse, RusotoError<ListViolationEventsError>> {
+        self.runtime
+            .block_on(self.client.list_violation_events(input))
+    }
+
+    /// Blocking version of [`Iot::register_ca_certificate`].
+    pub fn register_ca_certificate(
+        &self,
+        input: RegisterCACertificateRequest,
+    ) -> Result<RegisterCACertificateResponse, RusotoError<RegisterCACertificateError>> {
+        self.runtime
+            .block_on(self.client.register_ca_certificate(input))
+    }
+
+    /// Blocking version of [`Iot::register_certificate`].
+    pub fn register_certificate(
+        &self,
+        input: RegisterCertificateRequest,
+    ) -> Result<RegisterCertificateResponse, RusotoError<RegisterCertificateError>> {
+        self.runtime
+            .block_on(self.client.register_certificate(input))
+    }
+
+    /// Blocking version of [`Iot::register_certificate_without_ca`].
+    pub fn register_certificate_without_ca(
+        &self,
+        input: RegisterCertificateWithoutCARequest,
+    ) -> Result<RegisterCertificateWithoutCAResponse, RusotoError<RegisterCertificateWithoutCAError>>
+    {
+        self.runtime
+            .block_on(self.client.register_certificate_without_ca(input))
+    }
+
+    /// Blocking version of [`Iot::register_thing`].
+    pub fn register_thing(
+        &self,
+        input: RegisterThingRequest,
+    ) -> Result<RegisterThingResponse, RusotoError<RegisterThingError>> {
+        self.runtime.block_on(self.client.register_thing(input))
+    }
+
+    /// Blocking version of [`Iot::reject_certificate_transfer`].
+    pub fn reject_certificate_transfer(
+        &self,
+        input: RejectCertificateTransferRequest,
+    ) -> Result<(), RusotoError<RejectCertificateTransferError>> {
+        self.runtime
+            .block_on(self.client.reject_certificate_transfer(input))
+    }
+
+    /// Blocking version of [`Iot::remove_thing_from_billing_group`].
+    pub fn remove_thing_from_billing_group(
+        &self,
+        input: RemoveThingFromBillingGroupRequest,
+    ) -> Result<RemoveThingFromBillingGroupResponse, RusotoError<RemoveThingFromBillingGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.remove_thing_from_billing_group(input))
+    }
+
+    /// Blocking version of [`Iot::remove_thing_from_thing_group`].
+    pub fn remove_thing_from_thing_group(
+        &self,
+        input: RemoveThingFromThingGroupRequest,
+    ) -> Result<RemoveThingFromThingGroupResponse, RusotoError<RemoveThingFromThingGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.remove_thing_from_thing_group(input))
+    }
+
+    /// Blocking version of [`Iot::replace_topic_rule`].
+    pub fn replace_topic_rule(
+        &self,
+        input: ReplaceTopicRuleRequest,
+    ) -> Result<(), RusotoError<ReplaceTopicRuleError>> {
+        self.runtime.block_on(self.client.replace_topic_rule(input))
+    }
+
+    /// Blocking version of [`Iot::search_index`].
+    pub fn search_index(
+        &self,
+        input: SearchIndexRequest,
+    ) -> Result<SearchIndexResponse, RusotoError<SearchIndexError>> {
+        self.runtime.block_on(self.client.search_index(input))
+    }
+
+    /// Blocking version of [`Iot::set_default_authorizer`].
+    pub fn set_default_authorizer(
+        &self,
+        input: SetDefaultAuthorizerRequest,
+    ) -> Result<SetDefaultAuthorizerResponse, RusotoError<SetDefaultAuthorizerError>> {
+        self.runtime
+            .block_on(self.client.set_default_authorizer(input))
+    }
+
+    /// Blocking version of [`Iot::set_default_policy_version`].
+    pub fn set_default_policy_version(
+        &self,
+        input: SetDefaultPolicyVersionRequest,
+    ) -> Result<(), RusotoError<SetDefaultPolicyVersionError>> {
+        self.runtime
+            .block_on(self.client.set_default_policy_version(input))
+    }
+
+    /// Blocking version of [`Iot::set_logging_options`].
+    pub fn set_logging_options(
+        &self,
+        input: SetLoggingOptionsRequest,
+    ) -> Result<(), RusotoError<SetLoggingOptionsError>> {
+        self.runtime
+            .block_on(self.client.set_logging_options(input))
+    }
+
+    /// Blocking version of [`Iot::set_v2_logging_level`].
+    pub fn set_v2_logging_level(
+        &self,
+        input: SetV2LoggingLevelRequest,
+    ) -> Result<(), RusotoError<SetV2LoggingLevelError>> {
+        self.runtime
+            .block_on(self.client.set_v2_logging_level(input))
+    }
+
+    /// Blocking version of [`Iot::set_v2_logging_options`].
+    pub fn set_v2_logging_options(
+        &self,
+        input: SetV2LoggingOptionsRequest,
+    ) -> Result<(), RusotoError<SetV2LoggingOptionsError>> {
+        self.runtime
+            .block_on(self.client.set_v2_logging_options(input))
+    }
+
+    /// Blocking version of [`Iot::start_audit_mitigation_actions_task`].
+    pub fn start_audit_mitigation_actions_task(
+        &self,
+        input: StartAuditMitigationActionsTaskRequest,
+    ) -> Result<
+        StartAuditMitigationActionsTaskResponse,
+        RusotoError<StartAuditMitigationActionsTaskError>,
+    > {
+        self.runtime
+            .block_on(self.client.start_audit_mitigation_actions_task(input))
+    }
+
+    /// Blocking version of [`Iot::start_detect_mitigation_actions_task`].
+    pub fn start_detect_mitigation_actions_task(
+        &self,
+        input: StartDetectMitigationActionsTaskRequest,
+    ) -> Result<
+        StartDetectMitigationActionsTaskResponse,
+        RusotoError<StartDetectMitigationActionsTaskError>,
+    > {
+        self.runtime
+            .block_on(self.client.start_detect_mitigation_actions_task(input))
+    }
+
+    /// Blocking version of [`Iot::start_on_demand_audit_task`].
+    pub fn start_on_demand_audit_task(
+        &self,
+        input: StartOnDemandAuditTaskRequest,
+    ) -> Result<StartOnDemandAuditTaskResponse, RusotoError<StartOnDemandAuditTaskError>> {
+        self.runtime
+            .block_on(self.client.start_on_demand_audit_task(input))
+    }
+
+    /// Blocking version of [`Iot::start_thing_registration_task`].
+    pub fn start_thing_registration_task(
+        &self,
+        input: StartThingRegistrationTaskRequest,
+    ) -> Result<StartThingRegistrationTaskResponse, RusotoError<StartThingRegistrationTaskError>>
+    {
+        self.runtime
+            .block_on(self.client.start_thing_registration_task(input))
+    }
+
+    /// Blocking version of [`Iot::stop_thing_registration_task`].
+    pub fn stop_thing_registration_task(
+        &self,
+        input: StopThingRegistrationTaskRequest,
+    ) -> Result<StopThingRegistrationTaskResponse, RusotoError<StopThingRegistrationTaskError>>
+    {
+        self.runtime
+            .block_on(self.client.stop_thing_registration_task(input))
+    }
+
+    /// Blocking version of [`Iot::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`Iot::test_authorization`].
+    pub fn test_authorization(
+        &self,
+        input: TestAuthorizationRequest,
+    ) -> Result<TestAuthorizationResponse, RusotoError<TestAuthorizationError>> {
+        self.runtime.block_on(self.client.test_authorization(input))
+    }
+
+    /// Blocking version of [`Iot::test_invoke_authorizer`].
+    pub fn test_invoke_authorizer(
+        &self,
+        input: TestInvokeAuthorizerRequest,
+    ) -> Result<TestInvokeAuthorizerResponse, RusotoError<TestInvokeAuthorizerError>> {
+        self.runtime
+            .block_on(self.client.test_invoke_authorizer(input))
+    }
+
+    /// Blocking version of [`Iot::transfer_certificate`].
+    pub fn transfer_certificate(
+        &self,
+        input: TransferCertificateRequest,
+    ) -> Result<TransferCertificateResponse, RusotoError<TransferCertificateError>> {
+        self.runtime
+            .block_on(self.client.transfer_certificate(input))
+    }
+
+    /// Blocking version of [`Iot::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`Iot::update_account_audit_configuration`].
+    pub fn update_account_audit_configuration(
+        &self,
+        input: UpdateAccountAuditConfigurationRequest,
+    ) -> Result<
+        UpdateAccountAuditConfigurationResponse,
+        RusotoError<UpdateAccountAuditConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_account_audit_configuration(input))
+    }
+
+    /// Blocking version of [`Iot::update_audit_suppression`].
+    pub fn update_audit_suppression(
+        &self,
+        input: UpdateAuditSuppressionRequest,
+    ) -> Result<UpdateAuditSuppressionResponse, RusotoError<UpdateAuditSuppressionError>> {
+        self.runtime
+            .block_on(self.client.update_audit_suppression(input))
+    }
+
+    /// Blocking version of [`Iot::update_authorizer`].
+    pub fn update_authorizer(
+        &self,
+        input: UpdateAuthorizerRequest,
+    ) -> Result<UpdateAuthorizerResponse, RusotoError<UpdateAuthorizerError>> {
+        self.runtime.block_on(self.client.update_authorizer(input))
+    }
+
+    /// Blocking version of [`Iot::update_billing_group`].
+    pub fn update_billing_group(
+        &self,
+        input: UpdateBillingGroupRequest,
+    ) -> Result<UpdateBillingGroupResponse, RusotoError<UpdateBillingGroupError>> {
+        self.runtime
+            .block_on(self.client.update_billing_group(input))
+    }
+
+    /// Blocking version of [`Iot::update_ca_certificate`].
+    pub fn update_ca_certificate(
+        &self,
+        input: UpdateCACertificateRequest,
+    ) -> Result<(), RusotoError<UpdateCACertificateError>> {
+        self.runtime
+            .block_on(self.client.update_ca_certificate(input))
+    }
+
+    /// Blocking version of [`Iot::update_certificate`].
+    pub fn update_certificate(
+        &self,
+        input: UpdateCertificateRequest,
+    ) -> Result<(), RusotoError<UpdateCertificateError>> {
+        self.runtime.block_on(self.client.update_certificate(input))
+    }
+
+    /// Blocking version of [`Iot::update_custom_metric`].
+    pub fn update_custom_metric(
+        &self,
+        input: UpdateCustomMetricRequest,
+    ) -> Result<UpdateCustomMetricResponse, RusotoError<UpdateCustomMetricError>> {
+        self.runtime
+            .block_on(self.client.update_custom_metric(input))
+    }
+
+    /// Blocking version of [`Iot::update_dimension`].
+    pub fn update_dimension(
+        &self,
+        input: UpdateDimensionRequest,
+    ) -> Result<UpdateDimensionResponse, RusotoError<UpdateDimensionError>> {
+        self.runtime.block_on(self.client.update_dimension(input))
+    }
+
+    /// Blocking version of [`Iot::update_domain_configuration`].
+    pub fn update_domain_configuration(
+        &self,
+        input: UpdateDomainConfigurationRequest,
+    ) -> Result<UpdateDomainConfigurationResponse, RusotoError<UpdateDomainConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.update_domain_configuration(input))
+    }
+
+    /// Blocking version of [`Iot::update_dynamic_thing_group`].
+    pub fn update_dynamic_thing_group(
+        &self,
+        input: UpdateDynamicThingGroupRequest,
+    ) -> Result<UpdateDynamicThingGroupResponse, RusotoError<UpdateDynamicThingGroupError>> {
+        self.runtime
+            .block_on(self.client.update_dynamic_thing_group(input))
+    }
+
+    /// Blocking version of [`Iot::update_event_configurations`].
+    pub fn update_event_configurations(
+        &self,
+        input: UpdateEventConfigurationsRequest,
+    ) -> Result<UpdateEventConfigurationsResponse, RusotoError<UpdateEventConfigurationsError>>
+    {
+        self.runtime
+            .block_on(self.client.update_event_configurations(input))
+    }
+
+    /// Blocking version of [`Iot::update_indexing_configuration`].
+    pub fn update_indexing_configuration(
+        &self,
+        input: UpdateIndexingConfigurationRequest,
+    ) -> Result<UpdateIndexingConfigurationResponse, RusotoError<UpdateIndexingConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.update_indexing_configuration(input))
+    }
+
+    /// Blocking version of [`Iot::update_job`].
+    pub fn update_job(&self, input: UpdateJobRequest) -> Result<(), RusotoError<UpdateJobError>> {
+        self.runtime.block_on(self.client.update_job(input))
+    }
+
+    /// Blocking version of [`Iot::update_mitigation_action`].
+    pub fn update_mitigation_action(
+        &self,
+        input: UpdateMitigationActionRequest,
+    ) -> Result<UpdateMitigationActionResponse, RusotoError<UpdateMitigationActionError>> {
+        self.runtime
+            .block_on(self.client.update_mitigation_action(input))
+    }
+
+    /// Blocking version of [`Iot::update_provisioning_template`].
+    pub fn update_provisioning_template(
+        &self,
+        input: UpdateProvisioningTemplateRequest,
+    ) -> Result<UpdateProvisioningTemplateResponse, RusotoError<UpdateProvisioningTemplateError>>
+    {
+        self.runtime
+            .block_on(self.client.update_provisioning_template(input))
+    }
+
+    /// Blocking version of [`Iot::update_role_alias`].
+    pub fn update_role_alias(
+        &self,
+        input: UpdateRoleAliasRequest,
+    ) -> Result<UpdateRoleAliasResponse, RusotoError<UpdateRoleAliasError>> {
+        self.runtime.block_on(self.client.update_role_alias(input))
+    }
+
+    /// Blocking version of [`Iot::update_scheduled_audit`].
+    pub fn update_scheduled_audit(
+        &self,
+        input: UpdateScheduledAuditRequest,
+    ) -> Result<UpdateScheduledAuditResponse, RusotoError<UpdateScheduledAuditError>> {
+        self.runtime
+            .block_on(self.client.update_scheduled_audit(input))
+    }
+
+    /// Blocking version of [`Iot::update_security_profile`].
+    pub fn update_security_profile(
+        &self,
+        input: UpdateSecurityProfileRequest,
+    ) -> Result<UpdateSecurityProfileResponse, RusotoError<UpdateSecurityProfileError>> {
+        self.runtime
+            .block_on(self.client.update_security_profile(input))
+    }
+
+    /// Blocking version of [`Iot::update_stream`].
+    pub fn update_stream(
+        &self,
+        input: UpdateStreamRequest,
+    ) -> Result<UpdateStreamResponse, RusotoError<UpdateStreamError>> {
+        self.runtime.block_on(self.client.update_stream(input))
+    }
+
+    /// Blocking version of [`Iot::update_thing`].
+    pub fn update_thing(
+        &self,
+        input: UpdateThingRequest,
+    ) -> Result<UpdateThingResponse, RusotoError<UpdateThingError>> {
+        self.runtime.block_on(self.client.update_thing(input))
+    }
+
+    /// Blocking version of [`Iot::update_thing_group`].
+    pub fn update_thing_group(
+        &self,
+        input: UpdateThingGroupRequest,
+    ) -> Result<UpdateThingGroupResponse, RusotoError<UpdateThingGroupError>> {
+        self.runtime.block_on(self.client.update_thing_group(input))
+    }
+
+    /// Blocking version of [`Iot::update_thing_groups_for_thing`].
+    pub fn update_thing_groups_for_thing(
+        &self,
+        input: UpdateThingGroupsForThingRequest,
+    ) -> Result<UpdateThingGroupsForThingResponse, RusotoError<UpdateThingGroupsForThingError>>
+    {
+        self.runtime
+            .block_on(self.client.update_thing_groups_for_thing(input))
+    }
+
+    /// Blocking version of [`Iot::update_topic_rule_destination`].
+    pub fn update_topic_rule_destination(
+        &self,
+        input: UpdateTopicRuleDestinationRequest,
+    ) -> Result<UpdateTopicRuleDestinationResponse, RusotoError<UpdateTopicRuleDestinationError>>
+    {
+        self.runtime
+            .block_on(self.client.update_topic_rule_destination(input))
+    }
+
+    /// Blocking version of [`Iot::validate_security_profile_behaviors`].
+    pub fn validate_security_profile_behaviors(
+        &self,
+        input: ValidateSecurityProfileBehaviorsRequest,
+    ) -> Result<
+        ValidateSecurityProfileBehaviorsResponse,
+        RusotoError<ValidateSecurityProfileBehaviorsError>,
+    > {
+        self.runtime
+            .block_on(self.client.validate_security_profile_behaviors(input))
+    }
+}
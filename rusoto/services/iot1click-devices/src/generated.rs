@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -397,19 +398,30 @@ impl ClaimDevicesByClaimCodeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(ClaimDevicesByClaimCodeError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ClaimDevicesByClaimCodeError::Forbidden,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ClaimDevicesByClaimCodeError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ClaimDevicesByClaimCodeError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ClaimDevicesByClaimCodeError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ClaimDevicesByClaimCodeError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -442,15 +454,30 @@ impl DescribeDeviceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeDeviceError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDeviceError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeDeviceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDeviceError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDeviceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDeviceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -487,27 +514,46 @@ impl FinalizeDeviceClaimError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(FinalizeDeviceClaimError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        FinalizeDeviceClaimError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(FinalizeDeviceClaimError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        FinalizeDeviceClaimError::InvalidRequest,
+                    )
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(FinalizeDeviceClaimError::PreconditionFailed(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        FinalizeDeviceClaimError::PreconditionFailed,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(FinalizeDeviceClaimError::ResourceConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        FinalizeDeviceClaimError::ResourceConflict,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(FinalizeDeviceClaimError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        FinalizeDeviceClaimError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -542,15 +588,30 @@ impl GetDeviceMethodsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetDeviceMethodsError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeviceMethodsError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetDeviceMethodsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeviceMethodsError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDeviceMethodsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeviceMethodsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -585,22 +646,38 @@ impl InitiateDeviceClaimError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(InitiateDeviceClaimError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InitiateDeviceClaimError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(InitiateDeviceClaimError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InitiateDeviceClaimError::InvalidRequest,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(InitiateDeviceClaimError::ResourceConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        InitiateDeviceClaimError::ResourceConflict,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(InitiateDeviceClaimError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        InitiateDeviceClaimError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -640,28 +717,54 @@ impl InvokeDeviceMethodError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(InvokeDeviceMethodError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InvokeDeviceMethodError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(InvokeDeviceMethodError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InvokeDeviceMethodError::InvalidRequest,
+                    )
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(InvokeDeviceMethodError::PreconditionFailed(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        InvokeDeviceMethodError::PreconditionFailed,
+                    )
                 }
                 "RangeNotSatisfiableException" => {
-                    return RusotoError::Service(InvokeDeviceMethodError::RangeNotSatisfiable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        InvokeDeviceMethodError::RangeNotSatisfiable,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(InvokeDeviceMethodError::ResourceConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InvokeDeviceMethodError::ResourceConflict,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(InvokeDeviceMethodError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InvokeDeviceMethodError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -699,20 +802,38 @@ impl ListDeviceEventsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListDeviceEventsError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDeviceEventsError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListDeviceEventsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDeviceEventsError::InvalidRequest,
+                    )
                 }
                 "RangeNotSatisfiableException" => {
-                    return RusotoError::Service(ListDeviceEventsError::RangeNotSatisfiable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDeviceEventsError::RangeNotSatisfiable,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDeviceEventsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDeviceEventsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -746,15 +867,30 @@ impl ListDevicesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListDevicesError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDevicesError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListDevicesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDevicesError::InvalidRequest,
+                    )
                 }
                 "RangeNotSatisfiableException" => {
-                    return RusotoError::Service(ListDevicesError::RangeNotSatisfiable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDevicesError::RangeNotSatisfiable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -785,14 +921,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InternalFailure,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -824,15 +968,30 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(TagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -865,15 +1024,30 @@ impl UnclaimDeviceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UnclaimDeviceError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UnclaimDeviceError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UnclaimDeviceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UnclaimDeviceError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UnclaimDeviceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UnclaimDeviceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -906,15 +1080,30 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UntagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -947,15 +1136,30 @@ impl UpdateDeviceStateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateDeviceStateError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeviceStateError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateDeviceStateError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeviceStateError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDeviceStateError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeviceStateError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1081,11 +1285,12 @@ pub struct Iot1ClickDevicesClient {
 impl Iot1ClickDevicesClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> Iot1ClickDevicesClient {
         Iot1ClickDevicesClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "IoT 1Click Devices Service"),
         }
     }
 
@@ -1100,12 +1305,32 @@ impl Iot1ClickDevicesClient {
     {
         Iot1ClickDevicesClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "IoT 1Click Devices Service"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> Iot1ClickDevicesClient {
-        Iot1ClickDevicesClient { client, region }
+        Iot1ClickDevicesClient {
+            client,
+            region: region_for_service(region, "IoT 1Click Devices Service"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> Iot1ClickDevicesClient {
+        Iot1ClickDevicesClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("IoT 1Click Devices Service"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> Iot1ClickDevicesClient {
+        Iot1ClickDevicesClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -1121,6 +1346,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/claims/{claim_code}", claim_code = input.claim_code);
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation("ClaimDevicesByClaimCode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1152,6 +1378,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/devices/{device_id}", device_id = input.device_id);
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("DescribeDevice");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1190,6 +1417,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         );
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation("FinalizeDeviceClaim");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1222,6 +1450,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/devices/{device_id}/methods", device_id = input.device_id);
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("GetDeviceMethods");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1260,6 +1489,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         );
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation("InitiateDeviceClaim");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1291,6 +1521,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/devices/{device_id}/methods", device_id = input.device_id);
 
         let mut request = SignedRequest::new("POST", "iot1click", &self.region, &request_uri);
+        request.set_operation("InvokeDeviceMethod");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1324,6 +1555,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/devices/{device_id}/events", device_id = input.device_id);
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("ListDeviceEvents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1365,6 +1597,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = "/devices";
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("ListDevices");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1407,6 +1640,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "iot1click", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1438,6 +1672,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "iot1click", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1469,6 +1704,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/devices/{device_id}/unclaim", device_id = input.device_id);
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation("UnclaimDevice");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1500,6 +1736,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "iot1click", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1536,6 +1773,7 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         let request_uri = format!("/devices/{device_id}/state", device_id = input.device_id);
 
         let mut request = SignedRequest::new("PUT", "iot1click", &self.region, &request_uri);
+        request.set_operation("UpdateDeviceState");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("devices.iot1click".to_string());
@@ -1559,3 +1797,196 @@ impl Iot1ClickDevices for Iot1ClickDevicesClient {
         }
     }
 }
+
+/// A blocking client for the AWS IoT 1-Click Devices Service API, running the calls of a `Iot1ClickDevicesClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `Iot1ClickDevicesClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct Iot1ClickDevicesBlockingClient {
+    client: Iot1ClickDevicesClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl Iot1ClickDevicesBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> Iot1ClickDevicesBlockingClient {
+        Iot1ClickDevicesBlockingClient::from_client(Iot1ClickDevicesClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> Iot1ClickDevicesBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        Iot1ClickDevicesBlockingClient::from_client(Iot1ClickDevicesClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> Iot1ClickDevicesBlockingClient {
+        Iot1ClickDevicesBlockingClient::from_client(Iot1ClickDevicesClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> Iot1ClickDevicesBlockingClient {
+        Iot1ClickDevicesBlockingClient::from_client(Iot1ClickDevicesClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: Iot1ClickDevicesClient) -> Iot1ClickDevicesBlockingClient {
+        Iot1ClickDevicesBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> Iot1ClickDevicesBlockingClient {
+        Iot1ClickDevicesBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> Iot1ClickDevicesBlockingClient {
+        Iot1ClickDevicesBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Iot1ClickDevices::claim_devices_by_claim_code`].
+    pub fn claim_devices_by_claim_code(
+        &self,
+        input: ClaimDevicesByClaimCodeRequest,
+    ) -> Result<ClaimDevicesByClaimCodeResponse, RusotoError<ClaimDevicesByClaimCodeError>> {
+        self.runtime
+            .block_on(self.client.claim_devices_by_claim_code(input))
+    }
+
+    /// Blocking version of [`Iot1ClickDevices::describe_device`].
+    pub fn describe_device(
+        &self,
+        input: DescribeDeviceRequest,
+    ) -> Result<DescribeDeviceResponse, RusotoError<DescribeDeviceError>> {
+        self.runtime.block_on(self.client.describe_device(input))
+    }
+
+    /// Blocking version of [`Iot1ClickDevices::finalize_device_claim`].
+    pub fn finalize_device_claim(
+        &self,
+        input: FinalizeDeviceClaimRequest,
+    ) -> Result<FinalizeDeviceClaimResponse, RusotoError<FinalizeDeviceClaimError>> {
+        self.runtime
+            .block_on(self.client.finalize_device_claim(input))
+    }
+
+    /// Blocking version of [`Iot1ClickDevices::get_device_methods`].
+    pub fn get_device_methods(
+        &self,
+        input: GetDeviceMethodsRequest,
+    ) -> Result<GetDeviceMethodsResponse, RusotoError<GetDeviceMethodsError>> {
+        self.runtime.block_on(self.client.get_device_methods(input))
+    }
+
+    /// Blocking version of [`Iot1ClickDevices::initiate_device_claim`].
+    pub fn initiate_device_claim(
+        &self,
+        input: InitiateDeviceClaimRequest,
+    ) -> Result<InitiateDeviceClaimResponse, RusotoError<InitiateDeviceClaimError>> {
+        self.runtime
+            .block_on(self.client.initiate_device_claim(input))
+    }
+
+    /// Blocking version of [`Iot1ClickDevices::invoke_device_method`].
+    pub fn invoke_device_method(
+        &self,
+        input: InvokeDeviceMethodRequest,
+    ) -> Result<InvokeDeviceMethodResponse, RusotoError<InvokeDeviceMethodError>> {
+        self.runtime
+            .block_on(self.client.invoke_device_method(input))
+    }
+
+    /// Blocking version of [`Iot1ClickDevices::list_device_events`].
+    pub fn list_device_events(
+        &self,
+        input: ListDeviceEventsRequest,
+    ) -> Result<ListDeviceEventsResponse, RusotoError<ListDeviceEventsError>> {
+        self.runtime.block_on(self.client.list_device_events(input))
+    }
+
+    /// Blocking version of [`Iot1ClickDevices::list_devices`].
+    pub fn list_devices(
+        &self,
+        input: ListDevicesRequest,
+    ) -> Result<ListDevicesResponse, RusotoError<ListDevicesError>> {
+        self.runtime.block_on(self.client.list_devices(input))
+    }
+
+    /// Blocking version of [`Iot1ClickDevices::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`Iot1ClickDevices::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`Iot1ClickDevices::unclaim_device`].
+    pub fn unclaim_device(
+        &self,
+        input: UnclaimDeviceRequest,
+    ) -> Result<UnclaimDeviceResponse, RusotoError<UnclaimDeviceError>> {
+        self.runtime.block_on(self.client.unclaim_device(input))
+    }
+
+    /// Blocking version of [`Iot1ClickDevices::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`Iot1ClickDevices::update_device_state`].
+    pub fn update_device_state(
+        &self,
+        input: UpdateDeviceStateRequest,
+    ) -> Result<UpdateDeviceStateResponse, RusotoError<UpdateDeviceStateError>> {
+        self.runtime
+            .block_on(self.client.update_device_state(input))
+    }
+}
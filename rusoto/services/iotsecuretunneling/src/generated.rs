@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -346,9 +347,14 @@ impl CloseTunnelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CloseTunnelError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CloseTunnelError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -376,9 +382,14 @@ impl DescribeTunnelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeTunnelError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTunnelError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -406,11 +417,14 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -434,7 +448,7 @@ impl ListTunnelsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListTunnelsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -460,9 +474,14 @@ impl OpenTunnelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(OpenTunnelError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        OpenTunnelError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -490,9 +509,14 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -520,9 +544,14 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -593,11 +622,12 @@ pub struct IoTSecureTunnelingClient {
 impl IoTSecureTunnelingClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> IoTSecureTunnelingClient {
         IoTSecureTunnelingClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "IoTSecureTunneling"),
         }
     }
 
@@ -612,12 +642,32 @@ impl IoTSecureTunnelingClient {
     {
         IoTSecureTunnelingClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "IoTSecureTunneling"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> IoTSecureTunnelingClient {
-        IoTSecureTunnelingClient { client, region }
+        IoTSecureTunnelingClient {
+            client,
+            region: region_for_service(region, "IoTSecureTunneling"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> IoTSecureTunnelingClient {
+        IoTSecureTunnelingClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("IoTSecureTunneling"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> IoTSecureTunnelingClient {
+        IoTSecureTunnelingClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -629,6 +679,7 @@ impl IoTSecureTunneling for IoTSecureTunnelingClient {
         input: CloseTunnelRequest,
     ) -> Result<CloseTunnelResponse, RusotoError<CloseTunnelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CloseTunnel");
         request.add_header("x-amz-target", "IoTSecuredTunneling.CloseTunnel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -647,6 +698,7 @@ impl IoTSecureTunneling for IoTSecureTunnelingClient {
         input: DescribeTunnelRequest,
     ) -> Result<DescribeTunnelResponse, RusotoError<DescribeTunnelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeTunnel");
         request.add_header("x-amz-target", "IoTSecuredTunneling.DescribeTunnel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -665,6 +717,7 @@ impl IoTSecureTunneling for IoTSecureTunnelingClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header("x-amz-target", "IoTSecuredTunneling.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -683,6 +736,7 @@ impl IoTSecureTunneling for IoTSecureTunnelingClient {
         input: ListTunnelsRequest,
     ) -> Result<ListTunnelsResponse, RusotoError<ListTunnelsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTunnels");
         request.add_header("x-amz-target", "IoTSecuredTunneling.ListTunnels");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -701,6 +755,7 @@ impl IoTSecureTunneling for IoTSecureTunnelingClient {
         input: OpenTunnelRequest,
     ) -> Result<OpenTunnelResponse, RusotoError<OpenTunnelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("OpenTunnel");
         request.add_header("x-amz-target", "IoTSecuredTunneling.OpenTunnel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -719,6 +774,7 @@ impl IoTSecureTunneling for IoTSecureTunnelingClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "IoTSecuredTunneling.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -737,6 +793,7 @@ impl IoTSecureTunneling for IoTSecureTunnelingClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "IoTSecuredTunneling.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -749,3 +806,145 @@ impl IoTSecureTunneling for IoTSecureTunnelingClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceResponse, _>()
     }
 }
+
+/// A blocking client for the AWS IoT Secure Tunneling API, running the calls of a `IoTSecureTunnelingClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `IoTSecureTunnelingClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct IoTSecureTunnelingBlockingClient {
+    client: IoTSecureTunnelingClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl IoTSecureTunnelingBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> IoTSecureTunnelingBlockingClient {
+        IoTSecureTunnelingBlockingClient::from_client(IoTSecureTunnelingClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> IoTSecureTunnelingBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        IoTSecureTunnelingBlockingClient::from_client(IoTSecureTunnelingClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> IoTSecureTunnelingBlockingClient {
+        IoTSecureTunnelingBlockingClient::from_client(IoTSecureTunnelingClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> IoTSecureTunnelingBlockingClient {
+        IoTSecureTunnelingBlockingClient::from_client(IoTSecureTunnelingClient::new_with_config(
+            config,
+        ))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: IoTSecureTunnelingClient) -> IoTSecureTunnelingBlockingClient {
+        IoTSecureTunnelingBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> IoTSecureTunnelingBlockingClient {
+        IoTSecureTunnelingBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> IoTSecureTunnelingBlockingClient {
+        IoTSecureTunnelingBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`IoTSecureTunneling::close_tunnel`].
+    pub fn close_tunnel(
+        &self,
+        input: CloseTunnelRequest,
+    ) -> Result<CloseTunnelResponse, RusotoError<CloseTunnelError>> {
+        self.runtime.block_on(self.client.close_tunnel(input))
+    }
+
+    /// Blocking version of [`IoTSecureTunneling::describe_tunnel`].
+    pub fn describe_tunnel(
+        &self,
+        input: DescribeTunnelRequest,
+    ) -> Result<DescribeTunnelResponse, RusotoError<DescribeTunnelError>> {
+        self.runtime.block_on(self.client.describe_tunnel(input))
+    }
+
+    /// Blocking version of [`IoTSecureTunneling::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`IoTSecureTunneling::list_tunnels`].
+    pub fn list_tunnels(
+        &self,
+        input: ListTunnelsRequest,
+    ) -> Result<ListTunnelsResponse, RusotoError<ListTunnelsError>> {
+        self.runtime.block_on(self.client.list_tunnels(input))
+    }
+
+    /// Blocking version of [`IoTSecureTunneling::open_tunnel`].
+    pub fn open_tunnel(
+        &self,
+        input: OpenTunnelRequest,
+    ) -> Result<OpenTunnelResponse, RusotoError<OpenTunnelError>> {
+        self.runtime.block_on(self.client.open_tunnel(input))
+    }
+
+    /// Blocking version of [`IoTSecureTunneling::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`IoTSecureTunneling::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1248,24 +1249,38 @@ impl AssociateEntityToThingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(AssociateEntityToThingError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateEntityToThingError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AssociateEntityToThingError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateEntityToThingError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateEntityToThingError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateEntityToThingError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AssociateEntityToThingError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateEntityToThingError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1304,23 +1319,46 @@ impl CreateFlowTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateFlowTemplateError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFlowTemplateError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateFlowTemplateError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFlowTemplateError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateFlowTemplateError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFlowTemplateError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateFlowTemplateError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateFlowTemplateError::ResourceAlreadyExists,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateFlowTemplateError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFlowTemplateError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1360,25 +1398,46 @@ impl CreateSystemInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateSystemInstanceError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSystemInstanceError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateSystemInstanceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSystemInstanceError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateSystemInstanceError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSystemInstanceError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateSystemInstanceError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSystemInstanceError::ResourceAlreadyExists,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateSystemInstanceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSystemInstanceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1416,22 +1475,38 @@ impl CreateSystemTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateSystemTemplateError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSystemTemplateError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateSystemTemplateError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSystemTemplateError::InvalidRequest,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateSystemTemplateError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSystemTemplateError::ResourceAlreadyExists,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateSystemTemplateError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSystemTemplateError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1468,18 +1543,38 @@ impl DeleteFlowTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteFlowTemplateError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFlowTemplateError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteFlowTemplateError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFlowTemplateError::InvalidRequest,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteFlowTemplateError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFlowTemplateError::ResourceInUse,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteFlowTemplateError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFlowTemplateError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1512,12 +1607,22 @@ impl DeleteNamespaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteNamespaceError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNamespaceError::InternalFailure,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteNamespaceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNamespaceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1552,20 +1657,38 @@ impl DeleteSystemInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteSystemInstanceError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSystemInstanceError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteSystemInstanceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSystemInstanceError::InvalidRequest,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteSystemInstanceError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSystemInstanceError::ResourceInUse,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteSystemInstanceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSystemInstanceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1602,20 +1725,38 @@ impl DeleteSystemTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteSystemTemplateError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSystemTemplateError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteSystemTemplateError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSystemTemplateError::InvalidRequest,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteSystemTemplateError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSystemTemplateError::ResourceInUse,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteSystemTemplateError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSystemTemplateError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1654,25 +1795,46 @@ impl DeploySystemInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeploySystemInstanceError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeploySystemInstanceError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeploySystemInstanceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeploySystemInstanceError::InvalidRequest,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeploySystemInstanceError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeploySystemInstanceError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeploySystemInstanceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeploySystemInstanceError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeploySystemInstanceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeploySystemInstanceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1710,24 +1872,38 @@ impl DeprecateFlowTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeprecateFlowTemplateError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeprecateFlowTemplateError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeprecateFlowTemplateError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeprecateFlowTemplateError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeprecateFlowTemplateError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeprecateFlowTemplateError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeprecateFlowTemplateError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeprecateFlowTemplateError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1764,24 +1940,38 @@ impl DeprecateSystemTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeprecateSystemTemplateError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeprecateSystemTemplateError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeprecateSystemTemplateError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeprecateSystemTemplateError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeprecateSystemTemplateError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeprecateSystemTemplateError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeprecateSystemTemplateError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeprecateSystemTemplateError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1818,18 +2008,38 @@ impl DescribeNamespaceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeNamespaceError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeNamespaceError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeNamespaceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeNamespaceError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeNamespaceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeNamespaceError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeNamespaceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeNamespaceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1866,26 +2076,38 @@ impl DissociateEntityFromThingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DissociateEntityFromThingError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DissociateEntityFromThingError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DissociateEntityFromThingError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DissociateEntityFromThingError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DissociateEntityFromThingError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DissociateEntityFromThingError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DissociateEntityFromThingError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DissociateEntityFromThingError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1922,18 +2144,38 @@ impl GetEntitiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetEntitiesError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEntitiesError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetEntitiesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEntitiesError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetEntitiesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEntitiesError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetEntitiesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEntitiesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1970,18 +2212,38 @@ impl GetFlowTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetFlowTemplateError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFlowTemplateError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetFlowTemplateError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFlowTemplateError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetFlowTemplateError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFlowTemplateError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetFlowTemplateError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFlowTemplateError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2018,24 +2280,38 @@ impl GetFlowTemplateRevisionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetFlowTemplateRevisionsError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetFlowTemplateRevisionsError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetFlowTemplateRevisionsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetFlowTemplateRevisionsError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetFlowTemplateRevisionsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetFlowTemplateRevisionsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetFlowTemplateRevisionsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFlowTemplateRevisionsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2072,21 +2348,30 @@ impl GetNamespaceDeletionStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetNamespaceDeletionStatusError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetNamespaceDeletionStatusError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetNamespaceDeletionStatusError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetNamespaceDeletionStatusError::InvalidRequest,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetNamespaceDeletionStatusError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetNamespaceDeletionStatusError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2122,18 +2407,38 @@ impl GetSystemInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetSystemInstanceError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSystemInstanceError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetSystemInstanceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSystemInstanceError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetSystemInstanceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSystemInstanceError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetSystemInstanceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSystemInstanceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2170,18 +2475,38 @@ impl GetSystemTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetSystemTemplateError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSystemTemplateError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetSystemTemplateError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSystemTemplateError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetSystemTemplateError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSystemTemplateError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetSystemTemplateError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSystemTemplateError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2220,26 +2545,38 @@ impl GetSystemTemplateRevisionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetSystemTemplateRevisionsError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSystemTemplateRevisionsError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetSystemTemplateRevisionsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSystemTemplateRevisionsError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetSystemTemplateRevisionsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSystemTemplateRevisionsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetSystemTemplateRevisionsError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSystemTemplateRevisionsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2276,18 +2613,38 @@ impl GetUploadStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetUploadStatusError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUploadStatusError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetUploadStatusError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUploadStatusError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetUploadStatusError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUploadStatusError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetUploadStatusError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUploadStatusError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2324,26 +2681,38 @@ impl ListFlowExecutionMessagesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListFlowExecutionMessagesError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListFlowExecutionMessagesError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListFlowExecutionMessagesError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListFlowExecutionMessagesError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListFlowExecutionMessagesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListFlowExecutionMessagesError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListFlowExecutionMessagesError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListFlowExecutionMessagesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2380,20 +2749,38 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InvalidRequest,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceAlreadyExists,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2428,15 +2815,30 @@ impl SearchEntitiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(SearchEntitiesError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchEntitiesError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SearchEntitiesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchEntitiesError::InvalidRequest,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SearchEntitiesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchEntitiesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2472,22 +2874,38 @@ impl SearchFlowExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(SearchFlowExecutionsError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SearchFlowExecutionsError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SearchFlowExecutionsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchFlowExecutionsError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SearchFlowExecutionsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SearchFlowExecutionsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SearchFlowExecutionsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchFlowExecutionsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2522,15 +2940,30 @@ impl SearchFlowTemplatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(SearchFlowTemplatesError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchFlowTemplatesError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SearchFlowTemplatesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchFlowTemplatesError::InvalidRequest,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SearchFlowTemplatesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchFlowTemplatesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2564,19 +2997,30 @@ impl SearchSystemInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(SearchSystemInstancesError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SearchSystemInstancesError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SearchSystemInstancesError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SearchSystemInstancesError::InvalidRequest,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SearchSystemInstancesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchSystemInstancesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2610,19 +3054,30 @@ impl SearchSystemTemplatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(SearchSystemTemplatesError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SearchSystemTemplatesError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SearchSystemTemplatesError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SearchSystemTemplatesError::InvalidRequest,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SearchSystemTemplatesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchSystemTemplatesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2658,18 +3113,38 @@ impl SearchThingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(SearchThingsError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchThingsError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(SearchThingsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchThingsError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SearchThingsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchThingsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SearchThingsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchThingsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2706,18 +3181,38 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(TagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidRequest,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(TagResourceError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceAlreadyExists,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(TagResourceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2756,29 +3251,46 @@ impl UndeploySystemInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UndeploySystemInstanceError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UndeploySystemInstanceError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UndeploySystemInstanceError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UndeploySystemInstanceError::InvalidRequest,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UndeploySystemInstanceError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UndeploySystemInstanceError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UndeploySystemInstanceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UndeploySystemInstanceError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UndeploySystemInstanceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UndeploySystemInstanceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2816,18 +3328,38 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UntagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidRequest,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceAlreadyExists,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UntagResourceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2864,18 +3396,38 @@ impl UpdateFlowTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateFlowTemplateError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFlowTemplateError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateFlowTemplateError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFlowTemplateError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateFlowTemplateError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFlowTemplateError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateFlowTemplateError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFlowTemplateError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2912,22 +3464,38 @@ impl UpdateSystemTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateSystemTemplateError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSystemTemplateError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateSystemTemplateError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSystemTemplateError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateSystemTemplateError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSystemTemplateError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateSystemTemplateError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSystemTemplateError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2962,19 +3530,30 @@ impl UploadEntityDefinitionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(UploadEntityDefinitionsError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UploadEntityDefinitionsError::InternalFailure,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UploadEntityDefinitionsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UploadEntityDefinitionsError::InvalidRequest,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UploadEntityDefinitionsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UploadEntityDefinitionsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3213,11 +3792,12 @@ pub struct IotThingsGraphClient {
 impl IotThingsGraphClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> IotThingsGraphClient {
         IotThingsGraphClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "IoTThingsGraph"),
         }
     }
 
@@ -3232,12 +3812,32 @@ impl IotThingsGraphClient {
     {
         IotThingsGraphClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "IoTThingsGraph"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> IotThingsGraphClient {
-        IotThingsGraphClient { client, region }
+        IotThingsGraphClient {
+            client,
+            region: region_for_service(region, "IoTThingsGraph"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> IotThingsGraphClient {
+        IotThingsGraphClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("IoTThingsGraph"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> IotThingsGraphClient {
+        IotThingsGraphClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -3249,6 +3849,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: AssociateEntityToThingRequest,
     ) -> Result<AssociateEntityToThingResponse, RusotoError<AssociateEntityToThingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateEntityToThing");
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.AssociateEntityToThing",
@@ -3271,6 +3872,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: CreateFlowTemplateRequest,
     ) -> Result<CreateFlowTemplateResponse, RusotoError<CreateFlowTemplateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateFlowTemplate");
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.CreateFlowTemplate",
@@ -3292,6 +3894,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: CreateSystemInstanceRequest,
     ) -> Result<CreateSystemInstanceResponse, RusotoError<CreateSystemInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateSystemInstance");
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.CreateSystemInstance",
@@ -3314,6 +3917,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: CreateSystemTemplateRequest,
     ) -> Result<CreateSystemTemplateResponse, RusotoError<CreateSystemTemplateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateSystemTemplate");
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.CreateSystemTemplate",
@@ -3336,6 +3940,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: DeleteFlowTemplateRequest,
     ) -> Result<DeleteFlowTemplateResponse, RusotoError<DeleteFlowTemplateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteFlowTemplate");
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.DeleteFlowTemplate",
@@ -3356,6 +3961,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         &self,
     ) -> Result<DeleteNamespaceResponse, RusotoError<DeleteNamespaceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteNamespace");
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.DeleteNamespace",
@@ -3376,6 +3982,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: DeleteSystemInstanceRequest,
     ) -> Result<DeleteSystemInstanceResponse, RusotoError<DeleteSystemInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteSystemInstance");
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.DeleteSystemInstance",
@@ -3398,6 +4005,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: DeleteSystemTemplateRequest,
     ) -> Result<DeleteSystemTemplateResponse, RusotoError<DeleteSystemTemplateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteSystemTemplate");
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.DeleteSystemTemplate",
@@ -3420,6 +4028,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: DeploySystemInstanceRequest,
     ) -> Result<DeploySystemInstanceResponse, RusotoError<DeploySystemInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeploySystemInstance");
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.DeploySystemInstance",
@@ -3442,6 +4051,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: DeprecateFlowTemplateRequest,
     ) -> Result<DeprecateFlowTemplateResponse, RusotoError<DeprecateFlowTemplateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeprecateFlowTemplate");
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.DeprecateFlowTemplate",
@@ -3464,6 +4074,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: DeprecateSystemTemplateRequest,
     ) -> Result<DeprecateSystemTemplateResponse, RusotoError<DeprecateSystemTemplateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeprecateSystemTemplate");
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.DeprecateSystemTemplate",
@@ -3486,6 +4097,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: DescribeNamespaceRequest,
     ) -> Result<DescribeNamespaceResponse, RusotoError<DescribeNamespaceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeNamespace");
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.DescribeNamespace",
@@ -3508,6 +4120,7 @@ impl IotThingsGraph for IotThingsGraphClient {
     ) -> Result<DissociateEntityFromThingResponse, RusotoError<DissociateEntityFromThingError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DissociateEntityFromThing");
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.DissociateEntityFromThing",
@@ -3530,6 +4143,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: GetEntitiesRequest,
     ) -> Result<GetEntitiesResponse, RusotoError<GetEntitiesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetEntities");
         request.add_header("x-amz-target", "IotThingsGraphFrontEndService.GetEntities");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3548,6 +4162,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: GetFlowTemplateRequest,
     ) -> Result<GetFlowTemplateResponse, RusotoError<GetFlowTemplateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetFlowTemplate");
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.GetFlowTemplate",
@@ -3569,6 +4184,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: GetFlowTemplateRevisionsRequest,
     ) -> Result<GetFlowTemplateRevisionsResponse, RusotoError<GetFlowTemplateRevisionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetFlowTemplateRevisions");
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.GetFlowTemplateRevisions",
@@ -3591,6 +4207,7 @@ impl IotThingsGraph for IotThingsGraphClient {
     ) -> Result<GetNamespaceDeletionStatusResponse, RusotoError<GetNamespaceDeletionStatusError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetNamespaceDeletionStatus");
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.GetNamespaceDeletionStatus",
@@ -3612,6 +4229,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: GetSystemInstanceRequest,
     ) -> Result<GetSystemInstanceResponse, RusotoError<GetSystemInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetSystemInstance");
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.GetSystemInstance",
@@ -3633,6 +4251,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: GetSystemTemplateRequest,
     ) -> Result<GetSystemTemplateResponse, RusotoError<GetSystemTemplateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetSystemTemplate");
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.GetSystemTemplate",
@@ -3655,6 +4274,7 @@ impl IotThingsGraph for IotThingsGraphClient {
     ) -> Result<GetSystemTemplateRevisionsResponse, RusotoError<GetSystemTemplateRevisionsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetSystemTemplateRevisions");
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.GetSystemTemplateRevisions",
@@ -3677,6 +4297,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: GetUploadStatusRequest,
     ) -> Result<GetUploadStatusResponse, RusotoError<GetUploadStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetUploadStatus");
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.GetUploadStatus",
@@ -3699,6 +4320,7 @@ impl IotThingsGraph for IotThingsGraphClient {
     ) -> Result<ListFlowExecutionMessagesResponse, RusotoError<ListFlowExecutionMessagesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListFlowExecutionMessages");
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.ListFlowExecutionMessages",
@@ -3721,6 +4343,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.ListTagsForResource",
@@ -3742,6 +4365,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: SearchEntitiesRequest,
     ) -> Result<SearchEntitiesResponse, RusotoError<SearchEntitiesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SearchEntities");
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.SearchEntities",
@@ -3763,6 +4387,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: SearchFlowExecutionsRequest,
     ) -> Result<SearchFlowExecutionsResponse, RusotoError<SearchFlowExecutionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SearchFlowExecutions");
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.SearchFlowExecutions",
@@ -3785,6 +4410,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: SearchFlowTemplatesRequest,
     ) -> Result<SearchFlowTemplatesResponse, RusotoError<SearchFlowTemplatesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SearchFlowTemplates");
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.SearchFlowTemplates",
@@ -3806,6 +4432,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: SearchSystemInstancesRequest,
     ) -> Result<SearchSystemInstancesResponse, RusotoError<SearchSystemInstancesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SearchSystemInstances");
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.SearchSystemInstances",
@@ -3828,6 +4455,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: SearchSystemTemplatesRequest,
     ) -> Result<SearchSystemTemplatesResponse, RusotoError<SearchSystemTemplatesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SearchSystemTemplates");
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.SearchSystemTemplates",
@@ -3850,6 +4478,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: SearchThingsRequest,
     ) -> Result<SearchThingsResponse, RusotoError<SearchThingsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SearchThings");
         request.add_header("x-amz-target", "IotThingsGraphFrontEndService.SearchThings");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3868,6 +4497,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "IotThingsGraphFrontEndService.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3886,6 +4516,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: UndeploySystemInstanceRequest,
     ) -> Result<UndeploySystemInstanceResponse, RusotoError<UndeploySystemInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UndeploySystemInstance");
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.UndeploySystemInstance",
@@ -3908,6 +4539,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.UntagResource",
@@ -3929,6 +4561,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: UpdateFlowTemplateRequest,
     ) -> Result<UpdateFlowTemplateResponse, RusotoError<UpdateFlowTemplateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateFlowTemplate");
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.UpdateFlowTemplate",
@@ -3950,6 +4583,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: UpdateSystemTemplateRequest,
     ) -> Result<UpdateSystemTemplateResponse, RusotoError<UpdateSystemTemplateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateSystemTemplate");
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.UpdateSystemTemplate",
@@ -3972,6 +4606,7 @@ impl IotThingsGraph for IotThingsGraphClient {
         input: UploadEntityDefinitionsRequest,
     ) -> Result<UploadEntityDefinitionsResponse, RusotoError<UploadEntityDefinitionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UploadEntityDefinitions");
         request.add_header(
             "x-amz-target",
             "IotThingsGraphFrontEndService.UploadEntityDefinitions",
@@ -3988,3 +4623,391 @@ impl IotThingsGraph for IotThingsGraphClient {
             .deserialize::<UploadEntityDefinitionsResponse, _>()
     }
 }
+
+/// A blocking client for the AWS IoT Things Graph API, running the calls of a `IotThingsGraphClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `IotThingsGraphClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct IotThingsGraphBlockingClient {
+    client: IotThingsGraphClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl IotThingsGraphBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> IotThingsGraphBlockingClient {
+        IotThingsGraphBlockingClient::from_client(IotThingsGraphClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> IotThingsGraphBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        IotThingsGraphBlockingClient::from_client(IotThingsGraphClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> IotThingsGraphBlockingClient {
+        IotThingsGraphBlockingClient::from_client(IotThingsGraphClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> IotThingsGraphBlockingClient {
+        IotThingsGraphBlockingClient::from_client(IotThingsGraphClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: IotThingsGraphClient) -> IotThingsGraphBlockingClient {
+        IotThingsGraphBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> IotThingsGraphBlockingClient {
+        IotThingsGraphBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> IotThingsGraphBlockingClient {
+        IotThingsGraphBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`IotThingsGraph::associate_entity_to_thing`].
+    pub fn associate_entity_to_thing(
+        &self,
+        input: AssociateEntityToThingRequest,
+    ) -> Result<AssociateEntityToThingResponse, RusotoError<AssociateEntityToThingError>> {
+        self.runtime
+            .block_on(self.client.associate_entity_to_thing(input))
+    }
+
+    /// Blocking version of [`IotThingsGraph::create_flow_template`].
+    pub fn create_flow_template(
+        &self,
+        input: CreateFlowTemplateRequest,
+    ) -> Result<CreateFlowTemplateResponse, RusotoError<CreateFlowTemplateError>> {
+        self.runtime
+            .block_on(self.client.create_flow_template(input))
+    }
+
+    /// Blocking version of [`IotThingsGraph::create_system_instance`].
+    pub fn create_system_instance(
+        &self,
+        input: CreateSystemInstanceRequest,
+    ) -> Result<CreateSystemInstanceResponse, RusotoError<CreateSystemInstanceError>> {
+        self.runtime
+            .block_on(self.client.create_system_instance(input))
+    }
+
+    /// Blocking version of [`IotThingsGraph::create_system_template`].
+    pub fn create_system_template(
+        &self,
+        input: CreateSystemTemplateRequest,
+    ) -> Result<CreateSystemTemplateResponse, RusotoError<CreateSystemTemplateError>> {
+        self.runtime
+            .block_on(self.client.create_system_template(input))
+    }
+
+    /// Blocking version of [`IotThingsGraph::delete_flow_template`].
+    pub fn delete_flow_template(
+        &self,
+        input: DeleteFlowTemplateRequest,
+    ) -> Result<DeleteFlowTemplateResponse, RusotoError<DeleteFlowTemplateError>> {
+        self.runtime
+            .block_on(self.client.delete_flow_template(input))
+    }
+
+    /// Blocking version of [`IotThingsGraph::delete_namespace`].
+    pub fn delete_namespace(
+        &self,
+    ) -> Result<DeleteNamespaceResponse, RusotoError<DeleteNamespaceError>> {
+        self.runtime.block_on(self.client.delete_namespace())
+    }
+
+    /// Blocking version of [`IotThingsGraph::delete_system_instance`].
+    pub fn delete_system_instance(
+        &self,
+        input: DeleteSystemInstanceRequest,
+    ) -> Result<DeleteSystemInstanceResponse, RusotoError<DeleteSystemInstanceError>> {
+        self.runtime
+            .block_on(self.client.delete_system_instance(input))
+    }
+
+    /// Blocking version of [`IotThingsGraph::delete_system_template`].
+    pub fn delete_system_template(
+        &self,
+        input: DeleteSystemTemplateRequest,
+    ) -> Result<DeleteSystemTemplateResponse, RusotoError<DeleteSystemTemplateError>> {
+        self.runtime
+            .block_on(self.client.delete_system_template(input))
+    }
+
+    /// Blocking version of [`IotThingsGraph::deploy_system_instance`].
+    pub fn deploy_system_instance(
+        &self,
+        input: DeploySystemInstanceRequest,
+    ) -> Result<DeploySystemInstanceResponse, RusotoError<DeploySystemInstanceError>> {
+        self.runtime
+            .block_on(self.client.deploy_system_instance(input))
+    }
+
+    /// Blocking version of [`IotThingsGraph::deprecate_flow_template`].
+    pub fn deprecate_flow_template(
+        &self,
+        input: DeprecateFlowTemplateRequest,
+    ) -> Result<DeprecateFlowTemplateResponse, RusotoError<DeprecateFlowTemplateError>> {
+        self.runtime
+            .block_on(self.client.deprecate_flow_template(input))
+    }
+
+    /// Blocking version of [`IotThingsGraph::deprecate_system_template`].
+    pub fn deprecate_system_template(
+        &self,
+        input: DeprecateSystemTemplateRequest,
+    ) -> Result<DeprecateSystemTemplateResponse, RusotoError<DeprecateSystemTemplateError>> {
+        self.runtime
+            .block_on(self.client.deprecate_system_template(input))
+    }
+
+    /// Blocking version of [`IotThingsGraph::describe_namespace`].
+    pub fn describe_namespace(
+        &self,
+        input: DescribeNamespaceRequest,
+    ) -> Result<DescribeNamespaceResponse, RusotoError<DescribeNamespaceError>> {
+        self.runtime.block_on(self.client.describe_namespace(input))
+    }
+
+    /// Blocking version of [`IotThingsGraph::dissociate_entity_from_thing`].
+    pub fn dissociate_entity_from_thing(
+        &self,
+        input: DissociateEntityFromThingRequest,
+    ) -> Result<DissociateEntityFromThingResponse, RusotoError<DissociateEntityFromThingError>>
+    {
+        self.runtime
+            .block_on(self.client.dissociate_entity_from_thing(input))
+    }
+
+    /// Blocking version of [`IotThingsGraph::get_entities`].
+    pub fn get_entities(
+        &self,
+        input: GetEntitiesRequest,
+    ) -> Result<GetEntitiesResponse, RusotoError<GetEntitiesError>> {
+        self.runtime.block_on(self.client.get_entities(input))
+    }
+
+    /// Blocking version of [`IotThingsGraph::get_flow_template`].
+    pub fn get_flow_template(
+        &self,
+        input: GetFlowTemplateRequest,
+    ) -> Result<GetFlowTemplateResponse, RusotoError<GetFlowTemplateError>> {
+        self.runtime.block_on(self.client.get_flow_template(input))
+    }
+
+    /// Blocking version of [`IotThingsGraph::get_flow_template_revisions`].
+    pub fn get_flow_template_revisions(
+        &self,
+        input: GetFlowTemplateRevisionsRequest,
+    ) -> Result<GetFlowTemplateRevisionsResponse, RusotoError<GetFlowTemplateRevisionsError>> {
+        self.runtime
+            .block_on(self.client.get_flow_template_revisions(input))
+    }
+
+    /// Blocking version of [`IotThingsGraph::get_namespace_deletion_status`].
+    pub fn get_namespace_deletion_status(
+        &self,
+    ) -> Result<GetNamespaceDeletionStatusResponse, RusotoError<GetNamespaceDeletionStatusError>>
+    {
+        self.runtime
+            .block_on(self.client.get_namespace_deletion_status())
+    }
+
+    /// Blocking version of [`IotThingsGraph::get_system_instance`].
+    pub fn get_system_instance(
+        &self,
+        input: GetSystemInstanceRequest,
+    ) -> Result<GetSystemInstanceResponse, RusotoError<GetSystemInstanceError>> {
+        self.runtime
+            .block_on(self.client.get_system_instance(input))
+    }
+
+    /// Blocking version of [`IotThingsGraph::get_system_template`].
+    pub fn get_system_template(
+        &self,
+        input: GetSystemTemplateRequest,
+    ) -> Result<GetSystemTemplateResponse, RusotoError<GetSystemTemplateError>> {
+        self.runtime
+            .block_on(self.client.get_system_template(input))
+    }
+
+    /// Blocking version of [`IotThingsGraph::get_system_template_revisions`].
+    pub fn get_system_template_revisions(
+        &self,
+        input: GetSystemTemplateRevisionsRequest,
+    ) -> Result<GetSystemTemplateRevisionsResponse, RusotoError<GetSystemTemplateRevisionsError>>
+    {
+        self.runtime
+            .block_on(self.client.get_system_template_revisions(input))
+    }
+
+    /// Blocking version of [`IotThingsGraph::get_upload_status`].
+    pub fn get_upload_status(
+        &self,
+        input: GetUploadStatusRequest,
+    ) -> Result<GetUploadStatusResponse, RusotoError<GetUploadStatusError>> {
+        self.runtime.block_on(self.client.get_upload_status(input))
+    }
+
+    /// Blocking version of [`IotThingsGraph::list_flow_execution_messages`].
+    pub fn list_flow_execution_messages(
+        &self,
+        input: ListFlowExecutionMessagesRequest,
+    ) -> Result<ListFlowExecutionMessagesResponse, RusotoError<ListFlowExecutionMessagesError>>
+    {
+        self.runtime
+            .block_on(self.client.list_flow_execution_messages(input))
+    }
+
+    /// Blocking version of [`IotThingsGraph::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`IotThingsGraph::search_entities`].
+    pub fn search_entities(
+        &self,
+        input: SearchEntitiesRequest,
+    ) -> Result<SearchEntitiesResponse, RusotoError<SearchEntitiesError>> {
+        self.runtime.block_on(self.client.search_entities(input))
+    }
+
+    /// Blocking version of [`IotThingsGraph::search_flow_executions`].
+    pub fn search_flow_executions(
+        &self,
+        input: SearchFlowExecutionsRequest,
+    ) -> Result<SearchFlowExecutionsResponse, RusotoError<SearchFlowExecutionsError>> {
+        self.runtime
+            .block_on(self.client.search_flow_executions(input))
+    }
+
+    /// Blocking version of [`IotThingsGraph::search_flow_templates`].
+    pub fn search_flow_templates(
+        &self,
+        input: SearchFlowTemplatesRequest,
+    ) -> Result<SearchFlowTemplatesResponse, RusotoError<SearchFlowTemplatesError>> {
+        self.runtime
+            .block_on(self.client.search_flow_templates(input))
+    }
+
+    /// Blocking version of [`IotThingsGraph::search_system_instances`].
+    pub fn search_system_instances(
+        &self,
+        input: SearchSystemInstancesRequest,
+    ) -> Result<SearchSystemInstancesResponse, RusotoError<SearchSystemInstancesError>> {
+        self.runtime
+            .block_on(self.client.search_system_instances(input))
+    }
+
+    /// Blocking version of [`IotThingsGraph::search_system_templates`].
+    pub fn search_system_templates(
+        &self,
+        input: SearchSystemTemplatesRequest,
+    ) -> Result<SearchSystemTemplatesResponse, RusotoError<SearchSystemTemplatesError>> {
+        self.runtime
+            .block_on(self.client.search_system_templates(input))
+    }
+
+    /// Blocking version of [`IotThingsGraph::search_things`].
+    pub fn search_things(
+        &self,
+        input: SearchThingsRequest,
+    ) -> Result<SearchThingsResponse, RusotoError<SearchThingsError>> {
+        self.runtime.block_on(self.client.search_things(input))
+    }
+
+    /// Blocking version of [`IotThingsGraph::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`IotThingsGraph::undeploy_system_instance`].
+    pub fn undeploy_system_instance(
+        &self,
+        input: UndeploySystemInstanceRequest,
+    ) -> Result<UndeploySystemInstanceResponse, RusotoError<UndeploySystemInstanceError>> {
+        self.runtime
+            .block_on(self.client.undeploy_system_instance(input))
+    }
+
+    /// Blocking version of [`IotThingsGraph::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`IotThingsGraph::update_flow_template`].
+    pub fn update_flow_template(
+        &self,
+        input: UpdateFlowTemplateRequest,
+    ) -> Result<UpdateFlowTemplateResponse, RusotoError<UpdateFlowTemplateError>> {
+        self.runtime
+            .block_on(self.client.update_flow_template(input))
+    }
+
+    /// Blocking version of [`IotThingsGraph::update_system_template`].
+    pub fn update_system_template(
+        &self,
+        input: UpdateSystemTemplateRequest,
+    ) -> Result<UpdateSystemTemplateResponse, RusotoError<UpdateSystemTemplateError>> {
+        self.runtime
+            .block_on(self.client.update_system_template(input))
+    }
+
+    /// Blocking version of [`IotThingsGraph::upload_entity_definitions`].
+    pub fn upload_entity_definitions(
+        &self,
+        input: UploadEntityDefinitionsRequest,
+    ) -> Result<UploadEntityDefinitionsResponse, RusotoError<UploadEntityDefinitionsError>> {
+        self.runtime
+            .block_on(self.client.upload_entity_definitions(input))
+    }
+}
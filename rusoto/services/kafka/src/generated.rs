@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1844,37 +1845,62 @@ impl BatchAssociateScramSecretError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(BatchAssociateScramSecretError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchAssociateScramSecretError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(BatchAssociateScramSecretError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchAssociateScramSecretError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        BatchAssociateScramSecretError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchAssociateScramSecretError::InternalServerError,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(BatchAssociateScramSecretError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchAssociateScramSecretError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        BatchAssociateScramSecretError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchAssociateScramSecretError::ServiceUnavailable,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(BatchAssociateScramSecretError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchAssociateScramSecretError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(BatchAssociateScramSecretError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchAssociateScramSecretError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1931,41 +1957,62 @@ impl BatchDisassociateScramSecretError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(BatchDisassociateScramSecretError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDisassociateScramSecretError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(BatchDisassociateScramSecretError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDisassociateScramSecretError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        BatchDisassociateScramSecretError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDisassociateScramSecretError::InternalServerError,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(BatchDisassociateScramSecretError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDisassociateScramSecretError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        BatchDisassociateScramSecretError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDisassociateScramSecretError::ServiceUnavailable,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        BatchDisassociateScramSecretError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDisassociateScramSecretError::TooManyRequests,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(BatchDisassociateScramSecretError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDisassociateScramSecretError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2022,27 +2069,62 @@ impl CreateClusterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateClusterError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateClusterError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateClusterError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateClusterError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateClusterError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateClusterError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateClusterError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateClusterError::InternalServerError,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateClusterError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateClusterError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateClusterError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateClusterError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateClusterError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateClusterError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2095,31 +2177,62 @@ impl CreateConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateConfigurationError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConfigurationError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateConfigurationError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConfigurationError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateConfigurationError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConfigurationError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateConfigurationError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateConfigurationError::InternalServerError,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateConfigurationError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateConfigurationError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateConfigurationError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConfigurationError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(CreateConfigurationError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConfigurationError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2163,18 +2276,38 @@ impl DeleteClusterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteClusterError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteClusterError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteClusterError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteClusterError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteClusterError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteClusterError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteClusterError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteClusterError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2215,20 +2348,38 @@ impl DeleteConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteConfigurationError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteConfigurationError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteConfigurationError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteConfigurationError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteConfigurationError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteConfigurationError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteConfigurationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteConfigurationError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2272,21 +2423,46 @@ impl DescribeClusterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeClusterError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeClusterError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeClusterError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeClusterError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeClusterError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeClusterError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeClusterError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeClusterError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeClusterError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeClusterError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2331,25 +2507,46 @@ impl DescribeClusterOperationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeClusterOperationError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeClusterOperationError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeClusterOperationError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeClusterOperationError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        DescribeClusterOperationError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeClusterOperationError::InternalServerError,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeClusterOperationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeClusterOperationError::NotFound,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeClusterOperationError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeClusterOperationError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2397,28 +2594,54 @@ impl DescribeConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeConfigurationError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeConfigurationError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeConfigurationError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeConfigurationError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeConfigurationError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeConfigurationError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeConfigurationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeConfigurationError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeConfigurationError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeConfigurationError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeConfigurationError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeConfigurationError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2469,36 +2692,54 @@ impl DescribeConfigurationRevisionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeConfigurationRevisionError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeConfigurationRevisionError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeConfigurationRevisionError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeConfigurationRevisionError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        DescribeConfigurationRevisionError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeConfigurationRevisionError::InternalServerError,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeConfigurationRevisionError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeConfigurationRevisionError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DescribeConfigurationRevisionError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeConfigurationRevisionError::ServiceUnavailable,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(DescribeConfigurationRevisionError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeConfigurationRevisionError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2548,23 +2789,46 @@ impl GetBootstrapBrokersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBootstrapBrokersError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBootstrapBrokersError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(GetBootstrapBrokersError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBootstrapBrokersError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetBootstrapBrokersError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBootstrapBrokersError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetBootstrapBrokersError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetBootstrapBrokersError::InternalServerError,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetBootstrapBrokersError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBootstrapBrokersError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2617,39 +2881,62 @@ impl GetCompatibleKafkaVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetCompatibleKafkaVersionsError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCompatibleKafkaVersionsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetCompatibleKafkaVersionsError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCompatibleKafkaVersionsError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        GetCompatibleKafkaVersionsError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCompatibleKafkaVersionsError::InternalServerError,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetCompatibleKafkaVersionsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCompatibleKafkaVersionsError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        GetCompatibleKafkaVersionsError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCompatibleKafkaVersionsError::ServiceUnavailable,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetCompatibleKafkaVersionsError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCompatibleKafkaVersionsError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(GetCompatibleKafkaVersionsError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCompatibleKafkaVersionsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2697,20 +2984,38 @@ impl ListClusterOperationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListClusterOperationsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListClusterOperationsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListClusterOperationsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListClusterOperationsError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListClusterOperationsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListClusterOperationsError::InternalServerError,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListClusterOperationsError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListClusterOperationsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2751,18 +3056,38 @@ impl ListClustersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListClustersError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListClustersError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListClustersError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListClustersError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListClustersError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListClustersError::InternalServerError,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListClustersError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListClustersError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2811,34 +3136,54 @@ impl ListConfigurationRevisionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListConfigurationRevisionsError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListConfigurationRevisionsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListConfigurationRevisionsError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListConfigurationRevisionsError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        ListConfigurationRevisionsError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListConfigurationRevisionsError::InternalServerError,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListConfigurationRevisionsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListConfigurationRevisionsError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        ListConfigurationRevisionsError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListConfigurationRevisionsError::ServiceUnavailable,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListConfigurationRevisionsError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListConfigurationRevisionsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2888,25 +3233,46 @@ impl ListConfigurationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListConfigurationsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListConfigurationsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListConfigurationsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListConfigurationsError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListConfigurationsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListConfigurationsError::InternalServerError,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListConfigurationsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListConfigurationsError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListConfigurationsError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListConfigurationsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2948,20 +3314,38 @@ impl ListKafkaVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListKafkaVersionsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListKafkaVersionsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListKafkaVersionsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListKafkaVersionsError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListKafkaVersionsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListKafkaVersionsError::InternalServerError,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListKafkaVersionsError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListKafkaVersionsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3002,18 +3386,38 @@ impl ListNodesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListNodesError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListNodesError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListNodesError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListNodesError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListNodesError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListNodesError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListNodesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListNodesError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3063,29 +3467,62 @@ impl ListScramSecretsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListScramSecretsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListScramSecretsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListScramSecretsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListScramSecretsError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListScramSecretsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListScramSecretsError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListScramSecretsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListScramSecretsError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListScramSecretsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListScramSecretsError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListScramSecretsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListScramSecretsError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListScramSecretsError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListScramSecretsError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3126,17 +3563,30 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3185,27 +3635,62 @@ impl RebootBrokerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(RebootBrokerError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RebootBrokerError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(RebootBrokerError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RebootBrokerError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(RebootBrokerError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RebootBrokerError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RebootBrokerError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RebootBrokerError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(RebootBrokerError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RebootBrokerError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(RebootBrokerError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RebootBrokerError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(RebootBrokerError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RebootBrokerError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3246,15 +3731,30 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(TagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3291,15 +3791,30 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3342,25 +3857,46 @@ impl UpdateBrokerCountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateBrokerCountError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBrokerCountError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateBrokerCountError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBrokerCountError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateBrokerCountError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateBrokerCountError::InternalServerError,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateBrokerCountError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateBrokerCountError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateBrokerCountError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBrokerCountError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3405,25 +3941,46 @@ impl UpdateBrokerStorageError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateBrokerStorageError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBrokerStorageError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateBrokerStorageError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBrokerStorageError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateBrokerStorageError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateBrokerStorageError::InternalServerError,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateBrokerStorageError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateBrokerStorageError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateBrokerStorageError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBrokerStorageError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3473,34 +4030,54 @@ impl UpdateClusterConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateClusterConfigurationError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateClusterConfigurationError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateClusterConfigurationError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateClusterConfigurationError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        UpdateClusterConfigurationError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateClusterConfigurationError::InternalServerError,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateClusterConfigurationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateClusterConfigurationError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        UpdateClusterConfigurationError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateClusterConfigurationError::ServiceUnavailable,
                     )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateClusterConfigurationError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateClusterConfigurationError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3556,37 +4133,62 @@ impl UpdateClusterKafkaVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateClusterKafkaVersionError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateClusterKafkaVersionError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateClusterKafkaVersionError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateClusterKafkaVersionError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        UpdateClusterKafkaVersionError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateClusterKafkaVersionError::InternalServerError,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateClusterKafkaVersionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateClusterKafkaVersionError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        UpdateClusterKafkaVersionError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateClusterKafkaVersionError::ServiceUnavailable,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateClusterKafkaVersionError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateClusterKafkaVersionError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateClusterKafkaVersionError::Unauthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateClusterKafkaVersionError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3638,28 +4240,54 @@ impl UpdateConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateConfigurationError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateConfigurationError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateConfigurationError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateConfigurationError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateConfigurationError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateConfigurationError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateConfigurationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateConfigurationError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateConfigurationError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateConfigurationError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateConfigurationError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateConfigurationError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3705,23 +4333,46 @@ impl UpdateMonitoringError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateMonitoringError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMonitoringError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateMonitoringError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMonitoringError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateMonitoringError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateMonitoringError::InternalServerError,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateMonitoringError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMonitoringError::ServiceUnavailable,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(UpdateMonitoringError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMonitoringError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3959,11 +4610,12 @@ pub struct KafkaClient {
 impl KafkaClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> KafkaClient {
         KafkaClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Kafka"),
         }
     }
 
@@ -3978,12 +4630,32 @@ impl KafkaClient {
     {
         KafkaClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Kafka"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> KafkaClient {
-        KafkaClient { client, region }
+        KafkaClient {
+            client,
+            region: region_for_service(region, "Kafka"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> KafkaClient {
+        KafkaClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Kafka"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> KafkaClient {
+        KafkaClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -4003,6 +4675,7 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("POST", "kafka", &self.region, &request_uri);
+        request.set_operation("BatchAssociateScramSecret");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4039,6 +4712,7 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "kafka", &self.region, &request_uri);
+        request.set_operation("BatchDisassociateScramSecret");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4071,6 +4745,7 @@ impl Kafka for KafkaClient {
         let request_uri = "/v1/clusters";
 
         let mut request = SignedRequest::new("POST", "kafka", &self.region, &request_uri);
+        request.set_operation("CreateCluster");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4103,6 +4778,7 @@ impl Kafka for KafkaClient {
         let request_uri = "/v1/configurations";
 
         let mut request = SignedRequest::new("POST", "kafka", &self.region, &request_uri);
+        request.set_operation("CreateConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4138,6 +4814,7 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "kafka", &self.region, &request_uri);
+        request.set_operation("DeleteCluster");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4173,6 +4850,7 @@ impl Kafka for KafkaClient {
         let request_uri = format!("/v1/configurations/{arn}", arn = input.arn);
 
         let mut request = SignedRequest::new("DELETE", "kafka", &self.region, &request_uri);
+        request.set_operation("DeleteConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4205,6 +4883,7 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("DescribeCluster");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4237,6 +4916,7 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("DescribeClusterOperation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4266,6 +4946,7 @@ impl Kafka for KafkaClient {
         let request_uri = format!("/v1/configurations/{arn}", arn = input.arn);
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("DescribeConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4302,6 +4983,7 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("DescribeConfigurationRevision");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4334,6 +5016,7 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("GetBootstrapBrokers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4364,6 +5047,7 @@ impl Kafka for KafkaClient {
         let request_uri = "/v1/compatible-kafka-versions";
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("GetCompatibleKafkaVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4402,6 +5086,7 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("ListClusterOperations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4440,6 +5125,7 @@ impl Kafka for KafkaClient {
         let request_uri = "/v1/clusters";
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("ListClusters");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4482,6 +5168,7 @@ impl Kafka for KafkaClient {
         let request_uri = format!("/v1/configurations/{arn}/revisions", arn = input.arn);
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("ListConfigurationRevisions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4520,6 +5207,7 @@ impl Kafka for KafkaClient {
         let request_uri = "/v1/configurations";
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("ListConfigurations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4558,6 +5246,7 @@ impl Kafka for KafkaClient {
         let request_uri = "/v1/kafka-versions";
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("ListKafkaVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4599,6 +5288,7 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("ListNodes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4640,6 +5330,7 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("ListScramSecrets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4678,6 +5369,7 @@ impl Kafka for KafkaClient {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "kafka", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4709,6 +5401,7 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "kafka", &self.region, &request_uri);
+        request.set_operation("RebootBroker");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4741,6 +5434,7 @@ impl Kafka for KafkaClient {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "kafka", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4772,6 +5466,7 @@ impl Kafka for KafkaClient {
         let request_uri = format!("/v1/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "kafka", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4809,6 +5504,7 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "kafka", &self.region, &request_uri);
+        request.set_operation("UpdateBrokerCount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4844,6 +5540,7 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "kafka", &self.region, &request_uri);
+        request.set_operation("UpdateBrokerStorage");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4880,6 +5577,7 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "kafka", &self.region, &request_uri);
+        request.set_operation("UpdateClusterConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4916,6 +5614,7 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "kafka", &self.region, &request_uri);
+        request.set_operation("UpdateClusterKafkaVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4948,6 +5647,7 @@ impl Kafka for KafkaClient {
         let request_uri = format!("/v1/configurations/{arn}", arn = input.arn);
 
         let mut request = SignedRequest::new("PUT", "kafka", &self.region, &request_uri);
+        request.set_operation("UpdateConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4983,6 +5683,7 @@ impl Kafka for KafkaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "kafka", &self.region, &request_uri);
+        request.set_operation("UpdateMonitoring");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5005,3 +5706,338 @@ impl Kafka for KafkaClient {
         }
     }
 }
+
+/// A blocking client for the Kafka API, running the calls of a `KafkaClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `KafkaClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct KafkaBlockingClient {
+    client: KafkaClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl KafkaBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> KafkaBlockingClient {
+        KafkaBlockingClient::from_client(KafkaClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> KafkaBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        KafkaBlockingClient::from_client(KafkaClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> KafkaBlockingClient {
+        KafkaBlockingClient::from_client(KafkaClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> KafkaBlockingClient {
+        KafkaBlockingClient::from_client(KafkaClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: KafkaClient) -> KafkaBlockingClient {
+        KafkaBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> KafkaBlockingClient {
+        KafkaBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> KafkaBlockingClient {
+        KafkaBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Kafka::batch_associate_scram_secret`].
+    pub fn batch_associate_scram_secret(
+        &self,
+        input: BatchAssociateScramSecretRequest,
+    ) -> Result<BatchAssociateScramSecretResponse, RusotoError<BatchAssociateScramSecretError>>
+    {
+        self.runtime
+            .block_on(self.client.batch_associate_scram_secret(input))
+    }
+
+    /// Blocking version of [`Kafka::batch_disassociate_scram_secret`].
+    pub fn batch_disassociate_scram_secret(
+        &self,
+        input: BatchDisassociateScramSecretRequest,
+    ) -> Result<BatchDisassociateScramSecretResponse, RusotoError<BatchDisassociateScramSecretError>>
+    {
+        self.runtime
+            .block_on(self.client.batch_disassociate_scram_secret(input))
+    }
+
+    /// Blocking version of [`Kafka::create_cluster`].
+    pub fn create_cluster(
+        &self,
+        input: CreateClusterRequest,
+    ) -> Result<CreateClusterResponse, RusotoError<CreateClusterError>> {
+        self.runtime.block_on(self.client.create_cluster(input))
+    }
+
+    /// Blocking version of [`Kafka::create_configuration`].
+    pub fn create_configuration(
+        &self,
+        input: CreateConfigurationRequest,
+    ) -> Result<CreateConfigurationResponse, RusotoError<CreateConfigurationError>> {
+        self.runtime
+            .block_on(self.client.create_configuration(input))
+    }
+
+    /// Blocking version of [`Kafka::delete_cluster`].
+    pub fn delete_cluster(
+        &self,
+        input: DeleteClusterRequest,
+    ) -> Result<DeleteClusterResponse, RusotoError<DeleteClusterError>> {
+        self.runtime.block_on(self.client.delete_cluster(input))
+    }
+
+    /// Blocking version of [`Kafka::delete_configuration`].
+    pub fn delete_configuration(
+        &self,
+        input: DeleteConfigurationRequest,
+    ) -> Result<DeleteConfigurationResponse, RusotoError<DeleteConfigurationError>> {
+        self.runtime
+            .block_on(self.client.delete_configuration(input))
+    }
+
+    /// Blocking version of [`Kafka::describe_cluster`].
+    pub fn describe_cluster(
+        &self,
+        input: DescribeClusterRequest,
+    ) -> Result<DescribeClusterResponse, RusotoError<DescribeClusterError>> {
+        self.runtime.block_on(self.client.describe_cluster(input))
+    }
+
+    /// Blocking version of [`Kafka::describe_cluster_operation`].
+    pub fn describe_cluster_operation(
+        &self,
+        input: DescribeClusterOperationRequest,
+    ) -> Result<DescribeClusterOperationResponse, RusotoError<DescribeClusterOperationError>> {
+        self.runtime
+            .block_on(self.client.describe_cluster_operation(input))
+    }
+
+    /// Blocking version of [`Kafka::describe_configuration`].
+    pub fn describe_configuration(
+        &self,
+        input: DescribeConfigurationRequest,
+    ) -> Result<DescribeConfigurationResponse, RusotoError<DescribeConfigurationError>> {
+        self.runtime
+            .block_on(self.client.describe_configuration(input))
+    }
+
+    /// Blocking version of [`Kafka::describe_configuration_revision`].
+    pub fn describe_configuration_revision(
+        &self,
+        input: DescribeConfigurationRevisionRequest,
+    ) -> Result<
+        DescribeConfigurationRevisionResponse,
+        RusotoError<DescribeConfigurationRevisionError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_configuration_revision(input))
+    }
+
+    /// Blocking version of [`Kafka::get_bootstrap_brokers`].
+    pub fn get_bootstrap_brokers(
+        &self,
+        input: GetBootstrapBrokersRequest,
+    ) -> Result<GetBootstrapBrokersResponse, RusotoError<GetBootstrapBrokersError>> {
+        self.runtime
+            .block_on(self.client.get_bootstrap_brokers(input))
+    }
+
+    /// Blocking version of [`Kafka::get_compatible_kafka_versions`].
+    pub fn get_compatible_kafka_versions(
+        &self,
+        input: GetCompatibleKafkaVersionsRequest,
+    ) -> Result<GetCompatibleKafkaVersionsResponse, RusotoError<GetCompatibleKafkaVersionsError>>
+    {
+        self.runtime
+            .block_on(self.client.get_compatible_kafka_versions(input))
+    }
+
+    /// Blocking version of [`Kafka::list_cluster_operations`].
+    pub fn list_cluster_operations(
+        &self,
+        input: ListClusterOperationsRequest,
+    ) -> Result<ListClusterOperationsResponse, RusotoError<ListClusterOperationsError>> {
+        self.runtime
+            .block_on(self.client.list_cluster_operations(input))
+    }
+
+    /// Blocking version of [`Kafka::list_clusters`].
+    pub fn list_clusters(
+        &self,
+        input: ListClustersRequest,
+    ) -> Result<ListClustersResponse, RusotoError<ListClustersError>> {
+        self.runtime.block_on(self.client.list_clusters(input))
+    }
+
+    /// Blocking version of [`Kafka::list_configuration_revisions`].
+    pub fn list_configuration_revisions(
+        &self,
+        input: ListConfigurationRevisionsRequest,
+    ) -> Result<ListConfigurationRevisionsResponse, RusotoError<ListConfigurationRevisionsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_configuration_revisions(input))
+    }
+
+    /// Blocking version of [`Kafka::list_configurations`].
+    pub fn list_configurations(
+        &self,
+        input: ListConfigurationsRequest,
+    ) -> Result<ListConfigurationsResponse, RusotoError<ListConfigurationsError>> {
+        self.runtime
+            .block_on(self.client.list_configurations(input))
+    }
+
+    /// Blocking version of [`Kafka::list_kafka_versions`].
+    pub fn list_kafka_versions(
+        &self,
+        input: ListKafkaVersionsRequest,
+    ) -> Result<ListKafkaVersionsResponse, RusotoError<ListKafkaVersionsError>> {
+        self.runtime
+            .block_on(self.client.list_kafka_versions(input))
+    }
+
+    /// Blocking version of [`Kafka::list_nodes`].
+    pub fn list_nodes(
+        &self,
+        input: ListNodesRequest,
+    ) -> Result<ListNodesResponse, RusotoError<ListNodesError>> {
+        self.runtime.block_on(self.client.list_nodes(input))
+    }
+
+    /// Blocking version of [`Kafka::list_scram_secrets`].
+    pub fn list_scram_secrets(
+        &self,
+        input: ListScramSecretsRequest,
+    ) -> Result<ListScramSecretsResponse, RusotoError<ListScramSecretsError>> {
+        self.runtime.block_on(self.client.list_scram_secrets(input))
+    }
+
+    /// Blocking version of [`Kafka::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`Kafka::reboot_broker`].
+    pub fn reboot_broker(
+        &self,
+        input: RebootBrokerRequest,
+    ) -> Result<RebootBrokerResponse, RusotoError<RebootBrokerError>> {
+        self.runtime.block_on(self.client.reboot_broker(input))
+    }
+
+    /// Blocking version of [`Kafka::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`Kafka::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`Kafka::update_broker_count`].
+    pub fn update_broker_count(
+        &self,
+        input: UpdateBrokerCountRequest,
+    ) -> Result<UpdateBrokerCountResponse, RusotoError<UpdateBrokerCountError>> {
+        self.runtime
+            .block_on(self.client.update_broker_count(input))
+    }
+
+    /// Blocking version of [`Kafka::update_broker_storage`].
+    pub fn update_broker_storage(
+        &self,
+        input: UpdateBrokerStorageRequest,
+    ) -> Result<UpdateBrokerStorageResponse, RusotoError<UpdateBrokerStorageError>> {
+        self.runtime
+            .block_on(self.client.update_broker_storage(input))
+    }
+
+    /// Blocking version of [`Kafka::update_cluster_configuration`].
+    pub fn update_cluster_configuration(
+        &self,
+        input: UpdateClusterConfigurationRequest,
+    ) -> Result<UpdateClusterConfigurationResponse, RusotoError<UpdateClusterConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.update_cluster_configuration(input))
+    }
+
+    /// Blocking version of [`Kafka::update_cluster_kafka_version`].
+    pub fn update_cluster_kafka_version(
+        &self,
+        input: UpdateClusterKafkaVersionRequest,
+    ) -> Result<UpdateClusterKafkaVersionResponse, RusotoError<UpdateClusterKafkaVersionError>>
+    {
+        self.runtime
+            .block_on(self.client.update_cluster_kafka_version(input))
+    }
+
+    /// Blocking version of [`Kafka::update_configuration`].
+    pub fn update_configuration(
+        &self,
+        input: UpdateConfigurationRequest,
+    ) -> Result<UpdateConfigurationResponse, RusotoError<UpdateConfigurationError>> {
+        self.runtime
+            .block_on(self.client.update_configuration(input))
+    }
+
+    /// Blocking version of [`Kafka::update_monitoring`].
+    pub fn update_monitoring(
+        &self,
+        input: UpdateMonitoringRequest,
+    ) -> Result<UpdateMonitoringResponse, RusotoError<UpdateMonitoringError>> {
+        self.runtime.block_on(self.client.update_monitoring(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -2474,23 +2475,46 @@ impl BatchDeleteDocumentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(BatchDeleteDocumentError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDeleteDocumentError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(BatchDeleteDocumentError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDeleteDocumentError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(BatchDeleteDocumentError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDeleteDocumentError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(BatchDeleteDocumentError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDeleteDocumentError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(BatchDeleteDocumentError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDeleteDocumentError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2532,26 +2556,54 @@ impl BatchPutDocumentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(BatchPutDocumentError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchPutDocumentError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(BatchPutDocumentError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchPutDocumentError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(BatchPutDocumentError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchPutDocumentError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(BatchPutDocumentError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchPutDocumentError::ResourceNotFound,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(BatchPutDocumentError::ServiceQuotaExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchPutDocumentError::ServiceQuotaExceeded,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(BatchPutDocumentError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchPutDocumentError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2596,31 +2648,62 @@ impl CreateDataSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDataSourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataSourceError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateDataSourceError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataSourceError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateDataSourceError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataSourceError::InternalServer,
+                    )
                 }
                 "ResourceAlreadyExistException" => {
-                    return RusotoError::Service(CreateDataSourceError::ResourceAlreadyExist(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDataSourceError::ResourceAlreadyExist,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDataSourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataSourceError::ResourceNotFound,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateDataSourceError::ServiceQuotaExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDataSourceError::ServiceQuotaExceeded,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDataSourceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataSourceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2664,24 +2747,54 @@ impl CreateFaqError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateFaqError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFaqError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateFaqError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFaqError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateFaqError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFaqError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateFaqError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFaqError::ResourceNotFound,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateFaqError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFaqError::ServiceQuotaExceeded,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateFaqError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFaqError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2724,24 +2837,54 @@ impl CreateIndexError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateIndexError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIndexError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateIndexError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIndexError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateIndexError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIndexError::InternalServer,
+                    )
                 }
                 "ResourceAlreadyExistException" => {
-                    return RusotoError::Service(CreateIndexError::ResourceAlreadyExist(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIndexError::ResourceAlreadyExist,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateIndexError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIndexError::ServiceQuotaExceeded,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateIndexError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIndexError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2784,26 +2927,54 @@ impl CreateThesaurusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateThesaurusError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateThesaurusError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateThesaurusError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateThesaurusError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateThesaurusError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateThesaurusError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateThesaurusError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateThesaurusError::ResourceNotFound,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateThesaurusError::ServiceQuotaExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateThesaurusError::ServiceQuotaExceeded,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateThesaurusError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateThesaurusError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2844,21 +3015,46 @@ impl DeleteDataSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteDataSourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDataSourceError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteDataSourceError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDataSourceError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteDataSourceError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDataSourceError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDataSourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDataSourceError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteDataSourceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDataSourceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2898,21 +3094,46 @@ impl DeleteFaqError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteFaqError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFaqError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteFaqError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFaqError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteFaqError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFaqError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteFaqError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFaqError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteFaqError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFaqError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2952,21 +3173,46 @@ impl DeleteIndexError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteIndexError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIndexError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteIndexError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIndexError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteIndexError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIndexError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteIndexError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIndexError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteIndexError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIndexError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3006,21 +3252,46 @@ impl DeleteThesaurusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteThesaurusError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThesaurusError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteThesaurusError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThesaurusError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteThesaurusError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThesaurusError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteThesaurusError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThesaurusError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteThesaurusError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThesaurusError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3058,18 +3329,38 @@ impl DescribeDataSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeDataSourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDataSourceError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeDataSourceError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDataSourceError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDataSourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDataSourceError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDataSourceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDataSourceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3106,18 +3397,38 @@ impl DescribeFaqError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeFaqError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeFaqError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeFaqError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeFaqError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeFaqError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeFaqError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeFaqError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeFaqError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3154,18 +3465,38 @@ impl DescribeIndexError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeIndexError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeIndexError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeIndexError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeIndexError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeIndexError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeIndexError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeIndexError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeIndexError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3202,18 +3533,38 @@ impl DescribeThesaurusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeThesaurusError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeThesaurusError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeThesaurusError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeThesaurusError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeThesaurusError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeThesaurusError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeThesaurusError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeThesaurusError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3252,25 +3603,46 @@ impl ListDataSourceSyncJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListDataSourceSyncJobsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDataSourceSyncJobsError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(ListDataSourceSyncJobsError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDataSourceSyncJobsError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListDataSourceSyncJobsError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDataSourceSyncJobsError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDataSourceSyncJobsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDataSourceSyncJobsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDataSourceSyncJobsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDataSourceSyncJobsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3308,18 +3680,38 @@ impl ListDataSourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListDataSourcesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDataSourcesError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListDataSourcesError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDataSourcesError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDataSourcesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDataSourcesError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDataSourcesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDataSourcesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3356,18 +3748,38 @@ impl ListFaqsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListFaqsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFaqsError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListFaqsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFaqsError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListFaqsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFaqsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListFaqsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFaqsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3402,15 +3814,30 @@ impl ListIndicesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListIndicesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIndicesError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListIndicesError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIndicesError::InternalServer,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListIndicesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIndicesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3446,20 +3873,38 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InternalServer,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3496,18 +3941,38 @@ impl ListThesauriError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListThesauriError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThesauriError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListThesauriError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThesauriError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListThesauriError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThesauriError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListThesauriError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThesauriError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3548,22 +4013,54 @@ impl QueryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(QueryError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        QueryError::AccessDenied,
+                    )
+                }
+                "ConflictException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        QueryError::Conflict,
+                    )
                 }
-                "ConflictException" => return RusotoError::Service(QueryError::Conflict(err.msg)),
                 "InternalServerException" => {
-                    return RusotoError::Service(QueryError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        QueryError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(QueryError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        QueryError::ResourceNotFound,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(QueryError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        QueryError::ServiceQuotaExceeded,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(QueryError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        QueryError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3606,30 +4103,54 @@ impl StartDataSourceSyncJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartDataSourceSyncJobError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDataSourceSyncJobError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(StartDataSourceSyncJobError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDataSourceSyncJobError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(StartDataSourceSyncJobError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartDataSourceSyncJobError::InternalServer,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StartDataSourceSyncJobError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartDataSourceSyncJobError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartDataSourceSyncJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartDataSourceSyncJobError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartDataSourceSyncJobError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartDataSourceSyncJobError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3668,22 +4189,38 @@ impl StopDataSourceSyncJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StopDataSourceSyncJobError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopDataSourceSyncJobError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(StopDataSourceSyncJobError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopDataSourceSyncJobError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopDataSourceSyncJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopDataSourceSyncJobError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StopDataSourceSyncJobError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopDataSourceSyncJobError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3722,21 +4259,46 @@ impl SubmitFeedbackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(SubmitFeedbackError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SubmitFeedbackError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(SubmitFeedbackError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SubmitFeedbackError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SubmitFeedbackError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SubmitFeedbackError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(SubmitFeedbackError::ResourceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SubmitFeedbackError::ResourceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SubmitFeedbackError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SubmitFeedbackError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3774,18 +4336,38 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(TagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(TagResourceError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InternalServer,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(TagResourceError::ResourceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(TagResourceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3822,18 +4404,38 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UntagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InternalServer,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UntagResourceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3872,21 +4474,46 @@ impl UpdateDataSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateDataSourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDataSourceError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDataSourceError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDataSourceError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateDataSourceError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDataSourceError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDataSourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDataSourceError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDataSourceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDataSourceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3928,24 +4555,54 @@ impl UpdateIndexError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateIndexError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIndexError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateIndexError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIndexError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateIndexError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIndexError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateIndexError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIndexError::ResourceNotFound,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(UpdateIndexError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIndexError::ServiceQuotaExceeded,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateIndexError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIndexError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3986,21 +4643,46 @@ impl UpdateThesaurusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateThesaurusError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThesaurusError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateThesaurusError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThesaurusError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateThesaurusError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThesaurusError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateThesaurusError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThesaurusError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateThesaurusError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThesaurusError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4201,11 +4883,12 @@ pub struct KendraClient {
 impl KendraClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> KendraClient {
         KendraClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "kendra"),
         }
     }
 
@@ -4220,12 +4903,32 @@ impl KendraClient {
     {
         KendraClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "kendra"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> KendraClient {
-        KendraClient { client, region }
+        KendraClient {
+            client,
+            region: region_for_service(region, "kendra"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> KendraClient {
+        KendraClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("kendra"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> KendraClient {
+        KendraClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -4237,6 +4940,7 @@ impl Kendra for KendraClient {
         input: BatchDeleteDocumentRequest,
     ) -> Result<BatchDeleteDocumentResponse, RusotoError<BatchDeleteDocumentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchDeleteDocument");
         request.add_header(
             "x-amz-target",
             "AWSKendraFrontendService.BatchDeleteDocument",
@@ -4258,6 +4962,7 @@ impl Kendra for KendraClient {
         input: BatchPutDocumentRequest,
     ) -> Result<BatchPutDocumentResponse, RusotoError<BatchPutDocumentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchPutDocument");
         request.add_header("x-amz-target", "AWSKendraFrontendService.BatchPutDocument");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4276,6 +4981,7 @@ impl Kendra for KendraClient {
         input: CreateDataSourceRequest,
     ) -> Result<CreateDataSourceResponse, RusotoError<CreateDataSourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateDataSource");
         request.add_header("x-amz-target", "AWSKendraFrontendService.CreateDataSource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4294,6 +5000,7 @@ impl Kendra for KendraClient {
         input: CreateFaqRequest,
     ) -> Result<CreateFaqResponse, RusotoError<CreateFaqError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateFaq");
         request.add_header("x-amz-target", "AWSKendraFrontendService.CreateFaq");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4312,6 +5019,7 @@ impl Kendra for KendraClient {
         input: CreateIndexRequest,
     ) -> Result<CreateIndexResponse, RusotoError<CreateIndexError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateIndex");
         request.add_header("x-amz-target", "AWSKendraFrontendService.CreateIndex");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4330,6 +5038,7 @@ impl Kendra for KendraClient {
         input: CreateThesaurusRequest,
     ) -> Result<CreateThesaurusResponse, RusotoError<CreateThesaurusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateThesaurus");
         request.add_header("x-amz-target", "AWSKendraFrontendService.CreateThesaurus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4348,6 +5057,7 @@ impl Kendra for KendraClient {
         input: DeleteDataSourceRequest,
     ) -> Result<(), RusotoError<DeleteDataSourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteDataSource");
         request.add_header("x-amz-target", "AWSKendraFrontendService.DeleteDataSource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4362,6 +5072,7 @@ impl Kendra for KendraClient {
     /// <p>Removes an FAQ from an index.</p>
     async fn delete_faq(&self, input: DeleteFaqRequest) -> Result<(), RusotoError<DeleteFaqError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteFaq");
         request.add_header("x-amz-target", "AWSKendraFrontendService.DeleteFaq");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4379,6 +5090,7 @@ impl Kendra for KendraClient {
         input: DeleteIndexRequest,
     ) -> Result<(), RusotoError<DeleteIndexError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteIndex");
         request.add_header("x-amz-target", "AWSKendraFrontendService.DeleteIndex");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4396,6 +5108,7 @@ impl Kendra for KendraClient {
         input: DeleteThesaurusRequest,
     ) -> Result<(), RusotoError<DeleteThesaurusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteThesaurus");
         request.add_header("x-amz-target", "AWSKendraFrontendService.DeleteThesaurus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4413,6 +5126,7 @@ impl Kendra for KendraClient {
         input: DescribeDataSourceRequest,
     ) -> Result<DescribeDataSourceResponse, RusotoError<DescribeDataSourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeDataSource");
         request.add_header(
             "x-amz-target",
             "AWSKendraFrontendService.DescribeDataSource",
@@ -4434,6 +5148,7 @@ impl Kendra for KendraClient {
         input: DescribeFaqRequest,
     ) -> Result<DescribeFaqResponse, RusotoError<DescribeFaqError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeFaq");
         request.add_header("x-amz-target", "AWSKendraFrontendService.DescribeFaq");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4452,6 +5167,7 @@ impl Kendra for KendraClient {
         input: DescribeIndexRequest,
     ) -> Result<DescribeIndexResponse, RusotoError<DescribeIndexError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeIndex");
         request.add_header("x-amz-target", "AWSKendraFrontendService.DescribeIndex");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4470,6 +5186,7 @@ impl Kendra for KendraClient {
         input: DescribeThesaurusRequest,
     ) -> Result<DescribeThesaurusResponse, RusotoError<DescribeThesaurusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeThesaurus");
         request.add_header("x-amz-target", "AWSKendraFrontendService.DescribeThesaurus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4488,6 +5205,7 @@ impl Kendra for KendraClient {
         input: ListDataSourceSyncJobsRequest,
     ) -> Result<ListDataSourceSyncJobsResponse, RusotoError<ListDataSourceSyncJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListDataSourceSyncJobs");
         request.add_header(
             "x-amz-target",
             "AWSKendraFrontendService.ListDataSourceSyncJobs",
@@ -4510,6 +5228,7 @@ impl Kendra for KendraClient {
         input: ListDataSourcesRequest,
     ) -> Result<ListDataSourcesResponse, RusotoError<ListDataSourcesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListDataSources");
         request.add_header("x-amz-target", "AWSKendraFrontendService.ListDataSources");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4528,6 +5247,7 @@ impl Kendra for KendraClient {
         input: ListFaqsRequest,
     ) -> Result<ListFaqsResponse, RusotoError<ListFaqsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListFaqs");
         request.add_header("x-amz-target", "AWSKendraFrontendService.ListFaqs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4546,6 +5266,7 @@ impl Kendra for KendraClient {
         input: ListIndicesRequest,
     ) -> Result<ListIndicesResponse, RusotoError<ListIndicesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListIndices");
         request.add_header("x-amz-target", "AWSKendraFrontendService.ListIndices");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4564,6 +5285,7 @@ impl Kendra for KendraClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header(
             "x-amz-target",
             "AWSKendraFrontendService.ListTagsForResource",
@@ -4585,6 +5307,7 @@ impl Kendra for KendraClient {
         input: ListThesauriRequest,
     ) -> Result<ListThesauriResponse, RusotoError<ListThesauriError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListThesauri");
         request.add_header("x-amz-target", "AWSKendraFrontendService.ListThesauri");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4600,6 +5323,7 @@ impl Kendra for KendraClient {
     /// <p>Searches an active index. Use this API to search your documents using query. The <code>Query</code> operation enables to do faceted search and to filter results based on document attributes.</p> <p>It also enables you to provide user context that Amazon Kendra uses to enforce document access control in the search results. </p> <p>Amazon Kendra searches your index for text content and question and answer (FAQ) content. By default the response contains three types of results.</p> <ul> <li> <p>Relevant passages</p> </li> <li> <p>Matching FAQs</p> </li> <li> <p>Relevant documents</p> </li> </ul> <p>You can specify that the query return only one type of result using the <code>QueryResultTypeConfig</code> parameter.</p> <p>Each query returns the 100 most relevant results. </p>
     async fn query(&self, input: QueryRequest) -> Result<QueryResult, RusotoError<QueryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("Query");
         request.add_header("x-amz-target", "AWSKendraFrontendService.Query");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4618,6 +5342,7 @@ impl Kendra for KendraClient {
         input: StartDataSourceSyncJobRequest,
     ) -> Result<StartDataSourceSyncJobResponse, RusotoError<StartDataSourceSyncJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartDataSourceSyncJob");
         request.add_header(
             "x-amz-target",
             "AWSKendraFrontendService.StartDataSourceSyncJob",
@@ -4640,6 +5365,7 @@ impl Kendra for KendraClient {
         input: StopDataSourceSyncJobRequest,
     ) -> Result<(), RusotoError<StopDataSourceSyncJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopDataSourceSyncJob");
         request.add_header(
             "x-amz-target",
             "AWSKendraFrontendService.StopDataSourceSyncJob",
@@ -4660,6 +5386,7 @@ impl Kendra for KendraClient {
         input: SubmitFeedbackRequest,
     ) -> Result<(), RusotoError<SubmitFeedbackError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SubmitFeedback");
         request.add_header("x-amz-target", "AWSKendraFrontendService.SubmitFeedback");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4677,6 +5404,7 @@ impl Kendra for KendraClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "AWSKendraFrontendService.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4695,6 +5423,7 @@ impl Kendra for KendraClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "AWSKendraFrontendService.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4713,6 +5442,7 @@ impl Kendra for KendraClient {
         input: UpdateDataSourceRequest,
     ) -> Result<(), RusotoError<UpdateDataSourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateDataSource");
         request.add_header("x-amz-target", "AWSKendraFrontendService.UpdateDataSource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4730,6 +5460,7 @@ impl Kendra for KendraClient {
         input: UpdateIndexRequest,
     ) -> Result<(), RusotoError<UpdateIndexError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateIndex");
         request.add_header("x-amz-target", "AWSKendraFrontendService.UpdateIndex");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4747,6 +5478,7 @@ impl Kendra for KendraClient {
         input: UpdateThesaurusRequest,
     ) -> Result<(), RusotoError<UpdateThesaurusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateThesaurus");
         request.add_header("x-amz-target", "AWSKendraFrontendService.UpdateThesaurus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4758,3 +5490,310 @@ impl Kendra for KendraClient {
         Ok(())
     }
 }
+
+/// A blocking client for the kendra API, running the calls of a `KendraClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `KendraClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct KendraBlockingClient {
+    client: KendraClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl KendraBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> KendraBlockingClient {
+        KendraBlockingClient::from_client(KendraClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> KendraBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        KendraBlockingClient::from_client(KendraClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> KendraBlockingClient {
+        KendraBlockingClient::from_client(KendraClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> KendraBlockingClient {
+        KendraBlockingClient::from_client(KendraClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: KendraClient) -> KendraBlockingClient {
+        KendraBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> KendraBlockingClient {
+        KendraBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> KendraBlockingClient {
+        KendraBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Kendra::batch_delete_document`].
+    pub fn batch_delete_document(
+        &self,
+        input: BatchDeleteDocumentRequest,
+    ) -> Result<BatchDeleteDocumentResponse, RusotoError<BatchDeleteDocumentError>> {
+        self.runtime
+            .block_on(self.client.batch_delete_document(input))
+    }
+
+    /// Blocking version of [`Kendra::batch_put_document`].
+    pub fn batch_put_document(
+        &self,
+        input: BatchPutDocumentRequest,
+    ) -> Result<BatchPutDocumentResponse, RusotoError<BatchPutDocumentError>> {
+        self.runtime.block_on(self.client.batch_put_document(input))
+    }
+
+    /// Blocking version of [`Kendra::create_data_source`].
+    pub fn create_data_source(
+        &self,
+        input: CreateDataSourceRequest,
+    ) -> Result<CreateDataSourceResponse, RusotoError<CreateDataSourceError>> {
+        self.runtime.block_on(self.client.create_data_source(input))
+    }
+
+    /// Blocking version of [`Kendra::create_faq`].
+    pub fn create_faq(
+        &self,
+        input: CreateFaqRequest,
+    ) -> Result<CreateFaqResponse, RusotoError<CreateFaqError>> {
+        self.runtime.block_on(self.client.create_faq(input))
+    }
+
+    /// Blocking version of [`Kendra::create_index`].
+    pub fn create_index(
+        &self,
+        input: CreateIndexRequest,
+    ) -> Result<CreateIndexResponse, RusotoError<CreateIndexError>> {
+        self.runtime.block_on(self.client.create_index(input))
+    }
+
+    /// Blocking version of [`Kendra::create_thesaurus`].
+    pub fn create_thesaurus(
+        &self,
+        input: CreateThesaurusRequest,
+    ) -> Result<CreateThesaurusResponse, RusotoError<CreateThesaurusError>> {
+        self.runtime.block_on(self.client.create_thesaurus(input))
+    }
+
+    /// Blocking version of [`Kendra::delete_data_source`].
+    pub fn delete_data_source(
+        &self,
+        input: DeleteDataSourceRequest,
+    ) -> Result<(), RusotoError<DeleteDataSourceError>> {
+        self.runtime.block_on(self.client.delete_data_source(input))
+    }
+
+    /// Blocking version of [`Kendra::delete_faq`].
+    pub fn delete_faq(&self, input: DeleteFaqRequest) -> Result<(), RusotoError<DeleteFaqError>> {
+        self.runtime.block_on(self.client.delete_faq(input))
+    }
+
+    /// Blocking version of [`Kendra::delete_index`].
+    pub fn delete_index(
+        &self,
+        input: DeleteIndexRequest,
+    ) -> Result<(), RusotoError<DeleteIndexError>> {
+        self.runtime.block_on(self.client.delete_index(input))
+    }
+
+    /// Blocking version of [`Kendra::delete_thesaurus`].
+    pub fn delete_thesaurus(
+        &self,
+        input: DeleteThesaurusRequest,
+    ) -> Result<(), RusotoError<DeleteThesaurusError>> {
+        self.runtime.block_on(self.client.delete_thesaurus(input))
+    }
+
+    /// Blocking version of [`Kendra::describe_data_source`].
+    pub fn describe_data_source(
+        &self,
+        input: DescribeDataSourceRequest,
+    ) -> Result<DescribeDataSourceResponse, RusotoError<DescribeDataSourceError>> {
+        self.runtime
+            .block_on(self.client.describe_data_source(input))
+    }
+
+    /// Blocking version of [`Kendra::describe_faq`].
+    pub fn describe_faq(
+        &self,
+        input: DescribeFaqRequest,
+    ) -> Result<DescribeFaqResponse, RusotoError<DescribeFaqError>> {
+        self.runtime.block_on(self.client.describe_faq(input))
+    }
+
+    /// Blocking version of [`Kendra::describe_index`].
+    pub fn describe_index(
+        &self,
+        input: DescribeIndexRequest,
+    ) -> Result<DescribeIndexResponse, RusotoError<DescribeIndexError>> {
+        self.runtime.block_on(self.client.describe_index(input))
+    }
+
+    /// Blocking version of [`Kendra::describe_thesaurus`].
+    pub fn describe_thesaurus(
+        &self,
+        input: DescribeThesaurusRequest,
+    ) -> Result<DescribeThesaurusResponse, RusotoError<DescribeThesaurusError>> {
+        self.runtime.block_on(self.client.describe_thesaurus(input))
+    }
+
+    /// Blocking version of [`Kendra::list_data_source_sync_jobs`].
+    pub fn list_data_source_sync_jobs(
+        &self,
+        input: ListDataSourceSyncJobsRequest,
+    ) -> Result<ListDataSourceSyncJobsResponse, RusotoError<ListDataSourceSyncJobsError>> {
+        self.runtime
+            .block_on(self.client.list_data_source_sync_jobs(input))
+    }
+
+    /// Blocking version of [`Kendra::list_data_sources`].
+    pub fn list_data_sources(
+        &self,
+        input: ListDataSourcesRequest,
+    ) -> Result<ListDataSourcesResponse, RusotoError<ListDataSourcesError>> {
+        self.runtime.block_on(self.client.list_data_sources(input))
+    }
+
+    /// Blocking version of [`Kendra::list_faqs`].
+    pub fn list_faqs(
+        &self,
+        input: ListFaqsRequest,
+    ) -> Result<ListFaqsResponse, RusotoError<ListFaqsError>> {
+        self.runtime.block_on(self.client.list_faqs(input))
+    }
+
+    /// Blocking version of [`Kendra::list_indices`].
+    pub fn list_indices(
+        &self,
+        input: ListIndicesRequest,
+    ) -> Result<ListIndicesResponse, RusotoError<ListIndicesError>> {
+        self.runtime.block_on(self.client.list_indices(input))
+    }
+
+    /// Blocking version of [`Kendra::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`Kendra::list_thesauri`].
+    pub fn list_thesauri(
+        &self,
+        input: ListThesauriRequest,
+    ) -> Result<ListThesauriResponse, RusotoError<ListThesauriError>> {
+        self.runtime.block_on(self.client.list_thesauri(input))
+    }
+
+    /// Blocking version of [`Kendra::query`].
+    pub fn query(&self, input: QueryRequest) -> Result<QueryResult, RusotoError<QueryError>> {
+        self.runtime.block_on(self.client.query(input))
+    }
+
+    /// Blocking version of [`Kendra::start_data_source_sync_job`].
+    pub fn start_data_source_sync_job(
+        &self,
+        input: StartDataSourceSyncJobRequest,
+    ) -> Result<StartDataSourceSyncJobResponse, RusotoError<StartDataSourceSyncJobError>> {
+        self.runtime
+            .block_on(self.client.start_data_source_sync_job(input))
+    }
+
+    /// Blocking version of [`Kendra::stop_data_source_sync_job`].
+    pub fn stop_data_source_sync_job(
+        &self,
+        input: StopDataSourceSyncJobRequest,
+    ) -> Result<(), RusotoError<StopDataSourceSyncJobError>> {
+        self.runtime
+            .block_on(self.client.stop_data_source_sync_job(input))
+    }
+
+    /// Blocking version of [`Kendra::submit_feedback`].
+    pub fn submit_feedback(
+        &self,
+        input: SubmitFeedbackRequest,
+    ) -> Result<(), RusotoError<SubmitFeedbackError>> {
+        self.runtime.block_on(self.client.submit_feedback(input))
+    }
+
+    /// Blocking version of [`Kendra::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`Kendra::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`Kendra::update_data_source`].
+    pub fn update_data_source(
+        &self,
+        input: UpdateDataSourceRequest,
+    ) -> Result<(), RusotoError<UpdateDataSourceError>> {
+        self.runtime.block_on(self.client.update_data_source(input))
+    }
+
+    /// Blocking version of [`Kendra::update_index`].
+    pub fn update_index(
+        &self,
+        input: UpdateIndexRequest,
+    ) -> Result<(), RusotoError<UpdateIndexError>> {
+        self.runtime.block_on(self.client.update_index(input))
+    }
+
+    /// Blocking version of [`Kendra::update_thesaurus`].
+    pub fn update_thesaurus(
+        &self,
+        input: UpdateThesaurusRequest,
+    ) -> Result<(), RusotoError<UpdateThesaurusError>> {
+        self.runtime.block_on(self.client.update_thesaurus(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -354,33 +355,78 @@ impl GetClipError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(GetClipError::ClientLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetClipError::ClientLimitExceeded,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(GetClipError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetClipError::InvalidArgument,
+                    )
                 }
                 "InvalidCodecPrivateDataException" => {
-                    return RusotoError::Service(GetClipError::InvalidCodecPrivateData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetClipError::InvalidCodecPrivateData,
+                    )
                 }
                 "InvalidMediaFrameException" => {
-                    return RusotoError::Service(GetClipError::InvalidMediaFrame(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetClipError::InvalidMediaFrame,
+                    )
                 }
                 "MissingCodecPrivateDataException" => {
-                    return RusotoError::Service(GetClipError::MissingCodecPrivateData(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetClipError::MissingCodecPrivateData,
+                    )
                 }
                 "NoDataRetentionException" => {
-                    return RusotoError::Service(GetClipError::NoDataRetention(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetClipError::NoDataRetention,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetClipError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetClipError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetClipError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetClipError::ResourceNotFound,
+                    )
                 }
                 "UnsupportedStreamMediaTypeException" => {
-                    return RusotoError::Service(GetClipError::UnsupportedStreamMediaType(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetClipError::UnsupportedStreamMediaType,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -432,46 +478,70 @@ impl GetDASHStreamingSessionURLError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(
-                        GetDASHStreamingSessionURLError::ClientLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDASHStreamingSessionURLError::ClientLimitExceeded,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(GetDASHStreamingSessionURLError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDASHStreamingSessionURLError::InvalidArgument,
+                    )
                 }
                 "InvalidCodecPrivateDataException" => {
-                    return RusotoError::Service(
-                        GetDASHStreamingSessionURLError::InvalidCodecPrivateData(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDASHStreamingSessionURLError::InvalidCodecPrivateData,
                     )
                 }
                 "MissingCodecPrivateDataException" => {
-                    return RusotoError::Service(
-                        GetDASHStreamingSessionURLError::MissingCodecPrivateData(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDASHStreamingSessionURLError::MissingCodecPrivateData,
                     )
                 }
                 "NoDataRetentionException" => {
-                    return RusotoError::Service(GetDASHStreamingSessionURLError::NoDataRetention(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDASHStreamingSessionURLError::NoDataRetention,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetDASHStreamingSessionURLError::NotAuthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDASHStreamingSessionURLError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDASHStreamingSessionURLError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDASHStreamingSessionURLError::ResourceNotFound,
+                    )
                 }
                 "UnsupportedStreamMediaTypeException" => {
-                    return RusotoError::Service(
-                        GetDASHStreamingSessionURLError::UnsupportedStreamMediaType(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDASHStreamingSessionURLError::UnsupportedStreamMediaType,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -528,46 +598,70 @@ impl GetHLSStreamingSessionURLError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(
-                        GetHLSStreamingSessionURLError::ClientLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetHLSStreamingSessionURLError::ClientLimitExceeded,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(GetHLSStreamingSessionURLError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetHLSStreamingSessionURLError::InvalidArgument,
+                    )
                 }
                 "InvalidCodecPrivateDataException" => {
-                    return RusotoError::Service(
-                        GetHLSStreamingSessionURLError::InvalidCodecPrivateData(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetHLSStreamingSessionURLError::InvalidCodecPrivateData,
                     )
                 }
                 "MissingCodecPrivateDataException" => {
-                    return RusotoError::Service(
-                        GetHLSStreamingSessionURLError::MissingCodecPrivateData(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetHLSStreamingSessionURLError::MissingCodecPrivateData,
                     )
                 }
                 "NoDataRetentionException" => {
-                    return RusotoError::Service(GetHLSStreamingSessionURLError::NoDataRetention(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetHLSStreamingSessionURLError::NoDataRetention,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetHLSStreamingSessionURLError::NotAuthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetHLSStreamingSessionURLError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetHLSStreamingSessionURLError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetHLSStreamingSessionURLError::ResourceNotFound,
+                    )
                 }
                 "UnsupportedStreamMediaTypeException" => {
-                    return RusotoError::Service(
-                        GetHLSStreamingSessionURLError::UnsupportedStreamMediaType(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetHLSStreamingSessionURLError::UnsupportedStreamMediaType,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -616,26 +710,38 @@ impl GetMediaForFragmentListError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(GetMediaForFragmentListError::ClientLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMediaForFragmentListError::ClientLimitExceeded,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(GetMediaForFragmentListError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMediaForFragmentListError::InvalidArgument,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetMediaForFragmentListError::NotAuthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMediaForFragmentListError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetMediaForFragmentListError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetMediaForFragmentListError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -672,18 +778,38 @@ impl ListFragmentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(ListFragmentsError::ClientLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFragmentsError::ClientLimitExceeded,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListFragmentsError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFragmentsError::InvalidArgument,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListFragmentsError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFragmentsError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListFragmentsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFragmentsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -745,11 +871,12 @@ pub struct KinesisVideoArchivedMediaClient {
 impl KinesisVideoArchivedMediaClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> KinesisVideoArchivedMediaClient {
         KinesisVideoArchivedMediaClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Kinesis Video Archived Media"),
         }
     }
 
@@ -764,7 +891,7 @@ impl KinesisVideoArchivedMediaClient {
     {
         KinesisVideoArchivedMediaClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Kinesis Video Archived Media"),
         }
     }
 
@@ -772,7 +899,27 @@ impl KinesisVideoArchivedMediaClient {
         client: Client,
         region: region::Region,
     ) -> KinesisVideoArchivedMediaClient {
-        KinesisVideoArchivedMediaClient { client, region }
+        KinesisVideoArchivedMediaClient {
+            client,
+            region: region_for_service(region, "Kinesis Video Archived Media"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> KinesisVideoArchivedMediaClient {
+        KinesisVideoArchivedMediaClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Kinesis Video Archived Media"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> KinesisVideoArchivedMediaClient {
+        KinesisVideoArchivedMediaClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -787,6 +934,8 @@ impl KinesisVideoArchivedMedia for KinesisVideoArchivedMediaClient {
         let request_uri = "/getClip";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("GetClip");
+        request.set_raw_response(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -822,6 +971,7 @@ impl KinesisVideoArchivedMedia for KinesisVideoArchivedMediaClient {
         let request_uri = "/getDASHStreamingSessionURL";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("GetDASHStreamingSessionURL");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -853,6 +1003,7 @@ impl KinesisVideoArchivedMedia for KinesisVideoArchivedMediaClient {
         let request_uri = "/getHLSStreamingSessionURL";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("GetHLSStreamingSessionURL");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -884,6 +1035,8 @@ impl KinesisVideoArchivedMedia for KinesisVideoArchivedMediaClient {
         let request_uri = "/getMediaForFragmentList";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("GetMediaForFragmentList");
+        request.set_raw_response(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -918,6 +1071,7 @@ impl KinesisVideoArchivedMedia for KinesisVideoArchivedMediaClient {
         let request_uri = "/listFragments";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("ListFragments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -940,3 +1094,138 @@ impl KinesisVideoArchivedMedia for KinesisVideoArchivedMediaClient {
         }
     }
 }
+
+/// A blocking client for the Kinesis Video Archived Media API, running the calls of a `KinesisVideoArchivedMediaClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `KinesisVideoArchivedMediaClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct KinesisVideoArchivedMediaBlockingClient {
+    client: KinesisVideoArchivedMediaClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl KinesisVideoArchivedMediaBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> KinesisVideoArchivedMediaBlockingClient {
+        KinesisVideoArchivedMediaBlockingClient::from_client(KinesisVideoArchivedMediaClient::new(
+            region,
+        ))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> KinesisVideoArchivedMediaBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        KinesisVideoArchivedMediaBlockingClient::from_client(
+            KinesisVideoArchivedMediaClient::new_with(
+                request_dispatcher,
+                credentials_provider,
+                region,
+            ),
+        )
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> KinesisVideoArchivedMediaBlockingClient {
+        KinesisVideoArchivedMediaBlockingClient::from_client(
+            KinesisVideoArchivedMediaClient::new_with_client(client, region),
+        )
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> KinesisVideoArchivedMediaBlockingClient {
+        KinesisVideoArchivedMediaBlockingClient::from_client(
+            KinesisVideoArchivedMediaClient::new_with_config(config),
+        )
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(
+        client: KinesisVideoArchivedMediaClient,
+    ) -> KinesisVideoArchivedMediaBlockingClient {
+        KinesisVideoArchivedMediaBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> KinesisVideoArchivedMediaBlockingClient {
+        KinesisVideoArchivedMediaBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> KinesisVideoArchivedMediaBlockingClient {
+        KinesisVideoArchivedMediaBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`KinesisVideoArchivedMedia::get_clip`].
+    pub fn get_clip(
+        &self,
+        input: GetClipInput,
+    ) -> Result<GetClipOutput, RusotoError<GetClipError>> {
+        self.runtime.block_on(self.client.get_clip(input))
+    }
+
+    /// Blocking version of [`KinesisVideoArchivedMedia::get_dash_streaming_session_url`].
+    pub fn get_dash_streaming_session_url(
+        &self,
+        input: GetDASHStreamingSessionURLInput,
+    ) -> Result<GetDASHStreamingSessionURLOutput, RusotoError<GetDASHStreamingSessionURLError>>
+    {
+        self.runtime
+            .block_on(self.client.get_dash_streaming_session_url(input))
+    }
+
+    /// Blocking version of [`KinesisVideoArchivedMedia::get_hls_streaming_session_url`].
+    pub fn get_hls_streaming_session_url(
+        &self,
+        input: GetHLSStreamingSessionURLInput,
+    ) -> Result<GetHLSStreamingSessionURLOutput, RusotoError<GetHLSStreamingSessionURLError>> {
+        self.runtime
+            .block_on(self.client.get_hls_streaming_session_url(input))
+    }
+
+    /// Blocking version of [`KinesisVideoArchivedMedia::get_media_for_fragment_list`].
+    pub fn get_media_for_fragment_list(
+        &self,
+        input: GetMediaForFragmentListInput,
+    ) -> Result<GetMediaForFragmentListOutput, RusotoError<GetMediaForFragmentListError>> {
+        self.runtime
+            .block_on(self.client.get_media_for_fragment_list(input))
+    }
+
+    /// Blocking version of [`KinesisVideoArchivedMedia::list_fragments`].
+    pub fn list_fragments(
+        &self,
+        input: ListFragmentsInput,
+    ) -> Result<ListFragmentsOutput, RusotoError<ListFragmentsError>> {
+        self.runtime.block_on(self.client.list_fragments(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -91,24 +92,54 @@ impl GetMediaError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(GetMediaError::ClientLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMediaError::ClientLimitExceeded,
+                    )
                 }
                 "ConnectionLimitExceededException" => {
-                    return RusotoError::Service(GetMediaError::ConnectionLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMediaError::ConnectionLimitExceeded,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(GetMediaError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMediaError::InvalidArgument,
+                    )
                 }
                 "InvalidEndpointException" => {
-                    return RusotoError::Service(GetMediaError::InvalidEndpoint(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMediaError::InvalidEndpoint,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetMediaError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMediaError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetMediaError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMediaError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -148,11 +179,12 @@ pub struct KinesisVideoMediaClient {
 impl KinesisVideoMediaClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> KinesisVideoMediaClient {
         KinesisVideoMediaClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Kinesis Video Media"),
         }
     }
 
@@ -167,12 +199,32 @@ impl KinesisVideoMediaClient {
     {
         KinesisVideoMediaClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Kinesis Video Media"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> KinesisVideoMediaClient {
-        KinesisVideoMediaClient { client, region }
+        KinesisVideoMediaClient {
+            client,
+            region: region_for_service(region, "Kinesis Video Media"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> KinesisVideoMediaClient {
+        KinesisVideoMediaClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Kinesis Video Media"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> KinesisVideoMediaClient {
+        KinesisVideoMediaClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -187,6 +239,8 @@ impl KinesisVideoMedia for KinesisVideoMediaClient {
         let request_uri = "/getMedia";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("GetMedia");
+        request.set_raw_response(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -212,3 +266,96 @@ impl KinesisVideoMedia for KinesisVideoMediaClient {
         }
     }
 }
+
+/// A blocking client for the Kinesis Video Media API, running the calls of a `KinesisVideoMediaClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `KinesisVideoMediaClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct KinesisVideoMediaBlockingClient {
+    client: KinesisVideoMediaClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl KinesisVideoMediaBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> KinesisVideoMediaBlockingClient {
+        KinesisVideoMediaBlockingClient::from_client(KinesisVideoMediaClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> KinesisVideoMediaBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        KinesisVideoMediaBlockingClient::from_client(KinesisVideoMediaClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> KinesisVideoMediaBlockingClient {
+        KinesisVideoMediaBlockingClient::from_client(KinesisVideoMediaClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> KinesisVideoMediaBlockingClient {
+        KinesisVideoMediaBlockingClient::from_client(KinesisVideoMediaClient::new_with_config(
+            config,
+        ))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: KinesisVideoMediaClient) -> KinesisVideoMediaBlockingClient {
+        KinesisVideoMediaBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> KinesisVideoMediaBlockingClient {
+        KinesisVideoMediaBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> KinesisVideoMediaBlockingClient {
+        KinesisVideoMediaBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`KinesisVideoMedia::get_media`].
+    pub fn get_media(
+        &self,
+        input: GetMediaInput,
+    ) -> Result<GetMediaOutput, RusotoError<GetMediaError>> {
+        self.runtime.block_on(self.client.get_media(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -120,26 +121,54 @@ impl GetIceServerConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(GetIceServerConfigError::ClientLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetIceServerConfigError::ClientLimitExceeded,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(GetIceServerConfigError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIceServerConfigError::InvalidArgument,
+                    )
                 }
                 "InvalidClientException" => {
-                    return RusotoError::Service(GetIceServerConfigError::InvalidClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIceServerConfigError::InvalidClient,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetIceServerConfigError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIceServerConfigError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetIceServerConfigError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIceServerConfigError::ResourceNotFound,
+                    )
                 }
                 "SessionExpiredException" => {
-                    return RusotoError::Service(GetIceServerConfigError::SessionExpired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIceServerConfigError::SessionExpired,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -178,26 +207,38 @@ impl SendAlexaOfferToMasterError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(SendAlexaOfferToMasterError::ClientLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SendAlexaOfferToMasterError::ClientLimitExceeded,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(SendAlexaOfferToMasterError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SendAlexaOfferToMasterError::InvalidArgument,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(SendAlexaOfferToMasterError::NotAuthorized(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SendAlexaOfferToMasterError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SendAlexaOfferToMasterError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SendAlexaOfferToMasterError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -241,11 +282,12 @@ pub struct KinesisVideoSignalingClient {
 impl KinesisVideoSignalingClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> KinesisVideoSignalingClient {
         KinesisVideoSignalingClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Kinesis Video Signaling"),
         }
     }
 
@@ -260,12 +302,32 @@ impl KinesisVideoSignalingClient {
     {
         KinesisVideoSignalingClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Kinesis Video Signaling"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> KinesisVideoSignalingClient {
-        KinesisVideoSignalingClient { client, region }
+        KinesisVideoSignalingClient {
+            client,
+            region: region_for_service(region, "Kinesis Video Signaling"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> KinesisVideoSignalingClient {
+        KinesisVideoSignalingClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Kinesis Video Signaling"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> KinesisVideoSignalingClient {
+        KinesisVideoSignalingClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -280,6 +342,7 @@ impl KinesisVideoSignaling for KinesisVideoSignalingClient {
         let request_uri = "/v1/get-ice-server-config";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("GetIceServerConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -311,6 +374,7 @@ impl KinesisVideoSignaling for KinesisVideoSignalingClient {
         let request_uri = "/v1/send-alexa-offer-to-master";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("SendAlexaOfferToMaster");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -333,3 +397,106 @@ impl KinesisVideoSignaling for KinesisVideoSignalingClient {
         }
     }
 }
+
+/// A blocking client for the Amazon Kinesis Video Signaling Channels API, running the calls of a `KinesisVideoSignalingClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `KinesisVideoSignalingClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct KinesisVideoSignalingBlockingClient {
+    client: KinesisVideoSignalingClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl KinesisVideoSignalingBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> KinesisVideoSignalingBlockingClient {
+        KinesisVideoSignalingBlockingClient::from_client(KinesisVideoSignalingClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> KinesisVideoSignalingBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        KinesisVideoSignalingBlockingClient::from_client(KinesisVideoSignalingClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> KinesisVideoSignalingBlockingClient {
+        KinesisVideoSignalingBlockingClient::from_client(
+            KinesisVideoSignalingClient::new_with_client(client, region),
+        )
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> KinesisVideoSignalingBlockingClient {
+        KinesisVideoSignalingBlockingClient::from_client(
+            KinesisVideoSignalingClient::new_with_config(config),
+        )
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: KinesisVideoSignalingClient) -> KinesisVideoSignalingBlockingClient {
+        KinesisVideoSignalingBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> KinesisVideoSignalingBlockingClient {
+        KinesisVideoSignalingBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> KinesisVideoSignalingBlockingClient {
+        KinesisVideoSignalingBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`KinesisVideoSignaling::get_ice_server_config`].
+    pub fn get_ice_server_config(
+        &self,
+        input: GetIceServerConfigRequest,
+    ) -> Result<GetIceServerConfigResponse, RusotoError<GetIceServerConfigError>> {
+        self.runtime
+            .block_on(self.client.get_ice_server_config(input))
+    }
+
+    /// Blocking version of [`KinesisVideoSignaling::send_alexa_offer_to_master`].
+    pub fn send_alexa_offer_to_master(
+        &self,
+        input: SendAlexaOfferToMasterRequest,
+    ) -> Result<SendAlexaOfferToMasterResponse, RusotoError<SendAlexaOfferToMasterError>> {
+        self.runtime
+            .block_on(self.client.send_alexa_offer_to_master(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1188,18 +1189,38 @@ impl AddTagsToStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(AddTagsToStreamError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsToStreamError::InvalidArgument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AddTagsToStreamError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsToStreamError::LimitExceeded,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(AddTagsToStreamError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsToStreamError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddTagsToStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsToStreamError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1234,15 +1255,30 @@ impl CreateStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(CreateStreamError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStreamError::InvalidArgument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateStreamError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStreamError::LimitExceeded,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateStreamError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStreamError::ResourceInUse,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1280,26 +1316,38 @@ impl DecreaseStreamRetentionPeriodError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
-                        DecreaseStreamRetentionPeriodError::InvalidArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DecreaseStreamRetentionPeriodError::InvalidArgument,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DecreaseStreamRetentionPeriodError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DecreaseStreamRetentionPeriodError::LimitExceeded,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DecreaseStreamRetentionPeriodError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DecreaseStreamRetentionPeriodError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DecreaseStreamRetentionPeriodError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DecreaseStreamRetentionPeriodError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1338,15 +1386,30 @@ impl DeleteStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteStreamError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteStreamError::LimitExceeded,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteStreamError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteStreamError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteStreamError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1380,21 +1443,30 @@ impl DeregisterStreamConsumerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DeregisterStreamConsumerError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeregisterStreamConsumerError::InvalidArgument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeregisterStreamConsumerError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeregisterStreamConsumerError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeregisterStreamConsumerError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeregisterStreamConsumerError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1424,9 +1496,14 @@ impl DescribeLimitsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(DescribeLimitsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeLimitsError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1456,12 +1533,22 @@ impl DescribeStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(DescribeStreamError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeStreamError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeStreamError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1494,21 +1581,30 @@ impl DescribeStreamConsumerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DescribeStreamConsumerError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeStreamConsumerError::InvalidArgument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DescribeStreamConsumerError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeStreamConsumerError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeStreamConsumerError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeStreamConsumerError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1540,14 +1636,22 @@ impl DescribeStreamSummaryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(DescribeStreamSummaryError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeStreamSummaryError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeStreamSummaryError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeStreamSummaryError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1582,26 +1686,38 @@ impl DisableEnhancedMonitoringError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DisableEnhancedMonitoringError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisableEnhancedMonitoringError::InvalidArgument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DisableEnhancedMonitoringError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisableEnhancedMonitoringError::LimitExceeded,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DisableEnhancedMonitoringError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisableEnhancedMonitoringError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisableEnhancedMonitoringError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisableEnhancedMonitoringError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1638,26 +1754,38 @@ impl EnableEnhancedMonitoringError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(EnableEnhancedMonitoringError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        EnableEnhancedMonitoringError::InvalidArgument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(EnableEnhancedMonitoringError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        EnableEnhancedMonitoringError::LimitExceeded,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(EnableEnhancedMonitoringError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        EnableEnhancedMonitoringError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(EnableEnhancedMonitoringError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        EnableEnhancedMonitoringError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1706,38 +1834,86 @@ impl GetRecordsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExpiredIteratorException" => {
-                    return RusotoError::Service(GetRecordsError::ExpiredIterator(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRecordsError::ExpiredIterator,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(GetRecordsError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRecordsError::InvalidArgument,
+                    )
                 }
                 "KMSAccessDeniedException" => {
-                    return RusotoError::Service(GetRecordsError::KMSAccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRecordsError::KMSAccessDenied,
+                    )
                 }
                 "KMSDisabledException" => {
-                    return RusotoError::Service(GetRecordsError::KMSDisabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRecordsError::KMSDisabled,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(GetRecordsError::KMSInvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRecordsError::KMSInvalidState,
+                    )
                 }
                 "KMSNotFoundException" => {
-                    return RusotoError::Service(GetRecordsError::KMSNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRecordsError::KMSNotFound,
+                    )
                 }
                 "KMSOptInRequired" => {
-                    return RusotoError::Service(GetRecordsError::KMSOptInRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRecordsError::KMSOptInRequired,
+                    )
                 }
                 "KMSThrottlingException" => {
-                    return RusotoError::Service(GetRecordsError::KMSThrottling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRecordsError::KMSThrottling,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(GetRecordsError::ProvisionedThroughputExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRecordsError::ProvisionedThroughputExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetRecordsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRecordsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1778,17 +1954,30 @@ impl GetShardIteratorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(GetShardIteratorError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetShardIteratorError::InvalidArgument,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        GetShardIteratorError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetShardIteratorError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetShardIteratorError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetShardIteratorError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1828,26 +2017,38 @@ impl IncreaseStreamRetentionPeriodError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
-                        IncreaseStreamRetentionPeriodError::InvalidArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        IncreaseStreamRetentionPeriodError::InvalidArgument,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(IncreaseStreamRetentionPeriodError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        IncreaseStreamRetentionPeriodError::LimitExceeded,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(IncreaseStreamRetentionPeriodError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        IncreaseStreamRetentionPeriodError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        IncreaseStreamRetentionPeriodError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        IncreaseStreamRetentionPeriodError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1890,21 +2091,46 @@ impl ListShardsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExpiredNextTokenException" => {
-                    return RusotoError::Service(ListShardsError::ExpiredNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListShardsError::ExpiredNextToken,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListShardsError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListShardsError::InvalidArgument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListShardsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListShardsError::LimitExceeded,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(ListShardsError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListShardsError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListShardsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListShardsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1944,25 +2170,46 @@ impl ListStreamConsumersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExpiredNextTokenException" => {
-                    return RusotoError::Service(ListStreamConsumersError::ExpiredNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListStreamConsumersError::ExpiredNextToken,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListStreamConsumersError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListStreamConsumersError::InvalidArgument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListStreamConsumersError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListStreamConsumersError::LimitExceeded,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(ListStreamConsumersError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListStreamConsumersError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListStreamConsumersError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListStreamConsumersError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1994,9 +2241,14 @@ impl ListStreamsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListStreamsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListStreamsError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2028,15 +2280,30 @@ impl ListTagsForStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListTagsForStreamError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForStreamError::InvalidArgument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListTagsForStreamError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForStreamError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForStreamError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2072,18 +2339,38 @@ impl MergeShardsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(MergeShardsError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeShardsError::InvalidArgument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(MergeShardsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeShardsError::LimitExceeded,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(MergeShardsError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeShardsError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(MergeShardsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MergeShardsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2130,35 +2417,78 @@ impl PutRecordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(PutRecordError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRecordError::InvalidArgument,
+                    )
                 }
                 "KMSAccessDeniedException" => {
-                    return RusotoError::Service(PutRecordError::KMSAccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRecordError::KMSAccessDenied,
+                    )
                 }
                 "KMSDisabledException" => {
-                    return RusotoError::Service(PutRecordError::KMSDisabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRecordError::KMSDisabled,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(PutRecordError::KMSInvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRecordError::KMSInvalidState,
+                    )
                 }
                 "KMSNotFoundException" => {
-                    return RusotoError::Service(PutRecordError::KMSNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRecordError::KMSNotFound,
+                    )
                 }
                 "KMSOptInRequired" => {
-                    return RusotoError::Service(PutRecordError::KMSOptInRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRecordError::KMSOptInRequired,
+                    )
                 }
                 "KMSThrottlingException" => {
-                    return RusotoError::Service(PutRecordError::KMSThrottling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRecordError::KMSThrottling,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(PutRecordError::ProvisionedThroughputExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutRecordError::ProvisionedThroughputExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutRecordError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRecordError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2210,35 +2540,78 @@ impl PutRecordsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(PutRecordsError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRecordsError::InvalidArgument,
+                    )
                 }
                 "KMSAccessDeniedException" => {
-                    return RusotoError::Service(PutRecordsError::KMSAccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRecordsError::KMSAccessDenied,
+                    )
                 }
                 "KMSDisabledException" => {
-                    return RusotoError::Service(PutRecordsError::KMSDisabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRecordsError::KMSDisabled,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(PutRecordsError::KMSInvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRecordsError::KMSInvalidState,
+                    )
                 }
                 "KMSNotFoundException" => {
-                    return RusotoError::Service(PutRecordsError::KMSNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRecordsError::KMSNotFound,
+                    )
                 }
                 "KMSOptInRequired" => {
-                    return RusotoError::Service(PutRecordsError::KMSOptInRequired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRecordsError::KMSOptInRequired,
+                    )
                 }
                 "KMSThrottlingException" => {
-                    return RusotoError::Service(PutRecordsError::KMSThrottling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRecordsError::KMSThrottling,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(PutRecordsError::ProvisionedThroughputExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutRecordsError::ProvisionedThroughputExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutRecordsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRecordsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2280,26 +2653,38 @@ impl RegisterStreamConsumerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(RegisterStreamConsumerError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterStreamConsumerError::InvalidArgument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RegisterStreamConsumerError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterStreamConsumerError::LimitExceeded,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(RegisterStreamConsumerError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterStreamConsumerError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RegisterStreamConsumerError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterStreamConsumerError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2336,22 +2721,38 @@ impl RemoveTagsFromStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(RemoveTagsFromStreamError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveTagsFromStreamError::InvalidArgument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RemoveTagsFromStreamError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveTagsFromStreamError::LimitExceeded,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(RemoveTagsFromStreamError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveTagsFromStreamError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RemoveTagsFromStreamError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveTagsFromStreamError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2388,18 +2789,38 @@ impl SplitShardError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(SplitShardError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SplitShardError::InvalidArgument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(SplitShardError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SplitShardError::LimitExceeded,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(SplitShardError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SplitShardError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SplitShardError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SplitShardError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2448,46 +2869,86 @@ impl StartStreamEncryptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(StartStreamEncryptionError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartStreamEncryptionError::InvalidArgument,
+                    )
                 }
                 "KMSAccessDeniedException" => {
-                    return RusotoError::Service(StartStreamEncryptionError::KMSAccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartStreamEncryptionError::KMSAccessDenied,
+                    )
                 }
                 "KMSDisabledException" => {
-                    return RusotoError::Service(StartStreamEncryptionError::KMSDisabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartStreamEncryptionError::KMSDisabled,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(StartStreamEncryptionError::KMSInvalidState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartStreamEncryptionError::KMSInvalidState,
+                    )
                 }
                 "KMSNotFoundException" => {
-                    return RusotoError::Service(StartStreamEncryptionError::KMSNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartStreamEncryptionError::KMSNotFound,
+                    )
                 }
                 "KMSOptInRequired" => {
-                    return RusotoError::Service(StartStreamEncryptionError::KMSOptInRequired(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartStreamEncryptionError::KMSOptInRequired,
+                    )
                 }
                 "KMSThrottlingException" => {
-                    return RusotoError::Service(StartStreamEncryptionError::KMSThrottling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartStreamEncryptionError::KMSThrottling,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartStreamEncryptionError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartStreamEncryptionError::LimitExceeded,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StartStreamEncryptionError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartStreamEncryptionError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartStreamEncryptionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartStreamEncryptionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2530,22 +2991,38 @@ impl StopStreamEncryptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(StopStreamEncryptionError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopStreamEncryptionError::InvalidArgument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StopStreamEncryptionError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopStreamEncryptionError::LimitExceeded,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StopStreamEncryptionError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopStreamEncryptionError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopStreamEncryptionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopStreamEncryptionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2582,18 +3059,38 @@ impl SubscribeToShardError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(SubscribeToShardError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SubscribeToShardError::InvalidArgument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(SubscribeToShardError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SubscribeToShardError::LimitExceeded,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(SubscribeToShardError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SubscribeToShardError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SubscribeToShardError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SubscribeToShardError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2630,18 +3127,38 @@ impl UpdateShardCountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UpdateShardCountError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateShardCountError::InvalidArgument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateShardCountError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateShardCountError::LimitExceeded,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateShardCountError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateShardCountError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateShardCountError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateShardCountError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2838,11 +3355,12 @@ pub struct KinesisClient {
 impl KinesisClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> KinesisClient {
         KinesisClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Kinesis"),
         }
     }
 
@@ -2857,12 +3375,32 @@ impl KinesisClient {
     {
         KinesisClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Kinesis"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> KinesisClient {
-        KinesisClient { client, region }
+        KinesisClient {
+            client,
+            region: region_for_service(region, "Kinesis"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> KinesisClient {
+        KinesisClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Kinesis"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> KinesisClient {
+        KinesisClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -2874,6 +3412,7 @@ impl Kinesis for KinesisClient {
         input: AddTagsToStreamInput,
     ) -> Result<(), RusotoError<AddTagsToStreamError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AddTagsToStream");
         request.add_header("x-amz-target", "Kinesis_20131202.AddTagsToStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2891,6 +3430,7 @@ impl Kinesis for KinesisClient {
         input: CreateStreamInput,
     ) -> Result<(), RusotoError<CreateStreamError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateStream");
         request.add_header("x-amz-target", "Kinesis_20131202.CreateStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2908,6 +3448,7 @@ impl Kinesis for KinesisClient {
         input: DecreaseStreamRetentionPeriodInput,
     ) -> Result<(), RusotoError<DecreaseStreamRetentionPeriodError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DecreaseStreamRetentionPeriod");
         request.add_header(
             "x-amz-target",
             "Kinesis_20131202.DecreaseStreamRetentionPeriod",
@@ -2928,6 +3469,7 @@ impl Kinesis for KinesisClient {
         input: DeleteStreamInput,
     ) -> Result<(), RusotoError<DeleteStreamError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteStream");
         request.add_header("x-amz-target", "Kinesis_20131202.DeleteStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2945,6 +3487,7 @@ impl Kinesis for KinesisClient {
         input: DeregisterStreamConsumerInput,
     ) -> Result<(), RusotoError<DeregisterStreamConsumerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeregisterStreamConsumer");
         request.add_header("x-amz-target", "Kinesis_20131202.DeregisterStreamConsumer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2961,6 +3504,7 @@ impl Kinesis for KinesisClient {
         &self,
     ) -> Result<DescribeLimitsOutput, RusotoError<DescribeLimitsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeLimits");
         request.add_header("x-amz-target", "Kinesis_20131202.DescribeLimits");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -2978,6 +3522,7 @@ impl Kinesis for KinesisClient {
         input: DescribeStreamInput,
     ) -> Result<DescribeStreamOutput, RusotoError<DescribeStreamError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeStream");
         request.add_header("x-amz-target", "Kinesis_20131202.DescribeStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2996,6 +3541,7 @@ impl Kinesis for KinesisClient {
         input: DescribeStreamConsumerInput,
     ) -> Result<DescribeStreamConsumerOutput, RusotoError<DescribeStreamConsumerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeStreamConsumer");
         request.add_header("x-amz-target", "Kinesis_20131202.DescribeStreamConsumer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3015,6 +3561,7 @@ impl Kinesis for KinesisClient {
         input: DescribeStreamSummaryInput,
     ) -> Result<DescribeStreamSummaryOutput, RusotoError<DescribeStreamSummaryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeStreamSummary");
         request.add_header("x-amz-target", "Kinesis_20131202.DescribeStreamSummary");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3033,6 +3580,7 @@ impl Kinesis for KinesisClient {
         input: DisableEnhancedMonitoringInput,
     ) -> Result<EnhancedMonitoringOutput, RusotoError<DisableEnhancedMonitoringError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisableEnhancedMonitoring");
         request.add_header("x-amz-target", "Kinesis_20131202.DisableEnhancedMonitoring");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3051,6 +3599,7 @@ impl Kinesis for KinesisClient {
         input: EnableEnhancedMonitoringInput,
     ) -> Result<EnhancedMonitoringOutput, RusotoError<EnableEnhancedMonitoringError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("EnableEnhancedMonitoring");
         request.add_header("x-amz-target", "Kinesis_20131202.EnableEnhancedMonitoring");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3069,6 +3618,7 @@ impl Kinesis for KinesisClient {
         input: GetRecordsInput,
     ) -> Result<GetRecordsOutput, RusotoError<GetRecordsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRecords");
         request.add_header("x-amz-target", "Kinesis_20131202.GetRecords");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3087,6 +3637,7 @@ impl Kinesis for KinesisClient {
         input: GetShardIteratorInput,
     ) -> Result<GetShardIteratorOutput, RusotoError<GetShardIteratorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetShardIterator");
         request.add_header("x-amz-target", "Kinesis_20131202.GetShardIterator");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3105,6 +3656,7 @@ impl Kinesis for KinesisClient {
         input: IncreaseStreamRetentionPeriodInput,
     ) -> Result<(), RusotoError<IncreaseStreamRetentionPeriodError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("IncreaseStreamRetentionPeriod");
         request.add_header(
             "x-amz-target",
             "Kinesis_20131202.IncreaseStreamRetentionPeriod",
@@ -3125,6 +3677,7 @@ impl Kinesis for KinesisClient {
         input: ListShardsInput,
     ) -> Result<ListShardsOutput, RusotoError<ListShardsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListShards");
         request.add_header("x-amz-target", "Kinesis_20131202.ListShards");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3143,6 +3696,7 @@ impl Kinesis for KinesisClient {
         input: ListStreamConsumersInput,
     ) -> Result<ListStreamConsumersOutput, RusotoError<ListStreamConsumersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListStreamConsumers");
         request.add_header("x-amz-target", "Kinesis_20131202.ListStreamConsumers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3161,6 +3715,7 @@ impl Kinesis for KinesisClient {
         input: ListStreamsInput,
     ) -> Result<ListStreamsOutput, RusotoError<ListStreamsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListStreams");
         request.add_header("x-amz-target", "Kinesis_20131202.ListStreams");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3179,6 +3734,7 @@ impl Kinesis for KinesisClient {
         input: ListTagsForStreamInput,
     ) -> Result<ListTagsForStreamOutput, RusotoError<ListTagsForStreamError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForStream");
         request.add_header("x-amz-target", "Kinesis_20131202.ListTagsForStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3197,6 +3753,7 @@ impl Kinesis for KinesisClient {
         input: MergeShardsInput,
     ) -> Result<(), RusotoError<MergeShardsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("MergeShards");
         request.add_header("x-amz-target", "Kinesis_20131202.MergeShards");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3214,6 +3771,7 @@ impl Kinesis for KinesisClient {
         input: PutRecordInput,
     ) -> Result<PutRecordOutput, RusotoError<PutRecordError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutRecord");
         request.add_header("x-amz-target", "Kinesis_20131202.PutRecord");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3232,6 +3790,7 @@ impl Kinesis for KinesisClient {
         input: PutRecordsInput,
     ) -> Result<PutRecordsOutput, RusotoError<PutRecordsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutRecords");
         request.add_header("x-amz-target", "Kinesis_20131202.PutRecords");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3250,6 +3809,7 @@ impl Kinesis for KinesisClient {
         input: RegisterStreamConsumerInput,
     ) -> Result<RegisterStreamConsumerOutput, RusotoError<RegisterStreamConsumerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RegisterStreamConsumer");
         request.add_header("x-amz-target", "Kinesis_20131202.RegisterStreamConsumer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3269,6 +3829,7 @@ impl Kinesis for KinesisClient {
         input: RemoveTagsFromStreamInput,
     ) -> Result<(), RusotoError<RemoveTagsFromStreamError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RemoveTagsFromStream");
         request.add_header("x-amz-target", "Kinesis_20131202.RemoveTagsFromStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3286,6 +3847,7 @@ impl Kinesis for KinesisClient {
         input: SplitShardInput,
     ) -> Result<(), RusotoError<SplitShardError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SplitShard");
         request.add_header("x-amz-target", "Kinesis_20131202.SplitShard");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3303,6 +3865,7 @@ impl Kinesis for KinesisClient {
         input: StartStreamEncryptionInput,
     ) -> Result<(), RusotoError<StartStreamEncryptionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartStreamEncryption");
         request.add_header("x-amz-target", "Kinesis_20131202.StartStreamEncryption");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3320,6 +3883,7 @@ impl Kinesis for KinesisClient {
         input: StopStreamEncryptionInput,
     ) -> Result<(), RusotoError<StopStreamEncryptionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopStreamEncryption");
         request.add_header("x-amz-target", "Kinesis_20131202.StopStreamEncryption");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3337,6 +3901,7 @@ impl Kinesis for KinesisClient {
         input: SubscribeToShardInput,
     ) -> Result<SubscribeToShardOutput, RusotoError<SubscribeToShardError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SubscribeToShard");
         request.add_header("x-amz-target", "Kinesis_20131202.SubscribeToShard");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3355,6 +3920,7 @@ impl Kinesis for KinesisClient {
         input: UpdateShardCountInput,
     ) -> Result<UpdateShardCountOutput, RusotoError<UpdateShardCountError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateShardCount");
         request.add_header("x-amz-target", "Kinesis_20131202.UpdateShardCount");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3367,3 +3933,311 @@ impl Kinesis for KinesisClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateShardCountOutput, _>()
     }
 }
+
+/// A blocking client for the Kinesis API, running the calls of a `KinesisClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `KinesisClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct KinesisBlockingClient {
+    client: KinesisClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl KinesisBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> KinesisBlockingClient {
+        KinesisBlockingClient::from_client(KinesisClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> KinesisBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        KinesisBlockingClient::from_client(KinesisClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> KinesisBlockingClient {
+        KinesisBlockingClient::from_client(KinesisClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> KinesisBlockingClient {
+        KinesisBlockingClient::from_client(KinesisClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: KinesisClient) -> KinesisBlockingClient {
+        KinesisBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> KinesisBlockingClient {
+        KinesisBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> KinesisBlockingClient {
+        KinesisBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Kinesis::add_tags_to_stream`].
+    pub fn add_tags_to_stream(
+        &self,
+        input: AddTagsToStreamInput,
+    ) -> Result<(), RusotoError<AddTagsToStreamError>> {
+        self.runtime.block_on(self.client.add_tags_to_stream(input))
+    }
+
+    /// Blocking version of [`Kinesis::create_stream`].
+    pub fn create_stream(
+        &self,
+        input: CreateStreamInput,
+    ) -> Result<(), RusotoError<CreateStreamError>> {
+        self.runtime.block_on(self.client.create_stream(input))
+    }
+
+    /// Blocking version of [`Kinesis::decrease_stream_retention_period`].
+    pub fn decrease_stream_retention_period(
+        &self,
+        input: DecreaseStreamRetentionPeriodInput,
+    ) -> Result<(), RusotoError<DecreaseStreamRetentionPeriodError>> {
+        self.runtime
+            .block_on(self.client.decrease_stream_retention_period(input))
+    }
+
+    /// Blocking version of [`Kinesis::delete_stream`].
+    pub fn delete_stream(
+        &self,
+        input: DeleteStreamInput,
+    ) -> Result<(), RusotoError<DeleteStreamError>> {
+        self.runtime.block_on(self.client.delete_stream(input))
+    }
+
+    /// Blocking version of [`Kinesis::deregister_stream_consumer`].
+    pub fn deregister_stream_consumer(
+        &self,
+        input: DeregisterStreamConsumerInput,
+    ) -> Result<(), RusotoError<DeregisterStreamConsumerError>> {
+        self.runtime
+            .block_on(self.client.deregister_stream_consumer(input))
+    }
+
+    /// Blocking version of [`Kinesis::describe_limits`].
+    pub fn describe_limits(
+        &self,
+    ) -> Result<DescribeLimitsOutput, RusotoError<DescribeLimitsError>> {
+        self.runtime.block_on(self.client.describe_limits())
+    }
+
+    /// Blocking version of [`Kinesis::describe_stream`].
+    pub fn describe_stream(
+        &self,
+        input: DescribeStreamInput,
+    ) -> Result<DescribeStreamOutput, RusotoError<DescribeStreamError>> {
+        self.runtime.block_on(self.client.describe_stream(input))
+    }
+
+    /// Blocking version of [`Kinesis::describe_stream_consumer`].
+    pub fn describe_stream_consumer(
+        &self,
+        input: DescribeStreamConsumerInput,
+    ) -> Result<DescribeStreamConsumerOutput, RusotoError<DescribeStreamConsumerError>> {
+        self.runtime
+            .block_on(self.client.describe_stream_consumer(input))
+    }
+
+    /// Blocking version of [`Kinesis::describe_stream_summary`].
+    pub fn describe_stream_summary(
+        &self,
+        input: DescribeStreamSummaryInput,
+    ) -> Result<DescribeStreamSummaryOutput, RusotoError<DescribeStreamSummaryError>> {
+        self.runtime
+            .block_on(self.client.describe_stream_summary(input))
+    }
+
+    /// Blocking version of [`Kinesis::disable_enhanced_monitoring`].
+    pub fn disable_enhanced_monitoring(
+        &self,
+        input: DisableEnhancedMonitoringInput,
+    ) -> Result<EnhancedMonitoringOutput, RusotoError<DisableEnhancedMonitoringError>> {
+        self.runtime
+            .block_on(self.client.disable_enhanced_monitoring(input))
+    }
+
+    /// Blocking version of [`Kinesis::enable_enhanced_monitoring`].
+    pub fn enable_enhanced_monitoring(
+        &self,
+        input: EnableEnhancedMonitoringInput,
+    ) -> Result<EnhancedMonitoringOutput, RusotoError<EnableEnhancedMonitoringError>> {
+        self.runtime
+            .block_on(self.client.enable_enhanced_monitoring(input))
+    }
+
+    /// Blocking version of [`Kinesis::get_records`].
+    pub fn get_records(
+        &self,
+        input: GetRecordsInput,
+    ) -> Result<GetRecordsOutput, RusotoError<GetRecordsError>> {
+        self.runtime.block_on(self.client.get_records(input))
+    }
+
+    /// Blocking version of [`Kinesis::get_shard_iterator`].
+    pub fn get_shard_iterator(
+        &self,
+        input: GetShardIteratorInput,
+    ) -> Result<GetShardIteratorOutput, RusotoError<GetShardIteratorError>> {
+        self.runtime.block_on(self.client.get_shard_iterator(input))
+    }
+
+    /// Blocking version of [`Kinesis::increase_stream_retention_period`].
+    pub fn increase_stream_retention_period(
+        &self,
+        input: IncreaseStreamRetentionPeriodInput,
+    ) -> Result<(), RusotoError<IncreaseStreamRetentionPeriodError>> {
+        self.runtime
+            .block_on(self.client.increase_stream_retention_period(input))
+    }
+
+    /// Blocking version of [`Kinesis::list_shards`].
+    pub fn list_shards(
+        &self,
+        input: ListShardsInput,
+    ) -> Result<ListShardsOutput, RusotoError<ListShardsError>> {
+        self.runtime.block_on(self.client.list_shards(input))
+    }
+
+    /// Blocking version of [`Kinesis::list_stream_consumers`].
+    pub fn list_stream_consumers(
+        &self,
+        input: ListStreamConsumersInput,
+    ) -> Result<ListStreamConsumersOutput, RusotoError<ListStreamConsumersError>> {
+        self.runtime
+            .block_on(self.client.list_stream_consumers(input))
+    }
+
+    /// Blocking version of [`Kinesis::list_streams`].
+    pub fn list_streams(
+        &self,
+        input: ListStreamsInput,
+    ) -> Result<ListStreamsOutput, RusotoError<ListStreamsError>> {
+        self.runtime.block_on(self.client.list_streams(input))
+    }
+
+    /// Blocking version of [`Kinesis::list_tags_for_stream`].
+    pub fn list_tags_for_stream(
+        &self,
+        input: ListTagsForStreamInput,
+    ) -> Result<ListTagsForStreamOutput, RusotoError<ListTagsForStreamError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_stream(input))
+    }
+
+    /// Blocking version of [`Kinesis::merge_shards`].
+    pub fn merge_shards(
+        &self,
+        input: MergeShardsInput,
+    ) -> Result<(), RusotoError<MergeShardsError>> {
+        self.runtime.block_on(self.client.merge_shards(input))
+    }
+
+    /// Blocking version of [`Kinesis::put_record`].
+    pub fn put_record(
+        &self,
+        input: PutRecordInput,
+    ) -> Result<PutRecordOutput, RusotoError<PutRecordError>> {
+        self.runtime.block_on(self.client.put_record(input))
+    }
+
+    /// Blocking version of [`Kinesis::put_records`].
+    pub fn put_records(
+        &self,
+        input: PutRecordsInput,
+    ) -> Result<PutRecordsOutput, RusotoError<PutRecordsError>> {
+        self.runtime.block_on(self.client.put_records(input))
+    }
+
+    /// Blocking version of [`Kinesis::register_stream_consumer`].
+    pub fn register_stream_consumer(
+        &self,
+        input: RegisterStreamConsumerInput,
+    ) -> Result<RegisterStreamConsumerOutput, RusotoError<RegisterStreamConsumerError>> {
+        self.runtime
+            .block_on(self.client.register_stream_consumer(input))
+    }
+
+    /// Blocking version of [`Kinesis::remove_tags_from_stream`].
+    pub fn remove_tags_from_stream(
+        &self,
+        input: RemoveTagsFromStreamInput,
+    ) -> Result<(), RusotoError<RemoveTagsFromStreamError>> {
+        self.runtime
+            .block_on(self.client.remove_tags_from_stream(input))
+    }
+
+    /// Blocking version of [`Kinesis::split_shard`].
+    pub fn split_shard(&self, input: SplitShardInput) -> Result<(), RusotoError<SplitShardError>> {
+        self.runtime.block_on(self.client.split_shard(input))
+    }
+
+    /// Blocking version of [`Kinesis::start_stream_encryption`].
+    pub fn start_stream_encryption(
+        &self,
+        input: StartStreamEncryptionInput,
+    ) -> Result<(), RusotoError<StartStreamEncryptionError>> {
+        self.runtime
+            .block_on(self.client.start_stream_encryption(input))
+    }
+
+    /// Blocking version of [`Kinesis::stop_stream_encryption`].
+    pub fn stop_stream_encryption(
+        &self,
+        input: StopStreamEncryptionInput,
+    ) -> Result<(), RusotoError<StopStreamEncryptionError>> {
+        self.runtime
+            .block_on(self.client.stop_stream_encryption(input))
+    }
+
+    /// Blocking version of [`Kinesis::subscribe_to_shard`].
+    pub fn subscribe_to_shard(
+        &self,
+        input: SubscribeToShardInput,
+    ) -> Result<SubscribeToShardOutput, RusotoError<SubscribeToShardError>> {
+        self.runtime.block_on(self.client.subscribe_to_shard(input))
+    }
+
+    /// Blocking version of [`Kinesis::update_shard_count`].
+    pub fn update_shard_count(
+        &self,
+        input: UpdateShardCountInput,
+    ) -> Result<UpdateShardCountOutput, RusotoError<UpdateShardCountError>> {
+        self.runtime.block_on(self.client.update_shard_count(input))
+    }
+}
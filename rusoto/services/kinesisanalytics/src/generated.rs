@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1353,31 +1354,46 @@ impl AddApplicationCloudWatchLoggingOptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        AddApplicationCloudWatchLoggingOptionError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationCloudWatchLoggingOptionError::ConcurrentModification,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
-                        AddApplicationCloudWatchLoggingOptionError::InvalidArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationCloudWatchLoggingOptionError::InvalidArgument,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
-                        AddApplicationCloudWatchLoggingOptionError::ResourceInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationCloudWatchLoggingOptionError::ResourceInUse,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        AddApplicationCloudWatchLoggingOptionError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationCloudWatchLoggingOptionError::ResourceNotFound,
                     )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
-                        AddApplicationCloudWatchLoggingOptionError::UnsupportedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationCloudWatchLoggingOptionError::UnsupportedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1429,30 +1445,54 @@ impl AddApplicationInputError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeValidationException" => {
-                    return RusotoError::Service(AddApplicationInputError::CodeValidation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationInputError::CodeValidation,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(AddApplicationInputError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddApplicationInputError::ConcurrentModification,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(AddApplicationInputError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationInputError::InvalidArgument,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(AddApplicationInputError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationInputError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddApplicationInputError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddApplicationInputError::ResourceNotFound,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(AddApplicationInputError::UnsupportedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddApplicationInputError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1495,35 +1535,46 @@ impl AddApplicationInputProcessingConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        AddApplicationInputProcessingConfigurationError::ConcurrentModification(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationInputProcessingConfigurationError::ConcurrentModification,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
-                        AddApplicationInputProcessingConfigurationError::InvalidArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationInputProcessingConfigurationError::InvalidArgument,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
-                        AddApplicationInputProcessingConfigurationError::ResourceInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationInputProcessingConfigurationError::ResourceInUse,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        AddApplicationInputProcessingConfigurationError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationInputProcessingConfigurationError::ResourceNotFound,
                     )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
-                        AddApplicationInputProcessingConfigurationError::UnsupportedOperation(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationInputProcessingConfigurationError::UnsupportedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1573,29 +1624,46 @@ impl AddApplicationOutputError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(AddApplicationOutputError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddApplicationOutputError::ConcurrentModification,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(AddApplicationOutputError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddApplicationOutputError::InvalidArgument,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(AddApplicationOutputError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationOutputError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddApplicationOutputError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddApplicationOutputError::ResourceNotFound,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(AddApplicationOutputError::UnsupportedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddApplicationOutputError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1637,31 +1705,46 @@ impl AddApplicationReferenceDataSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        AddApplicationReferenceDataSourceError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationReferenceDataSourceError::ConcurrentModification,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
-                        AddApplicationReferenceDataSourceError::InvalidArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationReferenceDataSourceError::InvalidArgument,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
-                        AddApplicationReferenceDataSourceError::ResourceInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationReferenceDataSourceError::ResourceInUse,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        AddApplicationReferenceDataSourceError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationReferenceDataSourceError::ResourceNotFound,
                     )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
-                        AddApplicationReferenceDataSourceError::UnsupportedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationReferenceDataSourceError::UnsupportedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1713,26 +1796,54 @@ impl CreateApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeValidationException" => {
-                    return RusotoError::Service(CreateApplicationError::CodeValidation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApplicationError::CodeValidation,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateApplicationError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateApplicationError::ConcurrentModification,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(CreateApplicationError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApplicationError::InvalidArgument,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateApplicationError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApplicationError::LimitExceeded,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateApplicationError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApplicationError::ResourceInUse,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreateApplicationError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApplicationError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1771,22 +1882,38 @@ impl DeleteApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteApplicationError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteApplicationError::ConcurrentModification,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteApplicationError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationError::ResourceNotFound,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DeleteApplicationError::UnsupportedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteApplicationError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1827,35 +1954,46 @@ impl DeleteApplicationCloudWatchLoggingOptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        DeleteApplicationCloudWatchLoggingOptionError::ConcurrentModification(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationCloudWatchLoggingOptionError::ConcurrentModification,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
-                        DeleteApplicationCloudWatchLoggingOptionError::InvalidArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationCloudWatchLoggingOptionError::InvalidArgument,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
-                        DeleteApplicationCloudWatchLoggingOptionError::ResourceInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationCloudWatchLoggingOptionError::ResourceInUse,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteApplicationCloudWatchLoggingOptionError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationCloudWatchLoggingOptionError::ResourceNotFound,
                     )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
-                        DeleteApplicationCloudWatchLoggingOptionError::UnsupportedOperation(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationCloudWatchLoggingOptionError::UnsupportedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1907,39 +2045,46 @@ impl DeleteApplicationInputProcessingConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        DeleteApplicationInputProcessingConfigurationError::ConcurrentModification(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationInputProcessingConfigurationError::ConcurrentModification,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
-                        DeleteApplicationInputProcessingConfigurationError::InvalidArgument(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationInputProcessingConfigurationError::InvalidArgument,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
-                        DeleteApplicationInputProcessingConfigurationError::ResourceInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationInputProcessingConfigurationError::ResourceInUse,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteApplicationInputProcessingConfigurationError::ResourceNotFound(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationInputProcessingConfigurationError::ResourceNotFound,
                     )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
-                        DeleteApplicationInputProcessingConfigurationError::UnsupportedOperation(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationInputProcessingConfigurationError::UnsupportedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1989,31 +2134,46 @@ impl DeleteApplicationOutputError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        DeleteApplicationOutputError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationOutputError::ConcurrentModification,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DeleteApplicationOutputError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteApplicationOutputError::InvalidArgument,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteApplicationOutputError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteApplicationOutputError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteApplicationOutputError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteApplicationOutputError::ResourceNotFound,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
-                        DeleteApplicationOutputError::UnsupportedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationOutputError::UnsupportedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2057,31 +2217,46 @@ impl DeleteApplicationReferenceDataSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        DeleteApplicationReferenceDataSourceError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationReferenceDataSourceError::ConcurrentModification,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
-                        DeleteApplicationReferenceDataSourceError::InvalidArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationReferenceDataSourceError::InvalidArgument,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
-                        DeleteApplicationReferenceDataSourceError::ResourceInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationReferenceDataSourceError::ResourceInUse,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteApplicationReferenceDataSourceError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationReferenceDataSourceError::ResourceNotFound,
                     )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
-                        DeleteApplicationReferenceDataSourceError::UnsupportedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationReferenceDataSourceError::UnsupportedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2125,16 +2300,22 @@ impl DescribeApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeApplicationError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeApplicationError::ResourceNotFound,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DescribeApplicationError::UnsupportedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeApplicationError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2169,24 +2350,38 @@ impl DiscoverInputSchemaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DiscoverInputSchemaError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DiscoverInputSchemaError::InvalidArgument,
+                    )
                 }
                 "ResourceProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        DiscoverInputSchemaError::ResourceProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DiscoverInputSchemaError::ResourceProvisionedThroughputExceeded,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DiscoverInputSchemaError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DiscoverInputSchemaError::ServiceUnavailable,
+                    )
                 }
                 "UnableToDetectSchemaException" => {
-                    return RusotoError::Service(DiscoverInputSchemaError::UnableToDetectSchema(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DiscoverInputSchemaError::UnableToDetectSchema,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2215,7 +2410,7 @@ impl ListApplicationsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListApplicationsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2245,19 +2440,30 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ConcurrentModification,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InvalidArgument,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2295,25 +2501,46 @@ impl StartApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidApplicationConfigurationException" => {
-                    return RusotoError::Service(
-                        StartApplicationError::InvalidApplicationConfiguration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartApplicationError::InvalidApplicationConfiguration,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(StartApplicationError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartApplicationError::InvalidArgument,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StartApplicationError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartApplicationError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartApplicationError::ResourceNotFound,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(StartApplicationError::UnsupportedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartApplicationError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2351,17 +2578,30 @@ impl StopApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StopApplicationError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopApplicationError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopApplicationError::ResourceNotFound,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(StopApplicationError::UnsupportedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopApplicationError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2399,21 +2639,46 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(TagResourceError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ConcurrentModification,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(TagResourceError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidArgument,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(TagResourceError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2453,23 +2718,46 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UntagResourceError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UntagResourceError::ConcurrentModification,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidArgument,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2511,28 +2799,54 @@ impl UpdateApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeValidationException" => {
-                    return RusotoError::Service(UpdateApplicationError::CodeValidation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApplicationError::CodeValidation,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateApplicationError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateApplicationError::ConcurrentModification,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UpdateApplicationError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApplicationError::InvalidArgument,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateApplicationError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApplicationError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApplicationError::ResourceNotFound,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(UpdateApplicationError::UnsupportedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateApplicationError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2704,11 +3018,12 @@ pub struct KinesisAnalyticsClient {
 impl KinesisAnalyticsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> KinesisAnalyticsClient {
         KinesisAnalyticsClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Kinesis Analytics"),
         }
     }
 
@@ -2723,12 +3038,32 @@ impl KinesisAnalyticsClient {
     {
         KinesisAnalyticsClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Kinesis Analytics"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> KinesisAnalyticsClient {
-        KinesisAnalyticsClient { client, region }
+        KinesisAnalyticsClient {
+            client,
+            region: region_for_service(region, "Kinesis Analytics"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> KinesisAnalyticsClient {
+        KinesisAnalyticsClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Kinesis Analytics"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> KinesisAnalyticsClient {
+        KinesisAnalyticsClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -2743,6 +3078,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         RusotoError<AddApplicationCloudWatchLoggingOptionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AddApplicationCloudWatchLoggingOption");
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20150814.AddApplicationCloudWatchLoggingOption",
@@ -2768,6 +3104,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: AddApplicationInputRequest,
     ) -> Result<AddApplicationInputResponse, RusotoError<AddApplicationInputError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AddApplicationInput");
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20150814.AddApplicationInput",
@@ -2792,6 +3129,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         RusotoError<AddApplicationInputProcessingConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AddApplicationInputProcessingConfiguration");
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20150814.AddApplicationInputProcessingConfiguration",
@@ -2817,6 +3155,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: AddApplicationOutputRequest,
     ) -> Result<AddApplicationOutputResponse, RusotoError<AddApplicationOutputError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AddApplicationOutput");
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20150814.AddApplicationOutput",
@@ -2842,6 +3181,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         RusotoError<AddApplicationReferenceDataSourceError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AddApplicationReferenceDataSource");
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20150814.AddApplicationReferenceDataSource",
@@ -2867,6 +3207,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: CreateApplicationRequest,
     ) -> Result<CreateApplicationResponse, RusotoError<CreateApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateApplication");
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20150814.CreateApplication",
@@ -2888,6 +3229,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: DeleteApplicationRequest,
     ) -> Result<DeleteApplicationResponse, RusotoError<DeleteApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteApplication");
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20150814.DeleteApplication",
@@ -2912,6 +3254,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         RusotoError<DeleteApplicationCloudWatchLoggingOptionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteApplicationCloudWatchLoggingOption");
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20150814.DeleteApplicationCloudWatchLoggingOption",
@@ -2940,6 +3283,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         RusotoError<DeleteApplicationInputProcessingConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteApplicationInputProcessingConfiguration");
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20150814.DeleteApplicationInputProcessingConfiguration",
@@ -2965,6 +3309,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: DeleteApplicationOutputRequest,
     ) -> Result<DeleteApplicationOutputResponse, RusotoError<DeleteApplicationOutputError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteApplicationOutput");
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20150814.DeleteApplicationOutput",
@@ -2990,6 +3335,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         RusotoError<DeleteApplicationReferenceDataSourceError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteApplicationReferenceDataSource");
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20150814.DeleteApplicationReferenceDataSource",
@@ -3015,6 +3361,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: DescribeApplicationRequest,
     ) -> Result<DescribeApplicationResponse, RusotoError<DescribeApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeApplication");
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20150814.DescribeApplication",
@@ -3036,6 +3383,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: DiscoverInputSchemaRequest,
     ) -> Result<DiscoverInputSchemaResponse, RusotoError<DiscoverInputSchemaError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DiscoverInputSchema");
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20150814.DiscoverInputSchema",
@@ -3057,6 +3405,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: ListApplicationsRequest,
     ) -> Result<ListApplicationsResponse, RusotoError<ListApplicationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListApplications");
         request.add_header("x-amz-target", "KinesisAnalytics_20150814.ListApplications");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3075,6 +3424,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20150814.ListTagsForResource",
@@ -3096,6 +3446,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: StartApplicationRequest,
     ) -> Result<StartApplicationResponse, RusotoError<StartApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartApplication");
         request.add_header("x-amz-target", "KinesisAnalytics_20150814.StartApplication");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3114,6 +3465,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: StopApplicationRequest,
     ) -> Result<StopApplicationResponse, RusotoError<StopApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopApplication");
         request.add_header("x-amz-target", "KinesisAnalytics_20150814.StopApplication");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3132,6 +3484,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "KinesisAnalytics_20150814.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3150,6 +3503,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "KinesisAnalytics_20150814.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3168,6 +3522,7 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         input: UpdateApplicationRequest,
     ) -> Result<UpdateApplicationResponse, RusotoError<UpdateApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateApplication");
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20150814.UpdateApplication",
@@ -3183,3 +3538,284 @@ impl KinesisAnalytics for KinesisAnalyticsClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateApplicationResponse, _>()
     }
 }
+
+/// A blocking client for the Kinesis Analytics API, running the calls of a `KinesisAnalyticsClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `KinesisAnalyticsClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct KinesisAnalyticsBlockingClient {
+    client: KinesisAnalyticsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl KinesisAnalyticsBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> KinesisAnalyticsBlockingClient {
+        KinesisAnalyticsBlockingClient::from_client(KinesisAnalyticsClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> KinesisAnalyticsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        KinesisAnalyticsBlockingClient::from_client(KinesisAnalyticsClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> KinesisAnalyticsBlockingClient {
+        KinesisAnalyticsBlockingClient::from_client(KinesisAnalyticsClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> KinesisAnalyticsBlockingClient {
+        KinesisAnalyticsBlockingClient::from_client(KinesisAnalyticsClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: KinesisAnalyticsClient) -> KinesisAnalyticsBlockingClient {
+        KinesisAnalyticsBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> KinesisAnalyticsBlockingClient {
+        KinesisAnalyticsBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> KinesisAnalyticsBlockingClient {
+        KinesisAnalyticsBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`KinesisAnalytics::add_application_cloud_watch_logging_option`].
+    pub fn add_application_cloud_watch_logging_option(
+        &self,
+        input: AddApplicationCloudWatchLoggingOptionRequest,
+    ) -> Result<
+        AddApplicationCloudWatchLoggingOptionResponse,
+        RusotoError<AddApplicationCloudWatchLoggingOptionError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .add_application_cloud_watch_logging_option(input),
+        )
+    }
+
+    /// Blocking version of [`KinesisAnalytics::add_application_input`].
+    pub fn add_application_input(
+        &self,
+        input: AddApplicationInputRequest,
+    ) -> Result<AddApplicationInputResponse, RusotoError<AddApplicationInputError>> {
+        self.runtime
+            .block_on(self.client.add_application_input(input))
+    }
+
+    /// Blocking version of [`KinesisAnalytics::add_application_input_processing_configuration`].
+    pub fn add_application_input_processing_configuration(
+        &self,
+        input: AddApplicationInputProcessingConfigurationRequest,
+    ) -> Result<
+        AddApplicationInputProcessingConfigurationResponse,
+        RusotoError<AddApplicationInputProcessingConfigurationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .add_application_input_processing_configuration(input),
+        )
+    }
+
+    /// Blocking version of [`KinesisAnalytics::add_application_output`].
+    pub fn add_application_output(
+        &self,
+        input: AddApplicationOutputRequest,
+    ) -> Result<AddApplicationOutputResponse, RusotoError<AddApplicationOutputError>> {
+        self.runtime
+            .block_on(self.client.add_application_output(input))
+    }
+
+    /// Blocking version of [`KinesisAnalytics::add_application_reference_data_source`].
+    pub fn add_application_reference_data_source(
+        &self,
+        input: AddApplicationReferenceDataSourceRequest,
+    ) -> Result<
+        AddApplicationReferenceDataSourceResponse,
+        RusotoError<AddApplicationReferenceDataSourceError>,
+    > {
+        self.runtime
+            .block_on(self.client.add_application_reference_data_source(input))
+    }
+
+    /// Blocking version of [`KinesisAnalytics::create_application`].
+    pub fn create_application(
+        &self,
+        input: CreateApplicationRequest,
+    ) -> Result<CreateApplicationResponse, RusotoError<CreateApplicationError>> {
+        self.runtime.block_on(self.client.create_application(input))
+    }
+
+    /// Blocking version of [`KinesisAnalytics::delete_application`].
+    pub fn delete_application(
+        &self,
+        input: DeleteApplicationRequest,
+    ) -> Result<DeleteApplicationResponse, RusotoError<DeleteApplicationError>> {
+        self.runtime.block_on(self.client.delete_application(input))
+    }
+
+    /// Blocking version of [`KinesisAnalytics::delete_application_cloud_watch_logging_option`].
+    pub fn delete_application_cloud_watch_logging_option(
+        &self,
+        input: DeleteApplicationCloudWatchLoggingOptionRequest,
+    ) -> Result<
+        DeleteApplicationCloudWatchLoggingOptionResponse,
+        RusotoError<DeleteApplicationCloudWatchLoggingOptionError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .delete_application_cloud_watch_logging_option(input),
+        )
+    }
+
+    /// Blocking version of [`KinesisAnalytics::delete_application_input_processing_configuration`].
+    pub fn delete_application_input_processing_configuration(
+        &self,
+        input: DeleteApplicationInputProcessingConfigurationRequest,
+    ) -> Result<
+        DeleteApplicationInputProcessingConfigurationResponse,
+        RusotoError<DeleteApplicationInputProcessingConfigurationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .delete_application_input_processing_configuration(input),
+        )
+    }
+
+    /// Blocking version of [`KinesisAnalytics::delete_application_output`].
+    pub fn delete_application_output(
+        &self,
+        input: DeleteApplicationOutputRequest,
+    ) -> Result<DeleteApplicationOutputResponse, RusotoError<DeleteApplicationOutputError>> {
+        self.runtime
+            .block_on(self.client.delete_application_output(input))
+    }
+
+    /// Blocking version of [`KinesisAnalytics::delete_application_reference_data_source`].
+    pub fn delete_application_reference_data_source(
+        &self,
+        input: DeleteApplicationReferenceDataSourceRequest,
+    ) -> Result<
+        DeleteApplicationReferenceDataSourceResponse,
+        RusotoError<DeleteApplicationReferenceDataSourceError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_application_reference_data_source(input))
+    }
+
+    /// Blocking version of [`KinesisAnalytics::describe_application`].
+    pub fn describe_application(
+        &self,
+        input: DescribeApplicationRequest,
+    ) -> Result<DescribeApplicationResponse, RusotoError<DescribeApplicationError>> {
+        self.runtime
+            .block_on(self.client.describe_application(input))
+    }
+
+    /// Blocking version of [`KinesisAnalytics::discover_input_schema`].
+    pub fn discover_input_schema(
+        &self,
+        input: DiscoverInputSchemaRequest,
+    ) -> Result<DiscoverInputSchemaResponse, RusotoError<DiscoverInputSchemaError>> {
+        self.runtime
+            .block_on(self.client.discover_input_schema(input))
+    }
+
+    /// Blocking version of [`KinesisAnalytics::list_applications`].
+    pub fn list_applications(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> Result<ListApplicationsResponse, RusotoError<ListApplicationsError>> {
+        self.runtime.block_on(self.client.list_applications(input))
+    }
+
+    /// Blocking version of [`KinesisAnalytics::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`KinesisAnalytics::start_application`].
+    pub fn start_application(
+        &self,
+        input: StartApplicationRequest,
+    ) -> Result<StartApplicationResponse, RusotoError<StartApplicationError>> {
+        self.runtime.block_on(self.client.start_application(input))
+    }
+
+    /// Blocking version of [`KinesisAnalytics::stop_application`].
+    pub fn stop_application(
+        &self,
+        input: StopApplicationRequest,
+    ) -> Result<StopApplicationResponse, RusotoError<StopApplicationError>> {
+        self.runtime.block_on(self.client.stop_application(input))
+    }
+
+    /// Blocking version of [`KinesisAnalytics::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`KinesisAnalytics::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`KinesisAnalytics::update_application`].
+    pub fn update_application(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> Result<UpdateApplicationResponse, RusotoError<UpdateApplicationError>> {
+        self.runtime.block_on(self.client.update_application(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -2286,38 +2287,54 @@ impl AddApplicationCloudWatchLoggingOptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        AddApplicationCloudWatchLoggingOptionError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationCloudWatchLoggingOptionError::ConcurrentModification,
                     )
                 }
                 "InvalidApplicationConfigurationException" => {
-                    return RusotoError::Service(
-                        AddApplicationCloudWatchLoggingOptionError::InvalidApplicationConfiguration(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationCloudWatchLoggingOptionError::InvalidApplicationConfiguration,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
-                        AddApplicationCloudWatchLoggingOptionError::InvalidArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationCloudWatchLoggingOptionError::InvalidArgument,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        AddApplicationCloudWatchLoggingOptionError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationCloudWatchLoggingOptionError::InvalidRequest,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
-                        AddApplicationCloudWatchLoggingOptionError::ResourceInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationCloudWatchLoggingOptionError::ResourceInUse,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        AddApplicationCloudWatchLoggingOptionError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationCloudWatchLoggingOptionError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2372,28 +2389,54 @@ impl AddApplicationInputError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeValidationException" => {
-                    return RusotoError::Service(AddApplicationInputError::CodeValidation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationInputError::CodeValidation,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(AddApplicationInputError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddApplicationInputError::ConcurrentModification,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(AddApplicationInputError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationInputError::InvalidArgument,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AddApplicationInputError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationInputError::InvalidRequest,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(AddApplicationInputError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationInputError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddApplicationInputError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddApplicationInputError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2436,33 +2479,46 @@ impl AddApplicationInputProcessingConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        AddApplicationInputProcessingConfigurationError::ConcurrentModification(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationInputProcessingConfigurationError::ConcurrentModification,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
-                        AddApplicationInputProcessingConfigurationError::InvalidArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationInputProcessingConfigurationError::InvalidArgument,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        AddApplicationInputProcessingConfigurationError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationInputProcessingConfigurationError::InvalidRequest,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
-                        AddApplicationInputProcessingConfigurationError::ResourceInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationInputProcessingConfigurationError::ResourceInUse,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        AddApplicationInputProcessingConfigurationError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationInputProcessingConfigurationError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2512,27 +2568,46 @@ impl AddApplicationOutputError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(AddApplicationOutputError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddApplicationOutputError::ConcurrentModification,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(AddApplicationOutputError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddApplicationOutputError::InvalidArgument,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AddApplicationOutputError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationOutputError::InvalidRequest,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(AddApplicationOutputError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationOutputError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddApplicationOutputError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddApplicationOutputError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2574,31 +2649,46 @@ impl AddApplicationReferenceDataSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        AddApplicationReferenceDataSourceError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationReferenceDataSourceError::ConcurrentModification,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
-                        AddApplicationReferenceDataSourceError::InvalidArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationReferenceDataSourceError::InvalidArgument,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        AddApplicationReferenceDataSourceError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationReferenceDataSourceError::InvalidRequest,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
-                        AddApplicationReferenceDataSourceError::ResourceInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationReferenceDataSourceError::ResourceInUse,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        AddApplicationReferenceDataSourceError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationReferenceDataSourceError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2650,33 +2740,46 @@ impl AddApplicationVpcConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        AddApplicationVpcConfigurationError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationVpcConfigurationError::ConcurrentModification,
                     )
                 }
                 "InvalidApplicationConfigurationException" => {
-                    return RusotoError::Service(
-                        AddApplicationVpcConfigurationError::InvalidApplicationConfiguration(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationVpcConfigurationError::InvalidApplicationConfiguration,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
-                        AddApplicationVpcConfigurationError::InvalidArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationVpcConfigurationError::InvalidArgument,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
-                        AddApplicationVpcConfigurationError::ResourceInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationVpcConfigurationError::ResourceInUse,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        AddApplicationVpcConfigurationError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddApplicationVpcConfigurationError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2728,29 +2831,62 @@ impl CreateApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeValidationException" => {
-                    return RusotoError::Service(CreateApplicationError::CodeValidation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApplicationError::CodeValidation,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateApplicationError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateApplicationError::ConcurrentModification,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(CreateApplicationError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApplicationError::InvalidArgument,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateApplicationError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApplicationError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateApplicationError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApplicationError::LimitExceeded,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateApplicationError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApplicationError::ResourceInUse,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(CreateApplicationError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApplicationError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2790,21 +2926,30 @@ impl CreateApplicationPresignedUrlError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
-                        CreateApplicationPresignedUrlError::InvalidArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApplicationPresignedUrlError::InvalidArgument,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateApplicationPresignedUrlError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateApplicationPresignedUrlError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        CreateApplicationPresignedUrlError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApplicationPresignedUrlError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2850,41 +2995,62 @@ impl CreateApplicationSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidApplicationConfigurationException" => {
-                    return RusotoError::Service(
-                        CreateApplicationSnapshotError::InvalidApplicationConfiguration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApplicationSnapshotError::InvalidApplicationConfiguration,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(CreateApplicationSnapshotError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateApplicationSnapshotError::InvalidArgument,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateApplicationSnapshotError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateApplicationSnapshotError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateApplicationSnapshotError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateApplicationSnapshotError::LimitExceeded,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateApplicationSnapshotError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateApplicationSnapshotError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateApplicationSnapshotError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateApplicationSnapshotError::ResourceNotFound,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
-                        CreateApplicationSnapshotError::UnsupportedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateApplicationSnapshotError::UnsupportedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2932,28 +3098,54 @@ impl DeleteApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteApplicationError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteApplicationError::ConcurrentModification,
+                    )
                 }
                 "InvalidApplicationConfigurationException" => {
-                    return RusotoError::Service(
-                        DeleteApplicationError::InvalidApplicationConfiguration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationError::InvalidApplicationConfiguration,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DeleteApplicationError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationError::InvalidArgument,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteApplicationError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationError::InvalidRequest,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteApplicationError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3000,38 +3192,52 @@ impl DeleteApplicationCloudWatchLoggingOptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        DeleteApplicationCloudWatchLoggingOptionError::ConcurrentModification(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationCloudWatchLoggingOptionError::ConcurrentModification,
                     )
                 }
-                "InvalidApplicationConfigurationException" => return RusotoError::Service(
-                    DeleteApplicationCloudWatchLoggingOptionError::InvalidApplicationConfiguration(
-                        err.msg,
-                    ),
+                "InvalidApplicationConfigurationException" => return RusotoError::service_error(
+                    &res,
+                    &err.typ,
+                    err.msg,
+                    DeleteApplicationCloudWatchLoggingOptionError::InvalidApplicationConfiguration,
                 ),
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
-                        DeleteApplicationCloudWatchLoggingOptionError::InvalidArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationCloudWatchLoggingOptionError::InvalidArgument,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        DeleteApplicationCloudWatchLoggingOptionError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationCloudWatchLoggingOptionError::InvalidRequest,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
-                        DeleteApplicationCloudWatchLoggingOptionError::ResourceInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationCloudWatchLoggingOptionError::ResourceInUse,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteApplicationCloudWatchLoggingOptionError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationCloudWatchLoggingOptionError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3086,37 +3292,46 @@ impl DeleteApplicationInputProcessingConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        DeleteApplicationInputProcessingConfigurationError::ConcurrentModification(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationInputProcessingConfigurationError::ConcurrentModification,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
-                        DeleteApplicationInputProcessingConfigurationError::InvalidArgument(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationInputProcessingConfigurationError::InvalidArgument,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        DeleteApplicationInputProcessingConfigurationError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationInputProcessingConfigurationError::InvalidRequest,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
-                        DeleteApplicationInputProcessingConfigurationError::ResourceInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationInputProcessingConfigurationError::ResourceInUse,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteApplicationInputProcessingConfigurationError::ResourceNotFound(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationInputProcessingConfigurationError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3166,31 +3381,46 @@ impl DeleteApplicationOutputError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        DeleteApplicationOutputError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationOutputError::ConcurrentModification,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DeleteApplicationOutputError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteApplicationOutputError::InvalidArgument,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteApplicationOutputError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteApplicationOutputError::InvalidRequest,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteApplicationOutputError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteApplicationOutputError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteApplicationOutputError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteApplicationOutputError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3234,31 +3464,46 @@ impl DeleteApplicationReferenceDataSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        DeleteApplicationReferenceDataSourceError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationReferenceDataSourceError::ConcurrentModification,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
-                        DeleteApplicationReferenceDataSourceError::InvalidArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationReferenceDataSourceError::InvalidArgument,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        DeleteApplicationReferenceDataSourceError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationReferenceDataSourceError::InvalidRequest,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
-                        DeleteApplicationReferenceDataSourceError::ResourceInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationReferenceDataSourceError::ResourceInUse,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteApplicationReferenceDataSourceError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationReferenceDataSourceError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3308,31 +3553,46 @@ impl DeleteApplicationSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DeleteApplicationSnapshotError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteApplicationSnapshotError::InvalidArgument,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteApplicationSnapshotError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteApplicationSnapshotError::InvalidRequest,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteApplicationSnapshotError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteApplicationSnapshotError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteApplicationSnapshotError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteApplicationSnapshotError::ResourceNotFound,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
-                        DeleteApplicationSnapshotError::UnsupportedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationSnapshotError::UnsupportedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3376,33 +3636,46 @@ impl DeleteApplicationVpcConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        DeleteApplicationVpcConfigurationError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationVpcConfigurationError::ConcurrentModification,
                     )
                 }
                 "InvalidApplicationConfigurationException" => {
-                    return RusotoError::Service(
-                        DeleteApplicationVpcConfigurationError::InvalidApplicationConfiguration(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationVpcConfigurationError::InvalidApplicationConfiguration,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(
-                        DeleteApplicationVpcConfigurationError::InvalidArgument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationVpcConfigurationError::InvalidArgument,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(
-                        DeleteApplicationVpcConfigurationError::ResourceInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationVpcConfigurationError::ResourceInUse,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteApplicationVpcConfigurationError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteApplicationVpcConfigurationError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3448,17 +3721,30 @@ impl DescribeApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DescribeApplicationError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeApplicationError::InvalidArgument,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DescribeApplicationError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeApplicationError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeApplicationError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeApplicationError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3494,21 +3780,30 @@ impl DescribeApplicationSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DescribeApplicationSnapshotError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeApplicationSnapshotError::InvalidArgument,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeApplicationSnapshotError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeApplicationSnapshotError::ResourceNotFound,
                     )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
-                        DescribeApplicationSnapshotError::UnsupportedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeApplicationSnapshotError::UnsupportedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3548,27 +3843,46 @@ impl DiscoverInputSchemaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DiscoverInputSchemaError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DiscoverInputSchemaError::InvalidArgument,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DiscoverInputSchemaError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DiscoverInputSchemaError::InvalidRequest,
+                    )
                 }
                 "ResourceProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        DiscoverInputSchemaError::ResourceProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DiscoverInputSchemaError::ResourceProvisionedThroughputExceeded,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DiscoverInputSchemaError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DiscoverInputSchemaError::ServiceUnavailable,
+                    )
                 }
                 "UnableToDetectSchemaException" => {
-                    return RusotoError::Service(DiscoverInputSchemaError::UnableToDetectSchema(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DiscoverInputSchemaError::UnableToDetectSchema,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3604,16 +3918,22 @@ impl ListApplicationSnapshotsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListApplicationSnapshotsError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListApplicationSnapshotsError::InvalidArgument,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
-                        ListApplicationSnapshotsError::UnsupportedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListApplicationSnapshotsError::UnsupportedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3644,9 +3964,14 @@ impl ListApplicationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListApplicationsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListApplicationsError::InvalidRequest,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3678,19 +4003,30 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ConcurrentModification,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InvalidArgument,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3728,23 +4064,46 @@ impl StartApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidApplicationConfigurationException" => {
-                    return RusotoError::Service(
-                        StartApplicationError::InvalidApplicationConfiguration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartApplicationError::InvalidApplicationConfiguration,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(StartApplicationError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartApplicationError::InvalidArgument,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StartApplicationError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartApplicationError::InvalidRequest,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StartApplicationError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartApplicationError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartApplicationError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3788,28 +4147,54 @@ impl StopApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(StopApplicationError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopApplicationError::ConcurrentModification,
+                    )
                 }
                 "InvalidApplicationConfigurationException" => {
-                    return RusotoError::Service(
-                        StopApplicationError::InvalidApplicationConfiguration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopApplicationError::InvalidApplicationConfiguration,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(StopApplicationError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopApplicationError::InvalidArgument,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(StopApplicationError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopApplicationError::InvalidRequest,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StopApplicationError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopApplicationError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopApplicationError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3852,21 +4237,46 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(TagResourceError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ConcurrentModification,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(TagResourceError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidArgument,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(TagResourceError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3906,23 +4316,46 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UntagResourceError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UntagResourceError::ConcurrentModification,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidArgument,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
                 "TooManyTagsException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyTags(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::TooManyTags,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3966,31 +4399,62 @@ impl UpdateApplicationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CodeValidationException" => {
-                    return RusotoError::Service(UpdateApplicationError::CodeValidation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApplicationError::CodeValidation,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdateApplicationError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateApplicationError::ConcurrentModification,
+                    )
                 }
                 "InvalidApplicationConfigurationException" => {
-                    return RusotoError::Service(
-                        UpdateApplicationError::InvalidApplicationConfiguration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApplicationError::InvalidApplicationConfiguration,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UpdateApplicationError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApplicationError::InvalidArgument,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateApplicationError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApplicationError::InvalidRequest,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateApplicationError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApplicationError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateApplicationError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateApplicationError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4216,11 +4680,12 @@ pub struct KinesisAnalyticsV2Client {
 impl KinesisAnalyticsV2Client {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> KinesisAnalyticsV2Client {
         KinesisAnalyticsV2Client {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Kinesis Analytics V2"),
         }
     }
 
@@ -4235,12 +4700,32 @@ impl KinesisAnalyticsV2Client {
     {
         KinesisAnalyticsV2Client {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Kinesis Analytics V2"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> KinesisAnalyticsV2Client {
-        KinesisAnalyticsV2Client { client, region }
+        KinesisAnalyticsV2Client {
+            client,
+            region: region_for_service(region, "Kinesis Analytics V2"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> KinesisAnalyticsV2Client {
+        KinesisAnalyticsV2Client {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Kinesis Analytics V2"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> KinesisAnalyticsV2Client {
+        KinesisAnalyticsV2Client {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -4255,6 +4740,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         RusotoError<AddApplicationCloudWatchLoggingOptionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AddApplicationCloudWatchLoggingOption");
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.AddApplicationCloudWatchLoggingOption",
@@ -4280,6 +4766,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: AddApplicationInputRequest,
     ) -> Result<AddApplicationInputResponse, RusotoError<AddApplicationInputError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AddApplicationInput");
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.AddApplicationInput",
@@ -4304,6 +4791,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         RusotoError<AddApplicationInputProcessingConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AddApplicationInputProcessingConfiguration");
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.AddApplicationInputProcessingConfiguration",
@@ -4329,6 +4817,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: AddApplicationOutputRequest,
     ) -> Result<AddApplicationOutputResponse, RusotoError<AddApplicationOutputError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AddApplicationOutput");
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.AddApplicationOutput",
@@ -4354,6 +4843,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         RusotoError<AddApplicationReferenceDataSourceError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AddApplicationReferenceDataSource");
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.AddApplicationReferenceDataSource",
@@ -4382,6 +4872,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         RusotoError<AddApplicationVpcConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AddApplicationVpcConfiguration");
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.AddApplicationVpcConfiguration",
@@ -4404,6 +4895,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: CreateApplicationRequest,
     ) -> Result<CreateApplicationResponse, RusotoError<CreateApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateApplication");
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.CreateApplication",
@@ -4428,6 +4920,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         RusotoError<CreateApplicationPresignedUrlError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateApplicationPresignedUrl");
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.CreateApplicationPresignedUrl",
@@ -4451,6 +4944,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
     ) -> Result<CreateApplicationSnapshotResponse, RusotoError<CreateApplicationSnapshotError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateApplicationSnapshot");
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.CreateApplicationSnapshot",
@@ -4473,6 +4967,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: DeleteApplicationRequest,
     ) -> Result<DeleteApplicationResponse, RusotoError<DeleteApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteApplication");
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.DeleteApplication",
@@ -4497,6 +4992,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         RusotoError<DeleteApplicationCloudWatchLoggingOptionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteApplicationCloudWatchLoggingOption");
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.DeleteApplicationCloudWatchLoggingOption",
@@ -4525,6 +5021,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         RusotoError<DeleteApplicationInputProcessingConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteApplicationInputProcessingConfiguration");
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.DeleteApplicationInputProcessingConfiguration",
@@ -4550,6 +5047,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: DeleteApplicationOutputRequest,
     ) -> Result<DeleteApplicationOutputResponse, RusotoError<DeleteApplicationOutputError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteApplicationOutput");
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.DeleteApplicationOutput",
@@ -4575,6 +5073,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         RusotoError<DeleteApplicationReferenceDataSourceError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteApplicationReferenceDataSource");
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.DeleteApplicationReferenceDataSource",
@@ -4601,6 +5100,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
     ) -> Result<DeleteApplicationSnapshotResponse, RusotoError<DeleteApplicationSnapshotError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteApplicationSnapshot");
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.DeleteApplicationSnapshot",
@@ -4626,6 +5126,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         RusotoError<DeleteApplicationVpcConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteApplicationVpcConfiguration");
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.DeleteApplicationVpcConfiguration",
@@ -4651,6 +5152,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: DescribeApplicationRequest,
     ) -> Result<DescribeApplicationResponse, RusotoError<DescribeApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeApplication");
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.DescribeApplication",
@@ -4673,6 +5175,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
     ) -> Result<DescribeApplicationSnapshotResponse, RusotoError<DescribeApplicationSnapshotError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeApplicationSnapshot");
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.DescribeApplicationSnapshot",
@@ -4695,6 +5198,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: DiscoverInputSchemaRequest,
     ) -> Result<DiscoverInputSchemaResponse, RusotoError<DiscoverInputSchemaError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DiscoverInputSchema");
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.DiscoverInputSchema",
@@ -4716,6 +5220,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: ListApplicationSnapshotsRequest,
     ) -> Result<ListApplicationSnapshotsResponse, RusotoError<ListApplicationSnapshotsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListApplicationSnapshots");
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.ListApplicationSnapshots",
@@ -4738,6 +5243,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: ListApplicationsRequest,
     ) -> Result<ListApplicationsResponse, RusotoError<ListApplicationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListApplications");
         request.add_header("x-amz-target", "KinesisAnalytics_20180523.ListApplications");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4756,6 +5262,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.ListTagsForResource",
@@ -4777,6 +5284,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: StartApplicationRequest,
     ) -> Result<StartApplicationResponse, RusotoError<StartApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartApplication");
         request.add_header("x-amz-target", "KinesisAnalytics_20180523.StartApplication");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4795,6 +5303,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: StopApplicationRequest,
     ) -> Result<StopApplicationResponse, RusotoError<StopApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopApplication");
         request.add_header("x-amz-target", "KinesisAnalytics_20180523.StopApplication");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4813,6 +5322,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "KinesisAnalytics_20180523.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4831,6 +5341,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "KinesisAnalytics_20180523.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4849,6 +5360,7 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         input: UpdateApplicationRequest,
     ) -> Result<UpdateApplicationResponse, RusotoError<UpdateApplicationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateApplication");
         request.add_header(
             "x-amz-target",
             "KinesisAnalytics_20180523.UpdateApplication",
@@ -4864,3 +5376,361 @@ impl KinesisAnalyticsV2 for KinesisAnalyticsV2Client {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateApplicationResponse, _>()
     }
 }
+
+/// A blocking client for the Kinesis Analytics V2 API, running the calls of a `KinesisAnalyticsV2Client`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `KinesisAnalyticsV2Client`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct KinesisAnalyticsV2BlockingClient {
+    client: KinesisAnalyticsV2Client,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl KinesisAnalyticsV2BlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> KinesisAnalyticsV2BlockingClient {
+        KinesisAnalyticsV2BlockingClient::from_client(KinesisAnalyticsV2Client::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> KinesisAnalyticsV2BlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        KinesisAnalyticsV2BlockingClient::from_client(KinesisAnalyticsV2Client::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> KinesisAnalyticsV2BlockingClient {
+        KinesisAnalyticsV2BlockingClient::from_client(KinesisAnalyticsV2Client::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> KinesisAnalyticsV2BlockingClient {
+        KinesisAnalyticsV2BlockingClient::from_client(KinesisAnalyticsV2Client::new_with_config(
+            config,
+        ))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: KinesisAnalyticsV2Client) -> KinesisAnalyticsV2BlockingClient {
+        KinesisAnalyticsV2BlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> KinesisAnalyticsV2BlockingClient {
+        KinesisAnalyticsV2BlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> KinesisAnalyticsV2BlockingClient {
+        KinesisAnalyticsV2BlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`KinesisAnalyticsV2::add_application_cloud_watch_logging_option`].
+    pub fn add_application_cloud_watch_logging_option(
+        &self,
+        input: AddApplicationCloudWatchLoggingOptionRequest,
+    ) -> Result<
+        AddApplicationCloudWatchLoggingOptionResponse,
+        RusotoError<AddApplicationCloudWatchLoggingOptionError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .add_application_cloud_watch_logging_option(input),
+        )
+    }
+
+    /// Blocking version of [`KinesisAnalyticsV2::add_application_input`].
+    pub fn add_application_input(
+        &self,
+        input: AddApplicationInputRequest,
+    ) -> Result<AddApplicationInputResponse, RusotoError<AddApplicationInputError>> {
+        self.runtime
+            .block_on(self.client.add_application_input(input))
+    }
+
+    /// Blocking version of [`KinesisAnalyticsV2::add_application_input_processing_configuration`].
+    pub fn add_application_input_processing_configuration(
+        &self,
+        input: AddApplicationInputProcessingConfigurationRequest,
+    ) -> Result<
+        AddApplicationInputProcessingConfigurationResponse,
+        RusotoError<AddApplicationInputProcessingConfigurationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .add_application_input_processing_configuration(input),
+        )
+    }
+
+    /// Blocking version of [`KinesisAnalyticsV2::add_application_output`].
+    pub fn add_application_output(
+        &self,
+        input: AddApplicationOutputRequest,
+    ) -> Result<AddApplicationOutputResponse, RusotoError<AddApplicationOutputError>> {
+        self.runtime
+            .block_on(self.client.add_application_output(input))
+    }
+
+    /// Blocking version of [`KinesisAnalyticsV2::add_application_reference_data_source`].
+    pub fn add_application_reference_data_source(
+        &self,
+        input: AddApplicationReferenceDataSourceRequest,
+    ) -> Result<
+        AddApplicationReferenceDataSourceResponse,
+        RusotoError<AddApplicationReferenceDataSourceError>,
+    > {
+        self.runtime
+            .block_on(self.client.add_application_reference_data_source(input))
+    }
+
+    /// Blocking version of [`KinesisAnalyticsV2::add_application_vpc_configuration`].
+    pub fn add_application_vpc_configuration(
+        &self,
+        input: AddApplicationVpcConfigurationRequest,
+    ) -> Result<
+        AddApplicationVpcConfigurationResponse,
+        RusotoError<AddApplicationVpcConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.add_application_vpc_configuration(input))
+    }
+
+    /// Blocking version of [`KinesisAnalyticsV2::create_application`].
+    pub fn create_application(
+        &self,
+        input: CreateApplicationRequest,
+    ) -> Result<CreateApplicationResponse, RusotoError<CreateApplicationError>> {
+        self.runtime.block_on(self.client.create_application(input))
+    }
+
+    /// Blocking version of [`KinesisAnalyticsV2::create_application_presigned_url`].
+    pub fn create_application_presigned_url(
+        &self,
+        input: CreateApplicationPresignedUrlRequest,
+    ) -> Result<
+        CreateApplicationPresignedUrlResponse,
+        RusotoError<CreateApplicationPresignedUrlError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_application_presigned_url(input))
+    }
+
+    /// Blocking version of [`KinesisAnalyticsV2::create_application_snapshot`].
+    pub fn create_application_snapshot(
+        &self,
+        input: CreateApplicationSnapshotRequest,
+    ) -> Result<CreateApplicationSnapshotResponse, RusotoError<CreateApplicationSnapshotError>>
+    {
+        self.runtime
+            .block_on(self.client.create_application_snapshot(input))
+    }
+
+    /// Blocking version of [`KinesisAnalyticsV2::delete_application`].
+    pub fn delete_application(
+        &self,
+        input: DeleteApplicationRequest,
+    ) -> Result<DeleteApplicationResponse, RusotoError<DeleteApplicationError>> {
+        self.runtime.block_on(self.client.delete_application(input))
+    }
+
+    /// Blocking version of [`KinesisAnalyticsV2::delete_application_cloud_watch_logging_option`].
+    pub fn delete_application_cloud_watch_logging_option(
+        &self,
+        input: DeleteApplicationCloudWatchLoggingOptionRequest,
+    ) -> Result<
+        DeleteApplicationCloudWatchLoggingOptionResponse,
+        RusotoError<DeleteApplicationCloudWatchLoggingOptionError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .delete_application_cloud_watch_logging_option(input),
+        )
+    }
+
+    /// Blocking version of [`KinesisAnalyticsV2::delete_application_input_processing_configuration`].
+    pub fn delete_application_input_processing_configuration(
+        &self,
+        input: DeleteApplicationInputProcessingConfigurationRequest,
+    ) -> Result<
+        DeleteApplicationInputProcessingConfigurationResponse,
+        RusotoError<DeleteApplicationInputProcessingConfigurationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .delete_application_input_processing_configuration(input),
+        )
+    }
+
+    /// Blocking version of [`KinesisAnalyticsV2::delete_application_output`].
+    pub fn delete_application_output(
+        &self,
+        input: DeleteApplicationOutputRequest,
+    ) -> Result<DeleteApplicationOutputResponse, RusotoError<DeleteApplicationOutputError>> {
+        self.runtime
+            .block_on(self.client.delete_application_output(input))
+    }
+
+    /// Blocking version of [`KinesisAnalyticsV2::delete_application_reference_data_source`].
+    pub fn delete_application_reference_data_source(
+        &self,
+        input: DeleteApplicationReferenceDataSourceRequest,
+    ) -> Result<
+        DeleteApplicationReferenceDataSourceResponse,
+        RusotoError<DeleteApplicationReferenceDataSourceError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_application_reference_data_source(input))
+    }
+
+    /// Blocking version of [`KinesisAnalyticsV2::delete_application_snapshot`].
+    pub fn delete_application_snapshot(
+        &self,
+        input: DeleteApplicationSnapshotRequest,
+    ) -> Result<DeleteApplicationSnapshotResponse, RusotoError<DeleteApplicationSnapshotError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_application_snapshot(input))
+    }
+
+    /// Blocking version of [`KinesisAnalyticsV2::delete_application_vpc_configuration`].
+    pub fn delete_application_vpc_configuration(
+        &self,
+        input: DeleteApplicationVpcConfigurationRequest,
+    ) -> Result<
+        DeleteApplicationVpcConfigurationResponse,
+        RusotoError<DeleteApplicationVpcConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_application_vpc_configuration(input))
+    }
+
+    /// Blocking version of [`KinesisAnalyticsV2::describe_application`].
+    pub fn describe_application(
+        &self,
+        input: DescribeApplicationRequest,
+    ) -> Result<DescribeApplicationResponse, RusotoError<DescribeApplicationError>> {
+        self.runtime
+            .block_on(self.client.describe_application(input))
+    }
+
+    /// Blocking version of [`KinesisAnalyticsV2::describe_application_snapshot`].
+    pub fn describe_application_snapshot(
+        &self,
+        input: DescribeApplicationSnapshotRequest,
+    ) -> Result<DescribeApplicationSnapshotResponse, RusotoError<DescribeApplicationSnapshotError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_application_snapshot(input))
+    }
+
+    /// Blocking version of [`KinesisAnalyticsV2::discover_input_schema`].
+    pub fn discover_input_schema(
+        &self,
+        input: DiscoverInputSchemaRequest,
+    ) -> Result<DiscoverInputSchemaResponse, RusotoError<DiscoverInputSchemaError>> {
+        self.runtime
+            .block_on(self.client.discover_input_schema(input))
+    }
+
+    /// Blocking version of [`KinesisAnalyticsV2::list_application_snapshots`].
+    pub fn list_application_snapshots(
+        &self,
+        input: ListApplicationSnapshotsRequest,
+    ) -> Result<ListApplicationSnapshotsResponse, RusotoError<ListApplicationSnapshotsError>> {
+        self.runtime
+            .block_on(self.client.list_application_snapshots(input))
+    }
+
+    /// Blocking version of [`KinesisAnalyticsV2::list_applications`].
+    pub fn list_applications(
+        &self,
+        input: ListApplicationsRequest,
+    ) -> Result<ListApplicationsResponse, RusotoError<ListApplicationsError>> {
+        self.runtime.block_on(self.client.list_applications(input))
+    }
+
+    /// Blocking version of [`KinesisAnalyticsV2::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`KinesisAnalyticsV2::start_application`].
+    pub fn start_application(
+        &self,
+        input: StartApplicationRequest,
+    ) -> Result<StartApplicationResponse, RusotoError<StartApplicationError>> {
+        self.runtime.block_on(self.client.start_application(input))
+    }
+
+    /// Blocking version of [`KinesisAnalyticsV2::stop_application`].
+    pub fn stop_application(
+        &self,
+        input: StopApplicationRequest,
+    ) -> Result<StopApplicationResponse, RusotoError<StopApplicationError>> {
+        self.runtime.block_on(self.client.stop_application(input))
+    }
+
+    /// Blocking version of [`KinesisAnalyticsV2::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`KinesisAnalyticsV2::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`KinesisAnalyticsV2::update_application`].
+    pub fn update_application(
+        &self,
+        input: UpdateApplicationRequest,
+    ) -> Result<UpdateApplicationResponse, RusotoError<UpdateApplicationError>> {
+        self.runtime.block_on(self.client.update_application(input))
+    }
+}
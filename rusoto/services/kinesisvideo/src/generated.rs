@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -646,34 +647,54 @@ impl CreateSignalingChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateSignalingChannelError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSignalingChannelError::AccessDenied,
+                    )
                 }
                 "AccountChannelLimitExceededException" => {
-                    return RusotoError::Service(
-                        CreateSignalingChannelError::AccountChannelLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSignalingChannelError::AccountChannelLimitExceeded,
                     )
                 }
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(CreateSignalingChannelError::ClientLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSignalingChannelError::ClientLimitExceeded,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(CreateSignalingChannelError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSignalingChannelError::InvalidArgument,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateSignalingChannelError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSignalingChannelError::ResourceInUse,
+                    )
                 }
                 "TagsPerResourceExceededLimitException" => {
-                    return RusotoError::Service(
-                        CreateSignalingChannelError::TagsPerResourceExceededLimit(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSignalingChannelError::TagsPerResourceExceededLimit,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -722,33 +743,62 @@ impl CreateStreamError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccountStreamLimitExceededException" => {
-                    return RusotoError::Service(CreateStreamError::AccountStreamLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateStreamError::AccountStreamLimitExceeded,
+                    )
                 }
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(CreateStreamError::ClientLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStreamError::ClientLimitExceeded,
+                    )
                 }
                 "DeviceStreamLimitExceededException" => {
-                    return RusotoError::Service(CreateStreamError::DeviceStreamLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateStreamError::DeviceStreamLimitExceeded,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(CreateStreamError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStreamError::InvalidArgument,
+                    )
                 }
                 "InvalidDeviceException" => {
-                    return RusotoError::Service(CreateStreamError::InvalidDevice(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStreamError::InvalidDevice,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateStreamError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStreamError::ResourceInUse,
+                    )
                 }
                 "TagsPerResourceExceededLimitException" => {
-                    return RusotoError::Service(CreateStreamError::TagsPerResourceExceededLimit(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateStreamError::TagsPerResourceExceededLimit,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -792,34 +842,54 @@ impl DeleteSignalingChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteSignalingChannelError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSignalingChannelError::AccessDenied,
+                    )
                 }
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(DeleteSignalingChannelError::ClientLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSignalingChannelError::ClientLimitExceeded,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DeleteSignalingChannelError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSignalingChannelError::InvalidArgument,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteSignalingChannelError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSignalingChannelError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteSignalingChannelError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSignalingChannelError::ResourceNotFound,
+                    )
                 }
                 "VersionMismatchException" => {
-                    return RusotoError::Service(DeleteSignalingChannelError::VersionMismatch(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSignalingChannelError::VersionMismatch,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -862,24 +932,54 @@ impl DeleteStreamError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(DeleteStreamError::ClientLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteStreamError::ClientLimitExceeded,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DeleteStreamError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteStreamError::InvalidArgument,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DeleteStreamError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteStreamError::NotAuthorized,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteStreamError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteStreamError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteStreamError::ResourceNotFound,
+                    )
                 }
                 "VersionMismatchException" => {
-                    return RusotoError::Service(DeleteStreamError::VersionMismatch(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteStreamError::VersionMismatch,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -918,26 +1018,38 @@ impl DescribeSignalingChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeSignalingChannelError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeSignalingChannelError::AccessDenied,
+                    )
                 }
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(
-                        DescribeSignalingChannelError::ClientLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSignalingChannelError::ClientLimitExceeded,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DescribeSignalingChannelError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeSignalingChannelError::InvalidArgument,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeSignalingChannelError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeSignalingChannelError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -974,18 +1086,38 @@ impl DescribeStreamError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(DescribeStreamError::ClientLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeStreamError::ClientLimitExceeded,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DescribeStreamError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeStreamError::InvalidArgument,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeStreamError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeStreamError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeStreamError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1022,18 +1154,38 @@ impl GetDataEndpointError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(GetDataEndpointError::ClientLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDataEndpointError::ClientLimitExceeded,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(GetDataEndpointError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDataEndpointError::InvalidArgument,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetDataEndpointError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDataEndpointError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDataEndpointError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDataEndpointError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1074,31 +1226,46 @@ impl GetSignalingChannelEndpointError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetSignalingChannelEndpointError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSignalingChannelEndpointError::AccessDenied,
+                    )
                 }
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(
-                        GetSignalingChannelEndpointError::ClientLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSignalingChannelEndpointError::ClientLimitExceeded,
                     )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(GetSignalingChannelEndpointError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSignalingChannelEndpointError::InvalidArgument,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(GetSignalingChannelEndpointError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSignalingChannelEndpointError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        GetSignalingChannelEndpointError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSignalingChannelEndpointError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1136,19 +1303,30 @@ impl ListSignalingChannelsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListSignalingChannelsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSignalingChannelsError::AccessDenied,
+                    )
                 }
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(ListSignalingChannelsError::ClientLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSignalingChannelsError::ClientLimitExceeded,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListSignalingChannelsError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSignalingChannelsError::InvalidArgument,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1180,12 +1358,22 @@ impl ListStreamsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(ListStreamsError::ClientLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListStreamsError::ClientLimitExceeded,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListStreamsError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListStreamsError::InvalidArgument,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1220,22 +1408,38 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::AccessDenied,
+                    )
                 }
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ClientLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ClientLimitExceeded,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InvalidArgument,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1274,25 +1478,46 @@ impl ListTagsForStreamError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(ListTagsForStreamError::ClientLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForStreamError::ClientLimitExceeded,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(ListTagsForStreamError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForStreamError::InvalidArgument,
+                    )
                 }
                 "InvalidResourceFormatException" => {
-                    return RusotoError::Service(ListTagsForStreamError::InvalidResourceFormat(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForStreamError::InvalidResourceFormat,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(ListTagsForStreamError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForStreamError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForStreamError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1332,23 +1557,46 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(TagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::AccessDenied,
+                    )
                 }
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::ClientLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ClientLimitExceeded,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(TagResourceError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidArgument,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
                 "TagsPerResourceExceededLimitException" => {
-                    return RusotoError::Service(TagResourceError::TagsPerResourceExceededLimit(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TagResourceError::TagsPerResourceExceededLimit,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1390,26 +1638,54 @@ impl TagStreamError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(TagStreamError::ClientLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagStreamError::ClientLimitExceeded,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(TagStreamError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagStreamError::InvalidArgument,
+                    )
                 }
                 "InvalidResourceFormatException" => {
-                    return RusotoError::Service(TagStreamError::InvalidResourceFormat(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagStreamError::InvalidResourceFormat,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(TagStreamError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagStreamError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagStreamError::ResourceNotFound,
+                    )
                 }
                 "TagsPerResourceExceededLimitException" => {
-                    return RusotoError::Service(TagStreamError::TagsPerResourceExceededLimit(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TagStreamError::TagsPerResourceExceededLimit,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1448,18 +1724,38 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UntagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::AccessDenied,
+                    )
                 }
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(UntagResourceError::ClientLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ClientLimitExceeded,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidArgument,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1498,21 +1794,46 @@ impl UntagStreamError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(UntagStreamError::ClientLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagStreamError::ClientLimitExceeded,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UntagStreamError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagStreamError::InvalidArgument,
+                    )
                 }
                 "InvalidResourceFormatException" => {
-                    return RusotoError::Service(UntagStreamError::InvalidResourceFormat(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagStreamError::InvalidResourceFormat,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UntagStreamError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagStreamError::NotAuthorized,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagStreamError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1554,28 +1875,54 @@ impl UpdateDataRetentionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(UpdateDataRetentionError::ClientLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDataRetentionError::ClientLimitExceeded,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UpdateDataRetentionError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDataRetentionError::InvalidArgument,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UpdateDataRetentionError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDataRetentionError::NotAuthorized,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateDataRetentionError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDataRetentionError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDataRetentionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDataRetentionError::ResourceNotFound,
+                    )
                 }
                 "VersionMismatchException" => {
-                    return RusotoError::Service(UpdateDataRetentionError::VersionMismatch(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDataRetentionError::VersionMismatch,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1618,34 +1965,54 @@ impl UpdateSignalingChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateSignalingChannelError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSignalingChannelError::AccessDenied,
+                    )
                 }
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(UpdateSignalingChannelError::ClientLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSignalingChannelError::ClientLimitExceeded,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UpdateSignalingChannelError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSignalingChannelError::InvalidArgument,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateSignalingChannelError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSignalingChannelError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateSignalingChannelError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSignalingChannelError::ResourceNotFound,
+                    )
                 }
                 "VersionMismatchException" => {
-                    return RusotoError::Service(UpdateSignalingChannelError::VersionMismatch(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSignalingChannelError::VersionMismatch,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1688,24 +2055,54 @@ impl UpdateStreamError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ClientLimitExceededException" => {
-                    return RusotoError::Service(UpdateStreamError::ClientLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateStreamError::ClientLimitExceeded,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(UpdateStreamError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateStreamError::InvalidArgument,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(UpdateStreamError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateStreamError::NotAuthorized,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateStreamError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateStreamError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateStreamError::ResourceNotFound,
+                    )
                 }
                 "VersionMismatchException" => {
-                    return RusotoError::Service(UpdateStreamError::VersionMismatch(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateStreamError::VersionMismatch,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1853,11 +2250,12 @@ pub struct KinesisVideoClient {
 impl KinesisVideoClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> KinesisVideoClient {
         KinesisVideoClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Kinesis Video"),
         }
     }
 
@@ -1872,12 +2270,32 @@ impl KinesisVideoClient {
     {
         KinesisVideoClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Kinesis Video"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> KinesisVideoClient {
-        KinesisVideoClient { client, region }
+        KinesisVideoClient {
+            client,
+            region: region_for_service(region, "Kinesis Video"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> KinesisVideoClient {
+        KinesisVideoClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Kinesis Video"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> KinesisVideoClient {
+        KinesisVideoClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -1892,6 +2310,7 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/createSignalingChannel";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("CreateSignalingChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1923,6 +2342,7 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/createStream";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("CreateStream");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1954,6 +2374,7 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/deleteSignalingChannel";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("DeleteSignalingChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1985,6 +2406,7 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/deleteStream";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("DeleteStream");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2016,6 +2438,7 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/describeSignalingChannel";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("DescribeSignalingChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2047,6 +2470,7 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/describeStream";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("DescribeStream");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2078,6 +2502,7 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/getDataEndpoint";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("GetDataEndpoint");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2110,6 +2535,7 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/getSignalingChannelEndpoint";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("GetSignalingChannelEndpoint");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2141,6 +2567,7 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/listSignalingChannels";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("ListSignalingChannels");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2172,6 +2599,7 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/listStreams";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("ListStreams");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2203,6 +2631,7 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/ListTagsForResource";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2234,6 +2663,7 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/listTagsForStream";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("ListTagsForStream");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2265,6 +2695,7 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/TagResource";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2296,6 +2727,7 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/tagStream";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("TagStream");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2327,6 +2759,7 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/UntagResource";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2358,6 +2791,7 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/untagStream";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("UntagStream");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2389,6 +2823,7 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/updateDataRetention";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("UpdateDataRetention");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2420,6 +2855,7 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/updateSignalingChannel";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("UpdateSignalingChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2451,6 +2887,7 @@ impl KinesisVideo for KinesisVideoClient {
         let request_uri = "/updateStream";
 
         let mut request = SignedRequest::new("POST", "kinesisvideo", &self.region, &request_uri);
+        request.set_operation("UpdateStream");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2473,3 +2910,243 @@ impl KinesisVideo for KinesisVideoClient {
         }
     }
 }
+
+/// A blocking client for the Kinesis Video API, running the calls of a `KinesisVideoClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `KinesisVideoClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct KinesisVideoBlockingClient {
+    client: KinesisVideoClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl KinesisVideoBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> KinesisVideoBlockingClient {
+        KinesisVideoBlockingClient::from_client(KinesisVideoClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> KinesisVideoBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        KinesisVideoBlockingClient::from_client(KinesisVideoClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> KinesisVideoBlockingClient {
+        KinesisVideoBlockingClient::from_client(KinesisVideoClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> KinesisVideoBlockingClient {
+        KinesisVideoBlockingClient::from_client(KinesisVideoClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: KinesisVideoClient) -> KinesisVideoBlockingClient {
+        KinesisVideoBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> KinesisVideoBlockingClient {
+        KinesisVideoBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> KinesisVideoBlockingClient {
+        KinesisVideoBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`KinesisVideo::create_signaling_channel`].
+    pub fn create_signaling_channel(
+        &self,
+        input: CreateSignalingChannelInput,
+    ) -> Result<CreateSignalingChannelOutput, RusotoError<CreateSignalingChannelError>> {
+        self.runtime
+            .block_on(self.client.create_signaling_channel(input))
+    }
+
+    /// Blocking version of [`KinesisVideo::create_stream`].
+    pub fn create_stream(
+        &self,
+        input: CreateStreamInput,
+    ) -> Result<CreateStreamOutput, RusotoError<CreateStreamError>> {
+        self.runtime.block_on(self.client.create_stream(input))
+    }
+
+    /// Blocking version of [`KinesisVideo::delete_signaling_channel`].
+    pub fn delete_signaling_channel(
+        &self,
+        input: DeleteSignalingChannelInput,
+    ) -> Result<DeleteSignalingChannelOutput, RusotoError<DeleteSignalingChannelError>> {
+        self.runtime
+            .block_on(self.client.delete_signaling_channel(input))
+    }
+
+    /// Blocking version of [`KinesisVideo::delete_stream`].
+    pub fn delete_stream(
+        &self,
+        input: DeleteStreamInput,
+    ) -> Result<DeleteStreamOutput, RusotoError<DeleteStreamError>> {
+        self.runtime.block_on(self.client.delete_stream(input))
+    }
+
+    /// Blocking version of [`KinesisVideo::describe_signaling_channel`].
+    pub fn describe_signaling_channel(
+        &self,
+        input: DescribeSignalingChannelInput,
+    ) -> Result<DescribeSignalingChannelOutput, RusotoError<DescribeSignalingChannelError>> {
+        self.runtime
+            .block_on(self.client.describe_signaling_channel(input))
+    }
+
+    /// Blocking version of [`KinesisVideo::describe_stream`].
+    pub fn describe_stream(
+        &self,
+        input: DescribeStreamInput,
+    ) -> Result<DescribeStreamOutput, RusotoError<DescribeStreamError>> {
+        self.runtime.block_on(self.client.describe_stream(input))
+    }
+
+    /// Blocking version of [`KinesisVideo::get_data_endpoint`].
+    pub fn get_data_endpoint(
+        &self,
+        input: GetDataEndpointInput,
+    ) -> Result<GetDataEndpointOutput, RusotoError<GetDataEndpointError>> {
+        self.runtime.block_on(self.client.get_data_endpoint(input))
+    }
+
+    /// Blocking version of [`KinesisVideo::get_signaling_channel_endpoint`].
+    pub fn get_signaling_channel_endpoint(
+        &self,
+        input: GetSignalingChannelEndpointInput,
+    ) -> Result<GetSignalingChannelEndpointOutput, RusotoError<GetSignalingChannelEndpointError>>
+    {
+        self.runtime
+            .block_on(self.client.get_signaling_channel_endpoint(input))
+    }
+
+    /// Blocking version of [`KinesisVideo::list_signaling_channels`].
+    pub fn list_signaling_channels(
+        &self,
+        input: ListSignalingChannelsInput,
+    ) -> Result<ListSignalingChannelsOutput, RusotoError<ListSignalingChannelsError>> {
+        self.runtime
+            .block_on(self.client.list_signaling_channels(input))
+    }
+
+    /// Blocking version of [`KinesisVideo::list_streams`].
+    pub fn list_streams(
+        &self,
+        input: ListStreamsInput,
+    ) -> Result<ListStreamsOutput, RusotoError<ListStreamsError>> {
+        self.runtime.block_on(self.client.list_streams(input))
+    }
+
+    /// Blocking version of [`KinesisVideo::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`KinesisVideo::list_tags_for_stream`].
+    pub fn list_tags_for_stream(
+        &self,
+        input: ListTagsForStreamInput,
+    ) -> Result<ListTagsForStreamOutput, RusotoError<ListTagsForStreamError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_stream(input))
+    }
+
+    /// Blocking version of [`KinesisVideo::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`KinesisVideo::tag_stream`].
+    pub fn tag_stream(
+        &self,
+        input: TagStreamInput,
+    ) -> Result<TagStreamOutput, RusotoError<TagStreamError>> {
+        self.runtime.block_on(self.client.tag_stream(input))
+    }
+
+    /// Blocking version of [`KinesisVideo::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`KinesisVideo::untag_stream`].
+    pub fn untag_stream(
+        &self,
+        input: UntagStreamInput,
+    ) -> Result<UntagStreamOutput, RusotoError<UntagStreamError>> {
+        self.runtime.block_on(self.client.untag_stream(input))
+    }
+
+    /// Blocking version of [`KinesisVideo::update_data_retention`].
+    pub fn update_data_retention(
+        &self,
+        input: UpdateDataRetentionInput,
+    ) -> Result<UpdateDataRetentionOutput, RusotoError<UpdateDataRetentionError>> {
+        self.runtime
+            .block_on(self.client.update_data_retention(input))
+    }
+
+    /// Blocking version of [`KinesisVideo::update_signaling_channel`].
+    pub fn update_signaling_channel(
+        &self,
+        input: UpdateSignalingChannelInput,
+    ) -> Result<UpdateSignalingChannelOutput, RusotoError<UpdateSignalingChannelError>> {
+        self.runtime
+            .block_on(self.client.update_signaling_channel(input))
+    }
+
+    /// Blocking version of [`KinesisVideo::update_stream`].
+    pub fn update_stream(
+        &self,
+        input: UpdateStreamInput,
+    ) -> Result<UpdateStreamOutput, RusotoError<UpdateStreamError>> {
+        self.runtime.block_on(self.client.update_stream(input))
+    }
+}
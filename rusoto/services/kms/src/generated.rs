@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1556,21 +1557,46 @@ impl CancelKeyDeletionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(CancelKeyDeletionError::DependencyTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelKeyDeletionError::DependencyTimeout,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(CancelKeyDeletionError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelKeyDeletionError::InvalidArn,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(CancelKeyDeletionError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelKeyDeletionError::KMSInternal,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(CancelKeyDeletionError::KMSInvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelKeyDeletionError::KMSInvalidState,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CancelKeyDeletionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelKeyDeletionError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1610,29 +1636,46 @@ impl ConnectCustomKeyStoreError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmClusterInvalidConfigurationException" => {
-                    return RusotoError::Service(
-                        ConnectCustomKeyStoreError::CloudHsmClusterInvalidConfiguration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConnectCustomKeyStoreError::CloudHsmClusterInvalidConfiguration,
                     )
                 }
                 "CloudHsmClusterNotActiveException" => {
-                    return RusotoError::Service(
-                        ConnectCustomKeyStoreError::CloudHsmClusterNotActive(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConnectCustomKeyStoreError::CloudHsmClusterNotActive,
                     )
                 }
                 "CustomKeyStoreInvalidStateException" => {
-                    return RusotoError::Service(
-                        ConnectCustomKeyStoreError::CustomKeyStoreInvalidState(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConnectCustomKeyStoreError::CustomKeyStoreInvalidState,
                     )
                 }
                 "CustomKeyStoreNotFoundException" => {
-                    return RusotoError::Service(
-                        ConnectCustomKeyStoreError::CustomKeyStoreNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConnectCustomKeyStoreError::CustomKeyStoreNotFound,
                     )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(ConnectCustomKeyStoreError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ConnectCustomKeyStoreError::KMSInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1682,27 +1725,62 @@ impl CreateAliasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateAliasError::AlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAliasError::AlreadyExists,
+                    )
                 }
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(CreateAliasError::DependencyTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAliasError::DependencyTimeout,
+                    )
                 }
                 "InvalidAliasNameException" => {
-                    return RusotoError::Service(CreateAliasError::InvalidAliasName(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAliasError::InvalidAliasName,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(CreateAliasError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAliasError::KMSInternal,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(CreateAliasError::KMSInvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAliasError::KMSInvalidState,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateAliasError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAliasError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateAliasError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAliasError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1748,39 +1826,62 @@ impl CreateCustomKeyStoreError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmClusterInUseException" => {
-                    return RusotoError::Service(CreateCustomKeyStoreError::CloudHsmClusterInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCustomKeyStoreError::CloudHsmClusterInUse,
+                    )
                 }
                 "CloudHsmClusterInvalidConfigurationException" => {
-                    return RusotoError::Service(
-                        CreateCustomKeyStoreError::CloudHsmClusterInvalidConfiguration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCustomKeyStoreError::CloudHsmClusterInvalidConfiguration,
                     )
                 }
                 "CloudHsmClusterNotActiveException" => {
-                    return RusotoError::Service(
-                        CreateCustomKeyStoreError::CloudHsmClusterNotActive(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCustomKeyStoreError::CloudHsmClusterNotActive,
                     )
                 }
                 "CloudHsmClusterNotFoundException" => {
-                    return RusotoError::Service(
-                        CreateCustomKeyStoreError::CloudHsmClusterNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCustomKeyStoreError::CloudHsmClusterNotFound,
                     )
                 }
                 "CustomKeyStoreNameInUseException" => {
-                    return RusotoError::Service(
-                        CreateCustomKeyStoreError::CustomKeyStoreNameInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCustomKeyStoreError::CustomKeyStoreNameInUse,
                     )
                 }
                 "IncorrectTrustAnchorException" => {
-                    return RusotoError::Service(CreateCustomKeyStoreError::IncorrectTrustAnchor(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCustomKeyStoreError::IncorrectTrustAnchor,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(CreateCustomKeyStoreError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCustomKeyStoreError::KMSInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1832,30 +1933,70 @@ impl CreateGrantError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(CreateGrantError::DependencyTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGrantError::DependencyTimeout,
+                    )
                 }
                 "DisabledException" => {
-                    return RusotoError::Service(CreateGrantError::Disabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGrantError::Disabled,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(CreateGrantError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGrantError::InvalidArn,
+                    )
                 }
                 "InvalidGrantTokenException" => {
-                    return RusotoError::Service(CreateGrantError::InvalidGrantToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGrantError::InvalidGrantToken,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(CreateGrantError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGrantError::KMSInternal,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(CreateGrantError::KMSInvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGrantError::KMSInvalidState,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateGrantError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGrantError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateGrantError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGrantError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1908,38 +2049,81 @@ impl CreateKeyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmClusterInvalidConfigurationException" => {
-                    return RusotoError::Service(
-                        CreateKeyError::CloudHsmClusterInvalidConfiguration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateKeyError::CloudHsmClusterInvalidConfiguration,
                     )
                 }
                 "CustomKeyStoreInvalidStateException" => {
-                    return RusotoError::Service(CreateKeyError::CustomKeyStoreInvalidState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateKeyError::CustomKeyStoreInvalidState,
+                    )
                 }
                 "CustomKeyStoreNotFoundException" => {
-                    return RusotoError::Service(CreateKeyError::CustomKeyStoreNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateKeyError::CustomKeyStoreNotFound,
+                    )
                 }
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(CreateKeyError::DependencyTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateKeyError::DependencyTimeout,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(CreateKeyError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateKeyError::InvalidArn,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(CreateKeyError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateKeyError::KMSInternal,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateKeyError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateKeyError::LimitExceeded,
+                    )
                 }
                 "MalformedPolicyDocumentException" => {
-                    return RusotoError::Service(CreateKeyError::MalformedPolicyDocument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateKeyError::MalformedPolicyDocument,
+                    )
+                }
+                "TagException" => {
+                    return RusotoError::service_error(&res, &err.typ, err.msg, CreateKeyError::Tag)
                 }
-                "TagException" => return RusotoError::Service(CreateKeyError::Tag(err.msg)),
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(CreateKeyError::UnsupportedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateKeyError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1996,36 +2180,86 @@ impl DecryptError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(DecryptError::DependencyTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DecryptError::DependencyTimeout,
+                    )
                 }
                 "DisabledException" => {
-                    return RusotoError::Service(DecryptError::Disabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DecryptError::Disabled,
+                    )
                 }
                 "IncorrectKeyException" => {
-                    return RusotoError::Service(DecryptError::IncorrectKey(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DecryptError::IncorrectKey,
+                    )
                 }
                 "InvalidCiphertextException" => {
-                    return RusotoError::Service(DecryptError::InvalidCiphertext(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DecryptError::InvalidCiphertext,
+                    )
                 }
                 "InvalidGrantTokenException" => {
-                    return RusotoError::Service(DecryptError::InvalidGrantToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DecryptError::InvalidGrantToken,
+                    )
                 }
                 "InvalidKeyUsageException" => {
-                    return RusotoError::Service(DecryptError::InvalidKeyUsage(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DecryptError::InvalidKeyUsage,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(DecryptError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DecryptError::KMSInternal,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(DecryptError::KMSInvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DecryptError::KMSInvalidState,
+                    )
                 }
                 "KeyUnavailableException" => {
-                    return RusotoError::Service(DecryptError::KeyUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DecryptError::KeyUnavailable,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DecryptError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DecryptError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2068,18 +2302,38 @@ impl DeleteAliasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(DeleteAliasError::DependencyTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAliasError::DependencyTimeout,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(DeleteAliasError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAliasError::KMSInternal,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(DeleteAliasError::KMSInvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAliasError::KMSInvalidState,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteAliasError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAliasError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2116,24 +2370,38 @@ impl DeleteCustomKeyStoreError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CustomKeyStoreHasCMKsException" => {
-                    return RusotoError::Service(DeleteCustomKeyStoreError::CustomKeyStoreHasCMKs(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteCustomKeyStoreError::CustomKeyStoreHasCMKs,
+                    )
                 }
                 "CustomKeyStoreInvalidStateException" => {
-                    return RusotoError::Service(
-                        DeleteCustomKeyStoreError::CustomKeyStoreInvalidState(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCustomKeyStoreError::CustomKeyStoreInvalidState,
                     )
                 }
                 "CustomKeyStoreNotFoundException" => {
-                    return RusotoError::Service(DeleteCustomKeyStoreError::CustomKeyStoreNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteCustomKeyStoreError::CustomKeyStoreNotFound,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(DeleteCustomKeyStoreError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCustomKeyStoreError::KMSInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2176,34 +2444,54 @@ impl DeleteImportedKeyMaterialError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(DeleteImportedKeyMaterialError::DependencyTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteImportedKeyMaterialError::DependencyTimeout,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DeleteImportedKeyMaterialError::InvalidArn(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteImportedKeyMaterialError::InvalidArn,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(DeleteImportedKeyMaterialError::KMSInternal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteImportedKeyMaterialError::KMSInternal,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(DeleteImportedKeyMaterialError::KMSInvalidState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteImportedKeyMaterialError::KMSInvalidState,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteImportedKeyMaterialError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteImportedKeyMaterialError::NotFound,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
-                        DeleteImportedKeyMaterialError::UnsupportedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteImportedKeyMaterialError::UnsupportedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2240,14 +2528,22 @@ impl DescribeCustomKeyStoresError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CustomKeyStoreNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeCustomKeyStoresError::CustomKeyStoreNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCustomKeyStoresError::CustomKeyStoreNotFound,
                     )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(DescribeCustomKeyStoresError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCustomKeyStoresError::KMSInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2284,18 +2580,38 @@ impl DescribeKeyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(DescribeKeyError::DependencyTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeKeyError::DependencyTimeout,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DescribeKeyError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeKeyError::InvalidArn,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(DescribeKeyError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeKeyError::KMSInternal,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeKeyError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeKeyError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2334,21 +2650,46 @@ impl DisableKeyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(DisableKeyError::DependencyTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableKeyError::DependencyTimeout,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DisableKeyError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableKeyError::InvalidArn,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(DisableKeyError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableKeyError::KMSInternal,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(DisableKeyError::KMSInvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableKeyError::KMSInvalidState,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DisableKeyError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableKeyError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2392,31 +2733,62 @@ impl DisableKeyRotationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(DisableKeyRotationError::DependencyTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisableKeyRotationError::DependencyTimeout,
+                    )
                 }
                 "DisabledException" => {
-                    return RusotoError::Service(DisableKeyRotationError::Disabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableKeyRotationError::Disabled,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(DisableKeyRotationError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableKeyRotationError::InvalidArn,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(DisableKeyRotationError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableKeyRotationError::KMSInternal,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(DisableKeyRotationError::KMSInvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableKeyRotationError::KMSInvalidState,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DisableKeyRotationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableKeyRotationError::NotFound,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(DisableKeyRotationError::UnsupportedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisableKeyRotationError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2454,21 +2826,30 @@ impl DisconnectCustomKeyStoreError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CustomKeyStoreInvalidStateException" => {
-                    return RusotoError::Service(
-                        DisconnectCustomKeyStoreError::CustomKeyStoreInvalidState(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisconnectCustomKeyStoreError::CustomKeyStoreInvalidState,
                     )
                 }
                 "CustomKeyStoreNotFoundException" => {
-                    return RusotoError::Service(
-                        DisconnectCustomKeyStoreError::CustomKeyStoreNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisconnectCustomKeyStoreError::CustomKeyStoreNotFound,
                     )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(DisconnectCustomKeyStoreError::KMSInternal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisconnectCustomKeyStoreError::KMSInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2512,24 +2893,54 @@ impl EnableKeyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(EnableKeyError::DependencyTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableKeyError::DependencyTimeout,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(EnableKeyError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableKeyError::InvalidArn,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(EnableKeyError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableKeyError::KMSInternal,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(EnableKeyError::KMSInvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableKeyError::KMSInvalidState,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(EnableKeyError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableKeyError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(EnableKeyError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableKeyError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2574,29 +2985,62 @@ impl EnableKeyRotationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(EnableKeyRotationError::DependencyTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableKeyRotationError::DependencyTimeout,
+                    )
                 }
                 "DisabledException" => {
-                    return RusotoError::Service(EnableKeyRotationError::Disabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableKeyRotationError::Disabled,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(EnableKeyRotationError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableKeyRotationError::InvalidArn,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(EnableKeyRotationError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableKeyRotationError::KMSInternal,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(EnableKeyRotationError::KMSInvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableKeyRotationError::KMSInvalidState,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(EnableKeyRotationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableKeyRotationError::NotFound,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(EnableKeyRotationError::UnsupportedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        EnableKeyRotationError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2644,30 +3088,70 @@ impl EncryptError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(EncryptError::DependencyTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EncryptError::DependencyTimeout,
+                    )
                 }
                 "DisabledException" => {
-                    return RusotoError::Service(EncryptError::Disabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EncryptError::Disabled,
+                    )
                 }
                 "InvalidGrantTokenException" => {
-                    return RusotoError::Service(EncryptError::InvalidGrantToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EncryptError::InvalidGrantToken,
+                    )
                 }
                 "InvalidKeyUsageException" => {
-                    return RusotoError::Service(EncryptError::InvalidKeyUsage(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EncryptError::InvalidKeyUsage,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(EncryptError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EncryptError::KMSInternal,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(EncryptError::KMSInvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EncryptError::KMSInvalidState,
+                    )
                 }
                 "KeyUnavailableException" => {
-                    return RusotoError::Service(EncryptError::KeyUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EncryptError::KeyUnavailable,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(EncryptError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EncryptError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2716,30 +3200,70 @@ impl GenerateDataKeyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(GenerateDataKeyError::DependencyTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GenerateDataKeyError::DependencyTimeout,
+                    )
                 }
                 "DisabledException" => {
-                    return RusotoError::Service(GenerateDataKeyError::Disabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GenerateDataKeyError::Disabled,
+                    )
                 }
                 "InvalidGrantTokenException" => {
-                    return RusotoError::Service(GenerateDataKeyError::InvalidGrantToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GenerateDataKeyError::InvalidGrantToken,
+                    )
                 }
                 "InvalidKeyUsageException" => {
-                    return RusotoError::Service(GenerateDataKeyError::InvalidKeyUsage(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GenerateDataKeyError::InvalidKeyUsage,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(GenerateDataKeyError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GenerateDataKeyError::KMSInternal,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(GenerateDataKeyError::KMSInvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GenerateDataKeyError::KMSInvalidState,
+                    )
                 }
                 "KeyUnavailableException" => {
-                    return RusotoError::Service(GenerateDataKeyError::KeyUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GenerateDataKeyError::KeyUnavailable,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GenerateDataKeyError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GenerateDataKeyError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2790,39 +3314,78 @@ impl GenerateDataKeyPairError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(GenerateDataKeyPairError::DependencyTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GenerateDataKeyPairError::DependencyTimeout,
+                    )
                 }
                 "DisabledException" => {
-                    return RusotoError::Service(GenerateDataKeyPairError::Disabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GenerateDataKeyPairError::Disabled,
+                    )
                 }
                 "InvalidGrantTokenException" => {
-                    return RusotoError::Service(GenerateDataKeyPairError::InvalidGrantToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GenerateDataKeyPairError::InvalidGrantToken,
+                    )
                 }
                 "InvalidKeyUsageException" => {
-                    return RusotoError::Service(GenerateDataKeyPairError::InvalidKeyUsage(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GenerateDataKeyPairError::InvalidKeyUsage,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(GenerateDataKeyPairError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GenerateDataKeyPairError::KMSInternal,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(GenerateDataKeyPairError::KMSInvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GenerateDataKeyPairError::KMSInvalidState,
+                    )
                 }
                 "KeyUnavailableException" => {
-                    return RusotoError::Service(GenerateDataKeyPairError::KeyUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GenerateDataKeyPairError::KeyUnavailable,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GenerateDataKeyPairError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GenerateDataKeyPairError::NotFound,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(GenerateDataKeyPairError::UnsupportedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GenerateDataKeyPairError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2876,51 +3439,78 @@ impl GenerateDataKeyPairWithoutPlaintextError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(
-                        GenerateDataKeyPairWithoutPlaintextError::DependencyTimeout(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GenerateDataKeyPairWithoutPlaintextError::DependencyTimeout,
                     )
                 }
                 "DisabledException" => {
-                    return RusotoError::Service(
-                        GenerateDataKeyPairWithoutPlaintextError::Disabled(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GenerateDataKeyPairWithoutPlaintextError::Disabled,
                     )
                 }
                 "InvalidGrantTokenException" => {
-                    return RusotoError::Service(
-                        GenerateDataKeyPairWithoutPlaintextError::InvalidGrantToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GenerateDataKeyPairWithoutPlaintextError::InvalidGrantToken,
                     )
                 }
                 "InvalidKeyUsageException" => {
-                    return RusotoError::Service(
-                        GenerateDataKeyPairWithoutPlaintextError::InvalidKeyUsage(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GenerateDataKeyPairWithoutPlaintextError::InvalidKeyUsage,
                     )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(
-                        GenerateDataKeyPairWithoutPlaintextError::KMSInternal(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GenerateDataKeyPairWithoutPlaintextError::KMSInternal,
                     )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(
-                        GenerateDataKeyPairWithoutPlaintextError::KMSInvalidState(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GenerateDataKeyPairWithoutPlaintextError::KMSInvalidState,
                     )
                 }
                 "KeyUnavailableException" => {
-                    return RusotoError::Service(
-                        GenerateDataKeyPairWithoutPlaintextError::KeyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GenerateDataKeyPairWithoutPlaintextError::KeyUnavailable,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
-                        GenerateDataKeyPairWithoutPlaintextError::NotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GenerateDataKeyPairWithoutPlaintextError::NotFound,
                     )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(
-                        GenerateDataKeyPairWithoutPlaintextError::UnsupportedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GenerateDataKeyPairWithoutPlaintextError::UnsupportedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2986,46 +3576,70 @@ impl GenerateDataKeyWithoutPlaintextError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(
-                        GenerateDataKeyWithoutPlaintextError::DependencyTimeout(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GenerateDataKeyWithoutPlaintextError::DependencyTimeout,
                     )
                 }
                 "DisabledException" => {
-                    return RusotoError::Service(GenerateDataKeyWithoutPlaintextError::Disabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GenerateDataKeyWithoutPlaintextError::Disabled,
+                    )
                 }
                 "InvalidGrantTokenException" => {
-                    return RusotoError::Service(
-                        GenerateDataKeyWithoutPlaintextError::InvalidGrantToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GenerateDataKeyWithoutPlaintextError::InvalidGrantToken,
                     )
                 }
                 "InvalidKeyUsageException" => {
-                    return RusotoError::Service(
-                        GenerateDataKeyWithoutPlaintextError::InvalidKeyUsage(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GenerateDataKeyWithoutPlaintextError::InvalidKeyUsage,
                     )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(GenerateDataKeyWithoutPlaintextError::KMSInternal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GenerateDataKeyWithoutPlaintextError::KMSInternal,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(
-                        GenerateDataKeyWithoutPlaintextError::KMSInvalidState(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GenerateDataKeyWithoutPlaintextError::KMSInvalidState,
                     )
                 }
                 "KeyUnavailableException" => {
-                    return RusotoError::Service(
-                        GenerateDataKeyWithoutPlaintextError::KeyUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GenerateDataKeyWithoutPlaintextError::KeyUnavailable,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GenerateDataKeyWithoutPlaintextError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GenerateDataKeyWithoutPlaintextError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3076,22 +3690,38 @@ impl GenerateRandomError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CustomKeyStoreInvalidStateException" => {
-                    return RusotoError::Service(GenerateRandomError::CustomKeyStoreInvalidState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GenerateRandomError::CustomKeyStoreInvalidState,
+                    )
                 }
                 "CustomKeyStoreNotFoundException" => {
-                    return RusotoError::Service(GenerateRandomError::CustomKeyStoreNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GenerateRandomError::CustomKeyStoreNotFound,
+                    )
                 }
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(GenerateRandomError::DependencyTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GenerateRandomError::DependencyTimeout,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(GenerateRandomError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GenerateRandomError::KMSInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3130,21 +3760,46 @@ impl GetKeyPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(GetKeyPolicyError::DependencyTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetKeyPolicyError::DependencyTimeout,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetKeyPolicyError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetKeyPolicyError::InvalidArn,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(GetKeyPolicyError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetKeyPolicyError::KMSInternal,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(GetKeyPolicyError::KMSInvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetKeyPolicyError::KMSInvalidState,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetKeyPolicyError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetKeyPolicyError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3186,30 +3841,54 @@ impl GetKeyRotationStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(GetKeyRotationStatusError::DependencyTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetKeyRotationStatusError::DependencyTimeout,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetKeyRotationStatusError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetKeyRotationStatusError::InvalidArn,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(GetKeyRotationStatusError::KMSInternal(err.msg))
-                }
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetKeyRotationStatusError::KMSInternal,
+                    )
+                }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(GetKeyRotationStatusError::KMSInvalidState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetKeyRotationStatusError::KMSInvalidState,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetKeyRotationStatusError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetKeyRotationStatusError::NotFound,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(GetKeyRotationStatusError::UnsupportedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetKeyRotationStatusError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3252,30 +3931,54 @@ impl GetParametersForImportError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(GetParametersForImportError::DependencyTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetParametersForImportError::DependencyTimeout,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetParametersForImportError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetParametersForImportError::InvalidArn,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(GetParametersForImportError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetParametersForImportError::KMSInternal,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(GetParametersForImportError::KMSInvalidState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetParametersForImportError::KMSInvalidState,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetParametersForImportError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetParametersForImportError::NotFound,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(GetParametersForImportError::UnsupportedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetParametersForImportError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3326,36 +4029,86 @@ impl GetPublicKeyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(GetPublicKeyError::DependencyTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPublicKeyError::DependencyTimeout,
+                    )
                 }
                 "DisabledException" => {
-                    return RusotoError::Service(GetPublicKeyError::Disabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPublicKeyError::Disabled,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(GetPublicKeyError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPublicKeyError::InvalidArn,
+                    )
                 }
                 "InvalidGrantTokenException" => {
-                    return RusotoError::Service(GetPublicKeyError::InvalidGrantToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPublicKeyError::InvalidGrantToken,
+                    )
                 }
                 "InvalidKeyUsageException" => {
-                    return RusotoError::Service(GetPublicKeyError::InvalidKeyUsage(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPublicKeyError::InvalidKeyUsage,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(GetPublicKeyError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPublicKeyError::KMSInternal,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(GetPublicKeyError::KMSInvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPublicKeyError::KMSInvalidState,
+                    )
                 }
                 "KeyUnavailableException" => {
-                    return RusotoError::Service(GetPublicKeyError::KeyUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPublicKeyError::KeyUnavailable,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetPublicKeyError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPublicKeyError::NotFound,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(GetPublicKeyError::UnsupportedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPublicKeyError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3410,44 +4163,86 @@ impl ImportKeyMaterialError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(ImportKeyMaterialError::DependencyTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportKeyMaterialError::DependencyTimeout,
+                    )
                 }
                 "ExpiredImportTokenException" => {
-                    return RusotoError::Service(ImportKeyMaterialError::ExpiredImportToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ImportKeyMaterialError::ExpiredImportToken,
+                    )
                 }
                 "IncorrectKeyMaterialException" => {
-                    return RusotoError::Service(ImportKeyMaterialError::IncorrectKeyMaterial(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ImportKeyMaterialError::IncorrectKeyMaterial,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ImportKeyMaterialError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportKeyMaterialError::InvalidArn,
+                    )
                 }
                 "InvalidCiphertextException" => {
-                    return RusotoError::Service(ImportKeyMaterialError::InvalidCiphertext(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportKeyMaterialError::InvalidCiphertext,
+                    )
                 }
                 "InvalidImportTokenException" => {
-                    return RusotoError::Service(ImportKeyMaterialError::InvalidImportToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ImportKeyMaterialError::InvalidImportToken,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(ImportKeyMaterialError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportKeyMaterialError::KMSInternal,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(ImportKeyMaterialError::KMSInvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportKeyMaterialError::KMSInvalidState,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ImportKeyMaterialError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportKeyMaterialError::NotFound,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(ImportKeyMaterialError::UnsupportedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ImportKeyMaterialError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3492,21 +4287,46 @@ impl ListAliasesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(ListAliasesError::DependencyTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAliasesError::DependencyTimeout,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListAliasesError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAliasesError::InvalidArn,
+                    )
                 }
                 "InvalidMarkerException" => {
-                    return RusotoError::Service(ListAliasesError::InvalidMarker(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAliasesError::InvalidMarker,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(ListAliasesError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAliasesError::KMSInternal,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListAliasesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAliasesError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3548,24 +4368,54 @@ impl ListGrantsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(ListGrantsError::DependencyTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGrantsError::DependencyTimeout,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListGrantsError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGrantsError::InvalidArn,
+                    )
                 }
                 "InvalidMarkerException" => {
-                    return RusotoError::Service(ListGrantsError::InvalidMarker(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGrantsError::InvalidMarker,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(ListGrantsError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGrantsError::KMSInternal,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(ListGrantsError::KMSInvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGrantsError::KMSInvalidState,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListGrantsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGrantsError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3606,21 +4456,46 @@ impl ListKeyPoliciesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(ListKeyPoliciesError::DependencyTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListKeyPoliciesError::DependencyTimeout,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListKeyPoliciesError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListKeyPoliciesError::InvalidArn,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(ListKeyPoliciesError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListKeyPoliciesError::KMSInternal,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(ListKeyPoliciesError::KMSInvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListKeyPoliciesError::KMSInvalidState,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListKeyPoliciesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListKeyPoliciesError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3656,15 +4531,30 @@ impl ListKeysError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(ListKeysError::DependencyTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListKeysError::DependencyTimeout,
+                    )
                 }
                 "InvalidMarkerException" => {
-                    return RusotoError::Service(ListKeysError::InvalidMarker(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListKeysError::InvalidMarker,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(ListKeysError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListKeysError::KMSInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3700,18 +4590,38 @@ impl ListResourceTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListResourceTagsError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourceTagsError::InvalidArn,
+                    )
                 }
                 "InvalidMarkerException" => {
-                    return RusotoError::Service(ListResourceTagsError::InvalidMarker(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourceTagsError::InvalidMarker,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(ListResourceTagsError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourceTagsError::KMSInternal,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListResourceTagsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourceTagsError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3750,23 +4660,46 @@ impl ListRetirableGrantsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(ListRetirableGrantsError::DependencyTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListRetirableGrantsError::DependencyTimeout,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ListRetirableGrantsError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRetirableGrantsError::InvalidArn,
+                    )
                 }
                 "InvalidMarkerException" => {
-                    return RusotoError::Service(ListRetirableGrantsError::InvalidMarker(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRetirableGrantsError::InvalidMarker,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(ListRetirableGrantsError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRetirableGrantsError::KMSInternal,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListRetirableGrantsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRetirableGrantsError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3812,32 +4745,70 @@ impl PutKeyPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(PutKeyPolicyError::DependencyTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutKeyPolicyError::DependencyTimeout,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(PutKeyPolicyError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutKeyPolicyError::InvalidArn,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(PutKeyPolicyError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutKeyPolicyError::KMSInternal,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(PutKeyPolicyError::KMSInvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutKeyPolicyError::KMSInvalidState,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutKeyPolicyError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutKeyPolicyError::LimitExceeded,
+                    )
                 }
                 "MalformedPolicyDocumentException" => {
-                    return RusotoError::Service(PutKeyPolicyError::MalformedPolicyDocument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutKeyPolicyError::MalformedPolicyDocument,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutKeyPolicyError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutKeyPolicyError::NotFound,
+                    )
                 }
                 "UnsupportedOperationException" => {
-                    return RusotoError::Service(PutKeyPolicyError::UnsupportedOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutKeyPolicyError::UnsupportedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3890,36 +4861,86 @@ impl ReEncryptError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(ReEncryptError::DependencyTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReEncryptError::DependencyTimeout,
+                    )
                 }
                 "DisabledException" => {
-                    return RusotoError::Service(ReEncryptError::Disabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReEncryptError::Disabled,
+                    )
                 }
                 "IncorrectKeyException" => {
-                    return RusotoError::Service(ReEncryptError::IncorrectKey(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReEncryptError::IncorrectKey,
+                    )
                 }
                 "InvalidCiphertextException" => {
-                    return RusotoError::Service(ReEncryptError::InvalidCiphertext(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReEncryptError::InvalidCiphertext,
+                    )
                 }
                 "InvalidGrantTokenException" => {
-                    return RusotoError::Service(ReEncryptError::InvalidGrantToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReEncryptError::InvalidGrantToken,
+                    )
                 }
                 "InvalidKeyUsageException" => {
-                    return RusotoError::Service(ReEncryptError::InvalidKeyUsage(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReEncryptError::InvalidKeyUsage,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(ReEncryptError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReEncryptError::KMSInternal,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(ReEncryptError::KMSInvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReEncryptError::KMSInvalidState,
+                    )
                 }
                 "KeyUnavailableException" => {
-                    return RusotoError::Service(ReEncryptError::KeyUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReEncryptError::KeyUnavailable,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ReEncryptError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReEncryptError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3968,27 +4989,62 @@ impl RetireGrantError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(RetireGrantError::DependencyTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RetireGrantError::DependencyTimeout,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(RetireGrantError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RetireGrantError::InvalidArn,
+                    )
                 }
                 "InvalidGrantIdException" => {
-                    return RusotoError::Service(RetireGrantError::InvalidGrantId(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RetireGrantError::InvalidGrantId,
+                    )
                 }
                 "InvalidGrantTokenException" => {
-                    return RusotoError::Service(RetireGrantError::InvalidGrantToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RetireGrantError::InvalidGrantToken,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(RetireGrantError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RetireGrantError::KMSInternal,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(RetireGrantError::KMSInvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RetireGrantError::KMSInvalidState,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RetireGrantError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RetireGrantError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4032,24 +5088,54 @@ impl RevokeGrantError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(RevokeGrantError::DependencyTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RevokeGrantError::DependencyTimeout,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(RevokeGrantError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RevokeGrantError::InvalidArn,
+                    )
                 }
                 "InvalidGrantIdException" => {
-                    return RusotoError::Service(RevokeGrantError::InvalidGrantId(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RevokeGrantError::InvalidGrantId,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(RevokeGrantError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RevokeGrantError::KMSInternal,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(RevokeGrantError::KMSInvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RevokeGrantError::KMSInvalidState,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RevokeGrantError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RevokeGrantError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4090,23 +5176,46 @@ impl ScheduleKeyDeletionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(ScheduleKeyDeletionError::DependencyTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ScheduleKeyDeletionError::DependencyTimeout,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(ScheduleKeyDeletionError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ScheduleKeyDeletionError::InvalidArn,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(ScheduleKeyDeletionError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ScheduleKeyDeletionError::KMSInternal,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(ScheduleKeyDeletionError::KMSInvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ScheduleKeyDeletionError::KMSInvalidState,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ScheduleKeyDeletionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ScheduleKeyDeletionError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4152,26 +5261,60 @@ impl SignError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(SignError::DependencyTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SignError::DependencyTimeout,
+                    )
+                }
+                "DisabledException" => {
+                    return RusotoError::service_error(&res, &err.typ, err.msg, SignError::Disabled)
                 }
-                "DisabledException" => return RusotoError::Service(SignError::Disabled(err.msg)),
                 "InvalidGrantTokenException" => {
-                    return RusotoError::Service(SignError::InvalidGrantToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SignError::InvalidGrantToken,
+                    )
                 }
                 "InvalidKeyUsageException" => {
-                    return RusotoError::Service(SignError::InvalidKeyUsage(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SignError::InvalidKeyUsage,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(SignError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SignError::KMSInternal,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(SignError::KMSInvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SignError::KMSInvalidState,
+                    )
                 }
                 "KeyUnavailableException" => {
-                    return RusotoError::Service(SignError::KeyUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SignError::KeyUnavailable,
+                    )
+                }
+                "NotFoundException" => {
+                    return RusotoError::service_error(&res, &err.typ, err.msg, SignError::NotFound)
                 }
-                "NotFoundException" => return RusotoError::Service(SignError::NotFound(err.msg)),
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4216,22 +5359,54 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(TagResourceError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidArn,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(TagResourceError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::KMSInternal,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(TagResourceError::KMSInvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::KMSInvalidState,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::NotFound,
+                    )
+                }
+                "TagException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::Tag,
+                    )
                 }
-                "TagException" => return RusotoError::Service(TagResourceError::Tag(err.msg)),
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4272,19 +5447,46 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidArnException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidArn,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(UntagResourceError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::KMSInternal,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(UntagResourceError::KMSInvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::KMSInvalidState,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::NotFound,
+                    )
+                }
+                "TagException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::Tag,
+                    )
                 }
-                "TagException" => return RusotoError::Service(UntagResourceError::Tag(err.msg)),
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4324,21 +5526,46 @@ impl UpdateAliasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(UpdateAliasError::DependencyTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAliasError::DependencyTimeout,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(UpdateAliasError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAliasError::KMSInternal,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(UpdateAliasError::KMSInvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAliasError::KMSInvalidState,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateAliasError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAliasError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateAliasError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAliasError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4384,44 +5611,70 @@ impl UpdateCustomKeyStoreError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CloudHsmClusterInvalidConfigurationException" => {
-                    return RusotoError::Service(
-                        UpdateCustomKeyStoreError::CloudHsmClusterInvalidConfiguration(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCustomKeyStoreError::CloudHsmClusterInvalidConfiguration,
                     )
                 }
                 "CloudHsmClusterNotActiveException" => {
-                    return RusotoError::Service(
-                        UpdateCustomKeyStoreError::CloudHsmClusterNotActive(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCustomKeyStoreError::CloudHsmClusterNotActive,
                     )
                 }
                 "CloudHsmClusterNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateCustomKeyStoreError::CloudHsmClusterNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCustomKeyStoreError::CloudHsmClusterNotFound,
                     )
                 }
                 "CloudHsmClusterNotRelatedException" => {
-                    return RusotoError::Service(
-                        UpdateCustomKeyStoreError::CloudHsmClusterNotRelated(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCustomKeyStoreError::CloudHsmClusterNotRelated,
                     )
                 }
                 "CustomKeyStoreInvalidStateException" => {
-                    return RusotoError::Service(
-                        UpdateCustomKeyStoreError::CustomKeyStoreInvalidState(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCustomKeyStoreError::CustomKeyStoreInvalidState,
                     )
                 }
                 "CustomKeyStoreNameInUseException" => {
-                    return RusotoError::Service(
-                        UpdateCustomKeyStoreError::CustomKeyStoreNameInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCustomKeyStoreError::CustomKeyStoreNameInUse,
                     )
                 }
                 "CustomKeyStoreNotFoundException" => {
-                    return RusotoError::Service(UpdateCustomKeyStoreError::CustomKeyStoreNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateCustomKeyStoreError::CustomKeyStoreNotFound,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(UpdateCustomKeyStoreError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCustomKeyStoreError::KMSInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4472,25 +5725,46 @@ impl UpdateKeyDescriptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(UpdateKeyDescriptionError::DependencyTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateKeyDescriptionError::DependencyTimeout,
+                    )
                 }
                 "InvalidArnException" => {
-                    return RusotoError::Service(UpdateKeyDescriptionError::InvalidArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateKeyDescriptionError::InvalidArn,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(UpdateKeyDescriptionError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateKeyDescriptionError::KMSInternal,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(UpdateKeyDescriptionError::KMSInvalidState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateKeyDescriptionError::KMSInvalidState,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateKeyDescriptionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateKeyDescriptionError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4538,29 +5812,78 @@ impl VerifyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DependencyTimeoutException" => {
-                    return RusotoError::Service(VerifyError::DependencyTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        VerifyError::DependencyTimeout,
+                    )
+                }
+                "DisabledException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        VerifyError::Disabled,
+                    )
                 }
-                "DisabledException" => return RusotoError::Service(VerifyError::Disabled(err.msg)),
                 "InvalidGrantTokenException" => {
-                    return RusotoError::Service(VerifyError::InvalidGrantToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        VerifyError::InvalidGrantToken,
+                    )
                 }
                 "InvalidKeyUsageException" => {
-                    return RusotoError::Service(VerifyError::InvalidKeyUsage(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        VerifyError::InvalidKeyUsage,
+                    )
                 }
                 "KMSInternalException" => {
-                    return RusotoError::Service(VerifyError::KMSInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        VerifyError::KMSInternal,
+                    )
                 }
                 "KMSInvalidSignatureException" => {
-                    return RusotoError::Service(VerifyError::KMSInvalidSignature(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        VerifyError::KMSInvalidSignature,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(VerifyError::KMSInvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        VerifyError::KMSInvalidState,
+                    )
                 }
                 "KeyUnavailableException" => {
-                    return RusotoError::Service(VerifyError::KeyUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        VerifyError::KeyUnavailable,
+                    )
+                }
+                "NotFoundException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        VerifyError::NotFound,
+                    )
                 }
-                "NotFoundException" => return RusotoError::Service(VerifyError::NotFound(err.msg)),
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4873,11 +6196,12 @@ pub struct KmsClient {
 impl KmsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> KmsClient {
         KmsClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "KMS"),
         }
     }
 
@@ -4892,12 +6216,32 @@ impl KmsClient {
     {
         KmsClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "KMS"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> KmsClient {
-        KmsClient { client, region }
+        KmsClient {
+            client,
+            region: region_for_service(region, "KMS"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> KmsClient {
+        KmsClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("KMS"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> KmsClient {
+        KmsClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -4909,6 +6253,7 @@ impl Kms for KmsClient {
         input: CancelKeyDeletionRequest,
     ) -> Result<CancelKeyDeletionResponse, RusotoError<CancelKeyDeletionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CancelKeyDeletion");
         request.add_header("x-amz-target", "TrentService.CancelKeyDeletion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4927,6 +6272,7 @@ impl Kms for KmsClient {
         input: ConnectCustomKeyStoreRequest,
     ) -> Result<ConnectCustomKeyStoreResponse, RusotoError<ConnectCustomKeyStoreError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ConnectCustomKeyStore");
         request.add_header("x-amz-target", "TrentService.ConnectCustomKeyStore");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4946,6 +6292,7 @@ impl Kms for KmsClient {
         input: CreateAliasRequest,
     ) -> Result<(), RusotoError<CreateAliasError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateAlias");
         request.add_header("x-amz-target", "TrentService.CreateAlias");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4963,6 +6310,7 @@ impl Kms for KmsClient {
         input: CreateCustomKeyStoreRequest,
     ) -> Result<CreateCustomKeyStoreResponse, RusotoError<CreateCustomKeyStoreError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateCustomKeyStore");
         request.add_header("x-amz-target", "TrentService.CreateCustomKeyStore");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4982,6 +6330,7 @@ impl Kms for KmsClient {
         input: CreateGrantRequest,
     ) -> Result<CreateGrantResponse, RusotoError<CreateGrantError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateGrant");
         request.add_header("x-amz-target", "TrentService.CreateGrant");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5000,6 +6349,7 @@ impl Kms for KmsClient {
         input: CreateKeyRequest,
     ) -> Result<CreateKeyResponse, RusotoError<CreateKeyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateKey");
         request.add_header("x-amz-target", "TrentService.CreateKey");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5018,6 +6368,7 @@ impl Kms for KmsClient {
         input: DecryptRequest,
     ) -> Result<DecryptResponse, RusotoError<DecryptError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("Decrypt");
         request.add_header("x-amz-target", "TrentService.Decrypt");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5036,6 +6387,7 @@ impl Kms for KmsClient {
         input: DeleteAliasRequest,
     ) -> Result<(), RusotoError<DeleteAliasError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteAlias");
         request.add_header("x-amz-target", "TrentService.DeleteAlias");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5053,6 +6405,7 @@ impl Kms for KmsClient {
         input: DeleteCustomKeyStoreRequest,
     ) -> Result<DeleteCustomKeyStoreResponse, RusotoError<DeleteCustomKeyStoreError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteCustomKeyStore");
         request.add_header("x-amz-target", "TrentService.DeleteCustomKeyStore");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5072,6 +6425,7 @@ impl Kms for KmsClient {
         input: DeleteImportedKeyMaterialRequest,
     ) -> Result<(), RusotoError<DeleteImportedKeyMaterialError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteImportedKeyMaterial");
         request.add_header("x-amz-target", "TrentService.DeleteImportedKeyMaterial");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5089,6 +6443,7 @@ impl Kms for KmsClient {
         input: DescribeCustomKeyStoresRequest,
     ) -> Result<DescribeCustomKeyStoresResponse, RusotoError<DescribeCustomKeyStoresError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeCustomKeyStores");
         request.add_header("x-amz-target", "TrentService.DescribeCustomKeyStores");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5108,6 +6463,7 @@ impl Kms for KmsClient {
         input: DescribeKeyRequest,
     ) -> Result<DescribeKeyResponse, RusotoError<DescribeKeyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeKey");
         request.add_header("x-amz-target", "TrentService.DescribeKey");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5126,6 +6482,7 @@ impl Kms for KmsClient {
         input: DisableKeyRequest,
     ) -> Result<(), RusotoError<DisableKeyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisableKey");
         request.add_header("x-amz-target", "TrentService.DisableKey");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5143,6 +6500,7 @@ impl Kms for KmsClient {
         input: DisableKeyRotationRequest,
     ) -> Result<(), RusotoError<DisableKeyRotationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisableKeyRotation");
         request.add_header("x-amz-target", "TrentService.DisableKeyRotation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5160,6 +6518,7 @@ impl Kms for KmsClient {
         input: DisconnectCustomKeyStoreRequest,
     ) -> Result<DisconnectCustomKeyStoreResponse, RusotoError<DisconnectCustomKeyStoreError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisconnectCustomKeyStore");
         request.add_header("x-amz-target", "TrentService.DisconnectCustomKeyStore");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5176,6 +6535,7 @@ impl Kms for KmsClient {
     /// <p>Sets the key state of a customer master key (CMK) to enabled. This allows you to use the CMK for <a href="https://docs.aws.amazon.com/kms/latest/developerguide/concepts.html#cryptographic-operations">cryptographic operations</a>. </p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p> <b>Cross-account use</b>: No. You cannot perform this operation on a CMK in a different AWS account.</p> <p> <b>Required permissions</b>: <a href="https://docs.aws.amazon.com/kms/latest/developerguide/kms-api-permissions-reference.html">kms:EnableKey</a> (key policy)</p> <p> <b>Related operations</b>: <a>DisableKey</a> </p>
     async fn enable_key(&self, input: EnableKeyRequest) -> Result<(), RusotoError<EnableKeyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("EnableKey");
         request.add_header("x-amz-target", "TrentService.EnableKey");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5193,6 +6553,7 @@ impl Kms for KmsClient {
         input: EnableKeyRotationRequest,
     ) -> Result<(), RusotoError<EnableKeyRotationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("EnableKeyRotation");
         request.add_header("x-amz-target", "TrentService.EnableKeyRotation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5210,6 +6571,7 @@ impl Kms for KmsClient {
         input: EncryptRequest,
     ) -> Result<EncryptResponse, RusotoError<EncryptError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("Encrypt");
         request.add_header("x-amz-target", "TrentService.Encrypt");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5228,6 +6590,7 @@ impl Kms for KmsClient {
         input: GenerateDataKeyRequest,
     ) -> Result<GenerateDataKeyResponse, RusotoError<GenerateDataKeyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GenerateDataKey");
         request.add_header("x-amz-target", "TrentService.GenerateDataKey");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5246,6 +6609,7 @@ impl Kms for KmsClient {
         input: GenerateDataKeyPairRequest,
     ) -> Result<GenerateDataKeyPairResponse, RusotoError<GenerateDataKeyPairError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GenerateDataKeyPair");
         request.add_header("x-amz-target", "TrentService.GenerateDataKeyPair");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5267,6 +6631,7 @@ impl Kms for KmsClient {
         RusotoError<GenerateDataKeyPairWithoutPlaintextError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GenerateDataKeyPairWithoutPlaintext");
         request.add_header(
             "x-amz-target",
             "TrentService.GenerateDataKeyPairWithoutPlaintext",
@@ -5295,6 +6660,7 @@ impl Kms for KmsClient {
         RusotoError<GenerateDataKeyWithoutPlaintextError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GenerateDataKeyWithoutPlaintext");
         request.add_header(
             "x-amz-target",
             "TrentService.GenerateDataKeyWithoutPlaintext",
@@ -5317,6 +6683,7 @@ impl Kms for KmsClient {
         input: GenerateRandomRequest,
     ) -> Result<GenerateRandomResponse, RusotoError<GenerateRandomError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GenerateRandom");
         request.add_header("x-amz-target", "TrentService.GenerateRandom");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5335,6 +6702,7 @@ impl Kms for KmsClient {
         input: GetKeyPolicyRequest,
     ) -> Result<GetKeyPolicyResponse, RusotoError<GetKeyPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetKeyPolicy");
         request.add_header("x-amz-target", "TrentService.GetKeyPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5353,6 +6721,7 @@ impl Kms for KmsClient {
         input: GetKeyRotationStatusRequest,
     ) -> Result<GetKeyRotationStatusResponse, RusotoError<GetKeyRotationStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetKeyRotationStatus");
         request.add_header("x-amz-target", "TrentService.GetKeyRotationStatus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5372,6 +6741,7 @@ impl Kms for KmsClient {
         input: GetParametersForImportRequest,
     ) -> Result<GetParametersForImportResponse, RusotoError<GetParametersForImportError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetParametersForImport");
         request.add_header("x-amz-target", "TrentService.GetParametersForImport");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5391,6 +6761,7 @@ impl Kms for KmsClient {
         input: GetPublicKeyRequest,
     ) -> Result<GetPublicKeyResponse, RusotoError<GetPublicKeyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetPublicKey");
         request.add_header("x-amz-target", "TrentService.GetPublicKey");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5409,6 +6780,7 @@ impl Kms for KmsClient {
         input: ImportKeyMaterialRequest,
     ) -> Result<ImportKeyMaterialResponse, RusotoError<ImportKeyMaterialError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ImportKeyMaterial");
         request.add_header("x-amz-target", "TrentService.ImportKeyMaterial");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5427,6 +6799,7 @@ impl Kms for KmsClient {
         input: ListAliasesRequest,
     ) -> Result<ListAliasesResponse, RusotoError<ListAliasesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListAliases");
         request.add_header("x-amz-target", "TrentService.ListAliases");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5445,6 +6818,7 @@ impl Kms for KmsClient {
         input: ListGrantsRequest,
     ) -> Result<ListGrantsResponse, RusotoError<ListGrantsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListGrants");
         request.add_header("x-amz-target", "TrentService.ListGrants");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5463,6 +6837,7 @@ impl Kms for KmsClient {
         input: ListKeyPoliciesRequest,
     ) -> Result<ListKeyPoliciesResponse, RusotoError<ListKeyPoliciesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListKeyPolicies");
         request.add_header("x-amz-target", "TrentService.ListKeyPolicies");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5481,6 +6856,7 @@ impl Kms for KmsClient {
         input: ListKeysRequest,
     ) -> Result<ListKeysResponse, RusotoError<ListKeysError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListKeys");
         request.add_header("x-amz-target", "TrentService.ListKeys");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5499,6 +6875,7 @@ impl Kms for KmsClient {
         input: ListResourceTagsRequest,
     ) -> Result<ListResourceTagsResponse, RusotoError<ListResourceTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListResourceTags");
         request.add_header("x-amz-target", "TrentService.ListResourceTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5517,6 +6894,7 @@ impl Kms for KmsClient {
         input: ListRetirableGrantsRequest,
     ) -> Result<ListGrantsResponse, RusotoError<ListRetirableGrantsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListRetirableGrants");
         request.add_header("x-amz-target", "TrentService.ListRetirableGrants");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5535,6 +6913,7 @@ impl Kms for KmsClient {
         input: PutKeyPolicyRequest,
     ) -> Result<(), RusotoError<PutKeyPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutKeyPolicy");
         request.add_header("x-amz-target", "TrentService.PutKeyPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5552,6 +6931,7 @@ impl Kms for KmsClient {
         input: ReEncryptRequest,
     ) -> Result<ReEncryptResponse, RusotoError<ReEncryptError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ReEncrypt");
         request.add_header("x-amz-target", "TrentService.ReEncrypt");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5570,6 +6950,7 @@ impl Kms for KmsClient {
         input: RetireGrantRequest,
     ) -> Result<(), RusotoError<RetireGrantError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RetireGrant");
         request.add_header("x-amz-target", "TrentService.RetireGrant");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5587,6 +6968,7 @@ impl Kms for KmsClient {
         input: RevokeGrantRequest,
     ) -> Result<(), RusotoError<RevokeGrantError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RevokeGrant");
         request.add_header("x-amz-target", "TrentService.RevokeGrant");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5604,6 +6986,7 @@ impl Kms for KmsClient {
         input: ScheduleKeyDeletionRequest,
     ) -> Result<ScheduleKeyDeletionResponse, RusotoError<ScheduleKeyDeletionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ScheduleKeyDeletion");
         request.add_header("x-amz-target", "TrentService.ScheduleKeyDeletion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5619,6 +7002,7 @@ impl Kms for KmsClient {
     /// <p>Creates a <a href="https://en.wikipedia.org/wiki/Digital_signature">digital signature</a> for a message or message digest by using the private key in an asymmetric CMK. To verify the signature, use the <a>Verify</a> operation, or use the public key in the same asymmetric CMK outside of AWS KMS. For information about symmetric and asymmetric CMKs, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/symmetric-asymmetric.html">Using Symmetric and Asymmetric CMKs</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p>Digital signatures are generated and verified by using asymmetric key pair, such as an RSA or ECC pair that is represented by an asymmetric customer master key (CMK). The key owner (or an authorized user) uses their private key to sign a message. Anyone with the public key can verify that the message was signed with that particular private key and that the message hasn't changed since it was signed. </p> <p>To use the <code>Sign</code> operation, provide the following information:</p> <ul> <li> <p>Use the <code>KeyId</code> parameter to identify an asymmetric CMK with a <code>KeyUsage</code> value of <code>SIGN_VERIFY</code>. To get the <code>KeyUsage</code> value of a CMK, use the <a>DescribeKey</a> operation. The caller must have <code>kms:Sign</code> permission on the CMK.</p> </li> <li> <p>Use the <code>Message</code> parameter to specify the message or message digest to sign. You can submit messages of up to 4096 bytes. To sign a larger message, generate a hash digest of the message, and then provide the hash digest in the <code>Message</code> parameter. To indicate whether the message is a full message or a digest, use the <code>MessageType</code> parameter.</p> </li> <li> <p>Choose a signing algorithm that is compatible with the CMK. </p> </li> </ul> <important> <p>When signing a message, be sure to record the CMK and the signing algorithm. This information is required to verify the signature.</p> </important> <p>To verify the signature that this operation generates, use the <a>Verify</a> operation. Or use the <a>GetPublicKey</a> operation to download the public key and then use the public key to verify the signature outside of AWS KMS. </p> <p>The CMK that you use for this operation must be in a compatible key state. For details, see <a href="https://docs.aws.amazon.com/kms/latest/developerguide/key-state.html">How Key State Affects Use of a Customer Master Key</a> in the <i>AWS Key Management Service Developer Guide</i>.</p> <p> <b>Cross-account use</b>: Yes. To perform this operation with a CMK in a different AWS account, specify the key ARN or alias ARN in the value of the <code>KeyId</code> parameter.</p> <p> <b>Required permissions</b>: <a href="https://docs.aws.amazon.com/kms/latest/developerguide/kms-api-permissions-reference.html">kms:Sign</a> (key policy)</p> <p> <b>Related operations</b>: <a>Verify</a> </p>
     async fn sign(&self, input: SignRequest) -> Result<SignResponse, RusotoError<SignError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("Sign");
         request.add_header("x-amz-target", "TrentService.Sign");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5637,6 +7021,7 @@ impl Kms for KmsClient {
         input: TagResourceRequest,
     ) -> Result<(), RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "TrentService.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5654,6 +7039,7 @@ impl Kms for KmsClient {
         input: UntagResourceRequest,
     ) -> Result<(), RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "TrentService.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5671,6 +7057,7 @@ impl Kms for KmsClient {
         input: UpdateAliasRequest,
     ) -> Result<(), RusotoError<UpdateAliasError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateAlias");
         request.add_header("x-amz-target", "TrentService.UpdateAlias");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5688,6 +7075,7 @@ impl Kms for KmsClient {
         input: UpdateCustomKeyStoreRequest,
     ) -> Result<UpdateCustomKeyStoreResponse, RusotoError<UpdateCustomKeyStoreError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateCustomKeyStore");
         request.add_header("x-amz-target", "TrentService.UpdateCustomKeyStore");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5707,6 +7095,7 @@ impl Kms for KmsClient {
         input: UpdateKeyDescriptionRequest,
     ) -> Result<(), RusotoError<UpdateKeyDescriptionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateKeyDescription");
         request.add_header("x-amz-target", "TrentService.UpdateKeyDescription");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5724,6 +7113,7 @@ impl Kms for KmsClient {
         input: VerifyRequest,
     ) -> Result<VerifyResponse, RusotoError<VerifyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("Verify");
         request.add_header("x-amz-target", "TrentService.Verify");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5736,3 +7126,462 @@ impl Kms for KmsClient {
         proto::json::ResponsePayload::new(&response).deserialize::<VerifyResponse, _>()
     }
 }
+
+/// A blocking client for the KMS API, running the calls of a `KmsClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `KmsClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct KmsBlockingClient {
+    client: KmsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl KmsBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> KmsBlockingClient {
+        KmsBlockingClient::from_client(KmsClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> KmsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        KmsBlockingClient::from_client(KmsClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> KmsBlockingClient {
+        KmsBlockingClient::from_client(KmsClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> KmsBlockingClient {
+        KmsBlockingClient::from_client(KmsClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: KmsClient) -> KmsBlockingClient {
+        KmsBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> KmsBlockingClient {
+        KmsBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> KmsBlockingClient {
+        KmsBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Kms::cancel_key_deletion`].
+    pub fn cancel_key_deletion(
+        &self,
+        input: CancelKeyDeletionRequest,
+    ) -> Result<CancelKeyDeletionResponse, RusotoError<CancelKeyDeletionError>> {
+        self.runtime
+            .block_on(self.client.cancel_key_deletion(input))
+    }
+
+    /// Blocking version of [`Kms::connect_custom_key_store`].
+    pub fn connect_custom_key_store(
+        &self,
+        input: ConnectCustomKeyStoreRequest,
+    ) -> Result<ConnectCustomKeyStoreResponse, RusotoError<ConnectCustomKeyStoreError>> {
+        self.runtime
+            .block_on(self.client.connect_custom_key_store(input))
+    }
+
+    /// Blocking version of [`Kms::create_alias`].
+    pub fn create_alias(
+        &self,
+        input: CreateAliasRequest,
+    ) -> Result<(), RusotoError<CreateAliasError>> {
+        self.runtime.block_on(self.client.create_alias(input))
+    }
+
+    /// Blocking version of [`Kms::create_custom_key_store`].
+    pub fn create_custom_key_store(
+        &self,
+        input: CreateCustomKeyStoreRequest,
+    ) -> Result<CreateCustomKeyStoreResponse, RusotoError<CreateCustomKeyStoreError>> {
+        self.runtime
+            .block_on(self.client.create_custom_key_store(input))
+    }
+
+    /// Blocking version of [`Kms::create_grant`].
+    pub fn create_grant(
+        &self,
+        input: CreateGrantRequest,
+    ) -> Result<CreateGrantResponse, RusotoError<CreateGrantError>> {
+        self.runtime.block_on(self.client.create_grant(input))
+    }
+
+    /// Blocking version of [`Kms::create_key`].
+    pub fn create_key(
+        &self,
+        input: CreateKeyRequest,
+    ) -> Result<CreateKeyResponse, RusotoError<CreateKeyError>> {
+        self.runtime.block_on(self.client.create_key(input))
+    }
+
+    /// Blocking version of [`Kms::decrypt`].
+    pub fn decrypt(
+        &self,
+        input: DecryptRequest,
+    ) -> Result<DecryptResponse, RusotoError<DecryptError>> {
+        self.runtime.block_on(self.client.decrypt(input))
+    }
+
+    /// Blocking version of [`Kms::delete_alias`].
+    pub fn delete_alias(
+        &self,
+        input: DeleteAliasRequest,
+    ) -> Result<(), RusotoError<DeleteAliasError>> {
+        self.runtime.block_on(self.client.delete_alias(input))
+    }
+
+    /// Blocking version of [`Kms::delete_custom_key_store`].
+    pub fn delete_custom_key_store(
+        &self,
+        input: DeleteCustomKeyStoreRequest,
+    ) -> Result<DeleteCustomKeyStoreResponse, RusotoError<DeleteCustomKeyStoreError>> {
+        self.runtime
+            .block_on(self.client.delete_custom_key_store(input))
+    }
+
+    /// Blocking version of [`Kms::delete_imported_key_material`].
+    pub fn delete_imported_key_material(
+        &self,
+        input: DeleteImportedKeyMaterialRequest,
+    ) -> Result<(), RusotoError<DeleteImportedKeyMaterialError>> {
+        self.runtime
+            .block_on(self.client.delete_imported_key_material(input))
+    }
+
+    /// Blocking version of [`Kms::describe_custom_key_stores`].
+    pub fn describe_custom_key_stores(
+        &self,
+        input: DescribeCustomKeyStoresRequest,
+    ) -> Result<DescribeCustomKeyStoresResponse, RusotoError<DescribeCustomKeyStoresError>> {
+        self.runtime
+            .block_on(self.client.describe_custom_key_stores(input))
+    }
+
+    /// Blocking version of [`Kms::describe_key`].
+    pub fn describe_key(
+        &self,
+        input: DescribeKeyRequest,
+    ) -> Result<DescribeKeyResponse, RusotoError<DescribeKeyError>> {
+        self.runtime.block_on(self.client.describe_key(input))
+    }
+
+    /// Blocking version of [`Kms::disable_key`].
+    pub fn disable_key(
+        &self,
+        input: DisableKeyRequest,
+    ) -> Result<(), RusotoError<DisableKeyError>> {
+        self.runtime.block_on(self.client.disable_key(input))
+    }
+
+    /// Blocking version of [`Kms::disable_key_rotation`].
+    pub fn disable_key_rotation(
+        &self,
+        input: DisableKeyRotationRequest,
+    ) -> Result<(), RusotoError<DisableKeyRotationError>> {
+        self.runtime
+            .block_on(self.client.disable_key_rotation(input))
+    }
+
+    /// Blocking version of [`Kms::disconnect_custom_key_store`].
+    pub fn disconnect_custom_key_store(
+        &self,
+        input: DisconnectCustomKeyStoreRequest,
+    ) -> Result<DisconnectCustomKeyStoreResponse, RusotoError<DisconnectCustomKeyStoreError>> {
+        self.runtime
+            .block_on(self.client.disconnect_custom_key_store(input))
+    }
+
+    /// Blocking version of [`Kms::enable_key`].
+    pub fn enable_key(&self, input: EnableKeyRequest) -> Result<(), RusotoError<EnableKeyError>> {
+        self.runtime.block_on(self.client.enable_key(input))
+    }
+
+    /// Blocking version of [`Kms::enable_key_rotation`].
+    pub fn enable_key_rotation(
+        &self,
+        input: EnableKeyRotationRequest,
+    ) -> Result<(), RusotoError<EnableKeyRotationError>> {
+        self.runtime
+            .block_on(self.client.enable_key_rotation(input))
+    }
+
+    /// Blocking version of [`Kms::encrypt`].
+    pub fn encrypt(
+        &self,
+        input: EncryptRequest,
+    ) -> Result<EncryptResponse, RusotoError<EncryptError>> {
+        self.runtime.block_on(self.client.encrypt(input))
+    }
+
+    /// Blocking version of [`Kms::generate_data_key`].
+    pub fn generate_data_key(
+        &self,
+        input: GenerateDataKeyRequest,
+    ) -> Result<GenerateDataKeyResponse, RusotoError<GenerateDataKeyError>> {
+        self.runtime.block_on(self.client.generate_data_key(input))
+    }
+
+    /// Blocking version of [`Kms::generate_data_key_pair`].
+    pub fn generate_data_key_pair(
+        &self,
+        input: GenerateDataKeyPairRequest,
+    ) -> Result<GenerateDataKeyPairResponse, RusotoError<GenerateDataKeyPairError>> {
+        self.runtime
+            .block_on(self.client.generate_data_key_pair(input))
+    }
+
+    /// Blocking version of [`Kms::generate_data_key_pair_without_plaintext`].
+    pub fn generate_data_key_pair_without_plaintext(
+        &self,
+        input: GenerateDataKeyPairWithoutPlaintextRequest,
+    ) -> Result<
+        GenerateDataKeyPairWithoutPlaintextResponse,
+        RusotoError<GenerateDataKeyPairWithoutPlaintextError>,
+    > {
+        self.runtime
+            .block_on(self.client.generate_data_key_pair_without_plaintext(input))
+    }
+
+    /// Blocking version of [`Kms::generate_data_key_without_plaintext`].
+    pub fn generate_data_key_without_plaintext(
+        &self,
+        input: GenerateDataKeyWithoutPlaintextRequest,
+    ) -> Result<
+        GenerateDataKeyWithoutPlaintextResponse,
+        RusotoError<GenerateDataKeyWithoutPlaintextError>,
+    > {
+        self.runtime
+            .block_on(self.client.generate_data_key_without_plaintext(input))
+    }
+
+    /// Blocking version of [`Kms::generate_random`].
+    pub fn generate_random(
+        &self,
+        input: GenerateRandomRequest,
+    ) -> Result<GenerateRandomResponse, RusotoError<GenerateRandomError>> {
+        self.runtime.block_on(self.client.generate_random(input))
+    }
+
+    /// Blocking version of [`Kms::get_key_policy`].
+    pub fn get_key_policy(
+        &self,
+        input: GetKeyPolicyRequest,
+    ) -> Result<GetKeyPolicyResponse, RusotoError<GetKeyPolicyError>> {
+        self.runtime.block_on(self.client.get_key_policy(input))
+    }
+
+    /// Blocking version of [`Kms::get_key_rotation_status`].
+    pub fn get_key_rotation_status(
+        &self,
+        input: GetKeyRotationStatusRequest,
+    ) -> Result<GetKeyRotationStatusResponse, RusotoError<GetKeyRotationStatusError>> {
+        self.runtime
+            .block_on(self.client.get_key_rotation_status(input))
+    }
+
+    /// Blocking version of [`Kms::get_parameters_for_import`].
+    pub fn get_parameters_for_import(
+        &self,
+        input: GetParametersForImportRequest,
+    ) -> Result<GetParametersForImportResponse, RusotoError<GetParametersForImportError>> {
+        self.runtime
+            .block_on(self.client.get_parameters_for_import(input))
+    }
+
+    /// Blocking version of [`Kms::get_public_key`].
+    pub fn get_public_key(
+        &self,
+        input: GetPublicKeyRequest,
+    ) -> Result<GetPublicKeyResponse, RusotoError<GetPublicKeyError>> {
+        self.runtime.block_on(self.client.get_public_key(input))
+    }
+
+    /// Blocking version of [`Kms::import_key_material`].
+    pub fn import_key_material(
+        &self,
+        input: ImportKeyMaterialRequest,
+    ) -> Result<ImportKeyMaterialResponse, RusotoError<ImportKeyMaterialError>> {
+        self.runtime
+            .block_on(self.client.import_key_material(input))
+    }
+
+    /// Blocking version of [`Kms::list_aliases`].
+    pub fn list_aliases(
+        &self,
+        input: ListAliasesRequest,
+    ) -> Result<ListAliasesResponse, RusotoError<ListAliasesError>> {
+        self.runtime.block_on(self.client.list_aliases(input))
+    }
+
+    /// Blocking version of [`Kms::list_grants`].
+    pub fn list_grants(
+        &self,
+        input: ListGrantsRequest,
+    ) -> Result<ListGrantsResponse, RusotoError<ListGrantsError>> {
+        self.runtime.block_on(self.client.list_grants(input))
+    }
+
+    /// Blocking version of [`Kms::list_key_policies`].
+    pub fn list_key_policies(
+        &self,
+        input: ListKeyPoliciesRequest,
+    ) -> Result<ListKeyPoliciesResponse, RusotoError<ListKeyPoliciesError>> {
+        self.runtime.block_on(self.client.list_key_policies(input))
+    }
+
+    /// Blocking version of [`Kms::list_keys`].
+    pub fn list_keys(
+        &self,
+        input: ListKeysRequest,
+    ) -> Result<ListKeysResponse, RusotoError<ListKeysError>> {
+        self.runtime.block_on(self.client.list_keys(input))
+    }
+
+    /// Blocking version of [`Kms::list_resource_tags`].
+    pub fn list_resource_tags(
+        &self,
+        input: ListResourceTagsRequest,
+    ) -> Result<ListResourceTagsResponse, RusotoError<ListResourceTagsError>> {
+        self.runtime.block_on(self.client.list_resource_tags(input))
+    }
+
+    /// Blocking version of [`Kms::list_retirable_grants`].
+    pub fn list_retirable_grants(
+        &self,
+        input: ListRetirableGrantsRequest,
+    ) -> Result<ListGrantsResponse, RusotoError<ListRetirableGrantsError>> {
+        self.runtime
+            .block_on(self.client.list_retirable_grants(input))
+    }
+
+    /// Blocking version of [`Kms::put_key_policy`].
+    pub fn put_key_policy(
+        &self,
+        input: PutKeyPolicyRequest,
+    ) -> Result<(), RusotoError<PutKeyPolicyError>> {
+        self.runtime.block_on(self.client.put_key_policy(input))
+    }
+
+    /// Blocking version of [`Kms::re_encrypt`].
+    pub fn re_encrypt(
+        &self,
+        input: ReEncryptRequest,
+    ) -> Result<ReEncryptResponse, RusotoError<ReEncryptError>> {
+        self.runtime.block_on(self.client.re_encrypt(input))
+    }
+
+    /// Blocking version of [`Kms::retire_grant`].
+    pub fn retire_grant(
+        &self,
+        input: RetireGrantRequest,
+    ) -> Result<(), RusotoError<RetireGrantError>> {
+        self.runtime.block_on(self.client.retire_grant(input))
+    }
+
+    /// Blocking version of [`Kms::revoke_grant`].
+    pub fn revoke_grant(
+        &self,
+        input: RevokeGrantRequest,
+    ) -> Result<(), RusotoError<RevokeGrantError>> {
+        self.runtime.block_on(self.client.revoke_grant(input))
+    }
+
+    /// Blocking version of [`Kms::schedule_key_deletion`].
+    pub fn schedule_key_deletion(
+        &self,
+        input: ScheduleKeyDeletionRequest,
+    ) -> Result<ScheduleKeyDeletionResponse, RusotoError<ScheduleKeyDeletionError>> {
+        self.runtime
+            .block_on(self.client.schedule_key_deletion(input))
+    }
+
+    /// Blocking version of [`Kms::sign`].
+    pub fn sign(&self, input: SignRequest) -> Result<SignResponse, RusotoError<SignError>> {
+        self.runtime.block_on(self.client.sign(input))
+    }
+
+    /// Blocking version of [`Kms::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`Kms::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`Kms::update_alias`].
+    pub fn update_alias(
+        &self,
+        input: UpdateAliasRequest,
+    ) -> Result<(), RusotoError<UpdateAliasError>> {
+        self.runtime.block_on(self.client.update_alias(input))
+    }
+
+    /// Blocking version of [`Kms::update_custom_key_store`].
+    pub fn update_custom_key_store(
+        &self,
+        input: UpdateCustomKeyStoreRequest,
+    ) -> Result<UpdateCustomKeyStoreResponse, RusotoError<UpdateCustomKeyStoreError>> {
+        self.runtime
+            .block_on(self.client.update_custom_key_store(input))
+    }
+
+    /// Blocking version of [`Kms::update_key_description`].
+    pub fn update_key_description(
+        &self,
+        input: UpdateKeyDescriptionRequest,
+    ) -> Result<(), RusotoError<UpdateKeyDescriptionError>> {
+        self.runtime
+            .block_on(self.client.update_key_description(input))
+    }
+
+    /// Blocking version of [`Kms::verify`].
+    pub fn verify(&self, input: VerifyRequest) -> Result<VerifyResponse, RusotoError<VerifyError>> {
+        self.runtime.block_on(self.client.verify(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -638,14 +639,22 @@ impl BatchGrantPermissionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchGrantPermissionsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchGrantPermissionsError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(BatchGrantPermissionsError::OperationTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchGrantPermissionsError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -676,14 +685,22 @@ impl BatchRevokePermissionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(BatchRevokePermissionsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchRevokePermissionsError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(BatchRevokePermissionsError::OperationTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchRevokePermissionsError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -718,18 +735,38 @@ impl DeregisterResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DeregisterResourceError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterResourceError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DeregisterResourceError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterResourceError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeregisterResourceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterResourceError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DeregisterResourceError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterResourceError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -766,18 +803,38 @@ impl DescribeResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(DescribeResourceError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeResourceError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeResourceError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeResourceError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeResourceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeResourceError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(DescribeResourceError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeResourceError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -812,15 +869,30 @@ impl GetDataLakeSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GetDataLakeSettingsError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDataLakeSettingsError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetDataLakeSettingsError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDataLakeSettingsError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDataLakeSettingsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDataLakeSettingsError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -858,26 +930,38 @@ impl GetEffectivePermissionsForPathError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(
-                        GetEffectivePermissionsForPathError::EntityNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEffectivePermissionsForPathError::EntityNotFound,
                     )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(
-                        GetEffectivePermissionsForPathError::InternalService(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEffectivePermissionsForPathError::InternalService,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetEffectivePermissionsForPathError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetEffectivePermissionsForPathError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(
-                        GetEffectivePermissionsForPathError::OperationTimeout(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEffectivePermissionsForPathError::OperationTimeout,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -918,17 +1002,30 @@ impl GrantPermissionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(GrantPermissionsError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GrantPermissionsError::ConcurrentModification,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(GrantPermissionsError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GrantPermissionsError::EntityNotFound,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GrantPermissionsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GrantPermissionsError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -962,15 +1059,30 @@ impl ListPermissionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListPermissionsError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPermissionsError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListPermissionsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPermissionsError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(ListPermissionsError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPermissionsError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1004,15 +1116,30 @@ impl ListResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListResourcesError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourcesError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListResourcesError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourcesError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(ListResourcesError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourcesError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1044,12 +1171,22 @@ impl PutDataLakeSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(PutDataLakeSettingsError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutDataLakeSettingsError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(PutDataLakeSettingsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutDataLakeSettingsError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1084,18 +1221,38 @@ impl RegisterResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(RegisterResourceError::AlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterResourceError::AlreadyExists,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(RegisterResourceError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterResourceError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(RegisterResourceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterResourceError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(RegisterResourceError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterResourceError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1130,17 +1287,30 @@ impl RevokePermissionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(RevokePermissionsError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RevokePermissionsError::ConcurrentModification,
+                    )
                 }
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(RevokePermissionsError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RevokePermissionsError::EntityNotFound,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(RevokePermissionsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RevokePermissionsError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1176,18 +1346,38 @@ impl UpdateResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "EntityNotFoundException" => {
-                    return RusotoError::Service(UpdateResourceError::EntityNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateResourceError::EntityNotFound,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(UpdateResourceError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateResourceError::InternalService,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateResourceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateResourceError::InvalidInput,
+                    )
                 }
                 "OperationTimeoutException" => {
-                    return RusotoError::Service(UpdateResourceError::OperationTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateResourceError::OperationTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1300,11 +1490,12 @@ pub struct LakeFormationClient {
 impl LakeFormationClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> LakeFormationClient {
         LakeFormationClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "LakeFormation"),
         }
     }
 
@@ -1319,12 +1510,32 @@ impl LakeFormationClient {
     {
         LakeFormationClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "LakeFormation"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> LakeFormationClient {
-        LakeFormationClient { client, region }
+        LakeFormationClient {
+            client,
+            region: region_for_service(region, "LakeFormation"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> LakeFormationClient {
+        LakeFormationClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("LakeFormation"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> LakeFormationClient {
+        LakeFormationClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -1336,6 +1547,7 @@ impl LakeFormation for LakeFormationClient {
         input: BatchGrantPermissionsRequest,
     ) -> Result<BatchGrantPermissionsResponse, RusotoError<BatchGrantPermissionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchGrantPermissions");
         request.add_header("x-amz-target", "AWSLakeFormation.BatchGrantPermissions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1355,6 +1567,7 @@ impl LakeFormation for LakeFormationClient {
         input: BatchRevokePermissionsRequest,
     ) -> Result<BatchRevokePermissionsResponse, RusotoError<BatchRevokePermissionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchRevokePermissions");
         request.add_header("x-amz-target", "AWSLakeFormation.BatchRevokePermissions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1374,6 +1587,7 @@ impl LakeFormation for LakeFormationClient {
         input: DeregisterResourceRequest,
     ) -> Result<DeregisterResourceResponse, RusotoError<DeregisterResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeregisterResource");
         request.add_header("x-amz-target", "AWSLakeFormation.DeregisterResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1392,6 +1606,7 @@ impl LakeFormation for LakeFormationClient {
         input: DescribeResourceRequest,
     ) -> Result<DescribeResourceResponse, RusotoError<DescribeResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeResource");
         request.add_header("x-amz-target", "AWSLakeFormation.DescribeResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1410,6 +1625,7 @@ impl LakeFormation for LakeFormationClient {
         input: GetDataLakeSettingsRequest,
     ) -> Result<GetDataLakeSettingsResponse, RusotoError<GetDataLakeSettingsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDataLakeSettings");
         request.add_header("x-amz-target", "AWSLakeFormation.GetDataLakeSettings");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1431,6 +1647,7 @@ impl LakeFormation for LakeFormationClient {
         RusotoError<GetEffectivePermissionsForPathError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetEffectivePermissionsForPath");
         request.add_header(
             "x-amz-target",
             "AWSLakeFormation.GetEffectivePermissionsForPath",
@@ -1453,6 +1670,7 @@ impl LakeFormation for LakeFormationClient {
         input: GrantPermissionsRequest,
     ) -> Result<GrantPermissionsResponse, RusotoError<GrantPermissionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GrantPermissions");
         request.add_header("x-amz-target", "AWSLakeFormation.GrantPermissions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1471,6 +1689,7 @@ impl LakeFormation for LakeFormationClient {
         input: ListPermissionsRequest,
     ) -> Result<ListPermissionsResponse, RusotoError<ListPermissionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListPermissions");
         request.add_header("x-amz-target", "AWSLakeFormation.ListPermissions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1489,6 +1708,7 @@ impl LakeFormation for LakeFormationClient {
         input: ListResourcesRequest,
     ) -> Result<ListResourcesResponse, RusotoError<ListResourcesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListResources");
         request.add_header("x-amz-target", "AWSLakeFormation.ListResources");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1507,6 +1727,7 @@ impl LakeFormation for LakeFormationClient {
         input: PutDataLakeSettingsRequest,
     ) -> Result<PutDataLakeSettingsResponse, RusotoError<PutDataLakeSettingsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutDataLakeSettings");
         request.add_header("x-amz-target", "AWSLakeFormation.PutDataLakeSettings");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1525,6 +1746,7 @@ impl LakeFormation for LakeFormationClient {
         input: RegisterResourceRequest,
     ) -> Result<RegisterResourceResponse, RusotoError<RegisterResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RegisterResource");
         request.add_header("x-amz-target", "AWSLakeFormation.RegisterResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1543,6 +1765,7 @@ impl LakeFormation for LakeFormationClient {
         input: RevokePermissionsRequest,
     ) -> Result<RevokePermissionsResponse, RusotoError<RevokePermissionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RevokePermissions");
         request.add_header("x-amz-target", "AWSLakeFormation.RevokePermissions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1561,6 +1784,7 @@ impl LakeFormation for LakeFormationClient {
         input: UpdateResourceRequest,
     ) -> Result<UpdateResourceResponse, RusotoError<UpdateResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateResource");
         request.add_header("x-amz-target", "AWSLakeFormation.UpdateResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1573,3 +1797,196 @@ impl LakeFormation for LakeFormationClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateResourceResponse, _>()
     }
 }
+
+/// A blocking client for the AWS Lake Formation API, running the calls of a `LakeFormationClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `LakeFormationClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct LakeFormationBlockingClient {
+    client: LakeFormationClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl LakeFormationBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> LakeFormationBlockingClient {
+        LakeFormationBlockingClient::from_client(LakeFormationClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> LakeFormationBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        LakeFormationBlockingClient::from_client(LakeFormationClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> LakeFormationBlockingClient {
+        LakeFormationBlockingClient::from_client(LakeFormationClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> LakeFormationBlockingClient {
+        LakeFormationBlockingClient::from_client(LakeFormationClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: LakeFormationClient) -> LakeFormationBlockingClient {
+        LakeFormationBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> LakeFormationBlockingClient {
+        LakeFormationBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> LakeFormationBlockingClient {
+        LakeFormationBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`LakeFormation::batch_grant_permissions`].
+    pub fn batch_grant_permissions(
+        &self,
+        input: BatchGrantPermissionsRequest,
+    ) -> Result<BatchGrantPermissionsResponse, RusotoError<BatchGrantPermissionsError>> {
+        self.runtime
+            .block_on(self.client.batch_grant_permissions(input))
+    }
+
+    /// Blocking version of [`LakeFormation::batch_revoke_permissions`].
+    pub fn batch_revoke_permissions(
+        &self,
+        input: BatchRevokePermissionsRequest,
+    ) -> Result<BatchRevokePermissionsResponse, RusotoError<BatchRevokePermissionsError>> {
+        self.runtime
+            .block_on(self.client.batch_revoke_permissions(input))
+    }
+
+    /// Blocking version of [`LakeFormation::deregister_resource`].
+    pub fn deregister_resource(
+        &self,
+        input: DeregisterResourceRequest,
+    ) -> Result<DeregisterResourceResponse, RusotoError<DeregisterResourceError>> {
+        self.runtime
+            .block_on(self.client.deregister_resource(input))
+    }
+
+    /// Blocking version of [`LakeFormation::describe_resource`].
+    pub fn describe_resource(
+        &self,
+        input: DescribeResourceRequest,
+    ) -> Result<DescribeResourceResponse, RusotoError<DescribeResourceError>> {
+        self.runtime.block_on(self.client.describe_resource(input))
+    }
+
+    /// Blocking version of [`LakeFormation::get_data_lake_settings`].
+    pub fn get_data_lake_settings(
+        &self,
+        input: GetDataLakeSettingsRequest,
+    ) -> Result<GetDataLakeSettingsResponse, RusotoError<GetDataLakeSettingsError>> {
+        self.runtime
+            .block_on(self.client.get_data_lake_settings(input))
+    }
+
+    /// Blocking version of [`LakeFormation::get_effective_permissions_for_path`].
+    pub fn get_effective_permissions_for_path(
+        &self,
+        input: GetEffectivePermissionsForPathRequest,
+    ) -> Result<
+        GetEffectivePermissionsForPathResponse,
+        RusotoError<GetEffectivePermissionsForPathError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_effective_permissions_for_path(input))
+    }
+
+    /// Blocking version of [`LakeFormation::grant_permissions`].
+    pub fn grant_permissions(
+        &self,
+        input: GrantPermissionsRequest,
+    ) -> Result<GrantPermissionsResponse, RusotoError<GrantPermissionsError>> {
+        self.runtime.block_on(self.client.grant_permissions(input))
+    }
+
+    /// Blocking version of [`LakeFormation::list_permissions`].
+    pub fn list_permissions(
+        &self,
+        input: ListPermissionsRequest,
+    ) -> Result<ListPermissionsResponse, RusotoError<ListPermissionsError>> {
+        self.runtime.block_on(self.client.list_permissions(input))
+    }
+
+    /// Blocking version of [`LakeFormation::list_resources`].
+    pub fn list_resources(
+        &self,
+        input: ListResourcesRequest,
+    ) -> Result<ListResourcesResponse, RusotoError<ListResourcesError>> {
+        self.runtime.block_on(self.client.list_resources(input))
+    }
+
+    /// Blocking version of [`LakeFormation::put_data_lake_settings`].
+    pub fn put_data_lake_settings(
+        &self,
+        input: PutDataLakeSettingsRequest,
+    ) -> Result<PutDataLakeSettingsResponse, RusotoError<PutDataLakeSettingsError>> {
+        self.runtime
+            .block_on(self.client.put_data_lake_settings(input))
+    }
+
+    /// Blocking version of [`LakeFormation::register_resource`].
+    pub fn register_resource(
+        &self,
+        input: RegisterResourceRequest,
+    ) -> Result<RegisterResourceResponse, RusotoError<RegisterResourceError>> {
+        self.runtime.block_on(self.client.register_resource(input))
+    }
+
+    /// Blocking version of [`LakeFormation::revoke_permissions`].
+    pub fn revoke_permissions(
+        &self,
+        input: RevokePermissionsRequest,
+    ) -> Result<RevokePermissionsResponse, RusotoError<RevokePermissionsError>> {
+        self.runtime.block_on(self.client.revoke_permissions(input))
+    }
+
+    /// Blocking version of [`LakeFormation::update_resource`].
+    pub fn update_resource(
+        &self,
+        input: UpdateResourceRequest,
+    ) -> Result<UpdateResourceResponse, RusotoError<UpdateResourceError>> {
+        self.runtime.block_on(self.client.update_resource(input))
+    }
+}
@@ -3,12 +3,12 @@ extern crate rusoto_mock;
 use bytes::Bytes;
 
 use crate::generated::{
-    GetPolicyRequest, GetPolicyResponse, InvocationRequest, Lambda, LambdaClient,
+    GetPolicyError, GetPolicyRequest, GetPolicyResponse, InvocationRequest, Lambda, LambdaClient,
 };
 
 use self::rusoto_mock::*;
 use rusoto_core::signature::{SignedRequest, SignedRequestPayload};
-use rusoto_core::Region;
+use rusoto_core::{Region, RusotoError};
 
 #[tokio::test]
 async fn serialize_get_policy_response() {
@@ -64,3 +64,35 @@ async fn should_parse_invocation_response() {
     assert_eq!(Some("Handled".to_owned()), result.function_error);
     assert_eq!(Some(200), result.status_code);
 }
+
+#[tokio::test]
+async fn should_parse_error_metadata() {
+    let mock = MockRequestDispatcher::with_status(404)
+        .with_body(r#"{"Type":"User","Message":"Function not found: test-func"}"#)
+        .with_header(
+            "x-amzn-ErrorType",
+            "ResourceNotFoundException:http://internal.amazon.com/coral/com.amazon.coral.service/",
+        )
+        .with_header("x-amzn-RequestId", "8c5f5d3e-6b8a-4b8f-9b4c-2a1e4a6f2d5b");
+    let client = LambdaClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    let err = client
+        .get_policy(GetPolicyRequest {
+            function_name: "test-func".into(),
+            ..GetPolicyRequest::default()
+        })
+        .await
+        .unwrap_err();
+
+    match err {
+        RusotoError::Service(GetPolicyError::ResourceNotFound(ref message), _) => {
+            assert_eq!(message, "Function not found: test-func")
+        }
+        _ => panic!("Expected a service error, got {:?}", err),
+    }
+    let metadata = err.metadata().unwrap();
+    assert_eq!(metadata.code.as_deref(), Some("ResourceNotFoundException"));
+    assert_eq!(
+        metadata.request_id.as_deref(),
+        Some("8c5f5d3e-6b8a-4b8f-9b4c-2a1e4a6f2d5b")
+    );
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -2404,39 +2405,62 @@ impl AddLayerVersionPermissionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        AddLayerVersionPermissionError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddLayerVersionPermissionError::InvalidParameterValue,
                     )
                 }
                 "PolicyLengthExceededException" => {
-                    return RusotoError::Service(
-                        AddLayerVersionPermissionError::PolicyLengthExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddLayerVersionPermissionError::PolicyLengthExceeded,
                     )
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(
-                        AddLayerVersionPermissionError::PreconditionFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddLayerVersionPermissionError::PreconditionFailed,
                     )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(AddLayerVersionPermissionError::ResourceConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddLayerVersionPermissionError::ResourceConflict,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddLayerVersionPermissionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddLayerVersionPermissionError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AddLayerVersionPermissionError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddLayerVersionPermissionError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AddLayerVersionPermissionError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddLayerVersionPermissionError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2486,27 +2510,62 @@ impl AddPermissionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(AddPermissionError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddPermissionError::InvalidParameterValue,
+                    )
                 }
                 "PolicyLengthExceededException" => {
-                    return RusotoError::Service(AddPermissionError::PolicyLengthExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddPermissionError::PolicyLengthExceeded,
+                    )
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(AddPermissionError::PreconditionFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddPermissionError::PreconditionFailed,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(AddPermissionError::ResourceConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddPermissionError::ResourceConflict,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddPermissionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddPermissionError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AddPermissionError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddPermissionError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AddPermissionError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddPermissionError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2548,21 +2607,46 @@ impl CreateAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateAliasError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAliasError::InvalidParameterValue,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(CreateAliasError::ResourceConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAliasError::ResourceConflict,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateAliasError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAliasError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateAliasError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAliasError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateAliasError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAliasError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2596,14 +2680,22 @@ impl CreateCodeSigningConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        CreateCodeSigningConfigError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCodeSigningConfigError::InvalidParameterValue,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateCodeSigningConfigError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCodeSigningConfigError::Service,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2642,29 +2734,46 @@ impl CreateEventSourceMappingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        CreateEventSourceMappingError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEventSourceMappingError::InvalidParameterValue,
                     )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(CreateEventSourceMappingError::ResourceConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateEventSourceMappingError::ResourceConflict,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateEventSourceMappingError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateEventSourceMappingError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateEventSourceMappingError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEventSourceMappingError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateEventSourceMappingError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateEventSourceMappingError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2714,39 +2823,78 @@ impl CreateFunctionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CodeSigningConfigNotFoundException" => {
-                    return RusotoError::Service(CreateFunctionError::CodeSigningConfigNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateFunctionError::CodeSigningConfigNotFound,
+                    )
                 }
                 "CodeStorageExceededException" => {
-                    return RusotoError::Service(CreateFunctionError::CodeStorageExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFunctionError::CodeStorageExceeded,
+                    )
                 }
                 "CodeVerificationFailedException" => {
-                    return RusotoError::Service(CreateFunctionError::CodeVerificationFailed(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateFunctionError::CodeVerificationFailed,
+                    )
                 }
                 "InvalidCodeSignatureException" => {
-                    return RusotoError::Service(CreateFunctionError::InvalidCodeSignature(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFunctionError::InvalidCodeSignature,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateFunctionError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateFunctionError::InvalidParameterValue,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(CreateFunctionError::ResourceConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFunctionError::ResourceConflict,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateFunctionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFunctionError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateFunctionError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFunctionError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateFunctionError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFunctionError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2788,18 +2936,38 @@ impl DeleteAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteAliasError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAliasError::InvalidParameterValue,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(DeleteAliasError::ResourceConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAliasError::ResourceConflict,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteAliasError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAliasError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteAliasError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAliasError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2836,24 +3004,38 @@ impl DeleteCodeSigningConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DeleteCodeSigningConfigError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCodeSigningConfigError::InvalidParameterValue,
                     )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(DeleteCodeSigningConfigError::ResourceConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteCodeSigningConfigError::ResourceConflict,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteCodeSigningConfigError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteCodeSigningConfigError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteCodeSigningConfigError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCodeSigningConfigError::Service,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2894,29 +3076,46 @@ impl DeleteEventSourceMappingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DeleteEventSourceMappingError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEventSourceMappingError::InvalidParameterValue,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteEventSourceMappingError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteEventSourceMappingError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteEventSourceMappingError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteEventSourceMappingError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteEventSourceMappingError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEventSourceMappingError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteEventSourceMappingError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteEventSourceMappingError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2958,23 +3157,46 @@ impl DeleteFunctionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteFunctionError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteFunctionError::InvalidParameterValue,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(DeleteFunctionError::ResourceConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFunctionError::ResourceConflict,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteFunctionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFunctionError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteFunctionError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFunctionError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteFunctionError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFunctionError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3018,36 +3240,54 @@ impl DeleteFunctionCodeSigningConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CodeSigningConfigNotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteFunctionCodeSigningConfigError::CodeSigningConfigNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFunctionCodeSigningConfigError::CodeSigningConfigNotFound,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DeleteFunctionCodeSigningConfigError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFunctionCodeSigningConfigError::InvalidParameterValue,
                     )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(
-                        DeleteFunctionCodeSigningConfigError::ResourceConflict(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFunctionCodeSigningConfigError::ResourceConflict,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteFunctionCodeSigningConfigError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFunctionCodeSigningConfigError::ResourceNotFound,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteFunctionCodeSigningConfigError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteFunctionCodeSigningConfigError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        DeleteFunctionCodeSigningConfigError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFunctionCodeSigningConfigError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3098,29 +3338,46 @@ impl DeleteFunctionConcurrencyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DeleteFunctionConcurrencyError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFunctionConcurrencyError::InvalidParameterValue,
                     )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(DeleteFunctionConcurrencyError::ResourceConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteFunctionConcurrencyError::ResourceConflict,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteFunctionConcurrencyError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteFunctionConcurrencyError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteFunctionConcurrencyError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFunctionConcurrencyError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteFunctionConcurrencyError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteFunctionConcurrencyError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3162,26 +3419,38 @@ impl DeleteFunctionEventInvokeConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DeleteFunctionEventInvokeConfigError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFunctionEventInvokeConfigError::InvalidParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteFunctionEventInvokeConfigError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFunctionEventInvokeConfigError::ResourceNotFound,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteFunctionEventInvokeConfigError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteFunctionEventInvokeConfigError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        DeleteFunctionEventInvokeConfigError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFunctionEventInvokeConfigError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3220,12 +3489,22 @@ impl DeleteLayerVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteLayerVersionError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLayerVersionError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteLayerVersionError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLayerVersionError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3264,31 +3543,46 @@ impl DeleteProvisionedConcurrencyConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DeleteProvisionedConcurrencyConfigError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProvisionedConcurrencyConfigError::InvalidParameterValue,
                     )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(
-                        DeleteProvisionedConcurrencyConfigError::ResourceConflict(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProvisionedConcurrencyConfigError::ResourceConflict,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteProvisionedConcurrencyConfigError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProvisionedConcurrencyConfigError::ResourceNotFound,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteProvisionedConcurrencyConfigError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteProvisionedConcurrencyConfigError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        DeleteProvisionedConcurrencyConfigError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProvisionedConcurrencyConfigError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3330,12 +3624,22 @@ impl GetAccountSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ServiceException" => {
-                    return RusotoError::Service(GetAccountSettingsError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAccountSettingsError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetAccountSettingsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAccountSettingsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3370,16 +3674,38 @@ impl GetAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetAliasError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAliasError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetAliasError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAliasError::ResourceNotFound,
+                    )
+                }
+                "ServiceException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAliasError::Service,
+                    )
                 }
-                "ServiceException" => return RusotoError::Service(GetAliasError::Service(err.msg)),
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetAliasError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAliasError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3414,19 +3740,30 @@ impl GetCodeSigningConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetCodeSigningConfigError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCodeSigningConfigError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetCodeSigningConfigError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCodeSigningConfigError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetCodeSigningConfigError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCodeSigningConfigError::Service,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3462,24 +3799,38 @@ impl GetEventSourceMappingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetEventSourceMappingError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetEventSourceMappingError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetEventSourceMappingError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetEventSourceMappingError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetEventSourceMappingError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEventSourceMappingError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetEventSourceMappingError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetEventSourceMappingError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3516,18 +3867,38 @@ impl GetFunctionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetFunctionError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFunctionError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetFunctionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFunctionError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetFunctionError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFunctionError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetFunctionError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFunctionError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3566,26 +3937,38 @@ impl GetFunctionCodeSigningConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        GetFunctionCodeSigningConfigError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFunctionCodeSigningConfigError::InvalidParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        GetFunctionCodeSigningConfigError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFunctionCodeSigningConfigError::ResourceNotFound,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetFunctionCodeSigningConfigError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetFunctionCodeSigningConfigError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        GetFunctionCodeSigningConfigError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFunctionCodeSigningConfigError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3626,24 +4009,38 @@ impl GetFunctionConcurrencyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        GetFunctionConcurrencyError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFunctionConcurrencyError::InvalidParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetFunctionConcurrencyError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetFunctionConcurrencyError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetFunctionConcurrencyError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFunctionConcurrencyError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetFunctionConcurrencyError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetFunctionConcurrencyError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3680,24 +4077,38 @@ impl GetFunctionConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        GetFunctionConfigurationError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFunctionConfigurationError::InvalidParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetFunctionConfigurationError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetFunctionConfigurationError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetFunctionConfigurationError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFunctionConfigurationError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetFunctionConfigurationError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetFunctionConfigurationError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3738,26 +4149,38 @@ impl GetFunctionEventInvokeConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        GetFunctionEventInvokeConfigError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFunctionEventInvokeConfigError::InvalidParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        GetFunctionEventInvokeConfigError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFunctionEventInvokeConfigError::ResourceNotFound,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetFunctionEventInvokeConfigError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetFunctionEventInvokeConfigError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        GetFunctionEventInvokeConfigError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFunctionEventInvokeConfigError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3798,20 +4221,38 @@ impl GetLayerVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetLayerVersionError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetLayerVersionError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetLayerVersionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLayerVersionError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetLayerVersionError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLayerVersionError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetLayerVersionError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLayerVersionError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3848,24 +4289,38 @@ impl GetLayerVersionByArnError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetLayerVersionByArnError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetLayerVersionByArnError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetLayerVersionByArnError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetLayerVersionByArnError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetLayerVersionByArnError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLayerVersionByArnError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetLayerVersionByArnError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetLayerVersionByArnError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3902,24 +4357,38 @@ impl GetLayerVersionPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetLayerVersionPolicyError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetLayerVersionPolicyError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetLayerVersionPolicyError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetLayerVersionPolicyError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetLayerVersionPolicyError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLayerVersionPolicyError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetLayerVersionPolicyError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetLayerVersionPolicyError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3956,18 +4425,38 @@ impl GetPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetPolicyError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPolicyError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetPolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPolicyError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetPolicyError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPolicyError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetPolicyError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPolicyError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4008,33 +4497,46 @@ impl GetProvisionedConcurrencyConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        GetProvisionedConcurrencyConfigError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetProvisionedConcurrencyConfigError::InvalidParameterValue,
                     )
                 }
                 "ProvisionedConcurrencyConfigNotFoundException" => {
-                    return RusotoError::Service(
-                        GetProvisionedConcurrencyConfigError::ProvisionedConcurrencyConfigNotFound(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetProvisionedConcurrencyConfigError::ProvisionedConcurrencyConfigNotFound,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        GetProvisionedConcurrencyConfigError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetProvisionedConcurrencyConfigError::ResourceNotFound,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetProvisionedConcurrencyConfigError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetProvisionedConcurrencyConfigError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        GetProvisionedConcurrencyConfigError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetProvisionedConcurrencyConfigError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4124,80 +4626,209 @@ impl InvokeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EC2AccessDeniedException" => {
-                    return RusotoError::Service(InvokeError::EC2AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InvokeError::EC2AccessDenied,
+                    )
                 }
                 "EC2ThrottledException" => {
-                    return RusotoError::Service(InvokeError::EC2Throttled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InvokeError::EC2Throttled,
+                    )
                 }
                 "EC2UnexpectedException" => {
-                    return RusotoError::Service(InvokeError::EC2Unexpected(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InvokeError::EC2Unexpected,
+                    )
+                }
+                "EFSIOException" => {
+                    return RusotoError::service_error(&res, &err.typ, err.msg, InvokeError::EFSIO)
                 }
-                "EFSIOException" => return RusotoError::Service(InvokeError::EFSIO(err.msg)),
                 "EFSMountConnectivityException" => {
-                    return RusotoError::Service(InvokeError::EFSMountConnectivity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InvokeError::EFSMountConnectivity,
+                    )
                 }
                 "EFSMountFailureException" => {
-                    return RusotoError::Service(InvokeError::EFSMountFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InvokeError::EFSMountFailure,
+                    )
                 }
                 "EFSMountTimeoutException" => {
-                    return RusotoError::Service(InvokeError::EFSMountTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InvokeError::EFSMountTimeout,
+                    )
                 }
                 "ENILimitReachedException" => {
-                    return RusotoError::Service(InvokeError::ENILimitReached(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InvokeError::ENILimitReached,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(InvokeError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InvokeError::InvalidParameterValue,
+                    )
                 }
                 "InvalidRequestContentException" => {
-                    return RusotoError::Service(InvokeError::InvalidRequestContent(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InvokeError::InvalidRequestContent,
+                    )
                 }
                 "InvalidRuntimeException" => {
-                    return RusotoError::Service(InvokeError::InvalidRuntime(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InvokeError::InvalidRuntime,
+                    )
                 }
                 "InvalidSecurityGroupIDException" => {
-                    return RusotoError::Service(InvokeError::InvalidSecurityGroupID(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InvokeError::InvalidSecurityGroupID,
+                    )
                 }
                 "InvalidSubnetIDException" => {
-                    return RusotoError::Service(InvokeError::InvalidSubnetID(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InvokeError::InvalidSubnetID,
+                    )
                 }
                 "InvalidZipFileException" => {
-                    return RusotoError::Service(InvokeError::InvalidZipFile(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InvokeError::InvalidZipFile,
+                    )
                 }
                 "KMSAccessDeniedException" => {
-                    return RusotoError::Service(InvokeError::KMSAccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InvokeError::KMSAccessDenied,
+                    )
                 }
                 "KMSDisabledException" => {
-                    return RusotoError::Service(InvokeError::KMSDisabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InvokeError::KMSDisabled,
+                    )
                 }
                 "KMSInvalidStateException" => {
-                    return RusotoError::Service(InvokeError::KMSInvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InvokeError::KMSInvalidState,
+                    )
                 }
                 "KMSNotFoundException" => {
-                    return RusotoError::Service(InvokeError::KMSNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InvokeError::KMSNotFound,
+                    )
                 }
                 "RequestTooLargeException" => {
-                    return RusotoError::Service(InvokeError::RequestTooLarge(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InvokeError::RequestTooLarge,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(InvokeError::ResourceConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InvokeError::ResourceConflict,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(InvokeError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InvokeError::ResourceNotFound,
+                    )
                 }
                 "ResourceNotReadyException" => {
-                    return RusotoError::Service(InvokeError::ResourceNotReady(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InvokeError::ResourceNotReady,
+                    )
+                }
+                "ServiceException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InvokeError::Service,
+                    )
                 }
-                "ServiceException" => return RusotoError::Service(InvokeError::Service(err.msg)),
                 "SubnetIPAddressLimitReachedException" => {
-                    return RusotoError::Service(InvokeError::SubnetIPAddressLimitReached(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InvokeError::SubnetIPAddressLimitReached,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(InvokeError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InvokeError::TooManyRequests,
+                    )
                 }
                 "UnsupportedMediaTypeException" => {
-                    return RusotoError::Service(InvokeError::UnsupportedMediaType(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InvokeError::UnsupportedMediaType,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4258,21 +4889,46 @@ impl InvokeAsyncError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidRequestContentException" => {
-                    return RusotoError::Service(InvokeAsyncError::InvalidRequestContent(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InvokeAsyncError::InvalidRequestContent,
+                    )
                 }
                 "InvalidRuntimeException" => {
-                    return RusotoError::Service(InvokeAsyncError::InvalidRuntime(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InvokeAsyncError::InvalidRuntime,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(InvokeAsyncError::ResourceConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InvokeAsyncError::ResourceConflict,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(InvokeAsyncError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InvokeAsyncError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(InvokeAsyncError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InvokeAsyncError::Service,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4310,18 +4966,38 @@ impl ListAliasesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListAliasesError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAliasesError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListAliasesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAliasesError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListAliasesError::Service(err.msg))
-                }
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAliasesError::Service,
+                    )
+                }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListAliasesError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAliasesError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4354,14 +5030,22 @@ impl ListCodeSigningConfigsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        ListCodeSigningConfigsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCodeSigningConfigsError::InvalidParameterValue,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListCodeSigningConfigsError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCodeSigningConfigsError::Service,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4396,24 +5080,38 @@ impl ListEventSourceMappingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        ListEventSourceMappingsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListEventSourceMappingsError::InvalidParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListEventSourceMappingsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListEventSourceMappingsError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListEventSourceMappingsError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListEventSourceMappingsError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListEventSourceMappingsError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListEventSourceMappingsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4454,26 +5152,38 @@ impl ListFunctionEventInvokeConfigsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        ListFunctionEventInvokeConfigsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFunctionEventInvokeConfigsError::InvalidParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        ListFunctionEventInvokeConfigsError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFunctionEventInvokeConfigsError::ResourceNotFound,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListFunctionEventInvokeConfigsError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListFunctionEventInvokeConfigsError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        ListFunctionEventInvokeConfigsError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFunctionEventInvokeConfigsError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4514,15 +5224,30 @@ impl ListFunctionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListFunctionsError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFunctionsError::InvalidParameterValue,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListFunctionsError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFunctionsError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListFunctionsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFunctionsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4558,21 +5283,30 @@ impl ListFunctionsByCodeSigningConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        ListFunctionsByCodeSigningConfigError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFunctionsByCodeSigningConfigError::InvalidParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        ListFunctionsByCodeSigningConfigError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFunctionsByCodeSigningConfigError::ResourceNotFound,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListFunctionsByCodeSigningConfigError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListFunctionsByCodeSigningConfigError::Service,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4612,20 +5346,38 @@ impl ListLayerVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListLayerVersionsError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListLayerVersionsError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListLayerVersionsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLayerVersionsError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListLayerVersionsError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLayerVersionsError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListLayerVersionsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLayerVersionsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4660,15 +5412,30 @@ impl ListLayersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListLayersError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLayersError::InvalidParameterValue,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListLayersError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLayersError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListLayersError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLayersError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4706,26 +5473,38 @@ impl ListProvisionedConcurrencyConfigsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        ListProvisionedConcurrencyConfigsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProvisionedConcurrencyConfigsError::InvalidParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        ListProvisionedConcurrencyConfigsError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProvisionedConcurrencyConfigsError::ResourceNotFound,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListProvisionedConcurrencyConfigsError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListProvisionedConcurrencyConfigsError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        ListProvisionedConcurrencyConfigsError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProvisionedConcurrencyConfigsError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4768,16 +5547,38 @@ impl ListTagsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListTagsError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsError::ResourceNotFound,
+                    )
+                }
+                "ServiceException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsError::Service,
+                    )
                 }
-                "ServiceException" => return RusotoError::Service(ListTagsError::Service(err.msg)),
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListTagsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4814,24 +5615,38 @@ impl ListVersionsByFunctionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        ListVersionsByFunctionError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVersionsByFunctionError::InvalidParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListVersionsByFunctionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListVersionsByFunctionError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListVersionsByFunctionError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListVersionsByFunctionError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListVersionsByFunctionError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListVersionsByFunctionError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4870,27 +5685,46 @@ impl PublishLayerVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CodeStorageExceededException" => {
-                    return RusotoError::Service(PublishLayerVersionError::CodeStorageExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PublishLayerVersionError::CodeStorageExceeded,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(PublishLayerVersionError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PublishLayerVersionError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PublishLayerVersionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PublishLayerVersionError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(PublishLayerVersionError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PublishLayerVersionError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PublishLayerVersionError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PublishLayerVersionError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4934,29 +5768,62 @@ impl PublishVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CodeStorageExceededException" => {
-                    return RusotoError::Service(PublishVersionError::CodeStorageExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PublishVersionError::CodeStorageExceeded,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(PublishVersionError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PublishVersionError::InvalidParameterValue,
+                    )
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(PublishVersionError::PreconditionFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PublishVersionError::PreconditionFailed,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(PublishVersionError::ResourceConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PublishVersionError::ResourceConflict,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PublishVersionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PublishVersionError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(PublishVersionError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PublishVersionError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PublishVersionError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PublishVersionError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5002,36 +5869,54 @@ impl PutFunctionCodeSigningConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CodeSigningConfigNotFoundException" => {
-                    return RusotoError::Service(
-                        PutFunctionCodeSigningConfigError::CodeSigningConfigNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutFunctionCodeSigningConfigError::CodeSigningConfigNotFound,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        PutFunctionCodeSigningConfigError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutFunctionCodeSigningConfigError::InvalidParameterValue,
                     )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(
-                        PutFunctionCodeSigningConfigError::ResourceConflict(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutFunctionCodeSigningConfigError::ResourceConflict,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        PutFunctionCodeSigningConfigError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutFunctionCodeSigningConfigError::ResourceNotFound,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(PutFunctionCodeSigningConfigError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutFunctionCodeSigningConfigError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        PutFunctionCodeSigningConfigError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutFunctionCodeSigningConfigError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5080,29 +5965,46 @@ impl PutFunctionConcurrencyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        PutFunctionConcurrencyError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutFunctionConcurrencyError::InvalidParameterValue,
                     )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(PutFunctionConcurrencyError::ResourceConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutFunctionConcurrencyError::ResourceConflict,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutFunctionConcurrencyError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutFunctionConcurrencyError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(PutFunctionConcurrencyError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutFunctionConcurrencyError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PutFunctionConcurrencyError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutFunctionConcurrencyError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5142,26 +6044,38 @@ impl PutFunctionEventInvokeConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        PutFunctionEventInvokeConfigError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutFunctionEventInvokeConfigError::InvalidParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        PutFunctionEventInvokeConfigError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutFunctionEventInvokeConfigError::ResourceNotFound,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(PutFunctionEventInvokeConfigError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutFunctionEventInvokeConfigError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        PutFunctionEventInvokeConfigError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutFunctionEventInvokeConfigError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5206,31 +6120,46 @@ impl PutProvisionedConcurrencyConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        PutProvisionedConcurrencyConfigError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutProvisionedConcurrencyConfigError::InvalidParameterValue,
                     )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(
-                        PutProvisionedConcurrencyConfigError::ResourceConflict(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutProvisionedConcurrencyConfigError::ResourceConflict,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        PutProvisionedConcurrencyConfigError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutProvisionedConcurrencyConfigError::ResourceNotFound,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(PutProvisionedConcurrencyConfigError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutProvisionedConcurrencyConfigError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        PutProvisionedConcurrencyConfigError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutProvisionedConcurrencyConfigError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5280,31 +6209,46 @@ impl RemoveLayerVersionPermissionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        RemoveLayerVersionPermissionError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveLayerVersionPermissionError::InvalidParameterValue,
                     )
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(
-                        RemoveLayerVersionPermissionError::PreconditionFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveLayerVersionPermissionError::PreconditionFailed,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        RemoveLayerVersionPermissionError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveLayerVersionPermissionError::ResourceNotFound,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(RemoveLayerVersionPermissionError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveLayerVersionPermissionError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        RemoveLayerVersionPermissionError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveLayerVersionPermissionError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5350,23 +6294,46 @@ impl RemovePermissionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(RemovePermissionError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemovePermissionError::InvalidParameterValue,
+                    )
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(RemovePermissionError::PreconditionFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemovePermissionError::PreconditionFailed,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RemovePermissionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemovePermissionError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(RemovePermissionError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemovePermissionError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(RemovePermissionError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemovePermissionError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5406,21 +6373,46 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidParameterValue,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(TagResourceError::ResourceConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceConflict,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(TagResourceError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5460,21 +6452,46 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidParameterValue,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceConflict,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UntagResourceError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5516,24 +6533,54 @@ impl UpdateAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateAliasError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAliasError::InvalidParameterValue,
+                    )
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(UpdateAliasError::PreconditionFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAliasError::PreconditionFailed,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(UpdateAliasError::ResourceConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAliasError::ResourceConflict,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateAliasError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAliasError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateAliasError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAliasError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateAliasError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAliasError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5570,19 +6617,30 @@ impl UpdateCodeSigningConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        UpdateCodeSigningConfigError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCodeSigningConfigError::InvalidParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateCodeSigningConfigError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateCodeSigningConfigError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateCodeSigningConfigError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCodeSigningConfigError::Service,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5624,34 +6682,54 @@ impl UpdateEventSourceMappingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        UpdateEventSourceMappingError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateEventSourceMappingError::InvalidParameterValue,
                     )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(UpdateEventSourceMappingError::ResourceConflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateEventSourceMappingError::ResourceConflict,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateEventSourceMappingError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateEventSourceMappingError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateEventSourceMappingError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateEventSourceMappingError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateEventSourceMappingError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateEventSourceMappingError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateEventSourceMappingError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateEventSourceMappingError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5704,48 +6782,86 @@ impl UpdateFunctionCodeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CodeSigningConfigNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateFunctionCodeError::CodeSigningConfigNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFunctionCodeError::CodeSigningConfigNotFound,
                     )
                 }
                 "CodeStorageExceededException" => {
-                    return RusotoError::Service(UpdateFunctionCodeError::CodeStorageExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateFunctionCodeError::CodeStorageExceeded,
+                    )
                 }
                 "CodeVerificationFailedException" => {
-                    return RusotoError::Service(UpdateFunctionCodeError::CodeVerificationFailed(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateFunctionCodeError::CodeVerificationFailed,
+                    )
                 }
                 "InvalidCodeSignatureException" => {
-                    return RusotoError::Service(UpdateFunctionCodeError::InvalidCodeSignature(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateFunctionCodeError::InvalidCodeSignature,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateFunctionCodeError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateFunctionCodeError::InvalidParameterValue,
+                    )
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(UpdateFunctionCodeError::PreconditionFailed(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateFunctionCodeError::PreconditionFailed,
+                    )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(UpdateFunctionCodeError::ResourceConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFunctionCodeError::ResourceConflict,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateFunctionCodeError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFunctionCodeError::ResourceNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateFunctionCodeError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFunctionCodeError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateFunctionCodeError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFunctionCodeError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5800,49 +6916,78 @@ impl UpdateFunctionConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "CodeSigningConfigNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateFunctionConfigurationError::CodeSigningConfigNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFunctionConfigurationError::CodeSigningConfigNotFound,
                     )
                 }
                 "CodeVerificationFailedException" => {
-                    return RusotoError::Service(
-                        UpdateFunctionConfigurationError::CodeVerificationFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFunctionConfigurationError::CodeVerificationFailed,
                     )
                 }
                 "InvalidCodeSignatureException" => {
-                    return RusotoError::Service(
-                        UpdateFunctionConfigurationError::InvalidCodeSignature(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFunctionConfigurationError::InvalidCodeSignature,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        UpdateFunctionConfigurationError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFunctionConfigurationError::InvalidParameterValue,
                     )
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(
-                        UpdateFunctionConfigurationError::PreconditionFailed(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFunctionConfigurationError::PreconditionFailed,
                     )
                 }
                 "ResourceConflictException" => {
-                    return RusotoError::Service(
-                        UpdateFunctionConfigurationError::ResourceConflict(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFunctionConfigurationError::ResourceConflict,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateFunctionConfigurationError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFunctionConfigurationError::ResourceNotFound,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateFunctionConfigurationError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFunctionConfigurationError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateFunctionConfigurationError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateFunctionConfigurationError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5896,26 +7041,38 @@ impl UpdateFunctionEventInvokeConfigError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        UpdateFunctionEventInvokeConfigError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFunctionEventInvokeConfigError::InvalidParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateFunctionEventInvokeConfigError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFunctionEventInvokeConfigError::ResourceNotFound,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateFunctionEventInvokeConfigError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateFunctionEventInvokeConfigError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        UpdateFunctionEventInvokeConfigError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFunctionEventInvokeConfigError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6315,11 +7472,12 @@ pub struct LambdaClient {
 impl LambdaClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> LambdaClient {
         LambdaClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Lambda"),
         }
     }
 
@@ -6334,12 +7492,32 @@ impl LambdaClient {
     {
         LambdaClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Lambda"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> LambdaClient {
-        LambdaClient { client, region }
+        LambdaClient {
+            client,
+            region: region_for_service(region, "Lambda"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> LambdaClient {
+        LambdaClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Lambda"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> LambdaClient {
+        LambdaClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -6359,6 +7537,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("AddLayerVersionPermission");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6399,6 +7578,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("AddPermission");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6439,6 +7619,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("CreateAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6470,6 +7651,7 @@ impl Lambda for LambdaClient {
         let request_uri = "/2020-04-22/code-signing-configs/";
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("CreateCodeSigningConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6501,6 +7683,7 @@ impl Lambda for LambdaClient {
         let request_uri = "/2015-03-31/event-source-mappings/";
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("CreateEventSourceMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6532,6 +7715,7 @@ impl Lambda for LambdaClient {
         let request_uri = "/2015-03-31/functions";
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("CreateFunction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6567,6 +7751,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("DeleteAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6597,6 +7782,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("DeleteCodeSigningConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6628,6 +7814,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("DeleteEventSourceMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6659,6 +7846,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("DeleteFunction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6695,6 +7883,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("DeleteFunctionCodeSigningConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6727,6 +7916,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("DeleteFunctionConcurrency");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6757,6 +7947,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("DeleteFunctionEventInvokeConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6796,6 +7987,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("DeleteLayerVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6826,6 +8018,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("DeleteProvisionedConcurrencyConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -6858,6 +8051,7 @@ impl Lambda for LambdaClient {
         let request_uri = "/2016-08-19/account-settings/";
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetAccountSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6890,6 +8084,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6921,6 +8116,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetCodeSigningConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6952,6 +8148,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetEventSourceMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -6983,6 +8180,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetFunction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7021,6 +8219,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetFunctionCodeSigningConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7052,6 +8251,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetFunctionConcurrency");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7083,6 +8283,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetFunctionConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7120,6 +8321,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetFunctionEventInvokeConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7158,6 +8360,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetLayerVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7186,6 +8389,7 @@ impl Lambda for LambdaClient {
         let request_uri = "/2018-10-31/layers";
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetLayerVersionByArn");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7223,6 +8427,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetLayerVersionPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7254,6 +8459,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7294,6 +8500,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("GetProvisionedConcurrencyConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7331,6 +8538,8 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("Invoke");
+        request.set_raw_response(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = if let Some(ref payload) = input.payload {
@@ -7382,6 +8591,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("InvokeAsync");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(input.invoke_args.to_owned());
@@ -7417,6 +8627,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListAliases");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7457,6 +8668,7 @@ impl Lambda for LambdaClient {
         let request_uri = "/2020-04-22/code-signing-configs/";
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListCodeSigningConfigs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7494,6 +8706,7 @@ impl Lambda for LambdaClient {
         let request_uri = "/2015-03-31/event-source-mappings/";
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListEventSourceMappings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7543,6 +8756,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListFunctionEventInvokeConfigs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7580,6 +8794,7 @@ impl Lambda for LambdaClient {
         let request_uri = "/2015-03-31/functions/";
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListFunctions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7629,6 +8844,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListFunctionsByCodeSigningConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7671,6 +8887,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListLayerVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7711,6 +8928,7 @@ impl Lambda for LambdaClient {
         let request_uri = "/2018-10-31/layers";
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListLayers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7757,6 +8975,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListProvisionedConcurrencyConfigs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7797,6 +9016,7 @@ impl Lambda for LambdaClient {
         let request_uri = format!("/2017-03-31/tags/{arn}", arn = input.resource);
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -7828,6 +9048,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("GET", "lambda", &self.region, &request_uri);
+        request.set_operation("ListVersionsByFunction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -7868,6 +9089,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("PublishLayerVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7902,6 +9124,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("PublishVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7937,6 +9160,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation("PutFunctionCodeSigningConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7971,6 +9195,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation("PutFunctionConcurrency");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8005,6 +9230,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation("PutFunctionEventInvokeConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8048,6 +9274,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation("PutProvisionedConcurrencyConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8090,6 +9317,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("RemoveLayerVersionPermission");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8127,6 +9355,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("RemovePermission");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8163,6 +9392,7 @@ impl Lambda for LambdaClient {
         let request_uri = format!("/2017-03-31/tags/{arn}", arn = input.resource);
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8193,6 +9423,7 @@ impl Lambda for LambdaClient {
         let request_uri = format!("/2017-03-31/tags/{arn}", arn = input.resource);
 
         let mut request = SignedRequest::new("DELETE", "lambda", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8230,6 +9461,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation("UpdateAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8264,6 +9496,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation("UpdateCodeSigningConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8298,6 +9531,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation("UpdateEventSourceMapping");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8332,6 +9566,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation("UpdateFunctionCode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8366,6 +9601,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("PUT", "lambda", &self.region, &request_uri);
+        request.set_operation("UpdateFunctionConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8400,6 +9636,7 @@ impl Lambda for LambdaClient {
         );
 
         let mut request = SignedRequest::new("POST", "lambda", &self.region, &request_uri);
+        request.set_operation("UpdateFunctionEventInvokeConfig");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8430,3 +9667,596 @@ impl Lambda for LambdaClient {
         }
     }
 }
+
+/// A blocking client for the AWS Lambda API, running the calls of a `LambdaClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `LambdaClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct LambdaBlockingClient {
+    client: LambdaClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl LambdaBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> LambdaBlockingClient {
+        LambdaBlockingClient::from_client(LambdaClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> LambdaBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        LambdaBlockingClient::from_client(LambdaClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> LambdaBlockingClient {
+        LambdaBlockingClient::from_client(LambdaClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> LambdaBlockingClient {
+        LambdaBlockingClient::from_client(LambdaClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: LambdaClient) -> LambdaBlockingClient {
+        LambdaBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> LambdaBlockingClient {
+        LambdaBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> LambdaBlockingClient {
+        LambdaBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Lambda::add_layer_version_permission`].
+    pub fn add_layer_version_permission(
+        &self,
+        input: AddLayerVersionPermissionRequest,
+    ) -> Result<AddLayerVersionPermissionResponse, RusotoError<AddLayerVersionPermissionError>>
+    {
+        self.runtime
+            .block_on(self.client.add_layer_version_permission(input))
+    }
+
+    /// Blocking version of [`Lambda::add_permission`].
+    pub fn add_permission(
+        &self,
+        input: AddPermissionRequest,
+    ) -> Result<AddPermissionResponse, RusotoError<AddPermissionError>> {
+        self.runtime.block_on(self.client.add_permission(input))
+    }
+
+    /// Blocking version of [`Lambda::create_alias`].
+    pub fn create_alias(
+        &self,
+        input: CreateAliasRequest,
+    ) -> Result<AliasConfiguration, RusotoError<CreateAliasError>> {
+        self.runtime.block_on(self.client.create_alias(input))
+    }
+
+    /// Blocking version of [`Lambda::create_code_signing_config`].
+    pub fn create_code_signing_config(
+        &self,
+        input: CreateCodeSigningConfigRequest,
+    ) -> Result<CreateCodeSigningConfigResponse, RusotoError<CreateCodeSigningConfigError>> {
+        self.runtime
+            .block_on(self.client.create_code_signing_config(input))
+    }
+
+    /// Blocking version of [`Lambda::create_event_source_mapping`].
+    pub fn create_event_source_mapping(
+        &self,
+        input: CreateEventSourceMappingRequest,
+    ) -> Result<EventSourceMappingConfiguration, RusotoError<CreateEventSourceMappingError>> {
+        self.runtime
+            .block_on(self.client.create_event_source_mapping(input))
+    }
+
+    /// Blocking version of [`Lambda::create_function`].
+    pub fn create_function(
+        &self,
+        input: CreateFunctionRequest,
+    ) -> Result<FunctionConfiguration, RusotoError<CreateFunctionError>> {
+        self.runtime.block_on(self.client.create_function(input))
+    }
+
+    /// Blocking version of [`Lambda::delete_alias`].
+    pub fn delete_alias(
+        &self,
+        input: DeleteAliasRequest,
+    ) -> Result<(), RusotoError<DeleteAliasError>> {
+        self.runtime.block_on(self.client.delete_alias(input))
+    }
+
+    /// Blocking version of [`Lambda::delete_code_signing_config`].
+    pub fn delete_code_signing_config(
+        &self,
+        input: DeleteCodeSigningConfigRequest,
+    ) -> Result<DeleteCodeSigningConfigResponse, RusotoError<DeleteCodeSigningConfigError>> {
+        self.runtime
+            .block_on(self.client.delete_code_signing_config(input))
+    }
+
+    /// Blocking version of [`Lambda::delete_event_source_mapping`].
+    pub fn delete_event_source_mapping(
+        &self,
+        input: DeleteEventSourceMappingRequest,
+    ) -> Result<EventSourceMappingConfiguration, RusotoError<DeleteEventSourceMappingError>> {
+        self.runtime
+            .block_on(self.client.delete_event_source_mapping(input))
+    }
+
+    /// Blocking version of [`Lambda::delete_function`].
+    pub fn delete_function(
+        &self,
+        input: DeleteFunctionRequest,
+    ) -> Result<(), RusotoError<DeleteFunctionError>> {
+        self.runtime.block_on(self.client.delete_function(input))
+    }
+
+    /// Blocking version of [`Lambda::delete_function_code_signing_config`].
+    pub fn delete_function_code_signing_config(
+        &self,
+        input: DeleteFunctionCodeSigningConfigRequest,
+    ) -> Result<(), RusotoError<DeleteFunctionCodeSigningConfigError>> {
+        self.runtime
+            .block_on(self.client.delete_function_code_signing_config(input))
+    }
+
+    /// Blocking version of [`Lambda::delete_function_concurrency`].
+    pub fn delete_function_concurrency(
+        &self,
+        input: DeleteFunctionConcurrencyRequest,
+    ) -> Result<(), RusotoError<DeleteFunctionConcurrencyError>> {
+        self.runtime
+            .block_on(self.client.delete_function_concurrency(input))
+    }
+
+    /// Blocking version of [`Lambda::delete_function_event_invoke_config`].
+    pub fn delete_function_event_invoke_config(
+        &self,
+        input: DeleteFunctionEventInvokeConfigRequest,
+    ) -> Result<(), RusotoError<DeleteFunctionEventInvokeConfigError>> {
+        self.runtime
+            .block_on(self.client.delete_function_event_invoke_config(input))
+    }
+
+    /// Blocking version of [`Lambda::delete_layer_version`].
+    pub fn delete_layer_version(
+        &self,
+        input: DeleteLayerVersionRequest,
+    ) -> Result<(), RusotoError<DeleteLayerVersionError>> {
+        self.runtime
+            .block_on(self.client.delete_layer_version(input))
+    }
+
+    /// Blocking version of [`Lambda::delete_provisioned_concurrency_config`].
+    pub fn delete_provisioned_concurrency_config(
+        &self,
+        input: DeleteProvisionedConcurrencyConfigRequest,
+    ) -> Result<(), RusotoError<DeleteProvisionedConcurrencyConfigError>> {
+        self.runtime
+            .block_on(self.client.delete_provisioned_concurrency_config(input))
+    }
+
+    /// Blocking version of [`Lambda::get_account_settings`].
+    pub fn get_account_settings(
+        &self,
+    ) -> Result<GetAccountSettingsResponse, RusotoError<GetAccountSettingsError>> {
+        self.runtime.block_on(self.client.get_account_settings())
+    }
+
+    /// Blocking version of [`Lambda::get_alias`].
+    pub fn get_alias(
+        &self,
+        input: GetAliasRequest,
+    ) -> Result<AliasConfiguration, RusotoError<GetAliasError>> {
+        self.runtime.block_on(self.client.get_alias(input))
+    }
+
+    /// Blocking version of [`Lambda::get_code_signing_config`].
+    pub fn get_code_signing_config(
+        &self,
+        input: GetCodeSigningConfigRequest,
+    ) -> Result<GetCodeSigningConfigResponse, RusotoError<GetCodeSigningConfigError>> {
+        self.runtime
+            .block_on(self.client.get_code_signing_config(input))
+    }
+
+    /// Blocking version of [`Lambda::get_event_source_mapping`].
+    pub fn get_event_source_mapping(
+        &self,
+        input: GetEventSourceMappingRequest,
+    ) -> Result<EventSourceMappingConfiguration, RusotoError<GetEventSourceMappingError>> {
+        self.runtime
+            .block_on(self.client.get_event_source_mapping(input))
+    }
+
+    /// Blocking version of [`Lambda::get_function`].
+    pub fn get_function(
+        &self,
+        input: GetFunctionRequest,
+    ) -> Result<GetFunctionResponse, RusotoError<GetFunctionError>> {
+        self.runtime.block_on(self.client.get_function(input))
+    }
+
+    /// Blocking version of [`Lambda::get_function_code_signing_config`].
+    pub fn get_function_code_signing_config(
+        &self,
+        input: GetFunctionCodeSigningConfigRequest,
+    ) -> Result<GetFunctionCodeSigningConfigResponse, RusotoError<GetFunctionCodeSigningConfigError>>
+    {
+        self.runtime
+            .block_on(self.client.get_function_code_signing_config(input))
+    }
+
+    /// Blocking version of [`Lambda::get_function_concurrency`].
+    pub fn get_function_concurrency(
+        &self,
+        input: GetFunctionConcurrencyRequest,
+    ) -> Result<GetFunctionConcurrencyResponse, RusotoError<GetFunctionConcurrencyError>> {
+        self.runtime
+            .block_on(self.client.get_function_concurrency(input))
+    }
+
+    /// Blocking version of [`Lambda::get_function_configuration`].
+    pub fn get_function_configuration(
+        &self,
+        input: GetFunctionConfigurationRequest,
+    ) -> Result<FunctionConfiguration, RusotoError<GetFunctionConfigurationError>> {
+        self.runtime
+            .block_on(self.client.get_function_configuration(input))
+    }
+
+    /// Blocking version of [`Lambda::get_function_event_invoke_config`].
+    pub fn get_function_event_invoke_config(
+        &self,
+        input: GetFunctionEventInvokeConfigRequest,
+    ) -> Result<FunctionEventInvokeConfig, RusotoError<GetFunctionEventInvokeConfigError>> {
+        self.runtime
+            .block_on(self.client.get_function_event_invoke_config(input))
+    }
+
+    /// Blocking version of [`Lambda::get_layer_version`].
+    pub fn get_layer_version(
+        &self,
+        input: GetLayerVersionRequest,
+    ) -> Result<GetLayerVersionResponse, RusotoError<GetLayerVersionError>> {
+        self.runtime.block_on(self.client.get_layer_version(input))
+    }
+
+    /// Blocking version of [`Lambda::get_layer_version_by_arn`].
+    pub fn get_layer_version_by_arn(
+        &self,
+        input: GetLayerVersionByArnRequest,
+    ) -> Result<GetLayerVersionResponse, RusotoError<GetLayerVersionByArnError>> {
+        self.runtime
+            .block_on(self.client.get_layer_version_by_arn(input))
+    }
+
+    /// Blocking version of [`Lambda::get_layer_version_policy`].
+    pub fn get_layer_version_policy(
+        &self,
+        input: GetLayerVersionPolicyRequest,
+    ) -> Result<GetLayerVersionPolicyResponse, RusotoError<GetLayerVersionPolicyError>> {
+        self.runtime
+            .block_on(self.client.get_layer_version_policy(input))
+    }
+
+    /// Blocking version of [`Lambda::get_policy`].
+    pub fn get_policy(
+        &self,
+        input: GetPolicyRequest,
+    ) -> Result<GetPolicyResponse, RusotoError<GetPolicyError>> {
+        self.runtime.block_on(self.client.get_policy(input))
+    }
+
+    /// Blocking version of [`Lambda::get_provisioned_concurrency_config`].
+    pub fn get_provisioned_concurrency_config(
+        &self,
+        input: GetProvisionedConcurrencyConfigRequest,
+    ) -> Result<
+        GetProvisionedConcurrencyConfigResponse,
+        RusotoError<GetProvisionedConcurrencyConfigError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_provisioned_concurrency_config(input))
+    }
+
+    /// Blocking version of [`Lambda::invoke`].
+    pub fn invoke(
+        &self,
+        input: InvocationRequest,
+    ) -> Result<InvocationResponse, RusotoError<InvokeError>> {
+        self.runtime.block_on(self.client.invoke(input))
+    }
+
+    /// Blocking version of [`Lambda::invoke_async`].
+    pub fn invoke_async(
+        &self,
+        input: InvokeAsyncRequest,
+    ) -> Result<InvokeAsyncResponse, RusotoError<InvokeAsyncError>> {
+        self.runtime.block_on(self.client.invoke_async(input))
+    }
+
+    /// Blocking version of [`Lambda::list_aliases`].
+    pub fn list_aliases(
+        &self,
+        input: ListAliasesRequest,
+    ) -> Result<ListAliasesResponse, RusotoError<ListAliasesError>> {
+        self.runtime.block_on(self.client.list_aliases(input))
+    }
+
+    /// Blocking version of [`Lambda::list_code_signing_configs`].
+    pub fn list_code_signing_configs(
+        &self,
+        input: ListCodeSigningConfigsRequest,
+    ) -> Result<ListCodeSigningConfigsResponse, RusotoError<ListCodeSigningConfigsError>> {
+        self.runtime
+            .block_on(self.client.list_code_signing_configs(input))
+    }
+
+    /// Blocking version of [`Lambda::list_event_source_mappings`].
+    pub fn list_event_source_mappings(
+        &self,
+        input: ListEventSourceMappingsRequest,
+    ) -> Result<ListEventSourceMappingsResponse, RusotoError<ListEventSourceMappingsError>> {
+        self.runtime
+            .block_on(self.client.list_event_source_mappings(input))
+    }
+
+    /// Blocking version of [`Lambda::list_function_event_invoke_configs`].
+    pub fn list_function_event_invoke_configs(
+        &self,
+        input: ListFunctionEventInvokeConfigsRequest,
+    ) -> Result<
+        ListFunctionEventInvokeConfigsResponse,
+        RusotoError<ListFunctionEventInvokeConfigsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_function_event_invoke_configs(input))
+    }
+
+    /// Blocking version of [`Lambda::list_functions`].
+    pub fn list_functions(
+        &self,
+        input: ListFunctionsRequest,
+    ) -> Result<ListFunctionsResponse, RusotoError<ListFunctionsError>> {
+        self.runtime.block_on(self.client.list_functions(input))
+    }
+
+    /// Blocking version of [`Lambda::list_functions_by_code_signing_config`].
+    pub fn list_functions_by_code_signing_config(
+        &self,
+        input: ListFunctionsByCodeSigningConfigRequest,
+    ) -> Result<
+        ListFunctionsByCodeSigningConfigResponse,
+        RusotoError<ListFunctionsByCodeSigningConfigError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_functions_by_code_signing_config(input))
+    }
+
+    /// Blocking version of [`Lambda::list_layer_versions`].
+    pub fn list_layer_versions(
+        &self,
+        input: ListLayerVersionsRequest,
+    ) -> Result<ListLayerVersionsResponse, RusotoError<ListLayerVersionsError>> {
+        self.runtime
+            .block_on(self.client.list_layer_versions(input))
+    }
+
+    /// Blocking version of [`Lambda::list_layers`].
+    pub fn list_layers(
+        &self,
+        input: ListLayersRequest,
+    ) -> Result<ListLayersResponse, RusotoError<ListLayersError>> {
+        self.runtime.block_on(self.client.list_layers(input))
+    }
+
+    /// Blocking version of [`Lambda::list_provisioned_concurrency_configs`].
+    pub fn list_provisioned_concurrency_configs(
+        &self,
+        input: ListProvisionedConcurrencyConfigsRequest,
+    ) -> Result<
+        ListProvisionedConcurrencyConfigsResponse,
+        RusotoError<ListProvisionedConcurrencyConfigsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_provisioned_concurrency_configs(input))
+    }
+
+    /// Blocking version of [`Lambda::list_tags`].
+    pub fn list_tags(
+        &self,
+        input: ListTagsRequest,
+    ) -> Result<ListTagsResponse, RusotoError<ListTagsError>> {
+        self.runtime.block_on(self.client.list_tags(input))
+    }
+
+    /// Blocking version of [`Lambda::list_versions_by_function`].
+    pub fn list_versions_by_function(
+        &self,
+        input: ListVersionsByFunctionRequest,
+    ) -> Result<ListVersionsByFunctionResponse, RusotoError<ListVersionsByFunctionError>> {
+        self.runtime
+            .block_on(self.client.list_versions_by_function(input))
+    }
+
+    /// Blocking version of [`Lambda::publish_layer_version`].
+    pub fn publish_layer_version(
+        &self,
+        input: PublishLayerVersionRequest,
+    ) -> Result<PublishLayerVersionResponse, RusotoError<PublishLayerVersionError>> {
+        self.runtime
+            .block_on(self.client.publish_layer_version(input))
+    }
+
+    /// Blocking version of [`Lambda::publish_version`].
+    pub fn publish_version(
+        &self,
+        input: PublishVersionRequest,
+    ) -> Result<FunctionConfiguration, RusotoError<PublishVersionError>> {
+        self.runtime.block_on(self.client.publish_version(input))
+    }
+
+    /// Blocking version of [`Lambda::put_function_code_signing_config`].
+    pub fn put_function_code_signing_config(
+        &self,
+        input: PutFunctionCodeSigningConfigRequest,
+    ) -> Result<PutFunctionCodeSigningConfigResponse, RusotoError<PutFunctionCodeSigningConfigError>>
+    {
+        self.runtime
+            .block_on(self.client.put_function_code_signing_config(input))
+    }
+
+    /// Blocking version of [`Lambda::put_function_concurrency`].
+    pub fn put_function_concurrency(
+        &self,
+        input: PutFunctionConcurrencyRequest,
+    ) -> Result<Concurrency, RusotoError<PutFunctionConcurrencyError>> {
+        self.runtime
+            .block_on(self.client.put_function_concurrency(input))
+    }
+
+    /// Blocking version of [`Lambda::put_function_event_invoke_config`].
+    pub fn put_function_event_invoke_config(
+        &self,
+        input: PutFunctionEventInvokeConfigRequest,
+    ) -> Result<FunctionEventInvokeConfig, RusotoError<PutFunctionEventInvokeConfigError>> {
+        self.runtime
+            .block_on(self.client.put_function_event_invoke_config(input))
+    }
+
+    /// Blocking version of [`Lambda::put_provisioned_concurrency_config`].
+    pub fn put_provisioned_concurrency_config(
+        &self,
+        input: PutProvisionedConcurrencyConfigRequest,
+    ) -> Result<
+        PutProvisionedConcurrencyConfigResponse,
+        RusotoError<PutProvisionedConcurrencyConfigError>,
+    > {
+        self.runtime
+            .block_on(self.client.put_provisioned_concurrency_config(input))
+    }
+
+    /// Blocking version of [`Lambda::remove_layer_version_permission`].
+    pub fn remove_layer_version_permission(
+        &self,
+        input: RemoveLayerVersionPermissionRequest,
+    ) -> Result<(), RusotoError<RemoveLayerVersionPermissionError>> {
+        self.runtime
+            .block_on(self.client.remove_layer_version_permission(input))
+    }
+
+    /// Blocking version of [`Lambda::remove_permission`].
+    pub fn remove_permission(
+        &self,
+        input: RemovePermissionRequest,
+    ) -> Result<(), RusotoError<RemovePermissionError>> {
+        self.runtime.block_on(self.client.remove_permission(input))
+    }
+
+    /// Blocking version of [`Lambda::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`Lambda::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`Lambda::update_alias`].
+    pub fn update_alias(
+        &self,
+        input: UpdateAliasRequest,
+    ) -> Result<AliasConfiguration, RusotoError<UpdateAliasError>> {
+        self.runtime.block_on(self.client.update_alias(input))
+    }
+
+    /// Blocking version of [`Lambda::update_code_signing_config`].
+    pub fn update_code_signing_config(
+        &self,
+        input: UpdateCodeSigningConfigRequest,
+    ) -> Result<UpdateCodeSigningConfigResponse, RusotoError<UpdateCodeSigningConfigError>> {
+        self.runtime
+            .block_on(self.client.update_code_signing_config(input))
+    }
+
+    /// Blocking version of [`Lambda::update_event_source_mapping`].
+    pub fn update_event_source_mapping(
+        &self,
+        input: UpdateEventSourceMappingRequest,
+    ) -> Result<EventSourceMappingConfiguration, RusotoError<UpdateEventSourceMappingError>> {
+        self.runtime
+            .block_on(self.client.update_event_source_mapping(input))
+    }
+
+    /// Blocking version of [`Lambda::update_function_code`].
+    pub fn update_function_code(
+        &self,
+        input: UpdateFunctionCodeRequest,
+    ) -> Result<FunctionConfiguration, RusotoError<UpdateFunctionCodeError>> {
+        self.runtime
+            .block_on(self.client.update_function_code(input))
+    }
+
+    /// Blocking version of [`Lambda::update_function_configuration`].
+    pub fn update_function_configuration(
+        &self,
+        input: UpdateFunctionConfigurationRequest,
+    ) -> Result<FunctionConfiguration, RusotoError<UpdateFunctionConfigurationError>> {
+        self.runtime
+            .block_on(self.client.update_function_configuration(input))
+    }
+
+    /// Blocking version of [`Lambda::update_function_event_invoke_config`].
+    pub fn update_function_event_invoke_config(
+        &self,
+        input: UpdateFunctionEventInvokeConfigRequest,
+    ) -> Result<FunctionEventInvokeConfig, RusotoError<UpdateFunctionEventInvokeConfigError>> {
+        self.runtime
+            .block_on(self.client.update_function_event_invoke_config(input))
+    }
+}
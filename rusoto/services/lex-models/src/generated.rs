@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -2269,24 +2270,54 @@ impl CreateBotVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateBotVersionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBotVersionError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateBotVersionError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBotVersionError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateBotVersionError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBotVersionError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateBotVersionError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBotVersionError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateBotVersionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBotVersionError::NotFound,
+                    )
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(CreateBotVersionError::PreconditionFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBotVersionError::PreconditionFailed,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2329,26 +2360,54 @@ impl CreateIntentVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateIntentVersionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIntentVersionError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateIntentVersionError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIntentVersionError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateIntentVersionError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIntentVersionError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateIntentVersionError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIntentVersionError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateIntentVersionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIntentVersionError::NotFound,
+                    )
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(CreateIntentVersionError::PreconditionFailed(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateIntentVersionError::PreconditionFailed,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2391,28 +2450,54 @@ impl CreateSlotTypeVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateSlotTypeVersionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSlotTypeVersionError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateSlotTypeVersionError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSlotTypeVersionError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateSlotTypeVersionError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSlotTypeVersionError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateSlotTypeVersionError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSlotTypeVersionError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateSlotTypeVersionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSlotTypeVersionError::NotFound,
+                    )
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(CreateSlotTypeVersionError::PreconditionFailed(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSlotTypeVersionError::PreconditionFailed,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2455,24 +2540,54 @@ impl DeleteBotError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteBotError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBotError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteBotError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBotError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteBotError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBotError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteBotError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBotError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteBotError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBotError::NotFound,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteBotError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBotError::ResourceInUse,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2515,24 +2630,54 @@ impl DeleteBotAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteBotAliasError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBotAliasError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteBotAliasError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBotAliasError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteBotAliasError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBotAliasError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteBotAliasError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBotAliasError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteBotAliasError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBotAliasError::NotFound,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteBotAliasError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBotAliasError::ResourceInUse,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2575,31 +2720,46 @@ impl DeleteBotChannelAssociationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteBotChannelAssociationError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteBotChannelAssociationError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteBotChannelAssociationError::Conflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteBotChannelAssociationError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteBotChannelAssociationError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteBotChannelAssociationError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteBotChannelAssociationError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteBotChannelAssociationError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteBotChannelAssociationError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteBotChannelAssociationError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2641,24 +2801,54 @@ impl DeleteBotVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteBotVersionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBotVersionError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteBotVersionError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBotVersionError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteBotVersionError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBotVersionError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteBotVersionError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBotVersionError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteBotVersionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBotVersionError::NotFound,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteBotVersionError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBotVersionError::ResourceInUse,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2701,24 +2891,54 @@ impl DeleteIntentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteIntentError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIntentError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteIntentError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIntentError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteIntentError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIntentError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteIntentError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIntentError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteIntentError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIntentError::NotFound,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteIntentError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIntentError::ResourceInUse,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2761,24 +2981,54 @@ impl DeleteIntentVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteIntentVersionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIntentVersionError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteIntentVersionError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIntentVersionError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteIntentVersionError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIntentVersionError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteIntentVersionError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIntentVersionError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteIntentVersionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIntentVersionError::NotFound,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteIntentVersionError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIntentVersionError::ResourceInUse,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2821,24 +3071,54 @@ impl DeleteSlotTypeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteSlotTypeError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSlotTypeError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteSlotTypeError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSlotTypeError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteSlotTypeError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSlotTypeError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteSlotTypeError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSlotTypeError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteSlotTypeError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSlotTypeError::NotFound,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteSlotTypeError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSlotTypeError::ResourceInUse,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2881,26 +3161,54 @@ impl DeleteSlotTypeVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteSlotTypeVersionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSlotTypeVersionError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteSlotTypeVersionError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSlotTypeVersionError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteSlotTypeVersionError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSlotTypeVersionError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteSlotTypeVersionError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSlotTypeVersionError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteSlotTypeVersionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSlotTypeVersionError::NotFound,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteSlotTypeVersionError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSlotTypeVersionError::ResourceInUse,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2939,18 +3247,38 @@ impl DeleteUtterancesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteUtterancesError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUtterancesError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteUtterancesError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUtterancesError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteUtterancesError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUtterancesError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteUtterancesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUtterancesError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2987,16 +3315,38 @@ impl GetBotError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBotError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBotError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetBotError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBotError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetBotError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBotError::LimitExceeded,
+                    )
                 }
-                "NotFoundException" => return RusotoError::Service(GetBotError::NotFound(err.msg)),
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "NotFoundException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBotError::NotFound,
+                    )
+                }
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3033,18 +3383,38 @@ impl GetBotAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBotAliasError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBotAliasError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetBotAliasError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBotAliasError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetBotAliasError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBotAliasError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetBotAliasError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBotAliasError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3079,15 +3449,30 @@ impl GetBotAliasesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBotAliasesError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBotAliasesError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetBotAliasesError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBotAliasesError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetBotAliasesError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBotAliasesError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3123,22 +3508,38 @@ impl GetBotChannelAssociationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBotChannelAssociationError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBotChannelAssociationError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetBotChannelAssociationError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetBotChannelAssociationError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetBotChannelAssociationError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetBotChannelAssociationError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetBotChannelAssociationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBotChannelAssociationError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3173,21 +3574,30 @@ impl GetBotChannelAssociationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBotChannelAssociationsError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetBotChannelAssociationsError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetBotChannelAssociationsError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetBotChannelAssociationsError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetBotChannelAssociationsError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetBotChannelAssociationsError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3223,18 +3633,38 @@ impl GetBotVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBotVersionsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBotVersionsError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetBotVersionsError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBotVersionsError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetBotVersionsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBotVersionsError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetBotVersionsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBotVersionsError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3271,18 +3701,38 @@ impl GetBotsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBotsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBotsError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetBotsError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBotsError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetBotsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBotsError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetBotsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBotsError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3319,18 +3769,38 @@ impl GetBuiltinIntentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBuiltinIntentError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBuiltinIntentError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetBuiltinIntentError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBuiltinIntentError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetBuiltinIntentError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBuiltinIntentError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetBuiltinIntentError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBuiltinIntentError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3365,15 +3835,30 @@ impl GetBuiltinIntentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBuiltinIntentsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBuiltinIntentsError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetBuiltinIntentsError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBuiltinIntentsError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetBuiltinIntentsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBuiltinIntentsError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3407,15 +3892,30 @@ impl GetBuiltinSlotTypesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBuiltinSlotTypesError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBuiltinSlotTypesError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetBuiltinSlotTypesError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBuiltinSlotTypesError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetBuiltinSlotTypesError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBuiltinSlotTypesError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3451,18 +3951,38 @@ impl GetExportError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetExportError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetExportError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetExportError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetExportError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetExportError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetExportError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetExportError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetExportError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3499,18 +4019,38 @@ impl GetImportError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetImportError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetImportError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetImportError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetImportError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetImportError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetImportError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetImportError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetImportError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3547,18 +4087,38 @@ impl GetIntentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetIntentError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIntentError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetIntentError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIntentError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetIntentError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIntentError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetIntentError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIntentError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3595,18 +4155,38 @@ impl GetIntentVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetIntentVersionsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIntentVersionsError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetIntentVersionsError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIntentVersionsError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetIntentVersionsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIntentVersionsError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetIntentVersionsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIntentVersionsError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3643,18 +4223,38 @@ impl GetIntentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetIntentsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIntentsError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetIntentsError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIntentsError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetIntentsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIntentsError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetIntentsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetIntentsError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3691,18 +4291,38 @@ impl GetSlotTypeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetSlotTypeError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSlotTypeError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetSlotTypeError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSlotTypeError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetSlotTypeError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSlotTypeError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetSlotTypeError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSlotTypeError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3739,18 +4359,38 @@ impl GetSlotTypeVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetSlotTypeVersionsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSlotTypeVersionsError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetSlotTypeVersionsError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSlotTypeVersionsError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetSlotTypeVersionsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSlotTypeVersionsError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetSlotTypeVersionsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSlotTypeVersionsError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3787,18 +4427,38 @@ impl GetSlotTypesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetSlotTypesError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSlotTypesError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetSlotTypesError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSlotTypesError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetSlotTypesError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSlotTypesError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetSlotTypesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSlotTypesError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3833,15 +4493,30 @@ impl GetUtterancesViewError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetUtterancesViewError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUtterancesViewError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetUtterancesViewError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUtterancesViewError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetUtterancesViewError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetUtterancesViewError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3877,18 +4552,38 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(ListTagsForResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3927,19 +4622,46 @@ impl PutBotError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutBotError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutBotError::BadRequest,
+                    )
+                }
+                "ConflictException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutBotError::Conflict,
+                    )
                 }
-                "ConflictException" => return RusotoError::Service(PutBotError::Conflict(err.msg)),
                 "InternalFailureException" => {
-                    return RusotoError::Service(PutBotError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutBotError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutBotError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutBotError::LimitExceeded,
+                    )
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(PutBotError::PreconditionFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutBotError::PreconditionFailed,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3979,21 +4701,46 @@ impl PutBotAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutBotAliasError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutBotAliasError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(PutBotAliasError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutBotAliasError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(PutBotAliasError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutBotAliasError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutBotAliasError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutBotAliasError::LimitExceeded,
+                    )
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(PutBotAliasError::PreconditionFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutBotAliasError::PreconditionFailed,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4033,21 +4780,46 @@ impl PutIntentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutIntentError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutIntentError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(PutIntentError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutIntentError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(PutIntentError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutIntentError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutIntentError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutIntentError::LimitExceeded,
+                    )
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(PutIntentError::PreconditionFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutIntentError::PreconditionFailed,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4087,21 +4859,46 @@ impl PutSlotTypeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutSlotTypeError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSlotTypeError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(PutSlotTypeError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSlotTypeError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(PutSlotTypeError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSlotTypeError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutSlotTypeError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSlotTypeError::LimitExceeded,
+                    )
                 }
                 "PreconditionFailedException" => {
-                    return RusotoError::Service(PutSlotTypeError::PreconditionFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSlotTypeError::PreconditionFailed,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4137,15 +4934,30 @@ impl StartImportError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StartImportError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartImportError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(StartImportError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartImportError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartImportError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartImportError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4183,21 +4995,46 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(TagResourceError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(TagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4237,21 +5074,46 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UntagResourceError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UntagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UntagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4515,11 +5377,12 @@ pub struct LexModelsClient {
 impl LexModelsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> LexModelsClient {
         LexModelsClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Lex Model Building Service"),
         }
     }
 
@@ -4534,12 +5397,32 @@ impl LexModelsClient {
     {
         LexModelsClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Lex Model Building Service"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> LexModelsClient {
-        LexModelsClient { client, region }
+        LexModelsClient {
+            client,
+            region: region_for_service(region, "Lex Model Building Service"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> LexModelsClient {
+        LexModelsClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Lex Model Building Service"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> LexModelsClient {
+        LexModelsClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -4554,6 +5437,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/bots/{name}/versions", name = input.name);
 
         let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation("CreateBotVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4586,6 +5470,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/intents/{name}/versions", name = input.name);
 
         let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation("CreateIntentVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4618,6 +5503,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/slottypes/{name}/versions", name = input.name);
 
         let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation("CreateSlotTypeVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4647,6 +5533,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/bots/{name}", name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteBot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4680,6 +5567,7 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteBotAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4714,6 +5602,7 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteBotChannelAssociation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4747,6 +5636,7 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteBotVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4776,6 +5666,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/intents/{name}", name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteIntent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4809,6 +5700,7 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteIntentVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4838,6 +5730,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/slottypes/{name}", name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteSlotType");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4871,6 +5764,7 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteSlotTypeVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4904,6 +5798,7 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteUtterances");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4937,6 +5832,7 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -4971,6 +5867,7 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBotAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5001,6 +5898,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/bots/{bot_name}/aliases/", bot_name = input.bot_name);
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBotAliases");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5048,6 +5946,7 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBotChannelAssociation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5083,6 +5982,7 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBotChannelAssociations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5125,6 +6025,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/bots/{name}/versions/", name = input.name);
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBotVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5164,6 +6065,7 @@ impl LexModels for LexModelsClient {
         let request_uri = "/bots/";
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBots");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5206,6 +6108,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/builtins/intents/{signature}", signature = input.signature);
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBuiltinIntent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5236,6 +6139,7 @@ impl LexModels for LexModelsClient {
         let request_uri = "/builtins/intents/";
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBuiltinIntents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5281,6 +6185,7 @@ impl LexModels for LexModelsClient {
         let request_uri = "/builtins/slottypes/";
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetBuiltinSlotTypes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5326,6 +6231,7 @@ impl LexModels for LexModelsClient {
         let request_uri = "/exports/";
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetExport");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5363,6 +6269,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/imports/{import_id}", import_id = input.import_id);
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetImport");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5397,6 +6304,7 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetIntent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5427,6 +6335,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/intents/{name}/versions/", name = input.name);
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetIntentVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5466,6 +6375,7 @@ impl LexModels for LexModelsClient {
         let request_uri = "/intents/";
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetIntents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5512,6 +6422,7 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetSlotType");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5542,6 +6453,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/slottypes/{name}/versions/", name = input.name);
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetSlotTypeVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5581,6 +6493,7 @@ impl LexModels for LexModelsClient {
         let request_uri = "/slottypes/";
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetSlotTypes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5623,6 +6536,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/bots/{botname}/utterances", botname = input.bot_name);
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetUtterancesView");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5661,6 +6575,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5691,6 +6606,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/bots/{name}/versions/$LATEST", name = input.name);
 
         let mut request = SignedRequest::new("PUT", "lex", &self.region, &request_uri);
+        request.set_operation("PutBot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5727,6 +6643,7 @@ impl LexModels for LexModelsClient {
         );
 
         let mut request = SignedRequest::new("PUT", "lex", &self.region, &request_uri);
+        request.set_operation("PutBotAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5759,6 +6676,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/intents/{name}/versions/$LATEST", name = input.name);
 
         let mut request = SignedRequest::new("PUT", "lex", &self.region, &request_uri);
+        request.set_operation("PutIntent");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5791,6 +6709,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/slottypes/{name}/versions/$LATEST", name = input.name);
 
         let mut request = SignedRequest::new("PUT", "lex", &self.region, &request_uri);
+        request.set_operation("PutSlotType");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5823,6 +6742,7 @@ impl LexModels for LexModelsClient {
         let request_uri = "/imports/";
 
         let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation("StartImport");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5855,6 +6775,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5887,6 +6808,7 @@ impl LexModels for LexModelsClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("models.lex".to_string());
@@ -5914,3 +6836,401 @@ impl LexModels for LexModelsClient {
         }
     }
 }
+
+/// A blocking client for the Amazon Lex Model Building Service API, running the calls of a `LexModelsClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `LexModelsClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct LexModelsBlockingClient {
+    client: LexModelsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl LexModelsBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> LexModelsBlockingClient {
+        LexModelsBlockingClient::from_client(LexModelsClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> LexModelsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        LexModelsBlockingClient::from_client(LexModelsClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> LexModelsBlockingClient {
+        LexModelsBlockingClient::from_client(LexModelsClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> LexModelsBlockingClient {
+        LexModelsBlockingClient::from_client(LexModelsClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: LexModelsClient) -> LexModelsBlockingClient {
+        LexModelsBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> LexModelsBlockingClient {
+        LexModelsBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> LexModelsBlockingClient {
+        LexModelsBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`LexModels::create_bot_version`].
+    pub fn create_bot_version(
+        &self,
+        input: CreateBotVersionRequest,
+    ) -> Result<CreateBotVersionResponse, RusotoError<CreateBotVersionError>> {
+        self.runtime.block_on(self.client.create_bot_version(input))
+    }
+
+    /// Blocking version of [`LexModels::create_intent_version`].
+    pub fn create_intent_version(
+        &self,
+        input: CreateIntentVersionRequest,
+    ) -> Result<CreateIntentVersionResponse, RusotoError<CreateIntentVersionError>> {
+        self.runtime
+            .block_on(self.client.create_intent_version(input))
+    }
+
+    /// Blocking version of [`LexModels::create_slot_type_version`].
+    pub fn create_slot_type_version(
+        &self,
+        input: CreateSlotTypeVersionRequest,
+    ) -> Result<CreateSlotTypeVersionResponse, RusotoError<CreateSlotTypeVersionError>> {
+        self.runtime
+            .block_on(self.client.create_slot_type_version(input))
+    }
+
+    /// Blocking version of [`LexModels::delete_bot`].
+    pub fn delete_bot(&self, input: DeleteBotRequest) -> Result<(), RusotoError<DeleteBotError>> {
+        self.runtime.block_on(self.client.delete_bot(input))
+    }
+
+    /// Blocking version of [`LexModels::delete_bot_alias`].
+    pub fn delete_bot_alias(
+        &self,
+        input: DeleteBotAliasRequest,
+    ) -> Result<(), RusotoError<DeleteBotAliasError>> {
+        self.runtime.block_on(self.client.delete_bot_alias(input))
+    }
+
+    /// Blocking version of [`LexModels::delete_bot_channel_association`].
+    pub fn delete_bot_channel_association(
+        &self,
+        input: DeleteBotChannelAssociationRequest,
+    ) -> Result<(), RusotoError<DeleteBotChannelAssociationError>> {
+        self.runtime
+            .block_on(self.client.delete_bot_channel_association(input))
+    }
+
+    /// Blocking version of [`LexModels::delete_bot_version`].
+    pub fn delete_bot_version(
+        &self,
+        input: DeleteBotVersionRequest,
+    ) -> Result<(), RusotoError<DeleteBotVersionError>> {
+        self.runtime.block_on(self.client.delete_bot_version(input))
+    }
+
+    /// Blocking version of [`LexModels::delete_intent`].
+    pub fn delete_intent(
+        &self,
+        input: DeleteIntentRequest,
+    ) -> Result<(), RusotoError<DeleteIntentError>> {
+        self.runtime.block_on(self.client.delete_intent(input))
+    }
+
+    /// Blocking version of [`LexModels::delete_intent_version`].
+    pub fn delete_intent_version(
+        &self,
+        input: DeleteIntentVersionRequest,
+    ) -> Result<(), RusotoError<DeleteIntentVersionError>> {
+        self.runtime
+            .block_on(self.client.delete_intent_version(input))
+    }
+
+    /// Blocking version of [`LexModels::delete_slot_type`].
+    pub fn delete_slot_type(
+        &self,
+        input: DeleteSlotTypeRequest,
+    ) -> Result<(), RusotoError<DeleteSlotTypeError>> {
+        self.runtime.block_on(self.client.delete_slot_type(input))
+    }
+
+    /// Blocking version of [`LexModels::delete_slot_type_version`].
+    pub fn delete_slot_type_version(
+        &self,
+        input: DeleteSlotTypeVersionRequest,
+    ) -> Result<(), RusotoError<DeleteSlotTypeVersionError>> {
+        self.runtime
+            .block_on(self.client.delete_slot_type_version(input))
+    }
+
+    /// Blocking version of [`LexModels::delete_utterances`].
+    pub fn delete_utterances(
+        &self,
+        input: DeleteUtterancesRequest,
+    ) -> Result<(), RusotoError<DeleteUtterancesError>> {
+        self.runtime.block_on(self.client.delete_utterances(input))
+    }
+
+    /// Blocking version of [`LexModels::get_bot`].
+    pub fn get_bot(
+        &self,
+        input: GetBotRequest,
+    ) -> Result<GetBotResponse, RusotoError<GetBotError>> {
+        self.runtime.block_on(self.client.get_bot(input))
+    }
+
+    /// Blocking version of [`LexModels::get_bot_alias`].
+    pub fn get_bot_alias(
+        &self,
+        input: GetBotAliasRequest,
+    ) -> Result<GetBotAliasResponse, RusotoError<GetBotAliasError>> {
+        self.runtime.block_on(self.client.get_bot_alias(input))
+    }
+
+    /// Blocking version of [`LexModels::get_bot_aliases`].
+    pub fn get_bot_aliases(
+        &self,
+        input: GetBotAliasesRequest,
+    ) -> Result<GetBotAliasesResponse, RusotoError<GetBotAliasesError>> {
+        self.runtime.block_on(self.client.get_bot_aliases(input))
+    }
+
+    /// Blocking version of [`LexModels::get_bot_channel_association`].
+    pub fn get_bot_channel_association(
+        &self,
+        input: GetBotChannelAssociationRequest,
+    ) -> Result<GetBotChannelAssociationResponse, RusotoError<GetBotChannelAssociationError>> {
+        self.runtime
+            .block_on(self.client.get_bot_channel_association(input))
+    }
+
+    /// Blocking version of [`LexModels::get_bot_channel_associations`].
+    pub fn get_bot_channel_associations(
+        &self,
+        input: GetBotChannelAssociationsRequest,
+    ) -> Result<GetBotChannelAssociationsResponse, RusotoError<GetBotChannelAssociationsError>>
+    {
+        self.runtime
+            .block_on(self.client.get_bot_channel_associations(input))
+    }
+
+    /// Blocking version of [`LexModels::get_bot_versions`].
+    pub fn get_bot_versions(
+        &self,
+        input: GetBotVersionsRequest,
+    ) -> Result<GetBotVersionsResponse, RusotoError<GetBotVersionsError>> {
+        self.runtime.block_on(self.client.get_bot_versions(input))
+    }
+
+    /// Blocking version of [`LexModels::get_bots`].
+    pub fn get_bots(
+        &self,
+        input: GetBotsRequest,
+    ) -> Result<GetBotsResponse, RusotoError<GetBotsError>> {
+        self.runtime.block_on(self.client.get_bots(input))
+    }
+
+    /// Blocking version of [`LexModels::get_builtin_intent`].
+    pub fn get_builtin_intent(
+        &self,
+        input: GetBuiltinIntentRequest,
+    ) -> Result<GetBuiltinIntentResponse, RusotoError<GetBuiltinIntentError>> {
+        self.runtime.block_on(self.client.get_builtin_intent(input))
+    }
+
+    /// Blocking version of [`LexModels::get_builtin_intents`].
+    pub fn get_builtin_intents(
+        &self,
+        input: GetBuiltinIntentsRequest,
+    ) -> Result<GetBuiltinIntentsResponse, RusotoError<GetBuiltinIntentsError>> {
+        self.runtime
+            .block_on(self.client.get_builtin_intents(input))
+    }
+
+    /// Blocking version of [`LexModels::get_builtin_slot_types`].
+    pub fn get_builtin_slot_types(
+        &self,
+        input: GetBuiltinSlotTypesRequest,
+    ) -> Result<GetBuiltinSlotTypesResponse, RusotoError<GetBuiltinSlotTypesError>> {
+        self.runtime
+            .block_on(self.client.get_builtin_slot_types(input))
+    }
+
+    /// Blocking version of [`LexModels::get_export`].
+    pub fn get_export(
+        &self,
+        input: GetExportRequest,
+    ) -> Result<GetExportResponse, RusotoError<GetExportError>> {
+        self.runtime.block_on(self.client.get_export(input))
+    }
+
+    /// Blocking version of [`LexModels::get_import`].
+    pub fn get_import(
+        &self,
+        input: GetImportRequest,
+    ) -> Result<GetImportResponse, RusotoError<GetImportError>> {
+        self.runtime.block_on(self.client.get_import(input))
+    }
+
+    /// Blocking version of [`LexModels::get_intent`].
+    pub fn get_intent(
+        &self,
+        input: GetIntentRequest,
+    ) -> Result<GetIntentResponse, RusotoError<GetIntentError>> {
+        self.runtime.block_on(self.client.get_intent(input))
+    }
+
+    /// Blocking version of [`LexModels::get_intent_versions`].
+    pub fn get_intent_versions(
+        &self,
+        input: GetIntentVersionsRequest,
+    ) -> Result<GetIntentVersionsResponse, RusotoError<GetIntentVersionsError>> {
+        self.runtime
+            .block_on(self.client.get_intent_versions(input))
+    }
+
+    /// Blocking version of [`LexModels::get_intents`].
+    pub fn get_intents(
+        &self,
+        input: GetIntentsRequest,
+    ) -> Result<GetIntentsResponse, RusotoError<GetIntentsError>> {
+        self.runtime.block_on(self.client.get_intents(input))
+    }
+
+    /// Blocking version of [`LexModels::get_slot_type`].
+    pub fn get_slot_type(
+        &self,
+        input: GetSlotTypeRequest,
+    ) -> Result<GetSlotTypeResponse, RusotoError<GetSlotTypeError>> {
+        self.runtime.block_on(self.client.get_slot_type(input))
+    }
+
+    /// Blocking version of [`LexModels::get_slot_type_versions`].
+    pub fn get_slot_type_versions(
+        &self,
+        input: GetSlotTypeVersionsRequest,
+    ) -> Result<GetSlotTypeVersionsResponse, RusotoError<GetSlotTypeVersionsError>> {
+        self.runtime
+            .block_on(self.client.get_slot_type_versions(input))
+    }
+
+    /// Blocking version of [`LexModels::get_slot_types`].
+    pub fn get_slot_types(
+        &self,
+        input: GetSlotTypesRequest,
+    ) -> Result<GetSlotTypesResponse, RusotoError<GetSlotTypesError>> {
+        self.runtime.block_on(self.client.get_slot_types(input))
+    }
+
+    /// Blocking version of [`LexModels::get_utterances_view`].
+    pub fn get_utterances_view(
+        &self,
+        input: GetUtterancesViewRequest,
+    ) -> Result<GetUtterancesViewResponse, RusotoError<GetUtterancesViewError>> {
+        self.runtime
+            .block_on(self.client.get_utterances_view(input))
+    }
+
+    /// Blocking version of [`LexModels::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`LexModels::put_bot`].
+    pub fn put_bot(
+        &self,
+        input: PutBotRequest,
+    ) -> Result<PutBotResponse, RusotoError<PutBotError>> {
+        self.runtime.block_on(self.client.put_bot(input))
+    }
+
+    /// Blocking version of [`LexModels::put_bot_alias`].
+    pub fn put_bot_alias(
+        &self,
+        input: PutBotAliasRequest,
+    ) -> Result<PutBotAliasResponse, RusotoError<PutBotAliasError>> {
+        self.runtime.block_on(self.client.put_bot_alias(input))
+    }
+
+    /// Blocking version of [`LexModels::put_intent`].
+    pub fn put_intent(
+        &self,
+        input: PutIntentRequest,
+    ) -> Result<PutIntentResponse, RusotoError<PutIntentError>> {
+        self.runtime.block_on(self.client.put_intent(input))
+    }
+
+    /// Blocking version of [`LexModels::put_slot_type`].
+    pub fn put_slot_type(
+        &self,
+        input: PutSlotTypeRequest,
+    ) -> Result<PutSlotTypeResponse, RusotoError<PutSlotTypeError>> {
+        self.runtime.block_on(self.client.put_slot_type(input))
+    }
+
+    /// Blocking version of [`LexModels::start_import`].
+    pub fn start_import(
+        &self,
+        input: StartImportRequest,
+    ) -> Result<StartImportResponse, RusotoError<StartImportError>> {
+        self.runtime.block_on(self.client.start_import(input))
+    }
+
+    /// Blocking version of [`LexModels::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`LexModels::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+}
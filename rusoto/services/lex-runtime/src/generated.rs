@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -539,21 +540,46 @@ impl DeleteSessionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteSessionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSessionError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteSessionError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSessionError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteSessionError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSessionError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteSessionError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSessionError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteSessionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSessionError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -591,18 +617,38 @@ impl GetSessionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetSessionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSessionError::BadRequest,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetSessionError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSessionError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetSessionError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSessionError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetSessionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSessionError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -653,39 +699,94 @@ impl PostContentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(PostContentError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostContentError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(PostContentError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostContentError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(PostContentError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostContentError::Conflict,
+                    )
                 }
                 "DependencyFailedException" => {
-                    return RusotoError::Service(PostContentError::DependencyFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostContentError::DependencyFailed,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(PostContentError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostContentError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PostContentError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostContentError::LimitExceeded,
+                    )
                 }
                 "LoopDetectedException" => {
-                    return RusotoError::Service(PostContentError::LoopDetected(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostContentError::LoopDetected,
+                    )
                 }
                 "NotAcceptableException" => {
-                    return RusotoError::Service(PostContentError::NotAcceptable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostContentError::NotAcceptable,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PostContentError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostContentError::NotFound,
+                    )
                 }
                 "RequestTimeoutException" => {
-                    return RusotoError::Service(PostContentError::RequestTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostContentError::RequestTimeout,
+                    )
                 }
                 "UnsupportedMediaTypeException" => {
-                    return RusotoError::Service(PostContentError::UnsupportedMediaType(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostContentError::UnsupportedMediaType,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -737,30 +838,70 @@ impl PostTextError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(PostTextError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostTextError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(PostTextError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostTextError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(PostTextError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostTextError::Conflict,
+                    )
                 }
                 "DependencyFailedException" => {
-                    return RusotoError::Service(PostTextError::DependencyFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostTextError::DependencyFailed,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(PostTextError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostTextError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PostTextError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostTextError::LimitExceeded,
+                    )
                 }
                 "LoopDetectedException" => {
-                    return RusotoError::Service(PostTextError::LoopDetected(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostTextError::LoopDetected,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PostTextError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PostTextError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -809,30 +950,70 @@ impl PutSessionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(PutSessionError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSessionError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(PutSessionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSessionError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(PutSessionError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSessionError::Conflict,
+                    )
                 }
                 "DependencyFailedException" => {
-                    return RusotoError::Service(PutSessionError::DependencyFailed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSessionError::DependencyFailed,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(PutSessionError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSessionError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutSessionError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSessionError::LimitExceeded,
+                    )
                 }
                 "NotAcceptableException" => {
-                    return RusotoError::Service(PutSessionError::NotAcceptable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSessionError::NotAcceptable,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutSessionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSessionError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -898,11 +1079,12 @@ pub struct LexRuntimeClient {
 impl LexRuntimeClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> LexRuntimeClient {
         LexRuntimeClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Lex Runtime Service"),
         }
     }
 
@@ -917,12 +1099,32 @@ impl LexRuntimeClient {
     {
         LexRuntimeClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Lex Runtime Service"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> LexRuntimeClient {
-        LexRuntimeClient { client, region }
+        LexRuntimeClient {
+            client,
+            region: region_for_service(region, "Lex Runtime Service"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> LexRuntimeClient {
+        LexRuntimeClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Lex Runtime Service"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> LexRuntimeClient {
+        LexRuntimeClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -942,6 +1144,7 @@ impl LexRuntime for LexRuntimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "lex", &self.region, &request_uri);
+        request.set_operation("DeleteSession");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("runtime.lex".to_string());
@@ -977,6 +1180,7 @@ impl LexRuntime for LexRuntimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "lex", &self.region, &request_uri);
+        request.set_operation("GetSession");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("runtime.lex".to_string());
@@ -1018,6 +1222,8 @@ impl LexRuntime for LexRuntimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation("PostContent");
+        request.set_raw_response(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("runtime.lex".to_string());
@@ -1084,6 +1290,7 @@ impl LexRuntime for LexRuntimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation("PostText");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("runtime.lex".to_string());
@@ -1121,6 +1328,8 @@ impl LexRuntime for LexRuntimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "lex", &self.region, &request_uri);
+        request.set_operation("PutSession");
+        request.set_raw_response(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("runtime.lex".to_string());
@@ -1157,3 +1366,118 @@ impl LexRuntime for LexRuntimeClient {
         }
     }
 }
+
+/// A blocking client for the Amazon Lex Runtime Service API, running the calls of a `LexRuntimeClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `LexRuntimeClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct LexRuntimeBlockingClient {
+    client: LexRuntimeClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl LexRuntimeBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> LexRuntimeBlockingClient {
+        LexRuntimeBlockingClient::from_client(LexRuntimeClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> LexRuntimeBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        LexRuntimeBlockingClient::from_client(LexRuntimeClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> LexRuntimeBlockingClient {
+        LexRuntimeBlockingClient::from_client(LexRuntimeClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> LexRuntimeBlockingClient {
+        LexRuntimeBlockingClient::from_client(LexRuntimeClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: LexRuntimeClient) -> LexRuntimeBlockingClient {
+        LexRuntimeBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> LexRuntimeBlockingClient {
+        LexRuntimeBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> LexRuntimeBlockingClient {
+        LexRuntimeBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`LexRuntime::delete_session`].
+    pub fn delete_session(
+        &self,
+        input: DeleteSessionRequest,
+    ) -> Result<DeleteSessionResponse, RusotoError<DeleteSessionError>> {
+        self.runtime.block_on(self.client.delete_session(input))
+    }
+
+    /// Blocking version of [`LexRuntime::get_session`].
+    pub fn get_session(
+        &self,
+        input: GetSessionRequest,
+    ) -> Result<GetSessionResponse, RusotoError<GetSessionError>> {
+        self.runtime.block_on(self.client.get_session(input))
+    }
+
+    /// Blocking version of [`LexRuntime::post_content`].
+    pub fn post_content(
+        &self,
+        input: PostContentRequest,
+    ) -> Result<PostContentResponse, RusotoError<PostContentError>> {
+        self.runtime.block_on(self.client.post_content(input))
+    }
+
+    /// Blocking version of [`LexRuntime::post_text`].
+    pub fn post_text(
+        &self,
+        input: PostTextRequest,
+    ) -> Result<PostTextResponse, RusotoError<PostTextError>> {
+        self.runtime.block_on(self.client.post_text(input))
+    }
+
+    /// Blocking version of [`LexRuntime::put_session`].
+    pub fn put_session(
+        &self,
+        input: PutSessionRequest,
+    ) -> Result<PutSessionResponse, RusotoError<PutSessionError>> {
+        self.runtime.block_on(self.client.put_session(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -2056,24 +2057,54 @@ impl AcceptGrantError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AcceptGrantError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptGrantError::AccessDenied,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(AcceptGrantError::Authorization(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptGrantError::Authorization,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(AcceptGrantError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptGrantError::InvalidParameterValue,
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(AcceptGrantError::RateLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptGrantError::RateLimitExceeded,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(AcceptGrantError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptGrantError::ResourceLimitExceeded,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(AcceptGrantError::ServerInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptGrantError::ServerInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2116,24 +2147,54 @@ impl CheckInLicenseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CheckInLicenseError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CheckInLicenseError::AccessDenied,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(CheckInLicenseError::Authorization(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CheckInLicenseError::Authorization,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CheckInLicenseError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CheckInLicenseError::Conflict,
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(CheckInLicenseError::RateLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CheckInLicenseError::RateLimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CheckInLicenseError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CheckInLicenseError::ResourceNotFound,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(CheckInLicenseError::ServerInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CheckInLicenseError::ServerInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2182,45 +2243,78 @@ impl CheckoutBorrowLicenseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CheckoutBorrowLicenseError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CheckoutBorrowLicenseError::AccessDenied,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(CheckoutBorrowLicenseError::Authorization(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CheckoutBorrowLicenseError::Authorization,
+                    )
                 }
                 "EntitlementNotAllowedException" => {
-                    return RusotoError::Service(CheckoutBorrowLicenseError::EntitlementNotAllowed(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CheckoutBorrowLicenseError::EntitlementNotAllowed,
+                    )
                 }
                 "NoEntitlementsAllowedException" => {
-                    return RusotoError::Service(CheckoutBorrowLicenseError::NoEntitlementsAllowed(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CheckoutBorrowLicenseError::NoEntitlementsAllowed,
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(CheckoutBorrowLicenseError::RateLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CheckoutBorrowLicenseError::RateLimitExceeded,
+                    )
                 }
                 "RedirectException" => {
-                    return RusotoError::Service(CheckoutBorrowLicenseError::Redirect(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CheckoutBorrowLicenseError::Redirect,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CheckoutBorrowLicenseError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CheckoutBorrowLicenseError::ResourceNotFound,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(CheckoutBorrowLicenseError::ServerInternal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CheckoutBorrowLicenseError::ServerInternal,
+                    )
                 }
                 "UnsupportedDigitalSignatureMethodException" => {
-                    return RusotoError::Service(
-                        CheckoutBorrowLicenseError::UnsupportedDigitalSignatureMethod(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CheckoutBorrowLicenseError::UnsupportedDigitalSignatureMethod,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2272,34 +2366,70 @@ impl CheckoutLicenseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CheckoutLicenseError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CheckoutLicenseError::AccessDenied,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(CheckoutLicenseError::Authorization(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CheckoutLicenseError::Authorization,
+                    )
                 }
                 "NoEntitlementsAllowedException" => {
-                    return RusotoError::Service(CheckoutLicenseError::NoEntitlementsAllowed(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CheckoutLicenseError::NoEntitlementsAllowed,
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(CheckoutLicenseError::RateLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CheckoutLicenseError::RateLimitExceeded,
+                    )
                 }
                 "RedirectException" => {
-                    return RusotoError::Service(CheckoutLicenseError::Redirect(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CheckoutLicenseError::Redirect,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CheckoutLicenseError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CheckoutLicenseError::ResourceNotFound,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(CheckoutLicenseError::ServerInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CheckoutLicenseError::ServerInternal,
+                    )
                 }
                 "UnsupportedDigitalSignatureMethodException" => {
-                    return RusotoError::Service(
-                        CheckoutLicenseError::UnsupportedDigitalSignatureMethod(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CheckoutLicenseError::UnsupportedDigitalSignatureMethod,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2346,24 +2476,54 @@ impl CreateGrantError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateGrantError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGrantError::AccessDenied,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(CreateGrantError::Authorization(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGrantError::Authorization,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateGrantError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGrantError::InvalidParameterValue,
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(CreateGrantError::RateLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGrantError::RateLimitExceeded,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateGrantError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGrantError::ResourceLimitExceeded,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(CreateGrantError::ServerInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGrantError::ServerInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2406,30 +2566,54 @@ impl CreateGrantVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateGrantVersionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGrantVersionError::AccessDenied,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(CreateGrantVersionError::Authorization(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGrantVersionError::Authorization,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateGrantVersionError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateGrantVersionError::InvalidParameterValue,
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(CreateGrantVersionError::RateLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateGrantVersionError::RateLimitExceeded,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateGrantVersionError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateGrantVersionError::ResourceLimitExceeded,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(CreateGrantVersionError::ServerInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGrantVersionError::ServerInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2472,24 +2656,54 @@ impl CreateLicenseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateLicenseError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLicenseError::AccessDenied,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(CreateLicenseError::Authorization(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLicenseError::Authorization,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateLicenseError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLicenseError::InvalidParameterValue,
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(CreateLicenseError::RateLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLicenseError::RateLimitExceeded,
+                    )
                 }
                 "RedirectException" => {
-                    return RusotoError::Service(CreateLicenseError::Redirect(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLicenseError::Redirect,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(CreateLicenseError::ServerInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLicenseError::ServerInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2534,36 +2748,54 @@ impl CreateLicenseConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateLicenseConfigurationError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateLicenseConfigurationError::AccessDenied,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(CreateLicenseConfigurationError::Authorization(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateLicenseConfigurationError::Authorization,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        CreateLicenseConfigurationError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLicenseConfigurationError::InvalidParameterValue,
                     )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(
-                        CreateLicenseConfigurationError::RateLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLicenseConfigurationError::RateLimitExceeded,
                     )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
-                        CreateLicenseConfigurationError::ResourceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLicenseConfigurationError::ResourceLimitExceeded,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(CreateLicenseConfigurationError::ServerInternal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateLicenseConfigurationError::ServerInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2612,31 +2844,62 @@ impl CreateLicenseVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateLicenseVersionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLicenseVersionError::AccessDenied,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(CreateLicenseVersionError::Authorization(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLicenseVersionError::Authorization,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateLicenseVersionError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLicenseVersionError::Conflict,
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(CreateLicenseVersionError::RateLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateLicenseVersionError::RateLimitExceeded,
+                    )
                 }
                 "RedirectException" => {
-                    return RusotoError::Service(CreateLicenseVersionError::Redirect(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLicenseVersionError::Redirect,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateLicenseVersionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateLicenseVersionError::ResourceNotFound,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(CreateLicenseVersionError::ServerInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLicenseVersionError::ServerInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2682,27 +2945,62 @@ impl CreateTokenError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateTokenError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTokenError::AccessDenied,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(CreateTokenError::Authorization(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTokenError::Authorization,
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(CreateTokenError::RateLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTokenError::RateLimitExceeded,
+                    )
                 }
                 "RedirectException" => {
-                    return RusotoError::Service(CreateTokenError::Redirect(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTokenError::Redirect,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateTokenError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTokenError::ResourceLimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateTokenError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTokenError::ResourceNotFound,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(CreateTokenError::ServerInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTokenError::ServerInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2746,24 +3044,54 @@ impl DeleteGrantError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteGrantError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGrantError::AccessDenied,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(DeleteGrantError::Authorization(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGrantError::Authorization,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteGrantError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGrantError::InvalidParameterValue,
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(DeleteGrantError::RateLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGrantError::RateLimitExceeded,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(DeleteGrantError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGrantError::ResourceLimitExceeded,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(DeleteGrantError::ServerInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGrantError::ServerInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2808,27 +3136,62 @@ impl DeleteLicenseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteLicenseError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLicenseError::AccessDenied,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(DeleteLicenseError::Authorization(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLicenseError::Authorization,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteLicenseError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLicenseError::Conflict,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteLicenseError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLicenseError::InvalidParameterValue,
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(DeleteLicenseError::RateLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLicenseError::RateLimitExceeded,
+                    )
                 }
                 "RedirectException" => {
-                    return RusotoError::Service(DeleteLicenseError::Redirect(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLicenseError::Redirect,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(DeleteLicenseError::ServerInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLicenseError::ServerInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2872,31 +3235,46 @@ impl DeleteLicenseConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteLicenseConfigurationError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteLicenseConfigurationError::AccessDenied,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(DeleteLicenseConfigurationError::Authorization(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteLicenseConfigurationError::Authorization,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DeleteLicenseConfigurationError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLicenseConfigurationError::InvalidParameterValue,
                     )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(
-                        DeleteLicenseConfigurationError::RateLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLicenseConfigurationError::RateLimitExceeded,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(DeleteLicenseConfigurationError::ServerInternal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteLicenseConfigurationError::ServerInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2940,24 +3318,54 @@ impl DeleteTokenError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteTokenError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTokenError::AccessDenied,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(DeleteTokenError::Authorization(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTokenError::Authorization,
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(DeleteTokenError::RateLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTokenError::RateLimitExceeded,
+                    )
                 }
                 "RedirectException" => {
-                    return RusotoError::Service(DeleteTokenError::Redirect(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTokenError::Redirect,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteTokenError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTokenError::ResourceNotFound,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(DeleteTokenError::ServerInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTokenError::ServerInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3000,36 +3408,54 @@ impl ExtendLicenseConsumptionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ExtendLicenseConsumptionError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ExtendLicenseConsumptionError::AccessDenied,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(ExtendLicenseConsumptionError::Authorization(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ExtendLicenseConsumptionError::Authorization,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        ExtendLicenseConsumptionError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExtendLicenseConsumptionError::InvalidParameterValue,
                     )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(ExtendLicenseConsumptionError::RateLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ExtendLicenseConsumptionError::RateLimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ExtendLicenseConsumptionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ExtendLicenseConsumptionError::ResourceNotFound,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(ExtendLicenseConsumptionError::ServerInternal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ExtendLicenseConsumptionError::ServerInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3070,18 +3496,38 @@ impl GetAccessTokenError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetAccessTokenError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAccessTokenError::AccessDenied,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(GetAccessTokenError::Authorization(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAccessTokenError::Authorization,
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(GetAccessTokenError::RateLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAccessTokenError::RateLimitExceeded,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(GetAccessTokenError::ServerInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAccessTokenError::ServerInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3122,24 +3568,54 @@ impl GetGrantError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetGrantError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGrantError::AccessDenied,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(GetGrantError::Authorization(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGrantError::Authorization,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetGrantError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGrantError::InvalidParameterValue,
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(GetGrantError::RateLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGrantError::RateLimitExceeded,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(GetGrantError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGrantError::ResourceLimitExceeded,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(GetGrantError::ServerInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGrantError::ServerInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3180,21 +3656,46 @@ impl GetLicenseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetLicenseError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLicenseError::AccessDenied,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(GetLicenseError::Authorization(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLicenseError::Authorization,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetLicenseError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLicenseError::InvalidParameterValue,
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(GetLicenseError::RateLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLicenseError::RateLimitExceeded,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(GetLicenseError::ServerInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLicenseError::ServerInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3234,31 +3735,46 @@ impl GetLicenseConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetLicenseConfigurationError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetLicenseConfigurationError::AccessDenied,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(GetLicenseConfigurationError::Authorization(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetLicenseConfigurationError::Authorization,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        GetLicenseConfigurationError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLicenseConfigurationError::InvalidParameterValue,
                     )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(GetLicenseConfigurationError::RateLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetLicenseConfigurationError::RateLimitExceeded,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(GetLicenseConfigurationError::ServerInternal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetLicenseConfigurationError::ServerInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3300,23 +3816,46 @@ impl GetLicenseUsageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetLicenseUsageError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLicenseUsageError::AccessDenied,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(GetLicenseUsageError::Authorization(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLicenseUsageError::Authorization,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetLicenseUsageError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetLicenseUsageError::InvalidParameterValue,
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(GetLicenseUsageError::RateLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLicenseUsageError::RateLimitExceeded,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(GetLicenseUsageError::ServerInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLicenseUsageError::ServerInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3354,20 +3893,38 @@ impl GetServiceSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetServiceSettingsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetServiceSettingsError::AccessDenied,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(GetServiceSettingsError::Authorization(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetServiceSettingsError::Authorization,
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(GetServiceSettingsError::RateLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetServiceSettingsError::RateLimitExceeded,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(GetServiceSettingsError::ServerInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetServiceSettingsError::ServerInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3410,38 +3967,54 @@ impl ListAssociationsForLicenseConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        ListAssociationsForLicenseConfigurationError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAssociationsForLicenseConfigurationError::AccessDenied,
                     )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(
-                        ListAssociationsForLicenseConfigurationError::Authorization(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAssociationsForLicenseConfigurationError::Authorization,
                     )
                 }
                 "FilterLimitExceededException" => {
-                    return RusotoError::Service(
-                        ListAssociationsForLicenseConfigurationError::FilterLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAssociationsForLicenseConfigurationError::FilterLimitExceeded,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        ListAssociationsForLicenseConfigurationError::InvalidParameterValue(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAssociationsForLicenseConfigurationError::InvalidParameterValue,
                     )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(
-                        ListAssociationsForLicenseConfigurationError::RateLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAssociationsForLicenseConfigurationError::RateLimitExceeded,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(
-                        ListAssociationsForLicenseConfigurationError::ServerInternal(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAssociationsForLicenseConfigurationError::ServerInternal,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3496,32 +4069,54 @@ impl ListDistributedGrantsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListDistributedGrantsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDistributedGrantsError::AccessDenied,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(ListDistributedGrantsError::Authorization(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDistributedGrantsError::Authorization,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListDistributedGrantsError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDistributedGrantsError::InvalidParameterValue,
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(ListDistributedGrantsError::RateLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDistributedGrantsError::RateLimitExceeded,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(ListDistributedGrantsError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDistributedGrantsError::ResourceLimitExceeded,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(ListDistributedGrantsError::ServerInternal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDistributedGrantsError::ServerInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3564,35 +4159,46 @@ impl ListFailuresForLicenseConfigurationOperationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        ListFailuresForLicenseConfigurationOperationsError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFailuresForLicenseConfigurationOperationsError::AccessDenied,
                     )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(
-                        ListFailuresForLicenseConfigurationOperationsError::Authorization(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFailuresForLicenseConfigurationOperationsError::Authorization,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        ListFailuresForLicenseConfigurationOperationsError::InvalidParameterValue(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFailuresForLicenseConfigurationOperationsError::InvalidParameterValue,
                     )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(
-                        ListFailuresForLicenseConfigurationOperationsError::RateLimitExceeded(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFailuresForLicenseConfigurationOperationsError::RateLimitExceeded,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(
-                        ListFailuresForLicenseConfigurationOperationsError::ServerInternal(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFailuresForLicenseConfigurationOperationsError::ServerInternal,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3644,36 +4250,54 @@ impl ListLicenseConfigurationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListLicenseConfigurationsError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListLicenseConfigurationsError::AccessDenied,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(ListLicenseConfigurationsError::Authorization(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListLicenseConfigurationsError::Authorization,
+                    )
                 }
                 "FilterLimitExceededException" => {
-                    return RusotoError::Service(
-                        ListLicenseConfigurationsError::FilterLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLicenseConfigurationsError::FilterLimitExceeded,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        ListLicenseConfigurationsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLicenseConfigurationsError::InvalidParameterValue,
                     )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(ListLicenseConfigurationsError::RateLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListLicenseConfigurationsError::RateLimitExceeded,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(ListLicenseConfigurationsError::ServerInternal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListLicenseConfigurationsError::ServerInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3720,31 +4344,46 @@ impl ListLicenseSpecificationsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        ListLicenseSpecificationsForResourceError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLicenseSpecificationsForResourceError::AccessDenied,
                     )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(
-                        ListLicenseSpecificationsForResourceError::Authorization(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLicenseSpecificationsForResourceError::Authorization,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        ListLicenseSpecificationsForResourceError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLicenseSpecificationsForResourceError::InvalidParameterValue,
                     )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(
-                        ListLicenseSpecificationsForResourceError::RateLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLicenseSpecificationsForResourceError::RateLimitExceeded,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(
-                        ListLicenseSpecificationsForResourceError::ServerInternal(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLicenseSpecificationsForResourceError::ServerInternal,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3794,25 +4433,46 @@ impl ListLicenseVersionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListLicenseVersionsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLicenseVersionsError::AccessDenied,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(ListLicenseVersionsError::Authorization(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLicenseVersionsError::Authorization,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListLicenseVersionsError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListLicenseVersionsError::InvalidParameterValue,
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(ListLicenseVersionsError::RateLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListLicenseVersionsError::RateLimitExceeded,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(ListLicenseVersionsError::ServerInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLicenseVersionsError::ServerInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3852,21 +4512,46 @@ impl ListLicensesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListLicensesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLicensesError::AccessDenied,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(ListLicensesError::Authorization(err.msg))
-                }
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLicensesError::Authorization,
+                    )
+                }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListLicensesError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLicensesError::InvalidParameterValue,
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(ListLicensesError::RateLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLicensesError::RateLimitExceeded,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(ListLicensesError::ServerInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLicensesError::ServerInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3908,30 +4593,54 @@ impl ListReceivedGrantsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListReceivedGrantsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListReceivedGrantsError::AccessDenied,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(ListReceivedGrantsError::Authorization(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListReceivedGrantsError::Authorization,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListReceivedGrantsError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListReceivedGrantsError::InvalidParameterValue,
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(ListReceivedGrantsError::RateLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListReceivedGrantsError::RateLimitExceeded,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(ListReceivedGrantsError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListReceivedGrantsError::ResourceLimitExceeded,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(ListReceivedGrantsError::ServerInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListReceivedGrantsError::ServerInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3974,30 +4683,54 @@ impl ListReceivedLicensesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListReceivedLicensesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListReceivedLicensesError::AccessDenied,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(ListReceivedLicensesError::Authorization(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListReceivedLicensesError::Authorization,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListReceivedLicensesError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListReceivedLicensesError::InvalidParameterValue,
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(ListReceivedLicensesError::RateLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListReceivedLicensesError::RateLimitExceeded,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(ListReceivedLicensesError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListReceivedLicensesError::ResourceLimitExceeded,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(ListReceivedLicensesError::ServerInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListReceivedLicensesError::ServerInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4042,37 +4775,62 @@ impl ListResourceInventoryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListResourceInventoryError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourceInventoryError::AccessDenied,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(ListResourceInventoryError::Authorization(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourceInventoryError::Authorization,
+                    )
                 }
                 "FailedDependencyException" => {
-                    return RusotoError::Service(ListResourceInventoryError::FailedDependency(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResourceInventoryError::FailedDependency,
+                    )
                 }
                 "FilterLimitExceededException" => {
-                    return RusotoError::Service(ListResourceInventoryError::FilterLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResourceInventoryError::FilterLimitExceeded,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListResourceInventoryError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResourceInventoryError::InvalidParameterValue,
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(ListResourceInventoryError::RateLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResourceInventoryError::RateLimitExceeded,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(ListResourceInventoryError::ServerInternal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResourceInventoryError::ServerInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4114,25 +4872,46 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::AccessDenied,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Authorization(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::Authorization,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::InvalidParameterValue,
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(ListTagsForResourceError::RateLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::RateLimitExceeded,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ServerInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::ServerInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4170,18 +4949,38 @@ impl ListTokensError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTokensError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTokensError::AccessDenied,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(ListTokensError::Authorization(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTokensError::Authorization,
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(ListTokensError::RateLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTokensError::RateLimitExceeded,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(ListTokensError::ServerInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTokensError::ServerInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4224,36 +5023,54 @@ impl ListUsageForLicenseConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        ListUsageForLicenseConfigurationError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUsageForLicenseConfigurationError::AccessDenied,
                     )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(
-                        ListUsageForLicenseConfigurationError::Authorization(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUsageForLicenseConfigurationError::Authorization,
                     )
                 }
                 "FilterLimitExceededException" => {
-                    return RusotoError::Service(
-                        ListUsageForLicenseConfigurationError::FilterLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUsageForLicenseConfigurationError::FilterLimitExceeded,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        ListUsageForLicenseConfigurationError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUsageForLicenseConfigurationError::InvalidParameterValue,
                     )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(
-                        ListUsageForLicenseConfigurationError::RateLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUsageForLicenseConfigurationError::RateLimitExceeded,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(
-                        ListUsageForLicenseConfigurationError::ServerInternal(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUsageForLicenseConfigurationError::ServerInternal,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4308,24 +5125,54 @@ impl RejectGrantError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RejectGrantError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RejectGrantError::AccessDenied,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(RejectGrantError::Authorization(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RejectGrantError::Authorization,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(RejectGrantError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RejectGrantError::InvalidParameterValue,
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(RejectGrantError::RateLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RejectGrantError::RateLimitExceeded,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(RejectGrantError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RejectGrantError::ResourceLimitExceeded,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(RejectGrantError::ServerInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RejectGrantError::ServerInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4366,21 +5213,46 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(TagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::AccessDenied,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(TagResourceError::Authorization(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::Authorization,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidParameterValue,
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::RateLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::RateLimitExceeded,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(TagResourceError::ServerInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ServerInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4420,21 +5292,46 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UntagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::AccessDenied,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(UntagResourceError::Authorization(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::Authorization,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidParameterValue,
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(UntagResourceError::RateLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::RateLimitExceeded,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(UntagResourceError::ServerInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ServerInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4478,36 +5375,54 @@ impl UpdateLicenseConfigurationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateLicenseConfigurationError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateLicenseConfigurationError::AccessDenied,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(UpdateLicenseConfigurationError::Authorization(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateLicenseConfigurationError::Authorization,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        UpdateLicenseConfigurationError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateLicenseConfigurationError::InvalidParameterValue,
                     )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(
-                        UpdateLicenseConfigurationError::RateLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateLicenseConfigurationError::RateLimitExceeded,
                     )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(
-                        UpdateLicenseConfigurationError::ResourceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateLicenseConfigurationError::ResourceLimitExceeded,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(UpdateLicenseConfigurationError::ServerInternal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateLicenseConfigurationError::ServerInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4558,41 +5473,62 @@ impl UpdateLicenseSpecificationsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        UpdateLicenseSpecificationsForResourceError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateLicenseSpecificationsForResourceError::AccessDenied,
                     )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(
-                        UpdateLicenseSpecificationsForResourceError::Authorization(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateLicenseSpecificationsForResourceError::Authorization,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        UpdateLicenseSpecificationsForResourceError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateLicenseSpecificationsForResourceError::InvalidParameterValue,
                     )
                 }
                 "InvalidResourceStateException" => {
-                    return RusotoError::Service(
-                        UpdateLicenseSpecificationsForResourceError::InvalidResourceState(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateLicenseSpecificationsForResourceError::InvalidResourceState,
                     )
                 }
                 "LicenseUsageException" => {
-                    return RusotoError::Service(
-                        UpdateLicenseSpecificationsForResourceError::LicenseUsage(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateLicenseSpecificationsForResourceError::LicenseUsage,
                     )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(
-                        UpdateLicenseSpecificationsForResourceError::RateLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateLicenseSpecificationsForResourceError::RateLimitExceeded,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(
-                        UpdateLicenseSpecificationsForResourceError::ServerInternal(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateLicenseSpecificationsForResourceError::ServerInternal,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4648,27 +5584,46 @@ impl UpdateServiceSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateServiceSettingsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateServiceSettingsError::AccessDenied,
+                    )
                 }
                 "AuthorizationException" => {
-                    return RusotoError::Service(UpdateServiceSettingsError::Authorization(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateServiceSettingsError::Authorization,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateServiceSettingsError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateServiceSettingsError::InvalidParameterValue,
+                    )
                 }
                 "RateLimitExceededException" => {
-                    return RusotoError::Service(UpdateServiceSettingsError::RateLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateServiceSettingsError::RateLimitExceeded,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(UpdateServiceSettingsError::ServerInternal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateServiceSettingsError::ServerInternal,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4955,11 +5910,12 @@ pub struct LicenseManagerClient {
 impl LicenseManagerClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> LicenseManagerClient {
         LicenseManagerClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "License Manager"),
         }
     }
 
@@ -4974,12 +5930,32 @@ impl LicenseManagerClient {
     {
         LicenseManagerClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "License Manager"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> LicenseManagerClient {
-        LicenseManagerClient { client, region }
+        LicenseManagerClient {
+            client,
+            region: region_for_service(region, "License Manager"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> LicenseManagerClient {
+        LicenseManagerClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("License Manager"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> LicenseManagerClient {
+        LicenseManagerClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -4991,6 +5967,7 @@ impl LicenseManager for LicenseManagerClient {
         input: AcceptGrantRequest,
     ) -> Result<AcceptGrantResponse, RusotoError<AcceptGrantError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AcceptGrant");
         request.add_header("x-amz-target", "AWSLicenseManager.AcceptGrant");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5009,6 +5986,7 @@ impl LicenseManager for LicenseManagerClient {
         input: CheckInLicenseRequest,
     ) -> Result<CheckInLicenseResponse, RusotoError<CheckInLicenseError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CheckInLicense");
         request.add_header("x-amz-target", "AWSLicenseManager.CheckInLicense");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5027,6 +6005,7 @@ impl LicenseManager for LicenseManagerClient {
         input: CheckoutBorrowLicenseRequest,
     ) -> Result<CheckoutBorrowLicenseResponse, RusotoError<CheckoutBorrowLicenseError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CheckoutBorrowLicense");
         request.add_header("x-amz-target", "AWSLicenseManager.CheckoutBorrowLicense");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5046,6 +6025,7 @@ impl LicenseManager for LicenseManagerClient {
         input: CheckoutLicenseRequest,
     ) -> Result<CheckoutLicenseResponse, RusotoError<CheckoutLicenseError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CheckoutLicense");
         request.add_header("x-amz-target", "AWSLicenseManager.CheckoutLicense");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5064,6 +6044,7 @@ impl LicenseManager for LicenseManagerClient {
         input: CreateGrantRequest,
     ) -> Result<CreateGrantResponse, RusotoError<CreateGrantError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateGrant");
         request.add_header("x-amz-target", "AWSLicenseManager.CreateGrant");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5082,6 +6063,7 @@ impl LicenseManager for LicenseManagerClient {
         input: CreateGrantVersionRequest,
     ) -> Result<CreateGrantVersionResponse, RusotoError<CreateGrantVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateGrantVersion");
         request.add_header("x-amz-target", "AWSLicenseManager.CreateGrantVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5100,6 +6082,7 @@ impl LicenseManager for LicenseManagerClient {
         input: CreateLicenseRequest,
     ) -> Result<CreateLicenseResponse, RusotoError<CreateLicenseError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateLicense");
         request.add_header("x-amz-target", "AWSLicenseManager.CreateLicense");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5119,6 +6102,7 @@ impl LicenseManager for LicenseManagerClient {
     ) -> Result<CreateLicenseConfigurationResponse, RusotoError<CreateLicenseConfigurationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateLicenseConfiguration");
         request.add_header(
             "x-amz-target",
             "AWSLicenseManager.CreateLicenseConfiguration",
@@ -5141,6 +6125,7 @@ impl LicenseManager for LicenseManagerClient {
         input: CreateLicenseVersionRequest,
     ) -> Result<CreateLicenseVersionResponse, RusotoError<CreateLicenseVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateLicenseVersion");
         request.add_header("x-amz-target", "AWSLicenseManager.CreateLicenseVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5160,6 +6145,7 @@ impl LicenseManager for LicenseManagerClient {
         input: CreateTokenRequest,
     ) -> Result<CreateTokenResponse, RusotoError<CreateTokenError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateToken");
         request.add_header("x-amz-target", "AWSLicenseManager.CreateToken");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5178,6 +6164,7 @@ impl LicenseManager for LicenseManagerClient {
         input: DeleteGrantRequest,
     ) -> Result<DeleteGrantResponse, RusotoError<DeleteGrantError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteGrant");
         request.add_header("x-amz-target", "AWSLicenseManager.DeleteGrant");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5196,6 +6183,7 @@ impl LicenseManager for LicenseManagerClient {
         input: DeleteLicenseRequest,
     ) -> Result<DeleteLicenseResponse, RusotoError<DeleteLicenseError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteLicense");
         request.add_header("x-amz-target", "AWSLicenseManager.DeleteLicense");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5215,6 +6203,7 @@ impl LicenseManager for LicenseManagerClient {
     ) -> Result<DeleteLicenseConfigurationResponse, RusotoError<DeleteLicenseConfigurationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteLicenseConfiguration");
         request.add_header(
             "x-amz-target",
             "AWSLicenseManager.DeleteLicenseConfiguration",
@@ -5237,6 +6226,7 @@ impl LicenseManager for LicenseManagerClient {
         input: DeleteTokenRequest,
     ) -> Result<DeleteTokenResponse, RusotoError<DeleteTokenError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteToken");
         request.add_header("x-amz-target", "AWSLicenseManager.DeleteToken");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5255,6 +6245,7 @@ impl LicenseManager for LicenseManagerClient {
         input: ExtendLicenseConsumptionRequest,
     ) -> Result<ExtendLicenseConsumptionResponse, RusotoError<ExtendLicenseConsumptionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ExtendLicenseConsumption");
         request.add_header("x-amz-target", "AWSLicenseManager.ExtendLicenseConsumption");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5274,6 +6265,7 @@ impl LicenseManager for LicenseManagerClient {
         input: GetAccessTokenRequest,
     ) -> Result<GetAccessTokenResponse, RusotoError<GetAccessTokenError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetAccessToken");
         request.add_header("x-amz-target", "AWSLicenseManager.GetAccessToken");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5292,6 +6284,7 @@ impl LicenseManager for LicenseManagerClient {
         input: GetGrantRequest,
     ) -> Result<GetGrantResponse, RusotoError<GetGrantError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetGrant");
         request.add_header("x-amz-target", "AWSLicenseManager.GetGrant");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5310,6 +6303,7 @@ impl LicenseManager for LicenseManagerClient {
         input: GetLicenseRequest,
     ) -> Result<GetLicenseResponse, RusotoError<GetLicenseError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetLicense");
         request.add_header("x-amz-target", "AWSLicenseManager.GetLicense");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5328,6 +6322,7 @@ impl LicenseManager for LicenseManagerClient {
         input: GetLicenseConfigurationRequest,
     ) -> Result<GetLicenseConfigurationResponse, RusotoError<GetLicenseConfigurationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetLicenseConfiguration");
         request.add_header("x-amz-target", "AWSLicenseManager.GetLicenseConfiguration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5347,6 +6342,7 @@ impl LicenseManager for LicenseManagerClient {
         input: GetLicenseUsageRequest,
     ) -> Result<GetLicenseUsageResponse, RusotoError<GetLicenseUsageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetLicenseUsage");
         request.add_header("x-amz-target", "AWSLicenseManager.GetLicenseUsage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5364,6 +6360,7 @@ impl LicenseManager for LicenseManagerClient {
         &self,
     ) -> Result<GetServiceSettingsResponse, RusotoError<GetServiceSettingsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetServiceSettings");
         request.add_header("x-amz-target", "AWSLicenseManager.GetServiceSettings");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -5384,6 +6381,7 @@ impl LicenseManager for LicenseManagerClient {
         RusotoError<ListAssociationsForLicenseConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListAssociationsForLicenseConfiguration");
         request.add_header(
             "x-amz-target",
             "AWSLicenseManager.ListAssociationsForLicenseConfiguration",
@@ -5409,6 +6407,7 @@ impl LicenseManager for LicenseManagerClient {
         input: ListDistributedGrantsRequest,
     ) -> Result<ListDistributedGrantsResponse, RusotoError<ListDistributedGrantsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListDistributedGrants");
         request.add_header("x-amz-target", "AWSLicenseManager.ListDistributedGrants");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5431,6 +6430,7 @@ impl LicenseManager for LicenseManagerClient {
         RusotoError<ListFailuresForLicenseConfigurationOperationsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListFailuresForLicenseConfigurationOperations");
         request.add_header(
             "x-amz-target",
             "AWSLicenseManager.ListFailuresForLicenseConfigurationOperations",
@@ -5457,6 +6457,7 @@ impl LicenseManager for LicenseManagerClient {
     ) -> Result<ListLicenseConfigurationsResponse, RusotoError<ListLicenseConfigurationsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListLicenseConfigurations");
         request.add_header(
             "x-amz-target",
             "AWSLicenseManager.ListLicenseConfigurations",
@@ -5482,6 +6483,7 @@ impl LicenseManager for LicenseManagerClient {
         RusotoError<ListLicenseSpecificationsForResourceError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListLicenseSpecificationsForResource");
         request.add_header(
             "x-amz-target",
             "AWSLicenseManager.ListLicenseSpecificationsForResource",
@@ -5507,6 +6509,7 @@ impl LicenseManager for LicenseManagerClient {
         input: ListLicenseVersionsRequest,
     ) -> Result<ListLicenseVersionsResponse, RusotoError<ListLicenseVersionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListLicenseVersions");
         request.add_header("x-amz-target", "AWSLicenseManager.ListLicenseVersions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5525,6 +6528,7 @@ impl LicenseManager for LicenseManagerClient {
         input: ListLicensesRequest,
     ) -> Result<ListLicensesResponse, RusotoError<ListLicensesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListLicenses");
         request.add_header("x-amz-target", "AWSLicenseManager.ListLicenses");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5543,6 +6547,7 @@ impl LicenseManager for LicenseManagerClient {
         input: ListReceivedGrantsRequest,
     ) -> Result<ListReceivedGrantsResponse, RusotoError<ListReceivedGrantsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListReceivedGrants");
         request.add_header("x-amz-target", "AWSLicenseManager.ListReceivedGrants");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5561,6 +6566,7 @@ impl LicenseManager for LicenseManagerClient {
         input: ListReceivedLicensesRequest,
     ) -> Result<ListReceivedLicensesResponse, RusotoError<ListReceivedLicensesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListReceivedLicenses");
         request.add_header("x-amz-target", "AWSLicenseManager.ListReceivedLicenses");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5580,6 +6586,7 @@ impl LicenseManager for LicenseManagerClient {
         input: ListResourceInventoryRequest,
     ) -> Result<ListResourceInventoryResponse, RusotoError<ListResourceInventoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListResourceInventory");
         request.add_header("x-amz-target", "AWSLicenseManager.ListResourceInventory");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5599,6 +6606,7 @@ impl LicenseManager for LicenseManagerClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header("x-amz-target", "AWSLicenseManager.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5617,6 +6625,7 @@ impl LicenseManager for LicenseManagerClient {
         input: ListTokensRequest,
     ) -> Result<ListTokensResponse, RusotoError<ListTokensError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTokens");
         request.add_header("x-amz-target", "AWSLicenseManager.ListTokens");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5638,6 +6647,7 @@ impl LicenseManager for LicenseManagerClient {
         RusotoError<ListUsageForLicenseConfigurationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListUsageForLicenseConfiguration");
         request.add_header(
             "x-amz-target",
             "AWSLicenseManager.ListUsageForLicenseConfiguration",
@@ -5663,6 +6673,7 @@ impl LicenseManager for LicenseManagerClient {
         input: RejectGrantRequest,
     ) -> Result<RejectGrantResponse, RusotoError<RejectGrantError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RejectGrant");
         request.add_header("x-amz-target", "AWSLicenseManager.RejectGrant");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5681,6 +6692,7 @@ impl LicenseManager for LicenseManagerClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "AWSLicenseManager.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5699,6 +6711,7 @@ impl LicenseManager for LicenseManagerClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "AWSLicenseManager.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5718,6 +6731,7 @@ impl LicenseManager for LicenseManagerClient {
     ) -> Result<UpdateLicenseConfigurationResponse, RusotoError<UpdateLicenseConfigurationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateLicenseConfiguration");
         request.add_header(
             "x-amz-target",
             "AWSLicenseManager.UpdateLicenseConfiguration",
@@ -5743,6 +6757,7 @@ impl LicenseManager for LicenseManagerClient {
         RusotoError<UpdateLicenseSpecificationsForResourceError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateLicenseSpecificationsForResource");
         request.add_header(
             "x-amz-target",
             "AWSLicenseManager.UpdateLicenseSpecificationsForResource",
@@ -5768,6 +6783,7 @@ impl LicenseManager for LicenseManagerClient {
         input: UpdateServiceSettingsRequest,
     ) -> Result<UpdateServiceSettingsResponse, RusotoError<UpdateServiceSettingsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateServiceSettings");
         request.add_header("x-amz-target", "AWSLicenseManager.UpdateServiceSettings");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5781,3 +6797,448 @@ impl LicenseManager for LicenseManagerClient {
             .deserialize::<UpdateServiceSettingsResponse, _>()
     }
 }
+
+/// A blocking client for the AWS License Manager API, running the calls of a `LicenseManagerClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `LicenseManagerClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct LicenseManagerBlockingClient {
+    client: LicenseManagerClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl LicenseManagerBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> LicenseManagerBlockingClient {
+        LicenseManagerBlockingClient::from_client(LicenseManagerClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> LicenseManagerBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        LicenseManagerBlockingClient::from_client(LicenseManagerClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> LicenseManagerBlockingClient {
+        LicenseManagerBlockingClient::from_client(LicenseManagerClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> LicenseManagerBlockingClient {
+        LicenseManagerBlockingClient::from_client(LicenseManagerClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: LicenseManagerClient) -> LicenseManagerBlockingClient {
+        LicenseManagerBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> LicenseManagerBlockingClient {
+        LicenseManagerBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> LicenseManagerBlockingClient {
+        LicenseManagerBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`LicenseManager::accept_grant`].
+    pub fn accept_grant(
+        &self,
+        input: AcceptGrantRequest,
+    ) -> Result<AcceptGrantResponse, RusotoError<AcceptGrantError>> {
+        self.runtime.block_on(self.client.accept_grant(input))
+    }
+
+    /// Blocking version of [`LicenseManager::check_in_license`].
+    pub fn check_in_license(
+        &self,
+        input: CheckInLicenseRequest,
+    ) -> Result<CheckInLicenseResponse, RusotoError<CheckInLicenseError>> {
+        self.runtime.block_on(self.client.check_in_license(input))
+    }
+
+    /// Blocking version of [`LicenseManager::checkout_borrow_license`].
+    pub fn checkout_borrow_license(
+        &self,
+        input: CheckoutBorrowLicenseRequest,
+    ) -> Result<CheckoutBorrowLicenseResponse, RusotoError<CheckoutBorrowLicenseError>> {
+        self.runtime
+            .block_on(self.client.checkout_borrow_license(input))
+    }
+
+    /// Blocking version of [`LicenseManager::checkout_license`].
+    pub fn checkout_license(
+        &self,
+        input: CheckoutLicenseRequest,
+    ) -> Result<CheckoutLicenseResponse, RusotoError<CheckoutLicenseError>> {
+        self.runtime.block_on(self.client.checkout_license(input))
+    }
+
+    /// Blocking version of [`LicenseManager::create_grant`].
+    pub fn create_grant(
+        &self,
+        input: CreateGrantRequest,
+    ) -> Result<CreateGrantResponse, RusotoError<CreateGrantError>> {
+        self.runtime.block_on(self.client.create_grant(input))
+    }
+
+    /// Blocking version of [`LicenseManager::create_grant_version`].
+    pub fn create_grant_version(
+        &self,
+        input: CreateGrantVersionRequest,
+    ) -> Result<CreateGrantVersionResponse, RusotoError<CreateGrantVersionError>> {
+        self.runtime
+            .block_on(self.client.create_grant_version(input))
+    }
+
+    /// Blocking version of [`LicenseManager::create_license`].
+    pub fn create_license(
+        &self,
+        input: CreateLicenseRequest,
+    ) -> Result<CreateLicenseResponse, RusotoError<CreateLicenseError>> {
+        self.runtime.block_on(self.client.create_license(input))
+    }
+
+    /// Blocking version of [`LicenseManager::create_license_configuration`].
+    pub fn create_license_configuration(
+        &self,
+        input: CreateLicenseConfigurationRequest,
+    ) -> Result<CreateLicenseConfigurationResponse, RusotoError<CreateLicenseConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.create_license_configuration(input))
+    }
+
+    /// Blocking version of [`LicenseManager::create_license_version`].
+    pub fn create_license_version(
+        &self,
+        input: CreateLicenseVersionRequest,
+    ) -> Result<CreateLicenseVersionResponse, RusotoError<CreateLicenseVersionError>> {
+        self.runtime
+            .block_on(self.client.create_license_version(input))
+    }
+
+    /// Blocking version of [`LicenseManager::create_token`].
+    pub fn create_token(
+        &self,
+        input: CreateTokenRequest,
+    ) -> Result<CreateTokenResponse, RusotoError<CreateTokenError>> {
+        self.runtime.block_on(self.client.create_token(input))
+    }
+
+    /// Blocking version of [`LicenseManager::delete_grant`].
+    pub fn delete_grant(
+        &self,
+        input: DeleteGrantRequest,
+    ) -> Result<DeleteGrantResponse, RusotoError<DeleteGrantError>> {
+        self.runtime.block_on(self.client.delete_grant(input))
+    }
+
+    /// Blocking version of [`LicenseManager::delete_license`].
+    pub fn delete_license(
+        &self,
+        input: DeleteLicenseRequest,
+    ) -> Result<DeleteLicenseResponse, RusotoError<DeleteLicenseError>> {
+        self.runtime.block_on(self.client.delete_license(input))
+    }
+
+    /// Blocking version of [`LicenseManager::delete_license_configuration`].
+    pub fn delete_license_configuration(
+        &self,
+        input: DeleteLicenseConfigurationRequest,
+    ) -> Result<DeleteLicenseConfigurationResponse, RusotoError<DeleteLicenseConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_license_configuration(input))
+    }
+
+    /// Blocking version of [`LicenseManager::delete_token`].
+    pub fn delete_token(
+        &self,
+        input: DeleteTokenRequest,
+    ) -> Result<DeleteTokenResponse, RusotoError<DeleteTokenError>> {
+        self.runtime.block_on(self.client.delete_token(input))
+    }
+
+    /// Blocking version of [`LicenseManager::extend_license_consumption`].
+    pub fn extend_license_consumption(
+        &self,
+        input: ExtendLicenseConsumptionRequest,
+    ) -> Result<ExtendLicenseConsumptionResponse, RusotoError<ExtendLicenseConsumptionError>> {
+        self.runtime
+            .block_on(self.client.extend_license_consumption(input))
+    }
+
+    /// Blocking version of [`LicenseManager::get_access_token`].
+    pub fn get_access_token(
+        &self,
+        input: GetAccessTokenRequest,
+    ) -> Result<GetAccessTokenResponse, RusotoError<GetAccessTokenError>> {
+        self.runtime.block_on(self.client.get_access_token(input))
+    }
+
+    /// Blocking version of [`LicenseManager::get_grant`].
+    pub fn get_grant(
+        &self,
+        input: GetGrantRequest,
+    ) -> Result<GetGrantResponse, RusotoError<GetGrantError>> {
+        self.runtime.block_on(self.client.get_grant(input))
+    }
+
+    /// Blocking version of [`LicenseManager::get_license`].
+    pub fn get_license(
+        &self,
+        input: GetLicenseRequest,
+    ) -> Result<GetLicenseResponse, RusotoError<GetLicenseError>> {
+        self.runtime.block_on(self.client.get_license(input))
+    }
+
+    /// Blocking version of [`LicenseManager::get_license_configuration`].
+    pub fn get_license_configuration(
+        &self,
+        input: GetLicenseConfigurationRequest,
+    ) -> Result<GetLicenseConfigurationResponse, RusotoError<GetLicenseConfigurationError>> {
+        self.runtime
+            .block_on(self.client.get_license_configuration(input))
+    }
+
+    /// Blocking version of [`LicenseManager::get_license_usage`].
+    pub fn get_license_usage(
+        &self,
+        input: GetLicenseUsageRequest,
+    ) -> Result<GetLicenseUsageResponse, RusotoError<GetLicenseUsageError>> {
+        self.runtime.block_on(self.client.get_license_usage(input))
+    }
+
+    /// Blocking version of [`LicenseManager::get_service_settings`].
+    pub fn get_service_settings(
+        &self,
+    ) -> Result<GetServiceSettingsResponse, RusotoError<GetServiceSettingsError>> {
+        self.runtime.block_on(self.client.get_service_settings())
+    }
+
+    /// Blocking version of [`LicenseManager::list_associations_for_license_configuration`].
+    pub fn list_associations_for_license_configuration(
+        &self,
+        input: ListAssociationsForLicenseConfigurationRequest,
+    ) -> Result<
+        ListAssociationsForLicenseConfigurationResponse,
+        RusotoError<ListAssociationsForLicenseConfigurationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .list_associations_for_license_configuration(input),
+        )
+    }
+
+    /// Blocking version of [`LicenseManager::list_distributed_grants`].
+    pub fn list_distributed_grants(
+        &self,
+        input: ListDistributedGrantsRequest,
+    ) -> Result<ListDistributedGrantsResponse, RusotoError<ListDistributedGrantsError>> {
+        self.runtime
+            .block_on(self.client.list_distributed_grants(input))
+    }
+
+    /// Blocking version of [`LicenseManager::list_failures_for_license_configuration_operations`].
+    pub fn list_failures_for_license_configuration_operations(
+        &self,
+        input: ListFailuresForLicenseConfigurationOperationsRequest,
+    ) -> Result<
+        ListFailuresForLicenseConfigurationOperationsResponse,
+        RusotoError<ListFailuresForLicenseConfigurationOperationsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .list_failures_for_license_configuration_operations(input),
+        )
+    }
+
+    /// Blocking version of [`LicenseManager::list_license_configurations`].
+    pub fn list_license_configurations(
+        &self,
+        input: ListLicenseConfigurationsRequest,
+    ) -> Result<ListLicenseConfigurationsResponse, RusotoError<ListLicenseConfigurationsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_license_configurations(input))
+    }
+
+    /// Blocking version of [`LicenseManager::list_license_specifications_for_resource`].
+    pub fn list_license_specifications_for_resource(
+        &self,
+        input: ListLicenseSpecificationsForResourceRequest,
+    ) -> Result<
+        ListLicenseSpecificationsForResourceResponse,
+        RusotoError<ListLicenseSpecificationsForResourceError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_license_specifications_for_resource(input))
+    }
+
+    /// Blocking version of [`LicenseManager::list_license_versions`].
+    pub fn list_license_versions(
+        &self,
+        input: ListLicenseVersionsRequest,
+    ) -> Result<ListLicenseVersionsResponse, RusotoError<ListLicenseVersionsError>> {
+        self.runtime
+            .block_on(self.client.list_license_versions(input))
+    }
+
+    /// Blocking version of [`LicenseManager::list_licenses`].
+    pub fn list_licenses(
+        &self,
+        input: ListLicensesRequest,
+    ) -> Result<ListLicensesResponse, RusotoError<ListLicensesError>> {
+        self.runtime.block_on(self.client.list_licenses(input))
+    }
+
+    /// Blocking version of [`LicenseManager::list_received_grants`].
+    pub fn list_received_grants(
+        &self,
+        input: ListReceivedGrantsRequest,
+    ) -> Result<ListReceivedGrantsResponse, RusotoError<ListReceivedGrantsError>> {
+        self.runtime
+            .block_on(self.client.list_received_grants(input))
+    }
+
+    /// Blocking version of [`LicenseManager::list_received_licenses`].
+    pub fn list_received_licenses(
+        &self,
+        input: ListReceivedLicensesRequest,
+    ) -> Result<ListReceivedLicensesResponse, RusotoError<ListReceivedLicensesError>> {
+        self.runtime
+            .block_on(self.client.list_received_licenses(input))
+    }
+
+    /// Blocking version of [`LicenseManager::list_resource_inventory`].
+    pub fn list_resource_inventory(
+        &self,
+        input: ListResourceInventoryRequest,
+    ) -> Result<ListResourceInventoryResponse, RusotoError<ListResourceInventoryError>> {
+        self.runtime
+            .block_on(self.client.list_resource_inventory(input))
+    }
+
+    /// Blocking version of [`LicenseManager::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`LicenseManager::list_tokens`].
+    pub fn list_tokens(
+        &self,
+        input: ListTokensRequest,
+    ) -> Result<ListTokensResponse, RusotoError<ListTokensError>> {
+        self.runtime.block_on(self.client.list_tokens(input))
+    }
+
+    /// Blocking version of [`LicenseManager::list_usage_for_license_configuration`].
+    pub fn list_usage_for_license_configuration(
+        &self,
+        input: ListUsageForLicenseConfigurationRequest,
+    ) -> Result<
+        ListUsageForLicenseConfigurationResponse,
+        RusotoError<ListUsageForLicenseConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_usage_for_license_configuration(input))
+    }
+
+    /// Blocking version of [`LicenseManager::reject_grant`].
+    pub fn reject_grant(
+        &self,
+        input: RejectGrantRequest,
+    ) -> Result<RejectGrantResponse, RusotoError<RejectGrantError>> {
+        self.runtime.block_on(self.client.reject_grant(input))
+    }
+
+    /// Blocking version of [`LicenseManager::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`LicenseManager::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`LicenseManager::update_license_configuration`].
+    pub fn update_license_configuration(
+        &self,
+        input: UpdateLicenseConfigurationRequest,
+    ) -> Result<UpdateLicenseConfigurationResponse, RusotoError<UpdateLicenseConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.update_license_configuration(input))
+    }
+
+    /// Blocking version of [`LicenseManager::update_license_specifications_for_resource`].
+    pub fn update_license_specifications_for_resource(
+        &self,
+        input: UpdateLicenseSpecificationsForResourceRequest,
+    ) -> Result<
+        UpdateLicenseSpecificationsForResourceResponse,
+        RusotoError<UpdateLicenseSpecificationsForResourceError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .update_license_specifications_for_resource(input),
+        )
+    }
+
+    /// Blocking version of [`LicenseManager::update_service_settings`].
+    pub fn update_service_settings(
+        &self,
+        input: UpdateServiceSettingsRequest,
+    ) -> Result<UpdateServiceSettingsResponse, RusotoError<UpdateServiceSettingsError>> {
+        self.runtime
+            .block_on(self.client.update_service_settings(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -6115,29 +6116,62 @@ impl AllocateStaticIpError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AllocateStaticIpError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AllocateStaticIpError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(AllocateStaticIpError::AccountSetupInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AllocateStaticIpError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AllocateStaticIpError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AllocateStaticIpError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AllocateStaticIpError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AllocateStaticIpError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(AllocateStaticIpError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AllocateStaticIpError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AllocateStaticIpError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AllocateStaticIpError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(AllocateStaticIpError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AllocateStaticIpError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6183,36 +6217,54 @@ impl AttachCertificateToDistributionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        AttachCertificateToDistributionError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachCertificateToDistributionError::AccessDenied,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
-                        AttachCertificateToDistributionError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachCertificateToDistributionError::InvalidInput,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AttachCertificateToDistributionError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AttachCertificateToDistributionError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
-                        AttachCertificateToDistributionError::OperationFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachCertificateToDistributionError::OperationFailure,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AttachCertificateToDistributionError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AttachCertificateToDistributionError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
-                        AttachCertificateToDistributionError::Unauthenticated(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachCertificateToDistributionError::Unauthenticated,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6261,27 +6313,62 @@ impl AttachDiskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AttachDiskError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachDiskError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(AttachDiskError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachDiskError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AttachDiskError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachDiskError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AttachDiskError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachDiskError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(AttachDiskError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachDiskError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AttachDiskError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachDiskError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(AttachDiskError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachDiskError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6329,41 +6416,62 @@ impl AttachInstancesToLoadBalancerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AttachInstancesToLoadBalancerError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AttachInstancesToLoadBalancerError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        AttachInstancesToLoadBalancerError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachInstancesToLoadBalancerError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AttachInstancesToLoadBalancerError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AttachInstancesToLoadBalancerError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AttachInstancesToLoadBalancerError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AttachInstancesToLoadBalancerError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
-                        AttachInstancesToLoadBalancerError::OperationFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachInstancesToLoadBalancerError::OperationFailure,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AttachInstancesToLoadBalancerError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AttachInstancesToLoadBalancerError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
-                        AttachInstancesToLoadBalancerError::Unauthenticated(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachInstancesToLoadBalancerError::Unauthenticated,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6417,41 +6525,62 @@ impl AttachLoadBalancerTlsCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        AttachLoadBalancerTlsCertificateError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachLoadBalancerTlsCertificateError::AccessDenied,
                     )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        AttachLoadBalancerTlsCertificateError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachLoadBalancerTlsCertificateError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
-                        AttachLoadBalancerTlsCertificateError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachLoadBalancerTlsCertificateError::InvalidInput,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AttachLoadBalancerTlsCertificateError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AttachLoadBalancerTlsCertificateError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
-                        AttachLoadBalancerTlsCertificateError::OperationFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachLoadBalancerTlsCertificateError::OperationFailure,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AttachLoadBalancerTlsCertificateError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AttachLoadBalancerTlsCertificateError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
-                        AttachLoadBalancerTlsCertificateError::Unauthenticated(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachLoadBalancerTlsCertificateError::Unauthenticated,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6507,29 +6636,62 @@ impl AttachStaticIpError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AttachStaticIpError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachStaticIpError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(AttachStaticIpError::AccountSetupInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AttachStaticIpError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AttachStaticIpError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachStaticIpError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AttachStaticIpError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachStaticIpError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(AttachStaticIpError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachStaticIpError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AttachStaticIpError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachStaticIpError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(AttachStaticIpError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachStaticIpError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6575,37 +6737,62 @@ impl CloseInstancePublicPortsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CloseInstancePublicPortsError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CloseInstancePublicPortsError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        CloseInstancePublicPortsError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CloseInstancePublicPortsError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CloseInstancePublicPortsError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CloseInstancePublicPortsError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CloseInstancePublicPortsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CloseInstancePublicPortsError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CloseInstancePublicPortsError::OperationFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CloseInstancePublicPortsError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CloseInstancePublicPortsError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CloseInstancePublicPortsError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CloseInstancePublicPortsError::Unauthenticated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CloseInstancePublicPortsError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6653,27 +6840,62 @@ impl CopySnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CopySnapshotError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CopySnapshotError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(CopySnapshotError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CopySnapshotError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CopySnapshotError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CopySnapshotError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CopySnapshotError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CopySnapshotError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CopySnapshotError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CopySnapshotError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CopySnapshotError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CopySnapshotError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CopySnapshotError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CopySnapshotError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6715,21 +6937,46 @@ impl CreateCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateCertificateError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCertificateError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateCertificateError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCertificateError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateCertificateError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCertificateError::NotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateCertificateError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCertificateError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateCertificateError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCertificateError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6773,37 +7020,62 @@ impl CreateCloudFormationStackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateCloudFormationStackError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCloudFormationStackError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        CreateCloudFormationStackError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCloudFormationStackError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateCloudFormationStackError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCloudFormationStackError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateCloudFormationStackError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCloudFormationStackError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CreateCloudFormationStackError::OperationFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCloudFormationStackError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateCloudFormationStackError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCloudFormationStackError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateCloudFormationStackError::Unauthenticated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCloudFormationStackError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6849,26 +7121,54 @@ impl CreateContactMethodError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateContactMethodError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateContactMethodError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateContactMethodError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateContactMethodError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateContactMethodError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateContactMethodError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CreateContactMethodError::OperationFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateContactMethodError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateContactMethodError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateContactMethodError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateContactMethodError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateContactMethodError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6909,23 +7209,46 @@ impl CreateContainerServiceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateContainerServiceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateContainerServiceError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateContainerServiceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateContainerServiceError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateContainerServiceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateContainerServiceError::NotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateContainerServiceError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateContainerServiceError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateContainerServiceError::Unauthenticated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateContainerServiceError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6967,31 +7290,46 @@ impl CreateContainerServiceDeploymentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        CreateContainerServiceDeploymentError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateContainerServiceDeploymentError::AccessDenied,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
-                        CreateContainerServiceDeploymentError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateContainerServiceDeploymentError::InvalidInput,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateContainerServiceDeploymentError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateContainerServiceDeploymentError::NotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateContainerServiceDeploymentError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateContainerServiceDeploymentError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
-                        CreateContainerServiceDeploymentError::Unauthenticated(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateContainerServiceDeploymentError::Unauthenticated,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7039,31 +7377,46 @@ impl CreateContainerServiceRegistryLoginError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        CreateContainerServiceRegistryLoginError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateContainerServiceRegistryLoginError::AccessDenied,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
-                        CreateContainerServiceRegistryLoginError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateContainerServiceRegistryLoginError::InvalidInput,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
-                        CreateContainerServiceRegistryLoginError::NotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateContainerServiceRegistryLoginError::NotFound,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateContainerServiceRegistryLoginError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateContainerServiceRegistryLoginError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
-                        CreateContainerServiceRegistryLoginError::Unauthenticated(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateContainerServiceRegistryLoginError::Unauthenticated,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7113,27 +7466,62 @@ impl CreateDiskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDiskError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDiskError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(CreateDiskError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDiskError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDiskError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDiskError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDiskError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDiskError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CreateDiskError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDiskError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateDiskError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDiskError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateDiskError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDiskError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7179,33 +7567,62 @@ impl CreateDiskFromSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDiskFromSnapshotError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDiskFromSnapshotError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        CreateDiskFromSnapshotError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDiskFromSnapshotError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDiskFromSnapshotError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDiskFromSnapshotError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDiskFromSnapshotError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDiskFromSnapshotError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CreateDiskFromSnapshotError::OperationFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDiskFromSnapshotError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateDiskFromSnapshotError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDiskFromSnapshotError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateDiskFromSnapshotError::Unauthenticated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDiskFromSnapshotError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7253,29 +7670,62 @@ impl CreateDiskSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDiskSnapshotError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDiskSnapshotError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(CreateDiskSnapshotError::AccountSetupInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDiskSnapshotError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDiskSnapshotError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDiskSnapshotError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDiskSnapshotError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDiskSnapshotError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CreateDiskSnapshotError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDiskSnapshotError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateDiskSnapshotError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDiskSnapshotError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateDiskSnapshotError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDiskSnapshotError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7319,24 +7769,54 @@ impl CreateDistributionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDistributionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDistributionError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDistributionError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDistributionError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDistributionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDistributionError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CreateDistributionError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDistributionError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateDistributionError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDistributionError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateDistributionError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDistributionError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7381,27 +7861,62 @@ impl CreateDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDomainError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDomainError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(CreateDomainError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDomainError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDomainError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDomainError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDomainError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDomainError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CreateDomainError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDomainError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateDomainError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDomainError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateDomainError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDomainError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7447,29 +7962,62 @@ impl CreateDomainEntryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDomainEntryError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDomainEntryError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(CreateDomainEntryError::AccountSetupInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDomainEntryError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDomainEntryError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDomainEntryError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDomainEntryError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDomainEntryError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CreateDomainEntryError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDomainEntryError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateDomainEntryError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDomainEntryError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateDomainEntryError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDomainEntryError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7515,33 +8063,62 @@ impl CreateInstanceSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateInstanceSnapshotError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInstanceSnapshotError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        CreateInstanceSnapshotError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInstanceSnapshotError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateInstanceSnapshotError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInstanceSnapshotError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateInstanceSnapshotError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInstanceSnapshotError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CreateInstanceSnapshotError::OperationFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateInstanceSnapshotError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateInstanceSnapshotError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInstanceSnapshotError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateInstanceSnapshotError::Unauthenticated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateInstanceSnapshotError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7589,29 +8166,62 @@ impl CreateInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateInstancesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInstancesError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(CreateInstancesError::AccountSetupInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateInstancesError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateInstancesError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInstancesError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateInstancesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInstancesError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CreateInstancesError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInstancesError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateInstancesError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInstancesError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateInstancesError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInstancesError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7659,39 +8269,62 @@ impl CreateInstancesFromSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateInstancesFromSnapshotError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateInstancesFromSnapshotError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        CreateInstancesFromSnapshotError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInstancesFromSnapshotError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateInstancesFromSnapshotError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateInstancesFromSnapshotError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateInstancesFromSnapshotError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateInstancesFromSnapshotError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
-                        CreateInstancesFromSnapshotError::OperationFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInstancesFromSnapshotError::OperationFailure,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateInstancesFromSnapshotError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInstancesFromSnapshotError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateInstancesFromSnapshotError::Unauthenticated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateInstancesFromSnapshotError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7739,29 +8372,62 @@ impl CreateKeyPairError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateKeyPairError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateKeyPairError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(CreateKeyPairError::AccountSetupInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateKeyPairError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateKeyPairError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateKeyPairError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateKeyPairError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateKeyPairError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CreateKeyPairError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateKeyPairError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateKeyPairError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateKeyPairError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateKeyPairError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateKeyPairError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7807,29 +8473,62 @@ impl CreateLoadBalancerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateLoadBalancerError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLoadBalancerError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(CreateLoadBalancerError::AccountSetupInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateLoadBalancerError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateLoadBalancerError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLoadBalancerError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateLoadBalancerError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLoadBalancerError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CreateLoadBalancerError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLoadBalancerError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateLoadBalancerError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLoadBalancerError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateLoadBalancerError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLoadBalancerError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7877,41 +8576,62 @@ impl CreateLoadBalancerTlsCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        CreateLoadBalancerTlsCertificateError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLoadBalancerTlsCertificateError::AccessDenied,
                     )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        CreateLoadBalancerTlsCertificateError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLoadBalancerTlsCertificateError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
-                        CreateLoadBalancerTlsCertificateError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLoadBalancerTlsCertificateError::InvalidInput,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateLoadBalancerTlsCertificateError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateLoadBalancerTlsCertificateError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
-                        CreateLoadBalancerTlsCertificateError::OperationFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLoadBalancerTlsCertificateError::OperationFailure,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateLoadBalancerTlsCertificateError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateLoadBalancerTlsCertificateError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
-                        CreateLoadBalancerTlsCertificateError::Unauthenticated(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLoadBalancerTlsCertificateError::Unauthenticated,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7967,37 +8687,62 @@ impl CreateRelationalDatabaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateRelationalDatabaseError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRelationalDatabaseError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        CreateRelationalDatabaseError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRelationalDatabaseError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateRelationalDatabaseError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRelationalDatabaseError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateRelationalDatabaseError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRelationalDatabaseError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(CreateRelationalDatabaseError::OperationFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRelationalDatabaseError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateRelationalDatabaseError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRelationalDatabaseError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(CreateRelationalDatabaseError::Unauthenticated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRelationalDatabaseError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8047,41 +8792,62 @@ impl CreateRelationalDatabaseFromSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        CreateRelationalDatabaseFromSnapshotError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRelationalDatabaseFromSnapshotError::AccessDenied,
                     )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        CreateRelationalDatabaseFromSnapshotError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRelationalDatabaseFromSnapshotError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
-                        CreateRelationalDatabaseFromSnapshotError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRelationalDatabaseFromSnapshotError::InvalidInput,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
-                        CreateRelationalDatabaseFromSnapshotError::NotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRelationalDatabaseFromSnapshotError::NotFound,
                     )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
-                        CreateRelationalDatabaseFromSnapshotError::OperationFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRelationalDatabaseFromSnapshotError::OperationFailure,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(
-                        CreateRelationalDatabaseFromSnapshotError::Service(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRelationalDatabaseFromSnapshotError::Service,
                     )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
-                        CreateRelationalDatabaseFromSnapshotError::Unauthenticated(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRelationalDatabaseFromSnapshotError::Unauthenticated,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8141,41 +8907,62 @@ impl CreateRelationalDatabaseSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        CreateRelationalDatabaseSnapshotError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRelationalDatabaseSnapshotError::AccessDenied,
                     )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        CreateRelationalDatabaseSnapshotError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRelationalDatabaseSnapshotError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
-                        CreateRelationalDatabaseSnapshotError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRelationalDatabaseSnapshotError::InvalidInput,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateRelationalDatabaseSnapshotError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRelationalDatabaseSnapshotError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
-                        CreateRelationalDatabaseSnapshotError::OperationFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRelationalDatabaseSnapshotError::OperationFailure,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateRelationalDatabaseSnapshotError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRelationalDatabaseSnapshotError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
-                        CreateRelationalDatabaseSnapshotError::Unauthenticated(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRelationalDatabaseSnapshotError::Unauthenticated,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8229,24 +9016,54 @@ impl DeleteAlarmError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteAlarmError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAlarmError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteAlarmError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAlarmError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteAlarmError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAlarmError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DeleteAlarmError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAlarmError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteAlarmError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAlarmError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteAlarmError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAlarmError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8289,24 +9106,54 @@ impl DeleteAutoSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteAutoSnapshotError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAutoSnapshotError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteAutoSnapshotError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAutoSnapshotError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteAutoSnapshotError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAutoSnapshotError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DeleteAutoSnapshotError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAutoSnapshotError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteAutoSnapshotError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAutoSnapshotError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteAutoSnapshotError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAutoSnapshotError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8347,21 +9194,46 @@ impl DeleteCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteCertificateError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCertificateError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteCertificateError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCertificateError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteCertificateError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCertificateError::NotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteCertificateError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCertificateError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteCertificateError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCertificateError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8403,26 +9275,54 @@ impl DeleteContactMethodError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteContactMethodError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteContactMethodError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteContactMethodError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteContactMethodError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteContactMethodError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteContactMethodError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DeleteContactMethodError::OperationFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteContactMethodError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteContactMethodError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteContactMethodError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteContactMethodError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteContactMethodError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8463,23 +9363,46 @@ impl DeleteContainerImageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteContainerImageError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteContainerImageError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteContainerImageError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteContainerImageError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteContainerImageError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteContainerImageError::NotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteContainerImageError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteContainerImageError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteContainerImageError::Unauthenticated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteContainerImageError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8519,23 +9442,46 @@ impl DeleteContainerServiceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteContainerServiceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteContainerServiceError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteContainerServiceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteContainerServiceError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteContainerServiceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteContainerServiceError::NotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteContainerServiceError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteContainerServiceError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteContainerServiceError::Unauthenticated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteContainerServiceError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8579,27 +9525,62 @@ impl DeleteDiskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteDiskError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDiskError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(DeleteDiskError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDiskError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteDiskError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDiskError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDiskError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDiskError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DeleteDiskError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDiskError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteDiskError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDiskError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteDiskError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDiskError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8645,29 +9626,62 @@ impl DeleteDiskSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteDiskSnapshotError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDiskSnapshotError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(DeleteDiskSnapshotError::AccountSetupInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDiskSnapshotError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteDiskSnapshotError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDiskSnapshotError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDiskSnapshotError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDiskSnapshotError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DeleteDiskSnapshotError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDiskSnapshotError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteDiskSnapshotError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDiskSnapshotError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteDiskSnapshotError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDiskSnapshotError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8711,24 +9725,54 @@ impl DeleteDistributionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteDistributionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDistributionError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteDistributionError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDistributionError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDistributionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDistributionError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DeleteDistributionError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDistributionError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteDistributionError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDistributionError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteDistributionError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDistributionError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8773,27 +9817,62 @@ impl DeleteDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteDomainError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDomainError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(DeleteDomainError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDomainError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteDomainError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDomainError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDomainError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDomainError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DeleteDomainError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDomainError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteDomainError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDomainError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteDomainError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDomainError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8839,29 +9918,62 @@ impl DeleteDomainEntryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteDomainEntryError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDomainEntryError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(DeleteDomainEntryError::AccountSetupInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDomainEntryError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteDomainEntryError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDomainEntryError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDomainEntryError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDomainEntryError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DeleteDomainEntryError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDomainEntryError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteDomainEntryError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDomainEntryError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteDomainEntryError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDomainEntryError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8907,29 +10019,62 @@ impl DeleteInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteInstanceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInstanceError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(DeleteInstanceError::AccountSetupInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteInstanceError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteInstanceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInstanceError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteInstanceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInstanceError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DeleteInstanceError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInstanceError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteInstanceError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInstanceError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteInstanceError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInstanceError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8975,33 +10120,62 @@ impl DeleteInstanceSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteInstanceSnapshotError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInstanceSnapshotError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        DeleteInstanceSnapshotError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInstanceSnapshotError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteInstanceSnapshotError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInstanceSnapshotError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteInstanceSnapshotError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInstanceSnapshotError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DeleteInstanceSnapshotError::OperationFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteInstanceSnapshotError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteInstanceSnapshotError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInstanceSnapshotError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteInstanceSnapshotError::Unauthenticated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteInstanceSnapshotError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9049,29 +10223,62 @@ impl DeleteKeyPairError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteKeyPairError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteKeyPairError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(DeleteKeyPairError::AccountSetupInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteKeyPairError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteKeyPairError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteKeyPairError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteKeyPairError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteKeyPairError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DeleteKeyPairError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteKeyPairError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteKeyPairError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteKeyPairError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteKeyPairError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteKeyPairError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9117,31 +10324,62 @@ impl DeleteKnownHostKeysError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteKnownHostKeysError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteKnownHostKeysError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(DeleteKnownHostKeysError::AccountSetupInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteKnownHostKeysError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteKnownHostKeysError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteKnownHostKeysError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteKnownHostKeysError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteKnownHostKeysError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DeleteKnownHostKeysError::OperationFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteKnownHostKeysError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteKnownHostKeysError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteKnownHostKeysError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteKnownHostKeysError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteKnownHostKeysError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9187,29 +10425,62 @@ impl DeleteLoadBalancerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteLoadBalancerError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLoadBalancerError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(DeleteLoadBalancerError::AccountSetupInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteLoadBalancerError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteLoadBalancerError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLoadBalancerError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteLoadBalancerError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLoadBalancerError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DeleteLoadBalancerError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLoadBalancerError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteLoadBalancerError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLoadBalancerError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteLoadBalancerError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLoadBalancerError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9257,41 +10528,62 @@ impl DeleteLoadBalancerTlsCertificateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        DeleteLoadBalancerTlsCertificateError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLoadBalancerTlsCertificateError::AccessDenied,
                     )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        DeleteLoadBalancerTlsCertificateError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLoadBalancerTlsCertificateError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
-                        DeleteLoadBalancerTlsCertificateError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLoadBalancerTlsCertificateError::InvalidInput,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteLoadBalancerTlsCertificateError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteLoadBalancerTlsCertificateError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
-                        DeleteLoadBalancerTlsCertificateError::OperationFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLoadBalancerTlsCertificateError::OperationFailure,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteLoadBalancerTlsCertificateError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteLoadBalancerTlsCertificateError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
-                        DeleteLoadBalancerTlsCertificateError::Unauthenticated(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLoadBalancerTlsCertificateError::Unauthenticated,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9347,37 +10639,62 @@ impl DeleteRelationalDatabaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteRelationalDatabaseError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRelationalDatabaseError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        DeleteRelationalDatabaseError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRelationalDatabaseError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteRelationalDatabaseError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRelationalDatabaseError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRelationalDatabaseError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRelationalDatabaseError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DeleteRelationalDatabaseError::OperationFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRelationalDatabaseError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteRelationalDatabaseError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRelationalDatabaseError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DeleteRelationalDatabaseError::Unauthenticated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRelationalDatabaseError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9427,41 +10744,62 @@ impl DeleteRelationalDatabaseSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        DeleteRelationalDatabaseSnapshotError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRelationalDatabaseSnapshotError::AccessDenied,
                     )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        DeleteRelationalDatabaseSnapshotError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRelationalDatabaseSnapshotError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
-                        DeleteRelationalDatabaseSnapshotError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRelationalDatabaseSnapshotError::InvalidInput,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteRelationalDatabaseSnapshotError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRelationalDatabaseSnapshotError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
-                        DeleteRelationalDatabaseSnapshotError::OperationFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRelationalDatabaseSnapshotError::OperationFailure,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteRelationalDatabaseSnapshotError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRelationalDatabaseSnapshotError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
-                        DeleteRelationalDatabaseSnapshotError::Unauthenticated(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRelationalDatabaseSnapshotError::Unauthenticated,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9517,36 +10855,54 @@ impl DetachCertificateFromDistributionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        DetachCertificateFromDistributionError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachCertificateFromDistributionError::AccessDenied,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
-                        DetachCertificateFromDistributionError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachCertificateFromDistributionError::InvalidInput,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DetachCertificateFromDistributionError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetachCertificateFromDistributionError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
-                        DetachCertificateFromDistributionError::OperationFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachCertificateFromDistributionError::OperationFailure,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DetachCertificateFromDistributionError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetachCertificateFromDistributionError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
-                        DetachCertificateFromDistributionError::Unauthenticated(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachCertificateFromDistributionError::Unauthenticated,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9599,27 +10955,62 @@ impl DetachDiskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DetachDiskError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachDiskError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(DetachDiskError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachDiskError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DetachDiskError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachDiskError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DetachDiskError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachDiskError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DetachDiskError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachDiskError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DetachDiskError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachDiskError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DetachDiskError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachDiskError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9667,41 +11058,62 @@ impl DetachInstancesFromLoadBalancerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        DetachInstancesFromLoadBalancerError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachInstancesFromLoadBalancerError::AccessDenied,
                     )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        DetachInstancesFromLoadBalancerError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachInstancesFromLoadBalancerError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
-                        DetachInstancesFromLoadBalancerError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachInstancesFromLoadBalancerError::InvalidInput,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DetachInstancesFromLoadBalancerError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetachInstancesFromLoadBalancerError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
-                        DetachInstancesFromLoadBalancerError::OperationFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachInstancesFromLoadBalancerError::OperationFailure,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DetachInstancesFromLoadBalancerError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetachInstancesFromLoadBalancerError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
-                        DetachInstancesFromLoadBalancerError::Unauthenticated(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachInstancesFromLoadBalancerError::Unauthenticated,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9753,29 +11165,62 @@ impl DetachStaticIpError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DetachStaticIpError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachStaticIpError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(DetachStaticIpError::AccountSetupInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetachStaticIpError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DetachStaticIpError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachStaticIpError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DetachStaticIpError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachStaticIpError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DetachStaticIpError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachStaticIpError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DetachStaticIpError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachStaticIpError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DetachStaticIpError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachStaticIpError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9819,24 +11264,54 @@ impl DisableAddOnError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DisableAddOnError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableAddOnError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DisableAddOnError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableAddOnError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DisableAddOnError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableAddOnError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DisableAddOnError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableAddOnError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DisableAddOnError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableAddOnError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DisableAddOnError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableAddOnError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9881,33 +11356,62 @@ impl DownloadDefaultKeyPairError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DownloadDefaultKeyPairError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DownloadDefaultKeyPairError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        DownloadDefaultKeyPairError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DownloadDefaultKeyPairError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DownloadDefaultKeyPairError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DownloadDefaultKeyPairError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DownloadDefaultKeyPairError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DownloadDefaultKeyPairError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(DownloadDefaultKeyPairError::OperationFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DownloadDefaultKeyPairError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DownloadDefaultKeyPairError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DownloadDefaultKeyPairError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(DownloadDefaultKeyPairError::Unauthenticated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DownloadDefaultKeyPairError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9953,24 +11457,54 @@ impl EnableAddOnError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(EnableAddOnError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableAddOnError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(EnableAddOnError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableAddOnError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(EnableAddOnError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableAddOnError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(EnableAddOnError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableAddOnError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(EnableAddOnError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableAddOnError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(EnableAddOnError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableAddOnError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10015,29 +11549,62 @@ impl ExportSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ExportSnapshotError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExportSnapshotError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(ExportSnapshotError::AccountSetupInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ExportSnapshotError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ExportSnapshotError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExportSnapshotError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ExportSnapshotError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExportSnapshotError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(ExportSnapshotError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExportSnapshotError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ExportSnapshotError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExportSnapshotError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(ExportSnapshotError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExportSnapshotError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10083,29 +11650,62 @@ impl GetActiveNamesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetActiveNamesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetActiveNamesError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetActiveNamesError::AccountSetupInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetActiveNamesError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetActiveNamesError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetActiveNamesError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetActiveNamesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetActiveNamesError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetActiveNamesError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetActiveNamesError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetActiveNamesError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetActiveNamesError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetActiveNamesError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetActiveNamesError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10149,24 +11749,54 @@ impl GetAlarmsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetAlarmsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAlarmsError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetAlarmsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAlarmsError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetAlarmsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAlarmsError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetAlarmsError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAlarmsError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetAlarmsError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAlarmsError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetAlarmsError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAlarmsError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10209,24 +11839,54 @@ impl GetAutoSnapshotsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetAutoSnapshotsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAutoSnapshotsError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetAutoSnapshotsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAutoSnapshotsError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetAutoSnapshotsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAutoSnapshotsError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetAutoSnapshotsError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAutoSnapshotsError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetAutoSnapshotsError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAutoSnapshotsError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetAutoSnapshotsError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAutoSnapshotsError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10271,29 +11931,62 @@ impl GetBlueprintsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetBlueprintsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBlueprintsError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetBlueprintsError::AccountSetupInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetBlueprintsError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetBlueprintsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBlueprintsError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetBlueprintsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBlueprintsError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetBlueprintsError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBlueprintsError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetBlueprintsError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBlueprintsError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetBlueprintsError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBlueprintsError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10339,27 +12032,62 @@ impl GetBundlesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetBundlesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBundlesError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetBundlesError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBundlesError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetBundlesError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBundlesError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetBundlesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBundlesError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetBundlesError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBundlesError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetBundlesError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBundlesError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetBundlesError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBundlesError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10401,21 +12129,46 @@ impl GetCertificatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetCertificatesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCertificatesError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetCertificatesError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCertificatesError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetCertificatesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCertificatesError::NotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetCertificatesError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCertificatesError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetCertificatesError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCertificatesError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10461,41 +12214,62 @@ impl GetCloudFormationStackRecordsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetCloudFormationStackRecordsError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCloudFormationStackRecordsError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        GetCloudFormationStackRecordsError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCloudFormationStackRecordsError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetCloudFormationStackRecordsError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCloudFormationStackRecordsError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetCloudFormationStackRecordsError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCloudFormationStackRecordsError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
-                        GetCloudFormationStackRecordsError::OperationFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCloudFormationStackRecordsError::OperationFailure,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetCloudFormationStackRecordsError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCloudFormationStackRecordsError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
-                        GetCloudFormationStackRecordsError::Unauthenticated(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCloudFormationStackRecordsError::Unauthenticated,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10545,24 +12319,54 @@ impl GetContactMethodsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetContactMethodsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetContactMethodsError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetContactMethodsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetContactMethodsError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetContactMethodsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetContactMethodsError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetContactMethodsError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetContactMethodsError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetContactMethodsError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetContactMethodsError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetContactMethodsError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetContactMethodsError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10599,19 +12403,30 @@ impl GetContainerAPIMetadataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetContainerAPIMetadataError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetContainerAPIMetadataError::AccessDenied,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetContainerAPIMetadataError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetContainerAPIMetadataError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetContainerAPIMetadataError::Unauthenticated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetContainerAPIMetadataError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10649,21 +12464,46 @@ impl GetContainerImagesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetContainerImagesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetContainerImagesError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetContainerImagesError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetContainerImagesError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetContainerImagesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetContainerImagesError::NotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetContainerImagesError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetContainerImagesError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetContainerImagesError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetContainerImagesError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10703,21 +12543,46 @@ impl GetContainerLogError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetContainerLogError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetContainerLogError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetContainerLogError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetContainerLogError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetContainerLogError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetContainerLogError::NotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetContainerLogError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetContainerLogError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetContainerLogError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetContainerLogError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10759,31 +12624,46 @@ impl GetContainerServiceDeploymentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetContainerServiceDeploymentsError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetContainerServiceDeploymentsError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetContainerServiceDeploymentsError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetContainerServiceDeploymentsError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetContainerServiceDeploymentsError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetContainerServiceDeploymentsError::NotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetContainerServiceDeploymentsError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetContainerServiceDeploymentsError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
-                        GetContainerServiceDeploymentsError::Unauthenticated(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetContainerServiceDeploymentsError::Unauthenticated,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10827,31 +12707,46 @@ impl GetContainerServiceMetricDataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetContainerServiceMetricDataError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetContainerServiceMetricDataError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetContainerServiceMetricDataError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetContainerServiceMetricDataError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetContainerServiceMetricDataError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetContainerServiceMetricDataError::NotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetContainerServiceMetricDataError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetContainerServiceMetricDataError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
-                        GetContainerServiceMetricDataError::Unauthenticated(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetContainerServiceMetricDataError::Unauthenticated,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10893,27 +12788,46 @@ impl GetContainerServicePowersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetContainerServicePowersError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetContainerServicePowersError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetContainerServicePowersError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetContainerServicePowersError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetContainerServicePowersError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetContainerServicePowersError::NotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetContainerServicePowersError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetContainerServicePowersError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetContainerServicePowersError::Unauthenticated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetContainerServicePowersError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10953,23 +12867,46 @@ impl GetContainerServicesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetContainerServicesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetContainerServicesError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetContainerServicesError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetContainerServicesError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetContainerServicesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetContainerServicesError::NotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetContainerServicesError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetContainerServicesError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetContainerServicesError::Unauthenticated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetContainerServicesError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11013,25 +12950,62 @@ impl GetDiskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetDiskError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDiskError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetDiskError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDiskError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDiskError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDiskError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDiskError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDiskError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetDiskError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDiskError::OperationFailure,
+                    )
+                }
+                "ServiceException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDiskError::Service,
+                    )
                 }
-                "ServiceException" => return RusotoError::Service(GetDiskError::Service(err.msg)),
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetDiskError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDiskError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11077,29 +13051,62 @@ impl GetDiskSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetDiskSnapshotError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDiskSnapshotError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetDiskSnapshotError::AccountSetupInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDiskSnapshotError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDiskSnapshotError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDiskSnapshotError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDiskSnapshotError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDiskSnapshotError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetDiskSnapshotError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDiskSnapshotError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetDiskSnapshotError::Service(err.msg))
-                }
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDiskSnapshotError::Service,
+                    )
+                }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetDiskSnapshotError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDiskSnapshotError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11145,29 +13152,62 @@ impl GetDiskSnapshotsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetDiskSnapshotsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDiskSnapshotsError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetDiskSnapshotsError::AccountSetupInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDiskSnapshotsError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDiskSnapshotsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDiskSnapshotsError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDiskSnapshotsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDiskSnapshotsError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetDiskSnapshotsError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDiskSnapshotsError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetDiskSnapshotsError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDiskSnapshotsError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetDiskSnapshotsError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDiskSnapshotsError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11213,25 +13253,62 @@ impl GetDisksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetDisksError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDisksError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetDisksError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDisksError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDisksError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDisksError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDisksError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDisksError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetDisksError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDisksError::OperationFailure,
+                    )
+                }
+                "ServiceException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDisksError::Service,
+                    )
                 }
-                "ServiceException" => return RusotoError::Service(GetDisksError::Service(err.msg)),
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetDisksError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDisksError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11275,28 +13352,54 @@ impl GetDistributionBundlesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetDistributionBundlesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDistributionBundlesError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDistributionBundlesError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDistributionBundlesError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDistributionBundlesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDistributionBundlesError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetDistributionBundlesError::OperationFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDistributionBundlesError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetDistributionBundlesError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDistributionBundlesError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetDistributionBundlesError::Unauthenticated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDistributionBundlesError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11341,36 +13444,54 @@ impl GetDistributionLatestCacheResetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        GetDistributionLatestCacheResetError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDistributionLatestCacheResetError::AccessDenied,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
-                        GetDistributionLatestCacheResetError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDistributionLatestCacheResetError::InvalidInput,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDistributionLatestCacheResetError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDistributionLatestCacheResetError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
-                        GetDistributionLatestCacheResetError::OperationFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDistributionLatestCacheResetError::OperationFailure,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetDistributionLatestCacheResetError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDistributionLatestCacheResetError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
-                        GetDistributionLatestCacheResetError::Unauthenticated(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDistributionLatestCacheResetError::Unauthenticated,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11417,32 +13538,54 @@ impl GetDistributionMetricDataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetDistributionMetricDataError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDistributionMetricDataError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDistributionMetricDataError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDistributionMetricDataError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDistributionMetricDataError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDistributionMetricDataError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetDistributionMetricDataError::OperationFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDistributionMetricDataError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetDistributionMetricDataError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDistributionMetricDataError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetDistributionMetricDataError::Unauthenticated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDistributionMetricDataError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11485,24 +13628,54 @@ impl GetDistributionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetDistributionsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDistributionsError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDistributionsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDistributionsError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDistributionsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDistributionsError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetDistributionsError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDistributionsError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetDistributionsError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDistributionsError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetDistributionsError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDistributionsError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11547,27 +13720,62 @@ impl GetDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetDomainError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDomainError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetDomainError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDomainError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDomainError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDomainError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDomainError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDomainError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetDomainError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDomainError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetDomainError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDomainError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetDomainError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDomainError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11613,27 +13821,62 @@ impl GetDomainsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetDomainsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDomainsError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetDomainsError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDomainsError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDomainsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDomainsError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDomainsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDomainsError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetDomainsError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDomainsError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetDomainsError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDomainsError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetDomainsError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDomainsError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11679,37 +13922,62 @@ impl GetExportSnapshotRecordsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetExportSnapshotRecordsError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetExportSnapshotRecordsError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        GetExportSnapshotRecordsError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetExportSnapshotRecordsError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetExportSnapshotRecordsError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetExportSnapshotRecordsError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetExportSnapshotRecordsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetExportSnapshotRecordsError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetExportSnapshotRecordsError::OperationFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetExportSnapshotRecordsError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetExportSnapshotRecordsError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetExportSnapshotRecordsError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetExportSnapshotRecordsError::Unauthenticated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetExportSnapshotRecordsError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11757,27 +14025,62 @@ impl GetInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetInstanceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetInstanceError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetInstanceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetInstanceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetInstanceError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetInstanceError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetInstanceError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11823,37 +14126,62 @@ impl GetInstanceAccessDetailsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetInstanceAccessDetailsError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetInstanceAccessDetailsError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        GetInstanceAccessDetailsError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceAccessDetailsError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetInstanceAccessDetailsError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetInstanceAccessDetailsError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetInstanceAccessDetailsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceAccessDetailsError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetInstanceAccessDetailsError::OperationFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetInstanceAccessDetailsError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetInstanceAccessDetailsError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceAccessDetailsError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetInstanceAccessDetailsError::Unauthenticated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetInstanceAccessDetailsError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11901,33 +14229,62 @@ impl GetInstanceMetricDataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetInstanceMetricDataError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceMetricDataError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        GetInstanceMetricDataError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceMetricDataError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetInstanceMetricDataError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceMetricDataError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetInstanceMetricDataError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceMetricDataError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetInstanceMetricDataError::OperationFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetInstanceMetricDataError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetInstanceMetricDataError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceMetricDataError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetInstanceMetricDataError::Unauthenticated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetInstanceMetricDataError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11973,33 +14330,62 @@ impl GetInstancePortStatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetInstancePortStatesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstancePortStatesError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        GetInstancePortStatesError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstancePortStatesError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetInstancePortStatesError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstancePortStatesError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetInstancePortStatesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstancePortStatesError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetInstancePortStatesError::OperationFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetInstancePortStatesError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetInstancePortStatesError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstancePortStatesError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetInstancePortStatesError::Unauthenticated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetInstancePortStatesError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12045,31 +14431,62 @@ impl GetInstanceSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetInstanceSnapshotError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceSnapshotError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetInstanceSnapshotError::AccountSetupInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetInstanceSnapshotError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetInstanceSnapshotError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceSnapshotError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetInstanceSnapshotError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceSnapshotError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetInstanceSnapshotError::OperationFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetInstanceSnapshotError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetInstanceSnapshotError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceSnapshotError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetInstanceSnapshotError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceSnapshotError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12115,33 +14532,62 @@ impl GetInstanceSnapshotsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetInstanceSnapshotsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceSnapshotsError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetInstanceSnapshotsError::AccountSetupInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetInstanceSnapshotsError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetInstanceSnapshotsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceSnapshotsError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetInstanceSnapshotsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceSnapshotsError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetInstanceSnapshotsError::OperationFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetInstanceSnapshotsError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetInstanceSnapshotsError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceSnapshotsError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetInstanceSnapshotsError::Unauthenticated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetInstanceSnapshotsError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12187,29 +14633,62 @@ impl GetInstanceStateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetInstanceStateError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceStateError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetInstanceStateError::AccountSetupInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetInstanceStateError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetInstanceStateError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceStateError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetInstanceStateError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceStateError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetInstanceStateError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceStateError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetInstanceStateError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceStateError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetInstanceStateError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstanceStateError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12255,27 +14734,62 @@ impl GetInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetInstancesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstancesError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetInstancesError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstancesError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetInstancesError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstancesError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetInstancesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstancesError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetInstancesError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstancesError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetInstancesError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstancesError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetInstancesError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetInstancesError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12321,27 +14835,62 @@ impl GetKeyPairError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetKeyPairError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetKeyPairError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetKeyPairError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetKeyPairError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetKeyPairError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetKeyPairError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetKeyPairError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetKeyPairError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetKeyPairError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetKeyPairError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetKeyPairError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetKeyPairError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetKeyPairError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetKeyPairError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12387,27 +14936,62 @@ impl GetKeyPairsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetKeyPairsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetKeyPairsError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetKeyPairsError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetKeyPairsError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetKeyPairsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetKeyPairsError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetKeyPairsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetKeyPairsError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetKeyPairsError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetKeyPairsError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetKeyPairsError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetKeyPairsError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetKeyPairsError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetKeyPairsError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12453,29 +15037,62 @@ impl GetLoadBalancerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetLoadBalancerError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLoadBalancerError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetLoadBalancerError::AccountSetupInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetLoadBalancerError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetLoadBalancerError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLoadBalancerError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetLoadBalancerError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLoadBalancerError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetLoadBalancerError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLoadBalancerError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetLoadBalancerError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLoadBalancerError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetLoadBalancerError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLoadBalancerError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12521,37 +15138,62 @@ impl GetLoadBalancerMetricDataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetLoadBalancerMetricDataError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetLoadBalancerMetricDataError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        GetLoadBalancerMetricDataError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLoadBalancerMetricDataError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetLoadBalancerMetricDataError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetLoadBalancerMetricDataError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetLoadBalancerMetricDataError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLoadBalancerMetricDataError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetLoadBalancerMetricDataError::OperationFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetLoadBalancerMetricDataError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetLoadBalancerMetricDataError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLoadBalancerMetricDataError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetLoadBalancerMetricDataError::Unauthenticated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetLoadBalancerMetricDataError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12601,41 +15243,62 @@ impl GetLoadBalancerTlsCertificatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetLoadBalancerTlsCertificatesError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetLoadBalancerTlsCertificatesError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        GetLoadBalancerTlsCertificatesError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLoadBalancerTlsCertificatesError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetLoadBalancerTlsCertificatesError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetLoadBalancerTlsCertificatesError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetLoadBalancerTlsCertificatesError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetLoadBalancerTlsCertificatesError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
-                        GetLoadBalancerTlsCertificatesError::OperationFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLoadBalancerTlsCertificatesError::OperationFailure,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetLoadBalancerTlsCertificatesError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetLoadBalancerTlsCertificatesError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
-                        GetLoadBalancerTlsCertificatesError::Unauthenticated(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLoadBalancerTlsCertificatesError::Unauthenticated,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12687,29 +15350,62 @@ impl GetLoadBalancersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetLoadBalancersError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLoadBalancersError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetLoadBalancersError::AccountSetupInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetLoadBalancersError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetLoadBalancersError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLoadBalancersError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetLoadBalancersError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLoadBalancersError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetLoadBalancersError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLoadBalancersError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetLoadBalancersError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLoadBalancersError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetLoadBalancersError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLoadBalancersError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12755,27 +15451,62 @@ impl GetOperationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetOperationError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOperationError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetOperationError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOperationError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetOperationError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOperationError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetOperationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOperationError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetOperationError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOperationError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetOperationError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOperationError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetOperationError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOperationError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12821,29 +15552,62 @@ impl GetOperationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetOperationsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOperationsError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetOperationsError::AccountSetupInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetOperationsError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetOperationsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOperationsError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetOperationsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOperationsError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetOperationsError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOperationsError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetOperationsError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOperationsError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetOperationsError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOperationsError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12889,37 +15653,62 @@ impl GetOperationsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetOperationsForResourceError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetOperationsForResourceError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        GetOperationsForResourceError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOperationsForResourceError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetOperationsForResourceError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetOperationsForResourceError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetOperationsForResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOperationsForResourceError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetOperationsForResourceError::OperationFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetOperationsForResourceError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetOperationsForResourceError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOperationsForResourceError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetOperationsForResourceError::Unauthenticated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetOperationsForResourceError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12967,27 +15756,62 @@ impl GetRegionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetRegionsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRegionsError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetRegionsError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRegionsError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetRegionsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRegionsError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRegionsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRegionsError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetRegionsError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRegionsError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetRegionsError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRegionsError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetRegionsError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRegionsError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13033,33 +15857,62 @@ impl GetRelationalDatabaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetRelationalDatabaseError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetRelationalDatabaseError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRelationalDatabaseError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetRelationalDatabaseError::OperationFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRelationalDatabaseError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetRelationalDatabaseError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetRelationalDatabaseError::Unauthenticated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRelationalDatabaseError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13107,41 +15960,62 @@ impl GetRelationalDatabaseBlueprintsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseBlueprintsError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseBlueprintsError::AccessDenied,
                     )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseBlueprintsError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseBlueprintsError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseBlueprintsError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseBlueprintsError::InvalidInput,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRelationalDatabaseBlueprintsError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRelationalDatabaseBlueprintsError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseBlueprintsError::OperationFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseBlueprintsError::OperationFailure,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetRelationalDatabaseBlueprintsError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRelationalDatabaseBlueprintsError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseBlueprintsError::Unauthenticated(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseBlueprintsError::Unauthenticated,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13195,41 +16069,62 @@ impl GetRelationalDatabaseBundlesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetRelationalDatabaseBundlesError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRelationalDatabaseBundlesError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseBundlesError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseBundlesError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetRelationalDatabaseBundlesError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRelationalDatabaseBundlesError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRelationalDatabaseBundlesError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRelationalDatabaseBundlesError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseBundlesError::OperationFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseBundlesError::OperationFailure,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetRelationalDatabaseBundlesError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRelationalDatabaseBundlesError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseBundlesError::Unauthenticated(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseBundlesError::Unauthenticated,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13281,39 +16176,62 @@ impl GetRelationalDatabaseEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetRelationalDatabaseEventsError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRelationalDatabaseEventsError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseEventsError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseEventsError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetRelationalDatabaseEventsError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRelationalDatabaseEventsError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRelationalDatabaseEventsError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRelationalDatabaseEventsError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseEventsError::OperationFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseEventsError::OperationFailure,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetRelationalDatabaseEventsError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseEventsError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetRelationalDatabaseEventsError::Unauthenticated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRelationalDatabaseEventsError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13363,41 +16281,62 @@ impl GetRelationalDatabaseLogEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetRelationalDatabaseLogEventsError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRelationalDatabaseLogEventsError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseLogEventsError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseLogEventsError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetRelationalDatabaseLogEventsError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRelationalDatabaseLogEventsError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRelationalDatabaseLogEventsError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRelationalDatabaseLogEventsError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseLogEventsError::OperationFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseLogEventsError::OperationFailure,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetRelationalDatabaseLogEventsError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRelationalDatabaseLogEventsError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseLogEventsError::Unauthenticated(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseLogEventsError::Unauthenticated,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13451,41 +16390,62 @@ impl GetRelationalDatabaseLogStreamsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseLogStreamsError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseLogStreamsError::AccessDenied,
                     )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseLogStreamsError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseLogStreamsError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseLogStreamsError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseLogStreamsError::InvalidInput,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRelationalDatabaseLogStreamsError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRelationalDatabaseLogStreamsError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseLogStreamsError::OperationFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseLogStreamsError::OperationFailure,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetRelationalDatabaseLogStreamsError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRelationalDatabaseLogStreamsError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseLogStreamsError::Unauthenticated(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseLogStreamsError::Unauthenticated,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13539,43 +16499,62 @@ impl GetRelationalDatabaseMasterUserPasswordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseMasterUserPasswordError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseMasterUserPasswordError::AccessDenied,
                     )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseMasterUserPasswordError::AccountSetupInProgress(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseMasterUserPasswordError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseMasterUserPasswordError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseMasterUserPasswordError::InvalidInput,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseMasterUserPasswordError::NotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseMasterUserPasswordError::NotFound,
                     )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseMasterUserPasswordError::OperationFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseMasterUserPasswordError::OperationFailure,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseMasterUserPasswordError::Service(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseMasterUserPasswordError::Service,
                     )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseMasterUserPasswordError::Unauthenticated(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseMasterUserPasswordError::Unauthenticated,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13637,41 +16616,62 @@ impl GetRelationalDatabaseMetricDataError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseMetricDataError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseMetricDataError::AccessDenied,
                     )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseMetricDataError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseMetricDataError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseMetricDataError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseMetricDataError::InvalidInput,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRelationalDatabaseMetricDataError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRelationalDatabaseMetricDataError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseMetricDataError::OperationFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseMetricDataError::OperationFailure,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetRelationalDatabaseMetricDataError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRelationalDatabaseMetricDataError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseMetricDataError::Unauthenticated(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseMetricDataError::Unauthenticated,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13725,41 +16725,62 @@ impl GetRelationalDatabaseParametersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseParametersError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseParametersError::AccessDenied,
                     )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseParametersError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseParametersError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseParametersError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseParametersError::InvalidInput,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRelationalDatabaseParametersError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRelationalDatabaseParametersError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseParametersError::OperationFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseParametersError::OperationFailure,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetRelationalDatabaseParametersError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRelationalDatabaseParametersError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseParametersError::Unauthenticated(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseParametersError::Unauthenticated,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13813,41 +16834,62 @@ impl GetRelationalDatabaseSnapshotError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetRelationalDatabaseSnapshotError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRelationalDatabaseSnapshotError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseSnapshotError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseSnapshotError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetRelationalDatabaseSnapshotError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRelationalDatabaseSnapshotError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRelationalDatabaseSnapshotError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRelationalDatabaseSnapshotError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseSnapshotError::OperationFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseSnapshotError::OperationFailure,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetRelationalDatabaseSnapshotError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRelationalDatabaseSnapshotError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseSnapshotError::Unauthenticated(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseSnapshotError::Unauthenticated,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13901,41 +16943,62 @@ impl GetRelationalDatabaseSnapshotsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetRelationalDatabaseSnapshotsError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRelationalDatabaseSnapshotsError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseSnapshotsError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseSnapshotsError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetRelationalDatabaseSnapshotsError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRelationalDatabaseSnapshotsError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRelationalDatabaseSnapshotsError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRelationalDatabaseSnapshotsError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseSnapshotsError::OperationFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseSnapshotsError::OperationFailure,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetRelationalDatabaseSnapshotsError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRelationalDatabaseSnapshotsError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabaseSnapshotsError::Unauthenticated(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabaseSnapshotsError::Unauthenticated,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13987,33 +17050,62 @@ impl GetRelationalDatabasesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetRelationalDatabasesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabasesError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        GetRelationalDatabasesError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabasesError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetRelationalDatabasesError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabasesError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetRelationalDatabasesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabasesError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetRelationalDatabasesError::OperationFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRelationalDatabasesError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetRelationalDatabasesError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRelationalDatabasesError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetRelationalDatabasesError::Unauthenticated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRelationalDatabasesError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14061,27 +17153,62 @@ impl GetStaticIpError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetStaticIpError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetStaticIpError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetStaticIpError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetStaticIpError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetStaticIpError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetStaticIpError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetStaticIpError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetStaticIpError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetStaticIpError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetStaticIpError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetStaticIpError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetStaticIpError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetStaticIpError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetStaticIpError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14127,27 +17254,62 @@ impl GetStaticIpsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetStaticIpsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetStaticIpsError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(GetStaticIpsError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetStaticIpsError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetStaticIpsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetStaticIpsError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetStaticIpsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetStaticIpsError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(GetStaticIpsError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetStaticIpsError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(GetStaticIpsError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetStaticIpsError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(GetStaticIpsError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetStaticIpsError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14193,29 +17355,62 @@ impl ImportKeyPairError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ImportKeyPairError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportKeyPairError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(ImportKeyPairError::AccountSetupInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ImportKeyPairError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ImportKeyPairError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportKeyPairError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ImportKeyPairError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportKeyPairError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(ImportKeyPairError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportKeyPairError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ImportKeyPairError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportKeyPairError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(ImportKeyPairError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportKeyPairError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14261,27 +17456,62 @@ impl IsVpcPeeredError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(IsVpcPeeredError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        IsVpcPeeredError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(IsVpcPeeredError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        IsVpcPeeredError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(IsVpcPeeredError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        IsVpcPeeredError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(IsVpcPeeredError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        IsVpcPeeredError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(IsVpcPeeredError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        IsVpcPeeredError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(IsVpcPeeredError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        IsVpcPeeredError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(IsVpcPeeredError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        IsVpcPeeredError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14327,37 +17557,62 @@ impl OpenInstancePublicPortsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(OpenInstancePublicPortsError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        OpenInstancePublicPortsError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        OpenInstancePublicPortsError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        OpenInstancePublicPortsError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(OpenInstancePublicPortsError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        OpenInstancePublicPortsError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(OpenInstancePublicPortsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        OpenInstancePublicPortsError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(OpenInstancePublicPortsError::OperationFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        OpenInstancePublicPortsError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(OpenInstancePublicPortsError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        OpenInstancePublicPortsError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(OpenInstancePublicPortsError::Unauthenticated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        OpenInstancePublicPortsError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14405,25 +17660,62 @@ impl PeerVpcError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(PeerVpcError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PeerVpcError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(PeerVpcError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PeerVpcError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(PeerVpcError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PeerVpcError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PeerVpcError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PeerVpcError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(PeerVpcError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PeerVpcError::OperationFailure,
+                    )
+                }
+                "ServiceException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PeerVpcError::Service,
+                    )
                 }
-                "ServiceException" => return RusotoError::Service(PeerVpcError::Service(err.msg)),
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(PeerVpcError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PeerVpcError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14467,22 +17759,54 @@ impl PutAlarmError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(PutAlarmError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAlarmError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(PutAlarmError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAlarmError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutAlarmError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAlarmError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(PutAlarmError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAlarmError::OperationFailure,
+                    )
+                }
+                "ServiceException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAlarmError::Service,
+                    )
                 }
-                "ServiceException" => return RusotoError::Service(PutAlarmError::Service(err.msg)),
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(PutAlarmError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAlarmError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14527,33 +17851,62 @@ impl PutInstancePublicPortsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(PutInstancePublicPortsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutInstancePublicPortsError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        PutInstancePublicPortsError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutInstancePublicPortsError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(PutInstancePublicPortsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutInstancePublicPortsError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutInstancePublicPortsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutInstancePublicPortsError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(PutInstancePublicPortsError::OperationFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutInstancePublicPortsError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(PutInstancePublicPortsError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutInstancePublicPortsError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(PutInstancePublicPortsError::Unauthenticated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutInstancePublicPortsError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14601,29 +17954,62 @@ impl RebootInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RebootInstanceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RebootInstanceError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(RebootInstanceError::AccountSetupInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RebootInstanceError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(RebootInstanceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RebootInstanceError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RebootInstanceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RebootInstanceError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(RebootInstanceError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RebootInstanceError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(RebootInstanceError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RebootInstanceError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(RebootInstanceError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RebootInstanceError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14669,37 +18055,62 @@ impl RebootRelationalDatabaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RebootRelationalDatabaseError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RebootRelationalDatabaseError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        RebootRelationalDatabaseError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RebootRelationalDatabaseError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(RebootRelationalDatabaseError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RebootRelationalDatabaseError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RebootRelationalDatabaseError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RebootRelationalDatabaseError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(RebootRelationalDatabaseError::OperationFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RebootRelationalDatabaseError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(RebootRelationalDatabaseError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RebootRelationalDatabaseError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(RebootRelationalDatabaseError::Unauthenticated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RebootRelationalDatabaseError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14743,23 +18154,46 @@ impl RegisterContainerImageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RegisterContainerImageError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterContainerImageError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(RegisterContainerImageError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterContainerImageError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RegisterContainerImageError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterContainerImageError::NotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(RegisterContainerImageError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterContainerImageError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(RegisterContainerImageError::Unauthenticated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterContainerImageError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14803,29 +18237,62 @@ impl ReleaseStaticIpError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ReleaseStaticIpError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReleaseStaticIpError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(ReleaseStaticIpError::AccountSetupInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ReleaseStaticIpError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ReleaseStaticIpError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReleaseStaticIpError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ReleaseStaticIpError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReleaseStaticIpError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(ReleaseStaticIpError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReleaseStaticIpError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ReleaseStaticIpError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReleaseStaticIpError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(ReleaseStaticIpError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ReleaseStaticIpError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14869,28 +18336,54 @@ impl ResetDistributionCacheError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ResetDistributionCacheError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResetDistributionCacheError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ResetDistributionCacheError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResetDistributionCacheError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ResetDistributionCacheError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResetDistributionCacheError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(ResetDistributionCacheError::OperationFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ResetDistributionCacheError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ResetDistributionCacheError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResetDistributionCacheError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(ResetDistributionCacheError::Unauthenticated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ResetDistributionCacheError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14935,36 +18428,54 @@ impl SendContactMethodVerificationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(SendContactMethodVerificationError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SendContactMethodVerificationError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(SendContactMethodVerificationError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SendContactMethodVerificationError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(SendContactMethodVerificationError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SendContactMethodVerificationError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
-                        SendContactMethodVerificationError::OperationFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendContactMethodVerificationError::OperationFailure,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(SendContactMethodVerificationError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SendContactMethodVerificationError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
-                        SendContactMethodVerificationError::Unauthenticated(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendContactMethodVerificationError::Unauthenticated,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15013,29 +18524,62 @@ impl StartInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartInstanceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartInstanceError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(StartInstanceError::AccountSetupInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartInstanceError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StartInstanceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartInstanceError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StartInstanceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartInstanceError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(StartInstanceError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartInstanceError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(StartInstanceError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartInstanceError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(StartInstanceError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartInstanceError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15081,37 +18625,62 @@ impl StartRelationalDatabaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartRelationalDatabaseError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartRelationalDatabaseError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        StartRelationalDatabaseError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartRelationalDatabaseError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StartRelationalDatabaseError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartRelationalDatabaseError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StartRelationalDatabaseError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartRelationalDatabaseError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(StartRelationalDatabaseError::OperationFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartRelationalDatabaseError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(StartRelationalDatabaseError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartRelationalDatabaseError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(StartRelationalDatabaseError::Unauthenticated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartRelationalDatabaseError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15159,27 +18728,62 @@ impl StopInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StopInstanceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopInstanceError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(StopInstanceError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopInstanceError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StopInstanceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopInstanceError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StopInstanceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopInstanceError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(StopInstanceError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopInstanceError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(StopInstanceError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopInstanceError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(StopInstanceError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopInstanceError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15225,33 +18829,62 @@ impl StopRelationalDatabaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StopRelationalDatabaseError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopRelationalDatabaseError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        StopRelationalDatabaseError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopRelationalDatabaseError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(StopRelationalDatabaseError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopRelationalDatabaseError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StopRelationalDatabaseError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopRelationalDatabaseError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(StopRelationalDatabaseError::OperationFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopRelationalDatabaseError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(StopRelationalDatabaseError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopRelationalDatabaseError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(StopRelationalDatabaseError::Unauthenticated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopRelationalDatabaseError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15299,27 +18932,62 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(TagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(TagResourceError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(TagResourceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(TagResourceError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(TagResourceError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(TagResourceError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15363,24 +19031,54 @@ impl TestAlarmError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(TestAlarmError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestAlarmError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(TestAlarmError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestAlarmError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TestAlarmError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestAlarmError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(TestAlarmError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestAlarmError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(TestAlarmError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestAlarmError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(TestAlarmError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestAlarmError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15425,27 +19123,62 @@ impl UnpeerVpcError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UnpeerVpcError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UnpeerVpcError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(UnpeerVpcError::AccountSetupInProgress(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UnpeerVpcError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UnpeerVpcError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UnpeerVpcError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UnpeerVpcError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UnpeerVpcError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(UnpeerVpcError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UnpeerVpcError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UnpeerVpcError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UnpeerVpcError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(UnpeerVpcError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UnpeerVpcError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15491,29 +19224,62 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UntagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(UntagResourceError::AccountSetupInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UntagResourceError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(UntagResourceError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UntagResourceError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(UntagResourceError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15555,23 +19321,46 @@ impl UpdateContainerServiceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateContainerServiceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateContainerServiceError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateContainerServiceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateContainerServiceError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateContainerServiceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateContainerServiceError::NotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateContainerServiceError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateContainerServiceError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(UpdateContainerServiceError::Unauthenticated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateContainerServiceError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15613,24 +19402,54 @@ impl UpdateDistributionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateDistributionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDistributionError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateDistributionError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDistributionError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateDistributionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDistributionError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(UpdateDistributionError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDistributionError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateDistributionError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDistributionError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(UpdateDistributionError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDistributionError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15673,32 +19492,54 @@ impl UpdateDistributionBundleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateDistributionBundleError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDistributionBundleError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateDistributionBundleError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDistributionBundleError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateDistributionBundleError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDistributionBundleError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(UpdateDistributionBundleError::OperationFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDistributionBundleError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateDistributionBundleError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDistributionBundleError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(UpdateDistributionBundleError::Unauthenticated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDistributionBundleError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15743,29 +19584,62 @@ impl UpdateDomainEntryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateDomainEntryError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDomainEntryError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(UpdateDomainEntryError::AccountSetupInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDomainEntryError::AccountSetupInProgress,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateDomainEntryError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDomainEntryError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateDomainEntryError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDomainEntryError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(UpdateDomainEntryError::OperationFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDomainEntryError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateDomainEntryError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDomainEntryError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(UpdateDomainEntryError::Unauthenticated(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDomainEntryError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15813,39 +19687,62 @@ impl UpdateLoadBalancerAttributeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateLoadBalancerAttributeError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateLoadBalancerAttributeError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        UpdateLoadBalancerAttributeError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateLoadBalancerAttributeError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateLoadBalancerAttributeError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateLoadBalancerAttributeError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateLoadBalancerAttributeError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateLoadBalancerAttributeError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
-                        UpdateLoadBalancerAttributeError::OperationFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateLoadBalancerAttributeError::OperationFailure,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateLoadBalancerAttributeError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateLoadBalancerAttributeError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(UpdateLoadBalancerAttributeError::Unauthenticated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateLoadBalancerAttributeError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15893,37 +19790,62 @@ impl UpdateRelationalDatabaseError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateRelationalDatabaseError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRelationalDatabaseError::AccessDenied,
+                    )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        UpdateRelationalDatabaseError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRelationalDatabaseError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateRelationalDatabaseError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRelationalDatabaseError::InvalidInput,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateRelationalDatabaseError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRelationalDatabaseError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(UpdateRelationalDatabaseError::OperationFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRelationalDatabaseError::OperationFailure,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateRelationalDatabaseError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRelationalDatabaseError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(UpdateRelationalDatabaseError::Unauthenticated(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRelationalDatabaseError::Unauthenticated,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15973,41 +19895,62 @@ impl UpdateRelationalDatabaseParametersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        UpdateRelationalDatabaseParametersError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRelationalDatabaseParametersError::AccessDenied,
                     )
                 }
                 "AccountSetupInProgressException" => {
-                    return RusotoError::Service(
-                        UpdateRelationalDatabaseParametersError::AccountSetupInProgress(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRelationalDatabaseParametersError::AccountSetupInProgress,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
-                        UpdateRelationalDatabaseParametersError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRelationalDatabaseParametersError::InvalidInput,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateRelationalDatabaseParametersError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRelationalDatabaseParametersError::NotFound,
+                    )
                 }
                 "OperationFailureException" => {
-                    return RusotoError::Service(
-                        UpdateRelationalDatabaseParametersError::OperationFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRelationalDatabaseParametersError::OperationFailure,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateRelationalDatabaseParametersError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRelationalDatabaseParametersError::Service,
+                    )
                 }
                 "UnauthenticatedException" => {
-                    return RusotoError::Service(
-                        UpdateRelationalDatabaseParametersError::Unauthenticated(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRelationalDatabaseParametersError::Unauthenticated,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16950,11 +20893,12 @@ pub struct LightsailClient {
 impl LightsailClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> LightsailClient {
         LightsailClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Lightsail"),
         }
     }
 
@@ -16969,12 +20913,32 @@ impl LightsailClient {
     {
         LightsailClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Lightsail"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> LightsailClient {
-        LightsailClient { client, region }
+        LightsailClient {
+            client,
+            region: region_for_service(region, "Lightsail"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> LightsailClient {
+        LightsailClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Lightsail"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> LightsailClient {
+        LightsailClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -16986,6 +20950,7 @@ impl Lightsail for LightsailClient {
         input: AllocateStaticIpRequest,
     ) -> Result<AllocateStaticIpResult, RusotoError<AllocateStaticIpError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AllocateStaticIp");
         request.add_header("x-amz-target", "Lightsail_20161128.AllocateStaticIp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17007,6 +20972,7 @@ impl Lightsail for LightsailClient {
         RusotoError<AttachCertificateToDistributionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AttachCertificateToDistribution");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.AttachCertificateToDistribution",
@@ -17029,6 +20995,7 @@ impl Lightsail for LightsailClient {
         input: AttachDiskRequest,
     ) -> Result<AttachDiskResult, RusotoError<AttachDiskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AttachDisk");
         request.add_header("x-amz-target", "Lightsail_20161128.AttachDisk");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17048,6 +21015,7 @@ impl Lightsail for LightsailClient {
     ) -> Result<AttachInstancesToLoadBalancerResult, RusotoError<AttachInstancesToLoadBalancerError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AttachInstancesToLoadBalancer");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.AttachInstancesToLoadBalancer",
@@ -17073,6 +21041,7 @@ impl Lightsail for LightsailClient {
         RusotoError<AttachLoadBalancerTlsCertificateError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AttachLoadBalancerTlsCertificate");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.AttachLoadBalancerTlsCertificate",
@@ -17098,6 +21067,7 @@ impl Lightsail for LightsailClient {
         input: AttachStaticIpRequest,
     ) -> Result<AttachStaticIpResult, RusotoError<AttachStaticIpError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AttachStaticIp");
         request.add_header("x-amz-target", "Lightsail_20161128.AttachStaticIp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17116,6 +21086,7 @@ impl Lightsail for LightsailClient {
         input: CloseInstancePublicPortsRequest,
     ) -> Result<CloseInstancePublicPortsResult, RusotoError<CloseInstancePublicPortsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CloseInstancePublicPorts");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.CloseInstancePublicPorts",
@@ -17138,6 +21109,7 @@ impl Lightsail for LightsailClient {
         input: CopySnapshotRequest,
     ) -> Result<CopySnapshotResult, RusotoError<CopySnapshotError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CopySnapshot");
         request.add_header("x-amz-target", "Lightsail_20161128.CopySnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17156,6 +21128,7 @@ impl Lightsail for LightsailClient {
         input: CreateCertificateRequest,
     ) -> Result<CreateCertificateResult, RusotoError<CreateCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateCertificate");
         request.add_header("x-amz-target", "Lightsail_20161128.CreateCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17174,6 +21147,7 @@ impl Lightsail for LightsailClient {
         input: CreateCloudFormationStackRequest,
     ) -> Result<CreateCloudFormationStackResult, RusotoError<CreateCloudFormationStackError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateCloudFormationStack");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.CreateCloudFormationStack",
@@ -17196,6 +21170,7 @@ impl Lightsail for LightsailClient {
         input: CreateContactMethodRequest,
     ) -> Result<CreateContactMethodResult, RusotoError<CreateContactMethodError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateContactMethod");
         request.add_header("x-amz-target", "Lightsail_20161128.CreateContactMethod");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17214,6 +21189,7 @@ impl Lightsail for LightsailClient {
         input: CreateContainerServiceRequest,
     ) -> Result<CreateContainerServiceResult, RusotoError<CreateContainerServiceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateContainerService");
         request.add_header("x-amz-target", "Lightsail_20161128.CreateContainerService");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17236,6 +21212,7 @@ impl Lightsail for LightsailClient {
         RusotoError<CreateContainerServiceDeploymentError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateContainerServiceDeployment");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.CreateContainerServiceDeployment",
@@ -17263,6 +21240,7 @@ impl Lightsail for LightsailClient {
         RusotoError<CreateContainerServiceRegistryLoginError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateContainerServiceRegistryLogin");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.CreateContainerServiceRegistryLogin",
@@ -17287,6 +21265,7 @@ impl Lightsail for LightsailClient {
         input: CreateDiskRequest,
     ) -> Result<CreateDiskResult, RusotoError<CreateDiskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateDisk");
         request.add_header("x-amz-target", "Lightsail_20161128.CreateDisk");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17305,6 +21284,7 @@ impl Lightsail for LightsailClient {
         input: CreateDiskFromSnapshotRequest,
     ) -> Result<CreateDiskFromSnapshotResult, RusotoError<CreateDiskFromSnapshotError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateDiskFromSnapshot");
         request.add_header("x-amz-target", "Lightsail_20161128.CreateDiskFromSnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17324,6 +21304,7 @@ impl Lightsail for LightsailClient {
         input: CreateDiskSnapshotRequest,
     ) -> Result<CreateDiskSnapshotResult, RusotoError<CreateDiskSnapshotError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateDiskSnapshot");
         request.add_header("x-amz-target", "Lightsail_20161128.CreateDiskSnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17342,6 +21323,7 @@ impl Lightsail for LightsailClient {
         input: CreateDistributionRequest,
     ) -> Result<CreateDistributionResult, RusotoError<CreateDistributionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateDistribution");
         request.add_header("x-amz-target", "Lightsail_20161128.CreateDistribution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17360,6 +21342,7 @@ impl Lightsail for LightsailClient {
         input: CreateDomainRequest,
     ) -> Result<CreateDomainResult, RusotoError<CreateDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateDomain");
         request.add_header("x-amz-target", "Lightsail_20161128.CreateDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17378,6 +21361,7 @@ impl Lightsail for LightsailClient {
         input: CreateDomainEntryRequest,
     ) -> Result<CreateDomainEntryResult, RusotoError<CreateDomainEntryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateDomainEntry");
         request.add_header("x-amz-target", "Lightsail_20161128.CreateDomainEntry");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17396,6 +21380,7 @@ impl Lightsail for LightsailClient {
         input: CreateInstanceSnapshotRequest,
     ) -> Result<CreateInstanceSnapshotResult, RusotoError<CreateInstanceSnapshotError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateInstanceSnapshot");
         request.add_header("x-amz-target", "Lightsail_20161128.CreateInstanceSnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17415,6 +21400,7 @@ impl Lightsail for LightsailClient {
         input: CreateInstancesRequest,
     ) -> Result<CreateInstancesResult, RusotoError<CreateInstancesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateInstances");
         request.add_header("x-amz-target", "Lightsail_20161128.CreateInstances");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17434,6 +21420,7 @@ impl Lightsail for LightsailClient {
     ) -> Result<CreateInstancesFromSnapshotResult, RusotoError<CreateInstancesFromSnapshotError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateInstancesFromSnapshot");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.CreateInstancesFromSnapshot",
@@ -17456,6 +21443,7 @@ impl Lightsail for LightsailClient {
         input: CreateKeyPairRequest,
     ) -> Result<CreateKeyPairResult, RusotoError<CreateKeyPairError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateKeyPair");
         request.add_header("x-amz-target", "Lightsail_20161128.CreateKeyPair");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17474,6 +21462,7 @@ impl Lightsail for LightsailClient {
         input: CreateLoadBalancerRequest,
     ) -> Result<CreateLoadBalancerResult, RusotoError<CreateLoadBalancerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateLoadBalancer");
         request.add_header("x-amz-target", "Lightsail_20161128.CreateLoadBalancer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17495,6 +21484,7 @@ impl Lightsail for LightsailClient {
         RusotoError<CreateLoadBalancerTlsCertificateError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateLoadBalancerTlsCertificate");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.CreateLoadBalancerTlsCertificate",
@@ -17520,6 +21510,7 @@ impl Lightsail for LightsailClient {
         input: CreateRelationalDatabaseRequest,
     ) -> Result<CreateRelationalDatabaseResult, RusotoError<CreateRelationalDatabaseError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateRelationalDatabase");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.CreateRelationalDatabase",
@@ -17545,6 +21536,7 @@ impl Lightsail for LightsailClient {
         RusotoError<CreateRelationalDatabaseFromSnapshotError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateRelationalDatabaseFromSnapshot");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.CreateRelationalDatabaseFromSnapshot",
@@ -17573,6 +21565,7 @@ impl Lightsail for LightsailClient {
         RusotoError<CreateRelationalDatabaseSnapshotError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateRelationalDatabaseSnapshot");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.CreateRelationalDatabaseSnapshot",
@@ -17598,6 +21591,7 @@ impl Lightsail for LightsailClient {
         input: DeleteAlarmRequest,
     ) -> Result<DeleteAlarmResult, RusotoError<DeleteAlarmError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteAlarm");
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteAlarm");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17616,6 +21610,7 @@ impl Lightsail for LightsailClient {
         input: DeleteAutoSnapshotRequest,
     ) -> Result<DeleteAutoSnapshotResult, RusotoError<DeleteAutoSnapshotError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteAutoSnapshot");
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteAutoSnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17634,6 +21629,7 @@ impl Lightsail for LightsailClient {
         input: DeleteCertificateRequest,
     ) -> Result<DeleteCertificateResult, RusotoError<DeleteCertificateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteCertificate");
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteCertificate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17652,6 +21648,7 @@ impl Lightsail for LightsailClient {
         input: DeleteContactMethodRequest,
     ) -> Result<DeleteContactMethodResult, RusotoError<DeleteContactMethodError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteContactMethod");
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteContactMethod");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17670,6 +21667,7 @@ impl Lightsail for LightsailClient {
         input: DeleteContainerImageRequest,
     ) -> Result<DeleteContainerImageResult, RusotoError<DeleteContainerImageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteContainerImage");
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteContainerImage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17688,6 +21686,7 @@ impl Lightsail for LightsailClient {
         input: DeleteContainerServiceRequest,
     ) -> Result<DeleteContainerServiceResult, RusotoError<DeleteContainerServiceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteContainerService");
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteContainerService");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17707,6 +21706,7 @@ impl Lightsail for LightsailClient {
         input: DeleteDiskRequest,
     ) -> Result<DeleteDiskResult, RusotoError<DeleteDiskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteDisk");
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteDisk");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17725,6 +21725,7 @@ impl Lightsail for LightsailClient {
         input: DeleteDiskSnapshotRequest,
     ) -> Result<DeleteDiskSnapshotResult, RusotoError<DeleteDiskSnapshotError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteDiskSnapshot");
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteDiskSnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17743,6 +21744,7 @@ impl Lightsail for LightsailClient {
         input: DeleteDistributionRequest,
     ) -> Result<DeleteDistributionResult, RusotoError<DeleteDistributionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteDistribution");
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteDistribution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17761,6 +21763,7 @@ impl Lightsail for LightsailClient {
         input: DeleteDomainRequest,
     ) -> Result<DeleteDomainResult, RusotoError<DeleteDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteDomain");
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17779,6 +21782,7 @@ impl Lightsail for LightsailClient {
         input: DeleteDomainEntryRequest,
     ) -> Result<DeleteDomainEntryResult, RusotoError<DeleteDomainEntryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteDomainEntry");
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteDomainEntry");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17797,6 +21801,7 @@ impl Lightsail for LightsailClient {
         input: DeleteInstanceRequest,
     ) -> Result<DeleteInstanceResult, RusotoError<DeleteInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteInstance");
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17815,6 +21820,7 @@ impl Lightsail for LightsailClient {
         input: DeleteInstanceSnapshotRequest,
     ) -> Result<DeleteInstanceSnapshotResult, RusotoError<DeleteInstanceSnapshotError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteInstanceSnapshot");
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteInstanceSnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17834,6 +21840,7 @@ impl Lightsail for LightsailClient {
         input: DeleteKeyPairRequest,
     ) -> Result<DeleteKeyPairResult, RusotoError<DeleteKeyPairError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteKeyPair");
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteKeyPair");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17852,6 +21859,7 @@ impl Lightsail for LightsailClient {
         input: DeleteKnownHostKeysRequest,
     ) -> Result<DeleteKnownHostKeysResult, RusotoError<DeleteKnownHostKeysError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteKnownHostKeys");
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteKnownHostKeys");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17870,6 +21878,7 @@ impl Lightsail for LightsailClient {
         input: DeleteLoadBalancerRequest,
     ) -> Result<DeleteLoadBalancerResult, RusotoError<DeleteLoadBalancerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteLoadBalancer");
         request.add_header("x-amz-target", "Lightsail_20161128.DeleteLoadBalancer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -17891,6 +21900,7 @@ impl Lightsail for LightsailClient {
         RusotoError<DeleteLoadBalancerTlsCertificateError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteLoadBalancerTlsCertificate");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.DeleteLoadBalancerTlsCertificate",
@@ -17916,6 +21926,7 @@ impl Lightsail for LightsailClient {
         input: DeleteRelationalDatabaseRequest,
     ) -> Result<DeleteRelationalDatabaseResult, RusotoError<DeleteRelationalDatabaseError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteRelationalDatabase");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.DeleteRelationalDatabase",
@@ -17941,6 +21952,7 @@ impl Lightsail for LightsailClient {
         RusotoError<DeleteRelationalDatabaseSnapshotError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteRelationalDatabaseSnapshot");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.DeleteRelationalDatabaseSnapshot",
@@ -17969,6 +21981,7 @@ impl Lightsail for LightsailClient {
         RusotoError<DetachCertificateFromDistributionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DetachCertificateFromDistribution");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.DetachCertificateFromDistribution",
@@ -17994,6 +22007,7 @@ impl Lightsail for LightsailClient {
         input: DetachDiskRequest,
     ) -> Result<DetachDiskResult, RusotoError<DetachDiskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DetachDisk");
         request.add_header("x-amz-target", "Lightsail_20161128.DetachDisk");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18015,6 +22029,7 @@ impl Lightsail for LightsailClient {
         RusotoError<DetachInstancesFromLoadBalancerError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DetachInstancesFromLoadBalancer");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.DetachInstancesFromLoadBalancer",
@@ -18037,6 +22052,7 @@ impl Lightsail for LightsailClient {
         input: DetachStaticIpRequest,
     ) -> Result<DetachStaticIpResult, RusotoError<DetachStaticIpError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DetachStaticIp");
         request.add_header("x-amz-target", "Lightsail_20161128.DetachStaticIp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18055,6 +22071,7 @@ impl Lightsail for LightsailClient {
         input: DisableAddOnRequest,
     ) -> Result<DisableAddOnResult, RusotoError<DisableAddOnError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisableAddOn");
         request.add_header("x-amz-target", "Lightsail_20161128.DisableAddOn");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18072,6 +22089,7 @@ impl Lightsail for LightsailClient {
         &self,
     ) -> Result<DownloadDefaultKeyPairResult, RusotoError<DownloadDefaultKeyPairError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DownloadDefaultKeyPair");
         request.add_header("x-amz-target", "Lightsail_20161128.DownloadDefaultKeyPair");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -18090,6 +22108,7 @@ impl Lightsail for LightsailClient {
         input: EnableAddOnRequest,
     ) -> Result<EnableAddOnResult, RusotoError<EnableAddOnError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("EnableAddOn");
         request.add_header("x-amz-target", "Lightsail_20161128.EnableAddOn");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18108,6 +22127,7 @@ impl Lightsail for LightsailClient {
         input: ExportSnapshotRequest,
     ) -> Result<ExportSnapshotResult, RusotoError<ExportSnapshotError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ExportSnapshot");
         request.add_header("x-amz-target", "Lightsail_20161128.ExportSnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18126,6 +22146,7 @@ impl Lightsail for LightsailClient {
         input: GetActiveNamesRequest,
     ) -> Result<GetActiveNamesResult, RusotoError<GetActiveNamesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetActiveNames");
         request.add_header("x-amz-target", "Lightsail_20161128.GetActiveNames");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18144,6 +22165,7 @@ impl Lightsail for LightsailClient {
         input: GetAlarmsRequest,
     ) -> Result<GetAlarmsResult, RusotoError<GetAlarmsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetAlarms");
         request.add_header("x-amz-target", "Lightsail_20161128.GetAlarms");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18162,6 +22184,7 @@ impl Lightsail for LightsailClient {
         input: GetAutoSnapshotsRequest,
     ) -> Result<GetAutoSnapshotsResult, RusotoError<GetAutoSnapshotsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetAutoSnapshots");
         request.add_header("x-amz-target", "Lightsail_20161128.GetAutoSnapshots");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18180,6 +22203,7 @@ impl Lightsail for LightsailClient {
         input: GetBlueprintsRequest,
     ) -> Result<GetBlueprintsResult, RusotoError<GetBlueprintsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetBlueprints");
         request.add_header("x-amz-target", "Lightsail_20161128.GetBlueprints");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18198,6 +22222,7 @@ impl Lightsail for LightsailClient {
         input: GetBundlesRequest,
     ) -> Result<GetBundlesResult, RusotoError<GetBundlesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetBundles");
         request.add_header("x-amz-target", "Lightsail_20161128.GetBundles");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18216,6 +22241,7 @@ impl Lightsail for LightsailClient {
         input: GetCertificatesRequest,
     ) -> Result<GetCertificatesResult, RusotoError<GetCertificatesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetCertificates");
         request.add_header("x-amz-target", "Lightsail_20161128.GetCertificates");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18235,6 +22261,7 @@ impl Lightsail for LightsailClient {
     ) -> Result<GetCloudFormationStackRecordsResult, RusotoError<GetCloudFormationStackRecordsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetCloudFormationStackRecords");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.GetCloudFormationStackRecords",
@@ -18257,6 +22284,7 @@ impl Lightsail for LightsailClient {
         input: GetContactMethodsRequest,
     ) -> Result<GetContactMethodsResult, RusotoError<GetContactMethodsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetContactMethods");
         request.add_header("x-amz-target", "Lightsail_20161128.GetContactMethods");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18274,6 +22302,7 @@ impl Lightsail for LightsailClient {
         &self,
     ) -> Result<GetContainerAPIMetadataResult, RusotoError<GetContainerAPIMetadataError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetContainerAPIMetadata");
         request.add_header("x-amz-target", "Lightsail_20161128.GetContainerAPIMetadata");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -18292,6 +22321,7 @@ impl Lightsail for LightsailClient {
         input: GetContainerImagesRequest,
     ) -> Result<GetContainerImagesResult, RusotoError<GetContainerImagesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetContainerImages");
         request.add_header("x-amz-target", "Lightsail_20161128.GetContainerImages");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18310,6 +22340,7 @@ impl Lightsail for LightsailClient {
         input: GetContainerLogRequest,
     ) -> Result<GetContainerLogResult, RusotoError<GetContainerLogError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetContainerLog");
         request.add_header("x-amz-target", "Lightsail_20161128.GetContainerLog");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18331,6 +22362,7 @@ impl Lightsail for LightsailClient {
         RusotoError<GetContainerServiceDeploymentsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetContainerServiceDeployments");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.GetContainerServiceDeployments",
@@ -18354,6 +22386,7 @@ impl Lightsail for LightsailClient {
     ) -> Result<GetContainerServiceMetricDataResult, RusotoError<GetContainerServiceMetricDataError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetContainerServiceMetricData");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.GetContainerServiceMetricData",
@@ -18375,6 +22408,7 @@ impl Lightsail for LightsailClient {
         &self,
     ) -> Result<GetContainerServicePowersResult, RusotoError<GetContainerServicePowersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetContainerServicePowers");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.GetContainerServicePowers",
@@ -18396,6 +22430,7 @@ impl Lightsail for LightsailClient {
         input: GetContainerServicesRequest,
     ) -> Result<ContainerServicesListResult, RusotoError<GetContainerServicesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetContainerServices");
         request.add_header("x-amz-target", "Lightsail_20161128.GetContainerServices");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18414,6 +22449,7 @@ impl Lightsail for LightsailClient {
         input: GetDiskRequest,
     ) -> Result<GetDiskResult, RusotoError<GetDiskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDisk");
         request.add_header("x-amz-target", "Lightsail_20161128.GetDisk");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18432,6 +22468,7 @@ impl Lightsail for LightsailClient {
         input: GetDiskSnapshotRequest,
     ) -> Result<GetDiskSnapshotResult, RusotoError<GetDiskSnapshotError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDiskSnapshot");
         request.add_header("x-amz-target", "Lightsail_20161128.GetDiskSnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18450,6 +22487,7 @@ impl Lightsail for LightsailClient {
         input: GetDiskSnapshotsRequest,
     ) -> Result<GetDiskSnapshotsResult, RusotoError<GetDiskSnapshotsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDiskSnapshots");
         request.add_header("x-amz-target", "Lightsail_20161128.GetDiskSnapshots");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18468,6 +22506,7 @@ impl Lightsail for LightsailClient {
         input: GetDisksRequest,
     ) -> Result<GetDisksResult, RusotoError<GetDisksError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDisks");
         request.add_header("x-amz-target", "Lightsail_20161128.GetDisks");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18485,6 +22524,7 @@ impl Lightsail for LightsailClient {
         &self,
     ) -> Result<GetDistributionBundlesResult, RusotoError<GetDistributionBundlesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDistributionBundles");
         request.add_header("x-amz-target", "Lightsail_20161128.GetDistributionBundles");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -18506,6 +22546,7 @@ impl Lightsail for LightsailClient {
         RusotoError<GetDistributionLatestCacheResetError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDistributionLatestCacheReset");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.GetDistributionLatestCacheReset",
@@ -18528,6 +22569,7 @@ impl Lightsail for LightsailClient {
         input: GetDistributionMetricDataRequest,
     ) -> Result<GetDistributionMetricDataResult, RusotoError<GetDistributionMetricDataError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDistributionMetricData");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.GetDistributionMetricData",
@@ -18550,6 +22592,7 @@ impl Lightsail for LightsailClient {
         input: GetDistributionsRequest,
     ) -> Result<GetDistributionsResult, RusotoError<GetDistributionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDistributions");
         request.add_header("x-amz-target", "Lightsail_20161128.GetDistributions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18568,6 +22611,7 @@ impl Lightsail for LightsailClient {
         input: GetDomainRequest,
     ) -> Result<GetDomainResult, RusotoError<GetDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDomain");
         request.add_header("x-amz-target", "Lightsail_20161128.GetDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18586,6 +22630,7 @@ impl Lightsail for LightsailClient {
         input: GetDomainsRequest,
     ) -> Result<GetDomainsResult, RusotoError<GetDomainsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDomains");
         request.add_header("x-amz-target", "Lightsail_20161128.GetDomains");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18604,6 +22649,7 @@ impl Lightsail for LightsailClient {
         input: GetExportSnapshotRecordsRequest,
     ) -> Result<GetExportSnapshotRecordsResult, RusotoError<GetExportSnapshotRecordsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetExportSnapshotRecords");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.GetExportSnapshotRecords",
@@ -18626,6 +22672,7 @@ impl Lightsail for LightsailClient {
         input: GetInstanceRequest,
     ) -> Result<GetInstanceResult, RusotoError<GetInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetInstance");
         request.add_header("x-amz-target", "Lightsail_20161128.GetInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18644,6 +22691,7 @@ impl Lightsail for LightsailClient {
         input: GetInstanceAccessDetailsRequest,
     ) -> Result<GetInstanceAccessDetailsResult, RusotoError<GetInstanceAccessDetailsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetInstanceAccessDetails");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.GetInstanceAccessDetails",
@@ -18666,6 +22714,7 @@ impl Lightsail for LightsailClient {
         input: GetInstanceMetricDataRequest,
     ) -> Result<GetInstanceMetricDataResult, RusotoError<GetInstanceMetricDataError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetInstanceMetricData");
         request.add_header("x-amz-target", "Lightsail_20161128.GetInstanceMetricData");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18684,6 +22733,7 @@ impl Lightsail for LightsailClient {
         input: GetInstancePortStatesRequest,
     ) -> Result<GetInstancePortStatesResult, RusotoError<GetInstancePortStatesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetInstancePortStates");
         request.add_header("x-amz-target", "Lightsail_20161128.GetInstancePortStates");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18702,6 +22752,7 @@ impl Lightsail for LightsailClient {
         input: GetInstanceSnapshotRequest,
     ) -> Result<GetInstanceSnapshotResult, RusotoError<GetInstanceSnapshotError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetInstanceSnapshot");
         request.add_header("x-amz-target", "Lightsail_20161128.GetInstanceSnapshot");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18720,6 +22771,7 @@ impl Lightsail for LightsailClient {
         input: GetInstanceSnapshotsRequest,
     ) -> Result<GetInstanceSnapshotsResult, RusotoError<GetInstanceSnapshotsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetInstanceSnapshots");
         request.add_header("x-amz-target", "Lightsail_20161128.GetInstanceSnapshots");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18738,6 +22790,7 @@ impl Lightsail for LightsailClient {
         input: GetInstanceStateRequest,
     ) -> Result<GetInstanceStateResult, RusotoError<GetInstanceStateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetInstanceState");
         request.add_header("x-amz-target", "Lightsail_20161128.GetInstanceState");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18756,6 +22809,7 @@ impl Lightsail for LightsailClient {
         input: GetInstancesRequest,
     ) -> Result<GetInstancesResult, RusotoError<GetInstancesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetInstances");
         request.add_header("x-amz-target", "Lightsail_20161128.GetInstances");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18774,6 +22828,7 @@ impl Lightsail for LightsailClient {
         input: GetKeyPairRequest,
     ) -> Result<GetKeyPairResult, RusotoError<GetKeyPairError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetKeyPair");
         request.add_header("x-amz-target", "Lightsail_20161128.GetKeyPair");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18792,6 +22847,7 @@ impl Lightsail for LightsailClient {
         input: GetKeyPairsRequest,
     ) -> Result<GetKeyPairsResult, RusotoError<GetKeyPairsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetKeyPairs");
         request.add_header("x-amz-target", "Lightsail_20161128.GetKeyPairs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18810,6 +22866,7 @@ impl Lightsail for LightsailClient {
         input: GetLoadBalancerRequest,
     ) -> Result<GetLoadBalancerResult, RusotoError<GetLoadBalancerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetLoadBalancer");
         request.add_header("x-amz-target", "Lightsail_20161128.GetLoadBalancer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18828,6 +22885,7 @@ impl Lightsail for LightsailClient {
         input: GetLoadBalancerMetricDataRequest,
     ) -> Result<GetLoadBalancerMetricDataResult, RusotoError<GetLoadBalancerMetricDataError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetLoadBalancerMetricData");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.GetLoadBalancerMetricData",
@@ -18853,6 +22911,7 @@ impl Lightsail for LightsailClient {
         RusotoError<GetLoadBalancerTlsCertificatesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetLoadBalancerTlsCertificates");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.GetLoadBalancerTlsCertificates",
@@ -18875,6 +22934,7 @@ impl Lightsail for LightsailClient {
         input: GetLoadBalancersRequest,
     ) -> Result<GetLoadBalancersResult, RusotoError<GetLoadBalancersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetLoadBalancers");
         request.add_header("x-amz-target", "Lightsail_20161128.GetLoadBalancers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18893,6 +22953,7 @@ impl Lightsail for LightsailClient {
         input: GetOperationRequest,
     ) -> Result<GetOperationResult, RusotoError<GetOperationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetOperation");
         request.add_header("x-amz-target", "Lightsail_20161128.GetOperation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18911,6 +22972,7 @@ impl Lightsail for LightsailClient {
         input: GetOperationsRequest,
     ) -> Result<GetOperationsResult, RusotoError<GetOperationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetOperations");
         request.add_header("x-amz-target", "Lightsail_20161128.GetOperations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18929,6 +22991,7 @@ impl Lightsail for LightsailClient {
         input: GetOperationsForResourceRequest,
     ) -> Result<GetOperationsForResourceResult, RusotoError<GetOperationsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetOperationsForResource");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.GetOperationsForResource",
@@ -18951,6 +23014,7 @@ impl Lightsail for LightsailClient {
         input: GetRegionsRequest,
     ) -> Result<GetRegionsResult, RusotoError<GetRegionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRegions");
         request.add_header("x-amz-target", "Lightsail_20161128.GetRegions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18969,6 +23033,7 @@ impl Lightsail for LightsailClient {
         input: GetRelationalDatabaseRequest,
     ) -> Result<GetRelationalDatabaseResult, RusotoError<GetRelationalDatabaseError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRelationalDatabase");
         request.add_header("x-amz-target", "Lightsail_20161128.GetRelationalDatabase");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -18990,6 +23055,7 @@ impl Lightsail for LightsailClient {
         RusotoError<GetRelationalDatabaseBlueprintsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRelationalDatabaseBlueprints");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.GetRelationalDatabaseBlueprints",
@@ -19013,6 +23079,7 @@ impl Lightsail for LightsailClient {
     ) -> Result<GetRelationalDatabaseBundlesResult, RusotoError<GetRelationalDatabaseBundlesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRelationalDatabaseBundles");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.GetRelationalDatabaseBundles",
@@ -19036,6 +23103,7 @@ impl Lightsail for LightsailClient {
     ) -> Result<GetRelationalDatabaseEventsResult, RusotoError<GetRelationalDatabaseEventsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRelationalDatabaseEvents");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.GetRelationalDatabaseEvents",
@@ -19061,6 +23129,7 @@ impl Lightsail for LightsailClient {
         RusotoError<GetRelationalDatabaseLogEventsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRelationalDatabaseLogEvents");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.GetRelationalDatabaseLogEvents",
@@ -19086,6 +23155,7 @@ impl Lightsail for LightsailClient {
         RusotoError<GetRelationalDatabaseLogStreamsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRelationalDatabaseLogStreams");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.GetRelationalDatabaseLogStreams",
@@ -19111,6 +23181,7 @@ impl Lightsail for LightsailClient {
         RusotoError<GetRelationalDatabaseMasterUserPasswordError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRelationalDatabaseMasterUserPassword");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.GetRelationalDatabaseMasterUserPassword",
@@ -19139,6 +23210,7 @@ impl Lightsail for LightsailClient {
         RusotoError<GetRelationalDatabaseMetricDataError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRelationalDatabaseMetricData");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.GetRelationalDatabaseMetricData",
@@ -19164,6 +23236,7 @@ impl Lightsail for LightsailClient {
         RusotoError<GetRelationalDatabaseParametersError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRelationalDatabaseParameters");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.GetRelationalDatabaseParameters",
@@ -19187,6 +23260,7 @@ impl Lightsail for LightsailClient {
     ) -> Result<GetRelationalDatabaseSnapshotResult, RusotoError<GetRelationalDatabaseSnapshotError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRelationalDatabaseSnapshot");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.GetRelationalDatabaseSnapshot",
@@ -19212,6 +23286,7 @@ impl Lightsail for LightsailClient {
         RusotoError<GetRelationalDatabaseSnapshotsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRelationalDatabaseSnapshots");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.GetRelationalDatabaseSnapshots",
@@ -19234,6 +23309,7 @@ impl Lightsail for LightsailClient {
         input: GetRelationalDatabasesRequest,
     ) -> Result<GetRelationalDatabasesResult, RusotoError<GetRelationalDatabasesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetRelationalDatabases");
         request.add_header("x-amz-target", "Lightsail_20161128.GetRelationalDatabases");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19253,6 +23329,7 @@ impl Lightsail for LightsailClient {
         input: GetStaticIpRequest,
     ) -> Result<GetStaticIpResult, RusotoError<GetStaticIpError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetStaticIp");
         request.add_header("x-amz-target", "Lightsail_20161128.GetStaticIp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19271,6 +23348,7 @@ impl Lightsail for LightsailClient {
         input: GetStaticIpsRequest,
     ) -> Result<GetStaticIpsResult, RusotoError<GetStaticIpsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetStaticIps");
         request.add_header("x-amz-target", "Lightsail_20161128.GetStaticIps");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19289,6 +23367,7 @@ impl Lightsail for LightsailClient {
         input: ImportKeyPairRequest,
     ) -> Result<ImportKeyPairResult, RusotoError<ImportKeyPairError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ImportKeyPair");
         request.add_header("x-amz-target", "Lightsail_20161128.ImportKeyPair");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19304,6 +23383,7 @@ impl Lightsail for LightsailClient {
     /// <p>Returns a Boolean value indicating whether your Lightsail VPC is peered.</p>
     async fn is_vpc_peered(&self) -> Result<IsVpcPeeredResult, RusotoError<IsVpcPeeredError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("IsVpcPeered");
         request.add_header("x-amz-target", "Lightsail_20161128.IsVpcPeered");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -19321,6 +23401,7 @@ impl Lightsail for LightsailClient {
         input: OpenInstancePublicPortsRequest,
     ) -> Result<OpenInstancePublicPortsResult, RusotoError<OpenInstancePublicPortsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("OpenInstancePublicPorts");
         request.add_header("x-amz-target", "Lightsail_20161128.OpenInstancePublicPorts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19337,6 +23418,7 @@ impl Lightsail for LightsailClient {
     /// <p>Tries to peer the Lightsail VPC with the user's default VPC.</p>
     async fn peer_vpc(&self) -> Result<PeerVpcResult, RusotoError<PeerVpcError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PeerVpc");
         request.add_header("x-amz-target", "Lightsail_20161128.PeerVpc");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -19354,6 +23436,7 @@ impl Lightsail for LightsailClient {
         input: PutAlarmRequest,
     ) -> Result<PutAlarmResult, RusotoError<PutAlarmError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutAlarm");
         request.add_header("x-amz-target", "Lightsail_20161128.PutAlarm");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19372,6 +23455,7 @@ impl Lightsail for LightsailClient {
         input: PutInstancePublicPortsRequest,
     ) -> Result<PutInstancePublicPortsResult, RusotoError<PutInstancePublicPortsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutInstancePublicPorts");
         request.add_header("x-amz-target", "Lightsail_20161128.PutInstancePublicPorts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19391,6 +23475,7 @@ impl Lightsail for LightsailClient {
         input: RebootInstanceRequest,
     ) -> Result<RebootInstanceResult, RusotoError<RebootInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RebootInstance");
         request.add_header("x-amz-target", "Lightsail_20161128.RebootInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19409,6 +23494,7 @@ impl Lightsail for LightsailClient {
         input: RebootRelationalDatabaseRequest,
     ) -> Result<RebootRelationalDatabaseResult, RusotoError<RebootRelationalDatabaseError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RebootRelationalDatabase");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.RebootRelationalDatabase",
@@ -19431,6 +23517,7 @@ impl Lightsail for LightsailClient {
         input: RegisterContainerImageRequest,
     ) -> Result<RegisterContainerImageResult, RusotoError<RegisterContainerImageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RegisterContainerImage");
         request.add_header("x-amz-target", "Lightsail_20161128.RegisterContainerImage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19450,6 +23537,7 @@ impl Lightsail for LightsailClient {
         input: ReleaseStaticIpRequest,
     ) -> Result<ReleaseStaticIpResult, RusotoError<ReleaseStaticIpError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ReleaseStaticIp");
         request.add_header("x-amz-target", "Lightsail_20161128.ReleaseStaticIp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19468,6 +23556,7 @@ impl Lightsail for LightsailClient {
         input: ResetDistributionCacheRequest,
     ) -> Result<ResetDistributionCacheResult, RusotoError<ResetDistributionCacheError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ResetDistributionCache");
         request.add_header("x-amz-target", "Lightsail_20161128.ResetDistributionCache");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19488,6 +23577,7 @@ impl Lightsail for LightsailClient {
     ) -> Result<SendContactMethodVerificationResult, RusotoError<SendContactMethodVerificationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SendContactMethodVerification");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.SendContactMethodVerification",
@@ -19510,6 +23600,7 @@ impl Lightsail for LightsailClient {
         input: StartInstanceRequest,
     ) -> Result<StartInstanceResult, RusotoError<StartInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartInstance");
         request.add_header("x-amz-target", "Lightsail_20161128.StartInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19528,6 +23619,7 @@ impl Lightsail for LightsailClient {
         input: StartRelationalDatabaseRequest,
     ) -> Result<StartRelationalDatabaseResult, RusotoError<StartRelationalDatabaseError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartRelationalDatabase");
         request.add_header("x-amz-target", "Lightsail_20161128.StartRelationalDatabase");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19547,6 +23639,7 @@ impl Lightsail for LightsailClient {
         input: StopInstanceRequest,
     ) -> Result<StopInstanceResult, RusotoError<StopInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopInstance");
         request.add_header("x-amz-target", "Lightsail_20161128.StopInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19565,6 +23658,7 @@ impl Lightsail for LightsailClient {
         input: StopRelationalDatabaseRequest,
     ) -> Result<StopRelationalDatabaseResult, RusotoError<StopRelationalDatabaseError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopRelationalDatabase");
         request.add_header("x-amz-target", "Lightsail_20161128.StopRelationalDatabase");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19584,6 +23678,7 @@ impl Lightsail for LightsailClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResult, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "Lightsail_20161128.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19602,6 +23697,7 @@ impl Lightsail for LightsailClient {
         input: TestAlarmRequest,
     ) -> Result<TestAlarmResult, RusotoError<TestAlarmError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TestAlarm");
         request.add_header("x-amz-target", "Lightsail_20161128.TestAlarm");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19617,6 +23713,7 @@ impl Lightsail for LightsailClient {
     /// <p>Attempts to unpeer the Lightsail VPC from the user's default VPC.</p>
     async fn unpeer_vpc(&self) -> Result<UnpeerVpcResult, RusotoError<UnpeerVpcError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UnpeerVpc");
         request.add_header("x-amz-target", "Lightsail_20161128.UnpeerVpc");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -19634,6 +23731,7 @@ impl Lightsail for LightsailClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResult, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "Lightsail_20161128.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19652,6 +23750,7 @@ impl Lightsail for LightsailClient {
         input: UpdateContainerServiceRequest,
     ) -> Result<UpdateContainerServiceResult, RusotoError<UpdateContainerServiceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateContainerService");
         request.add_header("x-amz-target", "Lightsail_20161128.UpdateContainerService");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19671,6 +23770,7 @@ impl Lightsail for LightsailClient {
         input: UpdateDistributionRequest,
     ) -> Result<UpdateDistributionResult, RusotoError<UpdateDistributionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateDistribution");
         request.add_header("x-amz-target", "Lightsail_20161128.UpdateDistribution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19689,6 +23789,7 @@ impl Lightsail for LightsailClient {
         input: UpdateDistributionBundleRequest,
     ) -> Result<UpdateDistributionBundleResult, RusotoError<UpdateDistributionBundleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateDistributionBundle");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.UpdateDistributionBundle",
@@ -19711,6 +23812,7 @@ impl Lightsail for LightsailClient {
         input: UpdateDomainEntryRequest,
     ) -> Result<UpdateDomainEntryResult, RusotoError<UpdateDomainEntryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateDomainEntry");
         request.add_header("x-amz-target", "Lightsail_20161128.UpdateDomainEntry");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -19730,6 +23832,7 @@ impl Lightsail for LightsailClient {
     ) -> Result<UpdateLoadBalancerAttributeResult, RusotoError<UpdateLoadBalancerAttributeError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateLoadBalancerAttribute");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.UpdateLoadBalancerAttribute",
@@ -19752,6 +23855,7 @@ impl Lightsail for LightsailClient {
         input: UpdateRelationalDatabaseRequest,
     ) -> Result<UpdateRelationalDatabaseResult, RusotoError<UpdateRelationalDatabaseError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateRelationalDatabase");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.UpdateRelationalDatabase",
@@ -19777,6 +23881,7 @@ impl Lightsail for LightsailClient {
         RusotoError<UpdateRelationalDatabaseParametersError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateRelationalDatabaseParameters");
         request.add_header(
             "x-amz-target",
             "Lightsail_20161128.UpdateRelationalDatabaseParameters",
@@ -19796,3 +23901,1352 @@ impl Lightsail for LightsailClient {
             .deserialize::<UpdateRelationalDatabaseParametersResult, _>()
     }
 }
+
+/// A blocking client for the Amazon Lightsail API, running the calls of a `LightsailClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `LightsailClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct LightsailBlockingClient {
+    client: LightsailClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl LightsailBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> LightsailBlockingClient {
+        LightsailBlockingClient::from_client(LightsailClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> LightsailBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        LightsailBlockingClient::from_client(LightsailClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> LightsailBlockingClient {
+        LightsailBlockingClient::from_client(LightsailClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> LightsailBlockingClient {
+        LightsailBlockingClient::from_client(LightsailClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: LightsailClient) -> LightsailBlockingClient {
+        LightsailBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> LightsailBlockingClient {
+        LightsailBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> LightsailBlockingClient {
+        LightsailBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Lightsail::allocate_static_ip`].
+    pub fn allocate_static_ip(
+        &self,
+        input: AllocateStaticIpRequest,
+    ) -> Result<AllocateStaticIpResult, RusotoError<AllocateStaticIpError>> {
+        self.runtime.block_on(self.client.allocate_static_ip(input))
+    }
+
+    /// Blocking version of [`Lightsail::attach_certificate_to_distribution`].
+    pub fn attach_certificate_to_distribution(
+        &self,
+        input: AttachCertificateToDistributionRequest,
+    ) -> Result<
+        AttachCertificateToDistributionResult,
+        RusotoError<AttachCertificateToDistributionError>,
+    > {
+        self.runtime
+            .block_on(self.client.attach_certificate_to_distribution(input))
+    }
+
+    /// Blocking version of [`Lightsail::attach_disk`].
+    pub fn attach_disk(
+        &self,
+        input: AttachDiskRequest,
+    ) -> Result<AttachDiskResult, RusotoError<AttachDiskError>> {
+        self.runtime.block_on(self.client.attach_disk(input))
+    }
+
+    /// Blocking version of [`Lightsail::attach_instances_to_load_balancer`].
+    pub fn attach_instances_to_load_balancer(
+        &self,
+        input: AttachInstancesToLoadBalancerRequest,
+    ) -> Result<AttachInstancesToLoadBalancerResult, RusotoError<AttachInstancesToLoadBalancerError>>
+    {
+        self.runtime
+            .block_on(self.client.attach_instances_to_load_balancer(input))
+    }
+
+    /// Blocking version of [`Lightsail::attach_load_balancer_tls_certificate`].
+    pub fn attach_load_balancer_tls_certificate(
+        &self,
+        input: AttachLoadBalancerTlsCertificateRequest,
+    ) -> Result<
+        AttachLoadBalancerTlsCertificateResult,
+        RusotoError<AttachLoadBalancerTlsCertificateError>,
+    > {
+        self.runtime
+            .block_on(self.client.attach_load_balancer_tls_certificate(input))
+    }
+
+    /// Blocking version of [`Lightsail::attach_static_ip`].
+    pub fn attach_static_ip(
+        &self,
+        input: AttachStaticIpRequest,
+    ) -> Result<AttachStaticIpResult, RusotoError<AttachStaticIpError>> {
+        self.runtime.block_on(self.client.attach_static_ip(input))
+    }
+
+    /// Blocking version of [`Lightsail::close_instance_public_ports`].
+    pub fn close_instance_public_ports(
+        &self,
+        input: CloseInstancePublicPortsRequest,
+    ) -> Result<CloseInstancePublicPortsResult, RusotoError<CloseInstancePublicPortsError>> {
+        self.runtime
+            .block_on(self.client.close_instance_public_ports(input))
+    }
+
+    /// Blocking version of [`Lightsail::copy_snapshot`].
+    pub fn copy_snapshot(
+        &self,
+        input: CopySnapshotRequest,
+    ) -> Result<CopySnapshotResult, RusotoError<CopySnapshotError>> {
+        self.runtime.block_on(self.client.copy_snapshot(input))
+    }
+
+    /// Blocking version of [`Lightsail::create_certificate`].
+    pub fn create_certificate(
+        &self,
+        input: CreateCertificateRequest,
+    ) -> Result<CreateCertificateResult, RusotoError<CreateCertificateError>> {
+        self.runtime.block_on(self.client.create_certificate(input))
+    }
+
+    /// Blocking version of [`Lightsail::create_cloud_formation_stack`].
+    pub fn create_cloud_formation_stack(
+        &self,
+        input: CreateCloudFormationStackRequest,
+    ) -> Result<CreateCloudFormationStackResult, RusotoError<CreateCloudFormationStackError>> {
+        self.runtime
+            .block_on(self.client.create_cloud_formation_stack(input))
+    }
+
+    /// Blocking version of [`Lightsail::create_contact_method`].
+    pub fn create_contact_method(
+        &self,
+        input: CreateContactMethodRequest,
+    ) -> Result<CreateContactMethodResult, RusotoError<CreateContactMethodError>> {
+        self.runtime
+            .block_on(self.client.create_contact_method(input))
+    }
+
+    /// Blocking version of [`Lightsail::create_container_service`].
+    pub fn create_container_service(
+        &self,
+        input: CreateContainerServiceRequest,
+    ) -> Result<CreateContainerServiceResult, RusotoError<CreateContainerServiceError>> {
+        self.runtime
+            .block_on(self.client.create_container_service(input))
+    }
+
+    /// Blocking version of [`Lightsail::create_container_service_deployment`].
+    pub fn create_container_service_deployment(
+        &self,
+        input: CreateContainerServiceDeploymentRequest,
+    ) -> Result<
+        CreateContainerServiceDeploymentResult,
+        RusotoError<CreateContainerServiceDeploymentError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_container_service_deployment(input))
+    }
+
+    /// Blocking version of [`Lightsail::create_container_service_registry_login`].
+    pub fn create_container_service_registry_login(
+        &self,
+    ) -> Result<
+        CreateContainerServiceRegistryLoginResult,
+        RusotoError<CreateContainerServiceRegistryLoginError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_container_service_registry_login())
+    }
+
+    /// Blocking version of [`Lightsail::create_disk`].
+    pub fn create_disk(
+        &self,
+        input: CreateDiskRequest,
+    ) -> Result<CreateDiskResult, RusotoError<CreateDiskError>> {
+        self.runtime.block_on(self.client.create_disk(input))
+    }
+
+    /// Blocking version of [`Lightsail::create_disk_from_snapshot`].
+    pub fn create_disk_from_snapshot(
+        &self,
+        input: CreateDiskFromSnapshotRequest,
+    ) -> Result<CreateDiskFromSnapshotResult, RusotoError<CreateDiskFromSnapshotError>> {
+        self.runtime
+            .block_on(self.client.create_disk_from_snapshot(input))
+    }
+
+    /// Blocking version of [`Lightsail::create_disk_snapshot`].
+    pub fn create_disk_snapshot(
+        &self,
+        input: CreateDiskSnapshotRequest,
+    ) -> Result<CreateDiskSnapshotResult, RusotoError<CreateDiskSnapshotError>> {
+        self.runtime
+            .block_on(self.client.create_disk_snapshot(input))
+    }
+
+    /// Blocking version of [`Lightsail::create_distribution`].
+    pub fn create_distribution(
+        &self,
+        input: CreateDistributionRequest,
+    ) -> Result<CreateDistributionResult, RusotoError<CreateDistributionError>> {
+        self.runtime
+            .block_on(self.client.create_distribution(input))
+    }
+
+    /// Blocking version of [`Lightsail::create_domain`].
+    pub fn create_domain(
+        &self,
+        input: CreateDomainRequest,
+    ) -> Result<CreateDomainResult, RusotoError<CreateDomainError>> {
+        self.runtime.block_on(self.client.create_domain(input))
+    }
+
+    /// Blocking version of [`Lightsail::create_domain_entry`].
+    pub fn create_domain_entry(
+        &self,
+        input: CreateDomainEntryRequest,
+    ) -> Result<CreateDomainEntryResult, RusotoError<CreateDomainEntryError>> {
+        self.runtime
+            .block_on(self.client.create_domain_entry(input))
+    }
+
+    /// Blocking version of [`Lightsail::create_instance_snapshot`].
+    pub fn create_instance_snapshot(
+        &self,
+        input: CreateInstanceSnapshotRequest,
+    ) -> Result<CreateInstanceSnapshotResult, RusotoError<CreateInstanceSnapshotError>> {
+        self.runtime
+            .block_on(self.client.create_instance_snapshot(input))
+    }
+
+    /// Blocking version of [`Lightsail::create_instances`].
+    pub fn create_instances(
+        &self,
+        input: CreateInstancesRequest,
+    ) -> Result<CreateInstancesResult, RusotoError<CreateInstancesError>> {
+        self.runtime.block_on(self.client.create_instances(input))
+    }
+
+    /// Blocking version of [`Lightsail::create_instances_from_snapshot`].
+    pub fn create_instances_from_snapshot(
+        &self,
+        input: CreateInstancesFromSnapshotRequest,
+    ) -> Result<CreateInstancesFromSnapshotResult, RusotoError<CreateInstancesFromSnapshotError>>
+    {
+        self.runtime
+            .block_on(self.client.create_instances_from_snapshot(input))
+    }
+
+    /// Blocking version of [`Lightsail::create_key_pair`].
+    pub fn create_key_pair(
+        &self,
+        input: CreateKeyPairRequest,
+    ) -> Result<CreateKeyPairResult, RusotoError<CreateKeyPairError>> {
+        self.runtime.block_on(self.client.create_key_pair(input))
+    }
+
+    /// Blocking version of [`Lightsail::create_load_balancer`].
+    pub fn create_load_balancer(
+        &self,
+        input: CreateLoadBalancerRequest,
+    ) -> Result<CreateLoadBalancerResult, RusotoError<CreateLoadBalancerError>> {
+        self.runtime
+            .block_on(self.client.create_load_balancer(input))
+    }
+
+    /// Blocking version of [`Lightsail::create_load_balancer_tls_certificate`].
+    pub fn create_load_balancer_tls_certificate(
+        &self,
+        input: CreateLoadBalancerTlsCertificateRequest,
+    ) -> Result<
+        CreateLoadBalancerTlsCertificateResult,
+        RusotoError<CreateLoadBalancerTlsCertificateError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_load_balancer_tls_certificate(input))
+    }
+
+    /// Blocking version of [`Lightsail::create_relational_database`].
+    pub fn create_relational_database(
+        &self,
+        input: CreateRelationalDatabaseRequest,
+    ) -> Result<CreateRelationalDatabaseResult, RusotoError<CreateRelationalDatabaseError>> {
+        self.runtime
+            .block_on(self.client.create_relational_database(input))
+    }
+
+    /// Blocking version of [`Lightsail::create_relational_database_from_snapshot`].
+    pub fn create_relational_database_from_snapshot(
+        &self,
+        input: CreateRelationalDatabaseFromSnapshotRequest,
+    ) -> Result<
+        CreateRelationalDatabaseFromSnapshotResult,
+        RusotoError<CreateRelationalDatabaseFromSnapshotError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_relational_database_from_snapshot(input))
+    }
+
+    /// Blocking version of [`Lightsail::create_relational_database_snapshot`].
+    pub fn create_relational_database_snapshot(
+        &self,
+        input: CreateRelationalDatabaseSnapshotRequest,
+    ) -> Result<
+        CreateRelationalDatabaseSnapshotResult,
+        RusotoError<CreateRelationalDatabaseSnapshotError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_relational_database_snapshot(input))
+    }
+
+    /// Blocking version of [`Lightsail::delete_alarm`].
+    pub fn delete_alarm(
+        &self,
+        input: DeleteAlarmRequest,
+    ) -> Result<DeleteAlarmResult, RusotoError<DeleteAlarmError>> {
+        self.runtime.block_on(self.client.delete_alarm(input))
+    }
+
+    /// Blocking version of [`Lightsail::delete_auto_snapshot`].
+    pub fn delete_auto_snapshot(
+        &self,
+        input: DeleteAutoSnapshotRequest,
+    ) -> Result<DeleteAutoSnapshotResult, RusotoError<DeleteAutoSnapshotError>> {
+        self.runtime
+            .block_on(self.client.delete_auto_snapshot(input))
+    }
+
+    /// Blocking version of [`Lightsail::delete_certificate`].
+    pub fn delete_certificate(
+        &self,
+        input: DeleteCertificateRequest,
+    ) -> Result<DeleteCertificateResult, RusotoError<DeleteCertificateError>> {
+        self.runtime.block_on(self.client.delete_certificate(input))
+    }
+
+    /// Blocking version of [`Lightsail::delete_contact_method`].
+    pub fn delete_contact_method(
+        &self,
+        input: DeleteContactMethodRequest,
+    ) -> Result<DeleteContactMethodResult, RusotoError<DeleteContactMethodError>> {
+        self.runtime
+            .block_on(self.client.delete_contact_method(input))
+    }
+
+    /// Blocking version of [`Lightsail::delete_container_image`].
+    pub fn delete_container_image(
+        &self,
+        input: DeleteContainerImageRequest,
+    ) -> Result<DeleteContainerImageResult, RusotoError<DeleteContainerImageError>> {
+        self.runtime
+            .block_on(self.client.delete_container_image(input))
+    }
+
+    /// Blocking version of [`Lightsail::delete_container_service`].
+    pub fn delete_container_service(
+        &self,
+        input: DeleteContainerServiceRequest,
+    ) -> Result<DeleteContainerServiceResult, RusotoError<DeleteContainerServiceError>> {
+        self.runtime
+            .block_on(self.client.delete_container_service(input))
+    }
+
+    /// Blocking version of [`Lightsail::delete_disk`].
+    pub fn delete_disk(
+        &self,
+        input: DeleteDiskRequest,
+    ) -> Result<DeleteDiskResult, RusotoError<DeleteDiskError>> {
+        self.runtime.block_on(self.client.delete_disk(input))
+    }
+
+    /// Blocking version of [`Lightsail::delete_disk_snapshot`].
+    pub fn delete_disk_snapshot(
+        &self,
+        input: DeleteDiskSnapshotRequest,
+    ) -> Result<DeleteDiskSnapshotResult, RusotoError<DeleteDiskSnapshotError>> {
+        self.runtime
+            .block_on(self.client.delete_disk_snapshot(input))
+    }
+
+    /// Blocking version of [`Lightsail::delete_distribution`].
+    pub fn delete_distribution(
+        &self,
+        input: DeleteDistributionRequest,
+    ) -> Result<DeleteDistributionResult, RusotoError<DeleteDistributionError>> {
+        self.runtime
+            .block_on(self.client.delete_distribution(input))
+    }
+
+    /// Blocking version of [`Lightsail::delete_domain`].
+    pub fn delete_domain(
+        &self,
+        input: DeleteDomainRequest,
+    ) -> Result<DeleteDomainResult, RusotoError<DeleteDomainError>> {
+        self.runtime.block_on(self.client.delete_domain(input))
+    }
+
+    /// Blocking version of [`Lightsail::delete_domain_entry`].
+    pub fn delete_domain_entry(
+        &self,
+        input: DeleteDomainEntryRequest,
+    ) -> Result<DeleteDomainEntryResult, RusotoError<DeleteDomainEntryError>> {
+        self.runtime
+            .block_on(self.client.delete_domain_entry(input))
+    }
+
+    /// Blocking version of [`Lightsail::delete_instance`].
+    pub fn delete_instance(
+        &self,
+        input: DeleteInstanceRequest,
+    ) -> Result<DeleteInstanceResult, RusotoError<DeleteInstanceError>> {
+        self.runtime.block_on(self.client.delete_instance(input))
+    }
+
+    /// Blocking version of [`Lightsail::delete_instance_snapshot`].
+    pub fn delete_instance_snapshot(
+        &self,
+        input: DeleteInstanceSnapshotRequest,
+    ) -> Result<DeleteInstanceSnapshotResult, RusotoError<DeleteInstanceSnapshotError>> {
+        self.runtime
+            .block_on(self.client.delete_instance_snapshot(input))
+    }
+
+    /// Blocking version of [`Lightsail::delete_key_pair`].
+    pub fn delete_key_pair(
+        &self,
+        input: DeleteKeyPairRequest,
+    ) -> Result<DeleteKeyPairResult, RusotoError<DeleteKeyPairError>> {
+        self.runtime.block_on(self.client.delete_key_pair(input))
+    }
+
+    /// Blocking version of [`Lightsail::delete_known_host_keys`].
+    pub fn delete_known_host_keys(
+        &self,
+        input: DeleteKnownHostKeysRequest,
+    ) -> Result<DeleteKnownHostKeysResult, RusotoError<DeleteKnownHostKeysError>> {
+        self.runtime
+            .block_on(self.client.delete_known_host_keys(input))
+    }
+
+    /// Blocking version of [`Lightsail::delete_load_balancer`].
+    pub fn delete_load_balancer(
+        &self,
+        input: DeleteLoadBalancerRequest,
+    ) -> Result<DeleteLoadBalancerResult, RusotoError<DeleteLoadBalancerError>> {
+        self.runtime
+            .block_on(self.client.delete_load_balancer(input))
+    }
+
+    /// Blocking version of [`Lightsail::delete_load_balancer_tls_certificate`].
+    pub fn delete_load_balancer_tls_certificate(
+        &self,
+        input: DeleteLoadBalancerTlsCertificateRequest,
+    ) -> Result<
+        DeleteLoadBalancerTlsCertificateResult,
+        RusotoError<DeleteLoadBalancerTlsCertificateError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_load_balancer_tls_certificate(input))
+    }
+
+    /// Blocking version of [`Lightsail::delete_relational_database`].
+    pub fn delete_relational_database(
+        &self,
+        input: DeleteRelationalDatabaseRequest,
+    ) -> Result<DeleteRelationalDatabaseResult, RusotoError<DeleteRelationalDatabaseError>> {
+        self.runtime
+            .block_on(self.client.delete_relational_database(input))
+    }
+
+    /// Blocking version of [`Lightsail::delete_relational_database_snapshot`].
+    pub fn delete_relational_database_snapshot(
+        &self,
+        input: DeleteRelationalDatabaseSnapshotRequest,
+    ) -> Result<
+        DeleteRelationalDatabaseSnapshotResult,
+        RusotoError<DeleteRelationalDatabaseSnapshotError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_relational_database_snapshot(input))
+    }
+
+    /// Blocking version of [`Lightsail::detach_certificate_from_distribution`].
+    pub fn detach_certificate_from_distribution(
+        &self,
+        input: DetachCertificateFromDistributionRequest,
+    ) -> Result<
+        DetachCertificateFromDistributionResult,
+        RusotoError<DetachCertificateFromDistributionError>,
+    > {
+        self.runtime
+            .block_on(self.client.detach_certificate_from_distribution(input))
+    }
+
+    /// Blocking version of [`Lightsail::detach_disk`].
+    pub fn detach_disk(
+        &self,
+        input: DetachDiskRequest,
+    ) -> Result<DetachDiskResult, RusotoError<DetachDiskError>> {
+        self.runtime.block_on(self.client.detach_disk(input))
+    }
+
+    /// Blocking version of [`Lightsail::detach_instances_from_load_balancer`].
+    pub fn detach_instances_from_load_balancer(
+        &self,
+        input: DetachInstancesFromLoadBalancerRequest,
+    ) -> Result<
+        DetachInstancesFromLoadBalancerResult,
+        RusotoError<DetachInstancesFromLoadBalancerError>,
+    > {
+        self.runtime
+            .block_on(self.client.detach_instances_from_load_balancer(input))
+    }
+
+    /// Blocking version of [`Lightsail::detach_static_ip`].
+    pub fn detach_static_ip(
+        &self,
+        input: DetachStaticIpRequest,
+    ) -> Result<DetachStaticIpResult, RusotoError<DetachStaticIpError>> {
+        self.runtime.block_on(self.client.detach_static_ip(input))
+    }
+
+    /// Blocking version of [`Lightsail::disable_add_on`].
+    pub fn disable_add_on(
+        &self,
+        input: DisableAddOnRequest,
+    ) -> Result<DisableAddOnResult, RusotoError<DisableAddOnError>> {
+        self.runtime.block_on(self.client.disable_add_on(input))
+    }
+
+    /// Blocking version of [`Lightsail::download_default_key_pair`].
+    pub fn download_default_key_pair(
+        &self,
+    ) -> Result<DownloadDefaultKeyPairResult, RusotoError<DownloadDefaultKeyPairError>> {
+        self.runtime
+            .block_on(self.client.download_default_key_pair())
+    }
+
+    /// Blocking version of [`Lightsail::enable_add_on`].
+    pub fn enable_add_on(
+        &self,
+        input: EnableAddOnRequest,
+    ) -> Result<EnableAddOnResult, RusotoError<EnableAddOnError>> {
+        self.runtime.block_on(self.client.enable_add_on(input))
+    }
+
+    /// Blocking version of [`Lightsail::export_snapshot`].
+    pub fn export_snapshot(
+        &self,
+        input: ExportSnapshotRequest,
+    ) -> Result<ExportSnapshotResult, RusotoError<ExportSnapshotError>> {
+        self.runtime.block_on(self.client.export_snapshot(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_active_names`].
+    pub fn get_active_names(
+        &self,
+        input: GetActiveNamesRequest,
+    ) -> Result<GetActiveNamesResult, RusotoError<GetActiveNamesError>> {
+        self.runtime.block_on(self.client.get_active_names(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_alarms`].
+    pub fn get_alarms(
+        &self,
+        input: GetAlarmsRequest,
+    ) -> Result<GetAlarmsResult, RusotoError<GetAlarmsError>> {
+        self.runtime.block_on(self.client.get_alarms(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_auto_snapshots`].
+    pub fn get_auto_snapshots(
+        &self,
+        input: GetAutoSnapshotsRequest,
+    ) -> Result<GetAutoSnapshotsResult, RusotoError<GetAutoSnapshotsError>> {
+        self.runtime.block_on(self.client.get_auto_snapshots(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_blueprints`].
+    pub fn get_blueprints(
+        &self,
+        input: GetBlueprintsRequest,
+    ) -> Result<GetBlueprintsResult, RusotoError<GetBlueprintsError>> {
+        self.runtime.block_on(self.client.get_blueprints(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_bundles`].
+    pub fn get_bundles(
+        &self,
+        input: GetBundlesRequest,
+    ) -> Result<GetBundlesResult, RusotoError<GetBundlesError>> {
+        self.runtime.block_on(self.client.get_bundles(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_certificates`].
+    pub fn get_certificates(
+        &self,
+        input: GetCertificatesRequest,
+    ) -> Result<GetCertificatesResult, RusotoError<GetCertificatesError>> {
+        self.runtime.block_on(self.client.get_certificates(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_cloud_formation_stack_records`].
+    pub fn get_cloud_formation_stack_records(
+        &self,
+        input: GetCloudFormationStackRecordsRequest,
+    ) -> Result<GetCloudFormationStackRecordsResult, RusotoError<GetCloudFormationStackRecordsError>>
+    {
+        self.runtime
+            .block_on(self.client.get_cloud_formation_stack_records(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_contact_methods`].
+    pub fn get_contact_methods(
+        &self,
+        input: GetContactMethodsRequest,
+    ) -> Result<GetContactMethodsResult, RusotoError<GetContactMethodsError>> {
+        self.runtime
+            .block_on(self.client.get_contact_methods(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_container_api_metadata`].
+    pub fn get_container_api_metadata(
+        &self,
+    ) -> Result<GetContainerAPIMetadataResult, RusotoError<GetContainerAPIMetadataError>> {
+        self.runtime
+            .block_on(self.client.get_container_api_metadata())
+    }
+
+    /// Blocking version of [`Lightsail::get_container_images`].
+    pub fn get_container_images(
+        &self,
+        input: GetContainerImagesRequest,
+    ) -> Result<GetContainerImagesResult, RusotoError<GetContainerImagesError>> {
+        self.runtime
+            .block_on(self.client.get_container_images(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_container_log`].
+    pub fn get_container_log(
+        &self,
+        input: GetContainerLogRequest,
+    ) -> Result<GetContainerLogResult, RusotoError<GetContainerLogError>> {
+        self.runtime.block_on(self.client.get_container_log(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_container_service_deployments`].
+    pub fn get_container_service_deployments(
+        &self,
+        input: GetContainerServiceDeploymentsRequest,
+    ) -> Result<
+        GetContainerServiceDeploymentsResult,
+        RusotoError<GetContainerServiceDeploymentsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_container_service_deployments(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_container_service_metric_data`].
+    pub fn get_container_service_metric_data(
+        &self,
+        input: GetContainerServiceMetricDataRequest,
+    ) -> Result<GetContainerServiceMetricDataResult, RusotoError<GetContainerServiceMetricDataError>>
+    {
+        self.runtime
+            .block_on(self.client.get_container_service_metric_data(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_container_service_powers`].
+    pub fn get_container_service_powers(
+        &self,
+    ) -> Result<GetContainerServicePowersResult, RusotoError<GetContainerServicePowersError>> {
+        self.runtime
+            .block_on(self.client.get_container_service_powers())
+    }
+
+    /// Blocking version of [`Lightsail::get_container_services`].
+    pub fn get_container_services(
+        &self,
+        input: GetContainerServicesRequest,
+    ) -> Result<ContainerServicesListResult, RusotoError<GetContainerServicesError>> {
+        self.runtime
+            .block_on(self.client.get_container_services(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_disk`].
+    pub fn get_disk(
+        &self,
+        input: GetDiskRequest,
+    ) -> Result<GetDiskResult, RusotoError<GetDiskError>> {
+        self.runtime.block_on(self.client.get_disk(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_disk_snapshot`].
+    pub fn get_disk_snapshot(
+        &self,
+        input: GetDiskSnapshotRequest,
+    ) -> Result<GetDiskSnapshotResult, RusotoError<GetDiskSnapshotError>> {
+        self.runtime.block_on(self.client.get_disk_snapshot(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_disk_snapshots`].
+    pub fn get_disk_snapshots(
+        &self,
+        input: GetDiskSnapshotsRequest,
+    ) -> Result<GetDiskSnapshotsResult, RusotoError<GetDiskSnapshotsError>> {
+        self.runtime.block_on(self.client.get_disk_snapshots(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_disks`].
+    pub fn get_disks(
+        &self,
+        input: GetDisksRequest,
+    ) -> Result<GetDisksResult, RusotoError<GetDisksError>> {
+        self.runtime.block_on(self.client.get_disks(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_distribution_bundles`].
+    pub fn get_distribution_bundles(
+        &self,
+    ) -> Result<GetDistributionBundlesResult, RusotoError<GetDistributionBundlesError>> {
+        self.runtime
+            .block_on(self.client.get_distribution_bundles())
+    }
+
+    /// Blocking version of [`Lightsail::get_distribution_latest_cache_reset`].
+    pub fn get_distribution_latest_cache_reset(
+        &self,
+        input: GetDistributionLatestCacheResetRequest,
+    ) -> Result<
+        GetDistributionLatestCacheResetResult,
+        RusotoError<GetDistributionLatestCacheResetError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_distribution_latest_cache_reset(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_distribution_metric_data`].
+    pub fn get_distribution_metric_data(
+        &self,
+        input: GetDistributionMetricDataRequest,
+    ) -> Result<GetDistributionMetricDataResult, RusotoError<GetDistributionMetricDataError>> {
+        self.runtime
+            .block_on(self.client.get_distribution_metric_data(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_distributions`].
+    pub fn get_distributions(
+        &self,
+        input: GetDistributionsRequest,
+    ) -> Result<GetDistributionsResult, RusotoError<GetDistributionsError>> {
+        self.runtime.block_on(self.client.get_distributions(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_domain`].
+    pub fn get_domain(
+        &self,
+        input: GetDomainRequest,
+    ) -> Result<GetDomainResult, RusotoError<GetDomainError>> {
+        self.runtime.block_on(self.client.get_domain(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_domains`].
+    pub fn get_domains(
+        &self,
+        input: GetDomainsRequest,
+    ) -> Result<GetDomainsResult, RusotoError<GetDomainsError>> {
+        self.runtime.block_on(self.client.get_domains(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_export_snapshot_records`].
+    pub fn get_export_snapshot_records(
+        &self,
+        input: GetExportSnapshotRecordsRequest,
+    ) -> Result<GetExportSnapshotRecordsResult, RusotoError<GetExportSnapshotRecordsError>> {
+        self.runtime
+            .block_on(self.client.get_export_snapshot_records(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_instance`].
+    pub fn get_instance(
+        &self,
+        input: GetInstanceRequest,
+    ) -> Result<GetInstanceResult, RusotoError<GetInstanceError>> {
+        self.runtime.block_on(self.client.get_instance(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_instance_access_details`].
+    pub fn get_instance_access_details(
+        &self,
+        input: GetInstanceAccessDetailsRequest,
+    ) -> Result<GetInstanceAccessDetailsResult, RusotoError<GetInstanceAccessDetailsError>> {
+        self.runtime
+            .block_on(self.client.get_instance_access_details(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_instance_metric_data`].
+    pub fn get_instance_metric_data(
+        &self,
+        input: GetInstanceMetricDataRequest,
+    ) -> Result<GetInstanceMetricDataResult, RusotoError<GetInstanceMetricDataError>> {
+        self.runtime
+            .block_on(self.client.get_instance_metric_data(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_instance_port_states`].
+    pub fn get_instance_port_states(
+        &self,
+        input: GetInstancePortStatesRequest,
+    ) -> Result<GetInstancePortStatesResult, RusotoError<GetInstancePortStatesError>> {
+        self.runtime
+            .block_on(self.client.get_instance_port_states(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_instance_snapshot`].
+    pub fn get_instance_snapshot(
+        &self,
+        input: GetInstanceSnapshotRequest,
+    ) -> Result<GetInstanceSnapshotResult, RusotoError<GetInstanceSnapshotError>> {
+        self.runtime
+            .block_on(self.client.get_instance_snapshot(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_instance_snapshots`].
+    pub fn get_instance_snapshots(
+        &self,
+        input: GetInstanceSnapshotsRequest,
+    ) -> Result<GetInstanceSnapshotsResult, RusotoError<GetInstanceSnapshotsError>> {
+        self.runtime
+            .block_on(self.client.get_instance_snapshots(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_instance_state`].
+    pub fn get_instance_state(
+        &self,
+        input: GetInstanceStateRequest,
+    ) -> Result<GetInstanceStateResult, RusotoError<GetInstanceStateError>> {
+        self.runtime.block_on(self.client.get_instance_state(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_instances`].
+    pub fn get_instances(
+        &self,
+        input: GetInstancesRequest,
+    ) -> Result<GetInstancesResult, RusotoError<GetInstancesError>> {
+        self.runtime.block_on(self.client.get_instances(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_key_pair`].
+    pub fn get_key_pair(
+        &self,
+        input: GetKeyPairRequest,
+    ) -> Result<GetKeyPairResult, RusotoError<GetKeyPairError>> {
+        self.runtime.block_on(self.client.get_key_pair(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_key_pairs`].
+    pub fn get_key_pairs(
+        &self,
+        input: GetKeyPairsRequest,
+    ) -> Result<GetKeyPairsResult, RusotoError<GetKeyPairsError>> {
+        self.runtime.block_on(self.client.get_key_pairs(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_load_balancer`].
+    pub fn get_load_balancer(
+        &self,
+        input: GetLoadBalancerRequest,
+    ) -> Result<GetLoadBalancerResult, RusotoError<GetLoadBalancerError>> {
+        self.runtime.block_on(self.client.get_load_balancer(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_load_balancer_metric_data`].
+    pub fn get_load_balancer_metric_data(
+        &self,
+        input: GetLoadBalancerMetricDataRequest,
+    ) -> Result<GetLoadBalancerMetricDataResult, RusotoError<GetLoadBalancerMetricDataError>> {
+        self.runtime
+            .block_on(self.client.get_load_balancer_metric_data(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_load_balancer_tls_certificates`].
+    pub fn get_load_balancer_tls_certificates(
+        &self,
+        input: GetLoadBalancerTlsCertificatesRequest,
+    ) -> Result<
+        GetLoadBalancerTlsCertificatesResult,
+        RusotoError<GetLoadBalancerTlsCertificatesError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_load_balancer_tls_certificates(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_load_balancers`].
+    pub fn get_load_balancers(
+        &self,
+        input: GetLoadBalancersRequest,
+    ) -> Result<GetLoadBalancersResult, RusotoError<GetLoadBalancersError>> {
+        self.runtime.block_on(self.client.get_load_balancers(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_operation`].
+    pub fn get_operation(
+        &self,
+        input: GetOperationRequest,
+    ) -> Result<GetOperationResult, RusotoError<GetOperationError>> {
+        self.runtime.block_on(self.client.get_operation(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_operations`].
+    pub fn get_operations(
+        &self,
+        input: GetOperationsRequest,
+    ) -> Result<GetOperationsResult, RusotoError<GetOperationsError>> {
+        self.runtime.block_on(self.client.get_operations(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_operations_for_resource`].
+    pub fn get_operations_for_resource(
+        &self,
+        input: GetOperationsForResourceRequest,
+    ) -> Result<GetOperationsForResourceResult, RusotoError<GetOperationsForResourceError>> {
+        self.runtime
+            .block_on(self.client.get_operations_for_resource(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_regions`].
+    pub fn get_regions(
+        &self,
+        input: GetRegionsRequest,
+    ) -> Result<GetRegionsResult, RusotoError<GetRegionsError>> {
+        self.runtime.block_on(self.client.get_regions(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_relational_database`].
+    pub fn get_relational_database(
+        &self,
+        input: GetRelationalDatabaseRequest,
+    ) -> Result<GetRelationalDatabaseResult, RusotoError<GetRelationalDatabaseError>> {
+        self.runtime
+            .block_on(self.client.get_relational_database(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_relational_database_blueprints`].
+    pub fn get_relational_database_blueprints(
+        &self,
+        input: GetRelationalDatabaseBlueprintsRequest,
+    ) -> Result<
+        GetRelationalDatabaseBlueprintsResult,
+        RusotoError<GetRelationalDatabaseBlueprintsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_relational_database_blueprints(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_relational_database_bundles`].
+    pub fn get_relational_database_bundles(
+        &self,
+        input: GetRelationalDatabaseBundlesRequest,
+    ) -> Result<GetRelationalDatabaseBundlesResult, RusotoError<GetRelationalDatabaseBundlesError>>
+    {
+        self.runtime
+            .block_on(self.client.get_relational_database_bundles(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_relational_database_events`].
+    pub fn get_relational_database_events(
+        &self,
+        input: GetRelationalDatabaseEventsRequest,
+    ) -> Result<GetRelationalDatabaseEventsResult, RusotoError<GetRelationalDatabaseEventsError>>
+    {
+        self.runtime
+            .block_on(self.client.get_relational_database_events(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_relational_database_log_events`].
+    pub fn get_relational_database_log_events(
+        &self,
+        input: GetRelationalDatabaseLogEventsRequest,
+    ) -> Result<
+        GetRelationalDatabaseLogEventsResult,
+        RusotoError<GetRelationalDatabaseLogEventsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_relational_database_log_events(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_relational_database_log_streams`].
+    pub fn get_relational_database_log_streams(
+        &self,
+        input: GetRelationalDatabaseLogStreamsRequest,
+    ) -> Result<
+        GetRelationalDatabaseLogStreamsResult,
+        RusotoError<GetRelationalDatabaseLogStreamsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_relational_database_log_streams(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_relational_database_master_user_password`].
+    pub fn get_relational_database_master_user_password(
+        &self,
+        input: GetRelationalDatabaseMasterUserPasswordRequest,
+    ) -> Result<
+        GetRelationalDatabaseMasterUserPasswordResult,
+        RusotoError<GetRelationalDatabaseMasterUserPasswordError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .get_relational_database_master_user_password(input),
+        )
+    }
+
+    /// Blocking version of [`Lightsail::get_relational_database_metric_data`].
+    pub fn get_relational_database_metric_data(
+        &self,
+        input: GetRelationalDatabaseMetricDataRequest,
+    ) -> Result<
+        GetRelationalDatabaseMetricDataResult,
+        RusotoError<GetRelationalDatabaseMetricDataError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_relational_database_metric_data(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_relational_database_parameters`].
+    pub fn get_relational_database_parameters(
+        &self,
+        input: GetRelationalDatabaseParametersRequest,
+    ) -> Result<
+        GetRelationalDatabaseParametersResult,
+        RusotoError<GetRelationalDatabaseParametersError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_relational_database_parameters(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_relational_database_snapshot`].
+    pub fn get_relational_database_snapshot(
+        &self,
+        input: GetRelationalDatabaseSnapshotRequest,
+    ) -> Result<GetRelationalDatabaseSnapshotResult, RusotoError<GetRelationalDatabaseSnapshotError>>
+    {
+        self.runtime
+            .block_on(self.client.get_relational_database_snapshot(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_relational_database_snapshots`].
+    pub fn get_relational_database_snapshots(
+        &self,
+        input: GetRelationalDatabaseSnapshotsRequest,
+    ) -> Result<
+        GetRelationalDatabaseSnapshotsResult,
+        RusotoError<GetRelationalDatabaseSnapshotsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_relational_database_snapshots(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_relational_databases`].
+    pub fn get_relational_databases(
+        &self,
+        input: GetRelationalDatabasesRequest,
+    ) -> Result<GetRelationalDatabasesResult, RusotoError<GetRelationalDatabasesError>> {
+        self.runtime
+            .block_on(self.client.get_relational_databases(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_static_ip`].
+    pub fn get_static_ip(
+        &self,
+        input: GetStaticIpRequest,
+    ) -> Result<GetStaticIpResult, RusotoError<GetStaticIpError>> {
+        self.runtime.block_on(self.client.get_static_ip(input))
+    }
+
+    /// Blocking version of [`Lightsail::get_static_ips`].
+    pub fn get_static_ips(
+        &self,
+        input: GetStaticIpsRequest,
+    ) -> Result<GetStaticIpsResult, RusotoError<GetStaticIpsError>> {
+        self.runtime.block_on(self.client.get_static_ips(input))
+    }
+
+    /// Blocking version of [`Lightsail::import_key_pair`].
+    pub fn import_key_pair(
+        &self,
+        input: ImportKeyPairRequest,
+    ) -> Result<ImportKeyPairResult, RusotoError<ImportKeyPairError>> {
+        self.runtime.block_on(self.client.import_key_pair(input))
+    }
+
+    /// Blocking version of [`Lightsail::is_vpc_peered`].
+    pub fn is_vpc_peered(&self) -> Result<IsVpcPeeredResult, RusotoError<IsVpcPeeredError>> {
+        self.runtime.block_on(self.client.is_vpc_peered())
+    }
+
+    /// Blocking version of [`Lightsail::open_instance_public_ports`].
+    pub fn open_instance_public_ports(
+        &self,
+        input: OpenInstancePublicPortsRequest,
+    ) -> Result<OpenInstancePublicPortsResult, RusotoError<OpenInstancePublicPortsError>> {
+        self.runtime
+            .block_on(self.client.open_instance_public_ports(input))
+    }
+
+    /// Blocking version of [`Lightsail::peer_vpc`].
+    pub fn peer_vpc(&self) -> Result<PeerVpcResult, RusotoError<PeerVpcError>> {
+        self.runtime.block_on(self.client.peer_vpc())
+    }
+
+    /// Blocking version of [`Lightsail::put_alarm`].
+    pub fn put_alarm(
+        &self,
+        input: PutAlarmRequest,
+    ) -> Result<PutAlarmResult, RusotoError<PutAlarmError>> {
+        self.runtime.block_on(self.client.put_alarm(input))
+    }
+
+    /// Blocking version of [`Lightsail::put_instance_public_ports`].
+    pub fn put_instance_public_ports(
+        &self,
+        input: PutInstancePublicPortsRequest,
+    ) -> Result<PutInstancePublicPortsResult, RusotoError<PutInstancePublicPortsError>> {
+        self.runtime
+            .block_on(self.client.put_instance_public_ports(input))
+    }
+
+    /// Blocking version of [`Lightsail::reboot_instance`].
+    pub fn reboot_instance(
+        &self,
+        input: RebootInstanceRequest,
+    ) -> Result<RebootInstanceResult, RusotoError<RebootInstanceError>> {
+        self.runtime.block_on(self.client.reboot_instance(input))
+    }
+
+    /// Blocking version of [`Lightsail::reboot_relational_database`].
+    pub fn reboot_relational_database(
+        &self,
+        input: RebootRelationalDatabaseRequest,
+    ) -> Result<RebootRelationalDatabaseResult, RusotoError<RebootRelationalDatabaseError>> {
+        self.runtime
+            .block_on(self.client.reboot_relational_database(input))
+    }
+
+    /// Blocking version of [`Lightsail::register_container_image`].
+    pub fn register_container_image(
+        &self,
+        input: RegisterContainerImageRequest,
+    ) -> Result<RegisterContainerImageResult, RusotoError<RegisterContainerImageError>> {
+        self.runtime
+            .block_on(self.client.register_container_image(input))
+    }
+
+    /// Blocking version of [`Lightsail::release_static_ip`].
+    pub fn release_static_ip(
+        &self,
+        input: ReleaseStaticIpRequest,
+    ) -> Result<ReleaseStaticIpResult, RusotoError<ReleaseStaticIpError>> {
+        self.runtime.block_on(self.client.release_static_ip(input))
+    }
+
+    /// Blocking version of [`Lightsail::reset_distribution_cache`].
+    pub fn reset_distribution_cache(
+        &self,
+        input: ResetDistributionCacheRequest,
+    ) -> Result<ResetDistributionCacheResult, RusotoError<ResetDistributionCacheError>> {
+        self.runtime
+            .block_on(self.client.reset_distribution_cache(input))
+    }
+
+    /// Blocking version of [`Lightsail::send_contact_method_verification`].
+    pub fn send_contact_method_verification(
+        &self,
+        input: SendContactMethodVerificationRequest,
+    ) -> Result<SendContactMethodVerificationResult, RusotoError<SendContactMethodVerificationError>>
+    {
+        self.runtime
+            .block_on(self.client.send_contact_method_verification(input))
+    }
+
+    /// Blocking version of [`Lightsail::start_instance`].
+    pub fn start_instance(
+        &self,
+        input: StartInstanceRequest,
+    ) -> Result<StartInstanceResult, RusotoError<StartInstanceError>> {
+        self.runtime.block_on(self.client.start_instance(input))
+    }
+
+    /// Blocking version of [`Lightsail::start_relational_database`].
+    pub fn start_relational_database(
+        &self,
+        input: StartRelationalDatabaseRequest,
+    ) -> Result<StartRelationalDatabaseResult, RusotoError<StartRelationalDatabaseError>> {
+        self.runtime
+            .block_on(self.client.start_relational_database(input))
+    }
+
+    /// Blocking version of [`Lightsail::stop_instance`].
+    pub fn stop_instance(
+        &self,
+        input: StopInstanceRequest,
+    ) -> Result<StopInstanceResult, RusotoError<StopInstanceError>> {
+        self.runtime.block_on(self.client.stop_instance(input))
+    }
+
+    /// Blocking version of [`Lightsail::stop_relational_database`].
+    pub fn stop_relational_database(
+        &self,
+        input: StopRelationalDatabaseRequest,
+    ) -> Result<StopRelationalDatabaseResult, RusotoError<StopRelationalDatabaseError>> {
+        self.runtime
+            .block_on(self.client.stop_relational_database(input))
+    }
+
+    /// Blocking version of [`Lightsail::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResult, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`Lightsail::test_alarm`].
+    pub fn test_alarm(
+        &self,
+        input: TestAlarmRequest,
+    ) -> Result<TestAlarmResult, RusotoError<TestAlarmError>> {
+        self.runtime.block_on(self.client.test_alarm(input))
+    }
+
+    /// Blocking version of [`Lightsail::unpeer_vpc`].
+    pub fn unpeer_vpc(&self) -> Result<UnpeerVpcResult, RusotoError<UnpeerVpcError>> {
+        self.runtime.block_on(self.client.unpeer_vpc())
+    }
+
+    /// Blocking version of [`Lightsail::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResult, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`Lightsail::update_container_service`].
+    pub fn update_container_service(
+        &self,
+        input: UpdateContainerServiceRequest,
+    ) -> Result<UpdateContainerServiceResult, RusotoError<UpdateContainerServiceError>> {
+        self.runtime
+            .block_on(self.client.update_container_service(input))
+    }
+
+    /// Blocking version of [`Lightsail::update_distribution`].
+    pub fn update_distribution(
+        &self,
+        input: UpdateDistributionRequest,
+    ) -> Result<UpdateDistributionResult, RusotoError<UpdateDistributionError>> {
+        self.runtime
+            .block_on(self.client.update_distribution(input))
+    }
+
+    /// Blocking version of [`Lightsail::update_distribution_bundle`].
+    pub fn update_distribution_bundle(
+        &self,
+        input: UpdateDistributionBundleRequest,
+    ) -> Result<UpdateDistributionBundleResult, RusotoError<UpdateDistributionBundleError>> {
+        self.runtime
+            .block_on(self.client.update_distribution_bundle(input))
+    }
+
+    /// Blocking version of [`Lightsail::update_domain_entry`].
+    pub fn update_domain_entry(
+        &self,
+        input: UpdateDomainEntryRequest,
+    ) -> Result<UpdateDomainEntryResult, RusotoError<UpdateDomainEntryError>> {
+        self.runtime
+            .block_on(self.client.update_domain_entry(input))
+    }
+
+    /// Blocking version of [`Lightsail::update_load_balancer_attribute`].
+    pub fn update_load_balancer_attribute(
+        &self,
+        input: UpdateLoadBalancerAttributeRequest,
+    ) -> Result<UpdateLoadBalancerAttributeResult, RusotoError<UpdateLoadBalancerAttributeError>>
+    {
+        self.runtime
+            .block_on(self.client.update_load_balancer_attribute(input))
+    }
+
+    /// Blocking version of [`Lightsail::update_relational_database`].
+    pub fn update_relational_database(
+        &self,
+        input: UpdateRelationalDatabaseRequest,
+    ) -> Result<UpdateRelationalDatabaseResult, RusotoError<UpdateRelationalDatabaseError>> {
+        self.runtime
+            .block_on(self.client.update_relational_database(input))
+    }
+
+    /// Blocking version of [`Lightsail::update_relational_database_parameters`].
+    pub fn update_relational_database_parameters(
+        &self,
+        input: UpdateRelationalDatabaseParametersRequest,
+    ) -> Result<
+        UpdateRelationalDatabaseParametersResult,
+        RusotoError<UpdateRelationalDatabaseParametersError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_relational_database_parameters(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1454,18 +1455,38 @@ impl AssociateKmsKeyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AssociateKmsKeyError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateKmsKeyError::InvalidParameter,
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(AssociateKmsKeyError::OperationAborted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateKmsKeyError::OperationAborted,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateKmsKeyError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateKmsKeyError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(AssociateKmsKeyError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateKmsKeyError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1502,18 +1523,38 @@ impl CancelExportTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidOperationException" => {
-                    return RusotoError::Service(CancelExportTaskError::InvalidOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelExportTaskError::InvalidOperation,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CancelExportTaskError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelExportTaskError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelExportTaskError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelExportTaskError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CancelExportTaskError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelExportTaskError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1554,26 +1595,54 @@ impl CreateExportTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateExportTaskError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateExportTaskError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateExportTaskError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateExportTaskError::LimitExceeded,
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(CreateExportTaskError::OperationAborted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateExportTaskError::OperationAborted,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateExportTaskError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateExportTaskError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateExportTaskError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateExportTaskError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateExportTaskError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateExportTaskError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1614,23 +1683,46 @@ impl CreateLogGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateLogGroupError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLogGroupError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateLogGroupError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLogGroupError::LimitExceeded,
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(CreateLogGroupError::OperationAborted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLogGroupError::OperationAborted,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateLogGroupError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateLogGroupError::ResourceAlreadyExists,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateLogGroupError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLogGroupError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1668,20 +1760,38 @@ impl CreateLogStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateLogStreamError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLogStreamError::InvalidParameter,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateLogStreamError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateLogStreamError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateLogStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLogStreamError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateLogStreamError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLogStreamError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1718,20 +1828,38 @@ impl DeleteDestinationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteDestinationError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDestinationError::InvalidParameter,
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(DeleteDestinationError::OperationAborted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDestinationError::OperationAborted,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDestinationError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDestinationError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteDestinationError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDestinationError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1768,18 +1896,38 @@ impl DeleteLogGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteLogGroupError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLogGroupError::InvalidParameter,
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(DeleteLogGroupError::OperationAborted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLogGroupError::OperationAborted,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteLogGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLogGroupError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteLogGroupError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLogGroupError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1816,18 +1964,38 @@ impl DeleteLogStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteLogStreamError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLogStreamError::InvalidParameter,
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(DeleteLogStreamError::OperationAborted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLogStreamError::OperationAborted,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteLogStreamError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLogStreamError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteLogStreamError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLogStreamError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1864,20 +2032,38 @@ impl DeleteMetricFilterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteMetricFilterError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMetricFilterError::InvalidParameter,
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(DeleteMetricFilterError::OperationAborted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMetricFilterError::OperationAborted,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteMetricFilterError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMetricFilterError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteMetricFilterError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteMetricFilterError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1912,21 +2098,30 @@ impl DeleteQueryDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteQueryDefinitionError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteQueryDefinitionError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteQueryDefinitionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteQueryDefinitionError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteQueryDefinitionError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteQueryDefinitionError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1960,21 +2155,30 @@ impl DeleteResourcePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteResourcePolicyError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteResourcePolicyError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteResourcePolicyError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteResourcePolicyError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2010,26 +2214,38 @@ impl DeleteRetentionPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteRetentionPolicyError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRetentionPolicyError::InvalidParameter,
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(DeleteRetentionPolicyError::OperationAborted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRetentionPolicyError::OperationAborted,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteRetentionPolicyError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRetentionPolicyError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteRetentionPolicyError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRetentionPolicyError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2066,26 +2282,38 @@ impl DeleteSubscriptionFilterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteSubscriptionFilterError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSubscriptionFilterError::InvalidParameter,
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(DeleteSubscriptionFilterError::OperationAborted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSubscriptionFilterError::OperationAborted,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteSubscriptionFilterError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSubscriptionFilterError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteSubscriptionFilterError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSubscriptionFilterError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2118,16 +2346,22 @@ impl DescribeDestinationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeDestinationsError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDestinationsError::InvalidParameter,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeDestinationsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDestinationsError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2158,16 +2392,22 @@ impl DescribeExportTasksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeExportTasksError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeExportTasksError::InvalidParameter,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeExportTasksError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeExportTasksError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2198,14 +2438,22 @@ impl DescribeLogGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeLogGroupsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeLogGroupsError::InvalidParameter,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeLogGroupsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeLogGroupsError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2238,17 +2486,30 @@ impl DescribeLogStreamsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeLogStreamsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeLogStreamsError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeLogStreamsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeLogStreamsError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeLogStreamsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeLogStreamsError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2282,21 +2543,30 @@ impl DescribeMetricFiltersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeMetricFiltersError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeMetricFiltersError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeMetricFiltersError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeMetricFiltersError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeMetricFiltersError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeMetricFiltersError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2330,15 +2600,30 @@ impl DescribeQueriesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeQueriesError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeQueriesError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeQueriesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeQueriesError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeQueriesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeQueriesError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2370,16 +2655,22 @@ impl DescribeQueryDefinitionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeQueryDefinitionsError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeQueryDefinitionsError::InvalidParameter,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeQueryDefinitionsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeQueryDefinitionsError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2410,16 +2701,22 @@ impl DescribeResourcePoliciesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeResourcePoliciesError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeResourcePoliciesError::InvalidParameter,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeResourcePoliciesError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeResourcePoliciesError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2454,21 +2751,30 @@ impl DescribeSubscriptionFiltersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        DescribeSubscriptionFiltersError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSubscriptionFiltersError::InvalidParameter,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeSubscriptionFiltersError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSubscriptionFiltersError::ResourceNotFound,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DescribeSubscriptionFiltersError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSubscriptionFiltersError::ServiceUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2506,20 +2812,38 @@ impl DisassociateKmsKeyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DisassociateKmsKeyError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateKmsKeyError::InvalidParameter,
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(DisassociateKmsKeyError::OperationAborted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateKmsKeyError::OperationAborted,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateKmsKeyError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateKmsKeyError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DisassociateKmsKeyError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateKmsKeyError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2554,15 +2878,30 @@ impl FilterLogEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(FilterLogEventsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        FilterLogEventsError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(FilterLogEventsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        FilterLogEventsError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(FilterLogEventsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        FilterLogEventsError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2596,15 +2935,30 @@ impl GetLogEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetLogEventsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLogEventsError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetLogEventsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLogEventsError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetLogEventsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLogEventsError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2640,20 +2994,38 @@ impl GetLogGroupFieldsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetLogGroupFieldsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLogGroupFieldsError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetLogGroupFieldsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLogGroupFieldsError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetLogGroupFieldsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLogGroupFieldsError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetLogGroupFieldsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetLogGroupFieldsError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2690,18 +3062,38 @@ impl GetLogRecordError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetLogRecordError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLogRecordError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(GetLogRecordError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLogRecordError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetLogRecordError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLogRecordError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetLogRecordError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLogRecordError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2736,15 +3128,30 @@ impl GetQueryResultsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetQueryResultsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetQueryResultsError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetQueryResultsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetQueryResultsError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetQueryResultsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetQueryResultsError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2776,12 +3183,22 @@ impl ListTagsLogGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsLogGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsLogGroupError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListTagsLogGroupError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsLogGroupError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2814,15 +3231,30 @@ impl PutDestinationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutDestinationError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutDestinationError::InvalidParameter,
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(PutDestinationError::OperationAborted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutDestinationError::OperationAborted,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutDestinationError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutDestinationError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2856,21 +3288,30 @@ impl PutDestinationPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutDestinationPolicyError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutDestinationPolicyError::InvalidParameter,
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(PutDestinationPolicyError::OperationAborted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutDestinationPolicyError::OperationAborted,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutDestinationPolicyError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutDestinationPolicyError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2910,24 +3351,54 @@ impl PutLogEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DataAlreadyAcceptedException" => {
-                    return RusotoError::Service(PutLogEventsError::DataAlreadyAccepted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutLogEventsError::DataAlreadyAccepted,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutLogEventsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutLogEventsError::InvalidParameter,
+                    )
                 }
                 "InvalidSequenceTokenException" => {
-                    return RusotoError::Service(PutLogEventsError::InvalidSequenceToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutLogEventsError::InvalidSequenceToken,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutLogEventsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutLogEventsError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutLogEventsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutLogEventsError::ServiceUnavailable,
+                    )
                 }
                 "UnrecognizedClientException" => {
-                    return RusotoError::Service(PutLogEventsError::UnrecognizedClient(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutLogEventsError::UnrecognizedClient,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2968,21 +3439,46 @@ impl PutMetricFilterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutMetricFilterError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutMetricFilterError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutMetricFilterError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutMetricFilterError::LimitExceeded,
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(PutMetricFilterError::OperationAborted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutMetricFilterError::OperationAborted,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutMetricFilterError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutMetricFilterError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutMetricFilterError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutMetricFilterError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3018,17 +3514,30 @@ impl PutQueryDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutQueryDefinitionError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutQueryDefinitionError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutQueryDefinitionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutQueryDefinitionError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutQueryDefinitionError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutQueryDefinitionError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3062,17 +3571,30 @@ impl PutResourcePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutResourcePolicyError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutResourcePolicyError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutResourcePolicyError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutResourcePolicyError::LimitExceeded,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutResourcePolicyError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutResourcePolicyError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3108,20 +3630,38 @@ impl PutRetentionPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutRetentionPolicyError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRetentionPolicyError::InvalidParameter,
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(PutRetentionPolicyError::OperationAborted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRetentionPolicyError::OperationAborted,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutRetentionPolicyError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutRetentionPolicyError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutRetentionPolicyError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutRetentionPolicyError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3160,29 +3700,46 @@ impl PutSubscriptionFilterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutSubscriptionFilterError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutSubscriptionFilterError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PutSubscriptionFilterError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutSubscriptionFilterError::LimitExceeded,
+                    )
                 }
                 "OperationAbortedException" => {
-                    return RusotoError::Service(PutSubscriptionFilterError::OperationAborted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutSubscriptionFilterError::OperationAborted,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutSubscriptionFilterError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutSubscriptionFilterError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutSubscriptionFilterError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutSubscriptionFilterError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3222,21 +3779,46 @@ impl StartQueryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartQueryError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartQueryError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartQueryError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartQueryError::LimitExceeded,
+                    )
                 }
                 "MalformedQueryException" => {
-                    return RusotoError::Service(StartQueryError::MalformedQuery(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartQueryError::MalformedQuery,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartQueryError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartQueryError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(StartQueryError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartQueryError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3272,15 +3854,30 @@ impl StopQueryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StopQueryError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopQueryError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopQueryError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopQueryError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(StopQueryError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopQueryError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3312,12 +3909,22 @@ impl TagLogGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagLogGroupError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagLogGroupError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagLogGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagLogGroupError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3348,12 +3955,22 @@ impl TestMetricFilterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TestMetricFilterError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestMetricFilterError::InvalidParameter,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(TestMetricFilterError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TestMetricFilterError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3382,9 +3999,14 @@ impl UntagLogGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagLogGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagLogGroupError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3665,11 +4287,12 @@ pub struct CloudWatchLogsClient {
 impl CloudWatchLogsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> CloudWatchLogsClient {
         CloudWatchLogsClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "CloudWatch Logs"),
         }
     }
 
@@ -3684,12 +4307,32 @@ impl CloudWatchLogsClient {
     {
         CloudWatchLogsClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "CloudWatch Logs"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> CloudWatchLogsClient {
-        CloudWatchLogsClient { client, region }
+        CloudWatchLogsClient {
+            client,
+            region: region_for_service(region, "CloudWatch Logs"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> CloudWatchLogsClient {
+        CloudWatchLogsClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("CloudWatch Logs"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CloudWatchLogsClient {
+        CloudWatchLogsClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -3701,6 +4344,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: AssociateKmsKeyRequest,
     ) -> Result<(), RusotoError<AssociateKmsKeyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateKmsKey");
         request.add_header("x-amz-target", "Logs_20140328.AssociateKmsKey");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3718,6 +4362,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: CancelExportTaskRequest,
     ) -> Result<(), RusotoError<CancelExportTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CancelExportTask");
         request.add_header("x-amz-target", "Logs_20140328.CancelExportTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3735,6 +4380,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: CreateExportTaskRequest,
     ) -> Result<CreateExportTaskResponse, RusotoError<CreateExportTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateExportTask");
         request.add_header("x-amz-target", "Logs_20140328.CreateExportTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3753,6 +4399,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: CreateLogGroupRequest,
     ) -> Result<(), RusotoError<CreateLogGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateLogGroup");
         request.add_header("x-amz-target", "Logs_20140328.CreateLogGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3770,6 +4417,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: CreateLogStreamRequest,
     ) -> Result<(), RusotoError<CreateLogStreamError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateLogStream");
         request.add_header("x-amz-target", "Logs_20140328.CreateLogStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3787,6 +4435,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DeleteDestinationRequest,
     ) -> Result<(), RusotoError<DeleteDestinationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteDestination");
         request.add_header("x-amz-target", "Logs_20140328.DeleteDestination");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3804,6 +4453,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DeleteLogGroupRequest,
     ) -> Result<(), RusotoError<DeleteLogGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteLogGroup");
         request.add_header("x-amz-target", "Logs_20140328.DeleteLogGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3821,6 +4471,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DeleteLogStreamRequest,
     ) -> Result<(), RusotoError<DeleteLogStreamError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteLogStream");
         request.add_header("x-amz-target", "Logs_20140328.DeleteLogStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3838,6 +4489,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DeleteMetricFilterRequest,
     ) -> Result<(), RusotoError<DeleteMetricFilterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteMetricFilter");
         request.add_header("x-amz-target", "Logs_20140328.DeleteMetricFilter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3855,6 +4507,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DeleteQueryDefinitionRequest,
     ) -> Result<DeleteQueryDefinitionResponse, RusotoError<DeleteQueryDefinitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteQueryDefinition");
         request.add_header("x-amz-target", "Logs_20140328.DeleteQueryDefinition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3874,6 +4527,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DeleteResourcePolicyRequest,
     ) -> Result<(), RusotoError<DeleteResourcePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteResourcePolicy");
         request.add_header("x-amz-target", "Logs_20140328.DeleteResourcePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3891,6 +4545,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DeleteRetentionPolicyRequest,
     ) -> Result<(), RusotoError<DeleteRetentionPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteRetentionPolicy");
         request.add_header("x-amz-target", "Logs_20140328.DeleteRetentionPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3908,6 +4563,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DeleteSubscriptionFilterRequest,
     ) -> Result<(), RusotoError<DeleteSubscriptionFilterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteSubscriptionFilter");
         request.add_header("x-amz-target", "Logs_20140328.DeleteSubscriptionFilter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3925,6 +4581,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DescribeDestinationsRequest,
     ) -> Result<DescribeDestinationsResponse, RusotoError<DescribeDestinationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeDestinations");
         request.add_header("x-amz-target", "Logs_20140328.DescribeDestinations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3944,6 +4601,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DescribeExportTasksRequest,
     ) -> Result<DescribeExportTasksResponse, RusotoError<DescribeExportTasksError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeExportTasks");
         request.add_header("x-amz-target", "Logs_20140328.DescribeExportTasks");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3962,6 +4620,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DescribeLogGroupsRequest,
     ) -> Result<DescribeLogGroupsResponse, RusotoError<DescribeLogGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeLogGroups");
         request.add_header("x-amz-target", "Logs_20140328.DescribeLogGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3980,6 +4639,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DescribeLogStreamsRequest,
     ) -> Result<DescribeLogStreamsResponse, RusotoError<DescribeLogStreamsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeLogStreams");
         request.add_header("x-amz-target", "Logs_20140328.DescribeLogStreams");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3998,6 +4658,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DescribeMetricFiltersRequest,
     ) -> Result<DescribeMetricFiltersResponse, RusotoError<DescribeMetricFiltersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeMetricFilters");
         request.add_header("x-amz-target", "Logs_20140328.DescribeMetricFilters");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4017,6 +4678,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DescribeQueriesRequest,
     ) -> Result<DescribeQueriesResponse, RusotoError<DescribeQueriesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeQueries");
         request.add_header("x-amz-target", "Logs_20140328.DescribeQueries");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4035,6 +4697,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DescribeQueryDefinitionsRequest,
     ) -> Result<DescribeQueryDefinitionsResponse, RusotoError<DescribeQueryDefinitionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeQueryDefinitions");
         request.add_header("x-amz-target", "Logs_20140328.DescribeQueryDefinitions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4054,6 +4717,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DescribeResourcePoliciesRequest,
     ) -> Result<DescribeResourcePoliciesResponse, RusotoError<DescribeResourcePoliciesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeResourcePolicies");
         request.add_header("x-amz-target", "Logs_20140328.DescribeResourcePolicies");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4074,6 +4738,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
     ) -> Result<DescribeSubscriptionFiltersResponse, RusotoError<DescribeSubscriptionFiltersError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeSubscriptionFilters");
         request.add_header("x-amz-target", "Logs_20140328.DescribeSubscriptionFilters");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4093,6 +4758,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: DisassociateKmsKeyRequest,
     ) -> Result<(), RusotoError<DisassociateKmsKeyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisassociateKmsKey");
         request.add_header("x-amz-target", "Logs_20140328.DisassociateKmsKey");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4110,6 +4776,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: FilterLogEventsRequest,
     ) -> Result<FilterLogEventsResponse, RusotoError<FilterLogEventsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("FilterLogEvents");
         request.add_header("x-amz-target", "Logs_20140328.FilterLogEvents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4128,6 +4795,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: GetLogEventsRequest,
     ) -> Result<GetLogEventsResponse, RusotoError<GetLogEventsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetLogEvents");
         request.add_header("x-amz-target", "Logs_20140328.GetLogEvents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4146,6 +4814,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: GetLogGroupFieldsRequest,
     ) -> Result<GetLogGroupFieldsResponse, RusotoError<GetLogGroupFieldsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetLogGroupFields");
         request.add_header("x-amz-target", "Logs_20140328.GetLogGroupFields");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4164,6 +4833,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: GetLogRecordRequest,
     ) -> Result<GetLogRecordResponse, RusotoError<GetLogRecordError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetLogRecord");
         request.add_header("x-amz-target", "Logs_20140328.GetLogRecord");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4182,6 +4852,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: GetQueryResultsRequest,
     ) -> Result<GetQueryResultsResponse, RusotoError<GetQueryResultsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetQueryResults");
         request.add_header("x-amz-target", "Logs_20140328.GetQueryResults");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4200,6 +4871,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: ListTagsLogGroupRequest,
     ) -> Result<ListTagsLogGroupResponse, RusotoError<ListTagsLogGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsLogGroup");
         request.add_header("x-amz-target", "Logs_20140328.ListTagsLogGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4218,6 +4890,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: PutDestinationRequest,
     ) -> Result<PutDestinationResponse, RusotoError<PutDestinationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutDestination");
         request.add_header("x-amz-target", "Logs_20140328.PutDestination");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4236,6 +4909,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: PutDestinationPolicyRequest,
     ) -> Result<(), RusotoError<PutDestinationPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutDestinationPolicy");
         request.add_header("x-amz-target", "Logs_20140328.PutDestinationPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4253,6 +4927,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: PutLogEventsRequest,
     ) -> Result<PutLogEventsResponse, RusotoError<PutLogEventsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutLogEvents");
         request.add_header("x-amz-target", "Logs_20140328.PutLogEvents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4271,6 +4946,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: PutMetricFilterRequest,
     ) -> Result<(), RusotoError<PutMetricFilterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutMetricFilter");
         request.add_header("x-amz-target", "Logs_20140328.PutMetricFilter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4288,6 +4964,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: PutQueryDefinitionRequest,
     ) -> Result<PutQueryDefinitionResponse, RusotoError<PutQueryDefinitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutQueryDefinition");
         request.add_header("x-amz-target", "Logs_20140328.PutQueryDefinition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4306,6 +4983,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: PutResourcePolicyRequest,
     ) -> Result<PutResourcePolicyResponse, RusotoError<PutResourcePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutResourcePolicy");
         request.add_header("x-amz-target", "Logs_20140328.PutResourcePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4324,6 +5002,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: PutRetentionPolicyRequest,
     ) -> Result<(), RusotoError<PutRetentionPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutRetentionPolicy");
         request.add_header("x-amz-target", "Logs_20140328.PutRetentionPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4341,6 +5020,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: PutSubscriptionFilterRequest,
     ) -> Result<(), RusotoError<PutSubscriptionFilterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutSubscriptionFilter");
         request.add_header("x-amz-target", "Logs_20140328.PutSubscriptionFilter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4358,6 +5038,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: StartQueryRequest,
     ) -> Result<StartQueryResponse, RusotoError<StartQueryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartQuery");
         request.add_header("x-amz-target", "Logs_20140328.StartQuery");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4376,6 +5057,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: StopQueryRequest,
     ) -> Result<StopQueryResponse, RusotoError<StopQueryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopQuery");
         request.add_header("x-amz-target", "Logs_20140328.StopQuery");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4394,6 +5076,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: TagLogGroupRequest,
     ) -> Result<(), RusotoError<TagLogGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagLogGroup");
         request.add_header("x-amz-target", "Logs_20140328.TagLogGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4411,6 +5094,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: TestMetricFilterRequest,
     ) -> Result<TestMetricFilterResponse, RusotoError<TestMetricFilterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TestMetricFilter");
         request.add_header("x-amz-target", "Logs_20140328.TestMetricFilter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4429,6 +5113,7 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         input: UntagLogGroupRequest,
     ) -> Result<(), RusotoError<UntagLogGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagLogGroup");
         request.add_header("x-amz-target", "Logs_20140328.UntagLogGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4440,3 +5125,441 @@ impl CloudWatchLogs for CloudWatchLogsClient {
         Ok(())
     }
 }
+
+/// A blocking client for the Amazon CloudWatch Logs API, running the calls of a `CloudWatchLogsClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `CloudWatchLogsClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct CloudWatchLogsBlockingClient {
+    client: CloudWatchLogsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl CloudWatchLogsBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> CloudWatchLogsBlockingClient {
+        CloudWatchLogsBlockingClient::from_client(CloudWatchLogsClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> CloudWatchLogsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        CloudWatchLogsBlockingClient::from_client(CloudWatchLogsClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> CloudWatchLogsBlockingClient {
+        CloudWatchLogsBlockingClient::from_client(CloudWatchLogsClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> CloudWatchLogsBlockingClient {
+        CloudWatchLogsBlockingClient::from_client(CloudWatchLogsClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: CloudWatchLogsClient) -> CloudWatchLogsBlockingClient {
+        CloudWatchLogsBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> CloudWatchLogsBlockingClient {
+        CloudWatchLogsBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> CloudWatchLogsBlockingClient {
+        CloudWatchLogsBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`CloudWatchLogs::associate_kms_key`].
+    pub fn associate_kms_key(
+        &self,
+        input: AssociateKmsKeyRequest,
+    ) -> Result<(), RusotoError<AssociateKmsKeyError>> {
+        self.runtime.block_on(self.client.associate_kms_key(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::cancel_export_task`].
+    pub fn cancel_export_task(
+        &self,
+        input: CancelExportTaskRequest,
+    ) -> Result<(), RusotoError<CancelExportTaskError>> {
+        self.runtime.block_on(self.client.cancel_export_task(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::create_export_task`].
+    pub fn create_export_task(
+        &self,
+        input: CreateExportTaskRequest,
+    ) -> Result<CreateExportTaskResponse, RusotoError<CreateExportTaskError>> {
+        self.runtime.block_on(self.client.create_export_task(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::create_log_group`].
+    pub fn create_log_group(
+        &self,
+        input: CreateLogGroupRequest,
+    ) -> Result<(), RusotoError<CreateLogGroupError>> {
+        self.runtime.block_on(self.client.create_log_group(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::create_log_stream`].
+    pub fn create_log_stream(
+        &self,
+        input: CreateLogStreamRequest,
+    ) -> Result<(), RusotoError<CreateLogStreamError>> {
+        self.runtime.block_on(self.client.create_log_stream(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::delete_destination`].
+    pub fn delete_destination(
+        &self,
+        input: DeleteDestinationRequest,
+    ) -> Result<(), RusotoError<DeleteDestinationError>> {
+        self.runtime.block_on(self.client.delete_destination(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::delete_log_group`].
+    pub fn delete_log_group(
+        &self,
+        input: DeleteLogGroupRequest,
+    ) -> Result<(), RusotoError<DeleteLogGroupError>> {
+        self.runtime.block_on(self.client.delete_log_group(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::delete_log_stream`].
+    pub fn delete_log_stream(
+        &self,
+        input: DeleteLogStreamRequest,
+    ) -> Result<(), RusotoError<DeleteLogStreamError>> {
+        self.runtime.block_on(self.client.delete_log_stream(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::delete_metric_filter`].
+    pub fn delete_metric_filter(
+        &self,
+        input: DeleteMetricFilterRequest,
+    ) -> Result<(), RusotoError<DeleteMetricFilterError>> {
+        self.runtime
+            .block_on(self.client.delete_metric_filter(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::delete_query_definition`].
+    pub fn delete_query_definition(
+        &self,
+        input: DeleteQueryDefinitionRequest,
+    ) -> Result<DeleteQueryDefinitionResponse, RusotoError<DeleteQueryDefinitionError>> {
+        self.runtime
+            .block_on(self.client.delete_query_definition(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::delete_resource_policy`].
+    pub fn delete_resource_policy(
+        &self,
+        input: DeleteResourcePolicyRequest,
+    ) -> Result<(), RusotoError<DeleteResourcePolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_resource_policy(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::delete_retention_policy`].
+    pub fn delete_retention_policy(
+        &self,
+        input: DeleteRetentionPolicyRequest,
+    ) -> Result<(), RusotoError<DeleteRetentionPolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_retention_policy(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::delete_subscription_filter`].
+    pub fn delete_subscription_filter(
+        &self,
+        input: DeleteSubscriptionFilterRequest,
+    ) -> Result<(), RusotoError<DeleteSubscriptionFilterError>> {
+        self.runtime
+            .block_on(self.client.delete_subscription_filter(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::describe_destinations`].
+    pub fn describe_destinations(
+        &self,
+        input: DescribeDestinationsRequest,
+    ) -> Result<DescribeDestinationsResponse, RusotoError<DescribeDestinationsError>> {
+        self.runtime
+            .block_on(self.client.describe_destinations(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::describe_export_tasks`].
+    pub fn describe_export_tasks(
+        &self,
+        input: DescribeExportTasksRequest,
+    ) -> Result<DescribeExportTasksResponse, RusotoError<DescribeExportTasksError>> {
+        self.runtime
+            .block_on(self.client.describe_export_tasks(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::describe_log_groups`].
+    pub fn describe_log_groups(
+        &self,
+        input: DescribeLogGroupsRequest,
+    ) -> Result<DescribeLogGroupsResponse, RusotoError<DescribeLogGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_log_groups(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::describe_log_streams`].
+    pub fn describe_log_streams(
+        &self,
+        input: DescribeLogStreamsRequest,
+    ) -> Result<DescribeLogStreamsResponse, RusotoError<DescribeLogStreamsError>> {
+        self.runtime
+            .block_on(self.client.describe_log_streams(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::describe_metric_filters`].
+    pub fn describe_metric_filters(
+        &self,
+        input: DescribeMetricFiltersRequest,
+    ) -> Result<DescribeMetricFiltersResponse, RusotoError<DescribeMetricFiltersError>> {
+        self.runtime
+            .block_on(self.client.describe_metric_filters(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::describe_queries`].
+    pub fn describe_queries(
+        &self,
+        input: DescribeQueriesRequest,
+    ) -> Result<DescribeQueriesResponse, RusotoError<DescribeQueriesError>> {
+        self.runtime.block_on(self.client.describe_queries(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::describe_query_definitions`].
+    pub fn describe_query_definitions(
+        &self,
+        input: DescribeQueryDefinitionsRequest,
+    ) -> Result<DescribeQueryDefinitionsResponse, RusotoError<DescribeQueryDefinitionsError>> {
+        self.runtime
+            .block_on(self.client.describe_query_definitions(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::describe_resource_policies`].
+    pub fn describe_resource_policies(
+        &self,
+        input: DescribeResourcePoliciesRequest,
+    ) -> Result<DescribeResourcePoliciesResponse, RusotoError<DescribeResourcePoliciesError>> {
+        self.runtime
+            .block_on(self.client.describe_resource_policies(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::describe_subscription_filters`].
+    pub fn describe_subscription_filters(
+        &self,
+        input: DescribeSubscriptionFiltersRequest,
+    ) -> Result<DescribeSubscriptionFiltersResponse, RusotoError<DescribeSubscriptionFiltersError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_subscription_filters(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::disassociate_kms_key`].
+    pub fn disassociate_kms_key(
+        &self,
+        input: DisassociateKmsKeyRequest,
+    ) -> Result<(), RusotoError<DisassociateKmsKeyError>> {
+        self.runtime
+            .block_on(self.client.disassociate_kms_key(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::filter_log_events`].
+    pub fn filter_log_events(
+        &self,
+        input: FilterLogEventsRequest,
+    ) -> Result<FilterLogEventsResponse, RusotoError<FilterLogEventsError>> {
+        self.runtime.block_on(self.client.filter_log_events(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::get_log_events`].
+    pub fn get_log_events(
+        &self,
+        input: GetLogEventsRequest,
+    ) -> Result<GetLogEventsResponse, RusotoError<GetLogEventsError>> {
+        self.runtime.block_on(self.client.get_log_events(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::get_log_group_fields`].
+    pub fn get_log_group_fields(
+        &self,
+        input: GetLogGroupFieldsRequest,
+    ) -> Result<GetLogGroupFieldsResponse, RusotoError<GetLogGroupFieldsError>> {
+        self.runtime
+            .block_on(self.client.get_log_group_fields(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::get_log_record`].
+    pub fn get_log_record(
+        &self,
+        input: GetLogRecordRequest,
+    ) -> Result<GetLogRecordResponse, RusotoError<GetLogRecordError>> {
+        self.runtime.block_on(self.client.get_log_record(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::get_query_results`].
+    pub fn get_query_results(
+        &self,
+        input: GetQueryResultsRequest,
+    ) -> Result<GetQueryResultsResponse, RusotoError<GetQueryResultsError>> {
+        self.runtime.block_on(self.client.get_query_results(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::list_tags_log_group`].
+    pub fn list_tags_log_group(
+        &self,
+        input: ListTagsLogGroupRequest,
+    ) -> Result<ListTagsLogGroupResponse, RusotoError<ListTagsLogGroupError>> {
+        self.runtime
+            .block_on(self.client.list_tags_log_group(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::put_destination`].
+    pub fn put_destination(
+        &self,
+        input: PutDestinationRequest,
+    ) -> Result<PutDestinationResponse, RusotoError<PutDestinationError>> {
+        self.runtime.block_on(self.client.put_destination(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::put_destination_policy`].
+    pub fn put_destination_policy(
+        &self,
+        input: PutDestinationPolicyRequest,
+    ) -> Result<(), RusotoError<PutDestinationPolicyError>> {
+        self.runtime
+            .block_on(self.client.put_destination_policy(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::put_log_events`].
+    pub fn put_log_events(
+        &self,
+        input: PutLogEventsRequest,
+    ) -> Result<PutLogEventsResponse, RusotoError<PutLogEventsError>> {
+        self.runtime.block_on(self.client.put_log_events(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::put_metric_filter`].
+    pub fn put_metric_filter(
+        &self,
+        input: PutMetricFilterRequest,
+    ) -> Result<(), RusotoError<PutMetricFilterError>> {
+        self.runtime.block_on(self.client.put_metric_filter(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::put_query_definition`].
+    pub fn put_query_definition(
+        &self,
+        input: PutQueryDefinitionRequest,
+    ) -> Result<PutQueryDefinitionResponse, RusotoError<PutQueryDefinitionError>> {
+        self.runtime
+            .block_on(self.client.put_query_definition(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::put_resource_policy`].
+    pub fn put_resource_policy(
+        &self,
+        input: PutResourcePolicyRequest,
+    ) -> Result<PutResourcePolicyResponse, RusotoError<PutResourcePolicyError>> {
+        self.runtime
+            .block_on(self.client.put_resource_policy(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::put_retention_policy`].
+    pub fn put_retention_policy(
+        &self,
+        input: PutRetentionPolicyRequest,
+    ) -> Result<(), RusotoError<PutRetentionPolicyError>> {
+        self.runtime
+            .block_on(self.client.put_retention_policy(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::put_subscription_filter`].
+    pub fn put_subscription_filter(
+        &self,
+        input: PutSubscriptionFilterRequest,
+    ) -> Result<(), RusotoError<PutSubscriptionFilterError>> {
+        self.runtime
+            .block_on(self.client.put_subscription_filter(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::start_query`].
+    pub fn start_query(
+        &self,
+        input: StartQueryRequest,
+    ) -> Result<StartQueryResponse, RusotoError<StartQueryError>> {
+        self.runtime.block_on(self.client.start_query(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::stop_query`].
+    pub fn stop_query(
+        &self,
+        input: StopQueryRequest,
+    ) -> Result<StopQueryResponse, RusotoError<StopQueryError>> {
+        self.runtime.block_on(self.client.stop_query(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::tag_log_group`].
+    pub fn tag_log_group(
+        &self,
+        input: TagLogGroupRequest,
+    ) -> Result<(), RusotoError<TagLogGroupError>> {
+        self.runtime.block_on(self.client.tag_log_group(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::test_metric_filter`].
+    pub fn test_metric_filter(
+        &self,
+        input: TestMetricFilterRequest,
+    ) -> Result<TestMetricFilterResponse, RusotoError<TestMetricFilterError>> {
+        self.runtime.block_on(self.client.test_metric_filter(input))
+    }
+
+    /// Blocking version of [`CloudWatchLogs::untag_log_group`].
+    pub fn untag_log_group(
+        &self,
+        input: UntagLogGroupRequest,
+    ) -> Result<(), RusotoError<UntagLogGroupError>> {
+        self.runtime.block_on(self.client.untag_log_group(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1691,21 +1692,46 @@ impl AddTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(AddTagsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsError::InternalServer,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AddTagsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsError::InvalidInput,
+                    )
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(AddTagsError::InvalidTag(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsError::InvalidTag,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AddTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsError::ResourceNotFound,
+                    )
                 }
                 "TagLimitExceededException" => {
-                    return RusotoError::Service(AddTagsError::TagLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddTagsError::TagLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1741,19 +1767,30 @@ impl CreateBatchPredictionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        CreateBatchPredictionError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBatchPredictionError::IdempotentParameterMismatch,
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateBatchPredictionError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateBatchPredictionError::InternalServer,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateBatchPredictionError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBatchPredictionError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1789,21 +1826,30 @@ impl CreateDataSourceFromRDSError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        CreateDataSourceFromRDSError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataSourceFromRDSError::IdempotentParameterMismatch,
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateDataSourceFromRDSError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDataSourceFromRDSError::InternalServer,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDataSourceFromRDSError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDataSourceFromRDSError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1841,21 +1887,30 @@ impl CreateDataSourceFromRedshiftError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        CreateDataSourceFromRedshiftError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataSourceFromRedshiftError::IdempotentParameterMismatch,
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateDataSourceFromRedshiftError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDataSourceFromRedshiftError::InternalServer,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDataSourceFromRedshiftError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDataSourceFromRedshiftError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1891,19 +1946,30 @@ impl CreateDataSourceFromS3Error {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        CreateDataSourceFromS3Error::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataSourceFromS3Error::IdempotentParameterMismatch,
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateDataSourceFromS3Error::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDataSourceFromS3Error::InternalServer,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDataSourceFromS3Error::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataSourceFromS3Error::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1939,17 +2005,30 @@ impl CreateEvaluationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        CreateEvaluationError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEvaluationError::IdempotentParameterMismatch,
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateEvaluationError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEvaluationError::InternalServer,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateEvaluationError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEvaluationError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1983,17 +2062,30 @@ impl CreateMLModelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(CreateMLModelError::IdempotentParameterMismatch(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateMLModelError::IdempotentParameterMismatch,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateMLModelError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMLModelError::InternalServer,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateMLModelError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMLModelError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2027,19 +2119,30 @@ impl CreateRealtimeEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateRealtimeEndpointError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRealtimeEndpointError::InternalServer,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateRealtimeEndpointError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRealtimeEndpointError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateRealtimeEndpointError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRealtimeEndpointError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2073,19 +2176,30 @@ impl DeleteBatchPredictionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteBatchPredictionError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteBatchPredictionError::InternalServer,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteBatchPredictionError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBatchPredictionError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteBatchPredictionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteBatchPredictionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2119,15 +2233,30 @@ impl DeleteDataSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteDataSourceError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDataSourceError::InternalServer,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteDataSourceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDataSourceError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDataSourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDataSourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2161,15 +2290,30 @@ impl DeleteEvaluationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteEvaluationError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEvaluationError::InternalServer,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteEvaluationError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEvaluationError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteEvaluationError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEvaluationError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2203,15 +2347,30 @@ impl DeleteMLModelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteMLModelError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMLModelError::InternalServer,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteMLModelError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMLModelError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteMLModelError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMLModelError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2245,19 +2404,30 @@ impl DeleteRealtimeEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteRealtimeEndpointError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRealtimeEndpointError::InternalServer,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteRealtimeEndpointError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRealtimeEndpointError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteRealtimeEndpointError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRealtimeEndpointError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2293,18 +2463,38 @@ impl DeleteTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteTagsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTagsError::InternalServer,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteTagsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTagsError::InvalidInput,
+                    )
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(DeleteTagsError::InvalidTag(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTagsError::InvalidTag,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTagsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2337,16 +2527,22 @@ impl DescribeBatchPredictionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeBatchPredictionsError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeBatchPredictionsError::InternalServer,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeBatchPredictionsError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeBatchPredictionsError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2377,12 +2573,22 @@ impl DescribeDataSourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeDataSourcesError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDataSourcesError::InternalServer,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeDataSourcesError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDataSourcesError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2413,12 +2619,22 @@ impl DescribeEvaluationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeEvaluationsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEvaluationsError::InternalServer,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeEvaluationsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEvaluationsError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2449,12 +2665,22 @@ impl DescribeMLModelsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeMLModelsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMLModelsError::InternalServer,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeMLModelsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMLModelsError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2487,15 +2713,30 @@ impl DescribeTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeTagsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTagsError::InternalServer,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeTagsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTagsError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTagsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2529,15 +2770,30 @@ impl GetBatchPredictionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetBatchPredictionError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBatchPredictionError::InternalServer,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetBatchPredictionError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBatchPredictionError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetBatchPredictionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBatchPredictionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2571,15 +2827,30 @@ impl GetDataSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetDataSourceError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDataSourceError::InternalServer,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetDataSourceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDataSourceError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDataSourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDataSourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2613,15 +2884,30 @@ impl GetEvaluationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetEvaluationError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEvaluationError::InternalServer,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetEvaluationError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEvaluationError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetEvaluationError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEvaluationError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2655,15 +2941,30 @@ impl GetMLModelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetMLModelError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMLModelError::InternalServer,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetMLModelError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMLModelError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetMLModelError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMLModelError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2701,21 +3002,46 @@ impl PredictError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(PredictError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PredictError::InternalServer,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(PredictError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PredictError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(PredictError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PredictError::LimitExceeded,
+                    )
                 }
                 "PredictorNotMountedException" => {
-                    return RusotoError::Service(PredictError::PredictorNotMounted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PredictError::PredictorNotMounted,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PredictError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PredictError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2751,19 +3077,30 @@ impl UpdateBatchPredictionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateBatchPredictionError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateBatchPredictionError::InternalServer,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateBatchPredictionError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateBatchPredictionError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateBatchPredictionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateBatchPredictionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2797,15 +3134,30 @@ impl UpdateDataSourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateDataSourceError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDataSourceError::InternalServer,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateDataSourceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDataSourceError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDataSourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDataSourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2839,15 +3191,30 @@ impl UpdateEvaluationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateEvaluationError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateEvaluationError::InternalServer,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateEvaluationError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateEvaluationError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateEvaluationError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateEvaluationError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2881,15 +3248,30 @@ impl UpdateMLModelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateMLModelError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMLModelError::InternalServer,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateMLModelError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMLModelError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateMLModelError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMLModelError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3088,11 +3470,12 @@ pub struct MachineLearningClient {
 impl MachineLearningClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> MachineLearningClient {
         MachineLearningClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Machine Learning"),
         }
     }
 
@@ -3107,12 +3490,32 @@ impl MachineLearningClient {
     {
         MachineLearningClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Machine Learning"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> MachineLearningClient {
-        MachineLearningClient { client, region }
+        MachineLearningClient {
+            client,
+            region: region_for_service(region, "Machine Learning"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> MachineLearningClient {
+        MachineLearningClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Machine Learning"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> MachineLearningClient {
+        MachineLearningClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -3124,6 +3527,7 @@ impl MachineLearning for MachineLearningClient {
         input: AddTagsInput,
     ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AddTags");
         request.add_header("x-amz-target", "AmazonML_20141212.AddTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3142,6 +3546,7 @@ impl MachineLearning for MachineLearningClient {
         input: CreateBatchPredictionInput,
     ) -> Result<CreateBatchPredictionOutput, RusotoError<CreateBatchPredictionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateBatchPrediction");
         request.add_header("x-amz-target", "AmazonML_20141212.CreateBatchPrediction");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3160,6 +3565,7 @@ impl MachineLearning for MachineLearningClient {
         input: CreateDataSourceFromRDSInput,
     ) -> Result<CreateDataSourceFromRDSOutput, RusotoError<CreateDataSourceFromRDSError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateDataSourceFromRDS");
         request.add_header("x-amz-target", "AmazonML_20141212.CreateDataSourceFromRDS");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3180,6 +3586,7 @@ impl MachineLearning for MachineLearningClient {
     ) -> Result<CreateDataSourceFromRedshiftOutput, RusotoError<CreateDataSourceFromRedshiftError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateDataSourceFromRedshift");
         request.add_header(
             "x-amz-target",
             "AmazonML_20141212.CreateDataSourceFromRedshift",
@@ -3202,6 +3609,7 @@ impl MachineLearning for MachineLearningClient {
         input: CreateDataSourceFromS3Input,
     ) -> Result<CreateDataSourceFromS3Output, RusotoError<CreateDataSourceFromS3Error>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateDataSourceFromS3");
         request.add_header("x-amz-target", "AmazonML_20141212.CreateDataSourceFromS3");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3221,6 +3629,7 @@ impl MachineLearning for MachineLearningClient {
         input: CreateEvaluationInput,
     ) -> Result<CreateEvaluationOutput, RusotoError<CreateEvaluationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateEvaluation");
         request.add_header("x-amz-target", "AmazonML_20141212.CreateEvaluation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3239,6 +3648,7 @@ impl MachineLearning for MachineLearningClient {
         input: CreateMLModelInput,
     ) -> Result<CreateMLModelOutput, RusotoError<CreateMLModelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateMLModel");
         request.add_header("x-amz-target", "AmazonML_20141212.CreateMLModel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3257,6 +3667,7 @@ impl MachineLearning for MachineLearningClient {
         input: CreateRealtimeEndpointInput,
     ) -> Result<CreateRealtimeEndpointOutput, RusotoError<CreateRealtimeEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateRealtimeEndpoint");
         request.add_header("x-amz-target", "AmazonML_20141212.CreateRealtimeEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3276,6 +3687,7 @@ impl MachineLearning for MachineLearningClient {
         input: DeleteBatchPredictionInput,
     ) -> Result<DeleteBatchPredictionOutput, RusotoError<DeleteBatchPredictionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteBatchPrediction");
         request.add_header("x-amz-target", "AmazonML_20141212.DeleteBatchPrediction");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3294,6 +3706,7 @@ impl MachineLearning for MachineLearningClient {
         input: DeleteDataSourceInput,
     ) -> Result<DeleteDataSourceOutput, RusotoError<DeleteDataSourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteDataSource");
         request.add_header("x-amz-target", "AmazonML_20141212.DeleteDataSource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3312,6 +3725,7 @@ impl MachineLearning for MachineLearningClient {
         input: DeleteEvaluationInput,
     ) -> Result<DeleteEvaluationOutput, RusotoError<DeleteEvaluationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteEvaluation");
         request.add_header("x-amz-target", "AmazonML_20141212.DeleteEvaluation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3330,6 +3744,7 @@ impl MachineLearning for MachineLearningClient {
         input: DeleteMLModelInput,
     ) -> Result<DeleteMLModelOutput, RusotoError<DeleteMLModelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteMLModel");
         request.add_header("x-amz-target", "AmazonML_20141212.DeleteMLModel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3348,6 +3763,7 @@ impl MachineLearning for MachineLearningClient {
         input: DeleteRealtimeEndpointInput,
     ) -> Result<DeleteRealtimeEndpointOutput, RusotoError<DeleteRealtimeEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteRealtimeEndpoint");
         request.add_header("x-amz-target", "AmazonML_20141212.DeleteRealtimeEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3367,6 +3783,7 @@ impl MachineLearning for MachineLearningClient {
         input: DeleteTagsInput,
     ) -> Result<DeleteTagsOutput, RusotoError<DeleteTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteTags");
         request.add_header("x-amz-target", "AmazonML_20141212.DeleteTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3385,6 +3802,7 @@ impl MachineLearning for MachineLearningClient {
         input: DescribeBatchPredictionsInput,
     ) -> Result<DescribeBatchPredictionsOutput, RusotoError<DescribeBatchPredictionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeBatchPredictions");
         request.add_header("x-amz-target", "AmazonML_20141212.DescribeBatchPredictions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3404,6 +3822,7 @@ impl MachineLearning for MachineLearningClient {
         input: DescribeDataSourcesInput,
     ) -> Result<DescribeDataSourcesOutput, RusotoError<DescribeDataSourcesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeDataSources");
         request.add_header("x-amz-target", "AmazonML_20141212.DescribeDataSources");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3422,6 +3841,7 @@ impl MachineLearning for MachineLearningClient {
         input: DescribeEvaluationsInput,
     ) -> Result<DescribeEvaluationsOutput, RusotoError<DescribeEvaluationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeEvaluations");
         request.add_header("x-amz-target", "AmazonML_20141212.DescribeEvaluations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3440,6 +3860,7 @@ impl MachineLearning for MachineLearningClient {
         input: DescribeMLModelsInput,
     ) -> Result<DescribeMLModelsOutput, RusotoError<DescribeMLModelsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeMLModels");
         request.add_header("x-amz-target", "AmazonML_20141212.DescribeMLModels");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3458,6 +3879,7 @@ impl MachineLearning for MachineLearningClient {
         input: DescribeTagsInput,
     ) -> Result<DescribeTagsOutput, RusotoError<DescribeTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeTags");
         request.add_header("x-amz-target", "AmazonML_20141212.DescribeTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3476,6 +3898,7 @@ impl MachineLearning for MachineLearningClient {
         input: GetBatchPredictionInput,
     ) -> Result<GetBatchPredictionOutput, RusotoError<GetBatchPredictionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetBatchPrediction");
         request.add_header("x-amz-target", "AmazonML_20141212.GetBatchPrediction");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3494,6 +3917,7 @@ impl MachineLearning for MachineLearningClient {
         input: GetDataSourceInput,
     ) -> Result<GetDataSourceOutput, RusotoError<GetDataSourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDataSource");
         request.add_header("x-amz-target", "AmazonML_20141212.GetDataSource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3512,6 +3936,7 @@ impl MachineLearning for MachineLearningClient {
         input: GetEvaluationInput,
     ) -> Result<GetEvaluationOutput, RusotoError<GetEvaluationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetEvaluation");
         request.add_header("x-amz-target", "AmazonML_20141212.GetEvaluation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3530,6 +3955,7 @@ impl MachineLearning for MachineLearningClient {
         input: GetMLModelInput,
     ) -> Result<GetMLModelOutput, RusotoError<GetMLModelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetMLModel");
         request.add_header("x-amz-target", "AmazonML_20141212.GetMLModel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3548,6 +3974,7 @@ impl MachineLearning for MachineLearningClient {
         input: PredictInput,
     ) -> Result<PredictOutput, RusotoError<PredictError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("Predict");
         request.add_header("x-amz-target", "AmazonML_20141212.Predict");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3566,6 +3993,7 @@ impl MachineLearning for MachineLearningClient {
         input: UpdateBatchPredictionInput,
     ) -> Result<UpdateBatchPredictionOutput, RusotoError<UpdateBatchPredictionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateBatchPrediction");
         request.add_header("x-amz-target", "AmazonML_20141212.UpdateBatchPrediction");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3584,6 +4012,7 @@ impl MachineLearning for MachineLearningClient {
         input: UpdateDataSourceInput,
     ) -> Result<UpdateDataSourceOutput, RusotoError<UpdateDataSourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateDataSource");
         request.add_header("x-amz-target", "AmazonML_20141212.UpdateDataSource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3602,6 +4031,7 @@ impl MachineLearning for MachineLearningClient {
         input: UpdateEvaluationInput,
     ) -> Result<UpdateEvaluationOutput, RusotoError<UpdateEvaluationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateEvaluation");
         request.add_header("x-amz-target", "AmazonML_20141212.UpdateEvaluation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3620,6 +4050,7 @@ impl MachineLearning for MachineLearningClient {
         input: UpdateMLModelInput,
     ) -> Result<UpdateMLModelOutput, RusotoError<UpdateMLModelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateMLModel");
         request.add_header("x-amz-target", "AmazonML_20141212.UpdateMLModel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3632,3 +4063,320 @@ impl MachineLearning for MachineLearningClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateMLModelOutput, _>()
     }
 }
+
+/// A blocking client for the Amazon Machine Learning API, running the calls of a `MachineLearningClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `MachineLearningClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct MachineLearningBlockingClient {
+    client: MachineLearningClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl MachineLearningBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> MachineLearningBlockingClient {
+        MachineLearningBlockingClient::from_client(MachineLearningClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> MachineLearningBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        MachineLearningBlockingClient::from_client(MachineLearningClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> MachineLearningBlockingClient {
+        MachineLearningBlockingClient::from_client(MachineLearningClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> MachineLearningBlockingClient {
+        MachineLearningBlockingClient::from_client(MachineLearningClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: MachineLearningClient) -> MachineLearningBlockingClient {
+        MachineLearningBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> MachineLearningBlockingClient {
+        MachineLearningBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> MachineLearningBlockingClient {
+        MachineLearningBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`MachineLearning::add_tags`].
+    pub fn add_tags(
+        &self,
+        input: AddTagsInput,
+    ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
+        self.runtime.block_on(self.client.add_tags(input))
+    }
+
+    /// Blocking version of [`MachineLearning::create_batch_prediction`].
+    pub fn create_batch_prediction(
+        &self,
+        input: CreateBatchPredictionInput,
+    ) -> Result<CreateBatchPredictionOutput, RusotoError<CreateBatchPredictionError>> {
+        self.runtime
+            .block_on(self.client.create_batch_prediction(input))
+    }
+
+    /// Blocking version of [`MachineLearning::create_data_source_from_rds`].
+    pub fn create_data_source_from_rds(
+        &self,
+        input: CreateDataSourceFromRDSInput,
+    ) -> Result<CreateDataSourceFromRDSOutput, RusotoError<CreateDataSourceFromRDSError>> {
+        self.runtime
+            .block_on(self.client.create_data_source_from_rds(input))
+    }
+
+    /// Blocking version of [`MachineLearning::create_data_source_from_redshift`].
+    pub fn create_data_source_from_redshift(
+        &self,
+        input: CreateDataSourceFromRedshiftInput,
+    ) -> Result<CreateDataSourceFromRedshiftOutput, RusotoError<CreateDataSourceFromRedshiftError>>
+    {
+        self.runtime
+            .block_on(self.client.create_data_source_from_redshift(input))
+    }
+
+    /// Blocking version of [`MachineLearning::create_data_source_from_s3`].
+    pub fn create_data_source_from_s3(
+        &self,
+        input: CreateDataSourceFromS3Input,
+    ) -> Result<CreateDataSourceFromS3Output, RusotoError<CreateDataSourceFromS3Error>> {
+        self.runtime
+            .block_on(self.client.create_data_source_from_s3(input))
+    }
+
+    /// Blocking version of [`MachineLearning::create_evaluation`].
+    pub fn create_evaluation(
+        &self,
+        input: CreateEvaluationInput,
+    ) -> Result<CreateEvaluationOutput, RusotoError<CreateEvaluationError>> {
+        self.runtime.block_on(self.client.create_evaluation(input))
+    }
+
+    /// Blocking version of [`MachineLearning::create_ml_model`].
+    pub fn create_ml_model(
+        &self,
+        input: CreateMLModelInput,
+    ) -> Result<CreateMLModelOutput, RusotoError<CreateMLModelError>> {
+        self.runtime.block_on(self.client.create_ml_model(input))
+    }
+
+    /// Blocking version of [`MachineLearning::create_realtime_endpoint`].
+    pub fn create_realtime_endpoint(
+        &self,
+        input: CreateRealtimeEndpointInput,
+    ) -> Result<CreateRealtimeEndpointOutput, RusotoError<CreateRealtimeEndpointError>> {
+        self.runtime
+            .block_on(self.client.create_realtime_endpoint(input))
+    }
+
+    /// Blocking version of [`MachineLearning::delete_batch_prediction`].
+    pub fn delete_batch_prediction(
+        &self,
+        input: DeleteBatchPredictionInput,
+    ) -> Result<DeleteBatchPredictionOutput, RusotoError<DeleteBatchPredictionError>> {
+        self.runtime
+            .block_on(self.client.delete_batch_prediction(input))
+    }
+
+    /// Blocking version of [`MachineLearning::delete_data_source`].
+    pub fn delete_data_source(
+        &self,
+        input: DeleteDataSourceInput,
+    ) -> Result<DeleteDataSourceOutput, RusotoError<DeleteDataSourceError>> {
+        self.runtime.block_on(self.client.delete_data_source(input))
+    }
+
+    /// Blocking version of [`MachineLearning::delete_evaluation`].
+    pub fn delete_evaluation(
+        &self,
+        input: DeleteEvaluationInput,
+    ) -> Result<DeleteEvaluationOutput, RusotoError<DeleteEvaluationError>> {
+        self.runtime.block_on(self.client.delete_evaluation(input))
+    }
+
+    /// Blocking version of [`MachineLearning::delete_ml_model`].
+    pub fn delete_ml_model(
+        &self,
+        input: DeleteMLModelInput,
+    ) -> Result<DeleteMLModelOutput, RusotoError<DeleteMLModelError>> {
+        self.runtime.block_on(self.client.delete_ml_model(input))
+    }
+
+    /// Blocking version of [`MachineLearning::delete_realtime_endpoint`].
+    pub fn delete_realtime_endpoint(
+        &self,
+        input: DeleteRealtimeEndpointInput,
+    ) -> Result<DeleteRealtimeEndpointOutput, RusotoError<DeleteRealtimeEndpointError>> {
+        self.runtime
+            .block_on(self.client.delete_realtime_endpoint(input))
+    }
+
+    /// Blocking version of [`MachineLearning::delete_tags`].
+    pub fn delete_tags(
+        &self,
+        input: DeleteTagsInput,
+    ) -> Result<DeleteTagsOutput, RusotoError<DeleteTagsError>> {
+        self.runtime.block_on(self.client.delete_tags(input))
+    }
+
+    /// Blocking version of [`MachineLearning::describe_batch_predictions`].
+    pub fn describe_batch_predictions(
+        &self,
+        input: DescribeBatchPredictionsInput,
+    ) -> Result<DescribeBatchPredictionsOutput, RusotoError<DescribeBatchPredictionsError>> {
+        self.runtime
+            .block_on(self.client.describe_batch_predictions(input))
+    }
+
+    /// Blocking version of [`MachineLearning::describe_data_sources`].
+    pub fn describe_data_sources(
+        &self,
+        input: DescribeDataSourcesInput,
+    ) -> Result<DescribeDataSourcesOutput, RusotoError<DescribeDataSourcesError>> {
+        self.runtime
+            .block_on(self.client.describe_data_sources(input))
+    }
+
+    /// Blocking version of [`MachineLearning::describe_evaluations`].
+    pub fn describe_evaluations(
+        &self,
+        input: DescribeEvaluationsInput,
+    ) -> Result<DescribeEvaluationsOutput, RusotoError<DescribeEvaluationsError>> {
+        self.runtime
+            .block_on(self.client.describe_evaluations(input))
+    }
+
+    /// Blocking version of [`MachineLearning::describe_ml_models`].
+    pub fn describe_ml_models(
+        &self,
+        input: DescribeMLModelsInput,
+    ) -> Result<DescribeMLModelsOutput, RusotoError<DescribeMLModelsError>> {
+        self.runtime.block_on(self.client.describe_ml_models(input))
+    }
+
+    /// Blocking version of [`MachineLearning::describe_tags`].
+    pub fn describe_tags(
+        &self,
+        input: DescribeTagsInput,
+    ) -> Result<DescribeTagsOutput, RusotoError<DescribeTagsError>> {
+        self.runtime.block_on(self.client.describe_tags(input))
+    }
+
+    /// Blocking version of [`MachineLearning::get_batch_prediction`].
+    pub fn get_batch_prediction(
+        &self,
+        input: GetBatchPredictionInput,
+    ) -> Result<GetBatchPredictionOutput, RusotoError<GetBatchPredictionError>> {
+        self.runtime
+            .block_on(self.client.get_batch_prediction(input))
+    }
+
+    /// Blocking version of [`MachineLearning::get_data_source`].
+    pub fn get_data_source(
+        &self,
+        input: GetDataSourceInput,
+    ) -> Result<GetDataSourceOutput, RusotoError<GetDataSourceError>> {
+        self.runtime.block_on(self.client.get_data_source(input))
+    }
+
+    /// Blocking version of [`MachineLearning::get_evaluation`].
+    pub fn get_evaluation(
+        &self,
+        input: GetEvaluationInput,
+    ) -> Result<GetEvaluationOutput, RusotoError<GetEvaluationError>> {
+        self.runtime.block_on(self.client.get_evaluation(input))
+    }
+
+    /// Blocking version of [`MachineLearning::get_ml_model`].
+    pub fn get_ml_model(
+        &self,
+        input: GetMLModelInput,
+    ) -> Result<GetMLModelOutput, RusotoError<GetMLModelError>> {
+        self.runtime.block_on(self.client.get_ml_model(input))
+    }
+
+    /// Blocking version of [`MachineLearning::predict`].
+    pub fn predict(&self, input: PredictInput) -> Result<PredictOutput, RusotoError<PredictError>> {
+        self.runtime.block_on(self.client.predict(input))
+    }
+
+    /// Blocking version of [`MachineLearning::update_batch_prediction`].
+    pub fn update_batch_prediction(
+        &self,
+        input: UpdateBatchPredictionInput,
+    ) -> Result<UpdateBatchPredictionOutput, RusotoError<UpdateBatchPredictionError>> {
+        self.runtime
+            .block_on(self.client.update_batch_prediction(input))
+    }
+
+    /// Blocking version of [`MachineLearning::update_data_source`].
+    pub fn update_data_source(
+        &self,
+        input: UpdateDataSourceInput,
+    ) -> Result<UpdateDataSourceOutput, RusotoError<UpdateDataSourceError>> {
+        self.runtime.block_on(self.client.update_data_source(input))
+    }
+
+    /// Blocking version of [`MachineLearning::update_evaluation`].
+    pub fn update_evaluation(
+        &self,
+        input: UpdateEvaluationInput,
+    ) -> Result<UpdateEvaluationOutput, RusotoError<UpdateEvaluationError>> {
+        self.runtime.block_on(self.client.update_evaluation(input))
+    }
+
+    /// Blocking version of [`MachineLearning::update_ml_model`].
+    pub fn update_ml_model(
+        &self,
+        input: UpdateMLModelInput,
+    ) -> Result<UpdateMLModelOutput, RusotoError<UpdateMLModelError>> {
+        self.runtime.block_on(self.client.update_ml_model(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -297,17 +298,30 @@ impl AssociateMemberAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(AssociateMemberAccountError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateMemberAccountError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AssociateMemberAccountError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateMemberAccountError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AssociateMemberAccountError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateMemberAccountError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -343,18 +357,38 @@ impl AssociateS3ResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AssociateS3ResourcesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateS3ResourcesError::AccessDenied,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(AssociateS3ResourcesError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateS3ResourcesError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AssociateS3ResourcesError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateS3ResourcesError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AssociateS3ResourcesError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateS3ResourcesError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -387,14 +421,22 @@ impl DisassociateMemberAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(DisassociateMemberAccountError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateMemberAccountError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DisassociateMemberAccountError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateMemberAccountError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -427,19 +469,30 @@ impl DisassociateS3ResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DisassociateS3ResourcesError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateS3ResourcesError::AccessDenied,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(DisassociateS3ResourcesError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateS3ResourcesError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DisassociateS3ResourcesError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateS3ResourcesError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -471,12 +524,22 @@ impl ListMemberAccountsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalException" => {
-                    return RusotoError::Service(ListMemberAccountsError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMemberAccountsError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListMemberAccountsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMemberAccountsError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -509,15 +572,30 @@ impl ListS3ResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListS3ResourcesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListS3ResourcesError::AccessDenied,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(ListS3ResourcesError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListS3ResourcesError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListS3ResourcesError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListS3ResourcesError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -551,15 +629,30 @@ impl UpdateS3ResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateS3ResourcesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateS3ResourcesError::AccessDenied,
+                    )
                 }
                 "InternalException" => {
-                    return RusotoError::Service(UpdateS3ResourcesError::Internal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateS3ResourcesError::Internal,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateS3ResourcesError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateS3ResourcesError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -632,11 +725,12 @@ pub struct MacieClient {
 impl MacieClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> MacieClient {
         MacieClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Macie"),
         }
     }
 
@@ -651,12 +745,32 @@ impl MacieClient {
     {
         MacieClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Macie"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> MacieClient {
-        MacieClient { client, region }
+        MacieClient {
+            client,
+            region: region_for_service(region, "Macie"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> MacieClient {
+        MacieClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Macie"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> MacieClient {
+        MacieClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -668,6 +782,7 @@ impl Macie for MacieClient {
         input: AssociateMemberAccountRequest,
     ) -> Result<(), RusotoError<AssociateMemberAccountError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateMemberAccount");
         request.add_header("x-amz-target", "MacieService.AssociateMemberAccount");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -685,6 +800,7 @@ impl Macie for MacieClient {
         input: AssociateS3ResourcesRequest,
     ) -> Result<AssociateS3ResourcesResult, RusotoError<AssociateS3ResourcesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateS3Resources");
         request.add_header("x-amz-target", "MacieService.AssociateS3Resources");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -703,6 +819,7 @@ impl Macie for MacieClient {
         input: DisassociateMemberAccountRequest,
     ) -> Result<(), RusotoError<DisassociateMemberAccountError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisassociateMemberAccount");
         request.add_header("x-amz-target", "MacieService.DisassociateMemberAccount");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -720,6 +837,7 @@ impl Macie for MacieClient {
         input: DisassociateS3ResourcesRequest,
     ) -> Result<DisassociateS3ResourcesResult, RusotoError<DisassociateS3ResourcesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisassociateS3Resources");
         request.add_header("x-amz-target", "MacieService.DisassociateS3Resources");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -739,6 +857,7 @@ impl Macie for MacieClient {
         input: ListMemberAccountsRequest,
     ) -> Result<ListMemberAccountsResult, RusotoError<ListMemberAccountsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListMemberAccounts");
         request.add_header("x-amz-target", "MacieService.ListMemberAccounts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -757,6 +876,7 @@ impl Macie for MacieClient {
         input: ListS3ResourcesRequest,
     ) -> Result<ListS3ResourcesResult, RusotoError<ListS3ResourcesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListS3Resources");
         request.add_header("x-amz-target", "MacieService.ListS3Resources");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -775,6 +895,7 @@ impl Macie for MacieClient {
         input: UpdateS3ResourcesRequest,
     ) -> Result<UpdateS3ResourcesResult, RusotoError<UpdateS3ResourcesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateS3Resources");
         request.add_header("x-amz-target", "MacieService.UpdateS3Resources");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -787,3 +908,140 @@ impl Macie for MacieClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateS3ResourcesResult, _>()
     }
 }
+
+/// A blocking client for the Amazon Macie API, running the calls of a `MacieClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `MacieClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct MacieBlockingClient {
+    client: MacieClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl MacieBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> MacieBlockingClient {
+        MacieBlockingClient::from_client(MacieClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> MacieBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        MacieBlockingClient::from_client(MacieClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> MacieBlockingClient {
+        MacieBlockingClient::from_client(MacieClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> MacieBlockingClient {
+        MacieBlockingClient::from_client(MacieClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: MacieClient) -> MacieBlockingClient {
+        MacieBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> MacieBlockingClient {
+        MacieBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> MacieBlockingClient {
+        MacieBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Macie::associate_member_account`].
+    pub fn associate_member_account(
+        &self,
+        input: AssociateMemberAccountRequest,
+    ) -> Result<(), RusotoError<AssociateMemberAccountError>> {
+        self.runtime
+            .block_on(self.client.associate_member_account(input))
+    }
+
+    /// Blocking version of [`Macie::associate_s3_resources`].
+    pub fn associate_s3_resources(
+        &self,
+        input: AssociateS3ResourcesRequest,
+    ) -> Result<AssociateS3ResourcesResult, RusotoError<AssociateS3ResourcesError>> {
+        self.runtime
+            .block_on(self.client.associate_s3_resources(input))
+    }
+
+    /// Blocking version of [`Macie::disassociate_member_account`].
+    pub fn disassociate_member_account(
+        &self,
+        input: DisassociateMemberAccountRequest,
+    ) -> Result<(), RusotoError<DisassociateMemberAccountError>> {
+        self.runtime
+            .block_on(self.client.disassociate_member_account(input))
+    }
+
+    /// Blocking version of [`Macie::disassociate_s3_resources`].
+    pub fn disassociate_s3_resources(
+        &self,
+        input: DisassociateS3ResourcesRequest,
+    ) -> Result<DisassociateS3ResourcesResult, RusotoError<DisassociateS3ResourcesError>> {
+        self.runtime
+            .block_on(self.client.disassociate_s3_resources(input))
+    }
+
+    /// Blocking version of [`Macie::list_member_accounts`].
+    pub fn list_member_accounts(
+        &self,
+        input: ListMemberAccountsRequest,
+    ) -> Result<ListMemberAccountsResult, RusotoError<ListMemberAccountsError>> {
+        self.runtime
+            .block_on(self.client.list_member_accounts(input))
+    }
+
+    /// Blocking version of [`Macie::list_s3_resources`].
+    pub fn list_s3_resources(
+        &self,
+        input: ListS3ResourcesRequest,
+    ) -> Result<ListS3ResourcesResult, RusotoError<ListS3ResourcesError>> {
+        self.runtime.block_on(self.client.list_s3_resources(input))
+    }
+
+    /// Blocking version of [`Macie::update_s3_resources`].
+    pub fn update_s3_resources(
+        &self,
+        input: UpdateS3ResourcesRequest,
+    ) -> Result<UpdateS3ResourcesResult, RusotoError<UpdateS3ResourcesError>> {
+        self.runtime
+            .block_on(self.client.update_s3_resources(input))
+    }
+}
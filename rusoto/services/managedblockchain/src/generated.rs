@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1221,30 +1222,70 @@ impl CreateMemberError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateMemberError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMemberError::AccessDenied,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(CreateMemberError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMemberError::InternalServiceError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateMemberError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMemberError::InvalidRequest,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateMemberError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMemberError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateMemberError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMemberError::ResourceLimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateMemberError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMemberError::ResourceNotFound,
+                    )
                 }
                 "ResourceNotReadyException" => {
-                    return RusotoError::Service(CreateMemberError::ResourceNotReady(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMemberError::ResourceNotReady,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateMemberError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMemberError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1289,24 +1330,54 @@ impl CreateNetworkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateNetworkError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNetworkError::AccessDenied,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(CreateNetworkError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNetworkError::InternalServiceError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateNetworkError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNetworkError::InvalidRequest,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateNetworkError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNetworkError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateNetworkError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNetworkError::ResourceLimitExceeded,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateNetworkError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNetworkError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1353,30 +1424,70 @@ impl CreateNodeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateNodeError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNodeError::AccessDenied,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(CreateNodeError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNodeError::InternalServiceError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateNodeError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNodeError::InvalidRequest,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateNodeError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNodeError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(CreateNodeError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNodeError::ResourceLimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateNodeError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNodeError::ResourceNotFound,
+                    )
                 }
                 "ResourceNotReadyException" => {
-                    return RusotoError::Service(CreateNodeError::ResourceNotReady(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNodeError::ResourceNotReady,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateNodeError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNodeError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1421,24 +1532,54 @@ impl CreateProposalError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateProposalError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProposalError::AccessDenied,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(CreateProposalError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProposalError::InternalServiceError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateProposalError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProposalError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateProposalError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProposalError::ResourceNotFound,
+                    )
                 }
                 "ResourceNotReadyException" => {
-                    return RusotoError::Service(CreateProposalError::ResourceNotReady(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProposalError::ResourceNotReady,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateProposalError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProposalError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1481,24 +1622,54 @@ impl DeleteMemberError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteMemberError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMemberError::AccessDenied,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(DeleteMemberError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMemberError::InternalServiceError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteMemberError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMemberError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteMemberError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMemberError::ResourceNotFound,
+                    )
                 }
                 "ResourceNotReadyException" => {
-                    return RusotoError::Service(DeleteMemberError::ResourceNotReady(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMemberError::ResourceNotReady,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteMemberError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMemberError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1541,24 +1712,54 @@ impl DeleteNodeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteNodeError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNodeError::AccessDenied,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(DeleteNodeError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNodeError::InternalServiceError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteNodeError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNodeError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteNodeError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNodeError::ResourceNotFound,
+                    )
                 }
                 "ResourceNotReadyException" => {
-                    return RusotoError::Service(DeleteNodeError::ResourceNotReady(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNodeError::ResourceNotReady,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteNodeError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNodeError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1599,21 +1800,46 @@ impl GetMemberError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetMemberError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMemberError::AccessDenied,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(GetMemberError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMemberError::InternalServiceError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetMemberError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMemberError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetMemberError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMemberError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetMemberError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMemberError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1653,21 +1879,46 @@ impl GetNetworkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetNetworkError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetNetworkError::AccessDenied,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(GetNetworkError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetNetworkError::InternalServiceError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetNetworkError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetNetworkError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetNetworkError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetNetworkError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetNetworkError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetNetworkError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1707,21 +1958,46 @@ impl GetNodeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetNodeError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetNodeError::AccessDenied,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(GetNodeError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetNodeError::InternalServiceError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetNodeError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetNodeError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetNodeError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetNodeError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetNodeError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetNodeError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1761,21 +2037,46 @@ impl GetProposalError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetProposalError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetProposalError::AccessDenied,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(GetProposalError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetProposalError::InternalServiceError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetProposalError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetProposalError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetProposalError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetProposalError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetProposalError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetProposalError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1817,28 +2118,54 @@ impl ListInvitationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListInvitationsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInvitationsError::AccessDenied,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListInvitationsError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListInvitationsError::InternalServiceError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListInvitationsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInvitationsError::InvalidRequest,
+                    )
                 }
                 "ResourceLimitExceededException" => {
-                    return RusotoError::Service(ListInvitationsError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListInvitationsError::ResourceLimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListInvitationsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInvitationsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListInvitationsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInvitationsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1877,18 +2204,38 @@ impl ListMembersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListMembersError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMembersError::AccessDenied,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListMembersError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMembersError::InternalServiceError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListMembersError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMembersError::InvalidRequest,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListMembersError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMembersError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1925,18 +2272,38 @@ impl ListNetworksError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListNetworksError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListNetworksError::AccessDenied,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListNetworksError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListNetworksError::InternalServiceError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListNetworksError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListNetworksError::InvalidRequest,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListNetworksError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListNetworksError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1973,18 +2340,38 @@ impl ListNodesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListNodesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListNodesError::AccessDenied,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListNodesError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListNodesError::InternalServiceError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListNodesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListNodesError::InvalidRequest,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListNodesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListNodesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2021,20 +2408,38 @@ impl ListProposalVotesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListProposalVotesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProposalVotesError::AccessDenied,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListProposalVotesError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListProposalVotesError::InternalServiceError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListProposalVotesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProposalVotesError::InvalidRequest,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListProposalVotesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProposalVotesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2073,21 +2478,46 @@ impl ListProposalsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListProposalsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProposalsError::AccessDenied,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListProposalsError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProposalsError::InternalServiceError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListProposalsError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProposalsError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListProposalsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProposalsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListProposalsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProposalsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2129,26 +2559,54 @@ impl RejectInvitationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RejectInvitationError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RejectInvitationError::AccessDenied,
+                    )
                 }
                 "IllegalActionException" => {
-                    return RusotoError::Service(RejectInvitationError::IllegalAction(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RejectInvitationError::IllegalAction,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(RejectInvitationError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RejectInvitationError::InternalServiceError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(RejectInvitationError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RejectInvitationError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RejectInvitationError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RejectInvitationError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RejectInvitationError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RejectInvitationError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2189,21 +2647,46 @@ impl UpdateMemberError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateMemberError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMemberError::AccessDenied,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(UpdateMemberError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMemberError::InternalServiceError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateMemberError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMemberError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateMemberError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMemberError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateMemberError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMemberError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2243,21 +2726,46 @@ impl UpdateNodeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateNodeError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateNodeError::AccessDenied,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(UpdateNodeError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateNodeError::InternalServiceError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateNodeError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateNodeError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateNodeError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateNodeError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateNodeError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateNodeError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2299,24 +2807,54 @@ impl VoteOnProposalError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(VoteOnProposalError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        VoteOnProposalError::AccessDenied,
+                    )
                 }
                 "IllegalActionException" => {
-                    return RusotoError::Service(VoteOnProposalError::IllegalAction(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        VoteOnProposalError::IllegalAction,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(VoteOnProposalError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        VoteOnProposalError::InternalServiceError,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(VoteOnProposalError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        VoteOnProposalError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(VoteOnProposalError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        VoteOnProposalError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(VoteOnProposalError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        VoteOnProposalError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2470,11 +3008,12 @@ pub struct ManagedBlockchainClient {
 impl ManagedBlockchainClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> ManagedBlockchainClient {
         ManagedBlockchainClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "ManagedBlockchain"),
         }
     }
 
@@ -2489,12 +3028,32 @@ impl ManagedBlockchainClient {
     {
         ManagedBlockchainClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "ManagedBlockchain"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ManagedBlockchainClient {
-        ManagedBlockchainClient { client, region }
+        ManagedBlockchainClient {
+            client,
+            region: region_for_service(region, "ManagedBlockchain"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> ManagedBlockchainClient {
+        ManagedBlockchainClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("ManagedBlockchain"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ManagedBlockchainClient {
+        ManagedBlockchainClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -2513,6 +3072,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("POST", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("CreateMember");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2545,6 +3105,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("POST", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("CreateNetwork");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2580,6 +3141,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("POST", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("CreateNode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2615,6 +3177,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("POST", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("CreateProposal");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2651,6 +3214,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("DELETE", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("DeleteMember");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2684,6 +3248,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("DELETE", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("DeleteNode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2723,6 +3288,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("GetMember");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2752,6 +3318,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("GetNetwork");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2785,6 +3352,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("GetNode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2824,6 +3392,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("GetProposal");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2853,6 +3422,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("ListInvitations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2894,6 +3464,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("ListMembers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2941,6 +3512,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("ListNetworks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2991,6 +3563,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("ListNodes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3039,6 +3612,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("ListProposalVotes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3080,6 +3654,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("GET", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("ListProposals");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3121,6 +3696,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("DELETE", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("RejectInvitation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3154,6 +3730,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("PATCH", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("UpdateMember");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3190,6 +3767,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("PATCH", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("UpdateNode");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3226,6 +3804,7 @@ impl ManagedBlockchain for ManagedBlockchainClient {
 
         let mut request =
             SignedRequest::new("POST", "managedblockchain", &self.region, &request_uri);
+        request.set_operation("VoteOnProposal");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3248,3 +3827,249 @@ impl ManagedBlockchain for ManagedBlockchainClient {
         }
     }
 }
+
+/// A blocking client for the ManagedBlockchain API, running the calls of a `ManagedBlockchainClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `ManagedBlockchainClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ManagedBlockchainBlockingClient {
+    client: ManagedBlockchainClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ManagedBlockchainBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> ManagedBlockchainBlockingClient {
+        ManagedBlockchainBlockingClient::from_client(ManagedBlockchainClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ManagedBlockchainBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ManagedBlockchainBlockingClient::from_client(ManagedBlockchainClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> ManagedBlockchainBlockingClient {
+        ManagedBlockchainBlockingClient::from_client(ManagedBlockchainClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> ManagedBlockchainBlockingClient {
+        ManagedBlockchainBlockingClient::from_client(ManagedBlockchainClient::new_with_config(
+            config,
+        ))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: ManagedBlockchainClient) -> ManagedBlockchainBlockingClient {
+        ManagedBlockchainBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> ManagedBlockchainBlockingClient {
+        ManagedBlockchainBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ManagedBlockchainBlockingClient {
+        ManagedBlockchainBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`ManagedBlockchain::create_member`].
+    pub fn create_member(
+        &self,
+        input: CreateMemberInput,
+    ) -> Result<CreateMemberOutput, RusotoError<CreateMemberError>> {
+        self.runtime.block_on(self.client.create_member(input))
+    }
+
+    /// Blocking version of [`ManagedBlockchain::create_network`].
+    pub fn create_network(
+        &self,
+        input: CreateNetworkInput,
+    ) -> Result<CreateNetworkOutput, RusotoError<CreateNetworkError>> {
+        self.runtime.block_on(self.client.create_network(input))
+    }
+
+    /// Blocking version of [`ManagedBlockchain::create_node`].
+    pub fn create_node(
+        &self,
+        input: CreateNodeInput,
+    ) -> Result<CreateNodeOutput, RusotoError<CreateNodeError>> {
+        self.runtime.block_on(self.client.create_node(input))
+    }
+
+    /// Blocking version of [`ManagedBlockchain::create_proposal`].
+    pub fn create_proposal(
+        &self,
+        input: CreateProposalInput,
+    ) -> Result<CreateProposalOutput, RusotoError<CreateProposalError>> {
+        self.runtime.block_on(self.client.create_proposal(input))
+    }
+
+    /// Blocking version of [`ManagedBlockchain::delete_member`].
+    pub fn delete_member(
+        &self,
+        input: DeleteMemberInput,
+    ) -> Result<DeleteMemberOutput, RusotoError<DeleteMemberError>> {
+        self.runtime.block_on(self.client.delete_member(input))
+    }
+
+    /// Blocking version of [`ManagedBlockchain::delete_node`].
+    pub fn delete_node(
+        &self,
+        input: DeleteNodeInput,
+    ) -> Result<DeleteNodeOutput, RusotoError<DeleteNodeError>> {
+        self.runtime.block_on(self.client.delete_node(input))
+    }
+
+    /// Blocking version of [`ManagedBlockchain::get_member`].
+    pub fn get_member(
+        &self,
+        input: GetMemberInput,
+    ) -> Result<GetMemberOutput, RusotoError<GetMemberError>> {
+        self.runtime.block_on(self.client.get_member(input))
+    }
+
+    /// Blocking version of [`ManagedBlockchain::get_network`].
+    pub fn get_network(
+        &self,
+        input: GetNetworkInput,
+    ) -> Result<GetNetworkOutput, RusotoError<GetNetworkError>> {
+        self.runtime.block_on(self.client.get_network(input))
+    }
+
+    /// Blocking version of [`ManagedBlockchain::get_node`].
+    pub fn get_node(
+        &self,
+        input: GetNodeInput,
+    ) -> Result<GetNodeOutput, RusotoError<GetNodeError>> {
+        self.runtime.block_on(self.client.get_node(input))
+    }
+
+    /// Blocking version of [`ManagedBlockchain::get_proposal`].
+    pub fn get_proposal(
+        &self,
+        input: GetProposalInput,
+    ) -> Result<GetProposalOutput, RusotoError<GetProposalError>> {
+        self.runtime.block_on(self.client.get_proposal(input))
+    }
+
+    /// Blocking version of [`ManagedBlockchain::list_invitations`].
+    pub fn list_invitations(
+        &self,
+        input: ListInvitationsInput,
+    ) -> Result<ListInvitationsOutput, RusotoError<ListInvitationsError>> {
+        self.runtime.block_on(self.client.list_invitations(input))
+    }
+
+    /// Blocking version of [`ManagedBlockchain::list_members`].
+    pub fn list_members(
+        &self,
+        input: ListMembersInput,
+    ) -> Result<ListMembersOutput, RusotoError<ListMembersError>> {
+        self.runtime.block_on(self.client.list_members(input))
+    }
+
+    /// Blocking version of [`ManagedBlockchain::list_networks`].
+    pub fn list_networks(
+        &self,
+        input: ListNetworksInput,
+    ) -> Result<ListNetworksOutput, RusotoError<ListNetworksError>> {
+        self.runtime.block_on(self.client.list_networks(input))
+    }
+
+    /// Blocking version of [`ManagedBlockchain::list_nodes`].
+    pub fn list_nodes(
+        &self,
+        input: ListNodesInput,
+    ) -> Result<ListNodesOutput, RusotoError<ListNodesError>> {
+        self.runtime.block_on(self.client.list_nodes(input))
+    }
+
+    /// Blocking version of [`ManagedBlockchain::list_proposal_votes`].
+    pub fn list_proposal_votes(
+        &self,
+        input: ListProposalVotesInput,
+    ) -> Result<ListProposalVotesOutput, RusotoError<ListProposalVotesError>> {
+        self.runtime
+            .block_on(self.client.list_proposal_votes(input))
+    }
+
+    /// Blocking version of [`ManagedBlockchain::list_proposals`].
+    pub fn list_proposals(
+        &self,
+        input: ListProposalsInput,
+    ) -> Result<ListProposalsOutput, RusotoError<ListProposalsError>> {
+        self.runtime.block_on(self.client.list_proposals(input))
+    }
+
+    /// Blocking version of [`ManagedBlockchain::reject_invitation`].
+    pub fn reject_invitation(
+        &self,
+        input: RejectInvitationInput,
+    ) -> Result<RejectInvitationOutput, RusotoError<RejectInvitationError>> {
+        self.runtime.block_on(self.client.reject_invitation(input))
+    }
+
+    /// Blocking version of [`ManagedBlockchain::update_member`].
+    pub fn update_member(
+        &self,
+        input: UpdateMemberInput,
+    ) -> Result<UpdateMemberOutput, RusotoError<UpdateMemberError>> {
+        self.runtime.block_on(self.client.update_member(input))
+    }
+
+    /// Blocking version of [`ManagedBlockchain::update_node`].
+    pub fn update_node(
+        &self,
+        input: UpdateNodeInput,
+    ) -> Result<UpdateNodeOutput, RusotoError<UpdateNodeError>> {
+        self.runtime.block_on(self.client.update_node(input))
+    }
+
+    /// Blocking version of [`ManagedBlockchain::vote_on_proposal`].
+    pub fn vote_on_proposal(
+        &self,
+        input: VoteOnProposalInput,
+    ) -> Result<VoteOnProposalOutput, RusotoError<VoteOnProposalError>> {
+        self.runtime.block_on(self.client.vote_on_proposal(input))
+    }
+}
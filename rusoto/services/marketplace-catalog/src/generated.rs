@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -425,21 +426,46 @@ impl CancelChangeSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CancelChangeSetError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelChangeSetError::AccessDenied,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(CancelChangeSetError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelChangeSetError::InternalService,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CancelChangeSetError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelChangeSetError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelChangeSetError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelChangeSetError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CancelChangeSetError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelChangeSetError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -477,18 +503,38 @@ impl DescribeChangeSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeChangeSetError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChangeSetError::AccessDenied,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeChangeSetError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChangeSetError::InternalService,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeChangeSetError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChangeSetError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeChangeSetError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChangeSetError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -527,21 +573,46 @@ impl DescribeEntityError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeEntityError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEntityError::AccessDenied,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeEntityError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEntityError::InternalService,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeEntityError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEntityError::ResourceNotFound,
+                    )
                 }
                 "ResourceNotSupportedException" => {
-                    return RusotoError::Service(DescribeEntityError::ResourceNotSupported(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEntityError::ResourceNotSupported,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeEntityError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEntityError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -577,15 +648,30 @@ impl ListChangeSetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListChangeSetsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChangeSetsError::AccessDenied,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListChangeSetsError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChangeSetsError::InternalService,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListChangeSetsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChangeSetsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -621,18 +707,38 @@ impl ListEntitiesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListEntitiesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListEntitiesError::AccessDenied,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(ListEntitiesError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListEntitiesError::InternalService,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListEntitiesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListEntitiesError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListEntitiesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListEntitiesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -673,24 +779,54 @@ impl StartChangeSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartChangeSetError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartChangeSetError::AccessDenied,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartChangeSetError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartChangeSetError::InternalService,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StartChangeSetError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartChangeSetError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartChangeSetError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartChangeSetError::ResourceNotFound,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(StartChangeSetError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartChangeSetError::ServiceQuotaExceeded,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartChangeSetError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartChangeSetError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -760,11 +896,12 @@ pub struct MarketplaceCatalogClient {
 impl MarketplaceCatalogClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> MarketplaceCatalogClient {
         MarketplaceCatalogClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Marketplace Catalog"),
         }
     }
 
@@ -779,12 +916,32 @@ impl MarketplaceCatalogClient {
     {
         MarketplaceCatalogClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Marketplace Catalog"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> MarketplaceCatalogClient {
-        MarketplaceCatalogClient { client, region }
+        MarketplaceCatalogClient {
+            client,
+            region: region_for_service(region, "Marketplace Catalog"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> MarketplaceCatalogClient {
+        MarketplaceCatalogClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Marketplace Catalog"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> MarketplaceCatalogClient {
+        MarketplaceCatalogClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -800,6 +957,7 @@ impl MarketplaceCatalog for MarketplaceCatalogClient {
 
         let mut request =
             SignedRequest::new("PATCH", "aws-marketplace", &self.region, &request_uri);
+        request.set_operation("CancelChangeSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("catalog.marketplace".to_string());
@@ -835,6 +993,7 @@ impl MarketplaceCatalog for MarketplaceCatalogClient {
         let request_uri = "/DescribeChangeSet";
 
         let mut request = SignedRequest::new("GET", "aws-marketplace", &self.region, &request_uri);
+        request.set_operation("DescribeChangeSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("catalog.marketplace".to_string());
@@ -870,6 +1029,7 @@ impl MarketplaceCatalog for MarketplaceCatalogClient {
         let request_uri = "/DescribeEntity";
 
         let mut request = SignedRequest::new("GET", "aws-marketplace", &self.region, &request_uri);
+        request.set_operation("DescribeEntity");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("catalog.marketplace".to_string());
@@ -905,6 +1065,7 @@ impl MarketplaceCatalog for MarketplaceCatalogClient {
         let request_uri = "/ListChangeSets";
 
         let mut request = SignedRequest::new("POST", "aws-marketplace", &self.region, &request_uri);
+        request.set_operation("ListChangeSets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("catalog.marketplace".to_string());
@@ -937,6 +1098,7 @@ impl MarketplaceCatalog for MarketplaceCatalogClient {
         let request_uri = "/ListEntities";
 
         let mut request = SignedRequest::new("POST", "aws-marketplace", &self.region, &request_uri);
+        request.set_operation("ListEntities");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("catalog.marketplace".to_string());
@@ -969,6 +1131,7 @@ impl MarketplaceCatalog for MarketplaceCatalogClient {
         let request_uri = "/StartChangeSet";
 
         let mut request = SignedRequest::new("POST", "aws-marketplace", &self.region, &request_uri);
+        request.set_operation("StartChangeSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("catalog.marketplace".to_string());
@@ -992,3 +1155,137 @@ impl MarketplaceCatalog for MarketplaceCatalogClient {
         }
     }
 }
+
+/// A blocking client for the AWS Marketplace Catalog API, running the calls of a `MarketplaceCatalogClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `MarketplaceCatalogClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct MarketplaceCatalogBlockingClient {
+    client: MarketplaceCatalogClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl MarketplaceCatalogBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> MarketplaceCatalogBlockingClient {
+        MarketplaceCatalogBlockingClient::from_client(MarketplaceCatalogClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> MarketplaceCatalogBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        MarketplaceCatalogBlockingClient::from_client(MarketplaceCatalogClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> MarketplaceCatalogBlockingClient {
+        MarketplaceCatalogBlockingClient::from_client(MarketplaceCatalogClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> MarketplaceCatalogBlockingClient {
+        MarketplaceCatalogBlockingClient::from_client(MarketplaceCatalogClient::new_with_config(
+            config,
+        ))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: MarketplaceCatalogClient) -> MarketplaceCatalogBlockingClient {
+        MarketplaceCatalogBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> MarketplaceCatalogBlockingClient {
+        MarketplaceCatalogBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> MarketplaceCatalogBlockingClient {
+        MarketplaceCatalogBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`MarketplaceCatalog::cancel_change_set`].
+    pub fn cancel_change_set(
+        &self,
+        input: CancelChangeSetRequest,
+    ) -> Result<CancelChangeSetResponse, RusotoError<CancelChangeSetError>> {
+        self.runtime.block_on(self.client.cancel_change_set(input))
+    }
+
+    /// Blocking version of [`MarketplaceCatalog::describe_change_set`].
+    pub fn describe_change_set(
+        &self,
+        input: DescribeChangeSetRequest,
+    ) -> Result<DescribeChangeSetResponse, RusotoError<DescribeChangeSetError>> {
+        self.runtime
+            .block_on(self.client.describe_change_set(input))
+    }
+
+    /// Blocking version of [`MarketplaceCatalog::describe_entity`].
+    pub fn describe_entity(
+        &self,
+        input: DescribeEntityRequest,
+    ) -> Result<DescribeEntityResponse, RusotoError<DescribeEntityError>> {
+        self.runtime.block_on(self.client.describe_entity(input))
+    }
+
+    /// Blocking version of [`MarketplaceCatalog::list_change_sets`].
+    pub fn list_change_sets(
+        &self,
+        input: ListChangeSetsRequest,
+    ) -> Result<ListChangeSetsResponse, RusotoError<ListChangeSetsError>> {
+        self.runtime.block_on(self.client.list_change_sets(input))
+    }
+
+    /// Blocking version of [`MarketplaceCatalog::list_entities`].
+    pub fn list_entities(
+        &self,
+        input: ListEntitiesRequest,
+    ) -> Result<ListEntitiesResponse, RusotoError<ListEntitiesError>> {
+        self.runtime.block_on(self.client.list_entities(input))
+    }
+
+    /// Blocking version of [`MarketplaceCatalog::start_change_set`].
+    pub fn start_change_set(
+        &self,
+        input: StartChangeSetRequest,
+    ) -> Result<StartChangeSetResponse, RusotoError<StartChangeSetError>> {
+        self.runtime.block_on(self.client.start_change_set(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -151,17 +152,30 @@ impl GetEntitlementsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(GetEntitlementsError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetEntitlementsError::InternalServiceError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetEntitlementsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEntitlementsError::InvalidParameter,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetEntitlementsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEntitlementsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -198,11 +212,12 @@ pub struct MarketplaceEntitlementClient {
 impl MarketplaceEntitlementClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> MarketplaceEntitlementClient {
         MarketplaceEntitlementClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Marketplace Entitlement Service"),
         }
     }
 
@@ -217,12 +232,32 @@ impl MarketplaceEntitlementClient {
     {
         MarketplaceEntitlementClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Marketplace Entitlement Service"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> MarketplaceEntitlementClient {
-        MarketplaceEntitlementClient { client, region }
+        MarketplaceEntitlementClient {
+            client,
+            region: region_for_service(region, "Marketplace Entitlement Service"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> MarketplaceEntitlementClient {
+        MarketplaceEntitlementClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Marketplace Entitlement Service"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> MarketplaceEntitlementClient {
+        MarketplaceEntitlementClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -234,6 +269,7 @@ impl MarketplaceEntitlement for MarketplaceEntitlementClient {
         input: GetEntitlementsRequest,
     ) -> Result<GetEntitlementsResult, RusotoError<GetEntitlementsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetEntitlements");
         request.add_header("x-amz-target", "AWSMPEntitlementService.GetEntitlements");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -246,3 +282,98 @@ impl MarketplaceEntitlement for MarketplaceEntitlementClient {
         proto::json::ResponsePayload::new(&response).deserialize::<GetEntitlementsResult, _>()
     }
 }
+
+/// A blocking client for the AWS Marketplace Entitlement Service API, running the calls of a `MarketplaceEntitlementClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `MarketplaceEntitlementClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct MarketplaceEntitlementBlockingClient {
+    client: MarketplaceEntitlementClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl MarketplaceEntitlementBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> MarketplaceEntitlementBlockingClient {
+        MarketplaceEntitlementBlockingClient::from_client(MarketplaceEntitlementClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> MarketplaceEntitlementBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        MarketplaceEntitlementBlockingClient::from_client(MarketplaceEntitlementClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> MarketplaceEntitlementBlockingClient {
+        MarketplaceEntitlementBlockingClient::from_client(
+            MarketplaceEntitlementClient::new_with_client(client, region),
+        )
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> MarketplaceEntitlementBlockingClient {
+        MarketplaceEntitlementBlockingClient::from_client(
+            MarketplaceEntitlementClient::new_with_config(config),
+        )
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(
+        client: MarketplaceEntitlementClient,
+    ) -> MarketplaceEntitlementBlockingClient {
+        MarketplaceEntitlementBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> MarketplaceEntitlementBlockingClient {
+        MarketplaceEntitlementBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> MarketplaceEntitlementBlockingClient {
+        MarketplaceEntitlementBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`MarketplaceEntitlement::get_entitlements`].
+    pub fn get_entitlements(
+        &self,
+        input: GetEntitlementsRequest,
+    ) -> Result<GetEntitlementsResult, RusotoError<GetEntitlementsError>> {
+        self.runtime.block_on(self.client.get_entitlements(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -145,11 +146,14 @@ impl GenerateDataSetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "MarketplaceCommerceAnalyticsException" => {
-                    return RusotoError::Service(
-                        GenerateDataSetError::MarketplaceCommerceAnalytics(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GenerateDataSetError::MarketplaceCommerceAnalytics,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -177,11 +181,14 @@ impl StartSupportDataExportError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "MarketplaceCommerceAnalyticsException" => {
-                    return RusotoError::Service(
-                        StartSupportDataExportError::MarketplaceCommerceAnalytics(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartSupportDataExportError::MarketplaceCommerceAnalytics,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -224,11 +231,12 @@ pub struct MarketplaceCommerceAnalyticsClient {
 impl MarketplaceCommerceAnalyticsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> MarketplaceCommerceAnalyticsClient {
         MarketplaceCommerceAnalyticsClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Marketplace Commerce Analytics"),
         }
     }
 
@@ -243,7 +251,7 @@ impl MarketplaceCommerceAnalyticsClient {
     {
         MarketplaceCommerceAnalyticsClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Marketplace Commerce Analytics"),
         }
     }
 
@@ -251,7 +259,27 @@ impl MarketplaceCommerceAnalyticsClient {
         client: Client,
         region: region::Region,
     ) -> MarketplaceCommerceAnalyticsClient {
-        MarketplaceCommerceAnalyticsClient { client, region }
+        MarketplaceCommerceAnalyticsClient {
+            client,
+            region: region_for_service(region, "Marketplace Commerce Analytics"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> MarketplaceCommerceAnalyticsClient {
+        MarketplaceCommerceAnalyticsClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Marketplace Commerce Analytics"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> MarketplaceCommerceAnalyticsClient {
+        MarketplaceCommerceAnalyticsClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -263,6 +291,7 @@ impl MarketplaceCommerceAnalytics for MarketplaceCommerceAnalyticsClient {
         input: GenerateDataSetRequest,
     ) -> Result<GenerateDataSetResult, RusotoError<GenerateDataSetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GenerateDataSet");
         request.add_header(
             "x-amz-target",
             "MarketplaceCommerceAnalytics20150701.GenerateDataSet",
@@ -284,6 +313,7 @@ impl MarketplaceCommerceAnalytics for MarketplaceCommerceAnalyticsClient {
         input: StartSupportDataExportRequest,
     ) -> Result<StartSupportDataExportResult, RusotoError<StartSupportDataExportError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartSupportDataExport");
         request.add_header(
             "x-amz-target",
             "MarketplaceCommerceAnalytics20150701.StartSupportDataExport",
@@ -300,3 +330,111 @@ impl MarketplaceCommerceAnalytics for MarketplaceCommerceAnalyticsClient {
             .deserialize::<StartSupportDataExportResult, _>()
     }
 }
+
+/// A blocking client for the AWS Marketplace Commerce Analytics API, running the calls of a `MarketplaceCommerceAnalyticsClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `MarketplaceCommerceAnalyticsClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct MarketplaceCommerceAnalyticsBlockingClient {
+    client: MarketplaceCommerceAnalyticsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl MarketplaceCommerceAnalyticsBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> MarketplaceCommerceAnalyticsBlockingClient {
+        MarketplaceCommerceAnalyticsBlockingClient::from_client(
+            MarketplaceCommerceAnalyticsClient::new(region),
+        )
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> MarketplaceCommerceAnalyticsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        MarketplaceCommerceAnalyticsBlockingClient::from_client(
+            MarketplaceCommerceAnalyticsClient::new_with(
+                request_dispatcher,
+                credentials_provider,
+                region,
+            ),
+        )
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> MarketplaceCommerceAnalyticsBlockingClient {
+        MarketplaceCommerceAnalyticsBlockingClient::from_client(
+            MarketplaceCommerceAnalyticsClient::new_with_client(client, region),
+        )
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> MarketplaceCommerceAnalyticsBlockingClient {
+        MarketplaceCommerceAnalyticsBlockingClient::from_client(
+            MarketplaceCommerceAnalyticsClient::new_with_config(config),
+        )
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(
+        client: MarketplaceCommerceAnalyticsClient,
+    ) -> MarketplaceCommerceAnalyticsBlockingClient {
+        MarketplaceCommerceAnalyticsBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> MarketplaceCommerceAnalyticsBlockingClient {
+        MarketplaceCommerceAnalyticsBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> MarketplaceCommerceAnalyticsBlockingClient {
+        MarketplaceCommerceAnalyticsBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`MarketplaceCommerceAnalytics::generate_data_set`].
+    pub fn generate_data_set(
+        &self,
+        input: GenerateDataSetRequest,
+    ) -> Result<GenerateDataSetResult, RusotoError<GenerateDataSetError>> {
+        self.runtime.block_on(self.client.generate_data_set(input))
+    }
+
+    /// Blocking version of [`MarketplaceCommerceAnalytics::start_support_data_export`].
+    pub fn start_support_data_export(
+        &self,
+        input: StartSupportDataExportRequest,
+    ) -> Result<StartSupportDataExportResult, RusotoError<StartSupportDataExportError>> {
+        self.runtime
+            .block_on(self.client.start_support_data_export(input))
+    }
+}
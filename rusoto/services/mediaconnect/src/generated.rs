@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1394,27 +1395,62 @@ impl AddFlowOutputsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AddFlowOutputs420Exception" => {
-                    return RusotoError::Service(AddFlowOutputsError::AddFlowOutputs420(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddFlowOutputsError::AddFlowOutputs420,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(AddFlowOutputsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddFlowOutputsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(AddFlowOutputsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddFlowOutputsError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(AddFlowOutputsError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddFlowOutputsError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AddFlowOutputsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddFlowOutputsError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(AddFlowOutputsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddFlowOutputsError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AddFlowOutputsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddFlowOutputsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1458,24 +1494,54 @@ impl AddFlowSourcesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(AddFlowSourcesError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddFlowSourcesError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(AddFlowSourcesError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddFlowSourcesError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(AddFlowSourcesError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddFlowSourcesError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AddFlowSourcesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddFlowSourcesError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(AddFlowSourcesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddFlowSourcesError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AddFlowSourcesError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddFlowSourcesError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1518,30 +1584,54 @@ impl AddFlowVpcInterfacesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(AddFlowVpcInterfacesError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddFlowVpcInterfacesError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(AddFlowVpcInterfacesError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddFlowVpcInterfacesError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(AddFlowVpcInterfacesError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddFlowVpcInterfacesError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AddFlowVpcInterfacesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddFlowVpcInterfacesError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(AddFlowVpcInterfacesError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddFlowVpcInterfacesError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AddFlowVpcInterfacesError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddFlowVpcInterfacesError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1584,24 +1674,54 @@ impl CreateFlowError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateFlowError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFlowError::BadRequest,
+                    )
                 }
                 "CreateFlow420Exception" => {
-                    return RusotoError::Service(CreateFlowError::CreateFlow420(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFlowError::CreateFlow420,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateFlowError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFlowError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateFlowError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFlowError::InternalServerError,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateFlowError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFlowError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateFlowError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFlowError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1644,24 +1764,54 @@ impl DeleteFlowError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteFlowError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFlowError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteFlowError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFlowError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteFlowError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFlowError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteFlowError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFlowError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteFlowError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFlowError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteFlowError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFlowError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1704,24 +1854,54 @@ impl DescribeFlowError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeFlowError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeFlowError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeFlowError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeFlowError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeFlowError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeFlowError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeFlowError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeFlowError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeFlowError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeFlowError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeFlowError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeFlowError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1762,23 +1942,46 @@ impl DescribeOfferingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeOfferingError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeOfferingError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeOfferingError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeOfferingError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeOfferingError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeOfferingError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeOfferingError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeOfferingError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeOfferingError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeOfferingError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1818,25 +2021,46 @@ impl DescribeReservationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeReservationError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeReservationError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeReservationError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeReservationError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeReservationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeReservationError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeReservationError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeReservationError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeReservationError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeReservationError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1880,35 +2104,62 @@ impl GrantFlowEntitlementsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GrantFlowEntitlementsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GrantFlowEntitlementsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GrantFlowEntitlementsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GrantFlowEntitlementsError::Forbidden,
+                    )
                 }
                 "GrantFlowEntitlements420Exception" => {
-                    return RusotoError::Service(
-                        GrantFlowEntitlementsError::GrantFlowEntitlements420(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GrantFlowEntitlementsError::GrantFlowEntitlements420,
                     )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GrantFlowEntitlementsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GrantFlowEntitlementsError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GrantFlowEntitlementsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GrantFlowEntitlementsError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GrantFlowEntitlementsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GrantFlowEntitlementsError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GrantFlowEntitlementsError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GrantFlowEntitlementsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1950,20 +2201,38 @@ impl ListEntitlementsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListEntitlementsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListEntitlementsError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListEntitlementsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListEntitlementsError::InternalServerError,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListEntitlementsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListEntitlementsError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListEntitlementsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListEntitlementsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2000,18 +2269,38 @@ impl ListFlowsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListFlowsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFlowsError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListFlowsError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFlowsError::InternalServerError,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListFlowsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFlowsError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListFlowsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFlowsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2048,18 +2337,38 @@ impl ListOfferingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListOfferingsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOfferingsError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListOfferingsError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOfferingsError::InternalServerError,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListOfferingsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOfferingsError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListOfferingsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOfferingsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2096,20 +2405,38 @@ impl ListReservationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListReservationsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListReservationsError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListReservationsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListReservationsError::InternalServerError,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListReservationsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListReservationsError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListReservationsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListReservationsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2144,17 +2471,30 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2194,26 +2534,54 @@ impl PurchaseOfferingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PurchaseOfferingError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PurchaseOfferingError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(PurchaseOfferingError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PurchaseOfferingError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(PurchaseOfferingError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PurchaseOfferingError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PurchaseOfferingError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PurchaseOfferingError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PurchaseOfferingError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PurchaseOfferingError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PurchaseOfferingError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PurchaseOfferingError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2256,26 +2624,54 @@ impl RemoveFlowOutputError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(RemoveFlowOutputError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveFlowOutputError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(RemoveFlowOutputError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveFlowOutputError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(RemoveFlowOutputError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveFlowOutputError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RemoveFlowOutputError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveFlowOutputError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(RemoveFlowOutputError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveFlowOutputError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(RemoveFlowOutputError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveFlowOutputError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2318,26 +2714,54 @@ impl RemoveFlowSourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(RemoveFlowSourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveFlowSourceError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(RemoveFlowSourceError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveFlowSourceError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(RemoveFlowSourceError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveFlowSourceError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RemoveFlowSourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveFlowSourceError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(RemoveFlowSourceError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveFlowSourceError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(RemoveFlowSourceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveFlowSourceError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2380,30 +2804,54 @@ impl RemoveFlowVpcInterfaceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(RemoveFlowVpcInterfaceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveFlowVpcInterfaceError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(RemoveFlowVpcInterfaceError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveFlowVpcInterfaceError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(RemoveFlowVpcInterfaceError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveFlowVpcInterfaceError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RemoveFlowVpcInterfaceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveFlowVpcInterfaceError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(RemoveFlowVpcInterfaceError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveFlowVpcInterfaceError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(RemoveFlowVpcInterfaceError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveFlowVpcInterfaceError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2446,30 +2894,54 @@ impl RevokeFlowEntitlementError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(RevokeFlowEntitlementError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RevokeFlowEntitlementError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(RevokeFlowEntitlementError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RevokeFlowEntitlementError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(RevokeFlowEntitlementError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RevokeFlowEntitlementError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RevokeFlowEntitlementError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RevokeFlowEntitlementError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(RevokeFlowEntitlementError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RevokeFlowEntitlementError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(RevokeFlowEntitlementError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RevokeFlowEntitlementError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2512,24 +2984,54 @@ impl StartFlowError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StartFlowError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartFlowError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(StartFlowError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartFlowError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(StartFlowError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartFlowError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StartFlowError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartFlowError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(StartFlowError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartFlowError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartFlowError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartFlowError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2572,24 +3074,54 @@ impl StopFlowError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(StopFlowError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopFlowError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(StopFlowError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopFlowError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(StopFlowError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopFlowError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StopFlowError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopFlowError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(StopFlowError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopFlowError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StopFlowError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopFlowError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2626,15 +3158,30 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(TagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2668,15 +3215,30 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::BadRequest,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2716,24 +3278,54 @@ impl UpdateFlowError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateFlowError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFlowError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateFlowError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFlowError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateFlowError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFlowError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateFlowError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFlowError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateFlowError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFlowError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateFlowError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFlowError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2776,30 +3368,54 @@ impl UpdateFlowEntitlementError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateFlowEntitlementError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFlowEntitlementError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateFlowEntitlementError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFlowEntitlementError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateFlowEntitlementError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateFlowEntitlementError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateFlowEntitlementError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFlowEntitlementError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateFlowEntitlementError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateFlowEntitlementError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateFlowEntitlementError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateFlowEntitlementError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2842,26 +3458,54 @@ impl UpdateFlowOutputError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateFlowOutputError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFlowOutputError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateFlowOutputError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFlowOutputError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateFlowOutputError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateFlowOutputError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateFlowOutputError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFlowOutputError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateFlowOutputError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFlowOutputError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateFlowOutputError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFlowOutputError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2904,26 +3548,54 @@ impl UpdateFlowSourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateFlowSourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFlowSourceError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateFlowSourceError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFlowSourceError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateFlowSourceError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateFlowSourceError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateFlowSourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFlowSourceError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateFlowSourceError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFlowSourceError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateFlowSourceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateFlowSourceError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3119,11 +3791,12 @@ pub struct MediaConnectClient {
 impl MediaConnectClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> MediaConnectClient {
         MediaConnectClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "MediaConnect"),
         }
     }
 
@@ -3138,12 +3811,32 @@ impl MediaConnectClient {
     {
         MediaConnectClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "MediaConnect"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> MediaConnectClient {
-        MediaConnectClient { client, region }
+        MediaConnectClient {
+            client,
+            region: region_for_service(region, "MediaConnect"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> MediaConnectClient {
+        MediaConnectClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("MediaConnect"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> MediaConnectClient {
+        MediaConnectClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -3158,6 +3851,7 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = format!("/v1/flows/{flow_arn}/outputs", flow_arn = input.flow_arn);
 
         let mut request = SignedRequest::new("POST", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("AddFlowOutputs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3189,6 +3883,7 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = format!("/v1/flows/{flow_arn}/source", flow_arn = input.flow_arn);
 
         let mut request = SignedRequest::new("POST", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("AddFlowSources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3223,6 +3918,7 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("AddFlowVpcInterfaces");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3254,6 +3950,7 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = "/v1/flows";
 
         let mut request = SignedRequest::new("POST", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("CreateFlow");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3285,6 +3982,7 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = format!("/v1/flows/{flow_arn}", flow_arn = input.flow_arn);
 
         let mut request = SignedRequest::new("DELETE", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("DeleteFlow");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3313,6 +4011,7 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = format!("/v1/flows/{flow_arn}", flow_arn = input.flow_arn);
 
         let mut request = SignedRequest::new("GET", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("DescribeFlow");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3344,6 +4043,7 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("DescribeOffering");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3375,6 +4075,7 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("GET", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("DescribeReservation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3406,6 +4107,7 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("GrantFlowEntitlements");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3437,6 +4139,7 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = "/v1/entitlements";
 
         let mut request = SignedRequest::new("GET", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("ListEntitlements");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3474,6 +4177,7 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = "/v1/flows";
 
         let mut request = SignedRequest::new("GET", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("ListFlows");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3511,6 +4215,7 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = "/v1/offerings";
 
         let mut request = SignedRequest::new("GET", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("ListOfferings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3548,6 +4253,7 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = "/v1/reservations";
 
         let mut request = SignedRequest::new("GET", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("ListReservations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3585,6 +4291,7 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3616,6 +4323,7 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("POST", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("PurchaseOffering");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3651,6 +4359,7 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("RemoveFlowOutput");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3683,6 +4392,7 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("RemoveFlowSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3715,6 +4425,7 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("RemoveFlowVpcInterface");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3747,6 +4458,7 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("RevokeFlowEntitlement");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3775,6 +4487,7 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = format!("/v1/flows/start/{flow_arn}", flow_arn = input.flow_arn);
 
         let mut request = SignedRequest::new("POST", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("StartFlow");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3803,6 +4516,7 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = format!("/v1/flows/stop/{flow_arn}", flow_arn = input.flow_arn);
 
         let mut request = SignedRequest::new("POST", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("StopFlow");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3831,6 +4545,7 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3861,6 +4576,7 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3894,6 +4610,7 @@ impl MediaConnect for MediaConnectClient {
         let request_uri = format!("/v1/flows/{flow_arn}", flow_arn = input.flow_arn);
 
         let mut request = SignedRequest::new("PUT", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("UpdateFlow");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3929,6 +4646,7 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("PUT", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("UpdateFlowEntitlement");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3964,6 +4682,7 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("PUT", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("UpdateFlowOutput");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3999,6 +4718,7 @@ impl MediaConnect for MediaConnectClient {
         );
 
         let mut request = SignedRequest::new("PUT", "mediaconnect", &self.region, &request_uri);
+        request.set_operation("UpdateFlowSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4021,3 +4741,304 @@ impl MediaConnect for MediaConnectClient {
         }
     }
 }
+
+/// A blocking client for the AWS MediaConnect API, running the calls of a `MediaConnectClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `MediaConnectClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct MediaConnectBlockingClient {
+    client: MediaConnectClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl MediaConnectBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> MediaConnectBlockingClient {
+        MediaConnectBlockingClient::from_client(MediaConnectClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> MediaConnectBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        MediaConnectBlockingClient::from_client(MediaConnectClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> MediaConnectBlockingClient {
+        MediaConnectBlockingClient::from_client(MediaConnectClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> MediaConnectBlockingClient {
+        MediaConnectBlockingClient::from_client(MediaConnectClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: MediaConnectClient) -> MediaConnectBlockingClient {
+        MediaConnectBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> MediaConnectBlockingClient {
+        MediaConnectBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> MediaConnectBlockingClient {
+        MediaConnectBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`MediaConnect::add_flow_outputs`].
+    pub fn add_flow_outputs(
+        &self,
+        input: AddFlowOutputsRequest,
+    ) -> Result<AddFlowOutputsResponse, RusotoError<AddFlowOutputsError>> {
+        self.runtime.block_on(self.client.add_flow_outputs(input))
+    }
+
+    /// Blocking version of [`MediaConnect::add_flow_sources`].
+    pub fn add_flow_sources(
+        &self,
+        input: AddFlowSourcesRequest,
+    ) -> Result<AddFlowSourcesResponse, RusotoError<AddFlowSourcesError>> {
+        self.runtime.block_on(self.client.add_flow_sources(input))
+    }
+
+    /// Blocking version of [`MediaConnect::add_flow_vpc_interfaces`].
+    pub fn add_flow_vpc_interfaces(
+        &self,
+        input: AddFlowVpcInterfacesRequest,
+    ) -> Result<AddFlowVpcInterfacesResponse, RusotoError<AddFlowVpcInterfacesError>> {
+        self.runtime
+            .block_on(self.client.add_flow_vpc_interfaces(input))
+    }
+
+    /// Blocking version of [`MediaConnect::create_flow`].
+    pub fn create_flow(
+        &self,
+        input: CreateFlowRequest,
+    ) -> Result<CreateFlowResponse, RusotoError<CreateFlowError>> {
+        self.runtime.block_on(self.client.create_flow(input))
+    }
+
+    /// Blocking version of [`MediaConnect::delete_flow`].
+    pub fn delete_flow(
+        &self,
+        input: DeleteFlowRequest,
+    ) -> Result<DeleteFlowResponse, RusotoError<DeleteFlowError>> {
+        self.runtime.block_on(self.client.delete_flow(input))
+    }
+
+    /// Blocking version of [`MediaConnect::describe_flow`].
+    pub fn describe_flow(
+        &self,
+        input: DescribeFlowRequest,
+    ) -> Result<DescribeFlowResponse, RusotoError<DescribeFlowError>> {
+        self.runtime.block_on(self.client.describe_flow(input))
+    }
+
+    /// Blocking version of [`MediaConnect::describe_offering`].
+    pub fn describe_offering(
+        &self,
+        input: DescribeOfferingRequest,
+    ) -> Result<DescribeOfferingResponse, RusotoError<DescribeOfferingError>> {
+        self.runtime.block_on(self.client.describe_offering(input))
+    }
+
+    /// Blocking version of [`MediaConnect::describe_reservation`].
+    pub fn describe_reservation(
+        &self,
+        input: DescribeReservationRequest,
+    ) -> Result<DescribeReservationResponse, RusotoError<DescribeReservationError>> {
+        self.runtime
+            .block_on(self.client.describe_reservation(input))
+    }
+
+    /// Blocking version of [`MediaConnect::grant_flow_entitlements`].
+    pub fn grant_flow_entitlements(
+        &self,
+        input: GrantFlowEntitlementsRequest,
+    ) -> Result<GrantFlowEntitlementsResponse, RusotoError<GrantFlowEntitlementsError>> {
+        self.runtime
+            .block_on(self.client.grant_flow_entitlements(input))
+    }
+
+    /// Blocking version of [`MediaConnect::list_entitlements`].
+    pub fn list_entitlements(
+        &self,
+        input: ListEntitlementsRequest,
+    ) -> Result<ListEntitlementsResponse, RusotoError<ListEntitlementsError>> {
+        self.runtime.block_on(self.client.list_entitlements(input))
+    }
+
+    /// Blocking version of [`MediaConnect::list_flows`].
+    pub fn list_flows(
+        &self,
+        input: ListFlowsRequest,
+    ) -> Result<ListFlowsResponse, RusotoError<ListFlowsError>> {
+        self.runtime.block_on(self.client.list_flows(input))
+    }
+
+    /// Blocking version of [`MediaConnect::list_offerings`].
+    pub fn list_offerings(
+        &self,
+        input: ListOfferingsRequest,
+    ) -> Result<ListOfferingsResponse, RusotoError<ListOfferingsError>> {
+        self.runtime.block_on(self.client.list_offerings(input))
+    }
+
+    /// Blocking version of [`MediaConnect::list_reservations`].
+    pub fn list_reservations(
+        &self,
+        input: ListReservationsRequest,
+    ) -> Result<ListReservationsResponse, RusotoError<ListReservationsError>> {
+        self.runtime.block_on(self.client.list_reservations(input))
+    }
+
+    /// Blocking version of [`MediaConnect::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`MediaConnect::purchase_offering`].
+    pub fn purchase_offering(
+        &self,
+        input: PurchaseOfferingRequest,
+    ) -> Result<PurchaseOfferingResponse, RusotoError<PurchaseOfferingError>> {
+        self.runtime.block_on(self.client.purchase_offering(input))
+    }
+
+    /// Blocking version of [`MediaConnect::remove_flow_output`].
+    pub fn remove_flow_output(
+        &self,
+        input: RemoveFlowOutputRequest,
+    ) -> Result<RemoveFlowOutputResponse, RusotoError<RemoveFlowOutputError>> {
+        self.runtime.block_on(self.client.remove_flow_output(input))
+    }
+
+    /// Blocking version of [`MediaConnect::remove_flow_source`].
+    pub fn remove_flow_source(
+        &self,
+        input: RemoveFlowSourceRequest,
+    ) -> Result<RemoveFlowSourceResponse, RusotoError<RemoveFlowSourceError>> {
+        self.runtime.block_on(self.client.remove_flow_source(input))
+    }
+
+    /// Blocking version of [`MediaConnect::remove_flow_vpc_interface`].
+    pub fn remove_flow_vpc_interface(
+        &self,
+        input: RemoveFlowVpcInterfaceRequest,
+    ) -> Result<RemoveFlowVpcInterfaceResponse, RusotoError<RemoveFlowVpcInterfaceError>> {
+        self.runtime
+            .block_on(self.client.remove_flow_vpc_interface(input))
+    }
+
+    /// Blocking version of [`MediaConnect::revoke_flow_entitlement`].
+    pub fn revoke_flow_entitlement(
+        &self,
+        input: RevokeFlowEntitlementRequest,
+    ) -> Result<RevokeFlowEntitlementResponse, RusotoError<RevokeFlowEntitlementError>> {
+        self.runtime
+            .block_on(self.client.revoke_flow_entitlement(input))
+    }
+
+    /// Blocking version of [`MediaConnect::start_flow`].
+    pub fn start_flow(
+        &self,
+        input: StartFlowRequest,
+    ) -> Result<StartFlowResponse, RusotoError<StartFlowError>> {
+        self.runtime.block_on(self.client.start_flow(input))
+    }
+
+    /// Blocking version of [`MediaConnect::stop_flow`].
+    pub fn stop_flow(
+        &self,
+        input: StopFlowRequest,
+    ) -> Result<StopFlowResponse, RusotoError<StopFlowError>> {
+        self.runtime.block_on(self.client.stop_flow(input))
+    }
+
+    /// Blocking version of [`MediaConnect::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`MediaConnect::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`MediaConnect::update_flow`].
+    pub fn update_flow(
+        &self,
+        input: UpdateFlowRequest,
+    ) -> Result<UpdateFlowResponse, RusotoError<UpdateFlowError>> {
+        self.runtime.block_on(self.client.update_flow(input))
+    }
+
+    /// Blocking version of [`MediaConnect::update_flow_entitlement`].
+    pub fn update_flow_entitlement(
+        &self,
+        input: UpdateFlowEntitlementRequest,
+    ) -> Result<UpdateFlowEntitlementResponse, RusotoError<UpdateFlowEntitlementError>> {
+        self.runtime
+            .block_on(self.client.update_flow_entitlement(input))
+    }
+
+    /// Blocking version of [`MediaConnect::update_flow_output`].
+    pub fn update_flow_output(
+        &self,
+        input: UpdateFlowOutputRequest,
+    ) -> Result<UpdateFlowOutputResponse, RusotoError<UpdateFlowOutputError>> {
+        self.runtime.block_on(self.client.update_flow_output(input))
+    }
+
+    /// Blocking version of [`MediaConnect::update_flow_source`].
+    pub fn update_flow_source(
+        &self,
+        input: UpdateFlowSourceRequest,
+    ) -> Result<UpdateFlowSourceResponse, RusotoError<UpdateFlowSourceError>> {
+        self.runtime.block_on(self.client.update_flow_source(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -6910,45 +6911,78 @@ impl AcceptInputDeviceTransferError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(AcceptInputDeviceTransferError::BadGateway(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AcceptInputDeviceTransferError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(AcceptInputDeviceTransferError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AcceptInputDeviceTransferError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(AcceptInputDeviceTransferError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptInputDeviceTransferError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(AcceptInputDeviceTransferError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptInputDeviceTransferError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(AcceptInputDeviceTransferError::GatewayTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AcceptInputDeviceTransferError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        AcceptInputDeviceTransferError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptInputDeviceTransferError::InternalServerError,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(AcceptInputDeviceTransferError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptInputDeviceTransferError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AcceptInputDeviceTransferError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AcceptInputDeviceTransferError::TooManyRequests,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(
-                        AcceptInputDeviceTransferError::UnprocessableEntity(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptInputDeviceTransferError::UnprocessableEntity,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7002,30 +7036,70 @@ impl BatchDeleteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(BatchDeleteError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDeleteError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(BatchDeleteError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDeleteError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(BatchDeleteError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDeleteError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(BatchDeleteError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDeleteError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(BatchDeleteError::GatewayTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDeleteError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(BatchDeleteError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDeleteError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(BatchDeleteError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDeleteError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(BatchDeleteError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDeleteError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7074,30 +7148,70 @@ impl BatchStartError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(BatchStartError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchStartError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(BatchStartError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchStartError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(BatchStartError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchStartError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(BatchStartError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchStartError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(BatchStartError::GatewayTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchStartError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(BatchStartError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchStartError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(BatchStartError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchStartError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(BatchStartError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchStartError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7146,30 +7260,70 @@ impl BatchStopError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(BatchStopError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchStopError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(BatchStopError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchStopError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(BatchStopError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchStopError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(BatchStopError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchStopError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(BatchStopError::GatewayTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchStopError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(BatchStopError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchStopError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(BatchStopError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchStopError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(BatchStopError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchStopError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7218,34 +7372,70 @@ impl BatchUpdateScheduleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(BatchUpdateScheduleError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchUpdateScheduleError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(BatchUpdateScheduleError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchUpdateScheduleError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(BatchUpdateScheduleError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchUpdateScheduleError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(BatchUpdateScheduleError::GatewayTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchUpdateScheduleError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(BatchUpdateScheduleError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchUpdateScheduleError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(BatchUpdateScheduleError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchUpdateScheduleError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(BatchUpdateScheduleError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchUpdateScheduleError::TooManyRequests,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(BatchUpdateScheduleError::UnprocessableEntity(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchUpdateScheduleError::UnprocessableEntity,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7296,45 +7486,78 @@ impl CancelInputDeviceTransferError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(CancelInputDeviceTransferError::BadGateway(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelInputDeviceTransferError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CancelInputDeviceTransferError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelInputDeviceTransferError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CancelInputDeviceTransferError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelInputDeviceTransferError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CancelInputDeviceTransferError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelInputDeviceTransferError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(CancelInputDeviceTransferError::GatewayTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelInputDeviceTransferError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        CancelInputDeviceTransferError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelInputDeviceTransferError::InternalServerError,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CancelInputDeviceTransferError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelInputDeviceTransferError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CancelInputDeviceTransferError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelInputDeviceTransferError::TooManyRequests,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(
-                        CancelInputDeviceTransferError::UnprocessableEntity(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelInputDeviceTransferError::UnprocessableEntity,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7388,30 +7611,70 @@ impl CreateChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(CreateChannelError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateChannelError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateChannelError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateChannelError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateChannelError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateChannelError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateChannelError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateChannelError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(CreateChannelError::GatewayTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateChannelError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateChannelError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateChannelError::InternalServerError,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateChannelError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateChannelError::TooManyRequests,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(CreateChannelError::UnprocessableEntity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateChannelError::UnprocessableEntity,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7456,24 +7719,54 @@ impl CreateInputError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(CreateInputError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInputError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateInputError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInputError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateInputError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInputError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(CreateInputError::GatewayTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInputError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateInputError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInputError::InternalServerError,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateInputError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInputError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7516,30 +7809,54 @@ impl CreateInputSecurityGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(CreateInputSecurityGroupError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInputSecurityGroupError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateInputSecurityGroupError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInputSecurityGroupError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateInputSecurityGroupError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInputSecurityGroupError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(CreateInputSecurityGroupError::GatewayTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateInputSecurityGroupError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        CreateInputSecurityGroupError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInputSecurityGroupError::InternalServerError,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateInputSecurityGroupError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateInputSecurityGroupError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7586,30 +7903,70 @@ impl CreateMultiplexError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(CreateMultiplexError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMultiplexError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateMultiplexError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMultiplexError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateMultiplexError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMultiplexError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateMultiplexError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMultiplexError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(CreateMultiplexError::GatewayTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMultiplexError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateMultiplexError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMultiplexError::InternalServerError,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateMultiplexError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMultiplexError::TooManyRequests,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(CreateMultiplexError::UnprocessableEntity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMultiplexError::UnprocessableEntity,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7658,38 +8015,70 @@ impl CreateMultiplexProgramError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(CreateMultiplexProgramError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMultiplexProgramError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateMultiplexProgramError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMultiplexProgramError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateMultiplexProgramError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMultiplexProgramError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateMultiplexProgramError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMultiplexProgramError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(CreateMultiplexProgramError::GatewayTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateMultiplexProgramError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateMultiplexProgramError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateMultiplexProgramError::InternalServerError,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateMultiplexProgramError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateMultiplexProgramError::TooManyRequests,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(CreateMultiplexProgramError::UnprocessableEntity(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateMultiplexProgramError::UnprocessableEntity,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7730,18 +8119,38 @@ impl CreateTagsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateTagsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTagsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateTagsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTagsError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateTagsError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTagsError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateTagsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTagsError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7786,30 +8195,70 @@ impl DeleteChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DeleteChannelError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteChannelError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteChannelError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteChannelError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteChannelError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteChannelError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteChannelError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteChannelError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DeleteChannelError::GatewayTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteChannelError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteChannelError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteChannelError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteChannelError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteChannelError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteChannelError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteChannelError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7858,30 +8307,70 @@ impl DeleteInputError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DeleteInputError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInputError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteInputError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInputError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteInputError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInputError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteInputError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInputError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DeleteInputError::GatewayTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInputError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteInputError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInputError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteInputError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInputError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteInputError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInputError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7928,33 +8417,62 @@ impl DeleteInputSecurityGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DeleteInputSecurityGroupError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInputSecurityGroupError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteInputSecurityGroupError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInputSecurityGroupError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteInputSecurityGroupError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInputSecurityGroupError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DeleteInputSecurityGroupError::GatewayTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteInputSecurityGroupError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        DeleteInputSecurityGroupError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInputSecurityGroupError::InternalServerError,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteInputSecurityGroupError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInputSecurityGroupError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteInputSecurityGroupError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteInputSecurityGroupError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8002,30 +8520,70 @@ impl DeleteMultiplexError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DeleteMultiplexError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMultiplexError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteMultiplexError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMultiplexError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteMultiplexError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMultiplexError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteMultiplexError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMultiplexError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DeleteMultiplexError::GatewayTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMultiplexError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteMultiplexError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMultiplexError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteMultiplexError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMultiplexError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteMultiplexError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMultiplexError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8074,36 +8632,70 @@ impl DeleteMultiplexProgramError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DeleteMultiplexProgramError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMultiplexProgramError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteMultiplexProgramError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMultiplexProgramError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteMultiplexProgramError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMultiplexProgramError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteMultiplexProgramError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMultiplexProgramError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DeleteMultiplexProgramError::GatewayTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteMultiplexProgramError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteMultiplexProgramError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteMultiplexProgramError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteMultiplexProgramError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMultiplexProgramError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteMultiplexProgramError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteMultiplexProgramError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8152,32 +8744,70 @@ impl DeleteReservationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DeleteReservationError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteReservationError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteReservationError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteReservationError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteReservationError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteReservationError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteReservationError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteReservationError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DeleteReservationError::GatewayTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteReservationError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteReservationError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteReservationError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteReservationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteReservationError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteReservationError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteReservationError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8224,27 +8854,62 @@ impl DeleteScheduleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DeleteScheduleError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteScheduleError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteScheduleError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteScheduleError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteScheduleError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteScheduleError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DeleteScheduleError::GatewayTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteScheduleError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteScheduleError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteScheduleError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteScheduleError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteScheduleError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteScheduleError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteScheduleError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8284,18 +8949,38 @@ impl DeleteTagsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteTagsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTagsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteTagsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTagsError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteTagsError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTagsError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteTagsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTagsError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8338,27 +9023,62 @@ impl DescribeChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DescribeChannelError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChannelError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeChannelError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChannelError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeChannelError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChannelError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DescribeChannelError::GatewayTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChannelError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeChannelError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChannelError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeChannelError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChannelError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeChannelError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeChannelError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8404,27 +9124,62 @@ impl DescribeInputError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DescribeInputError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInputError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeInputError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInputError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeInputError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInputError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DescribeInputError::GatewayTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInputError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeInputError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInputError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeInputError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInputError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeInputError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInputError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8470,29 +9225,62 @@ impl DescribeInputDeviceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DescribeInputDeviceError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInputDeviceError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeInputDeviceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInputDeviceError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeInputDeviceError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInputDeviceError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DescribeInputDeviceError::GatewayTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInputDeviceError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeInputDeviceError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeInputDeviceError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeInputDeviceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInputDeviceError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeInputDeviceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInputDeviceError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8540,41 +9328,62 @@ impl DescribeInputDeviceThumbnailError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DescribeInputDeviceThumbnailError::BadGateway(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeInputDeviceThumbnailError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeInputDeviceThumbnailError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeInputDeviceThumbnailError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeInputDeviceThumbnailError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeInputDeviceThumbnailError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DescribeInputDeviceThumbnailError::GatewayTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeInputDeviceThumbnailError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        DescribeInputDeviceThumbnailError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInputDeviceThumbnailError::InternalServerError,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeInputDeviceThumbnailError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeInputDeviceThumbnailError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        DescribeInputDeviceThumbnailError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInputDeviceThumbnailError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8624,39 +9433,62 @@ impl DescribeInputSecurityGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DescribeInputSecurityGroupError::BadGateway(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeInputSecurityGroupError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeInputSecurityGroupError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeInputSecurityGroupError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeInputSecurityGroupError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeInputSecurityGroupError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DescribeInputSecurityGroupError::GatewayTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeInputSecurityGroupError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        DescribeInputSecurityGroupError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInputSecurityGroupError::InternalServerError,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeInputSecurityGroupError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInputSecurityGroupError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeInputSecurityGroupError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeInputSecurityGroupError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8704,29 +9536,62 @@ impl DescribeMultiplexError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DescribeMultiplexError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMultiplexError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeMultiplexError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMultiplexError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeMultiplexError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMultiplexError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DescribeMultiplexError::GatewayTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMultiplexError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeMultiplexError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeMultiplexError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeMultiplexError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMultiplexError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeMultiplexError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMultiplexError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8772,33 +9637,62 @@ impl DescribeMultiplexProgramError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DescribeMultiplexProgramError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMultiplexProgramError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeMultiplexProgramError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMultiplexProgramError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeMultiplexProgramError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMultiplexProgramError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DescribeMultiplexProgramError::GatewayTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeMultiplexProgramError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        DescribeMultiplexProgramError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMultiplexProgramError::InternalServerError,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeMultiplexProgramError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMultiplexProgramError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeMultiplexProgramError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeMultiplexProgramError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8844,29 +9738,62 @@ impl DescribeOfferingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DescribeOfferingError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeOfferingError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeOfferingError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeOfferingError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeOfferingError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeOfferingError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DescribeOfferingError::GatewayTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeOfferingError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeOfferingError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeOfferingError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeOfferingError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeOfferingError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeOfferingError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeOfferingError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8912,29 +9839,62 @@ impl DescribeReservationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DescribeReservationError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeReservationError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeReservationError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeReservationError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeReservationError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeReservationError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DescribeReservationError::GatewayTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeReservationError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeReservationError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeReservationError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeReservationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeReservationError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeReservationError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeReservationError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8980,29 +9940,62 @@ impl DescribeScheduleError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(DescribeScheduleError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeScheduleError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(DescribeScheduleError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeScheduleError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeScheduleError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeScheduleError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(DescribeScheduleError::GatewayTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeScheduleError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeScheduleError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeScheduleError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeScheduleError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeScheduleError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeScheduleError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeScheduleError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9046,24 +10039,54 @@ impl ListChannelsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(ListChannelsError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChannelsError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(ListChannelsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChannelsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListChannelsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChannelsError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(ListChannelsError::GatewayTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChannelsError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListChannelsError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChannelsError::InternalServerError,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListChannelsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChannelsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9108,35 +10131,62 @@ impl ListInputDeviceTransfersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(ListInputDeviceTransfersError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInputDeviceTransfersError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(ListInputDeviceTransfersError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInputDeviceTransfersError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListInputDeviceTransfersError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInputDeviceTransfersError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(ListInputDeviceTransfersError::GatewayTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListInputDeviceTransfersError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        ListInputDeviceTransfersError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInputDeviceTransfersError::InternalServerError,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListInputDeviceTransfersError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListInputDeviceTransfersError::TooManyRequests,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(
-                        ListInputDeviceTransfersError::UnprocessableEntity(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInputDeviceTransfersError::UnprocessableEntity,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9180,26 +10230,54 @@ impl ListInputDevicesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(ListInputDevicesError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInputDevicesError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(ListInputDevicesError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInputDevicesError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListInputDevicesError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInputDevicesError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(ListInputDevicesError::GatewayTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInputDevicesError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListInputDevicesError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListInputDevicesError::InternalServerError,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListInputDevicesError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInputDevicesError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9242,30 +10320,54 @@ impl ListInputSecurityGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(ListInputSecurityGroupsError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInputSecurityGroupsError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(ListInputSecurityGroupsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInputSecurityGroupsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListInputSecurityGroupsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInputSecurityGroupsError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(ListInputSecurityGroupsError::GatewayTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListInputSecurityGroupsError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListInputSecurityGroupsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListInputSecurityGroupsError::InternalServerError,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListInputSecurityGroupsError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListInputSecurityGroupsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9308,24 +10410,54 @@ impl ListInputsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(ListInputsError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInputsError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(ListInputsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInputsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListInputsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInputsError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(ListInputsError::GatewayTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInputsError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListInputsError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInputsError::InternalServerError,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListInputsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListInputsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9370,33 +10502,62 @@ impl ListMultiplexProgramsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(ListMultiplexProgramsError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMultiplexProgramsError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(ListMultiplexProgramsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMultiplexProgramsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListMultiplexProgramsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMultiplexProgramsError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(ListMultiplexProgramsError::GatewayTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListMultiplexProgramsError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListMultiplexProgramsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListMultiplexProgramsError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListMultiplexProgramsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMultiplexProgramsError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListMultiplexProgramsError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListMultiplexProgramsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9440,24 +10601,54 @@ impl ListMultiplexesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(ListMultiplexesError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMultiplexesError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(ListMultiplexesError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMultiplexesError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListMultiplexesError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMultiplexesError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(ListMultiplexesError::GatewayTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMultiplexesError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListMultiplexesError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMultiplexesError::InternalServerError,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListMultiplexesError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMultiplexesError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9500,24 +10691,54 @@ impl ListOfferingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(ListOfferingsError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOfferingsError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(ListOfferingsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOfferingsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListOfferingsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOfferingsError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(ListOfferingsError::GatewayTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOfferingsError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListOfferingsError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOfferingsError::InternalServerError,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListOfferingsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOfferingsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9560,26 +10781,54 @@ impl ListReservationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(ListReservationsError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListReservationsError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(ListReservationsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListReservationsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListReservationsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListReservationsError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(ListReservationsError::GatewayTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListReservationsError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListReservationsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListReservationsError::InternalServerError,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListReservationsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListReservationsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9618,20 +10867,38 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9676,32 +10943,70 @@ impl PurchaseOfferingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(PurchaseOfferingError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PurchaseOfferingError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(PurchaseOfferingError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PurchaseOfferingError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(PurchaseOfferingError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PurchaseOfferingError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(PurchaseOfferingError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PurchaseOfferingError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(PurchaseOfferingError::GatewayTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PurchaseOfferingError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(PurchaseOfferingError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PurchaseOfferingError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PurchaseOfferingError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PurchaseOfferingError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PurchaseOfferingError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PurchaseOfferingError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9752,45 +11057,78 @@ impl RejectInputDeviceTransferError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(RejectInputDeviceTransferError::BadGateway(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RejectInputDeviceTransferError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(RejectInputDeviceTransferError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RejectInputDeviceTransferError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(RejectInputDeviceTransferError::Conflict(err.msg))
-                }
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RejectInputDeviceTransferError::Conflict,
+                    )
+                }
                 "ForbiddenException" => {
-                    return RusotoError::Service(RejectInputDeviceTransferError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RejectInputDeviceTransferError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(RejectInputDeviceTransferError::GatewayTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RejectInputDeviceTransferError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        RejectInputDeviceTransferError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RejectInputDeviceTransferError::InternalServerError,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RejectInputDeviceTransferError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RejectInputDeviceTransferError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(RejectInputDeviceTransferError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RejectInputDeviceTransferError::TooManyRequests,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(
-                        RejectInputDeviceTransferError::UnprocessableEntity(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RejectInputDeviceTransferError::UnprocessableEntity,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9844,30 +11182,70 @@ impl StartChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(StartChannelError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartChannelError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(StartChannelError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartChannelError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(StartChannelError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartChannelError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(StartChannelError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartChannelError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(StartChannelError::GatewayTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartChannelError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(StartChannelError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartChannelError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StartChannelError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartChannelError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartChannelError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartChannelError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9916,30 +11294,70 @@ impl StartMultiplexError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(StartMultiplexError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartMultiplexError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(StartMultiplexError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartMultiplexError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(StartMultiplexError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartMultiplexError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(StartMultiplexError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartMultiplexError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(StartMultiplexError::GatewayTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartMultiplexError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(StartMultiplexError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartMultiplexError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StartMultiplexError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartMultiplexError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StartMultiplexError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartMultiplexError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9988,30 +11406,70 @@ impl StopChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(StopChannelError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopChannelError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(StopChannelError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopChannelError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(StopChannelError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopChannelError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(StopChannelError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopChannelError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(StopChannelError::GatewayTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopChannelError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(StopChannelError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopChannelError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StopChannelError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopChannelError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StopChannelError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopChannelError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10060,30 +11518,70 @@ impl StopMultiplexError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(StopMultiplexError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopMultiplexError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(StopMultiplexError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopMultiplexError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(StopMultiplexError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopMultiplexError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(StopMultiplexError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopMultiplexError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(StopMultiplexError::GatewayTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopMultiplexError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(StopMultiplexError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopMultiplexError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(StopMultiplexError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopMultiplexError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(StopMultiplexError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopMultiplexError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10134,37 +11632,78 @@ impl TransferInputDeviceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(TransferInputDeviceError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TransferInputDeviceError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(TransferInputDeviceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TransferInputDeviceError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(TransferInputDeviceError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TransferInputDeviceError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(TransferInputDeviceError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TransferInputDeviceError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(TransferInputDeviceError::GatewayTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TransferInputDeviceError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(TransferInputDeviceError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TransferInputDeviceError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TransferInputDeviceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TransferInputDeviceError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TransferInputDeviceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TransferInputDeviceError::TooManyRequests,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(TransferInputDeviceError::UnprocessableEntity(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TransferInputDeviceError::UnprocessableEntity,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10212,27 +11751,62 @@ impl UpdateChannelError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(UpdateChannelError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateChannelError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateChannelError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateChannelError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateChannelError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateChannelError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateChannelError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateChannelError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(UpdateChannelError::GatewayTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateChannelError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateChannelError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateChannelError::InternalServerError,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(UpdateChannelError::UnprocessableEntity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateChannelError::UnprocessableEntity,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10282,37 +11856,78 @@ impl UpdateChannelClassError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(UpdateChannelClassError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateChannelClassError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateChannelClassError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateChannelClassError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateChannelClassError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateChannelClassError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateChannelClassError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateChannelClassError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(UpdateChannelClassError::GatewayTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateChannelClassError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateChannelClassError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateChannelClassError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateChannelClassError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateChannelClassError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateChannelClassError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateChannelClassError::TooManyRequests,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(UpdateChannelClassError::UnprocessableEntity(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateChannelClassError::UnprocessableEntity,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10360,27 +11975,62 @@ impl UpdateInputError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(UpdateInputError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateInputError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateInputError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateInputError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateInputError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateInputError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateInputError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateInputError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(UpdateInputError::GatewayTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateInputError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateInputError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateInputError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateInputError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateInputError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10428,34 +12078,70 @@ impl UpdateInputDeviceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(UpdateInputDeviceError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateInputDeviceError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateInputDeviceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateInputDeviceError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateInputDeviceError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateInputDeviceError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(UpdateInputDeviceError::GatewayTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateInputDeviceError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateInputDeviceError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateInputDeviceError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateInputDeviceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateInputDeviceError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateInputDeviceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateInputDeviceError::TooManyRequests,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(UpdateInputDeviceError::UnprocessableEntity(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateInputDeviceError::UnprocessableEntity,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10502,31 +12188,62 @@ impl UpdateInputSecurityGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(UpdateInputSecurityGroupError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateInputSecurityGroupError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateInputSecurityGroupError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateInputSecurityGroupError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateInputSecurityGroupError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateInputSecurityGroupError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateInputSecurityGroupError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateInputSecurityGroupError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(UpdateInputSecurityGroupError::GatewayTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateInputSecurityGroupError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        UpdateInputSecurityGroupError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateInputSecurityGroupError::InternalServerError,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateInputSecurityGroupError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateInputSecurityGroupError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10574,30 +12291,70 @@ impl UpdateMultiplexError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(UpdateMultiplexError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMultiplexError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateMultiplexError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMultiplexError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateMultiplexError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMultiplexError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateMultiplexError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMultiplexError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(UpdateMultiplexError::GatewayTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMultiplexError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateMultiplexError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMultiplexError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateMultiplexError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMultiplexError::NotFound,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(UpdateMultiplexError::UnprocessableEntity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMultiplexError::UnprocessableEntity,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10646,36 +12403,70 @@ impl UpdateMultiplexProgramError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(UpdateMultiplexProgramError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMultiplexProgramError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateMultiplexProgramError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMultiplexProgramError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateMultiplexProgramError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMultiplexProgramError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateMultiplexProgramError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMultiplexProgramError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(UpdateMultiplexProgramError::GatewayTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateMultiplexProgramError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateMultiplexProgramError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateMultiplexProgramError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateMultiplexProgramError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMultiplexProgramError::NotFound,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(UpdateMultiplexProgramError::UnprocessableEntity(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateMultiplexProgramError::UnprocessableEntity,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10724,32 +12515,70 @@ impl UpdateReservationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadGatewayException" => {
-                    return RusotoError::Service(UpdateReservationError::BadGateway(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateReservationError::BadGateway,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateReservationError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateReservationError::BadRequest,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateReservationError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateReservationError::Conflict,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateReservationError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateReservationError::Forbidden,
+                    )
                 }
                 "GatewayTimeoutException" => {
-                    return RusotoError::Service(UpdateReservationError::GatewayTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateReservationError::GatewayTimeout,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateReservationError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateReservationError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateReservationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateReservationError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateReservationError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateReservationError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11115,11 +12944,12 @@ pub struct MediaLiveClient {
 impl MediaLiveClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> MediaLiveClient {
         MediaLiveClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "MediaLive"),
         }
     }
 
@@ -11134,12 +12964,32 @@ impl MediaLiveClient {
     {
         MediaLiveClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "MediaLive"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> MediaLiveClient {
-        MediaLiveClient { client, region }
+        MediaLiveClient {
+            client,
+            region: region_for_service(region, "MediaLive"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> MediaLiveClient {
+        MediaLiveClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("MediaLive"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> MediaLiveClient {
+        MediaLiveClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -11158,6 +13008,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("AcceptInputDeviceTransfer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11186,6 +13037,7 @@ impl MediaLive for MediaLiveClient {
         let request_uri = "/prod/batch/delete";
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("BatchDelete");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11217,6 +13069,7 @@ impl MediaLive for MediaLiveClient {
         let request_uri = "/prod/batch/start";
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("BatchStart");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11248,6 +13101,7 @@ impl MediaLive for MediaLiveClient {
         let request_uri = "/prod/batch/stop";
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("BatchStop");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11282,6 +13136,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation("BatchUpdateSchedule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11317,6 +13172,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("CancelInputDeviceTransfer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11345,6 +13201,7 @@ impl MediaLive for MediaLiveClient {
         let request_uri = "/prod/channels";
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("CreateChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11376,6 +13233,7 @@ impl MediaLive for MediaLiveClient {
         let request_uri = "/prod/inputs";
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("CreateInput");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11407,6 +13265,7 @@ impl MediaLive for MediaLiveClient {
         let request_uri = "/prod/inputSecurityGroups";
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("CreateInputSecurityGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11438,6 +13297,7 @@ impl MediaLive for MediaLiveClient {
         let request_uri = "/prod/multiplexes";
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("CreateMultiplex");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11472,6 +13332,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("CreateMultiplexProgram");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11506,6 +13367,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("CreateTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -11536,6 +13398,7 @@ impl MediaLive for MediaLiveClient {
         let request_uri = format!("/prod/channels/{channel_id}", channel_id = input.channel_id);
 
         let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
+        request.set_operation("DeleteChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11564,6 +13427,7 @@ impl MediaLive for MediaLiveClient {
         let request_uri = format!("/prod/inputs/{input_id}", input_id = input.input_id);
 
         let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
+        request.set_operation("DeleteInput");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11595,6 +13459,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
+        request.set_operation("DeleteInputSecurityGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11626,6 +13491,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
+        request.set_operation("DeleteMultiplex");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11658,6 +13524,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
+        request.set_operation("DeleteMultiplexProgram");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11689,6 +13556,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
+        request.set_operation("DeleteReservation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11720,6 +13588,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
+        request.set_operation("DeleteSchedule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11751,6 +13620,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "medialive", &self.region, &request_uri);
+        request.set_operation("DeleteTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -11784,6 +13654,7 @@ impl MediaLive for MediaLiveClient {
         let request_uri = format!("/prod/channels/{channel_id}", channel_id = input.channel_id);
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("DescribeChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11812,6 +13683,7 @@ impl MediaLive for MediaLiveClient {
         let request_uri = format!("/prod/inputs/{input_id}", input_id = input.input_id);
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("DescribeInput");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11843,6 +13715,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("DescribeInputDevice");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11875,6 +13748,8 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("DescribeInputDeviceThumbnail");
+        request.set_raw_response(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.add_header("accept", &input.accept.to_string());
@@ -11921,6 +13796,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("DescribeInputSecurityGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11952,6 +13828,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("DescribeMultiplex");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -11984,6 +13861,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("DescribeMultiplexProgram");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12015,6 +13893,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("DescribeOffering");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12046,6 +13925,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("DescribeReservation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12077,6 +13957,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("DescribeSchedule");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12114,6 +13995,7 @@ impl MediaLive for MediaLiveClient {
         let request_uri = "/prod/channels";
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("ListChannels");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12151,6 +14033,7 @@ impl MediaLive for MediaLiveClient {
         let request_uri = "/prod/inputDeviceTransfers";
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("ListInputDeviceTransfers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12189,6 +14072,7 @@ impl MediaLive for MediaLiveClient {
         let request_uri = "/prod/inputDevices";
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("ListInputDevices");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12226,6 +14110,7 @@ impl MediaLive for MediaLiveClient {
         let request_uri = "/prod/inputSecurityGroups";
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("ListInputSecurityGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12263,6 +14148,7 @@ impl MediaLive for MediaLiveClient {
         let request_uri = "/prod/inputs";
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("ListInputs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12303,6 +14189,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("ListMultiplexPrograms");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12340,6 +14227,7 @@ impl MediaLive for MediaLiveClient {
         let request_uri = "/prod/multiplexes";
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("ListMultiplexes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12377,6 +14265,7 @@ impl MediaLive for MediaLiveClient {
         let request_uri = "/prod/offerings";
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("ListOfferings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12444,6 +14333,7 @@ impl MediaLive for MediaLiveClient {
         let request_uri = "/prod/reservations";
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("ListReservations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -12508,6 +14398,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("GET", "medialive", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12539,6 +14430,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("PurchaseOffering");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -12574,6 +14466,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("RejectInputDeviceTransfer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12605,6 +14498,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("StartChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12636,6 +14530,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("StartMultiplex");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12667,6 +14562,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("StopChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12698,6 +14594,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("StopMultiplex");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -12729,6 +14626,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("POST", "medialive", &self.region, &request_uri);
+        request.set_operation("TransferInputDevice");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -12760,6 +14658,7 @@ impl MediaLive for MediaLiveClient {
         let request_uri = format!("/prod/channels/{channel_id}", channel_id = input.channel_id);
 
         let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation("UpdateChannel");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -12794,6 +14693,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation("UpdateChannelClass");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -12825,6 +14725,7 @@ impl MediaLive for MediaLiveClient {
         let request_uri = format!("/prod/inputs/{input_id}", input_id = input.input_id);
 
         let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation("UpdateInput");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -12859,6 +14760,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation("UpdateInputDevice");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -12893,6 +14795,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation("UpdateInputSecurityGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -12927,6 +14830,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation("UpdateMultiplex");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -12962,6 +14866,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation("UpdateMultiplexProgram");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -12996,6 +14901,7 @@ impl MediaLive for MediaLiveClient {
         );
 
         let mut request = SignedRequest::new("PUT", "medialive", &self.region, &request_uri);
+        request.set_operation("UpdateReservation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13018,3 +14924,545 @@ impl MediaLive for MediaLiveClient {
         }
     }
 }
+
+/// A blocking client for the MediaLive API, running the calls of a `MediaLiveClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `MediaLiveClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct MediaLiveBlockingClient {
+    client: MediaLiveClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl MediaLiveBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> MediaLiveBlockingClient {
+        MediaLiveBlockingClient::from_client(MediaLiveClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> MediaLiveBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        MediaLiveBlockingClient::from_client(MediaLiveClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> MediaLiveBlockingClient {
+        MediaLiveBlockingClient::from_client(MediaLiveClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> MediaLiveBlockingClient {
+        MediaLiveBlockingClient::from_client(MediaLiveClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: MediaLiveClient) -> MediaLiveBlockingClient {
+        MediaLiveBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> MediaLiveBlockingClient {
+        MediaLiveBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> MediaLiveBlockingClient {
+        MediaLiveBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`MediaLive::accept_input_device_transfer`].
+    pub fn accept_input_device_transfer(
+        &self,
+        input: AcceptInputDeviceTransferRequest,
+    ) -> Result<AcceptInputDeviceTransferResponse, RusotoError<AcceptInputDeviceTransferError>>
+    {
+        self.runtime
+            .block_on(self.client.accept_input_device_transfer(input))
+    }
+
+    /// Blocking version of [`MediaLive::batch_delete`].
+    pub fn batch_delete(
+        &self,
+        input: BatchDeleteRequest,
+    ) -> Result<BatchDeleteResponse, RusotoError<BatchDeleteError>> {
+        self.runtime.block_on(self.client.batch_delete(input))
+    }
+
+    /// Blocking version of [`MediaLive::batch_start`].
+    pub fn batch_start(
+        &self,
+        input: BatchStartRequest,
+    ) -> Result<BatchStartResponse, RusotoError<BatchStartError>> {
+        self.runtime.block_on(self.client.batch_start(input))
+    }
+
+    /// Blocking version of [`MediaLive::batch_stop`].
+    pub fn batch_stop(
+        &self,
+        input: BatchStopRequest,
+    ) -> Result<BatchStopResponse, RusotoError<BatchStopError>> {
+        self.runtime.block_on(self.client.batch_stop(input))
+    }
+
+    /// Blocking version of [`MediaLive::batch_update_schedule`].
+    pub fn batch_update_schedule(
+        &self,
+        input: BatchUpdateScheduleRequest,
+    ) -> Result<BatchUpdateScheduleResponse, RusotoError<BatchUpdateScheduleError>> {
+        self.runtime
+            .block_on(self.client.batch_update_schedule(input))
+    }
+
+    /// Blocking version of [`MediaLive::cancel_input_device_transfer`].
+    pub fn cancel_input_device_transfer(
+        &self,
+        input: CancelInputDeviceTransferRequest,
+    ) -> Result<CancelInputDeviceTransferResponse, RusotoError<CancelInputDeviceTransferError>>
+    {
+        self.runtime
+            .block_on(self.client.cancel_input_device_transfer(input))
+    }
+
+    /// Blocking version of [`MediaLive::create_channel`].
+    pub fn create_channel(
+        &self,
+        input: CreateChannelRequest,
+    ) -> Result<CreateChannelResponse, RusotoError<CreateChannelError>> {
+        self.runtime.block_on(self.client.create_channel(input))
+    }
+
+    /// Blocking version of [`MediaLive::create_input`].
+    pub fn create_input(
+        &self,
+        input: CreateInputRequest,
+    ) -> Result<CreateInputResponse, RusotoError<CreateInputError>> {
+        self.runtime.block_on(self.client.create_input(input))
+    }
+
+    /// Blocking version of [`MediaLive::create_input_security_group`].
+    pub fn create_input_security_group(
+        &self,
+        input: CreateInputSecurityGroupRequest,
+    ) -> Result<CreateInputSecurityGroupResponse, RusotoError<CreateInputSecurityGroupError>> {
+        self.runtime
+            .block_on(self.client.create_input_security_group(input))
+    }
+
+    /// Blocking version of [`MediaLive::create_multiplex`].
+    pub fn create_multiplex(
+        &self,
+        input: CreateMultiplexRequest,
+    ) -> Result<CreateMultiplexResponse, RusotoError<CreateMultiplexError>> {
+        self.runtime.block_on(self.client.create_multiplex(input))
+    }
+
+    /// Blocking version of [`MediaLive::create_multiplex_program`].
+    pub fn create_multiplex_program(
+        &self,
+        input: CreateMultiplexProgramRequest,
+    ) -> Result<CreateMultiplexProgramResponse, RusotoError<CreateMultiplexProgramError>> {
+        self.runtime
+            .block_on(self.client.create_multiplex_program(input))
+    }
+
+    /// Blocking version of [`MediaLive::create_tags`].
+    pub fn create_tags(
+        &self,
+        input: CreateTagsRequest,
+    ) -> Result<(), RusotoError<CreateTagsError>> {
+        self.runtime.block_on(self.client.create_tags(input))
+    }
+
+    /// Blocking version of [`MediaLive::delete_channel`].
+    pub fn delete_channel(
+        &self,
+        input: DeleteChannelRequest,
+    ) -> Result<DeleteChannelResponse, RusotoError<DeleteChannelError>> {
+        self.runtime.block_on(self.client.delete_channel(input))
+    }
+
+    /// Blocking version of [`MediaLive::delete_input`].
+    pub fn delete_input(
+        &self,
+        input: DeleteInputRequest,
+    ) -> Result<DeleteInputResponse, RusotoError<DeleteInputError>> {
+        self.runtime.block_on(self.client.delete_input(input))
+    }
+
+    /// Blocking version of [`MediaLive::delete_input_security_group`].
+    pub fn delete_input_security_group(
+        &self,
+        input: DeleteInputSecurityGroupRequest,
+    ) -> Result<DeleteInputSecurityGroupResponse, RusotoError<DeleteInputSecurityGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_input_security_group(input))
+    }
+
+    /// Blocking version of [`MediaLive::delete_multiplex`].
+    pub fn delete_multiplex(
+        &self,
+        input: DeleteMultiplexRequest,
+    ) -> Result<DeleteMultiplexResponse, RusotoError<DeleteMultiplexError>> {
+        self.runtime.block_on(self.client.delete_multiplex(input))
+    }
+
+    /// Blocking version of [`MediaLive::delete_multiplex_program`].
+    pub fn delete_multiplex_program(
+        &self,
+        input: DeleteMultiplexProgramRequest,
+    ) -> Result<DeleteMultiplexProgramResponse, RusotoError<DeleteMultiplexProgramError>> {
+        self.runtime
+            .block_on(self.client.delete_multiplex_program(input))
+    }
+
+    /// Blocking version of [`MediaLive::delete_reservation`].
+    pub fn delete_reservation(
+        &self,
+        input: DeleteReservationRequest,
+    ) -> Result<DeleteReservationResponse, RusotoError<DeleteReservationError>> {
+        self.runtime.block_on(self.client.delete_reservation(input))
+    }
+
+    /// Blocking version of [`MediaLive::delete_schedule`].
+    pub fn delete_schedule(
+        &self,
+        input: DeleteScheduleRequest,
+    ) -> Result<DeleteScheduleResponse, RusotoError<DeleteScheduleError>> {
+        self.runtime.block_on(self.client.delete_schedule(input))
+    }
+
+    /// Blocking version of [`MediaLive::delete_tags`].
+    pub fn delete_tags(
+        &self,
+        input: DeleteTagsRequest,
+    ) -> Result<(), RusotoError<DeleteTagsError>> {
+        self.runtime.block_on(self.client.delete_tags(input))
+    }
+
+    /// Blocking version of [`MediaLive::describe_channel`].
+    pub fn describe_channel(
+        &self,
+        input: DescribeChannelRequest,
+    ) -> Result<DescribeChannelResponse, RusotoError<DescribeChannelError>> {
+        self.runtime.block_on(self.client.describe_channel(input))
+    }
+
+    /// Blocking version of [`MediaLive::describe_input`].
+    pub fn describe_input(
+        &self,
+        input: DescribeInputRequest,
+    ) -> Result<DescribeInputResponse, RusotoError<DescribeInputError>> {
+        self.runtime.block_on(self.client.describe_input(input))
+    }
+
+    /// Blocking version of [`MediaLive::describe_input_device`].
+    pub fn describe_input_device(
+        &self,
+        input: DescribeInputDeviceRequest,
+    ) -> Result<DescribeInputDeviceResponse, RusotoError<DescribeInputDeviceError>> {
+        self.runtime
+            .block_on(self.client.describe_input_device(input))
+    }
+
+    /// Blocking version of [`MediaLive::describe_input_device_thumbnail`].
+    pub fn describe_input_device_thumbnail(
+        &self,
+        input: DescribeInputDeviceThumbnailRequest,
+    ) -> Result<DescribeInputDeviceThumbnailResponse, RusotoError<DescribeInputDeviceThumbnailError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_input_device_thumbnail(input))
+    }
+
+    /// Blocking version of [`MediaLive::describe_input_security_group`].
+    pub fn describe_input_security_group(
+        &self,
+        input: DescribeInputSecurityGroupRequest,
+    ) -> Result<DescribeInputSecurityGroupResponse, RusotoError<DescribeInputSecurityGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_input_security_group(input))
+    }
+
+    /// Blocking version of [`MediaLive::describe_multiplex`].
+    pub fn describe_multiplex(
+        &self,
+        input: DescribeMultiplexRequest,
+    ) -> Result<DescribeMultiplexResponse, RusotoError<DescribeMultiplexError>> {
+        self.runtime.block_on(self.client.describe_multiplex(input))
+    }
+
+    /// Blocking version of [`MediaLive::describe_multiplex_program`].
+    pub fn describe_multiplex_program(
+        &self,
+        input: DescribeMultiplexProgramRequest,
+    ) -> Result<DescribeMultiplexProgramResponse, RusotoError<DescribeMultiplexProgramError>> {
+        self.runtime
+            .block_on(self.client.describe_multiplex_program(input))
+    }
+
+    /// Blocking version of [`MediaLive::describe_offering`].
+    pub fn describe_offering(
+        &self,
+        input: DescribeOfferingRequest,
+    ) -> Result<DescribeOfferingResponse, RusotoError<DescribeOfferingError>> {
+        self.runtime.block_on(self.client.describe_offering(input))
+    }
+
+    /// Blocking version of [`MediaLive::describe_reservation`].
+    pub fn describe_reservation(
+        &self,
+        input: DescribeReservationRequest,
+    ) -> Result<DescribeReservationResponse, RusotoError<DescribeReservationError>> {
+        self.runtime
+            .block_on(self.client.describe_reservation(input))
+    }
+
+    /// Blocking version of [`MediaLive::describe_schedule`].
+    pub fn describe_schedule(
+        &self,
+        input: DescribeScheduleRequest,
+    ) -> Result<DescribeScheduleResponse, RusotoError<DescribeScheduleError>> {
+        self.runtime.block_on(self.client.describe_schedule(input))
+    }
+
+    /// Blocking version of [`MediaLive::list_channels`].
+    pub fn list_channels(
+        &self,
+        input: ListChannelsRequest,
+    ) -> Result<ListChannelsResponse, RusotoError<ListChannelsError>> {
+        self.runtime.block_on(self.client.list_channels(input))
+    }
+
+    /// Blocking version of [`MediaLive::list_input_device_transfers`].
+    pub fn list_input_device_transfers(
+        &self,
+        input: ListInputDeviceTransfersRequest,
+    ) -> Result<ListInputDeviceTransfersResponse, RusotoError<ListInputDeviceTransfersError>> {
+        self.runtime
+            .block_on(self.client.list_input_device_transfers(input))
+    }
+
+    /// Blocking version of [`MediaLive::list_input_devices`].
+    pub fn list_input_devices(
+        &self,
+        input: ListInputDevicesRequest,
+    ) -> Result<ListInputDevicesResponse, RusotoError<ListInputDevicesError>> {
+        self.runtime.block_on(self.client.list_input_devices(input))
+    }
+
+    /// Blocking version of [`MediaLive::list_input_security_groups`].
+    pub fn list_input_security_groups(
+        &self,
+        input: ListInputSecurityGroupsRequest,
+    ) -> Result<ListInputSecurityGroupsResponse, RusotoError<ListInputSecurityGroupsError>> {
+        self.runtime
+            .block_on(self.client.list_input_security_groups(input))
+    }
+
+    /// Blocking version of [`MediaLive::list_inputs`].
+    pub fn list_inputs(
+        &self,
+        input: ListInputsRequest,
+    ) -> Result<ListInputsResponse, RusotoError<ListInputsError>> {
+        self.runtime.block_on(self.client.list_inputs(input))
+    }
+
+    /// Blocking version of [`MediaLive::list_multiplex_programs`].
+    pub fn list_multiplex_programs(
+        &self,
+        input: ListMultiplexProgramsRequest,
+    ) -> Result<ListMultiplexProgramsResponse, RusotoError<ListMultiplexProgramsError>> {
+        self.runtime
+            .block_on(self.client.list_multiplex_programs(input))
+    }
+
+    /// Blocking version of [`MediaLive::list_multiplexes`].
+    pub fn list_multiplexes(
+        &self,
+        input: ListMultiplexesRequest,
+    ) -> Result<ListMultiplexesResponse, RusotoError<ListMultiplexesError>> {
+        self.runtime.block_on(self.client.list_multiplexes(input))
+    }
+
+    /// Blocking version of [`MediaLive::list_offerings`].
+    pub fn list_offerings(
+        &self,
+        input: ListOfferingsRequest,
+    ) -> Result<ListOfferingsResponse, RusotoError<ListOfferingsError>> {
+        self.runtime.block_on(self.client.list_offerings(input))
+    }
+
+    /// Blocking version of [`MediaLive::list_reservations`].
+    pub fn list_reservations(
+        &self,
+        input: ListReservationsRequest,
+    ) -> Result<ListReservationsResponse, RusotoError<ListReservationsError>> {
+        self.runtime.block_on(self.client.list_reservations(input))
+    }
+
+    /// Blocking version of [`MediaLive::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`MediaLive::purchase_offering`].
+    pub fn purchase_offering(
+        &self,
+        input: PurchaseOfferingRequest,
+    ) -> Result<PurchaseOfferingResponse, RusotoError<PurchaseOfferingError>> {
+        self.runtime.block_on(self.client.purchase_offering(input))
+    }
+
+    /// Blocking version of [`MediaLive::reject_input_device_transfer`].
+    pub fn reject_input_device_transfer(
+        &self,
+        input: RejectInputDeviceTransferRequest,
+    ) -> Result<RejectInputDeviceTransferResponse, RusotoError<RejectInputDeviceTransferError>>
+    {
+        self.runtime
+            .block_on(self.client.reject_input_device_transfer(input))
+    }
+
+    /// Blocking version of [`MediaLive::start_channel`].
+    pub fn start_channel(
+        &self,
+        input: StartChannelRequest,
+    ) -> Result<StartChannelResponse, RusotoError<StartChannelError>> {
+        self.runtime.block_on(self.client.start_channel(input))
+    }
+
+    /// Blocking version of [`MediaLive::start_multiplex`].
+    pub fn start_multiplex(
+        &self,
+        input: StartMultiplexRequest,
+    ) -> Result<StartMultiplexResponse, RusotoError<StartMultiplexError>> {
+        self.runtime.block_on(self.client.start_multiplex(input))
+    }
+
+    /// Blocking version of [`MediaLive::stop_channel`].
+    pub fn stop_channel(
+        &self,
+        input: StopChannelRequest,
+    ) -> Result<StopChannelResponse, RusotoError<StopChannelError>> {
+        self.runtime.block_on(self.client.stop_channel(input))
+    }
+
+    /// Blocking version of [`MediaLive::stop_multiplex`].
+    pub fn stop_multiplex(
+        &self,
+        input: StopMultiplexRequest,
+    ) -> Result<StopMultiplexResponse, RusotoError<StopMultiplexError>> {
+        self.runtime.block_on(self.client.stop_multiplex(input))
+    }
+
+    /// Blocking version of [`MediaLive::transfer_input_device`].
+    pub fn transfer_input_device(
+        &self,
+        input: TransferInputDeviceRequest,
+    ) -> Result<TransferInputDeviceResponse, RusotoError<TransferInputDeviceError>> {
+        self.runtime
+            .block_on(self.client.transfer_input_device(input))
+    }
+
+    /// Blocking version of [`MediaLive::update_channel`].
+    pub fn update_channel(
+        &self,
+        input: UpdateChannelRequest,
+    ) -> Result<UpdateChannelResponse, RusotoError<UpdateChannelError>> {
+        self.runtime.block_on(self.client.update_channel(input))
+    }
+
+    /// Blocking version of [`MediaLive::update_channel_class`].
+    pub fn update_channel_class(
+        &self,
+        input: UpdateChannelClassRequest,
+    ) -> Result<UpdateChannelClassResponse, RusotoError<UpdateChannelClassError>> {
+        self.runtime
+            .block_on(self.client.update_channel_class(input))
+    }
+
+    /// Blocking version of [`MediaLive::update_input`].
+    pub fn update_input(
+        &self,
+        input: UpdateInputRequest,
+    ) -> Result<UpdateInputResponse, RusotoError<UpdateInputError>> {
+        self.runtime.block_on(self.client.update_input(input))
+    }
+
+    /// Blocking version of [`MediaLive::update_input_device`].
+    pub fn update_input_device(
+        &self,
+        input: UpdateInputDeviceRequest,
+    ) -> Result<UpdateInputDeviceResponse, RusotoError<UpdateInputDeviceError>> {
+        self.runtime
+            .block_on(self.client.update_input_device(input))
+    }
+
+    /// Blocking version of [`MediaLive::update_input_security_group`].
+    pub fn update_input_security_group(
+        &self,
+        input: UpdateInputSecurityGroupRequest,
+    ) -> Result<UpdateInputSecurityGroupResponse, RusotoError<UpdateInputSecurityGroupError>> {
+        self.runtime
+            .block_on(self.client.update_input_security_group(input))
+    }
+
+    /// Blocking version of [`MediaLive::update_multiplex`].
+    pub fn update_multiplex(
+        &self,
+        input: UpdateMultiplexRequest,
+    ) -> Result<UpdateMultiplexResponse, RusotoError<UpdateMultiplexError>> {
+        self.runtime.block_on(self.client.update_multiplex(input))
+    }
+
+    /// Blocking version of [`MediaLive::update_multiplex_program`].
+    pub fn update_multiplex_program(
+        &self,
+        input: UpdateMultiplexProgramRequest,
+    ) -> Result<UpdateMultiplexProgramResponse, RusotoError<UpdateMultiplexProgramError>> {
+        self.runtime
+            .block_on(self.client.update_multiplex_program(input))
+    }
+
+    /// Blocking version of [`MediaLive::update_reservation`].
+    pub fn update_reservation(
+        &self,
+        input: UpdateReservationRequest,
+    ) -> Result<UpdateReservationResponse, RusotoError<UpdateReservationError>> {
+        self.runtime.block_on(self.client.update_reservation(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -867,24 +868,54 @@ impl CreateAssetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateAssetError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAssetError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateAssetError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAssetError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateAssetError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAssetError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateAssetError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAssetError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateAssetError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAssetError::TooManyRequests,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(CreateAssetError::UnprocessableEntity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAssetError::UnprocessableEntity,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -929,36 +960,54 @@ impl CreatePackagingConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreatePackagingConfigurationError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePackagingConfigurationError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        CreatePackagingConfigurationError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePackagingConfigurationError::InternalServerError,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreatePackagingConfigurationError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePackagingConfigurationError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        CreatePackagingConfigurationError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePackagingConfigurationError::ServiceUnavailable,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        CreatePackagingConfigurationError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePackagingConfigurationError::TooManyRequests,
                     )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(
-                        CreatePackagingConfigurationError::UnprocessableEntity(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePackagingConfigurationError::UnprocessableEntity,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1007,32 +1056,54 @@ impl CreatePackagingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreatePackagingGroupError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePackagingGroupError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreatePackagingGroupError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePackagingGroupError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreatePackagingGroupError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePackagingGroupError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreatePackagingGroupError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePackagingGroupError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreatePackagingGroupError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePackagingGroupError::TooManyRequests,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(CreatePackagingGroupError::UnprocessableEntity(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePackagingGroupError::UnprocessableEntity,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1075,24 +1146,54 @@ impl DeleteAssetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteAssetError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAssetError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteAssetError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAssetError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteAssetError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAssetError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteAssetError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAssetError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteAssetError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAssetError::TooManyRequests,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(DeleteAssetError::UnprocessableEntity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAssetError::UnprocessableEntity,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1137,36 +1238,54 @@ impl DeletePackagingConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeletePackagingConfigurationError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeletePackagingConfigurationError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        DeletePackagingConfigurationError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePackagingConfigurationError::InternalServerError,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeletePackagingConfigurationError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeletePackagingConfigurationError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DeletePackagingConfigurationError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePackagingConfigurationError::ServiceUnavailable,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        DeletePackagingConfigurationError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePackagingConfigurationError::TooManyRequests,
                     )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(
-                        DeletePackagingConfigurationError::UnprocessableEntity(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePackagingConfigurationError::UnprocessableEntity,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1215,32 +1334,54 @@ impl DeletePackagingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeletePackagingGroupError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePackagingGroupError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeletePackagingGroupError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeletePackagingGroupError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeletePackagingGroupError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePackagingGroupError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeletePackagingGroupError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeletePackagingGroupError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeletePackagingGroupError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeletePackagingGroupError::TooManyRequests,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(DeletePackagingGroupError::UnprocessableEntity(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeletePackagingGroupError::UnprocessableEntity,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1283,24 +1424,54 @@ impl DescribeAssetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribeAssetError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAssetError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribeAssetError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAssetError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeAssetError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAssetError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeAssetError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAssetError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeAssetError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAssetError::TooManyRequests,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(DescribeAssetError::UnprocessableEntity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAssetError::UnprocessableEntity,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1345,36 +1516,54 @@ impl DescribePackagingConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribePackagingConfigurationError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribePackagingConfigurationError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        DescribePackagingConfigurationError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePackagingConfigurationError::InternalServerError,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribePackagingConfigurationError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribePackagingConfigurationError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DescribePackagingConfigurationError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePackagingConfigurationError::ServiceUnavailable,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        DescribePackagingConfigurationError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePackagingConfigurationError::TooManyRequests,
                     )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(
-                        DescribePackagingConfigurationError::UnprocessableEntity(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePackagingConfigurationError::UnprocessableEntity,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1425,32 +1614,54 @@ impl DescribePackagingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(DescribePackagingGroupError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePackagingGroupError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DescribePackagingGroupError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribePackagingGroupError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribePackagingGroupError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePackagingGroupError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribePackagingGroupError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribePackagingGroupError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribePackagingGroupError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribePackagingGroupError::TooManyRequests,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(DescribePackagingGroupError::UnprocessableEntity(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribePackagingGroupError::UnprocessableEntity,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1493,24 +1704,54 @@ impl ListAssetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListAssetsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAssetsError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListAssetsError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAssetsError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListAssetsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAssetsError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListAssetsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAssetsError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListAssetsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAssetsError::TooManyRequests,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(ListAssetsError::UnprocessableEntity(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAssetsError::UnprocessableEntity,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1555,36 +1796,54 @@ impl ListPackagingConfigurationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListPackagingConfigurationsError::Forbidden(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPackagingConfigurationsError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(
-                        ListPackagingConfigurationsError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPackagingConfigurationsError::InternalServerError,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListPackagingConfigurationsError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPackagingConfigurationsError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        ListPackagingConfigurationsError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPackagingConfigurationsError::ServiceUnavailable,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListPackagingConfigurationsError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPackagingConfigurationsError::TooManyRequests,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(
-                        ListPackagingConfigurationsError::UnprocessableEntity(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPackagingConfigurationsError::UnprocessableEntity,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1633,30 +1892,54 @@ impl ListPackagingGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListPackagingGroupsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPackagingGroupsError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListPackagingGroupsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPackagingGroupsError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListPackagingGroupsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPackagingGroupsError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListPackagingGroupsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPackagingGroupsError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListPackagingGroupsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPackagingGroupsError::TooManyRequests,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(ListPackagingGroupsError::UnprocessableEntity(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPackagingGroupsError::UnprocessableEntity,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1685,7 +1968,7 @@ impl ListTagsForResourceError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListTagsForResourceError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1707,7 +1990,7 @@ impl TagResourceError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<TagResourceError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1729,7 +2012,7 @@ impl UntagResourceError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UntagResourceError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1765,32 +2048,54 @@ impl UpdatePackagingGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdatePackagingGroupError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePackagingGroupError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdatePackagingGroupError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdatePackagingGroupError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdatePackagingGroupError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePackagingGroupError::NotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdatePackagingGroupError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdatePackagingGroupError::ServiceUnavailable,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdatePackagingGroupError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdatePackagingGroupError::TooManyRequests,
+                    )
                 }
                 "UnprocessableEntityException" => {
-                    return RusotoError::Service(UpdatePackagingGroupError::UnprocessableEntity(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdatePackagingGroupError::UnprocessableEntity,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1923,11 +2228,12 @@ pub struct MediaPackageVodClient {
 impl MediaPackageVodClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> MediaPackageVodClient {
         MediaPackageVodClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "MediaPackage Vod"),
         }
     }
 
@@ -1942,12 +2248,32 @@ impl MediaPackageVodClient {
     {
         MediaPackageVodClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "MediaPackage Vod"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> MediaPackageVodClient {
-        MediaPackageVodClient { client, region }
+        MediaPackageVodClient {
+            client,
+            region: region_for_service(region, "MediaPackage Vod"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> MediaPackageVodClient {
+        MediaPackageVodClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("MediaPackage Vod"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> MediaPackageVodClient {
+        MediaPackageVodClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -1963,6 +2289,7 @@ impl MediaPackageVod for MediaPackageVodClient {
 
         let mut request =
             SignedRequest::new("POST", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("CreateAsset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1996,6 +2323,7 @@ impl MediaPackageVod for MediaPackageVodClient {
 
         let mut request =
             SignedRequest::new("POST", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("CreatePackagingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2028,6 +2356,7 @@ impl MediaPackageVod for MediaPackageVodClient {
 
         let mut request =
             SignedRequest::new("POST", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("CreatePackagingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2060,6 +2389,7 @@ impl MediaPackageVod for MediaPackageVodClient {
 
         let mut request =
             SignedRequest::new("DELETE", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("DeleteAsset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2090,6 +2420,7 @@ impl MediaPackageVod for MediaPackageVodClient {
 
         let mut request =
             SignedRequest::new("DELETE", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("DeletePackagingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2119,6 +2450,7 @@ impl MediaPackageVod for MediaPackageVodClient {
 
         let mut request =
             SignedRequest::new("DELETE", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("DeletePackagingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2147,6 +2479,7 @@ impl MediaPackageVod for MediaPackageVodClient {
         let request_uri = format!("/assets/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("DescribeAsset");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2178,6 +2511,7 @@ impl MediaPackageVod for MediaPackageVodClient {
         let request_uri = format!("/packaging_configurations/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("DescribePackagingConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2206,6 +2540,7 @@ impl MediaPackageVod for MediaPackageVodClient {
         let request_uri = format!("/packaging_groups/{id}", id = input.id);
 
         let mut request = SignedRequest::new("GET", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("DescribePackagingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2234,6 +2569,7 @@ impl MediaPackageVod for MediaPackageVodClient {
         let request_uri = "/assets";
 
         let mut request = SignedRequest::new("GET", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("ListAssets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2275,6 +2611,7 @@ impl MediaPackageVod for MediaPackageVodClient {
         let request_uri = "/packaging_configurations";
 
         let mut request = SignedRequest::new("GET", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("ListPackagingConfigurations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2315,6 +2652,7 @@ impl MediaPackageVod for MediaPackageVodClient {
         let request_uri = "/packaging_groups";
 
         let mut request = SignedRequest::new("GET", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("ListPackagingGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2352,6 +2690,7 @@ impl MediaPackageVod for MediaPackageVodClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2381,6 +2720,7 @@ impl MediaPackageVod for MediaPackageVodClient {
 
         let mut request =
             SignedRequest::new("POST", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2412,6 +2752,7 @@ impl MediaPackageVod for MediaPackageVodClient {
 
         let mut request =
             SignedRequest::new("DELETE", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2445,6 +2786,7 @@ impl MediaPackageVod for MediaPackageVodClient {
         let request_uri = format!("/packaging_groups/{id}", id = input.id);
 
         let mut request = SignedRequest::new("PUT", "mediapackage-vod", &self.region, &request_uri);
+        request.set_operation("UpdatePackagingGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2467,3 +2809,230 @@ impl MediaPackageVod for MediaPackageVodClient {
         }
     }
 }
+
+/// A blocking client for the MediaPackage Vod API, running the calls of a `MediaPackageVodClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `MediaPackageVodClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct MediaPackageVodBlockingClient {
+    client: MediaPackageVodClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl MediaPackageVodBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> MediaPackageVodBlockingClient {
+        MediaPackageVodBlockingClient::from_client(MediaPackageVodClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> MediaPackageVodBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        MediaPackageVodBlockingClient::from_client(MediaPackageVodClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> MediaPackageVodBlockingClient {
+        MediaPackageVodBlockingClient::from_client(MediaPackageVodClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> MediaPackageVodBlockingClient {
+        MediaPackageVodBlockingClient::from_client(MediaPackageVodClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: MediaPackageVodClient) -> MediaPackageVodBlockingClient {
+        MediaPackageVodBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> MediaPackageVodBlockingClient {
+        MediaPackageVodBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> MediaPackageVodBlockingClient {
+        MediaPackageVodBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`MediaPackageVod::create_asset`].
+    pub fn create_asset(
+        &self,
+        input: CreateAssetRequest,
+    ) -> Result<CreateAssetResponse, RusotoError<CreateAssetError>> {
+        self.runtime.block_on(self.client.create_asset(input))
+    }
+
+    /// Blocking version of [`MediaPackageVod::create_packaging_configuration`].
+    pub fn create_packaging_configuration(
+        &self,
+        input: CreatePackagingConfigurationRequest,
+    ) -> Result<CreatePackagingConfigurationResponse, RusotoError<CreatePackagingConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.create_packaging_configuration(input))
+    }
+
+    /// Blocking version of [`MediaPackageVod::create_packaging_group`].
+    pub fn create_packaging_group(
+        &self,
+        input: CreatePackagingGroupRequest,
+    ) -> Result<CreatePackagingGroupResponse, RusotoError<CreatePackagingGroupError>> {
+        self.runtime
+            .block_on(self.client.create_packaging_group(input))
+    }
+
+    /// Blocking version of [`MediaPackageVod::delete_asset`].
+    pub fn delete_asset(
+        &self,
+        input: DeleteAssetRequest,
+    ) -> Result<DeleteAssetResponse, RusotoError<DeleteAssetError>> {
+        self.runtime.block_on(self.client.delete_asset(input))
+    }
+
+    /// Blocking version of [`MediaPackageVod::delete_packaging_configuration`].
+    pub fn delete_packaging_configuration(
+        &self,
+        input: DeletePackagingConfigurationRequest,
+    ) -> Result<DeletePackagingConfigurationResponse, RusotoError<DeletePackagingConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_packaging_configuration(input))
+    }
+
+    /// Blocking version of [`MediaPackageVod::delete_packaging_group`].
+    pub fn delete_packaging_group(
+        &self,
+        input: DeletePackagingGroupRequest,
+    ) -> Result<DeletePackagingGroupResponse, RusotoError<DeletePackagingGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_packaging_group(input))
+    }
+
+    /// Blocking version of [`MediaPackageVod::describe_asset`].
+    pub fn describe_asset(
+        &self,
+        input: DescribeAssetRequest,
+    ) -> Result<DescribeAssetResponse, RusotoError<DescribeAssetError>> {
+        self.runtime.block_on(self.client.describe_asset(input))
+    }
+
+    /// Blocking version of [`MediaPackageVod::describe_packaging_configuration`].
+    pub fn describe_packaging_configuration(
+        &self,
+        input: DescribePackagingConfigurationRequest,
+    ) -> Result<
+        DescribePackagingConfigurationResponse,
+        RusotoError<DescribePackagingConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_packaging_configuration(input))
+    }
+
+    /// Blocking version of [`MediaPackageVod::describe_packaging_group`].
+    pub fn describe_packaging_group(
+        &self,
+        input: DescribePackagingGroupRequest,
+    ) -> Result<DescribePackagingGroupResponse, RusotoError<DescribePackagingGroupError>> {
+        self.runtime
+            .block_on(self.client.describe_packaging_group(input))
+    }
+
+    /// Blocking version of [`MediaPackageVod::list_assets`].
+    pub fn list_assets(
+        &self,
+        input: ListAssetsRequest,
+    ) -> Result<ListAssetsResponse, RusotoError<ListAssetsError>> {
+        self.runtime.block_on(self.client.list_assets(input))
+    }
+
+    /// Blocking version of [`MediaPackageVod::list_packaging_configurations`].
+    pub fn list_packaging_configurations(
+        &self,
+        input: ListPackagingConfigurationsRequest,
+    ) -> Result<ListPackagingConfigurationsResponse, RusotoError<ListPackagingConfigurationsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_packaging_configurations(input))
+    }
+
+    /// Blocking version of [`MediaPackageVod::list_packaging_groups`].
+    pub fn list_packaging_groups(
+        &self,
+        input: ListPackagingGroupsRequest,
+    ) -> Result<ListPackagingGroupsResponse, RusotoError<ListPackagingGroupsError>> {
+        self.runtime
+            .block_on(self.client.list_packaging_groups(input))
+    }
+
+    /// Blocking version of [`MediaPackageVod::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`MediaPackageVod::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`MediaPackageVod::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`MediaPackageVod::update_packaging_group`].
+    pub fn update_packaging_group(
+        &self,
+        input: UpdatePackagingGroupRequest,
+    ) -> Result<UpdatePackagingGroupResponse, RusotoError<UpdatePackagingGroupError>> {
+        self.runtime
+            .block_on(self.client.update_packaging_group(input))
+    }
+}
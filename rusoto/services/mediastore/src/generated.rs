@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -472,15 +473,30 @@ impl CreateContainerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(CreateContainerError::ContainerInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateContainerError::ContainerInUse,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateContainerError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateContainerError::InternalServerError,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateContainerError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateContainerError::LimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -514,15 +530,30 @@ impl DeleteContainerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(DeleteContainerError::ContainerInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteContainerError::ContainerInUse,
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(DeleteContainerError::ContainerNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteContainerError::ContainerNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteContainerError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteContainerError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -558,26 +589,38 @@ impl DeleteContainerPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(DeleteContainerPolicyError::ContainerInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteContainerPolicyError::ContainerInUse,
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(DeleteContainerPolicyError::ContainerNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteContainerPolicyError::ContainerNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteContainerPolicyError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteContainerPolicyError::InternalServerError,
+                    )
                 }
                 "PolicyNotFoundException" => {
-                    return RusotoError::Service(DeleteContainerPolicyError::PolicyNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteContainerPolicyError::PolicyNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -614,20 +657,38 @@ impl DeleteCorsPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(DeleteCorsPolicyError::ContainerInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCorsPolicyError::ContainerInUse,
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(DeleteCorsPolicyError::ContainerNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCorsPolicyError::ContainerNotFound,
+                    )
                 }
                 "CorsPolicyNotFoundException" => {
-                    return RusotoError::Service(DeleteCorsPolicyError::CorsPolicyNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCorsPolicyError::CorsPolicyNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteCorsPolicyError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteCorsPolicyError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -664,26 +725,38 @@ impl DeleteLifecyclePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(DeleteLifecyclePolicyError::ContainerInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteLifecyclePolicyError::ContainerInUse,
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(DeleteLifecyclePolicyError::ContainerNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteLifecyclePolicyError::ContainerNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteLifecyclePolicyError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteLifecyclePolicyError::InternalServerError,
+                    )
                 }
                 "PolicyNotFoundException" => {
-                    return RusotoError::Service(DeleteLifecyclePolicyError::PolicyNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteLifecyclePolicyError::PolicyNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -720,22 +793,38 @@ impl DeleteMetricPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(DeleteMetricPolicyError::ContainerInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMetricPolicyError::ContainerInUse,
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(DeleteMetricPolicyError::ContainerNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteMetricPolicyError::ContainerNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteMetricPolicyError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteMetricPolicyError::InternalServerError,
+                    )
                 }
                 "PolicyNotFoundException" => {
-                    return RusotoError::Service(DeleteMetricPolicyError::PolicyNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteMetricPolicyError::PolicyNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -768,14 +857,22 @@ impl DescribeContainerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(DescribeContainerError::ContainerNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeContainerError::ContainerNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeContainerError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeContainerError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -810,22 +907,38 @@ impl GetContainerPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(GetContainerPolicyError::ContainerInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetContainerPolicyError::ContainerInUse,
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(GetContainerPolicyError::ContainerNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetContainerPolicyError::ContainerNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetContainerPolicyError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetContainerPolicyError::InternalServerError,
+                    )
                 }
                 "PolicyNotFoundException" => {
-                    return RusotoError::Service(GetContainerPolicyError::PolicyNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetContainerPolicyError::PolicyNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -862,18 +975,38 @@ impl GetCorsPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(GetCorsPolicyError::ContainerInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCorsPolicyError::ContainerInUse,
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(GetCorsPolicyError::ContainerNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCorsPolicyError::ContainerNotFound,
+                    )
                 }
                 "CorsPolicyNotFoundException" => {
-                    return RusotoError::Service(GetCorsPolicyError::CorsPolicyNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCorsPolicyError::CorsPolicyNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetCorsPolicyError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCorsPolicyError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -910,22 +1043,38 @@ impl GetLifecyclePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(GetLifecyclePolicyError::ContainerInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLifecyclePolicyError::ContainerInUse,
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(GetLifecyclePolicyError::ContainerNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetLifecyclePolicyError::ContainerNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetLifecyclePolicyError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetLifecyclePolicyError::InternalServerError,
+                    )
                 }
                 "PolicyNotFoundException" => {
-                    return RusotoError::Service(GetLifecyclePolicyError::PolicyNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLifecyclePolicyError::PolicyNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -962,18 +1111,38 @@ impl GetMetricPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(GetMetricPolicyError::ContainerInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMetricPolicyError::ContainerInUse,
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(GetMetricPolicyError::ContainerNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMetricPolicyError::ContainerNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetMetricPolicyError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMetricPolicyError::InternalServerError,
+                    )
                 }
                 "PolicyNotFoundException" => {
-                    return RusotoError::Service(GetMetricPolicyError::PolicyNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetMetricPolicyError::PolicyNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1004,9 +1173,14 @@ impl ListContainersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServerError" => {
-                    return RusotoError::Service(ListContainersError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListContainersError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1038,19 +1212,30 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ContainerInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::ContainerInUse,
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ContainerNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ContainerNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1084,19 +1269,30 @@ impl PutContainerPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(PutContainerPolicyError::ContainerInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutContainerPolicyError::ContainerInUse,
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(PutContainerPolicyError::ContainerNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutContainerPolicyError::ContainerNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(PutContainerPolicyError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutContainerPolicyError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1130,15 +1326,30 @@ impl PutCorsPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(PutCorsPolicyError::ContainerInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutCorsPolicyError::ContainerInUse,
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(PutCorsPolicyError::ContainerNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutCorsPolicyError::ContainerNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(PutCorsPolicyError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutCorsPolicyError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1172,19 +1383,30 @@ impl PutLifecyclePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(PutLifecyclePolicyError::ContainerInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutLifecyclePolicyError::ContainerInUse,
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(PutLifecyclePolicyError::ContainerNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutLifecyclePolicyError::ContainerNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(PutLifecyclePolicyError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutLifecyclePolicyError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1218,15 +1440,30 @@ impl PutMetricPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(PutMetricPolicyError::ContainerInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutMetricPolicyError::ContainerInUse,
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(PutMetricPolicyError::ContainerNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutMetricPolicyError::ContainerNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(PutMetricPolicyError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutMetricPolicyError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1260,19 +1497,30 @@ impl StartAccessLoggingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(StartAccessLoggingError::ContainerInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartAccessLoggingError::ContainerInUse,
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(StartAccessLoggingError::ContainerNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartAccessLoggingError::ContainerNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StartAccessLoggingError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartAccessLoggingError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1306,17 +1554,30 @@ impl StopAccessLoggingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(StopAccessLoggingError::ContainerInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopAccessLoggingError::ContainerInUse,
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(StopAccessLoggingError::ContainerNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopAccessLoggingError::ContainerNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StopAccessLoggingError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopAccessLoggingError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1350,15 +1611,30 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(TagResourceError::ContainerInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ContainerInUse,
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ContainerNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ContainerNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(TagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1392,15 +1668,30 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ContainerInUseException" => {
-                    return RusotoError::Service(UntagResourceError::ContainerInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ContainerInUse,
+                    )
                 }
                 "ContainerNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ContainerNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ContainerNotFound,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(UntagResourceError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InternalServerError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1557,11 +1848,12 @@ pub struct MediaStoreClient {
 impl MediaStoreClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> MediaStoreClient {
         MediaStoreClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "MediaStore"),
         }
     }
 
@@ -1576,12 +1868,32 @@ impl MediaStoreClient {
     {
         MediaStoreClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "MediaStore"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> MediaStoreClient {
-        MediaStoreClient { client, region }
+        MediaStoreClient {
+            client,
+            region: region_for_service(region, "MediaStore"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> MediaStoreClient {
+        MediaStoreClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("MediaStore"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> MediaStoreClient {
+        MediaStoreClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -1593,6 +1905,7 @@ impl MediaStore for MediaStoreClient {
         input: CreateContainerInput,
     ) -> Result<CreateContainerOutput, RusotoError<CreateContainerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateContainer");
         request.add_header("x-amz-target", "MediaStore_20170901.CreateContainer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1611,6 +1924,7 @@ impl MediaStore for MediaStoreClient {
         input: DeleteContainerInput,
     ) -> Result<DeleteContainerOutput, RusotoError<DeleteContainerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteContainer");
         request.add_header("x-amz-target", "MediaStore_20170901.DeleteContainer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1629,6 +1943,7 @@ impl MediaStore for MediaStoreClient {
         input: DeleteContainerPolicyInput,
     ) -> Result<DeleteContainerPolicyOutput, RusotoError<DeleteContainerPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteContainerPolicy");
         request.add_header("x-amz-target", "MediaStore_20170901.DeleteContainerPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1647,6 +1962,7 @@ impl MediaStore for MediaStoreClient {
         input: DeleteCorsPolicyInput,
     ) -> Result<DeleteCorsPolicyOutput, RusotoError<DeleteCorsPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteCorsPolicy");
         request.add_header("x-amz-target", "MediaStore_20170901.DeleteCorsPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1665,6 +1981,7 @@ impl MediaStore for MediaStoreClient {
         input: DeleteLifecyclePolicyInput,
     ) -> Result<DeleteLifecyclePolicyOutput, RusotoError<DeleteLifecyclePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteLifecyclePolicy");
         request.add_header("x-amz-target", "MediaStore_20170901.DeleteLifecyclePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1683,6 +2000,7 @@ impl MediaStore for MediaStoreClient {
         input: DeleteMetricPolicyInput,
     ) -> Result<DeleteMetricPolicyOutput, RusotoError<DeleteMetricPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteMetricPolicy");
         request.add_header("x-amz-target", "MediaStore_20170901.DeleteMetricPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1701,6 +2019,7 @@ impl MediaStore for MediaStoreClient {
         input: DescribeContainerInput,
     ) -> Result<DescribeContainerOutput, RusotoError<DescribeContainerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeContainer");
         request.add_header("x-amz-target", "MediaStore_20170901.DescribeContainer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1719,6 +2038,7 @@ impl MediaStore for MediaStoreClient {
         input: GetContainerPolicyInput,
     ) -> Result<GetContainerPolicyOutput, RusotoError<GetContainerPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetContainerPolicy");
         request.add_header("x-amz-target", "MediaStore_20170901.GetContainerPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1737,6 +2057,7 @@ impl MediaStore for MediaStoreClient {
         input: GetCorsPolicyInput,
     ) -> Result<GetCorsPolicyOutput, RusotoError<GetCorsPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetCorsPolicy");
         request.add_header("x-amz-target", "MediaStore_20170901.GetCorsPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1755,6 +2076,7 @@ impl MediaStore for MediaStoreClient {
         input: GetLifecyclePolicyInput,
     ) -> Result<GetLifecyclePolicyOutput, RusotoError<GetLifecyclePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetLifecyclePolicy");
         request.add_header("x-amz-target", "MediaStore_20170901.GetLifecyclePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1773,6 +2095,7 @@ impl MediaStore for MediaStoreClient {
         input: GetMetricPolicyInput,
     ) -> Result<GetMetricPolicyOutput, RusotoError<GetMetricPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetMetricPolicy");
         request.add_header("x-amz-target", "MediaStore_20170901.GetMetricPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1791,6 +2114,7 @@ impl MediaStore for MediaStoreClient {
         input: ListContainersInput,
     ) -> Result<ListContainersOutput, RusotoError<ListContainersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListContainers");
         request.add_header("x-amz-target", "MediaStore_20170901.ListContainers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1809,6 +2133,7 @@ impl MediaStore for MediaStoreClient {
         input: ListTagsForResourceInput,
     ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header("x-amz-target", "MediaStore_20170901.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1827,6 +2152,7 @@ impl MediaStore for MediaStoreClient {
         input: PutContainerPolicyInput,
     ) -> Result<PutContainerPolicyOutput, RusotoError<PutContainerPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutContainerPolicy");
         request.add_header("x-amz-target", "MediaStore_20170901.PutContainerPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1845,6 +2171,7 @@ impl MediaStore for MediaStoreClient {
         input: PutCorsPolicyInput,
     ) -> Result<PutCorsPolicyOutput, RusotoError<PutCorsPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutCorsPolicy");
         request.add_header("x-amz-target", "MediaStore_20170901.PutCorsPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1863,6 +2190,7 @@ impl MediaStore for MediaStoreClient {
         input: PutLifecyclePolicyInput,
     ) -> Result<PutLifecyclePolicyOutput, RusotoError<PutLifecyclePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutLifecyclePolicy");
         request.add_header("x-amz-target", "MediaStore_20170901.PutLifecyclePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1881,6 +2209,7 @@ impl MediaStore for MediaStoreClient {
         input: PutMetricPolicyInput,
     ) -> Result<PutMetricPolicyOutput, RusotoError<PutMetricPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutMetricPolicy");
         request.add_header("x-amz-target", "MediaStore_20170901.PutMetricPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1899,6 +2228,7 @@ impl MediaStore for MediaStoreClient {
         input: StartAccessLoggingInput,
     ) -> Result<StartAccessLoggingOutput, RusotoError<StartAccessLoggingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartAccessLogging");
         request.add_header("x-amz-target", "MediaStore_20170901.StartAccessLogging");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1917,6 +2247,7 @@ impl MediaStore for MediaStoreClient {
         input: StopAccessLoggingInput,
     ) -> Result<StopAccessLoggingOutput, RusotoError<StopAccessLoggingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopAccessLogging");
         request.add_header("x-amz-target", "MediaStore_20170901.StopAccessLogging");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1935,6 +2266,7 @@ impl MediaStore for MediaStoreClient {
         input: TagResourceInput,
     ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "MediaStore_20170901.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1953,6 +2285,7 @@ impl MediaStore for MediaStoreClient {
         input: UntagResourceInput,
     ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "MediaStore_20170901.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1965,3 +2298,256 @@ impl MediaStore for MediaStoreClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourceOutput, _>()
     }
 }
+
+/// A blocking client for the MediaStore API, running the calls of a `MediaStoreClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `MediaStoreClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct MediaStoreBlockingClient {
+    client: MediaStoreClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl MediaStoreBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> MediaStoreBlockingClient {
+        MediaStoreBlockingClient::from_client(MediaStoreClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> MediaStoreBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        MediaStoreBlockingClient::from_client(MediaStoreClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> MediaStoreBlockingClient {
+        MediaStoreBlockingClient::from_client(MediaStoreClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> MediaStoreBlockingClient {
+        MediaStoreBlockingClient::from_client(MediaStoreClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: MediaStoreClient) -> MediaStoreBlockingClient {
+        MediaStoreBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> MediaStoreBlockingClient {
+        MediaStoreBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> MediaStoreBlockingClient {
+        MediaStoreBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`MediaStore::create_container`].
+    pub fn create_container(
+        &self,
+        input: CreateContainerInput,
+    ) -> Result<CreateContainerOutput, RusotoError<CreateContainerError>> {
+        self.runtime.block_on(self.client.create_container(input))
+    }
+
+    /// Blocking version of [`MediaStore::delete_container`].
+    pub fn delete_container(
+        &self,
+        input: DeleteContainerInput,
+    ) -> Result<DeleteContainerOutput, RusotoError<DeleteContainerError>> {
+        self.runtime.block_on(self.client.delete_container(input))
+    }
+
+    /// Blocking version of [`MediaStore::delete_container_policy`].
+    pub fn delete_container_policy(
+        &self,
+        input: DeleteContainerPolicyInput,
+    ) -> Result<DeleteContainerPolicyOutput, RusotoError<DeleteContainerPolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_container_policy(input))
+    }
+
+    /// Blocking version of [`MediaStore::delete_cors_policy`].
+    pub fn delete_cors_policy(
+        &self,
+        input: DeleteCorsPolicyInput,
+    ) -> Result<DeleteCorsPolicyOutput, RusotoError<DeleteCorsPolicyError>> {
+        self.runtime.block_on(self.client.delete_cors_policy(input))
+    }
+
+    /// Blocking version of [`MediaStore::delete_lifecycle_policy`].
+    pub fn delete_lifecycle_policy(
+        &self,
+        input: DeleteLifecyclePolicyInput,
+    ) -> Result<DeleteLifecyclePolicyOutput, RusotoError<DeleteLifecyclePolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_lifecycle_policy(input))
+    }
+
+    /// Blocking version of [`MediaStore::delete_metric_policy`].
+    pub fn delete_metric_policy(
+        &self,
+        input: DeleteMetricPolicyInput,
+    ) -> Result<DeleteMetricPolicyOutput, RusotoError<DeleteMetricPolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_metric_policy(input))
+    }
+
+    /// Blocking version of [`MediaStore::describe_container`].
+    pub fn describe_container(
+        &self,
+        input: DescribeContainerInput,
+    ) -> Result<DescribeContainerOutput, RusotoError<DescribeContainerError>> {
+        self.runtime.block_on(self.client.describe_container(input))
+    }
+
+    /// Blocking version of [`MediaStore::get_container_policy`].
+    pub fn get_container_policy(
+        &self,
+        input: GetContainerPolicyInput,
+    ) -> Result<GetContainerPolicyOutput, RusotoError<GetContainerPolicyError>> {
+        self.runtime
+            .block_on(self.client.get_container_policy(input))
+    }
+
+    /// Blocking version of [`MediaStore::get_cors_policy`].
+    pub fn get_cors_policy(
+        &self,
+        input: GetCorsPolicyInput,
+    ) -> Result<GetCorsPolicyOutput, RusotoError<GetCorsPolicyError>> {
+        self.runtime.block_on(self.client.get_cors_policy(input))
+    }
+
+    /// Blocking version of [`MediaStore::get_lifecycle_policy`].
+    pub fn get_lifecycle_policy(
+        &self,
+        input: GetLifecyclePolicyInput,
+    ) -> Result<GetLifecyclePolicyOutput, RusotoError<GetLifecyclePolicyError>> {
+        self.runtime
+            .block_on(self.client.get_lifecycle_policy(input))
+    }
+
+    /// Blocking version of [`MediaStore::get_metric_policy`].
+    pub fn get_metric_policy(
+        &self,
+        input: GetMetricPolicyInput,
+    ) -> Result<GetMetricPolicyOutput, RusotoError<GetMetricPolicyError>> {
+        self.runtime.block_on(self.client.get_metric_policy(input))
+    }
+
+    /// Blocking version of [`MediaStore::list_containers`].
+    pub fn list_containers(
+        &self,
+        input: ListContainersInput,
+    ) -> Result<ListContainersOutput, RusotoError<ListContainersError>> {
+        self.runtime.block_on(self.client.list_containers(input))
+    }
+
+    /// Blocking version of [`MediaStore::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceInput,
+    ) -> Result<ListTagsForResourceOutput, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`MediaStore::put_container_policy`].
+    pub fn put_container_policy(
+        &self,
+        input: PutContainerPolicyInput,
+    ) -> Result<PutContainerPolicyOutput, RusotoError<PutContainerPolicyError>> {
+        self.runtime
+            .block_on(self.client.put_container_policy(input))
+    }
+
+    /// Blocking version of [`MediaStore::put_cors_policy`].
+    pub fn put_cors_policy(
+        &self,
+        input: PutCorsPolicyInput,
+    ) -> Result<PutCorsPolicyOutput, RusotoError<PutCorsPolicyError>> {
+        self.runtime.block_on(self.client.put_cors_policy(input))
+    }
+
+    /// Blocking version of [`MediaStore::put_lifecycle_policy`].
+    pub fn put_lifecycle_policy(
+        &self,
+        input: PutLifecyclePolicyInput,
+    ) -> Result<PutLifecyclePolicyOutput, RusotoError<PutLifecyclePolicyError>> {
+        self.runtime
+            .block_on(self.client.put_lifecycle_policy(input))
+    }
+
+    /// Blocking version of [`MediaStore::put_metric_policy`].
+    pub fn put_metric_policy(
+        &self,
+        input: PutMetricPolicyInput,
+    ) -> Result<PutMetricPolicyOutput, RusotoError<PutMetricPolicyError>> {
+        self.runtime.block_on(self.client.put_metric_policy(input))
+    }
+
+    /// Blocking version of [`MediaStore::start_access_logging`].
+    pub fn start_access_logging(
+        &self,
+        input: StartAccessLoggingInput,
+    ) -> Result<StartAccessLoggingOutput, RusotoError<StartAccessLoggingError>> {
+        self.runtime
+            .block_on(self.client.start_access_logging(input))
+    }
+
+    /// Blocking version of [`MediaStore::stop_access_logging`].
+    pub fn stop_access_logging(
+        &self,
+        input: StopAccessLoggingInput,
+    ) -> Result<StopAccessLoggingOutput, RusotoError<StopAccessLoggingError>> {
+        self.runtime
+            .block_on(self.client.stop_access_logging(input))
+    }
+
+    /// Blocking version of [`MediaStore::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceInput,
+    ) -> Result<TagResourceOutput, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`MediaStore::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceInput,
+    ) -> Result<UntagResourceOutput, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -255,43 +256,78 @@ impl BatchMeterUsageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DisabledApiException" => {
-                    return RusotoError::Service(BatchMeterUsageError::DisabledApi(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchMeterUsageError::DisabledApi,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(BatchMeterUsageError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchMeterUsageError::InternalServiceError,
+                    )
                 }
                 "InvalidCustomerIdentifierException" => {
-                    return RusotoError::Service(BatchMeterUsageError::InvalidCustomerIdentifier(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchMeterUsageError::InvalidCustomerIdentifier,
+                    )
                 }
                 "InvalidProductCodeException" => {
-                    return RusotoError::Service(BatchMeterUsageError::InvalidProductCode(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchMeterUsageError::InvalidProductCode,
+                    )
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(BatchMeterUsageError::InvalidTag(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchMeterUsageError::InvalidTag,
+                    )
                 }
                 "InvalidUsageAllocationsException" => {
-                    return RusotoError::Service(BatchMeterUsageError::InvalidUsageAllocations(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchMeterUsageError::InvalidUsageAllocations,
+                    )
                 }
                 "InvalidUsageDimensionException" => {
-                    return RusotoError::Service(BatchMeterUsageError::InvalidUsageDimension(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchMeterUsageError::InvalidUsageDimension,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(BatchMeterUsageError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchMeterUsageError::Throttling,
+                    )
                 }
                 "TimestampOutOfBoundsException" => {
-                    return RusotoError::Service(BatchMeterUsageError::TimestampOutOfBounds(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchMeterUsageError::TimestampOutOfBounds,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -345,36 +381,86 @@ impl MeterUsageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CustomerNotEntitledException" => {
-                    return RusotoError::Service(MeterUsageError::CustomerNotEntitled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MeterUsageError::CustomerNotEntitled,
+                    )
                 }
                 "DuplicateRequestException" => {
-                    return RusotoError::Service(MeterUsageError::DuplicateRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MeterUsageError::DuplicateRequest,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(MeterUsageError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MeterUsageError::InternalServiceError,
+                    )
                 }
                 "InvalidEndpointRegionException" => {
-                    return RusotoError::Service(MeterUsageError::InvalidEndpointRegion(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MeterUsageError::InvalidEndpointRegion,
+                    )
                 }
                 "InvalidProductCodeException" => {
-                    return RusotoError::Service(MeterUsageError::InvalidProductCode(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MeterUsageError::InvalidProductCode,
+                    )
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(MeterUsageError::InvalidTag(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MeterUsageError::InvalidTag,
+                    )
                 }
                 "InvalidUsageAllocationsException" => {
-                    return RusotoError::Service(MeterUsageError::InvalidUsageAllocations(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MeterUsageError::InvalidUsageAllocations,
+                    )
                 }
                 "InvalidUsageDimensionException" => {
-                    return RusotoError::Service(MeterUsageError::InvalidUsageDimension(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MeterUsageError::InvalidUsageDimension,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(MeterUsageError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MeterUsageError::Throttling,
+                    )
                 }
                 "TimestampOutOfBoundsException" => {
-                    return RusotoError::Service(MeterUsageError::TimestampOutOfBounds(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MeterUsageError::TimestampOutOfBounds,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -425,32 +511,70 @@ impl RegisterUsageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "CustomerNotEntitledException" => {
-                    return RusotoError::Service(RegisterUsageError::CustomerNotEntitled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterUsageError::CustomerNotEntitled,
+                    )
                 }
                 "DisabledApiException" => {
-                    return RusotoError::Service(RegisterUsageError::DisabledApi(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterUsageError::DisabledApi,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(RegisterUsageError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterUsageError::InternalServiceError,
+                    )
                 }
                 "InvalidProductCodeException" => {
-                    return RusotoError::Service(RegisterUsageError::InvalidProductCode(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterUsageError::InvalidProductCode,
+                    )
                 }
                 "InvalidPublicKeyVersionException" => {
-                    return RusotoError::Service(RegisterUsageError::InvalidPublicKeyVersion(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterUsageError::InvalidPublicKeyVersion,
+                    )
                 }
                 "InvalidRegionException" => {
-                    return RusotoError::Service(RegisterUsageError::InvalidRegion(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterUsageError::InvalidRegion,
+                    )
                 }
                 "PlatformNotSupportedException" => {
-                    return RusotoError::Service(RegisterUsageError::PlatformNotSupported(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterUsageError::PlatformNotSupported,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RegisterUsageError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterUsageError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -493,23 +617,46 @@ impl ResolveCustomerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DisabledApiException" => {
-                    return RusotoError::Service(ResolveCustomerError::DisabledApi(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResolveCustomerError::DisabledApi,
+                    )
                 }
                 "ExpiredTokenException" => {
-                    return RusotoError::Service(ResolveCustomerError::ExpiredToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResolveCustomerError::ExpiredToken,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ResolveCustomerError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ResolveCustomerError::InternalServiceError,
+                    )
                 }
                 "InvalidTokenException" => {
-                    return RusotoError::Service(ResolveCustomerError::InvalidToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResolveCustomerError::InvalidToken,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ResolveCustomerError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResolveCustomerError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -566,11 +713,12 @@ pub struct MarketplaceMeteringClient {
 impl MarketplaceMeteringClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> MarketplaceMeteringClient {
         MarketplaceMeteringClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Marketplace Metering"),
         }
     }
 
@@ -585,12 +733,32 @@ impl MarketplaceMeteringClient {
     {
         MarketplaceMeteringClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Marketplace Metering"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> MarketplaceMeteringClient {
-        MarketplaceMeteringClient { client, region }
+        MarketplaceMeteringClient {
+            client,
+            region: region_for_service(region, "Marketplace Metering"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> MarketplaceMeteringClient {
+        MarketplaceMeteringClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Marketplace Metering"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> MarketplaceMeteringClient {
+        MarketplaceMeteringClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -602,6 +770,7 @@ impl MarketplaceMetering for MarketplaceMeteringClient {
         input: BatchMeterUsageRequest,
     ) -> Result<BatchMeterUsageResult, RusotoError<BatchMeterUsageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("BatchMeterUsage");
         request.add_header("x-amz-target", "AWSMPMeteringService.BatchMeterUsage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -620,6 +789,7 @@ impl MarketplaceMetering for MarketplaceMeteringClient {
         input: MeterUsageRequest,
     ) -> Result<MeterUsageResult, RusotoError<MeterUsageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("MeterUsage");
         request.add_header("x-amz-target", "AWSMPMeteringService.MeterUsage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -638,6 +808,7 @@ impl MarketplaceMetering for MarketplaceMeteringClient {
         input: RegisterUsageRequest,
     ) -> Result<RegisterUsageResult, RusotoError<RegisterUsageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RegisterUsage");
         request.add_header("x-amz-target", "AWSMPMeteringService.RegisterUsage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -656,6 +827,7 @@ impl MarketplaceMetering for MarketplaceMeteringClient {
         input: ResolveCustomerRequest,
     ) -> Result<ResolveCustomerResult, RusotoError<ResolveCustomerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ResolveCustomer");
         request.add_header("x-amz-target", "AWSMPMeteringService.ResolveCustomer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -668,3 +840,120 @@ impl MarketplaceMetering for MarketplaceMeteringClient {
         proto::json::ResponsePayload::new(&response).deserialize::<ResolveCustomerResult, _>()
     }
 }
+
+/// A blocking client for the AWSMarketplace Metering API, running the calls of a `MarketplaceMeteringClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `MarketplaceMeteringClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct MarketplaceMeteringBlockingClient {
+    client: MarketplaceMeteringClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl MarketplaceMeteringBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> MarketplaceMeteringBlockingClient {
+        MarketplaceMeteringBlockingClient::from_client(MarketplaceMeteringClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> MarketplaceMeteringBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        MarketplaceMeteringBlockingClient::from_client(MarketplaceMeteringClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> MarketplaceMeteringBlockingClient {
+        MarketplaceMeteringBlockingClient::from_client(MarketplaceMeteringClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> MarketplaceMeteringBlockingClient {
+        MarketplaceMeteringBlockingClient::from_client(MarketplaceMeteringClient::new_with_config(
+            config,
+        ))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: MarketplaceMeteringClient) -> MarketplaceMeteringBlockingClient {
+        MarketplaceMeteringBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> MarketplaceMeteringBlockingClient {
+        MarketplaceMeteringBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> MarketplaceMeteringBlockingClient {
+        MarketplaceMeteringBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`MarketplaceMetering::batch_meter_usage`].
+    pub fn batch_meter_usage(
+        &self,
+        input: BatchMeterUsageRequest,
+    ) -> Result<BatchMeterUsageResult, RusotoError<BatchMeterUsageError>> {
+        self.runtime.block_on(self.client.batch_meter_usage(input))
+    }
+
+    /// Blocking version of [`MarketplaceMetering::meter_usage`].
+    pub fn meter_usage(
+        &self,
+        input: MeterUsageRequest,
+    ) -> Result<MeterUsageResult, RusotoError<MeterUsageError>> {
+        self.runtime.block_on(self.client.meter_usage(input))
+    }
+
+    /// Blocking version of [`MarketplaceMetering::register_usage`].
+    pub fn register_usage(
+        &self,
+        input: RegisterUsageRequest,
+    ) -> Result<RegisterUsageResult, RusotoError<RegisterUsageError>> {
+        self.runtime.block_on(self.client.register_usage(input))
+    }
+
+    /// Blocking version of [`MarketplaceMetering::resolve_customer`].
+    pub fn resolve_customer(
+        &self,
+        input: ResolveCustomerRequest,
+    ) -> Result<ResolveCustomerResult, RusotoError<ResolveCustomerError>> {
+        self.runtime.block_on(self.client.resolve_customer(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -616,49 +617,78 @@ impl AssociateCreatedArtifactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AssociateCreatedArtifactError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateCreatedArtifactError::AccessDenied,
+                    )
                 }
                 "DryRunOperation" => {
-                    return RusotoError::Service(AssociateCreatedArtifactError::DryRunOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateCreatedArtifactError::DryRunOperation,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(AssociateCreatedArtifactError::HomeRegionNotSet(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateCreatedArtifactError::HomeRegionNotSet,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        AssociateCreatedArtifactError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateCreatedArtifactError::InternalServerError,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AssociateCreatedArtifactError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateCreatedArtifactError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateCreatedArtifactError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateCreatedArtifactError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(AssociateCreatedArtifactError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateCreatedArtifactError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AssociateCreatedArtifactError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateCreatedArtifactError::Throttling,
+                    )
                 }
                 "UnauthorizedOperation" => {
-                    return RusotoError::Service(
-                        AssociateCreatedArtifactError::UnauthorizedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateCreatedArtifactError::UnauthorizedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -716,56 +746,86 @@ impl AssociateDiscoveredResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AssociateDiscoveredResourceError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateDiscoveredResourceError::AccessDenied,
+                    )
                 }
                 "DryRunOperation" => {
-                    return RusotoError::Service(AssociateDiscoveredResourceError::DryRunOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateDiscoveredResourceError::DryRunOperation,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(
-                        AssociateDiscoveredResourceError::HomeRegionNotSet(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateDiscoveredResourceError::HomeRegionNotSet,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        AssociateDiscoveredResourceError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateDiscoveredResourceError::InternalServerError,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AssociateDiscoveredResourceError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateDiscoveredResourceError::InvalidInput,
+                    )
                 }
                 "PolicyErrorException" => {
-                    return RusotoError::Service(AssociateDiscoveredResourceError::PolicyError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateDiscoveredResourceError::PolicyError,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        AssociateDiscoveredResourceError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateDiscoveredResourceError::ResourceNotFound,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        AssociateDiscoveredResourceError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateDiscoveredResourceError::ServiceUnavailable,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AssociateDiscoveredResourceError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateDiscoveredResourceError::Throttling,
+                    )
                 }
                 "UnauthorizedOperation" => {
-                    return RusotoError::Service(
-                        AssociateDiscoveredResourceError::UnauthorizedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateDiscoveredResourceError::UnauthorizedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -824,46 +884,70 @@ impl CreateProgressUpdateStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateProgressUpdateStreamError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateProgressUpdateStreamError::AccessDenied,
+                    )
                 }
                 "DryRunOperation" => {
-                    return RusotoError::Service(CreateProgressUpdateStreamError::DryRunOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateProgressUpdateStreamError::DryRunOperation,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(CreateProgressUpdateStreamError::HomeRegionNotSet(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateProgressUpdateStreamError::HomeRegionNotSet,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        CreateProgressUpdateStreamError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProgressUpdateStreamError::InternalServerError,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateProgressUpdateStreamError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateProgressUpdateStreamError::InvalidInput,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        CreateProgressUpdateStreamError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProgressUpdateStreamError::ServiceUnavailable,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateProgressUpdateStreamError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateProgressUpdateStreamError::Throttling,
+                    )
                 }
                 "UnauthorizedOperation" => {
-                    return RusotoError::Service(
-                        CreateProgressUpdateStreamError::UnauthorizedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProgressUpdateStreamError::UnauthorizedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -922,51 +1006,78 @@ impl DeleteProgressUpdateStreamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteProgressUpdateStreamError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteProgressUpdateStreamError::AccessDenied,
+                    )
                 }
                 "DryRunOperation" => {
-                    return RusotoError::Service(DeleteProgressUpdateStreamError::DryRunOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteProgressUpdateStreamError::DryRunOperation,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(DeleteProgressUpdateStreamError::HomeRegionNotSet(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteProgressUpdateStreamError::HomeRegionNotSet,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DeleteProgressUpdateStreamError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProgressUpdateStreamError::InternalServerError,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteProgressUpdateStreamError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteProgressUpdateStreamError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteProgressUpdateStreamError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteProgressUpdateStreamError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DeleteProgressUpdateStreamError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProgressUpdateStreamError::ServiceUnavailable,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteProgressUpdateStreamError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteProgressUpdateStreamError::Throttling,
+                    )
                 }
                 "UnauthorizedOperation" => {
-                    return RusotoError::Service(
-                        DeleteProgressUpdateStreamError::UnauthorizedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProgressUpdateStreamError::UnauthorizedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1022,44 +1133,70 @@ impl DescribeApplicationStateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeApplicationStateError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeApplicationStateError::AccessDenied,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(DescribeApplicationStateError::HomeRegionNotSet(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeApplicationStateError::HomeRegionNotSet,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeApplicationStateError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeApplicationStateError::InternalServerError,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeApplicationStateError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeApplicationStateError::InvalidInput,
+                    )
                 }
                 "PolicyErrorException" => {
-                    return RusotoError::Service(DescribeApplicationStateError::PolicyError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeApplicationStateError::PolicyError,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeApplicationStateError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeApplicationStateError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeApplicationStateError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeApplicationStateError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeApplicationStateError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeApplicationStateError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1106,35 +1243,62 @@ impl DescribeMigrationTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeMigrationTaskError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMigrationTaskError::AccessDenied,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(DescribeMigrationTaskError::HomeRegionNotSet(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeMigrationTaskError::HomeRegionNotSet,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeMigrationTaskError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeMigrationTaskError::InternalServerError,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeMigrationTaskError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMigrationTaskError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeMigrationTaskError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeMigrationTaskError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DescribeMigrationTaskError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeMigrationTaskError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeMigrationTaskError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeMigrationTaskError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1186,51 +1350,78 @@ impl DisassociateCreatedArtifactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DisassociateCreatedArtifactError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateCreatedArtifactError::AccessDenied,
+                    )
                 }
                 "DryRunOperation" => {
-                    return RusotoError::Service(DisassociateCreatedArtifactError::DryRunOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateCreatedArtifactError::DryRunOperation,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(
-                        DisassociateCreatedArtifactError::HomeRegionNotSet(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateCreatedArtifactError::HomeRegionNotSet,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DisassociateCreatedArtifactError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateCreatedArtifactError::InternalServerError,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DisassociateCreatedArtifactError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateCreatedArtifactError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DisassociateCreatedArtifactError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateCreatedArtifactError::ResourceNotFound,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DisassociateCreatedArtifactError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateCreatedArtifactError::ServiceUnavailable,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DisassociateCreatedArtifactError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateCreatedArtifactError::Throttling,
+                    )
                 }
                 "UnauthorizedOperation" => {
-                    return RusotoError::Service(
-                        DisassociateCreatedArtifactError::UnauthorizedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateCreatedArtifactError::UnauthorizedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1290,51 +1481,78 @@ impl DisassociateDiscoveredResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DisassociateDiscoveredResourceError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateDiscoveredResourceError::AccessDenied,
+                    )
                 }
                 "DryRunOperation" => {
-                    return RusotoError::Service(
-                        DisassociateDiscoveredResourceError::DryRunOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateDiscoveredResourceError::DryRunOperation,
                     )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(
-                        DisassociateDiscoveredResourceError::HomeRegionNotSet(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateDiscoveredResourceError::HomeRegionNotSet,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DisassociateDiscoveredResourceError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateDiscoveredResourceError::InternalServerError,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DisassociateDiscoveredResourceError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateDiscoveredResourceError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DisassociateDiscoveredResourceError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateDiscoveredResourceError::ResourceNotFound,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DisassociateDiscoveredResourceError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateDiscoveredResourceError::ServiceUnavailable,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DisassociateDiscoveredResourceError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateDiscoveredResourceError::Throttling,
+                    )
                 }
                 "UnauthorizedOperation" => {
-                    return RusotoError::Service(
-                        DisassociateDiscoveredResourceError::UnauthorizedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateDiscoveredResourceError::UnauthorizedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1398,43 +1616,78 @@ impl ImportMigrationTaskError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ImportMigrationTaskError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportMigrationTaskError::AccessDenied,
+                    )
                 }
                 "DryRunOperation" => {
-                    return RusotoError::Service(ImportMigrationTaskError::DryRunOperation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportMigrationTaskError::DryRunOperation,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(ImportMigrationTaskError::HomeRegionNotSet(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ImportMigrationTaskError::HomeRegionNotSet,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ImportMigrationTaskError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ImportMigrationTaskError::InternalServerError,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ImportMigrationTaskError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportMigrationTaskError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ImportMigrationTaskError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ImportMigrationTaskError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ImportMigrationTaskError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ImportMigrationTaskError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ImportMigrationTaskError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ImportMigrationTaskError::Throttling,
+                    )
                 }
                 "UnauthorizedOperation" => {
-                    return RusotoError::Service(ImportMigrationTaskError::UnauthorizedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ImportMigrationTaskError::UnauthorizedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1480,30 +1733,54 @@ impl ListApplicationStatesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListApplicationStatesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListApplicationStatesError::AccessDenied,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(ListApplicationStatesError::HomeRegionNotSet(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListApplicationStatesError::HomeRegionNotSet,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ListApplicationStatesError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListApplicationStatesError::InternalServerError,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListApplicationStatesError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListApplicationStatesError::InvalidInput,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListApplicationStatesError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListApplicationStatesError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListApplicationStatesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListApplicationStatesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1548,35 +1825,62 @@ impl ListCreatedArtifactsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListCreatedArtifactsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCreatedArtifactsError::AccessDenied,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(ListCreatedArtifactsError::HomeRegionNotSet(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListCreatedArtifactsError::HomeRegionNotSet,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ListCreatedArtifactsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListCreatedArtifactsError::InternalServerError,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListCreatedArtifactsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCreatedArtifactsError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListCreatedArtifactsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListCreatedArtifactsError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListCreatedArtifactsError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListCreatedArtifactsError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListCreatedArtifactsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCreatedArtifactsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1622,39 +1926,62 @@ impl ListDiscoveredResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListDiscoveredResourcesError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDiscoveredResourcesError::AccessDenied,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(ListDiscoveredResourcesError::HomeRegionNotSet(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDiscoveredResourcesError::HomeRegionNotSet,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ListDiscoveredResourcesError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDiscoveredResourcesError::InternalServerError,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListDiscoveredResourcesError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDiscoveredResourcesError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDiscoveredResourcesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDiscoveredResourcesError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListDiscoveredResourcesError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDiscoveredResourcesError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDiscoveredResourcesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDiscoveredResourcesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1702,34 +2029,70 @@ impl ListMigrationTasksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListMigrationTasksError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMigrationTasksError::AccessDenied,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(ListMigrationTasksError::HomeRegionNotSet(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMigrationTasksError::HomeRegionNotSet,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ListMigrationTasksError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListMigrationTasksError::InternalServerError,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListMigrationTasksError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMigrationTasksError::InvalidInput,
+                    )
                 }
                 "PolicyErrorException" => {
-                    return RusotoError::Service(ListMigrationTasksError::PolicyError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMigrationTasksError::PolicyError,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListMigrationTasksError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMigrationTasksError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListMigrationTasksError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListMigrationTasksError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListMigrationTasksError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListMigrationTasksError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1774,36 +2137,54 @@ impl ListProgressUpdateStreamsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListProgressUpdateStreamsError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListProgressUpdateStreamsError::AccessDenied,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(ListProgressUpdateStreamsError::HomeRegionNotSet(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListProgressUpdateStreamsError::HomeRegionNotSet,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        ListProgressUpdateStreamsError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProgressUpdateStreamsError::InternalServerError,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListProgressUpdateStreamsError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListProgressUpdateStreamsError::InvalidInput,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        ListProgressUpdateStreamsError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListProgressUpdateStreamsError::ServiceUnavailable,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListProgressUpdateStreamsError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListProgressUpdateStreamsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1856,48 +2237,86 @@ impl NotifyApplicationStateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(NotifyApplicationStateError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        NotifyApplicationStateError::AccessDenied,
+                    )
                 }
                 "DryRunOperation" => {
-                    return RusotoError::Service(NotifyApplicationStateError::DryRunOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        NotifyApplicationStateError::DryRunOperation,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(NotifyApplicationStateError::HomeRegionNotSet(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        NotifyApplicationStateError::HomeRegionNotSet,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(NotifyApplicationStateError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        NotifyApplicationStateError::InternalServerError,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(NotifyApplicationStateError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        NotifyApplicationStateError::InvalidInput,
+                    )
                 }
                 "PolicyErrorException" => {
-                    return RusotoError::Service(NotifyApplicationStateError::PolicyError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        NotifyApplicationStateError::PolicyError,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(NotifyApplicationStateError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        NotifyApplicationStateError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(NotifyApplicationStateError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        NotifyApplicationStateError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(NotifyApplicationStateError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        NotifyApplicationStateError::Throttling,
+                    )
                 }
                 "UnauthorizedOperation" => {
-                    return RusotoError::Service(
-                        NotifyApplicationStateError::UnauthorizedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        NotifyApplicationStateError::UnauthorizedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1950,49 +2369,78 @@ impl NotifyMigrationTaskStateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(NotifyMigrationTaskStateError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        NotifyMigrationTaskStateError::AccessDenied,
+                    )
                 }
                 "DryRunOperation" => {
-                    return RusotoError::Service(NotifyMigrationTaskStateError::DryRunOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        NotifyMigrationTaskStateError::DryRunOperation,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(NotifyMigrationTaskStateError::HomeRegionNotSet(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        NotifyMigrationTaskStateError::HomeRegionNotSet,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        NotifyMigrationTaskStateError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        NotifyMigrationTaskStateError::InternalServerError,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(NotifyMigrationTaskStateError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        NotifyMigrationTaskStateError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(NotifyMigrationTaskStateError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        NotifyMigrationTaskStateError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(NotifyMigrationTaskStateError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        NotifyMigrationTaskStateError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(NotifyMigrationTaskStateError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        NotifyMigrationTaskStateError::Throttling,
+                    )
                 }
                 "UnauthorizedOperation" => {
-                    return RusotoError::Service(
-                        NotifyMigrationTaskStateError::UnauthorizedOperation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        NotifyMigrationTaskStateError::UnauthorizedOperation,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2046,45 +2494,78 @@ impl PutResourceAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(PutResourceAttributesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutResourceAttributesError::AccessDenied,
+                    )
                 }
                 "DryRunOperation" => {
-                    return RusotoError::Service(PutResourceAttributesError::DryRunOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutResourceAttributesError::DryRunOperation,
+                    )
                 }
                 "HomeRegionNotSetException" => {
-                    return RusotoError::Service(PutResourceAttributesError::HomeRegionNotSet(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutResourceAttributesError::HomeRegionNotSet,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(PutResourceAttributesError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutResourceAttributesError::InternalServerError,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(PutResourceAttributesError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutResourceAttributesError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutResourceAttributesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutResourceAttributesError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(PutResourceAttributesError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutResourceAttributesError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(PutResourceAttributesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutResourceAttributesError::Throttling,
+                    )
                 }
                 "UnauthorizedOperation" => {
-                    return RusotoError::Service(PutResourceAttributesError::UnauthorizedOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutResourceAttributesError::UnauthorizedOperation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2226,11 +2707,12 @@ pub struct MigrationHubClient {
 impl MigrationHubClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> MigrationHubClient {
         MigrationHubClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Migration Hub"),
         }
     }
 
@@ -2245,12 +2727,32 @@ impl MigrationHubClient {
     {
         MigrationHubClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Migration Hub"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> MigrationHubClient {
-        MigrationHubClient { client, region }
+        MigrationHubClient {
+            client,
+            region: region_for_service(region, "Migration Hub"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> MigrationHubClient {
+        MigrationHubClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Migration Hub"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> MigrationHubClient {
+        MigrationHubClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -2262,6 +2764,7 @@ impl MigrationHub for MigrationHubClient {
         input: AssociateCreatedArtifactRequest,
     ) -> Result<AssociateCreatedArtifactResult, RusotoError<AssociateCreatedArtifactError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateCreatedArtifact");
         request.add_header("x-amz-target", "AWSMigrationHub.AssociateCreatedArtifact");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2282,6 +2785,7 @@ impl MigrationHub for MigrationHubClient {
     ) -> Result<AssociateDiscoveredResourceResult, RusotoError<AssociateDiscoveredResourceError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateDiscoveredResource");
         request.add_header(
             "x-amz-target",
             "AWSMigrationHub.AssociateDiscoveredResource",
@@ -2305,6 +2809,7 @@ impl MigrationHub for MigrationHubClient {
     ) -> Result<CreateProgressUpdateStreamResult, RusotoError<CreateProgressUpdateStreamError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateProgressUpdateStream");
         request.add_header("x-amz-target", "AWSMigrationHub.CreateProgressUpdateStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2325,6 +2830,7 @@ impl MigrationHub for MigrationHubClient {
     ) -> Result<DeleteProgressUpdateStreamResult, RusotoError<DeleteProgressUpdateStreamError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteProgressUpdateStream");
         request.add_header("x-amz-target", "AWSMigrationHub.DeleteProgressUpdateStream");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2344,6 +2850,7 @@ impl MigrationHub for MigrationHubClient {
         input: DescribeApplicationStateRequest,
     ) -> Result<DescribeApplicationStateResult, RusotoError<DescribeApplicationStateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeApplicationState");
         request.add_header("x-amz-target", "AWSMigrationHub.DescribeApplicationState");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2363,6 +2870,7 @@ impl MigrationHub for MigrationHubClient {
         input: DescribeMigrationTaskRequest,
     ) -> Result<DescribeMigrationTaskResult, RusotoError<DescribeMigrationTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeMigrationTask");
         request.add_header("x-amz-target", "AWSMigrationHub.DescribeMigrationTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2382,6 +2890,7 @@ impl MigrationHub for MigrationHubClient {
     ) -> Result<DisassociateCreatedArtifactResult, RusotoError<DisassociateCreatedArtifactError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisassociateCreatedArtifact");
         request.add_header(
             "x-amz-target",
             "AWSMigrationHub.DisassociateCreatedArtifact",
@@ -2407,6 +2916,7 @@ impl MigrationHub for MigrationHubClient {
         RusotoError<DisassociateDiscoveredResourceError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisassociateDiscoveredResource");
         request.add_header(
             "x-amz-target",
             "AWSMigrationHub.DisassociateDiscoveredResource",
@@ -2429,6 +2939,7 @@ impl MigrationHub for MigrationHubClient {
         input: ImportMigrationTaskRequest,
     ) -> Result<ImportMigrationTaskResult, RusotoError<ImportMigrationTaskError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ImportMigrationTask");
         request.add_header("x-amz-target", "AWSMigrationHub.ImportMigrationTask");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2447,6 +2958,7 @@ impl MigrationHub for MigrationHubClient {
         input: ListApplicationStatesRequest,
     ) -> Result<ListApplicationStatesResult, RusotoError<ListApplicationStatesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListApplicationStates");
         request.add_header("x-amz-target", "AWSMigrationHub.ListApplicationStates");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2465,6 +2977,7 @@ impl MigrationHub for MigrationHubClient {
         input: ListCreatedArtifactsRequest,
     ) -> Result<ListCreatedArtifactsResult, RusotoError<ListCreatedArtifactsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListCreatedArtifacts");
         request.add_header("x-amz-target", "AWSMigrationHub.ListCreatedArtifacts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2483,6 +2996,7 @@ impl MigrationHub for MigrationHubClient {
         input: ListDiscoveredResourcesRequest,
     ) -> Result<ListDiscoveredResourcesResult, RusotoError<ListDiscoveredResourcesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListDiscoveredResources");
         request.add_header("x-amz-target", "AWSMigrationHub.ListDiscoveredResources");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2502,6 +3016,7 @@ impl MigrationHub for MigrationHubClient {
         input: ListMigrationTasksRequest,
     ) -> Result<ListMigrationTasksResult, RusotoError<ListMigrationTasksError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListMigrationTasks");
         request.add_header("x-amz-target", "AWSMigrationHub.ListMigrationTasks");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2520,6 +3035,7 @@ impl MigrationHub for MigrationHubClient {
         input: ListProgressUpdateStreamsRequest,
     ) -> Result<ListProgressUpdateStreamsResult, RusotoError<ListProgressUpdateStreamsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListProgressUpdateStreams");
         request.add_header("x-amz-target", "AWSMigrationHub.ListProgressUpdateStreams");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2539,6 +3055,7 @@ impl MigrationHub for MigrationHubClient {
         input: NotifyApplicationStateRequest,
     ) -> Result<NotifyApplicationStateResult, RusotoError<NotifyApplicationStateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("NotifyApplicationState");
         request.add_header("x-amz-target", "AWSMigrationHub.NotifyApplicationState");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2558,6 +3075,7 @@ impl MigrationHub for MigrationHubClient {
         input: NotifyMigrationTaskStateRequest,
     ) -> Result<NotifyMigrationTaskStateResult, RusotoError<NotifyMigrationTaskStateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("NotifyMigrationTaskState");
         request.add_header("x-amz-target", "AWSMigrationHub.NotifyMigrationTaskState");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2577,6 +3095,7 @@ impl MigrationHub for MigrationHubClient {
         input: PutResourceAttributesRequest,
     ) -> Result<PutResourceAttributesResult, RusotoError<PutResourceAttributesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutResourceAttributes");
         request.add_header("x-amz-target", "AWSMigrationHub.PutResourceAttributes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2589,3 +3108,241 @@ impl MigrationHub for MigrationHubClient {
         proto::json::ResponsePayload::new(&response).deserialize::<PutResourceAttributesResult, _>()
     }
 }
+
+/// A blocking client for the AWS Migration Hub API, running the calls of a `MigrationHubClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `MigrationHubClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct MigrationHubBlockingClient {
+    client: MigrationHubClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl MigrationHubBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> MigrationHubBlockingClient {
+        MigrationHubBlockingClient::from_client(MigrationHubClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> MigrationHubBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        MigrationHubBlockingClient::from_client(MigrationHubClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> MigrationHubBlockingClient {
+        MigrationHubBlockingClient::from_client(MigrationHubClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> MigrationHubBlockingClient {
+        MigrationHubBlockingClient::from_client(MigrationHubClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: MigrationHubClient) -> MigrationHubBlockingClient {
+        MigrationHubBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> MigrationHubBlockingClient {
+        MigrationHubBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> MigrationHubBlockingClient {
+        MigrationHubBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`MigrationHub::associate_created_artifact`].
+    pub fn associate_created_artifact(
+        &self,
+        input: AssociateCreatedArtifactRequest,
+    ) -> Result<AssociateCreatedArtifactResult, RusotoError<AssociateCreatedArtifactError>> {
+        self.runtime
+            .block_on(self.client.associate_created_artifact(input))
+    }
+
+    /// Blocking version of [`MigrationHub::associate_discovered_resource`].
+    pub fn associate_discovered_resource(
+        &self,
+        input: AssociateDiscoveredResourceRequest,
+    ) -> Result<AssociateDiscoveredResourceResult, RusotoError<AssociateDiscoveredResourceError>>
+    {
+        self.runtime
+            .block_on(self.client.associate_discovered_resource(input))
+    }
+
+    /// Blocking version of [`MigrationHub::create_progress_update_stream`].
+    pub fn create_progress_update_stream(
+        &self,
+        input: CreateProgressUpdateStreamRequest,
+    ) -> Result<CreateProgressUpdateStreamResult, RusotoError<CreateProgressUpdateStreamError>>
+    {
+        self.runtime
+            .block_on(self.client.create_progress_update_stream(input))
+    }
+
+    /// Blocking version of [`MigrationHub::delete_progress_update_stream`].
+    pub fn delete_progress_update_stream(
+        &self,
+        input: DeleteProgressUpdateStreamRequest,
+    ) -> Result<DeleteProgressUpdateStreamResult, RusotoError<DeleteProgressUpdateStreamError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_progress_update_stream(input))
+    }
+
+    /// Blocking version of [`MigrationHub::describe_application_state`].
+    pub fn describe_application_state(
+        &self,
+        input: DescribeApplicationStateRequest,
+    ) -> Result<DescribeApplicationStateResult, RusotoError<DescribeApplicationStateError>> {
+        self.runtime
+            .block_on(self.client.describe_application_state(input))
+    }
+
+    /// Blocking version of [`MigrationHub::describe_migration_task`].
+    pub fn describe_migration_task(
+        &self,
+        input: DescribeMigrationTaskRequest,
+    ) -> Result<DescribeMigrationTaskResult, RusotoError<DescribeMigrationTaskError>> {
+        self.runtime
+            .block_on(self.client.describe_migration_task(input))
+    }
+
+    /// Blocking version of [`MigrationHub::disassociate_created_artifact`].
+    pub fn disassociate_created_artifact(
+        &self,
+        input: DisassociateCreatedArtifactRequest,
+    ) -> Result<DisassociateCreatedArtifactResult, RusotoError<DisassociateCreatedArtifactError>>
+    {
+        self.runtime
+            .block_on(self.client.disassociate_created_artifact(input))
+    }
+
+    /// Blocking version of [`MigrationHub::disassociate_discovered_resource`].
+    pub fn disassociate_discovered_resource(
+        &self,
+        input: DisassociateDiscoveredResourceRequest,
+    ) -> Result<
+        DisassociateDiscoveredResourceResult,
+        RusotoError<DisassociateDiscoveredResourceError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_discovered_resource(input))
+    }
+
+    /// Blocking version of [`MigrationHub::import_migration_task`].
+    pub fn import_migration_task(
+        &self,
+        input: ImportMigrationTaskRequest,
+    ) -> Result<ImportMigrationTaskResult, RusotoError<ImportMigrationTaskError>> {
+        self.runtime
+            .block_on(self.client.import_migration_task(input))
+    }
+
+    /// Blocking version of [`MigrationHub::list_application_states`].
+    pub fn list_application_states(
+        &self,
+        input: ListApplicationStatesRequest,
+    ) -> Result<ListApplicationStatesResult, RusotoError<ListApplicationStatesError>> {
+        self.runtime
+            .block_on(self.client.list_application_states(input))
+    }
+
+    /// Blocking version of [`MigrationHub::list_created_artifacts`].
+    pub fn list_created_artifacts(
+        &self,
+        input: ListCreatedArtifactsRequest,
+    ) -> Result<ListCreatedArtifactsResult, RusotoError<ListCreatedArtifactsError>> {
+        self.runtime
+            .block_on(self.client.list_created_artifacts(input))
+    }
+
+    /// Blocking version of [`MigrationHub::list_discovered_resources`].
+    pub fn list_discovered_resources(
+        &self,
+        input: ListDiscoveredResourcesRequest,
+    ) -> Result<ListDiscoveredResourcesResult, RusotoError<ListDiscoveredResourcesError>> {
+        self.runtime
+            .block_on(self.client.list_discovered_resources(input))
+    }
+
+    /// Blocking version of [`MigrationHub::list_migration_tasks`].
+    pub fn list_migration_tasks(
+        &self,
+        input: ListMigrationTasksRequest,
+    ) -> Result<ListMigrationTasksResult, RusotoError<ListMigrationTasksError>> {
+        self.runtime
+            .block_on(self.client.list_migration_tasks(input))
+    }
+
+    /// Blocking version of [`MigrationHub::list_progress_update_streams`].
+    pub fn list_progress_update_streams(
+        &self,
+        input: ListProgressUpdateStreamsRequest,
+    ) -> Result<ListProgressUpdateStreamsResult, RusotoError<ListProgressUpdateStreamsError>> {
+        self.runtime
+            .block_on(self.client.list_progress_update_streams(input))
+    }
+
+    /// Blocking version of [`MigrationHub::notify_application_state`].
+    pub fn notify_application_state(
+        &self,
+        input: NotifyApplicationStateRequest,
+    ) -> Result<NotifyApplicationStateResult, RusotoError<NotifyApplicationStateError>> {
+        self.runtime
+            .block_on(self.client.notify_application_state(input))
+    }
+
+    /// Blocking version of [`MigrationHub::notify_migration_task_state`].
+    pub fn notify_migration_task_state(
+        &self,
+        input: NotifyMigrationTaskStateRequest,
+    ) -> Result<NotifyMigrationTaskStateResult, RusotoError<NotifyMigrationTaskStateError>> {
+        self.runtime
+            .block_on(self.client.notify_migration_task_state(input))
+    }
+
+    /// Blocking version of [`MigrationHub::put_resource_attributes`].
+    pub fn put_resource_attributes(
+        &self,
+        input: PutResourceAttributesRequest,
+    ) -> Result<PutResourceAttributesResult, RusotoError<PutResourceAttributesError>> {
+        self.runtime
+            .block_on(self.client.put_resource_attributes(input))
+    }
+}
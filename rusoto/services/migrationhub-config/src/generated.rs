@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -182,34 +183,54 @@ impl CreateHomeRegionControlError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateHomeRegionControlError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateHomeRegionControlError::AccessDenied,
+                    )
                 }
                 "DryRunOperation" => {
-                    return RusotoError::Service(CreateHomeRegionControlError::DryRunOperation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateHomeRegionControlError::DryRunOperation,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateHomeRegionControlError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateHomeRegionControlError::InternalServerError,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateHomeRegionControlError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateHomeRegionControlError::InvalidInput,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateHomeRegionControlError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateHomeRegionControlError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateHomeRegionControlError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateHomeRegionControlError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -252,31 +273,46 @@ impl DescribeHomeRegionControlsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeHomeRegionControlsError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeHomeRegionControlsError::AccessDenied,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DescribeHomeRegionControlsError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeHomeRegionControlsError::InternalServerError,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeHomeRegionControlsError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeHomeRegionControlsError::InvalidInput,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DescribeHomeRegionControlsError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeHomeRegionControlsError::ServiceUnavailable,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeHomeRegionControlsError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeHomeRegionControlsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -320,21 +356,46 @@ impl GetHomeRegionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetHomeRegionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetHomeRegionError::AccessDenied,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetHomeRegionError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetHomeRegionError::InternalServerError,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetHomeRegionError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetHomeRegionError::InvalidInput,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetHomeRegionError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetHomeRegionError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetHomeRegionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetHomeRegionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -383,11 +444,12 @@ pub struct MigrationHubConfigClient {
 impl MigrationHubConfigClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> MigrationHubConfigClient {
         MigrationHubConfigClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "MigrationHub Config"),
         }
     }
 
@@ -402,12 +464,32 @@ impl MigrationHubConfigClient {
     {
         MigrationHubConfigClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "MigrationHub Config"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> MigrationHubConfigClient {
-        MigrationHubConfigClient { client, region }
+        MigrationHubConfigClient {
+            client,
+            region: region_for_service(region, "MigrationHub Config"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> MigrationHubConfigClient {
+        MigrationHubConfigClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("MigrationHub Config"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> MigrationHubConfigClient {
+        MigrationHubConfigClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -419,6 +501,7 @@ impl MigrationHubConfig for MigrationHubConfigClient {
         input: CreateHomeRegionControlRequest,
     ) -> Result<CreateHomeRegionControlResult, RusotoError<CreateHomeRegionControlError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateHomeRegionControl");
         request.add_header(
             "x-amz-target",
             "AWSMigrationHubMultiAccountService.CreateHomeRegionControl",
@@ -442,6 +525,7 @@ impl MigrationHubConfig for MigrationHubConfigClient {
     ) -> Result<DescribeHomeRegionControlsResult, RusotoError<DescribeHomeRegionControlsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeHomeRegionControls");
         request.add_header(
             "x-amz-target",
             "AWSMigrationHubMultiAccountService.DescribeHomeRegionControls",
@@ -463,6 +547,7 @@ impl MigrationHubConfig for MigrationHubConfigClient {
         &self,
     ) -> Result<GetHomeRegionResult, RusotoError<GetHomeRegionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetHomeRegion");
         request.add_header(
             "x-amz-target",
             "AWSMigrationHubMultiAccountService.GetHomeRegion",
@@ -477,3 +562,112 @@ impl MigrationHubConfig for MigrationHubConfigClient {
         proto::json::ResponsePayload::new(&response).deserialize::<GetHomeRegionResult, _>()
     }
 }
+
+/// A blocking client for the AWS Migration Hub Config API, running the calls of a `MigrationHubConfigClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `MigrationHubConfigClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct MigrationHubConfigBlockingClient {
+    client: MigrationHubConfigClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl MigrationHubConfigBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> MigrationHubConfigBlockingClient {
+        MigrationHubConfigBlockingClient::from_client(MigrationHubConfigClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> MigrationHubConfigBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        MigrationHubConfigBlockingClient::from_client(MigrationHubConfigClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> MigrationHubConfigBlockingClient {
+        MigrationHubConfigBlockingClient::from_client(MigrationHubConfigClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> MigrationHubConfigBlockingClient {
+        MigrationHubConfigBlockingClient::from_client(MigrationHubConfigClient::new_with_config(
+            config,
+        ))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: MigrationHubConfigClient) -> MigrationHubConfigBlockingClient {
+        MigrationHubConfigBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> MigrationHubConfigBlockingClient {
+        MigrationHubConfigBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> MigrationHubConfigBlockingClient {
+        MigrationHubConfigBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`MigrationHubConfig::create_home_region_control`].
+    pub fn create_home_region_control(
+        &self,
+        input: CreateHomeRegionControlRequest,
+    ) -> Result<CreateHomeRegionControlResult, RusotoError<CreateHomeRegionControlError>> {
+        self.runtime
+            .block_on(self.client.create_home_region_control(input))
+    }
+
+    /// Blocking version of [`MigrationHubConfig::describe_home_region_controls`].
+    pub fn describe_home_region_controls(
+        &self,
+        input: DescribeHomeRegionControlsRequest,
+    ) -> Result<DescribeHomeRegionControlsResult, RusotoError<DescribeHomeRegionControlsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_home_region_controls(input))
+    }
+
+    /// Blocking version of [`MigrationHubConfig::get_home_region`].
+    pub fn get_home_region(&self) -> Result<GetHomeRegionResult, RusotoError<GetHomeRegionError>> {
+        self.runtime.block_on(self.client.get_home_region())
+    }
+}
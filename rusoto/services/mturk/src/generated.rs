@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1617,16 +1618,22 @@ impl AcceptQualificationRequestError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(AcceptQualificationRequestError::RequestError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AcceptQualificationRequestError::RequestError,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(AcceptQualificationRequestError::ServiceFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AcceptQualificationRequestError::ServiceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1657,12 +1664,22 @@ impl ApproveAssignmentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(ApproveAssignmentError::RequestError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ApproveAssignmentError::RequestError,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(ApproveAssignmentError::ServiceFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ApproveAssignmentError::ServiceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1695,16 +1712,22 @@ impl AssociateQualificationWithWorkerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(
-                        AssociateQualificationWithWorkerError::RequestError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateQualificationWithWorkerError::RequestError,
                     )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(
-                        AssociateQualificationWithWorkerError::ServiceFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateQualificationWithWorkerError::ServiceFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1741,16 +1764,22 @@ impl CreateAdditionalAssignmentsForHITError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(
-                        CreateAdditionalAssignmentsForHITError::RequestError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAdditionalAssignmentsForHITError::RequestError,
                     )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(
-                        CreateAdditionalAssignmentsForHITError::ServiceFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAdditionalAssignmentsForHITError::ServiceFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1785,12 +1814,22 @@ impl CreateHITError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(CreateHITError::RequestError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateHITError::RequestError,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(CreateHITError::ServiceFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateHITError::ServiceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1821,12 +1860,22 @@ impl CreateHITTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(CreateHITTypeError::RequestError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateHITTypeError::RequestError,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(CreateHITTypeError::ServiceFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateHITTypeError::ServiceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1857,12 +1906,22 @@ impl CreateHITWithHITTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(CreateHITWithHITTypeError::RequestError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateHITWithHITTypeError::RequestError,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(CreateHITWithHITTypeError::ServiceFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateHITWithHITTypeError::ServiceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1893,16 +1952,22 @@ impl CreateQualificationTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(CreateQualificationTypeError::RequestError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateQualificationTypeError::RequestError,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(CreateQualificationTypeError::ServiceFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateQualificationTypeError::ServiceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1933,12 +1998,22 @@ impl CreateWorkerBlockError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(CreateWorkerBlockError::RequestError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWorkerBlockError::RequestError,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(CreateWorkerBlockError::ServiceFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWorkerBlockError::ServiceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1969,12 +2044,22 @@ impl DeleteHITError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(DeleteHITError::RequestError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteHITError::RequestError,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(DeleteHITError::ServiceFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteHITError::ServiceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2005,16 +2090,22 @@ impl DeleteQualificationTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(DeleteQualificationTypeError::RequestError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteQualificationTypeError::RequestError,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(DeleteQualificationTypeError::ServiceFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteQualificationTypeError::ServiceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2045,12 +2136,22 @@ impl DeleteWorkerBlockError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(DeleteWorkerBlockError::RequestError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteWorkerBlockError::RequestError,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(DeleteWorkerBlockError::ServiceFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteWorkerBlockError::ServiceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2083,16 +2184,22 @@ impl DisassociateQualificationFromWorkerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(
-                        DisassociateQualificationFromWorkerError::RequestError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateQualificationFromWorkerError::RequestError,
                     )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(
-                        DisassociateQualificationFromWorkerError::ServiceFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateQualificationFromWorkerError::ServiceFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2127,12 +2234,22 @@ impl GetAccountBalanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(GetAccountBalanceError::RequestError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAccountBalanceError::RequestError,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(GetAccountBalanceError::ServiceFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAccountBalanceError::ServiceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2163,12 +2280,22 @@ impl GetAssignmentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(GetAssignmentError::RequestError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAssignmentError::RequestError,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(GetAssignmentError::ServiceFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAssignmentError::ServiceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2199,12 +2326,22 @@ impl GetFileUploadURLError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(GetFileUploadURLError::RequestError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFileUploadURLError::RequestError,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(GetFileUploadURLError::ServiceFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFileUploadURLError::ServiceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2234,9 +2371,23 @@ impl GetHITError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetHITError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "RequestError" => return RusotoError::Service(GetHITError::RequestError(err.msg)),
-                "ServiceFault" => return RusotoError::Service(GetHITError::ServiceFault(err.msg)),
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "RequestError" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetHITError::RequestError,
+                    )
+                }
+                "ServiceFault" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetHITError::ServiceFault,
+                    )
+                }
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2267,12 +2418,22 @@ impl GetQualificationScoreError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(GetQualificationScoreError::RequestError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetQualificationScoreError::RequestError,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(GetQualificationScoreError::ServiceFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetQualificationScoreError::ServiceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2303,12 +2464,22 @@ impl GetQualificationTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(GetQualificationTypeError::RequestError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetQualificationTypeError::RequestError,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(GetQualificationTypeError::ServiceFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetQualificationTypeError::ServiceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2339,12 +2510,22 @@ impl ListAssignmentsForHITError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(ListAssignmentsForHITError::RequestError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAssignmentsForHITError::RequestError,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(ListAssignmentsForHITError::ServiceFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAssignmentsForHITError::ServiceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2375,12 +2556,22 @@ impl ListBonusPaymentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(ListBonusPaymentsError::RequestError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBonusPaymentsError::RequestError,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(ListBonusPaymentsError::ServiceFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBonusPaymentsError::ServiceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2411,12 +2602,22 @@ impl ListHITsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(ListHITsError::RequestError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListHITsError::RequestError,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(ListHITsError::ServiceFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListHITsError::ServiceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2449,16 +2650,22 @@ impl ListHITsForQualificationTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(ListHITsForQualificationTypeError::RequestError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListHITsForQualificationTypeError::RequestError,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(ListHITsForQualificationTypeError::ServiceFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListHITsForQualificationTypeError::ServiceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2489,16 +2696,22 @@ impl ListQualificationRequestsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(ListQualificationRequestsError::RequestError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListQualificationRequestsError::RequestError,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(ListQualificationRequestsError::ServiceFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListQualificationRequestsError::ServiceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2529,12 +2742,22 @@ impl ListQualificationTypesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(ListQualificationTypesError::RequestError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListQualificationTypesError::RequestError,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(ListQualificationTypesError::ServiceFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListQualificationTypesError::ServiceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2567,16 +2790,22 @@ impl ListReviewPolicyResultsForHITError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(ListReviewPolicyResultsForHITError::RequestError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListReviewPolicyResultsForHITError::RequestError,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(ListReviewPolicyResultsForHITError::ServiceFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListReviewPolicyResultsForHITError::ServiceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2607,12 +2836,22 @@ impl ListReviewableHITsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(ListReviewableHITsError::RequestError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListReviewableHITsError::RequestError,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(ListReviewableHITsError::ServiceFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListReviewableHITsError::ServiceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2643,12 +2882,22 @@ impl ListWorkerBlocksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(ListWorkerBlocksError::RequestError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListWorkerBlocksError::RequestError,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(ListWorkerBlocksError::ServiceFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListWorkerBlocksError::ServiceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2681,16 +2930,22 @@ impl ListWorkersWithQualificationTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(
-                        ListWorkersWithQualificationTypeError::RequestError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListWorkersWithQualificationTypeError::RequestError,
                     )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(
-                        ListWorkersWithQualificationTypeError::ServiceFault(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListWorkersWithQualificationTypeError::ServiceFault,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2725,12 +2980,22 @@ impl NotifyWorkersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(NotifyWorkersError::RequestError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        NotifyWorkersError::RequestError,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(NotifyWorkersError::ServiceFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        NotifyWorkersError::ServiceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2761,12 +3026,22 @@ impl RejectAssignmentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(RejectAssignmentError::RequestError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RejectAssignmentError::RequestError,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(RejectAssignmentError::ServiceFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RejectAssignmentError::ServiceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2799,16 +3074,22 @@ impl RejectQualificationRequestError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(RejectQualificationRequestError::RequestError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RejectQualificationRequestError::RequestError,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(RejectQualificationRequestError::ServiceFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RejectQualificationRequestError::ServiceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2839,12 +3120,22 @@ impl SendBonusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(SendBonusError::RequestError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendBonusError::RequestError,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(SendBonusError::ServiceFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendBonusError::ServiceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2875,16 +3166,22 @@ impl SendTestEventNotificationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(SendTestEventNotificationError::RequestError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SendTestEventNotificationError::RequestError,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(SendTestEventNotificationError::ServiceFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SendTestEventNotificationError::ServiceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2915,12 +3212,22 @@ impl UpdateExpirationForHITError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(UpdateExpirationForHITError::RequestError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateExpirationForHITError::RequestError,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(UpdateExpirationForHITError::ServiceFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateExpirationForHITError::ServiceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2951,12 +3258,22 @@ impl UpdateHITReviewStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(UpdateHITReviewStatusError::RequestError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateHITReviewStatusError::RequestError,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(UpdateHITReviewStatusError::ServiceFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateHITReviewStatusError::ServiceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2987,12 +3304,22 @@ impl UpdateHITTypeOfHITError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(UpdateHITTypeOfHITError::RequestError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateHITTypeOfHITError::RequestError,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(UpdateHITTypeOfHITError::ServiceFault(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateHITTypeOfHITError::ServiceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3025,16 +3352,22 @@ impl UpdateNotificationSettingsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(UpdateNotificationSettingsError::RequestError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateNotificationSettingsError::RequestError,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(UpdateNotificationSettingsError::ServiceFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateNotificationSettingsError::ServiceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3065,16 +3398,22 @@ impl UpdateQualificationTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "RequestError" => {
-                    return RusotoError::Service(UpdateQualificationTypeError::RequestError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateQualificationTypeError::RequestError,
+                    )
                 }
                 "ServiceFault" => {
-                    return RusotoError::Service(UpdateQualificationTypeError::ServiceFault(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateQualificationTypeError::ServiceFault,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3352,11 +3691,12 @@ pub struct MechanicalTurkClient {
 impl MechanicalTurkClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> MechanicalTurkClient {
         MechanicalTurkClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "MTurk"),
         }
     }
 
@@ -3371,12 +3711,32 @@ impl MechanicalTurkClient {
     {
         MechanicalTurkClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "MTurk"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> MechanicalTurkClient {
-        MechanicalTurkClient { client, region }
+        MechanicalTurkClient {
+            client,
+            region: region_for_service(region, "MTurk"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> MechanicalTurkClient {
+        MechanicalTurkClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("MTurk"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> MechanicalTurkClient {
+        MechanicalTurkClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -3389,6 +3749,7 @@ impl MechanicalTurk for MechanicalTurkClient {
     ) -> Result<AcceptQualificationRequestResponse, RusotoError<AcceptQualificationRequestError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AcceptQualificationRequest");
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.AcceptQualificationRequest",
@@ -3411,6 +3772,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: ApproveAssignmentRequest,
     ) -> Result<ApproveAssignmentResponse, RusotoError<ApproveAssignmentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ApproveAssignment");
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.ApproveAssignment",
@@ -3435,6 +3797,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         RusotoError<AssociateQualificationWithWorkerError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateQualificationWithWorker");
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.AssociateQualificationWithWorker",
@@ -3463,6 +3826,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         RusotoError<CreateAdditionalAssignmentsForHITError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateAdditionalAssignmentsForHIT");
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.CreateAdditionalAssignmentsForHIT",
@@ -3488,6 +3852,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: CreateHITRequest,
     ) -> Result<CreateHITResponse, RusotoError<CreateHITError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateHIT");
         request.add_header("x-amz-target", "MTurkRequesterServiceV20170117.CreateHIT");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3506,6 +3871,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: CreateHITTypeRequest,
     ) -> Result<CreateHITTypeResponse, RusotoError<CreateHITTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateHITType");
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.CreateHITType",
@@ -3527,6 +3893,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: CreateHITWithHITTypeRequest,
     ) -> Result<CreateHITWithHITTypeResponse, RusotoError<CreateHITWithHITTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateHITWithHITType");
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.CreateHITWithHITType",
@@ -3549,6 +3916,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: CreateQualificationTypeRequest,
     ) -> Result<CreateQualificationTypeResponse, RusotoError<CreateQualificationTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateQualificationType");
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.CreateQualificationType",
@@ -3571,6 +3939,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: CreateWorkerBlockRequest,
     ) -> Result<CreateWorkerBlockResponse, RusotoError<CreateWorkerBlockError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateWorkerBlock");
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.CreateWorkerBlock",
@@ -3592,6 +3961,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: DeleteHITRequest,
     ) -> Result<DeleteHITResponse, RusotoError<DeleteHITError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteHIT");
         request.add_header("x-amz-target", "MTurkRequesterServiceV20170117.DeleteHIT");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3610,6 +3980,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: DeleteQualificationTypeRequest,
     ) -> Result<DeleteQualificationTypeResponse, RusotoError<DeleteQualificationTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteQualificationType");
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.DeleteQualificationType",
@@ -3632,6 +4003,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: DeleteWorkerBlockRequest,
     ) -> Result<DeleteWorkerBlockResponse, RusotoError<DeleteWorkerBlockError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteWorkerBlock");
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.DeleteWorkerBlock",
@@ -3656,6 +4028,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         RusotoError<DisassociateQualificationFromWorkerError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisassociateQualificationFromWorker");
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.DisassociateQualificationFromWorker",
@@ -3680,6 +4053,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         &self,
     ) -> Result<GetAccountBalanceResponse, RusotoError<GetAccountBalanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetAccountBalance");
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.GetAccountBalance",
@@ -3700,6 +4074,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: GetAssignmentRequest,
     ) -> Result<GetAssignmentResponse, RusotoError<GetAssignmentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetAssignment");
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.GetAssignment",
@@ -3721,6 +4096,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: GetFileUploadURLRequest,
     ) -> Result<GetFileUploadURLResponse, RusotoError<GetFileUploadURLError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetFileUploadURL");
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.GetFileUploadURL",
@@ -3742,6 +4118,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: GetHITRequest,
     ) -> Result<GetHITResponse, RusotoError<GetHITError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetHIT");
         request.add_header("x-amz-target", "MTurkRequesterServiceV20170117.GetHIT");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3760,6 +4137,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: GetQualificationScoreRequest,
     ) -> Result<GetQualificationScoreResponse, RusotoError<GetQualificationScoreError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetQualificationScore");
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.GetQualificationScore",
@@ -3782,6 +4160,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: GetQualificationTypeRequest,
     ) -> Result<GetQualificationTypeResponse, RusotoError<GetQualificationTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetQualificationType");
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.GetQualificationType",
@@ -3804,6 +4183,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: ListAssignmentsForHITRequest,
     ) -> Result<ListAssignmentsForHITResponse, RusotoError<ListAssignmentsForHITError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListAssignmentsForHIT");
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.ListAssignmentsForHIT",
@@ -3826,6 +4206,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: ListBonusPaymentsRequest,
     ) -> Result<ListBonusPaymentsResponse, RusotoError<ListBonusPaymentsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListBonusPayments");
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.ListBonusPayments",
@@ -3847,6 +4228,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: ListHITsRequest,
     ) -> Result<ListHITsResponse, RusotoError<ListHITsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListHITs");
         request.add_header("x-amz-target", "MTurkRequesterServiceV20170117.ListHITs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3866,6 +4248,7 @@ impl MechanicalTurk for MechanicalTurkClient {
     ) -> Result<ListHITsForQualificationTypeResponse, RusotoError<ListHITsForQualificationTypeError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListHITsForQualificationType");
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.ListHITsForQualificationType",
@@ -3889,6 +4272,7 @@ impl MechanicalTurk for MechanicalTurkClient {
     ) -> Result<ListQualificationRequestsResponse, RusotoError<ListQualificationRequestsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListQualificationRequests");
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.ListQualificationRequests",
@@ -3911,6 +4295,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: ListQualificationTypesRequest,
     ) -> Result<ListQualificationTypesResponse, RusotoError<ListQualificationTypesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListQualificationTypes");
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.ListQualificationTypes",
@@ -3936,6 +4321,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         RusotoError<ListReviewPolicyResultsForHITError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListReviewPolicyResultsForHIT");
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.ListReviewPolicyResultsForHIT",
@@ -3958,6 +4344,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: ListReviewableHITsRequest,
     ) -> Result<ListReviewableHITsResponse, RusotoError<ListReviewableHITsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListReviewableHITs");
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.ListReviewableHITs",
@@ -3979,6 +4366,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: ListWorkerBlocksRequest,
     ) -> Result<ListWorkerBlocksResponse, RusotoError<ListWorkerBlocksError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListWorkerBlocks");
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.ListWorkerBlocks",
@@ -4003,6 +4391,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         RusotoError<ListWorkersWithQualificationTypeError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListWorkersWithQualificationType");
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.ListWorkersWithQualificationType",
@@ -4028,6 +4417,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: NotifyWorkersRequest,
     ) -> Result<NotifyWorkersResponse, RusotoError<NotifyWorkersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("NotifyWorkers");
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.NotifyWorkers",
@@ -4049,6 +4439,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: RejectAssignmentRequest,
     ) -> Result<RejectAssignmentResponse, RusotoError<RejectAssignmentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RejectAssignment");
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.RejectAssignment",
@@ -4071,6 +4462,7 @@ impl MechanicalTurk for MechanicalTurkClient {
     ) -> Result<RejectQualificationRequestResponse, RusotoError<RejectQualificationRequestError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RejectQualificationRequest");
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.RejectQualificationRequest",
@@ -4093,6 +4485,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: SendBonusRequest,
     ) -> Result<SendBonusResponse, RusotoError<SendBonusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SendBonus");
         request.add_header("x-amz-target", "MTurkRequesterServiceV20170117.SendBonus");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4112,6 +4505,7 @@ impl MechanicalTurk for MechanicalTurkClient {
     ) -> Result<SendTestEventNotificationResponse, RusotoError<SendTestEventNotificationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SendTestEventNotification");
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.SendTestEventNotification",
@@ -4134,6 +4528,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: UpdateExpirationForHITRequest,
     ) -> Result<UpdateExpirationForHITResponse, RusotoError<UpdateExpirationForHITError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateExpirationForHIT");
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.UpdateExpirationForHIT",
@@ -4156,6 +4551,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: UpdateHITReviewStatusRequest,
     ) -> Result<UpdateHITReviewStatusResponse, RusotoError<UpdateHITReviewStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateHITReviewStatus");
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.UpdateHITReviewStatus",
@@ -4178,6 +4574,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: UpdateHITTypeOfHITRequest,
     ) -> Result<UpdateHITTypeOfHITResponse, RusotoError<UpdateHITTypeOfHITError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateHITTypeOfHIT");
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.UpdateHITTypeOfHIT",
@@ -4200,6 +4597,7 @@ impl MechanicalTurk for MechanicalTurkClient {
     ) -> Result<UpdateNotificationSettingsResponse, RusotoError<UpdateNotificationSettingsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateNotificationSettings");
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.UpdateNotificationSettings",
@@ -4222,6 +4620,7 @@ impl MechanicalTurk for MechanicalTurkClient {
         input: UpdateQualificationTypeRequest,
     ) -> Result<UpdateQualificationTypeResponse, RusotoError<UpdateQualificationTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateQualificationType");
         request.add_header(
             "x-amz-target",
             "MTurkRequesterServiceV20170117.UpdateQualificationType",
@@ -4238,3 +4637,442 @@ impl MechanicalTurk for MechanicalTurkClient {
             .deserialize::<UpdateQualificationTypeResponse, _>()
     }
 }
+
+/// A blocking client for the Amazon MTurk API, running the calls of a `MechanicalTurkClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `MechanicalTurkClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct MechanicalTurkBlockingClient {
+    client: MechanicalTurkClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl MechanicalTurkBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> MechanicalTurkBlockingClient {
+        MechanicalTurkBlockingClient::from_client(MechanicalTurkClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> MechanicalTurkBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        MechanicalTurkBlockingClient::from_client(MechanicalTurkClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> MechanicalTurkBlockingClient {
+        MechanicalTurkBlockingClient::from_client(MechanicalTurkClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> MechanicalTurkBlockingClient {
+        MechanicalTurkBlockingClient::from_client(MechanicalTurkClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: MechanicalTurkClient) -> MechanicalTurkBlockingClient {
+        MechanicalTurkBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> MechanicalTurkBlockingClient {
+        MechanicalTurkBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> MechanicalTurkBlockingClient {
+        MechanicalTurkBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`MechanicalTurk::accept_qualification_request`].
+    pub fn accept_qualification_request(
+        &self,
+        input: AcceptQualificationRequestRequest,
+    ) -> Result<AcceptQualificationRequestResponse, RusotoError<AcceptQualificationRequestError>>
+    {
+        self.runtime
+            .block_on(self.client.accept_qualification_request(input))
+    }
+
+    /// Blocking version of [`MechanicalTurk::approve_assignment`].
+    pub fn approve_assignment(
+        &self,
+        input: ApproveAssignmentRequest,
+    ) -> Result<ApproveAssignmentResponse, RusotoError<ApproveAssignmentError>> {
+        self.runtime.block_on(self.client.approve_assignment(input))
+    }
+
+    /// Blocking version of [`MechanicalTurk::associate_qualification_with_worker`].
+    pub fn associate_qualification_with_worker(
+        &self,
+        input: AssociateQualificationWithWorkerRequest,
+    ) -> Result<
+        AssociateQualificationWithWorkerResponse,
+        RusotoError<AssociateQualificationWithWorkerError>,
+    > {
+        self.runtime
+            .block_on(self.client.associate_qualification_with_worker(input))
+    }
+
+    /// Blocking version of [`MechanicalTurk::create_additional_assignments_for_hit`].
+    pub fn create_additional_assignments_for_hit(
+        &self,
+        input: CreateAdditionalAssignmentsForHITRequest,
+    ) -> Result<
+        CreateAdditionalAssignmentsForHITResponse,
+        RusotoError<CreateAdditionalAssignmentsForHITError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_additional_assignments_for_hit(input))
+    }
+
+    /// Blocking version of [`MechanicalTurk::create_hit`].
+    pub fn create_hit(
+        &self,
+        input: CreateHITRequest,
+    ) -> Result<CreateHITResponse, RusotoError<CreateHITError>> {
+        self.runtime.block_on(self.client.create_hit(input))
+    }
+
+    /// Blocking version of [`MechanicalTurk::create_hit_type`].
+    pub fn create_hit_type(
+        &self,
+        input: CreateHITTypeRequest,
+    ) -> Result<CreateHITTypeResponse, RusotoError<CreateHITTypeError>> {
+        self.runtime.block_on(self.client.create_hit_type(input))
+    }
+
+    /// Blocking version of [`MechanicalTurk::create_hit_with_hit_type`].
+    pub fn create_hit_with_hit_type(
+        &self,
+        input: CreateHITWithHITTypeRequest,
+    ) -> Result<CreateHITWithHITTypeResponse, RusotoError<CreateHITWithHITTypeError>> {
+        self.runtime
+            .block_on(self.client.create_hit_with_hit_type(input))
+    }
+
+    /// Blocking version of [`MechanicalTurk::create_qualification_type`].
+    pub fn create_qualification_type(
+        &self,
+        input: CreateQualificationTypeRequest,
+    ) -> Result<CreateQualificationTypeResponse, RusotoError<CreateQualificationTypeError>> {
+        self.runtime
+            .block_on(self.client.create_qualification_type(input))
+    }
+
+    /// Blocking version of [`MechanicalTurk::create_worker_block`].
+    pub fn create_worker_block(
+        &self,
+        input: CreateWorkerBlockRequest,
+    ) -> Result<CreateWorkerBlockResponse, RusotoError<CreateWorkerBlockError>> {
+        self.runtime
+            .block_on(self.client.create_worker_block(input))
+    }
+
+    /// Blocking version of [`MechanicalTurk::delete_hit`].
+    pub fn delete_hit(
+        &self,
+        input: DeleteHITRequest,
+    ) -> Result<DeleteHITResponse, RusotoError<DeleteHITError>> {
+        self.runtime.block_on(self.client.delete_hit(input))
+    }
+
+    /// Blocking version of [`MechanicalTurk::delete_qualification_type`].
+    pub fn delete_qualification_type(
+        &self,
+        input: DeleteQualificationTypeRequest,
+    ) -> Result<DeleteQualificationTypeResponse, RusotoError<DeleteQualificationTypeError>> {
+        self.runtime
+            .block_on(self.client.delete_qualification_type(input))
+    }
+
+    /// Blocking version of [`MechanicalTurk::delete_worker_block`].
+    pub fn delete_worker_block(
+        &self,
+        input: DeleteWorkerBlockRequest,
+    ) -> Result<DeleteWorkerBlockResponse, RusotoError<DeleteWorkerBlockError>> {
+        self.runtime
+            .block_on(self.client.delete_worker_block(input))
+    }
+
+    /// Blocking version of [`MechanicalTurk::disassociate_qualification_from_worker`].
+    pub fn disassociate_qualification_from_worker(
+        &self,
+        input: DisassociateQualificationFromWorkerRequest,
+    ) -> Result<
+        DisassociateQualificationFromWorkerResponse,
+        RusotoError<DisassociateQualificationFromWorkerError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_qualification_from_worker(input))
+    }
+
+    /// Blocking version of [`MechanicalTurk::get_account_balance`].
+    pub fn get_account_balance(
+        &self,
+    ) -> Result<GetAccountBalanceResponse, RusotoError<GetAccountBalanceError>> {
+        self.runtime.block_on(self.client.get_account_balance())
+    }
+
+    /// Blocking version of [`MechanicalTurk::get_assignment`].
+    pub fn get_assignment(
+        &self,
+        input: GetAssignmentRequest,
+    ) -> Result<GetAssignmentResponse, RusotoError<GetAssignmentError>> {
+        self.runtime.block_on(self.client.get_assignment(input))
+    }
+
+    /// Blocking version of [`MechanicalTurk::get_file_upload_url`].
+    pub fn get_file_upload_url(
+        &self,
+        input: GetFileUploadURLRequest,
+    ) -> Result<GetFileUploadURLResponse, RusotoError<GetFileUploadURLError>> {
+        self.runtime
+            .block_on(self.client.get_file_upload_url(input))
+    }
+
+    /// Blocking version of [`MechanicalTurk::get_hit`].
+    pub fn get_hit(
+        &self,
+        input: GetHITRequest,
+    ) -> Result<GetHITResponse, RusotoError<GetHITError>> {
+        self.runtime.block_on(self.client.get_hit(input))
+    }
+
+    /// Blocking version of [`MechanicalTurk::get_qualification_score`].
+    pub fn get_qualification_score(
+        &self,
+        input: GetQualificationScoreRequest,
+    ) -> Result<GetQualificationScoreResponse, RusotoError<GetQualificationScoreError>> {
+        self.runtime
+            .block_on(self.client.get_qualification_score(input))
+    }
+
+    /// Blocking version of [`MechanicalTurk::get_qualification_type`].
+    pub fn get_qualification_type(
+        &self,
+        input: GetQualificationTypeRequest,
+    ) -> Result<GetQualificationTypeResponse, RusotoError<GetQualificationTypeError>> {
+        self.runtime
+            .block_on(self.client.get_qualification_type(input))
+    }
+
+    /// Blocking version of [`MechanicalTurk::list_assignments_for_hit`].
+    pub fn list_assignments_for_hit(
+        &self,
+        input: ListAssignmentsForHITRequest,
+    ) -> Result<ListAssignmentsForHITResponse, RusotoError<ListAssignmentsForHITError>> {
+        self.runtime
+            .block_on(self.client.list_assignments_for_hit(input))
+    }
+
+    /// Blocking version of [`MechanicalTurk::list_bonus_payments`].
+    pub fn list_bonus_payments(
+        &self,
+        input: ListBonusPaymentsRequest,
+    ) -> Result<ListBonusPaymentsResponse, RusotoError<ListBonusPaymentsError>> {
+        self.runtime
+            .block_on(self.client.list_bonus_payments(input))
+    }
+
+    /// Blocking version of [`MechanicalTurk::list_hi_ts`].
+    pub fn list_hi_ts(
+        &self,
+        input: ListHITsRequest,
+    ) -> Result<ListHITsResponse, RusotoError<ListHITsError>> {
+        self.runtime.block_on(self.client.list_hi_ts(input))
+    }
+
+    /// Blocking version of [`MechanicalTurk::list_hi_ts_for_qualification_type`].
+    pub fn list_hi_ts_for_qualification_type(
+        &self,
+        input: ListHITsForQualificationTypeRequest,
+    ) -> Result<ListHITsForQualificationTypeResponse, RusotoError<ListHITsForQualificationTypeError>>
+    {
+        self.runtime
+            .block_on(self.client.list_hi_ts_for_qualification_type(input))
+    }
+
+    /// Blocking version of [`MechanicalTurk::list_qualification_requests`].
+    pub fn list_qualification_requests(
+        &self,
+        input: ListQualificationRequestsRequest,
+    ) -> Result<ListQualificationRequestsResponse, RusotoError<ListQualificationRequestsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_qualification_requests(input))
+    }
+
+    /// Blocking version of [`MechanicalTurk::list_qualification_types`].
+    pub fn list_qualification_types(
+        &self,
+        input: ListQualificationTypesRequest,
+    ) -> Result<ListQualificationTypesResponse, RusotoError<ListQualificationTypesError>> {
+        self.runtime
+            .block_on(self.client.list_qualification_types(input))
+    }
+
+    /// Blocking version of [`MechanicalTurk::list_review_policy_results_for_hit`].
+    pub fn list_review_policy_results_for_hit(
+        &self,
+        input: ListReviewPolicyResultsForHITRequest,
+    ) -> Result<
+        ListReviewPolicyResultsForHITResponse,
+        RusotoError<ListReviewPolicyResultsForHITError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_review_policy_results_for_hit(input))
+    }
+
+    /// Blocking version of [`MechanicalTurk::list_reviewable_hi_ts`].
+    pub fn list_reviewable_hi_ts(
+        &self,
+        input: ListReviewableHITsRequest,
+    ) -> Result<ListReviewableHITsResponse, RusotoError<ListReviewableHITsError>> {
+        self.runtime
+            .block_on(self.client.list_reviewable_hi_ts(input))
+    }
+
+    /// Blocking version of [`MechanicalTurk::list_worker_blocks`].
+    pub fn list_worker_blocks(
+        &self,
+        input: ListWorkerBlocksRequest,
+    ) -> Result<ListWorkerBlocksResponse, RusotoError<ListWorkerBlocksError>> {
+        self.runtime.block_on(self.client.list_worker_blocks(input))
+    }
+
+    /// Blocking version of [`MechanicalTurk::list_workers_with_qualification_type`].
+    pub fn list_workers_with_qualification_type(
+        &self,
+        input: ListWorkersWithQualificationTypeRequest,
+    ) -> Result<
+        ListWorkersWithQualificationTypeResponse,
+        RusotoError<ListWorkersWithQualificationTypeError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_workers_with_qualification_type(input))
+    }
+
+    /// Blocking version of [`MechanicalTurk::notify_workers`].
+    pub fn notify_workers(
+        &self,
+        input: NotifyWorkersRequest,
+    ) -> Result<NotifyWorkersResponse, RusotoError<NotifyWorkersError>> {
+        self.runtime.block_on(self.client.notify_workers(input))
+    }
+
+    /// Blocking version of [`MechanicalTurk::reject_assignment`].
+    pub fn reject_assignment(
+        &self,
+        input: RejectAssignmentRequest,
+    ) -> Result<RejectAssignmentResponse, RusotoError<RejectAssignmentError>> {
+        self.runtime.block_on(self.client.reject_assignment(input))
+    }
+
+    /// Blocking version of [`MechanicalTurk::reject_qualification_request`].
+    pub fn reject_qualification_request(
+        &self,
+        input: RejectQualificationRequestRequest,
+    ) -> Result<RejectQualificationRequestResponse, RusotoError<RejectQualificationRequestError>>
+    {
+        self.runtime
+            .block_on(self.client.reject_qualification_request(input))
+    }
+
+    /// Blocking version of [`MechanicalTurk::send_bonus`].
+    pub fn send_bonus(
+        &self,
+        input: SendBonusRequest,
+    ) -> Result<SendBonusResponse, RusotoError<SendBonusError>> {
+        self.runtime.block_on(self.client.send_bonus(input))
+    }
+
+    /// Blocking version of [`MechanicalTurk::send_test_event_notification`].
+    pub fn send_test_event_notification(
+        &self,
+        input: SendTestEventNotificationRequest,
+    ) -> Result<SendTestEventNotificationResponse, RusotoError<SendTestEventNotificationError>>
+    {
+        self.runtime
+            .block_on(self.client.send_test_event_notification(input))
+    }
+
+    /// Blocking version of [`MechanicalTurk::update_expiration_for_hit`].
+    pub fn update_expiration_for_hit(
+        &self,
+        input: UpdateExpirationForHITRequest,
+    ) -> Result<UpdateExpirationForHITResponse, RusotoError<UpdateExpirationForHITError>> {
+        self.runtime
+            .block_on(self.client.update_expiration_for_hit(input))
+    }
+
+    /// Blocking version of [`MechanicalTurk::update_hit_review_status`].
+    pub fn update_hit_review_status(
+        &self,
+        input: UpdateHITReviewStatusRequest,
+    ) -> Result<UpdateHITReviewStatusResponse, RusotoError<UpdateHITReviewStatusError>> {
+        self.runtime
+            .block_on(self.client.update_hit_review_status(input))
+    }
+
+    /// Blocking version of [`MechanicalTurk::update_hit_type_of_hit`].
+    pub fn update_hit_type_of_hit(
+        &self,
+        input: UpdateHITTypeOfHITRequest,
+    ) -> Result<UpdateHITTypeOfHITResponse, RusotoError<UpdateHITTypeOfHITError>> {
+        self.runtime
+            .block_on(self.client.update_hit_type_of_hit(input))
+    }
+
+    /// Blocking version of [`MechanicalTurk::update_notification_settings`].
+    pub fn update_notification_settings(
+        &self,
+        input: UpdateNotificationSettingsRequest,
+    ) -> Result<UpdateNotificationSettingsResponse, RusotoError<UpdateNotificationSettingsError>>
+    {
+        self.runtime
+            .block_on(self.client.update_notification_settings(input))
+    }
+
+    /// Blocking version of [`MechanicalTurk::update_qualification_type`].
+    pub fn update_qualification_type(
+        &self,
+        input: UpdateQualificationTypeRequest,
+    ) -> Result<UpdateQualificationTypeResponse, RusotoError<UpdateQualificationTypeError>> {
+        self.runtime
+            .block_on(self.client.update_qualification_type(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -9224,29 +9225,35 @@ impl AddRoleToDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            AddRoleToDBClusterError::DBClusterNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AddRoleToDBClusterError::DBClusterNotFoundFault,
                         )
                     }
                     "DBClusterRoleAlreadyExists" => {
-                        return RusotoError::Service(
-                            AddRoleToDBClusterError::DBClusterRoleAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AddRoleToDBClusterError::DBClusterRoleAlreadyExistsFault,
                         )
                     }
                     "DBClusterRoleQuotaExceeded" => {
-                        return RusotoError::Service(
-                            AddRoleToDBClusterError::DBClusterRoleQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AddRoleToDBClusterError::DBClusterRoleQuotaExceededFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            AddRoleToDBClusterError::InvalidDBClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AddRoleToDBClusterError::InvalidDBClusterStateFault,
                         )
                     }
                     _ => {}
@@ -9302,17 +9309,19 @@ impl AddSourceIdentifierToSubscriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "SourceNotFound" => {
-                        return RusotoError::Service(
-                            AddSourceIdentifierToSubscriptionError::SourceNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AddSourceIdentifierToSubscriptionError::SourceNotFoundFault,
                         )
                     }
                     "SubscriptionNotFound" => {
-                        return RusotoError::Service(
-                            AddSourceIdentifierToSubscriptionError::SubscriptionNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AddSourceIdentifierToSubscriptionError::SubscriptionNotFoundFault,
                         )
                     }
                     _ => {}
@@ -9364,18 +9373,27 @@ impl AddTagsToResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            AddTagsToResourceError::DBClusterNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AddTagsToResourceError::DBClusterNotFoundFault,
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
-                            AddTagsToResourceError::DBInstanceNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AddTagsToResourceError::DBInstanceNotFoundFault,
                         )
                     }
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
-                            AddTagsToResourceError::DBSnapshotNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AddTagsToResourceError::DBSnapshotNotFoundFault,
                         )
                     }
                     _ => {}
@@ -9422,10 +9440,11 @@ impl ApplyPendingMaintenanceActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceNotFoundFault" => {
-                        return RusotoError::Service(
-                            ApplyPendingMaintenanceActionError::ResourceNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ApplyPendingMaintenanceActionError::ResourceNotFoundFault,
                         )
                     }
                     _ => {}
@@ -9476,24 +9495,27 @@ impl CopyDBClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupAlreadyExists" => {
-                        return RusotoError::Service(
-                            CopyDBClusterParameterGroupError::DBParameterGroupAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyDBClusterParameterGroupError::DBParameterGroupAlreadyExistsFault,
                         )
                     }
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            CopyDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyDBClusterParameterGroupError::DBParameterGroupNotFoundFault,
                         )
                     }
                     "DBParameterGroupQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CopyDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -9554,45 +9576,51 @@ impl CopyDBClusterSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterSnapshotAlreadyExistsFault" => {
-                        return RusotoError::Service(
-                            CopyDBClusterSnapshotError::DBClusterSnapshotAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyDBClusterSnapshotError::DBClusterSnapshotAlreadyExistsFault,
                         )
                     }
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
-                            CopyDBClusterSnapshotError::DBClusterSnapshotNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyDBClusterSnapshotError::DBClusterSnapshotNotFoundFault,
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
-                            CopyDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            CopyDBClusterSnapshotError::InvalidDBClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyDBClusterSnapshotError::InvalidDBClusterStateFault,
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
-                            CopyDBClusterSnapshotError::KMSKeyNotAccessibleFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyDBClusterSnapshotError::KMSKeyNotAccessibleFault,
                         )
                     }
                     "SnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CopyDBClusterSnapshotError::SnapshotQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyDBClusterSnapshotError::SnapshotQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -9656,24 +9684,27 @@ impl CopyDBParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupAlreadyExists" => {
-                        return RusotoError::Service(
-                            CopyDBParameterGroupError::DBParameterGroupAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyDBParameterGroupError::DBParameterGroupAlreadyExistsFault,
                         )
                     }
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            CopyDBParameterGroupError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyDBParameterGroupError::DBParameterGroupNotFoundFault,
                         )
                     }
                     "DBParameterGroupQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CopyDBParameterGroupError::DBParameterGroupQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyDBParameterGroupError::DBParameterGroupQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -9752,86 +9783,123 @@ impl CreateDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterAlreadyExistsFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterError::DBClusterAlreadyExistsFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterError::DBClusterAlreadyExistsFault,
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(CreateDBClusterError::DBClusterNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateDBClusterError::DBClusterNotFoundFault,
+                        )
                     }
                     "DBClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            CreateDBClusterError::DBClusterParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterError::DBClusterParameterGroupNotFoundFault,
                         )
                     }
                     "DBClusterQuotaExceededFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterError::DBClusterQuotaExceededFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterError::DBClusterQuotaExceededFault,
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(CreateDBClusterError::DBInstanceNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateDBClusterError::DBInstanceNotFoundFault,
+                        )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
-                        return RusotoError::Service(
-                            CreateDBClusterError::DBSubnetGroupDoesNotCoverEnoughAZs(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterError::DBSubnetGroupDoesNotCoverEnoughAZs,
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterError::DBSubnetGroupNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterError::DBSubnetGroupNotFoundFault,
                         )
                     }
                     "InsufficientStorageClusterCapacity" => {
-                        return RusotoError::Service(
-                            CreateDBClusterError::InsufficientStorageClusterCapacityFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterError::InsufficientStorageClusterCapacityFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterError::InvalidDBClusterStateFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            CreateDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterError::InvalidDBInstanceStateFault,
                         )
                     }
                     "InvalidDBSubnetGroupStateFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterError::InvalidDBSubnetGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterError::InvalidDBSubnetGroupStateFault,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateDBClusterError::InvalidSubnet(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateDBClusterError::InvalidSubnet,
+                        )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterError::InvalidVPCNetworkStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterError::InvalidVPCNetworkStateFault,
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterError::KMSKeyNotAccessibleFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterError::KMSKeyNotAccessibleFault,
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CreateDBClusterError::StorageQuotaExceededFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterError::StorageQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -9906,45 +9974,51 @@ impl CreateDBClusterEndpointError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterEndpointAlreadyExistsFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterEndpointError::DBClusterEndpointAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterEndpointError::DBClusterEndpointAlreadyExistsFault,
                         )
                     }
                     "DBClusterEndpointQuotaExceededFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterEndpointError::DBClusterEndpointQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterEndpointError::DBClusterEndpointQuotaExceededFault,
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterEndpointError::DBClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterEndpointError::DBClusterNotFoundFault,
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
-                            CreateDBClusterEndpointError::DBInstanceNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterEndpointError::DBInstanceNotFoundFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterEndpointError::InvalidDBClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterEndpointError::InvalidDBClusterStateFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            CreateDBClusterEndpointError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterEndpointError::InvalidDBInstanceStateFault,
                         )
                     }
                     _ => {}
@@ -10008,17 +10082,19 @@ impl CreateDBClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupAlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateDBClusterParameterGroupError::DBParameterGroupAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterParameterGroupError::DBParameterGroupAlreadyExistsFault,
                         )
                     }
                     "DBParameterGroupQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CreateDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -10074,38 +10150,43 @@ impl CreateDBClusterSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterSnapshotError::DBClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterSnapshotError::DBClusterNotFoundFault,
                         )
                     }
                     "DBClusterSnapshotAlreadyExistsFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterSnapshotError::DBClusterSnapshotAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterSnapshotError::DBClusterSnapshotAlreadyExistsFault,
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterSnapshotError::InvalidDBClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterSnapshotError::InvalidDBClusterStateFault,
                         )
                     }
                     "SnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CreateDBClusterSnapshotError::SnapshotQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterSnapshotError::SnapshotQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -10196,109 +10277,147 @@ impl CreateDBInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationNotFound" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::AuthorizationNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::AuthorizationNotFoundFault,
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(CreateDBInstanceError::DBClusterNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateDBInstanceError::DBClusterNotFoundFault,
+                        )
                     }
                     "DBInstanceAlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::DBInstanceAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::DBInstanceAlreadyExistsFault,
                         )
                     }
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::DBParameterGroupNotFoundFault,
                         )
                     }
                     "DBSecurityGroupNotFound" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::DBSecurityGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::DBSecurityGroupNotFoundFault,
                         )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::DBSubnetGroupDoesNotCoverEnoughAZs(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::DBSubnetGroupDoesNotCoverEnoughAZs,
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::DBSubnetGroupNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::DBSubnetGroupNotFoundFault,
                         )
                     }
                     "DomainNotFoundFault" => {
-                        return RusotoError::Service(CreateDBInstanceError::DomainNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateDBInstanceError::DomainNotFoundFault,
+                        )
                     }
                     "InstanceQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::InstanceQuotaExceededFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::InstanceQuotaExceededFault,
                         )
                     }
                     "InsufficientDBInstanceCapacity" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::InsufficientDBInstanceCapacityFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::InsufficientDBInstanceCapacityFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::InvalidDBClusterStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::InvalidDBClusterStateFault,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateDBInstanceError::InvalidSubnet(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateDBInstanceError::InvalidSubnet,
+                        )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::InvalidVPCNetworkStateFault,
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::KMSKeyNotAccessibleFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::KMSKeyNotAccessibleFault,
                         )
                     }
                     "OptionGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::OptionGroupNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::OptionGroupNotFoundFault,
                         )
                     }
                     "ProvisionedIopsNotAvailableInAZFault" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::ProvisionedIopsNotAvailableInAZFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::ProvisionedIopsNotAvailableInAZFault,
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::StorageQuotaExceededFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::StorageQuotaExceededFault,
                         )
                     }
                     "StorageTypeNotSupported" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::StorageTypeNotSupportedFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::StorageTypeNotSupportedFault,
                         )
                     }
                     _ => {}
@@ -10374,17 +10493,19 @@ impl CreateDBParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupAlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateDBParameterGroupError::DBParameterGroupAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBParameterGroupError::DBParameterGroupAlreadyExistsFault,
                         )
                     }
                     "DBParameterGroupQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CreateDBParameterGroupError::DBParameterGroupQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBParameterGroupError::DBParameterGroupQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -10440,37 +10561,44 @@ impl CreateDBSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSubnetGroupAlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateDBSubnetGroupError::DBSubnetGroupAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBSubnetGroupError::DBSubnetGroupAlreadyExistsFault,
                         )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
-                        return RusotoError::Service(
-                            CreateDBSubnetGroupError::DBSubnetGroupDoesNotCoverEnoughAZs(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBSubnetGroupError::DBSubnetGroupDoesNotCoverEnoughAZs,
                         )
                     }
                     "DBSubnetGroupQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CreateDBSubnetGroupError::DBSubnetGroupQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBSubnetGroupError::DBSubnetGroupQuotaExceededFault,
                         )
                     }
                     "DBSubnetQuotaExceededFault" => {
-                        return RusotoError::Service(
-                            CreateDBSubnetGroupError::DBSubnetQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBSubnetGroupError::DBSubnetQuotaExceededFault,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateDBSubnetGroupError::InvalidSubnet(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateDBSubnetGroupError::InvalidSubnet,
+                        )
                     }
                     _ => {}
                 }
@@ -10536,50 +10664,59 @@ impl CreateEventSubscriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EventSubscriptionQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CreateEventSubscriptionError::EventSubscriptionQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateEventSubscriptionError::EventSubscriptionQuotaExceededFault,
                         )
                     }
                     "SNSInvalidTopic" => {
-                        return RusotoError::Service(
-                            CreateEventSubscriptionError::SNSInvalidTopicFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateEventSubscriptionError::SNSInvalidTopicFault,
                         )
                     }
                     "SNSNoAuthorization" => {
-                        return RusotoError::Service(
-                            CreateEventSubscriptionError::SNSNoAuthorizationFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateEventSubscriptionError::SNSNoAuthorizationFault,
                         )
                     }
                     "SNSTopicArnNotFound" => {
-                        return RusotoError::Service(
-                            CreateEventSubscriptionError::SNSTopicArnNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateEventSubscriptionError::SNSTopicArnNotFoundFault,
                         )
                     }
                     "SourceNotFound" => {
-                        return RusotoError::Service(
-                            CreateEventSubscriptionError::SourceNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateEventSubscriptionError::SourceNotFoundFault,
                         )
                     }
                     "SubscriptionAlreadyExist" => {
-                        return RusotoError::Service(
-                            CreateEventSubscriptionError::SubscriptionAlreadyExistFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateEventSubscriptionError::SubscriptionAlreadyExistFault,
                         )
                     }
                     "SubscriptionCategoryNotFound" => {
-                        return RusotoError::Service(
-                            CreateEventSubscriptionError::SubscriptionCategoryNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateEventSubscriptionError::SubscriptionCategoryNotFoundFault,
                         )
                     }
                     _ => {}
@@ -10646,32 +10783,43 @@ impl DeleteDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(DeleteDBClusterError::DBClusterNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteDBClusterError::DBClusterNotFoundFault,
+                        )
                     }
                     "DBClusterSnapshotAlreadyExistsFault" => {
-                        return RusotoError::Service(
-                            DeleteDBClusterError::DBClusterSnapshotAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBClusterError::DBClusterSnapshotAlreadyExistsFault,
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
-                            DeleteDBClusterError::InvalidDBClusterSnapshotStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBClusterError::InvalidDBClusterSnapshotStateFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            DeleteDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBClusterError::InvalidDBClusterStateFault,
                         )
                     }
                     "SnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
-                            DeleteDBClusterError::SnapshotQuotaExceededFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBClusterError::SnapshotQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -10726,24 +10874,27 @@ impl DeleteDBClusterEndpointError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterEndpointNotFoundFault" => {
-                        return RusotoError::Service(
-                            DeleteDBClusterEndpointError::DBClusterEndpointNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBClusterEndpointError::DBClusterEndpointNotFoundFault,
                         )
                     }
                     "InvalidDBClusterEndpointStateFault" => {
-                        return RusotoError::Service(
-                            DeleteDBClusterEndpointError::InvalidDBClusterEndpointStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBClusterEndpointError::InvalidDBClusterEndpointStateFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            DeleteDBClusterEndpointError::InvalidDBClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBClusterEndpointError::InvalidDBClusterStateFault,
                         )
                     }
                     _ => {}
@@ -10798,17 +10949,19 @@ impl DeleteDBClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            DeleteDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBClusterParameterGroupError::DBParameterGroupNotFoundFault,
                         )
                     }
                     "InvalidDBParameterGroupState" => {
-                        return RusotoError::Service(
-                            DeleteDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBClusterParameterGroupError::InvalidDBParameterGroupStateFault,
                         )
                     }
                     _ => {}
@@ -10858,17 +11011,19 @@ impl DeleteDBClusterSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
-                            DeleteDBClusterSnapshotError::DBClusterSnapshotNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBClusterSnapshotError::DBClusterSnapshotNotFoundFault,
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
-                            DeleteDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault,
                         )
                     }
                     _ => {}
@@ -10924,32 +11079,43 @@ impl DeleteDBInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
-                            DeleteDBInstanceError::DBInstanceNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBInstanceError::DBInstanceNotFoundFault,
                         )
                     }
                     "DBSnapshotAlreadyExists" => {
-                        return RusotoError::Service(
-                            DeleteDBInstanceError::DBSnapshotAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBInstanceError::DBSnapshotAlreadyExistsFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            DeleteDBInstanceError::InvalidDBClusterStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBInstanceError::InvalidDBClusterStateFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            DeleteDBInstanceError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBInstanceError::InvalidDBInstanceStateFault,
                         )
                     }
                     "SnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
-                            DeleteDBInstanceError::SnapshotQuotaExceededFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBInstanceError::SnapshotQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -11000,17 +11166,19 @@ impl DeleteDBParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            DeleteDBParameterGroupError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBParameterGroupError::DBParameterGroupNotFoundFault,
                         )
                     }
                     "InvalidDBParameterGroupState" => {
-                        return RusotoError::Service(
-                            DeleteDBParameterGroupError::InvalidDBParameterGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBParameterGroupError::InvalidDBParameterGroupStateFault,
                         )
                     }
                     _ => {}
@@ -11062,24 +11230,27 @@ impl DeleteDBSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            DeleteDBSubnetGroupError::DBSubnetGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBSubnetGroupError::DBSubnetGroupNotFoundFault,
                         )
                     }
                     "InvalidDBSubnetGroupStateFault" => {
-                        return RusotoError::Service(
-                            DeleteDBSubnetGroupError::InvalidDBSubnetGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBSubnetGroupError::InvalidDBSubnetGroupStateFault,
                         )
                     }
                     "InvalidDBSubnetStateFault" => {
-                        return RusotoError::Service(
-                            DeleteDBSubnetGroupError::InvalidDBSubnetStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBSubnetGroupError::InvalidDBSubnetStateFault,
                         )
                     }
                     _ => {}
@@ -11132,17 +11303,19 @@ impl DeleteEventSubscriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidEventSubscriptionState" => {
-                        return RusotoError::Service(
-                            DeleteEventSubscriptionError::InvalidEventSubscriptionStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteEventSubscriptionError::InvalidEventSubscriptionStateFault,
                         )
                     }
                     "SubscriptionNotFound" => {
-                        return RusotoError::Service(
-                            DeleteEventSubscriptionError::SubscriptionNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteEventSubscriptionError::SubscriptionNotFoundFault,
                         )
                     }
                     _ => {}
@@ -11192,10 +11365,11 @@ impl DescribeDBClusterEndpointsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            DescribeDBClusterEndpointsError::DBClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeDBClusterEndpointsError::DBClusterNotFoundFault,
                         )
                     }
                     _ => {}
@@ -11242,10 +11416,11 @@ impl DescribeDBClusterParameterGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            DescribeDBClusterParameterGroupsError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeDBClusterParameterGroupsError::DBParameterGroupNotFoundFault,
                         )
                     }
                     _ => {}
@@ -11292,10 +11467,11 @@ impl DescribeDBClusterParametersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            DescribeDBClusterParametersError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeDBClusterParametersError::DBParameterGroupNotFoundFault,
                         )
                     }
                     _ => {}
@@ -11341,10 +11517,11 @@ impl DescribeDBClusterSnapshotAttributesError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                    "DBClusterSnapshotNotFoundFault" => return RusotoError::Service(
-                        DescribeDBClusterSnapshotAttributesError::DBClusterSnapshotNotFoundFault(
-                            parsed_error.message,
-                        ),
+                    "DBClusterSnapshotNotFoundFault" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        DescribeDBClusterSnapshotAttributesError::DBClusterSnapshotNotFoundFault,
                     ),
                     _ => {}
                 }
@@ -11390,10 +11567,11 @@ impl DescribeDBClusterSnapshotsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
-                            DescribeDBClusterSnapshotsError::DBClusterSnapshotNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeDBClusterSnapshotsError::DBClusterSnapshotNotFoundFault,
                         )
                     }
                     _ => {}
@@ -11438,8 +11616,11 @@ impl DescribeDBClustersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            DescribeDBClustersError::DBClusterNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeDBClustersError::DBClusterNotFoundFault,
                         )
                     }
                     _ => {}
@@ -11516,8 +11697,11 @@ impl DescribeDBInstancesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
-                            DescribeDBInstancesError::DBInstanceNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeDBInstancesError::DBInstanceNotFoundFault,
                         )
                     }
                     _ => {}
@@ -11560,10 +11744,11 @@ impl DescribeDBParameterGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            DescribeDBParameterGroupsError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeDBParameterGroupsError::DBParameterGroupNotFoundFault,
                         )
                     }
                     _ => {}
@@ -11608,10 +11793,11 @@ impl DescribeDBParametersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            DescribeDBParametersError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeDBParametersError::DBParameterGroupNotFoundFault,
                         )
                     }
                     _ => {}
@@ -11656,10 +11842,11 @@ impl DescribeDBSubnetGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            DescribeDBSubnetGroupsError::DBSubnetGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeDBSubnetGroupsError::DBSubnetGroupNotFoundFault,
                         )
                     }
                     _ => {}
@@ -11812,10 +11999,11 @@ impl DescribeEventSubscriptionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "SubscriptionNotFound" => {
-                        return RusotoError::Service(
-                            DescribeEventSubscriptionsError::SubscriptionNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeEventSubscriptionsError::SubscriptionNotFoundFault,
                         )
                     }
                     _ => {}
@@ -11932,10 +12120,11 @@ impl DescribePendingMaintenanceActionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceNotFoundFault" => {
-                        return RusotoError::Service(
-                            DescribePendingMaintenanceActionsError::ResourceNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribePendingMaintenanceActionsError::ResourceNotFoundFault,
                         )
                     }
                     _ => {}
@@ -11984,17 +12173,19 @@ impl DescribeValidDBInstanceModificationsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
-                            DescribeValidDBInstanceModificationsError::DBInstanceNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeValidDBInstanceModificationsError::DBInstanceNotFoundFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            DescribeValidDBInstanceModificationsError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeValidDBInstanceModificationsError::InvalidDBInstanceStateFault,
                         )
                     }
                     _ => {}
@@ -12046,22 +12237,27 @@ impl FailoverDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            FailoverDBClusterError::DBClusterNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            FailoverDBClusterError::DBClusterNotFoundFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            FailoverDBClusterError::InvalidDBClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            FailoverDBClusterError::InvalidDBClusterStateFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            FailoverDBClusterError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            FailoverDBClusterError::InvalidDBInstanceStateFault,
                         )
                     }
                     _ => {}
@@ -12112,18 +12308,27 @@ impl ListTagsForResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            ListTagsForResourceError::DBClusterNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListTagsForResourceError::DBClusterNotFoundFault,
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
-                            ListTagsForResourceError::DBInstanceNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListTagsForResourceError::DBInstanceNotFoundFault,
                         )
                     }
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
-                            ListTagsForResourceError::DBSnapshotNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListTagsForResourceError::DBSnapshotNotFoundFault,
                         )
                     }
                     _ => {}
@@ -12188,64 +12393,91 @@ impl ModifyDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterAlreadyExistsFault" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterError::DBClusterAlreadyExistsFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterError::DBClusterAlreadyExistsFault,
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(ModifyDBClusterError::DBClusterNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyDBClusterError::DBClusterNotFoundFault,
+                        )
                     }
                     "DBClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterError::DBClusterParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterError::DBClusterParameterGroupNotFoundFault,
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterError::DBSubnetGroupNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterError::DBSubnetGroupNotFoundFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterError::InvalidDBClusterStateFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterError::InvalidDBInstanceStateFault,
                         )
                     }
                     "InvalidDBSecurityGroupState" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterError::InvalidDBSecurityGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterError::InvalidDBSecurityGroupStateFault,
                         )
                     }
                     "InvalidDBSubnetGroupStateFault" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterError::InvalidDBSubnetGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterError::InvalidDBSubnetGroupStateFault,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(ModifyDBClusterError::InvalidSubnet(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyDBClusterError::InvalidSubnet,
+                        )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterError::InvalidVPCNetworkStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterError::InvalidVPCNetworkStateFault,
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterError::StorageQuotaExceededFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterError::StorageQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -12312,38 +12544,43 @@ impl ModifyDBClusterEndpointError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterEndpointNotFoundFault" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterEndpointError::DBClusterEndpointNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterEndpointError::DBClusterEndpointNotFoundFault,
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterEndpointError::DBInstanceNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterEndpointError::DBInstanceNotFoundFault,
                         )
                     }
                     "InvalidDBClusterEndpointStateFault" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterEndpointError::InvalidDBClusterEndpointStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterEndpointError::InvalidDBClusterEndpointStateFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterEndpointError::InvalidDBClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterEndpointError::InvalidDBClusterStateFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterEndpointError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterEndpointError::InvalidDBInstanceStateFault,
                         )
                     }
                     _ => {}
@@ -12404,17 +12641,19 @@ impl ModifyDBClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterParameterGroupError::DBParameterGroupNotFoundFault,
                         )
                     }
                     "InvalidDBParameterGroupState" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterParameterGroupError::InvalidDBParameterGroupStateFault,
                         )
                     }
                     _ => {}
@@ -12468,22 +12707,25 @@ impl ModifyDBClusterSnapshotAttributeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterSnapshotAttributeError::DBClusterSnapshotNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterSnapshotAttributeError::DBClusterSnapshotNotFoundFault,
                         )
                     }
-                    "InvalidDBClusterSnapshotStateFault" => return RusotoError::Service(
-                        ModifyDBClusterSnapshotAttributeError::InvalidDBClusterSnapshotStateFault(
-                            parsed_error.message,
-                        ),
+                    "InvalidDBClusterSnapshotStateFault" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        ModifyDBClusterSnapshotAttributeError::InvalidDBClusterSnapshotStateFault,
                     ),
                     "SharedSnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterSnapshotAttributeError::SharedSnapshotQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterSnapshotAttributeError::SharedSnapshotQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -12564,103 +12806,131 @@ impl ModifyDBInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationNotFound" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::AuthorizationNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::AuthorizationNotFoundFault,
                         )
                     }
                     "CertificateNotFound" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::CertificateNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::CertificateNotFoundFault,
                         )
                     }
                     "DBInstanceAlreadyExists" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::DBInstanceAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::DBInstanceAlreadyExistsFault,
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::DBInstanceNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::DBInstanceNotFoundFault,
                         )
                     }
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::DBParameterGroupNotFoundFault,
                         )
                     }
                     "DBSecurityGroupNotFound" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::DBSecurityGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::DBSecurityGroupNotFoundFault,
                         )
                     }
                     "DBUpgradeDependencyFailure" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::DBUpgradeDependencyFailureFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::DBUpgradeDependencyFailureFault,
                         )
                     }
                     "DomainNotFoundFault" => {
-                        return RusotoError::Service(ModifyDBInstanceError::DomainNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyDBInstanceError::DomainNotFoundFault,
+                        )
                     }
                     "InsufficientDBInstanceCapacity" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::InsufficientDBInstanceCapacityFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::InsufficientDBInstanceCapacityFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::InvalidDBInstanceStateFault,
                         )
                     }
                     "InvalidDBSecurityGroupState" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::InvalidDBSecurityGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::InvalidDBSecurityGroupStateFault,
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::InvalidVPCNetworkStateFault,
                         )
                     }
                     "OptionGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::OptionGroupNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::OptionGroupNotFoundFault,
                         )
                     }
                     "ProvisionedIopsNotAvailableInAZFault" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::ProvisionedIopsNotAvailableInAZFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::ProvisionedIopsNotAvailableInAZFault,
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::StorageQuotaExceededFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::StorageQuotaExceededFault,
                         )
                     }
                     "StorageTypeNotSupported" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::StorageTypeNotSupportedFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::StorageTypeNotSupportedFault,
                         )
                     }
                     _ => {}
@@ -12736,17 +13006,19 @@ impl ModifyDBParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            ModifyDBParameterGroupError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBParameterGroupError::DBParameterGroupNotFoundFault,
                         )
                     }
                     "InvalidDBParameterGroupState" => {
-                        return RusotoError::Service(
-                            ModifyDBParameterGroupError::InvalidDBParameterGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBParameterGroupError::InvalidDBParameterGroupStateFault,
                         )
                     }
                     _ => {}
@@ -12802,35 +13074,44 @@ impl ModifyDBSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
-                        return RusotoError::Service(
-                            ModifyDBSubnetGroupError::DBSubnetGroupDoesNotCoverEnoughAZs(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBSubnetGroupError::DBSubnetGroupDoesNotCoverEnoughAZs,
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            ModifyDBSubnetGroupError::DBSubnetGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBSubnetGroupError::DBSubnetGroupNotFoundFault,
                         )
                     }
                     "DBSubnetQuotaExceededFault" => {
-                        return RusotoError::Service(
-                            ModifyDBSubnetGroupError::DBSubnetQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBSubnetGroupError::DBSubnetQuotaExceededFault,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(ModifyDBSubnetGroupError::InvalidSubnet(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyDBSubnetGroupError::InvalidSubnet,
+                        )
                     }
                     "SubnetAlreadyInUse" => {
-                        return RusotoError::Service(ModifyDBSubnetGroupError::SubnetAlreadyInUse(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyDBSubnetGroupError::SubnetAlreadyInUse,
+                        )
                     }
                     _ => {}
                 }
@@ -12892,45 +13173,51 @@ impl ModifyEventSubscriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EventSubscriptionQuotaExceeded" => {
-                        return RusotoError::Service(
-                            ModifyEventSubscriptionError::EventSubscriptionQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyEventSubscriptionError::EventSubscriptionQuotaExceededFault,
                         )
                     }
                     "SNSInvalidTopic" => {
-                        return RusotoError::Service(
-                            ModifyEventSubscriptionError::SNSInvalidTopicFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyEventSubscriptionError::SNSInvalidTopicFault,
                         )
                     }
                     "SNSNoAuthorization" => {
-                        return RusotoError::Service(
-                            ModifyEventSubscriptionError::SNSNoAuthorizationFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyEventSubscriptionError::SNSNoAuthorizationFault,
                         )
                     }
                     "SNSTopicArnNotFound" => {
-                        return RusotoError::Service(
-                            ModifyEventSubscriptionError::SNSTopicArnNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyEventSubscriptionError::SNSTopicArnNotFoundFault,
                         )
                     }
                     "SubscriptionCategoryNotFound" => {
-                        return RusotoError::Service(
-                            ModifyEventSubscriptionError::SubscriptionCategoryNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyEventSubscriptionError::SubscriptionCategoryNotFoundFault,
                         )
                     }
                     "SubscriptionNotFound" => {
-                        return RusotoError::Service(
-                            ModifyEventSubscriptionError::SubscriptionNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyEventSubscriptionError::SubscriptionNotFoundFault,
                         )
                     }
                     _ => {}
@@ -12992,17 +13279,19 @@ impl PromoteReadReplicaDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            PromoteReadReplicaDBClusterError::DBClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            PromoteReadReplicaDBClusterError::DBClusterNotFoundFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            PromoteReadReplicaDBClusterError::InvalidDBClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            PromoteReadReplicaDBClusterError::InvalidDBClusterStateFault,
                         )
                     }
                     _ => {}
@@ -13052,15 +13341,19 @@ impl RebootDBInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
-                            RebootDBInstanceError::DBInstanceNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RebootDBInstanceError::DBInstanceNotFoundFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            RebootDBInstanceError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RebootDBInstanceError::InvalidDBInstanceStateFault,
                         )
                     }
                     _ => {}
@@ -13108,24 +13401,27 @@ impl RemoveRoleFromDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            RemoveRoleFromDBClusterError::DBClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RemoveRoleFromDBClusterError::DBClusterNotFoundFault,
                         )
                     }
                     "DBClusterRoleNotFound" => {
-                        return RusotoError::Service(
-                            RemoveRoleFromDBClusterError::DBClusterRoleNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RemoveRoleFromDBClusterError::DBClusterRoleNotFoundFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            RemoveRoleFromDBClusterError::InvalidDBClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RemoveRoleFromDBClusterError::InvalidDBClusterStateFault,
                         )
                     }
                     _ => {}
@@ -13180,17 +13476,19 @@ impl RemoveSourceIdentifierFromSubscriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "SourceNotFound" => {
-                        return RusotoError::Service(
-                            RemoveSourceIdentifierFromSubscriptionError::SourceNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RemoveSourceIdentifierFromSubscriptionError::SourceNotFoundFault,
                         )
                     }
                     "SubscriptionNotFound" => {
-                        return RusotoError::Service(
-                            RemoveSourceIdentifierFromSubscriptionError::SubscriptionNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RemoveSourceIdentifierFromSubscriptionError::SubscriptionNotFoundFault,
                         )
                     }
                     _ => {}
@@ -13242,24 +13540,27 @@ impl RemoveTagsFromResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            RemoveTagsFromResourceError::DBClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RemoveTagsFromResourceError::DBClusterNotFoundFault,
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
-                            RemoveTagsFromResourceError::DBInstanceNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RemoveTagsFromResourceError::DBInstanceNotFoundFault,
                         )
                     }
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
-                            RemoveTagsFromResourceError::DBSnapshotNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RemoveTagsFromResourceError::DBSnapshotNotFoundFault,
                         )
                     }
                     _ => {}
@@ -13314,17 +13615,19 @@ impl ResetDBClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            ResetDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ResetDBClusterParameterGroupError::DBParameterGroupNotFoundFault,
                         )
                     }
                     "InvalidDBParameterGroupState" => {
-                        return RusotoError::Service(
-                            ResetDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ResetDBClusterParameterGroupError::InvalidDBParameterGroupStateFault,
                         )
                     }
                     _ => {}
@@ -13374,17 +13677,19 @@ impl ResetDBParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            ResetDBParameterGroupError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ResetDBParameterGroupError::DBParameterGroupNotFoundFault,
                         )
                     }
                     "InvalidDBParameterGroupState" => {
-                        return RusotoError::Service(
-                            ResetDBParameterGroupError::InvalidDBParameterGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ResetDBParameterGroupError::InvalidDBParameterGroupStateFault,
                         )
                     }
                     _ => {}
@@ -13464,111 +13769,129 @@ impl RestoreDBClusterFromSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterAlreadyExistsFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::DBClusterAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::DBClusterAlreadyExistsFault,
                         )
                     }
                     "DBClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::DBClusterParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::DBClusterParameterGroupNotFoundFault,
                         )
                     }
                     "DBClusterQuotaExceededFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::DBClusterQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::DBClusterQuotaExceededFault,
                         )
                     }
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::DBClusterSnapshotNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::DBClusterSnapshotNotFoundFault,
                         )
                     }
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::DBSnapshotNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::DBSnapshotNotFoundFault,
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::DBSubnetGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::DBSubnetGroupNotFoundFault,
                         )
                     }
                     "InsufficientDBClusterCapacityFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::InsufficientDBClusterCapacityFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::InsufficientDBClusterCapacityFault,
                         )
                     }
-                    "InsufficientStorageClusterCapacity" => return RusotoError::Service(
-                        RestoreDBClusterFromSnapshotError::InsufficientStorageClusterCapacityFault(
-                            parsed_error.message,
-                        ),
+                    "InsufficientStorageClusterCapacity" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        RestoreDBClusterFromSnapshotError::InsufficientStorageClusterCapacityFault,
                     ),
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::InvalidDBClusterSnapshotStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::InvalidDBClusterSnapshotStateFault,
                         )
                     }
                     "InvalidDBSnapshotState" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::InvalidDBSnapshotStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::InvalidDBSnapshotStateFault,
                         )
                     }
                     "InvalidRestoreFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::InvalidRestoreFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::InvalidRestoreFault,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::InvalidSubnet(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::InvalidSubnet,
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::InvalidVPCNetworkStateFault,
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::KMSKeyNotAccessibleFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::KMSKeyNotAccessibleFault,
                         )
                     }
                     "OptionGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::OptionGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::OptionGroupNotFoundFault,
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::StorageQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::StorageQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -13690,116 +14013,135 @@ impl RestoreDBClusterToPointInTimeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterAlreadyExistsFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::DBClusterAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::DBClusterAlreadyExistsFault,
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::DBClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::DBClusterNotFoundFault,
                         )
                     }
-                    "DBClusterParameterGroupNotFound" => return RusotoError::Service(
-                        RestoreDBClusterToPointInTimeError::DBClusterParameterGroupNotFoundFault(
-                            parsed_error.message,
-                        ),
+                    "DBClusterParameterGroupNotFound" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        RestoreDBClusterToPointInTimeError::DBClusterParameterGroupNotFoundFault,
                     ),
                     "DBClusterQuotaExceededFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::DBClusterQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::DBClusterQuotaExceededFault,
                         )
                     }
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::DBClusterSnapshotNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::DBClusterSnapshotNotFoundFault,
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::DBSubnetGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::DBSubnetGroupNotFoundFault,
                         )
                     }
                     "InsufficientDBClusterCapacityFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::InsufficientDBClusterCapacityFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::InsufficientDBClusterCapacityFault,
                         )
                     }
-                    "InsufficientStorageClusterCapacity" => return RusotoError::Service(
-                        RestoreDBClusterToPointInTimeError::InsufficientStorageClusterCapacityFault(
-                            parsed_error.message,
-                        ),
+                    "InsufficientStorageClusterCapacity" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        RestoreDBClusterToPointInTimeError::InsufficientStorageClusterCapacityFault,
                     ),
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::InvalidDBClusterSnapshotStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::InvalidDBClusterSnapshotStateFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::InvalidDBClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::InvalidDBClusterStateFault,
                         )
                     }
                     "InvalidDBSnapshotState" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::InvalidDBSnapshotStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::InvalidDBSnapshotStateFault,
                         )
                     }
                     "InvalidRestoreFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::InvalidRestoreFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::InvalidRestoreFault,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::InvalidSubnet(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::InvalidSubnet,
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::InvalidVPCNetworkStateFault,
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::KMSKeyNotAccessibleFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::KMSKeyNotAccessibleFault,
                         )
                     }
                     "OptionGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::OptionGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::OptionGroupNotFoundFault,
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::StorageQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::StorageQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -13894,18 +14236,27 @@ impl StartDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(StartDBClusterError::DBClusterNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            StartDBClusterError::DBClusterNotFoundFault,
+                        )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            StartDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StartDBClusterError::InvalidDBClusterStateFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            StartDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StartDBClusterError::InvalidDBInstanceStateFault,
                         )
                     }
                     _ => {}
@@ -13954,18 +14305,27 @@ impl StopDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(StopDBClusterError::DBClusterNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            StopDBClusterError::DBClusterNotFoundFault,
+                        )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            StopDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StopDBClusterError::InvalidDBClusterStateFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            StopDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StopDBClusterError::InvalidDBInstanceStateFault,
                         )
                     }
                     _ => {}
@@ -14409,11 +14769,12 @@ pub struct NeptuneClient {
 impl NeptuneClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> NeptuneClient {
         NeptuneClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Neptune"),
         }
     }
 
@@ -14428,12 +14789,32 @@ impl NeptuneClient {
     {
         NeptuneClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Neptune"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> NeptuneClient {
-        NeptuneClient { client, region }
+        NeptuneClient {
+            client,
+            region: region_for_service(region, "Neptune"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> NeptuneClient {
+        NeptuneClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Neptune"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> NeptuneClient {
+        NeptuneClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -14445,6 +14826,7 @@ impl Neptune for NeptuneClient {
         input: AddRoleToDBClusterMessage,
     ) -> Result<(), RusotoError<AddRoleToDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("AddRoleToDBCluster");
         let params = self.new_params("AddRoleToDBCluster");
         let mut params = params;
         AddRoleToDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -14468,6 +14850,7 @@ impl Neptune for NeptuneClient {
         RusotoError<AddSourceIdentifierToSubscriptionError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("AddSourceIdentifierToSubscription");
         let params = self.new_params("AddSourceIdentifierToSubscription");
         let mut params = params;
         AddSourceIdentifierToSubscriptionMessageSerializer::serialize(&mut params, "", &input);
@@ -14504,6 +14887,7 @@ impl Neptune for NeptuneClient {
         input: AddTagsToResourceMessage,
     ) -> Result<(), RusotoError<AddTagsToResourceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("AddTagsToResource");
         let params = self.new_params("AddTagsToResource");
         let mut params = params;
         AddTagsToResourceMessageSerializer::serialize(&mut params, "", &input);
@@ -14525,6 +14909,7 @@ impl Neptune for NeptuneClient {
     ) -> Result<ApplyPendingMaintenanceActionResult, RusotoError<ApplyPendingMaintenanceActionError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ApplyPendingMaintenanceAction");
         let params = self.new_params("ApplyPendingMaintenanceAction");
         let mut params = params;
         ApplyPendingMaintenanceActionMessageSerializer::serialize(&mut params, "", &input);
@@ -14559,6 +14944,7 @@ impl Neptune for NeptuneClient {
     ) -> Result<CopyDBClusterParameterGroupResult, RusotoError<CopyDBClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CopyDBClusterParameterGroup");
         let params = self.new_params("CopyDBClusterParameterGroup");
         let mut params = params;
         CopyDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -14592,6 +14978,7 @@ impl Neptune for NeptuneClient {
         input: CopyDBClusterSnapshotMessage,
     ) -> Result<CopyDBClusterSnapshotResult, RusotoError<CopyDBClusterSnapshotError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CopyDBClusterSnapshot");
         let params = self.new_params("CopyDBClusterSnapshot");
         let mut params = params;
         CopyDBClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -14625,6 +15012,7 @@ impl Neptune for NeptuneClient {
         input: CopyDBParameterGroupMessage,
     ) -> Result<CopyDBParameterGroupResult, RusotoError<CopyDBParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CopyDBParameterGroup");
         let params = self.new_params("CopyDBParameterGroup");
         let mut params = params;
         CopyDBParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -14658,6 +15046,7 @@ impl Neptune for NeptuneClient {
         input: CreateDBClusterMessage,
     ) -> Result<CreateDBClusterResult, RusotoError<CreateDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBCluster");
         let params = self.new_params("CreateDBCluster");
         let mut params = params;
         CreateDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -14689,6 +15078,7 @@ impl Neptune for NeptuneClient {
         input: CreateDBClusterEndpointMessage,
     ) -> Result<CreateDBClusterEndpointOutput, RusotoError<CreateDBClusterEndpointError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBClusterEndpoint");
         let params = self.new_params("CreateDBClusterEndpoint");
         let mut params = params;
         CreateDBClusterEndpointMessageSerializer::serialize(&mut params, "", &input);
@@ -14723,6 +15113,7 @@ impl Neptune for NeptuneClient {
     ) -> Result<CreateDBClusterParameterGroupResult, RusotoError<CreateDBClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBClusterParameterGroup");
         let params = self.new_params("CreateDBClusterParameterGroup");
         let mut params = params;
         CreateDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -14756,6 +15147,7 @@ impl Neptune for NeptuneClient {
         input: CreateDBClusterSnapshotMessage,
     ) -> Result<CreateDBClusterSnapshotResult, RusotoError<CreateDBClusterSnapshotError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBClusterSnapshot");
         let params = self.new_params("CreateDBClusterSnapshot");
         let mut params = params;
         CreateDBClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -14789,6 +15181,7 @@ impl Neptune for NeptuneClient {
         input: CreateDBInstanceMessage,
     ) -> Result<CreateDBInstanceResult, RusotoError<CreateDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBInstance");
         let params = self.new_params("CreateDBInstance");
         let mut params = params;
         CreateDBInstanceMessageSerializer::serialize(&mut params, "", &input);
@@ -14820,6 +15213,7 @@ impl Neptune for NeptuneClient {
         input: CreateDBParameterGroupMessage,
     ) -> Result<CreateDBParameterGroupResult, RusotoError<CreateDBParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBParameterGroup");
         let params = self.new_params("CreateDBParameterGroup");
         let mut params = params;
         CreateDBParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -14853,6 +15247,7 @@ impl Neptune for NeptuneClient {
         input: CreateDBSubnetGroupMessage,
     ) -> Result<CreateDBSubnetGroupResult, RusotoError<CreateDBSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBSubnetGroup");
         let params = self.new_params("CreateDBSubnetGroup");
         let mut params = params;
         CreateDBSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -14886,6 +15281,7 @@ impl Neptune for NeptuneClient {
         input: CreateEventSubscriptionMessage,
     ) -> Result<CreateEventSubscriptionResult, RusotoError<CreateEventSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateEventSubscription");
         let params = self.new_params("CreateEventSubscription");
         let mut params = params;
         CreateEventSubscriptionMessageSerializer::serialize(&mut params, "", &input);
@@ -14919,6 +15315,7 @@ impl Neptune for NeptuneClient {
         input: DeleteDBClusterMessage,
     ) -> Result<DeleteDBClusterResult, RusotoError<DeleteDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBCluster");
         let params = self.new_params("DeleteDBCluster");
         let mut params = params;
         DeleteDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -14950,6 +15347,7 @@ impl Neptune for NeptuneClient {
         input: DeleteDBClusterEndpointMessage,
     ) -> Result<DeleteDBClusterEndpointOutput, RusotoError<DeleteDBClusterEndpointError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBClusterEndpoint");
         let params = self.new_params("DeleteDBClusterEndpoint");
         let mut params = params;
         DeleteDBClusterEndpointMessageSerializer::serialize(&mut params, "", &input);
@@ -14983,6 +15381,7 @@ impl Neptune for NeptuneClient {
         input: DeleteDBClusterParameterGroupMessage,
     ) -> Result<(), RusotoError<DeleteDBClusterParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBClusterParameterGroup");
         let params = self.new_params("DeleteDBClusterParameterGroup");
         let mut params = params;
         DeleteDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -15003,6 +15402,7 @@ impl Neptune for NeptuneClient {
         input: DeleteDBClusterSnapshotMessage,
     ) -> Result<DeleteDBClusterSnapshotResult, RusotoError<DeleteDBClusterSnapshotError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBClusterSnapshot");
         let params = self.new_params("DeleteDBClusterSnapshot");
         let mut params = params;
         DeleteDBClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -15036,6 +15436,7 @@ impl Neptune for NeptuneClient {
         input: DeleteDBInstanceMessage,
     ) -> Result<DeleteDBInstanceResult, RusotoError<DeleteDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBInstance");
         let params = self.new_params("DeleteDBInstance");
         let mut params = params;
         DeleteDBInstanceMessageSerializer::serialize(&mut params, "", &input);
@@ -15067,6 +15468,7 @@ impl Neptune for NeptuneClient {
         input: DeleteDBParameterGroupMessage,
     ) -> Result<(), RusotoError<DeleteDBParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBParameterGroup");
         let params = self.new_params("DeleteDBParameterGroup");
         let mut params = params;
         DeleteDBParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -15087,6 +15489,7 @@ impl Neptune for NeptuneClient {
         input: DeleteDBSubnetGroupMessage,
     ) -> Result<(), RusotoError<DeleteDBSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBSubnetGroup");
         let params = self.new_params("DeleteDBSubnetGroup");
         let mut params = params;
         DeleteDBSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -15107,6 +15510,7 @@ impl Neptune for NeptuneClient {
         input: DeleteEventSubscriptionMessage,
     ) -> Result<DeleteEventSubscriptionResult, RusotoError<DeleteEventSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteEventSubscription");
         let params = self.new_params("DeleteEventSubscription");
         let mut params = params;
         DeleteEventSubscriptionMessageSerializer::serialize(&mut params, "", &input);
@@ -15140,6 +15544,7 @@ impl Neptune for NeptuneClient {
         input: DescribeDBClusterEndpointsMessage,
     ) -> Result<DBClusterEndpointMessage, RusotoError<DescribeDBClusterEndpointsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterEndpoints");
         let params = self.new_params("DescribeDBClusterEndpoints");
         let mut params = params;
         DescribeDBClusterEndpointsMessageSerializer::serialize(&mut params, "", &input);
@@ -15174,6 +15579,7 @@ impl Neptune for NeptuneClient {
     ) -> Result<DBClusterParameterGroupsMessage, RusotoError<DescribeDBClusterParameterGroupsError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterParameterGroups");
         let params = self.new_params("DescribeDBClusterParameterGroups");
         let mut params = params;
         DescribeDBClusterParameterGroupsMessageSerializer::serialize(&mut params, "", &input);
@@ -15210,6 +15616,7 @@ impl Neptune for NeptuneClient {
         input: DescribeDBClusterParametersMessage,
     ) -> Result<DBClusterParameterGroupDetails, RusotoError<DescribeDBClusterParametersError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterParameters");
         let params = self.new_params("DescribeDBClusterParameters");
         let mut params = params;
         DescribeDBClusterParametersMessageSerializer::serialize(&mut params, "", &input);
@@ -15246,6 +15653,7 @@ impl Neptune for NeptuneClient {
         RusotoError<DescribeDBClusterSnapshotAttributesError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterSnapshotAttributes");
         let params = self.new_params("DescribeDBClusterSnapshotAttributes");
         let mut params = params;
         DescribeDBClusterSnapshotAttributesMessageSerializer::serialize(&mut params, "", &input);
@@ -15282,6 +15690,7 @@ impl Neptune for NeptuneClient {
         input: DescribeDBClusterSnapshotsMessage,
     ) -> Result<DBClusterSnapshotMessage, RusotoError<DescribeDBClusterSnapshotsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterSnapshots");
         let params = self.new_params("DescribeDBClusterSnapshots");
         let mut params = params;
         DescribeDBClusterSnapshotsMessageSerializer::serialize(&mut params, "", &input);
@@ -15315,6 +15724,7 @@ impl Neptune for NeptuneClient {
         input: DescribeDBClustersMessage,
     ) -> Result<DBClusterMessage, RusotoError<DescribeDBClustersError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusters");
         let params = self.new_params("DescribeDBClusters");
         let mut params = params;
         DescribeDBClustersMessageSerializer::serialize(&mut params, "", &input);
@@ -15346,6 +15756,7 @@ impl Neptune for NeptuneClient {
         input: DescribeDBEngineVersionsMessage,
     ) -> Result<DBEngineVersionMessage, RusotoError<DescribeDBEngineVersionsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBEngineVersions");
         let params = self.new_params("DescribeDBEngineVersions");
         let mut params = params;
         DescribeDBEngineVersionsMessageSerializer::serialize(&mut params, "", &input);
@@ -15379,6 +15790,7 @@ impl Neptune for NeptuneClient {
         input: DescribeDBInstancesMessage,
     ) -> Result<DBInstanceMessage, RusotoError<DescribeDBInstancesError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBInstances");
         let params = self.new_params("DescribeDBInstances");
         let mut params = params;
         DescribeDBInstancesMessageSerializer::serialize(&mut params, "", &input);
@@ -15410,6 +15822,7 @@ impl Neptune for NeptuneClient {
         input: DescribeDBParameterGroupsMessage,
     ) -> Result<DBParameterGroupsMessage, RusotoError<DescribeDBParameterGroupsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBParameterGroups");
         let params = self.new_params("DescribeDBParameterGroups");
         let mut params = params;
         DescribeDBParameterGroupsMessageSerializer::serialize(&mut params, "", &input);
@@ -15443,6 +15856,7 @@ impl Neptune for NeptuneClient {
         input: DescribeDBParametersMessage,
     ) -> Result<DBParameterGroupDetails, RusotoError<DescribeDBParametersError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBParameters");
         let params = self.new_params("DescribeDBParameters");
         let mut params = params;
         DescribeDBParametersMessageSerializer::serialize(&mut params, "", &input);
@@ -15476,6 +15890,7 @@ impl Neptune for NeptuneClient {
         input: DescribeDBSubnetGroupsMessage,
     ) -> Result<DBSubnetGroupMessage, RusotoError<DescribeDBSubnetGroupsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBSubnetGroups");
         let params = self.new_params("DescribeDBSubnetGroups");
         let mut params = params;
         DescribeDBSubnetGroupsMessageSerializer::serialize(&mut params, "", &input);
@@ -15512,6 +15927,7 @@ impl Neptune for NeptuneClient {
         RusotoError<DescribeEngineDefaultClusterParametersError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeEngineDefaultClusterParameters");
         let params = self.new_params("DescribeEngineDefaultClusterParameters");
         let mut params = params;
         DescribeEngineDefaultClusterParametersMessageSerializer::serialize(&mut params, "", &input);
@@ -15551,6 +15967,7 @@ impl Neptune for NeptuneClient {
         RusotoError<DescribeEngineDefaultParametersError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeEngineDefaultParameters");
         let params = self.new_params("DescribeEngineDefaultParameters");
         let mut params = params;
         DescribeEngineDefaultParametersMessageSerializer::serialize(&mut params, "", &input);
@@ -15584,6 +16001,7 @@ impl Neptune for NeptuneClient {
         input: DescribeEventCategoriesMessage,
     ) -> Result<EventCategoriesMessage, RusotoError<DescribeEventCategoriesError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeEventCategories");
         let params = self.new_params("DescribeEventCategories");
         let mut params = params;
         DescribeEventCategoriesMessageSerializer::serialize(&mut params, "", &input);
@@ -15617,6 +16035,7 @@ impl Neptune for NeptuneClient {
         input: DescribeEventSubscriptionsMessage,
     ) -> Result<EventSubscriptionsMessage, RusotoError<DescribeEventSubscriptionsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeEventSubscriptions");
         let params = self.new_params("DescribeEventSubscriptions");
         let mut params = params;
         DescribeEventSubscriptionsMessageSerializer::serialize(&mut params, "", &input);
@@ -15650,6 +16069,7 @@ impl Neptune for NeptuneClient {
         input: DescribeEventsMessage,
     ) -> Result<EventsMessage, RusotoError<DescribeEventsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeEvents");
         let params = self.new_params("DescribeEvents");
         let mut params = params;
         DescribeEventsMessageSerializer::serialize(&mut params, "", &input);
@@ -15683,6 +16103,7 @@ impl Neptune for NeptuneClient {
         RusotoError<DescribeOrderableDBInstanceOptionsError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeOrderableDBInstanceOptions");
         let params = self.new_params("DescribeOrderableDBInstanceOptions");
         let mut params = params;
         DescribeOrderableDBInstanceOptionsMessageSerializer::serialize(&mut params, "", &input);
@@ -15720,6 +16141,7 @@ impl Neptune for NeptuneClient {
     ) -> Result<PendingMaintenanceActionsMessage, RusotoError<DescribePendingMaintenanceActionsError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribePendingMaintenanceActions");
         let params = self.new_params("DescribePendingMaintenanceActions");
         let mut params = params;
         DescribePendingMaintenanceActionsMessageSerializer::serialize(&mut params, "", &input);
@@ -15759,6 +16181,7 @@ impl Neptune for NeptuneClient {
         RusotoError<DescribeValidDBInstanceModificationsError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeValidDBInstanceModifications");
         let params = self.new_params("DescribeValidDBInstanceModifications");
         let mut params = params;
         DescribeValidDBInstanceModificationsMessageSerializer::serialize(&mut params, "", &input);
@@ -15795,6 +16218,7 @@ impl Neptune for NeptuneClient {
         input: FailoverDBClusterMessage,
     ) -> Result<FailoverDBClusterResult, RusotoError<FailoverDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("FailoverDBCluster");
         let params = self.new_params("FailoverDBCluster");
         let mut params = params;
         FailoverDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -15826,6 +16250,7 @@ impl Neptune for NeptuneClient {
         input: ListTagsForResourceMessage,
     ) -> Result<TagListMessage, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ListTagsForResource");
         let params = self.new_params("ListTagsForResource");
         let mut params = params;
         ListTagsForResourceMessageSerializer::serialize(&mut params, "", &input);
@@ -15857,6 +16282,7 @@ impl Neptune for NeptuneClient {
         input: ModifyDBClusterMessage,
     ) -> Result<ModifyDBClusterResult, RusotoError<ModifyDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBCluster");
         let params = self.new_params("ModifyDBCluster");
         let mut params = params;
         ModifyDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -15888,6 +16314,7 @@ impl Neptune for NeptuneClient {
         input: ModifyDBClusterEndpointMessage,
     ) -> Result<ModifyDBClusterEndpointOutput, RusotoError<ModifyDBClusterEndpointError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBClusterEndpoint");
         let params = self.new_params("ModifyDBClusterEndpoint");
         let mut params = params;
         ModifyDBClusterEndpointMessageSerializer::serialize(&mut params, "", &input);
@@ -15922,6 +16349,7 @@ impl Neptune for NeptuneClient {
     ) -> Result<DBClusterParameterGroupNameMessage, RusotoError<ModifyDBClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBClusterParameterGroup");
         let params = self.new_params("ModifyDBClusterParameterGroup");
         let mut params = params;
         ModifyDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -15958,6 +16386,7 @@ impl Neptune for NeptuneClient {
         RusotoError<ModifyDBClusterSnapshotAttributeError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBClusterSnapshotAttribute");
         let params = self.new_params("ModifyDBClusterSnapshotAttribute");
         let mut params = params;
         ModifyDBClusterSnapshotAttributeMessageSerializer::serialize(&mut params, "", &input);
@@ -15994,6 +16423,7 @@ impl Neptune for NeptuneClient {
         input: ModifyDBInstanceMessage,
     ) -> Result<ModifyDBInstanceResult, RusotoError<ModifyDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBInstance");
         let params = self.new_params("ModifyDBInstance");
         let mut params = params;
         ModifyDBInstanceMessageSerializer::serialize(&mut params, "", &input);
@@ -16025,6 +16455,7 @@ impl Neptune for NeptuneClient {
         input: ModifyDBParameterGroupMessage,
     ) -> Result<DBParameterGroupNameMessage, RusotoError<ModifyDBParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBParameterGroup");
         let params = self.new_params("ModifyDBParameterGroup");
         let mut params = params;
         ModifyDBParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -16058,6 +16489,7 @@ impl Neptune for NeptuneClient {
         input: ModifyDBSubnetGroupMessage,
     ) -> Result<ModifyDBSubnetGroupResult, RusotoError<ModifyDBSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBSubnetGroup");
         let params = self.new_params("ModifyDBSubnetGroup");
         let mut params = params;
         ModifyDBSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -16091,6 +16523,7 @@ impl Neptune for NeptuneClient {
         input: ModifyEventSubscriptionMessage,
     ) -> Result<ModifyEventSubscriptionResult, RusotoError<ModifyEventSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyEventSubscription");
         let params = self.new_params("ModifyEventSubscription");
         let mut params = params;
         ModifyEventSubscriptionMessageSerializer::serialize(&mut params, "", &input);
@@ -16125,6 +16558,7 @@ impl Neptune for NeptuneClient {
     ) -> Result<PromoteReadReplicaDBClusterResult, RusotoError<PromoteReadReplicaDBClusterError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("PromoteReadReplicaDBCluster");
         let params = self.new_params("PromoteReadReplicaDBCluster");
         let mut params = params;
         PromoteReadReplicaDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -16158,6 +16592,7 @@ impl Neptune for NeptuneClient {
         input: RebootDBInstanceMessage,
     ) -> Result<RebootDBInstanceResult, RusotoError<RebootDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RebootDBInstance");
         let params = self.new_params("RebootDBInstance");
         let mut params = params;
         RebootDBInstanceMessageSerializer::serialize(&mut params, "", &input);
@@ -16189,6 +16624,7 @@ impl Neptune for NeptuneClient {
         input: RemoveRoleFromDBClusterMessage,
     ) -> Result<(), RusotoError<RemoveRoleFromDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RemoveRoleFromDBCluster");
         let params = self.new_params("RemoveRoleFromDBCluster");
         let mut params = params;
         RemoveRoleFromDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -16212,6 +16648,7 @@ impl Neptune for NeptuneClient {
         RusotoError<RemoveSourceIdentifierFromSubscriptionError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RemoveSourceIdentifierFromSubscription");
         let params = self.new_params("RemoveSourceIdentifierFromSubscription");
         let mut params = params;
         RemoveSourceIdentifierFromSubscriptionMessageSerializer::serialize(&mut params, "", &input);
@@ -16248,6 +16685,7 @@ impl Neptune for NeptuneClient {
         input: RemoveTagsFromResourceMessage,
     ) -> Result<(), RusotoError<RemoveTagsFromResourceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RemoveTagsFromResource");
         let params = self.new_params("RemoveTagsFromResource");
         let mut params = params;
         RemoveTagsFromResourceMessageSerializer::serialize(&mut params, "", &input);
@@ -16269,6 +16707,7 @@ impl Neptune for NeptuneClient {
     ) -> Result<DBClusterParameterGroupNameMessage, RusotoError<ResetDBClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ResetDBClusterParameterGroup");
         let params = self.new_params("ResetDBClusterParameterGroup");
         let mut params = params;
         ResetDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -16302,6 +16741,7 @@ impl Neptune for NeptuneClient {
         input: ResetDBParameterGroupMessage,
     ) -> Result<DBParameterGroupNameMessage, RusotoError<ResetDBParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ResetDBParameterGroup");
         let params = self.new_params("ResetDBParameterGroup");
         let mut params = params;
         ResetDBParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -16336,6 +16776,7 @@ impl Neptune for NeptuneClient {
     ) -> Result<RestoreDBClusterFromSnapshotResult, RusotoError<RestoreDBClusterFromSnapshotError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RestoreDBClusterFromSnapshot");
         let params = self.new_params("RestoreDBClusterFromSnapshot");
         let mut params = params;
         RestoreDBClusterFromSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -16370,6 +16811,7 @@ impl Neptune for NeptuneClient {
     ) -> Result<RestoreDBClusterToPointInTimeResult, RusotoError<RestoreDBClusterToPointInTimeError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RestoreDBClusterToPointInTime");
         let params = self.new_params("RestoreDBClusterToPointInTime");
         let mut params = params;
         RestoreDBClusterToPointInTimeMessageSerializer::serialize(&mut params, "", &input);
@@ -16403,6 +16845,7 @@ impl Neptune for NeptuneClient {
         input: StartDBClusterMessage,
     ) -> Result<StartDBClusterResult, RusotoError<StartDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("StartDBCluster");
         let params = self.new_params("StartDBCluster");
         let mut params = params;
         StartDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -16434,6 +16877,7 @@ impl Neptune for NeptuneClient {
         input: StopDBClusterMessage,
     ) -> Result<StopDBClusterResult, RusotoError<StopDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("StopDBCluster");
         let params = self.new_params("StopDBCluster");
         let mut params = params;
         StopDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -16459,3 +16903,673 @@ impl Neptune for NeptuneClient {
         Ok(result)
     }
 }
+
+/// A blocking client for the Amazon Neptune API, running the calls of a `NeptuneClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `NeptuneClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct NeptuneBlockingClient {
+    client: NeptuneClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl NeptuneBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> NeptuneBlockingClient {
+        NeptuneBlockingClient::from_client(NeptuneClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> NeptuneBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        NeptuneBlockingClient::from_client(NeptuneClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> NeptuneBlockingClient {
+        NeptuneBlockingClient::from_client(NeptuneClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> NeptuneBlockingClient {
+        NeptuneBlockingClient::from_client(NeptuneClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: NeptuneClient) -> NeptuneBlockingClient {
+        NeptuneBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> NeptuneBlockingClient {
+        NeptuneBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> NeptuneBlockingClient {
+        NeptuneBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Neptune::add_role_to_db_cluster`].
+    pub fn add_role_to_db_cluster(
+        &self,
+        input: AddRoleToDBClusterMessage,
+    ) -> Result<(), RusotoError<AddRoleToDBClusterError>> {
+        self.runtime
+            .block_on(self.client.add_role_to_db_cluster(input))
+    }
+
+    /// Blocking version of [`Neptune::add_source_identifier_to_subscription`].
+    pub fn add_source_identifier_to_subscription(
+        &self,
+        input: AddSourceIdentifierToSubscriptionMessage,
+    ) -> Result<
+        AddSourceIdentifierToSubscriptionResult,
+        RusotoError<AddSourceIdentifierToSubscriptionError>,
+    > {
+        self.runtime
+            .block_on(self.client.add_source_identifier_to_subscription(input))
+    }
+
+    /// Blocking version of [`Neptune::add_tags_to_resource`].
+    pub fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceMessage,
+    ) -> Result<(), RusotoError<AddTagsToResourceError>> {
+        self.runtime
+            .block_on(self.client.add_tags_to_resource(input))
+    }
+
+    /// Blocking version of [`Neptune::apply_pending_maintenance_action`].
+    pub fn apply_pending_maintenance_action(
+        &self,
+        input: ApplyPendingMaintenanceActionMessage,
+    ) -> Result<ApplyPendingMaintenanceActionResult, RusotoError<ApplyPendingMaintenanceActionError>>
+    {
+        self.runtime
+            .block_on(self.client.apply_pending_maintenance_action(input))
+    }
+
+    /// Blocking version of [`Neptune::copy_db_cluster_parameter_group`].
+    pub fn copy_db_cluster_parameter_group(
+        &self,
+        input: CopyDBClusterParameterGroupMessage,
+    ) -> Result<CopyDBClusterParameterGroupResult, RusotoError<CopyDBClusterParameterGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.copy_db_cluster_parameter_group(input))
+    }
+
+    /// Blocking version of [`Neptune::copy_db_cluster_snapshot`].
+    pub fn copy_db_cluster_snapshot(
+        &self,
+        input: CopyDBClusterSnapshotMessage,
+    ) -> Result<CopyDBClusterSnapshotResult, RusotoError<CopyDBClusterSnapshotError>> {
+        self.runtime
+            .block_on(self.client.copy_db_cluster_snapshot(input))
+    }
+
+    /// Blocking version of [`Neptune::copy_db_parameter_group`].
+    pub fn copy_db_parameter_group(
+        &self,
+        input: CopyDBParameterGroupMessage,
+    ) -> Result<CopyDBParameterGroupResult, RusotoError<CopyDBParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.copy_db_parameter_group(input))
+    }
+
+    /// Blocking version of [`Neptune::create_db_cluster`].
+    pub fn create_db_cluster(
+        &self,
+        input: CreateDBClusterMessage,
+    ) -> Result<CreateDBClusterResult, RusotoError<CreateDBClusterError>> {
+        self.runtime.block_on(self.client.create_db_cluster(input))
+    }
+
+    /// Blocking version of [`Neptune::create_db_cluster_endpoint`].
+    pub fn create_db_cluster_endpoint(
+        &self,
+        input: CreateDBClusterEndpointMessage,
+    ) -> Result<CreateDBClusterEndpointOutput, RusotoError<CreateDBClusterEndpointError>> {
+        self.runtime
+            .block_on(self.client.create_db_cluster_endpoint(input))
+    }
+
+    /// Blocking version of [`Neptune::create_db_cluster_parameter_group`].
+    pub fn create_db_cluster_parameter_group(
+        &self,
+        input: CreateDBClusterParameterGroupMessage,
+    ) -> Result<CreateDBClusterParameterGroupResult, RusotoError<CreateDBClusterParameterGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.create_db_cluster_parameter_group(input))
+    }
+
+    /// Blocking version of [`Neptune::create_db_cluster_snapshot`].
+    pub fn create_db_cluster_snapshot(
+        &self,
+        input: CreateDBClusterSnapshotMessage,
+    ) -> Result<CreateDBClusterSnapshotResult, RusotoError<CreateDBClusterSnapshotError>> {
+        self.runtime
+            .block_on(self.client.create_db_cluster_snapshot(input))
+    }
+
+    /// Blocking version of [`Neptune::create_db_instance`].
+    pub fn create_db_instance(
+        &self,
+        input: CreateDBInstanceMessage,
+    ) -> Result<CreateDBInstanceResult, RusotoError<CreateDBInstanceError>> {
+        self.runtime.block_on(self.client.create_db_instance(input))
+    }
+
+    /// Blocking version of [`Neptune::create_db_parameter_group`].
+    pub fn create_db_parameter_group(
+        &self,
+        input: CreateDBParameterGroupMessage,
+    ) -> Result<CreateDBParameterGroupResult, RusotoError<CreateDBParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.create_db_parameter_group(input))
+    }
+
+    /// Blocking version of [`Neptune::create_db_subnet_group`].
+    pub fn create_db_subnet_group(
+        &self,
+        input: CreateDBSubnetGroupMessage,
+    ) -> Result<CreateDBSubnetGroupResult, RusotoError<CreateDBSubnetGroupError>> {
+        self.runtime
+            .block_on(self.client.create_db_subnet_group(input))
+    }
+
+    /// Blocking version of [`Neptune::create_event_subscription`].
+    pub fn create_event_subscription(
+        &self,
+        input: CreateEventSubscriptionMessage,
+    ) -> Result<CreateEventSubscriptionResult, RusotoError<CreateEventSubscriptionError>> {
+        self.runtime
+            .block_on(self.client.create_event_subscription(input))
+    }
+
+    /// Blocking version of [`Neptune::delete_db_cluster`].
+    pub fn delete_db_cluster(
+        &self,
+        input: DeleteDBClusterMessage,
+    ) -> Result<DeleteDBClusterResult, RusotoError<DeleteDBClusterError>> {
+        self.runtime.block_on(self.client.delete_db_cluster(input))
+    }
+
+    /// Blocking version of [`Neptune::delete_db_cluster_endpoint`].
+    pub fn delete_db_cluster_endpoint(
+        &self,
+        input: DeleteDBClusterEndpointMessage,
+    ) -> Result<DeleteDBClusterEndpointOutput, RusotoError<DeleteDBClusterEndpointError>> {
+        self.runtime
+            .block_on(self.client.delete_db_cluster_endpoint(input))
+    }
+
+    /// Blocking version of [`Neptune::delete_db_cluster_parameter_group`].
+    pub fn delete_db_cluster_parameter_group(
+        &self,
+        input: DeleteDBClusterParameterGroupMessage,
+    ) -> Result<(), RusotoError<DeleteDBClusterParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_db_cluster_parameter_group(input))
+    }
+
+    /// Blocking version of [`Neptune::delete_db_cluster_snapshot`].
+    pub fn delete_db_cluster_snapshot(
+        &self,
+        input: DeleteDBClusterSnapshotMessage,
+    ) -> Result<DeleteDBClusterSnapshotResult, RusotoError<DeleteDBClusterSnapshotError>> {
+        self.runtime
+            .block_on(self.client.delete_db_cluster_snapshot(input))
+    }
+
+    /// Blocking version of [`Neptune::delete_db_instance`].
+    pub fn delete_db_instance(
+        &self,
+        input: DeleteDBInstanceMessage,
+    ) -> Result<DeleteDBInstanceResult, RusotoError<DeleteDBInstanceError>> {
+        self.runtime.block_on(self.client.delete_db_instance(input))
+    }
+
+    /// Blocking version of [`Neptune::delete_db_parameter_group`].
+    pub fn delete_db_parameter_group(
+        &self,
+        input: DeleteDBParameterGroupMessage,
+    ) -> Result<(), RusotoError<DeleteDBParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_db_parameter_group(input))
+    }
+
+    /// Blocking version of [`Neptune::delete_db_subnet_group`].
+    pub fn delete_db_subnet_group(
+        &self,
+        input: DeleteDBSubnetGroupMessage,
+    ) -> Result<(), RusotoError<DeleteDBSubnetGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_db_subnet_group(input))
+    }
+
+    /// Blocking version of [`Neptune::delete_event_subscription`].
+    pub fn delete_event_subscription(
+        &self,
+        input: DeleteEventSubscriptionMessage,
+    ) -> Result<DeleteEventSubscriptionResult, RusotoError<DeleteEventSubscriptionError>> {
+        self.runtime
+            .block_on(self.client.delete_event_subscription(input))
+    }
+
+    /// Blocking version of [`Neptune::describe_db_cluster_endpoints`].
+    pub fn describe_db_cluster_endpoints(
+        &self,
+        input: DescribeDBClusterEndpointsMessage,
+    ) -> Result<DBClusterEndpointMessage, RusotoError<DescribeDBClusterEndpointsError>> {
+        self.runtime
+            .block_on(self.client.describe_db_cluster_endpoints(input))
+    }
+
+    /// Blocking version of [`Neptune::describe_db_cluster_parameter_groups`].
+    pub fn describe_db_cluster_parameter_groups(
+        &self,
+        input: DescribeDBClusterParameterGroupsMessage,
+    ) -> Result<DBClusterParameterGroupsMessage, RusotoError<DescribeDBClusterParameterGroupsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_db_cluster_parameter_groups(input))
+    }
+
+    /// Blocking version of [`Neptune::describe_db_cluster_parameters`].
+    pub fn describe_db_cluster_parameters(
+        &self,
+        input: DescribeDBClusterParametersMessage,
+    ) -> Result<DBClusterParameterGroupDetails, RusotoError<DescribeDBClusterParametersError>> {
+        self.runtime
+            .block_on(self.client.describe_db_cluster_parameters(input))
+    }
+
+    /// Blocking version of [`Neptune::describe_db_cluster_snapshot_attributes`].
+    pub fn describe_db_cluster_snapshot_attributes(
+        &self,
+        input: DescribeDBClusterSnapshotAttributesMessage,
+    ) -> Result<
+        DescribeDBClusterSnapshotAttributesResult,
+        RusotoError<DescribeDBClusterSnapshotAttributesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_db_cluster_snapshot_attributes(input))
+    }
+
+    /// Blocking version of [`Neptune::describe_db_cluster_snapshots`].
+    pub fn describe_db_cluster_snapshots(
+        &self,
+        input: DescribeDBClusterSnapshotsMessage,
+    ) -> Result<DBClusterSnapshotMessage, RusotoError<DescribeDBClusterSnapshotsError>> {
+        self.runtime
+            .block_on(self.client.describe_db_cluster_snapshots(input))
+    }
+
+    /// Blocking version of [`Neptune::describe_db_clusters`].
+    pub fn describe_db_clusters(
+        &self,
+        input: DescribeDBClustersMessage,
+    ) -> Result<DBClusterMessage, RusotoError<DescribeDBClustersError>> {
+        self.runtime
+            .block_on(self.client.describe_db_clusters(input))
+    }
+
+    /// Blocking version of [`Neptune::describe_db_engine_versions`].
+    pub fn describe_db_engine_versions(
+        &self,
+        input: DescribeDBEngineVersionsMessage,
+    ) -> Result<DBEngineVersionMessage, RusotoError<DescribeDBEngineVersionsError>> {
+        self.runtime
+            .block_on(self.client.describe_db_engine_versions(input))
+    }
+
+    /// Blocking version of [`Neptune::describe_db_instances`].
+    pub fn describe_db_instances(
+        &self,
+        input: DescribeDBInstancesMessage,
+    ) -> Result<DBInstanceMessage, RusotoError<DescribeDBInstancesError>> {
+        self.runtime
+            .block_on(self.client.describe_db_instances(input))
+    }
+
+    /// Blocking version of [`Neptune::describe_db_parameter_groups`].
+    pub fn describe_db_parameter_groups(
+        &self,
+        input: DescribeDBParameterGroupsMessage,
+    ) -> Result<DBParameterGroupsMessage, RusotoError<DescribeDBParameterGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_db_parameter_groups(input))
+    }
+
+    /// Blocking version of [`Neptune::describe_db_parameters`].
+    pub fn describe_db_parameters(
+        &self,
+        input: DescribeDBParametersMessage,
+    ) -> Result<DBParameterGroupDetails, RusotoError<DescribeDBParametersError>> {
+        self.runtime
+            .block_on(self.client.describe_db_parameters(input))
+    }
+
+    /// Blocking version of [`Neptune::describe_db_subnet_groups`].
+    pub fn describe_db_subnet_groups(
+        &self,
+        input: DescribeDBSubnetGroupsMessage,
+    ) -> Result<DBSubnetGroupMessage, RusotoError<DescribeDBSubnetGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_db_subnet_groups(input))
+    }
+
+    /// Blocking version of [`Neptune::describe_engine_default_cluster_parameters`].
+    pub fn describe_engine_default_cluster_parameters(
+        &self,
+        input: DescribeEngineDefaultClusterParametersMessage,
+    ) -> Result<
+        DescribeEngineDefaultClusterParametersResult,
+        RusotoError<DescribeEngineDefaultClusterParametersError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_engine_default_cluster_parameters(input),
+        )
+    }
+
+    /// Blocking version of [`Neptune::describe_engine_default_parameters`].
+    pub fn describe_engine_default_parameters(
+        &self,
+        input: DescribeEngineDefaultParametersMessage,
+    ) -> Result<
+        DescribeEngineDefaultParametersResult,
+        RusotoError<DescribeEngineDefaultParametersError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_engine_default_parameters(input))
+    }
+
+    /// Blocking version of [`Neptune::describe_event_categories`].
+    pub fn describe_event_categories(
+        &self,
+        input: DescribeEventCategoriesMessage,
+    ) -> Result<EventCategoriesMessage, RusotoError<DescribeEventCategoriesError>> {
+        self.runtime
+            .block_on(self.client.describe_event_categories(input))
+    }
+
+    /// Blocking version of [`Neptune::describe_event_subscriptions`].
+    pub fn describe_event_subscriptions(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> Result<EventSubscriptionsMessage, RusotoError<DescribeEventSubscriptionsError>> {
+        self.runtime
+            .block_on(self.client.describe_event_subscriptions(input))
+    }
+
+    /// Blocking version of [`Neptune::describe_events`].
+    pub fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> Result<EventsMessage, RusotoError<DescribeEventsError>> {
+        self.runtime.block_on(self.client.describe_events(input))
+    }
+
+    /// Blocking version of [`Neptune::describe_orderable_db_instance_options`].
+    pub fn describe_orderable_db_instance_options(
+        &self,
+        input: DescribeOrderableDBInstanceOptionsMessage,
+    ) -> Result<
+        OrderableDBInstanceOptionsMessage,
+        RusotoError<DescribeOrderableDBInstanceOptionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_orderable_db_instance_options(input))
+    }
+
+    /// Blocking version of [`Neptune::describe_pending_maintenance_actions`].
+    pub fn describe_pending_maintenance_actions(
+        &self,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> Result<PendingMaintenanceActionsMessage, RusotoError<DescribePendingMaintenanceActionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_pending_maintenance_actions(input))
+    }
+
+    /// Blocking version of [`Neptune::describe_valid_db_instance_modifications`].
+    pub fn describe_valid_db_instance_modifications(
+        &self,
+        input: DescribeValidDBInstanceModificationsMessage,
+    ) -> Result<
+        DescribeValidDBInstanceModificationsResult,
+        RusotoError<DescribeValidDBInstanceModificationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_valid_db_instance_modifications(input))
+    }
+
+    /// Blocking version of [`Neptune::failover_db_cluster`].
+    pub fn failover_db_cluster(
+        &self,
+        input: FailoverDBClusterMessage,
+    ) -> Result<FailoverDBClusterResult, RusotoError<FailoverDBClusterError>> {
+        self.runtime
+            .block_on(self.client.failover_db_cluster(input))
+    }
+
+    /// Blocking version of [`Neptune::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> Result<TagListMessage, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`Neptune::modify_db_cluster`].
+    pub fn modify_db_cluster(
+        &self,
+        input: ModifyDBClusterMessage,
+    ) -> Result<ModifyDBClusterResult, RusotoError<ModifyDBClusterError>> {
+        self.runtime.block_on(self.client.modify_db_cluster(input))
+    }
+
+    /// Blocking version of [`Neptune::modify_db_cluster_endpoint`].
+    pub fn modify_db_cluster_endpoint(
+        &self,
+        input: ModifyDBClusterEndpointMessage,
+    ) -> Result<ModifyDBClusterEndpointOutput, RusotoError<ModifyDBClusterEndpointError>> {
+        self.runtime
+            .block_on(self.client.modify_db_cluster_endpoint(input))
+    }
+
+    /// Blocking version of [`Neptune::modify_db_cluster_parameter_group`].
+    pub fn modify_db_cluster_parameter_group(
+        &self,
+        input: ModifyDBClusterParameterGroupMessage,
+    ) -> Result<DBClusterParameterGroupNameMessage, RusotoError<ModifyDBClusterParameterGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.modify_db_cluster_parameter_group(input))
+    }
+
+    /// Blocking version of [`Neptune::modify_db_cluster_snapshot_attribute`].
+    pub fn modify_db_cluster_snapshot_attribute(
+        &self,
+        input: ModifyDBClusterSnapshotAttributeMessage,
+    ) -> Result<
+        ModifyDBClusterSnapshotAttributeResult,
+        RusotoError<ModifyDBClusterSnapshotAttributeError>,
+    > {
+        self.runtime
+            .block_on(self.client.modify_db_cluster_snapshot_attribute(input))
+    }
+
+    /// Blocking version of [`Neptune::modify_db_instance`].
+    pub fn modify_db_instance(
+        &self,
+        input: ModifyDBInstanceMessage,
+    ) -> Result<ModifyDBInstanceResult, RusotoError<ModifyDBInstanceError>> {
+        self.runtime.block_on(self.client.modify_db_instance(input))
+    }
+
+    /// Blocking version of [`Neptune::modify_db_parameter_group`].
+    pub fn modify_db_parameter_group(
+        &self,
+        input: ModifyDBParameterGroupMessage,
+    ) -> Result<DBParameterGroupNameMessage, RusotoError<ModifyDBParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.modify_db_parameter_group(input))
+    }
+
+    /// Blocking version of [`Neptune::modify_db_subnet_group`].
+    pub fn modify_db_subnet_group(
+        &self,
+        input: ModifyDBSubnetGroupMessage,
+    ) -> Result<ModifyDBSubnetGroupResult, RusotoError<ModifyDBSubnetGroupError>> {
+        self.runtime
+            .block_on(self.client.modify_db_subnet_group(input))
+    }
+
+    /// Blocking version of [`Neptune::modify_event_subscription`].
+    pub fn modify_event_subscription(
+        &self,
+        input: ModifyEventSubscriptionMessage,
+    ) -> Result<ModifyEventSubscriptionResult, RusotoError<ModifyEventSubscriptionError>> {
+        self.runtime
+            .block_on(self.client.modify_event_subscription(input))
+    }
+
+    /// Blocking version of [`Neptune::promote_read_replica_db_cluster`].
+    pub fn promote_read_replica_db_cluster(
+        &self,
+        input: PromoteReadReplicaDBClusterMessage,
+    ) -> Result<PromoteReadReplicaDBClusterResult, RusotoError<PromoteReadReplicaDBClusterError>>
+    {
+        self.runtime
+            .block_on(self.client.promote_read_replica_db_cluster(input))
+    }
+
+    /// Blocking version of [`Neptune::reboot_db_instance`].
+    pub fn reboot_db_instance(
+        &self,
+        input: RebootDBInstanceMessage,
+    ) -> Result<RebootDBInstanceResult, RusotoError<RebootDBInstanceError>> {
+        self.runtime.block_on(self.client.reboot_db_instance(input))
+    }
+
+    /// Blocking version of [`Neptune::remove_role_from_db_cluster`].
+    pub fn remove_role_from_db_cluster(
+        &self,
+        input: RemoveRoleFromDBClusterMessage,
+    ) -> Result<(), RusotoError<RemoveRoleFromDBClusterError>> {
+        self.runtime
+            .block_on(self.client.remove_role_from_db_cluster(input))
+    }
+
+    /// Blocking version of [`Neptune::remove_source_identifier_from_subscription`].
+    pub fn remove_source_identifier_from_subscription(
+        &self,
+        input: RemoveSourceIdentifierFromSubscriptionMessage,
+    ) -> Result<
+        RemoveSourceIdentifierFromSubscriptionResult,
+        RusotoError<RemoveSourceIdentifierFromSubscriptionError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .remove_source_identifier_from_subscription(input),
+        )
+    }
+
+    /// Blocking version of [`Neptune::remove_tags_from_resource`].
+    pub fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceMessage,
+    ) -> Result<(), RusotoError<RemoveTagsFromResourceError>> {
+        self.runtime
+            .block_on(self.client.remove_tags_from_resource(input))
+    }
+
+    /// Blocking version of [`Neptune::reset_db_cluster_parameter_group`].
+    pub fn reset_db_cluster_parameter_group(
+        &self,
+        input: ResetDBClusterParameterGroupMessage,
+    ) -> Result<DBClusterParameterGroupNameMessage, RusotoError<ResetDBClusterParameterGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.reset_db_cluster_parameter_group(input))
+    }
+
+    /// Blocking version of [`Neptune::reset_db_parameter_group`].
+    pub fn reset_db_parameter_group(
+        &self,
+        input: ResetDBParameterGroupMessage,
+    ) -> Result<DBParameterGroupNameMessage, RusotoError<ResetDBParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.reset_db_parameter_group(input))
+    }
+
+    /// Blocking version of [`Neptune::restore_db_cluster_from_snapshot`].
+    pub fn restore_db_cluster_from_snapshot(
+        &self,
+        input: RestoreDBClusterFromSnapshotMessage,
+    ) -> Result<RestoreDBClusterFromSnapshotResult, RusotoError<RestoreDBClusterFromSnapshotError>>
+    {
+        self.runtime
+            .block_on(self.client.restore_db_cluster_from_snapshot(input))
+    }
+
+    /// Blocking version of [`Neptune::restore_db_cluster_to_point_in_time`].
+    pub fn restore_db_cluster_to_point_in_time(
+        &self,
+        input: RestoreDBClusterToPointInTimeMessage,
+    ) -> Result<RestoreDBClusterToPointInTimeResult, RusotoError<RestoreDBClusterToPointInTimeError>>
+    {
+        self.runtime
+            .block_on(self.client.restore_db_cluster_to_point_in_time(input))
+    }
+
+    /// Blocking version of [`Neptune::start_db_cluster`].
+    pub fn start_db_cluster(
+        &self,
+        input: StartDBClusterMessage,
+    ) -> Result<StartDBClusterResult, RusotoError<StartDBClusterError>> {
+        self.runtime.block_on(self.client.start_db_cluster(input))
+    }
+
+    /// Blocking version of [`Neptune::stop_db_cluster`].
+    pub fn stop_db_cluster(
+        &self,
+        input: StopDBClusterMessage,
+    ) -> Result<StopDBClusterResult, RusotoError<StopDBClusterError>> {
+        self.runtime.block_on(self.client.stop_db_cluster(input))
+    }
+}
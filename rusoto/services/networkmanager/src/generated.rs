@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1441,32 +1442,54 @@ impl AssociateCustomerGatewayError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AssociateCustomerGatewayError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateCustomerGatewayError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(AssociateCustomerGatewayError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateCustomerGatewayError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(AssociateCustomerGatewayError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateCustomerGatewayError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateCustomerGatewayError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateCustomerGatewayError::ResourceNotFound,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(
-                        AssociateCustomerGatewayError::ServiceQuotaExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateCustomerGatewayError::ServiceQuotaExceeded,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AssociateCustomerGatewayError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateCustomerGatewayError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1511,24 +1534,54 @@ impl AssociateLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AssociateLinkError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateLinkError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(AssociateLinkError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateLinkError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(AssociateLinkError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateLinkError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateLinkError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateLinkError::ResourceNotFound,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(AssociateLinkError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateLinkError::ServiceQuotaExceeded,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AssociateLinkError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateLinkError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1573,36 +1626,54 @@ impl AssociateTransitGatewayConnectPeerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        AssociateTransitGatewayConnectPeerError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateTransitGatewayConnectPeerError::AccessDenied,
                     )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(AssociateTransitGatewayConnectPeerError::Conflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateTransitGatewayConnectPeerError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
-                        AssociateTransitGatewayConnectPeerError::InternalServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateTransitGatewayConnectPeerError::InternalServer,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        AssociateTransitGatewayConnectPeerError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateTransitGatewayConnectPeerError::ResourceNotFound,
                     )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(
-                        AssociateTransitGatewayConnectPeerError::ServiceQuotaExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateTransitGatewayConnectPeerError::ServiceQuotaExceeded,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
-                        AssociateTransitGatewayConnectPeerError::Throttling(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateTransitGatewayConnectPeerError::Throttling,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1653,23 +1724,46 @@ impl CreateConnectionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateConnectionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConnectionError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateConnectionError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConnectionError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateConnectionError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConnectionError::InternalServer,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateConnectionError::ServiceQuotaExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateConnectionError::ServiceQuotaExceeded,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateConnectionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConnectionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1711,24 +1805,54 @@ impl CreateDeviceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDeviceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeviceError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateDeviceError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeviceError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateDeviceError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeviceError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDeviceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeviceError::ResourceNotFound,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateDeviceError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeviceError::ServiceQuotaExceeded,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDeviceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeviceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1769,23 +1893,46 @@ impl CreateGlobalNetworkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateGlobalNetworkError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGlobalNetworkError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateGlobalNetworkError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGlobalNetworkError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateGlobalNetworkError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGlobalNetworkError::InternalServer,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateGlobalNetworkError::ServiceQuotaExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateGlobalNetworkError::ServiceQuotaExceeded,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateGlobalNetworkError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGlobalNetworkError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1827,24 +1974,54 @@ impl CreateLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateLinkError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLinkError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateLinkError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLinkError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateLinkError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLinkError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateLinkError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLinkError::ResourceNotFound,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateLinkError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLinkError::ServiceQuotaExceeded,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateLinkError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLinkError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1887,24 +2064,54 @@ impl CreateSiteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateSiteError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSiteError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateSiteError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSiteError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateSiteError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSiteError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateSiteError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSiteError::ResourceNotFound,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(CreateSiteError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSiteError::ServiceQuotaExceeded,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateSiteError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSiteError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1945,21 +2152,46 @@ impl DeleteConnectionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteConnectionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteConnectionError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteConnectionError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteConnectionError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteConnectionError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteConnectionError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteConnectionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteConnectionError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteConnectionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteConnectionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1999,21 +2231,46 @@ impl DeleteDeviceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteDeviceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDeviceError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteDeviceError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDeviceError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteDeviceError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDeviceError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDeviceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDeviceError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteDeviceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDeviceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2053,23 +2310,46 @@ impl DeleteGlobalNetworkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteGlobalNetworkError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGlobalNetworkError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteGlobalNetworkError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGlobalNetworkError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteGlobalNetworkError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGlobalNetworkError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteGlobalNetworkError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteGlobalNetworkError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteGlobalNetworkError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGlobalNetworkError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2109,21 +2389,46 @@ impl DeleteLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteLinkError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLinkError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteLinkError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLinkError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteLinkError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLinkError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteLinkError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLinkError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteLinkError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLinkError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2163,21 +2468,46 @@ impl DeleteSiteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteSiteError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSiteError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteSiteError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSiteError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteSiteError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSiteError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteSiteError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSiteError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteSiteError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSiteError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2217,27 +2547,46 @@ impl DeregisterTransitGatewayError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeregisterTransitGatewayError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeregisterTransitGatewayError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeregisterTransitGatewayError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterTransitGatewayError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DeregisterTransitGatewayError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeregisterTransitGatewayError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeregisterTransitGatewayError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeregisterTransitGatewayError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeregisterTransitGatewayError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterTransitGatewayError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2275,22 +2624,38 @@ impl DescribeGlobalNetworksError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeGlobalNetworksError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeGlobalNetworksError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeGlobalNetworksError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeGlobalNetworksError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeGlobalNetworksError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeGlobalNetworksError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeGlobalNetworksError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeGlobalNetworksError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2331,31 +2696,46 @@ impl DisassociateCustomerGatewayError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DisassociateCustomerGatewayError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateCustomerGatewayError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DisassociateCustomerGatewayError::Conflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateCustomerGatewayError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DisassociateCustomerGatewayError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateCustomerGatewayError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DisassociateCustomerGatewayError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateCustomerGatewayError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DisassociateCustomerGatewayError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateCustomerGatewayError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2395,21 +2775,46 @@ impl DisassociateLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DisassociateLinkError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateLinkError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DisassociateLinkError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateLinkError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(DisassociateLinkError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateLinkError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateLinkError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateLinkError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DisassociateLinkError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateLinkError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2451,31 +2856,46 @@ impl DisassociateTransitGatewayConnectPeerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        DisassociateTransitGatewayConnectPeerError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateTransitGatewayConnectPeerError::AccessDenied,
                     )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(
-                        DisassociateTransitGatewayConnectPeerError::Conflict(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateTransitGatewayConnectPeerError::Conflict,
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
-                        DisassociateTransitGatewayConnectPeerError::InternalServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateTransitGatewayConnectPeerError::InternalServer,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DisassociateTransitGatewayConnectPeerError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateTransitGatewayConnectPeerError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
-                        DisassociateTransitGatewayConnectPeerError::Throttling(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateTransitGatewayConnectPeerError::Throttling,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2523,18 +2943,38 @@ impl GetConnectionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetConnectionsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConnectionsError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetConnectionsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConnectionsError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetConnectionsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConnectionsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetConnectionsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConnectionsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2575,31 +3015,46 @@ impl GetCustomerGatewayAssociationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetCustomerGatewayAssociationsError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCustomerGatewayAssociationsError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(GetCustomerGatewayAssociationsError::Conflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCustomerGatewayAssociationsError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
-                        GetCustomerGatewayAssociationsError::InternalServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCustomerGatewayAssociationsError::InternalServer,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        GetCustomerGatewayAssociationsError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCustomerGatewayAssociationsError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetCustomerGatewayAssociationsError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCustomerGatewayAssociationsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2641,18 +3096,38 @@ impl GetDevicesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetDevicesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDevicesError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetDevicesError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDevicesError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDevicesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDevicesError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetDevicesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDevicesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2689,20 +3164,38 @@ impl GetLinkAssociationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetLinkAssociationsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLinkAssociationsError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetLinkAssociationsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLinkAssociationsError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetLinkAssociationsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetLinkAssociationsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetLinkAssociationsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLinkAssociationsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2739,18 +3232,38 @@ impl GetLinksError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetLinksError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLinksError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetLinksError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLinksError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetLinksError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLinksError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetLinksError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLinksError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2787,18 +3300,38 @@ impl GetSitesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetSitesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSitesError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(GetSitesError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSitesError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetSitesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSitesError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetSitesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSitesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2839,31 +3372,46 @@ impl GetTransitGatewayConnectPeerAssociationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        GetTransitGatewayConnectPeerAssociationsError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTransitGatewayConnectPeerAssociationsError::AccessDenied,
                     )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(
-                        GetTransitGatewayConnectPeerAssociationsError::Conflict(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTransitGatewayConnectPeerAssociationsError::Conflict,
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
-                        GetTransitGatewayConnectPeerAssociationsError::InternalServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTransitGatewayConnectPeerAssociationsError::InternalServer,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        GetTransitGatewayConnectPeerAssociationsError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTransitGatewayConnectPeerAssociationsError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
-                        GetTransitGatewayConnectPeerAssociationsError::Throttling(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTransitGatewayConnectPeerAssociationsError::Throttling,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2913,26 +3461,38 @@ impl GetTransitGatewayRegistrationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetTransitGatewayRegistrationsError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetTransitGatewayRegistrationsError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
-                        GetTransitGatewayRegistrationsError::InternalServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTransitGatewayRegistrationsError::InternalServer,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        GetTransitGatewayRegistrationsError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTransitGatewayRegistrationsError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetTransitGatewayRegistrationsError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetTransitGatewayRegistrationsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2973,20 +3533,38 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::AccessDenied,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3025,25 +3603,46 @@ impl RegisterTransitGatewayError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RegisterTransitGatewayError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterTransitGatewayError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(RegisterTransitGatewayError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterTransitGatewayError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(RegisterTransitGatewayError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterTransitGatewayError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RegisterTransitGatewayError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterTransitGatewayError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RegisterTransitGatewayError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterTransitGatewayError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3085,24 +3684,54 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(TagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(TagResourceError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(TagResourceError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(TagResourceError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ServiceQuotaExceeded,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(TagResourceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3143,21 +3772,46 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UntagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UntagResourceError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UntagResourceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3197,21 +3851,46 @@ impl UpdateConnectionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateConnectionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateConnectionError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateConnectionError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateConnectionError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateConnectionError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateConnectionError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateConnectionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateConnectionError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateConnectionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateConnectionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3251,21 +3930,46 @@ impl UpdateDeviceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateDeviceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeviceError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDeviceError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeviceError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateDeviceError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeviceError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDeviceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeviceError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDeviceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeviceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3305,23 +4009,46 @@ impl UpdateGlobalNetworkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateGlobalNetworkError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGlobalNetworkError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateGlobalNetworkError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGlobalNetworkError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateGlobalNetworkError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGlobalNetworkError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateGlobalNetworkError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateGlobalNetworkError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateGlobalNetworkError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGlobalNetworkError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3363,24 +4090,54 @@ impl UpdateLinkError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateLinkError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateLinkError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateLinkError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateLinkError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateLinkError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateLinkError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateLinkError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateLinkError::ResourceNotFound,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(UpdateLinkError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateLinkError::ServiceQuotaExceeded,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateLinkError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateLinkError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3421,21 +4178,46 @@ impl UpdateSiteError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateSiteError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSiteError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateSiteError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSiteError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateSiteError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSiteError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateSiteError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSiteError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateSiteError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSiteError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3693,11 +4475,12 @@ pub struct NetworkManagerClient {
 impl NetworkManagerClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> NetworkManagerClient {
         NetworkManagerClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "NetworkManager"),
         }
     }
 
@@ -3712,12 +4495,32 @@ impl NetworkManagerClient {
     {
         NetworkManagerClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "NetworkManager"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> NetworkManagerClient {
-        NetworkManagerClient { client, region }
+        NetworkManagerClient {
+            client,
+            region: region_for_service(region, "NetworkManager"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> NetworkManagerClient {
+        NetworkManagerClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("NetworkManager"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> NetworkManagerClient {
+        NetworkManagerClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -3735,6 +4538,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation("AssociateCustomerGateway");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3769,6 +4573,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation("AssociateLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3806,6 +4611,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation("AssociateTransitGatewayConnectPeer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3842,6 +4648,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation("CreateConnection");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3876,6 +4683,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation("CreateDevice");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3907,6 +4715,7 @@ impl NetworkManager for NetworkManagerClient {
         let request_uri = "/global-networks";
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation("CreateGlobalNetwork");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3941,6 +4750,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation("CreateLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3975,6 +4785,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation("CreateSite");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4011,6 +4822,7 @@ impl NetworkManager for NetworkManagerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation("DeleteConnection");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4044,6 +4856,7 @@ impl NetworkManager for NetworkManagerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation("DeleteDevice");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4076,6 +4889,7 @@ impl NetworkManager for NetworkManagerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation("DeleteGlobalNetwork");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4109,6 +4923,7 @@ impl NetworkManager for NetworkManagerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation("DeleteLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4142,6 +4957,7 @@ impl NetworkManager for NetworkManagerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation("DeleteSite");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4171,6 +4987,7 @@ impl NetworkManager for NetworkManagerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation("DeregisterTransitGateway");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4199,6 +5016,7 @@ impl NetworkManager for NetworkManagerClient {
         let request_uri = "/global-networks";
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation("DescribeGlobalNetworks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4243,6 +5061,7 @@ impl NetworkManager for NetworkManagerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation("DisassociateCustomerGateway");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4275,6 +5094,7 @@ impl NetworkManager for NetworkManagerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation("DisassociateLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4312,6 +5132,7 @@ impl NetworkManager for NetworkManagerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation("DisassociateTransitGatewayConnectPeer");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4345,6 +5166,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation("GetConnections");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4396,6 +5218,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation("GetCustomerGatewayAssociations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4441,6 +5264,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation("GetDevices");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4489,6 +5313,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation("GetLinkAssociations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4535,6 +5360,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation("GetLinks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4589,6 +5415,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation("GetSites");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4637,6 +5464,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation("GetTransitGatewayConnectPeerAssociations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4685,6 +5513,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation("GetTransitGatewayRegistrations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4727,6 +5556,7 @@ impl NetworkManager for NetworkManagerClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "networkmanager", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -4758,6 +5588,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation("RegisterTransitGateway");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4789,6 +5620,7 @@ impl NetworkManager for NetworkManagerClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "networkmanager", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4821,6 +5653,7 @@ impl NetworkManager for NetworkManagerClient {
 
         let mut request =
             SignedRequest::new("DELETE", "networkmanager", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -4859,6 +5692,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "networkmanager", &self.region, &request_uri);
+        request.set_operation("UpdateConnection");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4894,6 +5728,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "networkmanager", &self.region, &request_uri);
+        request.set_operation("UpdateDevice");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4928,6 +5763,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "networkmanager", &self.region, &request_uri);
+        request.set_operation("UpdateGlobalNetwork");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4963,6 +5799,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "networkmanager", &self.region, &request_uri);
+        request.set_operation("UpdateLink");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4998,6 +5835,7 @@ impl NetworkManager for NetworkManagerClient {
         );
 
         let mut request = SignedRequest::new("PATCH", "networkmanager", &self.region, &request_uri);
+        request.set_operation("UpdateSite");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -5020,3 +5858,396 @@ impl NetworkManager for NetworkManagerClient {
         }
     }
 }
+
+/// A blocking client for the NetworkManager API, running the calls of a `NetworkManagerClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `NetworkManagerClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct NetworkManagerBlockingClient {
+    client: NetworkManagerClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl NetworkManagerBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> NetworkManagerBlockingClient {
+        NetworkManagerBlockingClient::from_client(NetworkManagerClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> NetworkManagerBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        NetworkManagerBlockingClient::from_client(NetworkManagerClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> NetworkManagerBlockingClient {
+        NetworkManagerBlockingClient::from_client(NetworkManagerClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> NetworkManagerBlockingClient {
+        NetworkManagerBlockingClient::from_client(NetworkManagerClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: NetworkManagerClient) -> NetworkManagerBlockingClient {
+        NetworkManagerBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> NetworkManagerBlockingClient {
+        NetworkManagerBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> NetworkManagerBlockingClient {
+        NetworkManagerBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`NetworkManager::associate_customer_gateway`].
+    pub fn associate_customer_gateway(
+        &self,
+        input: AssociateCustomerGatewayRequest,
+    ) -> Result<AssociateCustomerGatewayResponse, RusotoError<AssociateCustomerGatewayError>> {
+        self.runtime
+            .block_on(self.client.associate_customer_gateway(input))
+    }
+
+    /// Blocking version of [`NetworkManager::associate_link`].
+    pub fn associate_link(
+        &self,
+        input: AssociateLinkRequest,
+    ) -> Result<AssociateLinkResponse, RusotoError<AssociateLinkError>> {
+        self.runtime.block_on(self.client.associate_link(input))
+    }
+
+    /// Blocking version of [`NetworkManager::associate_transit_gateway_connect_peer`].
+    pub fn associate_transit_gateway_connect_peer(
+        &self,
+        input: AssociateTransitGatewayConnectPeerRequest,
+    ) -> Result<
+        AssociateTransitGatewayConnectPeerResponse,
+        RusotoError<AssociateTransitGatewayConnectPeerError>,
+    > {
+        self.runtime
+            .block_on(self.client.associate_transit_gateway_connect_peer(input))
+    }
+
+    /// Blocking version of [`NetworkManager::create_connection`].
+    pub fn create_connection(
+        &self,
+        input: CreateConnectionRequest,
+    ) -> Result<CreateConnectionResponse, RusotoError<CreateConnectionError>> {
+        self.runtime.block_on(self.client.create_connection(input))
+    }
+
+    /// Blocking version of [`NetworkManager::create_device`].
+    pub fn create_device(
+        &self,
+        input: CreateDeviceRequest,
+    ) -> Result<CreateDeviceResponse, RusotoError<CreateDeviceError>> {
+        self.runtime.block_on(self.client.create_device(input))
+    }
+
+    /// Blocking version of [`NetworkManager::create_global_network`].
+    pub fn create_global_network(
+        &self,
+        input: CreateGlobalNetworkRequest,
+    ) -> Result<CreateGlobalNetworkResponse, RusotoError<CreateGlobalNetworkError>> {
+        self.runtime
+            .block_on(self.client.create_global_network(input))
+    }
+
+    /// Blocking version of [`NetworkManager::create_link`].
+    pub fn create_link(
+        &self,
+        input: CreateLinkRequest,
+    ) -> Result<CreateLinkResponse, RusotoError<CreateLinkError>> {
+        self.runtime.block_on(self.client.create_link(input))
+    }
+
+    /// Blocking version of [`NetworkManager::create_site`].
+    pub fn create_site(
+        &self,
+        input: CreateSiteRequest,
+    ) -> Result<CreateSiteResponse, RusotoError<CreateSiteError>> {
+        self.runtime.block_on(self.client.create_site(input))
+    }
+
+    /// Blocking version of [`NetworkManager::delete_connection`].
+    pub fn delete_connection(
+        &self,
+        input: DeleteConnectionRequest,
+    ) -> Result<DeleteConnectionResponse, RusotoError<DeleteConnectionError>> {
+        self.runtime.block_on(self.client.delete_connection(input))
+    }
+
+    /// Blocking version of [`NetworkManager::delete_device`].
+    pub fn delete_device(
+        &self,
+        input: DeleteDeviceRequest,
+    ) -> Result<DeleteDeviceResponse, RusotoError<DeleteDeviceError>> {
+        self.runtime.block_on(self.client.delete_device(input))
+    }
+
+    /// Blocking version of [`NetworkManager::delete_global_network`].
+    pub fn delete_global_network(
+        &self,
+        input: DeleteGlobalNetworkRequest,
+    ) -> Result<DeleteGlobalNetworkResponse, RusotoError<DeleteGlobalNetworkError>> {
+        self.runtime
+            .block_on(self.client.delete_global_network(input))
+    }
+
+    /// Blocking version of [`NetworkManager::delete_link`].
+    pub fn delete_link(
+        &self,
+        input: DeleteLinkRequest,
+    ) -> Result<DeleteLinkResponse, RusotoError<DeleteLinkError>> {
+        self.runtime.block_on(self.client.delete_link(input))
+    }
+
+    /// Blocking version of [`NetworkManager::delete_site`].
+    pub fn delete_site(
+        &self,
+        input: DeleteSiteRequest,
+    ) -> Result<DeleteSiteResponse, RusotoError<DeleteSiteError>> {
+        self.runtime.block_on(self.client.delete_site(input))
+    }
+
+    /// Blocking version of [`NetworkManager::deregister_transit_gateway`].
+    pub fn deregister_transit_gateway(
+        &self,
+        input: DeregisterTransitGatewayRequest,
+    ) -> Result<DeregisterTransitGatewayResponse, RusotoError<DeregisterTransitGatewayError>> {
+        self.runtime
+            .block_on(self.client.deregister_transit_gateway(input))
+    }
+
+    /// Blocking version of [`NetworkManager::describe_global_networks`].
+    pub fn describe_global_networks(
+        &self,
+        input: DescribeGlobalNetworksRequest,
+    ) -> Result<DescribeGlobalNetworksResponse, RusotoError<DescribeGlobalNetworksError>> {
+        self.runtime
+            .block_on(self.client.describe_global_networks(input))
+    }
+
+    /// Blocking version of [`NetworkManager::disassociate_customer_gateway`].
+    pub fn disassociate_customer_gateway(
+        &self,
+        input: DisassociateCustomerGatewayRequest,
+    ) -> Result<DisassociateCustomerGatewayResponse, RusotoError<DisassociateCustomerGatewayError>>
+    {
+        self.runtime
+            .block_on(self.client.disassociate_customer_gateway(input))
+    }
+
+    /// Blocking version of [`NetworkManager::disassociate_link`].
+    pub fn disassociate_link(
+        &self,
+        input: DisassociateLinkRequest,
+    ) -> Result<DisassociateLinkResponse, RusotoError<DisassociateLinkError>> {
+        self.runtime.block_on(self.client.disassociate_link(input))
+    }
+
+    /// Blocking version of [`NetworkManager::disassociate_transit_gateway_connect_peer`].
+    pub fn disassociate_transit_gateway_connect_peer(
+        &self,
+        input: DisassociateTransitGatewayConnectPeerRequest,
+    ) -> Result<
+        DisassociateTransitGatewayConnectPeerResponse,
+        RusotoError<DisassociateTransitGatewayConnectPeerError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_transit_gateway_connect_peer(input))
+    }
+
+    /// Blocking version of [`NetworkManager::get_connections`].
+    pub fn get_connections(
+        &self,
+        input: GetConnectionsRequest,
+    ) -> Result<GetConnectionsResponse, RusotoError<GetConnectionsError>> {
+        self.runtime.block_on(self.client.get_connections(input))
+    }
+
+    /// Blocking version of [`NetworkManager::get_customer_gateway_associations`].
+    pub fn get_customer_gateway_associations(
+        &self,
+        input: GetCustomerGatewayAssociationsRequest,
+    ) -> Result<
+        GetCustomerGatewayAssociationsResponse,
+        RusotoError<GetCustomerGatewayAssociationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_customer_gateway_associations(input))
+    }
+
+    /// Blocking version of [`NetworkManager::get_devices`].
+    pub fn get_devices(
+        &self,
+        input: GetDevicesRequest,
+    ) -> Result<GetDevicesResponse, RusotoError<GetDevicesError>> {
+        self.runtime.block_on(self.client.get_devices(input))
+    }
+
+    /// Blocking version of [`NetworkManager::get_link_associations`].
+    pub fn get_link_associations(
+        &self,
+        input: GetLinkAssociationsRequest,
+    ) -> Result<GetLinkAssociationsResponse, RusotoError<GetLinkAssociationsError>> {
+        self.runtime
+            .block_on(self.client.get_link_associations(input))
+    }
+
+    /// Blocking version of [`NetworkManager::get_links`].
+    pub fn get_links(
+        &self,
+        input: GetLinksRequest,
+    ) -> Result<GetLinksResponse, RusotoError<GetLinksError>> {
+        self.runtime.block_on(self.client.get_links(input))
+    }
+
+    /// Blocking version of [`NetworkManager::get_sites`].
+    pub fn get_sites(
+        &self,
+        input: GetSitesRequest,
+    ) -> Result<GetSitesResponse, RusotoError<GetSitesError>> {
+        self.runtime.block_on(self.client.get_sites(input))
+    }
+
+    /// Blocking version of [`NetworkManager::get_transit_gateway_connect_peer_associations`].
+    pub fn get_transit_gateway_connect_peer_associations(
+        &self,
+        input: GetTransitGatewayConnectPeerAssociationsRequest,
+    ) -> Result<
+        GetTransitGatewayConnectPeerAssociationsResponse,
+        RusotoError<GetTransitGatewayConnectPeerAssociationsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .get_transit_gateway_connect_peer_associations(input),
+        )
+    }
+
+    /// Blocking version of [`NetworkManager::get_transit_gateway_registrations`].
+    pub fn get_transit_gateway_registrations(
+        &self,
+        input: GetTransitGatewayRegistrationsRequest,
+    ) -> Result<
+        GetTransitGatewayRegistrationsResponse,
+        RusotoError<GetTransitGatewayRegistrationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_transit_gateway_registrations(input))
+    }
+
+    /// Blocking version of [`NetworkManager::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`NetworkManager::register_transit_gateway`].
+    pub fn register_transit_gateway(
+        &self,
+        input: RegisterTransitGatewayRequest,
+    ) -> Result<RegisterTransitGatewayResponse, RusotoError<RegisterTransitGatewayError>> {
+        self.runtime
+            .block_on(self.client.register_transit_gateway(input))
+    }
+
+    /// Blocking version of [`NetworkManager::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`NetworkManager::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`NetworkManager::update_connection`].
+    pub fn update_connection(
+        &self,
+        input: UpdateConnectionRequest,
+    ) -> Result<UpdateConnectionResponse, RusotoError<UpdateConnectionError>> {
+        self.runtime.block_on(self.client.update_connection(input))
+    }
+
+    /// Blocking version of [`NetworkManager::update_device`].
+    pub fn update_device(
+        &self,
+        input: UpdateDeviceRequest,
+    ) -> Result<UpdateDeviceResponse, RusotoError<UpdateDeviceError>> {
+        self.runtime.block_on(self.client.update_device(input))
+    }
+
+    /// Blocking version of [`NetworkManager::update_global_network`].
+    pub fn update_global_network(
+        &self,
+        input: UpdateGlobalNetworkRequest,
+    ) -> Result<UpdateGlobalNetworkResponse, RusotoError<UpdateGlobalNetworkError>> {
+        self.runtime
+            .block_on(self.client.update_global_network(input))
+    }
+
+    /// Blocking version of [`NetworkManager::update_link`].
+    pub fn update_link(
+        &self,
+        input: UpdateLinkRequest,
+    ) -> Result<UpdateLinkResponse, RusotoError<UpdateLinkError>> {
+        self.runtime.block_on(self.client.update_link(input))
+    }
+
+    /// Blocking version of [`NetworkManager::update_site`].
+    pub fn update_site(
+        &self,
+        input: UpdateSiteRequest,
+    ) -> Result<UpdateSiteResponse, RusotoError<UpdateSiteError>> {
+        self.runtime.block_on(self.client.update_site(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -3368,9 +3369,14 @@ impl AssignInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssignInstanceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssignInstanceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3398,9 +3404,14 @@ impl AssignVolumeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssignVolumeError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssignVolumeError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3428,9 +3439,14 @@ impl AssociateElasticIpError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateElasticIpError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateElasticIpError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3458,11 +3474,14 @@ impl AttachElasticLoadBalancerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AttachElasticLoadBalancerError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AttachElasticLoadBalancerError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3490,9 +3509,14 @@ impl CloneStackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CloneStackError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CloneStackError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3520,9 +3544,14 @@ impl CreateAppError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateAppError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAppError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3550,9 +3579,14 @@ impl CreateDeploymentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDeploymentError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3580,9 +3614,14 @@ impl CreateInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateInstanceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateInstanceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3610,9 +3649,14 @@ impl CreateLayerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateLayerError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLayerError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3636,7 +3680,7 @@ impl CreateStackError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CreateStackError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3658,7 +3702,7 @@ impl CreateUserProfileError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CreateUserProfileError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3684,9 +3728,14 @@ impl DeleteAppError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteAppError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3714,9 +3763,14 @@ impl DeleteInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteInstanceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteInstanceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3744,9 +3798,14 @@ impl DeleteLayerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteLayerError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLayerError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3774,9 +3833,14 @@ impl DeleteStackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteStackError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteStackError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3804,9 +3868,14 @@ impl DeleteUserProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteUserProfileError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserProfileError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3834,11 +3903,14 @@ impl DeregisterEcsClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeregisterEcsClusterError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeregisterEcsClusterError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3866,11 +3938,14 @@ impl DeregisterElasticIpError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeregisterElasticIpError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeregisterElasticIpError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3898,9 +3973,14 @@ impl DeregisterInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeregisterInstanceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterInstanceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3928,11 +4008,14 @@ impl DeregisterRdsDbInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeregisterRdsDbInstanceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeregisterRdsDbInstanceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3960,9 +4043,14 @@ impl DeregisterVolumeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeregisterVolumeError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterVolumeError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3990,11 +4078,14 @@ impl DescribeAgentVersionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeAgentVersionsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAgentVersionsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4022,9 +4113,14 @@ impl DescribeAppsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeAppsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAppsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4052,9 +4148,14 @@ impl DescribeCommandsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeCommandsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCommandsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4082,11 +4183,14 @@ impl DescribeDeploymentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDeploymentsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDeploymentsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4114,11 +4218,14 @@ impl DescribeEcsClustersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeEcsClustersError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEcsClustersError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4146,9 +4253,14 @@ impl DescribeElasticIpsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeElasticIpsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeElasticIpsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4178,11 +4290,14 @@ impl DescribeElasticLoadBalancersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeElasticLoadBalancersError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeElasticLoadBalancersError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4212,9 +4327,14 @@ impl DescribeInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeInstancesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeInstancesError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4242,9 +4362,14 @@ impl DescribeLayersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeLayersError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeLayersError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4274,11 +4399,14 @@ impl DescribeLoadBasedAutoScalingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeLoadBasedAutoScalingError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeLoadBasedAutoScalingError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4304,7 +4432,7 @@ impl DescribeMyUserProfileError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeMyUserProfileError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4326,7 +4454,7 @@ impl DescribeOperatingSystemsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeOperatingSystemsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4352,11 +4480,14 @@ impl DescribePermissionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribePermissionsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribePermissionsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4384,9 +4515,14 @@ impl DescribeRaidArraysError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeRaidArraysError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRaidArraysError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4414,11 +4550,14 @@ impl DescribeRdsDbInstancesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeRdsDbInstancesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRdsDbInstancesError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4446,11 +4585,14 @@ impl DescribeServiceErrorsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeServiceErrorsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeServiceErrorsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4480,11 +4622,14 @@ impl DescribeStackProvisioningParametersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeStackProvisioningParametersError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeStackProvisioningParametersError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4514,11 +4659,14 @@ impl DescribeStackSummaryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeStackSummaryError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeStackSummaryError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4546,9 +4694,14 @@ impl DescribeStacksError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeStacksError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeStacksError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4578,11 +4731,14 @@ impl DescribeTimeBasedAutoScalingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeTimeBasedAutoScalingError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTimeBasedAutoScalingError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4612,11 +4768,14 @@ impl DescribeUserProfilesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeUserProfilesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeUserProfilesError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4644,9 +4803,14 @@ impl DescribeVolumesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeVolumesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeVolumesError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4674,11 +4838,14 @@ impl DetachElasticLoadBalancerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DetachElasticLoadBalancerError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetachElasticLoadBalancerError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4706,11 +4873,14 @@ impl DisassociateElasticIpError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateElasticIpError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateElasticIpError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4738,11 +4908,14 @@ impl GetHostnameSuggestionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetHostnameSuggestionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetHostnameSuggestionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4770,9 +4943,14 @@ impl GrantAccessError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GrantAccessError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GrantAccessError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4800,9 +4978,14 @@ impl ListTagsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4830,9 +5013,14 @@ impl RebootInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RebootInstanceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RebootInstanceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4860,9 +5048,14 @@ impl RegisterEcsClusterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RegisterEcsClusterError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterEcsClusterError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4890,9 +5083,14 @@ impl RegisterElasticIpError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RegisterElasticIpError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterElasticIpError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4920,9 +5118,14 @@ impl RegisterInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RegisterInstanceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterInstanceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4950,11 +5153,14 @@ impl RegisterRdsDbInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RegisterRdsDbInstanceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterRdsDbInstanceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4982,9 +5188,14 @@ impl RegisterVolumeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RegisterVolumeError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterVolumeError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5012,11 +5223,14 @@ impl SetLoadBasedAutoScalingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SetLoadBasedAutoScalingError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetLoadBasedAutoScalingError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5044,9 +5258,14 @@ impl SetPermissionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SetPermissionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SetPermissionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5074,11 +5293,14 @@ impl SetTimeBasedAutoScalingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SetTimeBasedAutoScalingError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SetTimeBasedAutoScalingError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5106,9 +5328,14 @@ impl StartInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartInstanceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartInstanceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5136,9 +5363,14 @@ impl StartStackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartStackError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartStackError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5166,9 +5398,14 @@ impl StopInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopInstanceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopInstanceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5196,9 +5433,14 @@ impl StopStackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopStackError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopStackError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5226,9 +5468,14 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5256,9 +5503,14 @@ impl UnassignInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UnassignInstanceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UnassignInstanceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5286,9 +5538,14 @@ impl UnassignVolumeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UnassignVolumeError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UnassignVolumeError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5316,9 +5573,14 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5346,9 +5608,14 @@ impl UpdateAppError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateAppError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAppError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5376,9 +5643,14 @@ impl UpdateElasticIpError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateElasticIpError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateElasticIpError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5406,9 +5678,14 @@ impl UpdateInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateInstanceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateInstanceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5436,9 +5713,14 @@ impl UpdateLayerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateLayerError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateLayerError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5462,7 +5744,7 @@ impl UpdateMyUserProfileError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UpdateMyUserProfileError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5488,11 +5770,14 @@ impl UpdateRdsDbInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateRdsDbInstanceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRdsDbInstanceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5520,9 +5805,14 @@ impl UpdateStackError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateStackError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateStackError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5550,9 +5840,14 @@ impl UpdateUserProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateUserProfileError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserProfileError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5580,9 +5875,14 @@ impl UpdateVolumeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateVolumeError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateVolumeError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6047,11 +6347,12 @@ pub struct OpsWorksClient {
 impl OpsWorksClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> OpsWorksClient {
         OpsWorksClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "OpsWorks"),
         }
     }
 
@@ -6066,12 +6367,32 @@ impl OpsWorksClient {
     {
         OpsWorksClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "OpsWorks"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> OpsWorksClient {
-        OpsWorksClient { client, region }
+        OpsWorksClient {
+            client,
+            region: region_for_service(region, "OpsWorks"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> OpsWorksClient {
+        OpsWorksClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("OpsWorks"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> OpsWorksClient {
+        OpsWorksClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -6083,6 +6404,7 @@ impl OpsWorks for OpsWorksClient {
         input: AssignInstanceRequest,
     ) -> Result<(), RusotoError<AssignInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssignInstance");
         request.add_header("x-amz-target", "OpsWorks_20130218.AssignInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6100,6 +6422,7 @@ impl OpsWorks for OpsWorksClient {
         input: AssignVolumeRequest,
     ) -> Result<(), RusotoError<AssignVolumeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssignVolume");
         request.add_header("x-amz-target", "OpsWorks_20130218.AssignVolume");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6117,6 +6440,7 @@ impl OpsWorks for OpsWorksClient {
         input: AssociateElasticIpRequest,
     ) -> Result<(), RusotoError<AssociateElasticIpError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateElasticIp");
         request.add_header("x-amz-target", "OpsWorks_20130218.AssociateElasticIp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6134,6 +6458,7 @@ impl OpsWorks for OpsWorksClient {
         input: AttachElasticLoadBalancerRequest,
     ) -> Result<(), RusotoError<AttachElasticLoadBalancerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AttachElasticLoadBalancer");
         request.add_header(
             "x-amz-target",
             "OpsWorks_20130218.AttachElasticLoadBalancer",
@@ -6154,6 +6479,7 @@ impl OpsWorks for OpsWorksClient {
         input: CloneStackRequest,
     ) -> Result<CloneStackResult, RusotoError<CloneStackError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CloneStack");
         request.add_header("x-amz-target", "OpsWorks_20130218.CloneStack");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6172,6 +6498,7 @@ impl OpsWorks for OpsWorksClient {
         input: CreateAppRequest,
     ) -> Result<CreateAppResult, RusotoError<CreateAppError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateApp");
         request.add_header("x-amz-target", "OpsWorks_20130218.CreateApp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6190,6 +6517,7 @@ impl OpsWorks for OpsWorksClient {
         input: CreateDeploymentRequest,
     ) -> Result<CreateDeploymentResult, RusotoError<CreateDeploymentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateDeployment");
         request.add_header("x-amz-target", "OpsWorks_20130218.CreateDeployment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6208,6 +6536,7 @@ impl OpsWorks for OpsWorksClient {
         input: CreateInstanceRequest,
     ) -> Result<CreateInstanceResult, RusotoError<CreateInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateInstance");
         request.add_header("x-amz-target", "OpsWorks_20130218.CreateInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6226,6 +6555,7 @@ impl OpsWorks for OpsWorksClient {
         input: CreateLayerRequest,
     ) -> Result<CreateLayerResult, RusotoError<CreateLayerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateLayer");
         request.add_header("x-amz-target", "OpsWorks_20130218.CreateLayer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6244,6 +6574,7 @@ impl OpsWorks for OpsWorksClient {
         input: CreateStackRequest,
     ) -> Result<CreateStackResult, RusotoError<CreateStackError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateStack");
         request.add_header("x-amz-target", "OpsWorks_20130218.CreateStack");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6262,6 +6593,7 @@ impl OpsWorks for OpsWorksClient {
         input: CreateUserProfileRequest,
     ) -> Result<CreateUserProfileResult, RusotoError<CreateUserProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateUserProfile");
         request.add_header("x-amz-target", "OpsWorks_20130218.CreateUserProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6277,6 +6609,7 @@ impl OpsWorks for OpsWorksClient {
     /// <p>Deletes a specified app.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     async fn delete_app(&self, input: DeleteAppRequest) -> Result<(), RusotoError<DeleteAppError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteApp");
         request.add_header("x-amz-target", "OpsWorks_20130218.DeleteApp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6294,6 +6627,7 @@ impl OpsWorks for OpsWorksClient {
         input: DeleteInstanceRequest,
     ) -> Result<(), RusotoError<DeleteInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteInstance");
         request.add_header("x-amz-target", "OpsWorks_20130218.DeleteInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6311,6 +6645,7 @@ impl OpsWorks for OpsWorksClient {
         input: DeleteLayerRequest,
     ) -> Result<(), RusotoError<DeleteLayerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteLayer");
         request.add_header("x-amz-target", "OpsWorks_20130218.DeleteLayer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6328,6 +6663,7 @@ impl OpsWorks for OpsWorksClient {
         input: DeleteStackRequest,
     ) -> Result<(), RusotoError<DeleteStackError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteStack");
         request.add_header("x-amz-target", "OpsWorks_20130218.DeleteStack");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6345,6 +6681,7 @@ impl OpsWorks for OpsWorksClient {
         input: DeleteUserProfileRequest,
     ) -> Result<(), RusotoError<DeleteUserProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteUserProfile");
         request.add_header("x-amz-target", "OpsWorks_20130218.DeleteUserProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6362,6 +6699,7 @@ impl OpsWorks for OpsWorksClient {
         input: DeregisterEcsClusterRequest,
     ) -> Result<(), RusotoError<DeregisterEcsClusterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeregisterEcsCluster");
         request.add_header("x-amz-target", "OpsWorks_20130218.DeregisterEcsCluster");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6379,6 +6717,7 @@ impl OpsWorks for OpsWorksClient {
         input: DeregisterElasticIpRequest,
     ) -> Result<(), RusotoError<DeregisterElasticIpError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeregisterElasticIp");
         request.add_header("x-amz-target", "OpsWorks_20130218.DeregisterElasticIp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6396,6 +6735,7 @@ impl OpsWorks for OpsWorksClient {
         input: DeregisterInstanceRequest,
     ) -> Result<(), RusotoError<DeregisterInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeregisterInstance");
         request.add_header("x-amz-target", "OpsWorks_20130218.DeregisterInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6413,6 +6753,7 @@ impl OpsWorks for OpsWorksClient {
         input: DeregisterRdsDbInstanceRequest,
     ) -> Result<(), RusotoError<DeregisterRdsDbInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeregisterRdsDbInstance");
         request.add_header("x-amz-target", "OpsWorks_20130218.DeregisterRdsDbInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6430,6 +6771,7 @@ impl OpsWorks for OpsWorksClient {
         input: DeregisterVolumeRequest,
     ) -> Result<(), RusotoError<DeregisterVolumeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeregisterVolume");
         request.add_header("x-amz-target", "OpsWorks_20130218.DeregisterVolume");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6447,6 +6789,7 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeAgentVersionsRequest,
     ) -> Result<DescribeAgentVersionsResult, RusotoError<DescribeAgentVersionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeAgentVersions");
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeAgentVersions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6465,6 +6808,7 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeAppsRequest,
     ) -> Result<DescribeAppsResult, RusotoError<DescribeAppsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeApps");
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeApps");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6483,6 +6827,7 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeCommandsRequest,
     ) -> Result<DescribeCommandsResult, RusotoError<DescribeCommandsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeCommands");
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeCommands");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6501,6 +6846,7 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeDeploymentsRequest,
     ) -> Result<DescribeDeploymentsResult, RusotoError<DescribeDeploymentsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeDeployments");
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeDeployments");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6519,6 +6865,7 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeEcsClustersRequest,
     ) -> Result<DescribeEcsClustersResult, RusotoError<DescribeEcsClustersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeEcsClusters");
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeEcsClusters");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6537,6 +6884,7 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeElasticIpsRequest,
     ) -> Result<DescribeElasticIpsResult, RusotoError<DescribeElasticIpsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeElasticIps");
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeElasticIps");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6556,6 +6904,7 @@ impl OpsWorks for OpsWorksClient {
     ) -> Result<DescribeElasticLoadBalancersResult, RusotoError<DescribeElasticLoadBalancersError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeElasticLoadBalancers");
         request.add_header(
             "x-amz-target",
             "OpsWorks_20130218.DescribeElasticLoadBalancers",
@@ -6578,6 +6927,7 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeInstancesRequest,
     ) -> Result<DescribeInstancesResult, RusotoError<DescribeInstancesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeInstances");
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeInstances");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6596,6 +6946,7 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeLayersRequest,
     ) -> Result<DescribeLayersResult, RusotoError<DescribeLayersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeLayers");
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeLayers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6615,6 +6966,7 @@ impl OpsWorks for OpsWorksClient {
     ) -> Result<DescribeLoadBasedAutoScalingResult, RusotoError<DescribeLoadBasedAutoScalingError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeLoadBasedAutoScaling");
         request.add_header(
             "x-amz-target",
             "OpsWorks_20130218.DescribeLoadBasedAutoScaling",
@@ -6636,6 +6988,7 @@ impl OpsWorks for OpsWorksClient {
         &self,
     ) -> Result<DescribeMyUserProfileResult, RusotoError<DescribeMyUserProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeMyUserProfile");
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeMyUserProfile");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -6652,6 +7005,7 @@ impl OpsWorks for OpsWorksClient {
         &self,
     ) -> Result<DescribeOperatingSystemsResponse, RusotoError<DescribeOperatingSystemsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeOperatingSystems");
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeOperatingSystems");
         request.set_payload(Some(bytes::Bytes::from_static(b"{}")));
 
@@ -6670,6 +7024,7 @@ impl OpsWorks for OpsWorksClient {
         input: DescribePermissionsRequest,
     ) -> Result<DescribePermissionsResult, RusotoError<DescribePermissionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribePermissions");
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribePermissions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6688,6 +7043,7 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeRaidArraysRequest,
     ) -> Result<DescribeRaidArraysResult, RusotoError<DescribeRaidArraysError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeRaidArrays");
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeRaidArrays");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6706,6 +7062,7 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeRdsDbInstancesRequest,
     ) -> Result<DescribeRdsDbInstancesResult, RusotoError<DescribeRdsDbInstancesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeRdsDbInstances");
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeRdsDbInstances");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6725,6 +7082,7 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeServiceErrorsRequest,
     ) -> Result<DescribeServiceErrorsResult, RusotoError<DescribeServiceErrorsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeServiceErrors");
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeServiceErrors");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6746,6 +7104,7 @@ impl OpsWorks for OpsWorksClient {
         RusotoError<DescribeStackProvisioningParametersError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeStackProvisioningParameters");
         request.add_header(
             "x-amz-target",
             "OpsWorks_20130218.DescribeStackProvisioningParameters",
@@ -6771,6 +7130,7 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeStackSummaryRequest,
     ) -> Result<DescribeStackSummaryResult, RusotoError<DescribeStackSummaryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeStackSummary");
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeStackSummary");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6789,6 +7149,7 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeStacksRequest,
     ) -> Result<DescribeStacksResult, RusotoError<DescribeStacksError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeStacks");
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeStacks");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6808,6 +7169,7 @@ impl OpsWorks for OpsWorksClient {
     ) -> Result<DescribeTimeBasedAutoScalingResult, RusotoError<DescribeTimeBasedAutoScalingError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeTimeBasedAutoScaling");
         request.add_header(
             "x-amz-target",
             "OpsWorks_20130218.DescribeTimeBasedAutoScaling",
@@ -6830,6 +7192,7 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeUserProfilesRequest,
     ) -> Result<DescribeUserProfilesResult, RusotoError<DescribeUserProfilesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeUserProfiles");
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeUserProfiles");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6848,6 +7211,7 @@ impl OpsWorks for OpsWorksClient {
         input: DescribeVolumesRequest,
     ) -> Result<DescribeVolumesResult, RusotoError<DescribeVolumesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeVolumes");
         request.add_header("x-amz-target", "OpsWorks_20130218.DescribeVolumes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6866,6 +7230,7 @@ impl OpsWorks for OpsWorksClient {
         input: DetachElasticLoadBalancerRequest,
     ) -> Result<(), RusotoError<DetachElasticLoadBalancerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DetachElasticLoadBalancer");
         request.add_header(
             "x-amz-target",
             "OpsWorks_20130218.DetachElasticLoadBalancer",
@@ -6886,6 +7251,7 @@ impl OpsWorks for OpsWorksClient {
         input: DisassociateElasticIpRequest,
     ) -> Result<(), RusotoError<DisassociateElasticIpError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisassociateElasticIp");
         request.add_header("x-amz-target", "OpsWorks_20130218.DisassociateElasticIp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6903,6 +7269,7 @@ impl OpsWorks for OpsWorksClient {
         input: GetHostnameSuggestionRequest,
     ) -> Result<GetHostnameSuggestionResult, RusotoError<GetHostnameSuggestionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetHostnameSuggestion");
         request.add_header("x-amz-target", "OpsWorks_20130218.GetHostnameSuggestion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6921,6 +7288,7 @@ impl OpsWorks for OpsWorksClient {
         input: GrantAccessRequest,
     ) -> Result<GrantAccessResult, RusotoError<GrantAccessError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GrantAccess");
         request.add_header("x-amz-target", "OpsWorks_20130218.GrantAccess");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6939,6 +7307,7 @@ impl OpsWorks for OpsWorksClient {
         input: ListTagsRequest,
     ) -> Result<ListTagsResult, RusotoError<ListTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTags");
         request.add_header("x-amz-target", "OpsWorks_20130218.ListTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6957,6 +7326,7 @@ impl OpsWorks for OpsWorksClient {
         input: RebootInstanceRequest,
     ) -> Result<(), RusotoError<RebootInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RebootInstance");
         request.add_header("x-amz-target", "OpsWorks_20130218.RebootInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6974,6 +7344,7 @@ impl OpsWorks for OpsWorksClient {
         input: RegisterEcsClusterRequest,
     ) -> Result<RegisterEcsClusterResult, RusotoError<RegisterEcsClusterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RegisterEcsCluster");
         request.add_header("x-amz-target", "OpsWorks_20130218.RegisterEcsCluster");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6992,6 +7363,7 @@ impl OpsWorks for OpsWorksClient {
         input: RegisterElasticIpRequest,
     ) -> Result<RegisterElasticIpResult, RusotoError<RegisterElasticIpError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RegisterElasticIp");
         request.add_header("x-amz-target", "OpsWorks_20130218.RegisterElasticIp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7010,6 +7382,7 @@ impl OpsWorks for OpsWorksClient {
         input: RegisterInstanceRequest,
     ) -> Result<RegisterInstanceResult, RusotoError<RegisterInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RegisterInstance");
         request.add_header("x-amz-target", "OpsWorks_20130218.RegisterInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7028,6 +7401,7 @@ impl OpsWorks for OpsWorksClient {
         input: RegisterRdsDbInstanceRequest,
     ) -> Result<(), RusotoError<RegisterRdsDbInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RegisterRdsDbInstance");
         request.add_header("x-amz-target", "OpsWorks_20130218.RegisterRdsDbInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7045,6 +7419,7 @@ impl OpsWorks for OpsWorksClient {
         input: RegisterVolumeRequest,
     ) -> Result<RegisterVolumeResult, RusotoError<RegisterVolumeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RegisterVolume");
         request.add_header("x-amz-target", "OpsWorks_20130218.RegisterVolume");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7063,6 +7438,7 @@ impl OpsWorks for OpsWorksClient {
         input: SetLoadBasedAutoScalingRequest,
     ) -> Result<(), RusotoError<SetLoadBasedAutoScalingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SetLoadBasedAutoScaling");
         request.add_header("x-amz-target", "OpsWorks_20130218.SetLoadBasedAutoScaling");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7080,6 +7456,7 @@ impl OpsWorks for OpsWorksClient {
         input: SetPermissionRequest,
     ) -> Result<(), RusotoError<SetPermissionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SetPermission");
         request.add_header("x-amz-target", "OpsWorks_20130218.SetPermission");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7097,6 +7474,7 @@ impl OpsWorks for OpsWorksClient {
         input: SetTimeBasedAutoScalingRequest,
     ) -> Result<(), RusotoError<SetTimeBasedAutoScalingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SetTimeBasedAutoScaling");
         request.add_header("x-amz-target", "OpsWorks_20130218.SetTimeBasedAutoScaling");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7114,6 +7492,7 @@ impl OpsWorks for OpsWorksClient {
         input: StartInstanceRequest,
     ) -> Result<(), RusotoError<StartInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartInstance");
         request.add_header("x-amz-target", "OpsWorks_20130218.StartInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7131,6 +7510,7 @@ impl OpsWorks for OpsWorksClient {
         input: StartStackRequest,
     ) -> Result<(), RusotoError<StartStackError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartStack");
         request.add_header("x-amz-target", "OpsWorks_20130218.StartStack");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7148,6 +7528,7 @@ impl OpsWorks for OpsWorksClient {
         input: StopInstanceRequest,
     ) -> Result<(), RusotoError<StopInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopInstance");
         request.add_header("x-amz-target", "OpsWorks_20130218.StopInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7162,6 +7543,7 @@ impl OpsWorks for OpsWorksClient {
     /// <p>Stops a specified stack.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     async fn stop_stack(&self, input: StopStackRequest) -> Result<(), RusotoError<StopStackError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopStack");
         request.add_header("x-amz-target", "OpsWorks_20130218.StopStack");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7179,6 +7561,7 @@ impl OpsWorks for OpsWorksClient {
         input: TagResourceRequest,
     ) -> Result<(), RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "OpsWorks_20130218.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7196,6 +7579,7 @@ impl OpsWorks for OpsWorksClient {
         input: UnassignInstanceRequest,
     ) -> Result<(), RusotoError<UnassignInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UnassignInstance");
         request.add_header("x-amz-target", "OpsWorks_20130218.UnassignInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7213,6 +7597,7 @@ impl OpsWorks for OpsWorksClient {
         input: UnassignVolumeRequest,
     ) -> Result<(), RusotoError<UnassignVolumeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UnassignVolume");
         request.add_header("x-amz-target", "OpsWorks_20130218.UnassignVolume");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7230,6 +7615,7 @@ impl OpsWorks for OpsWorksClient {
         input: UntagResourceRequest,
     ) -> Result<(), RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "OpsWorks_20130218.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7244,6 +7630,7 @@ impl OpsWorks for OpsWorksClient {
     /// <p>Updates a specified app.</p> <p> <b>Required Permissions</b>: To use this action, an IAM user must have a Deploy or Manage permissions level for the stack, or an attached policy that explicitly grants permissions. For more information on user permissions, see <a href="https://docs.aws.amazon.com/opsworks/latest/userguide/opsworks-security-users.html">Managing User Permissions</a>.</p>
     async fn update_app(&self, input: UpdateAppRequest) -> Result<(), RusotoError<UpdateAppError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateApp");
         request.add_header("x-amz-target", "OpsWorks_20130218.UpdateApp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7261,6 +7648,7 @@ impl OpsWorks for OpsWorksClient {
         input: UpdateElasticIpRequest,
     ) -> Result<(), RusotoError<UpdateElasticIpError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateElasticIp");
         request.add_header("x-amz-target", "OpsWorks_20130218.UpdateElasticIp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7278,6 +7666,7 @@ impl OpsWorks for OpsWorksClient {
         input: UpdateInstanceRequest,
     ) -> Result<(), RusotoError<UpdateInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateInstance");
         request.add_header("x-amz-target", "OpsWorks_20130218.UpdateInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7295,6 +7684,7 @@ impl OpsWorks for OpsWorksClient {
         input: UpdateLayerRequest,
     ) -> Result<(), RusotoError<UpdateLayerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateLayer");
         request.add_header("x-amz-target", "OpsWorks_20130218.UpdateLayer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7312,6 +7702,7 @@ impl OpsWorks for OpsWorksClient {
         input: UpdateMyUserProfileRequest,
     ) -> Result<(), RusotoError<UpdateMyUserProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateMyUserProfile");
         request.add_header("x-amz-target", "OpsWorks_20130218.UpdateMyUserProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7329,6 +7720,7 @@ impl OpsWorks for OpsWorksClient {
         input: UpdateRdsDbInstanceRequest,
     ) -> Result<(), RusotoError<UpdateRdsDbInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateRdsDbInstance");
         request.add_header("x-amz-target", "OpsWorks_20130218.UpdateRdsDbInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7346,6 +7738,7 @@ impl OpsWorks for OpsWorksClient {
         input: UpdateStackRequest,
     ) -> Result<(), RusotoError<UpdateStackError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateStack");
         request.add_header("x-amz-target", "OpsWorks_20130218.UpdateStack");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7363,6 +7756,7 @@ impl OpsWorks for OpsWorksClient {
         input: UpdateUserProfileRequest,
     ) -> Result<(), RusotoError<UpdateUserProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateUserProfile");
         request.add_header("x-amz-target", "OpsWorks_20130218.UpdateUserProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7380,6 +7774,7 @@ impl OpsWorks for OpsWorksClient {
         input: UpdateVolumeRequest,
     ) -> Result<(), RusotoError<UpdateVolumeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateVolume");
         request.add_header("x-amz-target", "OpsWorks_20130218.UpdateVolume");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7391,3 +7786,700 @@ impl OpsWorks for OpsWorksClient {
         Ok(())
     }
 }
+
+/// A blocking client for the AWS OpsWorks API, running the calls of a `OpsWorksClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `OpsWorksClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct OpsWorksBlockingClient {
+    client: OpsWorksClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl OpsWorksBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> OpsWorksBlockingClient {
+        OpsWorksBlockingClient::from_client(OpsWorksClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> OpsWorksBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        OpsWorksBlockingClient::from_client(OpsWorksClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> OpsWorksBlockingClient {
+        OpsWorksBlockingClient::from_client(OpsWorksClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> OpsWorksBlockingClient {
+        OpsWorksBlockingClient::from_client(OpsWorksClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: OpsWorksClient) -> OpsWorksBlockingClient {
+        OpsWorksBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> OpsWorksBlockingClient {
+        OpsWorksBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> OpsWorksBlockingClient {
+        OpsWorksBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`OpsWorks::assign_instance`].
+    pub fn assign_instance(
+        &self,
+        input: AssignInstanceRequest,
+    ) -> Result<(), RusotoError<AssignInstanceError>> {
+        self.runtime.block_on(self.client.assign_instance(input))
+    }
+
+    /// Blocking version of [`OpsWorks::assign_volume`].
+    pub fn assign_volume(
+        &self,
+        input: AssignVolumeRequest,
+    ) -> Result<(), RusotoError<AssignVolumeError>> {
+        self.runtime.block_on(self.client.assign_volume(input))
+    }
+
+    /// Blocking version of [`OpsWorks::associate_elastic_ip`].
+    pub fn associate_elastic_ip(
+        &self,
+        input: AssociateElasticIpRequest,
+    ) -> Result<(), RusotoError<AssociateElasticIpError>> {
+        self.runtime
+            .block_on(self.client.associate_elastic_ip(input))
+    }
+
+    /// Blocking version of [`OpsWorks::attach_elastic_load_balancer`].
+    pub fn attach_elastic_load_balancer(
+        &self,
+        input: AttachElasticLoadBalancerRequest,
+    ) -> Result<(), RusotoError<AttachElasticLoadBalancerError>> {
+        self.runtime
+            .block_on(self.client.attach_elastic_load_balancer(input))
+    }
+
+    /// Blocking version of [`OpsWorks::clone_stack`].
+    pub fn clone_stack(
+        &self,
+        input: CloneStackRequest,
+    ) -> Result<CloneStackResult, RusotoError<CloneStackError>> {
+        self.runtime.block_on(self.client.clone_stack(input))
+    }
+
+    /// Blocking version of [`OpsWorks::create_app`].
+    pub fn create_app(
+        &self,
+        input: CreateAppRequest,
+    ) -> Result<CreateAppResult, RusotoError<CreateAppError>> {
+        self.runtime.block_on(self.client.create_app(input))
+    }
+
+    /// Blocking version of [`OpsWorks::create_deployment`].
+    pub fn create_deployment(
+        &self,
+        input: CreateDeploymentRequest,
+    ) -> Result<CreateDeploymentResult, RusotoError<CreateDeploymentError>> {
+        self.runtime.block_on(self.client.create_deployment(input))
+    }
+
+    /// Blocking version of [`OpsWorks::create_instance`].
+    pub fn create_instance(
+        &self,
+        input: CreateInstanceRequest,
+    ) -> Result<CreateInstanceResult, RusotoError<CreateInstanceError>> {
+        self.runtime.block_on(self.client.create_instance(input))
+    }
+
+    /// Blocking version of [`OpsWorks::create_layer`].
+    pub fn create_layer(
+        &self,
+        input: CreateLayerRequest,
+    ) -> Result<CreateLayerResult, RusotoError<CreateLayerError>> {
+        self.runtime.block_on(self.client.create_layer(input))
+    }
+
+    /// Blocking version of [`OpsWorks::create_stack`].
+    pub fn create_stack(
+        &self,
+        input: CreateStackRequest,
+    ) -> Result<CreateStackResult, RusotoError<CreateStackError>> {
+        self.runtime.block_on(self.client.create_stack(input))
+    }
+
+    /// Blocking version of [`OpsWorks::create_user_profile`].
+    pub fn create_user_profile(
+        &self,
+        input: CreateUserProfileRequest,
+    ) -> Result<CreateUserProfileResult, RusotoError<CreateUserProfileError>> {
+        self.runtime
+            .block_on(self.client.create_user_profile(input))
+    }
+
+    /// Blocking version of [`OpsWorks::delete_app`].
+    pub fn delete_app(&self, input: DeleteAppRequest) -> Result<(), RusotoError<DeleteAppError>> {
+        self.runtime.block_on(self.client.delete_app(input))
+    }
+
+    /// Blocking version of [`OpsWorks::delete_instance`].
+    pub fn delete_instance(
+        &self,
+        input: DeleteInstanceRequest,
+    ) -> Result<(), RusotoError<DeleteInstanceError>> {
+        self.runtime.block_on(self.client.delete_instance(input))
+    }
+
+    /// Blocking version of [`OpsWorks::delete_layer`].
+    pub fn delete_layer(
+        &self,
+        input: DeleteLayerRequest,
+    ) -> Result<(), RusotoError<DeleteLayerError>> {
+        self.runtime.block_on(self.client.delete_layer(input))
+    }
+
+    /// Blocking version of [`OpsWorks::delete_stack`].
+    pub fn delete_stack(
+        &self,
+        input: DeleteStackRequest,
+    ) -> Result<(), RusotoError<DeleteStackError>> {
+        self.runtime.block_on(self.client.delete_stack(input))
+    }
+
+    /// Blocking version of [`OpsWorks::delete_user_profile`].
+    pub fn delete_user_profile(
+        &self,
+        input: DeleteUserProfileRequest,
+    ) -> Result<(), RusotoError<DeleteUserProfileError>> {
+        self.runtime
+            .block_on(self.client.delete_user_profile(input))
+    }
+
+    /// Blocking version of [`OpsWorks::deregister_ecs_cluster`].
+    pub fn deregister_ecs_cluster(
+        &self,
+        input: DeregisterEcsClusterRequest,
+    ) -> Result<(), RusotoError<DeregisterEcsClusterError>> {
+        self.runtime
+            .block_on(self.client.deregister_ecs_cluster(input))
+    }
+
+    /// Blocking version of [`OpsWorks::deregister_elastic_ip`].
+    pub fn deregister_elastic_ip(
+        &self,
+        input: DeregisterElasticIpRequest,
+    ) -> Result<(), RusotoError<DeregisterElasticIpError>> {
+        self.runtime
+            .block_on(self.client.deregister_elastic_ip(input))
+    }
+
+    /// Blocking version of [`OpsWorks::deregister_instance`].
+    pub fn deregister_instance(
+        &self,
+        input: DeregisterInstanceRequest,
+    ) -> Result<(), RusotoError<DeregisterInstanceError>> {
+        self.runtime
+            .block_on(self.client.deregister_instance(input))
+    }
+
+    /// Blocking version of [`OpsWorks::deregister_rds_db_instance`].
+    pub fn deregister_rds_db_instance(
+        &self,
+        input: DeregisterRdsDbInstanceRequest,
+    ) -> Result<(), RusotoError<DeregisterRdsDbInstanceError>> {
+        self.runtime
+            .block_on(self.client.deregister_rds_db_instance(input))
+    }
+
+    /// Blocking version of [`OpsWorks::deregister_volume`].
+    pub fn deregister_volume(
+        &self,
+        input: DeregisterVolumeRequest,
+    ) -> Result<(), RusotoError<DeregisterVolumeError>> {
+        self.runtime.block_on(self.client.deregister_volume(input))
+    }
+
+    /// Blocking version of [`OpsWorks::describe_agent_versions`].
+    pub fn describe_agent_versions(
+        &self,
+        input: DescribeAgentVersionsRequest,
+    ) -> Result<DescribeAgentVersionsResult, RusotoError<DescribeAgentVersionsError>> {
+        self.runtime
+            .block_on(self.client.describe_agent_versions(input))
+    }
+
+    /// Blocking version of [`OpsWorks::describe_apps`].
+    pub fn describe_apps(
+        &self,
+        input: DescribeAppsRequest,
+    ) -> Result<DescribeAppsResult, RusotoError<DescribeAppsError>> {
+        self.runtime.block_on(self.client.describe_apps(input))
+    }
+
+    /// Blocking version of [`OpsWorks::describe_commands`].
+    pub fn describe_commands(
+        &self,
+        input: DescribeCommandsRequest,
+    ) -> Result<DescribeCommandsResult, RusotoError<DescribeCommandsError>> {
+        self.runtime.block_on(self.client.describe_commands(input))
+    }
+
+    /// Blocking version of [`OpsWorks::describe_deployments`].
+    pub fn describe_deployments(
+        &self,
+        input: DescribeDeploymentsRequest,
+    ) -> Result<DescribeDeploymentsResult, RusotoError<DescribeDeploymentsError>> {
+        self.runtime
+            .block_on(self.client.describe_deployments(input))
+    }
+
+    /// Blocking version of [`OpsWorks::describe_ecs_clusters`].
+    pub fn describe_ecs_clusters(
+        &self,
+        input: DescribeEcsClustersRequest,
+    ) -> Result<DescribeEcsClustersResult, RusotoError<DescribeEcsClustersError>> {
+        self.runtime
+            .block_on(self.client.describe_ecs_clusters(input))
+    }
+
+    /// Blocking version of [`OpsWorks::describe_elastic_ips`].
+    pub fn describe_elastic_ips(
+        &self,
+        input: DescribeElasticIpsRequest,
+    ) -> Result<DescribeElasticIpsResult, RusotoError<DescribeElasticIpsError>> {
+        self.runtime
+            .block_on(self.client.describe_elastic_ips(input))
+    }
+
+    /// Blocking version of [`OpsWorks::describe_elastic_load_balancers`].
+    pub fn describe_elastic_load_balancers(
+        &self,
+        input: DescribeElasticLoadBalancersRequest,
+    ) -> Result<DescribeElasticLoadBalancersResult, RusotoError<DescribeElasticLoadBalancersError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_elastic_load_balancers(input))
+    }
+
+    /// Blocking version of [`OpsWorks::describe_instances`].
+    pub fn describe_instances(
+        &self,
+        input: DescribeInstancesRequest,
+    ) -> Result<DescribeInstancesResult, RusotoError<DescribeInstancesError>> {
+        self.runtime.block_on(self.client.describe_instances(input))
+    }
+
+    /// Blocking version of [`OpsWorks::describe_layers`].
+    pub fn describe_layers(
+        &self,
+        input: DescribeLayersRequest,
+    ) -> Result<DescribeLayersResult, RusotoError<DescribeLayersError>> {
+        self.runtime.block_on(self.client.describe_layers(input))
+    }
+
+    /// Blocking version of [`OpsWorks::describe_load_based_auto_scaling`].
+    pub fn describe_load_based_auto_scaling(
+        &self,
+        input: DescribeLoadBasedAutoScalingRequest,
+    ) -> Result<DescribeLoadBasedAutoScalingResult, RusotoError<DescribeLoadBasedAutoScalingError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_load_based_auto_scaling(input))
+    }
+
+    /// Blocking version of [`OpsWorks::describe_my_user_profile`].
+    pub fn describe_my_user_profile(
+        &self,
+    ) -> Result<DescribeMyUserProfileResult, RusotoError<DescribeMyUserProfileError>> {
+        self.runtime
+            .block_on(self.client.describe_my_user_profile())
+    }
+
+    /// Blocking version of [`OpsWorks::describe_operating_systems`].
+    pub fn describe_operating_systems(
+        &self,
+    ) -> Result<DescribeOperatingSystemsResponse, RusotoError<DescribeOperatingSystemsError>> {
+        self.runtime
+            .block_on(self.client.describe_operating_systems())
+    }
+
+    /// Blocking version of [`OpsWorks::describe_permissions`].
+    pub fn describe_permissions(
+        &self,
+        input: DescribePermissionsRequest,
+    ) -> Result<DescribePermissionsResult, RusotoError<DescribePermissionsError>> {
+        self.runtime
+            .block_on(self.client.describe_permissions(input))
+    }
+
+    /// Blocking version of [`OpsWorks::describe_raid_arrays`].
+    pub fn describe_raid_arrays(
+        &self,
+        input: DescribeRaidArraysRequest,
+    ) -> Result<DescribeRaidArraysResult, RusotoError<DescribeRaidArraysError>> {
+        self.runtime
+            .block_on(self.client.describe_raid_arrays(input))
+    }
+
+    /// Blocking version of [`OpsWorks::describe_rds_db_instances`].
+    pub fn describe_rds_db_instances(
+        &self,
+        input: DescribeRdsDbInstancesRequest,
+    ) -> Result<DescribeRdsDbInstancesResult, RusotoError<DescribeRdsDbInstancesError>> {
+        self.runtime
+            .block_on(self.client.describe_rds_db_instances(input))
+    }
+
+    /// Blocking version of [`OpsWorks::describe_service_errors`].
+    pub fn describe_service_errors(
+        &self,
+        input: DescribeServiceErrorsRequest,
+    ) -> Result<DescribeServiceErrorsResult, RusotoError<DescribeServiceErrorsError>> {
+        self.runtime
+            .block_on(self.client.describe_service_errors(input))
+    }
+
+    /// Blocking version of [`OpsWorks::describe_stack_provisioning_parameters`].
+    pub fn describe_stack_provisioning_parameters(
+        &self,
+        input: DescribeStackProvisioningParametersRequest,
+    ) -> Result<
+        DescribeStackProvisioningParametersResult,
+        RusotoError<DescribeStackProvisioningParametersError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_stack_provisioning_parameters(input))
+    }
+
+    /// Blocking version of [`OpsWorks::describe_stack_summary`].
+    pub fn describe_stack_summary(
+        &self,
+        input: DescribeStackSummaryRequest,
+    ) -> Result<DescribeStackSummaryResult, RusotoError<DescribeStackSummaryError>> {
+        self.runtime
+            .block_on(self.client.describe_stack_summary(input))
+    }
+
+    /// Blocking version of [`OpsWorks::describe_stacks`].
+    pub fn describe_stacks(
+        &self,
+        input: DescribeStacksRequest,
+    ) -> Result<DescribeStacksResult, RusotoError<DescribeStacksError>> {
+        self.runtime.block_on(self.client.describe_stacks(input))
+    }
+
+    /// Blocking version of [`OpsWorks::describe_time_based_auto_scaling`].
+    pub fn describe_time_based_auto_scaling(
+        &self,
+        input: DescribeTimeBasedAutoScalingRequest,
+    ) -> Result<DescribeTimeBasedAutoScalingResult, RusotoError<DescribeTimeBasedAutoScalingError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_time_based_auto_scaling(input))
+    }
+
+    /// Blocking version of [`OpsWorks::describe_user_profiles`].
+    pub fn describe_user_profiles(
+        &self,
+        input: DescribeUserProfilesRequest,
+    ) -> Result<DescribeUserProfilesResult, RusotoError<DescribeUserProfilesError>> {
+        self.runtime
+            .block_on(self.client.describe_user_profiles(input))
+    }
+
+    /// Blocking version of [`OpsWorks::describe_volumes`].
+    pub fn describe_volumes(
+        &self,
+        input: DescribeVolumesRequest,
+    ) -> Result<DescribeVolumesResult, RusotoError<DescribeVolumesError>> {
+        self.runtime.block_on(self.client.describe_volumes(input))
+    }
+
+    /// Blocking version of [`OpsWorks::detach_elastic_load_balancer`].
+    pub fn detach_elastic_load_balancer(
+        &self,
+        input: DetachElasticLoadBalancerRequest,
+    ) -> Result<(), RusotoError<DetachElasticLoadBalancerError>> {
+        self.runtime
+            .block_on(self.client.detach_elastic_load_balancer(input))
+    }
+
+    /// Blocking version of [`OpsWorks::disassociate_elastic_ip`].
+    pub fn disassociate_elastic_ip(
+        &self,
+        input: DisassociateElasticIpRequest,
+    ) -> Result<(), RusotoError<DisassociateElasticIpError>> {
+        self.runtime
+            .block_on(self.client.disassociate_elastic_ip(input))
+    }
+
+    /// Blocking version of [`OpsWorks::get_hostname_suggestion`].
+    pub fn get_hostname_suggestion(
+        &self,
+        input: GetHostnameSuggestionRequest,
+    ) -> Result<GetHostnameSuggestionResult, RusotoError<GetHostnameSuggestionError>> {
+        self.runtime
+            .block_on(self.client.get_hostname_suggestion(input))
+    }
+
+    /// Blocking version of [`OpsWorks::grant_access`].
+    pub fn grant_access(
+        &self,
+        input: GrantAccessRequest,
+    ) -> Result<GrantAccessResult, RusotoError<GrantAccessError>> {
+        self.runtime.block_on(self.client.grant_access(input))
+    }
+
+    /// Blocking version of [`OpsWorks::list_tags`].
+    pub fn list_tags(
+        &self,
+        input: ListTagsRequest,
+    ) -> Result<ListTagsResult, RusotoError<ListTagsError>> {
+        self.runtime.block_on(self.client.list_tags(input))
+    }
+
+    /// Blocking version of [`OpsWorks::reboot_instance`].
+    pub fn reboot_instance(
+        &self,
+        input: RebootInstanceRequest,
+    ) -> Result<(), RusotoError<RebootInstanceError>> {
+        self.runtime.block_on(self.client.reboot_instance(input))
+    }
+
+    /// Blocking version of [`OpsWorks::register_ecs_cluster`].
+    pub fn register_ecs_cluster(
+        &self,
+        input: RegisterEcsClusterRequest,
+    ) -> Result<RegisterEcsClusterResult, RusotoError<RegisterEcsClusterError>> {
+        self.runtime
+            .block_on(self.client.register_ecs_cluster(input))
+    }
+
+    /// Blocking version of [`OpsWorks::register_elastic_ip`].
+    pub fn register_elastic_ip(
+        &self,
+        input: RegisterElasticIpRequest,
+    ) -> Result<RegisterElasticIpResult, RusotoError<RegisterElasticIpError>> {
+        self.runtime
+            .block_on(self.client.register_elastic_ip(input))
+    }
+
+    /// Blocking version of [`OpsWorks::register_instance`].
+    pub fn register_instance(
+        &self,
+        input: RegisterInstanceRequest,
+    ) -> Result<RegisterInstanceResult, RusotoError<RegisterInstanceError>> {
+        self.runtime.block_on(self.client.register_instance(input))
+    }
+
+    /// Blocking version of [`OpsWorks::register_rds_db_instance`].
+    pub fn register_rds_db_instance(
+        &self,
+        input: RegisterRdsDbInstanceRequest,
+    ) -> Result<(), RusotoError<RegisterRdsDbInstanceError>> {
+        self.runtime
+            .block_on(self.client.register_rds_db_instance(input))
+    }
+
+    /// Blocking version of [`OpsWorks::register_volume`].
+    pub fn register_volume(
+        &self,
+        input: RegisterVolumeRequest,
+    ) -> Result<RegisterVolumeResult, RusotoError<RegisterVolumeError>> {
+        self.runtime.block_on(self.client.register_volume(input))
+    }
+
+    /// Blocking version of [`OpsWorks::set_load_based_auto_scaling`].
+    pub fn set_load_based_auto_scaling(
+        &self,
+        input: SetLoadBasedAutoScalingRequest,
+    ) -> Result<(), RusotoError<SetLoadBasedAutoScalingError>> {
+        self.runtime
+            .block_on(self.client.set_load_based_auto_scaling(input))
+    }
+
+    /// Blocking version of [`OpsWorks::set_permission`].
+    pub fn set_permission(
+        &self,
+        input: SetPermissionRequest,
+    ) -> Result<(), RusotoError<SetPermissionError>> {
+        self.runtime.block_on(self.client.set_permission(input))
+    }
+
+    /// Blocking version of [`OpsWorks::set_time_based_auto_scaling`].
+    pub fn set_time_based_auto_scaling(
+        &self,
+        input: SetTimeBasedAutoScalingRequest,
+    ) -> Result<(), RusotoError<SetTimeBasedAutoScalingError>> {
+        self.runtime
+            .block_on(self.client.set_time_based_auto_scaling(input))
+    }
+
+    /// Blocking version of [`OpsWorks::start_instance`].
+    pub fn start_instance(
+        &self,
+        input: StartInstanceRequest,
+    ) -> Result<(), RusotoError<StartInstanceError>> {
+        self.runtime.block_on(self.client.start_instance(input))
+    }
+
+    /// Blocking version of [`OpsWorks::start_stack`].
+    pub fn start_stack(
+        &self,
+        input: StartStackRequest,
+    ) -> Result<(), RusotoError<StartStackError>> {
+        self.runtime.block_on(self.client.start_stack(input))
+    }
+
+    /// Blocking version of [`OpsWorks::stop_instance`].
+    pub fn stop_instance(
+        &self,
+        input: StopInstanceRequest,
+    ) -> Result<(), RusotoError<StopInstanceError>> {
+        self.runtime.block_on(self.client.stop_instance(input))
+    }
+
+    /// Blocking version of [`OpsWorks::stop_stack`].
+    pub fn stop_stack(&self, input: StopStackRequest) -> Result<(), RusotoError<StopStackError>> {
+        self.runtime.block_on(self.client.stop_stack(input))
+    }
+
+    /// Blocking version of [`OpsWorks::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`OpsWorks::unassign_instance`].
+    pub fn unassign_instance(
+        &self,
+        input: UnassignInstanceRequest,
+    ) -> Result<(), RusotoError<UnassignInstanceError>> {
+        self.runtime.block_on(self.client.unassign_instance(input))
+    }
+
+    /// Blocking version of [`OpsWorks::unassign_volume`].
+    pub fn unassign_volume(
+        &self,
+        input: UnassignVolumeRequest,
+    ) -> Result<(), RusotoError<UnassignVolumeError>> {
+        self.runtime.block_on(self.client.unassign_volume(input))
+    }
+
+    /// Blocking version of [`OpsWorks::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`OpsWorks::update_app`].
+    pub fn update_app(&self, input: UpdateAppRequest) -> Result<(), RusotoError<UpdateAppError>> {
+        self.runtime.block_on(self.client.update_app(input))
+    }
+
+    /// Blocking version of [`OpsWorks::update_elastic_ip`].
+    pub fn update_elastic_ip(
+        &self,
+        input: UpdateElasticIpRequest,
+    ) -> Result<(), RusotoError<UpdateElasticIpError>> {
+        self.runtime.block_on(self.client.update_elastic_ip(input))
+    }
+
+    /// Blocking version of [`OpsWorks::update_instance`].
+    pub fn update_instance(
+        &self,
+        input: UpdateInstanceRequest,
+    ) -> Result<(), RusotoError<UpdateInstanceError>> {
+        self.runtime.block_on(self.client.update_instance(input))
+    }
+
+    /// Blocking version of [`OpsWorks::update_layer`].
+    pub fn update_layer(
+        &self,
+        input: UpdateLayerRequest,
+    ) -> Result<(), RusotoError<UpdateLayerError>> {
+        self.runtime.block_on(self.client.update_layer(input))
+    }
+
+    /// Blocking version of [`OpsWorks::update_my_user_profile`].
+    pub fn update_my_user_profile(
+        &self,
+        input: UpdateMyUserProfileRequest,
+    ) -> Result<(), RusotoError<UpdateMyUserProfileError>> {
+        self.runtime
+            .block_on(self.client.update_my_user_profile(input))
+    }
+
+    /// Blocking version of [`OpsWorks::update_rds_db_instance`].
+    pub fn update_rds_db_instance(
+        &self,
+        input: UpdateRdsDbInstanceRequest,
+    ) -> Result<(), RusotoError<UpdateRdsDbInstanceError>> {
+        self.runtime
+            .block_on(self.client.update_rds_db_instance(input))
+    }
+
+    /// Blocking version of [`OpsWorks::update_stack`].
+    pub fn update_stack(
+        &self,
+        input: UpdateStackRequest,
+    ) -> Result<(), RusotoError<UpdateStackError>> {
+        self.runtime.block_on(self.client.update_stack(input))
+    }
+
+    /// Blocking version of [`OpsWorks::update_user_profile`].
+    pub fn update_user_profile(
+        &self,
+        input: UpdateUserProfileRequest,
+    ) -> Result<(), RusotoError<UpdateUserProfileError>> {
+        self.runtime
+            .block_on(self.client.update_user_profile(input))
+    }
+
+    /// Blocking version of [`OpsWorks::update_volume`].
+    pub fn update_volume(
+        &self,
+        input: UpdateVolumeRequest,
+    ) -> Result<(), RusotoError<UpdateVolumeError>> {
+        self.runtime.block_on(self.client.update_volume(input))
+    }
+}
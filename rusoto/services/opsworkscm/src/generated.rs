@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -829,12 +830,22 @@ impl AssociateNodeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(AssociateNodeError::InvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateNodeError::InvalidState,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateNodeError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateNodeError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -867,15 +878,30 @@ impl CreateBackupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(CreateBackupError::InvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBackupError::InvalidState,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateBackupError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBackupError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateBackupError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBackupError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -909,15 +935,30 @@ impl CreateServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateServerError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateServerError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateServerError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateServerError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateServerError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateServerError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -949,12 +990,22 @@ impl DeleteBackupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(DeleteBackupError::InvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBackupError::InvalidState,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteBackupError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteBackupError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -985,12 +1036,22 @@ impl DeleteServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(DeleteServerError::InvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteServerError::InvalidState,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteServerError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteServerError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1015,7 +1076,7 @@ impl DescribeAccountAttributesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeAccountAttributesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1043,12 +1104,22 @@ impl DescribeBackupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeBackupsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBackupsError::InvalidNextToken,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeBackupsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeBackupsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1079,12 +1150,22 @@ impl DescribeEventsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeEventsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEventsError::InvalidNextToken,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeEventsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEventsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1115,11 +1196,14 @@ impl DescribeNodeAssociationStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeNodeAssociationStatusError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeNodeAssociationStatusError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1151,12 +1235,22 @@ impl DescribeServersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeServersError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeServersError::InvalidNextToken,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeServersError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeServersError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1187,12 +1281,22 @@ impl DisassociateNodeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(DisassociateNodeError::InvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateNodeError::InvalidState,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateNodeError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateNodeError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1225,16 +1329,22 @@ impl ExportServerEngineAttributeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(ExportServerEngineAttributeError::InvalidState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ExportServerEngineAttributeError::InvalidState,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        ExportServerEngineAttributeError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExportServerEngineAttributeError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1263,11 +1373,14 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1297,12 +1410,22 @@ impl RestoreServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(RestoreServerError::InvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RestoreServerError::InvalidState,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RestoreServerError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RestoreServerError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1333,12 +1456,22 @@ impl StartMaintenanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(StartMaintenanceError::InvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartMaintenanceError::InvalidState,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartMaintenanceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartMaintenanceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1369,12 +1502,22 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(TagResourceError::InvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidState,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1405,12 +1548,22 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidState,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1441,12 +1594,22 @@ impl UpdateServerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(UpdateServerError::InvalidState(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateServerError::InvalidState,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateServerError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateServerError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1479,16 +1642,22 @@ impl UpdateServerEngineAttributesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidStateException" => {
-                    return RusotoError::Service(UpdateServerEngineAttributesError::InvalidState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateServerEngineAttributesError::InvalidState,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateServerEngineAttributesError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateServerEngineAttributesError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1636,11 +1805,12 @@ pub struct OpsWorksCMClient {
 impl OpsWorksCMClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> OpsWorksCMClient {
         OpsWorksCMClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "OpsWorksCM"),
         }
     }
 
@@ -1655,12 +1825,32 @@ impl OpsWorksCMClient {
     {
         OpsWorksCMClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "OpsWorksCM"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> OpsWorksCMClient {
-        OpsWorksCMClient { client, region }
+        OpsWorksCMClient {
+            client,
+            region: region_for_service(region, "OpsWorksCM"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> OpsWorksCMClient {
+        OpsWorksCMClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("OpsWorksCM"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> OpsWorksCMClient {
+        OpsWorksCMClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -1672,6 +1862,7 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: AssociateNodeRequest,
     ) -> Result<AssociateNodeResponse, RusotoError<AssociateNodeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateNode");
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.AssociateNode");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1690,6 +1881,7 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: CreateBackupRequest,
     ) -> Result<CreateBackupResponse, RusotoError<CreateBackupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateBackup");
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.CreateBackup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1708,6 +1900,7 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: CreateServerRequest,
     ) -> Result<CreateServerResponse, RusotoError<CreateServerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateServer");
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.CreateServer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1726,6 +1919,7 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: DeleteBackupRequest,
     ) -> Result<DeleteBackupResponse, RusotoError<DeleteBackupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteBackup");
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.DeleteBackup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1744,6 +1938,7 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: DeleteServerRequest,
     ) -> Result<DeleteServerResponse, RusotoError<DeleteServerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteServer");
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.DeleteServer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1762,6 +1957,7 @@ impl OpsWorksCM for OpsWorksCMClient {
     ) -> Result<DescribeAccountAttributesResponse, RusotoError<DescribeAccountAttributesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeAccountAttributes");
         request.add_header(
             "x-amz-target",
             "OpsWorksCM_V2016_11_01.DescribeAccountAttributes",
@@ -1783,6 +1979,7 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: DescribeBackupsRequest,
     ) -> Result<DescribeBackupsResponse, RusotoError<DescribeBackupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeBackups");
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.DescribeBackups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1801,6 +1998,7 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: DescribeEventsRequest,
     ) -> Result<DescribeEventsResponse, RusotoError<DescribeEventsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeEvents");
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.DescribeEvents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1822,6 +2020,7 @@ impl OpsWorksCM for OpsWorksCMClient {
         RusotoError<DescribeNodeAssociationStatusError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeNodeAssociationStatus");
         request.add_header(
             "x-amz-target",
             "OpsWorksCM_V2016_11_01.DescribeNodeAssociationStatus",
@@ -1844,6 +2043,7 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: DescribeServersRequest,
     ) -> Result<DescribeServersResponse, RusotoError<DescribeServersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeServers");
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.DescribeServers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1862,6 +2062,7 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: DisassociateNodeRequest,
     ) -> Result<DisassociateNodeResponse, RusotoError<DisassociateNodeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisassociateNode");
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.DisassociateNode");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1881,6 +2082,7 @@ impl OpsWorksCM for OpsWorksCMClient {
     ) -> Result<ExportServerEngineAttributeResponse, RusotoError<ExportServerEngineAttributeError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ExportServerEngineAttribute");
         request.add_header(
             "x-amz-target",
             "OpsWorksCM_V2016_11_01.ExportServerEngineAttribute",
@@ -1903,6 +2105,7 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1921,6 +2124,7 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: RestoreServerRequest,
     ) -> Result<RestoreServerResponse, RusotoError<RestoreServerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RestoreServer");
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.RestoreServer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1939,6 +2143,7 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: StartMaintenanceRequest,
     ) -> Result<StartMaintenanceResponse, RusotoError<StartMaintenanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartMaintenance");
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.StartMaintenance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1957,6 +2162,7 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1975,6 +2181,7 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -1993,6 +2200,7 @@ impl OpsWorksCM for OpsWorksCMClient {
         input: UpdateServerRequest,
     ) -> Result<UpdateServerResponse, RusotoError<UpdateServerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateServer");
         request.add_header("x-amz-target", "OpsWorksCM_V2016_11_01.UpdateServer");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2012,6 +2220,7 @@ impl OpsWorksCM for OpsWorksCMClient {
     ) -> Result<UpdateServerEngineAttributesResponse, RusotoError<UpdateServerEngineAttributesError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateServerEngineAttributes");
         request.add_header(
             "x-amz-target",
             "OpsWorksCM_V2016_11_01.UpdateServerEngineAttributes",
@@ -2028,3 +2237,240 @@ impl OpsWorksCM for OpsWorksCMClient {
             .deserialize::<UpdateServerEngineAttributesResponse, _>()
     }
 }
+
+/// A blocking client for the OpsWorksCM API, running the calls of a `OpsWorksCMClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `OpsWorksCMClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct OpsWorksCMBlockingClient {
+    client: OpsWorksCMClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl OpsWorksCMBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> OpsWorksCMBlockingClient {
+        OpsWorksCMBlockingClient::from_client(OpsWorksCMClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> OpsWorksCMBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        OpsWorksCMBlockingClient::from_client(OpsWorksCMClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> OpsWorksCMBlockingClient {
+        OpsWorksCMBlockingClient::from_client(OpsWorksCMClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> OpsWorksCMBlockingClient {
+        OpsWorksCMBlockingClient::from_client(OpsWorksCMClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: OpsWorksCMClient) -> OpsWorksCMBlockingClient {
+        OpsWorksCMBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> OpsWorksCMBlockingClient {
+        OpsWorksCMBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> OpsWorksCMBlockingClient {
+        OpsWorksCMBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`OpsWorksCM::associate_node`].
+    pub fn associate_node(
+        &self,
+        input: AssociateNodeRequest,
+    ) -> Result<AssociateNodeResponse, RusotoError<AssociateNodeError>> {
+        self.runtime.block_on(self.client.associate_node(input))
+    }
+
+    /// Blocking version of [`OpsWorksCM::create_backup`].
+    pub fn create_backup(
+        &self,
+        input: CreateBackupRequest,
+    ) -> Result<CreateBackupResponse, RusotoError<CreateBackupError>> {
+        self.runtime.block_on(self.client.create_backup(input))
+    }
+
+    /// Blocking version of [`OpsWorksCM::create_server`].
+    pub fn create_server(
+        &self,
+        input: CreateServerRequest,
+    ) -> Result<CreateServerResponse, RusotoError<CreateServerError>> {
+        self.runtime.block_on(self.client.create_server(input))
+    }
+
+    /// Blocking version of [`OpsWorksCM::delete_backup`].
+    pub fn delete_backup(
+        &self,
+        input: DeleteBackupRequest,
+    ) -> Result<DeleteBackupResponse, RusotoError<DeleteBackupError>> {
+        self.runtime.block_on(self.client.delete_backup(input))
+    }
+
+    /// Blocking version of [`OpsWorksCM::delete_server`].
+    pub fn delete_server(
+        &self,
+        input: DeleteServerRequest,
+    ) -> Result<DeleteServerResponse, RusotoError<DeleteServerError>> {
+        self.runtime.block_on(self.client.delete_server(input))
+    }
+
+    /// Blocking version of [`OpsWorksCM::describe_account_attributes`].
+    pub fn describe_account_attributes(
+        &self,
+    ) -> Result<DescribeAccountAttributesResponse, RusotoError<DescribeAccountAttributesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_account_attributes())
+    }
+
+    /// Blocking version of [`OpsWorksCM::describe_backups`].
+    pub fn describe_backups(
+        &self,
+        input: DescribeBackupsRequest,
+    ) -> Result<DescribeBackupsResponse, RusotoError<DescribeBackupsError>> {
+        self.runtime.block_on(self.client.describe_backups(input))
+    }
+
+    /// Blocking version of [`OpsWorksCM::describe_events`].
+    pub fn describe_events(
+        &self,
+        input: DescribeEventsRequest,
+    ) -> Result<DescribeEventsResponse, RusotoError<DescribeEventsError>> {
+        self.runtime.block_on(self.client.describe_events(input))
+    }
+
+    /// Blocking version of [`OpsWorksCM::describe_node_association_status`].
+    pub fn describe_node_association_status(
+        &self,
+        input: DescribeNodeAssociationStatusRequest,
+    ) -> Result<
+        DescribeNodeAssociationStatusResponse,
+        RusotoError<DescribeNodeAssociationStatusError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_node_association_status(input))
+    }
+
+    /// Blocking version of [`OpsWorksCM::describe_servers`].
+    pub fn describe_servers(
+        &self,
+        input: DescribeServersRequest,
+    ) -> Result<DescribeServersResponse, RusotoError<DescribeServersError>> {
+        self.runtime.block_on(self.client.describe_servers(input))
+    }
+
+    /// Blocking version of [`OpsWorksCM::disassociate_node`].
+    pub fn disassociate_node(
+        &self,
+        input: DisassociateNodeRequest,
+    ) -> Result<DisassociateNodeResponse, RusotoError<DisassociateNodeError>> {
+        self.runtime.block_on(self.client.disassociate_node(input))
+    }
+
+    /// Blocking version of [`OpsWorksCM::export_server_engine_attribute`].
+    pub fn export_server_engine_attribute(
+        &self,
+        input: ExportServerEngineAttributeRequest,
+    ) -> Result<ExportServerEngineAttributeResponse, RusotoError<ExportServerEngineAttributeError>>
+    {
+        self.runtime
+            .block_on(self.client.export_server_engine_attribute(input))
+    }
+
+    /// Blocking version of [`OpsWorksCM::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`OpsWorksCM::restore_server`].
+    pub fn restore_server(
+        &self,
+        input: RestoreServerRequest,
+    ) -> Result<RestoreServerResponse, RusotoError<RestoreServerError>> {
+        self.runtime.block_on(self.client.restore_server(input))
+    }
+
+    /// Blocking version of [`OpsWorksCM::start_maintenance`].
+    pub fn start_maintenance(
+        &self,
+        input: StartMaintenanceRequest,
+    ) -> Result<StartMaintenanceResponse, RusotoError<StartMaintenanceError>> {
+        self.runtime.block_on(self.client.start_maintenance(input))
+    }
+
+    /// Blocking version of [`OpsWorksCM::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`OpsWorksCM::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`OpsWorksCM::update_server`].
+    pub fn update_server(
+        &self,
+        input: UpdateServerRequest,
+    ) -> Result<UpdateServerResponse, RusotoError<UpdateServerError>> {
+        self.runtime.block_on(self.client.update_server(input))
+    }
+
+    /// Blocking version of [`OpsWorksCM::update_server_engine_attributes`].
+    pub fn update_server_engine_attributes(
+        &self,
+        input: UpdateServerEngineAttributesRequest,
+    ) -> Result<UpdateServerEngineAttributesResponse, RusotoError<UpdateServerEngineAttributesError>>
+    {
+        self.runtime
+            .block_on(self.client.update_server_engine_attributes(input))
+    }
+}
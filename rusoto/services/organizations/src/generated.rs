@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1527,51 +1528,94 @@ impl AcceptHandshakeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(AcceptHandshakeError::AWSOrganizationsNotInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AcceptHandshakeError::AWSOrganizationsNotInUse,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AcceptHandshakeError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptHandshakeError::AccessDenied,
+                    )
                 }
                 "AccessDeniedForDependencyException" => {
-                    return RusotoError::Service(AcceptHandshakeError::AccessDeniedForDependency(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AcceptHandshakeError::AccessDeniedForDependency,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(AcceptHandshakeError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AcceptHandshakeError::ConcurrentModification,
+                    )
                 }
                 "HandshakeAlreadyInStateException" => {
-                    return RusotoError::Service(AcceptHandshakeError::HandshakeAlreadyInState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AcceptHandshakeError::HandshakeAlreadyInState,
+                    )
                 }
                 "HandshakeConstraintViolationException" => {
-                    return RusotoError::Service(
-                        AcceptHandshakeError::HandshakeConstraintViolation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptHandshakeError::HandshakeConstraintViolation,
                     )
                 }
                 "HandshakeNotFoundException" => {
-                    return RusotoError::Service(AcceptHandshakeError::HandshakeNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptHandshakeError::HandshakeNotFound,
+                    )
                 }
                 "InvalidHandshakeTransitionException" => {
-                    return RusotoError::Service(AcceptHandshakeError::InvalidHandshakeTransition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AcceptHandshakeError::InvalidHandshakeTransition,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AcceptHandshakeError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptHandshakeError::InvalidInput,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AcceptHandshakeError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptHandshakeError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AcceptHandshakeError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptHandshakeError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1633,51 +1677,110 @@ impl AttachPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(AttachPolicyError::AWSOrganizationsNotInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AttachPolicyError::AWSOrganizationsNotInUse,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(AttachPolicyError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachPolicyError::AccessDenied,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(AttachPolicyError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachPolicyError::ConcurrentModification,
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(AttachPolicyError::ConstraintViolation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachPolicyError::ConstraintViolation,
+                    )
                 }
                 "DuplicatePolicyAttachmentException" => {
-                    return RusotoError::Service(AttachPolicyError::DuplicatePolicyAttachment(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AttachPolicyError::DuplicatePolicyAttachment,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(AttachPolicyError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachPolicyError::InvalidInput,
+                    )
                 }
                 "PolicyChangesInProgressException" => {
-                    return RusotoError::Service(AttachPolicyError::PolicyChangesInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AttachPolicyError::PolicyChangesInProgress,
+                    )
                 }
                 "PolicyNotFoundException" => {
-                    return RusotoError::Service(AttachPolicyError::PolicyNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachPolicyError::PolicyNotFound,
+                    )
                 }
                 "PolicyTypeNotEnabledException" => {
-                    return RusotoError::Service(AttachPolicyError::PolicyTypeNotEnabled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachPolicyError::PolicyTypeNotEnabled,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(AttachPolicyError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachPolicyError::Service,
+                    )
                 }
                 "TargetNotFoundException" => {
-                    return RusotoError::Service(AttachPolicyError::TargetNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachPolicyError::TargetNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(AttachPolicyError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachPolicyError::TooManyRequests,
+                    )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(AttachPolicyError::UnsupportedAPIEndpoint(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AttachPolicyError::UnsupportedAPIEndpoint,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1731,36 +1834,70 @@ impl CancelHandshakeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CancelHandshakeError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelHandshakeError::AccessDenied,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CancelHandshakeError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelHandshakeError::ConcurrentModification,
+                    )
                 }
                 "HandshakeAlreadyInStateException" => {
-                    return RusotoError::Service(CancelHandshakeError::HandshakeAlreadyInState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelHandshakeError::HandshakeAlreadyInState,
+                    )
                 }
                 "HandshakeNotFoundException" => {
-                    return RusotoError::Service(CancelHandshakeError::HandshakeNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelHandshakeError::HandshakeNotFound,
+                    )
                 }
                 "InvalidHandshakeTransitionException" => {
-                    return RusotoError::Service(CancelHandshakeError::InvalidHandshakeTransition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelHandshakeError::InvalidHandshakeTransition,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CancelHandshakeError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelHandshakeError::InvalidInput,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CancelHandshakeError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelHandshakeError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CancelHandshakeError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelHandshakeError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1811,41 +1948,78 @@ impl CreateAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(CreateAccountError::AWSOrganizationsNotInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAccountError::AWSOrganizationsNotInUse,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateAccountError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAccountError::AccessDenied,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateAccountError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAccountError::ConcurrentModification,
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(CreateAccountError::ConstraintViolation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAccountError::ConstraintViolation,
+                    )
                 }
                 "FinalizingOrganizationException" => {
-                    return RusotoError::Service(CreateAccountError::FinalizingOrganization(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAccountError::FinalizingOrganization,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateAccountError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAccountError::InvalidInput,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateAccountError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAccountError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateAccountError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAccountError::TooManyRequests,
+                    )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(CreateAccountError::UnsupportedAPIEndpoint(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAccountError::UnsupportedAPIEndpoint,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1897,45 +2071,78 @@ impl CreateGovCloudAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
-                        CreateGovCloudAccountError::AWSOrganizationsNotInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGovCloudAccountError::AWSOrganizationsNotInUse,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateGovCloudAccountError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGovCloudAccountError::AccessDenied,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        CreateGovCloudAccountError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGovCloudAccountError::ConcurrentModification,
                     )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(CreateGovCloudAccountError::ConstraintViolation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateGovCloudAccountError::ConstraintViolation,
+                    )
                 }
                 "FinalizingOrganizationException" => {
-                    return RusotoError::Service(
-                        CreateGovCloudAccountError::FinalizingOrganization(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGovCloudAccountError::FinalizingOrganization,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateGovCloudAccountError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGovCloudAccountError::InvalidInput,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateGovCloudAccountError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGovCloudAccountError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateGovCloudAccountError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateGovCloudAccountError::TooManyRequests,
+                    )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(
-                        CreateGovCloudAccountError::UnsupportedAPIEndpoint(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGovCloudAccountError::UnsupportedAPIEndpoint,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1987,38 +2194,70 @@ impl CreateOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateOrganizationError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateOrganizationError::AccessDenied,
+                    )
                 }
                 "AccessDeniedForDependencyException" => {
-                    return RusotoError::Service(
-                        CreateOrganizationError::AccessDeniedForDependency(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateOrganizationError::AccessDeniedForDependency,
                     )
                 }
                 "AlreadyInOrganizationException" => {
-                    return RusotoError::Service(CreateOrganizationError::AlreadyInOrganization(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateOrganizationError::AlreadyInOrganization,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateOrganizationError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateOrganizationError::ConcurrentModification,
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(CreateOrganizationError::ConstraintViolation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateOrganizationError::ConstraintViolation,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateOrganizationError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateOrganizationError::InvalidInput,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateOrganizationError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateOrganizationError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateOrganizationError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateOrganizationError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2069,49 +2308,78 @@ impl CreateOrganizationalUnitError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
-                        CreateOrganizationalUnitError::AWSOrganizationsNotInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateOrganizationalUnitError::AWSOrganizationsNotInUse,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateOrganizationalUnitError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateOrganizationalUnitError::AccessDenied,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        CreateOrganizationalUnitError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateOrganizationalUnitError::ConcurrentModification,
                     )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(
-                        CreateOrganizationalUnitError::ConstraintViolation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateOrganizationalUnitError::ConstraintViolation,
                     )
                 }
                 "DuplicateOrganizationalUnitException" => {
-                    return RusotoError::Service(
-                        CreateOrganizationalUnitError::DuplicateOrganizationalUnit(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateOrganizationalUnitError::DuplicateOrganizationalUnit,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateOrganizationalUnitError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateOrganizationalUnitError::InvalidInput,
+                    )
                 }
                 "ParentNotFoundException" => {
-                    return RusotoError::Service(CreateOrganizationalUnitError::ParentNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateOrganizationalUnitError::ParentNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreateOrganizationalUnitError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateOrganizationalUnitError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateOrganizationalUnitError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateOrganizationalUnitError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2173,45 +2441,94 @@ impl CreatePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(CreatePolicyError::AWSOrganizationsNotInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePolicyError::AWSOrganizationsNotInUse,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreatePolicyError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePolicyError::AccessDenied,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreatePolicyError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePolicyError::ConcurrentModification,
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(CreatePolicyError::ConstraintViolation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePolicyError::ConstraintViolation,
+                    )
                 }
                 "DuplicatePolicyException" => {
-                    return RusotoError::Service(CreatePolicyError::DuplicatePolicy(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePolicyError::DuplicatePolicy,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreatePolicyError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePolicyError::InvalidInput,
+                    )
                 }
                 "MalformedPolicyDocumentException" => {
-                    return RusotoError::Service(CreatePolicyError::MalformedPolicyDocument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePolicyError::MalformedPolicyDocument,
+                    )
                 }
                 "PolicyTypeNotAvailableForOrganizationException" => {
-                    return RusotoError::Service(
-                        CreatePolicyError::PolicyTypeNotAvailableForOrganization(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePolicyError::PolicyTypeNotAvailableForOrganization,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(CreatePolicyError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePolicyError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreatePolicyError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePolicyError::TooManyRequests,
+                    )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(CreatePolicyError::UnsupportedAPIEndpoint(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePolicyError::UnsupportedAPIEndpoint,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2265,36 +2582,70 @@ impl DeclineHandshakeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeclineHandshakeError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeclineHandshakeError::AccessDenied,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeclineHandshakeError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeclineHandshakeError::ConcurrentModification,
+                    )
                 }
                 "HandshakeAlreadyInStateException" => {
-                    return RusotoError::Service(DeclineHandshakeError::HandshakeAlreadyInState(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeclineHandshakeError::HandshakeAlreadyInState,
+                    )
                 }
                 "HandshakeNotFoundException" => {
-                    return RusotoError::Service(DeclineHandshakeError::HandshakeNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeclineHandshakeError::HandshakeNotFound,
+                    )
                 }
                 "InvalidHandshakeTransitionException" => {
-                    return RusotoError::Service(DeclineHandshakeError::InvalidHandshakeTransition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeclineHandshakeError::InvalidHandshakeTransition,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeclineHandshakeError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeclineHandshakeError::InvalidInput,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeclineHandshakeError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeclineHandshakeError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeclineHandshakeError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeclineHandshakeError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2341,33 +2692,62 @@ impl DeleteOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(DeleteOrganizationError::AWSOrganizationsNotInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteOrganizationError::AWSOrganizationsNotInUse,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteOrganizationError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteOrganizationError::AccessDenied,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteOrganizationError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteOrganizationError::ConcurrentModification,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteOrganizationError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteOrganizationError::InvalidInput,
+                    )
                 }
                 "OrganizationNotEmptyException" => {
-                    return RusotoError::Service(DeleteOrganizationError::OrganizationNotEmpty(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteOrganizationError::OrganizationNotEmpty,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteOrganizationError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteOrganizationError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteOrganizationError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteOrganizationError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2415,44 +2795,70 @@ impl DeleteOrganizationalUnitError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
-                        DeleteOrganizationalUnitError::AWSOrganizationsNotInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteOrganizationalUnitError::AWSOrganizationsNotInUse,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteOrganizationalUnitError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteOrganizationalUnitError::AccessDenied,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        DeleteOrganizationalUnitError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteOrganizationalUnitError::ConcurrentModification,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteOrganizationalUnitError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteOrganizationalUnitError::InvalidInput,
+                    )
                 }
                 "OrganizationalUnitNotEmptyException" => {
-                    return RusotoError::Service(
-                        DeleteOrganizationalUnitError::OrganizationalUnitNotEmpty(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteOrganizationalUnitError::OrganizationalUnitNotEmpty,
                     )
                 }
                 "OrganizationalUnitNotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteOrganizationalUnitError::OrganizationalUnitNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteOrganizationalUnitError::OrganizationalUnitNotFound,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeleteOrganizationalUnitError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteOrganizationalUnitError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteOrganizationalUnitError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteOrganizationalUnitError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2511,35 +2917,78 @@ impl DeletePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(DeletePolicyError::AWSOrganizationsNotInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeletePolicyError::AWSOrganizationsNotInUse,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeletePolicyError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePolicyError::AccessDenied,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeletePolicyError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePolicyError::ConcurrentModification,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeletePolicyError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePolicyError::InvalidInput,
+                    )
                 }
                 "PolicyInUseException" => {
-                    return RusotoError::Service(DeletePolicyError::PolicyInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePolicyError::PolicyInUse,
+                    )
                 }
                 "PolicyNotFoundException" => {
-                    return RusotoError::Service(DeletePolicyError::PolicyNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePolicyError::PolicyNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeletePolicyError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePolicyError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeletePolicyError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePolicyError::TooManyRequests,
+                    )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(DeletePolicyError::UnsupportedAPIEndpoint(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePolicyError::UnsupportedAPIEndpoint,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2595,56 +3044,86 @@ impl DeregisterDelegatedAdministratorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
-                        DeregisterDelegatedAdministratorError::AWSOrganizationsNotInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterDelegatedAdministratorError::AWSOrganizationsNotInUse,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        DeregisterDelegatedAdministratorError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterDelegatedAdministratorError::AccessDenied,
                     )
                 }
                 "AccountNotFoundException" => {
-                    return RusotoError::Service(
-                        DeregisterDelegatedAdministratorError::AccountNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterDelegatedAdministratorError::AccountNotFound,
                     )
                 }
                 "AccountNotRegisteredException" => {
-                    return RusotoError::Service(
-                        DeregisterDelegatedAdministratorError::AccountNotRegistered(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterDelegatedAdministratorError::AccountNotRegistered,
                     )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        DeregisterDelegatedAdministratorError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterDelegatedAdministratorError::ConcurrentModification,
                     )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(
-                        DeregisterDelegatedAdministratorError::ConstraintViolation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterDelegatedAdministratorError::ConstraintViolation,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
-                        DeregisterDelegatedAdministratorError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterDelegatedAdministratorError::InvalidInput,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DeregisterDelegatedAdministratorError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeregisterDelegatedAdministratorError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        DeregisterDelegatedAdministratorError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterDelegatedAdministratorError::TooManyRequests,
                     )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(
-                        DeregisterDelegatedAdministratorError::UnsupportedAPIEndpoint(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterDelegatedAdministratorError::UnsupportedAPIEndpoint,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2709,26 +3188,54 @@ impl DescribeAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(DescribeAccountError::AWSOrganizationsNotInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAccountError::AWSOrganizationsNotInUse,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeAccountError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAccountError::AccessDenied,
+                    )
                 }
                 "AccountNotFoundException" => {
-                    return RusotoError::Service(DescribeAccountError::AccountNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAccountError::AccountNotFound,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeAccountError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAccountError::InvalidInput,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeAccountError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAccountError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeAccountError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAccountError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2775,39 +3282,62 @@ impl DescribeCreateAccountStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
-                        DescribeCreateAccountStatusError::AWSOrganizationsNotInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCreateAccountStatusError::AWSOrganizationsNotInUse,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeCreateAccountStatusError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeCreateAccountStatusError::AccessDenied,
+                    )
                 }
                 "CreateAccountStatusNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeCreateAccountStatusError::CreateAccountStatusNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCreateAccountStatusError::CreateAccountStatusNotFound,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeCreateAccountStatusError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeCreateAccountStatusError::InvalidInput,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeCreateAccountStatusError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCreateAccountStatusError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeCreateAccountStatusError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeCreateAccountStatusError::TooManyRequests,
+                    )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(
-                        DescribeCreateAccountStatusError::UnsupportedAPIEndpoint(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCreateAccountStatusError::UnsupportedAPIEndpoint,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2863,49 +3393,78 @@ impl DescribeEffectivePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
-                        DescribeEffectivePolicyError::AWSOrganizationsNotInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEffectivePolicyError::AWSOrganizationsNotInUse,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeEffectivePolicyError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEffectivePolicyError::AccessDenied,
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(DescribeEffectivePolicyError::ConstraintViolation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEffectivePolicyError::ConstraintViolation,
+                    )
                 }
                 "EffectivePolicyNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeEffectivePolicyError::EffectivePolicyNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEffectivePolicyError::EffectivePolicyNotFound,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeEffectivePolicyError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEffectivePolicyError::InvalidInput,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeEffectivePolicyError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEffectivePolicyError::Service,
+                    )
                 }
                 "TargetNotFoundException" => {
-                    return RusotoError::Service(DescribeEffectivePolicyError::TargetNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEffectivePolicyError::TargetNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeEffectivePolicyError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEffectivePolicyError::TooManyRequests,
+                    )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(
-                        DescribeEffectivePolicyError::UnsupportedAPIEndpoint(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEffectivePolicyError::UnsupportedAPIEndpoint,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2957,26 +3516,54 @@ impl DescribeHandshakeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeHandshakeError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeHandshakeError::AccessDenied,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DescribeHandshakeError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeHandshakeError::ConcurrentModification,
+                    )
                 }
                 "HandshakeNotFoundException" => {
-                    return RusotoError::Service(DescribeHandshakeError::HandshakeNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeHandshakeError::HandshakeNotFound,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeHandshakeError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeHandshakeError::InvalidInput,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeHandshakeError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeHandshakeError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeHandshakeError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeHandshakeError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3017,27 +3604,46 @@ impl DescribeOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
-                        DescribeOrganizationError::AWSOrganizationsNotInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeOrganizationError::AWSOrganizationsNotInUse,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeOrganizationError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeOrganizationError::AccessDenied,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DescribeOrganizationError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeOrganizationError::ConcurrentModification,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeOrganizationError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeOrganizationError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeOrganizationError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeOrganizationError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3083,34 +3689,54 @@ impl DescribeOrganizationalUnitError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
-                        DescribeOrganizationalUnitError::AWSOrganizationsNotInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeOrganizationalUnitError::AWSOrganizationsNotInUse,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeOrganizationalUnitError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeOrganizationalUnitError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeOrganizationalUnitError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeOrganizationalUnitError::InvalidInput,
+                    )
                 }
                 "OrganizationalUnitNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeOrganizationalUnitError::OrganizationalUnitNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeOrganizationalUnitError::OrganizationalUnitNotFound,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribeOrganizationalUnitError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeOrganizationalUnitError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribeOrganizationalUnitError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeOrganizationalUnitError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3159,31 +3785,62 @@ impl DescribePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(DescribePolicyError::AWSOrganizationsNotInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribePolicyError::AWSOrganizationsNotInUse,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribePolicyError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePolicyError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribePolicyError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePolicyError::InvalidInput,
+                    )
                 }
                 "PolicyNotFoundException" => {
-                    return RusotoError::Service(DescribePolicyError::PolicyNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePolicyError::PolicyNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DescribePolicyError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePolicyError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DescribePolicyError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePolicyError::TooManyRequests,
+                    )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(DescribePolicyError::UnsupportedAPIEndpoint(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribePolicyError::UnsupportedAPIEndpoint,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3239,46 +3896,102 @@ impl DetachPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(DetachPolicyError::AWSOrganizationsNotInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetachPolicyError::AWSOrganizationsNotInUse,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DetachPolicyError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachPolicyError::AccessDenied,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DetachPolicyError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachPolicyError::ConcurrentModification,
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(DetachPolicyError::ConstraintViolation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachPolicyError::ConstraintViolation,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DetachPolicyError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachPolicyError::InvalidInput,
+                    )
                 }
                 "PolicyChangesInProgressException" => {
-                    return RusotoError::Service(DetachPolicyError::PolicyChangesInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetachPolicyError::PolicyChangesInProgress,
+                    )
                 }
                 "PolicyNotAttachedException" => {
-                    return RusotoError::Service(DetachPolicyError::PolicyNotAttached(err.msg))
-                }
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachPolicyError::PolicyNotAttached,
+                    )
+                }
                 "PolicyNotFoundException" => {
-                    return RusotoError::Service(DetachPolicyError::PolicyNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachPolicyError::PolicyNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DetachPolicyError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachPolicyError::Service,
+                    )
                 }
                 "TargetNotFoundException" => {
-                    return RusotoError::Service(DetachPolicyError::TargetNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachPolicyError::TargetNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DetachPolicyError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachPolicyError::TooManyRequests,
+                    )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(DetachPolicyError::UnsupportedAPIEndpoint(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetachPolicyError::UnsupportedAPIEndpoint,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3331,44 +4044,70 @@ impl DisableAWSServiceAccessError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
-                        DisableAWSServiceAccessError::AWSOrganizationsNotInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableAWSServiceAccessError::AWSOrganizationsNotInUse,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DisableAWSServiceAccessError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisableAWSServiceAccessError::AccessDenied,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        DisableAWSServiceAccessError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableAWSServiceAccessError::ConcurrentModification,
                     )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(DisableAWSServiceAccessError::ConstraintViolation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisableAWSServiceAccessError::ConstraintViolation,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DisableAWSServiceAccessError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisableAWSServiceAccessError::InvalidInput,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DisableAWSServiceAccessError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableAWSServiceAccessError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DisableAWSServiceAccessError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisableAWSServiceAccessError::TooManyRequests,
+                    )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(
-                        DisableAWSServiceAccessError::UnsupportedAPIEndpoint(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableAWSServiceAccessError::UnsupportedAPIEndpoint,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3429,51 +4168,94 @@ impl DisablePolicyTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(DisablePolicyTypeError::AWSOrganizationsNotInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisablePolicyTypeError::AWSOrganizationsNotInUse,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DisablePolicyTypeError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisablePolicyTypeError::AccessDenied,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DisablePolicyTypeError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisablePolicyTypeError::ConcurrentModification,
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(DisablePolicyTypeError::ConstraintViolation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisablePolicyTypeError::ConstraintViolation,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(DisablePolicyTypeError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisablePolicyTypeError::InvalidInput,
+                    )
                 }
                 "PolicyChangesInProgressException" => {
-                    return RusotoError::Service(DisablePolicyTypeError::PolicyChangesInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisablePolicyTypeError::PolicyChangesInProgress,
+                    )
                 }
                 "PolicyTypeNotEnabledException" => {
-                    return RusotoError::Service(DisablePolicyTypeError::PolicyTypeNotEnabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisablePolicyTypeError::PolicyTypeNotEnabled,
+                    )
                 }
                 "RootNotFoundException" => {
-                    return RusotoError::Service(DisablePolicyTypeError::RootNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisablePolicyTypeError::RootNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(DisablePolicyTypeError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisablePolicyTypeError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DisablePolicyTypeError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisablePolicyTypeError::TooManyRequests,
+                    )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(DisablePolicyTypeError::UnsupportedAPIEndpoint(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisablePolicyTypeError::UnsupportedAPIEndpoint,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3525,40 +4307,70 @@ impl EnableAWSServiceAccessError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
-                        EnableAWSServiceAccessError::AWSOrganizationsNotInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableAWSServiceAccessError::AWSOrganizationsNotInUse,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(EnableAWSServiceAccessError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableAWSServiceAccessError::AccessDenied,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        EnableAWSServiceAccessError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableAWSServiceAccessError::ConcurrentModification,
                     )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(EnableAWSServiceAccessError::ConstraintViolation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        EnableAWSServiceAccessError::ConstraintViolation,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(EnableAWSServiceAccessError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableAWSServiceAccessError::InvalidInput,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(EnableAWSServiceAccessError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableAWSServiceAccessError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(EnableAWSServiceAccessError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        EnableAWSServiceAccessError::TooManyRequests,
+                    )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(
-                        EnableAWSServiceAccessError::UnsupportedAPIEndpoint(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableAWSServiceAccessError::UnsupportedAPIEndpoint,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3611,33 +4423,62 @@ impl EnableAllFeaturesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(EnableAllFeaturesError::AWSOrganizationsNotInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        EnableAllFeaturesError::AWSOrganizationsNotInUse,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(EnableAllFeaturesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableAllFeaturesError::AccessDenied,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(EnableAllFeaturesError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        EnableAllFeaturesError::ConcurrentModification,
+                    )
                 }
                 "HandshakeConstraintViolationException" => {
-                    return RusotoError::Service(
-                        EnableAllFeaturesError::HandshakeConstraintViolation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableAllFeaturesError::HandshakeConstraintViolation,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(EnableAllFeaturesError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableAllFeaturesError::InvalidInput,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(EnableAllFeaturesError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableAllFeaturesError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(EnableAllFeaturesError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableAllFeaturesError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3695,56 +4536,102 @@ impl EnablePolicyTypeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(EnablePolicyTypeError::AWSOrganizationsNotInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        EnablePolicyTypeError::AWSOrganizationsNotInUse,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(EnablePolicyTypeError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnablePolicyTypeError::AccessDenied,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(EnablePolicyTypeError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        EnablePolicyTypeError::ConcurrentModification,
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(EnablePolicyTypeError::ConstraintViolation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        EnablePolicyTypeError::ConstraintViolation,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(EnablePolicyTypeError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnablePolicyTypeError::InvalidInput,
+                    )
                 }
                 "PolicyChangesInProgressException" => {
-                    return RusotoError::Service(EnablePolicyTypeError::PolicyChangesInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        EnablePolicyTypeError::PolicyChangesInProgress,
+                    )
                 }
                 "PolicyTypeAlreadyEnabledException" => {
-                    return RusotoError::Service(EnablePolicyTypeError::PolicyTypeAlreadyEnabled(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        EnablePolicyTypeError::PolicyTypeAlreadyEnabled,
+                    )
                 }
                 "PolicyTypeNotAvailableForOrganizationException" => {
-                    return RusotoError::Service(
-                        EnablePolicyTypeError::PolicyTypeNotAvailableForOrganization(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnablePolicyTypeError::PolicyTypeNotAvailableForOrganization,
                     )
                 }
                 "RootNotFoundException" => {
-                    return RusotoError::Service(EnablePolicyTypeError::RootNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnablePolicyTypeError::RootNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(EnablePolicyTypeError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnablePolicyTypeError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(EnablePolicyTypeError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnablePolicyTypeError::TooManyRequests,
+                    )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(EnablePolicyTypeError::UnsupportedAPIEndpoint(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        EnablePolicyTypeError::UnsupportedAPIEndpoint,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3807,59 +4694,94 @@ impl InviteAccountToOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
-                        InviteAccountToOrganizationError::AWSOrganizationsNotInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InviteAccountToOrganizationError::AWSOrganizationsNotInUse,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(InviteAccountToOrganizationError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        InviteAccountToOrganizationError::AccessDenied,
+                    )
                 }
                 "AccountOwnerNotVerifiedException" => {
-                    return RusotoError::Service(
-                        InviteAccountToOrganizationError::AccountOwnerNotVerified(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InviteAccountToOrganizationError::AccountOwnerNotVerified,
                     )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        InviteAccountToOrganizationError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InviteAccountToOrganizationError::ConcurrentModification,
                     )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(
-                        InviteAccountToOrganizationError::ConstraintViolation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InviteAccountToOrganizationError::ConstraintViolation,
                     )
                 }
                 "DuplicateHandshakeException" => {
-                    return RusotoError::Service(
-                        InviteAccountToOrganizationError::DuplicateHandshake(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InviteAccountToOrganizationError::DuplicateHandshake,
                     )
                 }
                 "FinalizingOrganizationException" => {
-                    return RusotoError::Service(
-                        InviteAccountToOrganizationError::FinalizingOrganization(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InviteAccountToOrganizationError::FinalizingOrganization,
                     )
                 }
                 "HandshakeConstraintViolationException" => {
-                    return RusotoError::Service(
-                        InviteAccountToOrganizationError::HandshakeConstraintViolation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InviteAccountToOrganizationError::HandshakeConstraintViolation,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(InviteAccountToOrganizationError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        InviteAccountToOrganizationError::InvalidInput,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(InviteAccountToOrganizationError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InviteAccountToOrganizationError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(InviteAccountToOrganizationError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        InviteAccountToOrganizationError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3927,41 +4849,78 @@ impl LeaveOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(LeaveOrganizationError::AWSOrganizationsNotInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        LeaveOrganizationError::AWSOrganizationsNotInUse,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(LeaveOrganizationError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        LeaveOrganizationError::AccessDenied,
+                    )
                 }
                 "AccountNotFoundException" => {
-                    return RusotoError::Service(LeaveOrganizationError::AccountNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        LeaveOrganizationError::AccountNotFound,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(LeaveOrganizationError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        LeaveOrganizationError::ConcurrentModification,
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(LeaveOrganizationError::ConstraintViolation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        LeaveOrganizationError::ConstraintViolation,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(LeaveOrganizationError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        LeaveOrganizationError::InvalidInput,
+                    )
                 }
                 "MasterCannotLeaveOrganizationException" => {
-                    return RusotoError::Service(
-                        LeaveOrganizationError::MasterCannotLeaveOrganization(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        LeaveOrganizationError::MasterCannotLeaveOrganization,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(LeaveOrganizationError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        LeaveOrganizationError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(LeaveOrganizationError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        LeaveOrganizationError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4013,41 +4972,62 @@ impl ListAWSServiceAccessForOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
-                        ListAWSServiceAccessForOrganizationError::AWSOrganizationsNotInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAWSServiceAccessForOrganizationError::AWSOrganizationsNotInUse,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        ListAWSServiceAccessForOrganizationError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAWSServiceAccessForOrganizationError::AccessDenied,
                     )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(
-                        ListAWSServiceAccessForOrganizationError::ConstraintViolation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAWSServiceAccessForOrganizationError::ConstraintViolation,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
-                        ListAWSServiceAccessForOrganizationError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAWSServiceAccessForOrganizationError::InvalidInput,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListAWSServiceAccessForOrganizationError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAWSServiceAccessForOrganizationError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        ListAWSServiceAccessForOrganizationError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAWSServiceAccessForOrganizationError::TooManyRequests,
                     )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(
-                        ListAWSServiceAccessForOrganizationError::UnsupportedAPIEndpoint(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAWSServiceAccessForOrganizationError::UnsupportedAPIEndpoint,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4101,23 +5081,46 @@ impl ListAccountsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(ListAccountsError::AWSOrganizationsNotInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAccountsError::AWSOrganizationsNotInUse,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListAccountsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAccountsError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListAccountsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAccountsError::InvalidInput,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListAccountsError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAccountsError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListAccountsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAccountsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4159,30 +5162,54 @@ impl ListAccountsForParentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
-                        ListAccountsForParentError::AWSOrganizationsNotInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAccountsForParentError::AWSOrganizationsNotInUse,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListAccountsForParentError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAccountsForParentError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListAccountsForParentError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAccountsForParentError::InvalidInput,
+                    )
                 }
                 "ParentNotFoundException" => {
-                    return RusotoError::Service(ListAccountsForParentError::ParentNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAccountsForParentError::ParentNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListAccountsForParentError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAccountsForParentError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListAccountsForParentError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListAccountsForParentError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4227,26 +5254,54 @@ impl ListChildrenError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(ListChildrenError::AWSOrganizationsNotInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListChildrenError::AWSOrganizationsNotInUse,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListChildrenError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChildrenError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListChildrenError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChildrenError::InvalidInput,
+                    )
                 }
                 "ParentNotFoundException" => {
-                    return RusotoError::Service(ListChildrenError::ParentNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChildrenError::ParentNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListChildrenError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChildrenError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListChildrenError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListChildrenError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4289,34 +5344,54 @@ impl ListCreateAccountStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
-                        ListCreateAccountStatusError::AWSOrganizationsNotInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCreateAccountStatusError::AWSOrganizationsNotInUse,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListCreateAccountStatusError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListCreateAccountStatusError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListCreateAccountStatusError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListCreateAccountStatusError::InvalidInput,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListCreateAccountStatusError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCreateAccountStatusError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListCreateAccountStatusError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListCreateAccountStatusError::TooManyRequests,
+                    )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(
-                        ListCreateAccountStatusError::UnsupportedAPIEndpoint(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCreateAccountStatusError::UnsupportedAPIEndpoint,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4367,39 +5442,62 @@ impl ListDelegatedAdministratorsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
-                        ListDelegatedAdministratorsError::AWSOrganizationsNotInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDelegatedAdministratorsError::AWSOrganizationsNotInUse,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListDelegatedAdministratorsError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDelegatedAdministratorsError::AccessDenied,
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(
-                        ListDelegatedAdministratorsError::ConstraintViolation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDelegatedAdministratorsError::ConstraintViolation,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListDelegatedAdministratorsError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDelegatedAdministratorsError::InvalidInput,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListDelegatedAdministratorsError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDelegatedAdministratorsError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListDelegatedAdministratorsError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDelegatedAdministratorsError::TooManyRequests,
+                    )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(
-                        ListDelegatedAdministratorsError::UnsupportedAPIEndpoint(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDelegatedAdministratorsError::UnsupportedAPIEndpoint,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4457,51 +5555,78 @@ impl ListDelegatedServicesForAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
-                        ListDelegatedServicesForAccountError::AWSOrganizationsNotInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDelegatedServicesForAccountError::AWSOrganizationsNotInUse,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        ListDelegatedServicesForAccountError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDelegatedServicesForAccountError::AccessDenied,
                     )
                 }
                 "AccountNotFoundException" => {
-                    return RusotoError::Service(
-                        ListDelegatedServicesForAccountError::AccountNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDelegatedServicesForAccountError::AccountNotFound,
                     )
                 }
                 "AccountNotRegisteredException" => {
-                    return RusotoError::Service(
-                        ListDelegatedServicesForAccountError::AccountNotRegistered(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDelegatedServicesForAccountError::AccountNotRegistered,
                     )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(
-                        ListDelegatedServicesForAccountError::ConstraintViolation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDelegatedServicesForAccountError::ConstraintViolation,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
-                        ListDelegatedServicesForAccountError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDelegatedServicesForAccountError::InvalidInput,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListDelegatedServicesForAccountError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDelegatedServicesForAccountError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        ListDelegatedServicesForAccountError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDelegatedServicesForAccountError::TooManyRequests,
                     )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(
-                        ListDelegatedServicesForAccountError::UnsupportedAPIEndpoint(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDelegatedServicesForAccountError::UnsupportedAPIEndpoint,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4557,29 +5682,46 @@ impl ListHandshakesForAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListHandshakesForAccountError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListHandshakesForAccountError::AccessDenied,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        ListHandshakesForAccountError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListHandshakesForAccountError::ConcurrentModification,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListHandshakesForAccountError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListHandshakesForAccountError::InvalidInput,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListHandshakesForAccountError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListHandshakesForAccountError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListHandshakesForAccountError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListHandshakesForAccountError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4625,36 +5767,54 @@ impl ListHandshakesForOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
-                        ListHandshakesForOrganizationError::AWSOrganizationsNotInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListHandshakesForOrganizationError::AWSOrganizationsNotInUse,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListHandshakesForOrganizationError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListHandshakesForOrganizationError::AccessDenied,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        ListHandshakesForOrganizationError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListHandshakesForOrganizationError::ConcurrentModification,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListHandshakesForOrganizationError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListHandshakesForOrganizationError::InvalidInput,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListHandshakesForOrganizationError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListHandshakesForOrganizationError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        ListHandshakesForOrganizationError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListHandshakesForOrganizationError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4705,36 +5865,54 @@ impl ListOrganizationalUnitsForParentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
-                        ListOrganizationalUnitsForParentError::AWSOrganizationsNotInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOrganizationalUnitsForParentError::AWSOrganizationsNotInUse,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        ListOrganizationalUnitsForParentError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOrganizationalUnitsForParentError::AccessDenied,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(
-                        ListOrganizationalUnitsForParentError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOrganizationalUnitsForParentError::InvalidInput,
                     )
                 }
                 "ParentNotFoundException" => {
-                    return RusotoError::Service(
-                        ListOrganizationalUnitsForParentError::ParentNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOrganizationalUnitsForParentError::ParentNotFound,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListOrganizationalUnitsForParentError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListOrganizationalUnitsForParentError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        ListOrganizationalUnitsForParentError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOrganizationalUnitsForParentError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4787,26 +5965,54 @@ impl ListParentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(ListParentsError::AWSOrganizationsNotInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListParentsError::AWSOrganizationsNotInUse,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListParentsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListParentsError::AccessDenied,
+                    )
                 }
                 "ChildNotFoundException" => {
-                    return RusotoError::Service(ListParentsError::ChildNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListParentsError::ChildNotFound,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListParentsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListParentsError::InvalidInput,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListParentsError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListParentsError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListParentsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListParentsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4849,26 +6055,54 @@ impl ListPoliciesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(ListPoliciesError::AWSOrganizationsNotInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPoliciesError::AWSOrganizationsNotInUse,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListPoliciesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPoliciesError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListPoliciesError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPoliciesError::InvalidInput,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListPoliciesError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPoliciesError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListPoliciesError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPoliciesError::TooManyRequests,
+                    )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(ListPoliciesError::UnsupportedAPIEndpoint(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPoliciesError::UnsupportedAPIEndpoint,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4913,35 +6147,62 @@ impl ListPoliciesForTargetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
-                        ListPoliciesForTargetError::AWSOrganizationsNotInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPoliciesForTargetError::AWSOrganizationsNotInUse,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListPoliciesForTargetError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPoliciesForTargetError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListPoliciesForTargetError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPoliciesForTargetError::InvalidInput,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListPoliciesForTargetError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPoliciesForTargetError::Service,
+                    )
                 }
                 "TargetNotFoundException" => {
-                    return RusotoError::Service(ListPoliciesForTargetError::TargetNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPoliciesForTargetError::TargetNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListPoliciesForTargetError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPoliciesForTargetError::TooManyRequests,
+                    )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(
-                        ListPoliciesForTargetError::UnsupportedAPIEndpoint(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPoliciesForTargetError::UnsupportedAPIEndpoint,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4985,21 +6246,46 @@ impl ListRootsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(ListRootsError::AWSOrganizationsNotInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRootsError::AWSOrganizationsNotInUse,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListRootsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRootsError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListRootsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRootsError::InvalidInput,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListRootsError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRootsError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListRootsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRootsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5041,26 +6327,54 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
-                        ListTagsForResourceError::AWSOrganizationsNotInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::AWSOrganizationsNotInUse,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InvalidInput,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::Service,
+                    )
                 }
                 "TargetNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::TargetNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::TargetNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListTagsForResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5105,33 +6419,62 @@ impl ListTargetsForPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
-                        ListTargetsForPolicyError::AWSOrganizationsNotInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTargetsForPolicyError::AWSOrganizationsNotInUse,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTargetsForPolicyError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTargetsForPolicyError::AccessDenied,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListTargetsForPolicyError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTargetsForPolicyError::InvalidInput,
+                    )
                 }
                 "PolicyNotFoundException" => {
-                    return RusotoError::Service(ListTargetsForPolicyError::PolicyNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTargetsForPolicyError::PolicyNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(ListTargetsForPolicyError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTargetsForPolicyError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListTargetsForPolicyError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTargetsForPolicyError::TooManyRequests,
+                    )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(ListTargetsForPolicyError::UnsupportedAPIEndpoint(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTargetsForPolicyError::UnsupportedAPIEndpoint,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5185,40 +6528,86 @@ impl MoveAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(MoveAccountError::AWSOrganizationsNotInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MoveAccountError::AWSOrganizationsNotInUse,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(MoveAccountError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MoveAccountError::AccessDenied,
+                    )
                 }
                 "AccountNotFoundException" => {
-                    return RusotoError::Service(MoveAccountError::AccountNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MoveAccountError::AccountNotFound,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(MoveAccountError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MoveAccountError::ConcurrentModification,
+                    )
                 }
                 "DestinationParentNotFoundException" => {
-                    return RusotoError::Service(MoveAccountError::DestinationParentNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        MoveAccountError::DestinationParentNotFound,
+                    )
                 }
                 "DuplicateAccountException" => {
-                    return RusotoError::Service(MoveAccountError::DuplicateAccount(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MoveAccountError::DuplicateAccount,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(MoveAccountError::InvalidInput(err.msg))
-                }
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MoveAccountError::InvalidInput,
+                    )
+                }
                 "ServiceException" => {
-                    return RusotoError::Service(MoveAccountError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MoveAccountError::Service,
+                    )
                 }
                 "SourceParentNotFoundException" => {
-                    return RusotoError::Service(MoveAccountError::SourceParentNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MoveAccountError::SourceParentNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(MoveAccountError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        MoveAccountError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5275,56 +6664,86 @@ impl RegisterDelegatedAdministratorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
-                        RegisterDelegatedAdministratorError::AWSOrganizationsNotInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterDelegatedAdministratorError::AWSOrganizationsNotInUse,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RegisterDelegatedAdministratorError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterDelegatedAdministratorError::AccessDenied,
+                    )
                 }
                 "AccountAlreadyRegisteredException" => {
-                    return RusotoError::Service(
-                        RegisterDelegatedAdministratorError::AccountAlreadyRegistered(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterDelegatedAdministratorError::AccountAlreadyRegistered,
                     )
                 }
                 "AccountNotFoundException" => {
-                    return RusotoError::Service(
-                        RegisterDelegatedAdministratorError::AccountNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterDelegatedAdministratorError::AccountNotFound,
                     )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        RegisterDelegatedAdministratorError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterDelegatedAdministratorError::ConcurrentModification,
                     )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(
-                        RegisterDelegatedAdministratorError::ConstraintViolation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterDelegatedAdministratorError::ConstraintViolation,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(RegisterDelegatedAdministratorError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterDelegatedAdministratorError::InvalidInput,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(RegisterDelegatedAdministratorError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterDelegatedAdministratorError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        RegisterDelegatedAdministratorError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterDelegatedAdministratorError::TooManyRequests,
                     )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(
-                        RegisterDelegatedAdministratorError::UnsupportedAPIEndpoint(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterDelegatedAdministratorError::UnsupportedAPIEndpoint,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5393,51 +6812,78 @@ impl RemoveAccountFromOrganizationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
-                        RemoveAccountFromOrganizationError::AWSOrganizationsNotInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveAccountFromOrganizationError::AWSOrganizationsNotInUse,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RemoveAccountFromOrganizationError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveAccountFromOrganizationError::AccessDenied,
+                    )
                 }
                 "AccountNotFoundException" => {
-                    return RusotoError::Service(
-                        RemoveAccountFromOrganizationError::AccountNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveAccountFromOrganizationError::AccountNotFound,
                     )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        RemoveAccountFromOrganizationError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveAccountFromOrganizationError::ConcurrentModification,
                     )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(
-                        RemoveAccountFromOrganizationError::ConstraintViolation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveAccountFromOrganizationError::ConstraintViolation,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(RemoveAccountFromOrganizationError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveAccountFromOrganizationError::InvalidInput,
+                    )
                 }
                 "MasterCannotLeaveOrganizationException" => {
-                    return RusotoError::Service(
-                        RemoveAccountFromOrganizationError::MasterCannotLeaveOrganization(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveAccountFromOrganizationError::MasterCannotLeaveOrganization,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(RemoveAccountFromOrganizationError::Service(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RemoveAccountFromOrganizationError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        RemoveAccountFromOrganizationError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RemoveAccountFromOrganizationError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5499,32 +6945,70 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(TagResourceError::AWSOrganizationsNotInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TagResourceError::AWSOrganizationsNotInUse,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(TagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::AccessDenied,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(TagResourceError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ConcurrentModification,
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(TagResourceError::ConstraintViolation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ConstraintViolation,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(TagResourceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidInput,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(TagResourceError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::Service,
+                    )
                 }
                 "TargetNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::TargetNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::TargetNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5573,34 +7057,70 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(UntagResourceError::AWSOrganizationsNotInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UntagResourceError::AWSOrganizationsNotInUse,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UntagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::AccessDenied,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UntagResourceError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UntagResourceError::ConcurrentModification,
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(UntagResourceError::ConstraintViolation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ConstraintViolation,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidInput,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UntagResourceError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::Service,
+                    )
                 }
                 "TargetNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::TargetNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::TargetNotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5649,44 +7169,70 @@ impl UpdateOrganizationalUnitError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(
-                        UpdateOrganizationalUnitError::AWSOrganizationsNotInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateOrganizationalUnitError::AWSOrganizationsNotInUse,
                     )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateOrganizationalUnitError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateOrganizationalUnitError::AccessDenied,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        UpdateOrganizationalUnitError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateOrganizationalUnitError::ConcurrentModification,
                     )
                 }
                 "DuplicateOrganizationalUnitException" => {
-                    return RusotoError::Service(
-                        UpdateOrganizationalUnitError::DuplicateOrganizationalUnit(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateOrganizationalUnitError::DuplicateOrganizationalUnit,
                     )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateOrganizationalUnitError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateOrganizationalUnitError::InvalidInput,
+                    )
                 }
                 "OrganizationalUnitNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateOrganizationalUnitError::OrganizationalUnitNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateOrganizationalUnitError::OrganizationalUnitNotFound,
                     )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdateOrganizationalUnitError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateOrganizationalUnitError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateOrganizationalUnitError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateOrganizationalUnitError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5751,48 +7297,102 @@ impl UpdatePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AWSOrganizationsNotInUseException" => {
-                    return RusotoError::Service(UpdatePolicyError::AWSOrganizationsNotInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdatePolicyError::AWSOrganizationsNotInUse,
+                    )
                 }
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdatePolicyError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePolicyError::AccessDenied,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UpdatePolicyError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePolicyError::ConcurrentModification,
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(UpdatePolicyError::ConstraintViolation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePolicyError::ConstraintViolation,
+                    )
                 }
                 "DuplicatePolicyException" => {
-                    return RusotoError::Service(UpdatePolicyError::DuplicatePolicy(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePolicyError::DuplicatePolicy,
+                    )
                 }
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdatePolicyError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePolicyError::InvalidInput,
+                    )
                 }
                 "MalformedPolicyDocumentException" => {
-                    return RusotoError::Service(UpdatePolicyError::MalformedPolicyDocument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdatePolicyError::MalformedPolicyDocument,
+                    )
                 }
                 "PolicyChangesInProgressException" => {
-                    return RusotoError::Service(UpdatePolicyError::PolicyChangesInProgress(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdatePolicyError::PolicyChangesInProgress,
+                    )
                 }
                 "PolicyNotFoundException" => {
-                    return RusotoError::Service(UpdatePolicyError::PolicyNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePolicyError::PolicyNotFound,
+                    )
                 }
                 "ServiceException" => {
-                    return RusotoError::Service(UpdatePolicyError::Service(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePolicyError::Service,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdatePolicyError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePolicyError::TooManyRequests,
+                    )
                 }
                 "UnsupportedAPIEndpointException" => {
-                    return RusotoError::Service(UpdatePolicyError::UnsupportedAPIEndpoint(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePolicyError::UnsupportedAPIEndpoint,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6142,11 +7742,12 @@ pub struct OrganizationsClient {
 impl OrganizationsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> OrganizationsClient {
         OrganizationsClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Organizations"),
         }
     }
 
@@ -6161,12 +7762,32 @@ impl OrganizationsClient {
     {
         OrganizationsClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Organizations"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> OrganizationsClient {
-        OrganizationsClient { client, region }
+        OrganizationsClient {
+            client,
+            region: region_for_service(region, "Organizations"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> OrganizationsClient {
+        OrganizationsClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Organizations"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> OrganizationsClient {
+        OrganizationsClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -6178,6 +7799,7 @@ impl Organizations for OrganizationsClient {
         input: AcceptHandshakeRequest,
     ) -> Result<AcceptHandshakeResponse, RusotoError<AcceptHandshakeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AcceptHandshake");
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.AcceptHandshake");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6196,6 +7818,7 @@ impl Organizations for OrganizationsClient {
         input: AttachPolicyRequest,
     ) -> Result<(), RusotoError<AttachPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AttachPolicy");
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.AttachPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6213,6 +7836,7 @@ impl Organizations for OrganizationsClient {
         input: CancelHandshakeRequest,
     ) -> Result<CancelHandshakeResponse, RusotoError<CancelHandshakeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CancelHandshake");
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.CancelHandshake");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6231,6 +7855,7 @@ impl Organizations for OrganizationsClient {
         input: CreateAccountRequest,
     ) -> Result<CreateAccountResponse, RusotoError<CreateAccountError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateAccount");
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.CreateAccount");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6249,6 +7874,7 @@ impl Organizations for OrganizationsClient {
         input: CreateGovCloudAccountRequest,
     ) -> Result<CreateGovCloudAccountResponse, RusotoError<CreateGovCloudAccountError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateGovCloudAccount");
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.CreateGovCloudAccount",
@@ -6271,6 +7897,7 @@ impl Organizations for OrganizationsClient {
         input: CreateOrganizationRequest,
     ) -> Result<CreateOrganizationResponse, RusotoError<CreateOrganizationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateOrganization");
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.CreateOrganization",
@@ -6292,6 +7919,7 @@ impl Organizations for OrganizationsClient {
         input: CreateOrganizationalUnitRequest,
     ) -> Result<CreateOrganizationalUnitResponse, RusotoError<CreateOrganizationalUnitError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateOrganizationalUnit");
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.CreateOrganizationalUnit",
@@ -6314,6 +7942,7 @@ impl Organizations for OrganizationsClient {
         input: CreatePolicyRequest,
     ) -> Result<CreatePolicyResponse, RusotoError<CreatePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreatePolicy");
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.CreatePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6332,6 +7961,7 @@ impl Organizations for OrganizationsClient {
         input: DeclineHandshakeRequest,
     ) -> Result<DeclineHandshakeResponse, RusotoError<DeclineHandshakeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeclineHandshake");
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.DeclineHandshake");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6347,6 +7977,7 @@ impl Organizations for OrganizationsClient {
     /// <p>Deletes the organization. You can delete an organization only by using credentials from the management account. The organization must be empty of member accounts.</p>
     async fn delete_organization(&self) -> Result<(), RusotoError<DeleteOrganizationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteOrganization");
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.DeleteOrganization",
@@ -6366,6 +7997,7 @@ impl Organizations for OrganizationsClient {
         input: DeleteOrganizationalUnitRequest,
     ) -> Result<(), RusotoError<DeleteOrganizationalUnitError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteOrganizationalUnit");
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.DeleteOrganizationalUnit",
@@ -6386,6 +8018,7 @@ impl Organizations for OrganizationsClient {
         input: DeletePolicyRequest,
     ) -> Result<(), RusotoError<DeletePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeletePolicy");
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.DeletePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6403,6 +8036,7 @@ impl Organizations for OrganizationsClient {
         input: DeregisterDelegatedAdministratorRequest,
     ) -> Result<(), RusotoError<DeregisterDelegatedAdministratorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeregisterDelegatedAdministrator");
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.DeregisterDelegatedAdministrator",
@@ -6426,6 +8060,7 @@ impl Organizations for OrganizationsClient {
         input: DescribeAccountRequest,
     ) -> Result<DescribeAccountResponse, RusotoError<DescribeAccountError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeAccount");
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.DescribeAccount");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6445,6 +8080,7 @@ impl Organizations for OrganizationsClient {
     ) -> Result<DescribeCreateAccountStatusResponse, RusotoError<DescribeCreateAccountStatusError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeCreateAccountStatus");
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.DescribeCreateAccountStatus",
@@ -6467,6 +8103,7 @@ impl Organizations for OrganizationsClient {
         input: DescribeEffectivePolicyRequest,
     ) -> Result<DescribeEffectivePolicyResponse, RusotoError<DescribeEffectivePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeEffectivePolicy");
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.DescribeEffectivePolicy",
@@ -6489,6 +8126,7 @@ impl Organizations for OrganizationsClient {
         input: DescribeHandshakeRequest,
     ) -> Result<DescribeHandshakeResponse, RusotoError<DescribeHandshakeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeHandshake");
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.DescribeHandshake",
@@ -6509,6 +8147,7 @@ impl Organizations for OrganizationsClient {
         &self,
     ) -> Result<DescribeOrganizationResponse, RusotoError<DescribeOrganizationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeOrganization");
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.DescribeOrganization",
@@ -6531,6 +8170,7 @@ impl Organizations for OrganizationsClient {
     ) -> Result<DescribeOrganizationalUnitResponse, RusotoError<DescribeOrganizationalUnitError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeOrganizationalUnit");
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.DescribeOrganizationalUnit",
@@ -6553,6 +8193,7 @@ impl Organizations for OrganizationsClient {
         input: DescribePolicyRequest,
     ) -> Result<DescribePolicyResponse, RusotoError<DescribePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribePolicy");
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.DescribePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6571,6 +8212,7 @@ impl Organizations for OrganizationsClient {
         input: DetachPolicyRequest,
     ) -> Result<(), RusotoError<DetachPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DetachPolicy");
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.DetachPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6588,6 +8230,7 @@ impl Organizations for OrganizationsClient {
         input: DisableAWSServiceAccessRequest,
     ) -> Result<(), RusotoError<DisableAWSServiceAccessError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisableAWSServiceAccess");
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.DisableAWSServiceAccess",
@@ -6608,6 +8251,7 @@ impl Organizations for OrganizationsClient {
         input: DisablePolicyTypeRequest,
     ) -> Result<DisablePolicyTypeResponse, RusotoError<DisablePolicyTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisablePolicyType");
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.DisablePolicyType",
@@ -6629,6 +8273,7 @@ impl Organizations for OrganizationsClient {
         input: EnableAWSServiceAccessRequest,
     ) -> Result<(), RusotoError<EnableAWSServiceAccessError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("EnableAWSServiceAccess");
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.EnableAWSServiceAccess",
@@ -6648,6 +8293,7 @@ impl Organizations for OrganizationsClient {
         &self,
     ) -> Result<EnableAllFeaturesResponse, RusotoError<EnableAllFeaturesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("EnableAllFeatures");
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.EnableAllFeatures",
@@ -6668,6 +8314,7 @@ impl Organizations for OrganizationsClient {
         input: EnablePolicyTypeRequest,
     ) -> Result<EnablePolicyTypeResponse, RusotoError<EnablePolicyTypeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("EnablePolicyType");
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.EnablePolicyType");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6687,6 +8334,7 @@ impl Organizations for OrganizationsClient {
     ) -> Result<InviteAccountToOrganizationResponse, RusotoError<InviteAccountToOrganizationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("InviteAccountToOrganization");
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.InviteAccountToOrganization",
@@ -6706,6 +8354,7 @@ impl Organizations for OrganizationsClient {
     /// <p><p>Removes a member account from its parent organization. This version of the operation is performed by the account that wants to leave. To remove a member account as a user in the management account, use <a>RemoveAccountFromOrganization</a> instead.</p> <p>This operation can be called only from a member account in the organization.</p> <important> <ul> <li> <p>The management account in an organization with all features enabled can set service control policies (SCPs) that can restrict what administrators of member accounts can do. This includes preventing them from successfully calling <code>LeaveOrganization</code> and leaving the organization.</p> </li> <li> <p>You can leave an organization as a member account only if the account is configured with the information required to operate as a standalone account. When you create an account in an organization using the AWS Organizations console, API, or CLI commands, the information required of standalone accounts is <i>not</i> automatically collected. For each account that you want to make standalone, you must perform the following steps. If any of the steps are already completed for this account, that step doesn&#39;t appear.</p> <ul> <li> <p>Choose a support plan</p> </li> <li> <p>Provide and verify the required contact information</p> </li> <li> <p>Provide a current payment method</p> </li> </ul> <p>AWS uses the payment method to charge for any billable (not free tier) AWS activity that occurs while the account isn&#39;t attached to an organization. Follow the steps at <a href="http://docs.aws.amazon.com/organizations/latest/userguide/orgs_manage_accounts_remove.html#leave-without-all-info"> To leave an organization when all required account information has not yet been provided</a> in the <i>AWS Organizations User Guide.</i> </p> </li> <li> <p>You can leave an organization only after you enable IAM user access to billing in your account. For more information, see <a href="http://docs.aws.amazon.com/awsaccountbilling/latest/aboutv2/grantaccess.html#ControllingAccessWebsite-Activate">Activating Access to the Billing and Cost Management Console</a> in the <i>AWS Billing and Cost Management User Guide.</i> </p> </li> <li> <p>After the account leaves the organization, all tags that were attached to the account object in the organization are deleted. AWS accounts outside of an organization do not support tags.</p> </li> </ul> </important></p>
     async fn leave_organization(&self) -> Result<(), RusotoError<LeaveOrganizationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("LeaveOrganization");
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.LeaveOrganization",
@@ -6728,6 +8377,7 @@ impl Organizations for OrganizationsClient {
         RusotoError<ListAWSServiceAccessForOrganizationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListAWSServiceAccessForOrganization");
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.ListAWSServiceAccessForOrganization",
@@ -6753,6 +8403,7 @@ impl Organizations for OrganizationsClient {
         input: ListAccountsRequest,
     ) -> Result<ListAccountsResponse, RusotoError<ListAccountsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListAccounts");
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.ListAccounts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6771,6 +8422,7 @@ impl Organizations for OrganizationsClient {
         input: ListAccountsForParentRequest,
     ) -> Result<ListAccountsForParentResponse, RusotoError<ListAccountsForParentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListAccountsForParent");
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.ListAccountsForParent",
@@ -6793,6 +8445,7 @@ impl Organizations for OrganizationsClient {
         input: ListChildrenRequest,
     ) -> Result<ListChildrenResponse, RusotoError<ListChildrenError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListChildren");
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.ListChildren");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6811,6 +8464,7 @@ impl Organizations for OrganizationsClient {
         input: ListCreateAccountStatusRequest,
     ) -> Result<ListCreateAccountStatusResponse, RusotoError<ListCreateAccountStatusError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListCreateAccountStatus");
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.ListCreateAccountStatus",
@@ -6834,6 +8488,7 @@ impl Organizations for OrganizationsClient {
     ) -> Result<ListDelegatedAdministratorsResponse, RusotoError<ListDelegatedAdministratorsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListDelegatedAdministrators");
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.ListDelegatedAdministrators",
@@ -6859,6 +8514,7 @@ impl Organizations for OrganizationsClient {
         RusotoError<ListDelegatedServicesForAccountError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListDelegatedServicesForAccount");
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.ListDelegatedServicesForAccount",
@@ -6881,6 +8537,7 @@ impl Organizations for OrganizationsClient {
         input: ListHandshakesForAccountRequest,
     ) -> Result<ListHandshakesForAccountResponse, RusotoError<ListHandshakesForAccountError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListHandshakesForAccount");
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.ListHandshakesForAccount",
@@ -6906,6 +8563,7 @@ impl Organizations for OrganizationsClient {
         RusotoError<ListHandshakesForOrganizationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListHandshakesForOrganization");
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.ListHandshakesForOrganization",
@@ -6931,6 +8589,7 @@ impl Organizations for OrganizationsClient {
         RusotoError<ListOrganizationalUnitsForParentError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListOrganizationalUnitsForParent");
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.ListOrganizationalUnitsForParent",
@@ -6956,6 +8615,7 @@ impl Organizations for OrganizationsClient {
         input: ListParentsRequest,
     ) -> Result<ListParentsResponse, RusotoError<ListParentsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListParents");
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.ListParents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6974,6 +8634,7 @@ impl Organizations for OrganizationsClient {
         input: ListPoliciesRequest,
     ) -> Result<ListPoliciesResponse, RusotoError<ListPoliciesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListPolicies");
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.ListPolicies");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -6992,6 +8653,7 @@ impl Organizations for OrganizationsClient {
         input: ListPoliciesForTargetRequest,
     ) -> Result<ListPoliciesForTargetResponse, RusotoError<ListPoliciesForTargetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListPoliciesForTarget");
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.ListPoliciesForTarget",
@@ -7014,6 +8676,7 @@ impl Organizations for OrganizationsClient {
         input: ListRootsRequest,
     ) -> Result<ListRootsResponse, RusotoError<ListRootsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListRoots");
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.ListRoots");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7032,6 +8695,7 @@ impl Organizations for OrganizationsClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.ListTagsForResource",
@@ -7053,6 +8717,7 @@ impl Organizations for OrganizationsClient {
         input: ListTargetsForPolicyRequest,
     ) -> Result<ListTargetsForPolicyResponse, RusotoError<ListTargetsForPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTargetsForPolicy");
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.ListTargetsForPolicy",
@@ -7075,6 +8740,7 @@ impl Organizations for OrganizationsClient {
         input: MoveAccountRequest,
     ) -> Result<(), RusotoError<MoveAccountError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("MoveAccount");
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.MoveAccount");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7092,6 +8758,7 @@ impl Organizations for OrganizationsClient {
         input: RegisterDelegatedAdministratorRequest,
     ) -> Result<(), RusotoError<RegisterDelegatedAdministratorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RegisterDelegatedAdministrator");
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.RegisterDelegatedAdministrator",
@@ -7112,6 +8779,7 @@ impl Organizations for OrganizationsClient {
         input: RemoveAccountFromOrganizationRequest,
     ) -> Result<(), RusotoError<RemoveAccountFromOrganizationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RemoveAccountFromOrganization");
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.RemoveAccountFromOrganization",
@@ -7132,6 +8800,7 @@ impl Organizations for OrganizationsClient {
         input: TagResourceRequest,
     ) -> Result<(), RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7149,6 +8818,7 @@ impl Organizations for OrganizationsClient {
         input: UntagResourceRequest,
     ) -> Result<(), RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7166,6 +8836,7 @@ impl Organizations for OrganizationsClient {
         input: UpdateOrganizationalUnitRequest,
     ) -> Result<UpdateOrganizationalUnitResponse, RusotoError<UpdateOrganizationalUnitError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateOrganizationalUnit");
         request.add_header(
             "x-amz-target",
             "AWSOrganizationsV20161128.UpdateOrganizationalUnit",
@@ -7188,6 +8859,7 @@ impl Organizations for OrganizationsClient {
         input: UpdatePolicyRequest,
     ) -> Result<UpdatePolicyResponse, RusotoError<UpdatePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdatePolicy");
         request.add_header("x-amz-target", "AWSOrganizationsV20161128.UpdatePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7200,3 +8872,525 @@ impl Organizations for OrganizationsClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdatePolicyResponse, _>()
     }
 }
+
+/// A blocking client for the Organizations API, running the calls of a `OrganizationsClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `OrganizationsClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct OrganizationsBlockingClient {
+    client: OrganizationsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl OrganizationsBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> OrganizationsBlockingClient {
+        OrganizationsBlockingClient::from_client(OrganizationsClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> OrganizationsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        OrganizationsBlockingClient::from_client(OrganizationsClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> OrganizationsBlockingClient {
+        OrganizationsBlockingClient::from_client(OrganizationsClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> OrganizationsBlockingClient {
+        OrganizationsBlockingClient::from_client(OrganizationsClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: OrganizationsClient) -> OrganizationsBlockingClient {
+        OrganizationsBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> OrganizationsBlockingClient {
+        OrganizationsBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> OrganizationsBlockingClient {
+        OrganizationsBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Organizations::accept_handshake`].
+    pub fn accept_handshake(
+        &self,
+        input: AcceptHandshakeRequest,
+    ) -> Result<AcceptHandshakeResponse, RusotoError<AcceptHandshakeError>> {
+        self.runtime.block_on(self.client.accept_handshake(input))
+    }
+
+    /// Blocking version of [`Organizations::attach_policy`].
+    pub fn attach_policy(
+        &self,
+        input: AttachPolicyRequest,
+    ) -> Result<(), RusotoError<AttachPolicyError>> {
+        self.runtime.block_on(self.client.attach_policy(input))
+    }
+
+    /// Blocking version of [`Organizations::cancel_handshake`].
+    pub fn cancel_handshake(
+        &self,
+        input: CancelHandshakeRequest,
+    ) -> Result<CancelHandshakeResponse, RusotoError<CancelHandshakeError>> {
+        self.runtime.block_on(self.client.cancel_handshake(input))
+    }
+
+    /// Blocking version of [`Organizations::create_account`].
+    pub fn create_account(
+        &self,
+        input: CreateAccountRequest,
+    ) -> Result<CreateAccountResponse, RusotoError<CreateAccountError>> {
+        self.runtime.block_on(self.client.create_account(input))
+    }
+
+    /// Blocking version of [`Organizations::create_gov_cloud_account`].
+    pub fn create_gov_cloud_account(
+        &self,
+        input: CreateGovCloudAccountRequest,
+    ) -> Result<CreateGovCloudAccountResponse, RusotoError<CreateGovCloudAccountError>> {
+        self.runtime
+            .block_on(self.client.create_gov_cloud_account(input))
+    }
+
+    /// Blocking version of [`Organizations::create_organization`].
+    pub fn create_organization(
+        &self,
+        input: CreateOrganizationRequest,
+    ) -> Result<CreateOrganizationResponse, RusotoError<CreateOrganizationError>> {
+        self.runtime
+            .block_on(self.client.create_organization(input))
+    }
+
+    /// Blocking version of [`Organizations::create_organizational_unit`].
+    pub fn create_organizational_unit(
+        &self,
+        input: CreateOrganizationalUnitRequest,
+    ) -> Result<CreateOrganizationalUnitResponse, RusotoError<CreateOrganizationalUnitError>> {
+        self.runtime
+            .block_on(self.client.create_organizational_unit(input))
+    }
+
+    /// Blocking version of [`Organizations::create_policy`].
+    pub fn create_policy(
+        &self,
+        input: CreatePolicyRequest,
+    ) -> Result<CreatePolicyResponse, RusotoError<CreatePolicyError>> {
+        self.runtime.block_on(self.client.create_policy(input))
+    }
+
+    /// Blocking version of [`Organizations::decline_handshake`].
+    pub fn decline_handshake(
+        &self,
+        input: DeclineHandshakeRequest,
+    ) -> Result<DeclineHandshakeResponse, RusotoError<DeclineHandshakeError>> {
+        self.runtime.block_on(self.client.decline_handshake(input))
+    }
+
+    /// Blocking version of [`Organizations::delete_organization`].
+    pub fn delete_organization(&self) -> Result<(), RusotoError<DeleteOrganizationError>> {
+        self.runtime.block_on(self.client.delete_organization())
+    }
+
+    /// Blocking version of [`Organizations::delete_organizational_unit`].
+    pub fn delete_organizational_unit(
+        &self,
+        input: DeleteOrganizationalUnitRequest,
+    ) -> Result<(), RusotoError<DeleteOrganizationalUnitError>> {
+        self.runtime
+            .block_on(self.client.delete_organizational_unit(input))
+    }
+
+    /// Blocking version of [`Organizations::delete_policy`].
+    pub fn delete_policy(
+        &self,
+        input: DeletePolicyRequest,
+    ) -> Result<(), RusotoError<DeletePolicyError>> {
+        self.runtime.block_on(self.client.delete_policy(input))
+    }
+
+    /// Blocking version of [`Organizations::deregister_delegated_administrator`].
+    pub fn deregister_delegated_administrator(
+        &self,
+        input: DeregisterDelegatedAdministratorRequest,
+    ) -> Result<(), RusotoError<DeregisterDelegatedAdministratorError>> {
+        self.runtime
+            .block_on(self.client.deregister_delegated_administrator(input))
+    }
+
+    /// Blocking version of [`Organizations::describe_account`].
+    pub fn describe_account(
+        &self,
+        input: DescribeAccountRequest,
+    ) -> Result<DescribeAccountResponse, RusotoError<DescribeAccountError>> {
+        self.runtime.block_on(self.client.describe_account(input))
+    }
+
+    /// Blocking version of [`Organizations::describe_create_account_status`].
+    pub fn describe_create_account_status(
+        &self,
+        input: DescribeCreateAccountStatusRequest,
+    ) -> Result<DescribeCreateAccountStatusResponse, RusotoError<DescribeCreateAccountStatusError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_create_account_status(input))
+    }
+
+    /// Blocking version of [`Organizations::describe_effective_policy`].
+    pub fn describe_effective_policy(
+        &self,
+        input: DescribeEffectivePolicyRequest,
+    ) -> Result<DescribeEffectivePolicyResponse, RusotoError<DescribeEffectivePolicyError>> {
+        self.runtime
+            .block_on(self.client.describe_effective_policy(input))
+    }
+
+    /// Blocking version of [`Organizations::describe_handshake`].
+    pub fn describe_handshake(
+        &self,
+        input: DescribeHandshakeRequest,
+    ) -> Result<DescribeHandshakeResponse, RusotoError<DescribeHandshakeError>> {
+        self.runtime.block_on(self.client.describe_handshake(input))
+    }
+
+    /// Blocking version of [`Organizations::describe_organization`].
+    pub fn describe_organization(
+        &self,
+    ) -> Result<DescribeOrganizationResponse, RusotoError<DescribeOrganizationError>> {
+        self.runtime.block_on(self.client.describe_organization())
+    }
+
+    /// Blocking version of [`Organizations::describe_organizational_unit`].
+    pub fn describe_organizational_unit(
+        &self,
+        input: DescribeOrganizationalUnitRequest,
+    ) -> Result<DescribeOrganizationalUnitResponse, RusotoError<DescribeOrganizationalUnitError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_organizational_unit(input))
+    }
+
+    /// Blocking version of [`Organizations::describe_policy`].
+    pub fn describe_policy(
+        &self,
+        input: DescribePolicyRequest,
+    ) -> Result<DescribePolicyResponse, RusotoError<DescribePolicyError>> {
+        self.runtime.block_on(self.client.describe_policy(input))
+    }
+
+    /// Blocking version of [`Organizations::detach_policy`].
+    pub fn detach_policy(
+        &self,
+        input: DetachPolicyRequest,
+    ) -> Result<(), RusotoError<DetachPolicyError>> {
+        self.runtime.block_on(self.client.detach_policy(input))
+    }
+
+    /// Blocking version of [`Organizations::disable_aws_service_access`].
+    pub fn disable_aws_service_access(
+        &self,
+        input: DisableAWSServiceAccessRequest,
+    ) -> Result<(), RusotoError<DisableAWSServiceAccessError>> {
+        self.runtime
+            .block_on(self.client.disable_aws_service_access(input))
+    }
+
+    /// Blocking version of [`Organizations::disable_policy_type`].
+    pub fn disable_policy_type(
+        &self,
+        input: DisablePolicyTypeRequest,
+    ) -> Result<DisablePolicyTypeResponse, RusotoError<DisablePolicyTypeError>> {
+        self.runtime
+            .block_on(self.client.disable_policy_type(input))
+    }
+
+    /// Blocking version of [`Organizations::enable_aws_service_access`].
+    pub fn enable_aws_service_access(
+        &self,
+        input: EnableAWSServiceAccessRequest,
+    ) -> Result<(), RusotoError<EnableAWSServiceAccessError>> {
+        self.runtime
+            .block_on(self.client.enable_aws_service_access(input))
+    }
+
+    /// Blocking version of [`Organizations::enable_all_features`].
+    pub fn enable_all_features(
+        &self,
+    ) -> Result<EnableAllFeaturesResponse, RusotoError<EnableAllFeaturesError>> {
+        self.runtime.block_on(self.client.enable_all_features())
+    }
+
+    /// Blocking version of [`Organizations::enable_policy_type`].
+    pub fn enable_policy_type(
+        &self,
+        input: EnablePolicyTypeRequest,
+    ) -> Result<EnablePolicyTypeResponse, RusotoError<EnablePolicyTypeError>> {
+        self.runtime.block_on(self.client.enable_policy_type(input))
+    }
+
+    /// Blocking version of [`Organizations::invite_account_to_organization`].
+    pub fn invite_account_to_organization(
+        &self,
+        input: InviteAccountToOrganizationRequest,
+    ) -> Result<InviteAccountToOrganizationResponse, RusotoError<InviteAccountToOrganizationError>>
+    {
+        self.runtime
+            .block_on(self.client.invite_account_to_organization(input))
+    }
+
+    /// Blocking version of [`Organizations::leave_organization`].
+    pub fn leave_organization(&self) -> Result<(), RusotoError<LeaveOrganizationError>> {
+        self.runtime.block_on(self.client.leave_organization())
+    }
+
+    /// Blocking version of [`Organizations::list_aws_service_access_for_organization`].
+    pub fn list_aws_service_access_for_organization(
+        &self,
+        input: ListAWSServiceAccessForOrganizationRequest,
+    ) -> Result<
+        ListAWSServiceAccessForOrganizationResponse,
+        RusotoError<ListAWSServiceAccessForOrganizationError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_aws_service_access_for_organization(input))
+    }
+
+    /// Blocking version of [`Organizations::list_accounts`].
+    pub fn list_accounts(
+        &self,
+        input: ListAccountsRequest,
+    ) -> Result<ListAccountsResponse, RusotoError<ListAccountsError>> {
+        self.runtime.block_on(self.client.list_accounts(input))
+    }
+
+    /// Blocking version of [`Organizations::list_accounts_for_parent`].
+    pub fn list_accounts_for_parent(
+        &self,
+        input: ListAccountsForParentRequest,
+    ) -> Result<ListAccountsForParentResponse, RusotoError<ListAccountsForParentError>> {
+        self.runtime
+            .block_on(self.client.list_accounts_for_parent(input))
+    }
+
+    /// Blocking version of [`Organizations::list_children`].
+    pub fn list_children(
+        &self,
+        input: ListChildrenRequest,
+    ) -> Result<ListChildrenResponse, RusotoError<ListChildrenError>> {
+        self.runtime.block_on(self.client.list_children(input))
+    }
+
+    /// Blocking version of [`Organizations::list_create_account_status`].
+    pub fn list_create_account_status(
+        &self,
+        input: ListCreateAccountStatusRequest,
+    ) -> Result<ListCreateAccountStatusResponse, RusotoError<ListCreateAccountStatusError>> {
+        self.runtime
+            .block_on(self.client.list_create_account_status(input))
+    }
+
+    /// Blocking version of [`Organizations::list_delegated_administrators`].
+    pub fn list_delegated_administrators(
+        &self,
+        input: ListDelegatedAdministratorsRequest,
+    ) -> Result<ListDelegatedAdministratorsResponse, RusotoError<ListDelegatedAdministratorsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_delegated_administrators(input))
+    }
+
+    /// Blocking version of [`Organizations::list_delegated_services_for_account`].
+    pub fn list_delegated_services_for_account(
+        &self,
+        input: ListDelegatedServicesForAccountRequest,
+    ) -> Result<
+        ListDelegatedServicesForAccountResponse,
+        RusotoError<ListDelegatedServicesForAccountError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_delegated_services_for_account(input))
+    }
+
+    /// Blocking version of [`Organizations::list_handshakes_for_account`].
+    pub fn list_handshakes_for_account(
+        &self,
+        input: ListHandshakesForAccountRequest,
+    ) -> Result<ListHandshakesForAccountResponse, RusotoError<ListHandshakesForAccountError>> {
+        self.runtime
+            .block_on(self.client.list_handshakes_for_account(input))
+    }
+
+    /// Blocking version of [`Organizations::list_handshakes_for_organization`].
+    pub fn list_handshakes_for_organization(
+        &self,
+        input: ListHandshakesForOrganizationRequest,
+    ) -> Result<
+        ListHandshakesForOrganizationResponse,
+        RusotoError<ListHandshakesForOrganizationError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_handshakes_for_organization(input))
+    }
+
+    /// Blocking version of [`Organizations::list_organizational_units_for_parent`].
+    pub fn list_organizational_units_for_parent(
+        &self,
+        input: ListOrganizationalUnitsForParentRequest,
+    ) -> Result<
+        ListOrganizationalUnitsForParentResponse,
+        RusotoError<ListOrganizationalUnitsForParentError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_organizational_units_for_parent(input))
+    }
+
+    /// Blocking version of [`Organizations::list_parents`].
+    pub fn list_parents(
+        &self,
+        input: ListParentsRequest,
+    ) -> Result<ListParentsResponse, RusotoError<ListParentsError>> {
+        self.runtime.block_on(self.client.list_parents(input))
+    }
+
+    /// Blocking version of [`Organizations::list_policies`].
+    pub fn list_policies(
+        &self,
+        input: ListPoliciesRequest,
+    ) -> Result<ListPoliciesResponse, RusotoError<ListPoliciesError>> {
+        self.runtime.block_on(self.client.list_policies(input))
+    }
+
+    /// Blocking version of [`Organizations::list_policies_for_target`].
+    pub fn list_policies_for_target(
+        &self,
+        input: ListPoliciesForTargetRequest,
+    ) -> Result<ListPoliciesForTargetResponse, RusotoError<ListPoliciesForTargetError>> {
+        self.runtime
+            .block_on(self.client.list_policies_for_target(input))
+    }
+
+    /// Blocking version of [`Organizations::list_roots`].
+    pub fn list_roots(
+        &self,
+        input: ListRootsRequest,
+    ) -> Result<ListRootsResponse, RusotoError<ListRootsError>> {
+        self.runtime.block_on(self.client.list_roots(input))
+    }
+
+    /// Blocking version of [`Organizations::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`Organizations::list_targets_for_policy`].
+    pub fn list_targets_for_policy(
+        &self,
+        input: ListTargetsForPolicyRequest,
+    ) -> Result<ListTargetsForPolicyResponse, RusotoError<ListTargetsForPolicyError>> {
+        self.runtime
+            .block_on(self.client.list_targets_for_policy(input))
+    }
+
+    /// Blocking version of [`Organizations::move_account`].
+    pub fn move_account(
+        &self,
+        input: MoveAccountRequest,
+    ) -> Result<(), RusotoError<MoveAccountError>> {
+        self.runtime.block_on(self.client.move_account(input))
+    }
+
+    /// Blocking version of [`Organizations::register_delegated_administrator`].
+    pub fn register_delegated_administrator(
+        &self,
+        input: RegisterDelegatedAdministratorRequest,
+    ) -> Result<(), RusotoError<RegisterDelegatedAdministratorError>> {
+        self.runtime
+            .block_on(self.client.register_delegated_administrator(input))
+    }
+
+    /// Blocking version of [`Organizations::remove_account_from_organization`].
+    pub fn remove_account_from_organization(
+        &self,
+        input: RemoveAccountFromOrganizationRequest,
+    ) -> Result<(), RusotoError<RemoveAccountFromOrganizationError>> {
+        self.runtime
+            .block_on(self.client.remove_account_from_organization(input))
+    }
+
+    /// Blocking version of [`Organizations::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<(), RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`Organizations::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<(), RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`Organizations::update_organizational_unit`].
+    pub fn update_organizational_unit(
+        &self,
+        input: UpdateOrganizationalUnitRequest,
+    ) -> Result<UpdateOrganizationalUnitResponse, RusotoError<UpdateOrganizationalUnitError>> {
+        self.runtime
+            .block_on(self.client.update_organizational_unit(input))
+    }
+
+    /// Blocking version of [`Organizations::update_policy`].
+    pub fn update_policy(
+        &self,
+        input: UpdatePolicyRequest,
+    ) -> Result<UpdatePolicyResponse, RusotoError<UpdatePolicyError>> {
+        self.runtime.block_on(self.client.update_policy(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -138,9 +139,14 @@ impl PutEventsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(PutEventsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutEventsError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -170,12 +176,22 @@ impl PutItemsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(PutItemsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutItemsError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutItemsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutItemsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -206,12 +222,22 @@ impl PutUsersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(PutUsersError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutUsersError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(PutUsersError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutUsersError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -250,11 +276,12 @@ pub struct PersonalizeEventsClient {
 impl PersonalizeEventsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> PersonalizeEventsClient {
         PersonalizeEventsClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Personalize Events"),
         }
     }
 
@@ -269,12 +296,32 @@ impl PersonalizeEventsClient {
     {
         PersonalizeEventsClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Personalize Events"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> PersonalizeEventsClient {
-        PersonalizeEventsClient { client, region }
+        PersonalizeEventsClient {
+            client,
+            region: region_for_service(region, "Personalize Events"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> PersonalizeEventsClient {
+        PersonalizeEventsClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Personalize Events"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> PersonalizeEventsClient {
+        PersonalizeEventsClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -286,6 +333,7 @@ impl PersonalizeEvents for PersonalizeEventsClient {
         let request_uri = "/events";
 
         let mut request = SignedRequest::new("POST", "personalize", &self.region, &request_uri);
+        request.set_operation("PutEvents");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("personalize-events".to_string());
@@ -314,6 +362,7 @@ impl PersonalizeEvents for PersonalizeEventsClient {
         let request_uri = "/items";
 
         let mut request = SignedRequest::new("POST", "personalize", &self.region, &request_uri);
+        request.set_operation("PutItems");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("personalize-events".to_string());
@@ -342,6 +391,7 @@ impl PersonalizeEvents for PersonalizeEventsClient {
         let request_uri = "/users";
 
         let mut request = SignedRequest::new("POST", "personalize", &self.region, &request_uri);
+        request.set_operation("PutUsers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("personalize-events".to_string());
@@ -364,3 +414,103 @@ impl PersonalizeEvents for PersonalizeEventsClient {
         }
     }
 }
+
+/// A blocking client for the Amazon Personalize Events API, running the calls of a `PersonalizeEventsClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `PersonalizeEventsClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct PersonalizeEventsBlockingClient {
+    client: PersonalizeEventsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl PersonalizeEventsBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> PersonalizeEventsBlockingClient {
+        PersonalizeEventsBlockingClient::from_client(PersonalizeEventsClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> PersonalizeEventsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        PersonalizeEventsBlockingClient::from_client(PersonalizeEventsClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> PersonalizeEventsBlockingClient {
+        PersonalizeEventsBlockingClient::from_client(PersonalizeEventsClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> PersonalizeEventsBlockingClient {
+        PersonalizeEventsBlockingClient::from_client(PersonalizeEventsClient::new_with_config(
+            config,
+        ))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: PersonalizeEventsClient) -> PersonalizeEventsBlockingClient {
+        PersonalizeEventsBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> PersonalizeEventsBlockingClient {
+        PersonalizeEventsBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> PersonalizeEventsBlockingClient {
+        PersonalizeEventsBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`PersonalizeEvents::put_events`].
+    pub fn put_events(&self, input: PutEventsRequest) -> Result<(), RusotoError<PutEventsError>> {
+        self.runtime.block_on(self.client.put_events(input))
+    }
+
+    /// Blocking version of [`PersonalizeEvents::put_items`].
+    pub fn put_items(&self, input: PutItemsRequest) -> Result<(), RusotoError<PutItemsError>> {
+        self.runtime.block_on(self.client.put_items(input))
+    }
+
+    /// Blocking version of [`PersonalizeEvents::put_users`].
+    pub fn put_users(&self, input: PutUsersRequest) -> Result<(), RusotoError<PutUsersError>> {
+        self.runtime.block_on(self.client.put_users(input))
+    }
+}
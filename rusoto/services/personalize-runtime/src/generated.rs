@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -136,14 +137,22 @@ impl GetPersonalizedRankingError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetPersonalizedRankingError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPersonalizedRankingError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetPersonalizedRankingError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetPersonalizedRankingError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -174,12 +183,22 @@ impl GetRecommendationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetRecommendationsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRecommendationsError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetRecommendationsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRecommendationsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -221,11 +240,12 @@ pub struct PersonalizeRuntimeClient {
 impl PersonalizeRuntimeClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> PersonalizeRuntimeClient {
         PersonalizeRuntimeClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Personalize Runtime"),
         }
     }
 
@@ -240,12 +260,32 @@ impl PersonalizeRuntimeClient {
     {
         PersonalizeRuntimeClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Personalize Runtime"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> PersonalizeRuntimeClient {
-        PersonalizeRuntimeClient { client, region }
+        PersonalizeRuntimeClient {
+            client,
+            region: region_for_service(region, "Personalize Runtime"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> PersonalizeRuntimeClient {
+        PersonalizeRuntimeClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Personalize Runtime"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> PersonalizeRuntimeClient {
+        PersonalizeRuntimeClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -260,6 +300,7 @@ impl PersonalizeRuntime for PersonalizeRuntimeClient {
         let request_uri = "/personalize-ranking";
 
         let mut request = SignedRequest::new("POST", "personalize", &self.region, &request_uri);
+        request.set_operation("GetPersonalizedRanking");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("personalize-runtime".to_string());
@@ -292,6 +333,7 @@ impl PersonalizeRuntime for PersonalizeRuntimeClient {
         let request_uri = "/recommendations";
 
         let mut request = SignedRequest::new("POST", "personalize", &self.region, &request_uri);
+        request.set_operation("GetRecommendations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("personalize-runtime".to_string());
@@ -315,3 +357,106 @@ impl PersonalizeRuntime for PersonalizeRuntimeClient {
         }
     }
 }
+
+/// A blocking client for the Amazon Personalize Runtime API, running the calls of a `PersonalizeRuntimeClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `PersonalizeRuntimeClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct PersonalizeRuntimeBlockingClient {
+    client: PersonalizeRuntimeClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl PersonalizeRuntimeBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> PersonalizeRuntimeBlockingClient {
+        PersonalizeRuntimeBlockingClient::from_client(PersonalizeRuntimeClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> PersonalizeRuntimeBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        PersonalizeRuntimeBlockingClient::from_client(PersonalizeRuntimeClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> PersonalizeRuntimeBlockingClient {
+        PersonalizeRuntimeBlockingClient::from_client(PersonalizeRuntimeClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> PersonalizeRuntimeBlockingClient {
+        PersonalizeRuntimeBlockingClient::from_client(PersonalizeRuntimeClient::new_with_config(
+            config,
+        ))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: PersonalizeRuntimeClient) -> PersonalizeRuntimeBlockingClient {
+        PersonalizeRuntimeBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> PersonalizeRuntimeBlockingClient {
+        PersonalizeRuntimeBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> PersonalizeRuntimeBlockingClient {
+        PersonalizeRuntimeBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`PersonalizeRuntime::get_personalized_ranking`].
+    pub fn get_personalized_ranking(
+        &self,
+        input: GetPersonalizedRankingRequest,
+    ) -> Result<GetPersonalizedRankingResponse, RusotoError<GetPersonalizedRankingError>> {
+        self.runtime
+            .block_on(self.client.get_personalized_ranking(input))
+    }
+
+    /// Blocking version of [`PersonalizeRuntime::get_recommendations`].
+    pub fn get_recommendations(
+        &self,
+        input: GetRecommendationsRequest,
+    ) -> Result<GetRecommendationsResponse, RusotoError<GetRecommendationsError>> {
+        self.runtime
+            .block_on(self.client.get_recommendations(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -2221,31 +2222,46 @@ impl CreateBatchInferenceJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateBatchInferenceJobError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateBatchInferenceJobError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateBatchInferenceJobError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateBatchInferenceJobError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        CreateBatchInferenceJobError::ResourceAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateBatchInferenceJobError::ResourceAlreadyExists,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateBatchInferenceJobError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateBatchInferenceJobError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateBatchInferenceJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateBatchInferenceJobError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2287,23 +2303,46 @@ impl CreateCampaignError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateCampaignError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCampaignError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateCampaignError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCampaignError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateCampaignError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCampaignError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateCampaignError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCampaignError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateCampaignError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCampaignError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2343,21 +2382,46 @@ impl CreateDatasetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDatasetError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDatasetError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDatasetError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDatasetError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateDatasetError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDatasetError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateDatasetError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDatasetError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDatasetError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDatasetError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2393,17 +2457,30 @@ impl CreateDatasetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDatasetGroupError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDatasetGroupError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDatasetGroupError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDatasetGroupError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateDatasetGroupError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDatasetGroupError::ResourceAlreadyExists,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2441,29 +2518,46 @@ impl CreateDatasetImportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateDatasetImportJobError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDatasetImportJobError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDatasetImportJobError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDatasetImportJobError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        CreateDatasetImportJobError::ResourceAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDatasetImportJobError::ResourceAlreadyExists,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateDatasetImportJobError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDatasetImportJobError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDatasetImportJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDatasetImportJobError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2503,23 +2597,46 @@ impl CreateEventTrackerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateEventTrackerError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEventTrackerError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateEventTrackerError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEventTrackerError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateEventTrackerError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateEventTrackerError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateEventTrackerError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEventTrackerError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateEventTrackerError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEventTrackerError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2557,18 +2674,38 @@ impl CreateFilterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateFilterError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFilterError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateFilterError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFilterError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateFilterError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFilterError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateFilterError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFilterError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2603,15 +2740,30 @@ impl CreateSchemaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateSchemaError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSchemaError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateSchemaError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSchemaError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateSchemaError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSchemaError::ResourceAlreadyExists,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2649,23 +2801,46 @@ impl CreateSolutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateSolutionError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSolutionError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateSolutionError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSolutionError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateSolutionError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSolutionError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateSolutionError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSolutionError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateSolutionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSolutionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2703,20 +2878,38 @@ impl CreateSolutionVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(CreateSolutionVersionError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSolutionVersionError::InvalidInput,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateSolutionVersionError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSolutionVersionError::LimitExceeded,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateSolutionVersionError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSolutionVersionError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateSolutionVersionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSolutionVersionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2751,15 +2944,30 @@ impl DeleteCampaignError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteCampaignError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCampaignError::InvalidInput,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteCampaignError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCampaignError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteCampaignError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCampaignError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2793,15 +3001,30 @@ impl DeleteDatasetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteDatasetError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDatasetError::InvalidInput,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteDatasetError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDatasetError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDatasetError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDatasetError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2835,15 +3058,30 @@ impl DeleteDatasetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteDatasetGroupError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDatasetGroupError::InvalidInput,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteDatasetGroupError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDatasetGroupError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDatasetGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDatasetGroupError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2877,15 +3115,30 @@ impl DeleteEventTrackerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteEventTrackerError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEventTrackerError::InvalidInput,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteEventTrackerError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEventTrackerError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteEventTrackerError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEventTrackerError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2919,15 +3172,30 @@ impl DeleteFilterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteFilterError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFilterError::InvalidInput,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteFilterError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFilterError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteFilterError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFilterError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2961,15 +3229,30 @@ impl DeleteSchemaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteSchemaError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSchemaError::InvalidInput,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteSchemaError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSchemaError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteSchemaError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSchemaError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3003,15 +3286,30 @@ impl DeleteSolutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DeleteSolutionError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSolutionError::InvalidInput,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteSolutionError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSolutionError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteSolutionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSolutionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3043,12 +3341,22 @@ impl DescribeAlgorithmError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeAlgorithmError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAlgorithmError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeAlgorithmError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAlgorithmError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3079,16 +3387,22 @@ impl DescribeBatchInferenceJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeBatchInferenceJobError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeBatchInferenceJobError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeBatchInferenceJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeBatchInferenceJobError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3119,12 +3433,22 @@ impl DescribeCampaignError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeCampaignError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCampaignError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeCampaignError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCampaignError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3155,12 +3479,22 @@ impl DescribeDatasetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeDatasetError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDatasetError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDatasetError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDatasetError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3191,14 +3525,22 @@ impl DescribeDatasetGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeDatasetGroupError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDatasetGroupError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDatasetGroupError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDatasetGroupError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3229,16 +3571,22 @@ impl DescribeDatasetImportJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeDatasetImportJobError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDatasetImportJobError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDatasetImportJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDatasetImportJobError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3269,14 +3617,22 @@ impl DescribeEventTrackerError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeEventTrackerError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeEventTrackerError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeEventTrackerError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEventTrackerError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3309,16 +3665,22 @@ impl DescribeFeatureTransformationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeFeatureTransformationError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeFeatureTransformationError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeFeatureTransformationError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeFeatureTransformationError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3351,12 +3713,22 @@ impl DescribeFilterError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeFilterError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeFilterError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeFilterError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeFilterError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3387,12 +3759,22 @@ impl DescribeRecipeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeRecipeError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRecipeError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeRecipeError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRecipeError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3423,12 +3805,22 @@ impl DescribeSchemaError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeSchemaError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSchemaError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeSchemaError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSchemaError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3459,12 +3851,22 @@ impl DescribeSolutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeSolutionError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSolutionError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeSolutionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSolutionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3495,16 +3897,22 @@ impl DescribeSolutionVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(DescribeSolutionVersionError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeSolutionVersionError::InvalidInput,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeSolutionVersionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeSolutionVersionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3537,15 +3945,30 @@ impl GetSolutionMetricsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(GetSolutionMetricsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSolutionMetricsError::InvalidInput,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(GetSolutionMetricsError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSolutionMetricsError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetSolutionMetricsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSolutionMetricsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3577,14 +4000,22 @@ impl ListBatchInferenceJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListBatchInferenceJobsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListBatchInferenceJobsError::InvalidInput,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListBatchInferenceJobsError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListBatchInferenceJobsError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3615,12 +4046,22 @@ impl ListCampaignsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListCampaignsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCampaignsError::InvalidInput,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListCampaignsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCampaignsError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3649,9 +4090,14 @@ impl ListDatasetGroupsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDatasetGroupsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDatasetGroupsError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3681,14 +4127,22 @@ impl ListDatasetImportJobsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListDatasetImportJobsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDatasetImportJobsError::InvalidInput,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDatasetImportJobsError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDatasetImportJobsError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3719,12 +4173,22 @@ impl ListDatasetsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListDatasetsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDatasetsError::InvalidInput,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDatasetsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDatasetsError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3755,12 +4219,22 @@ impl ListEventTrackersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListEventTrackersError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListEventTrackersError::InvalidInput,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListEventTrackersError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListEventTrackersError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3791,12 +4265,22 @@ impl ListFiltersError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListFiltersError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFiltersError::InvalidInput,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListFiltersError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFiltersError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3825,9 +4309,14 @@ impl ListRecipesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListRecipesError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRecipesError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3855,9 +4344,14 @@ impl ListSchemasError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListSchemasError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSchemasError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3889,19 +4383,30 @@ impl ListSolutionVersionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListSolutionVersionsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSolutionVersionsError::InvalidInput,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListSolutionVersionsError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSolutionVersionsError::InvalidNextToken,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListSolutionVersionsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSolutionVersionsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3933,12 +4438,22 @@ impl ListSolutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(ListSolutionsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSolutionsError::InvalidInput,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListSolutionsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSolutionsError::InvalidNextToken,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3971,15 +4486,30 @@ impl UpdateCampaignError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInputException" => {
-                    return RusotoError::Service(UpdateCampaignError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCampaignError::InvalidInput,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(UpdateCampaignError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCampaignError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateCampaignError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateCampaignError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4271,11 +4801,12 @@ pub struct PersonalizeClient {
 impl PersonalizeClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> PersonalizeClient {
         PersonalizeClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Personalize"),
         }
     }
 
@@ -4290,12 +4821,32 @@ impl PersonalizeClient {
     {
         PersonalizeClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Personalize"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> PersonalizeClient {
-        PersonalizeClient { client, region }
+        PersonalizeClient {
+            client,
+            region: region_for_service(region, "Personalize"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> PersonalizeClient {
+        PersonalizeClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Personalize"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> PersonalizeClient {
+        PersonalizeClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -4307,6 +4858,7 @@ impl Personalize for PersonalizeClient {
         input: CreateBatchInferenceJobRequest,
     ) -> Result<CreateBatchInferenceJobResponse, RusotoError<CreateBatchInferenceJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateBatchInferenceJob");
         request.add_header("x-amz-target", "AmazonPersonalize.CreateBatchInferenceJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4326,6 +4878,7 @@ impl Personalize for PersonalizeClient {
         input: CreateCampaignRequest,
     ) -> Result<CreateCampaignResponse, RusotoError<CreateCampaignError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateCampaign");
         request.add_header("x-amz-target", "AmazonPersonalize.CreateCampaign");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4344,6 +4897,7 @@ impl Personalize for PersonalizeClient {
         input: CreateDatasetRequest,
     ) -> Result<CreateDatasetResponse, RusotoError<CreateDatasetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateDataset");
         request.add_header("x-amz-target", "AmazonPersonalize.CreateDataset");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4362,6 +4916,7 @@ impl Personalize for PersonalizeClient {
         input: CreateDatasetGroupRequest,
     ) -> Result<CreateDatasetGroupResponse, RusotoError<CreateDatasetGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateDatasetGroup");
         request.add_header("x-amz-target", "AmazonPersonalize.CreateDatasetGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4380,6 +4935,7 @@ impl Personalize for PersonalizeClient {
         input: CreateDatasetImportJobRequest,
     ) -> Result<CreateDatasetImportJobResponse, RusotoError<CreateDatasetImportJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateDatasetImportJob");
         request.add_header("x-amz-target", "AmazonPersonalize.CreateDatasetImportJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4399,6 +4955,7 @@ impl Personalize for PersonalizeClient {
         input: CreateEventTrackerRequest,
     ) -> Result<CreateEventTrackerResponse, RusotoError<CreateEventTrackerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateEventTracker");
         request.add_header("x-amz-target", "AmazonPersonalize.CreateEventTracker");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4417,6 +4974,7 @@ impl Personalize for PersonalizeClient {
         input: CreateFilterRequest,
     ) -> Result<CreateFilterResponse, RusotoError<CreateFilterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateFilter");
         request.add_header("x-amz-target", "AmazonPersonalize.CreateFilter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4435,6 +4993,7 @@ impl Personalize for PersonalizeClient {
         input: CreateSchemaRequest,
     ) -> Result<CreateSchemaResponse, RusotoError<CreateSchemaError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateSchema");
         request.add_header("x-amz-target", "AmazonPersonalize.CreateSchema");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4453,6 +5012,7 @@ impl Personalize for PersonalizeClient {
         input: CreateSolutionRequest,
     ) -> Result<CreateSolutionResponse, RusotoError<CreateSolutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateSolution");
         request.add_header("x-amz-target", "AmazonPersonalize.CreateSolution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4471,6 +5031,7 @@ impl Personalize for PersonalizeClient {
         input: CreateSolutionVersionRequest,
     ) -> Result<CreateSolutionVersionResponse, RusotoError<CreateSolutionVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateSolutionVersion");
         request.add_header("x-amz-target", "AmazonPersonalize.CreateSolutionVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4490,6 +5051,7 @@ impl Personalize for PersonalizeClient {
         input: DeleteCampaignRequest,
     ) -> Result<(), RusotoError<DeleteCampaignError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteCampaign");
         request.add_header("x-amz-target", "AmazonPersonalize.DeleteCampaign");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4507,6 +5069,7 @@ impl Personalize for PersonalizeClient {
         input: DeleteDatasetRequest,
     ) -> Result<(), RusotoError<DeleteDatasetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteDataset");
         request.add_header("x-amz-target", "AmazonPersonalize.DeleteDataset");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4524,6 +5087,7 @@ impl Personalize for PersonalizeClient {
         input: DeleteDatasetGroupRequest,
     ) -> Result<(), RusotoError<DeleteDatasetGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteDatasetGroup");
         request.add_header("x-amz-target", "AmazonPersonalize.DeleteDatasetGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4541,6 +5105,7 @@ impl Personalize for PersonalizeClient {
         input: DeleteEventTrackerRequest,
     ) -> Result<(), RusotoError<DeleteEventTrackerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteEventTracker");
         request.add_header("x-amz-target", "AmazonPersonalize.DeleteEventTracker");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4558,6 +5123,7 @@ impl Personalize for PersonalizeClient {
         input: DeleteFilterRequest,
     ) -> Result<(), RusotoError<DeleteFilterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteFilter");
         request.add_header("x-amz-target", "AmazonPersonalize.DeleteFilter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4575,6 +5141,7 @@ impl Personalize for PersonalizeClient {
         input: DeleteSchemaRequest,
     ) -> Result<(), RusotoError<DeleteSchemaError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteSchema");
         request.add_header("x-amz-target", "AmazonPersonalize.DeleteSchema");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4592,6 +5159,7 @@ impl Personalize for PersonalizeClient {
         input: DeleteSolutionRequest,
     ) -> Result<(), RusotoError<DeleteSolutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteSolution");
         request.add_header("x-amz-target", "AmazonPersonalize.DeleteSolution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4609,6 +5177,7 @@ impl Personalize for PersonalizeClient {
         input: DescribeAlgorithmRequest,
     ) -> Result<DescribeAlgorithmResponse, RusotoError<DescribeAlgorithmError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeAlgorithm");
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeAlgorithm");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4628,6 +5197,7 @@ impl Personalize for PersonalizeClient {
     ) -> Result<DescribeBatchInferenceJobResponse, RusotoError<DescribeBatchInferenceJobError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeBatchInferenceJob");
         request.add_header(
             "x-amz-target",
             "AmazonPersonalize.DescribeBatchInferenceJob",
@@ -4650,6 +5220,7 @@ impl Personalize for PersonalizeClient {
         input: DescribeCampaignRequest,
     ) -> Result<DescribeCampaignResponse, RusotoError<DescribeCampaignError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeCampaign");
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeCampaign");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4668,6 +5239,7 @@ impl Personalize for PersonalizeClient {
         input: DescribeDatasetRequest,
     ) -> Result<DescribeDatasetResponse, RusotoError<DescribeDatasetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeDataset");
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeDataset");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4686,6 +5258,7 @@ impl Personalize for PersonalizeClient {
         input: DescribeDatasetGroupRequest,
     ) -> Result<DescribeDatasetGroupResponse, RusotoError<DescribeDatasetGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeDatasetGroup");
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeDatasetGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4705,6 +5278,7 @@ impl Personalize for PersonalizeClient {
         input: DescribeDatasetImportJobRequest,
     ) -> Result<DescribeDatasetImportJobResponse, RusotoError<DescribeDatasetImportJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeDatasetImportJob");
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeDatasetImportJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4724,6 +5298,7 @@ impl Personalize for PersonalizeClient {
         input: DescribeEventTrackerRequest,
     ) -> Result<DescribeEventTrackerResponse, RusotoError<DescribeEventTrackerError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeEventTracker");
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeEventTracker");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4746,6 +5321,7 @@ impl Personalize for PersonalizeClient {
         RusotoError<DescribeFeatureTransformationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeFeatureTransformation");
         request.add_header(
             "x-amz-target",
             "AmazonPersonalize.DescribeFeatureTransformation",
@@ -4768,6 +5344,7 @@ impl Personalize for PersonalizeClient {
         input: DescribeFilterRequest,
     ) -> Result<DescribeFilterResponse, RusotoError<DescribeFilterError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeFilter");
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeFilter");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4786,6 +5363,7 @@ impl Personalize for PersonalizeClient {
         input: DescribeRecipeRequest,
     ) -> Result<DescribeRecipeResponse, RusotoError<DescribeRecipeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeRecipe");
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeRecipe");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4804,6 +5382,7 @@ impl Personalize for PersonalizeClient {
         input: DescribeSchemaRequest,
     ) -> Result<DescribeSchemaResponse, RusotoError<DescribeSchemaError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeSchema");
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeSchema");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4822,6 +5401,7 @@ impl Personalize for PersonalizeClient {
         input: DescribeSolutionRequest,
     ) -> Result<DescribeSolutionResponse, RusotoError<DescribeSolutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeSolution");
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeSolution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4840,6 +5420,7 @@ impl Personalize for PersonalizeClient {
         input: DescribeSolutionVersionRequest,
     ) -> Result<DescribeSolutionVersionResponse, RusotoError<DescribeSolutionVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeSolutionVersion");
         request.add_header("x-amz-target", "AmazonPersonalize.DescribeSolutionVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4859,6 +5440,7 @@ impl Personalize for PersonalizeClient {
         input: GetSolutionMetricsRequest,
     ) -> Result<GetSolutionMetricsResponse, RusotoError<GetSolutionMetricsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetSolutionMetrics");
         request.add_header("x-amz-target", "AmazonPersonalize.GetSolutionMetrics");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4877,6 +5459,7 @@ impl Personalize for PersonalizeClient {
         input: ListBatchInferenceJobsRequest,
     ) -> Result<ListBatchInferenceJobsResponse, RusotoError<ListBatchInferenceJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListBatchInferenceJobs");
         request.add_header("x-amz-target", "AmazonPersonalize.ListBatchInferenceJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4896,6 +5479,7 @@ impl Personalize for PersonalizeClient {
         input: ListCampaignsRequest,
     ) -> Result<ListCampaignsResponse, RusotoError<ListCampaignsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListCampaigns");
         request.add_header("x-amz-target", "AmazonPersonalize.ListCampaigns");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4914,6 +5498,7 @@ impl Personalize for PersonalizeClient {
         input: ListDatasetGroupsRequest,
     ) -> Result<ListDatasetGroupsResponse, RusotoError<ListDatasetGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListDatasetGroups");
         request.add_header("x-amz-target", "AmazonPersonalize.ListDatasetGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4932,6 +5517,7 @@ impl Personalize for PersonalizeClient {
         input: ListDatasetImportJobsRequest,
     ) -> Result<ListDatasetImportJobsResponse, RusotoError<ListDatasetImportJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListDatasetImportJobs");
         request.add_header("x-amz-target", "AmazonPersonalize.ListDatasetImportJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4951,6 +5537,7 @@ impl Personalize for PersonalizeClient {
         input: ListDatasetsRequest,
     ) -> Result<ListDatasetsResponse, RusotoError<ListDatasetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListDatasets");
         request.add_header("x-amz-target", "AmazonPersonalize.ListDatasets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4969,6 +5556,7 @@ impl Personalize for PersonalizeClient {
         input: ListEventTrackersRequest,
     ) -> Result<ListEventTrackersResponse, RusotoError<ListEventTrackersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListEventTrackers");
         request.add_header("x-amz-target", "AmazonPersonalize.ListEventTrackers");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4987,6 +5575,7 @@ impl Personalize for PersonalizeClient {
         input: ListFiltersRequest,
     ) -> Result<ListFiltersResponse, RusotoError<ListFiltersError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListFilters");
         request.add_header("x-amz-target", "AmazonPersonalize.ListFilters");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5005,6 +5594,7 @@ impl Personalize for PersonalizeClient {
         input: ListRecipesRequest,
     ) -> Result<ListRecipesResponse, RusotoError<ListRecipesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListRecipes");
         request.add_header("x-amz-target", "AmazonPersonalize.ListRecipes");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5023,6 +5613,7 @@ impl Personalize for PersonalizeClient {
         input: ListSchemasRequest,
     ) -> Result<ListSchemasResponse, RusotoError<ListSchemasError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListSchemas");
         request.add_header("x-amz-target", "AmazonPersonalize.ListSchemas");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5041,6 +5632,7 @@ impl Personalize for PersonalizeClient {
         input: ListSolutionVersionsRequest,
     ) -> Result<ListSolutionVersionsResponse, RusotoError<ListSolutionVersionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListSolutionVersions");
         request.add_header("x-amz-target", "AmazonPersonalize.ListSolutionVersions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5060,6 +5652,7 @@ impl Personalize for PersonalizeClient {
         input: ListSolutionsRequest,
     ) -> Result<ListSolutionsResponse, RusotoError<ListSolutionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListSolutions");
         request.add_header("x-amz-target", "AmazonPersonalize.ListSolutions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5078,6 +5671,7 @@ impl Personalize for PersonalizeClient {
         input: UpdateCampaignRequest,
     ) -> Result<UpdateCampaignResponse, RusotoError<UpdateCampaignError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateCampaign");
         request.add_header("x-amz-target", "AmazonPersonalize.UpdateCampaign");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -5090,3 +5684,445 @@ impl Personalize for PersonalizeClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateCampaignResponse, _>()
     }
 }
+
+/// A blocking client for the Amazon Personalize API, running the calls of a `PersonalizeClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `PersonalizeClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct PersonalizeBlockingClient {
+    client: PersonalizeClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl PersonalizeBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> PersonalizeBlockingClient {
+        PersonalizeBlockingClient::from_client(PersonalizeClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> PersonalizeBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        PersonalizeBlockingClient::from_client(PersonalizeClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> PersonalizeBlockingClient {
+        PersonalizeBlockingClient::from_client(PersonalizeClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> PersonalizeBlockingClient {
+        PersonalizeBlockingClient::from_client(PersonalizeClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: PersonalizeClient) -> PersonalizeBlockingClient {
+        PersonalizeBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> PersonalizeBlockingClient {
+        PersonalizeBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> PersonalizeBlockingClient {
+        PersonalizeBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Personalize::create_batch_inference_job`].
+    pub fn create_batch_inference_job(
+        &self,
+        input: CreateBatchInferenceJobRequest,
+    ) -> Result<CreateBatchInferenceJobResponse, RusotoError<CreateBatchInferenceJobError>> {
+        self.runtime
+            .block_on(self.client.create_batch_inference_job(input))
+    }
+
+    /// Blocking version of [`Personalize::create_campaign`].
+    pub fn create_campaign(
+        &self,
+        input: CreateCampaignRequest,
+    ) -> Result<CreateCampaignResponse, RusotoError<CreateCampaignError>> {
+        self.runtime.block_on(self.client.create_campaign(input))
+    }
+
+    /// Blocking version of [`Personalize::create_dataset`].
+    pub fn create_dataset(
+        &self,
+        input: CreateDatasetRequest,
+    ) -> Result<CreateDatasetResponse, RusotoError<CreateDatasetError>> {
+        self.runtime.block_on(self.client.create_dataset(input))
+    }
+
+    /// Blocking version of [`Personalize::create_dataset_group`].
+    pub fn create_dataset_group(
+        &self,
+        input: CreateDatasetGroupRequest,
+    ) -> Result<CreateDatasetGroupResponse, RusotoError<CreateDatasetGroupError>> {
+        self.runtime
+            .block_on(self.client.create_dataset_group(input))
+    }
+
+    /// Blocking version of [`Personalize::create_dataset_import_job`].
+    pub fn create_dataset_import_job(
+        &self,
+        input: CreateDatasetImportJobRequest,
+    ) -> Result<CreateDatasetImportJobResponse, RusotoError<CreateDatasetImportJobError>> {
+        self.runtime
+            .block_on(self.client.create_dataset_import_job(input))
+    }
+
+    /// Blocking version of [`Personalize::create_event_tracker`].
+    pub fn create_event_tracker(
+        &self,
+        input: CreateEventTrackerRequest,
+    ) -> Result<CreateEventTrackerResponse, RusotoError<CreateEventTrackerError>> {
+        self.runtime
+            .block_on(self.client.create_event_tracker(input))
+    }
+
+    /// Blocking version of [`Personalize::create_filter`].
+    pub fn create_filter(
+        &self,
+        input: CreateFilterRequest,
+    ) -> Result<CreateFilterResponse, RusotoError<CreateFilterError>> {
+        self.runtime.block_on(self.client.create_filter(input))
+    }
+
+    /// Blocking version of [`Personalize::create_schema`].
+    pub fn create_schema(
+        &self,
+        input: CreateSchemaRequest,
+    ) -> Result<CreateSchemaResponse, RusotoError<CreateSchemaError>> {
+        self.runtime.block_on(self.client.create_schema(input))
+    }
+
+    /// Blocking version of [`Personalize::create_solution`].
+    pub fn create_solution(
+        &self,
+        input: CreateSolutionRequest,
+    ) -> Result<CreateSolutionResponse, RusotoError<CreateSolutionError>> {
+        self.runtime.block_on(self.client.create_solution(input))
+    }
+
+    /// Blocking version of [`Personalize::create_solution_version`].
+    pub fn create_solution_version(
+        &self,
+        input: CreateSolutionVersionRequest,
+    ) -> Result<CreateSolutionVersionResponse, RusotoError<CreateSolutionVersionError>> {
+        self.runtime
+            .block_on(self.client.create_solution_version(input))
+    }
+
+    /// Blocking version of [`Personalize::delete_campaign`].
+    pub fn delete_campaign(
+        &self,
+        input: DeleteCampaignRequest,
+    ) -> Result<(), RusotoError<DeleteCampaignError>> {
+        self.runtime.block_on(self.client.delete_campaign(input))
+    }
+
+    /// Blocking version of [`Personalize::delete_dataset`].
+    pub fn delete_dataset(
+        &self,
+        input: DeleteDatasetRequest,
+    ) -> Result<(), RusotoError<DeleteDatasetError>> {
+        self.runtime.block_on(self.client.delete_dataset(input))
+    }
+
+    /// Blocking version of [`Personalize::delete_dataset_group`].
+    pub fn delete_dataset_group(
+        &self,
+        input: DeleteDatasetGroupRequest,
+    ) -> Result<(), RusotoError<DeleteDatasetGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_dataset_group(input))
+    }
+
+    /// Blocking version of [`Personalize::delete_event_tracker`].
+    pub fn delete_event_tracker(
+        &self,
+        input: DeleteEventTrackerRequest,
+    ) -> Result<(), RusotoError<DeleteEventTrackerError>> {
+        self.runtime
+            .block_on(self.client.delete_event_tracker(input))
+    }
+
+    /// Blocking version of [`Personalize::delete_filter`].
+    pub fn delete_filter(
+        &self,
+        input: DeleteFilterRequest,
+    ) -> Result<(), RusotoError<DeleteFilterError>> {
+        self.runtime.block_on(self.client.delete_filter(input))
+    }
+
+    /// Blocking version of [`Personalize::delete_schema`].
+    pub fn delete_schema(
+        &self,
+        input: DeleteSchemaRequest,
+    ) -> Result<(), RusotoError<DeleteSchemaError>> {
+        self.runtime.block_on(self.client.delete_schema(input))
+    }
+
+    /// Blocking version of [`Personalize::delete_solution`].
+    pub fn delete_solution(
+        &self,
+        input: DeleteSolutionRequest,
+    ) -> Result<(), RusotoError<DeleteSolutionError>> {
+        self.runtime.block_on(self.client.delete_solution(input))
+    }
+
+    /// Blocking version of [`Personalize::describe_algorithm`].
+    pub fn describe_algorithm(
+        &self,
+        input: DescribeAlgorithmRequest,
+    ) -> Result<DescribeAlgorithmResponse, RusotoError<DescribeAlgorithmError>> {
+        self.runtime.block_on(self.client.describe_algorithm(input))
+    }
+
+    /// Blocking version of [`Personalize::describe_batch_inference_job`].
+    pub fn describe_batch_inference_job(
+        &self,
+        input: DescribeBatchInferenceJobRequest,
+    ) -> Result<DescribeBatchInferenceJobResponse, RusotoError<DescribeBatchInferenceJobError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_batch_inference_job(input))
+    }
+
+    /// Blocking version of [`Personalize::describe_campaign`].
+    pub fn describe_campaign(
+        &self,
+        input: DescribeCampaignRequest,
+    ) -> Result<DescribeCampaignResponse, RusotoError<DescribeCampaignError>> {
+        self.runtime.block_on(self.client.describe_campaign(input))
+    }
+
+    /// Blocking version of [`Personalize::describe_dataset`].
+    pub fn describe_dataset(
+        &self,
+        input: DescribeDatasetRequest,
+    ) -> Result<DescribeDatasetResponse, RusotoError<DescribeDatasetError>> {
+        self.runtime.block_on(self.client.describe_dataset(input))
+    }
+
+    /// Blocking version of [`Personalize::describe_dataset_group`].
+    pub fn describe_dataset_group(
+        &self,
+        input: DescribeDatasetGroupRequest,
+    ) -> Result<DescribeDatasetGroupResponse, RusotoError<DescribeDatasetGroupError>> {
+        self.runtime
+            .block_on(self.client.describe_dataset_group(input))
+    }
+
+    /// Blocking version of [`Personalize::describe_dataset_import_job`].
+    pub fn describe_dataset_import_job(
+        &self,
+        input: DescribeDatasetImportJobRequest,
+    ) -> Result<DescribeDatasetImportJobResponse, RusotoError<DescribeDatasetImportJobError>> {
+        self.runtime
+            .block_on(self.client.describe_dataset_import_job(input))
+    }
+
+    /// Blocking version of [`Personalize::describe_event_tracker`].
+    pub fn describe_event_tracker(
+        &self,
+        input: DescribeEventTrackerRequest,
+    ) -> Result<DescribeEventTrackerResponse, RusotoError<DescribeEventTrackerError>> {
+        self.runtime
+            .block_on(self.client.describe_event_tracker(input))
+    }
+
+    /// Blocking version of [`Personalize::describe_feature_transformation`].
+    pub fn describe_feature_transformation(
+        &self,
+        input: DescribeFeatureTransformationRequest,
+    ) -> Result<
+        DescribeFeatureTransformationResponse,
+        RusotoError<DescribeFeatureTransformationError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_feature_transformation(input))
+    }
+
+    /// Blocking version of [`Personalize::describe_filter`].
+    pub fn describe_filter(
+        &self,
+        input: DescribeFilterRequest,
+    ) -> Result<DescribeFilterResponse, RusotoError<DescribeFilterError>> {
+        self.runtime.block_on(self.client.describe_filter(input))
+    }
+
+    /// Blocking version of [`Personalize::describe_recipe`].
+    pub fn describe_recipe(
+        &self,
+        input: DescribeRecipeRequest,
+    ) -> Result<DescribeRecipeResponse, RusotoError<DescribeRecipeError>> {
+        self.runtime.block_on(self.client.describe_recipe(input))
+    }
+
+    /// Blocking version of [`Personalize::describe_schema`].
+    pub fn describe_schema(
+        &self,
+        input: DescribeSchemaRequest,
+    ) -> Result<DescribeSchemaResponse, RusotoError<DescribeSchemaError>> {
+        self.runtime.block_on(self.client.describe_schema(input))
+    }
+
+    /// Blocking version of [`Personalize::describe_solution`].
+    pub fn describe_solution(
+        &self,
+        input: DescribeSolutionRequest,
+    ) -> Result<DescribeSolutionResponse, RusotoError<DescribeSolutionError>> {
+        self.runtime.block_on(self.client.describe_solution(input))
+    }
+
+    /// Blocking version of [`Personalize::describe_solution_version`].
+    pub fn describe_solution_version(
+        &self,
+        input: DescribeSolutionVersionRequest,
+    ) -> Result<DescribeSolutionVersionResponse, RusotoError<DescribeSolutionVersionError>> {
+        self.runtime
+            .block_on(self.client.describe_solution_version(input))
+    }
+
+    /// Blocking version of [`Personalize::get_solution_metrics`].
+    pub fn get_solution_metrics(
+        &self,
+        input: GetSolutionMetricsRequest,
+    ) -> Result<GetSolutionMetricsResponse, RusotoError<GetSolutionMetricsError>> {
+        self.runtime
+            .block_on(self.client.get_solution_metrics(input))
+    }
+
+    /// Blocking version of [`Personalize::list_batch_inference_jobs`].
+    pub fn list_batch_inference_jobs(
+        &self,
+        input: ListBatchInferenceJobsRequest,
+    ) -> Result<ListBatchInferenceJobsResponse, RusotoError<ListBatchInferenceJobsError>> {
+        self.runtime
+            .block_on(self.client.list_batch_inference_jobs(input))
+    }
+
+    /// Blocking version of [`Personalize::list_campaigns`].
+    pub fn list_campaigns(
+        &self,
+        input: ListCampaignsRequest,
+    ) -> Result<ListCampaignsResponse, RusotoError<ListCampaignsError>> {
+        self.runtime.block_on(self.client.list_campaigns(input))
+    }
+
+    /// Blocking version of [`Personalize::list_dataset_groups`].
+    pub fn list_dataset_groups(
+        &self,
+        input: ListDatasetGroupsRequest,
+    ) -> Result<ListDatasetGroupsResponse, RusotoError<ListDatasetGroupsError>> {
+        self.runtime
+            .block_on(self.client.list_dataset_groups(input))
+    }
+
+    /// Blocking version of [`Personalize::list_dataset_import_jobs`].
+    pub fn list_dataset_import_jobs(
+        &self,
+        input: ListDatasetImportJobsRequest,
+    ) -> Result<ListDatasetImportJobsResponse, RusotoError<ListDatasetImportJobsError>> {
+        self.runtime
+            .block_on(self.client.list_dataset_import_jobs(input))
+    }
+
+    /// Blocking version of [`Personalize::list_datasets`].
+    pub fn list_datasets(
+        &self,
+        input: ListDatasetsRequest,
+    ) -> Result<ListDatasetsResponse, RusotoError<ListDatasetsError>> {
+        self.runtime.block_on(self.client.list_datasets(input))
+    }
+
+    /// Blocking version of [`Personalize::list_event_trackers`].
+    pub fn list_event_trackers(
+        &self,
+        input: ListEventTrackersRequest,
+    ) -> Result<ListEventTrackersResponse, RusotoError<ListEventTrackersError>> {
+        self.runtime
+            .block_on(self.client.list_event_trackers(input))
+    }
+
+    /// Blocking version of [`Personalize::list_filters`].
+    pub fn list_filters(
+        &self,
+        input: ListFiltersRequest,
+    ) -> Result<ListFiltersResponse, RusotoError<ListFiltersError>> {
+        self.runtime.block_on(self.client.list_filters(input))
+    }
+
+    /// Blocking version of [`Personalize::list_recipes`].
+    pub fn list_recipes(
+        &self,
+        input: ListRecipesRequest,
+    ) -> Result<ListRecipesResponse, RusotoError<ListRecipesError>> {
+        self.runtime.block_on(self.client.list_recipes(input))
+    }
+
+    /// Blocking version of [`Personalize::list_schemas`].
+    pub fn list_schemas(
+        &self,
+        input: ListSchemasRequest,
+    ) -> Result<ListSchemasResponse, RusotoError<ListSchemasError>> {
+        self.runtime.block_on(self.client.list_schemas(input))
+    }
+
+    /// Blocking version of [`Personalize::list_solution_versions`].
+    pub fn list_solution_versions(
+        &self,
+        input: ListSolutionVersionsRequest,
+    ) -> Result<ListSolutionVersionsResponse, RusotoError<ListSolutionVersionsError>> {
+        self.runtime
+            .block_on(self.client.list_solution_versions(input))
+    }
+
+    /// Blocking version of [`Personalize::list_solutions`].
+    pub fn list_solutions(
+        &self,
+        input: ListSolutionsRequest,
+    ) -> Result<ListSolutionsResponse, RusotoError<ListSolutionsError>> {
+        self.runtime.block_on(self.client.list_solutions(input))
+    }
+
+    /// Blocking version of [`Personalize::update_campaign`].
+    pub fn update_campaign(
+        &self,
+        input: UpdateCampaignRequest,
+    ) -> Result<UpdateCampaignResponse, RusotoError<UpdateCampaignError>> {
+        self.runtime.block_on(self.client.update_campaign(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -291,19 +292,30 @@ impl DescribeDimensionKeysError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(DescribeDimensionKeysError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDimensionKeysError::InternalServiceError,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(DescribeDimensionKeysError::InvalidArgument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDimensionKeysError::InvalidArgument,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(DescribeDimensionKeysError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDimensionKeysError::NotAuthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -337,17 +349,30 @@ impl GetResourceMetricsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceError" => {
-                    return RusotoError::Service(GetResourceMetricsError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetResourceMetricsError::InternalServiceError,
+                    )
                 }
                 "InvalidArgumentException" => {
-                    return RusotoError::Service(GetResourceMetricsError::InvalidArgument(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourceMetricsError::InvalidArgument,
+                    )
                 }
                 "NotAuthorizedException" => {
-                    return RusotoError::Service(GetResourceMetricsError::NotAuthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourceMetricsError::NotAuthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -390,11 +415,12 @@ pub struct PerformanceInsightsClient {
 impl PerformanceInsightsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> PerformanceInsightsClient {
         PerformanceInsightsClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "PI"),
         }
     }
 
@@ -409,12 +435,32 @@ impl PerformanceInsightsClient {
     {
         PerformanceInsightsClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "PI"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> PerformanceInsightsClient {
-        PerformanceInsightsClient { client, region }
+        PerformanceInsightsClient {
+            client,
+            region: region_for_service(region, "PI"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> PerformanceInsightsClient {
+        PerformanceInsightsClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("PI"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> PerformanceInsightsClient {
+        PerformanceInsightsClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -426,6 +472,7 @@ impl PerformanceInsights for PerformanceInsightsClient {
         input: DescribeDimensionKeysRequest,
     ) -> Result<DescribeDimensionKeysResponse, RusotoError<DescribeDimensionKeysError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeDimensionKeys");
         request.add_header(
             "x-amz-target",
             "PerformanceInsightsv20180227.DescribeDimensionKeys",
@@ -448,6 +495,7 @@ impl PerformanceInsights for PerformanceInsightsClient {
         input: GetResourceMetricsRequest,
     ) -> Result<GetResourceMetricsResponse, RusotoError<GetResourceMetricsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetResourceMetrics");
         request.add_header(
             "x-amz-target",
             "PerformanceInsightsv20180227.GetResourceMetrics",
@@ -463,3 +511,106 @@ impl PerformanceInsights for PerformanceInsightsClient {
         proto::json::ResponsePayload::new(&response).deserialize::<GetResourceMetricsResponse, _>()
     }
 }
+
+/// A blocking client for the AWS PI API, running the calls of a `PerformanceInsightsClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `PerformanceInsightsClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct PerformanceInsightsBlockingClient {
+    client: PerformanceInsightsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl PerformanceInsightsBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> PerformanceInsightsBlockingClient {
+        PerformanceInsightsBlockingClient::from_client(PerformanceInsightsClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> PerformanceInsightsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        PerformanceInsightsBlockingClient::from_client(PerformanceInsightsClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> PerformanceInsightsBlockingClient {
+        PerformanceInsightsBlockingClient::from_client(PerformanceInsightsClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> PerformanceInsightsBlockingClient {
+        PerformanceInsightsBlockingClient::from_client(PerformanceInsightsClient::new_with_config(
+            config,
+        ))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: PerformanceInsightsClient) -> PerformanceInsightsBlockingClient {
+        PerformanceInsightsBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> PerformanceInsightsBlockingClient {
+        PerformanceInsightsBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> PerformanceInsightsBlockingClient {
+        PerformanceInsightsBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`PerformanceInsights::describe_dimension_keys`].
+    pub fn describe_dimension_keys(
+        &self,
+        input: DescribeDimensionKeysRequest,
+    ) -> Result<DescribeDimensionKeysResponse, RusotoError<DescribeDimensionKeysError>> {
+        self.runtime
+            .block_on(self.client.describe_dimension_keys(input))
+    }
+
+    /// Blocking version of [`PerformanceInsights::get_resource_metrics`].
+    pub fn get_resource_metrics(
+        &self,
+        input: GetResourceMetricsRequest,
+    ) -> Result<GetResourceMetricsResponse, RusotoError<GetResourceMetricsError>> {
+        self.runtime
+            .block_on(self.client.get_resource_metrics(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1648,32 +1649,54 @@ impl CreateConfigurationSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateConfigurationSetError::AlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateConfigurationSetError::AlreadyExists,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateConfigurationSetError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConfigurationSetError::BadRequest,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        CreateConfigurationSetError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConfigurationSetError::ConcurrentModification,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateConfigurationSetError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateConfigurationSetError::LimitExceeded,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateConfigurationSetError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConfigurationSetError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateConfigurationSetError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateConfigurationSetError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1718,31 +1741,46 @@ impl CreateConfigurationSetEventDestinationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(
-                        CreateConfigurationSetEventDestinationError::AlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConfigurationSetEventDestinationError::AlreadyExists,
                     )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        CreateConfigurationSetEventDestinationError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConfigurationSetEventDestinationError::BadRequest,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
-                        CreateConfigurationSetEventDestinationError::LimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConfigurationSetEventDestinationError::LimitExceeded,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
-                        CreateConfigurationSetEventDestinationError::NotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConfigurationSetEventDestinationError::NotFound,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        CreateConfigurationSetEventDestinationError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateConfigurationSetEventDestinationError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1792,25 +1830,46 @@ impl CreateDedicatedIpPoolError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(CreateDedicatedIpPoolError::AlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDedicatedIpPoolError::AlreadyExists,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDedicatedIpPoolError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDedicatedIpPoolError::BadRequest,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        CreateDedicatedIpPoolError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDedicatedIpPoolError::ConcurrentModification,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDedicatedIpPoolError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDedicatedIpPoolError::LimitExceeded,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateDedicatedIpPoolError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDedicatedIpPoolError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1860,51 +1919,78 @@ impl CreateDeliverabilityTestReportError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccountSuspendedException" => {
-                    return RusotoError::Service(
-                        CreateDeliverabilityTestReportError::AccountSuspended(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeliverabilityTestReportError::AccountSuspended,
                     )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateDeliverabilityTestReportError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDeliverabilityTestReportError::BadRequest,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        CreateDeliverabilityTestReportError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeliverabilityTestReportError::ConcurrentModification,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
-                        CreateDeliverabilityTestReportError::LimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeliverabilityTestReportError::LimitExceeded,
                     )
                 }
                 "MailFromDomainNotVerifiedException" => {
-                    return RusotoError::Service(
-                        CreateDeliverabilityTestReportError::MailFromDomainNotVerified(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeliverabilityTestReportError::MailFromDomainNotVerified,
                     )
                 }
                 "MessageRejected" => {
-                    return RusotoError::Service(
-                        CreateDeliverabilityTestReportError::MessageRejected(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeliverabilityTestReportError::MessageRejected,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CreateDeliverabilityTestReportError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDeliverabilityTestReportError::NotFound,
+                    )
                 }
                 "SendingPausedException" => {
-                    return RusotoError::Service(
-                        CreateDeliverabilityTestReportError::SendingPaused(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeliverabilityTestReportError::SendingPaused,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        CreateDeliverabilityTestReportError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeliverabilityTestReportError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1956,20 +2042,38 @@ impl CreateEmailIdentityError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateEmailIdentityError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEmailIdentityError::BadRequest,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(CreateEmailIdentityError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateEmailIdentityError::ConcurrentModification,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateEmailIdentityError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEmailIdentityError::LimitExceeded,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateEmailIdentityError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEmailIdentityError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2006,22 +2110,38 @@ impl DeleteConfigurationSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteConfigurationSetError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteConfigurationSetError::BadRequest,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        DeleteConfigurationSetError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteConfigurationSetError::ConcurrentModification,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteConfigurationSetError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteConfigurationSetError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteConfigurationSetError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteConfigurationSetError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2060,21 +2180,30 @@ impl DeleteConfigurationSetEventDestinationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        DeleteConfigurationSetEventDestinationError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteConfigurationSetEventDestinationError::BadRequest,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteConfigurationSetEventDestinationError::NotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteConfigurationSetEventDestinationError::NotFound,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        DeleteConfigurationSetEventDestinationError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteConfigurationSetEventDestinationError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2116,22 +2245,38 @@ impl DeleteDedicatedIpPoolError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteDedicatedIpPoolError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDedicatedIpPoolError::BadRequest,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(
-                        DeleteDedicatedIpPoolError::ConcurrentModification(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDedicatedIpPoolError::ConcurrentModification,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteDedicatedIpPoolError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDedicatedIpPoolError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteDedicatedIpPoolError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDedicatedIpPoolError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2168,20 +2313,38 @@ impl DeleteEmailIdentityError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteEmailIdentityError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEmailIdentityError::BadRequest,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(DeleteEmailIdentityError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteEmailIdentityError::ConcurrentModification,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteEmailIdentityError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEmailIdentityError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteEmailIdentityError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteEmailIdentityError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2214,12 +2377,22 @@ impl GetAccountError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetAccountError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAccountError::BadRequest,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetAccountError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAccountError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2252,15 +2425,30 @@ impl GetBlacklistReportsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetBlacklistReportsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBlacklistReportsError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetBlacklistReportsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBlacklistReportsError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetBlacklistReportsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBlacklistReportsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2294,15 +2482,30 @@ impl GetConfigurationSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetConfigurationSetError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConfigurationSetError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetConfigurationSetError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConfigurationSetError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetConfigurationSetError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConfigurationSetError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2338,21 +2541,30 @@ impl GetConfigurationSetEventDestinationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        GetConfigurationSetEventDestinationsError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConfigurationSetEventDestinationsError::BadRequest,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
-                        GetConfigurationSetEventDestinationsError::NotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConfigurationSetEventDestinationsError::NotFound,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        GetConfigurationSetEventDestinationsError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetConfigurationSetEventDestinationsError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2392,15 +2604,30 @@ impl GetDedicatedIpError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDedicatedIpError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDedicatedIpError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDedicatedIpError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDedicatedIpError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDedicatedIpError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDedicatedIpError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2434,15 +2661,30 @@ impl GetDedicatedIpsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDedicatedIpsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDedicatedIpsError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDedicatedIpsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDedicatedIpsError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDedicatedIpsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDedicatedIpsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2478,21 +2720,30 @@ impl GetDeliverabilityDashboardOptionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        GetDeliverabilityDashboardOptionsError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeliverabilityDashboardOptionsError::BadRequest,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
-                        GetDeliverabilityDashboardOptionsError::LimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeliverabilityDashboardOptionsError::LimitExceeded,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        GetDeliverabilityDashboardOptionsError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDeliverabilityDashboardOptionsError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2532,21 +2783,30 @@ impl GetDeliverabilityTestReportError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDeliverabilityTestReportError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDeliverabilityTestReportError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDeliverabilityTestReportError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDeliverabilityTestReportError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDeliverabilityTestReportError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDeliverabilityTestReportError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2582,21 +2842,30 @@ impl GetDomainDeliverabilityCampaignError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDomainDeliverabilityCampaignError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDomainDeliverabilityCampaignError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDomainDeliverabilityCampaignError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDomainDeliverabilityCampaignError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        GetDomainDeliverabilityCampaignError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDomainDeliverabilityCampaignError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2632,19 +2901,30 @@ impl GetDomainStatisticsReportError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetDomainStatisticsReportError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDomainStatisticsReportError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetDomainStatisticsReportError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDomainStatisticsReportError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetDomainStatisticsReportError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDomainStatisticsReportError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2678,15 +2958,30 @@ impl GetEmailIdentityError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetEmailIdentityError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEmailIdentityError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetEmailIdentityError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEmailIdentityError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetEmailIdentityError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetEmailIdentityError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2718,14 +3013,22 @@ impl ListConfigurationSetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListConfigurationSetsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListConfigurationSetsError::BadRequest,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListConfigurationSetsError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListConfigurationSetsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2756,14 +3059,22 @@ impl ListDedicatedIpPoolsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListDedicatedIpPoolsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDedicatedIpPoolsError::BadRequest,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListDedicatedIpPoolsError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDedicatedIpPoolsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2798,21 +3109,30 @@ impl ListDeliverabilityTestReportsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListDeliverabilityTestReportsError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDeliverabilityTestReportsError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListDeliverabilityTestReportsError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDeliverabilityTestReportsError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        ListDeliverabilityTestReportsError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDeliverabilityTestReportsError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2850,21 +3170,30 @@ impl ListDomainDeliverabilityCampaignsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        ListDomainDeliverabilityCampaignsError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDomainDeliverabilityCampaignsError::BadRequest,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListDomainDeliverabilityCampaignsError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDomainDeliverabilityCampaignsError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        ListDomainDeliverabilityCampaignsError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDomainDeliverabilityCampaignsError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2898,12 +3227,22 @@ impl ListEmailIdentitiesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListEmailIdentitiesError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListEmailIdentitiesError::BadRequest,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListEmailIdentitiesError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListEmailIdentitiesError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2936,15 +3275,30 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListTagsForResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2978,16 +3332,22 @@ impl PutAccountDedicatedIpWarmupAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        PutAccountDedicatedIpWarmupAttributesError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAccountDedicatedIpWarmupAttributesError::BadRequest,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        PutAccountDedicatedIpWarmupAttributesError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutAccountDedicatedIpWarmupAttributesError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3024,16 +3384,22 @@ impl PutAccountSendingAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutAccountSendingAttributesError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutAccountSendingAttributesError::BadRequest,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PutAccountSendingAttributesError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutAccountSendingAttributesError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3068,21 +3434,30 @@ impl PutConfigurationSetDeliveryOptionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        PutConfigurationSetDeliveryOptionsError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutConfigurationSetDeliveryOptionsError::BadRequest,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutConfigurationSetDeliveryOptionsError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutConfigurationSetDeliveryOptionsError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        PutConfigurationSetDeliveryOptionsError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutConfigurationSetDeliveryOptionsError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3122,21 +3497,30 @@ impl PutConfigurationSetReputationOptionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        PutConfigurationSetReputationOptionsError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutConfigurationSetReputationOptionsError::BadRequest,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
-                        PutConfigurationSetReputationOptionsError::NotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutConfigurationSetReputationOptionsError::NotFound,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        PutConfigurationSetReputationOptionsError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutConfigurationSetReputationOptionsError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3178,21 +3562,30 @@ impl PutConfigurationSetSendingOptionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        PutConfigurationSetSendingOptionsError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutConfigurationSetSendingOptionsError::BadRequest,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutConfigurationSetSendingOptionsError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutConfigurationSetSendingOptionsError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        PutConfigurationSetSendingOptionsError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutConfigurationSetSendingOptionsError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3230,21 +3623,30 @@ impl PutConfigurationSetTrackingOptionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        PutConfigurationSetTrackingOptionsError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutConfigurationSetTrackingOptionsError::BadRequest,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutConfigurationSetTrackingOptionsError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutConfigurationSetTrackingOptionsError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        PutConfigurationSetTrackingOptionsError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutConfigurationSetTrackingOptionsError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3282,17 +3684,30 @@ impl PutDedicatedIpInPoolError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutDedicatedIpInPoolError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutDedicatedIpInPoolError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutDedicatedIpInPoolError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutDedicatedIpInPoolError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(PutDedicatedIpInPoolError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutDedicatedIpInPoolError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3328,21 +3743,30 @@ impl PutDedicatedIpWarmupAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutDedicatedIpWarmupAttributesError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutDedicatedIpWarmupAttributesError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutDedicatedIpWarmupAttributesError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutDedicatedIpWarmupAttributesError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        PutDedicatedIpWarmupAttributesError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutDedicatedIpWarmupAttributesError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3384,31 +3808,46 @@ impl PutDeliverabilityDashboardOptionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AlreadyExistsException" => {
-                    return RusotoError::Service(
-                        PutDeliverabilityDashboardOptionError::AlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutDeliverabilityDashboardOptionError::AlreadyExists,
                     )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(PutDeliverabilityDashboardOptionError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutDeliverabilityDashboardOptionError::BadRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
-                        PutDeliverabilityDashboardOptionError::LimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutDeliverabilityDashboardOptionError::LimitExceeded,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutDeliverabilityDashboardOptionError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutDeliverabilityDashboardOptionError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        PutDeliverabilityDashboardOptionError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutDeliverabilityDashboardOptionError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3452,21 +3891,30 @@ impl PutEmailIdentityDkimAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(PutEmailIdentityDkimAttributesError::BadRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutEmailIdentityDkimAttributesError::BadRequest,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutEmailIdentityDkimAttributesError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutEmailIdentityDkimAttributesError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        PutEmailIdentityDkimAttributesError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutEmailIdentityDkimAttributesError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3504,21 +3952,30 @@ impl PutEmailIdentityFeedbackAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        PutEmailIdentityFeedbackAttributesError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutEmailIdentityFeedbackAttributesError::BadRequest,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutEmailIdentityFeedbackAttributesError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutEmailIdentityFeedbackAttributesError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        PutEmailIdentityFeedbackAttributesError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutEmailIdentityFeedbackAttributesError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3558,21 +4015,30 @@ impl PutEmailIdentityMailFromAttributesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        PutEmailIdentityMailFromAttributesError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutEmailIdentityMailFromAttributesError::BadRequest,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(PutEmailIdentityMailFromAttributesError::NotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutEmailIdentityMailFromAttributesError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        PutEmailIdentityMailFromAttributesError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutEmailIdentityMailFromAttributesError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3620,30 +4086,70 @@ impl SendEmailError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccountSuspendedException" => {
-                    return RusotoError::Service(SendEmailError::AccountSuspended(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendEmailError::AccountSuspended,
+                    )
                 }
                 "BadRequestException" => {
-                    return RusotoError::Service(SendEmailError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendEmailError::BadRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(SendEmailError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendEmailError::LimitExceeded,
+                    )
                 }
                 "MailFromDomainNotVerifiedException" => {
-                    return RusotoError::Service(SendEmailError::MailFromDomainNotVerified(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendEmailError::MailFromDomainNotVerified,
+                    )
                 }
                 "MessageRejected" => {
-                    return RusotoError::Service(SendEmailError::MessageRejected(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendEmailError::MessageRejected,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(SendEmailError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendEmailError::NotFound,
+                    )
                 }
                 "SendingPausedException" => {
-                    return RusotoError::Service(SendEmailError::SendingPaused(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendEmailError::SendingPaused,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(SendEmailError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendEmailError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3684,18 +4190,38 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::BadRequest,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(TagResourceError::ConcurrentModification(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ConcurrentModification,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(TagResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3732,20 +4258,38 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagResourceError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::BadRequest,
+                    )
                 }
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(UntagResourceError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UntagResourceError::ConcurrentModification,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UntagResourceError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3782,21 +4326,30 @@ impl UpdateConfigurationSetEventDestinationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(
-                        UpdateConfigurationSetEventDestinationError::BadRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateConfigurationSetEventDestinationError::BadRequest,
                     )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateConfigurationSetEventDestinationError::NotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateConfigurationSetEventDestinationError::NotFound,
                     )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(
-                        UpdateConfigurationSetEventDestinationError::TooManyRequests(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateConfigurationSetEventDestinationError::TooManyRequests,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4138,11 +4691,12 @@ pub struct PinpointEmailClient {
 impl PinpointEmailClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> PinpointEmailClient {
         PinpointEmailClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Pinpoint Email"),
         }
     }
 
@@ -4157,12 +4711,32 @@ impl PinpointEmailClient {
     {
         PinpointEmailClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Pinpoint Email"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> PinpointEmailClient {
-        PinpointEmailClient { client, region }
+        PinpointEmailClient {
+            client,
+            region: region_for_service(region, "Pinpoint Email"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> PinpointEmailClient {
+        PinpointEmailClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Pinpoint Email"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> PinpointEmailClient {
+        PinpointEmailClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -4177,6 +4751,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/configuration-sets";
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation("CreateConfigurationSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4215,6 +4790,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation("CreateConfigurationSetEventDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4249,6 +4825,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/dedicated-ip-pools";
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation("CreateDedicatedIpPool");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4284,6 +4861,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/deliverability-dashboard/test";
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation("CreateDeliverabilityTestReport");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4316,6 +4894,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/identities";
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation("CreateEmailIdentity");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4351,6 +4930,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "ses", &self.region, &request_uri);
+        request.set_operation("DeleteConfigurationSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4384,6 +4964,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = format!("/v1/email/configuration-sets/{configuration_set_name}/event-destinations/{event_destination_name}", configuration_set_name = input.configuration_set_name, event_destination_name = input.event_destination_name);
 
         let mut request = SignedRequest::new("DELETE", "ses", &self.region, &request_uri);
+        request.set_operation("DeleteConfigurationSetEventDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4419,6 +5000,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "ses", &self.region, &request_uri);
+        request.set_operation("DeleteDedicatedIpPool");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4452,6 +5034,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "ses", &self.region, &request_uri);
+        request.set_operation("DeleteEmailIdentity");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4479,6 +5062,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/account";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetAccount");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4509,6 +5093,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/deliverability-dashboard/blacklist-report";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetBlacklistReports");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4548,6 +5133,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetConfigurationSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4584,6 +5170,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetConfigurationSetEventDestinations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4616,6 +5203,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = format!("/v1/email/dedicated-ips/{ip}", ip = input.ip);
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetDedicatedIp");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4646,6 +5234,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/dedicated-ips";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetDedicatedIps");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4690,6 +5279,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/deliverability-dashboard";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetDeliverabilityDashboardOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4726,6 +5316,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetDeliverabilityTestReport");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4762,6 +5353,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetDomainDeliverabilityCampaign");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4798,6 +5390,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetDomainStatisticsReport");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4836,6 +5429,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("GetEmailIdentity");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4866,6 +5460,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/configuration-sets";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("ListConfigurationSets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4905,6 +5500,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/dedicated-ip-pools";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("ListDedicatedIpPools");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4947,6 +5543,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/deliverability-dashboard/test-reports";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("ListDeliverabilityTestReports");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -4992,6 +5589,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("ListDomainDeliverabilityCampaigns");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5035,6 +5633,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/identities";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("ListEmailIdentities");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5074,6 +5673,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/tags";
 
         let mut request = SignedRequest::new("GET", "ses", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5111,6 +5711,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/account/dedicated-ips/warmup";
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutAccountDedicatedIpWarmupAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5146,6 +5747,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/account/sending";
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutAccountSendingAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5184,6 +5786,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutConfigurationSetDeliveryOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5224,6 +5827,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutConfigurationSetReputationOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5264,6 +5868,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutConfigurationSetSendingOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5304,6 +5909,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutConfigurationSetTrackingOptions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5338,6 +5944,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = format!("/v1/email/dedicated-ips/{ip}/pool", ip = input.ip);
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutDedicatedIpInPool");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5373,6 +5980,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = format!("/v1/email/dedicated-ips/{ip}/warmup", ip = input.ip);
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutDedicatedIpWarmupAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5408,6 +6016,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/deliverability-dashboard";
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutDeliverabilityDashboardOption");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5448,6 +6057,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutEmailIdentityDkimAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5486,6 +6096,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutEmailIdentityFeedbackAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5526,6 +6137,7 @@ impl PinpointEmail for PinpointEmailClient {
         );
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("PutEmailIdentityMailFromAttributes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5560,6 +6172,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/outbound-emails";
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation("SendEmail");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5592,6 +6205,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/tags";
 
         let mut request = SignedRequest::new("POST", "ses", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5624,6 +6238,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = "/v1/email/tags";
 
         let mut request = SignedRequest::new("DELETE", "ses", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5664,6 +6279,7 @@ impl PinpointEmail for PinpointEmailClient {
         let request_uri = format!("/v1/email/configuration-sets/{configuration_set_name}/event-destinations/{event_destination_name}", configuration_set_name = input.configuration_set_name, event_destination_name = input.event_destination_name);
 
         let mut request = SignedRequest::new("PUT", "ses", &self.region, &request_uri);
+        request.set_operation("UpdateConfigurationSetEventDestination");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("email".to_string());
@@ -5689,3 +6305,518 @@ impl PinpointEmail for PinpointEmailClient {
         }
     }
 }
+
+/// A blocking client for the Pinpoint Email API, running the calls of a `PinpointEmailClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `PinpointEmailClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct PinpointEmailBlockingClient {
+    client: PinpointEmailClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl PinpointEmailBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> PinpointEmailBlockingClient {
+        PinpointEmailBlockingClient::from_client(PinpointEmailClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> PinpointEmailBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        PinpointEmailBlockingClient::from_client(PinpointEmailClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> PinpointEmailBlockingClient {
+        PinpointEmailBlockingClient::from_client(PinpointEmailClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> PinpointEmailBlockingClient {
+        PinpointEmailBlockingClient::from_client(PinpointEmailClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: PinpointEmailClient) -> PinpointEmailBlockingClient {
+        PinpointEmailBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> PinpointEmailBlockingClient {
+        PinpointEmailBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> PinpointEmailBlockingClient {
+        PinpointEmailBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`PinpointEmail::create_configuration_set`].
+    pub fn create_configuration_set(
+        &self,
+        input: CreateConfigurationSetRequest,
+    ) -> Result<CreateConfigurationSetResponse, RusotoError<CreateConfigurationSetError>> {
+        self.runtime
+            .block_on(self.client.create_configuration_set(input))
+    }
+
+    /// Blocking version of [`PinpointEmail::create_configuration_set_event_destination`].
+    pub fn create_configuration_set_event_destination(
+        &self,
+        input: CreateConfigurationSetEventDestinationRequest,
+    ) -> Result<
+        CreateConfigurationSetEventDestinationResponse,
+        RusotoError<CreateConfigurationSetEventDestinationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .create_configuration_set_event_destination(input),
+        )
+    }
+
+    /// Blocking version of [`PinpointEmail::create_dedicated_ip_pool`].
+    pub fn create_dedicated_ip_pool(
+        &self,
+        input: CreateDedicatedIpPoolRequest,
+    ) -> Result<CreateDedicatedIpPoolResponse, RusotoError<CreateDedicatedIpPoolError>> {
+        self.runtime
+            .block_on(self.client.create_dedicated_ip_pool(input))
+    }
+
+    /// Blocking version of [`PinpointEmail::create_deliverability_test_report`].
+    pub fn create_deliverability_test_report(
+        &self,
+        input: CreateDeliverabilityTestReportRequest,
+    ) -> Result<
+        CreateDeliverabilityTestReportResponse,
+        RusotoError<CreateDeliverabilityTestReportError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_deliverability_test_report(input))
+    }
+
+    /// Blocking version of [`PinpointEmail::create_email_identity`].
+    pub fn create_email_identity(
+        &self,
+        input: CreateEmailIdentityRequest,
+    ) -> Result<CreateEmailIdentityResponse, RusotoError<CreateEmailIdentityError>> {
+        self.runtime
+            .block_on(self.client.create_email_identity(input))
+    }
+
+    /// Blocking version of [`PinpointEmail::delete_configuration_set`].
+    pub fn delete_configuration_set(
+        &self,
+        input: DeleteConfigurationSetRequest,
+    ) -> Result<DeleteConfigurationSetResponse, RusotoError<DeleteConfigurationSetError>> {
+        self.runtime
+            .block_on(self.client.delete_configuration_set(input))
+    }
+
+    /// Blocking version of [`PinpointEmail::delete_configuration_set_event_destination`].
+    pub fn delete_configuration_set_event_destination(
+        &self,
+        input: DeleteConfigurationSetEventDestinationRequest,
+    ) -> Result<
+        DeleteConfigurationSetEventDestinationResponse,
+        RusotoError<DeleteConfigurationSetEventDestinationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .delete_configuration_set_event_destination(input),
+        )
+    }
+
+    /// Blocking version of [`PinpointEmail::delete_dedicated_ip_pool`].
+    pub fn delete_dedicated_ip_pool(
+        &self,
+        input: DeleteDedicatedIpPoolRequest,
+    ) -> Result<DeleteDedicatedIpPoolResponse, RusotoError<DeleteDedicatedIpPoolError>> {
+        self.runtime
+            .block_on(self.client.delete_dedicated_ip_pool(input))
+    }
+
+    /// Blocking version of [`PinpointEmail::delete_email_identity`].
+    pub fn delete_email_identity(
+        &self,
+        input: DeleteEmailIdentityRequest,
+    ) -> Result<DeleteEmailIdentityResponse, RusotoError<DeleteEmailIdentityError>> {
+        self.runtime
+            .block_on(self.client.delete_email_identity(input))
+    }
+
+    /// Blocking version of [`PinpointEmail::get_account`].
+    pub fn get_account(&self) -> Result<GetAccountResponse, RusotoError<GetAccountError>> {
+        self.runtime.block_on(self.client.get_account())
+    }
+
+    /// Blocking version of [`PinpointEmail::get_blacklist_reports`].
+    pub fn get_blacklist_reports(
+        &self,
+        input: GetBlacklistReportsRequest,
+    ) -> Result<GetBlacklistReportsResponse, RusotoError<GetBlacklistReportsError>> {
+        self.runtime
+            .block_on(self.client.get_blacklist_reports(input))
+    }
+
+    /// Blocking version of [`PinpointEmail::get_configuration_set`].
+    pub fn get_configuration_set(
+        &self,
+        input: GetConfigurationSetRequest,
+    ) -> Result<GetConfigurationSetResponse, RusotoError<GetConfigurationSetError>> {
+        self.runtime
+            .block_on(self.client.get_configuration_set(input))
+    }
+
+    /// Blocking version of [`PinpointEmail::get_configuration_set_event_destinations`].
+    pub fn get_configuration_set_event_destinations(
+        &self,
+        input: GetConfigurationSetEventDestinationsRequest,
+    ) -> Result<
+        GetConfigurationSetEventDestinationsResponse,
+        RusotoError<GetConfigurationSetEventDestinationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_configuration_set_event_destinations(input))
+    }
+
+    /// Blocking version of [`PinpointEmail::get_dedicated_ip`].
+    pub fn get_dedicated_ip(
+        &self,
+        input: GetDedicatedIpRequest,
+    ) -> Result<GetDedicatedIpResponse, RusotoError<GetDedicatedIpError>> {
+        self.runtime.block_on(self.client.get_dedicated_ip(input))
+    }
+
+    /// Blocking version of [`PinpointEmail::get_dedicated_ips`].
+    pub fn get_dedicated_ips(
+        &self,
+        input: GetDedicatedIpsRequest,
+    ) -> Result<GetDedicatedIpsResponse, RusotoError<GetDedicatedIpsError>> {
+        self.runtime.block_on(self.client.get_dedicated_ips(input))
+    }
+
+    /// Blocking version of [`PinpointEmail::get_deliverability_dashboard_options`].
+    pub fn get_deliverability_dashboard_options(
+        &self,
+    ) -> Result<
+        GetDeliverabilityDashboardOptionsResponse,
+        RusotoError<GetDeliverabilityDashboardOptionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_deliverability_dashboard_options())
+    }
+
+    /// Blocking version of [`PinpointEmail::get_deliverability_test_report`].
+    pub fn get_deliverability_test_report(
+        &self,
+        input: GetDeliverabilityTestReportRequest,
+    ) -> Result<GetDeliverabilityTestReportResponse, RusotoError<GetDeliverabilityTestReportError>>
+    {
+        self.runtime
+            .block_on(self.client.get_deliverability_test_report(input))
+    }
+
+    /// Blocking version of [`PinpointEmail::get_domain_deliverability_campaign`].
+    pub fn get_domain_deliverability_campaign(
+        &self,
+        input: GetDomainDeliverabilityCampaignRequest,
+    ) -> Result<
+        GetDomainDeliverabilityCampaignResponse,
+        RusotoError<GetDomainDeliverabilityCampaignError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_domain_deliverability_campaign(input))
+    }
+
+    /// Blocking version of [`PinpointEmail::get_domain_statistics_report`].
+    pub fn get_domain_statistics_report(
+        &self,
+        input: GetDomainStatisticsReportRequest,
+    ) -> Result<GetDomainStatisticsReportResponse, RusotoError<GetDomainStatisticsReportError>>
+    {
+        self.runtime
+            .block_on(self.client.get_domain_statistics_report(input))
+    }
+
+    /// Blocking version of [`PinpointEmail::get_email_identity`].
+    pub fn get_email_identity(
+        &self,
+        input: GetEmailIdentityRequest,
+    ) -> Result<GetEmailIdentityResponse, RusotoError<GetEmailIdentityError>> {
+        self.runtime.block_on(self.client.get_email_identity(input))
+    }
+
+    /// Blocking version of [`PinpointEmail::list_configuration_sets`].
+    pub fn list_configuration_sets(
+        &self,
+        input: ListConfigurationSetsRequest,
+    ) -> Result<ListConfigurationSetsResponse, RusotoError<ListConfigurationSetsError>> {
+        self.runtime
+            .block_on(self.client.list_configuration_sets(input))
+    }
+
+    /// Blocking version of [`PinpointEmail::list_dedicated_ip_pools`].
+    pub fn list_dedicated_ip_pools(
+        &self,
+        input: ListDedicatedIpPoolsRequest,
+    ) -> Result<ListDedicatedIpPoolsResponse, RusotoError<ListDedicatedIpPoolsError>> {
+        self.runtime
+            .block_on(self.client.list_dedicated_ip_pools(input))
+    }
+
+    /// Blocking version of [`PinpointEmail::list_deliverability_test_reports`].
+    pub fn list_deliverability_test_reports(
+        &self,
+        input: ListDeliverabilityTestReportsRequest,
+    ) -> Result<
+        ListDeliverabilityTestReportsResponse,
+        RusotoError<ListDeliverabilityTestReportsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_deliverability_test_reports(input))
+    }
+
+    /// Blocking version of [`PinpointEmail::list_domain_deliverability_campaigns`].
+    pub fn list_domain_deliverability_campaigns(
+        &self,
+        input: ListDomainDeliverabilityCampaignsRequest,
+    ) -> Result<
+        ListDomainDeliverabilityCampaignsResponse,
+        RusotoError<ListDomainDeliverabilityCampaignsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_domain_deliverability_campaigns(input))
+    }
+
+    /// Blocking version of [`PinpointEmail::list_email_identities`].
+    pub fn list_email_identities(
+        &self,
+        input: ListEmailIdentitiesRequest,
+    ) -> Result<ListEmailIdentitiesResponse, RusotoError<ListEmailIdentitiesError>> {
+        self.runtime
+            .block_on(self.client.list_email_identities(input))
+    }
+
+    /// Blocking version of [`PinpointEmail::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`PinpointEmail::put_account_dedicated_ip_warmup_attributes`].
+    pub fn put_account_dedicated_ip_warmup_attributes(
+        &self,
+        input: PutAccountDedicatedIpWarmupAttributesRequest,
+    ) -> Result<
+        PutAccountDedicatedIpWarmupAttributesResponse,
+        RusotoError<PutAccountDedicatedIpWarmupAttributesError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .put_account_dedicated_ip_warmup_attributes(input),
+        )
+    }
+
+    /// Blocking version of [`PinpointEmail::put_account_sending_attributes`].
+    pub fn put_account_sending_attributes(
+        &self,
+        input: PutAccountSendingAttributesRequest,
+    ) -> Result<PutAccountSendingAttributesResponse, RusotoError<PutAccountSendingAttributesError>>
+    {
+        self.runtime
+            .block_on(self.client.put_account_sending_attributes(input))
+    }
+
+    /// Blocking version of [`PinpointEmail::put_configuration_set_delivery_options`].
+    pub fn put_configuration_set_delivery_options(
+        &self,
+        input: PutConfigurationSetDeliveryOptionsRequest,
+    ) -> Result<
+        PutConfigurationSetDeliveryOptionsResponse,
+        RusotoError<PutConfigurationSetDeliveryOptionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.put_configuration_set_delivery_options(input))
+    }
+
+    /// Blocking version of [`PinpointEmail::put_configuration_set_reputation_options`].
+    pub fn put_configuration_set_reputation_options(
+        &self,
+        input: PutConfigurationSetReputationOptionsRequest,
+    ) -> Result<
+        PutConfigurationSetReputationOptionsResponse,
+        RusotoError<PutConfigurationSetReputationOptionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.put_configuration_set_reputation_options(input))
+    }
+
+    /// Blocking version of [`PinpointEmail::put_configuration_set_sending_options`].
+    pub fn put_configuration_set_sending_options(
+        &self,
+        input: PutConfigurationSetSendingOptionsRequest,
+    ) -> Result<
+        PutConfigurationSetSendingOptionsResponse,
+        RusotoError<PutConfigurationSetSendingOptionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.put_configuration_set_sending_options(input))
+    }
+
+    /// Blocking version of [`PinpointEmail::put_configuration_set_tracking_options`].
+    pub fn put_configuration_set_tracking_options(
+        &self,
+        input: PutConfigurationSetTrackingOptionsRequest,
+    ) -> Result<
+        PutConfigurationSetTrackingOptionsResponse,
+        RusotoError<PutConfigurationSetTrackingOptionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.put_configuration_set_tracking_options(input))
+    }
+
+    /// Blocking version of [`PinpointEmail::put_dedicated_ip_in_pool`].
+    pub fn put_dedicated_ip_in_pool(
+        &self,
+        input: PutDedicatedIpInPoolRequest,
+    ) -> Result<PutDedicatedIpInPoolResponse, RusotoError<PutDedicatedIpInPoolError>> {
+        self.runtime
+            .block_on(self.client.put_dedicated_ip_in_pool(input))
+    }
+
+    /// Blocking version of [`PinpointEmail::put_dedicated_ip_warmup_attributes`].
+    pub fn put_dedicated_ip_warmup_attributes(
+        &self,
+        input: PutDedicatedIpWarmupAttributesRequest,
+    ) -> Result<
+        PutDedicatedIpWarmupAttributesResponse,
+        RusotoError<PutDedicatedIpWarmupAttributesError>,
+    > {
+        self.runtime
+            .block_on(self.client.put_dedicated_ip_warmup_attributes(input))
+    }
+
+    /// Blocking version of [`PinpointEmail::put_deliverability_dashboard_option`].
+    pub fn put_deliverability_dashboard_option(
+        &self,
+        input: PutDeliverabilityDashboardOptionRequest,
+    ) -> Result<
+        PutDeliverabilityDashboardOptionResponse,
+        RusotoError<PutDeliverabilityDashboardOptionError>,
+    > {
+        self.runtime
+            .block_on(self.client.put_deliverability_dashboard_option(input))
+    }
+
+    /// Blocking version of [`PinpointEmail::put_email_identity_dkim_attributes`].
+    pub fn put_email_identity_dkim_attributes(
+        &self,
+        input: PutEmailIdentityDkimAttributesRequest,
+    ) -> Result<
+        PutEmailIdentityDkimAttributesResponse,
+        RusotoError<PutEmailIdentityDkimAttributesError>,
+    > {
+        self.runtime
+            .block_on(self.client.put_email_identity_dkim_attributes(input))
+    }
+
+    /// Blocking version of [`PinpointEmail::put_email_identity_feedback_attributes`].
+    pub fn put_email_identity_feedback_attributes(
+        &self,
+        input: PutEmailIdentityFeedbackAttributesRequest,
+    ) -> Result<
+        PutEmailIdentityFeedbackAttributesResponse,
+        RusotoError<PutEmailIdentityFeedbackAttributesError>,
+    > {
+        self.runtime
+            .block_on(self.client.put_email_identity_feedback_attributes(input))
+    }
+
+    /// Blocking version of [`PinpointEmail::put_email_identity_mail_from_attributes`].
+    pub fn put_email_identity_mail_from_attributes(
+        &self,
+        input: PutEmailIdentityMailFromAttributesRequest,
+    ) -> Result<
+        PutEmailIdentityMailFromAttributesResponse,
+        RusotoError<PutEmailIdentityMailFromAttributesError>,
+    > {
+        self.runtime
+            .block_on(self.client.put_email_identity_mail_from_attributes(input))
+    }
+
+    /// Blocking version of [`PinpointEmail::send_email`].
+    pub fn send_email(
+        &self,
+        input: SendEmailRequest,
+    ) -> Result<SendEmailResponse, RusotoError<SendEmailError>> {
+        self.runtime.block_on(self.client.send_email(input))
+    }
+
+    /// Blocking version of [`PinpointEmail::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`PinpointEmail::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`PinpointEmail::update_configuration_set_event_destination`].
+    pub fn update_configuration_set_event_destination(
+        &self,
+        input: UpdateConfigurationSetEventDestinationRequest,
+    ) -> Result<
+        UpdateConfigurationSetEventDestinationResponse,
+        RusotoError<UpdateConfigurationSetEventDestinationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .update_configuration_set_event_destination(input),
+        )
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -454,12 +455,22 @@ impl DeleteLexiconError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "LexiconNotFoundException" => {
-                    return RusotoError::Service(DeleteLexiconError::LexiconNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLexiconError::LexiconNotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DeleteLexiconError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLexiconError::ServiceFailure,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -490,12 +501,22 @@ impl DescribeVoicesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeVoicesError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeVoicesError::InvalidNextToken,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(DescribeVoicesError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeVoicesError::ServiceFailure,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -526,12 +547,22 @@ impl GetLexiconError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "LexiconNotFoundException" => {
-                    return RusotoError::Service(GetLexiconError::LexiconNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLexiconError::LexiconNotFound,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetLexiconError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLexiconError::ServiceFailure,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -564,21 +595,30 @@ impl GetSpeechSynthesisTaskError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidTaskIdException" => {
-                    return RusotoError::Service(GetSpeechSynthesisTaskError::InvalidTaskId(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSpeechSynthesisTaskError::InvalidTaskId,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(GetSpeechSynthesisTaskError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSpeechSynthesisTaskError::ServiceFailure,
+                    )
                 }
                 "SynthesisTaskNotFoundException" => {
-                    return RusotoError::Service(
-                        GetSpeechSynthesisTaskError::SynthesisTaskNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSpeechSynthesisTaskError::SynthesisTaskNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -610,12 +650,22 @@ impl ListLexiconsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListLexiconsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLexiconsError::InvalidNextToken,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListLexiconsError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLexiconsError::ServiceFailure,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -646,16 +696,22 @@ impl ListSpeechSynthesisTasksError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListSpeechSynthesisTasksError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSpeechSynthesisTasksError::InvalidNextToken,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(ListSpeechSynthesisTasksError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSpeechSynthesisTasksError::ServiceFailure,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -696,29 +752,62 @@ impl PutLexiconError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidLexiconException" => {
-                    return RusotoError::Service(PutLexiconError::InvalidLexicon(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutLexiconError::InvalidLexicon,
+                    )
                 }
                 "LexiconSizeExceededException" => {
-                    return RusotoError::Service(PutLexiconError::LexiconSizeExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutLexiconError::LexiconSizeExceeded,
+                    )
                 }
                 "MaxLexemeLengthExceededException" => {
-                    return RusotoError::Service(PutLexiconError::MaxLexemeLengthExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutLexiconError::MaxLexemeLengthExceeded,
+                    )
                 }
                 "MaxLexiconsNumberExceededException" => {
-                    return RusotoError::Service(PutLexiconError::MaxLexiconsNumberExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutLexiconError::MaxLexiconsNumberExceeded,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(PutLexiconError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutLexiconError::ServiceFailure,
+                    )
                 }
                 "UnsupportedPlsAlphabetException" => {
-                    return RusotoError::Service(PutLexiconError::UnsupportedPlsAlphabet(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutLexiconError::UnsupportedPlsAlphabet,
+                    )
                 }
                 "UnsupportedPlsLanguageException" => {
-                    return RusotoError::Service(PutLexiconError::UnsupportedPlsLanguage(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutLexiconError::UnsupportedPlsLanguage,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -774,66 +863,102 @@ impl StartSpeechSynthesisTaskError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EngineNotSupportedException" => {
-                    return RusotoError::Service(StartSpeechSynthesisTaskError::EngineNotSupported(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartSpeechSynthesisTaskError::EngineNotSupported,
+                    )
                 }
                 "InvalidS3BucketException" => {
-                    return RusotoError::Service(StartSpeechSynthesisTaskError::InvalidS3Bucket(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartSpeechSynthesisTaskError::InvalidS3Bucket,
+                    )
                 }
                 "InvalidS3KeyException" => {
-                    return RusotoError::Service(StartSpeechSynthesisTaskError::InvalidS3Key(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartSpeechSynthesisTaskError::InvalidS3Key,
+                    )
                 }
                 "InvalidSampleRateException" => {
-                    return RusotoError::Service(StartSpeechSynthesisTaskError::InvalidSampleRate(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartSpeechSynthesisTaskError::InvalidSampleRate,
+                    )
                 }
                 "InvalidSnsTopicArnException" => {
-                    return RusotoError::Service(StartSpeechSynthesisTaskError::InvalidSnsTopicArn(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartSpeechSynthesisTaskError::InvalidSnsTopicArn,
+                    )
                 }
                 "InvalidSsmlException" => {
-                    return RusotoError::Service(StartSpeechSynthesisTaskError::InvalidSsml(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartSpeechSynthesisTaskError::InvalidSsml,
+                    )
                 }
                 "LanguageNotSupportedException" => {
-                    return RusotoError::Service(
-                        StartSpeechSynthesisTaskError::LanguageNotSupported(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartSpeechSynthesisTaskError::LanguageNotSupported,
                     )
                 }
                 "LexiconNotFoundException" => {
-                    return RusotoError::Service(StartSpeechSynthesisTaskError::LexiconNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartSpeechSynthesisTaskError::LexiconNotFound,
+                    )
                 }
                 "MarksNotSupportedForFormatException" => {
-                    return RusotoError::Service(
-                        StartSpeechSynthesisTaskError::MarksNotSupportedForFormat(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartSpeechSynthesisTaskError::MarksNotSupportedForFormat,
                     )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(StartSpeechSynthesisTaskError::ServiceFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartSpeechSynthesisTaskError::ServiceFailure,
+                    )
                 }
                 "SsmlMarksNotSupportedForTextTypeException" => {
-                    return RusotoError::Service(
-                        StartSpeechSynthesisTaskError::SsmlMarksNotSupportedForTextType(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartSpeechSynthesisTaskError::SsmlMarksNotSupportedForTextType,
                     )
                 }
                 "TextLengthExceededException" => {
-                    return RusotoError::Service(StartSpeechSynthesisTaskError::TextLengthExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartSpeechSynthesisTaskError::TextLengthExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -894,39 +1019,78 @@ impl SynthesizeSpeechError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "EngineNotSupportedException" => {
-                    return RusotoError::Service(SynthesizeSpeechError::EngineNotSupported(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SynthesizeSpeechError::EngineNotSupported,
+                    )
                 }
                 "InvalidSampleRateException" => {
-                    return RusotoError::Service(SynthesizeSpeechError::InvalidSampleRate(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SynthesizeSpeechError::InvalidSampleRate,
+                    )
                 }
                 "InvalidSsmlException" => {
-                    return RusotoError::Service(SynthesizeSpeechError::InvalidSsml(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SynthesizeSpeechError::InvalidSsml,
+                    )
                 }
                 "LanguageNotSupportedException" => {
-                    return RusotoError::Service(SynthesizeSpeechError::LanguageNotSupported(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SynthesizeSpeechError::LanguageNotSupported,
+                    )
                 }
                 "LexiconNotFoundException" => {
-                    return RusotoError::Service(SynthesizeSpeechError::LexiconNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SynthesizeSpeechError::LexiconNotFound,
+                    )
                 }
                 "MarksNotSupportedForFormatException" => {
-                    return RusotoError::Service(SynthesizeSpeechError::MarksNotSupportedForFormat(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SynthesizeSpeechError::MarksNotSupportedForFormat,
+                    )
                 }
                 "ServiceFailureException" => {
-                    return RusotoError::Service(SynthesizeSpeechError::ServiceFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SynthesizeSpeechError::ServiceFailure,
+                    )
                 }
                 "SsmlMarksNotSupportedForTextTypeException" => {
-                    return RusotoError::Service(
-                        SynthesizeSpeechError::SsmlMarksNotSupportedForTextType(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SynthesizeSpeechError::SsmlMarksNotSupportedForTextType,
                     )
                 }
                 "TextLengthExceededException" => {
-                    return RusotoError::Service(SynthesizeSpeechError::TextLengthExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SynthesizeSpeechError::TextLengthExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1019,11 +1183,12 @@ pub struct PollyClient {
 impl PollyClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> PollyClient {
         PollyClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Polly"),
         }
     }
 
@@ -1038,12 +1203,32 @@ impl PollyClient {
     {
         PollyClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Polly"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> PollyClient {
-        PollyClient { client, region }
+        PollyClient {
+            client,
+            region: region_for_service(region, "Polly"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> PollyClient {
+        PollyClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Polly"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> PollyClient {
+        PollyClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -1058,6 +1243,7 @@ impl Polly for PollyClient {
         let request_uri = format!("/v1/lexicons/{lexicon_name}", lexicon_name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "polly", &self.region, &request_uri);
+        request.set_operation("DeleteLexicon");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1086,6 +1272,7 @@ impl Polly for PollyClient {
         let request_uri = "/v1/voices";
 
         let mut request = SignedRequest::new("GET", "polly", &self.region, &request_uri);
+        request.set_operation("DescribeVoices");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1129,6 +1316,7 @@ impl Polly for PollyClient {
         let request_uri = format!("/v1/lexicons/{lexicon_name}", lexicon_name = input.name);
 
         let mut request = SignedRequest::new("GET", "polly", &self.region, &request_uri);
+        request.set_operation("GetLexicon");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1157,6 +1345,7 @@ impl Polly for PollyClient {
         let request_uri = format!("/v1/synthesisTasks/{task_id}", task_id = input.task_id);
 
         let mut request = SignedRequest::new("GET", "polly", &self.region, &request_uri);
+        request.set_operation("GetSpeechSynthesisTask");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1185,6 +1374,7 @@ impl Polly for PollyClient {
         let request_uri = "/v1/lexicons";
 
         let mut request = SignedRequest::new("GET", "polly", &self.region, &request_uri);
+        request.set_operation("ListLexicons");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1219,6 +1409,7 @@ impl Polly for PollyClient {
         let request_uri = "/v1/synthesisTasks";
 
         let mut request = SignedRequest::new("GET", "polly", &self.region, &request_uri);
+        request.set_operation("ListSpeechSynthesisTasks");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1259,6 +1450,7 @@ impl Polly for PollyClient {
         let request_uri = format!("/v1/lexicons/{lexicon_name}", lexicon_name = input.name);
 
         let mut request = SignedRequest::new("PUT", "polly", &self.region, &request_uri);
+        request.set_operation("PutLexicon");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1290,6 +1482,7 @@ impl Polly for PollyClient {
         let request_uri = "/v1/synthesisTasks";
 
         let mut request = SignedRequest::new("POST", "polly", &self.region, &request_uri);
+        request.set_operation("StartSpeechSynthesisTask");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1321,6 +1514,8 @@ impl Polly for PollyClient {
         let request_uri = "/v1/speech";
 
         let mut request = SignedRequest::new("POST", "polly", &self.region, &request_uri);
+        request.set_operation("SynthesizeSpeech");
+        request.set_raw_response(true);
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1350,3 +1545,153 @@ impl Polly for PollyClient {
         }
     }
 }
+
+/// A blocking client for the Amazon Polly API, running the calls of a `PollyClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `PollyClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct PollyBlockingClient {
+    client: PollyClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl PollyBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> PollyBlockingClient {
+        PollyBlockingClient::from_client(PollyClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> PollyBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        PollyBlockingClient::from_client(PollyClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> PollyBlockingClient {
+        PollyBlockingClient::from_client(PollyClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> PollyBlockingClient {
+        PollyBlockingClient::from_client(PollyClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: PollyClient) -> PollyBlockingClient {
+        PollyBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> PollyBlockingClient {
+        PollyBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> PollyBlockingClient {
+        PollyBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Polly::delete_lexicon`].
+    pub fn delete_lexicon(
+        &self,
+        input: DeleteLexiconInput,
+    ) -> Result<DeleteLexiconOutput, RusotoError<DeleteLexiconError>> {
+        self.runtime.block_on(self.client.delete_lexicon(input))
+    }
+
+    /// Blocking version of [`Polly::describe_voices`].
+    pub fn describe_voices(
+        &self,
+        input: DescribeVoicesInput,
+    ) -> Result<DescribeVoicesOutput, RusotoError<DescribeVoicesError>> {
+        self.runtime.block_on(self.client.describe_voices(input))
+    }
+
+    /// Blocking version of [`Polly::get_lexicon`].
+    pub fn get_lexicon(
+        &self,
+        input: GetLexiconInput,
+    ) -> Result<GetLexiconOutput, RusotoError<GetLexiconError>> {
+        self.runtime.block_on(self.client.get_lexicon(input))
+    }
+
+    /// Blocking version of [`Polly::get_speech_synthesis_task`].
+    pub fn get_speech_synthesis_task(
+        &self,
+        input: GetSpeechSynthesisTaskInput,
+    ) -> Result<GetSpeechSynthesisTaskOutput, RusotoError<GetSpeechSynthesisTaskError>> {
+        self.runtime
+            .block_on(self.client.get_speech_synthesis_task(input))
+    }
+
+    /// Blocking version of [`Polly::list_lexicons`].
+    pub fn list_lexicons(
+        &self,
+        input: ListLexiconsInput,
+    ) -> Result<ListLexiconsOutput, RusotoError<ListLexiconsError>> {
+        self.runtime.block_on(self.client.list_lexicons(input))
+    }
+
+    /// Blocking version of [`Polly::list_speech_synthesis_tasks`].
+    pub fn list_speech_synthesis_tasks(
+        &self,
+        input: ListSpeechSynthesisTasksInput,
+    ) -> Result<ListSpeechSynthesisTasksOutput, RusotoError<ListSpeechSynthesisTasksError>> {
+        self.runtime
+            .block_on(self.client.list_speech_synthesis_tasks(input))
+    }
+
+    /// Blocking version of [`Polly::put_lexicon`].
+    pub fn put_lexicon(
+        &self,
+        input: PutLexiconInput,
+    ) -> Result<PutLexiconOutput, RusotoError<PutLexiconError>> {
+        self.runtime.block_on(self.client.put_lexicon(input))
+    }
+
+    /// Blocking version of [`Polly::start_speech_synthesis_task`].
+    pub fn start_speech_synthesis_task(
+        &self,
+        input: StartSpeechSynthesisTaskInput,
+    ) -> Result<StartSpeechSynthesisTaskOutput, RusotoError<StartSpeechSynthesisTaskError>> {
+        self.runtime
+            .block_on(self.client.start_speech_synthesis_task(input))
+    }
+
+    /// Blocking version of [`Polly::synthesize_speech`].
+    pub fn synthesize_speech(
+        &self,
+        input: SynthesizeSpeechInput,
+    ) -> Result<SynthesizeSpeechOutput, RusotoError<SynthesizeSpeechError>> {
+        self.runtime.block_on(self.client.synthesize_speech(input))
+    }
+}
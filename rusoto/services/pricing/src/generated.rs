@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -222,21 +223,46 @@ impl DescribeServicesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExpiredNextTokenException" => {
-                    return RusotoError::Service(DescribeServicesError::ExpiredNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeServicesError::ExpiredNextToken,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(DescribeServicesError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeServicesError::InternalError,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(DescribeServicesError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeServicesError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeServicesError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeServicesError::InvalidParameter,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DescribeServicesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeServicesError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -276,21 +302,46 @@ impl GetAttributeValuesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExpiredNextTokenException" => {
-                    return RusotoError::Service(GetAttributeValuesError::ExpiredNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAttributeValuesError::ExpiredNextToken,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetAttributeValuesError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAttributeValuesError::InternalError,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(GetAttributeValuesError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAttributeValuesError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetAttributeValuesError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAttributeValuesError::InvalidParameter,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetAttributeValuesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetAttributeValuesError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -330,21 +381,46 @@ impl GetProductsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ExpiredNextTokenException" => {
-                    return RusotoError::Service(GetProductsError::ExpiredNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetProductsError::ExpiredNextToken,
+                    )
                 }
                 "InternalErrorException" => {
-                    return RusotoError::Service(GetProductsError::InternalError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetProductsError::InternalError,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(GetProductsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetProductsError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetProductsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetProductsError::InvalidParameter,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetProductsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetProductsError::NotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -395,11 +471,12 @@ pub struct PricingClient {
 impl PricingClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> PricingClient {
         PricingClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Pricing"),
         }
     }
 
@@ -414,12 +491,32 @@ impl PricingClient {
     {
         PricingClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Pricing"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> PricingClient {
-        PricingClient { client, region }
+        PricingClient {
+            client,
+            region: region_for_service(region, "Pricing"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> PricingClient {
+        PricingClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Pricing"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> PricingClient {
+        PricingClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -431,6 +528,7 @@ impl Pricing for PricingClient {
         input: DescribeServicesRequest,
     ) -> Result<DescribeServicesResponse, RusotoError<DescribeServicesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeServices");
         request.add_header("x-amz-target", "AWSPriceListService.DescribeServices");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -449,6 +547,7 @@ impl Pricing for PricingClient {
         input: GetAttributeValuesRequest,
     ) -> Result<GetAttributeValuesResponse, RusotoError<GetAttributeValuesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetAttributeValues");
         request.add_header("x-amz-target", "AWSPriceListService.GetAttributeValues");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -467,6 +566,7 @@ impl Pricing for PricingClient {
         input: GetProductsRequest,
     ) -> Result<GetProductsResponse, RusotoError<GetProductsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetProducts");
         request.add_header("x-amz-target", "AWSPriceListService.GetProducts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -479,3 +579,103 @@ impl Pricing for PricingClient {
         proto::json::ResponsePayload::new(&response).deserialize::<GetProductsResponse, _>()
     }
 }
+
+/// A blocking client for the AWS Pricing API, running the calls of a `PricingClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `PricingClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct PricingBlockingClient {
+    client: PricingClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl PricingBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> PricingBlockingClient {
+        PricingBlockingClient::from_client(PricingClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> PricingBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        PricingBlockingClient::from_client(PricingClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> PricingBlockingClient {
+        PricingBlockingClient::from_client(PricingClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> PricingBlockingClient {
+        PricingBlockingClient::from_client(PricingClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: PricingClient) -> PricingBlockingClient {
+        PricingBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> PricingBlockingClient {
+        PricingBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> PricingBlockingClient {
+        PricingBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Pricing::describe_services`].
+    pub fn describe_services(
+        &self,
+        input: DescribeServicesRequest,
+    ) -> Result<DescribeServicesResponse, RusotoError<DescribeServicesError>> {
+        self.runtime.block_on(self.client.describe_services(input))
+    }
+
+    /// Blocking version of [`Pricing::get_attribute_values`].
+    pub fn get_attribute_values(
+        &self,
+        input: GetAttributeValuesRequest,
+    ) -> Result<GetAttributeValuesResponse, RusotoError<GetAttributeValuesError>> {
+        self.runtime
+            .block_on(self.client.get_attribute_values(input))
+    }
+
+    /// Blocking version of [`Pricing::get_products`].
+    pub fn get_products(
+        &self,
+        input: GetProductsRequest,
+    ) -> Result<GetProductsResponse, RusotoError<GetProductsError>> {
+        self.runtime.block_on(self.client.get_products(input))
+    }
+}
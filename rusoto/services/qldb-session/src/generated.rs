@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -377,21 +378,46 @@ impl SendCommandError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(SendCommandError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendCommandError::BadRequest,
+                    )
                 }
                 "InvalidSessionException" => {
-                    return RusotoError::Service(SendCommandError::InvalidSession(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendCommandError::InvalidSession,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(SendCommandError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendCommandError::LimitExceeded,
+                    )
                 }
                 "OccConflictException" => {
-                    return RusotoError::Service(SendCommandError::OccConflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendCommandError::OccConflict,
+                    )
                 }
                 "RateExceededException" => {
-                    return RusotoError::Service(SendCommandError::RateExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SendCommandError::RateExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -430,11 +456,12 @@ pub struct QldbSessionClient {
 impl QldbSessionClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> QldbSessionClient {
         QldbSessionClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "QLDB Session"),
         }
     }
 
@@ -449,12 +476,32 @@ impl QldbSessionClient {
     {
         QldbSessionClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "QLDB Session"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> QldbSessionClient {
-        QldbSessionClient { client, region }
+        QldbSessionClient {
+            client,
+            region: region_for_service(region, "QLDB Session"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> QldbSessionClient {
+        QldbSessionClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("QLDB Session"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> QldbSessionClient {
+        QldbSessionClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -466,6 +513,7 @@ impl QldbSession for QldbSessionClient {
         input: SendCommandRequest,
     ) -> Result<SendCommandResult, RusotoError<SendCommandError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SendCommand");
         request.add_header("x-amz-target", "QLDBSession.SendCommand");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -478,3 +526,86 @@ impl QldbSession for QldbSessionClient {
         proto::json::ResponsePayload::new(&response).deserialize::<SendCommandResult, _>()
     }
 }
+
+/// A blocking client for the QLDB Session API, running the calls of a `QldbSessionClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `QldbSessionClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct QldbSessionBlockingClient {
+    client: QldbSessionClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl QldbSessionBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> QldbSessionBlockingClient {
+        QldbSessionBlockingClient::from_client(QldbSessionClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> QldbSessionBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        QldbSessionBlockingClient::from_client(QldbSessionClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> QldbSessionBlockingClient {
+        QldbSessionBlockingClient::from_client(QldbSessionClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> QldbSessionBlockingClient {
+        QldbSessionBlockingClient::from_client(QldbSessionClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: QldbSessionClient) -> QldbSessionBlockingClient {
+        QldbSessionBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> QldbSessionBlockingClient {
+        QldbSessionBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> QldbSessionBlockingClient {
+        QldbSessionBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`QldbSession::send_command`].
+    pub fn send_command(
+        &self,
+        input: SendCommandRequest,
+    ) -> Result<SendCommandResult, RusotoError<SendCommandError>> {
+        self.runtime.block_on(self.client.send_command(input))
+    }
+}
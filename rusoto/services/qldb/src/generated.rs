@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -665,21 +666,30 @@ impl CancelJournalKinesisStreamError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CancelJournalKinesisStreamError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelJournalKinesisStreamError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelJournalKinesisStreamError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelJournalKinesisStreamError::ResourceNotFound,
+                    )
                 }
                 "ResourcePreconditionNotMetException" => {
-                    return RusotoError::Service(
-                        CancelJournalKinesisStreamError::ResourcePreconditionNotMet(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelJournalKinesisStreamError::ResourcePreconditionNotMet,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -717,18 +727,38 @@ impl CreateLedgerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateLedgerError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLedgerError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateLedgerError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLedgerError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateLedgerError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLedgerError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateLedgerError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLedgerError::ResourceInUse,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -765,20 +795,38 @@ impl DeleteLedgerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteLedgerError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLedgerError::InvalidParameter,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteLedgerError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLedgerError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteLedgerError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteLedgerError::ResourceNotFound,
+                    )
                 }
                 "ResourcePreconditionNotMetException" => {
-                    return RusotoError::Service(DeleteLedgerError::ResourcePreconditionNotMet(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteLedgerError::ResourcePreconditionNotMet,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -815,21 +863,30 @@ impl DescribeJournalKinesisStreamError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        DescribeJournalKinesisStreamError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeJournalKinesisStreamError::InvalidParameter,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeJournalKinesisStreamError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeJournalKinesisStreamError::ResourceNotFound,
                     )
                 }
                 "ResourcePreconditionNotMetException" => {
-                    return RusotoError::Service(
-                        DescribeJournalKinesisStreamError::ResourcePreconditionNotMet(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeJournalKinesisStreamError::ResourcePreconditionNotMet,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -865,11 +922,14 @@ impl DescribeJournalS3ExportError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeJournalS3ExportError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeJournalS3ExportError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -899,12 +959,22 @@ impl DescribeLedgerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeLedgerError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeLedgerError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeLedgerError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeLedgerError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -935,14 +1005,22 @@ impl ExportJournalToS3Error {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ExportJournalToS3Error::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExportJournalToS3Error::ResourceNotFound,
+                    )
                 }
                 "ResourcePreconditionNotMetException" => {
-                    return RusotoError::Service(
-                        ExportJournalToS3Error::ResourcePreconditionNotMet(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExportJournalToS3Error::ResourcePreconditionNotMet,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -975,15 +1053,30 @@ impl GetBlockError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetBlockError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBlockError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetBlockError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBlockError::ResourceNotFound,
+                    )
                 }
                 "ResourcePreconditionNotMetException" => {
-                    return RusotoError::Service(GetBlockError::ResourcePreconditionNotMet(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetBlockError::ResourcePreconditionNotMet,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1017,17 +1110,30 @@ impl GetDigestError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetDigestError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDigestError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDigestError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDigestError::ResourceNotFound,
+                    )
                 }
                 "ResourcePreconditionNotMetException" => {
-                    return RusotoError::Service(GetDigestError::ResourcePreconditionNotMet(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDigestError::ResourcePreconditionNotMet,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1061,17 +1167,30 @@ impl GetRevisionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetRevisionError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRevisionError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetRevisionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetRevisionError::ResourceNotFound,
+                    )
                 }
                 "ResourcePreconditionNotMetException" => {
-                    return RusotoError::Service(GetRevisionError::ResourcePreconditionNotMet(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetRevisionError::ResourcePreconditionNotMet,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1107,23 +1226,30 @@ impl ListJournalKinesisStreamsForLedgerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        ListJournalKinesisStreamsForLedgerError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListJournalKinesisStreamsForLedgerError::InvalidParameter,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        ListJournalKinesisStreamsForLedgerError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListJournalKinesisStreamsForLedgerError::ResourceNotFound,
                     )
                 }
                 "ResourcePreconditionNotMetException" => {
-                    return RusotoError::Service(
-                        ListJournalKinesisStreamsForLedgerError::ResourcePreconditionNotMet(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListJournalKinesisStreamsForLedgerError::ResourcePreconditionNotMet,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1155,7 +1281,7 @@ impl ListJournalS3ExportsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListJournalS3ExportsError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1179,7 +1305,7 @@ impl ListJournalS3ExportsForLedgerError {
     ) -> RusotoError<ListJournalS3ExportsForLedgerError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1201,7 +1327,7 @@ impl ListLedgersError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListLedgersError> {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1229,16 +1355,22 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1271,21 +1403,30 @@ impl StreamJournalToKinesisError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StreamJournalToKinesisError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StreamJournalToKinesisError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StreamJournalToKinesisError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StreamJournalToKinesisError::ResourceNotFound,
+                    )
                 }
                 "ResourcePreconditionNotMetException" => {
-                    return RusotoError::Service(
-                        StreamJournalToKinesisError::ResourcePreconditionNotMet(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StreamJournalToKinesisError::ResourcePreconditionNotMet,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1319,12 +1460,22 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1355,12 +1506,22 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1391,12 +1552,22 @@ impl UpdateLedgerError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateLedgerError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateLedgerError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateLedgerError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateLedgerError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1546,11 +1717,12 @@ pub struct QldbClient {
 impl QldbClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> QldbClient {
         QldbClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "QLDB"),
         }
     }
 
@@ -1565,12 +1737,32 @@ impl QldbClient {
     {
         QldbClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "QLDB"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> QldbClient {
-        QldbClient { client, region }
+        QldbClient {
+            client,
+            region: region_for_service(region, "QLDB"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> QldbClient {
+        QldbClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("QLDB"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> QldbClient {
+        QldbClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -1590,6 +1782,7 @@ impl Qldb for QldbClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "qldb", &self.region, &request_uri);
+        request.set_operation("CancelJournalKinesisStream");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1618,6 +1811,7 @@ impl Qldb for QldbClient {
         let request_uri = "/ledgers";
 
         let mut request = SignedRequest::new("POST", "qldb", &self.region, &request_uri);
+        request.set_operation("CreateLedger");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1649,6 +1843,7 @@ impl Qldb for QldbClient {
         let request_uri = format!("/ledgers/{name}", name = input.name);
 
         let mut request = SignedRequest::new("DELETE", "qldb", &self.region, &request_uri);
+        request.set_operation("DeleteLedger");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1681,6 +1876,7 @@ impl Qldb for QldbClient {
         );
 
         let mut request = SignedRequest::new("GET", "qldb", &self.region, &request_uri);
+        request.set_operation("DescribeJournalKinesisStream");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1713,6 +1909,7 @@ impl Qldb for QldbClient {
         );
 
         let mut request = SignedRequest::new("GET", "qldb", &self.region, &request_uri);
+        request.set_operation("DescribeJournalS3Export");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1741,6 +1938,7 @@ impl Qldb for QldbClient {
         let request_uri = format!("/ledgers/{name}", name = input.name);
 
         let mut request = SignedRequest::new("GET", "qldb", &self.region, &request_uri);
+        request.set_operation("DescribeLedger");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1769,6 +1967,7 @@ impl Qldb for QldbClient {
         let request_uri = format!("/ledgers/{name}/journal-s3-exports", name = input.name);
 
         let mut request = SignedRequest::new("POST", "qldb", &self.region, &request_uri);
+        request.set_operation("ExportJournalToS3");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1800,6 +1999,7 @@ impl Qldb for QldbClient {
         let request_uri = format!("/ledgers/{name}/block", name = input.name);
 
         let mut request = SignedRequest::new("POST", "qldb", &self.region, &request_uri);
+        request.set_operation("GetBlock");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1831,6 +2031,7 @@ impl Qldb for QldbClient {
         let request_uri = format!("/ledgers/{name}/digest", name = input.name);
 
         let mut request = SignedRequest::new("POST", "qldb", &self.region, &request_uri);
+        request.set_operation("GetDigest");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1859,6 +2060,7 @@ impl Qldb for QldbClient {
         let request_uri = format!("/ledgers/{name}/revision", name = input.name);
 
         let mut request = SignedRequest::new("POST", "qldb", &self.region, &request_uri);
+        request.set_operation("GetRevision");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1896,6 +2098,7 @@ impl Qldb for QldbClient {
         );
 
         let mut request = SignedRequest::new("GET", "qldb", &self.region, &request_uri);
+        request.set_operation("ListJournalKinesisStreamsForLedger");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1935,6 +2138,7 @@ impl Qldb for QldbClient {
         let request_uri = "/journal-s3-exports";
 
         let mut request = SignedRequest::new("GET", "qldb", &self.region, &request_uri);
+        request.set_operation("ListJournalS3Exports");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -1975,6 +2179,7 @@ impl Qldb for QldbClient {
         let request_uri = format!("/ledgers/{name}/journal-s3-exports", name = input.name);
 
         let mut request = SignedRequest::new("GET", "qldb", &self.region, &request_uri);
+        request.set_operation("ListJournalS3ExportsForLedger");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2012,6 +2217,7 @@ impl Qldb for QldbClient {
         let request_uri = "/ledgers";
 
         let mut request = SignedRequest::new("GET", "qldb", &self.region, &request_uri);
+        request.set_operation("ListLedgers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2049,6 +2255,7 @@ impl Qldb for QldbClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "qldb", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -2080,6 +2287,7 @@ impl Qldb for QldbClient {
         );
 
         let mut request = SignedRequest::new("POST", "qldb", &self.region, &request_uri);
+        request.set_operation("StreamJournalToKinesis");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2111,6 +2319,7 @@ impl Qldb for QldbClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "qldb", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2142,6 +2351,7 @@ impl Qldb for QldbClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "qldb", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -2176,6 +2386,7 @@ impl Qldb for QldbClient {
         let request_uri = format!("/ledgers/{name}", name = input.name);
 
         let mut request = SignedRequest::new("PATCH", "qldb", &self.region, &request_uri);
+        request.set_operation("UpdateLedger");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2198,3 +2409,247 @@ impl Qldb for QldbClient {
         }
     }
 }
+
+/// A blocking client for the QLDB API, running the calls of a `QldbClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `QldbClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct QldbBlockingClient {
+    client: QldbClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl QldbBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> QldbBlockingClient {
+        QldbBlockingClient::from_client(QldbClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> QldbBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        QldbBlockingClient::from_client(QldbClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> QldbBlockingClient {
+        QldbBlockingClient::from_client(QldbClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> QldbBlockingClient {
+        QldbBlockingClient::from_client(QldbClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: QldbClient) -> QldbBlockingClient {
+        QldbBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> QldbBlockingClient {
+        QldbBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> QldbBlockingClient {
+        QldbBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Qldb::cancel_journal_kinesis_stream`].
+    pub fn cancel_journal_kinesis_stream(
+        &self,
+        input: CancelJournalKinesisStreamRequest,
+    ) -> Result<CancelJournalKinesisStreamResponse, RusotoError<CancelJournalKinesisStreamError>>
+    {
+        self.runtime
+            .block_on(self.client.cancel_journal_kinesis_stream(input))
+    }
+
+    /// Blocking version of [`Qldb::create_ledger`].
+    pub fn create_ledger(
+        &self,
+        input: CreateLedgerRequest,
+    ) -> Result<CreateLedgerResponse, RusotoError<CreateLedgerError>> {
+        self.runtime.block_on(self.client.create_ledger(input))
+    }
+
+    /// Blocking version of [`Qldb::delete_ledger`].
+    pub fn delete_ledger(
+        &self,
+        input: DeleteLedgerRequest,
+    ) -> Result<(), RusotoError<DeleteLedgerError>> {
+        self.runtime.block_on(self.client.delete_ledger(input))
+    }
+
+    /// Blocking version of [`Qldb::describe_journal_kinesis_stream`].
+    pub fn describe_journal_kinesis_stream(
+        &self,
+        input: DescribeJournalKinesisStreamRequest,
+    ) -> Result<DescribeJournalKinesisStreamResponse, RusotoError<DescribeJournalKinesisStreamError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_journal_kinesis_stream(input))
+    }
+
+    /// Blocking version of [`Qldb::describe_journal_s3_export`].
+    pub fn describe_journal_s3_export(
+        &self,
+        input: DescribeJournalS3ExportRequest,
+    ) -> Result<DescribeJournalS3ExportResponse, RusotoError<DescribeJournalS3ExportError>> {
+        self.runtime
+            .block_on(self.client.describe_journal_s3_export(input))
+    }
+
+    /// Blocking version of [`Qldb::describe_ledger`].
+    pub fn describe_ledger(
+        &self,
+        input: DescribeLedgerRequest,
+    ) -> Result<DescribeLedgerResponse, RusotoError<DescribeLedgerError>> {
+        self.runtime.block_on(self.client.describe_ledger(input))
+    }
+
+    /// Blocking version of [`Qldb::export_journal_to_s3`].
+    pub fn export_journal_to_s3(
+        &self,
+        input: ExportJournalToS3Request,
+    ) -> Result<ExportJournalToS3Response, RusotoError<ExportJournalToS3Error>> {
+        self.runtime
+            .block_on(self.client.export_journal_to_s3(input))
+    }
+
+    /// Blocking version of [`Qldb::get_block`].
+    pub fn get_block(
+        &self,
+        input: GetBlockRequest,
+    ) -> Result<GetBlockResponse, RusotoError<GetBlockError>> {
+        self.runtime.block_on(self.client.get_block(input))
+    }
+
+    /// Blocking version of [`Qldb::get_digest`].
+    pub fn get_digest(
+        &self,
+        input: GetDigestRequest,
+    ) -> Result<GetDigestResponse, RusotoError<GetDigestError>> {
+        self.runtime.block_on(self.client.get_digest(input))
+    }
+
+    /// Blocking version of [`Qldb::get_revision`].
+    pub fn get_revision(
+        &self,
+        input: GetRevisionRequest,
+    ) -> Result<GetRevisionResponse, RusotoError<GetRevisionError>> {
+        self.runtime.block_on(self.client.get_revision(input))
+    }
+
+    /// Blocking version of [`Qldb::list_journal_kinesis_streams_for_ledger`].
+    pub fn list_journal_kinesis_streams_for_ledger(
+        &self,
+        input: ListJournalKinesisStreamsForLedgerRequest,
+    ) -> Result<
+        ListJournalKinesisStreamsForLedgerResponse,
+        RusotoError<ListJournalKinesisStreamsForLedgerError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_journal_kinesis_streams_for_ledger(input))
+    }
+
+    /// Blocking version of [`Qldb::list_journal_s3_exports`].
+    pub fn list_journal_s3_exports(
+        &self,
+        input: ListJournalS3ExportsRequest,
+    ) -> Result<ListJournalS3ExportsResponse, RusotoError<ListJournalS3ExportsError>> {
+        self.runtime
+            .block_on(self.client.list_journal_s3_exports(input))
+    }
+
+    /// Blocking version of [`Qldb::list_journal_s3_exports_for_ledger`].
+    pub fn list_journal_s3_exports_for_ledger(
+        &self,
+        input: ListJournalS3ExportsForLedgerRequest,
+    ) -> Result<
+        ListJournalS3ExportsForLedgerResponse,
+        RusotoError<ListJournalS3ExportsForLedgerError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_journal_s3_exports_for_ledger(input))
+    }
+
+    /// Blocking version of [`Qldb::list_ledgers`].
+    pub fn list_ledgers(
+        &self,
+        input: ListLedgersRequest,
+    ) -> Result<ListLedgersResponse, RusotoError<ListLedgersError>> {
+        self.runtime.block_on(self.client.list_ledgers(input))
+    }
+
+    /// Blocking version of [`Qldb::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`Qldb::stream_journal_to_kinesis`].
+    pub fn stream_journal_to_kinesis(
+        &self,
+        input: StreamJournalToKinesisRequest,
+    ) -> Result<StreamJournalToKinesisResponse, RusotoError<StreamJournalToKinesisError>> {
+        self.runtime
+            .block_on(self.client.stream_journal_to_kinesis(input))
+    }
+
+    /// Blocking version of [`Qldb::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`Qldb::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`Qldb::update_ledger`].
+    pub fn update_ledger(
+        &self,
+        input: UpdateLedgerRequest,
+    ) -> Result<UpdateLedgerResponse, RusotoError<UpdateLedgerError>> {
+        self.runtime.block_on(self.client.update_ledger(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -6273,26 +6274,54 @@ impl CancelIngestionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CancelIngestionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelIngestionError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CancelIngestionError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelIngestionError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CancelIngestionError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelIngestionError::InvalidParameterValue,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CancelIngestionError::ResourceExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelIngestionError::ResourceExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelIngestionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelIngestionError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CancelIngestionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelIngestionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6339,41 +6368,62 @@ impl CreateAccountCustomizationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateAccountCustomizationError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAccountCustomizationError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateAccountCustomizationError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAccountCustomizationError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        CreateAccountCustomizationError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAccountCustomizationError::InvalidParameterValue,
                     )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateAccountCustomizationError::ResourceExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAccountCustomizationError::ResourceExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateAccountCustomizationError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAccountCustomizationError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(
-                        CreateAccountCustomizationError::ResourceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAccountCustomizationError::ResourceUnavailable,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateAccountCustomizationError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAccountCustomizationError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6423,31 +6473,62 @@ impl CreateAnalysisError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(CreateAnalysisError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAnalysisError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateAnalysisError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAnalysisError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateAnalysisError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAnalysisError::InvalidParameterValue,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateAnalysisError::ResourceExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAnalysisError::ResourceExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateAnalysisError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAnalysisError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateAnalysisError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAnalysisError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(CreateAnalysisError::UnsupportedUserEdition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAnalysisError::UnsupportedUserEdition,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6493,31 +6574,62 @@ impl CreateDashboardError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(CreateDashboardError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDashboardError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateDashboardError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDashboardError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateDashboardError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDashboardError::InvalidParameterValue,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateDashboardError::ResourceExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDashboardError::ResourceExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDashboardError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDashboardError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDashboardError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDashboardError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(CreateDashboardError::UnsupportedUserEdition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDashboardError::UnsupportedUserEdition,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6567,35 +6679,78 @@ impl CreateDataSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDataSetError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataSetError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateDataSetError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataSetError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateDataSetError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataSetError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateDataSetError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataSetError::InvalidParameterValue,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDataSetError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataSetError::LimitExceeded,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateDataSetError::ResourceExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataSetError::ResourceExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDataSetError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataSetError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDataSetError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataSetError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(CreateDataSetError::UnsupportedUserEdition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDataSetError::UnsupportedUserEdition,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6645,32 +6800,70 @@ impl CreateDataSourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateDataSourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataSourceError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateDataSourceError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataSourceError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateDataSourceError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataSourceError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateDataSourceError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDataSourceError::InvalidParameterValue,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDataSourceError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataSourceError::LimitExceeded,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateDataSourceError::ResourceExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataSourceError::ResourceExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDataSourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataSourceError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDataSourceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataSourceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6721,33 +6914,78 @@ impl CreateGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateGroupError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGroupError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateGroupError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGroupError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateGroupError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGroupError::InvalidParameterValue,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateGroupError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGroupError::LimitExceeded,
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(CreateGroupError::PreconditionNotMet(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGroupError::PreconditionNotMet,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateGroupError::ResourceExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGroupError::ResourceExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGroupError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(CreateGroupError::ResourceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGroupError::ResourceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateGroupError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGroupError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6795,37 +7033,62 @@ impl CreateGroupMembershipError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateGroupMembershipError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGroupMembershipError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateGroupMembershipError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateGroupMembershipError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateGroupMembershipError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateGroupMembershipError::InvalidParameterValue,
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(CreateGroupMembershipError::PreconditionNotMet(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateGroupMembershipError::PreconditionNotMet,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateGroupMembershipError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateGroupMembershipError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(CreateGroupMembershipError::ResourceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateGroupMembershipError::ResourceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateGroupMembershipError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGroupMembershipError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6871,41 +7134,62 @@ impl CreateIAMPolicyAssignmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateIAMPolicyAssignmentError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateIAMPolicyAssignmentError::AccessDenied,
+                    )
                 }
                 "ConcurrentUpdatingException" => {
-                    return RusotoError::Service(
-                        CreateIAMPolicyAssignmentError::ConcurrentUpdating(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIAMPolicyAssignmentError::ConcurrentUpdating,
                     )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateIAMPolicyAssignmentError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateIAMPolicyAssignmentError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        CreateIAMPolicyAssignmentError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIAMPolicyAssignmentError::InvalidParameterValue,
                     )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateIAMPolicyAssignmentError::ResourceExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateIAMPolicyAssignmentError::ResourceExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateIAMPolicyAssignmentError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateIAMPolicyAssignmentError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateIAMPolicyAssignmentError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateIAMPolicyAssignmentError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6953,29 +7237,62 @@ impl CreateIngestionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateIngestionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIngestionError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateIngestionError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIngestionError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateIngestionError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateIngestionError::InvalidParameterValue,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateIngestionError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIngestionError::LimitExceeded,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateIngestionError::ResourceExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIngestionError::ResourceExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateIngestionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIngestionError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateIngestionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateIngestionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7027,38 +7344,86 @@ impl CreateNamespaceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateNamespaceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNamespaceError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateNamespaceError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNamespaceError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateNamespaceError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNamespaceError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateNamespaceError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateNamespaceError::InvalidParameterValue,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateNamespaceError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNamespaceError::LimitExceeded,
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(CreateNamespaceError::PreconditionNotMet(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNamespaceError::PreconditionNotMet,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateNamespaceError::ResourceExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNamespaceError::ResourceExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateNamespaceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNamespaceError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(CreateNamespaceError::ResourceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNamespaceError::ResourceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateNamespaceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNamespaceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7111,37 +7476,78 @@ impl CreateTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateTemplateError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTemplateError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(CreateTemplateError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTemplateError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateTemplateError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTemplateError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateTemplateError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateTemplateError::InvalidParameterValue,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateTemplateError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTemplateError::LimitExceeded,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateTemplateError::ResourceExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTemplateError::ResourceExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateTemplateError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTemplateError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateTemplateError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTemplateError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(CreateTemplateError::UnsupportedUserEdition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateTemplateError::UnsupportedUserEdition,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7189,31 +7595,62 @@ impl CreateTemplateAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(CreateTemplateAliasError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTemplateAliasError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateTemplateAliasError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTemplateAliasError::InternalFailure,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateTemplateAliasError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTemplateAliasError::LimitExceeded,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateTemplateAliasError::ResourceExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTemplateAliasError::ResourceExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateTemplateAliasError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateTemplateAliasError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateTemplateAliasError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTemplateAliasError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(CreateTemplateAliasError::UnsupportedUserEdition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateTemplateAliasError::UnsupportedUserEdition,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7261,30 +7698,70 @@ impl CreateThemeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateThemeError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateThemeError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateThemeError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateThemeError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateThemeError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateThemeError::InvalidParameterValue,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateThemeError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateThemeError::LimitExceeded,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateThemeError::ResourceExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateThemeError::ResourceExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateThemeError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateThemeError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateThemeError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateThemeError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(CreateThemeError::UnsupportedUserEdition(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateThemeError::UnsupportedUserEdition,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7333,34 +7810,70 @@ impl CreateThemeAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(CreateThemeAliasError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateThemeAliasError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(CreateThemeAliasError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateThemeAliasError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(CreateThemeAliasError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateThemeAliasError::InvalidParameterValue,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateThemeAliasError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateThemeAliasError::LimitExceeded,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateThemeAliasError::ResourceExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateThemeAliasError::ResourceExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateThemeAliasError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateThemeAliasError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateThemeAliasError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateThemeAliasError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(CreateThemeAliasError::UnsupportedUserEdition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateThemeAliasError::UnsupportedUserEdition,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7407,36 +7920,54 @@ impl DeleteAccountCustomizationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteAccountCustomizationError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteAccountCustomizationError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteAccountCustomizationError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteAccountCustomizationError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DeleteAccountCustomizationError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAccountCustomizationError::InvalidParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteAccountCustomizationError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteAccountCustomizationError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(
-                        DeleteAccountCustomizationError::ResourceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAccountCustomizationError::ResourceUnavailable,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteAccountCustomizationError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteAccountCustomizationError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7483,28 +8014,54 @@ impl DeleteAnalysisError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteAnalysisError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAnalysisError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteAnalysisError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAnalysisError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteAnalysisError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteAnalysisError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteAnalysisError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAnalysisError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteAnalysisError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAnalysisError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(DeleteAnalysisError::UnsupportedUserEdition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteAnalysisError::UnsupportedUserEdition,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7547,28 +8104,54 @@ impl DeleteDashboardError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteDashboardError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDashboardError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteDashboardError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDashboardError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteDashboardError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDashboardError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDashboardError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDashboardError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteDashboardError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDashboardError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(DeleteDashboardError::UnsupportedUserEdition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDashboardError::UnsupportedUserEdition,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7609,21 +8192,46 @@ impl DeleteDataSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteDataSetError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDataSetError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteDataSetError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDataSetError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteDataSetError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDataSetError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDataSetError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDataSetError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteDataSetError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDataSetError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7663,23 +8271,46 @@ impl DeleteDataSourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteDataSourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDataSourceError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteDataSourceError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDataSourceError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteDataSourceError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteDataSourceError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteDataSourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDataSourceError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteDataSourceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDataSourceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7723,27 +8354,62 @@ impl DeleteGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteGroupError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGroupError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteGroupError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGroupError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteGroupError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGroupError::InvalidParameterValue,
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(DeleteGroupError::PreconditionNotMet(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGroupError::PreconditionNotMet,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGroupError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(DeleteGroupError::ResourceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGroupError::ResourceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteGroupError::Throttling(err.msg))
-                }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGroupError::Throttling,
+                    )
+                }
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7789,37 +8455,62 @@ impl DeleteGroupMembershipError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteGroupMembershipError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGroupMembershipError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteGroupMembershipError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteGroupMembershipError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteGroupMembershipError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteGroupMembershipError::InvalidParameterValue,
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(DeleteGroupMembershipError::PreconditionNotMet(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteGroupMembershipError::PreconditionNotMet,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteGroupMembershipError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteGroupMembershipError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(DeleteGroupMembershipError::ResourceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteGroupMembershipError::ResourceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteGroupMembershipError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGroupMembershipError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7865,41 +8556,62 @@ impl DeleteIAMPolicyAssignmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteIAMPolicyAssignmentError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteIAMPolicyAssignmentError::AccessDenied,
+                    )
                 }
                 "ConcurrentUpdatingException" => {
-                    return RusotoError::Service(
-                        DeleteIAMPolicyAssignmentError::ConcurrentUpdating(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIAMPolicyAssignmentError::ConcurrentUpdating,
                     )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteIAMPolicyAssignmentError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteIAMPolicyAssignmentError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DeleteIAMPolicyAssignmentError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteIAMPolicyAssignmentError::InvalidParameterValue,
                     )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(DeleteIAMPolicyAssignmentError::ResourceExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteIAMPolicyAssignmentError::ResourceExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteIAMPolicyAssignmentError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteIAMPolicyAssignmentError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteIAMPolicyAssignmentError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteIAMPolicyAssignmentError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7947,29 +8659,62 @@ impl DeleteNamespaceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteNamespaceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNamespaceError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteNamespaceError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNamespaceError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteNamespaceError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteNamespaceError::InvalidParameterValue,
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(DeleteNamespaceError::PreconditionNotMet(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNamespaceError::PreconditionNotMet,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteNamespaceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNamespaceError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(DeleteNamespaceError::ResourceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNamespaceError::ResourceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteNamespaceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteNamespaceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8015,31 +8760,62 @@ impl DeleteTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteTemplateError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTemplateError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteTemplateError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTemplateError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteTemplateError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteTemplateError::InvalidParameterValue,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DeleteTemplateError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTemplateError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteTemplateError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTemplateError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteTemplateError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTemplateError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(DeleteTemplateError::UnsupportedUserEdition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteTemplateError::UnsupportedUserEdition,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8081,25 +8857,46 @@ impl DeleteTemplateAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteTemplateAliasError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTemplateAliasError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteTemplateAliasError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTemplateAliasError::InternalFailure,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteTemplateAliasError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteTemplateAliasError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteTemplateAliasError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTemplateAliasError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(DeleteTemplateAliasError::UnsupportedUserEdition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteTemplateAliasError::UnsupportedUserEdition,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8143,27 +8940,62 @@ impl DeleteThemeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteThemeError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThemeError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteThemeError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThemeError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteThemeError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThemeError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteThemeError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThemeError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteThemeError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThemeError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteThemeError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThemeError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(DeleteThemeError::UnsupportedUserEdition(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThemeError::UnsupportedUserEdition,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8207,28 +9039,54 @@ impl DeleteThemeAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteThemeAliasError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThemeAliasError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteThemeAliasError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThemeAliasError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteThemeAliasError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteThemeAliasError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteThemeAliasError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThemeAliasError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteThemeAliasError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteThemeAliasError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(DeleteThemeAliasError::UnsupportedUserEdition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteThemeAliasError::UnsupportedUserEdition,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8273,27 +9131,62 @@ impl DeleteUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteUserError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteUserError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DeleteUserError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserError::InvalidParameterValue,
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(DeleteUserError::PreconditionNotMet(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserError::PreconditionNotMet,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(DeleteUserError::ResourceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserError::ResourceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteUserError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8339,39 +9232,62 @@ impl DeleteUserByPrincipalIdError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteUserByPrincipalIdError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteUserByPrincipalIdError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DeleteUserByPrincipalIdError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteUserByPrincipalIdError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DeleteUserByPrincipalIdError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserByPrincipalIdError::InvalidParameterValue,
                     )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(DeleteUserByPrincipalIdError::PreconditionNotMet(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteUserByPrincipalIdError::PreconditionNotMet,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteUserByPrincipalIdError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteUserByPrincipalIdError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(DeleteUserByPrincipalIdError::ResourceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteUserByPrincipalIdError::ResourceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteUserByPrincipalIdError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserByPrincipalIdError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8419,36 +9335,54 @@ impl DescribeAccountCustomizationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeAccountCustomizationError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAccountCustomizationError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(
-                        DescribeAccountCustomizationError::InternalFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAccountCustomizationError::InternalFailure,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DescribeAccountCustomizationError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAccountCustomizationError::InvalidParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeAccountCustomizationError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAccountCustomizationError::ResourceNotFound,
                     )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(
-                        DescribeAccountCustomizationError::ResourceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAccountCustomizationError::ResourceUnavailable,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeAccountCustomizationError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAccountCustomizationError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8497,34 +9431,54 @@ impl DescribeAccountSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeAccountSettingsError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAccountSettingsError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeAccountSettingsError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAccountSettingsError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DescribeAccountSettingsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAccountSettingsError::InvalidParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeAccountSettingsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAccountSettingsError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(DescribeAccountSettingsError::ResourceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAccountSettingsError::ResourceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeAccountSettingsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAccountSettingsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8569,28 +9523,54 @@ impl DescribeAnalysisError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeAnalysisError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAnalysisError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeAnalysisError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAnalysisError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeAnalysisError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAnalysisError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeAnalysisError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAnalysisError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeAnalysisError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAnalysisError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(DescribeAnalysisError::UnsupportedUserEdition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAnalysisError::UnsupportedUserEdition,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8633,31 +9613,46 @@ impl DescribeAnalysisPermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeAnalysisPermissionsError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAnalysisPermissionsError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DescribeAnalysisPermissionsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAnalysisPermissionsError::InvalidParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeAnalysisPermissionsError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAnalysisPermissionsError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeAnalysisPermissionsError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAnalysisPermissionsError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(
-                        DescribeAnalysisPermissionsError::UnsupportedUserEdition(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAnalysisPermissionsError::UnsupportedUserEdition,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8703,28 +9698,54 @@ impl DescribeDashboardError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeDashboardError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDashboardError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeDashboardError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDashboardError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeDashboardError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDashboardError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDashboardError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDashboardError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDashboardError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDashboardError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(DescribeDashboardError::UnsupportedUserEdition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDashboardError::UnsupportedUserEdition,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8767,31 +9788,46 @@ impl DescribeDashboardPermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(
-                        DescribeDashboardPermissionsError::InternalFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDashboardPermissionsError::InternalFailure,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DescribeDashboardPermissionsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDashboardPermissionsError::InvalidParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeDashboardPermissionsError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDashboardPermissionsError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDashboardPermissionsError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDashboardPermissionsError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(
-                        DescribeDashboardPermissionsError::UnsupportedUserEdition(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDashboardPermissionsError::UnsupportedUserEdition,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8837,23 +9873,46 @@ impl DescribeDataSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeDataSetError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDataSetError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeDataSetError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDataSetError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeDataSetError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDataSetError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDataSetError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDataSetError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDataSetError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDataSetError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8895,31 +9954,46 @@ impl DescribeDataSetPermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeDataSetPermissionsError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDataSetPermissionsError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeDataSetPermissionsError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDataSetPermissionsError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DescribeDataSetPermissionsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDataSetPermissionsError::InvalidParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDataSetPermissionsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDataSetPermissionsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDataSetPermissionsError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDataSetPermissionsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -8961,23 +10035,46 @@ impl DescribeDataSourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeDataSourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDataSourceError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeDataSourceError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDataSourceError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeDataSourceError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDataSourceError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDataSourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDataSourceError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDataSourceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDataSourceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9019,31 +10116,46 @@ impl DescribeDataSourcePermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeDataSourcePermissionsError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDataSourcePermissionsError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(
-                        DescribeDataSourcePermissionsError::InternalFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDataSourcePermissionsError::InternalFailure,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DescribeDataSourcePermissionsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDataSourcePermissionsError::InvalidParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeDataSourcePermissionsError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDataSourcePermissionsError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDataSourcePermissionsError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDataSourcePermissionsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9093,27 +10205,62 @@ impl DescribeGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeGroupError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeGroupError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeGroupError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeGroupError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeGroupError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeGroupError::InvalidParameterValue,
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(DescribeGroupError::PreconditionNotMet(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeGroupError::PreconditionNotMet,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeGroupError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(DescribeGroupError::ResourceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeGroupError::ResourceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeGroupError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeGroupError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9159,36 +10306,54 @@ impl DescribeIAMPolicyAssignmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeIAMPolicyAssignmentError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeIAMPolicyAssignmentError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeIAMPolicyAssignmentError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeIAMPolicyAssignmentError::InternalFailure,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        DescribeIAMPolicyAssignmentError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeIAMPolicyAssignmentError::InvalidNextToken,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DescribeIAMPolicyAssignmentError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeIAMPolicyAssignmentError::InvalidParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeIAMPolicyAssignmentError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeIAMPolicyAssignmentError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeIAMPolicyAssignmentError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeIAMPolicyAssignmentError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9233,26 +10398,54 @@ impl DescribeIngestionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeIngestionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeIngestionError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeIngestionError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeIngestionError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeIngestionError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeIngestionError::InvalidParameterValue,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(DescribeIngestionError::ResourceExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeIngestionError::ResourceExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeIngestionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeIngestionError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeIngestionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeIngestionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9295,28 +10488,54 @@ impl DescribeNamespaceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeNamespaceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeNamespaceError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeNamespaceError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeNamespaceError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeNamespaceError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeNamespaceError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeNamespaceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeNamespaceError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(DescribeNamespaceError::ResourceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeNamespaceError::ResourceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeNamespaceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeNamespaceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9363,34 +10582,70 @@ impl DescribeTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeTemplateError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTemplateError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(DescribeTemplateError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTemplateError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeTemplateError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTemplateError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeTemplateError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeTemplateError::InvalidParameterValue,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(DescribeTemplateError::ResourceExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTemplateError::ResourceExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeTemplateError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTemplateError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeTemplateError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTemplateError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(DescribeTemplateError::UnsupportedUserEdition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeTemplateError::UnsupportedUserEdition,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9431,24 +10686,38 @@ impl DescribeTemplateAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeTemplateAliasError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeTemplateAliasError::InternalFailure,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeTemplateAliasError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeTemplateAliasError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeTemplateAliasError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTemplateAliasError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(
-                        DescribeTemplateAliasError::UnsupportedUserEdition(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTemplateAliasError::UnsupportedUserEdition,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9491,36 +10760,54 @@ impl DescribeTemplatePermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(DescribeTemplatePermissionsError::Conflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeTemplatePermissionsError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeTemplatePermissionsError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeTemplatePermissionsError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DescribeTemplatePermissionsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTemplatePermissionsError::InvalidParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeTemplatePermissionsError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTemplatePermissionsError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeTemplatePermissionsError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeTemplatePermissionsError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(
-                        DescribeTemplatePermissionsError::UnsupportedUserEdition(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTemplatePermissionsError::UnsupportedUserEdition,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9569,29 +10856,62 @@ impl DescribeThemeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeThemeError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeThemeError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeThemeError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeThemeError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeThemeError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeThemeError::InvalidParameterValue,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(DescribeThemeError::ResourceExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeThemeError::ResourceExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeThemeError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeThemeError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeThemeError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeThemeError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(DescribeThemeError::UnsupportedUserEdition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeThemeError::UnsupportedUserEdition,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9635,28 +10955,54 @@ impl DescribeThemeAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(DescribeThemeAliasError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeThemeAliasError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeThemeAliasError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeThemeAliasError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeThemeAliasError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeThemeAliasError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeThemeAliasError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeThemeAliasError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeThemeAliasError::Throttling(err.msg))
-                }
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeThemeAliasError::Throttling,
+                    )
+                }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(DescribeThemeAliasError::UnsupportedUserEdition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeThemeAliasError::UnsupportedUserEdition,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9699,34 +11045,54 @@ impl DescribeThemePermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeThemePermissionsError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeThemePermissionsError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeThemePermissionsError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeThemePermissionsError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        DescribeThemePermissionsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeThemePermissionsError::InvalidParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeThemePermissionsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeThemePermissionsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeThemePermissionsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeThemePermissionsError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(
-                        DescribeThemePermissionsError::UnsupportedUserEdition(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeThemePermissionsError::UnsupportedUserEdition,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9775,27 +11141,62 @@ impl DescribeUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeUserError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUserError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(DescribeUserError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUserError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(DescribeUserError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUserError::InvalidParameterValue,
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(DescribeUserError::PreconditionNotMet(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUserError::PreconditionNotMet,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUserError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(DescribeUserError::ResourceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUserError::ResourceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeUserError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeUserError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9851,60 +11252,102 @@ impl GetDashboardEmbedUrlError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetDashboardEmbedUrlError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDashboardEmbedUrlError::AccessDenied,
+                    )
                 }
                 "DomainNotWhitelistedException" => {
-                    return RusotoError::Service(GetDashboardEmbedUrlError::DomainNotWhitelisted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDashboardEmbedUrlError::DomainNotWhitelisted,
+                    )
                 }
                 "IdentityTypeNotSupportedException" => {
-                    return RusotoError::Service(
-                        GetDashboardEmbedUrlError::IdentityTypeNotSupported(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDashboardEmbedUrlError::IdentityTypeNotSupported,
                     )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetDashboardEmbedUrlError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDashboardEmbedUrlError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetDashboardEmbedUrlError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDashboardEmbedUrlError::InvalidParameterValue,
+                    )
                 }
                 "QuickSightUserNotFoundException" => {
-                    return RusotoError::Service(GetDashboardEmbedUrlError::QuickSightUserNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDashboardEmbedUrlError::QuickSightUserNotFound,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(GetDashboardEmbedUrlError::ResourceExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDashboardEmbedUrlError::ResourceExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetDashboardEmbedUrlError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDashboardEmbedUrlError::ResourceNotFound,
+                    )
                 }
                 "SessionLifetimeInMinutesInvalidException" => {
-                    return RusotoError::Service(
-                        GetDashboardEmbedUrlError::SessionLifetimeInMinutesInvalid(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDashboardEmbedUrlError::SessionLifetimeInMinutesInvalid,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetDashboardEmbedUrlError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDashboardEmbedUrlError::Throttling,
+                    )
                 }
                 "UnsupportedPricingPlanException" => {
-                    return RusotoError::Service(GetDashboardEmbedUrlError::UnsupportedPricingPlan(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDashboardEmbedUrlError::UnsupportedPricingPlan,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(GetDashboardEmbedUrlError::UnsupportedUserEdition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetDashboardEmbedUrlError::UnsupportedUserEdition,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -9963,41 +11406,78 @@ impl GetSessionEmbedUrlError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetSessionEmbedUrlError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSessionEmbedUrlError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(GetSessionEmbedUrlError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSessionEmbedUrlError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(GetSessionEmbedUrlError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSessionEmbedUrlError::InvalidParameterValue,
+                    )
                 }
                 "QuickSightUserNotFoundException" => {
-                    return RusotoError::Service(GetSessionEmbedUrlError::QuickSightUserNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSessionEmbedUrlError::QuickSightUserNotFound,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(GetSessionEmbedUrlError::ResourceExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSessionEmbedUrlError::ResourceExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetSessionEmbedUrlError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSessionEmbedUrlError::ResourceNotFound,
+                    )
                 }
                 "SessionLifetimeInMinutesInvalidException" => {
-                    return RusotoError::Service(
-                        GetSessionEmbedUrlError::SessionLifetimeInMinutesInvalid(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSessionEmbedUrlError::SessionLifetimeInMinutesInvalid,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetSessionEmbedUrlError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSessionEmbedUrlError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(GetSessionEmbedUrlError::UnsupportedUserEdition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSessionEmbedUrlError::UnsupportedUserEdition,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10041,18 +11521,38 @@ impl ListAnalysesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListAnalysesError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAnalysesError::InternalFailure,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListAnalysesError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAnalysesError::InvalidNextToken,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListAnalysesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAnalysesError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(ListAnalysesError::UnsupportedUserEdition(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAnalysesError::UnsupportedUserEdition,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10093,34 +11593,54 @@ impl ListDashboardVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListDashboardVersionsError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDashboardVersionsError::InternalFailure,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDashboardVersionsError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDashboardVersionsError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListDashboardVersionsError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDashboardVersionsError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDashboardVersionsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDashboardVersionsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDashboardVersionsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDashboardVersionsError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(
-                        ListDashboardVersionsError::UnsupportedUserEdition(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDashboardVersionsError::UnsupportedUserEdition,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10159,20 +11679,38 @@ impl ListDashboardsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListDashboardsError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDashboardsError::InternalFailure,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDashboardsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDashboardsError::InvalidNextToken,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDashboardsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDashboardsError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(ListDashboardsError::UnsupportedUserEdition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDashboardsError::UnsupportedUserEdition,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10211,21 +11749,46 @@ impl ListDataSetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListDataSetsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDataSetsError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListDataSetsError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDataSetsError::InternalFailure,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDataSetsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDataSetsError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListDataSetsError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDataSetsError::InvalidParameterValue,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDataSetsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDataSetsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10265,23 +11828,46 @@ impl ListDataSourcesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListDataSourcesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDataSourcesError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListDataSourcesError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDataSourcesError::InternalFailure,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListDataSourcesError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDataSourcesError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListDataSourcesError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListDataSourcesError::InvalidParameterValue,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDataSourcesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDataSourcesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10327,42 +11913,70 @@ impl ListGroupMembershipsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListGroupMembershipsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGroupMembershipsError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListGroupMembershipsError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListGroupMembershipsError::InternalFailure,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListGroupMembershipsError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListGroupMembershipsError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListGroupMembershipsError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListGroupMembershipsError::InvalidParameterValue,
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(ListGroupMembershipsError::PreconditionNotMet(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListGroupMembershipsError::PreconditionNotMet,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListGroupMembershipsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListGroupMembershipsError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(ListGroupMembershipsError::ResourceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListGroupMembershipsError::ResourceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListGroupMembershipsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGroupMembershipsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10411,30 +12025,70 @@ impl ListGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListGroupsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGroupsError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListGroupsError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGroupsError::InternalFailure,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListGroupsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGroupsError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListGroupsError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGroupsError::InvalidParameterValue,
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(ListGroupsError::PreconditionNotMet(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGroupsError::PreconditionNotMet,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListGroupsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGroupsError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(ListGroupsError::ResourceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGroupsError::ResourceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListGroupsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGroupsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10479,34 +12133,54 @@ impl ListIAMPolicyAssignmentsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListIAMPolicyAssignmentsError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListIAMPolicyAssignmentsError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListIAMPolicyAssignmentsError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListIAMPolicyAssignmentsError::InternalFailure,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListIAMPolicyAssignmentsError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListIAMPolicyAssignmentsError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        ListIAMPolicyAssignmentsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIAMPolicyAssignmentsError::InvalidParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListIAMPolicyAssignmentsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListIAMPolicyAssignmentsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListIAMPolicyAssignmentsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIAMPolicyAssignmentsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10555,41 +12229,62 @@ impl ListIAMPolicyAssignmentsForUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        ListIAMPolicyAssignmentsForUserError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIAMPolicyAssignmentsForUserError::AccessDenied,
                     )
                 }
                 "ConcurrentUpdatingException" => {
-                    return RusotoError::Service(
-                        ListIAMPolicyAssignmentsForUserError::ConcurrentUpdating(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIAMPolicyAssignmentsForUserError::ConcurrentUpdating,
                     )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(
-                        ListIAMPolicyAssignmentsForUserError::InternalFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIAMPolicyAssignmentsForUserError::InternalFailure,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        ListIAMPolicyAssignmentsForUserError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIAMPolicyAssignmentsForUserError::InvalidParameterValue,
                     )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(
-                        ListIAMPolicyAssignmentsForUserError::ResourceExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIAMPolicyAssignmentsForUserError::ResourceExists,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        ListIAMPolicyAssignmentsForUserError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIAMPolicyAssignmentsForUserError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListIAMPolicyAssignmentsForUserError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListIAMPolicyAssignmentsForUserError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10645,29 +12340,62 @@ impl ListIngestionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListIngestionsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIngestionsError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListIngestionsError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIngestionsError::InternalFailure,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListIngestionsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIngestionsError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListIngestionsError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListIngestionsError::InvalidParameterValue,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(ListIngestionsError::ResourceExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIngestionsError::ResourceExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListIngestionsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIngestionsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListIngestionsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListIngestionsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10715,32 +12443,70 @@ impl ListNamespacesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListNamespacesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListNamespacesError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListNamespacesError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListNamespacesError::InternalFailure,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListNamespacesError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListNamespacesError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListNamespacesError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListNamespacesError::InvalidParameterValue,
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(ListNamespacesError::PreconditionNotMet(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListNamespacesError::PreconditionNotMet,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListNamespacesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListNamespacesError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(ListNamespacesError::ResourceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListNamespacesError::ResourceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListNamespacesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListNamespacesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10783,25 +12549,46 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListTagsForResourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10841,27 +12628,46 @@ impl ListTemplateAliasesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTemplateAliasesError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTemplateAliasesError::InternalFailure,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListTemplateAliasesError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTemplateAliasesError::InvalidNextToken,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTemplateAliasesError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTemplateAliasesError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTemplateAliasesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTemplateAliasesError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(ListTemplateAliasesError::UnsupportedUserEdition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTemplateAliasesError::UnsupportedUserEdition,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10903,34 +12709,54 @@ impl ListTemplateVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTemplateVersionsError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTemplateVersionsError::InternalFailure,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListTemplateVersionsError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTemplateVersionsError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListTemplateVersionsError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTemplateVersionsError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTemplateVersionsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTemplateVersionsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTemplateVersionsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTemplateVersionsError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(ListTemplateVersionsError::UnsupportedUserEdition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTemplateVersionsError::UnsupportedUserEdition,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -10973,26 +12799,54 @@ impl ListTemplatesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListTemplatesError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTemplatesError::InternalFailure,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListTemplatesError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTemplatesError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListTemplatesError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTemplatesError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTemplatesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTemplatesError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTemplatesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTemplatesError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(ListTemplatesError::UnsupportedUserEdition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTemplatesError::UnsupportedUserEdition,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11037,31 +12891,62 @@ impl ListThemeAliasesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(ListThemeAliasesError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThemeAliasesError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListThemeAliasesError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThemeAliasesError::InternalFailure,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListThemeAliasesError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThemeAliasesError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListThemeAliasesError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListThemeAliasesError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListThemeAliasesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThemeAliasesError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListThemeAliasesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThemeAliasesError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(ListThemeAliasesError::UnsupportedUserEdition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListThemeAliasesError::UnsupportedUserEdition,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11107,31 +12992,62 @@ impl ListThemeVersionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListThemeVersionsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThemeVersionsError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListThemeVersionsError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThemeVersionsError::InternalFailure,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListThemeVersionsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThemeVersionsError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListThemeVersionsError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListThemeVersionsError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListThemeVersionsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThemeVersionsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListThemeVersionsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThemeVersionsError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(ListThemeVersionsError::UnsupportedUserEdition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListThemeVersionsError::UnsupportedUserEdition,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11177,27 +13093,62 @@ impl ListThemesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListThemesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThemesError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListThemesError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThemesError::InternalFailure,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListThemesError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThemesError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListThemesError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThemesError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListThemesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThemesError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListThemesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThemesError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(ListThemesError::UnsupportedUserEdition(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListThemesError::UnsupportedUserEdition,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11243,29 +13194,62 @@ impl ListUserGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListUserGroupsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUserGroupsError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListUserGroupsError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUserGroupsError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListUserGroupsError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListUserGroupsError::InvalidParameterValue,
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(ListUserGroupsError::PreconditionNotMet(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUserGroupsError::PreconditionNotMet,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListUserGroupsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUserGroupsError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(ListUserGroupsError::ResourceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUserGroupsError::ResourceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListUserGroupsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUserGroupsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11313,30 +13297,70 @@ impl ListUsersError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListUsersError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUsersError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(ListUsersError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUsersError::InternalFailure,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListUsersError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUsersError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(ListUsersError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUsersError::InvalidParameterValue,
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(ListUsersError::PreconditionNotMet(err.msg))
-                }
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUsersError::PreconditionNotMet,
+                    )
+                }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListUsersError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUsersError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(ListUsersError::ResourceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUsersError::ResourceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListUsersError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListUsersError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11387,33 +13411,78 @@ impl RegisterUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RegisterUserError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterUserError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(RegisterUserError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterUserError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(RegisterUserError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterUserError::InvalidParameterValue,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RegisterUserError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterUserError::LimitExceeded,
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(RegisterUserError::PreconditionNotMet(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterUserError::PreconditionNotMet,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(RegisterUserError::ResourceExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterUserError::ResourceExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RegisterUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterUserError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(RegisterUserError::ResourceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterUserError::ResourceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RegisterUserError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterUserError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11459,28 +13528,54 @@ impl RestoreAnalysisError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(RestoreAnalysisError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RestoreAnalysisError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(RestoreAnalysisError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RestoreAnalysisError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(RestoreAnalysisError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RestoreAnalysisError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RestoreAnalysisError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RestoreAnalysisError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RestoreAnalysisError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RestoreAnalysisError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(RestoreAnalysisError::UnsupportedUserEdition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RestoreAnalysisError::UnsupportedUserEdition,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11523,28 +13618,54 @@ impl SearchAnalysesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(SearchAnalysesError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchAnalysesError::InternalFailure,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(SearchAnalysesError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchAnalysesError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(SearchAnalysesError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SearchAnalysesError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SearchAnalysesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchAnalysesError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SearchAnalysesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchAnalysesError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(SearchAnalysesError::UnsupportedUserEdition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SearchAnalysesError::UnsupportedUserEdition,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11587,28 +13708,54 @@ impl SearchDashboardsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailureException" => {
-                    return RusotoError::Service(SearchDashboardsError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchDashboardsError::InternalFailure,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(SearchDashboardsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchDashboardsError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(SearchDashboardsError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SearchDashboardsError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SearchDashboardsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchDashboardsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SearchDashboardsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchDashboardsError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(SearchDashboardsError::UnsupportedUserEdition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SearchDashboardsError::UnsupportedUserEdition,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11651,24 +13798,54 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(TagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(TagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidParameterValue,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(TagResourceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11709,21 +13886,46 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UntagResourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UntagResourceError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UntagResourceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11767,36 +13969,54 @@ impl UpdateAccountCustomizationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateAccountCustomizationError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAccountCustomizationError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateAccountCustomizationError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAccountCustomizationError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        UpdateAccountCustomizationError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAccountCustomizationError::InvalidParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateAccountCustomizationError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAccountCustomizationError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(
-                        UpdateAccountCustomizationError::ResourceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAccountCustomizationError::ResourceUnavailable,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateAccountCustomizationError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAccountCustomizationError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11843,32 +14063,54 @@ impl UpdateAccountSettingsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateAccountSettingsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAccountSettingsError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateAccountSettingsError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAccountSettingsError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateAccountSettingsError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAccountSettingsError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateAccountSettingsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAccountSettingsError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(UpdateAccountSettingsError::ResourceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAccountSettingsError::ResourceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateAccountSettingsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAccountSettingsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11913,31 +14155,62 @@ impl UpdateAnalysisError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateAnalysisError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAnalysisError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateAnalysisError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAnalysisError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateAnalysisError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAnalysisError::InvalidParameterValue,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(UpdateAnalysisError::ResourceExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAnalysisError::ResourceExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateAnalysisError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAnalysisError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateAnalysisError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAnalysisError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(UpdateAnalysisError::UnsupportedUserEdition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAnalysisError::UnsupportedUserEdition,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -11981,34 +14254,54 @@ impl UpdateAnalysisPermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateAnalysisPermissionsError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAnalysisPermissionsError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateAnalysisPermissionsError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAnalysisPermissionsError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        UpdateAnalysisPermissionsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAnalysisPermissionsError::InvalidParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateAnalysisPermissionsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAnalysisPermissionsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateAnalysisPermissionsError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAnalysisPermissionsError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(
-                        UpdateAnalysisPermissionsError::UnsupportedUserEdition(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateAnalysisPermissionsError::UnsupportedUserEdition,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12057,31 +14350,62 @@ impl UpdateDashboardError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDashboardError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDashboardError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateDashboardError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDashboardError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateDashboardError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDashboardError::InvalidParameterValue,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateDashboardError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDashboardError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDashboardError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDashboardError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDashboardError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDashboardError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(UpdateDashboardError::UnsupportedUserEdition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDashboardError::UnsupportedUserEdition,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12127,34 +14451,54 @@ impl UpdateDashboardPermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDashboardPermissionsError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDashboardPermissionsError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateDashboardPermissionsError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDashboardPermissionsError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        UpdateDashboardPermissionsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDashboardPermissionsError::InvalidParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDashboardPermissionsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDashboardPermissionsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDashboardPermissionsError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDashboardPermissionsError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(
-                        UpdateDashboardPermissionsError::UnsupportedUserEdition(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDashboardPermissionsError::UnsupportedUserEdition,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12203,36 +14547,54 @@ impl UpdateDashboardPublishedVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDashboardPublishedVersionError::Conflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDashboardPublishedVersionError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(
-                        UpdateDashboardPublishedVersionError::InternalFailure(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDashboardPublishedVersionError::InternalFailure,
                     )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        UpdateDashboardPublishedVersionError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDashboardPublishedVersionError::InvalidParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateDashboardPublishedVersionError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDashboardPublishedVersionError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDashboardPublishedVersionError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDashboardPublishedVersionError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(
-                        UpdateDashboardPublishedVersionError::UnsupportedUserEdition(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDashboardPublishedVersionError::UnsupportedUserEdition,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12287,32 +14649,70 @@ impl UpdateDataSetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateDataSetError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDataSetError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDataSetError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDataSetError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateDataSetError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDataSetError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateDataSetError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDataSetError::InvalidParameterValue,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateDataSetError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDataSetError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDataSetError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDataSetError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDataSetError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDataSetError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(UpdateDataSetError::UnsupportedUserEdition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDataSetError::UnsupportedUserEdition,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12357,32 +14757,54 @@ impl UpdateDataSetPermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateDataSetPermissionsError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDataSetPermissionsError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDataSetPermissionsError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDataSetPermissionsError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateDataSetPermissionsError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDataSetPermissionsError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        UpdateDataSetPermissionsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDataSetPermissionsError::InvalidParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDataSetPermissionsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDataSetPermissionsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDataSetPermissionsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDataSetPermissionsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12427,26 +14849,54 @@ impl UpdateDataSourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateDataSourceError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDataSourceError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDataSourceError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDataSourceError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateDataSourceError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDataSourceError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateDataSourceError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDataSourceError::InvalidParameterValue,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateDataSourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDataSourceError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDataSourceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDataSourceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12491,36 +14941,54 @@ impl UpdateDataSourcePermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateDataSourcePermissionsError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDataSourcePermissionsError::AccessDenied,
+                    )
                 }
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateDataSourcePermissionsError::Conflict(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDataSourcePermissionsError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateDataSourcePermissionsError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDataSourcePermissionsError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        UpdateDataSourcePermissionsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDataSourcePermissionsError::InvalidParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateDataSourcePermissionsError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDataSourcePermissionsError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateDataSourcePermissionsError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDataSourcePermissionsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12567,27 +15035,62 @@ impl UpdateGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateGroupError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGroupError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateGroupError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGroupError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateGroupError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGroupError::InvalidParameterValue,
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(UpdateGroupError::PreconditionNotMet(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGroupError::PreconditionNotMet,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGroupError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(UpdateGroupError::ResourceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGroupError::ResourceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateGroupError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGroupError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12633,41 +15136,62 @@ impl UpdateIAMPolicyAssignmentError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateIAMPolicyAssignmentError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateIAMPolicyAssignmentError::AccessDenied,
+                    )
                 }
                 "ConcurrentUpdatingException" => {
-                    return RusotoError::Service(
-                        UpdateIAMPolicyAssignmentError::ConcurrentUpdating(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIAMPolicyAssignmentError::ConcurrentUpdating,
                     )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateIAMPolicyAssignmentError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateIAMPolicyAssignmentError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        UpdateIAMPolicyAssignmentError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateIAMPolicyAssignmentError::InvalidParameterValue,
                     )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(UpdateIAMPolicyAssignmentError::ResourceExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateIAMPolicyAssignmentError::ResourceExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateIAMPolicyAssignmentError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateIAMPolicyAssignmentError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateIAMPolicyAssignmentError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateIAMPolicyAssignmentError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12717,34 +15241,70 @@ impl UpdateTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateTemplateError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTemplateError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateTemplateError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTemplateError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateTemplateError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateTemplateError::InvalidParameterValue,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateTemplateError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTemplateError::LimitExceeded,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(UpdateTemplateError::ResourceExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTemplateError::ResourceExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateTemplateError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTemplateError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateTemplateError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTemplateError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(UpdateTemplateError::UnsupportedUserEdition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateTemplateError::UnsupportedUserEdition,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12787,25 +15347,46 @@ impl UpdateTemplateAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateTemplateAliasError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTemplateAliasError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateTemplateAliasError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTemplateAliasError::InternalFailure,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateTemplateAliasError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateTemplateAliasError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateTemplateAliasError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTemplateAliasError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(UpdateTemplateAliasError::UnsupportedUserEdition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateTemplateAliasError::UnsupportedUserEdition,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12847,34 +15428,54 @@ impl UpdateTemplatePermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateTemplatePermissionsError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTemplatePermissionsError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateTemplatePermissionsError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateTemplatePermissionsError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        UpdateTemplatePermissionsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTemplatePermissionsError::InvalidParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateTemplatePermissionsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateTemplatePermissionsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateTemplatePermissionsError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateTemplatePermissionsError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(
-                        UpdateTemplatePermissionsError::UnsupportedUserEdition(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTemplatePermissionsError::UnsupportedUserEdition,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12925,30 +15526,70 @@ impl UpdateThemeError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateThemeError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThemeError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateThemeError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThemeError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateThemeError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThemeError::InvalidParameterValue,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateThemeError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThemeError::LimitExceeded,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(UpdateThemeError::ResourceExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThemeError::ResourceExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateThemeError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThemeError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateThemeError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThemeError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(UpdateThemeError::UnsupportedUserEdition(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThemeError::UnsupportedUserEdition,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -12995,31 +15636,62 @@ impl UpdateThemeAliasError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateThemeAliasError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThemeAliasError::Conflict,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateThemeAliasError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThemeAliasError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateThemeAliasError::InvalidParameterValue(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateThemeAliasError::InvalidParameterValue,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(UpdateThemeAliasError::ResourceExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThemeAliasError::ResourceExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateThemeAliasError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThemeAliasError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateThemeAliasError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThemeAliasError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(UpdateThemeAliasError::UnsupportedUserEdition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateThemeAliasError::UnsupportedUserEdition,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13063,32 +15735,54 @@ impl UpdateThemePermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateThemePermissionsError::AccessDenied(err.msg))
-                }
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThemePermissionsError::AccessDenied,
+                    )
+                }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateThemePermissionsError::InternalFailure(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateThemePermissionsError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(
-                        UpdateThemePermissionsError::InvalidParameterValue(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThemePermissionsError::InvalidParameterValue,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateThemePermissionsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateThemePermissionsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateThemePermissionsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThemePermissionsError::Throttling,
+                    )
                 }
                 "UnsupportedUserEditionException" => {
-                    return RusotoError::Service(
-                        UpdateThemePermissionsError::UnsupportedUserEdition(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateThemePermissionsError::UnsupportedUserEdition,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13135,27 +15829,62 @@ impl UpdateUserError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateUserError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserError::AccessDenied,
+                    )
                 }
                 "InternalFailureException" => {
-                    return RusotoError::Service(UpdateUserError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserError::InternalFailure,
+                    )
                 }
                 "InvalidParameterValueException" => {
-                    return RusotoError::Service(UpdateUserError::InvalidParameterValue(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserError::InvalidParameterValue,
+                    )
                 }
                 "PreconditionNotMetException" => {
-                    return RusotoError::Service(UpdateUserError::PreconditionNotMet(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserError::PreconditionNotMet,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateUserError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(UpdateUserError::ResourceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserError::ResourceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateUserError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -13793,11 +16522,12 @@ pub struct QuicksightClient {
 impl QuicksightClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> QuicksightClient {
         QuicksightClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "QuickSight"),
         }
     }
 
@@ -13812,12 +16542,32 @@ impl QuicksightClient {
     {
         QuicksightClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "QuickSight"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> QuicksightClient {
-        QuicksightClient { client, region }
+        QuicksightClient {
+            client,
+            region: region_for_service(region, "QuickSight"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> QuicksightClient {
+        QuicksightClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("QuickSight"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> QuicksightClient {
+        QuicksightClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -13837,6 +16587,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("CancelIngestion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -13870,6 +16621,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateAccountCustomization");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13912,6 +16664,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateAnalysis");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13948,6 +16701,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateDashboard");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -13983,6 +16737,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateDataSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14018,6 +16773,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateDataSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14054,6 +16810,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14086,6 +16843,7 @@ impl Quicksight for QuicksightClient {
         let request_uri = format!("/accounts/{aws_account_id}/namespaces/{namespace}/groups/{group_name}/members/{member_name}", aws_account_id = input.aws_account_id, group_name = input.group_name, member_name = input.member_name, namespace = input.namespace);
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateGroupMembership");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14120,6 +16878,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateIAMPolicyAssignment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14157,6 +16916,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateIngestion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14189,6 +16949,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateNamespace");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14225,6 +16986,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14262,6 +17024,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateTemplateAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14298,6 +17061,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateTheme");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14335,6 +17099,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("CreateThemeAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -14371,6 +17136,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteAccountCustomization");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -14410,6 +17176,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteAnalysis");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -14452,6 +17219,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteDashboard");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -14491,6 +17259,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteDataSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14524,6 +17293,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteDataSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14558,6 +17328,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14587,6 +17358,7 @@ impl Quicksight for QuicksightClient {
         let request_uri = format!("/accounts/{aws_account_id}/namespaces/{namespace}/groups/{group_name}/members/{member_name}", aws_account_id = input.aws_account_id, group_name = input.group_name, member_name = input.member_name, namespace = input.namespace);
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteGroupMembership");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14617,6 +17389,7 @@ impl Quicksight for QuicksightClient {
         let request_uri = format!("/accounts/{aws_account_id}/namespace/{namespace}/iam-policy-assignments/{assignment_name}", assignment_name = input.assignment_name, aws_account_id = input.aws_account_id, namespace = input.namespace);
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteIAMPolicyAssignment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14650,6 +17423,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteNamespace");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14683,6 +17457,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -14723,6 +17498,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteTemplateAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14756,6 +17532,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteTheme");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -14796,6 +17573,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteThemeAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14830,6 +17608,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14864,6 +17643,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("DeleteUserByPrincipalId");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14897,6 +17677,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeAccountCustomization");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -14938,6 +17719,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeAccountSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -14971,6 +17753,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeAnalysis");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15005,6 +17788,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeAnalysisPermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15038,6 +17822,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeDashboard");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -15081,6 +17866,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeDashboardPermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15114,6 +17900,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeDataSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15148,6 +17935,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeDataSetPermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15181,6 +17969,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeDataSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15217,6 +18006,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeDataSourcePermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15251,6 +18041,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15281,6 +18072,7 @@ impl Quicksight for QuicksightClient {
         let request_uri = format!("/accounts/{aws_account_id}/namespaces/{namespace}/iam-policy-assignments/{assignment_name}", assignment_name = input.assignment_name, aws_account_id = input.aws_account_id, namespace = input.namespace);
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeIAMPolicyAssignment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15315,6 +18107,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeIngestion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15348,6 +18141,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeNamespace");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15381,6 +18175,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -15424,6 +18219,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeTemplateAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15458,6 +18254,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeTemplatePermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15491,6 +18288,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeTheme");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -15534,6 +18332,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeThemeAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15567,6 +18366,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeThemePermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15601,6 +18401,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("DescribeUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -15634,6 +18435,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("GetDashboardEmbedUrl");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -15693,6 +18495,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("GetSessionEmbedUrl");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -15737,6 +18540,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListAnalyses");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -15779,6 +18583,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListDashboardVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -15820,6 +18625,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListDashboards");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -15861,6 +18667,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListDataSets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -15902,6 +18709,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListDataSources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -15945,6 +18753,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListGroupMemberships");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -15987,6 +18796,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -16029,6 +18839,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListIAMPolicyAssignments");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -16073,6 +18884,7 @@ impl Quicksight for QuicksightClient {
         let request_uri = format!("/accounts/{aws_account_id}/namespaces/{namespace}/users/{user_name}/iam-policy-assignments", aws_account_id = input.aws_account_id, namespace = input.namespace, user_name = input.user_name);
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListIAMPolicyAssignmentsForUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -16117,6 +18929,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListIngestions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -16158,6 +18971,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListNamespaces");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -16199,6 +19013,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -16232,6 +19047,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListTemplateAliases");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -16274,6 +19090,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListTemplateVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -16315,6 +19132,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListTemplates");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -16357,6 +19175,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListThemeAliases");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -16399,6 +19218,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListThemeVersions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -16440,6 +19260,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListThemes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -16486,6 +19307,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListUserGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -16528,6 +19350,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("GET", "quicksight", &self.region, &request_uri);
+        request.set_operation("ListUsers");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -16570,6 +19393,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("RegisterUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -16606,6 +19430,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("RestoreAnalysis");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -16638,6 +19463,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("SearchAnalyses");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -16673,6 +19499,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("SearchDashboards");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -16708,6 +19535,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -16743,6 +19571,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "quicksight", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -16782,6 +19611,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateAccountCustomization");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -16823,6 +19653,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateAccountSettings");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -16859,6 +19690,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateAnalysis");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -16896,6 +19728,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateAnalysisPermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -16932,6 +19765,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateDashboard");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -16968,6 +19802,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateDashboardPermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17008,6 +19843,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateDashboardPublishedVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -17043,6 +19879,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateDataSet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17079,6 +19916,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateDataSetPermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17115,6 +19953,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateDataSource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17152,6 +19991,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("POST", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateDataSourcePermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17189,6 +20029,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17222,6 +20063,7 @@ impl Quicksight for QuicksightClient {
         let request_uri = format!("/accounts/{aws_account_id}/namespaces/{namespace}/iam-policy-assignments/{assignment_name}", assignment_name = input.assignment_name, aws_account_id = input.aws_account_id, namespace = input.namespace);
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateIAMPolicyAssignment");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17258,6 +20100,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17295,6 +20138,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateTemplateAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17332,6 +20176,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateTemplatePermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17368,6 +20213,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateTheme");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17405,6 +20251,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateThemeAlias");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17441,6 +20288,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateThemePermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17478,6 +20326,7 @@ impl Quicksight for QuicksightClient {
         );
 
         let mut request = SignedRequest::new("PUT", "quicksight", &self.region, &request_uri);
+        request.set_operation("UpdateUser");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -17501,3 +20350,937 @@ impl Quicksight for QuicksightClient {
         }
     }
 }
+
+/// A blocking client for the Amazon QuickSight API, running the calls of a `QuicksightClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `QuicksightClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct QuicksightBlockingClient {
+    client: QuicksightClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl QuicksightBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> QuicksightBlockingClient {
+        QuicksightBlockingClient::from_client(QuicksightClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> QuicksightBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        QuicksightBlockingClient::from_client(QuicksightClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> QuicksightBlockingClient {
+        QuicksightBlockingClient::from_client(QuicksightClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> QuicksightBlockingClient {
+        QuicksightBlockingClient::from_client(QuicksightClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: QuicksightClient) -> QuicksightBlockingClient {
+        QuicksightBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> QuicksightBlockingClient {
+        QuicksightBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> QuicksightBlockingClient {
+        QuicksightBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Quicksight::cancel_ingestion`].
+    pub fn cancel_ingestion(
+        &self,
+        input: CancelIngestionRequest,
+    ) -> Result<CancelIngestionResponse, RusotoError<CancelIngestionError>> {
+        self.runtime.block_on(self.client.cancel_ingestion(input))
+    }
+
+    /// Blocking version of [`Quicksight::create_account_customization`].
+    pub fn create_account_customization(
+        &self,
+        input: CreateAccountCustomizationRequest,
+    ) -> Result<CreateAccountCustomizationResponse, RusotoError<CreateAccountCustomizationError>>
+    {
+        self.runtime
+            .block_on(self.client.create_account_customization(input))
+    }
+
+    /// Blocking version of [`Quicksight::create_analysis`].
+    pub fn create_analysis(
+        &self,
+        input: CreateAnalysisRequest,
+    ) -> Result<CreateAnalysisResponse, RusotoError<CreateAnalysisError>> {
+        self.runtime.block_on(self.client.create_analysis(input))
+    }
+
+    /// Blocking version of [`Quicksight::create_dashboard`].
+    pub fn create_dashboard(
+        &self,
+        input: CreateDashboardRequest,
+    ) -> Result<CreateDashboardResponse, RusotoError<CreateDashboardError>> {
+        self.runtime.block_on(self.client.create_dashboard(input))
+    }
+
+    /// Blocking version of [`Quicksight::create_data_set`].
+    pub fn create_data_set(
+        &self,
+        input: CreateDataSetRequest,
+    ) -> Result<CreateDataSetResponse, RusotoError<CreateDataSetError>> {
+        self.runtime.block_on(self.client.create_data_set(input))
+    }
+
+    /// Blocking version of [`Quicksight::create_data_source`].
+    pub fn create_data_source(
+        &self,
+        input: CreateDataSourceRequest,
+    ) -> Result<CreateDataSourceResponse, RusotoError<CreateDataSourceError>> {
+        self.runtime.block_on(self.client.create_data_source(input))
+    }
+
+    /// Blocking version of [`Quicksight::create_group`].
+    pub fn create_group(
+        &self,
+        input: CreateGroupRequest,
+    ) -> Result<CreateGroupResponse, RusotoError<CreateGroupError>> {
+        self.runtime.block_on(self.client.create_group(input))
+    }
+
+    /// Blocking version of [`Quicksight::create_group_membership`].
+    pub fn create_group_membership(
+        &self,
+        input: CreateGroupMembershipRequest,
+    ) -> Result<CreateGroupMembershipResponse, RusotoError<CreateGroupMembershipError>> {
+        self.runtime
+            .block_on(self.client.create_group_membership(input))
+    }
+
+    /// Blocking version of [`Quicksight::create_iam_policy_assignment`].
+    pub fn create_iam_policy_assignment(
+        &self,
+        input: CreateIAMPolicyAssignmentRequest,
+    ) -> Result<CreateIAMPolicyAssignmentResponse, RusotoError<CreateIAMPolicyAssignmentError>>
+    {
+        self.runtime
+            .block_on(self.client.create_iam_policy_assignment(input))
+    }
+
+    /// Blocking version of [`Quicksight::create_ingestion`].
+    pub fn create_ingestion(
+        &self,
+        input: CreateIngestionRequest,
+    ) -> Result<CreateIngestionResponse, RusotoError<CreateIngestionError>> {
+        self.runtime.block_on(self.client.create_ingestion(input))
+    }
+
+    /// Blocking version of [`Quicksight::create_namespace`].
+    pub fn create_namespace(
+        &self,
+        input: CreateNamespaceRequest,
+    ) -> Result<CreateNamespaceResponse, RusotoError<CreateNamespaceError>> {
+        self.runtime.block_on(self.client.create_namespace(input))
+    }
+
+    /// Blocking version of [`Quicksight::create_template`].
+    pub fn create_template(
+        &self,
+        input: CreateTemplateRequest,
+    ) -> Result<CreateTemplateResponse, RusotoError<CreateTemplateError>> {
+        self.runtime.block_on(self.client.create_template(input))
+    }
+
+    /// Blocking version of [`Quicksight::create_template_alias`].
+    pub fn create_template_alias(
+        &self,
+        input: CreateTemplateAliasRequest,
+    ) -> Result<CreateTemplateAliasResponse, RusotoError<CreateTemplateAliasError>> {
+        self.runtime
+            .block_on(self.client.create_template_alias(input))
+    }
+
+    /// Blocking version of [`Quicksight::create_theme`].
+    pub fn create_theme(
+        &self,
+        input: CreateThemeRequest,
+    ) -> Result<CreateThemeResponse, RusotoError<CreateThemeError>> {
+        self.runtime.block_on(self.client.create_theme(input))
+    }
+
+    /// Blocking version of [`Quicksight::create_theme_alias`].
+    pub fn create_theme_alias(
+        &self,
+        input: CreateThemeAliasRequest,
+    ) -> Result<CreateThemeAliasResponse, RusotoError<CreateThemeAliasError>> {
+        self.runtime.block_on(self.client.create_theme_alias(input))
+    }
+
+    /// Blocking version of [`Quicksight::delete_account_customization`].
+    pub fn delete_account_customization(
+        &self,
+        input: DeleteAccountCustomizationRequest,
+    ) -> Result<DeleteAccountCustomizationResponse, RusotoError<DeleteAccountCustomizationError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_account_customization(input))
+    }
+
+    /// Blocking version of [`Quicksight::delete_analysis`].
+    pub fn delete_analysis(
+        &self,
+        input: DeleteAnalysisRequest,
+    ) -> Result<DeleteAnalysisResponse, RusotoError<DeleteAnalysisError>> {
+        self.runtime.block_on(self.client.delete_analysis(input))
+    }
+
+    /// Blocking version of [`Quicksight::delete_dashboard`].
+    pub fn delete_dashboard(
+        &self,
+        input: DeleteDashboardRequest,
+    ) -> Result<DeleteDashboardResponse, RusotoError<DeleteDashboardError>> {
+        self.runtime.block_on(self.client.delete_dashboard(input))
+    }
+
+    /// Blocking version of [`Quicksight::delete_data_set`].
+    pub fn delete_data_set(
+        &self,
+        input: DeleteDataSetRequest,
+    ) -> Result<DeleteDataSetResponse, RusotoError<DeleteDataSetError>> {
+        self.runtime.block_on(self.client.delete_data_set(input))
+    }
+
+    /// Blocking version of [`Quicksight::delete_data_source`].
+    pub fn delete_data_source(
+        &self,
+        input: DeleteDataSourceRequest,
+    ) -> Result<DeleteDataSourceResponse, RusotoError<DeleteDataSourceError>> {
+        self.runtime.block_on(self.client.delete_data_source(input))
+    }
+
+    /// Blocking version of [`Quicksight::delete_group`].
+    pub fn delete_group(
+        &self,
+        input: DeleteGroupRequest,
+    ) -> Result<DeleteGroupResponse, RusotoError<DeleteGroupError>> {
+        self.runtime.block_on(self.client.delete_group(input))
+    }
+
+    /// Blocking version of [`Quicksight::delete_group_membership`].
+    pub fn delete_group_membership(
+        &self,
+        input: DeleteGroupMembershipRequest,
+    ) -> Result<DeleteGroupMembershipResponse, RusotoError<DeleteGroupMembershipError>> {
+        self.runtime
+            .block_on(self.client.delete_group_membership(input))
+    }
+
+    /// Blocking version of [`Quicksight::delete_iam_policy_assignment`].
+    pub fn delete_iam_policy_assignment(
+        &self,
+        input: DeleteIAMPolicyAssignmentRequest,
+    ) -> Result<DeleteIAMPolicyAssignmentResponse, RusotoError<DeleteIAMPolicyAssignmentError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_iam_policy_assignment(input))
+    }
+
+    /// Blocking version of [`Quicksight::delete_namespace`].
+    pub fn delete_namespace(
+        &self,
+        input: DeleteNamespaceRequest,
+    ) -> Result<DeleteNamespaceResponse, RusotoError<DeleteNamespaceError>> {
+        self.runtime.block_on(self.client.delete_namespace(input))
+    }
+
+    /// Blocking version of [`Quicksight::delete_template`].
+    pub fn delete_template(
+        &self,
+        input: DeleteTemplateRequest,
+    ) -> Result<DeleteTemplateResponse, RusotoError<DeleteTemplateError>> {
+        self.runtime.block_on(self.client.delete_template(input))
+    }
+
+    /// Blocking version of [`Quicksight::delete_template_alias`].
+    pub fn delete_template_alias(
+        &self,
+        input: DeleteTemplateAliasRequest,
+    ) -> Result<DeleteTemplateAliasResponse, RusotoError<DeleteTemplateAliasError>> {
+        self.runtime
+            .block_on(self.client.delete_template_alias(input))
+    }
+
+    /// Blocking version of [`Quicksight::delete_theme`].
+    pub fn delete_theme(
+        &self,
+        input: DeleteThemeRequest,
+    ) -> Result<DeleteThemeResponse, RusotoError<DeleteThemeError>> {
+        self.runtime.block_on(self.client.delete_theme(input))
+    }
+
+    /// Blocking version of [`Quicksight::delete_theme_alias`].
+    pub fn delete_theme_alias(
+        &self,
+        input: DeleteThemeAliasRequest,
+    ) -> Result<DeleteThemeAliasResponse, RusotoError<DeleteThemeAliasError>> {
+        self.runtime.block_on(self.client.delete_theme_alias(input))
+    }
+
+    /// Blocking version of [`Quicksight::delete_user`].
+    pub fn delete_user(
+        &self,
+        input: DeleteUserRequest,
+    ) -> Result<DeleteUserResponse, RusotoError<DeleteUserError>> {
+        self.runtime.block_on(self.client.delete_user(input))
+    }
+
+    /// Blocking version of [`Quicksight::delete_user_by_principal_id`].
+    pub fn delete_user_by_principal_id(
+        &self,
+        input: DeleteUserByPrincipalIdRequest,
+    ) -> Result<DeleteUserByPrincipalIdResponse, RusotoError<DeleteUserByPrincipalIdError>> {
+        self.runtime
+            .block_on(self.client.delete_user_by_principal_id(input))
+    }
+
+    /// Blocking version of [`Quicksight::describe_account_customization`].
+    pub fn describe_account_customization(
+        &self,
+        input: DescribeAccountCustomizationRequest,
+    ) -> Result<DescribeAccountCustomizationResponse, RusotoError<DescribeAccountCustomizationError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_account_customization(input))
+    }
+
+    /// Blocking version of [`Quicksight::describe_account_settings`].
+    pub fn describe_account_settings(
+        &self,
+        input: DescribeAccountSettingsRequest,
+    ) -> Result<DescribeAccountSettingsResponse, RusotoError<DescribeAccountSettingsError>> {
+        self.runtime
+            .block_on(self.client.describe_account_settings(input))
+    }
+
+    /// Blocking version of [`Quicksight::describe_analysis`].
+    pub fn describe_analysis(
+        &self,
+        input: DescribeAnalysisRequest,
+    ) -> Result<DescribeAnalysisResponse, RusotoError<DescribeAnalysisError>> {
+        self.runtime.block_on(self.client.describe_analysis(input))
+    }
+
+    /// Blocking version of [`Quicksight::describe_analysis_permissions`].
+    pub fn describe_analysis_permissions(
+        &self,
+        input: DescribeAnalysisPermissionsRequest,
+    ) -> Result<DescribeAnalysisPermissionsResponse, RusotoError<DescribeAnalysisPermissionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_analysis_permissions(input))
+    }
+
+    /// Blocking version of [`Quicksight::describe_dashboard`].
+    pub fn describe_dashboard(
+        &self,
+        input: DescribeDashboardRequest,
+    ) -> Result<DescribeDashboardResponse, RusotoError<DescribeDashboardError>> {
+        self.runtime.block_on(self.client.describe_dashboard(input))
+    }
+
+    /// Blocking version of [`Quicksight::describe_dashboard_permissions`].
+    pub fn describe_dashboard_permissions(
+        &self,
+        input: DescribeDashboardPermissionsRequest,
+    ) -> Result<DescribeDashboardPermissionsResponse, RusotoError<DescribeDashboardPermissionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_dashboard_permissions(input))
+    }
+
+    /// Blocking version of [`Quicksight::describe_data_set`].
+    pub fn describe_data_set(
+        &self,
+        input: DescribeDataSetRequest,
+    ) -> Result<DescribeDataSetResponse, RusotoError<DescribeDataSetError>> {
+        self.runtime.block_on(self.client.describe_data_set(input))
+    }
+
+    /// Blocking version of [`Quicksight::describe_data_set_permissions`].
+    pub fn describe_data_set_permissions(
+        &self,
+        input: DescribeDataSetPermissionsRequest,
+    ) -> Result<DescribeDataSetPermissionsResponse, RusotoError<DescribeDataSetPermissionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_data_set_permissions(input))
+    }
+
+    /// Blocking version of [`Quicksight::describe_data_source`].
+    pub fn describe_data_source(
+        &self,
+        input: DescribeDataSourceRequest,
+    ) -> Result<DescribeDataSourceResponse, RusotoError<DescribeDataSourceError>> {
+        self.runtime
+            .block_on(self.client.describe_data_source(input))
+    }
+
+    /// Blocking version of [`Quicksight::describe_data_source_permissions`].
+    pub fn describe_data_source_permissions(
+        &self,
+        input: DescribeDataSourcePermissionsRequest,
+    ) -> Result<
+        DescribeDataSourcePermissionsResponse,
+        RusotoError<DescribeDataSourcePermissionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_data_source_permissions(input))
+    }
+
+    /// Blocking version of [`Quicksight::describe_group`].
+    pub fn describe_group(
+        &self,
+        input: DescribeGroupRequest,
+    ) -> Result<DescribeGroupResponse, RusotoError<DescribeGroupError>> {
+        self.runtime.block_on(self.client.describe_group(input))
+    }
+
+    /// Blocking version of [`Quicksight::describe_iam_policy_assignment`].
+    pub fn describe_iam_policy_assignment(
+        &self,
+        input: DescribeIAMPolicyAssignmentRequest,
+    ) -> Result<DescribeIAMPolicyAssignmentResponse, RusotoError<DescribeIAMPolicyAssignmentError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_iam_policy_assignment(input))
+    }
+
+    /// Blocking version of [`Quicksight::describe_ingestion`].
+    pub fn describe_ingestion(
+        &self,
+        input: DescribeIngestionRequest,
+    ) -> Result<DescribeIngestionResponse, RusotoError<DescribeIngestionError>> {
+        self.runtime.block_on(self.client.describe_ingestion(input))
+    }
+
+    /// Blocking version of [`Quicksight::describe_namespace`].
+    pub fn describe_namespace(
+        &self,
+        input: DescribeNamespaceRequest,
+    ) -> Result<DescribeNamespaceResponse, RusotoError<DescribeNamespaceError>> {
+        self.runtime.block_on(self.client.describe_namespace(input))
+    }
+
+    /// Blocking version of [`Quicksight::describe_template`].
+    pub fn describe_template(
+        &self,
+        input: DescribeTemplateRequest,
+    ) -> Result<DescribeTemplateResponse, RusotoError<DescribeTemplateError>> {
+        self.runtime.block_on(self.client.describe_template(input))
+    }
+
+    /// Blocking version of [`Quicksight::describe_template_alias`].
+    pub fn describe_template_alias(
+        &self,
+        input: DescribeTemplateAliasRequest,
+    ) -> Result<DescribeTemplateAliasResponse, RusotoError<DescribeTemplateAliasError>> {
+        self.runtime
+            .block_on(self.client.describe_template_alias(input))
+    }
+
+    /// Blocking version of [`Quicksight::describe_template_permissions`].
+    pub fn describe_template_permissions(
+        &self,
+        input: DescribeTemplatePermissionsRequest,
+    ) -> Result<DescribeTemplatePermissionsResponse, RusotoError<DescribeTemplatePermissionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_template_permissions(input))
+    }
+
+    /// Blocking version of [`Quicksight::describe_theme`].
+    pub fn describe_theme(
+        &self,
+        input: DescribeThemeRequest,
+    ) -> Result<DescribeThemeResponse, RusotoError<DescribeThemeError>> {
+        self.runtime.block_on(self.client.describe_theme(input))
+    }
+
+    /// Blocking version of [`Quicksight::describe_theme_alias`].
+    pub fn describe_theme_alias(
+        &self,
+        input: DescribeThemeAliasRequest,
+    ) -> Result<DescribeThemeAliasResponse, RusotoError<DescribeThemeAliasError>> {
+        self.runtime
+            .block_on(self.client.describe_theme_alias(input))
+    }
+
+    /// Blocking version of [`Quicksight::describe_theme_permissions`].
+    pub fn describe_theme_permissions(
+        &self,
+        input: DescribeThemePermissionsRequest,
+    ) -> Result<DescribeThemePermissionsResponse, RusotoError<DescribeThemePermissionsError>> {
+        self.runtime
+            .block_on(self.client.describe_theme_permissions(input))
+    }
+
+    /// Blocking version of [`Quicksight::describe_user`].
+    pub fn describe_user(
+        &self,
+        input: DescribeUserRequest,
+    ) -> Result<DescribeUserResponse, RusotoError<DescribeUserError>> {
+        self.runtime.block_on(self.client.describe_user(input))
+    }
+
+    /// Blocking version of [`Quicksight::get_dashboard_embed_url`].
+    pub fn get_dashboard_embed_url(
+        &self,
+        input: GetDashboardEmbedUrlRequest,
+    ) -> Result<GetDashboardEmbedUrlResponse, RusotoError<GetDashboardEmbedUrlError>> {
+        self.runtime
+            .block_on(self.client.get_dashboard_embed_url(input))
+    }
+
+    /// Blocking version of [`Quicksight::get_session_embed_url`].
+    pub fn get_session_embed_url(
+        &self,
+        input: GetSessionEmbedUrlRequest,
+    ) -> Result<GetSessionEmbedUrlResponse, RusotoError<GetSessionEmbedUrlError>> {
+        self.runtime
+            .block_on(self.client.get_session_embed_url(input))
+    }
+
+    /// Blocking version of [`Quicksight::list_analyses`].
+    pub fn list_analyses(
+        &self,
+        input: ListAnalysesRequest,
+    ) -> Result<ListAnalysesResponse, RusotoError<ListAnalysesError>> {
+        self.runtime.block_on(self.client.list_analyses(input))
+    }
+
+    /// Blocking version of [`Quicksight::list_dashboard_versions`].
+    pub fn list_dashboard_versions(
+        &self,
+        input: ListDashboardVersionsRequest,
+    ) -> Result<ListDashboardVersionsResponse, RusotoError<ListDashboardVersionsError>> {
+        self.runtime
+            .block_on(self.client.list_dashboard_versions(input))
+    }
+
+    /// Blocking version of [`Quicksight::list_dashboards`].
+    pub fn list_dashboards(
+        &self,
+        input: ListDashboardsRequest,
+    ) -> Result<ListDashboardsResponse, RusotoError<ListDashboardsError>> {
+        self.runtime.block_on(self.client.list_dashboards(input))
+    }
+
+    /// Blocking version of [`Quicksight::list_data_sets`].
+    pub fn list_data_sets(
+        &self,
+        input: ListDataSetsRequest,
+    ) -> Result<ListDataSetsResponse, RusotoError<ListDataSetsError>> {
+        self.runtime.block_on(self.client.list_data_sets(input))
+    }
+
+    /// Blocking version of [`Quicksight::list_data_sources`].
+    pub fn list_data_sources(
+        &self,
+        input: ListDataSourcesRequest,
+    ) -> Result<ListDataSourcesResponse, RusotoError<ListDataSourcesError>> {
+        self.runtime.block_on(self.client.list_data_sources(input))
+    }
+
+    /// Blocking version of [`Quicksight::list_group_memberships`].
+    pub fn list_group_memberships(
+        &self,
+        input: ListGroupMembershipsRequest,
+    ) -> Result<ListGroupMembershipsResponse, RusotoError<ListGroupMembershipsError>> {
+        self.runtime
+            .block_on(self.client.list_group_memberships(input))
+    }
+
+    /// Blocking version of [`Quicksight::list_groups`].
+    pub fn list_groups(
+        &self,
+        input: ListGroupsRequest,
+    ) -> Result<ListGroupsResponse, RusotoError<ListGroupsError>> {
+        self.runtime.block_on(self.client.list_groups(input))
+    }
+
+    /// Blocking version of [`Quicksight::list_iam_policy_assignments`].
+    pub fn list_iam_policy_assignments(
+        &self,
+        input: ListIAMPolicyAssignmentsRequest,
+    ) -> Result<ListIAMPolicyAssignmentsResponse, RusotoError<ListIAMPolicyAssignmentsError>> {
+        self.runtime
+            .block_on(self.client.list_iam_policy_assignments(input))
+    }
+
+    /// Blocking version of [`Quicksight::list_iam_policy_assignments_for_user`].
+    pub fn list_iam_policy_assignments_for_user(
+        &self,
+        input: ListIAMPolicyAssignmentsForUserRequest,
+    ) -> Result<
+        ListIAMPolicyAssignmentsForUserResponse,
+        RusotoError<ListIAMPolicyAssignmentsForUserError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_iam_policy_assignments_for_user(input))
+    }
+
+    /// Blocking version of [`Quicksight::list_ingestions`].
+    pub fn list_ingestions(
+        &self,
+        input: ListIngestionsRequest,
+    ) -> Result<ListIngestionsResponse, RusotoError<ListIngestionsError>> {
+        self.runtime.block_on(self.client.list_ingestions(input))
+    }
+
+    /// Blocking version of [`Quicksight::list_namespaces`].
+    pub fn list_namespaces(
+        &self,
+        input: ListNamespacesRequest,
+    ) -> Result<ListNamespacesResponse, RusotoError<ListNamespacesError>> {
+        self.runtime.block_on(self.client.list_namespaces(input))
+    }
+
+    /// Blocking version of [`Quicksight::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`Quicksight::list_template_aliases`].
+    pub fn list_template_aliases(
+        &self,
+        input: ListTemplateAliasesRequest,
+    ) -> Result<ListTemplateAliasesResponse, RusotoError<ListTemplateAliasesError>> {
+        self.runtime
+            .block_on(self.client.list_template_aliases(input))
+    }
+
+    /// Blocking version of [`Quicksight::list_template_versions`].
+    pub fn list_template_versions(
+        &self,
+        input: ListTemplateVersionsRequest,
+    ) -> Result<ListTemplateVersionsResponse, RusotoError<ListTemplateVersionsError>> {
+        self.runtime
+            .block_on(self.client.list_template_versions(input))
+    }
+
+    /// Blocking version of [`Quicksight::list_templates`].
+    pub fn list_templates(
+        &self,
+        input: ListTemplatesRequest,
+    ) -> Result<ListTemplatesResponse, RusotoError<ListTemplatesError>> {
+        self.runtime.block_on(self.client.list_templates(input))
+    }
+
+    /// Blocking version of [`Quicksight::list_theme_aliases`].
+    pub fn list_theme_aliases(
+        &self,
+        input: ListThemeAliasesRequest,
+    ) -> Result<ListThemeAliasesResponse, RusotoError<ListThemeAliasesError>> {
+        self.runtime.block_on(self.client.list_theme_aliases(input))
+    }
+
+    /// Blocking version of [`Quicksight::list_theme_versions`].
+    pub fn list_theme_versions(
+        &self,
+        input: ListThemeVersionsRequest,
+    ) -> Result<ListThemeVersionsResponse, RusotoError<ListThemeVersionsError>> {
+        self.runtime
+            .block_on(self.client.list_theme_versions(input))
+    }
+
+    /// Blocking version of [`Quicksight::list_themes`].
+    pub fn list_themes(
+        &self,
+        input: ListThemesRequest,
+    ) -> Result<ListThemesResponse, RusotoError<ListThemesError>> {
+        self.runtime.block_on(self.client.list_themes(input))
+    }
+
+    /// Blocking version of [`Quicksight::list_user_groups`].
+    pub fn list_user_groups(
+        &self,
+        input: ListUserGroupsRequest,
+    ) -> Result<ListUserGroupsResponse, RusotoError<ListUserGroupsError>> {
+        self.runtime.block_on(self.client.list_user_groups(input))
+    }
+
+    /// Blocking version of [`Quicksight::list_users`].
+    pub fn list_users(
+        &self,
+        input: ListUsersRequest,
+    ) -> Result<ListUsersResponse, RusotoError<ListUsersError>> {
+        self.runtime.block_on(self.client.list_users(input))
+    }
+
+    /// Blocking version of [`Quicksight::register_user`].
+    pub fn register_user(
+        &self,
+        input: RegisterUserRequest,
+    ) -> Result<RegisterUserResponse, RusotoError<RegisterUserError>> {
+        self.runtime.block_on(self.client.register_user(input))
+    }
+
+    /// Blocking version of [`Quicksight::restore_analysis`].
+    pub fn restore_analysis(
+        &self,
+        input: RestoreAnalysisRequest,
+    ) -> Result<RestoreAnalysisResponse, RusotoError<RestoreAnalysisError>> {
+        self.runtime.block_on(self.client.restore_analysis(input))
+    }
+
+    /// Blocking version of [`Quicksight::search_analyses`].
+    pub fn search_analyses(
+        &self,
+        input: SearchAnalysesRequest,
+    ) -> Result<SearchAnalysesResponse, RusotoError<SearchAnalysesError>> {
+        self.runtime.block_on(self.client.search_analyses(input))
+    }
+
+    /// Blocking version of [`Quicksight::search_dashboards`].
+    pub fn search_dashboards(
+        &self,
+        input: SearchDashboardsRequest,
+    ) -> Result<SearchDashboardsResponse, RusotoError<SearchDashboardsError>> {
+        self.runtime.block_on(self.client.search_dashboards(input))
+    }
+
+    /// Blocking version of [`Quicksight::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`Quicksight::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`Quicksight::update_account_customization`].
+    pub fn update_account_customization(
+        &self,
+        input: UpdateAccountCustomizationRequest,
+    ) -> Result<UpdateAccountCustomizationResponse, RusotoError<UpdateAccountCustomizationError>>
+    {
+        self.runtime
+            .block_on(self.client.update_account_customization(input))
+    }
+
+    /// Blocking version of [`Quicksight::update_account_settings`].
+    pub fn update_account_settings(
+        &self,
+        input: UpdateAccountSettingsRequest,
+    ) -> Result<UpdateAccountSettingsResponse, RusotoError<UpdateAccountSettingsError>> {
+        self.runtime
+            .block_on(self.client.update_account_settings(input))
+    }
+
+    /// Blocking version of [`Quicksight::update_analysis`].
+    pub fn update_analysis(
+        &self,
+        input: UpdateAnalysisRequest,
+    ) -> Result<UpdateAnalysisResponse, RusotoError<UpdateAnalysisError>> {
+        self.runtime.block_on(self.client.update_analysis(input))
+    }
+
+    /// Blocking version of [`Quicksight::update_analysis_permissions`].
+    pub fn update_analysis_permissions(
+        &self,
+        input: UpdateAnalysisPermissionsRequest,
+    ) -> Result<UpdateAnalysisPermissionsResponse, RusotoError<UpdateAnalysisPermissionsError>>
+    {
+        self.runtime
+            .block_on(self.client.update_analysis_permissions(input))
+    }
+
+    /// Blocking version of [`Quicksight::update_dashboard`].
+    pub fn update_dashboard(
+        &self,
+        input: UpdateDashboardRequest,
+    ) -> Result<UpdateDashboardResponse, RusotoError<UpdateDashboardError>> {
+        self.runtime.block_on(self.client.update_dashboard(input))
+    }
+
+    /// Blocking version of [`Quicksight::update_dashboard_permissions`].
+    pub fn update_dashboard_permissions(
+        &self,
+        input: UpdateDashboardPermissionsRequest,
+    ) -> Result<UpdateDashboardPermissionsResponse, RusotoError<UpdateDashboardPermissionsError>>
+    {
+        self.runtime
+            .block_on(self.client.update_dashboard_permissions(input))
+    }
+
+    /// Blocking version of [`Quicksight::update_dashboard_published_version`].
+    pub fn update_dashboard_published_version(
+        &self,
+        input: UpdateDashboardPublishedVersionRequest,
+    ) -> Result<
+        UpdateDashboardPublishedVersionResponse,
+        RusotoError<UpdateDashboardPublishedVersionError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_dashboard_published_version(input))
+    }
+
+    /// Blocking version of [`Quicksight::update_data_set`].
+    pub fn update_data_set(
+        &self,
+        input: UpdateDataSetRequest,
+    ) -> Result<UpdateDataSetResponse, RusotoError<UpdateDataSetError>> {
+        self.runtime.block_on(self.client.update_data_set(input))
+    }
+
+    /// Blocking version of [`Quicksight::update_data_set_permissions`].
+    pub fn update_data_set_permissions(
+        &self,
+        input: UpdateDataSetPermissionsRequest,
+    ) -> Result<UpdateDataSetPermissionsResponse, RusotoError<UpdateDataSetPermissionsError>> {
+        self.runtime
+            .block_on(self.client.update_data_set_permissions(input))
+    }
+
+    /// Blocking version of [`Quicksight::update_data_source`].
+    pub fn update_data_source(
+        &self,
+        input: UpdateDataSourceRequest,
+    ) -> Result<UpdateDataSourceResponse, RusotoError<UpdateDataSourceError>> {
+        self.runtime.block_on(self.client.update_data_source(input))
+    }
+
+    /// Blocking version of [`Quicksight::update_data_source_permissions`].
+    pub fn update_data_source_permissions(
+        &self,
+        input: UpdateDataSourcePermissionsRequest,
+    ) -> Result<UpdateDataSourcePermissionsResponse, RusotoError<UpdateDataSourcePermissionsError>>
+    {
+        self.runtime
+            .block_on(self.client.update_data_source_permissions(input))
+    }
+
+    /// Blocking version of [`Quicksight::update_group`].
+    pub fn update_group(
+        &self,
+        input: UpdateGroupRequest,
+    ) -> Result<UpdateGroupResponse, RusotoError<UpdateGroupError>> {
+        self.runtime.block_on(self.client.update_group(input))
+    }
+
+    /// Blocking version of [`Quicksight::update_iam_policy_assignment`].
+    pub fn update_iam_policy_assignment(
+        &self,
+        input: UpdateIAMPolicyAssignmentRequest,
+    ) -> Result<UpdateIAMPolicyAssignmentResponse, RusotoError<UpdateIAMPolicyAssignmentError>>
+    {
+        self.runtime
+            .block_on(self.client.update_iam_policy_assignment(input))
+    }
+
+    /// Blocking version of [`Quicksight::update_template`].
+    pub fn update_template(
+        &self,
+        input: UpdateTemplateRequest,
+    ) -> Result<UpdateTemplateResponse, RusotoError<UpdateTemplateError>> {
+        self.runtime.block_on(self.client.update_template(input))
+    }
+
+    /// Blocking version of [`Quicksight::update_template_alias`].
+    pub fn update_template_alias(
+        &self,
+        input: UpdateTemplateAliasRequest,
+    ) -> Result<UpdateTemplateAliasResponse, RusotoError<UpdateTemplateAliasError>> {
+        self.runtime
+            .block_on(self.client.update_template_alias(input))
+    }
+
+    /// Blocking version of [`Quicksight::update_template_permissions`].
+    pub fn update_template_permissions(
+        &self,
+        input: UpdateTemplatePermissionsRequest,
+    ) -> Result<UpdateTemplatePermissionsResponse, RusotoError<UpdateTemplatePermissionsError>>
+    {
+        self.runtime
+            .block_on(self.client.update_template_permissions(input))
+    }
+
+    /// Blocking version of [`Quicksight::update_theme`].
+    pub fn update_theme(
+        &self,
+        input: UpdateThemeRequest,
+    ) -> Result<UpdateThemeResponse, RusotoError<UpdateThemeError>> {
+        self.runtime.block_on(self.client.update_theme(input))
+    }
+
+    /// Blocking version of [`Quicksight::update_theme_alias`].
+    pub fn update_theme_alias(
+        &self,
+        input: UpdateThemeAliasRequest,
+    ) -> Result<UpdateThemeAliasResponse, RusotoError<UpdateThemeAliasError>> {
+        self.runtime.block_on(self.client.update_theme_alias(input))
+    }
+
+    /// Blocking version of [`Quicksight::update_theme_permissions`].
+    pub fn update_theme_permissions(
+        &self,
+        input: UpdateThemePermissionsRequest,
+    ) -> Result<UpdateThemePermissionsResponse, RusotoError<UpdateThemePermissionsError>> {
+        self.runtime
+            .block_on(self.client.update_theme_permissions(input))
+    }
+
+    /// Blocking version of [`Quicksight::update_user`].
+    pub fn update_user(
+        &self,
+        input: UpdateUserRequest,
+    ) -> Result<UpdateUserResponse, RusotoError<UpdateUserError>> {
+        self.runtime.block_on(self.client.update_user(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -1081,62 +1082,86 @@ impl AcceptResourceShareInvitationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        AcceptResourceShareInvitationError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptResourceShareInvitationError::IdempotentParameterMismatch,
                     )
                 }
                 "InvalidClientTokenException" => {
-                    return RusotoError::Service(
-                        AcceptResourceShareInvitationError::InvalidClientToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptResourceShareInvitationError::InvalidClientToken,
                     )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(AcceptResourceShareInvitationError::MalformedArn(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AcceptResourceShareInvitationError::MalformedArn,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
-                        AcceptResourceShareInvitationError::OperationNotPermitted(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptResourceShareInvitationError::OperationNotPermitted,
                     )
                 }
                 "ResourceShareInvitationAlreadyAcceptedException" => {
-                    return RusotoError::Service(
-                        AcceptResourceShareInvitationError::ResourceShareInvitationAlreadyAccepted(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptResourceShareInvitationError::ResourceShareInvitationAlreadyAccepted,
                     )
                 }
                 "ResourceShareInvitationAlreadyRejectedException" => {
-                    return RusotoError::Service(
-                        AcceptResourceShareInvitationError::ResourceShareInvitationAlreadyRejected(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptResourceShareInvitationError::ResourceShareInvitationAlreadyRejected,
                     )
                 }
                 "ResourceShareInvitationArnNotFoundException" => {
-                    return RusotoError::Service(
-                        AcceptResourceShareInvitationError::ResourceShareInvitationArnNotFound(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptResourceShareInvitationError::ResourceShareInvitationArnNotFound,
                     )
                 }
                 "ResourceShareInvitationExpiredException" => {
-                    return RusotoError::Service(
-                        AcceptResourceShareInvitationError::ResourceShareInvitationExpired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptResourceShareInvitationError::ResourceShareInvitationExpired,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(
-                        AcceptResourceShareInvitationError::ServerInternal(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptResourceShareInvitationError::ServerInternal,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        AcceptResourceShareInvitationError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptResourceShareInvitationError::ServiceUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1207,54 +1232,86 @@ impl AssociateResourceShareError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        AssociateResourceShareError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateResourceShareError::IdempotentParameterMismatch,
                     )
                 }
                 "InvalidClientTokenException" => {
-                    return RusotoError::Service(AssociateResourceShareError::InvalidClientToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateResourceShareError::InvalidClientToken,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AssociateResourceShareError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateResourceShareError::InvalidParameter,
+                    )
                 }
                 "InvalidStateTransitionException" => {
-                    return RusotoError::Service(
-                        AssociateResourceShareError::InvalidStateTransition(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateResourceShareError::InvalidStateTransition,
                     )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(AssociateResourceShareError::MalformedArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateResourceShareError::MalformedArn,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
-                        AssociateResourceShareError::OperationNotPermitted(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateResourceShareError::OperationNotPermitted,
                     )
                 }
                 "ResourceShareLimitExceededException" => {
-                    return RusotoError::Service(
-                        AssociateResourceShareError::ResourceShareLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateResourceShareError::ResourceShareLimitExceeded,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(AssociateResourceShareError::ServerInternal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateResourceShareError::ServerInternal,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(AssociateResourceShareError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateResourceShareError::ServiceUnavailable,
+                    )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(AssociateResourceShareError::UnknownResource(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateResourceShareError::UnknownResource,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1311,41 +1368,62 @@ impl AssociateResourceSharePermissionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidClientTokenException" => {
-                    return RusotoError::Service(
-                        AssociateResourceSharePermissionError::InvalidClientToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateResourceSharePermissionError::InvalidClientToken,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        AssociateResourceSharePermissionError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateResourceSharePermissionError::InvalidParameter,
                     )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(
-                        AssociateResourceSharePermissionError::MalformedArn(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateResourceSharePermissionError::MalformedArn,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
-                        AssociateResourceSharePermissionError::OperationNotPermitted(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateResourceSharePermissionError::OperationNotPermitted,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(
-                        AssociateResourceSharePermissionError::ServerInternal(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateResourceSharePermissionError::ServerInternal,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        AssociateResourceSharePermissionError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateResourceSharePermissionError::ServiceUnavailable,
                     )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(
-                        AssociateResourceSharePermissionError::UnknownResource(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateResourceSharePermissionError::UnknownResource,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1413,55 +1491,94 @@ impl CreateResourceShareError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        CreateResourceShareError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResourceShareError::IdempotentParameterMismatch,
                     )
                 }
                 "InvalidClientTokenException" => {
-                    return RusotoError::Service(CreateResourceShareError::InvalidClientToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateResourceShareError::InvalidClientToken,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateResourceShareError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateResourceShareError::InvalidParameter,
+                    )
                 }
                 "InvalidStateTransitionException" => {
-                    return RusotoError::Service(CreateResourceShareError::InvalidStateTransition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateResourceShareError::InvalidStateTransition,
+                    )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(CreateResourceShareError::MalformedArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResourceShareError::MalformedArn,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(CreateResourceShareError::OperationNotPermitted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateResourceShareError::OperationNotPermitted,
+                    )
                 }
                 "ResourceShareLimitExceededException" => {
-                    return RusotoError::Service(
-                        CreateResourceShareError::ResourceShareLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResourceShareError::ResourceShareLimitExceeded,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(CreateResourceShareError::ServerInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResourceShareError::ServerInternal,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateResourceShareError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateResourceShareError::ServiceUnavailable,
+                    )
                 }
                 "TagPolicyViolationException" => {
-                    return RusotoError::Service(CreateResourceShareError::TagPolicyViolation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateResourceShareError::TagPolicyViolation,
+                    )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(CreateResourceShareError::UnknownResource(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResourceShareError::UnknownResource,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1519,45 +1636,78 @@ impl DeleteResourceShareError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        DeleteResourceShareError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteResourceShareError::IdempotentParameterMismatch,
                     )
                 }
                 "InvalidClientTokenException" => {
-                    return RusotoError::Service(DeleteResourceShareError::InvalidClientToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteResourceShareError::InvalidClientToken,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteResourceShareError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteResourceShareError::InvalidParameter,
+                    )
                 }
                 "InvalidStateTransitionException" => {
-                    return RusotoError::Service(DeleteResourceShareError::InvalidStateTransition(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteResourceShareError::InvalidStateTransition,
+                    )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(DeleteResourceShareError::MalformedArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteResourceShareError::MalformedArn,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(DeleteResourceShareError::OperationNotPermitted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteResourceShareError::OperationNotPermitted,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(DeleteResourceShareError::ServerInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteResourceShareError::ServerInternal,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(DeleteResourceShareError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteResourceShareError::ServiceUnavailable,
+                    )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(DeleteResourceShareError::UnknownResource(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteResourceShareError::UnknownResource,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1613,56 +1763,86 @@ impl DisassociateResourceShareError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        DisassociateResourceShareError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateResourceShareError::IdempotentParameterMismatch,
                     )
                 }
                 "InvalidClientTokenException" => {
-                    return RusotoError::Service(
-                        DisassociateResourceShareError::InvalidClientToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateResourceShareError::InvalidClientToken,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DisassociateResourceShareError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateResourceShareError::InvalidParameter,
+                    )
                 }
                 "InvalidStateTransitionException" => {
-                    return RusotoError::Service(
-                        DisassociateResourceShareError::InvalidStateTransition(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateResourceShareError::InvalidStateTransition,
                     )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(DisassociateResourceShareError::MalformedArn(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateResourceShareError::MalformedArn,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
-                        DisassociateResourceShareError::OperationNotPermitted(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateResourceShareError::OperationNotPermitted,
                     )
                 }
                 "ResourceShareLimitExceededException" => {
-                    return RusotoError::Service(
-                        DisassociateResourceShareError::ResourceShareLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateResourceShareError::ResourceShareLimitExceeded,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(DisassociateResourceShareError::ServerInternal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateResourceShareError::ServerInternal,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DisassociateResourceShareError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateResourceShareError::ServiceUnavailable,
                     )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(DisassociateResourceShareError::UnknownResource(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateResourceShareError::UnknownResource,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1721,41 +1901,62 @@ impl DisassociateResourceSharePermissionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidClientTokenException" => {
-                    return RusotoError::Service(
-                        DisassociateResourceSharePermissionError::InvalidClientToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateResourceSharePermissionError::InvalidClientToken,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        DisassociateResourceSharePermissionError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateResourceSharePermissionError::InvalidParameter,
                     )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(
-                        DisassociateResourceSharePermissionError::MalformedArn(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateResourceSharePermissionError::MalformedArn,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
-                        DisassociateResourceSharePermissionError::OperationNotPermitted(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateResourceSharePermissionError::OperationNotPermitted,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(
-                        DisassociateResourceSharePermissionError::ServerInternal(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateResourceSharePermissionError::ServerInternal,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        DisassociateResourceSharePermissionError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateResourceSharePermissionError::ServiceUnavailable,
                     )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(
-                        DisassociateResourceSharePermissionError::UnknownResource(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateResourceSharePermissionError::UnknownResource,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1809,21 +2010,30 @@ impl EnableSharingWithAwsOrganizationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
-                        EnableSharingWithAwsOrganizationError::OperationNotPermitted(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableSharingWithAwsOrganizationError::OperationNotPermitted,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(
-                        EnableSharingWithAwsOrganizationError::ServerInternal(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableSharingWithAwsOrganizationError::ServerInternal,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        EnableSharingWithAwsOrganizationError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableSharingWithAwsOrganizationError::ServiceUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1869,24 +2079,54 @@ impl GetPermissionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetPermissionError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPermissionError::InvalidParameter,
+                    )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(GetPermissionError::MalformedArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPermissionError::MalformedArn,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(GetPermissionError::OperationNotPermitted(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPermissionError::OperationNotPermitted,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(GetPermissionError::ServerInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPermissionError::ServerInternal,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetPermissionError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPermissionError::ServiceUnavailable,
+                    )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(GetPermissionError::UnknownResource(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPermissionError::UnknownResource,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1929,32 +2169,54 @@ impl GetResourcePoliciesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(GetResourcePoliciesError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetResourcePoliciesError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetResourcePoliciesError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetResourcePoliciesError::InvalidParameter,
+                    )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(GetResourcePoliciesError::MalformedArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourcePoliciesError::MalformedArn,
+                    )
                 }
                 "ResourceArnNotFoundException" => {
-                    return RusotoError::Service(GetResourcePoliciesError::ResourceArnNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetResourcePoliciesError::ResourceArnNotFound,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(GetResourcePoliciesError::ServerInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourcePoliciesError::ServerInternal,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetResourcePoliciesError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetResourcePoliciesError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2001,41 +2263,62 @@ impl GetResourceShareAssociationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        GetResourceShareAssociationsError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourceShareAssociationsError::InvalidNextToken,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        GetResourceShareAssociationsError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourceShareAssociationsError::InvalidParameter,
                     )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(GetResourceShareAssociationsError::MalformedArn(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetResourceShareAssociationsError::MalformedArn,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
-                        GetResourceShareAssociationsError::OperationNotPermitted(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourceShareAssociationsError::OperationNotPermitted,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(GetResourceShareAssociationsError::ServerInternal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetResourceShareAssociationsError::ServerInternal,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        GetResourceShareAssociationsError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourceShareAssociationsError::ServiceUnavailable,
                     )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(
-                        GetResourceShareAssociationsError::UnknownResource(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourceShareAssociationsError::UnknownResource,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2093,48 +2376,70 @@ impl GetResourceShareInvitationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidMaxResultsException" => {
-                    return RusotoError::Service(
-                        GetResourceShareInvitationsError::InvalidMaxResults(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourceShareInvitationsError::InvalidMaxResults,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        GetResourceShareInvitationsError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourceShareInvitationsError::InvalidNextToken,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        GetResourceShareInvitationsError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourceShareInvitationsError::InvalidParameter,
                     )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(GetResourceShareInvitationsError::MalformedArn(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetResourceShareInvitationsError::MalformedArn,
+                    )
                 }
                 "ResourceShareInvitationArnNotFoundException" => {
-                    return RusotoError::Service(
-                        GetResourceShareInvitationsError::ResourceShareInvitationArnNotFound(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourceShareInvitationsError::ResourceShareInvitationArnNotFound,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(GetResourceShareInvitationsError::ServerInternal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetResourceShareInvitationsError::ServerInternal,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        GetResourceShareInvitationsError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourceShareInvitationsError::ServiceUnavailable,
                     )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(GetResourceShareInvitationsError::UnknownResource(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetResourceShareInvitationsError::UnknownResource,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2185,26 +2490,54 @@ impl GetResourceSharesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(GetResourceSharesError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourceSharesError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetResourceSharesError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourceSharesError::InvalidParameter,
+                    )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(GetResourceSharesError::MalformedArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourceSharesError::MalformedArn,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(GetResourceSharesError::ServerInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourceSharesError::ServerInternal,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(GetResourceSharesError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetResourceSharesError::ServiceUnavailable,
+                    )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(GetResourceSharesError::UnknownResource(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourceSharesError::UnknownResource,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2255,57 +2588,78 @@ impl ListPendingInvitationResourcesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        ListPendingInvitationResourcesError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPendingInvitationResourcesError::InvalidNextToken,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        ListPendingInvitationResourcesError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPendingInvitationResourcesError::InvalidParameter,
                     )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(ListPendingInvitationResourcesError::MalformedArn(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPendingInvitationResourcesError::MalformedArn,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
-                        ListPendingInvitationResourcesError::MissingRequiredParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPendingInvitationResourcesError::MissingRequiredParameter,
                     )
                 }
                 "ResourceShareInvitationAlreadyRejectedException" => {
-                    return RusotoError::Service(
-                        ListPendingInvitationResourcesError::ResourceShareInvitationAlreadyRejected(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPendingInvitationResourcesError::ResourceShareInvitationAlreadyRejected,
                     )
                 }
                 "ResourceShareInvitationArnNotFoundException" => {
-                    return RusotoError::Service(
-                        ListPendingInvitationResourcesError::ResourceShareInvitationArnNotFound(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPendingInvitationResourcesError::ResourceShareInvitationArnNotFound,
                     )
                 }
                 "ResourceShareInvitationExpiredException" => {
-                    return RusotoError::Service(
-                        ListPendingInvitationResourcesError::ResourceShareInvitationExpired(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPendingInvitationResourcesError::ResourceShareInvitationExpired,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(
-                        ListPendingInvitationResourcesError::ServerInternal(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPendingInvitationResourcesError::ServerInternal,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        ListPendingInvitationResourcesError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPendingInvitationResourcesError::ServiceUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2365,23 +2719,46 @@ impl ListPermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListPermissionsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPermissionsError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListPermissionsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPermissionsError::InvalidParameter,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(ListPermissionsError::OperationNotPermitted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPermissionsError::OperationNotPermitted,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(ListPermissionsError::ServerInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPermissionsError::ServerInternal,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListPermissionsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPermissionsError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2423,24 +2800,54 @@ impl ListPrincipalsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListPrincipalsError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPrincipalsError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListPrincipalsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPrincipalsError::InvalidParameter,
+                    )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(ListPrincipalsError::MalformedArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPrincipalsError::MalformedArn,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(ListPrincipalsError::ServerInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPrincipalsError::ServerInternal,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListPrincipalsError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPrincipalsError::ServiceUnavailable,
+                    )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(ListPrincipalsError::UnknownResource(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPrincipalsError::UnknownResource,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2487,41 +2894,62 @@ impl ListResourceSharePermissionsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        ListResourceSharePermissionsError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourceSharePermissionsError::InvalidNextToken,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        ListResourceSharePermissionsError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourceSharePermissionsError::InvalidParameter,
                     )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(ListResourceSharePermissionsError::MalformedArn(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResourceSharePermissionsError::MalformedArn,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
-                        ListResourceSharePermissionsError::OperationNotPermitted(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourceSharePermissionsError::OperationNotPermitted,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(ListResourceSharePermissionsError::ServerInternal(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResourceSharePermissionsError::ServerInternal,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        ListResourceSharePermissionsError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourceSharePermissionsError::ServiceUnavailable,
                     )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(
-                        ListResourceSharePermissionsError::UnknownResource(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourceSharePermissionsError::UnknownResource,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2569,20 +2997,38 @@ impl ListResourceTypesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListResourceTypesError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourceTypesError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListResourceTypesError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourceTypesError::InvalidParameter,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(ListResourceTypesError::ServerInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourceTypesError::ServerInternal,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListResourceTypesError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResourceTypesError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2625,27 +3071,62 @@ impl ListResourcesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListResourcesError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourcesError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListResourcesError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourcesError::InvalidParameter,
+                    )
                 }
                 "InvalidResourceTypeException" => {
-                    return RusotoError::Service(ListResourcesError::InvalidResourceType(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourcesError::InvalidResourceType,
+                    )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(ListResourcesError::MalformedArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourcesError::MalformedArn,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(ListResourcesError::ServerInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourcesError::ServerInternal,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(ListResourcesError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourcesError::ServiceUnavailable,
+                    )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(ListResourcesError::UnknownResource(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResourcesError::UnknownResource,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2693,43 +3174,62 @@ impl PromoteResourceShareCreatedFromPolicyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        PromoteResourceShareCreatedFromPolicyError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PromoteResourceShareCreatedFromPolicyError::InvalidParameter,
                     )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(
-                        PromoteResourceShareCreatedFromPolicyError::MalformedArn(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PromoteResourceShareCreatedFromPolicyError::MalformedArn,
                     )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
-                        PromoteResourceShareCreatedFromPolicyError::MissingRequiredParameter(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PromoteResourceShareCreatedFromPolicyError::MissingRequiredParameter,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
-                        PromoteResourceShareCreatedFromPolicyError::OperationNotPermitted(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PromoteResourceShareCreatedFromPolicyError::OperationNotPermitted,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(
-                        PromoteResourceShareCreatedFromPolicyError::ServerInternal(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PromoteResourceShareCreatedFromPolicyError::ServerInternal,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        PromoteResourceShareCreatedFromPolicyError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PromoteResourceShareCreatedFromPolicyError::ServiceUnavailable,
                     )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(
-                        PromoteResourceShareCreatedFromPolicyError::UnknownResource(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PromoteResourceShareCreatedFromPolicyError::UnknownResource,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2797,62 +3297,86 @@ impl RejectResourceShareInvitationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        RejectResourceShareInvitationError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RejectResourceShareInvitationError::IdempotentParameterMismatch,
                     )
                 }
                 "InvalidClientTokenException" => {
-                    return RusotoError::Service(
-                        RejectResourceShareInvitationError::InvalidClientToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RejectResourceShareInvitationError::InvalidClientToken,
                     )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(RejectResourceShareInvitationError::MalformedArn(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RejectResourceShareInvitationError::MalformedArn,
+                    )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(
-                        RejectResourceShareInvitationError::OperationNotPermitted(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RejectResourceShareInvitationError::OperationNotPermitted,
                     )
                 }
                 "ResourceShareInvitationAlreadyAcceptedException" => {
-                    return RusotoError::Service(
-                        RejectResourceShareInvitationError::ResourceShareInvitationAlreadyAccepted(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RejectResourceShareInvitationError::ResourceShareInvitationAlreadyAccepted,
                     )
                 }
                 "ResourceShareInvitationAlreadyRejectedException" => {
-                    return RusotoError::Service(
-                        RejectResourceShareInvitationError::ResourceShareInvitationAlreadyRejected(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RejectResourceShareInvitationError::ResourceShareInvitationAlreadyRejected,
                     )
                 }
                 "ResourceShareInvitationArnNotFoundException" => {
-                    return RusotoError::Service(
-                        RejectResourceShareInvitationError::ResourceShareInvitationArnNotFound(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RejectResourceShareInvitationError::ResourceShareInvitationArnNotFound,
                     )
                 }
                 "ResourceShareInvitationExpiredException" => {
-                    return RusotoError::Service(
-                        RejectResourceShareInvitationError::ResourceShareInvitationExpired(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RejectResourceShareInvitationError::ResourceShareInvitationExpired,
                     )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(
-                        RejectResourceShareInvitationError::ServerInternal(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RejectResourceShareInvitationError::ServerInternal,
                     )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(
-                        RejectResourceShareInvitationError::ServiceUnavailable(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RejectResourceShareInvitationError::ServiceUnavailable,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2917,27 +3441,62 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidParameter,
+                    )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(TagResourceError::MalformedArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::MalformedArn,
+                    )
                 }
                 "ResourceArnNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceArnNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceArnNotFound,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(TagResourceError::ServerInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ServerInternal,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(TagResourceError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ServiceUnavailable,
+                    )
                 }
                 "TagLimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::TagLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::TagLimitExceeded,
+                    )
                 }
                 "TagPolicyViolationException" => {
-                    return RusotoError::Service(TagResourceError::TagPolicyViolation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::TagPolicyViolation,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2975,15 +3534,30 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidParameter,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(UntagResourceError::ServerInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ServerInternal,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UntagResourceError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ServiceUnavailable,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3029,45 +3603,78 @@ impl UpdateResourceShareError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        UpdateResourceShareError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateResourceShareError::IdempotentParameterMismatch,
                     )
                 }
                 "InvalidClientTokenException" => {
-                    return RusotoError::Service(UpdateResourceShareError::InvalidClientToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateResourceShareError::InvalidClientToken,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateResourceShareError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateResourceShareError::InvalidParameter,
+                    )
                 }
                 "MalformedArnException" => {
-                    return RusotoError::Service(UpdateResourceShareError::MalformedArn(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateResourceShareError::MalformedArn,
+                    )
                 }
                 "MissingRequiredParameterException" => {
-                    return RusotoError::Service(
-                        UpdateResourceShareError::MissingRequiredParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateResourceShareError::MissingRequiredParameter,
                     )
                 }
                 "OperationNotPermittedException" => {
-                    return RusotoError::Service(UpdateResourceShareError::OperationNotPermitted(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateResourceShareError::OperationNotPermitted,
+                    )
                 }
                 "ServerInternalException" => {
-                    return RusotoError::Service(UpdateResourceShareError::ServerInternal(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateResourceShareError::ServerInternal,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(UpdateResourceShareError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateResourceShareError::ServiceUnavailable,
+                    )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(UpdateResourceShareError::UnknownResource(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateResourceShareError::UnknownResource,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3270,11 +3877,12 @@ pub struct RamClient {
 impl RamClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> RamClient {
         RamClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "RAM"),
         }
     }
 
@@ -3289,12 +3897,32 @@ impl RamClient {
     {
         RamClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "RAM"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> RamClient {
-        RamClient { client, region }
+        RamClient {
+            client,
+            region: region_for_service(region, "RAM"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> RamClient {
+        RamClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("RAM"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> RamClient {
+        RamClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -3312,6 +3940,7 @@ impl Ram for RamClient {
         let request_uri = "/acceptresourceshareinvitation";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("AcceptResourceShareInvitation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3343,6 +3972,7 @@ impl Ram for RamClient {
         let request_uri = "/associateresourceshare";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("AssociateResourceShare");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3377,6 +4007,7 @@ impl Ram for RamClient {
         let request_uri = "/associateresourcesharepermission";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("AssociateResourceSharePermission");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3410,6 +4041,7 @@ impl Ram for RamClient {
         let request_uri = "/createresourceshare";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("CreateResourceShare");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3441,6 +4073,7 @@ impl Ram for RamClient {
         let request_uri = "/deleteresourceshare";
 
         let mut request = SignedRequest::new("DELETE", "ram", &self.region, &request_uri);
+        request.set_operation("DeleteResourceShare");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3477,6 +4110,7 @@ impl Ram for RamClient {
         let request_uri = "/disassociateresourceshare";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("DisassociateResourceShare");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3511,6 +4145,7 @@ impl Ram for RamClient {
         let request_uri = "/disassociateresourcesharepermission";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("DisassociateResourceSharePermission");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3546,6 +4181,7 @@ impl Ram for RamClient {
         let request_uri = "/enablesharingwithawsorganization";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("EnableSharingWithAwsOrganization");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -3576,6 +4212,7 @@ impl Ram for RamClient {
         let request_uri = "/getpermission";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("GetPermission");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3607,6 +4244,7 @@ impl Ram for RamClient {
         let request_uri = "/getresourcepolicies";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("GetResourcePolicies");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3639,6 +4277,7 @@ impl Ram for RamClient {
         let request_uri = "/getresourceshareassociations";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("GetResourceShareAssociations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3671,6 +4310,7 @@ impl Ram for RamClient {
         let request_uri = "/getresourceshareinvitations";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("GetResourceShareInvitations");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3702,6 +4342,7 @@ impl Ram for RamClient {
         let request_uri = "/getresourceshares";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("GetResourceShares");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3736,6 +4377,7 @@ impl Ram for RamClient {
         let request_uri = "/listpendinginvitationresources";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("ListPendingInvitationResources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3767,6 +4409,7 @@ impl Ram for RamClient {
         let request_uri = "/listpermissions";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("ListPermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3798,6 +4441,7 @@ impl Ram for RamClient {
         let request_uri = "/listprincipals";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("ListPrincipals");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3830,6 +4474,7 @@ impl Ram for RamClient {
         let request_uri = "/listresourcesharepermissions";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("ListResourceSharePermissions");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3861,6 +4506,7 @@ impl Ram for RamClient {
         let request_uri = "/listresourcetypes";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("ListResourceTypes");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3892,6 +4538,7 @@ impl Ram for RamClient {
         let request_uri = "/listresources";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("ListResources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3926,6 +4573,7 @@ impl Ram for RamClient {
         let request_uri = "/promoteresourcesharecreatedfrompolicy";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("PromoteResourceShareCreatedFromPolicy");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -3963,6 +4611,7 @@ impl Ram for RamClient {
         let request_uri = "/rejectresourceshareinvitation";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("RejectResourceShareInvitation");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -3994,6 +4643,7 @@ impl Ram for RamClient {
         let request_uri = "/tagresource";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4025,6 +4675,7 @@ impl Ram for RamClient {
         let request_uri = "/untagresource";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4056,6 +4707,7 @@ impl Ram for RamClient {
         let request_uri = "/updateresourceshare";
 
         let mut request = SignedRequest::new("POST", "ram", &self.region, &request_uri);
+        request.set_operation("UpdateResourceShare");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -4078,3 +4730,314 @@ impl Ram for RamClient {
         }
     }
 }
+
+/// A blocking client for the RAM API, running the calls of a `RamClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `RamClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct RamBlockingClient {
+    client: RamClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl RamBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> RamBlockingClient {
+        RamBlockingClient::from_client(RamClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> RamBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        RamBlockingClient::from_client(RamClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> RamBlockingClient {
+        RamBlockingClient::from_client(RamClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> RamBlockingClient {
+        RamBlockingClient::from_client(RamClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: RamClient) -> RamBlockingClient {
+        RamBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> RamBlockingClient {
+        RamBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> RamBlockingClient {
+        RamBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Ram::accept_resource_share_invitation`].
+    pub fn accept_resource_share_invitation(
+        &self,
+        input: AcceptResourceShareInvitationRequest,
+    ) -> Result<
+        AcceptResourceShareInvitationResponse,
+        RusotoError<AcceptResourceShareInvitationError>,
+    > {
+        self.runtime
+            .block_on(self.client.accept_resource_share_invitation(input))
+    }
+
+    /// Blocking version of [`Ram::associate_resource_share`].
+    pub fn associate_resource_share(
+        &self,
+        input: AssociateResourceShareRequest,
+    ) -> Result<AssociateResourceShareResponse, RusotoError<AssociateResourceShareError>> {
+        self.runtime
+            .block_on(self.client.associate_resource_share(input))
+    }
+
+    /// Blocking version of [`Ram::associate_resource_share_permission`].
+    pub fn associate_resource_share_permission(
+        &self,
+        input: AssociateResourceSharePermissionRequest,
+    ) -> Result<
+        AssociateResourceSharePermissionResponse,
+        RusotoError<AssociateResourceSharePermissionError>,
+    > {
+        self.runtime
+            .block_on(self.client.associate_resource_share_permission(input))
+    }
+
+    /// Blocking version of [`Ram::create_resource_share`].
+    pub fn create_resource_share(
+        &self,
+        input: CreateResourceShareRequest,
+    ) -> Result<CreateResourceShareResponse, RusotoError<CreateResourceShareError>> {
+        self.runtime
+            .block_on(self.client.create_resource_share(input))
+    }
+
+    /// Blocking version of [`Ram::delete_resource_share`].
+    pub fn delete_resource_share(
+        &self,
+        input: DeleteResourceShareRequest,
+    ) -> Result<DeleteResourceShareResponse, RusotoError<DeleteResourceShareError>> {
+        self.runtime
+            .block_on(self.client.delete_resource_share(input))
+    }
+
+    /// Blocking version of [`Ram::disassociate_resource_share`].
+    pub fn disassociate_resource_share(
+        &self,
+        input: DisassociateResourceShareRequest,
+    ) -> Result<DisassociateResourceShareResponse, RusotoError<DisassociateResourceShareError>>
+    {
+        self.runtime
+            .block_on(self.client.disassociate_resource_share(input))
+    }
+
+    /// Blocking version of [`Ram::disassociate_resource_share_permission`].
+    pub fn disassociate_resource_share_permission(
+        &self,
+        input: DisassociateResourceSharePermissionRequest,
+    ) -> Result<
+        DisassociateResourceSharePermissionResponse,
+        RusotoError<DisassociateResourceSharePermissionError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_resource_share_permission(input))
+    }
+
+    /// Blocking version of [`Ram::enable_sharing_with_aws_organization`].
+    pub fn enable_sharing_with_aws_organization(
+        &self,
+    ) -> Result<
+        EnableSharingWithAwsOrganizationResponse,
+        RusotoError<EnableSharingWithAwsOrganizationError>,
+    > {
+        self.runtime
+            .block_on(self.client.enable_sharing_with_aws_organization())
+    }
+
+    /// Blocking version of [`Ram::get_permission`].
+    pub fn get_permission(
+        &self,
+        input: GetPermissionRequest,
+    ) -> Result<GetPermissionResponse, RusotoError<GetPermissionError>> {
+        self.runtime.block_on(self.client.get_permission(input))
+    }
+
+    /// Blocking version of [`Ram::get_resource_policies`].
+    pub fn get_resource_policies(
+        &self,
+        input: GetResourcePoliciesRequest,
+    ) -> Result<GetResourcePoliciesResponse, RusotoError<GetResourcePoliciesError>> {
+        self.runtime
+            .block_on(self.client.get_resource_policies(input))
+    }
+
+    /// Blocking version of [`Ram::get_resource_share_associations`].
+    pub fn get_resource_share_associations(
+        &self,
+        input: GetResourceShareAssociationsRequest,
+    ) -> Result<GetResourceShareAssociationsResponse, RusotoError<GetResourceShareAssociationsError>>
+    {
+        self.runtime
+            .block_on(self.client.get_resource_share_associations(input))
+    }
+
+    /// Blocking version of [`Ram::get_resource_share_invitations`].
+    pub fn get_resource_share_invitations(
+        &self,
+        input: GetResourceShareInvitationsRequest,
+    ) -> Result<GetResourceShareInvitationsResponse, RusotoError<GetResourceShareInvitationsError>>
+    {
+        self.runtime
+            .block_on(self.client.get_resource_share_invitations(input))
+    }
+
+    /// Blocking version of [`Ram::get_resource_shares`].
+    pub fn get_resource_shares(
+        &self,
+        input: GetResourceSharesRequest,
+    ) -> Result<GetResourceSharesResponse, RusotoError<GetResourceSharesError>> {
+        self.runtime
+            .block_on(self.client.get_resource_shares(input))
+    }
+
+    /// Blocking version of [`Ram::list_pending_invitation_resources`].
+    pub fn list_pending_invitation_resources(
+        &self,
+        input: ListPendingInvitationResourcesRequest,
+    ) -> Result<
+        ListPendingInvitationResourcesResponse,
+        RusotoError<ListPendingInvitationResourcesError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_pending_invitation_resources(input))
+    }
+
+    /// Blocking version of [`Ram::list_permissions`].
+    pub fn list_permissions(
+        &self,
+        input: ListPermissionsRequest,
+    ) -> Result<ListPermissionsResponse, RusotoError<ListPermissionsError>> {
+        self.runtime.block_on(self.client.list_permissions(input))
+    }
+
+    /// Blocking version of [`Ram::list_principals`].
+    pub fn list_principals(
+        &self,
+        input: ListPrincipalsRequest,
+    ) -> Result<ListPrincipalsResponse, RusotoError<ListPrincipalsError>> {
+        self.runtime.block_on(self.client.list_principals(input))
+    }
+
+    /// Blocking version of [`Ram::list_resource_share_permissions`].
+    pub fn list_resource_share_permissions(
+        &self,
+        input: ListResourceSharePermissionsRequest,
+    ) -> Result<ListResourceSharePermissionsResponse, RusotoError<ListResourceSharePermissionsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_resource_share_permissions(input))
+    }
+
+    /// Blocking version of [`Ram::list_resource_types`].
+    pub fn list_resource_types(
+        &self,
+        input: ListResourceTypesRequest,
+    ) -> Result<ListResourceTypesResponse, RusotoError<ListResourceTypesError>> {
+        self.runtime
+            .block_on(self.client.list_resource_types(input))
+    }
+
+    /// Blocking version of [`Ram::list_resources`].
+    pub fn list_resources(
+        &self,
+        input: ListResourcesRequest,
+    ) -> Result<ListResourcesResponse, RusotoError<ListResourcesError>> {
+        self.runtime.block_on(self.client.list_resources(input))
+    }
+
+    /// Blocking version of [`Ram::promote_resource_share_created_from_policy`].
+    pub fn promote_resource_share_created_from_policy(
+        &self,
+        input: PromoteResourceShareCreatedFromPolicyRequest,
+    ) -> Result<
+        PromoteResourceShareCreatedFromPolicyResponse,
+        RusotoError<PromoteResourceShareCreatedFromPolicyError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .promote_resource_share_created_from_policy(input),
+        )
+    }
+
+    /// Blocking version of [`Ram::reject_resource_share_invitation`].
+    pub fn reject_resource_share_invitation(
+        &self,
+        input: RejectResourceShareInvitationRequest,
+    ) -> Result<
+        RejectResourceShareInvitationResponse,
+        RusotoError<RejectResourceShareInvitationError>,
+    > {
+        self.runtime
+            .block_on(self.client.reject_resource_share_invitation(input))
+    }
+
+    /// Blocking version of [`Ram::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`Ram::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`Ram::update_resource_share`].
+    pub fn update_resource_share(
+        &self,
+        input: UpdateResourceShareRequest,
+    ) -> Result<UpdateResourceShareResponse, RusotoError<UpdateResourceShareError>> {
+        self.runtime
+            .block_on(self.client.update_resource_share(input))
+    }
+}
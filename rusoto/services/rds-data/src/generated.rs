@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -539,27 +540,46 @@ impl BatchExecuteStatementError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(BatchExecuteStatementError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchExecuteStatementError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(BatchExecuteStatementError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchExecuteStatementError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(BatchExecuteStatementError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchExecuteStatementError::InternalServerError,
+                    )
                 }
                 "ServiceUnavailableError" => {
-                    return RusotoError::Service(
-                        BatchExecuteStatementError::ServiceUnavailableError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchExecuteStatementError::ServiceUnavailableError,
                     )
                 }
                 "StatementTimeoutException" => {
-                    return RusotoError::Service(BatchExecuteStatementError::StatementTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchExecuteStatementError::StatementTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -601,25 +621,46 @@ impl BeginTransactionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(BeginTransactionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BeginTransactionError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(BeginTransactionError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BeginTransactionError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(BeginTransactionError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BeginTransactionError::InternalServerError,
+                    )
                 }
                 "ServiceUnavailableError" => {
-                    return RusotoError::Service(BeginTransactionError::ServiceUnavailableError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BeginTransactionError::ServiceUnavailableError,
+                    )
                 }
                 "StatementTimeoutException" => {
-                    return RusotoError::Service(BeginTransactionError::StatementTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BeginTransactionError::StatementTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -661,28 +702,54 @@ impl CommitTransactionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CommitTransactionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CommitTransactionError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CommitTransactionError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CommitTransactionError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CommitTransactionError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CommitTransactionError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(CommitTransactionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CommitTransactionError::NotFound,
+                    )
                 }
                 "ServiceUnavailableError" => {
-                    return RusotoError::Service(CommitTransactionError::ServiceUnavailableError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CommitTransactionError::ServiceUnavailableError,
+                    )
                 }
                 "StatementTimeoutException" => {
-                    return RusotoError::Service(CommitTransactionError::StatementTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CommitTransactionError::StatementTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -721,18 +788,38 @@ impl ExecuteSqlError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ExecuteSqlError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExecuteSqlError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ExecuteSqlError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExecuteSqlError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ExecuteSqlError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExecuteSqlError::InternalServerError,
+                    )
                 }
                 "ServiceUnavailableError" => {
-                    return RusotoError::Service(ExecuteSqlError::ServiceUnavailableError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExecuteSqlError::ServiceUnavailableError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -771,25 +858,46 @@ impl ExecuteStatementError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ExecuteStatementError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExecuteStatementError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ExecuteStatementError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExecuteStatementError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ExecuteStatementError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ExecuteStatementError::InternalServerError,
+                    )
                 }
                 "ServiceUnavailableError" => {
-                    return RusotoError::Service(ExecuteStatementError::ServiceUnavailableError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ExecuteStatementError::ServiceUnavailableError,
+                    )
                 }
                 "StatementTimeoutException" => {
-                    return RusotoError::Service(ExecuteStatementError::StatementTimeout(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ExecuteStatementError::StatementTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -831,30 +939,54 @@ impl RollbackTransactionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(RollbackTransactionError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RollbackTransactionError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(RollbackTransactionError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RollbackTransactionError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(RollbackTransactionError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RollbackTransactionError::InternalServerError,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(RollbackTransactionError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RollbackTransactionError::NotFound,
+                    )
                 }
                 "ServiceUnavailableError" => {
-                    return RusotoError::Service(RollbackTransactionError::ServiceUnavailableError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RollbackTransactionError::ServiceUnavailableError,
+                    )
                 }
                 "StatementTimeoutException" => {
-                    return RusotoError::Service(RollbackTransactionError::StatementTimeout(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RollbackTransactionError::StatementTimeout,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -924,11 +1056,12 @@ pub struct RdsDataClient {
 impl RdsDataClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> RdsDataClient {
         RdsDataClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "RDS Data"),
         }
     }
 
@@ -943,12 +1076,32 @@ impl RdsDataClient {
     {
         RdsDataClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "RDS Data"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> RdsDataClient {
-        RdsDataClient { client, region }
+        RdsDataClient {
+            client,
+            region: region_for_service(region, "RDS Data"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> RdsDataClient {
+        RdsDataClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("RDS Data"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> RdsDataClient {
+        RdsDataClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -963,6 +1116,7 @@ impl RdsData for RdsDataClient {
         let request_uri = "/BatchExecute";
 
         let mut request = SignedRequest::new("POST", "rds-data", &self.region, &request_uri);
+        request.set_operation("BatchExecuteStatement");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -994,6 +1148,7 @@ impl RdsData for RdsDataClient {
         let request_uri = "/BeginTransaction";
 
         let mut request = SignedRequest::new("POST", "rds-data", &self.region, &request_uri);
+        request.set_operation("BeginTransaction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1025,6 +1180,7 @@ impl RdsData for RdsDataClient {
         let request_uri = "/CommitTransaction";
 
         let mut request = SignedRequest::new("POST", "rds-data", &self.region, &request_uri);
+        request.set_operation("CommitTransaction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1056,6 +1212,7 @@ impl RdsData for RdsDataClient {
         let request_uri = "/ExecuteSql";
 
         let mut request = SignedRequest::new("POST", "rds-data", &self.region, &request_uri);
+        request.set_operation("ExecuteSql");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1087,6 +1244,7 @@ impl RdsData for RdsDataClient {
         let request_uri = "/Execute";
 
         let mut request = SignedRequest::new("POST", "rds-data", &self.region, &request_uri);
+        request.set_operation("ExecuteStatement");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1118,6 +1276,7 @@ impl RdsData for RdsDataClient {
         let request_uri = "/RollbackTransaction";
 
         let mut request = SignedRequest::new("POST", "rds-data", &self.region, &request_uri);
+        request.set_operation("RollbackTransaction");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1140,3 +1299,128 @@ impl RdsData for RdsDataClient {
         }
     }
 }
+
+/// A blocking client for the AWS RDS DataService API, running the calls of a `RdsDataClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `RdsDataClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct RdsDataBlockingClient {
+    client: RdsDataClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl RdsDataBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> RdsDataBlockingClient {
+        RdsDataBlockingClient::from_client(RdsDataClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> RdsDataBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        RdsDataBlockingClient::from_client(RdsDataClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> RdsDataBlockingClient {
+        RdsDataBlockingClient::from_client(RdsDataClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> RdsDataBlockingClient {
+        RdsDataBlockingClient::from_client(RdsDataClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: RdsDataClient) -> RdsDataBlockingClient {
+        RdsDataBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> RdsDataBlockingClient {
+        RdsDataBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> RdsDataBlockingClient {
+        RdsDataBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`RdsData::batch_execute_statement`].
+    pub fn batch_execute_statement(
+        &self,
+        input: BatchExecuteStatementRequest,
+    ) -> Result<BatchExecuteStatementResponse, RusotoError<BatchExecuteStatementError>> {
+        self.runtime
+            .block_on(self.client.batch_execute_statement(input))
+    }
+
+    /// Blocking version of [`RdsData::begin_transaction`].
+    pub fn begin_transaction(
+        &self,
+        input: BeginTransactionRequest,
+    ) -> Result<BeginTransactionResponse, RusotoError<BeginTransactionError>> {
+        self.runtime.block_on(self.client.begin_transaction(input))
+    }
+
+    /// Blocking version of [`RdsData::commit_transaction`].
+    pub fn commit_transaction(
+        &self,
+        input: CommitTransactionRequest,
+    ) -> Result<CommitTransactionResponse, RusotoError<CommitTransactionError>> {
+        self.runtime.block_on(self.client.commit_transaction(input))
+    }
+
+    /// Blocking version of [`RdsData::execute_sql`].
+    pub fn execute_sql(
+        &self,
+        input: ExecuteSqlRequest,
+    ) -> Result<ExecuteSqlResponse, RusotoError<ExecuteSqlError>> {
+        self.runtime.block_on(self.client.execute_sql(input))
+    }
+
+    /// Blocking version of [`RdsData::execute_statement`].
+    pub fn execute_statement(
+        &self,
+        input: ExecuteStatementRequest,
+    ) -> Result<ExecuteStatementResponse, RusotoError<ExecuteStatementError>> {
+        self.runtime.block_on(self.client.execute_statement(input))
+    }
+
+    /// Blocking version of [`RdsData::rollback_transaction`].
+    pub fn rollback_transaction(
+        &self,
+        input: RollbackTransactionRequest,
+    ) -> Result<RollbackTransactionResponse, RusotoError<RollbackTransactionError>> {
+        self.runtime
+            .block_on(self.client.rollback_transaction(input))
+    }
+}
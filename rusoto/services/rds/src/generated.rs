@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -19540,29 +19541,35 @@ impl AddRoleToDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            AddRoleToDBClusterError::DBClusterNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AddRoleToDBClusterError::DBClusterNotFoundFault,
                         )
                     }
                     "DBClusterRoleAlreadyExists" => {
-                        return RusotoError::Service(
-                            AddRoleToDBClusterError::DBClusterRoleAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AddRoleToDBClusterError::DBClusterRoleAlreadyExistsFault,
                         )
                     }
                     "DBClusterRoleQuotaExceeded" => {
-                        return RusotoError::Service(
-                            AddRoleToDBClusterError::DBClusterRoleQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AddRoleToDBClusterError::DBClusterRoleQuotaExceededFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            AddRoleToDBClusterError::InvalidDBClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AddRoleToDBClusterError::InvalidDBClusterStateFault,
                         )
                     }
                     _ => {}
@@ -19620,29 +19627,35 @@ impl AddRoleToDBInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
-                            AddRoleToDBInstanceError::DBInstanceNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AddRoleToDBInstanceError::DBInstanceNotFoundFault,
                         )
                     }
                     "DBInstanceRoleAlreadyExists" => {
-                        return RusotoError::Service(
-                            AddRoleToDBInstanceError::DBInstanceRoleAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AddRoleToDBInstanceError::DBInstanceRoleAlreadyExistsFault,
                         )
                     }
                     "DBInstanceRoleQuotaExceeded" => {
-                        return RusotoError::Service(
-                            AddRoleToDBInstanceError::DBInstanceRoleQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AddRoleToDBInstanceError::DBInstanceRoleQuotaExceededFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            AddRoleToDBInstanceError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AddRoleToDBInstanceError::InvalidDBInstanceStateFault,
                         )
                     }
                     _ => {}
@@ -19698,17 +19711,19 @@ impl AddSourceIdentifierToSubscriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "SourceNotFound" => {
-                        return RusotoError::Service(
-                            AddSourceIdentifierToSubscriptionError::SourceNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AddSourceIdentifierToSubscriptionError::SourceNotFoundFault,
                         )
                     }
                     "SubscriptionNotFound" => {
-                        return RusotoError::Service(
-                            AddSourceIdentifierToSubscriptionError::SubscriptionNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AddSourceIdentifierToSubscriptionError::SubscriptionNotFoundFault,
                         )
                     }
                     _ => {}
@@ -19764,30 +19779,43 @@ impl AddTagsToResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            AddTagsToResourceError::DBClusterNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AddTagsToResourceError::DBClusterNotFoundFault,
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
-                            AddTagsToResourceError::DBInstanceNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AddTagsToResourceError::DBInstanceNotFoundFault,
                         )
                     }
                     "DBProxyNotFoundFault" => {
-                        return RusotoError::Service(AddTagsToResourceError::DBProxyNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AddTagsToResourceError::DBProxyNotFoundFault,
+                        )
                     }
                     "DBProxyTargetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            AddTagsToResourceError::DBProxyTargetGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AddTagsToResourceError::DBProxyTargetGroupNotFoundFault,
                         )
                     }
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
-                            AddTagsToResourceError::DBSnapshotNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AddTagsToResourceError::DBSnapshotNotFoundFault,
                         )
                     }
                     _ => {}
@@ -19842,24 +19870,27 @@ impl ApplyPendingMaintenanceActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            ApplyPendingMaintenanceActionError::InvalidDBClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ApplyPendingMaintenanceActionError::InvalidDBClusterStateFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            ApplyPendingMaintenanceActionError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ApplyPendingMaintenanceActionError::InvalidDBInstanceStateFault,
                         )
                     }
                     "ResourceNotFoundFault" => {
-                        return RusotoError::Service(
-                            ApplyPendingMaintenanceActionError::ResourceNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ApplyPendingMaintenanceActionError::ResourceNotFoundFault,
                         )
                     }
                     _ => {}
@@ -19918,31 +19949,35 @@ impl AuthorizeDBSecurityGroupIngressError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationAlreadyExists" => {
-                        return RusotoError::Service(
-                            AuthorizeDBSecurityGroupIngressError::AuthorizationAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AuthorizeDBSecurityGroupIngressError::AuthorizationAlreadyExistsFault,
                         )
                     }
                     "AuthorizationQuotaExceeded" => {
-                        return RusotoError::Service(
-                            AuthorizeDBSecurityGroupIngressError::AuthorizationQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AuthorizeDBSecurityGroupIngressError::AuthorizationQuotaExceededFault,
                         )
                     }
                     "DBSecurityGroupNotFound" => {
-                        return RusotoError::Service(
-                            AuthorizeDBSecurityGroupIngressError::DBSecurityGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AuthorizeDBSecurityGroupIngressError::DBSecurityGroupNotFoundFault,
                         )
                     }
                     "InvalidDBSecurityGroupState" => {
-                        return RusotoError::Service(
-                            AuthorizeDBSecurityGroupIngressError::InvalidDBSecurityGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AuthorizeDBSecurityGroupIngressError::InvalidDBSecurityGroupStateFault,
                         )
                     }
                     _ => {}
@@ -19998,15 +20033,19 @@ impl BacktrackDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            BacktrackDBClusterError::DBClusterNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            BacktrackDBClusterError::DBClusterNotFoundFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            BacktrackDBClusterError::InvalidDBClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            BacktrackDBClusterError::InvalidDBClusterStateFault,
                         )
                     }
                     _ => {}
@@ -20054,15 +20093,19 @@ impl CancelExportTaskError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ExportTaskNotFound" => {
-                        return RusotoError::Service(
-                            CancelExportTaskError::ExportTaskNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CancelExportTaskError::ExportTaskNotFoundFault,
                         )
                     }
                     "InvalidExportTaskStateFault" => {
-                        return RusotoError::Service(
-                            CancelExportTaskError::InvalidExportTaskStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CancelExportTaskError::InvalidExportTaskStateFault,
                         )
                     }
                     _ => {}
@@ -20112,24 +20155,27 @@ impl CopyDBClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupAlreadyExists" => {
-                        return RusotoError::Service(
-                            CopyDBClusterParameterGroupError::DBParameterGroupAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyDBClusterParameterGroupError::DBParameterGroupAlreadyExistsFault,
                         )
                     }
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            CopyDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyDBClusterParameterGroupError::DBParameterGroupNotFoundFault,
                         )
                     }
                     "DBParameterGroupQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CopyDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -20190,45 +20236,51 @@ impl CopyDBClusterSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterSnapshotAlreadyExistsFault" => {
-                        return RusotoError::Service(
-                            CopyDBClusterSnapshotError::DBClusterSnapshotAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyDBClusterSnapshotError::DBClusterSnapshotAlreadyExistsFault,
                         )
                     }
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
-                            CopyDBClusterSnapshotError::DBClusterSnapshotNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyDBClusterSnapshotError::DBClusterSnapshotNotFoundFault,
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
-                            CopyDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            CopyDBClusterSnapshotError::InvalidDBClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyDBClusterSnapshotError::InvalidDBClusterStateFault,
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
-                            CopyDBClusterSnapshotError::KMSKeyNotAccessibleFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyDBClusterSnapshotError::KMSKeyNotAccessibleFault,
                         )
                     }
                     "SnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CopyDBClusterSnapshotError::SnapshotQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyDBClusterSnapshotError::SnapshotQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -20292,24 +20344,27 @@ impl CopyDBParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupAlreadyExists" => {
-                        return RusotoError::Service(
-                            CopyDBParameterGroupError::DBParameterGroupAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyDBParameterGroupError::DBParameterGroupAlreadyExistsFault,
                         )
                     }
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            CopyDBParameterGroupError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyDBParameterGroupError::DBParameterGroupNotFoundFault,
                         )
                     }
                     "DBParameterGroupQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CopyDBParameterGroupError::DBParameterGroupQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyDBParameterGroupError::DBParameterGroupQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -20370,35 +20425,51 @@ impl CopyDBSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CustomAvailabilityZoneNotFound" => {
-                        return RusotoError::Service(
-                            CopyDBSnapshotError::CustomAvailabilityZoneNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyDBSnapshotError::CustomAvailabilityZoneNotFoundFault,
                         )
                     }
                     "DBSnapshotAlreadyExists" => {
-                        return RusotoError::Service(
-                            CopyDBSnapshotError::DBSnapshotAlreadyExistsFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyDBSnapshotError::DBSnapshotAlreadyExistsFault,
                         )
                     }
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(CopyDBSnapshotError::DBSnapshotNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CopyDBSnapshotError::DBSnapshotNotFoundFault,
+                        )
                     }
                     "InvalidDBSnapshotState" => {
-                        return RusotoError::Service(
-                            CopyDBSnapshotError::InvalidDBSnapshotStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyDBSnapshotError::InvalidDBSnapshotStateFault,
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(CopyDBSnapshotError::KMSKeyNotAccessibleFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CopyDBSnapshotError::KMSKeyNotAccessibleFault,
+                        )
                     }
                     "SnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CopyDBSnapshotError::SnapshotQuotaExceededFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyDBSnapshotError::SnapshotQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -20452,22 +20523,27 @@ impl CopyOptionGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "OptionGroupAlreadyExistsFault" => {
-                        return RusotoError::Service(
-                            CopyOptionGroupError::OptionGroupAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyOptionGroupError::OptionGroupAlreadyExistsFault,
                         )
                     }
                     "OptionGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            CopyOptionGroupError::OptionGroupNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyOptionGroupError::OptionGroupNotFoundFault,
                         )
                     }
                     "OptionGroupQuotaExceededFault" => {
-                        return RusotoError::Service(
-                            CopyOptionGroupError::OptionGroupQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyOptionGroupError::OptionGroupQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -20521,21 +20597,24 @@ impl CreateCustomAvailabilityZoneError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                    "CustomAvailabilityZoneAlreadyExists" => return RusotoError::Service(
-                        CreateCustomAvailabilityZoneError::CustomAvailabilityZoneAlreadyExistsFault(
-                            parsed_error.message,
-                        ),
+                    "CustomAvailabilityZoneAlreadyExists" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        CreateCustomAvailabilityZoneError::CustomAvailabilityZoneAlreadyExistsFault,
                     ),
-                    "CustomAvailabilityZoneQuotaExceeded" => return RusotoError::Service(
-                        CreateCustomAvailabilityZoneError::CustomAvailabilityZoneQuotaExceededFault(
-                            parsed_error.message,
-                        ),
+                    "CustomAvailabilityZoneQuotaExceeded" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        CreateCustomAvailabilityZoneError::CustomAvailabilityZoneQuotaExceededFault,
                     ),
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
-                            CreateCustomAvailabilityZoneError::KMSKeyNotAccessibleFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateCustomAvailabilityZoneError::KMSKeyNotAccessibleFault,
                         )
                     }
                     _ => {}
@@ -20620,103 +20699,147 @@ impl CreateDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterAlreadyExistsFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterError::DBClusterAlreadyExistsFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterError::DBClusterAlreadyExistsFault,
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(CreateDBClusterError::DBClusterNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateDBClusterError::DBClusterNotFoundFault,
+                        )
                     }
                     "DBClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            CreateDBClusterError::DBClusterParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterError::DBClusterParameterGroupNotFoundFault,
                         )
                     }
                     "DBClusterQuotaExceededFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterError::DBClusterQuotaExceededFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterError::DBClusterQuotaExceededFault,
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(CreateDBClusterError::DBInstanceNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateDBClusterError::DBInstanceNotFoundFault,
+                        )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
-                        return RusotoError::Service(
-                            CreateDBClusterError::DBSubnetGroupDoesNotCoverEnoughAZs(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterError::DBSubnetGroupDoesNotCoverEnoughAZs,
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterError::DBSubnetGroupNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterError::DBSubnetGroupNotFoundFault,
                         )
                     }
                     "DomainNotFoundFault" => {
-                        return RusotoError::Service(CreateDBClusterError::DomainNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateDBClusterError::DomainNotFoundFault,
+                        )
                     }
                     "GlobalClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterError::GlobalClusterNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterError::GlobalClusterNotFoundFault,
                         )
                     }
                     "InsufficientStorageClusterCapacity" => {
-                        return RusotoError::Service(
-                            CreateDBClusterError::InsufficientStorageClusterCapacityFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterError::InsufficientStorageClusterCapacityFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterError::InvalidDBClusterStateFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            CreateDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterError::InvalidDBInstanceStateFault,
                         )
                     }
                     "InvalidDBSubnetGroupStateFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterError::InvalidDBSubnetGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterError::InvalidDBSubnetGroupStateFault,
                         )
                     }
                     "InvalidGlobalClusterStateFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterError::InvalidGlobalClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterError::InvalidGlobalClusterStateFault,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateDBClusterError::InvalidSubnet(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateDBClusterError::InvalidSubnet,
+                        )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterError::InvalidVPCNetworkStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterError::InvalidVPCNetworkStateFault,
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterError::KMSKeyNotAccessibleFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterError::KMSKeyNotAccessibleFault,
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CreateDBClusterError::StorageQuotaExceededFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterError::StorageQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -20796,45 +20919,51 @@ impl CreateDBClusterEndpointError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterEndpointAlreadyExistsFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterEndpointError::DBClusterEndpointAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterEndpointError::DBClusterEndpointAlreadyExistsFault,
                         )
                     }
                     "DBClusterEndpointQuotaExceededFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterEndpointError::DBClusterEndpointQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterEndpointError::DBClusterEndpointQuotaExceededFault,
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterEndpointError::DBClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterEndpointError::DBClusterNotFoundFault,
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
-                            CreateDBClusterEndpointError::DBInstanceNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterEndpointError::DBInstanceNotFoundFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterEndpointError::InvalidDBClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterEndpointError::InvalidDBClusterStateFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            CreateDBClusterEndpointError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterEndpointError::InvalidDBInstanceStateFault,
                         )
                     }
                     _ => {}
@@ -20898,17 +21027,19 @@ impl CreateDBClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupAlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateDBClusterParameterGroupError::DBParameterGroupAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterParameterGroupError::DBParameterGroupAlreadyExistsFault,
                         )
                     }
                     "DBParameterGroupQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CreateDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterParameterGroupError::DBParameterGroupQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -20964,38 +21095,43 @@ impl CreateDBClusterSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterSnapshotError::DBClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterSnapshotError::DBClusterNotFoundFault,
                         )
                     }
                     "DBClusterSnapshotAlreadyExistsFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterSnapshotError::DBClusterSnapshotAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterSnapshotError::DBClusterSnapshotAlreadyExistsFault,
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            CreateDBClusterSnapshotError::InvalidDBClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterSnapshotError::InvalidDBClusterStateFault,
                         )
                     }
                     "SnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CreateDBClusterSnapshotError::SnapshotQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBClusterSnapshotError::SnapshotQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -21088,114 +21224,155 @@ impl CreateDBInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationNotFound" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::AuthorizationNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::AuthorizationNotFoundFault,
                         )
                     }
                     "BackupPolicyNotFoundFault" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::BackupPolicyNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::BackupPolicyNotFoundFault,
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(CreateDBInstanceError::DBClusterNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateDBInstanceError::DBClusterNotFoundFault,
+                        )
                     }
                     "DBInstanceAlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::DBInstanceAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::DBInstanceAlreadyExistsFault,
                         )
                     }
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::DBParameterGroupNotFoundFault,
                         )
                     }
                     "DBSecurityGroupNotFound" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::DBSecurityGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::DBSecurityGroupNotFoundFault,
                         )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::DBSubnetGroupDoesNotCoverEnoughAZs(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::DBSubnetGroupDoesNotCoverEnoughAZs,
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::DBSubnetGroupNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::DBSubnetGroupNotFoundFault,
                         )
                     }
                     "DomainNotFoundFault" => {
-                        return RusotoError::Service(CreateDBInstanceError::DomainNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateDBInstanceError::DomainNotFoundFault,
+                        )
                     }
                     "InstanceQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::InstanceQuotaExceededFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::InstanceQuotaExceededFault,
                         )
                     }
                     "InsufficientDBInstanceCapacity" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::InsufficientDBInstanceCapacityFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::InsufficientDBInstanceCapacityFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::InvalidDBClusterStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::InvalidDBClusterStateFault,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateDBInstanceError::InvalidSubnet(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateDBInstanceError::InvalidSubnet,
+                        )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::InvalidVPCNetworkStateFault,
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::KMSKeyNotAccessibleFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::KMSKeyNotAccessibleFault,
                         )
                     }
                     "OptionGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::OptionGroupNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::OptionGroupNotFoundFault,
                         )
                     }
                     "ProvisionedIopsNotAvailableInAZFault" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::ProvisionedIopsNotAvailableInAZFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::ProvisionedIopsNotAvailableInAZFault,
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::StorageQuotaExceededFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::StorageQuotaExceededFault,
                         )
                     }
                     "StorageTypeNotSupported" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceError::StorageTypeNotSupportedFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceError::StorageTypeNotSupportedFault,
                         )
                     }
                     _ => {}
@@ -21308,134 +21485,155 @@ impl CreateDBInstanceReadReplicaError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceAlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceReadReplicaError::DBInstanceAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceReadReplicaError::DBInstanceAlreadyExistsFault,
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceReadReplicaError::DBInstanceNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceReadReplicaError::DBInstanceNotFoundFault,
                         )
                     }
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceReadReplicaError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceReadReplicaError::DBParameterGroupNotFoundFault,
                         )
                     }
                     "DBSecurityGroupNotFound" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceReadReplicaError::DBSecurityGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceReadReplicaError::DBSecurityGroupNotFoundFault,
                         )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceReadReplicaError::DBSubnetGroupDoesNotCoverEnoughAZs(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceReadReplicaError::DBSubnetGroupDoesNotCoverEnoughAZs,
                         )
                     }
                     "DBSubnetGroupNotAllowedFault" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceReadReplicaError::DBSubnetGroupNotAllowedFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceReadReplicaError::DBSubnetGroupNotAllowedFault,
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceReadReplicaError::DBSubnetGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceReadReplicaError::DBSubnetGroupNotFoundFault,
                         )
                     }
                     "DomainNotFoundFault" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceReadReplicaError::DomainNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceReadReplicaError::DomainNotFoundFault,
                         )
                     }
                     "InstanceQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceReadReplicaError::InstanceQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceReadReplicaError::InstanceQuotaExceededFault,
                         )
                     }
                     "InsufficientDBInstanceCapacity" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceReadReplicaError::InsufficientDBInstanceCapacityFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceReadReplicaError::InsufficientDBInstanceCapacityFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceReadReplicaError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceReadReplicaError::InvalidDBInstanceStateFault,
                         )
                     }
                     "InvalidDBSubnetGroupFault" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceReadReplicaError::InvalidDBSubnetGroupFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceReadReplicaError::InvalidDBSubnetGroupFault,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceReadReplicaError::InvalidSubnet(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceReadReplicaError::InvalidSubnet,
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceReadReplicaError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceReadReplicaError::InvalidVPCNetworkStateFault,
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceReadReplicaError::KMSKeyNotAccessibleFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceReadReplicaError::KMSKeyNotAccessibleFault,
                         )
                     }
                     "OptionGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceReadReplicaError::OptionGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceReadReplicaError::OptionGroupNotFoundFault,
                         )
                     }
                     "ProvisionedIopsNotAvailableInAZFault" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceReadReplicaError::ProvisionedIopsNotAvailableInAZFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceReadReplicaError::ProvisionedIopsNotAvailableInAZFault,
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceReadReplicaError::StorageQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceReadReplicaError::StorageQuotaExceededFault,
                         )
                     }
                     "StorageTypeNotSupported" => {
-                        return RusotoError::Service(
-                            CreateDBInstanceReadReplicaError::StorageTypeNotSupportedFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBInstanceReadReplicaError::StorageTypeNotSupportedFault,
                         )
                     }
                     _ => {}
@@ -21534,17 +21732,19 @@ impl CreateDBParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupAlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateDBParameterGroupError::DBParameterGroupAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBParameterGroupError::DBParameterGroupAlreadyExistsFault,
                         )
                     }
                     "DBParameterGroupQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CreateDBParameterGroupError::DBParameterGroupQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBParameterGroupError::DBParameterGroupQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -21596,19 +21796,28 @@ impl CreateDBProxyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBProxyTargetExistsFault" => {
-                        return RusotoError::Service(CreateDBProxyError::DBProxyAlreadyExistsFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateDBProxyError::DBProxyAlreadyExistsFault,
+                        )
                     }
                     "DBProxyQuotaExceededFault" => {
-                        return RusotoError::Service(CreateDBProxyError::DBProxyQuotaExceededFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateDBProxyError::DBProxyQuotaExceededFault,
+                        )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateDBProxyError::InvalidSubnet(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateDBProxyError::InvalidSubnet,
+                        )
                     }
                     _ => {}
                 }
@@ -21656,24 +21865,27 @@ impl CreateDBSecurityGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSecurityGroupAlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateDBSecurityGroupError::DBSecurityGroupAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBSecurityGroupError::DBSecurityGroupAlreadyExistsFault,
                         )
                     }
                     "DBSecurityGroupNotSupported" => {
-                        return RusotoError::Service(
-                            CreateDBSecurityGroupError::DBSecurityGroupNotSupportedFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBSecurityGroupError::DBSecurityGroupNotSupportedFault,
                         )
                     }
                     "QuotaExceeded.DBSecurityGroup" => {
-                        return RusotoError::Service(
-                            CreateDBSecurityGroupError::DBSecurityGroupQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBSecurityGroupError::DBSecurityGroupQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -21730,27 +21942,35 @@ impl CreateDBSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
-                            CreateDBSnapshotError::DBInstanceNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBSnapshotError::DBInstanceNotFoundFault,
                         )
                     }
                     "DBSnapshotAlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateDBSnapshotError::DBSnapshotAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBSnapshotError::DBSnapshotAlreadyExistsFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            CreateDBSnapshotError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBSnapshotError::InvalidDBInstanceStateFault,
                         )
                     }
                     "SnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CreateDBSnapshotError::SnapshotQuotaExceededFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBSnapshotError::SnapshotQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -21806,37 +22026,44 @@ impl CreateDBSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSubnetGroupAlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateDBSubnetGroupError::DBSubnetGroupAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBSubnetGroupError::DBSubnetGroupAlreadyExistsFault,
                         )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
-                        return RusotoError::Service(
-                            CreateDBSubnetGroupError::DBSubnetGroupDoesNotCoverEnoughAZs(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBSubnetGroupError::DBSubnetGroupDoesNotCoverEnoughAZs,
                         )
                     }
                     "DBSubnetGroupQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CreateDBSubnetGroupError::DBSubnetGroupQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBSubnetGroupError::DBSubnetGroupQuotaExceededFault,
                         )
                     }
                     "DBSubnetQuotaExceededFault" => {
-                        return RusotoError::Service(
-                            CreateDBSubnetGroupError::DBSubnetQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateDBSubnetGroupError::DBSubnetQuotaExceededFault,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateDBSubnetGroupError::InvalidSubnet(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateDBSubnetGroupError::InvalidSubnet,
+                        )
                     }
                     _ => {}
                 }
@@ -21902,50 +22129,59 @@ impl CreateEventSubscriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EventSubscriptionQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CreateEventSubscriptionError::EventSubscriptionQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateEventSubscriptionError::EventSubscriptionQuotaExceededFault,
                         )
                     }
                     "SNSInvalidTopic" => {
-                        return RusotoError::Service(
-                            CreateEventSubscriptionError::SNSInvalidTopicFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateEventSubscriptionError::SNSInvalidTopicFault,
                         )
                     }
                     "SNSNoAuthorization" => {
-                        return RusotoError::Service(
-                            CreateEventSubscriptionError::SNSNoAuthorizationFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateEventSubscriptionError::SNSNoAuthorizationFault,
                         )
                     }
                     "SNSTopicArnNotFound" => {
-                        return RusotoError::Service(
-                            CreateEventSubscriptionError::SNSTopicArnNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateEventSubscriptionError::SNSTopicArnNotFoundFault,
                         )
                     }
                     "SourceNotFound" => {
-                        return RusotoError::Service(
-                            CreateEventSubscriptionError::SourceNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateEventSubscriptionError::SourceNotFoundFault,
                         )
                     }
                     "SubscriptionAlreadyExist" => {
-                        return RusotoError::Service(
-                            CreateEventSubscriptionError::SubscriptionAlreadyExistFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateEventSubscriptionError::SubscriptionAlreadyExistFault,
                         )
                     }
                     "SubscriptionCategoryNotFound" => {
-                        return RusotoError::Service(
-                            CreateEventSubscriptionError::SubscriptionCategoryNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateEventSubscriptionError::SubscriptionCategoryNotFoundFault,
                         )
                     }
                     _ => {}
@@ -22010,29 +22246,35 @@ impl CreateGlobalClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            CreateGlobalClusterError::DBClusterNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateGlobalClusterError::DBClusterNotFoundFault,
                         )
                     }
                     "GlobalClusterAlreadyExistsFault" => {
-                        return RusotoError::Service(
-                            CreateGlobalClusterError::GlobalClusterAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateGlobalClusterError::GlobalClusterAlreadyExistsFault,
                         )
                     }
                     "GlobalClusterQuotaExceededFault" => {
-                        return RusotoError::Service(
-                            CreateGlobalClusterError::GlobalClusterQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateGlobalClusterError::GlobalClusterQuotaExceededFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            CreateGlobalClusterError::InvalidDBClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateGlobalClusterError::InvalidDBClusterStateFault,
                         )
                     }
                     _ => {}
@@ -22086,17 +22328,19 @@ impl CreateOptionGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "OptionGroupAlreadyExistsFault" => {
-                        return RusotoError::Service(
-                            CreateOptionGroupError::OptionGroupAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateOptionGroupError::OptionGroupAlreadyExistsFault,
                         )
                     }
                     "OptionGroupQuotaExceededFault" => {
-                        return RusotoError::Service(
-                            CreateOptionGroupError::OptionGroupQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateOptionGroupError::OptionGroupQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -22148,17 +22392,19 @@ impl DeleteCustomAvailabilityZoneError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CustomAvailabilityZoneNotFound" => {
-                        return RusotoError::Service(
-                            DeleteCustomAvailabilityZoneError::CustomAvailabilityZoneNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteCustomAvailabilityZoneError::CustomAvailabilityZoneNotFoundFault,
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
-                            DeleteCustomAvailabilityZoneError::KMSKeyNotAccessibleFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteCustomAvailabilityZoneError::KMSKeyNotAccessibleFault,
                         )
                     }
                     _ => {}
@@ -22214,32 +22460,43 @@ impl DeleteDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(DeleteDBClusterError::DBClusterNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteDBClusterError::DBClusterNotFoundFault,
+                        )
                     }
                     "DBClusterSnapshotAlreadyExistsFault" => {
-                        return RusotoError::Service(
-                            DeleteDBClusterError::DBClusterSnapshotAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBClusterError::DBClusterSnapshotAlreadyExistsFault,
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
-                            DeleteDBClusterError::InvalidDBClusterSnapshotStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBClusterError::InvalidDBClusterSnapshotStateFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            DeleteDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBClusterError::InvalidDBClusterStateFault,
                         )
                     }
                     "SnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
-                            DeleteDBClusterError::SnapshotQuotaExceededFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBClusterError::SnapshotQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -22294,24 +22551,27 @@ impl DeleteDBClusterEndpointError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterEndpointNotFoundFault" => {
-                        return RusotoError::Service(
-                            DeleteDBClusterEndpointError::DBClusterEndpointNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBClusterEndpointError::DBClusterEndpointNotFoundFault,
                         )
                     }
                     "InvalidDBClusterEndpointStateFault" => {
-                        return RusotoError::Service(
-                            DeleteDBClusterEndpointError::InvalidDBClusterEndpointStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBClusterEndpointError::InvalidDBClusterEndpointStateFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            DeleteDBClusterEndpointError::InvalidDBClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBClusterEndpointError::InvalidDBClusterStateFault,
                         )
                     }
                     _ => {}
@@ -22366,17 +22626,19 @@ impl DeleteDBClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            DeleteDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBClusterParameterGroupError::DBParameterGroupNotFoundFault,
                         )
                     }
                     "InvalidDBParameterGroupState" => {
-                        return RusotoError::Service(
-                            DeleteDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBClusterParameterGroupError::InvalidDBParameterGroupStateFault,
                         )
                     }
                     _ => {}
@@ -22426,17 +22688,19 @@ impl DeleteDBClusterSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
-                            DeleteDBClusterSnapshotError::DBClusterSnapshotNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBClusterSnapshotError::DBClusterSnapshotNotFoundFault,
                         )
                     }
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
-                            DeleteDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBClusterSnapshotError::InvalidDBClusterSnapshotStateFault,
                         )
                     }
                     _ => {}
@@ -22494,39 +22758,51 @@ impl DeleteDBInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceAutomatedBackupQuotaExceeded" => {
-                        return RusotoError::Service(
-                            DeleteDBInstanceError::DBInstanceAutomatedBackupQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBInstanceError::DBInstanceAutomatedBackupQuotaExceededFault,
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
-                            DeleteDBInstanceError::DBInstanceNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBInstanceError::DBInstanceNotFoundFault,
                         )
                     }
                     "DBSnapshotAlreadyExists" => {
-                        return RusotoError::Service(
-                            DeleteDBInstanceError::DBSnapshotAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBInstanceError::DBSnapshotAlreadyExistsFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            DeleteDBInstanceError::InvalidDBClusterStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBInstanceError::InvalidDBClusterStateFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            DeleteDBInstanceError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBInstanceError::InvalidDBInstanceStateFault,
                         )
                     }
                     "SnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
-                            DeleteDBInstanceError::SnapshotQuotaExceededFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBInstanceError::SnapshotQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -22581,7 +22857,7 @@ impl DeleteDBInstanceAutomatedBackupError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "DBInstanceAutomatedBackupNotFound" => return RusotoError::Service(DeleteDBInstanceAutomatedBackupError::DBInstanceAutomatedBackupNotFoundFault(parsed_error.message)),"InvalidDBInstanceAutomatedBackupState" => return RusotoError::Service(DeleteDBInstanceAutomatedBackupError::InvalidDBInstanceAutomatedBackupStateFault(parsed_error.message)),_ => {}
+                                    "DBInstanceAutomatedBackupNotFound" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, DeleteDBInstanceAutomatedBackupError::DBInstanceAutomatedBackupNotFoundFault),"InvalidDBInstanceAutomatedBackupState" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, DeleteDBInstanceAutomatedBackupError::InvalidDBInstanceAutomatedBackupStateFault),_ => {}
                                 }
             }
         }
@@ -22628,17 +22904,19 @@ impl DeleteDBParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            DeleteDBParameterGroupError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBParameterGroupError::DBParameterGroupNotFoundFault,
                         )
                     }
                     "InvalidDBParameterGroupState" => {
-                        return RusotoError::Service(
-                            DeleteDBParameterGroupError::InvalidDBParameterGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBParameterGroupError::InvalidDBParameterGroupStateFault,
                         )
                     }
                     _ => {}
@@ -22688,14 +22966,20 @@ impl DeleteDBProxyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBProxyNotFoundFault" => {
-                        return RusotoError::Service(DeleteDBProxyError::DBProxyNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteDBProxyError::DBProxyNotFoundFault,
+                        )
                     }
                     "InvalidDBProxyStateFault" => {
-                        return RusotoError::Service(DeleteDBProxyError::InvalidDBProxyStateFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteDBProxyError::InvalidDBProxyStateFault,
+                        )
                     }
                     _ => {}
                 }
@@ -22740,17 +23024,19 @@ impl DeleteDBSecurityGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSecurityGroupNotFound" => {
-                        return RusotoError::Service(
-                            DeleteDBSecurityGroupError::DBSecurityGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBSecurityGroupError::DBSecurityGroupNotFoundFault,
                         )
                     }
                     "InvalidDBSecurityGroupState" => {
-                        return RusotoError::Service(
-                            DeleteDBSecurityGroupError::InvalidDBSecurityGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBSecurityGroupError::InvalidDBSecurityGroupStateFault,
                         )
                     }
                     _ => {}
@@ -22800,15 +23086,19 @@ impl DeleteDBSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
-                            DeleteDBSnapshotError::DBSnapshotNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBSnapshotError::DBSnapshotNotFoundFault,
                         )
                     }
                     "InvalidDBSnapshotState" => {
-                        return RusotoError::Service(
-                            DeleteDBSnapshotError::InvalidDBSnapshotStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBSnapshotError::InvalidDBSnapshotStateFault,
                         )
                     }
                     _ => {}
@@ -22856,24 +23146,27 @@ impl DeleteDBSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            DeleteDBSubnetGroupError::DBSubnetGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBSubnetGroupError::DBSubnetGroupNotFoundFault,
                         )
                     }
                     "InvalidDBSubnetGroupStateFault" => {
-                        return RusotoError::Service(
-                            DeleteDBSubnetGroupError::InvalidDBSubnetGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBSubnetGroupError::InvalidDBSubnetGroupStateFault,
                         )
                     }
                     "InvalidDBSubnetStateFault" => {
-                        return RusotoError::Service(
-                            DeleteDBSubnetGroupError::InvalidDBSubnetStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteDBSubnetGroupError::InvalidDBSubnetStateFault,
                         )
                     }
                     _ => {}
@@ -22926,17 +23219,19 @@ impl DeleteEventSubscriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidEventSubscriptionState" => {
-                        return RusotoError::Service(
-                            DeleteEventSubscriptionError::InvalidEventSubscriptionStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteEventSubscriptionError::InvalidEventSubscriptionStateFault,
                         )
                     }
                     "SubscriptionNotFound" => {
-                        return RusotoError::Service(
-                            DeleteEventSubscriptionError::SubscriptionNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteEventSubscriptionError::SubscriptionNotFoundFault,
                         )
                     }
                     _ => {}
@@ -22986,17 +23281,19 @@ impl DeleteGlobalClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "GlobalClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            DeleteGlobalClusterError::GlobalClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteGlobalClusterError::GlobalClusterNotFoundFault,
                         )
                     }
                     "InvalidGlobalClusterStateFault" => {
-                        return RusotoError::Service(
-                            DeleteGlobalClusterError::InvalidGlobalClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteGlobalClusterError::InvalidGlobalClusterStateFault,
                         )
                     }
                     _ => {}
@@ -23044,10 +23341,11 @@ impl DeleteInstallationMediaError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InstallationMediaNotFound" => {
-                        return RusotoError::Service(
-                            DeleteInstallationMediaError::InstallationMediaNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteInstallationMediaError::InstallationMediaNotFoundFault,
                         )
                     }
                     _ => {}
@@ -23094,15 +23392,19 @@ impl DeleteOptionGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidOptionGroupStateFault" => {
-                        return RusotoError::Service(
-                            DeleteOptionGroupError::InvalidOptionGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteOptionGroupError::InvalidOptionGroupStateFault,
                         )
                     }
                     "OptionGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            DeleteOptionGroupError::OptionGroupNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteOptionGroupError::OptionGroupNotFoundFault,
                         )
                     }
                     _ => {}
@@ -23154,31 +23456,35 @@ impl DeregisterDBProxyTargetsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBProxyNotFoundFault" => {
-                        return RusotoError::Service(
-                            DeregisterDBProxyTargetsError::DBProxyNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeregisterDBProxyTargetsError::DBProxyNotFoundFault,
                         )
                     }
                     "DBProxyTargetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            DeregisterDBProxyTargetsError::DBProxyTargetGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeregisterDBProxyTargetsError::DBProxyTargetGroupNotFoundFault,
                         )
                     }
                     "DBProxyTargetNotFoundFault" => {
-                        return RusotoError::Service(
-                            DeregisterDBProxyTargetsError::DBProxyTargetNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeregisterDBProxyTargetsError::DBProxyTargetNotFoundFault,
                         )
                     }
                     "InvalidDBProxyStateFault" => {
-                        return RusotoError::Service(
-                            DeregisterDBProxyTargetsError::InvalidDBProxyStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeregisterDBProxyTargetsError::InvalidDBProxyStateFault,
                         )
                     }
                     _ => {}
@@ -23266,10 +23572,11 @@ impl DescribeCertificatesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CertificateNotFound" => {
-                        return RusotoError::Service(
-                            DescribeCertificatesError::CertificateNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeCertificatesError::CertificateNotFoundFault,
                         )
                     }
                     _ => {}
@@ -23315,10 +23622,11 @@ impl DescribeCustomAvailabilityZonesError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                    "CustomAvailabilityZoneNotFound" => return RusotoError::Service(
-                        DescribeCustomAvailabilityZonesError::CustomAvailabilityZoneNotFoundFault(
-                            parsed_error.message,
-                        ),
+                    "CustomAvailabilityZoneNotFound" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        DescribeCustomAvailabilityZonesError::CustomAvailabilityZoneNotFoundFault,
                     ),
                     _ => {}
                 }
@@ -23366,17 +23674,19 @@ impl DescribeDBClusterBacktracksError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterBacktrackNotFoundFault" => {
-                        return RusotoError::Service(
-                            DescribeDBClusterBacktracksError::DBClusterBacktrackNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeDBClusterBacktracksError::DBClusterBacktrackNotFoundFault,
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            DescribeDBClusterBacktracksError::DBClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeDBClusterBacktracksError::DBClusterNotFoundFault,
                         )
                     }
                     _ => {}
@@ -23426,10 +23736,11 @@ impl DescribeDBClusterEndpointsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            DescribeDBClusterEndpointsError::DBClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeDBClusterEndpointsError::DBClusterNotFoundFault,
                         )
                     }
                     _ => {}
@@ -23476,10 +23787,11 @@ impl DescribeDBClusterParameterGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            DescribeDBClusterParameterGroupsError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeDBClusterParameterGroupsError::DBParameterGroupNotFoundFault,
                         )
                     }
                     _ => {}
@@ -23526,10 +23838,11 @@ impl DescribeDBClusterParametersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            DescribeDBClusterParametersError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeDBClusterParametersError::DBParameterGroupNotFoundFault,
                         )
                     }
                     _ => {}
@@ -23575,10 +23888,11 @@ impl DescribeDBClusterSnapshotAttributesError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                    "DBClusterSnapshotNotFoundFault" => return RusotoError::Service(
-                        DescribeDBClusterSnapshotAttributesError::DBClusterSnapshotNotFoundFault(
-                            parsed_error.message,
-                        ),
+                    "DBClusterSnapshotNotFoundFault" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        DescribeDBClusterSnapshotAttributesError::DBClusterSnapshotNotFoundFault,
                     ),
                     _ => {}
                 }
@@ -23624,10 +23938,11 @@ impl DescribeDBClusterSnapshotsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
-                            DescribeDBClusterSnapshotsError::DBClusterSnapshotNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeDBClusterSnapshotsError::DBClusterSnapshotNotFoundFault,
                         )
                     }
                     _ => {}
@@ -23672,8 +23987,11 @@ impl DescribeDBClustersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            DescribeDBClustersError::DBClusterNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeDBClustersError::DBClusterNotFoundFault,
                         )
                     }
                     _ => {}
@@ -23751,7 +24069,7 @@ impl DescribeDBInstanceAutomatedBackupsError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "DBInstanceAutomatedBackupNotFound" => return RusotoError::Service(DescribeDBInstanceAutomatedBackupsError::DBInstanceAutomatedBackupNotFoundFault(parsed_error.message)),_ => {}
+                                    "DBInstanceAutomatedBackupNotFound" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, DescribeDBInstanceAutomatedBackupsError::DBInstanceAutomatedBackupNotFoundFault),_ => {}
                                 }
             }
         }
@@ -23793,8 +24111,11 @@ impl DescribeDBInstancesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
-                            DescribeDBInstancesError::DBInstanceNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeDBInstancesError::DBInstanceNotFoundFault,
                         )
                     }
                     _ => {}
@@ -23837,8 +24158,11 @@ impl DescribeDBLogFilesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
-                            DescribeDBLogFilesError::DBInstanceNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeDBLogFilesError::DBInstanceNotFoundFault,
                         )
                     }
                     _ => {}
@@ -23881,10 +24205,11 @@ impl DescribeDBParameterGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            DescribeDBParameterGroupsError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeDBParameterGroupsError::DBParameterGroupNotFoundFault,
                         )
                     }
                     _ => {}
@@ -23929,10 +24254,11 @@ impl DescribeDBParametersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            DescribeDBParametersError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeDBParametersError::DBParameterGroupNotFoundFault,
                         )
                     }
                     _ => {}
@@ -23977,9 +24303,12 @@ impl DescribeDBProxiesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBProxyNotFoundFault" => {
-                        return RusotoError::Service(DescribeDBProxiesError::DBProxyNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeDBProxiesError::DBProxyNotFoundFault,
+                        )
                     }
                     _ => {}
                 }
@@ -24027,24 +24356,27 @@ impl DescribeDBProxyTargetGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBProxyNotFoundFault" => {
-                        return RusotoError::Service(
-                            DescribeDBProxyTargetGroupsError::DBProxyNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeDBProxyTargetGroupsError::DBProxyNotFoundFault,
                         )
                     }
                     "DBProxyTargetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            DescribeDBProxyTargetGroupsError::DBProxyTargetGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeDBProxyTargetGroupsError::DBProxyTargetGroupNotFoundFault,
                         )
                     }
                     "InvalidDBProxyStateFault" => {
-                        return RusotoError::Service(
-                            DescribeDBProxyTargetGroupsError::InvalidDBProxyStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeDBProxyTargetGroupsError::InvalidDBProxyStateFault,
                         )
                     }
                     _ => {}
@@ -24101,29 +24433,35 @@ impl DescribeDBProxyTargetsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBProxyNotFoundFault" => {
-                        return RusotoError::Service(
-                            DescribeDBProxyTargetsError::DBProxyNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeDBProxyTargetsError::DBProxyNotFoundFault,
                         )
                     }
                     "DBProxyTargetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            DescribeDBProxyTargetsError::DBProxyTargetGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeDBProxyTargetsError::DBProxyTargetGroupNotFoundFault,
                         )
                     }
                     "DBProxyTargetNotFoundFault" => {
-                        return RusotoError::Service(
-                            DescribeDBProxyTargetsError::DBProxyTargetNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeDBProxyTargetsError::DBProxyTargetNotFoundFault,
                         )
                     }
                     "InvalidDBProxyStateFault" => {
-                        return RusotoError::Service(
-                            DescribeDBProxyTargetsError::InvalidDBProxyStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeDBProxyTargetsError::InvalidDBProxyStateFault,
                         )
                     }
                     _ => {}
@@ -24175,10 +24513,11 @@ impl DescribeDBSecurityGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSecurityGroupNotFound" => {
-                        return RusotoError::Service(
-                            DescribeDBSecurityGroupsError::DBSecurityGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeDBSecurityGroupsError::DBSecurityGroupNotFoundFault,
                         )
                     }
                     _ => {}
@@ -24225,10 +24564,11 @@ impl DescribeDBSnapshotAttributesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
-                            DescribeDBSnapshotAttributesError::DBSnapshotNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeDBSnapshotAttributesError::DBSnapshotNotFoundFault,
                         )
                     }
                     _ => {}
@@ -24273,8 +24613,11 @@ impl DescribeDBSnapshotsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
-                            DescribeDBSnapshotsError::DBSnapshotNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeDBSnapshotsError::DBSnapshotNotFoundFault,
                         )
                     }
                     _ => {}
@@ -24317,10 +24660,11 @@ impl DescribeDBSubnetGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            DescribeDBSubnetGroupsError::DBSubnetGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeDBSubnetGroupsError::DBSubnetGroupNotFoundFault,
                         )
                     }
                     _ => {}
@@ -24473,10 +24817,11 @@ impl DescribeEventSubscriptionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "SubscriptionNotFound" => {
-                        return RusotoError::Service(
-                            DescribeEventSubscriptionsError::SubscriptionNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeEventSubscriptionsError::SubscriptionNotFoundFault,
                         )
                     }
                     _ => {}
@@ -24555,8 +24900,11 @@ impl DescribeExportTasksError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ExportTaskNotFound" => {
-                        return RusotoError::Service(
-                            DescribeExportTasksError::ExportTaskNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeExportTasksError::ExportTaskNotFoundFault,
                         )
                     }
                     _ => {}
@@ -24599,10 +24947,11 @@ impl DescribeGlobalClustersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "GlobalClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            DescribeGlobalClustersError::GlobalClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeGlobalClustersError::GlobalClusterNotFoundFault,
                         )
                     }
                     _ => {}
@@ -24647,10 +24996,11 @@ impl DescribeInstallationMediaError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InstallationMediaNotFound" => {
-                        return RusotoError::Service(
-                            DescribeInstallationMediaError::InstallationMediaNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeInstallationMediaError::InstallationMediaNotFoundFault,
                         )
                     }
                     _ => {}
@@ -24731,10 +25081,11 @@ impl DescribeOptionGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "OptionGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            DescribeOptionGroupsError::OptionGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeOptionGroupsError::OptionGroupNotFoundFault,
                         )
                     }
                     _ => {}
@@ -24817,10 +25168,11 @@ impl DescribePendingMaintenanceActionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ResourceNotFoundFault" => {
-                        return RusotoError::Service(
-                            DescribePendingMaintenanceActionsError::ResourceNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribePendingMaintenanceActionsError::ResourceNotFoundFault,
                         )
                     }
                     _ => {}
@@ -24867,10 +25219,11 @@ impl DescribeReservedDBInstancesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ReservedDBInstanceNotFound" => {
-                        return RusotoError::Service(
-                            DescribeReservedDBInstancesError::ReservedDBInstanceNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeReservedDBInstancesError::ReservedDBInstanceNotFoundFault,
                         )
                     }
                     _ => {}
@@ -24916,7 +25269,7 @@ impl DescribeReservedDBInstancesOfferingsError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "ReservedDBInstancesOfferingNotFound" => return RusotoError::Service(DescribeReservedDBInstancesOfferingsError::ReservedDBInstancesOfferingNotFoundFault(parsed_error.message)),_ => {}
+                                    "ReservedDBInstancesOfferingNotFound" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, DescribeReservedDBInstancesOfferingsError::ReservedDBInstancesOfferingNotFoundFault),_ => {}
                                 }
             }
         }
@@ -24996,17 +25349,19 @@ impl DescribeValidDBInstanceModificationsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
-                            DescribeValidDBInstanceModificationsError::DBInstanceNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeValidDBInstanceModificationsError::DBInstanceNotFoundFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            DescribeValidDBInstanceModificationsError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeValidDBInstanceModificationsError::InvalidDBInstanceStateFault,
                         )
                     }
                     _ => {}
@@ -25056,17 +25411,19 @@ impl DownloadDBLogFilePortionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
-                            DownloadDBLogFilePortionError::DBInstanceNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DownloadDBLogFilePortionError::DBInstanceNotFoundFault,
                         )
                     }
                     "DBLogFileNotFoundFault" => {
-                        return RusotoError::Service(
-                            DownloadDBLogFilePortionError::DBLogFileNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DownloadDBLogFilePortionError::DBLogFileNotFoundFault,
                         )
                     }
                     _ => {}
@@ -25118,22 +25475,27 @@ impl FailoverDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            FailoverDBClusterError::DBClusterNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            FailoverDBClusterError::DBClusterNotFoundFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            FailoverDBClusterError::InvalidDBClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            FailoverDBClusterError::InvalidDBClusterStateFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            FailoverDBClusterError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            FailoverDBClusterError::InvalidDBInstanceStateFault,
                         )
                     }
                     _ => {}
@@ -25182,17 +25544,19 @@ impl ImportInstallationMediaError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CustomAvailabilityZoneNotFound" => {
-                        return RusotoError::Service(
-                            ImportInstallationMediaError::CustomAvailabilityZoneNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ImportInstallationMediaError::CustomAvailabilityZoneNotFoundFault,
                         )
                     }
                     "InstallationMediaAlreadyExists" => {
-                        return RusotoError::Service(
-                            ImportInstallationMediaError::InstallationMediaAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ImportInstallationMediaError::InstallationMediaAlreadyExistsFault,
                         )
                     }
                     _ => {}
@@ -25248,30 +25612,43 @@ impl ListTagsForResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            ListTagsForResourceError::DBClusterNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListTagsForResourceError::DBClusterNotFoundFault,
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
-                            ListTagsForResourceError::DBInstanceNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListTagsForResourceError::DBInstanceNotFoundFault,
                         )
                     }
                     "DBProxyNotFoundFault" => {
-                        return RusotoError::Service(
-                            ListTagsForResourceError::DBProxyNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListTagsForResourceError::DBProxyNotFoundFault,
                         )
                     }
                     "DBProxyTargetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            ListTagsForResourceError::DBProxyTargetGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListTagsForResourceError::DBProxyTargetGroupNotFoundFault,
                         )
                     }
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
-                            ListTagsForResourceError::DBSnapshotNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListTagsForResourceError::DBSnapshotNotFoundFault,
                         )
                     }
                     _ => {}
@@ -25320,8 +25697,11 @@ impl ModifyCertificatesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "CertificateNotFound" => {
-                        return RusotoError::Service(
-                            ModifyCertificatesError::CertificateNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyCertificatesError::CertificateNotFoundFault,
                         )
                     }
                     _ => {}
@@ -25370,24 +25750,27 @@ impl ModifyCurrentDBClusterCapacityError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            ModifyCurrentDBClusterCapacityError::DBClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyCurrentDBClusterCapacityError::DBClusterNotFoundFault,
                         )
                     }
                     "InvalidDBClusterCapacityFault" => {
-                        return RusotoError::Service(
-                            ModifyCurrentDBClusterCapacityError::InvalidDBClusterCapacityFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyCurrentDBClusterCapacityError::InvalidDBClusterCapacityFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            ModifyCurrentDBClusterCapacityError::InvalidDBClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyCurrentDBClusterCapacityError::InvalidDBClusterStateFault,
                         )
                     }
                     _ => {}
@@ -25460,69 +25843,99 @@ impl ModifyDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterAlreadyExistsFault" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterError::DBClusterAlreadyExistsFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterError::DBClusterAlreadyExistsFault,
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(ModifyDBClusterError::DBClusterNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyDBClusterError::DBClusterNotFoundFault,
+                        )
                     }
                     "DBClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterError::DBClusterParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterError::DBClusterParameterGroupNotFoundFault,
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterError::DBSubnetGroupNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterError::DBSubnetGroupNotFoundFault,
                         )
                     }
                     "DomainNotFoundFault" => {
-                        return RusotoError::Service(ModifyDBClusterError::DomainNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyDBClusterError::DomainNotFoundFault,
+                        )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterError::InvalidDBClusterStateFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterError::InvalidDBInstanceStateFault,
                         )
                     }
                     "InvalidDBSecurityGroupState" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterError::InvalidDBSecurityGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterError::InvalidDBSecurityGroupStateFault,
                         )
                     }
                     "InvalidDBSubnetGroupStateFault" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterError::InvalidDBSubnetGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterError::InvalidDBSubnetGroupStateFault,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(ModifyDBClusterError::InvalidSubnet(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyDBClusterError::InvalidSubnet,
+                        )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterError::InvalidVPCNetworkStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterError::InvalidVPCNetworkStateFault,
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterError::StorageQuotaExceededFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterError::StorageQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -25590,38 +26003,43 @@ impl ModifyDBClusterEndpointError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterEndpointNotFoundFault" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterEndpointError::DBClusterEndpointNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterEndpointError::DBClusterEndpointNotFoundFault,
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterEndpointError::DBInstanceNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterEndpointError::DBInstanceNotFoundFault,
                         )
                     }
                     "InvalidDBClusterEndpointStateFault" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterEndpointError::InvalidDBClusterEndpointStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterEndpointError::InvalidDBClusterEndpointStateFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterEndpointError::InvalidDBClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterEndpointError::InvalidDBClusterStateFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterEndpointError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterEndpointError::InvalidDBInstanceStateFault,
                         )
                     }
                     _ => {}
@@ -25682,17 +26100,19 @@ impl ModifyDBClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterParameterGroupError::DBParameterGroupNotFoundFault,
                         )
                     }
                     "InvalidDBParameterGroupState" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterParameterGroupError::InvalidDBParameterGroupStateFault,
                         )
                     }
                     _ => {}
@@ -25746,22 +26166,25 @@ impl ModifyDBClusterSnapshotAttributeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterSnapshotAttributeError::DBClusterSnapshotNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterSnapshotAttributeError::DBClusterSnapshotNotFoundFault,
                         )
                     }
-                    "InvalidDBClusterSnapshotStateFault" => return RusotoError::Service(
-                        ModifyDBClusterSnapshotAttributeError::InvalidDBClusterSnapshotStateFault(
-                            parsed_error.message,
-                        ),
+                    "InvalidDBClusterSnapshotStateFault" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        ModifyDBClusterSnapshotAttributeError::InvalidDBClusterSnapshotStateFault,
                     ),
                     "SharedSnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
-                            ModifyDBClusterSnapshotAttributeError::SharedSnapshotQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBClusterSnapshotAttributeError::SharedSnapshotQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -25848,118 +26271,155 @@ impl ModifyDBInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationNotFound" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::AuthorizationNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::AuthorizationNotFoundFault,
                         )
                     }
                     "BackupPolicyNotFoundFault" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::BackupPolicyNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::BackupPolicyNotFoundFault,
                         )
                     }
                     "CertificateNotFound" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::CertificateNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::CertificateNotFoundFault,
                         )
                     }
                     "DBInstanceAlreadyExists" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::DBInstanceAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::DBInstanceAlreadyExistsFault,
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::DBInstanceNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::DBInstanceNotFoundFault,
                         )
                     }
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::DBParameterGroupNotFoundFault,
                         )
                     }
                     "DBSecurityGroupNotFound" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::DBSecurityGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::DBSecurityGroupNotFoundFault,
                         )
                     }
                     "DBUpgradeDependencyFailure" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::DBUpgradeDependencyFailureFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::DBUpgradeDependencyFailureFault,
                         )
                     }
                     "DomainNotFoundFault" => {
-                        return RusotoError::Service(ModifyDBInstanceError::DomainNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyDBInstanceError::DomainNotFoundFault,
+                        )
                     }
                     "InsufficientDBInstanceCapacity" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::InsufficientDBInstanceCapacityFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::InsufficientDBInstanceCapacityFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::InvalidDBClusterStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::InvalidDBClusterStateFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::InvalidDBInstanceStateFault,
                         )
                     }
                     "InvalidDBSecurityGroupState" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::InvalidDBSecurityGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::InvalidDBSecurityGroupStateFault,
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::InvalidVPCNetworkStateFault,
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::KMSKeyNotAccessibleFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::KMSKeyNotAccessibleFault,
                         )
                     }
                     "OptionGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::OptionGroupNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::OptionGroupNotFoundFault,
                         )
                     }
                     "ProvisionedIopsNotAvailableInAZFault" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::ProvisionedIopsNotAvailableInAZFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::ProvisionedIopsNotAvailableInAZFault,
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::StorageQuotaExceededFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::StorageQuotaExceededFault,
                         )
                     }
                     "StorageTypeNotSupported" => {
-                        return RusotoError::Service(
-                            ModifyDBInstanceError::StorageTypeNotSupportedFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBInstanceError::StorageTypeNotSupportedFault,
                         )
                     }
                     _ => {}
@@ -26038,17 +26498,19 @@ impl ModifyDBParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            ModifyDBParameterGroupError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBParameterGroupError::DBParameterGroupNotFoundFault,
                         )
                     }
                     "InvalidDBParameterGroupState" => {
-                        return RusotoError::Service(
-                            ModifyDBParameterGroupError::InvalidDBParameterGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBParameterGroupError::InvalidDBParameterGroupStateFault,
                         )
                     }
                     _ => {}
@@ -26100,19 +26562,28 @@ impl ModifyDBProxyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBProxyTargetExistsFault" => {
-                        return RusotoError::Service(ModifyDBProxyError::DBProxyAlreadyExistsFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyDBProxyError::DBProxyAlreadyExistsFault,
+                        )
                     }
                     "DBProxyNotFoundFault" => {
-                        return RusotoError::Service(ModifyDBProxyError::DBProxyNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyDBProxyError::DBProxyNotFoundFault,
+                        )
                     }
                     "InvalidDBProxyStateFault" => {
-                        return RusotoError::Service(ModifyDBProxyError::InvalidDBProxyStateFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyDBProxyError::InvalidDBProxyStateFault,
+                        )
                     }
                     _ => {}
                 }
@@ -26160,24 +26631,27 @@ impl ModifyDBProxyTargetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBProxyNotFoundFault" => {
-                        return RusotoError::Service(
-                            ModifyDBProxyTargetGroupError::DBProxyNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBProxyTargetGroupError::DBProxyNotFoundFault,
                         )
                     }
                     "DBProxyTargetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            ModifyDBProxyTargetGroupError::DBProxyTargetGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBProxyTargetGroupError::DBProxyTargetGroupNotFoundFault,
                         )
                     }
                     "InvalidDBProxyStateFault" => {
-                        return RusotoError::Service(
-                            ModifyDBProxyTargetGroupError::InvalidDBProxyStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBProxyTargetGroupError::InvalidDBProxyStateFault,
                         )
                     }
                     _ => {}
@@ -26228,8 +26702,11 @@ impl ModifyDBSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
-                            ModifyDBSnapshotError::DBSnapshotNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBSnapshotError::DBSnapshotNotFoundFault,
                         )
                     }
                     _ => {}
@@ -26276,24 +26753,27 @@ impl ModifyDBSnapshotAttributeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
-                            ModifyDBSnapshotAttributeError::DBSnapshotNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBSnapshotAttributeError::DBSnapshotNotFoundFault,
                         )
                     }
                     "InvalidDBSnapshotState" => {
-                        return RusotoError::Service(
-                            ModifyDBSnapshotAttributeError::InvalidDBSnapshotStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBSnapshotAttributeError::InvalidDBSnapshotStateFault,
                         )
                     }
                     "SharedSnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
-                            ModifyDBSnapshotAttributeError::SharedSnapshotQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBSnapshotAttributeError::SharedSnapshotQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -26352,35 +26832,44 @@ impl ModifyDBSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
-                        return RusotoError::Service(
-                            ModifyDBSubnetGroupError::DBSubnetGroupDoesNotCoverEnoughAZs(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBSubnetGroupError::DBSubnetGroupDoesNotCoverEnoughAZs,
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            ModifyDBSubnetGroupError::DBSubnetGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBSubnetGroupError::DBSubnetGroupNotFoundFault,
                         )
                     }
                     "DBSubnetQuotaExceededFault" => {
-                        return RusotoError::Service(
-                            ModifyDBSubnetGroupError::DBSubnetQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyDBSubnetGroupError::DBSubnetQuotaExceededFault,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(ModifyDBSubnetGroupError::InvalidSubnet(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyDBSubnetGroupError::InvalidSubnet,
+                        )
                     }
                     "SubnetAlreadyInUse" => {
-                        return RusotoError::Service(ModifyDBSubnetGroupError::SubnetAlreadyInUse(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyDBSubnetGroupError::SubnetAlreadyInUse,
+                        )
                     }
                     _ => {}
                 }
@@ -26442,45 +26931,51 @@ impl ModifyEventSubscriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EventSubscriptionQuotaExceeded" => {
-                        return RusotoError::Service(
-                            ModifyEventSubscriptionError::EventSubscriptionQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyEventSubscriptionError::EventSubscriptionQuotaExceededFault,
                         )
                     }
                     "SNSInvalidTopic" => {
-                        return RusotoError::Service(
-                            ModifyEventSubscriptionError::SNSInvalidTopicFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyEventSubscriptionError::SNSInvalidTopicFault,
                         )
                     }
                     "SNSNoAuthorization" => {
-                        return RusotoError::Service(
-                            ModifyEventSubscriptionError::SNSNoAuthorizationFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyEventSubscriptionError::SNSNoAuthorizationFault,
                         )
                     }
                     "SNSTopicArnNotFound" => {
-                        return RusotoError::Service(
-                            ModifyEventSubscriptionError::SNSTopicArnNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyEventSubscriptionError::SNSTopicArnNotFoundFault,
                         )
                     }
                     "SubscriptionCategoryNotFound" => {
-                        return RusotoError::Service(
-                            ModifyEventSubscriptionError::SubscriptionCategoryNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyEventSubscriptionError::SubscriptionCategoryNotFoundFault,
                         )
                     }
                     "SubscriptionNotFound" => {
-                        return RusotoError::Service(
-                            ModifyEventSubscriptionError::SubscriptionNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyEventSubscriptionError::SubscriptionNotFoundFault,
                         )
                     }
                     _ => {}
@@ -26540,17 +27035,19 @@ impl ModifyGlobalClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "GlobalClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            ModifyGlobalClusterError::GlobalClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyGlobalClusterError::GlobalClusterNotFoundFault,
                         )
                     }
                     "InvalidGlobalClusterStateFault" => {
-                        return RusotoError::Service(
-                            ModifyGlobalClusterError::InvalidGlobalClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyGlobalClusterError::InvalidGlobalClusterStateFault,
                         )
                     }
                     _ => {}
@@ -26600,15 +27097,19 @@ impl ModifyOptionGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidOptionGroupStateFault" => {
-                        return RusotoError::Service(
-                            ModifyOptionGroupError::InvalidOptionGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyOptionGroupError::InvalidOptionGroupStateFault,
                         )
                     }
                     "OptionGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            ModifyOptionGroupError::OptionGroupNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyOptionGroupError::OptionGroupNotFoundFault,
                         )
                     }
                     _ => {}
@@ -26656,15 +27157,19 @@ impl PromoteReadReplicaError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
-                            PromoteReadReplicaError::DBInstanceNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            PromoteReadReplicaError::DBInstanceNotFoundFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            PromoteReadReplicaError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            PromoteReadReplicaError::InvalidDBInstanceStateFault,
                         )
                     }
                     _ => {}
@@ -26714,17 +27219,19 @@ impl PromoteReadReplicaDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            PromoteReadReplicaDBClusterError::DBClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            PromoteReadReplicaDBClusterError::DBClusterNotFoundFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            PromoteReadReplicaDBClusterError::InvalidDBClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            PromoteReadReplicaDBClusterError::InvalidDBClusterStateFault,
                         )
                     }
                     _ => {}
@@ -26777,7 +27284,7 @@ impl PurchaseReservedDBInstancesOfferingError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "ReservedDBInstanceAlreadyExists" => return RusotoError::Service(PurchaseReservedDBInstancesOfferingError::ReservedDBInstanceAlreadyExistsFault(parsed_error.message)),"ReservedDBInstanceQuotaExceeded" => return RusotoError::Service(PurchaseReservedDBInstancesOfferingError::ReservedDBInstanceQuotaExceededFault(parsed_error.message)),"ReservedDBInstancesOfferingNotFound" => return RusotoError::Service(PurchaseReservedDBInstancesOfferingError::ReservedDBInstancesOfferingNotFoundFault(parsed_error.message)),_ => {}
+                                    "ReservedDBInstanceAlreadyExists" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, PurchaseReservedDBInstancesOfferingError::ReservedDBInstanceAlreadyExistsFault),"ReservedDBInstanceQuotaExceeded" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, PurchaseReservedDBInstancesOfferingError::ReservedDBInstanceQuotaExceededFault),"ReservedDBInstancesOfferingNotFound" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, PurchaseReservedDBInstancesOfferingError::ReservedDBInstancesOfferingNotFoundFault),_ => {}
                                 }
             }
         }
@@ -26827,15 +27334,19 @@ impl RebootDBInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
-                            RebootDBInstanceError::DBInstanceNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RebootDBInstanceError::DBInstanceNotFoundFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            RebootDBInstanceError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RebootDBInstanceError::InvalidDBInstanceStateFault,
                         )
                     }
                     _ => {}
@@ -26895,64 +27406,75 @@ impl RegisterDBProxyTargetsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            RegisterDBProxyTargetsError::DBClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RegisterDBProxyTargetsError::DBClusterNotFoundFault,
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
-                            RegisterDBProxyTargetsError::DBInstanceNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RegisterDBProxyTargetsError::DBInstanceNotFoundFault,
                         )
                     }
                     "DBProxyNotFoundFault" => {
-                        return RusotoError::Service(
-                            RegisterDBProxyTargetsError::DBProxyNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RegisterDBProxyTargetsError::DBProxyNotFoundFault,
                         )
                     }
                     "DBProxyTargetAlreadyRegisteredFault" => {
-                        return RusotoError::Service(
-                            RegisterDBProxyTargetsError::DBProxyTargetAlreadyRegisteredFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RegisterDBProxyTargetsError::DBProxyTargetAlreadyRegisteredFault,
                         )
                     }
                     "DBProxyTargetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            RegisterDBProxyTargetsError::DBProxyTargetGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RegisterDBProxyTargetsError::DBProxyTargetGroupNotFoundFault,
                         )
                     }
                     "InsufficientAvailableIPsInSubnetFault" => {
-                        return RusotoError::Service(
-                            RegisterDBProxyTargetsError::InsufficientAvailableIPsInSubnetFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RegisterDBProxyTargetsError::InsufficientAvailableIPsInSubnetFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            RegisterDBProxyTargetsError::InvalidDBClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RegisterDBProxyTargetsError::InvalidDBClusterStateFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            RegisterDBProxyTargetsError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RegisterDBProxyTargetsError::InvalidDBInstanceStateFault,
                         )
                     }
                     "InvalidDBProxyStateFault" => {
-                        return RusotoError::Service(
-                            RegisterDBProxyTargetsError::InvalidDBProxyStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RegisterDBProxyTargetsError::InvalidDBProxyStateFault,
                         )
                     }
                     _ => {}
@@ -27023,24 +27545,27 @@ impl RemoveFromGlobalClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            RemoveFromGlobalClusterError::DBClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RemoveFromGlobalClusterError::DBClusterNotFoundFault,
                         )
                     }
                     "GlobalClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            RemoveFromGlobalClusterError::GlobalClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RemoveFromGlobalClusterError::GlobalClusterNotFoundFault,
                         )
                     }
                     "InvalidGlobalClusterStateFault" => {
-                        return RusotoError::Service(
-                            RemoveFromGlobalClusterError::InvalidGlobalClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RemoveFromGlobalClusterError::InvalidGlobalClusterStateFault,
                         )
                     }
                     _ => {}
@@ -27095,24 +27620,27 @@ impl RemoveRoleFromDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            RemoveRoleFromDBClusterError::DBClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RemoveRoleFromDBClusterError::DBClusterNotFoundFault,
                         )
                     }
                     "DBClusterRoleNotFound" => {
-                        return RusotoError::Service(
-                            RemoveRoleFromDBClusterError::DBClusterRoleNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RemoveRoleFromDBClusterError::DBClusterRoleNotFoundFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            RemoveRoleFromDBClusterError::InvalidDBClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RemoveRoleFromDBClusterError::InvalidDBClusterStateFault,
                         )
                     }
                     _ => {}
@@ -27167,24 +27695,27 @@ impl RemoveRoleFromDBInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
-                            RemoveRoleFromDBInstanceError::DBInstanceNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RemoveRoleFromDBInstanceError::DBInstanceNotFoundFault,
                         )
                     }
                     "DBInstanceRoleNotFound" => {
-                        return RusotoError::Service(
-                            RemoveRoleFromDBInstanceError::DBInstanceRoleNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RemoveRoleFromDBInstanceError::DBInstanceRoleNotFoundFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            RemoveRoleFromDBInstanceError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RemoveRoleFromDBInstanceError::InvalidDBInstanceStateFault,
                         )
                     }
                     _ => {}
@@ -27239,17 +27770,19 @@ impl RemoveSourceIdentifierFromSubscriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "SourceNotFound" => {
-                        return RusotoError::Service(
-                            RemoveSourceIdentifierFromSubscriptionError::SourceNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RemoveSourceIdentifierFromSubscriptionError::SourceNotFoundFault,
                         )
                     }
                     "SubscriptionNotFound" => {
-                        return RusotoError::Service(
-                            RemoveSourceIdentifierFromSubscriptionError::SubscriptionNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RemoveSourceIdentifierFromSubscriptionError::SubscriptionNotFoundFault,
                         )
                     }
                     _ => {}
@@ -27305,36 +27838,43 @@ impl RemoveTagsFromResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            RemoveTagsFromResourceError::DBClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RemoveTagsFromResourceError::DBClusterNotFoundFault,
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
-                            RemoveTagsFromResourceError::DBInstanceNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RemoveTagsFromResourceError::DBInstanceNotFoundFault,
                         )
                     }
                     "DBProxyNotFoundFault" => {
-                        return RusotoError::Service(
-                            RemoveTagsFromResourceError::DBProxyNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RemoveTagsFromResourceError::DBProxyNotFoundFault,
                         )
                     }
                     "DBProxyTargetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            RemoveTagsFromResourceError::DBProxyTargetGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RemoveTagsFromResourceError::DBProxyTargetGroupNotFoundFault,
                         )
                     }
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
-                            RemoveTagsFromResourceError::DBSnapshotNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RemoveTagsFromResourceError::DBSnapshotNotFoundFault,
                         )
                     }
                     _ => {}
@@ -27393,17 +27933,19 @@ impl ResetDBClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            ResetDBClusterParameterGroupError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ResetDBClusterParameterGroupError::DBParameterGroupNotFoundFault,
                         )
                     }
                     "InvalidDBParameterGroupState" => {
-                        return RusotoError::Service(
-                            ResetDBClusterParameterGroupError::InvalidDBParameterGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ResetDBClusterParameterGroupError::InvalidDBParameterGroupStateFault,
                         )
                     }
                     _ => {}
@@ -27453,17 +27995,19 @@ impl ResetDBParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            ResetDBParameterGroupError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ResetDBParameterGroupError::DBParameterGroupNotFoundFault,
                         )
                     }
                     "InvalidDBParameterGroupState" => {
-                        return RusotoError::Service(
-                            ResetDBParameterGroupError::InvalidDBParameterGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ResetDBParameterGroupError::InvalidDBParameterGroupStateFault,
                         )
                     }
                     _ => {}
@@ -27537,95 +28081,115 @@ impl RestoreDBClusterFromS3Error {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterAlreadyExistsFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromS3Error::DBClusterAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromS3Error::DBClusterAlreadyExistsFault,
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromS3Error::DBClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromS3Error::DBClusterNotFoundFault,
                         )
                     }
                     "DBClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromS3Error::DBClusterParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromS3Error::DBClusterParameterGroupNotFoundFault,
                         )
                     }
                     "DBClusterQuotaExceededFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromS3Error::DBClusterQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromS3Error::DBClusterQuotaExceededFault,
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromS3Error::DBSubnetGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromS3Error::DBSubnetGroupNotFoundFault,
                         )
                     }
                     "DomainNotFoundFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromS3Error::DomainNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromS3Error::DomainNotFoundFault,
                         )
                     }
                     "InsufficientStorageClusterCapacity" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromS3Error::InsufficientStorageClusterCapacityFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromS3Error::InsufficientStorageClusterCapacityFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromS3Error::InvalidDBClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromS3Error::InvalidDBClusterStateFault,
                         )
                     }
                     "InvalidDBSubnetGroupStateFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromS3Error::InvalidDBSubnetGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromS3Error::InvalidDBSubnetGroupStateFault,
                         )
                     }
                     "InvalidS3BucketFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromS3Error::InvalidS3BucketFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromS3Error::InvalidS3BucketFault,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(RestoreDBClusterFromS3Error::InvalidSubnet(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            RestoreDBClusterFromS3Error::InvalidSubnet,
+                        )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromS3Error::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromS3Error::InvalidVPCNetworkStateFault,
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromS3Error::KMSKeyNotAccessibleFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromS3Error::KMSKeyNotAccessibleFault,
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromS3Error::StorageQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromS3Error::StorageQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -27737,118 +28301,137 @@ impl RestoreDBClusterFromSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterAlreadyExistsFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::DBClusterAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::DBClusterAlreadyExistsFault,
                         )
                     }
                     "DBClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::DBClusterParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::DBClusterParameterGroupNotFoundFault,
                         )
                     }
                     "DBClusterQuotaExceededFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::DBClusterQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::DBClusterQuotaExceededFault,
                         )
                     }
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::DBClusterSnapshotNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::DBClusterSnapshotNotFoundFault,
                         )
                     }
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::DBSnapshotNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::DBSnapshotNotFoundFault,
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::DBSubnetGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::DBSubnetGroupNotFoundFault,
                         )
                     }
                     "DomainNotFoundFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::DomainNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::DomainNotFoundFault,
                         )
                     }
                     "InsufficientDBClusterCapacityFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::InsufficientDBClusterCapacityFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::InsufficientDBClusterCapacityFault,
                         )
                     }
-                    "InsufficientStorageClusterCapacity" => return RusotoError::Service(
-                        RestoreDBClusterFromSnapshotError::InsufficientStorageClusterCapacityFault(
-                            parsed_error.message,
-                        ),
+                    "InsufficientStorageClusterCapacity" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        RestoreDBClusterFromSnapshotError::InsufficientStorageClusterCapacityFault,
                     ),
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::InvalidDBClusterSnapshotStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::InvalidDBClusterSnapshotStateFault,
                         )
                     }
                     "InvalidDBSnapshotState" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::InvalidDBSnapshotStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::InvalidDBSnapshotStateFault,
                         )
                     }
                     "InvalidRestoreFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::InvalidRestoreFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::InvalidRestoreFault,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::InvalidSubnet(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::InvalidSubnet,
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::InvalidVPCNetworkStateFault,
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::KMSKeyNotAccessibleFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::KMSKeyNotAccessibleFault,
                         )
                     }
                     "OptionGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::OptionGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::OptionGroupNotFoundFault,
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterFromSnapshotError::StorageQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterFromSnapshotError::StorageQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -27975,123 +28558,143 @@ impl RestoreDBClusterToPointInTimeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterAlreadyExistsFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::DBClusterAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::DBClusterAlreadyExistsFault,
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::DBClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::DBClusterNotFoundFault,
                         )
                     }
-                    "DBClusterParameterGroupNotFound" => return RusotoError::Service(
-                        RestoreDBClusterToPointInTimeError::DBClusterParameterGroupNotFoundFault(
-                            parsed_error.message,
-                        ),
+                    "DBClusterParameterGroupNotFound" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        RestoreDBClusterToPointInTimeError::DBClusterParameterGroupNotFoundFault,
                     ),
                     "DBClusterQuotaExceededFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::DBClusterQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::DBClusterQuotaExceededFault,
                         )
                     }
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::DBClusterSnapshotNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::DBClusterSnapshotNotFoundFault,
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::DBSubnetGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::DBSubnetGroupNotFoundFault,
                         )
                     }
                     "DomainNotFoundFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::DomainNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::DomainNotFoundFault,
                         )
                     }
                     "InsufficientDBClusterCapacityFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::InsufficientDBClusterCapacityFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::InsufficientDBClusterCapacityFault,
                         )
                     }
-                    "InsufficientStorageClusterCapacity" => return RusotoError::Service(
-                        RestoreDBClusterToPointInTimeError::InsufficientStorageClusterCapacityFault(
-                            parsed_error.message,
-                        ),
+                    "InsufficientStorageClusterCapacity" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        RestoreDBClusterToPointInTimeError::InsufficientStorageClusterCapacityFault,
                     ),
                     "InvalidDBClusterSnapshotStateFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::InvalidDBClusterSnapshotStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::InvalidDBClusterSnapshotStateFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::InvalidDBClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::InvalidDBClusterStateFault,
                         )
                     }
                     "InvalidDBSnapshotState" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::InvalidDBSnapshotStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::InvalidDBSnapshotStateFault,
                         )
                     }
                     "InvalidRestoreFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::InvalidRestoreFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::InvalidRestoreFault,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::InvalidSubnet(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::InvalidSubnet,
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::InvalidVPCNetworkStateFault,
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::KMSKeyNotAccessibleFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::KMSKeyNotAccessibleFault,
                         )
                     }
                     "OptionGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::OptionGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::OptionGroupNotFoundFault,
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
-                            RestoreDBClusterToPointInTimeError::StorageQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBClusterToPointInTimeError::StorageQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -28225,137 +28828,157 @@ impl RestoreDBInstanceFromDBSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationNotFound" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceFromDBSnapshotError::AuthorizationNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceFromDBSnapshotError::AuthorizationNotFoundFault,
                         )
                     }
                     "BackupPolicyNotFoundFault" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceFromDBSnapshotError::BackupPolicyNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceFromDBSnapshotError::BackupPolicyNotFoundFault,
                         )
                     }
                     "DBInstanceAlreadyExists" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceFromDBSnapshotError::DBInstanceAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceFromDBSnapshotError::DBInstanceAlreadyExistsFault,
                         )
                     }
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceFromDBSnapshotError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceFromDBSnapshotError::DBParameterGroupNotFoundFault,
                         )
                     }
                     "DBSecurityGroupNotFound" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceFromDBSnapshotError::DBSecurityGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceFromDBSnapshotError::DBSecurityGroupNotFoundFault,
                         )
                     }
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceFromDBSnapshotError::DBSnapshotNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceFromDBSnapshotError::DBSnapshotNotFoundFault,
                         )
                     }
-                    "DBSubnetGroupDoesNotCoverEnoughAZs" => return RusotoError::Service(
-                        RestoreDBInstanceFromDBSnapshotError::DBSubnetGroupDoesNotCoverEnoughAZs(
-                            parsed_error.message,
-                        ),
+                    "DBSubnetGroupDoesNotCoverEnoughAZs" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        RestoreDBInstanceFromDBSnapshotError::DBSubnetGroupDoesNotCoverEnoughAZs,
                     ),
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceFromDBSnapshotError::DBSubnetGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceFromDBSnapshotError::DBSubnetGroupNotFoundFault,
                         )
                     }
                     "DomainNotFoundFault" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceFromDBSnapshotError::DomainNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceFromDBSnapshotError::DomainNotFoundFault,
                         )
                     }
                     "InstanceQuotaExceeded" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceFromDBSnapshotError::InstanceQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceFromDBSnapshotError::InstanceQuotaExceededFault,
                         )
                     }
-                    "InsufficientDBInstanceCapacity" => return RusotoError::Service(
-                        RestoreDBInstanceFromDBSnapshotError::InsufficientDBInstanceCapacityFault(
-                            parsed_error.message,
-                        ),
+                    "InsufficientDBInstanceCapacity" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        RestoreDBInstanceFromDBSnapshotError::InsufficientDBInstanceCapacityFault,
                     ),
                     "InvalidDBSnapshotState" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceFromDBSnapshotError::InvalidDBSnapshotStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceFromDBSnapshotError::InvalidDBSnapshotStateFault,
                         )
                     }
                     "InvalidRestoreFault" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceFromDBSnapshotError::InvalidRestoreFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceFromDBSnapshotError::InvalidRestoreFault,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceFromDBSnapshotError::InvalidSubnet(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceFromDBSnapshotError::InvalidSubnet,
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceFromDBSnapshotError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceFromDBSnapshotError::InvalidVPCNetworkStateFault,
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceFromDBSnapshotError::KMSKeyNotAccessibleFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceFromDBSnapshotError::KMSKeyNotAccessibleFault,
                         )
                     }
                     "OptionGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceFromDBSnapshotError::OptionGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceFromDBSnapshotError::OptionGroupNotFoundFault,
                         )
                     }
-                    "ProvisionedIopsNotAvailableInAZFault" => return RusotoError::Service(
-                        RestoreDBInstanceFromDBSnapshotError::ProvisionedIopsNotAvailableInAZFault(
-                            parsed_error.message,
-                        ),
+                    "ProvisionedIopsNotAvailableInAZFault" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        RestoreDBInstanceFromDBSnapshotError::ProvisionedIopsNotAvailableInAZFault,
                     ),
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceFromDBSnapshotError::StorageQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceFromDBSnapshotError::StorageQuotaExceededFault,
                         )
                     }
                     "StorageTypeNotSupported" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceFromDBSnapshotError::StorageTypeNotSupportedFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceFromDBSnapshotError::StorageTypeNotSupportedFault,
                         )
                     }
                     _ => {}
@@ -28489,120 +29112,139 @@ impl RestoreDBInstanceFromS3Error {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationNotFound" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceFromS3Error::AuthorizationNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceFromS3Error::AuthorizationNotFoundFault,
                         )
                     }
                     "BackupPolicyNotFoundFault" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceFromS3Error::BackupPolicyNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceFromS3Error::BackupPolicyNotFoundFault,
                         )
                     }
                     "DBInstanceAlreadyExists" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceFromS3Error::DBInstanceAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceFromS3Error::DBInstanceAlreadyExistsFault,
                         )
                     }
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceFromS3Error::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceFromS3Error::DBParameterGroupNotFoundFault,
                         )
                     }
                     "DBSecurityGroupNotFound" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceFromS3Error::DBSecurityGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceFromS3Error::DBSecurityGroupNotFoundFault,
                         )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceFromS3Error::DBSubnetGroupDoesNotCoverEnoughAZs(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceFromS3Error::DBSubnetGroupDoesNotCoverEnoughAZs,
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceFromS3Error::DBSubnetGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceFromS3Error::DBSubnetGroupNotFoundFault,
                         )
                     }
                     "InstanceQuotaExceeded" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceFromS3Error::InstanceQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceFromS3Error::InstanceQuotaExceededFault,
                         )
                     }
                     "InsufficientDBInstanceCapacity" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceFromS3Error::InsufficientDBInstanceCapacityFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceFromS3Error::InsufficientDBInstanceCapacityFault,
                         )
                     }
                     "InvalidS3BucketFault" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceFromS3Error::InvalidS3BucketFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceFromS3Error::InvalidS3BucketFault,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(RestoreDBInstanceFromS3Error::InvalidSubnet(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            RestoreDBInstanceFromS3Error::InvalidSubnet,
+                        )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceFromS3Error::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceFromS3Error::InvalidVPCNetworkStateFault,
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceFromS3Error::KMSKeyNotAccessibleFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceFromS3Error::KMSKeyNotAccessibleFault,
                         )
                     }
                     "OptionGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceFromS3Error::OptionGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceFromS3Error::OptionGroupNotFoundFault,
                         )
                     }
                     "ProvisionedIopsNotAvailableInAZFault" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceFromS3Error::ProvisionedIopsNotAvailableInAZFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceFromS3Error::ProvisionedIopsNotAvailableInAZFault,
                         )
                     }
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceFromS3Error::StorageQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceFromS3Error::StorageQuotaExceededFault,
                         )
                     }
                     "StorageTypeNotSupported" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceFromS3Error::StorageTypeNotSupportedFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceFromS3Error::StorageTypeNotSupportedFault,
                         )
                     }
                     _ => {}
@@ -28735,151 +29377,173 @@ impl RestoreDBInstanceToPointInTimeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationNotFound" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceToPointInTimeError::AuthorizationNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceToPointInTimeError::AuthorizationNotFoundFault,
                         )
                     }
                     "BackupPolicyNotFoundFault" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceToPointInTimeError::BackupPolicyNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceToPointInTimeError::BackupPolicyNotFoundFault,
                         )
                     }
                     "DBInstanceAlreadyExists" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceToPointInTimeError::DBInstanceAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceToPointInTimeError::DBInstanceAlreadyExistsFault,
                         )
                     }
-                    "DBInstanceAutomatedBackupNotFound" => return RusotoError::Service(
-                        RestoreDBInstanceToPointInTimeError::DBInstanceAutomatedBackupNotFoundFault(
-                            parsed_error.message,
-                        ),
+                    "DBInstanceAutomatedBackupNotFound" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        RestoreDBInstanceToPointInTimeError::DBInstanceAutomatedBackupNotFoundFault,
                     ),
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceToPointInTimeError::DBInstanceNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceToPointInTimeError::DBInstanceNotFoundFault,
                         )
                     }
                     "DBParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceToPointInTimeError::DBParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceToPointInTimeError::DBParameterGroupNotFoundFault,
                         )
                     }
                     "DBSecurityGroupNotFound" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceToPointInTimeError::DBSecurityGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceToPointInTimeError::DBSecurityGroupNotFoundFault,
                         )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceToPointInTimeError::DBSubnetGroupDoesNotCoverEnoughAZs(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceToPointInTimeError::DBSubnetGroupDoesNotCoverEnoughAZs,
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceToPointInTimeError::DBSubnetGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceToPointInTimeError::DBSubnetGroupNotFoundFault,
                         )
                     }
                     "DomainNotFoundFault" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceToPointInTimeError::DomainNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceToPointInTimeError::DomainNotFoundFault,
                         )
                     }
                     "InstanceQuotaExceeded" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceToPointInTimeError::InstanceQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceToPointInTimeError::InstanceQuotaExceededFault,
                         )
                     }
-                    "InsufficientDBInstanceCapacity" => return RusotoError::Service(
-                        RestoreDBInstanceToPointInTimeError::InsufficientDBInstanceCapacityFault(
-                            parsed_error.message,
-                        ),
+                    "InsufficientDBInstanceCapacity" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        RestoreDBInstanceToPointInTimeError::InsufficientDBInstanceCapacityFault,
                     ),
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceToPointInTimeError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceToPointInTimeError::InvalidDBInstanceStateFault,
                         )
                     }
                     "InvalidRestoreFault" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceToPointInTimeError::InvalidRestoreFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceToPointInTimeError::InvalidRestoreFault,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceToPointInTimeError::InvalidSubnet(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceToPointInTimeError::InvalidSubnet,
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceToPointInTimeError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceToPointInTimeError::InvalidVPCNetworkStateFault,
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceToPointInTimeError::KMSKeyNotAccessibleFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceToPointInTimeError::KMSKeyNotAccessibleFault,
                         )
                     }
                     "OptionGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceToPointInTimeError::OptionGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceToPointInTimeError::OptionGroupNotFoundFault,
                         )
                     }
                     "PointInTimeRestoreNotEnabled" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceToPointInTimeError::PointInTimeRestoreNotEnabledFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceToPointInTimeError::PointInTimeRestoreNotEnabledFault,
                         )
                     }
-                    "ProvisionedIopsNotAvailableInAZFault" => return RusotoError::Service(
-                        RestoreDBInstanceToPointInTimeError::ProvisionedIopsNotAvailableInAZFault(
-                            parsed_error.message,
-                        ),
+                    "ProvisionedIopsNotAvailableInAZFault" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        RestoreDBInstanceToPointInTimeError::ProvisionedIopsNotAvailableInAZFault,
                     ),
                     "StorageQuotaExceeded" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceToPointInTimeError::StorageQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceToPointInTimeError::StorageQuotaExceededFault,
                         )
                     }
                     "StorageTypeNotSupported" => {
-                        return RusotoError::Service(
-                            RestoreDBInstanceToPointInTimeError::StorageTypeNotSupportedFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreDBInstanceToPointInTimeError::StorageTypeNotSupportedFault,
                         )
                     }
                     _ => {}
@@ -28991,24 +29655,27 @@ impl RevokeDBSecurityGroupIngressError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationNotFound" => {
-                        return RusotoError::Service(
-                            RevokeDBSecurityGroupIngressError::AuthorizationNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RevokeDBSecurityGroupIngressError::AuthorizationNotFoundFault,
                         )
                     }
                     "DBSecurityGroupNotFound" => {
-                        return RusotoError::Service(
-                            RevokeDBSecurityGroupIngressError::DBSecurityGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RevokeDBSecurityGroupIngressError::DBSecurityGroupNotFoundFault,
                         )
                     }
                     "InvalidDBSecurityGroupState" => {
-                        return RusotoError::Service(
-                            RevokeDBSecurityGroupIngressError::InvalidDBSecurityGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RevokeDBSecurityGroupIngressError::InvalidDBSecurityGroupStateFault,
                         )
                     }
                     _ => {}
@@ -29069,39 +29736,51 @@ impl StartActivityStreamError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            StartActivityStreamError::DBClusterNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StartActivityStreamError::DBClusterNotFoundFault,
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
-                            StartActivityStreamError::DBInstanceNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StartActivityStreamError::DBInstanceNotFoundFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            StartActivityStreamError::InvalidDBClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StartActivityStreamError::InvalidDBClusterStateFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            StartActivityStreamError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StartActivityStreamError::InvalidDBInstanceStateFault,
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
-                            StartActivityStreamError::KMSKeyNotAccessibleFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StartActivityStreamError::KMSKeyNotAccessibleFault,
                         )
                     }
                     "ResourceNotFoundFault" => {
-                        return RusotoError::Service(
-                            StartActivityStreamError::ResourceNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StartActivityStreamError::ResourceNotFoundFault,
                         )
                     }
                     _ => {}
@@ -29157,18 +29836,27 @@ impl StartDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(StartDBClusterError::DBClusterNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            StartDBClusterError::DBClusterNotFoundFault,
+                        )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            StartDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StartDBClusterError::InvalidDBClusterStateFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            StartDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StartDBClusterError::InvalidDBInstanceStateFault,
                         )
                     }
                     _ => {}
@@ -29233,62 +29921,91 @@ impl StartDBInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationNotFound" => {
-                        return RusotoError::Service(
-                            StartDBInstanceError::AuthorizationNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StartDBInstanceError::AuthorizationNotFoundFault,
                         )
                     }
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(StartDBInstanceError::DBClusterNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            StartDBInstanceError::DBClusterNotFoundFault,
+                        )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(StartDBInstanceError::DBInstanceNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            StartDBInstanceError::DBInstanceNotFoundFault,
+                        )
                     }
                     "DBSubnetGroupDoesNotCoverEnoughAZs" => {
-                        return RusotoError::Service(
-                            StartDBInstanceError::DBSubnetGroupDoesNotCoverEnoughAZs(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StartDBInstanceError::DBSubnetGroupDoesNotCoverEnoughAZs,
                         )
                     }
                     "DBSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            StartDBInstanceError::DBSubnetGroupNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StartDBInstanceError::DBSubnetGroupNotFoundFault,
                         )
                     }
                     "InsufficientDBInstanceCapacity" => {
-                        return RusotoError::Service(
-                            StartDBInstanceError::InsufficientDBInstanceCapacityFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StartDBInstanceError::InsufficientDBInstanceCapacityFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            StartDBInstanceError::InvalidDBClusterStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StartDBInstanceError::InvalidDBClusterStateFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            StartDBInstanceError::InvalidDBInstanceStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StartDBInstanceError::InvalidDBInstanceStateFault,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(StartDBInstanceError::InvalidSubnet(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            StartDBInstanceError::InvalidSubnet,
+                        )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
-                            StartDBInstanceError::InvalidVPCNetworkStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StartDBInstanceError::InvalidVPCNetworkStateFault,
                         )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
-                            StartDBInstanceError::KMSKeyNotAccessibleFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StartDBInstanceError::KMSKeyNotAccessibleFault,
                         )
                     }
                     _ => {}
@@ -29354,7 +30071,7 @@ impl StartDBInstanceAutomatedBackupsReplicationError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "DBInstanceAutomatedBackupQuotaExceeded" => return RusotoError::Service(StartDBInstanceAutomatedBackupsReplicationError::DBInstanceAutomatedBackupQuotaExceededFault(parsed_error.message)),"DBInstanceNotFound" => return RusotoError::Service(StartDBInstanceAutomatedBackupsReplicationError::DBInstanceNotFoundFault(parsed_error.message)),"InvalidDBInstanceState" => return RusotoError::Service(StartDBInstanceAutomatedBackupsReplicationError::InvalidDBInstanceStateFault(parsed_error.message)),"KMSKeyNotAccessibleFault" => return RusotoError::Service(StartDBInstanceAutomatedBackupsReplicationError::KMSKeyNotAccessibleFault(parsed_error.message)),"StorageTypeNotSupported" => return RusotoError::Service(StartDBInstanceAutomatedBackupsReplicationError::StorageTypeNotSupportedFault(parsed_error.message)),_ => {}
+                                    "DBInstanceAutomatedBackupQuotaExceeded" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, StartDBInstanceAutomatedBackupsReplicationError::DBInstanceAutomatedBackupQuotaExceededFault),"DBInstanceNotFound" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, StartDBInstanceAutomatedBackupsReplicationError::DBInstanceNotFoundFault),"InvalidDBInstanceState" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, StartDBInstanceAutomatedBackupsReplicationError::InvalidDBInstanceStateFault),"KMSKeyNotAccessibleFault" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, StartDBInstanceAutomatedBackupsReplicationError::KMSKeyNotAccessibleFault),"StorageTypeNotSupported" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, StartDBInstanceAutomatedBackupsReplicationError::StorageTypeNotSupportedFault),_ => {}
                                 }
             }
         }
@@ -29414,56 +30131,75 @@ impl StartExportTaskError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterSnapshotNotFoundFault" => {
-                        return RusotoError::Service(
-                            StartExportTaskError::DBClusterSnapshotNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StartExportTaskError::DBClusterSnapshotNotFoundFault,
                         )
                     }
                     "DBSnapshotNotFound" => {
-                        return RusotoError::Service(StartExportTaskError::DBSnapshotNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            StartExportTaskError::DBSnapshotNotFoundFault,
+                        )
                     }
                     "ExportTaskAlreadyExists" => {
-                        return RusotoError::Service(
-                            StartExportTaskError::ExportTaskAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StartExportTaskError::ExportTaskAlreadyExistsFault,
                         )
                     }
                     "IamRoleMissingPermissions" => {
-                        return RusotoError::Service(
-                            StartExportTaskError::IamRoleMissingPermissionsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StartExportTaskError::IamRoleMissingPermissionsFault,
                         )
                     }
                     "IamRoleNotFound" => {
-                        return RusotoError::Service(StartExportTaskError::IamRoleNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            StartExportTaskError::IamRoleNotFoundFault,
+                        )
                     }
                     "InvalidExportOnly" => {
-                        return RusotoError::Service(StartExportTaskError::InvalidExportOnlyFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            StartExportTaskError::InvalidExportOnlyFault,
+                        )
                     }
                     "InvalidExportSourceState" => {
-                        return RusotoError::Service(
-                            StartExportTaskError::InvalidExportSourceStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StartExportTaskError::InvalidExportSourceStateFault,
                         )
                     }
                     "InvalidS3BucketFault" => {
-                        return RusotoError::Service(StartExportTaskError::InvalidS3BucketFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            StartExportTaskError::InvalidS3BucketFault,
+                        )
                     }
                     "KMSKeyNotAccessibleFault" => {
-                        return RusotoError::Service(
-                            StartExportTaskError::KMSKeyNotAccessibleFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StartExportTaskError::KMSKeyNotAccessibleFault,
                         )
                     }
                     _ => {}
@@ -29528,32 +30264,43 @@ impl StopActivityStreamError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(
-                            StopActivityStreamError::DBClusterNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StopActivityStreamError::DBClusterNotFoundFault,
                         )
                     }
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
-                            StopActivityStreamError::DBInstanceNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StopActivityStreamError::DBInstanceNotFoundFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            StopActivityStreamError::InvalidDBClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StopActivityStreamError::InvalidDBClusterStateFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            StopActivityStreamError::InvalidDBInstanceStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StopActivityStreamError::InvalidDBInstanceStateFault,
                         )
                     }
                     "ResourceNotFoundFault" => {
-                        return RusotoError::Service(
-                            StopActivityStreamError::ResourceNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StopActivityStreamError::ResourceNotFoundFault,
                         )
                     }
                     _ => {}
@@ -29608,18 +30355,27 @@ impl StopDBClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBClusterNotFoundFault" => {
-                        return RusotoError::Service(StopDBClusterError::DBClusterNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            StopDBClusterError::DBClusterNotFoundFault,
+                        )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            StopDBClusterError::InvalidDBClusterStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StopDBClusterError::InvalidDBClusterStateFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            StopDBClusterError::InvalidDBInstanceStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StopDBClusterError::InvalidDBInstanceStateFault,
                         )
                     }
                     _ => {}
@@ -29672,28 +30428,43 @@ impl StopDBInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(StopDBInstanceError::DBInstanceNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            StopDBInstanceError::DBInstanceNotFoundFault,
+                        )
                     }
                     "DBSnapshotAlreadyExists" => {
-                        return RusotoError::Service(
-                            StopDBInstanceError::DBSnapshotAlreadyExistsFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StopDBInstanceError::DBSnapshotAlreadyExistsFault,
                         )
                     }
                     "InvalidDBClusterStateFault" => {
-                        return RusotoError::Service(
-                            StopDBInstanceError::InvalidDBClusterStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StopDBInstanceError::InvalidDBClusterStateFault,
                         )
                     }
                     "InvalidDBInstanceState" => {
-                        return RusotoError::Service(
-                            StopDBInstanceError::InvalidDBInstanceStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StopDBInstanceError::InvalidDBInstanceStateFault,
                         )
                     }
                     "SnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
-                            StopDBInstanceError::SnapshotQuotaExceededFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StopDBInstanceError::SnapshotQuotaExceededFault,
                         )
                     }
                     _ => {}
@@ -29744,16 +30515,18 @@ impl StopDBInstanceAutomatedBackupsReplicationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DBInstanceNotFound" => {
-                        return RusotoError::Service(
-                            StopDBInstanceAutomatedBackupsReplicationError::DBInstanceNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            StopDBInstanceAutomatedBackupsReplicationError::DBInstanceNotFoundFault,
                         )
                     }
-                    "InvalidDBInstanceState" => return RusotoError::Service(
-                        StopDBInstanceAutomatedBackupsReplicationError::InvalidDBInstanceStateFault(
-                            parsed_error.message,
-                        ),
+                    "InvalidDBInstanceState" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        StopDBInstanceAutomatedBackupsReplicationError::InvalidDBInstanceStateFault,
                     ),
                     _ => {}
                 }
@@ -30640,11 +31413,12 @@ pub struct RdsClient {
 impl RdsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> RdsClient {
         RdsClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "RDS"),
         }
     }
 
@@ -30659,12 +31433,32 @@ impl RdsClient {
     {
         RdsClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "RDS"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> RdsClient {
-        RdsClient { client, region }
+        RdsClient {
+            client,
+            region: region_for_service(region, "RDS"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> RdsClient {
+        RdsClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("RDS"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> RdsClient {
+        RdsClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -30676,6 +31470,7 @@ impl Rds for RdsClient {
         input: AddRoleToDBClusterMessage,
     ) -> Result<(), RusotoError<AddRoleToDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("AddRoleToDBCluster");
         let params = self.new_params("AddRoleToDBCluster");
         let mut params = params;
         AddRoleToDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -30696,6 +31491,7 @@ impl Rds for RdsClient {
         input: AddRoleToDBInstanceMessage,
     ) -> Result<(), RusotoError<AddRoleToDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("AddRoleToDBInstance");
         let params = self.new_params("AddRoleToDBInstance");
         let mut params = params;
         AddRoleToDBInstanceMessageSerializer::serialize(&mut params, "", &input);
@@ -30719,6 +31515,7 @@ impl Rds for RdsClient {
         RusotoError<AddSourceIdentifierToSubscriptionError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("AddSourceIdentifierToSubscription");
         let params = self.new_params("AddSourceIdentifierToSubscription");
         let mut params = params;
         AddSourceIdentifierToSubscriptionMessageSerializer::serialize(&mut params, "", &input);
@@ -30755,6 +31552,7 @@ impl Rds for RdsClient {
         input: AddTagsToResourceMessage,
     ) -> Result<(), RusotoError<AddTagsToResourceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("AddTagsToResource");
         let params = self.new_params("AddTagsToResource");
         let mut params = params;
         AddTagsToResourceMessageSerializer::serialize(&mut params, "", &input);
@@ -30776,6 +31574,7 @@ impl Rds for RdsClient {
     ) -> Result<ApplyPendingMaintenanceActionResult, RusotoError<ApplyPendingMaintenanceActionError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ApplyPendingMaintenanceAction");
         let params = self.new_params("ApplyPendingMaintenanceAction");
         let mut params = params;
         ApplyPendingMaintenanceActionMessageSerializer::serialize(&mut params, "", &input);
@@ -30812,6 +31611,7 @@ impl Rds for RdsClient {
         RusotoError<AuthorizeDBSecurityGroupIngressError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("AuthorizeDBSecurityGroupIngress");
         let params = self.new_params("AuthorizeDBSecurityGroupIngress");
         let mut params = params;
         AuthorizeDBSecurityGroupIngressMessageSerializer::serialize(&mut params, "", &input);
@@ -30845,6 +31645,7 @@ impl Rds for RdsClient {
         input: BacktrackDBClusterMessage,
     ) -> Result<DBClusterBacktrack, RusotoError<BacktrackDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("BacktrackDBCluster");
         let params = self.new_params("BacktrackDBCluster");
         let mut params = params;
         BacktrackDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -30876,6 +31677,7 @@ impl Rds for RdsClient {
         input: CancelExportTaskMessage,
     ) -> Result<ExportTask, RusotoError<CancelExportTaskError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CancelExportTask");
         let params = self.new_params("CancelExportTask");
         let mut params = params;
         CancelExportTaskMessageSerializer::serialize(&mut params, "", &input);
@@ -30907,6 +31709,7 @@ impl Rds for RdsClient {
     ) -> Result<CopyDBClusterParameterGroupResult, RusotoError<CopyDBClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CopyDBClusterParameterGroup");
         let params = self.new_params("CopyDBClusterParameterGroup");
         let mut params = params;
         CopyDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -30940,6 +31743,7 @@ impl Rds for RdsClient {
         input: CopyDBClusterSnapshotMessage,
     ) -> Result<CopyDBClusterSnapshotResult, RusotoError<CopyDBClusterSnapshotError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CopyDBClusterSnapshot");
         let params = self.new_params("CopyDBClusterSnapshot");
         let mut params = params;
         CopyDBClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -30973,6 +31777,7 @@ impl Rds for RdsClient {
         input: CopyDBParameterGroupMessage,
     ) -> Result<CopyDBParameterGroupResult, RusotoError<CopyDBParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CopyDBParameterGroup");
         let params = self.new_params("CopyDBParameterGroup");
         let mut params = params;
         CopyDBParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -31006,6 +31811,7 @@ impl Rds for RdsClient {
         input: CopyDBSnapshotMessage,
     ) -> Result<CopyDBSnapshotResult, RusotoError<CopyDBSnapshotError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CopyDBSnapshot");
         let params = self.new_params("CopyDBSnapshot");
         let mut params = params;
         CopyDBSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -31037,6 +31843,7 @@ impl Rds for RdsClient {
         input: CopyOptionGroupMessage,
     ) -> Result<CopyOptionGroupResult, RusotoError<CopyOptionGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CopyOptionGroup");
         let params = self.new_params("CopyOptionGroup");
         let mut params = params;
         CopyOptionGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -31069,6 +31876,7 @@ impl Rds for RdsClient {
     ) -> Result<CreateCustomAvailabilityZoneResult, RusotoError<CreateCustomAvailabilityZoneError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateCustomAvailabilityZone");
         let params = self.new_params("CreateCustomAvailabilityZone");
         let mut params = params;
         CreateCustomAvailabilityZoneMessageSerializer::serialize(&mut params, "", &input);
@@ -31102,6 +31910,7 @@ impl Rds for RdsClient {
         input: CreateDBClusterMessage,
     ) -> Result<CreateDBClusterResult, RusotoError<CreateDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBCluster");
         let params = self.new_params("CreateDBCluster");
         let mut params = params;
         CreateDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -31133,6 +31942,7 @@ impl Rds for RdsClient {
         input: CreateDBClusterEndpointMessage,
     ) -> Result<DBClusterEndpoint, RusotoError<CreateDBClusterEndpointError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBClusterEndpoint");
         let params = self.new_params("CreateDBClusterEndpoint");
         let mut params = params;
         CreateDBClusterEndpointMessageSerializer::serialize(&mut params, "", &input);
@@ -31165,6 +31975,7 @@ impl Rds for RdsClient {
     ) -> Result<CreateDBClusterParameterGroupResult, RusotoError<CreateDBClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBClusterParameterGroup");
         let params = self.new_params("CreateDBClusterParameterGroup");
         let mut params = params;
         CreateDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -31198,6 +32009,7 @@ impl Rds for RdsClient {
         input: CreateDBClusterSnapshotMessage,
     ) -> Result<CreateDBClusterSnapshotResult, RusotoError<CreateDBClusterSnapshotError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBClusterSnapshot");
         let params = self.new_params("CreateDBClusterSnapshot");
         let mut params = params;
         CreateDBClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -31231,6 +32043,7 @@ impl Rds for RdsClient {
         input: CreateDBInstanceMessage,
     ) -> Result<CreateDBInstanceResult, RusotoError<CreateDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBInstance");
         let params = self.new_params("CreateDBInstance");
         let mut params = params;
         CreateDBInstanceMessageSerializer::serialize(&mut params, "", &input);
@@ -31263,6 +32076,7 @@ impl Rds for RdsClient {
     ) -> Result<CreateDBInstanceReadReplicaResult, RusotoError<CreateDBInstanceReadReplicaError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBInstanceReadReplica");
         let params = self.new_params("CreateDBInstanceReadReplica");
         let mut params = params;
         CreateDBInstanceReadReplicaMessageSerializer::serialize(&mut params, "", &input);
@@ -31296,6 +32110,7 @@ impl Rds for RdsClient {
         input: CreateDBParameterGroupMessage,
     ) -> Result<CreateDBParameterGroupResult, RusotoError<CreateDBParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBParameterGroup");
         let params = self.new_params("CreateDBParameterGroup");
         let mut params = params;
         CreateDBParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -31329,6 +32144,7 @@ impl Rds for RdsClient {
         input: CreateDBProxyRequest,
     ) -> Result<CreateDBProxyResponse, RusotoError<CreateDBProxyError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBProxy");
         let params = self.new_params("CreateDBProxy");
         let mut params = params;
         CreateDBProxyRequestSerializer::serialize(&mut params, "", &input);
@@ -31360,6 +32176,7 @@ impl Rds for RdsClient {
         input: CreateDBSecurityGroupMessage,
     ) -> Result<CreateDBSecurityGroupResult, RusotoError<CreateDBSecurityGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBSecurityGroup");
         let params = self.new_params("CreateDBSecurityGroup");
         let mut params = params;
         CreateDBSecurityGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -31393,6 +32210,7 @@ impl Rds for RdsClient {
         input: CreateDBSnapshotMessage,
     ) -> Result<CreateDBSnapshotResult, RusotoError<CreateDBSnapshotError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBSnapshot");
         let params = self.new_params("CreateDBSnapshot");
         let mut params = params;
         CreateDBSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -31424,6 +32242,7 @@ impl Rds for RdsClient {
         input: CreateDBSubnetGroupMessage,
     ) -> Result<CreateDBSubnetGroupResult, RusotoError<CreateDBSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateDBSubnetGroup");
         let params = self.new_params("CreateDBSubnetGroup");
         let mut params = params;
         CreateDBSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -31457,6 +32276,7 @@ impl Rds for RdsClient {
         input: CreateEventSubscriptionMessage,
     ) -> Result<CreateEventSubscriptionResult, RusotoError<CreateEventSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateEventSubscription");
         let params = self.new_params("CreateEventSubscription");
         let mut params = params;
         CreateEventSubscriptionMessageSerializer::serialize(&mut params, "", &input);
@@ -31490,6 +32310,7 @@ impl Rds for RdsClient {
         input: CreateGlobalClusterMessage,
     ) -> Result<CreateGlobalClusterResult, RusotoError<CreateGlobalClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateGlobalCluster");
         let params = self.new_params("CreateGlobalCluster");
         let mut params = params;
         CreateGlobalClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -31523,6 +32344,7 @@ impl Rds for RdsClient {
         input: CreateOptionGroupMessage,
     ) -> Result<CreateOptionGroupResult, RusotoError<CreateOptionGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("CreateOptionGroup");
         let params = self.new_params("CreateOptionGroup");
         let mut params = params;
         CreateOptionGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -31555,6 +32377,7 @@ impl Rds for RdsClient {
     ) -> Result<DeleteCustomAvailabilityZoneResult, RusotoError<DeleteCustomAvailabilityZoneError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteCustomAvailabilityZone");
         let params = self.new_params("DeleteCustomAvailabilityZone");
         let mut params = params;
         DeleteCustomAvailabilityZoneMessageSerializer::serialize(&mut params, "", &input);
@@ -31588,6 +32411,7 @@ impl Rds for RdsClient {
         input: DeleteDBClusterMessage,
     ) -> Result<DeleteDBClusterResult, RusotoError<DeleteDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBCluster");
         let params = self.new_params("DeleteDBCluster");
         let mut params = params;
         DeleteDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -31619,6 +32443,7 @@ impl Rds for RdsClient {
         input: DeleteDBClusterEndpointMessage,
     ) -> Result<DBClusterEndpoint, RusotoError<DeleteDBClusterEndpointError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBClusterEndpoint");
         let params = self.new_params("DeleteDBClusterEndpoint");
         let mut params = params;
         DeleteDBClusterEndpointMessageSerializer::serialize(&mut params, "", &input);
@@ -31650,6 +32475,7 @@ impl Rds for RdsClient {
         input: DeleteDBClusterParameterGroupMessage,
     ) -> Result<(), RusotoError<DeleteDBClusterParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBClusterParameterGroup");
         let params = self.new_params("DeleteDBClusterParameterGroup");
         let mut params = params;
         DeleteDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -31670,6 +32496,7 @@ impl Rds for RdsClient {
         input: DeleteDBClusterSnapshotMessage,
     ) -> Result<DeleteDBClusterSnapshotResult, RusotoError<DeleteDBClusterSnapshotError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBClusterSnapshot");
         let params = self.new_params("DeleteDBClusterSnapshot");
         let mut params = params;
         DeleteDBClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -31703,6 +32530,7 @@ impl Rds for RdsClient {
         input: DeleteDBInstanceMessage,
     ) -> Result<DeleteDBInstanceResult, RusotoError<DeleteDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBInstance");
         let params = self.new_params("DeleteDBInstance");
         let mut params = params;
         DeleteDBInstanceMessageSerializer::serialize(&mut params, "", &input);
@@ -31737,6 +32565,7 @@ impl Rds for RdsClient {
         RusotoError<DeleteDBInstanceAutomatedBackupError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBInstanceAutomatedBackup");
         let params = self.new_params("DeleteDBInstanceAutomatedBackup");
         let mut params = params;
         DeleteDBInstanceAutomatedBackupMessageSerializer::serialize(&mut params, "", &input);
@@ -31770,6 +32599,7 @@ impl Rds for RdsClient {
         input: DeleteDBParameterGroupMessage,
     ) -> Result<(), RusotoError<DeleteDBParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBParameterGroup");
         let params = self.new_params("DeleteDBParameterGroup");
         let mut params = params;
         DeleteDBParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -31790,6 +32620,7 @@ impl Rds for RdsClient {
         input: DeleteDBProxyRequest,
     ) -> Result<DeleteDBProxyResponse, RusotoError<DeleteDBProxyError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBProxy");
         let params = self.new_params("DeleteDBProxy");
         let mut params = params;
         DeleteDBProxyRequestSerializer::serialize(&mut params, "", &input);
@@ -31821,6 +32652,7 @@ impl Rds for RdsClient {
         input: DeleteDBSecurityGroupMessage,
     ) -> Result<(), RusotoError<DeleteDBSecurityGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBSecurityGroup");
         let params = self.new_params("DeleteDBSecurityGroup");
         let mut params = params;
         DeleteDBSecurityGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -31841,6 +32673,7 @@ impl Rds for RdsClient {
         input: DeleteDBSnapshotMessage,
     ) -> Result<DeleteDBSnapshotResult, RusotoError<DeleteDBSnapshotError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBSnapshot");
         let params = self.new_params("DeleteDBSnapshot");
         let mut params = params;
         DeleteDBSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -31872,6 +32705,7 @@ impl Rds for RdsClient {
         input: DeleteDBSubnetGroupMessage,
     ) -> Result<(), RusotoError<DeleteDBSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteDBSubnetGroup");
         let params = self.new_params("DeleteDBSubnetGroup");
         let mut params = params;
         DeleteDBSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -31892,6 +32726,7 @@ impl Rds for RdsClient {
         input: DeleteEventSubscriptionMessage,
     ) -> Result<DeleteEventSubscriptionResult, RusotoError<DeleteEventSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteEventSubscription");
         let params = self.new_params("DeleteEventSubscription");
         let mut params = params;
         DeleteEventSubscriptionMessageSerializer::serialize(&mut params, "", &input);
@@ -31925,6 +32760,7 @@ impl Rds for RdsClient {
         input: DeleteGlobalClusterMessage,
     ) -> Result<DeleteGlobalClusterResult, RusotoError<DeleteGlobalClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteGlobalCluster");
         let params = self.new_params("DeleteGlobalCluster");
         let mut params = params;
         DeleteGlobalClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -31958,6 +32794,7 @@ impl Rds for RdsClient {
         input: DeleteInstallationMediaMessage,
     ) -> Result<InstallationMedia, RusotoError<DeleteInstallationMediaError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteInstallationMedia");
         let params = self.new_params("DeleteInstallationMedia");
         let mut params = params;
         DeleteInstallationMediaMessageSerializer::serialize(&mut params, "", &input);
@@ -31989,6 +32826,7 @@ impl Rds for RdsClient {
         input: DeleteOptionGroupMessage,
     ) -> Result<(), RusotoError<DeleteOptionGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeleteOptionGroup");
         let params = self.new_params("DeleteOptionGroup");
         let mut params = params;
         DeleteOptionGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -32009,6 +32847,7 @@ impl Rds for RdsClient {
         input: DeregisterDBProxyTargetsRequest,
     ) -> Result<DeregisterDBProxyTargetsResponse, RusotoError<DeregisterDBProxyTargetsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DeregisterDBProxyTargets");
         let params = self.new_params("DeregisterDBProxyTargets");
         let mut params = params;
         DeregisterDBProxyTargetsRequestSerializer::serialize(&mut params, "", &input);
@@ -32031,6 +32870,7 @@ impl Rds for RdsClient {
         input: DescribeAccountAttributesMessage,
     ) -> Result<AccountAttributesMessage, RusotoError<DescribeAccountAttributesError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeAccountAttributes");
         let params = self.new_params("DescribeAccountAttributes");
         let mut params = params;
         DescribeAccountAttributesMessageSerializer::serialize(&mut params, "", &input);
@@ -32064,6 +32904,7 @@ impl Rds for RdsClient {
         input: DescribeCertificatesMessage,
     ) -> Result<CertificateMessage, RusotoError<DescribeCertificatesError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeCertificates");
         let params = self.new_params("DescribeCertificates");
         let mut params = params;
         DescribeCertificatesMessageSerializer::serialize(&mut params, "", &input);
@@ -32096,6 +32937,7 @@ impl Rds for RdsClient {
     ) -> Result<CustomAvailabilityZoneMessage, RusotoError<DescribeCustomAvailabilityZonesError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeCustomAvailabilityZones");
         let params = self.new_params("DescribeCustomAvailabilityZones");
         let mut params = params;
         DescribeCustomAvailabilityZonesMessageSerializer::serialize(&mut params, "", &input);
@@ -32129,6 +32971,7 @@ impl Rds for RdsClient {
         input: DescribeDBClusterBacktracksMessage,
     ) -> Result<DBClusterBacktrackMessage, RusotoError<DescribeDBClusterBacktracksError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterBacktracks");
         let params = self.new_params("DescribeDBClusterBacktracks");
         let mut params = params;
         DescribeDBClusterBacktracksMessageSerializer::serialize(&mut params, "", &input);
@@ -32162,6 +33005,7 @@ impl Rds for RdsClient {
         input: DescribeDBClusterEndpointsMessage,
     ) -> Result<DBClusterEndpointMessage, RusotoError<DescribeDBClusterEndpointsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterEndpoints");
         let params = self.new_params("DescribeDBClusterEndpoints");
         let mut params = params;
         DescribeDBClusterEndpointsMessageSerializer::serialize(&mut params, "", &input);
@@ -32196,6 +33040,7 @@ impl Rds for RdsClient {
     ) -> Result<DBClusterParameterGroupsMessage, RusotoError<DescribeDBClusterParameterGroupsError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterParameterGroups");
         let params = self.new_params("DescribeDBClusterParameterGroups");
         let mut params = params;
         DescribeDBClusterParameterGroupsMessageSerializer::serialize(&mut params, "", &input);
@@ -32232,6 +33077,7 @@ impl Rds for RdsClient {
         input: DescribeDBClusterParametersMessage,
     ) -> Result<DBClusterParameterGroupDetails, RusotoError<DescribeDBClusterParametersError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterParameters");
         let params = self.new_params("DescribeDBClusterParameters");
         let mut params = params;
         DescribeDBClusterParametersMessageSerializer::serialize(&mut params, "", &input);
@@ -32268,6 +33114,7 @@ impl Rds for RdsClient {
         RusotoError<DescribeDBClusterSnapshotAttributesError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterSnapshotAttributes");
         let params = self.new_params("DescribeDBClusterSnapshotAttributes");
         let mut params = params;
         DescribeDBClusterSnapshotAttributesMessageSerializer::serialize(&mut params, "", &input);
@@ -32304,6 +33151,7 @@ impl Rds for RdsClient {
         input: DescribeDBClusterSnapshotsMessage,
     ) -> Result<DBClusterSnapshotMessage, RusotoError<DescribeDBClusterSnapshotsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusterSnapshots");
         let params = self.new_params("DescribeDBClusterSnapshots");
         let mut params = params;
         DescribeDBClusterSnapshotsMessageSerializer::serialize(&mut params, "", &input);
@@ -32337,6 +33185,7 @@ impl Rds for RdsClient {
         input: DescribeDBClustersMessage,
     ) -> Result<DBClusterMessage, RusotoError<DescribeDBClustersError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBClusters");
         let params = self.new_params("DescribeDBClusters");
         let mut params = params;
         DescribeDBClustersMessageSerializer::serialize(&mut params, "", &input);
@@ -32368,6 +33217,7 @@ impl Rds for RdsClient {
         input: DescribeDBEngineVersionsMessage,
     ) -> Result<DBEngineVersionMessage, RusotoError<DescribeDBEngineVersionsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBEngineVersions");
         let params = self.new_params("DescribeDBEngineVersions");
         let mut params = params;
         DescribeDBEngineVersionsMessageSerializer::serialize(&mut params, "", &input);
@@ -32404,6 +33254,7 @@ impl Rds for RdsClient {
         RusotoError<DescribeDBInstanceAutomatedBackupsError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBInstanceAutomatedBackups");
         let params = self.new_params("DescribeDBInstanceAutomatedBackups");
         let mut params = params;
         DescribeDBInstanceAutomatedBackupsMessageSerializer::serialize(&mut params, "", &input);
@@ -32440,6 +33291,7 @@ impl Rds for RdsClient {
         input: DescribeDBInstancesMessage,
     ) -> Result<DBInstanceMessage, RusotoError<DescribeDBInstancesError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBInstances");
         let params = self.new_params("DescribeDBInstances");
         let mut params = params;
         DescribeDBInstancesMessageSerializer::serialize(&mut params, "", &input);
@@ -32471,6 +33323,7 @@ impl Rds for RdsClient {
         input: DescribeDBLogFilesMessage,
     ) -> Result<DescribeDBLogFilesResponse, RusotoError<DescribeDBLogFilesError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBLogFiles");
         let params = self.new_params("DescribeDBLogFiles");
         let mut params = params;
         DescribeDBLogFilesMessageSerializer::serialize(&mut params, "", &input);
@@ -32504,6 +33357,7 @@ impl Rds for RdsClient {
         input: DescribeDBParameterGroupsMessage,
     ) -> Result<DBParameterGroupsMessage, RusotoError<DescribeDBParameterGroupsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBParameterGroups");
         let params = self.new_params("DescribeDBParameterGroups");
         let mut params = params;
         DescribeDBParameterGroupsMessageSerializer::serialize(&mut params, "", &input);
@@ -32537,6 +33391,7 @@ impl Rds for RdsClient {
         input: DescribeDBParametersMessage,
     ) -> Result<DBParameterGroupDetails, RusotoError<DescribeDBParametersError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBParameters");
         let params = self.new_params("DescribeDBParameters");
         let mut params = params;
         DescribeDBParametersMessageSerializer::serialize(&mut params, "", &input);
@@ -32570,6 +33425,7 @@ impl Rds for RdsClient {
         input: DescribeDBProxiesRequest,
     ) -> Result<DescribeDBProxiesResponse, RusotoError<DescribeDBProxiesError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBProxies");
         let params = self.new_params("DescribeDBProxies");
         let mut params = params;
         DescribeDBProxiesRequestSerializer::serialize(&mut params, "", &input);
@@ -32604,6 +33460,7 @@ impl Rds for RdsClient {
     ) -> Result<DescribeDBProxyTargetGroupsResponse, RusotoError<DescribeDBProxyTargetGroupsError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBProxyTargetGroups");
         let params = self.new_params("DescribeDBProxyTargetGroups");
         let mut params = params;
         DescribeDBProxyTargetGroupsRequestSerializer::serialize(&mut params, "", &input);
@@ -32637,6 +33494,7 @@ impl Rds for RdsClient {
         input: DescribeDBProxyTargetsRequest,
     ) -> Result<DescribeDBProxyTargetsResponse, RusotoError<DescribeDBProxyTargetsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBProxyTargets");
         let params = self.new_params("DescribeDBProxyTargets");
         let mut params = params;
         DescribeDBProxyTargetsRequestSerializer::serialize(&mut params, "", &input);
@@ -32670,6 +33528,7 @@ impl Rds for RdsClient {
         input: DescribeDBSecurityGroupsMessage,
     ) -> Result<DBSecurityGroupMessage, RusotoError<DescribeDBSecurityGroupsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBSecurityGroups");
         let params = self.new_params("DescribeDBSecurityGroups");
         let mut params = params;
         DescribeDBSecurityGroupsMessageSerializer::serialize(&mut params, "", &input);
@@ -32704,6 +33563,7 @@ impl Rds for RdsClient {
     ) -> Result<DescribeDBSnapshotAttributesResult, RusotoError<DescribeDBSnapshotAttributesError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBSnapshotAttributes");
         let params = self.new_params("DescribeDBSnapshotAttributes");
         let mut params = params;
         DescribeDBSnapshotAttributesMessageSerializer::serialize(&mut params, "", &input);
@@ -32737,6 +33597,7 @@ impl Rds for RdsClient {
         input: DescribeDBSnapshotsMessage,
     ) -> Result<DBSnapshotMessage, RusotoError<DescribeDBSnapshotsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBSnapshots");
         let params = self.new_params("DescribeDBSnapshots");
         let mut params = params;
         DescribeDBSnapshotsMessageSerializer::serialize(&mut params, "", &input);
@@ -32768,6 +33629,7 @@ impl Rds for RdsClient {
         input: DescribeDBSubnetGroupsMessage,
     ) -> Result<DBSubnetGroupMessage, RusotoError<DescribeDBSubnetGroupsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeDBSubnetGroups");
         let params = self.new_params("DescribeDBSubnetGroups");
         let mut params = params;
         DescribeDBSubnetGroupsMessageSerializer::serialize(&mut params, "", &input);
@@ -32804,6 +33666,7 @@ impl Rds for RdsClient {
         RusotoError<DescribeEngineDefaultClusterParametersError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeEngineDefaultClusterParameters");
         let params = self.new_params("DescribeEngineDefaultClusterParameters");
         let mut params = params;
         DescribeEngineDefaultClusterParametersMessageSerializer::serialize(&mut params, "", &input);
@@ -32843,6 +33706,7 @@ impl Rds for RdsClient {
         RusotoError<DescribeEngineDefaultParametersError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeEngineDefaultParameters");
         let params = self.new_params("DescribeEngineDefaultParameters");
         let mut params = params;
         DescribeEngineDefaultParametersMessageSerializer::serialize(&mut params, "", &input);
@@ -32876,6 +33740,7 @@ impl Rds for RdsClient {
         input: DescribeEventCategoriesMessage,
     ) -> Result<EventCategoriesMessage, RusotoError<DescribeEventCategoriesError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeEventCategories");
         let params = self.new_params("DescribeEventCategories");
         let mut params = params;
         DescribeEventCategoriesMessageSerializer::serialize(&mut params, "", &input);
@@ -32909,6 +33774,7 @@ impl Rds for RdsClient {
         input: DescribeEventSubscriptionsMessage,
     ) -> Result<EventSubscriptionsMessage, RusotoError<DescribeEventSubscriptionsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeEventSubscriptions");
         let params = self.new_params("DescribeEventSubscriptions");
         let mut params = params;
         DescribeEventSubscriptionsMessageSerializer::serialize(&mut params, "", &input);
@@ -32942,6 +33808,7 @@ impl Rds for RdsClient {
         input: DescribeEventsMessage,
     ) -> Result<EventsMessage, RusotoError<DescribeEventsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeEvents");
         let params = self.new_params("DescribeEvents");
         let mut params = params;
         DescribeEventsMessageSerializer::serialize(&mut params, "", &input);
@@ -32972,6 +33839,7 @@ impl Rds for RdsClient {
         input: DescribeExportTasksMessage,
     ) -> Result<ExportTasksMessage, RusotoError<DescribeExportTasksError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeExportTasks");
         let params = self.new_params("DescribeExportTasks");
         let mut params = params;
         DescribeExportTasksMessageSerializer::serialize(&mut params, "", &input);
@@ -33003,6 +33871,7 @@ impl Rds for RdsClient {
         input: DescribeGlobalClustersMessage,
     ) -> Result<GlobalClustersMessage, RusotoError<DescribeGlobalClustersError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeGlobalClusters");
         let params = self.new_params("DescribeGlobalClusters");
         let mut params = params;
         DescribeGlobalClustersMessageSerializer::serialize(&mut params, "", &input);
@@ -33036,6 +33905,7 @@ impl Rds for RdsClient {
         input: DescribeInstallationMediaMessage,
     ) -> Result<InstallationMediaMessage, RusotoError<DescribeInstallationMediaError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeInstallationMedia");
         let params = self.new_params("DescribeInstallationMedia");
         let mut params = params;
         DescribeInstallationMediaMessageSerializer::serialize(&mut params, "", &input);
@@ -33069,6 +33939,7 @@ impl Rds for RdsClient {
         input: DescribeOptionGroupOptionsMessage,
     ) -> Result<OptionGroupOptionsMessage, RusotoError<DescribeOptionGroupOptionsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeOptionGroupOptions");
         let params = self.new_params("DescribeOptionGroupOptions");
         let mut params = params;
         DescribeOptionGroupOptionsMessageSerializer::serialize(&mut params, "", &input);
@@ -33102,6 +33973,7 @@ impl Rds for RdsClient {
         input: DescribeOptionGroupsMessage,
     ) -> Result<OptionGroups, RusotoError<DescribeOptionGroupsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeOptionGroups");
         let params = self.new_params("DescribeOptionGroups");
         let mut params = params;
         DescribeOptionGroupsMessageSerializer::serialize(&mut params, "", &input);
@@ -33136,6 +34008,7 @@ impl Rds for RdsClient {
         RusotoError<DescribeOrderableDBInstanceOptionsError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeOrderableDBInstanceOptions");
         let params = self.new_params("DescribeOrderableDBInstanceOptions");
         let mut params = params;
         DescribeOrderableDBInstanceOptionsMessageSerializer::serialize(&mut params, "", &input);
@@ -33173,6 +34046,7 @@ impl Rds for RdsClient {
     ) -> Result<PendingMaintenanceActionsMessage, RusotoError<DescribePendingMaintenanceActionsError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribePendingMaintenanceActions");
         let params = self.new_params("DescribePendingMaintenanceActions");
         let mut params = params;
         DescribePendingMaintenanceActionsMessageSerializer::serialize(&mut params, "", &input);
@@ -33209,6 +34083,7 @@ impl Rds for RdsClient {
         input: DescribeReservedDBInstancesMessage,
     ) -> Result<ReservedDBInstanceMessage, RusotoError<DescribeReservedDBInstancesError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeReservedDBInstances");
         let params = self.new_params("DescribeReservedDBInstances");
         let mut params = params;
         DescribeReservedDBInstancesMessageSerializer::serialize(&mut params, "", &input);
@@ -33245,6 +34120,7 @@ impl Rds for RdsClient {
         RusotoError<DescribeReservedDBInstancesOfferingsError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeReservedDBInstancesOfferings");
         let params = self.new_params("DescribeReservedDBInstancesOfferings");
         let mut params = params;
         DescribeReservedDBInstancesOfferingsMessageSerializer::serialize(&mut params, "", &input);
@@ -33281,6 +34157,7 @@ impl Rds for RdsClient {
         input: DescribeSourceRegionsMessage,
     ) -> Result<SourceRegionMessage, RusotoError<DescribeSourceRegionsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeSourceRegions");
         let params = self.new_params("DescribeSourceRegions");
         let mut params = params;
         DescribeSourceRegionsMessageSerializer::serialize(&mut params, "", &input);
@@ -33315,6 +34192,7 @@ impl Rds for RdsClient {
         RusotoError<DescribeValidDBInstanceModificationsError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DescribeValidDBInstanceModifications");
         let params = self.new_params("DescribeValidDBInstanceModifications");
         let mut params = params;
         DescribeValidDBInstanceModificationsMessageSerializer::serialize(&mut params, "", &input);
@@ -33351,6 +34229,7 @@ impl Rds for RdsClient {
         input: DownloadDBLogFilePortionMessage,
     ) -> Result<DownloadDBLogFilePortionDetails, RusotoError<DownloadDBLogFilePortionError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("DownloadDBLogFilePortion");
         let params = self.new_params("DownloadDBLogFilePortion");
         let mut params = params;
         DownloadDBLogFilePortionMessageSerializer::serialize(&mut params, "", &input);
@@ -33384,6 +34263,7 @@ impl Rds for RdsClient {
         input: FailoverDBClusterMessage,
     ) -> Result<FailoverDBClusterResult, RusotoError<FailoverDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("FailoverDBCluster");
         let params = self.new_params("FailoverDBCluster");
         let mut params = params;
         FailoverDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -33415,6 +34295,7 @@ impl Rds for RdsClient {
         input: ImportInstallationMediaMessage,
     ) -> Result<InstallationMedia, RusotoError<ImportInstallationMediaError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ImportInstallationMedia");
         let params = self.new_params("ImportInstallationMedia");
         let mut params = params;
         ImportInstallationMediaMessageSerializer::serialize(&mut params, "", &input);
@@ -33446,6 +34327,7 @@ impl Rds for RdsClient {
         input: ListTagsForResourceMessage,
     ) -> Result<TagListMessage, RusotoError<ListTagsForResourceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ListTagsForResource");
         let params = self.new_params("ListTagsForResource");
         let mut params = params;
         ListTagsForResourceMessageSerializer::serialize(&mut params, "", &input);
@@ -33477,6 +34359,7 @@ impl Rds for RdsClient {
         input: ModifyCertificatesMessage,
     ) -> Result<ModifyCertificatesResult, RusotoError<ModifyCertificatesError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyCertificates");
         let params = self.new_params("ModifyCertificates");
         let mut params = params;
         ModifyCertificatesMessageSerializer::serialize(&mut params, "", &input);
@@ -33510,6 +34393,7 @@ impl Rds for RdsClient {
         input: ModifyCurrentDBClusterCapacityMessage,
     ) -> Result<DBClusterCapacityInfo, RusotoError<ModifyCurrentDBClusterCapacityError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyCurrentDBClusterCapacity");
         let params = self.new_params("ModifyCurrentDBClusterCapacity");
         let mut params = params;
         ModifyCurrentDBClusterCapacityMessageSerializer::serialize(&mut params, "", &input);
@@ -33543,6 +34427,7 @@ impl Rds for RdsClient {
         input: ModifyDBClusterMessage,
     ) -> Result<ModifyDBClusterResult, RusotoError<ModifyDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBCluster");
         let params = self.new_params("ModifyDBCluster");
         let mut params = params;
         ModifyDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -33574,6 +34459,7 @@ impl Rds for RdsClient {
         input: ModifyDBClusterEndpointMessage,
     ) -> Result<DBClusterEndpoint, RusotoError<ModifyDBClusterEndpointError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBClusterEndpoint");
         let params = self.new_params("ModifyDBClusterEndpoint");
         let mut params = params;
         ModifyDBClusterEndpointMessageSerializer::serialize(&mut params, "", &input);
@@ -33606,6 +34492,7 @@ impl Rds for RdsClient {
     ) -> Result<DBClusterParameterGroupNameMessage, RusotoError<ModifyDBClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBClusterParameterGroup");
         let params = self.new_params("ModifyDBClusterParameterGroup");
         let mut params = params;
         ModifyDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -33642,6 +34529,7 @@ impl Rds for RdsClient {
         RusotoError<ModifyDBClusterSnapshotAttributeError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBClusterSnapshotAttribute");
         let params = self.new_params("ModifyDBClusterSnapshotAttribute");
         let mut params = params;
         ModifyDBClusterSnapshotAttributeMessageSerializer::serialize(&mut params, "", &input);
@@ -33678,6 +34566,7 @@ impl Rds for RdsClient {
         input: ModifyDBInstanceMessage,
     ) -> Result<ModifyDBInstanceResult, RusotoError<ModifyDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBInstance");
         let params = self.new_params("ModifyDBInstance");
         let mut params = params;
         ModifyDBInstanceMessageSerializer::serialize(&mut params, "", &input);
@@ -33709,6 +34598,7 @@ impl Rds for RdsClient {
         input: ModifyDBParameterGroupMessage,
     ) -> Result<DBParameterGroupNameMessage, RusotoError<ModifyDBParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBParameterGroup");
         let params = self.new_params("ModifyDBParameterGroup");
         let mut params = params;
         ModifyDBParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -33742,6 +34632,7 @@ impl Rds for RdsClient {
         input: ModifyDBProxyRequest,
     ) -> Result<ModifyDBProxyResponse, RusotoError<ModifyDBProxyError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBProxy");
         let params = self.new_params("ModifyDBProxy");
         let mut params = params;
         ModifyDBProxyRequestSerializer::serialize(&mut params, "", &input);
@@ -33773,6 +34664,7 @@ impl Rds for RdsClient {
         input: ModifyDBProxyTargetGroupRequest,
     ) -> Result<ModifyDBProxyTargetGroupResponse, RusotoError<ModifyDBProxyTargetGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBProxyTargetGroup");
         let params = self.new_params("ModifyDBProxyTargetGroup");
         let mut params = params;
         ModifyDBProxyTargetGroupRequestSerializer::serialize(&mut params, "", &input);
@@ -33806,6 +34698,7 @@ impl Rds for RdsClient {
         input: ModifyDBSnapshotMessage,
     ) -> Result<ModifyDBSnapshotResult, RusotoError<ModifyDBSnapshotError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBSnapshot");
         let params = self.new_params("ModifyDBSnapshot");
         let mut params = params;
         ModifyDBSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -33837,6 +34730,7 @@ impl Rds for RdsClient {
         input: ModifyDBSnapshotAttributeMessage,
     ) -> Result<ModifyDBSnapshotAttributeResult, RusotoError<ModifyDBSnapshotAttributeError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBSnapshotAttribute");
         let params = self.new_params("ModifyDBSnapshotAttribute");
         let mut params = params;
         ModifyDBSnapshotAttributeMessageSerializer::serialize(&mut params, "", &input);
@@ -33870,6 +34764,7 @@ impl Rds for RdsClient {
         input: ModifyDBSubnetGroupMessage,
     ) -> Result<ModifyDBSubnetGroupResult, RusotoError<ModifyDBSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyDBSubnetGroup");
         let params = self.new_params("ModifyDBSubnetGroup");
         let mut params = params;
         ModifyDBSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -33903,6 +34798,7 @@ impl Rds for RdsClient {
         input: ModifyEventSubscriptionMessage,
     ) -> Result<ModifyEventSubscriptionResult, RusotoError<ModifyEventSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyEventSubscription");
         let params = self.new_params("ModifyEventSubscription");
         let mut params = params;
         ModifyEventSubscriptionMessageSerializer::serialize(&mut params, "", &input);
@@ -33936,6 +34832,7 @@ impl Rds for RdsClient {
         input: ModifyGlobalClusterMessage,
     ) -> Result<ModifyGlobalClusterResult, RusotoError<ModifyGlobalClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyGlobalCluster");
         let params = self.new_params("ModifyGlobalCluster");
         let mut params = params;
         ModifyGlobalClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -33969,6 +34866,7 @@ impl Rds for RdsClient {
         input: ModifyOptionGroupMessage,
     ) -> Result<ModifyOptionGroupResult, RusotoError<ModifyOptionGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ModifyOptionGroup");
         let params = self.new_params("ModifyOptionGroup");
         let mut params = params;
         ModifyOptionGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -34000,6 +34898,7 @@ impl Rds for RdsClient {
         input: PromoteReadReplicaMessage,
     ) -> Result<PromoteReadReplicaResult, RusotoError<PromoteReadReplicaError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("PromoteReadReplica");
         let params = self.new_params("PromoteReadReplica");
         let mut params = params;
         PromoteReadReplicaMessageSerializer::serialize(&mut params, "", &input);
@@ -34034,6 +34933,7 @@ impl Rds for RdsClient {
     ) -> Result<PromoteReadReplicaDBClusterResult, RusotoError<PromoteReadReplicaDBClusterError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("PromoteReadReplicaDBCluster");
         let params = self.new_params("PromoteReadReplicaDBCluster");
         let mut params = params;
         PromoteReadReplicaDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -34070,6 +34970,7 @@ impl Rds for RdsClient {
         RusotoError<PurchaseReservedDBInstancesOfferingError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("PurchaseReservedDBInstancesOffering");
         let params = self.new_params("PurchaseReservedDBInstancesOffering");
         let mut params = params;
         PurchaseReservedDBInstancesOfferingMessageSerializer::serialize(&mut params, "", &input);
@@ -34106,6 +35007,7 @@ impl Rds for RdsClient {
         input: RebootDBInstanceMessage,
     ) -> Result<RebootDBInstanceResult, RusotoError<RebootDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RebootDBInstance");
         let params = self.new_params("RebootDBInstance");
         let mut params = params;
         RebootDBInstanceMessageSerializer::serialize(&mut params, "", &input);
@@ -34137,6 +35039,7 @@ impl Rds for RdsClient {
         input: RegisterDBProxyTargetsRequest,
     ) -> Result<RegisterDBProxyTargetsResponse, RusotoError<RegisterDBProxyTargetsError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RegisterDBProxyTargets");
         let params = self.new_params("RegisterDBProxyTargets");
         let mut params = params;
         RegisterDBProxyTargetsRequestSerializer::serialize(&mut params, "", &input);
@@ -34170,6 +35073,7 @@ impl Rds for RdsClient {
         input: RemoveFromGlobalClusterMessage,
     ) -> Result<RemoveFromGlobalClusterResult, RusotoError<RemoveFromGlobalClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RemoveFromGlobalCluster");
         let params = self.new_params("RemoveFromGlobalCluster");
         let mut params = params;
         RemoveFromGlobalClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -34203,6 +35107,7 @@ impl Rds for RdsClient {
         input: RemoveRoleFromDBClusterMessage,
     ) -> Result<(), RusotoError<RemoveRoleFromDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RemoveRoleFromDBCluster");
         let params = self.new_params("RemoveRoleFromDBCluster");
         let mut params = params;
         RemoveRoleFromDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -34223,6 +35128,7 @@ impl Rds for RdsClient {
         input: RemoveRoleFromDBInstanceMessage,
     ) -> Result<(), RusotoError<RemoveRoleFromDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RemoveRoleFromDBInstance");
         let params = self.new_params("RemoveRoleFromDBInstance");
         let mut params = params;
         RemoveRoleFromDBInstanceMessageSerializer::serialize(&mut params, "", &input);
@@ -34246,6 +35152,7 @@ impl Rds for RdsClient {
         RusotoError<RemoveSourceIdentifierFromSubscriptionError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RemoveSourceIdentifierFromSubscription");
         let params = self.new_params("RemoveSourceIdentifierFromSubscription");
         let mut params = params;
         RemoveSourceIdentifierFromSubscriptionMessageSerializer::serialize(&mut params, "", &input);
@@ -34282,6 +35189,7 @@ impl Rds for RdsClient {
         input: RemoveTagsFromResourceMessage,
     ) -> Result<(), RusotoError<RemoveTagsFromResourceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RemoveTagsFromResource");
         let params = self.new_params("RemoveTagsFromResource");
         let mut params = params;
         RemoveTagsFromResourceMessageSerializer::serialize(&mut params, "", &input);
@@ -34303,6 +35211,7 @@ impl Rds for RdsClient {
     ) -> Result<DBClusterParameterGroupNameMessage, RusotoError<ResetDBClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ResetDBClusterParameterGroup");
         let params = self.new_params("ResetDBClusterParameterGroup");
         let mut params = params;
         ResetDBClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -34336,6 +35245,7 @@ impl Rds for RdsClient {
         input: ResetDBParameterGroupMessage,
     ) -> Result<DBParameterGroupNameMessage, RusotoError<ResetDBParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("ResetDBParameterGroup");
         let params = self.new_params("ResetDBParameterGroup");
         let mut params = params;
         ResetDBParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -34369,6 +35279,7 @@ impl Rds for RdsClient {
         input: RestoreDBClusterFromS3Message,
     ) -> Result<RestoreDBClusterFromS3Result, RusotoError<RestoreDBClusterFromS3Error>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RestoreDBClusterFromS3");
         let params = self.new_params("RestoreDBClusterFromS3");
         let mut params = params;
         RestoreDBClusterFromS3MessageSerializer::serialize(&mut params, "", &input);
@@ -34403,6 +35314,7 @@ impl Rds for RdsClient {
     ) -> Result<RestoreDBClusterFromSnapshotResult, RusotoError<RestoreDBClusterFromSnapshotError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RestoreDBClusterFromSnapshot");
         let params = self.new_params("RestoreDBClusterFromSnapshot");
         let mut params = params;
         RestoreDBClusterFromSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -34437,6 +35349,7 @@ impl Rds for RdsClient {
     ) -> Result<RestoreDBClusterToPointInTimeResult, RusotoError<RestoreDBClusterToPointInTimeError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RestoreDBClusterToPointInTime");
         let params = self.new_params("RestoreDBClusterToPointInTime");
         let mut params = params;
         RestoreDBClusterToPointInTimeMessageSerializer::serialize(&mut params, "", &input);
@@ -34473,6 +35386,7 @@ impl Rds for RdsClient {
         RusotoError<RestoreDBInstanceFromDBSnapshotError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RestoreDBInstanceFromDBSnapshot");
         let params = self.new_params("RestoreDBInstanceFromDBSnapshot");
         let mut params = params;
         RestoreDBInstanceFromDBSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -34506,6 +35420,7 @@ impl Rds for RdsClient {
         input: RestoreDBInstanceFromS3Message,
     ) -> Result<RestoreDBInstanceFromS3Result, RusotoError<RestoreDBInstanceFromS3Error>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RestoreDBInstanceFromS3");
         let params = self.new_params("RestoreDBInstanceFromS3");
         let mut params = params;
         RestoreDBInstanceFromS3MessageSerializer::serialize(&mut params, "", &input);
@@ -34542,6 +35457,7 @@ impl Rds for RdsClient {
         RusotoError<RestoreDBInstanceToPointInTimeError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RestoreDBInstanceToPointInTime");
         let params = self.new_params("RestoreDBInstanceToPointInTime");
         let mut params = params;
         RestoreDBInstanceToPointInTimeMessageSerializer::serialize(&mut params, "", &input);
@@ -34576,6 +35492,7 @@ impl Rds for RdsClient {
     ) -> Result<RevokeDBSecurityGroupIngressResult, RusotoError<RevokeDBSecurityGroupIngressError>>
     {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("RevokeDBSecurityGroupIngress");
         let params = self.new_params("RevokeDBSecurityGroupIngress");
         let mut params = params;
         RevokeDBSecurityGroupIngressMessageSerializer::serialize(&mut params, "", &input);
@@ -34609,6 +35526,7 @@ impl Rds for RdsClient {
         input: StartActivityStreamRequest,
     ) -> Result<StartActivityStreamResponse, RusotoError<StartActivityStreamError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("StartActivityStream");
         let params = self.new_params("StartActivityStream");
         let mut params = params;
         StartActivityStreamRequestSerializer::serialize(&mut params, "", &input);
@@ -34642,6 +35560,7 @@ impl Rds for RdsClient {
         input: StartDBClusterMessage,
     ) -> Result<StartDBClusterResult, RusotoError<StartDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("StartDBCluster");
         let params = self.new_params("StartDBCluster");
         let mut params = params;
         StartDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -34673,6 +35592,7 @@ impl Rds for RdsClient {
         input: StartDBInstanceMessage,
     ) -> Result<StartDBInstanceResult, RusotoError<StartDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("StartDBInstance");
         let params = self.new_params("StartDBInstance");
         let mut params = params;
         StartDBInstanceMessageSerializer::serialize(&mut params, "", &input);
@@ -34707,6 +35627,7 @@ impl Rds for RdsClient {
         RusotoError<StartDBInstanceAutomatedBackupsReplicationError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("StartDBInstanceAutomatedBackupsReplication");
         let params = self.new_params("StartDBInstanceAutomatedBackupsReplication");
         let mut params = params;
         StartDBInstanceAutomatedBackupsReplicationMessageSerializer::serialize(
@@ -34747,6 +35668,7 @@ impl Rds for RdsClient {
         input: StartExportTaskMessage,
     ) -> Result<ExportTask, RusotoError<StartExportTaskError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("StartExportTask");
         let params = self.new_params("StartExportTask");
         let mut params = params;
         StartExportTaskMessageSerializer::serialize(&mut params, "", &input);
@@ -34777,6 +35699,7 @@ impl Rds for RdsClient {
         input: StopActivityStreamRequest,
     ) -> Result<StopActivityStreamResponse, RusotoError<StopActivityStreamError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("StopActivityStream");
         let params = self.new_params("StopActivityStream");
         let mut params = params;
         StopActivityStreamRequestSerializer::serialize(&mut params, "", &input);
@@ -34810,6 +35733,7 @@ impl Rds for RdsClient {
         input: StopDBClusterMessage,
     ) -> Result<StopDBClusterResult, RusotoError<StopDBClusterError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("StopDBCluster");
         let params = self.new_params("StopDBCluster");
         let mut params = params;
         StopDBClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -34841,6 +35765,7 @@ impl Rds for RdsClient {
         input: StopDBInstanceMessage,
     ) -> Result<StopDBInstanceResult, RusotoError<StopDBInstanceError>> {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("StopDBInstance");
         let params = self.new_params("StopDBInstance");
         let mut params = params;
         StopDBInstanceMessageSerializer::serialize(&mut params, "", &input);
@@ -34875,6 +35800,7 @@ impl Rds for RdsClient {
         RusotoError<StopDBInstanceAutomatedBackupsReplicationError>,
     > {
         let mut request = SignedRequest::new("POST", "rds", &self.region, "/");
+        request.set_operation("StopDBInstanceAutomatedBackupsReplication");
         let params = self.new_params("StopDBInstanceAutomatedBackupsReplication");
         let mut params = params;
         StopDBInstanceAutomatedBackupsReplicationMessageSerializer::serialize(
@@ -34910,6 +35836,1323 @@ impl Rds for RdsClient {
     }
 }
 
+/// A blocking client for the Amazon RDS API, running the calls of a `RdsClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `RdsClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct RdsBlockingClient {
+    client: RdsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl RdsBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> RdsBlockingClient {
+        RdsBlockingClient::from_client(RdsClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> RdsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        RdsBlockingClient::from_client(RdsClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> RdsBlockingClient {
+        RdsBlockingClient::from_client(RdsClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> RdsBlockingClient {
+        RdsBlockingClient::from_client(RdsClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: RdsClient) -> RdsBlockingClient {
+        RdsBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> RdsBlockingClient {
+        RdsBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> RdsBlockingClient {
+        RdsBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Rds::add_role_to_db_cluster`].
+    pub fn add_role_to_db_cluster(
+        &self,
+        input: AddRoleToDBClusterMessage,
+    ) -> Result<(), RusotoError<AddRoleToDBClusterError>> {
+        self.runtime
+            .block_on(self.client.add_role_to_db_cluster(input))
+    }
+
+    /// Blocking version of [`Rds::add_role_to_db_instance`].
+    pub fn add_role_to_db_instance(
+        &self,
+        input: AddRoleToDBInstanceMessage,
+    ) -> Result<(), RusotoError<AddRoleToDBInstanceError>> {
+        self.runtime
+            .block_on(self.client.add_role_to_db_instance(input))
+    }
+
+    /// Blocking version of [`Rds::add_source_identifier_to_subscription`].
+    pub fn add_source_identifier_to_subscription(
+        &self,
+        input: AddSourceIdentifierToSubscriptionMessage,
+    ) -> Result<
+        AddSourceIdentifierToSubscriptionResult,
+        RusotoError<AddSourceIdentifierToSubscriptionError>,
+    > {
+        self.runtime
+            .block_on(self.client.add_source_identifier_to_subscription(input))
+    }
+
+    /// Blocking version of [`Rds::add_tags_to_resource`].
+    pub fn add_tags_to_resource(
+        &self,
+        input: AddTagsToResourceMessage,
+    ) -> Result<(), RusotoError<AddTagsToResourceError>> {
+        self.runtime
+            .block_on(self.client.add_tags_to_resource(input))
+    }
+
+    /// Blocking version of [`Rds::apply_pending_maintenance_action`].
+    pub fn apply_pending_maintenance_action(
+        &self,
+        input: ApplyPendingMaintenanceActionMessage,
+    ) -> Result<ApplyPendingMaintenanceActionResult, RusotoError<ApplyPendingMaintenanceActionError>>
+    {
+        self.runtime
+            .block_on(self.client.apply_pending_maintenance_action(input))
+    }
+
+    /// Blocking version of [`Rds::authorize_db_security_group_ingress`].
+    pub fn authorize_db_security_group_ingress(
+        &self,
+        input: AuthorizeDBSecurityGroupIngressMessage,
+    ) -> Result<
+        AuthorizeDBSecurityGroupIngressResult,
+        RusotoError<AuthorizeDBSecurityGroupIngressError>,
+    > {
+        self.runtime
+            .block_on(self.client.authorize_db_security_group_ingress(input))
+    }
+
+    /// Blocking version of [`Rds::backtrack_db_cluster`].
+    pub fn backtrack_db_cluster(
+        &self,
+        input: BacktrackDBClusterMessage,
+    ) -> Result<DBClusterBacktrack, RusotoError<BacktrackDBClusterError>> {
+        self.runtime
+            .block_on(self.client.backtrack_db_cluster(input))
+    }
+
+    /// Blocking version of [`Rds::cancel_export_task`].
+    pub fn cancel_export_task(
+        &self,
+        input: CancelExportTaskMessage,
+    ) -> Result<ExportTask, RusotoError<CancelExportTaskError>> {
+        self.runtime.block_on(self.client.cancel_export_task(input))
+    }
+
+    /// Blocking version of [`Rds::copy_db_cluster_parameter_group`].
+    pub fn copy_db_cluster_parameter_group(
+        &self,
+        input: CopyDBClusterParameterGroupMessage,
+    ) -> Result<CopyDBClusterParameterGroupResult, RusotoError<CopyDBClusterParameterGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.copy_db_cluster_parameter_group(input))
+    }
+
+    /// Blocking version of [`Rds::copy_db_cluster_snapshot`].
+    pub fn copy_db_cluster_snapshot(
+        &self,
+        input: CopyDBClusterSnapshotMessage,
+    ) -> Result<CopyDBClusterSnapshotResult, RusotoError<CopyDBClusterSnapshotError>> {
+        self.runtime
+            .block_on(self.client.copy_db_cluster_snapshot(input))
+    }
+
+    /// Blocking version of [`Rds::copy_db_parameter_group`].
+    pub fn copy_db_parameter_group(
+        &self,
+        input: CopyDBParameterGroupMessage,
+    ) -> Result<CopyDBParameterGroupResult, RusotoError<CopyDBParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.copy_db_parameter_group(input))
+    }
+
+    /// Blocking version of [`Rds::copy_db_snapshot`].
+    pub fn copy_db_snapshot(
+        &self,
+        input: CopyDBSnapshotMessage,
+    ) -> Result<CopyDBSnapshotResult, RusotoError<CopyDBSnapshotError>> {
+        self.runtime.block_on(self.client.copy_db_snapshot(input))
+    }
+
+    /// Blocking version of [`Rds::copy_option_group`].
+    pub fn copy_option_group(
+        &self,
+        input: CopyOptionGroupMessage,
+    ) -> Result<CopyOptionGroupResult, RusotoError<CopyOptionGroupError>> {
+        self.runtime.block_on(self.client.copy_option_group(input))
+    }
+
+    /// Blocking version of [`Rds::create_custom_availability_zone`].
+    pub fn create_custom_availability_zone(
+        &self,
+        input: CreateCustomAvailabilityZoneMessage,
+    ) -> Result<CreateCustomAvailabilityZoneResult, RusotoError<CreateCustomAvailabilityZoneError>>
+    {
+        self.runtime
+            .block_on(self.client.create_custom_availability_zone(input))
+    }
+
+    /// Blocking version of [`Rds::create_db_cluster`].
+    pub fn create_db_cluster(
+        &self,
+        input: CreateDBClusterMessage,
+    ) -> Result<CreateDBClusterResult, RusotoError<CreateDBClusterError>> {
+        self.runtime.block_on(self.client.create_db_cluster(input))
+    }
+
+    /// Blocking version of [`Rds::create_db_cluster_endpoint`].
+    pub fn create_db_cluster_endpoint(
+        &self,
+        input: CreateDBClusterEndpointMessage,
+    ) -> Result<DBClusterEndpoint, RusotoError<CreateDBClusterEndpointError>> {
+        self.runtime
+            .block_on(self.client.create_db_cluster_endpoint(input))
+    }
+
+    /// Blocking version of [`Rds::create_db_cluster_parameter_group`].
+    pub fn create_db_cluster_parameter_group(
+        &self,
+        input: CreateDBClusterParameterGroupMessage,
+    ) -> Result<CreateDBClusterParameterGroupResult, RusotoError<CreateDBClusterParameterGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.create_db_cluster_parameter_group(input))
+    }
+
+    /// Blocking version of [`Rds::create_db_cluster_snapshot`].
+    pub fn create_db_cluster_snapshot(
+        &self,
+        input: CreateDBClusterSnapshotMessage,
+    ) -> Result<CreateDBClusterSnapshotResult, RusotoError<CreateDBClusterSnapshotError>> {
+        self.runtime
+            .block_on(self.client.create_db_cluster_snapshot(input))
+    }
+
+    /// Blocking version of [`Rds::create_db_instance`].
+    pub fn create_db_instance(
+        &self,
+        input: CreateDBInstanceMessage,
+    ) -> Result<CreateDBInstanceResult, RusotoError<CreateDBInstanceError>> {
+        self.runtime.block_on(self.client.create_db_instance(input))
+    }
+
+    /// Blocking version of [`Rds::create_db_instance_read_replica`].
+    pub fn create_db_instance_read_replica(
+        &self,
+        input: CreateDBInstanceReadReplicaMessage,
+    ) -> Result<CreateDBInstanceReadReplicaResult, RusotoError<CreateDBInstanceReadReplicaError>>
+    {
+        self.runtime
+            .block_on(self.client.create_db_instance_read_replica(input))
+    }
+
+    /// Blocking version of [`Rds::create_db_parameter_group`].
+    pub fn create_db_parameter_group(
+        &self,
+        input: CreateDBParameterGroupMessage,
+    ) -> Result<CreateDBParameterGroupResult, RusotoError<CreateDBParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.create_db_parameter_group(input))
+    }
+
+    /// Blocking version of [`Rds::create_db_proxy`].
+    pub fn create_db_proxy(
+        &self,
+        input: CreateDBProxyRequest,
+    ) -> Result<CreateDBProxyResponse, RusotoError<CreateDBProxyError>> {
+        self.runtime.block_on(self.client.create_db_proxy(input))
+    }
+
+    /// Blocking version of [`Rds::create_db_security_group`].
+    pub fn create_db_security_group(
+        &self,
+        input: CreateDBSecurityGroupMessage,
+    ) -> Result<CreateDBSecurityGroupResult, RusotoError<CreateDBSecurityGroupError>> {
+        self.runtime
+            .block_on(self.client.create_db_security_group(input))
+    }
+
+    /// Blocking version of [`Rds::create_db_snapshot`].
+    pub fn create_db_snapshot(
+        &self,
+        input: CreateDBSnapshotMessage,
+    ) -> Result<CreateDBSnapshotResult, RusotoError<CreateDBSnapshotError>> {
+        self.runtime.block_on(self.client.create_db_snapshot(input))
+    }
+
+    /// Blocking version of [`Rds::create_db_subnet_group`].
+    pub fn create_db_subnet_group(
+        &self,
+        input: CreateDBSubnetGroupMessage,
+    ) -> Result<CreateDBSubnetGroupResult, RusotoError<CreateDBSubnetGroupError>> {
+        self.runtime
+            .block_on(self.client.create_db_subnet_group(input))
+    }
+
+    /// Blocking version of [`Rds::create_event_subscription`].
+    pub fn create_event_subscription(
+        &self,
+        input: CreateEventSubscriptionMessage,
+    ) -> Result<CreateEventSubscriptionResult, RusotoError<CreateEventSubscriptionError>> {
+        self.runtime
+            .block_on(self.client.create_event_subscription(input))
+    }
+
+    /// Blocking version of [`Rds::create_global_cluster`].
+    pub fn create_global_cluster(
+        &self,
+        input: CreateGlobalClusterMessage,
+    ) -> Result<CreateGlobalClusterResult, RusotoError<CreateGlobalClusterError>> {
+        self.runtime
+            .block_on(self.client.create_global_cluster(input))
+    }
+
+    /// Blocking version of [`Rds::create_option_group`].
+    pub fn create_option_group(
+        &self,
+        input: CreateOptionGroupMessage,
+    ) -> Result<CreateOptionGroupResult, RusotoError<CreateOptionGroupError>> {
+        self.runtime
+            .block_on(self.client.create_option_group(input))
+    }
+
+    /// Blocking version of [`Rds::delete_custom_availability_zone`].
+    pub fn delete_custom_availability_zone(
+        &self,
+        input: DeleteCustomAvailabilityZoneMessage,
+    ) -> Result<DeleteCustomAvailabilityZoneResult, RusotoError<DeleteCustomAvailabilityZoneError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_custom_availability_zone(input))
+    }
+
+    /// Blocking version of [`Rds::delete_db_cluster`].
+    pub fn delete_db_cluster(
+        &self,
+        input: DeleteDBClusterMessage,
+    ) -> Result<DeleteDBClusterResult, RusotoError<DeleteDBClusterError>> {
+        self.runtime.block_on(self.client.delete_db_cluster(input))
+    }
+
+    /// Blocking version of [`Rds::delete_db_cluster_endpoint`].
+    pub fn delete_db_cluster_endpoint(
+        &self,
+        input: DeleteDBClusterEndpointMessage,
+    ) -> Result<DBClusterEndpoint, RusotoError<DeleteDBClusterEndpointError>> {
+        self.runtime
+            .block_on(self.client.delete_db_cluster_endpoint(input))
+    }
+
+    /// Blocking version of [`Rds::delete_db_cluster_parameter_group`].
+    pub fn delete_db_cluster_parameter_group(
+        &self,
+        input: DeleteDBClusterParameterGroupMessage,
+    ) -> Result<(), RusotoError<DeleteDBClusterParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_db_cluster_parameter_group(input))
+    }
+
+    /// Blocking version of [`Rds::delete_db_cluster_snapshot`].
+    pub fn delete_db_cluster_snapshot(
+        &self,
+        input: DeleteDBClusterSnapshotMessage,
+    ) -> Result<DeleteDBClusterSnapshotResult, RusotoError<DeleteDBClusterSnapshotError>> {
+        self.runtime
+            .block_on(self.client.delete_db_cluster_snapshot(input))
+    }
+
+    /// Blocking version of [`Rds::delete_db_instance`].
+    pub fn delete_db_instance(
+        &self,
+        input: DeleteDBInstanceMessage,
+    ) -> Result<DeleteDBInstanceResult, RusotoError<DeleteDBInstanceError>> {
+        self.runtime.block_on(self.client.delete_db_instance(input))
+    }
+
+    /// Blocking version of [`Rds::delete_db_instance_automated_backup`].
+    pub fn delete_db_instance_automated_backup(
+        &self,
+        input: DeleteDBInstanceAutomatedBackupMessage,
+    ) -> Result<
+        DeleteDBInstanceAutomatedBackupResult,
+        RusotoError<DeleteDBInstanceAutomatedBackupError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_db_instance_automated_backup(input))
+    }
+
+    /// Blocking version of [`Rds::delete_db_parameter_group`].
+    pub fn delete_db_parameter_group(
+        &self,
+        input: DeleteDBParameterGroupMessage,
+    ) -> Result<(), RusotoError<DeleteDBParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_db_parameter_group(input))
+    }
+
+    /// Blocking version of [`Rds::delete_db_proxy`].
+    pub fn delete_db_proxy(
+        &self,
+        input: DeleteDBProxyRequest,
+    ) -> Result<DeleteDBProxyResponse, RusotoError<DeleteDBProxyError>> {
+        self.runtime.block_on(self.client.delete_db_proxy(input))
+    }
+
+    /// Blocking version of [`Rds::delete_db_security_group`].
+    pub fn delete_db_security_group(
+        &self,
+        input: DeleteDBSecurityGroupMessage,
+    ) -> Result<(), RusotoError<DeleteDBSecurityGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_db_security_group(input))
+    }
+
+    /// Blocking version of [`Rds::delete_db_snapshot`].
+    pub fn delete_db_snapshot(
+        &self,
+        input: DeleteDBSnapshotMessage,
+    ) -> Result<DeleteDBSnapshotResult, RusotoError<DeleteDBSnapshotError>> {
+        self.runtime.block_on(self.client.delete_db_snapshot(input))
+    }
+
+    /// Blocking version of [`Rds::delete_db_subnet_group`].
+    pub fn delete_db_subnet_group(
+        &self,
+        input: DeleteDBSubnetGroupMessage,
+    ) -> Result<(), RusotoError<DeleteDBSubnetGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_db_subnet_group(input))
+    }
+
+    /// Blocking version of [`Rds::delete_event_subscription`].
+    pub fn delete_event_subscription(
+        &self,
+        input: DeleteEventSubscriptionMessage,
+    ) -> Result<DeleteEventSubscriptionResult, RusotoError<DeleteEventSubscriptionError>> {
+        self.runtime
+            .block_on(self.client.delete_event_subscription(input))
+    }
+
+    /// Blocking version of [`Rds::delete_global_cluster`].
+    pub fn delete_global_cluster(
+        &self,
+        input: DeleteGlobalClusterMessage,
+    ) -> Result<DeleteGlobalClusterResult, RusotoError<DeleteGlobalClusterError>> {
+        self.runtime
+            .block_on(self.client.delete_global_cluster(input))
+    }
+
+    /// Blocking version of [`Rds::delete_installation_media`].
+    pub fn delete_installation_media(
+        &self,
+        input: DeleteInstallationMediaMessage,
+    ) -> Result<InstallationMedia, RusotoError<DeleteInstallationMediaError>> {
+        self.runtime
+            .block_on(self.client.delete_installation_media(input))
+    }
+
+    /// Blocking version of [`Rds::delete_option_group`].
+    pub fn delete_option_group(
+        &self,
+        input: DeleteOptionGroupMessage,
+    ) -> Result<(), RusotoError<DeleteOptionGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_option_group(input))
+    }
+
+    /// Blocking version of [`Rds::deregister_db_proxy_targets`].
+    pub fn deregister_db_proxy_targets(
+        &self,
+        input: DeregisterDBProxyTargetsRequest,
+    ) -> Result<DeregisterDBProxyTargetsResponse, RusotoError<DeregisterDBProxyTargetsError>> {
+        self.runtime
+            .block_on(self.client.deregister_db_proxy_targets(input))
+    }
+
+    /// Blocking version of [`Rds::describe_account_attributes`].
+    pub fn describe_account_attributes(
+        &self,
+        input: DescribeAccountAttributesMessage,
+    ) -> Result<AccountAttributesMessage, RusotoError<DescribeAccountAttributesError>> {
+        self.runtime
+            .block_on(self.client.describe_account_attributes(input))
+    }
+
+    /// Blocking version of [`Rds::describe_certificates`].
+    pub fn describe_certificates(
+        &self,
+        input: DescribeCertificatesMessage,
+    ) -> Result<CertificateMessage, RusotoError<DescribeCertificatesError>> {
+        self.runtime
+            .block_on(self.client.describe_certificates(input))
+    }
+
+    /// Blocking version of [`Rds::describe_custom_availability_zones`].
+    pub fn describe_custom_availability_zones(
+        &self,
+        input: DescribeCustomAvailabilityZonesMessage,
+    ) -> Result<CustomAvailabilityZoneMessage, RusotoError<DescribeCustomAvailabilityZonesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_custom_availability_zones(input))
+    }
+
+    /// Blocking version of [`Rds::describe_db_cluster_backtracks`].
+    pub fn describe_db_cluster_backtracks(
+        &self,
+        input: DescribeDBClusterBacktracksMessage,
+    ) -> Result<DBClusterBacktrackMessage, RusotoError<DescribeDBClusterBacktracksError>> {
+        self.runtime
+            .block_on(self.client.describe_db_cluster_backtracks(input))
+    }
+
+    /// Blocking version of [`Rds::describe_db_cluster_endpoints`].
+    pub fn describe_db_cluster_endpoints(
+        &self,
+        input: DescribeDBClusterEndpointsMessage,
+    ) -> Result<DBClusterEndpointMessage, RusotoError<DescribeDBClusterEndpointsError>> {
+        self.runtime
+            .block_on(self.client.describe_db_cluster_endpoints(input))
+    }
+
+    /// Blocking version of [`Rds::describe_db_cluster_parameter_groups`].
+    pub fn describe_db_cluster_parameter_groups(
+        &self,
+        input: DescribeDBClusterParameterGroupsMessage,
+    ) -> Result<DBClusterParameterGroupsMessage, RusotoError<DescribeDBClusterParameterGroupsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_db_cluster_parameter_groups(input))
+    }
+
+    /// Blocking version of [`Rds::describe_db_cluster_parameters`].
+    pub fn describe_db_cluster_parameters(
+        &self,
+        input: DescribeDBClusterParametersMessage,
+    ) -> Result<DBClusterParameterGroupDetails, RusotoError<DescribeDBClusterParametersError>> {
+        self.runtime
+            .block_on(self.client.describe_db_cluster_parameters(input))
+    }
+
+    /// Blocking version of [`Rds::describe_db_cluster_snapshot_attributes`].
+    pub fn describe_db_cluster_snapshot_attributes(
+        &self,
+        input: DescribeDBClusterSnapshotAttributesMessage,
+    ) -> Result<
+        DescribeDBClusterSnapshotAttributesResult,
+        RusotoError<DescribeDBClusterSnapshotAttributesError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_db_cluster_snapshot_attributes(input))
+    }
+
+    /// Blocking version of [`Rds::describe_db_cluster_snapshots`].
+    pub fn describe_db_cluster_snapshots(
+        &self,
+        input: DescribeDBClusterSnapshotsMessage,
+    ) -> Result<DBClusterSnapshotMessage, RusotoError<DescribeDBClusterSnapshotsError>> {
+        self.runtime
+            .block_on(self.client.describe_db_cluster_snapshots(input))
+    }
+
+    /// Blocking version of [`Rds::describe_db_clusters`].
+    pub fn describe_db_clusters(
+        &self,
+        input: DescribeDBClustersMessage,
+    ) -> Result<DBClusterMessage, RusotoError<DescribeDBClustersError>> {
+        self.runtime
+            .block_on(self.client.describe_db_clusters(input))
+    }
+
+    /// Blocking version of [`Rds::describe_db_engine_versions`].
+    pub fn describe_db_engine_versions(
+        &self,
+        input: DescribeDBEngineVersionsMessage,
+    ) -> Result<DBEngineVersionMessage, RusotoError<DescribeDBEngineVersionsError>> {
+        self.runtime
+            .block_on(self.client.describe_db_engine_versions(input))
+    }
+
+    /// Blocking version of [`Rds::describe_db_instance_automated_backups`].
+    pub fn describe_db_instance_automated_backups(
+        &self,
+        input: DescribeDBInstanceAutomatedBackupsMessage,
+    ) -> Result<
+        DBInstanceAutomatedBackupMessage,
+        RusotoError<DescribeDBInstanceAutomatedBackupsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_db_instance_automated_backups(input))
+    }
+
+    /// Blocking version of [`Rds::describe_db_instances`].
+    pub fn describe_db_instances(
+        &self,
+        input: DescribeDBInstancesMessage,
+    ) -> Result<DBInstanceMessage, RusotoError<DescribeDBInstancesError>> {
+        self.runtime
+            .block_on(self.client.describe_db_instances(input))
+    }
+
+    /// Blocking version of [`Rds::describe_db_log_files`].
+    pub fn describe_db_log_files(
+        &self,
+        input: DescribeDBLogFilesMessage,
+    ) -> Result<DescribeDBLogFilesResponse, RusotoError<DescribeDBLogFilesError>> {
+        self.runtime
+            .block_on(self.client.describe_db_log_files(input))
+    }
+
+    /// Blocking version of [`Rds::describe_db_parameter_groups`].
+    pub fn describe_db_parameter_groups(
+        &self,
+        input: DescribeDBParameterGroupsMessage,
+    ) -> Result<DBParameterGroupsMessage, RusotoError<DescribeDBParameterGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_db_parameter_groups(input))
+    }
+
+    /// Blocking version of [`Rds::describe_db_parameters`].
+    pub fn describe_db_parameters(
+        &self,
+        input: DescribeDBParametersMessage,
+    ) -> Result<DBParameterGroupDetails, RusotoError<DescribeDBParametersError>> {
+        self.runtime
+            .block_on(self.client.describe_db_parameters(input))
+    }
+
+    /// Blocking version of [`Rds::describe_db_proxies`].
+    pub fn describe_db_proxies(
+        &self,
+        input: DescribeDBProxiesRequest,
+    ) -> Result<DescribeDBProxiesResponse, RusotoError<DescribeDBProxiesError>> {
+        self.runtime
+            .block_on(self.client.describe_db_proxies(input))
+    }
+
+    /// Blocking version of [`Rds::describe_db_proxy_target_groups`].
+    pub fn describe_db_proxy_target_groups(
+        &self,
+        input: DescribeDBProxyTargetGroupsRequest,
+    ) -> Result<DescribeDBProxyTargetGroupsResponse, RusotoError<DescribeDBProxyTargetGroupsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_db_proxy_target_groups(input))
+    }
+
+    /// Blocking version of [`Rds::describe_db_proxy_targets`].
+    pub fn describe_db_proxy_targets(
+        &self,
+        input: DescribeDBProxyTargetsRequest,
+    ) -> Result<DescribeDBProxyTargetsResponse, RusotoError<DescribeDBProxyTargetsError>> {
+        self.runtime
+            .block_on(self.client.describe_db_proxy_targets(input))
+    }
+
+    /// Blocking version of [`Rds::describe_db_security_groups`].
+    pub fn describe_db_security_groups(
+        &self,
+        input: DescribeDBSecurityGroupsMessage,
+    ) -> Result<DBSecurityGroupMessage, RusotoError<DescribeDBSecurityGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_db_security_groups(input))
+    }
+
+    /// Blocking version of [`Rds::describe_db_snapshot_attributes`].
+    pub fn describe_db_snapshot_attributes(
+        &self,
+        input: DescribeDBSnapshotAttributesMessage,
+    ) -> Result<DescribeDBSnapshotAttributesResult, RusotoError<DescribeDBSnapshotAttributesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_db_snapshot_attributes(input))
+    }
+
+    /// Blocking version of [`Rds::describe_db_snapshots`].
+    pub fn describe_db_snapshots(
+        &self,
+        input: DescribeDBSnapshotsMessage,
+    ) -> Result<DBSnapshotMessage, RusotoError<DescribeDBSnapshotsError>> {
+        self.runtime
+            .block_on(self.client.describe_db_snapshots(input))
+    }
+
+    /// Blocking version of [`Rds::describe_db_subnet_groups`].
+    pub fn describe_db_subnet_groups(
+        &self,
+        input: DescribeDBSubnetGroupsMessage,
+    ) -> Result<DBSubnetGroupMessage, RusotoError<DescribeDBSubnetGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_db_subnet_groups(input))
+    }
+
+    /// Blocking version of [`Rds::describe_engine_default_cluster_parameters`].
+    pub fn describe_engine_default_cluster_parameters(
+        &self,
+        input: DescribeEngineDefaultClusterParametersMessage,
+    ) -> Result<
+        DescribeEngineDefaultClusterParametersResult,
+        RusotoError<DescribeEngineDefaultClusterParametersError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_engine_default_cluster_parameters(input),
+        )
+    }
+
+    /// Blocking version of [`Rds::describe_engine_default_parameters`].
+    pub fn describe_engine_default_parameters(
+        &self,
+        input: DescribeEngineDefaultParametersMessage,
+    ) -> Result<
+        DescribeEngineDefaultParametersResult,
+        RusotoError<DescribeEngineDefaultParametersError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_engine_default_parameters(input))
+    }
+
+    /// Blocking version of [`Rds::describe_event_categories`].
+    pub fn describe_event_categories(
+        &self,
+        input: DescribeEventCategoriesMessage,
+    ) -> Result<EventCategoriesMessage, RusotoError<DescribeEventCategoriesError>> {
+        self.runtime
+            .block_on(self.client.describe_event_categories(input))
+    }
+
+    /// Blocking version of [`Rds::describe_event_subscriptions`].
+    pub fn describe_event_subscriptions(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> Result<EventSubscriptionsMessage, RusotoError<DescribeEventSubscriptionsError>> {
+        self.runtime
+            .block_on(self.client.describe_event_subscriptions(input))
+    }
+
+    /// Blocking version of [`Rds::describe_events`].
+    pub fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> Result<EventsMessage, RusotoError<DescribeEventsError>> {
+        self.runtime.block_on(self.client.describe_events(input))
+    }
+
+    /// Blocking version of [`Rds::describe_export_tasks`].
+    pub fn describe_export_tasks(
+        &self,
+        input: DescribeExportTasksMessage,
+    ) -> Result<ExportTasksMessage, RusotoError<DescribeExportTasksError>> {
+        self.runtime
+            .block_on(self.client.describe_export_tasks(input))
+    }
+
+    /// Blocking version of [`Rds::describe_global_clusters`].
+    pub fn describe_global_clusters(
+        &self,
+        input: DescribeGlobalClustersMessage,
+    ) -> Result<GlobalClustersMessage, RusotoError<DescribeGlobalClustersError>> {
+        self.runtime
+            .block_on(self.client.describe_global_clusters(input))
+    }
+
+    /// Blocking version of [`Rds::describe_installation_media`].
+    pub fn describe_installation_media(
+        &self,
+        input: DescribeInstallationMediaMessage,
+    ) -> Result<InstallationMediaMessage, RusotoError<DescribeInstallationMediaError>> {
+        self.runtime
+            .block_on(self.client.describe_installation_media(input))
+    }
+
+    /// Blocking version of [`Rds::describe_option_group_options`].
+    pub fn describe_option_group_options(
+        &self,
+        input: DescribeOptionGroupOptionsMessage,
+    ) -> Result<OptionGroupOptionsMessage, RusotoError<DescribeOptionGroupOptionsError>> {
+        self.runtime
+            .block_on(self.client.describe_option_group_options(input))
+    }
+
+    /// Blocking version of [`Rds::describe_option_groups`].
+    pub fn describe_option_groups(
+        &self,
+        input: DescribeOptionGroupsMessage,
+    ) -> Result<OptionGroups, RusotoError<DescribeOptionGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_option_groups(input))
+    }
+
+    /// Blocking version of [`Rds::describe_orderable_db_instance_options`].
+    pub fn describe_orderable_db_instance_options(
+        &self,
+        input: DescribeOrderableDBInstanceOptionsMessage,
+    ) -> Result<
+        OrderableDBInstanceOptionsMessage,
+        RusotoError<DescribeOrderableDBInstanceOptionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_orderable_db_instance_options(input))
+    }
+
+    /// Blocking version of [`Rds::describe_pending_maintenance_actions`].
+    pub fn describe_pending_maintenance_actions(
+        &self,
+        input: DescribePendingMaintenanceActionsMessage,
+    ) -> Result<PendingMaintenanceActionsMessage, RusotoError<DescribePendingMaintenanceActionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_pending_maintenance_actions(input))
+    }
+
+    /// Blocking version of [`Rds::describe_reserved_db_instances`].
+    pub fn describe_reserved_db_instances(
+        &self,
+        input: DescribeReservedDBInstancesMessage,
+    ) -> Result<ReservedDBInstanceMessage, RusotoError<DescribeReservedDBInstancesError>> {
+        self.runtime
+            .block_on(self.client.describe_reserved_db_instances(input))
+    }
+
+    /// Blocking version of [`Rds::describe_reserved_db_instances_offerings`].
+    pub fn describe_reserved_db_instances_offerings(
+        &self,
+        input: DescribeReservedDBInstancesOfferingsMessage,
+    ) -> Result<
+        ReservedDBInstancesOfferingMessage,
+        RusotoError<DescribeReservedDBInstancesOfferingsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_reserved_db_instances_offerings(input))
+    }
+
+    /// Blocking version of [`Rds::describe_source_regions`].
+    pub fn describe_source_regions(
+        &self,
+        input: DescribeSourceRegionsMessage,
+    ) -> Result<SourceRegionMessage, RusotoError<DescribeSourceRegionsError>> {
+        self.runtime
+            .block_on(self.client.describe_source_regions(input))
+    }
+
+    /// Blocking version of [`Rds::describe_valid_db_instance_modifications`].
+    pub fn describe_valid_db_instance_modifications(
+        &self,
+        input: DescribeValidDBInstanceModificationsMessage,
+    ) -> Result<
+        DescribeValidDBInstanceModificationsResult,
+        RusotoError<DescribeValidDBInstanceModificationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_valid_db_instance_modifications(input))
+    }
+
+    /// Blocking version of [`Rds::download_db_log_file_portion`].
+    pub fn download_db_log_file_portion(
+        &self,
+        input: DownloadDBLogFilePortionMessage,
+    ) -> Result<DownloadDBLogFilePortionDetails, RusotoError<DownloadDBLogFilePortionError>> {
+        self.runtime
+            .block_on(self.client.download_db_log_file_portion(input))
+    }
+
+    /// Blocking version of [`Rds::failover_db_cluster`].
+    pub fn failover_db_cluster(
+        &self,
+        input: FailoverDBClusterMessage,
+    ) -> Result<FailoverDBClusterResult, RusotoError<FailoverDBClusterError>> {
+        self.runtime
+            .block_on(self.client.failover_db_cluster(input))
+    }
+
+    /// Blocking version of [`Rds::import_installation_media`].
+    pub fn import_installation_media(
+        &self,
+        input: ImportInstallationMediaMessage,
+    ) -> Result<InstallationMedia, RusotoError<ImportInstallationMediaError>> {
+        self.runtime
+            .block_on(self.client.import_installation_media(input))
+    }
+
+    /// Blocking version of [`Rds::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceMessage,
+    ) -> Result<TagListMessage, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`Rds::modify_certificates`].
+    pub fn modify_certificates(
+        &self,
+        input: ModifyCertificatesMessage,
+    ) -> Result<ModifyCertificatesResult, RusotoError<ModifyCertificatesError>> {
+        self.runtime
+            .block_on(self.client.modify_certificates(input))
+    }
+
+    /// Blocking version of [`Rds::modify_current_db_cluster_capacity`].
+    pub fn modify_current_db_cluster_capacity(
+        &self,
+        input: ModifyCurrentDBClusterCapacityMessage,
+    ) -> Result<DBClusterCapacityInfo, RusotoError<ModifyCurrentDBClusterCapacityError>> {
+        self.runtime
+            .block_on(self.client.modify_current_db_cluster_capacity(input))
+    }
+
+    /// Blocking version of [`Rds::modify_db_cluster`].
+    pub fn modify_db_cluster(
+        &self,
+        input: ModifyDBClusterMessage,
+    ) -> Result<ModifyDBClusterResult, RusotoError<ModifyDBClusterError>> {
+        self.runtime.block_on(self.client.modify_db_cluster(input))
+    }
+
+    /// Blocking version of [`Rds::modify_db_cluster_endpoint`].
+    pub fn modify_db_cluster_endpoint(
+        &self,
+        input: ModifyDBClusterEndpointMessage,
+    ) -> Result<DBClusterEndpoint, RusotoError<ModifyDBClusterEndpointError>> {
+        self.runtime
+            .block_on(self.client.modify_db_cluster_endpoint(input))
+    }
+
+    /// Blocking version of [`Rds::modify_db_cluster_parameter_group`].
+    pub fn modify_db_cluster_parameter_group(
+        &self,
+        input: ModifyDBClusterParameterGroupMessage,
+    ) -> Result<DBClusterParameterGroupNameMessage, RusotoError<ModifyDBClusterParameterGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.modify_db_cluster_parameter_group(input))
+    }
+
+    /// Blocking version of [`Rds::modify_db_cluster_snapshot_attribute`].
+    pub fn modify_db_cluster_snapshot_attribute(
+        &self,
+        input: ModifyDBClusterSnapshotAttributeMessage,
+    ) -> Result<
+        ModifyDBClusterSnapshotAttributeResult,
+        RusotoError<ModifyDBClusterSnapshotAttributeError>,
+    > {
+        self.runtime
+            .block_on(self.client.modify_db_cluster_snapshot_attribute(input))
+    }
+
+    /// Blocking version of [`Rds::modify_db_instance`].
+    pub fn modify_db_instance(
+        &self,
+        input: ModifyDBInstanceMessage,
+    ) -> Result<ModifyDBInstanceResult, RusotoError<ModifyDBInstanceError>> {
+        self.runtime.block_on(self.client.modify_db_instance(input))
+    }
+
+    /// Blocking version of [`Rds::modify_db_parameter_group`].
+    pub fn modify_db_parameter_group(
+        &self,
+        input: ModifyDBParameterGroupMessage,
+    ) -> Result<DBParameterGroupNameMessage, RusotoError<ModifyDBParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.modify_db_parameter_group(input))
+    }
+
+    /// Blocking version of [`Rds::modify_db_proxy`].
+    pub fn modify_db_proxy(
+        &self,
+        input: ModifyDBProxyRequest,
+    ) -> Result<ModifyDBProxyResponse, RusotoError<ModifyDBProxyError>> {
+        self.runtime.block_on(self.client.modify_db_proxy(input))
+    }
+
+    /// Blocking version of [`Rds::modify_db_proxy_target_group`].
+    pub fn modify_db_proxy_target_group(
+        &self,
+        input: ModifyDBProxyTargetGroupRequest,
+    ) -> Result<ModifyDBProxyTargetGroupResponse, RusotoError<ModifyDBProxyTargetGroupError>> {
+        self.runtime
+            .block_on(self.client.modify_db_proxy_target_group(input))
+    }
+
+    /// Blocking version of [`Rds::modify_db_snapshot`].
+    pub fn modify_db_snapshot(
+        &self,
+        input: ModifyDBSnapshotMessage,
+    ) -> Result<ModifyDBSnapshotResult, RusotoError<ModifyDBSnapshotError>> {
+        self.runtime.block_on(self.client.modify_db_snapshot(input))
+    }
+
+    /// Blocking version of [`Rds::modify_db_snapshot_attribute`].
+    pub fn modify_db_snapshot_attribute(
+        &self,
+        input: ModifyDBSnapshotAttributeMessage,
+    ) -> Result<ModifyDBSnapshotAttributeResult, RusotoError<ModifyDBSnapshotAttributeError>> {
+        self.runtime
+            .block_on(self.client.modify_db_snapshot_attribute(input))
+    }
+
+    /// Blocking version of [`Rds::modify_db_subnet_group`].
+    pub fn modify_db_subnet_group(
+        &self,
+        input: ModifyDBSubnetGroupMessage,
+    ) -> Result<ModifyDBSubnetGroupResult, RusotoError<ModifyDBSubnetGroupError>> {
+        self.runtime
+            .block_on(self.client.modify_db_subnet_group(input))
+    }
+
+    /// Blocking version of [`Rds::modify_event_subscription`].
+    pub fn modify_event_subscription(
+        &self,
+        input: ModifyEventSubscriptionMessage,
+    ) -> Result<ModifyEventSubscriptionResult, RusotoError<ModifyEventSubscriptionError>> {
+        self.runtime
+            .block_on(self.client.modify_event_subscription(input))
+    }
+
+    /// Blocking version of [`Rds::modify_global_cluster`].
+    pub fn modify_global_cluster(
+        &self,
+        input: ModifyGlobalClusterMessage,
+    ) -> Result<ModifyGlobalClusterResult, RusotoError<ModifyGlobalClusterError>> {
+        self.runtime
+            .block_on(self.client.modify_global_cluster(input))
+    }
+
+    /// Blocking version of [`Rds::modify_option_group`].
+    pub fn modify_option_group(
+        &self,
+        input: ModifyOptionGroupMessage,
+    ) -> Result<ModifyOptionGroupResult, RusotoError<ModifyOptionGroupError>> {
+        self.runtime
+            .block_on(self.client.modify_option_group(input))
+    }
+
+    /// Blocking version of [`Rds::promote_read_replica`].
+    pub fn promote_read_replica(
+        &self,
+        input: PromoteReadReplicaMessage,
+    ) -> Result<PromoteReadReplicaResult, RusotoError<PromoteReadReplicaError>> {
+        self.runtime
+            .block_on(self.client.promote_read_replica(input))
+    }
+
+    /// Blocking version of [`Rds::promote_read_replica_db_cluster`].
+    pub fn promote_read_replica_db_cluster(
+        &self,
+        input: PromoteReadReplicaDBClusterMessage,
+    ) -> Result<PromoteReadReplicaDBClusterResult, RusotoError<PromoteReadReplicaDBClusterError>>
+    {
+        self.runtime
+            .block_on(self.client.promote_read_replica_db_cluster(input))
+    }
+
+    /// Blocking version of [`Rds::purchase_reserved_db_instances_offering`].
+    pub fn purchase_reserved_db_instances_offering(
+        &self,
+        input: PurchaseReservedDBInstancesOfferingMessage,
+    ) -> Result<
+        PurchaseReservedDBInstancesOfferingResult,
+        RusotoError<PurchaseReservedDBInstancesOfferingError>,
+    > {
+        self.runtime
+            .block_on(self.client.purchase_reserved_db_instances_offering(input))
+    }
+
+    /// Blocking version of [`Rds::reboot_db_instance`].
+    pub fn reboot_db_instance(
+        &self,
+        input: RebootDBInstanceMessage,
+    ) -> Result<RebootDBInstanceResult, RusotoError<RebootDBInstanceError>> {
+        self.runtime.block_on(self.client.reboot_db_instance(input))
+    }
+
+    /// Blocking version of [`Rds::register_db_proxy_targets`].
+    pub fn register_db_proxy_targets(
+        &self,
+        input: RegisterDBProxyTargetsRequest,
+    ) -> Result<RegisterDBProxyTargetsResponse, RusotoError<RegisterDBProxyTargetsError>> {
+        self.runtime
+            .block_on(self.client.register_db_proxy_targets(input))
+    }
+
+    /// Blocking version of [`Rds::remove_from_global_cluster`].
+    pub fn remove_from_global_cluster(
+        &self,
+        input: RemoveFromGlobalClusterMessage,
+    ) -> Result<RemoveFromGlobalClusterResult, RusotoError<RemoveFromGlobalClusterError>> {
+        self.runtime
+            .block_on(self.client.remove_from_global_cluster(input))
+    }
+
+    /// Blocking version of [`Rds::remove_role_from_db_cluster`].
+    pub fn remove_role_from_db_cluster(
+        &self,
+        input: RemoveRoleFromDBClusterMessage,
+    ) -> Result<(), RusotoError<RemoveRoleFromDBClusterError>> {
+        self.runtime
+            .block_on(self.client.remove_role_from_db_cluster(input))
+    }
+
+    /// Blocking version of [`Rds::remove_role_from_db_instance`].
+    pub fn remove_role_from_db_instance(
+        &self,
+        input: RemoveRoleFromDBInstanceMessage,
+    ) -> Result<(), RusotoError<RemoveRoleFromDBInstanceError>> {
+        self.runtime
+            .block_on(self.client.remove_role_from_db_instance(input))
+    }
+
+    /// Blocking version of [`Rds::remove_source_identifier_from_subscription`].
+    pub fn remove_source_identifier_from_subscription(
+        &self,
+        input: RemoveSourceIdentifierFromSubscriptionMessage,
+    ) -> Result<
+        RemoveSourceIdentifierFromSubscriptionResult,
+        RusotoError<RemoveSourceIdentifierFromSubscriptionError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .remove_source_identifier_from_subscription(input),
+        )
+    }
+
+    /// Blocking version of [`Rds::remove_tags_from_resource`].
+    pub fn remove_tags_from_resource(
+        &self,
+        input: RemoveTagsFromResourceMessage,
+    ) -> Result<(), RusotoError<RemoveTagsFromResourceError>> {
+        self.runtime
+            .block_on(self.client.remove_tags_from_resource(input))
+    }
+
+    /// Blocking version of [`Rds::reset_db_cluster_parameter_group`].
+    pub fn reset_db_cluster_parameter_group(
+        &self,
+        input: ResetDBClusterParameterGroupMessage,
+    ) -> Result<DBClusterParameterGroupNameMessage, RusotoError<ResetDBClusterParameterGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.reset_db_cluster_parameter_group(input))
+    }
+
+    /// Blocking version of [`Rds::reset_db_parameter_group`].
+    pub fn reset_db_parameter_group(
+        &self,
+        input: ResetDBParameterGroupMessage,
+    ) -> Result<DBParameterGroupNameMessage, RusotoError<ResetDBParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.reset_db_parameter_group(input))
+    }
+
+    /// Blocking version of [`Rds::restore_db_cluster_from_s3`].
+    pub fn restore_db_cluster_from_s3(
+        &self,
+        input: RestoreDBClusterFromS3Message,
+    ) -> Result<RestoreDBClusterFromS3Result, RusotoError<RestoreDBClusterFromS3Error>> {
+        self.runtime
+            .block_on(self.client.restore_db_cluster_from_s3(input))
+    }
+
+    /// Blocking version of [`Rds::restore_db_cluster_from_snapshot`].
+    pub fn restore_db_cluster_from_snapshot(
+        &self,
+        input: RestoreDBClusterFromSnapshotMessage,
+    ) -> Result<RestoreDBClusterFromSnapshotResult, RusotoError<RestoreDBClusterFromSnapshotError>>
+    {
+        self.runtime
+            .block_on(self.client.restore_db_cluster_from_snapshot(input))
+    }
+
+    /// Blocking version of [`Rds::restore_db_cluster_to_point_in_time`].
+    pub fn restore_db_cluster_to_point_in_time(
+        &self,
+        input: RestoreDBClusterToPointInTimeMessage,
+    ) -> Result<RestoreDBClusterToPointInTimeResult, RusotoError<RestoreDBClusterToPointInTimeError>>
+    {
+        self.runtime
+            .block_on(self.client.restore_db_cluster_to_point_in_time(input))
+    }
+
+    /// Blocking version of [`Rds::restore_db_instance_from_db_snapshot`].
+    pub fn restore_db_instance_from_db_snapshot(
+        &self,
+        input: RestoreDBInstanceFromDBSnapshotMessage,
+    ) -> Result<
+        RestoreDBInstanceFromDBSnapshotResult,
+        RusotoError<RestoreDBInstanceFromDBSnapshotError>,
+    > {
+        self.runtime
+            .block_on(self.client.restore_db_instance_from_db_snapshot(input))
+    }
+
+    /// Blocking version of [`Rds::restore_db_instance_from_s3`].
+    pub fn restore_db_instance_from_s3(
+        &self,
+        input: RestoreDBInstanceFromS3Message,
+    ) -> Result<RestoreDBInstanceFromS3Result, RusotoError<RestoreDBInstanceFromS3Error>> {
+        self.runtime
+            .block_on(self.client.restore_db_instance_from_s3(input))
+    }
+
+    /// Blocking version of [`Rds::restore_db_instance_to_point_in_time`].
+    pub fn restore_db_instance_to_point_in_time(
+        &self,
+        input: RestoreDBInstanceToPointInTimeMessage,
+    ) -> Result<
+        RestoreDBInstanceToPointInTimeResult,
+        RusotoError<RestoreDBInstanceToPointInTimeError>,
+    > {
+        self.runtime
+            .block_on(self.client.restore_db_instance_to_point_in_time(input))
+    }
+
+    /// Blocking version of [`Rds::revoke_db_security_group_ingress`].
+    pub fn revoke_db_security_group_ingress(
+        &self,
+        input: RevokeDBSecurityGroupIngressMessage,
+    ) -> Result<RevokeDBSecurityGroupIngressResult, RusotoError<RevokeDBSecurityGroupIngressError>>
+    {
+        self.runtime
+            .block_on(self.client.revoke_db_security_group_ingress(input))
+    }
+
+    /// Blocking version of [`Rds::start_activity_stream`].
+    pub fn start_activity_stream(
+        &self,
+        input: StartActivityStreamRequest,
+    ) -> Result<StartActivityStreamResponse, RusotoError<StartActivityStreamError>> {
+        self.runtime
+            .block_on(self.client.start_activity_stream(input))
+    }
+
+    /// Blocking version of [`Rds::start_db_cluster`].
+    pub fn start_db_cluster(
+        &self,
+        input: StartDBClusterMessage,
+    ) -> Result<StartDBClusterResult, RusotoError<StartDBClusterError>> {
+        self.runtime.block_on(self.client.start_db_cluster(input))
+    }
+
+    /// Blocking version of [`Rds::start_db_instance`].
+    pub fn start_db_instance(
+        &self,
+        input: StartDBInstanceMessage,
+    ) -> Result<StartDBInstanceResult, RusotoError<StartDBInstanceError>> {
+        self.runtime.block_on(self.client.start_db_instance(input))
+    }
+
+    /// Blocking version of [`Rds::start_db_instance_automated_backups_replication`].
+    pub fn start_db_instance_automated_backups_replication(
+        &self,
+        input: StartDBInstanceAutomatedBackupsReplicationMessage,
+    ) -> Result<
+        StartDBInstanceAutomatedBackupsReplicationResult,
+        RusotoError<StartDBInstanceAutomatedBackupsReplicationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .start_db_instance_automated_backups_replication(input),
+        )
+    }
+
+    /// Blocking version of [`Rds::start_export_task`].
+    pub fn start_export_task(
+        &self,
+        input: StartExportTaskMessage,
+    ) -> Result<ExportTask, RusotoError<StartExportTaskError>> {
+        self.runtime.block_on(self.client.start_export_task(input))
+    }
+
+    /// Blocking version of [`Rds::stop_activity_stream`].
+    pub fn stop_activity_stream(
+        &self,
+        input: StopActivityStreamRequest,
+    ) -> Result<StopActivityStreamResponse, RusotoError<StopActivityStreamError>> {
+        self.runtime
+            .block_on(self.client.stop_activity_stream(input))
+    }
+
+    /// Blocking version of [`Rds::stop_db_cluster`].
+    pub fn stop_db_cluster(
+        &self,
+        input: StopDBClusterMessage,
+    ) -> Result<StopDBClusterResult, RusotoError<StopDBClusterError>> {
+        self.runtime.block_on(self.client.stop_db_cluster(input))
+    }
+
+    /// Blocking version of [`Rds::stop_db_instance`].
+    pub fn stop_db_instance(
+        &self,
+        input: StopDBInstanceMessage,
+    ) -> Result<StopDBInstanceResult, RusotoError<StopDBInstanceError>> {
+        self.runtime.block_on(self.client.stop_db_instance(input))
+    }
+
+    /// Blocking version of [`Rds::stop_db_instance_automated_backups_replication`].
+    pub fn stop_db_instance_automated_backups_replication(
+        &self,
+        input: StopDBInstanceAutomatedBackupsReplicationMessage,
+    ) -> Result<
+        StopDBInstanceAutomatedBackupsReplicationResult,
+        RusotoError<StopDBInstanceAutomatedBackupsReplicationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .stop_db_instance_automated_backups_replication(input),
+        )
+    }
+}
+
 #[cfg(test)]
 mod protocol_tests {
 
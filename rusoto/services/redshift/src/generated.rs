@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -11937,52 +11938,59 @@ impl AcceptReservedNodeExchangeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DependentServiceUnavailableFault" => {
-                        return RusotoError::Service(
-                            AcceptReservedNodeExchangeError::DependentServiceUnavailableFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AcceptReservedNodeExchangeError::DependentServiceUnavailableFault,
                         )
                     }
                     "InvalidReservedNodeState" => {
-                        return RusotoError::Service(
-                            AcceptReservedNodeExchangeError::InvalidReservedNodeStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AcceptReservedNodeExchangeError::InvalidReservedNodeStateFault,
                         )
                     }
                     "ReservedNodeAlreadyExists" => {
-                        return RusotoError::Service(
-                            AcceptReservedNodeExchangeError::ReservedNodeAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AcceptReservedNodeExchangeError::ReservedNodeAlreadyExistsFault,
                         )
                     }
                     "ReservedNodeAlreadyMigrated" => {
-                        return RusotoError::Service(
-                            AcceptReservedNodeExchangeError::ReservedNodeAlreadyMigratedFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AcceptReservedNodeExchangeError::ReservedNodeAlreadyMigratedFault,
                         )
                     }
                     "ReservedNodeNotFound" => {
-                        return RusotoError::Service(
-                            AcceptReservedNodeExchangeError::ReservedNodeNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AcceptReservedNodeExchangeError::ReservedNodeNotFoundFault,
                         )
                     }
                     "ReservedNodeOfferingNotFound" => {
-                        return RusotoError::Service(
-                            AcceptReservedNodeExchangeError::ReservedNodeOfferingNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AcceptReservedNodeExchangeError::ReservedNodeOfferingNotFoundFault,
                         )
                     }
                     "UnsupportedOperation" => {
-                        return RusotoError::Service(
-                            AcceptReservedNodeExchangeError::UnsupportedOperationFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AcceptReservedNodeExchangeError::UnsupportedOperationFault,
                         )
                     }
                     _ => {}
@@ -12052,7 +12060,7 @@ impl AuthorizeClusterSecurityGroupIngressError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AuthorizationAlreadyExists" => return RusotoError::Service(AuthorizeClusterSecurityGroupIngressError::AuthorizationAlreadyExistsFault(parsed_error.message)),"AuthorizationQuotaExceeded" => return RusotoError::Service(AuthorizeClusterSecurityGroupIngressError::AuthorizationQuotaExceededFault(parsed_error.message)),"ClusterSecurityGroupNotFound" => return RusotoError::Service(AuthorizeClusterSecurityGroupIngressError::ClusterSecurityGroupNotFoundFault(parsed_error.message)),"InvalidClusterSecurityGroupState" => return RusotoError::Service(AuthorizeClusterSecurityGroupIngressError::InvalidClusterSecurityGroupStateFault(parsed_error.message)),_ => {}
+                                    "AuthorizationAlreadyExists" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, AuthorizeClusterSecurityGroupIngressError::AuthorizationAlreadyExistsFault),"AuthorizationQuotaExceeded" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, AuthorizeClusterSecurityGroupIngressError::AuthorizationQuotaExceededFault),"ClusterSecurityGroupNotFound" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, AuthorizeClusterSecurityGroupIngressError::ClusterSecurityGroupNotFoundFault),"InvalidClusterSecurityGroupState" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, AuthorizeClusterSecurityGroupIngressError::InvalidClusterSecurityGroupStateFault),_ => {}
                                 }
             }
         }
@@ -12113,43 +12121,51 @@ impl AuthorizeSnapshotAccessError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AuthorizationAlreadyExists" => {
-                        return RusotoError::Service(
-                            AuthorizeSnapshotAccessError::AuthorizationAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AuthorizeSnapshotAccessError::AuthorizationAlreadyExistsFault,
                         )
                     }
                     "AuthorizationQuotaExceeded" => {
-                        return RusotoError::Service(
-                            AuthorizeSnapshotAccessError::AuthorizationQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AuthorizeSnapshotAccessError::AuthorizationQuotaExceededFault,
                         )
                     }
                     "ClusterSnapshotNotFound" => {
-                        return RusotoError::Service(
-                            AuthorizeSnapshotAccessError::ClusterSnapshotNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AuthorizeSnapshotAccessError::ClusterSnapshotNotFoundFault,
                         )
                     }
                     "DependentServiceRequestThrottlingFault" => {
-                        return RusotoError::Service(
-                            AuthorizeSnapshotAccessError::DependentServiceRequestThrottlingFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AuthorizeSnapshotAccessError::DependentServiceRequestThrottlingFault,
                         )
                     }
                     "InvalidClusterSnapshotState" => {
-                        return RusotoError::Service(
-                            AuthorizeSnapshotAccessError::InvalidClusterSnapshotStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AuthorizeSnapshotAccessError::InvalidClusterSnapshotStateFault,
                         )
                     }
                     "LimitExceededFault" => {
-                        return RusotoError::Service(
-                            AuthorizeSnapshotAccessError::LimitExceededFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AuthorizeSnapshotAccessError::LimitExceededFault,
                         )
                     }
                     _ => {}
@@ -12209,10 +12225,11 @@ impl BatchDeleteClusterSnapshotsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BatchDeleteRequestSizeExceeded" => {
-                        return RusotoError::Service(
-                            BatchDeleteClusterSnapshotsError::BatchDeleteRequestSizeExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            BatchDeleteClusterSnapshotsError::BatchDeleteRequestSizeExceededFault,
                         )
                     }
                     _ => {}
@@ -12260,7 +12277,7 @@ impl BatchModifyClusterSnapshotsError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "BatchModifyClusterSnapshotsLimitExceededFault" => return RusotoError::Service(BatchModifyClusterSnapshotsError::BatchModifyClusterSnapshotsLimitExceededFault(parsed_error.message)),"InvalidRetentionPeriodFault" => return RusotoError::Service(BatchModifyClusterSnapshotsError::InvalidRetentionPeriodFault(parsed_error.message)),_ => {}
+                                    "BatchModifyClusterSnapshotsLimitExceededFault" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, BatchModifyClusterSnapshotsError::BatchModifyClusterSnapshotsLimitExceededFault),"InvalidRetentionPeriodFault" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, BatchModifyClusterSnapshotsError::InvalidRetentionPeriodFault),_ => {}
                                 }
             }
         }
@@ -12311,24 +12328,36 @@ impl CancelResizeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(CancelResizeError::ClusterNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CancelResizeError::ClusterNotFoundFault,
+                        )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(CancelResizeError::InvalidClusterStateFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CancelResizeError::InvalidClusterStateFault,
+                        )
                     }
                     "ResizeNotFound" => {
-                        return RusotoError::Service(CancelResizeError::ResizeNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CancelResizeError::ResizeNotFoundFault,
+                        )
                     }
                     "UnsupportedOperation" => {
-                        return RusotoError::Service(CancelResizeError::UnsupportedOperationFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CancelResizeError::UnsupportedOperationFault,
+                        )
                     }
                     _ => {}
                 }
@@ -12381,38 +12410,43 @@ impl CopyClusterSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterSnapshotAlreadyExists" => {
-                        return RusotoError::Service(
-                            CopyClusterSnapshotError::ClusterSnapshotAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyClusterSnapshotError::ClusterSnapshotAlreadyExistsFault,
                         )
                     }
                     "ClusterSnapshotNotFound" => {
-                        return RusotoError::Service(
-                            CopyClusterSnapshotError::ClusterSnapshotNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyClusterSnapshotError::ClusterSnapshotNotFoundFault,
                         )
                     }
                     "ClusterSnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CopyClusterSnapshotError::ClusterSnapshotQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyClusterSnapshotError::ClusterSnapshotQuotaExceededFault,
                         )
                     }
                     "InvalidClusterSnapshotState" => {
-                        return RusotoError::Service(
-                            CopyClusterSnapshotError::InvalidClusterSnapshotStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyClusterSnapshotError::InvalidClusterSnapshotStateFault,
                         )
                     }
                     "InvalidRetentionPeriodFault" => {
-                        return RusotoError::Service(
-                            CopyClusterSnapshotError::InvalidRetentionPeriodFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CopyClusterSnapshotError::InvalidRetentionPeriodFault,
                         )
                     }
                     _ => {}
@@ -12511,132 +12545,180 @@ impl CreateClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterAlreadyExists" => {
-                        return RusotoError::Service(CreateClusterError::ClusterAlreadyExistsFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateClusterError::ClusterAlreadyExistsFault,
+                        )
                     }
                     "ClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            CreateClusterError::ClusterParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateClusterError::ClusterParameterGroupNotFoundFault,
                         )
                     }
                     "ClusterQuotaExceeded" => {
-                        return RusotoError::Service(CreateClusterError::ClusterQuotaExceededFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateClusterError::ClusterQuotaExceededFault,
+                        )
                     }
                     "ClusterSecurityGroupNotFound" => {
-                        return RusotoError::Service(
-                            CreateClusterError::ClusterSecurityGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateClusterError::ClusterSecurityGroupNotFoundFault,
                         )
                     }
                     "ClusterSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            CreateClusterError::ClusterSubnetGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateClusterError::ClusterSubnetGroupNotFoundFault,
                         )
                     }
                     "DependentServiceRequestThrottlingFault" => {
-                        return RusotoError::Service(
-                            CreateClusterError::DependentServiceRequestThrottlingFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateClusterError::DependentServiceRequestThrottlingFault,
                         )
                     }
                     "HsmClientCertificateNotFoundFault" => {
-                        return RusotoError::Service(
-                            CreateClusterError::HsmClientCertificateNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateClusterError::HsmClientCertificateNotFoundFault,
                         )
                     }
                     "HsmConfigurationNotFoundFault" => {
-                        return RusotoError::Service(
-                            CreateClusterError::HsmConfigurationNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateClusterError::HsmConfigurationNotFoundFault,
                         )
                     }
                     "InsufficientClusterCapacity" => {
-                        return RusotoError::Service(
-                            CreateClusterError::InsufficientClusterCapacityFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateClusterError::InsufficientClusterCapacityFault,
                         )
                     }
                     "InvalidClusterSubnetGroupStateFault" => {
-                        return RusotoError::Service(
-                            CreateClusterError::InvalidClusterSubnetGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateClusterError::InvalidClusterSubnetGroupStateFault,
                         )
                     }
                     "InvalidClusterTrack" => {
-                        return RusotoError::Service(CreateClusterError::InvalidClusterTrackFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateClusterError::InvalidClusterTrackFault,
+                        )
                     }
                     "InvalidElasticIpFault" => {
-                        return RusotoError::Service(CreateClusterError::InvalidElasticIpFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateClusterError::InvalidElasticIpFault,
+                        )
                     }
                     "InvalidRetentionPeriodFault" => {
-                        return RusotoError::Service(
-                            CreateClusterError::InvalidRetentionPeriodFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateClusterError::InvalidRetentionPeriodFault,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateClusterError::InvalidSubnet(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateClusterError::InvalidSubnet,
+                        )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(CreateClusterError::InvalidTagFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateClusterError::InvalidTagFault,
+                        )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
-                            CreateClusterError::InvalidVPCNetworkStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateClusterError::InvalidVPCNetworkStateFault,
                         )
                     }
                     "LimitExceededFault" => {
-                        return RusotoError::Service(CreateClusterError::LimitExceededFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateClusterError::LimitExceededFault,
+                        )
                     }
                     "NumberOfNodesPerClusterLimitExceeded" => {
-                        return RusotoError::Service(
-                            CreateClusterError::NumberOfNodesPerClusterLimitExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateClusterError::NumberOfNodesPerClusterLimitExceededFault,
                         )
                     }
                     "NumberOfNodesQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CreateClusterError::NumberOfNodesQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateClusterError::NumberOfNodesQuotaExceededFault,
                         )
                     }
                     "SnapshotScheduleNotFound" => {
-                        return RusotoError::Service(
-                            CreateClusterError::SnapshotScheduleNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateClusterError::SnapshotScheduleNotFoundFault,
                         )
                     }
                     "TagLimitExceededFault" => {
-                        return RusotoError::Service(CreateClusterError::TagLimitExceededFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateClusterError::TagLimitExceededFault,
+                        )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(CreateClusterError::UnauthorizedOperation(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateClusterError::UnauthorizedOperation,
+                        )
                     }
                     _ => {}
                 }
@@ -12724,26 +12806,32 @@ impl CreateClusterParameterGroupError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                    "ClusterParameterGroupAlreadyExists" => return RusotoError::Service(
-                        CreateClusterParameterGroupError::ClusterParameterGroupAlreadyExistsFault(
-                            parsed_error.message,
-                        ),
+                    "ClusterParameterGroupAlreadyExists" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        CreateClusterParameterGroupError::ClusterParameterGroupAlreadyExistsFault,
                     ),
-                    "ClusterParameterGroupQuotaExceeded" => return RusotoError::Service(
-                        CreateClusterParameterGroupError::ClusterParameterGroupQuotaExceededFault(
-                            parsed_error.message,
-                        ),
+                    "ClusterParameterGroupQuotaExceeded" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        CreateClusterParameterGroupError::ClusterParameterGroupQuotaExceededFault,
                     ),
                     "InvalidTagFault" => {
-                        return RusotoError::Service(
-                            CreateClusterParameterGroupError::InvalidTagFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateClusterParameterGroupError::InvalidTagFault,
                         )
                     }
                     "TagLimitExceededFault" => {
-                        return RusotoError::Service(
-                            CreateClusterParameterGroupError::TagLimitExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateClusterParameterGroupError::TagLimitExceededFault,
                         )
                     }
                     _ => {}
@@ -12803,29 +12891,35 @@ impl CreateClusterSecurityGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterSecurityGroupAlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateClusterSecurityGroupError::ClusterSecurityGroupAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateClusterSecurityGroupError::ClusterSecurityGroupAlreadyExistsFault,
                         )
                     }
                     "QuotaExceeded.ClusterSecurityGroup" => {
-                        return RusotoError::Service(
-                            CreateClusterSecurityGroupError::ClusterSecurityGroupQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateClusterSecurityGroupError::ClusterSecurityGroupQuotaExceededFault,
                         )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(
-                            CreateClusterSecurityGroupError::InvalidTagFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateClusterSecurityGroupError::InvalidTagFault,
                         )
                     }
                     "TagLimitExceededFault" => {
-                        return RusotoError::Service(
-                            CreateClusterSecurityGroupError::TagLimitExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateClusterSecurityGroupError::TagLimitExceededFault,
                         )
                     }
                     _ => {}
@@ -12889,46 +12983,59 @@ impl CreateClusterSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
-                            CreateClusterSnapshotError::ClusterNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateClusterSnapshotError::ClusterNotFoundFault,
                         )
                     }
                     "ClusterSnapshotAlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateClusterSnapshotError::ClusterSnapshotAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateClusterSnapshotError::ClusterSnapshotAlreadyExistsFault,
                         )
                     }
                     "ClusterSnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CreateClusterSnapshotError::ClusterSnapshotQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateClusterSnapshotError::ClusterSnapshotQuotaExceededFault,
                         )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(
-                            CreateClusterSnapshotError::InvalidClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateClusterSnapshotError::InvalidClusterStateFault,
                         )
                     }
                     "InvalidRetentionPeriodFault" => {
-                        return RusotoError::Service(
-                            CreateClusterSnapshotError::InvalidRetentionPeriodFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateClusterSnapshotError::InvalidRetentionPeriodFault,
                         )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(CreateClusterSnapshotError::InvalidTagFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateClusterSnapshotError::InvalidTagFault,
+                        )
                     }
                     "TagLimitExceededFault" => {
-                        return RusotoError::Service(
-                            CreateClusterSnapshotError::TagLimitExceededFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateClusterSnapshotError::TagLimitExceededFault,
                         )
                     }
                     _ => {}
@@ -12999,55 +13106,67 @@ impl CreateClusterSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterSubnetGroupAlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateClusterSubnetGroupError::ClusterSubnetGroupAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateClusterSubnetGroupError::ClusterSubnetGroupAlreadyExistsFault,
                         )
                     }
                     "ClusterSubnetGroupQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CreateClusterSubnetGroupError::ClusterSubnetGroupQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateClusterSubnetGroupError::ClusterSubnetGroupQuotaExceededFault,
                         )
                     }
                     "ClusterSubnetQuotaExceededFault" => {
-                        return RusotoError::Service(
-                            CreateClusterSubnetGroupError::ClusterSubnetQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateClusterSubnetGroupError::ClusterSubnetQuotaExceededFault,
                         )
                     }
                     "DependentServiceRequestThrottlingFault" => {
-                        return RusotoError::Service(
-                            CreateClusterSubnetGroupError::DependentServiceRequestThrottlingFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateClusterSubnetGroupError::DependentServiceRequestThrottlingFault,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(CreateClusterSubnetGroupError::InvalidSubnet(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateClusterSubnetGroupError::InvalidSubnet,
+                        )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(
-                            CreateClusterSubnetGroupError::InvalidTagFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateClusterSubnetGroupError::InvalidTagFault,
                         )
                     }
                     "TagLimitExceededFault" => {
-                        return RusotoError::Service(
-                            CreateClusterSubnetGroupError::TagLimitExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateClusterSubnetGroupError::TagLimitExceededFault,
                         )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(
-                            CreateClusterSubnetGroupError::UnauthorizedOperation(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateClusterSubnetGroupError::UnauthorizedOperation,
                         )
                     }
                     _ => {}
@@ -13129,76 +13248,91 @@ impl CreateEventSubscriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "EventSubscriptionQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CreateEventSubscriptionError::EventSubscriptionQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateEventSubscriptionError::EventSubscriptionQuotaExceededFault,
                         )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(CreateEventSubscriptionError::InvalidTagFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateEventSubscriptionError::InvalidTagFault,
+                        )
                     }
                     "SNSInvalidTopic" => {
-                        return RusotoError::Service(
-                            CreateEventSubscriptionError::SNSInvalidTopicFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateEventSubscriptionError::SNSInvalidTopicFault,
                         )
                     }
                     "SNSNoAuthorization" => {
-                        return RusotoError::Service(
-                            CreateEventSubscriptionError::SNSNoAuthorizationFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateEventSubscriptionError::SNSNoAuthorizationFault,
                         )
                     }
                     "SNSTopicArnNotFound" => {
-                        return RusotoError::Service(
-                            CreateEventSubscriptionError::SNSTopicArnNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateEventSubscriptionError::SNSTopicArnNotFoundFault,
                         )
                     }
                     "SourceNotFound" => {
-                        return RusotoError::Service(
-                            CreateEventSubscriptionError::SourceNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateEventSubscriptionError::SourceNotFoundFault,
                         )
                     }
                     "SubscriptionAlreadyExist" => {
-                        return RusotoError::Service(
-                            CreateEventSubscriptionError::SubscriptionAlreadyExistFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateEventSubscriptionError::SubscriptionAlreadyExistFault,
                         )
                     }
                     "SubscriptionCategoryNotFound" => {
-                        return RusotoError::Service(
-                            CreateEventSubscriptionError::SubscriptionCategoryNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateEventSubscriptionError::SubscriptionCategoryNotFoundFault,
                         )
                     }
                     "SubscriptionEventIdNotFound" => {
-                        return RusotoError::Service(
-                            CreateEventSubscriptionError::SubscriptionEventIdNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateEventSubscriptionError::SubscriptionEventIdNotFoundFault,
                         )
                     }
                     "SubscriptionSeverityNotFound" => {
-                        return RusotoError::Service(
-                            CreateEventSubscriptionError::SubscriptionSeverityNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateEventSubscriptionError::SubscriptionSeverityNotFoundFault,
                         )
                     }
                     "TagLimitExceededFault" => {
-                        return RusotoError::Service(
-                            CreateEventSubscriptionError::TagLimitExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateEventSubscriptionError::TagLimitExceededFault,
                         )
                     }
                     _ => {}
@@ -13275,29 +13409,35 @@ impl CreateHsmClientCertificateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "HsmClientCertificateAlreadyExistsFault" => {
-                        return RusotoError::Service(
-                            CreateHsmClientCertificateError::HsmClientCertificateAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateHsmClientCertificateError::HsmClientCertificateAlreadyExistsFault,
                         )
                     }
                     "HsmClientCertificateQuotaExceededFault" => {
-                        return RusotoError::Service(
-                            CreateHsmClientCertificateError::HsmClientCertificateQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateHsmClientCertificateError::HsmClientCertificateQuotaExceededFault,
                         )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(
-                            CreateHsmClientCertificateError::InvalidTagFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateHsmClientCertificateError::InvalidTagFault,
                         )
                     }
                     "TagLimitExceededFault" => {
-                        return RusotoError::Service(
-                            CreateHsmClientCertificateError::TagLimitExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateHsmClientCertificateError::TagLimitExceededFault,
                         )
                     }
                     _ => {}
@@ -13355,29 +13495,35 @@ impl CreateHsmConfigurationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "HsmConfigurationAlreadyExistsFault" => {
-                        return RusotoError::Service(
-                            CreateHsmConfigurationError::HsmConfigurationAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateHsmConfigurationError::HsmConfigurationAlreadyExistsFault,
                         )
                     }
                     "HsmConfigurationQuotaExceededFault" => {
-                        return RusotoError::Service(
-                            CreateHsmConfigurationError::HsmConfigurationQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateHsmConfigurationError::HsmConfigurationQuotaExceededFault,
                         )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(CreateHsmConfigurationError::InvalidTagFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateHsmConfigurationError::InvalidTagFault,
+                        )
                     }
                     "TagLimitExceededFault" => {
-                        return RusotoError::Service(
-                            CreateHsmConfigurationError::TagLimitExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateHsmConfigurationError::TagLimitExceededFault,
                         )
                     }
                     _ => {}
@@ -13437,41 +13583,51 @@ impl CreateScheduledActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidSchedule" => {
-                        return RusotoError::Service(
-                            CreateScheduledActionError::InvalidScheduleFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateScheduledActionError::InvalidScheduleFault,
                         )
                     }
                     "InvalidScheduledAction" => {
-                        return RusotoError::Service(
-                            CreateScheduledActionError::InvalidScheduledActionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateScheduledActionError::InvalidScheduledActionFault,
                         )
                     }
                     "ScheduledActionAlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateScheduledActionError::ScheduledActionAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateScheduledActionError::ScheduledActionAlreadyExistsFault,
                         )
                     }
                     "ScheduledActionQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CreateScheduledActionError::ScheduledActionQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateScheduledActionError::ScheduledActionQuotaExceededFault,
                         )
                     }
                     "ScheduledActionTypeUnsupported" => {
-                        return RusotoError::Service(
-                            CreateScheduledActionError::ScheduledActionTypeUnsupportedFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateScheduledActionError::ScheduledActionTypeUnsupportedFault,
                         )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(
-                            CreateScheduledActionError::UnauthorizedOperation(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateScheduledActionError::UnauthorizedOperation,
                         )
                     }
                     _ => {}
@@ -13537,41 +13693,51 @@ impl CreateSnapshotCopyGrantError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DependentServiceRequestThrottlingFault" => {
-                        return RusotoError::Service(
-                            CreateSnapshotCopyGrantError::DependentServiceRequestThrottlingFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateSnapshotCopyGrantError::DependentServiceRequestThrottlingFault,
                         )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(CreateSnapshotCopyGrantError::InvalidTagFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateSnapshotCopyGrantError::InvalidTagFault,
+                        )
                     }
                     "LimitExceededFault" => {
-                        return RusotoError::Service(
-                            CreateSnapshotCopyGrantError::LimitExceededFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateSnapshotCopyGrantError::LimitExceededFault,
                         )
                     }
                     "SnapshotCopyGrantAlreadyExistsFault" => {
-                        return RusotoError::Service(
-                            CreateSnapshotCopyGrantError::SnapshotCopyGrantAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateSnapshotCopyGrantError::SnapshotCopyGrantAlreadyExistsFault,
                         )
                     }
                     "SnapshotCopyGrantQuotaExceededFault" => {
-                        return RusotoError::Service(
-                            CreateSnapshotCopyGrantError::SnapshotCopyGrantQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateSnapshotCopyGrantError::SnapshotCopyGrantQuotaExceededFault,
                         )
                     }
                     "TagLimitExceededFault" => {
-                        return RusotoError::Service(
-                            CreateSnapshotCopyGrantError::TagLimitExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateSnapshotCopyGrantError::TagLimitExceededFault,
                         )
                     }
                     _ => {}
@@ -13637,41 +13803,51 @@ impl CreateSnapshotScheduleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidSchedule" => {
-                        return RusotoError::Service(
-                            CreateSnapshotScheduleError::InvalidScheduleFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateSnapshotScheduleError::InvalidScheduleFault,
                         )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(CreateSnapshotScheduleError::InvalidTagFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateSnapshotScheduleError::InvalidTagFault,
+                        )
                     }
                     "ScheduleDefinitionTypeUnsupported" => {
-                        return RusotoError::Service(
-                            CreateSnapshotScheduleError::ScheduleDefinitionTypeUnsupportedFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateSnapshotScheduleError::ScheduleDefinitionTypeUnsupportedFault,
                         )
                     }
                     "SnapshotScheduleAlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateSnapshotScheduleError::SnapshotScheduleAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateSnapshotScheduleError::SnapshotScheduleAlreadyExistsFault,
                         )
                     }
                     "SnapshotScheduleQuotaExceeded" => {
-                        return RusotoError::Service(
-                            CreateSnapshotScheduleError::SnapshotScheduleQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateSnapshotScheduleError::SnapshotScheduleQuotaExceededFault,
                         )
                     }
                     "TagLimitExceededFault" => {
-                        return RusotoError::Service(
-                            CreateSnapshotScheduleError::TagLimitExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateSnapshotScheduleError::TagLimitExceededFault,
                         )
                     }
                     _ => {}
@@ -13731,24 +13907,36 @@ impl CreateTagsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidClusterState" => {
-                        return RusotoError::Service(CreateTagsError::InvalidClusterStateFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateTagsError::InvalidClusterStateFault,
+                        )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(CreateTagsError::InvalidTagFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateTagsError::InvalidTagFault,
+                        )
                     }
                     "ResourceNotFoundFault" => {
-                        return RusotoError::Service(CreateTagsError::ResourceNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateTagsError::ResourceNotFoundFault,
+                        )
                     }
                     "TagLimitExceededFault" => {
-                        return RusotoError::Service(CreateTagsError::TagLimitExceededFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateTagsError::TagLimitExceededFault,
+                        )
                     }
                     _ => {}
                 }
@@ -13805,40 +13993,59 @@ impl CreateUsageLimitError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(CreateUsageLimitError::ClusterNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateUsageLimitError::ClusterNotFoundFault,
+                        )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(
-                            CreateUsageLimitError::InvalidClusterStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateUsageLimitError::InvalidClusterStateFault,
                         )
                     }
                     "InvalidUsageLimit" => {
-                        return RusotoError::Service(CreateUsageLimitError::InvalidUsageLimitFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateUsageLimitError::InvalidUsageLimitFault,
+                        )
                     }
                     "LimitExceededFault" => {
-                        return RusotoError::Service(CreateUsageLimitError::LimitExceededFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateUsageLimitError::LimitExceededFault,
+                        )
                     }
                     "TagLimitExceededFault" => {
-                        return RusotoError::Service(CreateUsageLimitError::TagLimitExceededFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateUsageLimitError::TagLimitExceededFault,
+                        )
                     }
                     "UnsupportedOperation" => {
-                        return RusotoError::Service(
-                            CreateUsageLimitError::UnsupportedOperationFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateUsageLimitError::UnsupportedOperationFault,
                         )
                     }
                     "UsageLimitAlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateUsageLimitError::UsageLimitAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateUsageLimitError::UsageLimitAlreadyExistsFault,
                         )
                     }
                     _ => {}
@@ -13897,32 +14104,43 @@ impl DeleteClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(DeleteClusterError::ClusterNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteClusterError::ClusterNotFoundFault,
+                        )
                     }
                     "ClusterSnapshotAlreadyExists" => {
-                        return RusotoError::Service(
-                            DeleteClusterError::ClusterSnapshotAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteClusterError::ClusterSnapshotAlreadyExistsFault,
                         )
                     }
                     "ClusterSnapshotQuotaExceeded" => {
-                        return RusotoError::Service(
-                            DeleteClusterError::ClusterSnapshotQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteClusterError::ClusterSnapshotQuotaExceededFault,
                         )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(DeleteClusterError::InvalidClusterStateFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteClusterError::InvalidClusterStateFault,
+                        )
                     }
                     "InvalidRetentionPeriodFault" => {
-                        return RusotoError::Service(
-                            DeleteClusterError::InvalidRetentionPeriodFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteClusterError::InvalidRetentionPeriodFault,
                         )
                     }
                     _ => {}
@@ -13977,16 +14195,18 @@ impl DeleteClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            DeleteClusterParameterGroupError::ClusterParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteClusterParameterGroupError::ClusterParameterGroupNotFoundFault,
                         )
                     }
-                    "InvalidClusterParameterGroupState" => return RusotoError::Service(
-                        DeleteClusterParameterGroupError::InvalidClusterParameterGroupStateFault(
-                            parsed_error.message,
-                        ),
+                    "InvalidClusterParameterGroupState" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        DeleteClusterParameterGroupError::InvalidClusterParameterGroupStateFault,
                     ),
                     _ => {}
                 }
@@ -14037,17 +14257,19 @@ impl DeleteClusterSecurityGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterSecurityGroupNotFound" => {
-                        return RusotoError::Service(
-                            DeleteClusterSecurityGroupError::ClusterSecurityGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteClusterSecurityGroupError::ClusterSecurityGroupNotFoundFault,
                         )
                     }
                     "InvalidClusterSecurityGroupState" => {
-                        return RusotoError::Service(
-                            DeleteClusterSecurityGroupError::InvalidClusterSecurityGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteClusterSecurityGroupError::InvalidClusterSecurityGroupStateFault,
                         )
                     }
                     _ => {}
@@ -14097,17 +14319,19 @@ impl DeleteClusterSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterSnapshotNotFound" => {
-                        return RusotoError::Service(
-                            DeleteClusterSnapshotError::ClusterSnapshotNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteClusterSnapshotError::ClusterSnapshotNotFoundFault,
                         )
                     }
                     "InvalidClusterSnapshotState" => {
-                        return RusotoError::Service(
-                            DeleteClusterSnapshotError::InvalidClusterSnapshotStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteClusterSnapshotError::InvalidClusterSnapshotStateFault,
                         )
                     }
                     _ => {}
@@ -14159,24 +14383,27 @@ impl DeleteClusterSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            DeleteClusterSubnetGroupError::ClusterSubnetGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteClusterSubnetGroupError::ClusterSubnetGroupNotFoundFault,
                         )
                     }
                     "InvalidClusterSubnetGroupStateFault" => {
-                        return RusotoError::Service(
-                            DeleteClusterSubnetGroupError::InvalidClusterSubnetGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteClusterSubnetGroupError::InvalidClusterSubnetGroupStateFault,
                         )
                     }
                     "InvalidClusterSubnetStateFault" => {
-                        return RusotoError::Service(
-                            DeleteClusterSubnetGroupError::InvalidClusterSubnetStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteClusterSubnetGroupError::InvalidClusterSubnetStateFault,
                         )
                     }
                     _ => {}
@@ -14229,17 +14456,19 @@ impl DeleteEventSubscriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidSubscriptionStateFault" => {
-                        return RusotoError::Service(
-                            DeleteEventSubscriptionError::InvalidSubscriptionStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteEventSubscriptionError::InvalidSubscriptionStateFault,
                         )
                     }
                     "SubscriptionNotFound" => {
-                        return RusotoError::Service(
-                            DeleteEventSubscriptionError::SubscriptionNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteEventSubscriptionError::SubscriptionNotFoundFault,
                         )
                     }
                     _ => {}
@@ -14291,17 +14520,19 @@ impl DeleteHsmClientCertificateError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "HsmClientCertificateNotFoundFault" => {
-                        return RusotoError::Service(
-                            DeleteHsmClientCertificateError::HsmClientCertificateNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteHsmClientCertificateError::HsmClientCertificateNotFoundFault,
                         )
                     }
                     "InvalidHsmClientCertificateStateFault" => {
-                        return RusotoError::Service(
-                            DeleteHsmClientCertificateError::InvalidHsmClientCertificateStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteHsmClientCertificateError::InvalidHsmClientCertificateStateFault,
                         )
                     }
                     _ => {}
@@ -14351,17 +14582,19 @@ impl DeleteHsmConfigurationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "HsmConfigurationNotFoundFault" => {
-                        return RusotoError::Service(
-                            DeleteHsmConfigurationError::HsmConfigurationNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteHsmConfigurationError::HsmConfigurationNotFoundFault,
                         )
                     }
                     "InvalidHsmConfigurationStateFault" => {
-                        return RusotoError::Service(
-                            DeleteHsmConfigurationError::InvalidHsmConfigurationStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteHsmConfigurationError::InvalidHsmConfigurationStateFault,
                         )
                     }
                     _ => {}
@@ -14411,15 +14644,19 @@ impl DeleteScheduledActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ScheduledActionNotFound" => {
-                        return RusotoError::Service(
-                            DeleteScheduledActionError::ScheduledActionNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteScheduledActionError::ScheduledActionNotFoundFault,
                         )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(
-                            DeleteScheduledActionError::UnauthorizedOperation(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteScheduledActionError::UnauthorizedOperation,
                         )
                     }
                     _ => {}
@@ -14467,17 +14704,19 @@ impl DeleteSnapshotCopyGrantError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidSnapshotCopyGrantStateFault" => {
-                        return RusotoError::Service(
-                            DeleteSnapshotCopyGrantError::InvalidSnapshotCopyGrantStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteSnapshotCopyGrantError::InvalidSnapshotCopyGrantStateFault,
                         )
                     }
                     "SnapshotCopyGrantNotFoundFault" => {
-                        return RusotoError::Service(
-                            DeleteSnapshotCopyGrantError::SnapshotCopyGrantNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteSnapshotCopyGrantError::SnapshotCopyGrantNotFoundFault,
                         )
                     }
                     _ => {}
@@ -14527,17 +14766,19 @@ impl DeleteSnapshotScheduleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidClusterSnapshotScheduleState" => {
-                        return RusotoError::Service(
-                            DeleteSnapshotScheduleError::InvalidClusterSnapshotScheduleStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteSnapshotScheduleError::InvalidClusterSnapshotScheduleStateFault,
                         )
                     }
                     "SnapshotScheduleNotFound" => {
-                        return RusotoError::Service(
-                            DeleteSnapshotScheduleError::SnapshotScheduleNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteSnapshotScheduleError::SnapshotScheduleNotFoundFault,
                         )
                     }
                     _ => {}
@@ -14587,14 +14828,20 @@ impl DeleteTagsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidTagFault" => {
-                        return RusotoError::Service(DeleteTagsError::InvalidTagFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteTagsError::InvalidTagFault,
+                        )
                     }
                     "ResourceNotFoundFault" => {
-                        return RusotoError::Service(DeleteTagsError::ResourceNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteTagsError::ResourceNotFoundFault,
+                        )
                     }
                     _ => {}
                 }
@@ -14639,13 +14886,19 @@ impl DeleteUsageLimitError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "UnsupportedOperation" => {
-                        return RusotoError::Service(
-                            DeleteUsageLimitError::UnsupportedOperationFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteUsageLimitError::UnsupportedOperationFault,
                         )
                     }
                     "UsageLimitNotFound" => {
-                        return RusotoError::Service(
-                            DeleteUsageLimitError::UsageLimitNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteUsageLimitError::UsageLimitNotFoundFault,
                         )
                     }
                     _ => {}
@@ -14727,17 +14980,19 @@ impl DescribeClusterDbRevisionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
-                            DescribeClusterDbRevisionsError::ClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeClusterDbRevisionsError::ClusterNotFoundFault,
                         )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(
-                            DescribeClusterDbRevisionsError::InvalidClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeClusterDbRevisionsError::InvalidClusterStateFault,
                         )
                     }
                     _ => {}
@@ -14789,17 +15044,19 @@ impl DescribeClusterParameterGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            DescribeClusterParameterGroupsError::ClusterParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeClusterParameterGroupsError::ClusterParameterGroupNotFoundFault,
                         )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(
-                            DescribeClusterParameterGroupsError::InvalidTagFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeClusterParameterGroupsError::InvalidTagFault,
                         )
                     }
                     _ => {}
@@ -14847,10 +15104,11 @@ impl DescribeClusterParametersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            DescribeClusterParametersError::ClusterParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeClusterParametersError::ClusterParameterGroupNotFoundFault,
                         )
                     }
                     _ => {}
@@ -14899,17 +15157,19 @@ impl DescribeClusterSecurityGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterSecurityGroupNotFound" => {
-                        return RusotoError::Service(
-                            DescribeClusterSecurityGroupsError::ClusterSecurityGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeClusterSecurityGroupsError::ClusterSecurityGroupNotFoundFault,
                         )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(
-                            DescribeClusterSecurityGroupsError::InvalidTagFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeClusterSecurityGroupsError::InvalidTagFault,
                         )
                     }
                     _ => {}
@@ -14961,22 +15221,27 @@ impl DescribeClusterSnapshotsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
-                            DescribeClusterSnapshotsError::ClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeClusterSnapshotsError::ClusterNotFoundFault,
                         )
                     }
                     "ClusterSnapshotNotFound" => {
-                        return RusotoError::Service(
-                            DescribeClusterSnapshotsError::ClusterSnapshotNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeClusterSnapshotsError::ClusterSnapshotNotFoundFault,
                         )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(
-                            DescribeClusterSnapshotsError::InvalidTagFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeClusterSnapshotsError::InvalidTagFault,
                         )
                     }
                     _ => {}
@@ -15029,15 +15294,19 @@ impl DescribeClusterSubnetGroupsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            DescribeClusterSubnetGroupsError::ClusterSubnetGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeClusterSubnetGroupsError::ClusterSubnetGroupNotFoundFault,
                         )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(
-                            DescribeClusterSubnetGroupsError::InvalidTagFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeClusterSubnetGroupsError::InvalidTagFault,
                         )
                     }
                     _ => {}
@@ -15085,15 +15354,19 @@ impl DescribeClusterTracksError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidClusterTrack" => {
-                        return RusotoError::Service(
-                            DescribeClusterTracksError::InvalidClusterTrackFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeClusterTracksError::InvalidClusterTrackFault,
                         )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(
-                            DescribeClusterTracksError::UnauthorizedOperation(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeClusterTracksError::UnauthorizedOperation,
                         )
                     }
                     _ => {}
@@ -15175,14 +15448,20 @@ impl DescribeClustersError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(DescribeClustersError::ClusterNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeClustersError::ClusterNotFoundFault,
+                        )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(DescribeClustersError::InvalidTagFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeClustersError::InvalidTagFault,
+                        )
                     }
                     _ => {}
                 }
@@ -15299,15 +15578,19 @@ impl DescribeEventSubscriptionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidTagFault" => {
-                        return RusotoError::Service(
-                            DescribeEventSubscriptionsError::InvalidTagFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeEventSubscriptionsError::InvalidTagFault,
                         )
                     }
                     "SubscriptionNotFound" => {
-                        return RusotoError::Service(
-                            DescribeEventSubscriptionsError::SubscriptionNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeEventSubscriptionsError::SubscriptionNotFoundFault,
                         )
                     }
                     _ => {}
@@ -15391,17 +15674,19 @@ impl DescribeHsmClientCertificatesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "HsmClientCertificateNotFoundFault" => {
-                        return RusotoError::Service(
-                            DescribeHsmClientCertificatesError::HsmClientCertificateNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeHsmClientCertificatesError::HsmClientCertificateNotFoundFault,
                         )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(
-                            DescribeHsmClientCertificatesError::InvalidTagFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeHsmClientCertificatesError::InvalidTagFault,
                         )
                     }
                     _ => {}
@@ -15451,15 +15736,19 @@ impl DescribeHsmConfigurationsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "HsmConfigurationNotFoundFault" => {
-                        return RusotoError::Service(
-                            DescribeHsmConfigurationsError::HsmConfigurationNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeHsmConfigurationsError::HsmConfigurationNotFoundFault,
                         )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(
-                            DescribeHsmConfigurationsError::InvalidTagFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeHsmConfigurationsError::InvalidTagFault,
                         )
                     }
                     _ => {}
@@ -15505,8 +15794,11 @@ impl DescribeLoggingStatusError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
-                            DescribeLoggingStatusError::ClusterNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeLoggingStatusError::ClusterNotFoundFault,
                         )
                     }
                     _ => {}
@@ -15557,31 +15849,35 @@ impl DescribeNodeConfigurationOptionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessToSnapshotDenied" => {
-                        return RusotoError::Service(
-                            DescribeNodeConfigurationOptionsError::AccessToSnapshotDeniedFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeNodeConfigurationOptionsError::AccessToSnapshotDeniedFault,
                         )
                     }
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
-                            DescribeNodeConfigurationOptionsError::ClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeNodeConfigurationOptionsError::ClusterNotFoundFault,
                         )
                     }
                     "ClusterSnapshotNotFound" => {
-                        return RusotoError::Service(
-                            DescribeNodeConfigurationOptionsError::ClusterSnapshotNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeNodeConfigurationOptionsError::ClusterSnapshotNotFoundFault,
                         )
                     }
                     "InvalidClusterSnapshotState" => {
-                        return RusotoError::Service(
-                            DescribeNodeConfigurationOptionsError::InvalidClusterSnapshotStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeNodeConfigurationOptionsError::InvalidClusterSnapshotStateFault,
                         )
                     }
                     _ => {}
@@ -15677,24 +15973,27 @@ impl DescribeReservedNodeOfferingsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DependentServiceUnavailableFault" => {
-                        return RusotoError::Service(
-                            DescribeReservedNodeOfferingsError::DependentServiceUnavailableFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeReservedNodeOfferingsError::DependentServiceUnavailableFault,
                         )
                     }
                     "ReservedNodeOfferingNotFound" => {
-                        return RusotoError::Service(
-                            DescribeReservedNodeOfferingsError::ReservedNodeOfferingNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeReservedNodeOfferingsError::ReservedNodeOfferingNotFoundFault,
                         )
                     }
                     "UnsupportedOperation" => {
-                        return RusotoError::Service(
-                            DescribeReservedNodeOfferingsError::UnsupportedOperationFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeReservedNodeOfferingsError::UnsupportedOperationFault,
                         )
                     }
                     _ => {}
@@ -15747,17 +16046,19 @@ impl DescribeReservedNodesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DependentServiceUnavailableFault" => {
-                        return RusotoError::Service(
-                            DescribeReservedNodesError::DependentServiceUnavailableFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeReservedNodesError::DependentServiceUnavailableFault,
                         )
                     }
                     "ReservedNodeNotFound" => {
-                        return RusotoError::Service(
-                            DescribeReservedNodesError::ReservedNodeNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeReservedNodesError::ReservedNodeNotFoundFault,
                         )
                     }
                     _ => {}
@@ -15807,14 +16108,20 @@ impl DescribeResizeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(DescribeResizeError::ClusterNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeResizeError::ClusterNotFoundFault,
+                        )
                     }
                     "ResizeNotFound" => {
-                        return RusotoError::Service(DescribeResizeError::ResizeNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeResizeError::ResizeNotFoundFault,
+                        )
                     }
                     _ => {}
                 }
@@ -15859,17 +16166,19 @@ impl DescribeScheduledActionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ScheduledActionNotFound" => {
-                        return RusotoError::Service(
-                            DescribeScheduledActionsError::ScheduledActionNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeScheduledActionsError::ScheduledActionNotFoundFault,
                         )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(
-                            DescribeScheduledActionsError::UnauthorizedOperation(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeScheduledActionsError::UnauthorizedOperation,
                         )
                     }
                     _ => {}
@@ -15921,15 +16230,19 @@ impl DescribeSnapshotCopyGrantsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidTagFault" => {
-                        return RusotoError::Service(
-                            DescribeSnapshotCopyGrantsError::InvalidTagFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeSnapshotCopyGrantsError::InvalidTagFault,
                         )
                     }
                     "SnapshotCopyGrantNotFoundFault" => {
-                        return RusotoError::Service(
-                            DescribeSnapshotCopyGrantsError::SnapshotCopyGrantNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeSnapshotCopyGrantsError::SnapshotCopyGrantNotFoundFault,
                         )
                     }
                     _ => {}
@@ -16047,17 +16360,19 @@ impl DescribeTableRestoreStatusError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
-                            DescribeTableRestoreStatusError::ClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeTableRestoreStatusError::ClusterNotFoundFault,
                         )
                     }
                     "TableRestoreNotFoundFault" => {
-                        return RusotoError::Service(
-                            DescribeTableRestoreStatusError::TableRestoreNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeTableRestoreStatusError::TableRestoreNotFoundFault,
                         )
                     }
                     _ => {}
@@ -16107,14 +16422,20 @@ impl DescribeTagsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidTagFault" => {
-                        return RusotoError::Service(DescribeTagsError::InvalidTagFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeTagsError::InvalidTagFault,
+                        )
                     }
                     "ResourceNotFoundFault" => {
-                        return RusotoError::Service(DescribeTagsError::ResourceNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DescribeTagsError::ResourceNotFoundFault,
+                        )
                     }
                     _ => {}
                 }
@@ -16159,15 +16480,19 @@ impl DescribeUsageLimitsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
-                            DescribeUsageLimitsError::ClusterNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeUsageLimitsError::ClusterNotFoundFault,
                         )
                     }
                     "UnsupportedOperation" => {
-                        return RusotoError::Service(
-                            DescribeUsageLimitsError::UnsupportedOperationFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DescribeUsageLimitsError::UnsupportedOperationFault,
                         )
                     }
                     _ => {}
@@ -16213,9 +16538,12 @@ impl DisableLoggingError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(DisableLoggingError::ClusterNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DisableLoggingError::ClusterNotFoundFault,
+                        )
                     }
                     _ => {}
                 }
@@ -16263,27 +16591,35 @@ impl DisableSnapshotCopyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
-                            DisableSnapshotCopyError::ClusterNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DisableSnapshotCopyError::ClusterNotFoundFault,
                         )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(
-                            DisableSnapshotCopyError::InvalidClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DisableSnapshotCopyError::InvalidClusterStateFault,
                         )
                     }
                     "SnapshotCopyAlreadyDisabledFault" => {
-                        return RusotoError::Service(
-                            DisableSnapshotCopyError::SnapshotCopyAlreadyDisabledFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DisableSnapshotCopyError::SnapshotCopyAlreadyDisabledFault,
                         )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(
-                            DisableSnapshotCopyError::UnauthorizedOperation(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DisableSnapshotCopyError::UnauthorizedOperation,
                         )
                     }
                     _ => {}
@@ -16341,36 +16677,52 @@ impl EnableLoggingError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BucketNotFoundFault" => {
-                        return RusotoError::Service(EnableLoggingError::BucketNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            EnableLoggingError::BucketNotFoundFault,
+                        )
                     }
                     "ClusterNotFound" => {
-                        return RusotoError::Service(EnableLoggingError::ClusterNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            EnableLoggingError::ClusterNotFoundFault,
+                        )
                     }
                     "InsufficientS3BucketPolicyFault" => {
-                        return RusotoError::Service(
-                            EnableLoggingError::InsufficientS3BucketPolicyFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            EnableLoggingError::InsufficientS3BucketPolicyFault,
                         )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(EnableLoggingError::InvalidClusterStateFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            EnableLoggingError::InvalidClusterStateFault,
+                        )
                     }
                     "InvalidS3BucketNameFault" => {
-                        return RusotoError::Service(EnableLoggingError::InvalidS3BucketNameFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            EnableLoggingError::InvalidS3BucketNameFault,
+                        )
                     }
                     "InvalidS3KeyPrefixFault" => {
-                        return RusotoError::Service(EnableLoggingError::InvalidS3KeyPrefixFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            EnableLoggingError::InvalidS3KeyPrefixFault,
+                        )
                     }
                     _ => {}
                 }
@@ -16439,72 +16791,91 @@ impl EnableSnapshotCopyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(EnableSnapshotCopyError::ClusterNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            EnableSnapshotCopyError::ClusterNotFoundFault,
+                        )
                     }
                     "CopyToRegionDisabledFault" => {
-                        return RusotoError::Service(
-                            EnableSnapshotCopyError::CopyToRegionDisabledFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            EnableSnapshotCopyError::CopyToRegionDisabledFault,
                         )
                     }
                     "DependentServiceRequestThrottlingFault" => {
-                        return RusotoError::Service(
-                            EnableSnapshotCopyError::DependentServiceRequestThrottlingFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            EnableSnapshotCopyError::DependentServiceRequestThrottlingFault,
                         )
                     }
                     "IncompatibleOrderableOptions" => {
-                        return RusotoError::Service(
-                            EnableSnapshotCopyError::IncompatibleOrderableOptions(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            EnableSnapshotCopyError::IncompatibleOrderableOptions,
                         )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(
-                            EnableSnapshotCopyError::InvalidClusterStateFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            EnableSnapshotCopyError::InvalidClusterStateFault,
                         )
                     }
                     "InvalidRetentionPeriodFault" => {
-                        return RusotoError::Service(
-                            EnableSnapshotCopyError::InvalidRetentionPeriodFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            EnableSnapshotCopyError::InvalidRetentionPeriodFault,
                         )
                     }
                     "LimitExceededFault" => {
-                        return RusotoError::Service(EnableSnapshotCopyError::LimitExceededFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            EnableSnapshotCopyError::LimitExceededFault,
+                        )
                     }
                     "SnapshotCopyAlreadyEnabledFault" => {
-                        return RusotoError::Service(
-                            EnableSnapshotCopyError::SnapshotCopyAlreadyEnabledFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            EnableSnapshotCopyError::SnapshotCopyAlreadyEnabledFault,
                         )
                     }
                     "SnapshotCopyGrantNotFoundFault" => {
-                        return RusotoError::Service(
-                            EnableSnapshotCopyError::SnapshotCopyGrantNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            EnableSnapshotCopyError::SnapshotCopyGrantNotFoundFault,
                         )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(
-                            EnableSnapshotCopyError::UnauthorizedOperation(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            EnableSnapshotCopyError::UnauthorizedOperation,
                         )
                     }
                     "UnknownSnapshotCopyRegionFault" => {
-                        return RusotoError::Service(
-                            EnableSnapshotCopyError::UnknownSnapshotCopyRegionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            EnableSnapshotCopyError::UnknownSnapshotCopyRegionFault,
                         )
                     }
                     _ => {}
@@ -16571,15 +16942,19 @@ impl GetClusterCredentialsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
-                            GetClusterCredentialsError::ClusterNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetClusterCredentialsError::ClusterNotFoundFault,
                         )
                     }
                     "UnsupportedOperation" => {
-                        return RusotoError::Service(
-                            GetClusterCredentialsError::UnsupportedOperationFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetClusterCredentialsError::UnsupportedOperationFault,
                         )
                     }
                     _ => {}
@@ -16637,43 +17012,49 @@ impl GetReservedNodeExchangeOfferingsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DependentServiceUnavailableFault" => {
-                        return RusotoError::Service(
-                            GetReservedNodeExchangeOfferingsError::DependentServiceUnavailableFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetReservedNodeExchangeOfferingsError::DependentServiceUnavailableFault,
                         )
                     }
                     "InvalidReservedNodeState" => {
-                        return RusotoError::Service(
-                            GetReservedNodeExchangeOfferingsError::InvalidReservedNodeStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetReservedNodeExchangeOfferingsError::InvalidReservedNodeStateFault,
                         )
                     }
                     "ReservedNodeAlreadyMigrated" => {
-                        return RusotoError::Service(
-                            GetReservedNodeExchangeOfferingsError::ReservedNodeAlreadyMigratedFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetReservedNodeExchangeOfferingsError::ReservedNodeAlreadyMigratedFault,
                         )
                     }
                     "ReservedNodeNotFound" => {
-                        return RusotoError::Service(
-                            GetReservedNodeExchangeOfferingsError::ReservedNodeNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetReservedNodeExchangeOfferingsError::ReservedNodeNotFoundFault,
                         )
                     }
-                    "ReservedNodeOfferingNotFound" => return RusotoError::Service(
-                        GetReservedNodeExchangeOfferingsError::ReservedNodeOfferingNotFoundFault(
-                            parsed_error.message,
-                        ),
+                    "ReservedNodeOfferingNotFound" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        GetReservedNodeExchangeOfferingsError::ReservedNodeOfferingNotFoundFault,
                     ),
                     "UnsupportedOperation" => {
-                        return RusotoError::Service(
-                            GetReservedNodeExchangeOfferingsError::UnsupportedOperationFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetReservedNodeExchangeOfferingsError::UnsupportedOperationFault,
                         )
                     }
                     _ => {}
@@ -16769,115 +17150,156 @@ impl ModifyClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterAlreadyExists" => {
-                        return RusotoError::Service(ModifyClusterError::ClusterAlreadyExistsFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyClusterError::ClusterAlreadyExistsFault,
+                        )
                     }
                     "ClusterNotFound" => {
-                        return RusotoError::Service(ModifyClusterError::ClusterNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyClusterError::ClusterNotFoundFault,
+                        )
                     }
                     "ClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            ModifyClusterError::ClusterParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyClusterError::ClusterParameterGroupNotFoundFault,
                         )
                     }
                     "ClusterSecurityGroupNotFound" => {
-                        return RusotoError::Service(
-                            ModifyClusterError::ClusterSecurityGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyClusterError::ClusterSecurityGroupNotFoundFault,
                         )
                     }
                     "DependentServiceRequestThrottlingFault" => {
-                        return RusotoError::Service(
-                            ModifyClusterError::DependentServiceRequestThrottlingFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyClusterError::DependentServiceRequestThrottlingFault,
                         )
                     }
                     "HsmClientCertificateNotFoundFault" => {
-                        return RusotoError::Service(
-                            ModifyClusterError::HsmClientCertificateNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyClusterError::HsmClientCertificateNotFoundFault,
                         )
                     }
                     "HsmConfigurationNotFoundFault" => {
-                        return RusotoError::Service(
-                            ModifyClusterError::HsmConfigurationNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyClusterError::HsmConfigurationNotFoundFault,
                         )
                     }
                     "InsufficientClusterCapacity" => {
-                        return RusotoError::Service(
-                            ModifyClusterError::InsufficientClusterCapacityFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyClusterError::InsufficientClusterCapacityFault,
                         )
                     }
                     "InvalidClusterSecurityGroupState" => {
-                        return RusotoError::Service(
-                            ModifyClusterError::InvalidClusterSecurityGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyClusterError::InvalidClusterSecurityGroupStateFault,
                         )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(ModifyClusterError::InvalidClusterStateFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyClusterError::InvalidClusterStateFault,
+                        )
                     }
                     "InvalidClusterTrack" => {
-                        return RusotoError::Service(ModifyClusterError::InvalidClusterTrackFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyClusterError::InvalidClusterTrackFault,
+                        )
                     }
                     "InvalidElasticIpFault" => {
-                        return RusotoError::Service(ModifyClusterError::InvalidElasticIpFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyClusterError::InvalidElasticIpFault,
+                        )
                     }
                     "InvalidRetentionPeriodFault" => {
-                        return RusotoError::Service(
-                            ModifyClusterError::InvalidRetentionPeriodFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyClusterError::InvalidRetentionPeriodFault,
                         )
                     }
                     "LimitExceededFault" => {
-                        return RusotoError::Service(ModifyClusterError::LimitExceededFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyClusterError::LimitExceededFault,
+                        )
                     }
                     "NumberOfNodesPerClusterLimitExceeded" => {
-                        return RusotoError::Service(
-                            ModifyClusterError::NumberOfNodesPerClusterLimitExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyClusterError::NumberOfNodesPerClusterLimitExceededFault,
                         )
                     }
                     "NumberOfNodesQuotaExceeded" => {
-                        return RusotoError::Service(
-                            ModifyClusterError::NumberOfNodesQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyClusterError::NumberOfNodesQuotaExceededFault,
                         )
                     }
                     "TableLimitExceeded" => {
-                        return RusotoError::Service(ModifyClusterError::TableLimitExceededFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyClusterError::TableLimitExceededFault,
+                        )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(ModifyClusterError::UnauthorizedOperation(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyClusterError::UnauthorizedOperation,
+                        )
                     }
                     "UnsupportedOptionFault" => {
-                        return RusotoError::Service(ModifyClusterError::UnsupportedOptionFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyClusterError::UnsupportedOptionFault,
+                        )
                     }
                     _ => {}
                 }
@@ -16957,24 +17379,27 @@ impl ModifyClusterDbRevisionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
-                            ModifyClusterDbRevisionError::ClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyClusterDbRevisionError::ClusterNotFoundFault,
                         )
                     }
                     "ClusterOnLatestRevision" => {
-                        return RusotoError::Service(
-                            ModifyClusterDbRevisionError::ClusterOnLatestRevisionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyClusterDbRevisionError::ClusterOnLatestRevisionFault,
                         )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(
-                            ModifyClusterDbRevisionError::InvalidClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyClusterDbRevisionError::InvalidClusterStateFault,
                         )
                     }
                     _ => {}
@@ -17025,15 +17450,19 @@ impl ModifyClusterIamRolesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
-                            ModifyClusterIamRolesError::ClusterNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyClusterIamRolesError::ClusterNotFoundFault,
                         )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(
-                            ModifyClusterIamRolesError::InvalidClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyClusterIamRolesError::InvalidClusterStateFault,
                         )
                     }
                     _ => {}
@@ -17081,17 +17510,19 @@ impl ModifyClusterMaintenanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
-                            ModifyClusterMaintenanceError::ClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyClusterMaintenanceError::ClusterNotFoundFault,
                         )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(
-                            ModifyClusterMaintenanceError::InvalidClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyClusterMaintenanceError::InvalidClusterStateFault,
                         )
                     }
                     _ => {}
@@ -17143,16 +17574,18 @@ impl ModifyClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            ModifyClusterParameterGroupError::ClusterParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyClusterParameterGroupError::ClusterParameterGroupNotFoundFault,
                         )
                     }
-                    "InvalidClusterParameterGroupState" => return RusotoError::Service(
-                        ModifyClusterParameterGroupError::InvalidClusterParameterGroupStateFault(
-                            parsed_error.message,
-                        ),
+                    "InvalidClusterParameterGroupState" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        ModifyClusterParameterGroupError::InvalidClusterParameterGroupStateFault,
                     ),
                     _ => {}
                 }
@@ -17203,24 +17636,27 @@ impl ModifyClusterSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterSnapshotNotFound" => {
-                        return RusotoError::Service(
-                            ModifyClusterSnapshotError::ClusterSnapshotNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyClusterSnapshotError::ClusterSnapshotNotFoundFault,
                         )
                     }
                     "InvalidClusterSnapshotState" => {
-                        return RusotoError::Service(
-                            ModifyClusterSnapshotError::InvalidClusterSnapshotStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyClusterSnapshotError::InvalidClusterSnapshotStateFault,
                         )
                     }
                     "InvalidRetentionPeriodFault" => {
-                        return RusotoError::Service(
-                            ModifyClusterSnapshotError::InvalidRetentionPeriodFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyClusterSnapshotError::InvalidRetentionPeriodFault,
                         )
                     }
                     _ => {}
@@ -17276,7 +17712,7 @@ impl ModifyClusterSnapshotScheduleError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "ClusterNotFound" => return RusotoError::Service(ModifyClusterSnapshotScheduleError::ClusterNotFoundFault(parsed_error.message)),"InvalidClusterSnapshotScheduleState" => return RusotoError::Service(ModifyClusterSnapshotScheduleError::InvalidClusterSnapshotScheduleStateFault(parsed_error.message)),"SnapshotScheduleNotFound" => return RusotoError::Service(ModifyClusterSnapshotScheduleError::SnapshotScheduleNotFoundFault(parsed_error.message)),_ => {}
+                                    "ClusterNotFound" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, ModifyClusterSnapshotScheduleError::ClusterNotFoundFault),"InvalidClusterSnapshotScheduleState" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, ModifyClusterSnapshotScheduleError::InvalidClusterSnapshotScheduleStateFault),"SnapshotScheduleNotFound" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, ModifyClusterSnapshotScheduleError::SnapshotScheduleNotFoundFault),_ => {}
                                 }
             }
         }
@@ -17334,41 +17770,51 @@ impl ModifyClusterSubnetGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            ModifyClusterSubnetGroupError::ClusterSubnetGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyClusterSubnetGroupError::ClusterSubnetGroupNotFoundFault,
                         )
                     }
                     "ClusterSubnetQuotaExceededFault" => {
-                        return RusotoError::Service(
-                            ModifyClusterSubnetGroupError::ClusterSubnetQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyClusterSubnetGroupError::ClusterSubnetQuotaExceededFault,
                         )
                     }
                     "DependentServiceRequestThrottlingFault" => {
-                        return RusotoError::Service(
-                            ModifyClusterSubnetGroupError::DependentServiceRequestThrottlingFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyClusterSubnetGroupError::DependentServiceRequestThrottlingFault,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(ModifyClusterSubnetGroupError::InvalidSubnet(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyClusterSubnetGroupError::InvalidSubnet,
+                        )
                     }
                     "SubnetAlreadyInUse" => {
-                        return RusotoError::Service(
-                            ModifyClusterSubnetGroupError::SubnetAlreadyInUse(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyClusterSubnetGroupError::SubnetAlreadyInUse,
                         )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(
-                            ModifyClusterSubnetGroupError::UnauthorizedOperation(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyClusterSubnetGroupError::UnauthorizedOperation,
                         )
                     }
                     _ => {}
@@ -17440,64 +17886,75 @@ impl ModifyEventSubscriptionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidSubscriptionStateFault" => {
-                        return RusotoError::Service(
-                            ModifyEventSubscriptionError::InvalidSubscriptionStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyEventSubscriptionError::InvalidSubscriptionStateFault,
                         )
                     }
                     "SNSInvalidTopic" => {
-                        return RusotoError::Service(
-                            ModifyEventSubscriptionError::SNSInvalidTopicFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyEventSubscriptionError::SNSInvalidTopicFault,
                         )
                     }
                     "SNSNoAuthorization" => {
-                        return RusotoError::Service(
-                            ModifyEventSubscriptionError::SNSNoAuthorizationFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyEventSubscriptionError::SNSNoAuthorizationFault,
                         )
                     }
                     "SNSTopicArnNotFound" => {
-                        return RusotoError::Service(
-                            ModifyEventSubscriptionError::SNSTopicArnNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyEventSubscriptionError::SNSTopicArnNotFoundFault,
                         )
                     }
                     "SourceNotFound" => {
-                        return RusotoError::Service(
-                            ModifyEventSubscriptionError::SourceNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyEventSubscriptionError::SourceNotFoundFault,
                         )
                     }
                     "SubscriptionCategoryNotFound" => {
-                        return RusotoError::Service(
-                            ModifyEventSubscriptionError::SubscriptionCategoryNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyEventSubscriptionError::SubscriptionCategoryNotFoundFault,
                         )
                     }
                     "SubscriptionEventIdNotFound" => {
-                        return RusotoError::Service(
-                            ModifyEventSubscriptionError::SubscriptionEventIdNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyEventSubscriptionError::SubscriptionEventIdNotFoundFault,
                         )
                     }
                     "SubscriptionNotFound" => {
-                        return RusotoError::Service(
-                            ModifyEventSubscriptionError::SubscriptionNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyEventSubscriptionError::SubscriptionNotFoundFault,
                         )
                     }
                     "SubscriptionSeverityNotFound" => {
-                        return RusotoError::Service(
-                            ModifyEventSubscriptionError::SubscriptionSeverityNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyEventSubscriptionError::SubscriptionSeverityNotFoundFault,
                         )
                     }
                     _ => {}
@@ -17570,34 +18027,43 @@ impl ModifyScheduledActionError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidSchedule" => {
-                        return RusotoError::Service(
-                            ModifyScheduledActionError::InvalidScheduleFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyScheduledActionError::InvalidScheduleFault,
                         )
                     }
                     "InvalidScheduledAction" => {
-                        return RusotoError::Service(
-                            ModifyScheduledActionError::InvalidScheduledActionFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyScheduledActionError::InvalidScheduledActionFault,
                         )
                     }
                     "ScheduledActionNotFound" => {
-                        return RusotoError::Service(
-                            ModifyScheduledActionError::ScheduledActionNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyScheduledActionError::ScheduledActionNotFoundFault,
                         )
                     }
                     "ScheduledActionTypeUnsupported" => {
-                        return RusotoError::Service(
-                            ModifyScheduledActionError::ScheduledActionTypeUnsupportedFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyScheduledActionError::ScheduledActionTypeUnsupportedFault,
                         )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(
-                            ModifyScheduledActionError::UnauthorizedOperation(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyScheduledActionError::UnauthorizedOperation,
                         )
                     }
                     _ => {}
@@ -17660,38 +18126,43 @@ impl ModifySnapshotCopyRetentionPeriodError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
-                            ModifySnapshotCopyRetentionPeriodError::ClusterNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifySnapshotCopyRetentionPeriodError::ClusterNotFoundFault,
                         )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(
-                            ModifySnapshotCopyRetentionPeriodError::InvalidClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifySnapshotCopyRetentionPeriodError::InvalidClusterStateFault,
                         )
                     }
                     "InvalidRetentionPeriodFault" => {
-                        return RusotoError::Service(
-                            ModifySnapshotCopyRetentionPeriodError::InvalidRetentionPeriodFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifySnapshotCopyRetentionPeriodError::InvalidRetentionPeriodFault,
                         )
                     }
                     "SnapshotCopyDisabledFault" => {
-                        return RusotoError::Service(
-                            ModifySnapshotCopyRetentionPeriodError::SnapshotCopyDisabledFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifySnapshotCopyRetentionPeriodError::SnapshotCopyDisabledFault,
                         )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(
-                            ModifySnapshotCopyRetentionPeriodError::UnauthorizedOperation(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifySnapshotCopyRetentionPeriodError::UnauthorizedOperation,
                         )
                     }
                     _ => {}
@@ -17752,22 +18223,27 @@ impl ModifySnapshotScheduleError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidSchedule" => {
-                        return RusotoError::Service(
-                            ModifySnapshotScheduleError::InvalidScheduleFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifySnapshotScheduleError::InvalidScheduleFault,
                         )
                     }
                     "SnapshotScheduleNotFound" => {
-                        return RusotoError::Service(
-                            ModifySnapshotScheduleError::SnapshotScheduleNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifySnapshotScheduleError::SnapshotScheduleNotFoundFault,
                         )
                     }
                     "SnapshotScheduleUpdateInProgress" => {
-                        return RusotoError::Service(
-                            ModifySnapshotScheduleError::SnapshotScheduleUpdateInProgressFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifySnapshotScheduleError::SnapshotScheduleUpdateInProgressFault,
                         )
                     }
                     _ => {}
@@ -17820,18 +18296,27 @@ impl ModifyUsageLimitError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidUsageLimit" => {
-                        return RusotoError::Service(ModifyUsageLimitError::InvalidUsageLimitFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ModifyUsageLimitError::InvalidUsageLimitFault,
+                        )
                     }
                     "UnsupportedOperation" => {
-                        return RusotoError::Service(
-                            ModifyUsageLimitError::UnsupportedOperationFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyUsageLimitError::UnsupportedOperationFault,
                         )
                     }
                     "UsageLimitNotFound" => {
-                        return RusotoError::Service(
-                            ModifyUsageLimitError::UsageLimitNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ModifyUsageLimitError::UsageLimitNotFoundFault,
                         )
                     }
                     _ => {}
@@ -17878,14 +18363,20 @@ impl PauseClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(PauseClusterError::ClusterNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PauseClusterError::ClusterNotFoundFault,
+                        )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(PauseClusterError::InvalidClusterStateFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PauseClusterError::InvalidClusterStateFault,
+                        )
                     }
                     _ => {}
                 }
@@ -17936,31 +18427,35 @@ impl PurchaseReservedNodeOfferingError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ReservedNodeAlreadyExists" => {
-                        return RusotoError::Service(
-                            PurchaseReservedNodeOfferingError::ReservedNodeAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            PurchaseReservedNodeOfferingError::ReservedNodeAlreadyExistsFault,
                         )
                     }
                     "ReservedNodeOfferingNotFound" => {
-                        return RusotoError::Service(
-                            PurchaseReservedNodeOfferingError::ReservedNodeOfferingNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            PurchaseReservedNodeOfferingError::ReservedNodeOfferingNotFoundFault,
                         )
                     }
                     "ReservedNodeQuotaExceeded" => {
-                        return RusotoError::Service(
-                            PurchaseReservedNodeOfferingError::ReservedNodeQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            PurchaseReservedNodeOfferingError::ReservedNodeQuotaExceededFault,
                         )
                     }
                     "UnsupportedOperation" => {
-                        return RusotoError::Service(
-                            PurchaseReservedNodeOfferingError::UnsupportedOperationFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            PurchaseReservedNodeOfferingError::UnsupportedOperationFault,
                         )
                     }
                     _ => {}
@@ -18016,14 +18511,20 @@ impl RebootClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(RebootClusterError::ClusterNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            RebootClusterError::ClusterNotFoundFault,
+                        )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(RebootClusterError::InvalidClusterStateFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            RebootClusterError::InvalidClusterStateFault,
+                        )
                     }
                     _ => {}
                 }
@@ -18070,17 +18571,19 @@ impl ResetClusterParameterGroupError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            ResetClusterParameterGroupError::ClusterParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ResetClusterParameterGroupError::ClusterParameterGroupNotFoundFault,
                         )
                     }
                     "InvalidClusterParameterGroupState" => {
-                        return RusotoError::Service(
-                            ResetClusterParameterGroupError::InvalidClusterParameterGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ResetClusterParameterGroupError::InvalidClusterParameterGroupStateFault,
                         )
                     }
                     _ => {}
@@ -18144,55 +18647,76 @@ impl ResizeClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(ResizeClusterError::ClusterNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ResizeClusterError::ClusterNotFoundFault,
+                        )
                     }
                     "InsufficientClusterCapacity" => {
-                        return RusotoError::Service(
-                            ResizeClusterError::InsufficientClusterCapacityFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ResizeClusterError::InsufficientClusterCapacityFault,
                         )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(ResizeClusterError::InvalidClusterStateFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ResizeClusterError::InvalidClusterStateFault,
+                        )
                     }
                     "LimitExceededFault" => {
-                        return RusotoError::Service(ResizeClusterError::LimitExceededFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ResizeClusterError::LimitExceededFault,
+                        )
                     }
                     "NumberOfNodesPerClusterLimitExceeded" => {
-                        return RusotoError::Service(
-                            ResizeClusterError::NumberOfNodesPerClusterLimitExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ResizeClusterError::NumberOfNodesPerClusterLimitExceededFault,
                         )
                     }
                     "NumberOfNodesQuotaExceeded" => {
-                        return RusotoError::Service(
-                            ResizeClusterError::NumberOfNodesQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ResizeClusterError::NumberOfNodesQuotaExceededFault,
                         )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(ResizeClusterError::UnauthorizedOperation(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ResizeClusterError::UnauthorizedOperation,
+                        )
                     }
                     "UnsupportedOperation" => {
-                        return RusotoError::Service(ResizeClusterError::UnsupportedOperationFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ResizeClusterError::UnsupportedOperationFault,
+                        )
                     }
                     "UnsupportedOptionFault" => {
-                        return RusotoError::Service(ResizeClusterError::UnsupportedOptionFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ResizeClusterError::UnsupportedOptionFault,
+                        )
                     }
                     _ => {}
                 }
@@ -18298,172 +18822,201 @@ impl RestoreFromClusterSnapshotError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessToSnapshotDenied" => {
-                        return RusotoError::Service(
-                            RestoreFromClusterSnapshotError::AccessToSnapshotDeniedFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreFromClusterSnapshotError::AccessToSnapshotDeniedFault,
                         )
                     }
                     "ClusterAlreadyExists" => {
-                        return RusotoError::Service(
-                            RestoreFromClusterSnapshotError::ClusterAlreadyExistsFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreFromClusterSnapshotError::ClusterAlreadyExistsFault,
                         )
                     }
                     "ClusterParameterGroupNotFound" => {
-                        return RusotoError::Service(
-                            RestoreFromClusterSnapshotError::ClusterParameterGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreFromClusterSnapshotError::ClusterParameterGroupNotFoundFault,
                         )
                     }
                     "ClusterQuotaExceeded" => {
-                        return RusotoError::Service(
-                            RestoreFromClusterSnapshotError::ClusterQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreFromClusterSnapshotError::ClusterQuotaExceededFault,
                         )
                     }
                     "ClusterSecurityGroupNotFound" => {
-                        return RusotoError::Service(
-                            RestoreFromClusterSnapshotError::ClusterSecurityGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreFromClusterSnapshotError::ClusterSecurityGroupNotFoundFault,
                         )
                     }
                     "ClusterSnapshotNotFound" => {
-                        return RusotoError::Service(
-                            RestoreFromClusterSnapshotError::ClusterSnapshotNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreFromClusterSnapshotError::ClusterSnapshotNotFoundFault,
                         )
                     }
                     "ClusterSubnetGroupNotFoundFault" => {
-                        return RusotoError::Service(
-                            RestoreFromClusterSnapshotError::ClusterSubnetGroupNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreFromClusterSnapshotError::ClusterSubnetGroupNotFoundFault,
                         )
                     }
                     "DependentServiceRequestThrottlingFault" => {
-                        return RusotoError::Service(
-                            RestoreFromClusterSnapshotError::DependentServiceRequestThrottlingFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreFromClusterSnapshotError::DependentServiceRequestThrottlingFault,
                         )
                     }
                     "HsmClientCertificateNotFoundFault" => {
-                        return RusotoError::Service(
-                            RestoreFromClusterSnapshotError::HsmClientCertificateNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreFromClusterSnapshotError::HsmClientCertificateNotFoundFault,
                         )
                     }
                     "HsmConfigurationNotFoundFault" => {
-                        return RusotoError::Service(
-                            RestoreFromClusterSnapshotError::HsmConfigurationNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreFromClusterSnapshotError::HsmConfigurationNotFoundFault,
                         )
                     }
                     "InsufficientClusterCapacity" => {
-                        return RusotoError::Service(
-                            RestoreFromClusterSnapshotError::InsufficientClusterCapacityFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreFromClusterSnapshotError::InsufficientClusterCapacityFault,
                         )
                     }
                     "InvalidClusterSnapshotState" => {
-                        return RusotoError::Service(
-                            RestoreFromClusterSnapshotError::InvalidClusterSnapshotStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreFromClusterSnapshotError::InvalidClusterSnapshotStateFault,
                         )
                     }
                     "InvalidClusterSubnetGroupStateFault" => {
-                        return RusotoError::Service(
-                            RestoreFromClusterSnapshotError::InvalidClusterSubnetGroupStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreFromClusterSnapshotError::InvalidClusterSubnetGroupStateFault,
                         )
                     }
                     "InvalidClusterTrack" => {
-                        return RusotoError::Service(
-                            RestoreFromClusterSnapshotError::InvalidClusterTrackFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreFromClusterSnapshotError::InvalidClusterTrackFault,
                         )
                     }
                     "InvalidElasticIpFault" => {
-                        return RusotoError::Service(
-                            RestoreFromClusterSnapshotError::InvalidElasticIpFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreFromClusterSnapshotError::InvalidElasticIpFault,
                         )
                     }
                     "InvalidRestore" => {
-                        return RusotoError::Service(
-                            RestoreFromClusterSnapshotError::InvalidRestoreFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreFromClusterSnapshotError::InvalidRestoreFault,
                         )
                     }
                     "InvalidSubnet" => {
-                        return RusotoError::Service(
-                            RestoreFromClusterSnapshotError::InvalidSubnet(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreFromClusterSnapshotError::InvalidSubnet,
                         )
                     }
                     "InvalidTagFault" => {
-                        return RusotoError::Service(
-                            RestoreFromClusterSnapshotError::InvalidTagFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreFromClusterSnapshotError::InvalidTagFault,
                         )
                     }
                     "InvalidVPCNetworkStateFault" => {
-                        return RusotoError::Service(
-                            RestoreFromClusterSnapshotError::InvalidVPCNetworkStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreFromClusterSnapshotError::InvalidVPCNetworkStateFault,
                         )
                     }
                     "LimitExceededFault" => {
-                        return RusotoError::Service(
-                            RestoreFromClusterSnapshotError::LimitExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreFromClusterSnapshotError::LimitExceededFault,
                         )
                     }
-                    "NumberOfNodesPerClusterLimitExceeded" => return RusotoError::Service(
-                        RestoreFromClusterSnapshotError::NumberOfNodesPerClusterLimitExceededFault(
-                            parsed_error.message,
-                        ),
+                    "NumberOfNodesPerClusterLimitExceeded" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        RestoreFromClusterSnapshotError::NumberOfNodesPerClusterLimitExceededFault,
                     ),
                     "NumberOfNodesQuotaExceeded" => {
-                        return RusotoError::Service(
-                            RestoreFromClusterSnapshotError::NumberOfNodesQuotaExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreFromClusterSnapshotError::NumberOfNodesQuotaExceededFault,
                         )
                     }
                     "SnapshotScheduleNotFound" => {
-                        return RusotoError::Service(
-                            RestoreFromClusterSnapshotError::SnapshotScheduleNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreFromClusterSnapshotError::SnapshotScheduleNotFoundFault,
                         )
                     }
                     "TagLimitExceededFault" => {
-                        return RusotoError::Service(
-                            RestoreFromClusterSnapshotError::TagLimitExceededFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreFromClusterSnapshotError::TagLimitExceededFault,
                         )
                     }
                     "UnauthorizedOperation" => {
-                        return RusotoError::Service(
-                            RestoreFromClusterSnapshotError::UnauthorizedOperation(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreFromClusterSnapshotError::UnauthorizedOperation,
                         )
                     }
                     _ => {}
@@ -18589,7 +19142,7 @@ impl RestoreTableFromClusterSnapshotError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "ClusterNotFound" => return RusotoError::Service(RestoreTableFromClusterSnapshotError::ClusterNotFoundFault(parsed_error.message)),"ClusterSnapshotNotFound" => return RusotoError::Service(RestoreTableFromClusterSnapshotError::ClusterSnapshotNotFoundFault(parsed_error.message)),"InProgressTableRestoreQuotaExceededFault" => return RusotoError::Service(RestoreTableFromClusterSnapshotError::InProgressTableRestoreQuotaExceededFault(parsed_error.message)),"InvalidClusterSnapshotState" => return RusotoError::Service(RestoreTableFromClusterSnapshotError::InvalidClusterSnapshotStateFault(parsed_error.message)),"InvalidClusterState" => return RusotoError::Service(RestoreTableFromClusterSnapshotError::InvalidClusterStateFault(parsed_error.message)),"InvalidTableRestoreArgument" => return RusotoError::Service(RestoreTableFromClusterSnapshotError::InvalidTableRestoreArgumentFault(parsed_error.message)),"UnsupportedOperation" => return RusotoError::Service(RestoreTableFromClusterSnapshotError::UnsupportedOperationFault(parsed_error.message)),_ => {}
+                                    "ClusterNotFound" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, RestoreTableFromClusterSnapshotError::ClusterNotFoundFault),"ClusterSnapshotNotFound" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, RestoreTableFromClusterSnapshotError::ClusterSnapshotNotFoundFault),"InProgressTableRestoreQuotaExceededFault" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, RestoreTableFromClusterSnapshotError::InProgressTableRestoreQuotaExceededFault),"InvalidClusterSnapshotState" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, RestoreTableFromClusterSnapshotError::InvalidClusterSnapshotStateFault),"InvalidClusterState" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, RestoreTableFromClusterSnapshotError::InvalidClusterStateFault),"InvalidTableRestoreArgument" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, RestoreTableFromClusterSnapshotError::InvalidTableRestoreArgumentFault),"UnsupportedOperation" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, RestoreTableFromClusterSnapshotError::UnsupportedOperationFault),_ => {}
                                 }
             }
         }
@@ -18653,21 +19206,28 @@ impl ResumeClusterError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(ResumeClusterError::ClusterNotFoundFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ResumeClusterError::ClusterNotFoundFault,
+                        )
                     }
                     "InsufficientClusterCapacity" => {
-                        return RusotoError::Service(
-                            ResumeClusterError::InsufficientClusterCapacityFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ResumeClusterError::InsufficientClusterCapacityFault,
                         )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(ResumeClusterError::InvalidClusterStateFault(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ResumeClusterError::InvalidClusterStateFault,
+                        )
                     }
                     _ => {}
                 }
@@ -18718,7 +19278,7 @@ impl RevokeClusterSecurityGroupIngressError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "AuthorizationNotFound" => return RusotoError::Service(RevokeClusterSecurityGroupIngressError::AuthorizationNotFoundFault(parsed_error.message)),"ClusterSecurityGroupNotFound" => return RusotoError::Service(RevokeClusterSecurityGroupIngressError::ClusterSecurityGroupNotFoundFault(parsed_error.message)),"InvalidClusterSecurityGroupState" => return RusotoError::Service(RevokeClusterSecurityGroupIngressError::InvalidClusterSecurityGroupStateFault(parsed_error.message)),_ => {}
+                                    "AuthorizationNotFound" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, RevokeClusterSecurityGroupIngressError::AuthorizationNotFoundFault),"ClusterSecurityGroupNotFound" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, RevokeClusterSecurityGroupIngressError::ClusterSecurityGroupNotFoundFault),"InvalidClusterSecurityGroupState" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, RevokeClusterSecurityGroupIngressError::InvalidClusterSecurityGroupStateFault),_ => {}
                                 }
             }
         }
@@ -18770,24 +19330,27 @@ impl RevokeSnapshotAccessError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "AccessToSnapshotDenied" => {
-                        return RusotoError::Service(
-                            RevokeSnapshotAccessError::AccessToSnapshotDeniedFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RevokeSnapshotAccessError::AccessToSnapshotDeniedFault,
                         )
                     }
                     "AuthorizationNotFound" => {
-                        return RusotoError::Service(
-                            RevokeSnapshotAccessError::AuthorizationNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RevokeSnapshotAccessError::AuthorizationNotFoundFault,
                         )
                     }
                     "ClusterSnapshotNotFound" => {
-                        return RusotoError::Service(
-                            RevokeSnapshotAccessError::ClusterSnapshotNotFoundFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RevokeSnapshotAccessError::ClusterSnapshotNotFoundFault,
                         )
                     }
                     _ => {}
@@ -18842,22 +19405,27 @@ impl RotateEncryptionKeyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ClusterNotFound" => {
-                        return RusotoError::Service(
-                            RotateEncryptionKeyError::ClusterNotFoundFault(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RotateEncryptionKeyError::ClusterNotFoundFault,
                         )
                     }
                     "DependentServiceRequestThrottlingFault" => {
-                        return RusotoError::Service(
-                            RotateEncryptionKeyError::DependentServiceRequestThrottlingFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RotateEncryptionKeyError::DependentServiceRequestThrottlingFault,
                         )
                     }
                     "InvalidClusterState" => {
-                        return RusotoError::Service(
-                            RotateEncryptionKeyError::InvalidClusterStateFault(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RotateEncryptionKeyError::InvalidClusterStateFault,
                         )
                     }
                     _ => {}
@@ -19473,11 +20041,12 @@ pub struct RedshiftClient {
 impl RedshiftClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> RedshiftClient {
         RedshiftClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Redshift"),
         }
     }
 
@@ -19492,12 +20061,32 @@ impl RedshiftClient {
     {
         RedshiftClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Redshift"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> RedshiftClient {
-        RedshiftClient { client, region }
+        RedshiftClient {
+            client,
+            region: region_for_service(region, "Redshift"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> RedshiftClient {
+        RedshiftClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Redshift"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> RedshiftClient {
+        RedshiftClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -19510,6 +20099,7 @@ impl Redshift for RedshiftClient {
     ) -> Result<AcceptReservedNodeExchangeOutputMessage, RusotoError<AcceptReservedNodeExchangeError>>
     {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("AcceptReservedNodeExchange");
         let params = self.new_params("AcceptReservedNodeExchange");
         let mut params = params;
         AcceptReservedNodeExchangeInputMessageSerializer::serialize(&mut params, "", &input);
@@ -19546,6 +20136,7 @@ impl Redshift for RedshiftClient {
         RusotoError<AuthorizeClusterSecurityGroupIngressError>,
     > {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("AuthorizeClusterSecurityGroupIngress");
         let params = self.new_params("AuthorizeClusterSecurityGroupIngress");
         let mut params = params;
         AuthorizeClusterSecurityGroupIngressMessageSerializer::serialize(&mut params, "", &input);
@@ -19582,6 +20173,7 @@ impl Redshift for RedshiftClient {
         input: AuthorizeSnapshotAccessMessage,
     ) -> Result<AuthorizeSnapshotAccessResult, RusotoError<AuthorizeSnapshotAccessError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("AuthorizeSnapshotAccess");
         let params = self.new_params("AuthorizeSnapshotAccess");
         let mut params = params;
         AuthorizeSnapshotAccessMessageSerializer::serialize(&mut params, "", &input);
@@ -19616,6 +20208,7 @@ impl Redshift for RedshiftClient {
     ) -> Result<BatchDeleteClusterSnapshotsResult, RusotoError<BatchDeleteClusterSnapshotsError>>
     {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("BatchDeleteClusterSnapshots");
         let params = self.new_params("BatchDeleteClusterSnapshots");
         let mut params = params;
         BatchDeleteClusterSnapshotsRequestSerializer::serialize(&mut params, "", &input);
@@ -19652,6 +20245,7 @@ impl Redshift for RedshiftClient {
         RusotoError<BatchModifyClusterSnapshotsError>,
     > {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("BatchModifyClusterSnapshots");
         let params = self.new_params("BatchModifyClusterSnapshots");
         let mut params = params;
         BatchModifyClusterSnapshotsMessageSerializer::serialize(&mut params, "", &input);
@@ -19685,6 +20279,7 @@ impl Redshift for RedshiftClient {
         input: CancelResizeMessage,
     ) -> Result<ResizeProgressMessage, RusotoError<CancelResizeError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CancelResize");
         let params = self.new_params("CancelResize");
         let mut params = params;
         CancelResizeMessageSerializer::serialize(&mut params, "", &input);
@@ -19716,6 +20311,7 @@ impl Redshift for RedshiftClient {
         input: CopyClusterSnapshotMessage,
     ) -> Result<CopyClusterSnapshotResult, RusotoError<CopyClusterSnapshotError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CopyClusterSnapshot");
         let params = self.new_params("CopyClusterSnapshot");
         let mut params = params;
         CopyClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -19749,6 +20345,7 @@ impl Redshift for RedshiftClient {
         input: CreateClusterMessage,
     ) -> Result<CreateClusterResult, RusotoError<CreateClusterError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateCluster");
         let params = self.new_params("CreateCluster");
         let mut params = params;
         CreateClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -19781,6 +20378,7 @@ impl Redshift for RedshiftClient {
     ) -> Result<CreateClusterParameterGroupResult, RusotoError<CreateClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateClusterParameterGroup");
         let params = self.new_params("CreateClusterParameterGroup");
         let mut params = params;
         CreateClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -19815,6 +20413,7 @@ impl Redshift for RedshiftClient {
     ) -> Result<CreateClusterSecurityGroupResult, RusotoError<CreateClusterSecurityGroupError>>
     {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateClusterSecurityGroup");
         let params = self.new_params("CreateClusterSecurityGroup");
         let mut params = params;
         CreateClusterSecurityGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -19848,6 +20447,7 @@ impl Redshift for RedshiftClient {
         input: CreateClusterSnapshotMessage,
     ) -> Result<CreateClusterSnapshotResult, RusotoError<CreateClusterSnapshotError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateClusterSnapshot");
         let params = self.new_params("CreateClusterSnapshot");
         let mut params = params;
         CreateClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -19881,6 +20481,7 @@ impl Redshift for RedshiftClient {
         input: CreateClusterSubnetGroupMessage,
     ) -> Result<CreateClusterSubnetGroupResult, RusotoError<CreateClusterSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateClusterSubnetGroup");
         let params = self.new_params("CreateClusterSubnetGroup");
         let mut params = params;
         CreateClusterSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -19914,6 +20515,7 @@ impl Redshift for RedshiftClient {
         input: CreateEventSubscriptionMessage,
     ) -> Result<CreateEventSubscriptionResult, RusotoError<CreateEventSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateEventSubscription");
         let params = self.new_params("CreateEventSubscription");
         let mut params = params;
         CreateEventSubscriptionMessageSerializer::serialize(&mut params, "", &input);
@@ -19948,6 +20550,7 @@ impl Redshift for RedshiftClient {
     ) -> Result<CreateHsmClientCertificateResult, RusotoError<CreateHsmClientCertificateError>>
     {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateHsmClientCertificate");
         let params = self.new_params("CreateHsmClientCertificate");
         let mut params = params;
         CreateHsmClientCertificateMessageSerializer::serialize(&mut params, "", &input);
@@ -19981,6 +20584,7 @@ impl Redshift for RedshiftClient {
         input: CreateHsmConfigurationMessage,
     ) -> Result<CreateHsmConfigurationResult, RusotoError<CreateHsmConfigurationError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateHsmConfiguration");
         let params = self.new_params("CreateHsmConfiguration");
         let mut params = params;
         CreateHsmConfigurationMessageSerializer::serialize(&mut params, "", &input);
@@ -20014,6 +20618,7 @@ impl Redshift for RedshiftClient {
         input: CreateScheduledActionMessage,
     ) -> Result<ScheduledAction, RusotoError<CreateScheduledActionError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateScheduledAction");
         let params = self.new_params("CreateScheduledAction");
         let mut params = params;
         CreateScheduledActionMessageSerializer::serialize(&mut params, "", &input);
@@ -20045,6 +20650,7 @@ impl Redshift for RedshiftClient {
         input: CreateSnapshotCopyGrantMessage,
     ) -> Result<CreateSnapshotCopyGrantResult, RusotoError<CreateSnapshotCopyGrantError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateSnapshotCopyGrant");
         let params = self.new_params("CreateSnapshotCopyGrant");
         let mut params = params;
         CreateSnapshotCopyGrantMessageSerializer::serialize(&mut params, "", &input);
@@ -20078,6 +20684,7 @@ impl Redshift for RedshiftClient {
         input: CreateSnapshotScheduleMessage,
     ) -> Result<SnapshotSchedule, RusotoError<CreateSnapshotScheduleError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateSnapshotSchedule");
         let params = self.new_params("CreateSnapshotSchedule");
         let mut params = params;
         CreateSnapshotScheduleMessageSerializer::serialize(&mut params, "", &input);
@@ -20109,6 +20716,7 @@ impl Redshift for RedshiftClient {
         input: CreateTagsMessage,
     ) -> Result<(), RusotoError<CreateTagsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateTags");
         let params = self.new_params("CreateTags");
         let mut params = params;
         CreateTagsMessageSerializer::serialize(&mut params, "", &input);
@@ -20129,6 +20737,7 @@ impl Redshift for RedshiftClient {
         input: CreateUsageLimitMessage,
     ) -> Result<UsageLimit, RusotoError<CreateUsageLimitError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("CreateUsageLimit");
         let params = self.new_params("CreateUsageLimit");
         let mut params = params;
         CreateUsageLimitMessageSerializer::serialize(&mut params, "", &input);
@@ -20159,6 +20768,7 @@ impl Redshift for RedshiftClient {
         input: DeleteClusterMessage,
     ) -> Result<DeleteClusterResult, RusotoError<DeleteClusterError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteCluster");
         let params = self.new_params("DeleteCluster");
         let mut params = params;
         DeleteClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -20190,6 +20800,7 @@ impl Redshift for RedshiftClient {
         input: DeleteClusterParameterGroupMessage,
     ) -> Result<(), RusotoError<DeleteClusterParameterGroupError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteClusterParameterGroup");
         let params = self.new_params("DeleteClusterParameterGroup");
         let mut params = params;
         DeleteClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -20210,6 +20821,7 @@ impl Redshift for RedshiftClient {
         input: DeleteClusterSecurityGroupMessage,
     ) -> Result<(), RusotoError<DeleteClusterSecurityGroupError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteClusterSecurityGroup");
         let params = self.new_params("DeleteClusterSecurityGroup");
         let mut params = params;
         DeleteClusterSecurityGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -20230,6 +20842,7 @@ impl Redshift for RedshiftClient {
         input: DeleteClusterSnapshotMessage,
     ) -> Result<DeleteClusterSnapshotResult, RusotoError<DeleteClusterSnapshotError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteClusterSnapshot");
         let params = self.new_params("DeleteClusterSnapshot");
         let mut params = params;
         DeleteClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -20263,6 +20876,7 @@ impl Redshift for RedshiftClient {
         input: DeleteClusterSubnetGroupMessage,
     ) -> Result<(), RusotoError<DeleteClusterSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteClusterSubnetGroup");
         let params = self.new_params("DeleteClusterSubnetGroup");
         let mut params = params;
         DeleteClusterSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -20283,6 +20897,7 @@ impl Redshift for RedshiftClient {
         input: DeleteEventSubscriptionMessage,
     ) -> Result<(), RusotoError<DeleteEventSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteEventSubscription");
         let params = self.new_params("DeleteEventSubscription");
         let mut params = params;
         DeleteEventSubscriptionMessageSerializer::serialize(&mut params, "", &input);
@@ -20303,6 +20918,7 @@ impl Redshift for RedshiftClient {
         input: DeleteHsmClientCertificateMessage,
     ) -> Result<(), RusotoError<DeleteHsmClientCertificateError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteHsmClientCertificate");
         let params = self.new_params("DeleteHsmClientCertificate");
         let mut params = params;
         DeleteHsmClientCertificateMessageSerializer::serialize(&mut params, "", &input);
@@ -20323,6 +20939,7 @@ impl Redshift for RedshiftClient {
         input: DeleteHsmConfigurationMessage,
     ) -> Result<(), RusotoError<DeleteHsmConfigurationError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteHsmConfiguration");
         let params = self.new_params("DeleteHsmConfiguration");
         let mut params = params;
         DeleteHsmConfigurationMessageSerializer::serialize(&mut params, "", &input);
@@ -20343,6 +20960,7 @@ impl Redshift for RedshiftClient {
         input: DeleteScheduledActionMessage,
     ) -> Result<(), RusotoError<DeleteScheduledActionError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteScheduledAction");
         let params = self.new_params("DeleteScheduledAction");
         let mut params = params;
         DeleteScheduledActionMessageSerializer::serialize(&mut params, "", &input);
@@ -20363,6 +20981,7 @@ impl Redshift for RedshiftClient {
         input: DeleteSnapshotCopyGrantMessage,
     ) -> Result<(), RusotoError<DeleteSnapshotCopyGrantError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteSnapshotCopyGrant");
         let params = self.new_params("DeleteSnapshotCopyGrant");
         let mut params = params;
         DeleteSnapshotCopyGrantMessageSerializer::serialize(&mut params, "", &input);
@@ -20383,6 +21002,7 @@ impl Redshift for RedshiftClient {
         input: DeleteSnapshotScheduleMessage,
     ) -> Result<(), RusotoError<DeleteSnapshotScheduleError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteSnapshotSchedule");
         let params = self.new_params("DeleteSnapshotSchedule");
         let mut params = params;
         DeleteSnapshotScheduleMessageSerializer::serialize(&mut params, "", &input);
@@ -20403,6 +21023,7 @@ impl Redshift for RedshiftClient {
         input: DeleteTagsMessage,
     ) -> Result<(), RusotoError<DeleteTagsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteTags");
         let params = self.new_params("DeleteTags");
         let mut params = params;
         DeleteTagsMessageSerializer::serialize(&mut params, "", &input);
@@ -20423,6 +21044,7 @@ impl Redshift for RedshiftClient {
         input: DeleteUsageLimitMessage,
     ) -> Result<(), RusotoError<DeleteUsageLimitError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DeleteUsageLimit");
         let params = self.new_params("DeleteUsageLimit");
         let mut params = params;
         DeleteUsageLimitMessageSerializer::serialize(&mut params, "", &input);
@@ -20443,6 +21065,7 @@ impl Redshift for RedshiftClient {
         input: DescribeAccountAttributesMessage,
     ) -> Result<AccountAttributeList, RusotoError<DescribeAccountAttributesError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeAccountAttributes");
         let params = self.new_params("DescribeAccountAttributes");
         let mut params = params;
         DescribeAccountAttributesMessageSerializer::serialize(&mut params, "", &input);
@@ -20476,6 +21099,7 @@ impl Redshift for RedshiftClient {
         input: DescribeClusterDbRevisionsMessage,
     ) -> Result<ClusterDbRevisionsMessage, RusotoError<DescribeClusterDbRevisionsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeClusterDbRevisions");
         let params = self.new_params("DescribeClusterDbRevisions");
         let mut params = params;
         DescribeClusterDbRevisionsMessageSerializer::serialize(&mut params, "", &input);
@@ -20510,6 +21134,7 @@ impl Redshift for RedshiftClient {
     ) -> Result<ClusterParameterGroupsMessage, RusotoError<DescribeClusterParameterGroupsError>>
     {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeClusterParameterGroups");
         let params = self.new_params("DescribeClusterParameterGroups");
         let mut params = params;
         DescribeClusterParameterGroupsMessageSerializer::serialize(&mut params, "", &input);
@@ -20543,6 +21168,7 @@ impl Redshift for RedshiftClient {
         input: DescribeClusterParametersMessage,
     ) -> Result<ClusterParameterGroupDetails, RusotoError<DescribeClusterParametersError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeClusterParameters");
         let params = self.new_params("DescribeClusterParameters");
         let mut params = params;
         DescribeClusterParametersMessageSerializer::serialize(&mut params, "", &input);
@@ -20576,6 +21202,7 @@ impl Redshift for RedshiftClient {
         input: DescribeClusterSecurityGroupsMessage,
     ) -> Result<ClusterSecurityGroupMessage, RusotoError<DescribeClusterSecurityGroupsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeClusterSecurityGroups");
         let params = self.new_params("DescribeClusterSecurityGroups");
         let mut params = params;
         DescribeClusterSecurityGroupsMessageSerializer::serialize(&mut params, "", &input);
@@ -20609,6 +21236,7 @@ impl Redshift for RedshiftClient {
         input: DescribeClusterSnapshotsMessage,
     ) -> Result<SnapshotMessage, RusotoError<DescribeClusterSnapshotsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeClusterSnapshots");
         let params = self.new_params("DescribeClusterSnapshots");
         let mut params = params;
         DescribeClusterSnapshotsMessageSerializer::serialize(&mut params, "", &input);
@@ -20640,6 +21268,7 @@ impl Redshift for RedshiftClient {
         input: DescribeClusterSubnetGroupsMessage,
     ) -> Result<ClusterSubnetGroupMessage, RusotoError<DescribeClusterSubnetGroupsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeClusterSubnetGroups");
         let params = self.new_params("DescribeClusterSubnetGroups");
         let mut params = params;
         DescribeClusterSubnetGroupsMessageSerializer::serialize(&mut params, "", &input);
@@ -20673,6 +21302,7 @@ impl Redshift for RedshiftClient {
         input: DescribeClusterTracksMessage,
     ) -> Result<TrackListMessage, RusotoError<DescribeClusterTracksError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeClusterTracks");
         let params = self.new_params("DescribeClusterTracks");
         let mut params = params;
         DescribeClusterTracksMessageSerializer::serialize(&mut params, "", &input);
@@ -20704,6 +21334,7 @@ impl Redshift for RedshiftClient {
         input: DescribeClusterVersionsMessage,
     ) -> Result<ClusterVersionsMessage, RusotoError<DescribeClusterVersionsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeClusterVersions");
         let params = self.new_params("DescribeClusterVersions");
         let mut params = params;
         DescribeClusterVersionsMessageSerializer::serialize(&mut params, "", &input);
@@ -20737,6 +21368,7 @@ impl Redshift for RedshiftClient {
         input: DescribeClustersMessage,
     ) -> Result<ClustersMessage, RusotoError<DescribeClustersError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeClusters");
         let params = self.new_params("DescribeClusters");
         let mut params = params;
         DescribeClustersMessageSerializer::serialize(&mut params, "", &input);
@@ -20770,6 +21402,7 @@ impl Redshift for RedshiftClient {
         RusotoError<DescribeDefaultClusterParametersError>,
     > {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeDefaultClusterParameters");
         let params = self.new_params("DescribeDefaultClusterParameters");
         let mut params = params;
         DescribeDefaultClusterParametersMessageSerializer::serialize(&mut params, "", &input);
@@ -20806,6 +21439,7 @@ impl Redshift for RedshiftClient {
         input: DescribeEventCategoriesMessage,
     ) -> Result<EventCategoriesMessage, RusotoError<DescribeEventCategoriesError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeEventCategories");
         let params = self.new_params("DescribeEventCategories");
         let mut params = params;
         DescribeEventCategoriesMessageSerializer::serialize(&mut params, "", &input);
@@ -20839,6 +21473,7 @@ impl Redshift for RedshiftClient {
         input: DescribeEventSubscriptionsMessage,
     ) -> Result<EventSubscriptionsMessage, RusotoError<DescribeEventSubscriptionsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeEventSubscriptions");
         let params = self.new_params("DescribeEventSubscriptions");
         let mut params = params;
         DescribeEventSubscriptionsMessageSerializer::serialize(&mut params, "", &input);
@@ -20872,6 +21507,7 @@ impl Redshift for RedshiftClient {
         input: DescribeEventsMessage,
     ) -> Result<EventsMessage, RusotoError<DescribeEventsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeEvents");
         let params = self.new_params("DescribeEvents");
         let mut params = params;
         DescribeEventsMessageSerializer::serialize(&mut params, "", &input);
@@ -20902,6 +21538,7 @@ impl Redshift for RedshiftClient {
         input: DescribeHsmClientCertificatesMessage,
     ) -> Result<HsmClientCertificateMessage, RusotoError<DescribeHsmClientCertificatesError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeHsmClientCertificates");
         let params = self.new_params("DescribeHsmClientCertificates");
         let mut params = params;
         DescribeHsmClientCertificatesMessageSerializer::serialize(&mut params, "", &input);
@@ -20935,6 +21572,7 @@ impl Redshift for RedshiftClient {
         input: DescribeHsmConfigurationsMessage,
     ) -> Result<HsmConfigurationMessage, RusotoError<DescribeHsmConfigurationsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeHsmConfigurations");
         let params = self.new_params("DescribeHsmConfigurations");
         let mut params = params;
         DescribeHsmConfigurationsMessageSerializer::serialize(&mut params, "", &input);
@@ -20968,6 +21606,7 @@ impl Redshift for RedshiftClient {
         input: DescribeLoggingStatusMessage,
     ) -> Result<LoggingStatus, RusotoError<DescribeLoggingStatusError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeLoggingStatus");
         let params = self.new_params("DescribeLoggingStatus");
         let mut params = params;
         DescribeLoggingStatusMessageSerializer::serialize(&mut params, "", &input);
@@ -21000,6 +21639,7 @@ impl Redshift for RedshiftClient {
     ) -> Result<NodeConfigurationOptionsMessage, RusotoError<DescribeNodeConfigurationOptionsError>>
     {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeNodeConfigurationOptions");
         let params = self.new_params("DescribeNodeConfigurationOptions");
         let mut params = params;
         DescribeNodeConfigurationOptionsMessageSerializer::serialize(&mut params, "", &input);
@@ -21037,6 +21677,7 @@ impl Redshift for RedshiftClient {
     ) -> Result<OrderableClusterOptionsMessage, RusotoError<DescribeOrderableClusterOptionsError>>
     {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeOrderableClusterOptions");
         let params = self.new_params("DescribeOrderableClusterOptions");
         let mut params = params;
         DescribeOrderableClusterOptionsMessageSerializer::serialize(&mut params, "", &input);
@@ -21070,6 +21711,7 @@ impl Redshift for RedshiftClient {
         input: DescribeReservedNodeOfferingsMessage,
     ) -> Result<ReservedNodeOfferingsMessage, RusotoError<DescribeReservedNodeOfferingsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeReservedNodeOfferings");
         let params = self.new_params("DescribeReservedNodeOfferings");
         let mut params = params;
         DescribeReservedNodeOfferingsMessageSerializer::serialize(&mut params, "", &input);
@@ -21103,6 +21745,7 @@ impl Redshift for RedshiftClient {
         input: DescribeReservedNodesMessage,
     ) -> Result<ReservedNodesMessage, RusotoError<DescribeReservedNodesError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeReservedNodes");
         let params = self.new_params("DescribeReservedNodes");
         let mut params = params;
         DescribeReservedNodesMessageSerializer::serialize(&mut params, "", &input);
@@ -21136,6 +21779,7 @@ impl Redshift for RedshiftClient {
         input: DescribeResizeMessage,
     ) -> Result<ResizeProgressMessage, RusotoError<DescribeResizeError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeResize");
         let params = self.new_params("DescribeResize");
         let mut params = params;
         DescribeResizeMessageSerializer::serialize(&mut params, "", &input);
@@ -21167,6 +21811,7 @@ impl Redshift for RedshiftClient {
         input: DescribeScheduledActionsMessage,
     ) -> Result<ScheduledActionsMessage, RusotoError<DescribeScheduledActionsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeScheduledActions");
         let params = self.new_params("DescribeScheduledActions");
         let mut params = params;
         DescribeScheduledActionsMessageSerializer::serialize(&mut params, "", &input);
@@ -21200,6 +21845,7 @@ impl Redshift for RedshiftClient {
         input: DescribeSnapshotCopyGrantsMessage,
     ) -> Result<SnapshotCopyGrantMessage, RusotoError<DescribeSnapshotCopyGrantsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeSnapshotCopyGrants");
         let params = self.new_params("DescribeSnapshotCopyGrants");
         let mut params = params;
         DescribeSnapshotCopyGrantsMessageSerializer::serialize(&mut params, "", &input);
@@ -21234,6 +21880,7 @@ impl Redshift for RedshiftClient {
     ) -> Result<DescribeSnapshotSchedulesOutputMessage, RusotoError<DescribeSnapshotSchedulesError>>
     {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeSnapshotSchedules");
         let params = self.new_params("DescribeSnapshotSchedules");
         let mut params = params;
         DescribeSnapshotSchedulesMessageSerializer::serialize(&mut params, "", &input);
@@ -21266,6 +21913,7 @@ impl Redshift for RedshiftClient {
         &self,
     ) -> Result<CustomerStorageMessage, RusotoError<DescribeStorageError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeStorage");
         let params = self.new_params("DescribeStorage");
 
         request.set_payload(Some(serde_urlencoded::to_string(&params).unwrap()));
@@ -21296,6 +21944,7 @@ impl Redshift for RedshiftClient {
         input: DescribeTableRestoreStatusMessage,
     ) -> Result<TableRestoreStatusMessage, RusotoError<DescribeTableRestoreStatusError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeTableRestoreStatus");
         let params = self.new_params("DescribeTableRestoreStatus");
         let mut params = params;
         DescribeTableRestoreStatusMessageSerializer::serialize(&mut params, "", &input);
@@ -21329,6 +21978,7 @@ impl Redshift for RedshiftClient {
         input: DescribeTagsMessage,
     ) -> Result<TaggedResourceListMessage, RusotoError<DescribeTagsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeTags");
         let params = self.new_params("DescribeTags");
         let mut params = params;
         DescribeTagsMessageSerializer::serialize(&mut params, "", &input);
@@ -21360,6 +22010,7 @@ impl Redshift for RedshiftClient {
         input: DescribeUsageLimitsMessage,
     ) -> Result<UsageLimitList, RusotoError<DescribeUsageLimitsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DescribeUsageLimits");
         let params = self.new_params("DescribeUsageLimits");
         let mut params = params;
         DescribeUsageLimitsMessageSerializer::serialize(&mut params, "", &input);
@@ -21391,6 +22042,7 @@ impl Redshift for RedshiftClient {
         input: DisableLoggingMessage,
     ) -> Result<LoggingStatus, RusotoError<DisableLoggingError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DisableLogging");
         let params = self.new_params("DisableLogging");
         let mut params = params;
         DisableLoggingMessageSerializer::serialize(&mut params, "", &input);
@@ -21421,6 +22073,7 @@ impl Redshift for RedshiftClient {
         input: DisableSnapshotCopyMessage,
     ) -> Result<DisableSnapshotCopyResult, RusotoError<DisableSnapshotCopyError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("DisableSnapshotCopy");
         let params = self.new_params("DisableSnapshotCopy");
         let mut params = params;
         DisableSnapshotCopyMessageSerializer::serialize(&mut params, "", &input);
@@ -21454,6 +22107,7 @@ impl Redshift for RedshiftClient {
         input: EnableLoggingMessage,
     ) -> Result<LoggingStatus, RusotoError<EnableLoggingError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("EnableLogging");
         let params = self.new_params("EnableLogging");
         let mut params = params;
         EnableLoggingMessageSerializer::serialize(&mut params, "", &input);
@@ -21484,6 +22138,7 @@ impl Redshift for RedshiftClient {
         input: EnableSnapshotCopyMessage,
     ) -> Result<EnableSnapshotCopyResult, RusotoError<EnableSnapshotCopyError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("EnableSnapshotCopy");
         let params = self.new_params("EnableSnapshotCopy");
         let mut params = params;
         EnableSnapshotCopyMessageSerializer::serialize(&mut params, "", &input);
@@ -21517,6 +22172,7 @@ impl Redshift for RedshiftClient {
         input: GetClusterCredentialsMessage,
     ) -> Result<ClusterCredentials, RusotoError<GetClusterCredentialsError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("GetClusterCredentials");
         let params = self.new_params("GetClusterCredentials");
         let mut params = params;
         GetClusterCredentialsMessageSerializer::serialize(&mut params, "", &input);
@@ -21551,6 +22207,7 @@ impl Redshift for RedshiftClient {
         RusotoError<GetReservedNodeExchangeOfferingsError>,
     > {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("GetReservedNodeExchangeOfferings");
         let params = self.new_params("GetReservedNodeExchangeOfferings");
         let mut params = params;
         GetReservedNodeExchangeOfferingsInputMessageSerializer::serialize(&mut params, "", &input);
@@ -21587,6 +22244,7 @@ impl Redshift for RedshiftClient {
         input: ModifyClusterMessage,
     ) -> Result<ModifyClusterResult, RusotoError<ModifyClusterError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifyCluster");
         let params = self.new_params("ModifyCluster");
         let mut params = params;
         ModifyClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -21618,6 +22276,7 @@ impl Redshift for RedshiftClient {
         input: ModifyClusterDbRevisionMessage,
     ) -> Result<ModifyClusterDbRevisionResult, RusotoError<ModifyClusterDbRevisionError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifyClusterDbRevision");
         let params = self.new_params("ModifyClusterDbRevision");
         let mut params = params;
         ModifyClusterDbRevisionMessageSerializer::serialize(&mut params, "", &input);
@@ -21651,6 +22310,7 @@ impl Redshift for RedshiftClient {
         input: ModifyClusterIamRolesMessage,
     ) -> Result<ModifyClusterIamRolesResult, RusotoError<ModifyClusterIamRolesError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifyClusterIamRoles");
         let params = self.new_params("ModifyClusterIamRoles");
         let mut params = params;
         ModifyClusterIamRolesMessageSerializer::serialize(&mut params, "", &input);
@@ -21684,6 +22344,7 @@ impl Redshift for RedshiftClient {
         input: ModifyClusterMaintenanceMessage,
     ) -> Result<ModifyClusterMaintenanceResult, RusotoError<ModifyClusterMaintenanceError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifyClusterMaintenance");
         let params = self.new_params("ModifyClusterMaintenance");
         let mut params = params;
         ModifyClusterMaintenanceMessageSerializer::serialize(&mut params, "", &input);
@@ -21718,6 +22379,7 @@ impl Redshift for RedshiftClient {
     ) -> Result<ClusterParameterGroupNameMessage, RusotoError<ModifyClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifyClusterParameterGroup");
         let params = self.new_params("ModifyClusterParameterGroup");
         let mut params = params;
         ModifyClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -21751,6 +22413,7 @@ impl Redshift for RedshiftClient {
         input: ModifyClusterSnapshotMessage,
     ) -> Result<ModifyClusterSnapshotResult, RusotoError<ModifyClusterSnapshotError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifyClusterSnapshot");
         let params = self.new_params("ModifyClusterSnapshot");
         let mut params = params;
         ModifyClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -21784,6 +22447,7 @@ impl Redshift for RedshiftClient {
         input: ModifyClusterSnapshotScheduleMessage,
     ) -> Result<(), RusotoError<ModifyClusterSnapshotScheduleError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifyClusterSnapshotSchedule");
         let params = self.new_params("ModifyClusterSnapshotSchedule");
         let mut params = params;
         ModifyClusterSnapshotScheduleMessageSerializer::serialize(&mut params, "", &input);
@@ -21804,6 +22468,7 @@ impl Redshift for RedshiftClient {
         input: ModifyClusterSubnetGroupMessage,
     ) -> Result<ModifyClusterSubnetGroupResult, RusotoError<ModifyClusterSubnetGroupError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifyClusterSubnetGroup");
         let params = self.new_params("ModifyClusterSubnetGroup");
         let mut params = params;
         ModifyClusterSubnetGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -21837,6 +22502,7 @@ impl Redshift for RedshiftClient {
         input: ModifyEventSubscriptionMessage,
     ) -> Result<ModifyEventSubscriptionResult, RusotoError<ModifyEventSubscriptionError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifyEventSubscription");
         let params = self.new_params("ModifyEventSubscription");
         let mut params = params;
         ModifyEventSubscriptionMessageSerializer::serialize(&mut params, "", &input);
@@ -21870,6 +22536,7 @@ impl Redshift for RedshiftClient {
         input: ModifyScheduledActionMessage,
     ) -> Result<ScheduledAction, RusotoError<ModifyScheduledActionError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifyScheduledAction");
         let params = self.new_params("ModifyScheduledAction");
         let mut params = params;
         ModifyScheduledActionMessageSerializer::serialize(&mut params, "", &input);
@@ -21904,6 +22571,7 @@ impl Redshift for RedshiftClient {
         RusotoError<ModifySnapshotCopyRetentionPeriodError>,
     > {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifySnapshotCopyRetentionPeriod");
         let params = self.new_params("ModifySnapshotCopyRetentionPeriod");
         let mut params = params;
         ModifySnapshotCopyRetentionPeriodMessageSerializer::serialize(&mut params, "", &input);
@@ -21940,6 +22608,7 @@ impl Redshift for RedshiftClient {
         input: ModifySnapshotScheduleMessage,
     ) -> Result<SnapshotSchedule, RusotoError<ModifySnapshotScheduleError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifySnapshotSchedule");
         let params = self.new_params("ModifySnapshotSchedule");
         let mut params = params;
         ModifySnapshotScheduleMessageSerializer::serialize(&mut params, "", &input);
@@ -21971,6 +22640,7 @@ impl Redshift for RedshiftClient {
         input: ModifyUsageLimitMessage,
     ) -> Result<UsageLimit, RusotoError<ModifyUsageLimitError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ModifyUsageLimit");
         let params = self.new_params("ModifyUsageLimit");
         let mut params = params;
         ModifyUsageLimitMessageSerializer::serialize(&mut params, "", &input);
@@ -22001,6 +22671,7 @@ impl Redshift for RedshiftClient {
         input: PauseClusterMessage,
     ) -> Result<PauseClusterResult, RusotoError<PauseClusterError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("PauseCluster");
         let params = self.new_params("PauseCluster");
         let mut params = params;
         PauseClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -22032,6 +22703,7 @@ impl Redshift for RedshiftClient {
     ) -> Result<PurchaseReservedNodeOfferingResult, RusotoError<PurchaseReservedNodeOfferingError>>
     {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("PurchaseReservedNodeOffering");
         let params = self.new_params("PurchaseReservedNodeOffering");
         let mut params = params;
         PurchaseReservedNodeOfferingMessageSerializer::serialize(&mut params, "", &input);
@@ -22065,6 +22737,7 @@ impl Redshift for RedshiftClient {
         input: RebootClusterMessage,
     ) -> Result<RebootClusterResult, RusotoError<RebootClusterError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("RebootCluster");
         let params = self.new_params("RebootCluster");
         let mut params = params;
         RebootClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -22097,6 +22770,7 @@ impl Redshift for RedshiftClient {
     ) -> Result<ClusterParameterGroupNameMessage, RusotoError<ResetClusterParameterGroupError>>
     {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ResetClusterParameterGroup");
         let params = self.new_params("ResetClusterParameterGroup");
         let mut params = params;
         ResetClusterParameterGroupMessageSerializer::serialize(&mut params, "", &input);
@@ -22130,6 +22804,7 @@ impl Redshift for RedshiftClient {
         input: ResizeClusterMessage,
     ) -> Result<ResizeClusterResult, RusotoError<ResizeClusterError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ResizeCluster");
         let params = self.new_params("ResizeCluster");
         let mut params = params;
         ResizeClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -22162,6 +22837,7 @@ impl Redshift for RedshiftClient {
     ) -> Result<RestoreFromClusterSnapshotResult, RusotoError<RestoreFromClusterSnapshotError>>
     {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("RestoreFromClusterSnapshot");
         let params = self.new_params("RestoreFromClusterSnapshot");
         let mut params = params;
         RestoreFromClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -22198,6 +22874,7 @@ impl Redshift for RedshiftClient {
         RusotoError<RestoreTableFromClusterSnapshotError>,
     > {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("RestoreTableFromClusterSnapshot");
         let params = self.new_params("RestoreTableFromClusterSnapshot");
         let mut params = params;
         RestoreTableFromClusterSnapshotMessageSerializer::serialize(&mut params, "", &input);
@@ -22231,6 +22908,7 @@ impl Redshift for RedshiftClient {
         input: ResumeClusterMessage,
     ) -> Result<ResumeClusterResult, RusotoError<ResumeClusterError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("ResumeCluster");
         let params = self.new_params("ResumeCluster");
         let mut params = params;
         ResumeClusterMessageSerializer::serialize(&mut params, "", &input);
@@ -22265,6 +22943,7 @@ impl Redshift for RedshiftClient {
         RusotoError<RevokeClusterSecurityGroupIngressError>,
     > {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("RevokeClusterSecurityGroupIngress");
         let params = self.new_params("RevokeClusterSecurityGroupIngress");
         let mut params = params;
         RevokeClusterSecurityGroupIngressMessageSerializer::serialize(&mut params, "", &input);
@@ -22301,6 +22980,7 @@ impl Redshift for RedshiftClient {
         input: RevokeSnapshotAccessMessage,
     ) -> Result<RevokeSnapshotAccessResult, RusotoError<RevokeSnapshotAccessError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("RevokeSnapshotAccess");
         let params = self.new_params("RevokeSnapshotAccess");
         let mut params = params;
         RevokeSnapshotAccessMessageSerializer::serialize(&mut params, "", &input);
@@ -22334,6 +23014,7 @@ impl Redshift for RedshiftClient {
         input: RotateEncryptionKeyMessage,
     ) -> Result<RotateEncryptionKeyResult, RusotoError<RotateEncryptionKeyError>> {
         let mut request = SignedRequest::new("POST", "redshift", &self.region, "/");
+        request.set_operation("RotateEncryptionKey");
         let params = self.new_params("RotateEncryptionKey");
         let mut params = params;
         RotateEncryptionKeyMessageSerializer::serialize(&mut params, "", &input);
@@ -22362,6 +23043,922 @@ impl Redshift for RedshiftClient {
     }
 }
 
+/// A blocking client for the Amazon Redshift API, running the calls of a `RedshiftClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `RedshiftClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct RedshiftBlockingClient {
+    client: RedshiftClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl RedshiftBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> RedshiftBlockingClient {
+        RedshiftBlockingClient::from_client(RedshiftClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> RedshiftBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        RedshiftBlockingClient::from_client(RedshiftClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> RedshiftBlockingClient {
+        RedshiftBlockingClient::from_client(RedshiftClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> RedshiftBlockingClient {
+        RedshiftBlockingClient::from_client(RedshiftClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: RedshiftClient) -> RedshiftBlockingClient {
+        RedshiftBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> RedshiftBlockingClient {
+        RedshiftBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> RedshiftBlockingClient {
+        RedshiftBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Redshift::accept_reserved_node_exchange`].
+    pub fn accept_reserved_node_exchange(
+        &self,
+        input: AcceptReservedNodeExchangeInputMessage,
+    ) -> Result<AcceptReservedNodeExchangeOutputMessage, RusotoError<AcceptReservedNodeExchangeError>>
+    {
+        self.runtime
+            .block_on(self.client.accept_reserved_node_exchange(input))
+    }
+
+    /// Blocking version of [`Redshift::authorize_cluster_security_group_ingress`].
+    pub fn authorize_cluster_security_group_ingress(
+        &self,
+        input: AuthorizeClusterSecurityGroupIngressMessage,
+    ) -> Result<
+        AuthorizeClusterSecurityGroupIngressResult,
+        RusotoError<AuthorizeClusterSecurityGroupIngressError>,
+    > {
+        self.runtime
+            .block_on(self.client.authorize_cluster_security_group_ingress(input))
+    }
+
+    /// Blocking version of [`Redshift::authorize_snapshot_access`].
+    pub fn authorize_snapshot_access(
+        &self,
+        input: AuthorizeSnapshotAccessMessage,
+    ) -> Result<AuthorizeSnapshotAccessResult, RusotoError<AuthorizeSnapshotAccessError>> {
+        self.runtime
+            .block_on(self.client.authorize_snapshot_access(input))
+    }
+
+    /// Blocking version of [`Redshift::batch_delete_cluster_snapshots`].
+    pub fn batch_delete_cluster_snapshots(
+        &self,
+        input: BatchDeleteClusterSnapshotsRequest,
+    ) -> Result<BatchDeleteClusterSnapshotsResult, RusotoError<BatchDeleteClusterSnapshotsError>>
+    {
+        self.runtime
+            .block_on(self.client.batch_delete_cluster_snapshots(input))
+    }
+
+    /// Blocking version of [`Redshift::batch_modify_cluster_snapshots`].
+    pub fn batch_modify_cluster_snapshots(
+        &self,
+        input: BatchModifyClusterSnapshotsMessage,
+    ) -> Result<
+        BatchModifyClusterSnapshotsOutputMessage,
+        RusotoError<BatchModifyClusterSnapshotsError>,
+    > {
+        self.runtime
+            .block_on(self.client.batch_modify_cluster_snapshots(input))
+    }
+
+    /// Blocking version of [`Redshift::cancel_resize`].
+    pub fn cancel_resize(
+        &self,
+        input: CancelResizeMessage,
+    ) -> Result<ResizeProgressMessage, RusotoError<CancelResizeError>> {
+        self.runtime.block_on(self.client.cancel_resize(input))
+    }
+
+    /// Blocking version of [`Redshift::copy_cluster_snapshot`].
+    pub fn copy_cluster_snapshot(
+        &self,
+        input: CopyClusterSnapshotMessage,
+    ) -> Result<CopyClusterSnapshotResult, RusotoError<CopyClusterSnapshotError>> {
+        self.runtime
+            .block_on(self.client.copy_cluster_snapshot(input))
+    }
+
+    /// Blocking version of [`Redshift::create_cluster`].
+    pub fn create_cluster(
+        &self,
+        input: CreateClusterMessage,
+    ) -> Result<CreateClusterResult, RusotoError<CreateClusterError>> {
+        self.runtime.block_on(self.client.create_cluster(input))
+    }
+
+    /// Blocking version of [`Redshift::create_cluster_parameter_group`].
+    pub fn create_cluster_parameter_group(
+        &self,
+        input: CreateClusterParameterGroupMessage,
+    ) -> Result<CreateClusterParameterGroupResult, RusotoError<CreateClusterParameterGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.create_cluster_parameter_group(input))
+    }
+
+    /// Blocking version of [`Redshift::create_cluster_security_group`].
+    pub fn create_cluster_security_group(
+        &self,
+        input: CreateClusterSecurityGroupMessage,
+    ) -> Result<CreateClusterSecurityGroupResult, RusotoError<CreateClusterSecurityGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.create_cluster_security_group(input))
+    }
+
+    /// Blocking version of [`Redshift::create_cluster_snapshot`].
+    pub fn create_cluster_snapshot(
+        &self,
+        input: CreateClusterSnapshotMessage,
+    ) -> Result<CreateClusterSnapshotResult, RusotoError<CreateClusterSnapshotError>> {
+        self.runtime
+            .block_on(self.client.create_cluster_snapshot(input))
+    }
+
+    /// Blocking version of [`Redshift::create_cluster_subnet_group`].
+    pub fn create_cluster_subnet_group(
+        &self,
+        input: CreateClusterSubnetGroupMessage,
+    ) -> Result<CreateClusterSubnetGroupResult, RusotoError<CreateClusterSubnetGroupError>> {
+        self.runtime
+            .block_on(self.client.create_cluster_subnet_group(input))
+    }
+
+    /// Blocking version of [`Redshift::create_event_subscription`].
+    pub fn create_event_subscription(
+        &self,
+        input: CreateEventSubscriptionMessage,
+    ) -> Result<CreateEventSubscriptionResult, RusotoError<CreateEventSubscriptionError>> {
+        self.runtime
+            .block_on(self.client.create_event_subscription(input))
+    }
+
+    /// Blocking version of [`Redshift::create_hsm_client_certificate`].
+    pub fn create_hsm_client_certificate(
+        &self,
+        input: CreateHsmClientCertificateMessage,
+    ) -> Result<CreateHsmClientCertificateResult, RusotoError<CreateHsmClientCertificateError>>
+    {
+        self.runtime
+            .block_on(self.client.create_hsm_client_certificate(input))
+    }
+
+    /// Blocking version of [`Redshift::create_hsm_configuration`].
+    pub fn create_hsm_configuration(
+        &self,
+        input: CreateHsmConfigurationMessage,
+    ) -> Result<CreateHsmConfigurationResult, RusotoError<CreateHsmConfigurationError>> {
+        self.runtime
+            .block_on(self.client.create_hsm_configuration(input))
+    }
+
+    /// Blocking version of [`Redshift::create_scheduled_action`].
+    pub fn create_scheduled_action(
+        &self,
+        input: CreateScheduledActionMessage,
+    ) -> Result<ScheduledAction, RusotoError<CreateScheduledActionError>> {
+        self.runtime
+            .block_on(self.client.create_scheduled_action(input))
+    }
+
+    /// Blocking version of [`Redshift::create_snapshot_copy_grant`].
+    pub fn create_snapshot_copy_grant(
+        &self,
+        input: CreateSnapshotCopyGrantMessage,
+    ) -> Result<CreateSnapshotCopyGrantResult, RusotoError<CreateSnapshotCopyGrantError>> {
+        self.runtime
+            .block_on(self.client.create_snapshot_copy_grant(input))
+    }
+
+    /// Blocking version of [`Redshift::create_snapshot_schedule`].
+    pub fn create_snapshot_schedule(
+        &self,
+        input: CreateSnapshotScheduleMessage,
+    ) -> Result<SnapshotSchedule, RusotoError<CreateSnapshotScheduleError>> {
+        self.runtime
+            .block_on(self.client.create_snapshot_schedule(input))
+    }
+
+    /// Blocking version of [`Redshift::create_tags`].
+    pub fn create_tags(
+        &self,
+        input: CreateTagsMessage,
+    ) -> Result<(), RusotoError<CreateTagsError>> {
+        self.runtime.block_on(self.client.create_tags(input))
+    }
+
+    /// Blocking version of [`Redshift::create_usage_limit`].
+    pub fn create_usage_limit(
+        &self,
+        input: CreateUsageLimitMessage,
+    ) -> Result<UsageLimit, RusotoError<CreateUsageLimitError>> {
+        self.runtime.block_on(self.client.create_usage_limit(input))
+    }
+
+    /// Blocking version of [`Redshift::delete_cluster`].
+    pub fn delete_cluster(
+        &self,
+        input: DeleteClusterMessage,
+    ) -> Result<DeleteClusterResult, RusotoError<DeleteClusterError>> {
+        self.runtime.block_on(self.client.delete_cluster(input))
+    }
+
+    /// Blocking version of [`Redshift::delete_cluster_parameter_group`].
+    pub fn delete_cluster_parameter_group(
+        &self,
+        input: DeleteClusterParameterGroupMessage,
+    ) -> Result<(), RusotoError<DeleteClusterParameterGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_cluster_parameter_group(input))
+    }
+
+    /// Blocking version of [`Redshift::delete_cluster_security_group`].
+    pub fn delete_cluster_security_group(
+        &self,
+        input: DeleteClusterSecurityGroupMessage,
+    ) -> Result<(), RusotoError<DeleteClusterSecurityGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_cluster_security_group(input))
+    }
+
+    /// Blocking version of [`Redshift::delete_cluster_snapshot`].
+    pub fn delete_cluster_snapshot(
+        &self,
+        input: DeleteClusterSnapshotMessage,
+    ) -> Result<DeleteClusterSnapshotResult, RusotoError<DeleteClusterSnapshotError>> {
+        self.runtime
+            .block_on(self.client.delete_cluster_snapshot(input))
+    }
+
+    /// Blocking version of [`Redshift::delete_cluster_subnet_group`].
+    pub fn delete_cluster_subnet_group(
+        &self,
+        input: DeleteClusterSubnetGroupMessage,
+    ) -> Result<(), RusotoError<DeleteClusterSubnetGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_cluster_subnet_group(input))
+    }
+
+    /// Blocking version of [`Redshift::delete_event_subscription`].
+    pub fn delete_event_subscription(
+        &self,
+        input: DeleteEventSubscriptionMessage,
+    ) -> Result<(), RusotoError<DeleteEventSubscriptionError>> {
+        self.runtime
+            .block_on(self.client.delete_event_subscription(input))
+    }
+
+    /// Blocking version of [`Redshift::delete_hsm_client_certificate`].
+    pub fn delete_hsm_client_certificate(
+        &self,
+        input: DeleteHsmClientCertificateMessage,
+    ) -> Result<(), RusotoError<DeleteHsmClientCertificateError>> {
+        self.runtime
+            .block_on(self.client.delete_hsm_client_certificate(input))
+    }
+
+    /// Blocking version of [`Redshift::delete_hsm_configuration`].
+    pub fn delete_hsm_configuration(
+        &self,
+        input: DeleteHsmConfigurationMessage,
+    ) -> Result<(), RusotoError<DeleteHsmConfigurationError>> {
+        self.runtime
+            .block_on(self.client.delete_hsm_configuration(input))
+    }
+
+    /// Blocking version of [`Redshift::delete_scheduled_action`].
+    pub fn delete_scheduled_action(
+        &self,
+        input: DeleteScheduledActionMessage,
+    ) -> Result<(), RusotoError<DeleteScheduledActionError>> {
+        self.runtime
+            .block_on(self.client.delete_scheduled_action(input))
+    }
+
+    /// Blocking version of [`Redshift::delete_snapshot_copy_grant`].
+    pub fn delete_snapshot_copy_grant(
+        &self,
+        input: DeleteSnapshotCopyGrantMessage,
+    ) -> Result<(), RusotoError<DeleteSnapshotCopyGrantError>> {
+        self.runtime
+            .block_on(self.client.delete_snapshot_copy_grant(input))
+    }
+
+    /// Blocking version of [`Redshift::delete_snapshot_schedule`].
+    pub fn delete_snapshot_schedule(
+        &self,
+        input: DeleteSnapshotScheduleMessage,
+    ) -> Result<(), RusotoError<DeleteSnapshotScheduleError>> {
+        self.runtime
+            .block_on(self.client.delete_snapshot_schedule(input))
+    }
+
+    /// Blocking version of [`Redshift::delete_tags`].
+    pub fn delete_tags(
+        &self,
+        input: DeleteTagsMessage,
+    ) -> Result<(), RusotoError<DeleteTagsError>> {
+        self.runtime.block_on(self.client.delete_tags(input))
+    }
+
+    /// Blocking version of [`Redshift::delete_usage_limit`].
+    pub fn delete_usage_limit(
+        &self,
+        input: DeleteUsageLimitMessage,
+    ) -> Result<(), RusotoError<DeleteUsageLimitError>> {
+        self.runtime.block_on(self.client.delete_usage_limit(input))
+    }
+
+    /// Blocking version of [`Redshift::describe_account_attributes`].
+    pub fn describe_account_attributes(
+        &self,
+        input: DescribeAccountAttributesMessage,
+    ) -> Result<AccountAttributeList, RusotoError<DescribeAccountAttributesError>> {
+        self.runtime
+            .block_on(self.client.describe_account_attributes(input))
+    }
+
+    /// Blocking version of [`Redshift::describe_cluster_db_revisions`].
+    pub fn describe_cluster_db_revisions(
+        &self,
+        input: DescribeClusterDbRevisionsMessage,
+    ) -> Result<ClusterDbRevisionsMessage, RusotoError<DescribeClusterDbRevisionsError>> {
+        self.runtime
+            .block_on(self.client.describe_cluster_db_revisions(input))
+    }
+
+    /// Blocking version of [`Redshift::describe_cluster_parameter_groups`].
+    pub fn describe_cluster_parameter_groups(
+        &self,
+        input: DescribeClusterParameterGroupsMessage,
+    ) -> Result<ClusterParameterGroupsMessage, RusotoError<DescribeClusterParameterGroupsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_cluster_parameter_groups(input))
+    }
+
+    /// Blocking version of [`Redshift::describe_cluster_parameters`].
+    pub fn describe_cluster_parameters(
+        &self,
+        input: DescribeClusterParametersMessage,
+    ) -> Result<ClusterParameterGroupDetails, RusotoError<DescribeClusterParametersError>> {
+        self.runtime
+            .block_on(self.client.describe_cluster_parameters(input))
+    }
+
+    /// Blocking version of [`Redshift::describe_cluster_security_groups`].
+    pub fn describe_cluster_security_groups(
+        &self,
+        input: DescribeClusterSecurityGroupsMessage,
+    ) -> Result<ClusterSecurityGroupMessage, RusotoError<DescribeClusterSecurityGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_cluster_security_groups(input))
+    }
+
+    /// Blocking version of [`Redshift::describe_cluster_snapshots`].
+    pub fn describe_cluster_snapshots(
+        &self,
+        input: DescribeClusterSnapshotsMessage,
+    ) -> Result<SnapshotMessage, RusotoError<DescribeClusterSnapshotsError>> {
+        self.runtime
+            .block_on(self.client.describe_cluster_snapshots(input))
+    }
+
+    /// Blocking version of [`Redshift::describe_cluster_subnet_groups`].
+    pub fn describe_cluster_subnet_groups(
+        &self,
+        input: DescribeClusterSubnetGroupsMessage,
+    ) -> Result<ClusterSubnetGroupMessage, RusotoError<DescribeClusterSubnetGroupsError>> {
+        self.runtime
+            .block_on(self.client.describe_cluster_subnet_groups(input))
+    }
+
+    /// Blocking version of [`Redshift::describe_cluster_tracks`].
+    pub fn describe_cluster_tracks(
+        &self,
+        input: DescribeClusterTracksMessage,
+    ) -> Result<TrackListMessage, RusotoError<DescribeClusterTracksError>> {
+        self.runtime
+            .block_on(self.client.describe_cluster_tracks(input))
+    }
+
+    /// Blocking version of [`Redshift::describe_cluster_versions`].
+    pub fn describe_cluster_versions(
+        &self,
+        input: DescribeClusterVersionsMessage,
+    ) -> Result<ClusterVersionsMessage, RusotoError<DescribeClusterVersionsError>> {
+        self.runtime
+            .block_on(self.client.describe_cluster_versions(input))
+    }
+
+    /// Blocking version of [`Redshift::describe_clusters`].
+    pub fn describe_clusters(
+        &self,
+        input: DescribeClustersMessage,
+    ) -> Result<ClustersMessage, RusotoError<DescribeClustersError>> {
+        self.runtime.block_on(self.client.describe_clusters(input))
+    }
+
+    /// Blocking version of [`Redshift::describe_default_cluster_parameters`].
+    pub fn describe_default_cluster_parameters(
+        &self,
+        input: DescribeDefaultClusterParametersMessage,
+    ) -> Result<
+        DescribeDefaultClusterParametersResult,
+        RusotoError<DescribeDefaultClusterParametersError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_default_cluster_parameters(input))
+    }
+
+    /// Blocking version of [`Redshift::describe_event_categories`].
+    pub fn describe_event_categories(
+        &self,
+        input: DescribeEventCategoriesMessage,
+    ) -> Result<EventCategoriesMessage, RusotoError<DescribeEventCategoriesError>> {
+        self.runtime
+            .block_on(self.client.describe_event_categories(input))
+    }
+
+    /// Blocking version of [`Redshift::describe_event_subscriptions`].
+    pub fn describe_event_subscriptions(
+        &self,
+        input: DescribeEventSubscriptionsMessage,
+    ) -> Result<EventSubscriptionsMessage, RusotoError<DescribeEventSubscriptionsError>> {
+        self.runtime
+            .block_on(self.client.describe_event_subscriptions(input))
+    }
+
+    /// Blocking version of [`Redshift::describe_events`].
+    pub fn describe_events(
+        &self,
+        input: DescribeEventsMessage,
+    ) -> Result<EventsMessage, RusotoError<DescribeEventsError>> {
+        self.runtime.block_on(self.client.describe_events(input))
+    }
+
+    /// Blocking version of [`Redshift::describe_hsm_client_certificates`].
+    pub fn describe_hsm_client_certificates(
+        &self,
+        input: DescribeHsmClientCertificatesMessage,
+    ) -> Result<HsmClientCertificateMessage, RusotoError<DescribeHsmClientCertificatesError>> {
+        self.runtime
+            .block_on(self.client.describe_hsm_client_certificates(input))
+    }
+
+    /// Blocking version of [`Redshift::describe_hsm_configurations`].
+    pub fn describe_hsm_configurations(
+        &self,
+        input: DescribeHsmConfigurationsMessage,
+    ) -> Result<HsmConfigurationMessage, RusotoError<DescribeHsmConfigurationsError>> {
+        self.runtime
+            .block_on(self.client.describe_hsm_configurations(input))
+    }
+
+    /// Blocking version of [`Redshift::describe_logging_status`].
+    pub fn describe_logging_status(
+        &self,
+        input: DescribeLoggingStatusMessage,
+    ) -> Result<LoggingStatus, RusotoError<DescribeLoggingStatusError>> {
+        self.runtime
+            .block_on(self.client.describe_logging_status(input))
+    }
+
+    /// Blocking version of [`Redshift::describe_node_configuration_options`].
+    pub fn describe_node_configuration_options(
+        &self,
+        input: DescribeNodeConfigurationOptionsMessage,
+    ) -> Result<NodeConfigurationOptionsMessage, RusotoError<DescribeNodeConfigurationOptionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_node_configuration_options(input))
+    }
+
+    /// Blocking version of [`Redshift::describe_orderable_cluster_options`].
+    pub fn describe_orderable_cluster_options(
+        &self,
+        input: DescribeOrderableClusterOptionsMessage,
+    ) -> Result<OrderableClusterOptionsMessage, RusotoError<DescribeOrderableClusterOptionsError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_orderable_cluster_options(input))
+    }
+
+    /// Blocking version of [`Redshift::describe_reserved_node_offerings`].
+    pub fn describe_reserved_node_offerings(
+        &self,
+        input: DescribeReservedNodeOfferingsMessage,
+    ) -> Result<ReservedNodeOfferingsMessage, RusotoError<DescribeReservedNodeOfferingsError>> {
+        self.runtime
+            .block_on(self.client.describe_reserved_node_offerings(input))
+    }
+
+    /// Blocking version of [`Redshift::describe_reserved_nodes`].
+    pub fn describe_reserved_nodes(
+        &self,
+        input: DescribeReservedNodesMessage,
+    ) -> Result<ReservedNodesMessage, RusotoError<DescribeReservedNodesError>> {
+        self.runtime
+            .block_on(self.client.describe_reserved_nodes(input))
+    }
+
+    /// Blocking version of [`Redshift::describe_resize`].
+    pub fn describe_resize(
+        &self,
+        input: DescribeResizeMessage,
+    ) -> Result<ResizeProgressMessage, RusotoError<DescribeResizeError>> {
+        self.runtime.block_on(self.client.describe_resize(input))
+    }
+
+    /// Blocking version of [`Redshift::describe_scheduled_actions`].
+    pub fn describe_scheduled_actions(
+        &self,
+        input: DescribeScheduledActionsMessage,
+    ) -> Result<ScheduledActionsMessage, RusotoError<DescribeScheduledActionsError>> {
+        self.runtime
+            .block_on(self.client.describe_scheduled_actions(input))
+    }
+
+    /// Blocking version of [`Redshift::describe_snapshot_copy_grants`].
+    pub fn describe_snapshot_copy_grants(
+        &self,
+        input: DescribeSnapshotCopyGrantsMessage,
+    ) -> Result<SnapshotCopyGrantMessage, RusotoError<DescribeSnapshotCopyGrantsError>> {
+        self.runtime
+            .block_on(self.client.describe_snapshot_copy_grants(input))
+    }
+
+    /// Blocking version of [`Redshift::describe_snapshot_schedules`].
+    pub fn describe_snapshot_schedules(
+        &self,
+        input: DescribeSnapshotSchedulesMessage,
+    ) -> Result<DescribeSnapshotSchedulesOutputMessage, RusotoError<DescribeSnapshotSchedulesError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_snapshot_schedules(input))
+    }
+
+    /// Blocking version of [`Redshift::describe_storage`].
+    pub fn describe_storage(
+        &self,
+    ) -> Result<CustomerStorageMessage, RusotoError<DescribeStorageError>> {
+        self.runtime.block_on(self.client.describe_storage())
+    }
+
+    /// Blocking version of [`Redshift::describe_table_restore_status`].
+    pub fn describe_table_restore_status(
+        &self,
+        input: DescribeTableRestoreStatusMessage,
+    ) -> Result<TableRestoreStatusMessage, RusotoError<DescribeTableRestoreStatusError>> {
+        self.runtime
+            .block_on(self.client.describe_table_restore_status(input))
+    }
+
+    /// Blocking version of [`Redshift::describe_tags`].
+    pub fn describe_tags(
+        &self,
+        input: DescribeTagsMessage,
+    ) -> Result<TaggedResourceListMessage, RusotoError<DescribeTagsError>> {
+        self.runtime.block_on(self.client.describe_tags(input))
+    }
+
+    /// Blocking version of [`Redshift::describe_usage_limits`].
+    pub fn describe_usage_limits(
+        &self,
+        input: DescribeUsageLimitsMessage,
+    ) -> Result<UsageLimitList, RusotoError<DescribeUsageLimitsError>> {
+        self.runtime
+            .block_on(self.client.describe_usage_limits(input))
+    }
+
+    /// Blocking version of [`Redshift::disable_logging`].
+    pub fn disable_logging(
+        &self,
+        input: DisableLoggingMessage,
+    ) -> Result<LoggingStatus, RusotoError<DisableLoggingError>> {
+        self.runtime.block_on(self.client.disable_logging(input))
+    }
+
+    /// Blocking version of [`Redshift::disable_snapshot_copy`].
+    pub fn disable_snapshot_copy(
+        &self,
+        input: DisableSnapshotCopyMessage,
+    ) -> Result<DisableSnapshotCopyResult, RusotoError<DisableSnapshotCopyError>> {
+        self.runtime
+            .block_on(self.client.disable_snapshot_copy(input))
+    }
+
+    /// Blocking version of [`Redshift::enable_logging`].
+    pub fn enable_logging(
+        &self,
+        input: EnableLoggingMessage,
+    ) -> Result<LoggingStatus, RusotoError<EnableLoggingError>> {
+        self.runtime.block_on(self.client.enable_logging(input))
+    }
+
+    /// Blocking version of [`Redshift::enable_snapshot_copy`].
+    pub fn enable_snapshot_copy(
+        &self,
+        input: EnableSnapshotCopyMessage,
+    ) -> Result<EnableSnapshotCopyResult, RusotoError<EnableSnapshotCopyError>> {
+        self.runtime
+            .block_on(self.client.enable_snapshot_copy(input))
+    }
+
+    /// Blocking version of [`Redshift::get_cluster_credentials`].
+    pub fn get_cluster_credentials(
+        &self,
+        input: GetClusterCredentialsMessage,
+    ) -> Result<ClusterCredentials, RusotoError<GetClusterCredentialsError>> {
+        self.runtime
+            .block_on(self.client.get_cluster_credentials(input))
+    }
+
+    /// Blocking version of [`Redshift::get_reserved_node_exchange_offerings`].
+    pub fn get_reserved_node_exchange_offerings(
+        &self,
+        input: GetReservedNodeExchangeOfferingsInputMessage,
+    ) -> Result<
+        GetReservedNodeExchangeOfferingsOutputMessage,
+        RusotoError<GetReservedNodeExchangeOfferingsError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_reserved_node_exchange_offerings(input))
+    }
+
+    /// Blocking version of [`Redshift::modify_cluster`].
+    pub fn modify_cluster(
+        &self,
+        input: ModifyClusterMessage,
+    ) -> Result<ModifyClusterResult, RusotoError<ModifyClusterError>> {
+        self.runtime.block_on(self.client.modify_cluster(input))
+    }
+
+    /// Blocking version of [`Redshift::modify_cluster_db_revision`].
+    pub fn modify_cluster_db_revision(
+        &self,
+        input: ModifyClusterDbRevisionMessage,
+    ) -> Result<ModifyClusterDbRevisionResult, RusotoError<ModifyClusterDbRevisionError>> {
+        self.runtime
+            .block_on(self.client.modify_cluster_db_revision(input))
+    }
+
+    /// Blocking version of [`Redshift::modify_cluster_iam_roles`].
+    pub fn modify_cluster_iam_roles(
+        &self,
+        input: ModifyClusterIamRolesMessage,
+    ) -> Result<ModifyClusterIamRolesResult, RusotoError<ModifyClusterIamRolesError>> {
+        self.runtime
+            .block_on(self.client.modify_cluster_iam_roles(input))
+    }
+
+    /// Blocking version of [`Redshift::modify_cluster_maintenance`].
+    pub fn modify_cluster_maintenance(
+        &self,
+        input: ModifyClusterMaintenanceMessage,
+    ) -> Result<ModifyClusterMaintenanceResult, RusotoError<ModifyClusterMaintenanceError>> {
+        self.runtime
+            .block_on(self.client.modify_cluster_maintenance(input))
+    }
+
+    /// Blocking version of [`Redshift::modify_cluster_parameter_group`].
+    pub fn modify_cluster_parameter_group(
+        &self,
+        input: ModifyClusterParameterGroupMessage,
+    ) -> Result<ClusterParameterGroupNameMessage, RusotoError<ModifyClusterParameterGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.modify_cluster_parameter_group(input))
+    }
+
+    /// Blocking version of [`Redshift::modify_cluster_snapshot`].
+    pub fn modify_cluster_snapshot(
+        &self,
+        input: ModifyClusterSnapshotMessage,
+    ) -> Result<ModifyClusterSnapshotResult, RusotoError<ModifyClusterSnapshotError>> {
+        self.runtime
+            .block_on(self.client.modify_cluster_snapshot(input))
+    }
+
+    /// Blocking version of [`Redshift::modify_cluster_snapshot_schedule`].
+    pub fn modify_cluster_snapshot_schedule(
+        &self,
+        input: ModifyClusterSnapshotScheduleMessage,
+    ) -> Result<(), RusotoError<ModifyClusterSnapshotScheduleError>> {
+        self.runtime
+            .block_on(self.client.modify_cluster_snapshot_schedule(input))
+    }
+
+    /// Blocking version of [`Redshift::modify_cluster_subnet_group`].
+    pub fn modify_cluster_subnet_group(
+        &self,
+        input: ModifyClusterSubnetGroupMessage,
+    ) -> Result<ModifyClusterSubnetGroupResult, RusotoError<ModifyClusterSubnetGroupError>> {
+        self.runtime
+            .block_on(self.client.modify_cluster_subnet_group(input))
+    }
+
+    /// Blocking version of [`Redshift::modify_event_subscription`].
+    pub fn modify_event_subscription(
+        &self,
+        input: ModifyEventSubscriptionMessage,
+    ) -> Result<ModifyEventSubscriptionResult, RusotoError<ModifyEventSubscriptionError>> {
+        self.runtime
+            .block_on(self.client.modify_event_subscription(input))
+    }
+
+    /// Blocking version of [`Redshift::modify_scheduled_action`].
+    pub fn modify_scheduled_action(
+        &self,
+        input: ModifyScheduledActionMessage,
+    ) -> Result<ScheduledAction, RusotoError<ModifyScheduledActionError>> {
+        self.runtime
+            .block_on(self.client.modify_scheduled_action(input))
+    }
+
+    /// Blocking version of [`Redshift::modify_snapshot_copy_retention_period`].
+    pub fn modify_snapshot_copy_retention_period(
+        &self,
+        input: ModifySnapshotCopyRetentionPeriodMessage,
+    ) -> Result<
+        ModifySnapshotCopyRetentionPeriodResult,
+        RusotoError<ModifySnapshotCopyRetentionPeriodError>,
+    > {
+        self.runtime
+            .block_on(self.client.modify_snapshot_copy_retention_period(input))
+    }
+
+    /// Blocking version of [`Redshift::modify_snapshot_schedule`].
+    pub fn modify_snapshot_schedule(
+        &self,
+        input: ModifySnapshotScheduleMessage,
+    ) -> Result<SnapshotSchedule, RusotoError<ModifySnapshotScheduleError>> {
+        self.runtime
+            .block_on(self.client.modify_snapshot_schedule(input))
+    }
+
+    /// Blocking version of [`Redshift::modify_usage_limit`].
+    pub fn modify_usage_limit(
+        &self,
+        input: ModifyUsageLimitMessage,
+    ) -> Result<UsageLimit, RusotoError<ModifyUsageLimitError>> {
+        self.runtime.block_on(self.client.modify_usage_limit(input))
+    }
+
+    /// Blocking version of [`Redshift::pause_cluster`].
+    pub fn pause_cluster(
+        &self,
+        input: PauseClusterMessage,
+    ) -> Result<PauseClusterResult, RusotoError<PauseClusterError>> {
+        self.runtime.block_on(self.client.pause_cluster(input))
+    }
+
+    /// Blocking version of [`Redshift::purchase_reserved_node_offering`].
+    pub fn purchase_reserved_node_offering(
+        &self,
+        input: PurchaseReservedNodeOfferingMessage,
+    ) -> Result<PurchaseReservedNodeOfferingResult, RusotoError<PurchaseReservedNodeOfferingError>>
+    {
+        self.runtime
+            .block_on(self.client.purchase_reserved_node_offering(input))
+    }
+
+    /// Blocking version of [`Redshift::reboot_cluster`].
+    pub fn reboot_cluster(
+        &self,
+        input: RebootClusterMessage,
+    ) -> Result<RebootClusterResult, RusotoError<RebootClusterError>> {
+        self.runtime.block_on(self.client.reboot_cluster(input))
+    }
+
+    /// Blocking version of [`Redshift::reset_cluster_parameter_group`].
+    pub fn reset_cluster_parameter_group(
+        &self,
+        input: ResetClusterParameterGroupMessage,
+    ) -> Result<ClusterParameterGroupNameMessage, RusotoError<ResetClusterParameterGroupError>>
+    {
+        self.runtime
+            .block_on(self.client.reset_cluster_parameter_group(input))
+    }
+
+    /// Blocking version of [`Redshift::resize_cluster`].
+    pub fn resize_cluster(
+        &self,
+        input: ResizeClusterMessage,
+    ) -> Result<ResizeClusterResult, RusotoError<ResizeClusterError>> {
+        self.runtime.block_on(self.client.resize_cluster(input))
+    }
+
+    /// Blocking version of [`Redshift::restore_from_cluster_snapshot`].
+    pub fn restore_from_cluster_snapshot(
+        &self,
+        input: RestoreFromClusterSnapshotMessage,
+    ) -> Result<RestoreFromClusterSnapshotResult, RusotoError<RestoreFromClusterSnapshotError>>
+    {
+        self.runtime
+            .block_on(self.client.restore_from_cluster_snapshot(input))
+    }
+
+    /// Blocking version of [`Redshift::restore_table_from_cluster_snapshot`].
+    pub fn restore_table_from_cluster_snapshot(
+        &self,
+        input: RestoreTableFromClusterSnapshotMessage,
+    ) -> Result<
+        RestoreTableFromClusterSnapshotResult,
+        RusotoError<RestoreTableFromClusterSnapshotError>,
+    > {
+        self.runtime
+            .block_on(self.client.restore_table_from_cluster_snapshot(input))
+    }
+
+    /// Blocking version of [`Redshift::resume_cluster`].
+    pub fn resume_cluster(
+        &self,
+        input: ResumeClusterMessage,
+    ) -> Result<ResumeClusterResult, RusotoError<ResumeClusterError>> {
+        self.runtime.block_on(self.client.resume_cluster(input))
+    }
+
+    /// Blocking version of [`Redshift::revoke_cluster_security_group_ingress`].
+    pub fn revoke_cluster_security_group_ingress(
+        &self,
+        input: RevokeClusterSecurityGroupIngressMessage,
+    ) -> Result<
+        RevokeClusterSecurityGroupIngressResult,
+        RusotoError<RevokeClusterSecurityGroupIngressError>,
+    > {
+        self.runtime
+            .block_on(self.client.revoke_cluster_security_group_ingress(input))
+    }
+
+    /// Blocking version of [`Redshift::revoke_snapshot_access`].
+    pub fn revoke_snapshot_access(
+        &self,
+        input: RevokeSnapshotAccessMessage,
+    ) -> Result<RevokeSnapshotAccessResult, RusotoError<RevokeSnapshotAccessError>> {
+        self.runtime
+            .block_on(self.client.revoke_snapshot_access(input))
+    }
+
+    /// Blocking version of [`Redshift::rotate_encryption_key`].
+    pub fn rotate_encryption_key(
+        &self,
+        input: RotateEncryptionKeyMessage,
+    ) -> Result<RotateEncryptionKeyResult, RusotoError<RotateEncryptionKeyError>> {
+        self.runtime
+            .block_on(self.client.rotate_encryption_key(input))
+    }
+}
+
 #[cfg(test)]
 mod protocol_tests {
 
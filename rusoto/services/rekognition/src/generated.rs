@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -2978,32 +2979,70 @@ impl CompareFacesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CompareFacesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CompareFacesError::AccessDenied,
+                    )
                 }
                 "ImageTooLargeException" => {
-                    return RusotoError::Service(CompareFacesError::ImageTooLarge(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CompareFacesError::ImageTooLarge,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CompareFacesError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CompareFacesError::InternalServerError,
+                    )
                 }
                 "InvalidImageFormatException" => {
-                    return RusotoError::Service(CompareFacesError::InvalidImageFormat(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CompareFacesError::InvalidImageFormat,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CompareFacesError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CompareFacesError::InvalidParameter,
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(CompareFacesError::InvalidS3Object(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CompareFacesError::InvalidS3Object,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(CompareFacesError::ProvisionedThroughputExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CompareFacesError::ProvisionedThroughputExceeded,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CompareFacesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CompareFacesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3048,30 +3087,54 @@ impl CreateCollectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateCollectionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCollectionError::AccessDenied,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateCollectionError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCollectionError::InternalServerError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateCollectionError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCollectionError::InvalidParameter,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        CreateCollectionError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCollectionError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateCollectionError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCollectionError::ResourceAlreadyExists,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateCollectionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCollectionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3118,29 +3181,62 @@ impl CreateProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateProjectError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProjectError::AccessDenied,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateProjectError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProjectError::InternalServerError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateProjectError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProjectError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateProjectError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProjectError::LimitExceeded,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(CreateProjectError::ProvisionedThroughputExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateProjectError::ProvisionedThroughputExceeded,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateProjectError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProjectError::ResourceInUse,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateProjectError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProjectError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3188,38 +3284,70 @@ impl CreateProjectVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateProjectVersionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProjectVersionError::AccessDenied,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateProjectVersionError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateProjectVersionError::InternalServerError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateProjectVersionError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateProjectVersionError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateProjectVersionError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProjectVersionError::LimitExceeded,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        CreateProjectVersionError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProjectVersionError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateProjectVersionError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProjectVersionError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateProjectVersionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateProjectVersionError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateProjectVersionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProjectVersionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3268,33 +3396,62 @@ impl CreateStreamProcessorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateStreamProcessorError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStreamProcessorError::AccessDenied,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(CreateStreamProcessorError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateStreamProcessorError::InternalServerError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateStreamProcessorError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateStreamProcessorError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateStreamProcessorError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStreamProcessorError::LimitExceeded,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        CreateStreamProcessorError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStreamProcessorError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(CreateStreamProcessorError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStreamProcessorError::ResourceInUse,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateStreamProcessorError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateStreamProcessorError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3340,28 +3497,54 @@ impl DeleteCollectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteCollectionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCollectionError::AccessDenied,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteCollectionError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteCollectionError::InternalServerError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteCollectionError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCollectionError::InvalidParameter,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        DeleteCollectionError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCollectionError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteCollectionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCollectionError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteCollectionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteCollectionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3406,26 +3589,54 @@ impl DeleteFacesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteFacesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFacesError::AccessDenied,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteFacesError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFacesError::InternalServerError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteFacesError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFacesError::InvalidParameter,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(DeleteFacesError::ProvisionedThroughputExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteFacesError::ProvisionedThroughputExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteFacesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFacesError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteFacesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFacesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3470,29 +3681,62 @@ impl DeleteProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteProjectError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProjectError::AccessDenied,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteProjectError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProjectError::InternalServerError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteProjectError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProjectError::InvalidParameter,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(DeleteProjectError::ProvisionedThroughputExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteProjectError::ProvisionedThroughputExceeded,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteProjectError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProjectError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteProjectError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProjectError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteProjectError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProjectError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3538,35 +3782,62 @@ impl DeleteProjectVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteProjectVersionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProjectVersionError::AccessDenied,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteProjectVersionError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteProjectVersionError::InternalServerError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteProjectVersionError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteProjectVersionError::InvalidParameter,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        DeleteProjectVersionError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProjectVersionError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteProjectVersionError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProjectVersionError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteProjectVersionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteProjectVersionError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteProjectVersionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteProjectVersionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3614,35 +3885,62 @@ impl DeleteStreamProcessorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteStreamProcessorError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteStreamProcessorError::AccessDenied,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DeleteStreamProcessorError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteStreamProcessorError::InternalServerError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteStreamProcessorError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteStreamProcessorError::InvalidParameter,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        DeleteStreamProcessorError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteStreamProcessorError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteStreamProcessorError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteStreamProcessorError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteStreamProcessorError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteStreamProcessorError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteStreamProcessorError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteStreamProcessorError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3688,28 +3986,54 @@ impl DescribeCollectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeCollectionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCollectionError::AccessDenied,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeCollectionError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeCollectionError::InternalServerError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeCollectionError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCollectionError::InvalidParameter,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        DescribeCollectionError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCollectionError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeCollectionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCollectionError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeCollectionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeCollectionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3756,39 +4080,62 @@ impl DescribeProjectVersionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeProjectVersionsError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeProjectVersionsError::AccessDenied,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeProjectVersionsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeProjectVersionsError::InternalServerError,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(
-                        DescribeProjectVersionsError::InvalidPaginationToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeProjectVersionsError::InvalidPaginationToken,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeProjectVersionsError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeProjectVersionsError::InvalidParameter,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        DescribeProjectVersionsError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeProjectVersionsError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeProjectVersionsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeProjectVersionsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeProjectVersionsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeProjectVersionsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3836,30 +4183,54 @@ impl DescribeProjectsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeProjectsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeProjectsError::AccessDenied,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeProjectsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeProjectsError::InternalServerError,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(DescribeProjectsError::InvalidPaginationToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeProjectsError::InvalidPaginationToken,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeProjectsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeProjectsError::InvalidParameter,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        DescribeProjectsError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeProjectsError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeProjectsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeProjectsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3904,34 +4275,54 @@ impl DescribeStreamProcessorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DescribeStreamProcessorError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeStreamProcessorError::AccessDenied,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DescribeStreamProcessorError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeStreamProcessorError::InternalServerError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeStreamProcessorError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeStreamProcessorError::InvalidParameter,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        DescribeStreamProcessorError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeStreamProcessorError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeStreamProcessorError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeStreamProcessorError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeStreamProcessorError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeStreamProcessorError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3986,45 +4377,94 @@ impl DetectCustomLabelsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DetectCustomLabelsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectCustomLabelsError::AccessDenied,
+                    )
                 }
                 "ImageTooLargeException" => {
-                    return RusotoError::Service(DetectCustomLabelsError::ImageTooLarge(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectCustomLabelsError::ImageTooLarge,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DetectCustomLabelsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetectCustomLabelsError::InternalServerError,
+                    )
                 }
                 "InvalidImageFormatException" => {
-                    return RusotoError::Service(DetectCustomLabelsError::InvalidImageFormat(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetectCustomLabelsError::InvalidImageFormat,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DetectCustomLabelsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectCustomLabelsError::InvalidParameter,
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(DetectCustomLabelsError::InvalidS3Object(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectCustomLabelsError::InvalidS3Object,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(DetectCustomLabelsError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectCustomLabelsError::LimitExceeded,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        DetectCustomLabelsError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectCustomLabelsError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DetectCustomLabelsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectCustomLabelsError::ResourceNotFound,
+                    )
                 }
                 "ResourceNotReadyException" => {
-                    return RusotoError::Service(DetectCustomLabelsError::ResourceNotReady(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectCustomLabelsError::ResourceNotReady,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DetectCustomLabelsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectCustomLabelsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4078,32 +4518,70 @@ impl DetectFacesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DetectFacesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectFacesError::AccessDenied,
+                    )
                 }
                 "ImageTooLargeException" => {
-                    return RusotoError::Service(DetectFacesError::ImageTooLarge(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectFacesError::ImageTooLarge,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DetectFacesError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectFacesError::InternalServerError,
+                    )
                 }
                 "InvalidImageFormatException" => {
-                    return RusotoError::Service(DetectFacesError::InvalidImageFormat(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectFacesError::InvalidImageFormat,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DetectFacesError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectFacesError::InvalidParameter,
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(DetectFacesError::InvalidS3Object(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectFacesError::InvalidS3Object,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(DetectFacesError::ProvisionedThroughputExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetectFacesError::ProvisionedThroughputExceeded,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DetectFacesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectFacesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4152,32 +4630,70 @@ impl DetectLabelsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DetectLabelsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectLabelsError::AccessDenied,
+                    )
                 }
                 "ImageTooLargeException" => {
-                    return RusotoError::Service(DetectLabelsError::ImageTooLarge(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectLabelsError::ImageTooLarge,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DetectLabelsError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectLabelsError::InternalServerError,
+                    )
                 }
                 "InvalidImageFormatException" => {
-                    return RusotoError::Service(DetectLabelsError::InvalidImageFormat(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectLabelsError::InvalidImageFormat,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DetectLabelsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectLabelsError::InvalidParameter,
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(DetectLabelsError::InvalidS3Object(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectLabelsError::InvalidS3Object,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(DetectLabelsError::ProvisionedThroughputExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetectLabelsError::ProvisionedThroughputExceeded,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DetectLabelsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectLabelsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4228,47 +4744,78 @@ impl DetectModerationLabelsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DetectModerationLabelsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectModerationLabelsError::AccessDenied,
+                    )
                 }
                 "HumanLoopQuotaExceededException" => {
-                    return RusotoError::Service(
-                        DetectModerationLabelsError::HumanLoopQuotaExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectModerationLabelsError::HumanLoopQuotaExceeded,
                     )
                 }
                 "ImageTooLargeException" => {
-                    return RusotoError::Service(DetectModerationLabelsError::ImageTooLarge(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetectModerationLabelsError::ImageTooLarge,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DetectModerationLabelsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetectModerationLabelsError::InternalServerError,
+                    )
                 }
                 "InvalidImageFormatException" => {
-                    return RusotoError::Service(DetectModerationLabelsError::InvalidImageFormat(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetectModerationLabelsError::InvalidImageFormat,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DetectModerationLabelsError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetectModerationLabelsError::InvalidParameter,
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(DetectModerationLabelsError::InvalidS3Object(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetectModerationLabelsError::InvalidS3Object,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        DetectModerationLabelsError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectModerationLabelsError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DetectModerationLabelsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectModerationLabelsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4322,46 +4869,70 @@ impl DetectProtectiveEquipmentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DetectProtectiveEquipmentError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetectProtectiveEquipmentError::AccessDenied,
+                    )
                 }
                 "ImageTooLargeException" => {
-                    return RusotoError::Service(DetectProtectiveEquipmentError::ImageTooLarge(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetectProtectiveEquipmentError::ImageTooLarge,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        DetectProtectiveEquipmentError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectProtectiveEquipmentError::InternalServerError,
                     )
                 }
                 "InvalidImageFormatException" => {
-                    return RusotoError::Service(
-                        DetectProtectiveEquipmentError::InvalidImageFormat(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectProtectiveEquipmentError::InvalidImageFormat,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DetectProtectiveEquipmentError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetectProtectiveEquipmentError::InvalidParameter,
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(DetectProtectiveEquipmentError::InvalidS3Object(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetectProtectiveEquipmentError::InvalidS3Object,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        DetectProtectiveEquipmentError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectProtectiveEquipmentError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DetectProtectiveEquipmentError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetectProtectiveEquipmentError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4414,32 +4985,70 @@ impl DetectTextError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DetectTextError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectTextError::AccessDenied,
+                    )
                 }
                 "ImageTooLargeException" => {
-                    return RusotoError::Service(DetectTextError::ImageTooLarge(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectTextError::ImageTooLarge,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(DetectTextError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectTextError::InternalServerError,
+                    )
                 }
                 "InvalidImageFormatException" => {
-                    return RusotoError::Service(DetectTextError::InvalidImageFormat(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectTextError::InvalidImageFormat,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DetectTextError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectTextError::InvalidParameter,
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(DetectTextError::InvalidS3Object(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectTextError::InvalidS3Object,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(DetectTextError::ProvisionedThroughputExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DetectTextError::ProvisionedThroughputExceeded,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DetectTextError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DetectTextError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4484,28 +5093,54 @@ impl GetCelebrityInfoError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetCelebrityInfoError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCelebrityInfoError::AccessDenied,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetCelebrityInfoError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCelebrityInfoError::InternalServerError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetCelebrityInfoError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCelebrityInfoError::InvalidParameter,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        GetCelebrityInfoError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCelebrityInfoError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetCelebrityInfoError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCelebrityInfoError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetCelebrityInfoError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCelebrityInfoError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4552,39 +5187,62 @@ impl GetCelebrityRecognitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetCelebrityRecognitionError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCelebrityRecognitionError::AccessDenied,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetCelebrityRecognitionError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCelebrityRecognitionError::InternalServerError,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(
-                        GetCelebrityRecognitionError::InvalidPaginationToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCelebrityRecognitionError::InvalidPaginationToken,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetCelebrityRecognitionError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCelebrityRecognitionError::InvalidParameter,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        GetCelebrityRecognitionError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCelebrityRecognitionError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetCelebrityRecognitionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetCelebrityRecognitionError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetCelebrityRecognitionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetCelebrityRecognitionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4634,37 +5292,62 @@ impl GetContentModerationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetContentModerationError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetContentModerationError::AccessDenied,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetContentModerationError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetContentModerationError::InternalServerError,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(GetContentModerationError::InvalidPaginationToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetContentModerationError::InvalidPaginationToken,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetContentModerationError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetContentModerationError::InvalidParameter,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        GetContentModerationError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetContentModerationError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetContentModerationError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetContentModerationError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetContentModerationError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetContentModerationError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4712,33 +5395,62 @@ impl GetFaceDetectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetFaceDetectionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFaceDetectionError::AccessDenied,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetFaceDetectionError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetFaceDetectionError::InternalServerError,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(GetFaceDetectionError::InvalidPaginationToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetFaceDetectionError::InvalidPaginationToken,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetFaceDetectionError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFaceDetectionError::InvalidParameter,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        GetFaceDetectionError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFaceDetectionError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetFaceDetectionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFaceDetectionError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetFaceDetectionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFaceDetectionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4786,31 +5498,62 @@ impl GetFaceSearchError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetFaceSearchError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFaceSearchError::AccessDenied,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetFaceSearchError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFaceSearchError::InternalServerError,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(GetFaceSearchError::InvalidPaginationToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetFaceSearchError::InvalidPaginationToken,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetFaceSearchError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFaceSearchError::InvalidParameter,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(GetFaceSearchError::ProvisionedThroughputExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetFaceSearchError::ProvisionedThroughputExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetFaceSearchError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFaceSearchError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetFaceSearchError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetFaceSearchError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4856,33 +5599,62 @@ impl GetLabelDetectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetLabelDetectionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLabelDetectionError::AccessDenied,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetLabelDetectionError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetLabelDetectionError::InternalServerError,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(GetLabelDetectionError::InvalidPaginationToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetLabelDetectionError::InvalidPaginationToken,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetLabelDetectionError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLabelDetectionError::InvalidParameter,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        GetLabelDetectionError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLabelDetectionError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetLabelDetectionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLabelDetectionError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetLabelDetectionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetLabelDetectionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4930,33 +5702,62 @@ impl GetPersonTrackingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetPersonTrackingError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPersonTrackingError::AccessDenied,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetPersonTrackingError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetPersonTrackingError::InternalServerError,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(GetPersonTrackingError::InvalidPaginationToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetPersonTrackingError::InvalidPaginationToken,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetPersonTrackingError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPersonTrackingError::InvalidParameter,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        GetPersonTrackingError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPersonTrackingError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetPersonTrackingError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPersonTrackingError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetPersonTrackingError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetPersonTrackingError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5004,37 +5805,62 @@ impl GetSegmentDetectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetSegmentDetectionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSegmentDetectionError::AccessDenied,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetSegmentDetectionError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSegmentDetectionError::InternalServerError,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(GetSegmentDetectionError::InvalidPaginationToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSegmentDetectionError::InvalidPaginationToken,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetSegmentDetectionError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSegmentDetectionError::InvalidParameter,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        GetSegmentDetectionError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSegmentDetectionError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetSegmentDetectionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetSegmentDetectionError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetSegmentDetectionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetSegmentDetectionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5082,33 +5908,62 @@ impl GetTextDetectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetTextDetectionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTextDetectionError::AccessDenied,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(GetTextDetectionError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetTextDetectionError::InternalServerError,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(GetTextDetectionError::InvalidPaginationToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetTextDetectionError::InvalidPaginationToken,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetTextDetectionError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTextDetectionError::InvalidParameter,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        GetTextDetectionError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTextDetectionError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetTextDetectionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTextDetectionError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetTextDetectionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTextDetectionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5162,38 +6017,86 @@ impl IndexFacesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(IndexFacesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        IndexFacesError::AccessDenied,
+                    )
                 }
                 "ImageTooLargeException" => {
-                    return RusotoError::Service(IndexFacesError::ImageTooLarge(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        IndexFacesError::ImageTooLarge,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(IndexFacesError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        IndexFacesError::InternalServerError,
+                    )
                 }
                 "InvalidImageFormatException" => {
-                    return RusotoError::Service(IndexFacesError::InvalidImageFormat(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        IndexFacesError::InvalidImageFormat,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(IndexFacesError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        IndexFacesError::InvalidParameter,
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(IndexFacesError::InvalidS3Object(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        IndexFacesError::InvalidS3Object,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(IndexFacesError::ProvisionedThroughputExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        IndexFacesError::ProvisionedThroughputExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(IndexFacesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        IndexFacesError::ResourceNotFound,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(IndexFacesError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        IndexFacesError::ServiceQuotaExceeded,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(IndexFacesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        IndexFacesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5242,31 +6145,62 @@ impl ListCollectionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListCollectionsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCollectionsError::AccessDenied,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ListCollectionsError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCollectionsError::InternalServerError,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(ListCollectionsError::InvalidPaginationToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListCollectionsError::InvalidPaginationToken,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListCollectionsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCollectionsError::InvalidParameter,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        ListCollectionsError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCollectionsError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListCollectionsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCollectionsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListCollectionsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListCollectionsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5314,29 +6248,62 @@ impl ListFacesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListFacesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFacesError::AccessDenied,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ListFacesError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFacesError::InternalServerError,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(ListFacesError::InvalidPaginationToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFacesError::InvalidPaginationToken,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListFacesError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFacesError::InvalidParameter,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(ListFacesError::ProvisionedThroughputExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListFacesError::ProvisionedThroughputExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListFacesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFacesError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListFacesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFacesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5380,32 +6347,54 @@ impl ListStreamProcessorsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListStreamProcessorsError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListStreamProcessorsError::AccessDenied,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(ListStreamProcessorsError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListStreamProcessorsError::InternalServerError,
+                    )
                 }
                 "InvalidPaginationTokenException" => {
-                    return RusotoError::Service(ListStreamProcessorsError::InvalidPaginationToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListStreamProcessorsError::InvalidPaginationToken,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListStreamProcessorsError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListStreamProcessorsError::InvalidParameter,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        ListStreamProcessorsError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListStreamProcessorsError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListStreamProcessorsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListStreamProcessorsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5454,40 +6443,70 @@ impl RecognizeCelebritiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(RecognizeCelebritiesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RecognizeCelebritiesError::AccessDenied,
+                    )
                 }
                 "ImageTooLargeException" => {
-                    return RusotoError::Service(RecognizeCelebritiesError::ImageTooLarge(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RecognizeCelebritiesError::ImageTooLarge,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(RecognizeCelebritiesError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RecognizeCelebritiesError::InternalServerError,
+                    )
                 }
                 "InvalidImageFormatException" => {
-                    return RusotoError::Service(RecognizeCelebritiesError::InvalidImageFormat(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RecognizeCelebritiesError::InvalidImageFormat,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RecognizeCelebritiesError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RecognizeCelebritiesError::InvalidParameter,
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(RecognizeCelebritiesError::InvalidS3Object(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RecognizeCelebritiesError::InvalidS3Object,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        RecognizeCelebritiesError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RecognizeCelebritiesError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RecognizeCelebritiesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RecognizeCelebritiesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5534,26 +6553,54 @@ impl SearchFacesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(SearchFacesError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchFacesError::AccessDenied,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(SearchFacesError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchFacesError::InternalServerError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SearchFacesError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchFacesError::InvalidParameter,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(SearchFacesError::ProvisionedThroughputExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SearchFacesError::ProvisionedThroughputExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SearchFacesError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchFacesError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SearchFacesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchFacesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5602,39 +6649,78 @@ impl SearchFacesByImageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(SearchFacesByImageError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchFacesByImageError::AccessDenied,
+                    )
                 }
                 "ImageTooLargeException" => {
-                    return RusotoError::Service(SearchFacesByImageError::ImageTooLarge(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchFacesByImageError::ImageTooLarge,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(SearchFacesByImageError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SearchFacesByImageError::InternalServerError,
+                    )
                 }
                 "InvalidImageFormatException" => {
-                    return RusotoError::Service(SearchFacesByImageError::InvalidImageFormat(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SearchFacesByImageError::InvalidImageFormat,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SearchFacesByImageError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchFacesByImageError::InvalidParameter,
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(SearchFacesByImageError::InvalidS3Object(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchFacesByImageError::InvalidS3Object,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        SearchFacesByImageError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchFacesByImageError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SearchFacesByImageError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchFacesByImageError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SearchFacesByImageError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchFacesByImageError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5688,51 +6774,78 @@ impl StartCelebrityRecognitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartCelebrityRecognitionError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartCelebrityRecognitionError::AccessDenied,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        StartCelebrityRecognitionError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartCelebrityRecognitionError::IdempotentParameterMismatch,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(
-                        StartCelebrityRecognitionError::InternalServerError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartCelebrityRecognitionError::InternalServerError,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartCelebrityRecognitionError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartCelebrityRecognitionError::InvalidParameter,
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(StartCelebrityRecognitionError::InvalidS3Object(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartCelebrityRecognitionError::InvalidS3Object,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartCelebrityRecognitionError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartCelebrityRecognitionError::LimitExceeded,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        StartCelebrityRecognitionError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartCelebrityRecognitionError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartCelebrityRecognitionError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartCelebrityRecognitionError::Throttling,
+                    )
                 }
                 "VideoTooLargeException" => {
-                    return RusotoError::Service(StartCelebrityRecognitionError::VideoTooLarge(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartCelebrityRecognitionError::VideoTooLarge,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5790,47 +6903,78 @@ impl StartContentModerationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartContentModerationError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartContentModerationError::AccessDenied,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        StartContentModerationError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartContentModerationError::IdempotentParameterMismatch,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StartContentModerationError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartContentModerationError::InternalServerError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartContentModerationError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartContentModerationError::InvalidParameter,
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(StartContentModerationError::InvalidS3Object(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartContentModerationError::InvalidS3Object,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartContentModerationError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartContentModerationError::LimitExceeded,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        StartContentModerationError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartContentModerationError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartContentModerationError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartContentModerationError::Throttling,
+                    )
                 }
                 "VideoTooLargeException" => {
-                    return RusotoError::Service(StartContentModerationError::VideoTooLarge(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartContentModerationError::VideoTooLarge,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5886,39 +7030,78 @@ impl StartFaceDetectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartFaceDetectionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartFaceDetectionError::AccessDenied,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        StartFaceDetectionError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartFaceDetectionError::IdempotentParameterMismatch,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StartFaceDetectionError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartFaceDetectionError::InternalServerError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartFaceDetectionError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartFaceDetectionError::InvalidParameter,
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(StartFaceDetectionError::InvalidS3Object(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartFaceDetectionError::InvalidS3Object,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartFaceDetectionError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartFaceDetectionError::LimitExceeded,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        StartFaceDetectionError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartFaceDetectionError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartFaceDetectionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartFaceDetectionError::Throttling,
+                    )
                 }
                 "VideoTooLargeException" => {
-                    return RusotoError::Service(StartFaceDetectionError::VideoTooLarge(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartFaceDetectionError::VideoTooLarge,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5976,40 +7159,86 @@ impl StartFaceSearchError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartFaceSearchError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartFaceSearchError::AccessDenied,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(StartFaceSearchError::IdempotentParameterMismatch(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartFaceSearchError::IdempotentParameterMismatch,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StartFaceSearchError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartFaceSearchError::InternalServerError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartFaceSearchError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartFaceSearchError::InvalidParameter,
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(StartFaceSearchError::InvalidS3Object(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartFaceSearchError::InvalidS3Object,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartFaceSearchError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartFaceSearchError::LimitExceeded,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        StartFaceSearchError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartFaceSearchError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartFaceSearchError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartFaceSearchError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartFaceSearchError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartFaceSearchError::Throttling,
+                    )
                 }
                 "VideoTooLargeException" => {
-                    return RusotoError::Service(StartFaceSearchError::VideoTooLarge(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartFaceSearchError::VideoTooLarge,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6064,41 +7293,78 @@ impl StartLabelDetectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartLabelDetectionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartLabelDetectionError::AccessDenied,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        StartLabelDetectionError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartLabelDetectionError::IdempotentParameterMismatch,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StartLabelDetectionError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartLabelDetectionError::InternalServerError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartLabelDetectionError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartLabelDetectionError::InvalidParameter,
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(StartLabelDetectionError::InvalidS3Object(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartLabelDetectionError::InvalidS3Object,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartLabelDetectionError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartLabelDetectionError::LimitExceeded,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        StartLabelDetectionError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartLabelDetectionError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartLabelDetectionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartLabelDetectionError::Throttling,
+                    )
                 }
                 "VideoTooLargeException" => {
-                    return RusotoError::Service(StartLabelDetectionError::VideoTooLarge(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartLabelDetectionError::VideoTooLarge,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6154,41 +7420,78 @@ impl StartPersonTrackingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartPersonTrackingError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartPersonTrackingError::AccessDenied,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        StartPersonTrackingError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartPersonTrackingError::IdempotentParameterMismatch,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StartPersonTrackingError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartPersonTrackingError::InternalServerError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartPersonTrackingError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartPersonTrackingError::InvalidParameter,
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(StartPersonTrackingError::InvalidS3Object(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartPersonTrackingError::InvalidS3Object,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartPersonTrackingError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartPersonTrackingError::LimitExceeded,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        StartPersonTrackingError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartPersonTrackingError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartPersonTrackingError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartPersonTrackingError::Throttling,
+                    )
                 }
                 "VideoTooLargeException" => {
-                    return RusotoError::Service(StartPersonTrackingError::VideoTooLarge(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartPersonTrackingError::VideoTooLarge,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6242,38 +7545,70 @@ impl StartProjectVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartProjectVersionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartProjectVersionError::AccessDenied,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StartProjectVersionError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartProjectVersionError::InternalServerError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartProjectVersionError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartProjectVersionError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartProjectVersionError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartProjectVersionError::LimitExceeded,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        StartProjectVersionError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartProjectVersionError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StartProjectVersionError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartProjectVersionError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartProjectVersionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartProjectVersionError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartProjectVersionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartProjectVersionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6326,43 +7661,78 @@ impl StartSegmentDetectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartSegmentDetectionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartSegmentDetectionError::AccessDenied,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        StartSegmentDetectionError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartSegmentDetectionError::IdempotentParameterMismatch,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StartSegmentDetectionError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartSegmentDetectionError::InternalServerError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartSegmentDetectionError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartSegmentDetectionError::InvalidParameter,
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(StartSegmentDetectionError::InvalidS3Object(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartSegmentDetectionError::InvalidS3Object,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartSegmentDetectionError::LimitExceeded(err.msg))
-                }
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartSegmentDetectionError::LimitExceeded,
+                    )
+                }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        StartSegmentDetectionError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartSegmentDetectionError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartSegmentDetectionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartSegmentDetectionError::Throttling,
+                    )
                 }
                 "VideoTooLargeException" => {
-                    return RusotoError::Service(StartSegmentDetectionError::VideoTooLarge(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartSegmentDetectionError::VideoTooLarge,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6414,35 +7784,62 @@ impl StartStreamProcessorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartStreamProcessorError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartStreamProcessorError::AccessDenied,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StartStreamProcessorError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartStreamProcessorError::InternalServerError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartStreamProcessorError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartStreamProcessorError::InvalidParameter,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        StartStreamProcessorError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartStreamProcessorError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StartStreamProcessorError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartStreamProcessorError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StartStreamProcessorError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartStreamProcessorError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartStreamProcessorError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartStreamProcessorError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6494,39 +7891,78 @@ impl StartTextDetectionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StartTextDetectionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartTextDetectionError::AccessDenied,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        StartTextDetectionError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartTextDetectionError::IdempotentParameterMismatch,
                     )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StartTextDetectionError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartTextDetectionError::InternalServerError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartTextDetectionError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartTextDetectionError::InvalidParameter,
+                    )
                 }
                 "InvalidS3ObjectException" => {
-                    return RusotoError::Service(StartTextDetectionError::InvalidS3Object(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartTextDetectionError::InvalidS3Object,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartTextDetectionError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartTextDetectionError::LimitExceeded,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        StartTextDetectionError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartTextDetectionError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartTextDetectionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartTextDetectionError::Throttling,
+                    )
                 }
                 "VideoTooLargeException" => {
-                    return RusotoError::Service(StartTextDetectionError::VideoTooLarge(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartTextDetectionError::VideoTooLarge,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6578,31 +8014,62 @@ impl StopProjectVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StopProjectVersionError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopProjectVersionError::AccessDenied,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StopProjectVersionError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopProjectVersionError::InternalServerError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StopProjectVersionError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopProjectVersionError::InvalidParameter,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        StopProjectVersionError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopProjectVersionError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StopProjectVersionError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopProjectVersionError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopProjectVersionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopProjectVersionError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StopProjectVersionError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopProjectVersionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6650,35 +8117,62 @@ impl StopStreamProcessorError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(StopStreamProcessorError::AccessDenied(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopStreamProcessorError::AccessDenied,
+                    )
                 }
                 "InternalServerError" => {
-                    return RusotoError::Service(StopStreamProcessorError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopStreamProcessorError::InternalServerError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StopStreamProcessorError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopStreamProcessorError::InvalidParameter,
+                    )
                 }
                 "ProvisionedThroughputExceededException" => {
-                    return RusotoError::Service(
-                        StopStreamProcessorError::ProvisionedThroughputExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopStreamProcessorError::ProvisionedThroughputExceeded,
                     )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(StopStreamProcessorError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopStreamProcessorError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopStreamProcessorError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopStreamProcessorError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StopStreamProcessorError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopStreamProcessorError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -7003,11 +8497,12 @@ pub struct RekognitionClient {
 impl RekognitionClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> RekognitionClient {
         RekognitionClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Rekognition"),
         }
     }
 
@@ -7022,12 +8517,32 @@ impl RekognitionClient {
     {
         RekognitionClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Rekognition"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> RekognitionClient {
-        RekognitionClient { client, region }
+        RekognitionClient {
+            client,
+            region: region_for_service(region, "Rekognition"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> RekognitionClient {
+        RekognitionClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Rekognition"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> RekognitionClient {
+        RekognitionClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -7039,6 +8554,7 @@ impl Rekognition for RekognitionClient {
         input: CompareFacesRequest,
     ) -> Result<CompareFacesResponse, RusotoError<CompareFacesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CompareFaces");
         request.add_header("x-amz-target", "RekognitionService.CompareFaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7057,6 +8573,7 @@ impl Rekognition for RekognitionClient {
         input: CreateCollectionRequest,
     ) -> Result<CreateCollectionResponse, RusotoError<CreateCollectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateCollection");
         request.add_header("x-amz-target", "RekognitionService.CreateCollection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7075,6 +8592,7 @@ impl Rekognition for RekognitionClient {
         input: CreateProjectRequest,
     ) -> Result<CreateProjectResponse, RusotoError<CreateProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateProject");
         request.add_header("x-amz-target", "RekognitionService.CreateProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7093,6 +8611,7 @@ impl Rekognition for RekognitionClient {
         input: CreateProjectVersionRequest,
     ) -> Result<CreateProjectVersionResponse, RusotoError<CreateProjectVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateProjectVersion");
         request.add_header("x-amz-target", "RekognitionService.CreateProjectVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7112,6 +8631,7 @@ impl Rekognition for RekognitionClient {
         input: CreateStreamProcessorRequest,
     ) -> Result<CreateStreamProcessorResponse, RusotoError<CreateStreamProcessorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateStreamProcessor");
         request.add_header("x-amz-target", "RekognitionService.CreateStreamProcessor");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7131,6 +8651,7 @@ impl Rekognition for RekognitionClient {
         input: DeleteCollectionRequest,
     ) -> Result<DeleteCollectionResponse, RusotoError<DeleteCollectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteCollection");
         request.add_header("x-amz-target", "RekognitionService.DeleteCollection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7149,6 +8670,7 @@ impl Rekognition for RekognitionClient {
         input: DeleteFacesRequest,
     ) -> Result<DeleteFacesResponse, RusotoError<DeleteFacesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteFaces");
         request.add_header("x-amz-target", "RekognitionService.DeleteFaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7167,6 +8689,7 @@ impl Rekognition for RekognitionClient {
         input: DeleteProjectRequest,
     ) -> Result<DeleteProjectResponse, RusotoError<DeleteProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteProject");
         request.add_header("x-amz-target", "RekognitionService.DeleteProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7185,6 +8708,7 @@ impl Rekognition for RekognitionClient {
         input: DeleteProjectVersionRequest,
     ) -> Result<DeleteProjectVersionResponse, RusotoError<DeleteProjectVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteProjectVersion");
         request.add_header("x-amz-target", "RekognitionService.DeleteProjectVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7204,6 +8728,7 @@ impl Rekognition for RekognitionClient {
         input: DeleteStreamProcessorRequest,
     ) -> Result<DeleteStreamProcessorResponse, RusotoError<DeleteStreamProcessorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteStreamProcessor");
         request.add_header("x-amz-target", "RekognitionService.DeleteStreamProcessor");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7223,6 +8748,7 @@ impl Rekognition for RekognitionClient {
         input: DescribeCollectionRequest,
     ) -> Result<DescribeCollectionResponse, RusotoError<DescribeCollectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeCollection");
         request.add_header("x-amz-target", "RekognitionService.DescribeCollection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7241,6 +8767,7 @@ impl Rekognition for RekognitionClient {
         input: DescribeProjectVersionsRequest,
     ) -> Result<DescribeProjectVersionsResponse, RusotoError<DescribeProjectVersionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeProjectVersions");
         request.add_header("x-amz-target", "RekognitionService.DescribeProjectVersions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7260,6 +8787,7 @@ impl Rekognition for RekognitionClient {
         input: DescribeProjectsRequest,
     ) -> Result<DescribeProjectsResponse, RusotoError<DescribeProjectsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeProjects");
         request.add_header("x-amz-target", "RekognitionService.DescribeProjects");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7278,6 +8806,7 @@ impl Rekognition for RekognitionClient {
         input: DescribeStreamProcessorRequest,
     ) -> Result<DescribeStreamProcessorResponse, RusotoError<DescribeStreamProcessorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeStreamProcessor");
         request.add_header("x-amz-target", "RekognitionService.DescribeStreamProcessor");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7297,6 +8826,7 @@ impl Rekognition for RekognitionClient {
         input: DetectCustomLabelsRequest,
     ) -> Result<DetectCustomLabelsResponse, RusotoError<DetectCustomLabelsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DetectCustomLabels");
         request.add_header("x-amz-target", "RekognitionService.DetectCustomLabels");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7315,6 +8845,7 @@ impl Rekognition for RekognitionClient {
         input: DetectFacesRequest,
     ) -> Result<DetectFacesResponse, RusotoError<DetectFacesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DetectFaces");
         request.add_header("x-amz-target", "RekognitionService.DetectFaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7333,6 +8864,7 @@ impl Rekognition for RekognitionClient {
         input: DetectLabelsRequest,
     ) -> Result<DetectLabelsResponse, RusotoError<DetectLabelsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DetectLabels");
         request.add_header("x-amz-target", "RekognitionService.DetectLabels");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7351,6 +8883,7 @@ impl Rekognition for RekognitionClient {
         input: DetectModerationLabelsRequest,
     ) -> Result<DetectModerationLabelsResponse, RusotoError<DetectModerationLabelsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DetectModerationLabels");
         request.add_header("x-amz-target", "RekognitionService.DetectModerationLabels");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7371,6 +8904,7 @@ impl Rekognition for RekognitionClient {
     ) -> Result<DetectProtectiveEquipmentResponse, RusotoError<DetectProtectiveEquipmentError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DetectProtectiveEquipment");
         request.add_header(
             "x-amz-target",
             "RekognitionService.DetectProtectiveEquipment",
@@ -7393,6 +8927,7 @@ impl Rekognition for RekognitionClient {
         input: DetectTextRequest,
     ) -> Result<DetectTextResponse, RusotoError<DetectTextError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DetectText");
         request.add_header("x-amz-target", "RekognitionService.DetectText");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7411,6 +8946,7 @@ impl Rekognition for RekognitionClient {
         input: GetCelebrityInfoRequest,
     ) -> Result<GetCelebrityInfoResponse, RusotoError<GetCelebrityInfoError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetCelebrityInfo");
         request.add_header("x-amz-target", "RekognitionService.GetCelebrityInfo");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7429,6 +8965,7 @@ impl Rekognition for RekognitionClient {
         input: GetCelebrityRecognitionRequest,
     ) -> Result<GetCelebrityRecognitionResponse, RusotoError<GetCelebrityRecognitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetCelebrityRecognition");
         request.add_header("x-amz-target", "RekognitionService.GetCelebrityRecognition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7448,6 +8985,7 @@ impl Rekognition for RekognitionClient {
         input: GetContentModerationRequest,
     ) -> Result<GetContentModerationResponse, RusotoError<GetContentModerationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetContentModeration");
         request.add_header("x-amz-target", "RekognitionService.GetContentModeration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7467,6 +9005,7 @@ impl Rekognition for RekognitionClient {
         input: GetFaceDetectionRequest,
     ) -> Result<GetFaceDetectionResponse, RusotoError<GetFaceDetectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetFaceDetection");
         request.add_header("x-amz-target", "RekognitionService.GetFaceDetection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7485,6 +9024,7 @@ impl Rekognition for RekognitionClient {
         input: GetFaceSearchRequest,
     ) -> Result<GetFaceSearchResponse, RusotoError<GetFaceSearchError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetFaceSearch");
         request.add_header("x-amz-target", "RekognitionService.GetFaceSearch");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7503,6 +9043,7 @@ impl Rekognition for RekognitionClient {
         input: GetLabelDetectionRequest,
     ) -> Result<GetLabelDetectionResponse, RusotoError<GetLabelDetectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetLabelDetection");
         request.add_header("x-amz-target", "RekognitionService.GetLabelDetection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7521,6 +9062,7 @@ impl Rekognition for RekognitionClient {
         input: GetPersonTrackingRequest,
     ) -> Result<GetPersonTrackingResponse, RusotoError<GetPersonTrackingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetPersonTracking");
         request.add_header("x-amz-target", "RekognitionService.GetPersonTracking");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7539,6 +9081,7 @@ impl Rekognition for RekognitionClient {
         input: GetSegmentDetectionRequest,
     ) -> Result<GetSegmentDetectionResponse, RusotoError<GetSegmentDetectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetSegmentDetection");
         request.add_header("x-amz-target", "RekognitionService.GetSegmentDetection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7557,6 +9100,7 @@ impl Rekognition for RekognitionClient {
         input: GetTextDetectionRequest,
     ) -> Result<GetTextDetectionResponse, RusotoError<GetTextDetectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetTextDetection");
         request.add_header("x-amz-target", "RekognitionService.GetTextDetection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7575,6 +9119,7 @@ impl Rekognition for RekognitionClient {
         input: IndexFacesRequest,
     ) -> Result<IndexFacesResponse, RusotoError<IndexFacesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("IndexFaces");
         request.add_header("x-amz-target", "RekognitionService.IndexFaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7593,6 +9138,7 @@ impl Rekognition for RekognitionClient {
         input: ListCollectionsRequest,
     ) -> Result<ListCollectionsResponse, RusotoError<ListCollectionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListCollections");
         request.add_header("x-amz-target", "RekognitionService.ListCollections");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7611,6 +9157,7 @@ impl Rekognition for RekognitionClient {
         input: ListFacesRequest,
     ) -> Result<ListFacesResponse, RusotoError<ListFacesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListFaces");
         request.add_header("x-amz-target", "RekognitionService.ListFaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7629,6 +9176,7 @@ impl Rekognition for RekognitionClient {
         input: ListStreamProcessorsRequest,
     ) -> Result<ListStreamProcessorsResponse, RusotoError<ListStreamProcessorsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListStreamProcessors");
         request.add_header("x-amz-target", "RekognitionService.ListStreamProcessors");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7648,6 +9196,7 @@ impl Rekognition for RekognitionClient {
         input: RecognizeCelebritiesRequest,
     ) -> Result<RecognizeCelebritiesResponse, RusotoError<RecognizeCelebritiesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RecognizeCelebrities");
         request.add_header("x-amz-target", "RekognitionService.RecognizeCelebrities");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7667,6 +9216,7 @@ impl Rekognition for RekognitionClient {
         input: SearchFacesRequest,
     ) -> Result<SearchFacesResponse, RusotoError<SearchFacesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SearchFaces");
         request.add_header("x-amz-target", "RekognitionService.SearchFaces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7685,6 +9235,7 @@ impl Rekognition for RekognitionClient {
         input: SearchFacesByImageRequest,
     ) -> Result<SearchFacesByImageResponse, RusotoError<SearchFacesByImageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("SearchFacesByImage");
         request.add_header("x-amz-target", "RekognitionService.SearchFacesByImage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7704,6 +9255,7 @@ impl Rekognition for RekognitionClient {
     ) -> Result<StartCelebrityRecognitionResponse, RusotoError<StartCelebrityRecognitionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartCelebrityRecognition");
         request.add_header(
             "x-amz-target",
             "RekognitionService.StartCelebrityRecognition",
@@ -7726,6 +9278,7 @@ impl Rekognition for RekognitionClient {
         input: StartContentModerationRequest,
     ) -> Result<StartContentModerationResponse, RusotoError<StartContentModerationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartContentModeration");
         request.add_header("x-amz-target", "RekognitionService.StartContentModeration");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7745,6 +9298,7 @@ impl Rekognition for RekognitionClient {
         input: StartFaceDetectionRequest,
     ) -> Result<StartFaceDetectionResponse, RusotoError<StartFaceDetectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartFaceDetection");
         request.add_header("x-amz-target", "RekognitionService.StartFaceDetection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7763,6 +9317,7 @@ impl Rekognition for RekognitionClient {
         input: StartFaceSearchRequest,
     ) -> Result<StartFaceSearchResponse, RusotoError<StartFaceSearchError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartFaceSearch");
         request.add_header("x-amz-target", "RekognitionService.StartFaceSearch");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7781,6 +9336,7 @@ impl Rekognition for RekognitionClient {
         input: StartLabelDetectionRequest,
     ) -> Result<StartLabelDetectionResponse, RusotoError<StartLabelDetectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartLabelDetection");
         request.add_header("x-amz-target", "RekognitionService.StartLabelDetection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7799,6 +9355,7 @@ impl Rekognition for RekognitionClient {
         input: StartPersonTrackingRequest,
     ) -> Result<StartPersonTrackingResponse, RusotoError<StartPersonTrackingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartPersonTracking");
         request.add_header("x-amz-target", "RekognitionService.StartPersonTracking");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7817,6 +9374,7 @@ impl Rekognition for RekognitionClient {
         input: StartProjectVersionRequest,
     ) -> Result<StartProjectVersionResponse, RusotoError<StartProjectVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartProjectVersion");
         request.add_header("x-amz-target", "RekognitionService.StartProjectVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7835,6 +9393,7 @@ impl Rekognition for RekognitionClient {
         input: StartSegmentDetectionRequest,
     ) -> Result<StartSegmentDetectionResponse, RusotoError<StartSegmentDetectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartSegmentDetection");
         request.add_header("x-amz-target", "RekognitionService.StartSegmentDetection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7854,6 +9413,7 @@ impl Rekognition for RekognitionClient {
         input: StartStreamProcessorRequest,
     ) -> Result<StartStreamProcessorResponse, RusotoError<StartStreamProcessorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartStreamProcessor");
         request.add_header("x-amz-target", "RekognitionService.StartStreamProcessor");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7873,6 +9433,7 @@ impl Rekognition for RekognitionClient {
         input: StartTextDetectionRequest,
     ) -> Result<StartTextDetectionResponse, RusotoError<StartTextDetectionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartTextDetection");
         request.add_header("x-amz-target", "RekognitionService.StartTextDetection");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7891,6 +9452,7 @@ impl Rekognition for RekognitionClient {
         input: StopProjectVersionRequest,
     ) -> Result<StopProjectVersionResponse, RusotoError<StopProjectVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopProjectVersion");
         request.add_header("x-amz-target", "RekognitionService.StopProjectVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7909,6 +9471,7 @@ impl Rekognition for RekognitionClient {
         input: StopStreamProcessorRequest,
     ) -> Result<StopStreamProcessorResponse, RusotoError<StopStreamProcessorError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopStreamProcessor");
         request.add_header("x-amz-target", "RekognitionService.StopStreamProcessor");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -7921,3 +9484,493 @@ impl Rekognition for RekognitionClient {
         proto::json::ResponsePayload::new(&response).deserialize::<StopStreamProcessorResponse, _>()
     }
 }
+
+/// A blocking client for the Amazon Rekognition API, running the calls of a `RekognitionClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `RekognitionClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct RekognitionBlockingClient {
+    client: RekognitionClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl RekognitionBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> RekognitionBlockingClient {
+        RekognitionBlockingClient::from_client(RekognitionClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> RekognitionBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        RekognitionBlockingClient::from_client(RekognitionClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> RekognitionBlockingClient {
+        RekognitionBlockingClient::from_client(RekognitionClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> RekognitionBlockingClient {
+        RekognitionBlockingClient::from_client(RekognitionClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: RekognitionClient) -> RekognitionBlockingClient {
+        RekognitionBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> RekognitionBlockingClient {
+        RekognitionBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> RekognitionBlockingClient {
+        RekognitionBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Rekognition::compare_faces`].
+    pub fn compare_faces(
+        &self,
+        input: CompareFacesRequest,
+    ) -> Result<CompareFacesResponse, RusotoError<CompareFacesError>> {
+        self.runtime.block_on(self.client.compare_faces(input))
+    }
+
+    /// Blocking version of [`Rekognition::create_collection`].
+    pub fn create_collection(
+        &self,
+        input: CreateCollectionRequest,
+    ) -> Result<CreateCollectionResponse, RusotoError<CreateCollectionError>> {
+        self.runtime.block_on(self.client.create_collection(input))
+    }
+
+    /// Blocking version of [`Rekognition::create_project`].
+    pub fn create_project(
+        &self,
+        input: CreateProjectRequest,
+    ) -> Result<CreateProjectResponse, RusotoError<CreateProjectError>> {
+        self.runtime.block_on(self.client.create_project(input))
+    }
+
+    /// Blocking version of [`Rekognition::create_project_version`].
+    pub fn create_project_version(
+        &self,
+        input: CreateProjectVersionRequest,
+    ) -> Result<CreateProjectVersionResponse, RusotoError<CreateProjectVersionError>> {
+        self.runtime
+            .block_on(self.client.create_project_version(input))
+    }
+
+    /// Blocking version of [`Rekognition::create_stream_processor`].
+    pub fn create_stream_processor(
+        &self,
+        input: CreateStreamProcessorRequest,
+    ) -> Result<CreateStreamProcessorResponse, RusotoError<CreateStreamProcessorError>> {
+        self.runtime
+            .block_on(self.client.create_stream_processor(input))
+    }
+
+    /// Blocking version of [`Rekognition::delete_collection`].
+    pub fn delete_collection(
+        &self,
+        input: DeleteCollectionRequest,
+    ) -> Result<DeleteCollectionResponse, RusotoError<DeleteCollectionError>> {
+        self.runtime.block_on(self.client.delete_collection(input))
+    }
+
+    /// Blocking version of [`Rekognition::delete_faces`].
+    pub fn delete_faces(
+        &self,
+        input: DeleteFacesRequest,
+    ) -> Result<DeleteFacesResponse, RusotoError<DeleteFacesError>> {
+        self.runtime.block_on(self.client.delete_faces(input))
+    }
+
+    /// Blocking version of [`Rekognition::delete_project`].
+    pub fn delete_project(
+        &self,
+        input: DeleteProjectRequest,
+    ) -> Result<DeleteProjectResponse, RusotoError<DeleteProjectError>> {
+        self.runtime.block_on(self.client.delete_project(input))
+    }
+
+    /// Blocking version of [`Rekognition::delete_project_version`].
+    pub fn delete_project_version(
+        &self,
+        input: DeleteProjectVersionRequest,
+    ) -> Result<DeleteProjectVersionResponse, RusotoError<DeleteProjectVersionError>> {
+        self.runtime
+            .block_on(self.client.delete_project_version(input))
+    }
+
+    /// Blocking version of [`Rekognition::delete_stream_processor`].
+    pub fn delete_stream_processor(
+        &self,
+        input: DeleteStreamProcessorRequest,
+    ) -> Result<DeleteStreamProcessorResponse, RusotoError<DeleteStreamProcessorError>> {
+        self.runtime
+            .block_on(self.client.delete_stream_processor(input))
+    }
+
+    /// Blocking version of [`Rekognition::describe_collection`].
+    pub fn describe_collection(
+        &self,
+        input: DescribeCollectionRequest,
+    ) -> Result<DescribeCollectionResponse, RusotoError<DescribeCollectionError>> {
+        self.runtime
+            .block_on(self.client.describe_collection(input))
+    }
+
+    /// Blocking version of [`Rekognition::describe_project_versions`].
+    pub fn describe_project_versions(
+        &self,
+        input: DescribeProjectVersionsRequest,
+    ) -> Result<DescribeProjectVersionsResponse, RusotoError<DescribeProjectVersionsError>> {
+        self.runtime
+            .block_on(self.client.describe_project_versions(input))
+    }
+
+    /// Blocking version of [`Rekognition::describe_projects`].
+    pub fn describe_projects(
+        &self,
+        input: DescribeProjectsRequest,
+    ) -> Result<DescribeProjectsResponse, RusotoError<DescribeProjectsError>> {
+        self.runtime.block_on(self.client.describe_projects(input))
+    }
+
+    /// Blocking version of [`Rekognition::describe_stream_processor`].
+    pub fn describe_stream_processor(
+        &self,
+        input: DescribeStreamProcessorRequest,
+    ) -> Result<DescribeStreamProcessorResponse, RusotoError<DescribeStreamProcessorError>> {
+        self.runtime
+            .block_on(self.client.describe_stream_processor(input))
+    }
+
+    /// Blocking version of [`Rekognition::detect_custom_labels`].
+    pub fn detect_custom_labels(
+        &self,
+        input: DetectCustomLabelsRequest,
+    ) -> Result<DetectCustomLabelsResponse, RusotoError<DetectCustomLabelsError>> {
+        self.runtime
+            .block_on(self.client.detect_custom_labels(input))
+    }
+
+    /// Blocking version of [`Rekognition::detect_faces`].
+    pub fn detect_faces(
+        &self,
+        input: DetectFacesRequest,
+    ) -> Result<DetectFacesResponse, RusotoError<DetectFacesError>> {
+        self.runtime.block_on(self.client.detect_faces(input))
+    }
+
+    /// Blocking version of [`Rekognition::detect_labels`].
+    pub fn detect_labels(
+        &self,
+        input: DetectLabelsRequest,
+    ) -> Result<DetectLabelsResponse, RusotoError<DetectLabelsError>> {
+        self.runtime.block_on(self.client.detect_labels(input))
+    }
+
+    /// Blocking version of [`Rekognition::detect_moderation_labels`].
+    pub fn detect_moderation_labels(
+        &self,
+        input: DetectModerationLabelsRequest,
+    ) -> Result<DetectModerationLabelsResponse, RusotoError<DetectModerationLabelsError>> {
+        self.runtime
+            .block_on(self.client.detect_moderation_labels(input))
+    }
+
+    /// Blocking version of [`Rekognition::detect_protective_equipment`].
+    pub fn detect_protective_equipment(
+        &self,
+        input: DetectProtectiveEquipmentRequest,
+    ) -> Result<DetectProtectiveEquipmentResponse, RusotoError<DetectProtectiveEquipmentError>>
+    {
+        self.runtime
+            .block_on(self.client.detect_protective_equipment(input))
+    }
+
+    /// Blocking version of [`Rekognition::detect_text`].
+    pub fn detect_text(
+        &self,
+        input: DetectTextRequest,
+    ) -> Result<DetectTextResponse, RusotoError<DetectTextError>> {
+        self.runtime.block_on(self.client.detect_text(input))
+    }
+
+    /// Blocking version of [`Rekognition::get_celebrity_info`].
+    pub fn get_celebrity_info(
+        &self,
+        input: GetCelebrityInfoRequest,
+    ) -> Result<GetCelebrityInfoResponse, RusotoError<GetCelebrityInfoError>> {
+        self.runtime.block_on(self.client.get_celebrity_info(input))
+    }
+
+    /// Blocking version of [`Rekognition::get_celebrity_recognition`].
+    pub fn get_celebrity_recognition(
+        &self,
+        input: GetCelebrityRecognitionRequest,
+    ) -> Result<GetCelebrityRecognitionResponse, RusotoError<GetCelebrityRecognitionError>> {
+        self.runtime
+            .block_on(self.client.get_celebrity_recognition(input))
+    }
+
+    /// Blocking version of [`Rekognition::get_content_moderation`].
+    pub fn get_content_moderation(
+        &self,
+        input: GetContentModerationRequest,
+    ) -> Result<GetContentModerationResponse, RusotoError<GetContentModerationError>> {
+        self.runtime
+            .block_on(self.client.get_content_moderation(input))
+    }
+
+    /// Blocking version of [`Rekognition::get_face_detection`].
+    pub fn get_face_detection(
+        &self,
+        input: GetFaceDetectionRequest,
+    ) -> Result<GetFaceDetectionResponse, RusotoError<GetFaceDetectionError>> {
+        self.runtime.block_on(self.client.get_face_detection(input))
+    }
+
+    /// Blocking version of [`Rekognition::get_face_search`].
+    pub fn get_face_search(
+        &self,
+        input: GetFaceSearchRequest,
+    ) -> Result<GetFaceSearchResponse, RusotoError<GetFaceSearchError>> {
+        self.runtime.block_on(self.client.get_face_search(input))
+    }
+
+    /// Blocking version of [`Rekognition::get_label_detection`].
+    pub fn get_label_detection(
+        &self,
+        input: GetLabelDetectionRequest,
+    ) -> Result<GetLabelDetectionResponse, RusotoError<GetLabelDetectionError>> {
+        self.runtime
+            .block_on(self.client.get_label_detection(input))
+    }
+
+    /// Blocking version of [`Rekognition::get_person_tracking`].
+    pub fn get_person_tracking(
+        &self,
+        input: GetPersonTrackingRequest,
+    ) -> Result<GetPersonTrackingResponse, RusotoError<GetPersonTrackingError>> {
+        self.runtime
+            .block_on(self.client.get_person_tracking(input))
+    }
+
+    /// Blocking version of [`Rekognition::get_segment_detection`].
+    pub fn get_segment_detection(
+        &self,
+        input: GetSegmentDetectionRequest,
+    ) -> Result<GetSegmentDetectionResponse, RusotoError<GetSegmentDetectionError>> {
+        self.runtime
+            .block_on(self.client.get_segment_detection(input))
+    }
+
+    /// Blocking version of [`Rekognition::get_text_detection`].
+    pub fn get_text_detection(
+        &self,
+        input: GetTextDetectionRequest,
+    ) -> Result<GetTextDetectionResponse, RusotoError<GetTextDetectionError>> {
+        self.runtime.block_on(self.client.get_text_detection(input))
+    }
+
+    /// Blocking version of [`Rekognition::index_faces`].
+    pub fn index_faces(
+        &self,
+        input: IndexFacesRequest,
+    ) -> Result<IndexFacesResponse, RusotoError<IndexFacesError>> {
+        self.runtime.block_on(self.client.index_faces(input))
+    }
+
+    /// Blocking version of [`Rekognition::list_collections`].
+    pub fn list_collections(
+        &self,
+        input: ListCollectionsRequest,
+    ) -> Result<ListCollectionsResponse, RusotoError<ListCollectionsError>> {
+        self.runtime.block_on(self.client.list_collections(input))
+    }
+
+    /// Blocking version of [`Rekognition::list_faces`].
+    pub fn list_faces(
+        &self,
+        input: ListFacesRequest,
+    ) -> Result<ListFacesResponse, RusotoError<ListFacesError>> {
+        self.runtime.block_on(self.client.list_faces(input))
+    }
+
+    /// Blocking version of [`Rekognition::list_stream_processors`].
+    pub fn list_stream_processors(
+        &self,
+        input: ListStreamProcessorsRequest,
+    ) -> Result<ListStreamProcessorsResponse, RusotoError<ListStreamProcessorsError>> {
+        self.runtime
+            .block_on(self.client.list_stream_processors(input))
+    }
+
+    /// Blocking version of [`Rekognition::recognize_celebrities`].
+    pub fn recognize_celebrities(
+        &self,
+        input: RecognizeCelebritiesRequest,
+    ) -> Result<RecognizeCelebritiesResponse, RusotoError<RecognizeCelebritiesError>> {
+        self.runtime
+            .block_on(self.client.recognize_celebrities(input))
+    }
+
+    /// Blocking version of [`Rekognition::search_faces`].
+    pub fn search_faces(
+        &self,
+        input: SearchFacesRequest,
+    ) -> Result<SearchFacesResponse, RusotoError<SearchFacesError>> {
+        self.runtime.block_on(self.client.search_faces(input))
+    }
+
+    /// Blocking version of [`Rekognition::search_faces_by_image`].
+    pub fn search_faces_by_image(
+        &self,
+        input: SearchFacesByImageRequest,
+    ) -> Result<SearchFacesByImageResponse, RusotoError<SearchFacesByImageError>> {
+        self.runtime
+            .block_on(self.client.search_faces_by_image(input))
+    }
+
+    /// Blocking version of [`Rekognition::start_celebrity_recognition`].
+    pub fn start_celebrity_recognition(
+        &self,
+        input: StartCelebrityRecognitionRequest,
+    ) -> Result<StartCelebrityRecognitionResponse, RusotoError<StartCelebrityRecognitionError>>
+    {
+        self.runtime
+            .block_on(self.client.start_celebrity_recognition(input))
+    }
+
+    /// Blocking version of [`Rekognition::start_content_moderation`].
+    pub fn start_content_moderation(
+        &self,
+        input: StartContentModerationRequest,
+    ) -> Result<StartContentModerationResponse, RusotoError<StartContentModerationError>> {
+        self.runtime
+            .block_on(self.client.start_content_moderation(input))
+    }
+
+    /// Blocking version of [`Rekognition::start_face_detection`].
+    pub fn start_face_detection(
+        &self,
+        input: StartFaceDetectionRequest,
+    ) -> Result<StartFaceDetectionResponse, RusotoError<StartFaceDetectionError>> {
+        self.runtime
+            .block_on(self.client.start_face_detection(input))
+    }
+
+    /// Blocking version of [`Rekognition::start_face_search`].
+    pub fn start_face_search(
+        &self,
+        input: StartFaceSearchRequest,
+    ) -> Result<StartFaceSearchResponse, RusotoError<StartFaceSearchError>> {
+        self.runtime.block_on(self.client.start_face_search(input))
+    }
+
+    /// Blocking version of [`Rekognition::start_label_detection`].
+    pub fn start_label_detection(
+        &self,
+        input: StartLabelDetectionRequest,
+    ) -> Result<StartLabelDetectionResponse, RusotoError<StartLabelDetectionError>> {
+        self.runtime
+            .block_on(self.client.start_label_detection(input))
+    }
+
+    /// Blocking version of [`Rekognition::start_person_tracking`].
+    pub fn start_person_tracking(
+        &self,
+        input: StartPersonTrackingRequest,
+    ) -> Result<StartPersonTrackingResponse, RusotoError<StartPersonTrackingError>> {
+        self.runtime
+            .block_on(self.client.start_person_tracking(input))
+    }
+
+    /// Blocking version of [`Rekognition::start_project_version`].
+    pub fn start_project_version(
+        &self,
+        input: StartProjectVersionRequest,
+    ) -> Result<StartProjectVersionResponse, RusotoError<StartProjectVersionError>> {
+        self.runtime
+            .block_on(self.client.start_project_version(input))
+    }
+
+    /// Blocking version of [`Rekognition::start_segment_detection`].
+    pub fn start_segment_detection(
+        &self,
+        input: StartSegmentDetectionRequest,
+    ) -> Result<StartSegmentDetectionResponse, RusotoError<StartSegmentDetectionError>> {
+        self.runtime
+            .block_on(self.client.start_segment_detection(input))
+    }
+
+    /// Blocking version of [`Rekognition::start_stream_processor`].
+    pub fn start_stream_processor(
+        &self,
+        input: StartStreamProcessorRequest,
+    ) -> Result<StartStreamProcessorResponse, RusotoError<StartStreamProcessorError>> {
+        self.runtime
+            .block_on(self.client.start_stream_processor(input))
+    }
+
+    /// Blocking version of [`Rekognition::start_text_detection`].
+    pub fn start_text_detection(
+        &self,
+        input: StartTextDetectionRequest,
+    ) -> Result<StartTextDetectionResponse, RusotoError<StartTextDetectionError>> {
+        self.runtime
+            .block_on(self.client.start_text_detection(input))
+    }
+
+    /// Blocking version of [`Rekognition::stop_project_version`].
+    pub fn stop_project_version(
+        &self,
+        input: StopProjectVersionRequest,
+    ) -> Result<StopProjectVersionResponse, RusotoError<StopProjectVersionError>> {
+        self.runtime
+            .block_on(self.client.stop_project_version(input))
+    }
+
+    /// Blocking version of [`Rekognition::stop_stream_processor`].
+    pub fn stop_stream_processor(
+        &self,
+        input: StopStreamProcessorRequest,
+    ) -> Result<StopStreamProcessorResponse, RusotoError<StopStreamProcessorError>> {
+        self.runtime
+            .block_on(self.client.stop_stream_processor(input))
+    }
+}
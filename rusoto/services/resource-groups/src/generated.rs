@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -592,21 +593,46 @@ impl CreateGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(CreateGroupError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGroupError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(CreateGroupError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGroupError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(CreateGroupError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGroupError::InternalServerError,
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(CreateGroupError::MethodNotAllowed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGroupError::MethodNotAllowed,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(CreateGroupError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateGroupError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -648,24 +674,54 @@ impl DeleteGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(DeleteGroupError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGroupError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(DeleteGroupError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGroupError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(DeleteGroupError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGroupError::InternalServerError,
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(DeleteGroupError::MethodNotAllowed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGroupError::MethodNotAllowed,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(DeleteGroupError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGroupError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(DeleteGroupError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteGroupError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -708,24 +764,54 @@ impl GetGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetGroupError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGroupError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetGroupError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGroupError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetGroupError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGroupError::InternalServerError,
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(GetGroupError::MethodNotAllowed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGroupError::MethodNotAllowed,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetGroupError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGroupError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetGroupError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGroupError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -768,30 +854,54 @@ impl GetGroupConfigurationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetGroupConfigurationError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGroupConfigurationError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetGroupConfigurationError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGroupConfigurationError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetGroupConfigurationError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetGroupConfigurationError::InternalServerError,
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(GetGroupConfigurationError::MethodNotAllowed(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetGroupConfigurationError::MethodNotAllowed,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetGroupConfigurationError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGroupConfigurationError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetGroupConfigurationError::TooManyRequests(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetGroupConfigurationError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -834,24 +944,54 @@ impl GetGroupQueryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetGroupQueryError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGroupQueryError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetGroupQueryError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGroupQueryError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetGroupQueryError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGroupQueryError::InternalServerError,
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(GetGroupQueryError::MethodNotAllowed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGroupQueryError::MethodNotAllowed,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetGroupQueryError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGroupQueryError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetGroupQueryError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetGroupQueryError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -894,24 +1034,54 @@ impl GetTagsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GetTagsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTagsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GetTagsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTagsError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GetTagsError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTagsError::InternalServerError,
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(GetTagsError::MethodNotAllowed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTagsError::MethodNotAllowed,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GetTagsError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTagsError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GetTagsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTagsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -954,24 +1124,54 @@ impl GroupResourcesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(GroupResourcesError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GroupResourcesError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(GroupResourcesError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GroupResourcesError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(GroupResourcesError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GroupResourcesError::InternalServerError,
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(GroupResourcesError::MethodNotAllowed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GroupResourcesError::MethodNotAllowed,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(GroupResourcesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GroupResourcesError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(GroupResourcesError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GroupResourcesError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1016,29 +1216,62 @@ impl ListGroupResourcesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListGroupResourcesError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGroupResourcesError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListGroupResourcesError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGroupResourcesError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListGroupResourcesError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListGroupResourcesError::InternalServerError,
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(ListGroupResourcesError::MethodNotAllowed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGroupResourcesError::MethodNotAllowed,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(ListGroupResourcesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGroupResourcesError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListGroupResourcesError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGroupResourcesError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(ListGroupResourcesError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGroupResourcesError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1080,21 +1313,46 @@ impl ListGroupsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(ListGroupsError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGroupsError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(ListGroupsError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGroupsError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(ListGroupsError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGroupsError::InternalServerError,
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(ListGroupsError::MethodNotAllowed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGroupsError::MethodNotAllowed,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(ListGroupsError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListGroupsError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1136,24 +1394,54 @@ impl SearchResourcesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(SearchResourcesError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchResourcesError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(SearchResourcesError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchResourcesError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(SearchResourcesError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchResourcesError::InternalServerError,
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(SearchResourcesError::MethodNotAllowed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchResourcesError::MethodNotAllowed,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(SearchResourcesError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchResourcesError::TooManyRequests,
+                    )
                 }
                 "UnauthorizedException" => {
-                    return RusotoError::Service(SearchResourcesError::Unauthorized(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SearchResourcesError::Unauthorized,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1196,20 +1484,44 @@ impl TagError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(TagError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagError::BadRequest,
+                    )
+                }
+                "ForbiddenException" => {
+                    return RusotoError::service_error(&res, &err.typ, err.msg, TagError::Forbidden)
                 }
-                "ForbiddenException" => return RusotoError::Service(TagError::Forbidden(err.msg)),
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(TagError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagError::InternalServerError,
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(TagError::MethodNotAllowed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagError::MethodNotAllowed,
+                    )
+                }
+                "NotFoundException" => {
+                    return RusotoError::service_error(&res, &err.typ, err.msg, TagError::NotFound)
                 }
-                "NotFoundException" => return RusotoError::Service(TagError::NotFound(err.msg)),
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(TagError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1252,26 +1564,54 @@ impl UngroupResourcesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UngroupResourcesError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UngroupResourcesError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UngroupResourcesError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UngroupResourcesError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UngroupResourcesError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UngroupResourcesError::InternalServerError,
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(UngroupResourcesError::MethodNotAllowed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UngroupResourcesError::MethodNotAllowed,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UngroupResourcesError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UngroupResourcesError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UngroupResourcesError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UngroupResourcesError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1314,22 +1654,54 @@ impl UntagError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UntagError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UntagError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UntagError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagError::InternalServerError,
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(UntagError::MethodNotAllowed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagError::MethodNotAllowed,
+                    )
+                }
+                "NotFoundException" => {
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagError::NotFound,
+                    )
                 }
-                "NotFoundException" => return RusotoError::Service(UntagError::NotFound(err.msg)),
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UntagError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1372,24 +1744,54 @@ impl UpdateGroupError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateGroupError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGroupError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateGroupError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGroupError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateGroupError::InternalServerError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGroupError::InternalServerError,
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(UpdateGroupError::MethodNotAllowed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGroupError::MethodNotAllowed,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateGroupError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGroupError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateGroupError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGroupError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1432,26 +1834,54 @@ impl UpdateGroupQueryError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "BadRequestException" => {
-                    return RusotoError::Service(UpdateGroupQueryError::BadRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGroupQueryError::BadRequest,
+                    )
                 }
                 "ForbiddenException" => {
-                    return RusotoError::Service(UpdateGroupQueryError::Forbidden(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGroupQueryError::Forbidden,
+                    )
                 }
                 "InternalServerErrorException" => {
-                    return RusotoError::Service(UpdateGroupQueryError::InternalServerError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateGroupQueryError::InternalServerError,
+                    )
                 }
                 "MethodNotAllowedException" => {
-                    return RusotoError::Service(UpdateGroupQueryError::MethodNotAllowed(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGroupQueryError::MethodNotAllowed,
+                    )
                 }
                 "NotFoundException" => {
-                    return RusotoError::Service(UpdateGroupQueryError::NotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGroupQueryError::NotFound,
+                    )
                 }
                 "TooManyRequestsException" => {
-                    return RusotoError::Service(UpdateGroupQueryError::TooManyRequests(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateGroupQueryError::TooManyRequests,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1569,11 +1999,12 @@ pub struct ResourceGroupsClient {
 impl ResourceGroupsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> ResourceGroupsClient {
         ResourceGroupsClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Resource Groups"),
         }
     }
 
@@ -1588,12 +2019,32 @@ impl ResourceGroupsClient {
     {
         ResourceGroupsClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Resource Groups"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> ResourceGroupsClient {
-        ResourceGroupsClient { client, region }
+        ResourceGroupsClient {
+            client,
+            region: region_for_service(region, "Resource Groups"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> ResourceGroupsClient {
+        ResourceGroupsClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Resource Groups"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ResourceGroupsClient {
+        ResourceGroupsClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -1608,6 +2059,7 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = "/groups";
 
         let mut request = SignedRequest::new("POST", "resource-groups", &self.region, &request_uri);
+        request.set_operation("CreateGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1639,6 +2091,7 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = "/delete-group";
 
         let mut request = SignedRequest::new("POST", "resource-groups", &self.region, &request_uri);
+        request.set_operation("DeleteGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1670,6 +2123,7 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = "/get-group";
 
         let mut request = SignedRequest::new("POST", "resource-groups", &self.region, &request_uri);
+        request.set_operation("GetGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1701,6 +2155,7 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = "/get-group-configuration";
 
         let mut request = SignedRequest::new("POST", "resource-groups", &self.region, &request_uri);
+        request.set_operation("GetGroupConfiguration");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1732,6 +2187,7 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = "/get-group-query";
 
         let mut request = SignedRequest::new("POST", "resource-groups", &self.region, &request_uri);
+        request.set_operation("GetGroupQuery");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1763,6 +2219,7 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = format!("/resources/{arn}/tags", arn = input.arn);
 
         let mut request = SignedRequest::new("GET", "resource-groups", &self.region, &request_uri);
+        request.set_operation("GetTags");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -1791,6 +2248,7 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = "/group-resources";
 
         let mut request = SignedRequest::new("POST", "resource-groups", &self.region, &request_uri);
+        request.set_operation("GroupResources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1822,6 +2280,7 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = "/list-group-resources";
 
         let mut request = SignedRequest::new("POST", "resource-groups", &self.region, &request_uri);
+        request.set_operation("ListGroupResources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1853,6 +2312,7 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = "/groups-list";
 
         let mut request = SignedRequest::new("POST", "resource-groups", &self.region, &request_uri);
+        request.set_operation("ListGroups");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1893,6 +2353,7 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = "/resources/search";
 
         let mut request = SignedRequest::new("POST", "resource-groups", &self.region, &request_uri);
+        request.set_operation("SearchResources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1921,6 +2382,7 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = format!("/resources/{arn}/tags", arn = input.arn);
 
         let mut request = SignedRequest::new("PUT", "resource-groups", &self.region, &request_uri);
+        request.set_operation("Tag");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1952,6 +2414,7 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = "/ungroup-resources";
 
         let mut request = SignedRequest::new("POST", "resource-groups", &self.region, &request_uri);
+        request.set_operation("UngroupResources");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -1981,6 +2444,7 @@ impl ResourceGroups for ResourceGroupsClient {
 
         let mut request =
             SignedRequest::new("PATCH", "resource-groups", &self.region, &request_uri);
+        request.set_operation("Untag");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2012,6 +2476,7 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = "/update-group";
 
         let mut request = SignedRequest::new("POST", "resource-groups", &self.region, &request_uri);
+        request.set_operation("UpdateGroup");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2043,6 +2508,7 @@ impl ResourceGroups for ResourceGroupsClient {
         let request_uri = "/update-group-query";
 
         let mut request = SignedRequest::new("POST", "resource-groups", &self.region, &request_uri);
+        request.set_operation("UpdateGroupQuery");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -2065,3 +2531,199 @@ impl ResourceGroups for ResourceGroupsClient {
         }
     }
 }
+
+/// A blocking client for the Resource Groups API, running the calls of a `ResourceGroupsClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `ResourceGroupsClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ResourceGroupsBlockingClient {
+    client: ResourceGroupsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ResourceGroupsBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> ResourceGroupsBlockingClient {
+        ResourceGroupsBlockingClient::from_client(ResourceGroupsClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ResourceGroupsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ResourceGroupsBlockingClient::from_client(ResourceGroupsClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> ResourceGroupsBlockingClient {
+        ResourceGroupsBlockingClient::from_client(ResourceGroupsClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> ResourceGroupsBlockingClient {
+        ResourceGroupsBlockingClient::from_client(ResourceGroupsClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: ResourceGroupsClient) -> ResourceGroupsBlockingClient {
+        ResourceGroupsBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> ResourceGroupsBlockingClient {
+        ResourceGroupsBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ResourceGroupsBlockingClient {
+        ResourceGroupsBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`ResourceGroups::create_group`].
+    pub fn create_group(
+        &self,
+        input: CreateGroupInput,
+    ) -> Result<CreateGroupOutput, RusotoError<CreateGroupError>> {
+        self.runtime.block_on(self.client.create_group(input))
+    }
+
+    /// Blocking version of [`ResourceGroups::delete_group`].
+    pub fn delete_group(
+        &self,
+        input: DeleteGroupInput,
+    ) -> Result<DeleteGroupOutput, RusotoError<DeleteGroupError>> {
+        self.runtime.block_on(self.client.delete_group(input))
+    }
+
+    /// Blocking version of [`ResourceGroups::get_group`].
+    pub fn get_group(
+        &self,
+        input: GetGroupInput,
+    ) -> Result<GetGroupOutput, RusotoError<GetGroupError>> {
+        self.runtime.block_on(self.client.get_group(input))
+    }
+
+    /// Blocking version of [`ResourceGroups::get_group_configuration`].
+    pub fn get_group_configuration(
+        &self,
+        input: GetGroupConfigurationInput,
+    ) -> Result<GetGroupConfigurationOutput, RusotoError<GetGroupConfigurationError>> {
+        self.runtime
+            .block_on(self.client.get_group_configuration(input))
+    }
+
+    /// Blocking version of [`ResourceGroups::get_group_query`].
+    pub fn get_group_query(
+        &self,
+        input: GetGroupQueryInput,
+    ) -> Result<GetGroupQueryOutput, RusotoError<GetGroupQueryError>> {
+        self.runtime.block_on(self.client.get_group_query(input))
+    }
+
+    /// Blocking version of [`ResourceGroups::get_tags`].
+    pub fn get_tags(
+        &self,
+        input: GetTagsInput,
+    ) -> Result<GetTagsOutput, RusotoError<GetTagsError>> {
+        self.runtime.block_on(self.client.get_tags(input))
+    }
+
+    /// Blocking version of [`ResourceGroups::group_resources`].
+    pub fn group_resources(
+        &self,
+        input: GroupResourcesInput,
+    ) -> Result<GroupResourcesOutput, RusotoError<GroupResourcesError>> {
+        self.runtime.block_on(self.client.group_resources(input))
+    }
+
+    /// Blocking version of [`ResourceGroups::list_group_resources`].
+    pub fn list_group_resources(
+        &self,
+        input: ListGroupResourcesInput,
+    ) -> Result<ListGroupResourcesOutput, RusotoError<ListGroupResourcesError>> {
+        self.runtime
+            .block_on(self.client.list_group_resources(input))
+    }
+
+    /// Blocking version of [`ResourceGroups::list_groups`].
+    pub fn list_groups(
+        &self,
+        input: ListGroupsInput,
+    ) -> Result<ListGroupsOutput, RusotoError<ListGroupsError>> {
+        self.runtime.block_on(self.client.list_groups(input))
+    }
+
+    /// Blocking version of [`ResourceGroups::search_resources`].
+    pub fn search_resources(
+        &self,
+        input: SearchResourcesInput,
+    ) -> Result<SearchResourcesOutput, RusotoError<SearchResourcesError>> {
+        self.runtime.block_on(self.client.search_resources(input))
+    }
+
+    /// Blocking version of [`ResourceGroups::tag`].
+    pub fn tag(&self, input: TagInput) -> Result<TagOutput, RusotoError<TagError>> {
+        self.runtime.block_on(self.client.tag(input))
+    }
+
+    /// Blocking version of [`ResourceGroups::ungroup_resources`].
+    pub fn ungroup_resources(
+        &self,
+        input: UngroupResourcesInput,
+    ) -> Result<UngroupResourcesOutput, RusotoError<UngroupResourcesError>> {
+        self.runtime.block_on(self.client.ungroup_resources(input))
+    }
+
+    /// Blocking version of [`ResourceGroups::untag`].
+    pub fn untag(&self, input: UntagInput) -> Result<UntagOutput, RusotoError<UntagError>> {
+        self.runtime.block_on(self.client.untag(input))
+    }
+
+    /// Blocking version of [`ResourceGroups::update_group`].
+    pub fn update_group(
+        &self,
+        input: UpdateGroupInput,
+    ) -> Result<UpdateGroupOutput, RusotoError<UpdateGroupError>> {
+        self.runtime.block_on(self.client.update_group(input))
+    }
+
+    /// Blocking version of [`ResourceGroups::update_group_query`].
+    pub fn update_group_query(
+        &self,
+        input: UpdateGroupQueryInput,
+    ) -> Result<UpdateGroupQueryOutput, RusotoError<UpdateGroupQueryError>> {
+        self.runtime.block_on(self.client.update_group_query(input))
+    }
+}
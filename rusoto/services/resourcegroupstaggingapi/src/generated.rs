@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -390,24 +391,38 @@ impl DescribeReportCreationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(DescribeReportCreationError::ConstraintViolation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeReportCreationError::ConstraintViolation,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(DescribeReportCreationError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeReportCreationError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeReportCreationError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeReportCreationError::InvalidParameter,
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(DescribeReportCreationError::Throttled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeReportCreationError::Throttled,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -444,24 +459,38 @@ impl GetComplianceSummaryError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(GetComplianceSummaryError::ConstraintViolation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetComplianceSummaryError::ConstraintViolation,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetComplianceSummaryError::InternalService(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetComplianceSummaryError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetComplianceSummaryError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetComplianceSummaryError::InvalidParameter,
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(GetComplianceSummaryError::Throttled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetComplianceSummaryError::Throttled,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -498,18 +527,38 @@ impl GetResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetResourcesError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourcesError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetResourcesError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourcesError::InvalidParameter,
+                    )
                 }
                 "PaginationTokenExpiredException" => {
-                    return RusotoError::Service(GetResourcesError::PaginationTokenExpired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourcesError::PaginationTokenExpired,
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(GetResourcesError::Throttled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResourcesError::Throttled,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -546,18 +595,38 @@ impl GetTagKeysError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetTagKeysError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTagKeysError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetTagKeysError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTagKeysError::InvalidParameter,
+                    )
                 }
                 "PaginationTokenExpiredException" => {
-                    return RusotoError::Service(GetTagKeysError::PaginationTokenExpired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTagKeysError::PaginationTokenExpired,
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(GetTagKeysError::Throttled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTagKeysError::Throttled,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -594,18 +663,38 @@ impl GetTagValuesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(GetTagValuesError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTagValuesError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetTagValuesError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTagValuesError::InvalidParameter,
+                    )
                 }
                 "PaginationTokenExpiredException" => {
-                    return RusotoError::Service(GetTagValuesError::PaginationTokenExpired(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTagValuesError::PaginationTokenExpired,
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(GetTagValuesError::Throttled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetTagValuesError::Throttled,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -644,27 +733,46 @@ impl StartReportCreationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConcurrentModificationException" => {
-                    return RusotoError::Service(StartReportCreationError::ConcurrentModification(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartReportCreationError::ConcurrentModification,
+                    )
                 }
                 "ConstraintViolationException" => {
-                    return RusotoError::Service(StartReportCreationError::ConstraintViolation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartReportCreationError::ConstraintViolation,
+                    )
                 }
                 "InternalServiceException" => {
-                    return RusotoError::Service(StartReportCreationError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartReportCreationError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartReportCreationError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartReportCreationError::InvalidParameter,
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(StartReportCreationError::Throttled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartReportCreationError::Throttled,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -700,15 +808,30 @@ impl TagResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(TagResourcesError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourcesError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagResourcesError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourcesError::InvalidParameter,
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(TagResourcesError::Throttled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourcesError::Throttled,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -742,15 +865,30 @@ impl UntagResourcesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceException" => {
-                    return RusotoError::Service(UntagResourcesError::InternalService(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourcesError::InternalService,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UntagResourcesError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourcesError::InvalidParameter,
+                    )
                 }
                 "ThrottledException" => {
-                    return RusotoError::Service(UntagResourcesError::Throttled(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourcesError::Throttled,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -828,11 +966,12 @@ pub struct ResourceGroupsTaggingApiClient {
 impl ResourceGroupsTaggingApiClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> ResourceGroupsTaggingApiClient {
         ResourceGroupsTaggingApiClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Resource Groups Tagging API"),
         }
     }
 
@@ -847,7 +986,7 @@ impl ResourceGroupsTaggingApiClient {
     {
         ResourceGroupsTaggingApiClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Resource Groups Tagging API"),
         }
     }
 
@@ -855,7 +994,27 @@ impl ResourceGroupsTaggingApiClient {
         client: Client,
         region: region::Region,
     ) -> ResourceGroupsTaggingApiClient {
-        ResourceGroupsTaggingApiClient { client, region }
+        ResourceGroupsTaggingApiClient {
+            client,
+            region: region_for_service(region, "Resource Groups Tagging API"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> ResourceGroupsTaggingApiClient {
+        ResourceGroupsTaggingApiClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Resource Groups Tagging API"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ResourceGroupsTaggingApiClient {
+        ResourceGroupsTaggingApiClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -866,6 +1025,7 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         &self,
     ) -> Result<DescribeReportCreationOutput, RusotoError<DescribeReportCreationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeReportCreation");
         request.add_header(
             "x-amz-target",
             "ResourceGroupsTaggingAPI_20170126.DescribeReportCreation",
@@ -887,6 +1047,7 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         input: GetComplianceSummaryInput,
     ) -> Result<GetComplianceSummaryOutput, RusotoError<GetComplianceSummaryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetComplianceSummary");
         request.add_header(
             "x-amz-target",
             "ResourceGroupsTaggingAPI_20170126.GetComplianceSummary",
@@ -908,6 +1069,7 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         input: GetResourcesInput,
     ) -> Result<GetResourcesOutput, RusotoError<GetResourcesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetResources");
         request.add_header(
             "x-amz-target",
             "ResourceGroupsTaggingAPI_20170126.GetResources",
@@ -929,6 +1091,7 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         input: GetTagKeysInput,
     ) -> Result<GetTagKeysOutput, RusotoError<GetTagKeysError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetTagKeys");
         request.add_header(
             "x-amz-target",
             "ResourceGroupsTaggingAPI_20170126.GetTagKeys",
@@ -950,6 +1113,7 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         input: GetTagValuesInput,
     ) -> Result<GetTagValuesOutput, RusotoError<GetTagValuesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetTagValues");
         request.add_header(
             "x-amz-target",
             "ResourceGroupsTaggingAPI_20170126.GetTagValues",
@@ -971,6 +1135,7 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         input: StartReportCreationInput,
     ) -> Result<StartReportCreationOutput, RusotoError<StartReportCreationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartReportCreation");
         request.add_header(
             "x-amz-target",
             "ResourceGroupsTaggingAPI_20170126.StartReportCreation",
@@ -992,6 +1157,7 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         input: TagResourcesInput,
     ) -> Result<TagResourcesOutput, RusotoError<TagResourcesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResources");
         request.add_header(
             "x-amz-target",
             "ResourceGroupsTaggingAPI_20170126.TagResources",
@@ -1013,6 +1179,7 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         input: UntagResourcesInput,
     ) -> Result<UntagResourcesOutput, RusotoError<UntagResourcesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResources");
         request.add_header(
             "x-amz-target",
             "ResourceGroupsTaggingAPI_20170126.UntagResources",
@@ -1028,3 +1195,160 @@ impl ResourceGroupsTaggingApi for ResourceGroupsTaggingApiClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UntagResourcesOutput, _>()
     }
 }
+
+/// A blocking client for the AWS Resource Groups Tagging API API, running the calls of a `ResourceGroupsTaggingApiClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `ResourceGroupsTaggingApiClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct ResourceGroupsTaggingApiBlockingClient {
+    client: ResourceGroupsTaggingApiClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl ResourceGroupsTaggingApiBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> ResourceGroupsTaggingApiBlockingClient {
+        ResourceGroupsTaggingApiBlockingClient::from_client(ResourceGroupsTaggingApiClient::new(
+            region,
+        ))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> ResourceGroupsTaggingApiBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        ResourceGroupsTaggingApiBlockingClient::from_client(
+            ResourceGroupsTaggingApiClient::new_with(
+                request_dispatcher,
+                credentials_provider,
+                region,
+            ),
+        )
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> ResourceGroupsTaggingApiBlockingClient {
+        ResourceGroupsTaggingApiBlockingClient::from_client(
+            ResourceGroupsTaggingApiClient::new_with_client(client, region),
+        )
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> ResourceGroupsTaggingApiBlockingClient {
+        ResourceGroupsTaggingApiBlockingClient::from_client(
+            ResourceGroupsTaggingApiClient::new_with_config(config),
+        )
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(
+        client: ResourceGroupsTaggingApiClient,
+    ) -> ResourceGroupsTaggingApiBlockingClient {
+        ResourceGroupsTaggingApiBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> ResourceGroupsTaggingApiBlockingClient {
+        ResourceGroupsTaggingApiBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> ResourceGroupsTaggingApiBlockingClient {
+        ResourceGroupsTaggingApiBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`ResourceGroupsTaggingApi::describe_report_creation`].
+    pub fn describe_report_creation(
+        &self,
+    ) -> Result<DescribeReportCreationOutput, RusotoError<DescribeReportCreationError>> {
+        self.runtime
+            .block_on(self.client.describe_report_creation())
+    }
+
+    /// Blocking version of [`ResourceGroupsTaggingApi::get_compliance_summary`].
+    pub fn get_compliance_summary(
+        &self,
+        input: GetComplianceSummaryInput,
+    ) -> Result<GetComplianceSummaryOutput, RusotoError<GetComplianceSummaryError>> {
+        self.runtime
+            .block_on(self.client.get_compliance_summary(input))
+    }
+
+    /// Blocking version of [`ResourceGroupsTaggingApi::get_resources`].
+    pub fn get_resources(
+        &self,
+        input: GetResourcesInput,
+    ) -> Result<GetResourcesOutput, RusotoError<GetResourcesError>> {
+        self.runtime.block_on(self.client.get_resources(input))
+    }
+
+    /// Blocking version of [`ResourceGroupsTaggingApi::get_tag_keys`].
+    pub fn get_tag_keys(
+        &self,
+        input: GetTagKeysInput,
+    ) -> Result<GetTagKeysOutput, RusotoError<GetTagKeysError>> {
+        self.runtime.block_on(self.client.get_tag_keys(input))
+    }
+
+    /// Blocking version of [`ResourceGroupsTaggingApi::get_tag_values`].
+    pub fn get_tag_values(
+        &self,
+        input: GetTagValuesInput,
+    ) -> Result<GetTagValuesOutput, RusotoError<GetTagValuesError>> {
+        self.runtime.block_on(self.client.get_tag_values(input))
+    }
+
+    /// Blocking version of [`ResourceGroupsTaggingApi::start_report_creation`].
+    pub fn start_report_creation(
+        &self,
+        input: StartReportCreationInput,
+    ) -> Result<StartReportCreationOutput, RusotoError<StartReportCreationError>> {
+        self.runtime
+            .block_on(self.client.start_report_creation(input))
+    }
+
+    /// Blocking version of [`ResourceGroupsTaggingApi::tag_resources`].
+    pub fn tag_resources(
+        &self,
+        input: TagResourcesInput,
+    ) -> Result<TagResourcesOutput, RusotoError<TagResourcesError>> {
+        self.runtime.block_on(self.client.tag_resources(input))
+    }
+
+    /// Blocking version of [`ResourceGroupsTaggingApi::untag_resources`].
+    pub fn untag_resources(
+        &self,
+        input: UntagResourcesInput,
+    ) -> Result<UntagResourcesOutput, RusotoError<UntagResourcesError>> {
+        self.runtime.block_on(self.client.untag_resources(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -3238,15 +3239,30 @@ impl BatchDeleteWorldsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(BatchDeleteWorldsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDeleteWorldsError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(BatchDeleteWorldsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDeleteWorldsError::InvalidParameter,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(BatchDeleteWorldsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        BatchDeleteWorldsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3284,26 +3300,38 @@ impl BatchDescribeSimulationJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(BatchDescribeSimulationJobError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDescribeSimulationJobError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(BatchDescribeSimulationJobError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDescribeSimulationJobError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(BatchDescribeSimulationJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDescribeSimulationJobError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(BatchDescribeSimulationJobError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        BatchDescribeSimulationJobError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3340,22 +3368,38 @@ impl CancelDeploymentJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CancelDeploymentJobError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelDeploymentJobError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CancelDeploymentJobError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelDeploymentJobError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelDeploymentJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelDeploymentJobError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CancelDeploymentJobError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelDeploymentJobError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3392,22 +3436,38 @@ impl CancelSimulationJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CancelSimulationJobError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelSimulationJobError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CancelSimulationJobError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelSimulationJobError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelSimulationJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelSimulationJobError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CancelSimulationJobError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelSimulationJobError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3444,24 +3504,38 @@ impl CancelSimulationJobBatchError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CancelSimulationJobBatchError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelSimulationJobBatchError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CancelSimulationJobBatchError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelSimulationJobBatchError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelSimulationJobBatchError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelSimulationJobBatchError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CancelSimulationJobBatchError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelSimulationJobBatchError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3498,22 +3572,38 @@ impl CancelWorldExportJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CancelWorldExportJobError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelWorldExportJobError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CancelWorldExportJobError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelWorldExportJobError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelWorldExportJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelWorldExportJobError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CancelWorldExportJobError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelWorldExportJobError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3550,24 +3640,38 @@ impl CancelWorldGenerationJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CancelWorldGenerationJobError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelWorldGenerationJobError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CancelWorldGenerationJobError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelWorldGenerationJobError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CancelWorldGenerationJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CancelWorldGenerationJobError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CancelWorldGenerationJobError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelWorldGenerationJobError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3610,35 +3714,62 @@ impl CreateDeploymentJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentDeploymentException" => {
-                    return RusotoError::Service(CreateDeploymentJobError::ConcurrentDeployment(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDeploymentJobError::ConcurrentDeployment,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        CreateDeploymentJobError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentJobError::IdempotentParameterMismatch,
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateDeploymentJobError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentJobError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateDeploymentJobError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDeploymentJobError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateDeploymentJobError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentJobError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateDeploymentJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDeploymentJobError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateDeploymentJobError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeploymentJobError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3680,18 +3811,38 @@ impl CreateFleetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateFleetError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFleetError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateFleetError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFleetError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateFleetError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFleetError::LimitExceeded,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateFleetError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFleetError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3730,21 +3881,46 @@ impl CreateRobotError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateRobotError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRobotError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateRobotError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRobotError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateRobotError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRobotError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateRobotError::ResourceAlreadyExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRobotError::ResourceAlreadyExists,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateRobotError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRobotError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3786,34 +3962,54 @@ impl CreateRobotApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        CreateRobotApplicationError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRobotApplicationError::IdempotentParameterMismatch,
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateRobotApplicationError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRobotApplicationError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateRobotApplicationError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRobotApplicationError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateRobotApplicationError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRobotApplicationError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        CreateRobotApplicationError::ResourceAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRobotApplicationError::ResourceAlreadyExists,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateRobotApplicationError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRobotApplicationError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3858,31 +4054,46 @@ impl CreateRobotApplicationVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        CreateRobotApplicationVersionError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRobotApplicationVersionError::IdempotentParameterMismatch,
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
-                        CreateRobotApplicationVersionError::InternalServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRobotApplicationVersionError::InternalServer,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        CreateRobotApplicationVersionError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateRobotApplicationVersionError::InvalidParameter,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateRobotApplicationVersionError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRobotApplicationVersionError::LimitExceeded,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateRobotApplicationVersionError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateRobotApplicationVersionError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3930,36 +4141,54 @@ impl CreateSimulationApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        CreateSimulationApplicationError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSimulationApplicationError::IdempotentParameterMismatch,
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateSimulationApplicationError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSimulationApplicationError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        CreateSimulationApplicationError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSimulationApplicationError::InvalidParameter,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateSimulationApplicationError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSimulationApplicationError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(
-                        CreateSimulationApplicationError::ResourceAlreadyExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSimulationApplicationError::ResourceAlreadyExists,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateSimulationApplicationError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSimulationApplicationError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4006,33 +4235,46 @@ impl CreateSimulationApplicationVersionError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        CreateSimulationApplicationVersionError::IdempotentParameterMismatch(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSimulationApplicationVersionError::IdempotentParameterMismatch,
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(
-                        CreateSimulationApplicationVersionError::InternalServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSimulationApplicationVersionError::InternalServer,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        CreateSimulationApplicationVersionError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSimulationApplicationVersionError::InvalidParameter,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
-                        CreateSimulationApplicationVersionError::LimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSimulationApplicationVersionError::LimitExceeded,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
-                        CreateSimulationApplicationVersionError::Throttling(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSimulationApplicationVersionError::Throttling,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4086,35 +4328,62 @@ impl CreateSimulationJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        CreateSimulationJobError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSimulationJobError::IdempotentParameterMismatch,
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateSimulationJobError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSimulationJobError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateSimulationJobError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSimulationJobError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateSimulationJobError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSimulationJobError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateSimulationJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSimulationJobError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateSimulationJobError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateSimulationJobError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateSimulationJobError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateSimulationJobError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4160,32 +4429,54 @@ impl CreateWorldExportJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        CreateWorldExportJobError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWorldExportJobError::IdempotentParameterMismatch,
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateWorldExportJobError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWorldExportJobError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateWorldExportJobError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateWorldExportJobError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateWorldExportJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateWorldExportJobError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateWorldExportJobError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateWorldExportJobError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateWorldExportJobError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWorldExportJobError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4232,39 +4523,62 @@ impl CreateWorldGenerationJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        CreateWorldGenerationJobError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWorldGenerationJobError::IdempotentParameterMismatch,
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateWorldGenerationJobError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateWorldGenerationJobError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateWorldGenerationJobError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateWorldGenerationJobError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateWorldGenerationJobError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateWorldGenerationJobError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateWorldGenerationJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateWorldGenerationJobError::ResourceNotFound,
+                    )
                 }
                 "ServiceUnavailableException" => {
-                    return RusotoError::Service(CreateWorldGenerationJobError::ServiceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateWorldGenerationJobError::ServiceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateWorldGenerationJobError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWorldGenerationJobError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4310,30 +4624,54 @@ impl CreateWorldTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(CreateWorldTemplateError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWorldTemplateError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateWorldTemplateError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateWorldTemplateError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateWorldTemplateError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWorldTemplateError::LimitExceeded,
+                    )
                 }
                 "ResourceAlreadyExistsException" => {
-                    return RusotoError::Service(CreateWorldTemplateError::ResourceAlreadyExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateWorldTemplateError::ResourceAlreadyExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateWorldTemplateError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateWorldTemplateError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateWorldTemplateError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWorldTemplateError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4370,15 +4708,30 @@ impl DeleteFleetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteFleetError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFleetError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteFleetError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFleetError::InvalidParameter,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteFleetError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFleetError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4412,15 +4765,30 @@ impl DeleteRobotError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteRobotError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRobotError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteRobotError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRobotError::InvalidParameter,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteRobotError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRobotError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4454,19 +4822,30 @@ impl DeleteRobotApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteRobotApplicationError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRobotApplicationError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteRobotApplicationError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteRobotApplicationError::InvalidParameter,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteRobotApplicationError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteRobotApplicationError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4502,21 +4881,30 @@ impl DeleteSimulationApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteSimulationApplicationError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSimulationApplicationError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        DeleteSimulationApplicationError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteSimulationApplicationError::InvalidParameter,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteSimulationApplicationError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteSimulationApplicationError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4552,22 +4940,38 @@ impl DeleteWorldTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteWorldTemplateError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteWorldTemplateError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteWorldTemplateError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteWorldTemplateError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteWorldTemplateError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteWorldTemplateError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteWorldTemplateError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteWorldTemplateError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4604,18 +5008,38 @@ impl DeregisterRobotError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeregisterRobotError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterRobotError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeregisterRobotError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterRobotError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeregisterRobotError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterRobotError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeregisterRobotError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeregisterRobotError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4652,24 +5076,38 @@ impl DescribeDeploymentJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeDeploymentJobError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDeploymentJobError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeDeploymentJobError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDeploymentJobError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeDeploymentJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDeploymentJobError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeDeploymentJobError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDeploymentJobError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4706,18 +5144,38 @@ impl DescribeFleetError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeFleetError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeFleetError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeFleetError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeFleetError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeFleetError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeFleetError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeFleetError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeFleetError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4754,18 +5212,38 @@ impl DescribeRobotError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeRobotError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRobotError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeRobotError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRobotError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeRobotError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRobotError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeRobotError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRobotError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4802,24 +5280,38 @@ impl DescribeRobotApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeRobotApplicationError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRobotApplicationError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeRobotApplicationError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRobotApplicationError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeRobotApplicationError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeRobotApplicationError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeRobotApplicationError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeRobotApplicationError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4858,26 +5350,38 @@ impl DescribeSimulationApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(
-                        DescribeSimulationApplicationError::InternalServer(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSimulationApplicationError::InternalServer,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        DescribeSimulationApplicationError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSimulationApplicationError::InvalidParameter,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DescribeSimulationApplicationError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSimulationApplicationError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeSimulationApplicationError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeSimulationApplicationError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4918,24 +5422,38 @@ impl DescribeSimulationJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeSimulationJobError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeSimulationJobError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeSimulationJobError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeSimulationJobError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeSimulationJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeSimulationJobError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeSimulationJobError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeSimulationJobError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4972,21 +5490,30 @@ impl DescribeSimulationJobBatchError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeSimulationJobBatchError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeSimulationJobBatchError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeSimulationJobBatchError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeSimulationJobBatchError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeSimulationJobBatchError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeSimulationJobBatchError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5022,18 +5549,38 @@ impl DescribeWorldError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeWorldError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeWorldError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeWorldError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeWorldError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeWorldError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeWorldError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeWorldError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeWorldError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5070,24 +5617,38 @@ impl DescribeWorldExportJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeWorldExportJobError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeWorldExportJobError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeWorldExportJobError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeWorldExportJobError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeWorldExportJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeWorldExportJobError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeWorldExportJobError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeWorldExportJobError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5126,26 +5687,38 @@ impl DescribeWorldGenerationJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeWorldGenerationJobError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeWorldGenerationJobError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeWorldGenerationJobError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeWorldGenerationJobError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeWorldGenerationJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeWorldGenerationJobError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeWorldGenerationJobError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeWorldGenerationJobError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5182,24 +5755,38 @@ impl DescribeWorldTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeWorldTemplateError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeWorldTemplateError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DescribeWorldTemplateError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeWorldTemplateError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeWorldTemplateError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeWorldTemplateError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeWorldTemplateError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeWorldTemplateError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5236,22 +5823,38 @@ impl GetWorldTemplateBodyError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(GetWorldTemplateBodyError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetWorldTemplateBodyError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetWorldTemplateBodyError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetWorldTemplateBodyError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetWorldTemplateBodyError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetWorldTemplateBodyError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetWorldTemplateBodyError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetWorldTemplateBodyError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5288,18 +5891,38 @@ impl ListDeploymentJobsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListDeploymentJobsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDeploymentJobsError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListDeploymentJobsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDeploymentJobsError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListDeploymentJobsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDeploymentJobsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListDeploymentJobsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDeploymentJobsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5336,18 +5959,38 @@ impl ListFleetsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListFleetsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFleetsError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListFleetsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFleetsError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListFleetsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFleetsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListFleetsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListFleetsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5382,19 +6025,30 @@ impl ListRobotApplicationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListRobotApplicationsError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListRobotApplicationsError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListRobotApplicationsError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListRobotApplicationsError::InvalidParameter,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListRobotApplicationsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRobotApplicationsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5430,18 +6084,38 @@ impl ListRobotsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListRobotsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRobotsError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListRobotsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRobotsError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListRobotsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRobotsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListRobotsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListRobotsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5478,21 +6152,30 @@ impl ListSimulationApplicationsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListSimulationApplicationsError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSimulationApplicationsError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListSimulationApplicationsError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSimulationApplicationsError::InvalidParameter,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListSimulationApplicationsError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSimulationApplicationsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5524,16 +6207,22 @@ impl ListSimulationJobBatchesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListSimulationJobBatchesError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSimulationJobBatchesError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListSimulationJobBatchesError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListSimulationJobBatchesError::InvalidParameter,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5566,15 +6255,30 @@ impl ListSimulationJobsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListSimulationJobsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSimulationJobsError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListSimulationJobsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSimulationJobsError::InvalidParameter,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListSimulationJobsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListSimulationJobsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5610,22 +6314,38 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5660,17 +6380,30 @@ impl ListWorldExportJobsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListWorldExportJobsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListWorldExportJobsError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListWorldExportJobsError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListWorldExportJobsError::InvalidParameter,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListWorldExportJobsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListWorldExportJobsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5704,19 +6437,30 @@ impl ListWorldGenerationJobsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListWorldGenerationJobsError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListWorldGenerationJobsError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListWorldGenerationJobsError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListWorldGenerationJobsError::InvalidParameter,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListWorldGenerationJobsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListWorldGenerationJobsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5750,15 +6494,30 @@ impl ListWorldTemplatesError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListWorldTemplatesError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListWorldTemplatesError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListWorldTemplatesError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListWorldTemplatesError::InvalidParameter,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListWorldTemplatesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListWorldTemplatesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5792,15 +6551,30 @@ impl ListWorldsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListWorldsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListWorldsError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListWorldsError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListWorldsError::InvalidParameter,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListWorldsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListWorldsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5838,21 +6612,46 @@ impl RegisterRobotError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(RegisterRobotError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterRobotError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RegisterRobotError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterRobotError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RegisterRobotError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterRobotError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RegisterRobotError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterRobotError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RegisterRobotError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterRobotError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5892,25 +6691,46 @@ impl RestartSimulationJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(RestartSimulationJobError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RestartSimulationJobError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(RestartSimulationJobError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RestartSimulationJobError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(RestartSimulationJobError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RestartSimulationJobError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(RestartSimulationJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RestartSimulationJobError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(RestartSimulationJobError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RestartSimulationJobError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -5950,29 +6770,46 @@ impl StartSimulationJobBatchError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        StartSimulationJobBatchError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartSimulationJobBatchError::IdempotentParameterMismatch,
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(StartSimulationJobBatchError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartSimulationJobBatchError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(StartSimulationJobBatchError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartSimulationJobBatchError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(StartSimulationJobBatchError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartSimulationJobBatchError::LimitExceeded,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartSimulationJobBatchError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartSimulationJobBatchError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6018,31 +6855,62 @@ impl SyncDeploymentJobError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConcurrentDeploymentException" => {
-                    return RusotoError::Service(SyncDeploymentJobError::ConcurrentDeployment(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        SyncDeploymentJobError::ConcurrentDeployment,
+                    )
                 }
                 "IdempotentParameterMismatchException" => {
-                    return RusotoError::Service(
-                        SyncDeploymentJobError::IdempotentParameterMismatch(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SyncDeploymentJobError::IdempotentParameterMismatch,
                     )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(SyncDeploymentJobError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SyncDeploymentJobError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(SyncDeploymentJobError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SyncDeploymentJobError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(SyncDeploymentJobError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SyncDeploymentJobError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(SyncDeploymentJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SyncDeploymentJobError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(SyncDeploymentJobError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        SyncDeploymentJobError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6084,18 +6952,38 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(TagResourceError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(TagResourceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6132,18 +7020,38 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UntagResourceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6182,29 +7090,46 @@ impl UpdateRobotApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateRobotApplicationError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRobotApplicationError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateRobotApplicationError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRobotApplicationError::InvalidParameter,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateRobotApplicationError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRobotApplicationError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateRobotApplicationError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateRobotApplicationError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateRobotApplicationError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateRobotApplicationError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6246,31 +7171,46 @@ impl UpdateSimulationApplicationError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateSimulationApplicationError::InternalServer(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSimulationApplicationError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        UpdateSimulationApplicationError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSimulationApplicationError::InvalidParameter,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateSimulationApplicationError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSimulationApplicationError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        UpdateSimulationApplicationError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateSimulationApplicationError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateSimulationApplicationError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateSimulationApplicationError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6308,22 +7248,38 @@ impl UpdateWorldTemplateError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(UpdateWorldTemplateError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateWorldTemplateError::InternalServer,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateWorldTemplateError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateWorldTemplateError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateWorldTemplateError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateWorldTemplateError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateWorldTemplateError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateWorldTemplateError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -6706,11 +7662,12 @@ pub struct RobomakerClient {
 impl RobomakerClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> RobomakerClient {
         RobomakerClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "RoboMaker"),
         }
     }
 
@@ -6725,12 +7682,32 @@ impl RobomakerClient {
     {
         RobomakerClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "RoboMaker"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> RobomakerClient {
-        RobomakerClient { client, region }
+        RobomakerClient {
+            client,
+            region: region_for_service(region, "RoboMaker"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> RobomakerClient {
+        RobomakerClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("RoboMaker"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> RobomakerClient {
+        RobomakerClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -6745,6 +7722,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/batchDeleteWorlds";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("BatchDeleteWorlds");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6777,6 +7755,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/batchDescribeSimulationJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("BatchDescribeSimulationJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6808,6 +7787,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/cancelDeploymentJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CancelDeploymentJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6839,6 +7819,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/cancelSimulationJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CancelSimulationJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6870,6 +7851,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/cancelSimulationJobBatch";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CancelSimulationJobBatch");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6901,6 +7883,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/cancelWorldExportJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CancelWorldExportJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6932,6 +7915,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/cancelWorldGenerationJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CancelWorldGenerationJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6963,6 +7947,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/createDeploymentJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CreateDeploymentJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -6994,6 +7979,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/createFleet";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CreateFleet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7025,6 +8011,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/createRobot";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CreateRobot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7056,6 +8043,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/createRobotApplication";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CreateRobotApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7090,6 +8078,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/createRobotApplicationVersion";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CreateRobotApplicationVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7122,6 +8111,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/createSimulationApplication";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CreateSimulationApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7156,6 +8146,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/createSimulationApplicationVersion";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CreateSimulationApplicationVersion");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7189,6 +8180,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/createSimulationJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CreateSimulationJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7220,6 +8212,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/createWorldExportJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CreateWorldExportJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7251,6 +8244,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/createWorldGenerationJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CreateWorldGenerationJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7282,6 +8276,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/createWorldTemplate";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("CreateWorldTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7313,6 +8308,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/deleteFleet";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DeleteFleet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7344,6 +8340,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/deleteRobot";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DeleteRobot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7375,6 +8372,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/deleteRobotApplication";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DeleteRobotApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7407,6 +8405,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/deleteSimulationApplication";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DeleteSimulationApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7438,6 +8437,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/deleteWorldTemplate";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DeleteWorldTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7469,6 +8469,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/deregisterRobot";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DeregisterRobot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7500,6 +8501,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/describeDeploymentJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DescribeDeploymentJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7531,6 +8533,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/describeFleet";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DescribeFleet");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7562,6 +8565,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/describeRobot";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DescribeRobot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7593,6 +8597,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/describeRobotApplication";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DescribeRobotApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7627,6 +8632,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/describeSimulationApplication";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DescribeSimulationApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7658,6 +8664,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/describeSimulationJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DescribeSimulationJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7690,6 +8697,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/describeSimulationJobBatch";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DescribeSimulationJobBatch");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7721,6 +8729,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/describeWorld";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DescribeWorld");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7752,6 +8761,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/describeWorldExportJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DescribeWorldExportJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7784,6 +8794,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/describeWorldGenerationJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DescribeWorldGenerationJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7815,6 +8826,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/describeWorldTemplate";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("DescribeWorldTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7846,6 +8858,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/getWorldTemplateBody";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("GetWorldTemplateBody");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7877,6 +8890,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/listDeploymentJobs";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("ListDeploymentJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7908,6 +8922,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/listFleets";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("ListFleets");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7939,6 +8954,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/listRobotApplications";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("ListRobotApplications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -7970,6 +8986,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/listRobots";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("ListRobots");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8002,6 +9019,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/listSimulationApplications";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("ListSimulationApplications");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8033,6 +9051,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/listSimulationJobBatches";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("ListSimulationJobBatches");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8064,6 +9083,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/listSimulationJobs";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("ListSimulationJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8095,6 +9115,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("GET", "robomaker", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut response = self
@@ -8123,6 +9144,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/listWorldExportJobs";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("ListWorldExportJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8154,6 +9176,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/listWorldGenerationJobs";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("ListWorldGenerationJobs");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8185,6 +9208,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/listWorldTemplates";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("ListWorldTemplates");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8216,6 +9240,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/listWorlds";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("ListWorlds");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8247,6 +9272,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/registerRobot";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("RegisterRobot");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8278,6 +9304,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/restartSimulationJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("RestartSimulationJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8309,6 +9336,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/startSimulationJobBatch";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("StartSimulationJobBatch");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8340,6 +9368,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/syncDeploymentJob";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("SyncDeploymentJob");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8371,6 +9400,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("TagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8402,6 +9432,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = format!("/tags/{resource_arn}", resource_arn = input.resource_arn);
 
         let mut request = SignedRequest::new("DELETE", "robomaker", &self.region, &request_uri);
+        request.set_operation("UntagResource");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let mut params = Params::new();
@@ -8436,6 +9467,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/updateRobotApplication";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("UpdateRobotApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8468,6 +9500,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/updateSimulationApplication";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("UpdateSimulationApplication");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8499,6 +9532,7 @@ impl Robomaker for RobomakerClient {
         let request_uri = "/updateWorldTemplate";
 
         let mut request = SignedRequest::new("POST", "robomaker", &self.region, &request_uri);
+        request.set_operation("UpdateWorldTemplate");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         let encoded = Some(serde_json::to_vec(&input).unwrap());
@@ -8521,3 +9555,593 @@ impl Robomaker for RobomakerClient {
         }
     }
 }
+
+/// A blocking client for the RoboMaker API, running the calls of a `RobomakerClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `RobomakerClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct RobomakerBlockingClient {
+    client: RobomakerClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl RobomakerBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> RobomakerBlockingClient {
+        RobomakerBlockingClient::from_client(RobomakerClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> RobomakerBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        RobomakerBlockingClient::from_client(RobomakerClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> RobomakerBlockingClient {
+        RobomakerBlockingClient::from_client(RobomakerClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> RobomakerBlockingClient {
+        RobomakerBlockingClient::from_client(RobomakerClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: RobomakerClient) -> RobomakerBlockingClient {
+        RobomakerBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> RobomakerBlockingClient {
+        RobomakerBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> RobomakerBlockingClient {
+        RobomakerBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Robomaker::batch_delete_worlds`].
+    pub fn batch_delete_worlds(
+        &self,
+        input: BatchDeleteWorldsRequest,
+    ) -> Result<BatchDeleteWorldsResponse, RusotoError<BatchDeleteWorldsError>> {
+        self.runtime
+            .block_on(self.client.batch_delete_worlds(input))
+    }
+
+    /// Blocking version of [`Robomaker::batch_describe_simulation_job`].
+    pub fn batch_describe_simulation_job(
+        &self,
+        input: BatchDescribeSimulationJobRequest,
+    ) -> Result<BatchDescribeSimulationJobResponse, RusotoError<BatchDescribeSimulationJobError>>
+    {
+        self.runtime
+            .block_on(self.client.batch_describe_simulation_job(input))
+    }
+
+    /// Blocking version of [`Robomaker::cancel_deployment_job`].
+    pub fn cancel_deployment_job(
+        &self,
+        input: CancelDeploymentJobRequest,
+    ) -> Result<CancelDeploymentJobResponse, RusotoError<CancelDeploymentJobError>> {
+        self.runtime
+            .block_on(self.client.cancel_deployment_job(input))
+    }
+
+    /// Blocking version of [`Robomaker::cancel_simulation_job`].
+    pub fn cancel_simulation_job(
+        &self,
+        input: CancelSimulationJobRequest,
+    ) -> Result<CancelSimulationJobResponse, RusotoError<CancelSimulationJobError>> {
+        self.runtime
+            .block_on(self.client.cancel_simulation_job(input))
+    }
+
+    /// Blocking version of [`Robomaker::cancel_simulation_job_batch`].
+    pub fn cancel_simulation_job_batch(
+        &self,
+        input: CancelSimulationJobBatchRequest,
+    ) -> Result<CancelSimulationJobBatchResponse, RusotoError<CancelSimulationJobBatchError>> {
+        self.runtime
+            .block_on(self.client.cancel_simulation_job_batch(input))
+    }
+
+    /// Blocking version of [`Robomaker::cancel_world_export_job`].
+    pub fn cancel_world_export_job(
+        &self,
+        input: CancelWorldExportJobRequest,
+    ) -> Result<CancelWorldExportJobResponse, RusotoError<CancelWorldExportJobError>> {
+        self.runtime
+            .block_on(self.client.cancel_world_export_job(input))
+    }
+
+    /// Blocking version of [`Robomaker::cancel_world_generation_job`].
+    pub fn cancel_world_generation_job(
+        &self,
+        input: CancelWorldGenerationJobRequest,
+    ) -> Result<CancelWorldGenerationJobResponse, RusotoError<CancelWorldGenerationJobError>> {
+        self.runtime
+            .block_on(self.client.cancel_world_generation_job(input))
+    }
+
+    /// Blocking version of [`Robomaker::create_deployment_job`].
+    pub fn create_deployment_job(
+        &self,
+        input: CreateDeploymentJobRequest,
+    ) -> Result<CreateDeploymentJobResponse, RusotoError<CreateDeploymentJobError>> {
+        self.runtime
+            .block_on(self.client.create_deployment_job(input))
+    }
+
+    /// Blocking version of [`Robomaker::create_fleet`].
+    pub fn create_fleet(
+        &self,
+        input: CreateFleetRequest,
+    ) -> Result<CreateFleetResponse, RusotoError<CreateFleetError>> {
+        self.runtime.block_on(self.client.create_fleet(input))
+    }
+
+    /// Blocking version of [`Robomaker::create_robot`].
+    pub fn create_robot(
+        &self,
+        input: CreateRobotRequest,
+    ) -> Result<CreateRobotResponse, RusotoError<CreateRobotError>> {
+        self.runtime.block_on(self.client.create_robot(input))
+    }
+
+    /// Blocking version of [`Robomaker::create_robot_application`].
+    pub fn create_robot_application(
+        &self,
+        input: CreateRobotApplicationRequest,
+    ) -> Result<CreateRobotApplicationResponse, RusotoError<CreateRobotApplicationError>> {
+        self.runtime
+            .block_on(self.client.create_robot_application(input))
+    }
+
+    /// Blocking version of [`Robomaker::create_robot_application_version`].
+    pub fn create_robot_application_version(
+        &self,
+        input: CreateRobotApplicationVersionRequest,
+    ) -> Result<
+        CreateRobotApplicationVersionResponse,
+        RusotoError<CreateRobotApplicationVersionError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_robot_application_version(input))
+    }
+
+    /// Blocking version of [`Robomaker::create_simulation_application`].
+    pub fn create_simulation_application(
+        &self,
+        input: CreateSimulationApplicationRequest,
+    ) -> Result<CreateSimulationApplicationResponse, RusotoError<CreateSimulationApplicationError>>
+    {
+        self.runtime
+            .block_on(self.client.create_simulation_application(input))
+    }
+
+    /// Blocking version of [`Robomaker::create_simulation_application_version`].
+    pub fn create_simulation_application_version(
+        &self,
+        input: CreateSimulationApplicationVersionRequest,
+    ) -> Result<
+        CreateSimulationApplicationVersionResponse,
+        RusotoError<CreateSimulationApplicationVersionError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_simulation_application_version(input))
+    }
+
+    /// Blocking version of [`Robomaker::create_simulation_job`].
+    pub fn create_simulation_job(
+        &self,
+        input: CreateSimulationJobRequest,
+    ) -> Result<CreateSimulationJobResponse, RusotoError<CreateSimulationJobError>> {
+        self.runtime
+            .block_on(self.client.create_simulation_job(input))
+    }
+
+    /// Blocking version of [`Robomaker::create_world_export_job`].
+    pub fn create_world_export_job(
+        &self,
+        input: CreateWorldExportJobRequest,
+    ) -> Result<CreateWorldExportJobResponse, RusotoError<CreateWorldExportJobError>> {
+        self.runtime
+            .block_on(self.client.create_world_export_job(input))
+    }
+
+    /// Blocking version of [`Robomaker::create_world_generation_job`].
+    pub fn create_world_generation_job(
+        &self,
+        input: CreateWorldGenerationJobRequest,
+    ) -> Result<CreateWorldGenerationJobResponse, RusotoError<CreateWorldGenerationJobError>> {
+        self.runtime
+            .block_on(self.client.create_world_generation_job(input))
+    }
+
+    /// Blocking version of [`Robomaker::create_world_template`].
+    pub fn create_world_template(
+        &self,
+        input: CreateWorldTemplateRequest,
+    ) -> Result<CreateWorldTemplateResponse, RusotoError<CreateWorldTemplateError>> {
+        self.runtime
+            .block_on(self.client.create_world_template(input))
+    }
+
+    /// Blocking version of [`Robomaker::delete_fleet`].
+    pub fn delete_fleet(
+        &self,
+        input: DeleteFleetRequest,
+    ) -> Result<DeleteFleetResponse, RusotoError<DeleteFleetError>> {
+        self.runtime.block_on(self.client.delete_fleet(input))
+    }
+
+    /// Blocking version of [`Robomaker::delete_robot`].
+    pub fn delete_robot(
+        &self,
+        input: DeleteRobotRequest,
+    ) -> Result<DeleteRobotResponse, RusotoError<DeleteRobotError>> {
+        self.runtime.block_on(self.client.delete_robot(input))
+    }
+
+    /// Blocking version of [`Robomaker::delete_robot_application`].
+    pub fn delete_robot_application(
+        &self,
+        input: DeleteRobotApplicationRequest,
+    ) -> Result<DeleteRobotApplicationResponse, RusotoError<DeleteRobotApplicationError>> {
+        self.runtime
+            .block_on(self.client.delete_robot_application(input))
+    }
+
+    /// Blocking version of [`Robomaker::delete_simulation_application`].
+    pub fn delete_simulation_application(
+        &self,
+        input: DeleteSimulationApplicationRequest,
+    ) -> Result<DeleteSimulationApplicationResponse, RusotoError<DeleteSimulationApplicationError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_simulation_application(input))
+    }
+
+    /// Blocking version of [`Robomaker::delete_world_template`].
+    pub fn delete_world_template(
+        &self,
+        input: DeleteWorldTemplateRequest,
+    ) -> Result<DeleteWorldTemplateResponse, RusotoError<DeleteWorldTemplateError>> {
+        self.runtime
+            .block_on(self.client.delete_world_template(input))
+    }
+
+    /// Blocking version of [`Robomaker::deregister_robot`].
+    pub fn deregister_robot(
+        &self,
+        input: DeregisterRobotRequest,
+    ) -> Result<DeregisterRobotResponse, RusotoError<DeregisterRobotError>> {
+        self.runtime.block_on(self.client.deregister_robot(input))
+    }
+
+    /// Blocking version of [`Robomaker::describe_deployment_job`].
+    pub fn describe_deployment_job(
+        &self,
+        input: DescribeDeploymentJobRequest,
+    ) -> Result<DescribeDeploymentJobResponse, RusotoError<DescribeDeploymentJobError>> {
+        self.runtime
+            .block_on(self.client.describe_deployment_job(input))
+    }
+
+    /// Blocking version of [`Robomaker::describe_fleet`].
+    pub fn describe_fleet(
+        &self,
+        input: DescribeFleetRequest,
+    ) -> Result<DescribeFleetResponse, RusotoError<DescribeFleetError>> {
+        self.runtime.block_on(self.client.describe_fleet(input))
+    }
+
+    /// Blocking version of [`Robomaker::describe_robot`].
+    pub fn describe_robot(
+        &self,
+        input: DescribeRobotRequest,
+    ) -> Result<DescribeRobotResponse, RusotoError<DescribeRobotError>> {
+        self.runtime.block_on(self.client.describe_robot(input))
+    }
+
+    /// Blocking version of [`Robomaker::describe_robot_application`].
+    pub fn describe_robot_application(
+        &self,
+        input: DescribeRobotApplicationRequest,
+    ) -> Result<DescribeRobotApplicationResponse, RusotoError<DescribeRobotApplicationError>> {
+        self.runtime
+            .block_on(self.client.describe_robot_application(input))
+    }
+
+    /// Blocking version of [`Robomaker::describe_simulation_application`].
+    pub fn describe_simulation_application(
+        &self,
+        input: DescribeSimulationApplicationRequest,
+    ) -> Result<
+        DescribeSimulationApplicationResponse,
+        RusotoError<DescribeSimulationApplicationError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_simulation_application(input))
+    }
+
+    /// Blocking version of [`Robomaker::describe_simulation_job`].
+    pub fn describe_simulation_job(
+        &self,
+        input: DescribeSimulationJobRequest,
+    ) -> Result<DescribeSimulationJobResponse, RusotoError<DescribeSimulationJobError>> {
+        self.runtime
+            .block_on(self.client.describe_simulation_job(input))
+    }
+
+    /// Blocking version of [`Robomaker::describe_simulation_job_batch`].
+    pub fn describe_simulation_job_batch(
+        &self,
+        input: DescribeSimulationJobBatchRequest,
+    ) -> Result<DescribeSimulationJobBatchResponse, RusotoError<DescribeSimulationJobBatchError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_simulation_job_batch(input))
+    }
+
+    /// Blocking version of [`Robomaker::describe_world`].
+    pub fn describe_world(
+        &self,
+        input: DescribeWorldRequest,
+    ) -> Result<DescribeWorldResponse, RusotoError<DescribeWorldError>> {
+        self.runtime.block_on(self.client.describe_world(input))
+    }
+
+    /// Blocking version of [`Robomaker::describe_world_export_job`].
+    pub fn describe_world_export_job(
+        &self,
+        input: DescribeWorldExportJobRequest,
+    ) -> Result<DescribeWorldExportJobResponse, RusotoError<DescribeWorldExportJobError>> {
+        self.runtime
+            .block_on(self.client.describe_world_export_job(input))
+    }
+
+    /// Blocking version of [`Robomaker::describe_world_generation_job`].
+    pub fn describe_world_generation_job(
+        &self,
+        input: DescribeWorldGenerationJobRequest,
+    ) -> Result<DescribeWorldGenerationJobResponse, RusotoError<DescribeWorldGenerationJobError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_world_generation_job(input))
+    }
+
+    /// Blocking version of [`Robomaker::describe_world_template`].
+    pub fn describe_world_template(
+        &self,
+        input: DescribeWorldTemplateRequest,
+    ) -> Result<DescribeWorldTemplateResponse, RusotoError<DescribeWorldTemplateError>> {
+        self.runtime
+            .block_on(self.client.describe_world_template(input))
+    }
+
+    /// Blocking version of [`Robomaker::get_world_template_body`].
+    pub fn get_world_template_body(
+        &self,
+        input: GetWorldTemplateBodyRequest,
+    ) -> Result<GetWorldTemplateBodyResponse, RusotoError<GetWorldTemplateBodyError>> {
+        self.runtime
+            .block_on(self.client.get_world_template_body(input))
+    }
+
+    /// Blocking version of [`Robomaker::list_deployment_jobs`].
+    pub fn list_deployment_jobs(
+        &self,
+        input: ListDeploymentJobsRequest,
+    ) -> Result<ListDeploymentJobsResponse, RusotoError<ListDeploymentJobsError>> {
+        self.runtime
+            .block_on(self.client.list_deployment_jobs(input))
+    }
+
+    /// Blocking version of [`Robomaker::list_fleets`].
+    pub fn list_fleets(
+        &self,
+        input: ListFleetsRequest,
+    ) -> Result<ListFleetsResponse, RusotoError<ListFleetsError>> {
+        self.runtime.block_on(self.client.list_fleets(input))
+    }
+
+    /// Blocking version of [`Robomaker::list_robot_applications`].
+    pub fn list_robot_applications(
+        &self,
+        input: ListRobotApplicationsRequest,
+    ) -> Result<ListRobotApplicationsResponse, RusotoError<ListRobotApplicationsError>> {
+        self.runtime
+            .block_on(self.client.list_robot_applications(input))
+    }
+
+    /// Blocking version of [`Robomaker::list_robots`].
+    pub fn list_robots(
+        &self,
+        input: ListRobotsRequest,
+    ) -> Result<ListRobotsResponse, RusotoError<ListRobotsError>> {
+        self.runtime.block_on(self.client.list_robots(input))
+    }
+
+    /// Blocking version of [`Robomaker::list_simulation_applications`].
+    pub fn list_simulation_applications(
+        &self,
+        input: ListSimulationApplicationsRequest,
+    ) -> Result<ListSimulationApplicationsResponse, RusotoError<ListSimulationApplicationsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_simulation_applications(input))
+    }
+
+    /// Blocking version of [`Robomaker::list_simulation_job_batches`].
+    pub fn list_simulation_job_batches(
+        &self,
+        input: ListSimulationJobBatchesRequest,
+    ) -> Result<ListSimulationJobBatchesResponse, RusotoError<ListSimulationJobBatchesError>> {
+        self.runtime
+            .block_on(self.client.list_simulation_job_batches(input))
+    }
+
+    /// Blocking version of [`Robomaker::list_simulation_jobs`].
+    pub fn list_simulation_jobs(
+        &self,
+        input: ListSimulationJobsRequest,
+    ) -> Result<ListSimulationJobsResponse, RusotoError<ListSimulationJobsError>> {
+        self.runtime
+            .block_on(self.client.list_simulation_jobs(input))
+    }
+
+    /// Blocking version of [`Robomaker::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`Robomaker::list_world_export_jobs`].
+    pub fn list_world_export_jobs(
+        &self,
+        input: ListWorldExportJobsRequest,
+    ) -> Result<ListWorldExportJobsResponse, RusotoError<ListWorldExportJobsError>> {
+        self.runtime
+            .block_on(self.client.list_world_export_jobs(input))
+    }
+
+    /// Blocking version of [`Robomaker::list_world_generation_jobs`].
+    pub fn list_world_generation_jobs(
+        &self,
+        input: ListWorldGenerationJobsRequest,
+    ) -> Result<ListWorldGenerationJobsResponse, RusotoError<ListWorldGenerationJobsError>> {
+        self.runtime
+            .block_on(self.client.list_world_generation_jobs(input))
+    }
+
+    /// Blocking version of [`Robomaker::list_world_templates`].
+    pub fn list_world_templates(
+        &self,
+        input: ListWorldTemplatesRequest,
+    ) -> Result<ListWorldTemplatesResponse, RusotoError<ListWorldTemplatesError>> {
+        self.runtime
+            .block_on(self.client.list_world_templates(input))
+    }
+
+    /// Blocking version of [`Robomaker::list_worlds`].
+    pub fn list_worlds(
+        &self,
+        input: ListWorldsRequest,
+    ) -> Result<ListWorldsResponse, RusotoError<ListWorldsError>> {
+        self.runtime.block_on(self.client.list_worlds(input))
+    }
+
+    /// Blocking version of [`Robomaker::register_robot`].
+    pub fn register_robot(
+        &self,
+        input: RegisterRobotRequest,
+    ) -> Result<RegisterRobotResponse, RusotoError<RegisterRobotError>> {
+        self.runtime.block_on(self.client.register_robot(input))
+    }
+
+    /// Blocking version of [`Robomaker::restart_simulation_job`].
+    pub fn restart_simulation_job(
+        &self,
+        input: RestartSimulationJobRequest,
+    ) -> Result<RestartSimulationJobResponse, RusotoError<RestartSimulationJobError>> {
+        self.runtime
+            .block_on(self.client.restart_simulation_job(input))
+    }
+
+    /// Blocking version of [`Robomaker::start_simulation_job_batch`].
+    pub fn start_simulation_job_batch(
+        &self,
+        input: StartSimulationJobBatchRequest,
+    ) -> Result<StartSimulationJobBatchResponse, RusotoError<StartSimulationJobBatchError>> {
+        self.runtime
+            .block_on(self.client.start_simulation_job_batch(input))
+    }
+
+    /// Blocking version of [`Robomaker::sync_deployment_job`].
+    pub fn sync_deployment_job(
+        &self,
+        input: SyncDeploymentJobRequest,
+    ) -> Result<SyncDeploymentJobResponse, RusotoError<SyncDeploymentJobError>> {
+        self.runtime
+            .block_on(self.client.sync_deployment_job(input))
+    }
+
+    /// Blocking version of [`Robomaker::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`Robomaker::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`Robomaker::update_robot_application`].
+    pub fn update_robot_application(
+        &self,
+        input: UpdateRobotApplicationRequest,
+    ) -> Result<UpdateRobotApplicationResponse, RusotoError<UpdateRobotApplicationError>> {
+        self.runtime
+            .block_on(self.client.update_robot_application(input))
+    }
+
+    /// Blocking version of [`Robomaker::update_simulation_application`].
+    pub fn update_simulation_application(
+        &self,
+        input: UpdateSimulationApplicationRequest,
+    ) -> Result<UpdateSimulationApplicationResponse, RusotoError<UpdateSimulationApplicationError>>
+    {
+        self.runtime
+            .block_on(self.client.update_simulation_application(input))
+    }
+
+    /// Blocking version of [`Robomaker::update_world_template`].
+    pub fn update_world_template(
+        &self,
+        input: UpdateWorldTemplateRequest,
+    ) -> Result<UpdateWorldTemplateResponse, RusotoError<UpdateWorldTemplateError>> {
+        self.runtime
+            .block_on(self.client.update_world_template(input))
+    }
+}
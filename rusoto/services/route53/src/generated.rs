@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -8043,32 +8044,43 @@ impl ActivateKeySigningKeyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(
-                            ActivateKeySigningKeyError::ConcurrentModification(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ActivateKeySigningKeyError::ConcurrentModification,
                         )
                     }
                     "InvalidKMSArn" => {
-                        return RusotoError::Service(ActivateKeySigningKeyError::InvalidKMSArn(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ActivateKeySigningKeyError::InvalidKMSArn,
+                        )
                     }
                     "InvalidKeySigningKeyStatus" => {
-                        return RusotoError::Service(
-                            ActivateKeySigningKeyError::InvalidKeySigningKeyStatus(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ActivateKeySigningKeyError::InvalidKeySigningKeyStatus,
                         )
                     }
                     "InvalidSigningStatus" => {
-                        return RusotoError::Service(
-                            ActivateKeySigningKeyError::InvalidSigningStatus(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ActivateKeySigningKeyError::InvalidSigningStatus,
                         )
                     }
                     "NoSuchKeySigningKey" => {
-                        return RusotoError::Service(
-                            ActivateKeySigningKeyError::NoSuchKeySigningKey(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ActivateKeySigningKeyError::NoSuchKeySigningKey,
                         )
                     }
                     _ => {}
@@ -8133,49 +8145,67 @@ impl AssociateVPCWithHostedZoneError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConflictingDomainExists" => {
-                        return RusotoError::Service(
-                            AssociateVPCWithHostedZoneError::ConflictingDomainExists(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AssociateVPCWithHostedZoneError::ConflictingDomainExists,
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(AssociateVPCWithHostedZoneError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AssociateVPCWithHostedZoneError::InvalidInput,
+                        )
                     }
                     "InvalidVPCId" => {
-                        return RusotoError::Service(AssociateVPCWithHostedZoneError::InvalidVPCId(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AssociateVPCWithHostedZoneError::InvalidVPCId,
+                        )
                     }
                     "LimitsExceeded" => {
-                        return RusotoError::Service(
-                            AssociateVPCWithHostedZoneError::LimitsExceeded(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AssociateVPCWithHostedZoneError::LimitsExceeded,
                         )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(
-                            AssociateVPCWithHostedZoneError::NoSuchHostedZone(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AssociateVPCWithHostedZoneError::NoSuchHostedZone,
                         )
                     }
                     "NotAuthorizedException" => {
-                        return RusotoError::Service(
-                            AssociateVPCWithHostedZoneError::NotAuthorized(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AssociateVPCWithHostedZoneError::NotAuthorized,
                         )
                     }
                     "PriorRequestNotComplete" => {
-                        return RusotoError::Service(
-                            AssociateVPCWithHostedZoneError::PriorRequestNotComplete(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AssociateVPCWithHostedZoneError::PriorRequestNotComplete,
                         )
                     }
                     "PublicZoneVPCAssociation" => {
-                        return RusotoError::Service(
-                            AssociateVPCWithHostedZoneError::PublicZoneVPCAssociation(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            AssociateVPCWithHostedZoneError::PublicZoneVPCAssociation,
                         )
                     }
                     _ => {}
@@ -8239,30 +8269,43 @@ impl ChangeResourceRecordSetsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidChangeBatch" => {
-                        return RusotoError::Service(
-                            ChangeResourceRecordSetsError::InvalidChangeBatch(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ChangeResourceRecordSetsError::InvalidChangeBatch,
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(ChangeResourceRecordSetsError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ChangeResourceRecordSetsError::InvalidInput,
+                        )
                     }
                     "NoSuchHealthCheck" => {
-                        return RusotoError::Service(
-                            ChangeResourceRecordSetsError::NoSuchHealthCheck(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ChangeResourceRecordSetsError::NoSuchHealthCheck,
                         )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(
-                            ChangeResourceRecordSetsError::NoSuchHostedZone(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ChangeResourceRecordSetsError::NoSuchHostedZone,
                         )
                     }
                     "PriorRequestNotComplete" => {
-                        return RusotoError::Service(
-                            ChangeResourceRecordSetsError::PriorRequestNotComplete(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ChangeResourceRecordSetsError::PriorRequestNotComplete,
                         )
                     }
                     _ => {}
@@ -8319,31 +8362,44 @@ impl ChangeTagsForResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ChangeTagsForResourceError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ChangeTagsForResourceError::InvalidInput,
+                        )
                     }
                     "NoSuchHealthCheck" => {
-                        return RusotoError::Service(ChangeTagsForResourceError::NoSuchHealthCheck(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ChangeTagsForResourceError::NoSuchHealthCheck,
+                        )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(ChangeTagsForResourceError::NoSuchHostedZone(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ChangeTagsForResourceError::NoSuchHostedZone,
+                        )
                     }
                     "PriorRequestNotComplete" => {
-                        return RusotoError::Service(
-                            ChangeTagsForResourceError::PriorRequestNotComplete(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ChangeTagsForResourceError::PriorRequestNotComplete,
                         )
                     }
                     "ThrottlingException" => {
-                        return RusotoError::Service(ChangeTagsForResourceError::Throttling(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ChangeTagsForResourceError::Throttling,
+                        )
                     }
                     _ => {}
                 }
@@ -8395,19 +8451,28 @@ impl CreateHealthCheckError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "HealthCheckAlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateHealthCheckError::HealthCheckAlreadyExists(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateHealthCheckError::HealthCheckAlreadyExists,
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(CreateHealthCheckError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateHealthCheckError::InvalidInput,
+                        )
                     }
                     "TooManyHealthChecks" => {
-                        return RusotoError::Service(CreateHealthCheckError::TooManyHealthChecks(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateHealthCheckError::TooManyHealthChecks,
+                        )
                     }
                     _ => {}
                 }
@@ -8467,49 +8532,76 @@ impl CreateHostedZoneError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConflictingDomainExists" => {
-                        return RusotoError::Service(
-                            CreateHostedZoneError::ConflictingDomainExists(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateHostedZoneError::ConflictingDomainExists,
                         )
                     }
                     "DelegationSetNotAvailable" => {
-                        return RusotoError::Service(
-                            CreateHostedZoneError::DelegationSetNotAvailable(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateHostedZoneError::DelegationSetNotAvailable,
                         )
                     }
                     "DelegationSetNotReusable" => {
-                        return RusotoError::Service(
-                            CreateHostedZoneError::DelegationSetNotReusable(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateHostedZoneError::DelegationSetNotReusable,
                         )
                     }
                     "HostedZoneAlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateHostedZoneError::HostedZoneAlreadyExists(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateHostedZoneError::HostedZoneAlreadyExists,
                         )
                     }
                     "InvalidDomainName" => {
-                        return RusotoError::Service(CreateHostedZoneError::InvalidDomainName(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateHostedZoneError::InvalidDomainName,
+                        )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(CreateHostedZoneError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateHostedZoneError::InvalidInput,
+                        )
                     }
                     "InvalidVPCId" => {
-                        return RusotoError::Service(CreateHostedZoneError::InvalidVPCId(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateHostedZoneError::InvalidVPCId,
+                        )
                     }
                     "NoSuchDelegationSet" => {
-                        return RusotoError::Service(CreateHostedZoneError::NoSuchDelegationSet(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateHostedZoneError::NoSuchDelegationSet,
+                        )
                     }
                     "TooManyHostedZones" => {
-                        return RusotoError::Service(CreateHostedZoneError::TooManyHostedZones(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateHostedZoneError::TooManyHostedZones,
+                        )
                     }
                     _ => {}
                 }
@@ -8577,59 +8669,83 @@ impl CreateKeySigningKeyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(
-                            CreateKeySigningKeyError::ConcurrentModification(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateKeySigningKeyError::ConcurrentModification,
                         )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(CreateKeySigningKeyError::InvalidArgument(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateKeySigningKeyError::InvalidArgument,
+                        )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(CreateKeySigningKeyError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateKeySigningKeyError::InvalidInput,
+                        )
                     }
                     "InvalidKMSArn" => {
-                        return RusotoError::Service(CreateKeySigningKeyError::InvalidKMSArn(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateKeySigningKeyError::InvalidKMSArn,
+                        )
                     }
                     "InvalidKeySigningKeyName" => {
-                        return RusotoError::Service(
-                            CreateKeySigningKeyError::InvalidKeySigningKeyName(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateKeySigningKeyError::InvalidKeySigningKeyName,
                         )
                     }
                     "InvalidKeySigningKeyStatus" => {
-                        return RusotoError::Service(
-                            CreateKeySigningKeyError::InvalidKeySigningKeyStatus(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateKeySigningKeyError::InvalidKeySigningKeyStatus,
                         )
                     }
                     "InvalidSigningStatus" => {
-                        return RusotoError::Service(
-                            CreateKeySigningKeyError::InvalidSigningStatus(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateKeySigningKeyError::InvalidSigningStatus,
                         )
                     }
                     "KeySigningKeyAlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateKeySigningKeyError::KeySigningKeyAlreadyExists(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateKeySigningKeyError::KeySigningKeyAlreadyExists,
                         )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(CreateKeySigningKeyError::NoSuchHostedZone(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateKeySigningKeyError::NoSuchHostedZone,
+                        )
                     }
                     "TooManyKeySigningKeys" => {
-                        return RusotoError::Service(
-                            CreateKeySigningKeyError::TooManyKeySigningKeys(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateKeySigningKeyError::TooManyKeySigningKeys,
                         )
                     }
                     _ => {}
@@ -8695,41 +8811,51 @@ impl CreateQueryLoggingConfigError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(
-                            CreateQueryLoggingConfigError::ConcurrentModification(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateQueryLoggingConfigError::ConcurrentModification,
                         )
                     }
                     "InsufficientCloudWatchLogsResourcePolicy" => {
-                        return RusotoError::Service(
-                            CreateQueryLoggingConfigError::InsufficientCloudWatchLogsResourcePolicy(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateQueryLoggingConfigError::InsufficientCloudWatchLogsResourcePolicy,
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(CreateQueryLoggingConfigError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateQueryLoggingConfigError::InvalidInput,
+                        )
                     }
                     "NoSuchCloudWatchLogsLogGroup" => {
-                        return RusotoError::Service(
-                            CreateQueryLoggingConfigError::NoSuchCloudWatchLogsLogGroup(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateQueryLoggingConfigError::NoSuchCloudWatchLogsLogGroup,
                         )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(
-                            CreateQueryLoggingConfigError::NoSuchHostedZone(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateQueryLoggingConfigError::NoSuchHostedZone,
                         )
                     }
                     "QueryLoggingConfigAlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateQueryLoggingConfigError::QueryLoggingConfigAlreadyExists(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateQueryLoggingConfigError::QueryLoggingConfigAlreadyExists,
                         )
                     }
                     _ => {}
@@ -8799,46 +8925,59 @@ impl CreateReusableDelegationSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DelegationSetAlreadyCreated" => {
-                        return RusotoError::Service(
-                            CreateReusableDelegationSetError::DelegationSetAlreadyCreated(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateReusableDelegationSetError::DelegationSetAlreadyCreated,
                         )
                     }
                     "DelegationSetAlreadyReusable" => {
-                        return RusotoError::Service(
-                            CreateReusableDelegationSetError::DelegationSetAlreadyReusable(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateReusableDelegationSetError::DelegationSetAlreadyReusable,
                         )
                     }
                     "DelegationSetNotAvailable" => {
-                        return RusotoError::Service(
-                            CreateReusableDelegationSetError::DelegationSetNotAvailable(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateReusableDelegationSetError::DelegationSetNotAvailable,
                         )
                     }
                     "HostedZoneNotFound" => {
-                        return RusotoError::Service(
-                            CreateReusableDelegationSetError::HostedZoneNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateReusableDelegationSetError::HostedZoneNotFound,
                         )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(
-                            CreateReusableDelegationSetError::InvalidArgument(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateReusableDelegationSetError::InvalidArgument,
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(
-                            CreateReusableDelegationSetError::InvalidInput(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateReusableDelegationSetError::InvalidInput,
                         )
                     }
                     "LimitsExceeded" => {
-                        return RusotoError::Service(
-                            CreateReusableDelegationSetError::LimitsExceeded(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateReusableDelegationSetError::LimitsExceeded,
                         )
                     }
                     _ => {}
@@ -8901,27 +9040,35 @@ impl CreateTrafficPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(CreateTrafficPolicyError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateTrafficPolicyError::InvalidInput,
+                        )
                     }
                     "InvalidTrafficPolicyDocument" => {
-                        return RusotoError::Service(
-                            CreateTrafficPolicyError::InvalidTrafficPolicyDocument(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateTrafficPolicyError::InvalidTrafficPolicyDocument,
                         )
                     }
                     "TooManyTrafficPolicies" => {
-                        return RusotoError::Service(
-                            CreateTrafficPolicyError::TooManyTrafficPolicies(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateTrafficPolicyError::TooManyTrafficPolicies,
                         )
                     }
                     "TrafficPolicyAlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateTrafficPolicyError::TrafficPolicyAlreadyExists(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateTrafficPolicyError::TrafficPolicyAlreadyExists,
                         )
                     }
                     _ => {}
@@ -8981,36 +9128,43 @@ impl CreateTrafficPolicyInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
-                            CreateTrafficPolicyInstanceError::InvalidInput(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateTrafficPolicyInstanceError::InvalidInput,
                         )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(
-                            CreateTrafficPolicyInstanceError::NoSuchHostedZone(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateTrafficPolicyInstanceError::NoSuchHostedZone,
                         )
                     }
                     "NoSuchTrafficPolicy" => {
-                        return RusotoError::Service(
-                            CreateTrafficPolicyInstanceError::NoSuchTrafficPolicy(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateTrafficPolicyInstanceError::NoSuchTrafficPolicy,
                         )
                     }
                     "TooManyTrafficPolicyInstances" => {
-                        return RusotoError::Service(
-                            CreateTrafficPolicyInstanceError::TooManyTrafficPolicyInstances(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateTrafficPolicyInstanceError::TooManyTrafficPolicyInstances,
                         )
                     }
                     "TrafficPolicyInstanceAlreadyExists" => {
-                        return RusotoError::Service(
-                            CreateTrafficPolicyInstanceError::TrafficPolicyInstanceAlreadyExists(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateTrafficPolicyInstanceError::TrafficPolicyInstanceAlreadyExists,
                         )
                     }
                     _ => {}
@@ -9072,7 +9226,7 @@ impl CreateTrafficPolicyVersionError {
             find_start_element(&mut stack);
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
-                                    "ConcurrentModification" => return RusotoError::Service(CreateTrafficPolicyVersionError::ConcurrentModification(parsed_error.message)),"InvalidInput" => return RusotoError::Service(CreateTrafficPolicyVersionError::InvalidInput(parsed_error.message)),"InvalidTrafficPolicyDocument" => return RusotoError::Service(CreateTrafficPolicyVersionError::InvalidTrafficPolicyDocument(parsed_error.message)),"NoSuchTrafficPolicy" => return RusotoError::Service(CreateTrafficPolicyVersionError::NoSuchTrafficPolicy(parsed_error.message)),"TooManyTrafficPolicyVersionsForCurrentPolicy" => return RusotoError::Service(CreateTrafficPolicyVersionError::TooManyTrafficPolicyVersionsForCurrentPolicy(parsed_error.message)),_ => {}
+                                    "ConcurrentModification" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateTrafficPolicyVersionError::ConcurrentModification),"InvalidInput" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateTrafficPolicyVersionError::InvalidInput),"InvalidTrafficPolicyDocument" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateTrafficPolicyVersionError::InvalidTrafficPolicyDocument),"NoSuchTrafficPolicy" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateTrafficPolicyVersionError::NoSuchTrafficPolicy),"TooManyTrafficPolicyVersionsForCurrentPolicy" => return RusotoError::service_error(&res, &parsed_error.code, parsed_error.message, CreateTrafficPolicyVersionError::TooManyTrafficPolicyVersionsForCurrentPolicy),_ => {}
                                 }
             }
         }
@@ -9134,37 +9288,42 @@ impl CreateVPCAssociationAuthorizationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(
-                            CreateVPCAssociationAuthorizationError::ConcurrentModification(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateVPCAssociationAuthorizationError::ConcurrentModification,
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(
-                            CreateVPCAssociationAuthorizationError::InvalidInput(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateVPCAssociationAuthorizationError::InvalidInput,
                         )
                     }
                     "InvalidVPCId" => {
-                        return RusotoError::Service(
-                            CreateVPCAssociationAuthorizationError::InvalidVPCId(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateVPCAssociationAuthorizationError::InvalidVPCId,
                         )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(
-                            CreateVPCAssociationAuthorizationError::NoSuchHostedZone(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            CreateVPCAssociationAuthorizationError::NoSuchHostedZone,
                         )
                     }
-                    "TooManyVPCAssociationAuthorizations" => return RusotoError::Service(
-                        CreateVPCAssociationAuthorizationError::TooManyVPCAssociationAuthorizations(
-                            parsed_error.message,
-                        ),
+                    "TooManyVPCAssociationAuthorizations" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        CreateVPCAssociationAuthorizationError::TooManyVPCAssociationAuthorizations,
                     ),
                     _ => {}
                 }
@@ -9230,41 +9389,51 @@ impl DeactivateKeySigningKeyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(
-                            DeactivateKeySigningKeyError::ConcurrentModification(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeactivateKeySigningKeyError::ConcurrentModification,
                         )
                     }
                     "InvalidKeySigningKeyStatus" => {
-                        return RusotoError::Service(
-                            DeactivateKeySigningKeyError::InvalidKeySigningKeyStatus(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeactivateKeySigningKeyError::InvalidKeySigningKeyStatus,
                         )
                     }
                     "InvalidSigningStatus" => {
-                        return RusotoError::Service(
-                            DeactivateKeySigningKeyError::InvalidSigningStatus(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeactivateKeySigningKeyError::InvalidSigningStatus,
                         )
                     }
                     "KeySigningKeyInParentDSRecord" => {
-                        return RusotoError::Service(
-                            DeactivateKeySigningKeyError::KeySigningKeyInParentDSRecord(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeactivateKeySigningKeyError::KeySigningKeyInParentDSRecord,
                         )
                     }
                     "KeySigningKeyInUse" => {
-                        return RusotoError::Service(
-                            DeactivateKeySigningKeyError::KeySigningKeyInUse(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeactivateKeySigningKeyError::KeySigningKeyInUse,
                         )
                     }
                     "NoSuchKeySigningKey" => {
-                        return RusotoError::Service(
-                            DeactivateKeySigningKeyError::NoSuchKeySigningKey(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeactivateKeySigningKeyError::NoSuchKeySigningKey,
                         )
                     }
                     _ => {}
@@ -9322,19 +9491,28 @@ impl DeleteHealthCheckError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "HealthCheckInUse" => {
-                        return RusotoError::Service(DeleteHealthCheckError::HealthCheckInUse(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteHealthCheckError::HealthCheckInUse,
+                        )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(DeleteHealthCheckError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteHealthCheckError::InvalidInput,
+                        )
                     }
                     "NoSuchHealthCheck" => {
-                        return RusotoError::Service(DeleteHealthCheckError::NoSuchHealthCheck(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteHealthCheckError::NoSuchHealthCheck,
+                        )
                     }
                     _ => {}
                 }
@@ -9386,28 +9564,43 @@ impl DeleteHostedZoneError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "HostedZoneNotEmpty" => {
-                        return RusotoError::Service(DeleteHostedZoneError::HostedZoneNotEmpty(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteHostedZoneError::HostedZoneNotEmpty,
+                        )
                     }
                     "InvalidDomainName" => {
-                        return RusotoError::Service(DeleteHostedZoneError::InvalidDomainName(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteHostedZoneError::InvalidDomainName,
+                        )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(DeleteHostedZoneError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteHostedZoneError::InvalidInput,
+                        )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(DeleteHostedZoneError::NoSuchHostedZone(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteHostedZoneError::NoSuchHostedZone,
+                        )
                     }
                     "PriorRequestNotComplete" => {
-                        return RusotoError::Service(
-                            DeleteHostedZoneError::PriorRequestNotComplete(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteHostedZoneError::PriorRequestNotComplete,
                         )
                     }
                     _ => {}
@@ -9462,31 +9655,44 @@ impl DeleteKeySigningKeyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(
-                            DeleteKeySigningKeyError::ConcurrentModification(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteKeySigningKeyError::ConcurrentModification,
                         )
                     }
                     "InvalidKMSArn" => {
-                        return RusotoError::Service(DeleteKeySigningKeyError::InvalidKMSArn(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteKeySigningKeyError::InvalidKMSArn,
+                        )
                     }
                     "InvalidKeySigningKeyStatus" => {
-                        return RusotoError::Service(
-                            DeleteKeySigningKeyError::InvalidKeySigningKeyStatus(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteKeySigningKeyError::InvalidKeySigningKeyStatus,
                         )
                     }
                     "InvalidSigningStatus" => {
-                        return RusotoError::Service(
-                            DeleteKeySigningKeyError::InvalidSigningStatus(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteKeySigningKeyError::InvalidSigningStatus,
                         )
                     }
                     "NoSuchKeySigningKey" => {
-                        return RusotoError::Service(DeleteKeySigningKeyError::NoSuchKeySigningKey(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteKeySigningKeyError::NoSuchKeySigningKey,
+                        )
                     }
                     _ => {}
                 }
@@ -9538,22 +9744,27 @@ impl DeleteQueryLoggingConfigError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(
-                            DeleteQueryLoggingConfigError::ConcurrentModification(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteQueryLoggingConfigError::ConcurrentModification,
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(DeleteQueryLoggingConfigError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteQueryLoggingConfigError::InvalidInput,
+                        )
                     }
                     "NoSuchQueryLoggingConfig" => {
-                        return RusotoError::Service(
-                            DeleteQueryLoggingConfigError::NoSuchQueryLoggingConfig(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteQueryLoggingConfigError::NoSuchQueryLoggingConfig,
                         )
                     }
                     _ => {}
@@ -9610,29 +9821,35 @@ impl DeleteReusableDelegationSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DelegationSetInUse" => {
-                        return RusotoError::Service(
-                            DeleteReusableDelegationSetError::DelegationSetInUse(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteReusableDelegationSetError::DelegationSetInUse,
                         )
                     }
                     "DelegationSetNotReusable" => {
-                        return RusotoError::Service(
-                            DeleteReusableDelegationSetError::DelegationSetNotReusable(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteReusableDelegationSetError::DelegationSetNotReusable,
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(
-                            DeleteReusableDelegationSetError::InvalidInput(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteReusableDelegationSetError::InvalidInput,
                         )
                     }
                     "NoSuchDelegationSet" => {
-                        return RusotoError::Service(
-                            DeleteReusableDelegationSetError::NoSuchDelegationSet(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteReusableDelegationSetError::NoSuchDelegationSet,
                         )
                     }
                     _ => {}
@@ -9690,24 +9907,36 @@ impl DeleteTrafficPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(
-                            DeleteTrafficPolicyError::ConcurrentModification(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteTrafficPolicyError::ConcurrentModification,
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(DeleteTrafficPolicyError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteTrafficPolicyError::InvalidInput,
+                        )
                     }
                     "NoSuchTrafficPolicy" => {
-                        return RusotoError::Service(DeleteTrafficPolicyError::NoSuchTrafficPolicy(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteTrafficPolicyError::NoSuchTrafficPolicy,
+                        )
                     }
                     "TrafficPolicyInUse" => {
-                        return RusotoError::Service(DeleteTrafficPolicyError::TrafficPolicyInUse(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DeleteTrafficPolicyError::TrafficPolicyInUse,
+                        )
                     }
                     _ => {}
                 }
@@ -9758,22 +9987,27 @@ impl DeleteTrafficPolicyInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
-                            DeleteTrafficPolicyInstanceError::InvalidInput(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteTrafficPolicyInstanceError::InvalidInput,
                         )
                     }
                     "NoSuchTrafficPolicyInstance" => {
-                        return RusotoError::Service(
-                            DeleteTrafficPolicyInstanceError::NoSuchTrafficPolicyInstance(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteTrafficPolicyInstanceError::NoSuchTrafficPolicyInstance,
                         )
                     }
                     "PriorRequestNotComplete" => {
-                        return RusotoError::Service(
-                            DeleteTrafficPolicyInstanceError::PriorRequestNotComplete(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteTrafficPolicyInstanceError::PriorRequestNotComplete,
                         )
                     }
                     _ => {}
@@ -9832,37 +10066,42 @@ impl DeleteVPCAssociationAuthorizationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(
-                            DeleteVPCAssociationAuthorizationError::ConcurrentModification(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteVPCAssociationAuthorizationError::ConcurrentModification,
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(
-                            DeleteVPCAssociationAuthorizationError::InvalidInput(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteVPCAssociationAuthorizationError::InvalidInput,
                         )
                     }
                     "InvalidVPCId" => {
-                        return RusotoError::Service(
-                            DeleteVPCAssociationAuthorizationError::InvalidVPCId(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteVPCAssociationAuthorizationError::InvalidVPCId,
                         )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(
-                            DeleteVPCAssociationAuthorizationError::NoSuchHostedZone(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DeleteVPCAssociationAuthorizationError::NoSuchHostedZone,
                         )
                     }
-                    "VPCAssociationAuthorizationNotFound" => return RusotoError::Service(
-                        DeleteVPCAssociationAuthorizationError::VPCAssociationAuthorizationNotFound(
-                            parsed_error.message,
-                        ),
+                    "VPCAssociationAuthorizationNotFound" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        DeleteVPCAssociationAuthorizationError::VPCAssociationAuthorizationNotFound,
                     ),
                     _ => {}
                 }
@@ -9930,44 +10169,59 @@ impl DisableHostedZoneDNSSECError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(
-                            DisableHostedZoneDNSSECError::ConcurrentModification(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DisableHostedZoneDNSSECError::ConcurrentModification,
                         )
                     }
                     "DNSSECNotFound" => {
-                        return RusotoError::Service(DisableHostedZoneDNSSECError::DNSSECNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DisableHostedZoneDNSSECError::DNSSECNotFound,
+                        )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(DisableHostedZoneDNSSECError::InvalidArgument(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DisableHostedZoneDNSSECError::InvalidArgument,
+                        )
                     }
                     "InvalidKMSArn" => {
-                        return RusotoError::Service(DisableHostedZoneDNSSECError::InvalidKMSArn(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            DisableHostedZoneDNSSECError::InvalidKMSArn,
+                        )
                     }
                     "InvalidKeySigningKeyStatus" => {
-                        return RusotoError::Service(
-                            DisableHostedZoneDNSSECError::InvalidKeySigningKeyStatus(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DisableHostedZoneDNSSECError::InvalidKeySigningKeyStatus,
                         )
                     }
                     "KeySigningKeyInParentDSRecord" => {
-                        return RusotoError::Service(
-                            DisableHostedZoneDNSSECError::KeySigningKeyInParentDSRecord(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DisableHostedZoneDNSSECError::KeySigningKeyInParentDSRecord,
                         )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(
-                            DisableHostedZoneDNSSECError::NoSuchHostedZone(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DisableHostedZoneDNSSECError::NoSuchHostedZone,
                         )
                     }
                     _ => {}
@@ -10032,34 +10286,43 @@ impl DisassociateVPCFromHostedZoneError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
-                            DisassociateVPCFromHostedZoneError::InvalidInput(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DisassociateVPCFromHostedZoneError::InvalidInput,
                         )
                     }
                     "InvalidVPCId" => {
-                        return RusotoError::Service(
-                            DisassociateVPCFromHostedZoneError::InvalidVPCId(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DisassociateVPCFromHostedZoneError::InvalidVPCId,
                         )
                     }
                     "LastVPCAssociation" => {
-                        return RusotoError::Service(
-                            DisassociateVPCFromHostedZoneError::LastVPCAssociation(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DisassociateVPCFromHostedZoneError::LastVPCAssociation,
                         )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(
-                            DisassociateVPCFromHostedZoneError::NoSuchHostedZone(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DisassociateVPCFromHostedZoneError::NoSuchHostedZone,
                         )
                     }
                     "VPCAssociationNotFound" => {
-                        return RusotoError::Service(
-                            DisassociateVPCFromHostedZoneError::VPCAssociationNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            DisassociateVPCFromHostedZoneError::VPCAssociationNotFound,
                         )
                     }
                     _ => {}
@@ -10126,52 +10389,68 @@ impl EnableHostedZoneDNSSECError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(
-                            EnableHostedZoneDNSSECError::ConcurrentModification(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            EnableHostedZoneDNSSECError::ConcurrentModification,
                         )
                     }
                     "DNSSECNotFound" => {
-                        return RusotoError::Service(EnableHostedZoneDNSSECError::DNSSECNotFound(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            EnableHostedZoneDNSSECError::DNSSECNotFound,
+                        )
                     }
                     "HostedZonePartiallyDelegated" => {
-                        return RusotoError::Service(
-                            EnableHostedZoneDNSSECError::HostedZonePartiallyDelegated(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            EnableHostedZoneDNSSECError::HostedZonePartiallyDelegated,
                         )
                     }
                     "InvalidArgument" => {
-                        return RusotoError::Service(EnableHostedZoneDNSSECError::InvalidArgument(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            EnableHostedZoneDNSSECError::InvalidArgument,
+                        )
                     }
                     "InvalidKMSArn" => {
-                        return RusotoError::Service(EnableHostedZoneDNSSECError::InvalidKMSArn(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            EnableHostedZoneDNSSECError::InvalidKMSArn,
+                        )
                     }
                     "InvalidKeySigningKeyStatus" => {
-                        return RusotoError::Service(
-                            EnableHostedZoneDNSSECError::InvalidKeySigningKeyStatus(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            EnableHostedZoneDNSSECError::InvalidKeySigningKeyStatus,
                         )
                     }
                     "KeySigningKeyWithActiveStatusNotFound" => {
-                        return RusotoError::Service(
-                            EnableHostedZoneDNSSECError::KeySigningKeyWithActiveStatusNotFound(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            EnableHostedZoneDNSSECError::KeySigningKeyWithActiveStatusNotFound,
                         )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(EnableHostedZoneDNSSECError::NoSuchHostedZone(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            EnableHostedZoneDNSSECError::NoSuchHostedZone,
+                        )
                     }
                     _ => {}
                 }
@@ -10228,9 +10507,12 @@ impl GetAccountLimitError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(GetAccountLimitError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetAccountLimitError::InvalidInput,
+                        )
                     }
                     _ => {}
                 }
@@ -10274,14 +10556,20 @@ impl GetChangeError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(GetChangeError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetChangeError::InvalidInput,
+                        )
                     }
                     "NoSuchChange" => {
-                        return RusotoError::Service(GetChangeError::NoSuchChange(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetChangeError::NoSuchChange,
+                        )
                     }
                     _ => {}
                 }
@@ -10360,14 +10648,20 @@ impl GetDNSSECError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidArgument" => {
-                        return RusotoError::Service(GetDNSSECError::InvalidArgument(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetDNSSECError::InvalidArgument,
+                        )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(GetDNSSECError::NoSuchHostedZone(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetDNSSECError::NoSuchHostedZone,
+                        )
                     }
                     _ => {}
                 }
@@ -10412,14 +10706,20 @@ impl GetGeoLocationError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(GetGeoLocationError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetGeoLocationError::InvalidInput,
+                        )
                     }
                     "NoSuchGeoLocation" => {
-                        return RusotoError::Service(GetGeoLocationError::NoSuchGeoLocation(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetGeoLocationError::NoSuchGeoLocation,
+                        )
                     }
                     _ => {}
                 }
@@ -10466,19 +10766,28 @@ impl GetHealthCheckError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "IncompatibleVersion" => {
-                        return RusotoError::Service(GetHealthCheckError::IncompatibleVersion(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetHealthCheckError::IncompatibleVersion,
+                        )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(GetHealthCheckError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetHealthCheckError::InvalidInput,
+                        )
                     }
                     "NoSuchHealthCheck" => {
-                        return RusotoError::Service(GetHealthCheckError::NoSuchHealthCheck(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetHealthCheckError::NoSuchHealthCheck,
+                        )
                     }
                     _ => {}
                 }
@@ -10560,17 +10869,19 @@ impl GetHealthCheckLastFailureReasonError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
-                            GetHealthCheckLastFailureReasonError::InvalidInput(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetHealthCheckLastFailureReasonError::InvalidInput,
                         )
                     }
                     "NoSuchHealthCheck" => {
-                        return RusotoError::Service(
-                            GetHealthCheckLastFailureReasonError::NoSuchHealthCheck(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetHealthCheckLastFailureReasonError::NoSuchHealthCheck,
                         )
                     }
                     _ => {}
@@ -10618,14 +10929,20 @@ impl GetHealthCheckStatusError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(GetHealthCheckStatusError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetHealthCheckStatusError::InvalidInput,
+                        )
                     }
                     "NoSuchHealthCheck" => {
-                        return RusotoError::Service(GetHealthCheckStatusError::NoSuchHealthCheck(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetHealthCheckStatusError::NoSuchHealthCheck,
+                        )
                     }
                     _ => {}
                 }
@@ -10670,14 +10987,20 @@ impl GetHostedZoneError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(GetHostedZoneError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetHostedZoneError::InvalidInput,
+                        )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(GetHostedZoneError::NoSuchHostedZone(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetHostedZoneError::NoSuchHostedZone,
+                        )
                     }
                     _ => {}
                 }
@@ -10720,9 +11043,12 @@ impl GetHostedZoneCountError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(GetHostedZoneCountError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetHostedZoneCountError::InvalidInput,
+                        )
                     }
                     _ => {}
                 }
@@ -10768,19 +11094,28 @@ impl GetHostedZoneLimitError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "HostedZoneNotPrivate" => {
-                        return RusotoError::Service(GetHostedZoneLimitError::HostedZoneNotPrivate(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetHostedZoneLimitError::HostedZoneNotPrivate,
+                        )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(GetHostedZoneLimitError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetHostedZoneLimitError::InvalidInput,
+                        )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(GetHostedZoneLimitError::NoSuchHostedZone(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetHostedZoneLimitError::NoSuchHostedZone,
+                        )
                     }
                     _ => {}
                 }
@@ -10826,15 +11161,19 @@ impl GetQueryLoggingConfigError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(GetQueryLoggingConfigError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetQueryLoggingConfigError::InvalidInput,
+                        )
                     }
                     "NoSuchQueryLoggingConfig" => {
-                        return RusotoError::Service(
-                            GetQueryLoggingConfigError::NoSuchQueryLoggingConfig(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetQueryLoggingConfigError::NoSuchQueryLoggingConfig,
                         )
                     }
                     _ => {}
@@ -10884,22 +11223,27 @@ impl GetReusableDelegationSetError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DelegationSetNotReusable" => {
-                        return RusotoError::Service(
-                            GetReusableDelegationSetError::DelegationSetNotReusable(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetReusableDelegationSetError::DelegationSetNotReusable,
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(GetReusableDelegationSetError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetReusableDelegationSetError::InvalidInput,
+                        )
                     }
                     "NoSuchDelegationSet" => {
-                        return RusotoError::Service(
-                            GetReusableDelegationSetError::NoSuchDelegationSet(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetReusableDelegationSetError::NoSuchDelegationSet,
                         )
                     }
                     _ => {}
@@ -10950,15 +11294,19 @@ impl GetReusableDelegationSetLimitError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
-                            GetReusableDelegationSetLimitError::InvalidInput(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetReusableDelegationSetLimitError::InvalidInput,
                         )
                     }
                     "NoSuchDelegationSet" => {
-                        return RusotoError::Service(
-                            GetReusableDelegationSetLimitError::NoSuchDelegationSet(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetReusableDelegationSetLimitError::NoSuchDelegationSet,
                         )
                     }
                     _ => {}
@@ -11006,14 +11354,20 @@ impl GetTrafficPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(GetTrafficPolicyError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetTrafficPolicyError::InvalidInput,
+                        )
                     }
                     "NoSuchTrafficPolicy" => {
-                        return RusotoError::Service(GetTrafficPolicyError::NoSuchTrafficPolicy(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetTrafficPolicyError::NoSuchTrafficPolicy,
+                        )
                     }
                     _ => {}
                 }
@@ -11058,15 +11412,19 @@ impl GetTrafficPolicyInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(GetTrafficPolicyInstanceError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetTrafficPolicyInstanceError::InvalidInput,
+                        )
                     }
                     "NoSuchTrafficPolicyInstance" => {
-                        return RusotoError::Service(
-                            GetTrafficPolicyInstanceError::NoSuchTrafficPolicyInstance(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            GetTrafficPolicyInstanceError::NoSuchTrafficPolicyInstance,
                         )
                     }
                     _ => {}
@@ -11148,9 +11506,12 @@ impl ListGeoLocationsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ListGeoLocationsError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListGeoLocationsError::InvalidInput,
+                        )
                     }
                     _ => {}
                 }
@@ -11194,14 +11555,20 @@ impl ListHealthChecksError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "IncompatibleVersion" => {
-                        return RusotoError::Service(ListHealthChecksError::IncompatibleVersion(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListHealthChecksError::IncompatibleVersion,
+                        )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(ListHealthChecksError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListHealthChecksError::InvalidInput,
+                        )
                     }
                     _ => {}
                 }
@@ -11248,19 +11615,28 @@ impl ListHostedZonesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "DelegationSetNotReusable" => {
-                        return RusotoError::Service(
-                            ListHostedZonesError::DelegationSetNotReusable(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListHostedZonesError::DelegationSetNotReusable,
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(ListHostedZonesError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListHostedZonesError::InvalidInput,
+                        )
                     }
                     "NoSuchDelegationSet" => {
-                        return RusotoError::Service(ListHostedZonesError::NoSuchDelegationSet(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListHostedZonesError::NoSuchDelegationSet,
+                        )
                     }
                     _ => {}
                 }
@@ -11306,14 +11682,20 @@ impl ListHostedZonesByNameError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidDomainName" => {
-                        return RusotoError::Service(ListHostedZonesByNameError::InvalidDomainName(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListHostedZonesByNameError::InvalidDomainName,
+                        )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(ListHostedZonesByNameError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListHostedZonesByNameError::InvalidInput,
+                        )
                     }
                     _ => {}
                 }
@@ -11358,13 +11740,19 @@ impl ListHostedZonesByVPCError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ListHostedZonesByVPCError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListHostedZonesByVPCError::InvalidInput,
+                        )
                     }
                     "InvalidPaginationToken" => {
-                        return RusotoError::Service(
-                            ListHostedZonesByVPCError::InvalidPaginationToken(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListHostedZonesByVPCError::InvalidPaginationToken,
                         )
                     }
                     _ => {}
@@ -11412,20 +11800,27 @@ impl ListQueryLoggingConfigsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ListQueryLoggingConfigsError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListQueryLoggingConfigsError::InvalidInput,
+                        )
                     }
                     "InvalidPaginationToken" => {
-                        return RusotoError::Service(
-                            ListQueryLoggingConfigsError::InvalidPaginationToken(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListQueryLoggingConfigsError::InvalidPaginationToken,
                         )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(
-                            ListQueryLoggingConfigsError::NoSuchHostedZone(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListQueryLoggingConfigsError::NoSuchHostedZone,
                         )
                     }
                     _ => {}
@@ -11474,14 +11869,20 @@ impl ListResourceRecordSetsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ListResourceRecordSetsError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListResourceRecordSetsError::InvalidInput,
+                        )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(ListResourceRecordSetsError::NoSuchHostedZone(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListResourceRecordSetsError::NoSuchHostedZone,
+                        )
                     }
                     _ => {}
                 }
@@ -11526,9 +11927,12 @@ impl ListReusableDelegationSetsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ListReusableDelegationSetsError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListReusableDelegationSetsError::InvalidInput,
+                        )
                     }
                     _ => {}
                 }
@@ -11578,29 +11982,44 @@ impl ListTagsForResourceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ListTagsForResourceError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListTagsForResourceError::InvalidInput,
+                        )
                     }
                     "NoSuchHealthCheck" => {
-                        return RusotoError::Service(ListTagsForResourceError::NoSuchHealthCheck(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListTagsForResourceError::NoSuchHealthCheck,
+                        )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(ListTagsForResourceError::NoSuchHostedZone(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListTagsForResourceError::NoSuchHostedZone,
+                        )
                     }
                     "PriorRequestNotComplete" => {
-                        return RusotoError::Service(
-                            ListTagsForResourceError::PriorRequestNotComplete(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListTagsForResourceError::PriorRequestNotComplete,
                         )
                     }
                     "ThrottlingException" => {
-                        return RusotoError::Service(ListTagsForResourceError::Throttling(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListTagsForResourceError::Throttling,
+                        )
                     }
                     _ => {}
                 }
@@ -11654,31 +12073,44 @@ impl ListTagsForResourcesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ListTagsForResourcesError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListTagsForResourcesError::InvalidInput,
+                        )
                     }
                     "NoSuchHealthCheck" => {
-                        return RusotoError::Service(ListTagsForResourcesError::NoSuchHealthCheck(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListTagsForResourcesError::NoSuchHealthCheck,
+                        )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(ListTagsForResourcesError::NoSuchHostedZone(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListTagsForResourcesError::NoSuchHostedZone,
+                        )
                     }
                     "PriorRequestNotComplete" => {
-                        return RusotoError::Service(
-                            ListTagsForResourcesError::PriorRequestNotComplete(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListTagsForResourcesError::PriorRequestNotComplete,
                         )
                     }
                     "ThrottlingException" => {
-                        return RusotoError::Service(ListTagsForResourcesError::Throttling(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListTagsForResourcesError::Throttling,
+                        )
                     }
                     _ => {}
                 }
@@ -11724,9 +12156,12 @@ impl ListTrafficPoliciesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ListTrafficPoliciesError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListTrafficPoliciesError::InvalidInput,
+                        )
                     }
                     _ => {}
                 }
@@ -11772,15 +12207,19 @@ impl ListTrafficPolicyInstancesError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ListTrafficPolicyInstancesError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListTrafficPolicyInstancesError::InvalidInput,
+                        )
                     }
                     "NoSuchTrafficPolicyInstance" => {
-                        return RusotoError::Service(
-                            ListTrafficPolicyInstancesError::NoSuchTrafficPolicyInstance(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListTrafficPolicyInstancesError::NoSuchTrafficPolicyInstance,
                         )
                     }
                     _ => {}
@@ -11832,23 +12271,26 @@ impl ListTrafficPolicyInstancesByHostedZoneError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
-                            ListTrafficPolicyInstancesByHostedZoneError::InvalidInput(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListTrafficPolicyInstancesByHostedZoneError::InvalidInput,
                         )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(
-                            ListTrafficPolicyInstancesByHostedZoneError::NoSuchHostedZone(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListTrafficPolicyInstancesByHostedZoneError::NoSuchHostedZone,
                         )
                     }
-                    "NoSuchTrafficPolicyInstance" => return RusotoError::Service(
-                        ListTrafficPolicyInstancesByHostedZoneError::NoSuchTrafficPolicyInstance(
-                            parsed_error.message,
-                        ),
+                    "NoSuchTrafficPolicyInstance" => return RusotoError::service_error(
+                        &res,
+                        &parsed_error.code,
+                        parsed_error.message,
+                        ListTrafficPolicyInstancesByHostedZoneError::NoSuchTrafficPolicyInstance,
                     ),
                     _ => {}
                 }
@@ -11904,24 +12346,27 @@ impl ListTrafficPolicyInstancesByPolicyError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
-                            ListTrafficPolicyInstancesByPolicyError::InvalidInput(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListTrafficPolicyInstancesByPolicyError::InvalidInput,
                         )
                     }
                     "NoSuchTrafficPolicy" => {
-                        return RusotoError::Service(
-                            ListTrafficPolicyInstancesByPolicyError::NoSuchTrafficPolicy(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListTrafficPolicyInstancesByPolicyError::NoSuchTrafficPolicy,
                         )
                     }
                     "NoSuchTrafficPolicyInstance" => {
-                        return RusotoError::Service(
-                            ListTrafficPolicyInstancesByPolicyError::NoSuchTrafficPolicyInstance(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListTrafficPolicyInstancesByPolicyError::NoSuchTrafficPolicyInstance,
                         )
                     }
                     _ => {}
@@ -11974,15 +12419,19 @@ impl ListTrafficPolicyVersionsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(ListTrafficPolicyVersionsError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListTrafficPolicyVersionsError::InvalidInput,
+                        )
                     }
                     "NoSuchTrafficPolicy" => {
-                        return RusotoError::Service(
-                            ListTrafficPolicyVersionsError::NoSuchTrafficPolicy(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListTrafficPolicyVersionsError::NoSuchTrafficPolicy,
                         )
                     }
                     _ => {}
@@ -12034,24 +12483,27 @@ impl ListVPCAssociationAuthorizationsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(
-                            ListVPCAssociationAuthorizationsError::InvalidInput(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListVPCAssociationAuthorizationsError::InvalidInput,
                         )
                     }
                     "InvalidPaginationToken" => {
-                        return RusotoError::Service(
-                            ListVPCAssociationAuthorizationsError::InvalidPaginationToken(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListVPCAssociationAuthorizationsError::InvalidPaginationToken,
                         )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(
-                            ListVPCAssociationAuthorizationsError::NoSuchHostedZone(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            ListVPCAssociationAuthorizationsError::NoSuchHostedZone,
                         )
                     }
                     _ => {}
@@ -12104,14 +12556,20 @@ impl TestDNSAnswerError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(TestDNSAnswerError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            TestDNSAnswerError::InvalidInput,
+                        )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(TestDNSAnswerError::NoSuchHostedZone(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            TestDNSAnswerError::NoSuchHostedZone,
+                        )
                     }
                     _ => {}
                 }
@@ -12158,21 +12616,28 @@ impl UpdateHealthCheckError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "HealthCheckVersionMismatch" => {
-                        return RusotoError::Service(
-                            UpdateHealthCheckError::HealthCheckVersionMismatch(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateHealthCheckError::HealthCheckVersionMismatch,
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(UpdateHealthCheckError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateHealthCheckError::InvalidInput,
+                        )
                     }
                     "NoSuchHealthCheck" => {
-                        return RusotoError::Service(UpdateHealthCheckError::NoSuchHealthCheck(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateHealthCheckError::NoSuchHealthCheck,
+                        )
                     }
                     _ => {}
                 }
@@ -12218,13 +12683,19 @@ impl UpdateHostedZoneCommentError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidInput" => {
-                        return RusotoError::Service(UpdateHostedZoneCommentError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateHostedZoneCommentError::InvalidInput,
+                        )
                     }
                     "NoSuchHostedZone" => {
-                        return RusotoError::Service(
-                            UpdateHostedZoneCommentError::NoSuchHostedZone(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateHostedZoneCommentError::NoSuchHostedZone,
                         )
                     }
                     _ => {}
@@ -12274,22 +12745,27 @@ impl UpdateTrafficPolicyCommentError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConcurrentModification" => {
-                        return RusotoError::Service(
-                            UpdateTrafficPolicyCommentError::ConcurrentModification(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateTrafficPolicyCommentError::ConcurrentModification,
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(UpdateTrafficPolicyCommentError::InvalidInput(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            UpdateTrafficPolicyCommentError::InvalidInput,
+                        )
                     }
                     "NoSuchTrafficPolicy" => {
-                        return RusotoError::Service(
-                            UpdateTrafficPolicyCommentError::NoSuchTrafficPolicy(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateTrafficPolicyCommentError::NoSuchTrafficPolicy,
                         )
                     }
                     _ => {}
@@ -12348,36 +12824,43 @@ impl UpdateTrafficPolicyInstanceError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ConflictingTypes" => {
-                        return RusotoError::Service(
-                            UpdateTrafficPolicyInstanceError::ConflictingTypes(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateTrafficPolicyInstanceError::ConflictingTypes,
                         )
                     }
                     "InvalidInput" => {
-                        return RusotoError::Service(
-                            UpdateTrafficPolicyInstanceError::InvalidInput(parsed_error.message),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateTrafficPolicyInstanceError::InvalidInput,
                         )
                     }
                     "NoSuchTrafficPolicy" => {
-                        return RusotoError::Service(
-                            UpdateTrafficPolicyInstanceError::NoSuchTrafficPolicy(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateTrafficPolicyInstanceError::NoSuchTrafficPolicy,
                         )
                     }
                     "NoSuchTrafficPolicyInstance" => {
-                        return RusotoError::Service(
-                            UpdateTrafficPolicyInstanceError::NoSuchTrafficPolicyInstance(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateTrafficPolicyInstanceError::NoSuchTrafficPolicyInstance,
                         )
                     }
                     "PriorRequestNotComplete" => {
-                        return RusotoError::Service(
-                            UpdateTrafficPolicyInstanceError::PriorRequestNotComplete(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            UpdateTrafficPolicyInstanceError::PriorRequestNotComplete,
                         )
                     }
                     _ => {}
@@ -12839,11 +13322,12 @@ pub struct Route53Client {
 impl Route53Client {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> Route53Client {
         Route53Client {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Route 53"),
         }
     }
 
@@ -12858,12 +13342,32 @@ impl Route53Client {
     {
         Route53Client {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Route 53"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> Route53Client {
-        Route53Client { client, region }
+        Route53Client {
+            client,
+            region: region_for_service(region, "Route 53"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> Route53Client {
+        Route53Client {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Route 53"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> Route53Client {
+        Route53Client {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -12885,6 +13389,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("ActivateKeySigningKey");
 
         let mut response = self
             .sign_and_dispatch(request, ActivateKeySigningKeyError::from_response)
@@ -12916,6 +13421,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("AssociateVPCWithHostedZone");
 
         let mut writer = EventWriter::new(Vec::new());
         AssociateVPCWithHostedZoneRequestSerializer::serialize(
@@ -12956,6 +13462,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("ChangeResourceRecordSets");
 
         let mut writer = EventWriter::new(Vec::new());
         ChangeResourceRecordSetsRequestSerializer::serialize(
@@ -12996,6 +13503,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("ChangeTagsForResource");
 
         let mut writer = EventWriter::new(Vec::new());
         ChangeTagsForResourceRequestSerializer::serialize(
@@ -13025,6 +13533,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/healthcheck";
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateHealthCheck");
 
         let mut writer = EventWriter::new(Vec::new());
         CreateHealthCheckRequestSerializer::serialize(
@@ -13059,6 +13568,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/hostedzone";
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateHostedZone");
 
         let mut writer = EventWriter::new(Vec::new());
         CreateHostedZoneRequestSerializer::serialize(
@@ -13093,6 +13603,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/keysigningkey";
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateKeySigningKey");
 
         let mut writer = EventWriter::new(Vec::new());
         CreateKeySigningKeyRequestSerializer::serialize(
@@ -13127,6 +13638,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/queryloggingconfig";
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateQueryLoggingConfig");
 
         let mut writer = EventWriter::new(Vec::new());
         CreateQueryLoggingConfigRequestSerializer::serialize(
@@ -13162,6 +13674,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/delegationset";
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateReusableDelegationSet");
 
         let mut writer = EventWriter::new(Vec::new());
         CreateReusableDelegationSetRequestSerializer::serialize(
@@ -13196,6 +13709,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/trafficpolicy";
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateTrafficPolicy");
 
         let mut writer = EventWriter::new(Vec::new());
         CreateTrafficPolicyRequestSerializer::serialize(
@@ -13231,6 +13745,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/trafficpolicyinstance";
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateTrafficPolicyInstance");
 
         let mut writer = EventWriter::new(Vec::new());
         CreateTrafficPolicyInstanceRequestSerializer::serialize(
@@ -13269,6 +13784,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateTrafficPolicyVersion");
 
         let mut writer = EventWriter::new(Vec::new());
         CreateTrafficPolicyVersionRequestSerializer::serialize(
@@ -13312,6 +13828,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("CreateVPCAssociationAuthorization");
 
         let mut writer = EventWriter::new(Vec::new());
         CreateVPCAssociationAuthorizationRequestSerializer::serialize(
@@ -13358,6 +13875,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("DeactivateKeySigningKey");
 
         let mut response = self
             .sign_and_dispatch(request, DeactivateKeySigningKeyError::from_response)
@@ -13388,6 +13906,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
+        request.set_operation("DeleteHealthCheck");
 
         let mut response = self
             .sign_and_dispatch(request, DeleteHealthCheckError::from_response)
@@ -13411,6 +13930,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
+        request.set_operation("DeleteHostedZone");
 
         let mut response = self
             .sign_and_dispatch(request, DeleteHostedZoneError::from_response)
@@ -13442,6 +13962,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
+        request.set_operation("DeleteKeySigningKey");
 
         let mut response = self
             .sign_and_dispatch(request, DeleteKeySigningKeyError::from_response)
@@ -13469,6 +13990,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
+        request.set_operation("DeleteQueryLoggingConfig");
 
         let mut response = self
             .sign_and_dispatch(request, DeleteQueryLoggingConfigError::from_response)
@@ -13493,6 +14015,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
+        request.set_operation("DeleteReusableDelegationSet");
 
         let mut response = self
             .sign_and_dispatch(request, DeleteReusableDelegationSetError::from_response)
@@ -13520,6 +14043,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
+        request.set_operation("DeleteTrafficPolicy");
 
         let mut response = self
             .sign_and_dispatch(request, DeleteTrafficPolicyError::from_response)
@@ -13544,6 +14068,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("DELETE", "route53", &self.region, &request_uri);
+        request.set_operation("DeleteTrafficPolicyInstance");
 
         let mut response = self
             .sign_and_dispatch(request, DeleteTrafficPolicyInstanceError::from_response)
@@ -13573,6 +14098,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("DeleteVPCAssociationAuthorization");
 
         let mut writer = EventWriter::new(Vec::new());
         DeleteVPCAssociationAuthorizationRequestSerializer::serialize(
@@ -13611,6 +14137,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("DisableHostedZoneDNSSEC");
 
         let mut response = self
             .sign_and_dispatch(request, DisableHostedZoneDNSSECError::from_response)
@@ -13644,6 +14171,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("DisassociateVPCFromHostedZone");
 
         let mut writer = EventWriter::new(Vec::new());
         DisassociateVPCFromHostedZoneRequestSerializer::serialize(
@@ -13683,6 +14211,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("EnableHostedZoneDNSSEC");
 
         let mut response = self
             .sign_and_dispatch(request, EnableHostedZoneDNSSECError::from_response)
@@ -13710,6 +14239,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetAccountLimit");
 
         let mut response = self
             .sign_and_dispatch(request, GetAccountLimitError::from_response)
@@ -13737,6 +14267,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetChange");
 
         let mut response = self
             .sign_and_dispatch(request, GetChangeError::from_response)
@@ -13761,6 +14292,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/checkeripranges";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetCheckerIpRanges");
 
         let mut response = self
             .sign_and_dispatch(request, GetCheckerIpRangesError::from_response)
@@ -13791,6 +14323,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetDNSSEC");
 
         let mut response = self
             .sign_and_dispatch(request, GetDNSSECError::from_response)
@@ -13815,6 +14348,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/geolocation";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetGeoLocation");
 
         let mut params = Params::new();
         if let Some(ref x) = input.continent_code {
@@ -13857,6 +14391,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetHealthCheck");
 
         let mut response = self
             .sign_and_dispatch(request, GetHealthCheckError::from_response)
@@ -13881,6 +14416,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/healthcheckcount";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetHealthCheckCount");
 
         let mut response = self
             .sign_and_dispatch(request, GetHealthCheckCountError::from_response)
@@ -13914,6 +14450,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetHealthCheckLastFailureReason");
 
         let mut response = self
             .sign_and_dispatch(request, GetHealthCheckLastFailureReasonError::from_response)
@@ -13944,6 +14481,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetHealthCheckStatus");
 
         let mut response = self
             .sign_and_dispatch(request, GetHealthCheckStatusError::from_response)
@@ -13971,6 +14509,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetHostedZone");
 
         let mut response = self
             .sign_and_dispatch(request, GetHostedZoneError::from_response)
@@ -13995,6 +14534,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/hostedzonecount";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetHostedZoneCount");
 
         let mut response = self
             .sign_and_dispatch(request, GetHostedZoneCountError::from_response)
@@ -14019,6 +14559,7 @@ impl Route53 for Route53Client {
         let request_uri = format!("/2013-04-01/hostedzonelimit/{id}/{type}", id = input.hosted_zone_id, type = input.type_).replace("/hostedzone/hostedzone/", "/hostedzone/").replace("/hostedzone//hostedzone/", "/hostedzone/").replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetHostedZoneLimit");
 
         let mut response = self
             .sign_and_dispatch(request, GetHostedZoneLimitError::from_response)
@@ -14046,6 +14587,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetQueryLoggingConfig");
 
         let mut response = self
             .sign_and_dispatch(request, GetQueryLoggingConfigError::from_response)
@@ -14073,6 +14615,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetReusableDelegationSet");
 
         let mut response = self
             .sign_and_dispatch(request, GetReusableDelegationSetError::from_response)
@@ -14100,6 +14643,7 @@ impl Route53 for Route53Client {
         let request_uri = format!("/2013-04-01/reusabledelegationsetlimit/{id}/{type}", id = input.delegation_set_id, type = input.type_).replace("/hostedzone/hostedzone/", "/hostedzone/").replace("/hostedzone//hostedzone/", "/hostedzone/").replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetReusableDelegationSetLimit");
 
         let mut response = self
             .sign_and_dispatch(request, GetReusableDelegationSetLimitError::from_response)
@@ -14131,6 +14675,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetTrafficPolicy");
 
         let mut response = self
             .sign_and_dispatch(request, GetTrafficPolicyError::from_response)
@@ -14158,6 +14703,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetTrafficPolicyInstance");
 
         let mut response = self
             .sign_and_dispatch(request, GetTrafficPolicyInstanceError::from_response)
@@ -14185,6 +14731,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/trafficpolicyinstancecount";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("GetTrafficPolicyInstanceCount");
 
         let mut response = self
             .sign_and_dispatch(request, GetTrafficPolicyInstanceCountError::from_response)
@@ -14209,6 +14756,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/geolocations";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListGeoLocations");
 
         let mut params = Params::new();
         if let Some(ref x) = input.max_items {
@@ -14248,6 +14796,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/healthcheck";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListHealthChecks");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -14281,6 +14830,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/hostedzone";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListHostedZones");
 
         let mut params = Params::new();
         if let Some(ref x) = input.delegation_set_id {
@@ -14317,6 +14867,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/hostedzonesbyname";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListHostedZonesByName");
 
         let mut params = Params::new();
         if let Some(ref x) = input.dns_name {
@@ -14353,6 +14904,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/hostedzonesbyvpc";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListHostedZonesByVPC");
 
         let mut params = Params::new();
         if let Some(ref x) = input.max_items {
@@ -14388,6 +14940,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/queryloggingconfig";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListQueryLoggingConfigs");
 
         let mut params = Params::new();
         if let Some(ref x) = input.hosted_zone_id {
@@ -14430,6 +14983,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListResourceRecordSets");
 
         let mut params = Params::new();
         if let Some(ref x) = input.max_items {
@@ -14470,6 +15024,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/delegationset";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListReusableDelegationSets");
 
         let mut params = Params::new();
         if let Some(ref x) = input.marker {
@@ -14510,6 +15065,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListTagsForResource");
 
         let mut response = self
             .sign_and_dispatch(request, ListTagsForResourceError::from_response)
@@ -14540,6 +15096,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("ListTagsForResources");
 
         let mut writer = EventWriter::new(Vec::new());
         ListTagsForResourcesRequestSerializer::serialize(
@@ -14573,6 +15130,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/trafficpolicies";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListTrafficPolicies");
 
         let mut params = Params::new();
         if let Some(ref x) = input.max_items {
@@ -14607,6 +15165,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/trafficpolicyinstances";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListTrafficPolicyInstances");
 
         let mut params = Params::new();
         if let Some(ref x) = input.hosted_zone_id_marker {
@@ -14649,6 +15208,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/trafficpolicyinstances/hostedzone";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListTrafficPolicyInstancesByHostedZone");
 
         let mut params = Params::new();
         params.put("id", &input.hosted_zone_id);
@@ -14695,6 +15255,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/trafficpolicyinstances/trafficpolicy";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListTrafficPolicyInstancesByPolicy");
 
         let mut params = Params::new();
         if let Some(ref x) = input.hosted_zone_id_marker {
@@ -14746,6 +15307,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListTrafficPolicyVersions");
 
         let mut params = Params::new();
         if let Some(ref x) = input.max_items {
@@ -14788,6 +15350,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("ListVPCAssociationAuthorizations");
 
         let mut params = Params::new();
         if let Some(ref x) = input.max_results {
@@ -14827,6 +15390,7 @@ impl Route53 for Route53Client {
         let request_uri = "/2013-04-01/testdnsanswer";
 
         let mut request = SignedRequest::new("GET", "route53", &self.region, &request_uri);
+        request.set_operation("TestDNSAnswer");
 
         let mut params = Params::new();
         if let Some(ref x) = input.edns0_client_subnet_ip {
@@ -14872,6 +15436,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("UpdateHealthCheck");
 
         let mut writer = EventWriter::new(Vec::new());
         UpdateHealthCheckRequestSerializer::serialize(
@@ -14908,6 +15473,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("UpdateHostedZoneComment");
 
         let mut writer = EventWriter::new(Vec::new());
         UpdateHostedZoneCommentRequestSerializer::serialize(
@@ -14949,6 +15515,7 @@ impl Route53 for Route53Client {
         .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("UpdateTrafficPolicyComment");
 
         let mut writer = EventWriter::new(Vec::new());
         UpdateTrafficPolicyCommentRequestSerializer::serialize(
@@ -14986,6 +15553,7 @@ impl Route53 for Route53Client {
             .replace("/change/change/", "/change/");
 
         let mut request = SignedRequest::new("POST", "route53", &self.region, &request_uri);
+        request.set_operation("UpdateTrafficPolicyInstance");
 
         let mut writer = EventWriter::new(Vec::new());
         UpdateTrafficPolicyInstanceRequestSerializer::serialize(
@@ -15011,6 +15579,684 @@ impl Route53 for Route53Client {
     }
 }
 
+/// A blocking client for the Route 53 API, running the calls of a `Route53Client`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `Route53Client`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct Route53BlockingClient {
+    client: Route53Client,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl Route53BlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> Route53BlockingClient {
+        Route53BlockingClient::from_client(Route53Client::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> Route53BlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        Route53BlockingClient::from_client(Route53Client::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> Route53BlockingClient {
+        Route53BlockingClient::from_client(Route53Client::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> Route53BlockingClient {
+        Route53BlockingClient::from_client(Route53Client::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: Route53Client) -> Route53BlockingClient {
+        Route53BlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> Route53BlockingClient {
+        Route53BlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> Route53BlockingClient {
+        Route53BlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Route53::activate_key_signing_key`].
+    pub fn activate_key_signing_key(
+        &self,
+        input: ActivateKeySigningKeyRequest,
+    ) -> Result<ActivateKeySigningKeyResponse, RusotoError<ActivateKeySigningKeyError>> {
+        self.runtime
+            .block_on(self.client.activate_key_signing_key(input))
+    }
+
+    /// Blocking version of [`Route53::associate_vpc_with_hosted_zone`].
+    pub fn associate_vpc_with_hosted_zone(
+        &self,
+        input: AssociateVPCWithHostedZoneRequest,
+    ) -> Result<AssociateVPCWithHostedZoneResponse, RusotoError<AssociateVPCWithHostedZoneError>>
+    {
+        self.runtime
+            .block_on(self.client.associate_vpc_with_hosted_zone(input))
+    }
+
+    /// Blocking version of [`Route53::change_resource_record_sets`].
+    pub fn change_resource_record_sets(
+        &self,
+        input: ChangeResourceRecordSetsRequest,
+    ) -> Result<ChangeResourceRecordSetsResponse, RusotoError<ChangeResourceRecordSetsError>> {
+        self.runtime
+            .block_on(self.client.change_resource_record_sets(input))
+    }
+
+    /// Blocking version of [`Route53::change_tags_for_resource`].
+    pub fn change_tags_for_resource(
+        &self,
+        input: ChangeTagsForResourceRequest,
+    ) -> Result<ChangeTagsForResourceResponse, RusotoError<ChangeTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.change_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`Route53::create_health_check`].
+    pub fn create_health_check(
+        &self,
+        input: CreateHealthCheckRequest,
+    ) -> Result<CreateHealthCheckResponse, RusotoError<CreateHealthCheckError>> {
+        self.runtime
+            .block_on(self.client.create_health_check(input))
+    }
+
+    /// Blocking version of [`Route53::create_hosted_zone`].
+    pub fn create_hosted_zone(
+        &self,
+        input: CreateHostedZoneRequest,
+    ) -> Result<CreateHostedZoneResponse, RusotoError<CreateHostedZoneError>> {
+        self.runtime.block_on(self.client.create_hosted_zone(input))
+    }
+
+    /// Blocking version of [`Route53::create_key_signing_key`].
+    pub fn create_key_signing_key(
+        &self,
+        input: CreateKeySigningKeyRequest,
+    ) -> Result<CreateKeySigningKeyResponse, RusotoError<CreateKeySigningKeyError>> {
+        self.runtime
+            .block_on(self.client.create_key_signing_key(input))
+    }
+
+    /// Blocking version of [`Route53::create_query_logging_config`].
+    pub fn create_query_logging_config(
+        &self,
+        input: CreateQueryLoggingConfigRequest,
+    ) -> Result<CreateQueryLoggingConfigResponse, RusotoError<CreateQueryLoggingConfigError>> {
+        self.runtime
+            .block_on(self.client.create_query_logging_config(input))
+    }
+
+    /// Blocking version of [`Route53::create_reusable_delegation_set`].
+    pub fn create_reusable_delegation_set(
+        &self,
+        input: CreateReusableDelegationSetRequest,
+    ) -> Result<CreateReusableDelegationSetResponse, RusotoError<CreateReusableDelegationSetError>>
+    {
+        self.runtime
+            .block_on(self.client.create_reusable_delegation_set(input))
+    }
+
+    /// Blocking version of [`Route53::create_traffic_policy`].
+    pub fn create_traffic_policy(
+        &self,
+        input: CreateTrafficPolicyRequest,
+    ) -> Result<CreateTrafficPolicyResponse, RusotoError<CreateTrafficPolicyError>> {
+        self.runtime
+            .block_on(self.client.create_traffic_policy(input))
+    }
+
+    /// Blocking version of [`Route53::create_traffic_policy_instance`].
+    pub fn create_traffic_policy_instance(
+        &self,
+        input: CreateTrafficPolicyInstanceRequest,
+    ) -> Result<CreateTrafficPolicyInstanceResponse, RusotoError<CreateTrafficPolicyInstanceError>>
+    {
+        self.runtime
+            .block_on(self.client.create_traffic_policy_instance(input))
+    }
+
+    /// Blocking version of [`Route53::create_traffic_policy_version`].
+    pub fn create_traffic_policy_version(
+        &self,
+        input: CreateTrafficPolicyVersionRequest,
+    ) -> Result<CreateTrafficPolicyVersionResponse, RusotoError<CreateTrafficPolicyVersionError>>
+    {
+        self.runtime
+            .block_on(self.client.create_traffic_policy_version(input))
+    }
+
+    /// Blocking version of [`Route53::create_vpc_association_authorization`].
+    pub fn create_vpc_association_authorization(
+        &self,
+        input: CreateVPCAssociationAuthorizationRequest,
+    ) -> Result<
+        CreateVPCAssociationAuthorizationResponse,
+        RusotoError<CreateVPCAssociationAuthorizationError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_vpc_association_authorization(input))
+    }
+
+    /// Blocking version of [`Route53::deactivate_key_signing_key`].
+    pub fn deactivate_key_signing_key(
+        &self,
+        input: DeactivateKeySigningKeyRequest,
+    ) -> Result<DeactivateKeySigningKeyResponse, RusotoError<DeactivateKeySigningKeyError>> {
+        self.runtime
+            .block_on(self.client.deactivate_key_signing_key(input))
+    }
+
+    /// Blocking version of [`Route53::delete_health_check`].
+    pub fn delete_health_check(
+        &self,
+        input: DeleteHealthCheckRequest,
+    ) -> Result<DeleteHealthCheckResponse, RusotoError<DeleteHealthCheckError>> {
+        self.runtime
+            .block_on(self.client.delete_health_check(input))
+    }
+
+    /// Blocking version of [`Route53::delete_hosted_zone`].
+    pub fn delete_hosted_zone(
+        &self,
+        input: DeleteHostedZoneRequest,
+    ) -> Result<DeleteHostedZoneResponse, RusotoError<DeleteHostedZoneError>> {
+        self.runtime.block_on(self.client.delete_hosted_zone(input))
+    }
+
+    /// Blocking version of [`Route53::delete_key_signing_key`].
+    pub fn delete_key_signing_key(
+        &self,
+        input: DeleteKeySigningKeyRequest,
+    ) -> Result<DeleteKeySigningKeyResponse, RusotoError<DeleteKeySigningKeyError>> {
+        self.runtime
+            .block_on(self.client.delete_key_signing_key(input))
+    }
+
+    /// Blocking version of [`Route53::delete_query_logging_config`].
+    pub fn delete_query_logging_config(
+        &self,
+        input: DeleteQueryLoggingConfigRequest,
+    ) -> Result<DeleteQueryLoggingConfigResponse, RusotoError<DeleteQueryLoggingConfigError>> {
+        self.runtime
+            .block_on(self.client.delete_query_logging_config(input))
+    }
+
+    /// Blocking version of [`Route53::delete_reusable_delegation_set`].
+    pub fn delete_reusable_delegation_set(
+        &self,
+        input: DeleteReusableDelegationSetRequest,
+    ) -> Result<DeleteReusableDelegationSetResponse, RusotoError<DeleteReusableDelegationSetError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_reusable_delegation_set(input))
+    }
+
+    /// Blocking version of [`Route53::delete_traffic_policy`].
+    pub fn delete_traffic_policy(
+        &self,
+        input: DeleteTrafficPolicyRequest,
+    ) -> Result<DeleteTrafficPolicyResponse, RusotoError<DeleteTrafficPolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_traffic_policy(input))
+    }
+
+    /// Blocking version of [`Route53::delete_traffic_policy_instance`].
+    pub fn delete_traffic_policy_instance(
+        &self,
+        input: DeleteTrafficPolicyInstanceRequest,
+    ) -> Result<DeleteTrafficPolicyInstanceResponse, RusotoError<DeleteTrafficPolicyInstanceError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_traffic_policy_instance(input))
+    }
+
+    /// Blocking version of [`Route53::delete_vpc_association_authorization`].
+    pub fn delete_vpc_association_authorization(
+        &self,
+        input: DeleteVPCAssociationAuthorizationRequest,
+    ) -> Result<
+        DeleteVPCAssociationAuthorizationResponse,
+        RusotoError<DeleteVPCAssociationAuthorizationError>,
+    > {
+        self.runtime
+            .block_on(self.client.delete_vpc_association_authorization(input))
+    }
+
+    /// Blocking version of [`Route53::disable_hosted_zone_dnssec`].
+    pub fn disable_hosted_zone_dnssec(
+        &self,
+        input: DisableHostedZoneDNSSECRequest,
+    ) -> Result<DisableHostedZoneDNSSECResponse, RusotoError<DisableHostedZoneDNSSECError>> {
+        self.runtime
+            .block_on(self.client.disable_hosted_zone_dnssec(input))
+    }
+
+    /// Blocking version of [`Route53::disassociate_vpc_from_hosted_zone`].
+    pub fn disassociate_vpc_from_hosted_zone(
+        &self,
+        input: DisassociateVPCFromHostedZoneRequest,
+    ) -> Result<
+        DisassociateVPCFromHostedZoneResponse,
+        RusotoError<DisassociateVPCFromHostedZoneError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_vpc_from_hosted_zone(input))
+    }
+
+    /// Blocking version of [`Route53::enable_hosted_zone_dnssec`].
+    pub fn enable_hosted_zone_dnssec(
+        &self,
+        input: EnableHostedZoneDNSSECRequest,
+    ) -> Result<EnableHostedZoneDNSSECResponse, RusotoError<EnableHostedZoneDNSSECError>> {
+        self.runtime
+            .block_on(self.client.enable_hosted_zone_dnssec(input))
+    }
+
+    /// Blocking version of [`Route53::get_account_limit`].
+    pub fn get_account_limit(
+        &self,
+        input: GetAccountLimitRequest,
+    ) -> Result<GetAccountLimitResponse, RusotoError<GetAccountLimitError>> {
+        self.runtime.block_on(self.client.get_account_limit(input))
+    }
+
+    /// Blocking version of [`Route53::get_change`].
+    pub fn get_change(
+        &self,
+        input: GetChangeRequest,
+    ) -> Result<GetChangeResponse, RusotoError<GetChangeError>> {
+        self.runtime.block_on(self.client.get_change(input))
+    }
+
+    /// Blocking version of [`Route53::get_checker_ip_ranges`].
+    pub fn get_checker_ip_ranges(
+        &self,
+        input: GetCheckerIpRangesRequest,
+    ) -> Result<GetCheckerIpRangesResponse, RusotoError<GetCheckerIpRangesError>> {
+        self.runtime
+            .block_on(self.client.get_checker_ip_ranges(input))
+    }
+
+    /// Blocking version of [`Route53::get_dnssec`].
+    pub fn get_dnssec(
+        &self,
+        input: GetDNSSECRequest,
+    ) -> Result<GetDNSSECResponse, RusotoError<GetDNSSECError>> {
+        self.runtime.block_on(self.client.get_dnssec(input))
+    }
+
+    /// Blocking version of [`Route53::get_geo_location`].
+    pub fn get_geo_location(
+        &self,
+        input: GetGeoLocationRequest,
+    ) -> Result<GetGeoLocationResponse, RusotoError<GetGeoLocationError>> {
+        self.runtime.block_on(self.client.get_geo_location(input))
+    }
+
+    /// Blocking version of [`Route53::get_health_check`].
+    pub fn get_health_check(
+        &self,
+        input: GetHealthCheckRequest,
+    ) -> Result<GetHealthCheckResponse, RusotoError<GetHealthCheckError>> {
+        self.runtime.block_on(self.client.get_health_check(input))
+    }
+
+    /// Blocking version of [`Route53::get_health_check_count`].
+    pub fn get_health_check_count(
+        &self,
+        input: GetHealthCheckCountRequest,
+    ) -> Result<GetHealthCheckCountResponse, RusotoError<GetHealthCheckCountError>> {
+        self.runtime
+            .block_on(self.client.get_health_check_count(input))
+    }
+
+    /// Blocking version of [`Route53::get_health_check_last_failure_reason`].
+    pub fn get_health_check_last_failure_reason(
+        &self,
+        input: GetHealthCheckLastFailureReasonRequest,
+    ) -> Result<
+        GetHealthCheckLastFailureReasonResponse,
+        RusotoError<GetHealthCheckLastFailureReasonError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_health_check_last_failure_reason(input))
+    }
+
+    /// Blocking version of [`Route53::get_health_check_status`].
+    pub fn get_health_check_status(
+        &self,
+        input: GetHealthCheckStatusRequest,
+    ) -> Result<GetHealthCheckStatusResponse, RusotoError<GetHealthCheckStatusError>> {
+        self.runtime
+            .block_on(self.client.get_health_check_status(input))
+    }
+
+    /// Blocking version of [`Route53::get_hosted_zone`].
+    pub fn get_hosted_zone(
+        &self,
+        input: GetHostedZoneRequest,
+    ) -> Result<GetHostedZoneResponse, RusotoError<GetHostedZoneError>> {
+        self.runtime.block_on(self.client.get_hosted_zone(input))
+    }
+
+    /// Blocking version of [`Route53::get_hosted_zone_count`].
+    pub fn get_hosted_zone_count(
+        &self,
+        input: GetHostedZoneCountRequest,
+    ) -> Result<GetHostedZoneCountResponse, RusotoError<GetHostedZoneCountError>> {
+        self.runtime
+            .block_on(self.client.get_hosted_zone_count(input))
+    }
+
+    /// Blocking version of [`Route53::get_hosted_zone_limit`].
+    pub fn get_hosted_zone_limit(
+        &self,
+        input: GetHostedZoneLimitRequest,
+    ) -> Result<GetHostedZoneLimitResponse, RusotoError<GetHostedZoneLimitError>> {
+        self.runtime
+            .block_on(self.client.get_hosted_zone_limit(input))
+    }
+
+    /// Blocking version of [`Route53::get_query_logging_config`].
+    pub fn get_query_logging_config(
+        &self,
+        input: GetQueryLoggingConfigRequest,
+    ) -> Result<GetQueryLoggingConfigResponse, RusotoError<GetQueryLoggingConfigError>> {
+        self.runtime
+            .block_on(self.client.get_query_logging_config(input))
+    }
+
+    /// Blocking version of [`Route53::get_reusable_delegation_set`].
+    pub fn get_reusable_delegation_set(
+        &self,
+        input: GetReusableDelegationSetRequest,
+    ) -> Result<GetReusableDelegationSetResponse, RusotoError<GetReusableDelegationSetError>> {
+        self.runtime
+            .block_on(self.client.get_reusable_delegation_set(input))
+    }
+
+    /// Blocking version of [`Route53::get_reusable_delegation_set_limit`].
+    pub fn get_reusable_delegation_set_limit(
+        &self,
+        input: GetReusableDelegationSetLimitRequest,
+    ) -> Result<
+        GetReusableDelegationSetLimitResponse,
+        RusotoError<GetReusableDelegationSetLimitError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_reusable_delegation_set_limit(input))
+    }
+
+    /// Blocking version of [`Route53::get_traffic_policy`].
+    pub fn get_traffic_policy(
+        &self,
+        input: GetTrafficPolicyRequest,
+    ) -> Result<GetTrafficPolicyResponse, RusotoError<GetTrafficPolicyError>> {
+        self.runtime.block_on(self.client.get_traffic_policy(input))
+    }
+
+    /// Blocking version of [`Route53::get_traffic_policy_instance`].
+    pub fn get_traffic_policy_instance(
+        &self,
+        input: GetTrafficPolicyInstanceRequest,
+    ) -> Result<GetTrafficPolicyInstanceResponse, RusotoError<GetTrafficPolicyInstanceError>> {
+        self.runtime
+            .block_on(self.client.get_traffic_policy_instance(input))
+    }
+
+    /// Blocking version of [`Route53::get_traffic_policy_instance_count`].
+    pub fn get_traffic_policy_instance_count(
+        &self,
+        input: GetTrafficPolicyInstanceCountRequest,
+    ) -> Result<
+        GetTrafficPolicyInstanceCountResponse,
+        RusotoError<GetTrafficPolicyInstanceCountError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_traffic_policy_instance_count(input))
+    }
+
+    /// Blocking version of [`Route53::list_geo_locations`].
+    pub fn list_geo_locations(
+        &self,
+        input: ListGeoLocationsRequest,
+    ) -> Result<ListGeoLocationsResponse, RusotoError<ListGeoLocationsError>> {
+        self.runtime.block_on(self.client.list_geo_locations(input))
+    }
+
+    /// Blocking version of [`Route53::list_health_checks`].
+    pub fn list_health_checks(
+        &self,
+        input: ListHealthChecksRequest,
+    ) -> Result<ListHealthChecksResponse, RusotoError<ListHealthChecksError>> {
+        self.runtime.block_on(self.client.list_health_checks(input))
+    }
+
+    /// Blocking version of [`Route53::list_hosted_zones`].
+    pub fn list_hosted_zones(
+        &self,
+        input: ListHostedZonesRequest,
+    ) -> Result<ListHostedZonesResponse, RusotoError<ListHostedZonesError>> {
+        self.runtime.block_on(self.client.list_hosted_zones(input))
+    }
+
+    /// Blocking version of [`Route53::list_hosted_zones_by_name`].
+    pub fn list_hosted_zones_by_name(
+        &self,
+        input: ListHostedZonesByNameRequest,
+    ) -> Result<ListHostedZonesByNameResponse, RusotoError<ListHostedZonesByNameError>> {
+        self.runtime
+            .block_on(self.client.list_hosted_zones_by_name(input))
+    }
+
+    /// Blocking version of [`Route53::list_hosted_zones_by_vpc`].
+    pub fn list_hosted_zones_by_vpc(
+        &self,
+        input: ListHostedZonesByVPCRequest,
+    ) -> Result<ListHostedZonesByVPCResponse, RusotoError<ListHostedZonesByVPCError>> {
+        self.runtime
+            .block_on(self.client.list_hosted_zones_by_vpc(input))
+    }
+
+    /// Blocking version of [`Route53::list_query_logging_configs`].
+    pub fn list_query_logging_configs(
+        &self,
+        input: ListQueryLoggingConfigsRequest,
+    ) -> Result<ListQueryLoggingConfigsResponse, RusotoError<ListQueryLoggingConfigsError>> {
+        self.runtime
+            .block_on(self.client.list_query_logging_configs(input))
+    }
+
+    /// Blocking version of [`Route53::list_resource_record_sets`].
+    pub fn list_resource_record_sets(
+        &self,
+        input: ListResourceRecordSetsRequest,
+    ) -> Result<ListResourceRecordSetsResponse, RusotoError<ListResourceRecordSetsError>> {
+        self.runtime
+            .block_on(self.client.list_resource_record_sets(input))
+    }
+
+    /// Blocking version of [`Route53::list_reusable_delegation_sets`].
+    pub fn list_reusable_delegation_sets(
+        &self,
+        input: ListReusableDelegationSetsRequest,
+    ) -> Result<ListReusableDelegationSetsResponse, RusotoError<ListReusableDelegationSetsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_reusable_delegation_sets(input))
+    }
+
+    /// Blocking version of [`Route53::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`Route53::list_tags_for_resources`].
+    pub fn list_tags_for_resources(
+        &self,
+        input: ListTagsForResourcesRequest,
+    ) -> Result<ListTagsForResourcesResponse, RusotoError<ListTagsForResourcesError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resources(input))
+    }
+
+    /// Blocking version of [`Route53::list_traffic_policies`].
+    pub fn list_traffic_policies(
+        &self,
+        input: ListTrafficPoliciesRequest,
+    ) -> Result<ListTrafficPoliciesResponse, RusotoError<ListTrafficPoliciesError>> {
+        self.runtime
+            .block_on(self.client.list_traffic_policies(input))
+    }
+
+    /// Blocking version of [`Route53::list_traffic_policy_instances`].
+    pub fn list_traffic_policy_instances(
+        &self,
+        input: ListTrafficPolicyInstancesRequest,
+    ) -> Result<ListTrafficPolicyInstancesResponse, RusotoError<ListTrafficPolicyInstancesError>>
+    {
+        self.runtime
+            .block_on(self.client.list_traffic_policy_instances(input))
+    }
+
+    /// Blocking version of [`Route53::list_traffic_policy_instances_by_hosted_zone`].
+    pub fn list_traffic_policy_instances_by_hosted_zone(
+        &self,
+        input: ListTrafficPolicyInstancesByHostedZoneRequest,
+    ) -> Result<
+        ListTrafficPolicyInstancesByHostedZoneResponse,
+        RusotoError<ListTrafficPolicyInstancesByHostedZoneError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .list_traffic_policy_instances_by_hosted_zone(input),
+        )
+    }
+
+    /// Blocking version of [`Route53::list_traffic_policy_instances_by_policy`].
+    pub fn list_traffic_policy_instances_by_policy(
+        &self,
+        input: ListTrafficPolicyInstancesByPolicyRequest,
+    ) -> Result<
+        ListTrafficPolicyInstancesByPolicyResponse,
+        RusotoError<ListTrafficPolicyInstancesByPolicyError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_traffic_policy_instances_by_policy(input))
+    }
+
+    /// Blocking version of [`Route53::list_traffic_policy_versions`].
+    pub fn list_traffic_policy_versions(
+        &self,
+        input: ListTrafficPolicyVersionsRequest,
+    ) -> Result<ListTrafficPolicyVersionsResponse, RusotoError<ListTrafficPolicyVersionsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_traffic_policy_versions(input))
+    }
+
+    /// Blocking version of [`Route53::list_vpc_association_authorizations`].
+    pub fn list_vpc_association_authorizations(
+        &self,
+        input: ListVPCAssociationAuthorizationsRequest,
+    ) -> Result<
+        ListVPCAssociationAuthorizationsResponse,
+        RusotoError<ListVPCAssociationAuthorizationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_vpc_association_authorizations(input))
+    }
+
+    /// Blocking version of [`Route53::test_dns_answer`].
+    pub fn test_dns_answer(
+        &self,
+        input: TestDNSAnswerRequest,
+    ) -> Result<TestDNSAnswerResponse, RusotoError<TestDNSAnswerError>> {
+        self.runtime.block_on(self.client.test_dns_answer(input))
+    }
+
+    /// Blocking version of [`Route53::update_health_check`].
+    pub fn update_health_check(
+        &self,
+        input: UpdateHealthCheckRequest,
+    ) -> Result<UpdateHealthCheckResponse, RusotoError<UpdateHealthCheckError>> {
+        self.runtime
+            .block_on(self.client.update_health_check(input))
+    }
+
+    /// Blocking version of [`Route53::update_hosted_zone_comment`].
+    pub fn update_hosted_zone_comment(
+        &self,
+        input: UpdateHostedZoneCommentRequest,
+    ) -> Result<UpdateHostedZoneCommentResponse, RusotoError<UpdateHostedZoneCommentError>> {
+        self.runtime
+            .block_on(self.client.update_hosted_zone_comment(input))
+    }
+
+    /// Blocking version of [`Route53::update_traffic_policy_comment`].
+    pub fn update_traffic_policy_comment(
+        &self,
+        input: UpdateTrafficPolicyCommentRequest,
+    ) -> Result<UpdateTrafficPolicyCommentResponse, RusotoError<UpdateTrafficPolicyCommentError>>
+    {
+        self.runtime
+            .block_on(self.client.update_traffic_policy_comment(input))
+    }
+
+    /// Blocking version of [`Route53::update_traffic_policy_instance`].
+    pub fn update_traffic_policy_instance(
+        &self,
+        input: UpdateTrafficPolicyInstanceRequest,
+    ) -> Result<UpdateTrafficPolicyInstanceResponse, RusotoError<UpdateTrafficPolicyInstanceError>>
+    {
+        self.runtime
+            .block_on(self.client.update_traffic_policy_instance(input))
+    }
+}
+
 #[cfg(test)]
 mod protocol_tests {
 
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1024,25 +1025,30 @@ impl AcceptDomainTransferFromAnotherAwsAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DomainLimitExceeded" => {
-                    return RusotoError::Service(
-                        AcceptDomainTransferFromAnotherAwsAccountError::DomainLimitExceeded(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptDomainTransferFromAnotherAwsAccountError::DomainLimitExceeded,
                     )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(
-                        AcceptDomainTransferFromAnotherAwsAccountError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptDomainTransferFromAnotherAwsAccountError::InvalidInput,
                     )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(
-                        AcceptDomainTransferFromAnotherAwsAccountError::OperationLimitExceeded(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AcceptDomainTransferFromAnotherAwsAccountError::OperationLimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1082,18 +1088,22 @@ impl CancelDomainTransferToAnotherAwsAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(
-                        CancelDomainTransferToAnotherAwsAccountError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelDomainTransferToAnotherAwsAccountError::InvalidInput,
                     )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(
-                        CancelDomainTransferToAnotherAwsAccountError::OperationLimitExceeded(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CancelDomainTransferToAnotherAwsAccountError::OperationLimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1128,16 +1138,22 @@ impl CheckDomainAvailabilityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(CheckDomainAvailabilityError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CheckDomainAvailabilityError::InvalidInput,
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(CheckDomainAvailabilityError::UnsupportedTLD(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CheckDomainAvailabilityError::UnsupportedTLD,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1170,16 +1186,22 @@ impl CheckDomainTransferabilityError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(CheckDomainTransferabilityError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CheckDomainTransferabilityError::InvalidInput,
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(CheckDomainTransferabilityError::UnsupportedTLD(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CheckDomainTransferabilityError::UnsupportedTLD,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1212,17 +1234,30 @@ impl DeleteTagsForDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(DeleteTagsForDomainError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTagsForDomainError::InvalidInput,
+                    )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(DeleteTagsForDomainError::OperationLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteTagsForDomainError::OperationLimitExceeded,
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(DeleteTagsForDomainError::UnsupportedTLD(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTagsForDomainError::UnsupportedTLD,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1254,14 +1289,22 @@ impl DisableDomainAutoRenewError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(DisableDomainAutoRenewError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableDomainAutoRenewError::InvalidInput,
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(DisableDomainAutoRenewError::UnsupportedTLD(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisableDomainAutoRenewError::UnsupportedTLD,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1298,31 +1341,46 @@ impl DisableDomainTransferLockError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(DisableDomainTransferLockError::DuplicateRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisableDomainTransferLockError::DuplicateRequest,
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(DisableDomainTransferLockError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisableDomainTransferLockError::InvalidInput,
+                    )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(
-                        DisableDomainTransferLockError::OperationLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisableDomainTransferLockError::OperationLimitExceeded,
                     )
                 }
                 "TLDRulesViolation" => {
-                    return RusotoError::Service(DisableDomainTransferLockError::TLDRulesViolation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisableDomainTransferLockError::TLDRulesViolation,
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(DisableDomainTransferLockError::UnsupportedTLD(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisableDomainTransferLockError::UnsupportedTLD,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1360,19 +1418,30 @@ impl EnableDomainAutoRenewError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(EnableDomainAutoRenewError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableDomainAutoRenewError::InvalidInput,
+                    )
                 }
                 "TLDRulesViolation" => {
-                    return RusotoError::Service(EnableDomainAutoRenewError::TLDRulesViolation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        EnableDomainAutoRenewError::TLDRulesViolation,
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(EnableDomainAutoRenewError::UnsupportedTLD(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        EnableDomainAutoRenewError::UnsupportedTLD,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1410,31 +1479,46 @@ impl EnableDomainTransferLockError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(EnableDomainTransferLockError::DuplicateRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        EnableDomainTransferLockError::DuplicateRequest,
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(EnableDomainTransferLockError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        EnableDomainTransferLockError::InvalidInput,
+                    )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(
-                        EnableDomainTransferLockError::OperationLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        EnableDomainTransferLockError::OperationLimitExceeded,
                     )
                 }
                 "TLDRulesViolation" => {
-                    return RusotoError::Service(EnableDomainTransferLockError::TLDRulesViolation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        EnableDomainTransferLockError::TLDRulesViolation,
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(EnableDomainTransferLockError::UnsupportedTLD(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        EnableDomainTransferLockError::UnsupportedTLD,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1474,21 +1558,30 @@ impl GetContactReachabilityStatusError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(GetContactReachabilityStatusError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetContactReachabilityStatusError::InvalidInput,
+                    )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(
-                        GetContactReachabilityStatusError::OperationLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetContactReachabilityStatusError::OperationLimitExceeded,
                     )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(GetContactReachabilityStatusError::UnsupportedTLD(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetContactReachabilityStatusError::UnsupportedTLD,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1522,12 +1615,22 @@ impl GetDomainDetailError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(GetDomainDetailError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDomainDetailError::InvalidInput,
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(GetDomainDetailError::UnsupportedTLD(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDomainDetailError::UnsupportedTLD,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1558,12 +1661,22 @@ impl GetDomainSuggestionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(GetDomainSuggestionsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDomainSuggestionsError::InvalidInput,
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(GetDomainSuggestionsError::UnsupportedTLD(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetDomainSuggestionsError::UnsupportedTLD,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1592,9 +1705,14 @@ impl GetOperationDetailError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(GetOperationDetailError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetOperationDetailError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1622,9 +1740,14 @@ impl ListDomainsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(ListDomainsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListDomainsError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1652,9 +1775,14 @@ impl ListOperationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(ListOperationsError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListOperationsError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1686,17 +1814,30 @@ impl ListTagsForDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(ListTagsForDomainError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForDomainError::InvalidInput,
+                    )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(ListTagsForDomainError::OperationLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForDomainError::OperationLimitExceeded,
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(ListTagsForDomainError::UnsupportedTLD(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForDomainError::UnsupportedTLD,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1736,26 +1877,54 @@ impl RegisterDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DomainLimitExceeded" => {
-                    return RusotoError::Service(RegisterDomainError::DomainLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterDomainError::DomainLimitExceeded,
+                    )
                 }
                 "DuplicateRequest" => {
-                    return RusotoError::Service(RegisterDomainError::DuplicateRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterDomainError::DuplicateRequest,
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(RegisterDomainError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterDomainError::InvalidInput,
+                    )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(RegisterDomainError::OperationLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterDomainError::OperationLimitExceeded,
+                    )
                 }
                 "TLDRulesViolation" => {
-                    return RusotoError::Service(RegisterDomainError::TLDRulesViolation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterDomainError::TLDRulesViolation,
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(RegisterDomainError::UnsupportedTLD(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RegisterDomainError::UnsupportedTLD,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1792,18 +1961,22 @@ impl RejectDomainTransferFromAnotherAwsAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(
-                        RejectDomainTransferFromAnotherAwsAccountError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RejectDomainTransferFromAnotherAwsAccountError::InvalidInput,
                     )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(
-                        RejectDomainTransferFromAnotherAwsAccountError::OperationLimitExceeded(
-                            err.msg,
-                        ),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RejectDomainTransferFromAnotherAwsAccountError::OperationLimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1844,21 +2017,46 @@ impl RenewDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(RenewDomainError::DuplicateRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RenewDomainError::DuplicateRequest,
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(RenewDomainError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RenewDomainError::InvalidInput,
+                    )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(RenewDomainError::OperationLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RenewDomainError::OperationLimitExceeded,
+                    )
                 }
                 "TLDRulesViolation" => {
-                    return RusotoError::Service(RenewDomainError::TLDRulesViolation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RenewDomainError::TLDRulesViolation,
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(RenewDomainError::UnsupportedTLD(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RenewDomainError::UnsupportedTLD,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1896,21 +2094,30 @@ impl ResendContactReachabilityEmailError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(ResendContactReachabilityEmailError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ResendContactReachabilityEmailError::InvalidInput,
+                    )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(
-                        ResendContactReachabilityEmailError::OperationLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResendContactReachabilityEmailError::OperationLimitExceeded,
                     )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(
-                        ResendContactReachabilityEmailError::UnsupportedTLD(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ResendContactReachabilityEmailError::UnsupportedTLD,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1946,14 +2153,22 @@ impl RetrieveDomainAuthCodeError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(RetrieveDomainAuthCodeError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RetrieveDomainAuthCodeError::InvalidInput,
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(RetrieveDomainAuthCodeError::UnsupportedTLD(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RetrieveDomainAuthCodeError::UnsupportedTLD,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1992,26 +2207,54 @@ impl TransferDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DomainLimitExceeded" => {
-                    return RusotoError::Service(TransferDomainError::DomainLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TransferDomainError::DomainLimitExceeded,
+                    )
                 }
                 "DuplicateRequest" => {
-                    return RusotoError::Service(TransferDomainError::DuplicateRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TransferDomainError::DuplicateRequest,
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(TransferDomainError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TransferDomainError::InvalidInput,
+                    )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(TransferDomainError::OperationLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        TransferDomainError::OperationLimitExceeded,
+                    )
                 }
                 "TLDRulesViolation" => {
-                    return RusotoError::Service(TransferDomainError::TLDRulesViolation(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TransferDomainError::TLDRulesViolation,
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(TransferDomainError::UnsupportedTLD(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TransferDomainError::UnsupportedTLD,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2050,21 +2293,30 @@ impl TransferDomainToAnotherAwsAccountError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(
-                        TransferDomainToAnotherAwsAccountError::DuplicateRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TransferDomainToAnotherAwsAccountError::DuplicateRequest,
                     )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(
-                        TransferDomainToAnotherAwsAccountError::InvalidInput(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TransferDomainToAnotherAwsAccountError::InvalidInput,
                     )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(
-                        TransferDomainToAnotherAwsAccountError::OperationLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TransferDomainToAnotherAwsAccountError::OperationLimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2108,27 +2360,46 @@ impl UpdateDomainContactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(UpdateDomainContactError::DuplicateRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDomainContactError::DuplicateRequest,
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(UpdateDomainContactError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDomainContactError::InvalidInput,
+                    )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(UpdateDomainContactError::OperationLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDomainContactError::OperationLimitExceeded,
+                    )
                 }
                 "TLDRulesViolation" => {
-                    return RusotoError::Service(UpdateDomainContactError::TLDRulesViolation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDomainContactError::TLDRulesViolation,
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(UpdateDomainContactError::UnsupportedTLD(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDomainContactError::UnsupportedTLD,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2170,31 +2441,46 @@ impl UpdateDomainContactPrivacyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(UpdateDomainContactPrivacyError::DuplicateRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDomainContactPrivacyError::DuplicateRequest,
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(UpdateDomainContactPrivacyError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDomainContactPrivacyError::InvalidInput,
+                    )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(
-                        UpdateDomainContactPrivacyError::OperationLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDomainContactPrivacyError::OperationLimitExceeded,
                     )
                 }
                 "TLDRulesViolation" => {
-                    return RusotoError::Service(
-                        UpdateDomainContactPrivacyError::TLDRulesViolation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDomainContactPrivacyError::TLDRulesViolation,
                     )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(UpdateDomainContactPrivacyError::UnsupportedTLD(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDomainContactPrivacyError::UnsupportedTLD,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2236,31 +2522,46 @@ impl UpdateDomainNameserversError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "DuplicateRequest" => {
-                    return RusotoError::Service(UpdateDomainNameserversError::DuplicateRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDomainNameserversError::DuplicateRequest,
+                    )
                 }
                 "InvalidInput" => {
-                    return RusotoError::Service(UpdateDomainNameserversError::InvalidInput(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDomainNameserversError::InvalidInput,
+                    )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(
-                        UpdateDomainNameserversError::OperationLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDomainNameserversError::OperationLimitExceeded,
                     )
                 }
                 "TLDRulesViolation" => {
-                    return RusotoError::Service(UpdateDomainNameserversError::TLDRulesViolation(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDomainNameserversError::TLDRulesViolation,
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(UpdateDomainNameserversError::UnsupportedTLD(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateDomainNameserversError::UnsupportedTLD,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2298,17 +2599,30 @@ impl UpdateTagsForDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(UpdateTagsForDomainError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTagsForDomainError::InvalidInput,
+                    )
                 }
                 "OperationLimitExceeded" => {
-                    return RusotoError::Service(UpdateTagsForDomainError::OperationLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateTagsForDomainError::OperationLimitExceeded,
+                    )
                 }
                 "UnsupportedTLD" => {
-                    return RusotoError::Service(UpdateTagsForDomainError::UnsupportedTLD(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTagsForDomainError::UnsupportedTLD,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2338,9 +2652,14 @@ impl ViewBillingError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InvalidInput" => {
-                    return RusotoError::Service(ViewBillingError::InvalidInput(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ViewBillingError::InvalidInput,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2552,11 +2871,12 @@ pub struct Route53DomainsClient {
 impl Route53DomainsClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> Route53DomainsClient {
         Route53DomainsClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Route 53 Domains"),
         }
     }
 
@@ -2571,12 +2891,32 @@ impl Route53DomainsClient {
     {
         Route53DomainsClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Route 53 Domains"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> Route53DomainsClient {
-        Route53DomainsClient { client, region }
+        Route53DomainsClient {
+            client,
+            region: region_for_service(region, "Route 53 Domains"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> Route53DomainsClient {
+        Route53DomainsClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Route 53 Domains"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> Route53DomainsClient {
+        Route53DomainsClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -2591,6 +2931,7 @@ impl Route53Domains for Route53DomainsClient {
         RusotoError<AcceptDomainTransferFromAnotherAwsAccountError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AcceptDomainTransferFromAnotherAwsAccount");
         request.add_header(
             "x-amz-target",
             "Route53Domains_v20140515.AcceptDomainTransferFromAnotherAwsAccount",
@@ -2619,6 +2960,7 @@ impl Route53Domains for Route53DomainsClient {
         RusotoError<CancelDomainTransferToAnotherAwsAccountError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CancelDomainTransferToAnotherAwsAccount");
         request.add_header(
             "x-amz-target",
             "Route53Domains_v20140515.CancelDomainTransferToAnotherAwsAccount",
@@ -2644,6 +2986,7 @@ impl Route53Domains for Route53DomainsClient {
         input: CheckDomainAvailabilityRequest,
     ) -> Result<CheckDomainAvailabilityResponse, RusotoError<CheckDomainAvailabilityError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CheckDomainAvailability");
         request.add_header(
             "x-amz-target",
             "Route53Domains_v20140515.CheckDomainAvailability",
@@ -2667,6 +3010,7 @@ impl Route53Domains for Route53DomainsClient {
     ) -> Result<CheckDomainTransferabilityResponse, RusotoError<CheckDomainTransferabilityError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CheckDomainTransferability");
         request.add_header(
             "x-amz-target",
             "Route53Domains_v20140515.CheckDomainTransferability",
@@ -2689,6 +3033,7 @@ impl Route53Domains for Route53DomainsClient {
         input: DeleteTagsForDomainRequest,
     ) -> Result<DeleteTagsForDomainResponse, RusotoError<DeleteTagsForDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteTagsForDomain");
         request.add_header(
             "x-amz-target",
             "Route53Domains_v20140515.DeleteTagsForDomain",
@@ -2710,6 +3055,7 @@ impl Route53Domains for Route53DomainsClient {
         input: DisableDomainAutoRenewRequest,
     ) -> Result<DisableDomainAutoRenewResponse, RusotoError<DisableDomainAutoRenewError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisableDomainAutoRenew");
         request.add_header(
             "x-amz-target",
             "Route53Domains_v20140515.DisableDomainAutoRenew",
@@ -2733,6 +3079,7 @@ impl Route53Domains for Route53DomainsClient {
     ) -> Result<DisableDomainTransferLockResponse, RusotoError<DisableDomainTransferLockError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisableDomainTransferLock");
         request.add_header(
             "x-amz-target",
             "Route53Domains_v20140515.DisableDomainTransferLock",
@@ -2755,6 +3102,7 @@ impl Route53Domains for Route53DomainsClient {
         input: EnableDomainAutoRenewRequest,
     ) -> Result<EnableDomainAutoRenewResponse, RusotoError<EnableDomainAutoRenewError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("EnableDomainAutoRenew");
         request.add_header(
             "x-amz-target",
             "Route53Domains_v20140515.EnableDomainAutoRenew",
@@ -2777,6 +3125,7 @@ impl Route53Domains for Route53DomainsClient {
         input: EnableDomainTransferLockRequest,
     ) -> Result<EnableDomainTransferLockResponse, RusotoError<EnableDomainTransferLockError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("EnableDomainTransferLock");
         request.add_header(
             "x-amz-target",
             "Route53Domains_v20140515.EnableDomainTransferLock",
@@ -2800,6 +3149,7 @@ impl Route53Domains for Route53DomainsClient {
     ) -> Result<GetContactReachabilityStatusResponse, RusotoError<GetContactReachabilityStatusError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetContactReachabilityStatus");
         request.add_header(
             "x-amz-target",
             "Route53Domains_v20140515.GetContactReachabilityStatus",
@@ -2822,6 +3172,7 @@ impl Route53Domains for Route53DomainsClient {
         input: GetDomainDetailRequest,
     ) -> Result<GetDomainDetailResponse, RusotoError<GetDomainDetailError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDomainDetail");
         request.add_header("x-amz-target", "Route53Domains_v20140515.GetDomainDetail");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2840,6 +3191,7 @@ impl Route53Domains for Route53DomainsClient {
         input: GetDomainSuggestionsRequest,
     ) -> Result<GetDomainSuggestionsResponse, RusotoError<GetDomainSuggestionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDomainSuggestions");
         request.add_header(
             "x-amz-target",
             "Route53Domains_v20140515.GetDomainSuggestions",
@@ -2862,6 +3214,7 @@ impl Route53Domains for Route53DomainsClient {
         input: GetOperationDetailRequest,
     ) -> Result<GetOperationDetailResponse, RusotoError<GetOperationDetailError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetOperationDetail");
         request.add_header(
             "x-amz-target",
             "Route53Domains_v20140515.GetOperationDetail",
@@ -2883,6 +3236,7 @@ impl Route53Domains for Route53DomainsClient {
         input: ListDomainsRequest,
     ) -> Result<ListDomainsResponse, RusotoError<ListDomainsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListDomains");
         request.add_header("x-amz-target", "Route53Domains_v20140515.ListDomains");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2901,6 +3255,7 @@ impl Route53Domains for Route53DomainsClient {
         input: ListOperationsRequest,
     ) -> Result<ListOperationsResponse, RusotoError<ListOperationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListOperations");
         request.add_header("x-amz-target", "Route53Domains_v20140515.ListOperations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2919,6 +3274,7 @@ impl Route53Domains for Route53DomainsClient {
         input: ListTagsForDomainRequest,
     ) -> Result<ListTagsForDomainResponse, RusotoError<ListTagsForDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForDomain");
         request.add_header("x-amz-target", "Route53Domains_v20140515.ListTagsForDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2937,6 +3293,7 @@ impl Route53Domains for Route53DomainsClient {
         input: RegisterDomainRequest,
     ) -> Result<RegisterDomainResponse, RusotoError<RegisterDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RegisterDomain");
         request.add_header("x-amz-target", "Route53Domains_v20140515.RegisterDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -2958,6 +3315,7 @@ impl Route53Domains for Route53DomainsClient {
         RusotoError<RejectDomainTransferFromAnotherAwsAccountError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RejectDomainTransferFromAnotherAwsAccount");
         request.add_header(
             "x-amz-target",
             "Route53Domains_v20140515.RejectDomainTransferFromAnotherAwsAccount",
@@ -2983,6 +3341,7 @@ impl Route53Domains for Route53DomainsClient {
         input: RenewDomainRequest,
     ) -> Result<RenewDomainResponse, RusotoError<RenewDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RenewDomain");
         request.add_header("x-amz-target", "Route53Domains_v20140515.RenewDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3004,6 +3363,7 @@ impl Route53Domains for Route53DomainsClient {
         RusotoError<ResendContactReachabilityEmailError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ResendContactReachabilityEmail");
         request.add_header(
             "x-amz-target",
             "Route53Domains_v20140515.ResendContactReachabilityEmail",
@@ -3026,6 +3386,7 @@ impl Route53Domains for Route53DomainsClient {
         input: RetrieveDomainAuthCodeRequest,
     ) -> Result<RetrieveDomainAuthCodeResponse, RusotoError<RetrieveDomainAuthCodeError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RetrieveDomainAuthCode");
         request.add_header(
             "x-amz-target",
             "Route53Domains_v20140515.RetrieveDomainAuthCode",
@@ -3048,6 +3409,7 @@ impl Route53Domains for Route53DomainsClient {
         input: TransferDomainRequest,
     ) -> Result<TransferDomainResponse, RusotoError<TransferDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TransferDomain");
         request.add_header("x-amz-target", "Route53Domains_v20140515.TransferDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3069,6 +3431,7 @@ impl Route53Domains for Route53DomainsClient {
         RusotoError<TransferDomainToAnotherAwsAccountError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TransferDomainToAnotherAwsAccount");
         request.add_header(
             "x-amz-target",
             "Route53Domains_v20140515.TransferDomainToAnotherAwsAccount",
@@ -3094,6 +3457,7 @@ impl Route53Domains for Route53DomainsClient {
         input: UpdateDomainContactRequest,
     ) -> Result<UpdateDomainContactResponse, RusotoError<UpdateDomainContactError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateDomainContact");
         request.add_header(
             "x-amz-target",
             "Route53Domains_v20140515.UpdateDomainContact",
@@ -3116,6 +3480,7 @@ impl Route53Domains for Route53DomainsClient {
     ) -> Result<UpdateDomainContactPrivacyResponse, RusotoError<UpdateDomainContactPrivacyError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateDomainContactPrivacy");
         request.add_header(
             "x-amz-target",
             "Route53Domains_v20140515.UpdateDomainContactPrivacy",
@@ -3138,6 +3503,7 @@ impl Route53Domains for Route53DomainsClient {
         input: UpdateDomainNameserversRequest,
     ) -> Result<UpdateDomainNameserversResponse, RusotoError<UpdateDomainNameserversError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateDomainNameservers");
         request.add_header(
             "x-amz-target",
             "Route53Domains_v20140515.UpdateDomainNameservers",
@@ -3160,6 +3526,7 @@ impl Route53Domains for Route53DomainsClient {
         input: UpdateTagsForDomainRequest,
     ) -> Result<UpdateTagsForDomainResponse, RusotoError<UpdateTagsForDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateTagsForDomain");
         request.add_header(
             "x-amz-target",
             "Route53Domains_v20140515.UpdateTagsForDomain",
@@ -3181,6 +3548,7 @@ impl Route53Domains for Route53DomainsClient {
         input: ViewBillingRequest,
     ) -> Result<ViewBillingResponse, RusotoError<ViewBillingError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ViewBilling");
         request.add_header("x-amz-target", "Route53Domains_v20140515.ViewBilling");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -3193,3 +3561,353 @@ impl Route53Domains for Route53DomainsClient {
         proto::json::ResponsePayload::new(&response).deserialize::<ViewBillingResponse, _>()
     }
 }
+
+/// A blocking client for the Amazon Route 53 Domains API, running the calls of a `Route53DomainsClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `Route53DomainsClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct Route53DomainsBlockingClient {
+    client: Route53DomainsClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl Route53DomainsBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> Route53DomainsBlockingClient {
+        Route53DomainsBlockingClient::from_client(Route53DomainsClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> Route53DomainsBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        Route53DomainsBlockingClient::from_client(Route53DomainsClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> Route53DomainsBlockingClient {
+        Route53DomainsBlockingClient::from_client(Route53DomainsClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> Route53DomainsBlockingClient {
+        Route53DomainsBlockingClient::from_client(Route53DomainsClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: Route53DomainsClient) -> Route53DomainsBlockingClient {
+        Route53DomainsBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> Route53DomainsBlockingClient {
+        Route53DomainsBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> Route53DomainsBlockingClient {
+        Route53DomainsBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Route53Domains::accept_domain_transfer_from_another_aws_account`].
+    pub fn accept_domain_transfer_from_another_aws_account(
+        &self,
+        input: AcceptDomainTransferFromAnotherAwsAccountRequest,
+    ) -> Result<
+        AcceptDomainTransferFromAnotherAwsAccountResponse,
+        RusotoError<AcceptDomainTransferFromAnotherAwsAccountError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .accept_domain_transfer_from_another_aws_account(input),
+        )
+    }
+
+    /// Blocking version of [`Route53Domains::cancel_domain_transfer_to_another_aws_account`].
+    pub fn cancel_domain_transfer_to_another_aws_account(
+        &self,
+        input: CancelDomainTransferToAnotherAwsAccountRequest,
+    ) -> Result<
+        CancelDomainTransferToAnotherAwsAccountResponse,
+        RusotoError<CancelDomainTransferToAnotherAwsAccountError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .cancel_domain_transfer_to_another_aws_account(input),
+        )
+    }
+
+    /// Blocking version of [`Route53Domains::check_domain_availability`].
+    pub fn check_domain_availability(
+        &self,
+        input: CheckDomainAvailabilityRequest,
+    ) -> Result<CheckDomainAvailabilityResponse, RusotoError<CheckDomainAvailabilityError>> {
+        self.runtime
+            .block_on(self.client.check_domain_availability(input))
+    }
+
+    /// Blocking version of [`Route53Domains::check_domain_transferability`].
+    pub fn check_domain_transferability(
+        &self,
+        input: CheckDomainTransferabilityRequest,
+    ) -> Result<CheckDomainTransferabilityResponse, RusotoError<CheckDomainTransferabilityError>>
+    {
+        self.runtime
+            .block_on(self.client.check_domain_transferability(input))
+    }
+
+    /// Blocking version of [`Route53Domains::delete_tags_for_domain`].
+    pub fn delete_tags_for_domain(
+        &self,
+        input: DeleteTagsForDomainRequest,
+    ) -> Result<DeleteTagsForDomainResponse, RusotoError<DeleteTagsForDomainError>> {
+        self.runtime
+            .block_on(self.client.delete_tags_for_domain(input))
+    }
+
+    /// Blocking version of [`Route53Domains::disable_domain_auto_renew`].
+    pub fn disable_domain_auto_renew(
+        &self,
+        input: DisableDomainAutoRenewRequest,
+    ) -> Result<DisableDomainAutoRenewResponse, RusotoError<DisableDomainAutoRenewError>> {
+        self.runtime
+            .block_on(self.client.disable_domain_auto_renew(input))
+    }
+
+    /// Blocking version of [`Route53Domains::disable_domain_transfer_lock`].
+    pub fn disable_domain_transfer_lock(
+        &self,
+        input: DisableDomainTransferLockRequest,
+    ) -> Result<DisableDomainTransferLockResponse, RusotoError<DisableDomainTransferLockError>>
+    {
+        self.runtime
+            .block_on(self.client.disable_domain_transfer_lock(input))
+    }
+
+    /// Blocking version of [`Route53Domains::enable_domain_auto_renew`].
+    pub fn enable_domain_auto_renew(
+        &self,
+        input: EnableDomainAutoRenewRequest,
+    ) -> Result<EnableDomainAutoRenewResponse, RusotoError<EnableDomainAutoRenewError>> {
+        self.runtime
+            .block_on(self.client.enable_domain_auto_renew(input))
+    }
+
+    /// Blocking version of [`Route53Domains::enable_domain_transfer_lock`].
+    pub fn enable_domain_transfer_lock(
+        &self,
+        input: EnableDomainTransferLockRequest,
+    ) -> Result<EnableDomainTransferLockResponse, RusotoError<EnableDomainTransferLockError>> {
+        self.runtime
+            .block_on(self.client.enable_domain_transfer_lock(input))
+    }
+
+    /// Blocking version of [`Route53Domains::get_contact_reachability_status`].
+    pub fn get_contact_reachability_status(
+        &self,
+        input: GetContactReachabilityStatusRequest,
+    ) -> Result<GetContactReachabilityStatusResponse, RusotoError<GetContactReachabilityStatusError>>
+    {
+        self.runtime
+            .block_on(self.client.get_contact_reachability_status(input))
+    }
+
+    /// Blocking version of [`Route53Domains::get_domain_detail`].
+    pub fn get_domain_detail(
+        &self,
+        input: GetDomainDetailRequest,
+    ) -> Result<GetDomainDetailResponse, RusotoError<GetDomainDetailError>> {
+        self.runtime.block_on(self.client.get_domain_detail(input))
+    }
+
+    /// Blocking version of [`Route53Domains::get_domain_suggestions`].
+    pub fn get_domain_suggestions(
+        &self,
+        input: GetDomainSuggestionsRequest,
+    ) -> Result<GetDomainSuggestionsResponse, RusotoError<GetDomainSuggestionsError>> {
+        self.runtime
+            .block_on(self.client.get_domain_suggestions(input))
+    }
+
+    /// Blocking version of [`Route53Domains::get_operation_detail`].
+    pub fn get_operation_detail(
+        &self,
+        input: GetOperationDetailRequest,
+    ) -> Result<GetOperationDetailResponse, RusotoError<GetOperationDetailError>> {
+        self.runtime
+            .block_on(self.client.get_operation_detail(input))
+    }
+
+    /// Blocking version of [`Route53Domains::list_domains`].
+    pub fn list_domains(
+        &self,
+        input: ListDomainsRequest,
+    ) -> Result<ListDomainsResponse, RusotoError<ListDomainsError>> {
+        self.runtime.block_on(self.client.list_domains(input))
+    }
+
+    /// Blocking version of [`Route53Domains::list_operations`].
+    pub fn list_operations(
+        &self,
+        input: ListOperationsRequest,
+    ) -> Result<ListOperationsResponse, RusotoError<ListOperationsError>> {
+        self.runtime.block_on(self.client.list_operations(input))
+    }
+
+    /// Blocking version of [`Route53Domains::list_tags_for_domain`].
+    pub fn list_tags_for_domain(
+        &self,
+        input: ListTagsForDomainRequest,
+    ) -> Result<ListTagsForDomainResponse, RusotoError<ListTagsForDomainError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_domain(input))
+    }
+
+    /// Blocking version of [`Route53Domains::register_domain`].
+    pub fn register_domain(
+        &self,
+        input: RegisterDomainRequest,
+    ) -> Result<RegisterDomainResponse, RusotoError<RegisterDomainError>> {
+        self.runtime.block_on(self.client.register_domain(input))
+    }
+
+    /// Blocking version of [`Route53Domains::reject_domain_transfer_from_another_aws_account`].
+    pub fn reject_domain_transfer_from_another_aws_account(
+        &self,
+        input: RejectDomainTransferFromAnotherAwsAccountRequest,
+    ) -> Result<
+        RejectDomainTransferFromAnotherAwsAccountResponse,
+        RusotoError<RejectDomainTransferFromAnotherAwsAccountError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .reject_domain_transfer_from_another_aws_account(input),
+        )
+    }
+
+    /// Blocking version of [`Route53Domains::renew_domain`].
+    pub fn renew_domain(
+        &self,
+        input: RenewDomainRequest,
+    ) -> Result<RenewDomainResponse, RusotoError<RenewDomainError>> {
+        self.runtime.block_on(self.client.renew_domain(input))
+    }
+
+    /// Blocking version of [`Route53Domains::resend_contact_reachability_email`].
+    pub fn resend_contact_reachability_email(
+        &self,
+        input: ResendContactReachabilityEmailRequest,
+    ) -> Result<
+        ResendContactReachabilityEmailResponse,
+        RusotoError<ResendContactReachabilityEmailError>,
+    > {
+        self.runtime
+            .block_on(self.client.resend_contact_reachability_email(input))
+    }
+
+    /// Blocking version of [`Route53Domains::retrieve_domain_auth_code`].
+    pub fn retrieve_domain_auth_code(
+        &self,
+        input: RetrieveDomainAuthCodeRequest,
+    ) -> Result<RetrieveDomainAuthCodeResponse, RusotoError<RetrieveDomainAuthCodeError>> {
+        self.runtime
+            .block_on(self.client.retrieve_domain_auth_code(input))
+    }
+
+    /// Blocking version of [`Route53Domains::transfer_domain`].
+    pub fn transfer_domain(
+        &self,
+        input: TransferDomainRequest,
+    ) -> Result<TransferDomainResponse, RusotoError<TransferDomainError>> {
+        self.runtime.block_on(self.client.transfer_domain(input))
+    }
+
+    /// Blocking version of [`Route53Domains::transfer_domain_to_another_aws_account`].
+    pub fn transfer_domain_to_another_aws_account(
+        &self,
+        input: TransferDomainToAnotherAwsAccountRequest,
+    ) -> Result<
+        TransferDomainToAnotherAwsAccountResponse,
+        RusotoError<TransferDomainToAnotherAwsAccountError>,
+    > {
+        self.runtime
+            .block_on(self.client.transfer_domain_to_another_aws_account(input))
+    }
+
+    /// Blocking version of [`Route53Domains::update_domain_contact`].
+    pub fn update_domain_contact(
+        &self,
+        input: UpdateDomainContactRequest,
+    ) -> Result<UpdateDomainContactResponse, RusotoError<UpdateDomainContactError>> {
+        self.runtime
+            .block_on(self.client.update_domain_contact(input))
+    }
+
+    /// Blocking version of [`Route53Domains::update_domain_contact_privacy`].
+    pub fn update_domain_contact_privacy(
+        &self,
+        input: UpdateDomainContactPrivacyRequest,
+    ) -> Result<UpdateDomainContactPrivacyResponse, RusotoError<UpdateDomainContactPrivacyError>>
+    {
+        self.runtime
+            .block_on(self.client.update_domain_contact_privacy(input))
+    }
+
+    /// Blocking version of [`Route53Domains::update_domain_nameservers`].
+    pub fn update_domain_nameservers(
+        &self,
+        input: UpdateDomainNameserversRequest,
+    ) -> Result<UpdateDomainNameserversResponse, RusotoError<UpdateDomainNameserversError>> {
+        self.runtime
+            .block_on(self.client.update_domain_nameservers(input))
+    }
+
+    /// Blocking version of [`Route53Domains::update_tags_for_domain`].
+    pub fn update_tags_for_domain(
+        &self,
+        input: UpdateTagsForDomainRequest,
+    ) -> Result<UpdateTagsForDomainResponse, RusotoError<UpdateTagsForDomainError>> {
+        self.runtime
+            .block_on(self.client.update_tags_for_domain(input))
+    }
+
+    /// Blocking version of [`Route53Domains::view_billing`].
+    pub fn view_billing(
+        &self,
+        input: ViewBillingRequest,
+    ) -> Result<ViewBillingResponse, RusotoError<ViewBillingError>> {
+        self.runtime.block_on(self.client.view_billing(input))
+    }
+}
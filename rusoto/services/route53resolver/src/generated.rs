@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -1275,41 +1276,62 @@ impl AssociateResolverEndpointIpAddressError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        AssociateResolverEndpointIpAddressError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateResolverEndpointIpAddressError::InternalServiceError,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        AssociateResolverEndpointIpAddressError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateResolverEndpointIpAddressError::InvalidParameter,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        AssociateResolverEndpointIpAddressError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateResolverEndpointIpAddressError::InvalidRequest,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
-                        AssociateResolverEndpointIpAddressError::LimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateResolverEndpointIpAddressError::LimitExceeded,
                     )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(
-                        AssociateResolverEndpointIpAddressError::ResourceExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateResolverEndpointIpAddressError::ResourceExists,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        AssociateResolverEndpointIpAddressError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateResolverEndpointIpAddressError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
-                        AssociateResolverEndpointIpAddressError::Throttling(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateResolverEndpointIpAddressError::Throttling,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1373,46 +1395,70 @@ impl AssociateResolverQueryLogConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        AssociateResolverQueryLogConfigError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateResolverQueryLogConfigError::AccessDenied,
                     )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        AssociateResolverQueryLogConfigError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateResolverQueryLogConfigError::InternalServiceError,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        AssociateResolverQueryLogConfigError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateResolverQueryLogConfigError::InvalidParameter,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        AssociateResolverQueryLogConfigError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateResolverQueryLogConfigError::InvalidRequest,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
-                        AssociateResolverQueryLogConfigError::LimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateResolverQueryLogConfigError::LimitExceeded,
                     )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(
-                        AssociateResolverQueryLogConfigError::ResourceExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateResolverQueryLogConfigError::ResourceExists,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        AssociateResolverQueryLogConfigError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateResolverQueryLogConfigError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AssociateResolverQueryLogConfigError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateResolverQueryLogConfigError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1473,42 +1519,70 @@ impl AssociateResolverRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(AssociateResolverRuleError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateResolverRuleError::InternalServiceError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(AssociateResolverRuleError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateResolverRuleError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(AssociateResolverRuleError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateResolverRuleError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(AssociateResolverRuleError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateResolverRuleError::LimitExceeded,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(AssociateResolverRuleError::ResourceExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateResolverRuleError::ResourceExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(AssociateResolverRuleError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateResolverRuleError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(AssociateResolverRuleError::ResourceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateResolverRuleError::ResourceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(AssociateResolverRuleError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateResolverRuleError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1555,39 +1629,62 @@ impl CreateResolverEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(CreateResolverEndpointError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateResolverEndpointError::InternalServiceError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateResolverEndpointError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateResolverEndpointError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateResolverEndpointError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateResolverEndpointError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateResolverEndpointError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateResolverEndpointError::LimitExceeded,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateResolverEndpointError::ResourceExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateResolverEndpointError::ResourceExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateResolverEndpointError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateResolverEndpointError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateResolverEndpointError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResolverEndpointError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1637,46 +1734,70 @@ impl CreateResolverQueryLogConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(CreateResolverQueryLogConfigError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateResolverQueryLogConfigError::AccessDenied,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        CreateResolverQueryLogConfigError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResolverQueryLogConfigError::InternalServiceError,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        CreateResolverQueryLogConfigError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResolverQueryLogConfigError::InvalidParameter,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateResolverQueryLogConfigError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateResolverQueryLogConfigError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateResolverQueryLogConfigError::LimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateResolverQueryLogConfigError::LimitExceeded,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateResolverQueryLogConfigError::ResourceExists(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateResolverQueryLogConfigError::ResourceExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        CreateResolverQueryLogConfigError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResolverQueryLogConfigError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateResolverQueryLogConfigError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateResolverQueryLogConfigError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1731,34 +1852,70 @@ impl CreateResolverRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(CreateResolverRuleError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateResolverRuleError::InternalServiceError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(CreateResolverRuleError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResolverRuleError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(CreateResolverRuleError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResolverRuleError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(CreateResolverRuleError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResolverRuleError::LimitExceeded,
+                    )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(CreateResolverRuleError::ResourceExists(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResolverRuleError::ResourceExists,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(CreateResolverRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResolverRuleError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(CreateResolverRuleError::ResourceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateResolverRuleError::ResourceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(CreateResolverRuleError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateResolverRuleError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1801,29 +1958,46 @@ impl DeleteResolverEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(DeleteResolverEndpointError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteResolverEndpointError::InternalServiceError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteResolverEndpointError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteResolverEndpointError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteResolverEndpointError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteResolverEndpointError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteResolverEndpointError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteResolverEndpointError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteResolverEndpointError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteResolverEndpointError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1867,36 +2041,54 @@ impl DeleteResolverQueryLogConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(DeleteResolverQueryLogConfigError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteResolverQueryLogConfigError::AccessDenied,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        DeleteResolverQueryLogConfigError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteResolverQueryLogConfigError::InternalServiceError,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        DeleteResolverQueryLogConfigError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteResolverQueryLogConfigError::InvalidParameter,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(DeleteResolverQueryLogConfigError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteResolverQueryLogConfigError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DeleteResolverQueryLogConfigError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteResolverQueryLogConfigError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteResolverQueryLogConfigError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteResolverQueryLogConfigError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -1943,23 +2135,46 @@ impl DeleteResolverRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(DeleteResolverRuleError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteResolverRuleError::InternalServiceError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DeleteResolverRuleError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteResolverRuleError::InvalidParameter,
+                    )
                 }
                 "ResourceInUseException" => {
-                    return RusotoError::Service(DeleteResolverRuleError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteResolverRuleError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteResolverRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteResolverRuleError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteResolverRuleError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteResolverRuleError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2003,36 +2218,54 @@ impl DisassociateResolverEndpointIpAddressError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        DisassociateResolverEndpointIpAddressError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateResolverEndpointIpAddressError::InternalServiceError,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        DisassociateResolverEndpointIpAddressError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateResolverEndpointIpAddressError::InvalidParameter,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        DisassociateResolverEndpointIpAddressError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateResolverEndpointIpAddressError::InvalidRequest,
                     )
                 }
                 "ResourceExistsException" => {
-                    return RusotoError::Service(
-                        DisassociateResolverEndpointIpAddressError::ResourceExists(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateResolverEndpointIpAddressError::ResourceExists,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DisassociateResolverEndpointIpAddressError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateResolverEndpointIpAddressError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
-                        DisassociateResolverEndpointIpAddressError::Throttling(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateResolverEndpointIpAddressError::Throttling,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2089,36 +2322,54 @@ impl DisassociateResolverQueryLogConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        DisassociateResolverQueryLogConfigError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateResolverQueryLogConfigError::AccessDenied,
                     )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        DisassociateResolverQueryLogConfigError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateResolverQueryLogConfigError::InternalServiceError,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        DisassociateResolverQueryLogConfigError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateResolverQueryLogConfigError::InvalidParameter,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        DisassociateResolverQueryLogConfigError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateResolverQueryLogConfigError::InvalidRequest,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        DisassociateResolverQueryLogConfigError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateResolverQueryLogConfigError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
-                        DisassociateResolverQueryLogConfigError::Throttling(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateResolverQueryLogConfigError::Throttling,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2169,24 +2420,38 @@ impl DisassociateResolverRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        DisassociateResolverRuleError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateResolverRuleError::InternalServiceError,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(DisassociateResolverRuleError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateResolverRuleError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DisassociateResolverRuleError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateResolverRuleError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DisassociateResolverRuleError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DisassociateResolverRuleError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2229,34 +2494,54 @@ impl GetResolverDnssecConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetResolverDnssecConfigError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetResolverDnssecConfigError::AccessDenied,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        GetResolverDnssecConfigError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResolverDnssecConfigError::InternalServiceError,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetResolverDnssecConfigError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetResolverDnssecConfigError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetResolverDnssecConfigError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetResolverDnssecConfigError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetResolverDnssecConfigError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetResolverDnssecConfigError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetResolverDnssecConfigError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResolverDnssecConfigError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2295,24 +2580,38 @@ impl GetResolverEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(GetResolverEndpointError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetResolverEndpointError::InternalServiceError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetResolverEndpointError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetResolverEndpointError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetResolverEndpointError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetResolverEndpointError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetResolverEndpointError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResolverEndpointError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2353,36 +2652,54 @@ impl GetResolverQueryLogConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(GetResolverQueryLogConfigError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetResolverQueryLogConfigError::AccessDenied,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        GetResolverQueryLogConfigError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResolverQueryLogConfigError::InternalServiceError,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetResolverQueryLogConfigError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetResolverQueryLogConfigError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(GetResolverQueryLogConfigError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetResolverQueryLogConfigError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetResolverQueryLogConfigError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetResolverQueryLogConfigError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetResolverQueryLogConfigError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetResolverQueryLogConfigError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2429,36 +2746,54 @@ impl GetResolverQueryLogConfigAssociationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        GetResolverQueryLogConfigAssociationError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResolverQueryLogConfigAssociationError::AccessDenied,
                     )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        GetResolverQueryLogConfigAssociationError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResolverQueryLogConfigAssociationError::InternalServiceError,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        GetResolverQueryLogConfigAssociationError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResolverQueryLogConfigAssociationError::InvalidParameter,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        GetResolverQueryLogConfigAssociationError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResolverQueryLogConfigAssociationError::InvalidRequest,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        GetResolverQueryLogConfigAssociationError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResolverQueryLogConfigAssociationError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
-                        GetResolverQueryLogConfigAssociationError::Throttling(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResolverQueryLogConfigAssociationError::Throttling,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2513,31 +2848,46 @@ impl GetResolverQueryLogConfigPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        GetResolverQueryLogConfigPolicyError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResolverQueryLogConfigPolicyError::AccessDenied,
                     )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        GetResolverQueryLogConfigPolicyError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResolverQueryLogConfigPolicyError::InternalServiceError,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        GetResolverQueryLogConfigPolicyError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResolverQueryLogConfigPolicyError::InvalidParameter,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        GetResolverQueryLogConfigPolicyError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResolverQueryLogConfigPolicyError::InvalidRequest,
                     )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(
-                        GetResolverQueryLogConfigPolicyError::UnknownResource(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResolverQueryLogConfigPolicyError::UnknownResource,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2583,20 +2933,38 @@ impl GetResolverRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(GetResolverRuleError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetResolverRuleError::InternalServiceError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetResolverRuleError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResolverRuleError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetResolverRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResolverRuleError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetResolverRuleError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResolverRuleError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2635,26 +3003,38 @@ impl GetResolverRuleAssociationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        GetResolverRuleAssociationError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        GetResolverRuleAssociationError::InternalServiceError,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetResolverRuleAssociationError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetResolverRuleAssociationError::InvalidParameter,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(GetResolverRuleAssociationError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetResolverRuleAssociationError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(GetResolverRuleAssociationError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetResolverRuleAssociationError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2691,21 +3071,30 @@ impl GetResolverRulePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(GetResolverRulePolicyError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetResolverRulePolicyError::InternalServiceError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(GetResolverRulePolicyError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetResolverRulePolicyError::InvalidParameter,
+                    )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(GetResolverRulePolicyError::UnknownResource(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        GetResolverRulePolicyError::UnknownResource,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2745,36 +3134,54 @@ impl ListResolverDnssecConfigsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListResolverDnssecConfigsError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResolverDnssecConfigsError::AccessDenied,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        ListResolverDnssecConfigsError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResolverDnssecConfigsError::InternalServiceError,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListResolverDnssecConfigsError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResolverDnssecConfigsError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListResolverDnssecConfigsError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResolverDnssecConfigsError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListResolverDnssecConfigsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResolverDnssecConfigsError::InvalidRequest,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListResolverDnssecConfigsError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResolverDnssecConfigsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2819,31 +3226,46 @@ impl ListResolverEndpointIpAddressesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        ListResolverEndpointIpAddressesError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResolverEndpointIpAddressesError::InternalServiceError,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        ListResolverEndpointIpAddressesError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResolverEndpointIpAddressesError::InvalidNextToken,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        ListResolverEndpointIpAddressesError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResolverEndpointIpAddressesError::InvalidParameter,
                     )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(
-                        ListResolverEndpointIpAddressesError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResolverEndpointIpAddressesError::ResourceNotFound,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListResolverEndpointIpAddressesError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResolverEndpointIpAddressesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2891,29 +3313,46 @@ impl ListResolverEndpointsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListResolverEndpointsError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResolverEndpointsError::InternalServiceError,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListResolverEndpointsError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResolverEndpointsError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListResolverEndpointsError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResolverEndpointsError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListResolverEndpointsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResolverEndpointsError::InvalidRequest,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListResolverEndpointsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResolverEndpointsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -2957,36 +3396,54 @@ impl ListResolverQueryLogConfigAssociationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        ListResolverQueryLogConfigAssociationsError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResolverQueryLogConfigAssociationsError::AccessDenied,
                     )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        ListResolverQueryLogConfigAssociationsError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResolverQueryLogConfigAssociationsError::InternalServiceError,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        ListResolverQueryLogConfigAssociationsError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResolverQueryLogConfigAssociationsError::InvalidParameter,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        ListResolverQueryLogConfigAssociationsError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResolverQueryLogConfigAssociationsError::InvalidRequest,
                     )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(
-                        ListResolverQueryLogConfigAssociationsError::LimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResolverQueryLogConfigAssociationsError::LimitExceeded,
                     )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(
-                        ListResolverQueryLogConfigAssociationsError::Throttling(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResolverQueryLogConfigAssociationsError::Throttling,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3043,36 +3500,54 @@ impl ListResolverQueryLogConfigsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(ListResolverQueryLogConfigsError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResolverQueryLogConfigsError::AccessDenied,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        ListResolverQueryLogConfigsError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResolverQueryLogConfigsError::InternalServiceError,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        ListResolverQueryLogConfigsError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResolverQueryLogConfigsError::InvalidNextToken,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        ListResolverQueryLogConfigsError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResolverQueryLogConfigsError::InvalidParameter,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListResolverQueryLogConfigsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResolverQueryLogConfigsError::InvalidRequest,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListResolverQueryLogConfigsError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResolverQueryLogConfigsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3117,31 +3592,46 @@ impl ListResolverRuleAssociationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        ListResolverRuleAssociationsError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResolverRuleAssociationsError::InternalServiceError,
                     )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(
-                        ListResolverRuleAssociationsError::InvalidNextToken(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResolverRuleAssociationsError::InvalidNextToken,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        ListResolverRuleAssociationsError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResolverRuleAssociationsError::InvalidParameter,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListResolverRuleAssociationsError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResolverRuleAssociationsError::InvalidRequest,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListResolverRuleAssociationsError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResolverRuleAssociationsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3187,23 +3677,46 @@ impl ListResolverRulesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListResolverRulesError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListResolverRulesError::InternalServiceError,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListResolverRulesError::InvalidNextToken(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResolverRulesError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListResolverRulesError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResolverRulesError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListResolverRulesError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResolverRulesError::InvalidRequest,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListResolverRulesError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListResolverRulesError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3245,32 +3758,54 @@ impl ListTagsForResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::InternalServiceError,
+                    )
                 }
                 "InvalidNextTokenException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidNextToken(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::InvalidNextToken,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(ListTagsForResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListTagsForResourceError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTagsForResourceError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListTagsForResourceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTagsForResourceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3315,36 +3850,54 @@ impl PutResolverQueryLogConfigPolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(
-                        PutResolverQueryLogConfigPolicyError::AccessDenied(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutResolverQueryLogConfigPolicyError::AccessDenied,
                     )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        PutResolverQueryLogConfigPolicyError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutResolverQueryLogConfigPolicyError::InternalServiceError,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(
-                        PutResolverQueryLogConfigPolicyError::InvalidParameter(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutResolverQueryLogConfigPolicyError::InvalidParameter,
                     )
                 }
                 "InvalidPolicyDocument" => {
-                    return RusotoError::Service(
-                        PutResolverQueryLogConfigPolicyError::InvalidPolicyDocument(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutResolverQueryLogConfigPolicyError::InvalidPolicyDocument,
                     )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(
-                        PutResolverQueryLogConfigPolicyError::InvalidRequest(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutResolverQueryLogConfigPolicyError::InvalidRequest,
                     )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(
-                        PutResolverQueryLogConfigPolicyError::UnknownResource(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        PutResolverQueryLogConfigPolicyError::UnknownResource,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3393,26 +3946,38 @@ impl PutResolverRulePolicyError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(PutResolverRulePolicyError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutResolverRulePolicyError::InternalServiceError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(PutResolverRulePolicyError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutResolverRulePolicyError::InvalidParameter,
+                    )
                 }
                 "InvalidPolicyDocument" => {
-                    return RusotoError::Service(PutResolverRulePolicyError::InvalidPolicyDocument(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutResolverRulePolicyError::InvalidPolicyDocument,
+                    )
                 }
                 "UnknownResourceException" => {
-                    return RusotoError::Service(PutResolverRulePolicyError::UnknownResource(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        PutResolverRulePolicyError::UnknownResource,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3455,27 +4020,62 @@ impl TagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(TagResourceError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InternalServiceError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(TagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(TagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidRequest,
+                    )
                 }
                 "InvalidTagException" => {
-                    return RusotoError::Service(TagResourceError::InvalidTag(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::InvalidTag,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(TagResourceError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(TagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(TagResourceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        TagResourceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3517,21 +4117,46 @@ impl UntagResourceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(UntagResourceError::InternalServiceError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InternalServiceError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UntagResourceError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UntagResourceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UntagResourceError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UntagResourceError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3575,36 +4200,54 @@ impl UpdateResolverDnssecConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "AccessDeniedException" => {
-                    return RusotoError::Service(UpdateResolverDnssecConfigError::AccessDenied(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateResolverDnssecConfigError::AccessDenied,
+                    )
                 }
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(
-                        UpdateResolverDnssecConfigError::InternalServiceError(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateResolverDnssecConfigError::InternalServiceError,
                     )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateResolverDnssecConfigError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateResolverDnssecConfigError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateResolverDnssecConfigError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateResolverDnssecConfigError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateResolverDnssecConfigError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateResolverDnssecConfigError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateResolverDnssecConfigError::Throttling(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateResolverDnssecConfigError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3647,29 +4290,46 @@ impl UpdateResolverEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(UpdateResolverEndpointError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateResolverEndpointError::InternalServiceError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateResolverEndpointError::InvalidParameter(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateResolverEndpointError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateResolverEndpointError::InvalidRequest(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateResolverEndpointError::InvalidRequest,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateResolverEndpointError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateResolverEndpointError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateResolverEndpointError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateResolverEndpointError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -3713,31 +4373,62 @@ impl UpdateResolverRuleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "InternalServiceErrorException" => {
-                    return RusotoError::Service(UpdateResolverRuleError::InternalServiceError(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateResolverRuleError::InternalServiceError,
+                    )
                 }
                 "InvalidParameterException" => {
-                    return RusotoError::Service(UpdateResolverRuleError::InvalidParameter(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateResolverRuleError::InvalidParameter,
+                    )
                 }
                 "InvalidRequestException" => {
-                    return RusotoError::Service(UpdateResolverRuleError::InvalidRequest(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateResolverRuleError::InvalidRequest,
+                    )
                 }
                 "LimitExceededException" => {
-                    return RusotoError::Service(UpdateResolverRuleError::LimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateResolverRuleError::LimitExceeded,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(UpdateResolverRuleError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateResolverRuleError::ResourceNotFound,
+                    )
                 }
                 "ResourceUnavailableException" => {
-                    return RusotoError::Service(UpdateResolverRuleError::ResourceUnavailable(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateResolverRuleError::ResourceUnavailable,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(UpdateResolverRuleError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateResolverRuleError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -4009,11 +4700,12 @@ pub struct Route53ResolverClient {
 impl Route53ResolverClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> Route53ResolverClient {
         Route53ResolverClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "Route53Resolver"),
         }
     }
 
@@ -4028,12 +4720,32 @@ impl Route53ResolverClient {
     {
         Route53ResolverClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "Route53Resolver"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> Route53ResolverClient {
-        Route53ResolverClient { client, region }
+        Route53ResolverClient {
+            client,
+            region: region_for_service(region, "Route53Resolver"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> Route53ResolverClient {
+        Route53ResolverClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("Route53Resolver"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> Route53ResolverClient {
+        Route53ResolverClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -4048,6 +4760,7 @@ impl Route53Resolver for Route53ResolverClient {
         RusotoError<AssociateResolverEndpointIpAddressError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateResolverEndpointIpAddress");
         request.add_header(
             "x-amz-target",
             "Route53Resolver.AssociateResolverEndpointIpAddress",
@@ -4076,6 +4789,7 @@ impl Route53Resolver for Route53ResolverClient {
         RusotoError<AssociateResolverQueryLogConfigError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateResolverQueryLogConfig");
         request.add_header(
             "x-amz-target",
             "Route53Resolver.AssociateResolverQueryLogConfig",
@@ -4098,6 +4812,7 @@ impl Route53Resolver for Route53ResolverClient {
         input: AssociateResolverRuleRequest,
     ) -> Result<AssociateResolverRuleResponse, RusotoError<AssociateResolverRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateResolverRule");
         request.add_header("x-amz-target", "Route53Resolver.AssociateResolverRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4117,6 +4832,7 @@ impl Route53Resolver for Route53ResolverClient {
         input: CreateResolverEndpointRequest,
     ) -> Result<CreateResolverEndpointResponse, RusotoError<CreateResolverEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateResolverEndpoint");
         request.add_header("x-amz-target", "Route53Resolver.CreateResolverEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4137,6 +4853,7 @@ impl Route53Resolver for Route53ResolverClient {
     ) -> Result<CreateResolverQueryLogConfigResponse, RusotoError<CreateResolverQueryLogConfigError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateResolverQueryLogConfig");
         request.add_header(
             "x-amz-target",
             "Route53Resolver.CreateResolverQueryLogConfig",
@@ -4159,6 +4876,7 @@ impl Route53Resolver for Route53ResolverClient {
         input: CreateResolverRuleRequest,
     ) -> Result<CreateResolverRuleResponse, RusotoError<CreateResolverRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateResolverRule");
         request.add_header("x-amz-target", "Route53Resolver.CreateResolverRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4177,6 +4895,7 @@ impl Route53Resolver for Route53ResolverClient {
         input: DeleteResolverEndpointRequest,
     ) -> Result<DeleteResolverEndpointResponse, RusotoError<DeleteResolverEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteResolverEndpoint");
         request.add_header("x-amz-target", "Route53Resolver.DeleteResolverEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4197,6 +4916,7 @@ impl Route53Resolver for Route53ResolverClient {
     ) -> Result<DeleteResolverQueryLogConfigResponse, RusotoError<DeleteResolverQueryLogConfigError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteResolverQueryLogConfig");
         request.add_header(
             "x-amz-target",
             "Route53Resolver.DeleteResolverQueryLogConfig",
@@ -4219,6 +4939,7 @@ impl Route53Resolver for Route53ResolverClient {
         input: DeleteResolverRuleRequest,
     ) -> Result<DeleteResolverRuleResponse, RusotoError<DeleteResolverRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteResolverRule");
         request.add_header("x-amz-target", "Route53Resolver.DeleteResolverRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4240,6 +4961,7 @@ impl Route53Resolver for Route53ResolverClient {
         RusotoError<DisassociateResolverEndpointIpAddressError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisassociateResolverEndpointIpAddress");
         request.add_header(
             "x-amz-target",
             "Route53Resolver.DisassociateResolverEndpointIpAddress",
@@ -4268,6 +4990,7 @@ impl Route53Resolver for Route53ResolverClient {
         RusotoError<DisassociateResolverQueryLogConfigError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisassociateResolverQueryLogConfig");
         request.add_header(
             "x-amz-target",
             "Route53Resolver.DisassociateResolverQueryLogConfig",
@@ -4293,6 +5016,7 @@ impl Route53Resolver for Route53ResolverClient {
         input: DisassociateResolverRuleRequest,
     ) -> Result<DisassociateResolverRuleResponse, RusotoError<DisassociateResolverRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisassociateResolverRule");
         request.add_header("x-amz-target", "Route53Resolver.DisassociateResolverRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4312,6 +5036,7 @@ impl Route53Resolver for Route53ResolverClient {
         input: GetResolverDnssecConfigRequest,
     ) -> Result<GetResolverDnssecConfigResponse, RusotoError<GetResolverDnssecConfigError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetResolverDnssecConfig");
         request.add_header("x-amz-target", "Route53Resolver.GetResolverDnssecConfig");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4331,6 +5056,7 @@ impl Route53Resolver for Route53ResolverClient {
         input: GetResolverEndpointRequest,
     ) -> Result<GetResolverEndpointResponse, RusotoError<GetResolverEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetResolverEndpoint");
         request.add_header("x-amz-target", "Route53Resolver.GetResolverEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4350,6 +5076,7 @@ impl Route53Resolver for Route53ResolverClient {
     ) -> Result<GetResolverQueryLogConfigResponse, RusotoError<GetResolverQueryLogConfigError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetResolverQueryLogConfig");
         request.add_header("x-amz-target", "Route53Resolver.GetResolverQueryLogConfig");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4372,6 +5099,7 @@ impl Route53Resolver for Route53ResolverClient {
         RusotoError<GetResolverQueryLogConfigAssociationError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetResolverQueryLogConfigAssociation");
         request.add_header(
             "x-amz-target",
             "Route53Resolver.GetResolverQueryLogConfigAssociation",
@@ -4400,6 +5128,7 @@ impl Route53Resolver for Route53ResolverClient {
         RusotoError<GetResolverQueryLogConfigPolicyError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetResolverQueryLogConfigPolicy");
         request.add_header(
             "x-amz-target",
             "Route53Resolver.GetResolverQueryLogConfigPolicy",
@@ -4422,6 +5151,7 @@ impl Route53Resolver for Route53ResolverClient {
         input: GetResolverRuleRequest,
     ) -> Result<GetResolverRuleResponse, RusotoError<GetResolverRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetResolverRule");
         request.add_header("x-amz-target", "Route53Resolver.GetResolverRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4441,6 +5171,7 @@ impl Route53Resolver for Route53ResolverClient {
     ) -> Result<GetResolverRuleAssociationResponse, RusotoError<GetResolverRuleAssociationError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetResolverRuleAssociation");
         request.add_header("x-amz-target", "Route53Resolver.GetResolverRuleAssociation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4460,6 +5191,7 @@ impl Route53Resolver for Route53ResolverClient {
         input: GetResolverRulePolicyRequest,
     ) -> Result<GetResolverRulePolicyResponse, RusotoError<GetResolverRulePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetResolverRulePolicy");
         request.add_header("x-amz-target", "Route53Resolver.GetResolverRulePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4480,6 +5212,7 @@ impl Route53Resolver for Route53ResolverClient {
     ) -> Result<ListResolverDnssecConfigsResponse, RusotoError<ListResolverDnssecConfigsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListResolverDnssecConfigs");
         request.add_header("x-amz-target", "Route53Resolver.ListResolverDnssecConfigs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4502,6 +5235,7 @@ impl Route53Resolver for Route53ResolverClient {
         RusotoError<ListResolverEndpointIpAddressesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListResolverEndpointIpAddresses");
         request.add_header(
             "x-amz-target",
             "Route53Resolver.ListResolverEndpointIpAddresses",
@@ -4524,6 +5258,7 @@ impl Route53Resolver for Route53ResolverClient {
         input: ListResolverEndpointsRequest,
     ) -> Result<ListResolverEndpointsResponse, RusotoError<ListResolverEndpointsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListResolverEndpoints");
         request.add_header("x-amz-target", "Route53Resolver.ListResolverEndpoints");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4546,6 +5281,7 @@ impl Route53Resolver for Route53ResolverClient {
         RusotoError<ListResolverQueryLogConfigAssociationsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListResolverQueryLogConfigAssociations");
         request.add_header(
             "x-amz-target",
             "Route53Resolver.ListResolverQueryLogConfigAssociations",
@@ -4572,6 +5308,7 @@ impl Route53Resolver for Route53ResolverClient {
     ) -> Result<ListResolverQueryLogConfigsResponse, RusotoError<ListResolverQueryLogConfigsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListResolverQueryLogConfigs");
         request.add_header(
             "x-amz-target",
             "Route53Resolver.ListResolverQueryLogConfigs",
@@ -4595,6 +5332,7 @@ impl Route53Resolver for Route53ResolverClient {
     ) -> Result<ListResolverRuleAssociationsResponse, RusotoError<ListResolverRuleAssociationsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListResolverRuleAssociations");
         request.add_header(
             "x-amz-target",
             "Route53Resolver.ListResolverRuleAssociations",
@@ -4617,6 +5355,7 @@ impl Route53Resolver for Route53ResolverClient {
         input: ListResolverRulesRequest,
     ) -> Result<ListResolverRulesResponse, RusotoError<ListResolverRulesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListResolverRules");
         request.add_header("x-amz-target", "Route53Resolver.ListResolverRules");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4635,6 +5374,7 @@ impl Route53Resolver for Route53ResolverClient {
         input: ListTagsForResourceRequest,
     ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTagsForResource");
         request.add_header("x-amz-target", "Route53Resolver.ListTagsForResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4656,6 +5396,7 @@ impl Route53Resolver for Route53ResolverClient {
         RusotoError<PutResolverQueryLogConfigPolicyError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutResolverQueryLogConfigPolicy");
         request.add_header(
             "x-amz-target",
             "Route53Resolver.PutResolverQueryLogConfigPolicy",
@@ -4678,6 +5419,7 @@ impl Route53Resolver for Route53ResolverClient {
         input: PutResolverRulePolicyRequest,
     ) -> Result<PutResolverRulePolicyResponse, RusotoError<PutResolverRulePolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutResolverRulePolicy");
         request.add_header("x-amz-target", "Route53Resolver.PutResolverRulePolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4697,6 +5439,7 @@ impl Route53Resolver for Route53ResolverClient {
         input: TagResourceRequest,
     ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("TagResource");
         request.add_header("x-amz-target", "Route53Resolver.TagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4715,6 +5458,7 @@ impl Route53Resolver for Route53ResolverClient {
         input: UntagResourceRequest,
     ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UntagResource");
         request.add_header("x-amz-target", "Route53Resolver.UntagResource");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4734,6 +5478,7 @@ impl Route53Resolver for Route53ResolverClient {
     ) -> Result<UpdateResolverDnssecConfigResponse, RusotoError<UpdateResolverDnssecConfigError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateResolverDnssecConfig");
         request.add_header("x-amz-target", "Route53Resolver.UpdateResolverDnssecConfig");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4753,6 +5498,7 @@ impl Route53Resolver for Route53ResolverClient {
         input: UpdateResolverEndpointRequest,
     ) -> Result<UpdateResolverEndpointResponse, RusotoError<UpdateResolverEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateResolverEndpoint");
         request.add_header("x-amz-target", "Route53Resolver.UpdateResolverEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4772,6 +5518,7 @@ impl Route53Resolver for Route53ResolverClient {
         input: UpdateResolverRuleRequest,
     ) -> Result<UpdateResolverRuleResponse, RusotoError<UpdateResolverRuleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateResolverRule");
         request.add_header("x-amz-target", "Route53Resolver.UpdateResolverRule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -4784,3 +5531,435 @@ impl Route53Resolver for Route53ResolverClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateResolverRuleResponse, _>()
     }
 }
+
+/// A blocking client for the Route53Resolver API, running the calls of a `Route53ResolverClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `Route53ResolverClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct Route53ResolverBlockingClient {
+    client: Route53ResolverClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl Route53ResolverBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> Route53ResolverBlockingClient {
+        Route53ResolverBlockingClient::from_client(Route53ResolverClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> Route53ResolverBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        Route53ResolverBlockingClient::from_client(Route53ResolverClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> Route53ResolverBlockingClient {
+        Route53ResolverBlockingClient::from_client(Route53ResolverClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> Route53ResolverBlockingClient {
+        Route53ResolverBlockingClient::from_client(Route53ResolverClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: Route53ResolverClient) -> Route53ResolverBlockingClient {
+        Route53ResolverBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> Route53ResolverBlockingClient {
+        Route53ResolverBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> Route53ResolverBlockingClient {
+        Route53ResolverBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`Route53Resolver::associate_resolver_endpoint_ip_address`].
+    pub fn associate_resolver_endpoint_ip_address(
+        &self,
+        input: AssociateResolverEndpointIpAddressRequest,
+    ) -> Result<
+        AssociateResolverEndpointIpAddressResponse,
+        RusotoError<AssociateResolverEndpointIpAddressError>,
+    > {
+        self.runtime
+            .block_on(self.client.associate_resolver_endpoint_ip_address(input))
+    }
+
+    /// Blocking version of [`Route53Resolver::associate_resolver_query_log_config`].
+    pub fn associate_resolver_query_log_config(
+        &self,
+        input: AssociateResolverQueryLogConfigRequest,
+    ) -> Result<
+        AssociateResolverQueryLogConfigResponse,
+        RusotoError<AssociateResolverQueryLogConfigError>,
+    > {
+        self.runtime
+            .block_on(self.client.associate_resolver_query_log_config(input))
+    }
+
+    /// Blocking version of [`Route53Resolver::associate_resolver_rule`].
+    pub fn associate_resolver_rule(
+        &self,
+        input: AssociateResolverRuleRequest,
+    ) -> Result<AssociateResolverRuleResponse, RusotoError<AssociateResolverRuleError>> {
+        self.runtime
+            .block_on(self.client.associate_resolver_rule(input))
+    }
+
+    /// Blocking version of [`Route53Resolver::create_resolver_endpoint`].
+    pub fn create_resolver_endpoint(
+        &self,
+        input: CreateResolverEndpointRequest,
+    ) -> Result<CreateResolverEndpointResponse, RusotoError<CreateResolverEndpointError>> {
+        self.runtime
+            .block_on(self.client.create_resolver_endpoint(input))
+    }
+
+    /// Blocking version of [`Route53Resolver::create_resolver_query_log_config`].
+    pub fn create_resolver_query_log_config(
+        &self,
+        input: CreateResolverQueryLogConfigRequest,
+    ) -> Result<CreateResolverQueryLogConfigResponse, RusotoError<CreateResolverQueryLogConfigError>>
+    {
+        self.runtime
+            .block_on(self.client.create_resolver_query_log_config(input))
+    }
+
+    /// Blocking version of [`Route53Resolver::create_resolver_rule`].
+    pub fn create_resolver_rule(
+        &self,
+        input: CreateResolverRuleRequest,
+    ) -> Result<CreateResolverRuleResponse, RusotoError<CreateResolverRuleError>> {
+        self.runtime
+            .block_on(self.client.create_resolver_rule(input))
+    }
+
+    /// Blocking version of [`Route53Resolver::delete_resolver_endpoint`].
+    pub fn delete_resolver_endpoint(
+        &self,
+        input: DeleteResolverEndpointRequest,
+    ) -> Result<DeleteResolverEndpointResponse, RusotoError<DeleteResolverEndpointError>> {
+        self.runtime
+            .block_on(self.client.delete_resolver_endpoint(input))
+    }
+
+    /// Blocking version of [`Route53Resolver::delete_resolver_query_log_config`].
+    pub fn delete_resolver_query_log_config(
+        &self,
+        input: DeleteResolverQueryLogConfigRequest,
+    ) -> Result<DeleteResolverQueryLogConfigResponse, RusotoError<DeleteResolverQueryLogConfigError>>
+    {
+        self.runtime
+            .block_on(self.client.delete_resolver_query_log_config(input))
+    }
+
+    /// Blocking version of [`Route53Resolver::delete_resolver_rule`].
+    pub fn delete_resolver_rule(
+        &self,
+        input: DeleteResolverRuleRequest,
+    ) -> Result<DeleteResolverRuleResponse, RusotoError<DeleteResolverRuleError>> {
+        self.runtime
+            .block_on(self.client.delete_resolver_rule(input))
+    }
+
+    /// Blocking version of [`Route53Resolver::disassociate_resolver_endpoint_ip_address`].
+    pub fn disassociate_resolver_endpoint_ip_address(
+        &self,
+        input: DisassociateResolverEndpointIpAddressRequest,
+    ) -> Result<
+        DisassociateResolverEndpointIpAddressResponse,
+        RusotoError<DisassociateResolverEndpointIpAddressError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_resolver_endpoint_ip_address(input))
+    }
+
+    /// Blocking version of [`Route53Resolver::disassociate_resolver_query_log_config`].
+    pub fn disassociate_resolver_query_log_config(
+        &self,
+        input: DisassociateResolverQueryLogConfigRequest,
+    ) -> Result<
+        DisassociateResolverQueryLogConfigResponse,
+        RusotoError<DisassociateResolverQueryLogConfigError>,
+    > {
+        self.runtime
+            .block_on(self.client.disassociate_resolver_query_log_config(input))
+    }
+
+    /// Blocking version of [`Route53Resolver::disassociate_resolver_rule`].
+    pub fn disassociate_resolver_rule(
+        &self,
+        input: DisassociateResolverRuleRequest,
+    ) -> Result<DisassociateResolverRuleResponse, RusotoError<DisassociateResolverRuleError>> {
+        self.runtime
+            .block_on(self.client.disassociate_resolver_rule(input))
+    }
+
+    /// Blocking version of [`Route53Resolver::get_resolver_dnssec_config`].
+    pub fn get_resolver_dnssec_config(
+        &self,
+        input: GetResolverDnssecConfigRequest,
+    ) -> Result<GetResolverDnssecConfigResponse, RusotoError<GetResolverDnssecConfigError>> {
+        self.runtime
+            .block_on(self.client.get_resolver_dnssec_config(input))
+    }
+
+    /// Blocking version of [`Route53Resolver::get_resolver_endpoint`].
+    pub fn get_resolver_endpoint(
+        &self,
+        input: GetResolverEndpointRequest,
+    ) -> Result<GetResolverEndpointResponse, RusotoError<GetResolverEndpointError>> {
+        self.runtime
+            .block_on(self.client.get_resolver_endpoint(input))
+    }
+
+    /// Blocking version of [`Route53Resolver::get_resolver_query_log_config`].
+    pub fn get_resolver_query_log_config(
+        &self,
+        input: GetResolverQueryLogConfigRequest,
+    ) -> Result<GetResolverQueryLogConfigResponse, RusotoError<GetResolverQueryLogConfigError>>
+    {
+        self.runtime
+            .block_on(self.client.get_resolver_query_log_config(input))
+    }
+
+    /// Blocking version of [`Route53Resolver::get_resolver_query_log_config_association`].
+    pub fn get_resolver_query_log_config_association(
+        &self,
+        input: GetResolverQueryLogConfigAssociationRequest,
+    ) -> Result<
+        GetResolverQueryLogConfigAssociationResponse,
+        RusotoError<GetResolverQueryLogConfigAssociationError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_resolver_query_log_config_association(input))
+    }
+
+    /// Blocking version of [`Route53Resolver::get_resolver_query_log_config_policy`].
+    pub fn get_resolver_query_log_config_policy(
+        &self,
+        input: GetResolverQueryLogConfigPolicyRequest,
+    ) -> Result<
+        GetResolverQueryLogConfigPolicyResponse,
+        RusotoError<GetResolverQueryLogConfigPolicyError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_resolver_query_log_config_policy(input))
+    }
+
+    /// Blocking version of [`Route53Resolver::get_resolver_rule`].
+    pub fn get_resolver_rule(
+        &self,
+        input: GetResolverRuleRequest,
+    ) -> Result<GetResolverRuleResponse, RusotoError<GetResolverRuleError>> {
+        self.runtime.block_on(self.client.get_resolver_rule(input))
+    }
+
+    /// Blocking version of [`Route53Resolver::get_resolver_rule_association`].
+    pub fn get_resolver_rule_association(
+        &self,
+        input: GetResolverRuleAssociationRequest,
+    ) -> Result<GetResolverRuleAssociationResponse, RusotoError<GetResolverRuleAssociationError>>
+    {
+        self.runtime
+            .block_on(self.client.get_resolver_rule_association(input))
+    }
+
+    /// Blocking version of [`Route53Resolver::get_resolver_rule_policy`].
+    pub fn get_resolver_rule_policy(
+        &self,
+        input: GetResolverRulePolicyRequest,
+    ) -> Result<GetResolverRulePolicyResponse, RusotoError<GetResolverRulePolicyError>> {
+        self.runtime
+            .block_on(self.client.get_resolver_rule_policy(input))
+    }
+
+    /// Blocking version of [`Route53Resolver::list_resolver_dnssec_configs`].
+    pub fn list_resolver_dnssec_configs(
+        &self,
+        input: ListResolverDnssecConfigsRequest,
+    ) -> Result<ListResolverDnssecConfigsResponse, RusotoError<ListResolverDnssecConfigsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_resolver_dnssec_configs(input))
+    }
+
+    /// Blocking version of [`Route53Resolver::list_resolver_endpoint_ip_addresses`].
+    pub fn list_resolver_endpoint_ip_addresses(
+        &self,
+        input: ListResolverEndpointIpAddressesRequest,
+    ) -> Result<
+        ListResolverEndpointIpAddressesResponse,
+        RusotoError<ListResolverEndpointIpAddressesError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_resolver_endpoint_ip_addresses(input))
+    }
+
+    /// Blocking version of [`Route53Resolver::list_resolver_endpoints`].
+    pub fn list_resolver_endpoints(
+        &self,
+        input: ListResolverEndpointsRequest,
+    ) -> Result<ListResolverEndpointsResponse, RusotoError<ListResolverEndpointsError>> {
+        self.runtime
+            .block_on(self.client.list_resolver_endpoints(input))
+    }
+
+    /// Blocking version of [`Route53Resolver::list_resolver_query_log_config_associations`].
+    pub fn list_resolver_query_log_config_associations(
+        &self,
+        input: ListResolverQueryLogConfigAssociationsRequest,
+    ) -> Result<
+        ListResolverQueryLogConfigAssociationsResponse,
+        RusotoError<ListResolverQueryLogConfigAssociationsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .list_resolver_query_log_config_associations(input),
+        )
+    }
+
+    /// Blocking version of [`Route53Resolver::list_resolver_query_log_configs`].
+    pub fn list_resolver_query_log_configs(
+        &self,
+        input: ListResolverQueryLogConfigsRequest,
+    ) -> Result<ListResolverQueryLogConfigsResponse, RusotoError<ListResolverQueryLogConfigsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_resolver_query_log_configs(input))
+    }
+
+    /// Blocking version of [`Route53Resolver::list_resolver_rule_associations`].
+    pub fn list_resolver_rule_associations(
+        &self,
+        input: ListResolverRuleAssociationsRequest,
+    ) -> Result<ListResolverRuleAssociationsResponse, RusotoError<ListResolverRuleAssociationsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_resolver_rule_associations(input))
+    }
+
+    /// Blocking version of [`Route53Resolver::list_resolver_rules`].
+    pub fn list_resolver_rules(
+        &self,
+        input: ListResolverRulesRequest,
+    ) -> Result<ListResolverRulesResponse, RusotoError<ListResolverRulesError>> {
+        self.runtime
+            .block_on(self.client.list_resolver_rules(input))
+    }
+
+    /// Blocking version of [`Route53Resolver::list_tags_for_resource`].
+    pub fn list_tags_for_resource(
+        &self,
+        input: ListTagsForResourceRequest,
+    ) -> Result<ListTagsForResourceResponse, RusotoError<ListTagsForResourceError>> {
+        self.runtime
+            .block_on(self.client.list_tags_for_resource(input))
+    }
+
+    /// Blocking version of [`Route53Resolver::put_resolver_query_log_config_policy`].
+    pub fn put_resolver_query_log_config_policy(
+        &self,
+        input: PutResolverQueryLogConfigPolicyRequest,
+    ) -> Result<
+        PutResolverQueryLogConfigPolicyResponse,
+        RusotoError<PutResolverQueryLogConfigPolicyError>,
+    > {
+        self.runtime
+            .block_on(self.client.put_resolver_query_log_config_policy(input))
+    }
+
+    /// Blocking version of [`Route53Resolver::put_resolver_rule_policy`].
+    pub fn put_resolver_rule_policy(
+        &self,
+        input: PutResolverRulePolicyRequest,
+    ) -> Result<PutResolverRulePolicyResponse, RusotoError<PutResolverRulePolicyError>> {
+        self.runtime
+            .block_on(self.client.put_resolver_rule_policy(input))
+    }
+
+    /// Blocking version of [`Route53Resolver::tag_resource`].
+    pub fn tag_resource(
+        &self,
+        input: TagResourceRequest,
+    ) -> Result<TagResourceResponse, RusotoError<TagResourceError>> {
+        self.runtime.block_on(self.client.tag_resource(input))
+    }
+
+    /// Blocking version of [`Route53Resolver::untag_resource`].
+    pub fn untag_resource(
+        &self,
+        input: UntagResourceRequest,
+    ) -> Result<UntagResourceResponse, RusotoError<UntagResourceError>> {
+        self.runtime.block_on(self.client.untag_resource(input))
+    }
+
+    /// Blocking version of [`Route53Resolver::update_resolver_dnssec_config`].
+    pub fn update_resolver_dnssec_config(
+        &self,
+        input: UpdateResolverDnssecConfigRequest,
+    ) -> Result<UpdateResolverDnssecConfigResponse, RusotoError<UpdateResolverDnssecConfigError>>
+    {
+        self.runtime
+            .block_on(self.client.update_resolver_dnssec_config(input))
+    }
+
+    /// Blocking version of [`Route53Resolver::update_resolver_endpoint`].
+    pub fn update_resolver_endpoint(
+        &self,
+        input: UpdateResolverEndpointRequest,
+    ) -> Result<UpdateResolverEndpointResponse, RusotoError<UpdateResolverEndpointError>> {
+        self.runtime
+            .block_on(self.client.update_resolver_endpoint(input))
+    }
+
+    /// Blocking version of [`Route53Resolver::update_resolver_rule`].
+    pub fn update_resolver_rule(
+        &self,
+        input: UpdateResolverRuleRequest,
+    ) -> Result<UpdateResolverRuleResponse, RusotoError<UpdateResolverRuleError>> {
+        self.runtime
+            .block_on(self.client.update_resolver_rule(input))
+    }
+}
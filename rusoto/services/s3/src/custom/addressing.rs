@@ -0,0 +1,52 @@
+use rusoto_core::config::S3AddressingStyle;
+use rusoto_core::region::Region;
+use rusoto_core::signature::{SignedRequest, SigningAlgorithm};
+use rusoto_core::{Client, Interceptor};
+
+/// Returns the client sending the requests of the S3 client with the given addressing style.
+pub(crate) fn with_addressing_style(client: Client, style: S3AddressingStyle) -> Client {
+    match style {
+        S3AddressingStyle::Path => client,
+        style => client.with_interceptor(VirtualHostedStyle(style)),
+    }
+}
+
+/// Moves the bucket from the path of the requests to their hostname, for the buckets whose
+/// names are valid hostnames without dots, which would not match the certificates of S3.
+///
+/// `GetBucketLocation` and the requests signed with the legacy S3 authentication, whose
+/// signature covers the path, are left path-style.
+struct VirtualHostedStyle(S3AddressingStyle);
+
+impl Interceptor for VirtualHostedStyle {
+    fn before_sign(&self, request: &mut SignedRequest) {
+        if request.hostname.is_some()
+            || request.signing_algorithm == SigningAlgorithm::S3Legacy
+            || request.operation.as_deref() == Some("GetBucketLocation")
+        {
+            return;
+        }
+        if let (S3AddressingStyle::Auto, Region::Custom { .. }) = (self.0, &request.region) {
+            return;
+        }
+        let path = request.path.clone();
+        let mut parts = path.trim_start_matches('/').splitn(2, '/');
+        let bucket = parts.next().unwrap_or_default();
+        if !is_dns_compatible(bucket) {
+            return;
+        }
+        let hostname = format!("{}.{}", bucket, request.hostname());
+        request.set_hostname(Some(hostname));
+        request.path = format!("/{}", parts.next().unwrap_or_default());
+    }
+}
+
+/// Whether a bucket name is a valid hostname label without dots.
+fn is_dns_compatible(bucket: &str) -> bool {
+    (3..=63).contains(&bucket.len())
+        && bucket
+            .bytes()
+            .all(|b| b.is_ascii_lowercase() || b.is_ascii_digit() || b == b'-')
+        && !bucket.starts_with('-')
+        && !bucket.ends_with('-')
+}
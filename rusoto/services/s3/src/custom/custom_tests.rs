@@ -1,12 +1,14 @@
 extern crate rusoto_mock;
 
+use super::addressing;
 use crate::generated::*;
 
 use self::rusoto_mock::*;
 use bytes::BytesMut;
 use futures::TryStreamExt;
-use rusoto_core::signature::SignedRequest;
-use rusoto_core::{Region, RusotoError};
+use rusoto_core::config::S3AddressingStyle;
+use rusoto_core::signature::{PayloadSigning, SignedRequest, DEFAULT_CHUNK_SIZE};
+use rusoto_core::{Client, Region, RusotoError};
 
 #[tokio::test]
 async fn test_multipart_upload_copy_response() {
@@ -434,6 +436,7 @@ async fn should_serialize_complicated_request() {
                 .unwrap()
                 .contains(&Vec::from("range")));
             assert!(request.payload.is_none());
+            assert!(request.raw_response);
         });
 
     let client = S3Client::new_with(mock, MockCredentialsProvider, Region::UsEast1);
@@ -479,6 +482,86 @@ async fn can_construct_streaming_body() {
     assert_eq!("Simple Body Test", read_string);
 }
 
+#[tokio::test]
+async fn should_sign_put_object_body_in_chunks() {
+    let mock =
+        MockRequestDispatcher::with_status(200).with_request_checker(|request: &SignedRequest| {
+            assert_eq!(
+                request.headers["x-amz-content-sha256"][0],
+                b"STREAMING-AWS4-HMAC-SHA256-PAYLOAD".to_vec()
+            );
+            assert_eq!(
+                request.headers["content-encoding"][0],
+                b"aws-chunked".to_vec()
+            );
+            assert_eq!(
+                request.headers["x-amz-decoded-content-length"][0],
+                b"16".to_vec()
+            );
+            assert_eq!(request.headers["content-length"][0], b"189".to_vec());
+        });
+    let client = Client::new_with(MockCredentialsProvider, mock)
+        .with_payload_signing(PayloadSigning::chunked(DEFAULT_CHUNK_SIZE, None).unwrap());
+    let client = S3Client::new_with_client(client, Region::UsEast1);
+
+    let request = PutObjectRequest {
+        bucket: "bucket".to_owned(),
+        key: "key".to_owned(),
+        body: Some(b"Simple Body Test".to_vec().into()),
+        ..Default::default()
+    };
+    client.put_object(request).await.unwrap();
+}
+
+#[tokio::test]
+async fn should_address_buckets_virtual_hosted_style() {
+    let mock =
+        MockRequestDispatcher::with_status(200).with_request_checker(|request: &SignedRequest| {
+            if request.hostname().starts_with("my-bucket.") {
+                assert_eq!(request.hostname(), "my-bucket.s3.eu-west-1.amazonaws.com");
+                assert_eq!(request.path(), "/key");
+            } else {
+                assert_eq!(request.hostname(), "s3.eu-west-1.amazonaws.com");
+                assert_eq!(request.path(), "/my.bucket/key");
+            }
+        });
+    let client = Client::new_with(MockCredentialsProvider, mock);
+    let client = addressing::with_addressing_style(client, S3AddressingStyle::Auto);
+    let client = S3Client::new_with_client(client, Region::EuWest1);
+
+    for bucket in &["my-bucket", "my.bucket"] {
+        let request = DeleteObjectRequest {
+            bucket: bucket.to_string(),
+            key: "key".to_owned(),
+            ..Default::default()
+        };
+        client.delete_object(request).await.unwrap();
+    }
+}
+
+#[tokio::test]
+async fn should_keep_custom_endpoints_path_style() {
+    let mock =
+        MockRequestDispatcher::with_status(200).with_request_checker(|request: &SignedRequest| {
+            assert_eq!(request.hostname(), "localhost:4566");
+            assert_eq!(request.path(), "/my-bucket/key");
+        });
+    let client = Client::new_with(MockCredentialsProvider, mock);
+    let client = addressing::with_addressing_style(client, S3AddressingStyle::Auto);
+    let region = Region::Custom {
+        name: "eu-west-1".to_owned(),
+        endpoint: "http://localhost:4566".to_owned(),
+    };
+    let client = S3Client::new_with_client(client, region);
+
+    let request = DeleteObjectRequest {
+        bucket: "my-bucket".to_owned(),
+        key: "key".to_owned(),
+        ..Default::default()
+    };
+    client.delete_object(request).await.unwrap();
+}
+
 #[test]
 fn structs_should_impl_clone() {
     fn assert_clone<T: Clone>() {}
@@ -515,12 +598,17 @@ async fn test_parse_no_such_bucket_error() {
     let result = client.list_objects_v2(request).await;
     assert!(result.is_err());
     let err = result.err().unwrap();
-    assert_eq!(
-        RusotoError::Service(ListObjectsV2Error::NoSuchBucket(
-            "The specified bucket does not exist".to_owned()
-        )),
-        err
-    );
+    match err {
+        RusotoError::Service(error, metadata) => {
+            assert_eq!(
+                ListObjectsV2Error::NoSuchBucket("The specified bucket does not exist".to_owned()),
+                error
+            );
+            assert_eq!(metadata.code.as_deref(), Some("NoSuchBucket"));
+            assert_eq!(metadata.request_id.as_deref(), Some("4442587FB7D0A2F9"));
+        }
+        _ => panic!("Expected a service error"),
+    }
 }
 
 #[tokio::test]
@@ -548,3 +636,34 @@ async fn test_multiple_mock() {
     assert_eq!(result.expiration, Some("foo2".to_string()));
     assert_eq!(result.restore, Some("bar2".to_string()));
 }
+
+#[cfg(feature = "blocking")]
+#[test]
+fn test_blocking_get_object() {
+    use std::io::Read;
+
+    let mock = MockRequestDispatcher::with_status(200)
+        .with_body("object contents")
+        .with_header("Content-Type", "text/plain")
+        .with_request_checker(|request: &SignedRequest| {
+            assert_eq!(request.method, "GET");
+            assert_eq!(request.path, "/bucket/key");
+        });
+    let client = S3BlockingClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    let request = GetObjectRequest {
+        bucket: "bucket".to_owned(),
+        key: "key".to_owned(),
+        ..GetObjectRequest::default()
+    };
+    let result = client.get_object(request).unwrap();
+    assert_eq!(result.content_type.as_deref(), Some("text/plain"));
+
+    let mut contents = String::new();
+    result
+        .body
+        .unwrap()
+        .into_blocking_read()
+        .read_to_string(&mut contents)
+        .unwrap();
+    assert_eq!(contents, "object contents");
+}
@@ -1,5 +1,7 @@
 /// Utility helpers for working with S3
 pub mod util;
 
+pub(crate) mod addressing;
+
 #[cfg(test)]
 mod custom_tests;
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto::xml::error::*;
@@ -15307,9 +15308,12 @@ impl AbortMultipartUploadError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchUpload" => {
-                        return RusotoError::Service(AbortMultipartUploadError::NoSuchUpload(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            AbortMultipartUploadError::NoSuchUpload,
+                        )
                     }
                     _ => {}
                 }
@@ -15383,9 +15387,12 @@ impl CopyObjectError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ObjectNotInActiveTierError" => {
-                        return RusotoError::Service(CopyObjectError::ObjectNotInActiveTierError(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CopyObjectError::ObjectNotInActiveTierError,
+                        )
                     }
                     _ => {}
                 }
@@ -15428,14 +15435,20 @@ impl CreateBucketError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "BucketAlreadyExists" => {
-                        return RusotoError::Service(CreateBucketError::BucketAlreadyExists(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateBucketError::BucketAlreadyExists,
+                        )
                     }
                     "BucketAlreadyOwnedByYou" => {
-                        return RusotoError::Service(CreateBucketError::BucketAlreadyOwnedByYou(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            CreateBucketError::BucketAlreadyOwnedByYou,
+                        )
                     }
                     _ => {}
                 }
@@ -16825,14 +16838,20 @@ impl GetObjectError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "InvalidObjectState" => {
-                        return RusotoError::Service(GetObjectError::InvalidObjectState(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetObjectError::InvalidObjectState,
+                        )
                     }
                     "NoSuchKey" => {
-                        return RusotoError::Service(GetObjectError::NoSuchKey(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetObjectError::NoSuchKey,
+                        )
                     }
                     _ => {}
                 }
@@ -16874,9 +16893,12 @@ impl GetObjectAclError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchKey" => {
-                        return RusotoError::Service(GetObjectAclError::NoSuchKey(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            GetObjectAclError::NoSuchKey,
+                        )
                     }
                     _ => {}
                 }
@@ -17117,9 +17139,12 @@ impl HeadBucketError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchBucket" => {
-                        return RusotoError::Service(HeadBucketError::NoSuchBucket(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            HeadBucketError::NoSuchBucket,
+                        )
                     }
                     _ => {}
                 }
@@ -17160,9 +17185,12 @@ impl HeadObjectError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchKey" => {
-                        return RusotoError::Service(HeadObjectError::NoSuchKey(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            HeadObjectError::NoSuchKey,
+                        )
                     }
                     _ => {}
                 }
@@ -17442,9 +17470,12 @@ impl ListObjectsError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchBucket" => {
-                        return RusotoError::Service(ListObjectsError::NoSuchBucket(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListObjectsError::NoSuchBucket,
+                        )
                     }
                     _ => {}
                 }
@@ -17485,9 +17516,12 @@ impl ListObjectsV2Error {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchBucket" => {
-                        return RusotoError::Service(ListObjectsV2Error::NoSuchBucket(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            ListObjectsV2Error::NoSuchBucket,
+                        )
                     }
                     _ => {}
                 }
@@ -18270,9 +18304,12 @@ impl PutObjectAclError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "NoSuchKey" => {
-                        return RusotoError::Service(PutObjectAclError::NoSuchKey(
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
                             parsed_error.message,
-                        ))
+                            PutObjectAclError::NoSuchKey,
+                        )
                     }
                     _ => {}
                 }
@@ -18480,10 +18517,11 @@ impl RestoreObjectError {
             if let Ok(parsed_error) = Self::deserialize(&mut stack) {
                 match &parsed_error.code[..] {
                     "ObjectAlreadyInActiveTierError" => {
-                        return RusotoError::Service(
-                            RestoreObjectError::ObjectAlreadyInActiveTierError(
-                                parsed_error.message,
-                            ),
+                        return RusotoError::service_error(
+                            &res,
+                            &parsed_error.code,
+                            parsed_error.message,
+                            RestoreObjectError::ObjectAlreadyInActiveTierError,
                         )
                     }
                     _ => {}
@@ -19215,11 +19253,12 @@ pub struct S3Client {
 impl S3Client {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> S3Client {
         S3Client {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "S3"),
         }
     }
 
@@ -19234,12 +19273,35 @@ impl S3Client {
     {
         S3Client {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "S3"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> S3Client {
-        S3Client { client, region }
+        S3Client {
+            client,
+            region: region_for_service(region, "S3"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> S3Client {
+        S3Client {
+            client: crate::custom::addressing::with_addressing_style(
+                Client::new_with_config(config),
+                config.get_s3_addressing_style(),
+            ),
+            region: config.get_region_for_service("S3"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> S3Client {
+        S3Client {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -19254,6 +19316,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("AbortMultipartUpload");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -19283,6 +19346,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("POST", "s3", &self.region, &request_uri);
+        request.set_operation("CompleteMultipartUpload");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -19337,6 +19401,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("CopyObject");
 
         request.add_optional_header("x-amz-acl", input.acl.as_ref());
         request.add_optional_header(
@@ -19489,6 +19554,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("CreateBucket");
 
         request.add_optional_header("x-amz-acl", input.acl.as_ref());
         request.add_optional_header(
@@ -19535,6 +19601,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("POST", "s3", &self.region, &request_uri);
+        request.set_operation("CreateMultipartUpload");
 
         request.add_optional_header("x-amz-acl", input.acl.as_ref());
         request.add_optional_header(
@@ -19651,6 +19718,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucket");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -19674,6 +19742,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketAnalyticsConfiguration");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -19704,6 +19773,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketCors");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -19730,6 +19800,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketEncryption");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -19756,6 +19827,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketIntelligentTieringConfiguration");
 
         let mut params = Params::new();
         params.put("id", &input.id);
@@ -19782,6 +19854,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketInventoryConfiguration");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -19812,6 +19885,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketLifecycle");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -19838,6 +19912,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketMetricsConfiguration");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -19868,6 +19943,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketOwnershipControls");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -19894,6 +19970,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketPolicy");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -19920,6 +19997,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketReplication");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -19946,6 +20024,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketTagging");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -19972,6 +20051,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteBucketWebsite");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -19998,6 +20078,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteObject");
 
         request.add_optional_header(
             "x-amz-bypass-governance-retention",
@@ -20039,6 +20120,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteObjectTagging");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -20070,6 +20152,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("POST", "s3", &self.region, &request_uri);
+        request.set_operation("DeleteObjects");
 
         request.add_optional_header(
             "x-amz-bypass-governance-retention",
@@ -20112,6 +20195,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("DELETE", "s3", &self.region, &request_uri);
+        request.set_operation("DeletePublicAccessBlock");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -20141,6 +20225,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketAccelerateConfiguration");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -20176,6 +20261,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketAcl");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -20211,6 +20297,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketAnalyticsConfiguration");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -20244,6 +20331,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketCors");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -20276,6 +20364,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketEncryption");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -20311,6 +20400,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketIntelligentTieringConfiguration");
 
         let mut params = Params::new();
         params.put("id", &input.id);
@@ -20349,6 +20439,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketInventoryConfiguration");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -20382,6 +20473,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketLifecycle");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -20417,6 +20509,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketLifecycleConfiguration");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -20449,6 +20542,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketLocation");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -20481,6 +20575,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketLogging");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -20514,6 +20609,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketMetricsConfiguration");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -20547,6 +20643,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketNotification");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -20580,6 +20677,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketNotificationConfiguration");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -20616,6 +20714,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketOwnershipControls");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -20648,6 +20747,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketPolicy");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -20678,6 +20778,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketPolicyStatus");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -20710,6 +20811,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketReplication");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -20742,6 +20844,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketRequestPayment");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -20774,6 +20877,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketTagging");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -20806,6 +20910,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketVersioning");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -20838,6 +20943,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetBucketWebsite");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -20870,6 +20976,8 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetObject");
+        request.set_raw_response(true);
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -21006,6 +21114,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetObjectAcl");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -21042,6 +21151,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetObjectLegalHold");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -21079,6 +21189,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetObjectLockConfiguration");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -21111,6 +21222,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetObjectRetention");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -21147,6 +21259,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetObjectTagging");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -21182,6 +21295,8 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetObjectTorrent");
+        request.set_raw_response(true);
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -21211,6 +21326,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("GetPublicAccessBlock");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -21243,6 +21359,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("HEAD", "s3", &self.region, &request_uri);
+        request.set_operation("HeadBucket");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -21266,6 +21383,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("HEAD", "s3", &self.region, &request_uri);
+        request.set_operation("HeadObject");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -21383,6 +21501,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListBucketAnalyticsConfigurations");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -21424,6 +21543,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListBucketIntelligentTieringConfigurations");
 
         let mut params = Params::new();
         if let Some(ref x) = input.continuation_token {
@@ -21464,6 +21584,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListBucketInventoryConfigurations");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -21505,6 +21626,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListBucketMetricsConfigurations");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -21537,6 +21659,7 @@ impl S3 for S3Client {
         let request_uri = "/";
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListBuckets");
 
         let mut response = self
             .sign_and_dispatch(request, ListBucketsError::from_response)
@@ -21561,6 +21684,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListMultipartUploads");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -21611,6 +21735,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListObjectVersions");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -21661,6 +21786,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListObjects");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -21708,6 +21834,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListObjectsV2");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -21762,6 +21889,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("GET", "s3", &self.region, &request_uri);
+        request.set_operation("ListParts");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -21803,6 +21931,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketAccelerateConfiguration");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -21839,6 +21968,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketAcl");
 
         request.add_optional_header("x-amz-acl", input.acl.as_ref());
         request.add_optional_header("Content-MD5", input.content_md5.as_ref());
@@ -21887,6 +22017,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketAnalyticsConfiguration");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -21921,6 +22052,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketCors");
 
         request.add_optional_header("Content-MD5", input.content_md5.as_ref());
         request.add_optional_header(
@@ -21956,6 +22088,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketEncryption");
 
         request.add_optional_header("Content-MD5", input.content_md5.as_ref());
         request.add_optional_header(
@@ -21991,6 +22124,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketIntelligentTieringConfiguration");
 
         let mut params = Params::new();
         params.put("id", &input.id);
@@ -22024,6 +22158,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketInventoryConfiguration");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -22058,6 +22193,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketLifecycle");
 
         request.add_optional_header("Content-MD5", input.content_md5.as_ref());
         request.add_optional_header(
@@ -22097,6 +22233,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketLifecycleConfiguration");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -22135,6 +22272,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketLogging");
 
         request.add_optional_header("Content-MD5", input.content_md5.as_ref());
         request.add_optional_header(
@@ -22170,6 +22308,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketMetricsConfiguration");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -22204,6 +22343,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketNotification");
 
         request.add_optional_header("Content-MD5", input.content_md5.as_ref());
         request.add_optional_header(
@@ -22239,6 +22379,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketNotificationConfiguration");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -22275,6 +22416,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketOwnershipControls");
 
         request.add_optional_header("Content-MD5", input.content_md5.as_ref());
         request.add_optional_header(
@@ -22310,6 +22452,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketPolicy");
 
         request.add_optional_header(
             "x-amz-confirm-remove-self-bucket-access",
@@ -22343,6 +22486,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketReplication");
 
         request.add_optional_header("Content-MD5", input.content_md5.as_ref());
         request.add_optional_header(
@@ -22379,6 +22523,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketRequestPayment");
 
         request.add_optional_header("Content-MD5", input.content_md5.as_ref());
         request.add_optional_header(
@@ -22414,6 +22559,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketTagging");
 
         request.add_optional_header("Content-MD5", input.content_md5.as_ref());
         request.add_optional_header(
@@ -22445,6 +22591,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketVersioning");
 
         request.add_optional_header("Content-MD5", input.content_md5.as_ref());
         request.add_optional_header(
@@ -22481,6 +22628,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutBucketWebsite");
 
         request.add_optional_header("Content-MD5", input.content_md5.as_ref());
         request.add_optional_header(
@@ -22516,6 +22664,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutObject");
 
         request.add_optional_header("x-amz-acl", input.acl.as_ref());
         request.add_optional_header(
@@ -22632,6 +22781,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutObjectAcl");
 
         request.add_optional_header("x-amz-acl", input.acl.as_ref());
         request.add_optional_header("Content-MD5", input.content_md5.as_ref());
@@ -22686,6 +22836,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutObjectLegalHold");
 
         request.add_optional_header("Content-MD5", input.content_md5.as_ref());
         request.add_optional_header(
@@ -22732,6 +22883,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutObjectLockConfiguration");
 
         request.add_optional_header("Content-MD5", input.content_md5.as_ref());
         request.add_optional_header(
@@ -22775,6 +22927,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutObjectRetention");
 
         request.add_optional_header(
             "x-amz-bypass-governance-retention",
@@ -22824,6 +22977,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutObjectTagging");
 
         request.add_optional_header("Content-MD5", input.content_md5.as_ref());
         request.add_optional_header(
@@ -22860,6 +23014,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}", bucket = input.bucket);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("PutPublicAccessBlock");
 
         request.add_optional_header("Content-MD5", input.content_md5.as_ref());
         request.add_optional_header(
@@ -22895,6 +23050,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("POST", "s3", &self.region, &request_uri);
+        request.set_operation("RestoreObject");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -22939,6 +23095,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("POST", "s3", &self.region, &request_uri);
+        request.set_operation("SelectObjectContent");
 
         request.add_optional_header(
             "x-amz-expected-bucket-owner",
@@ -22985,6 +23142,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("UploadPart");
 
         request.add_optional_header("Content-Length", input.content_length.as_ref());
         request.add_optional_header("Content-MD5", input.content_md5.as_ref());
@@ -23047,6 +23205,7 @@ impl S3 for S3Client {
         let request_uri = format!("/{bucket}/{key}", bucket = input.bucket, key = input.key);
 
         let mut request = SignedRequest::new("PUT", "s3", &self.region, &request_uri);
+        request.set_operation("UploadPartCopy");
 
         request.add_header("x-amz-copy-source", &input.copy_source.to_string());
         request.add_optional_header(
@@ -23134,6 +23293,939 @@ impl S3 for S3Client {
     }
 }
 
+/// A blocking client for the Amazon S3 API, running the calls of a `S3Client`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `S3Client`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct S3BlockingClient {
+    client: S3Client,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl S3BlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> S3BlockingClient {
+        S3BlockingClient::from_client(S3Client::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> S3BlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        S3BlockingClient::from_client(S3Client::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> S3BlockingClient {
+        S3BlockingClient::from_client(S3Client::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> S3BlockingClient {
+        S3BlockingClient::from_client(S3Client::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: S3Client) -> S3BlockingClient {
+        S3BlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> S3BlockingClient {
+        S3BlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> S3BlockingClient {
+        S3BlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`S3::abort_multipart_upload`].
+    pub fn abort_multipart_upload(
+        &self,
+        input: AbortMultipartUploadRequest,
+    ) -> Result<AbortMultipartUploadOutput, RusotoError<AbortMultipartUploadError>> {
+        self.runtime
+            .block_on(self.client.abort_multipart_upload(input))
+    }
+
+    /// Blocking version of [`S3::complete_multipart_upload`].
+    pub fn complete_multipart_upload(
+        &self,
+        input: CompleteMultipartUploadRequest,
+    ) -> Result<CompleteMultipartUploadOutput, RusotoError<CompleteMultipartUploadError>> {
+        self.runtime
+            .block_on(self.client.complete_multipart_upload(input))
+    }
+
+    /// Blocking version of [`S3::copy_object`].
+    pub fn copy_object(
+        &self,
+        input: CopyObjectRequest,
+    ) -> Result<CopyObjectOutput, RusotoError<CopyObjectError>> {
+        self.runtime.block_on(self.client.copy_object(input))
+    }
+
+    /// Blocking version of [`S3::create_bucket`].
+    pub fn create_bucket(
+        &self,
+        input: CreateBucketRequest,
+    ) -> Result<CreateBucketOutput, RusotoError<CreateBucketError>> {
+        self.runtime.block_on(self.client.create_bucket(input))
+    }
+
+    /// Blocking version of [`S3::create_multipart_upload`].
+    pub fn create_multipart_upload(
+        &self,
+        input: CreateMultipartUploadRequest,
+    ) -> Result<CreateMultipartUploadOutput, RusotoError<CreateMultipartUploadError>> {
+        self.runtime
+            .block_on(self.client.create_multipart_upload(input))
+    }
+
+    /// Blocking version of [`S3::delete_bucket`].
+    pub fn delete_bucket(
+        &self,
+        input: DeleteBucketRequest,
+    ) -> Result<(), RusotoError<DeleteBucketError>> {
+        self.runtime.block_on(self.client.delete_bucket(input))
+    }
+
+    /// Blocking version of [`S3::delete_bucket_analytics_configuration`].
+    pub fn delete_bucket_analytics_configuration(
+        &self,
+        input: DeleteBucketAnalyticsConfigurationRequest,
+    ) -> Result<(), RusotoError<DeleteBucketAnalyticsConfigurationError>> {
+        self.runtime
+            .block_on(self.client.delete_bucket_analytics_configuration(input))
+    }
+
+    /// Blocking version of [`S3::delete_bucket_cors`].
+    pub fn delete_bucket_cors(
+        &self,
+        input: DeleteBucketCorsRequest,
+    ) -> Result<(), RusotoError<DeleteBucketCorsError>> {
+        self.runtime.block_on(self.client.delete_bucket_cors(input))
+    }
+
+    /// Blocking version of [`S3::delete_bucket_encryption`].
+    pub fn delete_bucket_encryption(
+        &self,
+        input: DeleteBucketEncryptionRequest,
+    ) -> Result<(), RusotoError<DeleteBucketEncryptionError>> {
+        self.runtime
+            .block_on(self.client.delete_bucket_encryption(input))
+    }
+
+    /// Blocking version of [`S3::delete_bucket_intelligent_tiering_configuration`].
+    pub fn delete_bucket_intelligent_tiering_configuration(
+        &self,
+        input: DeleteBucketIntelligentTieringConfigurationRequest,
+    ) -> Result<(), RusotoError<DeleteBucketIntelligentTieringConfigurationError>> {
+        self.runtime.block_on(
+            self.client
+                .delete_bucket_intelligent_tiering_configuration(input),
+        )
+    }
+
+    /// Blocking version of [`S3::delete_bucket_inventory_configuration`].
+    pub fn delete_bucket_inventory_configuration(
+        &self,
+        input: DeleteBucketInventoryConfigurationRequest,
+    ) -> Result<(), RusotoError<DeleteBucketInventoryConfigurationError>> {
+        self.runtime
+            .block_on(self.client.delete_bucket_inventory_configuration(input))
+    }
+
+    /// Blocking version of [`S3::delete_bucket_lifecycle`].
+    pub fn delete_bucket_lifecycle(
+        &self,
+        input: DeleteBucketLifecycleRequest,
+    ) -> Result<(), RusotoError<DeleteBucketLifecycleError>> {
+        self.runtime
+            .block_on(self.client.delete_bucket_lifecycle(input))
+    }
+
+    /// Blocking version of [`S3::delete_bucket_metrics_configuration`].
+    pub fn delete_bucket_metrics_configuration(
+        &self,
+        input: DeleteBucketMetricsConfigurationRequest,
+    ) -> Result<(), RusotoError<DeleteBucketMetricsConfigurationError>> {
+        self.runtime
+            .block_on(self.client.delete_bucket_metrics_configuration(input))
+    }
+
+    /// Blocking version of [`S3::delete_bucket_ownership_controls`].
+    pub fn delete_bucket_ownership_controls(
+        &self,
+        input: DeleteBucketOwnershipControlsRequest,
+    ) -> Result<(), RusotoError<DeleteBucketOwnershipControlsError>> {
+        self.runtime
+            .block_on(self.client.delete_bucket_ownership_controls(input))
+    }
+
+    /// Blocking version of [`S3::delete_bucket_policy`].
+    pub fn delete_bucket_policy(
+        &self,
+        input: DeleteBucketPolicyRequest,
+    ) -> Result<(), RusotoError<DeleteBucketPolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_bucket_policy(input))
+    }
+
+    /// Blocking version of [`S3::delete_bucket_replication`].
+    pub fn delete_bucket_replication(
+        &self,
+        input: DeleteBucketReplicationRequest,
+    ) -> Result<(), RusotoError<DeleteBucketReplicationError>> {
+        self.runtime
+            .block_on(self.client.delete_bucket_replication(input))
+    }
+
+    /// Blocking version of [`S3::delete_bucket_tagging`].
+    pub fn delete_bucket_tagging(
+        &self,
+        input: DeleteBucketTaggingRequest,
+    ) -> Result<(), RusotoError<DeleteBucketTaggingError>> {
+        self.runtime
+            .block_on(self.client.delete_bucket_tagging(input))
+    }
+
+    /// Blocking version of [`S3::delete_bucket_website`].
+    pub fn delete_bucket_website(
+        &self,
+        input: DeleteBucketWebsiteRequest,
+    ) -> Result<(), RusotoError<DeleteBucketWebsiteError>> {
+        self.runtime
+            .block_on(self.client.delete_bucket_website(input))
+    }
+
+    /// Blocking version of [`S3::delete_object`].
+    pub fn delete_object(
+        &self,
+        input: DeleteObjectRequest,
+    ) -> Result<DeleteObjectOutput, RusotoError<DeleteObjectError>> {
+        self.runtime.block_on(self.client.delete_object(input))
+    }
+
+    /// Blocking version of [`S3::delete_object_tagging`].
+    pub fn delete_object_tagging(
+        &self,
+        input: DeleteObjectTaggingRequest,
+    ) -> Result<DeleteObjectTaggingOutput, RusotoError<DeleteObjectTaggingError>> {
+        self.runtime
+            .block_on(self.client.delete_object_tagging(input))
+    }
+
+    /// Blocking version of [`S3::delete_objects`].
+    pub fn delete_objects(
+        &self,
+        input: DeleteObjectsRequest,
+    ) -> Result<DeleteObjectsOutput, RusotoError<DeleteObjectsError>> {
+        self.runtime.block_on(self.client.delete_objects(input))
+    }
+
+    /// Blocking version of [`S3::delete_public_access_block`].
+    pub fn delete_public_access_block(
+        &self,
+        input: DeletePublicAccessBlockRequest,
+    ) -> Result<(), RusotoError<DeletePublicAccessBlockError>> {
+        self.runtime
+            .block_on(self.client.delete_public_access_block(input))
+    }
+
+    /// Blocking version of [`S3::get_bucket_accelerate_configuration`].
+    pub fn get_bucket_accelerate_configuration(
+        &self,
+        input: GetBucketAccelerateConfigurationRequest,
+    ) -> Result<
+        GetBucketAccelerateConfigurationOutput,
+        RusotoError<GetBucketAccelerateConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_bucket_accelerate_configuration(input))
+    }
+
+    /// Blocking version of [`S3::get_bucket_acl`].
+    pub fn get_bucket_acl(
+        &self,
+        input: GetBucketAclRequest,
+    ) -> Result<GetBucketAclOutput, RusotoError<GetBucketAclError>> {
+        self.runtime.block_on(self.client.get_bucket_acl(input))
+    }
+
+    /// Blocking version of [`S3::get_bucket_analytics_configuration`].
+    pub fn get_bucket_analytics_configuration(
+        &self,
+        input: GetBucketAnalyticsConfigurationRequest,
+    ) -> Result<
+        GetBucketAnalyticsConfigurationOutput,
+        RusotoError<GetBucketAnalyticsConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_bucket_analytics_configuration(input))
+    }
+
+    /// Blocking version of [`S3::get_bucket_cors`].
+    pub fn get_bucket_cors(
+        &self,
+        input: GetBucketCorsRequest,
+    ) -> Result<GetBucketCorsOutput, RusotoError<GetBucketCorsError>> {
+        self.runtime.block_on(self.client.get_bucket_cors(input))
+    }
+
+    /// Blocking version of [`S3::get_bucket_encryption`].
+    pub fn get_bucket_encryption(
+        &self,
+        input: GetBucketEncryptionRequest,
+    ) -> Result<GetBucketEncryptionOutput, RusotoError<GetBucketEncryptionError>> {
+        self.runtime
+            .block_on(self.client.get_bucket_encryption(input))
+    }
+
+    /// Blocking version of [`S3::get_bucket_intelligent_tiering_configuration`].
+    pub fn get_bucket_intelligent_tiering_configuration(
+        &self,
+        input: GetBucketIntelligentTieringConfigurationRequest,
+    ) -> Result<
+        GetBucketIntelligentTieringConfigurationOutput,
+        RusotoError<GetBucketIntelligentTieringConfigurationError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .get_bucket_intelligent_tiering_configuration(input),
+        )
+    }
+
+    /// Blocking version of [`S3::get_bucket_inventory_configuration`].
+    pub fn get_bucket_inventory_configuration(
+        &self,
+        input: GetBucketInventoryConfigurationRequest,
+    ) -> Result<
+        GetBucketInventoryConfigurationOutput,
+        RusotoError<GetBucketInventoryConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_bucket_inventory_configuration(input))
+    }
+
+    /// Blocking version of [`S3::get_bucket_lifecycle`].
+    pub fn get_bucket_lifecycle(
+        &self,
+        input: GetBucketLifecycleRequest,
+    ) -> Result<GetBucketLifecycleOutput, RusotoError<GetBucketLifecycleError>> {
+        self.runtime
+            .block_on(self.client.get_bucket_lifecycle(input))
+    }
+
+    /// Blocking version of [`S3::get_bucket_lifecycle_configuration`].
+    pub fn get_bucket_lifecycle_configuration(
+        &self,
+        input: GetBucketLifecycleConfigurationRequest,
+    ) -> Result<
+        GetBucketLifecycleConfigurationOutput,
+        RusotoError<GetBucketLifecycleConfigurationError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_bucket_lifecycle_configuration(input))
+    }
+
+    /// Blocking version of [`S3::get_bucket_location`].
+    pub fn get_bucket_location(
+        &self,
+        input: GetBucketLocationRequest,
+    ) -> Result<GetBucketLocationOutput, RusotoError<GetBucketLocationError>> {
+        self.runtime
+            .block_on(self.client.get_bucket_location(input))
+    }
+
+    /// Blocking version of [`S3::get_bucket_logging`].
+    pub fn get_bucket_logging(
+        &self,
+        input: GetBucketLoggingRequest,
+    ) -> Result<GetBucketLoggingOutput, RusotoError<GetBucketLoggingError>> {
+        self.runtime.block_on(self.client.get_bucket_logging(input))
+    }
+
+    /// Blocking version of [`S3::get_bucket_metrics_configuration`].
+    pub fn get_bucket_metrics_configuration(
+        &self,
+        input: GetBucketMetricsConfigurationRequest,
+    ) -> Result<GetBucketMetricsConfigurationOutput, RusotoError<GetBucketMetricsConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.get_bucket_metrics_configuration(input))
+    }
+
+    /// Blocking version of [`S3::get_bucket_notification`].
+    pub fn get_bucket_notification(
+        &self,
+        input: GetBucketNotificationConfigurationRequest,
+    ) -> Result<NotificationConfigurationDeprecated, RusotoError<GetBucketNotificationError>> {
+        self.runtime
+            .block_on(self.client.get_bucket_notification(input))
+    }
+
+    /// Blocking version of [`S3::get_bucket_notification_configuration`].
+    pub fn get_bucket_notification_configuration(
+        &self,
+        input: GetBucketNotificationConfigurationRequest,
+    ) -> Result<NotificationConfiguration, RusotoError<GetBucketNotificationConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.get_bucket_notification_configuration(input))
+    }
+
+    /// Blocking version of [`S3::get_bucket_ownership_controls`].
+    pub fn get_bucket_ownership_controls(
+        &self,
+        input: GetBucketOwnershipControlsRequest,
+    ) -> Result<GetBucketOwnershipControlsOutput, RusotoError<GetBucketOwnershipControlsError>>
+    {
+        self.runtime
+            .block_on(self.client.get_bucket_ownership_controls(input))
+    }
+
+    /// Blocking version of [`S3::get_bucket_policy`].
+    pub fn get_bucket_policy(
+        &self,
+        input: GetBucketPolicyRequest,
+    ) -> Result<GetBucketPolicyOutput, RusotoError<GetBucketPolicyError>> {
+        self.runtime.block_on(self.client.get_bucket_policy(input))
+    }
+
+    /// Blocking version of [`S3::get_bucket_policy_status`].
+    pub fn get_bucket_policy_status(
+        &self,
+        input: GetBucketPolicyStatusRequest,
+    ) -> Result<GetBucketPolicyStatusOutput, RusotoError<GetBucketPolicyStatusError>> {
+        self.runtime
+            .block_on(self.client.get_bucket_policy_status(input))
+    }
+
+    /// Blocking version of [`S3::get_bucket_replication`].
+    pub fn get_bucket_replication(
+        &self,
+        input: GetBucketReplicationRequest,
+    ) -> Result<GetBucketReplicationOutput, RusotoError<GetBucketReplicationError>> {
+        self.runtime
+            .block_on(self.client.get_bucket_replication(input))
+    }
+
+    /// Blocking version of [`S3::get_bucket_request_payment`].
+    pub fn get_bucket_request_payment(
+        &self,
+        input: GetBucketRequestPaymentRequest,
+    ) -> Result<GetBucketRequestPaymentOutput, RusotoError<GetBucketRequestPaymentError>> {
+        self.runtime
+            .block_on(self.client.get_bucket_request_payment(input))
+    }
+
+    /// Blocking version of [`S3::get_bucket_tagging`].
+    pub fn get_bucket_tagging(
+        &self,
+        input: GetBucketTaggingRequest,
+    ) -> Result<GetBucketTaggingOutput, RusotoError<GetBucketTaggingError>> {
+        self.runtime.block_on(self.client.get_bucket_tagging(input))
+    }
+
+    /// Blocking version of [`S3::get_bucket_versioning`].
+    pub fn get_bucket_versioning(
+        &self,
+        input: GetBucketVersioningRequest,
+    ) -> Result<GetBucketVersioningOutput, RusotoError<GetBucketVersioningError>> {
+        self.runtime
+            .block_on(self.client.get_bucket_versioning(input))
+    }
+
+    /// Blocking version of [`S3::get_bucket_website`].
+    pub fn get_bucket_website(
+        &self,
+        input: GetBucketWebsiteRequest,
+    ) -> Result<GetBucketWebsiteOutput, RusotoError<GetBucketWebsiteError>> {
+        self.runtime.block_on(self.client.get_bucket_website(input))
+    }
+
+    /// Blocking version of [`S3::get_object`].
+    pub fn get_object(
+        &self,
+        input: GetObjectRequest,
+    ) -> Result<GetObjectOutput, RusotoError<GetObjectError>> {
+        self.runtime.block_on(self.client.get_object(input))
+    }
+
+    /// Blocking version of [`S3::get_object_acl`].
+    pub fn get_object_acl(
+        &self,
+        input: GetObjectAclRequest,
+    ) -> Result<GetObjectAclOutput, RusotoError<GetObjectAclError>> {
+        self.runtime.block_on(self.client.get_object_acl(input))
+    }
+
+    /// Blocking version of [`S3::get_object_legal_hold`].
+    pub fn get_object_legal_hold(
+        &self,
+        input: GetObjectLegalHoldRequest,
+    ) -> Result<GetObjectLegalHoldOutput, RusotoError<GetObjectLegalHoldError>> {
+        self.runtime
+            .block_on(self.client.get_object_legal_hold(input))
+    }
+
+    /// Blocking version of [`S3::get_object_lock_configuration`].
+    pub fn get_object_lock_configuration(
+        &self,
+        input: GetObjectLockConfigurationRequest,
+    ) -> Result<GetObjectLockConfigurationOutput, RusotoError<GetObjectLockConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.get_object_lock_configuration(input))
+    }
+
+    /// Blocking version of [`S3::get_object_retention`].
+    pub fn get_object_retention(
+        &self,
+        input: GetObjectRetentionRequest,
+    ) -> Result<GetObjectRetentionOutput, RusotoError<GetObjectRetentionError>> {
+        self.runtime
+            .block_on(self.client.get_object_retention(input))
+    }
+
+    /// Blocking version of [`S3::get_object_tagging`].
+    pub fn get_object_tagging(
+        &self,
+        input: GetObjectTaggingRequest,
+    ) -> Result<GetObjectTaggingOutput, RusotoError<GetObjectTaggingError>> {
+        self.runtime.block_on(self.client.get_object_tagging(input))
+    }
+
+    /// Blocking version of [`S3::get_object_torrent`].
+    pub fn get_object_torrent(
+        &self,
+        input: GetObjectTorrentRequest,
+    ) -> Result<GetObjectTorrentOutput, RusotoError<GetObjectTorrentError>> {
+        self.runtime.block_on(self.client.get_object_torrent(input))
+    }
+
+    /// Blocking version of [`S3::get_public_access_block`].
+    pub fn get_public_access_block(
+        &self,
+        input: GetPublicAccessBlockRequest,
+    ) -> Result<GetPublicAccessBlockOutput, RusotoError<GetPublicAccessBlockError>> {
+        self.runtime
+            .block_on(self.client.get_public_access_block(input))
+    }
+
+    /// Blocking version of [`S3::head_bucket`].
+    pub fn head_bucket(
+        &self,
+        input: HeadBucketRequest,
+    ) -> Result<(), RusotoError<HeadBucketError>> {
+        self.runtime.block_on(self.client.head_bucket(input))
+    }
+
+    /// Blocking version of [`S3::head_object`].
+    pub fn head_object(
+        &self,
+        input: HeadObjectRequest,
+    ) -> Result<HeadObjectOutput, RusotoError<HeadObjectError>> {
+        self.runtime.block_on(self.client.head_object(input))
+    }
+
+    /// Blocking version of [`S3::list_bucket_analytics_configurations`].
+    pub fn list_bucket_analytics_configurations(
+        &self,
+        input: ListBucketAnalyticsConfigurationsRequest,
+    ) -> Result<
+        ListBucketAnalyticsConfigurationsOutput,
+        RusotoError<ListBucketAnalyticsConfigurationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_bucket_analytics_configurations(input))
+    }
+
+    /// Blocking version of [`S3::list_bucket_intelligent_tiering_configurations`].
+    pub fn list_bucket_intelligent_tiering_configurations(
+        &self,
+        input: ListBucketIntelligentTieringConfigurationsRequest,
+    ) -> Result<
+        ListBucketIntelligentTieringConfigurationsOutput,
+        RusotoError<ListBucketIntelligentTieringConfigurationsError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .list_bucket_intelligent_tiering_configurations(input),
+        )
+    }
+
+    /// Blocking version of [`S3::list_bucket_inventory_configurations`].
+    pub fn list_bucket_inventory_configurations(
+        &self,
+        input: ListBucketInventoryConfigurationsRequest,
+    ) -> Result<
+        ListBucketInventoryConfigurationsOutput,
+        RusotoError<ListBucketInventoryConfigurationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_bucket_inventory_configurations(input))
+    }
+
+    /// Blocking version of [`S3::list_bucket_metrics_configurations`].
+    pub fn list_bucket_metrics_configurations(
+        &self,
+        input: ListBucketMetricsConfigurationsRequest,
+    ) -> Result<
+        ListBucketMetricsConfigurationsOutput,
+        RusotoError<ListBucketMetricsConfigurationsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_bucket_metrics_configurations(input))
+    }
+
+    /// Blocking version of [`S3::list_buckets`].
+    pub fn list_buckets(&self) -> Result<ListBucketsOutput, RusotoError<ListBucketsError>> {
+        self.runtime.block_on(self.client.list_buckets())
+    }
+
+    /// Blocking version of [`S3::list_multipart_uploads`].
+    pub fn list_multipart_uploads(
+        &self,
+        input: ListMultipartUploadsRequest,
+    ) -> Result<ListMultipartUploadsOutput, RusotoError<ListMultipartUploadsError>> {
+        self.runtime
+            .block_on(self.client.list_multipart_uploads(input))
+    }
+
+    /// Blocking version of [`S3::list_object_versions`].
+    pub fn list_object_versions(
+        &self,
+        input: ListObjectVersionsRequest,
+    ) -> Result<ListObjectVersionsOutput, RusotoError<ListObjectVersionsError>> {
+        self.runtime
+            .block_on(self.client.list_object_versions(input))
+    }
+
+    /// Blocking version of [`S3::list_objects`].
+    pub fn list_objects(
+        &self,
+        input: ListObjectsRequest,
+    ) -> Result<ListObjectsOutput, RusotoError<ListObjectsError>> {
+        self.runtime.block_on(self.client.list_objects(input))
+    }
+
+    /// Blocking version of [`S3::list_objects_v2`].
+    pub fn list_objects_v2(
+        &self,
+        input: ListObjectsV2Request,
+    ) -> Result<ListObjectsV2Output, RusotoError<ListObjectsV2Error>> {
+        self.runtime.block_on(self.client.list_objects_v2(input))
+    }
+
+    /// Blocking version of [`S3::list_parts`].
+    pub fn list_parts(
+        &self,
+        input: ListPartsRequest,
+    ) -> Result<ListPartsOutput, RusotoError<ListPartsError>> {
+        self.runtime.block_on(self.client.list_parts(input))
+    }
+
+    /// Blocking version of [`S3::put_bucket_accelerate_configuration`].
+    pub fn put_bucket_accelerate_configuration(
+        &self,
+        input: PutBucketAccelerateConfigurationRequest,
+    ) -> Result<(), RusotoError<PutBucketAccelerateConfigurationError>> {
+        self.runtime
+            .block_on(self.client.put_bucket_accelerate_configuration(input))
+    }
+
+    /// Blocking version of [`S3::put_bucket_acl`].
+    pub fn put_bucket_acl(
+        &self,
+        input: PutBucketAclRequest,
+    ) -> Result<(), RusotoError<PutBucketAclError>> {
+        self.runtime.block_on(self.client.put_bucket_acl(input))
+    }
+
+    /// Blocking version of [`S3::put_bucket_analytics_configuration`].
+    pub fn put_bucket_analytics_configuration(
+        &self,
+        input: PutBucketAnalyticsConfigurationRequest,
+    ) -> Result<(), RusotoError<PutBucketAnalyticsConfigurationError>> {
+        self.runtime
+            .block_on(self.client.put_bucket_analytics_configuration(input))
+    }
+
+    /// Blocking version of [`S3::put_bucket_cors`].
+    pub fn put_bucket_cors(
+        &self,
+        input: PutBucketCorsRequest,
+    ) -> Result<(), RusotoError<PutBucketCorsError>> {
+        self.runtime.block_on(self.client.put_bucket_cors(input))
+    }
+
+    /// Blocking version of [`S3::put_bucket_encryption`].
+    pub fn put_bucket_encryption(
+        &self,
+        input: PutBucketEncryptionRequest,
+    ) -> Result<(), RusotoError<PutBucketEncryptionError>> {
+        self.runtime
+            .block_on(self.client.put_bucket_encryption(input))
+    }
+
+    /// Blocking version of [`S3::put_bucket_intelligent_tiering_configuration`].
+    pub fn put_bucket_intelligent_tiering_configuration(
+        &self,
+        input: PutBucketIntelligentTieringConfigurationRequest,
+    ) -> Result<(), RusotoError<PutBucketIntelligentTieringConfigurationError>> {
+        self.runtime.block_on(
+            self.client
+                .put_bucket_intelligent_tiering_configuration(input),
+        )
+    }
+
+    /// Blocking version of [`S3::put_bucket_inventory_configuration`].
+    pub fn put_bucket_inventory_configuration(
+        &self,
+        input: PutBucketInventoryConfigurationRequest,
+    ) -> Result<(), RusotoError<PutBucketInventoryConfigurationError>> {
+        self.runtime
+            .block_on(self.client.put_bucket_inventory_configuration(input))
+    }
+
+    /// Blocking version of [`S3::put_bucket_lifecycle`].
+    pub fn put_bucket_lifecycle(
+        &self,
+        input: PutBucketLifecycleRequest,
+    ) -> Result<(), RusotoError<PutBucketLifecycleError>> {
+        self.runtime
+            .block_on(self.client.put_bucket_lifecycle(input))
+    }
+
+    /// Blocking version of [`S3::put_bucket_lifecycle_configuration`].
+    pub fn put_bucket_lifecycle_configuration(
+        &self,
+        input: PutBucketLifecycleConfigurationRequest,
+    ) -> Result<(), RusotoError<PutBucketLifecycleConfigurationError>> {
+        self.runtime
+            .block_on(self.client.put_bucket_lifecycle_configuration(input))
+    }
+
+    /// Blocking version of [`S3::put_bucket_logging`].
+    pub fn put_bucket_logging(
+        &self,
+        input: PutBucketLoggingRequest,
+    ) -> Result<(), RusotoError<PutBucketLoggingError>> {
+        self.runtime.block_on(self.client.put_bucket_logging(input))
+    }
+
+    /// Blocking version of [`S3::put_bucket_metrics_configuration`].
+    pub fn put_bucket_metrics_configuration(
+        &self,
+        input: PutBucketMetricsConfigurationRequest,
+    ) -> Result<(), RusotoError<PutBucketMetricsConfigurationError>> {
+        self.runtime
+            .block_on(self.client.put_bucket_metrics_configuration(input))
+    }
+
+    /// Blocking version of [`S3::put_bucket_notification`].
+    pub fn put_bucket_notification(
+        &self,
+        input: PutBucketNotificationRequest,
+    ) -> Result<(), RusotoError<PutBucketNotificationError>> {
+        self.runtime
+            .block_on(self.client.put_bucket_notification(input))
+    }
+
+    /// Blocking version of [`S3::put_bucket_notification_configuration`].
+    pub fn put_bucket_notification_configuration(
+        &self,
+        input: PutBucketNotificationConfigurationRequest,
+    ) -> Result<(), RusotoError<PutBucketNotificationConfigurationError>> {
+        self.runtime
+            .block_on(self.client.put_bucket_notification_configuration(input))
+    }
+
+    /// Blocking version of [`S3::put_bucket_ownership_controls`].
+    pub fn put_bucket_ownership_controls(
+        &self,
+        input: PutBucketOwnershipControlsRequest,
+    ) -> Result<(), RusotoError<PutBucketOwnershipControlsError>> {
+        self.runtime
+            .block_on(self.client.put_bucket_ownership_controls(input))
+    }
+
+    /// Blocking version of [`S3::put_bucket_policy`].
+    pub fn put_bucket_policy(
+        &self,
+        input: PutBucketPolicyRequest,
+    ) -> Result<(), RusotoError<PutBucketPolicyError>> {
+        self.runtime.block_on(self.client.put_bucket_policy(input))
+    }
+
+    /// Blocking version of [`S3::put_bucket_replication`].
+    pub fn put_bucket_replication(
+        &self,
+        input: PutBucketReplicationRequest,
+    ) -> Result<(), RusotoError<PutBucketReplicationError>> {
+        self.runtime
+            .block_on(self.client.put_bucket_replication(input))
+    }
+
+    /// Blocking version of [`S3::put_bucket_request_payment`].
+    pub fn put_bucket_request_payment(
+        &self,
+        input: PutBucketRequestPaymentRequest,
+    ) -> Result<(), RusotoError<PutBucketRequestPaymentError>> {
+        self.runtime
+            .block_on(self.client.put_bucket_request_payment(input))
+    }
+
+    /// Blocking version of [`S3::put_bucket_tagging`].
+    pub fn put_bucket_tagging(
+        &self,
+        input: PutBucketTaggingRequest,
+    ) -> Result<(), RusotoError<PutBucketTaggingError>> {
+        self.runtime.block_on(self.client.put_bucket_tagging(input))
+    }
+
+    /// Blocking version of [`S3::put_bucket_versioning`].
+    pub fn put_bucket_versioning(
+        &self,
+        input: PutBucketVersioningRequest,
+    ) -> Result<(), RusotoError<PutBucketVersioningError>> {
+        self.runtime
+            .block_on(self.client.put_bucket_versioning(input))
+    }
+
+    /// Blocking version of [`S3::put_bucket_website`].
+    pub fn put_bucket_website(
+        &self,
+        input: PutBucketWebsiteRequest,
+    ) -> Result<(), RusotoError<PutBucketWebsiteError>> {
+        self.runtime.block_on(self.client.put_bucket_website(input))
+    }
+
+    /// Blocking version of [`S3::put_object`].
+    pub fn put_object(
+        &self,
+        input: PutObjectRequest,
+    ) -> Result<PutObjectOutput, RusotoError<PutObjectError>> {
+        self.runtime.block_on(self.client.put_object(input))
+    }
+
+    /// Blocking version of [`S3::put_object_acl`].
+    pub fn put_object_acl(
+        &self,
+        input: PutObjectAclRequest,
+    ) -> Result<PutObjectAclOutput, RusotoError<PutObjectAclError>> {
+        self.runtime.block_on(self.client.put_object_acl(input))
+    }
+
+    /// Blocking version of [`S3::put_object_legal_hold`].
+    pub fn put_object_legal_hold(
+        &self,
+        input: PutObjectLegalHoldRequest,
+    ) -> Result<PutObjectLegalHoldOutput, RusotoError<PutObjectLegalHoldError>> {
+        self.runtime
+            .block_on(self.client.put_object_legal_hold(input))
+    }
+
+    /// Blocking version of [`S3::put_object_lock_configuration`].
+    pub fn put_object_lock_configuration(
+        &self,
+        input: PutObjectLockConfigurationRequest,
+    ) -> Result<PutObjectLockConfigurationOutput, RusotoError<PutObjectLockConfigurationError>>
+    {
+        self.runtime
+            .block_on(self.client.put_object_lock_configuration(input))
+    }
+
+    /// Blocking version of [`S3::put_object_retention`].
+    pub fn put_object_retention(
+        &self,
+        input: PutObjectRetentionRequest,
+    ) -> Result<PutObjectRetentionOutput, RusotoError<PutObjectRetentionError>> {
+        self.runtime
+            .block_on(self.client.put_object_retention(input))
+    }
+
+    /// Blocking version of [`S3::put_object_tagging`].
+    pub fn put_object_tagging(
+        &self,
+        input: PutObjectTaggingRequest,
+    ) -> Result<PutObjectTaggingOutput, RusotoError<PutObjectTaggingError>> {
+        self.runtime.block_on(self.client.put_object_tagging(input))
+    }
+
+    /// Blocking version of [`S3::put_public_access_block`].
+    pub fn put_public_access_block(
+        &self,
+        input: PutPublicAccessBlockRequest,
+    ) -> Result<(), RusotoError<PutPublicAccessBlockError>> {
+        self.runtime
+            .block_on(self.client.put_public_access_block(input))
+    }
+
+    /// Blocking version of [`S3::restore_object`].
+    pub fn restore_object(
+        &self,
+        input: RestoreObjectRequest,
+    ) -> Result<RestoreObjectOutput, RusotoError<RestoreObjectError>> {
+        self.runtime.block_on(self.client.restore_object(input))
+    }
+
+    /// Blocking version of [`S3::select_object_content`].
+    pub fn select_object_content(
+        &self,
+        input: SelectObjectContentRequest,
+    ) -> Result<SelectObjectContentOutput, RusotoError<SelectObjectContentError>> {
+        self.runtime
+            .block_on(self.client.select_object_content(input))
+    }
+
+    /// Blocking version of [`S3::upload_part`].
+    pub fn upload_part(
+        &self,
+        input: UploadPartRequest,
+    ) -> Result<UploadPartOutput, RusotoError<UploadPartError>> {
+        self.runtime.block_on(self.client.upload_part(input))
+    }
+
+    /// Blocking version of [`S3::upload_part_copy`].
+    pub fn upload_part_copy(
+        &self,
+        input: UploadPartCopyRequest,
+    ) -> Result<UploadPartCopyOutput, RusotoError<UploadPartCopyError>> {
+        self.runtime.block_on(self.client.upload_part_copy(input))
+    }
+}
+
 #[cfg(test)]
 mod protocol_tests {
 
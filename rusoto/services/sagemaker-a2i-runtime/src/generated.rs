@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::param::{Params, ServiceParams};
 use rusoto_core::proto;
@@ -225,15 +226,30 @@ impl DeleteHumanLoopError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DeleteHumanLoopError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteHumanLoopError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DeleteHumanLoopError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteHumanLoopError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DeleteHumanLoopError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteHumanLoopError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -267,15 +283,30 @@ impl DescribeHumanLoopError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(DescribeHumanLoopError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeHumanLoopError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(DescribeHumanLoopError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeHumanLoopError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(DescribeHumanLoopError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeHumanLoopError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -309,15 +340,30 @@ impl ListHumanLoopsError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(ListHumanLoopsError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListHumanLoopsError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(ListHumanLoopsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListHumanLoopsError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(ListHumanLoopsError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListHumanLoopsError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -353,18 +399,38 @@ impl StartHumanLoopError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(StartHumanLoopError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartHumanLoopError::Conflict,
+                    )
                 }
                 "InternalServerException" => {
-                    return RusotoError::Service(StartHumanLoopError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartHumanLoopError::InternalServer,
+                    )
                 }
                 "ServiceQuotaExceededException" => {
-                    return RusotoError::Service(StartHumanLoopError::ServiceQuotaExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartHumanLoopError::ServiceQuotaExceeded,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StartHumanLoopError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartHumanLoopError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -399,15 +465,30 @@ impl StopHumanLoopError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalServerException" => {
-                    return RusotoError::Service(StopHumanLoopError::InternalServer(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopHumanLoopError::InternalServer,
+                    )
                 }
                 "ResourceNotFoundException" => {
-                    return RusotoError::Service(StopHumanLoopError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopHumanLoopError::ResourceNotFound,
+                    )
                 }
                 "ThrottlingException" => {
-                    return RusotoError::Service(StopHumanLoopError::Throttling(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopHumanLoopError::Throttling,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -468,11 +549,12 @@ pub struct SagemakerA2iRuntimeClient {
 impl SagemakerA2iRuntimeClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> SagemakerA2iRuntimeClient {
         SagemakerA2iRuntimeClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "SageMaker A2I Runtime"),
         }
     }
 
@@ -487,12 +569,32 @@ impl SagemakerA2iRuntimeClient {
     {
         SagemakerA2iRuntimeClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "SageMaker A2I Runtime"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> SagemakerA2iRuntimeClient {
-        SagemakerA2iRuntimeClient { client, region }
+        SagemakerA2iRuntimeClient {
+            client,
+            region: region_for_service(region, "SageMaker A2I Runtime"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> SagemakerA2iRuntimeClient {
+        SagemakerA2iRuntimeClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("SageMaker A2I Runtime"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> SagemakerA2iRuntimeClient {
+        SagemakerA2iRuntimeClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -510,6 +612,7 @@ impl SagemakerA2iRuntime for SagemakerA2iRuntimeClient {
         );
 
         let mut request = SignedRequest::new("DELETE", "sagemaker", &self.region, &request_uri);
+        request.set_operation("DeleteHumanLoop");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("a2i-runtime.sagemaker".to_string());
@@ -543,6 +646,7 @@ impl SagemakerA2iRuntime for SagemakerA2iRuntimeClient {
         );
 
         let mut request = SignedRequest::new("GET", "sagemaker", &self.region, &request_uri);
+        request.set_operation("DescribeHumanLoop");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("a2i-runtime.sagemaker".to_string());
@@ -573,6 +677,7 @@ impl SagemakerA2iRuntime for SagemakerA2iRuntimeClient {
         let request_uri = "/human-loops";
 
         let mut request = SignedRequest::new("GET", "sagemaker", &self.region, &request_uri);
+        request.set_operation("ListHumanLoops");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("a2i-runtime.sagemaker".to_string());
@@ -622,6 +727,7 @@ impl SagemakerA2iRuntime for SagemakerA2iRuntimeClient {
         let request_uri = "/human-loops";
 
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, &request_uri);
+        request.set_operation("StartHumanLoop");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("a2i-runtime.sagemaker".to_string());
@@ -654,6 +760,7 @@ impl SagemakerA2iRuntime for SagemakerA2iRuntimeClient {
         let request_uri = "/human-loops/stop";
 
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, &request_uri);
+        request.set_operation("StopHumanLoop");
         request.set_content_type("application/x-amz-json-1.1".to_owned());
 
         request.set_endpoint_prefix("a2i-runtime.sagemaker".to_string());
@@ -677,3 +784,129 @@ impl SagemakerA2iRuntime for SagemakerA2iRuntimeClient {
         }
     }
 }
+
+/// A blocking client for the Amazon Augmented AI Runtime API, running the calls of a `SagemakerA2iRuntimeClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `SagemakerA2iRuntimeClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct SagemakerA2iRuntimeBlockingClient {
+    client: SagemakerA2iRuntimeClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl SagemakerA2iRuntimeBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> SagemakerA2iRuntimeBlockingClient {
+        SagemakerA2iRuntimeBlockingClient::from_client(SagemakerA2iRuntimeClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> SagemakerA2iRuntimeBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        SagemakerA2iRuntimeBlockingClient::from_client(SagemakerA2iRuntimeClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> SagemakerA2iRuntimeBlockingClient {
+        SagemakerA2iRuntimeBlockingClient::from_client(SagemakerA2iRuntimeClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> SagemakerA2iRuntimeBlockingClient {
+        SagemakerA2iRuntimeBlockingClient::from_client(SagemakerA2iRuntimeClient::new_with_config(
+            config,
+        ))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: SagemakerA2iRuntimeClient) -> SagemakerA2iRuntimeBlockingClient {
+        SagemakerA2iRuntimeBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> SagemakerA2iRuntimeBlockingClient {
+        SagemakerA2iRuntimeBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> SagemakerA2iRuntimeBlockingClient {
+        SagemakerA2iRuntimeBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`SagemakerA2iRuntime::delete_human_loop`].
+    pub fn delete_human_loop(
+        &self,
+        input: DeleteHumanLoopRequest,
+    ) -> Result<DeleteHumanLoopResponse, RusotoError<DeleteHumanLoopError>> {
+        self.runtime.block_on(self.client.delete_human_loop(input))
+    }
+
+    /// Blocking version of [`SagemakerA2iRuntime::describe_human_loop`].
+    pub fn describe_human_loop(
+        &self,
+        input: DescribeHumanLoopRequest,
+    ) -> Result<DescribeHumanLoopResponse, RusotoError<DescribeHumanLoopError>> {
+        self.runtime
+            .block_on(self.client.describe_human_loop(input))
+    }
+
+    /// Blocking version of [`SagemakerA2iRuntime::list_human_loops`].
+    pub fn list_human_loops(
+        &self,
+        input: ListHumanLoopsRequest,
+    ) -> Result<ListHumanLoopsResponse, RusotoError<ListHumanLoopsError>> {
+        self.runtime.block_on(self.client.list_human_loops(input))
+    }
+
+    /// Blocking version of [`SagemakerA2iRuntime::start_human_loop`].
+    pub fn start_human_loop(
+        &self,
+        input: StartHumanLoopRequest,
+    ) -> Result<StartHumanLoopResponse, RusotoError<StartHumanLoopError>> {
+        self.runtime.block_on(self.client.start_human_loop(input))
+    }
+
+    /// Blocking version of [`SagemakerA2iRuntime::stop_human_loop`].
+    pub fn stop_human_loop(
+        &self,
+        input: StopHumanLoopRequest,
+    ) -> Result<StopHumanLoopResponse, RusotoError<StopHumanLoopError>> {
+        self.runtime.block_on(self.client.stop_human_loop(input))
+    }
+}
@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::signature::SignedRequest;
@@ -93,18 +94,38 @@ impl InvokeEndpointError {
         if let Some(err) = proto::json::Error::parse_rest(&res) {
             match err.typ.as_str() {
                 "InternalFailure" => {
-                    return RusotoError::Service(InvokeEndpointError::InternalFailure(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InvokeEndpointError::InternalFailure,
+                    )
                 }
                 "ModelError" => {
-                    return RusotoError::Service(InvokeEndpointError::ModelError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InvokeEndpointError::ModelError,
+                    )
                 }
                 "ServiceUnavailable" => {
-                    return RusotoError::Service(InvokeEndpointError::ServiceUnavailable(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InvokeEndpointError::ServiceUnavailable,
+                    )
                 }
                 "ValidationError" => {
-                    return RusotoError::Service(InvokeEndpointError::ValidationError(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        InvokeEndpointError::ValidationError,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -142,11 +163,12 @@ pub struct SageMakerRuntimeClient {
 impl SageMakerRuntimeClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> SageMakerRuntimeClient {
         SageMakerRuntimeClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "SageMaker Runtime"),
         }
     }
 
@@ -161,12 +183,32 @@ impl SageMakerRuntimeClient {
     {
         SageMakerRuntimeClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "SageMaker Runtime"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> SageMakerRuntimeClient {
-        SageMakerRuntimeClient { client, region }
+        SageMakerRuntimeClient {
+            client,
+            region: region_for_service(region, "SageMaker Runtime"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> SageMakerRuntimeClient {
+        SageMakerRuntimeClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("SageMaker Runtime"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> SageMakerRuntimeClient {
+        SageMakerRuntimeClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -184,6 +226,8 @@ impl SageMakerRuntime for SageMakerRuntimeClient {
         );
 
         let mut request = SignedRequest::new("POST", "sagemaker", &self.region, &request_uri);
+        request.set_operation("InvokeEndpoint");
+        request.set_raw_response(true);
         if input.content_type.is_none() {
             request.set_content_type("application/x-amz-json-1.1".to_owned());
         }
@@ -229,3 +273,94 @@ impl SageMakerRuntime for SageMakerRuntimeClient {
         }
     }
 }
+
+/// A blocking client for the Amazon SageMaker Runtime API, running the calls of a `SageMakerRuntimeClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `SageMakerRuntimeClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct SageMakerRuntimeBlockingClient {
+    client: SageMakerRuntimeClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl SageMakerRuntimeBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> SageMakerRuntimeBlockingClient {
+        SageMakerRuntimeBlockingClient::from_client(SageMakerRuntimeClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> SageMakerRuntimeBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        SageMakerRuntimeBlockingClient::from_client(SageMakerRuntimeClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(
+        client: Client,
+        region: region::Region,
+    ) -> SageMakerRuntimeBlockingClient {
+        SageMakerRuntimeBlockingClient::from_client(SageMakerRuntimeClient::new_with_client(
+            client, region,
+        ))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> SageMakerRuntimeBlockingClient {
+        SageMakerRuntimeBlockingClient::from_client(SageMakerRuntimeClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: SageMakerRuntimeClient) -> SageMakerRuntimeBlockingClient {
+        SageMakerRuntimeBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(
+        &self,
+        runtime: rusoto_core::BlockingRuntime,
+    ) -> SageMakerRuntimeBlockingClient {
+        SageMakerRuntimeBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> SageMakerRuntimeBlockingClient {
+        SageMakerRuntimeBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`SageMakerRuntime::invoke_endpoint`].
+    pub fn invoke_endpoint(
+        &self,
+        input: InvokeEndpointInput,
+    ) -> Result<InvokeEndpointOutput, RusotoError<InvokeEndpointError>> {
+        self.runtime.block_on(self.client.invoke_endpoint(input))
+    }
+}
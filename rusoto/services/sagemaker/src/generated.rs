@@ -14,10 +14,11 @@ use std::error::Error;
 use std::fmt;
 
 use async_trait::async_trait;
+use rusoto_core::config::region_for_service;
 use rusoto_core::credential::ProvideAwsCredentials;
 use rusoto_core::region;
 use rusoto_core::request::{BufferedHttpResponse, DispatchSignedRequest};
-use rusoto_core::{Client, RusotoError};
+use rusoto_core::{AwsConfig, CallOptions, Client, RusotoError};
 
 use rusoto_core::proto;
 use rusoto_core::request::HttpResponse;
@@ -14557,14 +14558,22 @@ impl AddAssociationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(AddAssociationError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AddAssociationError::ResourceLimitExceeded,
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(AddAssociationError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AddAssociationError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14589,7 +14598,7 @@ impl AddTagsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<AddTagsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14617,16 +14626,22 @@ impl AssociateTrialComponentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(
-                        AssociateTrialComponentError::ResourceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        AssociateTrialComponentError::ResourceLimitExceeded,
                     )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(AssociateTrialComponentError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        AssociateTrialComponentError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14657,9 +14672,14 @@ impl CreateActionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateActionError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateActionError::ResourceLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14683,7 +14703,7 @@ impl CreateAlgorithmError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CreateAlgorithmError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14711,12 +14731,22 @@ impl CreateAppError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateAppError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAppError::ResourceInUse,
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateAppError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAppError::ResourceLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14745,9 +14775,14 @@ impl CreateAppImageConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateAppImageConfigError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAppImageConfigError::ResourceInUse,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14775,11 +14810,14 @@ impl CreateArtifactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateArtifactError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateArtifactError::ResourceLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14809,14 +14847,22 @@ impl CreateAutoMLJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateAutoMLJobError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateAutoMLJobError::ResourceInUse,
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateAutoMLJobError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateAutoMLJobError::ResourceLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14841,7 +14887,7 @@ impl CreateCodeRepositoryError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CreateCodeRepositoryError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14869,14 +14915,22 @@ impl CreateCompilationJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateCompilationJobError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateCompilationJobError::ResourceInUse,
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateCompilationJobError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateCompilationJobError::ResourceLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14905,9 +14959,14 @@ impl CreateContextError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateContextError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateContextError::ResourceLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14939,16 +14998,22 @@ impl CreateDataQualityJobDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(
-                        CreateDataQualityJobDefinitionError::ResourceInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataQualityJobDefinitionError::ResourceInUse,
                     )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(
-                        CreateDataQualityJobDefinitionError::ResourceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDataQualityJobDefinitionError::ResourceLimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -14981,14 +15046,22 @@ impl CreateDeviceFleetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateDeviceFleetError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDeviceFleetError::ResourceInUse,
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateDeviceFleetError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateDeviceFleetError::ResourceLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15019,12 +15092,22 @@ impl CreateDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateDomainError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDomainError::ResourceInUse,
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateDomainError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateDomainError::ResourceLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15053,11 +15136,14 @@ impl CreateEdgePackagingJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(
-                        CreateEdgePackagingJobError::ResourceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateEdgePackagingJobError::ResourceLimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15085,11 +15171,14 @@ impl CreateEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateEndpointError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateEndpointError::ResourceLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15117,11 +15206,14 @@ impl CreateEndpointConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateEndpointConfigError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateEndpointConfigError::ResourceLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15149,11 +15241,14 @@ impl CreateExperimentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateExperimentError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateExperimentError::ResourceLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15183,14 +15278,22 @@ impl CreateFeatureGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateFeatureGroupError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFeatureGroupError::ResourceInUse,
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateFeatureGroupError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateFeatureGroupError::ResourceLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15221,14 +15324,22 @@ impl CreateFlowDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateFlowDefinitionError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateFlowDefinitionError::ResourceInUse,
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateFlowDefinitionError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateFlowDefinitionError::ResourceLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15259,14 +15370,22 @@ impl CreateHumanTaskUiError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateHumanTaskUiError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateHumanTaskUiError::ResourceInUse,
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateHumanTaskUiError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateHumanTaskUiError::ResourceLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15299,16 +15418,22 @@ impl CreateHyperParameterTuningJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateHyperParameterTuningJobError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateHyperParameterTuningJobError::ResourceInUse,
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(
-                        CreateHyperParameterTuningJobError::ResourceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateHyperParameterTuningJobError::ResourceLimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15341,12 +15466,22 @@ impl CreateImageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateImageError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateImageError::ResourceInUse,
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateImageError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateImageError::ResourceLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15379,17 +15514,30 @@ impl CreateImageVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateImageVersionError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateImageVersionError::ResourceInUse,
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateImageVersionError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateImageVersionError::ResourceLimitExceeded,
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(CreateImageVersionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateImageVersionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15421,14 +15569,22 @@ impl CreateLabelingJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateLabelingJobError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateLabelingJobError::ResourceInUse,
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateLabelingJobError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateLabelingJobError::ResourceLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15457,9 +15613,14 @@ impl CreateModelError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateModelError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateModelError::ResourceLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15491,16 +15652,22 @@ impl CreateModelBiasJobDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateModelBiasJobDefinitionError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateModelBiasJobDefinitionError::ResourceInUse,
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(
-                        CreateModelBiasJobDefinitionError::ResourceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateModelBiasJobDefinitionError::ResourceLimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15535,16 +15702,22 @@ impl CreateModelExplainabilityJobDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(
-                        CreateModelExplainabilityJobDefinitionError::ResourceInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateModelExplainabilityJobDefinitionError::ResourceInUse,
                     )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(
-                        CreateModelExplainabilityJobDefinitionError::ResourceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateModelExplainabilityJobDefinitionError::ResourceLimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15579,14 +15752,22 @@ impl CreateModelPackageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(CreateModelPackageError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateModelPackageError::Conflict,
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateModelPackageError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateModelPackageError::ResourceLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15615,11 +15796,14 @@ impl CreateModelPackageGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(
-                        CreateModelPackageGroupError::ResourceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateModelPackageGroupError::ResourceLimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15653,16 +15837,22 @@ impl CreateModelQualityJobDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(
-                        CreateModelQualityJobDefinitionError::ResourceInUse(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateModelQualityJobDefinitionError::ResourceInUse,
                     )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(
-                        CreateModelQualityJobDefinitionError::ResourceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateModelQualityJobDefinitionError::ResourceLimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15697,16 +15887,22 @@ impl CreateMonitoringScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateMonitoringScheduleError::ResourceInUse(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateMonitoringScheduleError::ResourceInUse,
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(
-                        CreateMonitoringScheduleError::ResourceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateMonitoringScheduleError::ResourceLimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15737,11 +15933,14 @@ impl CreateNotebookInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(
-                        CreateNotebookInstanceError::ResourceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNotebookInstanceError::ResourceLimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15771,11 +15970,14 @@ impl CreateNotebookInstanceLifecycleConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(
-                        CreateNotebookInstanceLifecycleConfigError::ResourceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateNotebookInstanceLifecycleConfigError::ResourceLimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15807,14 +16009,22 @@ impl CreatePipelineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreatePipelineError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePipelineError::ResourceLimitExceeded,
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(CreatePipelineError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreatePipelineError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15843,11 +16053,14 @@ impl CreatePresignedDomainUrlError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(CreatePresignedDomainUrlError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreatePresignedDomainUrlError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15873,7 +16086,7 @@ impl CreatePresignedNotebookInstanceUrlError {
     ) -> RusotoError<CreatePresignedNotebookInstanceUrlError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15903,19 +16116,30 @@ impl CreateProcessingJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateProcessingJobError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProcessingJobError::ResourceInUse,
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateProcessingJobError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateProcessingJobError::ResourceLimitExceeded,
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(CreateProcessingJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateProcessingJobError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15945,9 +16169,14 @@ impl CreateProjectError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateProjectError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateProjectError::ResourceLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -15979,17 +16208,30 @@ impl CreateTrainingJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateTrainingJobError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTrainingJobError::ResourceInUse,
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateTrainingJobError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateTrainingJobError::ResourceLimitExceeded,
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(CreateTrainingJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTrainingJobError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16023,17 +16265,30 @@ impl CreateTransformJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateTransformJobError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTransformJobError::ResourceInUse,
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateTransformJobError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateTransformJobError::ResourceLimitExceeded,
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(CreateTransformJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTransformJobError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16065,12 +16320,22 @@ impl CreateTrialError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateTrialError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTrialError::ResourceLimitExceeded,
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(CreateTrialError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateTrialError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16099,11 +16364,14 @@ impl CreateTrialComponentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateTrialComponentError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateTrialComponentError::ResourceLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16133,14 +16401,22 @@ impl CreateUserProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateUserProfileError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateUserProfileError::ResourceInUse,
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateUserProfileError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateUserProfileError::ResourceLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16165,7 +16441,7 @@ impl CreateWorkforceError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<CreateWorkforceError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16193,14 +16469,22 @@ impl CreateWorkteamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(CreateWorkteamError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        CreateWorkteamError::ResourceInUse,
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(CreateWorkteamError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        CreateWorkteamError::ResourceLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16229,9 +16513,14 @@ impl DeleteActionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DeleteActionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteActionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16255,7 +16544,7 @@ impl DeleteAlgorithmError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteAlgorithmError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16283,12 +16572,22 @@ impl DeleteAppError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(DeleteAppError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DeleteAppError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAppError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16317,11 +16616,14 @@ impl DeleteAppImageConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DeleteAppImageConfigError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteAppImageConfigError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16349,9 +16651,14 @@ impl DeleteArtifactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DeleteArtifactError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteArtifactError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16379,9 +16686,14 @@ impl DeleteAssociationError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DeleteAssociationError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteAssociationError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16405,7 +16717,7 @@ impl DeleteCodeRepositoryError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteCodeRepositoryError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16431,9 +16743,14 @@ impl DeleteContextError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DeleteContextError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteContextError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16463,11 +16780,14 @@ impl DeleteDataQualityJobDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(
-                        DeleteDataQualityJobDefinitionError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDataQualityJobDefinitionError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16497,9 +16817,14 @@ impl DeleteDeviceFleetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(DeleteDeviceFleetError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDeviceFleetError::ResourceInUse,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16529,12 +16854,22 @@ impl DeleteDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(DeleteDomainError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDomainError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DeleteDomainError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteDomainError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16559,7 +16894,7 @@ impl DeleteEndpointError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteEndpointError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16581,7 +16916,7 @@ impl DeleteEndpointConfigError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteEndpointConfigError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16607,9 +16942,14 @@ impl DeleteExperimentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DeleteExperimentError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteExperimentError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16637,9 +16977,14 @@ impl DeleteFeatureGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DeleteFeatureGroupError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFeatureGroupError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16669,14 +17014,22 @@ impl DeleteFlowDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(DeleteFlowDefinitionError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteFlowDefinitionError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DeleteFlowDefinitionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteFlowDefinitionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16705,9 +17058,14 @@ impl DeleteHumanTaskUiError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DeleteHumanTaskUiError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteHumanTaskUiError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16737,12 +17095,22 @@ impl DeleteImageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(DeleteImageError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteImageError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DeleteImageError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteImageError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16773,12 +17141,22 @@ impl DeleteImageVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(DeleteImageVersionError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteImageVersionError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DeleteImageVersionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteImageVersionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16803,7 +17181,7 @@ impl DeleteModelError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteModelError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16831,11 +17209,14 @@ impl DeleteModelBiasJobDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(
-                        DeleteModelBiasJobDefinitionError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteModelBiasJobDefinitionError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16867,11 +17248,14 @@ impl DeleteModelExplainabilityJobDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(
-                        DeleteModelExplainabilityJobDefinitionError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteModelExplainabilityJobDefinitionError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16901,9 +17285,14 @@ impl DeleteModelPackageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(DeleteModelPackageError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteModelPackageError::Conflict,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16927,7 +17316,7 @@ impl DeleteModelPackageGroupError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteModelPackageGroupError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16951,7 +17340,7 @@ impl DeleteModelPackageGroupPolicyError {
     ) -> RusotoError<DeleteModelPackageGroupPolicyError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -16979,11 +17368,14 @@ impl DeleteModelQualityJobDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(
-                        DeleteModelQualityJobDefinitionError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteModelQualityJobDefinitionError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17013,11 +17405,14 @@ impl DeleteMonitoringScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DeleteMonitoringScheduleError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteMonitoringScheduleError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17041,7 +17436,7 @@ impl DeleteNotebookInstanceError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteNotebookInstanceError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17065,7 +17460,7 @@ impl DeleteNotebookInstanceLifecycleConfigError {
     ) -> RusotoError<DeleteNotebookInstanceLifecycleConfigError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17091,9 +17486,14 @@ impl DeletePipelineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DeletePipelineError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeletePipelineError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17117,7 +17517,7 @@ impl DeleteProjectError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteProjectError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17139,7 +17539,7 @@ impl DeleteTagsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteTagsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17165,9 +17565,14 @@ impl DeleteTrialError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DeleteTrialError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteTrialError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17195,11 +17600,14 @@ impl DeleteTrialComponentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DeleteTrialComponentError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteTrialComponentError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17229,12 +17637,22 @@ impl DeleteUserProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(DeleteUserProfileError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserProfileError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DeleteUserProfileError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DeleteUserProfileError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17259,7 +17677,7 @@ impl DeleteWorkforceError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeleteWorkforceError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17285,11 +17703,14 @@ impl DeleteWorkteamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(DeleteWorkteamError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DeleteWorkteamError::ResourceLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17313,7 +17734,7 @@ impl DeregisterDevicesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DeregisterDevicesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17339,9 +17760,14 @@ impl DescribeActionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeActionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeActionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17365,7 +17791,7 @@ impl DescribeAlgorithmError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeAlgorithmError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17391,9 +17817,14 @@ impl DescribeAppError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeAppError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAppError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17421,11 +17852,14 @@ impl DescribeAppImageConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeAppImageConfigError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeAppImageConfigError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17453,9 +17887,14 @@ impl DescribeArtifactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeArtifactError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeArtifactError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17483,9 +17922,14 @@ impl DescribeAutoMLJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeAutoMLJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeAutoMLJobError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17509,7 +17953,7 @@ impl DescribeCodeRepositoryError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeCodeRepositoryError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17535,11 +17979,14 @@ impl DescribeCompilationJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeCompilationJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeCompilationJobError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17567,9 +18014,14 @@ impl DescribeContextError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeContextError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeContextError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17599,11 +18051,14 @@ impl DescribeDataQualityJobDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(
-                        DescribeDataQualityJobDefinitionError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDataQualityJobDefinitionError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17633,9 +18088,14 @@ impl DescribeDeviceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeDeviceError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDeviceError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17663,11 +18123,14 @@ impl DescribeDeviceFleetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeDeviceFleetError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeDeviceFleetError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17695,9 +18158,14 @@ impl DescribeDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeDomainError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeDomainError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17725,11 +18193,14 @@ impl DescribeEdgePackagingJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeEdgePackagingJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeEdgePackagingJobError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17753,7 +18224,7 @@ impl DescribeEndpointError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeEndpointError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17775,7 +18246,7 @@ impl DescribeEndpointConfigError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeEndpointConfigError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17801,9 +18272,14 @@ impl DescribeExperimentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeExperimentError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeExperimentError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17831,11 +18307,14 @@ impl DescribeFeatureGroupError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeFeatureGroupError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeFeatureGroupError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17863,11 +18342,14 @@ impl DescribeFlowDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeFlowDefinitionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeFlowDefinitionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17895,11 +18377,14 @@ impl DescribeHumanTaskUiError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeHumanTaskUiError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeHumanTaskUiError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17929,11 +18414,14 @@ impl DescribeHyperParameterTuningJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(
-                        DescribeHyperParameterTuningJobError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeHyperParameterTuningJobError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17963,9 +18451,14 @@ impl DescribeImageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeImageError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeImageError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -17993,11 +18486,14 @@ impl DescribeImageVersionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeImageVersionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeImageVersionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18025,11 +18521,14 @@ impl DescribeLabelingJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeLabelingJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeLabelingJobError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18053,7 +18552,7 @@ impl DescribeModelError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeModelError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18081,11 +18580,14 @@ impl DescribeModelBiasJobDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(
-                        DescribeModelBiasJobDefinitionError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeModelBiasJobDefinitionError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18117,11 +18619,14 @@ impl DescribeModelExplainabilityJobDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(
-                        DescribeModelExplainabilityJobDefinitionError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeModelExplainabilityJobDefinitionError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18147,7 +18652,7 @@ impl DescribeModelPackageError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeModelPackageError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18169,7 +18674,7 @@ impl DescribeModelPackageGroupError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeModelPackageGroupError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18197,11 +18702,14 @@ impl DescribeModelQualityJobDefinitionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(
-                        DescribeModelQualityJobDefinitionError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeModelQualityJobDefinitionError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18233,11 +18741,14 @@ impl DescribeMonitoringScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeMonitoringScheduleError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeMonitoringScheduleError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18261,7 +18772,7 @@ impl DescribeNotebookInstanceError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeNotebookInstanceError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18285,7 +18796,7 @@ impl DescribeNotebookInstanceLifecycleConfigError {
     ) -> RusotoError<DescribeNotebookInstanceLifecycleConfigError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18311,9 +18822,14 @@ impl DescribePipelineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribePipelineError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePipelineError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18343,11 +18859,14 @@ impl DescribePipelineDefinitionForExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(
-                        DescribePipelineDefinitionForExecutionError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribePipelineDefinitionForExecutionError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18377,11 +18896,14 @@ impl DescribePipelineExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribePipelineExecutionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribePipelineExecutionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18409,11 +18931,14 @@ impl DescribeProcessingJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeProcessingJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeProcessingJobError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18437,7 +18962,7 @@ impl DescribeProjectError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeProjectError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18461,7 +18986,7 @@ impl DescribeSubscribedWorkteamError {
     ) -> RusotoError<DescribeSubscribedWorkteamError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18487,11 +19012,14 @@ impl DescribeTrainingJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeTrainingJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeTrainingJobError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18519,11 +19047,14 @@ impl DescribeTransformJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeTransformJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeTransformJobError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18551,9 +19082,14 @@ impl DescribeTrialError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeTrialError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        DescribeTrialError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18581,11 +19117,14 @@ impl DescribeTrialComponentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeTrialComponentError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeTrialComponentError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18613,11 +19152,14 @@ impl DescribeUserProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DescribeUserProfileError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DescribeUserProfileError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18641,7 +19183,7 @@ impl DescribeWorkforceError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeWorkforceError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18663,7 +19205,7 @@ impl DescribeWorkteamError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<DescribeWorkteamError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18687,7 +19229,7 @@ impl DisableSagemakerServicecatalogPortfolioError {
     ) -> RusotoError<DisableSagemakerServicecatalogPortfolioError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18715,11 +19257,14 @@ impl DisassociateTrialComponentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(DisassociateTrialComponentError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        DisassociateTrialComponentError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18745,7 +19290,7 @@ impl EnableSagemakerServicecatalogPortfolioError {
     ) -> RusotoError<EnableSagemakerServicecatalogPortfolioError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18767,7 +19312,7 @@ impl GetDeviceFleetReportError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetDeviceFleetReportError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18791,7 +19336,7 @@ impl GetModelPackageGroupPolicyError {
     ) -> RusotoError<GetModelPackageGroupPolicyError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18815,7 +19360,7 @@ impl GetSagemakerServicecatalogPortfolioStatusError {
     ) -> RusotoError<GetSagemakerServicecatalogPortfolioStatusError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18837,7 +19382,7 @@ impl GetSearchSuggestionsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<GetSearchSuggestionsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18863,9 +19408,14 @@ impl ListActionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(ListActionsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListActionsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18889,7 +19439,7 @@ impl ListAlgorithmsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListAlgorithmsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18911,7 +19461,7 @@ impl ListAppImageConfigsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListAppImageConfigsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18933,7 +19483,7 @@ impl ListAppsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListAppsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18959,9 +19509,14 @@ impl ListArtifactsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(ListArtifactsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListArtifactsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -18989,9 +19544,14 @@ impl ListAssociationsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(ListAssociationsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListAssociationsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19015,7 +19575,7 @@ impl ListAutoMLJobsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListAutoMLJobsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19043,11 +19603,14 @@ impl ListCandidatesForAutoMLJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(ListCandidatesForAutoMLJobError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListCandidatesForAutoMLJobError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19071,7 +19634,7 @@ impl ListCodeRepositoriesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListCodeRepositoriesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19093,7 +19656,7 @@ impl ListCompilationJobsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListCompilationJobsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19119,9 +19682,14 @@ impl ListContextsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(ListContextsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListContextsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19147,7 +19715,7 @@ impl ListDataQualityJobDefinitionsError {
     ) -> RusotoError<ListDataQualityJobDefinitionsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19169,7 +19737,7 @@ impl ListDeviceFleetsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListDeviceFleetsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19191,7 +19759,7 @@ impl ListDevicesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListDevicesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19213,7 +19781,7 @@ impl ListDomainsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListDomainsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19235,7 +19803,7 @@ impl ListEdgePackagingJobsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListEdgePackagingJobsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19257,7 +19825,7 @@ impl ListEndpointConfigsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListEndpointConfigsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19279,7 +19847,7 @@ impl ListEndpointsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListEndpointsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19301,7 +19869,7 @@ impl ListExperimentsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListExperimentsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19323,7 +19891,7 @@ impl ListFeatureGroupsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListFeatureGroupsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19345,7 +19913,7 @@ impl ListFlowDefinitionsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListFlowDefinitionsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19367,7 +19935,7 @@ impl ListHumanTaskUisError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListHumanTaskUisError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19391,7 +19959,7 @@ impl ListHyperParameterTuningJobsError {
     ) -> RusotoError<ListHyperParameterTuningJobsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19417,9 +19985,14 @@ impl ListImageVersionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(ListImageVersionsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListImageVersionsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19443,7 +20016,7 @@ impl ListImagesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListImagesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19465,7 +20038,7 @@ impl ListLabelingJobsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListLabelingJobsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19493,11 +20066,14 @@ impl ListLabelingJobsForWorkteamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(
-                        ListLabelingJobsForWorkteamError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListLabelingJobsForWorkteamError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19523,7 +20099,7 @@ impl ListModelBiasJobDefinitionsError {
     ) -> RusotoError<ListModelBiasJobDefinitionsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19547,7 +20123,7 @@ impl ListModelExplainabilityJobDefinitionsError {
     ) -> RusotoError<ListModelExplainabilityJobDefinitionsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19569,7 +20145,7 @@ impl ListModelPackageGroupsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListModelPackageGroupsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19591,7 +20167,7 @@ impl ListModelPackagesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListModelPackagesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19615,7 +20191,7 @@ impl ListModelQualityJobDefinitionsError {
     ) -> RusotoError<ListModelQualityJobDefinitionsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19637,7 +20213,7 @@ impl ListModelsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListModelsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19659,7 +20235,7 @@ impl ListMonitoringExecutionsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListMonitoringExecutionsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19681,7 +20257,7 @@ impl ListMonitoringSchedulesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListMonitoringSchedulesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19705,7 +20281,7 @@ impl ListNotebookInstanceLifecycleConfigsError {
     ) -> RusotoError<ListNotebookInstanceLifecycleConfigsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19727,7 +20303,7 @@ impl ListNotebookInstancesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListNotebookInstancesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19755,11 +20331,14 @@ impl ListPipelineExecutionStepsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(ListPipelineExecutionStepsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPipelineExecutionStepsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19787,11 +20366,14 @@ impl ListPipelineExecutionsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(ListPipelineExecutionsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListPipelineExecutionsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19821,11 +20403,14 @@ impl ListPipelineParametersForExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(
-                        ListPipelineParametersForExecutionError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListPipelineParametersForExecutionError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19851,7 +20436,7 @@ impl ListPipelinesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListPipelinesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19873,7 +20458,7 @@ impl ListProcessingJobsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListProcessingJobsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19895,7 +20480,7 @@ impl ListProjectsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListProjectsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19917,7 +20502,7 @@ impl ListSubscribedWorkteamsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListSubscribedWorkteamsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19939,7 +20524,7 @@ impl ListTagsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListTagsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19961,7 +20546,7 @@ impl ListTrainingJobsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListTrainingJobsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -19989,11 +20574,14 @@ impl ListTrainingJobsForHyperParameterTuningJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(
-                        ListTrainingJobsForHyperParameterTuningJobError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTrainingJobsForHyperParameterTuningJobError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20019,7 +20607,7 @@ impl ListTransformJobsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListTransformJobsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20045,11 +20633,14 @@ impl ListTrialComponentsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(ListTrialComponentsError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        ListTrialComponentsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20077,9 +20668,14 @@ impl ListTrialsError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(ListTrialsError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        ListTrialsError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20103,7 +20699,7 @@ impl ListUserProfilesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListUserProfilesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20125,7 +20721,7 @@ impl ListWorkforcesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListWorkforcesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20147,7 +20743,7 @@ impl ListWorkteamsError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<ListWorkteamsError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20171,7 +20767,7 @@ impl PutModelPackageGroupPolicyError {
     ) -> RusotoError<PutModelPackageGroupPolicyError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20197,11 +20793,14 @@ impl RegisterDevicesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(RegisterDevicesError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        RegisterDevicesError::ResourceLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20229,9 +20828,14 @@ impl RenderUiTemplateError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(RenderUiTemplateError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        RenderUiTemplateError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20255,7 +20859,7 @@ impl SearchError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<SearchError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20281,11 +20885,14 @@ impl StartMonitoringScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(StartMonitoringScheduleError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartMonitoringScheduleError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20313,11 +20920,14 @@ impl StartNotebookInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(StartNotebookInstanceError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartNotebookInstanceError::ResourceLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20347,16 +20957,22 @@ impl StartPipelineExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(
-                        StartPipelineExecutionError::ResourceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StartPipelineExecutionError::ResourceLimitExceeded,
                     )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(StartPipelineExecutionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StartPipelineExecutionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20385,9 +21001,14 @@ impl StopAutoMLJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(StopAutoMLJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopAutoMLJobError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20415,9 +21036,14 @@ impl StopCompilationJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(StopCompilationJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopCompilationJobError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20441,7 +21067,7 @@ impl StopEdgePackagingJobError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<StopEdgePackagingJobError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20469,11 +21095,14 @@ impl StopHyperParameterTuningJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(
-                        StopHyperParameterTuningJobError::ResourceNotFound(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopHyperParameterTuningJobError::ResourceNotFound,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20501,9 +21130,14 @@ impl StopLabelingJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(StopLabelingJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopLabelingJobError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20531,11 +21165,14 @@ impl StopMonitoringScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(StopMonitoringScheduleError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopMonitoringScheduleError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20559,7 +21196,7 @@ impl StopNotebookInstanceError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<StopNotebookInstanceError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20585,11 +21222,14 @@ impl StopPipelineExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(StopPipelineExecutionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        StopPipelineExecutionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20617,9 +21257,14 @@ impl StopProcessingJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(StopProcessingJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopProcessingJobError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20647,9 +21292,14 @@ impl StopTrainingJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(StopTrainingJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopTrainingJobError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20677,9 +21327,14 @@ impl StopTransformJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(StopTransformJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        StopTransformJobError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20709,12 +21364,22 @@ impl UpdateActionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateActionError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateActionError::Conflict,
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(UpdateActionError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateActionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20743,11 +21408,14 @@ impl UpdateAppImageConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(UpdateAppImageConfigError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateAppImageConfigError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20777,12 +21445,22 @@ impl UpdateArtifactError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateArtifactError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateArtifactError::Conflict,
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(UpdateArtifactError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateArtifactError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20807,7 +21485,7 @@ impl UpdateCodeRepositoryError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UpdateCodeRepositoryError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20835,12 +21513,22 @@ impl UpdateContextError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateContextError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateContextError::Conflict,
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(UpdateContextError::ResourceNotFound(err.msg))
-                }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateContextError::ResourceNotFound,
+                    )
+                }
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20869,9 +21557,14 @@ impl UpdateDeviceFleetError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(UpdateDeviceFleetError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDeviceFleetError::ResourceInUse,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20895,7 +21588,7 @@ impl UpdateDevicesError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UpdateDevicesError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20925,15 +21618,30 @@ impl UpdateDomainError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(UpdateDomainError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDomainError::ResourceInUse,
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(UpdateDomainError::ResourceLimitExceeded(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDomainError::ResourceLimitExceeded,
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(UpdateDomainError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateDomainError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20963,11 +21671,14 @@ impl UpdateEndpointError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(UpdateEndpointError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateEndpointError::ResourceLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -20997,11 +21708,14 @@ impl UpdateEndpointWeightsAndCapacitiesError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(
-                        UpdateEndpointWeightsAndCapacitiesError::ResourceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateEndpointWeightsAndCapacitiesError::ResourceLimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -21033,12 +21747,22 @@ impl UpdateExperimentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateExperimentError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateExperimentError::Conflict,
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(UpdateExperimentError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateExperimentError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -21069,12 +21793,22 @@ impl UpdateImageError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(UpdateImageError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateImageError::ResourceInUse,
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(UpdateImageError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateImageError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -21099,7 +21833,7 @@ impl UpdateModelPackageError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UpdateModelPackageError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -21127,16 +21861,22 @@ impl UpdateMonitoringScheduleError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(
-                        UpdateMonitoringScheduleError::ResourceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateMonitoringScheduleError::ResourceLimitExceeded,
                     )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(UpdateMonitoringScheduleError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateMonitoringScheduleError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -21167,11 +21907,14 @@ impl UpdateNotebookInstanceError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(
-                        UpdateNotebookInstanceError::ResourceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateNotebookInstanceError::ResourceLimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -21201,11 +21944,14 @@ impl UpdateNotebookInstanceLifecycleConfigError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(
-                        UpdateNotebookInstanceLifecycleConfigError::ResourceLimitExceeded(err.msg),
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateNotebookInstanceLifecycleConfigError::ResourceLimitExceeded,
                     )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -21235,9 +21981,14 @@ impl UpdatePipelineError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(UpdatePipelineError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdatePipelineError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -21265,11 +22016,14 @@ impl UpdatePipelineExecutionError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(UpdatePipelineExecutionError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdatePipelineExecutionError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -21297,9 +22051,14 @@ impl UpdateTrainingJobError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceNotFound" => {
-                    return RusotoError::Service(UpdateTrainingJobError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTrainingJobError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -21329,12 +22088,22 @@ impl UpdateTrialError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateTrialError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTrialError::Conflict,
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(UpdateTrialError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTrialError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -21365,14 +22134,22 @@ impl UpdateTrialComponentError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ConflictException" => {
-                    return RusotoError::Service(UpdateTrialComponentError::Conflict(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateTrialComponentError::Conflict,
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(UpdateTrialComponentError::ResourceNotFound(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateTrialComponentError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -21405,17 +22182,30 @@ impl UpdateUserProfileError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceInUse" => {
-                    return RusotoError::Service(UpdateUserProfileError::ResourceInUse(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserProfileError::ResourceInUse,
+                    )
                 }
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(UpdateUserProfileError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateUserProfileError::ResourceLimitExceeded,
+                    )
                 }
                 "ResourceNotFound" => {
-                    return RusotoError::Service(UpdateUserProfileError::ResourceNotFound(err.msg))
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
+                        err.msg,
+                        UpdateUserProfileError::ResourceNotFound,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -21441,7 +22231,7 @@ impl UpdateWorkforceError {
     pub fn from_response(res: BufferedHttpResponse) -> RusotoError<UpdateWorkforceError> {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -21467,11 +22257,14 @@ impl UpdateWorkteamError {
         if let Some(err) = proto::json::Error::parse(&res) {
             match err.typ.as_str() {
                 "ResourceLimitExceeded" => {
-                    return RusotoError::Service(UpdateWorkteamError::ResourceLimitExceeded(
+                    return RusotoError::service_error(
+                        &res,
+                        &err.typ,
                         err.msg,
-                    ))
+                        UpdateWorkteamError::ResourceLimitExceeded,
+                    )
                 }
-                "ValidationException" => return RusotoError::Validation(err.msg),
+                "ValidationException" => return RusotoError::validation_error(&res, err.msg),
                 _ => {}
             }
         }
@@ -22964,11 +23757,12 @@ pub struct SageMakerClient {
 impl SageMakerClient {
     /// Creates a client backed by the default tokio event loop.
     ///
-    /// The client will use the default credentials provider and tls client.
+    /// The client will use the default credentials provider and tls client, and the
+    /// endpoint URL configured for the service, if any.
     pub fn new(region: region::Region) -> SageMakerClient {
         SageMakerClient {
             client: Client::shared(),
-            region,
+            region: region_for_service(region, "SageMaker"),
         }
     }
 
@@ -22983,12 +23777,32 @@ impl SageMakerClient {
     {
         SageMakerClient {
             client: Client::new_with(credentials_provider, request_dispatcher),
-            region,
+            region: region_for_service(region, "SageMaker"),
         }
     }
 
     pub fn new_with_client(client: Client, region: region::Region) -> SageMakerClient {
-        SageMakerClient { client, region }
+        SageMakerClient {
+            client,
+            region: region_for_service(region, "SageMaker"),
+        }
+    }
+
+    /// Creates a client using the region, endpoint URL, credentials provider, HTTP
+    /// client and retry settings of the given configuration.
+    pub fn new_with_config(config: &AwsConfig) -> SageMakerClient {
+        SageMakerClient {
+            client: Client::new_with_config(config),
+            region: config.get_region_for_service("SageMaker"),
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> SageMakerClient {
+        SageMakerClient {
+            client: self.client.with_options(options),
+            region: self.region.clone(),
+        }
     }
 }
 
@@ -23000,6 +23814,7 @@ impl SageMaker for SageMakerClient {
         input: AddAssociationRequest,
     ) -> Result<AddAssociationResponse, RusotoError<AddAssociationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AddAssociation");
         request.add_header("x-amz-target", "SageMaker.AddAssociation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23018,6 +23833,7 @@ impl SageMaker for SageMakerClient {
         input: AddTagsInput,
     ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AddTags");
         request.add_header("x-amz-target", "SageMaker.AddTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23036,6 +23852,7 @@ impl SageMaker for SageMakerClient {
         input: AssociateTrialComponentRequest,
     ) -> Result<AssociateTrialComponentResponse, RusotoError<AssociateTrialComponentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("AssociateTrialComponent");
         request.add_header("x-amz-target", "SageMaker.AssociateTrialComponent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23055,6 +23872,7 @@ impl SageMaker for SageMakerClient {
         input: CreateActionRequest,
     ) -> Result<CreateActionResponse, RusotoError<CreateActionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateAction");
         request.add_header("x-amz-target", "SageMaker.CreateAction");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23073,6 +23891,7 @@ impl SageMaker for SageMakerClient {
         input: CreateAlgorithmInput,
     ) -> Result<CreateAlgorithmOutput, RusotoError<CreateAlgorithmError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateAlgorithm");
         request.add_header("x-amz-target", "SageMaker.CreateAlgorithm");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23091,6 +23910,7 @@ impl SageMaker for SageMakerClient {
         input: CreateAppRequest,
     ) -> Result<CreateAppResponse, RusotoError<CreateAppError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateApp");
         request.add_header("x-amz-target", "SageMaker.CreateApp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23109,6 +23929,7 @@ impl SageMaker for SageMakerClient {
         input: CreateAppImageConfigRequest,
     ) -> Result<CreateAppImageConfigResponse, RusotoError<CreateAppImageConfigError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateAppImageConfig");
         request.add_header("x-amz-target", "SageMaker.CreateAppImageConfig");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23128,6 +23949,7 @@ impl SageMaker for SageMakerClient {
         input: CreateArtifactRequest,
     ) -> Result<CreateArtifactResponse, RusotoError<CreateArtifactError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateArtifact");
         request.add_header("x-amz-target", "SageMaker.CreateArtifact");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23146,6 +23968,7 @@ impl SageMaker for SageMakerClient {
         input: CreateAutoMLJobRequest,
     ) -> Result<CreateAutoMLJobResponse, RusotoError<CreateAutoMLJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateAutoMLJob");
         request.add_header("x-amz-target", "SageMaker.CreateAutoMLJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23164,6 +23987,7 @@ impl SageMaker for SageMakerClient {
         input: CreateCodeRepositoryInput,
     ) -> Result<CreateCodeRepositoryOutput, RusotoError<CreateCodeRepositoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateCodeRepository");
         request.add_header("x-amz-target", "SageMaker.CreateCodeRepository");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23182,6 +24006,7 @@ impl SageMaker for SageMakerClient {
         input: CreateCompilationJobRequest,
     ) -> Result<CreateCompilationJobResponse, RusotoError<CreateCompilationJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateCompilationJob");
         request.add_header("x-amz-target", "SageMaker.CreateCompilationJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23201,6 +24026,7 @@ impl SageMaker for SageMakerClient {
         input: CreateContextRequest,
     ) -> Result<CreateContextResponse, RusotoError<CreateContextError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateContext");
         request.add_header("x-amz-target", "SageMaker.CreateContext");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23222,6 +24048,7 @@ impl SageMaker for SageMakerClient {
         RusotoError<CreateDataQualityJobDefinitionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateDataQualityJobDefinition");
         request.add_header("x-amz-target", "SageMaker.CreateDataQualityJobDefinition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23241,6 +24068,7 @@ impl SageMaker for SageMakerClient {
         input: CreateDeviceFleetRequest,
     ) -> Result<(), RusotoError<CreateDeviceFleetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateDeviceFleet");
         request.add_header("x-amz-target", "SageMaker.CreateDeviceFleet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23258,6 +24086,7 @@ impl SageMaker for SageMakerClient {
         input: CreateDomainRequest,
     ) -> Result<CreateDomainResponse, RusotoError<CreateDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateDomain");
         request.add_header("x-amz-target", "SageMaker.CreateDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23276,6 +24105,7 @@ impl SageMaker for SageMakerClient {
         input: CreateEdgePackagingJobRequest,
     ) -> Result<(), RusotoError<CreateEdgePackagingJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateEdgePackagingJob");
         request.add_header("x-amz-target", "SageMaker.CreateEdgePackagingJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23293,6 +24123,7 @@ impl SageMaker for SageMakerClient {
         input: CreateEndpointInput,
     ) -> Result<CreateEndpointOutput, RusotoError<CreateEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateEndpoint");
         request.add_header("x-amz-target", "SageMaker.CreateEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23311,6 +24142,7 @@ impl SageMaker for SageMakerClient {
         input: CreateEndpointConfigInput,
     ) -> Result<CreateEndpointConfigOutput, RusotoError<CreateEndpointConfigError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateEndpointConfig");
         request.add_header("x-amz-target", "SageMaker.CreateEndpointConfig");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23329,6 +24161,7 @@ impl SageMaker for SageMakerClient {
         input: CreateExperimentRequest,
     ) -> Result<CreateExperimentResponse, RusotoError<CreateExperimentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateExperiment");
         request.add_header("x-amz-target", "SageMaker.CreateExperiment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23347,6 +24180,7 @@ impl SageMaker for SageMakerClient {
         input: CreateFeatureGroupRequest,
     ) -> Result<CreateFeatureGroupResponse, RusotoError<CreateFeatureGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateFeatureGroup");
         request.add_header("x-amz-target", "SageMaker.CreateFeatureGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23365,6 +24199,7 @@ impl SageMaker for SageMakerClient {
         input: CreateFlowDefinitionRequest,
     ) -> Result<CreateFlowDefinitionResponse, RusotoError<CreateFlowDefinitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateFlowDefinition");
         request.add_header("x-amz-target", "SageMaker.CreateFlowDefinition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23384,6 +24219,7 @@ impl SageMaker for SageMakerClient {
         input: CreateHumanTaskUiRequest,
     ) -> Result<CreateHumanTaskUiResponse, RusotoError<CreateHumanTaskUiError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateHumanTaskUi");
         request.add_header("x-amz-target", "SageMaker.CreateHumanTaskUi");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23405,6 +24241,7 @@ impl SageMaker for SageMakerClient {
         RusotoError<CreateHyperParameterTuningJobError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateHyperParameterTuningJob");
         request.add_header("x-amz-target", "SageMaker.CreateHyperParameterTuningJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23424,6 +24261,7 @@ impl SageMaker for SageMakerClient {
         input: CreateImageRequest,
     ) -> Result<CreateImageResponse, RusotoError<CreateImageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateImage");
         request.add_header("x-amz-target", "SageMaker.CreateImage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23442,6 +24280,7 @@ impl SageMaker for SageMakerClient {
         input: CreateImageVersionRequest,
     ) -> Result<CreateImageVersionResponse, RusotoError<CreateImageVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateImageVersion");
         request.add_header("x-amz-target", "SageMaker.CreateImageVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23460,6 +24299,7 @@ impl SageMaker for SageMakerClient {
         input: CreateLabelingJobRequest,
     ) -> Result<CreateLabelingJobResponse, RusotoError<CreateLabelingJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateLabelingJob");
         request.add_header("x-amz-target", "SageMaker.CreateLabelingJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23478,6 +24318,7 @@ impl SageMaker for SageMakerClient {
         input: CreateModelInput,
     ) -> Result<CreateModelOutput, RusotoError<CreateModelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateModel");
         request.add_header("x-amz-target", "SageMaker.CreateModel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23497,6 +24338,7 @@ impl SageMaker for SageMakerClient {
     ) -> Result<CreateModelBiasJobDefinitionResponse, RusotoError<CreateModelBiasJobDefinitionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateModelBiasJobDefinition");
         request.add_header("x-amz-target", "SageMaker.CreateModelBiasJobDefinition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23519,6 +24361,7 @@ impl SageMaker for SageMakerClient {
         RusotoError<CreateModelExplainabilityJobDefinitionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateModelExplainabilityJobDefinition");
         request.add_header(
             "x-amz-target",
             "SageMaker.CreateModelExplainabilityJobDefinition",
@@ -23544,6 +24387,7 @@ impl SageMaker for SageMakerClient {
         input: CreateModelPackageInput,
     ) -> Result<CreateModelPackageOutput, RusotoError<CreateModelPackageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateModelPackage");
         request.add_header("x-amz-target", "SageMaker.CreateModelPackage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23562,6 +24406,7 @@ impl SageMaker for SageMakerClient {
         input: CreateModelPackageGroupInput,
     ) -> Result<CreateModelPackageGroupOutput, RusotoError<CreateModelPackageGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateModelPackageGroup");
         request.add_header("x-amz-target", "SageMaker.CreateModelPackageGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23584,6 +24429,7 @@ impl SageMaker for SageMakerClient {
         RusotoError<CreateModelQualityJobDefinitionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateModelQualityJobDefinition");
         request.add_header("x-amz-target", "SageMaker.CreateModelQualityJobDefinition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23603,6 +24449,7 @@ impl SageMaker for SageMakerClient {
         input: CreateMonitoringScheduleRequest,
     ) -> Result<CreateMonitoringScheduleResponse, RusotoError<CreateMonitoringScheduleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateMonitoringSchedule");
         request.add_header("x-amz-target", "SageMaker.CreateMonitoringSchedule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23622,6 +24469,7 @@ impl SageMaker for SageMakerClient {
         input: CreateNotebookInstanceInput,
     ) -> Result<CreateNotebookInstanceOutput, RusotoError<CreateNotebookInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateNotebookInstance");
         request.add_header("x-amz-target", "SageMaker.CreateNotebookInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23644,6 +24492,7 @@ impl SageMaker for SageMakerClient {
         RusotoError<CreateNotebookInstanceLifecycleConfigError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateNotebookInstanceLifecycleConfig");
         request.add_header(
             "x-amz-target",
             "SageMaker.CreateNotebookInstanceLifecycleConfig",
@@ -23669,6 +24518,7 @@ impl SageMaker for SageMakerClient {
         input: CreatePipelineRequest,
     ) -> Result<CreatePipelineResponse, RusotoError<CreatePipelineError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreatePipeline");
         request.add_header("x-amz-target", "SageMaker.CreatePipeline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23687,6 +24537,7 @@ impl SageMaker for SageMakerClient {
         input: CreatePresignedDomainUrlRequest,
     ) -> Result<CreatePresignedDomainUrlResponse, RusotoError<CreatePresignedDomainUrlError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreatePresignedDomainUrl");
         request.add_header("x-amz-target", "SageMaker.CreatePresignedDomainUrl");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23709,6 +24560,7 @@ impl SageMaker for SageMakerClient {
         RusotoError<CreatePresignedNotebookInstanceUrlError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreatePresignedNotebookInstanceUrl");
         request.add_header(
             "x-amz-target",
             "SageMaker.CreatePresignedNotebookInstanceUrl",
@@ -23734,6 +24586,7 @@ impl SageMaker for SageMakerClient {
         input: CreateProcessingJobRequest,
     ) -> Result<CreateProcessingJobResponse, RusotoError<CreateProcessingJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateProcessingJob");
         request.add_header("x-amz-target", "SageMaker.CreateProcessingJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23752,6 +24605,7 @@ impl SageMaker for SageMakerClient {
         input: CreateProjectInput,
     ) -> Result<CreateProjectOutput, RusotoError<CreateProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateProject");
         request.add_header("x-amz-target", "SageMaker.CreateProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23770,6 +24624,7 @@ impl SageMaker for SageMakerClient {
         input: CreateTrainingJobRequest,
     ) -> Result<CreateTrainingJobResponse, RusotoError<CreateTrainingJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateTrainingJob");
         request.add_header("x-amz-target", "SageMaker.CreateTrainingJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23788,6 +24643,7 @@ impl SageMaker for SageMakerClient {
         input: CreateTransformJobRequest,
     ) -> Result<CreateTransformJobResponse, RusotoError<CreateTransformJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateTransformJob");
         request.add_header("x-amz-target", "SageMaker.CreateTransformJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23806,6 +24662,7 @@ impl SageMaker for SageMakerClient {
         input: CreateTrialRequest,
     ) -> Result<CreateTrialResponse, RusotoError<CreateTrialError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateTrial");
         request.add_header("x-amz-target", "SageMaker.CreateTrial");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23824,6 +24681,7 @@ impl SageMaker for SageMakerClient {
         input: CreateTrialComponentRequest,
     ) -> Result<CreateTrialComponentResponse, RusotoError<CreateTrialComponentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateTrialComponent");
         request.add_header("x-amz-target", "SageMaker.CreateTrialComponent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23843,6 +24701,7 @@ impl SageMaker for SageMakerClient {
         input: CreateUserProfileRequest,
     ) -> Result<CreateUserProfileResponse, RusotoError<CreateUserProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateUserProfile");
         request.add_header("x-amz-target", "SageMaker.CreateUserProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23861,6 +24720,7 @@ impl SageMaker for SageMakerClient {
         input: CreateWorkforceRequest,
     ) -> Result<CreateWorkforceResponse, RusotoError<CreateWorkforceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateWorkforce");
         request.add_header("x-amz-target", "SageMaker.CreateWorkforce");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23879,6 +24739,7 @@ impl SageMaker for SageMakerClient {
         input: CreateWorkteamRequest,
     ) -> Result<CreateWorkteamResponse, RusotoError<CreateWorkteamError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("CreateWorkteam");
         request.add_header("x-amz-target", "SageMaker.CreateWorkteam");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23897,6 +24758,7 @@ impl SageMaker for SageMakerClient {
         input: DeleteActionRequest,
     ) -> Result<DeleteActionResponse, RusotoError<DeleteActionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteAction");
         request.add_header("x-amz-target", "SageMaker.DeleteAction");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23915,6 +24777,7 @@ impl SageMaker for SageMakerClient {
         input: DeleteAlgorithmInput,
     ) -> Result<(), RusotoError<DeleteAlgorithmError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteAlgorithm");
         request.add_header("x-amz-target", "SageMaker.DeleteAlgorithm");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23929,6 +24792,7 @@ impl SageMaker for SageMakerClient {
     /// <p>Used to stop and delete an app.</p>
     async fn delete_app(&self, input: DeleteAppRequest) -> Result<(), RusotoError<DeleteAppError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteApp");
         request.add_header("x-amz-target", "SageMaker.DeleteApp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23946,6 +24810,7 @@ impl SageMaker for SageMakerClient {
         input: DeleteAppImageConfigRequest,
     ) -> Result<(), RusotoError<DeleteAppImageConfigError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteAppImageConfig");
         request.add_header("x-amz-target", "SageMaker.DeleteAppImageConfig");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23963,6 +24828,7 @@ impl SageMaker for SageMakerClient {
         input: DeleteArtifactRequest,
     ) -> Result<DeleteArtifactResponse, RusotoError<DeleteArtifactError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteArtifact");
         request.add_header("x-amz-target", "SageMaker.DeleteArtifact");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23981,6 +24847,7 @@ impl SageMaker for SageMakerClient {
         input: DeleteAssociationRequest,
     ) -> Result<DeleteAssociationResponse, RusotoError<DeleteAssociationError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteAssociation");
         request.add_header("x-amz-target", "SageMaker.DeleteAssociation");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -23999,6 +24866,7 @@ impl SageMaker for SageMakerClient {
         input: DeleteCodeRepositoryInput,
     ) -> Result<(), RusotoError<DeleteCodeRepositoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteCodeRepository");
         request.add_header("x-amz-target", "SageMaker.DeleteCodeRepository");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24016,6 +24884,7 @@ impl SageMaker for SageMakerClient {
         input: DeleteContextRequest,
     ) -> Result<DeleteContextResponse, RusotoError<DeleteContextError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteContext");
         request.add_header("x-amz-target", "SageMaker.DeleteContext");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24034,6 +24903,7 @@ impl SageMaker for SageMakerClient {
         input: DeleteDataQualityJobDefinitionRequest,
     ) -> Result<(), RusotoError<DeleteDataQualityJobDefinitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteDataQualityJobDefinition");
         request.add_header("x-amz-target", "SageMaker.DeleteDataQualityJobDefinition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24051,6 +24921,7 @@ impl SageMaker for SageMakerClient {
         input: DeleteDeviceFleetRequest,
     ) -> Result<(), RusotoError<DeleteDeviceFleetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteDeviceFleet");
         request.add_header("x-amz-target", "SageMaker.DeleteDeviceFleet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24068,6 +24939,7 @@ impl SageMaker for SageMakerClient {
         input: DeleteDomainRequest,
     ) -> Result<(), RusotoError<DeleteDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteDomain");
         request.add_header("x-amz-target", "SageMaker.DeleteDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24085,6 +24957,7 @@ impl SageMaker for SageMakerClient {
         input: DeleteEndpointInput,
     ) -> Result<(), RusotoError<DeleteEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteEndpoint");
         request.add_header("x-amz-target", "SageMaker.DeleteEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24102,6 +24975,7 @@ impl SageMaker for SageMakerClient {
         input: DeleteEndpointConfigInput,
     ) -> Result<(), RusotoError<DeleteEndpointConfigError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteEndpointConfig");
         request.add_header("x-amz-target", "SageMaker.DeleteEndpointConfig");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24119,6 +24993,7 @@ impl SageMaker for SageMakerClient {
         input: DeleteExperimentRequest,
     ) -> Result<DeleteExperimentResponse, RusotoError<DeleteExperimentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteExperiment");
         request.add_header("x-amz-target", "SageMaker.DeleteExperiment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24137,6 +25012,7 @@ impl SageMaker for SageMakerClient {
         input: DeleteFeatureGroupRequest,
     ) -> Result<(), RusotoError<DeleteFeatureGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteFeatureGroup");
         request.add_header("x-amz-target", "SageMaker.DeleteFeatureGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24154,6 +25030,7 @@ impl SageMaker for SageMakerClient {
         input: DeleteFlowDefinitionRequest,
     ) -> Result<DeleteFlowDefinitionResponse, RusotoError<DeleteFlowDefinitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteFlowDefinition");
         request.add_header("x-amz-target", "SageMaker.DeleteFlowDefinition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24173,6 +25050,7 @@ impl SageMaker for SageMakerClient {
         input: DeleteHumanTaskUiRequest,
     ) -> Result<DeleteHumanTaskUiResponse, RusotoError<DeleteHumanTaskUiError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteHumanTaskUi");
         request.add_header("x-amz-target", "SageMaker.DeleteHumanTaskUi");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24191,6 +25069,7 @@ impl SageMaker for SageMakerClient {
         input: DeleteImageRequest,
     ) -> Result<DeleteImageResponse, RusotoError<DeleteImageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteImage");
         request.add_header("x-amz-target", "SageMaker.DeleteImage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24209,6 +25088,7 @@ impl SageMaker for SageMakerClient {
         input: DeleteImageVersionRequest,
     ) -> Result<DeleteImageVersionResponse, RusotoError<DeleteImageVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteImageVersion");
         request.add_header("x-amz-target", "SageMaker.DeleteImageVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24227,6 +25107,7 @@ impl SageMaker for SageMakerClient {
         input: DeleteModelInput,
     ) -> Result<(), RusotoError<DeleteModelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteModel");
         request.add_header("x-amz-target", "SageMaker.DeleteModel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24244,6 +25125,7 @@ impl SageMaker for SageMakerClient {
         input: DeleteModelBiasJobDefinitionRequest,
     ) -> Result<(), RusotoError<DeleteModelBiasJobDefinitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteModelBiasJobDefinition");
         request.add_header("x-amz-target", "SageMaker.DeleteModelBiasJobDefinition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24261,6 +25143,7 @@ impl SageMaker for SageMakerClient {
         input: DeleteModelExplainabilityJobDefinitionRequest,
     ) -> Result<(), RusotoError<DeleteModelExplainabilityJobDefinitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteModelExplainabilityJobDefinition");
         request.add_header(
             "x-amz-target",
             "SageMaker.DeleteModelExplainabilityJobDefinition",
@@ -24284,6 +25167,7 @@ impl SageMaker for SageMakerClient {
         input: DeleteModelPackageInput,
     ) -> Result<(), RusotoError<DeleteModelPackageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteModelPackage");
         request.add_header("x-amz-target", "SageMaker.DeleteModelPackage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24301,6 +25185,7 @@ impl SageMaker for SageMakerClient {
         input: DeleteModelPackageGroupInput,
     ) -> Result<(), RusotoError<DeleteModelPackageGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteModelPackageGroup");
         request.add_header("x-amz-target", "SageMaker.DeleteModelPackageGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24318,6 +25203,7 @@ impl SageMaker for SageMakerClient {
         input: DeleteModelPackageGroupPolicyInput,
     ) -> Result<(), RusotoError<DeleteModelPackageGroupPolicyError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteModelPackageGroupPolicy");
         request.add_header("x-amz-target", "SageMaker.DeleteModelPackageGroupPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24335,6 +25221,7 @@ impl SageMaker for SageMakerClient {
         input: DeleteModelQualityJobDefinitionRequest,
     ) -> Result<(), RusotoError<DeleteModelQualityJobDefinitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteModelQualityJobDefinition");
         request.add_header("x-amz-target", "SageMaker.DeleteModelQualityJobDefinition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24352,6 +25239,7 @@ impl SageMaker for SageMakerClient {
         input: DeleteMonitoringScheduleRequest,
     ) -> Result<(), RusotoError<DeleteMonitoringScheduleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteMonitoringSchedule");
         request.add_header("x-amz-target", "SageMaker.DeleteMonitoringSchedule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24369,6 +25257,7 @@ impl SageMaker for SageMakerClient {
         input: DeleteNotebookInstanceInput,
     ) -> Result<(), RusotoError<DeleteNotebookInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteNotebookInstance");
         request.add_header("x-amz-target", "SageMaker.DeleteNotebookInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24386,6 +25275,7 @@ impl SageMaker for SageMakerClient {
         input: DeleteNotebookInstanceLifecycleConfigInput,
     ) -> Result<(), RusotoError<DeleteNotebookInstanceLifecycleConfigError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteNotebookInstanceLifecycleConfig");
         request.add_header(
             "x-amz-target",
             "SageMaker.DeleteNotebookInstanceLifecycleConfig",
@@ -24409,6 +25299,7 @@ impl SageMaker for SageMakerClient {
         input: DeletePipelineRequest,
     ) -> Result<DeletePipelineResponse, RusotoError<DeletePipelineError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeletePipeline");
         request.add_header("x-amz-target", "SageMaker.DeletePipeline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24427,6 +25318,7 @@ impl SageMaker for SageMakerClient {
         input: DeleteProjectInput,
     ) -> Result<(), RusotoError<DeleteProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteProject");
         request.add_header("x-amz-target", "SageMaker.DeleteProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24444,6 +25336,7 @@ impl SageMaker for SageMakerClient {
         input: DeleteTagsInput,
     ) -> Result<DeleteTagsOutput, RusotoError<DeleteTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteTags");
         request.add_header("x-amz-target", "SageMaker.DeleteTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24462,6 +25355,7 @@ impl SageMaker for SageMakerClient {
         input: DeleteTrialRequest,
     ) -> Result<DeleteTrialResponse, RusotoError<DeleteTrialError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteTrial");
         request.add_header("x-amz-target", "SageMaker.DeleteTrial");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24480,6 +25374,7 @@ impl SageMaker for SageMakerClient {
         input: DeleteTrialComponentRequest,
     ) -> Result<DeleteTrialComponentResponse, RusotoError<DeleteTrialComponentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteTrialComponent");
         request.add_header("x-amz-target", "SageMaker.DeleteTrialComponent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24499,6 +25394,7 @@ impl SageMaker for SageMakerClient {
         input: DeleteUserProfileRequest,
     ) -> Result<(), RusotoError<DeleteUserProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteUserProfile");
         request.add_header("x-amz-target", "SageMaker.DeleteUserProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24516,6 +25412,7 @@ impl SageMaker for SageMakerClient {
         input: DeleteWorkforceRequest,
     ) -> Result<DeleteWorkforceResponse, RusotoError<DeleteWorkforceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteWorkforce");
         request.add_header("x-amz-target", "SageMaker.DeleteWorkforce");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24534,6 +25431,7 @@ impl SageMaker for SageMakerClient {
         input: DeleteWorkteamRequest,
     ) -> Result<DeleteWorkteamResponse, RusotoError<DeleteWorkteamError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeleteWorkteam");
         request.add_header("x-amz-target", "SageMaker.DeleteWorkteam");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24552,6 +25450,7 @@ impl SageMaker for SageMakerClient {
         input: DeregisterDevicesRequest,
     ) -> Result<(), RusotoError<DeregisterDevicesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DeregisterDevices");
         request.add_header("x-amz-target", "SageMaker.DeregisterDevices");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24569,6 +25468,7 @@ impl SageMaker for SageMakerClient {
         input: DescribeActionRequest,
     ) -> Result<DescribeActionResponse, RusotoError<DescribeActionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeAction");
         request.add_header("x-amz-target", "SageMaker.DescribeAction");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24587,6 +25487,7 @@ impl SageMaker for SageMakerClient {
         input: DescribeAlgorithmInput,
     ) -> Result<DescribeAlgorithmOutput, RusotoError<DescribeAlgorithmError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeAlgorithm");
         request.add_header("x-amz-target", "SageMaker.DescribeAlgorithm");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24605,6 +25506,7 @@ impl SageMaker for SageMakerClient {
         input: DescribeAppRequest,
     ) -> Result<DescribeAppResponse, RusotoError<DescribeAppError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeApp");
         request.add_header("x-amz-target", "SageMaker.DescribeApp");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24623,6 +25525,7 @@ impl SageMaker for SageMakerClient {
         input: DescribeAppImageConfigRequest,
     ) -> Result<DescribeAppImageConfigResponse, RusotoError<DescribeAppImageConfigError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeAppImageConfig");
         request.add_header("x-amz-target", "SageMaker.DescribeAppImageConfig");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24642,6 +25545,7 @@ impl SageMaker for SageMakerClient {
         input: DescribeArtifactRequest,
     ) -> Result<DescribeArtifactResponse, RusotoError<DescribeArtifactError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeArtifact");
         request.add_header("x-amz-target", "SageMaker.DescribeArtifact");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24660,6 +25564,7 @@ impl SageMaker for SageMakerClient {
         input: DescribeAutoMLJobRequest,
     ) -> Result<DescribeAutoMLJobResponse, RusotoError<DescribeAutoMLJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeAutoMLJob");
         request.add_header("x-amz-target", "SageMaker.DescribeAutoMLJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24678,6 +25583,7 @@ impl SageMaker for SageMakerClient {
         input: DescribeCodeRepositoryInput,
     ) -> Result<DescribeCodeRepositoryOutput, RusotoError<DescribeCodeRepositoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeCodeRepository");
         request.add_header("x-amz-target", "SageMaker.DescribeCodeRepository");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24697,6 +25603,7 @@ impl SageMaker for SageMakerClient {
         input: DescribeCompilationJobRequest,
     ) -> Result<DescribeCompilationJobResponse, RusotoError<DescribeCompilationJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeCompilationJob");
         request.add_header("x-amz-target", "SageMaker.DescribeCompilationJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24716,6 +25623,7 @@ impl SageMaker for SageMakerClient {
         input: DescribeContextRequest,
     ) -> Result<DescribeContextResponse, RusotoError<DescribeContextError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeContext");
         request.add_header("x-amz-target", "SageMaker.DescribeContext");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24737,6 +25645,7 @@ impl SageMaker for SageMakerClient {
         RusotoError<DescribeDataQualityJobDefinitionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeDataQualityJobDefinition");
         request.add_header("x-amz-target", "SageMaker.DescribeDataQualityJobDefinition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24759,6 +25668,7 @@ impl SageMaker for SageMakerClient {
         input: DescribeDeviceRequest,
     ) -> Result<DescribeDeviceResponse, RusotoError<DescribeDeviceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeDevice");
         request.add_header("x-amz-target", "SageMaker.DescribeDevice");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24777,6 +25687,7 @@ impl SageMaker for SageMakerClient {
         input: DescribeDeviceFleetRequest,
     ) -> Result<DescribeDeviceFleetResponse, RusotoError<DescribeDeviceFleetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeDeviceFleet");
         request.add_header("x-amz-target", "SageMaker.DescribeDeviceFleet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24795,6 +25706,7 @@ impl SageMaker for SageMakerClient {
         input: DescribeDomainRequest,
     ) -> Result<DescribeDomainResponse, RusotoError<DescribeDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeDomain");
         request.add_header("x-amz-target", "SageMaker.DescribeDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24813,6 +25725,7 @@ impl SageMaker for SageMakerClient {
         input: DescribeEdgePackagingJobRequest,
     ) -> Result<DescribeEdgePackagingJobResponse, RusotoError<DescribeEdgePackagingJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeEdgePackagingJob");
         request.add_header("x-amz-target", "SageMaker.DescribeEdgePackagingJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24832,6 +25745,7 @@ impl SageMaker for SageMakerClient {
         input: DescribeEndpointInput,
     ) -> Result<DescribeEndpointOutput, RusotoError<DescribeEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeEndpoint");
         request.add_header("x-amz-target", "SageMaker.DescribeEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24850,6 +25764,7 @@ impl SageMaker for SageMakerClient {
         input: DescribeEndpointConfigInput,
     ) -> Result<DescribeEndpointConfigOutput, RusotoError<DescribeEndpointConfigError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeEndpointConfig");
         request.add_header("x-amz-target", "SageMaker.DescribeEndpointConfig");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24869,6 +25784,7 @@ impl SageMaker for SageMakerClient {
         input: DescribeExperimentRequest,
     ) -> Result<DescribeExperimentResponse, RusotoError<DescribeExperimentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeExperiment");
         request.add_header("x-amz-target", "SageMaker.DescribeExperiment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24887,6 +25803,7 @@ impl SageMaker for SageMakerClient {
         input: DescribeFeatureGroupRequest,
     ) -> Result<DescribeFeatureGroupResponse, RusotoError<DescribeFeatureGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeFeatureGroup");
         request.add_header("x-amz-target", "SageMaker.DescribeFeatureGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24906,6 +25823,7 @@ impl SageMaker for SageMakerClient {
         input: DescribeFlowDefinitionRequest,
     ) -> Result<DescribeFlowDefinitionResponse, RusotoError<DescribeFlowDefinitionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeFlowDefinition");
         request.add_header("x-amz-target", "SageMaker.DescribeFlowDefinition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24925,6 +25843,7 @@ impl SageMaker for SageMakerClient {
         input: DescribeHumanTaskUiRequest,
     ) -> Result<DescribeHumanTaskUiResponse, RusotoError<DescribeHumanTaskUiError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeHumanTaskUi");
         request.add_header("x-amz-target", "SageMaker.DescribeHumanTaskUi");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24946,6 +25865,7 @@ impl SageMaker for SageMakerClient {
         RusotoError<DescribeHyperParameterTuningJobError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeHyperParameterTuningJob");
         request.add_header("x-amz-target", "SageMaker.DescribeHyperParameterTuningJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24965,6 +25885,7 @@ impl SageMaker for SageMakerClient {
         input: DescribeImageRequest,
     ) -> Result<DescribeImageResponse, RusotoError<DescribeImageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeImage");
         request.add_header("x-amz-target", "SageMaker.DescribeImage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -24983,6 +25904,7 @@ impl SageMaker for SageMakerClient {
         input: DescribeImageVersionRequest,
     ) -> Result<DescribeImageVersionResponse, RusotoError<DescribeImageVersionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeImageVersion");
         request.add_header("x-amz-target", "SageMaker.DescribeImageVersion");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25002,6 +25924,7 @@ impl SageMaker for SageMakerClient {
         input: DescribeLabelingJobRequest,
     ) -> Result<DescribeLabelingJobResponse, RusotoError<DescribeLabelingJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeLabelingJob");
         request.add_header("x-amz-target", "SageMaker.DescribeLabelingJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25020,6 +25943,7 @@ impl SageMaker for SageMakerClient {
         input: DescribeModelInput,
     ) -> Result<DescribeModelOutput, RusotoError<DescribeModelError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeModel");
         request.add_header("x-amz-target", "SageMaker.DescribeModel");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25041,6 +25965,7 @@ impl SageMaker for SageMakerClient {
         RusotoError<DescribeModelBiasJobDefinitionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeModelBiasJobDefinition");
         request.add_header("x-amz-target", "SageMaker.DescribeModelBiasJobDefinition");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25063,6 +25988,7 @@ impl SageMaker for SageMakerClient {
         RusotoError<DescribeModelExplainabilityJobDefinitionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeModelExplainabilityJobDefinition");
         request.add_header(
             "x-amz-target",
             "SageMaker.DescribeModelExplainabilityJobDefinition",
@@ -25088,6 +26014,7 @@ impl SageMaker for SageMakerClient {
         input: DescribeModelPackageInput,
     ) -> Result<DescribeModelPackageOutput, RusotoError<DescribeModelPackageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeModelPackage");
         request.add_header("x-amz-target", "SageMaker.DescribeModelPackage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25106,6 +26033,7 @@ impl SageMaker for SageMakerClient {
         input: DescribeModelPackageGroupInput,
     ) -> Result<DescribeModelPackageGroupOutput, RusotoError<DescribeModelPackageGroupError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeModelPackageGroup");
         request.add_header("x-amz-target", "SageMaker.DescribeModelPackageGroup");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25128,6 +26056,7 @@ impl SageMaker for SageMakerClient {
         RusotoError<DescribeModelQualityJobDefinitionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeModelQualityJobDefinition");
         request.add_header(
             "x-amz-target",
             "SageMaker.DescribeModelQualityJobDefinition",
@@ -25154,6 +26083,7 @@ impl SageMaker for SageMakerClient {
     ) -> Result<DescribeMonitoringScheduleResponse, RusotoError<DescribeMonitoringScheduleError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeMonitoringSchedule");
         request.add_header("x-amz-target", "SageMaker.DescribeMonitoringSchedule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25173,6 +26103,7 @@ impl SageMaker for SageMakerClient {
         input: DescribeNotebookInstanceInput,
     ) -> Result<DescribeNotebookInstanceOutput, RusotoError<DescribeNotebookInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeNotebookInstance");
         request.add_header("x-amz-target", "SageMaker.DescribeNotebookInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25195,6 +26126,7 @@ impl SageMaker for SageMakerClient {
         RusotoError<DescribeNotebookInstanceLifecycleConfigError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeNotebookInstanceLifecycleConfig");
         request.add_header(
             "x-amz-target",
             "SageMaker.DescribeNotebookInstanceLifecycleConfig",
@@ -25220,6 +26152,7 @@ impl SageMaker for SageMakerClient {
         input: DescribePipelineRequest,
     ) -> Result<DescribePipelineResponse, RusotoError<DescribePipelineError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribePipeline");
         request.add_header("x-amz-target", "SageMaker.DescribePipeline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25241,6 +26174,7 @@ impl SageMaker for SageMakerClient {
         RusotoError<DescribePipelineDefinitionForExecutionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribePipelineDefinitionForExecution");
         request.add_header(
             "x-amz-target",
             "SageMaker.DescribePipelineDefinitionForExecution",
@@ -25267,6 +26201,7 @@ impl SageMaker for SageMakerClient {
     ) -> Result<DescribePipelineExecutionResponse, RusotoError<DescribePipelineExecutionError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribePipelineExecution");
         request.add_header("x-amz-target", "SageMaker.DescribePipelineExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25286,6 +26221,7 @@ impl SageMaker for SageMakerClient {
         input: DescribeProcessingJobRequest,
     ) -> Result<DescribeProcessingJobResponse, RusotoError<DescribeProcessingJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeProcessingJob");
         request.add_header("x-amz-target", "SageMaker.DescribeProcessingJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25305,6 +26241,7 @@ impl SageMaker for SageMakerClient {
         input: DescribeProjectInput,
     ) -> Result<DescribeProjectOutput, RusotoError<DescribeProjectError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeProject");
         request.add_header("x-amz-target", "SageMaker.DescribeProject");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25324,6 +26261,7 @@ impl SageMaker for SageMakerClient {
     ) -> Result<DescribeSubscribedWorkteamResponse, RusotoError<DescribeSubscribedWorkteamError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeSubscribedWorkteam");
         request.add_header("x-amz-target", "SageMaker.DescribeSubscribedWorkteam");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25343,6 +26281,7 @@ impl SageMaker for SageMakerClient {
         input: DescribeTrainingJobRequest,
     ) -> Result<DescribeTrainingJobResponse, RusotoError<DescribeTrainingJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeTrainingJob");
         request.add_header("x-amz-target", "SageMaker.DescribeTrainingJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25361,6 +26300,7 @@ impl SageMaker for SageMakerClient {
         input: DescribeTransformJobRequest,
     ) -> Result<DescribeTransformJobResponse, RusotoError<DescribeTransformJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeTransformJob");
         request.add_header("x-amz-target", "SageMaker.DescribeTransformJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25380,6 +26320,7 @@ impl SageMaker for SageMakerClient {
         input: DescribeTrialRequest,
     ) -> Result<DescribeTrialResponse, RusotoError<DescribeTrialError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeTrial");
         request.add_header("x-amz-target", "SageMaker.DescribeTrial");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25398,6 +26339,7 @@ impl SageMaker for SageMakerClient {
         input: DescribeTrialComponentRequest,
     ) -> Result<DescribeTrialComponentResponse, RusotoError<DescribeTrialComponentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeTrialComponent");
         request.add_header("x-amz-target", "SageMaker.DescribeTrialComponent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25417,6 +26359,7 @@ impl SageMaker for SageMakerClient {
         input: DescribeUserProfileRequest,
     ) -> Result<DescribeUserProfileResponse, RusotoError<DescribeUserProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeUserProfile");
         request.add_header("x-amz-target", "SageMaker.DescribeUserProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25435,6 +26378,7 @@ impl SageMaker for SageMakerClient {
         input: DescribeWorkforceRequest,
     ) -> Result<DescribeWorkforceResponse, RusotoError<DescribeWorkforceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeWorkforce");
         request.add_header("x-amz-target", "SageMaker.DescribeWorkforce");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25453,6 +26397,7 @@ impl SageMaker for SageMakerClient {
         input: DescribeWorkteamRequest,
     ) -> Result<DescribeWorkteamResponse, RusotoError<DescribeWorkteamError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DescribeWorkteam");
         request.add_header("x-amz-target", "SageMaker.DescribeWorkteam");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25473,6 +26418,7 @@ impl SageMaker for SageMakerClient {
         RusotoError<DisableSagemakerServicecatalogPortfolioError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisableSagemakerServicecatalogPortfolio");
         request.add_header(
             "x-amz-target",
             "SageMaker.DisableSagemakerServicecatalogPortfolio",
@@ -25498,6 +26444,7 @@ impl SageMaker for SageMakerClient {
     ) -> Result<DisassociateTrialComponentResponse, RusotoError<DisassociateTrialComponentError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("DisassociateTrialComponent");
         request.add_header("x-amz-target", "SageMaker.DisassociateTrialComponent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25519,6 +26466,7 @@ impl SageMaker for SageMakerClient {
         RusotoError<EnableSagemakerServicecatalogPortfolioError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("EnableSagemakerServicecatalogPortfolio");
         request.add_header(
             "x-amz-target",
             "SageMaker.EnableSagemakerServicecatalogPortfolio",
@@ -25543,6 +26491,7 @@ impl SageMaker for SageMakerClient {
         input: GetDeviceFleetReportRequest,
     ) -> Result<GetDeviceFleetReportResponse, RusotoError<GetDeviceFleetReportError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetDeviceFleetReport");
         request.add_header("x-amz-target", "SageMaker.GetDeviceFleetReport");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25563,6 +26512,7 @@ impl SageMaker for SageMakerClient {
     ) -> Result<GetModelPackageGroupPolicyOutput, RusotoError<GetModelPackageGroupPolicyError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetModelPackageGroupPolicy");
         request.add_header("x-amz-target", "SageMaker.GetModelPackageGroupPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25584,6 +26534,7 @@ impl SageMaker for SageMakerClient {
         RusotoError<GetSagemakerServicecatalogPortfolioStatusError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetSagemakerServicecatalogPortfolioStatus");
         request.add_header(
             "x-amz-target",
             "SageMaker.GetSagemakerServicecatalogPortfolioStatus",
@@ -25608,6 +26559,7 @@ impl SageMaker for SageMakerClient {
         input: GetSearchSuggestionsRequest,
     ) -> Result<GetSearchSuggestionsResponse, RusotoError<GetSearchSuggestionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("GetSearchSuggestions");
         request.add_header("x-amz-target", "SageMaker.GetSearchSuggestions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25627,6 +26579,7 @@ impl SageMaker for SageMakerClient {
         input: ListActionsRequest,
     ) -> Result<ListActionsResponse, RusotoError<ListActionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListActions");
         request.add_header("x-amz-target", "SageMaker.ListActions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25645,6 +26598,7 @@ impl SageMaker for SageMakerClient {
         input: ListAlgorithmsInput,
     ) -> Result<ListAlgorithmsOutput, RusotoError<ListAlgorithmsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListAlgorithms");
         request.add_header("x-amz-target", "SageMaker.ListAlgorithms");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25663,6 +26617,7 @@ impl SageMaker for SageMakerClient {
         input: ListAppImageConfigsRequest,
     ) -> Result<ListAppImageConfigsResponse, RusotoError<ListAppImageConfigsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListAppImageConfigs");
         request.add_header("x-amz-target", "SageMaker.ListAppImageConfigs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25681,6 +26636,7 @@ impl SageMaker for SageMakerClient {
         input: ListAppsRequest,
     ) -> Result<ListAppsResponse, RusotoError<ListAppsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListApps");
         request.add_header("x-amz-target", "SageMaker.ListApps");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25699,6 +26655,7 @@ impl SageMaker for SageMakerClient {
         input: ListArtifactsRequest,
     ) -> Result<ListArtifactsResponse, RusotoError<ListArtifactsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListArtifacts");
         request.add_header("x-amz-target", "SageMaker.ListArtifacts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25717,6 +26674,7 @@ impl SageMaker for SageMakerClient {
         input: ListAssociationsRequest,
     ) -> Result<ListAssociationsResponse, RusotoError<ListAssociationsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListAssociations");
         request.add_header("x-amz-target", "SageMaker.ListAssociations");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25735,6 +26693,7 @@ impl SageMaker for SageMakerClient {
         input: ListAutoMLJobsRequest,
     ) -> Result<ListAutoMLJobsResponse, RusotoError<ListAutoMLJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListAutoMLJobs");
         request.add_header("x-amz-target", "SageMaker.ListAutoMLJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25754,6 +26713,7 @@ impl SageMaker for SageMakerClient {
     ) -> Result<ListCandidatesForAutoMLJobResponse, RusotoError<ListCandidatesForAutoMLJobError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListCandidatesForAutoMLJob");
         request.add_header("x-amz-target", "SageMaker.ListCandidatesForAutoMLJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25773,6 +26733,7 @@ impl SageMaker for SageMakerClient {
         input: ListCodeRepositoriesInput,
     ) -> Result<ListCodeRepositoriesOutput, RusotoError<ListCodeRepositoriesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListCodeRepositories");
         request.add_header("x-amz-target", "SageMaker.ListCodeRepositories");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25791,6 +26752,7 @@ impl SageMaker for SageMakerClient {
         input: ListCompilationJobsRequest,
     ) -> Result<ListCompilationJobsResponse, RusotoError<ListCompilationJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListCompilationJobs");
         request.add_header("x-amz-target", "SageMaker.ListCompilationJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25809,6 +26771,7 @@ impl SageMaker for SageMakerClient {
         input: ListContextsRequest,
     ) -> Result<ListContextsResponse, RusotoError<ListContextsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListContexts");
         request.add_header("x-amz-target", "SageMaker.ListContexts");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25830,6 +26793,7 @@ impl SageMaker for SageMakerClient {
         RusotoError<ListDataQualityJobDefinitionsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListDataQualityJobDefinitions");
         request.add_header("x-amz-target", "SageMaker.ListDataQualityJobDefinitions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25849,6 +26813,7 @@ impl SageMaker for SageMakerClient {
         input: ListDeviceFleetsRequest,
     ) -> Result<ListDeviceFleetsResponse, RusotoError<ListDeviceFleetsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListDeviceFleets");
         request.add_header("x-amz-target", "SageMaker.ListDeviceFleets");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25867,6 +26832,7 @@ impl SageMaker for SageMakerClient {
         input: ListDevicesRequest,
     ) -> Result<ListDevicesResponse, RusotoError<ListDevicesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListDevices");
         request.add_header("x-amz-target", "SageMaker.ListDevices");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25885,6 +26851,7 @@ impl SageMaker for SageMakerClient {
         input: ListDomainsRequest,
     ) -> Result<ListDomainsResponse, RusotoError<ListDomainsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListDomains");
         request.add_header("x-amz-target", "SageMaker.ListDomains");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25903,6 +26870,7 @@ impl SageMaker for SageMakerClient {
         input: ListEdgePackagingJobsRequest,
     ) -> Result<ListEdgePackagingJobsResponse, RusotoError<ListEdgePackagingJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListEdgePackagingJobs");
         request.add_header("x-amz-target", "SageMaker.ListEdgePackagingJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25922,6 +26890,7 @@ impl SageMaker for SageMakerClient {
         input: ListEndpointConfigsInput,
     ) -> Result<ListEndpointConfigsOutput, RusotoError<ListEndpointConfigsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListEndpointConfigs");
         request.add_header("x-amz-target", "SageMaker.ListEndpointConfigs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25940,6 +26909,7 @@ impl SageMaker for SageMakerClient {
         input: ListEndpointsInput,
     ) -> Result<ListEndpointsOutput, RusotoError<ListEndpointsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListEndpoints");
         request.add_header("x-amz-target", "SageMaker.ListEndpoints");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25958,6 +26928,7 @@ impl SageMaker for SageMakerClient {
         input: ListExperimentsRequest,
     ) -> Result<ListExperimentsResponse, RusotoError<ListExperimentsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListExperiments");
         request.add_header("x-amz-target", "SageMaker.ListExperiments");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25976,6 +26947,7 @@ impl SageMaker for SageMakerClient {
         input: ListFeatureGroupsRequest,
     ) -> Result<ListFeatureGroupsResponse, RusotoError<ListFeatureGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListFeatureGroups");
         request.add_header("x-amz-target", "SageMaker.ListFeatureGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -25994,6 +26966,7 @@ impl SageMaker for SageMakerClient {
         input: ListFlowDefinitionsRequest,
     ) -> Result<ListFlowDefinitionsResponse, RusotoError<ListFlowDefinitionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListFlowDefinitions");
         request.add_header("x-amz-target", "SageMaker.ListFlowDefinitions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26012,6 +26985,7 @@ impl SageMaker for SageMakerClient {
         input: ListHumanTaskUisRequest,
     ) -> Result<ListHumanTaskUisResponse, RusotoError<ListHumanTaskUisError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListHumanTaskUis");
         request.add_header("x-amz-target", "SageMaker.ListHumanTaskUis");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26031,6 +27005,7 @@ impl SageMaker for SageMakerClient {
     ) -> Result<ListHyperParameterTuningJobsResponse, RusotoError<ListHyperParameterTuningJobsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListHyperParameterTuningJobs");
         request.add_header("x-amz-target", "SageMaker.ListHyperParameterTuningJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26050,6 +27025,7 @@ impl SageMaker for SageMakerClient {
         input: ListImageVersionsRequest,
     ) -> Result<ListImageVersionsResponse, RusotoError<ListImageVersionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListImageVersions");
         request.add_header("x-amz-target", "SageMaker.ListImageVersions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26068,6 +27044,7 @@ impl SageMaker for SageMakerClient {
         input: ListImagesRequest,
     ) -> Result<ListImagesResponse, RusotoError<ListImagesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListImages");
         request.add_header("x-amz-target", "SageMaker.ListImages");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26086,6 +27063,7 @@ impl SageMaker for SageMakerClient {
         input: ListLabelingJobsRequest,
     ) -> Result<ListLabelingJobsResponse, RusotoError<ListLabelingJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListLabelingJobs");
         request.add_header("x-amz-target", "SageMaker.ListLabelingJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26105,6 +27083,7 @@ impl SageMaker for SageMakerClient {
     ) -> Result<ListLabelingJobsForWorkteamResponse, RusotoError<ListLabelingJobsForWorkteamError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListLabelingJobsForWorkteam");
         request.add_header("x-amz-target", "SageMaker.ListLabelingJobsForWorkteam");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26125,6 +27104,7 @@ impl SageMaker for SageMakerClient {
     ) -> Result<ListModelBiasJobDefinitionsResponse, RusotoError<ListModelBiasJobDefinitionsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListModelBiasJobDefinitions");
         request.add_header("x-amz-target", "SageMaker.ListModelBiasJobDefinitions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26147,6 +27127,7 @@ impl SageMaker for SageMakerClient {
         RusotoError<ListModelExplainabilityJobDefinitionsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListModelExplainabilityJobDefinitions");
         request.add_header(
             "x-amz-target",
             "SageMaker.ListModelExplainabilityJobDefinitions",
@@ -26172,6 +27153,7 @@ impl SageMaker for SageMakerClient {
         input: ListModelPackageGroupsInput,
     ) -> Result<ListModelPackageGroupsOutput, RusotoError<ListModelPackageGroupsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListModelPackageGroups");
         request.add_header("x-amz-target", "SageMaker.ListModelPackageGroups");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26191,6 +27173,7 @@ impl SageMaker for SageMakerClient {
         input: ListModelPackagesInput,
     ) -> Result<ListModelPackagesOutput, RusotoError<ListModelPackagesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListModelPackages");
         request.add_header("x-amz-target", "SageMaker.ListModelPackages");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26212,6 +27195,7 @@ impl SageMaker for SageMakerClient {
         RusotoError<ListModelQualityJobDefinitionsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListModelQualityJobDefinitions");
         request.add_header("x-amz-target", "SageMaker.ListModelQualityJobDefinitions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26231,6 +27215,7 @@ impl SageMaker for SageMakerClient {
         input: ListModelsInput,
     ) -> Result<ListModelsOutput, RusotoError<ListModelsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListModels");
         request.add_header("x-amz-target", "SageMaker.ListModels");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26249,6 +27234,7 @@ impl SageMaker for SageMakerClient {
         input: ListMonitoringExecutionsRequest,
     ) -> Result<ListMonitoringExecutionsResponse, RusotoError<ListMonitoringExecutionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListMonitoringExecutions");
         request.add_header("x-amz-target", "SageMaker.ListMonitoringExecutions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26268,6 +27254,7 @@ impl SageMaker for SageMakerClient {
         input: ListMonitoringSchedulesRequest,
     ) -> Result<ListMonitoringSchedulesResponse, RusotoError<ListMonitoringSchedulesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListMonitoringSchedules");
         request.add_header("x-amz-target", "SageMaker.ListMonitoringSchedules");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26290,6 +27277,7 @@ impl SageMaker for SageMakerClient {
         RusotoError<ListNotebookInstanceLifecycleConfigsError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListNotebookInstanceLifecycleConfigs");
         request.add_header(
             "x-amz-target",
             "SageMaker.ListNotebookInstanceLifecycleConfigs",
@@ -26315,6 +27303,7 @@ impl SageMaker for SageMakerClient {
         input: ListNotebookInstancesInput,
     ) -> Result<ListNotebookInstancesOutput, RusotoError<ListNotebookInstancesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListNotebookInstances");
         request.add_header("x-amz-target", "SageMaker.ListNotebookInstances");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26334,6 +27323,7 @@ impl SageMaker for SageMakerClient {
     ) -> Result<ListPipelineExecutionStepsResponse, RusotoError<ListPipelineExecutionStepsError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListPipelineExecutionSteps");
         request.add_header("x-amz-target", "SageMaker.ListPipelineExecutionSteps");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26353,6 +27343,7 @@ impl SageMaker for SageMakerClient {
         input: ListPipelineExecutionsRequest,
     ) -> Result<ListPipelineExecutionsResponse, RusotoError<ListPipelineExecutionsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListPipelineExecutions");
         request.add_header("x-amz-target", "SageMaker.ListPipelineExecutions");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26375,6 +27366,7 @@ impl SageMaker for SageMakerClient {
         RusotoError<ListPipelineParametersForExecutionError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListPipelineParametersForExecution");
         request.add_header(
             "x-amz-target",
             "SageMaker.ListPipelineParametersForExecution",
@@ -26400,6 +27392,7 @@ impl SageMaker for SageMakerClient {
         input: ListPipelinesRequest,
     ) -> Result<ListPipelinesResponse, RusotoError<ListPipelinesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListPipelines");
         request.add_header("x-amz-target", "SageMaker.ListPipelines");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26418,6 +27411,7 @@ impl SageMaker for SageMakerClient {
         input: ListProcessingJobsRequest,
     ) -> Result<ListProcessingJobsResponse, RusotoError<ListProcessingJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListProcessingJobs");
         request.add_header("x-amz-target", "SageMaker.ListProcessingJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26436,6 +27430,7 @@ impl SageMaker for SageMakerClient {
         input: ListProjectsInput,
     ) -> Result<ListProjectsOutput, RusotoError<ListProjectsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListProjects");
         request.add_header("x-amz-target", "SageMaker.ListProjects");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26454,6 +27449,7 @@ impl SageMaker for SageMakerClient {
         input: ListSubscribedWorkteamsRequest,
     ) -> Result<ListSubscribedWorkteamsResponse, RusotoError<ListSubscribedWorkteamsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListSubscribedWorkteams");
         request.add_header("x-amz-target", "SageMaker.ListSubscribedWorkteams");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26473,6 +27469,7 @@ impl SageMaker for SageMakerClient {
         input: ListTagsInput,
     ) -> Result<ListTagsOutput, RusotoError<ListTagsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTags");
         request.add_header("x-amz-target", "SageMaker.ListTags");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26491,6 +27488,7 @@ impl SageMaker for SageMakerClient {
         input: ListTrainingJobsRequest,
     ) -> Result<ListTrainingJobsResponse, RusotoError<ListTrainingJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTrainingJobs");
         request.add_header("x-amz-target", "SageMaker.ListTrainingJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26512,6 +27510,7 @@ impl SageMaker for SageMakerClient {
         RusotoError<ListTrainingJobsForHyperParameterTuningJobError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTrainingJobsForHyperParameterTuningJob");
         request.add_header(
             "x-amz-target",
             "SageMaker.ListTrainingJobsForHyperParameterTuningJob",
@@ -26537,6 +27536,7 @@ impl SageMaker for SageMakerClient {
         input: ListTransformJobsRequest,
     ) -> Result<ListTransformJobsResponse, RusotoError<ListTransformJobsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTransformJobs");
         request.add_header("x-amz-target", "SageMaker.ListTransformJobs");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26555,6 +27555,7 @@ impl SageMaker for SageMakerClient {
         input: ListTrialComponentsRequest,
     ) -> Result<ListTrialComponentsResponse, RusotoError<ListTrialComponentsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTrialComponents");
         request.add_header("x-amz-target", "SageMaker.ListTrialComponents");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26573,6 +27574,7 @@ impl SageMaker for SageMakerClient {
         input: ListTrialsRequest,
     ) -> Result<ListTrialsResponse, RusotoError<ListTrialsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListTrials");
         request.add_header("x-amz-target", "SageMaker.ListTrials");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26591,6 +27593,7 @@ impl SageMaker for SageMakerClient {
         input: ListUserProfilesRequest,
     ) -> Result<ListUserProfilesResponse, RusotoError<ListUserProfilesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListUserProfiles");
         request.add_header("x-amz-target", "SageMaker.ListUserProfiles");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26609,6 +27612,7 @@ impl SageMaker for SageMakerClient {
         input: ListWorkforcesRequest,
     ) -> Result<ListWorkforcesResponse, RusotoError<ListWorkforcesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListWorkforces");
         request.add_header("x-amz-target", "SageMaker.ListWorkforces");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26627,6 +27631,7 @@ impl SageMaker for SageMakerClient {
         input: ListWorkteamsRequest,
     ) -> Result<ListWorkteamsResponse, RusotoError<ListWorkteamsError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("ListWorkteams");
         request.add_header("x-amz-target", "SageMaker.ListWorkteams");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26646,6 +27651,7 @@ impl SageMaker for SageMakerClient {
     ) -> Result<PutModelPackageGroupPolicyOutput, RusotoError<PutModelPackageGroupPolicyError>>
     {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("PutModelPackageGroupPolicy");
         request.add_header("x-amz-target", "SageMaker.PutModelPackageGroupPolicy");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26665,6 +27671,7 @@ impl SageMaker for SageMakerClient {
         input: RegisterDevicesRequest,
     ) -> Result<(), RusotoError<RegisterDevicesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RegisterDevices");
         request.add_header("x-amz-target", "SageMaker.RegisterDevices");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26682,6 +27689,7 @@ impl SageMaker for SageMakerClient {
         input: RenderUiTemplateRequest,
     ) -> Result<RenderUiTemplateResponse, RusotoError<RenderUiTemplateError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("RenderUiTemplate");
         request.add_header("x-amz-target", "SageMaker.RenderUiTemplate");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26700,6 +27708,7 @@ impl SageMaker for SageMakerClient {
         input: SearchRequest,
     ) -> Result<SearchResponse, RusotoError<SearchError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("Search");
         request.add_header("x-amz-target", "SageMaker.Search");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26718,6 +27727,7 @@ impl SageMaker for SageMakerClient {
         input: StartMonitoringScheduleRequest,
     ) -> Result<(), RusotoError<StartMonitoringScheduleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartMonitoringSchedule");
         request.add_header("x-amz-target", "SageMaker.StartMonitoringSchedule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26735,6 +27745,7 @@ impl SageMaker for SageMakerClient {
         input: StartNotebookInstanceInput,
     ) -> Result<(), RusotoError<StartNotebookInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartNotebookInstance");
         request.add_header("x-amz-target", "SageMaker.StartNotebookInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26752,6 +27763,7 @@ impl SageMaker for SageMakerClient {
         input: StartPipelineExecutionRequest,
     ) -> Result<StartPipelineExecutionResponse, RusotoError<StartPipelineExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StartPipelineExecution");
         request.add_header("x-amz-target", "SageMaker.StartPipelineExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26771,6 +27783,7 @@ impl SageMaker for SageMakerClient {
         input: StopAutoMLJobRequest,
     ) -> Result<(), RusotoError<StopAutoMLJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopAutoMLJob");
         request.add_header("x-amz-target", "SageMaker.StopAutoMLJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26788,6 +27801,7 @@ impl SageMaker for SageMakerClient {
         input: StopCompilationJobRequest,
     ) -> Result<(), RusotoError<StopCompilationJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopCompilationJob");
         request.add_header("x-amz-target", "SageMaker.StopCompilationJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26805,6 +27819,7 @@ impl SageMaker for SageMakerClient {
         input: StopEdgePackagingJobRequest,
     ) -> Result<(), RusotoError<StopEdgePackagingJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopEdgePackagingJob");
         request.add_header("x-amz-target", "SageMaker.StopEdgePackagingJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26822,6 +27837,7 @@ impl SageMaker for SageMakerClient {
         input: StopHyperParameterTuningJobRequest,
     ) -> Result<(), RusotoError<StopHyperParameterTuningJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopHyperParameterTuningJob");
         request.add_header("x-amz-target", "SageMaker.StopHyperParameterTuningJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26839,6 +27855,7 @@ impl SageMaker for SageMakerClient {
         input: StopLabelingJobRequest,
     ) -> Result<(), RusotoError<StopLabelingJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopLabelingJob");
         request.add_header("x-amz-target", "SageMaker.StopLabelingJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26856,6 +27873,7 @@ impl SageMaker for SageMakerClient {
         input: StopMonitoringScheduleRequest,
     ) -> Result<(), RusotoError<StopMonitoringScheduleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopMonitoringSchedule");
         request.add_header("x-amz-target", "SageMaker.StopMonitoringSchedule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26873,6 +27891,7 @@ impl SageMaker for SageMakerClient {
         input: StopNotebookInstanceInput,
     ) -> Result<(), RusotoError<StopNotebookInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopNotebookInstance");
         request.add_header("x-amz-target", "SageMaker.StopNotebookInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26890,6 +27909,7 @@ impl SageMaker for SageMakerClient {
         input: StopPipelineExecutionRequest,
     ) -> Result<StopPipelineExecutionResponse, RusotoError<StopPipelineExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopPipelineExecution");
         request.add_header("x-amz-target", "SageMaker.StopPipelineExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26909,6 +27929,7 @@ impl SageMaker for SageMakerClient {
         input: StopProcessingJobRequest,
     ) -> Result<(), RusotoError<StopProcessingJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopProcessingJob");
         request.add_header("x-amz-target", "SageMaker.StopProcessingJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26926,6 +27947,7 @@ impl SageMaker for SageMakerClient {
         input: StopTrainingJobRequest,
     ) -> Result<(), RusotoError<StopTrainingJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopTrainingJob");
         request.add_header("x-amz-target", "SageMaker.StopTrainingJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26943,6 +27965,7 @@ impl SageMaker for SageMakerClient {
         input: StopTransformJobRequest,
     ) -> Result<(), RusotoError<StopTransformJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("StopTransformJob");
         request.add_header("x-amz-target", "SageMaker.StopTransformJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26960,6 +27983,7 @@ impl SageMaker for SageMakerClient {
         input: UpdateActionRequest,
     ) -> Result<UpdateActionResponse, RusotoError<UpdateActionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateAction");
         request.add_header("x-amz-target", "SageMaker.UpdateAction");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26978,6 +28002,7 @@ impl SageMaker for SageMakerClient {
         input: UpdateAppImageConfigRequest,
     ) -> Result<UpdateAppImageConfigResponse, RusotoError<UpdateAppImageConfigError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateAppImageConfig");
         request.add_header("x-amz-target", "SageMaker.UpdateAppImageConfig");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -26997,6 +28022,7 @@ impl SageMaker for SageMakerClient {
         input: UpdateArtifactRequest,
     ) -> Result<UpdateArtifactResponse, RusotoError<UpdateArtifactError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateArtifact");
         request.add_header("x-amz-target", "SageMaker.UpdateArtifact");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -27015,6 +28041,7 @@ impl SageMaker for SageMakerClient {
         input: UpdateCodeRepositoryInput,
     ) -> Result<UpdateCodeRepositoryOutput, RusotoError<UpdateCodeRepositoryError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateCodeRepository");
         request.add_header("x-amz-target", "SageMaker.UpdateCodeRepository");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -27033,6 +28060,7 @@ impl SageMaker for SageMakerClient {
         input: UpdateContextRequest,
     ) -> Result<UpdateContextResponse, RusotoError<UpdateContextError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateContext");
         request.add_header("x-amz-target", "SageMaker.UpdateContext");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -27051,6 +28079,7 @@ impl SageMaker for SageMakerClient {
         input: UpdateDeviceFleetRequest,
     ) -> Result<(), RusotoError<UpdateDeviceFleetError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateDeviceFleet");
         request.add_header("x-amz-target", "SageMaker.UpdateDeviceFleet");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -27068,6 +28097,7 @@ impl SageMaker for SageMakerClient {
         input: UpdateDevicesRequest,
     ) -> Result<(), RusotoError<UpdateDevicesError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateDevices");
         request.add_header("x-amz-target", "SageMaker.UpdateDevices");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -27085,6 +28115,7 @@ impl SageMaker for SageMakerClient {
         input: UpdateDomainRequest,
     ) -> Result<UpdateDomainResponse, RusotoError<UpdateDomainError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateDomain");
         request.add_header("x-amz-target", "SageMaker.UpdateDomain");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -27103,6 +28134,7 @@ impl SageMaker for SageMakerClient {
         input: UpdateEndpointInput,
     ) -> Result<UpdateEndpointOutput, RusotoError<UpdateEndpointError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateEndpoint");
         request.add_header("x-amz-target", "SageMaker.UpdateEndpoint");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -27124,6 +28156,7 @@ impl SageMaker for SageMakerClient {
         RusotoError<UpdateEndpointWeightsAndCapacitiesError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateEndpointWeightsAndCapacities");
         request.add_header(
             "x-amz-target",
             "SageMaker.UpdateEndpointWeightsAndCapacities",
@@ -27149,6 +28182,7 @@ impl SageMaker for SageMakerClient {
         input: UpdateExperimentRequest,
     ) -> Result<UpdateExperimentResponse, RusotoError<UpdateExperimentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateExperiment");
         request.add_header("x-amz-target", "SageMaker.UpdateExperiment");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -27167,6 +28201,7 @@ impl SageMaker for SageMakerClient {
         input: UpdateImageRequest,
     ) -> Result<UpdateImageResponse, RusotoError<UpdateImageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateImage");
         request.add_header("x-amz-target", "SageMaker.UpdateImage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -27185,6 +28220,7 @@ impl SageMaker for SageMakerClient {
         input: UpdateModelPackageInput,
     ) -> Result<UpdateModelPackageOutput, RusotoError<UpdateModelPackageError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateModelPackage");
         request.add_header("x-amz-target", "SageMaker.UpdateModelPackage");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -27203,6 +28239,7 @@ impl SageMaker for SageMakerClient {
         input: UpdateMonitoringScheduleRequest,
     ) -> Result<UpdateMonitoringScheduleResponse, RusotoError<UpdateMonitoringScheduleError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateMonitoringSchedule");
         request.add_header("x-amz-target", "SageMaker.UpdateMonitoringSchedule");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -27222,6 +28259,7 @@ impl SageMaker for SageMakerClient {
         input: UpdateNotebookInstanceInput,
     ) -> Result<UpdateNotebookInstanceOutput, RusotoError<UpdateNotebookInstanceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateNotebookInstance");
         request.add_header("x-amz-target", "SageMaker.UpdateNotebookInstance");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -27244,6 +28282,7 @@ impl SageMaker for SageMakerClient {
         RusotoError<UpdateNotebookInstanceLifecycleConfigError>,
     > {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateNotebookInstanceLifecycleConfig");
         request.add_header(
             "x-amz-target",
             "SageMaker.UpdateNotebookInstanceLifecycleConfig",
@@ -27269,6 +28308,7 @@ impl SageMaker for SageMakerClient {
         input: UpdatePipelineRequest,
     ) -> Result<UpdatePipelineResponse, RusotoError<UpdatePipelineError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdatePipeline");
         request.add_header("x-amz-target", "SageMaker.UpdatePipeline");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -27287,6 +28327,7 @@ impl SageMaker for SageMakerClient {
         input: UpdatePipelineExecutionRequest,
     ) -> Result<UpdatePipelineExecutionResponse, RusotoError<UpdatePipelineExecutionError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdatePipelineExecution");
         request.add_header("x-amz-target", "SageMaker.UpdatePipelineExecution");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -27306,6 +28347,7 @@ impl SageMaker for SageMakerClient {
         input: UpdateTrainingJobRequest,
     ) -> Result<UpdateTrainingJobResponse, RusotoError<UpdateTrainingJobError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateTrainingJob");
         request.add_header("x-amz-target", "SageMaker.UpdateTrainingJob");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -27324,6 +28366,7 @@ impl SageMaker for SageMakerClient {
         input: UpdateTrialRequest,
     ) -> Result<UpdateTrialResponse, RusotoError<UpdateTrialError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateTrial");
         request.add_header("x-amz-target", "SageMaker.UpdateTrial");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -27342,6 +28385,7 @@ impl SageMaker for SageMakerClient {
         input: UpdateTrialComponentRequest,
     ) -> Result<UpdateTrialComponentResponse, RusotoError<UpdateTrialComponentError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateTrialComponent");
         request.add_header("x-amz-target", "SageMaker.UpdateTrialComponent");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -27361,6 +28405,7 @@ impl SageMaker for SageMakerClient {
         input: UpdateUserProfileRequest,
     ) -> Result<UpdateUserProfileResponse, RusotoError<UpdateUserProfileError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateUserProfile");
         request.add_header("x-amz-target", "SageMaker.UpdateUserProfile");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -27379,6 +28424,7 @@ impl SageMaker for SageMakerClient {
         input: UpdateWorkforceRequest,
     ) -> Result<UpdateWorkforceResponse, RusotoError<UpdateWorkforceError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateWorkforce");
         request.add_header("x-amz-target", "SageMaker.UpdateWorkforce");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -27397,6 +28443,7 @@ impl SageMaker for SageMakerClient {
         input: UpdateWorkteamRequest,
     ) -> Result<UpdateWorkteamResponse, RusotoError<UpdateWorkteamError>> {
         let mut request = self.new_signed_request("POST", "/");
+        request.set_operation("UpdateWorkteam");
         request.add_header("x-amz-target", "SageMaker.UpdateWorkteam");
         let encoded = serde_json::to_string(&input).unwrap();
         request.set_payload(Some(encoded));
@@ -27409,3 +28456,2169 @@ impl SageMaker for SageMakerClient {
         proto::json::ResponsePayload::new(&response).deserialize::<UpdateWorkteamResponse, _>()
     }
 }
+
+/// A blocking client for the SageMaker API, running the calls of a `SageMakerClient`
+/// on a `BlockingRuntime`.
+///
+/// The outputs are those of the `SageMakerClient`: their streaming bodies are still
+/// `ByteStream`s, read with `ByteStream::into_blocking_read` outside of any async runtime.
+#[cfg(feature = "blocking")]
+#[derive(Clone)]
+pub struct SageMakerBlockingClient {
+    client: SageMakerClient,
+    runtime: rusoto_core::BlockingRuntime,
+}
+
+#[cfg(feature = "blocking")]
+impl SageMakerBlockingClient {
+    /// Creates a client using the default credentials provider and tls client, running
+    /// its calls on the shared `BlockingRuntime`.
+    pub fn new(region: region::Region) -> SageMakerBlockingClient {
+        SageMakerBlockingClient::from_client(SageMakerClient::new(region))
+    }
+
+    /// Creates a client using the given request dispatcher and credentials provider,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with<P, D>(
+        request_dispatcher: D,
+        credentials_provider: P,
+        region: region::Region,
+    ) -> SageMakerBlockingClient
+    where
+        P: ProvideAwsCredentials + Send + Sync + 'static,
+        D: DispatchSignedRequest + Send + Sync + 'static,
+    {
+        SageMakerBlockingClient::from_client(SageMakerClient::new_with(
+            request_dispatcher,
+            credentials_provider,
+            region,
+        ))
+    }
+
+    /// Creates a client sharing the dispatcher and credentials of the given `Client`,
+    /// running its calls on the shared `BlockingRuntime`.
+    pub fn new_with_client(client: Client, region: region::Region) -> SageMakerBlockingClient {
+        SageMakerBlockingClient::from_client(SageMakerClient::new_with_client(client, region))
+    }
+
+    /// Creates a client using the given configuration, running its calls on the
+    /// shared `BlockingRuntime`.
+    pub fn new_with_config(config: &AwsConfig) -> SageMakerBlockingClient {
+        SageMakerBlockingClient::from_client(SageMakerClient::new_with_config(config))
+    }
+
+    /// Wraps an async client, running its calls on the shared `BlockingRuntime`.
+    pub fn from_client(client: SageMakerClient) -> SageMakerBlockingClient {
+        SageMakerBlockingClient {
+            client,
+            runtime: rusoto_core::BlockingRuntime::shared(),
+        }
+    }
+
+    /// Returns a copy of the client running its calls on the given runtime.
+    pub fn with_runtime(&self, runtime: rusoto_core::BlockingRuntime) -> SageMakerBlockingClient {
+        SageMakerBlockingClient {
+            client: self.client.clone(),
+            runtime,
+        }
+    }
+
+    /// Returns a copy of the client applying the given options to every call made through it.
+    pub fn with_options(&self, options: CallOptions) -> SageMakerBlockingClient {
+        SageMakerBlockingClient {
+            client: self.client.with_options(options),
+            runtime: self.runtime.clone(),
+        }
+    }
+
+    /// Blocking version of [`SageMaker::add_association`].
+    pub fn add_association(
+        &self,
+        input: AddAssociationRequest,
+    ) -> Result<AddAssociationResponse, RusotoError<AddAssociationError>> {
+        self.runtime.block_on(self.client.add_association(input))
+    }
+
+    /// Blocking version of [`SageMaker::add_tags`].
+    pub fn add_tags(
+        &self,
+        input: AddTagsInput,
+    ) -> Result<AddTagsOutput, RusotoError<AddTagsError>> {
+        self.runtime.block_on(self.client.add_tags(input))
+    }
+
+    /// Blocking version of [`SageMaker::associate_trial_component`].
+    pub fn associate_trial_component(
+        &self,
+        input: AssociateTrialComponentRequest,
+    ) -> Result<AssociateTrialComponentResponse, RusotoError<AssociateTrialComponentError>> {
+        self.runtime
+            .block_on(self.client.associate_trial_component(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_action`].
+    pub fn create_action(
+        &self,
+        input: CreateActionRequest,
+    ) -> Result<CreateActionResponse, RusotoError<CreateActionError>> {
+        self.runtime.block_on(self.client.create_action(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_algorithm`].
+    pub fn create_algorithm(
+        &self,
+        input: CreateAlgorithmInput,
+    ) -> Result<CreateAlgorithmOutput, RusotoError<CreateAlgorithmError>> {
+        self.runtime.block_on(self.client.create_algorithm(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_app`].
+    pub fn create_app(
+        &self,
+        input: CreateAppRequest,
+    ) -> Result<CreateAppResponse, RusotoError<CreateAppError>> {
+        self.runtime.block_on(self.client.create_app(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_app_image_config`].
+    pub fn create_app_image_config(
+        &self,
+        input: CreateAppImageConfigRequest,
+    ) -> Result<CreateAppImageConfigResponse, RusotoError<CreateAppImageConfigError>> {
+        self.runtime
+            .block_on(self.client.create_app_image_config(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_artifact`].
+    pub fn create_artifact(
+        &self,
+        input: CreateArtifactRequest,
+    ) -> Result<CreateArtifactResponse, RusotoError<CreateArtifactError>> {
+        self.runtime.block_on(self.client.create_artifact(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_auto_ml_job`].
+    pub fn create_auto_ml_job(
+        &self,
+        input: CreateAutoMLJobRequest,
+    ) -> Result<CreateAutoMLJobResponse, RusotoError<CreateAutoMLJobError>> {
+        self.runtime.block_on(self.client.create_auto_ml_job(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_code_repository`].
+    pub fn create_code_repository(
+        &self,
+        input: CreateCodeRepositoryInput,
+    ) -> Result<CreateCodeRepositoryOutput, RusotoError<CreateCodeRepositoryError>> {
+        self.runtime
+            .block_on(self.client.create_code_repository(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_compilation_job`].
+    pub fn create_compilation_job(
+        &self,
+        input: CreateCompilationJobRequest,
+    ) -> Result<CreateCompilationJobResponse, RusotoError<CreateCompilationJobError>> {
+        self.runtime
+            .block_on(self.client.create_compilation_job(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_context`].
+    pub fn create_context(
+        &self,
+        input: CreateContextRequest,
+    ) -> Result<CreateContextResponse, RusotoError<CreateContextError>> {
+        self.runtime.block_on(self.client.create_context(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_data_quality_job_definition`].
+    pub fn create_data_quality_job_definition(
+        &self,
+        input: CreateDataQualityJobDefinitionRequest,
+    ) -> Result<
+        CreateDataQualityJobDefinitionResponse,
+        RusotoError<CreateDataQualityJobDefinitionError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_data_quality_job_definition(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_device_fleet`].
+    pub fn create_device_fleet(
+        &self,
+        input: CreateDeviceFleetRequest,
+    ) -> Result<(), RusotoError<CreateDeviceFleetError>> {
+        self.runtime
+            .block_on(self.client.create_device_fleet(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_domain`].
+    pub fn create_domain(
+        &self,
+        input: CreateDomainRequest,
+    ) -> Result<CreateDomainResponse, RusotoError<CreateDomainError>> {
+        self.runtime.block_on(self.client.create_domain(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_edge_packaging_job`].
+    pub fn create_edge_packaging_job(
+        &self,
+        input: CreateEdgePackagingJobRequest,
+    ) -> Result<(), RusotoError<CreateEdgePackagingJobError>> {
+        self.runtime
+            .block_on(self.client.create_edge_packaging_job(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_endpoint`].
+    pub fn create_endpoint(
+        &self,
+        input: CreateEndpointInput,
+    ) -> Result<CreateEndpointOutput, RusotoError<CreateEndpointError>> {
+        self.runtime.block_on(self.client.create_endpoint(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_endpoint_config`].
+    pub fn create_endpoint_config(
+        &self,
+        input: CreateEndpointConfigInput,
+    ) -> Result<CreateEndpointConfigOutput, RusotoError<CreateEndpointConfigError>> {
+        self.runtime
+            .block_on(self.client.create_endpoint_config(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_experiment`].
+    pub fn create_experiment(
+        &self,
+        input: CreateExperimentRequest,
+    ) -> Result<CreateExperimentResponse, RusotoError<CreateExperimentError>> {
+        self.runtime.block_on(self.client.create_experiment(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_feature_group`].
+    pub fn create_feature_group(
+        &self,
+        input: CreateFeatureGroupRequest,
+    ) -> Result<CreateFeatureGroupResponse, RusotoError<CreateFeatureGroupError>> {
+        self.runtime
+            .block_on(self.client.create_feature_group(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_flow_definition`].
+    pub fn create_flow_definition(
+        &self,
+        input: CreateFlowDefinitionRequest,
+    ) -> Result<CreateFlowDefinitionResponse, RusotoError<CreateFlowDefinitionError>> {
+        self.runtime
+            .block_on(self.client.create_flow_definition(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_human_task_ui`].
+    pub fn create_human_task_ui(
+        &self,
+        input: CreateHumanTaskUiRequest,
+    ) -> Result<CreateHumanTaskUiResponse, RusotoError<CreateHumanTaskUiError>> {
+        self.runtime
+            .block_on(self.client.create_human_task_ui(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_hyper_parameter_tuning_job`].
+    pub fn create_hyper_parameter_tuning_job(
+        &self,
+        input: CreateHyperParameterTuningJobRequest,
+    ) -> Result<
+        CreateHyperParameterTuningJobResponse,
+        RusotoError<CreateHyperParameterTuningJobError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_hyper_parameter_tuning_job(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_image`].
+    pub fn create_image(
+        &self,
+        input: CreateImageRequest,
+    ) -> Result<CreateImageResponse, RusotoError<CreateImageError>> {
+        self.runtime.block_on(self.client.create_image(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_image_version`].
+    pub fn create_image_version(
+        &self,
+        input: CreateImageVersionRequest,
+    ) -> Result<CreateImageVersionResponse, RusotoError<CreateImageVersionError>> {
+        self.runtime
+            .block_on(self.client.create_image_version(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_labeling_job`].
+    pub fn create_labeling_job(
+        &self,
+        input: CreateLabelingJobRequest,
+    ) -> Result<CreateLabelingJobResponse, RusotoError<CreateLabelingJobError>> {
+        self.runtime
+            .block_on(self.client.create_labeling_job(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_model`].
+    pub fn create_model(
+        &self,
+        input: CreateModelInput,
+    ) -> Result<CreateModelOutput, RusotoError<CreateModelError>> {
+        self.runtime.block_on(self.client.create_model(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_model_bias_job_definition`].
+    pub fn create_model_bias_job_definition(
+        &self,
+        input: CreateModelBiasJobDefinitionRequest,
+    ) -> Result<CreateModelBiasJobDefinitionResponse, RusotoError<CreateModelBiasJobDefinitionError>>
+    {
+        self.runtime
+            .block_on(self.client.create_model_bias_job_definition(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_model_explainability_job_definition`].
+    pub fn create_model_explainability_job_definition(
+        &self,
+        input: CreateModelExplainabilityJobDefinitionRequest,
+    ) -> Result<
+        CreateModelExplainabilityJobDefinitionResponse,
+        RusotoError<CreateModelExplainabilityJobDefinitionError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .create_model_explainability_job_definition(input),
+        )
+    }
+
+    /// Blocking version of [`SageMaker::create_model_package`].
+    pub fn create_model_package(
+        &self,
+        input: CreateModelPackageInput,
+    ) -> Result<CreateModelPackageOutput, RusotoError<CreateModelPackageError>> {
+        self.runtime
+            .block_on(self.client.create_model_package(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_model_package_group`].
+    pub fn create_model_package_group(
+        &self,
+        input: CreateModelPackageGroupInput,
+    ) -> Result<CreateModelPackageGroupOutput, RusotoError<CreateModelPackageGroupError>> {
+        self.runtime
+            .block_on(self.client.create_model_package_group(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_model_quality_job_definition`].
+    pub fn create_model_quality_job_definition(
+        &self,
+        input: CreateModelQualityJobDefinitionRequest,
+    ) -> Result<
+        CreateModelQualityJobDefinitionResponse,
+        RusotoError<CreateModelQualityJobDefinitionError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_model_quality_job_definition(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_monitoring_schedule`].
+    pub fn create_monitoring_schedule(
+        &self,
+        input: CreateMonitoringScheduleRequest,
+    ) -> Result<CreateMonitoringScheduleResponse, RusotoError<CreateMonitoringScheduleError>> {
+        self.runtime
+            .block_on(self.client.create_monitoring_schedule(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_notebook_instance`].
+    pub fn create_notebook_instance(
+        &self,
+        input: CreateNotebookInstanceInput,
+    ) -> Result<CreateNotebookInstanceOutput, RusotoError<CreateNotebookInstanceError>> {
+        self.runtime
+            .block_on(self.client.create_notebook_instance(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_notebook_instance_lifecycle_config`].
+    pub fn create_notebook_instance_lifecycle_config(
+        &self,
+        input: CreateNotebookInstanceLifecycleConfigInput,
+    ) -> Result<
+        CreateNotebookInstanceLifecycleConfigOutput,
+        RusotoError<CreateNotebookInstanceLifecycleConfigError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_notebook_instance_lifecycle_config(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_pipeline`].
+    pub fn create_pipeline(
+        &self,
+        input: CreatePipelineRequest,
+    ) -> Result<CreatePipelineResponse, RusotoError<CreatePipelineError>> {
+        self.runtime.block_on(self.client.create_pipeline(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_presigned_domain_url`].
+    pub fn create_presigned_domain_url(
+        &self,
+        input: CreatePresignedDomainUrlRequest,
+    ) -> Result<CreatePresignedDomainUrlResponse, RusotoError<CreatePresignedDomainUrlError>> {
+        self.runtime
+            .block_on(self.client.create_presigned_domain_url(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_presigned_notebook_instance_url`].
+    pub fn create_presigned_notebook_instance_url(
+        &self,
+        input: CreatePresignedNotebookInstanceUrlInput,
+    ) -> Result<
+        CreatePresignedNotebookInstanceUrlOutput,
+        RusotoError<CreatePresignedNotebookInstanceUrlError>,
+    > {
+        self.runtime
+            .block_on(self.client.create_presigned_notebook_instance_url(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_processing_job`].
+    pub fn create_processing_job(
+        &self,
+        input: CreateProcessingJobRequest,
+    ) -> Result<CreateProcessingJobResponse, RusotoError<CreateProcessingJobError>> {
+        self.runtime
+            .block_on(self.client.create_processing_job(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_project`].
+    pub fn create_project(
+        &self,
+        input: CreateProjectInput,
+    ) -> Result<CreateProjectOutput, RusotoError<CreateProjectError>> {
+        self.runtime.block_on(self.client.create_project(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_training_job`].
+    pub fn create_training_job(
+        &self,
+        input: CreateTrainingJobRequest,
+    ) -> Result<CreateTrainingJobResponse, RusotoError<CreateTrainingJobError>> {
+        self.runtime
+            .block_on(self.client.create_training_job(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_transform_job`].
+    pub fn create_transform_job(
+        &self,
+        input: CreateTransformJobRequest,
+    ) -> Result<CreateTransformJobResponse, RusotoError<CreateTransformJobError>> {
+        self.runtime
+            .block_on(self.client.create_transform_job(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_trial`].
+    pub fn create_trial(
+        &self,
+        input: CreateTrialRequest,
+    ) -> Result<CreateTrialResponse, RusotoError<CreateTrialError>> {
+        self.runtime.block_on(self.client.create_trial(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_trial_component`].
+    pub fn create_trial_component(
+        &self,
+        input: CreateTrialComponentRequest,
+    ) -> Result<CreateTrialComponentResponse, RusotoError<CreateTrialComponentError>> {
+        self.runtime
+            .block_on(self.client.create_trial_component(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_user_profile`].
+    pub fn create_user_profile(
+        &self,
+        input: CreateUserProfileRequest,
+    ) -> Result<CreateUserProfileResponse, RusotoError<CreateUserProfileError>> {
+        self.runtime
+            .block_on(self.client.create_user_profile(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_workforce`].
+    pub fn create_workforce(
+        &self,
+        input: CreateWorkforceRequest,
+    ) -> Result<CreateWorkforceResponse, RusotoError<CreateWorkforceError>> {
+        self.runtime.block_on(self.client.create_workforce(input))
+    }
+
+    /// Blocking version of [`SageMaker::create_workteam`].
+    pub fn create_workteam(
+        &self,
+        input: CreateWorkteamRequest,
+    ) -> Result<CreateWorkteamResponse, RusotoError<CreateWorkteamError>> {
+        self.runtime.block_on(self.client.create_workteam(input))
+    }
+
+    /// Blocking version of [`SageMaker::delete_action`].
+    pub fn delete_action(
+        &self,
+        input: DeleteActionRequest,
+    ) -> Result<DeleteActionResponse, RusotoError<DeleteActionError>> {
+        self.runtime.block_on(self.client.delete_action(input))
+    }
+
+    /// Blocking version of [`SageMaker::delete_algorithm`].
+    pub fn delete_algorithm(
+        &self,
+        input: DeleteAlgorithmInput,
+    ) -> Result<(), RusotoError<DeleteAlgorithmError>> {
+        self.runtime.block_on(self.client.delete_algorithm(input))
+    }
+
+    /// Blocking version of [`SageMaker::delete_app`].
+    pub fn delete_app(&self, input: DeleteAppRequest) -> Result<(), RusotoError<DeleteAppError>> {
+        self.runtime.block_on(self.client.delete_app(input))
+    }
+
+    /// Blocking version of [`SageMaker::delete_app_image_config`].
+    pub fn delete_app_image_config(
+        &self,
+        input: DeleteAppImageConfigRequest,
+    ) -> Result<(), RusotoError<DeleteAppImageConfigError>> {
+        self.runtime
+            .block_on(self.client.delete_app_image_config(input))
+    }
+
+    /// Blocking version of [`SageMaker::delete_artifact`].
+    pub fn delete_artifact(
+        &self,
+        input: DeleteArtifactRequest,
+    ) -> Result<DeleteArtifactResponse, RusotoError<DeleteArtifactError>> {
+        self.runtime.block_on(self.client.delete_artifact(input))
+    }
+
+    /// Blocking version of [`SageMaker::delete_association`].
+    pub fn delete_association(
+        &self,
+        input: DeleteAssociationRequest,
+    ) -> Result<DeleteAssociationResponse, RusotoError<DeleteAssociationError>> {
+        self.runtime.block_on(self.client.delete_association(input))
+    }
+
+    /// Blocking version of [`SageMaker::delete_code_repository`].
+    pub fn delete_code_repository(
+        &self,
+        input: DeleteCodeRepositoryInput,
+    ) -> Result<(), RusotoError<DeleteCodeRepositoryError>> {
+        self.runtime
+            .block_on(self.client.delete_code_repository(input))
+    }
+
+    /// Blocking version of [`SageMaker::delete_context`].
+    pub fn delete_context(
+        &self,
+        input: DeleteContextRequest,
+    ) -> Result<DeleteContextResponse, RusotoError<DeleteContextError>> {
+        self.runtime.block_on(self.client.delete_context(input))
+    }
+
+    /// Blocking version of [`SageMaker::delete_data_quality_job_definition`].
+    pub fn delete_data_quality_job_definition(
+        &self,
+        input: DeleteDataQualityJobDefinitionRequest,
+    ) -> Result<(), RusotoError<DeleteDataQualityJobDefinitionError>> {
+        self.runtime
+            .block_on(self.client.delete_data_quality_job_definition(input))
+    }
+
+    /// Blocking version of [`SageMaker::delete_device_fleet`].
+    pub fn delete_device_fleet(
+        &self,
+        input: DeleteDeviceFleetRequest,
+    ) -> Result<(), RusotoError<DeleteDeviceFleetError>> {
+        self.runtime
+            .block_on(self.client.delete_device_fleet(input))
+    }
+
+    /// Blocking version of [`SageMaker::delete_domain`].
+    pub fn delete_domain(
+        &self,
+        input: DeleteDomainRequest,
+    ) -> Result<(), RusotoError<DeleteDomainError>> {
+        self.runtime.block_on(self.client.delete_domain(input))
+    }
+
+    /// Blocking version of [`SageMaker::delete_endpoint`].
+    pub fn delete_endpoint(
+        &self,
+        input: DeleteEndpointInput,
+    ) -> Result<(), RusotoError<DeleteEndpointError>> {
+        self.runtime.block_on(self.client.delete_endpoint(input))
+    }
+
+    /// Blocking version of [`SageMaker::delete_endpoint_config`].
+    pub fn delete_endpoint_config(
+        &self,
+        input: DeleteEndpointConfigInput,
+    ) -> Result<(), RusotoError<DeleteEndpointConfigError>> {
+        self.runtime
+            .block_on(self.client.delete_endpoint_config(input))
+    }
+
+    /// Blocking version of [`SageMaker::delete_experiment`].
+    pub fn delete_experiment(
+        &self,
+        input: DeleteExperimentRequest,
+    ) -> Result<DeleteExperimentResponse, RusotoError<DeleteExperimentError>> {
+        self.runtime.block_on(self.client.delete_experiment(input))
+    }
+
+    /// Blocking version of [`SageMaker::delete_feature_group`].
+    pub fn delete_feature_group(
+        &self,
+        input: DeleteFeatureGroupRequest,
+    ) -> Result<(), RusotoError<DeleteFeatureGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_feature_group(input))
+    }
+
+    /// Blocking version of [`SageMaker::delete_flow_definition`].
+    pub fn delete_flow_definition(
+        &self,
+        input: DeleteFlowDefinitionRequest,
+    ) -> Result<DeleteFlowDefinitionResponse, RusotoError<DeleteFlowDefinitionError>> {
+        self.runtime
+            .block_on(self.client.delete_flow_definition(input))
+    }
+
+    /// Blocking version of [`SageMaker::delete_human_task_ui`].
+    pub fn delete_human_task_ui(
+        &self,
+        input: DeleteHumanTaskUiRequest,
+    ) -> Result<DeleteHumanTaskUiResponse, RusotoError<DeleteHumanTaskUiError>> {
+        self.runtime
+            .block_on(self.client.delete_human_task_ui(input))
+    }
+
+    /// Blocking version of [`SageMaker::delete_image`].
+    pub fn delete_image(
+        &self,
+        input: DeleteImageRequest,
+    ) -> Result<DeleteImageResponse, RusotoError<DeleteImageError>> {
+        self.runtime.block_on(self.client.delete_image(input))
+    }
+
+    /// Blocking version of [`SageMaker::delete_image_version`].
+    pub fn delete_image_version(
+        &self,
+        input: DeleteImageVersionRequest,
+    ) -> Result<DeleteImageVersionResponse, RusotoError<DeleteImageVersionError>> {
+        self.runtime
+            .block_on(self.client.delete_image_version(input))
+    }
+
+    /// Blocking version of [`SageMaker::delete_model`].
+    pub fn delete_model(
+        &self,
+        input: DeleteModelInput,
+    ) -> Result<(), RusotoError<DeleteModelError>> {
+        self.runtime.block_on(self.client.delete_model(input))
+    }
+
+    /// Blocking version of [`SageMaker::delete_model_bias_job_definition`].
+    pub fn delete_model_bias_job_definition(
+        &self,
+        input: DeleteModelBiasJobDefinitionRequest,
+    ) -> Result<(), RusotoError<DeleteModelBiasJobDefinitionError>> {
+        self.runtime
+            .block_on(self.client.delete_model_bias_job_definition(input))
+    }
+
+    /// Blocking version of [`SageMaker::delete_model_explainability_job_definition`].
+    pub fn delete_model_explainability_job_definition(
+        &self,
+        input: DeleteModelExplainabilityJobDefinitionRequest,
+    ) -> Result<(), RusotoError<DeleteModelExplainabilityJobDefinitionError>> {
+        self.runtime.block_on(
+            self.client
+                .delete_model_explainability_job_definition(input),
+        )
+    }
+
+    /// Blocking version of [`SageMaker::delete_model_package`].
+    pub fn delete_model_package(
+        &self,
+        input: DeleteModelPackageInput,
+    ) -> Result<(), RusotoError<DeleteModelPackageError>> {
+        self.runtime
+            .block_on(self.client.delete_model_package(input))
+    }
+
+    /// Blocking version of [`SageMaker::delete_model_package_group`].
+    pub fn delete_model_package_group(
+        &self,
+        input: DeleteModelPackageGroupInput,
+    ) -> Result<(), RusotoError<DeleteModelPackageGroupError>> {
+        self.runtime
+            .block_on(self.client.delete_model_package_group(input))
+    }
+
+    /// Blocking version of [`SageMaker::delete_model_package_group_policy`].
+    pub fn delete_model_package_group_policy(
+        &self,
+        input: DeleteModelPackageGroupPolicyInput,
+    ) -> Result<(), RusotoError<DeleteModelPackageGroupPolicyError>> {
+        self.runtime
+            .block_on(self.client.delete_model_package_group_policy(input))
+    }
+
+    /// Blocking version of [`SageMaker::delete_model_quality_job_definition`].
+    pub fn delete_model_quality_job_definition(
+        &self,
+        input: DeleteModelQualityJobDefinitionRequest,
+    ) -> Result<(), RusotoError<DeleteModelQualityJobDefinitionError>> {
+        self.runtime
+            .block_on(self.client.delete_model_quality_job_definition(input))
+    }
+
+    /// Blocking version of [`SageMaker::delete_monitoring_schedule`].
+    pub fn delete_monitoring_schedule(
+        &self,
+        input: DeleteMonitoringScheduleRequest,
+    ) -> Result<(), RusotoError<DeleteMonitoringScheduleError>> {
+        self.runtime
+            .block_on(self.client.delete_monitoring_schedule(input))
+    }
+
+    /// Blocking version of [`SageMaker::delete_notebook_instance`].
+    pub fn delete_notebook_instance(
+        &self,
+        input: DeleteNotebookInstanceInput,
+    ) -> Result<(), RusotoError<DeleteNotebookInstanceError>> {
+        self.runtime
+            .block_on(self.client.delete_notebook_instance(input))
+    }
+
+    /// Blocking version of [`SageMaker::delete_notebook_instance_lifecycle_config`].
+    pub fn delete_notebook_instance_lifecycle_config(
+        &self,
+        input: DeleteNotebookInstanceLifecycleConfigInput,
+    ) -> Result<(), RusotoError<DeleteNotebookInstanceLifecycleConfigError>> {
+        self.runtime
+            .block_on(self.client.delete_notebook_instance_lifecycle_config(input))
+    }
+
+    /// Blocking version of [`SageMaker::delete_pipeline`].
+    pub fn delete_pipeline(
+        &self,
+        input: DeletePipelineRequest,
+    ) -> Result<DeletePipelineResponse, RusotoError<DeletePipelineError>> {
+        self.runtime.block_on(self.client.delete_pipeline(input))
+    }
+
+    /// Blocking version of [`SageMaker::delete_project`].
+    pub fn delete_project(
+        &self,
+        input: DeleteProjectInput,
+    ) -> Result<(), RusotoError<DeleteProjectError>> {
+        self.runtime.block_on(self.client.delete_project(input))
+    }
+
+    /// Blocking version of [`SageMaker::delete_tags`].
+    pub fn delete_tags(
+        &self,
+        input: DeleteTagsInput,
+    ) -> Result<DeleteTagsOutput, RusotoError<DeleteTagsError>> {
+        self.runtime.block_on(self.client.delete_tags(input))
+    }
+
+    /// Blocking version of [`SageMaker::delete_trial`].
+    pub fn delete_trial(
+        &self,
+        input: DeleteTrialRequest,
+    ) -> Result<DeleteTrialResponse, RusotoError<DeleteTrialError>> {
+        self.runtime.block_on(self.client.delete_trial(input))
+    }
+
+    /// Blocking version of [`SageMaker::delete_trial_component`].
+    pub fn delete_trial_component(
+        &self,
+        input: DeleteTrialComponentRequest,
+    ) -> Result<DeleteTrialComponentResponse, RusotoError<DeleteTrialComponentError>> {
+        self.runtime
+            .block_on(self.client.delete_trial_component(input))
+    }
+
+    /// Blocking version of [`SageMaker::delete_user_profile`].
+    pub fn delete_user_profile(
+        &self,
+        input: DeleteUserProfileRequest,
+    ) -> Result<(), RusotoError<DeleteUserProfileError>> {
+        self.runtime
+            .block_on(self.client.delete_user_profile(input))
+    }
+
+    /// Blocking version of [`SageMaker::delete_workforce`].
+    pub fn delete_workforce(
+        &self,
+        input: DeleteWorkforceRequest,
+    ) -> Result<DeleteWorkforceResponse, RusotoError<DeleteWorkforceError>> {
+        self.runtime.block_on(self.client.delete_workforce(input))
+    }
+
+    /// Blocking version of [`SageMaker::delete_workteam`].
+    pub fn delete_workteam(
+        &self,
+        input: DeleteWorkteamRequest,
+    ) -> Result<DeleteWorkteamResponse, RusotoError<DeleteWorkteamError>> {
+        self.runtime.block_on(self.client.delete_workteam(input))
+    }
+
+    /// Blocking version of [`SageMaker::deregister_devices`].
+    pub fn deregister_devices(
+        &self,
+        input: DeregisterDevicesRequest,
+    ) -> Result<(), RusotoError<DeregisterDevicesError>> {
+        self.runtime.block_on(self.client.deregister_devices(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_action`].
+    pub fn describe_action(
+        &self,
+        input: DescribeActionRequest,
+    ) -> Result<DescribeActionResponse, RusotoError<DescribeActionError>> {
+        self.runtime.block_on(self.client.describe_action(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_algorithm`].
+    pub fn describe_algorithm(
+        &self,
+        input: DescribeAlgorithmInput,
+    ) -> Result<DescribeAlgorithmOutput, RusotoError<DescribeAlgorithmError>> {
+        self.runtime.block_on(self.client.describe_algorithm(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_app`].
+    pub fn describe_app(
+        &self,
+        input: DescribeAppRequest,
+    ) -> Result<DescribeAppResponse, RusotoError<DescribeAppError>> {
+        self.runtime.block_on(self.client.describe_app(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_app_image_config`].
+    pub fn describe_app_image_config(
+        &self,
+        input: DescribeAppImageConfigRequest,
+    ) -> Result<DescribeAppImageConfigResponse, RusotoError<DescribeAppImageConfigError>> {
+        self.runtime
+            .block_on(self.client.describe_app_image_config(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_artifact`].
+    pub fn describe_artifact(
+        &self,
+        input: DescribeArtifactRequest,
+    ) -> Result<DescribeArtifactResponse, RusotoError<DescribeArtifactError>> {
+        self.runtime.block_on(self.client.describe_artifact(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_auto_ml_job`].
+    pub fn describe_auto_ml_job(
+        &self,
+        input: DescribeAutoMLJobRequest,
+    ) -> Result<DescribeAutoMLJobResponse, RusotoError<DescribeAutoMLJobError>> {
+        self.runtime
+            .block_on(self.client.describe_auto_ml_job(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_code_repository`].
+    pub fn describe_code_repository(
+        &self,
+        input: DescribeCodeRepositoryInput,
+    ) -> Result<DescribeCodeRepositoryOutput, RusotoError<DescribeCodeRepositoryError>> {
+        self.runtime
+            .block_on(self.client.describe_code_repository(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_compilation_job`].
+    pub fn describe_compilation_job(
+        &self,
+        input: DescribeCompilationJobRequest,
+    ) -> Result<DescribeCompilationJobResponse, RusotoError<DescribeCompilationJobError>> {
+        self.runtime
+            .block_on(self.client.describe_compilation_job(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_context`].
+    pub fn describe_context(
+        &self,
+        input: DescribeContextRequest,
+    ) -> Result<DescribeContextResponse, RusotoError<DescribeContextError>> {
+        self.runtime.block_on(self.client.describe_context(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_data_quality_job_definition`].
+    pub fn describe_data_quality_job_definition(
+        &self,
+        input: DescribeDataQualityJobDefinitionRequest,
+    ) -> Result<
+        DescribeDataQualityJobDefinitionResponse,
+        RusotoError<DescribeDataQualityJobDefinitionError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_data_quality_job_definition(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_device`].
+    pub fn describe_device(
+        &self,
+        input: DescribeDeviceRequest,
+    ) -> Result<DescribeDeviceResponse, RusotoError<DescribeDeviceError>> {
+        self.runtime.block_on(self.client.describe_device(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_device_fleet`].
+    pub fn describe_device_fleet(
+        &self,
+        input: DescribeDeviceFleetRequest,
+    ) -> Result<DescribeDeviceFleetResponse, RusotoError<DescribeDeviceFleetError>> {
+        self.runtime
+            .block_on(self.client.describe_device_fleet(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_domain`].
+    pub fn describe_domain(
+        &self,
+        input: DescribeDomainRequest,
+    ) -> Result<DescribeDomainResponse, RusotoError<DescribeDomainError>> {
+        self.runtime.block_on(self.client.describe_domain(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_edge_packaging_job`].
+    pub fn describe_edge_packaging_job(
+        &self,
+        input: DescribeEdgePackagingJobRequest,
+    ) -> Result<DescribeEdgePackagingJobResponse, RusotoError<DescribeEdgePackagingJobError>> {
+        self.runtime
+            .block_on(self.client.describe_edge_packaging_job(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_endpoint`].
+    pub fn describe_endpoint(
+        &self,
+        input: DescribeEndpointInput,
+    ) -> Result<DescribeEndpointOutput, RusotoError<DescribeEndpointError>> {
+        self.runtime.block_on(self.client.describe_endpoint(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_endpoint_config`].
+    pub fn describe_endpoint_config(
+        &self,
+        input: DescribeEndpointConfigInput,
+    ) -> Result<DescribeEndpointConfigOutput, RusotoError<DescribeEndpointConfigError>> {
+        self.runtime
+            .block_on(self.client.describe_endpoint_config(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_experiment`].
+    pub fn describe_experiment(
+        &self,
+        input: DescribeExperimentRequest,
+    ) -> Result<DescribeExperimentResponse, RusotoError<DescribeExperimentError>> {
+        self.runtime
+            .block_on(self.client.describe_experiment(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_feature_group`].
+    pub fn describe_feature_group(
+        &self,
+        input: DescribeFeatureGroupRequest,
+    ) -> Result<DescribeFeatureGroupResponse, RusotoError<DescribeFeatureGroupError>> {
+        self.runtime
+            .block_on(self.client.describe_feature_group(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_flow_definition`].
+    pub fn describe_flow_definition(
+        &self,
+        input: DescribeFlowDefinitionRequest,
+    ) -> Result<DescribeFlowDefinitionResponse, RusotoError<DescribeFlowDefinitionError>> {
+        self.runtime
+            .block_on(self.client.describe_flow_definition(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_human_task_ui`].
+    pub fn describe_human_task_ui(
+        &self,
+        input: DescribeHumanTaskUiRequest,
+    ) -> Result<DescribeHumanTaskUiResponse, RusotoError<DescribeHumanTaskUiError>> {
+        self.runtime
+            .block_on(self.client.describe_human_task_ui(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_hyper_parameter_tuning_job`].
+    pub fn describe_hyper_parameter_tuning_job(
+        &self,
+        input: DescribeHyperParameterTuningJobRequest,
+    ) -> Result<
+        DescribeHyperParameterTuningJobResponse,
+        RusotoError<DescribeHyperParameterTuningJobError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_hyper_parameter_tuning_job(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_image`].
+    pub fn describe_image(
+        &self,
+        input: DescribeImageRequest,
+    ) -> Result<DescribeImageResponse, RusotoError<DescribeImageError>> {
+        self.runtime.block_on(self.client.describe_image(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_image_version`].
+    pub fn describe_image_version(
+        &self,
+        input: DescribeImageVersionRequest,
+    ) -> Result<DescribeImageVersionResponse, RusotoError<DescribeImageVersionError>> {
+        self.runtime
+            .block_on(self.client.describe_image_version(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_labeling_job`].
+    pub fn describe_labeling_job(
+        &self,
+        input: DescribeLabelingJobRequest,
+    ) -> Result<DescribeLabelingJobResponse, RusotoError<DescribeLabelingJobError>> {
+        self.runtime
+            .block_on(self.client.describe_labeling_job(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_model`].
+    pub fn describe_model(
+        &self,
+        input: DescribeModelInput,
+    ) -> Result<DescribeModelOutput, RusotoError<DescribeModelError>> {
+        self.runtime.block_on(self.client.describe_model(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_model_bias_job_definition`].
+    pub fn describe_model_bias_job_definition(
+        &self,
+        input: DescribeModelBiasJobDefinitionRequest,
+    ) -> Result<
+        DescribeModelBiasJobDefinitionResponse,
+        RusotoError<DescribeModelBiasJobDefinitionError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_model_bias_job_definition(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_model_explainability_job_definition`].
+    pub fn describe_model_explainability_job_definition(
+        &self,
+        input: DescribeModelExplainabilityJobDefinitionRequest,
+    ) -> Result<
+        DescribeModelExplainabilityJobDefinitionResponse,
+        RusotoError<DescribeModelExplainabilityJobDefinitionError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_model_explainability_job_definition(input),
+        )
+    }
+
+    /// Blocking version of [`SageMaker::describe_model_package`].
+    pub fn describe_model_package(
+        &self,
+        input: DescribeModelPackageInput,
+    ) -> Result<DescribeModelPackageOutput, RusotoError<DescribeModelPackageError>> {
+        self.runtime
+            .block_on(self.client.describe_model_package(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_model_package_group`].
+    pub fn describe_model_package_group(
+        &self,
+        input: DescribeModelPackageGroupInput,
+    ) -> Result<DescribeModelPackageGroupOutput, RusotoError<DescribeModelPackageGroupError>> {
+        self.runtime
+            .block_on(self.client.describe_model_package_group(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_model_quality_job_definition`].
+    pub fn describe_model_quality_job_definition(
+        &self,
+        input: DescribeModelQualityJobDefinitionRequest,
+    ) -> Result<
+        DescribeModelQualityJobDefinitionResponse,
+        RusotoError<DescribeModelQualityJobDefinitionError>,
+    > {
+        self.runtime
+            .block_on(self.client.describe_model_quality_job_definition(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_monitoring_schedule`].
+    pub fn describe_monitoring_schedule(
+        &self,
+        input: DescribeMonitoringScheduleRequest,
+    ) -> Result<DescribeMonitoringScheduleResponse, RusotoError<DescribeMonitoringScheduleError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_monitoring_schedule(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_notebook_instance`].
+    pub fn describe_notebook_instance(
+        &self,
+        input: DescribeNotebookInstanceInput,
+    ) -> Result<DescribeNotebookInstanceOutput, RusotoError<DescribeNotebookInstanceError>> {
+        self.runtime
+            .block_on(self.client.describe_notebook_instance(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_notebook_instance_lifecycle_config`].
+    pub fn describe_notebook_instance_lifecycle_config(
+        &self,
+        input: DescribeNotebookInstanceLifecycleConfigInput,
+    ) -> Result<
+        DescribeNotebookInstanceLifecycleConfigOutput,
+        RusotoError<DescribeNotebookInstanceLifecycleConfigError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_notebook_instance_lifecycle_config(input),
+        )
+    }
+
+    /// Blocking version of [`SageMaker::describe_pipeline`].
+    pub fn describe_pipeline(
+        &self,
+        input: DescribePipelineRequest,
+    ) -> Result<DescribePipelineResponse, RusotoError<DescribePipelineError>> {
+        self.runtime.block_on(self.client.describe_pipeline(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_pipeline_definition_for_execution`].
+    pub fn describe_pipeline_definition_for_execution(
+        &self,
+        input: DescribePipelineDefinitionForExecutionRequest,
+    ) -> Result<
+        DescribePipelineDefinitionForExecutionResponse,
+        RusotoError<DescribePipelineDefinitionForExecutionError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .describe_pipeline_definition_for_execution(input),
+        )
+    }
+
+    /// Blocking version of [`SageMaker::describe_pipeline_execution`].
+    pub fn describe_pipeline_execution(
+        &self,
+        input: DescribePipelineExecutionRequest,
+    ) -> Result<DescribePipelineExecutionResponse, RusotoError<DescribePipelineExecutionError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_pipeline_execution(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_processing_job`].
+    pub fn describe_processing_job(
+        &self,
+        input: DescribeProcessingJobRequest,
+    ) -> Result<DescribeProcessingJobResponse, RusotoError<DescribeProcessingJobError>> {
+        self.runtime
+            .block_on(self.client.describe_processing_job(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_project`].
+    pub fn describe_project(
+        &self,
+        input: DescribeProjectInput,
+    ) -> Result<DescribeProjectOutput, RusotoError<DescribeProjectError>> {
+        self.runtime.block_on(self.client.describe_project(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_subscribed_workteam`].
+    pub fn describe_subscribed_workteam(
+        &self,
+        input: DescribeSubscribedWorkteamRequest,
+    ) -> Result<DescribeSubscribedWorkteamResponse, RusotoError<DescribeSubscribedWorkteamError>>
+    {
+        self.runtime
+            .block_on(self.client.describe_subscribed_workteam(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_training_job`].
+    pub fn describe_training_job(
+        &self,
+        input: DescribeTrainingJobRequest,
+    ) -> Result<DescribeTrainingJobResponse, RusotoError<DescribeTrainingJobError>> {
+        self.runtime
+            .block_on(self.client.describe_training_job(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_transform_job`].
+    pub fn describe_transform_job(
+        &self,
+        input: DescribeTransformJobRequest,
+    ) -> Result<DescribeTransformJobResponse, RusotoError<DescribeTransformJobError>> {
+        self.runtime
+            .block_on(self.client.describe_transform_job(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_trial`].
+    pub fn describe_trial(
+        &self,
+        input: DescribeTrialRequest,
+    ) -> Result<DescribeTrialResponse, RusotoError<DescribeTrialError>> {
+        self.runtime.block_on(self.client.describe_trial(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_trial_component`].
+    pub fn describe_trial_component(
+        &self,
+        input: DescribeTrialComponentRequest,
+    ) -> Result<DescribeTrialComponentResponse, RusotoError<DescribeTrialComponentError>> {
+        self.runtime
+            .block_on(self.client.describe_trial_component(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_user_profile`].
+    pub fn describe_user_profile(
+        &self,
+        input: DescribeUserProfileRequest,
+    ) -> Result<DescribeUserProfileResponse, RusotoError<DescribeUserProfileError>> {
+        self.runtime
+            .block_on(self.client.describe_user_profile(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_workforce`].
+    pub fn describe_workforce(
+        &self,
+        input: DescribeWorkforceRequest,
+    ) -> Result<DescribeWorkforceResponse, RusotoError<DescribeWorkforceError>> {
+        self.runtime.block_on(self.client.describe_workforce(input))
+    }
+
+    /// Blocking version of [`SageMaker::describe_workteam`].
+    pub fn describe_workteam(
+        &self,
+        input: DescribeWorkteamRequest,
+    ) -> Result<DescribeWorkteamResponse, RusotoError<DescribeWorkteamError>> {
+        self.runtime.block_on(self.client.describe_workteam(input))
+    }
+
+    /// Blocking version of [`SageMaker::disable_sagemaker_servicecatalog_portfolio`].
+    pub fn disable_sagemaker_servicecatalog_portfolio(
+        &self,
+    ) -> Result<
+        DisableSagemakerServicecatalogPortfolioOutput,
+        RusotoError<DisableSagemakerServicecatalogPortfolioError>,
+    > {
+        self.runtime
+            .block_on(self.client.disable_sagemaker_servicecatalog_portfolio())
+    }
+
+    /// Blocking version of [`SageMaker::disassociate_trial_component`].
+    pub fn disassociate_trial_component(
+        &self,
+        input: DisassociateTrialComponentRequest,
+    ) -> Result<DisassociateTrialComponentResponse, RusotoError<DisassociateTrialComponentError>>
+    {
+        self.runtime
+            .block_on(self.client.disassociate_trial_component(input))
+    }
+
+    /// Blocking version of [`SageMaker::enable_sagemaker_servicecatalog_portfolio`].
+    pub fn enable_sagemaker_servicecatalog_portfolio(
+        &self,
+    ) -> Result<
+        EnableSagemakerServicecatalogPortfolioOutput,
+        RusotoError<EnableSagemakerServicecatalogPortfolioError>,
+    > {
+        self.runtime
+            .block_on(self.client.enable_sagemaker_servicecatalog_portfolio())
+    }
+
+    /// Blocking version of [`SageMaker::get_device_fleet_report`].
+    pub fn get_device_fleet_report(
+        &self,
+        input: GetDeviceFleetReportRequest,
+    ) -> Result<GetDeviceFleetReportResponse, RusotoError<GetDeviceFleetReportError>> {
+        self.runtime
+            .block_on(self.client.get_device_fleet_report(input))
+    }
+
+    /// Blocking version of [`SageMaker::get_model_package_group_policy`].
+    pub fn get_model_package_group_policy(
+        &self,
+        input: GetModelPackageGroupPolicyInput,
+    ) -> Result<GetModelPackageGroupPolicyOutput, RusotoError<GetModelPackageGroupPolicyError>>
+    {
+        self.runtime
+            .block_on(self.client.get_model_package_group_policy(input))
+    }
+
+    /// Blocking version of [`SageMaker::get_sagemaker_servicecatalog_portfolio_status`].
+    pub fn get_sagemaker_servicecatalog_portfolio_status(
+        &self,
+    ) -> Result<
+        GetSagemakerServicecatalogPortfolioStatusOutput,
+        RusotoError<GetSagemakerServicecatalogPortfolioStatusError>,
+    > {
+        self.runtime
+            .block_on(self.client.get_sagemaker_servicecatalog_portfolio_status())
+    }
+
+    /// Blocking version of [`SageMaker::get_search_suggestions`].
+    pub fn get_search_suggestions(
+        &self,
+        input: GetSearchSuggestionsRequest,
+    ) -> Result<GetSearchSuggestionsResponse, RusotoError<GetSearchSuggestionsError>> {
+        self.runtime
+            .block_on(self.client.get_search_suggestions(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_actions`].
+    pub fn list_actions(
+        &self,
+        input: ListActionsRequest,
+    ) -> Result<ListActionsResponse, RusotoError<ListActionsError>> {
+        self.runtime.block_on(self.client.list_actions(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_algorithms`].
+    pub fn list_algorithms(
+        &self,
+        input: ListAlgorithmsInput,
+    ) -> Result<ListAlgorithmsOutput, RusotoError<ListAlgorithmsError>> {
+        self.runtime.block_on(self.client.list_algorithms(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_app_image_configs`].
+    pub fn list_app_image_configs(
+        &self,
+        input: ListAppImageConfigsRequest,
+    ) -> Result<ListAppImageConfigsResponse, RusotoError<ListAppImageConfigsError>> {
+        self.runtime
+            .block_on(self.client.list_app_image_configs(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_apps`].
+    pub fn list_apps(
+        &self,
+        input: ListAppsRequest,
+    ) -> Result<ListAppsResponse, RusotoError<ListAppsError>> {
+        self.runtime.block_on(self.client.list_apps(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_artifacts`].
+    pub fn list_artifacts(
+        &self,
+        input: ListArtifactsRequest,
+    ) -> Result<ListArtifactsResponse, RusotoError<ListArtifactsError>> {
+        self.runtime.block_on(self.client.list_artifacts(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_associations`].
+    pub fn list_associations(
+        &self,
+        input: ListAssociationsRequest,
+    ) -> Result<ListAssociationsResponse, RusotoError<ListAssociationsError>> {
+        self.runtime.block_on(self.client.list_associations(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_auto_ml_jobs`].
+    pub fn list_auto_ml_jobs(
+        &self,
+        input: ListAutoMLJobsRequest,
+    ) -> Result<ListAutoMLJobsResponse, RusotoError<ListAutoMLJobsError>> {
+        self.runtime.block_on(self.client.list_auto_ml_jobs(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_candidates_for_auto_ml_job`].
+    pub fn list_candidates_for_auto_ml_job(
+        &self,
+        input: ListCandidatesForAutoMLJobRequest,
+    ) -> Result<ListCandidatesForAutoMLJobResponse, RusotoError<ListCandidatesForAutoMLJobError>>
+    {
+        self.runtime
+            .block_on(self.client.list_candidates_for_auto_ml_job(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_code_repositories`].
+    pub fn list_code_repositories(
+        &self,
+        input: ListCodeRepositoriesInput,
+    ) -> Result<ListCodeRepositoriesOutput, RusotoError<ListCodeRepositoriesError>> {
+        self.runtime
+            .block_on(self.client.list_code_repositories(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_compilation_jobs`].
+    pub fn list_compilation_jobs(
+        &self,
+        input: ListCompilationJobsRequest,
+    ) -> Result<ListCompilationJobsResponse, RusotoError<ListCompilationJobsError>> {
+        self.runtime
+            .block_on(self.client.list_compilation_jobs(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_contexts`].
+    pub fn list_contexts(
+        &self,
+        input: ListContextsRequest,
+    ) -> Result<ListContextsResponse, RusotoError<ListContextsError>> {
+        self.runtime.block_on(self.client.list_contexts(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_data_quality_job_definitions`].
+    pub fn list_data_quality_job_definitions(
+        &self,
+        input: ListDataQualityJobDefinitionsRequest,
+    ) -> Result<
+        ListDataQualityJobDefinitionsResponse,
+        RusotoError<ListDataQualityJobDefinitionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_data_quality_job_definitions(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_device_fleets`].
+    pub fn list_device_fleets(
+        &self,
+        input: ListDeviceFleetsRequest,
+    ) -> Result<ListDeviceFleetsResponse, RusotoError<ListDeviceFleetsError>> {
+        self.runtime.block_on(self.client.list_device_fleets(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_devices`].
+    pub fn list_devices(
+        &self,
+        input: ListDevicesRequest,
+    ) -> Result<ListDevicesResponse, RusotoError<ListDevicesError>> {
+        self.runtime.block_on(self.client.list_devices(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_domains`].
+    pub fn list_domains(
+        &self,
+        input: ListDomainsRequest,
+    ) -> Result<ListDomainsResponse, RusotoError<ListDomainsError>> {
+        self.runtime.block_on(self.client.list_domains(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_edge_packaging_jobs`].
+    pub fn list_edge_packaging_jobs(
+        &self,
+        input: ListEdgePackagingJobsRequest,
+    ) -> Result<ListEdgePackagingJobsResponse, RusotoError<ListEdgePackagingJobsError>> {
+        self.runtime
+            .block_on(self.client.list_edge_packaging_jobs(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_endpoint_configs`].
+    pub fn list_endpoint_configs(
+        &self,
+        input: ListEndpointConfigsInput,
+    ) -> Result<ListEndpointConfigsOutput, RusotoError<ListEndpointConfigsError>> {
+        self.runtime
+            .block_on(self.client.list_endpoint_configs(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_endpoints`].
+    pub fn list_endpoints(
+        &self,
+        input: ListEndpointsInput,
+    ) -> Result<ListEndpointsOutput, RusotoError<ListEndpointsError>> {
+        self.runtime.block_on(self.client.list_endpoints(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_experiments`].
+    pub fn list_experiments(
+        &self,
+        input: ListExperimentsRequest,
+    ) -> Result<ListExperimentsResponse, RusotoError<ListExperimentsError>> {
+        self.runtime.block_on(self.client.list_experiments(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_feature_groups`].
+    pub fn list_feature_groups(
+        &self,
+        input: ListFeatureGroupsRequest,
+    ) -> Result<ListFeatureGroupsResponse, RusotoError<ListFeatureGroupsError>> {
+        self.runtime
+            .block_on(self.client.list_feature_groups(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_flow_definitions`].
+    pub fn list_flow_definitions(
+        &self,
+        input: ListFlowDefinitionsRequest,
+    ) -> Result<ListFlowDefinitionsResponse, RusotoError<ListFlowDefinitionsError>> {
+        self.runtime
+            .block_on(self.client.list_flow_definitions(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_human_task_uis`].
+    pub fn list_human_task_uis(
+        &self,
+        input: ListHumanTaskUisRequest,
+    ) -> Result<ListHumanTaskUisResponse, RusotoError<ListHumanTaskUisError>> {
+        self.runtime
+            .block_on(self.client.list_human_task_uis(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_hyper_parameter_tuning_jobs`].
+    pub fn list_hyper_parameter_tuning_jobs(
+        &self,
+        input: ListHyperParameterTuningJobsRequest,
+    ) -> Result<ListHyperParameterTuningJobsResponse, RusotoError<ListHyperParameterTuningJobsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_hyper_parameter_tuning_jobs(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_image_versions`].
+    pub fn list_image_versions(
+        &self,
+        input: ListImageVersionsRequest,
+    ) -> Result<ListImageVersionsResponse, RusotoError<ListImageVersionsError>> {
+        self.runtime
+            .block_on(self.client.list_image_versions(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_images`].
+    pub fn list_images(
+        &self,
+        input: ListImagesRequest,
+    ) -> Result<ListImagesResponse, RusotoError<ListImagesError>> {
+        self.runtime.block_on(self.client.list_images(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_labeling_jobs`].
+    pub fn list_labeling_jobs(
+        &self,
+        input: ListLabelingJobsRequest,
+    ) -> Result<ListLabelingJobsResponse, RusotoError<ListLabelingJobsError>> {
+        self.runtime.block_on(self.client.list_labeling_jobs(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_labeling_jobs_for_workteam`].
+    pub fn list_labeling_jobs_for_workteam(
+        &self,
+        input: ListLabelingJobsForWorkteamRequest,
+    ) -> Result<ListLabelingJobsForWorkteamResponse, RusotoError<ListLabelingJobsForWorkteamError>>
+    {
+        self.runtime
+            .block_on(self.client.list_labeling_jobs_for_workteam(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_model_bias_job_definitions`].
+    pub fn list_model_bias_job_definitions(
+        &self,
+        input: ListModelBiasJobDefinitionsRequest,
+    ) -> Result<ListModelBiasJobDefinitionsResponse, RusotoError<ListModelBiasJobDefinitionsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_model_bias_job_definitions(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_model_explainability_job_definitions`].
+    pub fn list_model_explainability_job_definitions(
+        &self,
+        input: ListModelExplainabilityJobDefinitionsRequest,
+    ) -> Result<
+        ListModelExplainabilityJobDefinitionsResponse,
+        RusotoError<ListModelExplainabilityJobDefinitionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_model_explainability_job_definitions(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_model_package_groups`].
+    pub fn list_model_package_groups(
+        &self,
+        input: ListModelPackageGroupsInput,
+    ) -> Result<ListModelPackageGroupsOutput, RusotoError<ListModelPackageGroupsError>> {
+        self.runtime
+            .block_on(self.client.list_model_package_groups(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_model_packages`].
+    pub fn list_model_packages(
+        &self,
+        input: ListModelPackagesInput,
+    ) -> Result<ListModelPackagesOutput, RusotoError<ListModelPackagesError>> {
+        self.runtime
+            .block_on(self.client.list_model_packages(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_model_quality_job_definitions`].
+    pub fn list_model_quality_job_definitions(
+        &self,
+        input: ListModelQualityJobDefinitionsRequest,
+    ) -> Result<
+        ListModelQualityJobDefinitionsResponse,
+        RusotoError<ListModelQualityJobDefinitionsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_model_quality_job_definitions(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_models`].
+    pub fn list_models(
+        &self,
+        input: ListModelsInput,
+    ) -> Result<ListModelsOutput, RusotoError<ListModelsError>> {
+        self.runtime.block_on(self.client.list_models(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_monitoring_executions`].
+    pub fn list_monitoring_executions(
+        &self,
+        input: ListMonitoringExecutionsRequest,
+    ) -> Result<ListMonitoringExecutionsResponse, RusotoError<ListMonitoringExecutionsError>> {
+        self.runtime
+            .block_on(self.client.list_monitoring_executions(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_monitoring_schedules`].
+    pub fn list_monitoring_schedules(
+        &self,
+        input: ListMonitoringSchedulesRequest,
+    ) -> Result<ListMonitoringSchedulesResponse, RusotoError<ListMonitoringSchedulesError>> {
+        self.runtime
+            .block_on(self.client.list_monitoring_schedules(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_notebook_instance_lifecycle_configs`].
+    pub fn list_notebook_instance_lifecycle_configs(
+        &self,
+        input: ListNotebookInstanceLifecycleConfigsInput,
+    ) -> Result<
+        ListNotebookInstanceLifecycleConfigsOutput,
+        RusotoError<ListNotebookInstanceLifecycleConfigsError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_notebook_instance_lifecycle_configs(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_notebook_instances`].
+    pub fn list_notebook_instances(
+        &self,
+        input: ListNotebookInstancesInput,
+    ) -> Result<ListNotebookInstancesOutput, RusotoError<ListNotebookInstancesError>> {
+        self.runtime
+            .block_on(self.client.list_notebook_instances(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_pipeline_execution_steps`].
+    pub fn list_pipeline_execution_steps(
+        &self,
+        input: ListPipelineExecutionStepsRequest,
+    ) -> Result<ListPipelineExecutionStepsResponse, RusotoError<ListPipelineExecutionStepsError>>
+    {
+        self.runtime
+            .block_on(self.client.list_pipeline_execution_steps(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_pipeline_executions`].
+    pub fn list_pipeline_executions(
+        &self,
+        input: ListPipelineExecutionsRequest,
+    ) -> Result<ListPipelineExecutionsResponse, RusotoError<ListPipelineExecutionsError>> {
+        self.runtime
+            .block_on(self.client.list_pipeline_executions(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_pipeline_parameters_for_execution`].
+    pub fn list_pipeline_parameters_for_execution(
+        &self,
+        input: ListPipelineParametersForExecutionRequest,
+    ) -> Result<
+        ListPipelineParametersForExecutionResponse,
+        RusotoError<ListPipelineParametersForExecutionError>,
+    > {
+        self.runtime
+            .block_on(self.client.list_pipeline_parameters_for_execution(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_pipelines`].
+    pub fn list_pipelines(
+        &self,
+        input: ListPipelinesRequest,
+    ) -> Result<ListPipelinesResponse, RusotoError<ListPipelinesError>> {
+        self.runtime.block_on(self.client.list_pipelines(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_processing_jobs`].
+    pub fn list_processing_jobs(
+        &self,
+        input: ListProcessingJobsRequest,
+    ) -> Result<ListProcessingJobsResponse, RusotoError<ListProcessingJobsError>> {
+        self.runtime
+            .block_on(self.client.list_processing_jobs(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_projects`].
+    pub fn list_projects(
+        &self,
+        input: ListProjectsInput,
+    ) -> Result<ListProjectsOutput, RusotoError<ListProjectsError>> {
+        self.runtime.block_on(self.client.list_projects(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_subscribed_workteams`].
+    pub fn list_subscribed_workteams(
+        &self,
+        input: ListSubscribedWorkteamsRequest,
+    ) -> Result<ListSubscribedWorkteamsResponse, RusotoError<ListSubscribedWorkteamsError>> {
+        self.runtime
+            .block_on(self.client.list_subscribed_workteams(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_tags`].
+    pub fn list_tags(
+        &self,
+        input: ListTagsInput,
+    ) -> Result<ListTagsOutput, RusotoError<ListTagsError>> {
+        self.runtime.block_on(self.client.list_tags(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_training_jobs`].
+    pub fn list_training_jobs(
+        &self,
+        input: ListTrainingJobsRequest,
+    ) -> Result<ListTrainingJobsResponse, RusotoError<ListTrainingJobsError>> {
+        self.runtime.block_on(self.client.list_training_jobs(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_training_jobs_for_hyper_parameter_tuning_job`].
+    pub fn list_training_jobs_for_hyper_parameter_tuning_job(
+        &self,
+        input: ListTrainingJobsForHyperParameterTuningJobRequest,
+    ) -> Result<
+        ListTrainingJobsForHyperParameterTuningJobResponse,
+        RusotoError<ListTrainingJobsForHyperParameterTuningJobError>,
+    > {
+        self.runtime.block_on(
+            self.client
+                .list_training_jobs_for_hyper_parameter_tuning_job(input),
+        )
+    }
+
+    /// Blocking version of [`SageMaker::list_transform_jobs`].
+    pub fn list_transform_jobs(
+        &self,
+        input: ListTransformJobsRequest,
+    ) -> Result<ListTransformJobsResponse, RusotoError<ListTransformJobsError>> {
+        self.runtime
+            .block_on(self.client.list_transform_jobs(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_trial_components`].
+    pub fn list_trial_components(
+        &self,
+        input: ListTrialComponentsRequest,
+    ) -> Result<ListTrialComponentsResponse, RusotoError<ListTrialComponentsError>> {
+        self.runtime
+            .block_on(self.client.list_trial_components(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_trials`].
+    pub fn list_trials(
+        &self,
+        input: ListTrialsRequest,
+    ) -> Result<ListTrialsResponse, RusotoError<ListTrialsError>> {
+        self.runtime.block_on(self.client.list_trials(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_user_profiles`].
+    pub fn list_user_profiles(
+        &self,
+        input: ListUserProfilesRequest,
+    ) -> Result<ListUserProfilesResponse, RusotoError<ListUserProfilesError>> {
+        self.runtime.block_on(self.client.list_user_profiles(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_workforces`].
+    pub fn list_workforces(
+        &self,
+        input: ListWorkforcesRequest,
+    ) -> Result<ListWorkforcesResponse, RusotoError<ListWorkforcesError>> {
+        self.runtime.block_on(self.client.list_workforces(input))
+    }
+
+    /// Blocking version of [`SageMaker::list_workteams`].
+    pub fn list_workteams(
+        &self,
+        input: ListWorkteamsRequest,
+    ) -> Result<ListWorkteamsResponse, RusotoError<ListWorkteamsError>> {
+        self.runtime.block_on(self.client.list_workteams(input))
+    }
+
+    /// Blocking version of [`SageMaker::put_model_package_group_policy`].
+    pub fn put_model_package_group_policy(
+        &self,
+        input: PutModelPackageGroupPolicyInput,
+    ) -> Result<PutModelPackageGroupPolicyOutput, RusotoError<PutModelPackageGroupPolicyError>>
+    {
+        self.runtime
+            .block_on(self.client.put_model_package_group_policy(input))
+    }
+
+    /// Blocking version of [`SageMaker::register_devices`].
+    pub fn register_devices(
+        &self,
+        input: RegisterDevicesRequest,
+    ) -> Result<(), RusotoError<RegisterDevicesError>> {
+        self.runtime.block_on(self.client.register_devices(input))
+    }
+
+    /// Blocking version of [`SageMaker::render_ui_template`].
+    pub fn render_ui_template(
+        &self,
+        input: RenderUiTemplateRequest,
+    ) -> Result<RenderUiTemplateResponse, RusotoError<RenderUiTemplateError>> {
+        self.runtime.block_on(self.client.render_ui_template(input))
+    }
+
+    /// Blocking version of [`SageMaker::search`].
+    pub fn search(&self, input: SearchRequest) -> Result<SearchResponse, RusotoError<SearchError>> {
+        self.runtime.block_on(self.client.search(input))
+    }
+
+    /// Blocking version of [`SageMaker::start_monitoring_schedule`].
+    pub fn start_monitoring_schedule(
+        &self,
+        input: StartMonitoringScheduleRequest,
+    ) -> Result<(), RusotoError<StartMonitoringScheduleError>> {
+        self.runtime
+            .block_on(self.client.start_monitoring_schedule(input))
+    }
+
+    /// Blocking version of [`SageMaker::start_notebook_instance`].
+    pub fn start_notebook_instance(
+        &self,
+        input: StartNotebookInstanceInput,
+    ) -> Result<(), RusotoError<StartNotebookInstanceError>> {
+        self.runtime
+            .block_on(self.client.start_notebook_instance(input))
+    }
+
+    /// Blocking version of [`SageMaker::start_pipeline_execution`].
+    pub fn start_pipeline_execution(
+        &self,
+        input: StartPipelineExecutionRequest,
+    ) -> Result<StartPipelineExecutionResponse, RusotoError<StartPipelineExecutionError>> {
+        self.runtime
+            .block_on(self.client.start_pipeline_execution(input))
+    }
+
+    /// Blocking version of [`SageMaker::stop_auto_ml_job`].
+    pub fn stop_auto_ml_job(
+        &self,
+        input: StopAutoMLJobRequest,
+    ) -> Result<(), RusotoError<StopAutoMLJobError>> {
+        self.runtime.block_on(self.client.stop_auto_ml_job(input))
+    }
+
+    /// Blocking version of [`SageMaker::stop_compilation_job`].
+    pub fn stop_compilation_job(
+        &self,
+        input: StopCompilationJobRequest,
+    ) -> Result<(), RusotoError<StopCompilationJobError>> {
+        self.runtime
+            .block_on(self.client.stop_compilation_job(input))
+    }
+
+    /// Blocking version of [`SageMaker::stop_edge_packaging_job`].
+    pub fn stop_edge_packaging_job(
+        &self,
+        input: StopEdgePackagingJobRequest,
+    ) -> Result<(), RusotoError<StopEdgePackagingJobError>> {
+        self.runtime
+            .block_on(self.client.stop_edge_packaging_job(input))
+    }
+
+    /// Blocking version of [`SageMaker::stop_hyper_parameter_tuning_job`].
+    pub fn stop_hyper_parameter_tuning_job(
+        &self,
+        input: StopHyperParameterTuningJobRequest,
+    ) -> Result<(), RusotoError<StopHyperParameterTuningJobError>> {
+        self.runtime
+            .block_on(self.client.stop_hyper_parameter_tuning_job(input))
+    }
+
+    /// Blocking version of [`SageMaker::stop_labeling_job`].
+    pub fn stop_labeling_job(
+        &self,
+        input: StopLabelingJobRequest,
+    ) -> Result<(), RusotoError<StopLabelingJobError>> {
+        self.runtime.block_on(self.client.stop_labeling_job(input))
+    }
+
+    /// Blocking version of [`SageMaker::stop_monitoring_schedule`].
+    pub fn stop_monitoring_schedule(
+        &self,
+        input: StopMonitoringScheduleRequest,
+    ) -> Result<(), RusotoError<StopMonitoringScheduleError>> {
+        self.runtime
+            .block_on(self.client.stop_monitoring_schedule(input))
+    }
+
+    /// Blocking version of [`SageMaker::stop_notebook_instance`].
+    pub fn stop_notebook_instance(
+        &self,
+        input: StopNotebookInstanceInput,
+    ) -> Result<(), RusotoError<StopNotebookInstanceError>> {
+        self.runtime
+            .block_on(self.client.stop_notebook_instance(input))
+    }
+
+    /// Blocking version of [`SageMaker::stop_pipeline_execution`].
+    pub fn stop_pipeline_execution(
+        &self,
+        input: StopPipelineExecutionRequest,
+    ) -> Result<StopPipelineExecutionResponse, RusotoError<StopPipelineExecutionError>> {
+        self.runtime
+            .block_on(self.client.stop_pipeline_execution(input))
+    }
+
+    /// Blocking version of [`SageMaker::stop_processing_job`].
+    pub fn stop_processing_job(
+        &self,
+        input: StopProcessingJobRequest,
+    ) -> Result<(), RusotoError<StopProcessingJobError>> {
+        self.runtime
+            .block_on(self.client.stop_processing_job(input))
+    }
+
+    /// Blocking version of [`SageMaker::stop_training_job`].
+    pub fn stop_training_job(
+        &self,
+        input: StopTrainingJobRequest,
+    ) -> Result<(), RusotoError<StopTrainingJobError>> {
+        self.runtime.block_on(self.client.stop_training_job(input))
+    }
+
+    /// Blocking version of [`SageMaker::stop_transform_job`].
+    pub fn stop_transform_job(
+        &self,
+        input: StopTransformJobRequest,
+    ) -> Result<(), RusotoError<StopTransformJobError>> {
+        self.runtime.block_on(self.client.stop_transform_job(input))
+    }
+
+    /// Blocking version of [`SageMaker::update_action`].
+    pub fn update_action(
+        &self,
+        input: UpdateActionRequest,
+    ) -> Result<UpdateActionResponse, RusotoError<UpdateActionError>> {
+        self.runtime.block_on(self.client.update_action(input))
+    }
+
+    /// Blocking version of [`SageMaker::update_app_image_config`].
+    pub fn update_app_image_config(
+        &self,
+        input: UpdateAppImageConfigRequest,
+    ) -> Result<UpdateAppImageConfigResponse, RusotoError<UpdateAppImageConfigError>> {
+        self.runtime
+            .block_on(self.client.update_app_image_config(input))
+    }
+
+    /// Blocking version of [`SageMaker::update_artifact`].
+    pub fn update_artifact(
+        &self,
+        input: UpdateArtifactRequest,
+    ) -> Result<UpdateArtifactResponse, RusotoError<UpdateArtifactError>> {
+        self.runtime.block_on(self.client.update_artifact(input))
+    }
+
+    /// Blocking version of [`SageMaker::update_code_repository`].
+    pub fn update_code_repository(
+        &self,
+        input: UpdateCodeRepositoryInput,
+    ) -> Result<UpdateCodeRepositoryOutput, RusotoError<UpdateCodeRepositoryError>> {
+        self.runtime
+            .block_on(self.client.update_code_repository(input))
+    }
+
+    /// Blocking version of [`SageMaker::update_context`].
+    pub fn update_context(
+        &self,
+        input: UpdateContextRequest,
+    ) -> Result<UpdateContextResponse, RusotoError<UpdateContextError>> {
+        self.runtime.block_on(self.client.update_context(input))
+    }
+
+    /// Blocking version of [`SageMaker::update_device_fleet`].
+    pub fn update_device_fleet(
+        &self,
+        input: UpdateDeviceFleetRequest,
+    ) -> Result<(), RusotoError<UpdateDeviceFleetError>> {
+        self.runtime
+            .block_on(self.client.update_device_fleet(input))
+    }
+
+    /// Blocking version of [`SageMaker::update_devices`].
+    pub fn update_devices(
+        &self,
+        input: UpdateDevicesRequest,
+    ) -> Result<(), RusotoError<UpdateDevicesError>> {
+        self.runtime.block_on(self.client.update_devices(input))
+    }
+
+    /// Blocking version of [`SageMaker::update_domain`].
+    pub fn update_domain(
+        &self,
+        input: UpdateDomainRequest,
+    ) -> Result<UpdateDomainResponse, RusotoError<UpdateDomainError>> {
+        self.runtime.block_on(self.client.update_domain(input))
+    }
+
+    /// Blocking version of [`SageMaker::update_endpoint`].
+    pub fn update_endpoint(
+        &self,
+        input: UpdateEndpointInput,
+    ) -> Result<UpdateEndpointOutput, RusotoError<UpdateEndpointError>> {
+        self.runtime.block_on(self.client.update_endpoint(input))
+    }
+
+    /// Blocking version of [`SageMaker::update_endpoint_weights_and_capacities`].
+    pub fn update_endpoint_weights_and_capacities(
+        &self,
+        input: UpdateEndpointWeightsAndCapacitiesInput,
+    ) -> Result<
+        UpdateEndpointWeightsAndCapacitiesOutput,
+        RusotoError<UpdateEndpointWeightsAndCapacitiesError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_endpoint_weights_and_capacities(input))
+    }
+
+    /// Blocking version of [`SageMaker::update_experiment`].
+    pub fn update_experiment(
+        &self,
+        input: UpdateExperimentRequest,
+    ) -> Result<UpdateExperimentResponse, RusotoError<UpdateExperimentError>> {
+        self.runtime.block_on(self.client.update_experiment(input))
+    }
+
+    /// Blocking version of [`SageMaker::update_image`].
+    pub fn update_image(
+        &self,
+        input: UpdateImageRequest,
+    ) -> Result<UpdateImageResponse, RusotoError<UpdateImageError>> {
+        self.runtime.block_on(self.client.update_image(input))
+    }
+
+    /// Blocking version of [`SageMaker::update_model_package`].
+    pub fn update_model_package(
+        &self,
+        input: UpdateModelPackageInput,
+    ) -> Result<UpdateModelPackageOutput, RusotoError<UpdateModelPackageError>> {
+        self.runtime
+            .block_on(self.client.update_model_package(input))
+    }
+
+    /// Blocking version of [`SageMaker::update_monitoring_schedule`].
+    pub fn update_monitoring_schedule(
+        &self,
+        input: UpdateMonitoringScheduleRequest,
+    ) -> Result<UpdateMonitoringScheduleResponse, RusotoError<UpdateMonitoringScheduleError>> {
+        self.runtime
+            .block_on(self.client.update_monitoring_schedule(input))
+    }
+
+    /// Blocking version of [`SageMaker::update_notebook_instance`].
+    pub fn update_notebook_instance(
+        &self,
+        input: UpdateNotebookInstanceInput,
+    ) -> Result<UpdateNotebookInstanceOutput, RusotoError<UpdateNotebookInstanceError>> {
+        self.runtime
+            .block_on(self.client.update_notebook_instance(input))
+    }
+
+    /// Blocking version of [`SageMaker::update_notebook_instance_lifecycle_config`].
+    pub fn update_notebook_instance_lifecycle_config(
+        &self,
+        input: UpdateNotebookInstanceLifecycleConfigInput,
+    ) -> Result<
+        UpdateNotebookInstanceLifecycleConfigOutput,
+        RusotoError<UpdateNotebookInstanceLifecycleConfigError>,
+    > {
+        self.runtime
+            .block_on(self.client.update_notebook_instance_lifecycle_config(input))
+    }
+
+    /// Blocking version of [`SageMaker::update_pipeline`].
+    pub fn update_pipeline(
+        &self,
+        input: UpdatePipelineRequest,
+    ) -> Result<UpdatePipelineResponse, RusotoError<UpdatePipelineError>> {
+        self.runtime.block_on(self.client.update_pipeline(input))
+    }
+
+    /// Blocking version of [`SageMaker::update_pipeline_execution`].
+    pub fn update_pipeline_execution(
+        &self,
+        input: UpdatePipelineExecutionRequest,
+    ) -> Result<UpdatePipelineExecutionResponse, RusotoError<UpdatePipelineExecutionError>> {
+        self.runtime
+            .block_on(self.client.update_pipeline_execution(input))
+    }
+
+    /// Blocking version of [`SageMaker::update_training_job`].
+    pub fn update_training_job(
+        &self,
+        input: UpdateTrainingJobRequest,
+    ) -> Result<UpdateTrainingJobResponse, RusotoError<UpdateTrainingJobError>> {
+        self.runtime
+            .block_on(self.client.update_training_job(input))
+    }
+
+    /// Blocking version of [`SageMaker::update_trial`].
+    pub fn update_trial(
+        &self,
+        input: UpdateTrialRequest,
+    ) -> Result<UpdateTrialResponse, RusotoError<UpdateTrialError>> {
+        self.runtime.block_on(self.client.update_trial(input))
+    }
+
+    /// Blocking version of [`SageMaker::update_trial_component`].
+    pub fn update_trial_component(
+        &self,
+        input: UpdateTrialComponentRequest,
+    ) -> Result<UpdateTrialComponentResponse, RusotoError<UpdateTrialComponentError>> {
+        self.runtime
+            .block_on(self.client.update_trial_component(input))
+    }
+
+    /// Blocking version of [`SageMaker::update_user_profile`].
+    pub fn update_user_profile(
+        &self,
+        input: UpdateUserProfileRequest,
+    ) -> Result<UpdateUserProfileResponse, RusotoError<UpdateUserProfileError>> {
+        self.runtime
+            .block_on(self.client.update_user_profile(input))
+    }
+
+    /// Blocking version of [`SageMaker::update_workforce`].
+    pub fn update_workforce(
+        &self,
+        input: UpdateWorkforceRequest,
+    ) -> Result<UpdateWorkforceResponse, RusotoError<UpdateWorkforceError>> {
+        self.runtime.block_on(self.client.update_workforce(input))
+    }
+
+    /// Blocking version of [`SageMaker::update_workteam`].
+    pub fn update_workteam(
+        &self,
+        input: UpdateWorkteamRequest,
+    ) -> Result<UpdateWorkteamResponse, RusotoError<UpdateWorkteamError>> {
+        self.runtime.block_on(self.client.update_workteam(input))
+    }
+}
@@ -0,0 +1,49 @@
+extern crate rusoto_mock;
+
+use crate::generated::{ListDomainsRequest, SimpleDb, SimpleDbClient};
+
+use self::rusoto_mock::*;
+use rusoto_core::param::Params;
+use rusoto_core::signature::{SignedRequest, SignedRequestPayload};
+use rusoto_core::Region;
+
+#[tokio::test]
+async fn should_sign_requests_with_signature_version_2() {
+    let mock = MockRequestDispatcher::with_status(200)
+        .with_body(
+            r#"<?xml version="1.0" encoding="UTF-8"?>
+        <ListDomainsResponse>
+            <ListDomainsResult>
+                <DomainName>MyDomain</DomainName>
+            </ListDomainsResult>
+            <ResponseMetadata>
+                <RequestId>eb13162f-1b95-4511-8b12-489b86acfd28</RequestId>
+                <BoxUsage>0.0000219907</BoxUsage>
+            </ResponseMetadata>
+        </ListDomainsResponse>"#,
+        )
+        .with_request_checker(|request: &SignedRequest| {
+            assert!(!request.headers.contains_key("authorization"));
+            if let Some(SignedRequestPayload::Buffer(ref buffer)) = request.payload {
+                let params: Params = serde_urlencoded::from_bytes(buffer).unwrap();
+                assert_eq!(Some(&Some("ListDomains".to_owned())), params.get("Action"));
+                assert_eq!(
+                    Some(&Some("HmacSHA256".to_owned())),
+                    params.get("SignatureMethod")
+                );
+                assert_eq!(Some(&Some("2".to_owned())), params.get("SignatureVersion"));
+                assert!(params.contains_key("AWSAccessKeyId"));
+                assert!(params.contains_key("Timestamp"));
+                assert!(params.contains_key("Signature"));
+            } else {
+                panic!("Unexpected request.payload: {:?}", request.payload);
+            }
+        });
+
+    let client = SimpleDbClient::new_with(mock, MockCredentialsProvider, Region::UsEast1);
+    let result = client
+        .list_domains(ListDomainsRequest::default())
+        .await
+        .unwrap();
+    assert_eq!(result.domain_names, Some(vec!["MyDomain".to_owned()]));
+}